@@ -23,7 +23,7 @@ async fn send_ssh_public_key_correctly_errors_for_unknown_instance() {
             panic!("send_ssh_public_key should fail");
         }
         Err(error) => match error {
-            RusotoError::Service(e) => match e {
+            RusotoError::Service(e, _) => match e {
                 SendSSHPublicKeyError::InvalidArgs(error) => assert!(
                     error.contains("Instance not found"),
                     "Missing error message"
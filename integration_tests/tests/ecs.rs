@@ -37,7 +37,7 @@ async fn main() {
         })
         .await
     {
-        Err(RusotoError::Service(ListClustersError::InvalidParameter(msg))) => {
+        Err(RusotoError::Service(ListClustersError::InvalidParameter(msg), _)) => {
             assert!(msg.contains("Invalid token bogus"))
         }
         _ => panic!("this should have been an InvalidParameterException ECSError"),
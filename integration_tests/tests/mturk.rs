@@ -23,7 +23,7 @@ async fn should_list_hits() {
                     ),
                     "Missing error message"
                 ),
-                RusotoError::Service(ListHITsError::RequestError(_)) => (), // request doesn't work without a linked mturk account, this is ok
+                RusotoError::Service(ListHITsError::RequestError(_), _) => (), // request doesn't work without a linked mturk account, this is ok
                 _ => panic!("Should have a typed error from MTurk, got {:?}", e),
             }
         }
@@ -900,7 +900,7 @@ async fn test_get_object(client: &S3Client, bucket: &str, filename: &str) {
     println!("get object result: {:#?}", result);
 
     let stream = result.body.unwrap();
-    let body = stream.map_ok(|b| bytes::BytesMut::from(&b[..])).try_concat().await.unwrap();
+    let body = stream.into_bytes().await.unwrap();
 
     assert!(body.len() > 0);
 }
@@ -933,7 +933,7 @@ async fn test_get_object_no_such_object(client: &S3Client, bucket: &str, filenam
     };
 
     match client.get_object(get_req).await {
-        Err(RusotoError::Service(GetObjectError::NoSuchKey(_))) => (),
+        Err(RusotoError::Service(GetObjectError::NoSuchKey(_), _)) => (),
         r => panic!("unexpected response {:?}", r),
     };
 }
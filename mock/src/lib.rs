@@ -34,11 +34,12 @@
 #![deny(missing_docs)]
 use std::fs::File;
 use std::io::Read;
+use std::sync::Mutex;
 use std::time::Duration;
 
 use async_trait::async_trait;
 use futures::FutureExt;
-use http::{header::HeaderName, HeaderMap, StatusCode};
+use http::{header::HeaderName, HeaderMap, Request, StatusCode};
 use rusoto_core::credential::{AwsCredentials, ProvideAwsCredentials};
 use rusoto_core::request::HttpResponse;
 use rusoto_core::signature::SignedRequest;
@@ -110,6 +111,13 @@ impl MockRequestDispatcher {
         self
     }
 
+    /// Mocks a binary response body, like an event stream, what would be
+    /// returned from AWS
+    pub fn with_binary_body(mut self, body: &[u8]) -> MockRequestDispatcher {
+        self.body = body.to_vec();
+        self
+    }
+
     /// Mocks the json serialized response body what would be
     /// returned from AWS
     pub fn with_json_body<B>(mut self, body: B) -> MockRequestDispatcher
@@ -121,7 +129,8 @@ impl MockRequestDispatcher {
     }
 
     /// Mocks the signed request checking applied to a request before sending
-    /// to AWS
+    /// to AWS; streamed payloads are only in the body of the HTTP request, not in the
+    /// signed request checked
     pub fn with_request_checker<F>(mut self, checker: F) -> MockRequestDispatcher
     where
         F: Fn(&SignedRequest) + Send + Sync + 'static,
@@ -141,24 +150,73 @@ impl MockRequestDispatcher {
 impl DispatchSignedRequest for MockRequestDispatcher {
     fn dispatch(
         &self,
-        request: SignedRequest,
+        request: Request<ByteStream>,
         _timeout: Option<Duration>,
     ) -> rusoto_core::request::DispatchSignedRequestFuture {
-        if self.request_checker.is_some() {
-            self.request_checker.as_ref().unwrap()(&request);
+        if let Some(ref checker) = self.request_checker {
+            let request = request
+                .extensions()
+                .get::<SignedRequest>()
+                .expect("requests are sent with their signed request");
+            checker(request);
         }
         match self.outcome {
-            RequestOutcome::Performed(ref status) => futures::future::ready(Ok(HttpResponse {
-                status: *status,
-                body: ByteStream::from(self.body.clone()),
-                headers: self.headers.clone(),
-            }))
-            .boxed(),
+            RequestOutcome::Performed(ref status) => {
+                let response = HttpResponse {
+                    status: *status,
+                    body: ByteStream::from(self.body.clone()),
+                    headers: self.headers.clone(),
+                };
+                futures::future::ready(Ok(response.into())).boxed()
+            }
             RequestOutcome::Failed(ref error) => futures::future::ready(Err(error.clone())).boxed(),
         }
     }
 }
 
+/// Mocks a sequence of service responses, dispatching each request to the next mock of a
+/// collection, to test code making several requests, such as paginated listings
+pub struct MultipleMockRequestDispatcher<I>
+where
+    I: Iterator<Item = MockRequestDispatcher>,
+{
+    iterator: Mutex<I>,
+}
+
+impl<I> MultipleMockRequestDispatcher<I>
+where
+    I: Iterator<Item = MockRequestDispatcher>,
+{
+    /// Dispatches the requests to the mocks of `collection` in order, panicking on requests
+    /// past its end
+    pub fn new<C>(collection: C) -> MultipleMockRequestDispatcher<I>
+    where
+        C: IntoIterator<Item = MockRequestDispatcher, IntoIter = I>,
+    {
+        MultipleMockRequestDispatcher {
+            iterator: Mutex::new(collection.into_iter()),
+        }
+    }
+}
+
+impl<I> DispatchSignedRequest for MultipleMockRequestDispatcher<I>
+where
+    I: Iterator<Item = MockRequestDispatcher> + Send,
+{
+    fn dispatch(
+        &self,
+        request: Request<ByteStream>,
+        timeout: Option<Duration>,
+    ) -> rusoto_core::request::DispatchSignedRequestFuture {
+        self.iterator
+            .lock()
+            .unwrap()
+            .next()
+            .expect("Ran out of mock responses")
+            .dispatch(request, timeout)
+    }
+}
+
 /// An interface for producing response body content
 pub trait ReadMockResponse {
     /// Return a response body string for a given directory and file name
@@ -0,0 +1,85 @@
+//! Calling services from synchronous code.
+
+use std::future::Future;
+use std::io;
+
+use tokio::runtime::{Builder, Runtime};
+
+/// Wraps a service client to call its operations from synchronous code, running them on a
+/// runtime owned by the wrapper.
+///
+/// ```rust,ignore
+/// let s3 = BlockingClient::new(S3Client::new(Region::UsEast1))?;
+/// let buckets = s3.call(|s3| s3.list_buckets())?;
+/// ```
+///
+/// Calls may be made from several threads at once. They must not be made from within an async
+/// context, as they block the calling thread until the operation has completed.
+pub struct BlockingClient<T> {
+    client: T,
+    runtime: Runtime,
+}
+
+impl<T> BlockingClient<T> {
+    /// Wrap `client`, starting the runtime its operations run on.
+    pub fn new(client: T) -> io::Result<BlockingClient<T>> {
+        let runtime = Builder::new()
+            .threaded_scheduler()
+            .core_threads(1)
+            .thread_name("rusoto-blocking")
+            .enable_all()
+            .build()?;
+        Ok(BlockingClient { client, runtime })
+    }
+
+    /// Run the operation `f` starts on the client until it completes, and return its output.
+    pub fn call<'a, F, Fut>(&'a self, f: F) -> Fut::Output
+    where
+        F: FnOnce(&'a T) -> Fut,
+        Fut: Future + 'a,
+    {
+        self.block_on(f(&self.client))
+    }
+
+    /// Run any future, e.g. one reading the body of a response, on the runtime of this client.
+    pub fn block_on<F: Future>(&self, future: F) -> F::Output {
+        self.runtime.handle().block_on(future)
+    }
+
+    /// The wrapped client.
+    pub fn get_ref(&self) -> &T {
+        &self.client
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::Arc;
+    use std::thread;
+    use std::time::Duration;
+
+    struct SleepyClient;
+
+    impl SleepyClient {
+        async fn operation(&self, output: u32) -> u32 {
+            tokio::time::delay_for(Duration::from_millis(1)).await;
+            output
+        }
+    }
+
+    #[test]
+    fn runs_operations_to_completion() {
+        let client = Arc::new(BlockingClient::new(SleepyClient).unwrap());
+        assert_eq!(client.call(|client| client.operation(1)), 1);
+
+        let threads: Vec<_> = (0..4)
+            .map(|i| {
+                let client = client.clone();
+                thread::spawn(move || client.call(|client| client.operation(i)))
+            })
+            .collect();
+        let outputs: Vec<_> = threads.into_iter().map(|t| t.join().unwrap()).collect();
+        assert_eq!(outputs, vec![0, 1, 2, 3]);
+    }
+}
@@ -0,0 +1,360 @@
+//! Building service clients from optional settings.
+
+use std::fmt;
+use std::sync::Arc;
+use std::time::Duration;
+
+use crate::client::Client;
+#[cfg(feature = "tokio")]
+use crate::credential::DefaultCredentialsProvider;
+use crate::credential::{AnonymousCredentials, ProvideAwsCredentials};
+use crate::endpoint::{
+    AccountIdEndpointMode, EndpointResolver, S3AddressingStyle, StsRegionalEndpoints,
+};
+use crate::region::Region;
+use crate::request::DispatchSignedRequest;
+#[cfg(feature = "tokio")]
+use crate::request::HttpClient;
+use crate::retry::RetryPolicy;
+use crate::signature::PayloadSigning;
+
+/// Builder of the service client `C`, returned from the `builder` method of each service client,
+/// e.g. `S3Client::builder()`.
+///
+/// Settings left unset take the values `C::new` would use: the region from the environment, the
+/// shared `HttpClient` and the `DefaultCredentialsProvider`. On wasm32, the dispatcher defaults
+/// to a `FetchClient` and requests are not signed unless a credentials provider is set. Without
+/// the `tokio` feature elsewhere, there is no default dispatcher and requests are not signed
+/// unless a credentials provider is set.
+///
+/// ```rust,ignore
+/// let s3 = S3Client::builder()
+///     .region(Region::EuWest1)
+///     .endpoint("http://localhost:4566")
+///     .timeout(Duration::from_secs(10))
+///     .build();
+/// ```
+pub struct ClientBuilder<C> {
+    new_with_client: fn(Client, Region) -> C,
+    region: Option<Region>,
+    endpoint: Option<String>,
+    endpoint_resolver: Option<Arc<dyn EndpointResolver>>,
+    credentials_provider: Option<Arc<dyn ProvideAwsCredentials + Send + Sync>>,
+    dispatcher: Option<Arc<dyn DispatchSignedRequest + Send + Sync>>,
+    use_fips_endpoint: Option<bool>,
+    use_dualstack_endpoint: Option<bool>,
+    account_id_endpoint_mode: Option<AccountIdEndpointMode>,
+    sts_regional_endpoints: Option<StsRegionalEndpoints>,
+    s3_addressing_style: Option<S3AddressingStyle>,
+    use_accelerate_endpoint: Option<bool>,
+    follow_s3_redirects: Option<bool>,
+    payload_signing: Option<PayloadSigning>,
+    retry_policy: Option<RetryPolicy>,
+    timeout: Option<Duration>,
+    operation_timeout: Option<Duration>,
+}
+
+impl<C> ClientBuilder<C> {
+    /// Create a builder finishing with `new_with_client`, called by the generated service
+    /// clients.
+    #[doc(hidden)]
+    pub fn new(new_with_client: fn(Client, Region) -> C) -> ClientBuilder<C> {
+        ClientBuilder {
+            new_with_client,
+            region: None,
+            endpoint: None,
+            endpoint_resolver: None,
+            credentials_provider: None,
+            dispatcher: None,
+            use_fips_endpoint: None,
+            use_dualstack_endpoint: None,
+            account_id_endpoint_mode: None,
+            sts_regional_endpoints: None,
+            s3_addressing_style: None,
+            use_accelerate_endpoint: None,
+            follow_s3_redirects: None,
+            payload_signing: None,
+            retry_policy: None,
+            timeout: None,
+            operation_timeout: None,
+        }
+    }
+
+    /// Set the region to send requests to.
+    pub fn region(mut self, region: Region) -> Self {
+        self.region = Some(region);
+        self
+    }
+
+    /// Send requests to `endpoint`, keeping the name of the region for signing.
+    pub fn endpoint<S: Into<String>>(mut self, endpoint: S) -> Self {
+        self.endpoint = Some(endpoint.into());
+        self
+    }
+
+    /// Set a resolver choosing the endpoint of each request, see `Client::set_endpoint_resolver`.
+    pub fn endpoint_resolver<R>(mut self, resolver: R) -> Self
+    where
+        R: EndpointResolver + 'static,
+    {
+        self.endpoint_resolver = Some(Arc::new(resolver));
+        self
+    }
+
+    /// Send requests to FIPS endpoints, see `Client::set_use_fips_endpoint`.
+    pub fn use_fips_endpoint(mut self, use_fips_endpoint: bool) -> Self {
+        self.use_fips_endpoint = Some(use_fips_endpoint);
+        self
+    }
+
+    /// Send requests to dual-stack endpoints, see `Client::set_use_dualstack_endpoint`.
+    pub fn use_dualstack_endpoint(mut self, use_dualstack_endpoint: bool) -> Self {
+        self.use_dualstack_endpoint = Some(use_dualstack_endpoint);
+        self
+    }
+
+    /// Choose whether to use account-ID-based endpoints, see
+    /// `Client::set_account_id_endpoint_mode`.
+    pub fn account_id_endpoint_mode(mut self, mode: AccountIdEndpointMode) -> Self {
+        self.account_id_endpoint_mode = Some(mode);
+        self
+    }
+
+    /// Choose whether STS requests go to regional endpoints, see
+    /// `Client::set_sts_regional_endpoints`.
+    pub fn sts_regional_endpoints(mut self, setting: StsRegionalEndpoints) -> Self {
+        self.sts_regional_endpoints = Some(setting);
+        self
+    }
+
+    /// Choose whether S3 requests name their bucket in the path or the hostname, see
+    /// `Client::set_s3_addressing_style`.
+    pub fn s3_addressing_style(mut self, style: S3AddressingStyle) -> Self {
+        self.s3_addressing_style = Some(style);
+        self
+    }
+
+    /// Send S3 requests through S3 Transfer Acceleration, see
+    /// `Client::set_use_accelerate_endpoint`.
+    pub fn use_accelerate_endpoint(mut self, use_accelerate_endpoint: bool) -> Self {
+        self.use_accelerate_endpoint = Some(use_accelerate_endpoint);
+        self
+    }
+
+    /// Choose whether S3 requests redirected to the region of their bucket are sent there, see
+    /// `Client::set_follow_s3_redirects`.
+    pub fn follow_s3_redirects(mut self, follow_s3_redirects: bool) -> Self {
+        self.follow_s3_redirects = Some(follow_s3_redirects);
+        self
+    }
+
+    /// Choose how the payloads of S3 requests are signed, see `Client::set_payload_signing`.
+    pub fn payload_signing(mut self, payload_signing: PayloadSigning) -> Self {
+        self.payload_signing = Some(payload_signing);
+        self
+    }
+
+    /// Set the provider of the credentials requests are signed with.
+    pub fn credentials_provider<P>(mut self, credentials_provider: P) -> Self
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+    {
+        self.credentials_provider = Some(Arc::new(credentials_provider));
+        self
+    }
+
+    /// Send requests unsigned, with `AnonymousCredentials`, e.g. to read public S3 buckets.
+    pub fn anonymous(self) -> Self {
+        self.credentials_provider(AnonymousCredentials)
+    }
+
+    /// Set the dispatcher sending requests, e.g. an `HttpClient` with a custom `HttpConfig`.
+    pub fn dispatcher<D>(mut self, dispatcher: D) -> Self
+    where
+        D: DispatchSignedRequest + Send + Sync + 'static,
+    {
+        self.dispatcher = Some(Arc::new(dispatcher));
+        self
+    }
+
+    /// Set the policy used to retry failed requests, see `Client::set_retry_policy`.
+    pub fn retry_policy(mut self, retry_policy: RetryPolicy) -> Self {
+        self.retry_policy = Some(retry_policy);
+        self
+    }
+
+    /// Set the timeout of each attempt, see `Client::set_timeout`.
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// Set the deadline of whole operations, see `Client::set_operation_timeout`.
+    pub fn operation_timeout(mut self, operation_timeout: Duration) -> Self {
+        self.operation_timeout = Some(operation_timeout);
+        self
+    }
+
+    /// Create the service client.
+    ///
+    /// # Panics
+    ///
+    /// If the default credentials provider or dispatcher need to be created and can't be, like
+    /// `C::new`, or if no dispatcher is set without the `tokio` feature.
+    pub fn build(self) -> C {
+        let mut client = match (self.credentials_provider, self.dispatcher) {
+            (Some(credentials_provider), Some(dispatcher)) => {
+                Client::new_with(credentials_provider, dispatcher)
+            }
+            #[cfg(feature = "tokio")]
+            (None, None) => Client::shared(),
+            #[cfg(feature = "tokio")]
+            (credentials_provider, dispatcher) => Client::new_with(
+                credentials_provider.unwrap_or_else(|| {
+                    Arc::new(
+                        DefaultCredentialsProvider::new()
+                            .expect("failed to create credentials provider"),
+                    )
+                }),
+                dispatcher.unwrap_or_else(|| {
+                    Arc::new(HttpClient::new().expect("failed to create request dispatcher"))
+                }),
+            ),
+            #[cfg(target_arch = "wasm32")]
+            (Some(credentials_provider), None) => {
+                Client::new_with(credentials_provider, crate::wasm::FetchClient::new())
+            }
+            #[cfg(target_arch = "wasm32")]
+            (None, dispatcher) => match dispatcher {
+                Some(dispatcher) => Client::new_not_signing(dispatcher),
+                None => Client::new_not_signing(crate::wasm::FetchClient::new()),
+            },
+            #[cfg(not(any(feature = "tokio", target_arch = "wasm32")))]
+            (None, Some(dispatcher)) => Client::new_not_signing(dispatcher),
+            #[cfg(not(any(feature = "tokio", target_arch = "wasm32")))]
+            (_, None) => panic!("a dispatcher must be set without the tokio feature"),
+        };
+        if let Some(resolver) = self.endpoint_resolver {
+            client.set_endpoint_resolver(resolver);
+        }
+        if let Some(use_fips_endpoint) = self.use_fips_endpoint {
+            client.set_use_fips_endpoint(use_fips_endpoint);
+        }
+        if let Some(use_dualstack_endpoint) = self.use_dualstack_endpoint {
+            client.set_use_dualstack_endpoint(use_dualstack_endpoint);
+        }
+        if let Some(mode) = self.account_id_endpoint_mode {
+            client.set_account_id_endpoint_mode(mode);
+        }
+        if let Some(setting) = self.sts_regional_endpoints {
+            client.set_sts_regional_endpoints(setting);
+        }
+        if let Some(style) = self.s3_addressing_style {
+            client.set_s3_addressing_style(style);
+        }
+        if let Some(use_accelerate_endpoint) = self.use_accelerate_endpoint {
+            client.set_use_accelerate_endpoint(use_accelerate_endpoint);
+        }
+        if let Some(follow_s3_redirects) = self.follow_s3_redirects {
+            client.set_follow_s3_redirects(follow_s3_redirects);
+        }
+        if let Some(payload_signing) = self.payload_signing {
+            client.set_payload_signing(payload_signing);
+        }
+        if let Some(retry_policy) = self.retry_policy {
+            client.set_retry_policy(retry_policy);
+        }
+        if self.timeout.is_some() {
+            client.set_timeout(self.timeout);
+        }
+        if self.operation_timeout.is_some() {
+            client.set_operation_timeout(self.operation_timeout);
+        }
+        let region = self.region.unwrap_or_default();
+        let region = match self.endpoint {
+            Some(endpoint) => Region::Custom {
+                name: region.name().to_owned(),
+                endpoint,
+            },
+            None => region,
+        };
+        (self.new_with_client)(client, region)
+    }
+}
+
+impl<C> fmt::Debug for ClientBuilder<C> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("ClientBuilder")
+            .field("region", &self.region)
+            .field("endpoint", &self.endpoint)
+            .field("endpoint_resolver", &self.endpoint_resolver.is_some())
+            .field("credentials_provider", &self.credentials_provider.is_some())
+            .field("dispatcher", &self.dispatcher.is_some())
+            .field("use_fips_endpoint", &self.use_fips_endpoint)
+            .field("use_dualstack_endpoint", &self.use_dualstack_endpoint)
+            .field("account_id_endpoint_mode", &self.account_id_endpoint_mode)
+            .field("sts_regional_endpoints", &self.sts_regional_endpoints)
+            .field("s3_addressing_style", &self.s3_addressing_style)
+            .field("use_accelerate_endpoint", &self.use_accelerate_endpoint)
+            .field("follow_s3_redirects", &self.follow_s3_redirects)
+            .field("payload_signing", &self.payload_signing)
+            .field("retry_policy", &self.retry_policy)
+            .field("timeout", &self.timeout)
+            .field("operation_timeout", &self.operation_timeout)
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::credential::StaticProvider;
+    use crate::request::{DispatchSignedRequestFuture, HttpResponse};
+    use crate::signature::SignedRequest;
+    use crate::stream::ByteStream;
+    use futures::FutureExt;
+    use http::{HeaderMap, Request, StatusCode};
+
+    struct OkDispatcher;
+
+    impl DispatchSignedRequest for OkDispatcher {
+        fn dispatch(
+            &self,
+            _request: Request<ByteStream>,
+            _timeout: Option<Duration>,
+        ) -> DispatchSignedRequestFuture {
+            futures::future::ready(Ok(HttpResponse {
+                status: StatusCode::OK,
+                body: Vec::new().into(),
+                headers: HeaderMap::default(),
+            }
+            .into()))
+            .boxed()
+        }
+    }
+
+    #[tokio::test]
+    async fn builds_clients_from_settings() {
+        let (client, region) = ClientBuilder::new(|client, region| (client, region))
+            .region(Region::EuWest1)
+            .endpoint("http://localhost:4566")
+            .credentials_provider(StaticProvider::new_minimal(
+                "key".to_owned(),
+                "secret".to_owned(),
+            ))
+            .dispatcher(OkDispatcher)
+            .retry_policy(RetryPolicy::none())
+            .build();
+        assert_eq!(
+            region,
+            Region::Custom {
+                name: "eu-west-1".to_owned(),
+                endpoint: "http://localhost:4566".to_owned(),
+            }
+        );
+        assert_eq!(client.retry_policy().max_attempts(), 1);
+        let request = SignedRequest::new("GET", "sqs", &region, "/");
+        let response = client.sign_and_dispatch(request).await.unwrap();
+        assert_eq!(response.status, StatusCode::OK);
+    }
+}
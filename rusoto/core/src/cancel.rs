@@ -0,0 +1,190 @@
+//! Aborting operations that are in flight.
+
+use std::collections::HashMap;
+use std::fmt;
+use std::future::Future;
+use std::io;
+use std::pin::Pin;
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex};
+use std::task::{Context, Poll, Waker};
+
+use bytes::Bytes;
+use futures::future::{self, Either};
+use futures::{FutureExt, Stream, StreamExt};
+
+use crate::stream::ByteStream;
+
+/// A handle aborting the operations of the clients it is set on, with
+/// `Client::set_cancellation_token`.
+///
+/// Operations in flight when the token is cancelled, and any started afterwards, fail with an
+/// `HttpDispatchError` for which `is_cancelled` is true. So do reads from the bodies of responses
+/// they returned, such as large S3 downloads that are still streaming.
+///
+/// The connection of a cancelled request is closed rather than returned to the pool, as its
+/// response may not have been read completely. Dropping an operation future has the same effect
+/// on the request it was sending.
+#[derive(Clone, Default)]
+pub struct CancellationToken {
+    inner: Arc<Inner>,
+}
+
+#[derive(Default)]
+struct Inner {
+    cancelled: AtomicBool,
+    next_waiter: AtomicUsize,
+    waiters: Mutex<HashMap<usize, Waker>>,
+}
+
+impl CancellationToken {
+    /// Create a token that isn't cancelled.
+    pub fn new() -> CancellationToken {
+        CancellationToken::default()
+    }
+
+    /// Cancel the operations using this token, and all of its clones.
+    pub fn cancel(&self) {
+        self.inner.cancelled.store(true, Ordering::SeqCst);
+        let waiters = std::mem::take(&mut *self.inner.waiters.lock().unwrap());
+        for (_, waker) in waiters {
+            waker.wake();
+        }
+    }
+
+    /// Whether `cancel` has been called.
+    pub fn is_cancelled(&self) -> bool {
+        self.inner.cancelled.load(Ordering::SeqCst)
+    }
+
+    /// A future completing once the token is cancelled.
+    pub fn cancelled(&self) -> Cancelled {
+        Cancelled {
+            inner: self.inner.clone(),
+            id: self.inner.next_waiter.fetch_add(1, Ordering::Relaxed),
+        }
+    }
+
+    /// Await `future`, or return `None` if the token is cancelled first.
+    pub(crate) async fn run<F: Future>(&self, future: F) -> Option<F::Output> {
+        if self.is_cancelled() {
+            return None;
+        }
+        match future::select(Box::pin(future), self.cancelled()).await {
+            Either::Left((output, _)) => Some(output),
+            Either::Right(_) => None,
+        }
+    }
+
+    /// Make reads from `body` fail once the token is cancelled, dropping the underlying stream.
+    pub(crate) fn guard(&self, body: ByteStream) -> ByteStream {
+        ByteStream::new(GuardedStream {
+            inner: Some(body),
+            cancelled: self.cancelled(),
+        })
+    }
+}
+
+impl fmt::Debug for CancellationToken {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("CancellationToken")
+            .field("cancelled", &self.is_cancelled())
+            .finish()
+    }
+}
+
+/// Future returned from `CancellationToken::cancelled`.
+pub struct Cancelled {
+    inner: Arc<Inner>,
+    id: usize,
+}
+
+impl Future for Cancelled {
+    type Output = ();
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<()> {
+        if self.inner.cancelled.load(Ordering::SeqCst) {
+            return Poll::Ready(());
+        }
+        self.inner
+            .waiters
+            .lock()
+            .unwrap()
+            .insert(self.id, cx.waker().clone());
+        // `cancel` may have run between the check and registering the waker.
+        if self.inner.cancelled.load(Ordering::SeqCst) {
+            return Poll::Ready(());
+        }
+        Poll::Pending
+    }
+}
+
+impl Drop for Cancelled {
+    fn drop(&mut self) {
+        if let Ok(mut waiters) = self.inner.waiters.lock() {
+            waiters.remove(&self.id);
+        }
+    }
+}
+
+impl fmt::Debug for Cancelled {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("Cancelled").finish()
+    }
+}
+
+struct GuardedStream {
+    inner: Option<ByteStream>,
+    cancelled: Cancelled,
+}
+
+impl Stream for GuardedStream {
+    type Item = Result<Bytes, io::Error>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let inner = match self.inner {
+            Some(ref mut inner) => inner,
+            None => return Poll::Ready(None),
+        };
+        if let Poll::Ready(item) = inner.poll_next_unpin(cx) {
+            return Poll::Ready(item);
+        }
+        match self.cancelled.poll_unpin(cx) {
+            Poll::Ready(()) => {
+                self.inner = None;
+                Poll::Ready(Some(Err(io::Error::new(
+                    io::ErrorKind::Interrupted,
+                    "Request cancelled",
+                ))))
+            }
+            Poll::Pending => Poll::Pending,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn cancels_futures_and_bodies() {
+        let token = CancellationToken::new();
+        assert_eq!(token.run(async { 1 }).await, Some(1));
+
+        let (sender, receiver) = futures::channel::mpsc::unbounded::<Result<Bytes, io::Error>>();
+        sender.unbounded_send(Ok(Bytes::from("chunk"))).unwrap();
+        let mut body = token.guard(ByteStream::new(receiver));
+        assert_eq!(body.next().await.unwrap().unwrap(), "chunk");
+
+        let clone = token.clone();
+        let pending = tokio::spawn(async move { clone.run(future::pending::<()>()).await });
+        token.cancel();
+        assert_eq!(pending.await.unwrap(), None);
+        assert_eq!(token.run(async { 1 }).await, None);
+
+        let err = body.next().await.unwrap().unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::Interrupted);
+        assert!(body.next().await.is_none());
+        assert!(sender.is_closed());
+    }
+}
@@ -1,17 +1,43 @@
-use std::sync::{Arc, Mutex, Weak};
-use std::time::Duration;
+use std::collections::HashMap;
+use std::env;
+use std::fmt;
+use std::sync::atomic::{AtomicI64, Ordering};
+#[cfg(feature = "tokio")]
+use std::sync::Weak;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, SystemTime};
 
+use crate::cancel::CancellationToken;
+#[cfg(feature = "tokio")]
+use crate::credential::DefaultCredentialsProvider;
 use crate::credential::{
-    Anonymous, CredentialsError, DefaultCredentialsProvider, ProvideAwsCredentials, StaticProvider,
+    Anonymous, AwsCredentials, CredentialsError, ProvideAwsCredentials, StaticProvider,
 };
+use crate::dispatch::into_http_request;
 use crate::encoding::ContentEncoding;
-use crate::request::{DispatchSignedRequest, HttpClient, HttpDispatchError, HttpResponse};
-use crate::signature::SignedRequest;
+use crate::endpoint::{
+    self, AccountIdEndpointMode, ConfiguredEndpoints, EndpointResolver, EndpointVariant,
+    S3AddressingStyle, StsRegionalEndpoints,
+};
+use crate::hook::RequestHook;
+use crate::metrics::{RequestMetrics, RequestMetricsObserver};
+use crate::presign::{PresignError, PresignedRequest, Presigner};
+use crate::region::Region;
+#[cfg(feature = "tokio")]
+use crate::request::HttpClient;
+use crate::request::{DispatchSignedRequest, HttpDispatchError, HttpResponse, DEFAULT_USER_AGENT};
+use crate::retry::{self, ErrorClass, RetryPolicy};
+use crate::runtime::{self, DefaultRuntime, Runtime};
+use crate::shutdown::{Drain, ShutdownFuture};
+use crate::signature::{clock, PayloadSigning, SignedRequest};
+use crate::xray::{LambdaTraceId, ProvideTraceId, TRACE_ID_HEADER};
 
 use async_trait::async_trait;
+use instant::Instant;
+#[cfg(feature = "tokio")]
 use lazy_static::lazy_static;
-use tokio::time;
 
+#[cfg(feature = "tokio")]
 lazy_static! {
     static ref SHARED_CLIENT: Mutex<Weak<ClientInner<DefaultCredentialsProvider, HttpClient>>> =
         Mutex::new(Weak::new());
@@ -21,14 +47,68 @@ lazy_static! {
 #[derive(Clone)]
 pub struct Client {
     inner: Arc<dyn SignAndDispatch + Send + Sync>,
+    retry_policy: RetryPolicy,
+    timeout: Option<Duration>,
+    operation_timeout: Option<Duration>,
+    clock_skew: Arc<ClockSkew>,
+    metrics_observer: Option<Arc<dyn RequestMetricsObserver>>,
+    trace_id_provider: Arc<dyn ProvideTraceId>,
+    app_id: Option<String>,
+    user_agent_segments: Vec<String>,
+    runtime: Arc<dyn Runtime>,
+    cancellation_token: Option<CancellationToken>,
+    max_buffered_body_size: Option<usize>,
+    request_hooks: Vec<Arc<dyn RequestHook>>,
+    endpoint_resolver: Option<Arc<dyn EndpointResolver>>,
+    endpoint_variant: EndpointVariant,
+    account_id_endpoint_mode: AccountIdEndpointMode,
+    sts_regional_endpoints: StsRegionalEndpoints,
+    s3_addressing_style: S3AddressingStyle,
+    use_accelerate_endpoint: bool,
+    follow_s3_redirects: bool,
+    /// The regions of the S3 buckets requests were redirected to, by bucket name.
+    bucket_regions: Arc<Mutex<HashMap<String, Region>>>,
+    payload_signing: Option<PayloadSigning>,
 }
 
 impl Client {
+    fn from_inner(inner: Arc<dyn SignAndDispatch + Send + Sync>) -> Self {
+        Client {
+            inner,
+            retry_policy: RetryPolicy::default(),
+            timeout: None,
+            operation_timeout: None,
+            clock_skew: Default::default(),
+            metrics_observer: None,
+            trace_id_provider: Arc::new(LambdaTraceId),
+            app_id: env::var("AWS_SDK_UA_APP_ID")
+                .ok()
+                .filter(|app_id| !app_id.is_empty()),
+            user_agent_segments: Vec::new(),
+            runtime: Arc::new(DefaultRuntime::default()),
+            cancellation_token: None,
+            max_buffered_body_size: None,
+            request_hooks: Vec::new(),
+            endpoint_resolver: Some(ConfiguredEndpoints::from_env())
+                .filter(|endpoints| !endpoints.is_empty())
+                .map(|endpoints| Arc::new(endpoints) as Arc<dyn EndpointResolver>),
+            endpoint_variant: EndpointVariant::from_env(),
+            account_id_endpoint_mode: AccountIdEndpointMode::from_env(),
+            sts_regional_endpoints: StsRegionalEndpoints::from_env(),
+            s3_addressing_style: S3AddressingStyle::default(),
+            use_accelerate_endpoint: false,
+            follow_s3_redirects: true,
+            bucket_regions: Default::default(),
+            payload_signing: None,
+        }
+    }
+
+    #[cfg(feature = "tokio")]
     /// Return the shared default client.
     pub fn shared() -> Self {
         let mut lock = SHARED_CLIENT.lock().unwrap();
-        if let Some(inner) = lock.upgrade() {
-            return Client { inner };
+        if let Some(inner) = lock.upgrade().filter(|inner| !inner.drain.is_closed()) {
+            return Client::from_inner(inner);
         }
         let credentials_provider =
             DefaultCredentialsProvider::new().expect("failed to create credentials provider");
@@ -37,9 +117,11 @@ impl Client {
             credentials_provider: Some(Arc::new(credentials_provider)),
             dispatcher: Arc::new(dispatcher),
             content_encoding: Default::default(),
+            drain: Default::default(),
+            account_ids: Default::default(),
         });
         *lock = Arc::downgrade(&inner);
-        Client { inner }
+        Client::from_inner(inner)
     }
 
     /// Create a client from a credentials provider and request dispatcher.
@@ -52,10 +134,10 @@ impl Client {
             credentials_provider: Some(Arc::new(credentials_provider)),
             dispatcher: Arc::new(dispatcher),
             content_encoding: Default::default(),
+            drain: Default::default(),
+            account_ids: Default::default(),
         };
-        Client {
-            inner: Arc::new(inner),
-        }
+        Client::from_inner(Arc::new(inner))
     }
 
     /// Create a client from a request dispatcher without a credentials provider. The client will
@@ -71,10 +153,10 @@ impl Client {
             credentials_provider: None,
             dispatcher: Arc::new(dispatcher),
             content_encoding: Default::default(),
+            drain: Default::default(),
+            account_ids: Default::default(),
         };
-        Client {
-            inner: Arc::new(inner),
-        }
+        Client::from_inner(Arc::new(inner))
     }
 
     #[cfg(feature = "encoding")]
@@ -92,18 +174,702 @@ impl Client {
             credentials_provider: Some(Arc::new(credentials_provider)),
             dispatcher: Arc::new(dispatcher),
             content_encoding,
+            drain: Default::default(),
+            account_ids: Default::default(),
         };
-        Client {
-            inner: Arc::new(inner),
+        Client::from_inner(Arc::new(inner))
+    }
+
+    /// Set the policy used to retry failed requests.
+    pub fn set_retry_policy(&mut self, retry_policy: RetryPolicy) {
+        self.retry_policy = retry_policy;
+    }
+
+    /// Get the policy used to retry failed requests.
+    pub fn retry_policy(&self) -> &RetryPolicy {
+        &self.retry_policy
+    }
+
+    /// Set the timeout applied to each attempt of a request sent through this client, covering
+    /// fetching credentials and dispatching the request. Overrides the request timeout
+    /// configured on the dispatcher.
+    ///
+    /// `Client` is a cheap handle, so a clone with a different timeout can be used to create a
+    /// service client for a single operation call.
+    pub fn set_timeout(&mut self, timeout: Option<Duration>) {
+        self.timeout = timeout;
+    }
+
+    /// Set the deadline for a whole operation, spanning all attempts and the delays between them.
+    /// Once it has passed no further attempts are made and a `RusotoError::HttpDispatch`
+    /// describing the timeout is returned.
+    pub fn set_operation_timeout(&mut self, operation_timeout: Option<Duration>) {
+        self.operation_timeout = operation_timeout;
+    }
+
+    /// Set an observer receiving metrics about every operation sent through this client.
+    pub fn set_metrics_observer<O>(&mut self, observer: O)
+    where
+        O: RequestMetricsObserver + 'static,
+    {
+        self.metrics_observer = Some(Arc::new(observer));
+    }
+
+    /// Set the runtime used to wait for retry delays and timeouts. Defaults to `TokioRuntime`, to
+    /// `AsyncStdRuntime` with the `async-std` feature but not the `tokio` one, or to `WasmRuntime`
+    /// on wasm32.
+    pub fn set_runtime<R>(&mut self, runtime: R)
+    where
+        R: Runtime + 'static,
+    {
+        self.runtime = Arc::new(runtime);
+    }
+
+    /// Set the maximum size, in bytes, of the response bodies buffered in memory: error responses
+    /// and the outputs of operations that don't stream them. Longer bodies fail the operation
+    /// with `RusotoError::BodyTooLarge`. Unlimited by default.
+    pub fn set_max_buffered_body_size(&mut self, max_size: Option<usize>) {
+        self.max_buffered_body_size = max_size;
+    }
+
+    /// The maximum size of buffered response bodies.
+    pub fn max_buffered_body_size(&self) -> Option<usize> {
+        self.max_buffered_body_size
+    }
+
+    /// Set a token aborting the operations sent through this client, including the reads from
+    /// the bodies of their responses, once it is cancelled.
+    ///
+    /// Dropping an operation future also aborts it; a token additionally reaches response bodies
+    /// and can be shared by several clients, e.g. a clone of the client created for one download.
+    pub fn set_cancellation_token(&mut self, token: Option<CancellationToken>) {
+        self.cancellation_token = token;
+    }
+
+    /// A clone of this client with the credentials provider, timeouts and request hooks of
+    /// `config`, sharing its dispatcher, retry policy and other settings. The region and endpoint
+    /// are applied by service clients, see `OperationConfig`.
+    pub fn customize(&self, config: &OperationConfig) -> Client {
+        let mut client = self.clone();
+        if let Some(ref provider) = config.credentials_provider {
+            client.inner = self.inner.with_credentials(provider.clone());
+        }
+        if config.timeout.is_some() {
+            client.timeout = config.timeout;
+        }
+        if config.operation_timeout.is_some() {
+            client.operation_timeout = config.operation_timeout;
+        }
+        if config.payload_signing.is_some() {
+            client.payload_signing = config.payload_signing;
+        }
+        client
+            .request_hooks
+            .extend(config.request_hooks.iter().cloned());
+        client
+    }
+
+    /// Stop accepting new operations, wait for the ones in flight to complete, and then shut the
+    /// dispatcher down, closing the pooled connections of an `HttpClient`.
+    ///
+    /// This affects all clones of the client, and all service clients created from them. Later
+    /// operations fail with an `HttpDispatchError`.
+    pub fn shutdown(&self) -> ShutdownFuture {
+        let inner = self.inner.clone();
+        Box::pin(async move {
+            inner.drain().close().await;
+            inner.shutdown_dispatcher().await;
+        })
+    }
+
+    /// Set the provider of the `X-Amzn-Trace-Id` header sent with requests that do not carry one
+    /// already. Defaults to `LambdaTraceId`, propagating the trace of the current AWS Lambda
+    /// invocation.
+    pub fn set_trace_id_provider<T>(&mut self, trace_id_provider: T)
+    where
+        T: ProvideTraceId + 'static,
+    {
+        self.trace_id_provider = Arc::new(trace_id_provider);
+    }
+
+    /// Set the identifier of the application using this client, sent as an `app/<app_id>`
+    /// segment of the User-Agent header so that its traffic can be told apart, e.g. in
+    /// CloudTrail. Defaults to the value of the `AWS_SDK_UA_APP_ID` environment variable.
+    pub fn set_app_id(&mut self, app_id: Option<String>) {
+        self.app_id = app_id;
+    }
+
+    /// Set a resolver choosing the endpoint of each request sent through this client, replacing
+    /// the `ConfiguredEndpoints` read from the environment.
+    pub fn set_endpoint_resolver<R>(&mut self, resolver: R)
+    where
+        R: EndpointResolver + 'static,
+    {
+        self.endpoint_resolver = Some(Arc::new(resolver));
+    }
+
+    /// Send requests to the FIPS 140-2 validated endpoints of the services having them, e.g.
+    /// `sqs-fips.us-gov-west-1.amazonaws.com`, as required for GovCloud and FedRAMP workloads.
+    /// Endpoints of `Region::Custom` and those chosen by an `EndpointResolver` are kept.
+    ///
+    /// Defaults to the value of the `AWS_USE_FIPS_ENDPOINT` environment variable, or otherwise
+    /// the `use_fips_endpoint` key of the current profile.
+    pub fn set_use_fips_endpoint(&mut self, use_fips_endpoint: bool) {
+        self.endpoint_variant.fips = use_fips_endpoint;
+    }
+
+    /// Send requests to the dual-stack endpoints of the services, reachable over both IPv4 and
+    /// IPv6, e.g. `s3.dualstack.us-east-1.amazonaws.com` or `ec2.us-east-1.api.aws`, as required
+    /// from IPv6-only subnets. Endpoints of `Region::Custom` and those chosen by an
+    /// `EndpointResolver` are kept.
+    ///
+    /// Defaults to the value of the `AWS_USE_DUALSTACK_ENDPOINT` environment variable, or
+    /// otherwise the `use_dualstack_endpoint` key of the current profile.
+    pub fn set_use_dualstack_endpoint(&mut self, use_dualstack_endpoint: bool) {
+        self.endpoint_variant.dual_stack = use_dualstack_endpoint;
+    }
+
+    /// Choose whether requests are sent to the account-ID-based endpoints of the services having
+    /// them, e.g. `111122223333.ddb.us-east-1.api.aws` for DynamoDB. Endpoints of
+    /// `Region::Custom`, those chosen by an `EndpointResolver` and FIPS or dual-stack endpoints
+    /// are kept.
+    ///
+    /// Defaults to the value of the `AWS_ACCOUNT_ID_ENDPOINT_MODE` environment variable, or
+    /// otherwise the `account_id_endpoint_mode` key of the current profile, and to
+    /// `AccountIdEndpointMode::Preferred` if neither is set.
+    pub fn set_account_id_endpoint_mode(&mut self, mode: AccountIdEndpointMode) {
+        self.account_id_endpoint_mode = mode;
+    }
+
+    /// Choose whether STS requests are sent to the endpoint of their region, e.g.
+    /// `sts.eu-west-1.amazonaws.com`, or to the global endpoint `sts.amazonaws.com`. Endpoints of
+    /// `Region::Custom`, those chosen by an `EndpointResolver` and FIPS or dual-stack endpoints
+    /// are kept.
+    ///
+    /// Defaults to the value of the `AWS_STS_REGIONAL_ENDPOINTS` environment variable, or
+    /// otherwise the `sts_regional_endpoints` key of the current profile, and to
+    /// `StsRegionalEndpoints::Regional` if neither is set. The STS clients of the credential
+    /// providers assuming roles are created from the same settings.
+    pub fn set_sts_regional_endpoints(&mut self, setting: StsRegionalEndpoints) {
+        self.sts_regional_endpoints = setting;
+    }
+
+    /// Choose whether S3 requests name their bucket in the path of the URL, as by default, or in
+    /// its hostname, see `S3AddressingStyle`. The style applies to the endpoints of
+    /// `Region::Custom` and those chosen by an `EndpointResolver` too.
+    pub fn set_s3_addressing_style(&mut self, style: S3AddressingStyle) {
+        self.s3_addressing_style = style;
+    }
+
+    /// Send S3 requests through the S3 Transfer Acceleration endpoints of their buckets, e.g.
+    /// `<bucket>.s3-accelerate.amazonaws.com`, or their dual-stack variants with
+    /// `set_use_dualstack_endpoint`, as the AWS CLI does. The buckets must have acceleration
+    /// enabled. `CreateBucket`, `DeleteBucket` and `ListBuckets`, requests to buckets with dots in
+    /// their names, to `Region::Custom` and to endpoints chosen by an `EndpointResolver` keep
+    /// their regular endpoints, and FIPS endpoints, which have no accelerated variants, win.
+    pub fn set_use_accelerate_endpoint(&mut self, use_accelerate_endpoint: bool) {
+        self.use_accelerate_endpoint = use_accelerate_endpoint;
+    }
+
+    /// Choose whether S3 requests for buckets in another region than the one of the request are
+    /// sent again to the region S3 redirects them to with the `x-amz-bucket-region` header of
+    /// its `301 Moved Permanently`, `307 Temporary Redirect` or `400 Bad Request` response, as
+    /// by default. The regions of the buckets are remembered by the client and its clones, so
+    /// later requests for them go to their regions at once. Requests with streamed payloads
+    /// can't be sent again, their redirect responses are returned.
+    pub fn set_follow_s3_redirects(&mut self, follow_s3_redirects: bool) {
+        self.follow_s3_redirects = follow_s3_redirects;
+    }
+
+    /// Choose how the payloads of S3 requests are signed, e.g. `PayloadSigning::Unsigned` to
+    /// skip hashing multi-gigabyte bodies. Request hooks can still choose otherwise for single
+    /// requests.
+    ///
+    /// Defaults to `PayloadSigning::Signed`.
+    pub fn set_payload_signing(&mut self, payload_signing: PayloadSigning) {
+        self.payload_signing = Some(payload_signing);
+    }
+
+    /// Add a hook changing the requests sent through this client before they are signed.
+    pub fn add_request_hook<H>(&mut self, hook: H)
+    where
+        H: RequestHook + 'static,
+    {
+        self.request_hooks.push(Arc::new(hook));
+    }
+
+    /// Append a segment, e.g. `framework/1.2`, to the User-Agent header sent with requests.
+    pub fn add_user_agent_segment(&mut self, segment: String) {
+        self.user_agent_segments.push(segment);
+    }
+
+    /// The User-Agent header sent with requests, or `None` if it is left to the dispatcher.
+    fn user_agent(&self) -> Option<String> {
+        if self.app_id.is_none() && self.user_agent_segments.is_empty() {
+            return None;
+        }
+        let mut user_agent = DEFAULT_USER_AGENT.clone();
+        if let Some(ref app_id) = self.app_id {
+            user_agent.push_str(" app/");
+            user_agent.push_str(&app_id.replace(char::is_whitespace, "_"));
+        }
+        for segment in &self.user_agent_segments {
+            user_agent.push(' ');
+            user_agent.push_str(segment);
         }
+        Some(user_agent)
     }
 
     /// Fetch credentials, sign the request and dispatch it.
+    ///
+    /// Failed attempts are retried according to the client's `RetryPolicy`, re-signing the
+    /// request every time. Throttled responses carrying a `Retry-After` header are retried after
+    /// the delay requested by the service instead of the policy's back-off.
     pub async fn sign_and_dispatch(
+        &self,
+        mut request: SignedRequest,
+    ) -> Result<HttpResponse, SignAndDispatchError> {
+        let bucket = match endpoint::redirectable_bucket(&request) {
+            Some(bucket) if self.follow_s3_redirects => bucket.to_owned(),
+            _ => return self.sign_and_dispatch_once(request).await,
+        };
+        let known_region = self.bucket_regions.lock().unwrap().get(&bucket).cloned();
+        if let Some(region) = known_region {
+            request.region = region;
+        }
+        let redirected_request = request.try_clone();
+        let response = self.sign_and_dispatch_once(request).await?;
+        let mut redirected_request = match redirected_request {
+            Some(redirected_request) => redirected_request,
+            None => return Ok(response),
+        };
+        match endpoint::redirected_bucket_region(&redirected_request.region, &response) {
+            Some(region) => {
+                self.bucket_regions
+                    .lock()
+                    .unwrap()
+                    .insert(bucket, region.clone());
+                redirected_request.region = region;
+                self.sign_and_dispatch_once(redirected_request).await
+            }
+            None => Ok(response),
+        }
+    }
+
+    /// Presigns `request` with `presigner` instead of sending it, after resolving its endpoint
+    /// and running the request hooks as `sign_and_dispatch` would.
+    pub async fn presign(
+        &self,
+        presigner: &Presigner,
+        mut request: SignedRequest,
+    ) -> Result<PresignedRequest, PresignError> {
+        self.prepare(&mut request);
+        presigner.presign(request).await
+    }
+
+    /// Resolves the endpoint of `request` and runs the request hooks.
+    fn prepare(&self, request: &mut SignedRequest) {
+        if let Some(ref resolver) = self.endpoint_resolver {
+            endpoint::resolve(&**resolver, request);
+        }
+        let resolved = endpoint::resolve_access_point(request, self.endpoint_variant)
+            || (self.use_accelerate_endpoint
+                && !self.endpoint_variant.fips
+                && endpoint::accelerate(request, self.endpoint_variant.dual_stack));
+        if !resolved {
+            if self.endpoint_variant == EndpointVariant::default() {
+                // The global STS endpoint has no FIPS or dual-stack variants.
+                self.sts_regional_endpoints.apply(request);
+            } else {
+                self.endpoint_variant.apply(request);
+            }
+            // Accelerated requests and requests to access points name their bucket in the
+            // hostname already.
+            self.s3_addressing_style.apply(request);
+        }
+        if let Some(payload_signing) = self.payload_signing {
+            if request.service == "s3" {
+                request.set_payload_signing(payload_signing);
+            }
+        }
+        for hook in &self.request_hooks {
+            hook.prepare(request);
+        }
+    }
+
+    async fn sign_and_dispatch_once(
+        &self,
+        mut request: SignedRequest,
+    ) -> Result<HttpResponse, SignAndDispatchError> {
+        let _in_flight = self.inner.drain().start().ok_or_else(|| {
+            SignAndDispatchError::Dispatch(HttpDispatchError::new(
+                "Client has been shut down".to_owned(),
+            ))
+        })?;
+        self.prepare(&mut request);
+        if !request.headers.contains_key(TRACE_ID_HEADER) {
+            if let Some(trace_id) = self.trace_id_provider.trace_id() {
+                request.add_header(TRACE_ID_HEADER, &trace_id);
+            }
+        }
+        if !request.headers.contains_key("user-agent") {
+            if let Some(user_agent) = self.user_agent() {
+                request.add_header("user-agent", &user_agent);
+            }
+        }
+        #[cfg(feature = "tracing")]
+        let span = trace::request_span(&request);
+        let mut metrics = self.metrics_observer.as_ref().map(|_| RequestMetrics {
+            service: request.service.clone(),
+            operation: request.operation.clone(),
+            region: request.region.clone(),
+            latency: Duration::from_secs(0),
+            attempts: 0,
+            request_size: request
+                .payload
+                .as_ref()
+                .and_then(|payload| payload.size_hint())
+                .map(|size| size as u64),
+            response_size: None,
+            status: None,
+        });
+        let start = Instant::now();
+        let mut attempts = 0;
+        let future = self.sign_and_dispatch_with_retries(request, &mut attempts);
+        #[cfg(feature = "tracing")]
+        let future = tracing::Instrument::instrument(future, span);
+        let result = match self.cancellation_token {
+            Some(ref token) => match token.run(future).await {
+                Some(Ok(mut response)) => {
+                    response.body = token.guard(response.body);
+                    Ok(response)
+                }
+                Some(result) => result,
+                None => Err(SignAndDispatchError::Dispatch(
+                    HttpDispatchError::cancelled(),
+                )),
+            },
+            None => future.await,
+        };
+        if let (Some(observer), Some(metrics)) = (&self.metrics_observer, metrics.as_mut()) {
+            metrics.latency = start.elapsed();
+            metrics.attempts = attempts;
+            if let Ok(ref response) = result {
+                metrics.status = Some(response.status);
+                metrics.response_size = response
+                    .headers
+                    .get(http::header::CONTENT_LENGTH)
+                    .and_then(|value| value.parse().ok());
+            }
+            observer.on_request_complete(metrics);
+        }
+        result
+    }
+
+    async fn sign_and_dispatch_with_retries(
         &self,
         request: SignedRequest,
+        attempts: &mut u32,
     ) -> Result<HttpResponse, SignAndDispatchError> {
-        self.inner.sign_and_dispatch(request, None).await
+        let deadline = self
+            .operation_timeout
+            .map(|timeout| Instant::now() + timeout);
+        let mut request = request;
+        let mut attempt = 1;
+        // The response of the last attempt, returned rather than a timeout when the deadline
+        // passes while waiting to retry it
+        let mut last_result = None;
+        loop {
+            let retry_request = if attempt < self.retry_policy.max_attempts() {
+                request.try_clone()
+            } else {
+                None
+            };
+            let send_delay = self.retry_policy.acquire_send_token();
+            if send_delay > Duration::from_secs(0) {
+                self.runtime.sleep(send_delay).await;
+            }
+            let timeout = match deadline {
+                Some(deadline) => {
+                    let remaining = match remaining(deadline) {
+                        Some(remaining) => remaining,
+                        None => {
+                            return last_result.unwrap_or_else(|| Err(self.deadline_exceeded()))
+                        }
+                    };
+                    Some(
+                        self.timeout
+                            .map_or(remaining, |timeout| timeout.min(remaining)),
+                    )
+                }
+                None => self.timeout,
+            };
+            *attempts = attempt;
+            #[cfg(feature = "tracing")]
+            trace::record_attempt(attempt);
+            let signing_time = self.clock_skew.now();
+            let mut retry_after = None;
+            let (result, class) = match self
+                .inner
+                .sign_and_dispatch(
+                    request,
+                    timeout,
+                    signing_time,
+                    self.runtime.clone(),
+                    self.account_id_endpoint_mode,
+                )
+                .await
+            {
+                Ok(response) if response.status.is_success() => (Ok(response), None),
+                Ok(mut response) => {
+                    let buffered = response
+                        .buffer_with_limit(self.max_buffered_body_size)
+                        .await
+                        .map_err(SignAndDispatchError::Dispatch)?;
+                    let mut class = ErrorClass::classify(&buffered);
+                    retry_after = retry::retry_after(&buffered);
+                    if let Some(server_time) = retry::skewed_server_time(&buffered, signing_time) {
+                        self.clock_skew.adjust(server_time);
+                        class = Some(ErrorClass::ClockSkew);
+                        retry_after = Some(Duration::from_secs(0));
+                    }
+                    (Ok(HttpResponse::from(buffered)), class)
+                }
+                Err(SignAndDispatchError::Dispatch(err)) => (
+                    Err(SignAndDispatchError::Dispatch(err)),
+                    Some(ErrorClass::Dispatch),
+                ),
+                Err(err) => return Err(err),
+            };
+            #[cfg(feature = "tracing")]
+            trace::record_result(&result);
+            self.retry_policy.record_attempt(class);
+            let retry_request = match (retry_request, class) {
+                (Some(retry_request), Some(class))
+                    if self.retry_policy.should_retry(attempt, class) =>
+                {
+                    retry_request
+                }
+                _ => return result,
+            };
+            let delay = self.retry_policy.retry_delay(attempt, retry_after);
+            if let Some(deadline) = deadline {
+                match remaining(deadline) {
+                    Some(remaining) if remaining > delay => {}
+                    _ => return result,
+                }
+            }
+            self.runtime.sleep(delay).await;
+            last_result = Some(result);
+            request = retry_request;
+            attempt += 1;
+        }
+    }
+
+    fn deadline_exceeded(&self) -> SignAndDispatchError {
+        SignAndDispatchError::Dispatch(HttpDispatchError::new(format!(
+            "Timeout: operation deadline of {:?} exceeded",
+            self.operation_timeout.unwrap_or_default()
+        )))
+    }
+}
+
+/// Overrides for the operations of a single call, or a batch of them, e.g. fanning out across
+/// accounts and regions without creating a client for each of them.
+///
+/// Service clients apply it with `customize`, returning a cheap clone that shares the
+/// connections of the original client:
+///
+/// ```rust,ignore
+/// let mut config = OperationConfig::new();
+/// config.set_region(Region::EuWest1);
+/// config.set_credentials(assumed_role_provider);
+/// let tables = client.customize(&config).list_tables(Default::default()).await?;
+/// ```
+#[derive(Clone, Default)]
+pub struct OperationConfig {
+    region: Option<Region>,
+    endpoint: Option<String>,
+    credentials_provider: Option<Arc<dyn ProvideAwsCredentials + Send + Sync>>,
+    timeout: Option<Duration>,
+    operation_timeout: Option<Duration>,
+    payload_signing: Option<PayloadSigning>,
+    request_hooks: Vec<Arc<dyn RequestHook>>,
+}
+
+impl OperationConfig {
+    /// Create a config overriding nothing.
+    pub fn new() -> OperationConfig {
+        OperationConfig::default()
+    }
+
+    /// Send the operations to `region`.
+    pub fn set_region(&mut self, region: Region) {
+        self.region = Some(region);
+    }
+
+    /// Send the operations to `endpoint`, keeping the name of the region for signing.
+    pub fn set_endpoint(&mut self, endpoint: String) {
+        self.endpoint = Some(endpoint);
+    }
+
+    /// Sign the operations with the credentials of `provider`.
+    pub fn set_credentials<P>(&mut self, provider: P)
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+    {
+        self.credentials_provider = Some(Arc::new(provider));
+    }
+
+    /// Override the timeout of each attempt, see `Client::set_timeout`.
+    pub fn set_timeout(&mut self, timeout: Duration) {
+        self.timeout = Some(timeout);
+    }
+
+    /// Override the deadline of whole operations, see `Client::set_operation_timeout`.
+    pub fn set_operation_timeout(&mut self, operation_timeout: Duration) {
+        self.operation_timeout = Some(operation_timeout);
+    }
+
+    /// Override how the payloads of S3 requests are signed, see `Client::set_payload_signing`.
+    pub fn set_payload_signing(&mut self, payload_signing: PayloadSigning) {
+        self.payload_signing = Some(payload_signing);
+    }
+
+    /// Add a hook changing the requests of the operations, run after the hooks of the client.
+    pub fn add_request_hook<H>(&mut self, hook: H)
+    where
+        H: RequestHook + 'static,
+    {
+        self.request_hooks.push(Arc::new(hook));
+    }
+
+    /// The region operations are sent to, given the region of the service client.
+    pub fn resolve_region(&self, region: &Region) -> Region {
+        let region = self.region.as_ref().unwrap_or(region);
+        match self.endpoint {
+            Some(ref endpoint) => Region::Custom {
+                name: region.name().to_owned(),
+                endpoint: endpoint.clone(),
+            },
+            None => region.clone(),
+        }
+    }
+}
+
+impl fmt::Debug for OperationConfig {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("OperationConfig")
+            .field("region", &self.region)
+            .field("endpoint", &self.endpoint)
+            .field("credentials_provider", &self.credentials_provider.is_some())
+            .field("timeout", &self.timeout)
+            .field("operation_timeout", &self.operation_timeout)
+            .field("payload_signing", &self.payload_signing)
+            .field("request_hooks", &self.request_hooks.len())
+            .finish()
+    }
+}
+
+/// The offset between the local clock and the clock of the services a client talks to, learned
+/// from requests rejected because of clock skew.
+#[derive(Default)]
+struct ClockSkew {
+    offset_millis: AtomicI64,
+}
+
+impl ClockSkew {
+    /// The current time according to the services' clock.
+    fn now(&self) -> SystemTime {
+        let offset = self.offset_millis.load(Ordering::Relaxed);
+        let now = clock::now();
+        if offset >= 0 {
+            now + Duration::from_millis(offset as u64)
+        } else {
+            now - Duration::from_millis((-offset) as u64)
+        }
+    }
+
+    fn adjust(&self, server_time: SystemTime) {
+        let now = clock::now();
+        let offset = match server_time.duration_since(now) {
+            Ok(ahead) => ahead.as_millis() as i64,
+            Err(behind) => -(behind.duration().as_millis() as i64),
+        };
+        self.offset_millis.store(offset, Ordering::Relaxed);
+    }
+}
+
+/// Spans covering every operation, enabled by the `tracing` feature.
+#[cfg(feature = "tracing")]
+mod trace {
+    use tracing::field::Empty;
+    use tracing::Span;
+
+    use super::SignAndDispatchError;
+    use crate::request::HttpResponse;
+    use crate::signature::SignedRequest;
+
+    /// The span of an operation. `attempt`, `status` and `request_id` describe the latest attempt.
+    pub(super) fn request_span(request: &SignedRequest) -> Span {
+        tracing::info_span!(
+            "rusoto_request",
+            service = request.service.as_str(),
+            operation = request.operation().unwrap_or_default(),
+            region = request.region.name(),
+            attempt = Empty,
+            status = Empty,
+            request_id = Empty,
+        )
+    }
+
+    pub(super) fn record_attempt(attempt: u32) {
+        Span::current().record("attempt", attempt);
+    }
+
+    pub(super) fn record_result(result: &Result<HttpResponse, SignAndDispatchError>) {
+        let span = Span::current();
+        match result {
+            Ok(response) => {
+                span.record("status", response.status.as_u16());
+                if let Some(request_id) = response.request_id() {
+                    span.record("request_id", request_id);
+                }
+                tracing::debug!(status = response.status.as_u16(), "attempt finished");
+            }
+            Err(err) => tracing::debug!(error = ?err, "attempt failed"),
+        }
+    }
+}
+
+/// Time left until `deadline`, or `None` if it has passed.
+/// Signs `request` with `credentials`, with SigV4A for all regions for S3 Multi-Region Access
+/// Points, which requires the `sigv4a` feature.
+fn sign(
+    request: &mut SignedRequest,
+    credentials: &AwsCredentials,
+    signing_time: SystemTime,
+) -> Result<(), CredentialsError> {
+    if endpoint::is_multi_region_access_point(request) {
+        #[cfg(feature = "sigv4a")]
+        request.sign_v4a_with_plus_at(credentials, "*", true, signing_time);
+        #[cfg(not(feature = "sigv4a"))]
+        return Err(CredentialsError::new(
+            "Requests to S3 Multi-Region Access Points are signed with SigV4A, which requires the \
+             sigv4a feature",
+        ));
+    } else {
+        request.sign_with_plus_at(credentials, true, signing_time);
+    }
+    Ok(())
+}
+
+fn remaining(deadline: Instant) -> Option<Duration> {
+    let now = Instant::now();
+    if now < deadline {
+        Some(deadline - now)
+    } else {
+        None
     }
 }
 
@@ -122,21 +888,41 @@ trait SignAndDispatch {
         &self,
         request: SignedRequest,
         timeout: Option<Duration>,
+        signing_time: SystemTime,
+        runtime: Arc<dyn Runtime>,
+        account_id_endpoint_mode: AccountIdEndpointMode,
     ) -> Result<HttpResponse, SignAndDispatchError>;
+
+    /// The operations in flight.
+    fn drain(&self) -> &Arc<Drain>;
+
+    /// Shut the dispatcher down.
+    fn shutdown_dispatcher(&self) -> ShutdownFuture;
+
+    /// The same dispatcher, signing requests with the credentials of `provider`.
+    fn with_credentials(
+        &self,
+        provider: Arc<dyn ProvideAwsCredentials + Send + Sync>,
+    ) -> Arc<dyn SignAndDispatch + Send + Sync>;
 }
 
-struct ClientInner<P, D> {
+struct ClientInner<P: ?Sized, D> {
     credentials_provider: Option<Arc<P>>,
     dispatcher: Arc<D>,
     content_encoding: ContentEncoding,
+    drain: Arc<Drain>,
+    /// The account IDs returned by `sts:GetCallerIdentity`, by access key ID.
+    account_ids: Arc<Mutex<HashMap<String, String>>>,
 }
 
-impl<P, D> Clone for ClientInner<P, D> {
+impl<P: ?Sized, D> Clone for ClientInner<P, D> {
     fn clone(&self) -> Self {
         ClientInner {
             credentials_provider: self.credentials_provider.clone(),
             dispatcher: self.dispatcher.clone(),
             content_encoding: self.content_encoding.clone(),
+            drain: self.drain.clone(),
+            account_ids: self.account_ids.clone(),
         }
     }
 }
@@ -145,14 +931,17 @@ async fn sign_and_dispatch<P, D>(
     client: ClientInner<P, D>,
     mut request: SignedRequest,
     timeout: Option<Duration>,
+    signing_time: SystemTime,
+    runtime: Arc<dyn Runtime>,
+    account_id_endpoint_mode: AccountIdEndpointMode,
 ) -> Result<HttpResponse, SignAndDispatchError>
 where
-    P: ProvideAwsCredentials + Send + Sync + 'static,
+    P: ProvideAwsCredentials + Send + Sync + ?Sized + 'static,
     D: DispatchSignedRequest + Send + Sync + 'static,
 {
-    if let Some(provider) = client.credentials_provider {
+    if let Some(ref provider) = client.credentials_provider {
         let credentials = if let Some(to) = timeout {
-            time::timeout(to, provider.credentials())
+            runtime::timeout(&*runtime, to, provider.credentials())
                 .await
                 .map_err(|_| CredentialsError {
                     message: "Timeout getting credentials".to_owned(),
@@ -165,36 +954,960 @@ where
         if credentials.is_anonymous() {
             request.complement_with_plus(true);
         } else {
-            request.sign_with_plus(&credentials, true);
+            if account_id_endpoint_mode != AccountIdEndpointMode::Disabled
+                && endpoint::has_account_id_endpoint(&request)
+            {
+                let account_id = match credentials.account_id() {
+                    Some(account_id) => Some(account_id.to_owned()),
+                    None if account_id_endpoint_mode == AccountIdEndpointMode::Required => {
+                        let region = request.region.clone();
+                        let account_id = caller_account_id(
+                            &client,
+                            &credentials,
+                            &region,
+                            timeout,
+                            signing_time,
+                        )
+                        .await
+                        .map_err(|err| {
+                            SignAndDispatchError::Credentials(CredentialsError::new(format!(
+                                "Account ID required for the endpoint is unknown: {}",
+                                err
+                            )))
+                        })?;
+                        Some(account_id)
+                    }
+                    None => None,
+                };
+                if let Some(account_id) = account_id {
+                    endpoint::use_account_id_endpoint(&mut request, &account_id)
+                }
+            }
+            sign(&mut request, &credentials, signing_time)
+                .map_err(SignAndDispatchError::Credentials)?;
         }
     } else {
         request.complement_with_plus(true);
     }
-    client
-        .dispatcher
-        .dispatch(request, timeout)
+    dispatch(&client.dispatcher, request, timeout)
         .await
         .map_err(SignAndDispatchError::Dispatch)
 }
 
+/// Send `request` through `dispatcher` as an HTTP request.
+async fn dispatch<D>(
+    dispatcher: &D,
+    request: SignedRequest,
+    timeout: Option<Duration>,
+) -> Result<HttpResponse, HttpDispatchError>
+where
+    D: DispatchSignedRequest + ?Sized,
+{
+    let request = into_http_request(request)?;
+    dispatcher
+        .dispatch(request, timeout)
+        .await
+        .map(HttpResponse::from)
+}
+
+/// The account ID of `credentials` from `sts:GetCallerIdentity`, remembered by access key ID.
+async fn caller_account_id<P, D>(
+    client: &ClientInner<P, D>,
+    credentials: &AwsCredentials,
+    region: &Region,
+    timeout: Option<Duration>,
+    signing_time: SystemTime,
+) -> Result<String, String>
+where
+    P: ?Sized,
+    D: DispatchSignedRequest + Send + Sync + 'static,
+{
+    let key = credentials.aws_access_key_id().to_owned();
+    if let Some(account_id) = client.account_ids.lock().unwrap().get(&key) {
+        return Ok(account_id.clone());
+    }
+    let mut request = SignedRequest::new("POST", "sts", region, "/");
+    request.set_content_type("application/x-www-form-urlencoded".to_owned());
+    request.set_payload(Some(&b"Action=GetCallerIdentity&Version=2011-06-15"[..]));
+    request.sign_with_plus_at(credentials, true, signing_time);
+    let failed = |err: &dyn fmt::Display| format!("sts:GetCallerIdentity failed: {}", err);
+    let mut response = dispatch(&client.dispatcher, request, timeout)
+        .await
+        .map_err(|err| failed(&err))?;
+    let response = response.buffer().await.map_err(|err| failed(&err))?;
+    if !response.status.is_success() {
+        return Err(failed(&format_args!(
+            "{}: {}",
+            response.status,
+            String::from_utf8_lossy(&response.body)
+        )));
+    }
+    let account_id = endpoint::account_id_of_caller_identity(&response.body)
+        .ok_or_else(|| failed(&"no account ID in the response"))?;
+    client
+        .account_ids
+        .lock()
+        .unwrap()
+        .insert(key, account_id.clone());
+    Ok(account_id)
+}
+
 #[async_trait]
 impl<P, D> SignAndDispatch for ClientInner<P, D>
 where
-    P: ProvideAwsCredentials + Send + Sync + 'static,
+    P: ProvideAwsCredentials + Send + Sync + ?Sized + 'static,
     D: DispatchSignedRequest + Send + Sync + 'static,
 {
     async fn sign_and_dispatch(
         &self,
         request: SignedRequest,
         timeout: Option<Duration>,
+        signing_time: SystemTime,
+        runtime: Arc<dyn Runtime>,
+        account_id_endpoint_mode: AccountIdEndpointMode,
     ) -> Result<HttpResponse, SignAndDispatchError> {
-        sign_and_dispatch(self.clone(), request, timeout).await
+        sign_and_dispatch(
+            self.clone(),
+            request,
+            timeout,
+            signing_time,
+            runtime,
+            account_id_endpoint_mode,
+        )
+        .await
+    }
+
+    fn drain(&self) -> &Arc<Drain> {
+        &self.drain
+    }
+
+    fn shutdown_dispatcher(&self) -> ShutdownFuture {
+        self.dispatcher.shutdown()
+    }
+
+    fn with_credentials(
+        &self,
+        provider: Arc<dyn ProvideAwsCredentials + Send + Sync>,
+    ) -> Arc<dyn SignAndDispatch + Send + Sync> {
+        Arc::new(ClientInner {
+            credentials_provider: Some(provider),
+            dispatcher: self.dispatcher.clone(),
+            content_encoding: self.content_encoding.clone(),
+            drain: self.drain.clone(),
+            account_ids: self.account_ids.clone(),
+        })
     }
 }
 
-#[test]
-fn client_is_send_and_sync() {
-    fn is_send_and_sync<T: Send + Sync>() {}
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::request::{DispatchSignedRequestFuture, ResponseMetadata};
+    use crate::{ByteStream, Region};
+    use futures::FutureExt;
+    use http::Request;
+    use http::{HeaderMap, StatusCode};
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    /// The signed request sent as `request`.
+    fn signed(request: &Request<ByteStream>) -> &SignedRequest {
+        request.extensions().get().unwrap()
+    }
+
+    #[test]
+    fn client_is_send_and_sync() {
+        fn is_send_and_sync<T: Send + Sync>() {}
+
+        is_send_and_sync::<Client>();
+    }
+
+    struct StatusDispatcher {
+        statuses: Vec<u16>,
+        calls: Arc<AtomicUsize>,
+    }
+
+    impl DispatchSignedRequest for StatusDispatcher {
+        fn dispatch(
+            &self,
+            _request: Request<ByteStream>,
+            _timeout: Option<Duration>,
+        ) -> DispatchSignedRequestFuture {
+            let call = self.calls.fetch_add(1, Ordering::SeqCst);
+            let status = self.statuses[call.min(self.statuses.len() - 1)];
+            futures::future::ready(Ok(HttpResponse {
+                status: StatusCode::from_u16(status).unwrap(),
+                body: Vec::new().into(),
+                headers: HeaderMap::default(),
+            }
+            .into()))
+            .boxed()
+        }
+    }
+
+    fn test_client(statuses: Vec<u16>, retry_policy: RetryPolicy) -> (Client, Arc<AtomicUsize>) {
+        let calls = Arc::new(AtomicUsize::new(0));
+        let dispatcher = StatusDispatcher {
+            statuses,
+            calls: calls.clone(),
+        };
+        let mut client = Client::new_not_signing(dispatcher);
+        client.set_retry_policy(retry_policy);
+        (client, calls)
+    }
+
+    fn fast_retry_policy() -> RetryPolicy {
+        let mut retry_policy = RetryPolicy::new();
+        retry_policy.set_base_delay(Duration::from_millis(1));
+        retry_policy
+    }
+
+    fn request() -> SignedRequest {
+        SignedRequest::new("GET", "sqs", &Region::UsEast1, "/")
+    }
+
+    #[tokio::test]
+    async fn retries_transient_errors() {
+        let (client, calls) = test_client(vec![503, 500, 200], fast_retry_policy());
+        let response = client.sign_and_dispatch(request()).await.unwrap();
+        assert_eq!(response.status, StatusCode::OK);
+        assert_eq!(calls.load(Ordering::SeqCst), 3);
+    }
+
+    #[tokio::test]
+    async fn gives_up_after_max_attempts() {
+        let (client, calls) = test_client(vec![503], fast_retry_policy());
+        let response = client.sign_and_dispatch(request()).await.unwrap();
+        assert_eq!(response.status, StatusCode::SERVICE_UNAVAILABLE);
+        assert_eq!(calls.load(Ordering::SeqCst), 3);
+    }
+
+    struct BodyDispatcher {
+        bodies: Arc<Mutex<Vec<Vec<u8>>>>,
+    }
+
+    impl DispatchSignedRequest for BodyDispatcher {
+        fn dispatch(
+            &self,
+            request: Request<ByteStream>,
+            _timeout: Option<Duration>,
+        ) -> DispatchSignedRequestFuture {
+            let bodies = self.bodies.clone();
+            async move {
+                let body = request.into_body().into_bytes().await?;
+                let mut bodies = bodies.lock().unwrap();
+                bodies.push(body.to_vec());
+                let status = if bodies.len() == 1 { 503 } else { 200 };
+                Ok(HttpResponse {
+                    status: StatusCode::from_u16(status).unwrap(),
+                    body: Vec::new().into(),
+                    headers: HeaderMap::default(),
+                }
+                .into())
+            }
+            .boxed()
+        }
+    }
+
+    #[tokio::test]
+    async fn retries_replayable_streams_only() {
+        let bodies = Arc::new(Mutex::new(Vec::new()));
+        let mut client = Client::new_not_signing(BodyDispatcher {
+            bodies: bodies.clone(),
+        });
+        client.set_retry_policy(fast_retry_policy());
+
+        let mut replayable = SignedRequest::new("PUT", "s3", &Region::UsEast1, "/bucket/key");
+        replayable.set_payload_stream(ByteStream::from(b"payload".to_vec()));
+        let response = client.sign_and_dispatch(replayable).await.unwrap();
+        assert_eq!(response.status, StatusCode::OK);
+        assert_eq!(*bodies.lock().unwrap(), vec![b"payload".to_vec(); 2]);
+
+        bodies.lock().unwrap().clear();
+        let mut once = SignedRequest::new("PUT", "s3", &Region::UsEast1, "/bucket/key");
+        let stream = futures::stream::once(async { Ok(bytes::Bytes::from_static(b"payload")) });
+        once.set_payload_stream(ByteStream::new(stream));
+        let response = client.sign_and_dispatch(once).await.unwrap();
+        assert_eq!(response.status, StatusCode::SERVICE_UNAVAILABLE);
+        assert_eq!(bodies.lock().unwrap().len(), 1);
+    }
+
+    struct RecordingRuntime {
+        sleeps: Arc<Mutex<Vec<Duration>>>,
+    }
+
+    impl Runtime for RecordingRuntime {
+        fn sleep(&self, duration: Duration) -> runtime::SleepFuture {
+            self.sleeps.lock().unwrap().push(duration);
+            Box::pin(futures::future::ready(()))
+        }
+    }
+
+    #[test]
+    fn waits_on_the_configured_runtime() {
+        let mut retry_policy = RetryPolicy::new();
+        retry_policy.set_base_delay(Duration::from_secs(60));
+        let (mut client, calls) = test_client(vec![503, 503, 200], retry_policy);
+        let sleeps = Arc::new(Mutex::new(Vec::new()));
+        client.set_runtime(RecordingRuntime {
+            sleeps: sleeps.clone(),
+        });
+
+        let response = futures::executor::block_on(client.sign_and_dispatch(request())).unwrap();
+        assert_eq!(response.status, StatusCode::OK);
+        assert_eq!(calls.load(Ordering::SeqCst), 3);
+        assert_eq!(sleeps.lock().unwrap().len(), 2);
+    }
+
+    /// Records the sleeps of a client, taking `stall` to return from each of them.
+    struct StallingRuntime {
+        sleeps: Arc<Mutex<Vec<Duration>>>,
+        stall: Duration,
+    }
 
-    is_send_and_sync::<Client>();
+    impl Runtime for StallingRuntime {
+        fn sleep(&self, duration: Duration) -> runtime::SleepFuture {
+            self.sleeps.lock().unwrap().push(duration);
+            std::thread::sleep(self.stall);
+            Box::pin(futures::future::ready(()))
+        }
+    }
+
+    #[test]
+    fn operation_timeout_bounds_retries() {
+        let mut retry_policy = RetryPolicy::new();
+        retry_policy.set_max_attempts(10);
+        retry_policy.set_base_delay(Duration::from_millis(40));
+        let (mut client, calls) = test_client(vec![500], retry_policy);
+        client.set_operation_timeout(Some(Duration::from_millis(100)));
+        let sleeps = Arc::new(Mutex::new(Vec::new()));
+        client.set_runtime(StallingRuntime {
+            sleeps: sleeps.clone(),
+            stall: Duration::from_millis(150),
+        });
+
+        // The deadline passes while waiting to retry, returning the response of the first attempt
+        let response = futures::executor::block_on(client.sign_and_dispatch(request())).unwrap();
+        assert_eq!(response.status, StatusCode::INTERNAL_SERVER_ERROR);
+        assert_eq!(calls.load(Ordering::SeqCst), 1);
+        assert_eq!(sleeps.lock().unwrap().len(), 1);
+
+        client.set_operation_timeout(Some(Duration::from_secs(0)));
+        match futures::executor::block_on(client.sign_and_dispatch(request())) {
+            Err(SignAndDispatchError::Dispatch(err)) => {
+                assert!(err.to_string().contains("deadline"))
+            }
+            _ => panic!("expected the operation deadline to be exceeded"),
+        }
+    }
+
+    #[tokio::test]
+    async fn cancels_operations_in_flight() {
+        let mut retry_policy = RetryPolicy::new();
+        retry_policy.set_base_delay(Duration::from_secs(60));
+        let (mut client, calls) = test_client(vec![503], retry_policy);
+        let token = CancellationToken::new();
+        client.set_cancellation_token(Some(token.clone()));
+
+        let operation = tokio::spawn(async move { client.sign_and_dispatch(request()).await });
+        tokio::time::delay_for(Duration::from_millis(10)).await;
+        token.cancel();
+        match operation.await.unwrap() {
+            Err(SignAndDispatchError::Dispatch(err)) => assert!(err.is_cancelled()),
+            _ => panic!("expected the operation to be cancelled"),
+        }
+        assert_eq!(calls.load(Ordering::SeqCst), 1);
+    }
+
+    #[tokio::test]
+    async fn shutdown_rejects_new_operations() {
+        let mut retry_policy = RetryPolicy::new();
+        retry_policy.set_base_delay(Duration::from_millis(20));
+        let (client, calls) = test_client(vec![503, 200], retry_policy);
+        let operation = tokio::spawn({
+            let client = client.clone();
+            async move { client.sign_and_dispatch(request()).await }
+        });
+        tokio::time::delay_for(Duration::from_millis(5)).await;
+        client.shutdown().await;
+        assert_eq!(operation.await.unwrap().unwrap().status, StatusCode::OK);
+        assert_eq!(calls.load(Ordering::SeqCst), 2);
+
+        match client.sign_and_dispatch(request()).await {
+            Err(SignAndDispatchError::Dispatch(err)) => {
+                assert!(err.to_string().contains("shut down"))
+            }
+            _ => panic!("expected the client to be shut down"),
+        }
+    }
+
+    struct SkewedDispatcher {
+        server_time: SystemTime,
+        signed_at: Arc<Mutex<Vec<String>>>,
+    }
+
+    impl DispatchSignedRequest for SkewedDispatcher {
+        fn dispatch(
+            &self,
+            request: Request<ByteStream>,
+            _timeout: Option<Duration>,
+        ) -> DispatchSignedRequestFuture {
+            let request = signed(&request);
+            let amz_date = String::from_utf8(request.headers["x-amz-date"][0].clone()).unwrap();
+            let mut signed_at = self.signed_at.lock().unwrap();
+            signed_at.push(amz_date);
+            let mut headers = HeaderMap::default();
+            headers.insert("date", httpdate::fmt_http_date(self.server_time));
+            let (status, body) = if signed_at.len() == 1 {
+                (403, "<Error><Code>RequestTimeTooSkewed</Code></Error>")
+            } else {
+                (200, "")
+            };
+            futures::future::ready(Ok(HttpResponse {
+                status: StatusCode::from_u16(status).unwrap(),
+                body: body.as_bytes().to_vec().into(),
+                headers,
+            }
+            .into()))
+            .boxed()
+        }
+    }
+
+    #[tokio::test]
+    async fn corrects_clock_skew() {
+        let server_time = SystemTime::UNIX_EPOCH + Duration::from_secs(1_500_000_000);
+        let signed_at = Arc::new(Mutex::new(Vec::new()));
+        let dispatcher = SkewedDispatcher {
+            server_time,
+            signed_at: signed_at.clone(),
+        };
+        let credentials = StaticProvider::new_minimal("key".to_owned(), "secret".to_owned());
+        let client = Client::new_with(credentials, dispatcher);
+
+        let response = client.sign_and_dispatch(request()).await.unwrap();
+        assert_eq!(response.status, StatusCode::OK);
+        let signed_at = signed_at.lock().unwrap();
+        assert_eq!(signed_at.len(), 2);
+        assert!(!signed_at[0].starts_with("20170714"));
+        assert!(signed_at[1].starts_with("20170714T024"));
+    }
+
+    #[tokio::test]
+    async fn does_not_retry_client_errors_or_disabled_classes() {
+        let (client, calls) = test_client(vec![404, 200], fast_retry_policy());
+        let response = client.sign_and_dispatch(request()).await.unwrap();
+        assert_eq!(response.status, StatusCode::NOT_FOUND);
+        assert_eq!(calls.load(Ordering::SeqCst), 1);
+
+        let mut retry_policy = fast_retry_policy();
+        retry_policy.set_retryable(ErrorClass::Throttling, false);
+        let (client, calls) = test_client(vec![429, 200], retry_policy);
+        let response = client.sign_and_dispatch(request()).await.unwrap();
+        assert_eq!(response.status, StatusCode::TOO_MANY_REQUESTS);
+        assert_eq!(calls.load(Ordering::SeqCst), 1);
+    }
+
+    /// Echoes the trace header of each request back in its response.
+    struct TraceEchoDispatcher;
+
+    impl DispatchSignedRequest for TraceEchoDispatcher {
+        fn dispatch(
+            &self,
+            request: Request<ByteStream>,
+            _timeout: Option<Duration>,
+        ) -> DispatchSignedRequestFuture {
+            let request = signed(&request);
+            let mut headers = HeaderMap::default();
+            if let Some(trace_id) = request.headers.get("x-amzn-trace-id") {
+                let trace_id = String::from_utf8(trace_id[0].clone()).unwrap();
+                headers.insert("x-amzn-trace-id", trace_id);
+            }
+            futures::future::ready(Ok(HttpResponse {
+                status: StatusCode::OK,
+                body: Vec::new().into(),
+                headers,
+            }
+            .into()))
+            .boxed()
+        }
+    }
+
+    #[tokio::test]
+    async fn propagates_trace_headers() {
+        let credentials = StaticProvider::new_minimal("key".to_owned(), "secret".to_owned());
+        let mut client = Client::new_with(credentials, TraceEchoDispatcher);
+        client.set_trace_id_provider(|| Some("Root=1-abc;Parent=def;Sampled=1".to_owned()));
+
+        let response = client.sign_and_dispatch(request()).await.unwrap();
+        let trace_header = ResponseMetadata::from_response(&response)
+            .trace_header()
+            .unwrap();
+        assert_eq!(trace_header.root(), Some("1-abc"));
+        assert_eq!(trace_header.parent(), Some("def"));
+        assert_eq!(trace_header.sampled(), Some(true));
+
+        let mut request = request();
+        request.add_header("X-Amzn-Trace-Id", "Root=1-xyz");
+        let response = client.sign_and_dispatch(request).await.unwrap();
+        let metadata = ResponseMetadata::from_response(&response);
+        assert_eq!(metadata.trace_header().unwrap().root(), Some("1-xyz"));
+    }
+
+    struct AuthorizationEchoDispatcher;
+
+    impl DispatchSignedRequest for AuthorizationEchoDispatcher {
+        fn dispatch(
+            &self,
+            request: Request<ByteStream>,
+            _timeout: Option<Duration>,
+        ) -> DispatchSignedRequestFuture {
+            let request = signed(&request);
+            let mut headers = HeaderMap::default();
+            if let Some(authorization) = request.headers.get("authorization") {
+                let authorization = String::from_utf8(authorization[0].clone()).unwrap();
+                headers.insert("authorization", authorization);
+            }
+            futures::future::ready(Ok(HttpResponse {
+                status: StatusCode::OK,
+                body: Vec::new().into(),
+                headers,
+            }
+            .into()))
+            .boxed()
+        }
+    }
+
+    #[tokio::test]
+    async fn customizes_credentials_and_timeouts() {
+        let client = Client::new_not_signing(AuthorizationEchoDispatcher);
+        let response = client.sign_and_dispatch(request()).await.unwrap();
+        assert!(response.headers.get("authorization").is_none());
+
+        let mut config = OperationConfig::new();
+        config.set_credentials(StaticProvider::new_minimal(
+            "override".to_owned(),
+            "secret".to_owned(),
+        ));
+        config.set_timeout(Duration::from_secs(5));
+        let customized = client.customize(&config);
+        assert_eq!(customized.timeout, Some(Duration::from_secs(5)));
+        assert_eq!(customized.operation_timeout, None);
+        let response = customized.sign_and_dispatch(request()).await.unwrap();
+        let authorization = response.headers.get("authorization").unwrap();
+        assert!(authorization.contains("Credential=override/"));
+
+        customized.shutdown().await;
+        assert!(client.sign_and_dispatch(request()).await.is_err());
+    }
+
+    #[tokio::test]
+    async fn signs_changes_of_request_hooks() {
+        let credentials = StaticProvider::new_minimal("key".to_owned(), "secret".to_owned());
+        let mut client = Client::new_with(credentials, AuthorizationEchoDispatcher);
+        client.add_request_hook(|request: &mut SignedRequest| {
+            request.add_header("x-amz-expected-bucket-owner", "111122223333");
+        });
+        let mut config = OperationConfig::new();
+        config.add_request_hook(|request: &mut SignedRequest| {
+            assert!(request.headers.contains_key("x-amz-expected-bucket-owner"));
+            request.add_header("x-vendor-token", "abc");
+        });
+
+        let response = client.sign_and_dispatch(request()).await.unwrap();
+        let authorization = response.headers.get("authorization").unwrap();
+        assert!(authorization.contains("x-amz-expected-bucket-owner"));
+        assert!(!authorization.contains("x-vendor-token"));
+
+        let response = client
+            .customize(&config)
+            .sign_and_dispatch(request())
+            .await
+            .unwrap();
+        let authorization = response.headers.get("authorization").unwrap();
+        assert!(authorization.contains("x-amz-expected-bucket-owner;x-vendor-token"));
+    }
+
+    #[tokio::test]
+    async fn chooses_payload_signing_of_s3_requests() {
+        let (mut client, _) = test_client(vec![200], RetryPolicy::new());
+        client.set_payload_signing(PayloadSigning::Unsigned);
+        let seen = Arc::new(Mutex::new(Vec::new()));
+        let recorded = seen.clone();
+        client.add_request_hook(move |request: &mut SignedRequest| {
+            recorded.lock().unwrap().push(request.payload_signing);
+        });
+
+        let s3_request = || SignedRequest::new("PUT", "s3", &Region::UsEast1, "/bucket/key");
+        client.sign_and_dispatch(s3_request()).await.unwrap();
+        client.sign_and_dispatch(request()).await.unwrap();
+        let mut config = OperationConfig::new();
+        config.set_payload_signing(PayloadSigning::Signed);
+        client
+            .customize(&config)
+            .sign_and_dispatch(s3_request())
+            .await
+            .unwrap();
+        assert_eq!(
+            *seen.lock().unwrap(),
+            vec![
+                PayloadSigning::Unsigned,
+                PayloadSigning::Signed,
+                PayloadSigning::Signed
+            ]
+        );
+    }
+
+    /// Answers `sts:GetCallerIdentity` and records the hostnames of all requests.
+    struct CallerIdentityDispatcher {
+        hostnames: Arc<Mutex<Vec<String>>>,
+    }
+
+    impl DispatchSignedRequest for CallerIdentityDispatcher {
+        fn dispatch(
+            &self,
+            request: Request<ByteStream>,
+            _timeout: Option<Duration>,
+        ) -> DispatchSignedRequestFuture {
+            let request = signed(&request);
+            self.hostnames.lock().unwrap().push(request.hostname());
+            let body = if request.service == "sts" {
+                "<GetCallerIdentityResponse><GetCallerIdentityResult>\
+                 <Account>111122223333</Account>\
+                 </GetCallerIdentityResult></GetCallerIdentityResponse>"
+            } else {
+                ""
+            };
+            futures::future::ready(Ok(HttpResponse {
+                status: StatusCode::OK,
+                body: body.as_bytes().to_vec().into(),
+                headers: HeaderMap::default(),
+            }
+            .into()))
+            .boxed()
+        }
+    }
+
+    #[tokio::test]
+    async fn sends_requests_to_account_id_endpoints() {
+        let hostnames = Arc::new(Mutex::new(Vec::new()));
+        let credentials = StaticProvider::new_minimal("key".to_owned(), "secret".to_owned());
+        let mut client = Client::new_with(
+            credentials,
+            CallerIdentityDispatcher {
+                hostnames: hostnames.clone(),
+            },
+        );
+        let dynamodb = || SignedRequest::new("POST", "dynamodb", &Region::EuWest1, "/");
+
+        client.set_account_id_endpoint_mode(AccountIdEndpointMode::Preferred);
+        client.sign_and_dispatch(dynamodb()).await.unwrap();
+        client.set_account_id_endpoint_mode(AccountIdEndpointMode::Required);
+        client.sign_and_dispatch(dynamodb()).await.unwrap();
+        client.sign_and_dispatch(dynamodb()).await.unwrap();
+        client.set_account_id_endpoint_mode(AccountIdEndpointMode::Disabled);
+        client.sign_and_dispatch(dynamodb()).await.unwrap();
+        assert_eq!(
+            *hostnames.lock().unwrap(),
+            vec![
+                "dynamodb.eu-west-1.amazonaws.com",
+                "sts.eu-west-1.amazonaws.com",
+                "111122223333.ddb.eu-west-1.api.aws",
+                "111122223333.ddb.eu-west-1.api.aws",
+                "dynamodb.eu-west-1.amazonaws.com",
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn fails_with_the_errors_of_caller_identity_requests() {
+        let credentials = StaticProvider::new_minimal("key".to_owned(), "secret".to_owned());
+        let dispatcher = StatusDispatcher {
+            statuses: vec![403],
+            calls: Arc::new(AtomicUsize::new(0)),
+        };
+        let mut client = Client::new_with(credentials, dispatcher);
+        client.set_account_id_endpoint_mode(AccountIdEndpointMode::Required);
+        let request = SignedRequest::new("POST", "dynamodb", &Region::EuWest1, "/");
+        match client.sign_and_dispatch(request).await {
+            Err(SignAndDispatchError::Credentials(err)) => assert_eq!(
+                err.message,
+                "Account ID required for the endpoint is unknown: \
+                 sts:GetCallerIdentity failed: 403 Forbidden: "
+            ),
+            _ => panic!("expected the error of sts:GetCallerIdentity"),
+        }
+    }
+
+    /// Records the hostnames and authorization headers of all requests.
+    struct RecordingDispatcher {
+        requests: Arc<Mutex<Vec<(String, String)>>>,
+    }
+
+    impl DispatchSignedRequest for RecordingDispatcher {
+        fn dispatch(
+            &self,
+            request: Request<ByteStream>,
+            _timeout: Option<Duration>,
+        ) -> DispatchSignedRequestFuture {
+            let request = signed(&request);
+            let authorization = request.headers()["authorization"][0].clone();
+            self.requests.lock().unwrap().push((
+                request.hostname(),
+                String::from_utf8(authorization).unwrap(),
+            ));
+            futures::future::ready(Ok(HttpResponse {
+                status: StatusCode::OK,
+                body: Vec::new().into(),
+                headers: HeaderMap::default(),
+            }
+            .into()))
+            .boxed()
+        }
+    }
+
+    #[cfg(feature = "sigv4a")]
+    #[tokio::test]
+    async fn signs_requests_to_multi_region_access_points_with_sigv4a() {
+        let requests = Arc::new(Mutex::new(Vec::new()));
+        let credentials = StaticProvider::new_minimal("key".to_owned(), "secret".to_owned());
+        let client = Client::new_with(
+            credentials,
+            RecordingDispatcher {
+                requests: requests.clone(),
+            },
+        );
+        let s3 = |path: &str| SignedRequest::new("GET", "s3", &Region::EuWest1, path);
+
+        client
+            .sign_and_dispatch(s3(
+                "/arn:aws:s3::123456789012:accesspoint/mfzwi23gnjvgw.mrap/key",
+            ))
+            .await
+            .unwrap();
+        client.sign_and_dispatch(s3("/bucket/key")).await.unwrap();
+        let requests = requests.lock().unwrap();
+        assert_eq!(
+            requests[0].0,
+            "mfzwi23gnjvgw.mrap.accesspoint.s3-global.amazonaws.com"
+        );
+        assert!(requests[0].1.starts_with("AWS4-ECDSA-P256-SHA256 "));
+        assert_eq!(requests[1].0, "s3-eu-west-1.amazonaws.com");
+        assert!(requests[1].1.starts_with("AWS4-HMAC-SHA256 "));
+    }
+
+    #[cfg(not(feature = "sigv4a"))]
+    #[tokio::test]
+    async fn multi_region_access_points_require_sigv4a() {
+        let calls = Arc::new(AtomicUsize::new(0));
+        let credentials = StaticProvider::new_minimal("key".to_owned(), "secret".to_owned());
+        let dispatcher = StatusDispatcher {
+            statuses: vec![200],
+            calls: calls.clone(),
+        };
+        let client = Client::new_with(credentials, dispatcher);
+        let request = SignedRequest::new(
+            "GET",
+            "s3",
+            &Region::EuWest1,
+            "/arn:aws:s3::123456789012:accesspoint/mfzwi23gnjvgw.mrap/key",
+        );
+        match client.sign_and_dispatch(request).await {
+            Err(SignAndDispatchError::Credentials(err)) => {
+                assert!(err.to_string().contains("sigv4a feature"))
+            }
+            _ => panic!("expected the request to require the sigv4a feature"),
+        }
+        assert_eq!(calls.load(Ordering::SeqCst), 0);
+    }
+
+    /// Redirects S3 requests not sent to `us-west-2`, recording the hostnames of all requests.
+    struct BucketRegionDispatcher {
+        hostnames: Arc<Mutex<Vec<String>>>,
+    }
+
+    impl DispatchSignedRequest for BucketRegionDispatcher {
+        fn dispatch(
+            &self,
+            request: Request<ByteStream>,
+            _timeout: Option<Duration>,
+        ) -> DispatchSignedRequestFuture {
+            let request = signed(&request);
+            self.hostnames.lock().unwrap().push(request.hostname());
+            let mut headers = HeaderMap::default();
+            headers.insert("x-amz-bucket-region", "us-west-2".to_owned());
+            let status = if request.region == Region::UsWest2 {
+                StatusCode::OK
+            } else {
+                StatusCode::MOVED_PERMANENTLY
+            };
+            futures::future::ready(Ok(HttpResponse {
+                status,
+                body: Vec::new().into(),
+                headers,
+            }
+            .into()))
+            .boxed()
+        }
+    }
+
+    #[tokio::test]
+    async fn follows_s3_redirects_to_the_regions_of_buckets() {
+        let hostnames = Arc::new(Mutex::new(Vec::new()));
+        let mut client = Client::new_not_signing(BucketRegionDispatcher {
+            hostnames: hostnames.clone(),
+        });
+        let s3 = |path: &str| SignedRequest::new("GET", "s3", &Region::EuWest1, path);
+
+        let response = client.sign_and_dispatch(s3("/bucket/key")).await.unwrap();
+        assert_eq!(response.status, StatusCode::OK);
+        client.sign_and_dispatch(s3("/bucket/key")).await.unwrap();
+        let response = client.sign_and_dispatch(s3("/")).await.unwrap();
+        assert_eq!(response.status, StatusCode::MOVED_PERMANENTLY);
+        client.set_follow_s3_redirects(false);
+        let response = client.sign_and_dispatch(s3("/other/key")).await.unwrap();
+        assert_eq!(response.status, StatusCode::MOVED_PERMANENTLY);
+        assert_eq!(
+            *hostnames.lock().unwrap(),
+            vec![
+                "s3-eu-west-1.amazonaws.com",
+                "s3-us-west-2.amazonaws.com",
+                "s3-us-west-2.amazonaws.com",
+                "s3-eu-west-1.amazonaws.com",
+                "s3-eu-west-1.amazonaws.com",
+            ]
+        );
+    }
+
+    #[test]
+    fn resolves_overridden_regions_and_endpoints() {
+        let mut config = OperationConfig::new();
+        assert_eq!(config.resolve_region(&Region::UsEast1), Region::UsEast1);
+        config.set_region(Region::EuWest1);
+        assert_eq!(config.resolve_region(&Region::UsEast1), Region::EuWest1);
+        config.set_endpoint("http://localhost:4566".to_owned());
+        assert_eq!(
+            config.resolve_region(&Region::UsEast1),
+            Region::Custom {
+                name: "eu-west-1".to_owned(),
+                endpoint: "http://localhost:4566".to_owned(),
+            }
+        );
+    }
+
+    #[test]
+    fn appends_app_id_to_user_agent() {
+        let (mut client, _) = test_client(vec![200], RetryPolicy::none());
+        client.set_app_id(None);
+        assert_eq!(client.user_agent(), None);
+
+        client.set_app_id(Some("billing service".to_owned()));
+        client.add_user_agent_segment("framework/1.2".to_owned());
+        assert_eq!(
+            client.user_agent().unwrap(),
+            format!("{} app/billing_service framework/1.2", *DEFAULT_USER_AGENT)
+        );
+    }
+
+    #[tokio::test]
+    async fn reports_metrics_to_observer() {
+        struct Recorder(Mutex<Vec<RequestMetrics>>);
+
+        impl RequestMetricsObserver for Recorder {
+            fn on_request_complete(&self, metrics: &RequestMetrics) {
+                self.0.lock().unwrap().push(metrics.clone());
+            }
+        }
+
+        let recorder = Arc::new(Recorder(Mutex::new(Vec::new())));
+        let (mut client, _) = test_client(vec![500, 503, 200], fast_retry_policy());
+        client.set_metrics_observer(recorder.clone());
+        let mut request = request();
+        request.set_operation("SendMessage");
+        request.set_payload(Some(b"Action=SendMessage".to_vec()));
+        client.sign_and_dispatch(request).await.unwrap();
+
+        let metrics = recorder.0.lock().unwrap();
+        assert_eq!(metrics.len(), 1);
+        assert_eq!(metrics[0].service(), "sqs");
+        assert_eq!(metrics[0].operation(), Some("SendMessage"));
+        assert_eq!(metrics[0].region(), &Region::UsEast1);
+        assert_eq!(metrics[0].attempts(), 3);
+        assert_eq!(metrics[0].retries(), 2);
+        assert_eq!(metrics[0].request_size(), Some(18));
+        assert_eq!(metrics[0].response_size(), None);
+        assert_eq!(metrics[0].status(), Some(StatusCode::OK));
+    }
+
+    #[cfg(feature = "tracing")]
+    #[tokio::test]
+    async fn records_operations_in_spans() {
+        use tracing::field::{Field, Visit};
+        use tracing::span::{Attributes, Id, Record};
+        use tracing::{Event, Metadata};
+        use tracing_core::span::Current;
+
+        /// Records the fields of the only span of the test, which is current while entered.
+        #[derive(Clone, Default)]
+        struct Fields {
+            fields: Arc<Mutex<Vec<String>>>,
+            span: Arc<Mutex<Option<&'static Metadata<'static>>>>,
+            entered: Arc<Mutex<bool>>,
+        }
+
+        impl Visit for Fields {
+            fn record_debug(&mut self, field: &Field, value: &dyn std::fmt::Debug) {
+                let field = format!("{}={:?}", field.name(), value);
+                self.fields.lock().unwrap().push(field);
+            }
+        }
+
+        impl tracing::Subscriber for Fields {
+            fn enabled(&self, _: &Metadata<'_>) -> bool {
+                true
+            }
+            fn new_span(&self, span: &Attributes<'_>) -> Id {
+                *self.span.lock().unwrap() = Some(span.metadata());
+                span.record(&mut self.clone());
+                Id::from_u64(1)
+            }
+            fn record(&self, _: &Id, values: &Record<'_>) {
+                values.record(&mut self.clone());
+            }
+            fn record_follows_from(&self, _: &Id, _: &Id) {}
+            fn event(&self, _: &Event<'_>) {}
+            fn enter(&self, _: &Id) {
+                *self.entered.lock().unwrap() = true;
+            }
+            fn exit(&self, _: &Id) {
+                *self.entered.lock().unwrap() = false;
+            }
+            fn current_span(&self) -> Current {
+                match *self.span.lock().unwrap() {
+                    Some(metadata) if *self.entered.lock().unwrap() => {
+                        Current::new(Id::from_u64(1), metadata)
+                    }
+                    _ => Current::none(),
+                }
+            }
+        }
+
+        let fields = Fields::default();
+        let _guard = tracing::subscriber::set_default(fields.clone());
+        let (client, _) = test_client(vec![503, 200], fast_retry_policy());
+        let mut request = request();
+        request.set_operation("SendMessage");
+        client.sign_and_dispatch(request).await.unwrap();
+
+        assert_eq!(
+            *fields.fields.lock().unwrap(),
+            vec![
+                "service=\"sqs\"",
+                "operation=\"SendMessage\"",
+                "region=\"us-east-1\"",
+                "attempt=1",
+                "status=503",
+                "attempt=2",
+                "status=200",
+            ]
+        );
+    }
 }
@@ -0,0 +1,359 @@
+//! Building the requests of the `http` crate that dispatchers send, and dispatching them through
+//! HTTP clients other than `HttpClient`.
+//!
+//! With the `reqwest` feature, `DispatchSignedRequest` is implemented for `reqwest::Client`:
+//!
+//! ```rust,ignore
+//! let client = SqsClient::new_with(reqwest::Client::new(), DefaultCredentialsProvider::new()?, Region::UsEast1);
+//! ```
+//!
+//! With the `hyper1` feature, `Hyper1Dispatcher` sends requests through a hyper 1.x client.
+//! Clients built on tokio 1 can't be driven by the tokio 0.2 runtime rusoto runs on, so their
+//! requests are spawned on the tokio 1 runtime of the handle the dispatcher is created with:
+//!
+//! ```rust,ignore
+//! let runtime = tokio::runtime::Runtime::new()?;
+//! let client = hyper_util::client::legacy::Client::builder(TokioExecutor::new()).build(connector);
+//! let dispatcher = Hyper1Dispatcher::new(client, runtime.handle().clone());
+//! ```
+
+use http::header::HeaderValue;
+use http::{HeaderMap, Request, Response};
+
+use crate::request::{request_head, HttpDispatchError, HttpResponse};
+use crate::signature::{SignedRequest, SignedRequestPayload};
+use crate::stream::ByteStream;
+
+#[cfg(feature = "hyper1")]
+pub use self::hyper1_dispatcher::{Hyper1Body, Hyper1Dispatcher};
+
+/// Convert a signed request into the HTTP request sending it.
+///
+/// The extensions of the HTTP request hold the signed request, for dispatchers needing details
+/// such as the name of its operation. Its payload is only kept there if it is a buffer; streamed
+/// payloads are only in the body of the HTTP request.
+pub fn into_http_request(
+    mut request: SignedRequest,
+) -> Result<Request<ByteStream>, HttpDispatchError> {
+    let (method, uri, headers) = request_head(&request)?;
+    let body = match request.payload.take() {
+        Some(SignedRequestPayload::Buffer(buf)) => {
+            request.payload = Some(SignedRequestPayload::Buffer(buf.clone()));
+            ByteStream::from(buf)
+        }
+        Some(SignedRequestPayload::Stream(stream)) => stream,
+        None => ByteStream::from(Vec::new()),
+    };
+    let mut http_request = Request::builder()
+        .method(method)
+        .uri(uri)
+        .body(body)
+        .map_err(|err| HttpDispatchError::new(format!("error building request: {}", err)))?;
+    *http_request.headers_mut() = headers;
+    http_request.extensions_mut().insert(request);
+    Ok(http_request)
+}
+
+impl From<Response<ByteStream>> for HttpResponse {
+    fn from(response: Response<ByteStream>) -> HttpResponse {
+        let (parts, body) = response.into_parts();
+        let mut headers = HeaderMap::with_capacity(parts.headers.len());
+        for (name, value) in &parts.headers {
+            let value = String::from_utf8_lossy(value.as_bytes()).into_owned();
+            headers.append(name, value);
+        }
+        HttpResponse {
+            status: parts.status,
+            body,
+            headers,
+        }
+    }
+}
+
+impl From<HttpResponse> for Response<ByteStream> {
+    fn from(response: HttpResponse) -> Response<ByteStream> {
+        let mut http_response = Response::new(response.body);
+        *http_response.status_mut() = response.status;
+        for (name, value) in &response.headers {
+            if let Ok(value) = HeaderValue::from_str(value) {
+                http_response.headers_mut().append(name, value);
+            }
+        }
+        http_response
+    }
+}
+
+#[cfg(feature = "reqwest")]
+impl crate::request::DispatchSignedRequest for reqwest::Client {
+    /// Send `request`, failing if the response headers haven't been received within `timeout`.
+    /// Timeouts configured on the `reqwest::Client` itself also bound the reading of the body.
+    fn dispatch(
+        &self,
+        request: Request<ByteStream>,
+        timeout: Option<std::time::Duration>,
+    ) -> crate::request::DispatchSignedRequestFuture {
+        use crate::runtime::{self, DefaultRuntime};
+        use futures::{FutureExt, TryStreamExt};
+        use std::io;
+
+        let (parts, body) = request.into_parts();
+        let send = self
+            .request(parts.method, &parts.uri.to_string())
+            .headers(parts.headers)
+            .body(reqwest::Body::wrap_stream(body))
+            .send();
+        async move {
+            let response = match timeout {
+                None => send.await,
+                Some(timeout) => runtime::timeout(&DefaultRuntime::default(), timeout, send)
+                    .await
+                    .map_err(|_| {
+                        HttpDispatchError::new("Timeout while dispatching request".to_owned())
+                    })?,
+            }
+            .map_err(|err| HttpDispatchError::new(format!("Error during dispatch: {}", err)))?;
+            let mut http_response = Response::builder().status(response.status());
+            if let Some(headers) = http_response.headers_mut() {
+                *headers = response.headers().clone();
+            }
+            let body = response.bytes_stream().map_err(|err| {
+                let kind = if err.is_timeout() {
+                    io::ErrorKind::TimedOut
+                } else {
+                    io::ErrorKind::Other
+                };
+                io::Error::new(kind, err)
+            });
+            http_response
+                .body(ByteStream::new(body))
+                .map_err(|err| HttpDispatchError::new(err.to_string()))
+        }
+        .boxed()
+    }
+}
+
+#[cfg(feature = "hyper1")]
+mod hyper1_dispatcher {
+    use std::io;
+    use std::time::Duration;
+
+    use bytes::Bytes;
+    use futures::{FutureExt, StreamExt, TryStreamExt};
+    use http::{HeaderMap, Request, Response, StatusCode};
+    use http_body_util::{combinators::BoxBody, BodyExt, BodyStream, StreamBody};
+    use hyper1::body::Frame;
+    use hyper_util::client::legacy::connect::Connect;
+    use hyper_util::client::legacy::Client;
+    use tokio1::runtime::Handle;
+
+    use crate::request::{DispatchSignedRequest, DispatchSignedRequestFuture, HttpDispatchError};
+    use crate::stream::ByteStream;
+
+    /// The body of the requests a `Hyper1Dispatcher` sends.
+    pub type Hyper1Body = BoxBody<bytes1::Bytes, io::Error>;
+
+    /// A `DispatchSignedRequest` sending requests through a hyper 1.x client, whose requests
+    /// are spawned on a tokio 1 runtime.
+    ///
+    /// Timeouts are tokio 1 timers, so the runtime must have its time driver enabled.
+    pub struct Hyper1Dispatcher<C> {
+        client: Client<C, Hyper1Body>,
+        handle: Handle,
+    }
+
+    impl<C> Hyper1Dispatcher<C> {
+        /// Send requests through `client`, on the tokio 1 runtime of `handle`.
+        pub fn new(client: Client<C, Hyper1Body>, handle: Handle) -> Self {
+            Hyper1Dispatcher { client, handle }
+        }
+    }
+
+    fn error<E: std::fmt::Display>(context: &str, err: E) -> HttpDispatchError {
+        HttpDispatchError::new(format!("{}: {}", context, err))
+    }
+
+    fn into_hyper1_request(
+        request: Request<ByteStream>,
+    ) -> Result<http1::Request<Hyper1Body>, HttpDispatchError> {
+        let (parts, body) = request.into_parts();
+        let body = body.map_ok(|chunk| Frame::data(bytes1::Bytes::from(chunk.to_vec())));
+        let mut builder = http1::Request::builder()
+            .method(parts.method.as_str())
+            .uri(parts.uri.to_string());
+        for (name, value) in &parts.headers {
+            builder = builder.header(name.as_str(), value.as_bytes());
+        }
+        builder
+            .body(BodyExt::boxed(StreamBody::new(body)))
+            .map_err(|err| error("error building request", err))
+    }
+
+    fn from_hyper1_response(
+        response: http1::Response<hyper1::body::Incoming>,
+    ) -> Result<Response<ByteStream>, HttpDispatchError> {
+        let (parts, body) = response.into_parts();
+        let status = StatusCode::from_u16(parts.status.as_u16())
+            .map_err(|err| error("error reading response", err))?;
+        let mut headers = HeaderMap::with_capacity(parts.headers.len());
+        for (name, value) in &parts.headers {
+            let name = http::header::HeaderName::from_bytes(name.as_str().as_bytes())
+                .map_err(|err| error("error reading response", err))?;
+            let value = http::header::HeaderValue::from_bytes(value.as_bytes())
+                .map_err(|err| error("error reading response", err))?;
+            headers.append(name, value);
+        }
+        let body = BodyStream::new(body).filter_map(|frame| async move {
+            match frame {
+                Ok(frame) => frame
+                    .into_data()
+                    .ok()
+                    .map(|data| Ok(Bytes::from(data.to_vec()))),
+                Err(err) => Some(Err(io::Error::new(
+                    io::ErrorKind::Other,
+                    format!("Error obtaining chunk: {}", err),
+                ))),
+            }
+        });
+        let mut response = Response::new(ByteStream::new(body));
+        *response.status_mut() = status;
+        *response.headers_mut() = headers;
+        Ok(response)
+    }
+
+    impl<C> DispatchSignedRequest for Hyper1Dispatcher<C>
+    where
+        C: Connect + Clone + Send + Sync + 'static,
+    {
+        fn dispatch(
+            &self,
+            request: Request<ByteStream>,
+            timeout: Option<Duration>,
+        ) -> DispatchSignedRequestFuture {
+            let request = match into_hyper1_request(request) {
+                Ok(request) => request,
+                Err(err) => return futures::future::err(err).boxed(),
+            };
+            let response = self.client.request(request);
+            let task = self.handle.spawn(async move {
+                match timeout {
+                    None => response.await,
+                    Some(timeout) => match tokio1::time::timeout(timeout, response).await {
+                        Ok(response) => response,
+                        Err(_) => {
+                            return Err(HttpDispatchError::new(
+                                "Timeout while dispatching request".to_owned(),
+                            ))
+                        }
+                    },
+                }
+                .map_err(|err| error("Error during dispatch", err))
+            });
+            async move {
+                let response = task
+                    .await
+                    .map_err(|err| error("Error during dispatch", err))??;
+                from_hyper1_response(response)
+            }
+            .boxed()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Region;
+    use bytes::Bytes;
+    use futures::StreamExt;
+
+    #[test]
+    fn signed_requests_are_kept_in_extensions() {
+        let mut request = SignedRequest::new("PUT", "s3", &Region::UsEast1, "/bucket/key");
+        request.set_operation("PutObject");
+        request.set_payload(Some(b"contents".to_vec()));
+
+        let http_request = into_http_request(request).unwrap();
+        assert_eq!(http_request.method(), "PUT");
+        assert_eq!(
+            http_request.uri().to_string(),
+            "https://s3.amazonaws.com/bucket/key"
+        );
+        let signed = http_request.extensions().get::<SignedRequest>().unwrap();
+        assert_eq!(signed.operation.as_deref(), Some("PutObject"));
+        match signed.payload {
+            Some(SignedRequestPayload::Buffer(ref buf)) => assert_eq!(buf, "contents"),
+            ref payload => panic!("unexpected payload {:?}", payload),
+        }
+
+        let mut request = SignedRequest::new("PUT", "s3", &Region::UsEast1, "/bucket/key");
+        request.set_payload_stream(ByteStream::from(b"contents".to_vec()));
+        let http_request = into_http_request(request).unwrap();
+        let signed = http_request.extensions().get::<SignedRequest>().unwrap();
+        assert!(signed.payload.is_none());
+    }
+
+    #[tokio::test]
+    async fn converts_responses() {
+        let mut response = Response::new(ByteStream::from(b"body".to_vec()));
+        response
+            .headers_mut()
+            .insert("x-amz-request-id", HeaderValue::from_static("req-1"));
+        let response = HttpResponse::from(response);
+        assert_eq!(response.request_id(), Some("req-1"));
+
+        let response = Response::from(response);
+        assert_eq!(response.headers()["x-amz-request-id"], "req-1");
+        let body: Vec<Bytes> = response.into_body().map(Result::unwrap).collect().await;
+        assert_eq!(body.concat(), b"body");
+    }
+
+    #[cfg(feature = "hyper1")]
+    #[tokio::test]
+    async fn dispatches_through_hyper1_clients() {
+        use crate::request::DispatchSignedRequest;
+        use std::io::{Read, Write};
+        use std::net::TcpListener;
+
+        // Answers one request with its body.
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let endpoint = format!("http://{}", listener.local_addr().unwrap());
+        std::thread::spawn(move || {
+            let (mut stream, _) = listener.accept().unwrap();
+            let mut request = Vec::new();
+            let mut buf = [0; 1024];
+            while !String::from_utf8_lossy(&request).ends_with("contents") {
+                let read = stream.read(&mut buf).unwrap();
+                request.extend_from_slice(&buf[..read]);
+            }
+            let response =
+                "HTTP/1.1 201 Created\r\nx-method: PUT\r\ncontent-length: 8\r\n\r\ncontents";
+            stream.write_all(response.as_bytes()).unwrap();
+        });
+
+        let runtime = tokio1::runtime::Builder::new_multi_thread()
+            .enable_all()
+            .build()
+            .unwrap();
+        let client =
+            hyper_util::client::legacy::Client::builder(hyper_util::rt::TokioExecutor::new())
+                .build_http();
+        let dispatcher = Hyper1Dispatcher::new(client, runtime.handle().clone());
+
+        let region = Region::Custom {
+            name: "us-east-1".to_owned(),
+            endpoint,
+        };
+        let mut request = SignedRequest::new("PUT", "s3", &region, "/bucket/key");
+        request.set_payload(Some(b"contents".to_vec()));
+        request.complement();
+        let response = dispatcher
+            .dispatch(into_http_request(request).unwrap(), None)
+            .await
+            .unwrap();
+        assert_eq!(response.status(), 201);
+        assert_eq!(response.headers()["x-method"], "PUT");
+        let body: Vec<Bytes> = response.into_body().map(Result::unwrap).collect().await;
+        assert_eq!(body.concat(), b"contents");
+        runtime.shutdown_background();
+    }
+}
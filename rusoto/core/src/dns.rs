@@ -0,0 +1,168 @@
+//! Pluggable DNS resolution for `HttpClient`.
+
+use std::collections::HashMap;
+use std::future::Future;
+use std::io;
+use std::net::IpAddr;
+use std::pin::Pin;
+use std::str::FromStr;
+use std::sync::Arc;
+use std::task::{Context, Poll};
+
+use hyper::client::connect::dns::{GaiResolver, Name};
+use hyper::service::Service;
+
+/// Future returned from `ResolveDns::resolve`.
+pub type ResolveDnsFuture = Pin<Box<dyn Future<Output = io::Result<Vec<IpAddr>>> + Send>>;
+
+/// Resolves the host names `HttpClient` connects to, set with `HttpConfig::dns_resolver`.
+pub trait ResolveDns: Send + Sync {
+    /// Resolve `host` to the addresses to try connecting to, in order.
+    fn resolve(&self, host: &str) -> ResolveDnsFuture;
+}
+
+impl<R: ResolveDns + ?Sized> ResolveDns for Arc<R> {
+    fn resolve(&self, host: &str) -> ResolveDnsFuture {
+        (**self).resolve(host)
+    }
+}
+
+/// The system resolver, `getaddrinfo`, run on a thread pool. Used unless another resolver is
+/// configured.
+#[derive(Clone, Debug)]
+pub struct SystemResolver {
+    inner: GaiResolver,
+}
+
+impl SystemResolver {
+    /// Create a `SystemResolver`.
+    pub fn new() -> SystemResolver {
+        SystemResolver {
+            inner: GaiResolver::new(),
+        }
+    }
+}
+
+impl Default for SystemResolver {
+    fn default() -> SystemResolver {
+        SystemResolver::new()
+    }
+}
+
+impl ResolveDns for SystemResolver {
+    fn resolve(&self, host: &str) -> ResolveDnsFuture {
+        let name = Name::from_str(host);
+        let mut inner = self.inner.clone();
+        Box::pin(async move {
+            let name = name.map_err(|e| io::Error::new(io::ErrorKind::InvalidInput, e))?;
+            Ok(inner.call(name).await?.collect())
+        })
+    }
+}
+
+/// A resolver answering from a fixed map of host names to addresses, e.g. to pin endpoints to
+/// PrivateLink addresses, and falling back to another resolver for other hosts.
+pub struct StaticResolver {
+    hosts: HashMap<String, Vec<IpAddr>>,
+    fallback: Option<Arc<dyn ResolveDns>>,
+}
+
+impl StaticResolver {
+    /// Create a static resolver falling back to the `SystemResolver`.
+    pub fn new() -> StaticResolver {
+        StaticResolver {
+            hosts: HashMap::new(),
+            fallback: Some(Arc::new(SystemResolver::new())),
+        }
+    }
+
+    /// Resolve `host`, compared case-insensitively, to `addrs`.
+    pub fn add_host(&mut self, host: &str, addrs: Vec<IpAddr>) {
+        self.hosts.insert(host.to_ascii_lowercase(), addrs);
+    }
+
+    /// Set the resolver used for hosts not in the map. With `None` they fail to resolve.
+    pub fn set_fallback<R: ResolveDns + 'static>(&mut self, fallback: Option<R>) {
+        self.fallback = fallback.map(|fallback| Arc::new(fallback) as Arc<dyn ResolveDns>);
+    }
+}
+
+impl Default for StaticResolver {
+    fn default() -> StaticResolver {
+        StaticResolver::new()
+    }
+}
+
+impl ResolveDns for StaticResolver {
+    fn resolve(&self, host: &str) -> ResolveDnsFuture {
+        if let Some(addrs) = self.hosts.get(&host.to_ascii_lowercase()) {
+            let addrs = addrs.clone();
+            return Box::pin(async move { Ok(addrs) });
+        }
+        match self.fallback {
+            Some(ref fallback) => fallback.resolve(host),
+            None => {
+                let message = format!("no address configured for {}", host);
+                Box::pin(async move { Err(io::Error::new(io::ErrorKind::NotFound, message)) })
+            }
+        }
+    }
+}
+
+/// Adapts a `ResolveDns` to the resolver interface of hyper's `HttpConnector`.
+#[derive(Clone)]
+pub struct DnsResolver {
+    resolver: Arc<dyn ResolveDns>,
+}
+
+impl DnsResolver {
+    /// Wrap `resolver`.
+    pub fn new<R: ResolveDns + 'static>(resolver: R) -> DnsResolver {
+        DnsResolver {
+            resolver: Arc::new(resolver),
+        }
+    }
+}
+
+impl Default for DnsResolver {
+    fn default() -> DnsResolver {
+        DnsResolver::new(SystemResolver::new())
+    }
+}
+
+impl Service<Name> for DnsResolver {
+    type Response = std::vec::IntoIter<IpAddr>;
+    type Error = io::Error;
+    type Future = Pin<Box<dyn Future<Output = io::Result<Self::Response>> + Send>>;
+
+    fn poll_ready(&mut self, _cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        Poll::Ready(Ok(()))
+    }
+
+    fn call(&mut self, name: Name) -> Self::Future {
+        let resolving = self.resolver.resolve(name.as_str());
+        Box::pin(async move { Ok(resolving.await?.into_iter()) })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn resolves_static_hosts() {
+        let addr: IpAddr = "10.0.0.12".parse().unwrap();
+        let mut resolver = StaticResolver::new();
+        resolver.add_host("bucket.vpce.amazonaws.com", vec![addr]);
+        resolver.set_fallback::<SystemResolver>(None);
+
+        let mut dns = DnsResolver::new(resolver);
+        let name = Name::from_str("Bucket.VPCE.amazonaws.com").unwrap();
+        let addrs: Vec<_> = dns.call(name).await.unwrap().collect();
+        assert_eq!(addrs, vec![addr]);
+
+        let name = Name::from_str("s3.amazonaws.com").unwrap();
+        let err = dns.call(name).await.unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::NotFound);
+    }
+}
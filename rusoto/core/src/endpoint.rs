@@ -0,0 +1,1197 @@
+//! Routing requests to custom endpoints.
+
+use std::collections::HashMap;
+use std::env;
+use std::sync::Arc;
+
+use crate::credential::ProfileProvider;
+use crate::region::Region;
+use crate::request::HttpResponse;
+use crate::signature::SignedRequest;
+
+/// Chooses the endpoint of every request a `Client` sends, e.g. to route traffic to VPC
+/// endpoints, LocalStack or an S3 compatible store from a single place.
+///
+/// Set with `Client::set_endpoint_resolver` or `ClientBuilder::endpoint_resolver`. The resolver
+/// is consulted once per operation, before the request hooks run and the request is signed.
+/// Closures taking the same arguments as `resolve_endpoint` and returning an `Option` of an
+/// `Endpoint` or a `String` implement this trait.
+pub trait EndpointResolver: Send + Sync {
+    /// The endpoint of `operation` of the service signing requests as `service` (e.g. `s3`) in
+    /// `region`, or `None` to keep the endpoint of the region.
+    fn resolve_endpoint(
+        &self,
+        service: &str,
+        region: &Region,
+        operation: Option<&str>,
+    ) -> Option<Endpoint>;
+}
+
+impl<F, E> EndpointResolver for F
+where
+    F: Fn(&str, &Region, Option<&str>) -> Option<E> + Send + Sync,
+    E: Into<Endpoint>,
+{
+    fn resolve_endpoint(
+        &self,
+        service: &str,
+        region: &Region,
+        operation: Option<&str>,
+    ) -> Option<Endpoint> {
+        self(service, region, operation).map(Into::into)
+    }
+}
+
+impl<R: EndpointResolver + ?Sized> EndpointResolver for Arc<R> {
+    fn resolve_endpoint(
+        &self,
+        service: &str,
+        region: &Region,
+        operation: Option<&str>,
+    ) -> Option<Endpoint> {
+        R::resolve_endpoint(self, service, region, operation)
+    }
+}
+
+/// An endpoint returned by an `EndpointResolver`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Endpoint {
+    url: String,
+    host: Option<Host>,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+enum Host {
+    Default,
+    Custom(String),
+}
+
+impl Endpoint {
+    /// Send requests to `url`, e.g. `http://localhost:4566`.
+    pub fn new<S: Into<String>>(url: S) -> Endpoint {
+        Endpoint {
+            url: url.into(),
+            host: None,
+        }
+    }
+
+    /// Send and sign `host` as the `Host` header of requests, instead of the host of the URL.
+    pub fn with_host<S: Into<String>>(mut self, host: S) -> Endpoint {
+        self.host = Some(Host::Custom(host.into()));
+        self
+    }
+
+    /// Send and sign the default hostname of the service, e.g. `sqs.us-east-1.amazonaws.com`, as
+    /// the `Host` header of requests, as interface VPC endpoints without private DNS require.
+    pub fn with_default_host(mut self) -> Endpoint {
+        self.host = Some(Host::Default);
+        self
+    }
+
+    /// The URL requests are sent to.
+    pub fn url(&self) -> &str {
+        &self.url
+    }
+}
+
+impl From<String> for Endpoint {
+    fn from(url: String) -> Endpoint {
+        Endpoint::new(url)
+    }
+}
+
+impl<'a> From<&'a str> for Endpoint {
+    fn from(url: &'a str) -> Endpoint {
+        Endpoint::new(url)
+    }
+}
+
+const SERVICE_VAR_PREFIX: &str = "AWS_ENDPOINT_URL_";
+
+/// The endpoints configured outside of the code, like the official AWS SDKs do:
+///
+/// 1. `AWS_ENDPOINT_URL_<SERVICE>` for a single service, named after the name requests of the
+///    service are signed with in upper case, e.g. `AWS_ENDPOINT_URL_DYNAMODB` or
+///    `AWS_ENDPOINT_URL_EXECUTE_API`
+/// 2. `AWS_ENDPOINT_URL` for all services
+/// 3. the `endpoint_url` key of the current profile in `~/.aws/config`
+///
+/// Every `Client` resolves the endpoints of its requests with these unless another
+/// `EndpointResolver` is set. Regions created with `Region::Custom` take precedence, and setting
+/// `AWS_IGNORE_CONFIGURED_ENDPOINT_URLS` to `true` ignores all of them.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct ConfiguredEndpoints {
+    services: HashMap<String, String>,
+    global: Option<String>,
+}
+
+impl ConfiguredEndpoints {
+    /// Read the endpoints from the environment and the current profile.
+    pub fn from_env() -> ConfiguredEndpoints {
+        let ignored = env::var("AWS_IGNORE_CONFIGURED_ENDPOINT_URLS")
+            .map(|value| value.eq_ignore_ascii_case("true"))
+            .unwrap_or(false);
+        if ignored {
+            return ConfiguredEndpoints::default();
+        }
+        let profile_endpoint = ProfileProvider::endpoint_url().ok().and_then(|url| url);
+        ConfiguredEndpoints::from_vars(env::vars(), profile_endpoint)
+    }
+
+    fn from_vars<I>(vars: I, profile_endpoint: Option<String>) -> ConfiguredEndpoints
+    where
+        I: IntoIterator<Item = (String, String)>,
+    {
+        let mut endpoints = ConfiguredEndpoints {
+            services: HashMap::new(),
+            global: profile_endpoint.filter(|endpoint| !endpoint.is_empty()),
+        };
+        for (name, value) in vars {
+            if value.is_empty() {
+                continue;
+            }
+            if name == "AWS_ENDPOINT_URL" {
+                endpoints.global = Some(value);
+            } else if let Some(service) = name.strip_prefix(SERVICE_VAR_PREFIX) {
+                endpoints.services.insert(service.to_owned(), value);
+            }
+        }
+        endpoints
+    }
+
+    /// Whether no endpoints are configured.
+    pub fn is_empty(&self) -> bool {
+        self.global.is_none() && self.services.is_empty()
+    }
+}
+
+impl EndpointResolver for ConfiguredEndpoints {
+    fn resolve_endpoint(
+        &self,
+        service: &str,
+        region: &Region,
+        _operation: Option<&str>,
+    ) -> Option<Endpoint> {
+        if let Region::Custom { .. } = *region {
+            return None;
+        }
+        let service = service.to_ascii_uppercase().replace('-', "_");
+        self.services
+            .get(&service)
+            .or(self.global.as_ref())
+            .map(|url| Endpoint::new(url.as_str()))
+    }
+}
+
+/// Send `request` to the endpoint chosen by `resolver`, keeping the name of its region for
+/// signing.
+pub(crate) fn resolve(resolver: &dyn EndpointResolver, request: &mut SignedRequest) {
+    let endpoint =
+        match resolver.resolve_endpoint(&request.service, &request.region, request.operation()) {
+            Some(endpoint) => endpoint,
+            None => return,
+        };
+    let default_host = request.hostname();
+    request.region = Region::Custom {
+        name: request.region.name().to_owned(),
+        endpoint: endpoint.url,
+    };
+    // Drop any hostname derived from the previous region, e.g. by an endpoint prefix.
+    request.set_hostname(None);
+    let host = match endpoint.host {
+        Some(Host::Default) => default_host,
+        Some(Host::Custom(host)) => host,
+        None => return,
+    };
+    request.set_connect_host(Some(request.hostname()));
+    request.set_hostname(Some(host));
+}
+
+/// The endpoint prefixes of the services with FIPS 140-2 validated endpoints, named
+/// `<prefix>-fips.<region>.amazonaws.com`.
+const FIPS_SERVICES: &[&str] = &[
+    "acm",
+    "acm-pca",
+    "apigateway",
+    "appstream2",
+    "athena",
+    "autoscaling",
+    "batch",
+    "cloudformation",
+    "cloudtrail",
+    "codebuild",
+    "codecommit",
+    "codedeploy",
+    "codepipeline",
+    "cognito-identity",
+    "cognito-idp",
+    "comprehend",
+    "config",
+    "datasync",
+    "dms",
+    "ds",
+    "dynamodb",
+    "ebs",
+    "ec2",
+    "ecr",
+    "ecs",
+    "eks",
+    "elasticache",
+    "elasticbeanstalk",
+    "elasticfilesystem",
+    "elasticloadbalancing",
+    "elasticmapreduce",
+    "es",
+    "events",
+    "firehose",
+    "fms",
+    "glacier",
+    "glue",
+    "guardduty",
+    "iam",
+    "inspector",
+    "kinesis",
+    "kms",
+    "lambda",
+    "logs",
+    "monitoring",
+    "outposts",
+    "ram",
+    "rds",
+    "redshift",
+    "rekognition",
+    "route53",
+    "runtime.sagemaker",
+    "secretsmanager",
+    "securityhub",
+    "servicecatalog",
+    "sms",
+    "snowball",
+    "sns",
+    "sqs",
+    "ssm",
+    "states",
+    "storagegateway",
+    "streams.dynamodb",
+    "sts",
+    "swf",
+    "transfer",
+    "translate",
+    "waf",
+    "waf-regional",
+    "workspaces",
+];
+
+/// Whether the setting `name` is enabled by the environment variable `var`, or otherwise by the
+/// key `name` of the current profile.
+fn flag_from_env(var: &str, name: &str) -> bool {
+    match setting_from_env(var, name) {
+        Some(value) => value.trim().eq_ignore_ascii_case("true"),
+        None => false,
+    }
+}
+
+/// The value of the environment variable `var`, or otherwise of the key `name` of the current
+/// profile.
+fn setting_from_env(var: &str, name: &str) -> Option<String> {
+    env::var(var)
+        .ok()
+        .filter(|value| !value.is_empty())
+        .or_else(|| {
+            ProfileProvider::config_value(name)
+                .ok()
+                .and_then(|value| value)
+        })
+}
+
+/// The variant of the default endpoints requests are sent to.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub(crate) struct EndpointVariant {
+    /// Use the FIPS endpoints of the services having them.
+    pub(crate) fips: bool,
+    /// Use the dual-stack endpoints, reachable over IPv4 and IPv6.
+    pub(crate) dual_stack: bool,
+}
+
+impl EndpointVariant {
+    /// The variant configured in the environment or the current profile.
+    pub(crate) fn from_env() -> EndpointVariant {
+        EndpointVariant {
+            fips: flag_from_env("AWS_USE_FIPS_ENDPOINT", "use_fips_endpoint"),
+            dual_stack: flag_from_env("AWS_USE_DUALSTACK_ENDPOINT", "use_dualstack_endpoint"),
+        }
+    }
+
+    /// Send `request` to this variant of its endpoint. Requests to `Region::Custom` are left
+    /// alone.
+    pub(crate) fn apply(self, request: &mut SignedRequest) {
+        if self == EndpointVariant::default() {
+            return;
+        }
+        if let Region::Custom { .. } = request.region {
+            return;
+        }
+        let region = request.region.name().to_owned();
+        if request.service == "s3" {
+            // S3 keeps the legacy `s3-<region>` hostnames, which have no variants.
+            let prefix = if self.fips { "s3-fips" } else { "s3" };
+            let dual_stack = if self.dual_stack { ".dualstack" } else { "" };
+            let hostname = format!("{}{}.{}.amazonaws.com", prefix, dual_stack, region);
+            request.set_hostname(Some(hostname));
+            return;
+        }
+        let hostname = request.hostname();
+        let end = match hostname.find(".amazonaws.com") {
+            Some(end) => end,
+            None => return,
+        };
+        let region_suffix = format!(".{}", region);
+        // `<prefix>.<region>.amazonaws.com`, the prefix may contain dots itself, or global
+        // endpoints like `iam.amazonaws.com`.
+        let (prefix, regional) = if hostname[..end].ends_with(&region_suffix) {
+            (&hostname[..end - region_suffix.len()], true)
+        } else {
+            (&hostname[..end], false)
+        };
+        let fips = if self.fips && FIPS_SERVICES.contains(&prefix) {
+            "-fips"
+        } else {
+            ""
+        };
+        let suffix = match (self.dual_stack && regional, &hostname[end..]) {
+            (true, ".amazonaws.com.cn") => {
+                format!("{}.api.amazonwebservices.com.cn", region_suffix)
+            }
+            (true, _) => format!("{}.api.aws", region_suffix),
+            (false, dns_suffix) if regional => format!("{}{}", region_suffix, dns_suffix),
+            (false, dns_suffix) => dns_suffix.to_owned(),
+        };
+        let variant_hostname = format!("{}{}{}", prefix, fips, suffix);
+        if variant_hostname != hostname {
+            request.set_hostname(Some(variant_hostname));
+        }
+    }
+}
+
+/// Whether requests are sent to the account-ID-based endpoints of the services having them, e.g.
+/// `111122223333.ddb.us-east-1.api.aws` for DynamoDB, which route requests of each account
+/// separately.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum AccountIdEndpointMode {
+    /// Use the account-ID-based endpoint if the credentials carry the account ID, and the
+    /// regional endpoint otherwise.
+    #[default]
+    Preferred,
+    /// Always use the regional endpoint.
+    Disabled,
+    /// Always use the account-ID-based endpoint, asking `sts:GetCallerIdentity` for the account
+    /// ID if the credentials don't carry it, and fail requests if it can't be determined.
+    Required,
+}
+
+impl AccountIdEndpointMode {
+    /// The mode configured by the `AWS_ACCOUNT_ID_ENDPOINT_MODE` environment variable, or
+    /// otherwise the `account_id_endpoint_mode` key of the current profile.
+    pub(crate) fn from_env() -> AccountIdEndpointMode {
+        setting_from_env("AWS_ACCOUNT_ID_ENDPOINT_MODE", "account_id_endpoint_mode")
+            .and_then(|value| value.parse().ok())
+            .unwrap_or_default()
+    }
+}
+
+impl std::str::FromStr for AccountIdEndpointMode {
+    type Err = String;
+
+    fn from_str(mode: &str) -> Result<Self, Self::Err> {
+        match mode.trim().to_ascii_lowercase().as_str() {
+            "preferred" => Ok(AccountIdEndpointMode::Preferred),
+            "disabled" => Ok(AccountIdEndpointMode::Disabled),
+            "required" => Ok(AccountIdEndpointMode::Required),
+            _ => Err(format!("invalid account ID endpoint mode: {}", mode)),
+        }
+    }
+}
+
+/// The regions whose STS requests `StsRegionalEndpoints::Legacy` sends to the global endpoint.
+const STS_GLOBAL_REGIONS: &[&str] = &[
+    "ap-northeast-1",
+    "ap-south-1",
+    "ap-southeast-1",
+    "ap-southeast-2",
+    "ca-central-1",
+    "eu-central-1",
+    "eu-north-1",
+    "eu-west-1",
+    "eu-west-2",
+    "eu-west-3",
+    "sa-east-1",
+    "us-east-1",
+    "us-east-2",
+    "us-west-1",
+    "us-west-2",
+];
+
+/// Whether STS requests are sent to the endpoint of their region, e.g.
+/// `sts.eu-west-1.amazonaws.com`, or to the global endpoint `sts.amazonaws.com`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum StsRegionalEndpoints {
+    /// Send requests to the endpoint of their region, which is faster and reachable from VPCs
+    /// with an STS interface endpoint only.
+    #[default]
+    Regional,
+    /// Send the requests of the regions that existed before regional STS endpoints to the
+    /// global endpoint in `us-east-1`, as the AWS SDKs used to.
+    Legacy,
+}
+
+impl StsRegionalEndpoints {
+    /// The setting of the `AWS_STS_REGIONAL_ENDPOINTS` environment variable, or otherwise the
+    /// `sts_regional_endpoints` key of the current profile.
+    pub(crate) fn from_env() -> StsRegionalEndpoints {
+        setting_from_env("AWS_STS_REGIONAL_ENDPOINTS", "sts_regional_endpoints")
+            .and_then(|value| value.parse().ok())
+            .unwrap_or_default()
+    }
+
+    /// Send `request` to the global endpoint if it is an STS request of a legacy region and
+    /// this is `Legacy`. Requests to `Region::Custom` are left alone.
+    pub(crate) fn apply(self, request: &mut SignedRequest) {
+        if self != StsRegionalEndpoints::Legacy
+            || request.service != "sts"
+            || request.hostname.is_some()
+            || !STS_GLOBAL_REGIONS.contains(&request.region.name())
+        {
+            return;
+        }
+        request.region = Region::UsEast1;
+        request.set_hostname(Some("sts.amazonaws.com".to_owned()));
+    }
+}
+
+impl std::str::FromStr for StsRegionalEndpoints {
+    type Err = String;
+
+    fn from_str(setting: &str) -> Result<Self, Self::Err> {
+        match setting.trim().to_ascii_lowercase().as_str() {
+            "regional" => Ok(StsRegionalEndpoints::Regional),
+            "legacy" => Ok(StsRegionalEndpoints::Legacy),
+            _ => Err(format!(
+                "invalid STS regional endpoints setting: {}",
+                setting
+            )),
+        }
+    }
+}
+
+/// Where S3 requests name their bucket: in the path of the URL or in its hostname.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum S3AddressingStyle {
+    /// Name the bucket in the path, `https://s3.<region>.amazonaws.com/<bucket>/<key>`, as S3
+    /// compatible stores like MinIO and Ceph expect.
+    #[default]
+    Path,
+    /// Name the bucket in the hostname, `https://<bucket>.s3.<region>.amazonaws.com/<key>`, as AWS
+    /// prefers now that path-style requests are deprecated. Buckets whose names are not valid
+    /// hostname labels, and buckets with dots in their names over HTTPS, which the wildcard
+    /// certificates of S3 don't cover, are still named in the path.
+    VirtualHosted,
+}
+
+impl S3AddressingStyle {
+    /// Move the bucket of an S3 `request` to its hostname if this is `VirtualHosted` and the
+    /// bucket can be named there.
+    pub(crate) fn apply(self, request: &mut SignedRequest) {
+        if self != S3AddressingStyle::VirtualHosted || request.service != "s3" {
+            return;
+        }
+        let (bucket, key) = match split_bucket(&request.path) {
+            Some(split) => split,
+            None => return,
+        };
+        if !is_virtual_hostable_bucket(bucket, request.scheme() == "https") {
+            return;
+        }
+        let hostname = format!("{}.{}", bucket, request.hostname());
+        request.path = format!("/{}", key);
+        request.set_hostname(Some(hostname));
+    }
+}
+
+impl std::str::FromStr for S3AddressingStyle {
+    type Err = String;
+
+    fn from_str(style: &str) -> Result<Self, Self::Err> {
+        match style.trim().to_ascii_lowercase().as_str() {
+            "path" => Ok(S3AddressingStyle::Path),
+            "virtual" | "virtual-hosted" => Ok(S3AddressingStyle::VirtualHosted),
+            _ => Err(format!("invalid S3 addressing style: {}", style)),
+        }
+    }
+}
+
+/// The bucket and the rest of the path of a path-style S3 request, `/<bucket>/<key>`, or
+/// `None` for requests naming no bucket like `ListBuckets`.
+pub(crate) fn split_bucket(path: &str) -> Option<(&str, &str)> {
+    let path = path.strip_prefix('/')?;
+    let (bucket, key) = match path.find('/') {
+        Some(slash) => (&path[..slash], &path[slash + 1..]),
+        None => (path, ""),
+    };
+    Some((bucket, key)).filter(|(bucket, _)| !bucket.is_empty())
+}
+
+/// Whether the bucket named `bucket` can be named in the hostname of requests: its name is
+/// made of lowercase hostname labels, is not an IP address and, over HTTPS, has no dots.
+pub(crate) fn is_virtual_hostable_bucket(bucket: &str, https: bool) -> bool {
+    if bucket.len() < 3 || bucket.len() > 63 || (https && bucket.contains('.')) {
+        return false;
+    }
+    if bucket.parse::<std::net::Ipv4Addr>().is_ok() {
+        return false;
+    }
+    bucket.split('.').all(|label| {
+        !label.is_empty()
+            && !label.starts_with('-')
+            && !label.ends_with('-')
+            && label
+                .bytes()
+                .all(|b| b.is_ascii_lowercase() || b.is_ascii_digit() || b == b'-')
+    })
+}
+
+/// The S3 operations on buckets themselves, which S3 Transfer Acceleration does not serve.
+const NON_ACCELERATED_OPERATIONS: &[&str] = &["CreateBucket", "DeleteBucket", "ListBuckets"];
+
+/// Send an S3 `request` through the S3 Transfer Acceleration endpoint of its bucket,
+/// `<bucket>.s3-accelerate.amazonaws.com`, or its dual-stack variant. Requests to
+/// `Region::Custom`, requests naming no bucket or a bucket that can't be accelerated and the
+/// operations on buckets themselves are left alone. Returns whether the request was changed.
+pub(crate) fn accelerate(request: &mut SignedRequest, dual_stack: bool) -> bool {
+    if request.service != "s3" || request.hostname.is_some() {
+        return false;
+    }
+    if let Region::Custom { .. } = request.region {
+        return false;
+    }
+    let operation = request.operation.as_deref().unwrap_or_default();
+    if NON_ACCELERATED_OPERATIONS.contains(&operation) {
+        return false;
+    }
+    let (bucket, key) = match split_bucket(&request.path) {
+        // Accelerated buckets can't have dots in their names, whatever the scheme.
+        Some((bucket, key)) if is_virtual_hostable_bucket(bucket, true) => (bucket, key),
+        _ => return false,
+    };
+    let dual_stack = if dual_stack { ".dualstack" } else { "" };
+    let hostname = format!("{}.s3-accelerate{}.amazonaws.com", bucket, dual_stack);
+    request.path = format!("/{}", key);
+    request.set_hostname(Some(hostname));
+    true
+}
+
+/// An S3 access point, Object Lambda access point or Multi-Region Access Point named by its ARN
+/// in place of a bucket, e.g. `arn:aws:s3:us-west-2:123456789012:accesspoint/my-access-point`.
+#[derive(Debug, PartialEq)]
+struct AccessPointArn<'a> {
+    partition: &'a str,
+    /// `s3`, or `s3-object-lambda` for Object Lambda access points.
+    service: &'a str,
+    /// Empty for Multi-Region Access Points.
+    region: &'a str,
+    account_id: &'a str,
+    /// The alias of Multi-Region Access Points, e.g. `mfzwi23gnjvgw.mrap`.
+    name: &'a str,
+}
+
+impl<'a> AccessPointArn<'a> {
+    /// The access point ARN at the start of `path` and the rest of `path`, e.g.
+    /// `/arn:aws:s3:us-west-2:123456789012:accesspoint/my-access-point/key`, or `None` if it
+    /// names a bucket.
+    fn split(path: &'a str) -> Option<(AccessPointArn<'a>, &'a str)> {
+        let mut parts = path.strip_prefix("/arn:")?.splitn(5, ':');
+        let partition = parts.next()?;
+        let service = parts.next()?;
+        let region = parts.next()?;
+        let account_id = parts.next()?;
+        let resource = parts.next()?.strip_prefix("accesspoint")?;
+        // `accesspoint/<name>` or `accesspoint:<name>`.
+        let resource = resource
+            .strip_prefix('/')
+            .or_else(|| resource.strip_prefix(':'))?;
+        let (name, rest) = match resource.find('/') {
+            Some(slash) => (&resource[..slash], &resource[slash..]),
+            None => (resource, "/"),
+        };
+        let multi_region = service == "s3" && region.is_empty();
+        let valid = (service == "s3" || service == "s3-object-lambda")
+            && (multi_region || !region.is_empty())
+            && account_id.len() == 12
+            && account_id.bytes().all(|b| b.is_ascii_digit())
+            && !name.is_empty()
+            && name
+                .bytes()
+                .all(|b| b.is_ascii_alphanumeric() || b == b'-' || (multi_region && b == b'.'));
+        if !valid {
+            return None;
+        }
+        let arn = AccessPointArn {
+            partition,
+            service,
+            region,
+            account_id,
+            name,
+        };
+        Some((arn, rest))
+    }
+
+    /// Whether this is a Multi-Region Access Point.
+    fn is_multi_region(&self) -> bool {
+        self.region.is_empty()
+    }
+
+    /// The hostname of this access point in `variant` of its endpoint. Multi-Region Access
+    /// Points have no variants.
+    fn hostname(&self, variant: EndpointVariant) -> String {
+        if self.is_multi_region() {
+            return format!("{}.{}", self.name, MULTI_REGION_ACCESS_POINT_DOMAIN);
+        }
+        let fips = if variant.fips { "-fips" } else { "" };
+        // Object Lambda access points have no dual-stack endpoints.
+        let dual_stack = if variant.dual_stack && self.service == "s3" {
+            ".dualstack"
+        } else {
+            ""
+        };
+        let prefix = if self.service == "s3" {
+            "s3-accesspoint"
+        } else {
+            "s3-object-lambda"
+        };
+        let domain = if self.partition == "aws-cn" {
+            "amazonaws.com.cn"
+        } else {
+            "amazonaws.com"
+        };
+        format!(
+            "{}-{}.{}{}{}.{}.{}",
+            self.name, self.account_id, prefix, fips, dual_stack, self.region, domain
+        )
+    }
+}
+
+/// The domain of the global endpoints of S3 Multi-Region Access Points.
+const MULTI_REGION_ACCESS_POINT_DOMAIN: &str = "accesspoint.s3-global.amazonaws.com";
+
+/// Send an S3 `request` naming an access point ARN in place of its bucket to the endpoint of
+/// the access point, in `variant`, signed for the region of the ARN, and for the
+/// `s3-object-lambda` service for Object Lambda access points. Requests to Multi-Region
+/// Access Points keep their region, they are signed for all regions with SigV4A, see
+/// `is_multi_region_access_point`. Requests to `Region::Custom` and those naming a bucket are
+/// left alone. Returns whether the request was changed.
+pub(crate) fn resolve_access_point(request: &mut SignedRequest, variant: EndpointVariant) -> bool {
+    if request.service != "s3" || request.hostname.is_some() {
+        return false;
+    }
+    if let Region::Custom { .. } = request.region {
+        return false;
+    }
+    let (hostname, region, service, path) = match AccessPointArn::split(&request.path) {
+        Some((arn, path)) if arn.is_multi_region() => (
+            arn.hostname(variant),
+            request.region.clone(),
+            arn.service.to_owned(),
+            path.to_owned(),
+        ),
+        Some((arn, path)) => match arn.region.parse::<Region>() {
+            Ok(region) => (
+                arn.hostname(variant),
+                region,
+                arn.service.to_owned(),
+                path.to_owned(),
+            ),
+            Err(_) => return false,
+        },
+        None => return false,
+    };
+    request.path = path;
+    request.region = region;
+    request.service = service;
+    request.set_hostname(Some(hostname));
+    true
+}
+
+/// Whether `request` is sent to an S3 Multi-Region Access Point, and so has to be signed with
+/// SigV4A for all regions.
+pub(crate) fn is_multi_region_access_point(request: &SignedRequest) -> bool {
+    request.service == "s3"
+        && request
+            .hostname
+            .as_deref()
+            .and_then(|hostname| hostname.strip_suffix(MULTI_REGION_ACCESS_POINT_DOMAIN))
+            .map_or(false, |alias| alias.ends_with('.'))
+}
+
+/// The bucket of an S3 `request` S3 could redirect to another region, or `None` for
+/// requests naming no bucket or an access point, or sent to `Region::Custom`.
+pub(crate) fn redirectable_bucket(request: &SignedRequest) -> Option<&str> {
+    if request.service != "s3" || request.hostname.is_some() {
+        return None;
+    }
+    if let Region::Custom { .. } = request.region {
+        return None;
+    }
+    split_bucket(&request.path)
+        .map(|(bucket, _)| bucket)
+        .filter(|bucket| !bucket.starts_with("arn:"))
+}
+
+/// The region of the bucket S3 redirects a request sent to `region` to with `response`, from
+/// its `x-amz-bucket-region` header, or `None` if it's no redirect.
+pub(crate) fn redirected_bucket_region(region: &Region, response: &HttpResponse) -> Option<Region> {
+    let redirect = matches!(response.status.as_u16(), 301 | 307 | 400);
+    if !redirect {
+        return None;
+    }
+    response
+        .headers
+        .get("x-amz-bucket-region")
+        .filter(|bucket_region| bucket_region.as_str() != region.name())
+        .and_then(|bucket_region| bucket_region.parse().ok())
+}
+
+/// Whether `request` could go to an account-ID-based endpoint: it is sent to the default
+/// endpoint of a service having them in a region where they exist.
+pub(crate) fn has_account_id_endpoint(request: &SignedRequest) -> bool {
+    if request.service != "dynamodb" || request.hostname.is_some() {
+        return false;
+    }
+    !matches!(
+        request.region,
+        Region::Custom { .. } | Region::CnNorth1 | Region::CnNorthwest1
+    )
+}
+
+/// Send `request` to the endpoint of `account_id`, see `has_account_id_endpoint`.
+pub(crate) fn use_account_id_endpoint(request: &mut SignedRequest, account_id: &str) {
+    let hostname = format!("{}.ddb.{}.api.aws", account_id, request.region.name());
+    request.set_hostname(Some(hostname));
+}
+
+/// The account ID in the XML response to `sts:GetCallerIdentity`.
+pub(crate) fn account_id_of_caller_identity(body: &[u8]) -> Option<String> {
+    let body = std::str::from_utf8(body).ok()?;
+    let start = body.find("<Account>")? + "<Account>".len();
+    let end = start + body[start..].find("</Account>")?;
+    Some(body[start..end].trim().to_owned()).filter(|account_id| !account_id.is_empty())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn routes_requests_to_resolved_endpoints() {
+        let resolver = |service: &str, _: &Region, operation: Option<&str>| match service {
+            "sqs" if operation != Some("ListQueues") => Some("http://localhost:4566".to_owned()),
+            _ => None,
+        };
+
+        let mut request = SignedRequest::new("POST", "sqs", &Region::EuWest1, "/");
+        request.set_operation("SendMessage");
+        request.set_endpoint_prefix("queue".to_owned());
+        resolve(&resolver, &mut request);
+        assert_eq!(request.region.name(), "eu-west-1");
+        assert_eq!(request.hostname(), "localhost:4566");
+        assert_eq!(request.scheme(), "http");
+
+        let mut request = SignedRequest::new("POST", "sqs", &Region::EuWest1, "/");
+        request.set_operation("ListQueues");
+        resolve(&resolver, &mut request);
+        assert_eq!(request.hostname(), "sqs.eu-west-1.amazonaws.com");
+    }
+
+    #[test]
+    fn keeps_host_headers_of_overridden_endpoints() {
+        let vpc_endpoint = "https://vpce-1a2b.sqs.eu-west-1.vpce.amazonaws.com";
+        let resolver = |_: &str, _: &Region, _: Option<&str>| {
+            Some(Endpoint::new(vpc_endpoint).with_default_host())
+        };
+        let mut request = SignedRequest::new("POST", "sqs", &Region::EuWest1, "/");
+        resolve(&resolver, &mut request);
+        assert_eq!(request.hostname(), "sqs.eu-west-1.amazonaws.com");
+        assert_eq!(
+            request.connect_host(),
+            "vpce-1a2b.sqs.eu-west-1.vpce.amazonaws.com"
+        );
+
+        let resolver = |_: &str, _: &Region, _: Option<&str>| {
+            Some(Endpoint::new("http://10.0.0.1:9000").with_host("storage.example.com"))
+        };
+        let mut request = SignedRequest::new("GET", "s3", &Region::EuWest1, "/bucket");
+        resolve(&resolver, &mut request);
+        assert_eq!(request.hostname(), "storage.example.com");
+        assert_eq!(request.connect_host(), "10.0.0.1:9000");
+        assert_eq!(request.scheme(), "http");
+    }
+
+    fn variant_hostname(service: &str, region: Region, fips: bool, dual_stack: bool) -> String {
+        let mut request = SignedRequest::new("GET", service, &region, "/");
+        EndpointVariant { fips, dual_stack }.apply(&mut request);
+        request.hostname()
+    }
+
+    #[test]
+    fn uses_fips_endpoints_of_services_having_them() {
+        let fips_hostname = |service, region| variant_hostname(service, region, true, false);
+        assert_eq!(
+            fips_hostname("sqs", Region::UsGovWest1),
+            "sqs-fips.us-gov-west-1.amazonaws.com"
+        );
+        assert_eq!(
+            fips_hostname("s3", Region::UsEast1),
+            "s3-fips.us-east-1.amazonaws.com"
+        );
+        assert_eq!(
+            fips_hostname("iam", Region::UsEast1),
+            "iam-fips.amazonaws.com"
+        );
+        assert_eq!(
+            fips_hostname("chime", Region::UsEast1),
+            "service.chime.aws.amazon.com"
+        );
+        assert_eq!(
+            fips_hostname("codestar", Region::UsEast2),
+            "codestar.us-east-2.amazonaws.com"
+        );
+
+        let mut request = SignedRequest::new("POST", "sagemaker", &Region::UsWest2, "/");
+        request.set_endpoint_prefix("runtime.sagemaker".to_owned());
+        EndpointVariant {
+            fips: true,
+            dual_stack: false,
+        }
+        .apply(&mut request);
+        assert_eq!(
+            request.hostname(),
+            "runtime.sagemaker-fips.us-west-2.amazonaws.com"
+        );
+    }
+
+    #[test]
+    fn uses_dual_stack_endpoints() {
+        assert_eq!(
+            variant_hostname("s3", Region::UsEast1, false, true),
+            "s3.dualstack.us-east-1.amazonaws.com"
+        );
+        assert_eq!(
+            variant_hostname("s3", Region::UsGovWest1, true, true),
+            "s3-fips.dualstack.us-gov-west-1.amazonaws.com"
+        );
+        assert_eq!(
+            variant_hostname("ec2", Region::EuWest1, false, true),
+            "ec2.eu-west-1.api.aws"
+        );
+        assert_eq!(
+            variant_hostname("ec2", Region::UsEast1, true, true),
+            "ec2-fips.us-east-1.api.aws"
+        );
+        assert_eq!(
+            variant_hostname("ec2", Region::CnNorth1, false, true),
+            "ec2.cn-north-1.api.amazonwebservices.com.cn"
+        );
+        assert_eq!(
+            variant_hostname("iam", Region::UsEast1, false, true),
+            "iam.amazonaws.com"
+        );
+    }
+
+    #[test]
+    fn resolves_configured_endpoints() {
+        let vars = vec![
+            (
+                "AWS_ENDPOINT_URL".to_owned(),
+                "http://localhost:4566".to_owned(),
+            ),
+            (
+                "AWS_ENDPOINT_URL_EXECUTE_API".to_owned(),
+                "http://localhost:4567".to_owned(),
+            ),
+            ("AWS_ENDPOINT_URL_S3".to_owned(), String::new()),
+            ("HOME".to_owned(), "/root".to_owned()),
+        ];
+        let endpoints =
+            ConfiguredEndpoints::from_vars(vars, Some("http://profile:4566".to_owned()));
+        let resolve = |service| endpoints.resolve_endpoint(service, &Region::UsEast1, None);
+        assert_eq!(
+            resolve("execute-api").unwrap().url(),
+            "http://localhost:4567"
+        );
+        assert_eq!(resolve("s3").unwrap().url(), "http://localhost:4566");
+        let custom = Region::Custom {
+            name: "local".to_owned(),
+            endpoint: "http://localhost:8000".to_owned(),
+        };
+        assert_eq!(endpoints.resolve_endpoint("s3", &custom, None), None);
+
+        let endpoints =
+            ConfiguredEndpoints::from_vars(Vec::new(), Some("http://profile:4566".to_owned()));
+        assert_eq!(
+            endpoints.resolve_endpoint("sqs", &Region::UsEast1, None),
+            Some(Endpoint::new("http://profile:4566"))
+        );
+        assert!(ConfiguredEndpoints::from_vars(Vec::new(), None).is_empty());
+    }
+
+    #[test]
+    fn uses_account_id_endpoints_of_dynamodb() {
+        let mut request = SignedRequest::new("POST", "dynamodb", &Region::EuWest1, "/");
+        assert!(has_account_id_endpoint(&request));
+        use_account_id_endpoint(&mut request, "111122223333");
+        assert_eq!(request.hostname(), "111122223333.ddb.eu-west-1.api.aws");
+        assert!(!has_account_id_endpoint(&request));
+
+        let request = SignedRequest::new("POST", "dynamodb", &Region::CnNorth1, "/");
+        assert!(!has_account_id_endpoint(&request));
+        let request = SignedRequest::new("POST", "sqs", &Region::EuWest1, "/");
+        assert!(!has_account_id_endpoint(&request));
+
+        assert_eq!(
+            "Required".parse::<AccountIdEndpointMode>(),
+            Ok(AccountIdEndpointMode::Required)
+        );
+        assert!("sometimes".parse::<AccountIdEndpointMode>().is_err());
+    }
+
+    #[test]
+    fn names_buckets_in_the_hostname_of_virtual_hosted_requests() {
+        let request = |region: &Region, path: &str| {
+            let mut request = SignedRequest::new("GET", "s3", region, path);
+            S3AddressingStyle::VirtualHosted.apply(&mut request);
+            (request.hostname(), request.path().to_owned())
+        };
+        assert_eq!(
+            request(&Region::EuWest1, "/bucket/dir/key"),
+            (
+                "bucket.s3-eu-west-1.amazonaws.com".to_owned(),
+                "/dir/key".to_owned()
+            )
+        );
+        assert_eq!(
+            request(&Region::UsEast1, "/bucket"),
+            ("bucket.s3.amazonaws.com".to_owned(), "/".to_owned())
+        );
+        // No bucket, and buckets that can't be named in hostnames.
+        assert_eq!(
+            request(&Region::UsEast1, "/"),
+            ("s3.amazonaws.com".to_owned(), "/".to_owned())
+        );
+        assert_eq!(
+            request(&Region::UsEast1, "/my.bucket/key"),
+            ("s3.amazonaws.com".to_owned(), "/my.bucket/key".to_owned())
+        );
+        assert_eq!(
+            request(&Region::UsEast1, "/My_Bucket/key"),
+            ("s3.amazonaws.com".to_owned(), "/My_Bucket/key".to_owned())
+        );
+        let minio = Region::Custom {
+            name: "us-east-1".to_owned(),
+            endpoint: "http://minio.local:9000".to_owned(),
+        };
+        assert_eq!(
+            request(&minio, "/my.bucket/key"),
+            ("my.bucket.minio.local:9000".to_owned(), "/key".to_owned())
+        );
+
+        let mut path_style = SignedRequest::new("GET", "s3", &Region::UsEast1, "/bucket/key");
+        S3AddressingStyle::Path.apply(&mut path_style);
+        assert_eq!(path_style.hostname(), "s3.amazonaws.com");
+        assert_eq!(path_style.path(), "/bucket/key");
+    }
+
+    #[test]
+    fn accelerates_object_requests_but_not_bucket_requests() {
+        let request = |operation: &str, path: &str, dual_stack: bool| {
+            let mut request = SignedRequest::new("GET", "s3", &Region::EuWest1, path);
+            request.set_operation(operation);
+            let accelerated = accelerate(&mut request, dual_stack);
+            (accelerated, request.hostname(), request.path().to_owned())
+        };
+        assert_eq!(
+            request("GetObject", "/bucket/key", false),
+            (
+                true,
+                "bucket.s3-accelerate.amazonaws.com".to_owned(),
+                "/key".to_owned()
+            )
+        );
+        assert_eq!(
+            request("ListObjectsV2", "/bucket", true),
+            (
+                true,
+                "bucket.s3-accelerate.dualstack.amazonaws.com".to_owned(),
+                "/".to_owned()
+            )
+        );
+        for (operation, path) in &[
+            ("CreateBucket", "/bucket"),
+            ("DeleteBucket", "/bucket"),
+            ("ListBuckets", "/"),
+            ("GetObject", "/my.bucket/key"),
+        ] {
+            assert_eq!(
+                request(operation, path, false),
+                (
+                    false,
+                    "s3-eu-west-1.amazonaws.com".to_owned(),
+                    (*path).to_owned()
+                )
+            );
+        }
+
+        let minio = Region::Custom {
+            name: "us-east-1".to_owned(),
+            endpoint: "http://minio.local:9000".to_owned(),
+        };
+        let mut request = SignedRequest::new("GET", "s3", &minio, "/bucket/key");
+        assert!(!accelerate(&mut request, false));
+        assert_eq!(request.hostname(), "minio.local:9000");
+    }
+
+    #[test]
+    fn sends_requests_naming_access_points_to_their_endpoints() {
+        let request = |path: &str, variant: EndpointVariant| {
+            let mut request = SignedRequest::new("GET", "s3", &Region::UsEast1, path);
+            assert!(resolve_access_point(&mut request, variant));
+            (
+                request.hostname(),
+                request.path().to_owned(),
+                request.region.clone(),
+                request.service,
+            )
+        };
+        assert_eq!(
+            request(
+                "/arn:aws:s3:us-west-2:123456789012:accesspoint/my-ap/dir/key",
+                EndpointVariant::default()
+            ),
+            (
+                "my-ap-123456789012.s3-accesspoint.us-west-2.amazonaws.com".to_owned(),
+                "/dir/key".to_owned(),
+                Region::UsWest2,
+                "s3".to_owned()
+            )
+        );
+        let dual_stack = EndpointVariant {
+            fips: false,
+            dual_stack: true,
+        };
+        assert_eq!(
+            request(
+                "/arn:aws-cn:s3:cn-north-1:123456789012:accesspoint:my-ap",
+                dual_stack
+            ),
+            (
+                "my-ap-123456789012.s3-accesspoint.dualstack.cn-north-1.amazonaws.com.cn"
+                    .to_owned(),
+                "/".to_owned(),
+                Region::CnNorth1,
+                "s3".to_owned()
+            )
+        );
+        assert_eq!(
+            request(
+                "/arn:aws:s3-object-lambda:eu-west-1:123456789012:accesspoint/my-olap/key",
+                EndpointVariant::default()
+            ),
+            (
+                "my-olap-123456789012.s3-object-lambda.eu-west-1.amazonaws.com".to_owned(),
+                "/key".to_owned(),
+                Region::EuWest1,
+                "s3-object-lambda".to_owned()
+            )
+        );
+
+        let mut request = SignedRequest::new(
+            "GET",
+            "s3",
+            &Region::UsEast1,
+            "/arn:aws:s3::123456789012:accesspoint/mfzwi23gnjvgw.mrap/key",
+        );
+        assert!(!is_multi_region_access_point(&request));
+        assert!(resolve_access_point(&mut request, dual_stack));
+        assert_eq!(
+            request.hostname(),
+            "mfzwi23gnjvgw.mrap.accesspoint.s3-global.amazonaws.com"
+        );
+        assert_eq!(request.path(), "/key");
+        assert_eq!(request.region, Region::UsEast1);
+        assert!(is_multi_region_access_point(&request));
+
+        for path in &[
+            "/bucket/key",
+            "/arn:aws:s3-object-lambda::123456789012:accesspoint/my-olap/key",
+            "/arn:aws:s3:us-west-2:123456789012:bucket/key",
+            "/arn:aws:s3:us-west-2:1234:accesspoint/my-ap/key",
+            "/arn:aws:sqs:us-west-2:123456789012:accesspoint/my-ap/key",
+        ] {
+            let mut request = SignedRequest::new("GET", "s3", &Region::UsEast1, path);
+            assert!(!resolve_access_point(
+                &mut request,
+                EndpointVariant::default()
+            ));
+            assert_eq!(request.path(), *path);
+        }
+    }
+
+    #[test]
+    fn sends_legacy_sts_requests_to_the_global_endpoint() {
+        let hostname = |setting: StsRegionalEndpoints, service, region| {
+            let mut request = SignedRequest::new("POST", service, &region, "/");
+            setting.apply(&mut request);
+            (request.hostname(), request.region.name().to_owned())
+        };
+        let legacy = StsRegionalEndpoints::Legacy;
+        assert_eq!(
+            hostname(legacy, "sts", Region::EuWest1),
+            ("sts.amazonaws.com".to_owned(), "us-east-1".to_owned())
+        );
+        assert_eq!(
+            hostname(legacy, "sts", Region::ApEast1),
+            (
+                "sts.ap-east-1.amazonaws.com".to_owned(),
+                "ap-east-1".to_owned()
+            )
+        );
+        assert_eq!(
+            hostname(legacy, "sqs", Region::EuWest1).0,
+            "sqs.eu-west-1.amazonaws.com"
+        );
+        assert_eq!(
+            hostname(StsRegionalEndpoints::Regional, "sts", Region::EuWest1).0,
+            "sts.eu-west-1.amazonaws.com"
+        );
+
+        assert_eq!(
+            " Legacy".parse::<StsRegionalEndpoints>(),
+            Ok(StsRegionalEndpoints::Legacy)
+        );
+        assert!("global".parse::<StsRegionalEndpoints>().is_err());
+    }
+
+    #[test]
+    fn parses_account_id_of_caller_identity() {
+        let body =
+            br#"<GetCallerIdentityResponse xmlns="https://sts.amazonaws.com/doc/2011-06-15/">
+  <GetCallerIdentityResult>
+    <Arn>arn:aws:iam::111122223333:user/alice</Arn>
+    <UserId>AIDACKCEVSQ6C2EXAMPLE</UserId>
+    <Account>111122223333</Account>
+  </GetCallerIdentityResult>
+</GetCallerIdentityResponse>"#;
+        assert_eq!(
+            account_id_of_caller_identity(body),
+            Some("111122223333".to_owned())
+        );
+        assert_eq!(account_id_of_caller_identity(b"<Error/>"), None);
+    }
+}
@@ -2,17 +2,21 @@ use std::error::Error;
 use std::fmt;
 use std::io;
 
+use http::StatusCode;
+use xml::reader::{EventReader, XmlEvent};
+
 use crate::credential::CredentialsError;
 
 use super::proto::xml::util::XmlParseError;
 use super::request::{BufferedHttpResponse, HttpDispatchError};
 use crate::client::SignAndDispatchError;
+use crate::retry::ErrorClass;
 
 /// Generic error type returned by all rusoto requests.
 #[derive(Debug, PartialEq)]
 pub enum RusotoError<E> {
-    /// A service-specific error occurred.
-    Service(E),
+    /// A service-specific error occurred, with the metadata of the response that carried it.
+    Service(E, ErrorMetadata),
     /// An error occurred dispatching the HTTP request
     HttpDispatch(HttpDispatchError),
     /// An error was encountered with AWS credentials.
@@ -25,11 +29,228 @@ pub enum RusotoError<E> {
     Unknown(BufferedHttpResponse),
     /// An error occurred when attempting to run a future as blocking
     Blocking,
+    /// The body of a response was longer than the limit, in bytes, set with
+    /// `Client::set_max_buffered_body_size`.
+    BodyTooLarge(usize),
 }
 
 /// Result carrying a generic `RusotoError`.
 pub type RusotoResult<T, E> = Result<T, RusotoError<E>>;
 
+/// Metadata of the error response a service-specific error was parsed from.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct ErrorMetadata {
+    code: Option<String>,
+    message: Option<String>,
+    request_id: Option<String>,
+    extended_request_id: Option<String>,
+    status: Option<StatusCode>,
+}
+
+impl ErrorMetadata {
+    /// Collect the metadata carried by an error response, whichever protocol the service uses.
+    pub fn from_response(res: &BufferedHttpResponse) -> ErrorMetadata {
+        let (code, message) = parse_code_and_message(res);
+        ErrorMetadata {
+            code,
+            message,
+            request_id: res.request_id().map(ToOwned::to_owned),
+            extended_request_id: res.extended_request_id().map(ToOwned::to_owned),
+            status: Some(res.status),
+        }
+    }
+
+    /// The metadata of an error reported without a response of its own, like the error
+    /// messages of event streams.
+    pub(crate) fn new(code: Option<String>, message: Option<String>) -> ErrorMetadata {
+        ErrorMetadata {
+            code,
+            message,
+            ..Default::default()
+        }
+    }
+
+    /// The AWS error code, such as `ThrottlingException` or `NoSuchKey`.
+    pub fn code(&self) -> Option<&str> {
+        self.code.as_deref()
+    }
+
+    /// The human readable error message returned by the service.
+    pub fn message(&self) -> Option<&str> {
+        self.message.as_deref()
+    }
+
+    /// The HTTP status code of the error response.
+    pub fn status(&self) -> Option<StatusCode> {
+        self.status
+    }
+
+    /// The AWS request ID, to quote when contacting AWS support.
+    pub fn request_id(&self) -> Option<&str> {
+        self.request_id.as_deref()
+    }
+
+    /// The extended request ID (`x-amz-id-2`) returned by S3.
+    pub fn extended_request_id(&self) -> Option<&str> {
+        self.extended_request_id.as_deref()
+    }
+}
+
+impl fmt::Display for ErrorMetadata {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match (self.code(), self.message()) {
+            (Some(code), Some(message)) => write!(f, "{}: {}", code, message),
+            (Some(text), None) | (None, Some(text)) => write!(f, "{}", text),
+            (None, None) => write!(f, "Unhandled service error"),
+        }
+    }
+}
+
+/// Extract the AWS error code and message from an error response.
+///
+/// JSON services return them as the `__type` (or `code`) and `message` fields of the body, or,
+/// for the code, in the `x-amzn-errortype` header; XML and query services as the first `Code`
+/// and `Message` elements of the body.
+pub(crate) fn parse_code_and_message(
+    res: &BufferedHttpResponse,
+) -> (Option<String>, Option<String>) {
+    let header_code = res
+        .headers
+        .get("x-amzn-errortype")
+        .and_then(|typ| typ.split(':').next())
+        .map(ToOwned::to_owned);
+    if let Ok(json) = serde_json::from_slice::<serde_json::Value>(&res.body) {
+        let field = |names: &[&str]| {
+            names
+                .iter()
+                .find_map(|name| json.get(*name))
+                .and_then(serde_json::Value::as_str)
+        };
+        let code = header_code.or_else(|| {
+            field(&["__type", "code", "Code"])
+                .and_then(|typ| typ.split('#').next_back())
+                .map(ToOwned::to_owned)
+        });
+        let message = field(&["message", "Message", "errorMessage"]).map(ToOwned::to_owned);
+        return (code, message);
+    }
+
+    let (mut code, mut message) = (None, None);
+    let mut element = None;
+    for event in EventReader::new(res.body.as_ref()) {
+        match event {
+            Ok(XmlEvent::StartElement { name, .. }) => element = Some(name.local_name),
+            Ok(XmlEvent::Characters(text)) => match element.as_deref() {
+                Some("Code") if code.is_none() => code = Some(text.trim().to_owned()),
+                Some("Message") if message.is_none() => message = Some(text.trim().to_owned()),
+                _ => {}
+            },
+            Ok(XmlEvent::EndElement { .. }) => element = None,
+            Err(_) => break,
+            _ => {}
+        }
+    }
+    (header_code.or(code), message)
+}
+
+impl<E> RusotoError<E> {
+    /// Construct a service-specific error parsed from the error response `res`.
+    pub fn service(err: E, res: &BufferedHttpResponse) -> RusotoError<E> {
+        RusotoError::Service(err, ErrorMetadata::from_response(res))
+    }
+
+    /// Construct a service-specific error for an error code the service definition doesn't model,
+    /// wrapping the metadata of `res` in the `Unhandled` variant of the error type.
+    pub fn unhandled<F>(variant: F, res: &BufferedHttpResponse) -> RusotoError<E>
+    where
+        F: FnOnce(ErrorMetadata) -> E,
+    {
+        let metadata = ErrorMetadata::from_response(res);
+        RusotoError::Service(variant(metadata.clone()), metadata)
+    }
+
+    /// The metadata of the error response this error was produced from.
+    ///
+    /// Only `Service` and `Unknown` errors carry a response; every other variant returns `None`.
+    pub fn metadata(&self) -> Option<ErrorMetadata> {
+        match *self {
+            RusotoError::Service(_, ref metadata) => Some(metadata.clone()),
+            RusotoError::Unknown(ref res) => Some(ErrorMetadata::from_response(res)),
+            _ => None,
+        }
+    }
+
+    /// The AWS error code of the response this error was produced from, e.g. `ThrottlingException`.
+    pub fn error_code(&self) -> Option<String> {
+        self.metadata().and_then(|metadata| metadata.code)
+    }
+
+    /// The kind of failure this error represents, as used by `RetryPolicy` to decide on retries.
+    ///
+    /// Errors are classified by their AWS error code first, using the same tables of codes as the
+    /// retry policy, and by the HTTP status of the response otherwise.
+    pub fn error_class(&self) -> Option<ErrorClass> {
+        match *self {
+            RusotoError::HttpDispatch(_) => Some(ErrorClass::Dispatch),
+            RusotoError::Service(_, ref metadata) => {
+                ErrorClass::from_code_and_status(metadata.code(), metadata.status())
+            }
+            RusotoError::Unknown(ref res) => ErrorClass::classify(res),
+            _ => None,
+        }
+    }
+
+    /// Whether retrying the request that failed with this error may succeed.
+    pub fn is_retryable(&self) -> bool {
+        self.error_class().is_some()
+    }
+
+    /// Whether the service throttled the request, asking the caller to slow down.
+    pub fn is_throttling(&self) -> bool {
+        self.error_class() == Some(ErrorClass::Throttling)
+    }
+
+    /// Whether the service failed with an error that is expected to go away on its own.
+    pub fn is_transient(&self) -> bool {
+        self.error_class() == Some(ErrorClass::Transient)
+    }
+
+    /// Whether the request itself was at fault: it failed validation, or the service rejected it
+    /// with a 4xx status other than throttling.
+    pub fn is_client_error(&self) -> bool {
+        let status = match *self {
+            RusotoError::Validation(_) => return true,
+            RusotoError::Service(_, ref metadata) => metadata.status(),
+            RusotoError::Unknown(ref res) => Some(res.status),
+            _ => None,
+        };
+        match status {
+            Some(status) => status.is_client_error() && !self.is_throttling(),
+            None => false,
+        }
+    }
+
+    /// The AWS request ID of the response this error was produced from.
+    ///
+    /// Only `Service` and `Unknown` errors carry a response; every other variant returns `None`.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            RusotoError::Service(_, ref metadata) => metadata.request_id(),
+            RusotoError::Unknown(ref res) => res.request_id(),
+            _ => None,
+        }
+    }
+
+    /// The extended request ID (`x-amz-id-2`) of the S3 response this error was produced from.
+    pub fn extended_request_id(&self) -> Option<&str> {
+        match *self {
+            RusotoError::Service(_, ref metadata) => metadata.extended_request_id(),
+            RusotoError::Unknown(ref res) => res.extended_request_id(),
+            _ => None,
+        }
+    }
+}
+
 impl<E> From<XmlParseError> for RusotoError<E> {
     fn from(err: XmlParseError) -> Self {
         let XmlParseError(message) = err;
@@ -51,7 +272,10 @@ impl<E> From<CredentialsError> for RusotoError<E> {
 
 impl<E> From<HttpDispatchError> for RusotoError<E> {
     fn from(err: HttpDispatchError) -> Self {
-        RusotoError::HttpDispatch(err)
+        match err.body_limit() {
+            Some(limit) => RusotoError::BodyTooLarge(limit),
+            None => RusotoError::HttpDispatch(err),
+        }
     }
 }
 
@@ -73,13 +297,16 @@ impl<E> From<io::Error> for RusotoError<E> {
 impl<E: Error + 'static> fmt::Display for RusotoError<E> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
-            RusotoError::Service(ref err) => write!(f, "{}", err),
+            RusotoError::Service(ref err, _) => write!(f, "{}", err),
             RusotoError::Validation(ref cause) => write!(f, "{}", cause),
             RusotoError::Credentials(ref err) => write!(f, "{}", err),
             RusotoError::HttpDispatch(ref dispatch_error) => write!(f, "{}", dispatch_error),
             RusotoError::ParseError(ref cause) => write!(f, "{}", cause),
             RusotoError::Unknown(ref cause) => write!(f, "{}", cause.body_as_str()),
             RusotoError::Blocking => write!(f, "Failed to run blocking future"),
+            RusotoError::BodyTooLarge(limit) => {
+                write!(f, "Response body exceeds the limit of {} bytes", limit)
+            }
         }
     }
 }
@@ -87,10 +314,129 @@ impl<E: Error + 'static> fmt::Display for RusotoError<E> {
 impl<E: Error + 'static> Error for RusotoError<E> {
     fn source(&self) -> Option<&(dyn Error + 'static)> {
         match *self {
-            RusotoError::Service(ref err) => Some(err),
+            RusotoError::Service(ref err, _) => Some(err),
             RusotoError::Credentials(ref err) => Some(err),
             RusotoError::HttpDispatch(ref err) => Some(err),
             _ => None,
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bytes::Bytes;
+    use http::{HeaderMap, StatusCode};
+
+    fn response() -> BufferedHttpResponse {
+        let mut headers = HeaderMap::default();
+        headers.insert("x-amzn-requestid", "req-1".to_owned());
+        BufferedHttpResponse {
+            status: StatusCode::BAD_REQUEST,
+            body: Bytes::new(),
+            headers,
+        }
+    }
+
+    #[test]
+    fn request_id_on_response_errors() {
+        let err = RusotoError::service("oops", &response());
+        assert_eq!(err.request_id(), Some("req-1"));
+        assert_eq!(err.extended_request_id(), None);
+
+        let err: RusotoError<()> = RusotoError::Unknown(response());
+        assert_eq!(err.request_id(), Some("req-1"));
+
+        let err: RusotoError<()> = RusotoError::Validation("invalid".to_owned());
+        assert_eq!(err.request_id(), None);
+    }
+
+    #[test]
+    fn classifies_errors() {
+        let mut res = response();
+        res.body = Bytes::from_static(br#"{"__type": "ThrottlingException"}"#);
+        let err = RusotoError::service("slow down", &res);
+        assert_eq!(err.error_code().as_deref(), Some("ThrottlingException"));
+        assert!(err.is_throttling() && err.is_retryable());
+        assert!(!err.is_transient() && !err.is_client_error());
+
+        res.body = Bytes::from_static(br#"{"__type": "ResourceNotFoundException"}"#);
+        let err: RusotoError<()> = RusotoError::Unknown(res);
+        assert!(err.is_client_error() && !err.is_retryable());
+
+        let mut res = response();
+        res.status = StatusCode::SERVICE_UNAVAILABLE;
+        let err = RusotoError::service("unavailable", &res);
+        assert!(err.is_transient() && err.is_retryable() && !err.is_client_error());
+
+        let err: RusotoError<()> = HttpDispatchError::new("reset".to_owned()).into();
+        assert_eq!(err.error_class(), Some(ErrorClass::Dispatch));
+        assert_eq!(err.error_code(), None);
+
+        let err: RusotoError<()> = HttpDispatchError::body_too_large(1024).into();
+        assert_eq!(err, RusotoError::BodyTooLarge(1024));
+        assert!(!err.is_retryable());
+
+        let err: RusotoError<()> = RusotoError::Validation("invalid".to_owned());
+        assert!(err.is_client_error() && !err.is_retryable());
+    }
+
+    #[test]
+    fn unhandled_error_keeps_metadata() {
+        #[derive(Debug, PartialEq)]
+        enum OperationError {
+            Unhandled(ErrorMetadata),
+        }
+
+        let mut res = response();
+        res.body = Bytes::from_static(br#"{"__type": "BrandNewException", "message": "New"}"#);
+        let err = RusotoError::unhandled(OperationError::Unhandled, &res);
+        match err {
+            RusotoError::Service(OperationError::Unhandled(ref metadata), _) => {
+                assert_eq!(metadata.to_string(), "BrandNewException: New");
+            }
+            ref err => panic!("unexpected error: {:?}", err),
+        }
+        assert_eq!(err.error_code().as_deref(), Some("BrandNewException"));
+    }
+
+    #[test]
+    fn metadata_from_xml_error() {
+        let res = BufferedHttpResponse {
+            status: StatusCode::FORBIDDEN,
+            body: Bytes::from_static(
+                b"<ErrorResponse><Error><Type>Sender</Type><Code>AccessDenied</Code>\
+                  <Message>Not allowed</Message></Error><RequestId>req-2</RequestId></ErrorResponse>",
+            ),
+            headers: HeaderMap::default(),
+        };
+        let metadata = ErrorMetadata::from_response(&res);
+        assert_eq!(metadata.code(), Some("AccessDenied"));
+        assert_eq!(metadata.message(), Some("Not allowed"));
+        assert_eq!(metadata.request_id(), Some("req-2"));
+        assert_eq!(metadata.status(), Some(StatusCode::FORBIDDEN));
+    }
+
+    #[test]
+    fn metadata_from_json_error() {
+        let mut res = response();
+        res.body = Bytes::from_static(
+            br#"{"__type": "com.amazon.coral.validate#ValidationException", "message": "Bad"}"#,
+        );
+        let metadata = ErrorMetadata::from_response(&res);
+        assert_eq!(metadata.code(), Some("ValidationException"));
+        assert_eq!(metadata.message(), Some("Bad"));
+        assert_eq!(metadata.request_id(), Some("req-1"));
+
+        res.headers.insert(
+            "x-amzn-errortype",
+            "NotFoundException:http://internal/".to_owned(),
+        );
+        res.body = Bytes::from_static(br#"{"Message": "Missing"}"#);
+        let err: RusotoError<()> = RusotoError::Unknown(res);
+        let metadata = err.metadata().unwrap();
+        assert_eq!(metadata.code(), Some("NotFoundException"));
+        assert_eq!(metadata.message(), Some("Missing"));
+        assert_eq!(metadata.status(), Some(StatusCode::BAD_REQUEST));
+    }
+}
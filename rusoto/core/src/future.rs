@@ -146,6 +146,7 @@ pub(crate) type RusotoHandlerFuture<T, E> =
     Pin<Box<dyn Future<Output = Result<T, RusotoError<E>>> + Send>>;
 
 impl<T, E> RusotoFuture<T, E> {
+    #[cfg(feature = "tokio")]
     /// Blocks the current thread until the future has resolved.
     ///
     /// This is meant to provide a simple way for non-async consumers
@@ -202,7 +203,7 @@ async fn rusoto_future_from_err() {
     use std::error::Error;
     let fut: RusotoFuture<i32, Box<dyn Error + Send + Sync>> = RusotoFuture::from(
         "ab".parse::<i32>()
-            .map_err(|e| RusotoError::Service(e.into())),
+            .map_err(|e| RusotoError::Service(e.into(), crate::ErrorMetadata::default())),
     );
     assert!(fut.await.is_err());
 }
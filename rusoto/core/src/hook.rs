@@ -0,0 +1,24 @@
+//! Changing requests before they are signed.
+
+use crate::signature::SignedRequest;
+
+/// Adds or changes the headers and query parameters of the requests a `Client` sends, e.g.
+/// `x-amz-expected-bucket-owner` or the headers required by an S3 compatible store.
+///
+/// Set with `Client::add_request_hook`, or `OperationConfig::add_request_hook` for the operations
+/// of a customized client. Hooks run once per operation in the order they were added, before the
+/// request is signed, so that the signature covers their changes. Closures taking a
+/// `&mut SignedRequest` implement this trait.
+pub trait RequestHook: Send + Sync {
+    /// Change `request` before it is signed and dispatched.
+    fn prepare(&self, request: &mut SignedRequest);
+}
+
+impl<F> RequestHook for F
+where
+    F: Fn(&mut SignedRequest) + Send + Sync,
+{
+    fn prepare(&self, request: &mut SignedRequest) {
+        self(request)
+    }
+}
@@ -19,25 +19,51 @@
 //! Rusoto is an [AWS](https://aws.amazon.com/) SDK for Rust.
 //! A high level overview is available in `README.md` at <https://github.com/rusoto/rusoto>.
 
-#[cfg(feature = "rustls")]
-use hyper_rustls as tls;
-#[cfg(feature = "native-tls")]
-use hyper_tls as tls;
-
+#[cfg(feature = "blocking")]
+mod blocking;
+mod builder;
+mod cancel;
 mod client;
+mod dispatch;
+#[cfg(feature = "tokio")]
+mod dns;
+mod endpoint;
 mod error;
 mod future;
+mod hook;
+mod metrics;
+mod presign;
+#[cfg(feature = "tokio")]
+mod proxy;
+mod runtime;
+mod shutdown;
 mod stream;
+#[cfg(feature = "tokio")]
+mod tls;
+#[cfg(target_arch = "wasm32")]
+mod wasm;
+#[cfg(feature = "tokio")]
+mod wire_log;
+mod xray;
 
+#[cfg(feature = "tower")]
+pub mod middleware;
+pub mod pagination;
 pub mod param;
 #[doc(hidden)]
 pub mod region;
 pub mod request;
+pub mod retry;
 #[doc(hidden)]
 pub mod signature;
+pub mod waiter;
 
 #[doc(hidden)]
-pub use crate::client::Client;
+#[cfg(feature = "blocking")]
+pub use crate::blocking::BlockingClient;
+pub use crate::builder::ClientBuilder;
+pub use crate::cancel::{CancellationToken, Cancelled};
+pub use crate::client::{Client, OperationConfig};
 #[doc(hidden)]
 pub mod encoding;
 #[doc(hidden)]
@@ -45,9 +71,42 @@ pub mod proto;
 #[doc(hidden)]
 pub mod serialization;
 
-pub use crate::error::{RusotoError, RusotoResult};
+pub use crate::dispatch::into_http_request;
+#[cfg(feature = "hyper1")]
+pub use crate::dispatch::{Hyper1Body, Hyper1Dispatcher};
+#[cfg(feature = "tokio")]
+pub use crate::dns::{DnsResolver, ResolveDns, ResolveDnsFuture, StaticResolver, SystemResolver};
+pub use crate::endpoint::{
+    AccountIdEndpointMode, ConfiguredEndpoints, Endpoint, EndpointResolver, S3AddressingStyle,
+    StsRegionalEndpoints,
+};
+pub use crate::error::{ErrorMetadata, RusotoError, RusotoResult};
 pub use crate::future::RusotoFuture;
-pub use crate::region::Region;
-pub use crate::request::{DispatchSignedRequest, HttpClient, HttpConfig, HttpDispatchError};
-pub use crate::stream::ByteStream;
+pub use crate::hook::RequestHook;
+pub use crate::metrics::{RequestMetrics, RequestMetricsObserver};
+pub use crate::presign::{PresignError, PresignedRequest, Presigner};
+#[cfg(feature = "tokio")]
+pub use crate::proxy::{Proxy, ProxyConfig, ProxyConnector, ProxyError, ProxyStream};
+pub use crate::region::{
+    default_region, EnvironmentRegionProvider, ProfileRegionProvider, ProvideRegion, Region,
+    RegionProviderChain,
+};
+#[cfg(feature = "tokio")]
+pub use crate::region::{ContainerRegionProvider, InstanceMetadataRegionProvider};
+pub use crate::request::{DispatchSignedRequest, HttpDispatchError, ResponseMetadata};
+#[cfg(feature = "tokio")]
+pub use crate::request::{HttpClient, HttpConfig, HttpVersion};
+pub use crate::retry::{RetryMode, RetryPolicy};
+#[cfg(feature = "async-std")]
+pub use crate::runtime::AsyncStdRuntime;
+#[cfg(feature = "tokio")]
+pub use crate::runtime::TokioRuntime;
+pub use crate::runtime::{Runtime, SleepFuture};
+pub use crate::shutdown::ShutdownFuture;
+pub use crate::stream::{ByteStream, ReplayableBody};
+#[cfg(feature = "tokio")]
+pub use crate::tls::{HttpsConnector, HttpsStream, TlsBackend, TlsConfig};
+#[cfg(target_arch = "wasm32")]
+pub use crate::wasm::{FetchClient, WasmRuntime};
+pub use crate::xray::{LambdaTraceId, ProvideTraceId, TraceHeader};
 pub use rusoto_credential as credential;
@@ -0,0 +1,84 @@
+//! Hooks for collecting metrics about the requests sent by a client.
+
+use std::sync::Arc;
+use std::time::Duration;
+
+use http::StatusCode;
+
+use crate::region::Region;
+
+/// Receives metrics about every operation sent through a `Client`.
+///
+/// Set with `Client::set_metrics_observer`. The observer is called once per operation, after the
+/// last attempt, from the task awaiting the operation, so it should not block.
+pub trait RequestMetricsObserver: Send + Sync {
+    /// Called when an operation has completed, successfully or not.
+    fn on_request_complete(&self, metrics: &RequestMetrics);
+}
+
+impl<O: RequestMetricsObserver + ?Sized> RequestMetricsObserver for Arc<O> {
+    fn on_request_complete(&self, metrics: &RequestMetrics) {
+        (**self).on_request_complete(metrics)
+    }
+}
+
+/// Metrics about a single operation, including all of its attempts.
+#[derive(Clone, Debug, PartialEq)]
+pub struct RequestMetrics {
+    pub(crate) service: String,
+    pub(crate) operation: Option<String>,
+    pub(crate) region: Region,
+    pub(crate) latency: Duration,
+    pub(crate) attempts: u32,
+    pub(crate) request_size: Option<u64>,
+    pub(crate) response_size: Option<u64>,
+    pub(crate) status: Option<StatusCode>,
+}
+
+impl RequestMetrics {
+    /// The signing name of the service called, e.g. `s3`.
+    pub fn service(&self) -> &str {
+        &self.service
+    }
+
+    /// The name of the operation, e.g. `ListBuckets`, if known.
+    pub fn operation(&self) -> Option<&str> {
+        self.operation.as_deref()
+    }
+
+    /// The region the request was sent to.
+    pub fn region(&self) -> &Region {
+        &self.region
+    }
+
+    /// The time from the first attempt being sent until the last response head, or error, was
+    /// received, including the delays between retries.
+    pub fn latency(&self) -> Duration {
+        self.latency
+    }
+
+    /// The number of attempts made.
+    pub fn attempts(&self) -> u32 {
+        self.attempts
+    }
+
+    /// The number of attempts made after the first one failed.
+    pub fn retries(&self) -> u32 {
+        self.attempts.saturating_sub(1)
+    }
+
+    /// The length of the request payload in bytes, if known.
+    pub fn request_size(&self) -> Option<u64> {
+        self.request_size
+    }
+
+    /// The length of the final response body in bytes, taken from its `Content-Length` header.
+    pub fn response_size(&self) -> Option<u64> {
+        self.response_size
+    }
+
+    /// The status code of the final response, or `None` if no response was received.
+    pub fn status(&self) -> Option<StatusCode> {
+        self.status
+    }
+}
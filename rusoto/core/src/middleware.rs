@@ -0,0 +1,200 @@
+//! Adapters between request dispatchers and [tower](https://docs.rs/tower) services.
+//!
+//! `DispatchService` turns any `DispatchSignedRequest` into a `tower_service::Service`, so that it
+//! can be wrapped with tower layers for rate limiting, caching, fault injection and so on.
+//! `TowerDispatcher` turns the resulting stack back into a `DispatchSignedRequest` that a `Client`
+//! can be created with:
+//!
+//! ```rust,ignore
+//! let service = ServiceBuilder::new()
+//!     .concurrency_limit(16)
+//!     .service(DispatchService::new(HttpClient::new()?));
+//! let client = Client::new_with(DefaultCredentialsProvider::new()?, TowerDispatcher::new(service));
+//! ```
+//!
+//! Requests reach the service stack once they have been signed, and once per attempt made by
+//! the client's `RetryPolicy`.
+
+use std::error::Error;
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::Arc;
+use std::task::{Context, Poll};
+use std::time::Duration;
+
+use futures::future;
+use http::{Request, Response};
+use tower_service::Service;
+
+use crate::request::{DispatchSignedRequest, DispatchSignedRequestFuture, HttpDispatchError};
+use crate::stream::ByteStream;
+
+/// A signed request on its way to being dispatched.
+#[derive(Debug)]
+pub struct DispatchRequest {
+    /// The HTTP request of the signed request
+    pub request: Request<ByteStream>,
+    /// The timeout the dispatcher should apply to the request, if any
+    pub timeout: Option<Duration>,
+}
+
+/// A `tower_service::Service` dispatching requests with a `DispatchSignedRequest`.
+pub struct DispatchService<D> {
+    dispatcher: Arc<D>,
+}
+
+impl<D> DispatchService<D> {
+    /// Wrap a request dispatcher, e.g. an `HttpClient`.
+    pub fn new(dispatcher: D) -> Self {
+        DispatchService {
+            dispatcher: Arc::new(dispatcher),
+        }
+    }
+}
+
+impl<D> Clone for DispatchService<D> {
+    fn clone(&self) -> Self {
+        DispatchService {
+            dispatcher: self.dispatcher.clone(),
+        }
+    }
+}
+
+impl<D: DispatchSignedRequest> Service<DispatchRequest> for DispatchService<D> {
+    type Response = Response<ByteStream>;
+    type Error = HttpDispatchError;
+    type Future = DispatchSignedRequestFuture;
+
+    fn poll_ready(&mut self, _cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        Poll::Ready(Ok(()))
+    }
+
+    fn call(&mut self, req: DispatchRequest) -> Self::Future {
+        self.dispatcher.dispatch(req.request, req.timeout)
+    }
+}
+
+/// A `DispatchSignedRequest` sending requests through a `tower_service::Service`.
+///
+/// The service is cloned for every request, so state that must be shared across requests, like
+/// a rate limit, has to live behind the clones, as it does for the `Buffer` layer.
+pub struct TowerDispatcher<S> {
+    service: S,
+}
+
+impl<S> TowerDispatcher<S> {
+    /// Dispatch requests through `service`.
+    pub fn new(service: S) -> Self {
+        TowerDispatcher { service }
+    }
+}
+
+impl<S> DispatchSignedRequest for TowerDispatcher<S>
+where
+    S: Service<DispatchRequest, Response = Response<ByteStream>> + Clone + Send + 'static,
+    S::Error: Into<Box<dyn Error + Send + Sync>>,
+    S::Future: Send,
+{
+    fn dispatch(
+        &self,
+        request: Request<ByteStream>,
+        timeout: Option<Duration>,
+    ) -> DispatchSignedRequestFuture {
+        let mut service = self.service.clone();
+        let future = async move {
+            future::poll_fn(|cx| service.poll_ready(cx))
+                .await
+                .map_err(into_dispatch_error)?;
+            service
+                .call(DispatchRequest { request, timeout })
+                .await
+                .map_err(into_dispatch_error)
+        };
+        Box::pin(future) as Pin<Box<dyn Future<Output = _> + Send>>
+    }
+}
+
+fn into_dispatch_error<E: Into<Box<dyn Error + Send + Sync>>>(err: E) -> HttpDispatchError {
+    match err.into().downcast::<HttpDispatchError>() {
+        Ok(err) => *err,
+        Err(err) => HttpDispatchError::new(err.to_string()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    use crate::client::SignAndDispatchError;
+    use crate::credential::StaticProvider;
+    use crate::signature::SignedRequest;
+    use crate::{Client, Region};
+
+    struct OkDispatcher;
+
+    impl DispatchSignedRequest for OkDispatcher {
+        fn dispatch(
+            &self,
+            _: Request<ByteStream>,
+            _: Option<Duration>,
+        ) -> DispatchSignedRequestFuture {
+            Box::pin(future::ready(Ok(Response::new(ByteStream::from(
+                Vec::new(),
+            )))))
+        }
+    }
+
+    /// Counts requests and fails every other one.
+    #[derive(Clone)]
+    struct Flaky<S> {
+        inner: S,
+        calls: Arc<AtomicUsize>,
+    }
+
+    impl<S> Service<DispatchRequest> for Flaky<S>
+    where
+        S: Service<DispatchRequest, Response = Response<ByteStream>, Error = HttpDispatchError>,
+        S::Future: Send + 'static,
+    {
+        type Response = Response<ByteStream>;
+        type Error = Box<dyn Error + Send + Sync>;
+        type Future = Pin<Box<dyn Future<Output = Result<Self::Response, Self::Error>> + Send>>;
+
+        fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+            self.inner.poll_ready(cx).map_err(Into::into)
+        }
+
+        fn call(&mut self, req: DispatchRequest) -> Self::Future {
+            if self.calls.fetch_add(1, Ordering::SeqCst) % 2 == 1 {
+                return Box::pin(future::ready(Err("injected fault".into())));
+            }
+            let response = self.inner.call(req);
+            Box::pin(async move { Ok(response.await?) })
+        }
+    }
+
+    #[tokio::test]
+    async fn dispatches_through_tower_services() {
+        let calls = Arc::new(AtomicUsize::new(0));
+        let service = Flaky {
+            inner: DispatchService::new(OkDispatcher),
+            calls: calls.clone(),
+        };
+        let mut client = Client::new_with(
+            StaticProvider::new_minimal("key".to_owned(), "secret".to_owned()),
+            TowerDispatcher::new(service),
+        );
+        let request = || SignedRequest::new("GET", "sqs", &Region::UsEast1, "/");
+
+        assert!(client.sign_and_dispatch(request()).await.is_ok());
+        client.set_retry_policy(crate::RetryPolicy::none());
+        match client.sign_and_dispatch(request()).await {
+            Err(SignAndDispatchError::Dispatch(err)) => {
+                assert!(err.to_string().contains("injected fault"))
+            }
+            _ => panic!("expected the injected fault"),
+        }
+        assert_eq!(calls.load(Ordering::SeqCst), 2);
+    }
+}
@@ -0,0 +1,115 @@
+//! Streams of the pages of paginated operations.
+//!
+//! The service clients have `_pages` methods for the operations botocore describes as
+//! paginated, returning the pages `paginate` requests one after the other, and `_stream`
+//! methods for those with a single list of results, flattening the pages with `items`.
+
+use std::future::Future;
+
+use futures::{stream, Stream, StreamExt};
+
+use crate::error::RusotoError;
+
+/// The outputs of `fetch` called with `client` and `input`, then with the inputs `next`
+/// returns for the last input and output, until it returns `None`.
+///
+/// A page is only requested once the previous one is read, and the stream ends after the first
+/// error.
+pub fn paginate<C, I, O, E, F, Fut, N>(
+    client: C,
+    input: I,
+    fetch: F,
+    next: N,
+) -> impl Stream<Item = Result<O, RusotoError<E>>> + Send
+where
+    C: Clone + Send,
+    I: Clone + Send,
+    O: Send,
+    E: Send,
+    F: Fn(C, I) -> Fut + Send,
+    Fut: Future<Output = Result<O, RusotoError<E>>> + Send,
+    N: Fn(&I, &O) -> Option<I> + Send,
+{
+    stream::unfold(
+        (client, fetch, next, Some(input)),
+        |(client, fetch, next, input)| async move {
+            let input = input?;
+            match fetch(client.clone(), input.clone()).await {
+                Ok(output) => {
+                    let following = next(&input, &output);
+                    Some((Ok(output), (client, fetch, next, following)))
+                }
+                Err(error) => Some((Err(error), (client, fetch, next, None))),
+            }
+        },
+    )
+}
+
+/// The items `items` takes out of each page of `pages`, in order, followed by the error ending
+/// `pages` if there is one.
+pub fn items<S, O, T, E, F>(pages: S, items: F) -> impl Stream<Item = Result<T, E>> + Send
+where
+    S: Stream<Item = Result<O, E>> + Send,
+    T: Send,
+    E: Send,
+    F: Fn(O) -> Vec<T> + Send,
+{
+    pages
+        .map(move |page| {
+            let page: Vec<_> = match page {
+                Ok(page) => items(page).into_iter().map(Ok).collect(),
+                Err(error) => vec![Err(error)],
+            };
+            stream::iter(page)
+        })
+        .flatten()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use futures::executor::block_on;
+    use futures::future::ready;
+
+    #[derive(Debug, PartialEq)]
+    struct NoError;
+
+    fn pages(
+        fail_at: Option<u32>,
+    ) -> impl Stream<Item = Result<(Vec<u32>, Option<u32>), RusotoError<NoError>>> + Send {
+        paginate(
+            (),
+            Some(0),
+            move |_, token: Option<u32>| {
+                let token = token.unwrap();
+                ready(if Some(token) == fail_at {
+                    Err(RusotoError::Service(NoError, Default::default()))
+                } else {
+                    Ok((
+                        vec![token * 2, token * 2 + 1],
+                        Some(token + 1).filter(|&t| t < 3),
+                    ))
+                })
+            },
+            |_, output: &(Vec<u32>, Option<u32>)| output.1.map(Some),
+        )
+    }
+
+    #[test]
+    fn requests_pages_until_there_is_no_next_input() {
+        let pages: Vec<_> = block_on(pages(None).collect());
+        assert_eq!(pages.len(), 3);
+        let items: Vec<_> = block_on(items(self::pages(None), |page| page.0).collect());
+        assert_eq!(
+            items.into_iter().map(Result::unwrap).collect::<Vec<_>>(),
+            vec![0, 1, 2, 3, 4, 5]
+        );
+    }
+
+    #[test]
+    fn ends_after_errors() {
+        let items: Vec<_> = block_on(items(pages(Some(1)), |page| page.0).collect());
+        assert_eq!(items.len(), 3);
+        assert!(items[2].is_err());
+    }
+}
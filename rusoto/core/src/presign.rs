@@ -0,0 +1,283 @@
+//! Presigned URLs of the operations of any service.
+//!
+//! Every generated client has a `presign_<operation>` method next to each of its operations,
+//! which builds the request the operation would send and presigns it with a [`Presigner`]
+//! through [`Client::presign`](crate::Client::presign). The endpoint of the request is resolved
+//! and the request hooks of the client run as if the request were sent.
+
+use std::error::Error;
+use std::fmt;
+use std::sync::Arc;
+use std::time::Duration;
+
+use percent_encoding::{utf8_percent_encode, AsciiSet, NON_ALPHANUMERIC};
+
+use crate::credential::{AwsCredentials, CredentialsError, ProvideAwsCredentials};
+use crate::signature::SignedRequest;
+
+/// The characters that are percent encoded in query string values.
+const QUERY_VALUE: &AsciiSet = &NON_ALPHANUMERIC
+    .remove(b'-')
+    .remove(b'.')
+    .remove(b'_')
+    .remove(b'~');
+
+/// Creates presigned URLs of the operations of any service client.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// let presigner = Presigner::new(DefaultCredentialsProvider::new()?)
+///     .expires_in(Duration::from_secs(300));
+/// let polly = PollyClient::new(Region::UsEast1);
+/// let presigned = polly.presign_synthesize_speech(&presigner, input).await?;
+/// println!("{} {}", presigned.method, presigned.url);
+/// ```
+pub struct Presigner {
+    credentials_provider: Arc<dyn ProvideAwsCredentials + Send + Sync>,
+    expires_in: Duration,
+    signed_headers: Vec<(String, String)>,
+    sign_payload: bool,
+    sign_session_token: bool,
+}
+
+impl Presigner {
+    /// Creates a presigner signing with the credentials of `credentials_provider`, whose URLs
+    /// expire after an hour.
+    pub fn new<P>(credentials_provider: P) -> Presigner
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+    {
+        Presigner {
+            credentials_provider: Arc::new(credentials_provider),
+            expires_in: Duration::from_secs(3600),
+            signed_headers: Vec::new(),
+            sign_payload: false,
+            sign_session_token: true,
+        }
+    }
+
+    /// Sets how long the presigned URLs are valid, at most seven days.
+    pub fn expires_in(mut self, expires_in: Duration) -> Presigner {
+        self.expires_in = expires_in;
+        self
+    }
+
+    /// Adds a header to sign, which the request sent with the URL has to carry unchanged.
+    pub fn signed_header(mut self, name: &str, value: &str) -> Presigner {
+        self.signed_headers
+            .push((name.to_ascii_lowercase(), value.to_owned()));
+        self
+    }
+
+    /// Sets whether the payload of the operation is signed, so the URL can only be used with
+    /// that payload. By default the payload is unsigned.
+    pub fn sign_payload(mut self, sign_payload: bool) -> Presigner {
+        self.sign_payload = sign_payload;
+        self
+    }
+
+    /// Sets whether the session token of temporary credentials is signed along with the rest of
+    /// the query string, the default, or appended after signing, as some services, like AWS IoT,
+    /// require.
+    pub fn sign_session_token(mut self, sign_session_token: bool) -> Presigner {
+        self.sign_session_token = sign_session_token;
+        self
+    }
+
+    /// Presigns a request with the credentials of the presigner.
+    pub async fn presign(&self, request: SignedRequest) -> Result<PresignedRequest, PresignError> {
+        let credentials = self
+            .credentials_provider
+            .credentials()
+            .await
+            .map_err(PresignError::Credentials)?;
+        Ok(self.presign_request(request, &credentials))
+    }
+
+    /// Presigns a request with `credentials`.
+    pub fn presign_request(
+        &self,
+        mut request: SignedRequest,
+        credentials: &AwsCredentials,
+    ) -> PresignedRequest {
+        for (name, value) in &self.signed_headers {
+            request.remove_header(name);
+            request.add_header(name, value);
+        }
+        let appended_token = match credentials.token() {
+            Some(token) if !self.sign_session_token => Some(token.clone()),
+            _ => None,
+        };
+        let mut url = if appended_token.is_some() {
+            let unscoped = AwsCredentials::new(
+                credentials.aws_access_key_id(),
+                credentials.aws_secret_access_key(),
+                None,
+                None,
+            );
+            request.generate_presigned_url(&unscoped, &self.expires_in, self.sign_payload)
+        } else {
+            request.generate_presigned_url(credentials, &self.expires_in, self.sign_payload)
+        };
+        if let Some(token) = appended_token {
+            url.push_str("&X-Amz-Security-Token=");
+            url.extend(utf8_percent_encode(&token, QUERY_VALUE));
+        }
+
+        let signed = request
+            .params
+            .get("X-Amz-SignedHeaders")
+            .cloned()
+            .flatten()
+            .unwrap_or_default();
+        let headers = signed
+            .split(';')
+            .filter(|name| !name.is_empty() && *name != "host")
+            .flat_map(|name| {
+                request
+                    .headers()
+                    .get(name)
+                    .into_iter()
+                    .flatten()
+                    .map(move |value| {
+                        (name.to_owned(), String::from_utf8_lossy(value).into_owned())
+                    })
+            })
+            .collect();
+        PresignedRequest {
+            method: request.method().to_owned(),
+            url,
+            headers,
+        }
+    }
+}
+
+impl fmt::Debug for Presigner {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Presigner")
+            .field("expires_in", &self.expires_in)
+            .field("signed_headers", &self.signed_headers)
+            .field("sign_payload", &self.sign_payload)
+            .field("sign_session_token", &self.sign_session_token)
+            .finish()
+    }
+}
+
+/// A presigned request, which anyone can send without credentials until it expires.
+#[derive(Clone, Debug, PartialEq)]
+pub struct PresignedRequest {
+    /// The method the request has to be sent with.
+    pub method: String,
+    /// The presigned URL.
+    pub url: String,
+    /// The signed headers, other than `host`, the request has to be sent with.
+    pub headers: Vec<(String, String)>,
+}
+
+/// An error presigning an operation.
+#[derive(Debug, PartialEq)]
+pub enum PresignError {
+    /// The credentials to sign with could not be loaded.
+    Credentials(CredentialsError),
+}
+
+impl fmt::Display for PresignError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PresignError::Credentials(e) => write!(f, "{}", e),
+        }
+    }
+}
+
+impl Error for PresignError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            PresignError::Credentials(e) => Some(e),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::credential::StaticProvider;
+    use crate::{Client, HttpClient, Region};
+
+    fn presigner(token: Option<&str>) -> Presigner {
+        Presigner::new(StaticProvider::new(
+            "AKIDEXAMPLE".to_owned(),
+            "secret".to_owned(),
+            token.map(str::to_owned),
+            None,
+        ))
+    }
+
+    fn synthesize_speech() -> SignedRequest {
+        let mut request = SignedRequest::new("POST", "polly", &Region::UsEast1, "/v1/speech");
+        request.set_payload(Some(b"{\"Text\":\"hello\"}".to_vec()));
+        request.add_header("x-amz-target", "SynthesizeSpeech");
+        request
+    }
+
+    #[tokio::test]
+    async fn presigns_the_request_of_an_operation() {
+        let presigned = presigner(Some("token"))
+            .expires_in(Duration::from_secs(300))
+            .signed_header("X-Custom", "value")
+            .presign(synthesize_speech())
+            .await
+            .unwrap();
+        assert_eq!(presigned.method, "POST");
+        assert!(presigned
+            .url
+            .starts_with("https://polly.us-east-1.amazonaws.com/v1/speech?"));
+        for param in &[
+            "X-Amz-Algorithm=AWS4-HMAC-SHA256",
+            "X-Amz-Credential=AKIDEXAMPLE%2F",
+            "X-Amz-Expires=300",
+            "X-Amz-Security-Token=token",
+            "X-Amz-SignedHeaders=host%3Bx-amz-target%3Bx-custom",
+            "X-Amz-Signature=",
+        ] {
+            assert!(presigned.url.contains(param), "{}", presigned.url);
+        }
+        assert_eq!(
+            presigned.headers,
+            vec![
+                ("x-amz-target".to_owned(), "SynthesizeSpeech".to_owned()),
+                ("x-custom".to_owned(), "value".to_owned()),
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn appends_unsigned_session_tokens() {
+        let presigned = presigner(Some("a/token+"))
+            .sign_session_token(false)
+            .presign(synthesize_speech())
+            .await
+            .unwrap();
+        let (signed, token) = presigned
+            .url
+            .split_at(presigned.url.find("&X-Amz-Security-Token=").unwrap());
+        assert!(!signed.contains("Security-Token"));
+        assert!(signed.contains("X-Amz-Signature="));
+        assert_eq!(token, "&X-Amz-Security-Token=a%2Ftoken%2B");
+    }
+
+    #[tokio::test]
+    async fn presigns_for_the_endpoints_of_clients() {
+        let mut client = Client::new_not_signing(HttpClient::new().unwrap());
+        client.set_endpoint_resolver(|_: &str, _: &Region, _: Option<&str>| {
+            Some("http://localhost:4566")
+        });
+        let presigned = client
+            .presign(&presigner(None), synthesize_speech())
+            .await
+            .unwrap();
+        assert!(presigned
+            .url
+            .starts_with("http://localhost:4566/v1/speech?"));
+    }
+}
@@ -0,0 +1,603 @@
+//! The `application/vnd.amazon.eventstream` framing of streamed responses, like the ones of S3
+//! `SelectObjectContent` and Kinesis `SubscribeToShard`, and of streamed requests, like the
+//! audio sent to Transcribe streaming.
+//!
+//! Every message is framed as a prelude with its total length, the length of its headers and
+//! the CRC32 of both, followed by the headers, the payload and the CRC32 of everything before.
+
+use std::error::Error;
+use std::fmt;
+use std::io;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
+use bytes::{Buf, BufMut, Bytes, BytesMut};
+use futures::{Stream, StreamExt};
+
+use crate::error::{parse_code_and_message, ErrorMetadata};
+use crate::request::BufferedHttpResponse;
+use crate::ByteStream;
+
+/// The prelude, the total length, the length of the headers and their CRC, and the trailing
+/// CRC of a message.
+const PRELUDE_LENGTH: usize = 12;
+const CRC_LENGTH: usize = 4;
+/// The largest message, 16 MiB, any longer prelude is corrupt.
+const MAX_MESSAGE_LENGTH: usize = 16 * 1024 * 1024;
+
+/// The value of a header of a message.
+#[derive(Clone, Debug, PartialEq)]
+pub enum HeaderValue {
+    Bool(bool),
+    Byte(i8),
+    Int16(i16),
+    Int32(i32),
+    Int64(i64),
+    ByteArray(Bytes),
+    String(String),
+    /// Milliseconds since the Unix epoch.
+    Timestamp(i64),
+    Uuid([u8; 16]),
+}
+
+impl HeaderValue {
+    /// The value of a `String` header.
+    pub fn as_str(&self) -> Option<&str> {
+        match *self {
+            HeaderValue::String(ref value) => Some(value),
+            _ => None,
+        }
+    }
+
+    fn encode(&self, buffer: &mut BytesMut) -> Result<(), EventStreamError> {
+        match *self {
+            HeaderValue::Bool(true) => buffer.put_u8(0),
+            HeaderValue::Bool(false) => buffer.put_u8(1),
+            HeaderValue::Byte(value) => {
+                buffer.put_u8(2);
+                buffer.put_i8(value);
+            }
+            HeaderValue::Int16(value) => {
+                buffer.put_u8(3);
+                buffer.put_i16(value);
+            }
+            HeaderValue::Int32(value) => {
+                buffer.put_u8(4);
+                buffer.put_i32(value);
+            }
+            HeaderValue::Int64(value) => {
+                buffer.put_u8(5);
+                buffer.put_i64(value);
+            }
+            HeaderValue::ByteArray(ref value) => {
+                buffer.put_u8(6);
+                put_u16_length(buffer, value.len())?;
+                buffer.put_slice(value);
+            }
+            HeaderValue::String(ref value) => {
+                buffer.put_u8(7);
+                put_u16_length(buffer, value.len())?;
+                buffer.put_slice(value.as_bytes());
+            }
+            HeaderValue::Timestamp(value) => {
+                buffer.put_u8(8);
+                buffer.put_i64(value);
+            }
+            HeaderValue::Uuid(ref value) => {
+                buffer.put_u8(9);
+                buffer.put_slice(value);
+            }
+        }
+        Ok(())
+    }
+}
+
+impl From<&str> for HeaderValue {
+    fn from(value: &str) -> HeaderValue {
+        HeaderValue::String(value.to_owned())
+    }
+}
+
+impl From<String> for HeaderValue {
+    fn from(value: String) -> HeaderValue {
+        HeaderValue::String(value)
+    }
+}
+
+/// A message of an event stream.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Message {
+    /// The headers of the message, in the order they were sent.
+    pub headers: Vec<(String, HeaderValue)>,
+    /// The payload of the message.
+    pub payload: Bytes,
+}
+
+impl Message {
+    /// A message with the headers `headers` and the payload `payload`.
+    pub fn new<P: Into<Bytes>>(headers: Vec<(String, HeaderValue)>, payload: P) -> Message {
+        Message {
+            headers,
+            payload: payload.into(),
+        }
+    }
+
+    /// An `event` message of the type `event_type` with the payload `payload`.
+    pub fn event<P: Into<Bytes>>(event_type: &str, payload: P) -> Message {
+        Message::new(
+            vec![
+                (":message-type".to_owned(), HeaderValue::from("event")),
+                (":event-type".to_owned(), HeaderValue::from(event_type)),
+            ],
+            payload,
+        )
+    }
+
+    /// This message with the header `name` added after its other headers.
+    pub fn with_header<V: Into<HeaderValue>>(mut self, name: &str, value: V) -> Message {
+        self.headers.push((name.to_owned(), value.into()));
+        self
+    }
+
+    /// The value of the header `name`.
+    pub fn header(&self, name: &str) -> Option<&HeaderValue> {
+        self.headers
+            .iter()
+            .find(|(header, _)| header == name)
+            .map(|(_, value)| value)
+    }
+
+    /// The value of the `String` header `name`.
+    pub fn str_header(&self, name: &str) -> Option<&str> {
+        self.header(name).and_then(HeaderValue::as_str)
+    }
+
+    /// The type of the message, `event`, `exception` or `error`, from its `:message-type`
+    /// header.
+    pub fn message_type(&self) -> Option<&str> {
+        self.str_header(":message-type")
+    }
+
+    /// The type of the event, e.g. `Records`, from its `:event-type` header.
+    pub fn event_type(&self) -> Option<&str> {
+        self.str_header(":event-type")
+    }
+
+    /// The type of the exception of an `exception` message, e.g. `ResourceNotFoundException`,
+    /// from its `:exception-type` header.
+    pub fn exception_type(&self) -> Option<&str> {
+        self.str_header(":exception-type")
+    }
+
+    /// The code and message of an `error` message, from its `:error-code` and `:error-message`
+    /// headers, or of an `exception` message, from its `:exception-type` header and the JSON or
+    /// XML error of its payload; `None` for other messages.
+    pub fn error_metadata(&self) -> Option<ErrorMetadata> {
+        match self.message_type()? {
+            "error" => Some(ErrorMetadata::new(
+                self.str_header(":error-code").map(ToOwned::to_owned),
+                self.str_header(":error-message").map(ToOwned::to_owned),
+            )),
+            "exception" => {
+                let (code, message) = parse_code_and_message(&BufferedHttpResponse {
+                    status: Default::default(),
+                    body: self.payload.clone(),
+                    headers: Default::default(),
+                });
+                Some(ErrorMetadata::new(
+                    self.exception_type().map(ToOwned::to_owned).or(code),
+                    message,
+                ))
+            }
+            _ => None,
+        }
+    }
+
+    /// The message framed with its prelude and checksums, as it is sent in an event stream.
+    ///
+    /// Fails if a header name is longer than 255 bytes, a header value than 65535 bytes, or the
+    /// message than 16 MiB.
+    pub fn encode(&self) -> Result<Bytes, EventStreamError> {
+        let mut headers = BytesMut::new();
+        for (name, value) in &self.headers {
+            if name.len() > u8::MAX as usize {
+                return Err(EventStreamError::new(format!(
+                    "Message header name {} is too long",
+                    name
+                )));
+            }
+            headers.put_u8(name.len() as u8);
+            headers.put_slice(name.as_bytes());
+            value.encode(&mut headers)?;
+        }
+        let total_length = PRELUDE_LENGTH + headers.len() + self.payload.len() + CRC_LENGTH;
+        if total_length > MAX_MESSAGE_LENGTH {
+            return Err(EventStreamError::new(format!(
+                "Invalid message length {}",
+                total_length
+            )));
+        }
+        let mut message = BytesMut::with_capacity(total_length);
+        message.put_u32(total_length as u32);
+        message.put_u32(headers.len() as u32);
+        message.put_u32(crc32(&message));
+        message.put_slice(&headers);
+        message.put_slice(&self.payload);
+        message.put_u32(crc32(&message));
+        Ok(message.freeze())
+    }
+}
+
+/// A malformed event stream, or a failure reading it.
+#[derive(Debug, PartialEq)]
+pub struct EventStreamError {
+    message: String,
+}
+
+impl EventStreamError {
+    fn new<S: Into<String>>(message: S) -> EventStreamError {
+        EventStreamError {
+            message: message.into(),
+        }
+    }
+}
+
+impl fmt::Display for EventStreamError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.message)
+    }
+}
+
+impl Error for EventStreamError {}
+
+impl From<io::Error> for EventStreamError {
+    fn from(err: io::Error) -> EventStreamError {
+        EventStreamError::new(err.to_string())
+    }
+}
+
+/// Decodes the messages of an event stream from the chunks of it pushed in.
+#[derive(Debug, Default)]
+pub struct MessageDecoder {
+    buffer: BytesMut,
+}
+
+impl MessageDecoder {
+    /// Create a decoder of an event stream.
+    pub fn new() -> MessageDecoder {
+        MessageDecoder::default()
+    }
+
+    /// Append the next chunk of the event stream.
+    pub fn push(&mut self, chunk: &[u8]) {
+        self.buffer.extend_from_slice(chunk);
+    }
+
+    /// Whether no part of a message is left to decode.
+    pub fn is_empty(&self) -> bool {
+        self.buffer.is_empty()
+    }
+
+    /// The next complete message pushed in, or `None` until the rest of it is.
+    pub fn next_message(&mut self) -> Result<Option<Message>, EventStreamError> {
+        if self.buffer.len() < PRELUDE_LENGTH {
+            return Ok(None);
+        }
+        let total_length = read_u32(&self.buffer[0..4]) as usize;
+        let headers_length = read_u32(&self.buffer[4..8]) as usize;
+        if crc32(&self.buffer[0..8]) != read_u32(&self.buffer[8..12]) {
+            return Err(EventStreamError::new("Prelude checksum mismatch"));
+        }
+        if total_length < PRELUDE_LENGTH + headers_length + CRC_LENGTH
+            || total_length > MAX_MESSAGE_LENGTH
+        {
+            return Err(EventStreamError::new(format!(
+                "Invalid message length {}",
+                total_length
+            )));
+        }
+        if self.buffer.len() < total_length {
+            return Ok(None);
+        }
+        let mut message = self.buffer.split_to(total_length).freeze();
+        let checksum = read_u32(&message[total_length - CRC_LENGTH..]);
+        if crc32(&message[..total_length - CRC_LENGTH]) != checksum {
+            return Err(EventStreamError::new("Message checksum mismatch"));
+        }
+        message.truncate(total_length - CRC_LENGTH);
+        message.advance(PRELUDE_LENGTH);
+        let payload = message.split_off(headers_length);
+        let headers = decode_headers(message)?;
+        Ok(Some(Message { headers, payload }))
+    }
+}
+
+/// The messages of the event stream of a response body.
+pub struct MessageStream {
+    body: ByteStream,
+    decoder: MessageDecoder,
+    done: bool,
+}
+
+impl MessageStream {
+    /// Decode the messages of `body`.
+    pub fn new(body: ByteStream) -> MessageStream {
+        MessageStream {
+            body,
+            decoder: MessageDecoder::new(),
+            done: false,
+        }
+    }
+}
+
+impl fmt::Debug for MessageStream {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("MessageStream")
+            .field("decoder", &self.decoder)
+            .field("done", &self.done)
+            .finish()
+    }
+}
+
+impl Stream for MessageStream {
+    type Item = Result<Message, EventStreamError>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        loop {
+            if self.done {
+                return Poll::Ready(None);
+            }
+            match self.decoder.next_message() {
+                Ok(Some(message)) => return Poll::Ready(Some(Ok(message))),
+                Ok(None) => {}
+                Err(err) => {
+                    self.done = true;
+                    return Poll::Ready(Some(Err(err)));
+                }
+            }
+            match futures::ready!(self.body.poll_next_unpin(cx)) {
+                Some(Ok(chunk)) => self.decoder.push(&chunk),
+                Some(Err(err)) => {
+                    self.done = true;
+                    return Poll::Ready(Some(Err(err.into())));
+                }
+                None => {
+                    self.done = true;
+                    if !self.decoder.is_empty() {
+                        return Poll::Ready(Some(Err(EventStreamError::new(
+                            "Event stream ended in the middle of a message",
+                        ))));
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// The event stream of `messages`, as a request body.
+///
+/// A message that can't be encoded ends the body with an error.
+pub fn encode_messages<S>(messages: S) -> ByteStream
+where
+    S: Stream<Item = Message> + Send + Sync + 'static,
+{
+    ByteStream::new(messages.map(|message| {
+        message
+            .encode()
+            .map_err(|err| io::Error::new(io::ErrorKind::InvalidInput, err))
+    }))
+}
+
+fn put_u16_length(buffer: &mut BytesMut, length: usize) -> Result<(), EventStreamError> {
+    if length > u16::MAX as usize {
+        return Err(EventStreamError::new(format!(
+            "Message header value of {} bytes is too long",
+            length
+        )));
+    }
+    buffer.put_u16(length as u16);
+    Ok(())
+}
+
+fn read_u32(bytes: &[u8]) -> u32 {
+    u32::from_be_bytes([bytes[0], bytes[1], bytes[2], bytes[3]])
+}
+
+fn crc32(bytes: &[u8]) -> u32 {
+    let mut hasher = crc32fast::Hasher::new();
+    hasher.update(bytes);
+    hasher.finalize()
+}
+
+fn decode_headers(mut bytes: Bytes) -> Result<Vec<(String, HeaderValue)>, EventStreamError> {
+    fn take(bytes: &mut Bytes, len: usize) -> Result<Bytes, EventStreamError> {
+        if bytes.len() < len {
+            return Err(EventStreamError::new("Truncated message headers"));
+        }
+        Ok(bytes.split_to(len))
+    }
+    fn utf8(bytes: Bytes) -> Result<String, EventStreamError> {
+        String::from_utf8(bytes.to_vec())
+            .map_err(|_| EventStreamError::new("Message header is not UTF-8"))
+    }
+
+    let mut headers = Vec::new();
+    while !bytes.is_empty() {
+        let name_length = take(&mut bytes, 1)?[0] as usize;
+        let name = utf8(take(&mut bytes, name_length)?)?;
+        let value = match take(&mut bytes, 1)?[0] {
+            0 => HeaderValue::Bool(true),
+            1 => HeaderValue::Bool(false),
+            2 => HeaderValue::Byte(take(&mut bytes, 1)?[0] as i8),
+            3 => HeaderValue::Int16(take(&mut bytes, 2)?.get_i16()),
+            4 => HeaderValue::Int32(take(&mut bytes, 4)?.get_i32()),
+            5 => HeaderValue::Int64(take(&mut bytes, 8)?.get_i64()),
+            kind @ 6 | kind @ 7 => {
+                let length = take(&mut bytes, 2)?.get_u16() as usize;
+                let value = take(&mut bytes, length)?;
+                if kind == 6 {
+                    HeaderValue::ByteArray(value)
+                } else {
+                    HeaderValue::String(utf8(value)?)
+                }
+            }
+            8 => HeaderValue::Timestamp(take(&mut bytes, 8)?.get_i64()),
+            9 => {
+                let mut uuid = [0; 16];
+                uuid.copy_from_slice(&take(&mut bytes, 16)?);
+                HeaderValue::Uuid(uuid)
+            }
+            kind => {
+                return Err(EventStreamError::new(format!(
+                    "Unknown message header type {}",
+                    kind
+                )))
+            }
+        };
+        headers.push((name, value));
+    }
+    Ok(headers)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use futures::TryStreamExt;
+
+    /// A message with the `String` headers `headers`, framed by hand.
+    fn frame(headers: &[(&str, &str)], payload: &[u8]) -> Vec<u8> {
+        let mut encoded_headers = Vec::new();
+        for (name, value) in headers {
+            encoded_headers.push(name.len() as u8);
+            encoded_headers.extend_from_slice(name.as_bytes());
+            encoded_headers.push(7);
+            encoded_headers.extend_from_slice(&(value.len() as u16).to_be_bytes());
+            encoded_headers.extend_from_slice(value.as_bytes());
+        }
+        let total_length = PRELUDE_LENGTH + encoded_headers.len() + payload.len() + CRC_LENGTH;
+        let mut message = Vec::new();
+        message.extend_from_slice(&(total_length as u32).to_be_bytes());
+        message.extend_from_slice(&(encoded_headers.len() as u32).to_be_bytes());
+        let prelude_crc = crc32(&message);
+        message.extend_from_slice(&prelude_crc.to_be_bytes());
+        message.extend_from_slice(&encoded_headers);
+        message.extend_from_slice(payload);
+        let message_crc = crc32(&message);
+        message.extend_from_slice(&message_crc.to_be_bytes());
+        message
+    }
+
+    #[test]
+    fn decodes_messages_pushed_in_pieces() {
+        let mut stream = frame(
+            &[(":message-type", "event"), (":event-type", "Records")],
+            b"a,b\n",
+        );
+        stream.extend(frame(&[(":event-type", "End")], b""));
+        let mut decoder = MessageDecoder::new();
+        let mut messages = Vec::new();
+        for byte in stream {
+            decoder.push(&[byte]);
+            if let Some(message) = decoder.next_message().unwrap() {
+                messages.push(message);
+            }
+        }
+        assert!(decoder.is_empty());
+        assert_eq!(messages.len(), 2);
+        assert_eq!(messages[0].message_type(), Some("event"));
+        assert_eq!(messages[0].event_type(), Some("Records"));
+        assert_eq!(&messages[0].payload[..], b"a,b\n");
+        assert_eq!(messages[1].event_type(), Some("End"));
+        assert!(messages[1].payload.is_empty());
+    }
+
+    #[test]
+    fn rejects_corrupt_messages() {
+        let mut message = frame(&[(":event-type", "Records")], b"payload");
+        let last = message.len() - 5;
+        message[last] ^= 1;
+        let mut decoder = MessageDecoder::new();
+        decoder.push(&message);
+        assert!(decoder.next_message().is_err());
+
+        let mut decoder = MessageDecoder::new();
+        decoder.push(&[0; PRELUDE_LENGTH]);
+        assert!(decoder.next_message().is_err());
+    }
+
+    #[test]
+    fn encodes_messages_as_they_are_decoded() {
+        let message = Message::event("AudioEvent", &b"audio"[..])
+            .with_header(":content-type", "application/octet-stream")
+            .with_header("bool", HeaderValue::Bool(false))
+            .with_header("byte", HeaderValue::Byte(-1))
+            .with_header("int16", HeaderValue::Int16(-300))
+            .with_header("int32", HeaderValue::Int32(70_000))
+            .with_header("int64", HeaderValue::Int64(-5_000_000_000))
+            .with_header(
+                "bytes",
+                HeaderValue::ByteArray(Bytes::from_static(b"\x00\x01")),
+            )
+            .with_header(":date", HeaderValue::Timestamp(1_600_000_000_000))
+            .with_header("uuid", HeaderValue::Uuid([7; 16]));
+        let encoded = message.encode().unwrap();
+        let mut decoder = MessageDecoder::new();
+        decoder.push(&encoded);
+        assert_eq!(decoder.next_message().unwrap(), Some(message));
+        assert!(decoder.is_empty());
+
+        let framed = frame(&[(":event-type", "End")], b"");
+        let message = Message::new(vec![(":event-type".to_owned(), "End".into())], Bytes::new());
+        assert_eq!(&message.encode().unwrap()[..], &framed[..]);
+
+        let long = Message::default().with_header(&"n".repeat(256), HeaderValue::Bool(true));
+        assert!(long.encode().is_err());
+    }
+
+    #[tokio::test]
+    async fn decodes_exceptions() {
+        let exception = Message::new(
+            vec![
+                (":message-type".to_owned(), "exception".into()),
+                (
+                    ":exception-type".to_owned(),
+                    "ResourceInUseException".into(),
+                ),
+            ],
+            &br#"{"message": "the shard is subscribed to"}"#[..],
+        );
+        let body = encode_messages(futures::stream::iter(vec![exception]));
+        let messages: Vec<Message> = MessageStream::new(body).try_collect().await.unwrap();
+        assert_eq!(messages[0].exception_type(), Some("ResourceInUseException"));
+        let metadata = messages[0].error_metadata().unwrap();
+        assert_eq!(metadata.code(), Some("ResourceInUseException"));
+        assert_eq!(metadata.message(), Some("the shard is subscribed to"));
+        assert_eq!(
+            Message::event("Records", Bytes::new()).error_metadata(),
+            None
+        );
+    }
+
+    #[tokio::test]
+    async fn decodes_the_messages_of_response_bodies() {
+        let error = frame(
+            &[
+                (":message-type", "error"),
+                (":error-code", "InternalError"),
+                (":error-message", "try again"),
+            ],
+            b"",
+        );
+        let body = ByteStream::from(error);
+        let messages: Vec<Message> = MessageStream::new(body).try_collect().await.unwrap();
+        let metadata = messages[0].error_metadata().unwrap();
+        assert_eq!(metadata.code(), Some("InternalError"));
+        assert_eq!(metadata.message(), Some("try again"));
+
+        let mut truncated = frame(&[(":event-type", "End")], b"");
+        truncated.pop();
+        let body = ByteStream::from(truncated);
+        let result: Result<Vec<Message>, _> = MessageStream::new(body).try_collect().await;
+        assert!(result.is_err());
+    }
+}
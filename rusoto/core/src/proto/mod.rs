@@ -1,2 +1,3 @@
+pub mod event_stream;
 pub mod json;
 pub mod xml;
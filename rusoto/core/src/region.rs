@@ -1,2 +1,302 @@
 // moved to rusoto_signature
 pub use rusoto_signature::region::*;
+
+use std::env;
+use std::str::FromStr;
+#[cfg(feature = "tokio")]
+use std::time::Duration;
+
+use async_trait::async_trait;
+
+use crate::credential::ProfileProvider;
+
+/// Resolves the region clients send their requests to.
+#[async_trait]
+pub trait ProvideRegion {
+    /// The region, `None` if this source doesn't configure one, or an error if it configures a
+    /// region that isn't known.
+    async fn region(&self) -> Result<Option<Region>, ParseRegionError>;
+}
+
+#[async_trait]
+impl ProvideRegion for Region {
+    async fn region(&self) -> Result<Option<Region>, ParseRegionError> {
+        Ok(Some(self.clone()))
+    }
+}
+
+/// Reads the region from the `AWS_REGION` environment variable, or else `AWS_DEFAULT_REGION`.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct EnvironmentRegionProvider;
+
+#[async_trait]
+impl ProvideRegion for EnvironmentRegionProvider {
+    async fn region(&self) -> Result<Option<Region>, ParseRegionError> {
+        for var in &["AWS_REGION", "AWS_DEFAULT_REGION"] {
+            if let Some(region) = env::var(var)
+                .ok()
+                .map_or(Ok(None), |name| parse_region(&name))?
+            {
+                return Ok(Some(region));
+            }
+        }
+        Ok(None)
+    }
+}
+
+/// Reads the region from the `region` key of the current profile, see `ProfileProvider::region`.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct ProfileRegionProvider;
+
+#[async_trait]
+impl ProvideRegion for ProfileRegionProvider {
+    async fn region(&self) -> Result<Option<Region>, ParseRegionError> {
+        match ProfileProvider::region() {
+            Ok(Some(name)) => parse_region(&name),
+            _ => Ok(None),
+        }
+    }
+}
+
+/// Reads the region of the task an ECS container runs in from the task metadata endpoint.
+#[cfg(feature = "tokio")]
+#[derive(Clone, Copy, Debug, Default)]
+pub struct ContainerRegionProvider;
+
+#[cfg(feature = "tokio")]
+#[async_trait]
+impl ProvideRegion for ContainerRegionProvider {
+    async fn region(&self) -> Result<Option<Region>, ParseRegionError> {
+        let base = match env::var("ECS_CONTAINER_METADATA_URI_V4")
+            .or_else(|_| env::var("ECS_CONTAINER_METADATA_URI"))
+        {
+            Ok(base) => base,
+            Err(_) => return Ok(None),
+        };
+        match metadata::get(&format!("{}/task", base)).await {
+            Some(task) => region_of_task(&task),
+            None => Ok(None),
+        }
+    }
+}
+
+/// Reads the region of the EC2 instance from the instance metadata service.
+#[cfg(feature = "tokio")]
+#[derive(Clone, Copy, Debug, Default)]
+pub struct InstanceMetadataRegionProvider;
+
+#[cfg(feature = "tokio")]
+#[async_trait]
+impl ProvideRegion for InstanceMetadataRegionProvider {
+    async fn region(&self) -> Result<Option<Region>, ParseRegionError> {
+        // IMDSv2 requires a session token, IMDSv1 is tried without one.
+        let token = metadata::put_token("http://169.254.169.254/latest/api/token").await;
+        let name = metadata::get_with_token(
+            "http://169.254.169.254/latest/meta-data/placement/region",
+            token.as_deref(),
+        )
+        .await;
+        name.map_or(Ok(None), |name| parse_region(&name))
+    }
+}
+
+/// Resolves the region from the first of its providers configuring one.
+///
+/// The default chain consults, in order:
+///
+/// 1. the `AWS_REGION` and `AWS_DEFAULT_REGION` environment variables
+/// 2. the `region` key of the current profile in `~/.aws/config`
+/// 3. the ECS task metadata endpoint
+/// 4. the EC2 instance metadata service
+///
+/// Unlike `Region::default`, which falls back to `Region::UsEast1`, resolving the region fails
+/// with `None` if no provider configures one, and with the error of the first provider
+/// configuring a region that isn't known rather than consulting the providers after it.
+pub struct RegionProviderChain {
+    providers: Vec<Box<dyn ProvideRegion + Send + Sync>>,
+}
+
+impl RegionProviderChain {
+    /// Create the default chain.
+    pub fn new() -> RegionProviderChain {
+        RegionProviderChain::with_providers(vec![
+            Box::new(EnvironmentRegionProvider),
+            Box::new(ProfileRegionProvider),
+            #[cfg(feature = "tokio")]
+            Box::new(ContainerRegionProvider),
+            #[cfg(feature = "tokio")]
+            Box::new(InstanceMetadataRegionProvider),
+        ])
+    }
+
+    /// Create a chain consulting `providers` in order.
+    pub fn with_providers(
+        providers: Vec<Box<dyn ProvideRegion + Send + Sync>>,
+    ) -> RegionProviderChain {
+        RegionProviderChain { providers }
+    }
+}
+
+impl Default for RegionProviderChain {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl std::fmt::Debug for RegionProviderChain {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.debug_struct("RegionProviderChain")
+            .field("providers", &self.providers.len())
+            .finish()
+    }
+}
+
+#[async_trait]
+impl ProvideRegion for RegionProviderChain {
+    async fn region(&self) -> Result<Option<Region>, ParseRegionError> {
+        for provider in &self.providers {
+            if let Some(region) = provider.region().await? {
+                return Ok(Some(region));
+            }
+        }
+        Ok(None)
+    }
+}
+
+/// Resolve the default region with the default `RegionProviderChain`.
+pub async fn default_region() -> Result<Option<Region>, ParseRegionError> {
+    RegionProviderChain::new().region().await
+}
+
+/// The region named `name`, `None` if it is blank, or an error if it isn't known.
+fn parse_region(name: &str) -> Result<Option<Region>, ParseRegionError> {
+    let name = name.trim();
+    if name.is_empty() {
+        return Ok(None);
+    }
+    Region::from_str(name).map(Some)
+}
+
+/// The region in the ARN of an ECS task, from the JSON returned by the task metadata endpoint.
+#[cfg(feature = "tokio")]
+fn region_of_task(task: &str) -> Result<Option<Region>, ParseRegionError> {
+    let arn = serde_json::from_str::<serde_json::Value>(task)
+        .ok()
+        .and_then(|task| {
+            task.get("TaskARN")?
+                .as_str()?
+                .split(':')
+                .nth(3)
+                .map(str::to_owned)
+        });
+    arn.map_or(Ok(None), |region| parse_region(&region))
+}
+
+#[cfg(feature = "tokio")]
+mod metadata {
+    use super::*;
+
+    use futures::StreamExt;
+    use hyper::{Body, Client, Method, Request};
+
+    /// Metadata endpoints answer in milliseconds, and aren't reachable at all elsewhere.
+    const TIMEOUT: Duration = Duration::from_secs(1);
+
+    /// The body of a successful response to a GET request to `uri`.
+    pub(super) async fn get(uri: &str) -> Option<String> {
+        get_with_token(uri, None).await
+    }
+
+    /// Like `get`, sending the IMDSv2 session `token` if there is one.
+    pub(super) async fn get_with_token(uri: &str, token: Option<&str>) -> Option<String> {
+        let mut request = Request::get(uri);
+        if let Some(token) = token {
+            request = request.header("x-aws-ec2-metadata-token", token);
+        }
+        send(request.body(Body::empty()).ok()?).await
+    }
+
+    /// An IMDSv2 session token from the token endpoint `uri`.
+    pub(super) async fn put_token(uri: &str) -> Option<String> {
+        let request = Request::builder()
+            .method(Method::PUT)
+            .uri(uri)
+            .header("x-aws-ec2-metadata-token-ttl-seconds", "60")
+            .body(Body::empty())
+            .ok()?;
+        send(request).await
+    }
+
+    async fn send(request: Request<Body>) -> Option<String> {
+        let body = async {
+            let response = Client::new().request(request).await.ok()?;
+            if !response.status().is_success() {
+                return None;
+            }
+            let mut body = response.into_body();
+            let mut bytes = Vec::new();
+            while let Some(chunk) = body.next().await {
+                bytes.extend_from_slice(&chunk.ok()?);
+            }
+            String::from_utf8(bytes).ok()
+        };
+        tokio::time::timeout(TIMEOUT, body).await.ok()?
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct NoRegion;
+
+    #[async_trait]
+    impl ProvideRegion for NoRegion {
+        async fn region(&self) -> Result<Option<Region>, ParseRegionError> {
+            Ok(None)
+        }
+    }
+
+    #[tokio::test]
+    async fn chain_returns_the_first_region() {
+        let chain = RegionProviderChain::with_providers(vec![
+            Box::new(NoRegion),
+            Box::new(Region::EuWest1),
+            Box::new(Region::UsEast2),
+        ]);
+        assert_eq!(chain.region().await, Ok(Some(Region::EuWest1)));
+
+        let chain = RegionProviderChain::with_providers(vec![Box::new(NoRegion)]);
+        assert_eq!(chain.region().await, Ok(None));
+    }
+
+    struct UnknownRegion;
+
+    #[async_trait]
+    impl ProvideRegion for UnknownRegion {
+        async fn region(&self) -> Result<Option<Region>, ParseRegionError> {
+            parse_region("mars-north-1")
+        }
+    }
+
+    #[tokio::test]
+    async fn chain_stops_at_unknown_regions() {
+        let chain = RegionProviderChain::with_providers(vec![
+            Box::new(NoRegion),
+            Box::new(UnknownRegion),
+            Box::new(Region::UsEast2),
+        ]);
+        assert_eq!(
+            chain.region().await,
+            Err(ParseRegionError::new("mars-north-1"))
+        );
+    }
+
+    #[test]
+    fn parses_region_of_ecs_task() {
+        let task = r#"{"Cluster": "default", "TaskARN": "arn:aws:ecs:eu-central-1:111122223333:task/default/abc"}"#;
+        assert_eq!(region_of_task(task), Ok(Some(Region::EuCentral1)));
+        assert_eq!(region_of_task(r#"{"Cluster": "default"}"#), Ok(None));
+        assert_eq!(parse_region(" "), Ok(None));
+    }
+}
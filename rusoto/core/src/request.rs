@@ -6,7 +6,6 @@ use std::env;
 use std::error::Error;
 use std::fmt;
 use std::future::Future;
-use std::io;
 use std::io::Error as IoError;
 use std::pin::Pin;
 use std::rc::Rc;
@@ -14,23 +13,36 @@ use std::sync::Arc;
 use std::time::Duration;
 
 use bytes::{Bytes, BytesMut};
-use futures::{FutureExt, StreamExt};
+use futures::StreamExt;
 use http::header::{HeaderName, HeaderValue};
-use http::{HeaderMap, Method, StatusCode};
-use hyper::client::connect::Connect;
-use hyper::client::Builder as HyperBuilder;
-use hyper::client::HttpConnector;
+use http::{HeaderMap, Method, Request, Response, StatusCode};
 use hyper::Error as HyperError;
-use hyper::{Body, Client as HyperClient, Request as HyperRequest, Response as HyperResponse};
 use lazy_static::lazy_static;
-use tokio::time;
-
-use log::Level::Debug;
-use log::*;
 
+use crate::shutdown::ShutdownFuture;
 use crate::signature::SignedRequest;
 use crate::stream::ByteStream;
-use crate::tls::HttpsConnector;
+
+#[cfg(feature = "tokio")]
+use {
+    crate::dns::{DnsResolver, ResolveDns},
+    crate::proxy::{ProxyConfig, ProxyConnector},
+    crate::shutdown::Drain,
+    crate::tls::{HttpsConnector, TlsConfig},
+    crate::wire_log,
+    futures::{FutureExt, Stream},
+    hyper::client::{connect::Connect, Builder as HyperBuilder, HttpConnector},
+    hyper::{Body, Client as HyperClient},
+    log::{Level::Debug, *},
+    std::io,
+    std::sync::Mutex,
+    std::task::{Context, Poll},
+    tokio::time,
+};
+
+#[cfg(feature = "tokio")]
+pub use crate::tls::TlsError;
+use crate::xray::{TraceHeader, TRACE_ID_HEADER};
 
 // Pulls in the statically generated rustc version.
 include!(concat!(env!("OUT_DIR"), "/user_agent_vars.rs"));
@@ -38,7 +50,7 @@ include!(concat!(env!("OUT_DIR"), "/user_agent_vars.rs"));
 // Use a lazy static to cache the default User-Agent header
 // because it never changes once it's been computed.
 lazy_static! {
-    static ref DEFAULT_USER_AGENT: String = format!(
+    pub(crate) static ref DEFAULT_USER_AGENT: String = format!(
         "rusoto/{} rust/{} {}",
         env!("CARGO_PKG_VERSION"),
         RUST_VERSION,
@@ -75,6 +87,92 @@ impl BufferedHttpResponse {
             _ => "unknown error",
         }
     }
+
+    /// The AWS request ID, taken from the response headers or, for XML error
+    /// responses that only carry it in the body, from the `RequestId` element.
+    pub fn request_id(&self) -> Option<&str> {
+        header_request_id(&self.headers).or_else(|| {
+            let body = std::str::from_utf8(&self.body).ok()?;
+            ["RequestId", "RequestID"]
+                .iter()
+                .find_map(|tag| xml_element_text(body, tag))
+        })
+    }
+
+    /// The extended request ID (`x-amz-id-2`) returned by S3.
+    pub fn extended_request_id(&self) -> Option<&str> {
+        self.headers
+            .get(EXTENDED_REQUEST_ID_HEADER)
+            .map(String::as_str)
+    }
+}
+
+/// Headers AWS services use to return the ID of a request.
+const REQUEST_ID_HEADERS: &[&str] = &["x-amzn-requestid", "x-amz-request-id"];
+
+/// Header S3 uses to return the extended ID of a request.
+const EXTENDED_REQUEST_ID_HEADER: &str = "x-amz-id-2";
+
+fn header_request_id(headers: &HeaderMap<String>) -> Option<&str> {
+    REQUEST_ID_HEADERS
+        .iter()
+        .find_map(|name| headers.get(*name))
+        .map(String::as_str)
+}
+
+/// Text of the first `<tag>` element in `body`, without parsing the document.
+fn xml_element_text<'a>(body: &'a str, tag: &str) -> Option<&'a str> {
+    let start = body.find(&format!("<{}>", tag))? + tag.len() + 2;
+    let end = body[start..].find("</")? + start;
+    Some(body[start..end].trim())
+}
+
+/// Metadata AWS returns alongside the output of a successful request: the status and headers
+/// of the response, including those not modeled in the output, e.g. `ETag` or `Cache-Control`.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct ResponseMetadata {
+    status: StatusCode,
+    headers: HeaderMap<String>,
+}
+
+impl ResponseMetadata {
+    /// Collect the metadata carried by the status and headers of `response`.
+    pub fn from_response(response: &HttpResponse) -> ResponseMetadata {
+        ResponseMetadata {
+            status: response.status,
+            headers: response.headers.clone(),
+        }
+    }
+
+    /// The status code of the response.
+    pub fn status(&self) -> StatusCode {
+        self.status
+    }
+
+    /// The headers of the response.
+    pub fn headers(&self) -> &HeaderMap<String> {
+        &self.headers
+    }
+
+    /// The first value of the response header `name`, e.g. `x-amz-version-id`.
+    pub fn header(&self, name: &str) -> Option<&str> {
+        self.headers.get(name).map(String::as_str)
+    }
+
+    /// The AWS request ID, to quote when contacting AWS support.
+    pub fn request_id(&self) -> Option<&str> {
+        header_request_id(&self.headers)
+    }
+
+    /// The extended request ID (`x-amz-id-2`) returned by S3.
+    pub fn extended_request_id(&self) -> Option<&str> {
+        self.header(EXTENDED_REQUEST_ID_HEADER)
+    }
+
+    /// The X-Ray trace context returned in the `X-Amzn-Trace-Id` header, if any.
+    pub fn trace_header(&self) -> Option<TraceHeader> {
+        self.header(TRACE_ID_HEADER).map(TraceHeader::parse)
+    }
 }
 
 /// Best effort based Debug implementation to make generic error's body more readable.
@@ -96,13 +194,44 @@ impl fmt::Debug for BufferedHttpResponse {
 }
 
 impl HttpResponse {
+    /// The AWS request ID returned in the response headers.
+    pub fn request_id(&self) -> Option<&str> {
+        header_request_id(&self.headers)
+    }
+
+    /// The extended request ID (`x-amz-id-2`) returned by S3.
+    pub fn extended_request_id(&self) -> Option<&str> {
+        self.headers
+            .get(EXTENDED_REQUEST_ID_HEADER)
+            .map(String::as_str)
+    }
+
     /// Buffer the full response body in memory, resulting in a `BufferedHttpResponse`.
     pub async fn buffer(&mut self) -> Result<BufferedHttpResponse, HttpDispatchError> {
+        self.buffer_with_limit(None).await
+    }
+
+    /// Buffer the full response body in memory, failing with an error for which `body_limit`
+    /// returns the limit once the body is longer than `limit` bytes.
+    pub async fn buffer_with_limit(
+        &mut self,
+        limit: Option<usize>,
+    ) -> Result<BufferedHttpResponse, HttpDispatchError> {
+        let limit = limit.unwrap_or(usize::MAX);
+        let content_length = self
+            .headers
+            .get(http::header::CONTENT_LENGTH)
+            .and_then(|value| value.parse::<u64>().ok());
+        if matches!(content_length, Some(length) if length > limit as u64) {
+            return Err(HttpDispatchError::body_too_large(limit));
+        }
         let mut bytes = BytesMut::new();
         while let Some(try_chunk) = self.body.next().await {
-            let chunk = try_chunk.map_err(|e| HttpDispatchError {
-                message: format!("Error obtaining body: {}", e),
-            })?;
+            let chunk = try_chunk
+                .map_err(|e| HttpDispatchError::new(format!("Error obtaining body: {}", e)))?;
+            if chunk.len() > limit - bytes.len() {
+                return Err(HttpDispatchError::body_too_large(limit));
+            }
             bytes.extend(chunk);
         }
         Ok(BufferedHttpResponse {
@@ -111,30 +240,93 @@ impl HttpResponse {
             body: bytes.freeze(),
         })
     }
+}
 
-    async fn from_hyper(hyper_response: HyperResponse<Body>) -> HttpResponse {
-        let status = hyper_response.status();
-        let headers = hyper_response
-            .headers()
-            .iter()
-            .map(|(h, v)| {
-                let value_string = v.to_str().unwrap().to_owned();
-                (h.clone(), value_string)
-            })
-            .collect();
-        let body = hyper_response.into_body().map(|try_chunk| {
-            try_chunk.map(|c| c).map_err(|e| {
-                IoError::new(
-                    io::ErrorKind::Other,
-                    format!("Error obtaining chunk: {}", e),
-                )
-            })
-        });
+/// The response of a hyper client, with its body failing once no chunk arrives within
+/// `read_timeout`.
+#[cfg(feature = "tokio")]
+fn from_hyper(
+    hyper_response: Response<Body>,
+    read_timeout: Option<Duration>,
+) -> Response<ByteStream> {
+    let (parts, body) = hyper_response.into_parts();
+    let body = body.map(|try_chunk| {
+        try_chunk.map_err(|e| {
+            IoError::new(
+                io::ErrorKind::Other,
+                format!("Error obtaining chunk: {}", e),
+            )
+        })
+    });
+    let body = match read_timeout {
+        Some(timeout) => ByteStream::new(ReadTimeout::new(body, timeout)),
+        None => ByteStream::new(body),
+    };
+    Response::from_parts(parts, body)
+}
+
+/// The body of a hyper request streaming `body`, empty if `body` is known to be.
+#[cfg(feature = "tokio")]
+fn into_hyper_body(body: ByteStream) -> Body {
+    if body.size_hint() == Some(0) {
+        Body::empty()
+    } else {
+        Body::wrap_stream(body)
+    }
+}
+
+#[cfg(feature = "tokio")]
+/// Stream adapter failing with a `TimedOut` error if no chunk arrives within `timeout`.
+struct ReadTimeout<S> {
+    inner: S,
+    timeout: Duration,
+    delay: Option<time::Delay>,
+}
+
+#[cfg(feature = "tokio")]
+impl<S> ReadTimeout<S> {
+    fn new(inner: S, timeout: Duration) -> Self {
+        ReadTimeout {
+            inner,
+            timeout,
+            delay: None,
+        }
+    }
+}
+
+#[cfg(feature = "tokio")]
+impl<S> Stream for ReadTimeout<S>
+where
+    S: Stream<Item = Result<Bytes, IoError>> + Unpin,
+{
+    type Item = Result<Bytes, IoError>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        if let Poll::Ready(item) = self.inner.poll_next_unpin(cx) {
+            self.delay = None;
+            return Poll::Ready(item);
+        }
+        let timeout = self.timeout;
+        let delay = self.delay.get_or_insert_with(|| time::delay_for(timeout));
+        match delay.poll_unpin(cx) {
+            Poll::Ready(()) => {
+                self.delay = None;
+                Poll::Ready(Some(Err(IoError::new(
+                    io::ErrorKind::TimedOut,
+                    "Timeout while reading response body",
+                ))))
+            }
+            Poll::Pending => Poll::Pending,
+        }
+    }
+}
 
+impl From<BufferedHttpResponse> for HttpResponse {
+    fn from(buffered: BufferedHttpResponse) -> HttpResponse {
         HttpResponse {
-            status,
-            headers,
-            body: ByteStream::new(body),
+            status: buffered.status,
+            headers: buffered.headers,
+            body: ByteStream::from(buffered.body.to_vec()),
         }
     }
 }
@@ -143,12 +335,53 @@ impl HttpResponse {
 /// An error produced when sending the request, such as a timeout error.
 pub struct HttpDispatchError {
     message: String,
+    kind: DispatchErrorKind,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum DispatchErrorKind {
+    Other,
+    Cancelled,
+    BodyTooLarge(usize),
 }
 
 impl HttpDispatchError {
     /// Construct a new HttpDispatchError for testing purposes
     pub fn new(message: String) -> HttpDispatchError {
-        HttpDispatchError { message }
+        HttpDispatchError {
+            message,
+            kind: DispatchErrorKind::Other,
+        }
+    }
+
+    /// The error of operations aborted through a `CancellationToken`.
+    pub fn cancelled() -> HttpDispatchError {
+        HttpDispatchError {
+            message: "Request cancelled".to_owned(),
+            kind: DispatchErrorKind::Cancelled,
+        }
+    }
+
+    /// The error of responses whose bodies are longer than `limit` bytes.
+    pub fn body_too_large(limit: usize) -> HttpDispatchError {
+        HttpDispatchError {
+            message: format!("Response body exceeds the limit of {} bytes", limit),
+            kind: DispatchErrorKind::BodyTooLarge(limit),
+        }
+    }
+
+    /// Whether the request was aborted through a `CancellationToken`.
+    pub fn is_cancelled(&self) -> bool {
+        self.kind == DispatchErrorKind::Cancelled
+    }
+
+    /// The limit on the size of buffered response bodies, if the body of the response exceeded
+    /// it.
+    pub fn body_limit(&self) -> Option<usize> {
+        match self.kind {
+            DispatchErrorKind::BodyTooLarge(limit) => Some(limit),
+            _ => None,
+        }
     }
 }
 
@@ -162,83 +395,121 @@ impl fmt::Display for HttpDispatchError {
 
 impl From<HyperError> for HttpDispatchError {
     fn from(err: HyperError) -> HttpDispatchError {
-        HttpDispatchError {
-            message: err.to_string(),
-        }
+        HttpDispatchError::new(err.to_string())
     }
 }
 
 impl From<IoError> for HttpDispatchError {
     fn from(err: IoError) -> HttpDispatchError {
-        HttpDispatchError {
-            message: err.to_string(),
-        }
+        HttpDispatchError::new(err.to_string())
     }
 }
 
 /// Type returned from `dispatch` for a `DispatchSignedRequest` implementor
 pub type DispatchSignedRequestFuture =
-    Pin<Box<dyn Future<Output = Result<HttpResponse, HttpDispatchError>> + Send>>;
+    Pin<Box<dyn Future<Output = Result<Response<ByteStream>, HttpDispatchError>> + Send>>;
 
 /// Trait for implementing HTTP Request/Response
+///
+/// Requests and responses are those of the `http` crate with streaming bodies, so that any HTTP
+/// client can send the requests of service clients; `rusoto_core` implements it for
+/// `HttpClient`, for `reqwest::Client` with the `reqwest` feature and for hyper 1.x clients
+/// with the `hyper1` feature. Clients build requests with `into_http_request`, which keeps the
+/// `SignedRequest` a request was built from in its extensions.
 pub trait DispatchSignedRequest {
     /// Dispatch Request, and then return a Response
+    ///
+    /// `timeout` bounds the time until the response headers are received, not the reading of
+    /// the response body.
     fn dispatch(
         &self,
-        request: SignedRequest,
+        request: Request<ByteStream>,
         timeout: Option<Duration>,
     ) -> DispatchSignedRequestFuture;
+
+    /// Wait for the requests in flight to complete, and release the resources of the dispatcher,
+    /// such as pooled connections. Called by `Client::shutdown`.
+    ///
+    /// Does nothing by default.
+    fn shutdown(&self) -> ShutdownFuture {
+        Box::pin(futures::future::ready(()))
+    }
 }
 
-impl<D: DispatchSignedRequest> DispatchSignedRequest for Rc<D> {
+impl<D: DispatchSignedRequest + ?Sized> DispatchSignedRequest for Rc<D> {
     fn dispatch(
         &self,
-        request: SignedRequest,
+        request: Request<ByteStream>,
         timeout: Option<Duration>,
     ) -> DispatchSignedRequestFuture {
         D::dispatch(&*self, request, timeout)
     }
+
+    fn shutdown(&self) -> ShutdownFuture {
+        D::shutdown(self)
+    }
 }
 
-impl<D: DispatchSignedRequest> DispatchSignedRequest for Arc<D> {
+impl<D: DispatchSignedRequest + ?Sized> DispatchSignedRequest for Arc<D> {
     fn dispatch(
         &self,
-        request: SignedRequest,
+        request: Request<ByteStream>,
         timeout: Option<Duration>,
     ) -> DispatchSignedRequestFuture {
         D::dispatch(&*self, request, timeout)
     }
+
+    fn shutdown(&self) -> ShutdownFuture {
+        D::shutdown(self)
+    }
 }
 
+#[cfg(feature = "tokio")]
+/// The connector used by `HttpClient` unless a custom connector is given.
+pub type DefaultConnector = HttpsConnector<ProxyConnector<HttpConnector<DnsResolver>>>;
+
+#[cfg(feature = "tokio")]
 /// Http client for use with AWS services.
-pub struct HttpClient<C = HttpsConnector<HttpConnector>> {
-    inner: HyperClient<C, Body>,
+pub struct HttpClient<C = DefaultConnector> {
+    inner: Mutex<Option<HyperClient<C, Body>>>,
+    drain: Arc<Drain>,
+    read_timeout: Option<Duration>,
+    request_timeout: Option<Duration>,
+    wire_logging: bool,
+    proxy_config: Option<Arc<ProxyConfig>>,
+    expect_continue: ExpectContinue,
 }
 
+#[cfg(feature = "tokio")]
 impl HttpClient {
     /// Create a tls-enabled http client.
+    ///
+    /// Requests are sent through the proxies configured by the `HTTP_PROXY`, `HTTPS_PROXY` and
+    /// `NO_PROXY` environment variables.
     pub fn new() -> Result<Self, TlsError> {
-        #[cfg(feature = "native-tls")]
-        let connector = HttpsConnector::new();
-
-        #[cfg(feature = "rustls")]
-        let connector = HttpsConnector::new();
-
-        Ok(Self::from_connector(connector))
+        Self::new_with_config(HttpConfig::new())
     }
 
     /// Create a tls-enabled http client.
-    pub fn new_with_config(config: HttpConfig) -> Result<Self, TlsError> {
-        #[cfg(feature = "native-tls")]
-        let connector = HttpsConnector::new();
+    pub fn new_with_config(mut config: HttpConfig) -> Result<Self, TlsError> {
+        let resolver = config.dns_resolver.take().unwrap_or_default();
+        let mut http = HttpConnector::new_with_resolver(resolver);
+        http.enforce_http(false);
+        http.set_connect_timeout(config.connect_timeout);
+        http.set_keepalive(config.tcp_keepalive);
+        let proxy_config = config.proxy.take().unwrap_or_else(ProxyConfig::from_env);
+        let proxy = ProxyConnector::new(http, proxy_config);
+        let proxy_config = Arc::new(proxy.config().clone());
+        let tls = config.tls.take().unwrap_or_default();
+        let connector = HttpsConnector::with_http_version(proxy, &tls, config.http_version)?;
 
-        #[cfg(feature = "rustls")]
-        let connector = HttpsConnector::new();
-
-        Ok(Self::from_connector_with_config(connector, config))
+        let mut client = Self::from_connector_with_config(connector, config);
+        client.proxy_config = Some(proxy_config);
+        Ok(client)
     }
 }
 
+#[cfg(feature = "tokio")]
 impl<C> HttpClient<C>
 where
     C: Connect + Clone + Send + Sync,
@@ -246,38 +517,126 @@ where
     /// Allows for a custom connector to be used with the HttpClient
     pub fn from_connector(connector: C) -> Self {
         let inner = HyperClient::builder().build(connector);
-        HttpClient { inner }
+        HttpClient {
+            inner: Mutex::new(Some(inner)),
+            drain: Default::default(),
+            read_timeout: None,
+            request_timeout: None,
+            wire_logging: false,
+            proxy_config: None,
+            expect_continue: ExpectContinue::default(),
+        }
     }
 
     /// Allows for a custom connector to be used with the HttpClient
     /// with extra configuration options
+    ///
+    /// The connect timeout of the configuration is not applied to custom connectors.
     pub fn from_connector_with_config(connector: C, config: HttpConfig) -> Self {
         let mut builder = HyperClient::builder();
         config
             .read_buf_size
             .map(|sz| builder.http1_read_buf_exact_size(sz));
+        builder.http2_only(config.http_version == HttpVersion::Http2);
+        if let Some(max_idle) = config.pool_max_idle_per_host {
+            builder.pool_max_idle_per_host(max_idle);
+        }
+        if let Some(idle_timeout) = config.pool_idle_timeout {
+            builder.pool_idle_timeout(idle_timeout);
+        }
+        builder.http2_keep_alive_interval(config.http2_keep_alive_interval);
         let inner = builder.build(connector);
 
-        HttpClient { inner }
+        HttpClient {
+            inner: Mutex::new(Some(inner)),
+            drain: Default::default(),
+            read_timeout: config.read_timeout,
+            request_timeout: config.request_timeout,
+            wire_logging: config.wire_logging,
+            proxy_config: None,
+            expect_continue: config.expect_continue,
+        }
+    }
+
+    /// Log the full requests and responses sent by this client, with credentials and other
+    /// secrets redacted, to the `rusoto_core::wire` log target at debug level.
+    ///
+    /// Response bodies are logged chunk by chunk as they are read. Meant for debugging signature
+    /// and serialization issues; even redacted, the logs can contain sensitive data.
+    pub fn set_wire_logging(&mut self, enabled: bool) {
+        self.wire_logging = enabled;
     }
 
     /// Alows for a custom builder and connector to be used with the HttpClient
     pub fn from_builder(builder: HyperBuilder, connector: C) -> Self {
         let inner = builder.build(connector);
-        HttpClient { inner }
+        HttpClient {
+            inner: Mutex::new(Some(inner)),
+            drain: Default::default(),
+            read_timeout: None,
+            request_timeout: None,
+            wire_logging: false,
+            proxy_config: None,
+            expect_continue: ExpectContinue::default(),
+        }
     }
 }
 
+#[cfg(feature = "tokio")]
+/// The HTTP versions an `HttpClient` may speak.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum HttpVersion {
+    /// Only use HTTP/1.1.
+    Http1,
+    /// Use HTTP/2 on TLS connections where the server selects it through ALPN, and HTTP/1.1
+    /// otherwise.
+    ///
+    /// Only the `rustls` backend supports negotiating HTTP/2; with `native-tls` this is the same
+    /// as `Http1`.
+    Negotiate,
+    /// Only use HTTP/2, as required by event streams such as Kinesis' `SubscribeToShard`.
+    /// Plain HTTP connections use HTTP/2 with prior knowledge.
+    Http2,
+}
+
+#[cfg(feature = "tokio")]
 /// Configuration options for the HTTP Client
 pub struct HttpConfig {
     read_buf_size: Option<usize>,
+    connect_timeout: Option<Duration>,
+    read_timeout: Option<Duration>,
+    request_timeout: Option<Duration>,
+    wire_logging: bool,
+    proxy: Option<ProxyConfig>,
+    dns_resolver: Option<DnsResolver>,
+    http_version: HttpVersion,
+    pool_max_idle_per_host: Option<usize>,
+    pool_idle_timeout: Option<Option<Duration>>,
+    tcp_keepalive: Option<Duration>,
+    http2_keep_alive_interval: Option<Duration>,
+    tls: Option<TlsConfig>,
+    expect_continue: ExpectContinue,
 }
 
+#[cfg(feature = "tokio")]
 impl HttpConfig {
     /// Create a new HttpConfig
     pub fn new() -> HttpConfig {
         HttpConfig {
             read_buf_size: None,
+            connect_timeout: None,
+            read_timeout: None,
+            request_timeout: None,
+            wire_logging: false,
+            proxy: None,
+            dns_resolver: None,
+            http_version: HttpVersion::Negotiate,
+            pool_max_idle_per_host: None,
+            pool_idle_timeout: None,
+            tcp_keepalive: None,
+            http2_keep_alive_interval: None,
+            tls: None,
+            expect_continue: ExpectContinue::default(),
         }
     }
     /// Sets the size of the read buffer for inbound data
@@ -286,8 +645,126 @@ impl HttpConfig {
     pub fn read_buf_size(&mut self, sz: usize) {
         self.read_buf_size = Some(sz);
     }
+    /// Sets the maximum time to wait for a TCP connection to be established.
+    pub fn connect_timeout(&mut self, timeout: Duration) {
+        self.connect_timeout = Some(timeout);
+    }
+    /// Sets the maximum time to wait for the next chunk of a response body.
+    pub fn read_timeout(&mut self, timeout: Duration) {
+        self.read_timeout = Some(timeout);
+    }
+    /// Sets the maximum time from sending a request until the response headers have been
+    /// received. A timeout passed to `DispatchSignedRequest::dispatch` takes precedence.
+    pub fn request_timeout(&mut self, timeout: Duration) {
+        self.request_timeout = Some(timeout);
+    }
+    /// Logs the full requests and responses, with secrets redacted. See
+    /// `HttpClient::set_wire_logging`.
+    pub fn wire_logging(&mut self, enabled: bool) {
+        self.wire_logging = enabled;
+    }
+    /// Sets the proxies requests are sent through, instead of reading them from the
+    /// `HTTP_PROXY`, `HTTPS_PROXY` and `NO_PROXY` environment variables. Pass
+    /// `ProxyConfig::new()` to connect directly.
+    ///
+    /// Not applied to custom connectors, which can be wrapped by a `ProxyConnector` instead.
+    pub fn proxy(&mut self, config: ProxyConfig) {
+        self.proxy = Some(config);
+    }
+    /// Sets the resolver for the host names of endpoints and proxies, e.g. a `StaticResolver`
+    /// pinning endpoints to specific addresses. Defaults to the `SystemResolver`.
+    ///
+    /// Not applied to custom connectors.
+    pub fn dns_resolver<R: ResolveDns + 'static>(&mut self, resolver: R) {
+        self.dns_resolver = Some(DnsResolver::new(resolver));
+    }
+    /// Sets the HTTP versions to use. Defaults to `HttpVersion::Negotiate`.
+    pub fn http_version(&mut self, version: HttpVersion) {
+        self.http_version = version;
+    }
+    /// Sets the maximum number of idle connections kept open to each host. Unlimited by default.
+    pub fn pool_max_idle_per_host(&mut self, max_idle: usize) {
+        self.pool_max_idle_per_host = Some(max_idle);
+    }
+    /// Sets how long idle connections are kept open, or `None` to keep them open until the
+    /// server closes them. Defaults to 90 seconds.
+    pub fn pool_idle_timeout(&mut self, timeout: Option<Duration>) {
+        self.pool_idle_timeout = Some(timeout);
+    }
+    /// Enables TCP keep-alive, probing connections once they have been idle for the given time.
+    ///
+    /// Not applied to custom connectors.
+    pub fn tcp_keepalive(&mut self, interval: Duration) {
+        self.tcp_keepalive = Some(interval);
+    }
+    /// Sends HTTP/2 pings at the given interval to keep HTTP/2 connections alive.
+    pub fn http2_keep_alive_interval(&mut self, interval: Duration) {
+        self.http2_keep_alive_interval = Some(interval);
+    }
+    /// Sets the TLS backend, trusted certificate authorities and client certificate.
+    ///
+    /// Not applied to custom connectors, which can be wrapped by an `HttpsConnector` instead.
+    pub fn tls(&mut self, config: TlsConfig) {
+        self.tls = Some(config);
+    }
+    /// Sets the size from which PUT and POST bodies are sent with `Expect: 100-continue`, or
+    /// `None` to never send it. Defaults to `None`.
+    ///
+    /// The body of such a request is only sent after the expect continue wait, so that a
+    /// service refusing it right away, e.g. for a bad signature or a redirect, answers before
+    /// it has been uploaded. As every such request waits, only set it where re-uploading a
+    /// body costs more than the wait.
+    pub fn expect_continue_threshold(&mut self, threshold: Option<usize>) {
+        self.expect_continue.threshold = threshold;
+    }
+    /// Sets how long requests sent with `Expect: 100-continue` wait for an early response
+    /// before sending their bodies. Defaults to one second.
+    ///
+    /// hyper doesn't report the interim `100 Continue` response, so bodies are sent after the
+    /// wait even when the service asks for them earlier.
+    pub fn expect_continue_wait(&mut self, wait: Duration) {
+        self.expect_continue.wait = wait;
+    }
+}
+
+/// When requests are sent with `Expect: 100-continue`.
+#[cfg(feature = "tokio")]
+#[derive(Clone, Copy, Debug)]
+struct ExpectContinue {
+    threshold: Option<usize>,
+    wait: Duration,
+}
+
+#[cfg(feature = "tokio")]
+impl Default for ExpectContinue {
+    fn default() -> ExpectContinue {
+        ExpectContinue {
+            threshold: None,
+            wait: Duration::from_secs(1),
+        }
+    }
+}
+
+#[cfg(feature = "tokio")]
+impl ExpectContinue {
+    /// Whether a request with `method` and a body of `len` bytes expects to continue.
+    fn applies(&self, method: &Method, len: Option<usize>) -> bool {
+        let sends_body = *method == Method::PUT || *method == Method::POST;
+        match (self.threshold, len) {
+            (Some(threshold), Some(len)) => sends_body && len > 0 && len >= threshold,
+            _ => false,
+        }
+    }
+
+    /// `body`, only sent once the wait has elapsed.
+    fn delay(&self, body: Body) -> Body {
+        let wait = futures::stream::once(time::delay_for(self.wait))
+            .filter_map(|_| futures::future::ready(None::<Result<Bytes, HyperError>>));
+        Body::wrap_stream(wait.chain(body))
+    }
 }
 
+#[cfg(feature = "tokio")]
 impl Default for HttpConfig {
     /// Create a new HttpConfig. Same as HttpConfig::new().
     fn default() -> HttpConfig {
@@ -295,14 +772,10 @@ impl Default for HttpConfig {
     }
 }
 
-async fn http_client_dispatch<C>(
-    client: HyperClient<C, Body>,
-    request: SignedRequest,
-    timeout: Option<Duration>,
-) -> Result<HttpResponse, HttpDispatchError>
-where
-    C: Connect + Send + Sync + Clone + 'static,
-{
+/// The method, URI and headers of the HTTP request sending `request`.
+pub(crate) fn request_head(
+    request: &SignedRequest,
+) -> Result<(Method, String, HeaderMap), HttpDispatchError> {
     let hyper_method = match request.method().as_ref() {
         "POST" => Method::POST,
         "PUT" => Method::PUT,
@@ -310,9 +783,10 @@ where
         "GET" => Method::GET,
         "HEAD" => Method::HEAD,
         v => {
-            return Err(HttpDispatchError {
-                message: format!("Unsupported HTTP verb {}", v),
-            });
+            return Err(HttpDispatchError::new(format!(
+                "Unsupported HTTP verb {}",
+                v
+            )));
         }
     };
 
@@ -322,18 +796,20 @@ where
         let header_name = match h.0.parse::<HeaderName>() {
             Ok(name) => name,
             Err(err) => {
-                return Err(HttpDispatchError {
-                    message: format!("error parsing header name: {}", err),
-                });
+                return Err(HttpDispatchError::new(format!(
+                    "error parsing header name: {}",
+                    err
+                )));
             }
         };
         for v in h.1.iter() {
             let header_value = match HeaderValue::from_bytes(v) {
                 Ok(value) => value,
                 Err(err) => {
-                    return Err(HttpDispatchError {
-                        message: format!("error parsing header value: {}", err),
-                    });
+                    return Err(HttpDispatchError::new(format!(
+                        "error parsing header value: {}",
+                        err
+                    )));
                 }
             };
             hyper_headers.append(&header_name, header_value);
@@ -348,36 +824,75 @@ where
     let mut final_uri = format!(
         "{}://{}{}",
         request.scheme(),
-        request.hostname(),
+        request.connect_host(),
         request.canonical_path()
     );
     if !request.canonical_query_string().is_empty() {
         final_uri = final_uri + &format!("?{}", request.canonical_query_string());
     }
 
+    Ok((hyper_method, final_uri, hyper_headers))
+}
+
+#[cfg(feature = "tokio")]
+async fn http_client_dispatch<C>(
+    client: HyperClient<C, Body>,
+    request: Request<ByteStream>,
+    timeout: Option<Duration>,
+    read_timeout: Option<Duration>,
+    wire_logging: bool,
+    proxy_config: Option<Arc<ProxyConfig>>,
+    expect_continue: ExpectContinue,
+) -> Result<Response<ByteStream>, HttpDispatchError>
+where
+    C: Connect + Send + Sync + Clone + 'static,
+{
+    let (mut parts, body) = request.into_parts();
+
     if log_enabled!(Debug) {
         debug!(
             "Full request: \n method: {}\n final_uri: {}\nHeaders:\n",
-            hyper_method, final_uri
+            parts.method, parts.uri
         );
-        for (h, v) in hyper_headers.iter() {
+        for (h, v) in parts.headers.iter() {
             debug!("{}:{:?}", h.as_str(), v);
         }
     }
 
-    let http_request_builder = HyperRequest::builder().method(hyper_method).uri(final_uri);
+    // Proxies forwarding plain HTTP requests expect their credentials on every request.
+    if let Some(proxy_config) = proxy_config {
+        let proxy = Some(&parts.uri)
+            .filter(|uri| uri.scheme_str() == Some("http"))
+            .and_then(|uri| proxy_config.proxy_for(uri).cloned());
+        if let Some(authorization) = proxy.as_ref().and_then(|proxy| proxy.authorization()) {
+            if let Ok(value) = HeaderValue::from_str(&authorization) {
+                parts.headers.insert("proxy-authorization", value);
+            }
+        }
+    }
 
-    let try_http_request = if let Some(p) = request.payload {
-        http_request_builder.body(p.into_body())
-    } else {
-        http_request_builder.body(Body::empty())
-    };
+    let expects_continue = expect_continue.applies(&parts.method, body.size_hint());
+    if expects_continue {
+        parts.headers.insert(
+            http::header::EXPECT,
+            HeaderValue::from_static("100-continue"),
+        );
+    }
 
-    let mut http_request = try_http_request.map_err(|err| HttpDispatchError {
-        message: format!("error building request: {}", err),
-    })?;
+    if wire_logging {
+        wire_log::log_request(&parts, &body);
+    }
 
-    *http_request.headers_mut() = hyper_headers;
+    let body = into_hyper_body(body);
+    let body = if expects_continue {
+        expect_continue.delay(body)
+    } else {
+        body
+    };
+    let mut http_request = Request::new(body);
+    *http_request.method_mut() = parts.method;
+    *http_request.uri_mut() = parts.uri;
+    *http_request.headers_mut() = parts.headers;
 
     let f = client.request(http_request);
 
@@ -385,52 +900,94 @@ where
         None => f.await,
         Some(duration) => match time::timeout(duration, f).await {
             Err(_e) => {
-                return Err(HttpDispatchError {
-                    message: "Timeout while dispatching request".to_owned(),
-                })
+                return Err(HttpDispatchError::new(
+                    "Timeout while dispatching request".to_owned(),
+                ))
             }
             Ok(try_req) => try_req,
         },
     };
-    let resp = try_resp.map_err(|e| HttpDispatchError {
-        message: format!("Error during dispatch: {}", e),
-    })?;
-    Ok(HttpResponse::from_hyper(resp).await)
+    let resp =
+        try_resp.map_err(|e| HttpDispatchError::new(format!("Error during dispatch: {}", e)))?;
+    let response = from_hyper(resp, read_timeout);
+    if wire_logging {
+        Ok(wire_log::log_response(response))
+    } else {
+        Ok(response)
+    }
 }
 
+#[cfg(feature = "tokio")]
 impl<C> DispatchSignedRequest for HttpClient<C>
 where
     C: Connect + Clone + Send + Sync + 'static,
 {
     fn dispatch(
         &self,
-        request: SignedRequest,
+        request: Request<ByteStream>,
         timeout: Option<Duration>,
     ) -> DispatchSignedRequestFuture {
-        http_client_dispatch::<C>(self.inner.clone(), request, timeout).boxed()
+        let in_flight = self.drain.start();
+        let client = self.inner.lock().unwrap().clone();
+        let (in_flight, client) = match (in_flight, client) {
+            (Some(in_flight), Some(client)) => (in_flight, client),
+            _ => {
+                return futures::future::err(HttpDispatchError::new(
+                    "HttpClient has been shut down".to_owned(),
+                ))
+                .boxed()
+            }
+        };
+        http_client_dispatch::<C>(
+            client,
+            request,
+            timeout.or(self.request_timeout),
+            self.read_timeout,
+            self.wire_logging,
+            self.proxy_config.clone(),
+            self.expect_continue,
+        )
+        .map(move |response| {
+            drop(in_flight);
+            response
+        })
+        .boxed()
     }
-}
-
-#[derive(Debug, PartialEq)]
-/// An error produced when the user has an invalid TLS client
-pub struct TlsError {
-    message: String,
-}
-
-impl Error for TlsError {}
 
-impl fmt::Display for TlsError {
-    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "{}", self.message)
+    /// Reject new requests, wait for the ones in flight to receive their response heads, and
+    /// drop the connection pool. Idle connections are closed right away, the connections of
+    /// responses still being read once their bodies have been read or dropped.
+    fn shutdown(&self) -> ShutdownFuture {
+        let drained = self.drain.close();
+        let client = self.inner.lock().unwrap().take();
+        Box::pin(async move {
+            drained.await;
+            drop(client);
+        })
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::dispatch::into_http_request;
     use crate::signature::SignedRequest;
     use crate::Region;
 
+    async fn send<D>(
+        dispatcher: &D,
+        request: SignedRequest,
+    ) -> Result<HttpResponse, HttpDispatchError>
+    where
+        D: DispatchSignedRequest,
+    {
+        let request = into_http_request(request)?;
+        dispatcher
+            .dispatch(request, None)
+            .await
+            .map(HttpResponse::from)
+    }
+
     #[test]
     fn http_client_is_send_and_sync() {
         fn is_send_and_sync<T: Send + Sync>() {}
@@ -438,6 +995,223 @@ mod tests {
         is_send_and_sync::<HttpClient>();
     }
 
+    #[test]
+    fn metadata_exposes_status_and_headers() {
+        let mut headers = HeaderMap::default();
+        headers.insert("etag", "\"abc\"".to_owned());
+        headers.insert("x-amz-request-id", "req-1".to_owned());
+        let response = HttpResponse {
+            status: StatusCode::PARTIAL_CONTENT,
+            body: ByteStream::from(Vec::new()),
+            headers,
+        };
+        let metadata = ResponseMetadata::from_response(&response);
+        assert_eq!(metadata.status(), StatusCode::PARTIAL_CONTENT);
+        assert_eq!(metadata.header("ETag"), Some("\"abc\""));
+        assert_eq!(metadata.header("x-amz-version-id"), None);
+        assert_eq!(metadata.request_id(), Some("req-1"));
+        assert_eq!(metadata.headers().len(), 2);
+    }
+
+    #[tokio::test]
+    async fn buffering_fails_past_the_body_limit() {
+        let response = |headers: HeaderMap<String>| HttpResponse {
+            status: StatusCode::OK,
+            body: ByteStream::from(b"0123456789".to_vec()),
+            headers,
+        };
+        let buffered = response(HeaderMap::default())
+            .buffer_with_limit(Some(10))
+            .await
+            .unwrap();
+        assert_eq!(buffered.body, "0123456789");
+
+        let err = response(HeaderMap::default())
+            .buffer_with_limit(Some(9))
+            .await
+            .unwrap_err();
+        assert_eq!(err.body_limit(), Some(9));
+
+        let mut headers = HeaderMap::default();
+        headers.insert("content-length", "1000000".to_owned());
+        let err = response(headers)
+            .buffer_with_limit(Some(10))
+            .await
+            .unwrap_err();
+        assert_eq!(err.body_limit(), Some(10));
+    }
+
+    #[tokio::test]
+    async fn read_timeout_fails_stalled_body() {
+        let chunk = futures::stream::iter(vec![Ok(Bytes::from_static(b"chunk"))]);
+        let stalled = chunk.chain(futures::stream::pending());
+        let mut body = ReadTimeout::new(stalled, Duration::from_millis(10));
+        assert_eq!(body.next().await.unwrap().unwrap(), "chunk");
+        let err = body.next().await.unwrap().unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::TimedOut);
+    }
+
+    #[tokio::test]
+    async fn forces_http2() {
+        use hyper::service::{make_service_fn, service_fn};
+
+        let make_service = make_service_fn(|_| async {
+            Ok::<_, HyperError>(service_fn(|request: Request<Body>| async move {
+                let version = format!("{:?}", request.version());
+                Ok::<_, HyperError>(Response::new(Body::from(version)))
+            }))
+        });
+        let server = hyper::Server::bind(&([127, 0, 0, 1], 0).into())
+            .http2_only(true)
+            .serve(make_service);
+        let endpoint = format!("http://{}", server.local_addr());
+        tokio::spawn(server);
+
+        let mut config = HttpConfig::new();
+        config.http_version(HttpVersion::Http2);
+        config.proxy(ProxyConfig::new());
+        let client = HttpClient::new_with_config(config).unwrap();
+        let region = Region::Custom {
+            name: "us-east-1".to_owned(),
+            endpoint,
+        };
+        let request = SignedRequest::new("GET", "kinesis", &region, "/");
+        let mut response = send(&client, request).await.unwrap();
+        assert_eq!(response.status, StatusCode::OK);
+        let body = response.buffer().await.unwrap().body;
+        assert_eq!(body, "HTTP/2.0");
+    }
+
+    #[tokio::test]
+    async fn shutdown_drains_requests_in_flight() {
+        use hyper::service::{make_service_fn, service_fn};
+
+        let make_service = make_service_fn(|_| async {
+            Ok::<_, HyperError>(service_fn(|_: Request<Body>| async {
+                time::delay_for(Duration::from_millis(50)).await;
+                Ok::<_, HyperError>(Response::new(Body::empty()))
+            }))
+        });
+        let server = hyper::Server::bind(&([127, 0, 0, 1], 0).into()).serve(make_service);
+        let region = Region::Custom {
+            name: "us-east-1".to_owned(),
+            endpoint: format!("http://{}", server.local_addr()),
+        };
+        tokio::spawn(server);
+
+        let client = Arc::new(HttpClient::from_connector(HttpConnector::new()));
+        let in_flight = tokio::spawn({
+            let client = client.clone();
+            let request = SignedRequest::new("GET", "s3", &region, "/");
+            async move { send(&client, request).await }
+        });
+        time::delay_for(Duration::from_millis(10)).await;
+        client.shutdown().await;
+        let response = in_flight.now_or_never().unwrap().unwrap().unwrap();
+        assert_eq!(response.status, StatusCode::OK);
+
+        let request = SignedRequest::new("GET", "s3", &region, "/");
+        let err = send(&client, request).await.err().unwrap();
+        assert!(err.to_string().contains("shut down"));
+    }
+
+    #[test]
+    fn expect_continue_is_opt_in() {
+        let expect_continue = ExpectContinue::default();
+        assert!(!expect_continue.applies(&Method::PUT, Some(1 << 30)));
+
+        let mut config = HttpConfig::new();
+        config.expect_continue_threshold(Some(8));
+        assert!(config.expect_continue.applies(&Method::PUT, Some(8)));
+        assert!(!config.expect_continue.applies(&Method::GET, Some(8)));
+    }
+
+    #[tokio::test]
+    async fn large_bodies_expect_to_continue() {
+        use hyper::service::{make_service_fn, service_fn};
+        use std::sync::atomic::{AtomicBool, Ordering};
+
+        let make_service = make_service_fn(|_| async {
+            Ok::<_, HyperError>(service_fn(|request: Request<Body>| async move {
+                if request.uri().path() == "/reject" {
+                    let response = Response::builder().status(403).body(Body::empty());
+                    return Ok::<_, HyperError>(response.unwrap());
+                }
+                let expect = request.headers().get("expect").cloned();
+                let body = hyper::body::to_bytes(request.into_body()).await?;
+                let expect = expect.map_or("none".to_owned(), |v| v.to_str().unwrap().to_owned());
+                Ok(Response::new(Body::from(format!(
+                    "{} {}",
+                    expect,
+                    body.len()
+                ))))
+            }))
+        });
+        let server = hyper::Server::bind(&([127, 0, 0, 1], 0).into()).serve(make_service);
+        let region = Region::Custom {
+            name: "us-east-1".to_owned(),
+            endpoint: format!("http://{}", server.local_addr()),
+        };
+        tokio::spawn(server);
+
+        let mut config = HttpConfig::new();
+        config.proxy(ProxyConfig::new());
+        config.expect_continue_threshold(Some(8));
+        config.expect_continue_wait(Duration::from_millis(10));
+        let client = HttpClient::new_with_config(config).unwrap();
+        for (body, expected) in &[
+            (&b"large body"[..], "100-continue 10"),
+            (b"small", "none 5"),
+        ] {
+            let mut request = SignedRequest::new("PUT", "s3", &region, "/bucket/key");
+            request.set_payload(Some(body.to_vec()));
+            let mut response = send(&client, request).await.unwrap();
+            assert_eq!(response.buffer().await.unwrap().body, *expected);
+        }
+
+        let mut config = HttpConfig::new();
+        config.proxy(ProxyConfig::new());
+        config.expect_continue_threshold(Some(8));
+        config.expect_continue_wait(Duration::from_secs(60));
+        let client = HttpClient::new_with_config(config).unwrap();
+        let sent = Arc::new(AtomicBool::new(false));
+        let polled = sent.clone();
+        let body = futures::stream::poll_fn(move |_| {
+            polled.store(true, Ordering::SeqCst);
+            Poll::Ready(None)
+        });
+        let mut request = SignedRequest::new("PUT", "s3", &region, "/reject");
+        request.set_payload_stream(ByteStream::new_with_size(body, 1024));
+        let response = time::timeout(Duration::from_secs(5), send(&client, request))
+            .await
+            .expect("the response arrives before the body is sent")
+            .unwrap();
+        assert_eq!(response.status, StatusCode::FORBIDDEN);
+        assert!(!sent.load(Ordering::SeqCst));
+    }
+
+    #[test]
+    fn request_id_from_headers_or_xml_body() {
+        let mut headers = HeaderMap::default();
+        headers.insert("x-amz-request-id", "req-1".to_owned());
+        headers.insert("x-amz-id-2", "ext-1".to_owned());
+        let res = BufferedHttpResponse {
+            status: StatusCode::NOT_FOUND,
+            body: Bytes::new(),
+            headers,
+        };
+        assert_eq!(res.request_id(), Some("req-1"));
+        assert_eq!(res.extended_request_id(), Some("ext-1"));
+
+        let res = BufferedHttpResponse {
+            status: StatusCode::BAD_REQUEST,
+            body: Bytes::from_static(b"<Response><Errors/><RequestID>req-2</RequestID></Response>"),
+            headers: HeaderMap::default(),
+        };
+        assert_eq!(res.request_id(), Some("req-2"));
+        assert_eq!(res.extended_request_id(), None);
+    }
+
     #[test]
     fn custom_region_http() {
         let a_region = Region::Custom {
@@ -0,0 +1,606 @@
+//! Retry policies applied by `Client` when dispatching requests.
+//!
+//! A `RetryPolicy` decides how many times a request is attempted, how long to wait between
+//! attempts and which kinds of failure are worth retrying at all. Back-off between attempts is
+//! exponential with "full jitter", as described in
+//! <https://aws.amazon.com/blogs/architecture/exponential-backoff-and-jitter/>.
+//!
+//! In `RetryMode::Adaptive` the policy additionally rate limits the requests sent, using a token
+//! bucket whose fill rate is cut on throttling errors and recovers following a CUBIC curve on
+//! success, the same way the other AWS SDKs behave.
+
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, SystemTime};
+
+use instant::Instant;
+
+use http::StatusCode;
+use rand::Rng;
+
+use crate::error::parse_code_and_message;
+use crate::request::BufferedHttpResponse;
+
+/// Error codes AWS services use to signal that the caller is being throttled.
+pub(crate) const THROTTLING_ERROR_CODES: &[&str] = &[
+    "Throttling",
+    "ThrottlingException",
+    "ThrottledException",
+    "RequestThrottledException",
+    "TooManyRequestsException",
+    "ProvisionedThroughputExceededException",
+    "TransactionInProgressException",
+    "RequestLimitExceeded",
+    "BandwidthLimitExceeded",
+    "LimitExceededException",
+    "RequestThrottled",
+    "SlowDown",
+    "PriorRequestNotComplete",
+    "EC2ThrottledException",
+];
+
+/// Error codes AWS services use for failures that are expected to go away on their own.
+pub(crate) const TRANSIENT_ERROR_CODES: &[&str] = &[
+    "RequestTimeout",
+    "RequestTimeoutException",
+    "InternalError",
+    "InternalFailure",
+    "InternalServerError",
+    "ServiceUnavailable",
+    "IDPCommunicationError",
+];
+
+/// Error codes AWS services use to reject requests signed with a clock that is out of sync.
+pub(crate) const CLOCK_SKEW_ERROR_CODES: &[&str] = &[
+    "RequestTimeTooSkewed",
+    "RequestExpired",
+    "RequestInTheFuture",
+    "InvalidSignatureException",
+    "SignatureDoesNotMatch",
+    "AuthFailure",
+];
+
+/// Differences between the local and the service's clock below this are not considered skew.
+const CLOCK_SKEW_TOLERANCE: Duration = Duration::from_secs(4 * 60);
+
+/// The kinds of failure a `RetryPolicy` can be configured to retry.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum ErrorClass {
+    /// The request never produced a response, e.g. a connection was reset or timed out.
+    Dispatch,
+    /// The service answered with a 5xx status or a transient error code.
+    Transient,
+    /// The service asked the caller to slow down, e.g. with a 429 status or `ThrottlingException`.
+    Throttling,
+    /// The service rejected the request because the clock it was signed with is out of sync.
+    /// Such requests are retried immediately, signed with the service's clock.
+    ClockSkew,
+}
+
+impl ErrorClass {
+    /// Classify an unsuccessful response, returning `None` if it should never be retried.
+    pub fn classify(response: &BufferedHttpResponse) -> Option<ErrorClass> {
+        if response.status.is_success() {
+            return None;
+        }
+        ErrorClass::from_code_and_status(error_code(response).as_deref(), Some(response.status))
+    }
+
+    /// Classify an error by its AWS error code, falling back to the HTTP status of the response.
+    pub(crate) fn from_code_and_status(
+        code: Option<&str>,
+        status: Option<StatusCode>,
+    ) -> Option<ErrorClass> {
+        if let Some(code) = code {
+            if THROTTLING_ERROR_CODES.contains(&code) {
+                return Some(ErrorClass::Throttling);
+            }
+            if TRANSIENT_ERROR_CODES.contains(&code) {
+                return Some(ErrorClass::Transient);
+            }
+        }
+        match status? {
+            StatusCode::TOO_MANY_REQUESTS => Some(ErrorClass::Throttling),
+            StatusCode::INTERNAL_SERVER_ERROR
+            | StatusCode::BAD_GATEWAY
+            | StatusCode::SERVICE_UNAVAILABLE
+            | StatusCode::GATEWAY_TIMEOUT => Some(ErrorClass::Transient),
+            _ => None,
+        }
+    }
+}
+
+/// The time reported by the service in a response's `Date` header, if the response rejected a
+/// request signed at `signing_time` because of clock skew.
+pub(crate) fn skewed_server_time(
+    response: &BufferedHttpResponse,
+    signing_time: SystemTime,
+) -> Option<SystemTime> {
+    let code = error_code(response)?;
+    if !CLOCK_SKEW_ERROR_CODES.contains(&code.as_str()) {
+        return None;
+    }
+    let server_time = httpdate::parse_http_date(response.headers.get("date")?).ok()?;
+    let skew = match server_time.duration_since(signing_time) {
+        Ok(skew) => skew,
+        Err(err) => err.duration(),
+    };
+    if skew > CLOCK_SKEW_TOLERANCE {
+        Some(server_time)
+    } else {
+        None
+    }
+}
+
+/// The delay requested by a throttled (429) or unavailable (503) response's `Retry-After` header,
+/// given either in seconds or as an HTTP date.
+pub(crate) fn retry_after(response: &BufferedHttpResponse) -> Option<Duration> {
+    match response.status {
+        StatusCode::TOO_MANY_REQUESTS | StatusCode::SERVICE_UNAVAILABLE => {}
+        _ => return None,
+    }
+    let value = response.headers.get("retry-after")?.trim();
+    if let Ok(seconds) = value.parse::<u64>() {
+        return Some(Duration::from_secs(seconds));
+    }
+    let date = httpdate::parse_http_date(value).ok()?;
+    Some(
+        date.duration_since(crate::signature::clock::now())
+            .unwrap_or_else(|_| Duration::from_secs(0)),
+    )
+}
+
+/// Extract the AWS error code from an error response, regardless of the protocol used.
+pub(crate) fn error_code(response: &BufferedHttpResponse) -> Option<String> {
+    parse_code_and_message(response).0
+}
+
+/// The retry strategy used by a `RetryPolicy`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum RetryMode {
+    /// Retry with exponential back-off only.
+    Standard,
+    /// Retry with exponential back-off, and rate limit requests on the client side once the
+    /// service starts throttling. The rate limit is shared by all clients using clones of the
+    /// same policy.
+    Adaptive,
+}
+
+const MIN_FILL_RATE: f64 = 0.5;
+const MIN_CAPACITY: f64 = 1.0;
+const SMOOTH: f64 = 0.8;
+const BETA: f64 = 0.7;
+const SCALE_CONSTANT: f64 = 0.4;
+
+/// Client side rate limiter backing `RetryMode::Adaptive`.
+#[derive(Debug)]
+struct RateLimiter {
+    start: Instant,
+    fill_rate: f64,
+    max_capacity: f64,
+    current_capacity: f64,
+    last_timestamp: Option<f64>,
+    enabled: bool,
+    measured_tx_rate: f64,
+    last_tx_rate_bucket: f64,
+    request_count: u64,
+    last_max_rate: f64,
+    last_throttle_time: f64,
+    time_window: f64,
+}
+
+impl RateLimiter {
+    fn new() -> RateLimiter {
+        RateLimiter {
+            start: Instant::now(),
+            fill_rate: 0.0,
+            max_capacity: 0.0,
+            current_capacity: 0.0,
+            last_timestamp: None,
+            enabled: false,
+            measured_tx_rate: 0.0,
+            last_tx_rate_bucket: 0.0,
+            request_count: 0,
+            last_max_rate: 0.0,
+            last_throttle_time: 0.0,
+            time_window: 0.0,
+        }
+    }
+
+    fn now(&self) -> f64 {
+        self.start.elapsed().as_secs_f64()
+    }
+
+    fn refill(&mut self, now: f64) {
+        if let Some(last) = self.last_timestamp {
+            let fill_amount = (now - last) * self.fill_rate;
+            self.current_capacity = (self.current_capacity + fill_amount).min(self.max_capacity);
+        }
+        self.last_timestamp = Some(now);
+    }
+
+    /// Take a token from the bucket, returning how long the caller has to wait for it.
+    fn acquire(&mut self) -> Duration {
+        if !self.enabled {
+            return Duration::from_secs(0);
+        }
+        let now = self.now();
+        self.refill(now);
+        self.current_capacity -= 1.0;
+        if self.current_capacity >= 0.0 {
+            Duration::from_secs(0)
+        } else {
+            Duration::from_secs_f64(-self.current_capacity / self.fill_rate)
+        }
+    }
+
+    fn update(&mut self, throttled: bool) {
+        let now = self.now();
+        self.update_measured_rate(now);
+        let calculated_rate = if throttled {
+            let rate_to_use = if self.enabled {
+                self.measured_tx_rate.min(self.fill_rate)
+            } else {
+                self.measured_tx_rate
+            };
+            self.last_max_rate = rate_to_use;
+            self.time_window = (self.last_max_rate * (1.0 - BETA) / SCALE_CONSTANT).cbrt();
+            self.last_throttle_time = now;
+            self.enabled = true;
+            rate_to_use * BETA
+        } else {
+            SCALE_CONSTANT * (now - self.last_throttle_time - self.time_window).powi(3)
+                + self.last_max_rate
+        };
+        let new_rate = calculated_rate.min(2.0 * self.measured_tx_rate);
+        self.refill(now);
+        self.fill_rate = new_rate.max(MIN_FILL_RATE);
+        self.max_capacity = new_rate.max(MIN_CAPACITY);
+        self.current_capacity = self.current_capacity.min(self.max_capacity);
+    }
+
+    fn update_measured_rate(&mut self, now: f64) {
+        let time_bucket = (now * 2.0).floor() / 2.0;
+        self.request_count += 1;
+        if time_bucket > self.last_tx_rate_bucket {
+            let current_rate = self.request_count as f64 / (time_bucket - self.last_tx_rate_bucket);
+            self.measured_tx_rate = current_rate * SMOOTH + self.measured_tx_rate * (1.0 - SMOOTH);
+            self.request_count = 0;
+            self.last_tx_rate_bucket = time_bucket;
+        }
+    }
+}
+
+/// Controls how a `Client` retries failed requests.
+///
+/// The default policy makes up to three attempts, backing off exponentially from 100
+/// milliseconds up to 20 seconds, and retries dispatch errors, transient server errors,
+/// throttling errors and requests rejected because of clock skew.
+///
+/// Requests with a streaming payload can't be replayed and are therefore attempted only once.
+#[derive(Clone, Debug)]
+pub struct RetryPolicy {
+    mode: RetryMode,
+    rate_limiter: Option<Arc<Mutex<RateLimiter>>>,
+    max_attempts: u32,
+    base_delay: Duration,
+    max_backoff: Duration,
+    retry_dispatch: bool,
+    retry_transient: bool,
+    retry_throttling: bool,
+    retry_clock_skew: bool,
+}
+
+impl RetryPolicy {
+    /// Create a retry policy with the default settings.
+    pub fn new() -> RetryPolicy {
+        RetryPolicy {
+            mode: RetryMode::Standard,
+            rate_limiter: None,
+            max_attempts: 3,
+            base_delay: Duration::from_millis(100),
+            max_backoff: Duration::from_secs(20),
+            retry_dispatch: true,
+            retry_transient: true,
+            retry_throttling: true,
+            retry_clock_skew: true,
+        }
+    }
+
+    /// Create a retry policy that attempts every request exactly once.
+    pub fn none() -> RetryPolicy {
+        let mut policy = RetryPolicy::new();
+        policy.set_max_attempts(1);
+        policy
+    }
+
+    /// Set the retry strategy. Switching to `RetryMode::Adaptive` starts with a fresh rate limit.
+    pub fn set_mode(&mut self, mode: RetryMode) {
+        self.mode = mode;
+        self.rate_limiter = match mode {
+            RetryMode::Standard => None,
+            RetryMode::Adaptive => Some(Arc::new(Mutex::new(RateLimiter::new()))),
+        };
+    }
+
+    /// Get the retry strategy.
+    pub fn mode(&self) -> RetryMode {
+        self.mode
+    }
+
+    /// Set the maximum number of attempts, including the initial one. Values below one are
+    /// treated as one.
+    pub fn set_max_attempts(&mut self, max_attempts: u32) {
+        self.max_attempts = max_attempts.max(1);
+    }
+
+    /// Set the delay the exponential back-off starts from.
+    pub fn set_base_delay(&mut self, base_delay: Duration) {
+        self.base_delay = base_delay;
+    }
+
+    /// Set the upper bound for the delay between two attempts, including the delays responses
+    /// request with `Retry-After`.
+    pub fn set_max_backoff(&mut self, max_backoff: Duration) {
+        self.max_backoff = max_backoff;
+    }
+
+    /// Enable or disable retries for a class of errors.
+    pub fn set_retryable(&mut self, class: ErrorClass, retryable: bool) {
+        match class {
+            ErrorClass::Dispatch => self.retry_dispatch = retryable,
+            ErrorClass::Transient => self.retry_transient = retryable,
+            ErrorClass::Throttling => self.retry_throttling = retryable,
+            ErrorClass::ClockSkew => self.retry_clock_skew = retryable,
+        }
+    }
+
+    /// Get the maximum number of attempts, including the initial one.
+    pub fn max_attempts(&self) -> u32 {
+        self.max_attempts
+    }
+
+    /// Whether errors of the given class are retried.
+    pub fn is_retryable(&self, class: ErrorClass) -> bool {
+        match class {
+            ErrorClass::Dispatch => self.retry_dispatch,
+            ErrorClass::Transient => self.retry_transient,
+            ErrorClass::Throttling => self.retry_throttling,
+            ErrorClass::ClockSkew => self.retry_clock_skew,
+        }
+    }
+
+    /// Whether a request that failed on the given attempt (starting at one) should be retried.
+    pub fn should_retry(&self, attempt: u32, class: ErrorClass) -> bool {
+        attempt < self.max_attempts && self.is_retryable(class)
+    }
+
+    /// The upper bound of the delay before the attempt following `attempt` (starting at one).
+    pub fn max_delay(&self, attempt: u32) -> Duration {
+        let exponent = attempt.saturating_sub(1).min(31);
+        self.base_delay
+            .checked_mul(1 << exponent)
+            .map_or(self.max_backoff, |delay| delay.min(self.max_backoff))
+    }
+
+    /// A randomized delay before the attempt following `attempt`, uniformly distributed
+    /// between zero and `max_delay(attempt)`.
+    pub fn delay(&self, attempt: u32) -> Duration {
+        let max_delay = self.max_delay(attempt);
+        if max_delay == Duration::from_secs(0) {
+            return max_delay;
+        }
+        rand::thread_rng().gen_range(Duration::from_secs(0), max_delay)
+    }
+
+    /// The delay before the attempt following `attempt`, the one requested by its response's
+    /// `Retry-After` header if any, bounded by the maximum back-off.
+    pub(crate) fn retry_delay(&self, attempt: u32, retry_after: Option<Duration>) -> Duration {
+        match retry_after {
+            Some(retry_after) => retry_after.min(self.max_backoff),
+            None => self.delay(attempt),
+        }
+    }
+
+    /// How long to wait before sending the next attempt, as dictated by the adaptive rate limit.
+    pub(crate) fn acquire_send_token(&self) -> Duration {
+        match self.rate_limiter {
+            Some(ref limiter) => limiter.lock().unwrap().acquire(),
+            None => Duration::from_secs(0),
+        }
+    }
+
+    /// Feed the outcome of an attempt back into the adaptive rate limit.
+    pub(crate) fn record_attempt(&self, class: Option<ErrorClass>) {
+        if let Some(ref limiter) = self.rate_limiter {
+            limiter
+                .lock()
+                .unwrap()
+                .update(class == Some(ErrorClass::Throttling));
+        }
+    }
+}
+
+impl Default for RetryPolicy {
+    fn default() -> RetryPolicy {
+        RetryPolicy::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bytes::Bytes;
+    use http::header::{DATE, RETRY_AFTER};
+    use http::HeaderMap;
+
+    fn response(status: u16, body: &'static str) -> BufferedHttpResponse {
+        BufferedHttpResponse {
+            status: StatusCode::from_u16(status).unwrap(),
+            body: Bytes::from_static(body.as_bytes()),
+            headers: HeaderMap::default(),
+        }
+    }
+
+    #[test]
+    fn classify_by_status() {
+        assert_eq!(ErrorClass::classify(&response(200, "")), None);
+        assert_eq!(ErrorClass::classify(&response(404, "")), None);
+        assert_eq!(
+            ErrorClass::classify(&response(429, "")),
+            Some(ErrorClass::Throttling)
+        );
+        assert_eq!(
+            ErrorClass::classify(&response(503, "")),
+            Some(ErrorClass::Transient)
+        );
+    }
+
+    #[test]
+    fn classify_by_error_code() {
+        let json = r#"{"__type":"com.amazonaws.dynamodb.v20120810#ProvisionedThroughputExceededException"}"#;
+        assert_eq!(
+            ErrorClass::classify(&response(400, json)),
+            Some(ErrorClass::Throttling)
+        );
+        let xml = "<ErrorResponse><Error><Type>Sender</Type><Code>Throttling</Code>\
+                   <Message>Rate exceeded</Message></Error></ErrorResponse>";
+        assert_eq!(
+            ErrorClass::classify(&response(400, xml)),
+            Some(ErrorClass::Throttling)
+        );
+        let xml = "<Error><Code>NoSuchKey</Code></Error>";
+        assert_eq!(ErrorClass::classify(&response(404, xml)), None);
+    }
+
+    #[test]
+    fn retry_after_in_seconds_or_http_date() {
+        let mut throttled = response(429, "");
+        assert_eq!(retry_after(&throttled), None);
+        throttled.headers.insert(RETRY_AFTER, "3".to_owned());
+        assert_eq!(retry_after(&throttled), Some(Duration::from_secs(3)));
+
+        let mut unavailable = response(503, "");
+        let date = httpdate::fmt_http_date(SystemTime::now() + Duration::from_secs(120));
+        unavailable.headers.insert(RETRY_AFTER, date);
+        let delay = retry_after(&unavailable).unwrap();
+        assert!(delay > Duration::from_secs(100) && delay <= Duration::from_secs(120));
+
+        let mut past = response(503, "");
+        past.headers
+            .insert(RETRY_AFTER, "Wed, 21 Oct 2015 07:28:00 GMT".to_owned());
+        assert_eq!(retry_after(&past), Some(Duration::from_secs(0)));
+
+        let mut not_found = response(404, "");
+        not_found.headers.insert(RETRY_AFTER, "3".to_owned());
+        assert_eq!(retry_after(&not_found), None);
+    }
+
+    #[test]
+    fn detects_clock_skew_from_date_header() {
+        let signing_time = SystemTime::now();
+        let server_time = signing_time + Duration::from_secs(15 * 60);
+        let xml = "<Error><Code>RequestTimeTooSkewed</Code></Error>";
+        let mut skewed = response(403, xml);
+        skewed
+            .headers
+            .insert(DATE, httpdate::fmt_http_date(server_time));
+        let detected = skewed_server_time(&skewed, signing_time).unwrap();
+        assert!(detected <= server_time && detected + Duration::from_secs(1) > server_time);
+
+        skewed
+            .headers
+            .insert(DATE, httpdate::fmt_http_date(signing_time));
+        assert_eq!(skewed_server_time(&skewed, signing_time), None);
+
+        let mut denied = response(403, "<Error><Code>AccessDenied</Code></Error>");
+        denied
+            .headers
+            .insert(DATE, httpdate::fmt_http_date(server_time));
+        assert_eq!(skewed_server_time(&denied, signing_time), None);
+    }
+
+    #[test]
+    fn delays_grow_exponentially_up_to_max_backoff() {
+        let mut policy = RetryPolicy::new();
+        policy.set_base_delay(Duration::from_millis(100));
+        policy.set_max_backoff(Duration::from_secs(1));
+        assert_eq!(policy.max_delay(1), Duration::from_millis(100));
+        assert_eq!(policy.max_delay(2), Duration::from_millis(200));
+        assert_eq!(policy.max_delay(4), Duration::from_millis(800));
+        assert_eq!(policy.max_delay(5), Duration::from_secs(1));
+        assert_eq!(policy.max_delay(100), Duration::from_secs(1));
+        for attempt in 1..10 {
+            assert!(policy.delay(attempt) <= policy.max_delay(attempt));
+        }
+    }
+
+    #[test]
+    fn retry_after_is_bounded_by_max_backoff() {
+        let mut policy = RetryPolicy::new();
+        policy.set_max_backoff(Duration::from_secs(5));
+        let requested = Some(Duration::from_secs(3));
+        assert_eq!(policy.retry_delay(1, requested), Duration::from_secs(3));
+        let requested = Some(Duration::from_secs(86400));
+        assert_eq!(policy.retry_delay(1, requested), Duration::from_secs(5));
+
+        let mut far = response(503, "");
+        let date = httpdate::fmt_http_date(SystemTime::now() + Duration::from_secs(86400));
+        far.headers.insert(RETRY_AFTER, date);
+        assert_eq!(
+            policy.retry_delay(1, retry_after(&far)),
+            Duration::from_secs(5)
+        );
+        assert!(policy.retry_delay(1, None) <= policy.max_delay(1));
+    }
+
+    #[test]
+    fn should_retry_respects_attempts_and_classes() {
+        let mut policy = RetryPolicy::new();
+        policy.set_max_attempts(2);
+        policy.set_retryable(ErrorClass::Dispatch, false);
+        assert!(policy.should_retry(1, ErrorClass::Throttling));
+        assert!(!policy.should_retry(2, ErrorClass::Throttling));
+        assert!(!policy.should_retry(1, ErrorClass::Dispatch));
+        assert!(!RetryPolicy::none().should_retry(1, ErrorClass::Transient));
+    }
+
+    #[test]
+    fn standard_mode_never_rate_limits() {
+        let policy = RetryPolicy::new();
+        for _ in 0..10 {
+            policy.record_attempt(Some(ErrorClass::Throttling));
+            assert_eq!(policy.acquire_send_token(), Duration::from_secs(0));
+        }
+    }
+
+    #[test]
+    fn adaptive_mode_rate_limits_after_throttling() {
+        let mut policy = RetryPolicy::new();
+        policy.set_mode(RetryMode::Adaptive);
+        assert_eq!(policy.acquire_send_token(), Duration::from_secs(0));
+        policy.record_attempt(None);
+        assert_eq!(policy.acquire_send_token(), Duration::from_secs(0));
+
+        policy.record_attempt(Some(ErrorClass::Throttling));
+        let delays: Vec<Duration> = (0..5).map(|_| policy.acquire_send_token()).collect();
+        assert!(delays.iter().any(|delay| *delay > Duration::from_secs(0)));
+
+        // clones of the policy share the same rate limit
+        let clone = policy.clone();
+        assert!(clone.acquire_send_token() > *delays.last().unwrap());
+    }
+
+    #[test]
+    fn rate_limiter_cuts_rate_on_throttle_and_recovers_on_success() {
+        let mut limiter = RateLimiter::new();
+        limiter.measured_tx_rate = 10.0;
+        limiter.update(true);
+        assert!(limiter.enabled);
+        let throttled_rate = limiter.fill_rate;
+        assert!(throttled_rate < 10.0);
+
+        limiter.last_throttle_time -= 10.0;
+        limiter.update(false);
+        assert!(limiter.fill_rate > throttled_rate);
+    }
+}
@@ -0,0 +1,244 @@
+//! Timers of the async runtime a `Client` runs on.
+//!
+//! `Client` only needs a runtime to wait for retry delays and timeouts, and uses tokio unless
+//! another `Runtime` is set with `Client::set_runtime`. `HttpClient` and the credential
+//! providers making HTTP requests are built on hyper and still require tokio; on other runtimes
+//! dispatch requests through another `DispatchSignedRequest` instead. On wasm32 clients use
+//! `WasmRuntime`.
+//!
+//! The tokio pieces are behind the default `tokio` feature, which the `native-tls` and `rustls`
+//! features enable. Without it clients default to `AsyncStdRuntime` with the `async-std`
+//! feature, and otherwise to the timers of a single thread they share.
+
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::Arc;
+use std::time::Duration;
+
+use futures::future::{self, Either};
+
+/// Future returned from `Runtime::sleep`.
+pub type SleepFuture = Pin<Box<dyn Future<Output = ()> + Send>>;
+
+/// The timer of an async runtime. Implement it to run clients on runtimes such as smol, e.g.
+/// with `Box::pin(async move { smol::Timer::after(duration).await; })`.
+pub trait Runtime: Send + Sync {
+    /// A future completing once `duration` has elapsed.
+    fn sleep(&self, duration: Duration) -> SleepFuture;
+}
+
+impl<R: Runtime + ?Sized> Runtime for Arc<R> {
+    fn sleep(&self, duration: Duration) -> SleepFuture {
+        (**self).sleep(duration)
+    }
+}
+
+/// The tokio runtime, used by default.
+#[cfg(feature = "tokio")]
+#[derive(Clone, Copy, Debug, Default)]
+pub struct TokioRuntime;
+
+#[cfg(feature = "tokio")]
+impl Runtime for TokioRuntime {
+    fn sleep(&self, duration: Duration) -> SleepFuture {
+        Box::pin(tokio::time::delay_for(duration))
+    }
+}
+
+#[cfg(all(feature = "tokio", not(target_arch = "wasm32")))]
+pub(crate) type DefaultRuntime = TokioRuntime;
+#[cfg(all(
+    not(feature = "tokio"),
+    feature = "async-std",
+    not(target_arch = "wasm32")
+))]
+pub(crate) type DefaultRuntime = AsyncStdRuntime;
+#[cfg(not(any(feature = "tokio", feature = "async-std", target_arch = "wasm32")))]
+pub(crate) type DefaultRuntime = ThreadRuntime;
+#[cfg(target_arch = "wasm32")]
+pub(crate) type DefaultRuntime = crate::wasm::WasmRuntime;
+
+/// Timers of a single thread shared by all clients, used without a runtime feature. Also built
+/// for its tests, which run with the default features.
+#[cfg(all(
+    not(target_arch = "wasm32"),
+    any(test, not(any(feature = "tokio", feature = "async-std")))
+))]
+#[derive(Clone, Copy, Debug, Default)]
+pub(crate) struct ThreadRuntime;
+
+#[cfg(all(
+    not(target_arch = "wasm32"),
+    any(test, not(any(feature = "tokio", feature = "async-std")))
+))]
+impl Runtime for ThreadRuntime {
+    fn sleep(&self, duration: Duration) -> SleepFuture {
+        let (elapsed, sleep) = futures::channel::oneshot::channel();
+        // Without a timer thread sleeps complete right away rather than never
+        let _ = timer_thread::TIMERS
+            .lock()
+            .unwrap()
+            .send(timer_thread::Timer::new(duration, elapsed));
+        Box::pin(async move {
+            let _ = sleep.await;
+        })
+    }
+}
+
+#[cfg(all(
+    not(target_arch = "wasm32"),
+    any(test, not(any(feature = "tokio", feature = "async-std")))
+))]
+mod timer_thread {
+    use std::cmp::Ordering;
+    use std::collections::BinaryHeap;
+    use std::sync::mpsc::{self, RecvTimeoutError, Sender};
+    use std::sync::Mutex;
+    use std::time::{Duration, Instant};
+
+    use futures::channel::oneshot;
+    use lazy_static::lazy_static;
+
+    /// How many pending timers the thread keeps before it drops those nobody waits for anymore.
+    const MIN_PRUNE_LEN: usize = 64;
+
+    lazy_static! {
+        pub(super) static ref TIMERS: Mutex<Sender<Timer>> = {
+            let (timers, pending) = mpsc::channel();
+            std::thread::Builder::new()
+                .name("rusoto-timer".to_owned())
+                .spawn(move || run(pending))
+                .expect("failed to spawn the rusoto timer thread");
+            Mutex::new(timers)
+        };
+    }
+
+    /// A sleep waiting for the timer thread to reach its deadline.
+    pub(super) struct Timer {
+        deadline: Instant,
+        elapsed: oneshot::Sender<()>,
+    }
+
+    impl Timer {
+        pub(super) fn new(duration: Duration, elapsed: oneshot::Sender<()>) -> Timer {
+            Timer {
+                deadline: Instant::now() + duration,
+                elapsed,
+            }
+        }
+    }
+
+    // Ordered so that the `BinaryHeap` of timers pops the earliest deadline first
+    impl Ord for Timer {
+        fn cmp(&self, other: &Timer) -> Ordering {
+            other.deadline.cmp(&self.deadline)
+        }
+    }
+
+    impl PartialOrd for Timer {
+        fn partial_cmp(&self, other: &Timer) -> Option<Ordering> {
+            Some(self.cmp(other))
+        }
+    }
+
+    impl PartialEq for Timer {
+        fn eq(&self, other: &Timer) -> bool {
+            self.deadline == other.deadline
+        }
+    }
+
+    impl Eq for Timer {}
+
+    fn run(pending: mpsc::Receiver<Timer>) {
+        let mut timers = BinaryHeap::new();
+        let mut prune_len = MIN_PRUNE_LEN;
+        loop {
+            let now = Instant::now();
+            while timers
+                .peek()
+                .map_or(false, |timer: &Timer| timer.deadline <= now)
+            {
+                let _ = timers.pop().unwrap().elapsed.send(());
+            }
+            let received = match timers.peek() {
+                Some(timer) => pending.recv_timeout(timer.deadline - now),
+                None => pending.recv().map_err(|_| RecvTimeoutError::Disconnected),
+            };
+            match received {
+                Ok(timer) => timers.push(timer),
+                Err(RecvTimeoutError::Timeout) => {}
+                Err(RecvTimeoutError::Disconnected) => return,
+            }
+            // Sleeps are dropped before their deadlines when they time out futures completing
+            // in time; forget those instead of keeping them until they would have elapsed
+            if timers.len() >= prune_len {
+                timers = timers
+                    .into_iter()
+                    .filter(|timer| !timer.elapsed.is_canceled())
+                    .collect();
+                prune_len = MIN_PRUNE_LEN.max(timers.len() * 2);
+            }
+        }
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::super::{Runtime, ThreadRuntime};
+        use std::time::{Duration, Instant};
+
+        #[test]
+        fn sleeps_share_one_thread() {
+            let started = Instant::now();
+            let abandoned: Vec<_> = (0..2 * super::MIN_PRUNE_LEN)
+                .map(|_| ThreadRuntime.sleep(Duration::from_secs(60)))
+                .collect();
+            drop(abandoned);
+            futures::executor::block_on(futures::future::join(
+                ThreadRuntime.sleep(Duration::from_millis(20)),
+                ThreadRuntime.sleep(Duration::from_millis(10)),
+            ));
+            let elapsed = started.elapsed();
+            assert!(elapsed >= Duration::from_millis(20) && elapsed < Duration::from_secs(60));
+        }
+    }
+}
+
+/// The async-std runtime.
+#[cfg(feature = "async-std")]
+#[derive(Clone, Copy, Debug, Default)]
+pub struct AsyncStdRuntime;
+
+#[cfg(feature = "async-std")]
+impl Runtime for AsyncStdRuntime {
+    fn sleep(&self, duration: Duration) -> SleepFuture {
+        Box::pin(async_std::task::sleep(duration))
+    }
+}
+
+/// Await `future`, or `Err(())` if it doesn't complete within `duration`.
+pub(crate) async fn timeout<F: Future>(
+    runtime: &dyn Runtime,
+    duration: Duration,
+    future: F,
+) -> Result<F::Output, ()> {
+    let future = Box::pin(future);
+    match future::select(future, runtime.sleep(duration)).await {
+        Either::Left((output, _)) => Ok(output),
+        Either::Right(_) => Err(()),
+    }
+}
+
+#[cfg(all(test, feature = "tokio"))]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn times_out_futures() {
+        let runtime = TokioRuntime;
+        let ready = timeout(&runtime, Duration::from_secs(60), async { 1 }).await;
+        assert_eq!(ready, Ok(1));
+        let pending = future::pending::<()>();
+        let elapsed = timeout(&runtime, Duration::from_millis(1), pending).await;
+        assert_eq!(elapsed, Err(()));
+    }
+}
@@ -0,0 +1,78 @@
+//! Draining the requests in flight before shutting a client down.
+
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+use std::sync::Arc;
+
+use crate::cancel::CancellationToken;
+
+/// Future returned from `DispatchSignedRequest::shutdown` and `Client::shutdown`.
+pub type ShutdownFuture = Pin<Box<dyn Future<Output = ()> + Send>>;
+
+/// Counts the requests in flight, and rejects new ones once a shutdown has begun.
+#[derive(Default)]
+pub(crate) struct Drain {
+    closed: AtomicBool,
+    in_flight: AtomicUsize,
+    // Cancelled as soon as the drain is closed and no requests are in flight.
+    drained: CancellationToken,
+}
+
+impl Drain {
+    /// Register a request, or `None` if the drain is closed.
+    pub(crate) fn start(self: &Arc<Self>) -> Option<InFlight> {
+        self.in_flight.fetch_add(1, Ordering::SeqCst);
+        let request = InFlight {
+            drain: self.clone(),
+        };
+        if self.is_closed() {
+            return None;
+        }
+        Some(request)
+    }
+
+    /// Whether `close` has been called.
+    pub(crate) fn is_closed(&self) -> bool {
+        self.closed.load(Ordering::SeqCst)
+    }
+
+    /// Reject new requests, and wait for the ones in flight to complete.
+    pub(crate) fn close(&self) -> impl Future<Output = ()> + Send + 'static {
+        self.closed.store(true, Ordering::SeqCst);
+        if self.in_flight.load(Ordering::SeqCst) == 0 {
+            self.drained.cancel();
+        }
+        self.drained.cancelled()
+    }
+}
+
+/// A request in flight, completed when dropped.
+pub(crate) struct InFlight {
+    drain: Arc<Drain>,
+}
+
+impl Drop for InFlight {
+    fn drop(&mut self) {
+        if self.drain.in_flight.fetch_sub(1, Ordering::SeqCst) == 1 && self.drain.is_closed() {
+            self.drain.drained.cancel();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use futures::FutureExt;
+
+    #[test]
+    fn waits_for_requests_in_flight() {
+        let drain = Arc::new(Drain::default());
+        let request = drain.start().unwrap();
+        let mut closed = drain.close().boxed();
+        assert!(drain.start().is_none());
+        assert!((&mut closed).now_or_never().is_none());
+        drop(request);
+        assert!(closed.now_or_never().is_some());
+    }
+}
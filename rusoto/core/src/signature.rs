@@ -1,2 +1,5 @@
 // moved to rusoto_signature
+pub use rusoto_signature::cassandra;
+pub use rusoto_signature::clock;
+pub use rusoto_signature::http_request;
 pub use rusoto_signature::signature::*;
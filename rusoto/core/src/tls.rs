@@ -0,0 +1,414 @@
+//! TLS for `HttpClient`, through native-tls or rustls.
+//!
+//! The backends are enabled by the `native-tls` and `rustls` features. With both enabled,
+//! `TlsConfig::set_backend` chooses between them at runtime.
+
+use std::error::Error;
+use std::fmt;
+use std::future::Future;
+use std::io;
+use std::mem::MaybeUninit;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
+use bytes::{Buf, BufMut};
+use http::Uri;
+use hyper::client::connect::{Connected, Connection};
+use hyper::service::Service;
+use tokio::io::{AsyncRead, AsyncWrite};
+
+use crate::request::HttpVersion;
+
+#[cfg(not(any(feature = "native-tls", feature = "rustls")))]
+compile_error!("rusoto_core requires the `native-tls` or the `rustls` feature");
+
+type BoxError = Box<dyn Error + Send + Sync>;
+
+/// The TLS implementations `HttpClient` can use.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum TlsBackend {
+    /// The platform's TLS library through native-tls: OpenSSL, Secure Transport or SChannel.
+    #[cfg(feature = "native-tls")]
+    NativeTls,
+    /// rustls, which doesn't depend on any system library.
+    #[cfg(feature = "rustls")]
+    Rustls,
+}
+
+impl Default for TlsBackend {
+    /// native-tls if the `native-tls` feature is enabled, rustls otherwise.
+    fn default() -> TlsBackend {
+        #[cfg(feature = "native-tls")]
+        return TlsBackend::NativeTls;
+        #[cfg(not(feature = "native-tls"))]
+        return TlsBackend::Rustls;
+    }
+}
+
+/// TLS settings of an `HttpClient`, set with `HttpConfig::tls`.
+#[derive(Clone, Debug)]
+pub struct TlsConfig {
+    backend: TlsBackend,
+    root_certificates: Vec<Vec<u8>>,
+    native_roots: bool,
+    client_identity: Option<(Vec<u8>, Vec<u8>)>,
+}
+
+impl TlsConfig {
+    /// The default settings: the default backend trusting the certificate authorities of the
+    /// platform.
+    pub fn new() -> TlsConfig {
+        TlsConfig {
+            backend: TlsBackend::default(),
+            root_certificates: Vec::new(),
+            native_roots: true,
+            client_identity: None,
+        }
+    }
+
+    /// Sets the TLS implementation to use.
+    pub fn set_backend(&mut self, backend: TlsBackend) {
+        self.backend = backend;
+    }
+
+    /// Trusts the certificate authorities in a PEM bundle, e.g. the one of a TLS intercepting
+    /// proxy, in addition to those of the platform.
+    pub fn add_root_certificates(&mut self, pem: &[u8]) {
+        self.root_certificates.push(pem.to_vec());
+    }
+
+    /// Sets whether to trust the certificate authorities of the platform. With `false` only the
+    /// ones added with `add_root_certificates` are trusted.
+    pub fn set_native_roots(&mut self, enabled: bool) {
+        self.native_roots = enabled;
+    }
+
+    /// Presents a client certificate to servers requesting one, given as a PEM certificate chain
+    /// and a PEM encoded PKCS #8 private key.
+    pub fn set_client_identity(&mut self, certificate_chain: &[u8], private_key: &[u8]) {
+        self.client_identity = Some((certificate_chain.to_vec(), private_key.to_vec()));
+    }
+}
+
+impl Default for TlsConfig {
+    fn default() -> TlsConfig {
+        TlsConfig::new()
+    }
+}
+
+#[derive(Debug, PartialEq)]
+/// An error produced when the user has an invalid TLS client
+pub struct TlsError {
+    message: String,
+}
+
+impl TlsError {
+    fn new<E: fmt::Display>(context: &str, err: E) -> TlsError {
+        TlsError {
+            message: format!("{}: {}", context, err),
+        }
+    }
+}
+
+impl Error for TlsError {}
+
+impl fmt::Display for TlsError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.message)
+    }
+}
+
+/// A connector wrapping the connections of another one with TLS for `https` URIs.
+#[derive(Clone)]
+pub struct HttpsConnector<C> {
+    inner: HttpsConnectorInner<C>,
+}
+
+#[derive(Clone)]
+enum HttpsConnectorInner<C> {
+    #[cfg(feature = "native-tls")]
+    NativeTls(hyper_tls::HttpsConnector<C>),
+    #[cfg(feature = "rustls")]
+    Rustls(hyper_rustls::HttpsConnector<C>),
+}
+
+impl<C> HttpsConnector<C> {
+    /// Wrap the connections of `http` using the given configuration.
+    pub fn new(http: C, config: &TlsConfig) -> Result<HttpsConnector<C>, TlsError> {
+        HttpsConnector::with_http_version(http, config, HttpVersion::Negotiate)
+    }
+
+    pub(crate) fn with_http_version(
+        http: C,
+        config: &TlsConfig,
+        version: HttpVersion,
+    ) -> Result<HttpsConnector<C>, TlsError> {
+        let inner = match config.backend {
+            #[cfg(feature = "native-tls")]
+            TlsBackend::NativeTls => {
+                HttpsConnectorInner::NativeTls(native_tls_connector(http, config, version)?)
+            }
+            #[cfg(feature = "rustls")]
+            TlsBackend::Rustls => {
+                HttpsConnectorInner::Rustls(rustls_connector(http, config, version)?)
+            }
+        };
+        Ok(HttpsConnector { inner })
+    }
+}
+
+/// hyper-tls doesn't report the protocol negotiated through ALPN, so `h2` is only offered when
+/// HTTP/2 is forced.
+#[cfg(feature = "native-tls")]
+fn native_tls_connector<C>(
+    http: C,
+    config: &TlsConfig,
+    version: HttpVersion,
+) -> Result<hyper_tls::HttpsConnector<C>, TlsError> {
+    use native_tls_crate::{Certificate, Identity};
+
+    let mut builder = native_tls_crate::TlsConnector::builder();
+    if version == HttpVersion::Http2 {
+        builder.request_alpns(&["h2"]);
+    }
+    builder.disable_built_in_roots(!config.native_roots);
+    for bundle in &config.root_certificates {
+        for pem in split_certificates(bundle) {
+            let certificate = Certificate::from_pem(pem)
+                .map_err(|e| TlsError::new("Couldn't parse root certificate", e))?;
+            builder.add_root_certificate(certificate);
+        }
+    }
+    if let Some((ref certificate_chain, ref private_key)) = config.client_identity {
+        let identity = Identity::from_pkcs8(certificate_chain, private_key)
+            .map_err(|e| TlsError::new("Couldn't parse client identity", e))?;
+        builder.identity(identity);
+    }
+    let tls = builder
+        .build()
+        .map_err(|e| TlsError::new("Couldn't create the TLS connector", e))?;
+    Ok(hyper_tls::HttpsConnector::from((http, tls.into())))
+}
+
+/// The certificates of a PEM bundle, as native-tls only parses the first one of a bundle.
+#[cfg(feature = "native-tls")]
+fn split_certificates(bundle: &[u8]) -> Vec<&[u8]> {
+    const END: &[u8] = b"-----END CERTIFICATE-----";
+    let mut certificates = Vec::new();
+    let mut rest = bundle;
+    while let Some(index) = rest.windows(END.len()).position(|window| window == END) {
+        certificates.push(&rest[..index + END.len()]);
+        rest = &rest[index + END.len()..];
+    }
+    certificates
+}
+
+#[cfg(feature = "rustls")]
+fn rustls_connector<C>(
+    http: C,
+    config: &TlsConfig,
+    version: HttpVersion,
+) -> Result<hyper_rustls::HttpsConnector<C>, TlsError> {
+    use rustls_crate::internal::pemfile;
+
+    let mut tls = rustls_crate::ClientConfig::new();
+    tls.alpn_protocols = match version {
+        HttpVersion::Http1 => vec![b"http/1.1".to_vec()],
+        HttpVersion::Negotiate => vec![b"h2".to_vec(), b"http/1.1".to_vec()],
+        HttpVersion::Http2 => vec![b"h2".to_vec()],
+    };
+    if config.native_roots {
+        tls.root_store = rustls_native_certs::load_native_certs()
+            .map_err(|e| TlsError::new("Couldn't load native certificates", e))?;
+    }
+    for bundle in &config.root_certificates {
+        match tls.root_store.add_pem_file(&mut &bundle[..]) {
+            Ok((valid, 0)) if valid > 0 => {}
+            _ => {
+                return Err(TlsError::new(
+                    "Couldn't parse root certificate",
+                    "invalid PEM bundle",
+                ))
+            }
+        }
+    }
+    if let Some((ref certificate_chain, ref private_key)) = config.client_identity {
+        let certificates = pemfile::certs(&mut &certificate_chain[..])
+            .ok()
+            .filter(|certificates| !certificates.is_empty());
+        let key = pemfile::pkcs8_private_keys(&mut &private_key[..])
+            .ok()
+            .and_then(|mut keys| keys.pop());
+        match (certificates, key) {
+            (Some(certificates), Some(key)) => tls.set_single_client_cert(certificates, key),
+            _ => {
+                return Err(TlsError::new(
+                    "Couldn't parse client identity",
+                    "invalid PEM certificate chain or PKCS #8 private key",
+                ))
+            }
+        }
+    }
+    Ok(hyper_rustls::HttpsConnector::from((http, tls)))
+}
+
+impl<C> Service<Uri> for HttpsConnector<C>
+where
+    C: Service<Uri>,
+    C::Response: Connection + AsyncRead + AsyncWrite + Send + Unpin + 'static,
+    C::Future: Send + 'static,
+    C::Error: Into<BoxError>,
+{
+    type Response = HttpsStream<C::Response>;
+    type Error = BoxError;
+    type Future = Pin<Box<dyn Future<Output = Result<Self::Response, BoxError>> + Send>>;
+
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), BoxError>> {
+        match self.inner {
+            #[cfg(feature = "native-tls")]
+            HttpsConnectorInner::NativeTls(ref mut connector) => connector.poll_ready(cx),
+            #[cfg(feature = "rustls")]
+            HttpsConnectorInner::Rustls(ref mut connector) => connector.poll_ready(cx),
+        }
+    }
+
+    fn call(&mut self, dst: Uri) -> Self::Future {
+        match self.inner {
+            #[cfg(feature = "native-tls")]
+            HttpsConnectorInner::NativeTls(ref mut connector) => {
+                let connecting = connector.call(dst);
+                Box::pin(async move {
+                    let inner = HttpsStreamInner::NativeTls(connecting.await?);
+                    Ok(HttpsStream { inner })
+                })
+            }
+            #[cfg(feature = "rustls")]
+            HttpsConnectorInner::Rustls(ref mut connector) => {
+                let connecting = connector.call(dst);
+                Box::pin(async move {
+                    let inner = HttpsStreamInner::Rustls(Box::new(connecting.await?));
+                    Ok(HttpsStream { inner })
+                })
+            }
+        }
+    }
+}
+
+/// A connection opened by an `HttpsConnector`, using TLS for `https` URIs.
+pub struct HttpsStream<S> {
+    inner: HttpsStreamInner<S>,
+}
+
+enum HttpsStreamInner<S> {
+    #[cfg(feature = "native-tls")]
+    NativeTls(hyper_tls::MaybeHttpsStream<S>),
+    #[cfg(feature = "rustls")]
+    Rustls(Box<hyper_rustls::MaybeHttpsStream<S>>),
+}
+
+/// Evaluates `$call` with the stream of whichever backend opened the connection.
+macro_rules! delegate {
+    ($stream:expr, $binding:pat => $call:expr) => {
+        match $stream {
+            #[cfg(feature = "native-tls")]
+            HttpsStreamInner::NativeTls($binding) => $call,
+            #[cfg(feature = "rustls")]
+            HttpsStreamInner::Rustls($binding) => $call,
+        }
+    };
+}
+
+impl<S: AsyncRead + AsyncWrite + Connection + Unpin> Connection for HttpsStream<S> {
+    fn connected(&self) -> Connected {
+        delegate!(self.inner, ref stream => stream.connected())
+    }
+}
+
+impl<S: AsyncRead + AsyncWrite + Unpin> AsyncRead for HttpsStream<S> {
+    unsafe fn prepare_uninitialized_buffer(&self, buf: &mut [MaybeUninit<u8>]) -> bool {
+        delegate!(self.inner, ref stream => stream.prepare_uninitialized_buffer(buf))
+    }
+
+    fn poll_read(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &mut [u8],
+    ) -> Poll<io::Result<usize>> {
+        delegate!(self.inner, ref mut stream => Pin::new(stream).poll_read(cx, buf))
+    }
+
+    fn poll_read_buf<B: BufMut>(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &mut B,
+    ) -> Poll<io::Result<usize>> {
+        delegate!(self.inner, ref mut stream => Pin::new(stream).poll_read_buf(cx, buf))
+    }
+}
+
+impl<S: AsyncRead + AsyncWrite + Unpin> AsyncWrite for HttpsStream<S> {
+    fn poll_write(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &[u8],
+    ) -> Poll<io::Result<usize>> {
+        delegate!(self.inner, ref mut stream => Pin::new(stream).poll_write(cx, buf))
+    }
+
+    fn poll_flush(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        delegate!(self.inner, ref mut stream => Pin::new(stream).poll_flush(cx))
+    }
+
+    fn poll_shutdown(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        delegate!(self.inner, ref mut stream => Pin::new(stream).poll_shutdown(cx))
+    }
+
+    fn poll_write_buf<B: Buf>(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &mut B,
+    ) -> Poll<io::Result<usize>> {
+        delegate!(self.inner, ref mut stream => Pin::new(stream).poll_write_buf(cx, buf))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn backends() -> Vec<TlsBackend> {
+        vec![
+            #[cfg(feature = "native-tls")]
+            TlsBackend::NativeTls,
+            #[cfg(feature = "rustls")]
+            TlsBackend::Rustls,
+        ]
+    }
+
+    #[test]
+    fn rejects_invalid_certificates() {
+        for backend in backends() {
+            let mut config = TlsConfig::new();
+            config.set_backend(backend);
+            config.set_native_roots(false);
+            assert!(HttpsConnector::new((), &config).is_ok());
+
+            config.add_root_certificates(
+                b"-----BEGIN CERTIFICATE-----\nbm90IGEgY2VydA==\n-----END CERTIFICATE-----\n",
+            );
+            let err = HttpsConnector::new((), &config).err().unwrap();
+            assert!(err
+                .to_string()
+                .starts_with("Couldn't parse root certificate"));
+
+            let mut config = TlsConfig::new();
+            config.set_backend(backend);
+            config.set_native_roots(false);
+            config.set_client_identity(b"", b"");
+            let err = HttpsConnector::new((), &config).err().unwrap();
+            assert!(err
+                .to_string()
+                .starts_with("Couldn't parse client identity"));
+        }
+    }
+}
@@ -0,0 +1,260 @@
+//! Waiters polling an operation until the resource it describes reaches a state.
+//!
+//! The service clients have `wait_until_` methods for the waiters botocore describes, which
+//! `wait` polls with their operation, deciding after each attempt with the acceptors of the
+//! waiter whether it succeeded, failed or should be retried.
+
+use std::error::Error;
+use std::fmt;
+use std::future::Future;
+use std::sync::Arc;
+use std::time::Duration;
+
+use crate::error::RusotoError;
+use crate::runtime::{DefaultRuntime, Runtime};
+
+/// How often and how many times a waiter polls: at a fixed delay between attempts, until it
+/// has made the maximum number of attempts.
+#[derive(Clone)]
+pub struct Waiter {
+    delay: Duration,
+    max_attempts: u32,
+    runtime: Arc<dyn Runtime>,
+}
+
+impl Waiter {
+    /// A waiter making up to `max_attempts` attempts, `delay` apart, waiting on the default
+    /// runtime.
+    pub fn new(delay: Duration, max_attempts: u32) -> Waiter {
+        Waiter {
+            delay,
+            max_attempts,
+            runtime: Arc::new(DefaultRuntime::default()),
+        }
+    }
+
+    /// The delay between attempts.
+    pub fn delay(&self) -> Duration {
+        self.delay
+    }
+
+    /// Sets the delay between attempts.
+    pub fn set_delay(&mut self, delay: Duration) {
+        self.delay = delay;
+    }
+
+    /// The number of attempts after which the waiter times out.
+    pub fn max_attempts(&self) -> u32 {
+        self.max_attempts
+    }
+
+    /// Sets the number of attempts after which the waiter times out.
+    pub fn set_max_attempts(&mut self, max_attempts: u32) {
+        self.max_attempts = max_attempts;
+    }
+
+    /// Sets the runtime waiting for the delays between attempts.
+    pub fn set_runtime<R>(&mut self, runtime: R)
+    where
+        R: Runtime + 'static,
+    {
+        self.runtime = Arc::new(runtime);
+    }
+}
+
+impl fmt::Debug for Waiter {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Waiter")
+            .field("delay", &self.delay)
+            .field("max_attempts", &self.max_attempts)
+            .finish()
+    }
+}
+
+/// The state an acceptor of a waiter matching the result of an attempt puts the waiter in.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum WaiterState {
+    /// The resource reached the state waited for.
+    Success,
+    /// The resource reached a state it won't leave for the one waited for.
+    Failure,
+    /// The resource may still reach the state waited for.
+    Retry,
+}
+
+/// An error waiting for a resource to reach a state.
+#[derive(Debug, PartialEq)]
+pub enum WaiterError<E> {
+    /// The resource reached a state it won't leave for the one waited for, with the error an
+    /// acceptor matched, if it matched one.
+    Failed(Option<RusotoError<E>>),
+    /// An attempt failed with an error no acceptor matched.
+    Operation(RusotoError<E>),
+    /// The resource didn't reach the state after the number of attempts.
+    TimedOut(u32),
+}
+
+impl<E: Error + 'static> fmt::Display for WaiterError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            WaiterError::Failed(Some(error)) => {
+                write!(f, "the resource reached a failure state: {}", error)
+            }
+            WaiterError::Failed(None) => write!(f, "the resource reached a failure state"),
+            WaiterError::Operation(error) => write!(f, "{}", error),
+            WaiterError::TimedOut(attempts) => write!(
+                f,
+                "the resource didn't reach the state after {} attempts",
+                attempts
+            ),
+        }
+    }
+}
+
+impl<E: Error + 'static> Error for WaiterError<E> {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            WaiterError::Failed(Some(error)) | WaiterError::Operation(error) => Some(error),
+            WaiterError::Failed(None) | WaiterError::TimedOut(_) => None,
+        }
+    }
+}
+
+/// Calls `poll` until `accept` returns `WaiterState::Success` or `WaiterState::Failure` for
+/// its result, as `waiter` does.
+///
+/// `accept` returns the state of the first acceptor matching the result, `None` if none does,
+/// which retries after an output and fails after an error.
+pub async fn wait<O, E, F, Fut, A>(
+    waiter: &Waiter,
+    mut poll: F,
+    accept: A,
+) -> Result<(), WaiterError<E>>
+where
+    F: FnMut() -> Fut,
+    Fut: Future<Output = Result<O, RusotoError<E>>>,
+    A: Fn(&Result<O, RusotoError<E>>) -> Option<WaiterState>,
+{
+    let mut attempts = 0;
+    loop {
+        let result = poll().await;
+        attempts += 1;
+        match (accept(&result), result) {
+            (Some(WaiterState::Success), _) => return Ok(()),
+            (Some(WaiterState::Failure), result) => return Err(WaiterError::Failed(result.err())),
+            (None, Err(error)) => return Err(WaiterError::Operation(error)),
+            (Some(WaiterState::Retry), _) | (None, Ok(_)) => {}
+        }
+        if attempts >= waiter.max_attempts {
+            return Err(WaiterError::TimedOut(attempts));
+        }
+        waiter.runtime.sleep(waiter.delay).await;
+    }
+}
+
+/// The HTTP status of the response of an attempt, as the `status` acceptors of waiters match
+/// it: 200 for an output.
+pub fn status<O, E>(result: &Result<O, RusotoError<E>>) -> Option<u16> {
+    match result {
+        Ok(_) => Some(200),
+        Err(error) => error
+            .metadata()
+            .and_then(|metadata| metadata.status())
+            .map(|status| status.as_u16()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::error::ErrorMetadata;
+    use crate::runtime::SleepFuture;
+    use futures::executor::block_on;
+    use futures::future::ready;
+    use std::sync::Mutex;
+
+    #[derive(Debug, PartialEq)]
+    struct NotFound;
+
+    impl fmt::Display for NotFound {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            write!(f, "not found")
+        }
+    }
+
+    impl Error for NotFound {}
+
+    #[derive(Clone, Default)]
+    struct RecordingRuntime {
+        sleeps: Arc<Mutex<Vec<Duration>>>,
+    }
+
+    impl Runtime for RecordingRuntime {
+        fn sleep(&self, duration: Duration) -> SleepFuture {
+            self.sleeps.lock().unwrap().push(duration);
+            Box::pin(ready(()))
+        }
+    }
+
+    fn waiter(runtime: &RecordingRuntime) -> Waiter {
+        let mut waiter = Waiter::new(Duration::from_secs(5), 3);
+        waiter.set_runtime(runtime.clone());
+        waiter
+    }
+
+    fn accept(result: &Result<&'static str, RusotoError<NotFound>>) -> Option<WaiterState> {
+        match result {
+            Ok("ACTIVE") => Some(WaiterState::Success),
+            Ok("FAILED") => Some(WaiterState::Failure),
+            Err(RusotoError::Service(NotFound, _)) => Some(WaiterState::Retry),
+            _ => None,
+        }
+    }
+
+    fn poll(
+        states: &'static [Result<&'static str, ()>],
+    ) -> impl FnMut() -> futures::future::Ready<Result<&'static str, RusotoError<NotFound>>> {
+        let mut states = states.iter();
+        move || {
+            ready(match states.next().unwrap() {
+                Ok(state) => Ok(*state),
+                Err(()) => Err(RusotoError::Service(NotFound, ErrorMetadata::default())),
+            })
+        }
+    }
+
+    #[test]
+    fn waits_for_success() {
+        let runtime = RecordingRuntime::default();
+        let result = block_on(wait(
+            &waiter(&runtime),
+            poll(&[Err(()), Ok("CREATING"), Ok("ACTIVE")]),
+            accept,
+        ));
+        assert_eq!(result, Ok(()));
+        assert_eq!(
+            *runtime.sleeps.lock().unwrap(),
+            vec![Duration::from_secs(5); 2]
+        );
+    }
+
+    #[test]
+    fn fails_and_times_out() {
+        let runtime = RecordingRuntime::default();
+        let result = block_on(wait(
+            &waiter(&runtime),
+            poll(&[Ok("CREATING"), Ok("FAILED")]),
+            accept,
+        ));
+        assert_eq!(result, Err(WaiterError::Failed(None)));
+
+        let result = block_on(wait(
+            &waiter(&runtime),
+            poll(&[Ok("CREATING"), Ok("CREATING"), Ok("CREATING")]),
+            accept,
+        ));
+        assert_eq!(result, Err(WaiterError::TimedOut(3)));
+        assert_eq!(runtime.sleeps.lock().unwrap().len(), 3);
+        assert_eq!(status(&Ok::<_, RusotoError<NotFound>>(())), Some(200));
+    }
+}
@@ -0,0 +1,177 @@
+//! Running clients on wasm32, in browsers and other JavaScript hosts.
+//!
+//! There is no `HttpClient` and no default credentials chain on wasm32. Requests are sent
+//! through the host's `fetch` function with a `FetchClient`, and credentials are passed in
+//! explicitly:
+//!
+//! ```rust,ignore
+//! let credentials = StaticProvider::new_minimal(access_key, secret_key);
+//! let client = SqsClient::new_with(FetchClient::new(), credentials, Region::UsEast1);
+//! ```
+//!
+//! Timers use the host's `setTimeout` through `WasmRuntime`.
+
+use std::future::Future;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+use std::time::Duration;
+
+use bytes::Bytes;
+use futures::future::{self, Either};
+use futures::{FutureExt, TryStreamExt};
+use http::{Request, Response};
+use js_sys::{Function, Promise, Reflect, Uint8Array};
+use wasm_bindgen::{JsCast, JsValue};
+use wasm_bindgen_futures::JsFuture;
+use web_sys::{Headers, RequestInit};
+
+use crate::request::{DispatchSignedRequest, DispatchSignedRequestFuture, HttpDispatchError};
+use crate::runtime::{Runtime, SleepFuture};
+use crate::stream::ByteStream;
+
+/// Runs futures that aren't `Send`, such as those awaiting JavaScript promises, where `Send`
+/// futures are required.
+///
+/// wasm32-unknown-unknown has a single thread, so the futures can never be sent across threads.
+struct AssertSend<F>(F);
+
+// SAFETY: see above; there are no other threads the future could be moved to on wasm32.
+unsafe impl<F> Send for AssertSend<F> {}
+
+impl<F: Future> Future for AssertSend<F> {
+    type Output = F::Output;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        // SAFETY: the inner future is never moved out of the pinned wrapper.
+        unsafe { self.map_unchecked_mut(|this| &mut this.0) }.poll(cx)
+    }
+}
+
+/// Look up a function of the host's global object, such as `fetch` or `setTimeout`.
+fn global_function(name: &str) -> Result<Function, JsValue> {
+    Reflect::get(&js_sys::global(), &JsValue::from_str(name))?.dyn_into()
+}
+
+/// The timers of the JavaScript host, used by default on wasm32.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct WasmRuntime;
+
+impl Runtime for WasmRuntime {
+    fn sleep(&self, duration: Duration) -> SleepFuture {
+        let delay = JsValue::from_f64(duration.as_millis() as f64);
+        let promise = Promise::new(&mut |resolve, _reject| {
+            if let Ok(set_timeout) = global_function("setTimeout") {
+                let _ = set_timeout.call2(&JsValue::UNDEFINED, &resolve, &delay);
+            }
+        });
+        Box::pin(AssertSend(JsFuture::from(promise).map(|_| ())))
+    }
+}
+
+/// Sends requests with the `fetch` function of the JavaScript host.
+///
+/// Request and response bodies are buffered in memory. Timeouts bound the wait for the
+/// response headers, not the reading of the body.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct FetchClient;
+
+impl FetchClient {
+    /// Create a client.
+    pub fn new() -> FetchClient {
+        FetchClient
+    }
+}
+
+impl DispatchSignedRequest for FetchClient {
+    fn dispatch(
+        &self,
+        request: Request<ByteStream>,
+        timeout: Option<Duration>,
+    ) -> DispatchSignedRequestFuture {
+        let fetch = fetch(request);
+        let fetch = async move {
+            let js_response = match timeout {
+                None => fetch.await?,
+                Some(timeout) => {
+                    match future::select(Box::pin(fetch), WasmRuntime.sleep(timeout)).await {
+                        Either::Left((response, _)) => response?,
+                        Either::Right(_) => {
+                            return Err(HttpDispatchError::new(
+                                "Timeout while dispatching request".to_owned(),
+                            ))
+                        }
+                    }
+                }
+            };
+            read_response(js_response).await
+        };
+        Box::pin(AssertSend(fetch))
+    }
+}
+
+fn js_error(context: &str, err: JsValue) -> HttpDispatchError {
+    HttpDispatchError::new(format!("{}: {:?}", context, err))
+}
+
+/// Send `request`, resolving once the response headers arrive.
+async fn fetch(request: Request<ByteStream>) -> Result<web_sys::Response, HttpDispatchError> {
+    let (parts, body) = request.into_parts();
+    let body: Vec<Bytes> = body
+        .try_collect()
+        .await
+        .map_err(|err| HttpDispatchError::new(format!("Error reading request body: {}", err)))?;
+    let body = body.concat();
+
+    let headers = Headers::new().map_err(|err| js_error("Error building request", err))?;
+    for (name, value) in &parts.headers {
+        let value = String::from_utf8_lossy(value.as_bytes());
+        headers
+            .append(name.as_str(), &value)
+            .map_err(|err| js_error("Error building request", err))?;
+    }
+    let init = RequestInit::new();
+    init.set_method(parts.method.as_str());
+    init.set_headers(&headers);
+    if !body.is_empty() {
+        init.set_body(&Uint8Array::from(&body[..]));
+    }
+    let js_request = web_sys::Request::new_with_str_and_init(&parts.uri.to_string(), &init)
+        .map_err(|err| js_error("Error building request", err))?;
+
+    let fetch = global_function("fetch").map_err(|err| js_error("fetch is unavailable", err))?;
+    let promise: Promise = fetch
+        .call1(&JsValue::UNDEFINED, &js_request)
+        .and_then(JsCast::dyn_into)
+        .map_err(|err| js_error("Error during dispatch", err))?;
+    JsFuture::from(promise)
+        .await
+        .and_then(JsCast::dyn_into)
+        .map_err(|err| js_error("Error during dispatch", err))
+}
+
+/// The response of `fetch`, with its body read into memory.
+async fn read_response(
+    js_response: web_sys::Response,
+) -> Result<Response<ByteStream>, HttpDispatchError> {
+    let mut response = Response::builder().status(js_response.status());
+    let entries = js_sys::try_iter(js_response.headers().as_ref())
+        .ok()
+        .flatten();
+    for entry in entries.into_iter().flatten().flatten() {
+        let entry: js_sys::Array = entry.unchecked_into();
+        if let (Some(name), Some(value)) = (entry.get(0).as_string(), entry.get(1).as_string()) {
+            response = response.header(name.as_str(), value.as_str());
+        }
+    }
+
+    let buffer = js_response
+        .array_buffer()
+        .map_err(|err| js_error("Error obtaining body", err))?;
+    let buffer = JsFuture::from(buffer)
+        .await
+        .map_err(|err| js_error("Error obtaining body", err))?;
+    let body = Uint8Array::new(&buffer).to_vec();
+    response
+        .body(ByteStream::from(body))
+        .map_err(|err| HttpDispatchError::new(err.to_string()))
+}
@@ -0,0 +1,266 @@
+//! Logging of the full requests and responses sent by `HttpClient`, with secrets redacted.
+//!
+//! Enabled with `HttpConfig::wire_logging` or `HttpClient::set_wire_logging`, and written to the
+//! `rusoto_core::wire` log target at debug level.
+
+use futures::StreamExt;
+use http::{HeaderMap, Response};
+use log::debug;
+
+use crate::signature::{SignedRequest, SignedRequestPayload};
+use crate::stream::ByteStream;
+
+const TARGET: &str = "rusoto_core::wire";
+const REDACTED: &str = "** redacted **";
+
+/// Headers whose values are never logged.
+const SECRET_HEADERS: &[&str] = &[
+    "authorization",
+    "cookie",
+    "set-cookie",
+    "x-amz-security-token",
+    "x-amz-server-side-encryption-customer-key",
+    "x-amz-copy-source-server-side-encryption-customer-key",
+];
+
+/// Query string parameters whose values are never logged, as used by presigned requests.
+const SECRET_PARAMS: &[&str] = &[
+    "X-Amz-Credential",
+    "X-Amz-Security-Token",
+    "X-Amz-Signature",
+];
+
+/// Fields of XML, JSON and form encoded bodies whose values are never logged.
+const SECRET_FIELDS: &[&str] = &[
+    "AccessToken",
+    "AuthorizationToken",
+    "IdToken",
+    "Password",
+    "Plaintext",
+    "PrivateKey",
+    "RefreshToken",
+    "SecretAccessKey",
+    "SecretBinary",
+    "SecretKey",
+    "SecretString",
+    "SessionToken",
+];
+
+pub(crate) fn log_request(parts: &http::request::Parts, body: &ByteStream) {
+    debug!(
+        target: TARGET,
+        "request: {} {}",
+        parts.method,
+        redact_uri(&parts.uri.to_string())
+    );
+    log_headers("request header", &parts.headers);
+    let buffer = match parts.extensions.get::<SignedRequest>() {
+        Some(SignedRequest {
+            payload: Some(SignedRequestPayload::Buffer(ref buf)),
+            ..
+        }) => Some(buf),
+        _ => None,
+    };
+    match buffer {
+        Some(buf) => debug!(target: TARGET, "request body: {}", redact_body(buf)),
+        None if body.size_hint() == Some(0) => {}
+        None => debug!(target: TARGET, "request body: {:?}", body),
+    }
+}
+
+/// Log the status and headers of `response` and wrap its body so that every chunk is logged as
+/// it is read.
+///
+/// Secrets are redacted within each chunk, so a secret split between two chunks may be logged.
+pub(crate) fn log_response(response: Response<ByteStream>) -> Response<ByteStream> {
+    debug!(target: TARGET, "response: {}", response.status());
+    log_headers("response header", response.headers());
+    response.map(|body| {
+        ByteStream::new(body.inspect(|chunk| match chunk {
+            Ok(chunk) => debug!(target: TARGET, "response body: {}", redact_body(chunk)),
+            Err(err) => debug!(target: TARGET, "response body error: {}", err),
+        }))
+    })
+}
+
+fn log_headers(kind: &str, headers: &HeaderMap) {
+    for (name, value) in headers {
+        let value = if is_secret_header(name.as_str()) {
+            REDACTED
+        } else {
+            value.to_str().unwrap_or("<binary>")
+        };
+        debug!(target: TARGET, "{}: {}: {}", kind, name, value);
+    }
+}
+
+fn is_secret_header(name: &str) -> bool {
+    SECRET_HEADERS
+        .iter()
+        .any(|secret| name.eq_ignore_ascii_case(secret))
+}
+
+fn redact_uri(uri: &str) -> String {
+    let (path, query) = match uri.find('?') {
+        Some(index) => (&uri[..index], &uri[index + 1..]),
+        None => return uri.to_owned(),
+    };
+    format!("{}?{}", path, redact_pairs(query, SECRET_PARAMS))
+}
+
+/// Redact the secret values of `key=value` pairs joined by `&`.
+fn redact_pairs(pairs: &str, secrets: &[&str]) -> String {
+    pairs
+        .split('&')
+        .map(|pair| match pair.find('=') {
+            Some(index) if is_secret_key(&pair[..index], secrets) => {
+                format!("{}={}", &pair[..index], REDACTED)
+            }
+            _ => pair.to_owned(),
+        })
+        .collect::<Vec<_>>()
+        .join("&")
+}
+
+/// Form encoded keys of nested fields look like `Credentials.SecretAccessKey`.
+fn is_secret_key(key: &str, secrets: &[&str]) -> bool {
+    let field = key.rsplit('.').next().unwrap_or(key);
+    secrets
+        .iter()
+        .any(|secret| field.eq_ignore_ascii_case(secret))
+}
+
+fn redact_body(body: &[u8]) -> String {
+    let body = String::from_utf8_lossy(body);
+    match body.trim_start().chars().next() {
+        Some('<') => redact_xml(&body),
+        Some('{') | Some('[') => redact_json(&body),
+        Some(_) if !body.contains(char::is_whitespace) => redact_pairs(&body, SECRET_FIELDS),
+        _ => body.into_owned(),
+    }
+}
+
+fn redact_xml(body: &str) -> String {
+    let mut body = body.to_owned();
+    for field in SECRET_FIELDS {
+        let open = format!("<{}>", field);
+        let close = format!("</{}>", field);
+        let mut from = 0;
+        while let Some(start) = body[from..].find(&open).map(|i| from + i + open.len()) {
+            let end = match body[start..].find(&close) {
+                Some(end) => start + end,
+                None => break,
+            };
+            body.replace_range(start..end, REDACTED);
+            from = start + REDACTED.len() + close.len();
+        }
+    }
+    body
+}
+
+fn redact_json(body: &str) -> String {
+    let mut body = body.to_owned();
+    for field in SECRET_FIELDS {
+        let key = format!("\"{}\"", field);
+        let mut from = 0;
+        while let Some(key_end) =
+            find_ignore_case(&body[from..], &key).map(|i| from + i + key.len())
+        {
+            from = key_end;
+            let rest = body[key_end..].trim_start();
+            if !rest.starts_with(':') {
+                continue;
+            }
+            let value = rest[1..].trim_start();
+            if !value.starts_with('"') {
+                continue;
+            }
+            let start = body.len() - value.len() + 1;
+            let end = match string_end(&body[start..]) {
+                Some(end) => start + end,
+                None => break,
+            };
+            body.replace_range(start..end, REDACTED);
+            from = start + REDACTED.len();
+        }
+    }
+    body
+}
+
+fn find_ignore_case(haystack: &str, needle: &str) -> Option<usize> {
+    haystack
+        .to_ascii_lowercase()
+        .find(&needle.to_ascii_lowercase())
+}
+
+/// The index of the quote closing a JSON string starting at `s`.
+fn string_end(s: &str) -> Option<usize> {
+    let mut escaped = false;
+    for (i, c) in s.char_indices() {
+        match c {
+            '\\' if !escaped => escaped = true,
+            '"' if !escaped => return Some(i),
+            _ => escaped = false,
+        }
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn redacts_secret_params() {
+        assert_eq!(
+            redact_uri("https://bucket.s3.amazonaws.com/key?X-Amz-Expires=60&X-Amz-Signature=abc"),
+            "https://bucket.s3.amazonaws.com/key?X-Amz-Expires=60&X-Amz-Signature=** redacted **"
+        );
+        assert_eq!(
+            redact_body(b"Action=CreateLoginProfile&Password=hunter2&UserName=bob"),
+            "Action=CreateLoginProfile&Password=** redacted **&UserName=bob"
+        );
+        assert!(is_secret_header("X-Amz-Security-Token"));
+        assert!(!is_secret_header("x-amz-date"));
+    }
+
+    #[test]
+    fn redacts_secret_fields_of_xml_bodies() {
+        let body = b"<Credentials><AccessKeyId>AKID</AccessKeyId>\
+            <SecretAccessKey>secret</SecretAccessKey><SessionToken>token</SessionToken>\
+            </Credentials>";
+        assert_eq!(
+            redact_body(body),
+            "<Credentials><AccessKeyId>AKID</AccessKeyId>\
+            <SecretAccessKey>** redacted **</SecretAccessKey>\
+            <SessionToken>** redacted **</SessionToken></Credentials>"
+        );
+    }
+
+    #[test]
+    fn redacts_secret_fields_of_json_bodies() {
+        let body = br#"{"Credentials": {"AccessKeyId": "AKID", "SecretKey": "se\"cret", "Expiration": 1}, "secretString" : "x"}"#;
+        assert_eq!(
+            redact_body(body),
+            r#"{"Credentials": {"AccessKeyId": "AKID", "SecretKey": "** redacted **", "Expiration": 1}, "secretString" : "** redacted **"}"#
+        );
+    }
+
+    #[test]
+    fn redacts_kms_data_keys() {
+        let body = br#"{"KeyId": "arn:aws:kms:us-east-1:123456789012:key/k", "Plaintext": "ZGF0YSBrZXk=", "CiphertextBlob": "YmxvYg=="}"#;
+        assert_eq!(
+            redact_body(body),
+            r#"{"KeyId": "arn:aws:kms:us-east-1:123456789012:key/k", "Plaintext": "** redacted **", "CiphertextBlob": "YmxvYg=="}"#
+        );
+    }
+
+    #[test]
+    fn redacts_ecr_authorization_tokens() {
+        let body = br#"{"authorizationData": [{"authorizationToken": "QVdTOnBhc3N3b3Jk", "expiresAt": 1.6E9, "proxyEndpoint": "https://123456789012.dkr.ecr.us-east-1.amazonaws.com"}]}"#;
+        assert_eq!(
+            redact_body(body),
+            r#"{"authorizationData": [{"authorizationToken": "** redacted **", "expiresAt": 1.6E9, "proxyEndpoint": "https://123456789012.dkr.ecr.us-east-1.amazonaws.com"}]}"#
+        );
+    }
+}
@@ -0,0 +1,135 @@
+//! Propagation of AWS X-Ray trace context through the `X-Amzn-Trace-Id` header.
+
+use std::env;
+use std::fmt;
+
+/// The name of the header carrying the X-Ray trace context.
+pub const TRACE_ID_HEADER: &str = "x-amzn-trace-id";
+
+/// Supplies the value of the `X-Amzn-Trace-Id` header for each request a `Client` sends.
+///
+/// Set with `Client::set_trace_id_provider`, so that X-Ray or OpenTelemetry SDKs can supply the
+/// current segment of the calling task. Closures returning `Option<String>` implement this trait.
+pub trait ProvideTraceId: Send + Sync {
+    /// The trace header for the next request, or `None` to send it without one.
+    fn trace_id(&self) -> Option<String>;
+}
+
+impl<F> ProvideTraceId for F
+where
+    F: Fn() -> Option<String> + Send + Sync,
+{
+    fn trace_id(&self) -> Option<String> {
+        self()
+    }
+}
+
+/// The default `ProvideTraceId`, reading the trace header AWS Lambda exposes to functions in
+/// the `_X_AMZN_TRACE_ID` environment variable.
+///
+/// Outside of Lambda, i.e. if `AWS_LAMBDA_FUNCTION_NAME` is not set, no trace header is sent.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct LambdaTraceId;
+
+impl ProvideTraceId for LambdaTraceId {
+    fn trace_id(&self) -> Option<String> {
+        env::var_os("AWS_LAMBDA_FUNCTION_NAME")?;
+        env::var("_X_AMZN_TRACE_ID")
+            .ok()
+            .filter(|trace_id| !trace_id.is_empty())
+    }
+}
+
+/// A parsed `X-Amzn-Trace-Id` header, e.g.
+/// `Root=1-5759e988-bd862e3fe1be46a994272793;Parent=53995c3f42cd8ad8;Sampled=1`.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct TraceHeader {
+    root: Option<String>,
+    parent: Option<String>,
+    sampled: Option<bool>,
+    extra: Vec<(String, String)>,
+}
+
+impl TraceHeader {
+    /// Parse the value of a trace header. Unknown fields are kept and malformed ones ignored.
+    pub fn parse(header: &str) -> TraceHeader {
+        let mut trace_header = TraceHeader::default();
+        for field in header.split(';') {
+            let mut parts = field.trim().splitn(2, '=');
+            let (key, value) = match (parts.next(), parts.next()) {
+                (Some(key), Some(value)) if !key.is_empty() => (key, value),
+                _ => continue,
+            };
+            match key {
+                "Root" => trace_header.root = Some(value.to_owned()),
+                "Parent" => trace_header.parent = Some(value.to_owned()),
+                "Sampled" => {
+                    trace_header.sampled = match value {
+                        "1" => Some(true),
+                        "0" => Some(false),
+                        _ => None,
+                    }
+                }
+                _ => trace_header.extra.push((key.to_owned(), value.to_owned())),
+            }
+        }
+        trace_header
+    }
+
+    /// The ID of the trace.
+    pub fn root(&self) -> Option<&str> {
+        self.root.as_deref()
+    }
+
+    /// The ID of the parent segment.
+    pub fn parent(&self) -> Option<&str> {
+        self.parent.as_deref()
+    }
+
+    /// Whether the trace is sampled, or `None` if the sampling decision is left to the receiver.
+    pub fn sampled(&self) -> Option<bool> {
+        self.sampled
+    }
+}
+
+impl fmt::Display for TraceHeader {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let mut fields = Vec::new();
+        if let Some(ref root) = self.root {
+            fields.push(format!("Root={}", root));
+        }
+        if let Some(ref parent) = self.parent {
+            fields.push(format!("Parent={}", parent));
+        }
+        if let Some(sampled) = self.sampled {
+            fields.push(format!("Sampled={}", if sampled { 1 } else { 0 }));
+        }
+        for (key, value) in &self.extra {
+            fields.push(format!("{}={}", key, value));
+        }
+        write!(f, "{}", fields.join(";"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_and_formats_trace_headers() {
+        let header = "Root=1-5759e988-bd862e3fe1be46a994272793;Parent=53995c3f42cd8ad8;Sampled=1;Lineage=a87bd80c:0";
+        let trace_header = TraceHeader::parse(header);
+        assert_eq!(
+            trace_header.root(),
+            Some("1-5759e988-bd862e3fe1be46a994272793")
+        );
+        assert_eq!(trace_header.parent(), Some("53995c3f42cd8ad8"));
+        assert_eq!(trace_header.sampled(), Some(true));
+        assert_eq!(trace_header.to_string(), header);
+
+        let trace_header = TraceHeader::parse("Root=1-abc; Sampled=?;junk");
+        assert_eq!(trace_header.root(), Some("1-abc"));
+        assert_eq!(trace_header.sampled(), None);
+        assert_eq!(trace_header.to_string(), "Root=1-abc");
+    }
+}
@@ -1,9 +1,11 @@
 //! The Credentials provider to read from a task's IAM Role.
 
+use std::fs;
+use std::net::IpAddr;
 use std::time::Duration;
 
 use async_trait::async_trait;
-use hyper::{Body, Request};
+use hyper::{Body, Request, Uri};
 
 use crate::request::HttpClient;
 use crate::{
@@ -19,6 +21,10 @@ const AWS_CONTAINER_CREDENTIALS_RELATIVE_URI: &str = "AWS_CONTAINER_CREDENTIALS_
 // environment variables, but they are used by the Java, Go, JavaScript and the Python SDKs.
 const AWS_CONTAINER_CREDENTIALS_FULL_URI: &str = "AWS_CONTAINER_CREDENTIALS_FULL_URI";
 const AWS_CONTAINER_AUTHORIZATION_TOKEN: &str = "AWS_CONTAINER_AUTHORIZATION_TOKEN";
+// Set by EKS Pod Identity, the file is rotated and has to be read on every request.
+const AWS_CONTAINER_AUTHORIZATION_TOKEN_FILE: &str = "AWS_CONTAINER_AUTHORIZATION_TOKEN_FILE";
+// The addresses of the ECS and EKS Pod Identity agents, which may be reached over plain http.
+const AWS_CONTAINER_AGENT_HOSTS: &[&str] = &["169.254.170.2", "169.254.170.23", "[fd00:ec2::23]"];
 
 /// Provides AWS credentials from a task's IAM role.
 ///
@@ -31,8 +37,11 @@ const AWS_CONTAINER_AUTHORIZATION_TOKEN: &str = "AWS_CONTAINER_AUTHORIZATION_TOK
 /// URL stored in the environment variable ```AWS_CONTAINER_CREDENTIALS_RELATIVE_URI```, which will
 /// be used to obtain the AWS credentials. If that environment variable is not set, rusoto will use
 /// the URL set in environment variable ```AWS_CONTAINER_CREDENTIALS_FULL_URI``` to obtain AWS
-/// credentials and will (optionally) also set the ```Authorization``` header to the value of
-/// environment variable ```AWS_CONTAINER_AUTHORIZATION_TOKEN```.
+/// credentials and will (optionally) also set the ```Authorization``` header to the contents of
+/// the file named by environment variable ```AWS_CONTAINER_AUTHORIZATION_TOKEN_FILE```, as set
+/// by EKS Pod Identity, or else to the value of environment variable
+/// ```AWS_CONTAINER_AUTHORIZATION_TOKEN```. A full URL using plain http must point to a
+/// loopback address or to the ECS or EKS Pod Identity agent.
 ///
 /// # Example
 ///
@@ -103,8 +112,9 @@ fn request_from_env_vars() -> Result<Request<Body>, CredentialsError> {
         Some(ref uri) => new_request(uri, AWS_CONTAINER_CREDENTIALS_RELATIVE_URI),
         None => match non_empty_env_var(AWS_CONTAINER_CREDENTIALS_FULL_URI) {
             Some(ref uri) => {
+                check_full_uri(uri)?;
                 let mut request = new_request(uri, AWS_CONTAINER_CREDENTIALS_FULL_URI)?;
-                if let Some(token) = non_empty_env_var(AWS_CONTAINER_AUTHORIZATION_TOKEN) {
+                if let Some(token) = authorization_token()? {
                     match token.parse() {
                         Ok(parsed_token) => {
                             request.headers_mut().insert("authorization", parsed_token);
@@ -127,6 +137,51 @@ fn request_from_env_vars() -> Result<Request<Body>, CredentialsError> {
     }
 }
 
+/// The authorization token from the file named by `AWS_CONTAINER_AUTHORIZATION_TOKEN_FILE`, or
+/// else from `AWS_CONTAINER_AUTHORIZATION_TOKEN`.
+fn authorization_token() -> Result<Option<String>, CredentialsError> {
+    match non_empty_env_var(AWS_CONTAINER_AUTHORIZATION_TOKEN_FILE) {
+        Some(path) => {
+            let token = fs::read_to_string(&path).map_err(|err| {
+                CredentialsError::new(format!(
+                    "failed to read token file '{}' from environment variable '{}': {}",
+                    path, AWS_CONTAINER_AUTHORIZATION_TOKEN_FILE, err
+                ))
+            })?;
+            Ok(Some(token.trim().to_owned()).filter(|token| !token.is_empty()))
+        }
+        None => Ok(non_empty_env_var(AWS_CONTAINER_AUTHORIZATION_TOKEN)),
+    }
+}
+
+/// Fail unless the full URI uses https, or points to a loopback address or a container agent.
+fn check_full_uri(uri: &str) -> Result<(), CredentialsError> {
+    let parsed = match uri.parse::<Uri>() {
+        Ok(parsed) => parsed,
+        // reported by `new_request`
+        Err(_) => return Ok(()),
+    };
+    if parsed.scheme_str() == Some("https") {
+        return Ok(());
+    }
+    let host = parsed.host().unwrap_or_default();
+    let is_loopback = host == "localhost"
+        || host
+            .trim_start_matches('[')
+            .trim_end_matches(']')
+            .parse::<IpAddr>()
+            .map(|ip| ip.is_loopback())
+            .unwrap_or(false);
+    if is_loopback || AWS_CONTAINER_AGENT_HOSTS.contains(&host) {
+        Ok(())
+    } else {
+        Err(CredentialsError::new(format!(
+            "URI '{}' from environment variable '{}' must use https or a loopback address",
+            uri, AWS_CONTAINER_CREDENTIALS_FULL_URI
+        )))
+    }
+}
+
 fn new_request(uri: &str, env_var_name: &str) -> Result<Request<Body>, CredentialsError> {
     Request::get(uri).body(Body::empty()).map_err(|error| {
         CredentialsError::new(format!(
@@ -227,4 +282,49 @@ mod tests {
         assert_eq!(request.uri().to_string(), url);
         assert_eq!(request.headers().contains_key("authorization"), false);
     }
+
+    #[test]
+    fn request_from_full_uri_with_token_file() {
+        let url = "http://169.254.170.23/v1/credentials";
+        let token_file = env::temp_dir().join("rusoto-container-token-file");
+        std::fs::write(&token_file, "file-token\n").unwrap();
+        let _guard = lock_env();
+        env::remove_var(AWS_CONTAINER_CREDENTIALS_RELATIVE_URI);
+        env::set_var(AWS_CONTAINER_CREDENTIALS_FULL_URI, url);
+        env::set_var(AWS_CONTAINER_AUTHORIZATION_TOKEN, "dummy");
+        env::set_var(AWS_CONTAINER_AUTHORIZATION_TOKEN_FILE, &token_file);
+        let result = request_from_env_vars();
+        env::remove_var(AWS_CONTAINER_CREDENTIALS_FULL_URI);
+        env::remove_var(AWS_CONTAINER_AUTHORIZATION_TOKEN);
+        env::remove_var(AWS_CONTAINER_AUTHORIZATION_TOKEN_FILE);
+        std::fs::remove_file(&token_file).unwrap();
+        let request = result.unwrap();
+        assert_eq!(request.uri().to_string(), url);
+        assert_eq!(request.headers()["authorization"], "file-token");
+    }
+
+    #[test]
+    fn error_from_full_uri_to_remote_host_over_http() {
+        let _guard = lock_env();
+        env::remove_var(AWS_CONTAINER_CREDENTIALS_RELATIVE_URI);
+        env::set_var(
+            AWS_CONTAINER_CREDENTIALS_FULL_URI,
+            "http://example.com/creds",
+        );
+        let result = request_from_env_vars();
+        env::set_var(
+            AWS_CONTAINER_CREDENTIALS_FULL_URI,
+            "https://example.com/creds",
+        );
+        let https_result = request_from_env_vars();
+        env::set_var(
+            AWS_CONTAINER_CREDENTIALS_FULL_URI,
+            "http://[::1]:8080/creds",
+        );
+        let loopback_result = request_from_env_vars();
+        env::remove_var(AWS_CONTAINER_CREDENTIALS_FULL_URI);
+        assert!(result.is_err());
+        assert!(https_result.is_ok());
+        assert!(loopback_result.is_ok());
+    }
 }
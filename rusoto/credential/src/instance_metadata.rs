@@ -1,16 +1,33 @@
 //! The Credentials Provider for an AWS Resource's IAM Role.
 
 use async_trait::async_trait;
-use hyper::Uri;
-use std::time::Duration;
+use hyper::{Body, Request, StatusCode, Uri};
+use std::fmt;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
 
 use crate::request::HttpClient;
 use crate::{
-    parse_credentials_from_aws_service, AwsCredentials, CredentialsError, ProvideAwsCredentials,
+    non_empty_env_var, parse_credentials_from_aws_service, AwsCredentials, CredentialsError,
+    ProfileProvider, ProvideAwsCredentials,
 };
 
 const AWS_CREDENTIALS_PROVIDER_IP: &str = "169.254.169.254";
 const AWS_CREDENTIALS_PROVIDER_PATH: &str = "latest/meta-data/iam/security-credentials";
+const AWS_METADATA_TOKEN_PATH: &str = "latest/api/token";
+const AWS_METADATA_TOKEN_HEADER: &str = "x-aws-ec2-metadata-token";
+const AWS_METADATA_TOKEN_TTL_HEADER: &str = "x-aws-ec2-metadata-token-ttl-seconds";
+const AWS_EC2_METADATA_V1_DISABLED: &str = "AWS_EC2_METADATA_V1_DISABLED";
+const EC2_METADATA_V1_DISABLED: &str = "ec2_metadata_v1_disabled";
+/// The lifetime requested for session tokens, the maximum of six hours.
+const TOKEN_TTL: Duration = Duration::from_secs(6 * 60 * 60);
+/// Session tokens are renewed this long before they expire.
+const TOKEN_EXPIRY_MARGIN: Duration = Duration::from_secs(60);
+/// The longest session tokens are waited for, shorter than the provider's timeout as where they
+/// can't be fetched, e.g. in containers beyond the hop limit of the token response, the request
+/// usually never gets an answer.
+const TOKEN_TIMEOUT: Duration = Duration::from_secs(1);
 
 /// Provides AWS credentials from a resource's IAM role.
 ///
@@ -40,11 +57,39 @@ const AWS_CREDENTIALS_PROVIDER_PATH: &str = "latest/meta-data/iam/security-crede
 /// // you can overwrite the default endpoint like this:
 /// provider.set_ip_addr_with_port("127.0.0.1", "8080");
 /// ```
+///
+/// Credentials are requested with an IMDSv2 session token, which is fetched once and reused
+/// until shortly before it expires or the service rejects it. If the instance metadata service
+/// doesn't hand out tokens within a second, the provider falls back to IMDSv1 requests without
+/// one from then on, unless disabled with `set_allow_imdsv1_fallback`, the
+/// `AWS_EC2_METADATA_V1_DISABLED` environment variable or the `ec2_metadata_v1_disabled` key of
+/// the current profile.
 #[derive(Clone, Debug)]
 pub struct InstanceMetadataProvider {
     client: HttpClient,
     timeout: Duration,
     metadata_ip_addr: String,
+    /// Whether to fall back to IMDSv1, or `None` to look it up once a token can't be fetched.
+    allow_imdsv1_fallback: Option<bool>,
+    token: Arc<Mutex<Option<SessionToken>>>,
+    /// Set once a token couldn't be fetched and requests fall back to IMDSv1.
+    imdsv1_fallback: Arc<AtomicBool>,
+}
+
+/// An IMDSv2 session token.
+#[derive(Clone)]
+struct SessionToken {
+    value: String,
+    expires_at: Instant,
+}
+
+impl fmt::Debug for SessionToken {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("SessionToken")
+            .field("value", &"**********")
+            .field("expires_at", &self.expires_at)
+            .finish()
+    }
 }
 
 impl InstanceMetadataProvider {
@@ -54,6 +99,9 @@ impl InstanceMetadataProvider {
             client: HttpClient::new(),
             timeout: Duration::from_secs(30),
             metadata_ip_addr: AWS_CREDENTIALS_PROVIDER_IP.to_string(),
+            allow_imdsv1_fallback: None,
+            token: Default::default(),
+            imdsv1_fallback: Default::default(),
         }
     }
 
@@ -66,6 +114,48 @@ impl InstanceMetadataProvider {
     pub fn set_ip_addr_with_port(&mut self, ip: &str, port: &str) {
         self.metadata_ip_addr = format!("{}:{}", ip, port);
     }
+
+    /// Allow falling back to IMDSv1 requests without a session token if none can be fetched.
+    /// Instances requiring IMDSv2 reject these requests anyway, so the fallback only makes a
+    /// difference where IMDSv1 is still enabled.
+    pub fn set_allow_imdsv1_fallback(&mut self, allow_imdsv1_fallback: bool) {
+        self.allow_imdsv1_fallback = Some(allow_imdsv1_fallback);
+    }
+
+    /// The session token to send with requests, or `None` to fall back to IMDSv1.
+    async fn session_token(&self) -> Result<Option<String>, CredentialsError> {
+        if self.imdsv1_fallback.load(Ordering::Relaxed) {
+            return Ok(None);
+        }
+        if let Some(ref token) = *self.token.lock().unwrap() {
+            if token.expires_at > Instant::now() + TOKEN_EXPIRY_MARGIN {
+                return Ok(Some(token.value.clone()));
+            }
+        }
+        let requested_at = Instant::now();
+        let timeout = self.timeout.min(TOKEN_TIMEOUT);
+        match get_session_token(&self.client, timeout, &self.metadata_ip_addr).await {
+            Ok(value) => {
+                *self.token.lock().unwrap() = Some(SessionToken {
+                    value: value.clone(),
+                    expires_at: requested_at + TOKEN_TTL,
+                });
+                Ok(Some(value))
+            }
+            Err(_)
+                if self
+                    .allow_imdsv1_fallback
+                    .unwrap_or_else(|| !imdsv1_disabled()) =>
+            {
+                self.imdsv1_fallback.store(true, Ordering::Relaxed);
+                Ok(None)
+            }
+            Err(err) => Err(CredentialsError::new(format!(
+                "Could not get IMDSv2 session token: {}",
+                err
+            ))),
+        }
+    }
 }
 
 impl Default for InstanceMetadataProvider {
@@ -77,24 +167,111 @@ impl Default for InstanceMetadataProvider {
 #[async_trait]
 impl ProvideAwsCredentials for InstanceMetadataProvider {
     async fn credentials(&self) -> Result<AwsCredentials, CredentialsError> {
-        let role_name = get_role_name(&self.client, self.timeout, &self.metadata_ip_addr)
-            .await
-            .map_err(|err| CredentialsError {
-                message: format!("Could not get credentials from iam: {}", err.to_string()),
-            })?;
+        let token = self.session_token().await?;
+        if let Some(credentials) = self.credentials_with_token(token.as_deref()).await? {
+            return Ok(credentials);
+        }
+        // The service rejected the session token, e.g. after restarting, so get a new one
+        *self.token.lock().unwrap() = None;
+        let token = self.session_token().await?;
+        self.credentials_with_token(token.as_deref())
+            .await?
+            .ok_or_else(|| {
+                CredentialsError::new("Could not get credentials from iam: session token rejected")
+            })
+    }
+}
+
+impl InstanceMetadataProvider {
+    /// The credentials of the instance's role, or `None` if the service rejected `token`.
+    async fn credentials_with_token(
+        &self,
+        token: Option<&str>,
+    ) -> Result<Option<AwsCredentials>, CredentialsError> {
+        let role_name =
+            match get_role_name(&self.client, self.timeout, &self.metadata_ip_addr, token)
+                .await
+                .map_err(|err| CredentialsError {
+                    message: format!("Could not get credentials from iam: {}", err),
+                })? {
+                Some(role_name) => role_name,
+                None => return Ok(None),
+            };
 
-        let cred_str = get_credentials_from_role(
+        let cred_str = match get_credentials_from_role(
             &self.client,
             self.timeout,
             &role_name,
             &self.metadata_ip_addr,
+            token,
         )
         .await
         .map_err(|err| CredentialsError {
-            message: format!("Could not get credentials from iam: {}", err.to_string()),
-        })?;
+            message: format!("Could not get credentials from iam: {}", err),
+        })? {
+            Some(cred_str) => cred_str,
+            None => return Ok(None),
+        };
+
+        parse_credentials_from_aws_service(&cred_str).map(Some)
+    }
+}
+
+/// Whether IMDSv1 requests are disabled by the environment or the current profile.
+fn imdsv1_disabled() -> bool {
+    non_empty_env_var(AWS_EC2_METADATA_V1_DISABLED)
+        .or_else(|| {
+            ProfileProvider::config_value(EC2_METADATA_V1_DISABLED)
+                .ok()
+                .and_then(|value| value)
+        })
+        .map(|value| value.trim().eq_ignore_ascii_case("true"))
+        .unwrap_or(false)
+}
+
+/// Gets an IMDSv2 session token from the IAM Metadata Service (169.254.169.254).
+async fn get_session_token(
+    client: &HttpClient,
+    timeout: Duration,
+    ip_addr: &str,
+) -> Result<String, CredentialsError> {
+    let token_address = format!("http://{}/{}", ip_addr, AWS_METADATA_TOKEN_PATH);
+    let request = Request::put(token_address)
+        .header(
+            AWS_METADATA_TOKEN_TTL_HEADER,
+            TOKEN_TTL.as_secs().to_string(),
+        )
+        .body(Body::empty())
+        .map_err(CredentialsError::new)?;
+    let (status, token) = client.request_with_status(request, timeout).await?;
+    if !status.is_success() {
+        return Err(CredentialsError::new(format!(
+            "Unexpected status {}",
+            status
+        )));
+    }
+    Ok(token)
+}
 
-        parse_credentials_from_aws_service(&cred_str)
+/// Gets `uri` from the IAM Metadata Service, with the session `token` if there is one, or `None`
+/// if the service rejected it.
+async fn get_metadata(
+    client: &HttpClient,
+    timeout: Duration,
+    uri: Uri,
+    token: Option<&str>,
+) -> Result<Option<String>, CredentialsError> {
+    let token = match token {
+        Some(token) => token,
+        None => return Ok(Some(client.get(uri, timeout).await?)),
+    };
+    let request = Request::get(uri)
+        .header(AWS_METADATA_TOKEN_HEADER, token)
+        .body(Body::empty())
+        .map_err(CredentialsError::new)?;
+    match client.request_with_status(request, timeout).await? {
+        (StatusCode::UNAUTHORIZED, _) => Ok(None),
+        (_, body) => Ok(Some(body)),
     }
 }
 
@@ -103,14 +280,15 @@ async fn get_role_name(
     client: &HttpClient,
     timeout: Duration,
     ip_addr: &str,
-) -> Result<String, CredentialsError> {
+    token: Option<&str>,
+) -> Result<Option<String>, CredentialsError> {
     let role_name_address = format!("http://{}/{}/", ip_addr, AWS_CREDENTIALS_PROVIDER_PATH);
     let uri = match role_name_address.parse::<Uri>() {
         Ok(u) => u,
         Err(e) => return Err(CredentialsError::new(e)),
     };
 
-    Ok(client.get(uri, timeout).await?)
+    get_metadata(client, timeout, uri, token).await
 }
 
 /// Gets the credentials for an EC2 Instances IAM Role.
@@ -119,7 +297,8 @@ async fn get_credentials_from_role(
     timeout: Duration,
     role_name: &str,
     ip_addr: &str,
-) -> Result<String, CredentialsError> {
+    token: Option<&str>,
+) -> Result<Option<String>, CredentialsError> {
     let credentials_provider_url = format!(
         "http://{}/{}/{}",
         ip_addr, AWS_CREDENTIALS_PROVIDER_PATH, role_name
@@ -130,5 +309,127 @@ async fn get_credentials_from_role(
         Err(e) => return Err(CredentialsError::new(e)),
     };
 
-    Ok(client.get(uri, timeout).await?)
+    get_metadata(client, timeout, uri, token).await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use hyper::service::{make_service_fn, service_fn};
+    use hyper::{Method, Response, Server};
+    use std::convert::Infallible;
+    use std::sync::atomic::AtomicUsize;
+
+    /// Instance metadata served by `serve`.
+    #[derive(Clone, Default)]
+    struct Metadata {
+        /// The number of token requests received.
+        token_requests: Arc<AtomicUsize>,
+        /// Bumped to rotate the session token the service accepts.
+        generation: Arc<AtomicUsize>,
+        /// Answer IMDSv1 requests, and never answer token requests.
+        imdsv1: bool,
+    }
+
+    /// Serve `metadata`, answering requests carrying the current session token, returning the
+    /// address of the server.
+    fn serve(metadata: Metadata) -> String {
+        let make_service = make_service_fn(move |_| {
+            let metadata = metadata.clone();
+            async move {
+                Ok::<_, Infallible>(service_fn(move |request: Request<Body>| {
+                    let metadata = metadata.clone();
+                    async move {
+                        let path = request.uri().path().to_owned();
+                        let token = format!(
+                            "session-token-{}",
+                            metadata.generation.load(Ordering::SeqCst)
+                        );
+                        let sent_token = request.headers().get(AWS_METADATA_TOKEN_HEADER);
+                        let response = match (request.method(), path.as_str()) {
+                            (&Method::PUT, "/latest/api/token") => {
+                                metadata.token_requests.fetch_add(1, Ordering::SeqCst);
+                                if metadata.imdsv1 {
+                                    futures::future::pending::<()>().await;
+                                }
+                                Response::new(Body::from(token))
+                            }
+                            _ if !metadata.imdsv1
+                                && sent_token.map(|t| t != token.as_str()).unwrap_or(true) =>
+                            {
+                                let mut response = Response::new(Body::empty());
+                                *response.status_mut() = StatusCode::UNAUTHORIZED;
+                                response
+                            }
+                            (_, "/latest/meta-data/iam/security-credentials/") => {
+                                Response::new(Body::from("testrole"))
+                            }
+                            _ => Response::new(Body::from(
+                                r#"{"AccessKeyId": "key", "SecretAccessKey": "secret",
+                                    "Token": "token", "Expiration": "2015-08-04T06:32:37Z"}"#,
+                            )),
+                        };
+                        Ok::<_, Infallible>(response)
+                    }
+                }))
+            }
+        });
+        let server = Server::bind(&([127, 0, 0, 1], 0).into()).serve(make_service);
+        let addr = server.local_addr();
+        tokio::spawn(server);
+        addr.to_string()
+    }
+
+    fn provider(addr: String) -> InstanceMetadataProvider {
+        let mut provider = InstanceMetadataProvider::new();
+        provider.metadata_ip_addr = addr;
+        provider.set_timeout(Duration::from_secs(5));
+        provider
+    }
+
+    #[tokio::test]
+    async fn fetches_credentials_with_session_token() {
+        let metadata = Metadata::default();
+        let provider = provider(serve(metadata.clone()));
+
+        let credentials = provider.credentials().await.unwrap();
+        assert_eq!(credentials.aws_access_key_id(), "key");
+        assert_eq!(credentials.token().as_deref(), Some("token"));
+        provider.credentials().await.unwrap();
+        assert_eq!(metadata.token_requests.load(Ordering::SeqCst), 1);
+    }
+
+    #[tokio::test]
+    async fn renews_rejected_session_tokens() {
+        let metadata = Metadata::default();
+        let provider = provider(serve(metadata.clone()));
+
+        provider.credentials().await.unwrap();
+        metadata.generation.fetch_add(1, Ordering::SeqCst);
+        let credentials = provider.credentials().await.unwrap();
+        assert_eq!(credentials.aws_access_key_id(), "key");
+        assert_eq!(metadata.token_requests.load(Ordering::SeqCst), 2);
+    }
+
+    #[tokio::test]
+    async fn falls_back_to_imdsv1_once() {
+        let metadata = Metadata {
+            imdsv1: true,
+            ..Default::default()
+        };
+        let mut provider = provider(serve(metadata.clone()));
+        provider.set_allow_imdsv1_fallback(true);
+
+        let started = Instant::now();
+        let credentials = provider.credentials().await.unwrap();
+        assert_eq!(credentials.aws_access_key_id(), "key");
+        assert!(started.elapsed() < Duration::from_secs(5));
+        provider.credentials().await.unwrap();
+        assert_eq!(metadata.token_requests.load(Ordering::SeqCst), 1);
+
+        let mut strict = provider.clone();
+        strict.imdsv1_fallback = Default::default();
+        strict.set_allow_imdsv1_fallback(false);
+        assert!(strict.credentials().await.is_err());
+    }
 }
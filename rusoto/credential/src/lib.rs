@@ -6,20 +6,32 @@
 #![deny(missing_docs)]
 
 //! Types for loading and managing AWS access credentials for API requests.
+//!
+//! The providers making HTTP requests or running processes, and the chains of providers built on
+//! them, run on tokio and require the default `tokio` feature: `ContainerProvider`,
+//! `InstanceMetadataProvider`, `CredentialProcessProvider`, `ChainProvider` and
+//! `DefaultCredentialsProvider`.
 
+#[cfg(feature = "tokio")]
 pub use crate::container::ContainerProvider;
 pub use crate::environment::EnvironmentProvider;
+#[cfg(feature = "tokio")]
 pub use crate::instance_metadata::InstanceMetadataProvider;
-pub use crate::profile::ProfileProvider;
+#[cfg(feature = "tokio")]
+pub use crate::profile::CredentialProcessProvider;
+pub use crate::profile::{AssumeProfileRole, ProfileProvider, ProfileRole};
 pub use crate::secrets::Secret;
 pub use crate::static_provider::StaticProvider;
 pub use crate::variable::Variable;
 
 pub mod claims;
+#[cfg(feature = "tokio")]
 mod container;
 mod environment;
+#[cfg(feature = "tokio")]
 mod instance_metadata;
 mod profile;
+#[cfg(feature = "tokio")]
 mod request;
 mod secrets;
 mod static_provider;
@@ -34,6 +46,9 @@ use std::error::Error;
 use std::fmt;
 use std::io::Error as IoError;
 use std::string::FromUtf8Error;
+use std::sync::atomic::AtomicBool;
+#[cfg(feature = "tokio")]
+use std::sync::atomic::Ordering;
 use std::sync::Arc;
 use std::time::Duration;
 
@@ -54,14 +69,27 @@ impl Anonymous for AwsCredentials {
     }
 }
 
+/// Provides anonymous credentials, `AwsCredentials::default`, with which requests are sent
+/// without being signed, e.g. to read public S3 buckets without any credentials configured.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct AnonymousCredentials;
+
+#[async_trait]
+impl ProvideAwsCredentials for AnonymousCredentials {
+    async fn credentials(&self) -> Result<AwsCredentials, CredentialsError> {
+        Ok(AwsCredentials::default())
+    }
+}
+
 /// AWS API access credentials, including access key, secret key, token (for IAM profiles),
 /// expiration timestamp, and claims from federated login.
 ///
 /// # Anonymous example
 ///
 /// Some AWS services, like [s3](https://docs.aws.amazon.com/AmazonS3/latest/API/Welcome.html) do
-/// not require authenticated credentials. For these cases you can use `AwsCredentials::default`
-/// with `StaticProvider`.
+/// not require authenticated credentials. For these cases you can use `AnonymousCredentials`, or
+/// `AwsCredentials::default` with `StaticProvider`. Requests signed with anonymous credentials
+/// are sent unsigned.
 #[derive(Clone, Deserialize, Default)]
 pub struct AwsCredentials {
     #[serde(rename = "AccessKeyId")]
@@ -72,6 +100,8 @@ pub struct AwsCredentials {
     token: Option<String>,
     #[serde(rename = "Expiration")]
     expires_at: Option<DateTime<Utc>>,
+    #[serde(rename = "AccountId")]
+    account_id: Option<String>,
     #[serde(skip)]
     claims: BTreeMap<String, String>,
 }
@@ -94,6 +124,7 @@ impl AwsCredentials {
             secret: secret.into(),
             token,
             expires_at,
+            account_id: None,
             claims: BTreeMap::new(),
         }
     }
@@ -118,6 +149,16 @@ impl AwsCredentials {
         &self.token
     }
 
+    /// Get the ID of the AWS account the credentials belong to, if the provider knows it.
+    pub fn account_id(&self) -> Option<&str> {
+        self.account_id.as_deref()
+    }
+
+    /// Set the ID of the AWS account the credentials belong to.
+    pub fn set_account_id(&mut self, account_id: Option<String>) {
+        self.account_id = account_id;
+    }
+
     /// Determine whether or not the credentials are expired.
     fn credentials_are_expired(&self) -> bool {
         match self.expires_at {
@@ -131,6 +172,15 @@ impl AwsCredentials {
         }
     }
 
+    /// Determine whether the credentials have expired, without the margin of
+    /// `credentials_are_expired`.
+    fn have_expired(&self) -> bool {
+        match self.expires_at {
+            Some(ref e) => *e < Utc::now(),
+            None => false,
+        }
+    }
+
     /// Get the token claims
     pub fn claims(&self) -> &BTreeMap<String, String> {
         &self.claims
@@ -149,6 +199,7 @@ impl fmt::Debug for AwsCredentials {
             .field("secret", &"**********")
             .field("token", &self.token.as_ref().map(|_| "**********"))
             .field("expires_at", &self.expires_at)
+            .field("account_id", &self.account_id)
             .field("claims", &self.claims)
             .finish()
     }
@@ -223,6 +274,9 @@ impl From<FromUtf8Error> for CredentialsError {
 }
 
 /// A trait for types that produce `AwsCredentials`.
+///
+/// The trait is object safe, and implemented for `Arc`s of providers, so one provider can be
+/// shared by many clients, e.g. as a `CredentialsProvider`.
 #[async_trait]
 pub trait ProvideAwsCredentials {
     /// Produce a new `AwsCredentials` future.
@@ -230,30 +284,111 @@ pub trait ProvideAwsCredentials {
 }
 
 #[async_trait]
-impl<P: ProvideAwsCredentials + Send + Sync> ProvideAwsCredentials for Arc<P> {
+impl<P: ProvideAwsCredentials + Send + Sync + ?Sized> ProvideAwsCredentials for Arc<P> {
     async fn credentials(&self) -> Result<AwsCredentials, CredentialsError> {
         P::credentials(self).await
     }
 }
 
+/// A shared handle to a credentials provider of any type.
+///
+/// Clones share the provider, so passing clones of one `CredentialsProvider` wrapping an
+/// `AutoRefreshingProvider` to many clients lets them share its cached credentials, instead of
+/// each client refreshing its own.
+///
+/// ```rust
+/// use rusoto_credential::{AutoRefreshingProvider, CredentialsProvider, EnvironmentProvider};
+///
+/// let provider = CredentialsProvider::new(
+///     AutoRefreshingProvider::new(EnvironmentProvider::default()).unwrap(),
+/// );
+/// // e.g. S3Client::new_with(HttpClient::new()?, provider.clone(), region)
+/// let for_another_client = provider.clone();
+/// ```
+#[derive(Clone)]
+pub struct CredentialsProvider(Arc<dyn ProvideAwsCredentials + Send + Sync>);
+
+impl CredentialsProvider {
+    /// Wrap `provider`.
+    pub fn new<P>(provider: P) -> CredentialsProvider
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+    {
+        CredentialsProvider(Arc::new(provider))
+    }
+}
+
+impl From<Arc<dyn ProvideAwsCredentials + Send + Sync>> for CredentialsProvider {
+    fn from(provider: Arc<dyn ProvideAwsCredentials + Send + Sync>) -> CredentialsProvider {
+        CredentialsProvider(provider)
+    }
+}
+
+impl fmt::Debug for CredentialsProvider {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("CredentialsProvider")
+    }
+}
+
+#[async_trait]
+impl ProvideAwsCredentials for CredentialsProvider {
+    async fn credentials(&self) -> Result<AwsCredentials, CredentialsError> {
+        self.0.credentials().await
+    }
+}
+
 /// Wrapper for `ProvideAwsCredentials` that caches the credentials returned by the
 /// wrapped provider.  Each time the credentials are accessed, they are checked to see if
 /// they have expired, in which case they are retrieved from the wrapped provider again.
 ///
+/// Credentials expiring within the refresh buffer, 5 minutes by default, are refreshed ahead of
+/// their expiry. The refresh runs in the background on the current tokio runtime, and the cached
+/// credentials are returned in the meantime; without a runtime (e.g. on wasm32, or without the
+/// `tokio` feature) it delays the
+/// request that triggers it. If the refresh fails, the cached credentials keep being used until
+/// they expire, and the refresh is retried with the next request.
+///
+/// With `set_stale_while_revalidate`, failures to refresh expiring credentials are also tolerated
+/// once they are close enough to expiring to block requests, as long as they haven't expired yet.
+///
+/// Clones share the wrapped provider and the cached credentials, so one provider can be shared by
+/// many clients by passing them clones, or a `CredentialsProvider` wrapping it.
+///
 /// In order to access the wrapped provider, for instance to set a timeout, the `get_ref`
 /// and `get_mut` methods can be used.
-#[derive(Debug, Clone)]
+#[derive(Debug)]
 pub struct AutoRefreshingProvider<P: ProvideAwsCredentials + 'static> {
-    credentials_provider: P,
-    current_credentials: Arc<Mutex<Option<Result<AwsCredentials, CredentialsError>>>>,
+    credentials_provider: Arc<P>,
+    current_credentials: Arc<Mutex<Option<AwsCredentials>>>,
+    refreshing: Arc<AtomicBool>,
+    refresh_buffer: ChronoDuration,
+    background_refresh: bool,
+    stale_while_revalidate: bool,
+}
+
+impl<P: ProvideAwsCredentials + 'static> Clone for AutoRefreshingProvider<P> {
+    fn clone(&self) -> Self {
+        AutoRefreshingProvider {
+            credentials_provider: self.credentials_provider.clone(),
+            current_credentials: self.current_credentials.clone(),
+            refreshing: self.refreshing.clone(),
+            refresh_buffer: self.refresh_buffer,
+            background_refresh: self.background_refresh,
+            stale_while_revalidate: self.stale_while_revalidate,
+        }
+    }
 }
 
 impl<P: ProvideAwsCredentials + 'static> AutoRefreshingProvider<P> {
     /// Create a new `AutoRefreshingProvider` around the provided base provider.
     pub fn new(provider: P) -> Result<AutoRefreshingProvider<P>, CredentialsError> {
         Ok(AutoRefreshingProvider {
-            credentials_provider: provider,
+            credentials_provider: Arc::new(provider),
             current_credentials: Arc::new(Mutex::new(None)),
+            refreshing: Arc::new(AtomicBool::new(false)),
+            refresh_buffer: ChronoDuration::minutes(5),
+            background_refresh: true,
+            stale_while_revalidate: false,
         })
     }
 
@@ -266,8 +401,69 @@ impl<P: ProvideAwsCredentials + 'static> AutoRefreshingProvider<P> {
     ///
     /// This can be used to call `set_timeout` on the wrapped
     /// provider.
+    ///
+    /// # Panics
+    ///
+    /// If the wrapped provider is shared with a clone of this provider, or with a background
+    /// refresh in progress.
     pub fn get_mut(&mut self) -> &mut P {
-        &mut self.credentials_provider
+        Arc::get_mut(&mut self.credentials_provider)
+            .expect("the wrapped credentials provider is shared")
+    }
+
+    /// Set how long before they expire credentials are refreshed. Default 5 minutes.
+    pub fn set_refresh_buffer(&mut self, refresh_buffer: Duration) {
+        // out of range buffers are longer than any credentials are valid for
+        self.refresh_buffer = ChronoDuration::from_std(refresh_buffer)
+            .unwrap_or_else(|_| ChronoDuration::weeks(52 * 100));
+    }
+
+    /// Set whether refreshes ahead of expiry run in the background. Default `true`.
+    pub fn set_background_refresh(&mut self, background_refresh: bool) {
+        self.background_refresh = background_refresh;
+    }
+
+    /// Set whether to keep returning cached credentials that failed to be refreshed until they
+    /// expire. Default `false`.
+    pub fn set_stale_while_revalidate(&mut self, stale_while_revalidate: bool) {
+        self.stale_while_revalidate = stale_while_revalidate;
+    }
+
+    fn needs_refresh(&self, creds: &AwsCredentials) -> bool {
+        match creds.expires_at() {
+            Some(expires_at) => *expires_at < Utc::now() + self.refresh_buffer,
+            None => false,
+        }
+    }
+}
+
+impl<P: ProvideAwsCredentials + Send + Sync + 'static> AutoRefreshingProvider<P> {
+    /// Refresh the credentials on the current tokio runtime, returning `false` if there is none.
+    #[cfg(feature = "tokio")]
+    fn spawn_refresh(&self) -> bool {
+        let handle = match tokio::runtime::Handle::try_current() {
+            Ok(handle) => handle,
+            Err(_) => return false,
+        };
+        if self.refreshing.swap(true, Ordering::SeqCst) {
+            // another request already started a refresh
+            return true;
+        }
+        let provider = self.credentials_provider.clone();
+        let current_credentials = self.current_credentials.clone();
+        let refreshing = self.refreshing.clone();
+        handle.spawn(async move {
+            if let Ok(creds) = provider.credentials().await {
+                *current_credentials.lock().await = Some(creds);
+            }
+            refreshing.store(false, Ordering::SeqCst);
+        });
+        true
+    }
+
+    #[cfg(not(feature = "tokio"))]
+    fn spawn_refresh(&self) -> bool {
+        false
     }
 }
 
@@ -276,27 +472,34 @@ impl<P: ProvideAwsCredentials + Send + Sync + 'static> ProvideAwsCredentials
     for AutoRefreshingProvider<P>
 {
     async fn credentials(&self) -> Result<AwsCredentials, CredentialsError> {
-        loop {
-            let mut guard = self.current_credentials.lock().await;
-            match guard.as_ref() {
-                // no result from the future yet, let's keep using it
-                None => {
-                    let res = self.credentials_provider.credentials().await;
-                    *guard = Some(res);
+        let mut guard = self.current_credentials.lock().await;
+        let cached = match guard.as_ref() {
+            Some(creds) if !creds.credentials_are_expired() => {
+                if !self.needs_refresh(creds) {
+                    return Ok(creds.clone());
                 }
-                Some(Err(e)) => return Err(e.clone()),
-                Some(Ok(creds)) => {
-                    if creds.credentials_are_expired() {
-                        *guard = None;
-                    } else {
-                        return Ok(creds.clone());
-                    };
+                if self.background_refresh && self.spawn_refresh() {
+                    return Ok(creds.clone());
                 }
+                // the credentials are still usable if refreshing them fails
+                Some(creds.clone())
             }
+            Some(creds) if self.stale_while_revalidate && !creds.have_expired() => {
+                Some(creds.clone())
+            }
+            _ => None,
+        };
+        match self.credentials_provider.credentials().await {
+            Ok(creds) => {
+                *guard = Some(creds.clone());
+                Ok(creds)
+            }
+            Err(err) => cached.ok_or(err),
         }
     }
 }
 
+#[cfg(feature = "tokio")]
 /// Wraps a `ChainProvider` in an `AutoRefreshingProvider`.
 ///
 /// The underlying `ChainProvider` checks multiple sources for credentials, and the `AutoRefreshingProvider`
@@ -314,6 +517,7 @@ impl<P: ProvideAwsCredentials + Send + Sync + 'static> ProvideAwsCredentials
 #[derive(Clone)]
 pub struct DefaultCredentialsProvider(AutoRefreshingProvider<ChainProvider>);
 
+#[cfg(feature = "tokio")]
 impl DefaultCredentialsProvider {
     /// Creates a new thread-safe `DefaultCredentialsProvider`.
     pub fn new() -> Result<DefaultCredentialsProvider, CredentialsError> {
@@ -322,6 +526,7 @@ impl DefaultCredentialsProvider {
     }
 }
 
+#[cfg(feature = "tokio")]
 #[async_trait]
 impl ProvideAwsCredentials for DefaultCredentialsProvider {
     async fn credentials(&self) -> Result<AwsCredentials, CredentialsError> {
@@ -329,6 +534,7 @@ impl ProvideAwsCredentials for DefaultCredentialsProvider {
     }
 }
 
+#[cfg(feature = "tokio")]
 /// Provides AWS credentials from multiple possible sources using a priority order.
 ///
 /// The following sources are checked in order for credentials when calling `credentials`:
@@ -372,6 +578,7 @@ pub struct ChainProvider {
     profile_provider: Option<ProfileProvider>,
 }
 
+#[cfg(feature = "tokio")]
 impl ChainProvider {
     /// Set the timeout on the provider to the specified duration.
     pub fn set_timeout(&mut self, duration: Duration) {
@@ -380,6 +587,7 @@ impl ChainProvider {
     }
 }
 
+#[cfg(feature = "tokio")]
 async fn chain_provider_credentials(
     provider: ChainProvider,
 ) -> Result<AwsCredentials, CredentialsError> {
@@ -402,6 +610,7 @@ async fn chain_provider_credentials(
     ))
 }
 
+#[cfg(feature = "tokio")]
 #[async_trait]
 impl ProvideAwsCredentials for ChainProvider {
     async fn credentials(&self) -> Result<AwsCredentials, CredentialsError> {
@@ -409,6 +618,7 @@ impl ProvideAwsCredentials for ChainProvider {
     }
 }
 
+#[cfg(feature = "tokio")]
 impl ChainProvider {
     /// Create a new `ChainProvider` using a `ProfileProvider` with the default settings.
     pub fn new() -> ChainProvider {
@@ -429,8 +639,193 @@ impl ChainProvider {
             container_provider: ContainerProvider::new(),
         }
     }
+
+    /// Create a builder of a custom chain, starting from the providers of the default chain, see
+    /// `ChainProviderBuilder`.
+    pub fn builder() -> ChainProviderBuilder {
+        let mut builder =
+            ChainProviderBuilder::new().push("environment", EnvironmentProvider::default());
+        if let Ok(profile_provider) = ProfileProvider::new() {
+            builder = builder.push("profile", profile_provider);
+        }
+        builder
+            .push("container", ContainerProvider::new())
+            .push("instance_metadata", InstanceMetadataProvider::new())
+    }
 }
 
+#[cfg(feature = "tokio")]
+/// Builds a custom chain of credentials providers, consulted in order.
+///
+/// `ChainProvider::builder` starts from the providers of the default chain, named
+/// `environment`, `profile`, `container` and `instance_metadata`, and `ChainProviderBuilder::new`
+/// from an empty chain. Providers are named when added, to insert other providers next to them,
+/// remove them or set their timeout.
+///
+/// # Example
+///
+/// ```rust
+/// use std::time::Duration;
+///
+/// use rusoto_credential::{ChainProvider, StaticProvider};
+///
+/// let vault = StaticProvider::new_minimal("key".to_owned(), "secret".to_owned());
+/// let provider = ChainProvider::builder()
+///     .insert_after("environment", "vault", vault)
+///     .remove("container")
+///     .timeout("instance_metadata", Duration::from_secs(2))
+///     .build();
+/// ```
+#[derive(Clone, Default)]
+pub struct ChainProviderBuilder {
+    links: Vec<ChainLink>,
+}
+
+#[cfg(feature = "tokio")]
+#[derive(Clone)]
+struct ChainLink {
+    name: String,
+    provider: Arc<dyn ProvideAwsCredentials + Send + Sync>,
+    timeout: Option<Duration>,
+}
+
+#[cfg(feature = "tokio")]
+impl ChainProviderBuilder {
+    /// Create a builder of an empty chain.
+    pub fn new() -> ChainProviderBuilder {
+        ChainProviderBuilder::default()
+    }
+
+    /// Add `provider` named `name` to the end of the chain.
+    pub fn push<P>(mut self, name: &str, provider: P) -> Self
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+    {
+        let link = ChainLink::new(name, provider);
+        self.links.push(link);
+        self
+    }
+
+    /// Insert `provider` named `name` before the provider named `before`, or at the end of the
+    /// chain if there is none.
+    pub fn insert_before<P>(mut self, before: &str, name: &str, provider: P) -> Self
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+    {
+        let index = self.position(before).unwrap_or(self.links.len());
+        self.links.insert(index, ChainLink::new(name, provider));
+        self
+    }
+
+    /// Insert `provider` named `name` after the provider named `after`, or at the end of the
+    /// chain if there is none.
+    pub fn insert_after<P>(mut self, after: &str, name: &str, provider: P) -> Self
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+    {
+        let index = self
+            .position(after)
+            .map_or_else(|| self.links.len(), |index| index + 1);
+        self.links.insert(index, ChainLink::new(name, provider));
+        self
+    }
+
+    /// Remove the providers named `name`.
+    pub fn remove(mut self, name: &str) -> Self {
+        self.links.retain(|link| link.name != name);
+        self
+    }
+
+    /// Give up on the providers named `name` if they take longer than `timeout`, and move on to
+    /// the next provider.
+    pub fn timeout(mut self, name: &str, timeout: Duration) -> Self {
+        for link in self.links.iter_mut().filter(|link| link.name == name) {
+            link.timeout = Some(timeout);
+        }
+        self
+    }
+
+    /// The names of the providers of the chain, in order.
+    pub fn names(&self) -> Vec<&str> {
+        self.links.iter().map(|link| link.name.as_str()).collect()
+    }
+
+    /// Create the chain.
+    pub fn build(self) -> CustomChainProvider {
+        CustomChainProvider { links: self.links }
+    }
+
+    fn position(&self, name: &str) -> Option<usize> {
+        self.links.iter().position(|link| link.name == name)
+    }
+}
+
+#[cfg(feature = "tokio")]
+impl ChainLink {
+    fn new<P>(name: &str, provider: P) -> ChainLink
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+    {
+        ChainLink {
+            name: name.to_owned(),
+            provider: Arc::new(provider),
+            timeout: None,
+        }
+    }
+}
+
+#[cfg(feature = "tokio")]
+impl fmt::Debug for ChainProviderBuilder {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("ChainProviderBuilder")
+            .field("providers", &self.names())
+            .finish()
+    }
+}
+
+#[cfg(feature = "tokio")]
+/// A chain of credentials providers built by a `ChainProviderBuilder`, returning the credentials
+/// of the first provider that has some.
+#[derive(Clone)]
+pub struct CustomChainProvider {
+    links: Vec<ChainLink>,
+}
+
+#[cfg(feature = "tokio")]
+impl fmt::Debug for CustomChainProvider {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let names: Vec<&str> = self.links.iter().map(|link| link.name.as_str()).collect();
+        f.debug_struct("CustomChainProvider")
+            .field("providers", &names)
+            .finish()
+    }
+}
+
+#[cfg(feature = "tokio")]
+#[async_trait]
+impl ProvideAwsCredentials for CustomChainProvider {
+    async fn credentials(&self) -> Result<AwsCredentials, CredentialsError> {
+        let mut errors = Vec::new();
+        for link in &self.links {
+            let result = match link.timeout {
+                Some(timeout) => tokio::time::timeout(timeout, link.provider.credentials())
+                    .await
+                    .unwrap_or_else(|_| Err(CredentialsError::new("timed out"))),
+                None => link.provider.credentials().await,
+            };
+            match result {
+                Ok(creds) => return Ok(creds),
+                Err(err) => errors.push(format!("{}: {}", link.name, err)),
+            }
+        }
+        Err(CredentialsError::new(format!(
+            "Couldn't find AWS credentials in the chain ({})",
+            errors.join("; ")
+        )))
+    }
+}
+
+#[cfg(feature = "tokio")]
 impl Default for ChainProvider {
     fn default() -> Self {
         Self::new()
@@ -452,6 +847,7 @@ fn non_empty_env_var(name: &str) -> Option<String> {
     }
 }
 
+#[cfg(feature = "tokio")]
 /// Parses the response from an AWS Metadata Service, either from an IAM Role, or a Container.
 fn parse_credentials_from_aws_service(response: &str) -> Result<AwsCredentials, CredentialsError> {
     Ok(serde_json::from_str::<AwsCredentials>(response)?)
@@ -478,6 +874,15 @@ mod tests {
         assert!(!AwsCredentials::new("foo", "bar", None, None).is_anonymous())
     }
 
+    #[tokio::test]
+    async fn anonymous_credentials_are_anonymous() {
+        assert!(AnonymousCredentials
+            .credentials()
+            .await
+            .unwrap()
+            .is_anonymous())
+    }
+
     #[test]
     fn providers_are_send_and_sync() {
         fn is_send_and_sync<T: Send + Sync>() {}
@@ -485,6 +890,157 @@ mod tests {
         is_send_and_sync::<ChainProvider>();
         is_send_and_sync::<AutoRefreshingProvider<ChainProvider>>();
         is_send_and_sync::<DefaultCredentialsProvider>();
+        is_send_and_sync::<CredentialsProvider>();
+    }
+
+    #[tokio::test]
+    async fn clones_of_credentials_providers_share_cached_credentials() {
+        let provider = CredentialsProvider::new(
+            AutoRefreshingProvider::new(ScriptedProvider::new(vec![
+                Ok(expiring_in("first", ChronoDuration::hours(1))),
+                Ok(expiring_in("second", ChronoDuration::hours(1))),
+            ]))
+            .unwrap(),
+        );
+        let clone = provider.clone();
+        let first = provider.credentials().await.unwrap();
+        let second = clone.credentials().await.unwrap();
+        assert_eq!(first.aws_access_key_id(), "first");
+        assert_eq!(second.aws_access_key_id(), "first");
+    }
+
+    /// Never returns.
+    struct HangingProvider;
+
+    #[async_trait]
+    impl ProvideAwsCredentials for HangingProvider {
+        async fn credentials(&self) -> Result<AwsCredentials, CredentialsError> {
+            futures::future::pending().await
+        }
+    }
+
+    #[test]
+    fn chain_provider_builder_arranges_providers() {
+        let builder = ChainProviderBuilder::new()
+            .push("environment", EnvironmentProvider::default())
+            .push("instance_metadata", InstanceMetadataProvider::new())
+            .insert_after(
+                "environment",
+                "vault",
+                StaticProvider::new_minimal("a".into(), "b".into()),
+            )
+            .insert_before("environment", "first", AnonymousCredentials)
+            .remove("instance_metadata");
+        assert_eq!(builder.names(), vec!["first", "environment", "vault"]);
+    }
+
+    #[tokio::test]
+    async fn custom_chain_provider_moves_on_after_timeouts() {
+        let provider = ChainProviderBuilder::new()
+            .push("hanging", HangingProvider)
+            .push(
+                "vault",
+                StaticProvider::new_minimal("vault_key".to_owned(), "secret".to_owned()),
+            )
+            .timeout("hanging", Duration::from_millis(10))
+            .build();
+        let creds = provider.credentials().await.unwrap();
+        assert_eq!(creds.aws_access_key_id(), "vault_key");
+
+        let provider = ChainProviderBuilder::new()
+            .push("hanging", HangingProvider)
+            .timeout("hanging", Duration::from_millis(10))
+            .build();
+        let err = provider.credentials().await.unwrap_err();
+        assert!(err.message.contains("hanging: timed out"), "{}", err);
+    }
+
+    /// Returns the scripted results in turn.
+    struct ScriptedProvider(std::sync::Mutex<Vec<Result<AwsCredentials, CredentialsError>>>);
+
+    impl ScriptedProvider {
+        fn new(mut results: Vec<Result<AwsCredentials, CredentialsError>>) -> ScriptedProvider {
+            results.reverse();
+            ScriptedProvider(std::sync::Mutex::new(results))
+        }
+    }
+
+    #[async_trait]
+    impl ProvideAwsCredentials for ScriptedProvider {
+        async fn credentials(&self) -> Result<AwsCredentials, CredentialsError> {
+            self.0
+                .lock()
+                .unwrap()
+                .pop()
+                .unwrap_or_else(|| Err(CredentialsError::new("no more credentials")))
+        }
+    }
+
+    fn expiring_in(access_key: &str, duration: ChronoDuration) -> AwsCredentials {
+        AwsCredentials::new(access_key, "secret", None, Some(Utc::now() + duration))
+    }
+
+    async fn access_key_id<P>(
+        provider: &AutoRefreshingProvider<P>,
+    ) -> Result<String, CredentialsError>
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+    {
+        provider
+            .credentials()
+            .await
+            .map(|creds| creds.aws_access_key_id().to_owned())
+    }
+
+    #[tokio::test]
+    async fn auto_refreshing_provider_refreshes_ahead_in_background() {
+        let provider = AutoRefreshingProvider::new(ScriptedProvider::new(vec![
+            Ok(expiring_in("old", ChronoDuration::minutes(2))),
+            Ok(expiring_in("new", ChronoDuration::hours(1))),
+        ]))
+        .unwrap();
+        assert_eq!(access_key_id(&provider).await.unwrap(), "old");
+        // the cached credentials are returned while the refresh runs
+        assert_eq!(access_key_id(&provider).await.unwrap(), "old");
+        for _ in 0..100 {
+            if access_key_id(&provider).await.unwrap() == "new" {
+                return;
+            }
+            tokio::time::delay_for(Duration::from_millis(10)).await;
+        }
+        panic!("credentials weren't refreshed");
+    }
+
+    #[tokio::test]
+    async fn auto_refreshing_provider_serves_stale_credentials_if_refreshing_fails() {
+        let scripted = || {
+            ScriptedProvider::new(vec![
+                Ok(expiring_in("old", ChronoDuration::seconds(10))),
+                Err(CredentialsError::new("unavailable")),
+            ])
+        };
+        let provider = AutoRefreshingProvider::new(scripted()).unwrap();
+        assert_eq!(access_key_id(&provider).await.unwrap(), "old");
+        assert_eq!(
+            access_key_id(&provider).await,
+            Err(CredentialsError::new("unavailable"))
+        );
+
+        let mut provider = AutoRefreshingProvider::new(scripted()).unwrap();
+        provider.set_stale_while_revalidate(true);
+        assert_eq!(access_key_id(&provider).await.unwrap(), "old");
+        assert_eq!(access_key_id(&provider).await.unwrap(), "old");
+    }
+
+    #[tokio::test]
+    async fn auto_refreshing_provider_does_not_cache_errors() {
+        let provider = AutoRefreshingProvider::new(ScriptedProvider::new(vec![
+            Err(CredentialsError::new("unavailable")),
+            Ok(expiring_in("new", ChronoDuration::hours(1))),
+        ]))
+        .unwrap();
+        assert!(access_key_id(&provider).await.is_err());
+        assert_eq!(access_key_id(&provider).await.unwrap(), "new");
     }
 
     #[tokio::test]
@@ -541,6 +1097,14 @@ mod tests {
             credentials.expires_at().expect(""),
             DateTime::parse_from_rfc3339("2016-11-18T01:50:39Z").expect("")
         );
+        assert_eq!(credentials.account_id(), None);
+    }
+
+    #[test]
+    fn parse_account_id_from_aws_service() {
+        let response = r#"{"AccessKeyId": "AKID", "SecretAccessKey": "secret", "Token": "token", "AccountId": "111122223333"}"#;
+        let credentials = parse_credentials_from_aws_service(response).unwrap();
+        assert_eq!(credentials.account_id(), Some("111122223333"));
     }
 
     #[cfg(test)]
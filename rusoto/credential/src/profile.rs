@@ -1,24 +1,89 @@
 //! The Credentials Provider for Credentials stored in a profile inside of a Credentials file.
 
 use std::collections::HashMap;
+use std::fmt;
 use std::fs;
 use std::fs::File;
 use std::io::{BufRead, BufReader};
 use std::path::{Path, PathBuf};
+use std::sync::Arc;
 
 use async_trait::async_trait;
+#[cfg(not(target_arch = "wasm32"))]
 use dirs::home_dir;
 use regex::Regex;
+#[cfg(feature = "tokio")]
 use serde::Deserialize;
+#[cfg(feature = "tokio")]
 use tokio::process::Command;
+#[cfg(feature = "tokio")]
+use tokio::sync::Mutex;
 
-use crate::{non_empty_env_var, AwsCredentials, CredentialsError, ProvideAwsCredentials};
+use crate::{
+    non_empty_env_var, AwsCredentials, CredentialsError, EnvironmentProvider, ProvideAwsCredentials,
+};
+#[cfg(feature = "tokio")]
+use crate::{ContainerProvider, InstanceMetadataProvider};
 
 const AWS_CONFIG_FILE: &str = "AWS_CONFIG_FILE";
 const AWS_PROFILE: &str = "AWS_PROFILE";
 const AWS_SHARED_CREDENTIALS_FILE: &str = "AWS_SHARED_CREDENTIALS_FILE";
+const CREDENTIAL_PROCESS: &str = "credential_process";
+const CREDENTIAL_SOURCE: &str = "credential_source";
 const DEFAULT: &str = "default";
+const DURATION_SECONDS: &str = "duration_seconds";
+const ENDPOINT_URL: &str = "endpoint_url";
+const EXTERNAL_ID: &str = "external_id";
+const MFA_SERIAL: &str = "mfa_serial";
 const REGION: &str = "region";
+const ROLE_ARN: &str = "role_arn";
+const ROLE_SESSION_NAME: &str = "role_session_name";
+const SOURCE_PROFILE: &str = "source_profile";
+
+/// A role to assume, configured by the `role_arn` key of a profile and the keys next to it.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct ProfileRole {
+    /// The name of the profile configuring the role.
+    pub profile: String,
+    /// `role_arn`, the ARN of the role.
+    pub role_arn: String,
+    /// `role_session_name`, the name of the role session.
+    pub role_session_name: Option<String>,
+    /// `external_id`, the external ID the role's trust policy may require.
+    pub external_id: Option<String>,
+    /// `duration_seconds`, the duration of the role session.
+    pub duration_seconds: Option<i64>,
+    /// `mfa_serial`, the serial number or ARN of the MFA device the role may require.
+    pub mfa_serial: Option<String>,
+    /// `region`, the region of the profile.
+    pub region: Option<String>,
+}
+
+/// Assumes the roles of profiles for a `ProfileProvider`, see `ProfileProvider::set_role_assumer`.
+///
+/// `rusoto_sts::StsRoleAssumer` assumes them with `sts:AssumeRole`.
+#[async_trait]
+pub trait AssumeProfileRole {
+    /// Assume `role` with the `source` credentials.
+    async fn assume_role(
+        &self,
+        source: AwsCredentials,
+        role: &ProfileRole,
+    ) -> Result<AwsCredentials, CredentialsError>;
+}
+
+/// Where the credentials assuming the first role of a chain of profiles come from.
+#[derive(Clone, Debug, PartialEq)]
+enum RoleSource {
+    /// The static keys or `credential_process` of a profile.
+    Profile(String),
+    /// `credential_source = Environment`.
+    Environment,
+    /// `credential_source = Ec2InstanceMetadata`.
+    Ec2InstanceMetadata,
+    /// `credential_source = EcsContainer`.
+    EcsContainer,
+}
 
 /// Provides AWS credentials from a profile in a credentials file, or from a credential process.
 ///
@@ -31,12 +96,32 @@ const REGION: &str = "region";
 /// best practices for your operating system.
 ///
 /// [credential_process]: https://docs.aws.amazon.com/cli/latest/topic/config-vars.html#sourcing-credentials-from-external-processes
-#[derive(Clone, Debug)]
+///
+/// # Assuming roles
+///
+/// A profile with a `role_arn` assumes the role with the credentials of its `source_profile`,
+/// which may assume a role in turn, or of its `credential_source`: `Environment`,
+/// `Ec2InstanceMetadata` or `EcsContainer`. The roles are assumed by the role assumer set with
+/// `set_role_assumer`, e.g. `rusoto_sts::StsRoleAssumer`, each time credentials are requested, so
+/// wrap the provider in an `AutoRefreshingProvider` to cache them.
+#[derive(Clone)]
 pub struct ProfileProvider {
     /// The File Path the Credentials File is located at.
     file_path: PathBuf,
     /// The Profile Path to parse out of the Credentials File.
     profile: String,
+    /// Assumes the roles of profiles with a `role_arn`.
+    role_assumer: Option<Arc<dyn AssumeProfileRole + Send + Sync>>,
+}
+
+impl fmt::Debug for ProfileProvider {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("ProfileProvider")
+            .field("file_path", &self.file_path)
+            .field("profile", &self.profile)
+            .field("role_assumer", &self.role_assumer.is_some())
+            .finish()
+    }
 }
 
 impl ProfileProvider {
@@ -58,6 +143,7 @@ impl ProfileProvider {
         ProfileProvider {
             file_path: file_path.into(),
             profile: profile.into(),
+            role_assumer: None,
         }
     }
 
@@ -77,12 +163,50 @@ impl ProfileProvider {
     ///
     /// For a the ful region resolution chain, use the `Default` impl for `rusoto_core::Region`
     pub fn region() -> Result<Option<String>, CredentialsError> {
+        ProfileProvider::config_value(REGION)
+    }
+
+    /// Attempts to resolve the `endpoint_url` value of the current profile from the same file as
+    /// `region`, giving the endpoint to send the requests of all services to.
+    pub fn endpoint_url() -> Result<Option<String>, CredentialsError> {
+        ProfileProvider::config_value(ENDPOINT_URL)
+    }
+
+    /// Attempts to resolve the value of `key` in the current profile from the same file as
+    /// `region`, e.g. `use_fips_endpoint`.
+    pub fn config_value(key: &str) -> Result<Option<String>, CredentialsError> {
+        ProfileProvider::profile_config_value(&ProfileProvider::default_profile_name(), key)
+    }
+
+    /// Attempts to resolve the value of `key` in `profile` from the same file as `region`, e.g.
+    /// `sso_start_url`.
+    pub fn profile_config_value(
+        profile: &str,
+        key: &str,
+    ) -> Result<Option<String>, CredentialsError> {
         let location = ProfileProvider::default_config_location();
         location.map(|location| {
             parse_config_file(&location).and_then(|config| {
                 config
-                    .get(&ProfileProvider::default_profile_name())
-                    .and_then(|props| props.get(REGION))
+                    .get(profile)
+                    .and_then(|props| props.get(key))
+                    .map(std::borrow::ToOwned::to_owned)
+            })
+        })
+    }
+
+    /// Attempts to resolve the value of `key` in the `[sso-session <session>]` section of the
+    /// same file as `region`, e.g. `sso_start_url`.
+    pub fn sso_session_config_value(
+        session: &str,
+        key: &str,
+    ) -> Result<Option<String>, CredentialsError> {
+        let location = ProfileProvider::default_config_location();
+        location.map(|location| {
+            parse_config_sections(&location, SectionKind::SsoSession).and_then(|sessions| {
+                sessions
+                    .get(session)
+                    .and_then(|props| props.get(key))
                     .map(std::borrow::ToOwned::to_owned)
             })
         })
@@ -165,40 +289,118 @@ impl ProfileProvider {
     {
         self.profile = profile.into();
     }
-}
 
-#[async_trait]
-impl ProvideAwsCredentials for ProfileProvider {
-    async fn credentials(&self) -> Result<AwsCredentials, CredentialsError> {
+    /// Set the role assumer assuming the roles of profiles with a `role_arn`.
+    pub fn set_role_assumer<A>(&mut self, role_assumer: A)
+    where
+        A: AssumeProfileRole + Send + Sync + 'static,
+    {
+        self.role_assumer = Some(Arc::new(role_assumer));
+    }
+
+    /// The roles to assume for the profile, from the outermost to the innermost, and the source
+    /// of the credentials assuming the innermost one.
+    fn role_chain(&self) -> Result<(RoleSource, Vec<ProfileRole>), CredentialsError> {
+        let config =
+            parse_config_file(&ProfileProvider::default_config_location()?).unwrap_or_default();
+        let credentials = parse_config_file(self.file_path()).unwrap_or_default();
+        // keys of the credentials file take precedence over those of the config file
+        let properties = |profile: &str| {
+            let mut props = config.get(profile).cloned().unwrap_or_default();
+            props.extend(credentials.get(profile).cloned().unwrap_or_default());
+            props
+        };
+
+        let mut roles: Vec<ProfileRole> = Vec::new();
+        let mut profile = self.profile.clone();
+        loop {
+            let mut props = properties(&profile);
+            let role_arn = match props.remove(ROLE_ARN) {
+                Some(role_arn) => role_arn,
+                None => return Ok((RoleSource::Profile(profile), roles)),
+            };
+            if roles.iter().any(|role| role.profile == profile) {
+                return Err(CredentialsError::new(format!(
+                    "The source_profile chain of profile {} has a cycle at profile {}",
+                    self.profile, profile
+                )));
+            }
+            let duration_seconds = match props.remove(DURATION_SECONDS) {
+                Some(seconds) => Some(seconds.parse().map_err(|_| {
+                    CredentialsError::new(format!(
+                        "Invalid duration_seconds in profile {}: {}",
+                        profile, seconds
+                    ))
+                })?),
+                None => None,
+            };
+            roles.push(ProfileRole {
+                profile: profile.clone(),
+                role_arn,
+                role_session_name: props.remove(ROLE_SESSION_NAME),
+                external_id: props.remove(EXTERNAL_ID),
+                duration_seconds,
+                mfa_serial: props.remove(MFA_SERIAL),
+                region: props.remove(REGION),
+            });
+            match (
+                props.remove(SOURCE_PROFILE),
+                props.remove(CREDENTIAL_SOURCE),
+            ) {
+                // a profile can assume its role with its own static keys
+                (Some(source), None) if source == profile => {
+                    return Ok((RoleSource::Profile(profile), roles))
+                }
+                (Some(source), None) => profile = source,
+                (None, Some(source)) => {
+                    let source = match source.as_str() {
+                        "Environment" => RoleSource::Environment,
+                        "Ec2InstanceMetadata" => RoleSource::Ec2InstanceMetadata,
+                        "EcsContainer" => RoleSource::EcsContainer,
+                        _ => {
+                            return Err(CredentialsError::new(format!(
+                                "Unsupported credential_source in profile {}: {}",
+                                profile, source
+                            )))
+                        }
+                    };
+                    return Ok((source, roles));
+                }
+                (Some(_), Some(_)) => {
+                    return Err(CredentialsError::new(format!(
+                        "Profile {} has both a source_profile and a credential_source",
+                        profile
+                    )))
+                }
+                (None, None) => {
+                    return Err(CredentialsError::new(format!(
+                        "Profile {} has a role_arn but no source_profile or credential_source",
+                        profile
+                    )))
+                }
+            }
+        }
+    }
+
+    /// The credentials of the `credential_process` or static keys of `profile`.
+    async fn profile_credentials(&self, profile: &str) -> Result<AwsCredentials, CredentialsError> {
         match ProfileProvider::default_config_location().map(|location| {
             parse_config_file(&location).and_then(|config| {
                 config
-                    .get(&ProfileProvider::default_profile_name())
-                    .and_then(|props| props.get("credential_process"))
+                    .get(profile)
+                    .and_then(|props| props.get(CREDENTIAL_PROCESS))
                     .map(std::borrow::ToOwned::to_owned)
             })
         }) {
             Ok(Some(command)) => {
                 // credential_process is set, create the future
-                let mut command = parse_command_str(&command)?;
-                let output = command.output().await.map_err(|e| {
-                    CredentialsError::new(format!("Credential process failed: {:?}", e))
-                })?;
-                if output.status.success() {
-                    parse_credential_process_output(&output.stdout)
-                } else {
-                    Err(CredentialsError::new(format!(
-                        "Credential process failed with {}: {}",
-                        output.status,
-                        String::from_utf8_lossy(&output.stderr)
-                    )))
-                }
+                run_credential_process(&command).await
             }
             Ok(None) => {
                 // credential_process is not set, parse the credentials file
                 parse_credentials_file(self.file_path()).and_then(|mut profiles| {
                     profiles
-                        .remove(self.profile())
+                        .remove(profile)
                         .ok_or_else(|| CredentialsError::new("profile not found"))
                 })
             }
@@ -207,6 +409,146 @@ impl ProvideAwsCredentials for ProfileProvider {
     }
 }
 
+#[async_trait]
+impl ProvideAwsCredentials for ProfileProvider {
+    async fn credentials(&self) -> Result<AwsCredentials, CredentialsError> {
+        let (source, roles) = self.role_chain()?;
+        if roles.is_empty() {
+            return self.profile_credentials(self.profile()).await;
+        }
+        let role_assumer = self.role_assumer.as_ref().ok_or_else(|| {
+            CredentialsError::new(format!(
+                "Profile {} assumes a role, which needs a role assumer such as \
+                 rusoto_sts::StsRoleAssumer, see ProfileProvider::set_role_assumer",
+                self.profile
+            ))
+        })?;
+        let mut credentials = match source {
+            RoleSource::Profile(profile) => self.profile_credentials(&profile).await?,
+            RoleSource::Environment => EnvironmentProvider::default().credentials().await?,
+            #[cfg(feature = "tokio")]
+            RoleSource::Ec2InstanceMetadata => {
+                InstanceMetadataProvider::new().credentials().await?
+            }
+            #[cfg(feature = "tokio")]
+            RoleSource::EcsContainer => ContainerProvider::new().credentials().await?,
+            #[cfg(not(feature = "tokio"))]
+            RoleSource::Ec2InstanceMetadata | RoleSource::EcsContainer => {
+                return Err(CredentialsError::new(
+                    "credential_source is only supported as Environment without the tokio feature",
+                ))
+            }
+        };
+        for role in roles.iter().rev() {
+            credentials = role_assumer.assume_role(credentials, role).await?;
+        }
+        Ok(credentials)
+    }
+}
+
+/// Provides AWS credentials from an external process, like the
+/// [`credential_process`][credential_process] option of a profile.
+///
+/// The command is split into the program and its arguments like a shell would, and run without
+/// one. It has to print a JSON object with `Version` 1, `AccessKeyId`, `SecretAccessKey` and
+/// optionally `SessionToken` and `Expiration`. The credentials are cached, and the command is run
+/// again once they have expired.
+///
+/// # Example
+///
+/// ```rust
+/// use rusoto_credential::CredentialProcessProvider;
+///
+/// let provider = CredentialProcessProvider::new("/opt/bin/vend-credentials --role deploy");
+/// ```
+///
+/// [credential_process]: https://docs.aws.amazon.com/cli/latest/topic/config-vars.html#sourcing-credentials-from-external-processes
+#[cfg(feature = "tokio")]
+#[derive(Clone, Debug)]
+pub struct CredentialProcessProvider {
+    command: String,
+    cached: Arc<Mutex<Option<AwsCredentials>>>,
+}
+
+#[cfg(feature = "tokio")]
+impl CredentialProcessProvider {
+    /// Create a provider running `command`.
+    pub fn new<S>(command: S) -> CredentialProcessProvider
+    where
+        S: Into<String>,
+    {
+        CredentialProcessProvider {
+            command: command.into(),
+            cached: Default::default(),
+        }
+    }
+
+    /// Create a provider running the `credential_process` of `profile` in the config file,
+    /// or `None` if it has none.
+    pub fn from_profile(
+        profile: &str,
+    ) -> Result<Option<CredentialProcessProvider>, CredentialsError> {
+        let location = ProfileProvider::default_config_location()?;
+        Ok(parse_config_file(&location)
+            .and_then(|mut config| config.remove(profile))
+            .and_then(|mut props| props.remove(CREDENTIAL_PROCESS))
+            .map(CredentialProcessProvider::new))
+    }
+
+    /// The command run to get credentials.
+    pub fn command(&self) -> &str {
+        &self.command
+    }
+}
+
+#[cfg(feature = "tokio")]
+#[async_trait]
+impl ProvideAwsCredentials for CredentialProcessProvider {
+    async fn credentials(&self) -> Result<AwsCredentials, CredentialsError> {
+        let mut cached = self.cached.lock().await;
+        if let Some(ref credentials) = *cached {
+            if !credentials.credentials_are_expired() {
+                return Ok(credentials.clone());
+            }
+        }
+        let credentials = run_credential_process(&self.command).await?;
+        *cached = Some(credentials.clone());
+        Ok(credentials)
+    }
+}
+
+#[cfg(feature = "tokio")]
+async fn run_credential_process(command: &str) -> Result<AwsCredentials, CredentialsError> {
+    let mut command = parse_command_str(command)?;
+    let output = command
+        .output()
+        .await
+        .map_err(|e| CredentialsError::new(format!("Credential process failed: {:?}", e)))?;
+    if output.status.success() {
+        parse_credential_process_output(&output.stdout)
+    } else {
+        Err(CredentialsError::new(format!(
+            "Credential process failed with {}: {}",
+            output.status,
+            String::from_utf8_lossy(&output.stderr)
+        )))
+    }
+}
+
+#[cfg(not(feature = "tokio"))]
+async fn run_credential_process(_command: &str) -> Result<AwsCredentials, CredentialsError> {
+    Err(CredentialsError::new(
+        "credential_process is not supported without the tokio feature",
+    ))
+}
+
+/// There are no home directories to find configuration files in on wasm32.
+#[cfg(target_arch = "wasm32")]
+fn home_dir() -> Option<PathBuf> {
+    None
+}
+
+#[cfg(feature = "tokio")]
 #[derive(Deserialize)]
 struct CredentialProcessOutput {
     #[serde(flatten)]
@@ -215,6 +557,7 @@ struct CredentialProcessOutput {
     version: u8,
 }
 
+#[cfg(feature = "tokio")]
 fn parse_credential_process_output(v: &[u8]) -> Result<AwsCredentials, CredentialsError> {
     let output: CredentialProcessOutput = serde_json::from_slice(v)?;
     if output.version == 1 {
@@ -232,7 +575,27 @@ fn new_profile_regex() -> Regex {
     Regex::new(r"^\[(profile )?([^\]]+)\]$").expect("Failed to compile regex")
 }
 
+/// Matches the headers of config file sections, capturing the kind and the name of the section.
+fn new_section_regex() -> Regex {
+    Regex::new(r"^\[(?:(profile|sso-session) )?([^\]]+)\]$").expect("Failed to compile regex")
+}
+
+/// The sections of a config file, `[profile <name>]` or `[sso-session <name>]`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum SectionKind {
+    Profile,
+    SsoSession,
+}
+
 fn parse_config_file(file_path: &Path) -> Option<HashMap<String, HashMap<String, String>>> {
+    parse_config_sections(file_path, SectionKind::Profile)
+}
+
+/// Parses the sections of `kind` in a config file into a Map of <`Name`, <`Key`, `Value`>>.
+fn parse_config_sections(
+    file_path: &Path,
+    kind: SectionKind,
+) -> Option<HashMap<String, HashMap<String, String>>> {
     match fs::metadata(file_path) {
         Err(_) => return None,
         Ok(metadata) => {
@@ -241,7 +604,7 @@ fn parse_config_file(file_path: &Path) -> Option<HashMap<String, HashMap<String,
             }
         }
     };
-    let profile_regex = new_profile_regex();
+    let section_regex = new_section_regex();
     let file = File::open(file_path).expect("expected file");
     let file_lines = BufReader::new(&file);
     let result: (HashMap<String, HashMap<String, String>>, Option<String>) = file_lines
@@ -253,9 +616,16 @@ fn parse_config_file(file_path: &Path) -> Option<HashMap<String, HashMap<String,
                 .find(|l| !l.starts_with('#') && !l.is_empty())
         })
         .fold(Default::default(), |(mut result, profile), line| {
-            if profile_regex.is_match(&line) {
-                let caps = profile_regex.captures(&line).unwrap();
-                let next_profile = caps.get(2).map(|value| value.as_str().to_string());
+            if let Some(caps) = section_regex.captures(&line) {
+                let section_kind = match caps.get(1).map(|value| value.as_str()) {
+                    Some("sso-session") => SectionKind::SsoSession,
+                    _ => SectionKind::Profile,
+                };
+                // keys of sections of other kinds are skipped
+                let next_profile = caps
+                    .get(2)
+                    .filter(|_| section_kind == kind)
+                    .map(|value| value.as_str().to_string());
                 (result, next_profile)
             } else {
                 match &line
@@ -383,6 +753,7 @@ fn parse_credentials_file(
     Ok(profiles)
 }
 
+#[cfg(feature = "tokio")]
 fn parse_command_str(s: &str) -> Result<Command, CredentialsError> {
     let args = shlex::split(s)
         .ok_or_else(|| CredentialsError::new("Unable to parse credential_process value."))?;
@@ -457,6 +828,27 @@ mod tests {
         );
     }
 
+    #[test]
+    fn parse_config_file_sso_sessions() {
+        let path = Path::new("tests/sample-data/sso_session_config");
+        let profiles = super::parse_config_file(path).unwrap();
+        assert_eq!(profiles.len(), 1);
+        let profile = profiles
+            .get("dev")
+            .expect("No dev profile in sso_session_config");
+        assert_eq!(profile.get("sso_session"), Some(&"my-sso".to_string()));
+        assert_eq!(profile.get("sso_start_url"), None);
+
+        let sessions = super::parse_config_sections(path, SectionKind::SsoSession).unwrap();
+        assert_eq!(sessions.len(), 1);
+        let session = sessions.get("my-sso").expect("No my-sso session");
+        assert_eq!(
+            session.get("sso_start_url"),
+            Some(&"https://my-sso-portal.awsapps.com/start".to_string())
+        );
+        assert_eq!(session.get("sso_region"), Some(&"us-east-1".to_string()));
+    }
+
     #[test]
     fn parse_credentials_file_default_profile() {
         let result = super::parse_credentials_file(Path::new(
@@ -591,6 +983,156 @@ mod tests {
         env::remove_var(AWS_CONFIG_FILE);
     }
 
+    #[tokio::test]
+    async fn profile_provider_credential_process_of_profile() {
+        let _guard = lock_env();
+        env::set_var(
+            AWS_CONFIG_FILE,
+            "tests/sample-data/credential_process_config",
+        );
+        let mut provider = ProfileProvider::new().unwrap();
+        provider.set_profile("foo");
+        let result = provider.credentials().await;
+        env::remove_var(AWS_CONFIG_FILE);
+
+        assert_eq!(result.unwrap().aws_access_key_id(), "foo_access_key");
+    }
+
+    /// Assumes roles by returning credentials named after the role, recording its calls.
+    #[derive(Default)]
+    struct RecordingAssumer {
+        calls: std::sync::Mutex<Vec<(String, ProfileRole)>>,
+    }
+
+    #[async_trait]
+    impl AssumeProfileRole for Arc<RecordingAssumer> {
+        async fn assume_role(
+            &self,
+            source: AwsCredentials,
+            role: &ProfileRole,
+        ) -> Result<AwsCredentials, CredentialsError> {
+            self.calls
+                .lock()
+                .unwrap()
+                .push((source.aws_access_key_id().to_owned(), role.clone()));
+            Ok(AwsCredentials::new(&role.role_arn, "secret", None, None))
+        }
+    }
+
+    async fn role_chain_credentials(
+        profile: &str,
+        assumer: Option<Arc<RecordingAssumer>>,
+    ) -> Result<AwsCredentials, CredentialsError> {
+        env::set_var(AWS_CONFIG_FILE, "tests/sample-data/role_chain_config");
+        let mut provider = ProfileProvider::with_configuration(
+            "tests/sample-data/role_chain_credentials",
+            profile,
+        );
+        if let Some(assumer) = assumer {
+            provider.set_role_assumer(assumer);
+        }
+        let result = provider.credentials().await;
+        env::remove_var(AWS_CONFIG_FILE);
+        result
+    }
+
+    #[tokio::test]
+    async fn profile_provider_assumes_chained_roles() {
+        let _guard = lock_env();
+        let assumer = Arc::new(RecordingAssumer::default());
+        let creds = role_chain_credentials("deploy", Some(assumer.clone()))
+            .await
+            .unwrap();
+
+        assert_eq!(
+            creds.aws_access_key_id(),
+            "arn:aws:iam::111122223333:role/deploy"
+        );
+        let calls = assumer.calls.lock().unwrap();
+        assert_eq!(calls.len(), 2);
+        assert_eq!(calls[0].0, "base_access_key");
+        assert_eq!(calls[0].1.role_arn, "arn:aws:iam::111122223333:role/admin");
+        assert_eq!(calls[1].0, "arn:aws:iam::111122223333:role/admin");
+        assert_eq!(
+            calls[1].1,
+            ProfileRole {
+                profile: "deploy".to_owned(),
+                role_arn: "arn:aws:iam::111122223333:role/deploy".to_owned(),
+                external_id: Some("deploy-external-id".to_owned()),
+                duration_seconds: Some(1800),
+                ..Default::default()
+            }
+        );
+    }
+
+    #[tokio::test]
+    async fn profile_provider_assumes_role_with_credential_source() {
+        let _guard = lock_env();
+        env::set_var("AWS_ACCESS_KEY_ID", "env_access_key");
+        env::set_var("AWS_SECRET_ACCESS_KEY", "env_secret_key");
+        let assumer = Arc::new(RecordingAssumer::default());
+        let result = role_chain_credentials("env", Some(assumer.clone())).await;
+        env::remove_var("AWS_ACCESS_KEY_ID");
+        env::remove_var("AWS_SECRET_ACCESS_KEY");
+
+        assert!(result.is_ok());
+        assert_eq!(assumer.calls.lock().unwrap()[0].0, "env_access_key");
+    }
+
+    #[tokio::test]
+    async fn profile_provider_rejects_bad_role_chains() {
+        let _guard = lock_env();
+        let assumer = Arc::new(RecordingAssumer::default());
+        let err = role_chain_credentials("cycle-a", Some(assumer))
+            .await
+            .unwrap_err();
+        assert!(err.message.contains("cycle"), "{}", err);
+
+        let err = role_chain_credentials("deploy", None).await.unwrap_err();
+        assert!(err.message.contains("set_role_assumer"), "{}", err);
+    }
+
+    #[tokio::test]
+    async fn credential_process_provider_caches_credentials() {
+        let dir = env::temp_dir().join("rusoto-credential-process-provider");
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).unwrap();
+        let marker = dir.join("runs");
+        let script = dir.join("vend.sh");
+        // Each run appends to the marker file, so the number of runs can be counted.
+        fs::write(
+            &script,
+            format!(
+                "echo run >> '{}'\necho '{{\"Version\": 1, \"AccessKeyId\": \"process_key\", \"SecretAccessKey\": \"process_secret\"}}'\n",
+                marker.display()
+            ),
+        )
+        .unwrap();
+        let provider = CredentialProcessProvider::new(format!("sh '{}'", script.display()));
+        let first = provider.credentials().await.unwrap();
+        let second = provider.credentials().await.unwrap();
+        let runs = fs::read_to_string(&marker).unwrap().lines().count();
+        fs::remove_dir_all(&dir).unwrap();
+
+        assert_eq!(first.aws_access_key_id(), "process_key");
+        assert_eq!(second.aws_access_key_id(), "process_key");
+        assert_eq!(runs, 1);
+    }
+
+    #[test]
+    fn profile_endpoint_url() {
+        let _guard = lock_env();
+        env::set_var(AWS_CONFIG_FILE, "tests/sample-data/endpoint_url_config");
+        assert_eq!(
+            ProfileProvider::endpoint_url(),
+            Ok(Some("http://localhost:4566".to_owned()))
+        );
+        env::set_var(AWS_PROFILE, "foo");
+        assert_eq!(ProfileProvider::endpoint_url(), Ok(None));
+        env::remove_var(AWS_PROFILE);
+        env::remove_var(AWS_CONFIG_FILE);
+    }
+
     #[test]
     fn profile_provider_profile_name() {
         let _guard = lock_env();
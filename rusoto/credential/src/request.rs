@@ -4,7 +4,7 @@ use std::time::Duration;
 
 use futures::StreamExt;
 use hyper::client::HttpConnector;
-use hyper::{Body, Client as HyperClient, Request, Uri};
+use hyper::{Body, Client as HyperClient, Request, StatusCode, Uri};
 use tokio::time;
 
 /// Http client for use in a credentials provider.
@@ -32,12 +32,24 @@ impl HttpClient {
     }
 
     pub async fn request(&self, req: Request<Body>, timeout: Duration) -> Result<String, IoError> {
+        self.request_with_status(req, timeout)
+            .await
+            .map(|(_status, body)| body)
+    }
+
+    /// Send `req`, returning the status of the response along with its body.
+    pub async fn request_with_status(
+        &self,
+        req: Request<Body>,
+        timeout: Duration,
+    ) -> Result<(StatusCode, String), IoError> {
         match time::timeout(timeout, self.inner.request(req)).await {
             Err(_elapsed) => Err(IoError::new(ErrorKind::TimedOut, "Request timed out")),
             Ok(try_resp) => {
                 let mut resp = try_resp.map_err(|err| {
                     IoError::new(ErrorKind::Other, format!("Response failed: {}", err))
                 })?;
+                let status = resp.status();
                 let body = resp.body_mut();
                 let mut text = vec![];
                 while let Some(chunk) = body.next().await {
@@ -46,8 +58,9 @@ impl HttpClient {
                     })?;
                     text.extend(chunk.to_vec());
                 }
-                String::from_utf8(text)
-                    .map_err(|_| IoError::new(ErrorKind::InvalidData, "Non UTF-8 Data returned"))
+                let body = String::from_utf8(text)
+                    .map_err(|_| IoError::new(ErrorKind::InvalidData, "Non UTF-8 Data returned"))?;
+                Ok((status, body))
             }
         }
     }
@@ -17,9 +17,14 @@ async fn main() {
 }"#
         });
 
+    let session_token = warp::put()
+        .and(path!("latest" / "api" / "token"))
+        .map(|| "session-token");
+
     let routes = warp::get()
         .and(instance_profile_creds)
-        .or(instance_profile_role);
+        .or(instance_profile_role)
+        .or(session_token);
 
     warp::serve(routes).run(([127, 0, 0, 1], 8080)).await;
 }
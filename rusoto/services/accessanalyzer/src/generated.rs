@@ -16,8 +16,8 @@ use std::fmt;
 use async_trait::async_trait;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
-use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest, ResponseMetadata};
+use rusoto_core::{Client, ErrorMetadata, RusotoError};
 
 use rusoto_core::param::{Params, ServiceParams};
 use rusoto_core::proto;
@@ -682,6 +682,7 @@ pub struct ValidationExceptionField {
 
 /// Errors returned by CreateAnalyzer
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateAnalyzerError {
     /// <p>You do not have sufficient access to perform this action.</p>
     AccessDenied(String),
@@ -693,6 +694,8 @@ pub enum CreateAnalyzerError {
     ServiceQuotaExceeded(String),
     /// <p>Throttling limit exceeded error.</p>
     Throttling(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateAnalyzerError {
@@ -700,22 +703,25 @@ impl CreateAnalyzerError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(CreateAnalyzerError::AccessDenied(err.msg))
+                    return RusotoError::service(CreateAnalyzerError::AccessDenied(err.msg), &res)
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(CreateAnalyzerError::Conflict(err.msg))
+                    return RusotoError::service(CreateAnalyzerError::Conflict(err.msg), &res)
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(CreateAnalyzerError::InternalServer(err.msg))
+                    return RusotoError::service(CreateAnalyzerError::InternalServer(err.msg), &res)
                 }
                 "ServiceQuotaExceededException" => {
-                    return RusotoError::Service(CreateAnalyzerError::ServiceQuotaExceeded(err.msg))
+                    return RusotoError::service(
+                        CreateAnalyzerError::ServiceQuotaExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(CreateAnalyzerError::Throttling(err.msg))
+                    return RusotoError::service(CreateAnalyzerError::Throttling(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreateAnalyzerError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -730,12 +736,14 @@ impl fmt::Display for CreateAnalyzerError {
             CreateAnalyzerError::InternalServer(ref cause) => write!(f, "{}", cause),
             CreateAnalyzerError::ServiceQuotaExceeded(ref cause) => write!(f, "{}", cause),
             CreateAnalyzerError::Throttling(ref cause) => write!(f, "{}", cause),
+            CreateAnalyzerError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateAnalyzerError {}
 /// Errors returned by CreateArchiveRule
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateArchiveRuleError {
     /// <p>You do not have sufficient access to perform this action.</p>
     AccessDenied(String),
@@ -749,6 +757,8 @@ pub enum CreateArchiveRuleError {
     ServiceQuotaExceeded(String),
     /// <p>Throttling limit exceeded error.</p>
     Throttling(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateArchiveRuleError {
@@ -756,27 +766,37 @@ impl CreateArchiveRuleError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(CreateArchiveRuleError::AccessDenied(err.msg))
+                    return RusotoError::service(
+                        CreateArchiveRuleError::AccessDenied(err.msg),
+                        &res,
+                    )
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(CreateArchiveRuleError::Conflict(err.msg))
+                    return RusotoError::service(CreateArchiveRuleError::Conflict(err.msg), &res)
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(CreateArchiveRuleError::InternalServer(err.msg))
+                    return RusotoError::service(
+                        CreateArchiveRuleError::InternalServer(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(CreateArchiveRuleError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        CreateArchiveRuleError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceQuotaExceededException" => {
-                    return RusotoError::Service(CreateArchiveRuleError::ServiceQuotaExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateArchiveRuleError::ServiceQuotaExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(CreateArchiveRuleError::Throttling(err.msg))
+                    return RusotoError::service(CreateArchiveRuleError::Throttling(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreateArchiveRuleError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -792,12 +812,14 @@ impl fmt::Display for CreateArchiveRuleError {
             CreateArchiveRuleError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             CreateArchiveRuleError::ServiceQuotaExceeded(ref cause) => write!(f, "{}", cause),
             CreateArchiveRuleError::Throttling(ref cause) => write!(f, "{}", cause),
+            CreateArchiveRuleError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateArchiveRuleError {}
 /// Errors returned by DeleteAnalyzer
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteAnalyzerError {
     /// <p>You do not have sufficient access to perform this action.</p>
     AccessDenied(String),
@@ -807,6 +829,8 @@ pub enum DeleteAnalyzerError {
     ResourceNotFound(String),
     /// <p>Throttling limit exceeded error.</p>
     Throttling(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteAnalyzerError {
@@ -814,19 +838,22 @@ impl DeleteAnalyzerError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(DeleteAnalyzerError::AccessDenied(err.msg))
+                    return RusotoError::service(DeleteAnalyzerError::AccessDenied(err.msg), &res)
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(DeleteAnalyzerError::InternalServer(err.msg))
+                    return RusotoError::service(DeleteAnalyzerError::InternalServer(err.msg), &res)
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteAnalyzerError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        DeleteAnalyzerError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DeleteAnalyzerError::Throttling(err.msg))
+                    return RusotoError::service(DeleteAnalyzerError::Throttling(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteAnalyzerError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -840,12 +867,14 @@ impl fmt::Display for DeleteAnalyzerError {
             DeleteAnalyzerError::InternalServer(ref cause) => write!(f, "{}", cause),
             DeleteAnalyzerError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             DeleteAnalyzerError::Throttling(ref cause) => write!(f, "{}", cause),
+            DeleteAnalyzerError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteAnalyzerError {}
 /// Errors returned by DeleteArchiveRule
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteArchiveRuleError {
     /// <p>You do not have sufficient access to perform this action.</p>
     AccessDenied(String),
@@ -855,6 +884,8 @@ pub enum DeleteArchiveRuleError {
     ResourceNotFound(String),
     /// <p>Throttling limit exceeded error.</p>
     Throttling(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteArchiveRuleError {
@@ -862,19 +893,28 @@ impl DeleteArchiveRuleError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(DeleteArchiveRuleError::AccessDenied(err.msg))
+                    return RusotoError::service(
+                        DeleteArchiveRuleError::AccessDenied(err.msg),
+                        &res,
+                    )
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(DeleteArchiveRuleError::InternalServer(err.msg))
+                    return RusotoError::service(
+                        DeleteArchiveRuleError::InternalServer(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteArchiveRuleError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        DeleteArchiveRuleError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DeleteArchiveRuleError::Throttling(err.msg))
+                    return RusotoError::service(DeleteArchiveRuleError::Throttling(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteArchiveRuleError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -888,12 +928,14 @@ impl fmt::Display for DeleteArchiveRuleError {
             DeleteArchiveRuleError::InternalServer(ref cause) => write!(f, "{}", cause),
             DeleteArchiveRuleError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             DeleteArchiveRuleError::Throttling(ref cause) => write!(f, "{}", cause),
+            DeleteArchiveRuleError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteArchiveRuleError {}
 /// Errors returned by GetAnalyzedResource
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetAnalyzedResourceError {
     /// <p>You do not have sufficient access to perform this action.</p>
     AccessDenied(String),
@@ -903,6 +945,8 @@ pub enum GetAnalyzedResourceError {
     ResourceNotFound(String),
     /// <p>Throttling limit exceeded error.</p>
     Throttling(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetAnalyzedResourceError {
@@ -910,21 +954,31 @@ impl GetAnalyzedResourceError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(GetAnalyzedResourceError::AccessDenied(err.msg))
+                    return RusotoError::service(
+                        GetAnalyzedResourceError::AccessDenied(err.msg),
+                        &res,
+                    )
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(GetAnalyzedResourceError::InternalServer(err.msg))
+                    return RusotoError::service(
+                        GetAnalyzedResourceError::InternalServer(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetAnalyzedResourceError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetAnalyzedResourceError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(GetAnalyzedResourceError::Throttling(err.msg))
+                    return RusotoError::service(
+                        GetAnalyzedResourceError::Throttling(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetAnalyzedResourceError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -938,12 +992,14 @@ impl fmt::Display for GetAnalyzedResourceError {
             GetAnalyzedResourceError::InternalServer(ref cause) => write!(f, "{}", cause),
             GetAnalyzedResourceError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             GetAnalyzedResourceError::Throttling(ref cause) => write!(f, "{}", cause),
+            GetAnalyzedResourceError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetAnalyzedResourceError {}
 /// Errors returned by GetAnalyzer
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetAnalyzerError {
     /// <p>You do not have sufficient access to perform this action.</p>
     AccessDenied(String),
@@ -953,6 +1009,8 @@ pub enum GetAnalyzerError {
     ResourceNotFound(String),
     /// <p>Throttling limit exceeded error.</p>
     Throttling(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetAnalyzerError {
@@ -960,19 +1018,19 @@ impl GetAnalyzerError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(GetAnalyzerError::AccessDenied(err.msg))
+                    return RusotoError::service(GetAnalyzerError::AccessDenied(err.msg), &res)
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(GetAnalyzerError::InternalServer(err.msg))
+                    return RusotoError::service(GetAnalyzerError::InternalServer(err.msg), &res)
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetAnalyzerError::ResourceNotFound(err.msg))
+                    return RusotoError::service(GetAnalyzerError::ResourceNotFound(err.msg), &res)
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(GetAnalyzerError::Throttling(err.msg))
+                    return RusotoError::service(GetAnalyzerError::Throttling(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetAnalyzerError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -986,12 +1044,14 @@ impl fmt::Display for GetAnalyzerError {
             GetAnalyzerError::InternalServer(ref cause) => write!(f, "{}", cause),
             GetAnalyzerError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             GetAnalyzerError::Throttling(ref cause) => write!(f, "{}", cause),
+            GetAnalyzerError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetAnalyzerError {}
 /// Errors returned by GetArchiveRule
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetArchiveRuleError {
     /// <p>You do not have sufficient access to perform this action.</p>
     AccessDenied(String),
@@ -1001,6 +1061,8 @@ pub enum GetArchiveRuleError {
     ResourceNotFound(String),
     /// <p>Throttling limit exceeded error.</p>
     Throttling(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetArchiveRuleError {
@@ -1008,19 +1070,22 @@ impl GetArchiveRuleError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(GetArchiveRuleError::AccessDenied(err.msg))
+                    return RusotoError::service(GetArchiveRuleError::AccessDenied(err.msg), &res)
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(GetArchiveRuleError::InternalServer(err.msg))
+                    return RusotoError::service(GetArchiveRuleError::InternalServer(err.msg), &res)
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetArchiveRuleError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        GetArchiveRuleError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(GetArchiveRuleError::Throttling(err.msg))
+                    return RusotoError::service(GetArchiveRuleError::Throttling(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetArchiveRuleError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1034,12 +1099,14 @@ impl fmt::Display for GetArchiveRuleError {
             GetArchiveRuleError::InternalServer(ref cause) => write!(f, "{}", cause),
             GetArchiveRuleError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             GetArchiveRuleError::Throttling(ref cause) => write!(f, "{}", cause),
+            GetArchiveRuleError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetArchiveRuleError {}
 /// Errors returned by GetFinding
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetFindingError {
     /// <p>You do not have sufficient access to perform this action.</p>
     AccessDenied(String),
@@ -1049,6 +1116,8 @@ pub enum GetFindingError {
     ResourceNotFound(String),
     /// <p>Throttling limit exceeded error.</p>
     Throttling(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetFindingError {
@@ -1056,19 +1125,19 @@ impl GetFindingError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(GetFindingError::AccessDenied(err.msg))
+                    return RusotoError::service(GetFindingError::AccessDenied(err.msg), &res)
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(GetFindingError::InternalServer(err.msg))
+                    return RusotoError::service(GetFindingError::InternalServer(err.msg), &res)
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetFindingError::ResourceNotFound(err.msg))
+                    return RusotoError::service(GetFindingError::ResourceNotFound(err.msg), &res)
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(GetFindingError::Throttling(err.msg))
+                    return RusotoError::service(GetFindingError::Throttling(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetFindingError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1082,12 +1151,14 @@ impl fmt::Display for GetFindingError {
             GetFindingError::InternalServer(ref cause) => write!(f, "{}", cause),
             GetFindingError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             GetFindingError::Throttling(ref cause) => write!(f, "{}", cause),
+            GetFindingError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetFindingError {}
 /// Errors returned by ListAnalyzedResources
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListAnalyzedResourcesError {
     /// <p>You do not have sufficient access to perform this action.</p>
     AccessDenied(String),
@@ -1097,6 +1168,8 @@ pub enum ListAnalyzedResourcesError {
     ResourceNotFound(String),
     /// <p>Throttling limit exceeded error.</p>
     Throttling(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListAnalyzedResourcesError {
@@ -1104,23 +1177,31 @@ impl ListAnalyzedResourcesError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(ListAnalyzedResourcesError::AccessDenied(err.msg))
+                    return RusotoError::service(
+                        ListAnalyzedResourcesError::AccessDenied(err.msg),
+                        &res,
+                    )
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(ListAnalyzedResourcesError::InternalServer(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListAnalyzedResourcesError::InternalServer(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListAnalyzedResourcesError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListAnalyzedResourcesError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(ListAnalyzedResourcesError::Throttling(err.msg))
+                    return RusotoError::service(
+                        ListAnalyzedResourcesError::Throttling(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListAnalyzedResourcesError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1134,12 +1215,14 @@ impl fmt::Display for ListAnalyzedResourcesError {
             ListAnalyzedResourcesError::InternalServer(ref cause) => write!(f, "{}", cause),
             ListAnalyzedResourcesError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             ListAnalyzedResourcesError::Throttling(ref cause) => write!(f, "{}", cause),
+            ListAnalyzedResourcesError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListAnalyzedResourcesError {}
 /// Errors returned by ListAnalyzers
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListAnalyzersError {
     /// <p>You do not have sufficient access to perform this action.</p>
     AccessDenied(String),
@@ -1147,6 +1230,8 @@ pub enum ListAnalyzersError {
     InternalServer(String),
     /// <p>Throttling limit exceeded error.</p>
     Throttling(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListAnalyzersError {
@@ -1154,16 +1239,16 @@ impl ListAnalyzersError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(ListAnalyzersError::AccessDenied(err.msg))
+                    return RusotoError::service(ListAnalyzersError::AccessDenied(err.msg), &res)
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(ListAnalyzersError::InternalServer(err.msg))
+                    return RusotoError::service(ListAnalyzersError::InternalServer(err.msg), &res)
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(ListAnalyzersError::Throttling(err.msg))
+                    return RusotoError::service(ListAnalyzersError::Throttling(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListAnalyzersError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1176,12 +1261,14 @@ impl fmt::Display for ListAnalyzersError {
             ListAnalyzersError::AccessDenied(ref cause) => write!(f, "{}", cause),
             ListAnalyzersError::InternalServer(ref cause) => write!(f, "{}", cause),
             ListAnalyzersError::Throttling(ref cause) => write!(f, "{}", cause),
+            ListAnalyzersError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListAnalyzersError {}
 /// Errors returned by ListArchiveRules
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListArchiveRulesError {
     /// <p>You do not have sufficient access to perform this action.</p>
     AccessDenied(String),
@@ -1189,6 +1276,8 @@ pub enum ListArchiveRulesError {
     InternalServer(String),
     /// <p>Throttling limit exceeded error.</p>
     Throttling(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListArchiveRulesError {
@@ -1196,16 +1285,19 @@ impl ListArchiveRulesError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(ListArchiveRulesError::AccessDenied(err.msg))
+                    return RusotoError::service(ListArchiveRulesError::AccessDenied(err.msg), &res)
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(ListArchiveRulesError::InternalServer(err.msg))
+                    return RusotoError::service(
+                        ListArchiveRulesError::InternalServer(err.msg),
+                        &res,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(ListArchiveRulesError::Throttling(err.msg))
+                    return RusotoError::service(ListArchiveRulesError::Throttling(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListArchiveRulesError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1218,12 +1310,14 @@ impl fmt::Display for ListArchiveRulesError {
             ListArchiveRulesError::AccessDenied(ref cause) => write!(f, "{}", cause),
             ListArchiveRulesError::InternalServer(ref cause) => write!(f, "{}", cause),
             ListArchiveRulesError::Throttling(ref cause) => write!(f, "{}", cause),
+            ListArchiveRulesError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListArchiveRulesError {}
 /// Errors returned by ListFindings
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListFindingsError {
     /// <p>You do not have sufficient access to perform this action.</p>
     AccessDenied(String),
@@ -1233,6 +1327,8 @@ pub enum ListFindingsError {
     ResourceNotFound(String),
     /// <p>Throttling limit exceeded error.</p>
     Throttling(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListFindingsError {
@@ -1240,19 +1336,19 @@ impl ListFindingsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(ListFindingsError::AccessDenied(err.msg))
+                    return RusotoError::service(ListFindingsError::AccessDenied(err.msg), &res)
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(ListFindingsError::InternalServer(err.msg))
+                    return RusotoError::service(ListFindingsError::InternalServer(err.msg), &res)
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListFindingsError::ResourceNotFound(err.msg))
+                    return RusotoError::service(ListFindingsError::ResourceNotFound(err.msg), &res)
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(ListFindingsError::Throttling(err.msg))
+                    return RusotoError::service(ListFindingsError::Throttling(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListFindingsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1266,12 +1362,14 @@ impl fmt::Display for ListFindingsError {
             ListFindingsError::InternalServer(ref cause) => write!(f, "{}", cause),
             ListFindingsError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             ListFindingsError::Throttling(ref cause) => write!(f, "{}", cause),
+            ListFindingsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListFindingsError {}
 /// Errors returned by ListTagsForResource
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListTagsForResourceError {
     /// <p>You do not have sufficient access to perform this action.</p>
     AccessDenied(String),
@@ -1281,6 +1379,8 @@ pub enum ListTagsForResourceError {
     ResourceNotFound(String),
     /// <p>Throttling limit exceeded error.</p>
     Throttling(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListTagsForResourceError {
@@ -1288,21 +1388,31 @@ impl ListTagsForResourceError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(ListTagsForResourceError::AccessDenied(err.msg))
+                    return RusotoError::service(
+                        ListTagsForResourceError::AccessDenied(err.msg),
+                        &res,
+                    )
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(ListTagsForResourceError::InternalServer(err.msg))
+                    return RusotoError::service(
+                        ListTagsForResourceError::InternalServer(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListTagsForResourceError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListTagsForResourceError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(ListTagsForResourceError::Throttling(err.msg))
+                    return RusotoError::service(
+                        ListTagsForResourceError::Throttling(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListTagsForResourceError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1316,12 +1426,14 @@ impl fmt::Display for ListTagsForResourceError {
             ListTagsForResourceError::InternalServer(ref cause) => write!(f, "{}", cause),
             ListTagsForResourceError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             ListTagsForResourceError::Throttling(ref cause) => write!(f, "{}", cause),
+            ListTagsForResourceError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListTagsForResourceError {}
 /// Errors returned by StartResourceScan
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum StartResourceScanError {
     /// <p>You do not have sufficient access to perform this action.</p>
     AccessDenied(String),
@@ -1331,6 +1443,8 @@ pub enum StartResourceScanError {
     ResourceNotFound(String),
     /// <p>Throttling limit exceeded error.</p>
     Throttling(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl StartResourceScanError {
@@ -1338,19 +1452,28 @@ impl StartResourceScanError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(StartResourceScanError::AccessDenied(err.msg))
+                    return RusotoError::service(
+                        StartResourceScanError::AccessDenied(err.msg),
+                        &res,
+                    )
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(StartResourceScanError::InternalServer(err.msg))
+                    return RusotoError::service(
+                        StartResourceScanError::InternalServer(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(StartResourceScanError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        StartResourceScanError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(StartResourceScanError::Throttling(err.msg))
+                    return RusotoError::service(StartResourceScanError::Throttling(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(StartResourceScanError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1364,12 +1487,14 @@ impl fmt::Display for StartResourceScanError {
             StartResourceScanError::InternalServer(ref cause) => write!(f, "{}", cause),
             StartResourceScanError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             StartResourceScanError::Throttling(ref cause) => write!(f, "{}", cause),
+            StartResourceScanError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for StartResourceScanError {}
 /// Errors returned by TagResource
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum TagResourceError {
     /// <p>You do not have sufficient access to perform this action.</p>
     AccessDenied(String),
@@ -1379,6 +1504,8 @@ pub enum TagResourceError {
     ResourceNotFound(String),
     /// <p>Throttling limit exceeded error.</p>
     Throttling(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl TagResourceError {
@@ -1386,19 +1513,19 @@ impl TagResourceError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(TagResourceError::AccessDenied(err.msg))
+                    return RusotoError::service(TagResourceError::AccessDenied(err.msg), &res)
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(TagResourceError::InternalServer(err.msg))
+                    return RusotoError::service(TagResourceError::InternalServer(err.msg), &res)
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(TagResourceError::ResourceNotFound(err.msg))
+                    return RusotoError::service(TagResourceError::ResourceNotFound(err.msg), &res)
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(TagResourceError::Throttling(err.msg))
+                    return RusotoError::service(TagResourceError::Throttling(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(TagResourceError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1412,12 +1539,14 @@ impl fmt::Display for TagResourceError {
             TagResourceError::InternalServer(ref cause) => write!(f, "{}", cause),
             TagResourceError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             TagResourceError::Throttling(ref cause) => write!(f, "{}", cause),
+            TagResourceError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for TagResourceError {}
 /// Errors returned by UntagResource
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UntagResourceError {
     /// <p>You do not have sufficient access to perform this action.</p>
     AccessDenied(String),
@@ -1427,6 +1556,8 @@ pub enum UntagResourceError {
     ResourceNotFound(String),
     /// <p>Throttling limit exceeded error.</p>
     Throttling(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UntagResourceError {
@@ -1434,19 +1565,22 @@ impl UntagResourceError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(UntagResourceError::AccessDenied(err.msg))
+                    return RusotoError::service(UntagResourceError::AccessDenied(err.msg), &res)
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(UntagResourceError::InternalServer(err.msg))
+                    return RusotoError::service(UntagResourceError::InternalServer(err.msg), &res)
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UntagResourceError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        UntagResourceError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(UntagResourceError::Throttling(err.msg))
+                    return RusotoError::service(UntagResourceError::Throttling(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UntagResourceError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1460,12 +1594,14 @@ impl fmt::Display for UntagResourceError {
             UntagResourceError::InternalServer(ref cause) => write!(f, "{}", cause),
             UntagResourceError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             UntagResourceError::Throttling(ref cause) => write!(f, "{}", cause),
+            UntagResourceError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for UntagResourceError {}
 /// Errors returned by UpdateArchiveRule
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdateArchiveRuleError {
     /// <p>You do not have sufficient access to perform this action.</p>
     AccessDenied(String),
@@ -1475,6 +1611,8 @@ pub enum UpdateArchiveRuleError {
     ResourceNotFound(String),
     /// <p>Throttling limit exceeded error.</p>
     Throttling(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdateArchiveRuleError {
@@ -1482,19 +1620,28 @@ impl UpdateArchiveRuleError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(UpdateArchiveRuleError::AccessDenied(err.msg))
+                    return RusotoError::service(
+                        UpdateArchiveRuleError::AccessDenied(err.msg),
+                        &res,
+                    )
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(UpdateArchiveRuleError::InternalServer(err.msg))
+                    return RusotoError::service(
+                        UpdateArchiveRuleError::InternalServer(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateArchiveRuleError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        UpdateArchiveRuleError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(UpdateArchiveRuleError::Throttling(err.msg))
+                    return RusotoError::service(UpdateArchiveRuleError::Throttling(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UpdateArchiveRuleError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1508,12 +1655,14 @@ impl fmt::Display for UpdateArchiveRuleError {
             UpdateArchiveRuleError::InternalServer(ref cause) => write!(f, "{}", cause),
             UpdateArchiveRuleError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             UpdateArchiveRuleError::Throttling(ref cause) => write!(f, "{}", cause),
+            UpdateArchiveRuleError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for UpdateArchiveRuleError {}
 /// Errors returned by UpdateFindings
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdateFindingsError {
     /// <p>You do not have sufficient access to perform this action.</p>
     AccessDenied(String),
@@ -1523,6 +1672,8 @@ pub enum UpdateFindingsError {
     ResourceNotFound(String),
     /// <p>Throttling limit exceeded error.</p>
     Throttling(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdateFindingsError {
@@ -1530,19 +1681,22 @@ impl UpdateFindingsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(UpdateFindingsError::AccessDenied(err.msg))
+                    return RusotoError::service(UpdateFindingsError::AccessDenied(err.msg), &res)
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(UpdateFindingsError::InternalServer(err.msg))
+                    return RusotoError::service(UpdateFindingsError::InternalServer(err.msg), &res)
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateFindingsError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        UpdateFindingsError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(UpdateFindingsError::Throttling(err.msg))
+                    return RusotoError::service(UpdateFindingsError::Throttling(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UpdateFindingsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1556,6 +1710,7 @@ impl fmt::Display for UpdateFindingsError {
             UpdateFindingsError::InternalServer(ref cause) => write!(f, "{}", cause),
             UpdateFindingsError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             UpdateFindingsError::Throttling(ref cause) => write!(f, "{}", cause),
+            UpdateFindingsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
@@ -1682,6 +1837,7 @@ impl AccessAnalyzerClient {
     /// Creates a client backed by the default tokio event loop.
     ///
     /// The client will use the default credentials provider and tls client.
+    #[cfg(not(target_arch = "wasm32"))]
     pub fn new(region: region::Region) -> AccessAnalyzerClient {
         AccessAnalyzerClient {
             client: Client::shared(),
@@ -1707,77 +1863,175 @@ impl AccessAnalyzerClient {
     pub fn new_with_client(client: Client, region: region::Region) -> AccessAnalyzerClient {
         AccessAnalyzerClient { client, region }
     }
+
+    /// Returns a builder of a client, setting any of its region, credentials provider,
+    /// dispatcher, retry policy, endpoint and timeouts.
+    pub fn builder() -> rusoto_core::ClientBuilder<AccessAnalyzerClient> {
+        rusoto_core::ClientBuilder::new(AccessAnalyzerClient::new_with_client)
+    }
+
+    /// Returns a clone of this client with the overrides of `config` applied to its
+    /// operations, sharing its connections.
+    pub fn customize(&self, config: &rusoto_core::OperationConfig) -> AccessAnalyzerClient {
+        AccessAnalyzerClient {
+            client: self.client.customize(config),
+            region: config.resolve_region(&self.region),
+        }
+    }
 }
 
-#[async_trait]
-impl AccessAnalyzer for AccessAnalyzerClient {
-    /// <p>Creates an analyzer for your account.</p>
-    async fn create_analyzer(
+impl AccessAnalyzerClient {
+    /// Calls `create_analyzer`, also returning the metadata of the response.
+    pub async fn create_analyzer_with_metadata(
         &self,
         input: CreateAnalyzerRequest,
-    ) -> Result<CreateAnalyzerResponse, RusotoError<CreateAnalyzerError>> {
+    ) -> Result<(CreateAnalyzerResponse, ResponseMetadata), RusotoError<CreateAnalyzerError>> {
+        let request = self.build_create_analyzer_request(input);
+
+        let mut response = self
+            .client
+            .sign_and_dispatch(request)
+            .await
+            .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
+        if response.status.as_u16() == 200 {
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<CreateAnalyzerResponse, _>()?;
+
+            Ok((result, metadata))
+        } else {
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(CreateAnalyzerError::from_response(response))
+        }
+    }
+
+    /// Presigns the request `create_analyzer` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_analyzer(
+        &self,
+        presigner: &rusoto_core::Presigner,
+        input: CreateAnalyzerRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_analyzer_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_analyzer_request(&self, input: CreateAnalyzerRequest) -> SignedRequest {
         let request_uri = "/analyzer";
 
         let mut request = SignedRequest::new("PUT", "access-analyzer", &self.region, &request_uri);
+        request.set_operation("CreateAnalyzer");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `create_archive_rule`, also returning the metadata of the response.
+    pub async fn create_archive_rule_with_metadata(
+        &self,
+        input: CreateArchiveRuleRequest,
+    ) -> Result<((), ResponseMetadata), RusotoError<CreateArchiveRuleError>> {
+        let request = self.build_create_archive_rule_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<CreateAnalyzerResponse, _>()?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = ::std::mem::drop(response);
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(CreateAnalyzerError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(CreateArchiveRuleError::from_response(response))
         }
     }
 
-    /// <p>Creates an archive rule for the specified analyzer. Archive rules automatically archive findings that meet the criteria you define when you create the rule.</p>
-    async fn create_archive_rule(
+    /// Presigns the request `create_archive_rule` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_archive_rule(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: CreateArchiveRuleRequest,
-    ) -> Result<(), RusotoError<CreateArchiveRuleError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_archive_rule_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_archive_rule_request(&self, input: CreateArchiveRuleRequest) -> SignedRequest {
         let request_uri = format!(
             "/analyzer/{analyzer_name}/archive-rule",
             analyzer_name = input.analyzer_name
         );
 
         let mut request = SignedRequest::new("PUT", "access-analyzer", &self.region, &request_uri);
+        request.set_operation("CreateArchiveRule");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `delete_analyzer`, also returning the metadata of the response.
+    pub async fn delete_analyzer_with_metadata(
+        &self,
+        input: DeleteAnalyzerRequest,
+    ) -> Result<((), ResponseMetadata), RusotoError<DeleteAnalyzerError>> {
+        let request = self.build_delete_analyzer_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = ::std::mem::drop(response);
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(CreateArchiveRuleError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(DeleteAnalyzerError::from_response(response))
         }
     }
 
-    /// <p>Deletes the specified analyzer. When you delete an analyzer, Access Analyzer is disabled for the account in the current or specific Region. All findings that were generated by the analyzer are deleted. You cannot undo this action.</p>
-    async fn delete_analyzer(
+    /// Presigns the request `delete_analyzer` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_analyzer(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteAnalyzerRequest,
-    ) -> Result<(), RusotoError<DeleteAnalyzerError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_analyzer_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_analyzer_request(&self, input: DeleteAnalyzerRequest) -> SignedRequest {
         let request_uri = format!(
             "/analyzer/{analyzer_name}",
             analyzer_name = input.analyzer_name
@@ -1785,6 +2039,7 @@ impl AccessAnalyzer for AccessAnalyzerClient {
 
         let mut request =
             SignedRequest::new("DELETE", "access-analyzer", &self.region, &request_uri);
+        request.set_operation("DeleteAnalyzer");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -1793,27 +2048,50 @@ impl AccessAnalyzer for AccessAnalyzerClient {
         }
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `delete_archive_rule`, also returning the metadata of the response.
+    pub async fn delete_archive_rule_with_metadata(
+        &self,
+        input: DeleteArchiveRuleRequest,
+    ) -> Result<((), ResponseMetadata), RusotoError<DeleteArchiveRuleError>> {
+        let request = self.build_delete_archive_rule_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = ::std::mem::drop(response);
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteAnalyzerError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(DeleteArchiveRuleError::from_response(response))
         }
     }
 
-    /// <p>Deletes the specified archive rule.</p>
-    async fn delete_archive_rule(
+    /// Presigns the request `delete_archive_rule` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_archive_rule(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteArchiveRuleRequest,
-    ) -> Result<(), RusotoError<DeleteArchiveRuleError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_archive_rule_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_archive_rule_request(&self, input: DeleteArchiveRuleRequest) -> SignedRequest {
         let request_uri = format!(
             "/analyzer/{analyzer_name}/archive-rule/{rule_name}",
             analyzer_name = input.analyzer_name,
@@ -1822,6 +2100,7 @@ impl AccessAnalyzer for AccessAnalyzerClient {
 
         let mut request =
             SignedRequest::new("DELETE", "access-analyzer", &self.region, &request_uri);
+        request.set_operation("DeleteArchiveRule");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -1830,30 +2109,61 @@ impl AccessAnalyzer for AccessAnalyzerClient {
         }
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `get_analyzed_resource`, also returning the metadata of the response.
+    pub async fn get_analyzed_resource_with_metadata(
+        &self,
+        input: GetAnalyzedResourceRequest,
+    ) -> Result<
+        (GetAnalyzedResourceResponse, ResponseMetadata),
+        RusotoError<GetAnalyzedResourceError>,
+    > {
+        let request = self.build_get_analyzed_resource_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            let result = ::std::mem::drop(response);
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<GetAnalyzedResourceResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteArchiveRuleError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(GetAnalyzedResourceError::from_response(response))
         }
     }
 
-    /// <p>Retrieves information about a resource that was analyzed.</p>
-    async fn get_analyzed_resource(
+    /// Presigns the request `get_analyzed_resource` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_analyzed_resource(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetAnalyzedResourceRequest,
-    ) -> Result<GetAnalyzedResourceResponse, RusotoError<GetAnalyzedResourceError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_analyzed_resource_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_analyzed_resource_request(
+        &self,
+        input: GetAnalyzedResourceRequest,
+    ) -> SignedRequest {
         let request_uri = "/analyzed-resource";
 
         let mut request = SignedRequest::new("GET", "access-analyzer", &self.region, &request_uri);
+        request.set_operation("GetAnalyzedResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -1861,58 +2171,105 @@ impl AccessAnalyzer for AccessAnalyzerClient {
         params.put("resourceArn", &input.resource_arn);
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `get_analyzer`, also returning the metadata of the response.
+    pub async fn get_analyzer_with_metadata(
+        &self,
+        input: GetAnalyzerRequest,
+    ) -> Result<(GetAnalyzerResponse, ResponseMetadata), RusotoError<GetAnalyzerError>> {
+        let request = self.build_get_analyzer_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<GetAnalyzedResourceResponse, _>()?;
+                .deserialize::<GetAnalyzerResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(GetAnalyzedResourceError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(GetAnalyzerError::from_response(response))
         }
     }
 
-    /// <p>Retrieves information about the specified analyzer.</p>
-    async fn get_analyzer(
+    /// Presigns the request `get_analyzer` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_analyzer(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetAnalyzerRequest,
-    ) -> Result<GetAnalyzerResponse, RusotoError<GetAnalyzerError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_analyzer_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_analyzer_request(&self, input: GetAnalyzerRequest) -> SignedRequest {
         let request_uri = format!(
             "/analyzer/{analyzer_name}",
             analyzer_name = input.analyzer_name
         );
 
         let mut request = SignedRequest::new("GET", "access-analyzer", &self.region, &request_uri);
+        request.set_operation("GetAnalyzer");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
+        request
+    }
+
+    /// Calls `get_archive_rule`, also returning the metadata of the response.
+    pub async fn get_archive_rule_with_metadata(
+        &self,
+        input: GetArchiveRuleRequest,
+    ) -> Result<(GetArchiveRuleResponse, ResponseMetadata), RusotoError<GetArchiveRuleError>> {
+        let request = self.build_get_archive_rule_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<GetAnalyzerResponse, _>()?;
+                .deserialize::<GetArchiveRuleResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(GetAnalyzerError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(GetArchiveRuleError::from_response(response))
         }
     }
 
-    /// <p>Retrieves information about an archive rule.</p>
-    async fn get_archive_rule(
+    /// Presigns the request `get_archive_rule` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_archive_rule(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetArchiveRuleRequest,
-    ) -> Result<GetArchiveRuleResponse, RusotoError<GetArchiveRuleError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_archive_rule_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_archive_rule_request(&self, input: GetArchiveRuleRequest) -> SignedRequest {
         let request_uri = format!(
             "/analyzer/{analyzer_name}/archive-rule/{rule_name}",
             analyzer_name = input.analyzer_name,
@@ -1920,94 +2277,173 @@ impl AccessAnalyzer for AccessAnalyzerClient {
         );
 
         let mut request = SignedRequest::new("GET", "access-analyzer", &self.region, &request_uri);
+        request.set_operation("GetArchiveRule");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
+        request
+    }
+
+    /// Calls `get_finding`, also returning the metadata of the response.
+    pub async fn get_finding_with_metadata(
+        &self,
+        input: GetFindingRequest,
+    ) -> Result<(GetFindingResponse, ResponseMetadata), RusotoError<GetFindingError>> {
+        let request = self.build_get_finding_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<GetArchiveRuleResponse, _>()?;
+                .deserialize::<GetFindingResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(GetArchiveRuleError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(GetFindingError::from_response(response))
         }
     }
 
-    /// <p>Retrieves information about the specified finding.</p>
-    async fn get_finding(
+    /// Presigns the request `get_finding` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_finding(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetFindingRequest,
-    ) -> Result<GetFindingResponse, RusotoError<GetFindingError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_finding_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_finding_request(&self, input: GetFindingRequest) -> SignedRequest {
         let request_uri = format!("/finding/{id}", id = input.id);
 
         let mut request = SignedRequest::new("GET", "access-analyzer", &self.region, &request_uri);
+        request.set_operation("GetFinding");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
         params.put("analyzerArn", &input.analyzer_arn);
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `list_analyzed_resources`, also returning the metadata of the response.
+    pub async fn list_analyzed_resources_with_metadata(
+        &self,
+        input: ListAnalyzedResourcesRequest,
+    ) -> Result<
+        (ListAnalyzedResourcesResponse, ResponseMetadata),
+        RusotoError<ListAnalyzedResourcesError>,
+    > {
+        let request = self.build_list_analyzed_resources_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<GetFindingResponse, _>()?;
+                .deserialize::<ListAnalyzedResourcesResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(GetFindingError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(ListAnalyzedResourcesError::from_response(response))
         }
     }
 
-    /// <p>Retrieves a list of resources of the specified type that have been analyzed by the specified analyzer..</p>
-    async fn list_analyzed_resources(
+    /// Presigns the request `list_analyzed_resources` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_analyzed_resources(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListAnalyzedResourcesRequest,
-    ) -> Result<ListAnalyzedResourcesResponse, RusotoError<ListAnalyzedResourcesError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_analyzed_resources_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_analyzed_resources_request(
+        &self,
+        input: ListAnalyzedResourcesRequest,
+    ) -> SignedRequest {
         let request_uri = "/analyzed-resource";
 
         let mut request = SignedRequest::new("POST", "access-analyzer", &self.region, &request_uri);
+        request.set_operation("ListAnalyzedResources");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `list_analyzers`, also returning the metadata of the response.
+    pub async fn list_analyzers_with_metadata(
+        &self,
+        input: ListAnalyzersRequest,
+    ) -> Result<(ListAnalyzersResponse, ResponseMetadata), RusotoError<ListAnalyzersError>> {
+        let request = self.build_list_analyzers_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListAnalyzedResourcesResponse, _>()?;
+                .deserialize::<ListAnalyzersResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(ListAnalyzedResourcesError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(ListAnalyzersError::from_response(response))
         }
     }
 
-    /// <p>Retrieves a list of analyzers.</p>
-    async fn list_analyzers(
+    /// Presigns the request `list_analyzers` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_analyzers(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListAnalyzersRequest,
-    ) -> Result<ListAnalyzersResponse, RusotoError<ListAnalyzersError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_analyzers_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_analyzers_request(&self, input: ListAnalyzersRequest) -> SignedRequest {
         let request_uri = "/analyzer";
 
         let mut request = SignedRequest::new("GET", "access-analyzer", &self.region, &request_uri);
+        request.set_operation("ListAnalyzers");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -2022,34 +2458,59 @@ impl AccessAnalyzer for AccessAnalyzerClient {
         }
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `list_archive_rules`, also returning the metadata of the response.
+    pub async fn list_archive_rules_with_metadata(
+        &self,
+        input: ListArchiveRulesRequest,
+    ) -> Result<(ListArchiveRulesResponse, ResponseMetadata), RusotoError<ListArchiveRulesError>>
+    {
+        let request = self.build_list_archive_rules_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListAnalyzersResponse, _>()?;
+                .deserialize::<ListArchiveRulesResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(ListAnalyzersError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(ListArchiveRulesError::from_response(response))
         }
     }
 
-    /// <p>Retrieves a list of archive rules created for the specified analyzer.</p>
-    async fn list_archive_rules(
+    /// Presigns the request `list_archive_rules` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_archive_rules(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListArchiveRulesRequest,
-    ) -> Result<ListArchiveRulesResponse, RusotoError<ListArchiveRulesError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_archive_rules_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_archive_rules_request(&self, input: ListArchiveRulesRequest) -> SignedRequest {
         let request_uri = format!(
             "/analyzer/{analyzer_name}/archive-rule",
             analyzer_name = input.analyzer_name
         );
 
         let mut request = SignedRequest::new("GET", "access-analyzer", &self.region, &request_uri);
+        request.set_operation("ListArchiveRules");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -2061,148 +2522,274 @@ impl AccessAnalyzer for AccessAnalyzerClient {
         }
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `list_findings`, also returning the metadata of the response.
+    pub async fn list_findings_with_metadata(
+        &self,
+        input: ListFindingsRequest,
+    ) -> Result<(ListFindingsResponse, ResponseMetadata), RusotoError<ListFindingsError>> {
+        let request = self.build_list_findings_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListArchiveRulesResponse, _>()?;
+                .deserialize::<ListFindingsResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(ListArchiveRulesError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(ListFindingsError::from_response(response))
         }
     }
 
-    /// <p>Retrieves a list of findings generated by the specified analyzer.</p>
-    async fn list_findings(
+    /// Presigns the request `list_findings` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_findings(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListFindingsRequest,
-    ) -> Result<ListFindingsResponse, RusotoError<ListFindingsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_findings_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_findings_request(&self, input: ListFindingsRequest) -> SignedRequest {
         let request_uri = "/finding";
 
         let mut request = SignedRequest::new("POST", "access-analyzer", &self.region, &request_uri);
+        request.set_operation("ListFindings");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `list_tags_for_resource`, also returning the metadata of the response.
+    pub async fn list_tags_for_resource_with_metadata(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> Result<
+        (ListTagsForResourceResponse, ResponseMetadata),
+        RusotoError<ListTagsForResourceError>,
+    > {
+        let request = self.build_list_tags_for_resource_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListFindingsResponse, _>()?;
+                .deserialize::<ListTagsForResourceResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(ListFindingsError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(ListTagsForResourceError::from_response(response))
         }
     }
 
-    /// <p>Retrieves a list of tags applied to the specified resource.</p>
-    async fn list_tags_for_resource(
+    /// Presigns the request `list_tags_for_resource` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_tags_for_resource(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListTagsForResourceRequest,
-    ) -> Result<ListTagsForResourceResponse, RusotoError<ListTagsForResourceError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_tags_for_resource_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_tags_for_resource_request(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> SignedRequest {
         let request_uri = format!("/tags/{resource_arn}", resource_arn = input.resource_arn);
 
         let mut request = SignedRequest::new("GET", "access-analyzer", &self.region, &request_uri);
+        request.set_operation("ListTagsForResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
+        request
+    }
+
+    /// Calls `start_resource_scan`, also returning the metadata of the response.
+    pub async fn start_resource_scan_with_metadata(
+        &self,
+        input: StartResourceScanRequest,
+    ) -> Result<((), ResponseMetadata), RusotoError<StartResourceScanError>> {
+        let request = self.build_start_resource_scan_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListTagsForResourceResponse, _>()?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = ::std::mem::drop(response);
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(ListTagsForResourceError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(StartResourceScanError::from_response(response))
         }
     }
 
-    /// <p>Immediately starts a scan of the policies applied to the specified resource.</p>
-    async fn start_resource_scan(
+    /// Presigns the request `start_resource_scan` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_start_resource_scan(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: StartResourceScanRequest,
-    ) -> Result<(), RusotoError<StartResourceScanError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_start_resource_scan_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_start_resource_scan_request(&self, input: StartResourceScanRequest) -> SignedRequest {
         let request_uri = "/resource/scan";
 
         let mut request = SignedRequest::new("POST", "access-analyzer", &self.region, &request_uri);
+        request.set_operation("StartResourceScan");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `tag_resource`, also returning the metadata of the response.
+    pub async fn tag_resource_with_metadata(
+        &self,
+        input: TagResourceRequest,
+    ) -> Result<(TagResourceResponse, ResponseMetadata), RusotoError<TagResourceError>> {
+        let request = self.build_tag_resource_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            let result = ::std::mem::drop(response);
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<TagResourceResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(StartResourceScanError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(TagResourceError::from_response(response))
         }
     }
 
-    /// <p>Adds a tag to the specified resource.</p>
-    async fn tag_resource(
+    /// Presigns the request `tag_resource` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_tag_resource(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: TagResourceRequest,
-    ) -> Result<TagResourceResponse, RusotoError<TagResourceError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_tag_resource_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_tag_resource_request(&self, input: TagResourceRequest) -> SignedRequest {
         let request_uri = format!("/tags/{resource_arn}", resource_arn = input.resource_arn);
 
         let mut request = SignedRequest::new("POST", "access-analyzer", &self.region, &request_uri);
+        request.set_operation("TagResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `untag_resource`, also returning the metadata of the response.
+    pub async fn untag_resource_with_metadata(
+        &self,
+        input: UntagResourceRequest,
+    ) -> Result<(UntagResourceResponse, ResponseMetadata), RusotoError<UntagResourceError>> {
+        let request = self.build_untag_resource_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<TagResourceResponse, _>()?;
+                .deserialize::<UntagResourceResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(TagResourceError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(UntagResourceError::from_response(response))
         }
     }
 
-    /// <p>Removes a tag from the specified resource.</p>
-    async fn untag_resource(
+    /// Presigns the request `untag_resource` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_untag_resource(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UntagResourceRequest,
-    ) -> Result<UntagResourceResponse, RusotoError<UntagResourceError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_untag_resource_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_untag_resource_request(&self, input: UntagResourceRequest) -> SignedRequest {
         let request_uri = format!("/tags/{resource_arn}", resource_arn = input.resource_arn);
 
         let mut request =
             SignedRequest::new("DELETE", "access-analyzer", &self.region, &request_uri);
+        request.set_operation("UntagResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -2211,28 +2798,50 @@ impl AccessAnalyzer for AccessAnalyzerClient {
         }
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `update_archive_rule`, also returning the metadata of the response.
+    pub async fn update_archive_rule_with_metadata(
+        &self,
+        input: UpdateArchiveRuleRequest,
+    ) -> Result<((), ResponseMetadata), RusotoError<UpdateArchiveRuleError>> {
+        let request = self.build_update_archive_rule_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<UntagResourceResponse, _>()?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = ::std::mem::drop(response);
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(UntagResourceError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(UpdateArchiveRuleError::from_response(response))
         }
     }
 
-    /// <p>Updates the criteria and values for the specified archive rule.</p>
-    async fn update_archive_rule(
+    /// Presigns the request `update_archive_rule` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_archive_rule(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UpdateArchiveRuleRequest,
-    ) -> Result<(), RusotoError<UpdateArchiveRuleError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_archive_rule_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_archive_rule_request(&self, input: UpdateArchiveRuleRequest) -> SignedRequest {
         let request_uri = format!(
             "/analyzer/{analyzer_name}/archive-rule/{rule_name}",
             analyzer_name = input.analyzer_name,
@@ -2240,53 +2849,393 @@ impl AccessAnalyzer for AccessAnalyzerClient {
         );
 
         let mut request = SignedRequest::new("PUT", "access-analyzer", &self.region, &request_uri);
+        request.set_operation("UpdateArchiveRule");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `update_findings`, also returning the metadata of the response.
+    pub async fn update_findings_with_metadata(
+        &self,
+        input: UpdateFindingsRequest,
+    ) -> Result<((), ResponseMetadata), RusotoError<UpdateFindingsError>> {
+        let request = self.build_update_findings_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = ::std::mem::drop(response);
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(UpdateArchiveRuleError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(UpdateFindingsError::from_response(response))
         }
     }
 
-    /// <p>Updates the status for the specified findings.</p>
-    async fn update_findings(
+    /// Presigns the request `update_findings` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_findings(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UpdateFindingsRequest,
-    ) -> Result<(), RusotoError<UpdateFindingsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_findings_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_findings_request(&self, input: UpdateFindingsRequest) -> SignedRequest {
         let request_uri = "/finding";
 
         let mut request = SignedRequest::new("PUT", "access-analyzer", &self.region, &request_uri);
+        request.set_operation("UpdateFindings");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
-        let mut response = self
-            .client
-            .sign_and_dispatch(request)
+        request
+    }
+
+    /// The pages of `ListAnalyzedResources` results for `input`, requesting each next page with the `nextToken` of the last one as its `nextToken`.
+    /// The page size is the `maxResults` of `input`.
+    pub fn list_analyzed_resources_pages(
+        &self,
+        input: ListAnalyzedResourcesRequest,
+    ) -> impl futures::Stream<
+        Item = Result<ListAnalyzedResourcesResponse, RusotoError<ListAnalyzedResourcesError>>,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: AccessAnalyzerClient, input| async move {
+                client.list_analyzed_resources(input).await
+            },
+            |input: &ListAnalyzedResourcesRequest, output: &ListAnalyzedResourcesResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListAnalyzedResourcesRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `analyzedResources` of every page of `ListAnalyzedResources` results for `input`, as `list_analyzed_resources_pages` requests them.
+    pub fn list_analyzed_resources_stream(
+        &self,
+        input: ListAnalyzedResourcesRequest,
+    ) -> impl futures::Stream<
+        Item = Result<AnalyzedResourceSummary, RusotoError<ListAnalyzedResourcesError>>,
+    > + Send {
+        rusoto_core::pagination::items(self.list_analyzed_resources_pages(input), |page| {
+            page.analyzed_resources
+        })
+    }
+
+    /// The pages of `ListAnalyzers` results for `input`, requesting each next page with the `nextToken` of the last one as its `nextToken`.
+    /// The page size is the `maxResults` of `input`.
+    pub fn list_analyzers_pages(
+        &self,
+        input: ListAnalyzersRequest,
+    ) -> impl futures::Stream<Item = Result<ListAnalyzersResponse, RusotoError<ListAnalyzersError>>> + Send
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: AccessAnalyzerClient, input| async move { client.list_analyzers(input).await },
+            |input: &ListAnalyzersRequest, output: &ListAnalyzersResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListAnalyzersRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `analyzers` of every page of `ListAnalyzers` results for `input`, as `list_analyzers_pages` requests them.
+    pub fn list_analyzers_stream(
+        &self,
+        input: ListAnalyzersRequest,
+    ) -> impl futures::Stream<Item = Result<AnalyzerSummary, RusotoError<ListAnalyzersError>>> + Send
+    {
+        rusoto_core::pagination::items(self.list_analyzers_pages(input), |page| page.analyzers)
+    }
+
+    /// The pages of `ListArchiveRules` results for `input`, requesting each next page with the `nextToken` of the last one as its `nextToken`.
+    /// The page size is the `maxResults` of `input`.
+    pub fn list_archive_rules_pages(
+        &self,
+        input: ListArchiveRulesRequest,
+    ) -> impl futures::Stream<
+        Item = Result<ListArchiveRulesResponse, RusotoError<ListArchiveRulesError>>,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: AccessAnalyzerClient, input| async move { client.list_archive_rules(input).await },
+            |input: &ListArchiveRulesRequest, output: &ListArchiveRulesResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListArchiveRulesRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `archiveRules` of every page of `ListArchiveRules` results for `input`, as `list_archive_rules_pages` requests them.
+    pub fn list_archive_rules_stream(
+        &self,
+        input: ListArchiveRulesRequest,
+    ) -> impl futures::Stream<Item = Result<ArchiveRuleSummary, RusotoError<ListArchiveRulesError>>> + Send
+    {
+        rusoto_core::pagination::items(self.list_archive_rules_pages(input), |page| {
+            page.archive_rules
+        })
+    }
+
+    /// The pages of `ListFindings` results for `input`, requesting each next page with the `nextToken` of the last one as its `nextToken`.
+    /// The page size is the `maxResults` of `input`.
+    pub fn list_findings_pages(
+        &self,
+        input: ListFindingsRequest,
+    ) -> impl futures::Stream<Item = Result<ListFindingsResponse, RusotoError<ListFindingsError>>> + Send
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: AccessAnalyzerClient, input| async move { client.list_findings(input).await },
+            |input: &ListFindingsRequest, output: &ListFindingsResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListFindingsRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `findings` of every page of `ListFindings` results for `input`, as `list_findings_pages` requests them.
+    pub fn list_findings_stream(
+        &self,
+        input: ListFindingsRequest,
+    ) -> impl futures::Stream<Item = Result<FindingSummary, RusotoError<ListFindingsError>>> + Send
+    {
+        rusoto_core::pagination::items(self.list_findings_pages(input), |page| page.findings)
+    }
+}
+
+#[async_trait]
+impl AccessAnalyzer for AccessAnalyzerClient {
+    /// <p>Creates an analyzer for your account.</p>
+    async fn create_analyzer(
+        &self,
+        input: CreateAnalyzerRequest,
+    ) -> Result<CreateAnalyzerResponse, RusotoError<CreateAnalyzerError>> {
+        self.create_analyzer_with_metadata(input)
             .await
-            .map_err(RusotoError::from)?;
-        if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            let result = ::std::mem::drop(response);
+            .map(|(output, _)| output)
+    }
 
-            Ok(result)
-        } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(UpdateFindingsError::from_response(response))
-        }
+    /// <p>Creates an archive rule for the specified analyzer. Archive rules automatically archive findings that meet the criteria you define when you create the rule.</p>
+    async fn create_archive_rule(
+        &self,
+        input: CreateArchiveRuleRequest,
+    ) -> Result<(), RusotoError<CreateArchiveRuleError>> {
+        self.create_archive_rule_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes the specified analyzer. When you delete an analyzer, Access Analyzer is disabled for the account in the current or specific Region. All findings that were generated by the analyzer are deleted. You cannot undo this action.</p>
+    async fn delete_analyzer(
+        &self,
+        input: DeleteAnalyzerRequest,
+    ) -> Result<(), RusotoError<DeleteAnalyzerError>> {
+        self.delete_analyzer_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes the specified archive rule.</p>
+    async fn delete_archive_rule(
+        &self,
+        input: DeleteArchiveRuleRequest,
+    ) -> Result<(), RusotoError<DeleteArchiveRuleError>> {
+        self.delete_archive_rule_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Retrieves information about a resource that was analyzed.</p>
+    async fn get_analyzed_resource(
+        &self,
+        input: GetAnalyzedResourceRequest,
+    ) -> Result<GetAnalyzedResourceResponse, RusotoError<GetAnalyzedResourceError>> {
+        self.get_analyzed_resource_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Retrieves information about the specified analyzer.</p>
+    async fn get_analyzer(
+        &self,
+        input: GetAnalyzerRequest,
+    ) -> Result<GetAnalyzerResponse, RusotoError<GetAnalyzerError>> {
+        self.get_analyzer_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Retrieves information about an archive rule.</p>
+    async fn get_archive_rule(
+        &self,
+        input: GetArchiveRuleRequest,
+    ) -> Result<GetArchiveRuleResponse, RusotoError<GetArchiveRuleError>> {
+        self.get_archive_rule_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Retrieves information about the specified finding.</p>
+    async fn get_finding(
+        &self,
+        input: GetFindingRequest,
+    ) -> Result<GetFindingResponse, RusotoError<GetFindingError>> {
+        self.get_finding_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Retrieves a list of resources of the specified type that have been analyzed by the specified analyzer..</p>
+    async fn list_analyzed_resources(
+        &self,
+        input: ListAnalyzedResourcesRequest,
+    ) -> Result<ListAnalyzedResourcesResponse, RusotoError<ListAnalyzedResourcesError>> {
+        self.list_analyzed_resources_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Retrieves a list of analyzers.</p>
+    async fn list_analyzers(
+        &self,
+        input: ListAnalyzersRequest,
+    ) -> Result<ListAnalyzersResponse, RusotoError<ListAnalyzersError>> {
+        self.list_analyzers_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Retrieves a list of archive rules created for the specified analyzer.</p>
+    async fn list_archive_rules(
+        &self,
+        input: ListArchiveRulesRequest,
+    ) -> Result<ListArchiveRulesResponse, RusotoError<ListArchiveRulesError>> {
+        self.list_archive_rules_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Retrieves a list of findings generated by the specified analyzer.</p>
+    async fn list_findings(
+        &self,
+        input: ListFindingsRequest,
+    ) -> Result<ListFindingsResponse, RusotoError<ListFindingsError>> {
+        self.list_findings_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Retrieves a list of tags applied to the specified resource.</p>
+    async fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> Result<ListTagsForResourceResponse, RusotoError<ListTagsForResourceError>> {
+        self.list_tags_for_resource_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Immediately starts a scan of the policies applied to the specified resource.</p>
+    async fn start_resource_scan(
+        &self,
+        input: StartResourceScanRequest,
+    ) -> Result<(), RusotoError<StartResourceScanError>> {
+        self.start_resource_scan_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Adds a tag to the specified resource.</p>
+    async fn tag_resource(
+        &self,
+        input: TagResourceRequest,
+    ) -> Result<TagResourceResponse, RusotoError<TagResourceError>> {
+        self.tag_resource_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Removes a tag from the specified resource.</p>
+    async fn untag_resource(
+        &self,
+        input: UntagResourceRequest,
+    ) -> Result<UntagResourceResponse, RusotoError<UntagResourceError>> {
+        self.untag_resource_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Updates the criteria and values for the specified archive rule.</p>
+    async fn update_archive_rule(
+        &self,
+        input: UpdateArchiveRuleRequest,
+    ) -> Result<(), RusotoError<UpdateArchiveRuleError>> {
+        self.update_archive_rule_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Updates the status for the specified findings.</p>
+    async fn update_findings(
+        &self,
+        input: UpdateFindingsRequest,
+    ) -> Result<(), RusotoError<UpdateFindingsError>> {
+        self.update_findings_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
     }
 }
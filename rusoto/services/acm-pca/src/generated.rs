@@ -16,8 +16,8 @@ use std::fmt;
 use async_trait::async_trait;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
-use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest, ResponseMetadata};
+use rusoto_core::{Client, ErrorMetadata, RusotoError};
 
 use rusoto_core::proto;
 use rusoto_core::signature::SignedRequest;
@@ -661,6 +661,7 @@ pub struct Validity {
 
 /// Errors returned by CreateCertificateAuthority
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateCertificateAuthorityError {
     /// <p>One or more of the specified arguments was not valid.</p>
     InvalidArgs(String),
@@ -670,6 +671,8 @@ pub enum CreateCertificateAuthorityError {
     InvalidTag(String),
     /// <p>An ACM Private CA limit has been exceeded. See the exception message returned to determine the limit that was exceeded.</p>
     LimitExceeded(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateCertificateAuthorityError {
@@ -679,27 +682,33 @@ impl CreateCertificateAuthorityError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidArgsException" => {
-                    return RusotoError::Service(CreateCertificateAuthorityError::InvalidArgs(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateCertificateAuthorityError::InvalidArgs(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidPolicyException" => {
-                    return RusotoError::Service(CreateCertificateAuthorityError::InvalidPolicy(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateCertificateAuthorityError::InvalidPolicy(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidTagException" => {
-                    return RusotoError::Service(CreateCertificateAuthorityError::InvalidTag(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateCertificateAuthorityError::InvalidTag(err.msg),
+                        &res,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateCertificateAuthorityError::LimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateCertificateAuthorityError::LimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(CreateCertificateAuthorityError::Unhandled, &res)
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -713,12 +722,14 @@ impl fmt::Display for CreateCertificateAuthorityError {
             CreateCertificateAuthorityError::InvalidPolicy(ref cause) => write!(f, "{}", cause),
             CreateCertificateAuthorityError::InvalidTag(ref cause) => write!(f, "{}", cause),
             CreateCertificateAuthorityError::LimitExceeded(ref cause) => write!(f, "{}", cause),
+            CreateCertificateAuthorityError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateCertificateAuthorityError {}
 /// Errors returned by CreateCertificateAuthorityAuditReport
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateCertificateAuthorityAuditReportError {
     /// <p>One or more of the specified arguments was not valid.</p>
     InvalidArgs(String),
@@ -732,6 +743,8 @@ pub enum CreateCertificateAuthorityAuditReportError {
     RequestInProgress(String),
     /// <p>A resource such as a private CA, S3 bucket, certificate, or audit report cannot be found.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateCertificateAuthorityAuditReportError {
@@ -741,37 +754,48 @@ impl CreateCertificateAuthorityAuditReportError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidArgsException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         CreateCertificateAuthorityAuditReportError::InvalidArgs(err.msg),
+                        &res,
                     )
                 }
                 "InvalidArnException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         CreateCertificateAuthorityAuditReportError::InvalidArn(err.msg),
+                        &res,
                     )
                 }
                 "InvalidStateException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         CreateCertificateAuthorityAuditReportError::InvalidState(err.msg),
+                        &res,
                     )
                 }
                 "RequestFailedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         CreateCertificateAuthorityAuditReportError::RequestFailed(err.msg),
+                        &res,
                     )
                 }
                 "RequestInProgressException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         CreateCertificateAuthorityAuditReportError::RequestInProgress(err.msg),
+                        &res,
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         CreateCertificateAuthorityAuditReportError::ResourceNotFound(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        CreateCertificateAuthorityAuditReportError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -799,12 +823,16 @@ impl fmt::Display for CreateCertificateAuthorityAuditReportError {
             CreateCertificateAuthorityAuditReportError::ResourceNotFound(ref cause) => {
                 write!(f, "{}", cause)
             }
+            CreateCertificateAuthorityAuditReportError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for CreateCertificateAuthorityAuditReportError {}
 /// Errors returned by CreatePermission
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreatePermissionError {
     /// <p>The requested Amazon Resource Name (ARN) does not refer to an existing resource.</p>
     InvalidArn(String),
@@ -818,6 +846,8 @@ pub enum CreatePermissionError {
     RequestFailed(String),
     /// <p>A resource such as a private CA, S3 bucket, certificate, or audit report cannot be found.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreatePermissionError {
@@ -825,27 +855,37 @@ impl CreatePermissionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidArnException" => {
-                    return RusotoError::Service(CreatePermissionError::InvalidArn(err.msg))
+                    return RusotoError::service(CreatePermissionError::InvalidArn(err.msg), &res)
                 }
                 "InvalidStateException" => {
-                    return RusotoError::Service(CreatePermissionError::InvalidState(err.msg))
+                    return RusotoError::service(CreatePermissionError::InvalidState(err.msg), &res)
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreatePermissionError::LimitExceeded(err.msg))
+                    return RusotoError::service(
+                        CreatePermissionError::LimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "PermissionAlreadyExistsException" => {
-                    return RusotoError::Service(CreatePermissionError::PermissionAlreadyExists(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreatePermissionError::PermissionAlreadyExists(err.msg),
+                        &res,
+                    )
                 }
                 "RequestFailedException" => {
-                    return RusotoError::Service(CreatePermissionError::RequestFailed(err.msg))
+                    return RusotoError::service(
+                        CreatePermissionError::RequestFailed(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(CreatePermissionError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        CreatePermissionError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreatePermissionError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -861,12 +901,14 @@ impl fmt::Display for CreatePermissionError {
             CreatePermissionError::PermissionAlreadyExists(ref cause) => write!(f, "{}", cause),
             CreatePermissionError::RequestFailed(ref cause) => write!(f, "{}", cause),
             CreatePermissionError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            CreatePermissionError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreatePermissionError {}
 /// Errors returned by DeleteCertificateAuthority
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteCertificateAuthorityError {
     /// <p>A previous update to your private CA is still ongoing.</p>
     ConcurrentModification(String),
@@ -876,6 +918,8 @@ pub enum DeleteCertificateAuthorityError {
     InvalidState(String),
     /// <p>A resource such as a private CA, S3 bucket, certificate, or audit report cannot be found.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteCertificateAuthorityError {
@@ -885,27 +929,33 @@ impl DeleteCertificateAuthorityError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DeleteCertificateAuthorityError::ConcurrentModification(err.msg),
+                        &res,
                     )
                 }
                 "InvalidArnException" => {
-                    return RusotoError::Service(DeleteCertificateAuthorityError::InvalidArn(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteCertificateAuthorityError::InvalidArn(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidStateException" => {
-                    return RusotoError::Service(DeleteCertificateAuthorityError::InvalidState(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteCertificateAuthorityError::InvalidState(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteCertificateAuthorityError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteCertificateAuthorityError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(DeleteCertificateAuthorityError::Unhandled, &res)
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -921,12 +971,14 @@ impl fmt::Display for DeleteCertificateAuthorityError {
             DeleteCertificateAuthorityError::InvalidArn(ref cause) => write!(f, "{}", cause),
             DeleteCertificateAuthorityError::InvalidState(ref cause) => write!(f, "{}", cause),
             DeleteCertificateAuthorityError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            DeleteCertificateAuthorityError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteCertificateAuthorityError {}
 /// Errors returned by DeletePermission
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeletePermissionError {
     /// <p>The requested Amazon Resource Name (ARN) does not refer to an existing resource.</p>
     InvalidArn(String),
@@ -936,6 +988,8 @@ pub enum DeletePermissionError {
     RequestFailed(String),
     /// <p>A resource such as a private CA, S3 bucket, certificate, or audit report cannot be found.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeletePermissionError {
@@ -943,19 +997,25 @@ impl DeletePermissionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidArnException" => {
-                    return RusotoError::Service(DeletePermissionError::InvalidArn(err.msg))
+                    return RusotoError::service(DeletePermissionError::InvalidArn(err.msg), &res)
                 }
                 "InvalidStateException" => {
-                    return RusotoError::Service(DeletePermissionError::InvalidState(err.msg))
+                    return RusotoError::service(DeletePermissionError::InvalidState(err.msg), &res)
                 }
                 "RequestFailedException" => {
-                    return RusotoError::Service(DeletePermissionError::RequestFailed(err.msg))
+                    return RusotoError::service(
+                        DeletePermissionError::RequestFailed(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeletePermissionError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        DeletePermissionError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeletePermissionError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -969,17 +1029,21 @@ impl fmt::Display for DeletePermissionError {
             DeletePermissionError::InvalidState(ref cause) => write!(f, "{}", cause),
             DeletePermissionError::RequestFailed(ref cause) => write!(f, "{}", cause),
             DeletePermissionError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            DeletePermissionError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeletePermissionError {}
 /// Errors returned by DescribeCertificateAuthority
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeCertificateAuthorityError {
     /// <p>The requested Amazon Resource Name (ARN) does not refer to an existing resource.</p>
     InvalidArn(String),
     /// <p>A resource such as a private CA, S3 bucket, certificate, or audit report cannot be found.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeCertificateAuthorityError {
@@ -989,17 +1053,24 @@ impl DescribeCertificateAuthorityError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidArnException" => {
-                    return RusotoError::Service(DescribeCertificateAuthorityError::InvalidArn(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeCertificateAuthorityError::InvalidArn(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DescribeCertificateAuthorityError::ResourceNotFound(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        DescribeCertificateAuthorityError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -1013,12 +1084,14 @@ impl fmt::Display for DescribeCertificateAuthorityError {
             DescribeCertificateAuthorityError::ResourceNotFound(ref cause) => {
                 write!(f, "{}", cause)
             }
+            DescribeCertificateAuthorityError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeCertificateAuthorityError {}
 /// Errors returned by DescribeCertificateAuthorityAuditReport
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeCertificateAuthorityAuditReportError {
     /// <p>One or more of the specified arguments was not valid.</p>
     InvalidArgs(String),
@@ -1026,6 +1099,8 @@ pub enum DescribeCertificateAuthorityAuditReportError {
     InvalidArn(String),
     /// <p>A resource such as a private CA, S3 bucket, certificate, or audit report cannot be found.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeCertificateAuthorityAuditReportError {
@@ -1035,22 +1110,30 @@ impl DescribeCertificateAuthorityAuditReportError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidArgsException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DescribeCertificateAuthorityAuditReportError::InvalidArgs(err.msg),
+                        &res,
                     )
                 }
                 "InvalidArnException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DescribeCertificateAuthorityAuditReportError::InvalidArn(err.msg),
+                        &res,
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DescribeCertificateAuthorityAuditReportError::ResourceNotFound(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        DescribeCertificateAuthorityAuditReportError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -1069,12 +1152,16 @@ impl fmt::Display for DescribeCertificateAuthorityAuditReportError {
             DescribeCertificateAuthorityAuditReportError::ResourceNotFound(ref cause) => {
                 write!(f, "{}", cause)
             }
+            DescribeCertificateAuthorityAuditReportError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for DescribeCertificateAuthorityAuditReportError {}
 /// Errors returned by GetCertificate
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetCertificateError {
     /// <p>The requested Amazon Resource Name (ARN) does not refer to an existing resource.</p>
     InvalidArn(String),
@@ -1086,6 +1173,8 @@ pub enum GetCertificateError {
     RequestInProgress(String),
     /// <p>A resource such as a private CA, S3 bucket, certificate, or audit report cannot be found.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetCertificateError {
@@ -1093,22 +1182,28 @@ impl GetCertificateError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidArnException" => {
-                    return RusotoError::Service(GetCertificateError::InvalidArn(err.msg))
+                    return RusotoError::service(GetCertificateError::InvalidArn(err.msg), &res)
                 }
                 "InvalidStateException" => {
-                    return RusotoError::Service(GetCertificateError::InvalidState(err.msg))
+                    return RusotoError::service(GetCertificateError::InvalidState(err.msg), &res)
                 }
                 "RequestFailedException" => {
-                    return RusotoError::Service(GetCertificateError::RequestFailed(err.msg))
+                    return RusotoError::service(GetCertificateError::RequestFailed(err.msg), &res)
                 }
                 "RequestInProgressException" => {
-                    return RusotoError::Service(GetCertificateError::RequestInProgress(err.msg))
+                    return RusotoError::service(
+                        GetCertificateError::RequestInProgress(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetCertificateError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        GetCertificateError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetCertificateError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1123,12 +1218,14 @@ impl fmt::Display for GetCertificateError {
             GetCertificateError::RequestFailed(ref cause) => write!(f, "{}", cause),
             GetCertificateError::RequestInProgress(ref cause) => write!(f, "{}", cause),
             GetCertificateError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            GetCertificateError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetCertificateError {}
 /// Errors returned by GetCertificateAuthorityCertificate
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetCertificateAuthorityCertificateError {
     /// <p>The requested Amazon Resource Name (ARN) does not refer to an existing resource.</p>
     InvalidArn(String),
@@ -1136,6 +1233,8 @@ pub enum GetCertificateAuthorityCertificateError {
     InvalidState(String),
     /// <p>A resource such as a private CA, S3 bucket, certificate, or audit report cannot be found.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetCertificateAuthorityCertificateError {
@@ -1145,22 +1244,30 @@ impl GetCertificateAuthorityCertificateError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidArnException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         GetCertificateAuthorityCertificateError::InvalidArn(err.msg),
+                        &res,
                     )
                 }
                 "InvalidStateException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         GetCertificateAuthorityCertificateError::InvalidState(err.msg),
+                        &res,
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         GetCertificateAuthorityCertificateError::ResourceNotFound(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        GetCertificateAuthorityCertificateError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -1179,12 +1286,16 @@ impl fmt::Display for GetCertificateAuthorityCertificateError {
             GetCertificateAuthorityCertificateError::ResourceNotFound(ref cause) => {
                 write!(f, "{}", cause)
             }
+            GetCertificateAuthorityCertificateError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for GetCertificateAuthorityCertificateError {}
 /// Errors returned by GetCertificateAuthorityCsr
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetCertificateAuthorityCsrError {
     /// <p>The requested Amazon Resource Name (ARN) does not refer to an existing resource.</p>
     InvalidArn(String),
@@ -1196,6 +1307,8 @@ pub enum GetCertificateAuthorityCsrError {
     RequestInProgress(String),
     /// <p>A resource such as a private CA, S3 bucket, certificate, or audit report cannot be found.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetCertificateAuthorityCsrError {
@@ -1205,32 +1318,39 @@ impl GetCertificateAuthorityCsrError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidArnException" => {
-                    return RusotoError::Service(GetCertificateAuthorityCsrError::InvalidArn(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetCertificateAuthorityCsrError::InvalidArn(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidStateException" => {
-                    return RusotoError::Service(GetCertificateAuthorityCsrError::InvalidState(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetCertificateAuthorityCsrError::InvalidState(err.msg),
+                        &res,
+                    )
                 }
                 "RequestFailedException" => {
-                    return RusotoError::Service(GetCertificateAuthorityCsrError::RequestFailed(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetCertificateAuthorityCsrError::RequestFailed(err.msg),
+                        &res,
+                    )
                 }
                 "RequestInProgressException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         GetCertificateAuthorityCsrError::RequestInProgress(err.msg),
+                        &res,
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetCertificateAuthorityCsrError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetCertificateAuthorityCsrError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(GetCertificateAuthorityCsrError::Unhandled, &res)
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -1245,12 +1365,14 @@ impl fmt::Display for GetCertificateAuthorityCsrError {
             GetCertificateAuthorityCsrError::RequestFailed(ref cause) => write!(f, "{}", cause),
             GetCertificateAuthorityCsrError::RequestInProgress(ref cause) => write!(f, "{}", cause),
             GetCertificateAuthorityCsrError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            GetCertificateAuthorityCsrError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetCertificateAuthorityCsrError {}
 /// Errors returned by ImportCertificateAuthorityCertificate
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ImportCertificateAuthorityCertificateError {
     /// <p>The certificate authority certificate you are importing does not comply with conditions specified in the certificate that signed it.</p>
     CertificateMismatch(String),
@@ -1270,6 +1392,8 @@ pub enum ImportCertificateAuthorityCertificateError {
     RequestInProgress(String),
     /// <p>A resource such as a private CA, S3 bucket, certificate, or audit report cannot be found.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ImportCertificateAuthorityCertificateError {
@@ -1279,52 +1403,66 @@ impl ImportCertificateAuthorityCertificateError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "CertificateMismatchException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         ImportCertificateAuthorityCertificateError::CertificateMismatch(err.msg),
+                        &res,
                     )
                 }
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         ImportCertificateAuthorityCertificateError::ConcurrentModification(err.msg),
+                        &res,
                     )
                 }
                 "InvalidArnException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         ImportCertificateAuthorityCertificateError::InvalidArn(err.msg),
+                        &res,
                     )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         ImportCertificateAuthorityCertificateError::InvalidRequest(err.msg),
+                        &res,
                     )
                 }
                 "InvalidStateException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         ImportCertificateAuthorityCertificateError::InvalidState(err.msg),
+                        &res,
                     )
                 }
                 "MalformedCertificateException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         ImportCertificateAuthorityCertificateError::MalformedCertificate(err.msg),
+                        &res,
                     )
                 }
                 "RequestFailedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         ImportCertificateAuthorityCertificateError::RequestFailed(err.msg),
+                        &res,
                     )
                 }
                 "RequestInProgressException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         ImportCertificateAuthorityCertificateError::RequestInProgress(err.msg),
+                        &res,
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         ImportCertificateAuthorityCertificateError::ResourceNotFound(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        ImportCertificateAuthorityCertificateError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -1361,12 +1499,16 @@ impl fmt::Display for ImportCertificateAuthorityCertificateError {
             ImportCertificateAuthorityCertificateError::ResourceNotFound(ref cause) => {
                 write!(f, "{}", cause)
             }
+            ImportCertificateAuthorityCertificateError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for ImportCertificateAuthorityCertificateError {}
 /// Errors returned by IssueCertificate
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum IssueCertificateError {
     /// <p>One or more of the specified arguments was not valid.</p>
     InvalidArgs(String),
@@ -1380,6 +1522,8 @@ pub enum IssueCertificateError {
     MalformedCSR(String),
     /// <p>A resource such as a private CA, S3 bucket, certificate, or audit report cannot be found.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl IssueCertificateError {
@@ -1387,25 +1531,31 @@ impl IssueCertificateError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidArgsException" => {
-                    return RusotoError::Service(IssueCertificateError::InvalidArgs(err.msg))
+                    return RusotoError::service(IssueCertificateError::InvalidArgs(err.msg), &res)
                 }
                 "InvalidArnException" => {
-                    return RusotoError::Service(IssueCertificateError::InvalidArn(err.msg))
+                    return RusotoError::service(IssueCertificateError::InvalidArn(err.msg), &res)
                 }
                 "InvalidStateException" => {
-                    return RusotoError::Service(IssueCertificateError::InvalidState(err.msg))
+                    return RusotoError::service(IssueCertificateError::InvalidState(err.msg), &res)
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(IssueCertificateError::LimitExceeded(err.msg))
+                    return RusotoError::service(
+                        IssueCertificateError::LimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "MalformedCSRException" => {
-                    return RusotoError::Service(IssueCertificateError::MalformedCSR(err.msg))
+                    return RusotoError::service(IssueCertificateError::MalformedCSR(err.msg), &res)
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(IssueCertificateError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        IssueCertificateError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(IssueCertificateError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1421,15 +1571,19 @@ impl fmt::Display for IssueCertificateError {
             IssueCertificateError::LimitExceeded(ref cause) => write!(f, "{}", cause),
             IssueCertificateError::MalformedCSR(ref cause) => write!(f, "{}", cause),
             IssueCertificateError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            IssueCertificateError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for IssueCertificateError {}
 /// Errors returned by ListCertificateAuthorities
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListCertificateAuthoritiesError {
     /// <p>The token specified in the <code>NextToken</code> argument is not valid. Use the token returned from your previous call to <a>ListCertificateAuthorities</a>.</p>
     InvalidNextToken(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListCertificateAuthoritiesError {
@@ -1439,12 +1593,15 @@ impl ListCertificateAuthoritiesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(ListCertificateAuthoritiesError::InvalidNextToken(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListCertificateAuthoritiesError::InvalidNextToken(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(ListCertificateAuthoritiesError::Unhandled, &res)
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -1455,12 +1612,14 @@ impl fmt::Display for ListCertificateAuthoritiesError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             ListCertificateAuthoritiesError::InvalidNextToken(ref cause) => write!(f, "{}", cause),
+            ListCertificateAuthoritiesError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListCertificateAuthoritiesError {}
 /// Errors returned by ListPermissions
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListPermissionsError {
     /// <p>The requested Amazon Resource Name (ARN) does not refer to an existing resource.</p>
     InvalidArn(String),
@@ -1472,6 +1631,8 @@ pub enum ListPermissionsError {
     RequestFailed(String),
     /// <p>A resource such as a private CA, S3 bucket, certificate, or audit report cannot be found.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListPermissionsError {
@@ -1479,22 +1640,28 @@ impl ListPermissionsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidArnException" => {
-                    return RusotoError::Service(ListPermissionsError::InvalidArn(err.msg))
+                    return RusotoError::service(ListPermissionsError::InvalidArn(err.msg), &res)
                 }
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(ListPermissionsError::InvalidNextToken(err.msg))
+                    return RusotoError::service(
+                        ListPermissionsError::InvalidNextToken(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidStateException" => {
-                    return RusotoError::Service(ListPermissionsError::InvalidState(err.msg))
+                    return RusotoError::service(ListPermissionsError::InvalidState(err.msg), &res)
                 }
                 "RequestFailedException" => {
-                    return RusotoError::Service(ListPermissionsError::RequestFailed(err.msg))
+                    return RusotoError::service(ListPermissionsError::RequestFailed(err.msg), &res)
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListPermissionsError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        ListPermissionsError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListPermissionsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1509,12 +1676,14 @@ impl fmt::Display for ListPermissionsError {
             ListPermissionsError::InvalidState(ref cause) => write!(f, "{}", cause),
             ListPermissionsError::RequestFailed(ref cause) => write!(f, "{}", cause),
             ListPermissionsError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            ListPermissionsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListPermissionsError {}
 /// Errors returned by ListTags
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListTagsError {
     /// <p>The requested Amazon Resource Name (ARN) does not refer to an existing resource.</p>
     InvalidArn(String),
@@ -1522,6 +1691,8 @@ pub enum ListTagsError {
     InvalidState(String),
     /// <p>A resource such as a private CA, S3 bucket, certificate, or audit report cannot be found.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListTagsError {
@@ -1529,16 +1700,16 @@ impl ListTagsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidArnException" => {
-                    return RusotoError::Service(ListTagsError::InvalidArn(err.msg))
+                    return RusotoError::service(ListTagsError::InvalidArn(err.msg), &res)
                 }
                 "InvalidStateException" => {
-                    return RusotoError::Service(ListTagsError::InvalidState(err.msg))
+                    return RusotoError::service(ListTagsError::InvalidState(err.msg), &res)
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListTagsError::ResourceNotFound(err.msg))
+                    return RusotoError::service(ListTagsError::ResourceNotFound(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListTagsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1551,12 +1722,14 @@ impl fmt::Display for ListTagsError {
             ListTagsError::InvalidArn(ref cause) => write!(f, "{}", cause),
             ListTagsError::InvalidState(ref cause) => write!(f, "{}", cause),
             ListTagsError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            ListTagsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListTagsError {}
 /// Errors returned by RestoreCertificateAuthority
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum RestoreCertificateAuthorityError {
     /// <p>The requested Amazon Resource Name (ARN) does not refer to an existing resource.</p>
     InvalidArn(String),
@@ -1564,6 +1737,8 @@ pub enum RestoreCertificateAuthorityError {
     InvalidState(String),
     /// <p>A resource such as a private CA, S3 bucket, certificate, or audit report cannot be found.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl RestoreCertificateAuthorityError {
@@ -1573,22 +1748,30 @@ impl RestoreCertificateAuthorityError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidArnException" => {
-                    return RusotoError::Service(RestoreCertificateAuthorityError::InvalidArn(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        RestoreCertificateAuthorityError::InvalidArn(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidStateException" => {
-                    return RusotoError::Service(RestoreCertificateAuthorityError::InvalidState(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        RestoreCertificateAuthorityError::InvalidState(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         RestoreCertificateAuthorityError::ResourceNotFound(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        RestoreCertificateAuthorityError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -1601,12 +1784,14 @@ impl fmt::Display for RestoreCertificateAuthorityError {
             RestoreCertificateAuthorityError::InvalidArn(ref cause) => write!(f, "{}", cause),
             RestoreCertificateAuthorityError::InvalidState(ref cause) => write!(f, "{}", cause),
             RestoreCertificateAuthorityError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            RestoreCertificateAuthorityError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for RestoreCertificateAuthorityError {}
 /// Errors returned by RevokeCertificate
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum RevokeCertificateError {
     /// <p>A previous update to your private CA is still ongoing.</p>
     ConcurrentModification(String),
@@ -1626,6 +1811,8 @@ pub enum RevokeCertificateError {
     RequestInProgress(String),
     /// <p>A resource such as a private CA, S3 bucket, certificate, or audit report cannot be found.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl RevokeCertificateError {
@@ -1633,38 +1820,58 @@ impl RevokeCertificateError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(RevokeCertificateError::ConcurrentModification(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        RevokeCertificateError::ConcurrentModification(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidArnException" => {
-                    return RusotoError::Service(RevokeCertificateError::InvalidArn(err.msg))
+                    return RusotoError::service(RevokeCertificateError::InvalidArn(err.msg), &res)
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(RevokeCertificateError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        RevokeCertificateError::InvalidRequest(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidStateException" => {
-                    return RusotoError::Service(RevokeCertificateError::InvalidState(err.msg))
+                    return RusotoError::service(
+                        RevokeCertificateError::InvalidState(err.msg),
+                        &res,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(RevokeCertificateError::LimitExceeded(err.msg))
+                    return RusotoError::service(
+                        RevokeCertificateError::LimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "RequestAlreadyProcessedException" => {
-                    return RusotoError::Service(RevokeCertificateError::RequestAlreadyProcessed(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        RevokeCertificateError::RequestAlreadyProcessed(err.msg),
+                        &res,
+                    )
                 }
                 "RequestFailedException" => {
-                    return RusotoError::Service(RevokeCertificateError::RequestFailed(err.msg))
+                    return RusotoError::service(
+                        RevokeCertificateError::RequestFailed(err.msg),
+                        &res,
+                    )
                 }
                 "RequestInProgressException" => {
-                    return RusotoError::Service(RevokeCertificateError::RequestInProgress(err.msg))
+                    return RusotoError::service(
+                        RevokeCertificateError::RequestInProgress(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(RevokeCertificateError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        RevokeCertificateError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(RevokeCertificateError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1683,12 +1890,14 @@ impl fmt::Display for RevokeCertificateError {
             RevokeCertificateError::RequestFailed(ref cause) => write!(f, "{}", cause),
             RevokeCertificateError::RequestInProgress(ref cause) => write!(f, "{}", cause),
             RevokeCertificateError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            RevokeCertificateError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for RevokeCertificateError {}
 /// Errors returned by TagCertificateAuthority
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum TagCertificateAuthorityError {
     /// <p>The requested Amazon Resource Name (ARN) does not refer to an existing resource.</p>
     InvalidArn(String),
@@ -1700,6 +1909,8 @@ pub enum TagCertificateAuthorityError {
     ResourceNotFound(String),
     /// <p>You can associate up to 50 tags with a private CA. Exception information is contained in the exception message field.</p>
     TooManyTags(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl TagCertificateAuthorityError {
@@ -1707,26 +1918,37 @@ impl TagCertificateAuthorityError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidArnException" => {
-                    return RusotoError::Service(TagCertificateAuthorityError::InvalidArn(err.msg))
+                    return RusotoError::service(
+                        TagCertificateAuthorityError::InvalidArn(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidStateException" => {
-                    return RusotoError::Service(TagCertificateAuthorityError::InvalidState(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        TagCertificateAuthorityError::InvalidState(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidTagException" => {
-                    return RusotoError::Service(TagCertificateAuthorityError::InvalidTag(err.msg))
+                    return RusotoError::service(
+                        TagCertificateAuthorityError::InvalidTag(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(TagCertificateAuthorityError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        TagCertificateAuthorityError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "TooManyTagsException" => {
-                    return RusotoError::Service(TagCertificateAuthorityError::TooManyTags(err.msg))
+                    return RusotoError::service(
+                        TagCertificateAuthorityError::TooManyTags(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(TagCertificateAuthorityError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1741,12 +1963,14 @@ impl fmt::Display for TagCertificateAuthorityError {
             TagCertificateAuthorityError::InvalidTag(ref cause) => write!(f, "{}", cause),
             TagCertificateAuthorityError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             TagCertificateAuthorityError::TooManyTags(ref cause) => write!(f, "{}", cause),
+            TagCertificateAuthorityError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for TagCertificateAuthorityError {}
 /// Errors returned by UntagCertificateAuthority
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UntagCertificateAuthorityError {
     /// <p>The requested Amazon Resource Name (ARN) does not refer to an existing resource.</p>
     InvalidArn(String),
@@ -1756,6 +1980,8 @@ pub enum UntagCertificateAuthorityError {
     InvalidTag(String),
     /// <p>A resource such as a private CA, S3 bucket, certificate, or audit report cannot be found.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UntagCertificateAuthorityError {
@@ -1763,27 +1989,33 @@ impl UntagCertificateAuthorityError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidArnException" => {
-                    return RusotoError::Service(UntagCertificateAuthorityError::InvalidArn(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UntagCertificateAuthorityError::InvalidArn(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidStateException" => {
-                    return RusotoError::Service(UntagCertificateAuthorityError::InvalidState(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UntagCertificateAuthorityError::InvalidState(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidTagException" => {
-                    return RusotoError::Service(UntagCertificateAuthorityError::InvalidTag(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UntagCertificateAuthorityError::InvalidTag(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UntagCertificateAuthorityError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UntagCertificateAuthorityError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(UntagCertificateAuthorityError::Unhandled, &res)
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -1797,12 +2029,14 @@ impl fmt::Display for UntagCertificateAuthorityError {
             UntagCertificateAuthorityError::InvalidState(ref cause) => write!(f, "{}", cause),
             UntagCertificateAuthorityError::InvalidTag(ref cause) => write!(f, "{}", cause),
             UntagCertificateAuthorityError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            UntagCertificateAuthorityError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for UntagCertificateAuthorityError {}
 /// Errors returned by UpdateCertificateAuthority
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdateCertificateAuthorityError {
     /// <p>A previous update to your private CA is still ongoing.</p>
     ConcurrentModification(String),
@@ -1816,6 +2050,8 @@ pub enum UpdateCertificateAuthorityError {
     InvalidState(String),
     /// <p>A resource such as a private CA, S3 bucket, certificate, or audit report cannot be found.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdateCertificateAuthorityError {
@@ -1825,37 +2061,45 @@ impl UpdateCertificateAuthorityError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         UpdateCertificateAuthorityError::ConcurrentModification(err.msg),
+                        &res,
                     )
                 }
                 "InvalidArgsException" => {
-                    return RusotoError::Service(UpdateCertificateAuthorityError::InvalidArgs(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateCertificateAuthorityError::InvalidArgs(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidArnException" => {
-                    return RusotoError::Service(UpdateCertificateAuthorityError::InvalidArn(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateCertificateAuthorityError::InvalidArn(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidPolicyException" => {
-                    return RusotoError::Service(UpdateCertificateAuthorityError::InvalidPolicy(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateCertificateAuthorityError::InvalidPolicy(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidStateException" => {
-                    return RusotoError::Service(UpdateCertificateAuthorityError::InvalidState(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateCertificateAuthorityError::InvalidState(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateCertificateAuthorityError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateCertificateAuthorityError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(UpdateCertificateAuthorityError::Unhandled, &res)
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -1873,6 +2117,7 @@ impl fmt::Display for UpdateCertificateAuthorityError {
             UpdateCertificateAuthorityError::InvalidPolicy(ref cause) => write!(f, "{}", cause),
             UpdateCertificateAuthorityError::InvalidState(ref cause) => write!(f, "{}", cause),
             UpdateCertificateAuthorityError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            UpdateCertificateAuthorityError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
@@ -2020,6 +2265,7 @@ impl AcmPcaClient {
     /// Creates a client backed by the default tokio event loop.
     ///
     /// The client will use the default credentials provider and tls client.
+    #[cfg(not(target_arch = "wasm32"))]
     pub fn new(region: region::Region) -> AcmPcaClient {
         AcmPcaClient {
             client: Client::shared(),
@@ -2045,48 +2291,136 @@ impl AcmPcaClient {
     pub fn new_with_client(client: Client, region: region::Region) -> AcmPcaClient {
         AcmPcaClient { client, region }
     }
+
+    /// Returns a builder of a client, setting any of its region, credentials provider,
+    /// dispatcher, retry policy, endpoint and timeouts.
+    pub fn builder() -> rusoto_core::ClientBuilder<AcmPcaClient> {
+        rusoto_core::ClientBuilder::new(AcmPcaClient::new_with_client)
+    }
+
+    /// Returns a clone of this client with the overrides of `config` applied to its
+    /// operations, sharing its connections.
+    pub fn customize(&self, config: &rusoto_core::OperationConfig) -> AcmPcaClient {
+        AcmPcaClient {
+            client: self.client.customize(config),
+            region: config.resolve_region(&self.region),
+        }
+    }
 }
 
-#[async_trait]
-impl AcmPca for AcmPcaClient {
-    /// <p>Creates a root or subordinate private certificate authority (CA). You must specify the CA configuration, the certificate revocation list (CRL) configuration, the CA type, and an optional idempotency token to avoid accidental creation of multiple CAs. The CA configuration specifies the name of the algorithm and key size to be used to create the CA private key, the type of signing algorithm that the CA uses, and X.500 subject information. The CRL configuration specifies the CRL expiration period in days (the validity period of the CRL), the Amazon S3 bucket that will contain the CRL, and a CNAME alias for the S3 bucket that is included in certificates issued by the CA. If successful, this action returns the Amazon Resource Name (ARN) of the CA.</p>
-    async fn create_certificate_authority(
+impl AcmPcaClient {
+    /// Calls `create_certificate_authority`, also returning the metadata of the response.
+    pub async fn create_certificate_authority_with_metadata(
         &self,
         input: CreateCertificateAuthorityRequest,
-    ) -> Result<CreateCertificateAuthorityResponse, RusotoError<CreateCertificateAuthorityError>>
-    {
+    ) -> Result<
+        (CreateCertificateAuthorityResponse, ResponseMetadata),
+        RusotoError<CreateCertificateAuthorityError>,
+    > {
+        let request = self.build_create_certificate_authority_request(input);
+
+        let mut response = self
+            .client
+            .sign_and_dispatch(request)
+            .await
+            .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
+        if response.status.is_success() {
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<CreateCertificateAuthorityResponse, _>()?;
+            Ok((result, metadata))
+        } else {
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(CreateCertificateAuthorityError::from_response(response))
+        }
+    }
+
+    /// Presigns the request `create_certificate_authority` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_certificate_authority(
+        &self,
+        presigner: &rusoto_core::Presigner,
+        input: CreateCertificateAuthorityRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_certificate_authority_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_certificate_authority_request(
+        &self,
+        input: CreateCertificateAuthorityRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "acm-pca", &self.region, "/");
+        request.set_operation("CreateCertificateAuthority");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "ACMPrivateCA.CreateCertificateAuthority");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `create_certificate_authority_audit_report`, also returning the metadata of the response.
+    pub async fn create_certificate_authority_audit_report_with_metadata(
+        &self,
+        input: CreateCertificateAuthorityAuditReportRequest,
+    ) -> Result<
+        (
+            CreateCertificateAuthorityAuditReportResponse,
+            ResponseMetadata,
+        ),
+        RusotoError<CreateCertificateAuthorityAuditReportError>,
+    > {
+        let request = self.build_create_certificate_authority_audit_report_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<CreateCertificateAuthorityResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<CreateCertificateAuthorityAuditReportResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(CreateCertificateAuthorityError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(CreateCertificateAuthorityAuditReportError::from_response(
+                response,
+            ))
         }
     }
 
-    /// <p>Creates an audit report that lists every time that your CA private key is used. The report is saved in the Amazon S3 bucket that you specify on input. The <a>IssueCertificate</a> and <a>RevokeCertificate</a> actions use the private key.</p>
-    async fn create_certificate_authority_audit_report(
+    /// Presigns the request `create_certificate_authority_audit_report` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_certificate_authority_audit_report(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: CreateCertificateAuthorityAuditReportRequest,
-    ) -> Result<
-        CreateCertificateAuthorityAuditReportResponse,
-        RusotoError<CreateCertificateAuthorityAuditReportError>,
-    > {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_certificate_authority_audit_report_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_certificate_authority_audit_report_request(
+        &self,
+        input: CreateCertificateAuthorityAuditReportRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "acm-pca", &self.region, "/");
+        request.set_operation("CreateCertificateAuthorityAuditReport");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -2096,143 +2430,265 @@ impl AcmPca for AcmPcaClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `create_permission`, also returning the metadata of the response.
+    pub async fn create_permission_with_metadata(
+        &self,
+        input: CreatePermissionRequest,
+    ) -> Result<((), ResponseMetadata), RusotoError<CreatePermissionError>> {
+        let request = self.build_create_permission_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<CreateCertificateAuthorityAuditReportResponse, _>()
+            std::mem::drop(response);
+            Ok(((), metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(CreateCertificateAuthorityAuditReportError::from_response(
-                response,
-            ))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(CreatePermissionError::from_response(response))
         }
     }
 
-    /// <p>Assigns permissions from a private CA to a designated AWS service. Services are specified by their service principals and can be given permission to create and retrieve certificates on a private CA. Services can also be given permission to list the active permissions that the private CA has granted. For ACM to automatically renew your private CA's certificates, you must assign all possible permissions from the CA to the ACM service principal.</p> <p>At this time, you can only assign permissions to ACM (<code>acm.amazonaws.com</code>). Permissions can be revoked with the <a>DeletePermission</a> action and listed with the <a>ListPermissions</a> action.</p>
-    async fn create_permission(
+    /// Presigns the request `create_permission` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_permission(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: CreatePermissionRequest,
-    ) -> Result<(), RusotoError<CreatePermissionError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_permission_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_permission_request(&self, input: CreatePermissionRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "acm-pca", &self.region, "/");
+        request.set_operation("CreatePermission");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "ACMPrivateCA.CreatePermission");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `delete_certificate_authority`, also returning the metadata of the response.
+    pub async fn delete_certificate_authority_with_metadata(
+        &self,
+        input: DeleteCertificateAuthorityRequest,
+    ) -> Result<((), ResponseMetadata), RusotoError<DeleteCertificateAuthorityError>> {
+        let request = self.build_delete_certificate_authority_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
             std::mem::drop(response);
-            Ok(())
+            Ok(((), metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(CreatePermissionError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DeleteCertificateAuthorityError::from_response(response))
         }
     }
 
-    /// <p>Deletes a private certificate authority (CA). You must provide the Amazon Resource Name (ARN) of the private CA that you want to delete. You can find the ARN by calling the <a>ListCertificateAuthorities</a> action. </p> <note> <p>Deleting a CA will invalidate other CAs and certificates below it in your CA hierarchy.</p> </note> <p>Before you can delete a CA that you have created and activated, you must disable it. To do this, call the <a>UpdateCertificateAuthority</a> action and set the <b>CertificateAuthorityStatus</b> parameter to <code>DISABLED</code>. </p> <p>Additionally, you can delete a CA if you are waiting for it to be created (that is, the status of the CA is <code>CREATING</code>). You can also delete it if the CA has been created but you haven't yet imported the signed certificate into ACM Private CA (that is, the status of the CA is <code>PENDING_CERTIFICATE</code>). </p> <p>When you successfully call <a>DeleteCertificateAuthority</a>, the CA's status changes to <code>DELETED</code>. However, the CA won't be permanently deleted until the restoration period has passed. By default, if you do not set the <code>PermanentDeletionTimeInDays</code> parameter, the CA remains restorable for 30 days. You can set the parameter from 7 to 30 days. The <a>DescribeCertificateAuthority</a> action returns the time remaining in the restoration window of a private CA in the <code>DELETED</code> state. To restore an eligible CA, call the <a>RestoreCertificateAuthority</a> action.</p>
-    async fn delete_certificate_authority(
+    /// Presigns the request `delete_certificate_authority` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_certificate_authority(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteCertificateAuthorityRequest,
-    ) -> Result<(), RusotoError<DeleteCertificateAuthorityError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_certificate_authority_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_certificate_authority_request(
+        &self,
+        input: DeleteCertificateAuthorityRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "acm-pca", &self.region, "/");
+        request.set_operation("DeleteCertificateAuthority");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "ACMPrivateCA.DeleteCertificateAuthority");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `delete_permission`, also returning the metadata of the response.
+    pub async fn delete_permission_with_metadata(
+        &self,
+        input: DeletePermissionRequest,
+    ) -> Result<((), ResponseMetadata), RusotoError<DeletePermissionError>> {
+        let request = self.build_delete_permission_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
             std::mem::drop(response);
-            Ok(())
+            Ok(((), metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteCertificateAuthorityError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DeletePermissionError::from_response(response))
         }
     }
 
-    /// <p>Revokes permissions that a private CA assigned to a designated AWS service. Permissions can be created with the <a>CreatePermission</a> action and listed with the <a>ListPermissions</a> action. </p>
-    async fn delete_permission(
+    /// Presigns the request `delete_permission` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_permission(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeletePermissionRequest,
-    ) -> Result<(), RusotoError<DeletePermissionError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_permission_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_permission_request(&self, input: DeletePermissionRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "acm-pca", &self.region, "/");
+        request.set_operation("DeletePermission");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "ACMPrivateCA.DeletePermission");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `describe_certificate_authority`, also returning the metadata of the response.
+    pub async fn describe_certificate_authority_with_metadata(
+        &self,
+        input: DescribeCertificateAuthorityRequest,
+    ) -> Result<
+        (DescribeCertificateAuthorityResponse, ResponseMetadata),
+        RusotoError<DescribeCertificateAuthorityError>,
+    > {
+        let request = self.build_describe_certificate_authority_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            std::mem::drop(response);
-            Ok(())
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DescribeCertificateAuthorityResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DeletePermissionError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DescribeCertificateAuthorityError::from_response(response))
         }
     }
 
-    /// <p><p>Lists information about your private certificate authority (CA). You specify the private CA on input by its ARN (Amazon Resource Name). The output contains the status of your CA. This can be any of the following: </p> <ul> <li> <p> <code>CREATING</code> - ACM Private CA is creating your private certificate authority.</p> </li> <li> <p> <code>PENDING_CERTIFICATE</code> - The certificate is pending. You must use your ACM Private CA-hosted or on-premises root or subordinate CA to sign your private CA CSR and then import it into PCA. </p> </li> <li> <p> <code>ACTIVE</code> - Your private CA is active.</p> </li> <li> <p> <code>DISABLED</code> - Your private CA has been disabled.</p> </li> <li> <p> <code>EXPIRED</code> - Your private CA certificate has expired.</p> </li> <li> <p> <code>FAILED</code> - Your private CA has failed. Your CA can fail because of problems such a network outage or backend AWS failure or other errors. A failed CA can never return to the pending state. You must create a new CA. </p> </li> <li> <p> <code>DELETED</code> - Your private CA is within the restoration period, after which it is permanently deleted. The length of time remaining in the CA&#39;s restoration period is also included in this action&#39;s output.</p> </li> </ul></p>
-    async fn describe_certificate_authority(
+    /// Presigns the request `describe_certificate_authority` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_certificate_authority(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeCertificateAuthorityRequest,
-    ) -> Result<DescribeCertificateAuthorityResponse, RusotoError<DescribeCertificateAuthorityError>>
-    {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_certificate_authority_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_certificate_authority_request(
+        &self,
+        input: DescribeCertificateAuthorityRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "acm-pca", &self.region, "/");
+        request.set_operation("DescribeCertificateAuthority");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "ACMPrivateCA.DescribeCertificateAuthority");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `describe_certificate_authority_audit_report`, also returning the metadata of the response.
+    pub async fn describe_certificate_authority_audit_report_with_metadata(
+        &self,
+        input: DescribeCertificateAuthorityAuditReportRequest,
+    ) -> Result<
+        (
+            DescribeCertificateAuthorityAuditReportResponse,
+            ResponseMetadata,
+        ),
+        RusotoError<DescribeCertificateAuthorityAuditReportError>,
+    > {
+        let request = self.build_describe_certificate_authority_audit_report_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DescribeCertificateAuthorityResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DescribeCertificateAuthorityAuditReportResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeCertificateAuthorityError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DescribeCertificateAuthorityAuditReportError::from_response(
+                response,
+            ))
         }
     }
 
-    /// <p>Lists information about a specific audit report created by calling the <a>CreateCertificateAuthorityAuditReport</a> action. Audit information is created every time the certificate authority (CA) private key is used. The private key is used when you call the <a>IssueCertificate</a> action or the <a>RevokeCertificate</a> action. </p>
-    async fn describe_certificate_authority_audit_report(
+    /// Presigns the request `describe_certificate_authority_audit_report` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_certificate_authority_audit_report(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeCertificateAuthorityAuditReportRequest,
-    ) -> Result<
-        DescribeCertificateAuthorityAuditReportResponse,
-        RusotoError<DescribeCertificateAuthorityAuditReportError>,
-    > {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_certificate_authority_audit_report_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_certificate_authority_audit_report_request(
+        &self,
+        input: DescribeCertificateAuthorityAuditReportRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "acm-pca", &self.region, "/");
+        request.set_operation("DescribeCertificateAuthorityAuditReport");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -2242,60 +2698,112 @@ impl AcmPca for AcmPcaClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `get_certificate`, also returning the metadata of the response.
+    pub async fn get_certificate_with_metadata(
+        &self,
+        input: GetCertificateRequest,
+    ) -> Result<(GetCertificateResponse, ResponseMetadata), RusotoError<GetCertificateError>> {
+        let request = self.build_get_certificate_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DescribeCertificateAuthorityAuditReportResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<GetCertificateResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeCertificateAuthorityAuditReportError::from_response(
-                response,
-            ))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(GetCertificateError::from_response(response))
         }
     }
 
-    /// <p>Retrieves a certificate from your private CA. The ARN of the certificate is returned when you call the <a>IssueCertificate</a> action. You must specify both the ARN of your private CA and the ARN of the issued certificate when calling the <b>GetCertificate</b> action. You can retrieve the certificate if it is in the <b>ISSUED</b> state. You can call the <a>CreateCertificateAuthorityAuditReport</a> action to create a report that contains information about all of the certificates issued and revoked by your private CA. </p>
-    async fn get_certificate(
+    /// Presigns the request `get_certificate` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_certificate(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetCertificateRequest,
-    ) -> Result<GetCertificateResponse, RusotoError<GetCertificateError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_certificate_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_certificate_request(&self, input: GetCertificateRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "acm-pca", &self.region, "/");
+        request.set_operation("GetCertificate");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "ACMPrivateCA.GetCertificate");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `get_certificate_authority_certificate`, also returning the metadata of the response.
+    pub async fn get_certificate_authority_certificate_with_metadata(
+        &self,
+        input: GetCertificateAuthorityCertificateRequest,
+    ) -> Result<
+        (GetCertificateAuthorityCertificateResponse, ResponseMetadata),
+        RusotoError<GetCertificateAuthorityCertificateError>,
+    > {
+        let request = self.build_get_certificate_authority_certificate_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<GetCertificateResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<GetCertificateAuthorityCertificateResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(GetCertificateError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(GetCertificateAuthorityCertificateError::from_response(
+                response,
+            ))
         }
     }
 
-    /// <p>Retrieves the certificate and certificate chain for your private certificate authority (CA). Both the certificate and the chain are base64 PEM-encoded. The chain does not include the CA certificate. Each certificate in the chain signs the one before it. </p>
-    async fn get_certificate_authority_certificate(
+    /// Presigns the request `get_certificate_authority_certificate` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_certificate_authority_certificate(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetCertificateAuthorityCertificateRequest,
-    ) -> Result<
-        GetCertificateAuthorityCertificateResponse,
-        RusotoError<GetCertificateAuthorityCertificateError>,
-    > {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_certificate_authority_certificate_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_certificate_authority_certificate_request(
+        &self,
+        input: GetCertificateAuthorityCertificateRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "acm-pca", &self.region, "/");
+        request.set_operation("GetCertificateAuthorityCertificate");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -2305,59 +2813,111 @@ impl AcmPca for AcmPcaClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `get_certificate_authority_csr`, also returning the metadata of the response.
+    pub async fn get_certificate_authority_csr_with_metadata(
+        &self,
+        input: GetCertificateAuthorityCsrRequest,
+    ) -> Result<
+        (GetCertificateAuthorityCsrResponse, ResponseMetadata),
+        RusotoError<GetCertificateAuthorityCsrError>,
+    > {
+        let request = self.build_get_certificate_authority_csr_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<GetCertificateAuthorityCertificateResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<GetCertificateAuthorityCsrResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(GetCertificateAuthorityCertificateError::from_response(
-                response,
-            ))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(GetCertificateAuthorityCsrError::from_response(response))
         }
     }
 
-    /// <p>Retrieves the certificate signing request (CSR) for your private certificate authority (CA). The CSR is created when you call the <a>CreateCertificateAuthority</a> action. Sign the CSR with your ACM Private CA-hosted or on-premises root or subordinate CA. Then import the signed certificate back into ACM Private CA by calling the <a>ImportCertificateAuthorityCertificate</a> action. The CSR is returned as a base64 PEM-encoded string. </p>
-    async fn get_certificate_authority_csr(
+    /// Presigns the request `get_certificate_authority_csr` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_certificate_authority_csr(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetCertificateAuthorityCsrRequest,
-    ) -> Result<GetCertificateAuthorityCsrResponse, RusotoError<GetCertificateAuthorityCsrError>>
-    {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_certificate_authority_csr_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_certificate_authority_csr_request(
+        &self,
+        input: GetCertificateAuthorityCsrRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "acm-pca", &self.region, "/");
+        request.set_operation("GetCertificateAuthorityCsr");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "ACMPrivateCA.GetCertificateAuthorityCsr");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `import_certificate_authority_certificate`, also returning the metadata of the response.
+    pub async fn import_certificate_authority_certificate_with_metadata(
+        &self,
+        input: ImportCertificateAuthorityCertificateRequest,
+    ) -> Result<((), ResponseMetadata), RusotoError<ImportCertificateAuthorityCertificateError>>
+    {
+        let request = self.build_import_certificate_authority_certificate_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<GetCertificateAuthorityCsrResponse, _>()
+            std::mem::drop(response);
+            Ok(((), metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(GetCertificateAuthorityCsrError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ImportCertificateAuthorityCertificateError::from_response(
+                response,
+            ))
         }
     }
 
-    /// <p><p>Imports a signed private CA certificate into ACM Private CA. This action is used when you are using a chain of trust whose root is located outside ACM Private CA. Before you can call this action, the following preparations must in place:</p> <ol> <li> <p>In ACM Private CA, call the <a>CreateCertificateAuthority</a> action to create the private CA that that you plan to back with the imported certificate.</p> </li> <li> <p>Call the <a>GetCertificateAuthorityCsr</a> action to generate a certificate signing request (CSR).</p> </li> <li> <p>Sign the CSR using a root or intermediate CA hosted either by an on-premises PKI hierarchy or a commercial CA..</p> </li> <li> <p>Create a certificate chain and copy the signed certificate and the certificate chain to your working directory.</p> </li> </ol> <p>The following requirements apply when you import a CA certificate.</p> <ul> <li> <p>You cannot import a non-self-signed certificate for use as a root CA.</p> </li> <li> <p>You cannot import a self-signed certificate for use as a subordinate CA.</p> </li> <li> <p>Your certificate chain must not include the private CA certificate that you are importing.</p> </li> <li> <p>Your ACM Private CA-hosted or on-premises CA certificate must be the last certificate in your chain. The subordinate certificate, if any, that your root CA signed must be next to last. The subordinate certificate signed by the preceding subordinate CA must come next, and so on until your chain is built. </p> </li> <li> <p>The chain must be PEM-encoded.</p> </li> </ul></p>
-    async fn import_certificate_authority_certificate(
+    /// Presigns the request `import_certificate_authority_certificate` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_import_certificate_authority_certificate(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ImportCertificateAuthorityCertificateRequest,
-    ) -> Result<(), RusotoError<ImportCertificateAuthorityCertificateError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_import_certificate_authority_certificate_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_import_certificate_authority_certificate_request(
+        &self,
+        input: ImportCertificateAuthorityCertificateRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "acm-pca", &self.region, "/");
+        request.set_operation("ImportCertificateAuthorityCertificate");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -2367,266 +2927,948 @@ impl AcmPca for AcmPcaClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `issue_certificate`, also returning the metadata of the response.
+    pub async fn issue_certificate_with_metadata(
+        &self,
+        input: IssueCertificateRequest,
+    ) -> Result<(IssueCertificateResponse, ResponseMetadata), RusotoError<IssueCertificateError>>
+    {
+        let request = self.build_issue_certificate_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            std::mem::drop(response);
-            Ok(())
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<IssueCertificateResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ImportCertificateAuthorityCertificateError::from_response(
-                response,
-            ))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(IssueCertificateError::from_response(response))
         }
     }
 
-    /// <p><p>Uses your private certificate authority (CA) to issue a client certificate. This action returns the Amazon Resource Name (ARN) of the certificate. You can retrieve the certificate by calling the <a>GetCertificate</a> action and specifying the ARN. </p> <note> <p>You cannot use the ACM <b>ListCertificateAuthorities</b> action to retrieve the ARNs of the certificates that you issue by using ACM Private CA.</p> </note></p>
-    async fn issue_certificate(
+    /// Presigns the request `issue_certificate` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_issue_certificate(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: IssueCertificateRequest,
-    ) -> Result<IssueCertificateResponse, RusotoError<IssueCertificateError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_issue_certificate_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_issue_certificate_request(&self, input: IssueCertificateRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "acm-pca", &self.region, "/");
+        request.set_operation("IssueCertificate");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "ACMPrivateCA.IssueCertificate");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `list_certificate_authorities`, also returning the metadata of the response.
+    pub async fn list_certificate_authorities_with_metadata(
+        &self,
+        input: ListCertificateAuthoritiesRequest,
+    ) -> Result<
+        (ListCertificateAuthoritiesResponse, ResponseMetadata),
+        RusotoError<ListCertificateAuthoritiesError>,
+    > {
+        let request = self.build_list_certificate_authorities_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<IssueCertificateResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ListCertificateAuthoritiesResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(IssueCertificateError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ListCertificateAuthoritiesError::from_response(response))
         }
     }
 
-    /// <p>Lists the private certificate authorities that you created by using the <a>CreateCertificateAuthority</a> action.</p>
-    async fn list_certificate_authorities(
+    /// Presigns the request `list_certificate_authorities` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_certificate_authorities(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListCertificateAuthoritiesRequest,
-    ) -> Result<ListCertificateAuthoritiesResponse, RusotoError<ListCertificateAuthoritiesError>>
-    {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_certificate_authorities_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_certificate_authorities_request(
+        &self,
+        input: ListCertificateAuthoritiesRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "acm-pca", &self.region, "/");
+        request.set_operation("ListCertificateAuthorities");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "ACMPrivateCA.ListCertificateAuthorities");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `list_permissions`, also returning the metadata of the response.
+    pub async fn list_permissions_with_metadata(
+        &self,
+        input: ListPermissionsRequest,
+    ) -> Result<(ListPermissionsResponse, ResponseMetadata), RusotoError<ListPermissionsError>>
+    {
+        let request = self.build_list_permissions_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListCertificateAuthoritiesResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ListPermissionsResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ListCertificateAuthoritiesError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ListPermissionsError::from_response(response))
         }
     }
 
-    /// <p>Lists all the permissions, if any, that have been assigned by a private CA. Permissions can be granted with the <a>CreatePermission</a> action and revoked with the <a>DeletePermission</a> action.</p>
-    async fn list_permissions(
+    /// Presigns the request `list_permissions` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_permissions(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListPermissionsRequest,
-    ) -> Result<ListPermissionsResponse, RusotoError<ListPermissionsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_permissions_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_permissions_request(&self, input: ListPermissionsRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "acm-pca", &self.region, "/");
+        request.set_operation("ListPermissions");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "ACMPrivateCA.ListPermissions");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `list_tags`, also returning the metadata of the response.
+    pub async fn list_tags_with_metadata(
+        &self,
+        input: ListTagsRequest,
+    ) -> Result<(ListTagsResponse, ResponseMetadata), RusotoError<ListTagsError>> {
+        let request = self.build_list_tags_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<ListPermissionsResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ListTagsResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ListPermissionsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ListTagsError::from_response(response))
         }
     }
 
-    /// <p>Lists the tags, if any, that are associated with your private CA. Tags are labels that you can use to identify and organize your CAs. Each tag consists of a key and an optional value. Call the <a>TagCertificateAuthority</a> action to add one or more tags to your CA. Call the <a>UntagCertificateAuthority</a> action to remove tags. </p>
-    async fn list_tags(
+    /// Presigns the request `list_tags` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_tags(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListTagsRequest,
-    ) -> Result<ListTagsResponse, RusotoError<ListTagsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_tags_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_tags_request(&self, input: ListTagsRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "acm-pca", &self.region, "/");
+        request.set_operation("ListTags");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "ACMPrivateCA.ListTags");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `restore_certificate_authority`, also returning the metadata of the response.
+    pub async fn restore_certificate_authority_with_metadata(
+        &self,
+        input: RestoreCertificateAuthorityRequest,
+    ) -> Result<((), ResponseMetadata), RusotoError<RestoreCertificateAuthorityError>> {
+        let request = self.build_restore_certificate_authority_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<ListTagsResponse, _>()
+            std::mem::drop(response);
+            Ok(((), metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ListTagsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(RestoreCertificateAuthorityError::from_response(response))
         }
     }
 
-    /// <p>Restores a certificate authority (CA) that is in the <code>DELETED</code> state. You can restore a CA during the period that you defined in the <b>PermanentDeletionTimeInDays</b> parameter of the <a>DeleteCertificateAuthority</a> action. Currently, you can specify 7 to 30 days. If you did not specify a <b>PermanentDeletionTimeInDays</b> value, by default you can restore the CA at any time in a 30 day period. You can check the time remaining in the restoration period of a private CA in the <code>DELETED</code> state by calling the <a>DescribeCertificateAuthority</a> or <a>ListCertificateAuthorities</a> actions. The status of a restored CA is set to its pre-deletion status when the <b>RestoreCertificateAuthority</b> action returns. To change its status to <code>ACTIVE</code>, call the <a>UpdateCertificateAuthority</a> action. If the private CA was in the <code>PENDING_CERTIFICATE</code> state at deletion, you must use the <a>ImportCertificateAuthorityCertificate</a> action to import a certificate authority into the private CA before it can be activated. You cannot restore a CA after the restoration period has ended.</p>
-    async fn restore_certificate_authority(
+    /// Presigns the request `restore_certificate_authority` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_restore_certificate_authority(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: RestoreCertificateAuthorityRequest,
-    ) -> Result<(), RusotoError<RestoreCertificateAuthorityError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_restore_certificate_authority_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_restore_certificate_authority_request(
+        &self,
+        input: RestoreCertificateAuthorityRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "acm-pca", &self.region, "/");
+        request.set_operation("RestoreCertificateAuthority");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "ACMPrivateCA.RestoreCertificateAuthority");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `revoke_certificate`, also returning the metadata of the response.
+    pub async fn revoke_certificate_with_metadata(
+        &self,
+        input: RevokeCertificateRequest,
+    ) -> Result<((), ResponseMetadata), RusotoError<RevokeCertificateError>> {
+        let request = self.build_revoke_certificate_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
             std::mem::drop(response);
-            Ok(())
+            Ok(((), metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(RestoreCertificateAuthorityError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(RevokeCertificateError::from_response(response))
         }
     }
 
-    /// <p><p>Revokes a certificate that was issued inside ACM Private CA. If you enable a certificate revocation list (CRL) when you create or update your private CA, information about the revoked certificates will be included in the CRL. ACM Private CA writes the CRL to an S3 bucket that you specify. For more information about revocation, see the <a>CrlConfiguration</a> structure. ACM Private CA also writes revocation information to the audit report. For more information, see <a>CreateCertificateAuthorityAuditReport</a>. </p> <note> <p>You cannot revoke a root CA self-signed certificate.</p> </note></p>
-    async fn revoke_certificate(
+    /// Presigns the request `revoke_certificate` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_revoke_certificate(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: RevokeCertificateRequest,
-    ) -> Result<(), RusotoError<RevokeCertificateError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_revoke_certificate_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_revoke_certificate_request(&self, input: RevokeCertificateRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "acm-pca", &self.region, "/");
+        request.set_operation("RevokeCertificate");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "ACMPrivateCA.RevokeCertificate");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `tag_certificate_authority`, also returning the metadata of the response.
+    pub async fn tag_certificate_authority_with_metadata(
+        &self,
+        input: TagCertificateAuthorityRequest,
+    ) -> Result<((), ResponseMetadata), RusotoError<TagCertificateAuthorityError>> {
+        let request = self.build_tag_certificate_authority_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
             std::mem::drop(response);
-            Ok(())
+            Ok(((), metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(RevokeCertificateError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(TagCertificateAuthorityError::from_response(response))
         }
     }
 
-    /// <p>Adds one or more tags to your private CA. Tags are labels that you can use to identify and organize your AWS resources. Each tag consists of a key and an optional value. You specify the private CA on input by its Amazon Resource Name (ARN). You specify the tag by using a key-value pair. You can apply a tag to just one private CA if you want to identify a specific characteristic of that CA, or you can apply the same tag to multiple private CAs if you want to filter for a common relationship among those CAs. To remove one or more tags, use the <a>UntagCertificateAuthority</a> action. Call the <a>ListTags</a> action to see what tags are associated with your CA. </p>
-    async fn tag_certificate_authority(
+    /// Presigns the request `tag_certificate_authority` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_tag_certificate_authority(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: TagCertificateAuthorityRequest,
-    ) -> Result<(), RusotoError<TagCertificateAuthorityError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_tag_certificate_authority_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_tag_certificate_authority_request(
+        &self,
+        input: TagCertificateAuthorityRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "acm-pca", &self.region, "/");
+        request.set_operation("TagCertificateAuthority");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "ACMPrivateCA.TagCertificateAuthority");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `untag_certificate_authority`, also returning the metadata of the response.
+    pub async fn untag_certificate_authority_with_metadata(
+        &self,
+        input: UntagCertificateAuthorityRequest,
+    ) -> Result<((), ResponseMetadata), RusotoError<UntagCertificateAuthorityError>> {
+        let request = self.build_untag_certificate_authority_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
             std::mem::drop(response);
-            Ok(())
+            Ok(((), metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(TagCertificateAuthorityError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(UntagCertificateAuthorityError::from_response(response))
         }
     }
 
-    /// <p>Remove one or more tags from your private CA. A tag consists of a key-value pair. If you do not specify the value portion of the tag when calling this action, the tag will be removed regardless of value. If you specify a value, the tag is removed only if it is associated with the specified value. To add tags to a private CA, use the <a>TagCertificateAuthority</a>. Call the <a>ListTags</a> action to see what tags are associated with your CA. </p>
-    async fn untag_certificate_authority(
+    /// Presigns the request `untag_certificate_authority` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_untag_certificate_authority(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UntagCertificateAuthorityRequest,
-    ) -> Result<(), RusotoError<UntagCertificateAuthorityError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_untag_certificate_authority_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_untag_certificate_authority_request(
+        &self,
+        input: UntagCertificateAuthorityRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "acm-pca", &self.region, "/");
+        request.set_operation("UntagCertificateAuthority");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "ACMPrivateCA.UntagCertificateAuthority");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `update_certificate_authority`, also returning the metadata of the response.
+    pub async fn update_certificate_authority_with_metadata(
+        &self,
+        input: UpdateCertificateAuthorityRequest,
+    ) -> Result<((), ResponseMetadata), RusotoError<UpdateCertificateAuthorityError>> {
+        let request = self.build_update_certificate_authority_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
             std::mem::drop(response);
-            Ok(())
+            Ok(((), metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(UntagCertificateAuthorityError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(UpdateCertificateAuthorityError::from_response(response))
         }
     }
 
-    /// <p>Updates the status or configuration of a private certificate authority (CA). Your private CA must be in the <code>ACTIVE</code> or <code>DISABLED</code> state before you can update it. You can disable a private CA that is in the <code>ACTIVE</code> state or make a CA that is in the <code>DISABLED</code> state active again.</p>
-    async fn update_certificate_authority(
+    /// Presigns the request `update_certificate_authority` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_certificate_authority(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UpdateCertificateAuthorityRequest,
-    ) -> Result<(), RusotoError<UpdateCertificateAuthorityError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_certificate_authority_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_certificate_authority_request(
+        &self,
+        input: UpdateCertificateAuthorityRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "acm-pca", &self.region, "/");
+        request.set_operation("UpdateCertificateAuthority");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "ACMPrivateCA.UpdateCertificateAuthority");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
-        let mut response = self
-            .client
-            .sign_and_dispatch(request)
+        request
+    }
+
+    /// The pages of `ListCertificateAuthorities` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `MaxResults` of `input`.
+    pub fn list_certificate_authorities_pages(
+        &self,
+        input: ListCertificateAuthoritiesRequest,
+    ) -> impl futures::Stream<
+        Item = Result<
+            ListCertificateAuthoritiesResponse,
+            RusotoError<ListCertificateAuthoritiesError>,
+        >,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: AcmPcaClient, input| async move {
+                client.list_certificate_authorities(input).await
+            },
+            |input: &ListCertificateAuthoritiesRequest,
+             output: &ListCertificateAuthoritiesResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListCertificateAuthoritiesRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `CertificateAuthorities` of every page of `ListCertificateAuthorities` results for `input`, as `list_certificate_authorities_pages` requests them.
+    pub fn list_certificate_authorities_stream(
+        &self,
+        input: ListCertificateAuthoritiesRequest,
+    ) -> impl futures::Stream<
+        Item = Result<CertificateAuthority, RusotoError<ListCertificateAuthoritiesError>>,
+    > + Send {
+        rusoto_core::pagination::items(self.list_certificate_authorities_pages(input), |page| {
+            page.certificate_authorities.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `ListPermissions` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `MaxResults` of `input`.
+    pub fn list_permissions_pages(
+        &self,
+        input: ListPermissionsRequest,
+    ) -> impl futures::Stream<
+        Item = Result<ListPermissionsResponse, RusotoError<ListPermissionsError>>,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: AcmPcaClient, input| async move { client.list_permissions(input).await },
+            |input: &ListPermissionsRequest, output: &ListPermissionsResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListPermissionsRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `Permissions` of every page of `ListPermissions` results for `input`, as `list_permissions_pages` requests them.
+    pub fn list_permissions_stream(
+        &self,
+        input: ListPermissionsRequest,
+    ) -> impl futures::Stream<Item = Result<Permission, RusotoError<ListPermissionsError>>> + Send
+    {
+        rusoto_core::pagination::items(self.list_permissions_pages(input), |page| {
+            page.permissions.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `ListTags` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `MaxResults` of `input`.
+    pub fn list_tags_pages(
+        &self,
+        input: ListTagsRequest,
+    ) -> impl futures::Stream<Item = Result<ListTagsResponse, RusotoError<ListTagsError>>> + Send
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: AcmPcaClient, input| async move { client.list_tags(input).await },
+            |input: &ListTagsRequest, output: &ListTagsResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListTagsRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `Tags` of every page of `ListTags` results for `input`, as `list_tags_pages` requests them.
+    pub fn list_tags_stream(
+        &self,
+        input: ListTagsRequest,
+    ) -> impl futures::Stream<Item = Result<Tag, RusotoError<ListTagsError>>> + Send {
+        rusoto_core::pagination::items(self.list_tags_pages(input), |page| {
+            page.tags.unwrap_or_default()
+        })
+    }
+
+    /// Waits for `AuditReportCreated`, polling `DescribeCertificateAuthorityAuditReport` with `input` every 3 seconds, up to 60 times.
+    pub async fn wait_until_audit_report_created(
+        &self,
+        input: DescribeCertificateAuthorityAuditReportRequest,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<DescribeCertificateAuthorityAuditReportError>>
+    {
+        let waiter = rusoto_core::waiter::Waiter::new(std::time::Duration::from_secs(3), 60);
+        self.wait_until_audit_report_created_with(input, &waiter)
             .await
-            .map_err(RusotoError::from)?;
-        if response.status.is_success() {
-            std::mem::drop(response);
-            Ok(())
-        } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(UpdateCertificateAuthorityError::from_response(response))
-        }
+    }
+
+    /// Waits for `AuditReportCreated` like `wait_until_audit_report_created`, with the delay, attempts and runtime of `waiter`.
+    pub async fn wait_until_audit_report_created_with(
+        &self,
+        input: DescribeCertificateAuthorityAuditReportRequest,
+        waiter: &rusoto_core::waiter::Waiter,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<DescribeCertificateAuthorityAuditReportError>>
+    {
+        rusoto_core::waiter::wait(
+            waiter,
+            || self.describe_certificate_authority_audit_report(input.clone()),
+            |result| {
+                if match result {
+                    Ok(output) => std::iter::once(output)
+                        .filter_map(|value| value.audit_report_status.as_ref())
+                        .any(|value| value == "SUCCESS"),
+                    Err(_) => false,
+                } {
+                    return Some(rusoto_core::waiter::WaiterState::Success);
+                }
+                if match result {
+                    Ok(output) => std::iter::once(output)
+                        .filter_map(|value| value.audit_report_status.as_ref())
+                        .any(|value| value == "FAILED"),
+                    Err(_) => false,
+                } {
+                    return Some(rusoto_core::waiter::WaiterState::Failure);
+                }
+                if result
+                    .as_ref()
+                    .err()
+                    .and_then(|error| error.error_code())
+                    .as_deref()
+                    == Some("AccessDeniedException")
+                {
+                    return Some(rusoto_core::waiter::WaiterState::Failure);
+                }
+                None
+            },
+        )
+        .await
+    }
+
+    /// Waits for `CertificateAuthorityCSRCreated`, polling `GetCertificateAuthorityCsr` with `input` every 3 seconds, up to 60 times.
+    pub async fn wait_until_certificate_authority_csr_created(
+        &self,
+        input: GetCertificateAuthorityCsrRequest,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<GetCertificateAuthorityCsrError>> {
+        let waiter = rusoto_core::waiter::Waiter::new(std::time::Duration::from_secs(3), 60);
+        self.wait_until_certificate_authority_csr_created_with(input, &waiter)
+            .await
+    }
+
+    /// Waits for `CertificateAuthorityCSRCreated` like `wait_until_certificate_authority_csr_created`, with the delay, attempts and runtime of `waiter`.
+    pub async fn wait_until_certificate_authority_csr_created_with(
+        &self,
+        input: GetCertificateAuthorityCsrRequest,
+        waiter: &rusoto_core::waiter::Waiter,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<GetCertificateAuthorityCsrError>> {
+        rusoto_core::waiter::wait(
+            waiter,
+            || self.get_certificate_authority_csr(input.clone()),
+            |result| {
+                if result.is_ok() {
+                    return Some(rusoto_core::waiter::WaiterState::Success);
+                }
+                if result
+                    .as_ref()
+                    .err()
+                    .and_then(|error| error.error_code())
+                    .as_deref()
+                    == Some("RequestInProgressException")
+                {
+                    return Some(rusoto_core::waiter::WaiterState::Retry);
+                }
+                if result
+                    .as_ref()
+                    .err()
+                    .and_then(|error| error.error_code())
+                    .as_deref()
+                    == Some("AccessDeniedException")
+                {
+                    return Some(rusoto_core::waiter::WaiterState::Failure);
+                }
+                None
+            },
+        )
+        .await
+    }
+
+    /// Waits for `CertificateIssued`, polling `GetCertificate` with `input` every 1 seconds, up to 60 times.
+    pub async fn wait_until_certificate_issued(
+        &self,
+        input: GetCertificateRequest,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<GetCertificateError>> {
+        let waiter = rusoto_core::waiter::Waiter::new(std::time::Duration::from_secs(1), 60);
+        self.wait_until_certificate_issued_with(input, &waiter)
+            .await
+    }
+
+    /// Waits for `CertificateIssued` like `wait_until_certificate_issued`, with the delay, attempts and runtime of `waiter`.
+    pub async fn wait_until_certificate_issued_with(
+        &self,
+        input: GetCertificateRequest,
+        waiter: &rusoto_core::waiter::Waiter,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<GetCertificateError>> {
+        rusoto_core::waiter::wait(
+            waiter,
+            || self.get_certificate(input.clone()),
+            |result| {
+                if result.is_ok() {
+                    return Some(rusoto_core::waiter::WaiterState::Success);
+                }
+                if result
+                    .as_ref()
+                    .err()
+                    .and_then(|error| error.error_code())
+                    .as_deref()
+                    == Some("RequestInProgressException")
+                {
+                    return Some(rusoto_core::waiter::WaiterState::Retry);
+                }
+                if result
+                    .as_ref()
+                    .err()
+                    .and_then(|error| error.error_code())
+                    .as_deref()
+                    == Some("AccessDeniedException")
+                {
+                    return Some(rusoto_core::waiter::WaiterState::Failure);
+                }
+                None
+            },
+        )
+        .await
+    }
+}
+
+#[async_trait]
+impl AcmPca for AcmPcaClient {
+    /// <p>Creates a root or subordinate private certificate authority (CA). You must specify the CA configuration, the certificate revocation list (CRL) configuration, the CA type, and an optional idempotency token to avoid accidental creation of multiple CAs. The CA configuration specifies the name of the algorithm and key size to be used to create the CA private key, the type of signing algorithm that the CA uses, and X.500 subject information. The CRL configuration specifies the CRL expiration period in days (the validity period of the CRL), the Amazon S3 bucket that will contain the CRL, and a CNAME alias for the S3 bucket that is included in certificates issued by the CA. If successful, this action returns the Amazon Resource Name (ARN) of the CA.</p>
+    async fn create_certificate_authority(
+        &self,
+        input: CreateCertificateAuthorityRequest,
+    ) -> Result<CreateCertificateAuthorityResponse, RusotoError<CreateCertificateAuthorityError>>
+    {
+        self.create_certificate_authority_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Creates an audit report that lists every time that your CA private key is used. The report is saved in the Amazon S3 bucket that you specify on input. The <a>IssueCertificate</a> and <a>RevokeCertificate</a> actions use the private key.</p>
+    async fn create_certificate_authority_audit_report(
+        &self,
+        input: CreateCertificateAuthorityAuditReportRequest,
+    ) -> Result<
+        CreateCertificateAuthorityAuditReportResponse,
+        RusotoError<CreateCertificateAuthorityAuditReportError>,
+    > {
+        self.create_certificate_authority_audit_report_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Assigns permissions from a private CA to a designated AWS service. Services are specified by their service principals and can be given permission to create and retrieve certificates on a private CA. Services can also be given permission to list the active permissions that the private CA has granted. For ACM to automatically renew your private CA's certificates, you must assign all possible permissions from the CA to the ACM service principal.</p> <p>At this time, you can only assign permissions to ACM (<code>acm.amazonaws.com</code>). Permissions can be revoked with the <a>DeletePermission</a> action and listed with the <a>ListPermissions</a> action.</p>
+    async fn create_permission(
+        &self,
+        input: CreatePermissionRequest,
+    ) -> Result<(), RusotoError<CreatePermissionError>> {
+        self.create_permission_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes a private certificate authority (CA). You must provide the Amazon Resource Name (ARN) of the private CA that you want to delete. You can find the ARN by calling the <a>ListCertificateAuthorities</a> action. </p> <note> <p>Deleting a CA will invalidate other CAs and certificates below it in your CA hierarchy.</p> </note> <p>Before you can delete a CA that you have created and activated, you must disable it. To do this, call the <a>UpdateCertificateAuthority</a> action and set the <b>CertificateAuthorityStatus</b> parameter to <code>DISABLED</code>. </p> <p>Additionally, you can delete a CA if you are waiting for it to be created (that is, the status of the CA is <code>CREATING</code>). You can also delete it if the CA has been created but you haven't yet imported the signed certificate into ACM Private CA (that is, the status of the CA is <code>PENDING_CERTIFICATE</code>). </p> <p>When you successfully call <a>DeleteCertificateAuthority</a>, the CA's status changes to <code>DELETED</code>. However, the CA won't be permanently deleted until the restoration period has passed. By default, if you do not set the <code>PermanentDeletionTimeInDays</code> parameter, the CA remains restorable for 30 days. You can set the parameter from 7 to 30 days. The <a>DescribeCertificateAuthority</a> action returns the time remaining in the restoration window of a private CA in the <code>DELETED</code> state. To restore an eligible CA, call the <a>RestoreCertificateAuthority</a> action.</p>
+    async fn delete_certificate_authority(
+        &self,
+        input: DeleteCertificateAuthorityRequest,
+    ) -> Result<(), RusotoError<DeleteCertificateAuthorityError>> {
+        self.delete_certificate_authority_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Revokes permissions that a private CA assigned to a designated AWS service. Permissions can be created with the <a>CreatePermission</a> action and listed with the <a>ListPermissions</a> action. </p>
+    async fn delete_permission(
+        &self,
+        input: DeletePermissionRequest,
+    ) -> Result<(), RusotoError<DeletePermissionError>> {
+        self.delete_permission_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Lists information about your private certificate authority (CA). You specify the private CA on input by its ARN (Amazon Resource Name). The output contains the status of your CA. This can be any of the following: </p> <ul> <li> <p> <code>CREATING</code> - ACM Private CA is creating your private certificate authority.</p> </li> <li> <p> <code>PENDING_CERTIFICATE</code> - The certificate is pending. You must use your ACM Private CA-hosted or on-premises root or subordinate CA to sign your private CA CSR and then import it into PCA. </p> </li> <li> <p> <code>ACTIVE</code> - Your private CA is active.</p> </li> <li> <p> <code>DISABLED</code> - Your private CA has been disabled.</p> </li> <li> <p> <code>EXPIRED</code> - Your private CA certificate has expired.</p> </li> <li> <p> <code>FAILED</code> - Your private CA has failed. Your CA can fail because of problems such a network outage or backend AWS failure or other errors. A failed CA can never return to the pending state. You must create a new CA. </p> </li> <li> <p> <code>DELETED</code> - Your private CA is within the restoration period, after which it is permanently deleted. The length of time remaining in the CA&#39;s restoration period is also included in this action&#39;s output.</p> </li> </ul></p>
+    async fn describe_certificate_authority(
+        &self,
+        input: DescribeCertificateAuthorityRequest,
+    ) -> Result<DescribeCertificateAuthorityResponse, RusotoError<DescribeCertificateAuthorityError>>
+    {
+        self.describe_certificate_authority_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Lists information about a specific audit report created by calling the <a>CreateCertificateAuthorityAuditReport</a> action. Audit information is created every time the certificate authority (CA) private key is used. The private key is used when you call the <a>IssueCertificate</a> action or the <a>RevokeCertificate</a> action. </p>
+    async fn describe_certificate_authority_audit_report(
+        &self,
+        input: DescribeCertificateAuthorityAuditReportRequest,
+    ) -> Result<
+        DescribeCertificateAuthorityAuditReportResponse,
+        RusotoError<DescribeCertificateAuthorityAuditReportError>,
+    > {
+        self.describe_certificate_authority_audit_report_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Retrieves a certificate from your private CA. The ARN of the certificate is returned when you call the <a>IssueCertificate</a> action. You must specify both the ARN of your private CA and the ARN of the issued certificate when calling the <b>GetCertificate</b> action. You can retrieve the certificate if it is in the <b>ISSUED</b> state. You can call the <a>CreateCertificateAuthorityAuditReport</a> action to create a report that contains information about all of the certificates issued and revoked by your private CA. </p>
+    async fn get_certificate(
+        &self,
+        input: GetCertificateRequest,
+    ) -> Result<GetCertificateResponse, RusotoError<GetCertificateError>> {
+        self.get_certificate_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Retrieves the certificate and certificate chain for your private certificate authority (CA). Both the certificate and the chain are base64 PEM-encoded. The chain does not include the CA certificate. Each certificate in the chain signs the one before it. </p>
+    async fn get_certificate_authority_certificate(
+        &self,
+        input: GetCertificateAuthorityCertificateRequest,
+    ) -> Result<
+        GetCertificateAuthorityCertificateResponse,
+        RusotoError<GetCertificateAuthorityCertificateError>,
+    > {
+        self.get_certificate_authority_certificate_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Retrieves the certificate signing request (CSR) for your private certificate authority (CA). The CSR is created when you call the <a>CreateCertificateAuthority</a> action. Sign the CSR with your ACM Private CA-hosted or on-premises root or subordinate CA. Then import the signed certificate back into ACM Private CA by calling the <a>ImportCertificateAuthorityCertificate</a> action. The CSR is returned as a base64 PEM-encoded string. </p>
+    async fn get_certificate_authority_csr(
+        &self,
+        input: GetCertificateAuthorityCsrRequest,
+    ) -> Result<GetCertificateAuthorityCsrResponse, RusotoError<GetCertificateAuthorityCsrError>>
+    {
+        self.get_certificate_authority_csr_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Imports a signed private CA certificate into ACM Private CA. This action is used when you are using a chain of trust whose root is located outside ACM Private CA. Before you can call this action, the following preparations must in place:</p> <ol> <li> <p>In ACM Private CA, call the <a>CreateCertificateAuthority</a> action to create the private CA that that you plan to back with the imported certificate.</p> </li> <li> <p>Call the <a>GetCertificateAuthorityCsr</a> action to generate a certificate signing request (CSR).</p> </li> <li> <p>Sign the CSR using a root or intermediate CA hosted either by an on-premises PKI hierarchy or a commercial CA..</p> </li> <li> <p>Create a certificate chain and copy the signed certificate and the certificate chain to your working directory.</p> </li> </ol> <p>The following requirements apply when you import a CA certificate.</p> <ul> <li> <p>You cannot import a non-self-signed certificate for use as a root CA.</p> </li> <li> <p>You cannot import a self-signed certificate for use as a subordinate CA.</p> </li> <li> <p>Your certificate chain must not include the private CA certificate that you are importing.</p> </li> <li> <p>Your ACM Private CA-hosted or on-premises CA certificate must be the last certificate in your chain. The subordinate certificate, if any, that your root CA signed must be next to last. The subordinate certificate signed by the preceding subordinate CA must come next, and so on until your chain is built. </p> </li> <li> <p>The chain must be PEM-encoded.</p> </li> </ul></p>
+    async fn import_certificate_authority_certificate(
+        &self,
+        input: ImportCertificateAuthorityCertificateRequest,
+    ) -> Result<(), RusotoError<ImportCertificateAuthorityCertificateError>> {
+        self.import_certificate_authority_certificate_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Uses your private certificate authority (CA) to issue a client certificate. This action returns the Amazon Resource Name (ARN) of the certificate. You can retrieve the certificate by calling the <a>GetCertificate</a> action and specifying the ARN. </p> <note> <p>You cannot use the ACM <b>ListCertificateAuthorities</b> action to retrieve the ARNs of the certificates that you issue by using ACM Private CA.</p> </note></p>
+    async fn issue_certificate(
+        &self,
+        input: IssueCertificateRequest,
+    ) -> Result<IssueCertificateResponse, RusotoError<IssueCertificateError>> {
+        self.issue_certificate_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Lists the private certificate authorities that you created by using the <a>CreateCertificateAuthority</a> action.</p>
+    async fn list_certificate_authorities(
+        &self,
+        input: ListCertificateAuthoritiesRequest,
+    ) -> Result<ListCertificateAuthoritiesResponse, RusotoError<ListCertificateAuthoritiesError>>
+    {
+        self.list_certificate_authorities_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Lists all the permissions, if any, that have been assigned by a private CA. Permissions can be granted with the <a>CreatePermission</a> action and revoked with the <a>DeletePermission</a> action.</p>
+    async fn list_permissions(
+        &self,
+        input: ListPermissionsRequest,
+    ) -> Result<ListPermissionsResponse, RusotoError<ListPermissionsError>> {
+        self.list_permissions_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Lists the tags, if any, that are associated with your private CA. Tags are labels that you can use to identify and organize your CAs. Each tag consists of a key and an optional value. Call the <a>TagCertificateAuthority</a> action to add one or more tags to your CA. Call the <a>UntagCertificateAuthority</a> action to remove tags. </p>
+    async fn list_tags(
+        &self,
+        input: ListTagsRequest,
+    ) -> Result<ListTagsResponse, RusotoError<ListTagsError>> {
+        self.list_tags_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Restores a certificate authority (CA) that is in the <code>DELETED</code> state. You can restore a CA during the period that you defined in the <b>PermanentDeletionTimeInDays</b> parameter of the <a>DeleteCertificateAuthority</a> action. Currently, you can specify 7 to 30 days. If you did not specify a <b>PermanentDeletionTimeInDays</b> value, by default you can restore the CA at any time in a 30 day period. You can check the time remaining in the restoration period of a private CA in the <code>DELETED</code> state by calling the <a>DescribeCertificateAuthority</a> or <a>ListCertificateAuthorities</a> actions. The status of a restored CA is set to its pre-deletion status when the <b>RestoreCertificateAuthority</b> action returns. To change its status to <code>ACTIVE</code>, call the <a>UpdateCertificateAuthority</a> action. If the private CA was in the <code>PENDING_CERTIFICATE</code> state at deletion, you must use the <a>ImportCertificateAuthorityCertificate</a> action to import a certificate authority into the private CA before it can be activated. You cannot restore a CA after the restoration period has ended.</p>
+    async fn restore_certificate_authority(
+        &self,
+        input: RestoreCertificateAuthorityRequest,
+    ) -> Result<(), RusotoError<RestoreCertificateAuthorityError>> {
+        self.restore_certificate_authority_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Revokes a certificate that was issued inside ACM Private CA. If you enable a certificate revocation list (CRL) when you create or update your private CA, information about the revoked certificates will be included in the CRL. ACM Private CA writes the CRL to an S3 bucket that you specify. For more information about revocation, see the <a>CrlConfiguration</a> structure. ACM Private CA also writes revocation information to the audit report. For more information, see <a>CreateCertificateAuthorityAuditReport</a>. </p> <note> <p>You cannot revoke a root CA self-signed certificate.</p> </note></p>
+    async fn revoke_certificate(
+        &self,
+        input: RevokeCertificateRequest,
+    ) -> Result<(), RusotoError<RevokeCertificateError>> {
+        self.revoke_certificate_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Adds one or more tags to your private CA. Tags are labels that you can use to identify and organize your AWS resources. Each tag consists of a key and an optional value. You specify the private CA on input by its Amazon Resource Name (ARN). You specify the tag by using a key-value pair. You can apply a tag to just one private CA if you want to identify a specific characteristic of that CA, or you can apply the same tag to multiple private CAs if you want to filter for a common relationship among those CAs. To remove one or more tags, use the <a>UntagCertificateAuthority</a> action. Call the <a>ListTags</a> action to see what tags are associated with your CA. </p>
+    async fn tag_certificate_authority(
+        &self,
+        input: TagCertificateAuthorityRequest,
+    ) -> Result<(), RusotoError<TagCertificateAuthorityError>> {
+        self.tag_certificate_authority_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Remove one or more tags from your private CA. A tag consists of a key-value pair. If you do not specify the value portion of the tag when calling this action, the tag will be removed regardless of value. If you specify a value, the tag is removed only if it is associated with the specified value. To add tags to a private CA, use the <a>TagCertificateAuthority</a>. Call the <a>ListTags</a> action to see what tags are associated with your CA. </p>
+    async fn untag_certificate_authority(
+        &self,
+        input: UntagCertificateAuthorityRequest,
+    ) -> Result<(), RusotoError<UntagCertificateAuthorityError>> {
+        self.untag_certificate_authority_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Updates the status or configuration of a private certificate authority (CA). Your private CA must be in the <code>ACTIVE</code> or <code>DISABLED</code> state before you can update it. You can disable a private CA that is in the <code>ACTIVE</code> state or make a CA that is in the <code>DISABLED</code> state active again.</p>
+    async fn update_certificate_authority(
+        &self,
+        input: UpdateCertificateAuthorityRequest,
+    ) -> Result<(), RusotoError<UpdateCertificateAuthorityError>> {
+        self.update_certificate_authority_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
     }
 }
@@ -16,8 +16,8 @@ use std::fmt;
 use async_trait::async_trait;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
-use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest, ResponseMetadata};
+use rusoto_core::{Client, ErrorMetadata, RusotoError};
 
 use rusoto_core::proto;
 use rusoto_core::signature::SignedRequest;
@@ -565,6 +565,7 @@ pub struct UpdateCertificateOptionsRequest {
 
 /// Errors returned by AddTagsToCertificate
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum AddTagsToCertificateError {
     /// <p>The requested Amazon Resource Name (ARN) does not refer to an existing resource.</p>
     InvalidArn(String),
@@ -578,6 +579,8 @@ pub enum AddTagsToCertificateError {
     TagPolicy(String),
     /// <p>The request contains too many tags. Try the request again with fewer tags.</p>
     TooManyTags(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl AddTagsToCertificateError {
@@ -585,29 +588,43 @@ impl AddTagsToCertificateError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidArnException" => {
-                    return RusotoError::Service(AddTagsToCertificateError::InvalidArn(err.msg))
+                    return RusotoError::service(
+                        AddTagsToCertificateError::InvalidArn(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(AddTagsToCertificateError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        AddTagsToCertificateError::InvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidTagException" => {
-                    return RusotoError::Service(AddTagsToCertificateError::InvalidTag(err.msg))
+                    return RusotoError::service(
+                        AddTagsToCertificateError::InvalidTag(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(AddTagsToCertificateError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        AddTagsToCertificateError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "TagPolicyException" => {
-                    return RusotoError::Service(AddTagsToCertificateError::TagPolicy(err.msg))
+                    return RusotoError::service(
+                        AddTagsToCertificateError::TagPolicy(err.msg),
+                        &res,
+                    )
                 }
                 "TooManyTagsException" => {
-                    return RusotoError::Service(AddTagsToCertificateError::TooManyTags(err.msg))
+                    return RusotoError::service(
+                        AddTagsToCertificateError::TooManyTags(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(AddTagsToCertificateError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -623,12 +640,14 @@ impl fmt::Display for AddTagsToCertificateError {
             AddTagsToCertificateError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             AddTagsToCertificateError::TagPolicy(ref cause) => write!(f, "{}", cause),
             AddTagsToCertificateError::TooManyTags(ref cause) => write!(f, "{}", cause),
+            AddTagsToCertificateError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for AddTagsToCertificateError {}
 /// Errors returned by DeleteCertificate
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteCertificateError {
     /// <p>The requested Amazon Resource Name (ARN) does not refer to an existing resource.</p>
     InvalidArn(String),
@@ -636,6 +655,8 @@ pub enum DeleteCertificateError {
     ResourceInUse(String),
     /// <p>The specified certificate cannot be found in the caller's account or the caller's account cannot be found.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteCertificateError {
@@ -643,16 +664,22 @@ impl DeleteCertificateError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidArnException" => {
-                    return RusotoError::Service(DeleteCertificateError::InvalidArn(err.msg))
+                    return RusotoError::service(DeleteCertificateError::InvalidArn(err.msg), &res)
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(DeleteCertificateError::ResourceInUse(err.msg))
+                    return RusotoError::service(
+                        DeleteCertificateError::ResourceInUse(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteCertificateError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        DeleteCertificateError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteCertificateError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -665,17 +692,21 @@ impl fmt::Display for DeleteCertificateError {
             DeleteCertificateError::InvalidArn(ref cause) => write!(f, "{}", cause),
             DeleteCertificateError::ResourceInUse(ref cause) => write!(f, "{}", cause),
             DeleteCertificateError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            DeleteCertificateError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteCertificateError {}
 /// Errors returned by DescribeCertificate
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeCertificateError {
     /// <p>The requested Amazon Resource Name (ARN) does not refer to an existing resource.</p>
     InvalidArn(String),
     /// <p>The specified certificate cannot be found in the caller's account or the caller's account cannot be found.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeCertificateError {
@@ -683,15 +714,19 @@ impl DescribeCertificateError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidArnException" => {
-                    return RusotoError::Service(DescribeCertificateError::InvalidArn(err.msg))
+                    return RusotoError::service(
+                        DescribeCertificateError::InvalidArn(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeCertificateError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeCertificateError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DescribeCertificateError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -703,12 +738,14 @@ impl fmt::Display for DescribeCertificateError {
         match *self {
             DescribeCertificateError::InvalidArn(ref cause) => write!(f, "{}", cause),
             DescribeCertificateError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            DescribeCertificateError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeCertificateError {}
 /// Errors returned by ExportCertificate
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ExportCertificateError {
     /// <p>The requested Amazon Resource Name (ARN) does not refer to an existing resource.</p>
     InvalidArn(String),
@@ -716,6 +753,8 @@ pub enum ExportCertificateError {
     RequestInProgress(String),
     /// <p>The specified certificate cannot be found in the caller's account or the caller's account cannot be found.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ExportCertificateError {
@@ -723,16 +762,22 @@ impl ExportCertificateError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidArnException" => {
-                    return RusotoError::Service(ExportCertificateError::InvalidArn(err.msg))
+                    return RusotoError::service(ExportCertificateError::InvalidArn(err.msg), &res)
                 }
                 "RequestInProgressException" => {
-                    return RusotoError::Service(ExportCertificateError::RequestInProgress(err.msg))
+                    return RusotoError::service(
+                        ExportCertificateError::RequestInProgress(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ExportCertificateError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        ExportCertificateError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ExportCertificateError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -745,12 +790,14 @@ impl fmt::Display for ExportCertificateError {
             ExportCertificateError::InvalidArn(ref cause) => write!(f, "{}", cause),
             ExportCertificateError::RequestInProgress(ref cause) => write!(f, "{}", cause),
             ExportCertificateError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            ExportCertificateError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ExportCertificateError {}
 /// Errors returned by GetCertificate
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetCertificateError {
     /// <p>The requested Amazon Resource Name (ARN) does not refer to an existing resource.</p>
     InvalidArn(String),
@@ -758,6 +805,8 @@ pub enum GetCertificateError {
     RequestInProgress(String),
     /// <p>The specified certificate cannot be found in the caller's account or the caller's account cannot be found.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetCertificateError {
@@ -765,16 +814,22 @@ impl GetCertificateError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidArnException" => {
-                    return RusotoError::Service(GetCertificateError::InvalidArn(err.msg))
+                    return RusotoError::service(GetCertificateError::InvalidArn(err.msg), &res)
                 }
                 "RequestInProgressException" => {
-                    return RusotoError::Service(GetCertificateError::RequestInProgress(err.msg))
+                    return RusotoError::service(
+                        GetCertificateError::RequestInProgress(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetCertificateError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        GetCertificateError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetCertificateError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -787,12 +842,14 @@ impl fmt::Display for GetCertificateError {
             GetCertificateError::InvalidArn(ref cause) => write!(f, "{}", cause),
             GetCertificateError::RequestInProgress(ref cause) => write!(f, "{}", cause),
             GetCertificateError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            GetCertificateError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetCertificateError {}
 /// Errors returned by ImportCertificate
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ImportCertificateError {
     /// <p>An input parameter was invalid.</p>
     InvalidParameter(String),
@@ -806,6 +863,8 @@ pub enum ImportCertificateError {
     TagPolicy(String),
     /// <p>The request contains too many tags. Try the request again with fewer tags.</p>
     TooManyTags(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ImportCertificateError {
@@ -813,25 +872,34 @@ impl ImportCertificateError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParameterException" => {
-                    return RusotoError::Service(ImportCertificateError::InvalidParameter(err.msg))
+                    return RusotoError::service(
+                        ImportCertificateError::InvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidTagException" => {
-                    return RusotoError::Service(ImportCertificateError::InvalidTag(err.msg))
+                    return RusotoError::service(ImportCertificateError::InvalidTag(err.msg), &res)
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(ImportCertificateError::LimitExceeded(err.msg))
+                    return RusotoError::service(
+                        ImportCertificateError::LimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ImportCertificateError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        ImportCertificateError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "TagPolicyException" => {
-                    return RusotoError::Service(ImportCertificateError::TagPolicy(err.msg))
+                    return RusotoError::service(ImportCertificateError::TagPolicy(err.msg), &res)
                 }
                 "TooManyTagsException" => {
-                    return RusotoError::Service(ImportCertificateError::TooManyTags(err.msg))
+                    return RusotoError::service(ImportCertificateError::TooManyTags(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ImportCertificateError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -847,15 +915,19 @@ impl fmt::Display for ImportCertificateError {
             ImportCertificateError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             ImportCertificateError::TagPolicy(ref cause) => write!(f, "{}", cause),
             ImportCertificateError::TooManyTags(ref cause) => write!(f, "{}", cause),
+            ImportCertificateError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ImportCertificateError {}
 /// Errors returned by ListCertificates
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListCertificatesError {
     /// <p>One or more of of request parameters specified is not valid.</p>
     InvalidArgs(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListCertificatesError {
@@ -863,10 +935,10 @@ impl ListCertificatesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidArgsException" => {
-                    return RusotoError::Service(ListCertificatesError::InvalidArgs(err.msg))
+                    return RusotoError::service(ListCertificatesError::InvalidArgs(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListCertificatesError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -877,17 +949,21 @@ impl fmt::Display for ListCertificatesError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             ListCertificatesError::InvalidArgs(ref cause) => write!(f, "{}", cause),
+            ListCertificatesError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListCertificatesError {}
 /// Errors returned by ListTagsForCertificate
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListTagsForCertificateError {
     /// <p>The requested Amazon Resource Name (ARN) does not refer to an existing resource.</p>
     InvalidArn(String),
     /// <p>The specified certificate cannot be found in the caller's account or the caller's account cannot be found.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListTagsForCertificateError {
@@ -895,15 +971,19 @@ impl ListTagsForCertificateError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidArnException" => {
-                    return RusotoError::Service(ListTagsForCertificateError::InvalidArn(err.msg))
+                    return RusotoError::service(
+                        ListTagsForCertificateError::InvalidArn(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListTagsForCertificateError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListTagsForCertificateError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListTagsForCertificateError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -915,12 +995,14 @@ impl fmt::Display for ListTagsForCertificateError {
         match *self {
             ListTagsForCertificateError::InvalidArn(ref cause) => write!(f, "{}", cause),
             ListTagsForCertificateError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            ListTagsForCertificateError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListTagsForCertificateError {}
 /// Errors returned by RemoveTagsFromCertificate
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum RemoveTagsFromCertificateError {
     /// <p>The requested Amazon Resource Name (ARN) does not refer to an existing resource.</p>
     InvalidArn(String),
@@ -932,6 +1014,8 @@ pub enum RemoveTagsFromCertificateError {
     ResourceNotFound(String),
     /// <p>A specified tag did not comply with an existing tag policy and was rejected.</p>
     TagPolicy(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl RemoveTagsFromCertificateError {
@@ -939,30 +1023,39 @@ impl RemoveTagsFromCertificateError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidArnException" => {
-                    return RusotoError::Service(RemoveTagsFromCertificateError::InvalidArn(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        RemoveTagsFromCertificateError::InvalidArn(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(RemoveTagsFromCertificateError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        RemoveTagsFromCertificateError::InvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidTagException" => {
-                    return RusotoError::Service(RemoveTagsFromCertificateError::InvalidTag(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        RemoveTagsFromCertificateError::InvalidTag(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(RemoveTagsFromCertificateError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        RemoveTagsFromCertificateError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "TagPolicyException" => {
-                    return RusotoError::Service(RemoveTagsFromCertificateError::TagPolicy(err.msg))
+                    return RusotoError::service(
+                        RemoveTagsFromCertificateError::TagPolicy(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(RemoveTagsFromCertificateError::Unhandled, &res)
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -977,17 +1070,21 @@ impl fmt::Display for RemoveTagsFromCertificateError {
             RemoveTagsFromCertificateError::InvalidTag(ref cause) => write!(f, "{}", cause),
             RemoveTagsFromCertificateError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             RemoveTagsFromCertificateError::TagPolicy(ref cause) => write!(f, "{}", cause),
+            RemoveTagsFromCertificateError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for RemoveTagsFromCertificateError {}
 /// Errors returned by RenewCertificate
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum RenewCertificateError {
     /// <p>The requested Amazon Resource Name (ARN) does not refer to an existing resource.</p>
     InvalidArn(String),
     /// <p>The specified certificate cannot be found in the caller's account or the caller's account cannot be found.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl RenewCertificateError {
@@ -995,13 +1092,16 @@ impl RenewCertificateError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidArnException" => {
-                    return RusotoError::Service(RenewCertificateError::InvalidArn(err.msg))
+                    return RusotoError::service(RenewCertificateError::InvalidArn(err.msg), &res)
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(RenewCertificateError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        RenewCertificateError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(RenewCertificateError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1013,12 +1113,14 @@ impl fmt::Display for RenewCertificateError {
         match *self {
             RenewCertificateError::InvalidArn(ref cause) => write!(f, "{}", cause),
             RenewCertificateError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            RenewCertificateError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for RenewCertificateError {}
 /// Errors returned by RequestCertificate
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum RequestCertificateError {
     /// <p>The requested Amazon Resource Name (ARN) does not refer to an existing resource.</p>
     InvalidArn(String),
@@ -1034,6 +1136,8 @@ pub enum RequestCertificateError {
     TagPolicy(String),
     /// <p>The request contains too many tags. Try the request again with fewer tags.</p>
     TooManyTags(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl RequestCertificateError {
@@ -1041,30 +1145,40 @@ impl RequestCertificateError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidArnException" => {
-                    return RusotoError::Service(RequestCertificateError::InvalidArn(err.msg))
+                    return RusotoError::service(RequestCertificateError::InvalidArn(err.msg), &res)
                 }
                 "InvalidDomainValidationOptionsException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         RequestCertificateError::InvalidDomainValidationOptions(err.msg),
+                        &res,
                     )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(RequestCertificateError::InvalidParameter(err.msg))
+                    return RusotoError::service(
+                        RequestCertificateError::InvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidTagException" => {
-                    return RusotoError::Service(RequestCertificateError::InvalidTag(err.msg))
+                    return RusotoError::service(RequestCertificateError::InvalidTag(err.msg), &res)
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(RequestCertificateError::LimitExceeded(err.msg))
+                    return RusotoError::service(
+                        RequestCertificateError::LimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "TagPolicyException" => {
-                    return RusotoError::Service(RequestCertificateError::TagPolicy(err.msg))
+                    return RusotoError::service(RequestCertificateError::TagPolicy(err.msg), &res)
                 }
                 "TooManyTagsException" => {
-                    return RusotoError::Service(RequestCertificateError::TooManyTags(err.msg))
+                    return RusotoError::service(
+                        RequestCertificateError::TooManyTags(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(RequestCertificateError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1083,12 +1197,14 @@ impl fmt::Display for RequestCertificateError {
             RequestCertificateError::LimitExceeded(ref cause) => write!(f, "{}", cause),
             RequestCertificateError::TagPolicy(ref cause) => write!(f, "{}", cause),
             RequestCertificateError::TooManyTags(ref cause) => write!(f, "{}", cause),
+            RequestCertificateError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for RequestCertificateError {}
 /// Errors returned by ResendValidationEmail
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ResendValidationEmailError {
     /// <p>The requested Amazon Resource Name (ARN) does not refer to an existing resource.</p>
     InvalidArn(String),
@@ -1098,6 +1214,8 @@ pub enum ResendValidationEmailError {
     InvalidState(String),
     /// <p>The specified certificate cannot be found in the caller's account or the caller's account cannot be found.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ResendValidationEmailError {
@@ -1105,23 +1223,31 @@ impl ResendValidationEmailError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidArnException" => {
-                    return RusotoError::Service(ResendValidationEmailError::InvalidArn(err.msg))
+                    return RusotoError::service(
+                        ResendValidationEmailError::InvalidArn(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidDomainValidationOptionsException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         ResendValidationEmailError::InvalidDomainValidationOptions(err.msg),
+                        &res,
                     )
                 }
                 "InvalidStateException" => {
-                    return RusotoError::Service(ResendValidationEmailError::InvalidState(err.msg))
+                    return RusotoError::service(
+                        ResendValidationEmailError::InvalidState(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ResendValidationEmailError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ResendValidationEmailError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ResendValidationEmailError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1137,12 +1263,14 @@ impl fmt::Display for ResendValidationEmailError {
             }
             ResendValidationEmailError::InvalidState(ref cause) => write!(f, "{}", cause),
             ResendValidationEmailError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            ResendValidationEmailError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ResendValidationEmailError {}
 /// Errors returned by UpdateCertificateOptions
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdateCertificateOptionsError {
     /// <p>The requested Amazon Resource Name (ARN) does not refer to an existing resource.</p>
     InvalidArn(String),
@@ -1152,6 +1280,8 @@ pub enum UpdateCertificateOptionsError {
     LimitExceeded(String),
     /// <p>The specified certificate cannot be found in the caller's account or the caller's account cannot be found.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdateCertificateOptionsError {
@@ -1159,25 +1289,31 @@ impl UpdateCertificateOptionsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidArnException" => {
-                    return RusotoError::Service(UpdateCertificateOptionsError::InvalidArn(err.msg))
+                    return RusotoError::service(
+                        UpdateCertificateOptionsError::InvalidArn(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidStateException" => {
-                    return RusotoError::Service(UpdateCertificateOptionsError::InvalidState(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateCertificateOptionsError::InvalidState(err.msg),
+                        &res,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(UpdateCertificateOptionsError::LimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateCertificateOptionsError::LimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateCertificateOptionsError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateCertificateOptionsError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UpdateCertificateOptionsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1191,6 +1327,7 @@ impl fmt::Display for UpdateCertificateOptionsError {
             UpdateCertificateOptionsError::InvalidState(ref cause) => write!(f, "{}", cause),
             UpdateCertificateOptionsError::LimitExceeded(ref cause) => write!(f, "{}", cause),
             UpdateCertificateOptionsError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            UpdateCertificateOptionsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
@@ -1287,6 +1424,7 @@ impl AcmClient {
     /// Creates a client backed by the default tokio event loop.
     ///
     /// The client will use the default credentials provider and tls client.
+    #[cfg(not(target_arch = "wasm32"))]
     pub fn new(region: region::Region) -> AcmClient {
         AcmClient {
             client: Client::shared(),
@@ -1312,237 +1450,489 @@ impl AcmClient {
     pub fn new_with_client(client: Client, region: region::Region) -> AcmClient {
         AcmClient { client, region }
     }
+
+    /// Returns a builder of a client, setting any of its region, credentials provider,
+    /// dispatcher, retry policy, endpoint and timeouts.
+    pub fn builder() -> rusoto_core::ClientBuilder<AcmClient> {
+        rusoto_core::ClientBuilder::new(AcmClient::new_with_client)
+    }
+
+    /// Returns a clone of this client with the overrides of `config` applied to its
+    /// operations, sharing its connections.
+    pub fn customize(&self, config: &rusoto_core::OperationConfig) -> AcmClient {
+        AcmClient {
+            client: self.client.customize(config),
+            region: config.resolve_region(&self.region),
+        }
+    }
 }
 
-#[async_trait]
-impl Acm for AcmClient {
-    /// <p>Adds one or more tags to an ACM certificate. Tags are labels that you can use to identify and organize your AWS resources. Each tag consists of a <code>key</code> and an optional <code>value</code>. You specify the certificate on input by its Amazon Resource Name (ARN). You specify the tag by using a key-value pair. </p> <p>You can apply a tag to just one certificate if you want to identify a specific characteristic of that certificate, or you can apply the same tag to multiple certificates if you want to filter for a common relationship among those certificates. Similarly, you can apply the same tag to multiple resources if you want to specify a relationship among those resources. For example, you can add the same tag to an ACM certificate and an Elastic Load Balancing load balancer to indicate that they are both used by the same website. For more information, see <a href="https://docs.aws.amazon.com/acm/latest/userguide/tags.html">Tagging ACM certificates</a>. </p> <p>To remove one or more tags, use the <a>RemoveTagsFromCertificate</a> action. To view all of the tags that have been applied to the certificate, use the <a>ListTagsForCertificate</a> action. </p>
-    async fn add_tags_to_certificate(
+impl AcmClient {
+    /// Calls `add_tags_to_certificate`, also returning the metadata of the response.
+    pub async fn add_tags_to_certificate_with_metadata(
         &self,
         input: AddTagsToCertificateRequest,
-    ) -> Result<(), RusotoError<AddTagsToCertificateError>> {
+    ) -> Result<((), ResponseMetadata), RusotoError<AddTagsToCertificateError>> {
+        let request = self.build_add_tags_to_certificate_request(input);
+
+        let mut response = self
+            .client
+            .sign_and_dispatch(request)
+            .await
+            .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
+        if response.status.is_success() {
+            std::mem::drop(response);
+            Ok(((), metadata))
+        } else {
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(AddTagsToCertificateError::from_response(response))
+        }
+    }
+
+    /// Presigns the request `add_tags_to_certificate` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_add_tags_to_certificate(
+        &self,
+        presigner: &rusoto_core::Presigner,
+        input: AddTagsToCertificateRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_add_tags_to_certificate_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_add_tags_to_certificate_request(
+        &self,
+        input: AddTagsToCertificateRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "acm", &self.region, "/");
+        request.set_operation("AddTagsToCertificate");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "CertificateManager.AddTagsToCertificate");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `delete_certificate`, also returning the metadata of the response.
+    pub async fn delete_certificate_with_metadata(
+        &self,
+        input: DeleteCertificateRequest,
+    ) -> Result<((), ResponseMetadata), RusotoError<DeleteCertificateError>> {
+        let request = self.build_delete_certificate_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
             std::mem::drop(response);
-            Ok(())
+            Ok(((), metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(AddTagsToCertificateError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DeleteCertificateError::from_response(response))
         }
     }
 
-    /// <p><p>Deletes a certificate and its associated private key. If this action succeeds, the certificate no longer appears in the list that can be displayed by calling the <a>ListCertificates</a> action or be retrieved by calling the <a>GetCertificate</a> action. The certificate will not be available for use by AWS services integrated with ACM. </p> <note> <p>You cannot delete an ACM certificate that is being used by another AWS service. To delete a certificate that is in use, the certificate association must first be removed.</p> </note></p>
-    async fn delete_certificate(
+    /// Presigns the request `delete_certificate` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_certificate(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteCertificateRequest,
-    ) -> Result<(), RusotoError<DeleteCertificateError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_certificate_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_certificate_request(&self, input: DeleteCertificateRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "acm", &self.region, "/");
+        request.set_operation("DeleteCertificate");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "CertificateManager.DeleteCertificate");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `describe_certificate`, also returning the metadata of the response.
+    pub async fn describe_certificate_with_metadata(
+        &self,
+        input: DescribeCertificateRequest,
+    ) -> Result<
+        (DescribeCertificateResponse, ResponseMetadata),
+        RusotoError<DescribeCertificateError>,
+    > {
+        let request = self.build_describe_certificate_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            std::mem::drop(response);
-            Ok(())
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DescribeCertificateResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteCertificateError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DescribeCertificateError::from_response(response))
         }
     }
 
-    /// <p>Returns detailed metadata about the specified ACM certificate.</p>
-    async fn describe_certificate(
+    /// Presigns the request `describe_certificate` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_certificate(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeCertificateRequest,
-    ) -> Result<DescribeCertificateResponse, RusotoError<DescribeCertificateError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_certificate_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_certificate_request(
+        &self,
+        input: DescribeCertificateRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "acm", &self.region, "/");
+        request.set_operation("DescribeCertificate");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "CertificateManager.DescribeCertificate");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `export_certificate`, also returning the metadata of the response.
+    pub async fn export_certificate_with_metadata(
+        &self,
+        input: ExportCertificateRequest,
+    ) -> Result<(ExportCertificateResponse, ResponseMetadata), RusotoError<ExportCertificateError>>
+    {
+        let request = self.build_export_certificate_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DescribeCertificateResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ExportCertificateResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeCertificateError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ExportCertificateError::from_response(response))
         }
     }
 
-    /// <p>Exports a private certificate issued by a private certificate authority (CA) for use anywhere. The exported file contains the certificate, the certificate chain, and the encrypted private 2048-bit RSA key associated with the public key that is embedded in the certificate. For security, you must assign a passphrase for the private key when exporting it. </p> <p>For information about exporting and formatting a certificate using the ACM console or CLI, see <a href="https://docs.aws.amazon.com/acm/latest/userguide/gs-acm-export-private.html">Export a Private Certificate</a>.</p>
-    async fn export_certificate(
+    /// Presigns the request `export_certificate` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_export_certificate(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ExportCertificateRequest,
-    ) -> Result<ExportCertificateResponse, RusotoError<ExportCertificateError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_export_certificate_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_export_certificate_request(&self, input: ExportCertificateRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "acm", &self.region, "/");
+        request.set_operation("ExportCertificate");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "CertificateManager.ExportCertificate");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `get_certificate`, also returning the metadata of the response.
+    pub async fn get_certificate_with_metadata(
+        &self,
+        input: GetCertificateRequest,
+    ) -> Result<(GetCertificateResponse, ResponseMetadata), RusotoError<GetCertificateError>> {
+        let request = self.build_get_certificate_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<ExportCertificateResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<GetCertificateResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ExportCertificateError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(GetCertificateError::from_response(response))
         }
     }
 
-    /// <p>Retrieves a certificate specified by an ARN and its certificate chain . The chain is an ordered list of certificates that contains the end entity certificate, intermediate certificates of subordinate CAs, and the root certificate in that order. The certificate and certificate chain are base64 encoded. If you want to decode the certificate to see the individual fields, you can use OpenSSL.</p>
-    async fn get_certificate(
+    /// Presigns the request `get_certificate` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_certificate(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetCertificateRequest,
-    ) -> Result<GetCertificateResponse, RusotoError<GetCertificateError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_certificate_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_certificate_request(&self, input: GetCertificateRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "acm", &self.region, "/");
+        request.set_operation("GetCertificate");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "CertificateManager.GetCertificate");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `import_certificate`, also returning the metadata of the response.
+    pub async fn import_certificate_with_metadata(
+        &self,
+        input: ImportCertificateRequest,
+    ) -> Result<(ImportCertificateResponse, ResponseMetadata), RusotoError<ImportCertificateError>>
+    {
+        let request = self.build_import_certificate_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<GetCertificateResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ImportCertificateResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(GetCertificateError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ImportCertificateError::from_response(response))
         }
     }
 
-    /// <p>Imports a certificate into AWS Certificate Manager (ACM) to use with services that are integrated with ACM. Note that <a href="https://docs.aws.amazon.com/acm/latest/userguide/acm-services.html">integrated services</a> allow only certificate types and keys they support to be associated with their resources. Further, their support differs depending on whether the certificate is imported into IAM or into ACM. For more information, see the documentation for each service. For more information about importing certificates into ACM, see <a href="https://docs.aws.amazon.com/acm/latest/userguide/import-certificate.html">Importing Certificates</a> in the <i>AWS Certificate Manager User Guide</i>. </p> <note> <p>ACM does not provide <a href="https://docs.aws.amazon.com/acm/latest/userguide/acm-renewal.html">managed renewal</a> for certificates that you import.</p> </note> <p>Note the following guidelines when importing third party certificates:</p> <ul> <li> <p>You must enter the private key that matches the certificate you are importing.</p> </li> <li> <p>The private key must be unencrypted. You cannot import a private key that is protected by a password or a passphrase.</p> </li> <li> <p>If the certificate you are importing is not self-signed, you must enter its certificate chain.</p> </li> <li> <p>If a certificate chain is included, the issuer must be the subject of one of the certificates in the chain.</p> </li> <li> <p>The certificate, private key, and certificate chain must be PEM-encoded.</p> </li> <li> <p>The current time must be between the <code>Not Before</code> and <code>Not After</code> certificate fields.</p> </li> <li> <p>The <code>Issuer</code> field must not be empty.</p> </li> <li> <p>The OCSP authority URL, if present, must not exceed 1000 characters.</p> </li> <li> <p>To import a new certificate, omit the <code>CertificateArn</code> argument. Include this argument only when you want to replace a previously imported certifica</p> </li> <li> <p>When you import a certificate by using the CLI, you must specify the certificate, the certificate chain, and the private key by their file names preceded by <code>file://</code>. For example, you can specify a certificate saved in the <code>C:\temp</code> folder as <code>file://C:\temp\certificate_to_import.pem</code>. If you are making an HTTP or HTTPS Query request, include these arguments as BLOBs. </p> </li> <li> <p>When you import a certificate by using an SDK, you must specify the certificate, the certificate chain, and the private key files in the manner required by the programming language you're using. </p> </li> <li> <p>The cryptographic algorithm of an imported certificate must match the algorithm of the signing CA. For example, if the signing CA key type is RSA, then the certificate key type must also be RSA.</p> </li> </ul> <p>This operation returns the <a href="https://docs.aws.amazon.com/general/latest/gr/aws-arns-and-namespaces.html">Amazon Resource Name (ARN)</a> of the imported certificate.</p>
-    async fn import_certificate(
+    /// Presigns the request `import_certificate` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_import_certificate(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ImportCertificateRequest,
-    ) -> Result<ImportCertificateResponse, RusotoError<ImportCertificateError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_import_certificate_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_import_certificate_request(&self, input: ImportCertificateRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "acm", &self.region, "/");
+        request.set_operation("ImportCertificate");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "CertificateManager.ImportCertificate");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `list_certificates`, also returning the metadata of the response.
+    pub async fn list_certificates_with_metadata(
+        &self,
+        input: ListCertificatesRequest,
+    ) -> Result<(ListCertificatesResponse, ResponseMetadata), RusotoError<ListCertificatesError>>
+    {
+        let request = self.build_list_certificates_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<ImportCertificateResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ListCertificatesResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ImportCertificateError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ListCertificatesError::from_response(response))
         }
     }
 
-    /// <p>Retrieves a list of certificate ARNs and domain names. You can request that only certificates that match a specific status be listed. You can also filter by specific attributes of the certificate. Default filtering returns only <code>RSA_2048</code> certificates. For more information, see <a>Filters</a>.</p>
-    async fn list_certificates(
+    /// Presigns the request `list_certificates` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_certificates(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListCertificatesRequest,
-    ) -> Result<ListCertificatesResponse, RusotoError<ListCertificatesError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_certificates_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_certificates_request(&self, input: ListCertificatesRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "acm", &self.region, "/");
+        request.set_operation("ListCertificates");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "CertificateManager.ListCertificates");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `list_tags_for_certificate`, also returning the metadata of the response.
+    pub async fn list_tags_for_certificate_with_metadata(
+        &self,
+        input: ListTagsForCertificateRequest,
+    ) -> Result<
+        (ListTagsForCertificateResponse, ResponseMetadata),
+        RusotoError<ListTagsForCertificateError>,
+    > {
+        let request = self.build_list_tags_for_certificate_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListCertificatesResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ListTagsForCertificateResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ListCertificatesError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ListTagsForCertificateError::from_response(response))
         }
     }
 
-    /// <p>Lists the tags that have been applied to the ACM certificate. Use the certificate's Amazon Resource Name (ARN) to specify the certificate. To add a tag to an ACM certificate, use the <a>AddTagsToCertificate</a> action. To delete a tag, use the <a>RemoveTagsFromCertificate</a> action. </p>
-    async fn list_tags_for_certificate(
+    /// Presigns the request `list_tags_for_certificate` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_tags_for_certificate(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListTagsForCertificateRequest,
-    ) -> Result<ListTagsForCertificateResponse, RusotoError<ListTagsForCertificateError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_tags_for_certificate_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_tags_for_certificate_request(
+        &self,
+        input: ListTagsForCertificateRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "acm", &self.region, "/");
+        request.set_operation("ListTagsForCertificate");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "CertificateManager.ListTagsForCertificate");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `remove_tags_from_certificate`, also returning the metadata of the response.
+    pub async fn remove_tags_from_certificate_with_metadata(
+        &self,
+        input: RemoveTagsFromCertificateRequest,
+    ) -> Result<((), ResponseMetadata), RusotoError<RemoveTagsFromCertificateError>> {
+        let request = self.build_remove_tags_from_certificate_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListTagsForCertificateResponse, _>()
+            std::mem::drop(response);
+            Ok(((), metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ListTagsForCertificateError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(RemoveTagsFromCertificateError::from_response(response))
         }
     }
 
-    /// <p>Remove one or more tags from an ACM certificate. A tag consists of a key-value pair. If you do not specify the value portion of the tag when calling this function, the tag will be removed regardless of value. If you specify a value, the tag is removed only if it is associated with the specified value. </p> <p>To add tags to a certificate, use the <a>AddTagsToCertificate</a> action. To view all of the tags that have been applied to a specific ACM certificate, use the <a>ListTagsForCertificate</a> action. </p>
-    async fn remove_tags_from_certificate(
+    /// Presigns the request `remove_tags_from_certificate` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_remove_tags_from_certificate(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: RemoveTagsFromCertificateRequest,
-    ) -> Result<(), RusotoError<RemoveTagsFromCertificateError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_remove_tags_from_certificate_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_remove_tags_from_certificate_request(
+        &self,
+        input: RemoveTagsFromCertificateRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "acm", &self.region, "/");
+        request.set_operation("RemoveTagsFromCertificate");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -1552,109 +1942,200 @@ impl Acm for AcmClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `renew_certificate`, also returning the metadata of the response.
+    pub async fn renew_certificate_with_metadata(
+        &self,
+        input: RenewCertificateRequest,
+    ) -> Result<((), ResponseMetadata), RusotoError<RenewCertificateError>> {
+        let request = self.build_renew_certificate_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
             std::mem::drop(response);
-            Ok(())
+            Ok(((), metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(RemoveTagsFromCertificateError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(RenewCertificateError::from_response(response))
         }
     }
 
-    /// <p>Renews an eligable ACM certificate. At this time, only exported private certificates can be renewed with this operation. In order to renew your ACM PCA certificates with ACM, you must first <a href="https://docs.aws.amazon.com/acm-pca/latest/userguide/PcaPermissions.html">grant the ACM service principal permission to do so</a>. For more information, see <a href="https://docs.aws.amazon.com/acm/latest/userguide/manual-renewal.html">Testing Managed Renewal</a> in the ACM User Guide.</p>
-    async fn renew_certificate(
+    /// Presigns the request `renew_certificate` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_renew_certificate(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: RenewCertificateRequest,
-    ) -> Result<(), RusotoError<RenewCertificateError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_renew_certificate_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_renew_certificate_request(&self, input: RenewCertificateRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "acm", &self.region, "/");
+        request.set_operation("RenewCertificate");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "CertificateManager.RenewCertificate");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `request_certificate`, also returning the metadata of the response.
+    pub async fn request_certificate_with_metadata(
+        &self,
+        input: RequestCertificateRequest,
+    ) -> Result<(RequestCertificateResponse, ResponseMetadata), RusotoError<RequestCertificateError>>
+    {
+        let request = self.build_request_certificate_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            std::mem::drop(response);
-            Ok(())
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<RequestCertificateResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(RenewCertificateError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(RequestCertificateError::from_response(response))
         }
     }
 
-    /// <p>Requests an ACM certificate for use with other AWS services. To request an ACM certificate, you must specify a fully qualified domain name (FQDN) in the <code>DomainName</code> parameter. You can also specify additional FQDNs in the <code>SubjectAlternativeNames</code> parameter. </p> <p>If you are requesting a private certificate, domain validation is not required. If you are requesting a public certificate, each domain name that you specify must be validated to verify that you own or control the domain. You can use <a href="https://docs.aws.amazon.com/acm/latest/userguide/gs-acm-validate-dns.html">DNS validation</a> or <a href="https://docs.aws.amazon.com/acm/latest/userguide/gs-acm-validate-email.html">email validation</a>. We recommend that you use DNS validation. ACM issues public certificates after receiving approval from the domain owner. </p>
-    async fn request_certificate(
+    /// Presigns the request `request_certificate` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_request_certificate(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: RequestCertificateRequest,
-    ) -> Result<RequestCertificateResponse, RusotoError<RequestCertificateError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_request_certificate_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_request_certificate_request(&self, input: RequestCertificateRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "acm", &self.region, "/");
+        request.set_operation("RequestCertificate");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "CertificateManager.RequestCertificate");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `resend_validation_email`, also returning the metadata of the response.
+    pub async fn resend_validation_email_with_metadata(
+        &self,
+        input: ResendValidationEmailRequest,
+    ) -> Result<((), ResponseMetadata), RusotoError<ResendValidationEmailError>> {
+        let request = self.build_resend_validation_email_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<RequestCertificateResponse, _>()
+            std::mem::drop(response);
+            Ok(((), metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(RequestCertificateError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ResendValidationEmailError::from_response(response))
         }
     }
 
-    /// <p>Resends the email that requests domain ownership validation. The domain owner or an authorized representative must approve the ACM certificate before it can be issued. The certificate can be approved by clicking a link in the mail to navigate to the Amazon certificate approval website and then clicking <b>I Approve</b>. However, the validation email can be blocked by spam filters. Therefore, if you do not receive the original mail, you can request that the mail be resent within 72 hours of requesting the ACM certificate. If more than 72 hours have elapsed since your original request or since your last attempt to resend validation mail, you must request a new certificate. For more information about setting up your contact email addresses, see <a href="https://docs.aws.amazon.com/acm/latest/userguide/setup-email.html">Configure Email for your Domain</a>. </p>
-    async fn resend_validation_email(
+    /// Presigns the request `resend_validation_email` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_resend_validation_email(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ResendValidationEmailRequest,
-    ) -> Result<(), RusotoError<ResendValidationEmailError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_resend_validation_email_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_resend_validation_email_request(
+        &self,
+        input: ResendValidationEmailRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "acm", &self.region, "/");
+        request.set_operation("ResendValidationEmail");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "CertificateManager.ResendValidationEmail");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `update_certificate_options`, also returning the metadata of the response.
+    pub async fn update_certificate_options_with_metadata(
+        &self,
+        input: UpdateCertificateOptionsRequest,
+    ) -> Result<((), ResponseMetadata), RusotoError<UpdateCertificateOptionsError>> {
+        let request = self.build_update_certificate_options_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
             std::mem::drop(response);
-            Ok(())
+            Ok(((), metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ResendValidationEmailError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(UpdateCertificateOptionsError::from_response(response))
         }
     }
 
-    /// <p>Updates a certificate. Currently, you can use this function to specify whether to opt in to or out of recording your certificate in a certificate transparency log. For more information, see <a href="https://docs.aws.amazon.com/acm/latest/userguide/acm-bestpractices.html#best-practices-transparency"> Opting Out of Certificate Transparency Logging</a>. </p>
-    async fn update_certificate_options(
+    /// Presigns the request `update_certificate_options` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_certificate_options(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UpdateCertificateOptionsRequest,
-    ) -> Result<(), RusotoError<UpdateCertificateOptionsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_certificate_options_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_certificate_options_request(
+        &self,
+        input: UpdateCertificateOptionsRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "acm", &self.region, "/");
+        request.set_operation("UpdateCertificateOptions");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -1664,18 +2145,245 @@ impl Acm for AcmClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
-        let mut response = self
-            .client
-            .sign_and_dispatch(request)
+        request
+    }
+
+    /// The pages of `ListCertificates` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `MaxItems` of `input`.
+    pub fn list_certificates_pages(
+        &self,
+        input: ListCertificatesRequest,
+    ) -> impl futures::Stream<
+        Item = Result<ListCertificatesResponse, RusotoError<ListCertificatesError>>,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: AcmClient, input| async move { client.list_certificates(input).await },
+            |input: &ListCertificatesRequest, output: &ListCertificatesResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListCertificatesRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `CertificateSummaryList` of every page of `ListCertificates` results for `input`, as `list_certificates_pages` requests them.
+    pub fn list_certificates_stream(
+        &self,
+        input: ListCertificatesRequest,
+    ) -> impl futures::Stream<Item = Result<CertificateSummary, RusotoError<ListCertificatesError>>> + Send
+    {
+        rusoto_core::pagination::items(self.list_certificates_pages(input), |page| {
+            page.certificate_summary_list.unwrap_or_default()
+        })
+    }
+
+    /// Waits for `CertificateValidated`, polling `DescribeCertificate` with `input` every 60 seconds, up to 5 times.
+    pub async fn wait_until_certificate_validated(
+        &self,
+        input: DescribeCertificateRequest,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<DescribeCertificateError>> {
+        let waiter = rusoto_core::waiter::Waiter::new(std::time::Duration::from_secs(60), 5);
+        self.wait_until_certificate_validated_with(input, &waiter)
             .await
-            .map_err(RusotoError::from)?;
-        if response.status.is_success() {
-            std::mem::drop(response);
-            Ok(())
-        } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(UpdateCertificateOptionsError::from_response(response))
-        }
+    }
+
+    /// Waits for `CertificateValidated` like `wait_until_certificate_validated`, with the delay, attempts and runtime of `waiter`.
+    pub async fn wait_until_certificate_validated_with(
+        &self,
+        input: DescribeCertificateRequest,
+        waiter: &rusoto_core::waiter::Waiter,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<DescribeCertificateError>> {
+        rusoto_core::waiter::wait(
+            waiter,
+            || self.describe_certificate(input.clone()),
+            |result| {
+                if match result {
+                    Ok(output) => {
+                        let mut values = std::iter::once(output)
+                            .filter_map(|value| value.certificate.as_ref())
+                            .filter_map(|value| value.domain_validation_options.as_ref())
+                            .flat_map(|value| value.iter())
+                            .filter_map(|value| value.validation_status.as_ref())
+                            .peekable();
+                        values.peek().is_some() && values.all(|value| value == "SUCCESS")
+                    }
+                    Err(_) => false,
+                } {
+                    return Some(rusoto_core::waiter::WaiterState::Success);
+                }
+                if match result {
+                    Ok(output) => std::iter::once(output)
+                        .filter_map(|value| value.certificate.as_ref())
+                        .filter_map(|value| value.domain_validation_options.as_ref())
+                        .flat_map(|value| value.iter())
+                        .filter_map(|value| value.validation_status.as_ref())
+                        .any(|value| value == "PENDING_VALIDATION"),
+                    Err(_) => false,
+                } {
+                    return Some(rusoto_core::waiter::WaiterState::Retry);
+                }
+                if match result {
+                    Ok(output) => std::iter::once(output)
+                        .filter_map(|value| value.certificate.as_ref())
+                        .filter_map(|value| value.status.as_ref())
+                        .any(|value| value == "FAILED"),
+                    Err(_) => false,
+                } {
+                    return Some(rusoto_core::waiter::WaiterState::Failure);
+                }
+                if result
+                    .as_ref()
+                    .err()
+                    .and_then(|error| error.error_code())
+                    .as_deref()
+                    == Some("ResourceNotFoundException")
+                {
+                    return Some(rusoto_core::waiter::WaiterState::Failure);
+                }
+                None
+            },
+        )
+        .await
+    }
+}
+
+#[async_trait]
+impl Acm for AcmClient {
+    /// <p>Adds one or more tags to an ACM certificate. Tags are labels that you can use to identify and organize your AWS resources. Each tag consists of a <code>key</code> and an optional <code>value</code>. You specify the certificate on input by its Amazon Resource Name (ARN). You specify the tag by using a key-value pair. </p> <p>You can apply a tag to just one certificate if you want to identify a specific characteristic of that certificate, or you can apply the same tag to multiple certificates if you want to filter for a common relationship among those certificates. Similarly, you can apply the same tag to multiple resources if you want to specify a relationship among those resources. For example, you can add the same tag to an ACM certificate and an Elastic Load Balancing load balancer to indicate that they are both used by the same website. For more information, see <a href="https://docs.aws.amazon.com/acm/latest/userguide/tags.html">Tagging ACM certificates</a>. </p> <p>To remove one or more tags, use the <a>RemoveTagsFromCertificate</a> action. To view all of the tags that have been applied to the certificate, use the <a>ListTagsForCertificate</a> action. </p>
+    async fn add_tags_to_certificate(
+        &self,
+        input: AddTagsToCertificateRequest,
+    ) -> Result<(), RusotoError<AddTagsToCertificateError>> {
+        self.add_tags_to_certificate_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Deletes a certificate and its associated private key. If this action succeeds, the certificate no longer appears in the list that can be displayed by calling the <a>ListCertificates</a> action or be retrieved by calling the <a>GetCertificate</a> action. The certificate will not be available for use by AWS services integrated with ACM. </p> <note> <p>You cannot delete an ACM certificate that is being used by another AWS service. To delete a certificate that is in use, the certificate association must first be removed.</p> </note></p>
+    async fn delete_certificate(
+        &self,
+        input: DeleteCertificateRequest,
+    ) -> Result<(), RusotoError<DeleteCertificateError>> {
+        self.delete_certificate_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns detailed metadata about the specified ACM certificate.</p>
+    async fn describe_certificate(
+        &self,
+        input: DescribeCertificateRequest,
+    ) -> Result<DescribeCertificateResponse, RusotoError<DescribeCertificateError>> {
+        self.describe_certificate_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Exports a private certificate issued by a private certificate authority (CA) for use anywhere. The exported file contains the certificate, the certificate chain, and the encrypted private 2048-bit RSA key associated with the public key that is embedded in the certificate. For security, you must assign a passphrase for the private key when exporting it. </p> <p>For information about exporting and formatting a certificate using the ACM console or CLI, see <a href="https://docs.aws.amazon.com/acm/latest/userguide/gs-acm-export-private.html">Export a Private Certificate</a>.</p>
+    async fn export_certificate(
+        &self,
+        input: ExportCertificateRequest,
+    ) -> Result<ExportCertificateResponse, RusotoError<ExportCertificateError>> {
+        self.export_certificate_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Retrieves a certificate specified by an ARN and its certificate chain . The chain is an ordered list of certificates that contains the end entity certificate, intermediate certificates of subordinate CAs, and the root certificate in that order. The certificate and certificate chain are base64 encoded. If you want to decode the certificate to see the individual fields, you can use OpenSSL.</p>
+    async fn get_certificate(
+        &self,
+        input: GetCertificateRequest,
+    ) -> Result<GetCertificateResponse, RusotoError<GetCertificateError>> {
+        self.get_certificate_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Imports a certificate into AWS Certificate Manager (ACM) to use with services that are integrated with ACM. Note that <a href="https://docs.aws.amazon.com/acm/latest/userguide/acm-services.html">integrated services</a> allow only certificate types and keys they support to be associated with their resources. Further, their support differs depending on whether the certificate is imported into IAM or into ACM. For more information, see the documentation for each service. For more information about importing certificates into ACM, see <a href="https://docs.aws.amazon.com/acm/latest/userguide/import-certificate.html">Importing Certificates</a> in the <i>AWS Certificate Manager User Guide</i>. </p> <note> <p>ACM does not provide <a href="https://docs.aws.amazon.com/acm/latest/userguide/acm-renewal.html">managed renewal</a> for certificates that you import.</p> </note> <p>Note the following guidelines when importing third party certificates:</p> <ul> <li> <p>You must enter the private key that matches the certificate you are importing.</p> </li> <li> <p>The private key must be unencrypted. You cannot import a private key that is protected by a password or a passphrase.</p> </li> <li> <p>If the certificate you are importing is not self-signed, you must enter its certificate chain.</p> </li> <li> <p>If a certificate chain is included, the issuer must be the subject of one of the certificates in the chain.</p> </li> <li> <p>The certificate, private key, and certificate chain must be PEM-encoded.</p> </li> <li> <p>The current time must be between the <code>Not Before</code> and <code>Not After</code> certificate fields.</p> </li> <li> <p>The <code>Issuer</code> field must not be empty.</p> </li> <li> <p>The OCSP authority URL, if present, must not exceed 1000 characters.</p> </li> <li> <p>To import a new certificate, omit the <code>CertificateArn</code> argument. Include this argument only when you want to replace a previously imported certifica</p> </li> <li> <p>When you import a certificate by using the CLI, you must specify the certificate, the certificate chain, and the private key by their file names preceded by <code>file://</code>. For example, you can specify a certificate saved in the <code>C:\temp</code> folder as <code>file://C:\temp\certificate_to_import.pem</code>. If you are making an HTTP or HTTPS Query request, include these arguments as BLOBs. </p> </li> <li> <p>When you import a certificate by using an SDK, you must specify the certificate, the certificate chain, and the private key files in the manner required by the programming language you're using. </p> </li> <li> <p>The cryptographic algorithm of an imported certificate must match the algorithm of the signing CA. For example, if the signing CA key type is RSA, then the certificate key type must also be RSA.</p> </li> </ul> <p>This operation returns the <a href="https://docs.aws.amazon.com/general/latest/gr/aws-arns-and-namespaces.html">Amazon Resource Name (ARN)</a> of the imported certificate.</p>
+    async fn import_certificate(
+        &self,
+        input: ImportCertificateRequest,
+    ) -> Result<ImportCertificateResponse, RusotoError<ImportCertificateError>> {
+        self.import_certificate_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Retrieves a list of certificate ARNs and domain names. You can request that only certificates that match a specific status be listed. You can also filter by specific attributes of the certificate. Default filtering returns only <code>RSA_2048</code> certificates. For more information, see <a>Filters</a>.</p>
+    async fn list_certificates(
+        &self,
+        input: ListCertificatesRequest,
+    ) -> Result<ListCertificatesResponse, RusotoError<ListCertificatesError>> {
+        self.list_certificates_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Lists the tags that have been applied to the ACM certificate. Use the certificate's Amazon Resource Name (ARN) to specify the certificate. To add a tag to an ACM certificate, use the <a>AddTagsToCertificate</a> action. To delete a tag, use the <a>RemoveTagsFromCertificate</a> action. </p>
+    async fn list_tags_for_certificate(
+        &self,
+        input: ListTagsForCertificateRequest,
+    ) -> Result<ListTagsForCertificateResponse, RusotoError<ListTagsForCertificateError>> {
+        self.list_tags_for_certificate_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Remove one or more tags from an ACM certificate. A tag consists of a key-value pair. If you do not specify the value portion of the tag when calling this function, the tag will be removed regardless of value. If you specify a value, the tag is removed only if it is associated with the specified value. </p> <p>To add tags to a certificate, use the <a>AddTagsToCertificate</a> action. To view all of the tags that have been applied to a specific ACM certificate, use the <a>ListTagsForCertificate</a> action. </p>
+    async fn remove_tags_from_certificate(
+        &self,
+        input: RemoveTagsFromCertificateRequest,
+    ) -> Result<(), RusotoError<RemoveTagsFromCertificateError>> {
+        self.remove_tags_from_certificate_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Renews an eligable ACM certificate. At this time, only exported private certificates can be renewed with this operation. In order to renew your ACM PCA certificates with ACM, you must first <a href="https://docs.aws.amazon.com/acm-pca/latest/userguide/PcaPermissions.html">grant the ACM service principal permission to do so</a>. For more information, see <a href="https://docs.aws.amazon.com/acm/latest/userguide/manual-renewal.html">Testing Managed Renewal</a> in the ACM User Guide.</p>
+    async fn renew_certificate(
+        &self,
+        input: RenewCertificateRequest,
+    ) -> Result<(), RusotoError<RenewCertificateError>> {
+        self.renew_certificate_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Requests an ACM certificate for use with other AWS services. To request an ACM certificate, you must specify a fully qualified domain name (FQDN) in the <code>DomainName</code> parameter. You can also specify additional FQDNs in the <code>SubjectAlternativeNames</code> parameter. </p> <p>If you are requesting a private certificate, domain validation is not required. If you are requesting a public certificate, each domain name that you specify must be validated to verify that you own or control the domain. You can use <a href="https://docs.aws.amazon.com/acm/latest/userguide/gs-acm-validate-dns.html">DNS validation</a> or <a href="https://docs.aws.amazon.com/acm/latest/userguide/gs-acm-validate-email.html">email validation</a>. We recommend that you use DNS validation. ACM issues public certificates after receiving approval from the domain owner. </p>
+    async fn request_certificate(
+        &self,
+        input: RequestCertificateRequest,
+    ) -> Result<RequestCertificateResponse, RusotoError<RequestCertificateError>> {
+        self.request_certificate_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Resends the email that requests domain ownership validation. The domain owner or an authorized representative must approve the ACM certificate before it can be issued. The certificate can be approved by clicking a link in the mail to navigate to the Amazon certificate approval website and then clicking <b>I Approve</b>. However, the validation email can be blocked by spam filters. Therefore, if you do not receive the original mail, you can request that the mail be resent within 72 hours of requesting the ACM certificate. If more than 72 hours have elapsed since your original request or since your last attempt to resend validation mail, you must request a new certificate. For more information about setting up your contact email addresses, see <a href="https://docs.aws.amazon.com/acm/latest/userguide/setup-email.html">Configure Email for your Domain</a>. </p>
+    async fn resend_validation_email(
+        &self,
+        input: ResendValidationEmailRequest,
+    ) -> Result<(), RusotoError<ResendValidationEmailError>> {
+        self.resend_validation_email_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Updates a certificate. Currently, you can use this function to specify whether to opt in to or out of recording your certificate in a certificate transparency log. For more information, see <a href="https://docs.aws.amazon.com/acm/latest/userguide/acm-bestpractices.html#best-practices-transparency"> Opting Out of Certificate Transparency Logging</a>. </p>
+    async fn update_certificate_options(
+        &self,
+        input: UpdateCertificateOptionsRequest,
+    ) -> Result<(), RusotoError<UpdateCertificateOptionsError>> {
+        self.update_certificate_options_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
     }
 }
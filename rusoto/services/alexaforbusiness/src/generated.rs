@@ -16,8 +16,8 @@ use std::fmt;
 use async_trait::async_trait;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
-use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest, ResponseMetadata};
+use rusoto_core::{Client, ErrorMetadata, RusotoError};
 
 use rusoto_core::proto;
 use rusoto_core::signature::SignedRequest;
@@ -3499,6 +3499,7 @@ pub struct UserData {
 
 /// Errors returned by ApproveSkill
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ApproveSkillError {
     /// <p>There is a concurrent modification of resources.</p>
     ConcurrentModification(String),
@@ -3506,6 +3507,8 @@ pub enum ApproveSkillError {
     LimitExceeded(String),
     /// <p>The resource is not found.</p>
     NotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ApproveSkillError {
@@ -3513,16 +3516,19 @@ impl ApproveSkillError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(ApproveSkillError::ConcurrentModification(err.msg))
+                    return RusotoError::service(
+                        ApproveSkillError::ConcurrentModification(err.msg),
+                        &res,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(ApproveSkillError::LimitExceeded(err.msg))
+                    return RusotoError::service(ApproveSkillError::LimitExceeded(err.msg), &res)
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(ApproveSkillError::NotFound(err.msg))
+                    return RusotoError::service(ApproveSkillError::NotFound(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ApproveSkillError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3535,15 +3541,19 @@ impl fmt::Display for ApproveSkillError {
             ApproveSkillError::ConcurrentModification(ref cause) => write!(f, "{}", cause),
             ApproveSkillError::LimitExceeded(ref cause) => write!(f, "{}", cause),
             ApproveSkillError::NotFound(ref cause) => write!(f, "{}", cause),
+            ApproveSkillError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ApproveSkillError {}
 /// Errors returned by AssociateContactWithAddressBook
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum AssociateContactWithAddressBookError {
     /// <p>You are performing an action that would put you beyond your account's limits.</p>
     LimitExceeded(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl AssociateContactWithAddressBookError {
@@ -3553,12 +3563,18 @@ impl AssociateContactWithAddressBookError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "LimitExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         AssociateContactWithAddressBookError::LimitExceeded(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        AssociateContactWithAddressBookError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -3571,12 +3587,16 @@ impl fmt::Display for AssociateContactWithAddressBookError {
             AssociateContactWithAddressBookError::LimitExceeded(ref cause) => {
                 write!(f, "{}", cause)
             }
+            AssociateContactWithAddressBookError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for AssociateContactWithAddressBookError {}
 /// Errors returned by AssociateDeviceWithNetworkProfile
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum AssociateDeviceWithNetworkProfileError {
     /// <p>There is a concurrent modification of resources.</p>
     ConcurrentModification(String),
@@ -3584,6 +3604,8 @@ pub enum AssociateDeviceWithNetworkProfileError {
     DeviceNotRegistered(String),
     /// <p>The resource is not found.</p>
     NotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl AssociateDeviceWithNetworkProfileError {
@@ -3593,22 +3615,30 @@ impl AssociateDeviceWithNetworkProfileError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         AssociateDeviceWithNetworkProfileError::ConcurrentModification(err.msg),
+                        &res,
                     )
                 }
                 "DeviceNotRegisteredException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         AssociateDeviceWithNetworkProfileError::DeviceNotRegistered(err.msg),
+                        &res,
                     )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(AssociateDeviceWithNetworkProfileError::NotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        AssociateDeviceWithNetworkProfileError::NotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        AssociateDeviceWithNetworkProfileError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -3625,12 +3655,16 @@ impl fmt::Display for AssociateDeviceWithNetworkProfileError {
                 write!(f, "{}", cause)
             }
             AssociateDeviceWithNetworkProfileError::NotFound(ref cause) => write!(f, "{}", cause),
+            AssociateDeviceWithNetworkProfileError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for AssociateDeviceWithNetworkProfileError {}
 /// Errors returned by AssociateDeviceWithRoom
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum AssociateDeviceWithRoomError {
     /// <p>There is a concurrent modification of resources.</p>
     ConcurrentModification(String),
@@ -3638,6 +3672,8 @@ pub enum AssociateDeviceWithRoomError {
     DeviceNotRegistered(String),
     /// <p>You are performing an action that would put you beyond your account's limits.</p>
     LimitExceeded(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl AssociateDeviceWithRoomError {
@@ -3645,22 +3681,25 @@ impl AssociateDeviceWithRoomError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         AssociateDeviceWithRoomError::ConcurrentModification(err.msg),
+                        &res,
                     )
                 }
                 "DeviceNotRegisteredException" => {
-                    return RusotoError::Service(AssociateDeviceWithRoomError::DeviceNotRegistered(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        AssociateDeviceWithRoomError::DeviceNotRegistered(err.msg),
+                        &res,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(AssociateDeviceWithRoomError::LimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        AssociateDeviceWithRoomError::LimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(AssociateDeviceWithRoomError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3675,15 +3714,19 @@ impl fmt::Display for AssociateDeviceWithRoomError {
             }
             AssociateDeviceWithRoomError::DeviceNotRegistered(ref cause) => write!(f, "{}", cause),
             AssociateDeviceWithRoomError::LimitExceeded(ref cause) => write!(f, "{}", cause),
+            AssociateDeviceWithRoomError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for AssociateDeviceWithRoomError {}
 /// Errors returned by AssociateSkillGroupWithRoom
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum AssociateSkillGroupWithRoomError {
     /// <p>There is a concurrent modification of resources.</p>
     ConcurrentModification(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl AssociateSkillGroupWithRoomError {
@@ -3693,12 +3736,18 @@ impl AssociateSkillGroupWithRoomError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         AssociateSkillGroupWithRoomError::ConcurrentModification(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        AssociateSkillGroupWithRoomError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -3711,12 +3760,14 @@ impl fmt::Display for AssociateSkillGroupWithRoomError {
             AssociateSkillGroupWithRoomError::ConcurrentModification(ref cause) => {
                 write!(f, "{}", cause)
             }
+            AssociateSkillGroupWithRoomError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for AssociateSkillGroupWithRoomError {}
 /// Errors returned by AssociateSkillWithSkillGroup
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum AssociateSkillWithSkillGroupError {
     /// <p>There is a concurrent modification of resources.</p>
     ConcurrentModification(String),
@@ -3724,6 +3775,8 @@ pub enum AssociateSkillWithSkillGroupError {
     NotFound(String),
     /// <p>The skill must be linked to a third-party account.</p>
     SkillNotLinked(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl AssociateSkillWithSkillGroupError {
@@ -3733,22 +3786,30 @@ impl AssociateSkillWithSkillGroupError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         AssociateSkillWithSkillGroupError::ConcurrentModification(err.msg),
+                        &res,
                     )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(AssociateSkillWithSkillGroupError::NotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        AssociateSkillWithSkillGroupError::NotFound(err.msg),
+                        &res,
+                    )
                 }
                 "SkillNotLinkedException" => {
-                    return RusotoError::Service(AssociateSkillWithSkillGroupError::SkillNotLinked(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        AssociateSkillWithSkillGroupError::SkillNotLinked(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        AssociateSkillWithSkillGroupError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -3763,17 +3824,21 @@ impl fmt::Display for AssociateSkillWithSkillGroupError {
             }
             AssociateSkillWithSkillGroupError::NotFound(ref cause) => write!(f, "{}", cause),
             AssociateSkillWithSkillGroupError::SkillNotLinked(ref cause) => write!(f, "{}", cause),
+            AssociateSkillWithSkillGroupError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for AssociateSkillWithSkillGroupError {}
 /// Errors returned by AssociateSkillWithUsers
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum AssociateSkillWithUsersError {
     /// <p>There is a concurrent modification of resources.</p>
     ConcurrentModification(String),
     /// <p>The resource is not found.</p>
     NotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl AssociateSkillWithUsersError {
@@ -3781,15 +3846,19 @@ impl AssociateSkillWithUsersError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         AssociateSkillWithUsersError::ConcurrentModification(err.msg),
+                        &res,
                     )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(AssociateSkillWithUsersError::NotFound(err.msg))
+                    return RusotoError::service(
+                        AssociateSkillWithUsersError::NotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(AssociateSkillWithUsersError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3803,17 +3872,21 @@ impl fmt::Display for AssociateSkillWithUsersError {
                 write!(f, "{}", cause)
             }
             AssociateSkillWithUsersError::NotFound(ref cause) => write!(f, "{}", cause),
+            AssociateSkillWithUsersError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for AssociateSkillWithUsersError {}
 /// Errors returned by CreateAddressBook
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateAddressBookError {
     /// <p>The resource being created already exists.</p>
     AlreadyExists(String),
     /// <p>You are performing an action that would put you beyond your account's limits.</p>
     LimitExceeded(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateAddressBookError {
@@ -3821,13 +3894,19 @@ impl CreateAddressBookError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AlreadyExistsException" => {
-                    return RusotoError::Service(CreateAddressBookError::AlreadyExists(err.msg))
+                    return RusotoError::service(
+                        CreateAddressBookError::AlreadyExists(err.msg),
+                        &res,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateAddressBookError::LimitExceeded(err.msg))
+                    return RusotoError::service(
+                        CreateAddressBookError::LimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreateAddressBookError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3839,15 +3918,19 @@ impl fmt::Display for CreateAddressBookError {
         match *self {
             CreateAddressBookError::AlreadyExists(ref cause) => write!(f, "{}", cause),
             CreateAddressBookError::LimitExceeded(ref cause) => write!(f, "{}", cause),
+            CreateAddressBookError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateAddressBookError {}
 /// Errors returned by CreateBusinessReportSchedule
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateBusinessReportScheduleError {
     /// <p>The resource being created already exists.</p>
     AlreadyExists(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateBusinessReportScheduleError {
@@ -3857,12 +3940,18 @@ impl CreateBusinessReportScheduleError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AlreadyExistsException" => {
-                    return RusotoError::Service(CreateBusinessReportScheduleError::AlreadyExists(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateBusinessReportScheduleError::AlreadyExists(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        CreateBusinessReportScheduleError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -3873,15 +3962,19 @@ impl fmt::Display for CreateBusinessReportScheduleError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             CreateBusinessReportScheduleError::AlreadyExists(ref cause) => write!(f, "{}", cause),
+            CreateBusinessReportScheduleError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateBusinessReportScheduleError {}
 /// Errors returned by CreateConferenceProvider
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateConferenceProviderError {
     /// <p>The resource being created already exists.</p>
     AlreadyExists(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateConferenceProviderError {
@@ -3889,12 +3982,13 @@ impl CreateConferenceProviderError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AlreadyExistsException" => {
-                    return RusotoError::Service(CreateConferenceProviderError::AlreadyExists(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateConferenceProviderError::AlreadyExists(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreateConferenceProviderError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3905,17 +3999,21 @@ impl fmt::Display for CreateConferenceProviderError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             CreateConferenceProviderError::AlreadyExists(ref cause) => write!(f, "{}", cause),
+            CreateConferenceProviderError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateConferenceProviderError {}
 /// Errors returned by CreateContact
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateContactError {
     /// <p>The resource being created already exists.</p>
     AlreadyExists(String),
     /// <p>You are performing an action that would put you beyond your account's limits.</p>
     LimitExceeded(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateContactError {
@@ -3923,13 +4021,13 @@ impl CreateContactError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AlreadyExistsException" => {
-                    return RusotoError::Service(CreateContactError::AlreadyExists(err.msg))
+                    return RusotoError::service(CreateContactError::AlreadyExists(err.msg), &res)
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateContactError::LimitExceeded(err.msg))
+                    return RusotoError::service(CreateContactError::LimitExceeded(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreateContactError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3941,17 +4039,21 @@ impl fmt::Display for CreateContactError {
         match *self {
             CreateContactError::AlreadyExists(ref cause) => write!(f, "{}", cause),
             CreateContactError::LimitExceeded(ref cause) => write!(f, "{}", cause),
+            CreateContactError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateContactError {}
 /// Errors returned by CreateGatewayGroup
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateGatewayGroupError {
     /// <p>The resource being created already exists.</p>
     AlreadyExists(String),
     /// <p>You are performing an action that would put you beyond your account's limits.</p>
     LimitExceeded(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateGatewayGroupError {
@@ -3959,13 +4061,19 @@ impl CreateGatewayGroupError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AlreadyExistsException" => {
-                    return RusotoError::Service(CreateGatewayGroupError::AlreadyExists(err.msg))
+                    return RusotoError::service(
+                        CreateGatewayGroupError::AlreadyExists(err.msg),
+                        &res,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateGatewayGroupError::LimitExceeded(err.msg))
+                    return RusotoError::service(
+                        CreateGatewayGroupError::LimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreateGatewayGroupError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3977,12 +4085,14 @@ impl fmt::Display for CreateGatewayGroupError {
         match *self {
             CreateGatewayGroupError::AlreadyExists(ref cause) => write!(f, "{}", cause),
             CreateGatewayGroupError::LimitExceeded(ref cause) => write!(f, "{}", cause),
+            CreateGatewayGroupError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateGatewayGroupError {}
 /// Errors returned by CreateNetworkProfile
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateNetworkProfileError {
     /// <p>The resource being created already exists.</p>
     AlreadyExists(String),
@@ -3994,6 +4104,8 @@ pub enum CreateNetworkProfileError {
     InvalidServiceLinkedRoleState(String),
     /// <p>You are performing an action that would put you beyond your account's limits.</p>
     LimitExceeded(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateNetworkProfileError {
@@ -4001,28 +4113,37 @@ impl CreateNetworkProfileError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AlreadyExistsException" => {
-                    return RusotoError::Service(CreateNetworkProfileError::AlreadyExists(err.msg))
+                    return RusotoError::service(
+                        CreateNetworkProfileError::AlreadyExists(err.msg),
+                        &res,
+                    )
                 }
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(CreateNetworkProfileError::ConcurrentModification(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateNetworkProfileError::ConcurrentModification(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidCertificateAuthorityException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         CreateNetworkProfileError::InvalidCertificateAuthority(err.msg),
+                        &res,
                     )
                 }
                 "InvalidServiceLinkedRoleStateException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         CreateNetworkProfileError::InvalidServiceLinkedRoleState(err.msg),
+                        &res,
                     )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateNetworkProfileError::LimitExceeded(err.msg))
+                    return RusotoError::service(
+                        CreateNetworkProfileError::LimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreateNetworkProfileError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -4041,12 +4162,14 @@ impl fmt::Display for CreateNetworkProfileError {
                 write!(f, "{}", cause)
             }
             CreateNetworkProfileError::LimitExceeded(ref cause) => write!(f, "{}", cause),
+            CreateNetworkProfileError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateNetworkProfileError {}
 /// Errors returned by CreateProfile
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateProfileError {
     /// <p>The resource being created already exists.</p>
     AlreadyExists(String),
@@ -4054,6 +4177,8 @@ pub enum CreateProfileError {
     ConcurrentModification(String),
     /// <p>You are performing an action that would put you beyond your account's limits.</p>
     LimitExceeded(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateProfileError {
@@ -4061,18 +4186,19 @@ impl CreateProfileError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AlreadyExistsException" => {
-                    return RusotoError::Service(CreateProfileError::AlreadyExists(err.msg))
+                    return RusotoError::service(CreateProfileError::AlreadyExists(err.msg), &res)
                 }
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(CreateProfileError::ConcurrentModification(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateProfileError::ConcurrentModification(err.msg),
+                        &res,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateProfileError::LimitExceeded(err.msg))
+                    return RusotoError::service(CreateProfileError::LimitExceeded(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreateProfileError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -4085,17 +4211,21 @@ impl fmt::Display for CreateProfileError {
             CreateProfileError::AlreadyExists(ref cause) => write!(f, "{}", cause),
             CreateProfileError::ConcurrentModification(ref cause) => write!(f, "{}", cause),
             CreateProfileError::LimitExceeded(ref cause) => write!(f, "{}", cause),
+            CreateProfileError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateProfileError {}
 /// Errors returned by CreateRoom
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateRoomError {
     /// <p>The resource being created already exists.</p>
     AlreadyExists(String),
     /// <p>You are performing an action that would put you beyond your account's limits.</p>
     LimitExceeded(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateRoomError {
@@ -4103,13 +4233,13 @@ impl CreateRoomError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AlreadyExistsException" => {
-                    return RusotoError::Service(CreateRoomError::AlreadyExists(err.msg))
+                    return RusotoError::service(CreateRoomError::AlreadyExists(err.msg), &res)
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateRoomError::LimitExceeded(err.msg))
+                    return RusotoError::service(CreateRoomError::LimitExceeded(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreateRoomError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -4121,12 +4251,14 @@ impl fmt::Display for CreateRoomError {
         match *self {
             CreateRoomError::AlreadyExists(ref cause) => write!(f, "{}", cause),
             CreateRoomError::LimitExceeded(ref cause) => write!(f, "{}", cause),
+            CreateRoomError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateRoomError {}
 /// Errors returned by CreateSkillGroup
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateSkillGroupError {
     /// <p>The resource being created already exists.</p>
     AlreadyExists(String),
@@ -4134,6 +4266,8 @@ pub enum CreateSkillGroupError {
     ConcurrentModification(String),
     /// <p>You are performing an action that would put you beyond your account's limits.</p>
     LimitExceeded(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateSkillGroupError {
@@ -4141,18 +4275,25 @@ impl CreateSkillGroupError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AlreadyExistsException" => {
-                    return RusotoError::Service(CreateSkillGroupError::AlreadyExists(err.msg))
+                    return RusotoError::service(
+                        CreateSkillGroupError::AlreadyExists(err.msg),
+                        &res,
+                    )
                 }
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(CreateSkillGroupError::ConcurrentModification(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateSkillGroupError::ConcurrentModification(err.msg),
+                        &res,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateSkillGroupError::LimitExceeded(err.msg))
+                    return RusotoError::service(
+                        CreateSkillGroupError::LimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreateSkillGroupError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -4165,12 +4306,14 @@ impl fmt::Display for CreateSkillGroupError {
             CreateSkillGroupError::AlreadyExists(ref cause) => write!(f, "{}", cause),
             CreateSkillGroupError::ConcurrentModification(ref cause) => write!(f, "{}", cause),
             CreateSkillGroupError::LimitExceeded(ref cause) => write!(f, "{}", cause),
+            CreateSkillGroupError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateSkillGroupError {}
 /// Errors returned by CreateUser
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateUserError {
     /// <p>There is a concurrent modification of resources.</p>
     ConcurrentModification(String),
@@ -4178,6 +4321,8 @@ pub enum CreateUserError {
     LimitExceeded(String),
     /// <p>The resource in the request is already in use.</p>
     ResourceInUse(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateUserError {
@@ -4185,16 +4330,19 @@ impl CreateUserError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(CreateUserError::ConcurrentModification(err.msg))
+                    return RusotoError::service(
+                        CreateUserError::ConcurrentModification(err.msg),
+                        &res,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateUserError::LimitExceeded(err.msg))
+                    return RusotoError::service(CreateUserError::LimitExceeded(err.msg), &res)
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(CreateUserError::ResourceInUse(err.msg))
+                    return RusotoError::service(CreateUserError::ResourceInUse(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreateUserError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -4207,17 +4355,21 @@ impl fmt::Display for CreateUserError {
             CreateUserError::ConcurrentModification(ref cause) => write!(f, "{}", cause),
             CreateUserError::LimitExceeded(ref cause) => write!(f, "{}", cause),
             CreateUserError::ResourceInUse(ref cause) => write!(f, "{}", cause),
+            CreateUserError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateUserError {}
 /// Errors returned by DeleteAddressBook
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteAddressBookError {
     /// <p>There is a concurrent modification of resources.</p>
     ConcurrentModification(String),
     /// <p>The resource is not found.</p>
     NotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteAddressBookError {
@@ -4225,15 +4377,16 @@ impl DeleteAddressBookError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(DeleteAddressBookError::ConcurrentModification(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteAddressBookError::ConcurrentModification(err.msg),
+                        &res,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteAddressBookError::NotFound(err.msg))
+                    return RusotoError::service(DeleteAddressBookError::NotFound(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteAddressBookError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -4245,17 +4398,21 @@ impl fmt::Display for DeleteAddressBookError {
         match *self {
             DeleteAddressBookError::ConcurrentModification(ref cause) => write!(f, "{}", cause),
             DeleteAddressBookError::NotFound(ref cause) => write!(f, "{}", cause),
+            DeleteAddressBookError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteAddressBookError {}
 /// Errors returned by DeleteBusinessReportSchedule
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteBusinessReportScheduleError {
     /// <p>There is a concurrent modification of resources.</p>
     ConcurrentModification(String),
     /// <p>The resource is not found.</p>
     NotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteBusinessReportScheduleError {
@@ -4265,17 +4422,24 @@ impl DeleteBusinessReportScheduleError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DeleteBusinessReportScheduleError::ConcurrentModification(err.msg),
+                        &res,
                     )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteBusinessReportScheduleError::NotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteBusinessReportScheduleError::NotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        DeleteBusinessReportScheduleError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -4289,15 +4453,19 @@ impl fmt::Display for DeleteBusinessReportScheduleError {
                 write!(f, "{}", cause)
             }
             DeleteBusinessReportScheduleError::NotFound(ref cause) => write!(f, "{}", cause),
+            DeleteBusinessReportScheduleError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteBusinessReportScheduleError {}
 /// Errors returned by DeleteConferenceProvider
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteConferenceProviderError {
     /// <p>The resource is not found.</p>
     NotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteConferenceProviderError {
@@ -4305,10 +4473,13 @@ impl DeleteConferenceProviderError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteConferenceProviderError::NotFound(err.msg))
+                    return RusotoError::service(
+                        DeleteConferenceProviderError::NotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteConferenceProviderError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -4319,17 +4490,21 @@ impl fmt::Display for DeleteConferenceProviderError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             DeleteConferenceProviderError::NotFound(ref cause) => write!(f, "{}", cause),
+            DeleteConferenceProviderError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteConferenceProviderError {}
 /// Errors returned by DeleteContact
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteContactError {
     /// <p>There is a concurrent modification of resources.</p>
     ConcurrentModification(String),
     /// <p>The resource is not found.</p>
     NotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteContactError {
@@ -4337,15 +4512,16 @@ impl DeleteContactError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(DeleteContactError::ConcurrentModification(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteContactError::ConcurrentModification(err.msg),
+                        &res,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteContactError::NotFound(err.msg))
+                    return RusotoError::service(DeleteContactError::NotFound(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteContactError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -4357,12 +4533,14 @@ impl fmt::Display for DeleteContactError {
         match *self {
             DeleteContactError::ConcurrentModification(ref cause) => write!(f, "{}", cause),
             DeleteContactError::NotFound(ref cause) => write!(f, "{}", cause),
+            DeleteContactError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteContactError {}
 /// Errors returned by DeleteDevice
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteDeviceError {
     /// <p>There is a concurrent modification of resources.</p>
     ConcurrentModification(String),
@@ -4370,6 +4548,8 @@ pub enum DeleteDeviceError {
     InvalidCertificateAuthority(String),
     /// <p>The resource is not found.</p>
     NotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteDeviceError {
@@ -4377,18 +4557,22 @@ impl DeleteDeviceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(DeleteDeviceError::ConcurrentModification(err.msg))
+                    return RusotoError::service(
+                        DeleteDeviceError::ConcurrentModification(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidCertificateAuthorityException" => {
-                    return RusotoError::Service(DeleteDeviceError::InvalidCertificateAuthority(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteDeviceError::InvalidCertificateAuthority(err.msg),
+                        &res,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteDeviceError::NotFound(err.msg))
+                    return RusotoError::service(DeleteDeviceError::NotFound(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteDeviceError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -4401,12 +4585,14 @@ impl fmt::Display for DeleteDeviceError {
             DeleteDeviceError::ConcurrentModification(ref cause) => write!(f, "{}", cause),
             DeleteDeviceError::InvalidCertificateAuthority(ref cause) => write!(f, "{}", cause),
             DeleteDeviceError::NotFound(ref cause) => write!(f, "{}", cause),
+            DeleteDeviceError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteDeviceError {}
 /// Errors returned by DeleteDeviceUsageData
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteDeviceUsageDataError {
     /// <p>The request failed because this device is no longer registered and therefore no longer managed by this account.</p>
     DeviceNotRegistered(String),
@@ -4414,6 +4600,8 @@ pub enum DeleteDeviceUsageDataError {
     LimitExceeded(String),
     /// <p>The resource is not found.</p>
     NotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteDeviceUsageDataError {
@@ -4421,18 +4609,25 @@ impl DeleteDeviceUsageDataError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DeviceNotRegisteredException" => {
-                    return RusotoError::Service(DeleteDeviceUsageDataError::DeviceNotRegistered(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteDeviceUsageDataError::DeviceNotRegistered(err.msg),
+                        &res,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(DeleteDeviceUsageDataError::LimitExceeded(err.msg))
+                    return RusotoError::service(
+                        DeleteDeviceUsageDataError::LimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteDeviceUsageDataError::NotFound(err.msg))
+                    return RusotoError::service(
+                        DeleteDeviceUsageDataError::NotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteDeviceUsageDataError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -4445,15 +4640,19 @@ impl fmt::Display for DeleteDeviceUsageDataError {
             DeleteDeviceUsageDataError::DeviceNotRegistered(ref cause) => write!(f, "{}", cause),
             DeleteDeviceUsageDataError::LimitExceeded(ref cause) => write!(f, "{}", cause),
             DeleteDeviceUsageDataError::NotFound(ref cause) => write!(f, "{}", cause),
+            DeleteDeviceUsageDataError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteDeviceUsageDataError {}
 /// Errors returned by DeleteGatewayGroup
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteGatewayGroupError {
     /// <p>Another resource is associated with the resource in the request.</p>
     ResourceAssociated(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteGatewayGroupError {
@@ -4461,12 +4660,13 @@ impl DeleteGatewayGroupError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceAssociatedException" => {
-                    return RusotoError::Service(DeleteGatewayGroupError::ResourceAssociated(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteGatewayGroupError::ResourceAssociated(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteGatewayGroupError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -4477,12 +4677,14 @@ impl fmt::Display for DeleteGatewayGroupError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             DeleteGatewayGroupError::ResourceAssociated(ref cause) => write!(f, "{}", cause),
+            DeleteGatewayGroupError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteGatewayGroupError {}
 /// Errors returned by DeleteNetworkProfile
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteNetworkProfileError {
     /// <p>There is a concurrent modification of resources.</p>
     ConcurrentModification(String),
@@ -4490,6 +4692,8 @@ pub enum DeleteNetworkProfileError {
     NotFound(String),
     /// <p>The resource in the request is already in use.</p>
     ResourceInUse(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteNetworkProfileError {
@@ -4497,18 +4701,22 @@ impl DeleteNetworkProfileError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(DeleteNetworkProfileError::ConcurrentModification(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteNetworkProfileError::ConcurrentModification(err.msg),
+                        &res,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteNetworkProfileError::NotFound(err.msg))
+                    return RusotoError::service(DeleteNetworkProfileError::NotFound(err.msg), &res)
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(DeleteNetworkProfileError::ResourceInUse(err.msg))
+                    return RusotoError::service(
+                        DeleteNetworkProfileError::ResourceInUse(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteNetworkProfileError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -4521,17 +4729,21 @@ impl fmt::Display for DeleteNetworkProfileError {
             DeleteNetworkProfileError::ConcurrentModification(ref cause) => write!(f, "{}", cause),
             DeleteNetworkProfileError::NotFound(ref cause) => write!(f, "{}", cause),
             DeleteNetworkProfileError::ResourceInUse(ref cause) => write!(f, "{}", cause),
+            DeleteNetworkProfileError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteNetworkProfileError {}
 /// Errors returned by DeleteProfile
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteProfileError {
     /// <p>There is a concurrent modification of resources.</p>
     ConcurrentModification(String),
     /// <p>The resource is not found.</p>
     NotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteProfileError {
@@ -4539,15 +4751,16 @@ impl DeleteProfileError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(DeleteProfileError::ConcurrentModification(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteProfileError::ConcurrentModification(err.msg),
+                        &res,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteProfileError::NotFound(err.msg))
+                    return RusotoError::service(DeleteProfileError::NotFound(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteProfileError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -4559,17 +4772,21 @@ impl fmt::Display for DeleteProfileError {
         match *self {
             DeleteProfileError::ConcurrentModification(ref cause) => write!(f, "{}", cause),
             DeleteProfileError::NotFound(ref cause) => write!(f, "{}", cause),
+            DeleteProfileError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteProfileError {}
 /// Errors returned by DeleteRoom
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteRoomError {
     /// <p>There is a concurrent modification of resources.</p>
     ConcurrentModification(String),
     /// <p>The resource is not found.</p>
     NotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteRoomError {
@@ -4577,13 +4794,16 @@ impl DeleteRoomError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(DeleteRoomError::ConcurrentModification(err.msg))
+                    return RusotoError::service(
+                        DeleteRoomError::ConcurrentModification(err.msg),
+                        &res,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteRoomError::NotFound(err.msg))
+                    return RusotoError::service(DeleteRoomError::NotFound(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteRoomError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -4595,15 +4815,19 @@ impl fmt::Display for DeleteRoomError {
         match *self {
             DeleteRoomError::ConcurrentModification(ref cause) => write!(f, "{}", cause),
             DeleteRoomError::NotFound(ref cause) => write!(f, "{}", cause),
+            DeleteRoomError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteRoomError {}
 /// Errors returned by DeleteRoomSkillParameter
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteRoomSkillParameterError {
     /// <p>There is a concurrent modification of resources.</p>
     ConcurrentModification(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteRoomSkillParameterError {
@@ -4611,12 +4835,13 @@ impl DeleteRoomSkillParameterError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DeleteRoomSkillParameterError::ConcurrentModification(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteRoomSkillParameterError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -4629,17 +4854,21 @@ impl fmt::Display for DeleteRoomSkillParameterError {
             DeleteRoomSkillParameterError::ConcurrentModification(ref cause) => {
                 write!(f, "{}", cause)
             }
+            DeleteRoomSkillParameterError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteRoomSkillParameterError {}
 /// Errors returned by DeleteSkillAuthorization
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteSkillAuthorizationError {
     /// <p>There is a concurrent modification of resources.</p>
     ConcurrentModification(String),
     /// <p>The resource is not found.</p>
     NotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteSkillAuthorizationError {
@@ -4647,15 +4876,19 @@ impl DeleteSkillAuthorizationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DeleteSkillAuthorizationError::ConcurrentModification(err.msg),
+                        &res,
                     )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteSkillAuthorizationError::NotFound(err.msg))
+                    return RusotoError::service(
+                        DeleteSkillAuthorizationError::NotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteSkillAuthorizationError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -4669,17 +4902,21 @@ impl fmt::Display for DeleteSkillAuthorizationError {
                 write!(f, "{}", cause)
             }
             DeleteSkillAuthorizationError::NotFound(ref cause) => write!(f, "{}", cause),
+            DeleteSkillAuthorizationError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteSkillAuthorizationError {}
 /// Errors returned by DeleteSkillGroup
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteSkillGroupError {
     /// <p>There is a concurrent modification of resources.</p>
     ConcurrentModification(String),
     /// <p>The resource is not found.</p>
     NotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteSkillGroupError {
@@ -4687,15 +4924,16 @@ impl DeleteSkillGroupError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(DeleteSkillGroupError::ConcurrentModification(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteSkillGroupError::ConcurrentModification(err.msg),
+                        &res,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteSkillGroupError::NotFound(err.msg))
+                    return RusotoError::service(DeleteSkillGroupError::NotFound(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteSkillGroupError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -4707,17 +4945,21 @@ impl fmt::Display for DeleteSkillGroupError {
         match *self {
             DeleteSkillGroupError::ConcurrentModification(ref cause) => write!(f, "{}", cause),
             DeleteSkillGroupError::NotFound(ref cause) => write!(f, "{}", cause),
+            DeleteSkillGroupError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteSkillGroupError {}
 /// Errors returned by DeleteUser
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteUserError {
     /// <p>There is a concurrent modification of resources.</p>
     ConcurrentModification(String),
     /// <p>The resource is not found.</p>
     NotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteUserError {
@@ -4725,13 +4967,16 @@ impl DeleteUserError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(DeleteUserError::ConcurrentModification(err.msg))
+                    return RusotoError::service(
+                        DeleteUserError::ConcurrentModification(err.msg),
+                        &res,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteUserError::NotFound(err.msg))
+                    return RusotoError::service(DeleteUserError::NotFound(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteUserError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -4743,13 +4988,18 @@ impl fmt::Display for DeleteUserError {
         match *self {
             DeleteUserError::ConcurrentModification(ref cause) => write!(f, "{}", cause),
             DeleteUserError::NotFound(ref cause) => write!(f, "{}", cause),
+            DeleteUserError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteUserError {}
 /// Errors returned by DisassociateContactFromAddressBook
 #[derive(Debug, PartialEq)]
-pub enum DisassociateContactFromAddressBookError {}
+#[non_exhaustive]
+pub enum DisassociateContactFromAddressBookError {
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
+}
 
 impl DisassociateContactFromAddressBookError {
     pub fn from_response(
@@ -4758,7 +5008,12 @@ impl DisassociateContactFromAddressBookError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        DisassociateContactFromAddressBookError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -4767,17 +5022,24 @@ impl DisassociateContactFromAddressBookError {
 impl fmt::Display for DisassociateContactFromAddressBookError {
     #[allow(unused_variables)]
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        match *self {}
+        match *self {
+            DisassociateContactFromAddressBookError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
+        }
     }
 }
 impl Error for DisassociateContactFromAddressBookError {}
 /// Errors returned by DisassociateDeviceFromRoom
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DisassociateDeviceFromRoomError {
     /// <p>There is a concurrent modification of resources.</p>
     ConcurrentModification(String),
     /// <p>The request failed because this device is no longer registered and therefore no longer managed by this account.</p>
     DeviceNotRegistered(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DisassociateDeviceFromRoomError {
@@ -4787,17 +5049,21 @@ impl DisassociateDeviceFromRoomError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DisassociateDeviceFromRoomError::ConcurrentModification(err.msg),
+                        &res,
                     )
                 }
                 "DeviceNotRegisteredException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DisassociateDeviceFromRoomError::DeviceNotRegistered(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(DisassociateDeviceFromRoomError::Unhandled, &res)
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -4813,17 +5079,21 @@ impl fmt::Display for DisassociateDeviceFromRoomError {
             DisassociateDeviceFromRoomError::DeviceNotRegistered(ref cause) => {
                 write!(f, "{}", cause)
             }
+            DisassociateDeviceFromRoomError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DisassociateDeviceFromRoomError {}
 /// Errors returned by DisassociateSkillFromSkillGroup
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DisassociateSkillFromSkillGroupError {
     /// <p>There is a concurrent modification of resources.</p>
     ConcurrentModification(String),
     /// <p>The resource is not found.</p>
     NotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DisassociateSkillFromSkillGroupError {
@@ -4833,17 +5103,24 @@ impl DisassociateSkillFromSkillGroupError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DisassociateSkillFromSkillGroupError::ConcurrentModification(err.msg),
+                        &res,
                     )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DisassociateSkillFromSkillGroupError::NotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DisassociateSkillFromSkillGroupError::NotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        DisassociateSkillFromSkillGroupError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -4857,17 +5134,23 @@ impl fmt::Display for DisassociateSkillFromSkillGroupError {
                 write!(f, "{}", cause)
             }
             DisassociateSkillFromSkillGroupError::NotFound(ref cause) => write!(f, "{}", cause),
+            DisassociateSkillFromSkillGroupError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for DisassociateSkillFromSkillGroupError {}
 /// Errors returned by DisassociateSkillFromUsers
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DisassociateSkillFromUsersError {
     /// <p>There is a concurrent modification of resources.</p>
     ConcurrentModification(String),
     /// <p>The resource is not found.</p>
     NotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DisassociateSkillFromUsersError {
@@ -4877,15 +5160,21 @@ impl DisassociateSkillFromUsersError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DisassociateSkillFromUsersError::ConcurrentModification(err.msg),
+                        &res,
                     )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DisassociateSkillFromUsersError::NotFound(err.msg))
+                    return RusotoError::service(
+                        DisassociateSkillFromUsersError::NotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(DisassociateSkillFromUsersError::Unhandled, &res)
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -4899,15 +5188,19 @@ impl fmt::Display for DisassociateSkillFromUsersError {
                 write!(f, "{}", cause)
             }
             DisassociateSkillFromUsersError::NotFound(ref cause) => write!(f, "{}", cause),
+            DisassociateSkillFromUsersError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DisassociateSkillFromUsersError {}
 /// Errors returned by DisassociateSkillGroupFromRoom
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DisassociateSkillGroupFromRoomError {
     /// <p>There is a concurrent modification of resources.</p>
     ConcurrentModification(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DisassociateSkillGroupFromRoomError {
@@ -4917,12 +5210,18 @@ impl DisassociateSkillGroupFromRoomError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DisassociateSkillGroupFromRoomError::ConcurrentModification(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        DisassociateSkillGroupFromRoomError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -4935,15 +5234,21 @@ impl fmt::Display for DisassociateSkillGroupFromRoomError {
             DisassociateSkillGroupFromRoomError::ConcurrentModification(ref cause) => {
                 write!(f, "{}", cause)
             }
+            DisassociateSkillGroupFromRoomError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for DisassociateSkillGroupFromRoomError {}
 /// Errors returned by ForgetSmartHomeAppliances
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ForgetSmartHomeAppliancesError {
     /// <p>The resource is not found.</p>
     NotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ForgetSmartHomeAppliancesError {
@@ -4951,10 +5256,15 @@ impl ForgetSmartHomeAppliancesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "NotFoundException" => {
-                    return RusotoError::Service(ForgetSmartHomeAppliancesError::NotFound(err.msg))
+                    return RusotoError::service(
+                        ForgetSmartHomeAppliancesError::NotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(ForgetSmartHomeAppliancesError::Unhandled, &res)
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -4965,15 +5275,19 @@ impl fmt::Display for ForgetSmartHomeAppliancesError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             ForgetSmartHomeAppliancesError::NotFound(ref cause) => write!(f, "{}", cause),
+            ForgetSmartHomeAppliancesError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ForgetSmartHomeAppliancesError {}
 /// Errors returned by GetAddressBook
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetAddressBookError {
     /// <p>The resource is not found.</p>
     NotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetAddressBookError {
@@ -4981,10 +5295,10 @@ impl GetAddressBookError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "NotFoundException" => {
-                    return RusotoError::Service(GetAddressBookError::NotFound(err.msg))
+                    return RusotoError::service(GetAddressBookError::NotFound(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetAddressBookError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -4995,15 +5309,19 @@ impl fmt::Display for GetAddressBookError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             GetAddressBookError::NotFound(ref cause) => write!(f, "{}", cause),
+            GetAddressBookError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetAddressBookError {}
 /// Errors returned by GetConferencePreference
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetConferencePreferenceError {
     /// <p>The resource is not found.</p>
     NotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetConferencePreferenceError {
@@ -5011,10 +5329,13 @@ impl GetConferencePreferenceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "NotFoundException" => {
-                    return RusotoError::Service(GetConferencePreferenceError::NotFound(err.msg))
+                    return RusotoError::service(
+                        GetConferencePreferenceError::NotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetConferencePreferenceError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -5025,15 +5346,19 @@ impl fmt::Display for GetConferencePreferenceError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             GetConferencePreferenceError::NotFound(ref cause) => write!(f, "{}", cause),
+            GetConferencePreferenceError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetConferencePreferenceError {}
 /// Errors returned by GetConferenceProvider
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetConferenceProviderError {
     /// <p>The resource is not found.</p>
     NotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetConferenceProviderError {
@@ -5041,10 +5366,13 @@ impl GetConferenceProviderError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "NotFoundException" => {
-                    return RusotoError::Service(GetConferenceProviderError::NotFound(err.msg))
+                    return RusotoError::service(
+                        GetConferenceProviderError::NotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetConferenceProviderError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -5055,15 +5383,19 @@ impl fmt::Display for GetConferenceProviderError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             GetConferenceProviderError::NotFound(ref cause) => write!(f, "{}", cause),
+            GetConferenceProviderError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetConferenceProviderError {}
 /// Errors returned by GetContact
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetContactError {
     /// <p>The resource is not found.</p>
     NotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetContactError {
@@ -5071,10 +5403,10 @@ impl GetContactError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "NotFoundException" => {
-                    return RusotoError::Service(GetContactError::NotFound(err.msg))
+                    return RusotoError::service(GetContactError::NotFound(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetContactError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -5085,15 +5417,19 @@ impl fmt::Display for GetContactError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             GetContactError::NotFound(ref cause) => write!(f, "{}", cause),
+            GetContactError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetContactError {}
 /// Errors returned by GetDevice
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetDeviceError {
     /// <p>The resource is not found.</p>
     NotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetDeviceError {
@@ -5101,10 +5437,10 @@ impl GetDeviceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "NotFoundException" => {
-                    return RusotoError::Service(GetDeviceError::NotFound(err.msg))
+                    return RusotoError::service(GetDeviceError::NotFound(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetDeviceError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -5115,15 +5451,19 @@ impl fmt::Display for GetDeviceError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             GetDeviceError::NotFound(ref cause) => write!(f, "{}", cause),
+            GetDeviceError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetDeviceError {}
 /// Errors returned by GetGateway
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetGatewayError {
     /// <p>The resource is not found.</p>
     NotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetGatewayError {
@@ -5131,10 +5471,10 @@ impl GetGatewayError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "NotFoundException" => {
-                    return RusotoError::Service(GetGatewayError::NotFound(err.msg))
+                    return RusotoError::service(GetGatewayError::NotFound(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetGatewayError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -5145,15 +5485,19 @@ impl fmt::Display for GetGatewayError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             GetGatewayError::NotFound(ref cause) => write!(f, "{}", cause),
+            GetGatewayError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetGatewayError {}
 /// Errors returned by GetGatewayGroup
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetGatewayGroupError {
     /// <p>The resource is not found.</p>
     NotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetGatewayGroupError {
@@ -5161,10 +5505,10 @@ impl GetGatewayGroupError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "NotFoundException" => {
-                    return RusotoError::Service(GetGatewayGroupError::NotFound(err.msg))
+                    return RusotoError::service(GetGatewayGroupError::NotFound(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetGatewayGroupError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -5175,15 +5519,19 @@ impl fmt::Display for GetGatewayGroupError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             GetGatewayGroupError::NotFound(ref cause) => write!(f, "{}", cause),
+            GetGatewayGroupError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetGatewayGroupError {}
 /// Errors returned by GetInvitationConfiguration
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetInvitationConfigurationError {
     /// <p>The resource is not found.</p>
     NotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetInvitationConfigurationError {
@@ -5193,10 +5541,15 @@ impl GetInvitationConfigurationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "NotFoundException" => {
-                    return RusotoError::Service(GetInvitationConfigurationError::NotFound(err.msg))
+                    return RusotoError::service(
+                        GetInvitationConfigurationError::NotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(GetInvitationConfigurationError::Unhandled, &res)
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -5207,17 +5560,21 @@ impl fmt::Display for GetInvitationConfigurationError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             GetInvitationConfigurationError::NotFound(ref cause) => write!(f, "{}", cause),
+            GetInvitationConfigurationError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetInvitationConfigurationError {}
 /// Errors returned by GetNetworkProfile
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetNetworkProfileError {
     /// <p>A password in SecretsManager is in an invalid state.</p>
     InvalidSecretsManagerResource(String),
     /// <p>The resource is not found.</p>
     NotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetNetworkProfileError {
@@ -5225,15 +5582,16 @@ impl GetNetworkProfileError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidSecretsManagerResourceException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         GetNetworkProfileError::InvalidSecretsManagerResource(err.msg),
+                        &res,
                     )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetNetworkProfileError::NotFound(err.msg))
+                    return RusotoError::service(GetNetworkProfileError::NotFound(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetNetworkProfileError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -5247,15 +5605,19 @@ impl fmt::Display for GetNetworkProfileError {
                 write!(f, "{}", cause)
             }
             GetNetworkProfileError::NotFound(ref cause) => write!(f, "{}", cause),
+            GetNetworkProfileError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetNetworkProfileError {}
 /// Errors returned by GetProfile
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetProfileError {
     /// <p>The resource is not found.</p>
     NotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetProfileError {
@@ -5263,10 +5625,10 @@ impl GetProfileError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "NotFoundException" => {
-                    return RusotoError::Service(GetProfileError::NotFound(err.msg))
+                    return RusotoError::service(GetProfileError::NotFound(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetProfileError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -5277,15 +5639,19 @@ impl fmt::Display for GetProfileError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             GetProfileError::NotFound(ref cause) => write!(f, "{}", cause),
+            GetProfileError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetProfileError {}
 /// Errors returned by GetRoom
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetRoomError {
     /// <p>The resource is not found.</p>
     NotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetRoomError {
@@ -5293,10 +5659,10 @@ impl GetRoomError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "NotFoundException" => {
-                    return RusotoError::Service(GetRoomError::NotFound(err.msg))
+                    return RusotoError::service(GetRoomError::NotFound(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetRoomError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -5307,15 +5673,19 @@ impl fmt::Display for GetRoomError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             GetRoomError::NotFound(ref cause) => write!(f, "{}", cause),
+            GetRoomError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetRoomError {}
 /// Errors returned by GetRoomSkillParameter
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetRoomSkillParameterError {
     /// <p>The resource is not found.</p>
     NotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetRoomSkillParameterError {
@@ -5323,10 +5693,13 @@ impl GetRoomSkillParameterError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "NotFoundException" => {
-                    return RusotoError::Service(GetRoomSkillParameterError::NotFound(err.msg))
+                    return RusotoError::service(
+                        GetRoomSkillParameterError::NotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetRoomSkillParameterError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -5337,15 +5710,19 @@ impl fmt::Display for GetRoomSkillParameterError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             GetRoomSkillParameterError::NotFound(ref cause) => write!(f, "{}", cause),
+            GetRoomSkillParameterError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetRoomSkillParameterError {}
 /// Errors returned by GetSkillGroup
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetSkillGroupError {
     /// <p>The resource is not found.</p>
     NotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetSkillGroupError {
@@ -5353,10 +5730,10 @@ impl GetSkillGroupError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "NotFoundException" => {
-                    return RusotoError::Service(GetSkillGroupError::NotFound(err.msg))
+                    return RusotoError::service(GetSkillGroupError::NotFound(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetSkillGroupError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -5367,13 +5744,18 @@ impl fmt::Display for GetSkillGroupError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             GetSkillGroupError::NotFound(ref cause) => write!(f, "{}", cause),
+            GetSkillGroupError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetSkillGroupError {}
 /// Errors returned by ListBusinessReportSchedules
 #[derive(Debug, PartialEq)]
-pub enum ListBusinessReportSchedulesError {}
+#[non_exhaustive]
+pub enum ListBusinessReportSchedulesError {
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
+}
 
 impl ListBusinessReportSchedulesError {
     pub fn from_response(
@@ -5382,7 +5764,12 @@ impl ListBusinessReportSchedulesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        ListBusinessReportSchedulesError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -5391,20 +5778,26 @@ impl ListBusinessReportSchedulesError {
 impl fmt::Display for ListBusinessReportSchedulesError {
     #[allow(unused_variables)]
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        match *self {}
+        match *self {
+            ListBusinessReportSchedulesError::Unhandled(ref metadata) => write!(f, "{}", metadata),
+        }
     }
 }
 impl Error for ListBusinessReportSchedulesError {}
 /// Errors returned by ListConferenceProviders
 #[derive(Debug, PartialEq)]
-pub enum ListConferenceProvidersError {}
+#[non_exhaustive]
+pub enum ListConferenceProvidersError {
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
+}
 
 impl ListConferenceProvidersError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<ListConferenceProvidersError> {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListConferenceProvidersError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -5413,15 +5806,20 @@ impl ListConferenceProvidersError {
 impl fmt::Display for ListConferenceProvidersError {
     #[allow(unused_variables)]
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        match *self {}
+        match *self {
+            ListConferenceProvidersError::Unhandled(ref metadata) => write!(f, "{}", metadata),
+        }
     }
 }
 impl Error for ListConferenceProvidersError {}
 /// Errors returned by ListDeviceEvents
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListDeviceEventsError {
     /// <p>The resource is not found.</p>
     NotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListDeviceEventsError {
@@ -5429,10 +5827,10 @@ impl ListDeviceEventsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "NotFoundException" => {
-                    return RusotoError::Service(ListDeviceEventsError::NotFound(err.msg))
+                    return RusotoError::service(ListDeviceEventsError::NotFound(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListDeviceEventsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -5443,20 +5841,25 @@ impl fmt::Display for ListDeviceEventsError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             ListDeviceEventsError::NotFound(ref cause) => write!(f, "{}", cause),
+            ListDeviceEventsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListDeviceEventsError {}
 /// Errors returned by ListGatewayGroups
 #[derive(Debug, PartialEq)]
-pub enum ListGatewayGroupsError {}
+#[non_exhaustive]
+pub enum ListGatewayGroupsError {
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
+}
 
 impl ListGatewayGroupsError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<ListGatewayGroupsError> {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListGatewayGroupsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -5465,20 +5868,26 @@ impl ListGatewayGroupsError {
 impl fmt::Display for ListGatewayGroupsError {
     #[allow(unused_variables)]
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        match *self {}
+        match *self {
+            ListGatewayGroupsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
+        }
     }
 }
 impl Error for ListGatewayGroupsError {}
 /// Errors returned by ListGateways
 #[derive(Debug, PartialEq)]
-pub enum ListGatewaysError {}
+#[non_exhaustive]
+pub enum ListGatewaysError {
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
+}
 
 impl ListGatewaysError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<ListGatewaysError> {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListGatewaysError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -5487,20 +5896,26 @@ impl ListGatewaysError {
 impl fmt::Display for ListGatewaysError {
     #[allow(unused_variables)]
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        match *self {}
+        match *self {
+            ListGatewaysError::Unhandled(ref metadata) => write!(f, "{}", metadata),
+        }
     }
 }
 impl Error for ListGatewaysError {}
 /// Errors returned by ListSkills
 #[derive(Debug, PartialEq)]
-pub enum ListSkillsError {}
+#[non_exhaustive]
+pub enum ListSkillsError {
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
+}
 
 impl ListSkillsError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<ListSkillsError> {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListSkillsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -5509,20 +5924,28 @@ impl ListSkillsError {
 impl fmt::Display for ListSkillsError {
     #[allow(unused_variables)]
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        match *self {}
+        match *self {
+            ListSkillsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
+        }
     }
 }
 impl Error for ListSkillsError {}
 /// Errors returned by ListSkillsStoreCategories
 #[derive(Debug, PartialEq)]
-pub enum ListSkillsStoreCategoriesError {}
+#[non_exhaustive]
+pub enum ListSkillsStoreCategoriesError {
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
+}
 
 impl ListSkillsStoreCategoriesError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<ListSkillsStoreCategoriesError> {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(ListSkillsStoreCategoriesError::Unhandled, &res)
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -5531,13 +5954,19 @@ impl ListSkillsStoreCategoriesError {
 impl fmt::Display for ListSkillsStoreCategoriesError {
     #[allow(unused_variables)]
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        match *self {}
+        match *self {
+            ListSkillsStoreCategoriesError::Unhandled(ref metadata) => write!(f, "{}", metadata),
+        }
     }
 }
 impl Error for ListSkillsStoreCategoriesError {}
 /// Errors returned by ListSkillsStoreSkillsByCategory
 #[derive(Debug, PartialEq)]
-pub enum ListSkillsStoreSkillsByCategoryError {}
+#[non_exhaustive]
+pub enum ListSkillsStoreSkillsByCategoryError {
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
+}
 
 impl ListSkillsStoreSkillsByCategoryError {
     pub fn from_response(
@@ -5546,7 +5975,12 @@ impl ListSkillsStoreSkillsByCategoryError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        ListSkillsStoreSkillsByCategoryError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -5555,15 +5989,22 @@ impl ListSkillsStoreSkillsByCategoryError {
 impl fmt::Display for ListSkillsStoreSkillsByCategoryError {
     #[allow(unused_variables)]
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        match *self {}
+        match *self {
+            ListSkillsStoreSkillsByCategoryError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
+        }
     }
 }
 impl Error for ListSkillsStoreSkillsByCategoryError {}
 /// Errors returned by ListSmartHomeAppliances
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListSmartHomeAppliancesError {
     /// <p>The resource is not found.</p>
     NotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListSmartHomeAppliancesError {
@@ -5571,10 +6012,13 @@ impl ListSmartHomeAppliancesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "NotFoundException" => {
-                    return RusotoError::Service(ListSmartHomeAppliancesError::NotFound(err.msg))
+                    return RusotoError::service(
+                        ListSmartHomeAppliancesError::NotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListSmartHomeAppliancesError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -5585,15 +6029,19 @@ impl fmt::Display for ListSmartHomeAppliancesError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             ListSmartHomeAppliancesError::NotFound(ref cause) => write!(f, "{}", cause),
+            ListSmartHomeAppliancesError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListSmartHomeAppliancesError {}
 /// Errors returned by ListTags
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListTagsError {
     /// <p>The resource is not found.</p>
     NotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListTagsError {
@@ -5601,10 +6049,10 @@ impl ListTagsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "NotFoundException" => {
-                    return RusotoError::Service(ListTagsError::NotFound(err.msg))
+                    return RusotoError::service(ListTagsError::NotFound(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListTagsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -5615,15 +6063,19 @@ impl fmt::Display for ListTagsError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             ListTagsError::NotFound(ref cause) => write!(f, "{}", cause),
+            ListTagsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListTagsError {}
 /// Errors returned by PutConferencePreference
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum PutConferencePreferenceError {
     /// <p>The resource is not found.</p>
     NotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl PutConferencePreferenceError {
@@ -5631,10 +6083,13 @@ impl PutConferencePreferenceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "NotFoundException" => {
-                    return RusotoError::Service(PutConferencePreferenceError::NotFound(err.msg))
+                    return RusotoError::service(
+                        PutConferencePreferenceError::NotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(PutConferencePreferenceError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -5645,17 +6100,21 @@ impl fmt::Display for PutConferencePreferenceError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             PutConferencePreferenceError::NotFound(ref cause) => write!(f, "{}", cause),
+            PutConferencePreferenceError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for PutConferencePreferenceError {}
 /// Errors returned by PutInvitationConfiguration
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum PutInvitationConfigurationError {
     /// <p>There is a concurrent modification of resources.</p>
     ConcurrentModification(String),
     /// <p>The resource is not found.</p>
     NotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl PutInvitationConfigurationError {
@@ -5665,15 +6124,21 @@ impl PutInvitationConfigurationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         PutInvitationConfigurationError::ConcurrentModification(err.msg),
+                        &res,
                     )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(PutInvitationConfigurationError::NotFound(err.msg))
+                    return RusotoError::service(
+                        PutInvitationConfigurationError::NotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(PutInvitationConfigurationError::Unhandled, &res)
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -5687,15 +6152,19 @@ impl fmt::Display for PutInvitationConfigurationError {
                 write!(f, "{}", cause)
             }
             PutInvitationConfigurationError::NotFound(ref cause) => write!(f, "{}", cause),
+            PutInvitationConfigurationError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for PutInvitationConfigurationError {}
 /// Errors returned by PutRoomSkillParameter
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum PutRoomSkillParameterError {
     /// <p>There is a concurrent modification of resources.</p>
     ConcurrentModification(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl PutRoomSkillParameterError {
@@ -5703,12 +6172,13 @@ impl PutRoomSkillParameterError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         PutRoomSkillParameterError::ConcurrentModification(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(PutRoomSkillParameterError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -5719,17 +6189,21 @@ impl fmt::Display for PutRoomSkillParameterError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             PutRoomSkillParameterError::ConcurrentModification(ref cause) => write!(f, "{}", cause),
+            PutRoomSkillParameterError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for PutRoomSkillParameterError {}
 /// Errors returned by PutSkillAuthorization
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum PutSkillAuthorizationError {
     /// <p>There is a concurrent modification of resources.</p>
     ConcurrentModification(String),
     /// <p>The caller has no permissions to operate on the resource involved in the API call.</p>
     Unauthorized(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl PutSkillAuthorizationError {
@@ -5737,15 +6211,19 @@ impl PutSkillAuthorizationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         PutSkillAuthorizationError::ConcurrentModification(err.msg),
+                        &res,
                     )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(PutSkillAuthorizationError::Unauthorized(err.msg))
+                    return RusotoError::service(
+                        PutSkillAuthorizationError::Unauthorized(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(PutSkillAuthorizationError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -5757,12 +6235,14 @@ impl fmt::Display for PutSkillAuthorizationError {
         match *self {
             PutSkillAuthorizationError::ConcurrentModification(ref cause) => write!(f, "{}", cause),
             PutSkillAuthorizationError::Unauthorized(ref cause) => write!(f, "{}", cause),
+            PutSkillAuthorizationError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for PutSkillAuthorizationError {}
 /// Errors returned by RegisterAVSDevice
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum RegisterAVSDeviceError {
     /// <p>There is a concurrent modification of resources.</p>
     ConcurrentModification(String),
@@ -5770,6 +6250,8 @@ pub enum RegisterAVSDeviceError {
     InvalidDevice(String),
     /// <p>You are performing an action that would put you beyond your account's limits.</p>
     LimitExceeded(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl RegisterAVSDeviceError {
@@ -5777,18 +6259,25 @@ impl RegisterAVSDeviceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(RegisterAVSDeviceError::ConcurrentModification(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        RegisterAVSDeviceError::ConcurrentModification(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidDeviceException" => {
-                    return RusotoError::Service(RegisterAVSDeviceError::InvalidDevice(err.msg))
+                    return RusotoError::service(
+                        RegisterAVSDeviceError::InvalidDevice(err.msg),
+                        &res,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(RegisterAVSDeviceError::LimitExceeded(err.msg))
+                    return RusotoError::service(
+                        RegisterAVSDeviceError::LimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(RegisterAVSDeviceError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -5801,17 +6290,21 @@ impl fmt::Display for RegisterAVSDeviceError {
             RegisterAVSDeviceError::ConcurrentModification(ref cause) => write!(f, "{}", cause),
             RegisterAVSDeviceError::InvalidDevice(ref cause) => write!(f, "{}", cause),
             RegisterAVSDeviceError::LimitExceeded(ref cause) => write!(f, "{}", cause),
+            RegisterAVSDeviceError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for RegisterAVSDeviceError {}
 /// Errors returned by RejectSkill
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum RejectSkillError {
     /// <p>There is a concurrent modification of resources.</p>
     ConcurrentModification(String),
     /// <p>The resource is not found.</p>
     NotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl RejectSkillError {
@@ -5819,13 +6312,16 @@ impl RejectSkillError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(RejectSkillError::ConcurrentModification(err.msg))
+                    return RusotoError::service(
+                        RejectSkillError::ConcurrentModification(err.msg),
+                        &res,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(RejectSkillError::NotFound(err.msg))
+                    return RusotoError::service(RejectSkillError::NotFound(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(RejectSkillError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -5837,15 +6333,19 @@ impl fmt::Display for RejectSkillError {
         match *self {
             RejectSkillError::ConcurrentModification(ref cause) => write!(f, "{}", cause),
             RejectSkillError::NotFound(ref cause) => write!(f, "{}", cause),
+            RejectSkillError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for RejectSkillError {}
 /// Errors returned by ResolveRoom
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ResolveRoomError {
     /// <p>The resource is not found.</p>
     NotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ResolveRoomError {
@@ -5853,10 +6353,10 @@ impl ResolveRoomError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "NotFoundException" => {
-                    return RusotoError::Service(ResolveRoomError::NotFound(err.msg))
+                    return RusotoError::service(ResolveRoomError::NotFound(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ResolveRoomError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -5867,17 +6367,21 @@ impl fmt::Display for ResolveRoomError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             ResolveRoomError::NotFound(ref cause) => write!(f, "{}", cause),
+            ResolveRoomError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ResolveRoomError {}
 /// Errors returned by RevokeInvitation
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum RevokeInvitationError {
     /// <p>There is a concurrent modification of resources.</p>
     ConcurrentModification(String),
     /// <p>The resource is not found.</p>
     NotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl RevokeInvitationError {
@@ -5885,15 +6389,16 @@ impl RevokeInvitationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(RevokeInvitationError::ConcurrentModification(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        RevokeInvitationError::ConcurrentModification(err.msg),
+                        &res,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(RevokeInvitationError::NotFound(err.msg))
+                    return RusotoError::service(RevokeInvitationError::NotFound(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(RevokeInvitationError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -5905,20 +6410,25 @@ impl fmt::Display for RevokeInvitationError {
         match *self {
             RevokeInvitationError::ConcurrentModification(ref cause) => write!(f, "{}", cause),
             RevokeInvitationError::NotFound(ref cause) => write!(f, "{}", cause),
+            RevokeInvitationError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for RevokeInvitationError {}
 /// Errors returned by SearchAddressBooks
 #[derive(Debug, PartialEq)]
-pub enum SearchAddressBooksError {}
+#[non_exhaustive]
+pub enum SearchAddressBooksError {
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
+}
 
 impl SearchAddressBooksError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<SearchAddressBooksError> {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(SearchAddressBooksError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -5927,20 +6437,26 @@ impl SearchAddressBooksError {
 impl fmt::Display for SearchAddressBooksError {
     #[allow(unused_variables)]
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        match *self {}
+        match *self {
+            SearchAddressBooksError::Unhandled(ref metadata) => write!(f, "{}", metadata),
+        }
     }
 }
 impl Error for SearchAddressBooksError {}
 /// Errors returned by SearchContacts
 #[derive(Debug, PartialEq)]
-pub enum SearchContactsError {}
+#[non_exhaustive]
+pub enum SearchContactsError {
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
+}
 
 impl SearchContactsError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<SearchContactsError> {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(SearchContactsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -5949,20 +6465,26 @@ impl SearchContactsError {
 impl fmt::Display for SearchContactsError {
     #[allow(unused_variables)]
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        match *self {}
+        match *self {
+            SearchContactsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
+        }
     }
 }
 impl Error for SearchContactsError {}
 /// Errors returned by SearchDevices
 #[derive(Debug, PartialEq)]
-pub enum SearchDevicesError {}
+#[non_exhaustive]
+pub enum SearchDevicesError {
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
+}
 
 impl SearchDevicesError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<SearchDevicesError> {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(SearchDevicesError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -5971,20 +6493,26 @@ impl SearchDevicesError {
 impl fmt::Display for SearchDevicesError {
     #[allow(unused_variables)]
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        match *self {}
+        match *self {
+            SearchDevicesError::Unhandled(ref metadata) => write!(f, "{}", metadata),
+        }
     }
 }
 impl Error for SearchDevicesError {}
 /// Errors returned by SearchNetworkProfiles
 #[derive(Debug, PartialEq)]
-pub enum SearchNetworkProfilesError {}
+#[non_exhaustive]
+pub enum SearchNetworkProfilesError {
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
+}
 
 impl SearchNetworkProfilesError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<SearchNetworkProfilesError> {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(SearchNetworkProfilesError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -5993,20 +6521,26 @@ impl SearchNetworkProfilesError {
 impl fmt::Display for SearchNetworkProfilesError {
     #[allow(unused_variables)]
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        match *self {}
+        match *self {
+            SearchNetworkProfilesError::Unhandled(ref metadata) => write!(f, "{}", metadata),
+        }
     }
 }
 impl Error for SearchNetworkProfilesError {}
 /// Errors returned by SearchProfiles
 #[derive(Debug, PartialEq)]
-pub enum SearchProfilesError {}
+#[non_exhaustive]
+pub enum SearchProfilesError {
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
+}
 
 impl SearchProfilesError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<SearchProfilesError> {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(SearchProfilesError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -6015,20 +6549,26 @@ impl SearchProfilesError {
 impl fmt::Display for SearchProfilesError {
     #[allow(unused_variables)]
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        match *self {}
+        match *self {
+            SearchProfilesError::Unhandled(ref metadata) => write!(f, "{}", metadata),
+        }
     }
 }
 impl Error for SearchProfilesError {}
 /// Errors returned by SearchRooms
 #[derive(Debug, PartialEq)]
-pub enum SearchRoomsError {}
+#[non_exhaustive]
+pub enum SearchRoomsError {
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
+}
 
 impl SearchRoomsError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<SearchRoomsError> {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(SearchRoomsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -6037,20 +6577,26 @@ impl SearchRoomsError {
 impl fmt::Display for SearchRoomsError {
     #[allow(unused_variables)]
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        match *self {}
+        match *self {
+            SearchRoomsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
+        }
     }
 }
 impl Error for SearchRoomsError {}
 /// Errors returned by SearchSkillGroups
 #[derive(Debug, PartialEq)]
-pub enum SearchSkillGroupsError {}
+#[non_exhaustive]
+pub enum SearchSkillGroupsError {
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
+}
 
 impl SearchSkillGroupsError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<SearchSkillGroupsError> {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(SearchSkillGroupsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -6059,20 +6605,26 @@ impl SearchSkillGroupsError {
 impl fmt::Display for SearchSkillGroupsError {
     #[allow(unused_variables)]
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        match *self {}
+        match *self {
+            SearchSkillGroupsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
+        }
     }
 }
 impl Error for SearchSkillGroupsError {}
 /// Errors returned by SearchUsers
 #[derive(Debug, PartialEq)]
-pub enum SearchUsersError {}
+#[non_exhaustive]
+pub enum SearchUsersError {
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
+}
 
 impl SearchUsersError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<SearchUsersError> {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(SearchUsersError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -6081,17 +6633,22 @@ impl SearchUsersError {
 impl fmt::Display for SearchUsersError {
     #[allow(unused_variables)]
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        match *self {}
+        match *self {
+            SearchUsersError::Unhandled(ref metadata) => write!(f, "{}", metadata),
+        }
     }
 }
 impl Error for SearchUsersError {}
 /// Errors returned by SendAnnouncement
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum SendAnnouncementError {
     /// <p>The resource being created already exists.</p>
     AlreadyExists(String),
     /// <p>You are performing an action that would put you beyond your account's limits.</p>
     LimitExceeded(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl SendAnnouncementError {
@@ -6099,13 +6656,19 @@ impl SendAnnouncementError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AlreadyExistsException" => {
-                    return RusotoError::Service(SendAnnouncementError::AlreadyExists(err.msg))
+                    return RusotoError::service(
+                        SendAnnouncementError::AlreadyExists(err.msg),
+                        &res,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(SendAnnouncementError::LimitExceeded(err.msg))
+                    return RusotoError::service(
+                        SendAnnouncementError::LimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(SendAnnouncementError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -6117,12 +6680,14 @@ impl fmt::Display for SendAnnouncementError {
         match *self {
             SendAnnouncementError::AlreadyExists(ref cause) => write!(f, "{}", cause),
             SendAnnouncementError::LimitExceeded(ref cause) => write!(f, "{}", cause),
+            SendAnnouncementError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for SendAnnouncementError {}
 /// Errors returned by SendInvitation
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum SendInvitationError {
     /// <p>There is a concurrent modification of resources.</p>
     ConcurrentModification(String),
@@ -6130,6 +6695,8 @@ pub enum SendInvitationError {
     InvalidUserStatus(String),
     /// <p>The resource is not found.</p>
     NotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl SendInvitationError {
@@ -6137,18 +6704,22 @@ impl SendInvitationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(SendInvitationError::ConcurrentModification(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        SendInvitationError::ConcurrentModification(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidUserStatusException" => {
-                    return RusotoError::Service(SendInvitationError::InvalidUserStatus(err.msg))
+                    return RusotoError::service(
+                        SendInvitationError::InvalidUserStatus(err.msg),
+                        &res,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(SendInvitationError::NotFound(err.msg))
+                    return RusotoError::service(SendInvitationError::NotFound(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(SendInvitationError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -6161,15 +6732,19 @@ impl fmt::Display for SendInvitationError {
             SendInvitationError::ConcurrentModification(ref cause) => write!(f, "{}", cause),
             SendInvitationError::InvalidUserStatus(ref cause) => write!(f, "{}", cause),
             SendInvitationError::NotFound(ref cause) => write!(f, "{}", cause),
+            SendInvitationError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for SendInvitationError {}
 /// Errors returned by StartDeviceSync
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum StartDeviceSyncError {
     /// <p>The request failed because this device is no longer registered and therefore no longer managed by this account.</p>
     DeviceNotRegistered(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl StartDeviceSyncError {
@@ -6177,10 +6752,13 @@ impl StartDeviceSyncError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DeviceNotRegisteredException" => {
-                    return RusotoError::Service(StartDeviceSyncError::DeviceNotRegistered(err.msg))
+                    return RusotoError::service(
+                        StartDeviceSyncError::DeviceNotRegistered(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(StartDeviceSyncError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -6191,15 +6769,19 @@ impl fmt::Display for StartDeviceSyncError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             StartDeviceSyncError::DeviceNotRegistered(ref cause) => write!(f, "{}", cause),
+            StartDeviceSyncError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for StartDeviceSyncError {}
 /// Errors returned by StartSmartHomeApplianceDiscovery
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum StartSmartHomeApplianceDiscoveryError {
     /// <p>The resource is not found.</p>
     NotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl StartSmartHomeApplianceDiscoveryError {
@@ -6209,12 +6791,18 @@ impl StartSmartHomeApplianceDiscoveryError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "NotFoundException" => {
-                    return RusotoError::Service(StartSmartHomeApplianceDiscoveryError::NotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        StartSmartHomeApplianceDiscoveryError::NotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        StartSmartHomeApplianceDiscoveryError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -6225,15 +6813,21 @@ impl fmt::Display for StartSmartHomeApplianceDiscoveryError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             StartSmartHomeApplianceDiscoveryError::NotFound(ref cause) => write!(f, "{}", cause),
+            StartSmartHomeApplianceDiscoveryError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for StartSmartHomeApplianceDiscoveryError {}
 /// Errors returned by TagResource
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum TagResourceError {
     /// <p>The resource is not found.</p>
     NotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl TagResourceError {
@@ -6241,10 +6835,10 @@ impl TagResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "NotFoundException" => {
-                    return RusotoError::Service(TagResourceError::NotFound(err.msg))
+                    return RusotoError::service(TagResourceError::NotFound(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(TagResourceError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -6255,15 +6849,19 @@ impl fmt::Display for TagResourceError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             TagResourceError::NotFound(ref cause) => write!(f, "{}", cause),
+            TagResourceError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for TagResourceError {}
 /// Errors returned by UntagResource
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UntagResourceError {
     /// <p>The resource is not found.</p>
     NotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UntagResourceError {
@@ -6271,10 +6869,10 @@ impl UntagResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "NotFoundException" => {
-                    return RusotoError::Service(UntagResourceError::NotFound(err.msg))
+                    return RusotoError::service(UntagResourceError::NotFound(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UntagResourceError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -6285,12 +6883,14 @@ impl fmt::Display for UntagResourceError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             UntagResourceError::NotFound(ref cause) => write!(f, "{}", cause),
+            UntagResourceError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for UntagResourceError {}
 /// Errors returned by UpdateAddressBook
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdateAddressBookError {
     /// <p>There is a concurrent modification of resources.</p>
     ConcurrentModification(String),
@@ -6298,6 +6898,8 @@ pub enum UpdateAddressBookError {
     NameInUse(String),
     /// <p>The resource is not found.</p>
     NotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdateAddressBookError {
@@ -6305,18 +6907,19 @@ impl UpdateAddressBookError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(UpdateAddressBookError::ConcurrentModification(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateAddressBookError::ConcurrentModification(err.msg),
+                        &res,
+                    )
                 }
                 "NameInUseException" => {
-                    return RusotoError::Service(UpdateAddressBookError::NameInUse(err.msg))
+                    return RusotoError::service(UpdateAddressBookError::NameInUse(err.msg), &res)
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(UpdateAddressBookError::NotFound(err.msg))
+                    return RusotoError::service(UpdateAddressBookError::NotFound(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UpdateAddressBookError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -6329,17 +6932,21 @@ impl fmt::Display for UpdateAddressBookError {
             UpdateAddressBookError::ConcurrentModification(ref cause) => write!(f, "{}", cause),
             UpdateAddressBookError::NameInUse(ref cause) => write!(f, "{}", cause),
             UpdateAddressBookError::NotFound(ref cause) => write!(f, "{}", cause),
+            UpdateAddressBookError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for UpdateAddressBookError {}
 /// Errors returned by UpdateBusinessReportSchedule
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdateBusinessReportScheduleError {
     /// <p>There is a concurrent modification of resources.</p>
     ConcurrentModification(String),
     /// <p>The resource is not found.</p>
     NotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdateBusinessReportScheduleError {
@@ -6349,17 +6956,24 @@ impl UpdateBusinessReportScheduleError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         UpdateBusinessReportScheduleError::ConcurrentModification(err.msg),
+                        &res,
                     )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(UpdateBusinessReportScheduleError::NotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateBusinessReportScheduleError::NotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        UpdateBusinessReportScheduleError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -6373,15 +6987,19 @@ impl fmt::Display for UpdateBusinessReportScheduleError {
                 write!(f, "{}", cause)
             }
             UpdateBusinessReportScheduleError::NotFound(ref cause) => write!(f, "{}", cause),
+            UpdateBusinessReportScheduleError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for UpdateBusinessReportScheduleError {}
 /// Errors returned by UpdateConferenceProvider
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdateConferenceProviderError {
     /// <p>The resource is not found.</p>
     NotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdateConferenceProviderError {
@@ -6389,10 +7007,13 @@ impl UpdateConferenceProviderError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "NotFoundException" => {
-                    return RusotoError::Service(UpdateConferenceProviderError::NotFound(err.msg))
+                    return RusotoError::service(
+                        UpdateConferenceProviderError::NotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UpdateConferenceProviderError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -6403,17 +7024,21 @@ impl fmt::Display for UpdateConferenceProviderError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             UpdateConferenceProviderError::NotFound(ref cause) => write!(f, "{}", cause),
+            UpdateConferenceProviderError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for UpdateConferenceProviderError {}
 /// Errors returned by UpdateContact
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdateContactError {
     /// <p>There is a concurrent modification of resources.</p>
     ConcurrentModification(String),
     /// <p>The resource is not found.</p>
     NotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdateContactError {
@@ -6421,15 +7046,16 @@ impl UpdateContactError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(UpdateContactError::ConcurrentModification(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateContactError::ConcurrentModification(err.msg),
+                        &res,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(UpdateContactError::NotFound(err.msg))
+                    return RusotoError::service(UpdateContactError::NotFound(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UpdateContactError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -6441,12 +7067,14 @@ impl fmt::Display for UpdateContactError {
         match *self {
             UpdateContactError::ConcurrentModification(ref cause) => write!(f, "{}", cause),
             UpdateContactError::NotFound(ref cause) => write!(f, "{}", cause),
+            UpdateContactError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for UpdateContactError {}
 /// Errors returned by UpdateDevice
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdateDeviceError {
     /// <p>There is a concurrent modification of resources.</p>
     ConcurrentModification(String),
@@ -6454,6 +7082,8 @@ pub enum UpdateDeviceError {
     DeviceNotRegistered(String),
     /// <p>The resource is not found.</p>
     NotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdateDeviceError {
@@ -6461,16 +7091,22 @@ impl UpdateDeviceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(UpdateDeviceError::ConcurrentModification(err.msg))
+                    return RusotoError::service(
+                        UpdateDeviceError::ConcurrentModification(err.msg),
+                        &res,
+                    )
                 }
                 "DeviceNotRegisteredException" => {
-                    return RusotoError::Service(UpdateDeviceError::DeviceNotRegistered(err.msg))
+                    return RusotoError::service(
+                        UpdateDeviceError::DeviceNotRegistered(err.msg),
+                        &res,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(UpdateDeviceError::NotFound(err.msg))
+                    return RusotoError::service(UpdateDeviceError::NotFound(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UpdateDeviceError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -6483,17 +7119,21 @@ impl fmt::Display for UpdateDeviceError {
             UpdateDeviceError::ConcurrentModification(ref cause) => write!(f, "{}", cause),
             UpdateDeviceError::DeviceNotRegistered(ref cause) => write!(f, "{}", cause),
             UpdateDeviceError::NotFound(ref cause) => write!(f, "{}", cause),
+            UpdateDeviceError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for UpdateDeviceError {}
 /// Errors returned by UpdateGateway
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdateGatewayError {
     /// <p>The name sent in the request is already in use.</p>
     NameInUse(String),
     /// <p>The resource is not found.</p>
     NotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdateGatewayError {
@@ -6501,13 +7141,13 @@ impl UpdateGatewayError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "NameInUseException" => {
-                    return RusotoError::Service(UpdateGatewayError::NameInUse(err.msg))
+                    return RusotoError::service(UpdateGatewayError::NameInUse(err.msg), &res)
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(UpdateGatewayError::NotFound(err.msg))
+                    return RusotoError::service(UpdateGatewayError::NotFound(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UpdateGatewayError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -6519,17 +7159,21 @@ impl fmt::Display for UpdateGatewayError {
         match *self {
             UpdateGatewayError::NameInUse(ref cause) => write!(f, "{}", cause),
             UpdateGatewayError::NotFound(ref cause) => write!(f, "{}", cause),
+            UpdateGatewayError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for UpdateGatewayError {}
 /// Errors returned by UpdateGatewayGroup
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdateGatewayGroupError {
     /// <p>The name sent in the request is already in use.</p>
     NameInUse(String),
     /// <p>The resource is not found.</p>
     NotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdateGatewayGroupError {
@@ -6537,13 +7181,13 @@ impl UpdateGatewayGroupError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "NameInUseException" => {
-                    return RusotoError::Service(UpdateGatewayGroupError::NameInUse(err.msg))
+                    return RusotoError::service(UpdateGatewayGroupError::NameInUse(err.msg), &res)
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(UpdateGatewayGroupError::NotFound(err.msg))
+                    return RusotoError::service(UpdateGatewayGroupError::NotFound(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UpdateGatewayGroupError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -6555,12 +7199,14 @@ impl fmt::Display for UpdateGatewayGroupError {
         match *self {
             UpdateGatewayGroupError::NameInUse(ref cause) => write!(f, "{}", cause),
             UpdateGatewayGroupError::NotFound(ref cause) => write!(f, "{}", cause),
+            UpdateGatewayGroupError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for UpdateGatewayGroupError {}
 /// Errors returned by UpdateNetworkProfile
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdateNetworkProfileError {
     /// <p>There is a concurrent modification of resources.</p>
     ConcurrentModification(String),
@@ -6572,6 +7218,8 @@ pub enum UpdateNetworkProfileError {
     NameInUse(String),
     /// <p>The resource is not found.</p>
     NotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdateNetworkProfileError {
@@ -6579,28 +7227,34 @@ impl UpdateNetworkProfileError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(UpdateNetworkProfileError::ConcurrentModification(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateNetworkProfileError::ConcurrentModification(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidCertificateAuthorityException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         UpdateNetworkProfileError::InvalidCertificateAuthority(err.msg),
+                        &res,
                     )
                 }
                 "InvalidSecretsManagerResourceException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         UpdateNetworkProfileError::InvalidSecretsManagerResource(err.msg),
+                        &res,
                     )
                 }
                 "NameInUseException" => {
-                    return RusotoError::Service(UpdateNetworkProfileError::NameInUse(err.msg))
+                    return RusotoError::service(
+                        UpdateNetworkProfileError::NameInUse(err.msg),
+                        &res,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(UpdateNetworkProfileError::NotFound(err.msg))
+                    return RusotoError::service(UpdateNetworkProfileError::NotFound(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UpdateNetworkProfileError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -6619,12 +7273,14 @@ impl fmt::Display for UpdateNetworkProfileError {
             }
             UpdateNetworkProfileError::NameInUse(ref cause) => write!(f, "{}", cause),
             UpdateNetworkProfileError::NotFound(ref cause) => write!(f, "{}", cause),
+            UpdateNetworkProfileError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for UpdateNetworkProfileError {}
 /// Errors returned by UpdateProfile
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdateProfileError {
     /// <p>There is a concurrent modification of resources.</p>
     ConcurrentModification(String),
@@ -6632,6 +7288,8 @@ pub enum UpdateProfileError {
     NameInUse(String),
     /// <p>The resource is not found.</p>
     NotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdateProfileError {
@@ -6639,18 +7297,19 @@ impl UpdateProfileError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(UpdateProfileError::ConcurrentModification(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateProfileError::ConcurrentModification(err.msg),
+                        &res,
+                    )
                 }
                 "NameInUseException" => {
-                    return RusotoError::Service(UpdateProfileError::NameInUse(err.msg))
+                    return RusotoError::service(UpdateProfileError::NameInUse(err.msg), &res)
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(UpdateProfileError::NotFound(err.msg))
+                    return RusotoError::service(UpdateProfileError::NotFound(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UpdateProfileError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -6663,17 +7322,21 @@ impl fmt::Display for UpdateProfileError {
             UpdateProfileError::ConcurrentModification(ref cause) => write!(f, "{}", cause),
             UpdateProfileError::NameInUse(ref cause) => write!(f, "{}", cause),
             UpdateProfileError::NotFound(ref cause) => write!(f, "{}", cause),
+            UpdateProfileError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for UpdateProfileError {}
 /// Errors returned by UpdateRoom
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdateRoomError {
     /// <p>The name sent in the request is already in use.</p>
     NameInUse(String),
     /// <p>The resource is not found.</p>
     NotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdateRoomError {
@@ -6681,13 +7344,13 @@ impl UpdateRoomError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "NameInUseException" => {
-                    return RusotoError::Service(UpdateRoomError::NameInUse(err.msg))
+                    return RusotoError::service(UpdateRoomError::NameInUse(err.msg), &res)
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(UpdateRoomError::NotFound(err.msg))
+                    return RusotoError::service(UpdateRoomError::NotFound(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UpdateRoomError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -6699,12 +7362,14 @@ impl fmt::Display for UpdateRoomError {
         match *self {
             UpdateRoomError::NameInUse(ref cause) => write!(f, "{}", cause),
             UpdateRoomError::NotFound(ref cause) => write!(f, "{}", cause),
+            UpdateRoomError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for UpdateRoomError {}
 /// Errors returned by UpdateSkillGroup
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdateSkillGroupError {
     /// <p>There is a concurrent modification of resources.</p>
     ConcurrentModification(String),
@@ -6712,6 +7377,8 @@ pub enum UpdateSkillGroupError {
     NameInUse(String),
     /// <p>The resource is not found.</p>
     NotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdateSkillGroupError {
@@ -6719,18 +7386,19 @@ impl UpdateSkillGroupError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(UpdateSkillGroupError::ConcurrentModification(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateSkillGroupError::ConcurrentModification(err.msg),
+                        &res,
+                    )
                 }
                 "NameInUseException" => {
-                    return RusotoError::Service(UpdateSkillGroupError::NameInUse(err.msg))
+                    return RusotoError::service(UpdateSkillGroupError::NameInUse(err.msg), &res)
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(UpdateSkillGroupError::NotFound(err.msg))
+                    return RusotoError::service(UpdateSkillGroupError::NotFound(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UpdateSkillGroupError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -6743,6 +7411,7 @@ impl fmt::Display for UpdateSkillGroupError {
             UpdateSkillGroupError::ConcurrentModification(ref cause) => write!(f, "{}", cause),
             UpdateSkillGroupError::NameInUse(ref cause) => write!(f, "{}", cause),
             UpdateSkillGroupError::NotFound(ref cause) => write!(f, "{}", cause),
+            UpdateSkillGroupError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
@@ -7338,6 +8007,7 @@ impl AlexaForBusinessClient {
     /// Creates a client backed by the default tokio event loop.
     ///
     /// The client will use the default credentials provider and tls client.
+    #[cfg(not(target_arch = "wasm32"))]
     pub fn new(region: region::Region) -> AlexaForBusinessClient {
         AlexaForBusinessClient {
             client: Client::shared(),
@@ -7363,46 +8033,127 @@ impl AlexaForBusinessClient {
     pub fn new_with_client(client: Client, region: region::Region) -> AlexaForBusinessClient {
         AlexaForBusinessClient { client, region }
     }
+
+    /// Returns a builder of a client, setting any of its region, credentials provider,
+    /// dispatcher, retry policy, endpoint and timeouts.
+    pub fn builder() -> rusoto_core::ClientBuilder<AlexaForBusinessClient> {
+        rusoto_core::ClientBuilder::new(AlexaForBusinessClient::new_with_client)
+    }
+
+    /// Returns a clone of this client with the overrides of `config` applied to its
+    /// operations, sharing its connections.
+    pub fn customize(&self, config: &rusoto_core::OperationConfig) -> AlexaForBusinessClient {
+        AlexaForBusinessClient {
+            client: self.client.customize(config),
+            region: config.resolve_region(&self.region),
+        }
+    }
 }
 
-#[async_trait]
-impl AlexaForBusiness for AlexaForBusinessClient {
-    /// <p>Associates a skill with the organization under the customer's AWS account. If a skill is private, the user implicitly accepts access to this skill during enablement.</p>
-    async fn approve_skill(
+impl AlexaForBusinessClient {
+    /// Calls `approve_skill`, also returning the metadata of the response.
+    pub async fn approve_skill_with_metadata(
         &self,
         input: ApproveSkillRequest,
-    ) -> Result<ApproveSkillResponse, RusotoError<ApproveSkillError>> {
+    ) -> Result<(ApproveSkillResponse, ResponseMetadata), RusotoError<ApproveSkillError>> {
+        let request = self.build_approve_skill_request(input);
+
+        let mut response = self
+            .client
+            .sign_and_dispatch(request)
+            .await
+            .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
+        if response.status.is_success() {
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ApproveSkillResponse, _>()?;
+            Ok((result, metadata))
+        } else {
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ApproveSkillError::from_response(response))
+        }
+    }
+
+    /// Presigns the request `approve_skill` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_approve_skill(
+        &self,
+        presigner: &rusoto_core::Presigner,
+        input: ApproveSkillRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_approve_skill_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_approve_skill_request(&self, input: ApproveSkillRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "a4b", &self.region, "/");
+        request.set_operation("ApproveSkill");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AlexaForBusiness.ApproveSkill");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `associate_contact_with_address_book`, also returning the metadata of the response.
+    pub async fn associate_contact_with_address_book_with_metadata(
+        &self,
+        input: AssociateContactWithAddressBookRequest,
+    ) -> Result<
+        (AssociateContactWithAddressBookResponse, ResponseMetadata),
+        RusotoError<AssociateContactWithAddressBookError>,
+    > {
+        let request = self.build_associate_contact_with_address_book_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<ApproveSkillResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<AssociateContactWithAddressBookResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ApproveSkillError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(AssociateContactWithAddressBookError::from_response(
+                response,
+            ))
         }
     }
 
-    /// <p>Associates a contact with a given address book.</p>
-    async fn associate_contact_with_address_book(
+    /// Presigns the request `associate_contact_with_address_book` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_associate_contact_with_address_book(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: AssociateContactWithAddressBookRequest,
-    ) -> Result<
-        AssociateContactWithAddressBookResponse,
-        RusotoError<AssociateContactWithAddressBookError>,
-    > {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_associate_contact_with_address_book_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_associate_contact_with_address_book_request(
+        &self,
+        input: AssociateContactWithAddressBookRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "a4b", &self.region, "/");
+        request.set_operation("AssociateContactWithAddressBook");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -7412,33 +8163,60 @@ impl AlexaForBusiness for AlexaForBusinessClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `associate_device_with_network_profile`, also returning the metadata of the response.
+    pub async fn associate_device_with_network_profile_with_metadata(
+        &self,
+        input: AssociateDeviceWithNetworkProfileRequest,
+    ) -> Result<
+        (AssociateDeviceWithNetworkProfileResponse, ResponseMetadata),
+        RusotoError<AssociateDeviceWithNetworkProfileError>,
+    > {
+        let request = self.build_associate_device_with_network_profile_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<AssociateContactWithAddressBookResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<AssociateDeviceWithNetworkProfileResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(AssociateContactWithAddressBookError::from_response(
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(AssociateDeviceWithNetworkProfileError::from_response(
                 response,
             ))
         }
     }
 
-    /// <p>Associates a device with the specified network profile.</p>
-    async fn associate_device_with_network_profile(
+    /// Presigns the request `associate_device_with_network_profile` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_associate_device_with_network_profile(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: AssociateDeviceWithNetworkProfileRequest,
-    ) -> Result<
-        AssociateDeviceWithNetworkProfileResponse,
-        RusotoError<AssociateDeviceWithNetworkProfileError>,
-    > {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_associate_device_with_network_profile_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_associate_device_with_network_profile_request(
+        &self,
+        input: AssociateDeviceWithNetworkProfileRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "a4b", &self.region, "/");
+        request.set_operation("AssociateDeviceWithNetworkProfile");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -7448,59 +8226,116 @@ impl AlexaForBusiness for AlexaForBusinessClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `associate_device_with_room`, also returning the metadata of the response.
+    pub async fn associate_device_with_room_with_metadata(
+        &self,
+        input: AssociateDeviceWithRoomRequest,
+    ) -> Result<
+        (AssociateDeviceWithRoomResponse, ResponseMetadata),
+        RusotoError<AssociateDeviceWithRoomError>,
+    > {
+        let request = self.build_associate_device_with_room_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<AssociateDeviceWithNetworkProfileResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<AssociateDeviceWithRoomResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(AssociateDeviceWithNetworkProfileError::from_response(
-                response,
-            ))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(AssociateDeviceWithRoomError::from_response(response))
         }
     }
 
-    /// <p>Associates a device with a given room. This applies all the settings from the room profile to the device, and all the skills in any skill groups added to that room. This operation requires the device to be online, or else a manual sync is required. </p>
-    async fn associate_device_with_room(
+    /// Presigns the request `associate_device_with_room` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_associate_device_with_room(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: AssociateDeviceWithRoomRequest,
-    ) -> Result<AssociateDeviceWithRoomResponse, RusotoError<AssociateDeviceWithRoomError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_associate_device_with_room_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_associate_device_with_room_request(
+        &self,
+        input: AssociateDeviceWithRoomRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "a4b", &self.region, "/");
+        request.set_operation("AssociateDeviceWithRoom");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AlexaForBusiness.AssociateDeviceWithRoom");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `associate_skill_group_with_room`, also returning the metadata of the response.
+    pub async fn associate_skill_group_with_room_with_metadata(
+        &self,
+        input: AssociateSkillGroupWithRoomRequest,
+    ) -> Result<
+        (AssociateSkillGroupWithRoomResponse, ResponseMetadata),
+        RusotoError<AssociateSkillGroupWithRoomError>,
+    > {
+        let request = self.build_associate_skill_group_with_room_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<AssociateDeviceWithRoomResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<AssociateSkillGroupWithRoomResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(AssociateDeviceWithRoomError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(AssociateSkillGroupWithRoomError::from_response(response))
         }
     }
 
-    /// <p>Associates a skill group with a given room. This enables all skills in the associated skill group on all devices in the room.</p>
-    async fn associate_skill_group_with_room(
+    /// Presigns the request `associate_skill_group_with_room` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_associate_skill_group_with_room(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: AssociateSkillGroupWithRoomRequest,
-    ) -> Result<AssociateSkillGroupWithRoomResponse, RusotoError<AssociateSkillGroupWithRoomError>>
-    {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_associate_skill_group_with_room_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_associate_skill_group_with_room_request(
+        &self,
+        input: AssociateSkillGroupWithRoomRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "a4b", &self.region, "/");
+        request.set_operation("AssociateSkillGroupWithRoom");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -7510,29 +8345,58 @@ impl AlexaForBusiness for AlexaForBusinessClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `associate_skill_with_skill_group`, also returning the metadata of the response.
+    pub async fn associate_skill_with_skill_group_with_metadata(
+        &self,
+        input: AssociateSkillWithSkillGroupRequest,
+    ) -> Result<
+        (AssociateSkillWithSkillGroupResponse, ResponseMetadata),
+        RusotoError<AssociateSkillWithSkillGroupError>,
+    > {
+        let request = self.build_associate_skill_with_skill_group_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<AssociateSkillGroupWithRoomResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<AssociateSkillWithSkillGroupResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(AssociateSkillGroupWithRoomError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(AssociateSkillWithSkillGroupError::from_response(response))
         }
     }
 
-    /// <p>Associates a skill with a skill group.</p>
-    async fn associate_skill_with_skill_group(
+    /// Presigns the request `associate_skill_with_skill_group` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_associate_skill_with_skill_group(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: AssociateSkillWithSkillGroupRequest,
-    ) -> Result<AssociateSkillWithSkillGroupResponse, RusotoError<AssociateSkillWithSkillGroupError>>
-    {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_associate_skill_with_skill_group_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_associate_skill_with_skill_group_request(
+        &self,
+        input: AssociateSkillWithSkillGroupRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "a4b", &self.region, "/");
+        request.set_operation("AssociateSkillWithSkillGroup");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -7542,85 +8406,169 @@ impl AlexaForBusiness for AlexaForBusinessClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `associate_skill_with_users`, also returning the metadata of the response.
+    pub async fn associate_skill_with_users_with_metadata(
+        &self,
+        input: AssociateSkillWithUsersRequest,
+    ) -> Result<
+        (AssociateSkillWithUsersResponse, ResponseMetadata),
+        RusotoError<AssociateSkillWithUsersError>,
+    > {
+        let request = self.build_associate_skill_with_users_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<AssociateSkillWithSkillGroupResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<AssociateSkillWithUsersResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(AssociateSkillWithSkillGroupError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(AssociateSkillWithUsersError::from_response(response))
         }
     }
 
-    /// <p>Makes a private skill available for enrolled users to enable on their devices.</p>
-    async fn associate_skill_with_users(
+    /// Presigns the request `associate_skill_with_users` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_associate_skill_with_users(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: AssociateSkillWithUsersRequest,
-    ) -> Result<AssociateSkillWithUsersResponse, RusotoError<AssociateSkillWithUsersError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_associate_skill_with_users_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_associate_skill_with_users_request(
+        &self,
+        input: AssociateSkillWithUsersRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "a4b", &self.region, "/");
+        request.set_operation("AssociateSkillWithUsers");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AlexaForBusiness.AssociateSkillWithUsers");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `create_address_book`, also returning the metadata of the response.
+    pub async fn create_address_book_with_metadata(
+        &self,
+        input: CreateAddressBookRequest,
+    ) -> Result<(CreateAddressBookResponse, ResponseMetadata), RusotoError<CreateAddressBookError>>
+    {
+        let request = self.build_create_address_book_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<AssociateSkillWithUsersResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<CreateAddressBookResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(AssociateSkillWithUsersError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(CreateAddressBookError::from_response(response))
         }
     }
 
-    /// <p>Creates an address book with the specified details.</p>
-    async fn create_address_book(
+    /// Presigns the request `create_address_book` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_address_book(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: CreateAddressBookRequest,
-    ) -> Result<CreateAddressBookResponse, RusotoError<CreateAddressBookError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_address_book_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_address_book_request(&self, input: CreateAddressBookRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "a4b", &self.region, "/");
+        request.set_operation("CreateAddressBook");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AlexaForBusiness.CreateAddressBook");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `create_business_report_schedule`, also returning the metadata of the response.
+    pub async fn create_business_report_schedule_with_metadata(
+        &self,
+        input: CreateBusinessReportScheduleRequest,
+    ) -> Result<
+        (CreateBusinessReportScheduleResponse, ResponseMetadata),
+        RusotoError<CreateBusinessReportScheduleError>,
+    > {
+        let request = self.build_create_business_report_schedule_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<CreateAddressBookResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<CreateBusinessReportScheduleResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(CreateAddressBookError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(CreateBusinessReportScheduleError::from_response(response))
         }
     }
 
-    /// <p>Creates a recurring schedule for usage reports to deliver to the specified S3 location with a specified daily or weekly interval.</p>
-    async fn create_business_report_schedule(
+    /// Presigns the request `create_business_report_schedule` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_business_report_schedule(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: CreateBusinessReportScheduleRequest,
-    ) -> Result<CreateBusinessReportScheduleResponse, RusotoError<CreateBusinessReportScheduleError>>
-    {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_business_report_schedule_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_business_report_schedule_request(
+        &self,
+        input: CreateBusinessReportScheduleRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "a4b", &self.region, "/");
+        request.set_operation("CreateBusinessReportSchedule");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -7630,277 +8578,544 @@ impl AlexaForBusiness for AlexaForBusinessClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `create_conference_provider`, also returning the metadata of the response.
+    pub async fn create_conference_provider_with_metadata(
+        &self,
+        input: CreateConferenceProviderRequest,
+    ) -> Result<
+        (CreateConferenceProviderResponse, ResponseMetadata),
+        RusotoError<CreateConferenceProviderError>,
+    > {
+        let request = self.build_create_conference_provider_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<CreateBusinessReportScheduleResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<CreateConferenceProviderResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(CreateBusinessReportScheduleError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(CreateConferenceProviderError::from_response(response))
         }
     }
 
-    /// <p>Adds a new conference provider under the user's AWS account.</p>
-    async fn create_conference_provider(
+    /// Presigns the request `create_conference_provider` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_conference_provider(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: CreateConferenceProviderRequest,
-    ) -> Result<CreateConferenceProviderResponse, RusotoError<CreateConferenceProviderError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_conference_provider_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_conference_provider_request(
+        &self,
+        input: CreateConferenceProviderRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "a4b", &self.region, "/");
+        request.set_operation("CreateConferenceProvider");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AlexaForBusiness.CreateConferenceProvider");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `create_contact`, also returning the metadata of the response.
+    pub async fn create_contact_with_metadata(
+        &self,
+        input: CreateContactRequest,
+    ) -> Result<(CreateContactResponse, ResponseMetadata), RusotoError<CreateContactError>> {
+        let request = self.build_create_contact_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<CreateConferenceProviderResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<CreateContactResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(CreateConferenceProviderError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(CreateContactError::from_response(response))
         }
     }
 
-    /// <p>Creates a contact with the specified details.</p>
-    async fn create_contact(
+    /// Presigns the request `create_contact` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_contact(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: CreateContactRequest,
-    ) -> Result<CreateContactResponse, RusotoError<CreateContactError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_contact_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_contact_request(&self, input: CreateContactRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "a4b", &self.region, "/");
+        request.set_operation("CreateContact");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AlexaForBusiness.CreateContact");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `create_gateway_group`, also returning the metadata of the response.
+    pub async fn create_gateway_group_with_metadata(
+        &self,
+        input: CreateGatewayGroupRequest,
+    ) -> Result<(CreateGatewayGroupResponse, ResponseMetadata), RusotoError<CreateGatewayGroupError>>
+    {
+        let request = self.build_create_gateway_group_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<CreateContactResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<CreateGatewayGroupResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(CreateContactError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(CreateGatewayGroupError::from_response(response))
         }
     }
 
-    /// <p>Creates a gateway group with the specified details.</p>
-    async fn create_gateway_group(
+    /// Presigns the request `create_gateway_group` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_gateway_group(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: CreateGatewayGroupRequest,
-    ) -> Result<CreateGatewayGroupResponse, RusotoError<CreateGatewayGroupError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_gateway_group_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_gateway_group_request(
+        &self,
+        input: CreateGatewayGroupRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "a4b", &self.region, "/");
+        request.set_operation("CreateGatewayGroup");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AlexaForBusiness.CreateGatewayGroup");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `create_network_profile`, also returning the metadata of the response.
+    pub async fn create_network_profile_with_metadata(
+        &self,
+        input: CreateNetworkProfileRequest,
+    ) -> Result<
+        (CreateNetworkProfileResponse, ResponseMetadata),
+        RusotoError<CreateNetworkProfileError>,
+    > {
+        let request = self.build_create_network_profile_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<CreateGatewayGroupResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<CreateNetworkProfileResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(CreateGatewayGroupError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(CreateNetworkProfileError::from_response(response))
         }
     }
 
-    /// <p>Creates a network profile with the specified details.</p>
-    async fn create_network_profile(
+    /// Presigns the request `create_network_profile` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_network_profile(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: CreateNetworkProfileRequest,
-    ) -> Result<CreateNetworkProfileResponse, RusotoError<CreateNetworkProfileError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_network_profile_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_network_profile_request(
+        &self,
+        input: CreateNetworkProfileRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "a4b", &self.region, "/");
+        request.set_operation("CreateNetworkProfile");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AlexaForBusiness.CreateNetworkProfile");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `create_profile`, also returning the metadata of the response.
+    pub async fn create_profile_with_metadata(
+        &self,
+        input: CreateProfileRequest,
+    ) -> Result<(CreateProfileResponse, ResponseMetadata), RusotoError<CreateProfileError>> {
+        let request = self.build_create_profile_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<CreateNetworkProfileResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<CreateProfileResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(CreateNetworkProfileError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(CreateProfileError::from_response(response))
         }
     }
 
-    /// <p>Creates a new room profile with the specified details.</p>
-    async fn create_profile(
+    /// Presigns the request `create_profile` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_profile(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: CreateProfileRequest,
-    ) -> Result<CreateProfileResponse, RusotoError<CreateProfileError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_profile_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_profile_request(&self, input: CreateProfileRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "a4b", &self.region, "/");
+        request.set_operation("CreateProfile");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AlexaForBusiness.CreateProfile");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `create_room`, also returning the metadata of the response.
+    pub async fn create_room_with_metadata(
+        &self,
+        input: CreateRoomRequest,
+    ) -> Result<(CreateRoomResponse, ResponseMetadata), RusotoError<CreateRoomError>> {
+        let request = self.build_create_room_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<CreateProfileResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<CreateRoomResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(CreateProfileError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(CreateRoomError::from_response(response))
         }
     }
 
-    /// <p>Creates a room with the specified details.</p>
-    async fn create_room(
+    /// Presigns the request `create_room` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_room(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: CreateRoomRequest,
-    ) -> Result<CreateRoomResponse, RusotoError<CreateRoomError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_room_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_room_request(&self, input: CreateRoomRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "a4b", &self.region, "/");
+        request.set_operation("CreateRoom");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AlexaForBusiness.CreateRoom");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `create_skill_group`, also returning the metadata of the response.
+    pub async fn create_skill_group_with_metadata(
+        &self,
+        input: CreateSkillGroupRequest,
+    ) -> Result<(CreateSkillGroupResponse, ResponseMetadata), RusotoError<CreateSkillGroupError>>
+    {
+        let request = self.build_create_skill_group_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<CreateRoomResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<CreateSkillGroupResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(CreateRoomError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(CreateSkillGroupError::from_response(response))
         }
     }
 
-    /// <p>Creates a skill group with a specified name and description.</p>
-    async fn create_skill_group(
+    /// Presigns the request `create_skill_group` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_skill_group(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: CreateSkillGroupRequest,
-    ) -> Result<CreateSkillGroupResponse, RusotoError<CreateSkillGroupError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_skill_group_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_skill_group_request(&self, input: CreateSkillGroupRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "a4b", &self.region, "/");
+        request.set_operation("CreateSkillGroup");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AlexaForBusiness.CreateSkillGroup");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `create_user`, also returning the metadata of the response.
+    pub async fn create_user_with_metadata(
+        &self,
+        input: CreateUserRequest,
+    ) -> Result<(CreateUserResponse, ResponseMetadata), RusotoError<CreateUserError>> {
+        let request = self.build_create_user_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<CreateSkillGroupResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<CreateUserResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(CreateSkillGroupError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(CreateUserError::from_response(response))
         }
     }
 
-    /// <p>Creates a user.</p>
-    async fn create_user(
+    /// Presigns the request `create_user` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_user(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: CreateUserRequest,
-    ) -> Result<CreateUserResponse, RusotoError<CreateUserError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_user_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_user_request(&self, input: CreateUserRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "a4b", &self.region, "/");
+        request.set_operation("CreateUser");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AlexaForBusiness.CreateUser");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `delete_address_book`, also returning the metadata of the response.
+    pub async fn delete_address_book_with_metadata(
+        &self,
+        input: DeleteAddressBookRequest,
+    ) -> Result<(DeleteAddressBookResponse, ResponseMetadata), RusotoError<DeleteAddressBookError>>
+    {
+        let request = self.build_delete_address_book_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<CreateUserResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DeleteAddressBookResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(CreateUserError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DeleteAddressBookError::from_response(response))
         }
     }
 
-    /// <p>Deletes an address book by the address book ARN.</p>
-    async fn delete_address_book(
+    /// Presigns the request `delete_address_book` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_address_book(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteAddressBookRequest,
-    ) -> Result<DeleteAddressBookResponse, RusotoError<DeleteAddressBookError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_address_book_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_address_book_request(&self, input: DeleteAddressBookRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "a4b", &self.region, "/");
+        request.set_operation("DeleteAddressBook");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AlexaForBusiness.DeleteAddressBook");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `delete_business_report_schedule`, also returning the metadata of the response.
+    pub async fn delete_business_report_schedule_with_metadata(
+        &self,
+        input: DeleteBusinessReportScheduleRequest,
+    ) -> Result<
+        (DeleteBusinessReportScheduleResponse, ResponseMetadata),
+        RusotoError<DeleteBusinessReportScheduleError>,
+    > {
+        let request = self.build_delete_business_report_schedule_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DeleteAddressBookResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DeleteBusinessReportScheduleResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteAddressBookError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DeleteBusinessReportScheduleError::from_response(response))
         }
     }
 
-    /// <p>Deletes the recurring report delivery schedule with the specified schedule ARN.</p>
-    async fn delete_business_report_schedule(
+    /// Presigns the request `delete_business_report_schedule` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_business_report_schedule(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteBusinessReportScheduleRequest,
-    ) -> Result<DeleteBusinessReportScheduleResponse, RusotoError<DeleteBusinessReportScheduleError>>
-    {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_business_report_schedule_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_business_report_schedule_request(
+        &self,
+        input: DeleteBusinessReportScheduleRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "a4b", &self.region, "/");
+        request.set_operation("DeleteBusinessReportSchedule");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -7910,362 +9125,719 @@ impl AlexaForBusiness for AlexaForBusinessClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `delete_conference_provider`, also returning the metadata of the response.
+    pub async fn delete_conference_provider_with_metadata(
+        &self,
+        input: DeleteConferenceProviderRequest,
+    ) -> Result<
+        (DeleteConferenceProviderResponse, ResponseMetadata),
+        RusotoError<DeleteConferenceProviderError>,
+    > {
+        let request = self.build_delete_conference_provider_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DeleteBusinessReportScheduleResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DeleteConferenceProviderResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteBusinessReportScheduleError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DeleteConferenceProviderError::from_response(response))
         }
     }
 
-    /// <p>Deletes a conference provider.</p>
-    async fn delete_conference_provider(
+    /// Presigns the request `delete_conference_provider` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_conference_provider(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteConferenceProviderRequest,
-    ) -> Result<DeleteConferenceProviderResponse, RusotoError<DeleteConferenceProviderError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_conference_provider_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_conference_provider_request(
+        &self,
+        input: DeleteConferenceProviderRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "a4b", &self.region, "/");
+        request.set_operation("DeleteConferenceProvider");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AlexaForBusiness.DeleteConferenceProvider");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `delete_contact`, also returning the metadata of the response.
+    pub async fn delete_contact_with_metadata(
+        &self,
+        input: DeleteContactRequest,
+    ) -> Result<(DeleteContactResponse, ResponseMetadata), RusotoError<DeleteContactError>> {
+        let request = self.build_delete_contact_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DeleteConferenceProviderResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DeleteContactResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteConferenceProviderError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DeleteContactError::from_response(response))
         }
     }
 
-    /// <p>Deletes a contact by the contact ARN.</p>
-    async fn delete_contact(
+    /// Presigns the request `delete_contact` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_contact(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteContactRequest,
-    ) -> Result<DeleteContactResponse, RusotoError<DeleteContactError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_contact_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_contact_request(&self, input: DeleteContactRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "a4b", &self.region, "/");
+        request.set_operation("DeleteContact");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AlexaForBusiness.DeleteContact");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `delete_device`, also returning the metadata of the response.
+    pub async fn delete_device_with_metadata(
+        &self,
+        input: DeleteDeviceRequest,
+    ) -> Result<(DeleteDeviceResponse, ResponseMetadata), RusotoError<DeleteDeviceError>> {
+        let request = self.build_delete_device_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<DeleteContactResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DeleteDeviceResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteContactError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DeleteDeviceError::from_response(response))
         }
     }
 
-    /// <p>Removes a device from Alexa For Business.</p>
-    async fn delete_device(
+    /// Presigns the request `delete_device` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_device(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteDeviceRequest,
-    ) -> Result<DeleteDeviceResponse, RusotoError<DeleteDeviceError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_device_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_device_request(&self, input: DeleteDeviceRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "a4b", &self.region, "/");
+        request.set_operation("DeleteDevice");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AlexaForBusiness.DeleteDevice");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `delete_device_usage_data`, also returning the metadata of the response.
+    pub async fn delete_device_usage_data_with_metadata(
+        &self,
+        input: DeleteDeviceUsageDataRequest,
+    ) -> Result<
+        (DeleteDeviceUsageDataResponse, ResponseMetadata),
+        RusotoError<DeleteDeviceUsageDataError>,
+    > {
+        let request = self.build_delete_device_usage_data_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<DeleteDeviceResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DeleteDeviceUsageDataResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteDeviceError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DeleteDeviceUsageDataError::from_response(response))
         }
     }
 
-    /// <p>When this action is called for a specified shared device, it allows authorized users to delete the device's entire previous history of voice input data and associated response data. This action can be called once every 24 hours for a specific shared device.</p>
-    async fn delete_device_usage_data(
+    /// Presigns the request `delete_device_usage_data` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_device_usage_data(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteDeviceUsageDataRequest,
-    ) -> Result<DeleteDeviceUsageDataResponse, RusotoError<DeleteDeviceUsageDataError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_device_usage_data_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_device_usage_data_request(
+        &self,
+        input: DeleteDeviceUsageDataRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "a4b", &self.region, "/");
+        request.set_operation("DeleteDeviceUsageData");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AlexaForBusiness.DeleteDeviceUsageData");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `delete_gateway_group`, also returning the metadata of the response.
+    pub async fn delete_gateway_group_with_metadata(
+        &self,
+        input: DeleteGatewayGroupRequest,
+    ) -> Result<(DeleteGatewayGroupResponse, ResponseMetadata), RusotoError<DeleteGatewayGroupError>>
+    {
+        let request = self.build_delete_gateway_group_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DeleteDeviceUsageDataResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DeleteGatewayGroupResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteDeviceUsageDataError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DeleteGatewayGroupError::from_response(response))
         }
     }
 
-    /// <p>Deletes a gateway group.</p>
-    async fn delete_gateway_group(
+    /// Presigns the request `delete_gateway_group` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_gateway_group(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteGatewayGroupRequest,
-    ) -> Result<DeleteGatewayGroupResponse, RusotoError<DeleteGatewayGroupError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_gateway_group_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_gateway_group_request(
+        &self,
+        input: DeleteGatewayGroupRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "a4b", &self.region, "/");
+        request.set_operation("DeleteGatewayGroup");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AlexaForBusiness.DeleteGatewayGroup");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `delete_network_profile`, also returning the metadata of the response.
+    pub async fn delete_network_profile_with_metadata(
+        &self,
+        input: DeleteNetworkProfileRequest,
+    ) -> Result<
+        (DeleteNetworkProfileResponse, ResponseMetadata),
+        RusotoError<DeleteNetworkProfileError>,
+    > {
+        let request = self.build_delete_network_profile_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DeleteGatewayGroupResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DeleteNetworkProfileResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteGatewayGroupError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DeleteNetworkProfileError::from_response(response))
         }
     }
 
-    /// <p>Deletes a network profile by the network profile ARN.</p>
-    async fn delete_network_profile(
+    /// Presigns the request `delete_network_profile` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_network_profile(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteNetworkProfileRequest,
-    ) -> Result<DeleteNetworkProfileResponse, RusotoError<DeleteNetworkProfileError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_network_profile_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_network_profile_request(
+        &self,
+        input: DeleteNetworkProfileRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "a4b", &self.region, "/");
+        request.set_operation("DeleteNetworkProfile");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AlexaForBusiness.DeleteNetworkProfile");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `delete_profile`, also returning the metadata of the response.
+    pub async fn delete_profile_with_metadata(
+        &self,
+        input: DeleteProfileRequest,
+    ) -> Result<(DeleteProfileResponse, ResponseMetadata), RusotoError<DeleteProfileError>> {
+        let request = self.build_delete_profile_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DeleteNetworkProfileResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DeleteProfileResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteNetworkProfileError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DeleteProfileError::from_response(response))
         }
     }
 
-    /// <p>Deletes a room profile by the profile ARN.</p>
-    async fn delete_profile(
+    /// Presigns the request `delete_profile` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_profile(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteProfileRequest,
-    ) -> Result<DeleteProfileResponse, RusotoError<DeleteProfileError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_profile_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_profile_request(&self, input: DeleteProfileRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "a4b", &self.region, "/");
+        request.set_operation("DeleteProfile");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AlexaForBusiness.DeleteProfile");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `delete_room`, also returning the metadata of the response.
+    pub async fn delete_room_with_metadata(
+        &self,
+        input: DeleteRoomRequest,
+    ) -> Result<(DeleteRoomResponse, ResponseMetadata), RusotoError<DeleteRoomError>> {
+        let request = self.build_delete_room_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<DeleteProfileResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DeleteRoomResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteProfileError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DeleteRoomError::from_response(response))
         }
     }
 
-    /// <p>Deletes a room by the room ARN.</p>
-    async fn delete_room(
+    /// Presigns the request `delete_room` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_room(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteRoomRequest,
-    ) -> Result<DeleteRoomResponse, RusotoError<DeleteRoomError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_room_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_room_request(&self, input: DeleteRoomRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "a4b", &self.region, "/");
+        request.set_operation("DeleteRoom");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AlexaForBusiness.DeleteRoom");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `delete_room_skill_parameter`, also returning the metadata of the response.
+    pub async fn delete_room_skill_parameter_with_metadata(
+        &self,
+        input: DeleteRoomSkillParameterRequest,
+    ) -> Result<
+        (DeleteRoomSkillParameterResponse, ResponseMetadata),
+        RusotoError<DeleteRoomSkillParameterError>,
+    > {
+        let request = self.build_delete_room_skill_parameter_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<DeleteRoomResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DeleteRoomSkillParameterResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteRoomError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DeleteRoomSkillParameterError::from_response(response))
         }
     }
 
-    /// <p>Deletes room skill parameter details by room, skill, and parameter key ID.</p>
-    async fn delete_room_skill_parameter(
+    /// Presigns the request `delete_room_skill_parameter` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_room_skill_parameter(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteRoomSkillParameterRequest,
-    ) -> Result<DeleteRoomSkillParameterResponse, RusotoError<DeleteRoomSkillParameterError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_room_skill_parameter_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_room_skill_parameter_request(
+        &self,
+        input: DeleteRoomSkillParameterRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "a4b", &self.region, "/");
+        request.set_operation("DeleteRoomSkillParameter");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AlexaForBusiness.DeleteRoomSkillParameter");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `delete_skill_authorization`, also returning the metadata of the response.
+    pub async fn delete_skill_authorization_with_metadata(
+        &self,
+        input: DeleteSkillAuthorizationRequest,
+    ) -> Result<
+        (DeleteSkillAuthorizationResponse, ResponseMetadata),
+        RusotoError<DeleteSkillAuthorizationError>,
+    > {
+        let request = self.build_delete_skill_authorization_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DeleteRoomSkillParameterResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DeleteSkillAuthorizationResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteRoomSkillParameterError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DeleteSkillAuthorizationError::from_response(response))
         }
     }
 
-    /// <p>Unlinks a third-party account from a skill.</p>
-    async fn delete_skill_authorization(
+    /// Presigns the request `delete_skill_authorization` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_skill_authorization(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteSkillAuthorizationRequest,
-    ) -> Result<DeleteSkillAuthorizationResponse, RusotoError<DeleteSkillAuthorizationError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_skill_authorization_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_skill_authorization_request(
+        &self,
+        input: DeleteSkillAuthorizationRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "a4b", &self.region, "/");
+        request.set_operation("DeleteSkillAuthorization");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AlexaForBusiness.DeleteSkillAuthorization");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `delete_skill_group`, also returning the metadata of the response.
+    pub async fn delete_skill_group_with_metadata(
+        &self,
+        input: DeleteSkillGroupRequest,
+    ) -> Result<(DeleteSkillGroupResponse, ResponseMetadata), RusotoError<DeleteSkillGroupError>>
+    {
+        let request = self.build_delete_skill_group_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DeleteSkillAuthorizationResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DeleteSkillGroupResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteSkillAuthorizationError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DeleteSkillGroupError::from_response(response))
         }
     }
 
-    /// <p>Deletes a skill group by skill group ARN.</p>
-    async fn delete_skill_group(
+    /// Presigns the request `delete_skill_group` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_skill_group(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteSkillGroupRequest,
-    ) -> Result<DeleteSkillGroupResponse, RusotoError<DeleteSkillGroupError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_skill_group_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_skill_group_request(&self, input: DeleteSkillGroupRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "a4b", &self.region, "/");
+        request.set_operation("DeleteSkillGroup");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AlexaForBusiness.DeleteSkillGroup");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `delete_user`, also returning the metadata of the response.
+    pub async fn delete_user_with_metadata(
+        &self,
+        input: DeleteUserRequest,
+    ) -> Result<(DeleteUserResponse, ResponseMetadata), RusotoError<DeleteUserError>> {
+        let request = self.build_delete_user_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DeleteSkillGroupResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DeleteUserResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteSkillGroupError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DeleteUserError::from_response(response))
         }
     }
 
-    /// <p>Deletes a specified user by user ARN and enrollment ARN.</p>
-    async fn delete_user(
+    /// Presigns the request `delete_user` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_user(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteUserRequest,
-    ) -> Result<DeleteUserResponse, RusotoError<DeleteUserError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_user_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_user_request(&self, input: DeleteUserRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "a4b", &self.region, "/");
+        request.set_operation("DeleteUser");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AlexaForBusiness.DeleteUser");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `disassociate_contact_from_address_book`, also returning the metadata of the response.
+    pub async fn disassociate_contact_from_address_book_with_metadata(
+        &self,
+        input: DisassociateContactFromAddressBookRequest,
+    ) -> Result<
+        (DisassociateContactFromAddressBookResponse, ResponseMetadata),
+        RusotoError<DisassociateContactFromAddressBookError>,
+    > {
+        let request = self.build_disassociate_contact_from_address_book_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<DeleteUserResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DisassociateContactFromAddressBookResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteUserError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DisassociateContactFromAddressBookError::from_response(
+                response,
+            ))
         }
     }
 
-    /// <p>Disassociates a contact from a given address book.</p>
-    async fn disassociate_contact_from_address_book(
+    /// Presigns the request `disassociate_contact_from_address_book` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_disassociate_contact_from_address_book(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DisassociateContactFromAddressBookRequest,
-    ) -> Result<
-        DisassociateContactFromAddressBookResponse,
-        RusotoError<DisassociateContactFromAddressBookError>,
-    > {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_disassociate_contact_from_address_book_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_disassociate_contact_from_address_book_request(
+        &self,
+        input: DisassociateContactFromAddressBookRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "a4b", &self.region, "/");
+        request.set_operation("DisassociateContactFromAddressBook");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -8275,31 +9847,58 @@ impl AlexaForBusiness for AlexaForBusinessClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `disassociate_device_from_room`, also returning the metadata of the response.
+    pub async fn disassociate_device_from_room_with_metadata(
+        &self,
+        input: DisassociateDeviceFromRoomRequest,
+    ) -> Result<
+        (DisassociateDeviceFromRoomResponse, ResponseMetadata),
+        RusotoError<DisassociateDeviceFromRoomError>,
+    > {
+        let request = self.build_disassociate_device_from_room_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DisassociateContactFromAddressBookResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DisassociateDeviceFromRoomResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DisassociateContactFromAddressBookError::from_response(
-                response,
-            ))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DisassociateDeviceFromRoomError::from_response(response))
         }
     }
 
-    /// <p>Disassociates a device from its current room. The device continues to be connected to the Wi-Fi network and is still registered to the account. The device settings and skills are removed from the room.</p>
-    async fn disassociate_device_from_room(
+    /// Presigns the request `disassociate_device_from_room` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_disassociate_device_from_room(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DisassociateDeviceFromRoomRequest,
-    ) -> Result<DisassociateDeviceFromRoomResponse, RusotoError<DisassociateDeviceFromRoomError>>
-    {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_disassociate_device_from_room_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_disassociate_device_from_room_request(
+        &self,
+        input: DisassociateDeviceFromRoomRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "a4b", &self.region, "/");
+        request.set_operation("DisassociateDeviceFromRoom");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -8309,31 +9908,60 @@ impl AlexaForBusiness for AlexaForBusinessClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `disassociate_skill_from_skill_group`, also returning the metadata of the response.
+    pub async fn disassociate_skill_from_skill_group_with_metadata(
+        &self,
+        input: DisassociateSkillFromSkillGroupRequest,
+    ) -> Result<
+        (DisassociateSkillFromSkillGroupResponse, ResponseMetadata),
+        RusotoError<DisassociateSkillFromSkillGroupError>,
+    > {
+        let request = self.build_disassociate_skill_from_skill_group_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DisassociateDeviceFromRoomResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DisassociateSkillFromSkillGroupResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DisassociateDeviceFromRoomError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DisassociateSkillFromSkillGroupError::from_response(
+                response,
+            ))
         }
     }
 
-    /// <p>Disassociates a skill from a skill group.</p>
-    async fn disassociate_skill_from_skill_group(
+    /// Presigns the request `disassociate_skill_from_skill_group` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_disassociate_skill_from_skill_group(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DisassociateSkillFromSkillGroupRequest,
-    ) -> Result<
-        DisassociateSkillFromSkillGroupResponse,
-        RusotoError<DisassociateSkillFromSkillGroupError>,
-    > {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_disassociate_skill_from_skill_group_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_disassociate_skill_from_skill_group_request(
+        &self,
+        input: DisassociateSkillFromSkillGroupRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "a4b", &self.region, "/");
+        request.set_operation("DisassociateSkillFromSkillGroup");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -8343,31 +9971,58 @@ impl AlexaForBusiness for AlexaForBusinessClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `disassociate_skill_from_users`, also returning the metadata of the response.
+    pub async fn disassociate_skill_from_users_with_metadata(
+        &self,
+        input: DisassociateSkillFromUsersRequest,
+    ) -> Result<
+        (DisassociateSkillFromUsersResponse, ResponseMetadata),
+        RusotoError<DisassociateSkillFromUsersError>,
+    > {
+        let request = self.build_disassociate_skill_from_users_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DisassociateSkillFromSkillGroupResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DisassociateSkillFromUsersResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DisassociateSkillFromSkillGroupError::from_response(
-                response,
-            ))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DisassociateSkillFromUsersError::from_response(response))
         }
     }
 
-    /// <p>Makes a private skill unavailable for enrolled users and prevents them from enabling it on their devices.</p>
-    async fn disassociate_skill_from_users(
+    /// Presigns the request `disassociate_skill_from_users` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_disassociate_skill_from_users(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DisassociateSkillFromUsersRequest,
-    ) -> Result<DisassociateSkillFromUsersResponse, RusotoError<DisassociateSkillFromUsersError>>
-    {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_disassociate_skill_from_users_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_disassociate_skill_from_users_request(
+        &self,
+        input: DisassociateSkillFromUsersRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "a4b", &self.region, "/");
+        request.set_operation("DisassociateSkillFromUsers");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -8377,31 +10032,58 @@ impl AlexaForBusiness for AlexaForBusinessClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `disassociate_skill_group_from_room`, also returning the metadata of the response.
+    pub async fn disassociate_skill_group_from_room_with_metadata(
+        &self,
+        input: DisassociateSkillGroupFromRoomRequest,
+    ) -> Result<
+        (DisassociateSkillGroupFromRoomResponse, ResponseMetadata),
+        RusotoError<DisassociateSkillGroupFromRoomError>,
+    > {
+        let request = self.build_disassociate_skill_group_from_room_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DisassociateSkillFromUsersResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DisassociateSkillGroupFromRoomResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DisassociateSkillFromUsersError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DisassociateSkillGroupFromRoomError::from_response(response))
         }
     }
 
-    /// <p>Disassociates a skill group from a specified room. This disables all skills in the skill group on all devices in the room.</p>
-    async fn disassociate_skill_group_from_room(
+    /// Presigns the request `disassociate_skill_group_from_room` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_disassociate_skill_group_from_room(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DisassociateSkillGroupFromRoomRequest,
-    ) -> Result<
-        DisassociateSkillGroupFromRoomResponse,
-        RusotoError<DisassociateSkillGroupFromRoomError>,
-    > {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_disassociate_skill_group_from_room_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_disassociate_skill_group_from_room_request(
+        &self,
+        input: DisassociateSkillGroupFromRoomRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "a4b", &self.region, "/");
+        request.set_operation("DisassociateSkillGroupFromRoom");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -8411,246 +10093,482 @@ impl AlexaForBusiness for AlexaForBusinessClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `forget_smart_home_appliances`, also returning the metadata of the response.
+    pub async fn forget_smart_home_appliances_with_metadata(
+        &self,
+        input: ForgetSmartHomeAppliancesRequest,
+    ) -> Result<
+        (ForgetSmartHomeAppliancesResponse, ResponseMetadata),
+        RusotoError<ForgetSmartHomeAppliancesError>,
+    > {
+        let request = self.build_forget_smart_home_appliances_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DisassociateSkillGroupFromRoomResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ForgetSmartHomeAppliancesResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DisassociateSkillGroupFromRoomError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ForgetSmartHomeAppliancesError::from_response(response))
         }
     }
 
-    /// <p>Forgets smart home appliances associated to a room.</p>
-    async fn forget_smart_home_appliances(
+    /// Presigns the request `forget_smart_home_appliances` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_forget_smart_home_appliances(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ForgetSmartHomeAppliancesRequest,
-    ) -> Result<ForgetSmartHomeAppliancesResponse, RusotoError<ForgetSmartHomeAppliancesError>>
-    {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_forget_smart_home_appliances_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_forget_smart_home_appliances_request(
+        &self,
+        input: ForgetSmartHomeAppliancesRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "a4b", &self.region, "/");
+        request.set_operation("ForgetSmartHomeAppliances");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AlexaForBusiness.ForgetSmartHomeAppliances");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `get_address_book`, also returning the metadata of the response.
+    pub async fn get_address_book_with_metadata(
+        &self,
+        input: GetAddressBookRequest,
+    ) -> Result<(GetAddressBookResponse, ResponseMetadata), RusotoError<GetAddressBookError>> {
+        let request = self.build_get_address_book_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<ForgetSmartHomeAppliancesResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<GetAddressBookResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ForgetSmartHomeAppliancesError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(GetAddressBookError::from_response(response))
         }
     }
 
-    /// <p>Gets address the book details by the address book ARN.</p>
-    async fn get_address_book(
+    /// Presigns the request `get_address_book` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_address_book(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetAddressBookRequest,
-    ) -> Result<GetAddressBookResponse, RusotoError<GetAddressBookError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_address_book_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_address_book_request(&self, input: GetAddressBookRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "a4b", &self.region, "/");
+        request.set_operation("GetAddressBook");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AlexaForBusiness.GetAddressBook");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `get_conference_preference`, also returning the metadata of the response.
+    pub async fn get_conference_preference_with_metadata(
+        &self,
+    ) -> Result<
+        (GetConferencePreferenceResponse, ResponseMetadata),
+        RusotoError<GetConferencePreferenceError>,
+    > {
+        let request = self.build_get_conference_preference_request();
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<GetAddressBookResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<GetConferencePreferenceResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(GetAddressBookError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(GetConferencePreferenceError::from_response(response))
         }
     }
 
-    /// <p>Retrieves the existing conference preferences.</p>
-    async fn get_conference_preference(
+    /// Presigns the request `get_conference_preference` would send with `presigner` instead of sending it.
+    pub async fn presign_get_conference_preference(
         &self,
-    ) -> Result<GetConferencePreferenceResponse, RusotoError<GetConferencePreferenceError>> {
+        presigner: &rusoto_core::Presigner,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_conference_preference_request();
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_conference_preference_request(&self) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "a4b", &self.region, "/");
+        request.set_operation("GetConferencePreference");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AlexaForBusiness.GetConferencePreference");
         request.set_payload(Some(bytes::Bytes::from_static(b"{}")));
 
+        request
+    }
+
+    /// Calls `get_conference_provider`, also returning the metadata of the response.
+    pub async fn get_conference_provider_with_metadata(
+        &self,
+        input: GetConferenceProviderRequest,
+    ) -> Result<
+        (GetConferenceProviderResponse, ResponseMetadata),
+        RusotoError<GetConferenceProviderError>,
+    > {
+        let request = self.build_get_conference_provider_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<GetConferencePreferenceResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<GetConferenceProviderResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(GetConferencePreferenceError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(GetConferenceProviderError::from_response(response))
         }
     }
 
-    /// <p>Gets details about a specific conference provider.</p>
-    async fn get_conference_provider(
+    /// Presigns the request `get_conference_provider` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_conference_provider(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetConferenceProviderRequest,
-    ) -> Result<GetConferenceProviderResponse, RusotoError<GetConferenceProviderError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_conference_provider_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_conference_provider_request(
+        &self,
+        input: GetConferenceProviderRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "a4b", &self.region, "/");
+        request.set_operation("GetConferenceProvider");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AlexaForBusiness.GetConferenceProvider");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `get_contact`, also returning the metadata of the response.
+    pub async fn get_contact_with_metadata(
+        &self,
+        input: GetContactRequest,
+    ) -> Result<(GetContactResponse, ResponseMetadata), RusotoError<GetContactError>> {
+        let request = self.build_get_contact_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<GetConferenceProviderResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<GetContactResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(GetConferenceProviderError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(GetContactError::from_response(response))
         }
     }
 
-    /// <p>Gets the contact details by the contact ARN.</p>
-    async fn get_contact(
+    /// Presigns the request `get_contact` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_contact(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetContactRequest,
-    ) -> Result<GetContactResponse, RusotoError<GetContactError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_contact_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_contact_request(&self, input: GetContactRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "a4b", &self.region, "/");
+        request.set_operation("GetContact");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AlexaForBusiness.GetContact");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `get_device`, also returning the metadata of the response.
+    pub async fn get_device_with_metadata(
+        &self,
+        input: GetDeviceRequest,
+    ) -> Result<(GetDeviceResponse, ResponseMetadata), RusotoError<GetDeviceError>> {
+        let request = self.build_get_device_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<GetContactResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<GetDeviceResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(GetContactError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(GetDeviceError::from_response(response))
         }
     }
 
-    /// <p>Gets the details of a device by device ARN.</p>
-    async fn get_device(
+    /// Presigns the request `get_device` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_device(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetDeviceRequest,
-    ) -> Result<GetDeviceResponse, RusotoError<GetDeviceError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_device_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_device_request(&self, input: GetDeviceRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "a4b", &self.region, "/");
+        request.set_operation("GetDevice");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AlexaForBusiness.GetDevice");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `get_gateway`, also returning the metadata of the response.
+    pub async fn get_gateway_with_metadata(
+        &self,
+        input: GetGatewayRequest,
+    ) -> Result<(GetGatewayResponse, ResponseMetadata), RusotoError<GetGatewayError>> {
+        let request = self.build_get_gateway_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<GetDeviceResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<GetGatewayResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(GetDeviceError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(GetGatewayError::from_response(response))
         }
     }
 
-    /// <p>Retrieves the details of a gateway.</p>
-    async fn get_gateway(
+    /// Presigns the request `get_gateway` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_gateway(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetGatewayRequest,
-    ) -> Result<GetGatewayResponse, RusotoError<GetGatewayError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_gateway_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_gateway_request(&self, input: GetGatewayRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "a4b", &self.region, "/");
+        request.set_operation("GetGateway");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AlexaForBusiness.GetGateway");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `get_gateway_group`, also returning the metadata of the response.
+    pub async fn get_gateway_group_with_metadata(
+        &self,
+        input: GetGatewayGroupRequest,
+    ) -> Result<(GetGatewayGroupResponse, ResponseMetadata), RusotoError<GetGatewayGroupError>>
+    {
+        let request = self.build_get_gateway_group_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<GetGatewayResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<GetGatewayGroupResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(GetGatewayError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(GetGatewayGroupError::from_response(response))
         }
     }
 
-    /// <p>Retrieves the details of a gateway group.</p>
-    async fn get_gateway_group(
+    /// Presigns the request `get_gateway_group` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_gateway_group(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetGatewayGroupRequest,
-    ) -> Result<GetGatewayGroupResponse, RusotoError<GetGatewayGroupError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_gateway_group_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_gateway_group_request(&self, input: GetGatewayGroupRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "a4b", &self.region, "/");
+        request.set_operation("GetGatewayGroup");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AlexaForBusiness.GetGatewayGroup");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `get_invitation_configuration`, also returning the metadata of the response.
+    pub async fn get_invitation_configuration_with_metadata(
+        &self,
+    ) -> Result<
+        (GetInvitationConfigurationResponse, ResponseMetadata),
+        RusotoError<GetInvitationConfigurationError>,
+    > {
+        let request = self.build_get_invitation_configuration_request();
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<GetGatewayGroupResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<GetInvitationConfigurationResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(GetGatewayGroupError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(GetInvitationConfigurationError::from_response(response))
         }
     }
 
-    /// <p>Retrieves the configured values for the user enrollment invitation email template.</p>
-    async fn get_invitation_configuration(
+    /// Presigns the request `get_invitation_configuration` would send with `presigner` instead of sending it.
+    pub async fn presign_get_invitation_configuration(
         &self,
-    ) -> Result<GetInvitationConfigurationResponse, RusotoError<GetInvitationConfigurationError>>
-    {
+        presigner: &rusoto_core::Presigner,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_invitation_configuration_request();
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_invitation_configuration_request(&self) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "a4b", &self.region, "/");
+        request.set_operation("GetInvitationConfiguration");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -8659,166 +10577,325 @@ impl AlexaForBusiness for AlexaForBusinessClient {
         );
         request.set_payload(Some(bytes::Bytes::from_static(b"{}")));
 
+        request
+    }
+
+    /// Calls `get_network_profile`, also returning the metadata of the response.
+    pub async fn get_network_profile_with_metadata(
+        &self,
+        input: GetNetworkProfileRequest,
+    ) -> Result<(GetNetworkProfileResponse, ResponseMetadata), RusotoError<GetNetworkProfileError>>
+    {
+        let request = self.build_get_network_profile_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<GetInvitationConfigurationResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<GetNetworkProfileResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(GetInvitationConfigurationError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(GetNetworkProfileError::from_response(response))
         }
     }
 
-    /// <p>Gets the network profile details by the network profile ARN.</p>
-    async fn get_network_profile(
+    /// Presigns the request `get_network_profile` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_network_profile(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetNetworkProfileRequest,
-    ) -> Result<GetNetworkProfileResponse, RusotoError<GetNetworkProfileError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_network_profile_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_network_profile_request(&self, input: GetNetworkProfileRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "a4b", &self.region, "/");
+        request.set_operation("GetNetworkProfile");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AlexaForBusiness.GetNetworkProfile");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `get_profile`, also returning the metadata of the response.
+    pub async fn get_profile_with_metadata(
+        &self,
+        input: GetProfileRequest,
+    ) -> Result<(GetProfileResponse, ResponseMetadata), RusotoError<GetProfileError>> {
+        let request = self.build_get_profile_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<GetNetworkProfileResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<GetProfileResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(GetNetworkProfileError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(GetProfileError::from_response(response))
         }
     }
 
-    /// <p>Gets the details of a room profile by profile ARN.</p>
-    async fn get_profile(
+    /// Presigns the request `get_profile` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_profile(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetProfileRequest,
-    ) -> Result<GetProfileResponse, RusotoError<GetProfileError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_profile_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_profile_request(&self, input: GetProfileRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "a4b", &self.region, "/");
+        request.set_operation("GetProfile");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AlexaForBusiness.GetProfile");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `get_room`, also returning the metadata of the response.
+    pub async fn get_room_with_metadata(
+        &self,
+        input: GetRoomRequest,
+    ) -> Result<(GetRoomResponse, ResponseMetadata), RusotoError<GetRoomError>> {
+        let request = self.build_get_room_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<GetProfileResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result =
+                proto::json::ResponsePayload::new(&response).deserialize::<GetRoomResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(GetProfileError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(GetRoomError::from_response(response))
         }
     }
 
-    /// <p>Gets room details by room ARN.</p>
-    async fn get_room(
+    /// Presigns the request `get_room` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_room(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetRoomRequest,
-    ) -> Result<GetRoomResponse, RusotoError<GetRoomError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_room_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_room_request(&self, input: GetRoomRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "a4b", &self.region, "/");
+        request.set_operation("GetRoom");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AlexaForBusiness.GetRoom");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `get_room_skill_parameter`, also returning the metadata of the response.
+    pub async fn get_room_skill_parameter_with_metadata(
+        &self,
+        input: GetRoomSkillParameterRequest,
+    ) -> Result<
+        (GetRoomSkillParameterResponse, ResponseMetadata),
+        RusotoError<GetRoomSkillParameterError>,
+    > {
+        let request = self.build_get_room_skill_parameter_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<GetRoomResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<GetRoomSkillParameterResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(GetRoomError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(GetRoomSkillParameterError::from_response(response))
         }
     }
 
-    /// <p>Gets room skill parameter details by room, skill, and parameter key ARN.</p>
-    async fn get_room_skill_parameter(
+    /// Presigns the request `get_room_skill_parameter` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_room_skill_parameter(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetRoomSkillParameterRequest,
-    ) -> Result<GetRoomSkillParameterResponse, RusotoError<GetRoomSkillParameterError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_room_skill_parameter_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_room_skill_parameter_request(
+        &self,
+        input: GetRoomSkillParameterRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "a4b", &self.region, "/");
+        request.set_operation("GetRoomSkillParameter");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AlexaForBusiness.GetRoomSkillParameter");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `get_skill_group`, also returning the metadata of the response.
+    pub async fn get_skill_group_with_metadata(
+        &self,
+        input: GetSkillGroupRequest,
+    ) -> Result<(GetSkillGroupResponse, ResponseMetadata), RusotoError<GetSkillGroupError>> {
+        let request = self.build_get_skill_group_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<GetRoomSkillParameterResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<GetSkillGroupResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(GetRoomSkillParameterError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(GetSkillGroupError::from_response(response))
         }
     }
 
-    /// <p>Gets skill group details by skill group ARN.</p>
-    async fn get_skill_group(
+    /// Presigns the request `get_skill_group` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_skill_group(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetSkillGroupRequest,
-    ) -> Result<GetSkillGroupResponse, RusotoError<GetSkillGroupError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_skill_group_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_skill_group_request(&self, input: GetSkillGroupRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "a4b", &self.region, "/");
+        request.set_operation("GetSkillGroup");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AlexaForBusiness.GetSkillGroup");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `list_business_report_schedules`, also returning the metadata of the response.
+    pub async fn list_business_report_schedules_with_metadata(
+        &self,
+        input: ListBusinessReportSchedulesRequest,
+    ) -> Result<
+        (ListBusinessReportSchedulesResponse, ResponseMetadata),
+        RusotoError<ListBusinessReportSchedulesError>,
+    > {
+        let request = self.build_list_business_report_schedules_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<GetSkillGroupResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ListBusinessReportSchedulesResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(GetSkillGroupError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ListBusinessReportSchedulesError::from_response(response))
         }
     }
 
-    /// <p>Lists the details of the schedules that a user configured. A download URL of the report associated with each schedule is returned every time this action is called. A new download URL is returned each time, and is valid for 24 hours.</p>
-    async fn list_business_report_schedules(
+    /// Presigns the request `list_business_report_schedules` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_business_report_schedules(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListBusinessReportSchedulesRequest,
-    ) -> Result<ListBusinessReportSchedulesResponse, RusotoError<ListBusinessReportSchedulesError>>
-    {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_business_report_schedules_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_business_report_schedules_request(
+        &self,
+        input: ListBusinessReportSchedulesRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "a4b", &self.region, "/");
+        request.set_operation("ListBusinessReportSchedules");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -8828,198 +10905,386 @@ impl AlexaForBusiness for AlexaForBusinessClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `list_conference_providers`, also returning the metadata of the response.
+    pub async fn list_conference_providers_with_metadata(
+        &self,
+        input: ListConferenceProvidersRequest,
+    ) -> Result<
+        (ListConferenceProvidersResponse, ResponseMetadata),
+        RusotoError<ListConferenceProvidersError>,
+    > {
+        let request = self.build_list_conference_providers_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListBusinessReportSchedulesResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ListConferenceProvidersResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ListBusinessReportSchedulesError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ListConferenceProvidersError::from_response(response))
         }
     }
 
-    /// <p>Lists conference providers under a specific AWS account.</p>
-    async fn list_conference_providers(
+    /// Presigns the request `list_conference_providers` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_conference_providers(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListConferenceProvidersRequest,
-    ) -> Result<ListConferenceProvidersResponse, RusotoError<ListConferenceProvidersError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_conference_providers_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_conference_providers_request(
+        &self,
+        input: ListConferenceProvidersRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "a4b", &self.region, "/");
+        request.set_operation("ListConferenceProviders");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AlexaForBusiness.ListConferenceProviders");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `list_device_events`, also returning the metadata of the response.
+    pub async fn list_device_events_with_metadata(
+        &self,
+        input: ListDeviceEventsRequest,
+    ) -> Result<(ListDeviceEventsResponse, ResponseMetadata), RusotoError<ListDeviceEventsError>>
+    {
+        let request = self.build_list_device_events_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListConferenceProvidersResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ListDeviceEventsResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ListConferenceProvidersError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ListDeviceEventsError::from_response(response))
         }
     }
 
-    /// <p>Lists the device event history, including device connection status, for up to 30 days.</p>
-    async fn list_device_events(
+    /// Presigns the request `list_device_events` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_device_events(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListDeviceEventsRequest,
-    ) -> Result<ListDeviceEventsResponse, RusotoError<ListDeviceEventsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_device_events_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_device_events_request(&self, input: ListDeviceEventsRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "a4b", &self.region, "/");
+        request.set_operation("ListDeviceEvents");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AlexaForBusiness.ListDeviceEvents");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `list_gateway_groups`, also returning the metadata of the response.
+    pub async fn list_gateway_groups_with_metadata(
+        &self,
+        input: ListGatewayGroupsRequest,
+    ) -> Result<(ListGatewayGroupsResponse, ResponseMetadata), RusotoError<ListGatewayGroupsError>>
+    {
+        let request = self.build_list_gateway_groups_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListDeviceEventsResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ListGatewayGroupsResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ListDeviceEventsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ListGatewayGroupsError::from_response(response))
         }
     }
 
-    /// <p>Retrieves a list of gateway group summaries. Use GetGatewayGroup to retrieve details of a specific gateway group.</p>
-    async fn list_gateway_groups(
+    /// Presigns the request `list_gateway_groups` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_gateway_groups(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListGatewayGroupsRequest,
-    ) -> Result<ListGatewayGroupsResponse, RusotoError<ListGatewayGroupsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_gateway_groups_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_gateway_groups_request(&self, input: ListGatewayGroupsRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "a4b", &self.region, "/");
+        request.set_operation("ListGatewayGroups");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AlexaForBusiness.ListGatewayGroups");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `list_gateways`, also returning the metadata of the response.
+    pub async fn list_gateways_with_metadata(
+        &self,
+        input: ListGatewaysRequest,
+    ) -> Result<(ListGatewaysResponse, ResponseMetadata), RusotoError<ListGatewaysError>> {
+        let request = self.build_list_gateways_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListGatewayGroupsResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ListGatewaysResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ListGatewayGroupsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ListGatewaysError::from_response(response))
         }
     }
 
-    /// <p>Retrieves a list of gateway summaries. Use GetGateway to retrieve details of a specific gateway. An optional gateway group ARN can be provided to only retrieve gateway summaries of gateways that are associated with that gateway group ARN.</p>
-    async fn list_gateways(
+    /// Presigns the request `list_gateways` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_gateways(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListGatewaysRequest,
-    ) -> Result<ListGatewaysResponse, RusotoError<ListGatewaysError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_gateways_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_gateways_request(&self, input: ListGatewaysRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "a4b", &self.region, "/");
+        request.set_operation("ListGateways");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AlexaForBusiness.ListGateways");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `list_skills`, also returning the metadata of the response.
+    pub async fn list_skills_with_metadata(
+        &self,
+        input: ListSkillsRequest,
+    ) -> Result<(ListSkillsResponse, ResponseMetadata), RusotoError<ListSkillsError>> {
+        let request = self.build_list_skills_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<ListGatewaysResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ListSkillsResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ListGatewaysError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ListSkillsError::from_response(response))
         }
     }
 
-    /// <p>Lists all enabled skills in a specific skill group.</p>
-    async fn list_skills(
+    /// Presigns the request `list_skills` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_skills(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListSkillsRequest,
-    ) -> Result<ListSkillsResponse, RusotoError<ListSkillsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_skills_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_skills_request(&self, input: ListSkillsRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "a4b", &self.region, "/");
+        request.set_operation("ListSkills");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AlexaForBusiness.ListSkills");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `list_skills_store_categories`, also returning the metadata of the response.
+    pub async fn list_skills_store_categories_with_metadata(
+        &self,
+        input: ListSkillsStoreCategoriesRequest,
+    ) -> Result<
+        (ListSkillsStoreCategoriesResponse, ResponseMetadata),
+        RusotoError<ListSkillsStoreCategoriesError>,
+    > {
+        let request = self.build_list_skills_store_categories_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<ListSkillsResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ListSkillsStoreCategoriesResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ListSkillsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ListSkillsStoreCategoriesError::from_response(response))
         }
     }
 
-    /// <p>Lists all categories in the Alexa skill store.</p>
-    async fn list_skills_store_categories(
+    /// Presigns the request `list_skills_store_categories` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_skills_store_categories(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListSkillsStoreCategoriesRequest,
-    ) -> Result<ListSkillsStoreCategoriesResponse, RusotoError<ListSkillsStoreCategoriesError>>
-    {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_skills_store_categories_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_skills_store_categories_request(
+        &self,
+        input: ListSkillsStoreCategoriesRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "a4b", &self.region, "/");
+        request.set_operation("ListSkillsStoreCategories");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AlexaForBusiness.ListSkillsStoreCategories");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
-        let mut response = self
-            .client
-            .sign_and_dispatch(request)
-            .await
-            .map_err(RusotoError::from)?;
-        if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListSkillsStoreCategoriesResponse, _>()
-        } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ListSkillsStoreCategoriesError::from_response(response))
-        }
+        request
     }
 
-    /// <p>Lists all skills in the Alexa skill store by category.</p>
-    async fn list_skills_store_skills_by_category(
+    /// Calls `list_skills_store_skills_by_category`, also returning the metadata of the response.
+    pub async fn list_skills_store_skills_by_category_with_metadata(
         &self,
         input: ListSkillsStoreSkillsByCategoryRequest,
     ) -> Result<
-        ListSkillsStoreSkillsByCategoryResponse,
+        (ListSkillsStoreSkillsByCategoryResponse, ResponseMetadata),
         RusotoError<ListSkillsStoreSkillsByCategoryError>,
     > {
+        let request = self.build_list_skills_store_skills_by_category_request(input);
+
+        let mut response = self
+            .client
+            .sign_and_dispatch(request)
+            .await
+            .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
+        if response.status.is_success() {
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ListSkillsStoreSkillsByCategoryResponse, _>()?;
+            Ok((result, metadata))
+        } else {
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ListSkillsStoreSkillsByCategoryError::from_response(
+                response,
+            ))
+        }
+    }
+
+    /// Presigns the request `list_skills_store_skills_by_category` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_skills_store_skills_by_category(
+        &self,
+        presigner: &rusoto_core::Presigner,
+        input: ListSkillsStoreSkillsByCategoryRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_skills_store_skills_by_category_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_skills_store_skills_by_category_request(
+        &self,
+        input: ListSkillsStoreSkillsByCategoryRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "a4b", &self.region, "/");
+        request.set_operation("ListSkillsStoreSkillsByCategory");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -9029,114 +11294,226 @@ impl AlexaForBusiness for AlexaForBusinessClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `list_smart_home_appliances`, also returning the metadata of the response.
+    pub async fn list_smart_home_appliances_with_metadata(
+        &self,
+        input: ListSmartHomeAppliancesRequest,
+    ) -> Result<
+        (ListSmartHomeAppliancesResponse, ResponseMetadata),
+        RusotoError<ListSmartHomeAppliancesError>,
+    > {
+        let request = self.build_list_smart_home_appliances_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListSkillsStoreSkillsByCategoryResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ListSmartHomeAppliancesResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ListSkillsStoreSkillsByCategoryError::from_response(
-                response,
-            ))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ListSmartHomeAppliancesError::from_response(response))
         }
     }
 
-    /// <p>Lists all of the smart home appliances associated with a room.</p>
-    async fn list_smart_home_appliances(
+    /// Presigns the request `list_smart_home_appliances` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_smart_home_appliances(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListSmartHomeAppliancesRequest,
-    ) -> Result<ListSmartHomeAppliancesResponse, RusotoError<ListSmartHomeAppliancesError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_smart_home_appliances_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_smart_home_appliances_request(
+        &self,
+        input: ListSmartHomeAppliancesRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "a4b", &self.region, "/");
+        request.set_operation("ListSmartHomeAppliances");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AlexaForBusiness.ListSmartHomeAppliances");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `list_tags`, also returning the metadata of the response.
+    pub async fn list_tags_with_metadata(
+        &self,
+        input: ListTagsRequest,
+    ) -> Result<(ListTagsResponse, ResponseMetadata), RusotoError<ListTagsError>> {
+        let request = self.build_list_tags_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListSmartHomeAppliancesResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ListTagsResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ListSmartHomeAppliancesError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ListTagsError::from_response(response))
         }
     }
 
-    /// <p>Lists all tags for the specified resource.</p>
-    async fn list_tags(
+    /// Presigns the request `list_tags` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_tags(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListTagsRequest,
-    ) -> Result<ListTagsResponse, RusotoError<ListTagsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_tags_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_tags_request(&self, input: ListTagsRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "a4b", &self.region, "/");
+        request.set_operation("ListTags");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AlexaForBusiness.ListTags");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `put_conference_preference`, also returning the metadata of the response.
+    pub async fn put_conference_preference_with_metadata(
+        &self,
+        input: PutConferencePreferenceRequest,
+    ) -> Result<
+        (PutConferencePreferenceResponse, ResponseMetadata),
+        RusotoError<PutConferencePreferenceError>,
+    > {
+        let request = self.build_put_conference_preference_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<ListTagsResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<PutConferencePreferenceResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ListTagsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(PutConferencePreferenceError::from_response(response))
         }
     }
 
-    /// <p>Sets the conference preferences on a specific conference provider at the account level.</p>
-    async fn put_conference_preference(
+    /// Presigns the request `put_conference_preference` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_put_conference_preference(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: PutConferencePreferenceRequest,
-    ) -> Result<PutConferencePreferenceResponse, RusotoError<PutConferencePreferenceError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_put_conference_preference_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_put_conference_preference_request(
+        &self,
+        input: PutConferencePreferenceRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "a4b", &self.region, "/");
+        request.set_operation("PutConferencePreference");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AlexaForBusiness.PutConferencePreference");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `put_invitation_configuration`, also returning the metadata of the response.
+    pub async fn put_invitation_configuration_with_metadata(
+        &self,
+        input: PutInvitationConfigurationRequest,
+    ) -> Result<
+        (PutInvitationConfigurationResponse, ResponseMetadata),
+        RusotoError<PutInvitationConfigurationError>,
+    > {
+        let request = self.build_put_invitation_configuration_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<PutConferencePreferenceResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<PutInvitationConfigurationResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(PutConferencePreferenceError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(PutInvitationConfigurationError::from_response(response))
         }
     }
 
-    /// <p>Configures the email template for the user enrollment invitation with the specified attributes.</p>
-    async fn put_invitation_configuration(
+    /// Presigns the request `put_invitation_configuration` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_put_invitation_configuration(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: PutInvitationConfigurationRequest,
-    ) -> Result<PutInvitationConfigurationResponse, RusotoError<PutInvitationConfigurationError>>
-    {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_put_invitation_configuration_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_put_invitation_configuration_request(
+        &self,
+        input: PutInvitationConfigurationRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "a4b", &self.region, "/");
+        request.set_operation("PutInvitationConfiguration");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -9146,498 +11523,971 @@ impl AlexaForBusiness for AlexaForBusinessClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `put_room_skill_parameter`, also returning the metadata of the response.
+    pub async fn put_room_skill_parameter_with_metadata(
+        &self,
+        input: PutRoomSkillParameterRequest,
+    ) -> Result<
+        (PutRoomSkillParameterResponse, ResponseMetadata),
+        RusotoError<PutRoomSkillParameterError>,
+    > {
+        let request = self.build_put_room_skill_parameter_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<PutInvitationConfigurationResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<PutRoomSkillParameterResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(PutInvitationConfigurationError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(PutRoomSkillParameterError::from_response(response))
         }
     }
 
-    /// <p>Updates room skill parameter details by room, skill, and parameter key ID. Not all skills have a room skill parameter.</p>
-    async fn put_room_skill_parameter(
+    /// Presigns the request `put_room_skill_parameter` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_put_room_skill_parameter(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: PutRoomSkillParameterRequest,
-    ) -> Result<PutRoomSkillParameterResponse, RusotoError<PutRoomSkillParameterError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_put_room_skill_parameter_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_put_room_skill_parameter_request(
+        &self,
+        input: PutRoomSkillParameterRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "a4b", &self.region, "/");
+        request.set_operation("PutRoomSkillParameter");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AlexaForBusiness.PutRoomSkillParameter");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `put_skill_authorization`, also returning the metadata of the response.
+    pub async fn put_skill_authorization_with_metadata(
+        &self,
+        input: PutSkillAuthorizationRequest,
+    ) -> Result<
+        (PutSkillAuthorizationResponse, ResponseMetadata),
+        RusotoError<PutSkillAuthorizationError>,
+    > {
+        let request = self.build_put_skill_authorization_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<PutRoomSkillParameterResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<PutSkillAuthorizationResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(PutRoomSkillParameterError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(PutSkillAuthorizationError::from_response(response))
         }
     }
 
-    /// <p>Links a user's account to a third-party skill provider. If this API operation is called by an assumed IAM role, the skill being linked must be a private skill. Also, the skill must be owned by the AWS account that assumed the IAM role.</p>
-    async fn put_skill_authorization(
+    /// Presigns the request `put_skill_authorization` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_put_skill_authorization(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: PutSkillAuthorizationRequest,
-    ) -> Result<PutSkillAuthorizationResponse, RusotoError<PutSkillAuthorizationError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_put_skill_authorization_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_put_skill_authorization_request(
+        &self,
+        input: PutSkillAuthorizationRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "a4b", &self.region, "/");
+        request.set_operation("PutSkillAuthorization");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AlexaForBusiness.PutSkillAuthorization");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `register_avs_device`, also returning the metadata of the response.
+    pub async fn register_avs_device_with_metadata(
+        &self,
+        input: RegisterAVSDeviceRequest,
+    ) -> Result<(RegisterAVSDeviceResponse, ResponseMetadata), RusotoError<RegisterAVSDeviceError>>
+    {
+        let request = self.build_register_avs_device_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<PutSkillAuthorizationResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<RegisterAVSDeviceResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(PutSkillAuthorizationError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(RegisterAVSDeviceError::from_response(response))
         }
     }
 
-    /// <p>Registers an Alexa-enabled device built by an Original Equipment Manufacturer (OEM) using Alexa Voice Service (AVS).</p>
-    async fn register_avs_device(
+    /// Presigns the request `register_avs_device` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_register_avs_device(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: RegisterAVSDeviceRequest,
-    ) -> Result<RegisterAVSDeviceResponse, RusotoError<RegisterAVSDeviceError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_register_avs_device_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_register_avs_device_request(&self, input: RegisterAVSDeviceRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "a4b", &self.region, "/");
+        request.set_operation("RegisterAVSDevice");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AlexaForBusiness.RegisterAVSDevice");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `reject_skill`, also returning the metadata of the response.
+    pub async fn reject_skill_with_metadata(
+        &self,
+        input: RejectSkillRequest,
+    ) -> Result<(RejectSkillResponse, ResponseMetadata), RusotoError<RejectSkillError>> {
+        let request = self.build_reject_skill_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<RegisterAVSDeviceResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<RejectSkillResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(RegisterAVSDeviceError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(RejectSkillError::from_response(response))
         }
     }
 
-    /// <p>Disassociates a skill from the organization under a user's AWS account. If the skill is a private skill, it moves to an AcceptStatus of PENDING. Any private or public skill that is rejected can be added later by calling the ApproveSkill API. </p>
-    async fn reject_skill(
+    /// Presigns the request `reject_skill` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_reject_skill(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: RejectSkillRequest,
-    ) -> Result<RejectSkillResponse, RusotoError<RejectSkillError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_reject_skill_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_reject_skill_request(&self, input: RejectSkillRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "a4b", &self.region, "/");
+        request.set_operation("RejectSkill");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AlexaForBusiness.RejectSkill");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `resolve_room`, also returning the metadata of the response.
+    pub async fn resolve_room_with_metadata(
+        &self,
+        input: ResolveRoomRequest,
+    ) -> Result<(ResolveRoomResponse, ResponseMetadata), RusotoError<ResolveRoomError>> {
+        let request = self.build_resolve_room_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<RejectSkillResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ResolveRoomResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(RejectSkillError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ResolveRoomError::from_response(response))
         }
     }
 
-    /// <p>Determines the details for the room from which a skill request was invoked. This operation is used by skill developers.</p>
-    async fn resolve_room(
+    /// Presigns the request `resolve_room` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_resolve_room(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ResolveRoomRequest,
-    ) -> Result<ResolveRoomResponse, RusotoError<ResolveRoomError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_resolve_room_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_resolve_room_request(&self, input: ResolveRoomRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "a4b", &self.region, "/");
+        request.set_operation("ResolveRoom");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AlexaForBusiness.ResolveRoom");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `revoke_invitation`, also returning the metadata of the response.
+    pub async fn revoke_invitation_with_metadata(
+        &self,
+        input: RevokeInvitationRequest,
+    ) -> Result<(RevokeInvitationResponse, ResponseMetadata), RusotoError<RevokeInvitationError>>
+    {
+        let request = self.build_revoke_invitation_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<ResolveRoomResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<RevokeInvitationResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ResolveRoomError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(RevokeInvitationError::from_response(response))
         }
     }
 
-    /// <p>Revokes an invitation and invalidates the enrollment URL.</p>
-    async fn revoke_invitation(
+    /// Presigns the request `revoke_invitation` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_revoke_invitation(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: RevokeInvitationRequest,
-    ) -> Result<RevokeInvitationResponse, RusotoError<RevokeInvitationError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_revoke_invitation_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_revoke_invitation_request(&self, input: RevokeInvitationRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "a4b", &self.region, "/");
+        request.set_operation("RevokeInvitation");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AlexaForBusiness.RevokeInvitation");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `search_address_books`, also returning the metadata of the response.
+    pub async fn search_address_books_with_metadata(
+        &self,
+        input: SearchAddressBooksRequest,
+    ) -> Result<(SearchAddressBooksResponse, ResponseMetadata), RusotoError<SearchAddressBooksError>>
+    {
+        let request = self.build_search_address_books_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<RevokeInvitationResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<SearchAddressBooksResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(RevokeInvitationError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(SearchAddressBooksError::from_response(response))
         }
     }
 
-    /// <p>Searches address books and lists the ones that meet a set of filter and sort criteria.</p>
-    async fn search_address_books(
+    /// Presigns the request `search_address_books` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_search_address_books(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: SearchAddressBooksRequest,
-    ) -> Result<SearchAddressBooksResponse, RusotoError<SearchAddressBooksError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_search_address_books_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_search_address_books_request(
+        &self,
+        input: SearchAddressBooksRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "a4b", &self.region, "/");
+        request.set_operation("SearchAddressBooks");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AlexaForBusiness.SearchAddressBooks");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `search_contacts`, also returning the metadata of the response.
+    pub async fn search_contacts_with_metadata(
+        &self,
+        input: SearchContactsRequest,
+    ) -> Result<(SearchContactsResponse, ResponseMetadata), RusotoError<SearchContactsError>> {
+        let request = self.build_search_contacts_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<SearchAddressBooksResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<SearchContactsResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(SearchAddressBooksError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(SearchContactsError::from_response(response))
         }
     }
 
-    /// <p>Searches contacts and lists the ones that meet a set of filter and sort criteria.</p>
-    async fn search_contacts(
+    /// Presigns the request `search_contacts` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_search_contacts(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: SearchContactsRequest,
-    ) -> Result<SearchContactsResponse, RusotoError<SearchContactsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_search_contacts_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_search_contacts_request(&self, input: SearchContactsRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "a4b", &self.region, "/");
+        request.set_operation("SearchContacts");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AlexaForBusiness.SearchContacts");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `search_devices`, also returning the metadata of the response.
+    pub async fn search_devices_with_metadata(
+        &self,
+        input: SearchDevicesRequest,
+    ) -> Result<(SearchDevicesResponse, ResponseMetadata), RusotoError<SearchDevicesError>> {
+        let request = self.build_search_devices_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<SearchContactsResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<SearchDevicesResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(SearchContactsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(SearchDevicesError::from_response(response))
         }
     }
 
-    /// <p>Searches devices and lists the ones that meet a set of filter criteria.</p>
-    async fn search_devices(
+    /// Presigns the request `search_devices` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_search_devices(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: SearchDevicesRequest,
-    ) -> Result<SearchDevicesResponse, RusotoError<SearchDevicesError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_search_devices_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_search_devices_request(&self, input: SearchDevicesRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "a4b", &self.region, "/");
+        request.set_operation("SearchDevices");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AlexaForBusiness.SearchDevices");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `search_network_profiles`, also returning the metadata of the response.
+    pub async fn search_network_profiles_with_metadata(
+        &self,
+        input: SearchNetworkProfilesRequest,
+    ) -> Result<
+        (SearchNetworkProfilesResponse, ResponseMetadata),
+        RusotoError<SearchNetworkProfilesError>,
+    > {
+        let request = self.build_search_network_profiles_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<SearchDevicesResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<SearchNetworkProfilesResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(SearchDevicesError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(SearchNetworkProfilesError::from_response(response))
         }
     }
 
-    /// <p>Searches network profiles and lists the ones that meet a set of filter and sort criteria.</p>
-    async fn search_network_profiles(
+    /// Presigns the request `search_network_profiles` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_search_network_profiles(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: SearchNetworkProfilesRequest,
-    ) -> Result<SearchNetworkProfilesResponse, RusotoError<SearchNetworkProfilesError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_search_network_profiles_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_search_network_profiles_request(
+        &self,
+        input: SearchNetworkProfilesRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "a4b", &self.region, "/");
+        request.set_operation("SearchNetworkProfiles");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AlexaForBusiness.SearchNetworkProfiles");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `search_profiles`, also returning the metadata of the response.
+    pub async fn search_profiles_with_metadata(
+        &self,
+        input: SearchProfilesRequest,
+    ) -> Result<(SearchProfilesResponse, ResponseMetadata), RusotoError<SearchProfilesError>> {
+        let request = self.build_search_profiles_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<SearchNetworkProfilesResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<SearchProfilesResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(SearchNetworkProfilesError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(SearchProfilesError::from_response(response))
         }
     }
 
-    /// <p>Searches room profiles and lists the ones that meet a set of filter criteria.</p>
-    async fn search_profiles(
+    /// Presigns the request `search_profiles` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_search_profiles(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: SearchProfilesRequest,
-    ) -> Result<SearchProfilesResponse, RusotoError<SearchProfilesError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_search_profiles_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_search_profiles_request(&self, input: SearchProfilesRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "a4b", &self.region, "/");
+        request.set_operation("SearchProfiles");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AlexaForBusiness.SearchProfiles");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `search_rooms`, also returning the metadata of the response.
+    pub async fn search_rooms_with_metadata(
+        &self,
+        input: SearchRoomsRequest,
+    ) -> Result<(SearchRoomsResponse, ResponseMetadata), RusotoError<SearchRoomsError>> {
+        let request = self.build_search_rooms_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<SearchProfilesResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<SearchRoomsResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(SearchProfilesError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(SearchRoomsError::from_response(response))
         }
     }
 
-    /// <p>Searches rooms and lists the ones that meet a set of filter and sort criteria.</p>
-    async fn search_rooms(
+    /// Presigns the request `search_rooms` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_search_rooms(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: SearchRoomsRequest,
-    ) -> Result<SearchRoomsResponse, RusotoError<SearchRoomsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_search_rooms_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_search_rooms_request(&self, input: SearchRoomsRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "a4b", &self.region, "/");
+        request.set_operation("SearchRooms");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AlexaForBusiness.SearchRooms");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
-        let mut response = self
-            .client
-            .sign_and_dispatch(request)
-            .await
-            .map_err(RusotoError::from)?;
+        request
+    }
+
+    /// Calls `search_skill_groups`, also returning the metadata of the response.
+    pub async fn search_skill_groups_with_metadata(
+        &self,
+        input: SearchSkillGroupsRequest,
+    ) -> Result<(SearchSkillGroupsResponse, ResponseMetadata), RusotoError<SearchSkillGroupsError>>
+    {
+        let request = self.build_search_skill_groups_request(input);
+
+        let mut response = self
+            .client
+            .sign_and_dispatch(request)
+            .await
+            .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<SearchRoomsResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<SearchSkillGroupsResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(SearchRoomsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(SearchSkillGroupsError::from_response(response))
         }
     }
 
-    /// <p>Searches skill groups and lists the ones that meet a set of filter and sort criteria.</p>
-    async fn search_skill_groups(
+    /// Presigns the request `search_skill_groups` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_search_skill_groups(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: SearchSkillGroupsRequest,
-    ) -> Result<SearchSkillGroupsResponse, RusotoError<SearchSkillGroupsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_search_skill_groups_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_search_skill_groups_request(&self, input: SearchSkillGroupsRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "a4b", &self.region, "/");
+        request.set_operation("SearchSkillGroups");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AlexaForBusiness.SearchSkillGroups");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `search_users`, also returning the metadata of the response.
+    pub async fn search_users_with_metadata(
+        &self,
+        input: SearchUsersRequest,
+    ) -> Result<(SearchUsersResponse, ResponseMetadata), RusotoError<SearchUsersError>> {
+        let request = self.build_search_users_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<SearchSkillGroupsResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<SearchUsersResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(SearchSkillGroupsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(SearchUsersError::from_response(response))
         }
     }
 
-    /// <p>Searches users and lists the ones that meet a set of filter and sort criteria.</p>
-    async fn search_users(
+    /// Presigns the request `search_users` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_search_users(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: SearchUsersRequest,
-    ) -> Result<SearchUsersResponse, RusotoError<SearchUsersError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_search_users_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_search_users_request(&self, input: SearchUsersRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "a4b", &self.region, "/");
+        request.set_operation("SearchUsers");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AlexaForBusiness.SearchUsers");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `send_announcement`, also returning the metadata of the response.
+    pub async fn send_announcement_with_metadata(
+        &self,
+        input: SendAnnouncementRequest,
+    ) -> Result<(SendAnnouncementResponse, ResponseMetadata), RusotoError<SendAnnouncementError>>
+    {
+        let request = self.build_send_announcement_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<SearchUsersResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<SendAnnouncementResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(SearchUsersError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(SendAnnouncementError::from_response(response))
         }
     }
 
-    /// <p>Triggers an asynchronous flow to send text, SSML, or audio announcements to rooms that are identified by a search or filter. </p>
-    async fn send_announcement(
+    /// Presigns the request `send_announcement` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_send_announcement(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: SendAnnouncementRequest,
-    ) -> Result<SendAnnouncementResponse, RusotoError<SendAnnouncementError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_send_announcement_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_send_announcement_request(&self, input: SendAnnouncementRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "a4b", &self.region, "/");
+        request.set_operation("SendAnnouncement");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AlexaForBusiness.SendAnnouncement");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `send_invitation`, also returning the metadata of the response.
+    pub async fn send_invitation_with_metadata(
+        &self,
+        input: SendInvitationRequest,
+    ) -> Result<(SendInvitationResponse, ResponseMetadata), RusotoError<SendInvitationError>> {
+        let request = self.build_send_invitation_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<SendAnnouncementResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<SendInvitationResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(SendAnnouncementError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(SendInvitationError::from_response(response))
         }
     }
 
-    /// <p>Sends an enrollment invitation email with a URL to a user. The URL is valid for 30 days or until you call this operation again, whichever comes first. </p>
-    async fn send_invitation(
+    /// Presigns the request `send_invitation` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_send_invitation(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: SendInvitationRequest,
-    ) -> Result<SendInvitationResponse, RusotoError<SendInvitationError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_send_invitation_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_send_invitation_request(&self, input: SendInvitationRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "a4b", &self.region, "/");
+        request.set_operation("SendInvitation");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AlexaForBusiness.SendInvitation");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `start_device_sync`, also returning the metadata of the response.
+    pub async fn start_device_sync_with_metadata(
+        &self,
+        input: StartDeviceSyncRequest,
+    ) -> Result<(StartDeviceSyncResponse, ResponseMetadata), RusotoError<StartDeviceSyncError>>
+    {
+        let request = self.build_start_device_sync_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<SendInvitationResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<StartDeviceSyncResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(SendInvitationError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(StartDeviceSyncError::from_response(response))
         }
     }
 
-    /// <p><p>Resets a device and its account to the known default settings. This clears all information and settings set by previous users in the following ways:</p> <ul> <li> <p>Bluetooth - This unpairs all bluetooth devices paired with your echo device.</p> </li> <li> <p>Volume - This resets the echo device&#39;s volume to the default value.</p> </li> <li> <p>Notifications - This clears all notifications from your echo device.</p> </li> <li> <p>Lists - This clears all to-do items from your echo device.</p> </li> <li> <p>Settings - This internally syncs the room&#39;s profile (if the device is assigned to a room), contacts, address books, delegation access for account linking, and communications (if enabled on the room profile).</p> </li> </ul></p>
-    async fn start_device_sync(
+    /// Presigns the request `start_device_sync` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_start_device_sync(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: StartDeviceSyncRequest,
-    ) -> Result<StartDeviceSyncResponse, RusotoError<StartDeviceSyncError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_start_device_sync_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_start_device_sync_request(&self, input: StartDeviceSyncRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "a4b", &self.region, "/");
+        request.set_operation("StartDeviceSync");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AlexaForBusiness.StartDeviceSync");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `start_smart_home_appliance_discovery`, also returning the metadata of the response.
+    pub async fn start_smart_home_appliance_discovery_with_metadata(
+        &self,
+        input: StartSmartHomeApplianceDiscoveryRequest,
+    ) -> Result<
+        (StartSmartHomeApplianceDiscoveryResponse, ResponseMetadata),
+        RusotoError<StartSmartHomeApplianceDiscoveryError>,
+    > {
+        let request = self.build_start_smart_home_appliance_discovery_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<StartDeviceSyncResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<StartSmartHomeApplianceDiscoveryResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(StartDeviceSyncError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(StartSmartHomeApplianceDiscoveryError::from_response(
+                response,
+            ))
         }
     }
 
-    /// <p>Initiates the discovery of any smart home appliances associated with the room.</p>
-    async fn start_smart_home_appliance_discovery(
+    /// Presigns the request `start_smart_home_appliance_discovery` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_start_smart_home_appliance_discovery(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: StartSmartHomeApplianceDiscoveryRequest,
-    ) -> Result<
-        StartSmartHomeApplianceDiscoveryResponse,
-        RusotoError<StartSmartHomeApplianceDiscoveryError>,
-    > {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_start_smart_home_appliance_discovery_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_start_smart_home_appliance_discovery_request(
+        &self,
+        input: StartSmartHomeApplianceDiscoveryRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "a4b", &self.region, "/");
+        request.set_operation("StartSmartHomeApplianceDiscovery");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -9647,113 +12497,215 @@ impl AlexaForBusiness for AlexaForBusinessClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `tag_resource`, also returning the metadata of the response.
+    pub async fn tag_resource_with_metadata(
+        &self,
+        input: TagResourceRequest,
+    ) -> Result<(TagResourceResponse, ResponseMetadata), RusotoError<TagResourceError>> {
+        let request = self.build_tag_resource_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<StartSmartHomeApplianceDiscoveryResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<TagResourceResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(StartSmartHomeApplianceDiscoveryError::from_response(
-                response,
-            ))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(TagResourceError::from_response(response))
         }
     }
 
-    /// <p>Adds metadata tags to a specified resource.</p>
-    async fn tag_resource(
+    /// Presigns the request `tag_resource` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_tag_resource(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: TagResourceRequest,
-    ) -> Result<TagResourceResponse, RusotoError<TagResourceError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_tag_resource_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_tag_resource_request(&self, input: TagResourceRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "a4b", &self.region, "/");
+        request.set_operation("TagResource");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AlexaForBusiness.TagResource");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `untag_resource`, also returning the metadata of the response.
+    pub async fn untag_resource_with_metadata(
+        &self,
+        input: UntagResourceRequest,
+    ) -> Result<(UntagResourceResponse, ResponseMetadata), RusotoError<UntagResourceError>> {
+        let request = self.build_untag_resource_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<TagResourceResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<UntagResourceResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(TagResourceError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(UntagResourceError::from_response(response))
         }
     }
 
-    /// <p>Removes metadata tags from a specified resource.</p>
-    async fn untag_resource(
+    /// Presigns the request `untag_resource` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_untag_resource(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UntagResourceRequest,
-    ) -> Result<UntagResourceResponse, RusotoError<UntagResourceError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_untag_resource_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_untag_resource_request(&self, input: UntagResourceRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "a4b", &self.region, "/");
+        request.set_operation("UntagResource");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AlexaForBusiness.UntagResource");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `update_address_book`, also returning the metadata of the response.
+    pub async fn update_address_book_with_metadata(
+        &self,
+        input: UpdateAddressBookRequest,
+    ) -> Result<(UpdateAddressBookResponse, ResponseMetadata), RusotoError<UpdateAddressBookError>>
+    {
+        let request = self.build_update_address_book_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<UntagResourceResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<UpdateAddressBookResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(UntagResourceError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(UpdateAddressBookError::from_response(response))
         }
     }
 
-    /// <p>Updates address book details by the address book ARN.</p>
-    async fn update_address_book(
+    /// Presigns the request `update_address_book` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_address_book(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UpdateAddressBookRequest,
-    ) -> Result<UpdateAddressBookResponse, RusotoError<UpdateAddressBookError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_address_book_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_address_book_request(&self, input: UpdateAddressBookRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "a4b", &self.region, "/");
+        request.set_operation("UpdateAddressBook");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AlexaForBusiness.UpdateAddressBook");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `update_business_report_schedule`, also returning the metadata of the response.
+    pub async fn update_business_report_schedule_with_metadata(
+        &self,
+        input: UpdateBusinessReportScheduleRequest,
+    ) -> Result<
+        (UpdateBusinessReportScheduleResponse, ResponseMetadata),
+        RusotoError<UpdateBusinessReportScheduleError>,
+    > {
+        let request = self.build_update_business_report_schedule_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<UpdateAddressBookResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<UpdateBusinessReportScheduleResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(UpdateAddressBookError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(UpdateBusinessReportScheduleError::from_response(response))
         }
     }
 
-    /// <p>Updates the configuration of the report delivery schedule with the specified schedule ARN.</p>
-    async fn update_business_report_schedule(
+    /// Presigns the request `update_business_report_schedule` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_business_report_schedule(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UpdateBusinessReportScheduleRequest,
-    ) -> Result<UpdateBusinessReportScheduleResponse, RusotoError<UpdateBusinessReportScheduleError>>
-    {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_business_report_schedule_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_business_report_schedule_request(
+        &self,
+        input: UpdateBusinessReportScheduleRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "a4b", &self.region, "/");
+        request.set_operation("UpdateBusinessReportSchedule");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -9763,266 +12715,1455 @@ impl AlexaForBusiness for AlexaForBusinessClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `update_conference_provider`, also returning the metadata of the response.
+    pub async fn update_conference_provider_with_metadata(
+        &self,
+        input: UpdateConferenceProviderRequest,
+    ) -> Result<
+        (UpdateConferenceProviderResponse, ResponseMetadata),
+        RusotoError<UpdateConferenceProviderError>,
+    > {
+        let request = self.build_update_conference_provider_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<UpdateBusinessReportScheduleResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<UpdateConferenceProviderResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(UpdateBusinessReportScheduleError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(UpdateConferenceProviderError::from_response(response))
         }
     }
 
-    /// <p>Updates an existing conference provider's settings.</p>
-    async fn update_conference_provider(
+    /// Presigns the request `update_conference_provider` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_conference_provider(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UpdateConferenceProviderRequest,
-    ) -> Result<UpdateConferenceProviderResponse, RusotoError<UpdateConferenceProviderError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_conference_provider_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_conference_provider_request(
+        &self,
+        input: UpdateConferenceProviderRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "a4b", &self.region, "/");
+        request.set_operation("UpdateConferenceProvider");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AlexaForBusiness.UpdateConferenceProvider");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `update_contact`, also returning the metadata of the response.
+    pub async fn update_contact_with_metadata(
+        &self,
+        input: UpdateContactRequest,
+    ) -> Result<(UpdateContactResponse, ResponseMetadata), RusotoError<UpdateContactError>> {
+        let request = self.build_update_contact_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<UpdateConferenceProviderResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<UpdateContactResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(UpdateConferenceProviderError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(UpdateContactError::from_response(response))
         }
     }
 
-    /// <p>Updates the contact details by the contact ARN.</p>
-    async fn update_contact(
+    /// Presigns the request `update_contact` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_contact(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UpdateContactRequest,
-    ) -> Result<UpdateContactResponse, RusotoError<UpdateContactError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_contact_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_contact_request(&self, input: UpdateContactRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "a4b", &self.region, "/");
+        request.set_operation("UpdateContact");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AlexaForBusiness.UpdateContact");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `update_device`, also returning the metadata of the response.
+    pub async fn update_device_with_metadata(
+        &self,
+        input: UpdateDeviceRequest,
+    ) -> Result<(UpdateDeviceResponse, ResponseMetadata), RusotoError<UpdateDeviceError>> {
+        let request = self.build_update_device_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<UpdateContactResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<UpdateDeviceResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(UpdateContactError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(UpdateDeviceError::from_response(response))
         }
     }
 
-    /// <p>Updates the device name by device ARN.</p>
-    async fn update_device(
+    /// Presigns the request `update_device` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_device(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UpdateDeviceRequest,
-    ) -> Result<UpdateDeviceResponse, RusotoError<UpdateDeviceError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_device_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_device_request(&self, input: UpdateDeviceRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "a4b", &self.region, "/");
+        request.set_operation("UpdateDevice");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AlexaForBusiness.UpdateDevice");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `update_gateway`, also returning the metadata of the response.
+    pub async fn update_gateway_with_metadata(
+        &self,
+        input: UpdateGatewayRequest,
+    ) -> Result<(UpdateGatewayResponse, ResponseMetadata), RusotoError<UpdateGatewayError>> {
+        let request = self.build_update_gateway_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<UpdateDeviceResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<UpdateGatewayResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(UpdateDeviceError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(UpdateGatewayError::from_response(response))
         }
     }
 
-    /// <p>Updates the details of a gateway. If any optional field is not provided, the existing corresponding value is left unmodified.</p>
-    async fn update_gateway(
+    /// Presigns the request `update_gateway` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_gateway(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UpdateGatewayRequest,
-    ) -> Result<UpdateGatewayResponse, RusotoError<UpdateGatewayError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_gateway_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_gateway_request(&self, input: UpdateGatewayRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "a4b", &self.region, "/");
+        request.set_operation("UpdateGateway");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AlexaForBusiness.UpdateGateway");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `update_gateway_group`, also returning the metadata of the response.
+    pub async fn update_gateway_group_with_metadata(
+        &self,
+        input: UpdateGatewayGroupRequest,
+    ) -> Result<(UpdateGatewayGroupResponse, ResponseMetadata), RusotoError<UpdateGatewayGroupError>>
+    {
+        let request = self.build_update_gateway_group_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<UpdateGatewayResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<UpdateGatewayGroupResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(UpdateGatewayError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(UpdateGatewayGroupError::from_response(response))
         }
     }
 
-    /// <p>Updates the details of a gateway group. If any optional field is not provided, the existing corresponding value is left unmodified.</p>
-    async fn update_gateway_group(
+    /// Presigns the request `update_gateway_group` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_gateway_group(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UpdateGatewayGroupRequest,
-    ) -> Result<UpdateGatewayGroupResponse, RusotoError<UpdateGatewayGroupError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_gateway_group_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_gateway_group_request(
+        &self,
+        input: UpdateGatewayGroupRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "a4b", &self.region, "/");
+        request.set_operation("UpdateGatewayGroup");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AlexaForBusiness.UpdateGatewayGroup");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `update_network_profile`, also returning the metadata of the response.
+    pub async fn update_network_profile_with_metadata(
+        &self,
+        input: UpdateNetworkProfileRequest,
+    ) -> Result<
+        (UpdateNetworkProfileResponse, ResponseMetadata),
+        RusotoError<UpdateNetworkProfileError>,
+    > {
+        let request = self.build_update_network_profile_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<UpdateGatewayGroupResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<UpdateNetworkProfileResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(UpdateGatewayGroupError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(UpdateNetworkProfileError::from_response(response))
         }
     }
 
-    /// <p>Updates a network profile by the network profile ARN.</p>
-    async fn update_network_profile(
+    /// Presigns the request `update_network_profile` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_network_profile(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UpdateNetworkProfileRequest,
-    ) -> Result<UpdateNetworkProfileResponse, RusotoError<UpdateNetworkProfileError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_network_profile_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_network_profile_request(
+        &self,
+        input: UpdateNetworkProfileRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "a4b", &self.region, "/");
+        request.set_operation("UpdateNetworkProfile");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AlexaForBusiness.UpdateNetworkProfile");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `update_profile`, also returning the metadata of the response.
+    pub async fn update_profile_with_metadata(
+        &self,
+        input: UpdateProfileRequest,
+    ) -> Result<(UpdateProfileResponse, ResponseMetadata), RusotoError<UpdateProfileError>> {
+        let request = self.build_update_profile_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<UpdateNetworkProfileResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<UpdateProfileResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(UpdateNetworkProfileError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(UpdateProfileError::from_response(response))
         }
     }
 
-    /// <p>Updates an existing room profile by room profile ARN.</p>
-    async fn update_profile(
+    /// Presigns the request `update_profile` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_profile(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UpdateProfileRequest,
-    ) -> Result<UpdateProfileResponse, RusotoError<UpdateProfileError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_profile_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_profile_request(&self, input: UpdateProfileRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "a4b", &self.region, "/");
+        request.set_operation("UpdateProfile");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AlexaForBusiness.UpdateProfile");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `update_room`, also returning the metadata of the response.
+    pub async fn update_room_with_metadata(
+        &self,
+        input: UpdateRoomRequest,
+    ) -> Result<(UpdateRoomResponse, ResponseMetadata), RusotoError<UpdateRoomError>> {
+        let request = self.build_update_room_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<UpdateProfileResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<UpdateRoomResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(UpdateProfileError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(UpdateRoomError::from_response(response))
         }
     }
 
-    /// <p>Updates room details by room ARN.</p>
-    async fn update_room(
+    /// Presigns the request `update_room` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_room(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UpdateRoomRequest,
-    ) -> Result<UpdateRoomResponse, RusotoError<UpdateRoomError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_room_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_room_request(&self, input: UpdateRoomRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "a4b", &self.region, "/");
+        request.set_operation("UpdateRoom");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AlexaForBusiness.UpdateRoom");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
-        let mut response = self
-            .client
-            .sign_and_dispatch(request)
+        request
+    }
+
+    /// Calls `update_skill_group`, also returning the metadata of the response.
+    pub async fn update_skill_group_with_metadata(
+        &self,
+        input: UpdateSkillGroupRequest,
+    ) -> Result<(UpdateSkillGroupResponse, ResponseMetadata), RusotoError<UpdateSkillGroupError>>
+    {
+        let request = self.build_update_skill_group_request(input);
+
+        let mut response = self
+            .client
+            .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<UpdateRoomResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<UpdateSkillGroupResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(UpdateRoomError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(UpdateSkillGroupError::from_response(response))
         }
     }
 
-    /// <p>Updates skill group details by skill group ARN.</p>
-    async fn update_skill_group(
+    /// Presigns the request `update_skill_group` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_skill_group(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UpdateSkillGroupRequest,
-    ) -> Result<UpdateSkillGroupResponse, RusotoError<UpdateSkillGroupError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_skill_group_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_skill_group_request(&self, input: UpdateSkillGroupRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "a4b", &self.region, "/");
+        request.set_operation("UpdateSkillGroup");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AlexaForBusiness.UpdateSkillGroup");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
-        let mut response = self
-            .client
-            .sign_and_dispatch(request)
+        request
+    }
+}
+
+#[async_trait]
+impl AlexaForBusiness for AlexaForBusinessClient {
+    /// <p>Associates a skill with the organization under the customer's AWS account. If a skill is private, the user implicitly accepts access to this skill during enablement.</p>
+    async fn approve_skill(
+        &self,
+        input: ApproveSkillRequest,
+    ) -> Result<ApproveSkillResponse, RusotoError<ApproveSkillError>> {
+        self.approve_skill_with_metadata(input)
             .await
-            .map_err(RusotoError::from)?;
-        if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<UpdateSkillGroupResponse, _>()
-        } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(UpdateSkillGroupError::from_response(response))
-        }
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Associates a contact with a given address book.</p>
+    async fn associate_contact_with_address_book(
+        &self,
+        input: AssociateContactWithAddressBookRequest,
+    ) -> Result<
+        AssociateContactWithAddressBookResponse,
+        RusotoError<AssociateContactWithAddressBookError>,
+    > {
+        self.associate_contact_with_address_book_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Associates a device with the specified network profile.</p>
+    async fn associate_device_with_network_profile(
+        &self,
+        input: AssociateDeviceWithNetworkProfileRequest,
+    ) -> Result<
+        AssociateDeviceWithNetworkProfileResponse,
+        RusotoError<AssociateDeviceWithNetworkProfileError>,
+    > {
+        self.associate_device_with_network_profile_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Associates a device with a given room. This applies all the settings from the room profile to the device, and all the skills in any skill groups added to that room. This operation requires the device to be online, or else a manual sync is required. </p>
+    async fn associate_device_with_room(
+        &self,
+        input: AssociateDeviceWithRoomRequest,
+    ) -> Result<AssociateDeviceWithRoomResponse, RusotoError<AssociateDeviceWithRoomError>> {
+        self.associate_device_with_room_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Associates a skill group with a given room. This enables all skills in the associated skill group on all devices in the room.</p>
+    async fn associate_skill_group_with_room(
+        &self,
+        input: AssociateSkillGroupWithRoomRequest,
+    ) -> Result<AssociateSkillGroupWithRoomResponse, RusotoError<AssociateSkillGroupWithRoomError>>
+    {
+        self.associate_skill_group_with_room_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Associates a skill with a skill group.</p>
+    async fn associate_skill_with_skill_group(
+        &self,
+        input: AssociateSkillWithSkillGroupRequest,
+    ) -> Result<AssociateSkillWithSkillGroupResponse, RusotoError<AssociateSkillWithSkillGroupError>>
+    {
+        self.associate_skill_with_skill_group_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Makes a private skill available for enrolled users to enable on their devices.</p>
+    async fn associate_skill_with_users(
+        &self,
+        input: AssociateSkillWithUsersRequest,
+    ) -> Result<AssociateSkillWithUsersResponse, RusotoError<AssociateSkillWithUsersError>> {
+        self.associate_skill_with_users_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Creates an address book with the specified details.</p>
+    async fn create_address_book(
+        &self,
+        input: CreateAddressBookRequest,
+    ) -> Result<CreateAddressBookResponse, RusotoError<CreateAddressBookError>> {
+        self.create_address_book_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Creates a recurring schedule for usage reports to deliver to the specified S3 location with a specified daily or weekly interval.</p>
+    async fn create_business_report_schedule(
+        &self,
+        input: CreateBusinessReportScheduleRequest,
+    ) -> Result<CreateBusinessReportScheduleResponse, RusotoError<CreateBusinessReportScheduleError>>
+    {
+        self.create_business_report_schedule_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Adds a new conference provider under the user's AWS account.</p>
+    async fn create_conference_provider(
+        &self,
+        input: CreateConferenceProviderRequest,
+    ) -> Result<CreateConferenceProviderResponse, RusotoError<CreateConferenceProviderError>> {
+        self.create_conference_provider_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Creates a contact with the specified details.</p>
+    async fn create_contact(
+        &self,
+        input: CreateContactRequest,
+    ) -> Result<CreateContactResponse, RusotoError<CreateContactError>> {
+        self.create_contact_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Creates a gateway group with the specified details.</p>
+    async fn create_gateway_group(
+        &self,
+        input: CreateGatewayGroupRequest,
+    ) -> Result<CreateGatewayGroupResponse, RusotoError<CreateGatewayGroupError>> {
+        self.create_gateway_group_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Creates a network profile with the specified details.</p>
+    async fn create_network_profile(
+        &self,
+        input: CreateNetworkProfileRequest,
+    ) -> Result<CreateNetworkProfileResponse, RusotoError<CreateNetworkProfileError>> {
+        self.create_network_profile_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Creates a new room profile with the specified details.</p>
+    async fn create_profile(
+        &self,
+        input: CreateProfileRequest,
+    ) -> Result<CreateProfileResponse, RusotoError<CreateProfileError>> {
+        self.create_profile_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Creates a room with the specified details.</p>
+    async fn create_room(
+        &self,
+        input: CreateRoomRequest,
+    ) -> Result<CreateRoomResponse, RusotoError<CreateRoomError>> {
+        self.create_room_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Creates a skill group with a specified name and description.</p>
+    async fn create_skill_group(
+        &self,
+        input: CreateSkillGroupRequest,
+    ) -> Result<CreateSkillGroupResponse, RusotoError<CreateSkillGroupError>> {
+        self.create_skill_group_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Creates a user.</p>
+    async fn create_user(
+        &self,
+        input: CreateUserRequest,
+    ) -> Result<CreateUserResponse, RusotoError<CreateUserError>> {
+        self.create_user_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes an address book by the address book ARN.</p>
+    async fn delete_address_book(
+        &self,
+        input: DeleteAddressBookRequest,
+    ) -> Result<DeleteAddressBookResponse, RusotoError<DeleteAddressBookError>> {
+        self.delete_address_book_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes the recurring report delivery schedule with the specified schedule ARN.</p>
+    async fn delete_business_report_schedule(
+        &self,
+        input: DeleteBusinessReportScheduleRequest,
+    ) -> Result<DeleteBusinessReportScheduleResponse, RusotoError<DeleteBusinessReportScheduleError>>
+    {
+        self.delete_business_report_schedule_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes a conference provider.</p>
+    async fn delete_conference_provider(
+        &self,
+        input: DeleteConferenceProviderRequest,
+    ) -> Result<DeleteConferenceProviderResponse, RusotoError<DeleteConferenceProviderError>> {
+        self.delete_conference_provider_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes a contact by the contact ARN.</p>
+    async fn delete_contact(
+        &self,
+        input: DeleteContactRequest,
+    ) -> Result<DeleteContactResponse, RusotoError<DeleteContactError>> {
+        self.delete_contact_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Removes a device from Alexa For Business.</p>
+    async fn delete_device(
+        &self,
+        input: DeleteDeviceRequest,
+    ) -> Result<DeleteDeviceResponse, RusotoError<DeleteDeviceError>> {
+        self.delete_device_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>When this action is called for a specified shared device, it allows authorized users to delete the device's entire previous history of voice input data and associated response data. This action can be called once every 24 hours for a specific shared device.</p>
+    async fn delete_device_usage_data(
+        &self,
+        input: DeleteDeviceUsageDataRequest,
+    ) -> Result<DeleteDeviceUsageDataResponse, RusotoError<DeleteDeviceUsageDataError>> {
+        self.delete_device_usage_data_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes a gateway group.</p>
+    async fn delete_gateway_group(
+        &self,
+        input: DeleteGatewayGroupRequest,
+    ) -> Result<DeleteGatewayGroupResponse, RusotoError<DeleteGatewayGroupError>> {
+        self.delete_gateway_group_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes a network profile by the network profile ARN.</p>
+    async fn delete_network_profile(
+        &self,
+        input: DeleteNetworkProfileRequest,
+    ) -> Result<DeleteNetworkProfileResponse, RusotoError<DeleteNetworkProfileError>> {
+        self.delete_network_profile_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes a room profile by the profile ARN.</p>
+    async fn delete_profile(
+        &self,
+        input: DeleteProfileRequest,
+    ) -> Result<DeleteProfileResponse, RusotoError<DeleteProfileError>> {
+        self.delete_profile_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes a room by the room ARN.</p>
+    async fn delete_room(
+        &self,
+        input: DeleteRoomRequest,
+    ) -> Result<DeleteRoomResponse, RusotoError<DeleteRoomError>> {
+        self.delete_room_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes room skill parameter details by room, skill, and parameter key ID.</p>
+    async fn delete_room_skill_parameter(
+        &self,
+        input: DeleteRoomSkillParameterRequest,
+    ) -> Result<DeleteRoomSkillParameterResponse, RusotoError<DeleteRoomSkillParameterError>> {
+        self.delete_room_skill_parameter_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Unlinks a third-party account from a skill.</p>
+    async fn delete_skill_authorization(
+        &self,
+        input: DeleteSkillAuthorizationRequest,
+    ) -> Result<DeleteSkillAuthorizationResponse, RusotoError<DeleteSkillAuthorizationError>> {
+        self.delete_skill_authorization_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes a skill group by skill group ARN.</p>
+    async fn delete_skill_group(
+        &self,
+        input: DeleteSkillGroupRequest,
+    ) -> Result<DeleteSkillGroupResponse, RusotoError<DeleteSkillGroupError>> {
+        self.delete_skill_group_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes a specified user by user ARN and enrollment ARN.</p>
+    async fn delete_user(
+        &self,
+        input: DeleteUserRequest,
+    ) -> Result<DeleteUserResponse, RusotoError<DeleteUserError>> {
+        self.delete_user_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Disassociates a contact from a given address book.</p>
+    async fn disassociate_contact_from_address_book(
+        &self,
+        input: DisassociateContactFromAddressBookRequest,
+    ) -> Result<
+        DisassociateContactFromAddressBookResponse,
+        RusotoError<DisassociateContactFromAddressBookError>,
+    > {
+        self.disassociate_contact_from_address_book_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Disassociates a device from its current room. The device continues to be connected to the Wi-Fi network and is still registered to the account. The device settings and skills are removed from the room.</p>
+    async fn disassociate_device_from_room(
+        &self,
+        input: DisassociateDeviceFromRoomRequest,
+    ) -> Result<DisassociateDeviceFromRoomResponse, RusotoError<DisassociateDeviceFromRoomError>>
+    {
+        self.disassociate_device_from_room_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Disassociates a skill from a skill group.</p>
+    async fn disassociate_skill_from_skill_group(
+        &self,
+        input: DisassociateSkillFromSkillGroupRequest,
+    ) -> Result<
+        DisassociateSkillFromSkillGroupResponse,
+        RusotoError<DisassociateSkillFromSkillGroupError>,
+    > {
+        self.disassociate_skill_from_skill_group_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Makes a private skill unavailable for enrolled users and prevents them from enabling it on their devices.</p>
+    async fn disassociate_skill_from_users(
+        &self,
+        input: DisassociateSkillFromUsersRequest,
+    ) -> Result<DisassociateSkillFromUsersResponse, RusotoError<DisassociateSkillFromUsersError>>
+    {
+        self.disassociate_skill_from_users_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Disassociates a skill group from a specified room. This disables all skills in the skill group on all devices in the room.</p>
+    async fn disassociate_skill_group_from_room(
+        &self,
+        input: DisassociateSkillGroupFromRoomRequest,
+    ) -> Result<
+        DisassociateSkillGroupFromRoomResponse,
+        RusotoError<DisassociateSkillGroupFromRoomError>,
+    > {
+        self.disassociate_skill_group_from_room_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Forgets smart home appliances associated to a room.</p>
+    async fn forget_smart_home_appliances(
+        &self,
+        input: ForgetSmartHomeAppliancesRequest,
+    ) -> Result<ForgetSmartHomeAppliancesResponse, RusotoError<ForgetSmartHomeAppliancesError>>
+    {
+        self.forget_smart_home_appliances_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Gets address the book details by the address book ARN.</p>
+    async fn get_address_book(
+        &self,
+        input: GetAddressBookRequest,
+    ) -> Result<GetAddressBookResponse, RusotoError<GetAddressBookError>> {
+        self.get_address_book_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Retrieves the existing conference preferences.</p>
+    async fn get_conference_preference(
+        &self,
+    ) -> Result<GetConferencePreferenceResponse, RusotoError<GetConferencePreferenceError>> {
+        self.get_conference_preference_with_metadata()
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Gets details about a specific conference provider.</p>
+    async fn get_conference_provider(
+        &self,
+        input: GetConferenceProviderRequest,
+    ) -> Result<GetConferenceProviderResponse, RusotoError<GetConferenceProviderError>> {
+        self.get_conference_provider_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Gets the contact details by the contact ARN.</p>
+    async fn get_contact(
+        &self,
+        input: GetContactRequest,
+    ) -> Result<GetContactResponse, RusotoError<GetContactError>> {
+        self.get_contact_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Gets the details of a device by device ARN.</p>
+    async fn get_device(
+        &self,
+        input: GetDeviceRequest,
+    ) -> Result<GetDeviceResponse, RusotoError<GetDeviceError>> {
+        self.get_device_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Retrieves the details of a gateway.</p>
+    async fn get_gateway(
+        &self,
+        input: GetGatewayRequest,
+    ) -> Result<GetGatewayResponse, RusotoError<GetGatewayError>> {
+        self.get_gateway_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Retrieves the details of a gateway group.</p>
+    async fn get_gateway_group(
+        &self,
+        input: GetGatewayGroupRequest,
+    ) -> Result<GetGatewayGroupResponse, RusotoError<GetGatewayGroupError>> {
+        self.get_gateway_group_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Retrieves the configured values for the user enrollment invitation email template.</p>
+    async fn get_invitation_configuration(
+        &self,
+    ) -> Result<GetInvitationConfigurationResponse, RusotoError<GetInvitationConfigurationError>>
+    {
+        self.get_invitation_configuration_with_metadata()
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Gets the network profile details by the network profile ARN.</p>
+    async fn get_network_profile(
+        &self,
+        input: GetNetworkProfileRequest,
+    ) -> Result<GetNetworkProfileResponse, RusotoError<GetNetworkProfileError>> {
+        self.get_network_profile_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Gets the details of a room profile by profile ARN.</p>
+    async fn get_profile(
+        &self,
+        input: GetProfileRequest,
+    ) -> Result<GetProfileResponse, RusotoError<GetProfileError>> {
+        self.get_profile_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Gets room details by room ARN.</p>
+    async fn get_room(
+        &self,
+        input: GetRoomRequest,
+    ) -> Result<GetRoomResponse, RusotoError<GetRoomError>> {
+        self.get_room_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Gets room skill parameter details by room, skill, and parameter key ARN.</p>
+    async fn get_room_skill_parameter(
+        &self,
+        input: GetRoomSkillParameterRequest,
+    ) -> Result<GetRoomSkillParameterResponse, RusotoError<GetRoomSkillParameterError>> {
+        self.get_room_skill_parameter_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Gets skill group details by skill group ARN.</p>
+    async fn get_skill_group(
+        &self,
+        input: GetSkillGroupRequest,
+    ) -> Result<GetSkillGroupResponse, RusotoError<GetSkillGroupError>> {
+        self.get_skill_group_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Lists the details of the schedules that a user configured. A download URL of the report associated with each schedule is returned every time this action is called. A new download URL is returned each time, and is valid for 24 hours.</p>
+    async fn list_business_report_schedules(
+        &self,
+        input: ListBusinessReportSchedulesRequest,
+    ) -> Result<ListBusinessReportSchedulesResponse, RusotoError<ListBusinessReportSchedulesError>>
+    {
+        self.list_business_report_schedules_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Lists conference providers under a specific AWS account.</p>
+    async fn list_conference_providers(
+        &self,
+        input: ListConferenceProvidersRequest,
+    ) -> Result<ListConferenceProvidersResponse, RusotoError<ListConferenceProvidersError>> {
+        self.list_conference_providers_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Lists the device event history, including device connection status, for up to 30 days.</p>
+    async fn list_device_events(
+        &self,
+        input: ListDeviceEventsRequest,
+    ) -> Result<ListDeviceEventsResponse, RusotoError<ListDeviceEventsError>> {
+        self.list_device_events_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Retrieves a list of gateway group summaries. Use GetGatewayGroup to retrieve details of a specific gateway group.</p>
+    async fn list_gateway_groups(
+        &self,
+        input: ListGatewayGroupsRequest,
+    ) -> Result<ListGatewayGroupsResponse, RusotoError<ListGatewayGroupsError>> {
+        self.list_gateway_groups_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Retrieves a list of gateway summaries. Use GetGateway to retrieve details of a specific gateway. An optional gateway group ARN can be provided to only retrieve gateway summaries of gateways that are associated with that gateway group ARN.</p>
+    async fn list_gateways(
+        &self,
+        input: ListGatewaysRequest,
+    ) -> Result<ListGatewaysResponse, RusotoError<ListGatewaysError>> {
+        self.list_gateways_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Lists all enabled skills in a specific skill group.</p>
+    async fn list_skills(
+        &self,
+        input: ListSkillsRequest,
+    ) -> Result<ListSkillsResponse, RusotoError<ListSkillsError>> {
+        self.list_skills_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Lists all categories in the Alexa skill store.</p>
+    async fn list_skills_store_categories(
+        &self,
+        input: ListSkillsStoreCategoriesRequest,
+    ) -> Result<ListSkillsStoreCategoriesResponse, RusotoError<ListSkillsStoreCategoriesError>>
+    {
+        self.list_skills_store_categories_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Lists all skills in the Alexa skill store by category.</p>
+    async fn list_skills_store_skills_by_category(
+        &self,
+        input: ListSkillsStoreSkillsByCategoryRequest,
+    ) -> Result<
+        ListSkillsStoreSkillsByCategoryResponse,
+        RusotoError<ListSkillsStoreSkillsByCategoryError>,
+    > {
+        self.list_skills_store_skills_by_category_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Lists all of the smart home appliances associated with a room.</p>
+    async fn list_smart_home_appliances(
+        &self,
+        input: ListSmartHomeAppliancesRequest,
+    ) -> Result<ListSmartHomeAppliancesResponse, RusotoError<ListSmartHomeAppliancesError>> {
+        self.list_smart_home_appliances_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Lists all tags for the specified resource.</p>
+    async fn list_tags(
+        &self,
+        input: ListTagsRequest,
+    ) -> Result<ListTagsResponse, RusotoError<ListTagsError>> {
+        self.list_tags_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Sets the conference preferences on a specific conference provider at the account level.</p>
+    async fn put_conference_preference(
+        &self,
+        input: PutConferencePreferenceRequest,
+    ) -> Result<PutConferencePreferenceResponse, RusotoError<PutConferencePreferenceError>> {
+        self.put_conference_preference_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Configures the email template for the user enrollment invitation with the specified attributes.</p>
+    async fn put_invitation_configuration(
+        &self,
+        input: PutInvitationConfigurationRequest,
+    ) -> Result<PutInvitationConfigurationResponse, RusotoError<PutInvitationConfigurationError>>
+    {
+        self.put_invitation_configuration_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Updates room skill parameter details by room, skill, and parameter key ID. Not all skills have a room skill parameter.</p>
+    async fn put_room_skill_parameter(
+        &self,
+        input: PutRoomSkillParameterRequest,
+    ) -> Result<PutRoomSkillParameterResponse, RusotoError<PutRoomSkillParameterError>> {
+        self.put_room_skill_parameter_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Links a user's account to a third-party skill provider. If this API operation is called by an assumed IAM role, the skill being linked must be a private skill. Also, the skill must be owned by the AWS account that assumed the IAM role.</p>
+    async fn put_skill_authorization(
+        &self,
+        input: PutSkillAuthorizationRequest,
+    ) -> Result<PutSkillAuthorizationResponse, RusotoError<PutSkillAuthorizationError>> {
+        self.put_skill_authorization_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Registers an Alexa-enabled device built by an Original Equipment Manufacturer (OEM) using Alexa Voice Service (AVS).</p>
+    async fn register_avs_device(
+        &self,
+        input: RegisterAVSDeviceRequest,
+    ) -> Result<RegisterAVSDeviceResponse, RusotoError<RegisterAVSDeviceError>> {
+        self.register_avs_device_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Disassociates a skill from the organization under a user's AWS account. If the skill is a private skill, it moves to an AcceptStatus of PENDING. Any private or public skill that is rejected can be added later by calling the ApproveSkill API. </p>
+    async fn reject_skill(
+        &self,
+        input: RejectSkillRequest,
+    ) -> Result<RejectSkillResponse, RusotoError<RejectSkillError>> {
+        self.reject_skill_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Determines the details for the room from which a skill request was invoked. This operation is used by skill developers.</p>
+    async fn resolve_room(
+        &self,
+        input: ResolveRoomRequest,
+    ) -> Result<ResolveRoomResponse, RusotoError<ResolveRoomError>> {
+        self.resolve_room_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Revokes an invitation and invalidates the enrollment URL.</p>
+    async fn revoke_invitation(
+        &self,
+        input: RevokeInvitationRequest,
+    ) -> Result<RevokeInvitationResponse, RusotoError<RevokeInvitationError>> {
+        self.revoke_invitation_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Searches address books and lists the ones that meet a set of filter and sort criteria.</p>
+    async fn search_address_books(
+        &self,
+        input: SearchAddressBooksRequest,
+    ) -> Result<SearchAddressBooksResponse, RusotoError<SearchAddressBooksError>> {
+        self.search_address_books_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Searches contacts and lists the ones that meet a set of filter and sort criteria.</p>
+    async fn search_contacts(
+        &self,
+        input: SearchContactsRequest,
+    ) -> Result<SearchContactsResponse, RusotoError<SearchContactsError>> {
+        self.search_contacts_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Searches devices and lists the ones that meet a set of filter criteria.</p>
+    async fn search_devices(
+        &self,
+        input: SearchDevicesRequest,
+    ) -> Result<SearchDevicesResponse, RusotoError<SearchDevicesError>> {
+        self.search_devices_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Searches network profiles and lists the ones that meet a set of filter and sort criteria.</p>
+    async fn search_network_profiles(
+        &self,
+        input: SearchNetworkProfilesRequest,
+    ) -> Result<SearchNetworkProfilesResponse, RusotoError<SearchNetworkProfilesError>> {
+        self.search_network_profiles_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Searches room profiles and lists the ones that meet a set of filter criteria.</p>
+    async fn search_profiles(
+        &self,
+        input: SearchProfilesRequest,
+    ) -> Result<SearchProfilesResponse, RusotoError<SearchProfilesError>> {
+        self.search_profiles_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Searches rooms and lists the ones that meet a set of filter and sort criteria.</p>
+    async fn search_rooms(
+        &self,
+        input: SearchRoomsRequest,
+    ) -> Result<SearchRoomsResponse, RusotoError<SearchRoomsError>> {
+        self.search_rooms_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Searches skill groups and lists the ones that meet a set of filter and sort criteria.</p>
+    async fn search_skill_groups(
+        &self,
+        input: SearchSkillGroupsRequest,
+    ) -> Result<SearchSkillGroupsResponse, RusotoError<SearchSkillGroupsError>> {
+        self.search_skill_groups_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Searches users and lists the ones that meet a set of filter and sort criteria.</p>
+    async fn search_users(
+        &self,
+        input: SearchUsersRequest,
+    ) -> Result<SearchUsersResponse, RusotoError<SearchUsersError>> {
+        self.search_users_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Triggers an asynchronous flow to send text, SSML, or audio announcements to rooms that are identified by a search or filter. </p>
+    async fn send_announcement(
+        &self,
+        input: SendAnnouncementRequest,
+    ) -> Result<SendAnnouncementResponse, RusotoError<SendAnnouncementError>> {
+        self.send_announcement_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Sends an enrollment invitation email with a URL to a user. The URL is valid for 30 days or until you call this operation again, whichever comes first. </p>
+    async fn send_invitation(
+        &self,
+        input: SendInvitationRequest,
+    ) -> Result<SendInvitationResponse, RusotoError<SendInvitationError>> {
+        self.send_invitation_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Resets a device and its account to the known default settings. This clears all information and settings set by previous users in the following ways:</p> <ul> <li> <p>Bluetooth - This unpairs all bluetooth devices paired with your echo device.</p> </li> <li> <p>Volume - This resets the echo device&#39;s volume to the default value.</p> </li> <li> <p>Notifications - This clears all notifications from your echo device.</p> </li> <li> <p>Lists - This clears all to-do items from your echo device.</p> </li> <li> <p>Settings - This internally syncs the room&#39;s profile (if the device is assigned to a room), contacts, address books, delegation access for account linking, and communications (if enabled on the room profile).</p> </li> </ul></p>
+    async fn start_device_sync(
+        &self,
+        input: StartDeviceSyncRequest,
+    ) -> Result<StartDeviceSyncResponse, RusotoError<StartDeviceSyncError>> {
+        self.start_device_sync_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Initiates the discovery of any smart home appliances associated with the room.</p>
+    async fn start_smart_home_appliance_discovery(
+        &self,
+        input: StartSmartHomeApplianceDiscoveryRequest,
+    ) -> Result<
+        StartSmartHomeApplianceDiscoveryResponse,
+        RusotoError<StartSmartHomeApplianceDiscoveryError>,
+    > {
+        self.start_smart_home_appliance_discovery_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Adds metadata tags to a specified resource.</p>
+    async fn tag_resource(
+        &self,
+        input: TagResourceRequest,
+    ) -> Result<TagResourceResponse, RusotoError<TagResourceError>> {
+        self.tag_resource_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Removes metadata tags from a specified resource.</p>
+    async fn untag_resource(
+        &self,
+        input: UntagResourceRequest,
+    ) -> Result<UntagResourceResponse, RusotoError<UntagResourceError>> {
+        self.untag_resource_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Updates address book details by the address book ARN.</p>
+    async fn update_address_book(
+        &self,
+        input: UpdateAddressBookRequest,
+    ) -> Result<UpdateAddressBookResponse, RusotoError<UpdateAddressBookError>> {
+        self.update_address_book_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Updates the configuration of the report delivery schedule with the specified schedule ARN.</p>
+    async fn update_business_report_schedule(
+        &self,
+        input: UpdateBusinessReportScheduleRequest,
+    ) -> Result<UpdateBusinessReportScheduleResponse, RusotoError<UpdateBusinessReportScheduleError>>
+    {
+        self.update_business_report_schedule_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Updates an existing conference provider's settings.</p>
+    async fn update_conference_provider(
+        &self,
+        input: UpdateConferenceProviderRequest,
+    ) -> Result<UpdateConferenceProviderResponse, RusotoError<UpdateConferenceProviderError>> {
+        self.update_conference_provider_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Updates the contact details by the contact ARN.</p>
+    async fn update_contact(
+        &self,
+        input: UpdateContactRequest,
+    ) -> Result<UpdateContactResponse, RusotoError<UpdateContactError>> {
+        self.update_contact_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Updates the device name by device ARN.</p>
+    async fn update_device(
+        &self,
+        input: UpdateDeviceRequest,
+    ) -> Result<UpdateDeviceResponse, RusotoError<UpdateDeviceError>> {
+        self.update_device_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Updates the details of a gateway. If any optional field is not provided, the existing corresponding value is left unmodified.</p>
+    async fn update_gateway(
+        &self,
+        input: UpdateGatewayRequest,
+    ) -> Result<UpdateGatewayResponse, RusotoError<UpdateGatewayError>> {
+        self.update_gateway_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Updates the details of a gateway group. If any optional field is not provided, the existing corresponding value is left unmodified.</p>
+    async fn update_gateway_group(
+        &self,
+        input: UpdateGatewayGroupRequest,
+    ) -> Result<UpdateGatewayGroupResponse, RusotoError<UpdateGatewayGroupError>> {
+        self.update_gateway_group_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Updates a network profile by the network profile ARN.</p>
+    async fn update_network_profile(
+        &self,
+        input: UpdateNetworkProfileRequest,
+    ) -> Result<UpdateNetworkProfileResponse, RusotoError<UpdateNetworkProfileError>> {
+        self.update_network_profile_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Updates an existing room profile by room profile ARN.</p>
+    async fn update_profile(
+        &self,
+        input: UpdateProfileRequest,
+    ) -> Result<UpdateProfileResponse, RusotoError<UpdateProfileError>> {
+        self.update_profile_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Updates room details by room ARN.</p>
+    async fn update_room(
+        &self,
+        input: UpdateRoomRequest,
+    ) -> Result<UpdateRoomResponse, RusotoError<UpdateRoomError>> {
+        self.update_room_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Updates skill group details by skill group ARN.</p>
+    async fn update_skill_group(
+        &self,
+        input: UpdateSkillGroupRequest,
+    ) -> Result<UpdateSkillGroupResponse, RusotoError<UpdateSkillGroupError>> {
+        self.update_skill_group_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
     }
 }
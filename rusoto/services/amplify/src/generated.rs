@@ -16,8 +16,8 @@ use std::fmt;
 use async_trait::async_trait;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
-use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest, ResponseMetadata};
+use rusoto_core::{Client, ErrorMetadata, RusotoError};
 
 use rusoto_core::param::{Params, ServiceParams};
 use rusoto_core::proto;
@@ -1636,6 +1636,7 @@ pub struct Webhook {
 
 /// Errors returned by CreateApp
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateAppError {
     /// <p> Exception thrown when a request contains unexpected data. </p>
     BadRequest(String),
@@ -1647,6 +1648,8 @@ pub enum CreateAppError {
     LimitExceeded(String),
     /// <p> Exception thrown when an operation fails due to a lack of access. </p>
     Unauthorized(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateAppError {
@@ -1654,22 +1657,25 @@ impl CreateAppError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(CreateAppError::BadRequest(err.msg))
+                    return RusotoError::service(CreateAppError::BadRequest(err.msg), &res)
                 }
                 "DependentServiceFailureException" => {
-                    return RusotoError::Service(CreateAppError::DependentServiceFailure(err.msg))
+                    return RusotoError::service(
+                        CreateAppError::DependentServiceFailure(err.msg),
+                        &res,
+                    )
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(CreateAppError::InternalFailure(err.msg))
+                    return RusotoError::service(CreateAppError::InternalFailure(err.msg), &res)
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateAppError::LimitExceeded(err.msg))
+                    return RusotoError::service(CreateAppError::LimitExceeded(err.msg), &res)
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(CreateAppError::Unauthorized(err.msg))
+                    return RusotoError::service(CreateAppError::Unauthorized(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreateAppError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1684,12 +1690,14 @@ impl fmt::Display for CreateAppError {
             CreateAppError::InternalFailure(ref cause) => write!(f, "{}", cause),
             CreateAppError::LimitExceeded(ref cause) => write!(f, "{}", cause),
             CreateAppError::Unauthorized(ref cause) => write!(f, "{}", cause),
+            CreateAppError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateAppError {}
 /// Errors returned by CreateBackendEnvironment
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateBackendEnvironmentError {
     /// <p> Exception thrown when a request contains unexpected data. </p>
     BadRequest(String),
@@ -1701,6 +1709,8 @@ pub enum CreateBackendEnvironmentError {
     NotFound(String),
     /// <p> Exception thrown when an operation fails due to a lack of access. </p>
     Unauthorized(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateBackendEnvironmentError {
@@ -1708,28 +1718,37 @@ impl CreateBackendEnvironmentError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(CreateBackendEnvironmentError::BadRequest(err.msg))
+                    return RusotoError::service(
+                        CreateBackendEnvironmentError::BadRequest(err.msg),
+                        &res,
+                    )
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(CreateBackendEnvironmentError::InternalFailure(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateBackendEnvironmentError::InternalFailure(err.msg),
+                        &res,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateBackendEnvironmentError::LimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateBackendEnvironmentError::LimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(CreateBackendEnvironmentError::NotFound(err.msg))
+                    return RusotoError::service(
+                        CreateBackendEnvironmentError::NotFound(err.msg),
+                        &res,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(CreateBackendEnvironmentError::Unauthorized(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateBackendEnvironmentError::Unauthorized(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreateBackendEnvironmentError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1744,12 +1763,14 @@ impl fmt::Display for CreateBackendEnvironmentError {
             CreateBackendEnvironmentError::LimitExceeded(ref cause) => write!(f, "{}", cause),
             CreateBackendEnvironmentError::NotFound(ref cause) => write!(f, "{}", cause),
             CreateBackendEnvironmentError::Unauthorized(ref cause) => write!(f, "{}", cause),
+            CreateBackendEnvironmentError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateBackendEnvironmentError {}
 /// Errors returned by CreateBranch
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateBranchError {
     /// <p> Exception thrown when a request contains unexpected data. </p>
     BadRequest(String),
@@ -1763,6 +1784,8 @@ pub enum CreateBranchError {
     NotFound(String),
     /// <p> Exception thrown when an operation fails due to a lack of access. </p>
     Unauthorized(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateBranchError {
@@ -1770,27 +1793,28 @@ impl CreateBranchError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(CreateBranchError::BadRequest(err.msg))
+                    return RusotoError::service(CreateBranchError::BadRequest(err.msg), &res)
                 }
                 "DependentServiceFailureException" => {
-                    return RusotoError::Service(CreateBranchError::DependentServiceFailure(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateBranchError::DependentServiceFailure(err.msg),
+                        &res,
+                    )
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(CreateBranchError::InternalFailure(err.msg))
+                    return RusotoError::service(CreateBranchError::InternalFailure(err.msg), &res)
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateBranchError::LimitExceeded(err.msg))
+                    return RusotoError::service(CreateBranchError::LimitExceeded(err.msg), &res)
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(CreateBranchError::NotFound(err.msg))
+                    return RusotoError::service(CreateBranchError::NotFound(err.msg), &res)
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(CreateBranchError::Unauthorized(err.msg))
+                    return RusotoError::service(CreateBranchError::Unauthorized(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreateBranchError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1806,12 +1830,14 @@ impl fmt::Display for CreateBranchError {
             CreateBranchError::LimitExceeded(ref cause) => write!(f, "{}", cause),
             CreateBranchError::NotFound(ref cause) => write!(f, "{}", cause),
             CreateBranchError::Unauthorized(ref cause) => write!(f, "{}", cause),
+            CreateBranchError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateBranchError {}
 /// Errors returned by CreateDeployment
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateDeploymentError {
     /// <p> Exception thrown when a request contains unexpected data. </p>
     BadRequest(String),
@@ -1821,6 +1847,8 @@ pub enum CreateDeploymentError {
     LimitExceeded(String),
     /// <p> Exception thrown when an operation fails due to a lack of access. </p>
     Unauthorized(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateDeploymentError {
@@ -1828,19 +1856,25 @@ impl CreateDeploymentError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(CreateDeploymentError::BadRequest(err.msg))
+                    return RusotoError::service(CreateDeploymentError::BadRequest(err.msg), &res)
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(CreateDeploymentError::InternalFailure(err.msg))
+                    return RusotoError::service(
+                        CreateDeploymentError::InternalFailure(err.msg),
+                        &res,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateDeploymentError::LimitExceeded(err.msg))
+                    return RusotoError::service(
+                        CreateDeploymentError::LimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(CreateDeploymentError::Unauthorized(err.msg))
+                    return RusotoError::service(CreateDeploymentError::Unauthorized(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreateDeploymentError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1854,12 +1888,14 @@ impl fmt::Display for CreateDeploymentError {
             CreateDeploymentError::InternalFailure(ref cause) => write!(f, "{}", cause),
             CreateDeploymentError::LimitExceeded(ref cause) => write!(f, "{}", cause),
             CreateDeploymentError::Unauthorized(ref cause) => write!(f, "{}", cause),
+            CreateDeploymentError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateDeploymentError {}
 /// Errors returned by CreateDomainAssociation
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateDomainAssociationError {
     /// <p> Exception thrown when a request contains unexpected data. </p>
     BadRequest(String),
@@ -1873,6 +1909,8 @@ pub enum CreateDomainAssociationError {
     NotFound(String),
     /// <p> Exception thrown when an operation fails due to a lack of access. </p>
     Unauthorized(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateDomainAssociationError {
@@ -1880,33 +1918,43 @@ impl CreateDomainAssociationError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(CreateDomainAssociationError::BadRequest(err.msg))
+                    return RusotoError::service(
+                        CreateDomainAssociationError::BadRequest(err.msg),
+                        &res,
+                    )
                 }
                 "DependentServiceFailureException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         CreateDomainAssociationError::DependentServiceFailure(err.msg),
+                        &res,
                     )
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(CreateDomainAssociationError::InternalFailure(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateDomainAssociationError::InternalFailure(err.msg),
+                        &res,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateDomainAssociationError::LimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateDomainAssociationError::LimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(CreateDomainAssociationError::NotFound(err.msg))
+                    return RusotoError::service(
+                        CreateDomainAssociationError::NotFound(err.msg),
+                        &res,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(CreateDomainAssociationError::Unauthorized(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateDomainAssociationError::Unauthorized(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreateDomainAssociationError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1924,12 +1972,14 @@ impl fmt::Display for CreateDomainAssociationError {
             CreateDomainAssociationError::LimitExceeded(ref cause) => write!(f, "{}", cause),
             CreateDomainAssociationError::NotFound(ref cause) => write!(f, "{}", cause),
             CreateDomainAssociationError::Unauthorized(ref cause) => write!(f, "{}", cause),
+            CreateDomainAssociationError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateDomainAssociationError {}
 /// Errors returned by CreateWebhook
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateWebhookError {
     /// <p> Exception thrown when a request contains unexpected data. </p>
     BadRequest(String),
@@ -1943,6 +1993,8 @@ pub enum CreateWebhookError {
     NotFound(String),
     /// <p> Exception thrown when an operation fails due to a lack of access. </p>
     Unauthorized(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateWebhookError {
@@ -1950,27 +2002,28 @@ impl CreateWebhookError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(CreateWebhookError::BadRequest(err.msg))
+                    return RusotoError::service(CreateWebhookError::BadRequest(err.msg), &res)
                 }
                 "DependentServiceFailureException" => {
-                    return RusotoError::Service(CreateWebhookError::DependentServiceFailure(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateWebhookError::DependentServiceFailure(err.msg),
+                        &res,
+                    )
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(CreateWebhookError::InternalFailure(err.msg))
+                    return RusotoError::service(CreateWebhookError::InternalFailure(err.msg), &res)
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateWebhookError::LimitExceeded(err.msg))
+                    return RusotoError::service(CreateWebhookError::LimitExceeded(err.msg), &res)
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(CreateWebhookError::NotFound(err.msg))
+                    return RusotoError::service(CreateWebhookError::NotFound(err.msg), &res)
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(CreateWebhookError::Unauthorized(err.msg))
+                    return RusotoError::service(CreateWebhookError::Unauthorized(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreateWebhookError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1986,12 +2039,14 @@ impl fmt::Display for CreateWebhookError {
             CreateWebhookError::LimitExceeded(ref cause) => write!(f, "{}", cause),
             CreateWebhookError::NotFound(ref cause) => write!(f, "{}", cause),
             CreateWebhookError::Unauthorized(ref cause) => write!(f, "{}", cause),
+            CreateWebhookError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateWebhookError {}
 /// Errors returned by DeleteApp
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteAppError {
     /// <p> Exception thrown when a request contains unexpected data. </p>
     BadRequest(String),
@@ -2003,6 +2058,8 @@ pub enum DeleteAppError {
     NotFound(String),
     /// <p> Exception thrown when an operation fails due to a lack of access. </p>
     Unauthorized(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteAppError {
@@ -2010,22 +2067,25 @@ impl DeleteAppError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(DeleteAppError::BadRequest(err.msg))
+                    return RusotoError::service(DeleteAppError::BadRequest(err.msg), &res)
                 }
                 "DependentServiceFailureException" => {
-                    return RusotoError::Service(DeleteAppError::DependentServiceFailure(err.msg))
+                    return RusotoError::service(
+                        DeleteAppError::DependentServiceFailure(err.msg),
+                        &res,
+                    )
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(DeleteAppError::InternalFailure(err.msg))
+                    return RusotoError::service(DeleteAppError::InternalFailure(err.msg), &res)
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteAppError::NotFound(err.msg))
+                    return RusotoError::service(DeleteAppError::NotFound(err.msg), &res)
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(DeleteAppError::Unauthorized(err.msg))
+                    return RusotoError::service(DeleteAppError::Unauthorized(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteAppError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2040,12 +2100,14 @@ impl fmt::Display for DeleteAppError {
             DeleteAppError::InternalFailure(ref cause) => write!(f, "{}", cause),
             DeleteAppError::NotFound(ref cause) => write!(f, "{}", cause),
             DeleteAppError::Unauthorized(ref cause) => write!(f, "{}", cause),
+            DeleteAppError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteAppError {}
 /// Errors returned by DeleteBackendEnvironment
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteBackendEnvironmentError {
     /// <p> Exception thrown when a request contains unexpected data. </p>
     BadRequest(String),
@@ -2057,6 +2119,8 @@ pub enum DeleteBackendEnvironmentError {
     NotFound(String),
     /// <p> Exception thrown when an operation fails due to a lack of access. </p>
     Unauthorized(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteBackendEnvironmentError {
@@ -2064,28 +2128,37 @@ impl DeleteBackendEnvironmentError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(DeleteBackendEnvironmentError::BadRequest(err.msg))
+                    return RusotoError::service(
+                        DeleteBackendEnvironmentError::BadRequest(err.msg),
+                        &res,
+                    )
                 }
                 "DependentServiceFailureException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DeleteBackendEnvironmentError::DependentServiceFailure(err.msg),
+                        &res,
                     )
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(DeleteBackendEnvironmentError::InternalFailure(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteBackendEnvironmentError::InternalFailure(err.msg),
+                        &res,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteBackendEnvironmentError::NotFound(err.msg))
+                    return RusotoError::service(
+                        DeleteBackendEnvironmentError::NotFound(err.msg),
+                        &res,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(DeleteBackendEnvironmentError::Unauthorized(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteBackendEnvironmentError::Unauthorized(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteBackendEnvironmentError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2102,12 +2175,14 @@ impl fmt::Display for DeleteBackendEnvironmentError {
             DeleteBackendEnvironmentError::InternalFailure(ref cause) => write!(f, "{}", cause),
             DeleteBackendEnvironmentError::NotFound(ref cause) => write!(f, "{}", cause),
             DeleteBackendEnvironmentError::Unauthorized(ref cause) => write!(f, "{}", cause),
+            DeleteBackendEnvironmentError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteBackendEnvironmentError {}
 /// Errors returned by DeleteBranch
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteBranchError {
     /// <p> Exception thrown when a request contains unexpected data. </p>
     BadRequest(String),
@@ -2119,6 +2194,8 @@ pub enum DeleteBranchError {
     NotFound(String),
     /// <p> Exception thrown when an operation fails due to a lack of access. </p>
     Unauthorized(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteBranchError {
@@ -2126,24 +2203,25 @@ impl DeleteBranchError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(DeleteBranchError::BadRequest(err.msg))
+                    return RusotoError::service(DeleteBranchError::BadRequest(err.msg), &res)
                 }
                 "DependentServiceFailureException" => {
-                    return RusotoError::Service(DeleteBranchError::DependentServiceFailure(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteBranchError::DependentServiceFailure(err.msg),
+                        &res,
+                    )
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(DeleteBranchError::InternalFailure(err.msg))
+                    return RusotoError::service(DeleteBranchError::InternalFailure(err.msg), &res)
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteBranchError::NotFound(err.msg))
+                    return RusotoError::service(DeleteBranchError::NotFound(err.msg), &res)
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(DeleteBranchError::Unauthorized(err.msg))
+                    return RusotoError::service(DeleteBranchError::Unauthorized(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteBranchError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2158,12 +2236,14 @@ impl fmt::Display for DeleteBranchError {
             DeleteBranchError::InternalFailure(ref cause) => write!(f, "{}", cause),
             DeleteBranchError::NotFound(ref cause) => write!(f, "{}", cause),
             DeleteBranchError::Unauthorized(ref cause) => write!(f, "{}", cause),
+            DeleteBranchError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteBranchError {}
 /// Errors returned by DeleteDomainAssociation
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteDomainAssociationError {
     /// <p> Exception thrown when a request contains unexpected data. </p>
     BadRequest(String),
@@ -2175,6 +2255,8 @@ pub enum DeleteDomainAssociationError {
     NotFound(String),
     /// <p> Exception thrown when an operation fails due to a lack of access. </p>
     Unauthorized(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteDomainAssociationError {
@@ -2182,28 +2264,37 @@ impl DeleteDomainAssociationError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(DeleteDomainAssociationError::BadRequest(err.msg))
+                    return RusotoError::service(
+                        DeleteDomainAssociationError::BadRequest(err.msg),
+                        &res,
+                    )
                 }
                 "DependentServiceFailureException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DeleteDomainAssociationError::DependentServiceFailure(err.msg),
+                        &res,
                     )
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(DeleteDomainAssociationError::InternalFailure(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteDomainAssociationError::InternalFailure(err.msg),
+                        &res,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteDomainAssociationError::NotFound(err.msg))
+                    return RusotoError::service(
+                        DeleteDomainAssociationError::NotFound(err.msg),
+                        &res,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(DeleteDomainAssociationError::Unauthorized(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteDomainAssociationError::Unauthorized(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteDomainAssociationError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2220,12 +2311,14 @@ impl fmt::Display for DeleteDomainAssociationError {
             DeleteDomainAssociationError::InternalFailure(ref cause) => write!(f, "{}", cause),
             DeleteDomainAssociationError::NotFound(ref cause) => write!(f, "{}", cause),
             DeleteDomainAssociationError::Unauthorized(ref cause) => write!(f, "{}", cause),
+            DeleteDomainAssociationError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteDomainAssociationError {}
 /// Errors returned by DeleteJob
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteJobError {
     /// <p> Exception thrown when a request contains unexpected data. </p>
     BadRequest(String),
@@ -2237,6 +2330,8 @@ pub enum DeleteJobError {
     NotFound(String),
     /// <p> Exception thrown when an operation fails due to a lack of access. </p>
     Unauthorized(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteJobError {
@@ -2244,22 +2339,22 @@ impl DeleteJobError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(DeleteJobError::BadRequest(err.msg))
+                    return RusotoError::service(DeleteJobError::BadRequest(err.msg), &res)
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(DeleteJobError::InternalFailure(err.msg))
+                    return RusotoError::service(DeleteJobError::InternalFailure(err.msg), &res)
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(DeleteJobError::LimitExceeded(err.msg))
+                    return RusotoError::service(DeleteJobError::LimitExceeded(err.msg), &res)
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteJobError::NotFound(err.msg))
+                    return RusotoError::service(DeleteJobError::NotFound(err.msg), &res)
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(DeleteJobError::Unauthorized(err.msg))
+                    return RusotoError::service(DeleteJobError::Unauthorized(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteJobError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2274,12 +2369,14 @@ impl fmt::Display for DeleteJobError {
             DeleteJobError::LimitExceeded(ref cause) => write!(f, "{}", cause),
             DeleteJobError::NotFound(ref cause) => write!(f, "{}", cause),
             DeleteJobError::Unauthorized(ref cause) => write!(f, "{}", cause),
+            DeleteJobError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteJobError {}
 /// Errors returned by DeleteWebhook
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteWebhookError {
     /// <p> Exception thrown when a request contains unexpected data. </p>
     BadRequest(String),
@@ -2291,6 +2388,8 @@ pub enum DeleteWebhookError {
     NotFound(String),
     /// <p> Exception thrown when an operation fails due to a lack of access. </p>
     Unauthorized(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteWebhookError {
@@ -2298,22 +2397,22 @@ impl DeleteWebhookError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(DeleteWebhookError::BadRequest(err.msg))
+                    return RusotoError::service(DeleteWebhookError::BadRequest(err.msg), &res)
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(DeleteWebhookError::InternalFailure(err.msg))
+                    return RusotoError::service(DeleteWebhookError::InternalFailure(err.msg), &res)
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(DeleteWebhookError::LimitExceeded(err.msg))
+                    return RusotoError::service(DeleteWebhookError::LimitExceeded(err.msg), &res)
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteWebhookError::NotFound(err.msg))
+                    return RusotoError::service(DeleteWebhookError::NotFound(err.msg), &res)
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(DeleteWebhookError::Unauthorized(err.msg))
+                    return RusotoError::service(DeleteWebhookError::Unauthorized(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteWebhookError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2328,12 +2427,14 @@ impl fmt::Display for DeleteWebhookError {
             DeleteWebhookError::LimitExceeded(ref cause) => write!(f, "{}", cause),
             DeleteWebhookError::NotFound(ref cause) => write!(f, "{}", cause),
             DeleteWebhookError::Unauthorized(ref cause) => write!(f, "{}", cause),
+            DeleteWebhookError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteWebhookError {}
 /// Errors returned by GenerateAccessLogs
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GenerateAccessLogsError {
     /// <p> Exception thrown when a request contains unexpected data. </p>
     BadRequest(String),
@@ -2343,6 +2444,8 @@ pub enum GenerateAccessLogsError {
     NotFound(String),
     /// <p> Exception thrown when an operation fails due to a lack of access. </p>
     Unauthorized(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GenerateAccessLogsError {
@@ -2350,19 +2453,25 @@ impl GenerateAccessLogsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GenerateAccessLogsError::BadRequest(err.msg))
+                    return RusotoError::service(GenerateAccessLogsError::BadRequest(err.msg), &res)
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(GenerateAccessLogsError::InternalFailure(err.msg))
+                    return RusotoError::service(
+                        GenerateAccessLogsError::InternalFailure(err.msg),
+                        &res,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GenerateAccessLogsError::NotFound(err.msg))
+                    return RusotoError::service(GenerateAccessLogsError::NotFound(err.msg), &res)
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(GenerateAccessLogsError::Unauthorized(err.msg))
+                    return RusotoError::service(
+                        GenerateAccessLogsError::Unauthorized(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GenerateAccessLogsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2376,12 +2485,14 @@ impl fmt::Display for GenerateAccessLogsError {
             GenerateAccessLogsError::InternalFailure(ref cause) => write!(f, "{}", cause),
             GenerateAccessLogsError::NotFound(ref cause) => write!(f, "{}", cause),
             GenerateAccessLogsError::Unauthorized(ref cause) => write!(f, "{}", cause),
+            GenerateAccessLogsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GenerateAccessLogsError {}
 /// Errors returned by GetApp
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetAppError {
     /// <p> Exception thrown when a request contains unexpected data. </p>
     BadRequest(String),
@@ -2391,6 +2502,8 @@ pub enum GetAppError {
     NotFound(String),
     /// <p> Exception thrown when an operation fails due to a lack of access. </p>
     Unauthorized(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetAppError {
@@ -2398,17 +2511,19 @@ impl GetAppError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetAppError::BadRequest(err.msg))
+                    return RusotoError::service(GetAppError::BadRequest(err.msg), &res)
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(GetAppError::InternalFailure(err.msg))
+                    return RusotoError::service(GetAppError::InternalFailure(err.msg), &res)
+                }
+                "NotFoundException" => {
+                    return RusotoError::service(GetAppError::NotFound(err.msg), &res)
                 }
-                "NotFoundException" => return RusotoError::Service(GetAppError::NotFound(err.msg)),
                 "UnauthorizedException" => {
-                    return RusotoError::Service(GetAppError::Unauthorized(err.msg))
+                    return RusotoError::service(GetAppError::Unauthorized(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetAppError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2422,12 +2537,14 @@ impl fmt::Display for GetAppError {
             GetAppError::InternalFailure(ref cause) => write!(f, "{}", cause),
             GetAppError::NotFound(ref cause) => write!(f, "{}", cause),
             GetAppError::Unauthorized(ref cause) => write!(f, "{}", cause),
+            GetAppError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetAppError {}
 /// Errors returned by GetArtifactUrl
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetArtifactUrlError {
     /// <p> Exception thrown when a request contains unexpected data. </p>
     BadRequest(String),
@@ -2439,6 +2556,8 @@ pub enum GetArtifactUrlError {
     NotFound(String),
     /// <p> Exception thrown when an operation fails due to a lack of access. </p>
     Unauthorized(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetArtifactUrlError {
@@ -2446,22 +2565,25 @@ impl GetArtifactUrlError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetArtifactUrlError::BadRequest(err.msg))
+                    return RusotoError::service(GetArtifactUrlError::BadRequest(err.msg), &res)
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(GetArtifactUrlError::InternalFailure(err.msg))
+                    return RusotoError::service(
+                        GetArtifactUrlError::InternalFailure(err.msg),
+                        &res,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(GetArtifactUrlError::LimitExceeded(err.msg))
+                    return RusotoError::service(GetArtifactUrlError::LimitExceeded(err.msg), &res)
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetArtifactUrlError::NotFound(err.msg))
+                    return RusotoError::service(GetArtifactUrlError::NotFound(err.msg), &res)
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(GetArtifactUrlError::Unauthorized(err.msg))
+                    return RusotoError::service(GetArtifactUrlError::Unauthorized(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetArtifactUrlError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2476,12 +2598,14 @@ impl fmt::Display for GetArtifactUrlError {
             GetArtifactUrlError::LimitExceeded(ref cause) => write!(f, "{}", cause),
             GetArtifactUrlError::NotFound(ref cause) => write!(f, "{}", cause),
             GetArtifactUrlError::Unauthorized(ref cause) => write!(f, "{}", cause),
+            GetArtifactUrlError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetArtifactUrlError {}
 /// Errors returned by GetBackendEnvironment
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetBackendEnvironmentError {
     /// <p> Exception thrown when a request contains unexpected data. </p>
     BadRequest(String),
@@ -2491,6 +2615,8 @@ pub enum GetBackendEnvironmentError {
     NotFound(String),
     /// <p> Exception thrown when an operation fails due to a lack of access. </p>
     Unauthorized(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetBackendEnvironmentError {
@@ -2498,21 +2624,31 @@ impl GetBackendEnvironmentError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetBackendEnvironmentError::BadRequest(err.msg))
+                    return RusotoError::service(
+                        GetBackendEnvironmentError::BadRequest(err.msg),
+                        &res,
+                    )
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(GetBackendEnvironmentError::InternalFailure(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetBackendEnvironmentError::InternalFailure(err.msg),
+                        &res,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetBackendEnvironmentError::NotFound(err.msg))
+                    return RusotoError::service(
+                        GetBackendEnvironmentError::NotFound(err.msg),
+                        &res,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(GetBackendEnvironmentError::Unauthorized(err.msg))
+                    return RusotoError::service(
+                        GetBackendEnvironmentError::Unauthorized(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetBackendEnvironmentError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2526,12 +2662,14 @@ impl fmt::Display for GetBackendEnvironmentError {
             GetBackendEnvironmentError::InternalFailure(ref cause) => write!(f, "{}", cause),
             GetBackendEnvironmentError::NotFound(ref cause) => write!(f, "{}", cause),
             GetBackendEnvironmentError::Unauthorized(ref cause) => write!(f, "{}", cause),
+            GetBackendEnvironmentError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetBackendEnvironmentError {}
 /// Errors returned by GetBranch
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetBranchError {
     /// <p> Exception thrown when a request contains unexpected data. </p>
     BadRequest(String),
@@ -2541,6 +2679,8 @@ pub enum GetBranchError {
     NotFound(String),
     /// <p> Exception thrown when an operation fails due to a lack of access. </p>
     Unauthorized(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetBranchError {
@@ -2548,19 +2688,19 @@ impl GetBranchError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetBranchError::BadRequest(err.msg))
+                    return RusotoError::service(GetBranchError::BadRequest(err.msg), &res)
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(GetBranchError::InternalFailure(err.msg))
+                    return RusotoError::service(GetBranchError::InternalFailure(err.msg), &res)
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetBranchError::NotFound(err.msg))
+                    return RusotoError::service(GetBranchError::NotFound(err.msg), &res)
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(GetBranchError::Unauthorized(err.msg))
+                    return RusotoError::service(GetBranchError::Unauthorized(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetBranchError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2574,12 +2714,14 @@ impl fmt::Display for GetBranchError {
             GetBranchError::InternalFailure(ref cause) => write!(f, "{}", cause),
             GetBranchError::NotFound(ref cause) => write!(f, "{}", cause),
             GetBranchError::Unauthorized(ref cause) => write!(f, "{}", cause),
+            GetBranchError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetBranchError {}
 /// Errors returned by GetDomainAssociation
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetDomainAssociationError {
     /// <p> Exception thrown when a request contains unexpected data. </p>
     BadRequest(String),
@@ -2589,6 +2731,8 @@ pub enum GetDomainAssociationError {
     NotFound(String),
     /// <p> Exception thrown when an operation fails due to a lack of access. </p>
     Unauthorized(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetDomainAssociationError {
@@ -2596,21 +2740,28 @@ impl GetDomainAssociationError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetDomainAssociationError::BadRequest(err.msg))
+                    return RusotoError::service(
+                        GetDomainAssociationError::BadRequest(err.msg),
+                        &res,
+                    )
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(GetDomainAssociationError::InternalFailure(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetDomainAssociationError::InternalFailure(err.msg),
+                        &res,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetDomainAssociationError::NotFound(err.msg))
+                    return RusotoError::service(GetDomainAssociationError::NotFound(err.msg), &res)
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(GetDomainAssociationError::Unauthorized(err.msg))
+                    return RusotoError::service(
+                        GetDomainAssociationError::Unauthorized(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetDomainAssociationError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2624,12 +2775,14 @@ impl fmt::Display for GetDomainAssociationError {
             GetDomainAssociationError::InternalFailure(ref cause) => write!(f, "{}", cause),
             GetDomainAssociationError::NotFound(ref cause) => write!(f, "{}", cause),
             GetDomainAssociationError::Unauthorized(ref cause) => write!(f, "{}", cause),
+            GetDomainAssociationError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetDomainAssociationError {}
 /// Errors returned by GetJob
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetJobError {
     /// <p> Exception thrown when a request contains unexpected data. </p>
     BadRequest(String),
@@ -2641,6 +2794,8 @@ pub enum GetJobError {
     NotFound(String),
     /// <p> Exception thrown when an operation fails due to a lack of access. </p>
     Unauthorized(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetJobError {
@@ -2648,20 +2803,22 @@ impl GetJobError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetJobError::BadRequest(err.msg))
+                    return RusotoError::service(GetJobError::BadRequest(err.msg), &res)
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(GetJobError::InternalFailure(err.msg))
+                    return RusotoError::service(GetJobError::InternalFailure(err.msg), &res)
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(GetJobError::LimitExceeded(err.msg))
+                    return RusotoError::service(GetJobError::LimitExceeded(err.msg), &res)
+                }
+                "NotFoundException" => {
+                    return RusotoError::service(GetJobError::NotFound(err.msg), &res)
                 }
-                "NotFoundException" => return RusotoError::Service(GetJobError::NotFound(err.msg)),
                 "UnauthorizedException" => {
-                    return RusotoError::Service(GetJobError::Unauthorized(err.msg))
+                    return RusotoError::service(GetJobError::Unauthorized(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetJobError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2676,12 +2833,14 @@ impl fmt::Display for GetJobError {
             GetJobError::LimitExceeded(ref cause) => write!(f, "{}", cause),
             GetJobError::NotFound(ref cause) => write!(f, "{}", cause),
             GetJobError::Unauthorized(ref cause) => write!(f, "{}", cause),
+            GetJobError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetJobError {}
 /// Errors returned by GetWebhook
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetWebhookError {
     /// <p> Exception thrown when a request contains unexpected data. </p>
     BadRequest(String),
@@ -2693,6 +2852,8 @@ pub enum GetWebhookError {
     NotFound(String),
     /// <p> Exception thrown when an operation fails due to a lack of access. </p>
     Unauthorized(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetWebhookError {
@@ -2700,22 +2861,22 @@ impl GetWebhookError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetWebhookError::BadRequest(err.msg))
+                    return RusotoError::service(GetWebhookError::BadRequest(err.msg), &res)
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(GetWebhookError::InternalFailure(err.msg))
+                    return RusotoError::service(GetWebhookError::InternalFailure(err.msg), &res)
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(GetWebhookError::LimitExceeded(err.msg))
+                    return RusotoError::service(GetWebhookError::LimitExceeded(err.msg), &res)
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetWebhookError::NotFound(err.msg))
+                    return RusotoError::service(GetWebhookError::NotFound(err.msg), &res)
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(GetWebhookError::Unauthorized(err.msg))
+                    return RusotoError::service(GetWebhookError::Unauthorized(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetWebhookError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2730,12 +2891,14 @@ impl fmt::Display for GetWebhookError {
             GetWebhookError::LimitExceeded(ref cause) => write!(f, "{}", cause),
             GetWebhookError::NotFound(ref cause) => write!(f, "{}", cause),
             GetWebhookError::Unauthorized(ref cause) => write!(f, "{}", cause),
+            GetWebhookError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetWebhookError {}
 /// Errors returned by ListApps
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListAppsError {
     /// <p> Exception thrown when a request contains unexpected data. </p>
     BadRequest(String),
@@ -2743,6 +2906,8 @@ pub enum ListAppsError {
     InternalFailure(String),
     /// <p> Exception thrown when an operation fails due to a lack of access. </p>
     Unauthorized(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListAppsError {
@@ -2750,16 +2915,16 @@ impl ListAppsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(ListAppsError::BadRequest(err.msg))
+                    return RusotoError::service(ListAppsError::BadRequest(err.msg), &res)
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(ListAppsError::InternalFailure(err.msg))
+                    return RusotoError::service(ListAppsError::InternalFailure(err.msg), &res)
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(ListAppsError::Unauthorized(err.msg))
+                    return RusotoError::service(ListAppsError::Unauthorized(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListAppsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2772,12 +2937,14 @@ impl fmt::Display for ListAppsError {
             ListAppsError::BadRequest(ref cause) => write!(f, "{}", cause),
             ListAppsError::InternalFailure(ref cause) => write!(f, "{}", cause),
             ListAppsError::Unauthorized(ref cause) => write!(f, "{}", cause),
+            ListAppsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListAppsError {}
 /// Errors returned by ListArtifacts
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListArtifactsError {
     /// <p> Exception thrown when a request contains unexpected data. </p>
     BadRequest(String),
@@ -2787,6 +2954,8 @@ pub enum ListArtifactsError {
     LimitExceeded(String),
     /// <p> Exception thrown when an operation fails due to a lack of access. </p>
     Unauthorized(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListArtifactsError {
@@ -2794,19 +2963,19 @@ impl ListArtifactsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(ListArtifactsError::BadRequest(err.msg))
+                    return RusotoError::service(ListArtifactsError::BadRequest(err.msg), &res)
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(ListArtifactsError::InternalFailure(err.msg))
+                    return RusotoError::service(ListArtifactsError::InternalFailure(err.msg), &res)
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(ListArtifactsError::LimitExceeded(err.msg))
+                    return RusotoError::service(ListArtifactsError::LimitExceeded(err.msg), &res)
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(ListArtifactsError::Unauthorized(err.msg))
+                    return RusotoError::service(ListArtifactsError::Unauthorized(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListArtifactsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2820,12 +2989,14 @@ impl fmt::Display for ListArtifactsError {
             ListArtifactsError::InternalFailure(ref cause) => write!(f, "{}", cause),
             ListArtifactsError::LimitExceeded(ref cause) => write!(f, "{}", cause),
             ListArtifactsError::Unauthorized(ref cause) => write!(f, "{}", cause),
+            ListArtifactsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListArtifactsError {}
 /// Errors returned by ListBackendEnvironments
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListBackendEnvironmentsError {
     /// <p> Exception thrown when a request contains unexpected data. </p>
     BadRequest(String),
@@ -2833,6 +3004,8 @@ pub enum ListBackendEnvironmentsError {
     InternalFailure(String),
     /// <p> Exception thrown when an operation fails due to a lack of access. </p>
     Unauthorized(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListBackendEnvironmentsError {
@@ -2840,20 +3013,25 @@ impl ListBackendEnvironmentsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(ListBackendEnvironmentsError::BadRequest(err.msg))
+                    return RusotoError::service(
+                        ListBackendEnvironmentsError::BadRequest(err.msg),
+                        &res,
+                    )
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(ListBackendEnvironmentsError::InternalFailure(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListBackendEnvironmentsError::InternalFailure(err.msg),
+                        &res,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(ListBackendEnvironmentsError::Unauthorized(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListBackendEnvironmentsError::Unauthorized(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListBackendEnvironmentsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2866,12 +3044,14 @@ impl fmt::Display for ListBackendEnvironmentsError {
             ListBackendEnvironmentsError::BadRequest(ref cause) => write!(f, "{}", cause),
             ListBackendEnvironmentsError::InternalFailure(ref cause) => write!(f, "{}", cause),
             ListBackendEnvironmentsError::Unauthorized(ref cause) => write!(f, "{}", cause),
+            ListBackendEnvironmentsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListBackendEnvironmentsError {}
 /// Errors returned by ListBranches
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListBranchesError {
     /// <p> Exception thrown when a request contains unexpected data. </p>
     BadRequest(String),
@@ -2879,6 +3059,8 @@ pub enum ListBranchesError {
     InternalFailure(String),
     /// <p> Exception thrown when an operation fails due to a lack of access. </p>
     Unauthorized(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListBranchesError {
@@ -2886,16 +3068,16 @@ impl ListBranchesError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(ListBranchesError::BadRequest(err.msg))
+                    return RusotoError::service(ListBranchesError::BadRequest(err.msg), &res)
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(ListBranchesError::InternalFailure(err.msg))
+                    return RusotoError::service(ListBranchesError::InternalFailure(err.msg), &res)
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(ListBranchesError::Unauthorized(err.msg))
+                    return RusotoError::service(ListBranchesError::Unauthorized(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListBranchesError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2908,12 +3090,14 @@ impl fmt::Display for ListBranchesError {
             ListBranchesError::BadRequest(ref cause) => write!(f, "{}", cause),
             ListBranchesError::InternalFailure(ref cause) => write!(f, "{}", cause),
             ListBranchesError::Unauthorized(ref cause) => write!(f, "{}", cause),
+            ListBranchesError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListBranchesError {}
 /// Errors returned by ListDomainAssociations
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListDomainAssociationsError {
     /// <p> Exception thrown when a request contains unexpected data. </p>
     BadRequest(String),
@@ -2921,6 +3105,8 @@ pub enum ListDomainAssociationsError {
     InternalFailure(String),
     /// <p> Exception thrown when an operation fails due to a lack of access. </p>
     Unauthorized(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListDomainAssociationsError {
@@ -2928,18 +3114,25 @@ impl ListDomainAssociationsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(ListDomainAssociationsError::BadRequest(err.msg))
+                    return RusotoError::service(
+                        ListDomainAssociationsError::BadRequest(err.msg),
+                        &res,
+                    )
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(ListDomainAssociationsError::InternalFailure(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListDomainAssociationsError::InternalFailure(err.msg),
+                        &res,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(ListDomainAssociationsError::Unauthorized(err.msg))
+                    return RusotoError::service(
+                        ListDomainAssociationsError::Unauthorized(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListDomainAssociationsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2952,12 +3145,14 @@ impl fmt::Display for ListDomainAssociationsError {
             ListDomainAssociationsError::BadRequest(ref cause) => write!(f, "{}", cause),
             ListDomainAssociationsError::InternalFailure(ref cause) => write!(f, "{}", cause),
             ListDomainAssociationsError::Unauthorized(ref cause) => write!(f, "{}", cause),
+            ListDomainAssociationsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListDomainAssociationsError {}
 /// Errors returned by ListJobs
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListJobsError {
     /// <p> Exception thrown when a request contains unexpected data. </p>
     BadRequest(String),
@@ -2967,6 +3162,8 @@ pub enum ListJobsError {
     LimitExceeded(String),
     /// <p> Exception thrown when an operation fails due to a lack of access. </p>
     Unauthorized(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListJobsError {
@@ -2974,19 +3171,19 @@ impl ListJobsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(ListJobsError::BadRequest(err.msg))
+                    return RusotoError::service(ListJobsError::BadRequest(err.msg), &res)
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(ListJobsError::InternalFailure(err.msg))
+                    return RusotoError::service(ListJobsError::InternalFailure(err.msg), &res)
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(ListJobsError::LimitExceeded(err.msg))
+                    return RusotoError::service(ListJobsError::LimitExceeded(err.msg), &res)
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(ListJobsError::Unauthorized(err.msg))
+                    return RusotoError::service(ListJobsError::Unauthorized(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListJobsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3000,12 +3197,14 @@ impl fmt::Display for ListJobsError {
             ListJobsError::InternalFailure(ref cause) => write!(f, "{}", cause),
             ListJobsError::LimitExceeded(ref cause) => write!(f, "{}", cause),
             ListJobsError::Unauthorized(ref cause) => write!(f, "{}", cause),
+            ListJobsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListJobsError {}
 /// Errors returned by ListTagsForResource
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListTagsForResourceError {
     /// <p> Exception thrown when a request contains unexpected data. </p>
     BadRequest(String),
@@ -3013,6 +3212,8 @@ pub enum ListTagsForResourceError {
     InternalFailure(String),
     /// <p> Exception thrown when an operation fails due to non-existent resource. </p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListTagsForResourceError {
@@ -3020,18 +3221,25 @@ impl ListTagsForResourceError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(ListTagsForResourceError::BadRequest(err.msg))
+                    return RusotoError::service(
+                        ListTagsForResourceError::BadRequest(err.msg),
+                        &res,
+                    )
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(ListTagsForResourceError::InternalFailure(err.msg))
+                    return RusotoError::service(
+                        ListTagsForResourceError::InternalFailure(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListTagsForResourceError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListTagsForResourceError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListTagsForResourceError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3044,12 +3252,14 @@ impl fmt::Display for ListTagsForResourceError {
             ListTagsForResourceError::BadRequest(ref cause) => write!(f, "{}", cause),
             ListTagsForResourceError::InternalFailure(ref cause) => write!(f, "{}", cause),
             ListTagsForResourceError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            ListTagsForResourceError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListTagsForResourceError {}
 /// Errors returned by ListWebhooks
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListWebhooksError {
     /// <p> Exception thrown when a request contains unexpected data. </p>
     BadRequest(String),
@@ -3059,6 +3269,8 @@ pub enum ListWebhooksError {
     LimitExceeded(String),
     /// <p> Exception thrown when an operation fails due to a lack of access. </p>
     Unauthorized(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListWebhooksError {
@@ -3066,19 +3278,19 @@ impl ListWebhooksError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(ListWebhooksError::BadRequest(err.msg))
+                    return RusotoError::service(ListWebhooksError::BadRequest(err.msg), &res)
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(ListWebhooksError::InternalFailure(err.msg))
+                    return RusotoError::service(ListWebhooksError::InternalFailure(err.msg), &res)
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(ListWebhooksError::LimitExceeded(err.msg))
+                    return RusotoError::service(ListWebhooksError::LimitExceeded(err.msg), &res)
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(ListWebhooksError::Unauthorized(err.msg))
+                    return RusotoError::service(ListWebhooksError::Unauthorized(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListWebhooksError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3092,12 +3304,14 @@ impl fmt::Display for ListWebhooksError {
             ListWebhooksError::InternalFailure(ref cause) => write!(f, "{}", cause),
             ListWebhooksError::LimitExceeded(ref cause) => write!(f, "{}", cause),
             ListWebhooksError::Unauthorized(ref cause) => write!(f, "{}", cause),
+            ListWebhooksError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListWebhooksError {}
 /// Errors returned by StartDeployment
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum StartDeploymentError {
     /// <p> Exception thrown when a request contains unexpected data. </p>
     BadRequest(String),
@@ -3109,6 +3323,8 @@ pub enum StartDeploymentError {
     NotFound(String),
     /// <p> Exception thrown when an operation fails due to a lack of access. </p>
     Unauthorized(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl StartDeploymentError {
@@ -3116,22 +3332,25 @@ impl StartDeploymentError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(StartDeploymentError::BadRequest(err.msg))
+                    return RusotoError::service(StartDeploymentError::BadRequest(err.msg), &res)
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(StartDeploymentError::InternalFailure(err.msg))
+                    return RusotoError::service(
+                        StartDeploymentError::InternalFailure(err.msg),
+                        &res,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(StartDeploymentError::LimitExceeded(err.msg))
+                    return RusotoError::service(StartDeploymentError::LimitExceeded(err.msg), &res)
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(StartDeploymentError::NotFound(err.msg))
+                    return RusotoError::service(StartDeploymentError::NotFound(err.msg), &res)
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(StartDeploymentError::Unauthorized(err.msg))
+                    return RusotoError::service(StartDeploymentError::Unauthorized(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(StartDeploymentError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3146,12 +3365,14 @@ impl fmt::Display for StartDeploymentError {
             StartDeploymentError::LimitExceeded(ref cause) => write!(f, "{}", cause),
             StartDeploymentError::NotFound(ref cause) => write!(f, "{}", cause),
             StartDeploymentError::Unauthorized(ref cause) => write!(f, "{}", cause),
+            StartDeploymentError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for StartDeploymentError {}
 /// Errors returned by StartJob
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum StartJobError {
     /// <p> Exception thrown when a request contains unexpected data. </p>
     BadRequest(String),
@@ -3163,6 +3384,8 @@ pub enum StartJobError {
     NotFound(String),
     /// <p> Exception thrown when an operation fails due to a lack of access. </p>
     Unauthorized(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl StartJobError {
@@ -3170,22 +3393,22 @@ impl StartJobError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(StartJobError::BadRequest(err.msg))
+                    return RusotoError::service(StartJobError::BadRequest(err.msg), &res)
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(StartJobError::InternalFailure(err.msg))
+                    return RusotoError::service(StartJobError::InternalFailure(err.msg), &res)
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(StartJobError::LimitExceeded(err.msg))
+                    return RusotoError::service(StartJobError::LimitExceeded(err.msg), &res)
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(StartJobError::NotFound(err.msg))
+                    return RusotoError::service(StartJobError::NotFound(err.msg), &res)
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(StartJobError::Unauthorized(err.msg))
+                    return RusotoError::service(StartJobError::Unauthorized(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(StartJobError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3200,12 +3423,14 @@ impl fmt::Display for StartJobError {
             StartJobError::LimitExceeded(ref cause) => write!(f, "{}", cause),
             StartJobError::NotFound(ref cause) => write!(f, "{}", cause),
             StartJobError::Unauthorized(ref cause) => write!(f, "{}", cause),
+            StartJobError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for StartJobError {}
 /// Errors returned by StopJob
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum StopJobError {
     /// <p> Exception thrown when a request contains unexpected data. </p>
     BadRequest(String),
@@ -3217,6 +3442,8 @@ pub enum StopJobError {
     NotFound(String),
     /// <p> Exception thrown when an operation fails due to a lack of access. </p>
     Unauthorized(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl StopJobError {
@@ -3224,22 +3451,22 @@ impl StopJobError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(StopJobError::BadRequest(err.msg))
+                    return RusotoError::service(StopJobError::BadRequest(err.msg), &res)
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(StopJobError::InternalFailure(err.msg))
+                    return RusotoError::service(StopJobError::InternalFailure(err.msg), &res)
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(StopJobError::LimitExceeded(err.msg))
+                    return RusotoError::service(StopJobError::LimitExceeded(err.msg), &res)
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(StopJobError::NotFound(err.msg))
+                    return RusotoError::service(StopJobError::NotFound(err.msg), &res)
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(StopJobError::Unauthorized(err.msg))
+                    return RusotoError::service(StopJobError::Unauthorized(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(StopJobError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3254,12 +3481,14 @@ impl fmt::Display for StopJobError {
             StopJobError::LimitExceeded(ref cause) => write!(f, "{}", cause),
             StopJobError::NotFound(ref cause) => write!(f, "{}", cause),
             StopJobError::Unauthorized(ref cause) => write!(f, "{}", cause),
+            StopJobError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for StopJobError {}
 /// Errors returned by TagResource
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum TagResourceError {
     /// <p> Exception thrown when a request contains unexpected data. </p>
     BadRequest(String),
@@ -3267,6 +3496,8 @@ pub enum TagResourceError {
     InternalFailure(String),
     /// <p> Exception thrown when an operation fails due to non-existent resource. </p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl TagResourceError {
@@ -3274,16 +3505,16 @@ impl TagResourceError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(TagResourceError::BadRequest(err.msg))
+                    return RusotoError::service(TagResourceError::BadRequest(err.msg), &res)
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(TagResourceError::InternalFailure(err.msg))
+                    return RusotoError::service(TagResourceError::InternalFailure(err.msg), &res)
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(TagResourceError::ResourceNotFound(err.msg))
+                    return RusotoError::service(TagResourceError::ResourceNotFound(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(TagResourceError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3296,12 +3527,14 @@ impl fmt::Display for TagResourceError {
             TagResourceError::BadRequest(ref cause) => write!(f, "{}", cause),
             TagResourceError::InternalFailure(ref cause) => write!(f, "{}", cause),
             TagResourceError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            TagResourceError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for TagResourceError {}
 /// Errors returned by UntagResource
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UntagResourceError {
     /// <p> Exception thrown when a request contains unexpected data. </p>
     BadRequest(String),
@@ -3309,6 +3542,8 @@ pub enum UntagResourceError {
     InternalFailure(String),
     /// <p> Exception thrown when an operation fails due to non-existent resource. </p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UntagResourceError {
@@ -3316,16 +3551,19 @@ impl UntagResourceError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(UntagResourceError::BadRequest(err.msg))
+                    return RusotoError::service(UntagResourceError::BadRequest(err.msg), &res)
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(UntagResourceError::InternalFailure(err.msg))
+                    return RusotoError::service(UntagResourceError::InternalFailure(err.msg), &res)
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UntagResourceError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        UntagResourceError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UntagResourceError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3338,12 +3576,14 @@ impl fmt::Display for UntagResourceError {
             UntagResourceError::BadRequest(ref cause) => write!(f, "{}", cause),
             UntagResourceError::InternalFailure(ref cause) => write!(f, "{}", cause),
             UntagResourceError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            UntagResourceError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for UntagResourceError {}
 /// Errors returned by UpdateApp
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdateAppError {
     /// <p> Exception thrown when a request contains unexpected data. </p>
     BadRequest(String),
@@ -3353,6 +3593,8 @@ pub enum UpdateAppError {
     NotFound(String),
     /// <p> Exception thrown when an operation fails due to a lack of access. </p>
     Unauthorized(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdateAppError {
@@ -3360,19 +3602,19 @@ impl UpdateAppError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(UpdateAppError::BadRequest(err.msg))
+                    return RusotoError::service(UpdateAppError::BadRequest(err.msg), &res)
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(UpdateAppError::InternalFailure(err.msg))
+                    return RusotoError::service(UpdateAppError::InternalFailure(err.msg), &res)
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(UpdateAppError::NotFound(err.msg))
+                    return RusotoError::service(UpdateAppError::NotFound(err.msg), &res)
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(UpdateAppError::Unauthorized(err.msg))
+                    return RusotoError::service(UpdateAppError::Unauthorized(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UpdateAppError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3386,12 +3628,14 @@ impl fmt::Display for UpdateAppError {
             UpdateAppError::InternalFailure(ref cause) => write!(f, "{}", cause),
             UpdateAppError::NotFound(ref cause) => write!(f, "{}", cause),
             UpdateAppError::Unauthorized(ref cause) => write!(f, "{}", cause),
+            UpdateAppError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for UpdateAppError {}
 /// Errors returned by UpdateBranch
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdateBranchError {
     /// <p> Exception thrown when a request contains unexpected data. </p>
     BadRequest(String),
@@ -3403,6 +3647,8 @@ pub enum UpdateBranchError {
     NotFound(String),
     /// <p> Exception thrown when an operation fails due to a lack of access. </p>
     Unauthorized(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdateBranchError {
@@ -3410,24 +3656,25 @@ impl UpdateBranchError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(UpdateBranchError::BadRequest(err.msg))
+                    return RusotoError::service(UpdateBranchError::BadRequest(err.msg), &res)
                 }
                 "DependentServiceFailureException" => {
-                    return RusotoError::Service(UpdateBranchError::DependentServiceFailure(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateBranchError::DependentServiceFailure(err.msg),
+                        &res,
+                    )
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(UpdateBranchError::InternalFailure(err.msg))
+                    return RusotoError::service(UpdateBranchError::InternalFailure(err.msg), &res)
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(UpdateBranchError::NotFound(err.msg))
+                    return RusotoError::service(UpdateBranchError::NotFound(err.msg), &res)
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(UpdateBranchError::Unauthorized(err.msg))
+                    return RusotoError::service(UpdateBranchError::Unauthorized(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UpdateBranchError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3442,12 +3689,14 @@ impl fmt::Display for UpdateBranchError {
             UpdateBranchError::InternalFailure(ref cause) => write!(f, "{}", cause),
             UpdateBranchError::NotFound(ref cause) => write!(f, "{}", cause),
             UpdateBranchError::Unauthorized(ref cause) => write!(f, "{}", cause),
+            UpdateBranchError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for UpdateBranchError {}
 /// Errors returned by UpdateDomainAssociation
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdateDomainAssociationError {
     /// <p> Exception thrown when a request contains unexpected data. </p>
     BadRequest(String),
@@ -3459,6 +3708,8 @@ pub enum UpdateDomainAssociationError {
     NotFound(String),
     /// <p> Exception thrown when an operation fails due to a lack of access. </p>
     Unauthorized(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdateDomainAssociationError {
@@ -3466,28 +3717,37 @@ impl UpdateDomainAssociationError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(UpdateDomainAssociationError::BadRequest(err.msg))
+                    return RusotoError::service(
+                        UpdateDomainAssociationError::BadRequest(err.msg),
+                        &res,
+                    )
                 }
                 "DependentServiceFailureException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         UpdateDomainAssociationError::DependentServiceFailure(err.msg),
+                        &res,
                     )
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(UpdateDomainAssociationError::InternalFailure(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateDomainAssociationError::InternalFailure(err.msg),
+                        &res,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(UpdateDomainAssociationError::NotFound(err.msg))
+                    return RusotoError::service(
+                        UpdateDomainAssociationError::NotFound(err.msg),
+                        &res,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(UpdateDomainAssociationError::Unauthorized(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateDomainAssociationError::Unauthorized(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UpdateDomainAssociationError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3504,12 +3764,14 @@ impl fmt::Display for UpdateDomainAssociationError {
             UpdateDomainAssociationError::InternalFailure(ref cause) => write!(f, "{}", cause),
             UpdateDomainAssociationError::NotFound(ref cause) => write!(f, "{}", cause),
             UpdateDomainAssociationError::Unauthorized(ref cause) => write!(f, "{}", cause),
+            UpdateDomainAssociationError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for UpdateDomainAssociationError {}
 /// Errors returned by UpdateWebhook
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdateWebhookError {
     /// <p> Exception thrown when a request contains unexpected data. </p>
     BadRequest(String),
@@ -3521,6 +3783,8 @@ pub enum UpdateWebhookError {
     NotFound(String),
     /// <p> Exception thrown when an operation fails due to a lack of access. </p>
     Unauthorized(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdateWebhookError {
@@ -3528,24 +3792,25 @@ impl UpdateWebhookError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(UpdateWebhookError::BadRequest(err.msg))
+                    return RusotoError::service(UpdateWebhookError::BadRequest(err.msg), &res)
                 }
                 "DependentServiceFailureException" => {
-                    return RusotoError::Service(UpdateWebhookError::DependentServiceFailure(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateWebhookError::DependentServiceFailure(err.msg),
+                        &res,
+                    )
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(UpdateWebhookError::InternalFailure(err.msg))
+                    return RusotoError::service(UpdateWebhookError::InternalFailure(err.msg), &res)
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(UpdateWebhookError::NotFound(err.msg))
+                    return RusotoError::service(UpdateWebhookError::NotFound(err.msg), &res)
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(UpdateWebhookError::Unauthorized(err.msg))
+                    return RusotoError::service(UpdateWebhookError::Unauthorized(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UpdateWebhookError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3560,6 +3825,7 @@ impl fmt::Display for UpdateWebhookError {
             UpdateWebhookError::InternalFailure(ref cause) => write!(f, "{}", cause),
             UpdateWebhookError::NotFound(ref cause) => write!(f, "{}", cause),
             UpdateWebhookError::Unauthorized(ref cause) => write!(f, "{}", cause),
+            UpdateWebhookError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
@@ -3796,6 +4062,7 @@ impl AmplifyClient {
     /// Creates a client backed by the default tokio event loop.
     ///
     /// The client will use the default credentials provider and tls client.
+    #[cfg(not(target_arch = "wasm32"))]
     pub fn new(region: region::Region) -> AmplifyClient {
         AmplifyClient {
             client: Client::shared(),
@@ -3821,105 +4088,235 @@ impl AmplifyClient {
     pub fn new_with_client(client: Client, region: region::Region) -> AmplifyClient {
         AmplifyClient { client, region }
     }
+
+    /// Returns a builder of a client, setting any of its region, credentials provider,
+    /// dispatcher, retry policy, endpoint and timeouts.
+    pub fn builder() -> rusoto_core::ClientBuilder<AmplifyClient> {
+        rusoto_core::ClientBuilder::new(AmplifyClient::new_with_client)
+    }
+
+    /// Returns a clone of this client with the overrides of `config` applied to its
+    /// operations, sharing its connections.
+    pub fn customize(&self, config: &rusoto_core::OperationConfig) -> AmplifyClient {
+        AmplifyClient {
+            client: self.client.customize(config),
+            region: config.resolve_region(&self.region),
+        }
+    }
 }
 
-#[async_trait]
-impl Amplify for AmplifyClient {
-    /// <p> Creates a new Amplify App. </p>
-    async fn create_app(
+impl AmplifyClient {
+    /// Calls `create_app`, also returning the metadata of the response.
+    pub async fn create_app_with_metadata(
         &self,
         input: CreateAppRequest,
-    ) -> Result<CreateAppResult, RusotoError<CreateAppError>> {
+    ) -> Result<(CreateAppResult, ResponseMetadata), RusotoError<CreateAppError>> {
+        let request = self.build_create_app_request(input);
+
+        let mut response = self
+            .client
+            .sign_and_dispatch(request)
+            .await
+            .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
+        if response.status.is_success() {
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result =
+                proto::json::ResponsePayload::new(&response).deserialize::<CreateAppResult, _>()?;
+
+            Ok((result, metadata))
+        } else {
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(CreateAppError::from_response(response))
+        }
+    }
+
+    /// Presigns the request `create_app` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_app(
+        &self,
+        presigner: &rusoto_core::Presigner,
+        input: CreateAppRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_app_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_app_request(&self, input: CreateAppRequest) -> SignedRequest {
         let request_uri = "/apps";
 
         let mut request = SignedRequest::new("POST", "amplify", &self.region, &request_uri);
+        request.set_operation("CreateApp");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `create_backend_environment`, also returning the metadata of the response.
+    pub async fn create_backend_environment_with_metadata(
+        &self,
+        input: CreateBackendEnvironmentRequest,
+    ) -> Result<
+        (CreateBackendEnvironmentResult, ResponseMetadata),
+        RusotoError<CreateBackendEnvironmentError>,
+    > {
+        let request = self.build_create_backend_environment_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            let result =
-                proto::json::ResponsePayload::new(&response).deserialize::<CreateAppResult, _>()?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<CreateBackendEnvironmentResult, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(CreateAppError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(CreateBackendEnvironmentError::from_response(response))
         }
     }
 
-    /// <p> Creates a new backend environment for an Amplify App. </p>
-    async fn create_backend_environment(
+    /// Presigns the request `create_backend_environment` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_backend_environment(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: CreateBackendEnvironmentRequest,
-    ) -> Result<CreateBackendEnvironmentResult, RusotoError<CreateBackendEnvironmentError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_backend_environment_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_backend_environment_request(
+        &self,
+        input: CreateBackendEnvironmentRequest,
+    ) -> SignedRequest {
         let request_uri = format!("/apps/{app_id}/backendenvironments", app_id = input.app_id);
 
         let mut request = SignedRequest::new("POST", "amplify", &self.region, &request_uri);
+        request.set_operation("CreateBackendEnvironment");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `create_branch`, also returning the metadata of the response.
+    pub async fn create_branch_with_metadata(
+        &self,
+        input: CreateBranchRequest,
+    ) -> Result<(CreateBranchResult, ResponseMetadata), RusotoError<CreateBranchError>> {
+        let request = self.build_create_branch_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<CreateBackendEnvironmentResult, _>()?;
+                .deserialize::<CreateBranchResult, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(CreateBackendEnvironmentError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(CreateBranchError::from_response(response))
         }
     }
 
-    /// <p> Creates a new Branch for an Amplify App. </p>
-    async fn create_branch(
+    /// Presigns the request `create_branch` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_branch(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: CreateBranchRequest,
-    ) -> Result<CreateBranchResult, RusotoError<CreateBranchError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_branch_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_branch_request(&self, input: CreateBranchRequest) -> SignedRequest {
         let request_uri = format!("/apps/{app_id}/branches", app_id = input.app_id);
 
         let mut request = SignedRequest::new("POST", "amplify", &self.region, &request_uri);
+        request.set_operation("CreateBranch");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `create_deployment`, also returning the metadata of the response.
+    pub async fn create_deployment_with_metadata(
+        &self,
+        input: CreateDeploymentRequest,
+    ) -> Result<(CreateDeploymentResult, ResponseMetadata), RusotoError<CreateDeploymentError>>
+    {
+        let request = self.build_create_deployment_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<CreateBranchResult, _>()?;
+                .deserialize::<CreateDeploymentResult, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(CreateBranchError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(CreateDeploymentError::from_response(response))
         }
     }
 
-    /// <p> Create a deployment for manual deploy apps. (Apps are not connected to repository) </p>
-    async fn create_deployment(
+    /// Presigns the request `create_deployment` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_deployment(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: CreateDeploymentRequest,
-    ) -> Result<CreateDeploymentResult, RusotoError<CreateDeploymentError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_deployment_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_deployment_request(&self, input: CreateDeploymentRequest) -> SignedRequest {
         let request_uri = format!(
             "/apps/{app_id}/branches/{branch_name}/deployments",
             app_id = input.app_id,
@@ -3927,120 +4324,228 @@ impl Amplify for AmplifyClient {
         );
 
         let mut request = SignedRequest::new("POST", "amplify", &self.region, &request_uri);
+        request.set_operation("CreateDeployment");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `create_domain_association`, also returning the metadata of the response.
+    pub async fn create_domain_association_with_metadata(
+        &self,
+        input: CreateDomainAssociationRequest,
+    ) -> Result<
+        (CreateDomainAssociationResult, ResponseMetadata),
+        RusotoError<CreateDomainAssociationError>,
+    > {
+        let request = self.build_create_domain_association_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<CreateDeploymentResult, _>()?;
+                .deserialize::<CreateDomainAssociationResult, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(CreateDeploymentError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(CreateDomainAssociationError::from_response(response))
         }
     }
 
-    /// <p> Create a new DomainAssociation on an App </p>
-    async fn create_domain_association(
+    /// Presigns the request `create_domain_association` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_domain_association(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: CreateDomainAssociationRequest,
-    ) -> Result<CreateDomainAssociationResult, RusotoError<CreateDomainAssociationError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_domain_association_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_domain_association_request(
+        &self,
+        input: CreateDomainAssociationRequest,
+    ) -> SignedRequest {
         let request_uri = format!("/apps/{app_id}/domains", app_id = input.app_id);
 
         let mut request = SignedRequest::new("POST", "amplify", &self.region, &request_uri);
+        request.set_operation("CreateDomainAssociation");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `create_webhook`, also returning the metadata of the response.
+    pub async fn create_webhook_with_metadata(
+        &self,
+        input: CreateWebhookRequest,
+    ) -> Result<(CreateWebhookResult, ResponseMetadata), RusotoError<CreateWebhookError>> {
+        let request = self.build_create_webhook_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<CreateDomainAssociationResult, _>()?;
+                .deserialize::<CreateWebhookResult, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(CreateDomainAssociationError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(CreateWebhookError::from_response(response))
         }
     }
 
-    /// <p> Create a new webhook on an App. </p>
-    async fn create_webhook(
+    /// Presigns the request `create_webhook` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_webhook(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: CreateWebhookRequest,
-    ) -> Result<CreateWebhookResult, RusotoError<CreateWebhookError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_webhook_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_webhook_request(&self, input: CreateWebhookRequest) -> SignedRequest {
         let request_uri = format!("/apps/{app_id}/webhooks", app_id = input.app_id);
 
         let mut request = SignedRequest::new("POST", "amplify", &self.region, &request_uri);
+        request.set_operation("CreateWebhook");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `delete_app`, also returning the metadata of the response.
+    pub async fn delete_app_with_metadata(
+        &self,
+        input: DeleteAppRequest,
+    ) -> Result<(DeleteAppResult, ResponseMetadata), RusotoError<DeleteAppError>> {
+        let request = self.build_delete_app_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<CreateWebhookResult, _>()?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result =
+                proto::json::ResponsePayload::new(&response).deserialize::<DeleteAppResult, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(CreateWebhookError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(DeleteAppError::from_response(response))
         }
     }
 
-    /// <p> Delete an existing Amplify App by appId. </p>
-    async fn delete_app(
+    /// Presigns the request `delete_app` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_app(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteAppRequest,
-    ) -> Result<DeleteAppResult, RusotoError<DeleteAppError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_app_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_app_request(&self, input: DeleteAppRequest) -> SignedRequest {
         let request_uri = format!("/apps/{app_id}", app_id = input.app_id);
 
         let mut request = SignedRequest::new("DELETE", "amplify", &self.region, &request_uri);
+        request.set_operation("DeleteApp");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
+        request
+    }
+
+    /// Calls `delete_backend_environment`, also returning the metadata of the response.
+    pub async fn delete_backend_environment_with_metadata(
+        &self,
+        input: DeleteBackendEnvironmentRequest,
+    ) -> Result<
+        (DeleteBackendEnvironmentResult, ResponseMetadata),
+        RusotoError<DeleteBackendEnvironmentError>,
+    > {
+        let request = self.build_delete_backend_environment_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            let result =
-                proto::json::ResponsePayload::new(&response).deserialize::<DeleteAppResult, _>()?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DeleteBackendEnvironmentResult, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteAppError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(DeleteBackendEnvironmentError::from_response(response))
         }
     }
 
-    /// <p> Delete backend environment for an Amplify App. </p>
-    async fn delete_backend_environment(
+    /// Presigns the request `delete_backend_environment` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_backend_environment(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteBackendEnvironmentRequest,
-    ) -> Result<DeleteBackendEnvironmentResult, RusotoError<DeleteBackendEnvironmentError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_backend_environment_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_backend_environment_request(
+        &self,
+        input: DeleteBackendEnvironmentRequest,
+    ) -> SignedRequest {
         let request_uri = format!(
             "/apps/{app_id}/backendenvironments/{environment_name}",
             app_id = input.app_id,
@@ -4048,30 +4553,54 @@ impl Amplify for AmplifyClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "amplify", &self.region, &request_uri);
+        request.set_operation("DeleteBackendEnvironment");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
+        request
+    }
+
+    /// Calls `delete_branch`, also returning the metadata of the response.
+    pub async fn delete_branch_with_metadata(
+        &self,
+        input: DeleteBranchRequest,
+    ) -> Result<(DeleteBranchResult, ResponseMetadata), RusotoError<DeleteBranchError>> {
+        let request = self.build_delete_branch_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<DeleteBackendEnvironmentResult, _>()?;
+                .deserialize::<DeleteBranchResult, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteBackendEnvironmentError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(DeleteBranchError::from_response(response))
         }
     }
 
-    /// <p> Deletes a branch for an Amplify App. </p>
-    async fn delete_branch(
+    /// Presigns the request `delete_branch` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_branch(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteBranchRequest,
-    ) -> Result<DeleteBranchResult, RusotoError<DeleteBranchError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_branch_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_branch_request(&self, input: DeleteBranchRequest) -> SignedRequest {
         let request_uri = format!(
             "/apps/{app_id}/branches/{branch_name}",
             app_id = input.app_id,
@@ -4079,30 +4608,60 @@ impl Amplify for AmplifyClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "amplify", &self.region, &request_uri);
+        request.set_operation("DeleteBranch");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
+        request
+    }
+
+    /// Calls `delete_domain_association`, also returning the metadata of the response.
+    pub async fn delete_domain_association_with_metadata(
+        &self,
+        input: DeleteDomainAssociationRequest,
+    ) -> Result<
+        (DeleteDomainAssociationResult, ResponseMetadata),
+        RusotoError<DeleteDomainAssociationError>,
+    > {
+        let request = self.build_delete_domain_association_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<DeleteBranchResult, _>()?;
+                .deserialize::<DeleteDomainAssociationResult, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteBranchError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(DeleteDomainAssociationError::from_response(response))
         }
     }
 
-    /// <p> Deletes a DomainAssociation. </p>
-    async fn delete_domain_association(
+    /// Presigns the request `delete_domain_association` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_domain_association(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteDomainAssociationRequest,
-    ) -> Result<DeleteDomainAssociationResult, RusotoError<DeleteDomainAssociationError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_domain_association_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_domain_association_request(
+        &self,
+        input: DeleteDomainAssociationRequest,
+    ) -> SignedRequest {
         let request_uri = format!(
             "/apps/{app_id}/domains/{domain_name}",
             app_id = input.app_id,
@@ -4110,30 +4669,54 @@ impl Amplify for AmplifyClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "amplify", &self.region, &request_uri);
+        request.set_operation("DeleteDomainAssociation");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
+        request
+    }
+
+    /// Calls `delete_job`, also returning the metadata of the response.
+    pub async fn delete_job_with_metadata(
+        &self,
+        input: DeleteJobRequest,
+    ) -> Result<(DeleteJobResult, ResponseMetadata), RusotoError<DeleteJobError>> {
+        let request = self.build_delete_job_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<DeleteDomainAssociationResult, _>()?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result =
+                proto::json::ResponsePayload::new(&response).deserialize::<DeleteJobResult, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteDomainAssociationError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(DeleteJobError::from_response(response))
         }
     }
 
-    /// <p> Delete a job, for an Amplify branch, part of Amplify App. </p>
-    async fn delete_job(
+    /// Presigns the request `delete_job` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_job(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteJobRequest,
-    ) -> Result<DeleteJobResult, RusotoError<DeleteJobError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_job_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_job_request(&self, input: DeleteJobRequest) -> SignedRequest {
         let request_uri = format!(
             "/apps/{app_id}/branches/{branch_name}/jobs/{job_id}",
             app_id = input.app_id,
@@ -4142,141 +4725,271 @@ impl Amplify for AmplifyClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "amplify", &self.region, &request_uri);
+        request.set_operation("DeleteJob");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
+        request
+    }
+
+    /// Calls `delete_webhook`, also returning the metadata of the response.
+    pub async fn delete_webhook_with_metadata(
+        &self,
+        input: DeleteWebhookRequest,
+    ) -> Result<(DeleteWebhookResult, ResponseMetadata), RusotoError<DeleteWebhookError>> {
+        let request = self.build_delete_webhook_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            let result =
-                proto::json::ResponsePayload::new(&response).deserialize::<DeleteJobResult, _>()?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DeleteWebhookResult, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteJobError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(DeleteWebhookError::from_response(response))
         }
     }
 
-    /// <p> Deletes a webhook. </p>
-    async fn delete_webhook(
+    /// Presigns the request `delete_webhook` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_webhook(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteWebhookRequest,
-    ) -> Result<DeleteWebhookResult, RusotoError<DeleteWebhookError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_webhook_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_webhook_request(&self, input: DeleteWebhookRequest) -> SignedRequest {
         let request_uri = format!("/webhooks/{webhook_id}", webhook_id = input.webhook_id);
 
         let mut request = SignedRequest::new("DELETE", "amplify", &self.region, &request_uri);
+        request.set_operation("DeleteWebhook");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
+        request
+    }
+
+    /// Calls `generate_access_logs`, also returning the metadata of the response.
+    pub async fn generate_access_logs_with_metadata(
+        &self,
+        input: GenerateAccessLogsRequest,
+    ) -> Result<(GenerateAccessLogsResult, ResponseMetadata), RusotoError<GenerateAccessLogsError>>
+    {
+        let request = self.build_generate_access_logs_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<DeleteWebhookResult, _>()?;
+                .deserialize::<GenerateAccessLogsResult, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteWebhookError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(GenerateAccessLogsError::from_response(response))
         }
     }
 
-    /// <p> Retrieve website access logs for a specific time range via a pre-signed URL. </p>
-    async fn generate_access_logs(
+    /// Presigns the request `generate_access_logs` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_generate_access_logs(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GenerateAccessLogsRequest,
-    ) -> Result<GenerateAccessLogsResult, RusotoError<GenerateAccessLogsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_generate_access_logs_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_generate_access_logs_request(
+        &self,
+        input: GenerateAccessLogsRequest,
+    ) -> SignedRequest {
         let request_uri = format!("/apps/{app_id}/accesslogs", app_id = input.app_id);
 
         let mut request = SignedRequest::new("POST", "amplify", &self.region, &request_uri);
+        request.set_operation("GenerateAccessLogs");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `get_app`, also returning the metadata of the response.
+    pub async fn get_app_with_metadata(
+        &self,
+        input: GetAppRequest,
+    ) -> Result<(GetAppResult, ResponseMetadata), RusotoError<GetAppError>> {
+        let request = self.build_get_app_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<GenerateAccessLogsResult, _>()?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result =
+                proto::json::ResponsePayload::new(&response).deserialize::<GetAppResult, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(GenerateAccessLogsError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(GetAppError::from_response(response))
         }
     }
 
-    /// <p> Retrieves an existing Amplify App by appId. </p>
-    async fn get_app(
+    /// Presigns the request `get_app` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_app(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetAppRequest,
-    ) -> Result<GetAppResult, RusotoError<GetAppError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_app_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_app_request(&self, input: GetAppRequest) -> SignedRequest {
         let request_uri = format!("/apps/{app_id}", app_id = input.app_id);
 
         let mut request = SignedRequest::new("GET", "amplify", &self.region, &request_uri);
+        request.set_operation("GetApp");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
+        request
+    }
+
+    /// Calls `get_artifact_url`, also returning the metadata of the response.
+    pub async fn get_artifact_url_with_metadata(
+        &self,
+        input: GetArtifactUrlRequest,
+    ) -> Result<(GetArtifactUrlResult, ResponseMetadata), RusotoError<GetArtifactUrlError>> {
+        let request = self.build_get_artifact_url_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            let result =
-                proto::json::ResponsePayload::new(&response).deserialize::<GetAppResult, _>()?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<GetArtifactUrlResult, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(GetAppError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(GetArtifactUrlError::from_response(response))
         }
     }
 
-    /// <p> Retrieves artifact info that corresponds to a artifactId. </p>
-    async fn get_artifact_url(
+    /// Presigns the request `get_artifact_url` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_artifact_url(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetArtifactUrlRequest,
-    ) -> Result<GetArtifactUrlResult, RusotoError<GetArtifactUrlError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_artifact_url_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_artifact_url_request(&self, input: GetArtifactUrlRequest) -> SignedRequest {
         let request_uri = format!("/artifacts/{artifact_id}", artifact_id = input.artifact_id);
 
         let mut request = SignedRequest::new("GET", "amplify", &self.region, &request_uri);
+        request.set_operation("GetArtifactUrl");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
+        request
+    }
+
+    /// Calls `get_backend_environment`, also returning the metadata of the response.
+    pub async fn get_backend_environment_with_metadata(
+        &self,
+        input: GetBackendEnvironmentRequest,
+    ) -> Result<
+        (GetBackendEnvironmentResult, ResponseMetadata),
+        RusotoError<GetBackendEnvironmentError>,
+    > {
+        let request = self.build_get_backend_environment_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<GetArtifactUrlResult, _>()?;
+                .deserialize::<GetBackendEnvironmentResult, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(GetArtifactUrlError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(GetBackendEnvironmentError::from_response(response))
         }
     }
 
-    /// <p> Retrieves a backend environment for an Amplify App. </p>
-    async fn get_backend_environment(
+    /// Presigns the request `get_backend_environment` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_backend_environment(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetBackendEnvironmentRequest,
-    ) -> Result<GetBackendEnvironmentResult, RusotoError<GetBackendEnvironmentError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_backend_environment_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_backend_environment_request(
+        &self,
+        input: GetBackendEnvironmentRequest,
+    ) -> SignedRequest {
         let request_uri = format!(
             "/apps/{app_id}/backendenvironments/{environment_name}",
             app_id = input.app_id,
@@ -4284,30 +4997,54 @@ impl Amplify for AmplifyClient {
         );
 
         let mut request = SignedRequest::new("GET", "amplify", &self.region, &request_uri);
+        request.set_operation("GetBackendEnvironment");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
+        request
+    }
+
+    /// Calls `get_branch`, also returning the metadata of the response.
+    pub async fn get_branch_with_metadata(
+        &self,
+        input: GetBranchRequest,
+    ) -> Result<(GetBranchResult, ResponseMetadata), RusotoError<GetBranchError>> {
+        let request = self.build_get_branch_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<GetBackendEnvironmentResult, _>()?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result =
+                proto::json::ResponsePayload::new(&response).deserialize::<GetBranchResult, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(GetBackendEnvironmentError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(GetBranchError::from_response(response))
         }
     }
 
-    /// <p> Retrieves a branch for an Amplify App. </p>
-    async fn get_branch(
+    /// Presigns the request `get_branch` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_branch(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetBranchRequest,
-    ) -> Result<GetBranchResult, RusotoError<GetBranchError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_branch_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_branch_request(&self, input: GetBranchRequest) -> SignedRequest {
         let request_uri = format!(
             "/apps/{app_id}/branches/{branch_name}",
             app_id = input.app_id,
@@ -4315,30 +5052,60 @@ impl Amplify for AmplifyClient {
         );
 
         let mut request = SignedRequest::new("GET", "amplify", &self.region, &request_uri);
+        request.set_operation("GetBranch");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
+        request
+    }
+
+    /// Calls `get_domain_association`, also returning the metadata of the response.
+    pub async fn get_domain_association_with_metadata(
+        &self,
+        input: GetDomainAssociationRequest,
+    ) -> Result<
+        (GetDomainAssociationResult, ResponseMetadata),
+        RusotoError<GetDomainAssociationError>,
+    > {
+        let request = self.build_get_domain_association_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            let result =
-                proto::json::ResponsePayload::new(&response).deserialize::<GetBranchResult, _>()?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<GetDomainAssociationResult, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(GetBranchError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(GetDomainAssociationError::from_response(response))
         }
     }
 
-    /// <p> Retrieves domain info that corresponds to an appId and domainName. </p>
-    async fn get_domain_association(
+    /// Presigns the request `get_domain_association` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_domain_association(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetDomainAssociationRequest,
-    ) -> Result<GetDomainAssociationResult, RusotoError<GetDomainAssociationError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_domain_association_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_domain_association_request(
+        &self,
+        input: GetDomainAssociationRequest,
+    ) -> SignedRequest {
         let request_uri = format!(
             "/apps/{app_id}/domains/{domain_name}",
             app_id = input.app_id,
@@ -4346,30 +5113,54 @@ impl Amplify for AmplifyClient {
         );
 
         let mut request = SignedRequest::new("GET", "amplify", &self.region, &request_uri);
+        request.set_operation("GetDomainAssociation");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
+        request
+    }
+
+    /// Calls `get_job`, also returning the metadata of the response.
+    pub async fn get_job_with_metadata(
+        &self,
+        input: GetJobRequest,
+    ) -> Result<(GetJobResult, ResponseMetadata), RusotoError<GetJobError>> {
+        let request = self.build_get_job_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<GetDomainAssociationResult, _>()?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result =
+                proto::json::ResponsePayload::new(&response).deserialize::<GetJobResult, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(GetDomainAssociationError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(GetJobError::from_response(response))
         }
     }
 
-    /// <p> Get a job for a branch, part of an Amplify App. </p>
-    async fn get_job(
+    /// Presigns the request `get_job` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_job(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetJobRequest,
-    ) -> Result<GetJobResult, RusotoError<GetJobError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_job_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_job_request(&self, input: GetJobRequest) -> SignedRequest {
         let request_uri = format!(
             "/apps/{app_id}/branches/{branch_name}/jobs/{job_id}",
             app_id = input.app_id,
@@ -4378,60 +5169,109 @@ impl Amplify for AmplifyClient {
         );
 
         let mut request = SignedRequest::new("GET", "amplify", &self.region, &request_uri);
+        request.set_operation("GetJob");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
+        request
+    }
+
+    /// Calls `get_webhook`, also returning the metadata of the response.
+    pub async fn get_webhook_with_metadata(
+        &self,
+        input: GetWebhookRequest,
+    ) -> Result<(GetWebhookResult, ResponseMetadata), RusotoError<GetWebhookError>> {
+        let request = self.build_get_webhook_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            let result =
-                proto::json::ResponsePayload::new(&response).deserialize::<GetJobResult, _>()?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<GetWebhookResult, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(GetJobError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(GetWebhookError::from_response(response))
         }
     }
 
-    /// <p> Retrieves webhook info that corresponds to a webhookId. </p>
-    async fn get_webhook(
+    /// Presigns the request `get_webhook` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_webhook(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetWebhookRequest,
-    ) -> Result<GetWebhookResult, RusotoError<GetWebhookError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_webhook_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_webhook_request(&self, input: GetWebhookRequest) -> SignedRequest {
         let request_uri = format!("/webhooks/{webhook_id}", webhook_id = input.webhook_id);
 
         let mut request = SignedRequest::new("GET", "amplify", &self.region, &request_uri);
+        request.set_operation("GetWebhook");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
+        request
+    }
+
+    /// Calls `list_apps`, also returning the metadata of the response.
+    pub async fn list_apps_with_metadata(
+        &self,
+        input: ListAppsRequest,
+    ) -> Result<(ListAppsResult, ResponseMetadata), RusotoError<ListAppsError>> {
+        let request = self.build_list_apps_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<GetWebhookResult, _>()?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result =
+                proto::json::ResponsePayload::new(&response).deserialize::<ListAppsResult, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(GetWebhookError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(ListAppsError::from_response(response))
         }
     }
 
-    /// <p> Lists existing Amplify Apps. </p>
-    async fn list_apps(
+    /// Presigns the request `list_apps` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_apps(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListAppsRequest,
-    ) -> Result<ListAppsResult, RusotoError<ListAppsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_apps_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_apps_request(&self, input: ListAppsRequest) -> SignedRequest {
         let request_uri = "/apps";
 
         let mut request = SignedRequest::new("GET", "amplify", &self.region, &request_uri);
+        request.set_operation("ListApps");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -4443,28 +5283,51 @@ impl Amplify for AmplifyClient {
         }
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `list_artifacts`, also returning the metadata of the response.
+    pub async fn list_artifacts_with_metadata(
+        &self,
+        input: ListArtifactsRequest,
+    ) -> Result<(ListArtifactsResult, ResponseMetadata), RusotoError<ListArtifactsError>> {
+        let request = self.build_list_artifacts_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            let result =
-                proto::json::ResponsePayload::new(&response).deserialize::<ListAppsResult, _>()?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ListArtifactsResult, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(ListAppsError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(ListArtifactsError::from_response(response))
         }
     }
 
-    /// <p> List artifacts with an app, a branch, a job and an artifact type. </p>
-    async fn list_artifacts(
+    /// Presigns the request `list_artifacts` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_artifacts(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListArtifactsRequest,
-    ) -> Result<ListArtifactsResult, RusotoError<ListArtifactsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_artifacts_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_artifacts_request(&self, input: ListArtifactsRequest) -> SignedRequest {
         let request_uri = format!(
             "/apps/{app_id}/branches/{branch_name}/jobs/{job_id}/artifacts",
             app_id = input.app_id,
@@ -4473,6 +5336,7 @@ impl Amplify for AmplifyClient {
         );
 
         let mut request = SignedRequest::new("GET", "amplify", &self.region, &request_uri);
+        request.set_operation("ListArtifacts");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -4484,31 +5348,61 @@ impl Amplify for AmplifyClient {
         }
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `list_backend_environments`, also returning the metadata of the response.
+    pub async fn list_backend_environments_with_metadata(
+        &self,
+        input: ListBackendEnvironmentsRequest,
+    ) -> Result<
+        (ListBackendEnvironmentsResult, ResponseMetadata),
+        RusotoError<ListBackendEnvironmentsError>,
+    > {
+        let request = self.build_list_backend_environments_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListArtifactsResult, _>()?;
+                .deserialize::<ListBackendEnvironmentsResult, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(ListArtifactsError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(ListBackendEnvironmentsError::from_response(response))
         }
     }
 
-    /// <p> Lists backend environments for an Amplify App. </p>
-    async fn list_backend_environments(
+    /// Presigns the request `list_backend_environments` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_backend_environments(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListBackendEnvironmentsRequest,
-    ) -> Result<ListBackendEnvironmentsResult, RusotoError<ListBackendEnvironmentsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_backend_environments_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_backend_environments_request(
+        &self,
+        input: ListBackendEnvironmentsRequest,
+    ) -> SignedRequest {
         let request_uri = format!("/apps/{app_id}/backendenvironments", app_id = input.app_id);
 
         let mut request = SignedRequest::new("GET", "amplify", &self.region, &request_uri);
+        request.set_operation("ListBackendEnvironments");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -4523,31 +5417,55 @@ impl Amplify for AmplifyClient {
         }
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `list_branches`, also returning the metadata of the response.
+    pub async fn list_branches_with_metadata(
+        &self,
+        input: ListBranchesRequest,
+    ) -> Result<(ListBranchesResult, ResponseMetadata), RusotoError<ListBranchesError>> {
+        let request = self.build_list_branches_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListBackendEnvironmentsResult, _>()?;
+                .deserialize::<ListBranchesResult, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(ListBackendEnvironmentsError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(ListBranchesError::from_response(response))
         }
     }
 
-    /// <p> Lists branches for an Amplify App. </p>
-    async fn list_branches(
+    /// Presigns the request `list_branches` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_branches(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListBranchesRequest,
-    ) -> Result<ListBranchesResult, RusotoError<ListBranchesError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_branches_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_branches_request(&self, input: ListBranchesRequest) -> SignedRequest {
         let request_uri = format!("/apps/{app_id}/branches", app_id = input.app_id);
 
         let mut request = SignedRequest::new("GET", "amplify", &self.region, &request_uri);
+        request.set_operation("ListBranches");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -4559,31 +5477,61 @@ impl Amplify for AmplifyClient {
         }
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `list_domain_associations`, also returning the metadata of the response.
+    pub async fn list_domain_associations_with_metadata(
+        &self,
+        input: ListDomainAssociationsRequest,
+    ) -> Result<
+        (ListDomainAssociationsResult, ResponseMetadata),
+        RusotoError<ListDomainAssociationsError>,
+    > {
+        let request = self.build_list_domain_associations_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListBranchesResult, _>()?;
+                .deserialize::<ListDomainAssociationsResult, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(ListBranchesError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(ListDomainAssociationsError::from_response(response))
         }
     }
 
-    /// <p> List domains with an app </p>
-    async fn list_domain_associations(
+    /// Presigns the request `list_domain_associations` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_domain_associations(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListDomainAssociationsRequest,
-    ) -> Result<ListDomainAssociationsResult, RusotoError<ListDomainAssociationsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_domain_associations_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_domain_associations_request(
+        &self,
+        input: ListDomainAssociationsRequest,
+    ) -> SignedRequest {
         let request_uri = format!("/apps/{app_id}/domains", app_id = input.app_id);
 
         let mut request = SignedRequest::new("GET", "amplify", &self.region, &request_uri);
+        request.set_operation("ListDomainAssociations");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -4595,28 +5543,51 @@ impl Amplify for AmplifyClient {
         }
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `list_jobs`, also returning the metadata of the response.
+    pub async fn list_jobs_with_metadata(
+        &self,
+        input: ListJobsRequest,
+    ) -> Result<(ListJobsResult, ResponseMetadata), RusotoError<ListJobsError>> {
+        let request = self.build_list_jobs_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListDomainAssociationsResult, _>()?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result =
+                proto::json::ResponsePayload::new(&response).deserialize::<ListJobsResult, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(ListDomainAssociationsError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(ListJobsError::from_response(response))
         }
     }
 
-    /// <p> List Jobs for a branch, part of an Amplify App. </p>
-    async fn list_jobs(
+    /// Presigns the request `list_jobs` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_jobs(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListJobsRequest,
-    ) -> Result<ListJobsResult, RusotoError<ListJobsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_jobs_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_jobs_request(&self, input: ListJobsRequest) -> SignedRequest {
         let request_uri = format!(
             "/apps/{app_id}/branches/{branch_name}/jobs",
             app_id = input.app_id,
@@ -4624,6 +5595,7 @@ impl Amplify for AmplifyClient {
         );
 
         let mut request = SignedRequest::new("GET", "amplify", &self.region, &request_uri);
+        request.set_operation("ListJobs");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -4635,58 +5607,112 @@ impl Amplify for AmplifyClient {
         }
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `list_tags_for_resource`, also returning the metadata of the response.
+    pub async fn list_tags_for_resource_with_metadata(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> Result<
+        (ListTagsForResourceResponse, ResponseMetadata),
+        RusotoError<ListTagsForResourceError>,
+    > {
+        let request = self.build_list_tags_for_resource_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            let result =
-                proto::json::ResponsePayload::new(&response).deserialize::<ListJobsResult, _>()?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ListTagsForResourceResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(ListJobsError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(ListTagsForResourceError::from_response(response))
         }
     }
 
-    /// <p> List tags for resource. </p>
-    async fn list_tags_for_resource(
+    /// Presigns the request `list_tags_for_resource` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_tags_for_resource(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListTagsForResourceRequest,
-    ) -> Result<ListTagsForResourceResponse, RusotoError<ListTagsForResourceError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_tags_for_resource_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_tags_for_resource_request(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> SignedRequest {
         let request_uri = format!("/tags/{resource_arn}", resource_arn = input.resource_arn);
 
         let mut request = SignedRequest::new("GET", "amplify", &self.region, &request_uri);
+        request.set_operation("ListTagsForResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
+        request
+    }
+
+    /// Calls `list_webhooks`, also returning the metadata of the response.
+    pub async fn list_webhooks_with_metadata(
+        &self,
+        input: ListWebhooksRequest,
+    ) -> Result<(ListWebhooksResult, ResponseMetadata), RusotoError<ListWebhooksError>> {
+        let request = self.build_list_webhooks_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListTagsForResourceResponse, _>()?;
+                .deserialize::<ListWebhooksResult, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(ListTagsForResourceError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(ListWebhooksError::from_response(response))
         }
     }
 
-    /// <p> List webhooks with an app. </p>
-    async fn list_webhooks(
+    /// Presigns the request `list_webhooks` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_webhooks(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListWebhooksRequest,
-    ) -> Result<ListWebhooksResult, RusotoError<ListWebhooksError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_webhooks_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_webhooks_request(&self, input: ListWebhooksRequest) -> SignedRequest {
         let request_uri = format!("/apps/{app_id}/webhooks", app_id = input.app_id);
 
         let mut request = SignedRequest::new("GET", "amplify", &self.region, &request_uri);
+        request.set_operation("ListWebhooks");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -4698,28 +5724,51 @@ impl Amplify for AmplifyClient {
         }
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `start_deployment`, also returning the metadata of the response.
+    pub async fn start_deployment_with_metadata(
+        &self,
+        input: StartDeploymentRequest,
+    ) -> Result<(StartDeploymentResult, ResponseMetadata), RusotoError<StartDeploymentError>> {
+        let request = self.build_start_deployment_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListWebhooksResult, _>()?;
+                .deserialize::<StartDeploymentResult, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(ListWebhooksError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(StartDeploymentError::from_response(response))
         }
     }
 
-    /// <p> Start a deployment for manual deploy apps. (Apps are not connected to repository) </p>
-    async fn start_deployment(
+    /// Presigns the request `start_deployment` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_start_deployment(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: StartDeploymentRequest,
-    ) -> Result<StartDeploymentResult, RusotoError<StartDeploymentError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_start_deployment_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_start_deployment_request(&self, input: StartDeploymentRequest) -> SignedRequest {
         let request_uri = format!(
             "/apps/{app_id}/branches/{branch_name}/deployments/start",
             app_id = input.app_id,
@@ -4727,33 +5776,57 @@ impl Amplify for AmplifyClient {
         );
 
         let mut request = SignedRequest::new("POST", "amplify", &self.region, &request_uri);
+        request.set_operation("StartDeployment");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `start_job`, also returning the metadata of the response.
+    pub async fn start_job_with_metadata(
+        &self,
+        input: StartJobRequest,
+    ) -> Result<(StartJobResult, ResponseMetadata), RusotoError<StartJobError>> {
+        let request = self.build_start_job_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<StartDeploymentResult, _>()?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result =
+                proto::json::ResponsePayload::new(&response).deserialize::<StartJobResult, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(StartDeploymentError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(StartJobError::from_response(response))
         }
     }
 
-    /// <p> Starts a new job for a branch, part of an Amplify App. </p>
-    async fn start_job(
+    /// Presigns the request `start_job` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_start_job(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: StartJobRequest,
-    ) -> Result<StartJobResult, RusotoError<StartJobError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_start_job_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_start_job_request(&self, input: StartJobRequest) -> SignedRequest {
         let request_uri = format!(
             "/apps/{app_id}/branches/{branch_name}/jobs",
             app_id = input.app_id,
@@ -4761,33 +5834,57 @@ impl Amplify for AmplifyClient {
         );
 
         let mut request = SignedRequest::new("POST", "amplify", &self.region, &request_uri);
+        request.set_operation("StartJob");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `stop_job`, also returning the metadata of the response.
+    pub async fn stop_job_with_metadata(
+        &self,
+        input: StopJobRequest,
+    ) -> Result<(StopJobResult, ResponseMetadata), RusotoError<StopJobError>> {
+        let request = self.build_stop_job_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result =
-                proto::json::ResponsePayload::new(&response).deserialize::<StartJobResult, _>()?;
+                proto::json::ResponsePayload::new(&response).deserialize::<StopJobResult, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(StartJobError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(StopJobError::from_response(response))
         }
     }
 
-    /// <p> Stop a job that is in progress, for an Amplify branch, part of Amplify App. </p>
-    async fn stop_job(
+    /// Presigns the request `stop_job` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_stop_job(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: StopJobRequest,
-    ) -> Result<StopJobResult, RusotoError<StopJobError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_stop_job_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_stop_job_request(&self, input: StopJobRequest) -> SignedRequest {
         let request_uri = format!(
             "/apps/{app_id}/branches/{branch_name}/jobs/{job_id}/stop",
             app_id = input.app_id,
@@ -4796,63 +5893,112 @@ impl Amplify for AmplifyClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "amplify", &self.region, &request_uri);
+        request.set_operation("StopJob");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
+        request
+    }
+
+    /// Calls `tag_resource`, also returning the metadata of the response.
+    pub async fn tag_resource_with_metadata(
+        &self,
+        input: TagResourceRequest,
+    ) -> Result<(TagResourceResponse, ResponseMetadata), RusotoError<TagResourceError>> {
+        let request = self.build_tag_resource_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            let result =
-                proto::json::ResponsePayload::new(&response).deserialize::<StopJobResult, _>()?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<TagResourceResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(StopJobError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(TagResourceError::from_response(response))
         }
     }
 
-    /// <p> Tag resource with tag key and value. </p>
-    async fn tag_resource(
+    /// Presigns the request `tag_resource` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_tag_resource(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: TagResourceRequest,
-    ) -> Result<TagResourceResponse, RusotoError<TagResourceError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_tag_resource_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_tag_resource_request(&self, input: TagResourceRequest) -> SignedRequest {
         let request_uri = format!("/tags/{resource_arn}", resource_arn = input.resource_arn);
 
         let mut request = SignedRequest::new("POST", "amplify", &self.region, &request_uri);
+        request.set_operation("TagResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `untag_resource`, also returning the metadata of the response.
+    pub async fn untag_resource_with_metadata(
+        &self,
+        input: UntagResourceRequest,
+    ) -> Result<(UntagResourceResponse, ResponseMetadata), RusotoError<UntagResourceError>> {
+        let request = self.build_untag_resource_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<TagResourceResponse, _>()?;
+                .deserialize::<UntagResourceResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(TagResourceError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(UntagResourceError::from_response(response))
         }
     }
 
-    /// <p> Untag resource with resourceArn. </p>
-    async fn untag_resource(
+    /// Presigns the request `untag_resource` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_untag_resource(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UntagResourceRequest,
-    ) -> Result<UntagResourceResponse, RusotoError<UntagResourceError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_untag_resource_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_untag_resource_request(&self, input: UntagResourceRequest) -> SignedRequest {
         let request_uri = format!("/tags/{resource_arn}", resource_arn = input.resource_arn);
 
         let mut request = SignedRequest::new("DELETE", "amplify", &self.region, &request_uri);
+        request.set_operation("UntagResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -4861,58 +6007,105 @@ impl Amplify for AmplifyClient {
         }
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `update_app`, also returning the metadata of the response.
+    pub async fn update_app_with_metadata(
+        &self,
+        input: UpdateAppRequest,
+    ) -> Result<(UpdateAppResult, ResponseMetadata), RusotoError<UpdateAppError>> {
+        let request = self.build_update_app_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<UntagResourceResponse, _>()?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result =
+                proto::json::ResponsePayload::new(&response).deserialize::<UpdateAppResult, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(UntagResourceError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(UpdateAppError::from_response(response))
         }
     }
 
-    /// <p> Updates an existing Amplify App. </p>
-    async fn update_app(
+    /// Presigns the request `update_app` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_app(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UpdateAppRequest,
-    ) -> Result<UpdateAppResult, RusotoError<UpdateAppError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_app_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_app_request(&self, input: UpdateAppRequest) -> SignedRequest {
         let request_uri = format!("/apps/{app_id}", app_id = input.app_id);
 
         let mut request = SignedRequest::new("POST", "amplify", &self.region, &request_uri);
+        request.set_operation("UpdateApp");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `update_branch`, also returning the metadata of the response.
+    pub async fn update_branch_with_metadata(
+        &self,
+        input: UpdateBranchRequest,
+    ) -> Result<(UpdateBranchResult, ResponseMetadata), RusotoError<UpdateBranchError>> {
+        let request = self.build_update_branch_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            let result =
-                proto::json::ResponsePayload::new(&response).deserialize::<UpdateAppResult, _>()?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<UpdateBranchResult, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(UpdateAppError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(UpdateBranchError::from_response(response))
         }
     }
 
-    /// <p> Updates a branch for an Amplify App. </p>
-    async fn update_branch(
+    /// Presigns the request `update_branch` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_branch(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UpdateBranchRequest,
-    ) -> Result<UpdateBranchResult, RusotoError<UpdateBranchError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_branch_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_branch_request(&self, input: UpdateBranchRequest) -> SignedRequest {
         let request_uri = format!(
             "/apps/{app_id}/branches/{branch_name}",
             app_id = input.app_id,
@@ -4920,33 +6113,63 @@ impl Amplify for AmplifyClient {
         );
 
         let mut request = SignedRequest::new("POST", "amplify", &self.region, &request_uri);
+        request.set_operation("UpdateBranch");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `update_domain_association`, also returning the metadata of the response.
+    pub async fn update_domain_association_with_metadata(
+        &self,
+        input: UpdateDomainAssociationRequest,
+    ) -> Result<
+        (UpdateDomainAssociationResult, ResponseMetadata),
+        RusotoError<UpdateDomainAssociationError>,
+    > {
+        let request = self.build_update_domain_association_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<UpdateBranchResult, _>()?;
+                .deserialize::<UpdateDomainAssociationResult, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(UpdateBranchError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(UpdateDomainAssociationError::from_response(response))
         }
     }
 
-    /// <p> Create a new DomainAssociation on an App </p>
-    async fn update_domain_association(
+    /// Presigns the request `update_domain_association` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_domain_association(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UpdateDomainAssociationRequest,
-    ) -> Result<UpdateDomainAssociationResult, RusotoError<UpdateDomainAssociationError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_domain_association_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_domain_association_request(
+        &self,
+        input: UpdateDomainAssociationRequest,
+    ) -> SignedRequest {
         let request_uri = format!(
             "/apps/{app_id}/domains/{domain_name}",
             app_id = input.app_id,
@@ -4954,55 +6177,576 @@ impl Amplify for AmplifyClient {
         );
 
         let mut request = SignedRequest::new("POST", "amplify", &self.region, &request_uri);
+        request.set_operation("UpdateDomainAssociation");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `update_webhook`, also returning the metadata of the response.
+    pub async fn update_webhook_with_metadata(
+        &self,
+        input: UpdateWebhookRequest,
+    ) -> Result<(UpdateWebhookResult, ResponseMetadata), RusotoError<UpdateWebhookError>> {
+        let request = self.build_update_webhook_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<UpdateDomainAssociationResult, _>()?;
+                .deserialize::<UpdateWebhookResult, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(UpdateDomainAssociationError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(UpdateWebhookError::from_response(response))
         }
     }
 
-    /// <p> Update a webhook. </p>
-    async fn update_webhook(
+    /// Presigns the request `update_webhook` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_webhook(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UpdateWebhookRequest,
-    ) -> Result<UpdateWebhookResult, RusotoError<UpdateWebhookError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_webhook_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_webhook_request(&self, input: UpdateWebhookRequest) -> SignedRequest {
         let request_uri = format!("/webhooks/{webhook_id}", webhook_id = input.webhook_id);
 
         let mut request = SignedRequest::new("POST", "amplify", &self.region, &request_uri);
+        request.set_operation("UpdateWebhook");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
-        let mut response = self
-            .client
-            .sign_and_dispatch(request)
+        request
+    }
+
+    /// The pages of `ListApps` results for `input`, requesting each next page with the `nextToken` of the last one as its `nextToken`.
+    /// The page size is the `maxResults` of `input`.
+    pub fn list_apps_pages(
+        &self,
+        input: ListAppsRequest,
+    ) -> impl futures::Stream<Item = Result<ListAppsResult, RusotoError<ListAppsError>>> + Send
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: AmplifyClient, input| async move { client.list_apps(input).await },
+            |input: &ListAppsRequest, output: &ListAppsResult| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListAppsRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `apps` of every page of `ListApps` results for `input`, as `list_apps_pages` requests them.
+    pub fn list_apps_stream(
+        &self,
+        input: ListAppsRequest,
+    ) -> impl futures::Stream<Item = Result<App, RusotoError<ListAppsError>>> + Send {
+        rusoto_core::pagination::items(self.list_apps_pages(input), |page| page.apps)
+    }
+
+    /// The pages of `ListBranches` results for `input`, requesting each next page with the `nextToken` of the last one as its `nextToken`.
+    /// The page size is the `maxResults` of `input`.
+    pub fn list_branches_pages(
+        &self,
+        input: ListBranchesRequest,
+    ) -> impl futures::Stream<Item = Result<ListBranchesResult, RusotoError<ListBranchesError>>> + Send
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: AmplifyClient, input| async move { client.list_branches(input).await },
+            |input: &ListBranchesRequest, output: &ListBranchesResult| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListBranchesRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `branches` of every page of `ListBranches` results for `input`, as `list_branches_pages` requests them.
+    pub fn list_branches_stream(
+        &self,
+        input: ListBranchesRequest,
+    ) -> impl futures::Stream<Item = Result<Branch, RusotoError<ListBranchesError>>> + Send {
+        rusoto_core::pagination::items(self.list_branches_pages(input), |page| page.branches)
+    }
+
+    /// The pages of `ListDomainAssociations` results for `input`, requesting each next page with the `nextToken` of the last one as its `nextToken`.
+    /// The page size is the `maxResults` of `input`.
+    pub fn list_domain_associations_pages(
+        &self,
+        input: ListDomainAssociationsRequest,
+    ) -> impl futures::Stream<
+        Item = Result<ListDomainAssociationsResult, RusotoError<ListDomainAssociationsError>>,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: AmplifyClient, input| async move { client.list_domain_associations(input).await },
+            |input: &ListDomainAssociationsRequest, output: &ListDomainAssociationsResult| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListDomainAssociationsRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `domainAssociations` of every page of `ListDomainAssociations` results for `input`, as `list_domain_associations_pages` requests them.
+    pub fn list_domain_associations_stream(
+        &self,
+        input: ListDomainAssociationsRequest,
+    ) -> impl futures::Stream<
+        Item = Result<DomainAssociation, RusotoError<ListDomainAssociationsError>>,
+    > + Send {
+        rusoto_core::pagination::items(self.list_domain_associations_pages(input), |page| {
+            page.domain_associations
+        })
+    }
+
+    /// The pages of `ListJobs` results for `input`, requesting each next page with the `nextToken` of the last one as its `nextToken`.
+    /// The page size is the `maxResults` of `input`.
+    pub fn list_jobs_pages(
+        &self,
+        input: ListJobsRequest,
+    ) -> impl futures::Stream<Item = Result<ListJobsResult, RusotoError<ListJobsError>>> + Send
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: AmplifyClient, input| async move { client.list_jobs(input).await },
+            |input: &ListJobsRequest, output: &ListJobsResult| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListJobsRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `jobSummaries` of every page of `ListJobs` results for `input`, as `list_jobs_pages` requests them.
+    pub fn list_jobs_stream(
+        &self,
+        input: ListJobsRequest,
+    ) -> impl futures::Stream<Item = Result<JobSummary, RusotoError<ListJobsError>>> + Send {
+        rusoto_core::pagination::items(self.list_jobs_pages(input), |page| page.job_summaries)
+    }
+}
+
+#[async_trait]
+impl Amplify for AmplifyClient {
+    /// <p> Creates a new Amplify App. </p>
+    async fn create_app(
+        &self,
+        input: CreateAppRequest,
+    ) -> Result<CreateAppResult, RusotoError<CreateAppError>> {
+        self.create_app_with_metadata(input)
             .await
-            .map_err(RusotoError::from)?;
-        if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<UpdateWebhookResult, _>()?;
+            .map(|(output, _)| output)
+    }
 
-            Ok(result)
-        } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(UpdateWebhookError::from_response(response))
-        }
+    /// <p> Creates a new backend environment for an Amplify App. </p>
+    async fn create_backend_environment(
+        &self,
+        input: CreateBackendEnvironmentRequest,
+    ) -> Result<CreateBackendEnvironmentResult, RusotoError<CreateBackendEnvironmentError>> {
+        self.create_backend_environment_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p> Creates a new Branch for an Amplify App. </p>
+    async fn create_branch(
+        &self,
+        input: CreateBranchRequest,
+    ) -> Result<CreateBranchResult, RusotoError<CreateBranchError>> {
+        self.create_branch_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p> Create a deployment for manual deploy apps. (Apps are not connected to repository) </p>
+    async fn create_deployment(
+        &self,
+        input: CreateDeploymentRequest,
+    ) -> Result<CreateDeploymentResult, RusotoError<CreateDeploymentError>> {
+        self.create_deployment_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p> Create a new DomainAssociation on an App </p>
+    async fn create_domain_association(
+        &self,
+        input: CreateDomainAssociationRequest,
+    ) -> Result<CreateDomainAssociationResult, RusotoError<CreateDomainAssociationError>> {
+        self.create_domain_association_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p> Create a new webhook on an App. </p>
+    async fn create_webhook(
+        &self,
+        input: CreateWebhookRequest,
+    ) -> Result<CreateWebhookResult, RusotoError<CreateWebhookError>> {
+        self.create_webhook_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p> Delete an existing Amplify App by appId. </p>
+    async fn delete_app(
+        &self,
+        input: DeleteAppRequest,
+    ) -> Result<DeleteAppResult, RusotoError<DeleteAppError>> {
+        self.delete_app_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p> Delete backend environment for an Amplify App. </p>
+    async fn delete_backend_environment(
+        &self,
+        input: DeleteBackendEnvironmentRequest,
+    ) -> Result<DeleteBackendEnvironmentResult, RusotoError<DeleteBackendEnvironmentError>> {
+        self.delete_backend_environment_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p> Deletes a branch for an Amplify App. </p>
+    async fn delete_branch(
+        &self,
+        input: DeleteBranchRequest,
+    ) -> Result<DeleteBranchResult, RusotoError<DeleteBranchError>> {
+        self.delete_branch_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p> Deletes a DomainAssociation. </p>
+    async fn delete_domain_association(
+        &self,
+        input: DeleteDomainAssociationRequest,
+    ) -> Result<DeleteDomainAssociationResult, RusotoError<DeleteDomainAssociationError>> {
+        self.delete_domain_association_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p> Delete a job, for an Amplify branch, part of Amplify App. </p>
+    async fn delete_job(
+        &self,
+        input: DeleteJobRequest,
+    ) -> Result<DeleteJobResult, RusotoError<DeleteJobError>> {
+        self.delete_job_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p> Deletes a webhook. </p>
+    async fn delete_webhook(
+        &self,
+        input: DeleteWebhookRequest,
+    ) -> Result<DeleteWebhookResult, RusotoError<DeleteWebhookError>> {
+        self.delete_webhook_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p> Retrieve website access logs for a specific time range via a pre-signed URL. </p>
+    async fn generate_access_logs(
+        &self,
+        input: GenerateAccessLogsRequest,
+    ) -> Result<GenerateAccessLogsResult, RusotoError<GenerateAccessLogsError>> {
+        self.generate_access_logs_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p> Retrieves an existing Amplify App by appId. </p>
+    async fn get_app(
+        &self,
+        input: GetAppRequest,
+    ) -> Result<GetAppResult, RusotoError<GetAppError>> {
+        self.get_app_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p> Retrieves artifact info that corresponds to a artifactId. </p>
+    async fn get_artifact_url(
+        &self,
+        input: GetArtifactUrlRequest,
+    ) -> Result<GetArtifactUrlResult, RusotoError<GetArtifactUrlError>> {
+        self.get_artifact_url_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p> Retrieves a backend environment for an Amplify App. </p>
+    async fn get_backend_environment(
+        &self,
+        input: GetBackendEnvironmentRequest,
+    ) -> Result<GetBackendEnvironmentResult, RusotoError<GetBackendEnvironmentError>> {
+        self.get_backend_environment_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p> Retrieves a branch for an Amplify App. </p>
+    async fn get_branch(
+        &self,
+        input: GetBranchRequest,
+    ) -> Result<GetBranchResult, RusotoError<GetBranchError>> {
+        self.get_branch_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p> Retrieves domain info that corresponds to an appId and domainName. </p>
+    async fn get_domain_association(
+        &self,
+        input: GetDomainAssociationRequest,
+    ) -> Result<GetDomainAssociationResult, RusotoError<GetDomainAssociationError>> {
+        self.get_domain_association_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p> Get a job for a branch, part of an Amplify App. </p>
+    async fn get_job(
+        &self,
+        input: GetJobRequest,
+    ) -> Result<GetJobResult, RusotoError<GetJobError>> {
+        self.get_job_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p> Retrieves webhook info that corresponds to a webhookId. </p>
+    async fn get_webhook(
+        &self,
+        input: GetWebhookRequest,
+    ) -> Result<GetWebhookResult, RusotoError<GetWebhookError>> {
+        self.get_webhook_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p> Lists existing Amplify Apps. </p>
+    async fn list_apps(
+        &self,
+        input: ListAppsRequest,
+    ) -> Result<ListAppsResult, RusotoError<ListAppsError>> {
+        self.list_apps_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p> List artifacts with an app, a branch, a job and an artifact type. </p>
+    async fn list_artifacts(
+        &self,
+        input: ListArtifactsRequest,
+    ) -> Result<ListArtifactsResult, RusotoError<ListArtifactsError>> {
+        self.list_artifacts_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p> Lists backend environments for an Amplify App. </p>
+    async fn list_backend_environments(
+        &self,
+        input: ListBackendEnvironmentsRequest,
+    ) -> Result<ListBackendEnvironmentsResult, RusotoError<ListBackendEnvironmentsError>> {
+        self.list_backend_environments_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p> Lists branches for an Amplify App. </p>
+    async fn list_branches(
+        &self,
+        input: ListBranchesRequest,
+    ) -> Result<ListBranchesResult, RusotoError<ListBranchesError>> {
+        self.list_branches_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p> List domains with an app </p>
+    async fn list_domain_associations(
+        &self,
+        input: ListDomainAssociationsRequest,
+    ) -> Result<ListDomainAssociationsResult, RusotoError<ListDomainAssociationsError>> {
+        self.list_domain_associations_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p> List Jobs for a branch, part of an Amplify App. </p>
+    async fn list_jobs(
+        &self,
+        input: ListJobsRequest,
+    ) -> Result<ListJobsResult, RusotoError<ListJobsError>> {
+        self.list_jobs_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p> List tags for resource. </p>
+    async fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> Result<ListTagsForResourceResponse, RusotoError<ListTagsForResourceError>> {
+        self.list_tags_for_resource_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p> List webhooks with an app. </p>
+    async fn list_webhooks(
+        &self,
+        input: ListWebhooksRequest,
+    ) -> Result<ListWebhooksResult, RusotoError<ListWebhooksError>> {
+        self.list_webhooks_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p> Start a deployment for manual deploy apps. (Apps are not connected to repository) </p>
+    async fn start_deployment(
+        &self,
+        input: StartDeploymentRequest,
+    ) -> Result<StartDeploymentResult, RusotoError<StartDeploymentError>> {
+        self.start_deployment_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p> Starts a new job for a branch, part of an Amplify App. </p>
+    async fn start_job(
+        &self,
+        input: StartJobRequest,
+    ) -> Result<StartJobResult, RusotoError<StartJobError>> {
+        self.start_job_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p> Stop a job that is in progress, for an Amplify branch, part of Amplify App. </p>
+    async fn stop_job(
+        &self,
+        input: StopJobRequest,
+    ) -> Result<StopJobResult, RusotoError<StopJobError>> {
+        self.stop_job_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p> Tag resource with tag key and value. </p>
+    async fn tag_resource(
+        &self,
+        input: TagResourceRequest,
+    ) -> Result<TagResourceResponse, RusotoError<TagResourceError>> {
+        self.tag_resource_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p> Untag resource with resourceArn. </p>
+    async fn untag_resource(
+        &self,
+        input: UntagResourceRequest,
+    ) -> Result<UntagResourceResponse, RusotoError<UntagResourceError>> {
+        self.untag_resource_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p> Updates an existing Amplify App. </p>
+    async fn update_app(
+        &self,
+        input: UpdateAppRequest,
+    ) -> Result<UpdateAppResult, RusotoError<UpdateAppError>> {
+        self.update_app_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p> Updates a branch for an Amplify App. </p>
+    async fn update_branch(
+        &self,
+        input: UpdateBranchRequest,
+    ) -> Result<UpdateBranchResult, RusotoError<UpdateBranchError>> {
+        self.update_branch_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p> Create a new DomainAssociation on an App </p>
+    async fn update_domain_association(
+        &self,
+        input: UpdateDomainAssociationRequest,
+    ) -> Result<UpdateDomainAssociationResult, RusotoError<UpdateDomainAssociationError>> {
+        self.update_domain_association_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p> Update a webhook. </p>
+    async fn update_webhook(
+        &self,
+        input: UpdateWebhookRequest,
+    ) -> Result<UpdateWebhookResult, RusotoError<UpdateWebhookError>> {
+        self.update_webhook_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
     }
 }
@@ -16,8 +16,8 @@ use std::fmt;
 use async_trait::async_trait;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
-use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest, ResponseMetadata};
+use rusoto_core::{Client, ErrorMetadata, RusotoError};
 
 use rusoto_core::param::{Params, ServiceParams};
 use rusoto_core::proto;
@@ -3637,6 +3637,7 @@ pub struct VpcLinks {
 
 /// Errors returned by CreateApiKey
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateApiKeyError {
     /// <p>The submitted request is not valid, for example, the input is incomplete or incorrect. See the accompanying error message for details.</p>
     BadRequest(String),
@@ -3650,6 +3651,8 @@ pub enum CreateApiKeyError {
     TooManyRequests(String),
     /// <p>The request is denied because the caller has insufficient permissions.</p>
     Unauthorized(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateApiKeyError {
@@ -3657,25 +3660,25 @@ impl CreateApiKeyError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(CreateApiKeyError::BadRequest(err.msg))
+                    return RusotoError::service(CreateApiKeyError::BadRequest(err.msg), &res)
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(CreateApiKeyError::Conflict(err.msg))
+                    return RusotoError::service(CreateApiKeyError::Conflict(err.msg), &res)
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateApiKeyError::LimitExceeded(err.msg))
+                    return RusotoError::service(CreateApiKeyError::LimitExceeded(err.msg), &res)
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(CreateApiKeyError::NotFound(err.msg))
+                    return RusotoError::service(CreateApiKeyError::NotFound(err.msg), &res)
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(CreateApiKeyError::TooManyRequests(err.msg))
+                    return RusotoError::service(CreateApiKeyError::TooManyRequests(err.msg), &res)
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(CreateApiKeyError::Unauthorized(err.msg))
+                    return RusotoError::service(CreateApiKeyError::Unauthorized(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreateApiKeyError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3691,12 +3694,14 @@ impl fmt::Display for CreateApiKeyError {
             CreateApiKeyError::NotFound(ref cause) => write!(f, "{}", cause),
             CreateApiKeyError::TooManyRequests(ref cause) => write!(f, "{}", cause),
             CreateApiKeyError::Unauthorized(ref cause) => write!(f, "{}", cause),
+            CreateApiKeyError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateApiKeyError {}
 /// Errors returned by CreateAuthorizer
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateAuthorizerError {
     /// <p>The submitted request is not valid, for example, the input is incomplete or incorrect. See the accompanying error message for details.</p>
     BadRequest(String),
@@ -3708,6 +3713,8 @@ pub enum CreateAuthorizerError {
     TooManyRequests(String),
     /// <p>The request is denied because the caller has insufficient permissions.</p>
     Unauthorized(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateAuthorizerError {
@@ -3715,22 +3722,28 @@ impl CreateAuthorizerError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(CreateAuthorizerError::BadRequest(err.msg))
+                    return RusotoError::service(CreateAuthorizerError::BadRequest(err.msg), &res)
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateAuthorizerError::LimitExceeded(err.msg))
+                    return RusotoError::service(
+                        CreateAuthorizerError::LimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(CreateAuthorizerError::NotFound(err.msg))
+                    return RusotoError::service(CreateAuthorizerError::NotFound(err.msg), &res)
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(CreateAuthorizerError::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        CreateAuthorizerError::TooManyRequests(err.msg),
+                        &res,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(CreateAuthorizerError::Unauthorized(err.msg))
+                    return RusotoError::service(CreateAuthorizerError::Unauthorized(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreateAuthorizerError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3745,12 +3758,14 @@ impl fmt::Display for CreateAuthorizerError {
             CreateAuthorizerError::NotFound(ref cause) => write!(f, "{}", cause),
             CreateAuthorizerError::TooManyRequests(ref cause) => write!(f, "{}", cause),
             CreateAuthorizerError::Unauthorized(ref cause) => write!(f, "{}", cause),
+            CreateAuthorizerError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateAuthorizerError {}
 /// Errors returned by CreateBasePathMapping
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateBasePathMappingError {
     /// <p>The submitted request is not valid, for example, the input is incomplete or incorrect. See the accompanying error message for details.</p>
     BadRequest(String),
@@ -3762,6 +3777,8 @@ pub enum CreateBasePathMappingError {
     TooManyRequests(String),
     /// <p>The request is denied because the caller has insufficient permissions.</p>
     Unauthorized(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateBasePathMappingError {
@@ -3769,24 +3786,37 @@ impl CreateBasePathMappingError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(CreateBasePathMappingError::BadRequest(err.msg))
+                    return RusotoError::service(
+                        CreateBasePathMappingError::BadRequest(err.msg),
+                        &res,
+                    )
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(CreateBasePathMappingError::Conflict(err.msg))
+                    return RusotoError::service(
+                        CreateBasePathMappingError::Conflict(err.msg),
+                        &res,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(CreateBasePathMappingError::NotFound(err.msg))
+                    return RusotoError::service(
+                        CreateBasePathMappingError::NotFound(err.msg),
+                        &res,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(CreateBasePathMappingError::TooManyRequests(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateBasePathMappingError::TooManyRequests(err.msg),
+                        &res,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(CreateBasePathMappingError::Unauthorized(err.msg))
+                    return RusotoError::service(
+                        CreateBasePathMappingError::Unauthorized(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreateBasePathMappingError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3801,12 +3831,14 @@ impl fmt::Display for CreateBasePathMappingError {
             CreateBasePathMappingError::NotFound(ref cause) => write!(f, "{}", cause),
             CreateBasePathMappingError::TooManyRequests(ref cause) => write!(f, "{}", cause),
             CreateBasePathMappingError::Unauthorized(ref cause) => write!(f, "{}", cause),
+            CreateBasePathMappingError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateBasePathMappingError {}
 /// Errors returned by CreateDeployment
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateDeploymentError {
     /// <p>The submitted request is not valid, for example, the input is incomplete or incorrect. See the accompanying error message for details.</p>
     BadRequest(String),
@@ -3822,6 +3854,8 @@ pub enum CreateDeploymentError {
     TooManyRequests(String),
     /// <p>The request is denied because the caller has insufficient permissions.</p>
     Unauthorized(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateDeploymentError {
@@ -3829,28 +3863,37 @@ impl CreateDeploymentError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(CreateDeploymentError::BadRequest(err.msg))
+                    return RusotoError::service(CreateDeploymentError::BadRequest(err.msg), &res)
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(CreateDeploymentError::Conflict(err.msg))
+                    return RusotoError::service(CreateDeploymentError::Conflict(err.msg), &res)
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateDeploymentError::LimitExceeded(err.msg))
+                    return RusotoError::service(
+                        CreateDeploymentError::LimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(CreateDeploymentError::NotFound(err.msg))
+                    return RusotoError::service(CreateDeploymentError::NotFound(err.msg), &res)
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(CreateDeploymentError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        CreateDeploymentError::ServiceUnavailable(err.msg),
+                        &res,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(CreateDeploymentError::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        CreateDeploymentError::TooManyRequests(err.msg),
+                        &res,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(CreateDeploymentError::Unauthorized(err.msg))
+                    return RusotoError::service(CreateDeploymentError::Unauthorized(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreateDeploymentError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3867,12 +3910,14 @@ impl fmt::Display for CreateDeploymentError {
             CreateDeploymentError::ServiceUnavailable(ref cause) => write!(f, "{}", cause),
             CreateDeploymentError::TooManyRequests(ref cause) => write!(f, "{}", cause),
             CreateDeploymentError::Unauthorized(ref cause) => write!(f, "{}", cause),
+            CreateDeploymentError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateDeploymentError {}
 /// Errors returned by CreateDocumentationPart
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateDocumentationPartError {
     /// <p>The submitted request is not valid, for example, the input is incomplete or incorrect. See the accompanying error message for details.</p>
     BadRequest(String),
@@ -3886,6 +3931,8 @@ pub enum CreateDocumentationPartError {
     TooManyRequests(String),
     /// <p>The request is denied because the caller has insufficient permissions.</p>
     Unauthorized(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateDocumentationPartError {
@@ -3893,31 +3940,43 @@ impl CreateDocumentationPartError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(CreateDocumentationPartError::BadRequest(err.msg))
+                    return RusotoError::service(
+                        CreateDocumentationPartError::BadRequest(err.msg),
+                        &res,
+                    )
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(CreateDocumentationPartError::Conflict(err.msg))
+                    return RusotoError::service(
+                        CreateDocumentationPartError::Conflict(err.msg),
+                        &res,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateDocumentationPartError::LimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateDocumentationPartError::LimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(CreateDocumentationPartError::NotFound(err.msg))
+                    return RusotoError::service(
+                        CreateDocumentationPartError::NotFound(err.msg),
+                        &res,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(CreateDocumentationPartError::TooManyRequests(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateDocumentationPartError::TooManyRequests(err.msg),
+                        &res,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(CreateDocumentationPartError::Unauthorized(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateDocumentationPartError::Unauthorized(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreateDocumentationPartError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3933,12 +3992,14 @@ impl fmt::Display for CreateDocumentationPartError {
             CreateDocumentationPartError::NotFound(ref cause) => write!(f, "{}", cause),
             CreateDocumentationPartError::TooManyRequests(ref cause) => write!(f, "{}", cause),
             CreateDocumentationPartError::Unauthorized(ref cause) => write!(f, "{}", cause),
+            CreateDocumentationPartError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateDocumentationPartError {}
 /// Errors returned by CreateDocumentationVersion
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateDocumentationVersionError {
     /// <p>The submitted request is not valid, for example, the input is incomplete or incorrect. See the accompanying error message for details.</p>
     BadRequest(String),
@@ -3952,6 +4013,8 @@ pub enum CreateDocumentationVersionError {
     TooManyRequests(String),
     /// <p>The request is denied because the caller has insufficient permissions.</p>
     Unauthorized(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateDocumentationVersionError {
@@ -3961,33 +4024,45 @@ impl CreateDocumentationVersionError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(CreateDocumentationVersionError::BadRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateDocumentationVersionError::BadRequest(err.msg),
+                        &res,
+                    )
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(CreateDocumentationVersionError::Conflict(err.msg))
+                    return RusotoError::service(
+                        CreateDocumentationVersionError::Conflict(err.msg),
+                        &res,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateDocumentationVersionError::LimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateDocumentationVersionError::LimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(CreateDocumentationVersionError::NotFound(err.msg))
+                    return RusotoError::service(
+                        CreateDocumentationVersionError::NotFound(err.msg),
+                        &res,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(CreateDocumentationVersionError::TooManyRequests(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateDocumentationVersionError::TooManyRequests(err.msg),
+                        &res,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(CreateDocumentationVersionError::Unauthorized(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateDocumentationVersionError::Unauthorized(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(CreateDocumentationVersionError::Unhandled, &res)
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -4003,12 +4078,14 @@ impl fmt::Display for CreateDocumentationVersionError {
             CreateDocumentationVersionError::NotFound(ref cause) => write!(f, "{}", cause),
             CreateDocumentationVersionError::TooManyRequests(ref cause) => write!(f, "{}", cause),
             CreateDocumentationVersionError::Unauthorized(ref cause) => write!(f, "{}", cause),
+            CreateDocumentationVersionError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateDocumentationVersionError {}
 /// Errors returned by CreateDomainName
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateDomainNameError {
     /// <p>The submitted request is not valid, for example, the input is incomplete or incorrect. See the accompanying error message for details.</p>
     BadRequest(String),
@@ -4018,6 +4095,8 @@ pub enum CreateDomainNameError {
     TooManyRequests(String),
     /// <p>The request is denied because the caller has insufficient permissions.</p>
     Unauthorized(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateDomainNameError {
@@ -4025,19 +4104,22 @@ impl CreateDomainNameError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(CreateDomainNameError::BadRequest(err.msg))
+                    return RusotoError::service(CreateDomainNameError::BadRequest(err.msg), &res)
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(CreateDomainNameError::Conflict(err.msg))
+                    return RusotoError::service(CreateDomainNameError::Conflict(err.msg), &res)
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(CreateDomainNameError::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        CreateDomainNameError::TooManyRequests(err.msg),
+                        &res,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(CreateDomainNameError::Unauthorized(err.msg))
+                    return RusotoError::service(CreateDomainNameError::Unauthorized(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreateDomainNameError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -4051,12 +4133,14 @@ impl fmt::Display for CreateDomainNameError {
             CreateDomainNameError::Conflict(ref cause) => write!(f, "{}", cause),
             CreateDomainNameError::TooManyRequests(ref cause) => write!(f, "{}", cause),
             CreateDomainNameError::Unauthorized(ref cause) => write!(f, "{}", cause),
+            CreateDomainNameError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateDomainNameError {}
 /// Errors returned by CreateModel
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateModelError {
     /// <p>The submitted request is not valid, for example, the input is incomplete or incorrect. See the accompanying error message for details.</p>
     BadRequest(String),
@@ -4070,6 +4154,8 @@ pub enum CreateModelError {
     TooManyRequests(String),
     /// <p>The request is denied because the caller has insufficient permissions.</p>
     Unauthorized(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateModelError {
@@ -4077,25 +4163,25 @@ impl CreateModelError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(CreateModelError::BadRequest(err.msg))
+                    return RusotoError::service(CreateModelError::BadRequest(err.msg), &res)
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(CreateModelError::Conflict(err.msg))
+                    return RusotoError::service(CreateModelError::Conflict(err.msg), &res)
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateModelError::LimitExceeded(err.msg))
+                    return RusotoError::service(CreateModelError::LimitExceeded(err.msg), &res)
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(CreateModelError::NotFound(err.msg))
+                    return RusotoError::service(CreateModelError::NotFound(err.msg), &res)
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(CreateModelError::TooManyRequests(err.msg))
+                    return RusotoError::service(CreateModelError::TooManyRequests(err.msg), &res)
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(CreateModelError::Unauthorized(err.msg))
+                    return RusotoError::service(CreateModelError::Unauthorized(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreateModelError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -4111,12 +4197,14 @@ impl fmt::Display for CreateModelError {
             CreateModelError::NotFound(ref cause) => write!(f, "{}", cause),
             CreateModelError::TooManyRequests(ref cause) => write!(f, "{}", cause),
             CreateModelError::Unauthorized(ref cause) => write!(f, "{}", cause),
+            CreateModelError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateModelError {}
 /// Errors returned by CreateRequestValidator
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateRequestValidatorError {
     /// <p>The submitted request is not valid, for example, the input is incomplete or incorrect. See the accompanying error message for details.</p>
     BadRequest(String),
@@ -4128,6 +4216,8 @@ pub enum CreateRequestValidatorError {
     TooManyRequests(String),
     /// <p>The request is denied because the caller has insufficient permissions.</p>
     Unauthorized(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateRequestValidatorError {
@@ -4135,26 +4225,37 @@ impl CreateRequestValidatorError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(CreateRequestValidatorError::BadRequest(err.msg))
+                    return RusotoError::service(
+                        CreateRequestValidatorError::BadRequest(err.msg),
+                        &res,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateRequestValidatorError::LimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateRequestValidatorError::LimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(CreateRequestValidatorError::NotFound(err.msg))
+                    return RusotoError::service(
+                        CreateRequestValidatorError::NotFound(err.msg),
+                        &res,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(CreateRequestValidatorError::TooManyRequests(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateRequestValidatorError::TooManyRequests(err.msg),
+                        &res,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(CreateRequestValidatorError::Unauthorized(err.msg))
+                    return RusotoError::service(
+                        CreateRequestValidatorError::Unauthorized(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreateRequestValidatorError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -4169,12 +4270,14 @@ impl fmt::Display for CreateRequestValidatorError {
             CreateRequestValidatorError::NotFound(ref cause) => write!(f, "{}", cause),
             CreateRequestValidatorError::TooManyRequests(ref cause) => write!(f, "{}", cause),
             CreateRequestValidatorError::Unauthorized(ref cause) => write!(f, "{}", cause),
+            CreateRequestValidatorError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateRequestValidatorError {}
 /// Errors returned by CreateResource
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateResourceError {
     /// <p>The submitted request is not valid, for example, the input is incomplete or incorrect. See the accompanying error message for details.</p>
     BadRequest(String),
@@ -4188,6 +4291,8 @@ pub enum CreateResourceError {
     TooManyRequests(String),
     /// <p>The request is denied because the caller has insufficient permissions.</p>
     Unauthorized(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateResourceError {
@@ -4195,25 +4300,28 @@ impl CreateResourceError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(CreateResourceError::BadRequest(err.msg))
+                    return RusotoError::service(CreateResourceError::BadRequest(err.msg), &res)
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(CreateResourceError::Conflict(err.msg))
+                    return RusotoError::service(CreateResourceError::Conflict(err.msg), &res)
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateResourceError::LimitExceeded(err.msg))
+                    return RusotoError::service(CreateResourceError::LimitExceeded(err.msg), &res)
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(CreateResourceError::NotFound(err.msg))
+                    return RusotoError::service(CreateResourceError::NotFound(err.msg), &res)
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(CreateResourceError::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        CreateResourceError::TooManyRequests(err.msg),
+                        &res,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(CreateResourceError::Unauthorized(err.msg))
+                    return RusotoError::service(CreateResourceError::Unauthorized(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreateResourceError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -4229,12 +4337,14 @@ impl fmt::Display for CreateResourceError {
             CreateResourceError::NotFound(ref cause) => write!(f, "{}", cause),
             CreateResourceError::TooManyRequests(ref cause) => write!(f, "{}", cause),
             CreateResourceError::Unauthorized(ref cause) => write!(f, "{}", cause),
+            CreateResourceError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateResourceError {}
 /// Errors returned by CreateRestApi
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateRestApiError {
     /// <p>The submitted request is not valid, for example, the input is incomplete or incorrect. See the accompanying error message for details.</p>
     BadRequest(String),
@@ -4244,6 +4354,8 @@ pub enum CreateRestApiError {
     TooManyRequests(String),
     /// <p>The request is denied because the caller has insufficient permissions.</p>
     Unauthorized(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateRestApiError {
@@ -4251,19 +4363,19 @@ impl CreateRestApiError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(CreateRestApiError::BadRequest(err.msg))
+                    return RusotoError::service(CreateRestApiError::BadRequest(err.msg), &res)
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateRestApiError::LimitExceeded(err.msg))
+                    return RusotoError::service(CreateRestApiError::LimitExceeded(err.msg), &res)
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(CreateRestApiError::TooManyRequests(err.msg))
+                    return RusotoError::service(CreateRestApiError::TooManyRequests(err.msg), &res)
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(CreateRestApiError::Unauthorized(err.msg))
+                    return RusotoError::service(CreateRestApiError::Unauthorized(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreateRestApiError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -4277,12 +4389,14 @@ impl fmt::Display for CreateRestApiError {
             CreateRestApiError::LimitExceeded(ref cause) => write!(f, "{}", cause),
             CreateRestApiError::TooManyRequests(ref cause) => write!(f, "{}", cause),
             CreateRestApiError::Unauthorized(ref cause) => write!(f, "{}", cause),
+            CreateRestApiError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateRestApiError {}
 /// Errors returned by CreateStage
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateStageError {
     /// <p>The submitted request is not valid, for example, the input is incomplete or incorrect. See the accompanying error message for details.</p>
     BadRequest(String),
@@ -4296,6 +4410,8 @@ pub enum CreateStageError {
     TooManyRequests(String),
     /// <p>The request is denied because the caller has insufficient permissions.</p>
     Unauthorized(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateStageError {
@@ -4303,25 +4419,25 @@ impl CreateStageError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(CreateStageError::BadRequest(err.msg))
+                    return RusotoError::service(CreateStageError::BadRequest(err.msg), &res)
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(CreateStageError::Conflict(err.msg))
+                    return RusotoError::service(CreateStageError::Conflict(err.msg), &res)
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateStageError::LimitExceeded(err.msg))
+                    return RusotoError::service(CreateStageError::LimitExceeded(err.msg), &res)
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(CreateStageError::NotFound(err.msg))
+                    return RusotoError::service(CreateStageError::NotFound(err.msg), &res)
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(CreateStageError::TooManyRequests(err.msg))
+                    return RusotoError::service(CreateStageError::TooManyRequests(err.msg), &res)
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(CreateStageError::Unauthorized(err.msg))
+                    return RusotoError::service(CreateStageError::Unauthorized(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreateStageError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -4337,12 +4453,14 @@ impl fmt::Display for CreateStageError {
             CreateStageError::NotFound(ref cause) => write!(f, "{}", cause),
             CreateStageError::TooManyRequests(ref cause) => write!(f, "{}", cause),
             CreateStageError::Unauthorized(ref cause) => write!(f, "{}", cause),
+            CreateStageError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateStageError {}
 /// Errors returned by CreateUsagePlan
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateUsagePlanError {
     /// <p>The submitted request is not valid, for example, the input is incomplete or incorrect. See the accompanying error message for details.</p>
     BadRequest(String),
@@ -4356,6 +4474,8 @@ pub enum CreateUsagePlanError {
     TooManyRequests(String),
     /// <p>The request is denied because the caller has insufficient permissions.</p>
     Unauthorized(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateUsagePlanError {
@@ -4363,25 +4483,28 @@ impl CreateUsagePlanError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(CreateUsagePlanError::BadRequest(err.msg))
+                    return RusotoError::service(CreateUsagePlanError::BadRequest(err.msg), &res)
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(CreateUsagePlanError::Conflict(err.msg))
+                    return RusotoError::service(CreateUsagePlanError::Conflict(err.msg), &res)
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateUsagePlanError::LimitExceeded(err.msg))
+                    return RusotoError::service(CreateUsagePlanError::LimitExceeded(err.msg), &res)
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(CreateUsagePlanError::NotFound(err.msg))
+                    return RusotoError::service(CreateUsagePlanError::NotFound(err.msg), &res)
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(CreateUsagePlanError::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        CreateUsagePlanError::TooManyRequests(err.msg),
+                        &res,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(CreateUsagePlanError::Unauthorized(err.msg))
+                    return RusotoError::service(CreateUsagePlanError::Unauthorized(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreateUsagePlanError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -4397,12 +4520,14 @@ impl fmt::Display for CreateUsagePlanError {
             CreateUsagePlanError::NotFound(ref cause) => write!(f, "{}", cause),
             CreateUsagePlanError::TooManyRequests(ref cause) => write!(f, "{}", cause),
             CreateUsagePlanError::Unauthorized(ref cause) => write!(f, "{}", cause),
+            CreateUsagePlanError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateUsagePlanError {}
 /// Errors returned by CreateUsagePlanKey
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateUsagePlanKeyError {
     /// <p>The submitted request is not valid, for example, the input is incomplete or incorrect. See the accompanying error message for details.</p>
     BadRequest(String),
@@ -4414,6 +4539,8 @@ pub enum CreateUsagePlanKeyError {
     TooManyRequests(String),
     /// <p>The request is denied because the caller has insufficient permissions.</p>
     Unauthorized(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateUsagePlanKeyError {
@@ -4421,22 +4548,28 @@ impl CreateUsagePlanKeyError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(CreateUsagePlanKeyError::BadRequest(err.msg))
+                    return RusotoError::service(CreateUsagePlanKeyError::BadRequest(err.msg), &res)
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(CreateUsagePlanKeyError::Conflict(err.msg))
+                    return RusotoError::service(CreateUsagePlanKeyError::Conflict(err.msg), &res)
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(CreateUsagePlanKeyError::NotFound(err.msg))
+                    return RusotoError::service(CreateUsagePlanKeyError::NotFound(err.msg), &res)
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(CreateUsagePlanKeyError::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        CreateUsagePlanKeyError::TooManyRequests(err.msg),
+                        &res,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(CreateUsagePlanKeyError::Unauthorized(err.msg))
+                    return RusotoError::service(
+                        CreateUsagePlanKeyError::Unauthorized(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreateUsagePlanKeyError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -4451,12 +4584,14 @@ impl fmt::Display for CreateUsagePlanKeyError {
             CreateUsagePlanKeyError::NotFound(ref cause) => write!(f, "{}", cause),
             CreateUsagePlanKeyError::TooManyRequests(ref cause) => write!(f, "{}", cause),
             CreateUsagePlanKeyError::Unauthorized(ref cause) => write!(f, "{}", cause),
+            CreateUsagePlanKeyError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateUsagePlanKeyError {}
 /// Errors returned by CreateVpcLink
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateVpcLinkError {
     /// <p>The submitted request is not valid, for example, the input is incomplete or incorrect. See the accompanying error message for details.</p>
     BadRequest(String),
@@ -4464,6 +4599,8 @@ pub enum CreateVpcLinkError {
     TooManyRequests(String),
     /// <p>The request is denied because the caller has insufficient permissions.</p>
     Unauthorized(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateVpcLinkError {
@@ -4471,16 +4608,16 @@ impl CreateVpcLinkError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(CreateVpcLinkError::BadRequest(err.msg))
+                    return RusotoError::service(CreateVpcLinkError::BadRequest(err.msg), &res)
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(CreateVpcLinkError::TooManyRequests(err.msg))
+                    return RusotoError::service(CreateVpcLinkError::TooManyRequests(err.msg), &res)
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(CreateVpcLinkError::Unauthorized(err.msg))
+                    return RusotoError::service(CreateVpcLinkError::Unauthorized(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreateVpcLinkError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -4493,12 +4630,14 @@ impl fmt::Display for CreateVpcLinkError {
             CreateVpcLinkError::BadRequest(ref cause) => write!(f, "{}", cause),
             CreateVpcLinkError::TooManyRequests(ref cause) => write!(f, "{}", cause),
             CreateVpcLinkError::Unauthorized(ref cause) => write!(f, "{}", cause),
+            CreateVpcLinkError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateVpcLinkError {}
 /// Errors returned by DeleteApiKey
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteApiKeyError {
     /// <p>The requested resource is not found. Make sure that the request URI is correct.</p>
     NotFound(String),
@@ -4506,6 +4645,8 @@ pub enum DeleteApiKeyError {
     TooManyRequests(String),
     /// <p>The request is denied because the caller has insufficient permissions.</p>
     Unauthorized(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteApiKeyError {
@@ -4513,16 +4654,16 @@ impl DeleteApiKeyError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteApiKeyError::NotFound(err.msg))
+                    return RusotoError::service(DeleteApiKeyError::NotFound(err.msg), &res)
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(DeleteApiKeyError::TooManyRequests(err.msg))
+                    return RusotoError::service(DeleteApiKeyError::TooManyRequests(err.msg), &res)
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(DeleteApiKeyError::Unauthorized(err.msg))
+                    return RusotoError::service(DeleteApiKeyError::Unauthorized(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteApiKeyError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -4535,12 +4676,14 @@ impl fmt::Display for DeleteApiKeyError {
             DeleteApiKeyError::NotFound(ref cause) => write!(f, "{}", cause),
             DeleteApiKeyError::TooManyRequests(ref cause) => write!(f, "{}", cause),
             DeleteApiKeyError::Unauthorized(ref cause) => write!(f, "{}", cause),
+            DeleteApiKeyError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteApiKeyError {}
 /// Errors returned by DeleteAuthorizer
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteAuthorizerError {
     /// <p>The submitted request is not valid, for example, the input is incomplete or incorrect. See the accompanying error message for details.</p>
     BadRequest(String),
@@ -4552,6 +4695,8 @@ pub enum DeleteAuthorizerError {
     TooManyRequests(String),
     /// <p>The request is denied because the caller has insufficient permissions.</p>
     Unauthorized(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteAuthorizerError {
@@ -4559,22 +4704,25 @@ impl DeleteAuthorizerError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(DeleteAuthorizerError::BadRequest(err.msg))
+                    return RusotoError::service(DeleteAuthorizerError::BadRequest(err.msg), &res)
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(DeleteAuthorizerError::Conflict(err.msg))
+                    return RusotoError::service(DeleteAuthorizerError::Conflict(err.msg), &res)
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteAuthorizerError::NotFound(err.msg))
+                    return RusotoError::service(DeleteAuthorizerError::NotFound(err.msg), &res)
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(DeleteAuthorizerError::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        DeleteAuthorizerError::TooManyRequests(err.msg),
+                        &res,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(DeleteAuthorizerError::Unauthorized(err.msg))
+                    return RusotoError::service(DeleteAuthorizerError::Unauthorized(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteAuthorizerError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -4589,12 +4737,14 @@ impl fmt::Display for DeleteAuthorizerError {
             DeleteAuthorizerError::NotFound(ref cause) => write!(f, "{}", cause),
             DeleteAuthorizerError::TooManyRequests(ref cause) => write!(f, "{}", cause),
             DeleteAuthorizerError::Unauthorized(ref cause) => write!(f, "{}", cause),
+            DeleteAuthorizerError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteAuthorizerError {}
 /// Errors returned by DeleteBasePathMapping
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteBasePathMappingError {
     /// <p>The submitted request is not valid, for example, the input is incomplete or incorrect. See the accompanying error message for details.</p>
     BadRequest(String),
@@ -4606,6 +4756,8 @@ pub enum DeleteBasePathMappingError {
     TooManyRequests(String),
     /// <p>The request is denied because the caller has insufficient permissions.</p>
     Unauthorized(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteBasePathMappingError {
@@ -4613,24 +4765,37 @@ impl DeleteBasePathMappingError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(DeleteBasePathMappingError::BadRequest(err.msg))
+                    return RusotoError::service(
+                        DeleteBasePathMappingError::BadRequest(err.msg),
+                        &res,
+                    )
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(DeleteBasePathMappingError::Conflict(err.msg))
+                    return RusotoError::service(
+                        DeleteBasePathMappingError::Conflict(err.msg),
+                        &res,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteBasePathMappingError::NotFound(err.msg))
+                    return RusotoError::service(
+                        DeleteBasePathMappingError::NotFound(err.msg),
+                        &res,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(DeleteBasePathMappingError::TooManyRequests(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteBasePathMappingError::TooManyRequests(err.msg),
+                        &res,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(DeleteBasePathMappingError::Unauthorized(err.msg))
+                    return RusotoError::service(
+                        DeleteBasePathMappingError::Unauthorized(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteBasePathMappingError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -4645,12 +4810,14 @@ impl fmt::Display for DeleteBasePathMappingError {
             DeleteBasePathMappingError::NotFound(ref cause) => write!(f, "{}", cause),
             DeleteBasePathMappingError::TooManyRequests(ref cause) => write!(f, "{}", cause),
             DeleteBasePathMappingError::Unauthorized(ref cause) => write!(f, "{}", cause),
+            DeleteBasePathMappingError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteBasePathMappingError {}
 /// Errors returned by DeleteClientCertificate
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteClientCertificateError {
     /// <p>The submitted request is not valid, for example, the input is incomplete or incorrect. See the accompanying error message for details.</p>
     BadRequest(String),
@@ -4660,6 +4827,8 @@ pub enum DeleteClientCertificateError {
     TooManyRequests(String),
     /// <p>The request is denied because the caller has insufficient permissions.</p>
     Unauthorized(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteClientCertificateError {
@@ -4667,23 +4836,31 @@ impl DeleteClientCertificateError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(DeleteClientCertificateError::BadRequest(err.msg))
+                    return RusotoError::service(
+                        DeleteClientCertificateError::BadRequest(err.msg),
+                        &res,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteClientCertificateError::NotFound(err.msg))
+                    return RusotoError::service(
+                        DeleteClientCertificateError::NotFound(err.msg),
+                        &res,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(DeleteClientCertificateError::TooManyRequests(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteClientCertificateError::TooManyRequests(err.msg),
+                        &res,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(DeleteClientCertificateError::Unauthorized(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteClientCertificateError::Unauthorized(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteClientCertificateError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -4697,12 +4874,14 @@ impl fmt::Display for DeleteClientCertificateError {
             DeleteClientCertificateError::NotFound(ref cause) => write!(f, "{}", cause),
             DeleteClientCertificateError::TooManyRequests(ref cause) => write!(f, "{}", cause),
             DeleteClientCertificateError::Unauthorized(ref cause) => write!(f, "{}", cause),
+            DeleteClientCertificateError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteClientCertificateError {}
 /// Errors returned by DeleteDeployment
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteDeploymentError {
     /// <p>The submitted request is not valid, for example, the input is incomplete or incorrect. See the accompanying error message for details.</p>
     BadRequest(String),
@@ -4712,6 +4891,8 @@ pub enum DeleteDeploymentError {
     TooManyRequests(String),
     /// <p>The request is denied because the caller has insufficient permissions.</p>
     Unauthorized(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteDeploymentError {
@@ -4719,19 +4900,22 @@ impl DeleteDeploymentError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(DeleteDeploymentError::BadRequest(err.msg))
+                    return RusotoError::service(DeleteDeploymentError::BadRequest(err.msg), &res)
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteDeploymentError::NotFound(err.msg))
+                    return RusotoError::service(DeleteDeploymentError::NotFound(err.msg), &res)
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(DeleteDeploymentError::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        DeleteDeploymentError::TooManyRequests(err.msg),
+                        &res,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(DeleteDeploymentError::Unauthorized(err.msg))
+                    return RusotoError::service(DeleteDeploymentError::Unauthorized(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteDeploymentError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -4745,12 +4929,14 @@ impl fmt::Display for DeleteDeploymentError {
             DeleteDeploymentError::NotFound(ref cause) => write!(f, "{}", cause),
             DeleteDeploymentError::TooManyRequests(ref cause) => write!(f, "{}", cause),
             DeleteDeploymentError::Unauthorized(ref cause) => write!(f, "{}", cause),
+            DeleteDeploymentError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteDeploymentError {}
 /// Errors returned by DeleteDocumentationPart
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteDocumentationPartError {
     /// <p>The submitted request is not valid, for example, the input is incomplete or incorrect. See the accompanying error message for details.</p>
     BadRequest(String),
@@ -4762,6 +4948,8 @@ pub enum DeleteDocumentationPartError {
     TooManyRequests(String),
     /// <p>The request is denied because the caller has insufficient permissions.</p>
     Unauthorized(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteDocumentationPartError {
@@ -4769,26 +4957,37 @@ impl DeleteDocumentationPartError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(DeleteDocumentationPartError::BadRequest(err.msg))
+                    return RusotoError::service(
+                        DeleteDocumentationPartError::BadRequest(err.msg),
+                        &res,
+                    )
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(DeleteDocumentationPartError::Conflict(err.msg))
+                    return RusotoError::service(
+                        DeleteDocumentationPartError::Conflict(err.msg),
+                        &res,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteDocumentationPartError::NotFound(err.msg))
+                    return RusotoError::service(
+                        DeleteDocumentationPartError::NotFound(err.msg),
+                        &res,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(DeleteDocumentationPartError::TooManyRequests(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteDocumentationPartError::TooManyRequests(err.msg),
+                        &res,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(DeleteDocumentationPartError::Unauthorized(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteDocumentationPartError::Unauthorized(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteDocumentationPartError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -4803,12 +5002,14 @@ impl fmt::Display for DeleteDocumentationPartError {
             DeleteDocumentationPartError::NotFound(ref cause) => write!(f, "{}", cause),
             DeleteDocumentationPartError::TooManyRequests(ref cause) => write!(f, "{}", cause),
             DeleteDocumentationPartError::Unauthorized(ref cause) => write!(f, "{}", cause),
+            DeleteDocumentationPartError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteDocumentationPartError {}
 /// Errors returned by DeleteDocumentationVersion
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteDocumentationVersionError {
     /// <p>The submitted request is not valid, for example, the input is incomplete or incorrect. See the accompanying error message for details.</p>
     BadRequest(String),
@@ -4820,6 +5021,8 @@ pub enum DeleteDocumentationVersionError {
     TooManyRequests(String),
     /// <p>The request is denied because the caller has insufficient permissions.</p>
     Unauthorized(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteDocumentationVersionError {
@@ -4829,28 +5032,39 @@ impl DeleteDocumentationVersionError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(DeleteDocumentationVersionError::BadRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteDocumentationVersionError::BadRequest(err.msg),
+                        &res,
+                    )
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(DeleteDocumentationVersionError::Conflict(err.msg))
+                    return RusotoError::service(
+                        DeleteDocumentationVersionError::Conflict(err.msg),
+                        &res,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteDocumentationVersionError::NotFound(err.msg))
+                    return RusotoError::service(
+                        DeleteDocumentationVersionError::NotFound(err.msg),
+                        &res,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(DeleteDocumentationVersionError::TooManyRequests(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteDocumentationVersionError::TooManyRequests(err.msg),
+                        &res,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(DeleteDocumentationVersionError::Unauthorized(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteDocumentationVersionError::Unauthorized(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(DeleteDocumentationVersionError::Unhandled, &res)
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -4865,12 +5079,14 @@ impl fmt::Display for DeleteDocumentationVersionError {
             DeleteDocumentationVersionError::NotFound(ref cause) => write!(f, "{}", cause),
             DeleteDocumentationVersionError::TooManyRequests(ref cause) => write!(f, "{}", cause),
             DeleteDocumentationVersionError::Unauthorized(ref cause) => write!(f, "{}", cause),
+            DeleteDocumentationVersionError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteDocumentationVersionError {}
 /// Errors returned by DeleteDomainName
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteDomainNameError {
     /// <p>The submitted request is not valid, for example, the input is incomplete or incorrect. See the accompanying error message for details.</p>
     BadRequest(String),
@@ -4880,6 +5096,8 @@ pub enum DeleteDomainNameError {
     TooManyRequests(String),
     /// <p>The request is denied because the caller has insufficient permissions.</p>
     Unauthorized(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteDomainNameError {
@@ -4887,19 +5105,22 @@ impl DeleteDomainNameError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(DeleteDomainNameError::BadRequest(err.msg))
+                    return RusotoError::service(DeleteDomainNameError::BadRequest(err.msg), &res)
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteDomainNameError::NotFound(err.msg))
+                    return RusotoError::service(DeleteDomainNameError::NotFound(err.msg), &res)
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(DeleteDomainNameError::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        DeleteDomainNameError::TooManyRequests(err.msg),
+                        &res,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(DeleteDomainNameError::Unauthorized(err.msg))
+                    return RusotoError::service(DeleteDomainNameError::Unauthorized(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteDomainNameError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -4913,12 +5134,14 @@ impl fmt::Display for DeleteDomainNameError {
             DeleteDomainNameError::NotFound(ref cause) => write!(f, "{}", cause),
             DeleteDomainNameError::TooManyRequests(ref cause) => write!(f, "{}", cause),
             DeleteDomainNameError::Unauthorized(ref cause) => write!(f, "{}", cause),
+            DeleteDomainNameError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteDomainNameError {}
 /// Errors returned by DeleteGatewayResponse
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteGatewayResponseError {
     /// <p>The submitted request is not valid, for example, the input is incomplete or incorrect. See the accompanying error message for details.</p>
     BadRequest(String),
@@ -4930,6 +5153,8 @@ pub enum DeleteGatewayResponseError {
     TooManyRequests(String),
     /// <p>The request is denied because the caller has insufficient permissions.</p>
     Unauthorized(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteGatewayResponseError {
@@ -4937,24 +5162,37 @@ impl DeleteGatewayResponseError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(DeleteGatewayResponseError::BadRequest(err.msg))
+                    return RusotoError::service(
+                        DeleteGatewayResponseError::BadRequest(err.msg),
+                        &res,
+                    )
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(DeleteGatewayResponseError::Conflict(err.msg))
+                    return RusotoError::service(
+                        DeleteGatewayResponseError::Conflict(err.msg),
+                        &res,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteGatewayResponseError::NotFound(err.msg))
+                    return RusotoError::service(
+                        DeleteGatewayResponseError::NotFound(err.msg),
+                        &res,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(DeleteGatewayResponseError::TooManyRequests(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteGatewayResponseError::TooManyRequests(err.msg),
+                        &res,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(DeleteGatewayResponseError::Unauthorized(err.msg))
+                    return RusotoError::service(
+                        DeleteGatewayResponseError::Unauthorized(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteGatewayResponseError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -4969,12 +5207,14 @@ impl fmt::Display for DeleteGatewayResponseError {
             DeleteGatewayResponseError::NotFound(ref cause) => write!(f, "{}", cause),
             DeleteGatewayResponseError::TooManyRequests(ref cause) => write!(f, "{}", cause),
             DeleteGatewayResponseError::Unauthorized(ref cause) => write!(f, "{}", cause),
+            DeleteGatewayResponseError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteGatewayResponseError {}
 /// Errors returned by DeleteIntegration
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteIntegrationError {
     /// <p>The request configuration has conflicts. For details, see the accompanying error message.</p>
     Conflict(String),
@@ -4984,6 +5224,8 @@ pub enum DeleteIntegrationError {
     TooManyRequests(String),
     /// <p>The request is denied because the caller has insufficient permissions.</p>
     Unauthorized(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteIntegrationError {
@@ -4991,19 +5233,25 @@ impl DeleteIntegrationError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ConflictException" => {
-                    return RusotoError::Service(DeleteIntegrationError::Conflict(err.msg))
+                    return RusotoError::service(DeleteIntegrationError::Conflict(err.msg), &res)
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteIntegrationError::NotFound(err.msg))
+                    return RusotoError::service(DeleteIntegrationError::NotFound(err.msg), &res)
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(DeleteIntegrationError::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        DeleteIntegrationError::TooManyRequests(err.msg),
+                        &res,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(DeleteIntegrationError::Unauthorized(err.msg))
+                    return RusotoError::service(
+                        DeleteIntegrationError::Unauthorized(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteIntegrationError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -5017,12 +5265,14 @@ impl fmt::Display for DeleteIntegrationError {
             DeleteIntegrationError::NotFound(ref cause) => write!(f, "{}", cause),
             DeleteIntegrationError::TooManyRequests(ref cause) => write!(f, "{}", cause),
             DeleteIntegrationError::Unauthorized(ref cause) => write!(f, "{}", cause),
+            DeleteIntegrationError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteIntegrationError {}
 /// Errors returned by DeleteIntegrationResponse
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteIntegrationResponseError {
     /// <p>The submitted request is not valid, for example, the input is incomplete or incorrect. See the accompanying error message for details.</p>
     BadRequest(String),
@@ -5034,6 +5284,8 @@ pub enum DeleteIntegrationResponseError {
     TooManyRequests(String),
     /// <p>The request is denied because the caller has insufficient permissions.</p>
     Unauthorized(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteIntegrationResponseError {
@@ -5041,28 +5293,39 @@ impl DeleteIntegrationResponseError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(DeleteIntegrationResponseError::BadRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteIntegrationResponseError::BadRequest(err.msg),
+                        &res,
+                    )
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(DeleteIntegrationResponseError::Conflict(err.msg))
+                    return RusotoError::service(
+                        DeleteIntegrationResponseError::Conflict(err.msg),
+                        &res,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteIntegrationResponseError::NotFound(err.msg))
+                    return RusotoError::service(
+                        DeleteIntegrationResponseError::NotFound(err.msg),
+                        &res,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(DeleteIntegrationResponseError::TooManyRequests(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteIntegrationResponseError::TooManyRequests(err.msg),
+                        &res,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(DeleteIntegrationResponseError::Unauthorized(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteIntegrationResponseError::Unauthorized(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(DeleteIntegrationResponseError::Unhandled, &res)
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -5077,12 +5340,14 @@ impl fmt::Display for DeleteIntegrationResponseError {
             DeleteIntegrationResponseError::NotFound(ref cause) => write!(f, "{}", cause),
             DeleteIntegrationResponseError::TooManyRequests(ref cause) => write!(f, "{}", cause),
             DeleteIntegrationResponseError::Unauthorized(ref cause) => write!(f, "{}", cause),
+            DeleteIntegrationResponseError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteIntegrationResponseError {}
 /// Errors returned by DeleteMethod
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteMethodError {
     /// <p>The request configuration has conflicts. For details, see the accompanying error message.</p>
     Conflict(String),
@@ -5092,6 +5357,8 @@ pub enum DeleteMethodError {
     TooManyRequests(String),
     /// <p>The request is denied because the caller has insufficient permissions.</p>
     Unauthorized(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteMethodError {
@@ -5099,19 +5366,19 @@ impl DeleteMethodError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ConflictException" => {
-                    return RusotoError::Service(DeleteMethodError::Conflict(err.msg))
+                    return RusotoError::service(DeleteMethodError::Conflict(err.msg), &res)
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteMethodError::NotFound(err.msg))
+                    return RusotoError::service(DeleteMethodError::NotFound(err.msg), &res)
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(DeleteMethodError::TooManyRequests(err.msg))
+                    return RusotoError::service(DeleteMethodError::TooManyRequests(err.msg), &res)
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(DeleteMethodError::Unauthorized(err.msg))
+                    return RusotoError::service(DeleteMethodError::Unauthorized(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteMethodError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -5125,12 +5392,14 @@ impl fmt::Display for DeleteMethodError {
             DeleteMethodError::NotFound(ref cause) => write!(f, "{}", cause),
             DeleteMethodError::TooManyRequests(ref cause) => write!(f, "{}", cause),
             DeleteMethodError::Unauthorized(ref cause) => write!(f, "{}", cause),
+            DeleteMethodError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteMethodError {}
 /// Errors returned by DeleteMethodResponse
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteMethodResponseError {
     /// <p>The submitted request is not valid, for example, the input is incomplete or incorrect. See the accompanying error message for details.</p>
     BadRequest(String),
@@ -5142,6 +5411,8 @@ pub enum DeleteMethodResponseError {
     TooManyRequests(String),
     /// <p>The request is denied because the caller has insufficient permissions.</p>
     Unauthorized(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteMethodResponseError {
@@ -5149,24 +5420,31 @@ impl DeleteMethodResponseError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(DeleteMethodResponseError::BadRequest(err.msg))
+                    return RusotoError::service(
+                        DeleteMethodResponseError::BadRequest(err.msg),
+                        &res,
+                    )
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(DeleteMethodResponseError::Conflict(err.msg))
+                    return RusotoError::service(DeleteMethodResponseError::Conflict(err.msg), &res)
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteMethodResponseError::NotFound(err.msg))
+                    return RusotoError::service(DeleteMethodResponseError::NotFound(err.msg), &res)
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(DeleteMethodResponseError::TooManyRequests(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteMethodResponseError::TooManyRequests(err.msg),
+                        &res,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(DeleteMethodResponseError::Unauthorized(err.msg))
+                    return RusotoError::service(
+                        DeleteMethodResponseError::Unauthorized(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteMethodResponseError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -5181,12 +5459,14 @@ impl fmt::Display for DeleteMethodResponseError {
             DeleteMethodResponseError::NotFound(ref cause) => write!(f, "{}", cause),
             DeleteMethodResponseError::TooManyRequests(ref cause) => write!(f, "{}", cause),
             DeleteMethodResponseError::Unauthorized(ref cause) => write!(f, "{}", cause),
+            DeleteMethodResponseError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteMethodResponseError {}
 /// Errors returned by DeleteModel
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteModelError {
     /// <p>The submitted request is not valid, for example, the input is incomplete or incorrect. See the accompanying error message for details.</p>
     BadRequest(String),
@@ -5198,6 +5478,8 @@ pub enum DeleteModelError {
     TooManyRequests(String),
     /// <p>The request is denied because the caller has insufficient permissions.</p>
     Unauthorized(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteModelError {
@@ -5205,22 +5487,22 @@ impl DeleteModelError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(DeleteModelError::BadRequest(err.msg))
+                    return RusotoError::service(DeleteModelError::BadRequest(err.msg), &res)
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(DeleteModelError::Conflict(err.msg))
+                    return RusotoError::service(DeleteModelError::Conflict(err.msg), &res)
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteModelError::NotFound(err.msg))
+                    return RusotoError::service(DeleteModelError::NotFound(err.msg), &res)
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(DeleteModelError::TooManyRequests(err.msg))
+                    return RusotoError::service(DeleteModelError::TooManyRequests(err.msg), &res)
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(DeleteModelError::Unauthorized(err.msg))
+                    return RusotoError::service(DeleteModelError::Unauthorized(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteModelError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -5235,12 +5517,14 @@ impl fmt::Display for DeleteModelError {
             DeleteModelError::NotFound(ref cause) => write!(f, "{}", cause),
             DeleteModelError::TooManyRequests(ref cause) => write!(f, "{}", cause),
             DeleteModelError::Unauthorized(ref cause) => write!(f, "{}", cause),
+            DeleteModelError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteModelError {}
 /// Errors returned by DeleteRequestValidator
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteRequestValidatorError {
     /// <p>The submitted request is not valid, for example, the input is incomplete or incorrect. See the accompanying error message for details.</p>
     BadRequest(String),
@@ -5252,6 +5536,8 @@ pub enum DeleteRequestValidatorError {
     TooManyRequests(String),
     /// <p>The request is denied because the caller has insufficient permissions.</p>
     Unauthorized(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteRequestValidatorError {
@@ -5259,24 +5545,37 @@ impl DeleteRequestValidatorError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(DeleteRequestValidatorError::BadRequest(err.msg))
+                    return RusotoError::service(
+                        DeleteRequestValidatorError::BadRequest(err.msg),
+                        &res,
+                    )
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(DeleteRequestValidatorError::Conflict(err.msg))
+                    return RusotoError::service(
+                        DeleteRequestValidatorError::Conflict(err.msg),
+                        &res,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteRequestValidatorError::NotFound(err.msg))
+                    return RusotoError::service(
+                        DeleteRequestValidatorError::NotFound(err.msg),
+                        &res,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(DeleteRequestValidatorError::TooManyRequests(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteRequestValidatorError::TooManyRequests(err.msg),
+                        &res,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(DeleteRequestValidatorError::Unauthorized(err.msg))
+                    return RusotoError::service(
+                        DeleteRequestValidatorError::Unauthorized(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteRequestValidatorError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -5291,12 +5590,14 @@ impl fmt::Display for DeleteRequestValidatorError {
             DeleteRequestValidatorError::NotFound(ref cause) => write!(f, "{}", cause),
             DeleteRequestValidatorError::TooManyRequests(ref cause) => write!(f, "{}", cause),
             DeleteRequestValidatorError::Unauthorized(ref cause) => write!(f, "{}", cause),
+            DeleteRequestValidatorError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteRequestValidatorError {}
 /// Errors returned by DeleteResource
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteResourceError {
     /// <p>The submitted request is not valid, for example, the input is incomplete or incorrect. See the accompanying error message for details.</p>
     BadRequest(String),
@@ -5308,6 +5609,8 @@ pub enum DeleteResourceError {
     TooManyRequests(String),
     /// <p>The request is denied because the caller has insufficient permissions.</p>
     Unauthorized(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteResourceError {
@@ -5315,22 +5618,25 @@ impl DeleteResourceError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(DeleteResourceError::BadRequest(err.msg))
+                    return RusotoError::service(DeleteResourceError::BadRequest(err.msg), &res)
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(DeleteResourceError::Conflict(err.msg))
+                    return RusotoError::service(DeleteResourceError::Conflict(err.msg), &res)
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteResourceError::NotFound(err.msg))
+                    return RusotoError::service(DeleteResourceError::NotFound(err.msg), &res)
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(DeleteResourceError::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        DeleteResourceError::TooManyRequests(err.msg),
+                        &res,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(DeleteResourceError::Unauthorized(err.msg))
+                    return RusotoError::service(DeleteResourceError::Unauthorized(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteResourceError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -5345,12 +5651,14 @@ impl fmt::Display for DeleteResourceError {
             DeleteResourceError::NotFound(ref cause) => write!(f, "{}", cause),
             DeleteResourceError::TooManyRequests(ref cause) => write!(f, "{}", cause),
             DeleteResourceError::Unauthorized(ref cause) => write!(f, "{}", cause),
+            DeleteResourceError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteResourceError {}
 /// Errors returned by DeleteRestApi
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteRestApiError {
     /// <p>The submitted request is not valid, for example, the input is incomplete or incorrect. See the accompanying error message for details.</p>
     BadRequest(String),
@@ -5360,6 +5668,8 @@ pub enum DeleteRestApiError {
     TooManyRequests(String),
     /// <p>The request is denied because the caller has insufficient permissions.</p>
     Unauthorized(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteRestApiError {
@@ -5367,19 +5677,19 @@ impl DeleteRestApiError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(DeleteRestApiError::BadRequest(err.msg))
+                    return RusotoError::service(DeleteRestApiError::BadRequest(err.msg), &res)
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteRestApiError::NotFound(err.msg))
+                    return RusotoError::service(DeleteRestApiError::NotFound(err.msg), &res)
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(DeleteRestApiError::TooManyRequests(err.msg))
+                    return RusotoError::service(DeleteRestApiError::TooManyRequests(err.msg), &res)
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(DeleteRestApiError::Unauthorized(err.msg))
+                    return RusotoError::service(DeleteRestApiError::Unauthorized(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteRestApiError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -5393,12 +5703,14 @@ impl fmt::Display for DeleteRestApiError {
             DeleteRestApiError::NotFound(ref cause) => write!(f, "{}", cause),
             DeleteRestApiError::TooManyRequests(ref cause) => write!(f, "{}", cause),
             DeleteRestApiError::Unauthorized(ref cause) => write!(f, "{}", cause),
+            DeleteRestApiError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteRestApiError {}
 /// Errors returned by DeleteStage
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteStageError {
     /// <p>The submitted request is not valid, for example, the input is incomplete or incorrect. See the accompanying error message for details.</p>
     BadRequest(String),
@@ -5408,6 +5720,8 @@ pub enum DeleteStageError {
     TooManyRequests(String),
     /// <p>The request is denied because the caller has insufficient permissions.</p>
     Unauthorized(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteStageError {
@@ -5415,19 +5729,19 @@ impl DeleteStageError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(DeleteStageError::BadRequest(err.msg))
+                    return RusotoError::service(DeleteStageError::BadRequest(err.msg), &res)
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteStageError::NotFound(err.msg))
+                    return RusotoError::service(DeleteStageError::NotFound(err.msg), &res)
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(DeleteStageError::TooManyRequests(err.msg))
+                    return RusotoError::service(DeleteStageError::TooManyRequests(err.msg), &res)
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(DeleteStageError::Unauthorized(err.msg))
+                    return RusotoError::service(DeleteStageError::Unauthorized(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteStageError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -5441,12 +5755,14 @@ impl fmt::Display for DeleteStageError {
             DeleteStageError::NotFound(ref cause) => write!(f, "{}", cause),
             DeleteStageError::TooManyRequests(ref cause) => write!(f, "{}", cause),
             DeleteStageError::Unauthorized(ref cause) => write!(f, "{}", cause),
+            DeleteStageError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteStageError {}
 /// Errors returned by DeleteUsagePlan
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteUsagePlanError {
     /// <p>The submitted request is not valid, for example, the input is incomplete or incorrect. See the accompanying error message for details.</p>
     BadRequest(String),
@@ -5456,6 +5772,8 @@ pub enum DeleteUsagePlanError {
     TooManyRequests(String),
     /// <p>The request is denied because the caller has insufficient permissions.</p>
     Unauthorized(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteUsagePlanError {
@@ -5463,19 +5781,22 @@ impl DeleteUsagePlanError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(DeleteUsagePlanError::BadRequest(err.msg))
+                    return RusotoError::service(DeleteUsagePlanError::BadRequest(err.msg), &res)
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteUsagePlanError::NotFound(err.msg))
+                    return RusotoError::service(DeleteUsagePlanError::NotFound(err.msg), &res)
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(DeleteUsagePlanError::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        DeleteUsagePlanError::TooManyRequests(err.msg),
+                        &res,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(DeleteUsagePlanError::Unauthorized(err.msg))
+                    return RusotoError::service(DeleteUsagePlanError::Unauthorized(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteUsagePlanError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -5489,12 +5810,14 @@ impl fmt::Display for DeleteUsagePlanError {
             DeleteUsagePlanError::NotFound(ref cause) => write!(f, "{}", cause),
             DeleteUsagePlanError::TooManyRequests(ref cause) => write!(f, "{}", cause),
             DeleteUsagePlanError::Unauthorized(ref cause) => write!(f, "{}", cause),
+            DeleteUsagePlanError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteUsagePlanError {}
 /// Errors returned by DeleteUsagePlanKey
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteUsagePlanKeyError {
     /// <p>The submitted request is not valid, for example, the input is incomplete or incorrect. See the accompanying error message for details.</p>
     BadRequest(String),
@@ -5506,6 +5829,8 @@ pub enum DeleteUsagePlanKeyError {
     TooManyRequests(String),
     /// <p>The request is denied because the caller has insufficient permissions.</p>
     Unauthorized(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteUsagePlanKeyError {
@@ -5513,22 +5838,28 @@ impl DeleteUsagePlanKeyError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(DeleteUsagePlanKeyError::BadRequest(err.msg))
+                    return RusotoError::service(DeleteUsagePlanKeyError::BadRequest(err.msg), &res)
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(DeleteUsagePlanKeyError::Conflict(err.msg))
+                    return RusotoError::service(DeleteUsagePlanKeyError::Conflict(err.msg), &res)
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteUsagePlanKeyError::NotFound(err.msg))
+                    return RusotoError::service(DeleteUsagePlanKeyError::NotFound(err.msg), &res)
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(DeleteUsagePlanKeyError::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        DeleteUsagePlanKeyError::TooManyRequests(err.msg),
+                        &res,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(DeleteUsagePlanKeyError::Unauthorized(err.msg))
+                    return RusotoError::service(
+                        DeleteUsagePlanKeyError::Unauthorized(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteUsagePlanKeyError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -5543,12 +5874,14 @@ impl fmt::Display for DeleteUsagePlanKeyError {
             DeleteUsagePlanKeyError::NotFound(ref cause) => write!(f, "{}", cause),
             DeleteUsagePlanKeyError::TooManyRequests(ref cause) => write!(f, "{}", cause),
             DeleteUsagePlanKeyError::Unauthorized(ref cause) => write!(f, "{}", cause),
+            DeleteUsagePlanKeyError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteUsagePlanKeyError {}
 /// Errors returned by DeleteVpcLink
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteVpcLinkError {
     /// <p>The submitted request is not valid, for example, the input is incomplete or incorrect. See the accompanying error message for details.</p>
     BadRequest(String),
@@ -5558,6 +5891,8 @@ pub enum DeleteVpcLinkError {
     TooManyRequests(String),
     /// <p>The request is denied because the caller has insufficient permissions.</p>
     Unauthorized(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteVpcLinkError {
@@ -5565,19 +5900,19 @@ impl DeleteVpcLinkError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(DeleteVpcLinkError::BadRequest(err.msg))
+                    return RusotoError::service(DeleteVpcLinkError::BadRequest(err.msg), &res)
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteVpcLinkError::NotFound(err.msg))
+                    return RusotoError::service(DeleteVpcLinkError::NotFound(err.msg), &res)
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(DeleteVpcLinkError::TooManyRequests(err.msg))
+                    return RusotoError::service(DeleteVpcLinkError::TooManyRequests(err.msg), &res)
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(DeleteVpcLinkError::Unauthorized(err.msg))
+                    return RusotoError::service(DeleteVpcLinkError::Unauthorized(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteVpcLinkError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -5591,12 +5926,14 @@ impl fmt::Display for DeleteVpcLinkError {
             DeleteVpcLinkError::NotFound(ref cause) => write!(f, "{}", cause),
             DeleteVpcLinkError::TooManyRequests(ref cause) => write!(f, "{}", cause),
             DeleteVpcLinkError::Unauthorized(ref cause) => write!(f, "{}", cause),
+            DeleteVpcLinkError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteVpcLinkError {}
 /// Errors returned by FlushStageAuthorizersCache
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum FlushStageAuthorizersCacheError {
     /// <p>The submitted request is not valid, for example, the input is incomplete or incorrect. See the accompanying error message for details.</p>
     BadRequest(String),
@@ -5606,6 +5943,8 @@ pub enum FlushStageAuthorizersCacheError {
     TooManyRequests(String),
     /// <p>The request is denied because the caller has insufficient permissions.</p>
     Unauthorized(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl FlushStageAuthorizersCacheError {
@@ -5615,25 +5954,33 @@ impl FlushStageAuthorizersCacheError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(FlushStageAuthorizersCacheError::BadRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        FlushStageAuthorizersCacheError::BadRequest(err.msg),
+                        &res,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(FlushStageAuthorizersCacheError::NotFound(err.msg))
+                    return RusotoError::service(
+                        FlushStageAuthorizersCacheError::NotFound(err.msg),
+                        &res,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(FlushStageAuthorizersCacheError::TooManyRequests(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        FlushStageAuthorizersCacheError::TooManyRequests(err.msg),
+                        &res,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(FlushStageAuthorizersCacheError::Unauthorized(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        FlushStageAuthorizersCacheError::Unauthorized(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(FlushStageAuthorizersCacheError::Unhandled, &res)
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -5647,12 +5994,14 @@ impl fmt::Display for FlushStageAuthorizersCacheError {
             FlushStageAuthorizersCacheError::NotFound(ref cause) => write!(f, "{}", cause),
             FlushStageAuthorizersCacheError::TooManyRequests(ref cause) => write!(f, "{}", cause),
             FlushStageAuthorizersCacheError::Unauthorized(ref cause) => write!(f, "{}", cause),
+            FlushStageAuthorizersCacheError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for FlushStageAuthorizersCacheError {}
 /// Errors returned by FlushStageCache
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum FlushStageCacheError {
     /// <p>The submitted request is not valid, for example, the input is incomplete or incorrect. See the accompanying error message for details.</p>
     BadRequest(String),
@@ -5662,6 +6011,8 @@ pub enum FlushStageCacheError {
     TooManyRequests(String),
     /// <p>The request is denied because the caller has insufficient permissions.</p>
     Unauthorized(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl FlushStageCacheError {
@@ -5669,19 +6020,22 @@ impl FlushStageCacheError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(FlushStageCacheError::BadRequest(err.msg))
+                    return RusotoError::service(FlushStageCacheError::BadRequest(err.msg), &res)
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(FlushStageCacheError::NotFound(err.msg))
+                    return RusotoError::service(FlushStageCacheError::NotFound(err.msg), &res)
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(FlushStageCacheError::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        FlushStageCacheError::TooManyRequests(err.msg),
+                        &res,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(FlushStageCacheError::Unauthorized(err.msg))
+                    return RusotoError::service(FlushStageCacheError::Unauthorized(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(FlushStageCacheError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -5695,12 +6049,14 @@ impl fmt::Display for FlushStageCacheError {
             FlushStageCacheError::NotFound(ref cause) => write!(f, "{}", cause),
             FlushStageCacheError::TooManyRequests(ref cause) => write!(f, "{}", cause),
             FlushStageCacheError::Unauthorized(ref cause) => write!(f, "{}", cause),
+            FlushStageCacheError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for FlushStageCacheError {}
 /// Errors returned by GenerateClientCertificate
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GenerateClientCertificateError {
     /// <p>The request exceeded the rate limit. Retry after the specified time period.</p>
     LimitExceeded(String),
@@ -5708,6 +6064,8 @@ pub enum GenerateClientCertificateError {
     TooManyRequests(String),
     /// <p>The request is denied because the caller has insufficient permissions.</p>
     Unauthorized(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GenerateClientCertificateError {
@@ -5715,22 +6073,27 @@ impl GenerateClientCertificateError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "LimitExceededException" => {
-                    return RusotoError::Service(GenerateClientCertificateError::LimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GenerateClientCertificateError::LimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(GenerateClientCertificateError::TooManyRequests(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GenerateClientCertificateError::TooManyRequests(err.msg),
+                        &res,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(GenerateClientCertificateError::Unauthorized(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GenerateClientCertificateError::Unauthorized(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(GenerateClientCertificateError::Unhandled, &res)
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -5743,12 +6106,14 @@ impl fmt::Display for GenerateClientCertificateError {
             GenerateClientCertificateError::LimitExceeded(ref cause) => write!(f, "{}", cause),
             GenerateClientCertificateError::TooManyRequests(ref cause) => write!(f, "{}", cause),
             GenerateClientCertificateError::Unauthorized(ref cause) => write!(f, "{}", cause),
+            GenerateClientCertificateError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GenerateClientCertificateError {}
 /// Errors returned by GetAccount
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetAccountError {
     /// <p>The requested resource is not found. Make sure that the request URI is correct.</p>
     NotFound(String),
@@ -5756,6 +6121,8 @@ pub enum GetAccountError {
     TooManyRequests(String),
     /// <p>The request is denied because the caller has insufficient permissions.</p>
     Unauthorized(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetAccountError {
@@ -5763,16 +6130,16 @@ impl GetAccountError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "NotFoundException" => {
-                    return RusotoError::Service(GetAccountError::NotFound(err.msg))
+                    return RusotoError::service(GetAccountError::NotFound(err.msg), &res)
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(GetAccountError::TooManyRequests(err.msg))
+                    return RusotoError::service(GetAccountError::TooManyRequests(err.msg), &res)
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(GetAccountError::Unauthorized(err.msg))
+                    return RusotoError::service(GetAccountError::Unauthorized(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetAccountError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -5785,12 +6152,14 @@ impl fmt::Display for GetAccountError {
             GetAccountError::NotFound(ref cause) => write!(f, "{}", cause),
             GetAccountError::TooManyRequests(ref cause) => write!(f, "{}", cause),
             GetAccountError::Unauthorized(ref cause) => write!(f, "{}", cause),
+            GetAccountError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetAccountError {}
 /// Errors returned by GetApiKey
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetApiKeyError {
     /// <p>The requested resource is not found. Make sure that the request URI is correct.</p>
     NotFound(String),
@@ -5798,6 +6167,8 @@ pub enum GetApiKeyError {
     TooManyRequests(String),
     /// <p>The request is denied because the caller has insufficient permissions.</p>
     Unauthorized(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetApiKeyError {
@@ -5805,16 +6176,16 @@ impl GetApiKeyError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "NotFoundException" => {
-                    return RusotoError::Service(GetApiKeyError::NotFound(err.msg))
+                    return RusotoError::service(GetApiKeyError::NotFound(err.msg), &res)
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(GetApiKeyError::TooManyRequests(err.msg))
+                    return RusotoError::service(GetApiKeyError::TooManyRequests(err.msg), &res)
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(GetApiKeyError::Unauthorized(err.msg))
+                    return RusotoError::service(GetApiKeyError::Unauthorized(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetApiKeyError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -5827,12 +6198,14 @@ impl fmt::Display for GetApiKeyError {
             GetApiKeyError::NotFound(ref cause) => write!(f, "{}", cause),
             GetApiKeyError::TooManyRequests(ref cause) => write!(f, "{}", cause),
             GetApiKeyError::Unauthorized(ref cause) => write!(f, "{}", cause),
+            GetApiKeyError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetApiKeyError {}
 /// Errors returned by GetApiKeys
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetApiKeysError {
     /// <p>The submitted request is not valid, for example, the input is incomplete or incorrect. See the accompanying error message for details.</p>
     BadRequest(String),
@@ -5840,6 +6213,8 @@ pub enum GetApiKeysError {
     TooManyRequests(String),
     /// <p>The request is denied because the caller has insufficient permissions.</p>
     Unauthorized(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetApiKeysError {
@@ -5847,16 +6222,16 @@ impl GetApiKeysError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetApiKeysError::BadRequest(err.msg))
+                    return RusotoError::service(GetApiKeysError::BadRequest(err.msg), &res)
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(GetApiKeysError::TooManyRequests(err.msg))
+                    return RusotoError::service(GetApiKeysError::TooManyRequests(err.msg), &res)
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(GetApiKeysError::Unauthorized(err.msg))
+                    return RusotoError::service(GetApiKeysError::Unauthorized(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetApiKeysError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -5869,12 +6244,14 @@ impl fmt::Display for GetApiKeysError {
             GetApiKeysError::BadRequest(ref cause) => write!(f, "{}", cause),
             GetApiKeysError::TooManyRequests(ref cause) => write!(f, "{}", cause),
             GetApiKeysError::Unauthorized(ref cause) => write!(f, "{}", cause),
+            GetApiKeysError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetApiKeysError {}
 /// Errors returned by GetAuthorizer
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetAuthorizerError {
     /// <p>The requested resource is not found. Make sure that the request URI is correct.</p>
     NotFound(String),
@@ -5882,6 +6259,8 @@ pub enum GetAuthorizerError {
     TooManyRequests(String),
     /// <p>The request is denied because the caller has insufficient permissions.</p>
     Unauthorized(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetAuthorizerError {
@@ -5889,16 +6268,16 @@ impl GetAuthorizerError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "NotFoundException" => {
-                    return RusotoError::Service(GetAuthorizerError::NotFound(err.msg))
+                    return RusotoError::service(GetAuthorizerError::NotFound(err.msg), &res)
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(GetAuthorizerError::TooManyRequests(err.msg))
+                    return RusotoError::service(GetAuthorizerError::TooManyRequests(err.msg), &res)
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(GetAuthorizerError::Unauthorized(err.msg))
+                    return RusotoError::service(GetAuthorizerError::Unauthorized(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetAuthorizerError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -5911,12 +6290,14 @@ impl fmt::Display for GetAuthorizerError {
             GetAuthorizerError::NotFound(ref cause) => write!(f, "{}", cause),
             GetAuthorizerError::TooManyRequests(ref cause) => write!(f, "{}", cause),
             GetAuthorizerError::Unauthorized(ref cause) => write!(f, "{}", cause),
+            GetAuthorizerError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetAuthorizerError {}
 /// Errors returned by GetAuthorizers
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetAuthorizersError {
     /// <p>The submitted request is not valid, for example, the input is incomplete or incorrect. See the accompanying error message for details.</p>
     BadRequest(String),
@@ -5926,6 +6307,8 @@ pub enum GetAuthorizersError {
     TooManyRequests(String),
     /// <p>The request is denied because the caller has insufficient permissions.</p>
     Unauthorized(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetAuthorizersError {
@@ -5933,19 +6316,22 @@ impl GetAuthorizersError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetAuthorizersError::BadRequest(err.msg))
+                    return RusotoError::service(GetAuthorizersError::BadRequest(err.msg), &res)
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetAuthorizersError::NotFound(err.msg))
+                    return RusotoError::service(GetAuthorizersError::NotFound(err.msg), &res)
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(GetAuthorizersError::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        GetAuthorizersError::TooManyRequests(err.msg),
+                        &res,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(GetAuthorizersError::Unauthorized(err.msg))
+                    return RusotoError::service(GetAuthorizersError::Unauthorized(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetAuthorizersError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -5959,12 +6345,14 @@ impl fmt::Display for GetAuthorizersError {
             GetAuthorizersError::NotFound(ref cause) => write!(f, "{}", cause),
             GetAuthorizersError::TooManyRequests(ref cause) => write!(f, "{}", cause),
             GetAuthorizersError::Unauthorized(ref cause) => write!(f, "{}", cause),
+            GetAuthorizersError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetAuthorizersError {}
 /// Errors returned by GetBasePathMapping
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetBasePathMappingError {
     /// <p>The requested resource is not found. Make sure that the request URI is correct.</p>
     NotFound(String),
@@ -5972,6 +6360,8 @@ pub enum GetBasePathMappingError {
     TooManyRequests(String),
     /// <p>The request is denied because the caller has insufficient permissions.</p>
     Unauthorized(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetBasePathMappingError {
@@ -5979,16 +6369,22 @@ impl GetBasePathMappingError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "NotFoundException" => {
-                    return RusotoError::Service(GetBasePathMappingError::NotFound(err.msg))
+                    return RusotoError::service(GetBasePathMappingError::NotFound(err.msg), &res)
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(GetBasePathMappingError::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        GetBasePathMappingError::TooManyRequests(err.msg),
+                        &res,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(GetBasePathMappingError::Unauthorized(err.msg))
+                    return RusotoError::service(
+                        GetBasePathMappingError::Unauthorized(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetBasePathMappingError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -6001,12 +6397,14 @@ impl fmt::Display for GetBasePathMappingError {
             GetBasePathMappingError::NotFound(ref cause) => write!(f, "{}", cause),
             GetBasePathMappingError::TooManyRequests(ref cause) => write!(f, "{}", cause),
             GetBasePathMappingError::Unauthorized(ref cause) => write!(f, "{}", cause),
+            GetBasePathMappingError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetBasePathMappingError {}
 /// Errors returned by GetBasePathMappings
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetBasePathMappingsError {
     /// <p>The requested resource is not found. Make sure that the request URI is correct.</p>
     NotFound(String),
@@ -6014,6 +6412,8 @@ pub enum GetBasePathMappingsError {
     TooManyRequests(String),
     /// <p>The request is denied because the caller has insufficient permissions.</p>
     Unauthorized(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetBasePathMappingsError {
@@ -6021,16 +6421,22 @@ impl GetBasePathMappingsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "NotFoundException" => {
-                    return RusotoError::Service(GetBasePathMappingsError::NotFound(err.msg))
+                    return RusotoError::service(GetBasePathMappingsError::NotFound(err.msg), &res)
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(GetBasePathMappingsError::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        GetBasePathMappingsError::TooManyRequests(err.msg),
+                        &res,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(GetBasePathMappingsError::Unauthorized(err.msg))
+                    return RusotoError::service(
+                        GetBasePathMappingsError::Unauthorized(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetBasePathMappingsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -6043,12 +6449,14 @@ impl fmt::Display for GetBasePathMappingsError {
             GetBasePathMappingsError::NotFound(ref cause) => write!(f, "{}", cause),
             GetBasePathMappingsError::TooManyRequests(ref cause) => write!(f, "{}", cause),
             GetBasePathMappingsError::Unauthorized(ref cause) => write!(f, "{}", cause),
+            GetBasePathMappingsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetBasePathMappingsError {}
 /// Errors returned by GetClientCertificate
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetClientCertificateError {
     /// <p>The requested resource is not found. Make sure that the request URI is correct.</p>
     NotFound(String),
@@ -6056,6 +6464,8 @@ pub enum GetClientCertificateError {
     TooManyRequests(String),
     /// <p>The request is denied because the caller has insufficient permissions.</p>
     Unauthorized(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetClientCertificateError {
@@ -6063,18 +6473,22 @@ impl GetClientCertificateError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "NotFoundException" => {
-                    return RusotoError::Service(GetClientCertificateError::NotFound(err.msg))
+                    return RusotoError::service(GetClientCertificateError::NotFound(err.msg), &res)
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(GetClientCertificateError::TooManyRequests(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetClientCertificateError::TooManyRequests(err.msg),
+                        &res,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(GetClientCertificateError::Unauthorized(err.msg))
+                    return RusotoError::service(
+                        GetClientCertificateError::Unauthorized(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetClientCertificateError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -6087,12 +6501,14 @@ impl fmt::Display for GetClientCertificateError {
             GetClientCertificateError::NotFound(ref cause) => write!(f, "{}", cause),
             GetClientCertificateError::TooManyRequests(ref cause) => write!(f, "{}", cause),
             GetClientCertificateError::Unauthorized(ref cause) => write!(f, "{}", cause),
+            GetClientCertificateError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetClientCertificateError {}
 /// Errors returned by GetClientCertificates
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetClientCertificatesError {
     /// <p>The submitted request is not valid, for example, the input is incomplete or incorrect. See the accompanying error message for details.</p>
     BadRequest(String),
@@ -6100,6 +6516,8 @@ pub enum GetClientCertificatesError {
     TooManyRequests(String),
     /// <p>The request is denied because the caller has insufficient permissions.</p>
     Unauthorized(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetClientCertificatesError {
@@ -6107,18 +6525,25 @@ impl GetClientCertificatesError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetClientCertificatesError::BadRequest(err.msg))
+                    return RusotoError::service(
+                        GetClientCertificatesError::BadRequest(err.msg),
+                        &res,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(GetClientCertificatesError::TooManyRequests(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetClientCertificatesError::TooManyRequests(err.msg),
+                        &res,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(GetClientCertificatesError::Unauthorized(err.msg))
+                    return RusotoError::service(
+                        GetClientCertificatesError::Unauthorized(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetClientCertificatesError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -6131,12 +6556,14 @@ impl fmt::Display for GetClientCertificatesError {
             GetClientCertificatesError::BadRequest(ref cause) => write!(f, "{}", cause),
             GetClientCertificatesError::TooManyRequests(ref cause) => write!(f, "{}", cause),
             GetClientCertificatesError::Unauthorized(ref cause) => write!(f, "{}", cause),
+            GetClientCertificatesError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetClientCertificatesError {}
 /// Errors returned by GetDeployment
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetDeploymentError {
     /// <p>The requested resource is not found. Make sure that the request URI is correct.</p>
     NotFound(String),
@@ -6146,6 +6573,8 @@ pub enum GetDeploymentError {
     TooManyRequests(String),
     /// <p>The request is denied because the caller has insufficient permissions.</p>
     Unauthorized(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetDeploymentError {
@@ -6153,19 +6582,22 @@ impl GetDeploymentError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "NotFoundException" => {
-                    return RusotoError::Service(GetDeploymentError::NotFound(err.msg))
+                    return RusotoError::service(GetDeploymentError::NotFound(err.msg), &res)
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(GetDeploymentError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        GetDeploymentError::ServiceUnavailable(err.msg),
+                        &res,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(GetDeploymentError::TooManyRequests(err.msg))
+                    return RusotoError::service(GetDeploymentError::TooManyRequests(err.msg), &res)
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(GetDeploymentError::Unauthorized(err.msg))
+                    return RusotoError::service(GetDeploymentError::Unauthorized(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetDeploymentError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -6179,12 +6611,14 @@ impl fmt::Display for GetDeploymentError {
             GetDeploymentError::ServiceUnavailable(ref cause) => write!(f, "{}", cause),
             GetDeploymentError::TooManyRequests(ref cause) => write!(f, "{}", cause),
             GetDeploymentError::Unauthorized(ref cause) => write!(f, "{}", cause),
+            GetDeploymentError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetDeploymentError {}
 /// Errors returned by GetDeployments
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetDeploymentsError {
     /// <p>The submitted request is not valid, for example, the input is incomplete or incorrect. See the accompanying error message for details.</p>
     BadRequest(String),
@@ -6194,6 +6628,8 @@ pub enum GetDeploymentsError {
     TooManyRequests(String),
     /// <p>The request is denied because the caller has insufficient permissions.</p>
     Unauthorized(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetDeploymentsError {
@@ -6201,19 +6637,25 @@ impl GetDeploymentsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetDeploymentsError::BadRequest(err.msg))
+                    return RusotoError::service(GetDeploymentsError::BadRequest(err.msg), &res)
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(GetDeploymentsError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        GetDeploymentsError::ServiceUnavailable(err.msg),
+                        &res,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(GetDeploymentsError::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        GetDeploymentsError::TooManyRequests(err.msg),
+                        &res,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(GetDeploymentsError::Unauthorized(err.msg))
+                    return RusotoError::service(GetDeploymentsError::Unauthorized(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetDeploymentsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -6227,12 +6669,14 @@ impl fmt::Display for GetDeploymentsError {
             GetDeploymentsError::ServiceUnavailable(ref cause) => write!(f, "{}", cause),
             GetDeploymentsError::TooManyRequests(ref cause) => write!(f, "{}", cause),
             GetDeploymentsError::Unauthorized(ref cause) => write!(f, "{}", cause),
+            GetDeploymentsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetDeploymentsError {}
 /// Errors returned by GetDocumentationPart
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetDocumentationPartError {
     /// <p>The requested resource is not found. Make sure that the request URI is correct.</p>
     NotFound(String),
@@ -6240,6 +6684,8 @@ pub enum GetDocumentationPartError {
     TooManyRequests(String),
     /// <p>The request is denied because the caller has insufficient permissions.</p>
     Unauthorized(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetDocumentationPartError {
@@ -6247,18 +6693,22 @@ impl GetDocumentationPartError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "NotFoundException" => {
-                    return RusotoError::Service(GetDocumentationPartError::NotFound(err.msg))
+                    return RusotoError::service(GetDocumentationPartError::NotFound(err.msg), &res)
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(GetDocumentationPartError::TooManyRequests(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetDocumentationPartError::TooManyRequests(err.msg),
+                        &res,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(GetDocumentationPartError::Unauthorized(err.msg))
+                    return RusotoError::service(
+                        GetDocumentationPartError::Unauthorized(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetDocumentationPartError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -6271,12 +6721,14 @@ impl fmt::Display for GetDocumentationPartError {
             GetDocumentationPartError::NotFound(ref cause) => write!(f, "{}", cause),
             GetDocumentationPartError::TooManyRequests(ref cause) => write!(f, "{}", cause),
             GetDocumentationPartError::Unauthorized(ref cause) => write!(f, "{}", cause),
+            GetDocumentationPartError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetDocumentationPartError {}
 /// Errors returned by GetDocumentationParts
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetDocumentationPartsError {
     /// <p>The submitted request is not valid, for example, the input is incomplete or incorrect. See the accompanying error message for details.</p>
     BadRequest(String),
@@ -6286,6 +6738,8 @@ pub enum GetDocumentationPartsError {
     TooManyRequests(String),
     /// <p>The request is denied because the caller has insufficient permissions.</p>
     Unauthorized(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetDocumentationPartsError {
@@ -6293,21 +6747,31 @@ impl GetDocumentationPartsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetDocumentationPartsError::BadRequest(err.msg))
+                    return RusotoError::service(
+                        GetDocumentationPartsError::BadRequest(err.msg),
+                        &res,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetDocumentationPartsError::NotFound(err.msg))
+                    return RusotoError::service(
+                        GetDocumentationPartsError::NotFound(err.msg),
+                        &res,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(GetDocumentationPartsError::TooManyRequests(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetDocumentationPartsError::TooManyRequests(err.msg),
+                        &res,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(GetDocumentationPartsError::Unauthorized(err.msg))
+                    return RusotoError::service(
+                        GetDocumentationPartsError::Unauthorized(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetDocumentationPartsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -6321,12 +6785,14 @@ impl fmt::Display for GetDocumentationPartsError {
             GetDocumentationPartsError::NotFound(ref cause) => write!(f, "{}", cause),
             GetDocumentationPartsError::TooManyRequests(ref cause) => write!(f, "{}", cause),
             GetDocumentationPartsError::Unauthorized(ref cause) => write!(f, "{}", cause),
+            GetDocumentationPartsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetDocumentationPartsError {}
 /// Errors returned by GetDocumentationVersion
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetDocumentationVersionError {
     /// <p>The requested resource is not found. Make sure that the request URI is correct.</p>
     NotFound(String),
@@ -6334,6 +6800,8 @@ pub enum GetDocumentationVersionError {
     TooManyRequests(String),
     /// <p>The request is denied because the caller has insufficient permissions.</p>
     Unauthorized(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetDocumentationVersionError {
@@ -6341,20 +6809,25 @@ impl GetDocumentationVersionError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "NotFoundException" => {
-                    return RusotoError::Service(GetDocumentationVersionError::NotFound(err.msg))
+                    return RusotoError::service(
+                        GetDocumentationVersionError::NotFound(err.msg),
+                        &res,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(GetDocumentationVersionError::TooManyRequests(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetDocumentationVersionError::TooManyRequests(err.msg),
+                        &res,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(GetDocumentationVersionError::Unauthorized(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetDocumentationVersionError::Unauthorized(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetDocumentationVersionError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -6367,12 +6840,14 @@ impl fmt::Display for GetDocumentationVersionError {
             GetDocumentationVersionError::NotFound(ref cause) => write!(f, "{}", cause),
             GetDocumentationVersionError::TooManyRequests(ref cause) => write!(f, "{}", cause),
             GetDocumentationVersionError::Unauthorized(ref cause) => write!(f, "{}", cause),
+            GetDocumentationVersionError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetDocumentationVersionError {}
 /// Errors returned by GetDocumentationVersions
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetDocumentationVersionsError {
     /// <p>The submitted request is not valid, for example, the input is incomplete or incorrect. See the accompanying error message for details.</p>
     BadRequest(String),
@@ -6382,6 +6857,8 @@ pub enum GetDocumentationVersionsError {
     TooManyRequests(String),
     /// <p>The request is denied because the caller has insufficient permissions.</p>
     Unauthorized(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetDocumentationVersionsError {
@@ -6389,23 +6866,31 @@ impl GetDocumentationVersionsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetDocumentationVersionsError::BadRequest(err.msg))
+                    return RusotoError::service(
+                        GetDocumentationVersionsError::BadRequest(err.msg),
+                        &res,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetDocumentationVersionsError::NotFound(err.msg))
+                    return RusotoError::service(
+                        GetDocumentationVersionsError::NotFound(err.msg),
+                        &res,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(GetDocumentationVersionsError::TooManyRequests(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetDocumentationVersionsError::TooManyRequests(err.msg),
+                        &res,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(GetDocumentationVersionsError::Unauthorized(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetDocumentationVersionsError::Unauthorized(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetDocumentationVersionsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -6419,12 +6904,14 @@ impl fmt::Display for GetDocumentationVersionsError {
             GetDocumentationVersionsError::NotFound(ref cause) => write!(f, "{}", cause),
             GetDocumentationVersionsError::TooManyRequests(ref cause) => write!(f, "{}", cause),
             GetDocumentationVersionsError::Unauthorized(ref cause) => write!(f, "{}", cause),
+            GetDocumentationVersionsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetDocumentationVersionsError {}
 /// Errors returned by GetDomainName
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetDomainNameError {
     /// <p>The requested resource is not found. Make sure that the request URI is correct.</p>
     NotFound(String),
@@ -6434,6 +6921,8 @@ pub enum GetDomainNameError {
     TooManyRequests(String),
     /// <p>The request is denied because the caller has insufficient permissions.</p>
     Unauthorized(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetDomainNameError {
@@ -6441,19 +6930,22 @@ impl GetDomainNameError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "NotFoundException" => {
-                    return RusotoError::Service(GetDomainNameError::NotFound(err.msg))
+                    return RusotoError::service(GetDomainNameError::NotFound(err.msg), &res)
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(GetDomainNameError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        GetDomainNameError::ServiceUnavailable(err.msg),
+                        &res,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(GetDomainNameError::TooManyRequests(err.msg))
+                    return RusotoError::service(GetDomainNameError::TooManyRequests(err.msg), &res)
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(GetDomainNameError::Unauthorized(err.msg))
+                    return RusotoError::service(GetDomainNameError::Unauthorized(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetDomainNameError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -6467,12 +6959,14 @@ impl fmt::Display for GetDomainNameError {
             GetDomainNameError::ServiceUnavailable(ref cause) => write!(f, "{}", cause),
             GetDomainNameError::TooManyRequests(ref cause) => write!(f, "{}", cause),
             GetDomainNameError::Unauthorized(ref cause) => write!(f, "{}", cause),
+            GetDomainNameError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetDomainNameError {}
 /// Errors returned by GetDomainNames
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetDomainNamesError {
     /// <p>The submitted request is not valid, for example, the input is incomplete or incorrect. See the accompanying error message for details.</p>
     BadRequest(String),
@@ -6480,6 +6974,8 @@ pub enum GetDomainNamesError {
     TooManyRequests(String),
     /// <p>The request is denied because the caller has insufficient permissions.</p>
     Unauthorized(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetDomainNamesError {
@@ -6487,16 +6983,19 @@ impl GetDomainNamesError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetDomainNamesError::BadRequest(err.msg))
+                    return RusotoError::service(GetDomainNamesError::BadRequest(err.msg), &res)
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(GetDomainNamesError::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        GetDomainNamesError::TooManyRequests(err.msg),
+                        &res,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(GetDomainNamesError::Unauthorized(err.msg))
+                    return RusotoError::service(GetDomainNamesError::Unauthorized(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetDomainNamesError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -6509,12 +7008,14 @@ impl fmt::Display for GetDomainNamesError {
             GetDomainNamesError::BadRequest(ref cause) => write!(f, "{}", cause),
             GetDomainNamesError::TooManyRequests(ref cause) => write!(f, "{}", cause),
             GetDomainNamesError::Unauthorized(ref cause) => write!(f, "{}", cause),
+            GetDomainNamesError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetDomainNamesError {}
 /// Errors returned by GetExport
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetExportError {
     /// <p>The submitted request is not valid, for example, the input is incomplete or incorrect. See the accompanying error message for details.</p>
     BadRequest(String),
@@ -6526,6 +7027,8 @@ pub enum GetExportError {
     TooManyRequests(String),
     /// <p>The request is denied because the caller has insufficient permissions.</p>
     Unauthorized(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetExportError {
@@ -6533,22 +7036,22 @@ impl GetExportError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetExportError::BadRequest(err.msg))
+                    return RusotoError::service(GetExportError::BadRequest(err.msg), &res)
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(GetExportError::Conflict(err.msg))
+                    return RusotoError::service(GetExportError::Conflict(err.msg), &res)
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetExportError::NotFound(err.msg))
+                    return RusotoError::service(GetExportError::NotFound(err.msg), &res)
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(GetExportError::TooManyRequests(err.msg))
+                    return RusotoError::service(GetExportError::TooManyRequests(err.msg), &res)
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(GetExportError::Unauthorized(err.msg))
+                    return RusotoError::service(GetExportError::Unauthorized(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetExportError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -6563,12 +7066,14 @@ impl fmt::Display for GetExportError {
             GetExportError::NotFound(ref cause) => write!(f, "{}", cause),
             GetExportError::TooManyRequests(ref cause) => write!(f, "{}", cause),
             GetExportError::Unauthorized(ref cause) => write!(f, "{}", cause),
+            GetExportError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetExportError {}
 /// Errors returned by GetGatewayResponse
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetGatewayResponseError {
     /// <p>The requested resource is not found. Make sure that the request URI is correct.</p>
     NotFound(String),
@@ -6576,6 +7081,8 @@ pub enum GetGatewayResponseError {
     TooManyRequests(String),
     /// <p>The request is denied because the caller has insufficient permissions.</p>
     Unauthorized(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetGatewayResponseError {
@@ -6583,16 +7090,22 @@ impl GetGatewayResponseError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "NotFoundException" => {
-                    return RusotoError::Service(GetGatewayResponseError::NotFound(err.msg))
+                    return RusotoError::service(GetGatewayResponseError::NotFound(err.msg), &res)
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(GetGatewayResponseError::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        GetGatewayResponseError::TooManyRequests(err.msg),
+                        &res,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(GetGatewayResponseError::Unauthorized(err.msg))
+                    return RusotoError::service(
+                        GetGatewayResponseError::Unauthorized(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetGatewayResponseError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -6605,12 +7118,14 @@ impl fmt::Display for GetGatewayResponseError {
             GetGatewayResponseError::NotFound(ref cause) => write!(f, "{}", cause),
             GetGatewayResponseError::TooManyRequests(ref cause) => write!(f, "{}", cause),
             GetGatewayResponseError::Unauthorized(ref cause) => write!(f, "{}", cause),
+            GetGatewayResponseError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetGatewayResponseError {}
 /// Errors returned by GetGatewayResponses
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetGatewayResponsesError {
     /// <p>The submitted request is not valid, for example, the input is incomplete or incorrect. See the accompanying error message for details.</p>
     BadRequest(String),
@@ -6620,6 +7135,8 @@ pub enum GetGatewayResponsesError {
     TooManyRequests(String),
     /// <p>The request is denied because the caller has insufficient permissions.</p>
     Unauthorized(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetGatewayResponsesError {
@@ -6627,19 +7144,28 @@ impl GetGatewayResponsesError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetGatewayResponsesError::BadRequest(err.msg))
+                    return RusotoError::service(
+                        GetGatewayResponsesError::BadRequest(err.msg),
+                        &res,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetGatewayResponsesError::NotFound(err.msg))
+                    return RusotoError::service(GetGatewayResponsesError::NotFound(err.msg), &res)
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(GetGatewayResponsesError::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        GetGatewayResponsesError::TooManyRequests(err.msg),
+                        &res,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(GetGatewayResponsesError::Unauthorized(err.msg))
+                    return RusotoError::service(
+                        GetGatewayResponsesError::Unauthorized(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetGatewayResponsesError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -6653,12 +7179,14 @@ impl fmt::Display for GetGatewayResponsesError {
             GetGatewayResponsesError::NotFound(ref cause) => write!(f, "{}", cause),
             GetGatewayResponsesError::TooManyRequests(ref cause) => write!(f, "{}", cause),
             GetGatewayResponsesError::Unauthorized(ref cause) => write!(f, "{}", cause),
+            GetGatewayResponsesError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetGatewayResponsesError {}
 /// Errors returned by GetIntegration
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetIntegrationError {
     /// <p>The requested resource is not found. Make sure that the request URI is correct.</p>
     NotFound(String),
@@ -6666,6 +7194,8 @@ pub enum GetIntegrationError {
     TooManyRequests(String),
     /// <p>The request is denied because the caller has insufficient permissions.</p>
     Unauthorized(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetIntegrationError {
@@ -6673,16 +7203,19 @@ impl GetIntegrationError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "NotFoundException" => {
-                    return RusotoError::Service(GetIntegrationError::NotFound(err.msg))
+                    return RusotoError::service(GetIntegrationError::NotFound(err.msg), &res)
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(GetIntegrationError::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        GetIntegrationError::TooManyRequests(err.msg),
+                        &res,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(GetIntegrationError::Unauthorized(err.msg))
+                    return RusotoError::service(GetIntegrationError::Unauthorized(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetIntegrationError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -6695,12 +7228,14 @@ impl fmt::Display for GetIntegrationError {
             GetIntegrationError::NotFound(ref cause) => write!(f, "{}", cause),
             GetIntegrationError::TooManyRequests(ref cause) => write!(f, "{}", cause),
             GetIntegrationError::Unauthorized(ref cause) => write!(f, "{}", cause),
+            GetIntegrationError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetIntegrationError {}
 /// Errors returned by GetIntegrationResponse
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetIntegrationResponseError {
     /// <p>The requested resource is not found. Make sure that the request URI is correct.</p>
     NotFound(String),
@@ -6708,6 +7243,8 @@ pub enum GetIntegrationResponseError {
     TooManyRequests(String),
     /// <p>The request is denied because the caller has insufficient permissions.</p>
     Unauthorized(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetIntegrationResponseError {
@@ -6715,18 +7252,25 @@ impl GetIntegrationResponseError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "NotFoundException" => {
-                    return RusotoError::Service(GetIntegrationResponseError::NotFound(err.msg))
+                    return RusotoError::service(
+                        GetIntegrationResponseError::NotFound(err.msg),
+                        &res,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(GetIntegrationResponseError::TooManyRequests(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetIntegrationResponseError::TooManyRequests(err.msg),
+                        &res,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(GetIntegrationResponseError::Unauthorized(err.msg))
+                    return RusotoError::service(
+                        GetIntegrationResponseError::Unauthorized(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetIntegrationResponseError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -6739,12 +7283,14 @@ impl fmt::Display for GetIntegrationResponseError {
             GetIntegrationResponseError::NotFound(ref cause) => write!(f, "{}", cause),
             GetIntegrationResponseError::TooManyRequests(ref cause) => write!(f, "{}", cause),
             GetIntegrationResponseError::Unauthorized(ref cause) => write!(f, "{}", cause),
+            GetIntegrationResponseError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetIntegrationResponseError {}
 /// Errors returned by GetMethod
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetMethodError {
     /// <p>The requested resource is not found. Make sure that the request URI is correct.</p>
     NotFound(String),
@@ -6752,6 +7298,8 @@ pub enum GetMethodError {
     TooManyRequests(String),
     /// <p>The request is denied because the caller has insufficient permissions.</p>
     Unauthorized(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetMethodError {
@@ -6759,16 +7307,16 @@ impl GetMethodError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "NotFoundException" => {
-                    return RusotoError::Service(GetMethodError::NotFound(err.msg))
+                    return RusotoError::service(GetMethodError::NotFound(err.msg), &res)
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(GetMethodError::TooManyRequests(err.msg))
+                    return RusotoError::service(GetMethodError::TooManyRequests(err.msg), &res)
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(GetMethodError::Unauthorized(err.msg))
+                    return RusotoError::service(GetMethodError::Unauthorized(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetMethodError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -6781,12 +7329,14 @@ impl fmt::Display for GetMethodError {
             GetMethodError::NotFound(ref cause) => write!(f, "{}", cause),
             GetMethodError::TooManyRequests(ref cause) => write!(f, "{}", cause),
             GetMethodError::Unauthorized(ref cause) => write!(f, "{}", cause),
+            GetMethodError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetMethodError {}
 /// Errors returned by GetMethodResponse
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetMethodResponseError {
     /// <p>The requested resource is not found. Make sure that the request URI is correct.</p>
     NotFound(String),
@@ -6794,6 +7344,8 @@ pub enum GetMethodResponseError {
     TooManyRequests(String),
     /// <p>The request is denied because the caller has insufficient permissions.</p>
     Unauthorized(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetMethodResponseError {
@@ -6801,16 +7353,22 @@ impl GetMethodResponseError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "NotFoundException" => {
-                    return RusotoError::Service(GetMethodResponseError::NotFound(err.msg))
+                    return RusotoError::service(GetMethodResponseError::NotFound(err.msg), &res)
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(GetMethodResponseError::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        GetMethodResponseError::TooManyRequests(err.msg),
+                        &res,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(GetMethodResponseError::Unauthorized(err.msg))
+                    return RusotoError::service(
+                        GetMethodResponseError::Unauthorized(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetMethodResponseError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -6823,12 +7381,14 @@ impl fmt::Display for GetMethodResponseError {
             GetMethodResponseError::NotFound(ref cause) => write!(f, "{}", cause),
             GetMethodResponseError::TooManyRequests(ref cause) => write!(f, "{}", cause),
             GetMethodResponseError::Unauthorized(ref cause) => write!(f, "{}", cause),
+            GetMethodResponseError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetMethodResponseError {}
 /// Errors returned by GetModel
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetModelError {
     /// <p>The requested resource is not found. Make sure that the request URI is correct.</p>
     NotFound(String),
@@ -6836,6 +7396,8 @@ pub enum GetModelError {
     TooManyRequests(String),
     /// <p>The request is denied because the caller has insufficient permissions.</p>
     Unauthorized(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetModelError {
@@ -6843,16 +7405,16 @@ impl GetModelError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "NotFoundException" => {
-                    return RusotoError::Service(GetModelError::NotFound(err.msg))
+                    return RusotoError::service(GetModelError::NotFound(err.msg), &res)
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(GetModelError::TooManyRequests(err.msg))
+                    return RusotoError::service(GetModelError::TooManyRequests(err.msg), &res)
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(GetModelError::Unauthorized(err.msg))
+                    return RusotoError::service(GetModelError::Unauthorized(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetModelError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -6865,12 +7427,14 @@ impl fmt::Display for GetModelError {
             GetModelError::NotFound(ref cause) => write!(f, "{}", cause),
             GetModelError::TooManyRequests(ref cause) => write!(f, "{}", cause),
             GetModelError::Unauthorized(ref cause) => write!(f, "{}", cause),
+            GetModelError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetModelError {}
 /// Errors returned by GetModelTemplate
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetModelTemplateError {
     /// <p>The submitted request is not valid, for example, the input is incomplete or incorrect. See the accompanying error message for details.</p>
     BadRequest(String),
@@ -6880,6 +7444,8 @@ pub enum GetModelTemplateError {
     TooManyRequests(String),
     /// <p>The request is denied because the caller has insufficient permissions.</p>
     Unauthorized(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetModelTemplateError {
@@ -6887,19 +7453,22 @@ impl GetModelTemplateError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetModelTemplateError::BadRequest(err.msg))
+                    return RusotoError::service(GetModelTemplateError::BadRequest(err.msg), &res)
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetModelTemplateError::NotFound(err.msg))
+                    return RusotoError::service(GetModelTemplateError::NotFound(err.msg), &res)
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(GetModelTemplateError::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        GetModelTemplateError::TooManyRequests(err.msg),
+                        &res,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(GetModelTemplateError::Unauthorized(err.msg))
+                    return RusotoError::service(GetModelTemplateError::Unauthorized(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetModelTemplateError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -6913,12 +7482,14 @@ impl fmt::Display for GetModelTemplateError {
             GetModelTemplateError::NotFound(ref cause) => write!(f, "{}", cause),
             GetModelTemplateError::TooManyRequests(ref cause) => write!(f, "{}", cause),
             GetModelTemplateError::Unauthorized(ref cause) => write!(f, "{}", cause),
+            GetModelTemplateError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetModelTemplateError {}
 /// Errors returned by GetModels
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetModelsError {
     /// <p>The submitted request is not valid, for example, the input is incomplete or incorrect. See the accompanying error message for details.</p>
     BadRequest(String),
@@ -6928,6 +7499,8 @@ pub enum GetModelsError {
     TooManyRequests(String),
     /// <p>The request is denied because the caller has insufficient permissions.</p>
     Unauthorized(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetModelsError {
@@ -6935,19 +7508,19 @@ impl GetModelsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetModelsError::BadRequest(err.msg))
+                    return RusotoError::service(GetModelsError::BadRequest(err.msg), &res)
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetModelsError::NotFound(err.msg))
+                    return RusotoError::service(GetModelsError::NotFound(err.msg), &res)
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(GetModelsError::TooManyRequests(err.msg))
+                    return RusotoError::service(GetModelsError::TooManyRequests(err.msg), &res)
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(GetModelsError::Unauthorized(err.msg))
+                    return RusotoError::service(GetModelsError::Unauthorized(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetModelsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -6961,12 +7534,14 @@ impl fmt::Display for GetModelsError {
             GetModelsError::NotFound(ref cause) => write!(f, "{}", cause),
             GetModelsError::TooManyRequests(ref cause) => write!(f, "{}", cause),
             GetModelsError::Unauthorized(ref cause) => write!(f, "{}", cause),
+            GetModelsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetModelsError {}
 /// Errors returned by GetRequestValidator
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetRequestValidatorError {
     /// <p>The requested resource is not found. Make sure that the request URI is correct.</p>
     NotFound(String),
@@ -6974,6 +7549,8 @@ pub enum GetRequestValidatorError {
     TooManyRequests(String),
     /// <p>The request is denied because the caller has insufficient permissions.</p>
     Unauthorized(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetRequestValidatorError {
@@ -6981,16 +7558,22 @@ impl GetRequestValidatorError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "NotFoundException" => {
-                    return RusotoError::Service(GetRequestValidatorError::NotFound(err.msg))
+                    return RusotoError::service(GetRequestValidatorError::NotFound(err.msg), &res)
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(GetRequestValidatorError::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        GetRequestValidatorError::TooManyRequests(err.msg),
+                        &res,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(GetRequestValidatorError::Unauthorized(err.msg))
+                    return RusotoError::service(
+                        GetRequestValidatorError::Unauthorized(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetRequestValidatorError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -7003,12 +7586,14 @@ impl fmt::Display for GetRequestValidatorError {
             GetRequestValidatorError::NotFound(ref cause) => write!(f, "{}", cause),
             GetRequestValidatorError::TooManyRequests(ref cause) => write!(f, "{}", cause),
             GetRequestValidatorError::Unauthorized(ref cause) => write!(f, "{}", cause),
+            GetRequestValidatorError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetRequestValidatorError {}
 /// Errors returned by GetRequestValidators
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetRequestValidatorsError {
     /// <p>The submitted request is not valid, for example, the input is incomplete or incorrect. See the accompanying error message for details.</p>
     BadRequest(String),
@@ -7018,6 +7603,8 @@ pub enum GetRequestValidatorsError {
     TooManyRequests(String),
     /// <p>The request is denied because the caller has insufficient permissions.</p>
     Unauthorized(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetRequestValidatorsError {
@@ -7025,21 +7612,28 @@ impl GetRequestValidatorsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetRequestValidatorsError::BadRequest(err.msg))
+                    return RusotoError::service(
+                        GetRequestValidatorsError::BadRequest(err.msg),
+                        &res,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetRequestValidatorsError::NotFound(err.msg))
+                    return RusotoError::service(GetRequestValidatorsError::NotFound(err.msg), &res)
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(GetRequestValidatorsError::TooManyRequests(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetRequestValidatorsError::TooManyRequests(err.msg),
+                        &res,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(GetRequestValidatorsError::Unauthorized(err.msg))
+                    return RusotoError::service(
+                        GetRequestValidatorsError::Unauthorized(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetRequestValidatorsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -7053,12 +7647,14 @@ impl fmt::Display for GetRequestValidatorsError {
             GetRequestValidatorsError::NotFound(ref cause) => write!(f, "{}", cause),
             GetRequestValidatorsError::TooManyRequests(ref cause) => write!(f, "{}", cause),
             GetRequestValidatorsError::Unauthorized(ref cause) => write!(f, "{}", cause),
+            GetRequestValidatorsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetRequestValidatorsError {}
 /// Errors returned by GetResource
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetResourceError {
     /// <p>The requested resource is not found. Make sure that the request URI is correct.</p>
     NotFound(String),
@@ -7066,6 +7662,8 @@ pub enum GetResourceError {
     TooManyRequests(String),
     /// <p>The request is denied because the caller has insufficient permissions.</p>
     Unauthorized(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetResourceError {
@@ -7073,16 +7671,16 @@ impl GetResourceError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "NotFoundException" => {
-                    return RusotoError::Service(GetResourceError::NotFound(err.msg))
+                    return RusotoError::service(GetResourceError::NotFound(err.msg), &res)
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(GetResourceError::TooManyRequests(err.msg))
+                    return RusotoError::service(GetResourceError::TooManyRequests(err.msg), &res)
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(GetResourceError::Unauthorized(err.msg))
+                    return RusotoError::service(GetResourceError::Unauthorized(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetResourceError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -7095,12 +7693,14 @@ impl fmt::Display for GetResourceError {
             GetResourceError::NotFound(ref cause) => write!(f, "{}", cause),
             GetResourceError::TooManyRequests(ref cause) => write!(f, "{}", cause),
             GetResourceError::Unauthorized(ref cause) => write!(f, "{}", cause),
+            GetResourceError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetResourceError {}
 /// Errors returned by GetResources
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetResourcesError {
     /// <p>The submitted request is not valid, for example, the input is incomplete or incorrect. See the accompanying error message for details.</p>
     BadRequest(String),
@@ -7110,6 +7710,8 @@ pub enum GetResourcesError {
     TooManyRequests(String),
     /// <p>The request is denied because the caller has insufficient permissions.</p>
     Unauthorized(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetResourcesError {
@@ -7117,19 +7719,19 @@ impl GetResourcesError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetResourcesError::BadRequest(err.msg))
+                    return RusotoError::service(GetResourcesError::BadRequest(err.msg), &res)
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetResourcesError::NotFound(err.msg))
+                    return RusotoError::service(GetResourcesError::NotFound(err.msg), &res)
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(GetResourcesError::TooManyRequests(err.msg))
+                    return RusotoError::service(GetResourcesError::TooManyRequests(err.msg), &res)
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(GetResourcesError::Unauthorized(err.msg))
+                    return RusotoError::service(GetResourcesError::Unauthorized(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetResourcesError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -7143,12 +7745,14 @@ impl fmt::Display for GetResourcesError {
             GetResourcesError::NotFound(ref cause) => write!(f, "{}", cause),
             GetResourcesError::TooManyRequests(ref cause) => write!(f, "{}", cause),
             GetResourcesError::Unauthorized(ref cause) => write!(f, "{}", cause),
+            GetResourcesError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetResourcesError {}
 /// Errors returned by GetRestApi
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetRestApiError {
     /// <p>The requested resource is not found. Make sure that the request URI is correct.</p>
     NotFound(String),
@@ -7156,6 +7760,8 @@ pub enum GetRestApiError {
     TooManyRequests(String),
     /// <p>The request is denied because the caller has insufficient permissions.</p>
     Unauthorized(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetRestApiError {
@@ -7163,16 +7769,16 @@ impl GetRestApiError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "NotFoundException" => {
-                    return RusotoError::Service(GetRestApiError::NotFound(err.msg))
+                    return RusotoError::service(GetRestApiError::NotFound(err.msg), &res)
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(GetRestApiError::TooManyRequests(err.msg))
+                    return RusotoError::service(GetRestApiError::TooManyRequests(err.msg), &res)
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(GetRestApiError::Unauthorized(err.msg))
+                    return RusotoError::service(GetRestApiError::Unauthorized(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetRestApiError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -7185,12 +7791,14 @@ impl fmt::Display for GetRestApiError {
             GetRestApiError::NotFound(ref cause) => write!(f, "{}", cause),
             GetRestApiError::TooManyRequests(ref cause) => write!(f, "{}", cause),
             GetRestApiError::Unauthorized(ref cause) => write!(f, "{}", cause),
+            GetRestApiError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetRestApiError {}
 /// Errors returned by GetRestApis
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetRestApisError {
     /// <p>The submitted request is not valid, for example, the input is incomplete or incorrect. See the accompanying error message for details.</p>
     BadRequest(String),
@@ -7198,6 +7806,8 @@ pub enum GetRestApisError {
     TooManyRequests(String),
     /// <p>The request is denied because the caller has insufficient permissions.</p>
     Unauthorized(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetRestApisError {
@@ -7205,16 +7815,16 @@ impl GetRestApisError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetRestApisError::BadRequest(err.msg))
+                    return RusotoError::service(GetRestApisError::BadRequest(err.msg), &res)
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(GetRestApisError::TooManyRequests(err.msg))
+                    return RusotoError::service(GetRestApisError::TooManyRequests(err.msg), &res)
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(GetRestApisError::Unauthorized(err.msg))
+                    return RusotoError::service(GetRestApisError::Unauthorized(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetRestApisError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -7227,12 +7837,14 @@ impl fmt::Display for GetRestApisError {
             GetRestApisError::BadRequest(ref cause) => write!(f, "{}", cause),
             GetRestApisError::TooManyRequests(ref cause) => write!(f, "{}", cause),
             GetRestApisError::Unauthorized(ref cause) => write!(f, "{}", cause),
+            GetRestApisError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetRestApisError {}
 /// Errors returned by GetSdk
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetSdkError {
     /// <p>The submitted request is not valid, for example, the input is incomplete or incorrect. See the accompanying error message for details.</p>
     BadRequest(String),
@@ -7244,6 +7856,8 @@ pub enum GetSdkError {
     TooManyRequests(String),
     /// <p>The request is denied because the caller has insufficient permissions.</p>
     Unauthorized(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetSdkError {
@@ -7251,18 +7865,22 @@ impl GetSdkError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetSdkError::BadRequest(err.msg))
+                    return RusotoError::service(GetSdkError::BadRequest(err.msg), &res)
+                }
+                "ConflictException" => {
+                    return RusotoError::service(GetSdkError::Conflict(err.msg), &res)
+                }
+                "NotFoundException" => {
+                    return RusotoError::service(GetSdkError::NotFound(err.msg), &res)
                 }
-                "ConflictException" => return RusotoError::Service(GetSdkError::Conflict(err.msg)),
-                "NotFoundException" => return RusotoError::Service(GetSdkError::NotFound(err.msg)),
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(GetSdkError::TooManyRequests(err.msg))
+                    return RusotoError::service(GetSdkError::TooManyRequests(err.msg), &res)
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(GetSdkError::Unauthorized(err.msg))
+                    return RusotoError::service(GetSdkError::Unauthorized(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetSdkError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -7277,12 +7895,14 @@ impl fmt::Display for GetSdkError {
             GetSdkError::NotFound(ref cause) => write!(f, "{}", cause),
             GetSdkError::TooManyRequests(ref cause) => write!(f, "{}", cause),
             GetSdkError::Unauthorized(ref cause) => write!(f, "{}", cause),
+            GetSdkError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetSdkError {}
 /// Errors returned by GetSdkType
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetSdkTypeError {
     /// <p>The requested resource is not found. Make sure that the request URI is correct.</p>
     NotFound(String),
@@ -7290,6 +7910,8 @@ pub enum GetSdkTypeError {
     TooManyRequests(String),
     /// <p>The request is denied because the caller has insufficient permissions.</p>
     Unauthorized(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetSdkTypeError {
@@ -7297,16 +7919,16 @@ impl GetSdkTypeError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "NotFoundException" => {
-                    return RusotoError::Service(GetSdkTypeError::NotFound(err.msg))
+                    return RusotoError::service(GetSdkTypeError::NotFound(err.msg), &res)
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(GetSdkTypeError::TooManyRequests(err.msg))
+                    return RusotoError::service(GetSdkTypeError::TooManyRequests(err.msg), &res)
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(GetSdkTypeError::Unauthorized(err.msg))
+                    return RusotoError::service(GetSdkTypeError::Unauthorized(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetSdkTypeError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -7319,17 +7941,21 @@ impl fmt::Display for GetSdkTypeError {
             GetSdkTypeError::NotFound(ref cause) => write!(f, "{}", cause),
             GetSdkTypeError::TooManyRequests(ref cause) => write!(f, "{}", cause),
             GetSdkTypeError::Unauthorized(ref cause) => write!(f, "{}", cause),
+            GetSdkTypeError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetSdkTypeError {}
 /// Errors returned by GetSdkTypes
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetSdkTypesError {
     /// <p>The request has reached its throttling limit. Retry after the specified time period.</p>
     TooManyRequests(String),
     /// <p>The request is denied because the caller has insufficient permissions.</p>
     Unauthorized(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetSdkTypesError {
@@ -7337,13 +7963,13 @@ impl GetSdkTypesError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(GetSdkTypesError::TooManyRequests(err.msg))
+                    return RusotoError::service(GetSdkTypesError::TooManyRequests(err.msg), &res)
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(GetSdkTypesError::Unauthorized(err.msg))
+                    return RusotoError::service(GetSdkTypesError::Unauthorized(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetSdkTypesError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -7355,12 +7981,14 @@ impl fmt::Display for GetSdkTypesError {
         match *self {
             GetSdkTypesError::TooManyRequests(ref cause) => write!(f, "{}", cause),
             GetSdkTypesError::Unauthorized(ref cause) => write!(f, "{}", cause),
+            GetSdkTypesError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetSdkTypesError {}
 /// Errors returned by GetStage
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetStageError {
     /// <p>The requested resource is not found. Make sure that the request URI is correct.</p>
     NotFound(String),
@@ -7368,6 +7996,8 @@ pub enum GetStageError {
     TooManyRequests(String),
     /// <p>The request is denied because the caller has insufficient permissions.</p>
     Unauthorized(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetStageError {
@@ -7375,16 +8005,16 @@ impl GetStageError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "NotFoundException" => {
-                    return RusotoError::Service(GetStageError::NotFound(err.msg))
+                    return RusotoError::service(GetStageError::NotFound(err.msg), &res)
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(GetStageError::TooManyRequests(err.msg))
+                    return RusotoError::service(GetStageError::TooManyRequests(err.msg), &res)
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(GetStageError::Unauthorized(err.msg))
+                    return RusotoError::service(GetStageError::Unauthorized(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetStageError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -7397,12 +8027,14 @@ impl fmt::Display for GetStageError {
             GetStageError::NotFound(ref cause) => write!(f, "{}", cause),
             GetStageError::TooManyRequests(ref cause) => write!(f, "{}", cause),
             GetStageError::Unauthorized(ref cause) => write!(f, "{}", cause),
+            GetStageError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetStageError {}
 /// Errors returned by GetStages
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetStagesError {
     /// <p>The requested resource is not found. Make sure that the request URI is correct.</p>
     NotFound(String),
@@ -7410,6 +8042,8 @@ pub enum GetStagesError {
     TooManyRequests(String),
     /// <p>The request is denied because the caller has insufficient permissions.</p>
     Unauthorized(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetStagesError {
@@ -7417,16 +8051,16 @@ impl GetStagesError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "NotFoundException" => {
-                    return RusotoError::Service(GetStagesError::NotFound(err.msg))
+                    return RusotoError::service(GetStagesError::NotFound(err.msg), &res)
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(GetStagesError::TooManyRequests(err.msg))
+                    return RusotoError::service(GetStagesError::TooManyRequests(err.msg), &res)
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(GetStagesError::Unauthorized(err.msg))
+                    return RusotoError::service(GetStagesError::Unauthorized(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetStagesError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -7439,12 +8073,14 @@ impl fmt::Display for GetStagesError {
             GetStagesError::NotFound(ref cause) => write!(f, "{}", cause),
             GetStagesError::TooManyRequests(ref cause) => write!(f, "{}", cause),
             GetStagesError::Unauthorized(ref cause) => write!(f, "{}", cause),
+            GetStagesError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetStagesError {}
 /// Errors returned by GetTags
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetTagsError {
     /// <p>The submitted request is not valid, for example, the input is incomplete or incorrect. See the accompanying error message for details.</p>
     BadRequest(String),
@@ -7456,6 +8092,8 @@ pub enum GetTagsError {
     TooManyRequests(String),
     /// <p>The request is denied because the caller has insufficient permissions.</p>
     Unauthorized(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetTagsError {
@@ -7463,22 +8101,22 @@ impl GetTagsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetTagsError::BadRequest(err.msg))
+                    return RusotoError::service(GetTagsError::BadRequest(err.msg), &res)
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(GetTagsError::LimitExceeded(err.msg))
+                    return RusotoError::service(GetTagsError::LimitExceeded(err.msg), &res)
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetTagsError::NotFound(err.msg))
+                    return RusotoError::service(GetTagsError::NotFound(err.msg), &res)
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(GetTagsError::TooManyRequests(err.msg))
+                    return RusotoError::service(GetTagsError::TooManyRequests(err.msg), &res)
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(GetTagsError::Unauthorized(err.msg))
+                    return RusotoError::service(GetTagsError::Unauthorized(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetTagsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -7493,12 +8131,14 @@ impl fmt::Display for GetTagsError {
             GetTagsError::NotFound(ref cause) => write!(f, "{}", cause),
             GetTagsError::TooManyRequests(ref cause) => write!(f, "{}", cause),
             GetTagsError::Unauthorized(ref cause) => write!(f, "{}", cause),
+            GetTagsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetTagsError {}
 /// Errors returned by GetUsage
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetUsageError {
     /// <p>The submitted request is not valid, for example, the input is incomplete or incorrect. See the accompanying error message for details.</p>
     BadRequest(String),
@@ -7508,6 +8148,8 @@ pub enum GetUsageError {
     TooManyRequests(String),
     /// <p>The request is denied because the caller has insufficient permissions.</p>
     Unauthorized(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetUsageError {
@@ -7515,19 +8157,19 @@ impl GetUsageError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetUsageError::BadRequest(err.msg))
+                    return RusotoError::service(GetUsageError::BadRequest(err.msg), &res)
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetUsageError::NotFound(err.msg))
+                    return RusotoError::service(GetUsageError::NotFound(err.msg), &res)
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(GetUsageError::TooManyRequests(err.msg))
+                    return RusotoError::service(GetUsageError::TooManyRequests(err.msg), &res)
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(GetUsageError::Unauthorized(err.msg))
+                    return RusotoError::service(GetUsageError::Unauthorized(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetUsageError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -7541,12 +8183,14 @@ impl fmt::Display for GetUsageError {
             GetUsageError::NotFound(ref cause) => write!(f, "{}", cause),
             GetUsageError::TooManyRequests(ref cause) => write!(f, "{}", cause),
             GetUsageError::Unauthorized(ref cause) => write!(f, "{}", cause),
+            GetUsageError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetUsageError {}
 /// Errors returned by GetUsagePlan
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetUsagePlanError {
     /// <p>The submitted request is not valid, for example, the input is incomplete or incorrect. See the accompanying error message for details.</p>
     BadRequest(String),
@@ -7556,6 +8200,8 @@ pub enum GetUsagePlanError {
     TooManyRequests(String),
     /// <p>The request is denied because the caller has insufficient permissions.</p>
     Unauthorized(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetUsagePlanError {
@@ -7563,19 +8209,19 @@ impl GetUsagePlanError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetUsagePlanError::BadRequest(err.msg))
+                    return RusotoError::service(GetUsagePlanError::BadRequest(err.msg), &res)
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetUsagePlanError::NotFound(err.msg))
+                    return RusotoError::service(GetUsagePlanError::NotFound(err.msg), &res)
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(GetUsagePlanError::TooManyRequests(err.msg))
+                    return RusotoError::service(GetUsagePlanError::TooManyRequests(err.msg), &res)
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(GetUsagePlanError::Unauthorized(err.msg))
+                    return RusotoError::service(GetUsagePlanError::Unauthorized(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetUsagePlanError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -7589,12 +8235,14 @@ impl fmt::Display for GetUsagePlanError {
             GetUsagePlanError::NotFound(ref cause) => write!(f, "{}", cause),
             GetUsagePlanError::TooManyRequests(ref cause) => write!(f, "{}", cause),
             GetUsagePlanError::Unauthorized(ref cause) => write!(f, "{}", cause),
+            GetUsagePlanError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetUsagePlanError {}
 /// Errors returned by GetUsagePlanKey
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetUsagePlanKeyError {
     /// <p>The submitted request is not valid, for example, the input is incomplete or incorrect. See the accompanying error message for details.</p>
     BadRequest(String),
@@ -7604,6 +8252,8 @@ pub enum GetUsagePlanKeyError {
     TooManyRequests(String),
     /// <p>The request is denied because the caller has insufficient permissions.</p>
     Unauthorized(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetUsagePlanKeyError {
@@ -7611,19 +8261,22 @@ impl GetUsagePlanKeyError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetUsagePlanKeyError::BadRequest(err.msg))
+                    return RusotoError::service(GetUsagePlanKeyError::BadRequest(err.msg), &res)
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetUsagePlanKeyError::NotFound(err.msg))
+                    return RusotoError::service(GetUsagePlanKeyError::NotFound(err.msg), &res)
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(GetUsagePlanKeyError::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        GetUsagePlanKeyError::TooManyRequests(err.msg),
+                        &res,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(GetUsagePlanKeyError::Unauthorized(err.msg))
+                    return RusotoError::service(GetUsagePlanKeyError::Unauthorized(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetUsagePlanKeyError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -7637,12 +8290,14 @@ impl fmt::Display for GetUsagePlanKeyError {
             GetUsagePlanKeyError::NotFound(ref cause) => write!(f, "{}", cause),
             GetUsagePlanKeyError::TooManyRequests(ref cause) => write!(f, "{}", cause),
             GetUsagePlanKeyError::Unauthorized(ref cause) => write!(f, "{}", cause),
+            GetUsagePlanKeyError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetUsagePlanKeyError {}
 /// Errors returned by GetUsagePlanKeys
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetUsagePlanKeysError {
     /// <p>The submitted request is not valid, for example, the input is incomplete or incorrect. See the accompanying error message for details.</p>
     BadRequest(String),
@@ -7652,6 +8307,8 @@ pub enum GetUsagePlanKeysError {
     TooManyRequests(String),
     /// <p>The request is denied because the caller has insufficient permissions.</p>
     Unauthorized(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetUsagePlanKeysError {
@@ -7659,19 +8316,22 @@ impl GetUsagePlanKeysError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetUsagePlanKeysError::BadRequest(err.msg))
+                    return RusotoError::service(GetUsagePlanKeysError::BadRequest(err.msg), &res)
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetUsagePlanKeysError::NotFound(err.msg))
+                    return RusotoError::service(GetUsagePlanKeysError::NotFound(err.msg), &res)
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(GetUsagePlanKeysError::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        GetUsagePlanKeysError::TooManyRequests(err.msg),
+                        &res,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(GetUsagePlanKeysError::Unauthorized(err.msg))
+                    return RusotoError::service(GetUsagePlanKeysError::Unauthorized(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetUsagePlanKeysError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -7685,12 +8345,14 @@ impl fmt::Display for GetUsagePlanKeysError {
             GetUsagePlanKeysError::NotFound(ref cause) => write!(f, "{}", cause),
             GetUsagePlanKeysError::TooManyRequests(ref cause) => write!(f, "{}", cause),
             GetUsagePlanKeysError::Unauthorized(ref cause) => write!(f, "{}", cause),
+            GetUsagePlanKeysError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetUsagePlanKeysError {}
 /// Errors returned by GetUsagePlans
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetUsagePlansError {
     /// <p>The submitted request is not valid, for example, the input is incomplete or incorrect. See the accompanying error message for details.</p>
     BadRequest(String),
@@ -7702,6 +8364,8 @@ pub enum GetUsagePlansError {
     TooManyRequests(String),
     /// <p>The request is denied because the caller has insufficient permissions.</p>
     Unauthorized(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetUsagePlansError {
@@ -7709,22 +8373,22 @@ impl GetUsagePlansError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetUsagePlansError::BadRequest(err.msg))
+                    return RusotoError::service(GetUsagePlansError::BadRequest(err.msg), &res)
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(GetUsagePlansError::Conflict(err.msg))
+                    return RusotoError::service(GetUsagePlansError::Conflict(err.msg), &res)
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetUsagePlansError::NotFound(err.msg))
+                    return RusotoError::service(GetUsagePlansError::NotFound(err.msg), &res)
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(GetUsagePlansError::TooManyRequests(err.msg))
+                    return RusotoError::service(GetUsagePlansError::TooManyRequests(err.msg), &res)
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(GetUsagePlansError::Unauthorized(err.msg))
+                    return RusotoError::service(GetUsagePlansError::Unauthorized(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetUsagePlansError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -7739,12 +8403,14 @@ impl fmt::Display for GetUsagePlansError {
             GetUsagePlansError::NotFound(ref cause) => write!(f, "{}", cause),
             GetUsagePlansError::TooManyRequests(ref cause) => write!(f, "{}", cause),
             GetUsagePlansError::Unauthorized(ref cause) => write!(f, "{}", cause),
+            GetUsagePlansError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetUsagePlansError {}
 /// Errors returned by GetVpcLink
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetVpcLinkError {
     /// <p>The requested resource is not found. Make sure that the request URI is correct.</p>
     NotFound(String),
@@ -7752,6 +8418,8 @@ pub enum GetVpcLinkError {
     TooManyRequests(String),
     /// <p>The request is denied because the caller has insufficient permissions.</p>
     Unauthorized(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetVpcLinkError {
@@ -7759,16 +8427,16 @@ impl GetVpcLinkError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "NotFoundException" => {
-                    return RusotoError::Service(GetVpcLinkError::NotFound(err.msg))
+                    return RusotoError::service(GetVpcLinkError::NotFound(err.msg), &res)
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(GetVpcLinkError::TooManyRequests(err.msg))
+                    return RusotoError::service(GetVpcLinkError::TooManyRequests(err.msg), &res)
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(GetVpcLinkError::Unauthorized(err.msg))
+                    return RusotoError::service(GetVpcLinkError::Unauthorized(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetVpcLinkError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -7781,12 +8449,14 @@ impl fmt::Display for GetVpcLinkError {
             GetVpcLinkError::NotFound(ref cause) => write!(f, "{}", cause),
             GetVpcLinkError::TooManyRequests(ref cause) => write!(f, "{}", cause),
             GetVpcLinkError::Unauthorized(ref cause) => write!(f, "{}", cause),
+            GetVpcLinkError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetVpcLinkError {}
 /// Errors returned by GetVpcLinks
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetVpcLinksError {
     /// <p>The submitted request is not valid, for example, the input is incomplete or incorrect. See the accompanying error message for details.</p>
     BadRequest(String),
@@ -7794,6 +8464,8 @@ pub enum GetVpcLinksError {
     TooManyRequests(String),
     /// <p>The request is denied because the caller has insufficient permissions.</p>
     Unauthorized(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetVpcLinksError {
@@ -7801,16 +8473,16 @@ impl GetVpcLinksError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetVpcLinksError::BadRequest(err.msg))
+                    return RusotoError::service(GetVpcLinksError::BadRequest(err.msg), &res)
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(GetVpcLinksError::TooManyRequests(err.msg))
+                    return RusotoError::service(GetVpcLinksError::TooManyRequests(err.msg), &res)
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(GetVpcLinksError::Unauthorized(err.msg))
+                    return RusotoError::service(GetVpcLinksError::Unauthorized(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetVpcLinksError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -7823,12 +8495,14 @@ impl fmt::Display for GetVpcLinksError {
             GetVpcLinksError::BadRequest(ref cause) => write!(f, "{}", cause),
             GetVpcLinksError::TooManyRequests(ref cause) => write!(f, "{}", cause),
             GetVpcLinksError::Unauthorized(ref cause) => write!(f, "{}", cause),
+            GetVpcLinksError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetVpcLinksError {}
 /// Errors returned by ImportApiKeys
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ImportApiKeysError {
     /// <p>The submitted request is not valid, for example, the input is incomplete or incorrect. See the accompanying error message for details.</p>
     BadRequest(String),
@@ -7842,6 +8516,8 @@ pub enum ImportApiKeysError {
     TooManyRequests(String),
     /// <p>The request is denied because the caller has insufficient permissions.</p>
     Unauthorized(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ImportApiKeysError {
@@ -7849,25 +8525,25 @@ impl ImportApiKeysError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(ImportApiKeysError::BadRequest(err.msg))
+                    return RusotoError::service(ImportApiKeysError::BadRequest(err.msg), &res)
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(ImportApiKeysError::Conflict(err.msg))
+                    return RusotoError::service(ImportApiKeysError::Conflict(err.msg), &res)
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(ImportApiKeysError::LimitExceeded(err.msg))
+                    return RusotoError::service(ImportApiKeysError::LimitExceeded(err.msg), &res)
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(ImportApiKeysError::NotFound(err.msg))
+                    return RusotoError::service(ImportApiKeysError::NotFound(err.msg), &res)
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(ImportApiKeysError::TooManyRequests(err.msg))
+                    return RusotoError::service(ImportApiKeysError::TooManyRequests(err.msg), &res)
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(ImportApiKeysError::Unauthorized(err.msg))
+                    return RusotoError::service(ImportApiKeysError::Unauthorized(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ImportApiKeysError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -7883,12 +8559,14 @@ impl fmt::Display for ImportApiKeysError {
             ImportApiKeysError::NotFound(ref cause) => write!(f, "{}", cause),
             ImportApiKeysError::TooManyRequests(ref cause) => write!(f, "{}", cause),
             ImportApiKeysError::Unauthorized(ref cause) => write!(f, "{}", cause),
+            ImportApiKeysError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ImportApiKeysError {}
 /// Errors returned by ImportDocumentationParts
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ImportDocumentationPartsError {
     /// <p>The submitted request is not valid, for example, the input is incomplete or incorrect. See the accompanying error message for details.</p>
     BadRequest(String),
@@ -7900,6 +8578,8 @@ pub enum ImportDocumentationPartsError {
     TooManyRequests(String),
     /// <p>The request is denied because the caller has insufficient permissions.</p>
     Unauthorized(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ImportDocumentationPartsError {
@@ -7907,28 +8587,37 @@ impl ImportDocumentationPartsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(ImportDocumentationPartsError::BadRequest(err.msg))
+                    return RusotoError::service(
+                        ImportDocumentationPartsError::BadRequest(err.msg),
+                        &res,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(ImportDocumentationPartsError::LimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ImportDocumentationPartsError::LimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(ImportDocumentationPartsError::NotFound(err.msg))
+                    return RusotoError::service(
+                        ImportDocumentationPartsError::NotFound(err.msg),
+                        &res,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(ImportDocumentationPartsError::TooManyRequests(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ImportDocumentationPartsError::TooManyRequests(err.msg),
+                        &res,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(ImportDocumentationPartsError::Unauthorized(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ImportDocumentationPartsError::Unauthorized(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ImportDocumentationPartsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -7943,12 +8632,14 @@ impl fmt::Display for ImportDocumentationPartsError {
             ImportDocumentationPartsError::NotFound(ref cause) => write!(f, "{}", cause),
             ImportDocumentationPartsError::TooManyRequests(ref cause) => write!(f, "{}", cause),
             ImportDocumentationPartsError::Unauthorized(ref cause) => write!(f, "{}", cause),
+            ImportDocumentationPartsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ImportDocumentationPartsError {}
 /// Errors returned by ImportRestApi
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ImportRestApiError {
     /// <p>The submitted request is not valid, for example, the input is incomplete or incorrect. See the accompanying error message for details.</p>
     BadRequest(String),
@@ -7960,6 +8651,8 @@ pub enum ImportRestApiError {
     TooManyRequests(String),
     /// <p>The request is denied because the caller has insufficient permissions.</p>
     Unauthorized(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ImportRestApiError {
@@ -7967,22 +8660,22 @@ impl ImportRestApiError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(ImportRestApiError::BadRequest(err.msg))
+                    return RusotoError::service(ImportRestApiError::BadRequest(err.msg), &res)
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(ImportRestApiError::Conflict(err.msg))
+                    return RusotoError::service(ImportRestApiError::Conflict(err.msg), &res)
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(ImportRestApiError::LimitExceeded(err.msg))
+                    return RusotoError::service(ImportRestApiError::LimitExceeded(err.msg), &res)
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(ImportRestApiError::TooManyRequests(err.msg))
+                    return RusotoError::service(ImportRestApiError::TooManyRequests(err.msg), &res)
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(ImportRestApiError::Unauthorized(err.msg))
+                    return RusotoError::service(ImportRestApiError::Unauthorized(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ImportRestApiError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -7997,12 +8690,14 @@ impl fmt::Display for ImportRestApiError {
             ImportRestApiError::LimitExceeded(ref cause) => write!(f, "{}", cause),
             ImportRestApiError::TooManyRequests(ref cause) => write!(f, "{}", cause),
             ImportRestApiError::Unauthorized(ref cause) => write!(f, "{}", cause),
+            ImportRestApiError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ImportRestApiError {}
 /// Errors returned by PutGatewayResponse
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum PutGatewayResponseError {
     /// <p>The submitted request is not valid, for example, the input is incomplete or incorrect. See the accompanying error message for details.</p>
     BadRequest(String),
@@ -8014,6 +8709,8 @@ pub enum PutGatewayResponseError {
     TooManyRequests(String),
     /// <p>The request is denied because the caller has insufficient permissions.</p>
     Unauthorized(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl PutGatewayResponseError {
@@ -8021,22 +8718,31 @@ impl PutGatewayResponseError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(PutGatewayResponseError::BadRequest(err.msg))
+                    return RusotoError::service(PutGatewayResponseError::BadRequest(err.msg), &res)
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(PutGatewayResponseError::LimitExceeded(err.msg))
+                    return RusotoError::service(
+                        PutGatewayResponseError::LimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(PutGatewayResponseError::NotFound(err.msg))
+                    return RusotoError::service(PutGatewayResponseError::NotFound(err.msg), &res)
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(PutGatewayResponseError::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        PutGatewayResponseError::TooManyRequests(err.msg),
+                        &res,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(PutGatewayResponseError::Unauthorized(err.msg))
+                    return RusotoError::service(
+                        PutGatewayResponseError::Unauthorized(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(PutGatewayResponseError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -8051,12 +8757,14 @@ impl fmt::Display for PutGatewayResponseError {
             PutGatewayResponseError::NotFound(ref cause) => write!(f, "{}", cause),
             PutGatewayResponseError::TooManyRequests(ref cause) => write!(f, "{}", cause),
             PutGatewayResponseError::Unauthorized(ref cause) => write!(f, "{}", cause),
+            PutGatewayResponseError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for PutGatewayResponseError {}
 /// Errors returned by PutIntegration
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum PutIntegrationError {
     /// <p>The submitted request is not valid, for example, the input is incomplete or incorrect. See the accompanying error message for details.</p>
     BadRequest(String),
@@ -8068,6 +8776,8 @@ pub enum PutIntegrationError {
     TooManyRequests(String),
     /// <p>The request is denied because the caller has insufficient permissions.</p>
     Unauthorized(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl PutIntegrationError {
@@ -8075,22 +8785,25 @@ impl PutIntegrationError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(PutIntegrationError::BadRequest(err.msg))
+                    return RusotoError::service(PutIntegrationError::BadRequest(err.msg), &res)
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(PutIntegrationError::Conflict(err.msg))
+                    return RusotoError::service(PutIntegrationError::Conflict(err.msg), &res)
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(PutIntegrationError::NotFound(err.msg))
+                    return RusotoError::service(PutIntegrationError::NotFound(err.msg), &res)
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(PutIntegrationError::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        PutIntegrationError::TooManyRequests(err.msg),
+                        &res,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(PutIntegrationError::Unauthorized(err.msg))
+                    return RusotoError::service(PutIntegrationError::Unauthorized(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(PutIntegrationError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -8105,12 +8818,14 @@ impl fmt::Display for PutIntegrationError {
             PutIntegrationError::NotFound(ref cause) => write!(f, "{}", cause),
             PutIntegrationError::TooManyRequests(ref cause) => write!(f, "{}", cause),
             PutIntegrationError::Unauthorized(ref cause) => write!(f, "{}", cause),
+            PutIntegrationError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for PutIntegrationError {}
 /// Errors returned by PutIntegrationResponse
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum PutIntegrationResponseError {
     /// <p>The submitted request is not valid, for example, the input is incomplete or incorrect. See the accompanying error message for details.</p>
     BadRequest(String),
@@ -8124,6 +8839,8 @@ pub enum PutIntegrationResponseError {
     TooManyRequests(String),
     /// <p>The request is denied because the caller has insufficient permissions.</p>
     Unauthorized(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl PutIntegrationResponseError {
@@ -8131,29 +8848,43 @@ impl PutIntegrationResponseError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(PutIntegrationResponseError::BadRequest(err.msg))
+                    return RusotoError::service(
+                        PutIntegrationResponseError::BadRequest(err.msg),
+                        &res,
+                    )
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(PutIntegrationResponseError::Conflict(err.msg))
+                    return RusotoError::service(
+                        PutIntegrationResponseError::Conflict(err.msg),
+                        &res,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(PutIntegrationResponseError::LimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        PutIntegrationResponseError::LimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(PutIntegrationResponseError::NotFound(err.msg))
+                    return RusotoError::service(
+                        PutIntegrationResponseError::NotFound(err.msg),
+                        &res,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(PutIntegrationResponseError::TooManyRequests(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        PutIntegrationResponseError::TooManyRequests(err.msg),
+                        &res,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(PutIntegrationResponseError::Unauthorized(err.msg))
+                    return RusotoError::service(
+                        PutIntegrationResponseError::Unauthorized(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(PutIntegrationResponseError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -8169,12 +8900,14 @@ impl fmt::Display for PutIntegrationResponseError {
             PutIntegrationResponseError::NotFound(ref cause) => write!(f, "{}", cause),
             PutIntegrationResponseError::TooManyRequests(ref cause) => write!(f, "{}", cause),
             PutIntegrationResponseError::Unauthorized(ref cause) => write!(f, "{}", cause),
+            PutIntegrationResponseError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for PutIntegrationResponseError {}
 /// Errors returned by PutMethod
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum PutMethodError {
     /// <p>The submitted request is not valid, for example, the input is incomplete or incorrect. See the accompanying error message for details.</p>
     BadRequest(String),
@@ -8188,6 +8921,8 @@ pub enum PutMethodError {
     TooManyRequests(String),
     /// <p>The request is denied because the caller has insufficient permissions.</p>
     Unauthorized(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl PutMethodError {
@@ -8195,25 +8930,25 @@ impl PutMethodError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(PutMethodError::BadRequest(err.msg))
+                    return RusotoError::service(PutMethodError::BadRequest(err.msg), &res)
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(PutMethodError::Conflict(err.msg))
+                    return RusotoError::service(PutMethodError::Conflict(err.msg), &res)
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(PutMethodError::LimitExceeded(err.msg))
+                    return RusotoError::service(PutMethodError::LimitExceeded(err.msg), &res)
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(PutMethodError::NotFound(err.msg))
+                    return RusotoError::service(PutMethodError::NotFound(err.msg), &res)
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(PutMethodError::TooManyRequests(err.msg))
+                    return RusotoError::service(PutMethodError::TooManyRequests(err.msg), &res)
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(PutMethodError::Unauthorized(err.msg))
+                    return RusotoError::service(PutMethodError::Unauthorized(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(PutMethodError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -8229,12 +8964,14 @@ impl fmt::Display for PutMethodError {
             PutMethodError::NotFound(ref cause) => write!(f, "{}", cause),
             PutMethodError::TooManyRequests(ref cause) => write!(f, "{}", cause),
             PutMethodError::Unauthorized(ref cause) => write!(f, "{}", cause),
+            PutMethodError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for PutMethodError {}
 /// Errors returned by PutMethodResponse
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum PutMethodResponseError {
     /// <p>The submitted request is not valid, for example, the input is incomplete or incorrect. See the accompanying error message for details.</p>
     BadRequest(String),
@@ -8248,6 +8985,8 @@ pub enum PutMethodResponseError {
     TooManyRequests(String),
     /// <p>The request is denied because the caller has insufficient permissions.</p>
     Unauthorized(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl PutMethodResponseError {
@@ -8255,25 +8994,34 @@ impl PutMethodResponseError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(PutMethodResponseError::BadRequest(err.msg))
+                    return RusotoError::service(PutMethodResponseError::BadRequest(err.msg), &res)
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(PutMethodResponseError::Conflict(err.msg))
+                    return RusotoError::service(PutMethodResponseError::Conflict(err.msg), &res)
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(PutMethodResponseError::LimitExceeded(err.msg))
+                    return RusotoError::service(
+                        PutMethodResponseError::LimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(PutMethodResponseError::NotFound(err.msg))
+                    return RusotoError::service(PutMethodResponseError::NotFound(err.msg), &res)
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(PutMethodResponseError::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        PutMethodResponseError::TooManyRequests(err.msg),
+                        &res,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(PutMethodResponseError::Unauthorized(err.msg))
+                    return RusotoError::service(
+                        PutMethodResponseError::Unauthorized(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(PutMethodResponseError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -8289,12 +9037,14 @@ impl fmt::Display for PutMethodResponseError {
             PutMethodResponseError::NotFound(ref cause) => write!(f, "{}", cause),
             PutMethodResponseError::TooManyRequests(ref cause) => write!(f, "{}", cause),
             PutMethodResponseError::Unauthorized(ref cause) => write!(f, "{}", cause),
+            PutMethodResponseError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for PutMethodResponseError {}
 /// Errors returned by PutRestApi
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum PutRestApiError {
     /// <p>The submitted request is not valid, for example, the input is incomplete or incorrect. See the accompanying error message for details.</p>
     BadRequest(String),
@@ -8308,6 +9058,8 @@ pub enum PutRestApiError {
     TooManyRequests(String),
     /// <p>The request is denied because the caller has insufficient permissions.</p>
     Unauthorized(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl PutRestApiError {
@@ -8315,25 +9067,25 @@ impl PutRestApiError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(PutRestApiError::BadRequest(err.msg))
+                    return RusotoError::service(PutRestApiError::BadRequest(err.msg), &res)
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(PutRestApiError::Conflict(err.msg))
+                    return RusotoError::service(PutRestApiError::Conflict(err.msg), &res)
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(PutRestApiError::LimitExceeded(err.msg))
+                    return RusotoError::service(PutRestApiError::LimitExceeded(err.msg), &res)
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(PutRestApiError::NotFound(err.msg))
+                    return RusotoError::service(PutRestApiError::NotFound(err.msg), &res)
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(PutRestApiError::TooManyRequests(err.msg))
+                    return RusotoError::service(PutRestApiError::TooManyRequests(err.msg), &res)
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(PutRestApiError::Unauthorized(err.msg))
+                    return RusotoError::service(PutRestApiError::Unauthorized(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(PutRestApiError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -8349,12 +9101,14 @@ impl fmt::Display for PutRestApiError {
             PutRestApiError::NotFound(ref cause) => write!(f, "{}", cause),
             PutRestApiError::TooManyRequests(ref cause) => write!(f, "{}", cause),
             PutRestApiError::Unauthorized(ref cause) => write!(f, "{}", cause),
+            PutRestApiError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for PutRestApiError {}
 /// Errors returned by TagResource
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum TagResourceError {
     /// <p>The submitted request is not valid, for example, the input is incomplete or incorrect. See the accompanying error message for details.</p>
     BadRequest(String),
@@ -8368,6 +9122,8 @@ pub enum TagResourceError {
     TooManyRequests(String),
     /// <p>The request is denied because the caller has insufficient permissions.</p>
     Unauthorized(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl TagResourceError {
@@ -8375,25 +9131,25 @@ impl TagResourceError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(TagResourceError::BadRequest(err.msg))
+                    return RusotoError::service(TagResourceError::BadRequest(err.msg), &res)
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(TagResourceError::Conflict(err.msg))
+                    return RusotoError::service(TagResourceError::Conflict(err.msg), &res)
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(TagResourceError::LimitExceeded(err.msg))
+                    return RusotoError::service(TagResourceError::LimitExceeded(err.msg), &res)
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(TagResourceError::NotFound(err.msg))
+                    return RusotoError::service(TagResourceError::NotFound(err.msg), &res)
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(TagResourceError::TooManyRequests(err.msg))
+                    return RusotoError::service(TagResourceError::TooManyRequests(err.msg), &res)
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(TagResourceError::Unauthorized(err.msg))
+                    return RusotoError::service(TagResourceError::Unauthorized(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(TagResourceError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -8409,12 +9165,14 @@ impl fmt::Display for TagResourceError {
             TagResourceError::NotFound(ref cause) => write!(f, "{}", cause),
             TagResourceError::TooManyRequests(ref cause) => write!(f, "{}", cause),
             TagResourceError::Unauthorized(ref cause) => write!(f, "{}", cause),
+            TagResourceError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for TagResourceError {}
 /// Errors returned by TestInvokeAuthorizer
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum TestInvokeAuthorizerError {
     /// <p>The submitted request is not valid, for example, the input is incomplete or incorrect. See the accompanying error message for details.</p>
     BadRequest(String),
@@ -8424,6 +9182,8 @@ pub enum TestInvokeAuthorizerError {
     TooManyRequests(String),
     /// <p>The request is denied because the caller has insufficient permissions.</p>
     Unauthorized(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl TestInvokeAuthorizerError {
@@ -8431,21 +9191,28 @@ impl TestInvokeAuthorizerError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(TestInvokeAuthorizerError::BadRequest(err.msg))
+                    return RusotoError::service(
+                        TestInvokeAuthorizerError::BadRequest(err.msg),
+                        &res,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(TestInvokeAuthorizerError::NotFound(err.msg))
+                    return RusotoError::service(TestInvokeAuthorizerError::NotFound(err.msg), &res)
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(TestInvokeAuthorizerError::TooManyRequests(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        TestInvokeAuthorizerError::TooManyRequests(err.msg),
+                        &res,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(TestInvokeAuthorizerError::Unauthorized(err.msg))
+                    return RusotoError::service(
+                        TestInvokeAuthorizerError::Unauthorized(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(TestInvokeAuthorizerError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -8459,12 +9226,14 @@ impl fmt::Display for TestInvokeAuthorizerError {
             TestInvokeAuthorizerError::NotFound(ref cause) => write!(f, "{}", cause),
             TestInvokeAuthorizerError::TooManyRequests(ref cause) => write!(f, "{}", cause),
             TestInvokeAuthorizerError::Unauthorized(ref cause) => write!(f, "{}", cause),
+            TestInvokeAuthorizerError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for TestInvokeAuthorizerError {}
 /// Errors returned by TestInvokeMethod
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum TestInvokeMethodError {
     /// <p>The submitted request is not valid, for example, the input is incomplete or incorrect. See the accompanying error message for details.</p>
     BadRequest(String),
@@ -8474,6 +9243,8 @@ pub enum TestInvokeMethodError {
     TooManyRequests(String),
     /// <p>The request is denied because the caller has insufficient permissions.</p>
     Unauthorized(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl TestInvokeMethodError {
@@ -8481,19 +9252,22 @@ impl TestInvokeMethodError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(TestInvokeMethodError::BadRequest(err.msg))
+                    return RusotoError::service(TestInvokeMethodError::BadRequest(err.msg), &res)
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(TestInvokeMethodError::NotFound(err.msg))
+                    return RusotoError::service(TestInvokeMethodError::NotFound(err.msg), &res)
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(TestInvokeMethodError::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        TestInvokeMethodError::TooManyRequests(err.msg),
+                        &res,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(TestInvokeMethodError::Unauthorized(err.msg))
+                    return RusotoError::service(TestInvokeMethodError::Unauthorized(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(TestInvokeMethodError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -8507,12 +9281,14 @@ impl fmt::Display for TestInvokeMethodError {
             TestInvokeMethodError::NotFound(ref cause) => write!(f, "{}", cause),
             TestInvokeMethodError::TooManyRequests(ref cause) => write!(f, "{}", cause),
             TestInvokeMethodError::Unauthorized(ref cause) => write!(f, "{}", cause),
+            TestInvokeMethodError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for TestInvokeMethodError {}
 /// Errors returned by UntagResource
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UntagResourceError {
     /// <p>The submitted request is not valid, for example, the input is incomplete or incorrect. See the accompanying error message for details.</p>
     BadRequest(String),
@@ -8524,6 +9300,8 @@ pub enum UntagResourceError {
     TooManyRequests(String),
     /// <p>The request is denied because the caller has insufficient permissions.</p>
     Unauthorized(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UntagResourceError {
@@ -8531,22 +9309,22 @@ impl UntagResourceError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(UntagResourceError::BadRequest(err.msg))
+                    return RusotoError::service(UntagResourceError::BadRequest(err.msg), &res)
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(UntagResourceError::Conflict(err.msg))
+                    return RusotoError::service(UntagResourceError::Conflict(err.msg), &res)
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(UntagResourceError::NotFound(err.msg))
+                    return RusotoError::service(UntagResourceError::NotFound(err.msg), &res)
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(UntagResourceError::TooManyRequests(err.msg))
+                    return RusotoError::service(UntagResourceError::TooManyRequests(err.msg), &res)
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(UntagResourceError::Unauthorized(err.msg))
+                    return RusotoError::service(UntagResourceError::Unauthorized(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UntagResourceError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -8561,12 +9339,14 @@ impl fmt::Display for UntagResourceError {
             UntagResourceError::NotFound(ref cause) => write!(f, "{}", cause),
             UntagResourceError::TooManyRequests(ref cause) => write!(f, "{}", cause),
             UntagResourceError::Unauthorized(ref cause) => write!(f, "{}", cause),
+            UntagResourceError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for UntagResourceError {}
 /// Errors returned by UpdateAccount
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdateAccountError {
     /// <p>The submitted request is not valid, for example, the input is incomplete or incorrect. See the accompanying error message for details.</p>
     BadRequest(String),
@@ -8576,6 +9356,8 @@ pub enum UpdateAccountError {
     TooManyRequests(String),
     /// <p>The request is denied because the caller has insufficient permissions.</p>
     Unauthorized(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdateAccountError {
@@ -8583,19 +9365,19 @@ impl UpdateAccountError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(UpdateAccountError::BadRequest(err.msg))
+                    return RusotoError::service(UpdateAccountError::BadRequest(err.msg), &res)
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(UpdateAccountError::NotFound(err.msg))
+                    return RusotoError::service(UpdateAccountError::NotFound(err.msg), &res)
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(UpdateAccountError::TooManyRequests(err.msg))
+                    return RusotoError::service(UpdateAccountError::TooManyRequests(err.msg), &res)
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(UpdateAccountError::Unauthorized(err.msg))
+                    return RusotoError::service(UpdateAccountError::Unauthorized(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UpdateAccountError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -8609,12 +9391,14 @@ impl fmt::Display for UpdateAccountError {
             UpdateAccountError::NotFound(ref cause) => write!(f, "{}", cause),
             UpdateAccountError::TooManyRequests(ref cause) => write!(f, "{}", cause),
             UpdateAccountError::Unauthorized(ref cause) => write!(f, "{}", cause),
+            UpdateAccountError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for UpdateAccountError {}
 /// Errors returned by UpdateApiKey
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdateApiKeyError {
     /// <p>The submitted request is not valid, for example, the input is incomplete or incorrect. See the accompanying error message for details.</p>
     BadRequest(String),
@@ -8626,6 +9410,8 @@ pub enum UpdateApiKeyError {
     TooManyRequests(String),
     /// <p>The request is denied because the caller has insufficient permissions.</p>
     Unauthorized(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdateApiKeyError {
@@ -8633,22 +9419,22 @@ impl UpdateApiKeyError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(UpdateApiKeyError::BadRequest(err.msg))
+                    return RusotoError::service(UpdateApiKeyError::BadRequest(err.msg), &res)
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(UpdateApiKeyError::Conflict(err.msg))
+                    return RusotoError::service(UpdateApiKeyError::Conflict(err.msg), &res)
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(UpdateApiKeyError::NotFound(err.msg))
+                    return RusotoError::service(UpdateApiKeyError::NotFound(err.msg), &res)
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(UpdateApiKeyError::TooManyRequests(err.msg))
+                    return RusotoError::service(UpdateApiKeyError::TooManyRequests(err.msg), &res)
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(UpdateApiKeyError::Unauthorized(err.msg))
+                    return RusotoError::service(UpdateApiKeyError::Unauthorized(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UpdateApiKeyError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -8663,12 +9449,14 @@ impl fmt::Display for UpdateApiKeyError {
             UpdateApiKeyError::NotFound(ref cause) => write!(f, "{}", cause),
             UpdateApiKeyError::TooManyRequests(ref cause) => write!(f, "{}", cause),
             UpdateApiKeyError::Unauthorized(ref cause) => write!(f, "{}", cause),
+            UpdateApiKeyError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for UpdateApiKeyError {}
 /// Errors returned by UpdateAuthorizer
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdateAuthorizerError {
     /// <p>The submitted request is not valid, for example, the input is incomplete or incorrect. See the accompanying error message for details.</p>
     BadRequest(String),
@@ -8678,6 +9466,8 @@ pub enum UpdateAuthorizerError {
     TooManyRequests(String),
     /// <p>The request is denied because the caller has insufficient permissions.</p>
     Unauthorized(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdateAuthorizerError {
@@ -8685,19 +9475,22 @@ impl UpdateAuthorizerError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(UpdateAuthorizerError::BadRequest(err.msg))
+                    return RusotoError::service(UpdateAuthorizerError::BadRequest(err.msg), &res)
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(UpdateAuthorizerError::NotFound(err.msg))
+                    return RusotoError::service(UpdateAuthorizerError::NotFound(err.msg), &res)
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(UpdateAuthorizerError::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        UpdateAuthorizerError::TooManyRequests(err.msg),
+                        &res,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(UpdateAuthorizerError::Unauthorized(err.msg))
+                    return RusotoError::service(UpdateAuthorizerError::Unauthorized(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UpdateAuthorizerError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -8711,12 +9504,14 @@ impl fmt::Display for UpdateAuthorizerError {
             UpdateAuthorizerError::NotFound(ref cause) => write!(f, "{}", cause),
             UpdateAuthorizerError::TooManyRequests(ref cause) => write!(f, "{}", cause),
             UpdateAuthorizerError::Unauthorized(ref cause) => write!(f, "{}", cause),
+            UpdateAuthorizerError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for UpdateAuthorizerError {}
 /// Errors returned by UpdateBasePathMapping
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdateBasePathMappingError {
     /// <p>The submitted request is not valid, for example, the input is incomplete or incorrect. See the accompanying error message for details.</p>
     BadRequest(String),
@@ -8728,6 +9523,8 @@ pub enum UpdateBasePathMappingError {
     TooManyRequests(String),
     /// <p>The request is denied because the caller has insufficient permissions.</p>
     Unauthorized(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdateBasePathMappingError {
@@ -8735,24 +9532,37 @@ impl UpdateBasePathMappingError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(UpdateBasePathMappingError::BadRequest(err.msg))
+                    return RusotoError::service(
+                        UpdateBasePathMappingError::BadRequest(err.msg),
+                        &res,
+                    )
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(UpdateBasePathMappingError::Conflict(err.msg))
+                    return RusotoError::service(
+                        UpdateBasePathMappingError::Conflict(err.msg),
+                        &res,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(UpdateBasePathMappingError::NotFound(err.msg))
+                    return RusotoError::service(
+                        UpdateBasePathMappingError::NotFound(err.msg),
+                        &res,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(UpdateBasePathMappingError::TooManyRequests(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateBasePathMappingError::TooManyRequests(err.msg),
+                        &res,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(UpdateBasePathMappingError::Unauthorized(err.msg))
+                    return RusotoError::service(
+                        UpdateBasePathMappingError::Unauthorized(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UpdateBasePathMappingError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -8767,12 +9577,14 @@ impl fmt::Display for UpdateBasePathMappingError {
             UpdateBasePathMappingError::NotFound(ref cause) => write!(f, "{}", cause),
             UpdateBasePathMappingError::TooManyRequests(ref cause) => write!(f, "{}", cause),
             UpdateBasePathMappingError::Unauthorized(ref cause) => write!(f, "{}", cause),
+            UpdateBasePathMappingError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for UpdateBasePathMappingError {}
 /// Errors returned by UpdateClientCertificate
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdateClientCertificateError {
     /// <p>The submitted request is not valid, for example, the input is incomplete or incorrect. See the accompanying error message for details.</p>
     BadRequest(String),
@@ -8782,6 +9594,8 @@ pub enum UpdateClientCertificateError {
     TooManyRequests(String),
     /// <p>The request is denied because the caller has insufficient permissions.</p>
     Unauthorized(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdateClientCertificateError {
@@ -8789,23 +9603,31 @@ impl UpdateClientCertificateError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(UpdateClientCertificateError::BadRequest(err.msg))
+                    return RusotoError::service(
+                        UpdateClientCertificateError::BadRequest(err.msg),
+                        &res,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(UpdateClientCertificateError::NotFound(err.msg))
+                    return RusotoError::service(
+                        UpdateClientCertificateError::NotFound(err.msg),
+                        &res,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(UpdateClientCertificateError::TooManyRequests(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateClientCertificateError::TooManyRequests(err.msg),
+                        &res,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(UpdateClientCertificateError::Unauthorized(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateClientCertificateError::Unauthorized(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UpdateClientCertificateError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -8819,12 +9641,14 @@ impl fmt::Display for UpdateClientCertificateError {
             UpdateClientCertificateError::NotFound(ref cause) => write!(f, "{}", cause),
             UpdateClientCertificateError::TooManyRequests(ref cause) => write!(f, "{}", cause),
             UpdateClientCertificateError::Unauthorized(ref cause) => write!(f, "{}", cause),
+            UpdateClientCertificateError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for UpdateClientCertificateError {}
 /// Errors returned by UpdateDeployment
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdateDeploymentError {
     /// <p>The submitted request is not valid, for example, the input is incomplete or incorrect. See the accompanying error message for details.</p>
     BadRequest(String),
@@ -8836,6 +9660,8 @@ pub enum UpdateDeploymentError {
     TooManyRequests(String),
     /// <p>The request is denied because the caller has insufficient permissions.</p>
     Unauthorized(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdateDeploymentError {
@@ -8843,22 +9669,28 @@ impl UpdateDeploymentError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(UpdateDeploymentError::BadRequest(err.msg))
+                    return RusotoError::service(UpdateDeploymentError::BadRequest(err.msg), &res)
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(UpdateDeploymentError::NotFound(err.msg))
+                    return RusotoError::service(UpdateDeploymentError::NotFound(err.msg), &res)
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(UpdateDeploymentError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        UpdateDeploymentError::ServiceUnavailable(err.msg),
+                        &res,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(UpdateDeploymentError::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        UpdateDeploymentError::TooManyRequests(err.msg),
+                        &res,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(UpdateDeploymentError::Unauthorized(err.msg))
+                    return RusotoError::service(UpdateDeploymentError::Unauthorized(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UpdateDeploymentError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -8873,12 +9705,14 @@ impl fmt::Display for UpdateDeploymentError {
             UpdateDeploymentError::ServiceUnavailable(ref cause) => write!(f, "{}", cause),
             UpdateDeploymentError::TooManyRequests(ref cause) => write!(f, "{}", cause),
             UpdateDeploymentError::Unauthorized(ref cause) => write!(f, "{}", cause),
+            UpdateDeploymentError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for UpdateDeploymentError {}
 /// Errors returned by UpdateDocumentationPart
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdateDocumentationPartError {
     /// <p>The submitted request is not valid, for example, the input is incomplete or incorrect. See the accompanying error message for details.</p>
     BadRequest(String),
@@ -8892,6 +9726,8 @@ pub enum UpdateDocumentationPartError {
     TooManyRequests(String),
     /// <p>The request is denied because the caller has insufficient permissions.</p>
     Unauthorized(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdateDocumentationPartError {
@@ -8899,31 +9735,43 @@ impl UpdateDocumentationPartError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(UpdateDocumentationPartError::BadRequest(err.msg))
+                    return RusotoError::service(
+                        UpdateDocumentationPartError::BadRequest(err.msg),
+                        &res,
+                    )
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(UpdateDocumentationPartError::Conflict(err.msg))
+                    return RusotoError::service(
+                        UpdateDocumentationPartError::Conflict(err.msg),
+                        &res,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(UpdateDocumentationPartError::LimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateDocumentationPartError::LimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(UpdateDocumentationPartError::NotFound(err.msg))
+                    return RusotoError::service(
+                        UpdateDocumentationPartError::NotFound(err.msg),
+                        &res,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(UpdateDocumentationPartError::TooManyRequests(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateDocumentationPartError::TooManyRequests(err.msg),
+                        &res,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(UpdateDocumentationPartError::Unauthorized(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateDocumentationPartError::Unauthorized(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UpdateDocumentationPartError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -8939,12 +9787,14 @@ impl fmt::Display for UpdateDocumentationPartError {
             UpdateDocumentationPartError::NotFound(ref cause) => write!(f, "{}", cause),
             UpdateDocumentationPartError::TooManyRequests(ref cause) => write!(f, "{}", cause),
             UpdateDocumentationPartError::Unauthorized(ref cause) => write!(f, "{}", cause),
+            UpdateDocumentationPartError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for UpdateDocumentationPartError {}
 /// Errors returned by UpdateDocumentationVersion
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdateDocumentationVersionError {
     /// <p>The submitted request is not valid, for example, the input is incomplete or incorrect. See the accompanying error message for details.</p>
     BadRequest(String),
@@ -8956,6 +9806,8 @@ pub enum UpdateDocumentationVersionError {
     TooManyRequests(String),
     /// <p>The request is denied because the caller has insufficient permissions.</p>
     Unauthorized(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdateDocumentationVersionError {
@@ -8965,28 +9817,39 @@ impl UpdateDocumentationVersionError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(UpdateDocumentationVersionError::BadRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateDocumentationVersionError::BadRequest(err.msg),
+                        &res,
+                    )
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(UpdateDocumentationVersionError::Conflict(err.msg))
+                    return RusotoError::service(
+                        UpdateDocumentationVersionError::Conflict(err.msg),
+                        &res,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(UpdateDocumentationVersionError::NotFound(err.msg))
+                    return RusotoError::service(
+                        UpdateDocumentationVersionError::NotFound(err.msg),
+                        &res,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(UpdateDocumentationVersionError::TooManyRequests(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateDocumentationVersionError::TooManyRequests(err.msg),
+                        &res,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(UpdateDocumentationVersionError::Unauthorized(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateDocumentationVersionError::Unauthorized(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(UpdateDocumentationVersionError::Unhandled, &res)
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -9001,12 +9864,14 @@ impl fmt::Display for UpdateDocumentationVersionError {
             UpdateDocumentationVersionError::NotFound(ref cause) => write!(f, "{}", cause),
             UpdateDocumentationVersionError::TooManyRequests(ref cause) => write!(f, "{}", cause),
             UpdateDocumentationVersionError::Unauthorized(ref cause) => write!(f, "{}", cause),
+            UpdateDocumentationVersionError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for UpdateDocumentationVersionError {}
 /// Errors returned by UpdateDomainName
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdateDomainNameError {
     /// <p>The submitted request is not valid, for example, the input is incomplete or incorrect. See the accompanying error message for details.</p>
     BadRequest(String),
@@ -9018,6 +9883,8 @@ pub enum UpdateDomainNameError {
     TooManyRequests(String),
     /// <p>The request is denied because the caller has insufficient permissions.</p>
     Unauthorized(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdateDomainNameError {
@@ -9025,22 +9892,25 @@ impl UpdateDomainNameError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(UpdateDomainNameError::BadRequest(err.msg))
+                    return RusotoError::service(UpdateDomainNameError::BadRequest(err.msg), &res)
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(UpdateDomainNameError::Conflict(err.msg))
+                    return RusotoError::service(UpdateDomainNameError::Conflict(err.msg), &res)
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(UpdateDomainNameError::NotFound(err.msg))
+                    return RusotoError::service(UpdateDomainNameError::NotFound(err.msg), &res)
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(UpdateDomainNameError::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        UpdateDomainNameError::TooManyRequests(err.msg),
+                        &res,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(UpdateDomainNameError::Unauthorized(err.msg))
+                    return RusotoError::service(UpdateDomainNameError::Unauthorized(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UpdateDomainNameError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -9055,12 +9925,14 @@ impl fmt::Display for UpdateDomainNameError {
             UpdateDomainNameError::NotFound(ref cause) => write!(f, "{}", cause),
             UpdateDomainNameError::TooManyRequests(ref cause) => write!(f, "{}", cause),
             UpdateDomainNameError::Unauthorized(ref cause) => write!(f, "{}", cause),
+            UpdateDomainNameError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for UpdateDomainNameError {}
 /// Errors returned by UpdateGatewayResponse
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdateGatewayResponseError {
     /// <p>The submitted request is not valid, for example, the input is incomplete or incorrect. See the accompanying error message for details.</p>
     BadRequest(String),
@@ -9070,6 +9942,8 @@ pub enum UpdateGatewayResponseError {
     TooManyRequests(String),
     /// <p>The request is denied because the caller has insufficient permissions.</p>
     Unauthorized(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdateGatewayResponseError {
@@ -9077,21 +9951,31 @@ impl UpdateGatewayResponseError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(UpdateGatewayResponseError::BadRequest(err.msg))
+                    return RusotoError::service(
+                        UpdateGatewayResponseError::BadRequest(err.msg),
+                        &res,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(UpdateGatewayResponseError::NotFound(err.msg))
+                    return RusotoError::service(
+                        UpdateGatewayResponseError::NotFound(err.msg),
+                        &res,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(UpdateGatewayResponseError::TooManyRequests(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateGatewayResponseError::TooManyRequests(err.msg),
+                        &res,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(UpdateGatewayResponseError::Unauthorized(err.msg))
+                    return RusotoError::service(
+                        UpdateGatewayResponseError::Unauthorized(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UpdateGatewayResponseError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -9105,12 +9989,14 @@ impl fmt::Display for UpdateGatewayResponseError {
             UpdateGatewayResponseError::NotFound(ref cause) => write!(f, "{}", cause),
             UpdateGatewayResponseError::TooManyRequests(ref cause) => write!(f, "{}", cause),
             UpdateGatewayResponseError::Unauthorized(ref cause) => write!(f, "{}", cause),
+            UpdateGatewayResponseError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for UpdateGatewayResponseError {}
 /// Errors returned by UpdateIntegration
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdateIntegrationError {
     /// <p>The submitted request is not valid, for example, the input is incomplete or incorrect. See the accompanying error message for details.</p>
     BadRequest(String),
@@ -9122,6 +10008,8 @@ pub enum UpdateIntegrationError {
     TooManyRequests(String),
     /// <p>The request is denied because the caller has insufficient permissions.</p>
     Unauthorized(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdateIntegrationError {
@@ -9129,22 +10017,28 @@ impl UpdateIntegrationError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(UpdateIntegrationError::BadRequest(err.msg))
+                    return RusotoError::service(UpdateIntegrationError::BadRequest(err.msg), &res)
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(UpdateIntegrationError::Conflict(err.msg))
+                    return RusotoError::service(UpdateIntegrationError::Conflict(err.msg), &res)
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(UpdateIntegrationError::NotFound(err.msg))
+                    return RusotoError::service(UpdateIntegrationError::NotFound(err.msg), &res)
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(UpdateIntegrationError::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        UpdateIntegrationError::TooManyRequests(err.msg),
+                        &res,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(UpdateIntegrationError::Unauthorized(err.msg))
+                    return RusotoError::service(
+                        UpdateIntegrationError::Unauthorized(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UpdateIntegrationError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -9159,12 +10053,14 @@ impl fmt::Display for UpdateIntegrationError {
             UpdateIntegrationError::NotFound(ref cause) => write!(f, "{}", cause),
             UpdateIntegrationError::TooManyRequests(ref cause) => write!(f, "{}", cause),
             UpdateIntegrationError::Unauthorized(ref cause) => write!(f, "{}", cause),
+            UpdateIntegrationError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for UpdateIntegrationError {}
 /// Errors returned by UpdateIntegrationResponse
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdateIntegrationResponseError {
     /// <p>The submitted request is not valid, for example, the input is incomplete or incorrect. See the accompanying error message for details.</p>
     BadRequest(String),
@@ -9176,6 +10072,8 @@ pub enum UpdateIntegrationResponseError {
     TooManyRequests(String),
     /// <p>The request is denied because the caller has insufficient permissions.</p>
     Unauthorized(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdateIntegrationResponseError {
@@ -9183,28 +10081,39 @@ impl UpdateIntegrationResponseError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(UpdateIntegrationResponseError::BadRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateIntegrationResponseError::BadRequest(err.msg),
+                        &res,
+                    )
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(UpdateIntegrationResponseError::Conflict(err.msg))
+                    return RusotoError::service(
+                        UpdateIntegrationResponseError::Conflict(err.msg),
+                        &res,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(UpdateIntegrationResponseError::NotFound(err.msg))
+                    return RusotoError::service(
+                        UpdateIntegrationResponseError::NotFound(err.msg),
+                        &res,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(UpdateIntegrationResponseError::TooManyRequests(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateIntegrationResponseError::TooManyRequests(err.msg),
+                        &res,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(UpdateIntegrationResponseError::Unauthorized(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateIntegrationResponseError::Unauthorized(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(UpdateIntegrationResponseError::Unhandled, &res)
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -9219,12 +10128,14 @@ impl fmt::Display for UpdateIntegrationResponseError {
             UpdateIntegrationResponseError::NotFound(ref cause) => write!(f, "{}", cause),
             UpdateIntegrationResponseError::TooManyRequests(ref cause) => write!(f, "{}", cause),
             UpdateIntegrationResponseError::Unauthorized(ref cause) => write!(f, "{}", cause),
+            UpdateIntegrationResponseError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for UpdateIntegrationResponseError {}
 /// Errors returned by UpdateMethod
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdateMethodError {
     /// <p>The submitted request is not valid, for example, the input is incomplete or incorrect. See the accompanying error message for details.</p>
     BadRequest(String),
@@ -9236,6 +10147,8 @@ pub enum UpdateMethodError {
     TooManyRequests(String),
     /// <p>The request is denied because the caller has insufficient permissions.</p>
     Unauthorized(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdateMethodError {
@@ -9243,22 +10156,22 @@ impl UpdateMethodError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(UpdateMethodError::BadRequest(err.msg))
+                    return RusotoError::service(UpdateMethodError::BadRequest(err.msg), &res)
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(UpdateMethodError::Conflict(err.msg))
+                    return RusotoError::service(UpdateMethodError::Conflict(err.msg), &res)
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(UpdateMethodError::NotFound(err.msg))
+                    return RusotoError::service(UpdateMethodError::NotFound(err.msg), &res)
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(UpdateMethodError::TooManyRequests(err.msg))
+                    return RusotoError::service(UpdateMethodError::TooManyRequests(err.msg), &res)
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(UpdateMethodError::Unauthorized(err.msg))
+                    return RusotoError::service(UpdateMethodError::Unauthorized(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UpdateMethodError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -9273,12 +10186,14 @@ impl fmt::Display for UpdateMethodError {
             UpdateMethodError::NotFound(ref cause) => write!(f, "{}", cause),
             UpdateMethodError::TooManyRequests(ref cause) => write!(f, "{}", cause),
             UpdateMethodError::Unauthorized(ref cause) => write!(f, "{}", cause),
+            UpdateMethodError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for UpdateMethodError {}
 /// Errors returned by UpdateMethodResponse
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdateMethodResponseError {
     /// <p>The submitted request is not valid, for example, the input is incomplete or incorrect. See the accompanying error message for details.</p>
     BadRequest(String),
@@ -9292,6 +10207,8 @@ pub enum UpdateMethodResponseError {
     TooManyRequests(String),
     /// <p>The request is denied because the caller has insufficient permissions.</p>
     Unauthorized(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdateMethodResponseError {
@@ -9299,27 +10216,37 @@ impl UpdateMethodResponseError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(UpdateMethodResponseError::BadRequest(err.msg))
+                    return RusotoError::service(
+                        UpdateMethodResponseError::BadRequest(err.msg),
+                        &res,
+                    )
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(UpdateMethodResponseError::Conflict(err.msg))
+                    return RusotoError::service(UpdateMethodResponseError::Conflict(err.msg), &res)
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(UpdateMethodResponseError::LimitExceeded(err.msg))
+                    return RusotoError::service(
+                        UpdateMethodResponseError::LimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(UpdateMethodResponseError::NotFound(err.msg))
+                    return RusotoError::service(UpdateMethodResponseError::NotFound(err.msg), &res)
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(UpdateMethodResponseError::TooManyRequests(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateMethodResponseError::TooManyRequests(err.msg),
+                        &res,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(UpdateMethodResponseError::Unauthorized(err.msg))
+                    return RusotoError::service(
+                        UpdateMethodResponseError::Unauthorized(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UpdateMethodResponseError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -9335,12 +10262,14 @@ impl fmt::Display for UpdateMethodResponseError {
             UpdateMethodResponseError::NotFound(ref cause) => write!(f, "{}", cause),
             UpdateMethodResponseError::TooManyRequests(ref cause) => write!(f, "{}", cause),
             UpdateMethodResponseError::Unauthorized(ref cause) => write!(f, "{}", cause),
+            UpdateMethodResponseError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for UpdateMethodResponseError {}
 /// Errors returned by UpdateModel
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdateModelError {
     /// <p>The submitted request is not valid, for example, the input is incomplete or incorrect. See the accompanying error message for details.</p>
     BadRequest(String),
@@ -9352,6 +10281,8 @@ pub enum UpdateModelError {
     TooManyRequests(String),
     /// <p>The request is denied because the caller has insufficient permissions.</p>
     Unauthorized(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdateModelError {
@@ -9359,22 +10290,22 @@ impl UpdateModelError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(UpdateModelError::BadRequest(err.msg))
+                    return RusotoError::service(UpdateModelError::BadRequest(err.msg), &res)
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(UpdateModelError::Conflict(err.msg))
+                    return RusotoError::service(UpdateModelError::Conflict(err.msg), &res)
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(UpdateModelError::NotFound(err.msg))
+                    return RusotoError::service(UpdateModelError::NotFound(err.msg), &res)
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(UpdateModelError::TooManyRequests(err.msg))
+                    return RusotoError::service(UpdateModelError::TooManyRequests(err.msg), &res)
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(UpdateModelError::Unauthorized(err.msg))
+                    return RusotoError::service(UpdateModelError::Unauthorized(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UpdateModelError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -9389,12 +10320,14 @@ impl fmt::Display for UpdateModelError {
             UpdateModelError::NotFound(ref cause) => write!(f, "{}", cause),
             UpdateModelError::TooManyRequests(ref cause) => write!(f, "{}", cause),
             UpdateModelError::Unauthorized(ref cause) => write!(f, "{}", cause),
+            UpdateModelError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for UpdateModelError {}
 /// Errors returned by UpdateRequestValidator
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdateRequestValidatorError {
     /// <p>The submitted request is not valid, for example, the input is incomplete or incorrect. See the accompanying error message for details.</p>
     BadRequest(String),
@@ -9404,6 +10337,8 @@ pub enum UpdateRequestValidatorError {
     TooManyRequests(String),
     /// <p>The request is denied because the caller has insufficient permissions.</p>
     Unauthorized(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdateRequestValidatorError {
@@ -9411,21 +10346,31 @@ impl UpdateRequestValidatorError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(UpdateRequestValidatorError::BadRequest(err.msg))
+                    return RusotoError::service(
+                        UpdateRequestValidatorError::BadRequest(err.msg),
+                        &res,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(UpdateRequestValidatorError::NotFound(err.msg))
+                    return RusotoError::service(
+                        UpdateRequestValidatorError::NotFound(err.msg),
+                        &res,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(UpdateRequestValidatorError::TooManyRequests(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateRequestValidatorError::TooManyRequests(err.msg),
+                        &res,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(UpdateRequestValidatorError::Unauthorized(err.msg))
+                    return RusotoError::service(
+                        UpdateRequestValidatorError::Unauthorized(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UpdateRequestValidatorError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -9439,12 +10384,14 @@ impl fmt::Display for UpdateRequestValidatorError {
             UpdateRequestValidatorError::NotFound(ref cause) => write!(f, "{}", cause),
             UpdateRequestValidatorError::TooManyRequests(ref cause) => write!(f, "{}", cause),
             UpdateRequestValidatorError::Unauthorized(ref cause) => write!(f, "{}", cause),
+            UpdateRequestValidatorError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for UpdateRequestValidatorError {}
 /// Errors returned by UpdateResource
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdateResourceError {
     /// <p>The submitted request is not valid, for example, the input is incomplete or incorrect. See the accompanying error message for details.</p>
     BadRequest(String),
@@ -9456,6 +10403,8 @@ pub enum UpdateResourceError {
     TooManyRequests(String),
     /// <p>The request is denied because the caller has insufficient permissions.</p>
     Unauthorized(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdateResourceError {
@@ -9463,22 +10412,25 @@ impl UpdateResourceError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(UpdateResourceError::BadRequest(err.msg))
+                    return RusotoError::service(UpdateResourceError::BadRequest(err.msg), &res)
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(UpdateResourceError::Conflict(err.msg))
+                    return RusotoError::service(UpdateResourceError::Conflict(err.msg), &res)
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(UpdateResourceError::NotFound(err.msg))
+                    return RusotoError::service(UpdateResourceError::NotFound(err.msg), &res)
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(UpdateResourceError::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        UpdateResourceError::TooManyRequests(err.msg),
+                        &res,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(UpdateResourceError::Unauthorized(err.msg))
+                    return RusotoError::service(UpdateResourceError::Unauthorized(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UpdateResourceError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -9493,12 +10445,14 @@ impl fmt::Display for UpdateResourceError {
             UpdateResourceError::NotFound(ref cause) => write!(f, "{}", cause),
             UpdateResourceError::TooManyRequests(ref cause) => write!(f, "{}", cause),
             UpdateResourceError::Unauthorized(ref cause) => write!(f, "{}", cause),
+            UpdateResourceError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for UpdateResourceError {}
 /// Errors returned by UpdateRestApi
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdateRestApiError {
     /// <p>The submitted request is not valid, for example, the input is incomplete or incorrect. See the accompanying error message for details.</p>
     BadRequest(String),
@@ -9510,6 +10464,8 @@ pub enum UpdateRestApiError {
     TooManyRequests(String),
     /// <p>The request is denied because the caller has insufficient permissions.</p>
     Unauthorized(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdateRestApiError {
@@ -9517,22 +10473,22 @@ impl UpdateRestApiError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(UpdateRestApiError::BadRequest(err.msg))
+                    return RusotoError::service(UpdateRestApiError::BadRequest(err.msg), &res)
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(UpdateRestApiError::Conflict(err.msg))
+                    return RusotoError::service(UpdateRestApiError::Conflict(err.msg), &res)
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(UpdateRestApiError::NotFound(err.msg))
+                    return RusotoError::service(UpdateRestApiError::NotFound(err.msg), &res)
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(UpdateRestApiError::TooManyRequests(err.msg))
+                    return RusotoError::service(UpdateRestApiError::TooManyRequests(err.msg), &res)
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(UpdateRestApiError::Unauthorized(err.msg))
+                    return RusotoError::service(UpdateRestApiError::Unauthorized(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UpdateRestApiError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -9547,12 +10503,14 @@ impl fmt::Display for UpdateRestApiError {
             UpdateRestApiError::NotFound(ref cause) => write!(f, "{}", cause),
             UpdateRestApiError::TooManyRequests(ref cause) => write!(f, "{}", cause),
             UpdateRestApiError::Unauthorized(ref cause) => write!(f, "{}", cause),
+            UpdateRestApiError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for UpdateRestApiError {}
 /// Errors returned by UpdateStage
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdateStageError {
     /// <p>The submitted request is not valid, for example, the input is incomplete or incorrect. See the accompanying error message for details.</p>
     BadRequest(String),
@@ -9564,6 +10522,8 @@ pub enum UpdateStageError {
     TooManyRequests(String),
     /// <p>The request is denied because the caller has insufficient permissions.</p>
     Unauthorized(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdateStageError {
@@ -9571,22 +10531,22 @@ impl UpdateStageError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(UpdateStageError::BadRequest(err.msg))
+                    return RusotoError::service(UpdateStageError::BadRequest(err.msg), &res)
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(UpdateStageError::Conflict(err.msg))
+                    return RusotoError::service(UpdateStageError::Conflict(err.msg), &res)
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(UpdateStageError::NotFound(err.msg))
+                    return RusotoError::service(UpdateStageError::NotFound(err.msg), &res)
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(UpdateStageError::TooManyRequests(err.msg))
+                    return RusotoError::service(UpdateStageError::TooManyRequests(err.msg), &res)
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(UpdateStageError::Unauthorized(err.msg))
+                    return RusotoError::service(UpdateStageError::Unauthorized(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UpdateStageError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -9601,12 +10561,14 @@ impl fmt::Display for UpdateStageError {
             UpdateStageError::NotFound(ref cause) => write!(f, "{}", cause),
             UpdateStageError::TooManyRequests(ref cause) => write!(f, "{}", cause),
             UpdateStageError::Unauthorized(ref cause) => write!(f, "{}", cause),
+            UpdateStageError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for UpdateStageError {}
 /// Errors returned by UpdateUsage
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdateUsageError {
     /// <p>The submitted request is not valid, for example, the input is incomplete or incorrect. See the accompanying error message for details.</p>
     BadRequest(String),
@@ -9616,6 +10578,8 @@ pub enum UpdateUsageError {
     TooManyRequests(String),
     /// <p>The request is denied because the caller has insufficient permissions.</p>
     Unauthorized(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdateUsageError {
@@ -9623,19 +10587,19 @@ impl UpdateUsageError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(UpdateUsageError::BadRequest(err.msg))
+                    return RusotoError::service(UpdateUsageError::BadRequest(err.msg), &res)
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(UpdateUsageError::NotFound(err.msg))
+                    return RusotoError::service(UpdateUsageError::NotFound(err.msg), &res)
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(UpdateUsageError::TooManyRequests(err.msg))
+                    return RusotoError::service(UpdateUsageError::TooManyRequests(err.msg), &res)
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(UpdateUsageError::Unauthorized(err.msg))
+                    return RusotoError::service(UpdateUsageError::Unauthorized(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UpdateUsageError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -9649,12 +10613,14 @@ impl fmt::Display for UpdateUsageError {
             UpdateUsageError::NotFound(ref cause) => write!(f, "{}", cause),
             UpdateUsageError::TooManyRequests(ref cause) => write!(f, "{}", cause),
             UpdateUsageError::Unauthorized(ref cause) => write!(f, "{}", cause),
+            UpdateUsageError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for UpdateUsageError {}
 /// Errors returned by UpdateUsagePlan
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdateUsagePlanError {
     /// <p>The submitted request is not valid, for example, the input is incomplete or incorrect. See the accompanying error message for details.</p>
     BadRequest(String),
@@ -9666,6 +10632,8 @@ pub enum UpdateUsagePlanError {
     TooManyRequests(String),
     /// <p>The request is denied because the caller has insufficient permissions.</p>
     Unauthorized(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdateUsagePlanError {
@@ -9673,22 +10641,25 @@ impl UpdateUsagePlanError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(UpdateUsagePlanError::BadRequest(err.msg))
+                    return RusotoError::service(UpdateUsagePlanError::BadRequest(err.msg), &res)
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(UpdateUsagePlanError::Conflict(err.msg))
+                    return RusotoError::service(UpdateUsagePlanError::Conflict(err.msg), &res)
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(UpdateUsagePlanError::NotFound(err.msg))
+                    return RusotoError::service(UpdateUsagePlanError::NotFound(err.msg), &res)
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(UpdateUsagePlanError::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        UpdateUsagePlanError::TooManyRequests(err.msg),
+                        &res,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(UpdateUsagePlanError::Unauthorized(err.msg))
+                    return RusotoError::service(UpdateUsagePlanError::Unauthorized(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UpdateUsagePlanError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -9703,12 +10674,14 @@ impl fmt::Display for UpdateUsagePlanError {
             UpdateUsagePlanError::NotFound(ref cause) => write!(f, "{}", cause),
             UpdateUsagePlanError::TooManyRequests(ref cause) => write!(f, "{}", cause),
             UpdateUsagePlanError::Unauthorized(ref cause) => write!(f, "{}", cause),
+            UpdateUsagePlanError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for UpdateUsagePlanError {}
 /// Errors returned by UpdateVpcLink
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdateVpcLinkError {
     /// <p>The submitted request is not valid, for example, the input is incomplete or incorrect. See the accompanying error message for details.</p>
     BadRequest(String),
@@ -9720,6 +10693,8 @@ pub enum UpdateVpcLinkError {
     TooManyRequests(String),
     /// <p>The request is denied because the caller has insufficient permissions.</p>
     Unauthorized(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdateVpcLinkError {
@@ -9727,22 +10702,22 @@ impl UpdateVpcLinkError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(UpdateVpcLinkError::BadRequest(err.msg))
+                    return RusotoError::service(UpdateVpcLinkError::BadRequest(err.msg), &res)
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(UpdateVpcLinkError::Conflict(err.msg))
+                    return RusotoError::service(UpdateVpcLinkError::Conflict(err.msg), &res)
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(UpdateVpcLinkError::NotFound(err.msg))
+                    return RusotoError::service(UpdateVpcLinkError::NotFound(err.msg), &res)
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(UpdateVpcLinkError::TooManyRequests(err.msg))
+                    return RusotoError::service(UpdateVpcLinkError::TooManyRequests(err.msg), &res)
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(UpdateVpcLinkError::Unauthorized(err.msg))
+                    return RusotoError::service(UpdateVpcLinkError::Unauthorized(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UpdateVpcLinkError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -9757,6 +10732,7 @@ impl fmt::Display for UpdateVpcLinkError {
             UpdateVpcLinkError::NotFound(ref cause) => write!(f, "{}", cause),
             UpdateVpcLinkError::TooManyRequests(ref cause) => write!(f, "{}", cause),
             UpdateVpcLinkError::Unauthorized(ref cause) => write!(f, "{}", cause),
+            UpdateVpcLinkError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
@@ -10464,6 +11440,7 @@ impl ApiGatewayClient {
     /// Creates a client backed by the default tokio event loop.
     ///
     /// The client will use the default credentials provider and tls client.
+    #[cfg(not(target_arch = "wasm32"))]
     pub fn new(region: region::Region) -> ApiGatewayClient {
         ApiGatewayClient {
             client: Client::shared(),
@@ -10489,302 +11466,588 @@ impl ApiGatewayClient {
     pub fn new_with_client(client: Client, region: region::Region) -> ApiGatewayClient {
         ApiGatewayClient { client, region }
     }
+
+    /// Returns a builder of a client, setting any of its region, credentials provider,
+    /// dispatcher, retry policy, endpoint and timeouts.
+    pub fn builder() -> rusoto_core::ClientBuilder<ApiGatewayClient> {
+        rusoto_core::ClientBuilder::new(ApiGatewayClient::new_with_client)
+    }
+
+    /// Returns a clone of this client with the overrides of `config` applied to its
+    /// operations, sharing its connections.
+    pub fn customize(&self, config: &rusoto_core::OperationConfig) -> ApiGatewayClient {
+        ApiGatewayClient {
+            client: self.client.customize(config),
+            region: config.resolve_region(&self.region),
+        }
+    }
 }
 
-#[async_trait]
-impl ApiGateway for ApiGatewayClient {
-    /// <p><p>Create an <a>ApiKey</a> resource. </p> <div class="seeAlso"><a href="https://docs.aws.amazon.com/cli/latest/reference/apigateway/create-api-key.html">AWS CLI</a></div></p>
-    async fn create_api_key(
+impl ApiGatewayClient {
+    /// Calls `create_api_key`, also returning the metadata of the response.
+    pub async fn create_api_key_with_metadata(
         &self,
         input: CreateApiKeyRequest,
-    ) -> Result<ApiKey, RusotoError<CreateApiKeyError>> {
+    ) -> Result<(ApiKey, ResponseMetadata), RusotoError<CreateApiKeyError>> {
+        let request = self.build_create_api_key_request(input);
+
+        let mut response = self
+            .client
+            .sign_and_dispatch(request)
+            .await
+            .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
+        if response.status.as_u16() == 201 {
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response).deserialize::<ApiKey, _>()?;
+
+            Ok((result, metadata))
+        } else {
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(CreateApiKeyError::from_response(response))
+        }
+    }
+
+    /// Presigns the request `create_api_key` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_api_key(
+        &self,
+        presigner: &rusoto_core::Presigner,
+        input: CreateApiKeyRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_api_key_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_api_key_request(&self, input: CreateApiKeyRequest) -> SignedRequest {
         let request_uri = "/apikeys";
 
         let mut request = SignedRequest::new("POST", "apigateway", &self.region, &request_uri);
+        request.set_operation("CreateApiKey");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `create_authorizer`, also returning the metadata of the response.
+    pub async fn create_authorizer_with_metadata(
+        &self,
+        input: CreateAuthorizerRequest,
+    ) -> Result<(Authorizer, ResponseMetadata), RusotoError<CreateAuthorizerError>> {
+        let request = self.build_create_authorizer_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 201 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            let result = proto::json::ResponsePayload::new(&response).deserialize::<ApiKey, _>()?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result =
+                proto::json::ResponsePayload::new(&response).deserialize::<Authorizer, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(CreateApiKeyError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(CreateAuthorizerError::from_response(response))
         }
     }
 
-    /// <p><p>Adds a new <a>Authorizer</a> resource to an existing <a>RestApi</a> resource.</p> <div class="seeAlso"><a href="https://docs.aws.amazon.com/cli/latest/reference/apigateway/create-authorizer.html">AWS CLI</a></div></p>
-    async fn create_authorizer(
+    /// Presigns the request `create_authorizer` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_authorizer(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: CreateAuthorizerRequest,
-    ) -> Result<Authorizer, RusotoError<CreateAuthorizerError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_authorizer_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_authorizer_request(&self, input: CreateAuthorizerRequest) -> SignedRequest {
         let request_uri = format!(
             "/restapis/{restapi_id}/authorizers",
             restapi_id = input.rest_api_id
         );
 
         let mut request = SignedRequest::new("POST", "apigateway", &self.region, &request_uri);
+        request.set_operation("CreateAuthorizer");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `create_base_path_mapping`, also returning the metadata of the response.
+    pub async fn create_base_path_mapping_with_metadata(
+        &self,
+        input: CreateBasePathMappingRequest,
+    ) -> Result<(BasePathMapping, ResponseMetadata), RusotoError<CreateBasePathMappingError>> {
+        let request = self.build_create_base_path_mapping_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 201 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result =
-                proto::json::ResponsePayload::new(&response).deserialize::<Authorizer, _>()?;
+                proto::json::ResponsePayload::new(&response).deserialize::<BasePathMapping, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(CreateAuthorizerError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(CreateBasePathMappingError::from_response(response))
         }
     }
 
-    /// <p>Creates a new <a>BasePathMapping</a> resource.</p>
-    async fn create_base_path_mapping(
+    /// Presigns the request `create_base_path_mapping` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_base_path_mapping(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: CreateBasePathMappingRequest,
-    ) -> Result<BasePathMapping, RusotoError<CreateBasePathMappingError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_base_path_mapping_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_base_path_mapping_request(
+        &self,
+        input: CreateBasePathMappingRequest,
+    ) -> SignedRequest {
         let request_uri = format!(
             "/domainnames/{domain_name}/basepathmappings",
             domain_name = input.domain_name
         );
 
         let mut request = SignedRequest::new("POST", "apigateway", &self.region, &request_uri);
+        request.set_operation("CreateBasePathMapping");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `create_deployment`, also returning the metadata of the response.
+    pub async fn create_deployment_with_metadata(
+        &self,
+        input: CreateDeploymentRequest,
+    ) -> Result<(Deployment, ResponseMetadata), RusotoError<CreateDeploymentError>> {
+        let request = self.build_create_deployment_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 201 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result =
-                proto::json::ResponsePayload::new(&response).deserialize::<BasePathMapping, _>()?;
+                proto::json::ResponsePayload::new(&response).deserialize::<Deployment, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(CreateBasePathMappingError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(CreateDeploymentError::from_response(response))
         }
     }
 
-    /// <p>Creates a <a>Deployment</a> resource, which makes a specified <a>RestApi</a> callable over the internet.</p>
-    async fn create_deployment(
+    /// Presigns the request `create_deployment` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_deployment(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: CreateDeploymentRequest,
-    ) -> Result<Deployment, RusotoError<CreateDeploymentError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_deployment_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_deployment_request(&self, input: CreateDeploymentRequest) -> SignedRequest {
         let request_uri = format!(
             "/restapis/{restapi_id}/deployments",
             restapi_id = input.rest_api_id
         );
 
         let mut request = SignedRequest::new("POST", "apigateway", &self.region, &request_uri);
+        request.set_operation("CreateDeployment");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `create_documentation_part`, also returning the metadata of the response.
+    pub async fn create_documentation_part_with_metadata(
+        &self,
+        input: CreateDocumentationPartRequest,
+    ) -> Result<(DocumentationPart, ResponseMetadata), RusotoError<CreateDocumentationPartError>>
+    {
+        let request = self.build_create_documentation_part_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 201 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            let result =
-                proto::json::ResponsePayload::new(&response).deserialize::<Deployment, _>()?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DocumentationPart, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(CreateDeploymentError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(CreateDocumentationPartError::from_response(response))
         }
     }
 
-    async fn create_documentation_part(
+    /// Presigns the request `create_documentation_part` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_documentation_part(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: CreateDocumentationPartRequest,
-    ) -> Result<DocumentationPart, RusotoError<CreateDocumentationPartError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_documentation_part_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_documentation_part_request(
+        &self,
+        input: CreateDocumentationPartRequest,
+    ) -> SignedRequest {
         let request_uri = format!(
             "/restapis/{restapi_id}/documentation/parts",
             restapi_id = input.rest_api_id
         );
 
         let mut request = SignedRequest::new("POST", "apigateway", &self.region, &request_uri);
+        request.set_operation("CreateDocumentationPart");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `create_documentation_version`, also returning the metadata of the response.
+    pub async fn create_documentation_version_with_metadata(
+        &self,
+        input: CreateDocumentationVersionRequest,
+    ) -> Result<
+        (DocumentationVersion, ResponseMetadata),
+        RusotoError<CreateDocumentationVersionError>,
+    > {
+        let request = self.build_create_documentation_version_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 201 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<DocumentationPart, _>()?;
+                .deserialize::<DocumentationVersion, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(CreateDocumentationPartError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(CreateDocumentationVersionError::from_response(response))
         }
     }
 
-    async fn create_documentation_version(
+    /// Presigns the request `create_documentation_version` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_documentation_version(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: CreateDocumentationVersionRequest,
-    ) -> Result<DocumentationVersion, RusotoError<CreateDocumentationVersionError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_documentation_version_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_documentation_version_request(
+        &self,
+        input: CreateDocumentationVersionRequest,
+    ) -> SignedRequest {
         let request_uri = format!(
             "/restapis/{restapi_id}/documentation/versions",
             restapi_id = input.rest_api_id
         );
 
         let mut request = SignedRequest::new("POST", "apigateway", &self.region, &request_uri);
+        request.set_operation("CreateDocumentationVersion");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `create_domain_name`, also returning the metadata of the response.
+    pub async fn create_domain_name_with_metadata(
+        &self,
+        input: CreateDomainNameRequest,
+    ) -> Result<(DomainName, ResponseMetadata), RusotoError<CreateDomainNameError>> {
+        let request = self.build_create_domain_name_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 201 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<DocumentationVersion, _>()?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result =
+                proto::json::ResponsePayload::new(&response).deserialize::<DomainName, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(CreateDocumentationVersionError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(CreateDomainNameError::from_response(response))
         }
     }
 
-    /// <p>Creates a new domain name.</p>
-    async fn create_domain_name(
+    /// Presigns the request `create_domain_name` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_domain_name(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: CreateDomainNameRequest,
-    ) -> Result<DomainName, RusotoError<CreateDomainNameError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_domain_name_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_domain_name_request(&self, input: CreateDomainNameRequest) -> SignedRequest {
         let request_uri = "/domainnames";
 
         let mut request = SignedRequest::new("POST", "apigateway", &self.region, &request_uri);
+        request.set_operation("CreateDomainName");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `create_model`, also returning the metadata of the response.
+    pub async fn create_model_with_metadata(
+        &self,
+        input: CreateModelRequest,
+    ) -> Result<(Model, ResponseMetadata), RusotoError<CreateModelError>> {
+        let request = self.build_create_model_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 201 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            let result =
-                proto::json::ResponsePayload::new(&response).deserialize::<DomainName, _>()?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response).deserialize::<Model, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(CreateDomainNameError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(CreateModelError::from_response(response))
         }
     }
 
-    /// <p>Adds a new <a>Model</a> resource to an existing <a>RestApi</a> resource.</p>
-    async fn create_model(
+    /// Presigns the request `create_model` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_model(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: CreateModelRequest,
-    ) -> Result<Model, RusotoError<CreateModelError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_model_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_model_request(&self, input: CreateModelRequest) -> SignedRequest {
         let request_uri = format!(
             "/restapis/{restapi_id}/models",
             restapi_id = input.rest_api_id
         );
 
         let mut request = SignedRequest::new("POST", "apigateway", &self.region, &request_uri);
+        request.set_operation("CreateModel");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `create_request_validator`, also returning the metadata of the response.
+    pub async fn create_request_validator_with_metadata(
+        &self,
+        input: CreateRequestValidatorRequest,
+    ) -> Result<(RequestValidator, ResponseMetadata), RusotoError<CreateRequestValidatorError>>
+    {
+        let request = self.build_create_request_validator_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 201 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            let result = proto::json::ResponsePayload::new(&response).deserialize::<Model, _>()?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<RequestValidator, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(CreateModelError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(CreateRequestValidatorError::from_response(response))
         }
     }
 
-    /// <p>Creates a <a>ReqeustValidator</a> of a given <a>RestApi</a>.</p>
-    async fn create_request_validator(
+    /// Presigns the request `create_request_validator` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_request_validator(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: CreateRequestValidatorRequest,
-    ) -> Result<RequestValidator, RusotoError<CreateRequestValidatorError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_request_validator_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_request_validator_request(
+        &self,
+        input: CreateRequestValidatorRequest,
+    ) -> SignedRequest {
         let request_uri = format!(
             "/restapis/{restapi_id}/requestvalidators",
             restapi_id = input.rest_api_id
         );
 
         let mut request = SignedRequest::new("POST", "apigateway", &self.region, &request_uri);
+        request.set_operation("CreateRequestValidator");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `create_resource`, also returning the metadata of the response.
+    pub async fn create_resource_with_metadata(
+        &self,
+        input: CreateResourceRequest,
+    ) -> Result<(Resource, ResponseMetadata), RusotoError<CreateResourceError>> {
+        let request = self.build_create_resource_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 201 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<RequestValidator, _>()?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result =
+                proto::json::ResponsePayload::new(&response).deserialize::<Resource, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(CreateRequestValidatorError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(CreateResourceError::from_response(response))
         }
     }
 
-    /// <p>Creates a <a>Resource</a> resource.</p>
-    async fn create_resource(
+    /// Presigns the request `create_resource` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_resource(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: CreateResourceRequest,
-    ) -> Result<Resource, RusotoError<CreateResourceError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_resource_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_resource_request(&self, input: CreateResourceRequest) -> SignedRequest {
         let request_uri = format!(
             "/restapis/{restapi_id}/resources/{parent_id}",
             parent_id = input.parent_id,
@@ -10792,214 +12055,384 @@ impl ApiGateway for ApiGatewayClient {
         );
 
         let mut request = SignedRequest::new("POST", "apigateway", &self.region, &request_uri);
+        request.set_operation("CreateResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `create_rest_api`, also returning the metadata of the response.
+    pub async fn create_rest_api_with_metadata(
+        &self,
+        input: CreateRestApiRequest,
+    ) -> Result<(RestApi, ResponseMetadata), RusotoError<CreateRestApiError>> {
+        let request = self.build_create_rest_api_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 201 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result =
-                proto::json::ResponsePayload::new(&response).deserialize::<Resource, _>()?;
+                proto::json::ResponsePayload::new(&response).deserialize::<RestApi, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(CreateResourceError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(CreateRestApiError::from_response(response))
         }
     }
 
-    /// <p>Creates a new <a>RestApi</a> resource.</p>
-    async fn create_rest_api(
+    /// Presigns the request `create_rest_api` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_rest_api(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: CreateRestApiRequest,
-    ) -> Result<RestApi, RusotoError<CreateRestApiError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_rest_api_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_rest_api_request(&self, input: CreateRestApiRequest) -> SignedRequest {
         let request_uri = "/restapis";
 
         let mut request = SignedRequest::new("POST", "apigateway", &self.region, &request_uri);
+        request.set_operation("CreateRestApi");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `create_stage`, also returning the metadata of the response.
+    pub async fn create_stage_with_metadata(
+        &self,
+        input: CreateStageRequest,
+    ) -> Result<(Stage, ResponseMetadata), RusotoError<CreateStageError>> {
+        let request = self.build_create_stage_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 201 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            let result =
-                proto::json::ResponsePayload::new(&response).deserialize::<RestApi, _>()?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response).deserialize::<Stage, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(CreateRestApiError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(CreateStageError::from_response(response))
         }
     }
 
-    /// <p>Creates a new <a>Stage</a> resource that references a pre-existing <a>Deployment</a> for the API. </p>
-    async fn create_stage(
+    /// Presigns the request `create_stage` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_stage(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: CreateStageRequest,
-    ) -> Result<Stage, RusotoError<CreateStageError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_stage_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_stage_request(&self, input: CreateStageRequest) -> SignedRequest {
         let request_uri = format!(
             "/restapis/{restapi_id}/stages",
             restapi_id = input.rest_api_id
         );
 
         let mut request = SignedRequest::new("POST", "apigateway", &self.region, &request_uri);
+        request.set_operation("CreateStage");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `create_usage_plan`, also returning the metadata of the response.
+    pub async fn create_usage_plan_with_metadata(
+        &self,
+        input: CreateUsagePlanRequest,
+    ) -> Result<(UsagePlan, ResponseMetadata), RusotoError<CreateUsagePlanError>> {
+        let request = self.build_create_usage_plan_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 201 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            let result = proto::json::ResponsePayload::new(&response).deserialize::<Stage, _>()?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result =
+                proto::json::ResponsePayload::new(&response).deserialize::<UsagePlan, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(CreateStageError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(CreateUsagePlanError::from_response(response))
         }
     }
 
-    /// <p>Creates a usage plan with the throttle and quota limits, as well as the associated API stages, specified in the payload. </p>
-    async fn create_usage_plan(
+    /// Presigns the request `create_usage_plan` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_usage_plan(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: CreateUsagePlanRequest,
-    ) -> Result<UsagePlan, RusotoError<CreateUsagePlanError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_usage_plan_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_usage_plan_request(&self, input: CreateUsagePlanRequest) -> SignedRequest {
         let request_uri = "/usageplans";
 
         let mut request = SignedRequest::new("POST", "apigateway", &self.region, &request_uri);
+        request.set_operation("CreateUsagePlan");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `create_usage_plan_key`, also returning the metadata of the response.
+    pub async fn create_usage_plan_key_with_metadata(
+        &self,
+        input: CreateUsagePlanKeyRequest,
+    ) -> Result<(UsagePlanKey, ResponseMetadata), RusotoError<CreateUsagePlanKeyError>> {
+        let request = self.build_create_usage_plan_key_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 201 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result =
-                proto::json::ResponsePayload::new(&response).deserialize::<UsagePlan, _>()?;
+                proto::json::ResponsePayload::new(&response).deserialize::<UsagePlanKey, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(CreateUsagePlanError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(CreateUsagePlanKeyError::from_response(response))
         }
     }
 
-    /// <p>Creates a usage plan key for adding an existing API key to a usage plan.</p>
-    async fn create_usage_plan_key(
+    /// Presigns the request `create_usage_plan_key` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_usage_plan_key(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: CreateUsagePlanKeyRequest,
-    ) -> Result<UsagePlanKey, RusotoError<CreateUsagePlanKeyError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_usage_plan_key_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_usage_plan_key_request(
+        &self,
+        input: CreateUsagePlanKeyRequest,
+    ) -> SignedRequest {
         let request_uri = format!(
             "/usageplans/{usageplan_id}/keys",
             usageplan_id = input.usage_plan_id
         );
 
         let mut request = SignedRequest::new("POST", "apigateway", &self.region, &request_uri);
+        request.set_operation("CreateUsagePlanKey");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `create_vpc_link`, also returning the metadata of the response.
+    pub async fn create_vpc_link_with_metadata(
+        &self,
+        input: CreateVpcLinkRequest,
+    ) -> Result<(VpcLink, ResponseMetadata), RusotoError<CreateVpcLinkError>> {
+        let request = self.build_create_vpc_link_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
-        if response.status.as_u16() == 201 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let metadata = ResponseMetadata::from_response(&response);
+        if response.status.as_u16() == 202 {
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result =
-                proto::json::ResponsePayload::new(&response).deserialize::<UsagePlanKey, _>()?;
+                proto::json::ResponsePayload::new(&response).deserialize::<VpcLink, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(CreateUsagePlanKeyError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(CreateVpcLinkError::from_response(response))
         }
     }
 
-    /// <p>Creates a VPC link, under the caller's account in a selected region, in an asynchronous operation that typically takes 2-4 minutes to complete and become operational. The caller must have permissions to create and update VPC Endpoint services.</p>
-    async fn create_vpc_link(
+    /// Presigns the request `create_vpc_link` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_vpc_link(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: CreateVpcLinkRequest,
-    ) -> Result<VpcLink, RusotoError<CreateVpcLinkError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_vpc_link_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_vpc_link_request(&self, input: CreateVpcLinkRequest) -> SignedRequest {
         let request_uri = "/vpclinks";
 
         let mut request = SignedRequest::new("POST", "apigateway", &self.region, &request_uri);
+        request.set_operation("CreateVpcLink");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `delete_api_key`, also returning the metadata of the response.
+    pub async fn delete_api_key_with_metadata(
+        &self,
+        input: DeleteApiKeyRequest,
+    ) -> Result<((), ResponseMetadata), RusotoError<DeleteApiKeyError>> {
+        let request = self.build_delete_api_key_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 202 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            let result =
-                proto::json::ResponsePayload::new(&response).deserialize::<VpcLink, _>()?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = ::std::mem::drop(response);
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(CreateVpcLinkError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(DeleteApiKeyError::from_response(response))
         }
     }
 
-    /// <p>Deletes the <a>ApiKey</a> resource.</p>
-    async fn delete_api_key(
+    /// Presigns the request `delete_api_key` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_api_key(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteApiKeyRequest,
-    ) -> Result<(), RusotoError<DeleteApiKeyError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_api_key_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_api_key_request(&self, input: DeleteApiKeyRequest) -> SignedRequest {
         let request_uri = format!("/apikeys/{api_key}", api_key = input.api_key);
 
         let mut request = SignedRequest::new("DELETE", "apigateway", &self.region, &request_uri);
+        request.set_operation("DeleteApiKey");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
+        request
+    }
+
+    /// Calls `delete_authorizer`, also returning the metadata of the response.
+    pub async fn delete_authorizer_with_metadata(
+        &self,
+        input: DeleteAuthorizerRequest,
+    ) -> Result<((), ResponseMetadata), RusotoError<DeleteAuthorizerError>> {
+        let request = self.build_delete_authorizer_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 202 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = ::std::mem::drop(response);
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteApiKeyError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(DeleteAuthorizerError::from_response(response))
         }
     }
 
-    /// <p><p>Deletes an existing <a>Authorizer</a> resource.</p> <div class="seeAlso"><a href="https://docs.aws.amazon.com/cli/latest/reference/apigateway/delete-authorizer.html">AWS CLI</a></div></p>
-    async fn delete_authorizer(
+    /// Presigns the request `delete_authorizer` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_authorizer(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteAuthorizerRequest,
-    ) -> Result<(), RusotoError<DeleteAuthorizerError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_authorizer_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_authorizer_request(&self, input: DeleteAuthorizerRequest) -> SignedRequest {
         let request_uri = format!(
             "/restapis/{restapi_id}/authorizers/{authorizer_id}",
             authorizer_id = input.authorizer_id,
@@ -11007,29 +12440,56 @@ impl ApiGateway for ApiGatewayClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "apigateway", &self.region, &request_uri);
+        request.set_operation("DeleteAuthorizer");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
+        request
+    }
+
+    /// Calls `delete_base_path_mapping`, also returning the metadata of the response.
+    pub async fn delete_base_path_mapping_with_metadata(
+        &self,
+        input: DeleteBasePathMappingRequest,
+    ) -> Result<((), ResponseMetadata), RusotoError<DeleteBasePathMappingError>> {
+        let request = self.build_delete_base_path_mapping_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 202 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = ::std::mem::drop(response);
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteAuthorizerError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(DeleteBasePathMappingError::from_response(response))
         }
     }
 
-    /// <p>Deletes the <a>BasePathMapping</a> resource.</p>
-    async fn delete_base_path_mapping(
+    /// Presigns the request `delete_base_path_mapping` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_base_path_mapping(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteBasePathMappingRequest,
-    ) -> Result<(), RusotoError<DeleteBasePathMappingError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_base_path_mapping_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_base_path_mapping_request(
+        &self,
+        input: DeleteBasePathMappingRequest,
+    ) -> SignedRequest {
         let request_uri = format!(
             "/domainnames/{domain_name}/basepathmappings/{base_path}",
             base_path = input.base_path,
@@ -11037,58 +12497,109 @@ impl ApiGateway for ApiGatewayClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "apigateway", &self.region, &request_uri);
+        request.set_operation("DeleteBasePathMapping");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
+        request
+    }
+
+    /// Calls `delete_client_certificate`, also returning the metadata of the response.
+    pub async fn delete_client_certificate_with_metadata(
+        &self,
+        input: DeleteClientCertificateRequest,
+    ) -> Result<((), ResponseMetadata), RusotoError<DeleteClientCertificateError>> {
+        let request = self.build_delete_client_certificate_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 202 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = ::std::mem::drop(response);
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteBasePathMappingError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(DeleteClientCertificateError::from_response(response))
         }
     }
 
-    /// <p>Deletes the <a>ClientCertificate</a> resource.</p>
-    async fn delete_client_certificate(
+    /// Presigns the request `delete_client_certificate` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_client_certificate(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteClientCertificateRequest,
-    ) -> Result<(), RusotoError<DeleteClientCertificateError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_client_certificate_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_client_certificate_request(
+        &self,
+        input: DeleteClientCertificateRequest,
+    ) -> SignedRequest {
         let request_uri = format!(
             "/clientcertificates/{clientcertificate_id}",
             clientcertificate_id = input.client_certificate_id
         );
 
         let mut request = SignedRequest::new("DELETE", "apigateway", &self.region, &request_uri);
+        request.set_operation("DeleteClientCertificate");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
+        request
+    }
+
+    /// Calls `delete_deployment`, also returning the metadata of the response.
+    pub async fn delete_deployment_with_metadata(
+        &self,
+        input: DeleteDeploymentRequest,
+    ) -> Result<((), ResponseMetadata), RusotoError<DeleteDeploymentError>> {
+        let request = self.build_delete_deployment_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 202 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = ::std::mem::drop(response);
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteClientCertificateError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(DeleteDeploymentError::from_response(response))
         }
     }
 
-    /// <p>Deletes a <a>Deployment</a> resource. Deleting a deployment will only succeed if there are no <a>Stage</a> resources associated with it.</p>
-    async fn delete_deployment(
+    /// Presigns the request `delete_deployment` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_deployment(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteDeploymentRequest,
-    ) -> Result<(), RusotoError<DeleteDeploymentError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_deployment_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_deployment_request(&self, input: DeleteDeploymentRequest) -> SignedRequest {
         let request_uri = format!(
             "/restapis/{restapi_id}/deployments/{deployment_id}",
             deployment_id = input.deployment_id,
@@ -11096,28 +12607,56 @@ impl ApiGateway for ApiGatewayClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "apigateway", &self.region, &request_uri);
+        request.set_operation("DeleteDeployment");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
+        request
+    }
+
+    /// Calls `delete_documentation_part`, also returning the metadata of the response.
+    pub async fn delete_documentation_part_with_metadata(
+        &self,
+        input: DeleteDocumentationPartRequest,
+    ) -> Result<((), ResponseMetadata), RusotoError<DeleteDocumentationPartError>> {
+        let request = self.build_delete_documentation_part_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 202 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = ::std::mem::drop(response);
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteDeploymentError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(DeleteDocumentationPartError::from_response(response))
         }
     }
 
-    async fn delete_documentation_part(
+    /// Presigns the request `delete_documentation_part` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_documentation_part(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteDocumentationPartRequest,
-    ) -> Result<(), RusotoError<DeleteDocumentationPartError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_documentation_part_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_documentation_part_request(
+        &self,
+        input: DeleteDocumentationPartRequest,
+    ) -> SignedRequest {
         let request_uri = format!(
             "/restapis/{restapi_id}/documentation/parts/{part_id}",
             part_id = input.documentation_part_id,
@@ -11125,28 +12664,56 @@ impl ApiGateway for ApiGatewayClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "apigateway", &self.region, &request_uri);
+        request.set_operation("DeleteDocumentationPart");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
+        request
+    }
+
+    /// Calls `delete_documentation_version`, also returning the metadata of the response.
+    pub async fn delete_documentation_version_with_metadata(
+        &self,
+        input: DeleteDocumentationVersionRequest,
+    ) -> Result<((), ResponseMetadata), RusotoError<DeleteDocumentationVersionError>> {
+        let request = self.build_delete_documentation_version_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 202 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = ::std::mem::drop(response);
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteDocumentationPartError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(DeleteDocumentationVersionError::from_response(response))
         }
     }
 
-    async fn delete_documentation_version(
+    /// Presigns the request `delete_documentation_version` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_documentation_version(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteDocumentationVersionRequest,
-    ) -> Result<(), RusotoError<DeleteDocumentationVersionError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_documentation_version_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_documentation_version_request(
+        &self,
+        input: DeleteDocumentationVersionRequest,
+    ) -> SignedRequest {
         let request_uri = format!(
             "/restapis/{restapi_id}/documentation/versions/{doc_version}",
             doc_version = input.documentation_version,
@@ -11154,58 +12721,109 @@ impl ApiGateway for ApiGatewayClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "apigateway", &self.region, &request_uri);
+        request.set_operation("DeleteDocumentationVersion");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
+        request
+    }
+
+    /// Calls `delete_domain_name`, also returning the metadata of the response.
+    pub async fn delete_domain_name_with_metadata(
+        &self,
+        input: DeleteDomainNameRequest,
+    ) -> Result<((), ResponseMetadata), RusotoError<DeleteDomainNameError>> {
+        let request = self.build_delete_domain_name_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 202 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = ::std::mem::drop(response);
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteDocumentationVersionError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(DeleteDomainNameError::from_response(response))
         }
     }
 
-    /// <p>Deletes the <a>DomainName</a> resource.</p>
-    async fn delete_domain_name(
+    /// Presigns the request `delete_domain_name` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_domain_name(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteDomainNameRequest,
-    ) -> Result<(), RusotoError<DeleteDomainNameError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_domain_name_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_domain_name_request(&self, input: DeleteDomainNameRequest) -> SignedRequest {
         let request_uri = format!(
             "/domainnames/{domain_name}",
             domain_name = input.domain_name
         );
 
         let mut request = SignedRequest::new("DELETE", "apigateway", &self.region, &request_uri);
+        request.set_operation("DeleteDomainName");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
+        request
+    }
+
+    /// Calls `delete_gateway_response`, also returning the metadata of the response.
+    pub async fn delete_gateway_response_with_metadata(
+        &self,
+        input: DeleteGatewayResponseRequest,
+    ) -> Result<((), ResponseMetadata), RusotoError<DeleteGatewayResponseError>> {
+        let request = self.build_delete_gateway_response_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 202 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = ::std::mem::drop(response);
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteDomainNameError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(DeleteGatewayResponseError::from_response(response))
         }
     }
 
-    /// <p>Clears any customization of a <a>GatewayResponse</a> of a specified response type on the given <a>RestApi</a> and resets it with the default settings.</p>
-    async fn delete_gateway_response(
+    /// Presigns the request `delete_gateway_response` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_gateway_response(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteGatewayResponseRequest,
-    ) -> Result<(), RusotoError<DeleteGatewayResponseError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_gateway_response_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_gateway_response_request(
+        &self,
+        input: DeleteGatewayResponseRequest,
+    ) -> SignedRequest {
         let request_uri = format!(
             "/restapis/{restapi_id}/gatewayresponses/{response_type}",
             response_type = input.response_type,
@@ -11213,29 +12831,53 @@ impl ApiGateway for ApiGatewayClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "apigateway", &self.region, &request_uri);
+        request.set_operation("DeleteGatewayResponse");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
+        request
+    }
+
+    /// Calls `delete_integration`, also returning the metadata of the response.
+    pub async fn delete_integration_with_metadata(
+        &self,
+        input: DeleteIntegrationRequest,
+    ) -> Result<((), ResponseMetadata), RusotoError<DeleteIntegrationError>> {
+        let request = self.build_delete_integration_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
-        if response.status.as_u16() == 202 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let metadata = ResponseMetadata::from_response(&response);
+        if response.status.as_u16() == 204 {
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = ::std::mem::drop(response);
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteGatewayResponseError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(DeleteIntegrationError::from_response(response))
         }
     }
 
-    /// <p>Represents a delete integration.</p>
-    async fn delete_integration(
+    /// Presigns the request `delete_integration` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_integration(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteIntegrationRequest,
-    ) -> Result<(), RusotoError<DeleteIntegrationError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_integration_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_integration_request(&self, input: DeleteIntegrationRequest) -> SignedRequest {
         let request_uri = format!(
             "/restapis/{restapi_id}/resources/{resource_id}/methods/{http_method}/integration",
             http_method = input.http_method,
@@ -11244,55 +12886,106 @@ impl ApiGateway for ApiGatewayClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "apigateway", &self.region, &request_uri);
+        request.set_operation("DeleteIntegration");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
+        request
+    }
+
+    /// Calls `delete_integration_response`, also returning the metadata of the response.
+    pub async fn delete_integration_response_with_metadata(
+        &self,
+        input: DeleteIntegrationResponseRequest,
+    ) -> Result<((), ResponseMetadata), RusotoError<DeleteIntegrationResponseError>> {
+        let request = self.build_delete_integration_response_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 204 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = ::std::mem::drop(response);
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteIntegrationError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(DeleteIntegrationResponseError::from_response(response))
         }
     }
 
-    /// <p>Represents a delete integration response.</p>
-    async fn delete_integration_response(
+    /// Presigns the request `delete_integration_response` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_integration_response(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteIntegrationResponseRequest,
-    ) -> Result<(), RusotoError<DeleteIntegrationResponseError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_integration_response_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_integration_response_request(
+        &self,
+        input: DeleteIntegrationResponseRequest,
+    ) -> SignedRequest {
         let request_uri = format!("/restapis/{restapi_id}/resources/{resource_id}/methods/{http_method}/integration/responses/{status_code}", http_method = input.http_method, resource_id = input.resource_id, restapi_id = input.rest_api_id, status_code = input.status_code);
 
         let mut request = SignedRequest::new("DELETE", "apigateway", &self.region, &request_uri);
+        request.set_operation("DeleteIntegrationResponse");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
+        request
+    }
+
+    /// Calls `delete_method`, also returning the metadata of the response.
+    pub async fn delete_method_with_metadata(
+        &self,
+        input: DeleteMethodRequest,
+    ) -> Result<((), ResponseMetadata), RusotoError<DeleteMethodError>> {
+        let request = self.build_delete_method_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 204 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = ::std::mem::drop(response);
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteIntegrationResponseError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(DeleteMethodError::from_response(response))
         }
     }
 
-    /// <p>Deletes an existing <a>Method</a> resource.</p>
-    async fn delete_method(
+    /// Presigns the request `delete_method` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_method(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteMethodRequest,
-    ) -> Result<(), RusotoError<DeleteMethodError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_method_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_method_request(&self, input: DeleteMethodRequest) -> SignedRequest {
         let request_uri = format!(
             "/restapis/{restapi_id}/resources/{resource_id}/methods/{http_method}",
             http_method = input.http_method,
@@ -11301,55 +12994,106 @@ impl ApiGateway for ApiGatewayClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "apigateway", &self.region, &request_uri);
+        request.set_operation("DeleteMethod");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
+        request
+    }
+
+    /// Calls `delete_method_response`, also returning the metadata of the response.
+    pub async fn delete_method_response_with_metadata(
+        &self,
+        input: DeleteMethodResponseRequest,
+    ) -> Result<((), ResponseMetadata), RusotoError<DeleteMethodResponseError>> {
+        let request = self.build_delete_method_response_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 204 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = ::std::mem::drop(response);
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteMethodError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(DeleteMethodResponseError::from_response(response))
         }
     }
 
-    /// <p>Deletes an existing <a>MethodResponse</a> resource.</p>
-    async fn delete_method_response(
+    /// Presigns the request `delete_method_response` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_method_response(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteMethodResponseRequest,
-    ) -> Result<(), RusotoError<DeleteMethodResponseError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_method_response_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_method_response_request(
+        &self,
+        input: DeleteMethodResponseRequest,
+    ) -> SignedRequest {
         let request_uri = format!("/restapis/{restapi_id}/resources/{resource_id}/methods/{http_method}/responses/{status_code}", http_method = input.http_method, resource_id = input.resource_id, restapi_id = input.rest_api_id, status_code = input.status_code);
 
         let mut request = SignedRequest::new("DELETE", "apigateway", &self.region, &request_uri);
+        request.set_operation("DeleteMethodResponse");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
+        request
+    }
+
+    /// Calls `delete_model`, also returning the metadata of the response.
+    pub async fn delete_model_with_metadata(
+        &self,
+        input: DeleteModelRequest,
+    ) -> Result<((), ResponseMetadata), RusotoError<DeleteModelError>> {
+        let request = self.build_delete_model_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
-        if response.status.as_u16() == 204 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let metadata = ResponseMetadata::from_response(&response);
+        if response.status.as_u16() == 202 {
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = ::std::mem::drop(response);
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteMethodResponseError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(DeleteModelError::from_response(response))
         }
     }
 
-    /// <p>Deletes a model.</p>
-    async fn delete_model(
+    /// Presigns the request `delete_model` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_model(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteModelRequest,
-    ) -> Result<(), RusotoError<DeleteModelError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_model_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_model_request(&self, input: DeleteModelRequest) -> SignedRequest {
         let request_uri = format!(
             "/restapis/{restapi_id}/models/{model_name}",
             model_name = input.model_name,
@@ -11357,29 +13101,56 @@ impl ApiGateway for ApiGatewayClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "apigateway", &self.region, &request_uri);
+        request.set_operation("DeleteModel");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
+        request
+    }
+
+    /// Calls `delete_request_validator`, also returning the metadata of the response.
+    pub async fn delete_request_validator_with_metadata(
+        &self,
+        input: DeleteRequestValidatorRequest,
+    ) -> Result<((), ResponseMetadata), RusotoError<DeleteRequestValidatorError>> {
+        let request = self.build_delete_request_validator_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 202 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = ::std::mem::drop(response);
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteModelError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(DeleteRequestValidatorError::from_response(response))
         }
     }
 
-    /// <p>Deletes a <a>RequestValidator</a> of a given <a>RestApi</a>.</p>
-    async fn delete_request_validator(
+    /// Presigns the request `delete_request_validator` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_request_validator(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteRequestValidatorRequest,
-    ) -> Result<(), RusotoError<DeleteRequestValidatorError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_request_validator_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_request_validator_request(
+        &self,
+        input: DeleteRequestValidatorRequest,
+    ) -> SignedRequest {
         let request_uri = format!(
             "/restapis/{restapi_id}/requestvalidators/{requestvalidator_id}",
             requestvalidator_id = input.request_validator_id,
@@ -11387,29 +13158,53 @@ impl ApiGateway for ApiGatewayClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "apigateway", &self.region, &request_uri);
+        request.set_operation("DeleteRequestValidator");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
+        request
+    }
+
+    /// Calls `delete_resource`, also returning the metadata of the response.
+    pub async fn delete_resource_with_metadata(
+        &self,
+        input: DeleteResourceRequest,
+    ) -> Result<((), ResponseMetadata), RusotoError<DeleteResourceError>> {
+        let request = self.build_delete_resource_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 202 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = ::std::mem::drop(response);
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteRequestValidatorError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(DeleteResourceError::from_response(response))
         }
     }
 
-    /// <p>Deletes a <a>Resource</a> resource.</p>
-    async fn delete_resource(
+    /// Presigns the request `delete_resource` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_resource(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteResourceRequest,
-    ) -> Result<(), RusotoError<DeleteResourceError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_resource_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_resource_request(&self, input: DeleteResourceRequest) -> SignedRequest {
         let request_uri = format!(
             "/restapis/{restapi_id}/resources/{resource_id}",
             resource_id = input.resource_id,
@@ -11417,55 +13212,103 @@ impl ApiGateway for ApiGatewayClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "apigateway", &self.region, &request_uri);
+        request.set_operation("DeleteResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
+        request
+    }
+
+    /// Calls `delete_rest_api`, also returning the metadata of the response.
+    pub async fn delete_rest_api_with_metadata(
+        &self,
+        input: DeleteRestApiRequest,
+    ) -> Result<((), ResponseMetadata), RusotoError<DeleteRestApiError>> {
+        let request = self.build_delete_rest_api_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 202 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = ::std::mem::drop(response);
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteResourceError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(DeleteRestApiError::from_response(response))
         }
     }
 
-    /// <p>Deletes the specified API.</p>
-    async fn delete_rest_api(
+    /// Presigns the request `delete_rest_api` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_rest_api(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteRestApiRequest,
-    ) -> Result<(), RusotoError<DeleteRestApiError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_rest_api_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_rest_api_request(&self, input: DeleteRestApiRequest) -> SignedRequest {
         let request_uri = format!("/restapis/{restapi_id}", restapi_id = input.rest_api_id);
 
         let mut request = SignedRequest::new("DELETE", "apigateway", &self.region, &request_uri);
+        request.set_operation("DeleteRestApi");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
+        request
+    }
+
+    /// Calls `delete_stage`, also returning the metadata of the response.
+    pub async fn delete_stage_with_metadata(
+        &self,
+        input: DeleteStageRequest,
+    ) -> Result<((), ResponseMetadata), RusotoError<DeleteStageError>> {
+        let request = self.build_delete_stage_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 202 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = ::std::mem::drop(response);
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteRestApiError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(DeleteStageError::from_response(response))
         }
     }
 
-    /// <p>Deletes a <a>Stage</a> resource.</p>
-    async fn delete_stage(
+    /// Presigns the request `delete_stage` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_stage(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteStageRequest,
-    ) -> Result<(), RusotoError<DeleteStageError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_stage_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_stage_request(&self, input: DeleteStageRequest) -> SignedRequest {
         let request_uri = format!(
             "/restapis/{restapi_id}/stages/{stage_name}",
             restapi_id = input.rest_api_id,
@@ -11473,58 +13316,109 @@ impl ApiGateway for ApiGatewayClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "apigateway", &self.region, &request_uri);
+        request.set_operation("DeleteStage");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
+        request
+    }
+
+    /// Calls `delete_usage_plan`, also returning the metadata of the response.
+    pub async fn delete_usage_plan_with_metadata(
+        &self,
+        input: DeleteUsagePlanRequest,
+    ) -> Result<((), ResponseMetadata), RusotoError<DeleteUsagePlanError>> {
+        let request = self.build_delete_usage_plan_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 202 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = ::std::mem::drop(response);
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteStageError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(DeleteUsagePlanError::from_response(response))
         }
     }
 
-    /// <p>Deletes a usage plan of a given plan Id.</p>
-    async fn delete_usage_plan(
+    /// Presigns the request `delete_usage_plan` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_usage_plan(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteUsagePlanRequest,
-    ) -> Result<(), RusotoError<DeleteUsagePlanError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_usage_plan_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_usage_plan_request(&self, input: DeleteUsagePlanRequest) -> SignedRequest {
         let request_uri = format!(
             "/usageplans/{usageplan_id}",
             usageplan_id = input.usage_plan_id
         );
 
         let mut request = SignedRequest::new("DELETE", "apigateway", &self.region, &request_uri);
+        request.set_operation("DeleteUsagePlan");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
-        let mut response = self
-            .client
-            .sign_and_dispatch(request)
+        request
+    }
+
+    /// Calls `delete_usage_plan_key`, also returning the metadata of the response.
+    pub async fn delete_usage_plan_key_with_metadata(
+        &self,
+        input: DeleteUsagePlanKeyRequest,
+    ) -> Result<((), ResponseMetadata), RusotoError<DeleteUsagePlanKeyError>> {
+        let request = self.build_delete_usage_plan_key_request(input);
+
+        let mut response = self
+            .client
+            .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 202 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = ::std::mem::drop(response);
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteUsagePlanError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(DeleteUsagePlanKeyError::from_response(response))
         }
     }
 
-    /// <p>Deletes a usage plan key and remove the underlying API key from the associated usage plan.</p>
-    async fn delete_usage_plan_key(
+    /// Presigns the request `delete_usage_plan_key` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_usage_plan_key(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteUsagePlanKeyRequest,
-    ) -> Result<(), RusotoError<DeleteUsagePlanKeyError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_usage_plan_key_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_usage_plan_key_request(
+        &self,
+        input: DeleteUsagePlanKeyRequest,
+    ) -> SignedRequest {
         let request_uri = format!(
             "/usageplans/{usageplan_id}/keys/{key_id}",
             key_id = input.key_id,
@@ -11532,55 +13426,106 @@ impl ApiGateway for ApiGatewayClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "apigateway", &self.region, &request_uri);
+        request.set_operation("DeleteUsagePlanKey");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
+        request
+    }
+
+    /// Calls `delete_vpc_link`, also returning the metadata of the response.
+    pub async fn delete_vpc_link_with_metadata(
+        &self,
+        input: DeleteVpcLinkRequest,
+    ) -> Result<((), ResponseMetadata), RusotoError<DeleteVpcLinkError>> {
+        let request = self.build_delete_vpc_link_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 202 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = ::std::mem::drop(response);
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteUsagePlanKeyError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(DeleteVpcLinkError::from_response(response))
         }
     }
 
-    /// <p>Deletes an existing <a>VpcLink</a> of a specified identifier.</p>
-    async fn delete_vpc_link(
+    /// Presigns the request `delete_vpc_link` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_vpc_link(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteVpcLinkRequest,
-    ) -> Result<(), RusotoError<DeleteVpcLinkError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_vpc_link_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_vpc_link_request(&self, input: DeleteVpcLinkRequest) -> SignedRequest {
         let request_uri = format!("/vpclinks/{vpclink_id}", vpclink_id = input.vpc_link_id);
 
         let mut request = SignedRequest::new("DELETE", "apigateway", &self.region, &request_uri);
+        request.set_operation("DeleteVpcLink");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
+        request
+    }
+
+    /// Calls `flush_stage_authorizers_cache`, also returning the metadata of the response.
+    pub async fn flush_stage_authorizers_cache_with_metadata(
+        &self,
+        input: FlushStageAuthorizersCacheRequest,
+    ) -> Result<((), ResponseMetadata), RusotoError<FlushStageAuthorizersCacheError>> {
+        let request = self.build_flush_stage_authorizers_cache_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 202 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = ::std::mem::drop(response);
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteVpcLinkError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(FlushStageAuthorizersCacheError::from_response(response))
         }
     }
 
-    /// <p>Flushes all authorizer cache entries on a stage.</p>
-    async fn flush_stage_authorizers_cache(
+    /// Presigns the request `flush_stage_authorizers_cache` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_flush_stage_authorizers_cache(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: FlushStageAuthorizersCacheRequest,
-    ) -> Result<(), RusotoError<FlushStageAuthorizersCacheError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_flush_stage_authorizers_cache_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_flush_stage_authorizers_cache_request(
+        &self,
+        input: FlushStageAuthorizersCacheRequest,
+    ) -> SignedRequest {
         let request_uri = format!(
             "/restapis/{restapi_id}/stages/{stage_name}/cache/authorizers",
             restapi_id = input.rest_api_id,
@@ -11588,29 +13533,53 @@ impl ApiGateway for ApiGatewayClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "apigateway", &self.region, &request_uri);
+        request.set_operation("FlushStageAuthorizersCache");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
+        request
+    }
+
+    /// Calls `flush_stage_cache`, also returning the metadata of the response.
+    pub async fn flush_stage_cache_with_metadata(
+        &self,
+        input: FlushStageCacheRequest,
+    ) -> Result<((), ResponseMetadata), RusotoError<FlushStageCacheError>> {
+        let request = self.build_flush_stage_cache_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 202 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = ::std::mem::drop(response);
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(FlushStageAuthorizersCacheError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(FlushStageCacheError::from_response(response))
         }
     }
 
-    /// <p>Flushes a stage's cache.</p>
-    async fn flush_stage_cache(
+    /// Presigns the request `flush_stage_cache` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_flush_stage_cache(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: FlushStageCacheRequest,
-    ) -> Result<(), RusotoError<FlushStageCacheError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_flush_stage_cache_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_flush_stage_cache_request(&self, input: FlushStageCacheRequest) -> SignedRequest {
         let request_uri = format!(
             "/restapis/{restapi_id}/stages/{stage_name}/cache/data",
             restapi_id = input.rest_api_id,
@@ -11618,86 +13587,164 @@ impl ApiGateway for ApiGatewayClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "apigateway", &self.region, &request_uri);
+        request.set_operation("FlushStageCache");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
+        request
+    }
+
+    /// Calls `generate_client_certificate`, also returning the metadata of the response.
+    pub async fn generate_client_certificate_with_metadata(
+        &self,
+        input: GenerateClientCertificateRequest,
+    ) -> Result<(ClientCertificate, ResponseMetadata), RusotoError<GenerateClientCertificateError>>
+    {
+        let request = self.build_generate_client_certificate_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
-        if response.status.as_u16() == 202 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            let result = ::std::mem::drop(response);
+        let metadata = ResponseMetadata::from_response(&response);
+        if response.status.as_u16() == 201 {
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ClientCertificate, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(FlushStageCacheError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(GenerateClientCertificateError::from_response(response))
         }
     }
 
-    /// <p>Generates a <a>ClientCertificate</a> resource.</p>
-    async fn generate_client_certificate(
+    /// Presigns the request `generate_client_certificate` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_generate_client_certificate(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GenerateClientCertificateRequest,
-    ) -> Result<ClientCertificate, RusotoError<GenerateClientCertificateError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_generate_client_certificate_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_generate_client_certificate_request(
+        &self,
+        input: GenerateClientCertificateRequest,
+    ) -> SignedRequest {
         let request_uri = "/clientcertificates";
 
         let mut request = SignedRequest::new("POST", "apigateway", &self.region, &request_uri);
+        request.set_operation("GenerateClientCertificate");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `get_account`, also returning the metadata of the response.
+    pub async fn get_account_with_metadata(
+        &self,
+    ) -> Result<(Account, ResponseMetadata), RusotoError<GetAccountError>> {
+        let request = self.build_get_account_request();
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
-        if response.status.as_u16() == 201 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<ClientCertificate, _>()?;
+        let metadata = ResponseMetadata::from_response(&response);
+        if response.status.is_success() {
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result =
+                proto::json::ResponsePayload::new(&response).deserialize::<Account, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(GenerateClientCertificateError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(GetAccountError::from_response(response))
         }
     }
 
-    /// <p>Gets information about the current <a>Account</a> resource.</p>
-    async fn get_account(&self) -> Result<Account, RusotoError<GetAccountError>> {
+    /// Presigns the request `get_account` would send with `presigner` instead of sending it.
+    pub async fn presign_get_account(
+        &self,
+        presigner: &rusoto_core::Presigner,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_account_request();
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_account_request(&self) -> SignedRequest {
         let request_uri = "/account";
 
         let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
+        request.set_operation("GetAccount");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
+        request
+    }
+
+    /// Calls `get_api_key`, also returning the metadata of the response.
+    pub async fn get_api_key_with_metadata(
+        &self,
+        input: GetApiKeyRequest,
+    ) -> Result<(ApiKey, ResponseMetadata), RusotoError<GetApiKeyError>> {
+        let request = self.build_get_api_key_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            let result =
-                proto::json::ResponsePayload::new(&response).deserialize::<Account, _>()?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response).deserialize::<ApiKey, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(GetAccountError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(GetApiKeyError::from_response(response))
         }
     }
 
-    /// <p>Gets information about the current <a>ApiKey</a> resource.</p>
-    async fn get_api_key(
+    /// Presigns the request `get_api_key` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_api_key(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetApiKeyRequest,
-    ) -> Result<ApiKey, RusotoError<GetApiKeyError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_api_key_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_api_key_request(&self, input: GetApiKeyRequest) -> SignedRequest {
         let request_uri = format!("/apikeys/{api_key}", api_key = input.api_key);
 
         let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
+        request.set_operation("GetApiKey");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -11706,30 +13753,55 @@ impl ApiGateway for ApiGatewayClient {
         }
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `get_api_keys`, also returning the metadata of the response.
+    pub async fn get_api_keys_with_metadata(
+        &self,
+        input: GetApiKeysRequest,
+    ) -> Result<(ApiKeys, ResponseMetadata), RusotoError<GetApiKeysError>> {
+        let request = self.build_get_api_keys_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            let result = proto::json::ResponsePayload::new(&response).deserialize::<ApiKey, _>()?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result =
+                proto::json::ResponsePayload::new(&response).deserialize::<ApiKeys, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(GetApiKeyError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(GetApiKeysError::from_response(response))
         }
     }
 
-    /// <p>Gets information about the current <a>ApiKeys</a> resource.</p>
-    async fn get_api_keys(
+    /// Presigns the request `get_api_keys` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_api_keys(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetApiKeysRequest,
-    ) -> Result<ApiKeys, RusotoError<GetApiKeysError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_api_keys_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_api_keys_request(&self, input: GetApiKeysRequest) -> SignedRequest {
         let request_uri = "/apikeys";
 
         let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
+        request.set_operation("GetApiKeys");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -11750,28 +13822,51 @@ impl ApiGateway for ApiGatewayClient {
         }
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `get_authorizer`, also returning the metadata of the response.
+    pub async fn get_authorizer_with_metadata(
+        &self,
+        input: GetAuthorizerRequest,
+    ) -> Result<(Authorizer, ResponseMetadata), RusotoError<GetAuthorizerError>> {
+        let request = self.build_get_authorizer_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result =
-                proto::json::ResponsePayload::new(&response).deserialize::<ApiKeys, _>()?;
+                proto::json::ResponsePayload::new(&response).deserialize::<Authorizer, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(GetApiKeysError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(GetAuthorizerError::from_response(response))
         }
     }
 
-    /// <p><p>Describe an existing <a>Authorizer</a> resource.</p> <div class="seeAlso"><a href="https://docs.aws.amazon.com/cli/latest/reference/apigateway/get-authorizer.html">AWS CLI</a></div></p>
-    async fn get_authorizer(
+    /// Presigns the request `get_authorizer` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_authorizer(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetAuthorizerRequest,
-    ) -> Result<Authorizer, RusotoError<GetAuthorizerError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_authorizer_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_authorizer_request(&self, input: GetAuthorizerRequest) -> SignedRequest {
         let request_uri = format!(
             "/restapis/{restapi_id}/authorizers/{authorizer_id}",
             authorizer_id = input.authorizer_id,
@@ -11779,36 +13874,61 @@ impl ApiGateway for ApiGatewayClient {
         );
 
         let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
+        request.set_operation("GetAuthorizer");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
+        request
+    }
+
+    /// Calls `get_authorizers`, also returning the metadata of the response.
+    pub async fn get_authorizers_with_metadata(
+        &self,
+        input: GetAuthorizersRequest,
+    ) -> Result<(Authorizers, ResponseMetadata), RusotoError<GetAuthorizersError>> {
+        let request = self.build_get_authorizers_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result =
-                proto::json::ResponsePayload::new(&response).deserialize::<Authorizer, _>()?;
+                proto::json::ResponsePayload::new(&response).deserialize::<Authorizers, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(GetAuthorizerError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(GetAuthorizersError::from_response(response))
         }
     }
 
-    /// <p><p>Describe an existing <a>Authorizers</a> resource.</p> <div class="seeAlso"><a href="https://docs.aws.amazon.com/cli/latest/reference/apigateway/get-authorizers.html">AWS CLI</a></div></p>
-    async fn get_authorizers(
+    /// Presigns the request `get_authorizers` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_authorizers(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetAuthorizersRequest,
-    ) -> Result<Authorizers, RusotoError<GetAuthorizersError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_authorizers_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_authorizers_request(&self, input: GetAuthorizersRequest) -> SignedRequest {
         let request_uri = format!(
             "/restapis/{restapi_id}/authorizers",
             restapi_id = input.rest_api_id
         );
 
         let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
+        request.set_operation("GetAuthorizers");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -11820,28 +13940,54 @@ impl ApiGateway for ApiGatewayClient {
         }
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `get_base_path_mapping`, also returning the metadata of the response.
+    pub async fn get_base_path_mapping_with_metadata(
+        &self,
+        input: GetBasePathMappingRequest,
+    ) -> Result<(BasePathMapping, ResponseMetadata), RusotoError<GetBasePathMappingError>> {
+        let request = self.build_get_base_path_mapping_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result =
-                proto::json::ResponsePayload::new(&response).deserialize::<Authorizers, _>()?;
+                proto::json::ResponsePayload::new(&response).deserialize::<BasePathMapping, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(GetAuthorizersError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(GetBasePathMappingError::from_response(response))
         }
     }
 
-    /// <p>Describe a <a>BasePathMapping</a> resource.</p>
-    async fn get_base_path_mapping(
+    /// Presigns the request `get_base_path_mapping` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_base_path_mapping(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetBasePathMappingRequest,
-    ) -> Result<BasePathMapping, RusotoError<GetBasePathMappingError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_base_path_mapping_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_base_path_mapping_request(
+        &self,
+        input: GetBasePathMappingRequest,
+    ) -> SignedRequest {
         let request_uri = format!(
             "/domainnames/{domain_name}/basepathmappings/{base_path}",
             base_path = input.base_path,
@@ -11849,36 +13995,64 @@ impl ApiGateway for ApiGatewayClient {
         );
 
         let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
+        request.set_operation("GetBasePathMapping");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
+        request
+    }
+
+    /// Calls `get_base_path_mappings`, also returning the metadata of the response.
+    pub async fn get_base_path_mappings_with_metadata(
+        &self,
+        input: GetBasePathMappingsRequest,
+    ) -> Result<(BasePathMappings, ResponseMetadata), RusotoError<GetBasePathMappingsError>> {
+        let request = self.build_get_base_path_mappings_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            let result =
-                proto::json::ResponsePayload::new(&response).deserialize::<BasePathMapping, _>()?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<BasePathMappings, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(GetBasePathMappingError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(GetBasePathMappingsError::from_response(response))
         }
     }
 
-    /// <p>Represents a collection of <a>BasePathMapping</a> resources.</p>
-    async fn get_base_path_mappings(
+    /// Presigns the request `get_base_path_mappings` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_base_path_mappings(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetBasePathMappingsRequest,
-    ) -> Result<BasePathMappings, RusotoError<GetBasePathMappingsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_base_path_mappings_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_base_path_mappings_request(
+        &self,
+        input: GetBasePathMappingsRequest,
+    ) -> SignedRequest {
         let request_uri = format!(
             "/domainnames/{domain_name}/basepathmappings",
             domain_name = input.domain_name
         );
 
         let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
+        request.set_operation("GetBasePathMappings");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -11890,61 +14064,116 @@ impl ApiGateway for ApiGatewayClient {
         }
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `get_client_certificate`, also returning the metadata of the response.
+    pub async fn get_client_certificate_with_metadata(
+        &self,
+        input: GetClientCertificateRequest,
+    ) -> Result<(ClientCertificate, ResponseMetadata), RusotoError<GetClientCertificateError>> {
+        let request = self.build_get_client_certificate_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<BasePathMappings, _>()?;
+                .deserialize::<ClientCertificate, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(GetBasePathMappingsError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(GetClientCertificateError::from_response(response))
         }
     }
 
-    /// <p>Gets information about the current <a>ClientCertificate</a> resource.</p>
-    async fn get_client_certificate(
+    /// Presigns the request `get_client_certificate` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_client_certificate(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetClientCertificateRequest,
-    ) -> Result<ClientCertificate, RusotoError<GetClientCertificateError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_client_certificate_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_client_certificate_request(
+        &self,
+        input: GetClientCertificateRequest,
+    ) -> SignedRequest {
         let request_uri = format!(
             "/clientcertificates/{clientcertificate_id}",
             clientcertificate_id = input.client_certificate_id
         );
 
         let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
+        request.set_operation("GetClientCertificate");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
+        request
+    }
+
+    /// Calls `get_client_certificates`, also returning the metadata of the response.
+    pub async fn get_client_certificates_with_metadata(
+        &self,
+        input: GetClientCertificatesRequest,
+    ) -> Result<(ClientCertificates, ResponseMetadata), RusotoError<GetClientCertificatesError>>
+    {
+        let request = self.build_get_client_certificates_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<ClientCertificate, _>()?;
+                .deserialize::<ClientCertificates, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(GetClientCertificateError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(GetClientCertificatesError::from_response(response))
         }
     }
 
-    /// <p>Gets a collection of <a>ClientCertificate</a> resources.</p>
-    async fn get_client_certificates(
+    /// Presigns the request `get_client_certificates` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_client_certificates(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetClientCertificatesRequest,
-    ) -> Result<ClientCertificates, RusotoError<GetClientCertificatesError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_client_certificates_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_client_certificates_request(
+        &self,
+        input: GetClientCertificatesRequest,
+    ) -> SignedRequest {
         let request_uri = "/clientcertificates";
 
         let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
+        request.set_operation("GetClientCertificates");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -11956,28 +14185,51 @@ impl ApiGateway for ApiGatewayClient {
         }
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `get_deployment`, also returning the metadata of the response.
+    pub async fn get_deployment_with_metadata(
+        &self,
+        input: GetDeploymentRequest,
+    ) -> Result<(Deployment, ResponseMetadata), RusotoError<GetDeploymentError>> {
+        let request = self.build_get_deployment_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<ClientCertificates, _>()?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result =
+                proto::json::ResponsePayload::new(&response).deserialize::<Deployment, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(GetClientCertificatesError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(GetDeploymentError::from_response(response))
         }
     }
 
-    /// <p>Gets information about a <a>Deployment</a> resource.</p>
-    async fn get_deployment(
+    /// Presigns the request `get_deployment` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_deployment(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetDeploymentRequest,
-    ) -> Result<Deployment, RusotoError<GetDeploymentError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_deployment_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_deployment_request(&self, input: GetDeploymentRequest) -> SignedRequest {
         let request_uri = format!(
             "/restapis/{restapi_id}/deployments/{deployment_id}",
             deployment_id = input.deployment_id,
@@ -11985,6 +14237,7 @@ impl ApiGateway for ApiGatewayClient {
         );
 
         let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
+        request.set_operation("GetDeployment");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -11995,34 +14248,58 @@ impl ApiGateway for ApiGatewayClient {
         }
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `get_deployments`, also returning the metadata of the response.
+    pub async fn get_deployments_with_metadata(
+        &self,
+        input: GetDeploymentsRequest,
+    ) -> Result<(Deployments, ResponseMetadata), RusotoError<GetDeploymentsError>> {
+        let request = self.build_get_deployments_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result =
-                proto::json::ResponsePayload::new(&response).deserialize::<Deployment, _>()?;
+                proto::json::ResponsePayload::new(&response).deserialize::<Deployments, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(GetDeploymentError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(GetDeploymentsError::from_response(response))
         }
     }
 
-    /// <p>Gets information about a <a>Deployments</a> collection.</p>
-    async fn get_deployments(
+    /// Presigns the request `get_deployments` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_deployments(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetDeploymentsRequest,
-    ) -> Result<Deployments, RusotoError<GetDeploymentsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_deployments_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_deployments_request(&self, input: GetDeploymentsRequest) -> SignedRequest {
         let request_uri = format!(
             "/restapis/{restapi_id}/deployments",
             restapi_id = input.rest_api_id
         );
 
         let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
+        request.set_operation("GetDeployments");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -12034,27 +14311,54 @@ impl ApiGateway for ApiGatewayClient {
         }
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `get_documentation_part`, also returning the metadata of the response.
+    pub async fn get_documentation_part_with_metadata(
+        &self,
+        input: GetDocumentationPartRequest,
+    ) -> Result<(DocumentationPart, ResponseMetadata), RusotoError<GetDocumentationPartError>> {
+        let request = self.build_get_documentation_part_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            let result =
-                proto::json::ResponsePayload::new(&response).deserialize::<Deployments, _>()?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DocumentationPart, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(GetDeploymentsError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(GetDocumentationPartError::from_response(response))
         }
     }
 
-    async fn get_documentation_part(
+    /// Presigns the request `get_documentation_part` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_documentation_part(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetDocumentationPartRequest,
-    ) -> Result<DocumentationPart, RusotoError<GetDocumentationPartError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_documentation_part_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_documentation_part_request(
+        &self,
+        input: GetDocumentationPartRequest,
+    ) -> SignedRequest {
         let request_uri = format!(
             "/restapis/{restapi_id}/documentation/parts/{part_id}",
             part_id = input.documentation_part_id,
@@ -12062,35 +14366,65 @@ impl ApiGateway for ApiGatewayClient {
         );
 
         let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
+        request.set_operation("GetDocumentationPart");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
+        request
+    }
+
+    /// Calls `get_documentation_parts`, also returning the metadata of the response.
+    pub async fn get_documentation_parts_with_metadata(
+        &self,
+        input: GetDocumentationPartsRequest,
+    ) -> Result<(DocumentationParts, ResponseMetadata), RusotoError<GetDocumentationPartsError>>
+    {
+        let request = self.build_get_documentation_parts_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<DocumentationPart, _>()?;
+                .deserialize::<DocumentationParts, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(GetDocumentationPartError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(GetDocumentationPartsError::from_response(response))
         }
     }
 
-    async fn get_documentation_parts(
+    /// Presigns the request `get_documentation_parts` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_documentation_parts(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetDocumentationPartsRequest,
-    ) -> Result<DocumentationParts, RusotoError<GetDocumentationPartsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_documentation_parts_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_documentation_parts_request(
+        &self,
+        input: GetDocumentationPartsRequest,
+    ) -> SignedRequest {
         let request_uri = format!(
             "/restapis/{restapi_id}/documentation/parts",
             restapi_id = input.rest_api_id
         );
 
         let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
+        request.set_operation("GetDocumentationParts");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -12114,27 +14448,55 @@ impl ApiGateway for ApiGatewayClient {
         }
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `get_documentation_version`, also returning the metadata of the response.
+    pub async fn get_documentation_version_with_metadata(
+        &self,
+        input: GetDocumentationVersionRequest,
+    ) -> Result<(DocumentationVersion, ResponseMetadata), RusotoError<GetDocumentationVersionError>>
+    {
+        let request = self.build_get_documentation_version_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<DocumentationParts, _>()?;
+                .deserialize::<DocumentationVersion, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(GetDocumentationPartsError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(GetDocumentationVersionError::from_response(response))
         }
     }
 
-    async fn get_documentation_version(
+    /// Presigns the request `get_documentation_version` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_documentation_version(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetDocumentationVersionRequest,
-    ) -> Result<DocumentationVersion, RusotoError<GetDocumentationVersionError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_documentation_version_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_documentation_version_request(
+        &self,
+        input: GetDocumentationVersionRequest,
+    ) -> SignedRequest {
         let request_uri = format!(
             "/restapis/{restapi_id}/documentation/versions/{doc_version}",
             doc_version = input.documentation_version,
@@ -12142,35 +14504,65 @@ impl ApiGateway for ApiGatewayClient {
         );
 
         let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
+        request.set_operation("GetDocumentationVersion");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
+        request
+    }
+
+    /// Calls `get_documentation_versions`, also returning the metadata of the response.
+    pub async fn get_documentation_versions_with_metadata(
+        &self,
+        input: GetDocumentationVersionsRequest,
+    ) -> Result<(DocumentationVersions, ResponseMetadata), RusotoError<GetDocumentationVersionsError>>
+    {
+        let request = self.build_get_documentation_versions_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<DocumentationVersion, _>()?;
+                .deserialize::<DocumentationVersions, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(GetDocumentationVersionError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(GetDocumentationVersionsError::from_response(response))
         }
     }
 
-    async fn get_documentation_versions(
+    /// Presigns the request `get_documentation_versions` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_documentation_versions(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetDocumentationVersionsRequest,
-    ) -> Result<DocumentationVersions, RusotoError<GetDocumentationVersionsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_documentation_versions_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_documentation_versions_request(
+        &self,
+        input: GetDocumentationVersionsRequest,
+    ) -> SignedRequest {
         let request_uri = format!(
             "/restapis/{restapi_id}/documentation/versions",
             restapi_id = input.rest_api_id
         );
 
         let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
+        request.set_operation("GetDocumentationVersions");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -12182,61 +14574,109 @@ impl ApiGateway for ApiGatewayClient {
         }
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `get_domain_name`, also returning the metadata of the response.
+    pub async fn get_domain_name_with_metadata(
+        &self,
+        input: GetDomainNameRequest,
+    ) -> Result<(DomainName, ResponseMetadata), RusotoError<GetDomainNameError>> {
+        let request = self.build_get_domain_name_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<DocumentationVersions, _>()?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result =
+                proto::json::ResponsePayload::new(&response).deserialize::<DomainName, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(GetDocumentationVersionsError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(GetDomainNameError::from_response(response))
         }
     }
 
-    /// <p>Represents a domain name that is contained in a simpler, more intuitive URL that can be called.</p>
-    async fn get_domain_name(
+    /// Presigns the request `get_domain_name` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_domain_name(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetDomainNameRequest,
-    ) -> Result<DomainName, RusotoError<GetDomainNameError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_domain_name_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_domain_name_request(&self, input: GetDomainNameRequest) -> SignedRequest {
         let request_uri = format!(
             "/domainnames/{domain_name}",
             domain_name = input.domain_name
         );
 
         let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
+        request.set_operation("GetDomainName");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
+        request
+    }
+
+    /// Calls `get_domain_names`, also returning the metadata of the response.
+    pub async fn get_domain_names_with_metadata(
+        &self,
+        input: GetDomainNamesRequest,
+    ) -> Result<(DomainNames, ResponseMetadata), RusotoError<GetDomainNamesError>> {
+        let request = self.build_get_domain_names_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result =
-                proto::json::ResponsePayload::new(&response).deserialize::<DomainName, _>()?;
+                proto::json::ResponsePayload::new(&response).deserialize::<DomainNames, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(GetDomainNameError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(GetDomainNamesError::from_response(response))
         }
     }
 
-    /// <p>Represents a collection of <a>DomainName</a> resources.</p>
-    async fn get_domain_names(
+    /// Presigns the request `get_domain_names` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_domain_names(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetDomainNamesRequest,
-    ) -> Result<DomainNames, RusotoError<GetDomainNamesError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_domain_names_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_domain_names_request(&self, input: GetDomainNamesRequest) -> SignedRequest {
         let request_uri = "/domainnames";
 
         let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
+        request.set_operation("GetDomainNames");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -12248,28 +14688,61 @@ impl ApiGateway for ApiGatewayClient {
         }
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `get_export`, also returning the metadata of the response.
+    pub async fn get_export_with_metadata(
+        &self,
+        input: GetExportRequest,
+    ) -> Result<(ExportResponse, ResponseMetadata), RusotoError<GetExportError>> {
+        let request = self.build_get_export_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
-        if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            let result =
-                proto::json::ResponsePayload::new(&response).deserialize::<DomainNames, _>()?;
+        let metadata = ResponseMetadata::from_response(&response);
+        if response.status.as_u16() == 200 {
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+
+            let mut result = ExportResponse::default();
+            result.body = Some(response.body);
+
+            if let Some(content_disposition) = response.headers.get("Content-Disposition") {
+                let value = content_disposition.to_owned();
+                result.content_disposition = Some(value)
+            };
+            if let Some(content_type) = response.headers.get("Content-Type") {
+                let value = content_type.to_owned();
+                result.content_type = Some(value)
+            };
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(GetDomainNamesError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(GetExportError::from_response(response))
         }
     }
 
-    /// <p>Exports a deployed version of a <a>RestApi</a> in a specified format.</p>
-    async fn get_export(
+    /// Presigns the request `get_export` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_export(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetExportRequest,
-    ) -> Result<ExportResponse, RusotoError<GetExportError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_export_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_export_request(&self, input: GetExportRequest) -> SignedRequest {
         let request_uri = format!(
             "/restapis/{restapi_id}/stages/{stage_name}/exports/{export_type}",
             export_type = input.export_type,
@@ -12278,6 +14751,7 @@ impl ApiGateway for ApiGatewayClient {
         );
 
         let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
+        request.set_operation("GetExport");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         if let Some(ref accepts) = input.accepts {
@@ -12291,38 +14765,54 @@ impl ApiGateway for ApiGatewayClient {
         }
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `get_gateway_response`, also returning the metadata of the response.
+    pub async fn get_gateway_response_with_metadata(
+        &self,
+        input: GetGatewayResponseRequest,
+    ) -> Result<(GatewayResponse, ResponseMetadata), RusotoError<GetGatewayResponseError>> {
+        let request = self.build_get_gateway_response_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
-        if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-
-            let mut result = ExportResponse::default();
-            result.body = Some(response.body);
-
-            if let Some(content_disposition) = response.headers.get("Content-Disposition") {
-                let value = content_disposition.to_owned();
-                result.content_disposition = Some(value)
-            };
-            if let Some(content_type) = response.headers.get("Content-Type") {
-                let value = content_type.to_owned();
-                result.content_type = Some(value)
-            };
+        let metadata = ResponseMetadata::from_response(&response);
+        if response.status.is_success() {
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result =
+                proto::json::ResponsePayload::new(&response).deserialize::<GatewayResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(GetExportError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(GetGatewayResponseError::from_response(response))
         }
     }
 
-    /// <p>Gets a <a>GatewayResponse</a> of a specified response type on the given <a>RestApi</a>.</p>
-    async fn get_gateway_response(
+    /// Presigns the request `get_gateway_response` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_gateway_response(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetGatewayResponseRequest,
-    ) -> Result<GatewayResponse, RusotoError<GetGatewayResponseError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_gateway_response_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_gateway_response_request(
+        &self,
+        input: GetGatewayResponseRequest,
+    ) -> SignedRequest {
         let request_uri = format!(
             "/restapis/{restapi_id}/gatewayresponses/{response_type}",
             response_type = input.response_type,
@@ -12330,36 +14820,64 @@ impl ApiGateway for ApiGatewayClient {
         );
 
         let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
+        request.set_operation("GetGatewayResponse");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
+        request
+    }
+
+    /// Calls `get_gateway_responses`, also returning the metadata of the response.
+    pub async fn get_gateway_responses_with_metadata(
+        &self,
+        input: GetGatewayResponsesRequest,
+    ) -> Result<(GatewayResponses, ResponseMetadata), RusotoError<GetGatewayResponsesError>> {
+        let request = self.build_get_gateway_responses_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            let result =
-                proto::json::ResponsePayload::new(&response).deserialize::<GatewayResponse, _>()?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<GatewayResponses, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(GetGatewayResponseError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(GetGatewayResponsesError::from_response(response))
         }
     }
 
-    /// <p>Gets the <a>GatewayResponses</a> collection on the given <a>RestApi</a>. If an API developer has not added any definitions for gateway responses, the result will be the API Gateway-generated default <a>GatewayResponses</a> collection for the supported response types.</p>
-    async fn get_gateway_responses(
+    /// Presigns the request `get_gateway_responses` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_gateway_responses(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetGatewayResponsesRequest,
-    ) -> Result<GatewayResponses, RusotoError<GetGatewayResponsesError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_gateway_responses_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_gateway_responses_request(
+        &self,
+        input: GetGatewayResponsesRequest,
+    ) -> SignedRequest {
         let request_uri = format!(
             "/restapis/{restapi_id}/gatewayresponses",
             restapi_id = input.rest_api_id
         );
 
         let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
+        request.set_operation("GetGatewayResponses");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -12371,28 +14889,51 @@ impl ApiGateway for ApiGatewayClient {
         }
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `get_integration`, also returning the metadata of the response.
+    pub async fn get_integration_with_metadata(
+        &self,
+        input: GetIntegrationRequest,
+    ) -> Result<(Integration, ResponseMetadata), RusotoError<GetIntegrationError>> {
+        let request = self.build_get_integration_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<GatewayResponses, _>()?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result =
+                proto::json::ResponsePayload::new(&response).deserialize::<Integration, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(GetGatewayResponsesError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(GetIntegrationError::from_response(response))
         }
     }
 
-    /// <p>Get the integration settings.</p>
-    async fn get_integration(
+    /// Presigns the request `get_integration` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_integration(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetIntegrationRequest,
-    ) -> Result<Integration, RusotoError<GetIntegrationError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_integration_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_integration_request(&self, input: GetIntegrationRequest) -> SignedRequest {
         let request_uri = format!(
             "/restapis/{restapi_id}/resources/{resource_id}/methods/{http_method}/integration",
             http_method = input.http_method,
@@ -12401,57 +14942,108 @@ impl ApiGateway for ApiGatewayClient {
         );
 
         let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
+        request.set_operation("GetIntegration");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
+        request
+    }
+
+    /// Calls `get_integration_response`, also returning the metadata of the response.
+    pub async fn get_integration_response_with_metadata(
+        &self,
+        input: GetIntegrationResponseRequest,
+    ) -> Result<(IntegrationResponse, ResponseMetadata), RusotoError<GetIntegrationResponseError>>
+    {
+        let request = self.build_get_integration_response_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            let result =
-                proto::json::ResponsePayload::new(&response).deserialize::<Integration, _>()?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<IntegrationResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(GetIntegrationError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(GetIntegrationResponseError::from_response(response))
         }
     }
 
-    /// <p>Represents a get integration response.</p>
-    async fn get_integration_response(
+    /// Presigns the request `get_integration_response` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_integration_response(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetIntegrationResponseRequest,
-    ) -> Result<IntegrationResponse, RusotoError<GetIntegrationResponseError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_integration_response_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_integration_response_request(
+        &self,
+        input: GetIntegrationResponseRequest,
+    ) -> SignedRequest {
         let request_uri = format!("/restapis/{restapi_id}/resources/{resource_id}/methods/{http_method}/integration/responses/{status_code}", http_method = input.http_method, resource_id = input.resource_id, restapi_id = input.rest_api_id, status_code = input.status_code);
 
         let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
+        request.set_operation("GetIntegrationResponse");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
+        request
+    }
+
+    /// Calls `get_method`, also returning the metadata of the response.
+    pub async fn get_method_with_metadata(
+        &self,
+        input: GetMethodRequest,
+    ) -> Result<(Method, ResponseMetadata), RusotoError<GetMethodError>> {
+        let request = self.build_get_method_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<IntegrationResponse, _>()?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response).deserialize::<Method, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(GetIntegrationResponseError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(GetMethodError::from_response(response))
         }
     }
 
-    /// <p>Describe an existing <a>Method</a> resource.</p>
-    async fn get_method(
+    /// Presigns the request `get_method` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_method(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetMethodRequest,
-    ) -> Result<Method, RusotoError<GetMethodError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_method_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_method_request(&self, input: GetMethodRequest) -> SignedRequest {
         let request_uri = format!(
             "/restapis/{restapi_id}/resources/{resource_id}/methods/{http_method}",
             http_method = input.http_method,
@@ -12460,53 +15052,104 @@ impl ApiGateway for ApiGatewayClient {
         );
 
         let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
+        request.set_operation("GetMethod");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
+        request
+    }
+
+    /// Calls `get_method_response`, also returning the metadata of the response.
+    pub async fn get_method_response_with_metadata(
+        &self,
+        input: GetMethodResponseRequest,
+    ) -> Result<(MethodResponse, ResponseMetadata), RusotoError<GetMethodResponseError>> {
+        let request = self.build_get_method_response_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            let result = proto::json::ResponsePayload::new(&response).deserialize::<Method, _>()?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result =
+                proto::json::ResponsePayload::new(&response).deserialize::<MethodResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(GetMethodError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(GetMethodResponseError::from_response(response))
         }
     }
 
-    /// <p>Describes a <a>MethodResponse</a> resource.</p>
-    async fn get_method_response(
+    /// Presigns the request `get_method_response` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_method_response(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetMethodResponseRequest,
-    ) -> Result<MethodResponse, RusotoError<GetMethodResponseError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_method_response_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_method_response_request(&self, input: GetMethodResponseRequest) -> SignedRequest {
         let request_uri = format!("/restapis/{restapi_id}/resources/{resource_id}/methods/{http_method}/responses/{status_code}", http_method = input.http_method, resource_id = input.resource_id, restapi_id = input.rest_api_id, status_code = input.status_code);
 
         let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
+        request.set_operation("GetMethodResponse");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
+        request
+    }
+
+    /// Calls `get_model`, also returning the metadata of the response.
+    pub async fn get_model_with_metadata(
+        &self,
+        input: GetModelRequest,
+    ) -> Result<(Model, ResponseMetadata), RusotoError<GetModelError>> {
+        let request = self.build_get_model_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            let result =
-                proto::json::ResponsePayload::new(&response).deserialize::<MethodResponse, _>()?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response).deserialize::<Model, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(GetMethodResponseError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(GetModelError::from_response(response))
         }
     }
 
-    /// <p>Describes an existing model defined for a <a>RestApi</a> resource.</p>
-    async fn get_model(&self, input: GetModelRequest) -> Result<Model, RusotoError<GetModelError>> {
+    /// Presigns the request `get_model` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_model(
+        &self,
+        presigner: &rusoto_core::Presigner,
+        input: GetModelRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_model_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_model_request(&self, input: GetModelRequest) -> SignedRequest {
         let request_uri = format!(
             "/restapis/{restapi_id}/models/{model_name}",
             model_name = input.model_name,
@@ -12514,6 +15157,7 @@ impl ApiGateway for ApiGatewayClient {
         );
 
         let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
+        request.set_operation("GetModel");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -12522,27 +15166,51 @@ impl ApiGateway for ApiGatewayClient {
         }
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `get_model_template`, also returning the metadata of the response.
+    pub async fn get_model_template_with_metadata(
+        &self,
+        input: GetModelTemplateRequest,
+    ) -> Result<(Template, ResponseMetadata), RusotoError<GetModelTemplateError>> {
+        let request = self.build_get_model_template_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            let result = proto::json::ResponsePayload::new(&response).deserialize::<Model, _>()?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result =
+                proto::json::ResponsePayload::new(&response).deserialize::<Template, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(GetModelError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(GetModelTemplateError::from_response(response))
         }
     }
 
-    /// <p>Generates a sample mapping template that can be used to transform a payload into the structure of a model.</p>
-    async fn get_model_template(
+    /// Presigns the request `get_model_template` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_model_template(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetModelTemplateRequest,
-    ) -> Result<Template, RusotoError<GetModelTemplateError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_model_template_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_model_template_request(&self, input: GetModelTemplateRequest) -> SignedRequest {
         let request_uri = format!(
             "/restapis/{restapi_id}/models/{model_name}/default_template",
             model_name = input.model_name,
@@ -12550,36 +15218,60 @@ impl ApiGateway for ApiGatewayClient {
         );
 
         let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
+        request.set_operation("GetModelTemplate");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
+        request
+    }
+
+    /// Calls `get_models`, also returning the metadata of the response.
+    pub async fn get_models_with_metadata(
+        &self,
+        input: GetModelsRequest,
+    ) -> Result<(Models, ResponseMetadata), RusotoError<GetModelsError>> {
+        let request = self.build_get_models_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            let result =
-                proto::json::ResponsePayload::new(&response).deserialize::<Template, _>()?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response).deserialize::<Models, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(GetModelTemplateError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(GetModelsError::from_response(response))
         }
     }
 
-    /// <p>Describes existing <a>Models</a> defined for a <a>RestApi</a> resource.</p>
-    async fn get_models(
+    /// Presigns the request `get_models` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_models(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetModelsRequest,
-    ) -> Result<Models, RusotoError<GetModelsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_models_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_models_request(&self, input: GetModelsRequest) -> SignedRequest {
         let request_uri = format!(
             "/restapis/{restapi_id}/models",
             restapi_id = input.rest_api_id
         );
 
         let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
+        request.set_operation("GetModels");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -12591,27 +15283,54 @@ impl ApiGateway for ApiGatewayClient {
         }
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `get_request_validator`, also returning the metadata of the response.
+    pub async fn get_request_validator_with_metadata(
+        &self,
+        input: GetRequestValidatorRequest,
+    ) -> Result<(RequestValidator, ResponseMetadata), RusotoError<GetRequestValidatorError>> {
+        let request = self.build_get_request_validator_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            let result = proto::json::ResponsePayload::new(&response).deserialize::<Models, _>()?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<RequestValidator, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(GetModelsError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(GetRequestValidatorError::from_response(response))
         }
     }
 
-    /// <p>Gets a <a>RequestValidator</a> of a given <a>RestApi</a>.</p>
-    async fn get_request_validator(
+    /// Presigns the request `get_request_validator` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_request_validator(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetRequestValidatorRequest,
-    ) -> Result<RequestValidator, RusotoError<GetRequestValidatorError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_request_validator_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_request_validator_request(
+        &self,
+        input: GetRequestValidatorRequest,
+    ) -> SignedRequest {
         let request_uri = format!(
             "/restapis/{restapi_id}/requestvalidators/{requestvalidator_id}",
             requestvalidator_id = input.request_validator_id,
@@ -12619,36 +15338,64 @@ impl ApiGateway for ApiGatewayClient {
         );
 
         let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
+        request.set_operation("GetRequestValidator");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
+        request
+    }
+
+    /// Calls `get_request_validators`, also returning the metadata of the response.
+    pub async fn get_request_validators_with_metadata(
+        &self,
+        input: GetRequestValidatorsRequest,
+    ) -> Result<(RequestValidators, ResponseMetadata), RusotoError<GetRequestValidatorsError>> {
+        let request = self.build_get_request_validators_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<RequestValidator, _>()?;
+                .deserialize::<RequestValidators, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(GetRequestValidatorError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(GetRequestValidatorsError::from_response(response))
         }
     }
 
-    /// <p>Gets the <a>RequestValidators</a> collection of a given <a>RestApi</a>.</p>
-    async fn get_request_validators(
+    /// Presigns the request `get_request_validators` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_request_validators(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetRequestValidatorsRequest,
-    ) -> Result<RequestValidators, RusotoError<GetRequestValidatorsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_request_validators_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_request_validators_request(
+        &self,
+        input: GetRequestValidatorsRequest,
+    ) -> SignedRequest {
         let request_uri = format!(
             "/restapis/{restapi_id}/requestvalidators",
             restapi_id = input.rest_api_id
         );
 
         let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
+        request.set_operation("GetRequestValidators");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -12660,28 +15407,51 @@ impl ApiGateway for ApiGatewayClient {
         }
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `get_resource`, also returning the metadata of the response.
+    pub async fn get_resource_with_metadata(
+        &self,
+        input: GetResourceRequest,
+    ) -> Result<(Resource, ResponseMetadata), RusotoError<GetResourceError>> {
+        let request = self.build_get_resource_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<RequestValidators, _>()?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result =
+                proto::json::ResponsePayload::new(&response).deserialize::<Resource, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(GetRequestValidatorsError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(GetResourceError::from_response(response))
         }
     }
 
-    /// <p>Lists information about a resource.</p>
-    async fn get_resource(
+    /// Presigns the request `get_resource` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_resource(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetResourceRequest,
-    ) -> Result<Resource, RusotoError<GetResourceError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_resource_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_resource_request(&self, input: GetResourceRequest) -> SignedRequest {
         let request_uri = format!(
             "/restapis/{restapi_id}/resources/{resource_id}",
             resource_id = input.resource_id,
@@ -12689,6 +15459,7 @@ impl ApiGateway for ApiGatewayClient {
         );
 
         let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
+        request.set_operation("GetResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -12699,34 +15470,58 @@ impl ApiGateway for ApiGatewayClient {
         }
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `get_resources`, also returning the metadata of the response.
+    pub async fn get_resources_with_metadata(
+        &self,
+        input: GetResourcesRequest,
+    ) -> Result<(Resources, ResponseMetadata), RusotoError<GetResourcesError>> {
+        let request = self.build_get_resources_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result =
-                proto::json::ResponsePayload::new(&response).deserialize::<Resource, _>()?;
+                proto::json::ResponsePayload::new(&response).deserialize::<Resources, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(GetResourceError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(GetResourcesError::from_response(response))
         }
     }
 
-    /// <p>Lists information about a collection of <a>Resource</a> resources.</p>
-    async fn get_resources(
+    /// Presigns the request `get_resources` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_resources(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetResourcesRequest,
-    ) -> Result<Resources, RusotoError<GetResourcesError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_resources_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_resources_request(&self, input: GetResourcesRequest) -> SignedRequest {
         let request_uri = format!(
             "/restapis/{restapi_id}/resources",
             restapi_id = input.rest_api_id
         );
 
         let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
+        request.set_operation("GetResources");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -12743,58 +15538,106 @@ impl ApiGateway for ApiGatewayClient {
         }
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `get_rest_api`, also returning the metadata of the response.
+    pub async fn get_rest_api_with_metadata(
+        &self,
+        input: GetRestApiRequest,
+    ) -> Result<(RestApi, ResponseMetadata), RusotoError<GetRestApiError>> {
+        let request = self.build_get_rest_api_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result =
-                proto::json::ResponsePayload::new(&response).deserialize::<Resources, _>()?;
+                proto::json::ResponsePayload::new(&response).deserialize::<RestApi, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(GetResourcesError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(GetRestApiError::from_response(response))
         }
     }
 
-    /// <p>Lists the <a>RestApi</a> resource in the collection.</p>
-    async fn get_rest_api(
+    /// Presigns the request `get_rest_api` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_rest_api(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetRestApiRequest,
-    ) -> Result<RestApi, RusotoError<GetRestApiError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_rest_api_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_rest_api_request(&self, input: GetRestApiRequest) -> SignedRequest {
         let request_uri = format!("/restapis/{restapi_id}", restapi_id = input.rest_api_id);
 
         let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
+        request.set_operation("GetRestApi");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
+        request
+    }
+
+    /// Calls `get_rest_apis`, also returning the metadata of the response.
+    pub async fn get_rest_apis_with_metadata(
+        &self,
+        input: GetRestApisRequest,
+    ) -> Result<(RestApis, ResponseMetadata), RusotoError<GetRestApisError>> {
+        let request = self.build_get_rest_apis_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result =
-                proto::json::ResponsePayload::new(&response).deserialize::<RestApi, _>()?;
+                proto::json::ResponsePayload::new(&response).deserialize::<RestApis, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(GetRestApiError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(GetRestApisError::from_response(response))
         }
     }
 
-    /// <p>Lists the <a>RestApis</a> resources for your collection.</p>
-    async fn get_rest_apis(
+    /// Presigns the request `get_rest_apis` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_rest_apis(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetRestApisRequest,
-    ) -> Result<RestApis, RusotoError<GetRestApisError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_rest_apis_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_rest_apis_request(&self, input: GetRestApisRequest) -> SignedRequest {
         let request_uri = "/restapis";
 
         let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
+        request.set_operation("GetRestApis");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -12806,25 +15649,61 @@ impl ApiGateway for ApiGatewayClient {
         }
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `get_sdk`, also returning the metadata of the response.
+    pub async fn get_sdk_with_metadata(
+        &self,
+        input: GetSdkRequest,
+    ) -> Result<(SdkResponse, ResponseMetadata), RusotoError<GetSdkError>> {
+        let request = self.build_get_sdk_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
-        if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            let result =
-                proto::json::ResponsePayload::new(&response).deserialize::<RestApis, _>()?;
+        let metadata = ResponseMetadata::from_response(&response);
+        if response.status.as_u16() == 200 {
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+
+            let mut result = SdkResponse::default();
+            result.body = Some(response.body);
+
+            if let Some(content_disposition) = response.headers.get("Content-Disposition") {
+                let value = content_disposition.to_owned();
+                result.content_disposition = Some(value)
+            };
+            if let Some(content_type) = response.headers.get("Content-Type") {
+                let value = content_type.to_owned();
+                result.content_type = Some(value)
+            };
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(GetRestApisError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(GetSdkError::from_response(response))
         }
     }
 
-    /// <p>Generates a client SDK for a <a>RestApi</a> and <a>Stage</a>.</p>
-    async fn get_sdk(&self, input: GetSdkRequest) -> Result<SdkResponse, RusotoError<GetSdkError>> {
+    /// Presigns the request `get_sdk` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_sdk(
+        &self,
+        presigner: &rusoto_core::Presigner,
+        input: GetSdkRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_sdk_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_sdk_request(&self, input: GetSdkRequest) -> SignedRequest {
         let request_uri = format!(
             "/restapis/{restapi_id}/stages/{stage_name}/sdks/{sdk_type}",
             restapi_id = input.rest_api_id,
@@ -12833,6 +15712,7 @@ impl ApiGateway for ApiGatewayClient {
         );
 
         let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
+        request.set_operation("GetSdk");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -12843,66 +15723,106 @@ impl ApiGateway for ApiGatewayClient {
         }
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `get_sdk_type`, also returning the metadata of the response.
+    pub async fn get_sdk_type_with_metadata(
+        &self,
+        input: GetSdkTypeRequest,
+    ) -> Result<(SdkType, ResponseMetadata), RusotoError<GetSdkTypeError>> {
+        let request = self.build_get_sdk_type_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
-        if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-
-            let mut result = SdkResponse::default();
-            result.body = Some(response.body);
-
-            if let Some(content_disposition) = response.headers.get("Content-Disposition") {
-                let value = content_disposition.to_owned();
-                result.content_disposition = Some(value)
-            };
-            if let Some(content_type) = response.headers.get("Content-Type") {
-                let value = content_type.to_owned();
-                result.content_type = Some(value)
-            };
+        let metadata = ResponseMetadata::from_response(&response);
+        if response.status.is_success() {
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result =
+                proto::json::ResponsePayload::new(&response).deserialize::<SdkType, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(GetSdkError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(GetSdkTypeError::from_response(response))
         }
     }
 
-    async fn get_sdk_type(
+    /// Presigns the request `get_sdk_type` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_sdk_type(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetSdkTypeRequest,
-    ) -> Result<SdkType, RusotoError<GetSdkTypeError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_sdk_type_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_sdk_type_request(&self, input: GetSdkTypeRequest) -> SignedRequest {
         let request_uri = format!("/sdktypes/{sdktype_id}", sdktype_id = input.id);
 
         let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
+        request.set_operation("GetSdkType");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
+        request
+    }
+
+    /// Calls `get_sdk_types`, also returning the metadata of the response.
+    pub async fn get_sdk_types_with_metadata(
+        &self,
+        input: GetSdkTypesRequest,
+    ) -> Result<(SdkTypes, ResponseMetadata), RusotoError<GetSdkTypesError>> {
+        let request = self.build_get_sdk_types_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result =
-                proto::json::ResponsePayload::new(&response).deserialize::<SdkType, _>()?;
+                proto::json::ResponsePayload::new(&response).deserialize::<SdkTypes, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(GetSdkTypeError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(GetSdkTypesError::from_response(response))
         }
     }
 
-    async fn get_sdk_types(
+    /// Presigns the request `get_sdk_types` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_sdk_types(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetSdkTypesRequest,
-    ) -> Result<SdkTypes, RusotoError<GetSdkTypesError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_sdk_types_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_sdk_types_request(&self, input: GetSdkTypesRequest) -> SignedRequest {
         let request_uri = "/sdktypes";
 
         let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
+        request.set_operation("GetSdkTypes");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -12914,25 +15834,50 @@ impl ApiGateway for ApiGatewayClient {
         }
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `get_stage`, also returning the metadata of the response.
+    pub async fn get_stage_with_metadata(
+        &self,
+        input: GetStageRequest,
+    ) -> Result<(Stage, ResponseMetadata), RusotoError<GetStageError>> {
+        let request = self.build_get_stage_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            let result =
-                proto::json::ResponsePayload::new(&response).deserialize::<SdkTypes, _>()?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response).deserialize::<Stage, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(GetSdkTypesError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(GetStageError::from_response(response))
         }
     }
 
-    /// <p>Gets information about a <a>Stage</a> resource.</p>
-    async fn get_stage(&self, input: GetStageRequest) -> Result<Stage, RusotoError<GetStageError>> {
+    /// Presigns the request `get_stage` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_stage(
+        &self,
+        presigner: &rusoto_core::Presigner,
+        input: GetStageRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_stage_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_stage_request(&self, input: GetStageRequest) -> SignedRequest {
         let request_uri = format!(
             "/restapis/{restapi_id}/stages/{stage_name}",
             restapi_id = input.rest_api_id,
@@ -12940,35 +15885,60 @@ impl ApiGateway for ApiGatewayClient {
         );
 
         let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
+        request.set_operation("GetStage");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
+        request
+    }
+
+    /// Calls `get_stages`, also returning the metadata of the response.
+    pub async fn get_stages_with_metadata(
+        &self,
+        input: GetStagesRequest,
+    ) -> Result<(Stages, ResponseMetadata), RusotoError<GetStagesError>> {
+        let request = self.build_get_stages_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            let result = proto::json::ResponsePayload::new(&response).deserialize::<Stage, _>()?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response).deserialize::<Stages, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(GetStageError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(GetStagesError::from_response(response))
         }
     }
 
-    /// <p>Gets information about one or more <a>Stage</a> resources.</p>
-    async fn get_stages(
+    /// Presigns the request `get_stages` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_stages(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetStagesRequest,
-    ) -> Result<Stages, RusotoError<GetStagesError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_stages_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_stages_request(&self, input: GetStagesRequest) -> SignedRequest {
         let request_uri = format!(
             "/restapis/{restapi_id}/stages",
             restapi_id = input.rest_api_id
         );
 
         let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
+        request.set_operation("GetStages");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -12977,27 +15947,54 @@ impl ApiGateway for ApiGatewayClient {
         }
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `get_tags`, also returning the metadata of the response.
+    pub async fn get_tags_with_metadata(
+        &self,
+        input: GetTagsRequest,
+    ) -> Result<(Tags, ResponseMetadata), RusotoError<GetTagsError>> {
+        let request = self.build_get_tags_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            let result = proto::json::ResponsePayload::new(&response).deserialize::<Stages, _>()?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response).deserialize::<Tags, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(GetStagesError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(GetTagsError::from_response(response))
         }
     }
 
-    /// <p>Gets the <a>Tags</a> collection for a given resource.</p>
-    async fn get_tags(&self, input: GetTagsRequest) -> Result<Tags, RusotoError<GetTagsError>> {
+    /// Presigns the request `get_tags` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_tags(
+        &self,
+        presigner: &rusoto_core::Presigner,
+        input: GetTagsRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_tags_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_tags_request(&self, input: GetTagsRequest) -> SignedRequest {
         let request_uri = format!("/tags/{resource_arn}", resource_arn = input.resource_arn);
 
         let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
+        request.set_operation("GetTags");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -13009,30 +16006,57 @@ impl ApiGateway for ApiGatewayClient {
         }
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `get_usage`, also returning the metadata of the response.
+    pub async fn get_usage_with_metadata(
+        &self,
+        input: GetUsageRequest,
+    ) -> Result<(Usage, ResponseMetadata), RusotoError<GetUsageError>> {
+        let request = self.build_get_usage_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            let result = proto::json::ResponsePayload::new(&response).deserialize::<Tags, _>()?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response).deserialize::<Usage, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(GetTagsError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(GetUsageError::from_response(response))
         }
     }
 
-    /// <p>Gets the usage data of a usage plan in a specified time interval.</p>
-    async fn get_usage(&self, input: GetUsageRequest) -> Result<Usage, RusotoError<GetUsageError>> {
+    /// Presigns the request `get_usage` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_usage(
+        &self,
+        presigner: &rusoto_core::Presigner,
+        input: GetUsageRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_usage_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_usage_request(&self, input: GetUsageRequest) -> SignedRequest {
         let request_uri = format!(
             "/usageplans/{usageplan_id}/usage",
             usageplan_id = input.usage_plan_id
         );
 
         let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
+        request.set_operation("GetUsage");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -13049,57 +16073,105 @@ impl ApiGateway for ApiGatewayClient {
         params.put("startDate", &input.start_date);
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `get_usage_plan`, also returning the metadata of the response.
+    pub async fn get_usage_plan_with_metadata(
+        &self,
+        input: GetUsagePlanRequest,
+    ) -> Result<(UsagePlan, ResponseMetadata), RusotoError<GetUsagePlanError>> {
+        let request = self.build_get_usage_plan_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            let result = proto::json::ResponsePayload::new(&response).deserialize::<Usage, _>()?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result =
+                proto::json::ResponsePayload::new(&response).deserialize::<UsagePlan, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(GetUsageError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(GetUsagePlanError::from_response(response))
         }
     }
 
-    /// <p>Gets a usage plan of a given plan identifier.</p>
-    async fn get_usage_plan(
+    /// Presigns the request `get_usage_plan` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_usage_plan(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetUsagePlanRequest,
-    ) -> Result<UsagePlan, RusotoError<GetUsagePlanError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_usage_plan_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_usage_plan_request(&self, input: GetUsagePlanRequest) -> SignedRequest {
         let request_uri = format!(
             "/usageplans/{usageplan_id}",
             usageplan_id = input.usage_plan_id
         );
 
         let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
+        request.set_operation("GetUsagePlan");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
+        request
+    }
+
+    /// Calls `get_usage_plan_key`, also returning the metadata of the response.
+    pub async fn get_usage_plan_key_with_metadata(
+        &self,
+        input: GetUsagePlanKeyRequest,
+    ) -> Result<(UsagePlanKey, ResponseMetadata), RusotoError<GetUsagePlanKeyError>> {
+        let request = self.build_get_usage_plan_key_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
-        if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let metadata = ResponseMetadata::from_response(&response);
+        if response.status.as_u16() == 200 {
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result =
-                proto::json::ResponsePayload::new(&response).deserialize::<UsagePlan, _>()?;
+                proto::json::ResponsePayload::new(&response).deserialize::<UsagePlanKey, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(GetUsagePlanError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(GetUsagePlanKeyError::from_response(response))
         }
     }
 
-    /// <p>Gets a usage plan key of a given key identifier.</p>
-    async fn get_usage_plan_key(
+    /// Presigns the request `get_usage_plan_key` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_usage_plan_key(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetUsagePlanKeyRequest,
-    ) -> Result<UsagePlanKey, RusotoError<GetUsagePlanKeyError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_usage_plan_key_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_usage_plan_key_request(&self, input: GetUsagePlanKeyRequest) -> SignedRequest {
         let request_uri = format!(
             "/usageplans/{usageplan_id}/keys/{key_id}",
             key_id = input.key_id,
@@ -13107,36 +16179,61 @@ impl ApiGateway for ApiGatewayClient {
         );
 
         let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
+        request.set_operation("GetUsagePlanKey");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
+        request
+    }
+
+    /// Calls `get_usage_plan_keys`, also returning the metadata of the response.
+    pub async fn get_usage_plan_keys_with_metadata(
+        &self,
+        input: GetUsagePlanKeysRequest,
+    ) -> Result<(UsagePlanKeys, ResponseMetadata), RusotoError<GetUsagePlanKeysError>> {
+        let request = self.build_get_usage_plan_keys_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
-        if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let metadata = ResponseMetadata::from_response(&response);
+        if response.status.is_success() {
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result =
-                proto::json::ResponsePayload::new(&response).deserialize::<UsagePlanKey, _>()?;
+                proto::json::ResponsePayload::new(&response).deserialize::<UsagePlanKeys, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(GetUsagePlanKeyError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(GetUsagePlanKeysError::from_response(response))
         }
     }
 
-    /// <p>Gets all the usage plan keys representing the API keys added to a specified usage plan.</p>
-    async fn get_usage_plan_keys(
+    /// Presigns the request `get_usage_plan_keys` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_usage_plan_keys(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetUsagePlanKeysRequest,
-    ) -> Result<UsagePlanKeys, RusotoError<GetUsagePlanKeysError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_usage_plan_keys_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_usage_plan_keys_request(&self, input: GetUsagePlanKeysRequest) -> SignedRequest {
         let request_uri = format!(
             "/usageplans/{usageplan_id}/keys",
             usageplan_id = input.usage_plan_id
         );
 
         let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
+        request.set_operation("GetUsagePlanKeys");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -13151,31 +16248,55 @@ impl ApiGateway for ApiGatewayClient {
         }
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `get_usage_plans`, also returning the metadata of the response.
+    pub async fn get_usage_plans_with_metadata(
+        &self,
+        input: GetUsagePlansRequest,
+    ) -> Result<(UsagePlans, ResponseMetadata), RusotoError<GetUsagePlansError>> {
+        let request = self.build_get_usage_plans_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result =
-                proto::json::ResponsePayload::new(&response).deserialize::<UsagePlanKeys, _>()?;
+                proto::json::ResponsePayload::new(&response).deserialize::<UsagePlans, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(GetUsagePlanKeysError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(GetUsagePlansError::from_response(response))
         }
     }
 
-    /// <p>Gets all the usage plans of the caller's account.</p>
-    async fn get_usage_plans(
+    /// Presigns the request `get_usage_plans` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_usage_plans(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetUsagePlansRequest,
-    ) -> Result<UsagePlans, RusotoError<GetUsagePlansError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_usage_plans_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_usage_plans_request(&self, input: GetUsagePlansRequest) -> SignedRequest {
         let request_uri = "/usageplans";
 
         let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
+        request.set_operation("GetUsagePlans");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -13190,58 +16311,106 @@ impl ApiGateway for ApiGatewayClient {
         }
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `get_vpc_link`, also returning the metadata of the response.
+    pub async fn get_vpc_link_with_metadata(
+        &self,
+        input: GetVpcLinkRequest,
+    ) -> Result<(VpcLink, ResponseMetadata), RusotoError<GetVpcLinkError>> {
+        let request = self.build_get_vpc_link_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result =
-                proto::json::ResponsePayload::new(&response).deserialize::<UsagePlans, _>()?;
+                proto::json::ResponsePayload::new(&response).deserialize::<VpcLink, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(GetUsagePlansError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(GetVpcLinkError::from_response(response))
         }
     }
 
-    /// <p>Gets a specified VPC link under the caller's account in a region.</p>
-    async fn get_vpc_link(
+    /// Presigns the request `get_vpc_link` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_vpc_link(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetVpcLinkRequest,
-    ) -> Result<VpcLink, RusotoError<GetVpcLinkError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_vpc_link_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_vpc_link_request(&self, input: GetVpcLinkRequest) -> SignedRequest {
         let request_uri = format!("/vpclinks/{vpclink_id}", vpclink_id = input.vpc_link_id);
 
         let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
+        request.set_operation("GetVpcLink");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
+        request
+    }
+
+    /// Calls `get_vpc_links`, also returning the metadata of the response.
+    pub async fn get_vpc_links_with_metadata(
+        &self,
+        input: GetVpcLinksRequest,
+    ) -> Result<(VpcLinks, ResponseMetadata), RusotoError<GetVpcLinksError>> {
+        let request = self.build_get_vpc_links_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result =
-                proto::json::ResponsePayload::new(&response).deserialize::<VpcLink, _>()?;
+                proto::json::ResponsePayload::new(&response).deserialize::<VpcLinks, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(GetVpcLinkError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(GetVpcLinksError::from_response(response))
         }
     }
 
-    /// <p>Gets the <a>VpcLinks</a> collection under the caller's account in a selected region.</p>
-    async fn get_vpc_links(
+    /// Presigns the request `get_vpc_links` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_vpc_links(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetVpcLinksRequest,
-    ) -> Result<VpcLinks, RusotoError<GetVpcLinksError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_vpc_links_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_vpc_links_request(&self, input: GetVpcLinksRequest) -> SignedRequest {
         let request_uri = "/vpclinks";
 
         let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
+        request.set_operation("GetVpcLinks");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -13253,31 +16422,55 @@ impl ApiGateway for ApiGatewayClient {
         }
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `import_api_keys`, also returning the metadata of the response.
+    pub async fn import_api_keys_with_metadata(
+        &self,
+        input: ImportApiKeysRequest,
+    ) -> Result<(ApiKeyIds, ResponseMetadata), RusotoError<ImportApiKeysError>> {
+        let request = self.build_import_api_keys_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
-        if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let metadata = ResponseMetadata::from_response(&response);
+        if response.status.as_u16() == 201 {
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result =
-                proto::json::ResponsePayload::new(&response).deserialize::<VpcLinks, _>()?;
+                proto::json::ResponsePayload::new(&response).deserialize::<ApiKeyIds, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(GetVpcLinksError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(ImportApiKeysError::from_response(response))
         }
     }
 
-    /// <p>Import API keys from an external source, such as a CSV-formatted file.</p>
-    async fn import_api_keys(
+    /// Presigns the request `import_api_keys` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_import_api_keys(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ImportApiKeysRequest,
-    ) -> Result<ApiKeyIds, RusotoError<ImportApiKeysError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_import_api_keys_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_import_api_keys_request(&self, input: ImportApiKeysRequest) -> SignedRequest {
         let request_uri = "/apikeys";
 
         let mut request = SignedRequest::new("POST", "apigateway", &self.region, &request_uri);
+        request.set_operation("ImportApiKeys");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(input.body.to_owned());
@@ -13291,33 +16484,62 @@ impl ApiGateway for ApiGatewayClient {
         params.put("mode", "import");
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `import_documentation_parts`, also returning the metadata of the response.
+    pub async fn import_documentation_parts_with_metadata(
+        &self,
+        input: ImportDocumentationPartsRequest,
+    ) -> Result<(DocumentationPartIds, ResponseMetadata), RusotoError<ImportDocumentationPartsError>>
+    {
+        let request = self.build_import_documentation_parts_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
-        if response.status.as_u16() == 201 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            let result =
-                proto::json::ResponsePayload::new(&response).deserialize::<ApiKeyIds, _>()?;
+        let metadata = ResponseMetadata::from_response(&response);
+        if response.status.is_success() {
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DocumentationPartIds, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(ImportApiKeysError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(ImportDocumentationPartsError::from_response(response))
         }
     }
 
-    async fn import_documentation_parts(
+    /// Presigns the request `import_documentation_parts` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_import_documentation_parts(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ImportDocumentationPartsRequest,
-    ) -> Result<DocumentationPartIds, RusotoError<ImportDocumentationPartsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_import_documentation_parts_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_import_documentation_parts_request(
+        &self,
+        input: ImportDocumentationPartsRequest,
+    ) -> SignedRequest {
         let request_uri = format!(
             "/restapis/{restapi_id}/documentation/parts",
             restapi_id = input.rest_api_id
         );
 
         let mut request = SignedRequest::new("PUT", "apigateway", &self.region, &request_uri);
+        request.set_operation("ImportDocumentationParts");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(input.body.to_owned());
@@ -13332,31 +16554,55 @@ impl ApiGateway for ApiGatewayClient {
         }
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `import_rest_api`, also returning the metadata of the response.
+    pub async fn import_rest_api_with_metadata(
+        &self,
+        input: ImportRestApiRequest,
+    ) -> Result<(RestApi, ResponseMetadata), RusotoError<ImportRestApiError>> {
+        let request = self.build_import_rest_api_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
-        if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<DocumentationPartIds, _>()?;
+        let metadata = ResponseMetadata::from_response(&response);
+        if response.status.as_u16() == 201 {
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result =
+                proto::json::ResponsePayload::new(&response).deserialize::<RestApi, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(ImportDocumentationPartsError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(ImportRestApiError::from_response(response))
         }
     }
 
-    /// <p>A feature of the API Gateway control service for creating a new API from an external API definition file.</p>
-    async fn import_rest_api(
+    /// Presigns the request `import_rest_api` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_import_rest_api(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ImportRestApiRequest,
-    ) -> Result<RestApi, RusotoError<ImportRestApiError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_import_rest_api_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_import_rest_api_request(&self, input: ImportRestApiRequest) -> SignedRequest {
         let request_uri = "/restapis";
 
         let mut request = SignedRequest::new("POST", "apigateway", &self.region, &request_uri);
+        request.set_operation("ImportRestApi");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(input.body.to_owned());
@@ -13374,28 +16620,54 @@ impl ApiGateway for ApiGatewayClient {
         params.put("mode", "import");
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `put_gateway_response`, also returning the metadata of the response.
+    pub async fn put_gateway_response_with_metadata(
+        &self,
+        input: PutGatewayResponseRequest,
+    ) -> Result<(GatewayResponse, ResponseMetadata), RusotoError<PutGatewayResponseError>> {
+        let request = self.build_put_gateway_response_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 201 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result =
-                proto::json::ResponsePayload::new(&response).deserialize::<RestApi, _>()?;
+                proto::json::ResponsePayload::new(&response).deserialize::<GatewayResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(ImportRestApiError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(PutGatewayResponseError::from_response(response))
         }
     }
 
-    /// <p>Creates a customization of a <a>GatewayResponse</a> of a specified response type and status code on the given <a>RestApi</a>.</p>
-    async fn put_gateway_response(
+    /// Presigns the request `put_gateway_response` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_put_gateway_response(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: PutGatewayResponseRequest,
-    ) -> Result<GatewayResponse, RusotoError<PutGatewayResponseError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_put_gateway_response_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_put_gateway_response_request(
+        &self,
+        input: PutGatewayResponseRequest,
+    ) -> SignedRequest {
         let request_uri = format!(
             "/restapis/{restapi_id}/gatewayresponses/{response_type}",
             response_type = input.response_type,
@@ -13403,33 +16675,57 @@ impl ApiGateway for ApiGatewayClient {
         );
 
         let mut request = SignedRequest::new("PUT", "apigateway", &self.region, &request_uri);
+        request.set_operation("PutGatewayResponse");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `put_integration`, also returning the metadata of the response.
+    pub async fn put_integration_with_metadata(
+        &self,
+        input: PutIntegrationRequest,
+    ) -> Result<(Integration, ResponseMetadata), RusotoError<PutIntegrationError>> {
+        let request = self.build_put_integration_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 201 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result =
-                proto::json::ResponsePayload::new(&response).deserialize::<GatewayResponse, _>()?;
+                proto::json::ResponsePayload::new(&response).deserialize::<Integration, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(PutGatewayResponseError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(PutIntegrationError::from_response(response))
         }
     }
 
-    /// <p>Sets up a method's integration.</p>
-    async fn put_integration(
+    /// Presigns the request `put_integration` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_put_integration(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: PutIntegrationRequest,
-    ) -> Result<Integration, RusotoError<PutIntegrationError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_put_integration_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_put_integration_request(&self, input: PutIntegrationRequest) -> SignedRequest {
         let request_uri = format!(
             "/restapis/{restapi_id}/resources/{resource_id}/methods/{http_method}/integration",
             http_method = input.http_method,
@@ -13438,63 +16734,114 @@ impl ApiGateway for ApiGatewayClient {
         );
 
         let mut request = SignedRequest::new("PUT", "apigateway", &self.region, &request_uri);
+        request.set_operation("PutIntegration");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `put_integration_response`, also returning the metadata of the response.
+    pub async fn put_integration_response_with_metadata(
+        &self,
+        input: PutIntegrationResponseRequest,
+    ) -> Result<(IntegrationResponse, ResponseMetadata), RusotoError<PutIntegrationResponseError>>
+    {
+        let request = self.build_put_integration_response_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 201 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            let result =
-                proto::json::ResponsePayload::new(&response).deserialize::<Integration, _>()?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<IntegrationResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(PutIntegrationError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(PutIntegrationResponseError::from_response(response))
         }
     }
 
-    /// <p>Represents a put integration.</p>
-    async fn put_integration_response(
+    /// Presigns the request `put_integration_response` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_put_integration_response(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: PutIntegrationResponseRequest,
-    ) -> Result<IntegrationResponse, RusotoError<PutIntegrationResponseError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_put_integration_response_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_put_integration_response_request(
+        &self,
+        input: PutIntegrationResponseRequest,
+    ) -> SignedRequest {
         let request_uri = format!("/restapis/{restapi_id}/resources/{resource_id}/methods/{http_method}/integration/responses/{status_code}", http_method = input.http_method, resource_id = input.resource_id, restapi_id = input.rest_api_id, status_code = input.status_code);
 
         let mut request = SignedRequest::new("PUT", "apigateway", &self.region, &request_uri);
+        request.set_operation("PutIntegrationResponse");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `put_method`, also returning the metadata of the response.
+    pub async fn put_method_with_metadata(
+        &self,
+        input: PutMethodRequest,
+    ) -> Result<(Method, ResponseMetadata), RusotoError<PutMethodError>> {
+        let request = self.build_put_method_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 201 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<IntegrationResponse, _>()?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response).deserialize::<Method, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(PutIntegrationResponseError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(PutMethodError::from_response(response))
         }
     }
 
-    /// <p>Add a method to an existing <a>Resource</a> resource.</p>
-    async fn put_method(
+    /// Presigns the request `put_method` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_put_method(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: PutMethodRequest,
-    ) -> Result<Method, RusotoError<PutMethodError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_put_method_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_put_method_request(&self, input: PutMethodRequest) -> SignedRequest {
         let request_uri = format!(
             "/restapis/{restapi_id}/resources/{resource_id}/methods/{http_method}",
             http_method = input.http_method,
@@ -13503,65 +16850,115 @@ impl ApiGateway for ApiGatewayClient {
         );
 
         let mut request = SignedRequest::new("PUT", "apigateway", &self.region, &request_uri);
+        request.set_operation("PutMethod");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `put_method_response`, also returning the metadata of the response.
+    pub async fn put_method_response_with_metadata(
+        &self,
+        input: PutMethodResponseRequest,
+    ) -> Result<(MethodResponse, ResponseMetadata), RusotoError<PutMethodResponseError>> {
+        let request = self.build_put_method_response_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 201 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            let result = proto::json::ResponsePayload::new(&response).deserialize::<Method, _>()?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result =
+                proto::json::ResponsePayload::new(&response).deserialize::<MethodResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(PutMethodError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(PutMethodResponseError::from_response(response))
         }
     }
 
-    /// <p>Adds a <a>MethodResponse</a> to an existing <a>Method</a> resource.</p>
-    async fn put_method_response(
+    /// Presigns the request `put_method_response` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_put_method_response(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: PutMethodResponseRequest,
-    ) -> Result<MethodResponse, RusotoError<PutMethodResponseError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_put_method_response_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_put_method_response_request(&self, input: PutMethodResponseRequest) -> SignedRequest {
         let request_uri = format!("/restapis/{restapi_id}/resources/{resource_id}/methods/{http_method}/responses/{status_code}", http_method = input.http_method, resource_id = input.resource_id, restapi_id = input.rest_api_id, status_code = input.status_code);
 
         let mut request = SignedRequest::new("PUT", "apigateway", &self.region, &request_uri);
+        request.set_operation("PutMethodResponse");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `put_rest_api`, also returning the metadata of the response.
+    pub async fn put_rest_api_with_metadata(
+        &self,
+        input: PutRestApiRequest,
+    ) -> Result<(RestApi, ResponseMetadata), RusotoError<PutRestApiError>> {
+        let request = self.build_put_rest_api_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
-        if response.status.as_u16() == 201 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let metadata = ResponseMetadata::from_response(&response);
+        if response.status.is_success() {
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result =
-                proto::json::ResponsePayload::new(&response).deserialize::<MethodResponse, _>()?;
+                proto::json::ResponsePayload::new(&response).deserialize::<RestApi, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(PutMethodResponseError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(PutRestApiError::from_response(response))
         }
     }
 
-    /// <p>A feature of the API Gateway control service for updating an existing API with an input of external API definitions. The update can take the form of merging the supplied definition into the existing API or overwriting the existing API.</p>
-    async fn put_rest_api(
+    /// Presigns the request `put_rest_api` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_put_rest_api(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: PutRestApiRequest,
-    ) -> Result<RestApi, RusotoError<PutRestApiError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_put_rest_api_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_put_rest_api_request(&self, input: PutRestApiRequest) -> SignedRequest {
         let request_uri = format!("/restapis/{restapi_id}", restapi_id = input.rest_api_id);
 
         let mut request = SignedRequest::new("PUT", "apigateway", &self.region, &request_uri);
+        request.set_operation("PutRestApi");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(input.body.to_owned());
@@ -13581,57 +16978,110 @@ impl ApiGateway for ApiGatewayClient {
         }
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `tag_resource`, also returning the metadata of the response.
+    pub async fn tag_resource_with_metadata(
+        &self,
+        input: TagResourceRequest,
+    ) -> Result<((), ResponseMetadata), RusotoError<TagResourceError>> {
+        let request = self.build_tag_resource_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
-        if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            let result =
-                proto::json::ResponsePayload::new(&response).deserialize::<RestApi, _>()?;
+        let metadata = ResponseMetadata::from_response(&response);
+        if response.status.as_u16() == 204 {
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = ::std::mem::drop(response);
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(PutRestApiError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(TagResourceError::from_response(response))
         }
     }
 
-    /// <p>Adds or updates a tag on a given resource.</p>
-    async fn tag_resource(
+    /// Presigns the request `tag_resource` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_tag_resource(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: TagResourceRequest,
-    ) -> Result<(), RusotoError<TagResourceError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_tag_resource_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_tag_resource_request(&self, input: TagResourceRequest) -> SignedRequest {
         let request_uri = format!("/tags/{resource_arn}", resource_arn = input.resource_arn);
 
         let mut request = SignedRequest::new("PUT", "apigateway", &self.region, &request_uri);
+        request.set_operation("TagResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `test_invoke_authorizer`, also returning the metadata of the response.
+    pub async fn test_invoke_authorizer_with_metadata(
+        &self,
+        input: TestInvokeAuthorizerRequest,
+    ) -> Result<
+        (TestInvokeAuthorizerResponse, ResponseMetadata),
+        RusotoError<TestInvokeAuthorizerError>,
+    > {
+        let request = self.build_test_invoke_authorizer_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
-        if response.status.as_u16() == 204 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            let result = ::std::mem::drop(response);
+        let metadata = ResponseMetadata::from_response(&response);
+        if response.status.is_success() {
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<TestInvokeAuthorizerResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(TagResourceError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(TestInvokeAuthorizerError::from_response(response))
         }
     }
 
-    /// <p><p>Simulate the execution of an <a>Authorizer</a> in your <a>RestApi</a> with headers, parameters, and an incoming request body.</p> <div class="seeAlso"> <a href="https://docs.aws.amazon.com/apigateway/latest/developerguide/apigateway-use-lambda-authorizer.html">Use Lambda Function as Authorizer</a> <a href="https://docs.aws.amazon.com/apigateway/latest/developerguide/apigateway-integrate-with-cognito.html">Use Cognito User Pool as Authorizer</a> </div></p>
-    async fn test_invoke_authorizer(
+    /// Presigns the request `test_invoke_authorizer` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_test_invoke_authorizer(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: TestInvokeAuthorizerRequest,
-    ) -> Result<TestInvokeAuthorizerResponse, RusotoError<TestInvokeAuthorizerError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_test_invoke_authorizer_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_test_invoke_authorizer_request(
+        &self,
+        input: TestInvokeAuthorizerRequest,
+    ) -> SignedRequest {
         let request_uri = format!(
             "/restapis/{restapi_id}/authorizers/{authorizer_id}",
             authorizer_id = input.authorizer_id,
@@ -13639,33 +17089,58 @@ impl ApiGateway for ApiGatewayClient {
         );
 
         let mut request = SignedRequest::new("POST", "apigateway", &self.region, &request_uri);
+        request.set_operation("TestInvokeAuthorizer");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `test_invoke_method`, also returning the metadata of the response.
+    pub async fn test_invoke_method_with_metadata(
+        &self,
+        input: TestInvokeMethodRequest,
+    ) -> Result<(TestInvokeMethodResponse, ResponseMetadata), RusotoError<TestInvokeMethodError>>
+    {
+        let request = self.build_test_invoke_method_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<TestInvokeAuthorizerResponse, _>()?;
+                .deserialize::<TestInvokeMethodResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(TestInvokeAuthorizerError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(TestInvokeMethodError::from_response(response))
         }
     }
 
-    /// <p>Simulate the execution of a <a>Method</a> in your <a>RestApi</a> with headers, parameters, and an incoming request body.</p>
-    async fn test_invoke_method(
+    /// Presigns the request `test_invoke_method` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_test_invoke_method(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: TestInvokeMethodRequest,
-    ) -> Result<TestInvokeMethodResponse, RusotoError<TestInvokeMethodError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_test_invoke_method_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_test_invoke_method_request(&self, input: TestInvokeMethodRequest) -> SignedRequest {
         let request_uri = format!(
             "/restapis/{restapi_id}/resources/{resource_id}/methods/{http_method}",
             http_method = input.http_method,
@@ -13674,36 +17149,60 @@ impl ApiGateway for ApiGatewayClient {
         );
 
         let mut request = SignedRequest::new("POST", "apigateway", &self.region, &request_uri);
+        request.set_operation("TestInvokeMethod");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `untag_resource`, also returning the metadata of the response.
+    pub async fn untag_resource_with_metadata(
+        &self,
+        input: UntagResourceRequest,
+    ) -> Result<((), ResponseMetadata), RusotoError<UntagResourceError>> {
+        let request = self.build_untag_resource_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
-        if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<TestInvokeMethodResponse, _>()?;
+        let metadata = ResponseMetadata::from_response(&response);
+        if response.status.as_u16() == 204 {
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = ::std::mem::drop(response);
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(TestInvokeMethodError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(UntagResourceError::from_response(response))
         }
     }
 
-    /// <p>Removes a tag from a given resource.</p>
-    async fn untag_resource(
+    /// Presigns the request `untag_resource` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_untag_resource(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UntagResourceRequest,
-    ) -> Result<(), RusotoError<UntagResourceError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_untag_resource_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_untag_resource_request(&self, input: UntagResourceRequest) -> SignedRequest {
         let request_uri = format!("/tags/{resource_arn}", resource_arn = input.resource_arn);
 
         let mut request = SignedRequest::new("DELETE", "apigateway", &self.region, &request_uri);
+        request.set_operation("UntagResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -13712,86 +17211,158 @@ impl ApiGateway for ApiGatewayClient {
         }
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `update_account`, also returning the metadata of the response.
+    pub async fn update_account_with_metadata(
+        &self,
+        input: UpdateAccountRequest,
+    ) -> Result<(Account, ResponseMetadata), RusotoError<UpdateAccountError>> {
+        let request = self.build_update_account_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
-        if response.status.as_u16() == 204 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            let result = ::std::mem::drop(response);
+        let metadata = ResponseMetadata::from_response(&response);
+        if response.status.is_success() {
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result =
+                proto::json::ResponsePayload::new(&response).deserialize::<Account, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(UntagResourceError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(UpdateAccountError::from_response(response))
         }
     }
 
-    /// <p>Changes information about the current <a>Account</a> resource.</p>
-    async fn update_account(
+    /// Presigns the request `update_account` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_account(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UpdateAccountRequest,
-    ) -> Result<Account, RusotoError<UpdateAccountError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_account_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_account_request(&self, input: UpdateAccountRequest) -> SignedRequest {
         let request_uri = "/account";
 
         let mut request = SignedRequest::new("PATCH", "apigateway", &self.region, &request_uri);
+        request.set_operation("UpdateAccount");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `update_api_key`, also returning the metadata of the response.
+    pub async fn update_api_key_with_metadata(
+        &self,
+        input: UpdateApiKeyRequest,
+    ) -> Result<(ApiKey, ResponseMetadata), RusotoError<UpdateApiKeyError>> {
+        let request = self.build_update_api_key_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            let result =
-                proto::json::ResponsePayload::new(&response).deserialize::<Account, _>()?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response).deserialize::<ApiKey, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(UpdateAccountError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(UpdateApiKeyError::from_response(response))
         }
     }
 
-    /// <p>Changes information about an <a>ApiKey</a> resource.</p>
-    async fn update_api_key(
+    /// Presigns the request `update_api_key` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_api_key(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UpdateApiKeyRequest,
-    ) -> Result<ApiKey, RusotoError<UpdateApiKeyError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_api_key_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_api_key_request(&self, input: UpdateApiKeyRequest) -> SignedRequest {
         let request_uri = format!("/apikeys/{api_key}", api_key = input.api_key);
 
         let mut request = SignedRequest::new("PATCH", "apigateway", &self.region, &request_uri);
+        request.set_operation("UpdateApiKey");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `update_authorizer`, also returning the metadata of the response.
+    pub async fn update_authorizer_with_metadata(
+        &self,
+        input: UpdateAuthorizerRequest,
+    ) -> Result<(Authorizer, ResponseMetadata), RusotoError<UpdateAuthorizerError>> {
+        let request = self.build_update_authorizer_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            let result = proto::json::ResponsePayload::new(&response).deserialize::<ApiKey, _>()?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result =
+                proto::json::ResponsePayload::new(&response).deserialize::<Authorizer, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(UpdateApiKeyError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(UpdateAuthorizerError::from_response(response))
         }
     }
 
-    /// <p><p>Updates an existing <a>Authorizer</a> resource.</p> <div class="seeAlso"><a href="https://docs.aws.amazon.com/cli/latest/reference/apigateway/update-authorizer.html">AWS CLI</a></div></p>
-    async fn update_authorizer(
+    /// Presigns the request `update_authorizer` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_authorizer(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UpdateAuthorizerRequest,
-    ) -> Result<Authorizer, RusotoError<UpdateAuthorizerError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_authorizer_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_authorizer_request(&self, input: UpdateAuthorizerRequest) -> SignedRequest {
         let request_uri = format!(
             "/restapis/{restapi_id}/authorizers/{authorizer_id}",
             authorizer_id = input.authorizer_id,
@@ -13799,33 +17370,60 @@ impl ApiGateway for ApiGatewayClient {
         );
 
         let mut request = SignedRequest::new("PATCH", "apigateway", &self.region, &request_uri);
+        request.set_operation("UpdateAuthorizer");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `update_base_path_mapping`, also returning the metadata of the response.
+    pub async fn update_base_path_mapping_with_metadata(
+        &self,
+        input: UpdateBasePathMappingRequest,
+    ) -> Result<(BasePathMapping, ResponseMetadata), RusotoError<UpdateBasePathMappingError>> {
+        let request = self.build_update_base_path_mapping_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result =
-                proto::json::ResponsePayload::new(&response).deserialize::<Authorizer, _>()?;
+                proto::json::ResponsePayload::new(&response).deserialize::<BasePathMapping, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(UpdateAuthorizerError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(UpdateBasePathMappingError::from_response(response))
         }
     }
 
-    /// <p>Changes information about the <a>BasePathMapping</a> resource.</p>
-    async fn update_base_path_mapping(
+    /// Presigns the request `update_base_path_mapping` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_base_path_mapping(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UpdateBasePathMappingRequest,
-    ) -> Result<BasePathMapping, RusotoError<UpdateBasePathMappingError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_base_path_mapping_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_base_path_mapping_request(
+        &self,
+        input: UpdateBasePathMappingRequest,
+    ) -> SignedRequest {
         let request_uri = format!(
             "/domainnames/{domain_name}/basepathmappings/{base_path}",
             base_path = input.base_path,
@@ -13833,66 +17431,118 @@ impl ApiGateway for ApiGatewayClient {
         );
 
         let mut request = SignedRequest::new("PATCH", "apigateway", &self.region, &request_uri);
+        request.set_operation("UpdateBasePathMapping");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `update_client_certificate`, also returning the metadata of the response.
+    pub async fn update_client_certificate_with_metadata(
+        &self,
+        input: UpdateClientCertificateRequest,
+    ) -> Result<(ClientCertificate, ResponseMetadata), RusotoError<UpdateClientCertificateError>>
+    {
+        let request = self.build_update_client_certificate_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            let result =
-                proto::json::ResponsePayload::new(&response).deserialize::<BasePathMapping, _>()?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ClientCertificate, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(UpdateBasePathMappingError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(UpdateClientCertificateError::from_response(response))
         }
     }
 
-    /// <p>Changes information about an <a>ClientCertificate</a> resource.</p>
-    async fn update_client_certificate(
+    /// Presigns the request `update_client_certificate` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_client_certificate(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UpdateClientCertificateRequest,
-    ) -> Result<ClientCertificate, RusotoError<UpdateClientCertificateError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_client_certificate_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_client_certificate_request(
+        &self,
+        input: UpdateClientCertificateRequest,
+    ) -> SignedRequest {
         let request_uri = format!(
             "/clientcertificates/{clientcertificate_id}",
             clientcertificate_id = input.client_certificate_id
         );
 
         let mut request = SignedRequest::new("PATCH", "apigateway", &self.region, &request_uri);
+        request.set_operation("UpdateClientCertificate");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `update_deployment`, also returning the metadata of the response.
+    pub async fn update_deployment_with_metadata(
+        &self,
+        input: UpdateDeploymentRequest,
+    ) -> Result<(Deployment, ResponseMetadata), RusotoError<UpdateDeploymentError>> {
+        let request = self.build_update_deployment_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<ClientCertificate, _>()?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result =
+                proto::json::ResponsePayload::new(&response).deserialize::<Deployment, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(UpdateClientCertificateError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(UpdateDeploymentError::from_response(response))
         }
     }
 
-    /// <p>Changes information about a <a>Deployment</a> resource.</p>
-    async fn update_deployment(
+    /// Presigns the request `update_deployment` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_deployment(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UpdateDeploymentRequest,
-    ) -> Result<Deployment, RusotoError<UpdateDeploymentError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_deployment_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_deployment_request(&self, input: UpdateDeploymentRequest) -> SignedRequest {
         let request_uri = format!(
             "/restapis/{restapi_id}/deployments/{deployment_id}",
             deployment_id = input.deployment_id,
@@ -13900,32 +17550,61 @@ impl ApiGateway for ApiGatewayClient {
         );
 
         let mut request = SignedRequest::new("PATCH", "apigateway", &self.region, &request_uri);
+        request.set_operation("UpdateDeployment");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `update_documentation_part`, also returning the metadata of the response.
+    pub async fn update_documentation_part_with_metadata(
+        &self,
+        input: UpdateDocumentationPartRequest,
+    ) -> Result<(DocumentationPart, ResponseMetadata), RusotoError<UpdateDocumentationPartError>>
+    {
+        let request = self.build_update_documentation_part_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            let result =
-                proto::json::ResponsePayload::new(&response).deserialize::<Deployment, _>()?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DocumentationPart, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(UpdateDeploymentError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(UpdateDocumentationPartError::from_response(response))
         }
     }
 
-    async fn update_documentation_part(
+    /// Presigns the request `update_documentation_part` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_documentation_part(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UpdateDocumentationPartRequest,
-    ) -> Result<DocumentationPart, RusotoError<UpdateDocumentationPartError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_documentation_part_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_documentation_part_request(
+        &self,
+        input: UpdateDocumentationPartRequest,
+    ) -> SignedRequest {
         let request_uri = format!(
             "/restapis/{restapi_id}/documentation/parts/{part_id}",
             part_id = input.documentation_part_id,
@@ -13933,32 +17612,63 @@ impl ApiGateway for ApiGatewayClient {
         );
 
         let mut request = SignedRequest::new("PATCH", "apigateway", &self.region, &request_uri);
+        request.set_operation("UpdateDocumentationPart");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `update_documentation_version`, also returning the metadata of the response.
+    pub async fn update_documentation_version_with_metadata(
+        &self,
+        input: UpdateDocumentationVersionRequest,
+    ) -> Result<
+        (DocumentationVersion, ResponseMetadata),
+        RusotoError<UpdateDocumentationVersionError>,
+    > {
+        let request = self.build_update_documentation_version_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<DocumentationPart, _>()?;
+                .deserialize::<DocumentationVersion, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(UpdateDocumentationPartError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(UpdateDocumentationVersionError::from_response(response))
         }
     }
 
-    async fn update_documentation_version(
+    /// Presigns the request `update_documentation_version` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_documentation_version(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UpdateDocumentationVersionRequest,
-    ) -> Result<DocumentationVersion, RusotoError<UpdateDocumentationVersionError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_documentation_version_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_documentation_version_request(
+        &self,
+        input: UpdateDocumentationVersionRequest,
+    ) -> SignedRequest {
         let request_uri = format!(
             "/restapis/{restapi_id}/documentation/versions/{doc_version}",
             doc_version = input.documentation_version,
@@ -13966,66 +17676,117 @@ impl ApiGateway for ApiGatewayClient {
         );
 
         let mut request = SignedRequest::new("PATCH", "apigateway", &self.region, &request_uri);
+        request.set_operation("UpdateDocumentationVersion");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `update_domain_name`, also returning the metadata of the response.
+    pub async fn update_domain_name_with_metadata(
+        &self,
+        input: UpdateDomainNameRequest,
+    ) -> Result<(DomainName, ResponseMetadata), RusotoError<UpdateDomainNameError>> {
+        let request = self.build_update_domain_name_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<DocumentationVersion, _>()?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result =
+                proto::json::ResponsePayload::new(&response).deserialize::<DomainName, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(UpdateDocumentationVersionError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(UpdateDomainNameError::from_response(response))
         }
     }
 
-    /// <p>Changes information about the <a>DomainName</a> resource.</p>
-    async fn update_domain_name(
+    /// Presigns the request `update_domain_name` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_domain_name(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UpdateDomainNameRequest,
-    ) -> Result<DomainName, RusotoError<UpdateDomainNameError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_domain_name_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_domain_name_request(&self, input: UpdateDomainNameRequest) -> SignedRequest {
         let request_uri = format!(
             "/domainnames/{domain_name}",
             domain_name = input.domain_name
         );
 
         let mut request = SignedRequest::new("PATCH", "apigateway", &self.region, &request_uri);
+        request.set_operation("UpdateDomainName");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `update_gateway_response`, also returning the metadata of the response.
+    pub async fn update_gateway_response_with_metadata(
+        &self,
+        input: UpdateGatewayResponseRequest,
+    ) -> Result<(GatewayResponse, ResponseMetadata), RusotoError<UpdateGatewayResponseError>> {
+        let request = self.build_update_gateway_response_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result =
-                proto::json::ResponsePayload::new(&response).deserialize::<DomainName, _>()?;
+                proto::json::ResponsePayload::new(&response).deserialize::<GatewayResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(UpdateDomainNameError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(UpdateGatewayResponseError::from_response(response))
         }
     }
 
-    /// <p>Updates a <a>GatewayResponse</a> of a specified response type on the given <a>RestApi</a>.</p>
-    async fn update_gateway_response(
+    /// Presigns the request `update_gateway_response` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_gateway_response(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UpdateGatewayResponseRequest,
-    ) -> Result<GatewayResponse, RusotoError<UpdateGatewayResponseError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_gateway_response_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_gateway_response_request(
+        &self,
+        input: UpdateGatewayResponseRequest,
+    ) -> SignedRequest {
         let request_uri = format!(
             "/restapis/{restapi_id}/gatewayresponses/{response_type}",
             response_type = input.response_type,
@@ -14033,33 +17794,57 @@ impl ApiGateway for ApiGatewayClient {
         );
 
         let mut request = SignedRequest::new("PATCH", "apigateway", &self.region, &request_uri);
+        request.set_operation("UpdateGatewayResponse");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `update_integration`, also returning the metadata of the response.
+    pub async fn update_integration_with_metadata(
+        &self,
+        input: UpdateIntegrationRequest,
+    ) -> Result<(Integration, ResponseMetadata), RusotoError<UpdateIntegrationError>> {
+        let request = self.build_update_integration_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result =
-                proto::json::ResponsePayload::new(&response).deserialize::<GatewayResponse, _>()?;
+                proto::json::ResponsePayload::new(&response).deserialize::<Integration, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(UpdateGatewayResponseError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(UpdateIntegrationError::from_response(response))
         }
     }
 
-    /// <p>Represents an update integration.</p>
-    async fn update_integration(
+    /// Presigns the request `update_integration` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_integration(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UpdateIntegrationRequest,
-    ) -> Result<Integration, RusotoError<UpdateIntegrationError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_integration_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_integration_request(&self, input: UpdateIntegrationRequest) -> SignedRequest {
         let request_uri = format!(
             "/restapis/{restapi_id}/resources/{resource_id}/methods/{http_method}/integration",
             http_method = input.http_method,
@@ -14068,63 +17853,114 @@ impl ApiGateway for ApiGatewayClient {
         );
 
         let mut request = SignedRequest::new("PATCH", "apigateway", &self.region, &request_uri);
+        request.set_operation("UpdateIntegration");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `update_integration_response`, also returning the metadata of the response.
+    pub async fn update_integration_response_with_metadata(
+        &self,
+        input: UpdateIntegrationResponseRequest,
+    ) -> Result<(IntegrationResponse, ResponseMetadata), RusotoError<UpdateIntegrationResponseError>>
+    {
+        let request = self.build_update_integration_response_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            let result =
-                proto::json::ResponsePayload::new(&response).deserialize::<Integration, _>()?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<IntegrationResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(UpdateIntegrationError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(UpdateIntegrationResponseError::from_response(response))
         }
     }
 
-    /// <p>Represents an update integration response.</p>
-    async fn update_integration_response(
+    /// Presigns the request `update_integration_response` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_integration_response(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UpdateIntegrationResponseRequest,
-    ) -> Result<IntegrationResponse, RusotoError<UpdateIntegrationResponseError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_integration_response_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_integration_response_request(
+        &self,
+        input: UpdateIntegrationResponseRequest,
+    ) -> SignedRequest {
         let request_uri = format!("/restapis/{restapi_id}/resources/{resource_id}/methods/{http_method}/integration/responses/{status_code}", http_method = input.http_method, resource_id = input.resource_id, restapi_id = input.rest_api_id, status_code = input.status_code);
 
         let mut request = SignedRequest::new("PATCH", "apigateway", &self.region, &request_uri);
+        request.set_operation("UpdateIntegrationResponse");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `update_method`, also returning the metadata of the response.
+    pub async fn update_method_with_metadata(
+        &self,
+        input: UpdateMethodRequest,
+    ) -> Result<(Method, ResponseMetadata), RusotoError<UpdateMethodError>> {
+        let request = self.build_update_method_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<IntegrationResponse, _>()?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response).deserialize::<Method, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(UpdateIntegrationResponseError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(UpdateMethodError::from_response(response))
         }
     }
 
-    /// <p>Updates an existing <a>Method</a> resource.</p>
-    async fn update_method(
+    /// Presigns the request `update_method` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_method(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UpdateMethodRequest,
-    ) -> Result<Method, RusotoError<UpdateMethodError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_method_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_method_request(&self, input: UpdateMethodRequest) -> SignedRequest {
         let request_uri = format!(
             "/restapis/{restapi_id}/resources/{resource_id}/methods/{http_method}",
             http_method = input.http_method,
@@ -14133,62 +17969,113 @@ impl ApiGateway for ApiGatewayClient {
         );
 
         let mut request = SignedRequest::new("PATCH", "apigateway", &self.region, &request_uri);
+        request.set_operation("UpdateMethod");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `update_method_response`, also returning the metadata of the response.
+    pub async fn update_method_response_with_metadata(
+        &self,
+        input: UpdateMethodResponseRequest,
+    ) -> Result<(MethodResponse, ResponseMetadata), RusotoError<UpdateMethodResponseError>> {
+        let request = self.build_update_method_response_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
-        if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            let result = proto::json::ResponsePayload::new(&response).deserialize::<Method, _>()?;
+        let metadata = ResponseMetadata::from_response(&response);
+        if response.status.as_u16() == 201 {
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result =
+                proto::json::ResponsePayload::new(&response).deserialize::<MethodResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(UpdateMethodError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(UpdateMethodResponseError::from_response(response))
         }
     }
 
-    /// <p>Updates an existing <a>MethodResponse</a> resource.</p>
-    async fn update_method_response(
+    /// Presigns the request `update_method_response` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_method_response(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UpdateMethodResponseRequest,
-    ) -> Result<MethodResponse, RusotoError<UpdateMethodResponseError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_method_response_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_method_response_request(
+        &self,
+        input: UpdateMethodResponseRequest,
+    ) -> SignedRequest {
         let request_uri = format!("/restapis/{restapi_id}/resources/{resource_id}/methods/{http_method}/responses/{status_code}", http_method = input.http_method, resource_id = input.resource_id, restapi_id = input.rest_api_id, status_code = input.status_code);
 
         let mut request = SignedRequest::new("PATCH", "apigateway", &self.region, &request_uri);
+        request.set_operation("UpdateMethodResponse");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `update_model`, also returning the metadata of the response.
+    pub async fn update_model_with_metadata(
+        &self,
+        input: UpdateModelRequest,
+    ) -> Result<(Model, ResponseMetadata), RusotoError<UpdateModelError>> {
+        let request = self.build_update_model_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
-        if response.status.as_u16() == 201 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            let result =
-                proto::json::ResponsePayload::new(&response).deserialize::<MethodResponse, _>()?;
+        let metadata = ResponseMetadata::from_response(&response);
+        if response.status.is_success() {
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response).deserialize::<Model, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(UpdateMethodResponseError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(UpdateModelError::from_response(response))
         }
     }
 
-    /// <p>Changes information about a model.</p>
-    async fn update_model(
+    /// Presigns the request `update_model` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_model(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UpdateModelRequest,
-    ) -> Result<Model, RusotoError<UpdateModelError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_model_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_model_request(&self, input: UpdateModelRequest) -> SignedRequest {
         let request_uri = format!(
             "/restapis/{restapi_id}/models/{model_name}",
             model_name = input.model_name,
@@ -14196,32 +18083,61 @@ impl ApiGateway for ApiGatewayClient {
         );
 
         let mut request = SignedRequest::new("PATCH", "apigateway", &self.region, &request_uri);
+        request.set_operation("UpdateModel");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `update_request_validator`, also returning the metadata of the response.
+    pub async fn update_request_validator_with_metadata(
+        &self,
+        input: UpdateRequestValidatorRequest,
+    ) -> Result<(RequestValidator, ResponseMetadata), RusotoError<UpdateRequestValidatorError>>
+    {
+        let request = self.build_update_request_validator_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            let result = proto::json::ResponsePayload::new(&response).deserialize::<Model, _>()?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<RequestValidator, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(UpdateModelError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(UpdateRequestValidatorError::from_response(response))
         }
     }
 
-    /// <p>Updates a <a>RequestValidator</a> of a given <a>RestApi</a>.</p>
-    async fn update_request_validator(
+    /// Presigns the request `update_request_validator` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_request_validator(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UpdateRequestValidatorRequest,
-    ) -> Result<RequestValidator, RusotoError<UpdateRequestValidatorError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_request_validator_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_request_validator_request(
+        &self,
+        input: UpdateRequestValidatorRequest,
+    ) -> SignedRequest {
         let request_uri = format!(
             "/restapis/{restapi_id}/requestvalidators/{requestvalidator_id}",
             requestvalidator_id = input.request_validator_id,
@@ -14229,33 +18145,57 @@ impl ApiGateway for ApiGatewayClient {
         );
 
         let mut request = SignedRequest::new("PATCH", "apigateway", &self.region, &request_uri);
+        request.set_operation("UpdateRequestValidator");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `update_resource`, also returning the metadata of the response.
+    pub async fn update_resource_with_metadata(
+        &self,
+        input: UpdateResourceRequest,
+    ) -> Result<(Resource, ResponseMetadata), RusotoError<UpdateResourceError>> {
+        let request = self.build_update_resource_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<RequestValidator, _>()?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result =
+                proto::json::ResponsePayload::new(&response).deserialize::<Resource, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(UpdateRequestValidatorError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(UpdateResourceError::from_response(response))
         }
     }
 
-    /// <p>Changes information about a <a>Resource</a> resource.</p>
-    async fn update_resource(
+    /// Presigns the request `update_resource` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_resource(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UpdateResourceRequest,
-    ) -> Result<Resource, RusotoError<UpdateResourceError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_resource_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_resource_request(&self, input: UpdateResourceRequest) -> SignedRequest {
         let request_uri = format!(
             "/restapis/{restapi_id}/resources/{resource_id}",
             resource_id = input.resource_id,
@@ -14263,63 +18203,110 @@ impl ApiGateway for ApiGatewayClient {
         );
 
         let mut request = SignedRequest::new("PATCH", "apigateway", &self.region, &request_uri);
+        request.set_operation("UpdateResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `update_rest_api`, also returning the metadata of the response.
+    pub async fn update_rest_api_with_metadata(
+        &self,
+        input: UpdateRestApiRequest,
+    ) -> Result<(RestApi, ResponseMetadata), RusotoError<UpdateRestApiError>> {
+        let request = self.build_update_rest_api_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result =
-                proto::json::ResponsePayload::new(&response).deserialize::<Resource, _>()?;
+                proto::json::ResponsePayload::new(&response).deserialize::<RestApi, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(UpdateResourceError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(UpdateRestApiError::from_response(response))
         }
     }
 
-    /// <p>Changes information about the specified API.</p>
-    async fn update_rest_api(
+    /// Presigns the request `update_rest_api` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_rest_api(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UpdateRestApiRequest,
-    ) -> Result<RestApi, RusotoError<UpdateRestApiError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_rest_api_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_rest_api_request(&self, input: UpdateRestApiRequest) -> SignedRequest {
         let request_uri = format!("/restapis/{restapi_id}", restapi_id = input.rest_api_id);
 
         let mut request = SignedRequest::new("PATCH", "apigateway", &self.region, &request_uri);
+        request.set_operation("UpdateRestApi");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `update_stage`, also returning the metadata of the response.
+    pub async fn update_stage_with_metadata(
+        &self,
+        input: UpdateStageRequest,
+    ) -> Result<(Stage, ResponseMetadata), RusotoError<UpdateStageError>> {
+        let request = self.build_update_stage_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            let result =
-                proto::json::ResponsePayload::new(&response).deserialize::<RestApi, _>()?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response).deserialize::<Stage, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(UpdateRestApiError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(UpdateStageError::from_response(response))
         }
     }
 
-    /// <p>Changes information about a <a>Stage</a> resource.</p>
-    async fn update_stage(
+    /// Presigns the request `update_stage` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_stage(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UpdateStageRequest,
-    ) -> Result<Stage, RusotoError<UpdateStageError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_stage_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_stage_request(&self, input: UpdateStageRequest) -> SignedRequest {
         let request_uri = format!(
             "/restapis/{restapi_id}/stages/{stage_name}",
             restapi_id = input.rest_api_id,
@@ -14327,32 +18314,56 @@ impl ApiGateway for ApiGatewayClient {
         );
 
         let mut request = SignedRequest::new("PATCH", "apigateway", &self.region, &request_uri);
+        request.set_operation("UpdateStage");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `update_usage`, also returning the metadata of the response.
+    pub async fn update_usage_with_metadata(
+        &self,
+        input: UpdateUsageRequest,
+    ) -> Result<(Usage, ResponseMetadata), RusotoError<UpdateUsageError>> {
+        let request = self.build_update_usage_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            let result = proto::json::ResponsePayload::new(&response).deserialize::<Stage, _>()?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response).deserialize::<Usage, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(UpdateStageError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(UpdateUsageError::from_response(response))
         }
     }
 
-    /// <p>Grants a temporary extension to the remaining quota of a usage plan associated with a specified API key.</p>
-    async fn update_usage(
+    /// Presigns the request `update_usage` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_usage(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UpdateUsageRequest,
-    ) -> Result<Usage, RusotoError<UpdateUsageError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_usage_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_usage_request(&self, input: UpdateUsageRequest) -> SignedRequest {
         let request_uri = format!(
             "/usageplans/{usageplan_id}/keys/{key_id}/usage",
             key_id = input.key_id,
@@ -14360,87 +18371,1927 @@ impl ApiGateway for ApiGatewayClient {
         );
 
         let mut request = SignedRequest::new("PATCH", "apigateway", &self.region, &request_uri);
+        request.set_operation("UpdateUsage");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `update_usage_plan`, also returning the metadata of the response.
+    pub async fn update_usage_plan_with_metadata(
+        &self,
+        input: UpdateUsagePlanRequest,
+    ) -> Result<(UsagePlan, ResponseMetadata), RusotoError<UpdateUsagePlanError>> {
+        let request = self.build_update_usage_plan_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            let result = proto::json::ResponsePayload::new(&response).deserialize::<Usage, _>()?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result =
+                proto::json::ResponsePayload::new(&response).deserialize::<UsagePlan, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(UpdateUsageError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(UpdateUsagePlanError::from_response(response))
         }
     }
 
-    /// <p>Updates a usage plan of a given plan Id.</p>
-    async fn update_usage_plan(
+    /// Presigns the request `update_usage_plan` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_usage_plan(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UpdateUsagePlanRequest,
-    ) -> Result<UsagePlan, RusotoError<UpdateUsagePlanError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_usage_plan_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_usage_plan_request(&self, input: UpdateUsagePlanRequest) -> SignedRequest {
         let request_uri = format!(
             "/usageplans/{usageplan_id}",
             usageplan_id = input.usage_plan_id
         );
 
         let mut request = SignedRequest::new("PATCH", "apigateway", &self.region, &request_uri);
+        request.set_operation("UpdateUsagePlan");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `update_vpc_link`, also returning the metadata of the response.
+    pub async fn update_vpc_link_with_metadata(
+        &self,
+        input: UpdateVpcLinkRequest,
+    ) -> Result<(VpcLink, ResponseMetadata), RusotoError<UpdateVpcLinkError>> {
+        let request = self.build_update_vpc_link_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result =
-                proto::json::ResponsePayload::new(&response).deserialize::<UsagePlan, _>()?;
+                proto::json::ResponsePayload::new(&response).deserialize::<VpcLink, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(UpdateUsagePlanError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(UpdateVpcLinkError::from_response(response))
         }
     }
 
-    /// <p>Updates an existing <a>VpcLink</a> of a specified identifier.</p>
-    async fn update_vpc_link(
+    /// Presigns the request `update_vpc_link` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_vpc_link(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UpdateVpcLinkRequest,
-    ) -> Result<VpcLink, RusotoError<UpdateVpcLinkError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_vpc_link_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_vpc_link_request(&self, input: UpdateVpcLinkRequest) -> SignedRequest {
         let request_uri = format!("/vpclinks/{vpclink_id}", vpclink_id = input.vpc_link_id);
 
         let mut request = SignedRequest::new("PATCH", "apigateway", &self.region, &request_uri);
+        request.set_operation("UpdateVpcLink");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
-        let mut response = self
-            .client
-            .sign_and_dispatch(request)
+        request
+    }
+
+    /// The pages of `GetApiKeys` results for `input`, requesting each next page with the `position` of the last one as its `position`.
+    /// The page size is the `limit` of `input`.
+    pub fn get_api_keys_pages(
+        &self,
+        input: GetApiKeysRequest,
+    ) -> impl futures::Stream<Item = Result<ApiKeys, RusotoError<GetApiKeysError>>> + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: ApiGatewayClient, input| async move { client.get_api_keys(input).await },
+            |input: &GetApiKeysRequest, output: &ApiKeys| {
+                let position = output.position.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if position.is_none() || position == input.position {
+                    return None;
+                }
+                Some(GetApiKeysRequest {
+                    position,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `items` of every page of `GetApiKeys` results for `input`, as `get_api_keys_pages` requests them.
+    pub fn get_api_keys_stream(
+        &self,
+        input: GetApiKeysRequest,
+    ) -> impl futures::Stream<Item = Result<ApiKey, RusotoError<GetApiKeysError>>> + Send {
+        rusoto_core::pagination::items(self.get_api_keys_pages(input), |page| {
+            page.items.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `GetAuthorizers` results for `input`, requesting each next page with the `position` of the last one as its `position`.
+    /// The page size is the `limit` of `input`.
+    pub fn get_authorizers_pages(
+        &self,
+        input: GetAuthorizersRequest,
+    ) -> impl futures::Stream<Item = Result<Authorizers, RusotoError<GetAuthorizersError>>> + Send
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: ApiGatewayClient, input| async move { client.get_authorizers(input).await },
+            |input: &GetAuthorizersRequest, output: &Authorizers| {
+                let position = output.position.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if position.is_none() || position == input.position {
+                    return None;
+                }
+                Some(GetAuthorizersRequest {
+                    position,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `items` of every page of `GetAuthorizers` results for `input`, as `get_authorizers_pages` requests them.
+    pub fn get_authorizers_stream(
+        &self,
+        input: GetAuthorizersRequest,
+    ) -> impl futures::Stream<Item = Result<Authorizer, RusotoError<GetAuthorizersError>>> + Send
+    {
+        rusoto_core::pagination::items(self.get_authorizers_pages(input), |page| {
+            page.items.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `GetBasePathMappings` results for `input`, requesting each next page with the `position` of the last one as its `position`.
+    /// The page size is the `limit` of `input`.
+    pub fn get_base_path_mappings_pages(
+        &self,
+        input: GetBasePathMappingsRequest,
+    ) -> impl futures::Stream<Item = Result<BasePathMappings, RusotoError<GetBasePathMappingsError>>>
+           + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: ApiGatewayClient, input| async move { client.get_base_path_mappings(input).await },
+            |input: &GetBasePathMappingsRequest, output: &BasePathMappings| {
+                let position = output.position.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if position.is_none() || position == input.position {
+                    return None;
+                }
+                Some(GetBasePathMappingsRequest {
+                    position,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `items` of every page of `GetBasePathMappings` results for `input`, as `get_base_path_mappings_pages` requests them.
+    pub fn get_base_path_mappings_stream(
+        &self,
+        input: GetBasePathMappingsRequest,
+    ) -> impl futures::Stream<Item = Result<BasePathMapping, RusotoError<GetBasePathMappingsError>>> + Send
+    {
+        rusoto_core::pagination::items(self.get_base_path_mappings_pages(input), |page| {
+            page.items.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `GetClientCertificates` results for `input`, requesting each next page with the `position` of the last one as its `position`.
+    /// The page size is the `limit` of `input`.
+    pub fn get_client_certificates_pages(
+        &self,
+        input: GetClientCertificatesRequest,
+    ) -> impl futures::Stream<
+        Item = Result<ClientCertificates, RusotoError<GetClientCertificatesError>>,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: ApiGatewayClient, input| async move { client.get_client_certificates(input).await },
+            |input: &GetClientCertificatesRequest, output: &ClientCertificates| {
+                let position = output.position.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if position.is_none() || position == input.position {
+                    return None;
+                }
+                Some(GetClientCertificatesRequest {
+                    position,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `items` of every page of `GetClientCertificates` results for `input`, as `get_client_certificates_pages` requests them.
+    pub fn get_client_certificates_stream(
+        &self,
+        input: GetClientCertificatesRequest,
+    ) -> impl futures::Stream<
+        Item = Result<ClientCertificate, RusotoError<GetClientCertificatesError>>,
+    > + Send {
+        rusoto_core::pagination::items(self.get_client_certificates_pages(input), |page| {
+            page.items.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `GetDeployments` results for `input`, requesting each next page with the `position` of the last one as its `position`.
+    /// The page size is the `limit` of `input`.
+    pub fn get_deployments_pages(
+        &self,
+        input: GetDeploymentsRequest,
+    ) -> impl futures::Stream<Item = Result<Deployments, RusotoError<GetDeploymentsError>>> + Send
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: ApiGatewayClient, input| async move { client.get_deployments(input).await },
+            |input: &GetDeploymentsRequest, output: &Deployments| {
+                let position = output.position.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if position.is_none() || position == input.position {
+                    return None;
+                }
+                Some(GetDeploymentsRequest {
+                    position,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `items` of every page of `GetDeployments` results for `input`, as `get_deployments_pages` requests them.
+    pub fn get_deployments_stream(
+        &self,
+        input: GetDeploymentsRequest,
+    ) -> impl futures::Stream<Item = Result<Deployment, RusotoError<GetDeploymentsError>>> + Send
+    {
+        rusoto_core::pagination::items(self.get_deployments_pages(input), |page| {
+            page.items.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `GetDocumentationParts` results for `input`, requesting each next page with the `position` of the last one as its `position`.
+    /// The page size is the `limit` of `input`.
+    pub fn get_documentation_parts_pages(
+        &self,
+        input: GetDocumentationPartsRequest,
+    ) -> impl futures::Stream<
+        Item = Result<DocumentationParts, RusotoError<GetDocumentationPartsError>>,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: ApiGatewayClient, input| async move { client.get_documentation_parts(input).await },
+            |input: &GetDocumentationPartsRequest, output: &DocumentationParts| {
+                let position = output.position.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if position.is_none() || position == input.position {
+                    return None;
+                }
+                Some(GetDocumentationPartsRequest {
+                    position,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `items` of every page of `GetDocumentationParts` results for `input`, as `get_documentation_parts_pages` requests them.
+    pub fn get_documentation_parts_stream(
+        &self,
+        input: GetDocumentationPartsRequest,
+    ) -> impl futures::Stream<
+        Item = Result<DocumentationPart, RusotoError<GetDocumentationPartsError>>,
+    > + Send {
+        rusoto_core::pagination::items(self.get_documentation_parts_pages(input), |page| {
+            page.items.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `GetDocumentationVersions` results for `input`, requesting each next page with the `position` of the last one as its `position`.
+    /// The page size is the `limit` of `input`.
+    pub fn get_documentation_versions_pages(
+        &self,
+        input: GetDocumentationVersionsRequest,
+    ) -> impl futures::Stream<
+        Item = Result<DocumentationVersions, RusotoError<GetDocumentationVersionsError>>,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: ApiGatewayClient, input| async move {
+                client.get_documentation_versions(input).await
+            },
+            |input: &GetDocumentationVersionsRequest, output: &DocumentationVersions| {
+                let position = output.position.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if position.is_none() || position == input.position {
+                    return None;
+                }
+                Some(GetDocumentationVersionsRequest {
+                    position,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `items` of every page of `GetDocumentationVersions` results for `input`, as `get_documentation_versions_pages` requests them.
+    pub fn get_documentation_versions_stream(
+        &self,
+        input: GetDocumentationVersionsRequest,
+    ) -> impl futures::Stream<
+        Item = Result<DocumentationVersion, RusotoError<GetDocumentationVersionsError>>,
+    > + Send {
+        rusoto_core::pagination::items(self.get_documentation_versions_pages(input), |page| {
+            page.items.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `GetDomainNames` results for `input`, requesting each next page with the `position` of the last one as its `position`.
+    /// The page size is the `limit` of `input`.
+    pub fn get_domain_names_pages(
+        &self,
+        input: GetDomainNamesRequest,
+    ) -> impl futures::Stream<Item = Result<DomainNames, RusotoError<GetDomainNamesError>>> + Send
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: ApiGatewayClient, input| async move { client.get_domain_names(input).await },
+            |input: &GetDomainNamesRequest, output: &DomainNames| {
+                let position = output.position.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if position.is_none() || position == input.position {
+                    return None;
+                }
+                Some(GetDomainNamesRequest {
+                    position,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `items` of every page of `GetDomainNames` results for `input`, as `get_domain_names_pages` requests them.
+    pub fn get_domain_names_stream(
+        &self,
+        input: GetDomainNamesRequest,
+    ) -> impl futures::Stream<Item = Result<DomainName, RusotoError<GetDomainNamesError>>> + Send
+    {
+        rusoto_core::pagination::items(self.get_domain_names_pages(input), |page| {
+            page.items.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `GetGatewayResponses` results for `input`, requesting each next page with the `position` of the last one as its `position`.
+    /// The page size is the `limit` of `input`.
+    pub fn get_gateway_responses_pages(
+        &self,
+        input: GetGatewayResponsesRequest,
+    ) -> impl futures::Stream<Item = Result<GatewayResponses, RusotoError<GetGatewayResponsesError>>>
+           + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: ApiGatewayClient, input| async move { client.get_gateway_responses(input).await },
+            |input: &GetGatewayResponsesRequest, output: &GatewayResponses| {
+                let position = output.position.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if position.is_none() || position == input.position {
+                    return None;
+                }
+                Some(GetGatewayResponsesRequest {
+                    position,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `items` of every page of `GetGatewayResponses` results for `input`, as `get_gateway_responses_pages` requests them.
+    pub fn get_gateway_responses_stream(
+        &self,
+        input: GetGatewayResponsesRequest,
+    ) -> impl futures::Stream<Item = Result<GatewayResponse, RusotoError<GetGatewayResponsesError>>> + Send
+    {
+        rusoto_core::pagination::items(self.get_gateway_responses_pages(input), |page| {
+            page.items.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `GetModels` results for `input`, requesting each next page with the `position` of the last one as its `position`.
+    /// The page size is the `limit` of `input`.
+    pub fn get_models_pages(
+        &self,
+        input: GetModelsRequest,
+    ) -> impl futures::Stream<Item = Result<Models, RusotoError<GetModelsError>>> + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: ApiGatewayClient, input| async move { client.get_models(input).await },
+            |input: &GetModelsRequest, output: &Models| {
+                let position = output.position.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if position.is_none() || position == input.position {
+                    return None;
+                }
+                Some(GetModelsRequest {
+                    position,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `items` of every page of `GetModels` results for `input`, as `get_models_pages` requests them.
+    pub fn get_models_stream(
+        &self,
+        input: GetModelsRequest,
+    ) -> impl futures::Stream<Item = Result<Model, RusotoError<GetModelsError>>> + Send {
+        rusoto_core::pagination::items(self.get_models_pages(input), |page| {
+            page.items.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `GetRequestValidators` results for `input`, requesting each next page with the `position` of the last one as its `position`.
+    /// The page size is the `limit` of `input`.
+    pub fn get_request_validators_pages(
+        &self,
+        input: GetRequestValidatorsRequest,
+    ) -> impl futures::Stream<Item = Result<RequestValidators, RusotoError<GetRequestValidatorsError>>>
+           + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: ApiGatewayClient, input| async move { client.get_request_validators(input).await },
+            |input: &GetRequestValidatorsRequest, output: &RequestValidators| {
+                let position = output.position.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if position.is_none() || position == input.position {
+                    return None;
+                }
+                Some(GetRequestValidatorsRequest {
+                    position,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `items` of every page of `GetRequestValidators` results for `input`, as `get_request_validators_pages` requests them.
+    pub fn get_request_validators_stream(
+        &self,
+        input: GetRequestValidatorsRequest,
+    ) -> impl futures::Stream<Item = Result<RequestValidator, RusotoError<GetRequestValidatorsError>>>
+           + Send {
+        rusoto_core::pagination::items(self.get_request_validators_pages(input), |page| {
+            page.items.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `GetResources` results for `input`, requesting each next page with the `position` of the last one as its `position`.
+    /// The page size is the `limit` of `input`.
+    pub fn get_resources_pages(
+        &self,
+        input: GetResourcesRequest,
+    ) -> impl futures::Stream<Item = Result<Resources, RusotoError<GetResourcesError>>> + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: ApiGatewayClient, input| async move { client.get_resources(input).await },
+            |input: &GetResourcesRequest, output: &Resources| {
+                let position = output.position.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if position.is_none() || position == input.position {
+                    return None;
+                }
+                Some(GetResourcesRequest {
+                    position,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `items` of every page of `GetResources` results for `input`, as `get_resources_pages` requests them.
+    pub fn get_resources_stream(
+        &self,
+        input: GetResourcesRequest,
+    ) -> impl futures::Stream<Item = Result<Resource, RusotoError<GetResourcesError>>> + Send {
+        rusoto_core::pagination::items(self.get_resources_pages(input), |page| {
+            page.items.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `GetRestApis` results for `input`, requesting each next page with the `position` of the last one as its `position`.
+    /// The page size is the `limit` of `input`.
+    pub fn get_rest_apis_pages(
+        &self,
+        input: GetRestApisRequest,
+    ) -> impl futures::Stream<Item = Result<RestApis, RusotoError<GetRestApisError>>> + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: ApiGatewayClient, input| async move { client.get_rest_apis(input).await },
+            |input: &GetRestApisRequest, output: &RestApis| {
+                let position = output.position.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if position.is_none() || position == input.position {
+                    return None;
+                }
+                Some(GetRestApisRequest {
+                    position,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `items` of every page of `GetRestApis` results for `input`, as `get_rest_apis_pages` requests them.
+    pub fn get_rest_apis_stream(
+        &self,
+        input: GetRestApisRequest,
+    ) -> impl futures::Stream<Item = Result<RestApi, RusotoError<GetRestApisError>>> + Send {
+        rusoto_core::pagination::items(self.get_rest_apis_pages(input), |page| {
+            page.items.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `GetSdkTypes` results for `input`, requesting each next page with the `position` of the last one as its `position`.
+    /// The page size is the `limit` of `input`.
+    pub fn get_sdk_types_pages(
+        &self,
+        input: GetSdkTypesRequest,
+    ) -> impl futures::Stream<Item = Result<SdkTypes, RusotoError<GetSdkTypesError>>> + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: ApiGatewayClient, input| async move { client.get_sdk_types(input).await },
+            |input: &GetSdkTypesRequest, output: &SdkTypes| {
+                let position = output.position.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if position.is_none() || position == input.position {
+                    return None;
+                }
+                Some(GetSdkTypesRequest {
+                    position,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `items` of every page of `GetSdkTypes` results for `input`, as `get_sdk_types_pages` requests them.
+    pub fn get_sdk_types_stream(
+        &self,
+        input: GetSdkTypesRequest,
+    ) -> impl futures::Stream<Item = Result<SdkType, RusotoError<GetSdkTypesError>>> + Send {
+        rusoto_core::pagination::items(self.get_sdk_types_pages(input), |page| {
+            page.items.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `GetUsage` results for `input`, requesting each next page with the `position` of the last one as its `position`.
+    /// The page size is the `limit` of `input`.
+    pub fn get_usage_pages(
+        &self,
+        input: GetUsageRequest,
+    ) -> impl futures::Stream<Item = Result<Usage, RusotoError<GetUsageError>>> + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: ApiGatewayClient, input| async move { client.get_usage(input).await },
+            |input: &GetUsageRequest, output: &Usage| {
+                let position = output.position.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if position.is_none() || position == input.position {
+                    return None;
+                }
+                Some(GetUsageRequest {
+                    position,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The pages of `GetUsagePlanKeys` results for `input`, requesting each next page with the `position` of the last one as its `position`.
+    /// The page size is the `limit` of `input`.
+    pub fn get_usage_plan_keys_pages(
+        &self,
+        input: GetUsagePlanKeysRequest,
+    ) -> impl futures::Stream<Item = Result<UsagePlanKeys, RusotoError<GetUsagePlanKeysError>>> + Send
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: ApiGatewayClient, input| async move { client.get_usage_plan_keys(input).await },
+            |input: &GetUsagePlanKeysRequest, output: &UsagePlanKeys| {
+                let position = output.position.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if position.is_none() || position == input.position {
+                    return None;
+                }
+                Some(GetUsagePlanKeysRequest {
+                    position,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `items` of every page of `GetUsagePlanKeys` results for `input`, as `get_usage_plan_keys_pages` requests them.
+    pub fn get_usage_plan_keys_stream(
+        &self,
+        input: GetUsagePlanKeysRequest,
+    ) -> impl futures::Stream<Item = Result<UsagePlanKey, RusotoError<GetUsagePlanKeysError>>> + Send
+    {
+        rusoto_core::pagination::items(self.get_usage_plan_keys_pages(input), |page| {
+            page.items.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `GetUsagePlans` results for `input`, requesting each next page with the `position` of the last one as its `position`.
+    /// The page size is the `limit` of `input`.
+    pub fn get_usage_plans_pages(
+        &self,
+        input: GetUsagePlansRequest,
+    ) -> impl futures::Stream<Item = Result<UsagePlans, RusotoError<GetUsagePlansError>>> + Send
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: ApiGatewayClient, input| async move { client.get_usage_plans(input).await },
+            |input: &GetUsagePlansRequest, output: &UsagePlans| {
+                let position = output.position.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if position.is_none() || position == input.position {
+                    return None;
+                }
+                Some(GetUsagePlansRequest {
+                    position,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `items` of every page of `GetUsagePlans` results for `input`, as `get_usage_plans_pages` requests them.
+    pub fn get_usage_plans_stream(
+        &self,
+        input: GetUsagePlansRequest,
+    ) -> impl futures::Stream<Item = Result<UsagePlan, RusotoError<GetUsagePlansError>>> + Send
+    {
+        rusoto_core::pagination::items(self.get_usage_plans_pages(input), |page| {
+            page.items.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `GetVpcLinks` results for `input`, requesting each next page with the `position` of the last one as its `position`.
+    /// The page size is the `limit` of `input`.
+    pub fn get_vpc_links_pages(
+        &self,
+        input: GetVpcLinksRequest,
+    ) -> impl futures::Stream<Item = Result<VpcLinks, RusotoError<GetVpcLinksError>>> + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: ApiGatewayClient, input| async move { client.get_vpc_links(input).await },
+            |input: &GetVpcLinksRequest, output: &VpcLinks| {
+                let position = output.position.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if position.is_none() || position == input.position {
+                    return None;
+                }
+                Some(GetVpcLinksRequest {
+                    position,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `items` of every page of `GetVpcLinks` results for `input`, as `get_vpc_links_pages` requests them.
+    pub fn get_vpc_links_stream(
+        &self,
+        input: GetVpcLinksRequest,
+    ) -> impl futures::Stream<Item = Result<VpcLink, RusotoError<GetVpcLinksError>>> + Send {
+        rusoto_core::pagination::items(self.get_vpc_links_pages(input), |page| {
+            page.items.unwrap_or_default()
+        })
+    }
+}
+
+#[async_trait]
+impl ApiGateway for ApiGatewayClient {
+    /// <p><p>Create an <a>ApiKey</a> resource. </p> <div class="seeAlso"><a href="https://docs.aws.amazon.com/cli/latest/reference/apigateway/create-api-key.html">AWS CLI</a></div></p>
+    async fn create_api_key(
+        &self,
+        input: CreateApiKeyRequest,
+    ) -> Result<ApiKey, RusotoError<CreateApiKeyError>> {
+        self.create_api_key_with_metadata(input)
             .await
-            .map_err(RusotoError::from)?;
-        if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            let result =
-                proto::json::ResponsePayload::new(&response).deserialize::<VpcLink, _>()?;
+            .map(|(output, _)| output)
+    }
 
-            Ok(result)
-        } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(UpdateVpcLinkError::from_response(response))
-        }
+    /// <p><p>Adds a new <a>Authorizer</a> resource to an existing <a>RestApi</a> resource.</p> <div class="seeAlso"><a href="https://docs.aws.amazon.com/cli/latest/reference/apigateway/create-authorizer.html">AWS CLI</a></div></p>
+    async fn create_authorizer(
+        &self,
+        input: CreateAuthorizerRequest,
+    ) -> Result<Authorizer, RusotoError<CreateAuthorizerError>> {
+        self.create_authorizer_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Creates a new <a>BasePathMapping</a> resource.</p>
+    async fn create_base_path_mapping(
+        &self,
+        input: CreateBasePathMappingRequest,
+    ) -> Result<BasePathMapping, RusotoError<CreateBasePathMappingError>> {
+        self.create_base_path_mapping_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Creates a <a>Deployment</a> resource, which makes a specified <a>RestApi</a> callable over the internet.</p>
+    async fn create_deployment(
+        &self,
+        input: CreateDeploymentRequest,
+    ) -> Result<Deployment, RusotoError<CreateDeploymentError>> {
+        self.create_deployment_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    async fn create_documentation_part(
+        &self,
+        input: CreateDocumentationPartRequest,
+    ) -> Result<DocumentationPart, RusotoError<CreateDocumentationPartError>> {
+        self.create_documentation_part_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    async fn create_documentation_version(
+        &self,
+        input: CreateDocumentationVersionRequest,
+    ) -> Result<DocumentationVersion, RusotoError<CreateDocumentationVersionError>> {
+        self.create_documentation_version_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Creates a new domain name.</p>
+    async fn create_domain_name(
+        &self,
+        input: CreateDomainNameRequest,
+    ) -> Result<DomainName, RusotoError<CreateDomainNameError>> {
+        self.create_domain_name_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Adds a new <a>Model</a> resource to an existing <a>RestApi</a> resource.</p>
+    async fn create_model(
+        &self,
+        input: CreateModelRequest,
+    ) -> Result<Model, RusotoError<CreateModelError>> {
+        self.create_model_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Creates a <a>ReqeustValidator</a> of a given <a>RestApi</a>.</p>
+    async fn create_request_validator(
+        &self,
+        input: CreateRequestValidatorRequest,
+    ) -> Result<RequestValidator, RusotoError<CreateRequestValidatorError>> {
+        self.create_request_validator_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Creates a <a>Resource</a> resource.</p>
+    async fn create_resource(
+        &self,
+        input: CreateResourceRequest,
+    ) -> Result<Resource, RusotoError<CreateResourceError>> {
+        self.create_resource_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Creates a new <a>RestApi</a> resource.</p>
+    async fn create_rest_api(
+        &self,
+        input: CreateRestApiRequest,
+    ) -> Result<RestApi, RusotoError<CreateRestApiError>> {
+        self.create_rest_api_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Creates a new <a>Stage</a> resource that references a pre-existing <a>Deployment</a> for the API. </p>
+    async fn create_stage(
+        &self,
+        input: CreateStageRequest,
+    ) -> Result<Stage, RusotoError<CreateStageError>> {
+        self.create_stage_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Creates a usage plan with the throttle and quota limits, as well as the associated API stages, specified in the payload. </p>
+    async fn create_usage_plan(
+        &self,
+        input: CreateUsagePlanRequest,
+    ) -> Result<UsagePlan, RusotoError<CreateUsagePlanError>> {
+        self.create_usage_plan_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Creates a usage plan key for adding an existing API key to a usage plan.</p>
+    async fn create_usage_plan_key(
+        &self,
+        input: CreateUsagePlanKeyRequest,
+    ) -> Result<UsagePlanKey, RusotoError<CreateUsagePlanKeyError>> {
+        self.create_usage_plan_key_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Creates a VPC link, under the caller's account in a selected region, in an asynchronous operation that typically takes 2-4 minutes to complete and become operational. The caller must have permissions to create and update VPC Endpoint services.</p>
+    async fn create_vpc_link(
+        &self,
+        input: CreateVpcLinkRequest,
+    ) -> Result<VpcLink, RusotoError<CreateVpcLinkError>> {
+        self.create_vpc_link_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes the <a>ApiKey</a> resource.</p>
+    async fn delete_api_key(
+        &self,
+        input: DeleteApiKeyRequest,
+    ) -> Result<(), RusotoError<DeleteApiKeyError>> {
+        self.delete_api_key_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Deletes an existing <a>Authorizer</a> resource.</p> <div class="seeAlso"><a href="https://docs.aws.amazon.com/cli/latest/reference/apigateway/delete-authorizer.html">AWS CLI</a></div></p>
+    async fn delete_authorizer(
+        &self,
+        input: DeleteAuthorizerRequest,
+    ) -> Result<(), RusotoError<DeleteAuthorizerError>> {
+        self.delete_authorizer_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes the <a>BasePathMapping</a> resource.</p>
+    async fn delete_base_path_mapping(
+        &self,
+        input: DeleteBasePathMappingRequest,
+    ) -> Result<(), RusotoError<DeleteBasePathMappingError>> {
+        self.delete_base_path_mapping_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes the <a>ClientCertificate</a> resource.</p>
+    async fn delete_client_certificate(
+        &self,
+        input: DeleteClientCertificateRequest,
+    ) -> Result<(), RusotoError<DeleteClientCertificateError>> {
+        self.delete_client_certificate_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes a <a>Deployment</a> resource. Deleting a deployment will only succeed if there are no <a>Stage</a> resources associated with it.</p>
+    async fn delete_deployment(
+        &self,
+        input: DeleteDeploymentRequest,
+    ) -> Result<(), RusotoError<DeleteDeploymentError>> {
+        self.delete_deployment_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    async fn delete_documentation_part(
+        &self,
+        input: DeleteDocumentationPartRequest,
+    ) -> Result<(), RusotoError<DeleteDocumentationPartError>> {
+        self.delete_documentation_part_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    async fn delete_documentation_version(
+        &self,
+        input: DeleteDocumentationVersionRequest,
+    ) -> Result<(), RusotoError<DeleteDocumentationVersionError>> {
+        self.delete_documentation_version_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes the <a>DomainName</a> resource.</p>
+    async fn delete_domain_name(
+        &self,
+        input: DeleteDomainNameRequest,
+    ) -> Result<(), RusotoError<DeleteDomainNameError>> {
+        self.delete_domain_name_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Clears any customization of a <a>GatewayResponse</a> of a specified response type on the given <a>RestApi</a> and resets it with the default settings.</p>
+    async fn delete_gateway_response(
+        &self,
+        input: DeleteGatewayResponseRequest,
+    ) -> Result<(), RusotoError<DeleteGatewayResponseError>> {
+        self.delete_gateway_response_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Represents a delete integration.</p>
+    async fn delete_integration(
+        &self,
+        input: DeleteIntegrationRequest,
+    ) -> Result<(), RusotoError<DeleteIntegrationError>> {
+        self.delete_integration_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Represents a delete integration response.</p>
+    async fn delete_integration_response(
+        &self,
+        input: DeleteIntegrationResponseRequest,
+    ) -> Result<(), RusotoError<DeleteIntegrationResponseError>> {
+        self.delete_integration_response_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes an existing <a>Method</a> resource.</p>
+    async fn delete_method(
+        &self,
+        input: DeleteMethodRequest,
+    ) -> Result<(), RusotoError<DeleteMethodError>> {
+        self.delete_method_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes an existing <a>MethodResponse</a> resource.</p>
+    async fn delete_method_response(
+        &self,
+        input: DeleteMethodResponseRequest,
+    ) -> Result<(), RusotoError<DeleteMethodResponseError>> {
+        self.delete_method_response_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes a model.</p>
+    async fn delete_model(
+        &self,
+        input: DeleteModelRequest,
+    ) -> Result<(), RusotoError<DeleteModelError>> {
+        self.delete_model_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes a <a>RequestValidator</a> of a given <a>RestApi</a>.</p>
+    async fn delete_request_validator(
+        &self,
+        input: DeleteRequestValidatorRequest,
+    ) -> Result<(), RusotoError<DeleteRequestValidatorError>> {
+        self.delete_request_validator_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes a <a>Resource</a> resource.</p>
+    async fn delete_resource(
+        &self,
+        input: DeleteResourceRequest,
+    ) -> Result<(), RusotoError<DeleteResourceError>> {
+        self.delete_resource_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes the specified API.</p>
+    async fn delete_rest_api(
+        &self,
+        input: DeleteRestApiRequest,
+    ) -> Result<(), RusotoError<DeleteRestApiError>> {
+        self.delete_rest_api_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes a <a>Stage</a> resource.</p>
+    async fn delete_stage(
+        &self,
+        input: DeleteStageRequest,
+    ) -> Result<(), RusotoError<DeleteStageError>> {
+        self.delete_stage_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes a usage plan of a given plan Id.</p>
+    async fn delete_usage_plan(
+        &self,
+        input: DeleteUsagePlanRequest,
+    ) -> Result<(), RusotoError<DeleteUsagePlanError>> {
+        self.delete_usage_plan_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes a usage plan key and remove the underlying API key from the associated usage plan.</p>
+    async fn delete_usage_plan_key(
+        &self,
+        input: DeleteUsagePlanKeyRequest,
+    ) -> Result<(), RusotoError<DeleteUsagePlanKeyError>> {
+        self.delete_usage_plan_key_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes an existing <a>VpcLink</a> of a specified identifier.</p>
+    async fn delete_vpc_link(
+        &self,
+        input: DeleteVpcLinkRequest,
+    ) -> Result<(), RusotoError<DeleteVpcLinkError>> {
+        self.delete_vpc_link_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Flushes all authorizer cache entries on a stage.</p>
+    async fn flush_stage_authorizers_cache(
+        &self,
+        input: FlushStageAuthorizersCacheRequest,
+    ) -> Result<(), RusotoError<FlushStageAuthorizersCacheError>> {
+        self.flush_stage_authorizers_cache_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Flushes a stage's cache.</p>
+    async fn flush_stage_cache(
+        &self,
+        input: FlushStageCacheRequest,
+    ) -> Result<(), RusotoError<FlushStageCacheError>> {
+        self.flush_stage_cache_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Generates a <a>ClientCertificate</a> resource.</p>
+    async fn generate_client_certificate(
+        &self,
+        input: GenerateClientCertificateRequest,
+    ) -> Result<ClientCertificate, RusotoError<GenerateClientCertificateError>> {
+        self.generate_client_certificate_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Gets information about the current <a>Account</a> resource.</p>
+    async fn get_account(&self) -> Result<Account, RusotoError<GetAccountError>> {
+        self.get_account_with_metadata()
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Gets information about the current <a>ApiKey</a> resource.</p>
+    async fn get_api_key(
+        &self,
+        input: GetApiKeyRequest,
+    ) -> Result<ApiKey, RusotoError<GetApiKeyError>> {
+        self.get_api_key_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Gets information about the current <a>ApiKeys</a> resource.</p>
+    async fn get_api_keys(
+        &self,
+        input: GetApiKeysRequest,
+    ) -> Result<ApiKeys, RusotoError<GetApiKeysError>> {
+        self.get_api_keys_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Describe an existing <a>Authorizer</a> resource.</p> <div class="seeAlso"><a href="https://docs.aws.amazon.com/cli/latest/reference/apigateway/get-authorizer.html">AWS CLI</a></div></p>
+    async fn get_authorizer(
+        &self,
+        input: GetAuthorizerRequest,
+    ) -> Result<Authorizer, RusotoError<GetAuthorizerError>> {
+        self.get_authorizer_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Describe an existing <a>Authorizers</a> resource.</p> <div class="seeAlso"><a href="https://docs.aws.amazon.com/cli/latest/reference/apigateway/get-authorizers.html">AWS CLI</a></div></p>
+    async fn get_authorizers(
+        &self,
+        input: GetAuthorizersRequest,
+    ) -> Result<Authorizers, RusotoError<GetAuthorizersError>> {
+        self.get_authorizers_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Describe a <a>BasePathMapping</a> resource.</p>
+    async fn get_base_path_mapping(
+        &self,
+        input: GetBasePathMappingRequest,
+    ) -> Result<BasePathMapping, RusotoError<GetBasePathMappingError>> {
+        self.get_base_path_mapping_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Represents a collection of <a>BasePathMapping</a> resources.</p>
+    async fn get_base_path_mappings(
+        &self,
+        input: GetBasePathMappingsRequest,
+    ) -> Result<BasePathMappings, RusotoError<GetBasePathMappingsError>> {
+        self.get_base_path_mappings_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Gets information about the current <a>ClientCertificate</a> resource.</p>
+    async fn get_client_certificate(
+        &self,
+        input: GetClientCertificateRequest,
+    ) -> Result<ClientCertificate, RusotoError<GetClientCertificateError>> {
+        self.get_client_certificate_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Gets a collection of <a>ClientCertificate</a> resources.</p>
+    async fn get_client_certificates(
+        &self,
+        input: GetClientCertificatesRequest,
+    ) -> Result<ClientCertificates, RusotoError<GetClientCertificatesError>> {
+        self.get_client_certificates_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Gets information about a <a>Deployment</a> resource.</p>
+    async fn get_deployment(
+        &self,
+        input: GetDeploymentRequest,
+    ) -> Result<Deployment, RusotoError<GetDeploymentError>> {
+        self.get_deployment_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Gets information about a <a>Deployments</a> collection.</p>
+    async fn get_deployments(
+        &self,
+        input: GetDeploymentsRequest,
+    ) -> Result<Deployments, RusotoError<GetDeploymentsError>> {
+        self.get_deployments_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    async fn get_documentation_part(
+        &self,
+        input: GetDocumentationPartRequest,
+    ) -> Result<DocumentationPart, RusotoError<GetDocumentationPartError>> {
+        self.get_documentation_part_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    async fn get_documentation_parts(
+        &self,
+        input: GetDocumentationPartsRequest,
+    ) -> Result<DocumentationParts, RusotoError<GetDocumentationPartsError>> {
+        self.get_documentation_parts_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    async fn get_documentation_version(
+        &self,
+        input: GetDocumentationVersionRequest,
+    ) -> Result<DocumentationVersion, RusotoError<GetDocumentationVersionError>> {
+        self.get_documentation_version_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    async fn get_documentation_versions(
+        &self,
+        input: GetDocumentationVersionsRequest,
+    ) -> Result<DocumentationVersions, RusotoError<GetDocumentationVersionsError>> {
+        self.get_documentation_versions_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Represents a domain name that is contained in a simpler, more intuitive URL that can be called.</p>
+    async fn get_domain_name(
+        &self,
+        input: GetDomainNameRequest,
+    ) -> Result<DomainName, RusotoError<GetDomainNameError>> {
+        self.get_domain_name_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Represents a collection of <a>DomainName</a> resources.</p>
+    async fn get_domain_names(
+        &self,
+        input: GetDomainNamesRequest,
+    ) -> Result<DomainNames, RusotoError<GetDomainNamesError>> {
+        self.get_domain_names_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Exports a deployed version of a <a>RestApi</a> in a specified format.</p>
+    async fn get_export(
+        &self,
+        input: GetExportRequest,
+    ) -> Result<ExportResponse, RusotoError<GetExportError>> {
+        self.get_export_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Gets a <a>GatewayResponse</a> of a specified response type on the given <a>RestApi</a>.</p>
+    async fn get_gateway_response(
+        &self,
+        input: GetGatewayResponseRequest,
+    ) -> Result<GatewayResponse, RusotoError<GetGatewayResponseError>> {
+        self.get_gateway_response_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Gets the <a>GatewayResponses</a> collection on the given <a>RestApi</a>. If an API developer has not added any definitions for gateway responses, the result will be the API Gateway-generated default <a>GatewayResponses</a> collection for the supported response types.</p>
+    async fn get_gateway_responses(
+        &self,
+        input: GetGatewayResponsesRequest,
+    ) -> Result<GatewayResponses, RusotoError<GetGatewayResponsesError>> {
+        self.get_gateway_responses_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Get the integration settings.</p>
+    async fn get_integration(
+        &self,
+        input: GetIntegrationRequest,
+    ) -> Result<Integration, RusotoError<GetIntegrationError>> {
+        self.get_integration_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Represents a get integration response.</p>
+    async fn get_integration_response(
+        &self,
+        input: GetIntegrationResponseRequest,
+    ) -> Result<IntegrationResponse, RusotoError<GetIntegrationResponseError>> {
+        self.get_integration_response_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Describe an existing <a>Method</a> resource.</p>
+    async fn get_method(
+        &self,
+        input: GetMethodRequest,
+    ) -> Result<Method, RusotoError<GetMethodError>> {
+        self.get_method_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Describes a <a>MethodResponse</a> resource.</p>
+    async fn get_method_response(
+        &self,
+        input: GetMethodResponseRequest,
+    ) -> Result<MethodResponse, RusotoError<GetMethodResponseError>> {
+        self.get_method_response_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Describes an existing model defined for a <a>RestApi</a> resource.</p>
+    async fn get_model(&self, input: GetModelRequest) -> Result<Model, RusotoError<GetModelError>> {
+        self.get_model_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Generates a sample mapping template that can be used to transform a payload into the structure of a model.</p>
+    async fn get_model_template(
+        &self,
+        input: GetModelTemplateRequest,
+    ) -> Result<Template, RusotoError<GetModelTemplateError>> {
+        self.get_model_template_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Describes existing <a>Models</a> defined for a <a>RestApi</a> resource.</p>
+    async fn get_models(
+        &self,
+        input: GetModelsRequest,
+    ) -> Result<Models, RusotoError<GetModelsError>> {
+        self.get_models_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Gets a <a>RequestValidator</a> of a given <a>RestApi</a>.</p>
+    async fn get_request_validator(
+        &self,
+        input: GetRequestValidatorRequest,
+    ) -> Result<RequestValidator, RusotoError<GetRequestValidatorError>> {
+        self.get_request_validator_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Gets the <a>RequestValidators</a> collection of a given <a>RestApi</a>.</p>
+    async fn get_request_validators(
+        &self,
+        input: GetRequestValidatorsRequest,
+    ) -> Result<RequestValidators, RusotoError<GetRequestValidatorsError>> {
+        self.get_request_validators_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Lists information about a resource.</p>
+    async fn get_resource(
+        &self,
+        input: GetResourceRequest,
+    ) -> Result<Resource, RusotoError<GetResourceError>> {
+        self.get_resource_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Lists information about a collection of <a>Resource</a> resources.</p>
+    async fn get_resources(
+        &self,
+        input: GetResourcesRequest,
+    ) -> Result<Resources, RusotoError<GetResourcesError>> {
+        self.get_resources_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Lists the <a>RestApi</a> resource in the collection.</p>
+    async fn get_rest_api(
+        &self,
+        input: GetRestApiRequest,
+    ) -> Result<RestApi, RusotoError<GetRestApiError>> {
+        self.get_rest_api_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Lists the <a>RestApis</a> resources for your collection.</p>
+    async fn get_rest_apis(
+        &self,
+        input: GetRestApisRequest,
+    ) -> Result<RestApis, RusotoError<GetRestApisError>> {
+        self.get_rest_apis_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Generates a client SDK for a <a>RestApi</a> and <a>Stage</a>.</p>
+    async fn get_sdk(&self, input: GetSdkRequest) -> Result<SdkResponse, RusotoError<GetSdkError>> {
+        self.get_sdk_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    async fn get_sdk_type(
+        &self,
+        input: GetSdkTypeRequest,
+    ) -> Result<SdkType, RusotoError<GetSdkTypeError>> {
+        self.get_sdk_type_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    async fn get_sdk_types(
+        &self,
+        input: GetSdkTypesRequest,
+    ) -> Result<SdkTypes, RusotoError<GetSdkTypesError>> {
+        self.get_sdk_types_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Gets information about a <a>Stage</a> resource.</p>
+    async fn get_stage(&self, input: GetStageRequest) -> Result<Stage, RusotoError<GetStageError>> {
+        self.get_stage_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Gets information about one or more <a>Stage</a> resources.</p>
+    async fn get_stages(
+        &self,
+        input: GetStagesRequest,
+    ) -> Result<Stages, RusotoError<GetStagesError>> {
+        self.get_stages_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Gets the <a>Tags</a> collection for a given resource.</p>
+    async fn get_tags(&self, input: GetTagsRequest) -> Result<Tags, RusotoError<GetTagsError>> {
+        self.get_tags_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Gets the usage data of a usage plan in a specified time interval.</p>
+    async fn get_usage(&self, input: GetUsageRequest) -> Result<Usage, RusotoError<GetUsageError>> {
+        self.get_usage_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Gets a usage plan of a given plan identifier.</p>
+    async fn get_usage_plan(
+        &self,
+        input: GetUsagePlanRequest,
+    ) -> Result<UsagePlan, RusotoError<GetUsagePlanError>> {
+        self.get_usage_plan_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Gets a usage plan key of a given key identifier.</p>
+    async fn get_usage_plan_key(
+        &self,
+        input: GetUsagePlanKeyRequest,
+    ) -> Result<UsagePlanKey, RusotoError<GetUsagePlanKeyError>> {
+        self.get_usage_plan_key_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Gets all the usage plan keys representing the API keys added to a specified usage plan.</p>
+    async fn get_usage_plan_keys(
+        &self,
+        input: GetUsagePlanKeysRequest,
+    ) -> Result<UsagePlanKeys, RusotoError<GetUsagePlanKeysError>> {
+        self.get_usage_plan_keys_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Gets all the usage plans of the caller's account.</p>
+    async fn get_usage_plans(
+        &self,
+        input: GetUsagePlansRequest,
+    ) -> Result<UsagePlans, RusotoError<GetUsagePlansError>> {
+        self.get_usage_plans_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Gets a specified VPC link under the caller's account in a region.</p>
+    async fn get_vpc_link(
+        &self,
+        input: GetVpcLinkRequest,
+    ) -> Result<VpcLink, RusotoError<GetVpcLinkError>> {
+        self.get_vpc_link_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Gets the <a>VpcLinks</a> collection under the caller's account in a selected region.</p>
+    async fn get_vpc_links(
+        &self,
+        input: GetVpcLinksRequest,
+    ) -> Result<VpcLinks, RusotoError<GetVpcLinksError>> {
+        self.get_vpc_links_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Import API keys from an external source, such as a CSV-formatted file.</p>
+    async fn import_api_keys(
+        &self,
+        input: ImportApiKeysRequest,
+    ) -> Result<ApiKeyIds, RusotoError<ImportApiKeysError>> {
+        self.import_api_keys_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    async fn import_documentation_parts(
+        &self,
+        input: ImportDocumentationPartsRequest,
+    ) -> Result<DocumentationPartIds, RusotoError<ImportDocumentationPartsError>> {
+        self.import_documentation_parts_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>A feature of the API Gateway control service for creating a new API from an external API definition file.</p>
+    async fn import_rest_api(
+        &self,
+        input: ImportRestApiRequest,
+    ) -> Result<RestApi, RusotoError<ImportRestApiError>> {
+        self.import_rest_api_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Creates a customization of a <a>GatewayResponse</a> of a specified response type and status code on the given <a>RestApi</a>.</p>
+    async fn put_gateway_response(
+        &self,
+        input: PutGatewayResponseRequest,
+    ) -> Result<GatewayResponse, RusotoError<PutGatewayResponseError>> {
+        self.put_gateway_response_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Sets up a method's integration.</p>
+    async fn put_integration(
+        &self,
+        input: PutIntegrationRequest,
+    ) -> Result<Integration, RusotoError<PutIntegrationError>> {
+        self.put_integration_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Represents a put integration.</p>
+    async fn put_integration_response(
+        &self,
+        input: PutIntegrationResponseRequest,
+    ) -> Result<IntegrationResponse, RusotoError<PutIntegrationResponseError>> {
+        self.put_integration_response_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Add a method to an existing <a>Resource</a> resource.</p>
+    async fn put_method(
+        &self,
+        input: PutMethodRequest,
+    ) -> Result<Method, RusotoError<PutMethodError>> {
+        self.put_method_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Adds a <a>MethodResponse</a> to an existing <a>Method</a> resource.</p>
+    async fn put_method_response(
+        &self,
+        input: PutMethodResponseRequest,
+    ) -> Result<MethodResponse, RusotoError<PutMethodResponseError>> {
+        self.put_method_response_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>A feature of the API Gateway control service for updating an existing API with an input of external API definitions. The update can take the form of merging the supplied definition into the existing API or overwriting the existing API.</p>
+    async fn put_rest_api(
+        &self,
+        input: PutRestApiRequest,
+    ) -> Result<RestApi, RusotoError<PutRestApiError>> {
+        self.put_rest_api_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Adds or updates a tag on a given resource.</p>
+    async fn tag_resource(
+        &self,
+        input: TagResourceRequest,
+    ) -> Result<(), RusotoError<TagResourceError>> {
+        self.tag_resource_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Simulate the execution of an <a>Authorizer</a> in your <a>RestApi</a> with headers, parameters, and an incoming request body.</p> <div class="seeAlso"> <a href="https://docs.aws.amazon.com/apigateway/latest/developerguide/apigateway-use-lambda-authorizer.html">Use Lambda Function as Authorizer</a> <a href="https://docs.aws.amazon.com/apigateway/latest/developerguide/apigateway-integrate-with-cognito.html">Use Cognito User Pool as Authorizer</a> </div></p>
+    async fn test_invoke_authorizer(
+        &self,
+        input: TestInvokeAuthorizerRequest,
+    ) -> Result<TestInvokeAuthorizerResponse, RusotoError<TestInvokeAuthorizerError>> {
+        self.test_invoke_authorizer_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Simulate the execution of a <a>Method</a> in your <a>RestApi</a> with headers, parameters, and an incoming request body.</p>
+    async fn test_invoke_method(
+        &self,
+        input: TestInvokeMethodRequest,
+    ) -> Result<TestInvokeMethodResponse, RusotoError<TestInvokeMethodError>> {
+        self.test_invoke_method_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Removes a tag from a given resource.</p>
+    async fn untag_resource(
+        &self,
+        input: UntagResourceRequest,
+    ) -> Result<(), RusotoError<UntagResourceError>> {
+        self.untag_resource_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Changes information about the current <a>Account</a> resource.</p>
+    async fn update_account(
+        &self,
+        input: UpdateAccountRequest,
+    ) -> Result<Account, RusotoError<UpdateAccountError>> {
+        self.update_account_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Changes information about an <a>ApiKey</a> resource.</p>
+    async fn update_api_key(
+        &self,
+        input: UpdateApiKeyRequest,
+    ) -> Result<ApiKey, RusotoError<UpdateApiKeyError>> {
+        self.update_api_key_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Updates an existing <a>Authorizer</a> resource.</p> <div class="seeAlso"><a href="https://docs.aws.amazon.com/cli/latest/reference/apigateway/update-authorizer.html">AWS CLI</a></div></p>
+    async fn update_authorizer(
+        &self,
+        input: UpdateAuthorizerRequest,
+    ) -> Result<Authorizer, RusotoError<UpdateAuthorizerError>> {
+        self.update_authorizer_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Changes information about the <a>BasePathMapping</a> resource.</p>
+    async fn update_base_path_mapping(
+        &self,
+        input: UpdateBasePathMappingRequest,
+    ) -> Result<BasePathMapping, RusotoError<UpdateBasePathMappingError>> {
+        self.update_base_path_mapping_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Changes information about an <a>ClientCertificate</a> resource.</p>
+    async fn update_client_certificate(
+        &self,
+        input: UpdateClientCertificateRequest,
+    ) -> Result<ClientCertificate, RusotoError<UpdateClientCertificateError>> {
+        self.update_client_certificate_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Changes information about a <a>Deployment</a> resource.</p>
+    async fn update_deployment(
+        &self,
+        input: UpdateDeploymentRequest,
+    ) -> Result<Deployment, RusotoError<UpdateDeploymentError>> {
+        self.update_deployment_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    async fn update_documentation_part(
+        &self,
+        input: UpdateDocumentationPartRequest,
+    ) -> Result<DocumentationPart, RusotoError<UpdateDocumentationPartError>> {
+        self.update_documentation_part_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    async fn update_documentation_version(
+        &self,
+        input: UpdateDocumentationVersionRequest,
+    ) -> Result<DocumentationVersion, RusotoError<UpdateDocumentationVersionError>> {
+        self.update_documentation_version_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Changes information about the <a>DomainName</a> resource.</p>
+    async fn update_domain_name(
+        &self,
+        input: UpdateDomainNameRequest,
+    ) -> Result<DomainName, RusotoError<UpdateDomainNameError>> {
+        self.update_domain_name_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Updates a <a>GatewayResponse</a> of a specified response type on the given <a>RestApi</a>.</p>
+    async fn update_gateway_response(
+        &self,
+        input: UpdateGatewayResponseRequest,
+    ) -> Result<GatewayResponse, RusotoError<UpdateGatewayResponseError>> {
+        self.update_gateway_response_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Represents an update integration.</p>
+    async fn update_integration(
+        &self,
+        input: UpdateIntegrationRequest,
+    ) -> Result<Integration, RusotoError<UpdateIntegrationError>> {
+        self.update_integration_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Represents an update integration response.</p>
+    async fn update_integration_response(
+        &self,
+        input: UpdateIntegrationResponseRequest,
+    ) -> Result<IntegrationResponse, RusotoError<UpdateIntegrationResponseError>> {
+        self.update_integration_response_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Updates an existing <a>Method</a> resource.</p>
+    async fn update_method(
+        &self,
+        input: UpdateMethodRequest,
+    ) -> Result<Method, RusotoError<UpdateMethodError>> {
+        self.update_method_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Updates an existing <a>MethodResponse</a> resource.</p>
+    async fn update_method_response(
+        &self,
+        input: UpdateMethodResponseRequest,
+    ) -> Result<MethodResponse, RusotoError<UpdateMethodResponseError>> {
+        self.update_method_response_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Changes information about a model.</p>
+    async fn update_model(
+        &self,
+        input: UpdateModelRequest,
+    ) -> Result<Model, RusotoError<UpdateModelError>> {
+        self.update_model_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Updates a <a>RequestValidator</a> of a given <a>RestApi</a>.</p>
+    async fn update_request_validator(
+        &self,
+        input: UpdateRequestValidatorRequest,
+    ) -> Result<RequestValidator, RusotoError<UpdateRequestValidatorError>> {
+        self.update_request_validator_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Changes information about a <a>Resource</a> resource.</p>
+    async fn update_resource(
+        &self,
+        input: UpdateResourceRequest,
+    ) -> Result<Resource, RusotoError<UpdateResourceError>> {
+        self.update_resource_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Changes information about the specified API.</p>
+    async fn update_rest_api(
+        &self,
+        input: UpdateRestApiRequest,
+    ) -> Result<RestApi, RusotoError<UpdateRestApiError>> {
+        self.update_rest_api_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Changes information about a <a>Stage</a> resource.</p>
+    async fn update_stage(
+        &self,
+        input: UpdateStageRequest,
+    ) -> Result<Stage, RusotoError<UpdateStageError>> {
+        self.update_stage_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Grants a temporary extension to the remaining quota of a usage plan associated with a specified API key.</p>
+    async fn update_usage(
+        &self,
+        input: UpdateUsageRequest,
+    ) -> Result<Usage, RusotoError<UpdateUsageError>> {
+        self.update_usage_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Updates a usage plan of a given plan Id.</p>
+    async fn update_usage_plan(
+        &self,
+        input: UpdateUsagePlanRequest,
+    ) -> Result<UsagePlan, RusotoError<UpdateUsagePlanError>> {
+        self.update_usage_plan_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Updates an existing <a>VpcLink</a> of a specified identifier.</p>
+    async fn update_vpc_link(
+        &self,
+        input: UpdateVpcLinkRequest,
+    ) -> Result<VpcLink, RusotoError<UpdateVpcLinkError>> {
+        self.update_vpc_link_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
     }
 }
@@ -16,8 +16,8 @@ use std::fmt;
 use async_trait::async_trait;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
-use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest, ResponseMetadata};
+use rusoto_core::{Client, ErrorMetadata, RusotoError};
 
 use rusoto_core::proto;
 use rusoto_core::signature::SignedRequest;
@@ -82,6 +82,7 @@ pub struct PostToConnectionRequest {
 
 /// Errors returned by DeleteConnection
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteConnectionError {
     /// <p>The caller is not authorized to invoke this operation.</p>
     Forbidden(String),
@@ -89,6 +90,8 @@ pub enum DeleteConnectionError {
     Gone(String),
     /// <p>The client is sending more than the allowed number of requests per unit of time or the WebSocket client side buffer is full.</p>
     LimitExceeded(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteConnectionError {
@@ -96,16 +99,19 @@ impl DeleteConnectionError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ForbiddenException" => {
-                    return RusotoError::Service(DeleteConnectionError::Forbidden(err.msg))
+                    return RusotoError::service(DeleteConnectionError::Forbidden(err.msg), &res)
                 }
                 "GoneException" => {
-                    return RusotoError::Service(DeleteConnectionError::Gone(err.msg))
+                    return RusotoError::service(DeleteConnectionError::Gone(err.msg), &res)
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(DeleteConnectionError::LimitExceeded(err.msg))
+                    return RusotoError::service(
+                        DeleteConnectionError::LimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteConnectionError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -118,12 +124,14 @@ impl fmt::Display for DeleteConnectionError {
             DeleteConnectionError::Forbidden(ref cause) => write!(f, "{}", cause),
             DeleteConnectionError::Gone(ref cause) => write!(f, "{}", cause),
             DeleteConnectionError::LimitExceeded(ref cause) => write!(f, "{}", cause),
+            DeleteConnectionError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteConnectionError {}
 /// Errors returned by GetConnection
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetConnectionError {
     /// <p>The caller is not authorized to invoke this operation.</p>
     Forbidden(String),
@@ -131,6 +139,8 @@ pub enum GetConnectionError {
     Gone(String),
     /// <p>The client is sending more than the allowed number of requests per unit of time or the WebSocket client side buffer is full.</p>
     LimitExceeded(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetConnectionError {
@@ -138,14 +148,16 @@ impl GetConnectionError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ForbiddenException" => {
-                    return RusotoError::Service(GetConnectionError::Forbidden(err.msg))
+                    return RusotoError::service(GetConnectionError::Forbidden(err.msg), &res)
+                }
+                "GoneException" => {
+                    return RusotoError::service(GetConnectionError::Gone(err.msg), &res)
                 }
-                "GoneException" => return RusotoError::Service(GetConnectionError::Gone(err.msg)),
                 "LimitExceededException" => {
-                    return RusotoError::Service(GetConnectionError::LimitExceeded(err.msg))
+                    return RusotoError::service(GetConnectionError::LimitExceeded(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetConnectionError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -158,12 +170,14 @@ impl fmt::Display for GetConnectionError {
             GetConnectionError::Forbidden(ref cause) => write!(f, "{}", cause),
             GetConnectionError::Gone(ref cause) => write!(f, "{}", cause),
             GetConnectionError::LimitExceeded(ref cause) => write!(f, "{}", cause),
+            GetConnectionError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetConnectionError {}
 /// Errors returned by PostToConnection
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum PostToConnectionError {
     /// <p>The caller is not authorized to invoke this operation.</p>
     Forbidden(String),
@@ -173,6 +187,8 @@ pub enum PostToConnectionError {
     LimitExceeded(String),
     /// <p>The data has exceeded the maximum size allowed.</p>
     PayloadTooLarge(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl PostToConnectionError {
@@ -180,19 +196,25 @@ impl PostToConnectionError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ForbiddenException" => {
-                    return RusotoError::Service(PostToConnectionError::Forbidden(err.msg))
+                    return RusotoError::service(PostToConnectionError::Forbidden(err.msg), &res)
                 }
                 "GoneException" => {
-                    return RusotoError::Service(PostToConnectionError::Gone(err.msg))
+                    return RusotoError::service(PostToConnectionError::Gone(err.msg), &res)
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(PostToConnectionError::LimitExceeded(err.msg))
+                    return RusotoError::service(
+                        PostToConnectionError::LimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "PayloadTooLargeException" => {
-                    return RusotoError::Service(PostToConnectionError::PayloadTooLarge(err.msg))
+                    return RusotoError::service(
+                        PostToConnectionError::PayloadTooLarge(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(PostToConnectionError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -206,6 +228,7 @@ impl fmt::Display for PostToConnectionError {
             PostToConnectionError::Gone(ref cause) => write!(f, "{}", cause),
             PostToConnectionError::LimitExceeded(ref cause) => write!(f, "{}", cause),
             PostToConnectionError::PayloadTooLarge(ref cause) => write!(f, "{}", cause),
+            PostToConnectionError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
@@ -242,6 +265,7 @@ impl ApiGatewayManagementApiClient {
     /// Creates a client backed by the default tokio event loop.
     ///
     /// The client will use the default credentials provider and tls client.
+    #[cfg(not(target_arch = "wasm32"))]
     pub fn new(region: region::Region) -> ApiGatewayManagementApiClient {
         ApiGatewayManagementApiClient {
             client: Client::shared(),
@@ -270,98 +294,220 @@ impl ApiGatewayManagementApiClient {
     ) -> ApiGatewayManagementApiClient {
         ApiGatewayManagementApiClient { client, region }
     }
+
+    /// Returns a builder of a client, setting any of its region, credentials provider,
+    /// dispatcher, retry policy, endpoint and timeouts.
+    pub fn builder() -> rusoto_core::ClientBuilder<ApiGatewayManagementApiClient> {
+        rusoto_core::ClientBuilder::new(ApiGatewayManagementApiClient::new_with_client)
+    }
+
+    /// Returns a clone of this client with the overrides of `config` applied to its
+    /// operations, sharing its connections.
+    pub fn customize(
+        &self,
+        config: &rusoto_core::OperationConfig,
+    ) -> ApiGatewayManagementApiClient {
+        ApiGatewayManagementApiClient {
+            client: self.client.customize(config),
+            region: config.resolve_region(&self.region),
+        }
+    }
 }
 
-#[async_trait]
-impl ApiGatewayManagementApi for ApiGatewayManagementApiClient {
-    /// <p>Delete the connection with the provided id.</p>
-    async fn delete_connection(
+impl ApiGatewayManagementApiClient {
+    /// Calls `delete_connection`, also returning the metadata of the response.
+    pub async fn delete_connection_with_metadata(
         &self,
         input: DeleteConnectionRequest,
-    ) -> Result<(), RusotoError<DeleteConnectionError>> {
-        let request_uri = format!(
-            "/@connections/{connection_id}",
-            connection_id = input.connection_id
-        );
-
-        let mut request = SignedRequest::new("DELETE", "execute-api", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
+    ) -> Result<((), ResponseMetadata), RusotoError<DeleteConnectionError>> {
+        let request = self.build_delete_connection_request(input);
 
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 204 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = ::std::mem::drop(response);
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             Err(DeleteConnectionError::from_response(response))
         }
     }
 
-    /// <p>Get information about the connection with the provided id.</p>
-    async fn get_connection(
+    /// Presigns the request `delete_connection` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_connection(
         &self,
-        input: GetConnectionRequest,
-    ) -> Result<GetConnectionResponse, RusotoError<GetConnectionError>> {
+        presigner: &rusoto_core::Presigner,
+        input: DeleteConnectionRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_connection_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_connection_request(&self, input: DeleteConnectionRequest) -> SignedRequest {
         let request_uri = format!(
             "/@connections/{connection_id}",
             connection_id = input.connection_id
         );
 
-        let mut request = SignedRequest::new("GET", "execute-api", &self.region, &request_uri);
+        let mut request = SignedRequest::new("DELETE", "execute-api", &self.region, &request_uri);
+        request.set_operation("DeleteConnection");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
+        request
+    }
+
+    /// Calls `get_connection`, also returning the metadata of the response.
+    pub async fn get_connection_with_metadata(
+        &self,
+        input: GetConnectionRequest,
+    ) -> Result<(GetConnectionResponse, ResponseMetadata), RusotoError<GetConnectionError>> {
+        let request = self.build_get_connection_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<GetConnectionResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             Err(GetConnectionError::from_response(response))
         }
     }
 
-    /// <p>Sends the provided data to the specified connection.</p>
-    async fn post_to_connection(
+    /// Presigns the request `get_connection` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_connection(
         &self,
-        input: PostToConnectionRequest,
-    ) -> Result<(), RusotoError<PostToConnectionError>> {
+        presigner: &rusoto_core::Presigner,
+        input: GetConnectionRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_connection_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_connection_request(&self, input: GetConnectionRequest) -> SignedRequest {
         let request_uri = format!(
             "/@connections/{connection_id}",
             connection_id = input.connection_id
         );
 
-        let mut request = SignedRequest::new("POST", "execute-api", &self.region, &request_uri);
+        let mut request = SignedRequest::new("GET", "execute-api", &self.region, &request_uri);
+        request.set_operation("GetConnection");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
-        let encoded = Some(input.data.to_owned());
-        request.set_payload(encoded);
+        request
+    }
+
+    /// Calls `post_to_connection`, also returning the metadata of the response.
+    pub async fn post_to_connection_with_metadata(
+        &self,
+        input: PostToConnectionRequest,
+    ) -> Result<((), ResponseMetadata), RusotoError<PostToConnectionError>> {
+        let request = self.build_post_to_connection_request(input);
 
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = ::std::mem::drop(response);
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             Err(PostToConnectionError::from_response(response))
         }
     }
+
+    /// Presigns the request `post_to_connection` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_post_to_connection(
+        &self,
+        presigner: &rusoto_core::Presigner,
+        input: PostToConnectionRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_post_to_connection_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_post_to_connection_request(&self, input: PostToConnectionRequest) -> SignedRequest {
+        let request_uri = format!(
+            "/@connections/{connection_id}",
+            connection_id = input.connection_id
+        );
+
+        let mut request = SignedRequest::new("POST", "execute-api", &self.region, &request_uri);
+        request.set_operation("PostToConnection");
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let encoded = Some(input.data.to_owned());
+        request.set_payload(encoded);
+
+        request
+    }
+}
+
+#[async_trait]
+impl ApiGatewayManagementApi for ApiGatewayManagementApiClient {
+    /// <p>Delete the connection with the provided id.</p>
+    async fn delete_connection(
+        &self,
+        input: DeleteConnectionRequest,
+    ) -> Result<(), RusotoError<DeleteConnectionError>> {
+        self.delete_connection_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Get information about the connection with the provided id.</p>
+    async fn get_connection(
+        &self,
+        input: GetConnectionRequest,
+    ) -> Result<GetConnectionResponse, RusotoError<GetConnectionError>> {
+        self.get_connection_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Sends the provided data to the specified connection.</p>
+    async fn post_to_connection(
+        &self,
+        input: PostToConnectionRequest,
+    ) -> Result<(), RusotoError<PostToConnectionError>> {
+        self.post_to_connection_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
 }
@@ -16,8 +16,8 @@ use std::fmt;
 use async_trait::async_trait;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
-use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest, ResponseMetadata};
+use rusoto_core::{Client, ErrorMetadata, RusotoError};
 
 use rusoto_core::param::{Params, ServiceParams};
 use rusoto_core::proto;
@@ -3573,6 +3573,7 @@ pub struct UpdateStageResponse {
 
 /// Errors returned by CreateApi
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateApiError {
     /// <p>The request is not valid, for example, the input is incomplete or incorrect. See the accompanying error message for details.</p>
     BadRequest(String),
@@ -3582,6 +3583,8 @@ pub enum CreateApiError {
     NotFound(String),
     /// <p>A limit has been exceeded. See the accompanying error message for details.</p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateApiError {
@@ -3589,19 +3592,19 @@ impl CreateApiError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(CreateApiError::BadRequest(err.msg))
+                    return RusotoError::service(CreateApiError::BadRequest(err.msg), &res)
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(CreateApiError::Conflict(err.msg))
+                    return RusotoError::service(CreateApiError::Conflict(err.msg), &res)
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(CreateApiError::NotFound(err.msg))
+                    return RusotoError::service(CreateApiError::NotFound(err.msg), &res)
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(CreateApiError::TooManyRequests(err.msg))
+                    return RusotoError::service(CreateApiError::TooManyRequests(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreateApiError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3615,12 +3618,14 @@ impl fmt::Display for CreateApiError {
             CreateApiError::Conflict(ref cause) => write!(f, "{}", cause),
             CreateApiError::NotFound(ref cause) => write!(f, "{}", cause),
             CreateApiError::TooManyRequests(ref cause) => write!(f, "{}", cause),
+            CreateApiError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateApiError {}
 /// Errors returned by CreateApiMapping
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateApiMappingError {
     /// <p>The request is not valid, for example, the input is incomplete or incorrect. See the accompanying error message for details.</p>
     BadRequest(String),
@@ -3630,6 +3635,8 @@ pub enum CreateApiMappingError {
     NotFound(String),
     /// <p>A limit has been exceeded. See the accompanying error message for details.</p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateApiMappingError {
@@ -3637,19 +3644,22 @@ impl CreateApiMappingError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(CreateApiMappingError::BadRequest(err.msg))
+                    return RusotoError::service(CreateApiMappingError::BadRequest(err.msg), &res)
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(CreateApiMappingError::Conflict(err.msg))
+                    return RusotoError::service(CreateApiMappingError::Conflict(err.msg), &res)
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(CreateApiMappingError::NotFound(err.msg))
+                    return RusotoError::service(CreateApiMappingError::NotFound(err.msg), &res)
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(CreateApiMappingError::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        CreateApiMappingError::TooManyRequests(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreateApiMappingError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3663,12 +3673,14 @@ impl fmt::Display for CreateApiMappingError {
             CreateApiMappingError::Conflict(ref cause) => write!(f, "{}", cause),
             CreateApiMappingError::NotFound(ref cause) => write!(f, "{}", cause),
             CreateApiMappingError::TooManyRequests(ref cause) => write!(f, "{}", cause),
+            CreateApiMappingError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateApiMappingError {}
 /// Errors returned by CreateAuthorizer
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateAuthorizerError {
     /// <p>The request is not valid, for example, the input is incomplete or incorrect. See the accompanying error message for details.</p>
     BadRequest(String),
@@ -3678,6 +3690,8 @@ pub enum CreateAuthorizerError {
     NotFound(String),
     /// <p>A limit has been exceeded. See the accompanying error message for details.</p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateAuthorizerError {
@@ -3685,19 +3699,22 @@ impl CreateAuthorizerError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(CreateAuthorizerError::BadRequest(err.msg))
+                    return RusotoError::service(CreateAuthorizerError::BadRequest(err.msg), &res)
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(CreateAuthorizerError::Conflict(err.msg))
+                    return RusotoError::service(CreateAuthorizerError::Conflict(err.msg), &res)
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(CreateAuthorizerError::NotFound(err.msg))
+                    return RusotoError::service(CreateAuthorizerError::NotFound(err.msg), &res)
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(CreateAuthorizerError::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        CreateAuthorizerError::TooManyRequests(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreateAuthorizerError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3711,12 +3728,14 @@ impl fmt::Display for CreateAuthorizerError {
             CreateAuthorizerError::Conflict(ref cause) => write!(f, "{}", cause),
             CreateAuthorizerError::NotFound(ref cause) => write!(f, "{}", cause),
             CreateAuthorizerError::TooManyRequests(ref cause) => write!(f, "{}", cause),
+            CreateAuthorizerError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateAuthorizerError {}
 /// Errors returned by CreateDeployment
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateDeploymentError {
     /// <p>The request is not valid, for example, the input is incomplete or incorrect. See the accompanying error message for details.</p>
     BadRequest(String),
@@ -3726,6 +3745,8 @@ pub enum CreateDeploymentError {
     NotFound(String),
     /// <p>A limit has been exceeded. See the accompanying error message for details.</p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateDeploymentError {
@@ -3733,19 +3754,22 @@ impl CreateDeploymentError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(CreateDeploymentError::BadRequest(err.msg))
+                    return RusotoError::service(CreateDeploymentError::BadRequest(err.msg), &res)
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(CreateDeploymentError::Conflict(err.msg))
+                    return RusotoError::service(CreateDeploymentError::Conflict(err.msg), &res)
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(CreateDeploymentError::NotFound(err.msg))
+                    return RusotoError::service(CreateDeploymentError::NotFound(err.msg), &res)
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(CreateDeploymentError::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        CreateDeploymentError::TooManyRequests(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreateDeploymentError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3759,12 +3783,14 @@ impl fmt::Display for CreateDeploymentError {
             CreateDeploymentError::Conflict(ref cause) => write!(f, "{}", cause),
             CreateDeploymentError::NotFound(ref cause) => write!(f, "{}", cause),
             CreateDeploymentError::TooManyRequests(ref cause) => write!(f, "{}", cause),
+            CreateDeploymentError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateDeploymentError {}
 /// Errors returned by CreateDomainName
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateDomainNameError {
     AccessDenied(String),
     /// <p>The request is not valid, for example, the input is incomplete or incorrect. See the accompanying error message for details.</p>
@@ -3775,6 +3801,8 @@ pub enum CreateDomainNameError {
     NotFound(String),
     /// <p>A limit has been exceeded. See the accompanying error message for details.</p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateDomainNameError {
@@ -3782,22 +3810,25 @@ impl CreateDomainNameError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(CreateDomainNameError::AccessDenied(err.msg))
+                    return RusotoError::service(CreateDomainNameError::AccessDenied(err.msg), &res)
                 }
                 "BadRequestException" => {
-                    return RusotoError::Service(CreateDomainNameError::BadRequest(err.msg))
+                    return RusotoError::service(CreateDomainNameError::BadRequest(err.msg), &res)
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(CreateDomainNameError::Conflict(err.msg))
+                    return RusotoError::service(CreateDomainNameError::Conflict(err.msg), &res)
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(CreateDomainNameError::NotFound(err.msg))
+                    return RusotoError::service(CreateDomainNameError::NotFound(err.msg), &res)
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(CreateDomainNameError::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        CreateDomainNameError::TooManyRequests(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreateDomainNameError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3812,12 +3843,14 @@ impl fmt::Display for CreateDomainNameError {
             CreateDomainNameError::Conflict(ref cause) => write!(f, "{}", cause),
             CreateDomainNameError::NotFound(ref cause) => write!(f, "{}", cause),
             CreateDomainNameError::TooManyRequests(ref cause) => write!(f, "{}", cause),
+            CreateDomainNameError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateDomainNameError {}
 /// Errors returned by CreateIntegration
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateIntegrationError {
     /// <p>The request is not valid, for example, the input is incomplete or incorrect. See the accompanying error message for details.</p>
     BadRequest(String),
@@ -3827,6 +3860,8 @@ pub enum CreateIntegrationError {
     NotFound(String),
     /// <p>A limit has been exceeded. See the accompanying error message for details.</p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateIntegrationError {
@@ -3834,19 +3869,22 @@ impl CreateIntegrationError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(CreateIntegrationError::BadRequest(err.msg))
+                    return RusotoError::service(CreateIntegrationError::BadRequest(err.msg), &res)
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(CreateIntegrationError::Conflict(err.msg))
+                    return RusotoError::service(CreateIntegrationError::Conflict(err.msg), &res)
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(CreateIntegrationError::NotFound(err.msg))
+                    return RusotoError::service(CreateIntegrationError::NotFound(err.msg), &res)
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(CreateIntegrationError::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        CreateIntegrationError::TooManyRequests(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreateIntegrationError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3860,12 +3898,14 @@ impl fmt::Display for CreateIntegrationError {
             CreateIntegrationError::Conflict(ref cause) => write!(f, "{}", cause),
             CreateIntegrationError::NotFound(ref cause) => write!(f, "{}", cause),
             CreateIntegrationError::TooManyRequests(ref cause) => write!(f, "{}", cause),
+            CreateIntegrationError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateIntegrationError {}
 /// Errors returned by CreateIntegrationResponse
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateIntegrationResponseError {
     /// <p>The request is not valid, for example, the input is incomplete or incorrect. See the accompanying error message for details.</p>
     BadRequest(String),
@@ -3875,6 +3915,8 @@ pub enum CreateIntegrationResponseError {
     NotFound(String),
     /// <p>A limit has been exceeded. See the accompanying error message for details.</p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateIntegrationResponseError {
@@ -3882,23 +3924,33 @@ impl CreateIntegrationResponseError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(CreateIntegrationResponseError::BadRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateIntegrationResponseError::BadRequest(err.msg),
+                        &res,
+                    )
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(CreateIntegrationResponseError::Conflict(err.msg))
+                    return RusotoError::service(
+                        CreateIntegrationResponseError::Conflict(err.msg),
+                        &res,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(CreateIntegrationResponseError::NotFound(err.msg))
+                    return RusotoError::service(
+                        CreateIntegrationResponseError::NotFound(err.msg),
+                        &res,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(CreateIntegrationResponseError::TooManyRequests(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateIntegrationResponseError::TooManyRequests(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(CreateIntegrationResponseError::Unhandled, &res)
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -3912,12 +3964,14 @@ impl fmt::Display for CreateIntegrationResponseError {
             CreateIntegrationResponseError::Conflict(ref cause) => write!(f, "{}", cause),
             CreateIntegrationResponseError::NotFound(ref cause) => write!(f, "{}", cause),
             CreateIntegrationResponseError::TooManyRequests(ref cause) => write!(f, "{}", cause),
+            CreateIntegrationResponseError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateIntegrationResponseError {}
 /// Errors returned by CreateModel
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateModelError {
     /// <p>The request is not valid, for example, the input is incomplete or incorrect. See the accompanying error message for details.</p>
     BadRequest(String),
@@ -3927,6 +3981,8 @@ pub enum CreateModelError {
     NotFound(String),
     /// <p>A limit has been exceeded. See the accompanying error message for details.</p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateModelError {
@@ -3934,19 +3990,19 @@ impl CreateModelError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(CreateModelError::BadRequest(err.msg))
+                    return RusotoError::service(CreateModelError::BadRequest(err.msg), &res)
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(CreateModelError::Conflict(err.msg))
+                    return RusotoError::service(CreateModelError::Conflict(err.msg), &res)
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(CreateModelError::NotFound(err.msg))
+                    return RusotoError::service(CreateModelError::NotFound(err.msg), &res)
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(CreateModelError::TooManyRequests(err.msg))
+                    return RusotoError::service(CreateModelError::TooManyRequests(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreateModelError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3960,12 +4016,14 @@ impl fmt::Display for CreateModelError {
             CreateModelError::Conflict(ref cause) => write!(f, "{}", cause),
             CreateModelError::NotFound(ref cause) => write!(f, "{}", cause),
             CreateModelError::TooManyRequests(ref cause) => write!(f, "{}", cause),
+            CreateModelError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateModelError {}
 /// Errors returned by CreateRoute
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateRouteError {
     /// <p>The request is not valid, for example, the input is incomplete or incorrect. See the accompanying error message for details.</p>
     BadRequest(String),
@@ -3975,6 +4033,8 @@ pub enum CreateRouteError {
     NotFound(String),
     /// <p>A limit has been exceeded. See the accompanying error message for details.</p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateRouteError {
@@ -3982,19 +4042,19 @@ impl CreateRouteError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(CreateRouteError::BadRequest(err.msg))
+                    return RusotoError::service(CreateRouteError::BadRequest(err.msg), &res)
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(CreateRouteError::Conflict(err.msg))
+                    return RusotoError::service(CreateRouteError::Conflict(err.msg), &res)
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(CreateRouteError::NotFound(err.msg))
+                    return RusotoError::service(CreateRouteError::NotFound(err.msg), &res)
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(CreateRouteError::TooManyRequests(err.msg))
+                    return RusotoError::service(CreateRouteError::TooManyRequests(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreateRouteError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -4008,12 +4068,14 @@ impl fmt::Display for CreateRouteError {
             CreateRouteError::Conflict(ref cause) => write!(f, "{}", cause),
             CreateRouteError::NotFound(ref cause) => write!(f, "{}", cause),
             CreateRouteError::TooManyRequests(ref cause) => write!(f, "{}", cause),
+            CreateRouteError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateRouteError {}
 /// Errors returned by CreateRouteResponse
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateRouteResponseError {
     /// <p>The request is not valid, for example, the input is incomplete or incorrect. See the accompanying error message for details.</p>
     BadRequest(String),
@@ -4023,6 +4085,8 @@ pub enum CreateRouteResponseError {
     NotFound(String),
     /// <p>A limit has been exceeded. See the accompanying error message for details.</p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateRouteResponseError {
@@ -4030,19 +4094,25 @@ impl CreateRouteResponseError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(CreateRouteResponseError::BadRequest(err.msg))
+                    return RusotoError::service(
+                        CreateRouteResponseError::BadRequest(err.msg),
+                        &res,
+                    )
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(CreateRouteResponseError::Conflict(err.msg))
+                    return RusotoError::service(CreateRouteResponseError::Conflict(err.msg), &res)
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(CreateRouteResponseError::NotFound(err.msg))
+                    return RusotoError::service(CreateRouteResponseError::NotFound(err.msg), &res)
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(CreateRouteResponseError::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        CreateRouteResponseError::TooManyRequests(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreateRouteResponseError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -4056,12 +4126,14 @@ impl fmt::Display for CreateRouteResponseError {
             CreateRouteResponseError::Conflict(ref cause) => write!(f, "{}", cause),
             CreateRouteResponseError::NotFound(ref cause) => write!(f, "{}", cause),
             CreateRouteResponseError::TooManyRequests(ref cause) => write!(f, "{}", cause),
+            CreateRouteResponseError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateRouteResponseError {}
 /// Errors returned by CreateStage
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateStageError {
     /// <p>The request is not valid, for example, the input is incomplete or incorrect. See the accompanying error message for details.</p>
     BadRequest(String),
@@ -4071,6 +4143,8 @@ pub enum CreateStageError {
     NotFound(String),
     /// <p>A limit has been exceeded. See the accompanying error message for details.</p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateStageError {
@@ -4078,19 +4152,19 @@ impl CreateStageError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(CreateStageError::BadRequest(err.msg))
+                    return RusotoError::service(CreateStageError::BadRequest(err.msg), &res)
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(CreateStageError::Conflict(err.msg))
+                    return RusotoError::service(CreateStageError::Conflict(err.msg), &res)
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(CreateStageError::NotFound(err.msg))
+                    return RusotoError::service(CreateStageError::NotFound(err.msg), &res)
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(CreateStageError::TooManyRequests(err.msg))
+                    return RusotoError::service(CreateStageError::TooManyRequests(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreateStageError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -4104,17 +4178,21 @@ impl fmt::Display for CreateStageError {
             CreateStageError::Conflict(ref cause) => write!(f, "{}", cause),
             CreateStageError::NotFound(ref cause) => write!(f, "{}", cause),
             CreateStageError::TooManyRequests(ref cause) => write!(f, "{}", cause),
+            CreateStageError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateStageError {}
 /// Errors returned by DeleteApi
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteApiError {
     /// <p>The resource specified in the request was not found. See the message field for more information.</p>
     NotFound(String),
     /// <p>A limit has been exceeded. See the accompanying error message for details.</p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteApiError {
@@ -4122,13 +4200,13 @@ impl DeleteApiError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteApiError::NotFound(err.msg))
+                    return RusotoError::service(DeleteApiError::NotFound(err.msg), &res)
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(DeleteApiError::TooManyRequests(err.msg))
+                    return RusotoError::service(DeleteApiError::TooManyRequests(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteApiError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -4140,12 +4218,14 @@ impl fmt::Display for DeleteApiError {
         match *self {
             DeleteApiError::NotFound(ref cause) => write!(f, "{}", cause),
             DeleteApiError::TooManyRequests(ref cause) => write!(f, "{}", cause),
+            DeleteApiError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteApiError {}
 /// Errors returned by DeleteApiMapping
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteApiMappingError {
     /// <p>The request is not valid, for example, the input is incomplete or incorrect. See the accompanying error message for details.</p>
     BadRequest(String),
@@ -4153,6 +4233,8 @@ pub enum DeleteApiMappingError {
     NotFound(String),
     /// <p>A limit has been exceeded. See the accompanying error message for details.</p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteApiMappingError {
@@ -4160,16 +4242,19 @@ impl DeleteApiMappingError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(DeleteApiMappingError::BadRequest(err.msg))
+                    return RusotoError::service(DeleteApiMappingError::BadRequest(err.msg), &res)
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteApiMappingError::NotFound(err.msg))
+                    return RusotoError::service(DeleteApiMappingError::NotFound(err.msg), &res)
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(DeleteApiMappingError::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        DeleteApiMappingError::TooManyRequests(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteApiMappingError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -4182,17 +4267,21 @@ impl fmt::Display for DeleteApiMappingError {
             DeleteApiMappingError::BadRequest(ref cause) => write!(f, "{}", cause),
             DeleteApiMappingError::NotFound(ref cause) => write!(f, "{}", cause),
             DeleteApiMappingError::TooManyRequests(ref cause) => write!(f, "{}", cause),
+            DeleteApiMappingError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteApiMappingError {}
 /// Errors returned by DeleteAuthorizer
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteAuthorizerError {
     /// <p>The resource specified in the request was not found. See the message field for more information.</p>
     NotFound(String),
     /// <p>A limit has been exceeded. See the accompanying error message for details.</p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteAuthorizerError {
@@ -4200,13 +4289,16 @@ impl DeleteAuthorizerError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteAuthorizerError::NotFound(err.msg))
+                    return RusotoError::service(DeleteAuthorizerError::NotFound(err.msg), &res)
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(DeleteAuthorizerError::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        DeleteAuthorizerError::TooManyRequests(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteAuthorizerError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -4218,17 +4310,21 @@ impl fmt::Display for DeleteAuthorizerError {
         match *self {
             DeleteAuthorizerError::NotFound(ref cause) => write!(f, "{}", cause),
             DeleteAuthorizerError::TooManyRequests(ref cause) => write!(f, "{}", cause),
+            DeleteAuthorizerError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteAuthorizerError {}
 /// Errors returned by DeleteCorsConfiguration
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteCorsConfigurationError {
     /// <p>The resource specified in the request was not found. See the message field for more information.</p>
     NotFound(String),
     /// <p>A limit has been exceeded. See the accompanying error message for details.</p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteCorsConfigurationError {
@@ -4236,15 +4332,19 @@ impl DeleteCorsConfigurationError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteCorsConfigurationError::NotFound(err.msg))
+                    return RusotoError::service(
+                        DeleteCorsConfigurationError::NotFound(err.msg),
+                        &res,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(DeleteCorsConfigurationError::TooManyRequests(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteCorsConfigurationError::TooManyRequests(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteCorsConfigurationError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -4256,17 +4356,21 @@ impl fmt::Display for DeleteCorsConfigurationError {
         match *self {
             DeleteCorsConfigurationError::NotFound(ref cause) => write!(f, "{}", cause),
             DeleteCorsConfigurationError::TooManyRequests(ref cause) => write!(f, "{}", cause),
+            DeleteCorsConfigurationError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteCorsConfigurationError {}
 /// Errors returned by DeleteDeployment
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteDeploymentError {
     /// <p>The resource specified in the request was not found. See the message field for more information.</p>
     NotFound(String),
     /// <p>A limit has been exceeded. See the accompanying error message for details.</p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteDeploymentError {
@@ -4274,13 +4378,16 @@ impl DeleteDeploymentError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteDeploymentError::NotFound(err.msg))
+                    return RusotoError::service(DeleteDeploymentError::NotFound(err.msg), &res)
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(DeleteDeploymentError::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        DeleteDeploymentError::TooManyRequests(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteDeploymentError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -4292,17 +4399,21 @@ impl fmt::Display for DeleteDeploymentError {
         match *self {
             DeleteDeploymentError::NotFound(ref cause) => write!(f, "{}", cause),
             DeleteDeploymentError::TooManyRequests(ref cause) => write!(f, "{}", cause),
+            DeleteDeploymentError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteDeploymentError {}
 /// Errors returned by DeleteDomainName
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteDomainNameError {
     /// <p>The resource specified in the request was not found. See the message field for more information.</p>
     NotFound(String),
     /// <p>A limit has been exceeded. See the accompanying error message for details.</p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteDomainNameError {
@@ -4310,13 +4421,16 @@ impl DeleteDomainNameError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteDomainNameError::NotFound(err.msg))
+                    return RusotoError::service(DeleteDomainNameError::NotFound(err.msg), &res)
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(DeleteDomainNameError::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        DeleteDomainNameError::TooManyRequests(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteDomainNameError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -4328,17 +4442,21 @@ impl fmt::Display for DeleteDomainNameError {
         match *self {
             DeleteDomainNameError::NotFound(ref cause) => write!(f, "{}", cause),
             DeleteDomainNameError::TooManyRequests(ref cause) => write!(f, "{}", cause),
+            DeleteDomainNameError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteDomainNameError {}
 /// Errors returned by DeleteIntegration
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteIntegrationError {
     /// <p>The resource specified in the request was not found. See the message field for more information.</p>
     NotFound(String),
     /// <p>A limit has been exceeded. See the accompanying error message for details.</p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteIntegrationError {
@@ -4346,13 +4464,16 @@ impl DeleteIntegrationError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteIntegrationError::NotFound(err.msg))
+                    return RusotoError::service(DeleteIntegrationError::NotFound(err.msg), &res)
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(DeleteIntegrationError::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        DeleteIntegrationError::TooManyRequests(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteIntegrationError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -4364,17 +4485,21 @@ impl fmt::Display for DeleteIntegrationError {
         match *self {
             DeleteIntegrationError::NotFound(ref cause) => write!(f, "{}", cause),
             DeleteIntegrationError::TooManyRequests(ref cause) => write!(f, "{}", cause),
+            DeleteIntegrationError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteIntegrationError {}
 /// Errors returned by DeleteIntegrationResponse
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteIntegrationResponseError {
     /// <p>The resource specified in the request was not found. See the message field for more information.</p>
     NotFound(String),
     /// <p>A limit has been exceeded. See the accompanying error message for details.</p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteIntegrationResponseError {
@@ -4382,15 +4507,21 @@ impl DeleteIntegrationResponseError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteIntegrationResponseError::NotFound(err.msg))
+                    return RusotoError::service(
+                        DeleteIntegrationResponseError::NotFound(err.msg),
+                        &res,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(DeleteIntegrationResponseError::TooManyRequests(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteIntegrationResponseError::TooManyRequests(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(DeleteIntegrationResponseError::Unhandled, &res)
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -4402,17 +4533,21 @@ impl fmt::Display for DeleteIntegrationResponseError {
         match *self {
             DeleteIntegrationResponseError::NotFound(ref cause) => write!(f, "{}", cause),
             DeleteIntegrationResponseError::TooManyRequests(ref cause) => write!(f, "{}", cause),
+            DeleteIntegrationResponseError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteIntegrationResponseError {}
 /// Errors returned by DeleteModel
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteModelError {
     /// <p>The resource specified in the request was not found. See the message field for more information.</p>
     NotFound(String),
     /// <p>A limit has been exceeded. See the accompanying error message for details.</p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteModelError {
@@ -4420,13 +4555,13 @@ impl DeleteModelError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteModelError::NotFound(err.msg))
+                    return RusotoError::service(DeleteModelError::NotFound(err.msg), &res)
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(DeleteModelError::TooManyRequests(err.msg))
+                    return RusotoError::service(DeleteModelError::TooManyRequests(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteModelError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -4438,17 +4573,21 @@ impl fmt::Display for DeleteModelError {
         match *self {
             DeleteModelError::NotFound(ref cause) => write!(f, "{}", cause),
             DeleteModelError::TooManyRequests(ref cause) => write!(f, "{}", cause),
+            DeleteModelError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteModelError {}
 /// Errors returned by DeleteRoute
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteRouteError {
     /// <p>The resource specified in the request was not found. See the message field for more information.</p>
     NotFound(String),
     /// <p>A limit has been exceeded. See the accompanying error message for details.</p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteRouteError {
@@ -4456,13 +4595,13 @@ impl DeleteRouteError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteRouteError::NotFound(err.msg))
+                    return RusotoError::service(DeleteRouteError::NotFound(err.msg), &res)
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(DeleteRouteError::TooManyRequests(err.msg))
+                    return RusotoError::service(DeleteRouteError::TooManyRequests(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteRouteError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -4474,17 +4613,21 @@ impl fmt::Display for DeleteRouteError {
         match *self {
             DeleteRouteError::NotFound(ref cause) => write!(f, "{}", cause),
             DeleteRouteError::TooManyRequests(ref cause) => write!(f, "{}", cause),
+            DeleteRouteError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteRouteError {}
 /// Errors returned by DeleteRouteResponse
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteRouteResponseError {
     /// <p>The resource specified in the request was not found. See the message field for more information.</p>
     NotFound(String),
     /// <p>A limit has been exceeded. See the accompanying error message for details.</p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteRouteResponseError {
@@ -4492,13 +4635,16 @@ impl DeleteRouteResponseError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteRouteResponseError::NotFound(err.msg))
+                    return RusotoError::service(DeleteRouteResponseError::NotFound(err.msg), &res)
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(DeleteRouteResponseError::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        DeleteRouteResponseError::TooManyRequests(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteRouteResponseError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -4510,17 +4656,21 @@ impl fmt::Display for DeleteRouteResponseError {
         match *self {
             DeleteRouteResponseError::NotFound(ref cause) => write!(f, "{}", cause),
             DeleteRouteResponseError::TooManyRequests(ref cause) => write!(f, "{}", cause),
+            DeleteRouteResponseError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteRouteResponseError {}
 /// Errors returned by DeleteRouteSettings
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteRouteSettingsError {
     /// <p>The resource specified in the request was not found. See the message field for more information.</p>
     NotFound(String),
     /// <p>A limit has been exceeded. See the accompanying error message for details.</p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteRouteSettingsError {
@@ -4528,13 +4678,16 @@ impl DeleteRouteSettingsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteRouteSettingsError::NotFound(err.msg))
+                    return RusotoError::service(DeleteRouteSettingsError::NotFound(err.msg), &res)
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(DeleteRouteSettingsError::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        DeleteRouteSettingsError::TooManyRequests(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteRouteSettingsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -4546,17 +4699,21 @@ impl fmt::Display for DeleteRouteSettingsError {
         match *self {
             DeleteRouteSettingsError::NotFound(ref cause) => write!(f, "{}", cause),
             DeleteRouteSettingsError::TooManyRequests(ref cause) => write!(f, "{}", cause),
+            DeleteRouteSettingsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteRouteSettingsError {}
 /// Errors returned by DeleteStage
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteStageError {
     /// <p>The resource specified in the request was not found. See the message field for more information.</p>
     NotFound(String),
     /// <p>A limit has been exceeded. See the accompanying error message for details.</p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteStageError {
@@ -4564,13 +4721,13 @@ impl DeleteStageError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteStageError::NotFound(err.msg))
+                    return RusotoError::service(DeleteStageError::NotFound(err.msg), &res)
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(DeleteStageError::TooManyRequests(err.msg))
+                    return RusotoError::service(DeleteStageError::TooManyRequests(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteStageError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -4582,29 +4739,35 @@ impl fmt::Display for DeleteStageError {
         match *self {
             DeleteStageError::NotFound(ref cause) => write!(f, "{}", cause),
             DeleteStageError::TooManyRequests(ref cause) => write!(f, "{}", cause),
+            DeleteStageError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteStageError {}
 /// Errors returned by GetApi
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetApiError {
     /// <p>The resource specified in the request was not found. See the message field for more information.</p>
     NotFound(String),
     /// <p>A limit has been exceeded. See the accompanying error message for details.</p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetApiError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<GetApiError> {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
-                "NotFoundException" => return RusotoError::Service(GetApiError::NotFound(err.msg)),
+                "NotFoundException" => {
+                    return RusotoError::service(GetApiError::NotFound(err.msg), &res)
+                }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(GetApiError::TooManyRequests(err.msg))
+                    return RusotoError::service(GetApiError::TooManyRequests(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetApiError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -4616,12 +4779,14 @@ impl fmt::Display for GetApiError {
         match *self {
             GetApiError::NotFound(ref cause) => write!(f, "{}", cause),
             GetApiError::TooManyRequests(ref cause) => write!(f, "{}", cause),
+            GetApiError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetApiError {}
 /// Errors returned by GetApiMapping
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetApiMappingError {
     /// <p>The request is not valid, for example, the input is incomplete or incorrect. See the accompanying error message for details.</p>
     BadRequest(String),
@@ -4629,6 +4794,8 @@ pub enum GetApiMappingError {
     NotFound(String),
     /// <p>A limit has been exceeded. See the accompanying error message for details.</p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetApiMappingError {
@@ -4636,16 +4803,16 @@ impl GetApiMappingError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetApiMappingError::BadRequest(err.msg))
+                    return RusotoError::service(GetApiMappingError::BadRequest(err.msg), &res)
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetApiMappingError::NotFound(err.msg))
+                    return RusotoError::service(GetApiMappingError::NotFound(err.msg), &res)
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(GetApiMappingError::TooManyRequests(err.msg))
+                    return RusotoError::service(GetApiMappingError::TooManyRequests(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetApiMappingError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -4658,12 +4825,14 @@ impl fmt::Display for GetApiMappingError {
             GetApiMappingError::BadRequest(ref cause) => write!(f, "{}", cause),
             GetApiMappingError::NotFound(ref cause) => write!(f, "{}", cause),
             GetApiMappingError::TooManyRequests(ref cause) => write!(f, "{}", cause),
+            GetApiMappingError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetApiMappingError {}
 /// Errors returned by GetApiMappings
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetApiMappingsError {
     /// <p>The request is not valid, for example, the input is incomplete or incorrect. See the accompanying error message for details.</p>
     BadRequest(String),
@@ -4671,6 +4840,8 @@ pub enum GetApiMappingsError {
     NotFound(String),
     /// <p>A limit has been exceeded. See the accompanying error message for details.</p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetApiMappingsError {
@@ -4678,16 +4849,19 @@ impl GetApiMappingsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetApiMappingsError::BadRequest(err.msg))
+                    return RusotoError::service(GetApiMappingsError::BadRequest(err.msg), &res)
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetApiMappingsError::NotFound(err.msg))
+                    return RusotoError::service(GetApiMappingsError::NotFound(err.msg), &res)
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(GetApiMappingsError::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        GetApiMappingsError::TooManyRequests(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetApiMappingsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -4700,12 +4874,14 @@ impl fmt::Display for GetApiMappingsError {
             GetApiMappingsError::BadRequest(ref cause) => write!(f, "{}", cause),
             GetApiMappingsError::NotFound(ref cause) => write!(f, "{}", cause),
             GetApiMappingsError::TooManyRequests(ref cause) => write!(f, "{}", cause),
+            GetApiMappingsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetApiMappingsError {}
 /// Errors returned by GetApis
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetApisError {
     /// <p>The request is not valid, for example, the input is incomplete or incorrect. See the accompanying error message for details.</p>
     BadRequest(String),
@@ -4713,6 +4889,8 @@ pub enum GetApisError {
     NotFound(String),
     /// <p>A limit has been exceeded. See the accompanying error message for details.</p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetApisError {
@@ -4720,16 +4898,16 @@ impl GetApisError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetApisError::BadRequest(err.msg))
+                    return RusotoError::service(GetApisError::BadRequest(err.msg), &res)
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetApisError::NotFound(err.msg))
+                    return RusotoError::service(GetApisError::NotFound(err.msg), &res)
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(GetApisError::TooManyRequests(err.msg))
+                    return RusotoError::service(GetApisError::TooManyRequests(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetApisError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -4742,17 +4920,21 @@ impl fmt::Display for GetApisError {
             GetApisError::BadRequest(ref cause) => write!(f, "{}", cause),
             GetApisError::NotFound(ref cause) => write!(f, "{}", cause),
             GetApisError::TooManyRequests(ref cause) => write!(f, "{}", cause),
+            GetApisError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetApisError {}
 /// Errors returned by GetAuthorizer
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetAuthorizerError {
     /// <p>The resource specified in the request was not found. See the message field for more information.</p>
     NotFound(String),
     /// <p>A limit has been exceeded. See the accompanying error message for details.</p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetAuthorizerError {
@@ -4760,13 +4942,13 @@ impl GetAuthorizerError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "NotFoundException" => {
-                    return RusotoError::Service(GetAuthorizerError::NotFound(err.msg))
+                    return RusotoError::service(GetAuthorizerError::NotFound(err.msg), &res)
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(GetAuthorizerError::TooManyRequests(err.msg))
+                    return RusotoError::service(GetAuthorizerError::TooManyRequests(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetAuthorizerError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -4778,12 +4960,14 @@ impl fmt::Display for GetAuthorizerError {
         match *self {
             GetAuthorizerError::NotFound(ref cause) => write!(f, "{}", cause),
             GetAuthorizerError::TooManyRequests(ref cause) => write!(f, "{}", cause),
+            GetAuthorizerError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetAuthorizerError {}
 /// Errors returned by GetAuthorizers
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetAuthorizersError {
     /// <p>The request is not valid, for example, the input is incomplete or incorrect. See the accompanying error message for details.</p>
     BadRequest(String),
@@ -4791,6 +4975,8 @@ pub enum GetAuthorizersError {
     NotFound(String),
     /// <p>A limit has been exceeded. See the accompanying error message for details.</p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetAuthorizersError {
@@ -4798,16 +4984,19 @@ impl GetAuthorizersError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetAuthorizersError::BadRequest(err.msg))
+                    return RusotoError::service(GetAuthorizersError::BadRequest(err.msg), &res)
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetAuthorizersError::NotFound(err.msg))
+                    return RusotoError::service(GetAuthorizersError::NotFound(err.msg), &res)
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(GetAuthorizersError::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        GetAuthorizersError::TooManyRequests(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetAuthorizersError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -4820,17 +5009,21 @@ impl fmt::Display for GetAuthorizersError {
             GetAuthorizersError::BadRequest(ref cause) => write!(f, "{}", cause),
             GetAuthorizersError::NotFound(ref cause) => write!(f, "{}", cause),
             GetAuthorizersError::TooManyRequests(ref cause) => write!(f, "{}", cause),
+            GetAuthorizersError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetAuthorizersError {}
 /// Errors returned by GetDeployment
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetDeploymentError {
     /// <p>The resource specified in the request was not found. See the message field for more information.</p>
     NotFound(String),
     /// <p>A limit has been exceeded. See the accompanying error message for details.</p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetDeploymentError {
@@ -4838,13 +5031,13 @@ impl GetDeploymentError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "NotFoundException" => {
-                    return RusotoError::Service(GetDeploymentError::NotFound(err.msg))
+                    return RusotoError::service(GetDeploymentError::NotFound(err.msg), &res)
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(GetDeploymentError::TooManyRequests(err.msg))
+                    return RusotoError::service(GetDeploymentError::TooManyRequests(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetDeploymentError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -4856,12 +5049,14 @@ impl fmt::Display for GetDeploymentError {
         match *self {
             GetDeploymentError::NotFound(ref cause) => write!(f, "{}", cause),
             GetDeploymentError::TooManyRequests(ref cause) => write!(f, "{}", cause),
+            GetDeploymentError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetDeploymentError {}
 /// Errors returned by GetDeployments
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetDeploymentsError {
     /// <p>The request is not valid, for example, the input is incomplete or incorrect. See the accompanying error message for details.</p>
     BadRequest(String),
@@ -4869,6 +5064,8 @@ pub enum GetDeploymentsError {
     NotFound(String),
     /// <p>A limit has been exceeded. See the accompanying error message for details.</p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetDeploymentsError {
@@ -4876,16 +5073,19 @@ impl GetDeploymentsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetDeploymentsError::BadRequest(err.msg))
+                    return RusotoError::service(GetDeploymentsError::BadRequest(err.msg), &res)
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetDeploymentsError::NotFound(err.msg))
+                    return RusotoError::service(GetDeploymentsError::NotFound(err.msg), &res)
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(GetDeploymentsError::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        GetDeploymentsError::TooManyRequests(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetDeploymentsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -4898,17 +5098,21 @@ impl fmt::Display for GetDeploymentsError {
             GetDeploymentsError::BadRequest(ref cause) => write!(f, "{}", cause),
             GetDeploymentsError::NotFound(ref cause) => write!(f, "{}", cause),
             GetDeploymentsError::TooManyRequests(ref cause) => write!(f, "{}", cause),
+            GetDeploymentsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetDeploymentsError {}
 /// Errors returned by GetDomainName
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetDomainNameError {
     /// <p>The resource specified in the request was not found. See the message field for more information.</p>
     NotFound(String),
     /// <p>A limit has been exceeded. See the accompanying error message for details.</p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetDomainNameError {
@@ -4916,13 +5120,13 @@ impl GetDomainNameError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "NotFoundException" => {
-                    return RusotoError::Service(GetDomainNameError::NotFound(err.msg))
+                    return RusotoError::service(GetDomainNameError::NotFound(err.msg), &res)
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(GetDomainNameError::TooManyRequests(err.msg))
+                    return RusotoError::service(GetDomainNameError::TooManyRequests(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetDomainNameError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -4934,12 +5138,14 @@ impl fmt::Display for GetDomainNameError {
         match *self {
             GetDomainNameError::NotFound(ref cause) => write!(f, "{}", cause),
             GetDomainNameError::TooManyRequests(ref cause) => write!(f, "{}", cause),
+            GetDomainNameError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetDomainNameError {}
 /// Errors returned by GetDomainNames
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetDomainNamesError {
     /// <p>The request is not valid, for example, the input is incomplete or incorrect. See the accompanying error message for details.</p>
     BadRequest(String),
@@ -4947,6 +5153,8 @@ pub enum GetDomainNamesError {
     NotFound(String),
     /// <p>A limit has been exceeded. See the accompanying error message for details.</p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetDomainNamesError {
@@ -4954,16 +5162,19 @@ impl GetDomainNamesError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetDomainNamesError::BadRequest(err.msg))
+                    return RusotoError::service(GetDomainNamesError::BadRequest(err.msg), &res)
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetDomainNamesError::NotFound(err.msg))
+                    return RusotoError::service(GetDomainNamesError::NotFound(err.msg), &res)
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(GetDomainNamesError::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        GetDomainNamesError::TooManyRequests(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetDomainNamesError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -4976,17 +5187,21 @@ impl fmt::Display for GetDomainNamesError {
             GetDomainNamesError::BadRequest(ref cause) => write!(f, "{}", cause),
             GetDomainNamesError::NotFound(ref cause) => write!(f, "{}", cause),
             GetDomainNamesError::TooManyRequests(ref cause) => write!(f, "{}", cause),
+            GetDomainNamesError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetDomainNamesError {}
 /// Errors returned by GetIntegration
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetIntegrationError {
     /// <p>The resource specified in the request was not found. See the message field for more information.</p>
     NotFound(String),
     /// <p>A limit has been exceeded. See the accompanying error message for details.</p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetIntegrationError {
@@ -4994,13 +5209,16 @@ impl GetIntegrationError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "NotFoundException" => {
-                    return RusotoError::Service(GetIntegrationError::NotFound(err.msg))
+                    return RusotoError::service(GetIntegrationError::NotFound(err.msg), &res)
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(GetIntegrationError::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        GetIntegrationError::TooManyRequests(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetIntegrationError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -5012,17 +5230,21 @@ impl fmt::Display for GetIntegrationError {
         match *self {
             GetIntegrationError::NotFound(ref cause) => write!(f, "{}", cause),
             GetIntegrationError::TooManyRequests(ref cause) => write!(f, "{}", cause),
+            GetIntegrationError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetIntegrationError {}
 /// Errors returned by GetIntegrationResponse
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetIntegrationResponseError {
     /// <p>The resource specified in the request was not found. See the message field for more information.</p>
     NotFound(String),
     /// <p>A limit has been exceeded. See the accompanying error message for details.</p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetIntegrationResponseError {
@@ -5030,15 +5252,19 @@ impl GetIntegrationResponseError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "NotFoundException" => {
-                    return RusotoError::Service(GetIntegrationResponseError::NotFound(err.msg))
+                    return RusotoError::service(
+                        GetIntegrationResponseError::NotFound(err.msg),
+                        &res,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(GetIntegrationResponseError::TooManyRequests(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetIntegrationResponseError::TooManyRequests(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetIntegrationResponseError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -5050,12 +5276,14 @@ impl fmt::Display for GetIntegrationResponseError {
         match *self {
             GetIntegrationResponseError::NotFound(ref cause) => write!(f, "{}", cause),
             GetIntegrationResponseError::TooManyRequests(ref cause) => write!(f, "{}", cause),
+            GetIntegrationResponseError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetIntegrationResponseError {}
 /// Errors returned by GetIntegrationResponses
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetIntegrationResponsesError {
     /// <p>The request is not valid, for example, the input is incomplete or incorrect. See the accompanying error message for details.</p>
     BadRequest(String),
@@ -5063,6 +5291,8 @@ pub enum GetIntegrationResponsesError {
     NotFound(String),
     /// <p>A limit has been exceeded. See the accompanying error message for details.</p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetIntegrationResponsesError {
@@ -5070,18 +5300,25 @@ impl GetIntegrationResponsesError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetIntegrationResponsesError::BadRequest(err.msg))
+                    return RusotoError::service(
+                        GetIntegrationResponsesError::BadRequest(err.msg),
+                        &res,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetIntegrationResponsesError::NotFound(err.msg))
+                    return RusotoError::service(
+                        GetIntegrationResponsesError::NotFound(err.msg),
+                        &res,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(GetIntegrationResponsesError::TooManyRequests(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetIntegrationResponsesError::TooManyRequests(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetIntegrationResponsesError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -5094,12 +5331,14 @@ impl fmt::Display for GetIntegrationResponsesError {
             GetIntegrationResponsesError::BadRequest(ref cause) => write!(f, "{}", cause),
             GetIntegrationResponsesError::NotFound(ref cause) => write!(f, "{}", cause),
             GetIntegrationResponsesError::TooManyRequests(ref cause) => write!(f, "{}", cause),
+            GetIntegrationResponsesError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetIntegrationResponsesError {}
 /// Errors returned by GetIntegrations
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetIntegrationsError {
     /// <p>The request is not valid, for example, the input is incomplete or incorrect. See the accompanying error message for details.</p>
     BadRequest(String),
@@ -5107,6 +5346,8 @@ pub enum GetIntegrationsError {
     NotFound(String),
     /// <p>A limit has been exceeded. See the accompanying error message for details.</p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetIntegrationsError {
@@ -5114,16 +5355,19 @@ impl GetIntegrationsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetIntegrationsError::BadRequest(err.msg))
+                    return RusotoError::service(GetIntegrationsError::BadRequest(err.msg), &res)
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetIntegrationsError::NotFound(err.msg))
+                    return RusotoError::service(GetIntegrationsError::NotFound(err.msg), &res)
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(GetIntegrationsError::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        GetIntegrationsError::TooManyRequests(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetIntegrationsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -5136,17 +5380,21 @@ impl fmt::Display for GetIntegrationsError {
             GetIntegrationsError::BadRequest(ref cause) => write!(f, "{}", cause),
             GetIntegrationsError::NotFound(ref cause) => write!(f, "{}", cause),
             GetIntegrationsError::TooManyRequests(ref cause) => write!(f, "{}", cause),
+            GetIntegrationsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetIntegrationsError {}
 /// Errors returned by GetModel
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetModelError {
     /// <p>The resource specified in the request was not found. See the message field for more information.</p>
     NotFound(String),
     /// <p>A limit has been exceeded. See the accompanying error message for details.</p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetModelError {
@@ -5154,13 +5402,13 @@ impl GetModelError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "NotFoundException" => {
-                    return RusotoError::Service(GetModelError::NotFound(err.msg))
+                    return RusotoError::service(GetModelError::NotFound(err.msg), &res)
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(GetModelError::TooManyRequests(err.msg))
+                    return RusotoError::service(GetModelError::TooManyRequests(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetModelError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -5172,17 +5420,21 @@ impl fmt::Display for GetModelError {
         match *self {
             GetModelError::NotFound(ref cause) => write!(f, "{}", cause),
             GetModelError::TooManyRequests(ref cause) => write!(f, "{}", cause),
+            GetModelError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetModelError {}
 /// Errors returned by GetModelTemplate
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetModelTemplateError {
     /// <p>The resource specified in the request was not found. See the message field for more information.</p>
     NotFound(String),
     /// <p>A limit has been exceeded. See the accompanying error message for details.</p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetModelTemplateError {
@@ -5190,13 +5442,16 @@ impl GetModelTemplateError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "NotFoundException" => {
-                    return RusotoError::Service(GetModelTemplateError::NotFound(err.msg))
+                    return RusotoError::service(GetModelTemplateError::NotFound(err.msg), &res)
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(GetModelTemplateError::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        GetModelTemplateError::TooManyRequests(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetModelTemplateError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -5208,12 +5463,14 @@ impl fmt::Display for GetModelTemplateError {
         match *self {
             GetModelTemplateError::NotFound(ref cause) => write!(f, "{}", cause),
             GetModelTemplateError::TooManyRequests(ref cause) => write!(f, "{}", cause),
+            GetModelTemplateError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetModelTemplateError {}
 /// Errors returned by GetModels
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetModelsError {
     /// <p>The request is not valid, for example, the input is incomplete or incorrect. See the accompanying error message for details.</p>
     BadRequest(String),
@@ -5221,6 +5478,8 @@ pub enum GetModelsError {
     NotFound(String),
     /// <p>A limit has been exceeded. See the accompanying error message for details.</p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetModelsError {
@@ -5228,16 +5487,16 @@ impl GetModelsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetModelsError::BadRequest(err.msg))
+                    return RusotoError::service(GetModelsError::BadRequest(err.msg), &res)
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetModelsError::NotFound(err.msg))
+                    return RusotoError::service(GetModelsError::NotFound(err.msg), &res)
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(GetModelsError::TooManyRequests(err.msg))
+                    return RusotoError::service(GetModelsError::TooManyRequests(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetModelsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -5250,17 +5509,21 @@ impl fmt::Display for GetModelsError {
             GetModelsError::BadRequest(ref cause) => write!(f, "{}", cause),
             GetModelsError::NotFound(ref cause) => write!(f, "{}", cause),
             GetModelsError::TooManyRequests(ref cause) => write!(f, "{}", cause),
+            GetModelsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetModelsError {}
 /// Errors returned by GetRoute
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetRouteError {
     /// <p>The resource specified in the request was not found. See the message field for more information.</p>
     NotFound(String),
     /// <p>A limit has been exceeded. See the accompanying error message for details.</p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetRouteError {
@@ -5268,13 +5531,13 @@ impl GetRouteError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "NotFoundException" => {
-                    return RusotoError::Service(GetRouteError::NotFound(err.msg))
+                    return RusotoError::service(GetRouteError::NotFound(err.msg), &res)
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(GetRouteError::TooManyRequests(err.msg))
+                    return RusotoError::service(GetRouteError::TooManyRequests(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetRouteError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -5286,17 +5549,21 @@ impl fmt::Display for GetRouteError {
         match *self {
             GetRouteError::NotFound(ref cause) => write!(f, "{}", cause),
             GetRouteError::TooManyRequests(ref cause) => write!(f, "{}", cause),
+            GetRouteError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetRouteError {}
 /// Errors returned by GetRouteResponse
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetRouteResponseError {
     /// <p>The resource specified in the request was not found. See the message field for more information.</p>
     NotFound(String),
     /// <p>A limit has been exceeded. See the accompanying error message for details.</p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetRouteResponseError {
@@ -5304,13 +5571,16 @@ impl GetRouteResponseError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "NotFoundException" => {
-                    return RusotoError::Service(GetRouteResponseError::NotFound(err.msg))
+                    return RusotoError::service(GetRouteResponseError::NotFound(err.msg), &res)
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(GetRouteResponseError::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        GetRouteResponseError::TooManyRequests(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetRouteResponseError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -5322,12 +5592,14 @@ impl fmt::Display for GetRouteResponseError {
         match *self {
             GetRouteResponseError::NotFound(ref cause) => write!(f, "{}", cause),
             GetRouteResponseError::TooManyRequests(ref cause) => write!(f, "{}", cause),
+            GetRouteResponseError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetRouteResponseError {}
 /// Errors returned by GetRouteResponses
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetRouteResponsesError {
     /// <p>The request is not valid, for example, the input is incomplete or incorrect. See the accompanying error message for details.</p>
     BadRequest(String),
@@ -5335,6 +5607,8 @@ pub enum GetRouteResponsesError {
     NotFound(String),
     /// <p>A limit has been exceeded. See the accompanying error message for details.</p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetRouteResponsesError {
@@ -5342,16 +5616,19 @@ impl GetRouteResponsesError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetRouteResponsesError::BadRequest(err.msg))
+                    return RusotoError::service(GetRouteResponsesError::BadRequest(err.msg), &res)
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetRouteResponsesError::NotFound(err.msg))
+                    return RusotoError::service(GetRouteResponsesError::NotFound(err.msg), &res)
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(GetRouteResponsesError::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        GetRouteResponsesError::TooManyRequests(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetRouteResponsesError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -5364,12 +5641,14 @@ impl fmt::Display for GetRouteResponsesError {
             GetRouteResponsesError::BadRequest(ref cause) => write!(f, "{}", cause),
             GetRouteResponsesError::NotFound(ref cause) => write!(f, "{}", cause),
             GetRouteResponsesError::TooManyRequests(ref cause) => write!(f, "{}", cause),
+            GetRouteResponsesError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetRouteResponsesError {}
 /// Errors returned by GetRoutes
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetRoutesError {
     /// <p>The request is not valid, for example, the input is incomplete or incorrect. See the accompanying error message for details.</p>
     BadRequest(String),
@@ -5377,6 +5656,8 @@ pub enum GetRoutesError {
     NotFound(String),
     /// <p>A limit has been exceeded. See the accompanying error message for details.</p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetRoutesError {
@@ -5384,16 +5665,16 @@ impl GetRoutesError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetRoutesError::BadRequest(err.msg))
+                    return RusotoError::service(GetRoutesError::BadRequest(err.msg), &res)
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetRoutesError::NotFound(err.msg))
+                    return RusotoError::service(GetRoutesError::NotFound(err.msg), &res)
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(GetRoutesError::TooManyRequests(err.msg))
+                    return RusotoError::service(GetRoutesError::TooManyRequests(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetRoutesError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -5406,17 +5687,21 @@ impl fmt::Display for GetRoutesError {
             GetRoutesError::BadRequest(ref cause) => write!(f, "{}", cause),
             GetRoutesError::NotFound(ref cause) => write!(f, "{}", cause),
             GetRoutesError::TooManyRequests(ref cause) => write!(f, "{}", cause),
+            GetRoutesError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetRoutesError {}
 /// Errors returned by GetStage
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetStageError {
     /// <p>The resource specified in the request was not found. See the message field for more information.</p>
     NotFound(String),
     /// <p>A limit has been exceeded. See the accompanying error message for details.</p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetStageError {
@@ -5424,13 +5709,13 @@ impl GetStageError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "NotFoundException" => {
-                    return RusotoError::Service(GetStageError::NotFound(err.msg))
+                    return RusotoError::service(GetStageError::NotFound(err.msg), &res)
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(GetStageError::TooManyRequests(err.msg))
+                    return RusotoError::service(GetStageError::TooManyRequests(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetStageError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -5442,12 +5727,14 @@ impl fmt::Display for GetStageError {
         match *self {
             GetStageError::NotFound(ref cause) => write!(f, "{}", cause),
             GetStageError::TooManyRequests(ref cause) => write!(f, "{}", cause),
+            GetStageError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetStageError {}
 /// Errors returned by GetStages
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetStagesError {
     /// <p>The request is not valid, for example, the input is incomplete or incorrect. See the accompanying error message for details.</p>
     BadRequest(String),
@@ -5455,6 +5742,8 @@ pub enum GetStagesError {
     NotFound(String),
     /// <p>A limit has been exceeded. See the accompanying error message for details.</p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetStagesError {
@@ -5462,16 +5751,16 @@ impl GetStagesError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetStagesError::BadRequest(err.msg))
+                    return RusotoError::service(GetStagesError::BadRequest(err.msg), &res)
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetStagesError::NotFound(err.msg))
+                    return RusotoError::service(GetStagesError::NotFound(err.msg), &res)
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(GetStagesError::TooManyRequests(err.msg))
+                    return RusotoError::service(GetStagesError::TooManyRequests(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetStagesError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -5484,12 +5773,14 @@ impl fmt::Display for GetStagesError {
             GetStagesError::BadRequest(ref cause) => write!(f, "{}", cause),
             GetStagesError::NotFound(ref cause) => write!(f, "{}", cause),
             GetStagesError::TooManyRequests(ref cause) => write!(f, "{}", cause),
+            GetStagesError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetStagesError {}
 /// Errors returned by GetTags
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetTagsError {
     /// <p>The request is not valid, for example, the input is incomplete or incorrect. See the accompanying error message for details.</p>
     BadRequest(String),
@@ -5499,6 +5790,8 @@ pub enum GetTagsError {
     NotFound(String),
     /// <p>A limit has been exceeded. See the accompanying error message for details.</p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetTagsError {
@@ -5506,19 +5799,19 @@ impl GetTagsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetTagsError::BadRequest(err.msg))
+                    return RusotoError::service(GetTagsError::BadRequest(err.msg), &res)
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(GetTagsError::Conflict(err.msg))
+                    return RusotoError::service(GetTagsError::Conflict(err.msg), &res)
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetTagsError::NotFound(err.msg))
+                    return RusotoError::service(GetTagsError::NotFound(err.msg), &res)
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(GetTagsError::TooManyRequests(err.msg))
+                    return RusotoError::service(GetTagsError::TooManyRequests(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetTagsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -5532,12 +5825,14 @@ impl fmt::Display for GetTagsError {
             GetTagsError::Conflict(ref cause) => write!(f, "{}", cause),
             GetTagsError::NotFound(ref cause) => write!(f, "{}", cause),
             GetTagsError::TooManyRequests(ref cause) => write!(f, "{}", cause),
+            GetTagsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetTagsError {}
 /// Errors returned by ImportApi
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ImportApiError {
     /// <p>The request is not valid, for example, the input is incomplete or incorrect. See the accompanying error message for details.</p>
     BadRequest(String),
@@ -5547,6 +5842,8 @@ pub enum ImportApiError {
     NotFound(String),
     /// <p>A limit has been exceeded. See the accompanying error message for details.</p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ImportApiError {
@@ -5554,19 +5851,19 @@ impl ImportApiError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(ImportApiError::BadRequest(err.msg))
+                    return RusotoError::service(ImportApiError::BadRequest(err.msg), &res)
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(ImportApiError::Conflict(err.msg))
+                    return RusotoError::service(ImportApiError::Conflict(err.msg), &res)
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(ImportApiError::NotFound(err.msg))
+                    return RusotoError::service(ImportApiError::NotFound(err.msg), &res)
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(ImportApiError::TooManyRequests(err.msg))
+                    return RusotoError::service(ImportApiError::TooManyRequests(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ImportApiError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -5580,12 +5877,14 @@ impl fmt::Display for ImportApiError {
             ImportApiError::Conflict(ref cause) => write!(f, "{}", cause),
             ImportApiError::NotFound(ref cause) => write!(f, "{}", cause),
             ImportApiError::TooManyRequests(ref cause) => write!(f, "{}", cause),
+            ImportApiError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ImportApiError {}
 /// Errors returned by ReimportApi
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ReimportApiError {
     /// <p>The request is not valid, for example, the input is incomplete or incorrect. See the accompanying error message for details.</p>
     BadRequest(String),
@@ -5595,6 +5894,8 @@ pub enum ReimportApiError {
     NotFound(String),
     /// <p>A limit has been exceeded. See the accompanying error message for details.</p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ReimportApiError {
@@ -5602,19 +5903,19 @@ impl ReimportApiError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(ReimportApiError::BadRequest(err.msg))
+                    return RusotoError::service(ReimportApiError::BadRequest(err.msg), &res)
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(ReimportApiError::Conflict(err.msg))
+                    return RusotoError::service(ReimportApiError::Conflict(err.msg), &res)
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(ReimportApiError::NotFound(err.msg))
+                    return RusotoError::service(ReimportApiError::NotFound(err.msg), &res)
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(ReimportApiError::TooManyRequests(err.msg))
+                    return RusotoError::service(ReimportApiError::TooManyRequests(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ReimportApiError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -5628,12 +5929,14 @@ impl fmt::Display for ReimportApiError {
             ReimportApiError::Conflict(ref cause) => write!(f, "{}", cause),
             ReimportApiError::NotFound(ref cause) => write!(f, "{}", cause),
             ReimportApiError::TooManyRequests(ref cause) => write!(f, "{}", cause),
+            ReimportApiError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ReimportApiError {}
 /// Errors returned by TagResource
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum TagResourceError {
     /// <p>The request is not valid, for example, the input is incomplete or incorrect. See the accompanying error message for details.</p>
     BadRequest(String),
@@ -5643,6 +5946,8 @@ pub enum TagResourceError {
     NotFound(String),
     /// <p>A limit has been exceeded. See the accompanying error message for details.</p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl TagResourceError {
@@ -5650,19 +5955,19 @@ impl TagResourceError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(TagResourceError::BadRequest(err.msg))
+                    return RusotoError::service(TagResourceError::BadRequest(err.msg), &res)
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(TagResourceError::Conflict(err.msg))
+                    return RusotoError::service(TagResourceError::Conflict(err.msg), &res)
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(TagResourceError::NotFound(err.msg))
+                    return RusotoError::service(TagResourceError::NotFound(err.msg), &res)
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(TagResourceError::TooManyRequests(err.msg))
+                    return RusotoError::service(TagResourceError::TooManyRequests(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(TagResourceError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -5676,12 +5981,14 @@ impl fmt::Display for TagResourceError {
             TagResourceError::Conflict(ref cause) => write!(f, "{}", cause),
             TagResourceError::NotFound(ref cause) => write!(f, "{}", cause),
             TagResourceError::TooManyRequests(ref cause) => write!(f, "{}", cause),
+            TagResourceError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for TagResourceError {}
 /// Errors returned by UntagResource
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UntagResourceError {
     /// <p>The request is not valid, for example, the input is incomplete or incorrect. See the accompanying error message for details.</p>
     BadRequest(String),
@@ -5691,6 +5998,8 @@ pub enum UntagResourceError {
     NotFound(String),
     /// <p>A limit has been exceeded. See the accompanying error message for details.</p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UntagResourceError {
@@ -5698,19 +6007,19 @@ impl UntagResourceError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(UntagResourceError::BadRequest(err.msg))
+                    return RusotoError::service(UntagResourceError::BadRequest(err.msg), &res)
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(UntagResourceError::Conflict(err.msg))
+                    return RusotoError::service(UntagResourceError::Conflict(err.msg), &res)
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(UntagResourceError::NotFound(err.msg))
+                    return RusotoError::service(UntagResourceError::NotFound(err.msg), &res)
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(UntagResourceError::TooManyRequests(err.msg))
+                    return RusotoError::service(UntagResourceError::TooManyRequests(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UntagResourceError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -5724,12 +6033,14 @@ impl fmt::Display for UntagResourceError {
             UntagResourceError::Conflict(ref cause) => write!(f, "{}", cause),
             UntagResourceError::NotFound(ref cause) => write!(f, "{}", cause),
             UntagResourceError::TooManyRequests(ref cause) => write!(f, "{}", cause),
+            UntagResourceError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for UntagResourceError {}
 /// Errors returned by UpdateApi
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdateApiError {
     /// <p>The request is not valid, for example, the input is incomplete or incorrect. See the accompanying error message for details.</p>
     BadRequest(String),
@@ -5739,6 +6050,8 @@ pub enum UpdateApiError {
     NotFound(String),
     /// <p>A limit has been exceeded. See the accompanying error message for details.</p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdateApiError {
@@ -5746,19 +6059,19 @@ impl UpdateApiError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(UpdateApiError::BadRequest(err.msg))
+                    return RusotoError::service(UpdateApiError::BadRequest(err.msg), &res)
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(UpdateApiError::Conflict(err.msg))
+                    return RusotoError::service(UpdateApiError::Conflict(err.msg), &res)
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(UpdateApiError::NotFound(err.msg))
+                    return RusotoError::service(UpdateApiError::NotFound(err.msg), &res)
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(UpdateApiError::TooManyRequests(err.msg))
+                    return RusotoError::service(UpdateApiError::TooManyRequests(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UpdateApiError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -5772,12 +6085,14 @@ impl fmt::Display for UpdateApiError {
             UpdateApiError::Conflict(ref cause) => write!(f, "{}", cause),
             UpdateApiError::NotFound(ref cause) => write!(f, "{}", cause),
             UpdateApiError::TooManyRequests(ref cause) => write!(f, "{}", cause),
+            UpdateApiError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for UpdateApiError {}
 /// Errors returned by UpdateApiMapping
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdateApiMappingError {
     /// <p>The request is not valid, for example, the input is incomplete or incorrect. See the accompanying error message for details.</p>
     BadRequest(String),
@@ -5787,6 +6102,8 @@ pub enum UpdateApiMappingError {
     NotFound(String),
     /// <p>A limit has been exceeded. See the accompanying error message for details.</p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdateApiMappingError {
@@ -5794,19 +6111,22 @@ impl UpdateApiMappingError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(UpdateApiMappingError::BadRequest(err.msg))
+                    return RusotoError::service(UpdateApiMappingError::BadRequest(err.msg), &res)
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(UpdateApiMappingError::Conflict(err.msg))
+                    return RusotoError::service(UpdateApiMappingError::Conflict(err.msg), &res)
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(UpdateApiMappingError::NotFound(err.msg))
+                    return RusotoError::service(UpdateApiMappingError::NotFound(err.msg), &res)
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(UpdateApiMappingError::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        UpdateApiMappingError::TooManyRequests(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UpdateApiMappingError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -5820,12 +6140,14 @@ impl fmt::Display for UpdateApiMappingError {
             UpdateApiMappingError::Conflict(ref cause) => write!(f, "{}", cause),
             UpdateApiMappingError::NotFound(ref cause) => write!(f, "{}", cause),
             UpdateApiMappingError::TooManyRequests(ref cause) => write!(f, "{}", cause),
+            UpdateApiMappingError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for UpdateApiMappingError {}
 /// Errors returned by UpdateAuthorizer
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdateAuthorizerError {
     /// <p>The request is not valid, for example, the input is incomplete or incorrect. See the accompanying error message for details.</p>
     BadRequest(String),
@@ -5835,6 +6157,8 @@ pub enum UpdateAuthorizerError {
     NotFound(String),
     /// <p>A limit has been exceeded. See the accompanying error message for details.</p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdateAuthorizerError {
@@ -5842,19 +6166,22 @@ impl UpdateAuthorizerError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(UpdateAuthorizerError::BadRequest(err.msg))
+                    return RusotoError::service(UpdateAuthorizerError::BadRequest(err.msg), &res)
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(UpdateAuthorizerError::Conflict(err.msg))
+                    return RusotoError::service(UpdateAuthorizerError::Conflict(err.msg), &res)
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(UpdateAuthorizerError::NotFound(err.msg))
+                    return RusotoError::service(UpdateAuthorizerError::NotFound(err.msg), &res)
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(UpdateAuthorizerError::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        UpdateAuthorizerError::TooManyRequests(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UpdateAuthorizerError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -5868,12 +6195,14 @@ impl fmt::Display for UpdateAuthorizerError {
             UpdateAuthorizerError::Conflict(ref cause) => write!(f, "{}", cause),
             UpdateAuthorizerError::NotFound(ref cause) => write!(f, "{}", cause),
             UpdateAuthorizerError::TooManyRequests(ref cause) => write!(f, "{}", cause),
+            UpdateAuthorizerError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for UpdateAuthorizerError {}
 /// Errors returned by UpdateDeployment
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdateDeploymentError {
     /// <p>The request is not valid, for example, the input is incomplete or incorrect. See the accompanying error message for details.</p>
     BadRequest(String),
@@ -5883,6 +6212,8 @@ pub enum UpdateDeploymentError {
     NotFound(String),
     /// <p>A limit has been exceeded. See the accompanying error message for details.</p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdateDeploymentError {
@@ -5890,19 +6221,22 @@ impl UpdateDeploymentError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(UpdateDeploymentError::BadRequest(err.msg))
+                    return RusotoError::service(UpdateDeploymentError::BadRequest(err.msg), &res)
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(UpdateDeploymentError::Conflict(err.msg))
+                    return RusotoError::service(UpdateDeploymentError::Conflict(err.msg), &res)
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(UpdateDeploymentError::NotFound(err.msg))
+                    return RusotoError::service(UpdateDeploymentError::NotFound(err.msg), &res)
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(UpdateDeploymentError::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        UpdateDeploymentError::TooManyRequests(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UpdateDeploymentError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -5916,12 +6250,14 @@ impl fmt::Display for UpdateDeploymentError {
             UpdateDeploymentError::Conflict(ref cause) => write!(f, "{}", cause),
             UpdateDeploymentError::NotFound(ref cause) => write!(f, "{}", cause),
             UpdateDeploymentError::TooManyRequests(ref cause) => write!(f, "{}", cause),
+            UpdateDeploymentError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for UpdateDeploymentError {}
 /// Errors returned by UpdateDomainName
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdateDomainNameError {
     /// <p>The request is not valid, for example, the input is incomplete or incorrect. See the accompanying error message for details.</p>
     BadRequest(String),
@@ -5931,6 +6267,8 @@ pub enum UpdateDomainNameError {
     NotFound(String),
     /// <p>A limit has been exceeded. See the accompanying error message for details.</p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdateDomainNameError {
@@ -5938,19 +6276,22 @@ impl UpdateDomainNameError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(UpdateDomainNameError::BadRequest(err.msg))
+                    return RusotoError::service(UpdateDomainNameError::BadRequest(err.msg), &res)
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(UpdateDomainNameError::Conflict(err.msg))
+                    return RusotoError::service(UpdateDomainNameError::Conflict(err.msg), &res)
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(UpdateDomainNameError::NotFound(err.msg))
+                    return RusotoError::service(UpdateDomainNameError::NotFound(err.msg), &res)
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(UpdateDomainNameError::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        UpdateDomainNameError::TooManyRequests(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UpdateDomainNameError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -5964,12 +6305,14 @@ impl fmt::Display for UpdateDomainNameError {
             UpdateDomainNameError::Conflict(ref cause) => write!(f, "{}", cause),
             UpdateDomainNameError::NotFound(ref cause) => write!(f, "{}", cause),
             UpdateDomainNameError::TooManyRequests(ref cause) => write!(f, "{}", cause),
+            UpdateDomainNameError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for UpdateDomainNameError {}
 /// Errors returned by UpdateIntegration
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdateIntegrationError {
     /// <p>The request is not valid, for example, the input is incomplete or incorrect. See the accompanying error message for details.</p>
     BadRequest(String),
@@ -5979,6 +6322,8 @@ pub enum UpdateIntegrationError {
     NotFound(String),
     /// <p>A limit has been exceeded. See the accompanying error message for details.</p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdateIntegrationError {
@@ -5986,19 +6331,22 @@ impl UpdateIntegrationError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(UpdateIntegrationError::BadRequest(err.msg))
+                    return RusotoError::service(UpdateIntegrationError::BadRequest(err.msg), &res)
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(UpdateIntegrationError::Conflict(err.msg))
+                    return RusotoError::service(UpdateIntegrationError::Conflict(err.msg), &res)
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(UpdateIntegrationError::NotFound(err.msg))
+                    return RusotoError::service(UpdateIntegrationError::NotFound(err.msg), &res)
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(UpdateIntegrationError::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        UpdateIntegrationError::TooManyRequests(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UpdateIntegrationError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -6012,12 +6360,14 @@ impl fmt::Display for UpdateIntegrationError {
             UpdateIntegrationError::Conflict(ref cause) => write!(f, "{}", cause),
             UpdateIntegrationError::NotFound(ref cause) => write!(f, "{}", cause),
             UpdateIntegrationError::TooManyRequests(ref cause) => write!(f, "{}", cause),
+            UpdateIntegrationError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for UpdateIntegrationError {}
 /// Errors returned by UpdateIntegrationResponse
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdateIntegrationResponseError {
     /// <p>The request is not valid, for example, the input is incomplete or incorrect. See the accompanying error message for details.</p>
     BadRequest(String),
@@ -6027,6 +6377,8 @@ pub enum UpdateIntegrationResponseError {
     NotFound(String),
     /// <p>A limit has been exceeded. See the accompanying error message for details.</p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdateIntegrationResponseError {
@@ -6034,23 +6386,33 @@ impl UpdateIntegrationResponseError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(UpdateIntegrationResponseError::BadRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateIntegrationResponseError::BadRequest(err.msg),
+                        &res,
+                    )
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(UpdateIntegrationResponseError::Conflict(err.msg))
+                    return RusotoError::service(
+                        UpdateIntegrationResponseError::Conflict(err.msg),
+                        &res,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(UpdateIntegrationResponseError::NotFound(err.msg))
+                    return RusotoError::service(
+                        UpdateIntegrationResponseError::NotFound(err.msg),
+                        &res,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(UpdateIntegrationResponseError::TooManyRequests(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateIntegrationResponseError::TooManyRequests(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(UpdateIntegrationResponseError::Unhandled, &res)
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -6064,12 +6426,14 @@ impl fmt::Display for UpdateIntegrationResponseError {
             UpdateIntegrationResponseError::Conflict(ref cause) => write!(f, "{}", cause),
             UpdateIntegrationResponseError::NotFound(ref cause) => write!(f, "{}", cause),
             UpdateIntegrationResponseError::TooManyRequests(ref cause) => write!(f, "{}", cause),
+            UpdateIntegrationResponseError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for UpdateIntegrationResponseError {}
 /// Errors returned by UpdateModel
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdateModelError {
     /// <p>The request is not valid, for example, the input is incomplete or incorrect. See the accompanying error message for details.</p>
     BadRequest(String),
@@ -6079,6 +6443,8 @@ pub enum UpdateModelError {
     NotFound(String),
     /// <p>A limit has been exceeded. See the accompanying error message for details.</p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdateModelError {
@@ -6086,19 +6452,19 @@ impl UpdateModelError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(UpdateModelError::BadRequest(err.msg))
+                    return RusotoError::service(UpdateModelError::BadRequest(err.msg), &res)
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(UpdateModelError::Conflict(err.msg))
+                    return RusotoError::service(UpdateModelError::Conflict(err.msg), &res)
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(UpdateModelError::NotFound(err.msg))
+                    return RusotoError::service(UpdateModelError::NotFound(err.msg), &res)
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(UpdateModelError::TooManyRequests(err.msg))
+                    return RusotoError::service(UpdateModelError::TooManyRequests(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UpdateModelError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -6112,12 +6478,14 @@ impl fmt::Display for UpdateModelError {
             UpdateModelError::Conflict(ref cause) => write!(f, "{}", cause),
             UpdateModelError::NotFound(ref cause) => write!(f, "{}", cause),
             UpdateModelError::TooManyRequests(ref cause) => write!(f, "{}", cause),
+            UpdateModelError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for UpdateModelError {}
 /// Errors returned by UpdateRoute
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdateRouteError {
     /// <p>The request is not valid, for example, the input is incomplete or incorrect. See the accompanying error message for details.</p>
     BadRequest(String),
@@ -6127,6 +6495,8 @@ pub enum UpdateRouteError {
     NotFound(String),
     /// <p>A limit has been exceeded. See the accompanying error message for details.</p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdateRouteError {
@@ -6134,19 +6504,19 @@ impl UpdateRouteError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(UpdateRouteError::BadRequest(err.msg))
+                    return RusotoError::service(UpdateRouteError::BadRequest(err.msg), &res)
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(UpdateRouteError::Conflict(err.msg))
+                    return RusotoError::service(UpdateRouteError::Conflict(err.msg), &res)
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(UpdateRouteError::NotFound(err.msg))
+                    return RusotoError::service(UpdateRouteError::NotFound(err.msg), &res)
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(UpdateRouteError::TooManyRequests(err.msg))
+                    return RusotoError::service(UpdateRouteError::TooManyRequests(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UpdateRouteError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -6160,12 +6530,14 @@ impl fmt::Display for UpdateRouteError {
             UpdateRouteError::Conflict(ref cause) => write!(f, "{}", cause),
             UpdateRouteError::NotFound(ref cause) => write!(f, "{}", cause),
             UpdateRouteError::TooManyRequests(ref cause) => write!(f, "{}", cause),
+            UpdateRouteError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for UpdateRouteError {}
 /// Errors returned by UpdateRouteResponse
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdateRouteResponseError {
     /// <p>The request is not valid, for example, the input is incomplete or incorrect. See the accompanying error message for details.</p>
     BadRequest(String),
@@ -6175,6 +6547,8 @@ pub enum UpdateRouteResponseError {
     NotFound(String),
     /// <p>A limit has been exceeded. See the accompanying error message for details.</p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdateRouteResponseError {
@@ -6182,19 +6556,25 @@ impl UpdateRouteResponseError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(UpdateRouteResponseError::BadRequest(err.msg))
+                    return RusotoError::service(
+                        UpdateRouteResponseError::BadRequest(err.msg),
+                        &res,
+                    )
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(UpdateRouteResponseError::Conflict(err.msg))
+                    return RusotoError::service(UpdateRouteResponseError::Conflict(err.msg), &res)
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(UpdateRouteResponseError::NotFound(err.msg))
+                    return RusotoError::service(UpdateRouteResponseError::NotFound(err.msg), &res)
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(UpdateRouteResponseError::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        UpdateRouteResponseError::TooManyRequests(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UpdateRouteResponseError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -6208,12 +6588,14 @@ impl fmt::Display for UpdateRouteResponseError {
             UpdateRouteResponseError::Conflict(ref cause) => write!(f, "{}", cause),
             UpdateRouteResponseError::NotFound(ref cause) => write!(f, "{}", cause),
             UpdateRouteResponseError::TooManyRequests(ref cause) => write!(f, "{}", cause),
+            UpdateRouteResponseError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for UpdateRouteResponseError {}
 /// Errors returned by UpdateStage
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdateStageError {
     /// <p>The request is not valid, for example, the input is incomplete or incorrect. See the accompanying error message for details.</p>
     BadRequest(String),
@@ -6223,6 +6605,8 @@ pub enum UpdateStageError {
     NotFound(String),
     /// <p>A limit has been exceeded. See the accompanying error message for details.</p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdateStageError {
@@ -6230,19 +6614,19 @@ impl UpdateStageError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(UpdateStageError::BadRequest(err.msg))
+                    return RusotoError::service(UpdateStageError::BadRequest(err.msg), &res)
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(UpdateStageError::Conflict(err.msg))
+                    return RusotoError::service(UpdateStageError::Conflict(err.msg), &res)
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(UpdateStageError::NotFound(err.msg))
+                    return RusotoError::service(UpdateStageError::NotFound(err.msg), &res)
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(UpdateStageError::TooManyRequests(err.msg))
+                    return RusotoError::service(UpdateStageError::TooManyRequests(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UpdateStageError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -6256,6 +6640,7 @@ impl fmt::Display for UpdateStageError {
             UpdateStageError::Conflict(ref cause) => write!(f, "{}", cause),
             UpdateStageError::NotFound(ref cause) => write!(f, "{}", cause),
             UpdateStageError::TooManyRequests(ref cause) => write!(f, "{}", cause),
+            UpdateStageError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
@@ -6649,6 +7034,7 @@ impl ApiGatewayV2Client {
     /// Creates a client backed by the default tokio event loop.
     ///
     /// The client will use the default credentials provider and tls client.
+    #[cfg(not(target_arch = "wasm32"))]
     pub fn new(region: region::Region) -> ApiGatewayV2Client {
         ApiGatewayV2Client {
             client: Client::shared(),
@@ -6674,199 +7060,404 @@ impl ApiGatewayV2Client {
     pub fn new_with_client(client: Client, region: region::Region) -> ApiGatewayV2Client {
         ApiGatewayV2Client { client, region }
     }
+
+    /// Returns a builder of a client, setting any of its region, credentials provider,
+    /// dispatcher, retry policy, endpoint and timeouts.
+    pub fn builder() -> rusoto_core::ClientBuilder<ApiGatewayV2Client> {
+        rusoto_core::ClientBuilder::new(ApiGatewayV2Client::new_with_client)
+    }
+
+    /// Returns a clone of this client with the overrides of `config` applied to its
+    /// operations, sharing its connections.
+    pub fn customize(&self, config: &rusoto_core::OperationConfig) -> ApiGatewayV2Client {
+        ApiGatewayV2Client {
+            client: self.client.customize(config),
+            region: config.resolve_region(&self.region),
+        }
+    }
 }
 
-#[async_trait]
-impl ApiGatewayV2 for ApiGatewayV2Client {
-    /// <p>Creates an Api resource.</p>
-    async fn create_api(
+impl ApiGatewayV2Client {
+    /// Calls `create_api`, also returning the metadata of the response.
+    pub async fn create_api_with_metadata(
         &self,
         input: CreateApiRequest,
-    ) -> Result<CreateApiResponse, RusotoError<CreateApiError>> {
+    ) -> Result<(CreateApiResponse, ResponseMetadata), RusotoError<CreateApiError>> {
+        let request = self.build_create_api_request(input);
+
+        let mut response = self
+            .client
+            .sign_and_dispatch(request)
+            .await
+            .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
+        if response.status.as_u16() == 201 {
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<CreateApiResponse, _>()?;
+
+            Ok((result, metadata))
+        } else {
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(CreateApiError::from_response(response))
+        }
+    }
+
+    /// Presigns the request `create_api` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_api(
+        &self,
+        presigner: &rusoto_core::Presigner,
+        input: CreateApiRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_api_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_api_request(&self, input: CreateApiRequest) -> SignedRequest {
         let request_uri = "/v2/apis";
 
         let mut request = SignedRequest::new("POST", "apigateway", &self.region, &request_uri);
+        request.set_operation("CreateApi");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `create_api_mapping`, also returning the metadata of the response.
+    pub async fn create_api_mapping_with_metadata(
+        &self,
+        input: CreateApiMappingRequest,
+    ) -> Result<(CreateApiMappingResponse, ResponseMetadata), RusotoError<CreateApiMappingError>>
+    {
+        let request = self.build_create_api_mapping_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 201 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<CreateApiResponse, _>()?;
+                .deserialize::<CreateApiMappingResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(CreateApiError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(CreateApiMappingError::from_response(response))
         }
     }
 
-    /// <p>Creates an API mapping.</p>
-    async fn create_api_mapping(
+    /// Presigns the request `create_api_mapping` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_api_mapping(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: CreateApiMappingRequest,
-    ) -> Result<CreateApiMappingResponse, RusotoError<CreateApiMappingError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_api_mapping_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_api_mapping_request(&self, input: CreateApiMappingRequest) -> SignedRequest {
         let request_uri = format!(
             "/v2/domainnames/{domain_name}/apimappings",
             domain_name = input.domain_name
         );
 
         let mut request = SignedRequest::new("POST", "apigateway", &self.region, &request_uri);
+        request.set_operation("CreateApiMapping");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `create_authorizer`, also returning the metadata of the response.
+    pub async fn create_authorizer_with_metadata(
+        &self,
+        input: CreateAuthorizerRequest,
+    ) -> Result<(CreateAuthorizerResponse, ResponseMetadata), RusotoError<CreateAuthorizerError>>
+    {
+        let request = self.build_create_authorizer_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 201 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<CreateApiMappingResponse, _>()?;
+                .deserialize::<CreateAuthorizerResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(CreateApiMappingError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(CreateAuthorizerError::from_response(response))
         }
     }
 
-    /// <p>Creates an Authorizer for an API.</p>
-    async fn create_authorizer(
+    /// Presigns the request `create_authorizer` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_authorizer(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: CreateAuthorizerRequest,
-    ) -> Result<CreateAuthorizerResponse, RusotoError<CreateAuthorizerError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_authorizer_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_authorizer_request(&self, input: CreateAuthorizerRequest) -> SignedRequest {
         let request_uri = format!("/v2/apis/{api_id}/authorizers", api_id = input.api_id);
 
         let mut request = SignedRequest::new("POST", "apigateway", &self.region, &request_uri);
+        request.set_operation("CreateAuthorizer");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `create_deployment`, also returning the metadata of the response.
+    pub async fn create_deployment_with_metadata(
+        &self,
+        input: CreateDeploymentRequest,
+    ) -> Result<(CreateDeploymentResponse, ResponseMetadata), RusotoError<CreateDeploymentError>>
+    {
+        let request = self.build_create_deployment_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 201 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<CreateAuthorizerResponse, _>()?;
+                .deserialize::<CreateDeploymentResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(CreateAuthorizerError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(CreateDeploymentError::from_response(response))
         }
     }
 
-    /// <p>Creates a Deployment for an API.</p>
-    async fn create_deployment(
+    /// Presigns the request `create_deployment` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_deployment(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: CreateDeploymentRequest,
-    ) -> Result<CreateDeploymentResponse, RusotoError<CreateDeploymentError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_deployment_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_deployment_request(&self, input: CreateDeploymentRequest) -> SignedRequest {
         let request_uri = format!("/v2/apis/{api_id}/deployments", api_id = input.api_id);
 
         let mut request = SignedRequest::new("POST", "apigateway", &self.region, &request_uri);
+        request.set_operation("CreateDeployment");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `create_domain_name`, also returning the metadata of the response.
+    pub async fn create_domain_name_with_metadata(
+        &self,
+        input: CreateDomainNameRequest,
+    ) -> Result<(CreateDomainNameResponse, ResponseMetadata), RusotoError<CreateDomainNameError>>
+    {
+        let request = self.build_create_domain_name_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 201 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<CreateDeploymentResponse, _>()?;
+                .deserialize::<CreateDomainNameResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(CreateDeploymentError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(CreateDomainNameError::from_response(response))
         }
     }
 
-    /// <p>Creates a domain name.</p>
-    async fn create_domain_name(
+    /// Presigns the request `create_domain_name` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_domain_name(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: CreateDomainNameRequest,
-    ) -> Result<CreateDomainNameResponse, RusotoError<CreateDomainNameError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_domain_name_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_domain_name_request(&self, input: CreateDomainNameRequest) -> SignedRequest {
         let request_uri = "/v2/domainnames";
 
         let mut request = SignedRequest::new("POST", "apigateway", &self.region, &request_uri);
+        request.set_operation("CreateDomainName");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `create_integration`, also returning the metadata of the response.
+    pub async fn create_integration_with_metadata(
+        &self,
+        input: CreateIntegrationRequest,
+    ) -> Result<(CreateIntegrationResult, ResponseMetadata), RusotoError<CreateIntegrationError>>
+    {
+        let request = self.build_create_integration_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 201 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<CreateDomainNameResponse, _>()?;
+                .deserialize::<CreateIntegrationResult, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(CreateDomainNameError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(CreateIntegrationError::from_response(response))
         }
     }
 
-    /// <p>Creates an Integration.</p>
-    async fn create_integration(
+    /// Presigns the request `create_integration` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_integration(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: CreateIntegrationRequest,
-    ) -> Result<CreateIntegrationResult, RusotoError<CreateIntegrationError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_integration_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_integration_request(&self, input: CreateIntegrationRequest) -> SignedRequest {
         let request_uri = format!("/v2/apis/{api_id}/integrations", api_id = input.api_id);
 
         let mut request = SignedRequest::new("POST", "apigateway", &self.region, &request_uri);
+        request.set_operation("CreateIntegration");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `create_integration_response`, also returning the metadata of the response.
+    pub async fn create_integration_response_with_metadata(
+        &self,
+        input: CreateIntegrationResponseRequest,
+    ) -> Result<
+        (CreateIntegrationResponseResponse, ResponseMetadata),
+        RusotoError<CreateIntegrationResponseError>,
+    > {
+        let request = self.build_create_integration_response_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 201 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<CreateIntegrationResult, _>()?;
+                .deserialize::<CreateIntegrationResponseResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(CreateIntegrationError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(CreateIntegrationResponseError::from_response(response))
         }
     }
 
-    /// <p>Creates an IntegrationResponses.</p>
-    async fn create_integration_response(
+    /// Presigns the request `create_integration_response` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_integration_response(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: CreateIntegrationResponseRequest,
-    ) -> Result<CreateIntegrationResponseResponse, RusotoError<CreateIntegrationResponseError>>
-    {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_integration_response_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_integration_response_request(
+        &self,
+        input: CreateIntegrationResponseRequest,
+    ) -> SignedRequest {
         let request_uri = format!(
             "/v2/apis/{api_id}/integrations/{integration_id}/integrationresponses",
             api_id = input.api_id,
@@ -6874,93 +7465,171 @@ impl ApiGatewayV2 for ApiGatewayV2Client {
         );
 
         let mut request = SignedRequest::new("POST", "apigateway", &self.region, &request_uri);
+        request.set_operation("CreateIntegrationResponse");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `create_model`, also returning the metadata of the response.
+    pub async fn create_model_with_metadata(
+        &self,
+        input: CreateModelRequest,
+    ) -> Result<(CreateModelResponse, ResponseMetadata), RusotoError<CreateModelError>> {
+        let request = self.build_create_model_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 201 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<CreateIntegrationResponseResponse, _>()?;
+                .deserialize::<CreateModelResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(CreateIntegrationResponseError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(CreateModelError::from_response(response))
         }
     }
 
-    /// <p>Creates a Model for an API.</p>
-    async fn create_model(
+    /// Presigns the request `create_model` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_model(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: CreateModelRequest,
-    ) -> Result<CreateModelResponse, RusotoError<CreateModelError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_model_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_model_request(&self, input: CreateModelRequest) -> SignedRequest {
         let request_uri = format!("/v2/apis/{api_id}/models", api_id = input.api_id);
 
         let mut request = SignedRequest::new("POST", "apigateway", &self.region, &request_uri);
+        request.set_operation("CreateModel");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `create_route`, also returning the metadata of the response.
+    pub async fn create_route_with_metadata(
+        &self,
+        input: CreateRouteRequest,
+    ) -> Result<(CreateRouteResult, ResponseMetadata), RusotoError<CreateRouteError>> {
+        let request = self.build_create_route_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 201 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<CreateModelResponse, _>()?;
+                .deserialize::<CreateRouteResult, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(CreateModelError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(CreateRouteError::from_response(response))
         }
     }
 
-    /// <p>Creates a Route for an API.</p>
-    async fn create_route(
+    /// Presigns the request `create_route` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_route(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: CreateRouteRequest,
-    ) -> Result<CreateRouteResult, RusotoError<CreateRouteError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_route_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_route_request(&self, input: CreateRouteRequest) -> SignedRequest {
         let request_uri = format!("/v2/apis/{api_id}/routes", api_id = input.api_id);
 
         let mut request = SignedRequest::new("POST", "apigateway", &self.region, &request_uri);
+        request.set_operation("CreateRoute");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `create_route_response`, also returning the metadata of the response.
+    pub async fn create_route_response_with_metadata(
+        &self,
+        input: CreateRouteResponseRequest,
+    ) -> Result<
+        (CreateRouteResponseResponse, ResponseMetadata),
+        RusotoError<CreateRouteResponseError>,
+    > {
+        let request = self.build_create_route_response_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 201 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<CreateRouteResult, _>()?;
+                .deserialize::<CreateRouteResponseResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(CreateRouteError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(CreateRouteResponseError::from_response(response))
         }
     }
 
-    /// <p>Creates a RouteResponse for a Route.</p>
-    async fn create_route_response(
+    /// Presigns the request `create_route_response` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_route_response(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: CreateRouteResponseRequest,
-    ) -> Result<CreateRouteResponseResponse, RusotoError<CreateRouteResponseError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_route_response_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_route_response_request(
+        &self,
+        input: CreateRouteResponseRequest,
+    ) -> SignedRequest {
         let request_uri = format!(
             "/v2/apis/{api_id}/routes/{route_id}/routeresponses",
             api_id = input.api_id,
@@ -6968,86 +7637,160 @@ impl ApiGatewayV2 for ApiGatewayV2Client {
         );
 
         let mut request = SignedRequest::new("POST", "apigateway", &self.region, &request_uri);
+        request.set_operation("CreateRouteResponse");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `create_stage`, also returning the metadata of the response.
+    pub async fn create_stage_with_metadata(
+        &self,
+        input: CreateStageRequest,
+    ) -> Result<(CreateStageResponse, ResponseMetadata), RusotoError<CreateStageError>> {
+        let request = self.build_create_stage_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 201 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<CreateRouteResponseResponse, _>()?;
+                .deserialize::<CreateStageResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(CreateRouteResponseError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(CreateStageError::from_response(response))
         }
     }
 
-    /// <p>Creates a Stage for an API.</p>
-    async fn create_stage(
+    /// Presigns the request `create_stage` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_stage(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: CreateStageRequest,
-    ) -> Result<CreateStageResponse, RusotoError<CreateStageError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_stage_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_stage_request(&self, input: CreateStageRequest) -> SignedRequest {
         let request_uri = format!("/v2/apis/{api_id}/stages", api_id = input.api_id);
 
         let mut request = SignedRequest::new("POST", "apigateway", &self.region, &request_uri);
+        request.set_operation("CreateStage");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `delete_api`, also returning the metadata of the response.
+    pub async fn delete_api_with_metadata(
+        &self,
+        input: DeleteApiRequest,
+    ) -> Result<((), ResponseMetadata), RusotoError<DeleteApiError>> {
+        let request = self.build_delete_api_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
-        if response.status.as_u16() == 201 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<CreateStageResponse, _>()?;
+        let metadata = ResponseMetadata::from_response(&response);
+        if response.status.as_u16() == 204 {
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = ::std::mem::drop(response);
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(CreateStageError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(DeleteApiError::from_response(response))
         }
     }
 
-    /// <p>Deletes an Api resource.</p>
-    async fn delete_api(&self, input: DeleteApiRequest) -> Result<(), RusotoError<DeleteApiError>> {
+    /// Presigns the request `delete_api` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_api(
+        &self,
+        presigner: &rusoto_core::Presigner,
+        input: DeleteApiRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_api_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_api_request(&self, input: DeleteApiRequest) -> SignedRequest {
         let request_uri = format!("/v2/apis/{api_id}", api_id = input.api_id);
 
         let mut request = SignedRequest::new("DELETE", "apigateway", &self.region, &request_uri);
+        request.set_operation("DeleteApi");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
+        request
+    }
+
+    /// Calls `delete_api_mapping`, also returning the metadata of the response.
+    pub async fn delete_api_mapping_with_metadata(
+        &self,
+        input: DeleteApiMappingRequest,
+    ) -> Result<((), ResponseMetadata), RusotoError<DeleteApiMappingError>> {
+        let request = self.build_delete_api_mapping_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 204 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = ::std::mem::drop(response);
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteApiError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(DeleteApiMappingError::from_response(response))
         }
     }
 
-    /// <p>Deletes an API mapping.</p>
-    async fn delete_api_mapping(
+    /// Presigns the request `delete_api_mapping` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_api_mapping(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteApiMappingRequest,
-    ) -> Result<(), RusotoError<DeleteApiMappingError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_api_mapping_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_api_mapping_request(&self, input: DeleteApiMappingRequest) -> SignedRequest {
         let request_uri = format!(
             "/v2/domainnames/{domain_name}/apimappings/{api_mapping_id}",
             api_mapping_id = input.api_mapping_id,
@@ -7055,29 +7798,53 @@ impl ApiGatewayV2 for ApiGatewayV2Client {
         );
 
         let mut request = SignedRequest::new("DELETE", "apigateway", &self.region, &request_uri);
+        request.set_operation("DeleteApiMapping");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
+        request
+    }
+
+    /// Calls `delete_authorizer`, also returning the metadata of the response.
+    pub async fn delete_authorizer_with_metadata(
+        &self,
+        input: DeleteAuthorizerRequest,
+    ) -> Result<((), ResponseMetadata), RusotoError<DeleteAuthorizerError>> {
+        let request = self.build_delete_authorizer_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 204 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = ::std::mem::drop(response);
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteApiMappingError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(DeleteAuthorizerError::from_response(response))
         }
     }
 
-    /// <p>Deletes an Authorizer.</p>
-    async fn delete_authorizer(
+    /// Presigns the request `delete_authorizer` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_authorizer(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteAuthorizerRequest,
-    ) -> Result<(), RusotoError<DeleteAuthorizerError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_authorizer_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_authorizer_request(&self, input: DeleteAuthorizerRequest) -> SignedRequest {
         let request_uri = format!(
             "/v2/apis/{api_id}/authorizers/{authorizer_id}",
             api_id = input.api_id,
@@ -7085,55 +7852,106 @@ impl ApiGatewayV2 for ApiGatewayV2Client {
         );
 
         let mut request = SignedRequest::new("DELETE", "apigateway", &self.region, &request_uri);
+        request.set_operation("DeleteAuthorizer");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
+        request
+    }
+
+    /// Calls `delete_cors_configuration`, also returning the metadata of the response.
+    pub async fn delete_cors_configuration_with_metadata(
+        &self,
+        input: DeleteCorsConfigurationRequest,
+    ) -> Result<((), ResponseMetadata), RusotoError<DeleteCorsConfigurationError>> {
+        let request = self.build_delete_cors_configuration_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 204 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = ::std::mem::drop(response);
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteAuthorizerError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(DeleteCorsConfigurationError::from_response(response))
         }
     }
 
-    /// <p>Deletes a CORS configuration.</p>
-    async fn delete_cors_configuration(
+    /// Presigns the request `delete_cors_configuration` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_cors_configuration(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteCorsConfigurationRequest,
-    ) -> Result<(), RusotoError<DeleteCorsConfigurationError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_cors_configuration_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_cors_configuration_request(
+        &self,
+        input: DeleteCorsConfigurationRequest,
+    ) -> SignedRequest {
         let request_uri = format!("/v2/apis/{api_id}/cors", api_id = input.api_id);
 
         let mut request = SignedRequest::new("DELETE", "apigateway", &self.region, &request_uri);
+        request.set_operation("DeleteCorsConfiguration");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
+        request
+    }
+
+    /// Calls `delete_deployment`, also returning the metadata of the response.
+    pub async fn delete_deployment_with_metadata(
+        &self,
+        input: DeleteDeploymentRequest,
+    ) -> Result<((), ResponseMetadata), RusotoError<DeleteDeploymentError>> {
+        let request = self.build_delete_deployment_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 204 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = ::std::mem::drop(response);
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteCorsConfigurationError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(DeleteDeploymentError::from_response(response))
         }
     }
 
-    /// <p>Deletes a Deployment.</p>
-    async fn delete_deployment(
+    /// Presigns the request `delete_deployment` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_deployment(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteDeploymentRequest,
-    ) -> Result<(), RusotoError<DeleteDeploymentError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_deployment_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_deployment_request(&self, input: DeleteDeploymentRequest) -> SignedRequest {
         let request_uri = format!(
             "/v2/apis/{api_id}/deployments/{deployment_id}",
             api_id = input.api_id,
@@ -7141,58 +7959,106 @@ impl ApiGatewayV2 for ApiGatewayV2Client {
         );
 
         let mut request = SignedRequest::new("DELETE", "apigateway", &self.region, &request_uri);
+        request.set_operation("DeleteDeployment");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
-        let mut response = self
-            .client
-            .sign_and_dispatch(request)
-            .await
+        request
+    }
+
+    /// Calls `delete_domain_name`, also returning the metadata of the response.
+    pub async fn delete_domain_name_with_metadata(
+        &self,
+        input: DeleteDomainNameRequest,
+    ) -> Result<((), ResponseMetadata), RusotoError<DeleteDomainNameError>> {
+        let request = self.build_delete_domain_name_request(input);
+
+        let mut response = self
+            .client
+            .sign_and_dispatch(request)
+            .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 204 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = ::std::mem::drop(response);
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteDeploymentError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(DeleteDomainNameError::from_response(response))
         }
     }
 
-    /// <p>Deletes a domain name.</p>
-    async fn delete_domain_name(
+    /// Presigns the request `delete_domain_name` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_domain_name(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteDomainNameRequest,
-    ) -> Result<(), RusotoError<DeleteDomainNameError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_domain_name_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_domain_name_request(&self, input: DeleteDomainNameRequest) -> SignedRequest {
         let request_uri = format!(
             "/v2/domainnames/{domain_name}",
             domain_name = input.domain_name
         );
 
         let mut request = SignedRequest::new("DELETE", "apigateway", &self.region, &request_uri);
+        request.set_operation("DeleteDomainName");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
+        request
+    }
+
+    /// Calls `delete_integration`, also returning the metadata of the response.
+    pub async fn delete_integration_with_metadata(
+        &self,
+        input: DeleteIntegrationRequest,
+    ) -> Result<((), ResponseMetadata), RusotoError<DeleteIntegrationError>> {
+        let request = self.build_delete_integration_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 204 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = ::std::mem::drop(response);
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteDomainNameError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(DeleteIntegrationError::from_response(response))
         }
     }
 
-    /// <p>Deletes an Integration.</p>
-    async fn delete_integration(
+    /// Presigns the request `delete_integration` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_integration(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteIntegrationRequest,
-    ) -> Result<(), RusotoError<DeleteIntegrationError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_integration_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_integration_request(&self, input: DeleteIntegrationRequest) -> SignedRequest {
         let request_uri = format!(
             "/v2/apis/{api_id}/integrations/{integration_id}",
             api_id = input.api_id,
@@ -7200,55 +8066,106 @@ impl ApiGatewayV2 for ApiGatewayV2Client {
         );
 
         let mut request = SignedRequest::new("DELETE", "apigateway", &self.region, &request_uri);
+        request.set_operation("DeleteIntegration");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
+        request
+    }
+
+    /// Calls `delete_integration_response`, also returning the metadata of the response.
+    pub async fn delete_integration_response_with_metadata(
+        &self,
+        input: DeleteIntegrationResponseRequest,
+    ) -> Result<((), ResponseMetadata), RusotoError<DeleteIntegrationResponseError>> {
+        let request = self.build_delete_integration_response_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 204 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = ::std::mem::drop(response);
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteIntegrationError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(DeleteIntegrationResponseError::from_response(response))
         }
     }
 
-    /// <p>Deletes an IntegrationResponses.</p>
-    async fn delete_integration_response(
+    /// Presigns the request `delete_integration_response` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_integration_response(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteIntegrationResponseRequest,
-    ) -> Result<(), RusotoError<DeleteIntegrationResponseError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_integration_response_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_integration_response_request(
+        &self,
+        input: DeleteIntegrationResponseRequest,
+    ) -> SignedRequest {
         let request_uri = format!("/v2/apis/{api_id}/integrations/{integration_id}/integrationresponses/{integration_response_id}", api_id = input.api_id, integration_id = input.integration_id, integration_response_id = input.integration_response_id);
 
         let mut request = SignedRequest::new("DELETE", "apigateway", &self.region, &request_uri);
+        request.set_operation("DeleteIntegrationResponse");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
+        request
+    }
+
+    /// Calls `delete_model`, also returning the metadata of the response.
+    pub async fn delete_model_with_metadata(
+        &self,
+        input: DeleteModelRequest,
+    ) -> Result<((), ResponseMetadata), RusotoError<DeleteModelError>> {
+        let request = self.build_delete_model_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 204 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = ::std::mem::drop(response);
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteIntegrationResponseError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(DeleteModelError::from_response(response))
         }
     }
 
-    /// <p>Deletes a Model.</p>
-    async fn delete_model(
+    /// Presigns the request `delete_model` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_model(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteModelRequest,
-    ) -> Result<(), RusotoError<DeleteModelError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_model_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_model_request(&self, input: DeleteModelRequest) -> SignedRequest {
         let request_uri = format!(
             "/v2/apis/{api_id}/models/{model_id}",
             api_id = input.api_id,
@@ -7256,29 +8173,53 @@ impl ApiGatewayV2 for ApiGatewayV2Client {
         );
 
         let mut request = SignedRequest::new("DELETE", "apigateway", &self.region, &request_uri);
+        request.set_operation("DeleteModel");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
+        request
+    }
+
+    /// Calls `delete_route`, also returning the metadata of the response.
+    pub async fn delete_route_with_metadata(
+        &self,
+        input: DeleteRouteRequest,
+    ) -> Result<((), ResponseMetadata), RusotoError<DeleteRouteError>> {
+        let request = self.build_delete_route_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 204 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = ::std::mem::drop(response);
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteModelError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(DeleteRouteError::from_response(response))
         }
     }
 
-    /// <p>Deletes a Route.</p>
-    async fn delete_route(
+    /// Presigns the request `delete_route` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_route(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteRouteRequest,
-    ) -> Result<(), RusotoError<DeleteRouteError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_route_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_route_request(&self, input: DeleteRouteRequest) -> SignedRequest {
         let request_uri = format!(
             "/v2/apis/{api_id}/routes/{route_id}",
             api_id = input.api_id,
@@ -7286,29 +8227,56 @@ impl ApiGatewayV2 for ApiGatewayV2Client {
         );
 
         let mut request = SignedRequest::new("DELETE", "apigateway", &self.region, &request_uri);
+        request.set_operation("DeleteRoute");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
+        request
+    }
+
+    /// Calls `delete_route_response`, also returning the metadata of the response.
+    pub async fn delete_route_response_with_metadata(
+        &self,
+        input: DeleteRouteResponseRequest,
+    ) -> Result<((), ResponseMetadata), RusotoError<DeleteRouteResponseError>> {
+        let request = self.build_delete_route_response_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 204 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = ::std::mem::drop(response);
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteRouteError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(DeleteRouteResponseError::from_response(response))
         }
     }
 
-    /// <p>Deletes a RouteResponse.</p>
-    async fn delete_route_response(
+    /// Presigns the request `delete_route_response` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_route_response(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteRouteResponseRequest,
-    ) -> Result<(), RusotoError<DeleteRouteResponseError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_route_response_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_route_response_request(
+        &self,
+        input: DeleteRouteResponseRequest,
+    ) -> SignedRequest {
         let request_uri = format!(
             "/v2/apis/{api_id}/routes/{route_id}/routeresponses/{route_response_id}",
             api_id = input.api_id,
@@ -7317,29 +8285,56 @@ impl ApiGatewayV2 for ApiGatewayV2Client {
         );
 
         let mut request = SignedRequest::new("DELETE", "apigateway", &self.region, &request_uri);
+        request.set_operation("DeleteRouteResponse");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
+        request
+    }
+
+    /// Calls `delete_route_settings`, also returning the metadata of the response.
+    pub async fn delete_route_settings_with_metadata(
+        &self,
+        input: DeleteRouteSettingsRequest,
+    ) -> Result<((), ResponseMetadata), RusotoError<DeleteRouteSettingsError>> {
+        let request = self.build_delete_route_settings_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 204 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = ::std::mem::drop(response);
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteRouteResponseError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(DeleteRouteSettingsError::from_response(response))
         }
     }
 
-    /// <p>Deletes the RouteSettings for a stage.</p>
-    async fn delete_route_settings(
+    /// Presigns the request `delete_route_settings` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_route_settings(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteRouteSettingsRequest,
-    ) -> Result<(), RusotoError<DeleteRouteSettingsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_route_settings_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_route_settings_request(
+        &self,
+        input: DeleteRouteSettingsRequest,
+    ) -> SignedRequest {
         let request_uri = format!(
             "/v2/apis/{api_id}/stages/{stage_name}/routesettings/{route_key}",
             api_id = input.api_id,
@@ -7348,29 +8343,53 @@ impl ApiGatewayV2 for ApiGatewayV2Client {
         );
 
         let mut request = SignedRequest::new("DELETE", "apigateway", &self.region, &request_uri);
+        request.set_operation("DeleteRouteSettings");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
+        request
+    }
+
+    /// Calls `delete_stage`, also returning the metadata of the response.
+    pub async fn delete_stage_with_metadata(
+        &self,
+        input: DeleteStageRequest,
+    ) -> Result<((), ResponseMetadata), RusotoError<DeleteStageError>> {
+        let request = self.build_delete_stage_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 204 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = ::std::mem::drop(response);
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteRouteSettingsError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(DeleteStageError::from_response(response))
         }
     }
 
-    /// <p>Deletes a Stage.</p>
-    async fn delete_stage(
+    /// Presigns the request `delete_stage` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_stage(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteStageRequest,
-    ) -> Result<(), RusotoError<DeleteStageError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_stage_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_stage_request(&self, input: DeleteStageRequest) -> SignedRequest {
         let request_uri = format!(
             "/v2/apis/{api_id}/stages/{stage_name}",
             api_id = input.api_id,
@@ -7378,56 +8397,105 @@ impl ApiGatewayV2 for ApiGatewayV2Client {
         );
 
         let mut request = SignedRequest::new("DELETE", "apigateway", &self.region, &request_uri);
+        request.set_operation("DeleteStage");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
+        request
+    }
+
+    /// Calls `get_api`, also returning the metadata of the response.
+    pub async fn get_api_with_metadata(
+        &self,
+        input: GetApiRequest,
+    ) -> Result<(GetApiResponse, ResponseMetadata), RusotoError<GetApiError>> {
+        let request = self.build_get_api_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
-        if response.status.as_u16() == 204 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            let result = ::std::mem::drop(response);
+        let metadata = ResponseMetadata::from_response(&response);
+        if response.status.as_u16() == 200 {
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result =
+                proto::json::ResponsePayload::new(&response).deserialize::<GetApiResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteStageError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(GetApiError::from_response(response))
         }
     }
 
-    /// <p>Gets an Api resource.</p>
-    async fn get_api(
+    /// Presigns the request `get_api` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_api(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetApiRequest,
-    ) -> Result<GetApiResponse, RusotoError<GetApiError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_api_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_api_request(&self, input: GetApiRequest) -> SignedRequest {
         let request_uri = format!("/v2/apis/{api_id}", api_id = input.api_id);
 
         let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
+        request.set_operation("GetApi");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
+        request
+    }
+
+    /// Calls `get_api_mapping`, also returning the metadata of the response.
+    pub async fn get_api_mapping_with_metadata(
+        &self,
+        input: GetApiMappingRequest,
+    ) -> Result<(GetApiMappingResponse, ResponseMetadata), RusotoError<GetApiMappingError>> {
+        let request = self.build_get_api_mapping_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            let result =
-                proto::json::ResponsePayload::new(&response).deserialize::<GetApiResponse, _>()?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<GetApiMappingResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(GetApiError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(GetApiMappingError::from_response(response))
         }
     }
 
-    /// <p>Gets an API mapping.</p>
-    async fn get_api_mapping(
+    /// Presigns the request `get_api_mapping` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_api_mapping(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetApiMappingRequest,
-    ) -> Result<GetApiMappingResponse, RusotoError<GetApiMappingError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_api_mapping_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_api_mapping_request(&self, input: GetApiMappingRequest) -> SignedRequest {
         let request_uri = format!(
             "/v2/domainnames/{domain_name}/apimappings/{api_mapping_id}",
             api_mapping_id = input.api_mapping_id,
@@ -7435,36 +8503,61 @@ impl ApiGatewayV2 for ApiGatewayV2Client {
         );
 
         let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
+        request.set_operation("GetApiMapping");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
+        request
+    }
+
+    /// Calls `get_api_mappings`, also returning the metadata of the response.
+    pub async fn get_api_mappings_with_metadata(
+        &self,
+        input: GetApiMappingsRequest,
+    ) -> Result<(GetApiMappingsResponse, ResponseMetadata), RusotoError<GetApiMappingsError>> {
+        let request = self.build_get_api_mappings_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<GetApiMappingResponse, _>()?;
+                .deserialize::<GetApiMappingsResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(GetApiMappingError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(GetApiMappingsError::from_response(response))
         }
     }
 
-    /// <p>Gets API mappings.</p>
-    async fn get_api_mappings(
+    /// Presigns the request `get_api_mappings` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_api_mappings(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetApiMappingsRequest,
-    ) -> Result<GetApiMappingsResponse, RusotoError<GetApiMappingsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_api_mappings_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_api_mappings_request(&self, input: GetApiMappingsRequest) -> SignedRequest {
         let request_uri = format!(
             "/v2/domainnames/{domain_name}/apimappings",
             domain_name = input.domain_name
         );
 
         let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
+        request.set_operation("GetApiMappings");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -7476,31 +8569,55 @@ impl ApiGatewayV2 for ApiGatewayV2Client {
         }
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `get_apis`, also returning the metadata of the response.
+    pub async fn get_apis_with_metadata(
+        &self,
+        input: GetApisRequest,
+    ) -> Result<(GetApisResponse, ResponseMetadata), RusotoError<GetApisError>> {
+        let request = self.build_get_apis_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<GetApiMappingsResponse, _>()?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result =
+                proto::json::ResponsePayload::new(&response).deserialize::<GetApisResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(GetApiMappingsError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(GetApisError::from_response(response))
         }
     }
 
-    /// <p>Gets a collection of Api resources.</p>
-    async fn get_apis(
+    /// Presigns the request `get_apis` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_apis(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetApisRequest,
-    ) -> Result<GetApisResponse, RusotoError<GetApisError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_apis_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_apis_request(&self, input: GetApisRequest) -> SignedRequest {
         let request_uri = "/v2/apis";
 
         let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
+        request.set_operation("GetApis");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -7512,28 +8629,51 @@ impl ApiGatewayV2 for ApiGatewayV2Client {
         }
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `get_authorizer`, also returning the metadata of the response.
+    pub async fn get_authorizer_with_metadata(
+        &self,
+        input: GetAuthorizerRequest,
+    ) -> Result<(GetAuthorizerResponse, ResponseMetadata), RusotoError<GetAuthorizerError>> {
+        let request = self.build_get_authorizer_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            let result =
-                proto::json::ResponsePayload::new(&response).deserialize::<GetApisResponse, _>()?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<GetAuthorizerResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(GetApisError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(GetAuthorizerError::from_response(response))
         }
     }
 
-    /// <p>Gets an Authorizer.</p>
-    async fn get_authorizer(
+    /// Presigns the request `get_authorizer` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_authorizer(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetAuthorizerRequest,
-    ) -> Result<GetAuthorizerResponse, RusotoError<GetAuthorizerError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_authorizer_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_authorizer_request(&self, input: GetAuthorizerRequest) -> SignedRequest {
         let request_uri = format!(
             "/v2/apis/{api_id}/authorizers/{authorizer_id}",
             api_id = input.api_id,
@@ -7541,33 +8681,58 @@ impl ApiGatewayV2 for ApiGatewayV2Client {
         );
 
         let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
+        request.set_operation("GetAuthorizer");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
+        request
+    }
+
+    /// Calls `get_authorizers`, also returning the metadata of the response.
+    pub async fn get_authorizers_with_metadata(
+        &self,
+        input: GetAuthorizersRequest,
+    ) -> Result<(GetAuthorizersResponse, ResponseMetadata), RusotoError<GetAuthorizersError>> {
+        let request = self.build_get_authorizers_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<GetAuthorizerResponse, _>()?;
+                .deserialize::<GetAuthorizersResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(GetAuthorizerError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(GetAuthorizersError::from_response(response))
         }
     }
 
-    /// <p>Gets the Authorizers for an API.</p>
-    async fn get_authorizers(
+    /// Presigns the request `get_authorizers` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_authorizers(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetAuthorizersRequest,
-    ) -> Result<GetAuthorizersResponse, RusotoError<GetAuthorizersError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_authorizers_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_authorizers_request(&self, input: GetAuthorizersRequest) -> SignedRequest {
         let request_uri = format!("/v2/apis/{api_id}/authorizers", api_id = input.api_id);
 
         let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
+        request.set_operation("GetAuthorizers");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -7579,28 +8744,51 @@ impl ApiGatewayV2 for ApiGatewayV2Client {
         }
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `get_deployment`, also returning the metadata of the response.
+    pub async fn get_deployment_with_metadata(
+        &self,
+        input: GetDeploymentRequest,
+    ) -> Result<(GetDeploymentResponse, ResponseMetadata), RusotoError<GetDeploymentError>> {
+        let request = self.build_get_deployment_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<GetAuthorizersResponse, _>()?;
+                .deserialize::<GetDeploymentResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(GetAuthorizersError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(GetDeploymentError::from_response(response))
         }
     }
 
-    /// <p>Gets a Deployment.</p>
-    async fn get_deployment(
+    /// Presigns the request `get_deployment` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_deployment(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetDeploymentRequest,
-    ) -> Result<GetDeploymentResponse, RusotoError<GetDeploymentError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_deployment_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_deployment_request(&self, input: GetDeploymentRequest) -> SignedRequest {
         let request_uri = format!(
             "/v2/apis/{api_id}/deployments/{deployment_id}",
             api_id = input.api_id,
@@ -7608,33 +8796,58 @@ impl ApiGatewayV2 for ApiGatewayV2Client {
         );
 
         let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
+        request.set_operation("GetDeployment");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
+        request
+    }
+
+    /// Calls `get_deployments`, also returning the metadata of the response.
+    pub async fn get_deployments_with_metadata(
+        &self,
+        input: GetDeploymentsRequest,
+    ) -> Result<(GetDeploymentsResponse, ResponseMetadata), RusotoError<GetDeploymentsError>> {
+        let request = self.build_get_deployments_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<GetDeploymentResponse, _>()?;
+                .deserialize::<GetDeploymentsResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(GetDeploymentError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(GetDeploymentsError::from_response(response))
         }
     }
 
-    /// <p>Gets the Deployments for an API.</p>
-    async fn get_deployments(
+    /// Presigns the request `get_deployments` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_deployments(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetDeploymentsRequest,
-    ) -> Result<GetDeploymentsResponse, RusotoError<GetDeploymentsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_deployments_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_deployments_request(&self, input: GetDeploymentsRequest) -> SignedRequest {
         let request_uri = format!("/v2/apis/{api_id}/deployments", api_id = input.api_id);
 
         let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
+        request.set_operation("GetDeployments");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -7646,61 +8859,109 @@ impl ApiGatewayV2 for ApiGatewayV2Client {
         }
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `get_domain_name`, also returning the metadata of the response.
+    pub async fn get_domain_name_with_metadata(
+        &self,
+        input: GetDomainNameRequest,
+    ) -> Result<(GetDomainNameResponse, ResponseMetadata), RusotoError<GetDomainNameError>> {
+        let request = self.build_get_domain_name_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<GetDeploymentsResponse, _>()?;
+                .deserialize::<GetDomainNameResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(GetDeploymentsError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(GetDomainNameError::from_response(response))
         }
     }
 
-    /// <p>Gets a domain name.</p>
-    async fn get_domain_name(
+    /// Presigns the request `get_domain_name` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_domain_name(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetDomainNameRequest,
-    ) -> Result<GetDomainNameResponse, RusotoError<GetDomainNameError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_domain_name_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_domain_name_request(&self, input: GetDomainNameRequest) -> SignedRequest {
         let request_uri = format!(
             "/v2/domainnames/{domain_name}",
             domain_name = input.domain_name
         );
 
         let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
+        request.set_operation("GetDomainName");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
+        request
+    }
+
+    /// Calls `get_domain_names`, also returning the metadata of the response.
+    pub async fn get_domain_names_with_metadata(
+        &self,
+        input: GetDomainNamesRequest,
+    ) -> Result<(GetDomainNamesResponse, ResponseMetadata), RusotoError<GetDomainNamesError>> {
+        let request = self.build_get_domain_names_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<GetDomainNameResponse, _>()?;
+                .deserialize::<GetDomainNamesResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(GetDomainNameError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(GetDomainNamesError::from_response(response))
         }
     }
 
-    /// <p>Gets the domain names for an AWS account.</p>
-    async fn get_domain_names(
+    /// Presigns the request `get_domain_names` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_domain_names(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetDomainNamesRequest,
-    ) -> Result<GetDomainNamesResponse, RusotoError<GetDomainNamesError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_domain_names_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_domain_names_request(&self, input: GetDomainNamesRequest) -> SignedRequest {
         let request_uri = "/v2/domainnames";
 
         let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
+        request.set_operation("GetDomainNames");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -7712,28 +8973,51 @@ impl ApiGatewayV2 for ApiGatewayV2Client {
         }
         request.set_params(params);
 
-        let mut response = self
+        request
+    }
+
+    /// Calls `get_integration`, also returning the metadata of the response.
+    pub async fn get_integration_with_metadata(
+        &self,
+        input: GetIntegrationRequest,
+    ) -> Result<(GetIntegrationResult, ResponseMetadata), RusotoError<GetIntegrationError>> {
+        let request = self.build_get_integration_request(input);
+
+        let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<GetDomainNamesResponse, _>()?;
+                .deserialize::<GetIntegrationResult, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(GetDomainNamesError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(GetIntegrationError::from_response(response))
         }
     }
 
-    /// <p>Gets an Integration.</p>
-    async fn get_integration(
+    /// Presigns the request `get_integration` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_integration(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetIntegrationRequest,
-    ) -> Result<GetIntegrationResult, RusotoError<GetIntegrationError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_integration_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_integration_request(&self, input: GetIntegrationRequest) -> SignedRequest {
         let request_uri = format!(
             "/v2/apis/{api_id}/integrations/{integration_id}",
             api_id = input.api_id,
@@ -7741,57 +9025,117 @@ impl ApiGatewayV2 for ApiGatewayV2Client {
         );
 
         let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
+        request.set_operation("GetIntegration");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
+        request
+    }
+
+    /// Calls `get_integration_response`, also returning the metadata of the response.
+    pub async fn get_integration_response_with_metadata(
+        &self,
+        input: GetIntegrationResponseRequest,
+    ) -> Result<
+        (GetIntegrationResponseResponse, ResponseMetadata),
+        RusotoError<GetIntegrationResponseError>,
+    > {
+        let request = self.build_get_integration_response_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<GetIntegrationResult, _>()?;
+                .deserialize::<GetIntegrationResponseResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(GetIntegrationError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(GetIntegrationResponseError::from_response(response))
         }
     }
 
-    /// <p>Gets an IntegrationResponses.</p>
-    async fn get_integration_response(
+    /// Presigns the request `get_integration_response` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_integration_response(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetIntegrationResponseRequest,
-    ) -> Result<GetIntegrationResponseResponse, RusotoError<GetIntegrationResponseError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_integration_response_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_integration_response_request(
+        &self,
+        input: GetIntegrationResponseRequest,
+    ) -> SignedRequest {
         let request_uri = format!("/v2/apis/{api_id}/integrations/{integration_id}/integrationresponses/{integration_response_id}", api_id = input.api_id, integration_id = input.integration_id, integration_response_id = input.integration_response_id);
 
         let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
+        request.set_operation("GetIntegrationResponse");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
+        request
+    }
+
+    /// Calls `get_integration_responses`, also returning the metadata of the response.
+    pub async fn get_integration_responses_with_metadata(
+        &self,
+        input: GetIntegrationResponsesRequest,
+    ) -> Result<
+        (GetIntegrationResponsesResponse, ResponseMetadata),
+        RusotoError<GetIntegrationResponsesError>,
+    > {
+        let request = self.build_get_integration_responses_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<GetIntegrationResponseResponse, _>()?;
+                .deserialize::<GetIntegrationResponsesResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(GetIntegrationResponseError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(GetIntegrationResponsesError::from_response(response))
         }
     }
 
-    /// <p>Gets the IntegrationResponses for an Integration.</p>
-    async fn get_integration_responses(
+    /// Presigns the request `get_integration_responses` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_integration_responses(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetIntegrationResponsesRequest,
-    ) -> Result<GetIntegrationResponsesResponse, RusotoError<GetIntegrationResponsesError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_integration_responses_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_integration_responses_request(
+        &self,
+        input: GetIntegrationResponsesRequest,
+    ) -> SignedRequest {
         let request_uri = format!(
             "/v2/apis/{api_id}/integrations/{integration_id}/integrationresponses",
             api_id = input.api_id,
@@ -7799,6 +9143,7 @@ impl ApiGatewayV2 for ApiGatewayV2Client {
         );
 
         let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
+        request.set_operation("GetIntegrationResponses");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -7810,31 +9155,56 @@ impl ApiGatewayV2 for ApiGatewayV2Client {
         }
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `get_integrations`, also returning the metadata of the response.
+    pub async fn get_integrations_with_metadata(
+        &self,
+        input: GetIntegrationsRequest,
+    ) -> Result<(GetIntegrationsResponse, ResponseMetadata), RusotoError<GetIntegrationsError>>
+    {
+        let request = self.build_get_integrations_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<GetIntegrationResponsesResponse, _>()?;
+                .deserialize::<GetIntegrationsResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(GetIntegrationResponsesError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(GetIntegrationsError::from_response(response))
         }
     }
 
-    /// <p>Gets the Integrations for an API.</p>
-    async fn get_integrations(
+    /// Presigns the request `get_integrations` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_integrations(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetIntegrationsRequest,
-    ) -> Result<GetIntegrationsResponse, RusotoError<GetIntegrationsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_integrations_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_integrations_request(&self, input: GetIntegrationsRequest) -> SignedRequest {
         let request_uri = format!("/v2/apis/{api_id}/integrations", api_id = input.api_id);
 
         let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
+        request.set_operation("GetIntegrations");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -7846,28 +9216,51 @@ impl ApiGatewayV2 for ApiGatewayV2Client {
         }
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `get_model`, also returning the metadata of the response.
+    pub async fn get_model_with_metadata(
+        &self,
+        input: GetModelRequest,
+    ) -> Result<(GetModelResponse, ResponseMetadata), RusotoError<GetModelError>> {
+        let request = self.build_get_model_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<GetIntegrationsResponse, _>()?;
+                .deserialize::<GetModelResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(GetIntegrationsError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(GetModelError::from_response(response))
         }
     }
 
-    /// <p>Gets a Model.</p>
-    async fn get_model(
+    /// Presigns the request `get_model` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_model(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetModelRequest,
-    ) -> Result<GetModelResponse, RusotoError<GetModelError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_model_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_model_request(&self, input: GetModelRequest) -> SignedRequest {
         let request_uri = format!(
             "/v2/apis/{api_id}/models/{model_id}",
             api_id = input.api_id,
@@ -7875,30 +9268,55 @@ impl ApiGatewayV2 for ApiGatewayV2Client {
         );
 
         let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
+        request.set_operation("GetModel");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
+        request
+    }
+
+    /// Calls `get_model_template`, also returning the metadata of the response.
+    pub async fn get_model_template_with_metadata(
+        &self,
+        input: GetModelTemplateRequest,
+    ) -> Result<(GetModelTemplateResponse, ResponseMetadata), RusotoError<GetModelTemplateError>>
+    {
+        let request = self.build_get_model_template_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<GetModelResponse, _>()?;
+                .deserialize::<GetModelTemplateResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(GetModelError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(GetModelTemplateError::from_response(response))
         }
     }
 
-    /// <p>Gets a model template.</p>
-    async fn get_model_template(
+    /// Presigns the request `get_model_template` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_model_template(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetModelTemplateRequest,
-    ) -> Result<GetModelTemplateResponse, RusotoError<GetModelTemplateError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_model_template_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_model_template_request(&self, input: GetModelTemplateRequest) -> SignedRequest {
         let request_uri = format!(
             "/v2/apis/{api_id}/models/{model_id}/template",
             api_id = input.api_id,
@@ -7906,33 +9324,58 @@ impl ApiGatewayV2 for ApiGatewayV2Client {
         );
 
         let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
+        request.set_operation("GetModelTemplate");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
+        request
+    }
+
+    /// Calls `get_models`, also returning the metadata of the response.
+    pub async fn get_models_with_metadata(
+        &self,
+        input: GetModelsRequest,
+    ) -> Result<(GetModelsResponse, ResponseMetadata), RusotoError<GetModelsError>> {
+        let request = self.build_get_models_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<GetModelTemplateResponse, _>()?;
+                .deserialize::<GetModelsResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(GetModelTemplateError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(GetModelsError::from_response(response))
         }
     }
 
-    /// <p>Gets the Models for an API.</p>
-    async fn get_models(
+    /// Presigns the request `get_models` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_models(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetModelsRequest,
-    ) -> Result<GetModelsResponse, RusotoError<GetModelsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_models_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_models_request(&self, input: GetModelsRequest) -> SignedRequest {
         let request_uri = format!("/v2/apis/{api_id}/models", api_id = input.api_id);
 
         let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
+        request.set_operation("GetModels");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -7944,28 +9387,51 @@ impl ApiGatewayV2 for ApiGatewayV2Client {
         }
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `get_route`, also returning the metadata of the response.
+    pub async fn get_route_with_metadata(
+        &self,
+        input: GetRouteRequest,
+    ) -> Result<(GetRouteResult, ResponseMetadata), RusotoError<GetRouteError>> {
+        let request = self.build_get_route_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<GetModelsResponse, _>()?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result =
+                proto::json::ResponsePayload::new(&response).deserialize::<GetRouteResult, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(GetModelsError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(GetRouteError::from_response(response))
         }
     }
 
-    /// <p>Gets a Route.</p>
-    async fn get_route(
+    /// Presigns the request `get_route` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_route(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetRouteRequest,
-    ) -> Result<GetRouteResult, RusotoError<GetRouteError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_route_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_route_request(&self, input: GetRouteRequest) -> SignedRequest {
         let request_uri = format!(
             "/v2/apis/{api_id}/routes/{route_id}",
             api_id = input.api_id,
@@ -7973,30 +9439,55 @@ impl ApiGatewayV2 for ApiGatewayV2Client {
         );
 
         let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
+        request.set_operation("GetRoute");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
+        request
+    }
+
+    /// Calls `get_route_response`, also returning the metadata of the response.
+    pub async fn get_route_response_with_metadata(
+        &self,
+        input: GetRouteResponseRequest,
+    ) -> Result<(GetRouteResponseResponse, ResponseMetadata), RusotoError<GetRouteResponseError>>
+    {
+        let request = self.build_get_route_response_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            let result =
-                proto::json::ResponsePayload::new(&response).deserialize::<GetRouteResult, _>()?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<GetRouteResponseResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(GetRouteError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(GetRouteResponseError::from_response(response))
         }
     }
 
-    /// <p>Gets a RouteResponse.</p>
-    async fn get_route_response(
+    /// Presigns the request `get_route_response` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_route_response(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetRouteResponseRequest,
-    ) -> Result<GetRouteResponseResponse, RusotoError<GetRouteResponseError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_route_response_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_route_response_request(&self, input: GetRouteResponseRequest) -> SignedRequest {
         let request_uri = format!(
             "/v2/apis/{api_id}/routes/{route_id}/routeresponses/{route_response_id}",
             api_id = input.api_id,
@@ -8005,30 +9496,55 @@ impl ApiGatewayV2 for ApiGatewayV2Client {
         );
 
         let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
+        request.set_operation("GetRouteResponse");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
+        request
+    }
+
+    /// Calls `get_route_responses`, also returning the metadata of the response.
+    pub async fn get_route_responses_with_metadata(
+        &self,
+        input: GetRouteResponsesRequest,
+    ) -> Result<(GetRouteResponsesResponse, ResponseMetadata), RusotoError<GetRouteResponsesError>>
+    {
+        let request = self.build_get_route_responses_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<GetRouteResponseResponse, _>()?;
+                .deserialize::<GetRouteResponsesResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(GetRouteResponseError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(GetRouteResponsesError::from_response(response))
         }
     }
 
-    /// <p>Gets the RouteResponses for a Route.</p>
-    async fn get_route_responses(
+    /// Presigns the request `get_route_responses` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_route_responses(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetRouteResponsesRequest,
-    ) -> Result<GetRouteResponsesResponse, RusotoError<GetRouteResponsesError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_route_responses_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_route_responses_request(&self, input: GetRouteResponsesRequest) -> SignedRequest {
         let request_uri = format!(
             "/v2/apis/{api_id}/routes/{route_id}/routeresponses",
             api_id = input.api_id,
@@ -8036,6 +9552,7 @@ impl ApiGatewayV2 for ApiGatewayV2Client {
         );
 
         let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
+        request.set_operation("GetRouteResponses");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -8047,31 +9564,55 @@ impl ApiGatewayV2 for ApiGatewayV2Client {
         }
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `get_routes`, also returning the metadata of the response.
+    pub async fn get_routes_with_metadata(
+        &self,
+        input: GetRoutesRequest,
+    ) -> Result<(GetRoutesResponse, ResponseMetadata), RusotoError<GetRoutesError>> {
+        let request = self.build_get_routes_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<GetRouteResponsesResponse, _>()?;
+                .deserialize::<GetRoutesResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(GetRouteResponsesError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(GetRoutesError::from_response(response))
         }
     }
 
-    /// <p>Gets the Routes for an API.</p>
-    async fn get_routes(
+    /// Presigns the request `get_routes` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_routes(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetRoutesRequest,
-    ) -> Result<GetRoutesResponse, RusotoError<GetRoutesError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_routes_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_routes_request(&self, input: GetRoutesRequest) -> SignedRequest {
         let request_uri = format!("/v2/apis/{api_id}/routes", api_id = input.api_id);
 
         let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
+        request.set_operation("GetRoutes");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -8083,28 +9624,51 @@ impl ApiGatewayV2 for ApiGatewayV2Client {
         }
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `get_stage`, also returning the metadata of the response.
+    pub async fn get_stage_with_metadata(
+        &self,
+        input: GetStageRequest,
+    ) -> Result<(GetStageResponse, ResponseMetadata), RusotoError<GetStageError>> {
+        let request = self.build_get_stage_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<GetRoutesResponse, _>()?;
+                .deserialize::<GetStageResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(GetRoutesError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(GetStageError::from_response(response))
         }
     }
 
-    /// <p>Gets a Stage.</p>
-    async fn get_stage(
+    /// Presigns the request `get_stage` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_stage(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetStageRequest,
-    ) -> Result<GetStageResponse, RusotoError<GetStageError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_stage_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_stage_request(&self, input: GetStageRequest) -> SignedRequest {
         let request_uri = format!(
             "/v2/apis/{api_id}/stages/{stage_name}",
             api_id = input.api_id,
@@ -8112,33 +9676,58 @@ impl ApiGatewayV2 for ApiGatewayV2Client {
         );
 
         let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
+        request.set_operation("GetStage");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
+        request
+    }
+
+    /// Calls `get_stages`, also returning the metadata of the response.
+    pub async fn get_stages_with_metadata(
+        &self,
+        input: GetStagesRequest,
+    ) -> Result<(GetStagesResponse, ResponseMetadata), RusotoError<GetStagesError>> {
+        let request = self.build_get_stages_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<GetStageResponse, _>()?;
+                .deserialize::<GetStagesResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(GetStageError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(GetStagesError::from_response(response))
         }
     }
 
-    /// <p>Gets the Stages for an API.</p>
-    async fn get_stages(
+    /// Presigns the request `get_stages` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_stages(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetStagesRequest,
-    ) -> Result<GetStagesResponse, RusotoError<GetStagesError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_stages_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_stages_request(&self, input: GetStagesRequest) -> SignedRequest {
         let request_uri = format!("/v2/apis/{api_id}/stages", api_id = input.api_id);
 
         let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
+        request.set_operation("GetStages");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -8150,58 +9739,106 @@ impl ApiGatewayV2 for ApiGatewayV2Client {
         }
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `get_tags`, also returning the metadata of the response.
+    pub async fn get_tags_with_metadata(
+        &self,
+        input: GetTagsRequest,
+    ) -> Result<(GetTagsResponse, ResponseMetadata), RusotoError<GetTagsError>> {
+        let request = self.build_get_tags_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<GetStagesResponse, _>()?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result =
+                proto::json::ResponsePayload::new(&response).deserialize::<GetTagsResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(GetStagesError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(GetTagsError::from_response(response))
         }
     }
 
-    /// <p>Gets a collection of Tag resources.</p>
-    async fn get_tags(
+    /// Presigns the request `get_tags` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_tags(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetTagsRequest,
-    ) -> Result<GetTagsResponse, RusotoError<GetTagsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_tags_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_tags_request(&self, input: GetTagsRequest) -> SignedRequest {
         let request_uri = format!("/v2/tags/{resource_arn}", resource_arn = input.resource_arn);
 
         let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
+        request.set_operation("GetTags");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
+        request
+    }
+
+    /// Calls `import_api`, also returning the metadata of the response.
+    pub async fn import_api_with_metadata(
+        &self,
+        input: ImportApiRequest,
+    ) -> Result<(ImportApiResponse, ResponseMetadata), RusotoError<ImportApiError>> {
+        let request = self.build_import_api_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
-        if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            let result =
-                proto::json::ResponsePayload::new(&response).deserialize::<GetTagsResponse, _>()?;
+        let metadata = ResponseMetadata::from_response(&response);
+        if response.status.as_u16() == 201 {
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ImportApiResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(GetTagsError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(ImportApiError::from_response(response))
         }
     }
 
-    /// <p>Imports an API.</p>
-    async fn import_api(
+    /// Presigns the request `import_api` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_import_api(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ImportApiRequest,
-    ) -> Result<ImportApiResponse, RusotoError<ImportApiError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_import_api_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_import_api_request(&self, input: ImportApiRequest) -> SignedRequest {
         let request_uri = "/v2/apis";
 
         let mut request = SignedRequest::new("PUT", "apigateway", &self.region, &request_uri);
+        request.set_operation("ImportApi");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -8216,31 +9853,55 @@ impl ApiGatewayV2 for ApiGatewayV2Client {
         }
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `reimport_api`, also returning the metadata of the response.
+    pub async fn reimport_api_with_metadata(
+        &self,
+        input: ReimportApiRequest,
+    ) -> Result<(ReimportApiResponse, ResponseMetadata), RusotoError<ReimportApiError>> {
+        let request = self.build_reimport_api_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 201 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<ImportApiResponse, _>()?;
+                .deserialize::<ReimportApiResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(ImportApiError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(ReimportApiError::from_response(response))
         }
     }
 
-    /// <p>Puts an Api resource.</p>
-    async fn reimport_api(
+    /// Presigns the request `reimport_api` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_reimport_api(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ReimportApiRequest,
-    ) -> Result<ReimportApiResponse, RusotoError<ReimportApiError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_reimport_api_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_reimport_api_request(&self, input: ReimportApiRequest) -> SignedRequest {
         let request_uri = format!("/v2/apis/{api_id}", api_id = input.api_id);
 
         let mut request = SignedRequest::new("PUT", "apigateway", &self.region, &request_uri);
+        request.set_operation("ReimportApi");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -8255,61 +9916,108 @@ impl ApiGatewayV2 for ApiGatewayV2Client {
         }
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `tag_resource`, also returning the metadata of the response.
+    pub async fn tag_resource_with_metadata(
+        &self,
+        input: TagResourceRequest,
+    ) -> Result<(TagResourceResponse, ResponseMetadata), RusotoError<TagResourceError>> {
+        let request = self.build_tag_resource_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 201 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<ReimportApiResponse, _>()?;
+                .deserialize::<TagResourceResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(ReimportApiError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(TagResourceError::from_response(response))
         }
     }
 
-    /// <p>Creates a new Tag resource to represent a tag.</p>
-    async fn tag_resource(
+    /// Presigns the request `tag_resource` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_tag_resource(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: TagResourceRequest,
-    ) -> Result<TagResourceResponse, RusotoError<TagResourceError>> {
-        let request_uri = format!("/v2/tags/{resource_arn}", resource_arn = input.resource_arn);
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_tag_resource_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_tag_resource_request(&self, input: TagResourceRequest) -> SignedRequest {
+        let request_uri = format!("/v2/tags/{resource_arn}", resource_arn = input.resource_arn);
 
         let mut request = SignedRequest::new("POST", "apigateway", &self.region, &request_uri);
+        request.set_operation("TagResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `untag_resource`, also returning the metadata of the response.
+    pub async fn untag_resource_with_metadata(
+        &self,
+        input: UntagResourceRequest,
+    ) -> Result<((), ResponseMetadata), RusotoError<UntagResourceError>> {
+        let request = self.build_untag_resource_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
-        if response.status.as_u16() == 201 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<TagResourceResponse, _>()?;
+        let metadata = ResponseMetadata::from_response(&response);
+        if response.status.as_u16() == 204 {
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = ::std::mem::drop(response);
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(TagResourceError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(UntagResourceError::from_response(response))
         }
     }
 
-    /// <p>Deletes a Tag.</p>
-    async fn untag_resource(
+    /// Presigns the request `untag_resource` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_untag_resource(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UntagResourceRequest,
-    ) -> Result<(), RusotoError<UntagResourceError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_untag_resource_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_untag_resource_request(&self, input: UntagResourceRequest) -> SignedRequest {
         let request_uri = format!("/v2/tags/{resource_arn}", resource_arn = input.resource_arn);
 
         let mut request = SignedRequest::new("DELETE", "apigateway", &self.region, &request_uri);
+        request.set_operation("UntagResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -8318,57 +10026,106 @@ impl ApiGatewayV2 for ApiGatewayV2Client {
         }
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `update_api`, also returning the metadata of the response.
+    pub async fn update_api_with_metadata(
+        &self,
+        input: UpdateApiRequest,
+    ) -> Result<(UpdateApiResponse, ResponseMetadata), RusotoError<UpdateApiError>> {
+        let request = self.build_update_api_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
-        if response.status.as_u16() == 204 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            let result = ::std::mem::drop(response);
+        let metadata = ResponseMetadata::from_response(&response);
+        if response.status.as_u16() == 200 {
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<UpdateApiResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(UntagResourceError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(UpdateApiError::from_response(response))
         }
     }
 
-    /// <p>Updates an Api resource.</p>
-    async fn update_api(
+    /// Presigns the request `update_api` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_api(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UpdateApiRequest,
-    ) -> Result<UpdateApiResponse, RusotoError<UpdateApiError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_api_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_api_request(&self, input: UpdateApiRequest) -> SignedRequest {
         let request_uri = format!("/v2/apis/{api_id}", api_id = input.api_id);
 
         let mut request = SignedRequest::new("PATCH", "apigateway", &self.region, &request_uri);
+        request.set_operation("UpdateApi");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `update_api_mapping`, also returning the metadata of the response.
+    pub async fn update_api_mapping_with_metadata(
+        &self,
+        input: UpdateApiMappingRequest,
+    ) -> Result<(UpdateApiMappingResponse, ResponseMetadata), RusotoError<UpdateApiMappingError>>
+    {
+        let request = self.build_update_api_mapping_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<UpdateApiResponse, _>()?;
+                .deserialize::<UpdateApiMappingResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(UpdateApiError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(UpdateApiMappingError::from_response(response))
         }
     }
 
-    /// <p>The API mapping.</p>
-    async fn update_api_mapping(
+    /// Presigns the request `update_api_mapping` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_api_mapping(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UpdateApiMappingRequest,
-    ) -> Result<UpdateApiMappingResponse, RusotoError<UpdateApiMappingError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_api_mapping_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_api_mapping_request(&self, input: UpdateApiMappingRequest) -> SignedRequest {
         let request_uri = format!(
             "/v2/domainnames/{domain_name}/apimappings/{api_mapping_id}",
             api_mapping_id = input.api_mapping_id,
@@ -8376,33 +10133,58 @@ impl ApiGatewayV2 for ApiGatewayV2Client {
         );
 
         let mut request = SignedRequest::new("PATCH", "apigateway", &self.region, &request_uri);
+        request.set_operation("UpdateApiMapping");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `update_authorizer`, also returning the metadata of the response.
+    pub async fn update_authorizer_with_metadata(
+        &self,
+        input: UpdateAuthorizerRequest,
+    ) -> Result<(UpdateAuthorizerResponse, ResponseMetadata), RusotoError<UpdateAuthorizerError>>
+    {
+        let request = self.build_update_authorizer_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<UpdateApiMappingResponse, _>()?;
+                .deserialize::<UpdateAuthorizerResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(UpdateApiMappingError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(UpdateAuthorizerError::from_response(response))
         }
     }
 
-    /// <p>Updates an Authorizer.</p>
-    async fn update_authorizer(
+    /// Presigns the request `update_authorizer` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_authorizer(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UpdateAuthorizerRequest,
-    ) -> Result<UpdateAuthorizerResponse, RusotoError<UpdateAuthorizerError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_authorizer_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_authorizer_request(&self, input: UpdateAuthorizerRequest) -> SignedRequest {
         let request_uri = format!(
             "/v2/apis/{api_id}/authorizers/{authorizer_id}",
             api_id = input.api_id,
@@ -8410,33 +10192,58 @@ impl ApiGatewayV2 for ApiGatewayV2Client {
         );
 
         let mut request = SignedRequest::new("PATCH", "apigateway", &self.region, &request_uri);
+        request.set_operation("UpdateAuthorizer");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `update_deployment`, also returning the metadata of the response.
+    pub async fn update_deployment_with_metadata(
+        &self,
+        input: UpdateDeploymentRequest,
+    ) -> Result<(UpdateDeploymentResponse, ResponseMetadata), RusotoError<UpdateDeploymentError>>
+    {
+        let request = self.build_update_deployment_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<UpdateAuthorizerResponse, _>()?;
+                .deserialize::<UpdateDeploymentResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(UpdateAuthorizerError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(UpdateDeploymentError::from_response(response))
         }
     }
 
-    /// <p>Updates a Deployment.</p>
-    async fn update_deployment(
+    /// Presigns the request `update_deployment` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_deployment(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UpdateDeploymentRequest,
-    ) -> Result<UpdateDeploymentResponse, RusotoError<UpdateDeploymentError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_deployment_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_deployment_request(&self, input: UpdateDeploymentRequest) -> SignedRequest {
         let request_uri = format!(
             "/v2/apis/{api_id}/deployments/{deployment_id}",
             api_id = input.api_id,
@@ -8444,66 +10251,116 @@ impl ApiGatewayV2 for ApiGatewayV2Client {
         );
 
         let mut request = SignedRequest::new("PATCH", "apigateway", &self.region, &request_uri);
+        request.set_operation("UpdateDeployment");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `update_domain_name`, also returning the metadata of the response.
+    pub async fn update_domain_name_with_metadata(
+        &self,
+        input: UpdateDomainNameRequest,
+    ) -> Result<(UpdateDomainNameResponse, ResponseMetadata), RusotoError<UpdateDomainNameError>>
+    {
+        let request = self.build_update_domain_name_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<UpdateDeploymentResponse, _>()?;
+                .deserialize::<UpdateDomainNameResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(UpdateDeploymentError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(UpdateDomainNameError::from_response(response))
         }
     }
 
-    /// <p>Updates a domain name.</p>
-    async fn update_domain_name(
+    /// Presigns the request `update_domain_name` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_domain_name(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UpdateDomainNameRequest,
-    ) -> Result<UpdateDomainNameResponse, RusotoError<UpdateDomainNameError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_domain_name_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_domain_name_request(&self, input: UpdateDomainNameRequest) -> SignedRequest {
         let request_uri = format!(
             "/v2/domainnames/{domain_name}",
             domain_name = input.domain_name
         );
 
         let mut request = SignedRequest::new("PATCH", "apigateway", &self.region, &request_uri);
+        request.set_operation("UpdateDomainName");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `update_integration`, also returning the metadata of the response.
+    pub async fn update_integration_with_metadata(
+        &self,
+        input: UpdateIntegrationRequest,
+    ) -> Result<(UpdateIntegrationResult, ResponseMetadata), RusotoError<UpdateIntegrationError>>
+    {
+        let request = self.build_update_integration_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<UpdateDomainNameResponse, _>()?;
+                .deserialize::<UpdateIntegrationResult, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(UpdateDomainNameError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(UpdateIntegrationError::from_response(response))
         }
     }
 
-    /// <p>Updates an Integration.</p>
-    async fn update_integration(
+    /// Presigns the request `update_integration` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_integration(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UpdateIntegrationRequest,
-    ) -> Result<UpdateIntegrationResult, RusotoError<UpdateIntegrationError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_integration_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_integration_request(&self, input: UpdateIntegrationRequest) -> SignedRequest {
         let request_uri = format!(
             "/v2/apis/{api_id}/integrations/{integration_id}",
             api_id = input.api_id,
@@ -8511,64 +10368,117 @@ impl ApiGatewayV2 for ApiGatewayV2Client {
         );
 
         let mut request = SignedRequest::new("PATCH", "apigateway", &self.region, &request_uri);
+        request.set_operation("UpdateIntegration");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `update_integration_response`, also returning the metadata of the response.
+    pub async fn update_integration_response_with_metadata(
+        &self,
+        input: UpdateIntegrationResponseRequest,
+    ) -> Result<
+        (UpdateIntegrationResponseResponse, ResponseMetadata),
+        RusotoError<UpdateIntegrationResponseError>,
+    > {
+        let request = self.build_update_integration_response_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<UpdateIntegrationResult, _>()?;
+                .deserialize::<UpdateIntegrationResponseResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(UpdateIntegrationError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(UpdateIntegrationResponseError::from_response(response))
         }
     }
 
-    /// <p>Updates an IntegrationResponses.</p>
-    async fn update_integration_response(
+    /// Presigns the request `update_integration_response` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_integration_response(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UpdateIntegrationResponseRequest,
-    ) -> Result<UpdateIntegrationResponseResponse, RusotoError<UpdateIntegrationResponseError>>
-    {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_integration_response_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_integration_response_request(
+        &self,
+        input: UpdateIntegrationResponseRequest,
+    ) -> SignedRequest {
         let request_uri = format!("/v2/apis/{api_id}/integrations/{integration_id}/integrationresponses/{integration_response_id}", api_id = input.api_id, integration_id = input.integration_id, integration_response_id = input.integration_response_id);
 
         let mut request = SignedRequest::new("PATCH", "apigateway", &self.region, &request_uri);
+        request.set_operation("UpdateIntegrationResponse");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `update_model`, also returning the metadata of the response.
+    pub async fn update_model_with_metadata(
+        &self,
+        input: UpdateModelRequest,
+    ) -> Result<(UpdateModelResponse, ResponseMetadata), RusotoError<UpdateModelError>> {
+        let request = self.build_update_model_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<UpdateIntegrationResponseResponse, _>()?;
+                .deserialize::<UpdateModelResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(UpdateIntegrationResponseError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(UpdateModelError::from_response(response))
         }
     }
 
-    /// <p>Updates a Model.</p>
-    async fn update_model(
+    /// Presigns the request `update_model` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_model(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UpdateModelRequest,
-    ) -> Result<UpdateModelResponse, RusotoError<UpdateModelError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_model_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_model_request(&self, input: UpdateModelRequest) -> SignedRequest {
         let request_uri = format!(
             "/v2/apis/{api_id}/models/{model_id}",
             api_id = input.api_id,
@@ -8576,33 +10486,57 @@ impl ApiGatewayV2 for ApiGatewayV2Client {
         );
 
         let mut request = SignedRequest::new("PATCH", "apigateway", &self.region, &request_uri);
+        request.set_operation("UpdateModel");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `update_route`, also returning the metadata of the response.
+    pub async fn update_route_with_metadata(
+        &self,
+        input: UpdateRouteRequest,
+    ) -> Result<(UpdateRouteResult, ResponseMetadata), RusotoError<UpdateRouteError>> {
+        let request = self.build_update_route_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<UpdateModelResponse, _>()?;
+                .deserialize::<UpdateRouteResult, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(UpdateModelError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(UpdateRouteError::from_response(response))
         }
     }
 
-    /// <p>Updates a Route.</p>
-    async fn update_route(
+    /// Presigns the request `update_route` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_route(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UpdateRouteRequest,
-    ) -> Result<UpdateRouteResult, RusotoError<UpdateRouteError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_route_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_route_request(&self, input: UpdateRouteRequest) -> SignedRequest {
         let request_uri = format!(
             "/v2/apis/{api_id}/routes/{route_id}",
             api_id = input.api_id,
@@ -8610,33 +10544,63 @@ impl ApiGatewayV2 for ApiGatewayV2Client {
         );
 
         let mut request = SignedRequest::new("PATCH", "apigateway", &self.region, &request_uri);
+        request.set_operation("UpdateRoute");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `update_route_response`, also returning the metadata of the response.
+    pub async fn update_route_response_with_metadata(
+        &self,
+        input: UpdateRouteResponseRequest,
+    ) -> Result<
+        (UpdateRouteResponseResponse, ResponseMetadata),
+        RusotoError<UpdateRouteResponseError>,
+    > {
+        let request = self.build_update_route_response_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<UpdateRouteResult, _>()?;
+                .deserialize::<UpdateRouteResponseResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(UpdateRouteError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(UpdateRouteResponseError::from_response(response))
         }
     }
 
-    /// <p>Updates a RouteResponse.</p>
-    async fn update_route_response(
+    /// Presigns the request `update_route_response` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_route_response(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UpdateRouteResponseRequest,
-    ) -> Result<UpdateRouteResponseResponse, RusotoError<UpdateRouteResponseError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_route_response_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_route_response_request(
+        &self,
+        input: UpdateRouteResponseRequest,
+    ) -> SignedRequest {
         let request_uri = format!(
             "/v2/apis/{api_id}/routes/{route_id}/routeresponses/{route_response_id}",
             api_id = input.api_id,
@@ -8645,33 +10609,57 @@ impl ApiGatewayV2 for ApiGatewayV2Client {
         );
 
         let mut request = SignedRequest::new("PATCH", "apigateway", &self.region, &request_uri);
+        request.set_operation("UpdateRouteResponse");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `update_stage`, also returning the metadata of the response.
+    pub async fn update_stage_with_metadata(
+        &self,
+        input: UpdateStageRequest,
+    ) -> Result<(UpdateStageResponse, ResponseMetadata), RusotoError<UpdateStageError>> {
+        let request = self.build_update_stage_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<UpdateRouteResponseResponse, _>()?;
+                .deserialize::<UpdateStageResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(UpdateRouteResponseError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(UpdateStageError::from_response(response))
         }
     }
 
-    /// <p>Updates a Stage.</p>
-    async fn update_stage(
+    /// Presigns the request `update_stage` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_stage(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UpdateStageRequest,
-    ) -> Result<UpdateStageResponse, RusotoError<UpdateStageError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_stage_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_stage_request(&self, input: UpdateStageRequest) -> SignedRequest {
         let request_uri = format!(
             "/v2/apis/{api_id}/stages/{stage_name}",
             api_id = input.api_id,
@@ -8679,25 +10667,1006 @@ impl ApiGatewayV2 for ApiGatewayV2Client {
         );
 
         let mut request = SignedRequest::new("PATCH", "apigateway", &self.region, &request_uri);
+        request.set_operation("UpdateStage");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
-        let mut response = self
-            .client
-            .sign_and_dispatch(request)
-            .await
-            .map_err(RusotoError::from)?;
-        if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<UpdateStageResponse, _>()?;
+        request
+    }
 
-            Ok(result)
-        } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(UpdateStageError::from_response(response))
-        }
+    /// The pages of `GetApis` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `MaxResults` of `input`.
+    pub fn get_apis_pages(
+        &self,
+        input: GetApisRequest,
+    ) -> impl futures::Stream<Item = Result<GetApisResponse, RusotoError<GetApisError>>> + Send
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: ApiGatewayV2Client, input| async move { client.get_apis(input).await },
+            |input: &GetApisRequest, output: &GetApisResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(GetApisRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `Items` of every page of `GetApis` results for `input`, as `get_apis_pages` requests them.
+    pub fn get_apis_stream(
+        &self,
+        input: GetApisRequest,
+    ) -> impl futures::Stream<Item = Result<Api, RusotoError<GetApisError>>> + Send {
+        rusoto_core::pagination::items(self.get_apis_pages(input), |page| {
+            page.items.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `GetAuthorizers` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `MaxResults` of `input`.
+    pub fn get_authorizers_pages(
+        &self,
+        input: GetAuthorizersRequest,
+    ) -> impl futures::Stream<Item = Result<GetAuthorizersResponse, RusotoError<GetAuthorizersError>>>
+           + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: ApiGatewayV2Client, input| async move { client.get_authorizers(input).await },
+            |input: &GetAuthorizersRequest, output: &GetAuthorizersResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(GetAuthorizersRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `Items` of every page of `GetAuthorizers` results for `input`, as `get_authorizers_pages` requests them.
+    pub fn get_authorizers_stream(
+        &self,
+        input: GetAuthorizersRequest,
+    ) -> impl futures::Stream<Item = Result<Authorizer, RusotoError<GetAuthorizersError>>> + Send
+    {
+        rusoto_core::pagination::items(self.get_authorizers_pages(input), |page| {
+            page.items.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `GetDeployments` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `MaxResults` of `input`.
+    pub fn get_deployments_pages(
+        &self,
+        input: GetDeploymentsRequest,
+    ) -> impl futures::Stream<Item = Result<GetDeploymentsResponse, RusotoError<GetDeploymentsError>>>
+           + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: ApiGatewayV2Client, input| async move { client.get_deployments(input).await },
+            |input: &GetDeploymentsRequest, output: &GetDeploymentsResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(GetDeploymentsRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `Items` of every page of `GetDeployments` results for `input`, as `get_deployments_pages` requests them.
+    pub fn get_deployments_stream(
+        &self,
+        input: GetDeploymentsRequest,
+    ) -> impl futures::Stream<Item = Result<Deployment, RusotoError<GetDeploymentsError>>> + Send
+    {
+        rusoto_core::pagination::items(self.get_deployments_pages(input), |page| {
+            page.items.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `GetDomainNames` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `MaxResults` of `input`.
+    pub fn get_domain_names_pages(
+        &self,
+        input: GetDomainNamesRequest,
+    ) -> impl futures::Stream<Item = Result<GetDomainNamesResponse, RusotoError<GetDomainNamesError>>>
+           + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: ApiGatewayV2Client, input| async move { client.get_domain_names(input).await },
+            |input: &GetDomainNamesRequest, output: &GetDomainNamesResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(GetDomainNamesRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `Items` of every page of `GetDomainNames` results for `input`, as `get_domain_names_pages` requests them.
+    pub fn get_domain_names_stream(
+        &self,
+        input: GetDomainNamesRequest,
+    ) -> impl futures::Stream<Item = Result<DomainName, RusotoError<GetDomainNamesError>>> + Send
+    {
+        rusoto_core::pagination::items(self.get_domain_names_pages(input), |page| {
+            page.items.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `GetIntegrationResponses` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `MaxResults` of `input`.
+    pub fn get_integration_responses_pages(
+        &self,
+        input: GetIntegrationResponsesRequest,
+    ) -> impl futures::Stream<
+        Item = Result<GetIntegrationResponsesResponse, RusotoError<GetIntegrationResponsesError>>,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: ApiGatewayV2Client, input| async move {
+                client.get_integration_responses(input).await
+            },
+            |input: &GetIntegrationResponsesRequest, output: &GetIntegrationResponsesResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(GetIntegrationResponsesRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `Items` of every page of `GetIntegrationResponses` results for `input`, as `get_integration_responses_pages` requests them.
+    pub fn get_integration_responses_stream(
+        &self,
+        input: GetIntegrationResponsesRequest,
+    ) -> impl futures::Stream<
+        Item = Result<IntegrationResponse, RusotoError<GetIntegrationResponsesError>>,
+    > + Send {
+        rusoto_core::pagination::items(self.get_integration_responses_pages(input), |page| {
+            page.items.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `GetIntegrations` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `MaxResults` of `input`.
+    pub fn get_integrations_pages(
+        &self,
+        input: GetIntegrationsRequest,
+    ) -> impl futures::Stream<
+        Item = Result<GetIntegrationsResponse, RusotoError<GetIntegrationsError>>,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: ApiGatewayV2Client, input| async move { client.get_integrations(input).await },
+            |input: &GetIntegrationsRequest, output: &GetIntegrationsResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(GetIntegrationsRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `Items` of every page of `GetIntegrations` results for `input`, as `get_integrations_pages` requests them.
+    pub fn get_integrations_stream(
+        &self,
+        input: GetIntegrationsRequest,
+    ) -> impl futures::Stream<Item = Result<Integration, RusotoError<GetIntegrationsError>>> + Send
+    {
+        rusoto_core::pagination::items(self.get_integrations_pages(input), |page| {
+            page.items.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `GetModels` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `MaxResults` of `input`.
+    pub fn get_models_pages(
+        &self,
+        input: GetModelsRequest,
+    ) -> impl futures::Stream<Item = Result<GetModelsResponse, RusotoError<GetModelsError>>> + Send
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: ApiGatewayV2Client, input| async move { client.get_models(input).await },
+            |input: &GetModelsRequest, output: &GetModelsResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(GetModelsRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `Items` of every page of `GetModels` results for `input`, as `get_models_pages` requests them.
+    pub fn get_models_stream(
+        &self,
+        input: GetModelsRequest,
+    ) -> impl futures::Stream<Item = Result<Model, RusotoError<GetModelsError>>> + Send {
+        rusoto_core::pagination::items(self.get_models_pages(input), |page| {
+            page.items.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `GetRouteResponses` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `MaxResults` of `input`.
+    pub fn get_route_responses_pages(
+        &self,
+        input: GetRouteResponsesRequest,
+    ) -> impl futures::Stream<
+        Item = Result<GetRouteResponsesResponse, RusotoError<GetRouteResponsesError>>,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: ApiGatewayV2Client, input| async move { client.get_route_responses(input).await },
+            |input: &GetRouteResponsesRequest, output: &GetRouteResponsesResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(GetRouteResponsesRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `Items` of every page of `GetRouteResponses` results for `input`, as `get_route_responses_pages` requests them.
+    pub fn get_route_responses_stream(
+        &self,
+        input: GetRouteResponsesRequest,
+    ) -> impl futures::Stream<Item = Result<RouteResponse, RusotoError<GetRouteResponsesError>>> + Send
+    {
+        rusoto_core::pagination::items(self.get_route_responses_pages(input), |page| {
+            page.items.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `GetRoutes` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `MaxResults` of `input`.
+    pub fn get_routes_pages(
+        &self,
+        input: GetRoutesRequest,
+    ) -> impl futures::Stream<Item = Result<GetRoutesResponse, RusotoError<GetRoutesError>>> + Send
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: ApiGatewayV2Client, input| async move { client.get_routes(input).await },
+            |input: &GetRoutesRequest, output: &GetRoutesResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(GetRoutesRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `Items` of every page of `GetRoutes` results for `input`, as `get_routes_pages` requests them.
+    pub fn get_routes_stream(
+        &self,
+        input: GetRoutesRequest,
+    ) -> impl futures::Stream<Item = Result<Route, RusotoError<GetRoutesError>>> + Send {
+        rusoto_core::pagination::items(self.get_routes_pages(input), |page| {
+            page.items.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `GetStages` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `MaxResults` of `input`.
+    pub fn get_stages_pages(
+        &self,
+        input: GetStagesRequest,
+    ) -> impl futures::Stream<Item = Result<GetStagesResponse, RusotoError<GetStagesError>>> + Send
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: ApiGatewayV2Client, input| async move { client.get_stages(input).await },
+            |input: &GetStagesRequest, output: &GetStagesResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(GetStagesRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `Items` of every page of `GetStages` results for `input`, as `get_stages_pages` requests them.
+    pub fn get_stages_stream(
+        &self,
+        input: GetStagesRequest,
+    ) -> impl futures::Stream<Item = Result<Stage, RusotoError<GetStagesError>>> + Send {
+        rusoto_core::pagination::items(self.get_stages_pages(input), |page| {
+            page.items.unwrap_or_default()
+        })
+    }
+}
+
+#[async_trait]
+impl ApiGatewayV2 for ApiGatewayV2Client {
+    /// <p>Creates an Api resource.</p>
+    async fn create_api(
+        &self,
+        input: CreateApiRequest,
+    ) -> Result<CreateApiResponse, RusotoError<CreateApiError>> {
+        self.create_api_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Creates an API mapping.</p>
+    async fn create_api_mapping(
+        &self,
+        input: CreateApiMappingRequest,
+    ) -> Result<CreateApiMappingResponse, RusotoError<CreateApiMappingError>> {
+        self.create_api_mapping_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Creates an Authorizer for an API.</p>
+    async fn create_authorizer(
+        &self,
+        input: CreateAuthorizerRequest,
+    ) -> Result<CreateAuthorizerResponse, RusotoError<CreateAuthorizerError>> {
+        self.create_authorizer_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Creates a Deployment for an API.</p>
+    async fn create_deployment(
+        &self,
+        input: CreateDeploymentRequest,
+    ) -> Result<CreateDeploymentResponse, RusotoError<CreateDeploymentError>> {
+        self.create_deployment_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Creates a domain name.</p>
+    async fn create_domain_name(
+        &self,
+        input: CreateDomainNameRequest,
+    ) -> Result<CreateDomainNameResponse, RusotoError<CreateDomainNameError>> {
+        self.create_domain_name_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Creates an Integration.</p>
+    async fn create_integration(
+        &self,
+        input: CreateIntegrationRequest,
+    ) -> Result<CreateIntegrationResult, RusotoError<CreateIntegrationError>> {
+        self.create_integration_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Creates an IntegrationResponses.</p>
+    async fn create_integration_response(
+        &self,
+        input: CreateIntegrationResponseRequest,
+    ) -> Result<CreateIntegrationResponseResponse, RusotoError<CreateIntegrationResponseError>>
+    {
+        self.create_integration_response_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Creates a Model for an API.</p>
+    async fn create_model(
+        &self,
+        input: CreateModelRequest,
+    ) -> Result<CreateModelResponse, RusotoError<CreateModelError>> {
+        self.create_model_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Creates a Route for an API.</p>
+    async fn create_route(
+        &self,
+        input: CreateRouteRequest,
+    ) -> Result<CreateRouteResult, RusotoError<CreateRouteError>> {
+        self.create_route_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Creates a RouteResponse for a Route.</p>
+    async fn create_route_response(
+        &self,
+        input: CreateRouteResponseRequest,
+    ) -> Result<CreateRouteResponseResponse, RusotoError<CreateRouteResponseError>> {
+        self.create_route_response_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Creates a Stage for an API.</p>
+    async fn create_stage(
+        &self,
+        input: CreateStageRequest,
+    ) -> Result<CreateStageResponse, RusotoError<CreateStageError>> {
+        self.create_stage_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes an Api resource.</p>
+    async fn delete_api(&self, input: DeleteApiRequest) -> Result<(), RusotoError<DeleteApiError>> {
+        self.delete_api_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes an API mapping.</p>
+    async fn delete_api_mapping(
+        &self,
+        input: DeleteApiMappingRequest,
+    ) -> Result<(), RusotoError<DeleteApiMappingError>> {
+        self.delete_api_mapping_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes an Authorizer.</p>
+    async fn delete_authorizer(
+        &self,
+        input: DeleteAuthorizerRequest,
+    ) -> Result<(), RusotoError<DeleteAuthorizerError>> {
+        self.delete_authorizer_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes a CORS configuration.</p>
+    async fn delete_cors_configuration(
+        &self,
+        input: DeleteCorsConfigurationRequest,
+    ) -> Result<(), RusotoError<DeleteCorsConfigurationError>> {
+        self.delete_cors_configuration_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes a Deployment.</p>
+    async fn delete_deployment(
+        &self,
+        input: DeleteDeploymentRequest,
+    ) -> Result<(), RusotoError<DeleteDeploymentError>> {
+        self.delete_deployment_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes a domain name.</p>
+    async fn delete_domain_name(
+        &self,
+        input: DeleteDomainNameRequest,
+    ) -> Result<(), RusotoError<DeleteDomainNameError>> {
+        self.delete_domain_name_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes an Integration.</p>
+    async fn delete_integration(
+        &self,
+        input: DeleteIntegrationRequest,
+    ) -> Result<(), RusotoError<DeleteIntegrationError>> {
+        self.delete_integration_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes an IntegrationResponses.</p>
+    async fn delete_integration_response(
+        &self,
+        input: DeleteIntegrationResponseRequest,
+    ) -> Result<(), RusotoError<DeleteIntegrationResponseError>> {
+        self.delete_integration_response_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes a Model.</p>
+    async fn delete_model(
+        &self,
+        input: DeleteModelRequest,
+    ) -> Result<(), RusotoError<DeleteModelError>> {
+        self.delete_model_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes a Route.</p>
+    async fn delete_route(
+        &self,
+        input: DeleteRouteRequest,
+    ) -> Result<(), RusotoError<DeleteRouteError>> {
+        self.delete_route_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes a RouteResponse.</p>
+    async fn delete_route_response(
+        &self,
+        input: DeleteRouteResponseRequest,
+    ) -> Result<(), RusotoError<DeleteRouteResponseError>> {
+        self.delete_route_response_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes the RouteSettings for a stage.</p>
+    async fn delete_route_settings(
+        &self,
+        input: DeleteRouteSettingsRequest,
+    ) -> Result<(), RusotoError<DeleteRouteSettingsError>> {
+        self.delete_route_settings_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes a Stage.</p>
+    async fn delete_stage(
+        &self,
+        input: DeleteStageRequest,
+    ) -> Result<(), RusotoError<DeleteStageError>> {
+        self.delete_stage_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Gets an Api resource.</p>
+    async fn get_api(
+        &self,
+        input: GetApiRequest,
+    ) -> Result<GetApiResponse, RusotoError<GetApiError>> {
+        self.get_api_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Gets an API mapping.</p>
+    async fn get_api_mapping(
+        &self,
+        input: GetApiMappingRequest,
+    ) -> Result<GetApiMappingResponse, RusotoError<GetApiMappingError>> {
+        self.get_api_mapping_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Gets API mappings.</p>
+    async fn get_api_mappings(
+        &self,
+        input: GetApiMappingsRequest,
+    ) -> Result<GetApiMappingsResponse, RusotoError<GetApiMappingsError>> {
+        self.get_api_mappings_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Gets a collection of Api resources.</p>
+    async fn get_apis(
+        &self,
+        input: GetApisRequest,
+    ) -> Result<GetApisResponse, RusotoError<GetApisError>> {
+        self.get_apis_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Gets an Authorizer.</p>
+    async fn get_authorizer(
+        &self,
+        input: GetAuthorizerRequest,
+    ) -> Result<GetAuthorizerResponse, RusotoError<GetAuthorizerError>> {
+        self.get_authorizer_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Gets the Authorizers for an API.</p>
+    async fn get_authorizers(
+        &self,
+        input: GetAuthorizersRequest,
+    ) -> Result<GetAuthorizersResponse, RusotoError<GetAuthorizersError>> {
+        self.get_authorizers_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Gets a Deployment.</p>
+    async fn get_deployment(
+        &self,
+        input: GetDeploymentRequest,
+    ) -> Result<GetDeploymentResponse, RusotoError<GetDeploymentError>> {
+        self.get_deployment_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Gets the Deployments for an API.</p>
+    async fn get_deployments(
+        &self,
+        input: GetDeploymentsRequest,
+    ) -> Result<GetDeploymentsResponse, RusotoError<GetDeploymentsError>> {
+        self.get_deployments_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Gets a domain name.</p>
+    async fn get_domain_name(
+        &self,
+        input: GetDomainNameRequest,
+    ) -> Result<GetDomainNameResponse, RusotoError<GetDomainNameError>> {
+        self.get_domain_name_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Gets the domain names for an AWS account.</p>
+    async fn get_domain_names(
+        &self,
+        input: GetDomainNamesRequest,
+    ) -> Result<GetDomainNamesResponse, RusotoError<GetDomainNamesError>> {
+        self.get_domain_names_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Gets an Integration.</p>
+    async fn get_integration(
+        &self,
+        input: GetIntegrationRequest,
+    ) -> Result<GetIntegrationResult, RusotoError<GetIntegrationError>> {
+        self.get_integration_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Gets an IntegrationResponses.</p>
+    async fn get_integration_response(
+        &self,
+        input: GetIntegrationResponseRequest,
+    ) -> Result<GetIntegrationResponseResponse, RusotoError<GetIntegrationResponseError>> {
+        self.get_integration_response_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Gets the IntegrationResponses for an Integration.</p>
+    async fn get_integration_responses(
+        &self,
+        input: GetIntegrationResponsesRequest,
+    ) -> Result<GetIntegrationResponsesResponse, RusotoError<GetIntegrationResponsesError>> {
+        self.get_integration_responses_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Gets the Integrations for an API.</p>
+    async fn get_integrations(
+        &self,
+        input: GetIntegrationsRequest,
+    ) -> Result<GetIntegrationsResponse, RusotoError<GetIntegrationsError>> {
+        self.get_integrations_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Gets a Model.</p>
+    async fn get_model(
+        &self,
+        input: GetModelRequest,
+    ) -> Result<GetModelResponse, RusotoError<GetModelError>> {
+        self.get_model_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Gets a model template.</p>
+    async fn get_model_template(
+        &self,
+        input: GetModelTemplateRequest,
+    ) -> Result<GetModelTemplateResponse, RusotoError<GetModelTemplateError>> {
+        self.get_model_template_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Gets the Models for an API.</p>
+    async fn get_models(
+        &self,
+        input: GetModelsRequest,
+    ) -> Result<GetModelsResponse, RusotoError<GetModelsError>> {
+        self.get_models_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Gets a Route.</p>
+    async fn get_route(
+        &self,
+        input: GetRouteRequest,
+    ) -> Result<GetRouteResult, RusotoError<GetRouteError>> {
+        self.get_route_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Gets a RouteResponse.</p>
+    async fn get_route_response(
+        &self,
+        input: GetRouteResponseRequest,
+    ) -> Result<GetRouteResponseResponse, RusotoError<GetRouteResponseError>> {
+        self.get_route_response_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Gets the RouteResponses for a Route.</p>
+    async fn get_route_responses(
+        &self,
+        input: GetRouteResponsesRequest,
+    ) -> Result<GetRouteResponsesResponse, RusotoError<GetRouteResponsesError>> {
+        self.get_route_responses_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Gets the Routes for an API.</p>
+    async fn get_routes(
+        &self,
+        input: GetRoutesRequest,
+    ) -> Result<GetRoutesResponse, RusotoError<GetRoutesError>> {
+        self.get_routes_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Gets a Stage.</p>
+    async fn get_stage(
+        &self,
+        input: GetStageRequest,
+    ) -> Result<GetStageResponse, RusotoError<GetStageError>> {
+        self.get_stage_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Gets the Stages for an API.</p>
+    async fn get_stages(
+        &self,
+        input: GetStagesRequest,
+    ) -> Result<GetStagesResponse, RusotoError<GetStagesError>> {
+        self.get_stages_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Gets a collection of Tag resources.</p>
+    async fn get_tags(
+        &self,
+        input: GetTagsRequest,
+    ) -> Result<GetTagsResponse, RusotoError<GetTagsError>> {
+        self.get_tags_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Imports an API.</p>
+    async fn import_api(
+        &self,
+        input: ImportApiRequest,
+    ) -> Result<ImportApiResponse, RusotoError<ImportApiError>> {
+        self.import_api_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Puts an Api resource.</p>
+    async fn reimport_api(
+        &self,
+        input: ReimportApiRequest,
+    ) -> Result<ReimportApiResponse, RusotoError<ReimportApiError>> {
+        self.reimport_api_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Creates a new Tag resource to represent a tag.</p>
+    async fn tag_resource(
+        &self,
+        input: TagResourceRequest,
+    ) -> Result<TagResourceResponse, RusotoError<TagResourceError>> {
+        self.tag_resource_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes a Tag.</p>
+    async fn untag_resource(
+        &self,
+        input: UntagResourceRequest,
+    ) -> Result<(), RusotoError<UntagResourceError>> {
+        self.untag_resource_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Updates an Api resource.</p>
+    async fn update_api(
+        &self,
+        input: UpdateApiRequest,
+    ) -> Result<UpdateApiResponse, RusotoError<UpdateApiError>> {
+        self.update_api_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>The API mapping.</p>
+    async fn update_api_mapping(
+        &self,
+        input: UpdateApiMappingRequest,
+    ) -> Result<UpdateApiMappingResponse, RusotoError<UpdateApiMappingError>> {
+        self.update_api_mapping_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Updates an Authorizer.</p>
+    async fn update_authorizer(
+        &self,
+        input: UpdateAuthorizerRequest,
+    ) -> Result<UpdateAuthorizerResponse, RusotoError<UpdateAuthorizerError>> {
+        self.update_authorizer_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Updates a Deployment.</p>
+    async fn update_deployment(
+        &self,
+        input: UpdateDeploymentRequest,
+    ) -> Result<UpdateDeploymentResponse, RusotoError<UpdateDeploymentError>> {
+        self.update_deployment_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Updates a domain name.</p>
+    async fn update_domain_name(
+        &self,
+        input: UpdateDomainNameRequest,
+    ) -> Result<UpdateDomainNameResponse, RusotoError<UpdateDomainNameError>> {
+        self.update_domain_name_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Updates an Integration.</p>
+    async fn update_integration(
+        &self,
+        input: UpdateIntegrationRequest,
+    ) -> Result<UpdateIntegrationResult, RusotoError<UpdateIntegrationError>> {
+        self.update_integration_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Updates an IntegrationResponses.</p>
+    async fn update_integration_response(
+        &self,
+        input: UpdateIntegrationResponseRequest,
+    ) -> Result<UpdateIntegrationResponseResponse, RusotoError<UpdateIntegrationResponseError>>
+    {
+        self.update_integration_response_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Updates a Model.</p>
+    async fn update_model(
+        &self,
+        input: UpdateModelRequest,
+    ) -> Result<UpdateModelResponse, RusotoError<UpdateModelError>> {
+        self.update_model_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Updates a Route.</p>
+    async fn update_route(
+        &self,
+        input: UpdateRouteRequest,
+    ) -> Result<UpdateRouteResult, RusotoError<UpdateRouteError>> {
+        self.update_route_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Updates a RouteResponse.</p>
+    async fn update_route_response(
+        &self,
+        input: UpdateRouteResponseRequest,
+    ) -> Result<UpdateRouteResponseResponse, RusotoError<UpdateRouteResponseError>> {
+        self.update_route_response_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Updates a Stage.</p>
+    async fn update_stage(
+        &self,
+        input: UpdateStageRequest,
+    ) -> Result<UpdateStageResponse, RusotoError<UpdateStageError>> {
+        self.update_stage_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
     }
 }
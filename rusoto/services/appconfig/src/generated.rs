@@ -16,8 +16,8 @@ use std::fmt;
 use async_trait::async_trait;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
-use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest, ResponseMetadata};
+use rusoto_core::{Client, ErrorMetadata, RusotoError};
 
 use rusoto_core::param::{Params, ServiceParams};
 use rusoto_core::proto;
@@ -869,11 +869,14 @@ pub struct Validator {
 
 /// Errors returned by CreateApplication
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateApplicationError {
     /// <p>The input fails to satisfy the constraints specified by an AWS service.</p>
     BadRequest(String),
     /// <p>There was an internal failure in the AppConfig service.</p>
     InternalServer(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateApplicationError {
@@ -881,13 +884,16 @@ impl CreateApplicationError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(CreateApplicationError::BadRequest(err.msg))
+                    return RusotoError::service(CreateApplicationError::BadRequest(err.msg), &res)
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(CreateApplicationError::InternalServer(err.msg))
+                    return RusotoError::service(
+                        CreateApplicationError::InternalServer(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreateApplicationError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -899,12 +905,14 @@ impl fmt::Display for CreateApplicationError {
         match *self {
             CreateApplicationError::BadRequest(ref cause) => write!(f, "{}", cause),
             CreateApplicationError::InternalServer(ref cause) => write!(f, "{}", cause),
+            CreateApplicationError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateApplicationError {}
 /// Errors returned by CreateConfigurationProfile
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateConfigurationProfileError {
     /// <p>The input fails to satisfy the constraints specified by an AWS service.</p>
     BadRequest(String),
@@ -912,6 +920,8 @@ pub enum CreateConfigurationProfileError {
     InternalServer(String),
     /// <p>The requested resource could not be found.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateConfigurationProfileError {
@@ -921,22 +931,27 @@ impl CreateConfigurationProfileError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(CreateConfigurationProfileError::BadRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateConfigurationProfileError::BadRequest(err.msg),
+                        &res,
+                    )
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(CreateConfigurationProfileError::InternalServer(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateConfigurationProfileError::InternalServer(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(CreateConfigurationProfileError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateConfigurationProfileError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(CreateConfigurationProfileError::Unhandled, &res)
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -949,17 +964,21 @@ impl fmt::Display for CreateConfigurationProfileError {
             CreateConfigurationProfileError::BadRequest(ref cause) => write!(f, "{}", cause),
             CreateConfigurationProfileError::InternalServer(ref cause) => write!(f, "{}", cause),
             CreateConfigurationProfileError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            CreateConfigurationProfileError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateConfigurationProfileError {}
 /// Errors returned by CreateDeploymentStrategy
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateDeploymentStrategyError {
     /// <p>The input fails to satisfy the constraints specified by an AWS service.</p>
     BadRequest(String),
     /// <p>There was an internal failure in the AppConfig service.</p>
     InternalServer(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateDeploymentStrategyError {
@@ -967,15 +986,19 @@ impl CreateDeploymentStrategyError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(CreateDeploymentStrategyError::BadRequest(err.msg))
+                    return RusotoError::service(
+                        CreateDeploymentStrategyError::BadRequest(err.msg),
+                        &res,
+                    )
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(CreateDeploymentStrategyError::InternalServer(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateDeploymentStrategyError::InternalServer(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreateDeploymentStrategyError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -987,12 +1010,14 @@ impl fmt::Display for CreateDeploymentStrategyError {
         match *self {
             CreateDeploymentStrategyError::BadRequest(ref cause) => write!(f, "{}", cause),
             CreateDeploymentStrategyError::InternalServer(ref cause) => write!(f, "{}", cause),
+            CreateDeploymentStrategyError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateDeploymentStrategyError {}
 /// Errors returned by CreateEnvironment
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateEnvironmentError {
     /// <p>The input fails to satisfy the constraints specified by an AWS service.</p>
     BadRequest(String),
@@ -1000,6 +1025,8 @@ pub enum CreateEnvironmentError {
     InternalServer(String),
     /// <p>The requested resource could not be found.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateEnvironmentError {
@@ -1007,16 +1034,22 @@ impl CreateEnvironmentError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(CreateEnvironmentError::BadRequest(err.msg))
+                    return RusotoError::service(CreateEnvironmentError::BadRequest(err.msg), &res)
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(CreateEnvironmentError::InternalServer(err.msg))
+                    return RusotoError::service(
+                        CreateEnvironmentError::InternalServer(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(CreateEnvironmentError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        CreateEnvironmentError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreateEnvironmentError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1029,12 +1062,14 @@ impl fmt::Display for CreateEnvironmentError {
             CreateEnvironmentError::BadRequest(ref cause) => write!(f, "{}", cause),
             CreateEnvironmentError::InternalServer(ref cause) => write!(f, "{}", cause),
             CreateEnvironmentError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            CreateEnvironmentError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateEnvironmentError {}
 /// Errors returned by DeleteApplication
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteApplicationError {
     /// <p>The input fails to satisfy the constraints specified by an AWS service.</p>
     BadRequest(String),
@@ -1042,6 +1077,8 @@ pub enum DeleteApplicationError {
     InternalServer(String),
     /// <p>The requested resource could not be found.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteApplicationError {
@@ -1049,16 +1086,22 @@ impl DeleteApplicationError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(DeleteApplicationError::BadRequest(err.msg))
+                    return RusotoError::service(DeleteApplicationError::BadRequest(err.msg), &res)
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(DeleteApplicationError::InternalServer(err.msg))
+                    return RusotoError::service(
+                        DeleteApplicationError::InternalServer(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteApplicationError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        DeleteApplicationError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteApplicationError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1071,12 +1114,14 @@ impl fmt::Display for DeleteApplicationError {
             DeleteApplicationError::BadRequest(ref cause) => write!(f, "{}", cause),
             DeleteApplicationError::InternalServer(ref cause) => write!(f, "{}", cause),
             DeleteApplicationError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            DeleteApplicationError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteApplicationError {}
 /// Errors returned by DeleteConfigurationProfile
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteConfigurationProfileError {
     /// <p>The input fails to satisfy the constraints specified by an AWS service.</p>
     BadRequest(String),
@@ -1086,6 +1131,8 @@ pub enum DeleteConfigurationProfileError {
     InternalServer(String),
     /// <p>The requested resource could not be found.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteConfigurationProfileError {
@@ -1095,25 +1142,33 @@ impl DeleteConfigurationProfileError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(DeleteConfigurationProfileError::BadRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteConfigurationProfileError::BadRequest(err.msg),
+                        &res,
+                    )
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(DeleteConfigurationProfileError::Conflict(err.msg))
+                    return RusotoError::service(
+                        DeleteConfigurationProfileError::Conflict(err.msg),
+                        &res,
+                    )
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(DeleteConfigurationProfileError::InternalServer(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteConfigurationProfileError::InternalServer(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteConfigurationProfileError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteConfigurationProfileError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(DeleteConfigurationProfileError::Unhandled, &res)
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -1127,12 +1182,14 @@ impl fmt::Display for DeleteConfigurationProfileError {
             DeleteConfigurationProfileError::Conflict(ref cause) => write!(f, "{}", cause),
             DeleteConfigurationProfileError::InternalServer(ref cause) => write!(f, "{}", cause),
             DeleteConfigurationProfileError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            DeleteConfigurationProfileError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteConfigurationProfileError {}
 /// Errors returned by DeleteDeploymentStrategy
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteDeploymentStrategyError {
     /// <p>The input fails to satisfy the constraints specified by an AWS service.</p>
     BadRequest(String),
@@ -1140,6 +1197,8 @@ pub enum DeleteDeploymentStrategyError {
     InternalServer(String),
     /// <p>The requested resource could not be found.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteDeploymentStrategyError {
@@ -1147,20 +1206,25 @@ impl DeleteDeploymentStrategyError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(DeleteDeploymentStrategyError::BadRequest(err.msg))
+                    return RusotoError::service(
+                        DeleteDeploymentStrategyError::BadRequest(err.msg),
+                        &res,
+                    )
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(DeleteDeploymentStrategyError::InternalServer(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteDeploymentStrategyError::InternalServer(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteDeploymentStrategyError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteDeploymentStrategyError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteDeploymentStrategyError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1173,12 +1237,14 @@ impl fmt::Display for DeleteDeploymentStrategyError {
             DeleteDeploymentStrategyError::BadRequest(ref cause) => write!(f, "{}", cause),
             DeleteDeploymentStrategyError::InternalServer(ref cause) => write!(f, "{}", cause),
             DeleteDeploymentStrategyError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            DeleteDeploymentStrategyError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteDeploymentStrategyError {}
 /// Errors returned by DeleteEnvironment
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteEnvironmentError {
     /// <p>The input fails to satisfy the constraints specified by an AWS service.</p>
     BadRequest(String),
@@ -1188,6 +1254,8 @@ pub enum DeleteEnvironmentError {
     InternalServer(String),
     /// <p>The requested resource could not be found.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteEnvironmentError {
@@ -1195,19 +1263,25 @@ impl DeleteEnvironmentError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(DeleteEnvironmentError::BadRequest(err.msg))
+                    return RusotoError::service(DeleteEnvironmentError::BadRequest(err.msg), &res)
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(DeleteEnvironmentError::Conflict(err.msg))
+                    return RusotoError::service(DeleteEnvironmentError::Conflict(err.msg), &res)
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(DeleteEnvironmentError::InternalServer(err.msg))
+                    return RusotoError::service(
+                        DeleteEnvironmentError::InternalServer(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteEnvironmentError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        DeleteEnvironmentError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteEnvironmentError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1221,12 +1295,14 @@ impl fmt::Display for DeleteEnvironmentError {
             DeleteEnvironmentError::Conflict(ref cause) => write!(f, "{}", cause),
             DeleteEnvironmentError::InternalServer(ref cause) => write!(f, "{}", cause),
             DeleteEnvironmentError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            DeleteEnvironmentError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteEnvironmentError {}
 /// Errors returned by GetApplication
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetApplicationError {
     /// <p>The input fails to satisfy the constraints specified by an AWS service.</p>
     BadRequest(String),
@@ -1234,6 +1310,8 @@ pub enum GetApplicationError {
     InternalServer(String),
     /// <p>The requested resource could not be found.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetApplicationError {
@@ -1241,16 +1319,19 @@ impl GetApplicationError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetApplicationError::BadRequest(err.msg))
+                    return RusotoError::service(GetApplicationError::BadRequest(err.msg), &res)
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(GetApplicationError::InternalServer(err.msg))
+                    return RusotoError::service(GetApplicationError::InternalServer(err.msg), &res)
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetApplicationError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        GetApplicationError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetApplicationError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1263,12 +1344,14 @@ impl fmt::Display for GetApplicationError {
             GetApplicationError::BadRequest(ref cause) => write!(f, "{}", cause),
             GetApplicationError::InternalServer(ref cause) => write!(f, "{}", cause),
             GetApplicationError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            GetApplicationError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetApplicationError {}
 /// Errors returned by GetConfiguration
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetConfigurationError {
     /// <p>The input fails to satisfy the constraints specified by an AWS service.</p>
     BadRequest(String),
@@ -1276,6 +1359,8 @@ pub enum GetConfigurationError {
     InternalServer(String),
     /// <p>The requested resource could not be found.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetConfigurationError {
@@ -1283,16 +1368,22 @@ impl GetConfigurationError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetConfigurationError::BadRequest(err.msg))
+                    return RusotoError::service(GetConfigurationError::BadRequest(err.msg), &res)
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(GetConfigurationError::InternalServer(err.msg))
+                    return RusotoError::service(
+                        GetConfigurationError::InternalServer(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetConfigurationError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        GetConfigurationError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetConfigurationError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1305,12 +1396,14 @@ impl fmt::Display for GetConfigurationError {
             GetConfigurationError::BadRequest(ref cause) => write!(f, "{}", cause),
             GetConfigurationError::InternalServer(ref cause) => write!(f, "{}", cause),
             GetConfigurationError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            GetConfigurationError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetConfigurationError {}
 /// Errors returned by GetConfigurationProfile
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetConfigurationProfileError {
     /// <p>The input fails to satisfy the constraints specified by an AWS service.</p>
     BadRequest(String),
@@ -1318,6 +1411,8 @@ pub enum GetConfigurationProfileError {
     InternalServer(String),
     /// <p>The requested resource could not be found.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetConfigurationProfileError {
@@ -1325,20 +1420,25 @@ impl GetConfigurationProfileError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetConfigurationProfileError::BadRequest(err.msg))
+                    return RusotoError::service(
+                        GetConfigurationProfileError::BadRequest(err.msg),
+                        &res,
+                    )
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(GetConfigurationProfileError::InternalServer(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetConfigurationProfileError::InternalServer(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetConfigurationProfileError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetConfigurationProfileError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetConfigurationProfileError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1351,12 +1451,14 @@ impl fmt::Display for GetConfigurationProfileError {
             GetConfigurationProfileError::BadRequest(ref cause) => write!(f, "{}", cause),
             GetConfigurationProfileError::InternalServer(ref cause) => write!(f, "{}", cause),
             GetConfigurationProfileError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            GetConfigurationProfileError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetConfigurationProfileError {}
 /// Errors returned by GetDeployment
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetDeploymentError {
     /// <p>The input fails to satisfy the constraints specified by an AWS service.</p>
     BadRequest(String),
@@ -1364,6 +1466,8 @@ pub enum GetDeploymentError {
     InternalServer(String),
     /// <p>The requested resource could not be found.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetDeploymentError {
@@ -1371,16 +1475,19 @@ impl GetDeploymentError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetDeploymentError::BadRequest(err.msg))
+                    return RusotoError::service(GetDeploymentError::BadRequest(err.msg), &res)
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(GetDeploymentError::InternalServer(err.msg))
+                    return RusotoError::service(GetDeploymentError::InternalServer(err.msg), &res)
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetDeploymentError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        GetDeploymentError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetDeploymentError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1393,12 +1500,14 @@ impl fmt::Display for GetDeploymentError {
             GetDeploymentError::BadRequest(ref cause) => write!(f, "{}", cause),
             GetDeploymentError::InternalServer(ref cause) => write!(f, "{}", cause),
             GetDeploymentError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            GetDeploymentError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetDeploymentError {}
 /// Errors returned by GetDeploymentStrategy
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetDeploymentStrategyError {
     /// <p>The input fails to satisfy the constraints specified by an AWS service.</p>
     BadRequest(String),
@@ -1406,6 +1515,8 @@ pub enum GetDeploymentStrategyError {
     InternalServer(String),
     /// <p>The requested resource could not be found.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetDeploymentStrategyError {
@@ -1413,20 +1524,25 @@ impl GetDeploymentStrategyError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetDeploymentStrategyError::BadRequest(err.msg))
+                    return RusotoError::service(
+                        GetDeploymentStrategyError::BadRequest(err.msg),
+                        &res,
+                    )
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(GetDeploymentStrategyError::InternalServer(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetDeploymentStrategyError::InternalServer(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetDeploymentStrategyError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetDeploymentStrategyError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetDeploymentStrategyError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1439,12 +1555,14 @@ impl fmt::Display for GetDeploymentStrategyError {
             GetDeploymentStrategyError::BadRequest(ref cause) => write!(f, "{}", cause),
             GetDeploymentStrategyError::InternalServer(ref cause) => write!(f, "{}", cause),
             GetDeploymentStrategyError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            GetDeploymentStrategyError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetDeploymentStrategyError {}
 /// Errors returned by GetEnvironment
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetEnvironmentError {
     /// <p>The input fails to satisfy the constraints specified by an AWS service.</p>
     BadRequest(String),
@@ -1452,6 +1570,8 @@ pub enum GetEnvironmentError {
     InternalServer(String),
     /// <p>The requested resource could not be found.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetEnvironmentError {
@@ -1459,16 +1579,19 @@ impl GetEnvironmentError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetEnvironmentError::BadRequest(err.msg))
+                    return RusotoError::service(GetEnvironmentError::BadRequest(err.msg), &res)
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(GetEnvironmentError::InternalServer(err.msg))
+                    return RusotoError::service(GetEnvironmentError::InternalServer(err.msg), &res)
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetEnvironmentError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        GetEnvironmentError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetEnvironmentError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1481,17 +1604,21 @@ impl fmt::Display for GetEnvironmentError {
             GetEnvironmentError::BadRequest(ref cause) => write!(f, "{}", cause),
             GetEnvironmentError::InternalServer(ref cause) => write!(f, "{}", cause),
             GetEnvironmentError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            GetEnvironmentError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetEnvironmentError {}
 /// Errors returned by ListApplications
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListApplicationsError {
     /// <p>The input fails to satisfy the constraints specified by an AWS service.</p>
     BadRequest(String),
     /// <p>There was an internal failure in the AppConfig service.</p>
     InternalServer(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListApplicationsError {
@@ -1499,13 +1626,16 @@ impl ListApplicationsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(ListApplicationsError::BadRequest(err.msg))
+                    return RusotoError::service(ListApplicationsError::BadRequest(err.msg), &res)
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(ListApplicationsError::InternalServer(err.msg))
+                    return RusotoError::service(
+                        ListApplicationsError::InternalServer(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListApplicationsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1517,12 +1647,14 @@ impl fmt::Display for ListApplicationsError {
         match *self {
             ListApplicationsError::BadRequest(ref cause) => write!(f, "{}", cause),
             ListApplicationsError::InternalServer(ref cause) => write!(f, "{}", cause),
+            ListApplicationsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListApplicationsError {}
 /// Errors returned by ListConfigurationProfiles
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListConfigurationProfilesError {
     /// <p>The input fails to satisfy the constraints specified by an AWS service.</p>
     BadRequest(String),
@@ -1530,6 +1662,8 @@ pub enum ListConfigurationProfilesError {
     InternalServer(String),
     /// <p>The requested resource could not be found.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListConfigurationProfilesError {
@@ -1537,22 +1671,27 @@ impl ListConfigurationProfilesError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(ListConfigurationProfilesError::BadRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListConfigurationProfilesError::BadRequest(err.msg),
+                        &res,
+                    )
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(ListConfigurationProfilesError::InternalServer(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListConfigurationProfilesError::InternalServer(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListConfigurationProfilesError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListConfigurationProfilesError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(ListConfigurationProfilesError::Unhandled, &res)
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -1565,17 +1704,21 @@ impl fmt::Display for ListConfigurationProfilesError {
             ListConfigurationProfilesError::BadRequest(ref cause) => write!(f, "{}", cause),
             ListConfigurationProfilesError::InternalServer(ref cause) => write!(f, "{}", cause),
             ListConfigurationProfilesError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            ListConfigurationProfilesError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListConfigurationProfilesError {}
 /// Errors returned by ListDeploymentStrategies
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListDeploymentStrategiesError {
     /// <p>The input fails to satisfy the constraints specified by an AWS service.</p>
     BadRequest(String),
     /// <p>There was an internal failure in the AppConfig service.</p>
     InternalServer(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListDeploymentStrategiesError {
@@ -1583,15 +1726,19 @@ impl ListDeploymentStrategiesError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(ListDeploymentStrategiesError::BadRequest(err.msg))
+                    return RusotoError::service(
+                        ListDeploymentStrategiesError::BadRequest(err.msg),
+                        &res,
+                    )
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(ListDeploymentStrategiesError::InternalServer(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListDeploymentStrategiesError::InternalServer(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListDeploymentStrategiesError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1603,12 +1750,14 @@ impl fmt::Display for ListDeploymentStrategiesError {
         match *self {
             ListDeploymentStrategiesError::BadRequest(ref cause) => write!(f, "{}", cause),
             ListDeploymentStrategiesError::InternalServer(ref cause) => write!(f, "{}", cause),
+            ListDeploymentStrategiesError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListDeploymentStrategiesError {}
 /// Errors returned by ListDeployments
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListDeploymentsError {
     /// <p>The input fails to satisfy the constraints specified by an AWS service.</p>
     BadRequest(String),
@@ -1616,6 +1765,8 @@ pub enum ListDeploymentsError {
     InternalServer(String),
     /// <p>The requested resource could not be found.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListDeploymentsError {
@@ -1623,16 +1774,22 @@ impl ListDeploymentsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(ListDeploymentsError::BadRequest(err.msg))
+                    return RusotoError::service(ListDeploymentsError::BadRequest(err.msg), &res)
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(ListDeploymentsError::InternalServer(err.msg))
+                    return RusotoError::service(
+                        ListDeploymentsError::InternalServer(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListDeploymentsError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        ListDeploymentsError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListDeploymentsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1645,12 +1802,14 @@ impl fmt::Display for ListDeploymentsError {
             ListDeploymentsError::BadRequest(ref cause) => write!(f, "{}", cause),
             ListDeploymentsError::InternalServer(ref cause) => write!(f, "{}", cause),
             ListDeploymentsError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            ListDeploymentsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListDeploymentsError {}
 /// Errors returned by ListEnvironments
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListEnvironmentsError {
     /// <p>The input fails to satisfy the constraints specified by an AWS service.</p>
     BadRequest(String),
@@ -1658,6 +1817,8 @@ pub enum ListEnvironmentsError {
     InternalServer(String),
     /// <p>The requested resource could not be found.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListEnvironmentsError {
@@ -1665,16 +1826,22 @@ impl ListEnvironmentsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(ListEnvironmentsError::BadRequest(err.msg))
+                    return RusotoError::service(ListEnvironmentsError::BadRequest(err.msg), &res)
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(ListEnvironmentsError::InternalServer(err.msg))
+                    return RusotoError::service(
+                        ListEnvironmentsError::InternalServer(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListEnvironmentsError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        ListEnvironmentsError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListEnvironmentsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1687,12 +1854,14 @@ impl fmt::Display for ListEnvironmentsError {
             ListEnvironmentsError::BadRequest(ref cause) => write!(f, "{}", cause),
             ListEnvironmentsError::InternalServer(ref cause) => write!(f, "{}", cause),
             ListEnvironmentsError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            ListEnvironmentsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListEnvironmentsError {}
 /// Errors returned by ListTagsForResource
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListTagsForResourceError {
     /// <p>The input fails to satisfy the constraints specified by an AWS service.</p>
     BadRequest(String),
@@ -1700,6 +1869,8 @@ pub enum ListTagsForResourceError {
     InternalServer(String),
     /// <p>The requested resource could not be found.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListTagsForResourceError {
@@ -1707,18 +1878,25 @@ impl ListTagsForResourceError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(ListTagsForResourceError::BadRequest(err.msg))
+                    return RusotoError::service(
+                        ListTagsForResourceError::BadRequest(err.msg),
+                        &res,
+                    )
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(ListTagsForResourceError::InternalServer(err.msg))
+                    return RusotoError::service(
+                        ListTagsForResourceError::InternalServer(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListTagsForResourceError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListTagsForResourceError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListTagsForResourceError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1731,12 +1909,14 @@ impl fmt::Display for ListTagsForResourceError {
             ListTagsForResourceError::BadRequest(ref cause) => write!(f, "{}", cause),
             ListTagsForResourceError::InternalServer(ref cause) => write!(f, "{}", cause),
             ListTagsForResourceError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            ListTagsForResourceError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListTagsForResourceError {}
 /// Errors returned by StartDeployment
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum StartDeploymentError {
     /// <p>The input fails to satisfy the constraints specified by an AWS service.</p>
     BadRequest(String),
@@ -1746,6 +1926,8 @@ pub enum StartDeploymentError {
     InternalServer(String),
     /// <p>The requested resource could not be found.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl StartDeploymentError {
@@ -1753,19 +1935,25 @@ impl StartDeploymentError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(StartDeploymentError::BadRequest(err.msg))
+                    return RusotoError::service(StartDeploymentError::BadRequest(err.msg), &res)
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(StartDeploymentError::Conflict(err.msg))
+                    return RusotoError::service(StartDeploymentError::Conflict(err.msg), &res)
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(StartDeploymentError::InternalServer(err.msg))
+                    return RusotoError::service(
+                        StartDeploymentError::InternalServer(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(StartDeploymentError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        StartDeploymentError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(StartDeploymentError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1779,12 +1967,14 @@ impl fmt::Display for StartDeploymentError {
             StartDeploymentError::Conflict(ref cause) => write!(f, "{}", cause),
             StartDeploymentError::InternalServer(ref cause) => write!(f, "{}", cause),
             StartDeploymentError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            StartDeploymentError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for StartDeploymentError {}
 /// Errors returned by StopDeployment
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum StopDeploymentError {
     /// <p>The input fails to satisfy the constraints specified by an AWS service.</p>
     BadRequest(String),
@@ -1792,6 +1982,8 @@ pub enum StopDeploymentError {
     InternalServer(String),
     /// <p>The requested resource could not be found.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl StopDeploymentError {
@@ -1799,16 +1991,19 @@ impl StopDeploymentError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(StopDeploymentError::BadRequest(err.msg))
+                    return RusotoError::service(StopDeploymentError::BadRequest(err.msg), &res)
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(StopDeploymentError::InternalServer(err.msg))
+                    return RusotoError::service(StopDeploymentError::InternalServer(err.msg), &res)
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(StopDeploymentError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        StopDeploymentError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(StopDeploymentError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1821,12 +2016,14 @@ impl fmt::Display for StopDeploymentError {
             StopDeploymentError::BadRequest(ref cause) => write!(f, "{}", cause),
             StopDeploymentError::InternalServer(ref cause) => write!(f, "{}", cause),
             StopDeploymentError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            StopDeploymentError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for StopDeploymentError {}
 /// Errors returned by TagResource
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum TagResourceError {
     /// <p>The input fails to satisfy the constraints specified by an AWS service.</p>
     BadRequest(String),
@@ -1834,6 +2031,8 @@ pub enum TagResourceError {
     InternalServer(String),
     /// <p>The requested resource could not be found.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl TagResourceError {
@@ -1841,16 +2040,16 @@ impl TagResourceError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(TagResourceError::BadRequest(err.msg))
+                    return RusotoError::service(TagResourceError::BadRequest(err.msg), &res)
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(TagResourceError::InternalServer(err.msg))
+                    return RusotoError::service(TagResourceError::InternalServer(err.msg), &res)
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(TagResourceError::ResourceNotFound(err.msg))
+                    return RusotoError::service(TagResourceError::ResourceNotFound(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(TagResourceError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1863,12 +2062,14 @@ impl fmt::Display for TagResourceError {
             TagResourceError::BadRequest(ref cause) => write!(f, "{}", cause),
             TagResourceError::InternalServer(ref cause) => write!(f, "{}", cause),
             TagResourceError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            TagResourceError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for TagResourceError {}
 /// Errors returned by UntagResource
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UntagResourceError {
     /// <p>The input fails to satisfy the constraints specified by an AWS service.</p>
     BadRequest(String),
@@ -1876,6 +2077,8 @@ pub enum UntagResourceError {
     InternalServer(String),
     /// <p>The requested resource could not be found.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UntagResourceError {
@@ -1883,16 +2086,19 @@ impl UntagResourceError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(UntagResourceError::BadRequest(err.msg))
+                    return RusotoError::service(UntagResourceError::BadRequest(err.msg), &res)
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(UntagResourceError::InternalServer(err.msg))
+                    return RusotoError::service(UntagResourceError::InternalServer(err.msg), &res)
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UntagResourceError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        UntagResourceError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UntagResourceError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1905,12 +2111,14 @@ impl fmt::Display for UntagResourceError {
             UntagResourceError::BadRequest(ref cause) => write!(f, "{}", cause),
             UntagResourceError::InternalServer(ref cause) => write!(f, "{}", cause),
             UntagResourceError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            UntagResourceError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for UntagResourceError {}
 /// Errors returned by UpdateApplication
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdateApplicationError {
     /// <p>The input fails to satisfy the constraints specified by an AWS service.</p>
     BadRequest(String),
@@ -1918,6 +2126,8 @@ pub enum UpdateApplicationError {
     InternalServer(String),
     /// <p>The requested resource could not be found.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdateApplicationError {
@@ -1925,16 +2135,22 @@ impl UpdateApplicationError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(UpdateApplicationError::BadRequest(err.msg))
+                    return RusotoError::service(UpdateApplicationError::BadRequest(err.msg), &res)
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(UpdateApplicationError::InternalServer(err.msg))
+                    return RusotoError::service(
+                        UpdateApplicationError::InternalServer(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateApplicationError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        UpdateApplicationError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UpdateApplicationError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1947,12 +2163,14 @@ impl fmt::Display for UpdateApplicationError {
             UpdateApplicationError::BadRequest(ref cause) => write!(f, "{}", cause),
             UpdateApplicationError::InternalServer(ref cause) => write!(f, "{}", cause),
             UpdateApplicationError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            UpdateApplicationError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for UpdateApplicationError {}
 /// Errors returned by UpdateConfigurationProfile
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdateConfigurationProfileError {
     /// <p>The input fails to satisfy the constraints specified by an AWS service.</p>
     BadRequest(String),
@@ -1960,6 +2178,8 @@ pub enum UpdateConfigurationProfileError {
     InternalServer(String),
     /// <p>The requested resource could not be found.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdateConfigurationProfileError {
@@ -1969,22 +2189,27 @@ impl UpdateConfigurationProfileError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(UpdateConfigurationProfileError::BadRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateConfigurationProfileError::BadRequest(err.msg),
+                        &res,
+                    )
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(UpdateConfigurationProfileError::InternalServer(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateConfigurationProfileError::InternalServer(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateConfigurationProfileError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateConfigurationProfileError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(UpdateConfigurationProfileError::Unhandled, &res)
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -1997,12 +2222,14 @@ impl fmt::Display for UpdateConfigurationProfileError {
             UpdateConfigurationProfileError::BadRequest(ref cause) => write!(f, "{}", cause),
             UpdateConfigurationProfileError::InternalServer(ref cause) => write!(f, "{}", cause),
             UpdateConfigurationProfileError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            UpdateConfigurationProfileError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for UpdateConfigurationProfileError {}
 /// Errors returned by UpdateDeploymentStrategy
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdateDeploymentStrategyError {
     /// <p>The input fails to satisfy the constraints specified by an AWS service.</p>
     BadRequest(String),
@@ -2010,6 +2237,8 @@ pub enum UpdateDeploymentStrategyError {
     InternalServer(String),
     /// <p>The requested resource could not be found.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdateDeploymentStrategyError {
@@ -2017,20 +2246,25 @@ impl UpdateDeploymentStrategyError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(UpdateDeploymentStrategyError::BadRequest(err.msg))
+                    return RusotoError::service(
+                        UpdateDeploymentStrategyError::BadRequest(err.msg),
+                        &res,
+                    )
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(UpdateDeploymentStrategyError::InternalServer(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateDeploymentStrategyError::InternalServer(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateDeploymentStrategyError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateDeploymentStrategyError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UpdateDeploymentStrategyError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2043,12 +2277,14 @@ impl fmt::Display for UpdateDeploymentStrategyError {
             UpdateDeploymentStrategyError::BadRequest(ref cause) => write!(f, "{}", cause),
             UpdateDeploymentStrategyError::InternalServer(ref cause) => write!(f, "{}", cause),
             UpdateDeploymentStrategyError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            UpdateDeploymentStrategyError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for UpdateDeploymentStrategyError {}
 /// Errors returned by UpdateEnvironment
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdateEnvironmentError {
     /// <p>The input fails to satisfy the constraints specified by an AWS service.</p>
     BadRequest(String),
@@ -2056,6 +2292,8 @@ pub enum UpdateEnvironmentError {
     InternalServer(String),
     /// <p>The requested resource could not be found.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdateEnvironmentError {
@@ -2063,16 +2301,22 @@ impl UpdateEnvironmentError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(UpdateEnvironmentError::BadRequest(err.msg))
+                    return RusotoError::service(UpdateEnvironmentError::BadRequest(err.msg), &res)
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(UpdateEnvironmentError::InternalServer(err.msg))
+                    return RusotoError::service(
+                        UpdateEnvironmentError::InternalServer(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateEnvironmentError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        UpdateEnvironmentError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UpdateEnvironmentError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2085,12 +2329,14 @@ impl fmt::Display for UpdateEnvironmentError {
             UpdateEnvironmentError::BadRequest(ref cause) => write!(f, "{}", cause),
             UpdateEnvironmentError::InternalServer(ref cause) => write!(f, "{}", cause),
             UpdateEnvironmentError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            UpdateEnvironmentError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for UpdateEnvironmentError {}
 /// Errors returned by ValidateConfiguration
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ValidateConfigurationError {
     /// <p>The input fails to satisfy the constraints specified by an AWS service.</p>
     BadRequest(String),
@@ -2098,6 +2344,8 @@ pub enum ValidateConfigurationError {
     InternalServer(String),
     /// <p>The requested resource could not be found.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ValidateConfigurationError {
@@ -2105,20 +2353,25 @@ impl ValidateConfigurationError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(ValidateConfigurationError::BadRequest(err.msg))
+                    return RusotoError::service(
+                        ValidateConfigurationError::BadRequest(err.msg),
+                        &res,
+                    )
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(ValidateConfigurationError::InternalServer(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ValidateConfigurationError::InternalServer(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ValidateConfigurationError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ValidateConfigurationError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ValidateConfigurationError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2131,6 +2384,7 @@ impl fmt::Display for ValidateConfigurationError {
             ValidateConfigurationError::BadRequest(ref cause) => write!(f, "{}", cause),
             ValidateConfigurationError::InternalServer(ref cause) => write!(f, "{}", cause),
             ValidateConfigurationError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            ValidateConfigurationError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
@@ -2323,6 +2577,7 @@ impl AppConfigClient {
     /// Creates a client backed by the default tokio event loop.
     ///
     /// The client will use the default credentials provider and tls client.
+    #[cfg(not(target_arch = "wasm32"))]
     pub fn new(region: region::Region) -> AppConfigClient {
         AppConfigClient {
             client: Client::shared(),
@@ -2348,170 +2603,353 @@ impl AppConfigClient {
     pub fn new_with_client(client: Client, region: region::Region) -> AppConfigClient {
         AppConfigClient { client, region }
     }
+
+    /// Returns a builder of a client, setting any of its region, credentials provider,
+    /// dispatcher, retry policy, endpoint and timeouts.
+    pub fn builder() -> rusoto_core::ClientBuilder<AppConfigClient> {
+        rusoto_core::ClientBuilder::new(AppConfigClient::new_with_client)
+    }
+
+    /// Returns a clone of this client with the overrides of `config` applied to its
+    /// operations, sharing its connections.
+    pub fn customize(&self, config: &rusoto_core::OperationConfig) -> AppConfigClient {
+        AppConfigClient {
+            client: self.client.customize(config),
+            region: config.resolve_region(&self.region),
+        }
+    }
 }
 
-#[async_trait]
-impl AppConfig for AppConfigClient {
-    /// <p>An application in AppConfig is a logical unit of code that provides capabilities for your customers. For example, an application can be a microservice that runs on Amazon EC2 instances, a mobile application installed by your users, a serverless application using Amazon API Gateway and AWS Lambda, or any system you run on behalf of others.</p>
-    async fn create_application(
+impl AppConfigClient {
+    /// Calls `create_application`, also returning the metadata of the response.
+    pub async fn create_application_with_metadata(
         &self,
         input: CreateApplicationRequest,
-    ) -> Result<Application, RusotoError<CreateApplicationError>> {
+    ) -> Result<(Application, ResponseMetadata), RusotoError<CreateApplicationError>> {
+        let request = self.build_create_application_request(input);
+
+        let mut response = self
+            .client
+            .sign_and_dispatch(request)
+            .await
+            .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
+        if response.status.as_u16() == 201 {
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result =
+                proto::json::ResponsePayload::new(&response).deserialize::<Application, _>()?;
+
+            Ok((result, metadata))
+        } else {
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(CreateApplicationError::from_response(response))
+        }
+    }
+
+    /// Presigns the request `create_application` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_application(
+        &self,
+        presigner: &rusoto_core::Presigner,
+        input: CreateApplicationRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_application_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_application_request(&self, input: CreateApplicationRequest) -> SignedRequest {
         let request_uri = "/applications";
 
         let mut request = SignedRequest::new("POST", "appconfig", &self.region, &request_uri);
+        request.set_operation("CreateApplication");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `create_configuration_profile`, also returning the metadata of the response.
+    pub async fn create_configuration_profile_with_metadata(
+        &self,
+        input: CreateConfigurationProfileRequest,
+    ) -> Result<
+        (ConfigurationProfile, ResponseMetadata),
+        RusotoError<CreateConfigurationProfileError>,
+    > {
+        let request = self.build_create_configuration_profile_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 201 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            let result =
-                proto::json::ResponsePayload::new(&response).deserialize::<Application, _>()?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ConfigurationProfile, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(CreateApplicationError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(CreateConfigurationProfileError::from_response(response))
         }
     }
 
-    /// <p><p>Information that enables AppConfig to access the configuration source. Valid configuration sources include Systems Manager (SSM) documents and SSM Parameter Store parameters. A configuration profile includes the following information.</p> <ul> <li> <p>The Uri location of the configuration data.</p> </li> <li> <p>The AWS Identity and Access Management (IAM) role that provides access to the configuration data.</p> </li> <li> <p>A validator for the configuration data. Available validators include either a JSON Schema or an AWS Lambda function.</p> </li> </ul></p>
-    async fn create_configuration_profile(
+    /// Presigns the request `create_configuration_profile` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_configuration_profile(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: CreateConfigurationProfileRequest,
-    ) -> Result<ConfigurationProfile, RusotoError<CreateConfigurationProfileError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_configuration_profile_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_configuration_profile_request(
+        &self,
+        input: CreateConfigurationProfileRequest,
+    ) -> SignedRequest {
         let request_uri = format!(
             "/applications/{application_id}/configurationprofiles",
             application_id = input.application_id
         );
 
         let mut request = SignedRequest::new("POST", "appconfig", &self.region, &request_uri);
+        request.set_operation("CreateConfigurationProfile");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `create_deployment_strategy`, also returning the metadata of the response.
+    pub async fn create_deployment_strategy_with_metadata(
+        &self,
+        input: CreateDeploymentStrategyRequest,
+    ) -> Result<(DeploymentStrategy, ResponseMetadata), RusotoError<CreateDeploymentStrategyError>>
+    {
+        let request = self.build_create_deployment_strategy_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 201 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<ConfigurationProfile, _>()?;
+                .deserialize::<DeploymentStrategy, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(CreateConfigurationProfileError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(CreateDeploymentStrategyError::from_response(response))
         }
     }
 
-    /// <p>A deployment strategy defines important criteria for rolling out your configuration to the designated targets. A deployment strategy includes: the overall duration required, a percentage of targets to receive the deployment during each interval, an algorithm that defines how percentage grows, and bake time.</p>
-    async fn create_deployment_strategy(
+    /// Presigns the request `create_deployment_strategy` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_deployment_strategy(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: CreateDeploymentStrategyRequest,
-    ) -> Result<DeploymentStrategy, RusotoError<CreateDeploymentStrategyError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_deployment_strategy_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_deployment_strategy_request(
+        &self,
+        input: CreateDeploymentStrategyRequest,
+    ) -> SignedRequest {
         let request_uri = "/deploymentstrategies";
 
         let mut request = SignedRequest::new("POST", "appconfig", &self.region, &request_uri);
+        request.set_operation("CreateDeploymentStrategy");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `create_environment`, also returning the metadata of the response.
+    pub async fn create_environment_with_metadata(
+        &self,
+        input: CreateEnvironmentRequest,
+    ) -> Result<(Environment, ResponseMetadata), RusotoError<CreateEnvironmentError>> {
+        let request = self.build_create_environment_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 201 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<DeploymentStrategy, _>()?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result =
+                proto::json::ResponsePayload::new(&response).deserialize::<Environment, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(CreateDeploymentStrategyError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(CreateEnvironmentError::from_response(response))
         }
     }
 
-    /// <p>For each application, you define one or more environments. An environment is a logical deployment group of AppConfig targets, such as applications in a <code>Beta</code> or <code>Production</code> environment. You can also define environments for application subcomponents such as the <code>Web</code>, <code>Mobile</code> and <code>Back-end</code> components for your application. You can configure Amazon CloudWatch alarms for each environment. The system monitors alarms during a configuration deployment. If an alarm is triggered, the system rolls back the configuration.</p>
-    async fn create_environment(
+    /// Presigns the request `create_environment` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_environment(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: CreateEnvironmentRequest,
-    ) -> Result<Environment, RusotoError<CreateEnvironmentError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_environment_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_environment_request(&self, input: CreateEnvironmentRequest) -> SignedRequest {
         let request_uri = format!(
             "/applications/{application_id}/environments",
             application_id = input.application_id
         );
 
         let mut request = SignedRequest::new("POST", "appconfig", &self.region, &request_uri);
+        request.set_operation("CreateEnvironment");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `delete_application`, also returning the metadata of the response.
+    pub async fn delete_application_with_metadata(
+        &self,
+        input: DeleteApplicationRequest,
+    ) -> Result<((), ResponseMetadata), RusotoError<DeleteApplicationError>> {
+        let request = self.build_delete_application_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
-        if response.status.as_u16() == 201 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            let result =
-                proto::json::ResponsePayload::new(&response).deserialize::<Environment, _>()?;
+        let metadata = ResponseMetadata::from_response(&response);
+        if response.status.as_u16() == 204 {
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = ::std::mem::drop(response);
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(CreateEnvironmentError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(DeleteApplicationError::from_response(response))
         }
     }
 
-    /// <p>Delete an application. Deleting an application does not delete a configuration from a host.</p>
-    async fn delete_application(
+    /// Presigns the request `delete_application` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_application(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteApplicationRequest,
-    ) -> Result<(), RusotoError<DeleteApplicationError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_application_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_application_request(&self, input: DeleteApplicationRequest) -> SignedRequest {
         let request_uri = format!(
             "/applications/{application_id}",
             application_id = input.application_id
         );
 
         let mut request = SignedRequest::new("DELETE", "appconfig", &self.region, &request_uri);
+        request.set_operation("DeleteApplication");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
+        request
+    }
+
+    /// Calls `delete_configuration_profile`, also returning the metadata of the response.
+    pub async fn delete_configuration_profile_with_metadata(
+        &self,
+        input: DeleteConfigurationProfileRequest,
+    ) -> Result<((), ResponseMetadata), RusotoError<DeleteConfigurationProfileError>> {
+        let request = self.build_delete_configuration_profile_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 204 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = ::std::mem::drop(response);
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteApplicationError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(DeleteConfigurationProfileError::from_response(response))
         }
     }
 
-    /// <p>Delete a configuration profile. Deleting a configuration profile does not delete a configuration from a host.</p>
-    async fn delete_configuration_profile(
+    /// Presigns the request `delete_configuration_profile` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_configuration_profile(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteConfigurationProfileRequest,
-    ) -> Result<(), RusotoError<DeleteConfigurationProfileError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_configuration_profile_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_configuration_profile_request(
+        &self,
+        input: DeleteConfigurationProfileRequest,
+    ) -> SignedRequest {
         let request_uri = format!(
             "/applications/{application_id}/configurationprofiles/{configuration_profile_id}",
             application_id = input.application_id,
@@ -2519,58 +2957,109 @@ impl AppConfig for AppConfigClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "appconfig", &self.region, &request_uri);
+        request.set_operation("DeleteConfigurationProfile");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
+        request
+    }
+
+    /// Calls `delete_deployment_strategy`, also returning the metadata of the response.
+    pub async fn delete_deployment_strategy_with_metadata(
+        &self,
+        input: DeleteDeploymentStrategyRequest,
+    ) -> Result<((), ResponseMetadata), RusotoError<DeleteDeploymentStrategyError>> {
+        let request = self.build_delete_deployment_strategy_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 204 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = ::std::mem::drop(response);
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteConfigurationProfileError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(DeleteDeploymentStrategyError::from_response(response))
         }
     }
 
-    /// <p>Delete a deployment strategy. Deleting a deployment strategy does not delete a configuration from a host.</p>
-    async fn delete_deployment_strategy(
+    /// Presigns the request `delete_deployment_strategy` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_deployment_strategy(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteDeploymentStrategyRequest,
-    ) -> Result<(), RusotoError<DeleteDeploymentStrategyError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_deployment_strategy_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_deployment_strategy_request(
+        &self,
+        input: DeleteDeploymentStrategyRequest,
+    ) -> SignedRequest {
         let request_uri = format!(
             "/deployementstrategies/{deployment_strategy_id}",
             deployment_strategy_id = input.deployment_strategy_id
         );
 
         let mut request = SignedRequest::new("DELETE", "appconfig", &self.region, &request_uri);
+        request.set_operation("DeleteDeploymentStrategy");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
+        request
+    }
+
+    /// Calls `delete_environment`, also returning the metadata of the response.
+    pub async fn delete_environment_with_metadata(
+        &self,
+        input: DeleteEnvironmentRequest,
+    ) -> Result<((), ResponseMetadata), RusotoError<DeleteEnvironmentError>> {
+        let request = self.build_delete_environment_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 204 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = ::std::mem::drop(response);
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteDeploymentStrategyError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(DeleteEnvironmentError::from_response(response))
         }
     }
 
-    /// <p>Delete an environment. Deleting an environment does not delete a configuration from a host.</p>
-    async fn delete_environment(
+    /// Presigns the request `delete_environment` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_environment(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteEnvironmentRequest,
-    ) -> Result<(), RusotoError<DeleteEnvironmentError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_environment_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_environment_request(&self, input: DeleteEnvironmentRequest) -> SignedRequest {
         let request_uri = format!(
             "/applications/{application_id}/environments/{environment_id}",
             application_id = input.application_id,
@@ -2578,59 +3067,118 @@ impl AppConfig for AppConfigClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "appconfig", &self.region, &request_uri);
+        request.set_operation("DeleteEnvironment");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
+        request
+    }
+
+    /// Calls `get_application`, also returning the metadata of the response.
+    pub async fn get_application_with_metadata(
+        &self,
+        input: GetApplicationRequest,
+    ) -> Result<(Application, ResponseMetadata), RusotoError<GetApplicationError>> {
+        let request = self.build_get_application_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
-        if response.status.as_u16() == 204 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            let result = ::std::mem::drop(response);
+        let metadata = ResponseMetadata::from_response(&response);
+        if response.status.as_u16() == 200 {
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result =
+                proto::json::ResponsePayload::new(&response).deserialize::<Application, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteEnvironmentError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(GetApplicationError::from_response(response))
         }
     }
 
-    /// <p>Retrieve information about an application.</p>
-    async fn get_application(
+    /// Presigns the request `get_application` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_application(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetApplicationRequest,
-    ) -> Result<Application, RusotoError<GetApplicationError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_application_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_application_request(&self, input: GetApplicationRequest) -> SignedRequest {
         let request_uri = format!(
             "/applications/{application_id}",
             application_id = input.application_id
         );
 
         let mut request = SignedRequest::new("GET", "appconfig", &self.region, &request_uri);
+        request.set_operation("GetApplication");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
+        request
+    }
+
+    /// Calls `get_configuration`, also returning the metadata of the response.
+    pub async fn get_configuration_with_metadata(
+        &self,
+        input: GetConfigurationRequest,
+    ) -> Result<(Configuration, ResponseMetadata), RusotoError<GetConfigurationError>> {
+        let request = self.build_get_configuration_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            let result =
-                proto::json::ResponsePayload::new(&response).deserialize::<Application, _>()?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+
+            let mut result = Configuration::default();
+            result.content = Some(response.body);
+
+            if let Some(configuration_version) = response.headers.get("Configuration-Version") {
+                let value = configuration_version.to_owned();
+                result.configuration_version = Some(value)
+            };
+            if let Some(content_type) = response.headers.get("Content-Type") {
+                let value = content_type.to_owned();
+                result.content_type = Some(value)
+            };
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(GetApplicationError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(GetConfigurationError::from_response(response))
         }
     }
 
-    /// <p>Retrieve information about a configuration.</p>
-    async fn get_configuration(
+    /// Presigns the request `get_configuration` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_configuration(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetConfigurationRequest,
-    ) -> Result<Configuration, RusotoError<GetConfigurationError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_configuration_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_configuration_request(&self, input: GetConfigurationRequest) -> SignedRequest {
         let request_uri = format!(
             "/applications/{application}/environments/{environment}/configurations/{configuration}",
             application = input.application,
@@ -2639,6 +3187,7 @@ impl AppConfig for AppConfigClient {
         );
 
         let mut request = SignedRequest::new("GET", "appconfig", &self.region, &request_uri);
+        request.set_operation("GetConfiguration");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -2648,38 +3197,55 @@ impl AppConfig for AppConfigClient {
         params.put("client_id", &input.client_id);
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `get_configuration_profile`, also returning the metadata of the response.
+    pub async fn get_configuration_profile_with_metadata(
+        &self,
+        input: GetConfigurationProfileRequest,
+    ) -> Result<(ConfigurationProfile, ResponseMetadata), RusotoError<GetConfigurationProfileError>>
+    {
+        let request = self.build_get_configuration_profile_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-
-            let mut result = Configuration::default();
-            result.content = Some(response.body);
-
-            if let Some(configuration_version) = response.headers.get("Configuration-Version") {
-                let value = configuration_version.to_owned();
-                result.configuration_version = Some(value)
-            };
-            if let Some(content_type) = response.headers.get("Content-Type") {
-                let value = content_type.to_owned();
-                result.content_type = Some(value)
-            };
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ConfigurationProfile, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(GetConfigurationError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(GetConfigurationProfileError::from_response(response))
         }
     }
 
-    /// <p>Retrieve information about a configuration profile.</p>
-    async fn get_configuration_profile(
+    /// Presigns the request `get_configuration_profile` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_configuration_profile(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetConfigurationProfileRequest,
-    ) -> Result<ConfigurationProfile, RusotoError<GetConfigurationProfileError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_configuration_profile_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_configuration_profile_request(
+        &self,
+        input: GetConfigurationProfileRequest,
+    ) -> SignedRequest {
         let request_uri = format!(
             "/applications/{application_id}/configurationprofiles/{configuration_profile_id}",
             application_id = input.application_id,
@@ -2687,87 +3253,163 @@ impl AppConfig for AppConfigClient {
         );
 
         let mut request = SignedRequest::new("GET", "appconfig", &self.region, &request_uri);
+        request.set_operation("GetConfigurationProfile");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
+        request
+    }
+
+    /// Calls `get_deployment`, also returning the metadata of the response.
+    pub async fn get_deployment_with_metadata(
+        &self,
+        input: GetDeploymentRequest,
+    ) -> Result<(Deployment, ResponseMetadata), RusotoError<GetDeploymentError>> {
+        let request = self.build_get_deployment_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<ConfigurationProfile, _>()?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result =
+                proto::json::ResponsePayload::new(&response).deserialize::<Deployment, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(GetConfigurationProfileError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(GetDeploymentError::from_response(response))
         }
     }
 
-    /// <p>Retrieve information about a configuration deployment.</p>
-    async fn get_deployment(
+    /// Presigns the request `get_deployment` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_deployment(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetDeploymentRequest,
-    ) -> Result<Deployment, RusotoError<GetDeploymentError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_deployment_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_deployment_request(&self, input: GetDeploymentRequest) -> SignedRequest {
         let request_uri = format!("/applications/{application_id}/environments/{environment_id}/deployments/{deployment_number}", application_id = input.application_id, deployment_number = input.deployment_number, environment_id = input.environment_id);
 
         let mut request = SignedRequest::new("GET", "appconfig", &self.region, &request_uri);
+        request.set_operation("GetDeployment");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
+        request
+    }
+
+    /// Calls `get_deployment_strategy`, also returning the metadata of the response.
+    pub async fn get_deployment_strategy_with_metadata(
+        &self,
+        input: GetDeploymentStrategyRequest,
+    ) -> Result<(DeploymentStrategy, ResponseMetadata), RusotoError<GetDeploymentStrategyError>>
+    {
+        let request = self.build_get_deployment_strategy_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            let result =
-                proto::json::ResponsePayload::new(&response).deserialize::<Deployment, _>()?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DeploymentStrategy, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(GetDeploymentError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(GetDeploymentStrategyError::from_response(response))
         }
     }
 
-    /// <p>Retrieve information about a deployment strategy. A deployment strategy defines important criteria for rolling out your configuration to the designated targets. A deployment strategy includes: the overall duration required, a percentage of targets to receive the deployment during each interval, an algorithm that defines how percentage grows, and bake time.</p>
-    async fn get_deployment_strategy(
+    /// Presigns the request `get_deployment_strategy` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_deployment_strategy(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetDeploymentStrategyRequest,
-    ) -> Result<DeploymentStrategy, RusotoError<GetDeploymentStrategyError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_deployment_strategy_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_deployment_strategy_request(
+        &self,
+        input: GetDeploymentStrategyRequest,
+    ) -> SignedRequest {
         let request_uri = format!(
             "/deploymentstrategies/{deployment_strategy_id}",
             deployment_strategy_id = input.deployment_strategy_id
         );
 
         let mut request = SignedRequest::new("GET", "appconfig", &self.region, &request_uri);
+        request.set_operation("GetDeploymentStrategy");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
+        request
+    }
+
+    /// Calls `get_environment`, also returning the metadata of the response.
+    pub async fn get_environment_with_metadata(
+        &self,
+        input: GetEnvironmentRequest,
+    ) -> Result<(Environment, ResponseMetadata), RusotoError<GetEnvironmentError>> {
+        let request = self.build_get_environment_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<DeploymentStrategy, _>()?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result =
+                proto::json::ResponsePayload::new(&response).deserialize::<Environment, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(GetDeploymentStrategyError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(GetEnvironmentError::from_response(response))
         }
     }
 
-    /// <p>Retrieve information about an environment. An environment is a logical deployment group of AppConfig applications, such as applications in a <code>Production</code> environment or in an <code>EU_Region</code> environment. Each configuration deployment targets an environment. You can enable one or more Amazon CloudWatch alarms for an environment. If an alarm is triggered during a deployment, AppConfig roles back the configuration.</p>
-    async fn get_environment(
+    /// Presigns the request `get_environment` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_environment(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetEnvironmentRequest,
-    ) -> Result<Environment, RusotoError<GetEnvironmentError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_environment_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_environment_request(&self, input: GetEnvironmentRequest) -> SignedRequest {
         let request_uri = format!(
             "/applications/{application_id}/environments/{environment_id}",
             application_id = input.application_id,
@@ -2775,33 +3417,58 @@ impl AppConfig for AppConfigClient {
         );
 
         let mut request = SignedRequest::new("GET", "appconfig", &self.region, &request_uri);
+        request.set_operation("GetEnvironment");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
+        request
+    }
+
+    /// Calls `list_applications`, also returning the metadata of the response.
+    pub async fn list_applications_with_metadata(
+        &self,
+        input: ListApplicationsRequest,
+    ) -> Result<(Applications, ResponseMetadata), RusotoError<ListApplicationsError>> {
+        let request = self.build_list_applications_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result =
-                proto::json::ResponsePayload::new(&response).deserialize::<Environment, _>()?;
+                proto::json::ResponsePayload::new(&response).deserialize::<Applications, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(GetEnvironmentError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(ListApplicationsError::from_response(response))
         }
     }
 
-    /// <p>List all applications in your AWS account.</p>
-    async fn list_applications(
+    /// Presigns the request `list_applications` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_applications(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListApplicationsRequest,
-    ) -> Result<Applications, RusotoError<ListApplicationsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_applications_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_applications_request(&self, input: ListApplicationsRequest) -> SignedRequest {
         let request_uri = "/applications";
 
         let mut request = SignedRequest::new("GET", "appconfig", &self.region, &request_uri);
+        request.set_operation("ListApplications");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -2813,34 +3480,64 @@ impl AppConfig for AppConfigClient {
         }
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `list_configuration_profiles`, also returning the metadata of the response.
+    pub async fn list_configuration_profiles_with_metadata(
+        &self,
+        input: ListConfigurationProfilesRequest,
+    ) -> Result<
+        (ConfigurationProfiles, ResponseMetadata),
+        RusotoError<ListConfigurationProfilesError>,
+    > {
+        let request = self.build_list_configuration_profiles_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            let result =
-                proto::json::ResponsePayload::new(&response).deserialize::<Applications, _>()?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ConfigurationProfiles, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(ListApplicationsError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(ListConfigurationProfilesError::from_response(response))
         }
     }
 
-    /// <p>Lists the configuration profiles for an application.</p>
-    async fn list_configuration_profiles(
+    /// Presigns the request `list_configuration_profiles` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_configuration_profiles(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListConfigurationProfilesRequest,
-    ) -> Result<ConfigurationProfiles, RusotoError<ListConfigurationProfilesError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_configuration_profiles_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_configuration_profiles_request(
+        &self,
+        input: ListConfigurationProfilesRequest,
+    ) -> SignedRequest {
         let request_uri = format!(
             "/applications/{application_id}/configurationprofiles",
             application_id = input.application_id
         );
 
         let mut request = SignedRequest::new("GET", "appconfig", &self.region, &request_uri);
+        request.set_operation("ListConfigurationProfiles");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -2852,31 +3549,59 @@ impl AppConfig for AppConfigClient {
         }
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `list_deployment_strategies`, also returning the metadata of the response.
+    pub async fn list_deployment_strategies_with_metadata(
+        &self,
+        input: ListDeploymentStrategiesRequest,
+    ) -> Result<(DeploymentStrategies, ResponseMetadata), RusotoError<ListDeploymentStrategiesError>>
+    {
+        let request = self.build_list_deployment_strategies_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<ConfigurationProfiles, _>()?;
+                .deserialize::<DeploymentStrategies, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(ListConfigurationProfilesError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(ListDeploymentStrategiesError::from_response(response))
         }
     }
 
-    /// <p>List deployment strategies.</p>
-    async fn list_deployment_strategies(
+    /// Presigns the request `list_deployment_strategies` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_deployment_strategies(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListDeploymentStrategiesRequest,
-    ) -> Result<DeploymentStrategies, RusotoError<ListDeploymentStrategiesError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_deployment_strategies_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_deployment_strategies_request(
+        &self,
+        input: ListDeploymentStrategiesRequest,
+    ) -> SignedRequest {
         let request_uri = "/deploymentstrategies";
 
         let mut request = SignedRequest::new("GET", "appconfig", &self.region, &request_uri);
+        request.set_operation("ListDeploymentStrategies");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -2888,28 +3613,51 @@ impl AppConfig for AppConfigClient {
         }
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `list_deployments`, also returning the metadata of the response.
+    pub async fn list_deployments_with_metadata(
+        &self,
+        input: ListDeploymentsRequest,
+    ) -> Result<(Deployments, ResponseMetadata), RusotoError<ListDeploymentsError>> {
+        let request = self.build_list_deployments_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<DeploymentStrategies, _>()?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result =
+                proto::json::ResponsePayload::new(&response).deserialize::<Deployments, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(ListDeploymentStrategiesError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(ListDeploymentsError::from_response(response))
         }
     }
 
-    /// <p>Lists the deployments for an environment.</p>
-    async fn list_deployments(
+    /// Presigns the request `list_deployments` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_deployments(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListDeploymentsRequest,
-    ) -> Result<Deployments, RusotoError<ListDeploymentsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_deployments_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_deployments_request(&self, input: ListDeploymentsRequest) -> SignedRequest {
         let request_uri = format!(
             "/applications/{application_id}/environments/{environment_id}/deployments",
             application_id = input.application_id,
@@ -2917,6 +3665,7 @@ impl AppConfig for AppConfigClient {
         );
 
         let mut request = SignedRequest::new("GET", "appconfig", &self.region, &request_uri);
+        request.set_operation("ListDeployments");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -2928,34 +3677,58 @@ impl AppConfig for AppConfigClient {
         }
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `list_environments`, also returning the metadata of the response.
+    pub async fn list_environments_with_metadata(
+        &self,
+        input: ListEnvironmentsRequest,
+    ) -> Result<(Environments, ResponseMetadata), RusotoError<ListEnvironmentsError>> {
+        let request = self.build_list_environments_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result =
-                proto::json::ResponsePayload::new(&response).deserialize::<Deployments, _>()?;
+                proto::json::ResponsePayload::new(&response).deserialize::<Environments, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(ListDeploymentsError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(ListEnvironmentsError::from_response(response))
         }
     }
 
-    /// <p>List the environments for an application.</p>
-    async fn list_environments(
+    /// Presigns the request `list_environments` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_environments(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListEnvironmentsRequest,
-    ) -> Result<Environments, RusotoError<ListEnvironmentsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_environments_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_environments_request(&self, input: ListEnvironmentsRequest) -> SignedRequest {
         let request_uri = format!(
             "/applications/{application_id}/environments",
             application_id = input.application_id
         );
 
         let mut request = SignedRequest::new("GET", "appconfig", &self.region, &request_uri);
+        request.set_operation("ListEnvironments");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -2967,55 +3740,105 @@ impl AppConfig for AppConfigClient {
         }
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `list_tags_for_resource`, also returning the metadata of the response.
+    pub async fn list_tags_for_resource_with_metadata(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> Result<(ResourceTags, ResponseMetadata), RusotoError<ListTagsForResourceError>> {
+        let request = self.build_list_tags_for_resource_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result =
-                proto::json::ResponsePayload::new(&response).deserialize::<Environments, _>()?;
+                proto::json::ResponsePayload::new(&response).deserialize::<ResourceTags, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(ListEnvironmentsError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(ListTagsForResourceError::from_response(response))
         }
     }
 
-    /// <p>Retrieves the list of key-value tags assigned to the resource.</p>
-    async fn list_tags_for_resource(
+    /// Presigns the request `list_tags_for_resource` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_tags_for_resource(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListTagsForResourceRequest,
-    ) -> Result<ResourceTags, RusotoError<ListTagsForResourceError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_tags_for_resource_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_tags_for_resource_request(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> SignedRequest {
         let request_uri = format!("/tags/{resource_arn}", resource_arn = input.resource_arn);
 
         let mut request = SignedRequest::new("GET", "appconfig", &self.region, &request_uri);
+        request.set_operation("ListTagsForResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
+        request
+    }
+
+    /// Calls `start_deployment`, also returning the metadata of the response.
+    pub async fn start_deployment_with_metadata(
+        &self,
+        input: StartDeploymentRequest,
+    ) -> Result<(Deployment, ResponseMetadata), RusotoError<StartDeploymentError>> {
+        let request = self.build_start_deployment_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
-        if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let metadata = ResponseMetadata::from_response(&response);
+        if response.status.as_u16() == 201 {
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result =
-                proto::json::ResponsePayload::new(&response).deserialize::<ResourceTags, _>()?;
+                proto::json::ResponsePayload::new(&response).deserialize::<Deployment, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(ListTagsForResourceError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(StartDeploymentError::from_response(response))
         }
     }
 
-    /// <p>Starts a deployment.</p>
-    async fn start_deployment(
+    /// Presigns the request `start_deployment` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_start_deployment(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: StartDeploymentRequest,
-    ) -> Result<Deployment, RusotoError<StartDeploymentError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_start_deployment_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_start_deployment_request(&self, input: StartDeploymentRequest) -> SignedRequest {
         let request_uri = format!(
             "/applications/{application_id}/environments/{environment_id}/deployments",
             application_id = input.application_id,
@@ -3023,92 +3846,164 @@ impl AppConfig for AppConfigClient {
         );
 
         let mut request = SignedRequest::new("POST", "appconfig", &self.region, &request_uri);
+        request.set_operation("StartDeployment");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `stop_deployment`, also returning the metadata of the response.
+    pub async fn stop_deployment_with_metadata(
+        &self,
+        input: StopDeploymentRequest,
+    ) -> Result<(Deployment, ResponseMetadata), RusotoError<StopDeploymentError>> {
+        let request = self.build_stop_deployment_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
-        if response.status.as_u16() == 201 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let metadata = ResponseMetadata::from_response(&response);
+        if response.status.as_u16() == 202 {
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result =
                 proto::json::ResponsePayload::new(&response).deserialize::<Deployment, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(StartDeploymentError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(StopDeploymentError::from_response(response))
         }
     }
 
-    /// <p>Stops a deployment. This API action works only on deployments that have a status of <code>DEPLOYING</code>. This action moves the deployment to a status of <code>ROLLED_BACK</code>.</p>
-    async fn stop_deployment(
+    /// Presigns the request `stop_deployment` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_stop_deployment(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: StopDeploymentRequest,
-    ) -> Result<Deployment, RusotoError<StopDeploymentError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_stop_deployment_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_stop_deployment_request(&self, input: StopDeploymentRequest) -> SignedRequest {
         let request_uri = format!("/applications/{application_id}/environments/{environment_id}/deployments/{deployment_number}", application_id = input.application_id, deployment_number = input.deployment_number, environment_id = input.environment_id);
 
         let mut request = SignedRequest::new("DELETE", "appconfig", &self.region, &request_uri);
+        request.set_operation("StopDeployment");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
+        request
+    }
+
+    /// Calls `tag_resource`, also returning the metadata of the response.
+    pub async fn tag_resource_with_metadata(
+        &self,
+        input: TagResourceRequest,
+    ) -> Result<((), ResponseMetadata), RusotoError<TagResourceError>> {
+        let request = self.build_tag_resource_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
-        if response.status.as_u16() == 202 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            let result =
-                proto::json::ResponsePayload::new(&response).deserialize::<Deployment, _>()?;
+        let metadata = ResponseMetadata::from_response(&response);
+        if response.status.as_u16() == 204 {
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = ::std::mem::drop(response);
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(StopDeploymentError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(TagResourceError::from_response(response))
         }
     }
 
-    /// <p>Metadata to assign to an AppConfig resource. Tags help organize and categorize your AppConfig resources. Each tag consists of a key and an optional value, both of which you define. You can specify a maximum of 50 tags for a resource.</p>
-    async fn tag_resource(
+    /// Presigns the request `tag_resource` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_tag_resource(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: TagResourceRequest,
-    ) -> Result<(), RusotoError<TagResourceError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_tag_resource_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_tag_resource_request(&self, input: TagResourceRequest) -> SignedRequest {
         let request_uri = format!("/tags/{resource_arn}", resource_arn = input.resource_arn);
 
         let mut request = SignedRequest::new("POST", "appconfig", &self.region, &request_uri);
+        request.set_operation("TagResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `untag_resource`, also returning the metadata of the response.
+    pub async fn untag_resource_with_metadata(
+        &self,
+        input: UntagResourceRequest,
+    ) -> Result<((), ResponseMetadata), RusotoError<UntagResourceError>> {
+        let request = self.build_untag_resource_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 204 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = ::std::mem::drop(response);
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(TagResourceError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(UntagResourceError::from_response(response))
         }
     }
 
-    /// <p>Deletes a tag key and value from an AppConfig resource.</p>
-    async fn untag_resource(
+    /// Presigns the request `untag_resource` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_untag_resource(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UntagResourceRequest,
-    ) -> Result<(), RusotoError<UntagResourceError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_untag_resource_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_untag_resource_request(&self, input: UntagResourceRequest) -> SignedRequest {
         let request_uri = format!("/tags/{resource_arn}", resource_arn = input.resource_arn);
 
         let mut request = SignedRequest::new("DELETE", "appconfig", &self.region, &request_uri);
+        request.set_operation("UntagResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -3117,60 +4012,114 @@ impl AppConfig for AppConfigClient {
         }
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `update_application`, also returning the metadata of the response.
+    pub async fn update_application_with_metadata(
+        &self,
+        input: UpdateApplicationRequest,
+    ) -> Result<(Application, ResponseMetadata), RusotoError<UpdateApplicationError>> {
+        let request = self.build_update_application_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
-        if response.status.as_u16() == 204 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            let result = ::std::mem::drop(response);
+        let metadata = ResponseMetadata::from_response(&response);
+        if response.status.as_u16() == 200 {
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result =
+                proto::json::ResponsePayload::new(&response).deserialize::<Application, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(UntagResourceError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(UpdateApplicationError::from_response(response))
         }
     }
 
-    /// <p>Updates an application.</p>
-    async fn update_application(
+    /// Presigns the request `update_application` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_application(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UpdateApplicationRequest,
-    ) -> Result<Application, RusotoError<UpdateApplicationError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_application_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_application_request(&self, input: UpdateApplicationRequest) -> SignedRequest {
         let request_uri = format!(
             "/applications/{application_id}",
             application_id = input.application_id
         );
 
         let mut request = SignedRequest::new("PATCH", "appconfig", &self.region, &request_uri);
+        request.set_operation("UpdateApplication");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `update_configuration_profile`, also returning the metadata of the response.
+    pub async fn update_configuration_profile_with_metadata(
+        &self,
+        input: UpdateConfigurationProfileRequest,
+    ) -> Result<
+        (ConfigurationProfile, ResponseMetadata),
+        RusotoError<UpdateConfigurationProfileError>,
+    > {
+        let request = self.build_update_configuration_profile_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            let result =
-                proto::json::ResponsePayload::new(&response).deserialize::<Application, _>()?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ConfigurationProfile, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(UpdateApplicationError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(UpdateConfigurationProfileError::from_response(response))
         }
     }
 
-    /// <p>Updates a configuration profile.</p>
-    async fn update_configuration_profile(
+    /// Presigns the request `update_configuration_profile` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_configuration_profile(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UpdateConfigurationProfileRequest,
-    ) -> Result<ConfigurationProfile, RusotoError<UpdateConfigurationProfileError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_configuration_profile_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_configuration_profile_request(
+        &self,
+        input: UpdateConfigurationProfileRequest,
+    ) -> SignedRequest {
         let request_uri = format!(
             "/applications/{application_id}/configurationprofiles/{configuration_profile_id}",
             application_id = input.application_id,
@@ -3178,66 +4127,118 @@ impl AppConfig for AppConfigClient {
         );
 
         let mut request = SignedRequest::new("PATCH", "appconfig", &self.region, &request_uri);
+        request.set_operation("UpdateConfigurationProfile");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `update_deployment_strategy`, also returning the metadata of the response.
+    pub async fn update_deployment_strategy_with_metadata(
+        &self,
+        input: UpdateDeploymentStrategyRequest,
+    ) -> Result<(DeploymentStrategy, ResponseMetadata), RusotoError<UpdateDeploymentStrategyError>>
+    {
+        let request = self.build_update_deployment_strategy_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<ConfigurationProfile, _>()?;
+                .deserialize::<DeploymentStrategy, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(UpdateConfigurationProfileError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(UpdateDeploymentStrategyError::from_response(response))
         }
     }
 
-    /// <p>Updates a deployment strategy.</p>
-    async fn update_deployment_strategy(
+    /// Presigns the request `update_deployment_strategy` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_deployment_strategy(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UpdateDeploymentStrategyRequest,
-    ) -> Result<DeploymentStrategy, RusotoError<UpdateDeploymentStrategyError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_deployment_strategy_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_deployment_strategy_request(
+        &self,
+        input: UpdateDeploymentStrategyRequest,
+    ) -> SignedRequest {
         let request_uri = format!(
             "/deploymentstrategies/{deployment_strategy_id}",
             deployment_strategy_id = input.deployment_strategy_id
         );
 
         let mut request = SignedRequest::new("PATCH", "appconfig", &self.region, &request_uri);
+        request.set_operation("UpdateDeploymentStrategy");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `update_environment`, also returning the metadata of the response.
+    pub async fn update_environment_with_metadata(
+        &self,
+        input: UpdateEnvironmentRequest,
+    ) -> Result<(Environment, ResponseMetadata), RusotoError<UpdateEnvironmentError>> {
+        let request = self.build_update_environment_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<DeploymentStrategy, _>()?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result =
+                proto::json::ResponsePayload::new(&response).deserialize::<Environment, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(UpdateDeploymentStrategyError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(UpdateEnvironmentError::from_response(response))
         }
     }
 
-    /// <p>Updates an environment.</p>
-    async fn update_environment(
+    /// Presigns the request `update_environment` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_environment(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UpdateEnvironmentRequest,
-    ) -> Result<Environment, RusotoError<UpdateEnvironmentError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_environment_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_environment_request(&self, input: UpdateEnvironmentRequest) -> SignedRequest {
         let request_uri = format!(
             "/applications/{application_id}/environments/{environment_id}",
             application_id = input.application_id,
@@ -3245,55 +4246,650 @@ impl AppConfig for AppConfigClient {
         );
 
         let mut request = SignedRequest::new("PATCH", "appconfig", &self.region, &request_uri);
+        request.set_operation("UpdateEnvironment");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `validate_configuration`, also returning the metadata of the response.
+    pub async fn validate_configuration_with_metadata(
+        &self,
+        input: ValidateConfigurationRequest,
+    ) -> Result<((), ResponseMetadata), RusotoError<ValidateConfigurationError>> {
+        let request = self.build_validate_configuration_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
-        if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            let result =
-                proto::json::ResponsePayload::new(&response).deserialize::<Environment, _>()?;
+        let metadata = ResponseMetadata::from_response(&response);
+        if response.status.as_u16() == 204 {
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = ::std::mem::drop(response);
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(UpdateEnvironmentError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(ValidateConfigurationError::from_response(response))
         }
     }
 
-    /// <p>Uses the validators in a configuration profile to validate a configuration.</p>
-    async fn validate_configuration(
+    /// Presigns the request `validate_configuration` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_validate_configuration(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ValidateConfigurationRequest,
-    ) -> Result<(), RusotoError<ValidateConfigurationError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_validate_configuration_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_validate_configuration_request(
+        &self,
+        input: ValidateConfigurationRequest,
+    ) -> SignedRequest {
         let request_uri = format!("/applications/{application_id}/configurationprofiles/{configuration_profile_id}/validators", application_id = input.application_id, configuration_profile_id = input.configuration_profile_id);
 
         let mut request = SignedRequest::new("POST", "appconfig", &self.region, &request_uri);
+        request.set_operation("ValidateConfiguration");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
         params.put("configuration_version", &input.configuration_version);
         request.set_params(params);
 
-        let mut response = self
-            .client
-            .sign_and_dispatch(request)
-            .await
-            .map_err(RusotoError::from)?;
-        if response.status.as_u16() == 204 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            let result = ::std::mem::drop(response);
+        request
+    }
 
-            Ok(result)
-        } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(ValidateConfigurationError::from_response(response))
-        }
+    /// The pages of `ListApplications` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `MaxResults` of `input`.
+    pub fn list_applications_pages(
+        &self,
+        input: ListApplicationsRequest,
+    ) -> impl futures::Stream<Item = Result<Applications, RusotoError<ListApplicationsError>>> + Send
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: AppConfigClient, input| async move { client.list_applications(input).await },
+            |input: &ListApplicationsRequest, output: &Applications| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListApplicationsRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `Items` of every page of `ListApplications` results for `input`, as `list_applications_pages` requests them.
+    pub fn list_applications_stream(
+        &self,
+        input: ListApplicationsRequest,
+    ) -> impl futures::Stream<Item = Result<Application, RusotoError<ListApplicationsError>>> + Send
+    {
+        rusoto_core::pagination::items(self.list_applications_pages(input), |page| {
+            page.items.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `ListConfigurationProfiles` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `MaxResults` of `input`.
+    pub fn list_configuration_profiles_pages(
+        &self,
+        input: ListConfigurationProfilesRequest,
+    ) -> impl futures::Stream<
+        Item = Result<ConfigurationProfiles, RusotoError<ListConfigurationProfilesError>>,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: AppConfigClient, input| async move {
+                client.list_configuration_profiles(input).await
+            },
+            |input: &ListConfigurationProfilesRequest, output: &ConfigurationProfiles| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListConfigurationProfilesRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `Items` of every page of `ListConfigurationProfiles` results for `input`, as `list_configuration_profiles_pages` requests them.
+    pub fn list_configuration_profiles_stream(
+        &self,
+        input: ListConfigurationProfilesRequest,
+    ) -> impl futures::Stream<
+        Item = Result<ConfigurationProfileSummary, RusotoError<ListConfigurationProfilesError>>,
+    > + Send {
+        rusoto_core::pagination::items(self.list_configuration_profiles_pages(input), |page| {
+            page.items.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `ListDeploymentStrategies` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `MaxResults` of `input`.
+    pub fn list_deployment_strategies_pages(
+        &self,
+        input: ListDeploymentStrategiesRequest,
+    ) -> impl futures::Stream<
+        Item = Result<DeploymentStrategies, RusotoError<ListDeploymentStrategiesError>>,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: AppConfigClient, input| async move {
+                client.list_deployment_strategies(input).await
+            },
+            |input: &ListDeploymentStrategiesRequest, output: &DeploymentStrategies| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListDeploymentStrategiesRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `Items` of every page of `ListDeploymentStrategies` results for `input`, as `list_deployment_strategies_pages` requests them.
+    pub fn list_deployment_strategies_stream(
+        &self,
+        input: ListDeploymentStrategiesRequest,
+    ) -> impl futures::Stream<
+        Item = Result<DeploymentStrategy, RusotoError<ListDeploymentStrategiesError>>,
+    > + Send {
+        rusoto_core::pagination::items(self.list_deployment_strategies_pages(input), |page| {
+            page.items.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `ListDeployments` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `MaxResults` of `input`.
+    pub fn list_deployments_pages(
+        &self,
+        input: ListDeploymentsRequest,
+    ) -> impl futures::Stream<Item = Result<Deployments, RusotoError<ListDeploymentsError>>> + Send
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: AppConfigClient, input| async move { client.list_deployments(input).await },
+            |input: &ListDeploymentsRequest, output: &Deployments| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListDeploymentsRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `Items` of every page of `ListDeployments` results for `input`, as `list_deployments_pages` requests them.
+    pub fn list_deployments_stream(
+        &self,
+        input: ListDeploymentsRequest,
+    ) -> impl futures::Stream<Item = Result<DeploymentSummary, RusotoError<ListDeploymentsError>>> + Send
+    {
+        rusoto_core::pagination::items(self.list_deployments_pages(input), |page| {
+            page.items.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `ListEnvironments` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `MaxResults` of `input`.
+    pub fn list_environments_pages(
+        &self,
+        input: ListEnvironmentsRequest,
+    ) -> impl futures::Stream<Item = Result<Environments, RusotoError<ListEnvironmentsError>>> + Send
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: AppConfigClient, input| async move { client.list_environments(input).await },
+            |input: &ListEnvironmentsRequest, output: &Environments| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListEnvironmentsRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `Items` of every page of `ListEnvironments` results for `input`, as `list_environments_pages` requests them.
+    pub fn list_environments_stream(
+        &self,
+        input: ListEnvironmentsRequest,
+    ) -> impl futures::Stream<Item = Result<Environment, RusotoError<ListEnvironmentsError>>> + Send
+    {
+        rusoto_core::pagination::items(self.list_environments_pages(input), |page| {
+            page.items.unwrap_or_default()
+        })
+    }
+
+    /// Waits for `DeploymentComplete`, polling `GetDeployment` with `input` every 30 seconds, up to 999 times.
+    pub async fn wait_until_deployment_complete(
+        &self,
+        input: GetDeploymentRequest,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<GetDeploymentError>> {
+        let waiter = rusoto_core::waiter::Waiter::new(std::time::Duration::from_secs(30), 999);
+        self.wait_until_deployment_complete_with(input, &waiter)
+            .await
+    }
+
+    /// Waits for `DeploymentComplete` like `wait_until_deployment_complete`, with the delay, attempts and runtime of `waiter`.
+    pub async fn wait_until_deployment_complete_with(
+        &self,
+        input: GetDeploymentRequest,
+        waiter: &rusoto_core::waiter::Waiter,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<GetDeploymentError>> {
+        rusoto_core::waiter::wait(
+            waiter,
+            || self.get_deployment(input.clone()),
+            |result| {
+                if match result {
+                    Ok(output) => std::iter::once(output)
+                        .filter_map(|value| value.state.as_ref())
+                        .any(|value| value == "COMPLETE"),
+                    Err(_) => false,
+                } {
+                    return Some(rusoto_core::waiter::WaiterState::Success);
+                }
+                if match result {
+                    Ok(output) => std::iter::once(output)
+                        .filter_map(|value| value.state.as_ref())
+                        .any(|value| value == "ROLLED_BACK"),
+                    Err(_) => false,
+                } {
+                    return Some(rusoto_core::waiter::WaiterState::Failure);
+                }
+                if match result {
+                    Ok(output) => std::iter::once(output)
+                        .filter_map(|value| value.state.as_ref())
+                        .any(|value| value == "REVERTED"),
+                    Err(_) => false,
+                } {
+                    return Some(rusoto_core::waiter::WaiterState::Failure);
+                }
+                None
+            },
+        )
+        .await
+    }
+
+    /// Waits for `EnvironmentReadyForDeployment`, polling `GetEnvironment` with `input` every 30 seconds, up to 999 times.
+    pub async fn wait_until_environment_ready_for_deployment(
+        &self,
+        input: GetEnvironmentRequest,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<GetEnvironmentError>> {
+        let waiter = rusoto_core::waiter::Waiter::new(std::time::Duration::from_secs(30), 999);
+        self.wait_until_environment_ready_for_deployment_with(input, &waiter)
+            .await
+    }
+
+    /// Waits for `EnvironmentReadyForDeployment` like `wait_until_environment_ready_for_deployment`, with the delay, attempts and runtime of `waiter`.
+    pub async fn wait_until_environment_ready_for_deployment_with(
+        &self,
+        input: GetEnvironmentRequest,
+        waiter: &rusoto_core::waiter::Waiter,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<GetEnvironmentError>> {
+        rusoto_core::waiter::wait(
+            waiter,
+            || self.get_environment(input.clone()),
+            |result| {
+                if match result {
+                    Ok(output) => std::iter::once(output)
+                        .filter_map(|value| value.state.as_ref())
+                        .any(|value| value == "ReadyForDeployment"),
+                    Err(_) => false,
+                } {
+                    return Some(rusoto_core::waiter::WaiterState::Success);
+                }
+                if match result {
+                    Ok(output) => std::iter::once(output)
+                        .filter_map(|value| value.state.as_ref())
+                        .any(|value| value == "RolledBack"),
+                    Err(_) => false,
+                } {
+                    return Some(rusoto_core::waiter::WaiterState::Failure);
+                }
+                if match result {
+                    Ok(output) => std::iter::once(output)
+                        .filter_map(|value| value.state.as_ref())
+                        .any(|value| value == "Reverted"),
+                    Err(_) => false,
+                } {
+                    return Some(rusoto_core::waiter::WaiterState::Failure);
+                }
+                None
+            },
+        )
+        .await
+    }
+}
+
+#[async_trait]
+impl AppConfig for AppConfigClient {
+    /// <p>An application in AppConfig is a logical unit of code that provides capabilities for your customers. For example, an application can be a microservice that runs on Amazon EC2 instances, a mobile application installed by your users, a serverless application using Amazon API Gateway and AWS Lambda, or any system you run on behalf of others.</p>
+    async fn create_application(
+        &self,
+        input: CreateApplicationRequest,
+    ) -> Result<Application, RusotoError<CreateApplicationError>> {
+        self.create_application_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Information that enables AppConfig to access the configuration source. Valid configuration sources include Systems Manager (SSM) documents and SSM Parameter Store parameters. A configuration profile includes the following information.</p> <ul> <li> <p>The Uri location of the configuration data.</p> </li> <li> <p>The AWS Identity and Access Management (IAM) role that provides access to the configuration data.</p> </li> <li> <p>A validator for the configuration data. Available validators include either a JSON Schema or an AWS Lambda function.</p> </li> </ul></p>
+    async fn create_configuration_profile(
+        &self,
+        input: CreateConfigurationProfileRequest,
+    ) -> Result<ConfigurationProfile, RusotoError<CreateConfigurationProfileError>> {
+        self.create_configuration_profile_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>A deployment strategy defines important criteria for rolling out your configuration to the designated targets. A deployment strategy includes: the overall duration required, a percentage of targets to receive the deployment during each interval, an algorithm that defines how percentage grows, and bake time.</p>
+    async fn create_deployment_strategy(
+        &self,
+        input: CreateDeploymentStrategyRequest,
+    ) -> Result<DeploymentStrategy, RusotoError<CreateDeploymentStrategyError>> {
+        self.create_deployment_strategy_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>For each application, you define one or more environments. An environment is a logical deployment group of AppConfig targets, such as applications in a <code>Beta</code> or <code>Production</code> environment. You can also define environments for application subcomponents such as the <code>Web</code>, <code>Mobile</code> and <code>Back-end</code> components for your application. You can configure Amazon CloudWatch alarms for each environment. The system monitors alarms during a configuration deployment. If an alarm is triggered, the system rolls back the configuration.</p>
+    async fn create_environment(
+        &self,
+        input: CreateEnvironmentRequest,
+    ) -> Result<Environment, RusotoError<CreateEnvironmentError>> {
+        self.create_environment_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Delete an application. Deleting an application does not delete a configuration from a host.</p>
+    async fn delete_application(
+        &self,
+        input: DeleteApplicationRequest,
+    ) -> Result<(), RusotoError<DeleteApplicationError>> {
+        self.delete_application_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Delete a configuration profile. Deleting a configuration profile does not delete a configuration from a host.</p>
+    async fn delete_configuration_profile(
+        &self,
+        input: DeleteConfigurationProfileRequest,
+    ) -> Result<(), RusotoError<DeleteConfigurationProfileError>> {
+        self.delete_configuration_profile_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Delete a deployment strategy. Deleting a deployment strategy does not delete a configuration from a host.</p>
+    async fn delete_deployment_strategy(
+        &self,
+        input: DeleteDeploymentStrategyRequest,
+    ) -> Result<(), RusotoError<DeleteDeploymentStrategyError>> {
+        self.delete_deployment_strategy_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Delete an environment. Deleting an environment does not delete a configuration from a host.</p>
+    async fn delete_environment(
+        &self,
+        input: DeleteEnvironmentRequest,
+    ) -> Result<(), RusotoError<DeleteEnvironmentError>> {
+        self.delete_environment_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Retrieve information about an application.</p>
+    async fn get_application(
+        &self,
+        input: GetApplicationRequest,
+    ) -> Result<Application, RusotoError<GetApplicationError>> {
+        self.get_application_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Retrieve information about a configuration.</p>
+    async fn get_configuration(
+        &self,
+        input: GetConfigurationRequest,
+    ) -> Result<Configuration, RusotoError<GetConfigurationError>> {
+        self.get_configuration_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Retrieve information about a configuration profile.</p>
+    async fn get_configuration_profile(
+        &self,
+        input: GetConfigurationProfileRequest,
+    ) -> Result<ConfigurationProfile, RusotoError<GetConfigurationProfileError>> {
+        self.get_configuration_profile_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Retrieve information about a configuration deployment.</p>
+    async fn get_deployment(
+        &self,
+        input: GetDeploymentRequest,
+    ) -> Result<Deployment, RusotoError<GetDeploymentError>> {
+        self.get_deployment_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Retrieve information about a deployment strategy. A deployment strategy defines important criteria for rolling out your configuration to the designated targets. A deployment strategy includes: the overall duration required, a percentage of targets to receive the deployment during each interval, an algorithm that defines how percentage grows, and bake time.</p>
+    async fn get_deployment_strategy(
+        &self,
+        input: GetDeploymentStrategyRequest,
+    ) -> Result<DeploymentStrategy, RusotoError<GetDeploymentStrategyError>> {
+        self.get_deployment_strategy_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Retrieve information about an environment. An environment is a logical deployment group of AppConfig applications, such as applications in a <code>Production</code> environment or in an <code>EU_Region</code> environment. Each configuration deployment targets an environment. You can enable one or more Amazon CloudWatch alarms for an environment. If an alarm is triggered during a deployment, AppConfig roles back the configuration.</p>
+    async fn get_environment(
+        &self,
+        input: GetEnvironmentRequest,
+    ) -> Result<Environment, RusotoError<GetEnvironmentError>> {
+        self.get_environment_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>List all applications in your AWS account.</p>
+    async fn list_applications(
+        &self,
+        input: ListApplicationsRequest,
+    ) -> Result<Applications, RusotoError<ListApplicationsError>> {
+        self.list_applications_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Lists the configuration profiles for an application.</p>
+    async fn list_configuration_profiles(
+        &self,
+        input: ListConfigurationProfilesRequest,
+    ) -> Result<ConfigurationProfiles, RusotoError<ListConfigurationProfilesError>> {
+        self.list_configuration_profiles_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>List deployment strategies.</p>
+    async fn list_deployment_strategies(
+        &self,
+        input: ListDeploymentStrategiesRequest,
+    ) -> Result<DeploymentStrategies, RusotoError<ListDeploymentStrategiesError>> {
+        self.list_deployment_strategies_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Lists the deployments for an environment.</p>
+    async fn list_deployments(
+        &self,
+        input: ListDeploymentsRequest,
+    ) -> Result<Deployments, RusotoError<ListDeploymentsError>> {
+        self.list_deployments_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>List the environments for an application.</p>
+    async fn list_environments(
+        &self,
+        input: ListEnvironmentsRequest,
+    ) -> Result<Environments, RusotoError<ListEnvironmentsError>> {
+        self.list_environments_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Retrieves the list of key-value tags assigned to the resource.</p>
+    async fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> Result<ResourceTags, RusotoError<ListTagsForResourceError>> {
+        self.list_tags_for_resource_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Starts a deployment.</p>
+    async fn start_deployment(
+        &self,
+        input: StartDeploymentRequest,
+    ) -> Result<Deployment, RusotoError<StartDeploymentError>> {
+        self.start_deployment_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Stops a deployment. This API action works only on deployments that have a status of <code>DEPLOYING</code>. This action moves the deployment to a status of <code>ROLLED_BACK</code>.</p>
+    async fn stop_deployment(
+        &self,
+        input: StopDeploymentRequest,
+    ) -> Result<Deployment, RusotoError<StopDeploymentError>> {
+        self.stop_deployment_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Metadata to assign to an AppConfig resource. Tags help organize and categorize your AppConfig resources. Each tag consists of a key and an optional value, both of which you define. You can specify a maximum of 50 tags for a resource.</p>
+    async fn tag_resource(
+        &self,
+        input: TagResourceRequest,
+    ) -> Result<(), RusotoError<TagResourceError>> {
+        self.tag_resource_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes a tag key and value from an AppConfig resource.</p>
+    async fn untag_resource(
+        &self,
+        input: UntagResourceRequest,
+    ) -> Result<(), RusotoError<UntagResourceError>> {
+        self.untag_resource_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Updates an application.</p>
+    async fn update_application(
+        &self,
+        input: UpdateApplicationRequest,
+    ) -> Result<Application, RusotoError<UpdateApplicationError>> {
+        self.update_application_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Updates a configuration profile.</p>
+    async fn update_configuration_profile(
+        &self,
+        input: UpdateConfigurationProfileRequest,
+    ) -> Result<ConfigurationProfile, RusotoError<UpdateConfigurationProfileError>> {
+        self.update_configuration_profile_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Updates a deployment strategy.</p>
+    async fn update_deployment_strategy(
+        &self,
+        input: UpdateDeploymentStrategyRequest,
+    ) -> Result<DeploymentStrategy, RusotoError<UpdateDeploymentStrategyError>> {
+        self.update_deployment_strategy_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Updates an environment.</p>
+    async fn update_environment(
+        &self,
+        input: UpdateEnvironmentRequest,
+    ) -> Result<Environment, RusotoError<UpdateEnvironmentError>> {
+        self.update_environment_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Uses the validators in a configuration profile to validate a configuration.</p>
+    async fn validate_configuration(
+        &self,
+        input: ValidateConfigurationRequest,
+    ) -> Result<(), RusotoError<ValidateConfigurationError>> {
+        self.validate_configuration_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
     }
 }
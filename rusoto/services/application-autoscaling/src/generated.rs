@@ -16,8 +16,8 @@ use std::fmt;
 use async_trait::async_trait;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
-use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest, ResponseMetadata};
+use rusoto_core::{Client, ErrorMetadata, RusotoError};
 
 use rusoto_core::proto;
 use rusoto_core::signature::SignedRequest;
@@ -663,6 +663,7 @@ pub struct TargetTrackingScalingPolicyConfiguration {
 
 /// Errors returned by DeleteScalingPolicy
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteScalingPolicyError {
     /// <p>Concurrent updates caused an exception, for example, if you request an update to an Application Auto Scaling resource that already has a pending update.</p>
     ConcurrentUpdate(String),
@@ -670,6 +671,8 @@ pub enum DeleteScalingPolicyError {
     InternalService(String),
     /// <p>The specified object could not be found. For any operation that depends on the existence of a scalable target, this exception is thrown if the scalable target with the specified service namespace, resource ID, and scalable dimension does not exist. For any operation that deletes or deregisters a resource, this exception is thrown if the resource cannot be found.</p>
     ObjectNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteScalingPolicyError {
@@ -677,18 +680,25 @@ impl DeleteScalingPolicyError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentUpdateException" => {
-                    return RusotoError::Service(DeleteScalingPolicyError::ConcurrentUpdate(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteScalingPolicyError::ConcurrentUpdate(err.msg),
+                        &res,
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(DeleteScalingPolicyError::InternalService(err.msg))
+                    return RusotoError::service(
+                        DeleteScalingPolicyError::InternalService(err.msg),
+                        &res,
+                    )
                 }
                 "ObjectNotFoundException" => {
-                    return RusotoError::Service(DeleteScalingPolicyError::ObjectNotFound(err.msg))
+                    return RusotoError::service(
+                        DeleteScalingPolicyError::ObjectNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteScalingPolicyError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -701,12 +711,14 @@ impl fmt::Display for DeleteScalingPolicyError {
             DeleteScalingPolicyError::ConcurrentUpdate(ref cause) => write!(f, "{}", cause),
             DeleteScalingPolicyError::InternalService(ref cause) => write!(f, "{}", cause),
             DeleteScalingPolicyError::ObjectNotFound(ref cause) => write!(f, "{}", cause),
+            DeleteScalingPolicyError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteScalingPolicyError {}
 /// Errors returned by DeleteScheduledAction
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteScheduledActionError {
     /// <p>Concurrent updates caused an exception, for example, if you request an update to an Application Auto Scaling resource that already has a pending update.</p>
     ConcurrentUpdate(String),
@@ -714,6 +726,8 @@ pub enum DeleteScheduledActionError {
     InternalService(String),
     /// <p>The specified object could not be found. For any operation that depends on the existence of a scalable target, this exception is thrown if the scalable target with the specified service namespace, resource ID, and scalable dimension does not exist. For any operation that deletes or deregisters a resource, this exception is thrown if the resource cannot be found.</p>
     ObjectNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteScheduledActionError {
@@ -721,22 +735,25 @@ impl DeleteScheduledActionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentUpdateException" => {
-                    return RusotoError::Service(DeleteScheduledActionError::ConcurrentUpdate(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteScheduledActionError::ConcurrentUpdate(err.msg),
+                        &res,
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(DeleteScheduledActionError::InternalService(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteScheduledActionError::InternalService(err.msg),
+                        &res,
+                    )
                 }
                 "ObjectNotFoundException" => {
-                    return RusotoError::Service(DeleteScheduledActionError::ObjectNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteScheduledActionError::ObjectNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteScheduledActionError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -749,12 +766,14 @@ impl fmt::Display for DeleteScheduledActionError {
             DeleteScheduledActionError::ConcurrentUpdate(ref cause) => write!(f, "{}", cause),
             DeleteScheduledActionError::InternalService(ref cause) => write!(f, "{}", cause),
             DeleteScheduledActionError::ObjectNotFound(ref cause) => write!(f, "{}", cause),
+            DeleteScheduledActionError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteScheduledActionError {}
 /// Errors returned by DeregisterScalableTarget
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeregisterScalableTargetError {
     /// <p>Concurrent updates caused an exception, for example, if you request an update to an Application Auto Scaling resource that already has a pending update.</p>
     ConcurrentUpdate(String),
@@ -762,6 +781,8 @@ pub enum DeregisterScalableTargetError {
     InternalService(String),
     /// <p>The specified object could not be found. For any operation that depends on the existence of a scalable target, this exception is thrown if the scalable target with the specified service namespace, resource ID, and scalable dimension does not exist. For any operation that deletes or deregisters a resource, this exception is thrown if the resource cannot be found.</p>
     ObjectNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeregisterScalableTargetError {
@@ -769,22 +790,25 @@ impl DeregisterScalableTargetError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentUpdateException" => {
-                    return RusotoError::Service(DeregisterScalableTargetError::ConcurrentUpdate(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeregisterScalableTargetError::ConcurrentUpdate(err.msg),
+                        &res,
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(DeregisterScalableTargetError::InternalService(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeregisterScalableTargetError::InternalService(err.msg),
+                        &res,
+                    )
                 }
                 "ObjectNotFoundException" => {
-                    return RusotoError::Service(DeregisterScalableTargetError::ObjectNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeregisterScalableTargetError::ObjectNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeregisterScalableTargetError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -797,12 +821,14 @@ impl fmt::Display for DeregisterScalableTargetError {
             DeregisterScalableTargetError::ConcurrentUpdate(ref cause) => write!(f, "{}", cause),
             DeregisterScalableTargetError::InternalService(ref cause) => write!(f, "{}", cause),
             DeregisterScalableTargetError::ObjectNotFound(ref cause) => write!(f, "{}", cause),
+            DeregisterScalableTargetError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeregisterScalableTargetError {}
 /// Errors returned by DescribeScalableTargets
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeScalableTargetsError {
     /// <p>Concurrent updates caused an exception, for example, if you request an update to an Application Auto Scaling resource that already has a pending update.</p>
     ConcurrentUpdate(String),
@@ -810,6 +836,8 @@ pub enum DescribeScalableTargetsError {
     InternalService(String),
     /// <p>The next token supplied was invalid.</p>
     InvalidNextToken(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeScalableTargetsError {
@@ -817,22 +845,25 @@ impl DescribeScalableTargetsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentUpdateException" => {
-                    return RusotoError::Service(DescribeScalableTargetsError::ConcurrentUpdate(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeScalableTargetsError::ConcurrentUpdate(err.msg),
+                        &res,
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(DescribeScalableTargetsError::InternalService(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeScalableTargetsError::InternalService(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(DescribeScalableTargetsError::InvalidNextToken(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeScalableTargetsError::InvalidNextToken(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DescribeScalableTargetsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -845,12 +876,14 @@ impl fmt::Display for DescribeScalableTargetsError {
             DescribeScalableTargetsError::ConcurrentUpdate(ref cause) => write!(f, "{}", cause),
             DescribeScalableTargetsError::InternalService(ref cause) => write!(f, "{}", cause),
             DescribeScalableTargetsError::InvalidNextToken(ref cause) => write!(f, "{}", cause),
+            DescribeScalableTargetsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeScalableTargetsError {}
 /// Errors returned by DescribeScalingActivities
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeScalingActivitiesError {
     /// <p>Concurrent updates caused an exception, for example, if you request an update to an Application Auto Scaling resource that already has a pending update.</p>
     ConcurrentUpdate(String),
@@ -858,6 +891,8 @@ pub enum DescribeScalingActivitiesError {
     InternalService(String),
     /// <p>The next token supplied was invalid.</p>
     InvalidNextToken(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeScalingActivitiesError {
@@ -865,22 +900,27 @@ impl DescribeScalingActivitiesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentUpdateException" => {
-                    return RusotoError::Service(DescribeScalingActivitiesError::ConcurrentUpdate(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeScalingActivitiesError::ConcurrentUpdate(err.msg),
+                        &res,
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(DescribeScalingActivitiesError::InternalService(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeScalingActivitiesError::InternalService(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(DescribeScalingActivitiesError::InvalidNextToken(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeScalingActivitiesError::InvalidNextToken(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(DescribeScalingActivitiesError::Unhandled, &res)
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -893,12 +933,14 @@ impl fmt::Display for DescribeScalingActivitiesError {
             DescribeScalingActivitiesError::ConcurrentUpdate(ref cause) => write!(f, "{}", cause),
             DescribeScalingActivitiesError::InternalService(ref cause) => write!(f, "{}", cause),
             DescribeScalingActivitiesError::InvalidNextToken(ref cause) => write!(f, "{}", cause),
+            DescribeScalingActivitiesError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeScalingActivitiesError {}
 /// Errors returned by DescribeScalingPolicies
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeScalingPoliciesError {
     /// <p>Concurrent updates caused an exception, for example, if you request an update to an Application Auto Scaling resource that already has a pending update.</p>
     ConcurrentUpdate(String),
@@ -908,6 +950,8 @@ pub enum DescribeScalingPoliciesError {
     InternalService(String),
     /// <p>The next token supplied was invalid.</p>
     InvalidNextToken(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeScalingPoliciesError {
@@ -915,27 +959,31 @@ impl DescribeScalingPoliciesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentUpdateException" => {
-                    return RusotoError::Service(DescribeScalingPoliciesError::ConcurrentUpdate(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeScalingPoliciesError::ConcurrentUpdate(err.msg),
+                        &res,
+                    )
                 }
                 "FailedResourceAccessException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DescribeScalingPoliciesError::FailedResourceAccess(err.msg),
+                        &res,
                     )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(DescribeScalingPoliciesError::InternalService(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeScalingPoliciesError::InternalService(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(DescribeScalingPoliciesError::InvalidNextToken(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeScalingPoliciesError::InvalidNextToken(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DescribeScalingPoliciesError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -949,12 +997,14 @@ impl fmt::Display for DescribeScalingPoliciesError {
             DescribeScalingPoliciesError::FailedResourceAccess(ref cause) => write!(f, "{}", cause),
             DescribeScalingPoliciesError::InternalService(ref cause) => write!(f, "{}", cause),
             DescribeScalingPoliciesError::InvalidNextToken(ref cause) => write!(f, "{}", cause),
+            DescribeScalingPoliciesError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeScalingPoliciesError {}
 /// Errors returned by DescribeScheduledActions
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeScheduledActionsError {
     /// <p>Concurrent updates caused an exception, for example, if you request an update to an Application Auto Scaling resource that already has a pending update.</p>
     ConcurrentUpdate(String),
@@ -962,6 +1012,8 @@ pub enum DescribeScheduledActionsError {
     InternalService(String),
     /// <p>The next token supplied was invalid.</p>
     InvalidNextToken(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeScheduledActionsError {
@@ -969,22 +1021,25 @@ impl DescribeScheduledActionsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentUpdateException" => {
-                    return RusotoError::Service(DescribeScheduledActionsError::ConcurrentUpdate(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeScheduledActionsError::ConcurrentUpdate(err.msg),
+                        &res,
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(DescribeScheduledActionsError::InternalService(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeScheduledActionsError::InternalService(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(DescribeScheduledActionsError::InvalidNextToken(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeScheduledActionsError::InvalidNextToken(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DescribeScheduledActionsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -997,12 +1052,14 @@ impl fmt::Display for DescribeScheduledActionsError {
             DescribeScheduledActionsError::ConcurrentUpdate(ref cause) => write!(f, "{}", cause),
             DescribeScheduledActionsError::InternalService(ref cause) => write!(f, "{}", cause),
             DescribeScheduledActionsError::InvalidNextToken(ref cause) => write!(f, "{}", cause),
+            DescribeScheduledActionsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeScheduledActionsError {}
 /// Errors returned by PutScalingPolicy
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum PutScalingPolicyError {
     /// <p>Concurrent updates caused an exception, for example, if you request an update to an Application Auto Scaling resource that already has a pending update.</p>
     ConcurrentUpdate(String),
@@ -1014,6 +1071,8 @@ pub enum PutScalingPolicyError {
     LimitExceeded(String),
     /// <p>The specified object could not be found. For any operation that depends on the existence of a scalable target, this exception is thrown if the scalable target with the specified service namespace, resource ID, and scalable dimension does not exist. For any operation that deletes or deregisters a resource, this exception is thrown if the resource cannot be found.</p>
     ObjectNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl PutScalingPolicyError {
@@ -1021,24 +1080,37 @@ impl PutScalingPolicyError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentUpdateException" => {
-                    return RusotoError::Service(PutScalingPolicyError::ConcurrentUpdate(err.msg))
+                    return RusotoError::service(
+                        PutScalingPolicyError::ConcurrentUpdate(err.msg),
+                        &res,
+                    )
                 }
                 "FailedResourceAccessException" => {
-                    return RusotoError::Service(PutScalingPolicyError::FailedResourceAccess(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        PutScalingPolicyError::FailedResourceAccess(err.msg),
+                        &res,
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(PutScalingPolicyError::InternalService(err.msg))
+                    return RusotoError::service(
+                        PutScalingPolicyError::InternalService(err.msg),
+                        &res,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(PutScalingPolicyError::LimitExceeded(err.msg))
+                    return RusotoError::service(
+                        PutScalingPolicyError::LimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "ObjectNotFoundException" => {
-                    return RusotoError::Service(PutScalingPolicyError::ObjectNotFound(err.msg))
+                    return RusotoError::service(
+                        PutScalingPolicyError::ObjectNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(PutScalingPolicyError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1053,12 +1125,14 @@ impl fmt::Display for PutScalingPolicyError {
             PutScalingPolicyError::InternalService(ref cause) => write!(f, "{}", cause),
             PutScalingPolicyError::LimitExceeded(ref cause) => write!(f, "{}", cause),
             PutScalingPolicyError::ObjectNotFound(ref cause) => write!(f, "{}", cause),
+            PutScalingPolicyError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for PutScalingPolicyError {}
 /// Errors returned by PutScheduledAction
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum PutScheduledActionError {
     /// <p>Concurrent updates caused an exception, for example, if you request an update to an Application Auto Scaling resource that already has a pending update.</p>
     ConcurrentUpdate(String),
@@ -1068,6 +1142,8 @@ pub enum PutScheduledActionError {
     LimitExceeded(String),
     /// <p>The specified object could not be found. For any operation that depends on the existence of a scalable target, this exception is thrown if the scalable target with the specified service namespace, resource ID, and scalable dimension does not exist. For any operation that deletes or deregisters a resource, this exception is thrown if the resource cannot be found.</p>
     ObjectNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl PutScheduledActionError {
@@ -1075,19 +1151,31 @@ impl PutScheduledActionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentUpdateException" => {
-                    return RusotoError::Service(PutScheduledActionError::ConcurrentUpdate(err.msg))
+                    return RusotoError::service(
+                        PutScheduledActionError::ConcurrentUpdate(err.msg),
+                        &res,
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(PutScheduledActionError::InternalService(err.msg))
+                    return RusotoError::service(
+                        PutScheduledActionError::InternalService(err.msg),
+                        &res,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(PutScheduledActionError::LimitExceeded(err.msg))
+                    return RusotoError::service(
+                        PutScheduledActionError::LimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "ObjectNotFoundException" => {
-                    return RusotoError::Service(PutScheduledActionError::ObjectNotFound(err.msg))
+                    return RusotoError::service(
+                        PutScheduledActionError::ObjectNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(PutScheduledActionError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1101,12 +1189,14 @@ impl fmt::Display for PutScheduledActionError {
             PutScheduledActionError::InternalService(ref cause) => write!(f, "{}", cause),
             PutScheduledActionError::LimitExceeded(ref cause) => write!(f, "{}", cause),
             PutScheduledActionError::ObjectNotFound(ref cause) => write!(f, "{}", cause),
+            PutScheduledActionError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for PutScheduledActionError {}
 /// Errors returned by RegisterScalableTarget
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum RegisterScalableTargetError {
     /// <p>Concurrent updates caused an exception, for example, if you request an update to an Application Auto Scaling resource that already has a pending update.</p>
     ConcurrentUpdate(String),
@@ -1114,6 +1204,8 @@ pub enum RegisterScalableTargetError {
     InternalService(String),
     /// <p>A per-account resource limit is exceeded. For more information, see <a href="https://docs.aws.amazon.com/ApplicationAutoScaling/latest/userguide/application-auto-scaling-limits.html">Application Auto Scaling Limits</a>.</p>
     LimitExceeded(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl RegisterScalableTargetError {
@@ -1121,22 +1213,25 @@ impl RegisterScalableTargetError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentUpdateException" => {
-                    return RusotoError::Service(RegisterScalableTargetError::ConcurrentUpdate(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        RegisterScalableTargetError::ConcurrentUpdate(err.msg),
+                        &res,
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(RegisterScalableTargetError::InternalService(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        RegisterScalableTargetError::InternalService(err.msg),
+                        &res,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(RegisterScalableTargetError::LimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        RegisterScalableTargetError::LimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(RegisterScalableTargetError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1149,6 +1244,7 @@ impl fmt::Display for RegisterScalableTargetError {
             RegisterScalableTargetError::ConcurrentUpdate(ref cause) => write!(f, "{}", cause),
             RegisterScalableTargetError::InternalService(ref cause) => write!(f, "{}", cause),
             RegisterScalableTargetError::LimitExceeded(ref cause) => write!(f, "{}", cause),
+            RegisterScalableTargetError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
@@ -1227,6 +1323,7 @@ impl ApplicationAutoScalingClient {
     /// Creates a client backed by the default tokio event loop.
     ///
     /// The client will use the default credentials provider and tls client.
+    #[cfg(not(target_arch = "wasm32"))]
     pub fn new(region: region::Region) -> ApplicationAutoScalingClient {
         ApplicationAutoScalingClient {
             client: Client::shared(),
@@ -1252,16 +1349,73 @@ impl ApplicationAutoScalingClient {
     pub fn new_with_client(client: Client, region: region::Region) -> ApplicationAutoScalingClient {
         ApplicationAutoScalingClient { client, region }
     }
+
+    /// Returns a builder of a client, setting any of its region, credentials provider,
+    /// dispatcher, retry policy, endpoint and timeouts.
+    pub fn builder() -> rusoto_core::ClientBuilder<ApplicationAutoScalingClient> {
+        rusoto_core::ClientBuilder::new(ApplicationAutoScalingClient::new_with_client)
+    }
+
+    /// Returns a clone of this client with the overrides of `config` applied to its
+    /// operations, sharing its connections.
+    pub fn customize(&self, config: &rusoto_core::OperationConfig) -> ApplicationAutoScalingClient {
+        ApplicationAutoScalingClient {
+            client: self.client.customize(config),
+            region: config.resolve_region(&self.region),
+        }
+    }
 }
 
-#[async_trait]
-impl ApplicationAutoScaling for ApplicationAutoScalingClient {
-    /// <p>Deletes the specified scaling policy for an Application Auto Scaling scalable target.</p> <p>Deleting a step scaling policy deletes the underlying alarm action, but does not delete the CloudWatch alarm associated with the scaling policy, even if it no longer has an associated action.</p> <p>For more information, see <a href="https://docs.aws.amazon.com/autoscaling/application/userguide/application-auto-scaling-step-scaling-policies.html#delete-step-scaling-policy">Delete a Step Scaling Policy</a> and <a href="https://docs.aws.amazon.com/autoscaling/application/userguide/application-auto-scaling-target-tracking.html#delete-target-tracking-policy">Delete a Target Tracking Scaling Policy</a> in the <i>Application Auto Scaling User Guide</i>.</p> <p>To create a scaling policy or update an existing one, see <a>PutScalingPolicy</a>.</p>
-    async fn delete_scaling_policy(
+impl ApplicationAutoScalingClient {
+    /// Calls `delete_scaling_policy`, also returning the metadata of the response.
+    pub async fn delete_scaling_policy_with_metadata(
         &self,
         input: DeleteScalingPolicyRequest,
-    ) -> Result<DeleteScalingPolicyResponse, RusotoError<DeleteScalingPolicyError>> {
+    ) -> Result<
+        (DeleteScalingPolicyResponse, ResponseMetadata),
+        RusotoError<DeleteScalingPolicyError>,
+    > {
+        let request = self.build_delete_scaling_policy_request(input);
+
+        let mut response = self
+            .client
+            .sign_and_dispatch(request)
+            .await
+            .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
+        if response.status.is_success() {
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DeleteScalingPolicyResponse, _>()?;
+            Ok((result, metadata))
+        } else {
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DeleteScalingPolicyError::from_response(response))
+        }
+    }
+
+    /// Presigns the request `delete_scaling_policy` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_scaling_policy(
+        &self,
+        presigner: &rusoto_core::Presigner,
+        input: DeleteScalingPolicyRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_scaling_policy_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_scaling_policy_request(
+        &self,
+        input: DeleteScalingPolicyRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "application-autoscaling", &self.region, "/");
+        request.set_operation("DeleteScalingPolicy");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -1271,28 +1425,58 @@ impl ApplicationAutoScaling for ApplicationAutoScalingClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `delete_scheduled_action`, also returning the metadata of the response.
+    pub async fn delete_scheduled_action_with_metadata(
+        &self,
+        input: DeleteScheduledActionRequest,
+    ) -> Result<
+        (DeleteScheduledActionResponse, ResponseMetadata),
+        RusotoError<DeleteScheduledActionError>,
+    > {
+        let request = self.build_delete_scheduled_action_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DeleteScalingPolicyResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DeleteScheduledActionResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteScalingPolicyError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DeleteScheduledActionError::from_response(response))
         }
     }
 
-    /// <p>Deletes the specified scheduled action for an Application Auto Scaling scalable target.</p> <p>For more information, see <a href="https://docs.aws.amazon.com/autoscaling/application/userguide/application-auto-scaling-scheduled-scaling.html#delete-scheduled-action">Delete a Scheduled Action</a> in the <i>Application Auto Scaling User Guide</i>.</p>
-    async fn delete_scheduled_action(
+    /// Presigns the request `delete_scheduled_action` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_scheduled_action(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteScheduledActionRequest,
-    ) -> Result<DeleteScheduledActionResponse, RusotoError<DeleteScheduledActionError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_scheduled_action_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_scheduled_action_request(
+        &self,
+        input: DeleteScheduledActionRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "application-autoscaling", &self.region, "/");
+        request.set_operation("DeleteScheduledAction");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -1302,28 +1486,58 @@ impl ApplicationAutoScaling for ApplicationAutoScalingClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `deregister_scalable_target`, also returning the metadata of the response.
+    pub async fn deregister_scalable_target_with_metadata(
+        &self,
+        input: DeregisterScalableTargetRequest,
+    ) -> Result<
+        (DeregisterScalableTargetResponse, ResponseMetadata),
+        RusotoError<DeregisterScalableTargetError>,
+    > {
+        let request = self.build_deregister_scalable_target_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DeleteScheduledActionResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DeregisterScalableTargetResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteScheduledActionError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DeregisterScalableTargetError::from_response(response))
         }
     }
 
-    /// <p>Deregisters an Application Auto Scaling scalable target.</p> <p>Deregistering a scalable target deletes the scaling policies that are associated with it.</p> <p>To create a scalable target or update an existing one, see <a>RegisterScalableTarget</a>. </p>
-    async fn deregister_scalable_target(
+    /// Presigns the request `deregister_scalable_target` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_deregister_scalable_target(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeregisterScalableTargetRequest,
-    ) -> Result<DeregisterScalableTargetResponse, RusotoError<DeregisterScalableTargetError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_deregister_scalable_target_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_deregister_scalable_target_request(
+        &self,
+        input: DeregisterScalableTargetRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "application-autoscaling", &self.region, "/");
+        request.set_operation("DeregisterScalableTarget");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -1333,28 +1547,58 @@ impl ApplicationAutoScaling for ApplicationAutoScalingClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `describe_scalable_targets`, also returning the metadata of the response.
+    pub async fn describe_scalable_targets_with_metadata(
+        &self,
+        input: DescribeScalableTargetsRequest,
+    ) -> Result<
+        (DescribeScalableTargetsResponse, ResponseMetadata),
+        RusotoError<DescribeScalableTargetsError>,
+    > {
+        let request = self.build_describe_scalable_targets_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DeregisterScalableTargetResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DescribeScalableTargetsResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DeregisterScalableTargetError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DescribeScalableTargetsError::from_response(response))
         }
     }
 
-    /// <p>Gets information about the scalable targets in the specified namespace.</p> <p>You can filter the results using <code>ResourceIds</code> and <code>ScalableDimension</code>.</p> <p>To create a scalable target or update an existing one, see <a>RegisterScalableTarget</a>. If you are no longer using a scalable target, you can deregister it using <a>DeregisterScalableTarget</a>.</p>
-    async fn describe_scalable_targets(
+    /// Presigns the request `describe_scalable_targets` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_scalable_targets(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeScalableTargetsRequest,
-    ) -> Result<DescribeScalableTargetsResponse, RusotoError<DescribeScalableTargetsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_scalable_targets_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_scalable_targets_request(
+        &self,
+        input: DescribeScalableTargetsRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "application-autoscaling", &self.region, "/");
+        request.set_operation("DescribeScalableTargets");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -1364,29 +1608,58 @@ impl ApplicationAutoScaling for ApplicationAutoScalingClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `describe_scaling_activities`, also returning the metadata of the response.
+    pub async fn describe_scaling_activities_with_metadata(
+        &self,
+        input: DescribeScalingActivitiesRequest,
+    ) -> Result<
+        (DescribeScalingActivitiesResponse, ResponseMetadata),
+        RusotoError<DescribeScalingActivitiesError>,
+    > {
+        let request = self.build_describe_scaling_activities_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DescribeScalableTargetsResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DescribeScalingActivitiesResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeScalableTargetsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DescribeScalingActivitiesError::from_response(response))
         }
     }
 
-    /// <p>Provides descriptive information about the scaling activities in the specified namespace from the previous six weeks.</p> <p>You can filter the results using <code>ResourceId</code> and <code>ScalableDimension</code>.</p> <p>Scaling activities are triggered by CloudWatch alarms that are associated with scaling policies. To view the scaling policies for a service namespace, see <a>DescribeScalingPolicies</a>. To create a scaling policy or update an existing one, see <a>PutScalingPolicy</a>.</p>
-    async fn describe_scaling_activities(
+    /// Presigns the request `describe_scaling_activities` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_scaling_activities(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeScalingActivitiesRequest,
-    ) -> Result<DescribeScalingActivitiesResponse, RusotoError<DescribeScalingActivitiesError>>
-    {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_scaling_activities_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_scaling_activities_request(
+        &self,
+        input: DescribeScalingActivitiesRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "application-autoscaling", &self.region, "/");
+        request.set_operation("DescribeScalingActivities");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -1396,28 +1669,58 @@ impl ApplicationAutoScaling for ApplicationAutoScalingClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `describe_scaling_policies`, also returning the metadata of the response.
+    pub async fn describe_scaling_policies_with_metadata(
+        &self,
+        input: DescribeScalingPoliciesRequest,
+    ) -> Result<
+        (DescribeScalingPoliciesResponse, ResponseMetadata),
+        RusotoError<DescribeScalingPoliciesError>,
+    > {
+        let request = self.build_describe_scaling_policies_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DescribeScalingActivitiesResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DescribeScalingPoliciesResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeScalingActivitiesError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DescribeScalingPoliciesError::from_response(response))
         }
     }
 
-    /// <p>Describes the Application Auto Scaling scaling policies for the specified service namespace.</p> <p>You can filter the results using <code>ResourceId</code>, <code>ScalableDimension</code>, and <code>PolicyNames</code>.</p> <p>To create a scaling policy or update an existing one, see <a>PutScalingPolicy</a>. If you are no longer using a scaling policy, you can delete it using <a>DeleteScalingPolicy</a>.</p>
-    async fn describe_scaling_policies(
+    /// Presigns the request `describe_scaling_policies` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_scaling_policies(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeScalingPoliciesRequest,
-    ) -> Result<DescribeScalingPoliciesResponse, RusotoError<DescribeScalingPoliciesError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_scaling_policies_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_scaling_policies_request(
+        &self,
+        input: DescribeScalingPoliciesRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "application-autoscaling", &self.region, "/");
+        request.set_operation("DescribeScalingPolicies");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -1427,28 +1730,58 @@ impl ApplicationAutoScaling for ApplicationAutoScalingClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `describe_scheduled_actions`, also returning the metadata of the response.
+    pub async fn describe_scheduled_actions_with_metadata(
+        &self,
+        input: DescribeScheduledActionsRequest,
+    ) -> Result<
+        (DescribeScheduledActionsResponse, ResponseMetadata),
+        RusotoError<DescribeScheduledActionsError>,
+    > {
+        let request = self.build_describe_scheduled_actions_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DescribeScalingPoliciesResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DescribeScheduledActionsResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeScalingPoliciesError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DescribeScheduledActionsError::from_response(response))
         }
     }
 
-    /// <p>Describes the Application Auto Scaling scheduled actions for the specified service namespace.</p> <p>You can filter the results using the <code>ResourceId</code>, <code>ScalableDimension</code>, and <code>ScheduledActionNames</code> parameters.</p> <p>To create a scheduled action or update an existing one, see <a>PutScheduledAction</a>. If you are no longer using a scheduled action, you can delete it using <a>DeleteScheduledAction</a>.</p>
-    async fn describe_scheduled_actions(
+    /// Presigns the request `describe_scheduled_actions` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_scheduled_actions(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeScheduledActionsRequest,
-    ) -> Result<DescribeScheduledActionsResponse, RusotoError<DescribeScheduledActionsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_scheduled_actions_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_scheduled_actions_request(
+        &self,
+        input: DescribeScheduledActionsRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "application-autoscaling", &self.region, "/");
+        request.set_operation("DescribeScheduledActions");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -1458,84 +1791,167 @@ impl ApplicationAutoScaling for ApplicationAutoScalingClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `put_scaling_policy`, also returning the metadata of the response.
+    pub async fn put_scaling_policy_with_metadata(
+        &self,
+        input: PutScalingPolicyRequest,
+    ) -> Result<(PutScalingPolicyResponse, ResponseMetadata), RusotoError<PutScalingPolicyError>>
+    {
+        let request = self.build_put_scaling_policy_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DescribeScheduledActionsResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<PutScalingPolicyResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeScheduledActionsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(PutScalingPolicyError::from_response(response))
         }
     }
 
-    /// <p>Creates or updates a policy for an Application Auto Scaling scalable target.</p> <p>Each scalable target is identified by a service namespace, resource ID, and scalable dimension. A scaling policy applies to the scalable target identified by those three attributes. You cannot create a scaling policy until you have registered the resource as a scalable target using <a>RegisterScalableTarget</a>.</p> <p>To update a policy, specify its policy name and the parameters that you want to change. Any parameters that you don't specify are not changed by this update request.</p> <p>You can view the scaling policies for a service namespace using <a>DescribeScalingPolicies</a>. If you are no longer using a scaling policy, you can delete it using <a>DeleteScalingPolicy</a>.</p> <p>Multiple scaling policies can be in force at the same time for the same scalable target. You can have one or more target tracking scaling policies, one or more step scaling policies, or both. However, there is a chance that multiple policies could conflict, instructing the scalable target to scale out or in at the same time. Application Auto Scaling gives precedence to the policy that provides the largest capacity for both scale out and scale in. For example, if one policy increases capacity by 3, another policy increases capacity by 200 percent, and the current capacity is 10, Application Auto Scaling uses the policy with the highest calculated capacity (200% of 10 = 20) and scales out to 30. </p> <p>Learn more about how to work with scaling policies in the <a href="https://docs.aws.amazon.com/autoscaling/application/userguide/what-is-application-auto-scaling.html">Application Auto Scaling User Guide</a>.</p>
-    async fn put_scaling_policy(
+    /// Presigns the request `put_scaling_policy` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_put_scaling_policy(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: PutScalingPolicyRequest,
-    ) -> Result<PutScalingPolicyResponse, RusotoError<PutScalingPolicyError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_put_scaling_policy_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_put_scaling_policy_request(&self, input: PutScalingPolicyRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "application-autoscaling", &self.region, "/");
+        request.set_operation("PutScalingPolicy");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AnyScaleFrontendService.PutScalingPolicy");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `put_scheduled_action`, also returning the metadata of the response.
+    pub async fn put_scheduled_action_with_metadata(
+        &self,
+        input: PutScheduledActionRequest,
+    ) -> Result<(PutScheduledActionResponse, ResponseMetadata), RusotoError<PutScheduledActionError>>
+    {
+        let request = self.build_put_scheduled_action_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<PutScalingPolicyResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<PutScheduledActionResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(PutScalingPolicyError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(PutScheduledActionError::from_response(response))
         }
     }
 
-    /// <p>Creates or updates a scheduled action for an Application Auto Scaling scalable target.</p> <p>Each scalable target is identified by a service namespace, resource ID, and scalable dimension. A scheduled action applies to the scalable target identified by those three attributes. You cannot create a scheduled action until you have registered the resource as a scalable target using <a>RegisterScalableTarget</a>. </p> <p>To update an action, specify its name and the parameters that you want to change. If you don't specify start and end times, the old values are deleted. Any other parameters that you don't specify are not changed by this update request.</p> <p>You can view the scheduled actions using <a>DescribeScheduledActions</a>. If you are no longer using a scheduled action, you can delete it using <a>DeleteScheduledAction</a>.</p> <p>Learn more about how to work with scheduled actions in the <a href="https://docs.aws.amazon.com/autoscaling/application/userguide/what-is-application-auto-scaling.html">Application Auto Scaling User Guide</a>.</p>
-    async fn put_scheduled_action(
+    /// Presigns the request `put_scheduled_action` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_put_scheduled_action(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: PutScheduledActionRequest,
-    ) -> Result<PutScheduledActionResponse, RusotoError<PutScheduledActionError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_put_scheduled_action_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_put_scheduled_action_request(
+        &self,
+        input: PutScheduledActionRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "application-autoscaling", &self.region, "/");
+        request.set_operation("PutScheduledAction");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AnyScaleFrontendService.PutScheduledAction");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `register_scalable_target`, also returning the metadata of the response.
+    pub async fn register_scalable_target_with_metadata(
+        &self,
+        input: RegisterScalableTargetRequest,
+    ) -> Result<
+        (RegisterScalableTargetResponse, ResponseMetadata),
+        RusotoError<RegisterScalableTargetError>,
+    > {
+        let request = self.build_register_scalable_target_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<PutScheduledActionResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<RegisterScalableTargetResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(PutScheduledActionError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(RegisterScalableTargetError::from_response(response))
         }
     }
 
-    /// <p>Registers or updates a scalable target. A scalable target is a resource that Application Auto Scaling can scale out and scale in. Scalable targets are uniquely identified by the combination of resource ID, scalable dimension, and namespace. </p> <p>When you register a new scalable target, you must specify values for minimum and maximum capacity. Application Auto Scaling will not scale capacity to values that are outside of this range. </p> <p>To update a scalable target, specify the parameter that you want to change as well as the following parameters that identify the scalable target: resource ID, scalable dimension, and namespace. Any parameters that you don't specify are not changed by this update request. </p> <p>After you register a scalable target, you do not need to register it again to use other Application Auto Scaling operations. To see which resources have been registered, use <a>DescribeScalableTargets</a>. You can also view the scaling policies for a service namespace by using <a>DescribeScalableTargets</a>. </p> <p>If you no longer need a scalable target, you can deregister it by using <a>DeregisterScalableTarget</a>.</p>
-    async fn register_scalable_target(
+    /// Presigns the request `register_scalable_target` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_register_scalable_target(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: RegisterScalableTargetRequest,
-    ) -> Result<RegisterScalableTargetResponse, RusotoError<RegisterScalableTargetError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_register_scalable_target_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_register_scalable_target_request(
+        &self,
+        input: RegisterScalableTargetRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "application-autoscaling", &self.region, "/");
+        request.set_operation("RegisterScalableTarget");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -1545,19 +1961,272 @@ impl ApplicationAutoScaling for ApplicationAutoScalingClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
-        let mut response = self
-            .client
-            .sign_and_dispatch(request)
+        request
+    }
+
+    /// The pages of `DescribeScalableTargets` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `MaxResults` of `input`.
+    pub fn describe_scalable_targets_pages(
+        &self,
+        input: DescribeScalableTargetsRequest,
+    ) -> impl futures::Stream<
+        Item = Result<DescribeScalableTargetsResponse, RusotoError<DescribeScalableTargetsError>>,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: ApplicationAutoScalingClient, input| async move {
+                client.describe_scalable_targets(input).await
+            },
+            |input: &DescribeScalableTargetsRequest, output: &DescribeScalableTargetsResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(DescribeScalableTargetsRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `ScalableTargets` of every page of `DescribeScalableTargets` results for `input`, as `describe_scalable_targets_pages` requests them.
+    pub fn describe_scalable_targets_stream(
+        &self,
+        input: DescribeScalableTargetsRequest,
+    ) -> impl futures::Stream<Item = Result<ScalableTarget, RusotoError<DescribeScalableTargetsError>>>
+           + Send {
+        rusoto_core::pagination::items(self.describe_scalable_targets_pages(input), |page| {
+            page.scalable_targets.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `DescribeScalingActivities` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `MaxResults` of `input`.
+    pub fn describe_scaling_activities_pages(
+        &self,
+        input: DescribeScalingActivitiesRequest,
+    ) -> impl futures::Stream<
+        Item = Result<
+            DescribeScalingActivitiesResponse,
+            RusotoError<DescribeScalingActivitiesError>,
+        >,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: ApplicationAutoScalingClient, input| async move {
+                client.describe_scaling_activities(input).await
+            },
+            |input: &DescribeScalingActivitiesRequest,
+             output: &DescribeScalingActivitiesResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(DescribeScalingActivitiesRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `ScalingActivities` of every page of `DescribeScalingActivities` results for `input`, as `describe_scaling_activities_pages` requests them.
+    pub fn describe_scaling_activities_stream(
+        &self,
+        input: DescribeScalingActivitiesRequest,
+    ) -> impl futures::Stream<
+        Item = Result<ScalingActivity, RusotoError<DescribeScalingActivitiesError>>,
+    > + Send {
+        rusoto_core::pagination::items(self.describe_scaling_activities_pages(input), |page| {
+            page.scaling_activities.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `DescribeScalingPolicies` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `MaxResults` of `input`.
+    pub fn describe_scaling_policies_pages(
+        &self,
+        input: DescribeScalingPoliciesRequest,
+    ) -> impl futures::Stream<
+        Item = Result<DescribeScalingPoliciesResponse, RusotoError<DescribeScalingPoliciesError>>,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: ApplicationAutoScalingClient, input| async move {
+                client.describe_scaling_policies(input).await
+            },
+            |input: &DescribeScalingPoliciesRequest, output: &DescribeScalingPoliciesResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(DescribeScalingPoliciesRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `ScalingPolicies` of every page of `DescribeScalingPolicies` results for `input`, as `describe_scaling_policies_pages` requests them.
+    pub fn describe_scaling_policies_stream(
+        &self,
+        input: DescribeScalingPoliciesRequest,
+    ) -> impl futures::Stream<Item = Result<ScalingPolicy, RusotoError<DescribeScalingPoliciesError>>>
+           + Send {
+        rusoto_core::pagination::items(self.describe_scaling_policies_pages(input), |page| {
+            page.scaling_policies.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `DescribeScheduledActions` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `MaxResults` of `input`.
+    pub fn describe_scheduled_actions_pages(
+        &self,
+        input: DescribeScheduledActionsRequest,
+    ) -> impl futures::Stream<
+        Item = Result<DescribeScheduledActionsResponse, RusotoError<DescribeScheduledActionsError>>,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: ApplicationAutoScalingClient, input| async move {
+                client.describe_scheduled_actions(input).await
+            },
+            |input: &DescribeScheduledActionsRequest, output: &DescribeScheduledActionsResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(DescribeScheduledActionsRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `ScheduledActions` of every page of `DescribeScheduledActions` results for `input`, as `describe_scheduled_actions_pages` requests them.
+    pub fn describe_scheduled_actions_stream(
+        &self,
+        input: DescribeScheduledActionsRequest,
+    ) -> impl futures::Stream<
+        Item = Result<ScheduledAction, RusotoError<DescribeScheduledActionsError>>,
+    > + Send {
+        rusoto_core::pagination::items(self.describe_scheduled_actions_pages(input), |page| {
+            page.scheduled_actions.unwrap_or_default()
+        })
+    }
+}
+
+#[async_trait]
+impl ApplicationAutoScaling for ApplicationAutoScalingClient {
+    /// <p>Deletes the specified scaling policy for an Application Auto Scaling scalable target.</p> <p>Deleting a step scaling policy deletes the underlying alarm action, but does not delete the CloudWatch alarm associated with the scaling policy, even if it no longer has an associated action.</p> <p>For more information, see <a href="https://docs.aws.amazon.com/autoscaling/application/userguide/application-auto-scaling-step-scaling-policies.html#delete-step-scaling-policy">Delete a Step Scaling Policy</a> and <a href="https://docs.aws.amazon.com/autoscaling/application/userguide/application-auto-scaling-target-tracking.html#delete-target-tracking-policy">Delete a Target Tracking Scaling Policy</a> in the <i>Application Auto Scaling User Guide</i>.</p> <p>To create a scaling policy or update an existing one, see <a>PutScalingPolicy</a>.</p>
+    async fn delete_scaling_policy(
+        &self,
+        input: DeleteScalingPolicyRequest,
+    ) -> Result<DeleteScalingPolicyResponse, RusotoError<DeleteScalingPolicyError>> {
+        self.delete_scaling_policy_with_metadata(input)
             .await
-            .map_err(RusotoError::from)?;
-        if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<RegisterScalableTargetResponse, _>()
-        } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(RegisterScalableTargetError::from_response(response))
-        }
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes the specified scheduled action for an Application Auto Scaling scalable target.</p> <p>For more information, see <a href="https://docs.aws.amazon.com/autoscaling/application/userguide/application-auto-scaling-scheduled-scaling.html#delete-scheduled-action">Delete a Scheduled Action</a> in the <i>Application Auto Scaling User Guide</i>.</p>
+    async fn delete_scheduled_action(
+        &self,
+        input: DeleteScheduledActionRequest,
+    ) -> Result<DeleteScheduledActionResponse, RusotoError<DeleteScheduledActionError>> {
+        self.delete_scheduled_action_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deregisters an Application Auto Scaling scalable target.</p> <p>Deregistering a scalable target deletes the scaling policies that are associated with it.</p> <p>To create a scalable target or update an existing one, see <a>RegisterScalableTarget</a>. </p>
+    async fn deregister_scalable_target(
+        &self,
+        input: DeregisterScalableTargetRequest,
+    ) -> Result<DeregisterScalableTargetResponse, RusotoError<DeregisterScalableTargetError>> {
+        self.deregister_scalable_target_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Gets information about the scalable targets in the specified namespace.</p> <p>You can filter the results using <code>ResourceIds</code> and <code>ScalableDimension</code>.</p> <p>To create a scalable target or update an existing one, see <a>RegisterScalableTarget</a>. If you are no longer using a scalable target, you can deregister it using <a>DeregisterScalableTarget</a>.</p>
+    async fn describe_scalable_targets(
+        &self,
+        input: DescribeScalableTargetsRequest,
+    ) -> Result<DescribeScalableTargetsResponse, RusotoError<DescribeScalableTargetsError>> {
+        self.describe_scalable_targets_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Provides descriptive information about the scaling activities in the specified namespace from the previous six weeks.</p> <p>You can filter the results using <code>ResourceId</code> and <code>ScalableDimension</code>.</p> <p>Scaling activities are triggered by CloudWatch alarms that are associated with scaling policies. To view the scaling policies for a service namespace, see <a>DescribeScalingPolicies</a>. To create a scaling policy or update an existing one, see <a>PutScalingPolicy</a>.</p>
+    async fn describe_scaling_activities(
+        &self,
+        input: DescribeScalingActivitiesRequest,
+    ) -> Result<DescribeScalingActivitiesResponse, RusotoError<DescribeScalingActivitiesError>>
+    {
+        self.describe_scaling_activities_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Describes the Application Auto Scaling scaling policies for the specified service namespace.</p> <p>You can filter the results using <code>ResourceId</code>, <code>ScalableDimension</code>, and <code>PolicyNames</code>.</p> <p>To create a scaling policy or update an existing one, see <a>PutScalingPolicy</a>. If you are no longer using a scaling policy, you can delete it using <a>DeleteScalingPolicy</a>.</p>
+    async fn describe_scaling_policies(
+        &self,
+        input: DescribeScalingPoliciesRequest,
+    ) -> Result<DescribeScalingPoliciesResponse, RusotoError<DescribeScalingPoliciesError>> {
+        self.describe_scaling_policies_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Describes the Application Auto Scaling scheduled actions for the specified service namespace.</p> <p>You can filter the results using the <code>ResourceId</code>, <code>ScalableDimension</code>, and <code>ScheduledActionNames</code> parameters.</p> <p>To create a scheduled action or update an existing one, see <a>PutScheduledAction</a>. If you are no longer using a scheduled action, you can delete it using <a>DeleteScheduledAction</a>.</p>
+    async fn describe_scheduled_actions(
+        &self,
+        input: DescribeScheduledActionsRequest,
+    ) -> Result<DescribeScheduledActionsResponse, RusotoError<DescribeScheduledActionsError>> {
+        self.describe_scheduled_actions_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Creates or updates a policy for an Application Auto Scaling scalable target.</p> <p>Each scalable target is identified by a service namespace, resource ID, and scalable dimension. A scaling policy applies to the scalable target identified by those three attributes. You cannot create a scaling policy until you have registered the resource as a scalable target using <a>RegisterScalableTarget</a>.</p> <p>To update a policy, specify its policy name and the parameters that you want to change. Any parameters that you don't specify are not changed by this update request.</p> <p>You can view the scaling policies for a service namespace using <a>DescribeScalingPolicies</a>. If you are no longer using a scaling policy, you can delete it using <a>DeleteScalingPolicy</a>.</p> <p>Multiple scaling policies can be in force at the same time for the same scalable target. You can have one or more target tracking scaling policies, one or more step scaling policies, or both. However, there is a chance that multiple policies could conflict, instructing the scalable target to scale out or in at the same time. Application Auto Scaling gives precedence to the policy that provides the largest capacity for both scale out and scale in. For example, if one policy increases capacity by 3, another policy increases capacity by 200 percent, and the current capacity is 10, Application Auto Scaling uses the policy with the highest calculated capacity (200% of 10 = 20) and scales out to 30. </p> <p>Learn more about how to work with scaling policies in the <a href="https://docs.aws.amazon.com/autoscaling/application/userguide/what-is-application-auto-scaling.html">Application Auto Scaling User Guide</a>.</p>
+    async fn put_scaling_policy(
+        &self,
+        input: PutScalingPolicyRequest,
+    ) -> Result<PutScalingPolicyResponse, RusotoError<PutScalingPolicyError>> {
+        self.put_scaling_policy_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Creates or updates a scheduled action for an Application Auto Scaling scalable target.</p> <p>Each scalable target is identified by a service namespace, resource ID, and scalable dimension. A scheduled action applies to the scalable target identified by those three attributes. You cannot create a scheduled action until you have registered the resource as a scalable target using <a>RegisterScalableTarget</a>. </p> <p>To update an action, specify its name and the parameters that you want to change. If you don't specify start and end times, the old values are deleted. Any other parameters that you don't specify are not changed by this update request.</p> <p>You can view the scheduled actions using <a>DescribeScheduledActions</a>. If you are no longer using a scheduled action, you can delete it using <a>DeleteScheduledAction</a>.</p> <p>Learn more about how to work with scheduled actions in the <a href="https://docs.aws.amazon.com/autoscaling/application/userguide/what-is-application-auto-scaling.html">Application Auto Scaling User Guide</a>.</p>
+    async fn put_scheduled_action(
+        &self,
+        input: PutScheduledActionRequest,
+    ) -> Result<PutScheduledActionResponse, RusotoError<PutScheduledActionError>> {
+        self.put_scheduled_action_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Registers or updates a scalable target. A scalable target is a resource that Application Auto Scaling can scale out and scale in. Scalable targets are uniquely identified by the combination of resource ID, scalable dimension, and namespace. </p> <p>When you register a new scalable target, you must specify values for minimum and maximum capacity. Application Auto Scaling will not scale capacity to values that are outside of this range. </p> <p>To update a scalable target, specify the parameter that you want to change as well as the following parameters that identify the scalable target: resource ID, scalable dimension, and namespace. Any parameters that you don't specify are not changed by this update request. </p> <p>After you register a scalable target, you do not need to register it again to use other Application Auto Scaling operations. To see which resources have been registered, use <a>DescribeScalableTargets</a>. You can also view the scaling policies for a service namespace by using <a>DescribeScalableTargets</a>. </p> <p>If you no longer need a scalable target, you can deregister it by using <a>DeregisterScalableTarget</a>.</p>
+    async fn register_scalable_target(
+        &self,
+        input: RegisterScalableTargetRequest,
+    ) -> Result<RegisterScalableTargetResponse, RusotoError<RegisterScalableTargetError>> {
+        self.register_scalable_target_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
     }
 }
@@ -16,8 +16,8 @@ use std::fmt;
 use async_trait::async_trait;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
-use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest, ResponseMetadata};
+use rusoto_core::{Client, ErrorMetadata, RusotoError};
 
 use rusoto_core::proto;
 use rusoto_core::signature::SignedRequest;
@@ -911,6 +911,7 @@ pub struct UpdateLogPatternResponse {
 
 /// Errors returned by CreateApplication
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateApplicationError {
     /// <p>The server encountered an internal error and is unable to complete the request.</p>
     InternalServer(String),
@@ -920,6 +921,8 @@ pub enum CreateApplicationError {
     ResourceNotFound(String),
     /// <p>Tags are already registered for the specified application ARN.</p>
     TagsAlreadyExist(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateApplicationError {
@@ -927,19 +930,31 @@ impl CreateApplicationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(CreateApplicationError::InternalServer(err.msg))
+                    return RusotoError::service(
+                        CreateApplicationError::InternalServer(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(CreateApplicationError::ResourceInUse(err.msg))
+                    return RusotoError::service(
+                        CreateApplicationError::ResourceInUse(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(CreateApplicationError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        CreateApplicationError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "TagsAlreadyExistException" => {
-                    return RusotoError::Service(CreateApplicationError::TagsAlreadyExist(err.msg))
+                    return RusotoError::service(
+                        CreateApplicationError::TagsAlreadyExist(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreateApplicationError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -953,12 +968,14 @@ impl fmt::Display for CreateApplicationError {
             CreateApplicationError::ResourceInUse(ref cause) => write!(f, "{}", cause),
             CreateApplicationError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             CreateApplicationError::TagsAlreadyExist(ref cause) => write!(f, "{}", cause),
+            CreateApplicationError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateApplicationError {}
 /// Errors returned by CreateComponent
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateComponentError {
     /// <p>The server encountered an internal error and is unable to complete the request.</p>
     InternalServer(String),
@@ -966,6 +983,8 @@ pub enum CreateComponentError {
     ResourceInUse(String),
     /// <p>The resource does not exist in the customer account.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateComponentError {
@@ -973,16 +992,22 @@ impl CreateComponentError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(CreateComponentError::InternalServer(err.msg))
+                    return RusotoError::service(
+                        CreateComponentError::InternalServer(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(CreateComponentError::ResourceInUse(err.msg))
+                    return RusotoError::service(CreateComponentError::ResourceInUse(err.msg), &res)
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(CreateComponentError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        CreateComponentError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreateComponentError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -995,12 +1020,14 @@ impl fmt::Display for CreateComponentError {
             CreateComponentError::InternalServer(ref cause) => write!(f, "{}", cause),
             CreateComponentError::ResourceInUse(ref cause) => write!(f, "{}", cause),
             CreateComponentError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            CreateComponentError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateComponentError {}
 /// Errors returned by CreateLogPattern
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateLogPatternError {
     /// <p>The server encountered an internal error and is unable to complete the request.</p>
     InternalServer(String),
@@ -1008,6 +1035,8 @@ pub enum CreateLogPatternError {
     ResourceInUse(String),
     /// <p>The resource does not exist in the customer account.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateLogPatternError {
@@ -1015,16 +1044,25 @@ impl CreateLogPatternError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(CreateLogPatternError::InternalServer(err.msg))
+                    return RusotoError::service(
+                        CreateLogPatternError::InternalServer(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(CreateLogPatternError::ResourceInUse(err.msg))
+                    return RusotoError::service(
+                        CreateLogPatternError::ResourceInUse(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(CreateLogPatternError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        CreateLogPatternError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreateLogPatternError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1037,12 +1075,14 @@ impl fmt::Display for CreateLogPatternError {
             CreateLogPatternError::InternalServer(ref cause) => write!(f, "{}", cause),
             CreateLogPatternError::ResourceInUse(ref cause) => write!(f, "{}", cause),
             CreateLogPatternError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            CreateLogPatternError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateLogPatternError {}
 /// Errors returned by DeleteApplication
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteApplicationError {
     /// <p>The request is not understood by the server.</p>
     BadRequest(String),
@@ -1050,6 +1090,8 @@ pub enum DeleteApplicationError {
     InternalServer(String),
     /// <p>The resource does not exist in the customer account.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteApplicationError {
@@ -1057,16 +1099,22 @@ impl DeleteApplicationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(DeleteApplicationError::BadRequest(err.msg))
+                    return RusotoError::service(DeleteApplicationError::BadRequest(err.msg), &res)
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(DeleteApplicationError::InternalServer(err.msg))
+                    return RusotoError::service(
+                        DeleteApplicationError::InternalServer(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteApplicationError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        DeleteApplicationError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteApplicationError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1079,17 +1127,21 @@ impl fmt::Display for DeleteApplicationError {
             DeleteApplicationError::BadRequest(ref cause) => write!(f, "{}", cause),
             DeleteApplicationError::InternalServer(ref cause) => write!(f, "{}", cause),
             DeleteApplicationError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            DeleteApplicationError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteApplicationError {}
 /// Errors returned by DeleteComponent
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteComponentError {
     /// <p>The server encountered an internal error and is unable to complete the request.</p>
     InternalServer(String),
     /// <p>The resource does not exist in the customer account.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteComponentError {
@@ -1097,13 +1149,19 @@ impl DeleteComponentError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(DeleteComponentError::InternalServer(err.msg))
+                    return RusotoError::service(
+                        DeleteComponentError::InternalServer(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteComponentError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        DeleteComponentError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteComponentError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1115,12 +1173,14 @@ impl fmt::Display for DeleteComponentError {
         match *self {
             DeleteComponentError::InternalServer(ref cause) => write!(f, "{}", cause),
             DeleteComponentError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            DeleteComponentError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteComponentError {}
 /// Errors returned by DeleteLogPattern
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteLogPatternError {
     /// <p>The request is not understood by the server.</p>
     BadRequest(String),
@@ -1128,6 +1188,8 @@ pub enum DeleteLogPatternError {
     InternalServer(String),
     /// <p>The resource does not exist in the customer account.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteLogPatternError {
@@ -1135,16 +1197,22 @@ impl DeleteLogPatternError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(DeleteLogPatternError::BadRequest(err.msg))
+                    return RusotoError::service(DeleteLogPatternError::BadRequest(err.msg), &res)
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(DeleteLogPatternError::InternalServer(err.msg))
+                    return RusotoError::service(
+                        DeleteLogPatternError::InternalServer(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteLogPatternError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        DeleteLogPatternError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteLogPatternError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1157,17 +1225,21 @@ impl fmt::Display for DeleteLogPatternError {
             DeleteLogPatternError::BadRequest(ref cause) => write!(f, "{}", cause),
             DeleteLogPatternError::InternalServer(ref cause) => write!(f, "{}", cause),
             DeleteLogPatternError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            DeleteLogPatternError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteLogPatternError {}
 /// Errors returned by DescribeApplication
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeApplicationError {
     /// <p>The server encountered an internal error and is unable to complete the request.</p>
     InternalServer(String),
     /// <p>The resource does not exist in the customer account.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeApplicationError {
@@ -1175,15 +1247,19 @@ impl DescribeApplicationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(DescribeApplicationError::InternalServer(err.msg))
+                    return RusotoError::service(
+                        DescribeApplicationError::InternalServer(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeApplicationError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeApplicationError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DescribeApplicationError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1195,17 +1271,21 @@ impl fmt::Display for DescribeApplicationError {
         match *self {
             DescribeApplicationError::InternalServer(ref cause) => write!(f, "{}", cause),
             DescribeApplicationError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            DescribeApplicationError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeApplicationError {}
 /// Errors returned by DescribeComponent
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeComponentError {
     /// <p>The server encountered an internal error and is unable to complete the request.</p>
     InternalServer(String),
     /// <p>The resource does not exist in the customer account.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeComponentError {
@@ -1213,13 +1293,19 @@ impl DescribeComponentError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(DescribeComponentError::InternalServer(err.msg))
+                    return RusotoError::service(
+                        DescribeComponentError::InternalServer(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeComponentError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        DescribeComponentError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DescribeComponentError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1231,17 +1317,21 @@ impl fmt::Display for DescribeComponentError {
         match *self {
             DescribeComponentError::InternalServer(ref cause) => write!(f, "{}", cause),
             DescribeComponentError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            DescribeComponentError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeComponentError {}
 /// Errors returned by DescribeComponentConfiguration
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeComponentConfigurationError {
     /// <p>The server encountered an internal error and is unable to complete the request.</p>
     InternalServer(String),
     /// <p>The resource does not exist in the customer account.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeComponentConfigurationError {
@@ -1251,17 +1341,24 @@ impl DescribeComponentConfigurationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DescribeComponentConfigurationError::InternalServer(err.msg),
+                        &res,
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DescribeComponentConfigurationError::ResourceNotFound(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        DescribeComponentConfigurationError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -1277,17 +1374,23 @@ impl fmt::Display for DescribeComponentConfigurationError {
             DescribeComponentConfigurationError::ResourceNotFound(ref cause) => {
                 write!(f, "{}", cause)
             }
+            DescribeComponentConfigurationError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for DescribeComponentConfigurationError {}
 /// Errors returned by DescribeComponentConfigurationRecommendation
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeComponentConfigurationRecommendationError {
     /// <p>The server encountered an internal error and is unable to complete the request.</p>
     InternalServer(String),
     /// <p>The resource does not exist in the customer account.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeComponentConfigurationRecommendationError {
@@ -1297,19 +1400,26 @@ impl DescribeComponentConfigurationRecommendationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DescribeComponentConfigurationRecommendationError::InternalServer(err.msg),
+                        &res,
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DescribeComponentConfigurationRecommendationError::ResourceNotFound(
                             err.msg,
                         ),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        DescribeComponentConfigurationRecommendationError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -1325,17 +1435,23 @@ impl fmt::Display for DescribeComponentConfigurationRecommendationError {
             DescribeComponentConfigurationRecommendationError::ResourceNotFound(ref cause) => {
                 write!(f, "{}", cause)
             }
+            DescribeComponentConfigurationRecommendationError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for DescribeComponentConfigurationRecommendationError {}
 /// Errors returned by DescribeLogPattern
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeLogPatternError {
     /// <p>The server encountered an internal error and is unable to complete the request.</p>
     InternalServer(String),
     /// <p>The resource does not exist in the customer account.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeLogPatternError {
@@ -1343,13 +1459,19 @@ impl DescribeLogPatternError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(DescribeLogPatternError::InternalServer(err.msg))
+                    return RusotoError::service(
+                        DescribeLogPatternError::InternalServer(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeLogPatternError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        DescribeLogPatternError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DescribeLogPatternError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1361,17 +1483,21 @@ impl fmt::Display for DescribeLogPatternError {
         match *self {
             DescribeLogPatternError::InternalServer(ref cause) => write!(f, "{}", cause),
             DescribeLogPatternError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            DescribeLogPatternError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeLogPatternError {}
 /// Errors returned by DescribeObservation
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeObservationError {
     /// <p>The server encountered an internal error and is unable to complete the request.</p>
     InternalServer(String),
     /// <p>The resource does not exist in the customer account.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeObservationError {
@@ -1379,15 +1505,19 @@ impl DescribeObservationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(DescribeObservationError::InternalServer(err.msg))
+                    return RusotoError::service(
+                        DescribeObservationError::InternalServer(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeObservationError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeObservationError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DescribeObservationError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1399,17 +1529,21 @@ impl fmt::Display for DescribeObservationError {
         match *self {
             DescribeObservationError::InternalServer(ref cause) => write!(f, "{}", cause),
             DescribeObservationError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            DescribeObservationError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeObservationError {}
 /// Errors returned by DescribeProblem
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeProblemError {
     /// <p>The server encountered an internal error and is unable to complete the request.</p>
     InternalServer(String),
     /// <p>The resource does not exist in the customer account.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeProblemError {
@@ -1417,13 +1551,19 @@ impl DescribeProblemError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(DescribeProblemError::InternalServer(err.msg))
+                    return RusotoError::service(
+                        DescribeProblemError::InternalServer(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeProblemError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        DescribeProblemError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DescribeProblemError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1435,17 +1575,21 @@ impl fmt::Display for DescribeProblemError {
         match *self {
             DescribeProblemError::InternalServer(ref cause) => write!(f, "{}", cause),
             DescribeProblemError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            DescribeProblemError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeProblemError {}
 /// Errors returned by DescribeProblemObservations
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeProblemObservationsError {
     /// <p>The server encountered an internal error and is unable to complete the request.</p>
     InternalServer(String),
     /// <p>The resource does not exist in the customer account.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeProblemObservationsError {
@@ -1455,17 +1599,24 @@ impl DescribeProblemObservationsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(DescribeProblemObservationsError::InternalServer(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeProblemObservationsError::InternalServer(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DescribeProblemObservationsError::ResourceNotFound(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        DescribeProblemObservationsError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -1477,15 +1628,19 @@ impl fmt::Display for DescribeProblemObservationsError {
         match *self {
             DescribeProblemObservationsError::InternalServer(ref cause) => write!(f, "{}", cause),
             DescribeProblemObservationsError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            DescribeProblemObservationsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeProblemObservationsError {}
 /// Errors returned by ListApplications
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListApplicationsError {
     /// <p>The server encountered an internal error and is unable to complete the request.</p>
     InternalServer(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListApplicationsError {
@@ -1493,10 +1648,13 @@ impl ListApplicationsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(ListApplicationsError::InternalServer(err.msg))
+                    return RusotoError::service(
+                        ListApplicationsError::InternalServer(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListApplicationsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1507,17 +1665,21 @@ impl fmt::Display for ListApplicationsError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             ListApplicationsError::InternalServer(ref cause) => write!(f, "{}", cause),
+            ListApplicationsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListApplicationsError {}
 /// Errors returned by ListComponents
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListComponentsError {
     /// <p>The server encountered an internal error and is unable to complete the request.</p>
     InternalServer(String),
     /// <p>The resource does not exist in the customer account.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListComponentsError {
@@ -1525,13 +1687,16 @@ impl ListComponentsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(ListComponentsError::InternalServer(err.msg))
+                    return RusotoError::service(ListComponentsError::InternalServer(err.msg), &res)
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListComponentsError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        ListComponentsError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListComponentsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1543,17 +1708,21 @@ impl fmt::Display for ListComponentsError {
         match *self {
             ListComponentsError::InternalServer(ref cause) => write!(f, "{}", cause),
             ListComponentsError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            ListComponentsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListComponentsError {}
 /// Errors returned by ListConfigurationHistory
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListConfigurationHistoryError {
     /// <p>The server encountered an internal error and is unable to complete the request.</p>
     InternalServer(String),
     /// <p>The resource does not exist in the customer account.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListConfigurationHistoryError {
@@ -1561,17 +1730,19 @@ impl ListConfigurationHistoryError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(ListConfigurationHistoryError::InternalServer(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListConfigurationHistoryError::InternalServer(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListConfigurationHistoryError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListConfigurationHistoryError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListConfigurationHistoryError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1583,17 +1754,21 @@ impl fmt::Display for ListConfigurationHistoryError {
         match *self {
             ListConfigurationHistoryError::InternalServer(ref cause) => write!(f, "{}", cause),
             ListConfigurationHistoryError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            ListConfigurationHistoryError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListConfigurationHistoryError {}
 /// Errors returned by ListLogPatternSets
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListLogPatternSetsError {
     /// <p>The server encountered an internal error and is unable to complete the request.</p>
     InternalServer(String),
     /// <p>The resource does not exist in the customer account.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListLogPatternSetsError {
@@ -1601,13 +1776,19 @@ impl ListLogPatternSetsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(ListLogPatternSetsError::InternalServer(err.msg))
+                    return RusotoError::service(
+                        ListLogPatternSetsError::InternalServer(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListLogPatternSetsError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        ListLogPatternSetsError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListLogPatternSetsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1619,17 +1800,21 @@ impl fmt::Display for ListLogPatternSetsError {
         match *self {
             ListLogPatternSetsError::InternalServer(ref cause) => write!(f, "{}", cause),
             ListLogPatternSetsError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            ListLogPatternSetsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListLogPatternSetsError {}
 /// Errors returned by ListLogPatterns
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListLogPatternsError {
     /// <p>The server encountered an internal error and is unable to complete the request.</p>
     InternalServer(String),
     /// <p>The resource does not exist in the customer account.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListLogPatternsError {
@@ -1637,13 +1822,19 @@ impl ListLogPatternsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(ListLogPatternsError::InternalServer(err.msg))
+                    return RusotoError::service(
+                        ListLogPatternsError::InternalServer(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListLogPatternsError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        ListLogPatternsError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListLogPatternsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1655,17 +1846,21 @@ impl fmt::Display for ListLogPatternsError {
         match *self {
             ListLogPatternsError::InternalServer(ref cause) => write!(f, "{}", cause),
             ListLogPatternsError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            ListLogPatternsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListLogPatternsError {}
 /// Errors returned by ListProblems
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListProblemsError {
     /// <p>The server encountered an internal error and is unable to complete the request.</p>
     InternalServer(String),
     /// <p>The resource does not exist in the customer account.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListProblemsError {
@@ -1673,13 +1868,13 @@ impl ListProblemsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(ListProblemsError::InternalServer(err.msg))
+                    return RusotoError::service(ListProblemsError::InternalServer(err.msg), &res)
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListProblemsError::ResourceNotFound(err.msg))
+                    return RusotoError::service(ListProblemsError::ResourceNotFound(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListProblemsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1691,15 +1886,19 @@ impl fmt::Display for ListProblemsError {
         match *self {
             ListProblemsError::InternalServer(ref cause) => write!(f, "{}", cause),
             ListProblemsError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            ListProblemsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListProblemsError {}
 /// Errors returned by ListTagsForResource
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListTagsForResourceError {
     /// <p>The resource does not exist in the customer account.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListTagsForResourceError {
@@ -1707,12 +1906,13 @@ impl ListTagsForResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListTagsForResourceError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListTagsForResourceError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListTagsForResourceError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1723,17 +1923,21 @@ impl fmt::Display for ListTagsForResourceError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             ListTagsForResourceError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            ListTagsForResourceError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListTagsForResourceError {}
 /// Errors returned by TagResource
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum TagResourceError {
     /// <p>The resource does not exist in the customer account.</p>
     ResourceNotFound(String),
     /// <p>The number of the provided tags is beyond the limit, or the number of total tags you are trying to attach to the specified resource exceeds the limit.</p>
     TooManyTags(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl TagResourceError {
@@ -1741,13 +1945,13 @@ impl TagResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(TagResourceError::ResourceNotFound(err.msg))
+                    return RusotoError::service(TagResourceError::ResourceNotFound(err.msg), &res)
                 }
                 "TooManyTagsException" => {
-                    return RusotoError::Service(TagResourceError::TooManyTags(err.msg))
+                    return RusotoError::service(TagResourceError::TooManyTags(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(TagResourceError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1759,15 +1963,19 @@ impl fmt::Display for TagResourceError {
         match *self {
             TagResourceError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             TagResourceError::TooManyTags(ref cause) => write!(f, "{}", cause),
+            TagResourceError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for TagResourceError {}
 /// Errors returned by UntagResource
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UntagResourceError {
     /// <p>The resource does not exist in the customer account.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UntagResourceError {
@@ -1775,10 +1983,13 @@ impl UntagResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UntagResourceError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        UntagResourceError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UntagResourceError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1789,17 +2000,21 @@ impl fmt::Display for UntagResourceError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             UntagResourceError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            UntagResourceError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for UntagResourceError {}
 /// Errors returned by UpdateApplication
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdateApplicationError {
     /// <p>The server encountered an internal error and is unable to complete the request.</p>
     InternalServer(String),
     /// <p>The resource does not exist in the customer account.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdateApplicationError {
@@ -1807,13 +2022,19 @@ impl UpdateApplicationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(UpdateApplicationError::InternalServer(err.msg))
+                    return RusotoError::service(
+                        UpdateApplicationError::InternalServer(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateApplicationError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        UpdateApplicationError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UpdateApplicationError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1825,12 +2046,14 @@ impl fmt::Display for UpdateApplicationError {
         match *self {
             UpdateApplicationError::InternalServer(ref cause) => write!(f, "{}", cause),
             UpdateApplicationError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            UpdateApplicationError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for UpdateApplicationError {}
 /// Errors returned by UpdateComponent
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdateComponentError {
     /// <p>The server encountered an internal error and is unable to complete the request.</p>
     InternalServer(String),
@@ -1838,6 +2061,8 @@ pub enum UpdateComponentError {
     ResourceInUse(String),
     /// <p>The resource does not exist in the customer account.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdateComponentError {
@@ -1845,16 +2070,22 @@ impl UpdateComponentError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(UpdateComponentError::InternalServer(err.msg))
+                    return RusotoError::service(
+                        UpdateComponentError::InternalServer(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(UpdateComponentError::ResourceInUse(err.msg))
+                    return RusotoError::service(UpdateComponentError::ResourceInUse(err.msg), &res)
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateComponentError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        UpdateComponentError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UpdateComponentError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1867,17 +2098,21 @@ impl fmt::Display for UpdateComponentError {
             UpdateComponentError::InternalServer(ref cause) => write!(f, "{}", cause),
             UpdateComponentError::ResourceInUse(ref cause) => write!(f, "{}", cause),
             UpdateComponentError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            UpdateComponentError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for UpdateComponentError {}
 /// Errors returned by UpdateComponentConfiguration
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdateComponentConfigurationError {
     /// <p>The server encountered an internal error and is unable to complete the request.</p>
     InternalServer(String),
     /// <p>The resource does not exist in the customer account.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdateComponentConfigurationError {
@@ -1887,17 +2122,24 @@ impl UpdateComponentConfigurationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(UpdateComponentConfigurationError::InternalServer(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateComponentConfigurationError::InternalServer(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         UpdateComponentConfigurationError::ResourceNotFound(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        UpdateComponentConfigurationError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -1911,12 +2153,14 @@ impl fmt::Display for UpdateComponentConfigurationError {
             UpdateComponentConfigurationError::ResourceNotFound(ref cause) => {
                 write!(f, "{}", cause)
             }
+            UpdateComponentConfigurationError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for UpdateComponentConfigurationError {}
 /// Errors returned by UpdateLogPattern
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdateLogPatternError {
     /// <p>The server encountered an internal error and is unable to complete the request.</p>
     InternalServer(String),
@@ -1924,6 +2168,8 @@ pub enum UpdateLogPatternError {
     ResourceInUse(String),
     /// <p>The resource does not exist in the customer account.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdateLogPatternError {
@@ -1931,16 +2177,25 @@ impl UpdateLogPatternError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(UpdateLogPatternError::InternalServer(err.msg))
+                    return RusotoError::service(
+                        UpdateLogPatternError::InternalServer(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(UpdateLogPatternError::ResourceInUse(err.msg))
+                    return RusotoError::service(
+                        UpdateLogPatternError::ResourceInUse(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateLogPatternError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        UpdateLogPatternError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UpdateLogPatternError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1953,6 +2208,7 @@ impl fmt::Display for UpdateLogPatternError {
             UpdateLogPatternError::InternalServer(ref cause) => write!(f, "{}", cause),
             UpdateLogPatternError::ResourceInUse(ref cause) => write!(f, "{}", cause),
             UpdateLogPatternError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            UpdateLogPatternError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
@@ -2139,6 +2395,7 @@ impl ApplicationInsightsClient {
     /// Creates a client backed by the default tokio event loop.
     ///
     /// The client will use the default credentials provider and tls client.
+    #[cfg(not(target_arch = "wasm32"))]
     pub fn new(region: region::Region) -> ApplicationInsightsClient {
         ApplicationInsightsClient {
             client: Client::shared(),
@@ -2164,182 +2421,391 @@ impl ApplicationInsightsClient {
     pub fn new_with_client(client: Client, region: region::Region) -> ApplicationInsightsClient {
         ApplicationInsightsClient { client, region }
     }
+
+    /// Returns a builder of a client, setting any of its region, credentials provider,
+    /// dispatcher, retry policy, endpoint and timeouts.
+    pub fn builder() -> rusoto_core::ClientBuilder<ApplicationInsightsClient> {
+        rusoto_core::ClientBuilder::new(ApplicationInsightsClient::new_with_client)
+    }
+
+    /// Returns a clone of this client with the overrides of `config` applied to its
+    /// operations, sharing its connections.
+    pub fn customize(&self, config: &rusoto_core::OperationConfig) -> ApplicationInsightsClient {
+        ApplicationInsightsClient {
+            client: self.client.customize(config),
+            region: config.resolve_region(&self.region),
+        }
+    }
 }
 
-#[async_trait]
-impl ApplicationInsights for ApplicationInsightsClient {
-    /// <p>Adds an application that is created from a resource group.</p>
-    async fn create_application(
+impl ApplicationInsightsClient {
+    /// Calls `create_application`, also returning the metadata of the response.
+    pub async fn create_application_with_metadata(
         &self,
         input: CreateApplicationRequest,
-    ) -> Result<CreateApplicationResponse, RusotoError<CreateApplicationError>> {
+    ) -> Result<(CreateApplicationResponse, ResponseMetadata), RusotoError<CreateApplicationError>>
+    {
+        let request = self.build_create_application_request(input);
+
+        let mut response = self
+            .client
+            .sign_and_dispatch(request)
+            .await
+            .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
+        if response.status.is_success() {
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<CreateApplicationResponse, _>()?;
+            Ok((result, metadata))
+        } else {
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(CreateApplicationError::from_response(response))
+        }
+    }
+
+    /// Presigns the request `create_application` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_application(
+        &self,
+        presigner: &rusoto_core::Presigner,
+        input: CreateApplicationRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_application_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_application_request(&self, input: CreateApplicationRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "applicationinsights", &self.region, "/");
+        request.set_operation("CreateApplication");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "EC2WindowsBarleyService.CreateApplication");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `create_component`, also returning the metadata of the response.
+    pub async fn create_component_with_metadata(
+        &self,
+        input: CreateComponentRequest,
+    ) -> Result<(CreateComponentResponse, ResponseMetadata), RusotoError<CreateComponentError>>
+    {
+        let request = self.build_create_component_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<CreateApplicationResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<CreateComponentResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(CreateApplicationError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(CreateComponentError::from_response(response))
         }
     }
 
-    /// <p>Creates a custom component by grouping similar standalone instances to monitor.</p>
-    async fn create_component(
+    /// Presigns the request `create_component` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_component(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: CreateComponentRequest,
-    ) -> Result<CreateComponentResponse, RusotoError<CreateComponentError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_component_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_component_request(&self, input: CreateComponentRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "applicationinsights", &self.region, "/");
+        request.set_operation("CreateComponent");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "EC2WindowsBarleyService.CreateComponent");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `create_log_pattern`, also returning the metadata of the response.
+    pub async fn create_log_pattern_with_metadata(
+        &self,
+        input: CreateLogPatternRequest,
+    ) -> Result<(CreateLogPatternResponse, ResponseMetadata), RusotoError<CreateLogPatternError>>
+    {
+        let request = self.build_create_log_pattern_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<CreateComponentResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<CreateLogPatternResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(CreateComponentError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(CreateLogPatternError::from_response(response))
         }
     }
 
-    /// <p>Adds an log pattern to a <code>LogPatternSet</code>.</p>
-    async fn create_log_pattern(
+    /// Presigns the request `create_log_pattern` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_log_pattern(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: CreateLogPatternRequest,
-    ) -> Result<CreateLogPatternResponse, RusotoError<CreateLogPatternError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_log_pattern_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_log_pattern_request(&self, input: CreateLogPatternRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "applicationinsights", &self.region, "/");
+        request.set_operation("CreateLogPattern");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "EC2WindowsBarleyService.CreateLogPattern");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `delete_application`, also returning the metadata of the response.
+    pub async fn delete_application_with_metadata(
+        &self,
+        input: DeleteApplicationRequest,
+    ) -> Result<(DeleteApplicationResponse, ResponseMetadata), RusotoError<DeleteApplicationError>>
+    {
+        let request = self.build_delete_application_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<CreateLogPatternResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DeleteApplicationResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(CreateLogPatternError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DeleteApplicationError::from_response(response))
         }
     }
 
-    /// <p>Removes the specified application from monitoring. Does not delete the application.</p>
-    async fn delete_application(
+    /// Presigns the request `delete_application` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_application(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteApplicationRequest,
-    ) -> Result<DeleteApplicationResponse, RusotoError<DeleteApplicationError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_application_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_application_request(&self, input: DeleteApplicationRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "applicationinsights", &self.region, "/");
+        request.set_operation("DeleteApplication");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "EC2WindowsBarleyService.DeleteApplication");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `delete_component`, also returning the metadata of the response.
+    pub async fn delete_component_with_metadata(
+        &self,
+        input: DeleteComponentRequest,
+    ) -> Result<(DeleteComponentResponse, ResponseMetadata), RusotoError<DeleteComponentError>>
+    {
+        let request = self.build_delete_component_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DeleteApplicationResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DeleteComponentResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteApplicationError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DeleteComponentError::from_response(response))
         }
     }
 
-    /// <p>Ungroups a custom component. When you ungroup custom components, all applicable monitors that are set up for the component are removed and the instances revert to their standalone status.</p>
-    async fn delete_component(
+    /// Presigns the request `delete_component` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_component(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteComponentRequest,
-    ) -> Result<DeleteComponentResponse, RusotoError<DeleteComponentError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_component_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_component_request(&self, input: DeleteComponentRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "applicationinsights", &self.region, "/");
+        request.set_operation("DeleteComponent");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "EC2WindowsBarleyService.DeleteComponent");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `delete_log_pattern`, also returning the metadata of the response.
+    pub async fn delete_log_pattern_with_metadata(
+        &self,
+        input: DeleteLogPatternRequest,
+    ) -> Result<(DeleteLogPatternResponse, ResponseMetadata), RusotoError<DeleteLogPatternError>>
+    {
+        let request = self.build_delete_log_pattern_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<DeleteComponentResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DeleteLogPatternResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteComponentError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DeleteLogPatternError::from_response(response))
         }
     }
 
-    /// <p>Removes the specified log pattern from a <code>LogPatternSet</code>.</p>
-    async fn delete_log_pattern(
+    /// Presigns the request `delete_log_pattern` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_log_pattern(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteLogPatternRequest,
-    ) -> Result<DeleteLogPatternResponse, RusotoError<DeleteLogPatternError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_log_pattern_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_log_pattern_request(&self, input: DeleteLogPatternRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "applicationinsights", &self.region, "/");
+        request.set_operation("DeleteLogPattern");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "EC2WindowsBarleyService.DeleteLogPattern");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `describe_application`, also returning the metadata of the response.
+    pub async fn describe_application_with_metadata(
+        &self,
+        input: DescribeApplicationRequest,
+    ) -> Result<
+        (DescribeApplicationResponse, ResponseMetadata),
+        RusotoError<DescribeApplicationError>,
+    > {
+        let request = self.build_describe_application_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DeleteLogPatternResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DescribeApplicationResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteLogPatternError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DescribeApplicationError::from_response(response))
         }
     }
 
-    /// <p>Describes the application.</p>
-    async fn describe_application(
+    /// Presigns the request `describe_application` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_application(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeApplicationRequest,
-    ) -> Result<DescribeApplicationResponse, RusotoError<DescribeApplicationError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_application_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_application_request(
+        &self,
+        input: DescribeApplicationRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "applicationinsights", &self.region, "/");
+        request.set_operation("DescribeApplication");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -2349,59 +2815,111 @@ impl ApplicationInsights for ApplicationInsightsClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `describe_component`, also returning the metadata of the response.
+    pub async fn describe_component_with_metadata(
+        &self,
+        input: DescribeComponentRequest,
+    ) -> Result<(DescribeComponentResponse, ResponseMetadata), RusotoError<DescribeComponentError>>
+    {
+        let request = self.build_describe_component_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DescribeApplicationResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DescribeComponentResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeApplicationError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DescribeComponentError::from_response(response))
         }
     }
 
-    /// <p>Describes a component and lists the resources that are grouped together in a component.</p>
-    async fn describe_component(
+    /// Presigns the request `describe_component` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_component(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeComponentRequest,
-    ) -> Result<DescribeComponentResponse, RusotoError<DescribeComponentError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_component_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_component_request(&self, input: DescribeComponentRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "applicationinsights", &self.region, "/");
+        request.set_operation("DescribeComponent");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "EC2WindowsBarleyService.DescribeComponent");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `describe_component_configuration`, also returning the metadata of the response.
+    pub async fn describe_component_configuration_with_metadata(
+        &self,
+        input: DescribeComponentConfigurationRequest,
+    ) -> Result<
+        (DescribeComponentConfigurationResponse, ResponseMetadata),
+        RusotoError<DescribeComponentConfigurationError>,
+    > {
+        let request = self.build_describe_component_configuration_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DescribeComponentResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DescribeComponentConfigurationResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeComponentError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DescribeComponentConfigurationError::from_response(response))
         }
     }
 
-    /// <p>Describes the monitoring configuration of the component.</p>
-    async fn describe_component_configuration(
+    /// Presigns the request `describe_component_configuration` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_component_configuration(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeComponentConfigurationRequest,
-    ) -> Result<
-        DescribeComponentConfigurationResponse,
-        RusotoError<DescribeComponentConfigurationError>,
-    > {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_component_configuration_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_component_configuration_request(
+        &self,
+        input: DescribeComponentConfigurationRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "applicationinsights", &self.region, "/");
+        request.set_operation("DescribeComponentConfiguration");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -2411,31 +2929,62 @@ impl ApplicationInsights for ApplicationInsightsClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `describe_component_configuration_recommendation`, also returning the metadata of the response.
+    pub async fn describe_component_configuration_recommendation_with_metadata(
+        &self,
+        input: DescribeComponentConfigurationRecommendationRequest,
+    ) -> Result<
+        (
+            DescribeComponentConfigurationRecommendationResponse,
+            ResponseMetadata,
+        ),
+        RusotoError<DescribeComponentConfigurationRecommendationError>,
+    > {
+        let request = self.build_describe_component_configuration_recommendation_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DescribeComponentConfigurationResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DescribeComponentConfigurationRecommendationResponse, _>(
+            )?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeComponentConfigurationError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DescribeComponentConfigurationRecommendationError::from_response(response))
         }
     }
 
-    /// <p>Describes the recommended monitoring configuration of the component.</p>
-    async fn describe_component_configuration_recommendation(
+    /// Presigns the request `describe_component_configuration_recommendation` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_component_configuration_recommendation(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeComponentConfigurationRecommendationRequest,
-    ) -> Result<
-        DescribeComponentConfigurationRecommendationResponse,
-        RusotoError<DescribeComponentConfigurationRecommendationError>,
-    > {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_component_configuration_recommendation_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_component_configuration_recommendation_request(
+        &self,
+        input: DescribeComponentConfigurationRecommendationRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "applicationinsights", &self.region, "/");
+        request.set_operation("DescribeComponentConfigurationRecommendation");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -2445,56 +2994,114 @@ impl ApplicationInsights for ApplicationInsightsClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `describe_log_pattern`, also returning the metadata of the response.
+    pub async fn describe_log_pattern_with_metadata(
+        &self,
+        input: DescribeLogPatternRequest,
+    ) -> Result<(DescribeLogPatternResponse, ResponseMetadata), RusotoError<DescribeLogPatternError>>
+    {
+        let request = self.build_describe_log_pattern_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DescribeComponentConfigurationRecommendationResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DescribeLogPatternResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeComponentConfigurationRecommendationError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DescribeLogPatternError::from_response(response))
         }
     }
 
-    /// <p>Describe a specific log pattern from a <code>LogPatternSet</code>.</p>
-    async fn describe_log_pattern(
+    /// Presigns the request `describe_log_pattern` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_log_pattern(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeLogPatternRequest,
-    ) -> Result<DescribeLogPatternResponse, RusotoError<DescribeLogPatternError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_log_pattern_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_log_pattern_request(
+        &self,
+        input: DescribeLogPatternRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "applicationinsights", &self.region, "/");
+        request.set_operation("DescribeLogPattern");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "EC2WindowsBarleyService.DescribeLogPattern");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `describe_observation`, also returning the metadata of the response.
+    pub async fn describe_observation_with_metadata(
+        &self,
+        input: DescribeObservationRequest,
+    ) -> Result<
+        (DescribeObservationResponse, ResponseMetadata),
+        RusotoError<DescribeObservationError>,
+    > {
+        let request = self.build_describe_observation_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DescribeLogPatternResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DescribeObservationResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeLogPatternError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DescribeObservationError::from_response(response))
         }
     }
 
-    /// <p>Describes an anomaly or error with the application.</p>
-    async fn describe_observation(
+    /// Presigns the request `describe_observation` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_observation(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeObservationRequest,
-    ) -> Result<DescribeObservationResponse, RusotoError<DescribeObservationError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_observation_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_observation_request(
+        &self,
+        input: DescribeObservationRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "applicationinsights", &self.region, "/");
+        request.set_operation("DescribeObservation");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -2504,56 +3111,111 @@ impl ApplicationInsights for ApplicationInsightsClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `describe_problem`, also returning the metadata of the response.
+    pub async fn describe_problem_with_metadata(
+        &self,
+        input: DescribeProblemRequest,
+    ) -> Result<(DescribeProblemResponse, ResponseMetadata), RusotoError<DescribeProblemError>>
+    {
+        let request = self.build_describe_problem_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DescribeObservationResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DescribeProblemResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeObservationError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DescribeProblemError::from_response(response))
         }
     }
 
-    /// <p>Describes an application problem.</p>
-    async fn describe_problem(
+    /// Presigns the request `describe_problem` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_problem(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeProblemRequest,
-    ) -> Result<DescribeProblemResponse, RusotoError<DescribeProblemError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_problem_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_problem_request(&self, input: DescribeProblemRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "applicationinsights", &self.region, "/");
+        request.set_operation("DescribeProblem");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "EC2WindowsBarleyService.DescribeProblem");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `describe_problem_observations`, also returning the metadata of the response.
+    pub async fn describe_problem_observations_with_metadata(
+        &self,
+        input: DescribeProblemObservationsRequest,
+    ) -> Result<
+        (DescribeProblemObservationsResponse, ResponseMetadata),
+        RusotoError<DescribeProblemObservationsError>,
+    > {
+        let request = self.build_describe_problem_observations_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<DescribeProblemResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DescribeProblemObservationsResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeProblemError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DescribeProblemObservationsError::from_response(response))
         }
     }
 
-    /// <p>Describes the anomalies or errors associated with the problem.</p>
-    async fn describe_problem_observations(
+    /// Presigns the request `describe_problem_observations` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_problem_observations(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeProblemObservationsRequest,
-    ) -> Result<DescribeProblemObservationsResponse, RusotoError<DescribeProblemObservationsError>>
-    {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_problem_observations_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_problem_observations_request(
+        &self,
+        input: DescribeProblemObservationsRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "applicationinsights", &self.region, "/");
+        request.set_operation("DescribeProblemObservations");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -2563,83 +3225,163 @@ impl ApplicationInsights for ApplicationInsightsClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `list_applications`, also returning the metadata of the response.
+    pub async fn list_applications_with_metadata(
+        &self,
+        input: ListApplicationsRequest,
+    ) -> Result<(ListApplicationsResponse, ResponseMetadata), RusotoError<ListApplicationsError>>
+    {
+        let request = self.build_list_applications_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DescribeProblemObservationsResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ListApplicationsResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeProblemObservationsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ListApplicationsError::from_response(response))
         }
     }
 
-    /// <p>Lists the IDs of the applications that you are monitoring. </p>
-    async fn list_applications(
+    /// Presigns the request `list_applications` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_applications(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListApplicationsRequest,
-    ) -> Result<ListApplicationsResponse, RusotoError<ListApplicationsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_applications_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_applications_request(&self, input: ListApplicationsRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "applicationinsights", &self.region, "/");
+        request.set_operation("ListApplications");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "EC2WindowsBarleyService.ListApplications");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `list_components`, also returning the metadata of the response.
+    pub async fn list_components_with_metadata(
+        &self,
+        input: ListComponentsRequest,
+    ) -> Result<(ListComponentsResponse, ResponseMetadata), RusotoError<ListComponentsError>> {
+        let request = self.build_list_components_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListApplicationsResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ListComponentsResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ListApplicationsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ListComponentsError::from_response(response))
         }
     }
 
-    /// <p>Lists the auto-grouped, standalone, and custom components of the application.</p>
-    async fn list_components(
+    /// Presigns the request `list_components` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_components(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListComponentsRequest,
-    ) -> Result<ListComponentsResponse, RusotoError<ListComponentsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_components_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_components_request(&self, input: ListComponentsRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "applicationinsights", &self.region, "/");
+        request.set_operation("ListComponents");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "EC2WindowsBarleyService.ListComponents");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `list_configuration_history`, also returning the metadata of the response.
+    pub async fn list_configuration_history_with_metadata(
+        &self,
+        input: ListConfigurationHistoryRequest,
+    ) -> Result<
+        (ListConfigurationHistoryResponse, ResponseMetadata),
+        RusotoError<ListConfigurationHistoryError>,
+    > {
+        let request = self.build_list_configuration_history_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<ListComponentsResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ListConfigurationHistoryResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ListComponentsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ListConfigurationHistoryError::from_response(response))
         }
     }
 
-    /// <p><p> Lists the INFO, WARN, and ERROR events for periodic configuration updates performed by Application Insights. Examples of events represented are: </p> <ul> <li> <p>INFO: creating a new alarm or updating an alarm threshold.</p> </li> <li> <p>WARN: alarm not created due to insufficient data points used to predict thresholds.</p> </li> <li> <p>ERROR: alarm not created due to permission errors or exceeding quotas. </p> </li> </ul></p>
-    async fn list_configuration_history(
+    /// Presigns the request `list_configuration_history` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_configuration_history(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListConfigurationHistoryRequest,
-    ) -> Result<ListConfigurationHistoryResponse, RusotoError<ListConfigurationHistoryError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_configuration_history_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_configuration_history_request(
+        &self,
+        input: ListConfigurationHistoryRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "applicationinsights", &self.region, "/");
+        request.set_operation("ListConfigurationHistory");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -2649,110 +3391,219 @@ impl ApplicationInsights for ApplicationInsightsClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `list_log_pattern_sets`, also returning the metadata of the response.
+    pub async fn list_log_pattern_sets_with_metadata(
+        &self,
+        input: ListLogPatternSetsRequest,
+    ) -> Result<(ListLogPatternSetsResponse, ResponseMetadata), RusotoError<ListLogPatternSetsError>>
+    {
+        let request = self.build_list_log_pattern_sets_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListConfigurationHistoryResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ListLogPatternSetsResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ListConfigurationHistoryError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ListLogPatternSetsError::from_response(response))
         }
     }
 
-    /// <p>Lists the log pattern sets in the specific application.</p>
-    async fn list_log_pattern_sets(
+    /// Presigns the request `list_log_pattern_sets` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_log_pattern_sets(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListLogPatternSetsRequest,
-    ) -> Result<ListLogPatternSetsResponse, RusotoError<ListLogPatternSetsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_log_pattern_sets_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_log_pattern_sets_request(
+        &self,
+        input: ListLogPatternSetsRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "applicationinsights", &self.region, "/");
+        request.set_operation("ListLogPatternSets");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "EC2WindowsBarleyService.ListLogPatternSets");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `list_log_patterns`, also returning the metadata of the response.
+    pub async fn list_log_patterns_with_metadata(
+        &self,
+        input: ListLogPatternsRequest,
+    ) -> Result<(ListLogPatternsResponse, ResponseMetadata), RusotoError<ListLogPatternsError>>
+    {
+        let request = self.build_list_log_patterns_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListLogPatternSetsResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ListLogPatternsResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ListLogPatternSetsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ListLogPatternsError::from_response(response))
         }
     }
 
-    /// <p>Lists the log patterns in the specific log <code>LogPatternSet</code>.</p>
-    async fn list_log_patterns(
+    /// Presigns the request `list_log_patterns` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_log_patterns(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListLogPatternsRequest,
-    ) -> Result<ListLogPatternsResponse, RusotoError<ListLogPatternsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_log_patterns_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_log_patterns_request(&self, input: ListLogPatternsRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "applicationinsights", &self.region, "/");
+        request.set_operation("ListLogPatterns");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "EC2WindowsBarleyService.ListLogPatterns");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `list_problems`, also returning the metadata of the response.
+    pub async fn list_problems_with_metadata(
+        &self,
+        input: ListProblemsRequest,
+    ) -> Result<(ListProblemsResponse, ResponseMetadata), RusotoError<ListProblemsError>> {
+        let request = self.build_list_problems_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<ListLogPatternsResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ListProblemsResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ListLogPatternsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ListProblemsError::from_response(response))
         }
     }
 
-    /// <p>Lists the problems with your application.</p>
-    async fn list_problems(
+    /// Presigns the request `list_problems` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_problems(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListProblemsRequest,
-    ) -> Result<ListProblemsResponse, RusotoError<ListProblemsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_problems_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_problems_request(&self, input: ListProblemsRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "applicationinsights", &self.region, "/");
+        request.set_operation("ListProblems");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "EC2WindowsBarleyService.ListProblems");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `list_tags_for_resource`, also returning the metadata of the response.
+    pub async fn list_tags_for_resource_with_metadata(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> Result<
+        (ListTagsForResourceResponse, ResponseMetadata),
+        RusotoError<ListTagsForResourceError>,
+    > {
+        let request = self.build_list_tags_for_resource_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<ListProblemsResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ListTagsForResourceResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ListProblemsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ListTagsForResourceError::from_response(response))
         }
     }
 
-    /// <p>Retrieve a list of the tags (keys and values) that are associated with a specified application. A <i>tag</i> is a label that you optionally define and associate with an application. Each tag consists of a required <i>tag key</i> and an optional associated <i>tag value</i>. A tag key is a general label that acts as a category for more specific tag values. A tag value acts as a descriptor within a tag key.</p>
-    async fn list_tags_for_resource(
+    /// Presigns the request `list_tags_for_resource` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_tags_for_resource(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListTagsForResourceRequest,
-    ) -> Result<ListTagsForResourceResponse, RusotoError<ListTagsForResourceError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_tags_for_resource_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_tags_for_resource_request(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "applicationinsights", &self.region, "/");
+        request.set_operation("ListTagsForResource");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -2762,138 +3613,268 @@ impl ApplicationInsights for ApplicationInsightsClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `tag_resource`, also returning the metadata of the response.
+    pub async fn tag_resource_with_metadata(
+        &self,
+        input: TagResourceRequest,
+    ) -> Result<(TagResourceResponse, ResponseMetadata), RusotoError<TagResourceError>> {
+        let request = self.build_tag_resource_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListTagsForResourceResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<TagResourceResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ListTagsForResourceError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(TagResourceError::from_response(response))
         }
     }
 
-    /// <p>Add one or more tags (keys and values) to a specified application. A <i>tag</i> is a label that you optionally define and associate with an application. Tags can help you categorize and manage application in different ways, such as by purpose, owner, environment, or other criteria. </p> <p>Each tag consists of a required <i>tag key</i> and an associated <i>tag value</i>, both of which you define. A tag key is a general label that acts as a category for more specific tag values. A tag value acts as a descriptor within a tag key.</p>
-    async fn tag_resource(
+    /// Presigns the request `tag_resource` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_tag_resource(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: TagResourceRequest,
-    ) -> Result<TagResourceResponse, RusotoError<TagResourceError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_tag_resource_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_tag_resource_request(&self, input: TagResourceRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "applicationinsights", &self.region, "/");
+        request.set_operation("TagResource");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "EC2WindowsBarleyService.TagResource");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `untag_resource`, also returning the metadata of the response.
+    pub async fn untag_resource_with_metadata(
+        &self,
+        input: UntagResourceRequest,
+    ) -> Result<(UntagResourceResponse, ResponseMetadata), RusotoError<UntagResourceError>> {
+        let request = self.build_untag_resource_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<TagResourceResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<UntagResourceResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(TagResourceError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(UntagResourceError::from_response(response))
         }
     }
 
-    /// <p>Remove one or more tags (keys and values) from a specified application.</p>
-    async fn untag_resource(
+    /// Presigns the request `untag_resource` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_untag_resource(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UntagResourceRequest,
-    ) -> Result<UntagResourceResponse, RusotoError<UntagResourceError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_untag_resource_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_untag_resource_request(&self, input: UntagResourceRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "applicationinsights", &self.region, "/");
+        request.set_operation("UntagResource");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "EC2WindowsBarleyService.UntagResource");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `update_application`, also returning the metadata of the response.
+    pub async fn update_application_with_metadata(
+        &self,
+        input: UpdateApplicationRequest,
+    ) -> Result<(UpdateApplicationResponse, ResponseMetadata), RusotoError<UpdateApplicationError>>
+    {
+        let request = self.build_update_application_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<UntagResourceResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<UpdateApplicationResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(UntagResourceError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(UpdateApplicationError::from_response(response))
         }
     }
 
-    /// <p>Updates the application.</p>
-    async fn update_application(
+    /// Presigns the request `update_application` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_application(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UpdateApplicationRequest,
-    ) -> Result<UpdateApplicationResponse, RusotoError<UpdateApplicationError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_application_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_application_request(&self, input: UpdateApplicationRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "applicationinsights", &self.region, "/");
+        request.set_operation("UpdateApplication");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "EC2WindowsBarleyService.UpdateApplication");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `update_component`, also returning the metadata of the response.
+    pub async fn update_component_with_metadata(
+        &self,
+        input: UpdateComponentRequest,
+    ) -> Result<(UpdateComponentResponse, ResponseMetadata), RusotoError<UpdateComponentError>>
+    {
+        let request = self.build_update_component_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<UpdateApplicationResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<UpdateComponentResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(UpdateApplicationError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(UpdateComponentError::from_response(response))
         }
     }
 
-    /// <p>Updates the custom component name and/or the list of resources that make up the component.</p>
-    async fn update_component(
+    /// Presigns the request `update_component` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_component(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UpdateComponentRequest,
-    ) -> Result<UpdateComponentResponse, RusotoError<UpdateComponentError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_component_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_component_request(&self, input: UpdateComponentRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "applicationinsights", &self.region, "/");
+        request.set_operation("UpdateComponent");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "EC2WindowsBarleyService.UpdateComponent");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `update_component_configuration`, also returning the metadata of the response.
+    pub async fn update_component_configuration_with_metadata(
+        &self,
+        input: UpdateComponentConfigurationRequest,
+    ) -> Result<
+        (UpdateComponentConfigurationResponse, ResponseMetadata),
+        RusotoError<UpdateComponentConfigurationError>,
+    > {
+        let request = self.build_update_component_configuration_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<UpdateComponentResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<UpdateComponentConfigurationResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(UpdateComponentError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(UpdateComponentConfigurationError::from_response(response))
         }
     }
 
-    /// <p>Updates the monitoring configurations for the component. The configuration input parameter is an escaped JSON of the configuration and should match the schema of what is returned by <code>DescribeComponentConfigurationRecommendation</code>. </p>
-    async fn update_component_configuration(
+    /// Presigns the request `update_component_configuration` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_component_configuration(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UpdateComponentConfigurationRequest,
-    ) -> Result<UpdateComponentConfigurationResponse, RusotoError<UpdateComponentConfigurationError>>
-    {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_component_configuration_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_component_configuration_request(
+        &self,
+        input: UpdateComponentConfigurationRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "applicationinsights", &self.region, "/");
+        request.set_operation("UpdateComponentConfiguration");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -2903,47 +3884,340 @@ impl ApplicationInsights for ApplicationInsightsClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `update_log_pattern`, also returning the metadata of the response.
+    pub async fn update_log_pattern_with_metadata(
+        &self,
+        input: UpdateLogPatternRequest,
+    ) -> Result<(UpdateLogPatternResponse, ResponseMetadata), RusotoError<UpdateLogPatternError>>
+    {
+        let request = self.build_update_log_pattern_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<UpdateComponentConfigurationResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<UpdateLogPatternResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(UpdateComponentConfigurationError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(UpdateLogPatternError::from_response(response))
         }
     }
 
-    /// <p>Adds a log pattern to a <code>LogPatternSet</code>.</p>
-    async fn update_log_pattern(
+    /// Presigns the request `update_log_pattern` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_log_pattern(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UpdateLogPatternRequest,
-    ) -> Result<UpdateLogPatternResponse, RusotoError<UpdateLogPatternError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_log_pattern_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_log_pattern_request(&self, input: UpdateLogPatternRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "applicationinsights", &self.region, "/");
+        request.set_operation("UpdateLogPattern");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "EC2WindowsBarleyService.UpdateLogPattern");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
-        let mut response = self
-            .client
-            .sign_and_dispatch(request)
+        request
+    }
+}
+
+#[async_trait]
+impl ApplicationInsights for ApplicationInsightsClient {
+    /// <p>Adds an application that is created from a resource group.</p>
+    async fn create_application(
+        &self,
+        input: CreateApplicationRequest,
+    ) -> Result<CreateApplicationResponse, RusotoError<CreateApplicationError>> {
+        self.create_application_with_metadata(input)
             .await
-            .map_err(RusotoError::from)?;
-        if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<UpdateLogPatternResponse, _>()
-        } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(UpdateLogPatternError::from_response(response))
-        }
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Creates a custom component by grouping similar standalone instances to monitor.</p>
+    async fn create_component(
+        &self,
+        input: CreateComponentRequest,
+    ) -> Result<CreateComponentResponse, RusotoError<CreateComponentError>> {
+        self.create_component_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Adds an log pattern to a <code>LogPatternSet</code>.</p>
+    async fn create_log_pattern(
+        &self,
+        input: CreateLogPatternRequest,
+    ) -> Result<CreateLogPatternResponse, RusotoError<CreateLogPatternError>> {
+        self.create_log_pattern_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Removes the specified application from monitoring. Does not delete the application.</p>
+    async fn delete_application(
+        &self,
+        input: DeleteApplicationRequest,
+    ) -> Result<DeleteApplicationResponse, RusotoError<DeleteApplicationError>> {
+        self.delete_application_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Ungroups a custom component. When you ungroup custom components, all applicable monitors that are set up for the component are removed and the instances revert to their standalone status.</p>
+    async fn delete_component(
+        &self,
+        input: DeleteComponentRequest,
+    ) -> Result<DeleteComponentResponse, RusotoError<DeleteComponentError>> {
+        self.delete_component_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Removes the specified log pattern from a <code>LogPatternSet</code>.</p>
+    async fn delete_log_pattern(
+        &self,
+        input: DeleteLogPatternRequest,
+    ) -> Result<DeleteLogPatternResponse, RusotoError<DeleteLogPatternError>> {
+        self.delete_log_pattern_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Describes the application.</p>
+    async fn describe_application(
+        &self,
+        input: DescribeApplicationRequest,
+    ) -> Result<DescribeApplicationResponse, RusotoError<DescribeApplicationError>> {
+        self.describe_application_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Describes a component and lists the resources that are grouped together in a component.</p>
+    async fn describe_component(
+        &self,
+        input: DescribeComponentRequest,
+    ) -> Result<DescribeComponentResponse, RusotoError<DescribeComponentError>> {
+        self.describe_component_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Describes the monitoring configuration of the component.</p>
+    async fn describe_component_configuration(
+        &self,
+        input: DescribeComponentConfigurationRequest,
+    ) -> Result<
+        DescribeComponentConfigurationResponse,
+        RusotoError<DescribeComponentConfigurationError>,
+    > {
+        self.describe_component_configuration_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Describes the recommended monitoring configuration of the component.</p>
+    async fn describe_component_configuration_recommendation(
+        &self,
+        input: DescribeComponentConfigurationRecommendationRequest,
+    ) -> Result<
+        DescribeComponentConfigurationRecommendationResponse,
+        RusotoError<DescribeComponentConfigurationRecommendationError>,
+    > {
+        self.describe_component_configuration_recommendation_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Describe a specific log pattern from a <code>LogPatternSet</code>.</p>
+    async fn describe_log_pattern(
+        &self,
+        input: DescribeLogPatternRequest,
+    ) -> Result<DescribeLogPatternResponse, RusotoError<DescribeLogPatternError>> {
+        self.describe_log_pattern_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Describes an anomaly or error with the application.</p>
+    async fn describe_observation(
+        &self,
+        input: DescribeObservationRequest,
+    ) -> Result<DescribeObservationResponse, RusotoError<DescribeObservationError>> {
+        self.describe_observation_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Describes an application problem.</p>
+    async fn describe_problem(
+        &self,
+        input: DescribeProblemRequest,
+    ) -> Result<DescribeProblemResponse, RusotoError<DescribeProblemError>> {
+        self.describe_problem_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Describes the anomalies or errors associated with the problem.</p>
+    async fn describe_problem_observations(
+        &self,
+        input: DescribeProblemObservationsRequest,
+    ) -> Result<DescribeProblemObservationsResponse, RusotoError<DescribeProblemObservationsError>>
+    {
+        self.describe_problem_observations_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Lists the IDs of the applications that you are monitoring. </p>
+    async fn list_applications(
+        &self,
+        input: ListApplicationsRequest,
+    ) -> Result<ListApplicationsResponse, RusotoError<ListApplicationsError>> {
+        self.list_applications_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Lists the auto-grouped, standalone, and custom components of the application.</p>
+    async fn list_components(
+        &self,
+        input: ListComponentsRequest,
+    ) -> Result<ListComponentsResponse, RusotoError<ListComponentsError>> {
+        self.list_components_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p> Lists the INFO, WARN, and ERROR events for periodic configuration updates performed by Application Insights. Examples of events represented are: </p> <ul> <li> <p>INFO: creating a new alarm or updating an alarm threshold.</p> </li> <li> <p>WARN: alarm not created due to insufficient data points used to predict thresholds.</p> </li> <li> <p>ERROR: alarm not created due to permission errors or exceeding quotas. </p> </li> </ul></p>
+    async fn list_configuration_history(
+        &self,
+        input: ListConfigurationHistoryRequest,
+    ) -> Result<ListConfigurationHistoryResponse, RusotoError<ListConfigurationHistoryError>> {
+        self.list_configuration_history_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Lists the log pattern sets in the specific application.</p>
+    async fn list_log_pattern_sets(
+        &self,
+        input: ListLogPatternSetsRequest,
+    ) -> Result<ListLogPatternSetsResponse, RusotoError<ListLogPatternSetsError>> {
+        self.list_log_pattern_sets_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Lists the log patterns in the specific log <code>LogPatternSet</code>.</p>
+    async fn list_log_patterns(
+        &self,
+        input: ListLogPatternsRequest,
+    ) -> Result<ListLogPatternsResponse, RusotoError<ListLogPatternsError>> {
+        self.list_log_patterns_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Lists the problems with your application.</p>
+    async fn list_problems(
+        &self,
+        input: ListProblemsRequest,
+    ) -> Result<ListProblemsResponse, RusotoError<ListProblemsError>> {
+        self.list_problems_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Retrieve a list of the tags (keys and values) that are associated with a specified application. A <i>tag</i> is a label that you optionally define and associate with an application. Each tag consists of a required <i>tag key</i> and an optional associated <i>tag value</i>. A tag key is a general label that acts as a category for more specific tag values. A tag value acts as a descriptor within a tag key.</p>
+    async fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> Result<ListTagsForResourceResponse, RusotoError<ListTagsForResourceError>> {
+        self.list_tags_for_resource_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Add one or more tags (keys and values) to a specified application. A <i>tag</i> is a label that you optionally define and associate with an application. Tags can help you categorize and manage application in different ways, such as by purpose, owner, environment, or other criteria. </p> <p>Each tag consists of a required <i>tag key</i> and an associated <i>tag value</i>, both of which you define. A tag key is a general label that acts as a category for more specific tag values. A tag value acts as a descriptor within a tag key.</p>
+    async fn tag_resource(
+        &self,
+        input: TagResourceRequest,
+    ) -> Result<TagResourceResponse, RusotoError<TagResourceError>> {
+        self.tag_resource_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Remove one or more tags (keys and values) from a specified application.</p>
+    async fn untag_resource(
+        &self,
+        input: UntagResourceRequest,
+    ) -> Result<UntagResourceResponse, RusotoError<UntagResourceError>> {
+        self.untag_resource_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Updates the application.</p>
+    async fn update_application(
+        &self,
+        input: UpdateApplicationRequest,
+    ) -> Result<UpdateApplicationResponse, RusotoError<UpdateApplicationError>> {
+        self.update_application_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Updates the custom component name and/or the list of resources that make up the component.</p>
+    async fn update_component(
+        &self,
+        input: UpdateComponentRequest,
+    ) -> Result<UpdateComponentResponse, RusotoError<UpdateComponentError>> {
+        self.update_component_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Updates the monitoring configurations for the component. The configuration input parameter is an escaped JSON of the configuration and should match the schema of what is returned by <code>DescribeComponentConfigurationRecommendation</code>. </p>
+    async fn update_component_configuration(
+        &self,
+        input: UpdateComponentConfigurationRequest,
+    ) -> Result<UpdateComponentConfigurationResponse, RusotoError<UpdateComponentConfigurationError>>
+    {
+        self.update_component_configuration_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Adds a log pattern to a <code>LogPatternSet</code>.</p>
+    async fn update_log_pattern(
+        &self,
+        input: UpdateLogPatternRequest,
+    ) -> Result<UpdateLogPatternResponse, RusotoError<UpdateLogPatternError>> {
+        self.update_log_pattern_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
     }
 }
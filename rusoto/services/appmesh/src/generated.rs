@@ -16,8 +16,8 @@ use std::fmt;
 use async_trait::async_trait;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
-use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest, ResponseMetadata};
+use rusoto_core::{Client, ErrorMetadata, RusotoError};
 
 use rusoto_core::param::{Params, ServiceParams};
 use rusoto_core::proto;
@@ -1676,6 +1676,7 @@ pub struct WeightedTarget {
 
 /// Errors returned by CreateMesh
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateMeshError {
     /// <p>The request syntax was malformed. Check your request syntax and try again.</p>
     BadRequest(String),
@@ -1698,6 +1699,8 @@ pub enum CreateMeshError {
     /// account. For best results, use an increasing or variable sleep interval between
     /// requests.</p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateMeshError {
@@ -1705,31 +1708,34 @@ impl CreateMeshError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(CreateMeshError::BadRequest(err.msg))
+                    return RusotoError::service(CreateMeshError::BadRequest(err.msg), &res)
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(CreateMeshError::Conflict(err.msg))
+                    return RusotoError::service(CreateMeshError::Conflict(err.msg), &res)
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(CreateMeshError::Forbidden(err.msg))
+                    return RusotoError::service(CreateMeshError::Forbidden(err.msg), &res)
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(CreateMeshError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        CreateMeshError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateMeshError::LimitExceeded(err.msg))
+                    return RusotoError::service(CreateMeshError::LimitExceeded(err.msg), &res)
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(CreateMeshError::NotFound(err.msg))
+                    return RusotoError::service(CreateMeshError::NotFound(err.msg), &res)
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(CreateMeshError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(CreateMeshError::ServiceUnavailable(err.msg), &res)
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(CreateMeshError::TooManyRequests(err.msg))
+                    return RusotoError::service(CreateMeshError::TooManyRequests(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreateMeshError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1747,12 +1753,14 @@ impl fmt::Display for CreateMeshError {
             CreateMeshError::NotFound(ref cause) => write!(f, "{}", cause),
             CreateMeshError::ServiceUnavailable(ref cause) => write!(f, "{}", cause),
             CreateMeshError::TooManyRequests(ref cause) => write!(f, "{}", cause),
+            CreateMeshError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateMeshError {}
 /// Errors returned by CreateRoute
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateRouteError {
     /// <p>The request syntax was malformed. Check your request syntax and try again.</p>
     BadRequest(String),
@@ -1775,6 +1783,8 @@ pub enum CreateRouteError {
     /// account. For best results, use an increasing or variable sleep interval between
     /// requests.</p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateRouteError {
@@ -1782,31 +1792,37 @@ impl CreateRouteError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(CreateRouteError::BadRequest(err.msg))
+                    return RusotoError::service(CreateRouteError::BadRequest(err.msg), &res)
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(CreateRouteError::Conflict(err.msg))
+                    return RusotoError::service(CreateRouteError::Conflict(err.msg), &res)
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(CreateRouteError::Forbidden(err.msg))
+                    return RusotoError::service(CreateRouteError::Forbidden(err.msg), &res)
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(CreateRouteError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        CreateRouteError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateRouteError::LimitExceeded(err.msg))
+                    return RusotoError::service(CreateRouteError::LimitExceeded(err.msg), &res)
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(CreateRouteError::NotFound(err.msg))
+                    return RusotoError::service(CreateRouteError::NotFound(err.msg), &res)
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(CreateRouteError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        CreateRouteError::ServiceUnavailable(err.msg),
+                        &res,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(CreateRouteError::TooManyRequests(err.msg))
+                    return RusotoError::service(CreateRouteError::TooManyRequests(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreateRouteError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1824,12 +1840,14 @@ impl fmt::Display for CreateRouteError {
             CreateRouteError::NotFound(ref cause) => write!(f, "{}", cause),
             CreateRouteError::ServiceUnavailable(ref cause) => write!(f, "{}", cause),
             CreateRouteError::TooManyRequests(ref cause) => write!(f, "{}", cause),
+            CreateRouteError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateRouteError {}
 /// Errors returned by CreateVirtualNode
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateVirtualNodeError {
     /// <p>The request syntax was malformed. Check your request syntax and try again.</p>
     BadRequest(String),
@@ -1852,6 +1870,8 @@ pub enum CreateVirtualNodeError {
     /// account. For best results, use an increasing or variable sleep interval between
     /// requests.</p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateVirtualNodeError {
@@ -1859,35 +1879,43 @@ impl CreateVirtualNodeError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(CreateVirtualNodeError::BadRequest(err.msg))
+                    return RusotoError::service(CreateVirtualNodeError::BadRequest(err.msg), &res)
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(CreateVirtualNodeError::Conflict(err.msg))
+                    return RusotoError::service(CreateVirtualNodeError::Conflict(err.msg), &res)
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(CreateVirtualNodeError::Forbidden(err.msg))
+                    return RusotoError::service(CreateVirtualNodeError::Forbidden(err.msg), &res)
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(CreateVirtualNodeError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateVirtualNodeError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateVirtualNodeError::LimitExceeded(err.msg))
+                    return RusotoError::service(
+                        CreateVirtualNodeError::LimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(CreateVirtualNodeError::NotFound(err.msg))
+                    return RusotoError::service(CreateVirtualNodeError::NotFound(err.msg), &res)
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(CreateVirtualNodeError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateVirtualNodeError::ServiceUnavailable(err.msg),
+                        &res,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(CreateVirtualNodeError::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        CreateVirtualNodeError::TooManyRequests(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreateVirtualNodeError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1905,12 +1933,14 @@ impl fmt::Display for CreateVirtualNodeError {
             CreateVirtualNodeError::NotFound(ref cause) => write!(f, "{}", cause),
             CreateVirtualNodeError::ServiceUnavailable(ref cause) => write!(f, "{}", cause),
             CreateVirtualNodeError::TooManyRequests(ref cause) => write!(f, "{}", cause),
+            CreateVirtualNodeError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateVirtualNodeError {}
 /// Errors returned by CreateVirtualRouter
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateVirtualRouterError {
     /// <p>The request syntax was malformed. Check your request syntax and try again.</p>
     BadRequest(String),
@@ -1933,6 +1963,8 @@ pub enum CreateVirtualRouterError {
     /// account. For best results, use an increasing or variable sleep interval between
     /// requests.</p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateVirtualRouterError {
@@ -1940,35 +1972,46 @@ impl CreateVirtualRouterError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(CreateVirtualRouterError::BadRequest(err.msg))
+                    return RusotoError::service(
+                        CreateVirtualRouterError::BadRequest(err.msg),
+                        &res,
+                    )
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(CreateVirtualRouterError::Conflict(err.msg))
+                    return RusotoError::service(CreateVirtualRouterError::Conflict(err.msg), &res)
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(CreateVirtualRouterError::Forbidden(err.msg))
+                    return RusotoError::service(CreateVirtualRouterError::Forbidden(err.msg), &res)
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(CreateVirtualRouterError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateVirtualRouterError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateVirtualRouterError::LimitExceeded(err.msg))
+                    return RusotoError::service(
+                        CreateVirtualRouterError::LimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(CreateVirtualRouterError::NotFound(err.msg))
+                    return RusotoError::service(CreateVirtualRouterError::NotFound(err.msg), &res)
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(CreateVirtualRouterError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateVirtualRouterError::ServiceUnavailable(err.msg),
+                        &res,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(CreateVirtualRouterError::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        CreateVirtualRouterError::TooManyRequests(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreateVirtualRouterError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1986,12 +2029,14 @@ impl fmt::Display for CreateVirtualRouterError {
             CreateVirtualRouterError::NotFound(ref cause) => write!(f, "{}", cause),
             CreateVirtualRouterError::ServiceUnavailable(ref cause) => write!(f, "{}", cause),
             CreateVirtualRouterError::TooManyRequests(ref cause) => write!(f, "{}", cause),
+            CreateVirtualRouterError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateVirtualRouterError {}
 /// Errors returned by CreateVirtualService
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateVirtualServiceError {
     /// <p>The request syntax was malformed. Check your request syntax and try again.</p>
     BadRequest(String),
@@ -2014,6 +2059,8 @@ pub enum CreateVirtualServiceError {
     /// account. For best results, use an increasing or variable sleep interval between
     /// requests.</p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateVirtualServiceError {
@@ -2021,37 +2068,49 @@ impl CreateVirtualServiceError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(CreateVirtualServiceError::BadRequest(err.msg))
+                    return RusotoError::service(
+                        CreateVirtualServiceError::BadRequest(err.msg),
+                        &res,
+                    )
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(CreateVirtualServiceError::Conflict(err.msg))
+                    return RusotoError::service(CreateVirtualServiceError::Conflict(err.msg), &res)
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(CreateVirtualServiceError::Forbidden(err.msg))
+                    return RusotoError::service(
+                        CreateVirtualServiceError::Forbidden(err.msg),
+                        &res,
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(CreateVirtualServiceError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateVirtualServiceError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateVirtualServiceError::LimitExceeded(err.msg))
+                    return RusotoError::service(
+                        CreateVirtualServiceError::LimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(CreateVirtualServiceError::NotFound(err.msg))
+                    return RusotoError::service(CreateVirtualServiceError::NotFound(err.msg), &res)
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(CreateVirtualServiceError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateVirtualServiceError::ServiceUnavailable(err.msg),
+                        &res,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(CreateVirtualServiceError::TooManyRequests(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateVirtualServiceError::TooManyRequests(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreateVirtualServiceError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2069,12 +2128,14 @@ impl fmt::Display for CreateVirtualServiceError {
             CreateVirtualServiceError::NotFound(ref cause) => write!(f, "{}", cause),
             CreateVirtualServiceError::ServiceUnavailable(ref cause) => write!(f, "{}", cause),
             CreateVirtualServiceError::TooManyRequests(ref cause) => write!(f, "{}", cause),
+            CreateVirtualServiceError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateVirtualServiceError {}
 /// Errors returned by DeleteMesh
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteMeshError {
     /// <p>The request syntax was malformed. Check your request syntax and try again.</p>
     BadRequest(String),
@@ -2094,6 +2155,8 @@ pub enum DeleteMeshError {
     /// account. For best results, use an increasing or variable sleep interval between
     /// requests.</p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteMeshError {
@@ -2101,28 +2164,31 @@ impl DeleteMeshError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(DeleteMeshError::BadRequest(err.msg))
+                    return RusotoError::service(DeleteMeshError::BadRequest(err.msg), &res)
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(DeleteMeshError::Forbidden(err.msg))
+                    return RusotoError::service(DeleteMeshError::Forbidden(err.msg), &res)
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(DeleteMeshError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        DeleteMeshError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteMeshError::NotFound(err.msg))
+                    return RusotoError::service(DeleteMeshError::NotFound(err.msg), &res)
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(DeleteMeshError::ResourceInUse(err.msg))
+                    return RusotoError::service(DeleteMeshError::ResourceInUse(err.msg), &res)
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(DeleteMeshError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(DeleteMeshError::ServiceUnavailable(err.msg), &res)
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(DeleteMeshError::TooManyRequests(err.msg))
+                    return RusotoError::service(DeleteMeshError::TooManyRequests(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteMeshError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2139,12 +2205,14 @@ impl fmt::Display for DeleteMeshError {
             DeleteMeshError::ResourceInUse(ref cause) => write!(f, "{}", cause),
             DeleteMeshError::ServiceUnavailable(ref cause) => write!(f, "{}", cause),
             DeleteMeshError::TooManyRequests(ref cause) => write!(f, "{}", cause),
+            DeleteMeshError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteMeshError {}
 /// Errors returned by DeleteRoute
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteRouteError {
     /// <p>The request syntax was malformed. Check your request syntax and try again.</p>
     BadRequest(String),
@@ -2164,6 +2232,8 @@ pub enum DeleteRouteError {
     /// account. For best results, use an increasing or variable sleep interval between
     /// requests.</p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteRouteError {
@@ -2171,28 +2241,34 @@ impl DeleteRouteError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(DeleteRouteError::BadRequest(err.msg))
+                    return RusotoError::service(DeleteRouteError::BadRequest(err.msg), &res)
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(DeleteRouteError::Forbidden(err.msg))
+                    return RusotoError::service(DeleteRouteError::Forbidden(err.msg), &res)
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(DeleteRouteError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        DeleteRouteError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteRouteError::NotFound(err.msg))
+                    return RusotoError::service(DeleteRouteError::NotFound(err.msg), &res)
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(DeleteRouteError::ResourceInUse(err.msg))
+                    return RusotoError::service(DeleteRouteError::ResourceInUse(err.msg), &res)
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(DeleteRouteError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        DeleteRouteError::ServiceUnavailable(err.msg),
+                        &res,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(DeleteRouteError::TooManyRequests(err.msg))
+                    return RusotoError::service(DeleteRouteError::TooManyRequests(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteRouteError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2209,12 +2285,14 @@ impl fmt::Display for DeleteRouteError {
             DeleteRouteError::ResourceInUse(ref cause) => write!(f, "{}", cause),
             DeleteRouteError::ServiceUnavailable(ref cause) => write!(f, "{}", cause),
             DeleteRouteError::TooManyRequests(ref cause) => write!(f, "{}", cause),
+            DeleteRouteError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteRouteError {}
 /// Errors returned by DeleteVirtualNode
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteVirtualNodeError {
     /// <p>The request syntax was malformed. Check your request syntax and try again.</p>
     BadRequest(String),
@@ -2234,6 +2312,8 @@ pub enum DeleteVirtualNodeError {
     /// account. For best results, use an increasing or variable sleep interval between
     /// requests.</p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteVirtualNodeError {
@@ -2241,32 +2321,40 @@ impl DeleteVirtualNodeError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(DeleteVirtualNodeError::BadRequest(err.msg))
+                    return RusotoError::service(DeleteVirtualNodeError::BadRequest(err.msg), &res)
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(DeleteVirtualNodeError::Forbidden(err.msg))
+                    return RusotoError::service(DeleteVirtualNodeError::Forbidden(err.msg), &res)
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(DeleteVirtualNodeError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteVirtualNodeError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteVirtualNodeError::NotFound(err.msg))
+                    return RusotoError::service(DeleteVirtualNodeError::NotFound(err.msg), &res)
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(DeleteVirtualNodeError::ResourceInUse(err.msg))
+                    return RusotoError::service(
+                        DeleteVirtualNodeError::ResourceInUse(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(DeleteVirtualNodeError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteVirtualNodeError::ServiceUnavailable(err.msg),
+                        &res,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(DeleteVirtualNodeError::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        DeleteVirtualNodeError::TooManyRequests(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteVirtualNodeError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2283,12 +2371,14 @@ impl fmt::Display for DeleteVirtualNodeError {
             DeleteVirtualNodeError::ResourceInUse(ref cause) => write!(f, "{}", cause),
             DeleteVirtualNodeError::ServiceUnavailable(ref cause) => write!(f, "{}", cause),
             DeleteVirtualNodeError::TooManyRequests(ref cause) => write!(f, "{}", cause),
+            DeleteVirtualNodeError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteVirtualNodeError {}
 /// Errors returned by DeleteVirtualRouter
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteVirtualRouterError {
     /// <p>The request syntax was malformed. Check your request syntax and try again.</p>
     BadRequest(String),
@@ -2308,6 +2398,8 @@ pub enum DeleteVirtualRouterError {
     /// account. For best results, use an increasing or variable sleep interval between
     /// requests.</p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteVirtualRouterError {
@@ -2315,32 +2407,43 @@ impl DeleteVirtualRouterError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(DeleteVirtualRouterError::BadRequest(err.msg))
+                    return RusotoError::service(
+                        DeleteVirtualRouterError::BadRequest(err.msg),
+                        &res,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(DeleteVirtualRouterError::Forbidden(err.msg))
+                    return RusotoError::service(DeleteVirtualRouterError::Forbidden(err.msg), &res)
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(DeleteVirtualRouterError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteVirtualRouterError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteVirtualRouterError::NotFound(err.msg))
+                    return RusotoError::service(DeleteVirtualRouterError::NotFound(err.msg), &res)
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(DeleteVirtualRouterError::ResourceInUse(err.msg))
+                    return RusotoError::service(
+                        DeleteVirtualRouterError::ResourceInUse(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(DeleteVirtualRouterError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteVirtualRouterError::ServiceUnavailable(err.msg),
+                        &res,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(DeleteVirtualRouterError::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        DeleteVirtualRouterError::TooManyRequests(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteVirtualRouterError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2357,12 +2460,14 @@ impl fmt::Display for DeleteVirtualRouterError {
             DeleteVirtualRouterError::ResourceInUse(ref cause) => write!(f, "{}", cause),
             DeleteVirtualRouterError::ServiceUnavailable(ref cause) => write!(f, "{}", cause),
             DeleteVirtualRouterError::TooManyRequests(ref cause) => write!(f, "{}", cause),
+            DeleteVirtualRouterError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteVirtualRouterError {}
 /// Errors returned by DeleteVirtualService
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteVirtualServiceError {
     /// <p>The request syntax was malformed. Check your request syntax and try again.</p>
     BadRequest(String),
@@ -2379,6 +2484,8 @@ pub enum DeleteVirtualServiceError {
     /// account. For best results, use an increasing or variable sleep interval between
     /// requests.</p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteVirtualServiceError {
@@ -2386,31 +2493,40 @@ impl DeleteVirtualServiceError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(DeleteVirtualServiceError::BadRequest(err.msg))
+                    return RusotoError::service(
+                        DeleteVirtualServiceError::BadRequest(err.msg),
+                        &res,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(DeleteVirtualServiceError::Forbidden(err.msg))
+                    return RusotoError::service(
+                        DeleteVirtualServiceError::Forbidden(err.msg),
+                        &res,
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(DeleteVirtualServiceError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteVirtualServiceError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteVirtualServiceError::NotFound(err.msg))
+                    return RusotoError::service(DeleteVirtualServiceError::NotFound(err.msg), &res)
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(DeleteVirtualServiceError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteVirtualServiceError::ServiceUnavailable(err.msg),
+                        &res,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(DeleteVirtualServiceError::TooManyRequests(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteVirtualServiceError::TooManyRequests(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteVirtualServiceError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2426,12 +2542,14 @@ impl fmt::Display for DeleteVirtualServiceError {
             DeleteVirtualServiceError::NotFound(ref cause) => write!(f, "{}", cause),
             DeleteVirtualServiceError::ServiceUnavailable(ref cause) => write!(f, "{}", cause),
             DeleteVirtualServiceError::TooManyRequests(ref cause) => write!(f, "{}", cause),
+            DeleteVirtualServiceError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteVirtualServiceError {}
 /// Errors returned by DescribeMesh
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeMeshError {
     /// <p>The request syntax was malformed. Check your request syntax and try again.</p>
     BadRequest(String),
@@ -2448,6 +2566,8 @@ pub enum DescribeMeshError {
     /// account. For best results, use an increasing or variable sleep interval between
     /// requests.</p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeMeshError {
@@ -2455,25 +2575,31 @@ impl DescribeMeshError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(DescribeMeshError::BadRequest(err.msg))
+                    return RusotoError::service(DescribeMeshError::BadRequest(err.msg), &res)
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(DescribeMeshError::Forbidden(err.msg))
+                    return RusotoError::service(DescribeMeshError::Forbidden(err.msg), &res)
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(DescribeMeshError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        DescribeMeshError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DescribeMeshError::NotFound(err.msg))
+                    return RusotoError::service(DescribeMeshError::NotFound(err.msg), &res)
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(DescribeMeshError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        DescribeMeshError::ServiceUnavailable(err.msg),
+                        &res,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(DescribeMeshError::TooManyRequests(err.msg))
+                    return RusotoError::service(DescribeMeshError::TooManyRequests(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DescribeMeshError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2489,12 +2615,14 @@ impl fmt::Display for DescribeMeshError {
             DescribeMeshError::NotFound(ref cause) => write!(f, "{}", cause),
             DescribeMeshError::ServiceUnavailable(ref cause) => write!(f, "{}", cause),
             DescribeMeshError::TooManyRequests(ref cause) => write!(f, "{}", cause),
+            DescribeMeshError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeMeshError {}
 /// Errors returned by DescribeRoute
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeRouteError {
     /// <p>The request syntax was malformed. Check your request syntax and try again.</p>
     BadRequest(String),
@@ -2511,6 +2639,8 @@ pub enum DescribeRouteError {
     /// account. For best results, use an increasing or variable sleep interval between
     /// requests.</p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeRouteError {
@@ -2518,25 +2648,31 @@ impl DescribeRouteError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(DescribeRouteError::BadRequest(err.msg))
+                    return RusotoError::service(DescribeRouteError::BadRequest(err.msg), &res)
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(DescribeRouteError::Forbidden(err.msg))
+                    return RusotoError::service(DescribeRouteError::Forbidden(err.msg), &res)
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(DescribeRouteError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        DescribeRouteError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DescribeRouteError::NotFound(err.msg))
+                    return RusotoError::service(DescribeRouteError::NotFound(err.msg), &res)
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(DescribeRouteError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        DescribeRouteError::ServiceUnavailable(err.msg),
+                        &res,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(DescribeRouteError::TooManyRequests(err.msg))
+                    return RusotoError::service(DescribeRouteError::TooManyRequests(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DescribeRouteError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2552,12 +2688,14 @@ impl fmt::Display for DescribeRouteError {
             DescribeRouteError::NotFound(ref cause) => write!(f, "{}", cause),
             DescribeRouteError::ServiceUnavailable(ref cause) => write!(f, "{}", cause),
             DescribeRouteError::TooManyRequests(ref cause) => write!(f, "{}", cause),
+            DescribeRouteError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeRouteError {}
 /// Errors returned by DescribeVirtualNode
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeVirtualNodeError {
     /// <p>The request syntax was malformed. Check your request syntax and try again.</p>
     BadRequest(String),
@@ -2574,6 +2712,8 @@ pub enum DescribeVirtualNodeError {
     /// account. For best results, use an increasing or variable sleep interval between
     /// requests.</p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeVirtualNodeError {
@@ -2581,29 +2721,37 @@ impl DescribeVirtualNodeError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(DescribeVirtualNodeError::BadRequest(err.msg))
+                    return RusotoError::service(
+                        DescribeVirtualNodeError::BadRequest(err.msg),
+                        &res,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(DescribeVirtualNodeError::Forbidden(err.msg))
+                    return RusotoError::service(DescribeVirtualNodeError::Forbidden(err.msg), &res)
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(DescribeVirtualNodeError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeVirtualNodeError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DescribeVirtualNodeError::NotFound(err.msg))
+                    return RusotoError::service(DescribeVirtualNodeError::NotFound(err.msg), &res)
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(DescribeVirtualNodeError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeVirtualNodeError::ServiceUnavailable(err.msg),
+                        &res,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(DescribeVirtualNodeError::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        DescribeVirtualNodeError::TooManyRequests(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DescribeVirtualNodeError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2619,12 +2767,14 @@ impl fmt::Display for DescribeVirtualNodeError {
             DescribeVirtualNodeError::NotFound(ref cause) => write!(f, "{}", cause),
             DescribeVirtualNodeError::ServiceUnavailable(ref cause) => write!(f, "{}", cause),
             DescribeVirtualNodeError::TooManyRequests(ref cause) => write!(f, "{}", cause),
+            DescribeVirtualNodeError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeVirtualNodeError {}
 /// Errors returned by DescribeVirtualRouter
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeVirtualRouterError {
     /// <p>The request syntax was malformed. Check your request syntax and try again.</p>
     BadRequest(String),
@@ -2641,6 +2791,8 @@ pub enum DescribeVirtualRouterError {
     /// account. For best results, use an increasing or variable sleep interval between
     /// requests.</p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeVirtualRouterError {
@@ -2648,31 +2800,43 @@ impl DescribeVirtualRouterError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(DescribeVirtualRouterError::BadRequest(err.msg))
+                    return RusotoError::service(
+                        DescribeVirtualRouterError::BadRequest(err.msg),
+                        &res,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(DescribeVirtualRouterError::Forbidden(err.msg))
+                    return RusotoError::service(
+                        DescribeVirtualRouterError::Forbidden(err.msg),
+                        &res,
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(DescribeVirtualRouterError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeVirtualRouterError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DescribeVirtualRouterError::NotFound(err.msg))
+                    return RusotoError::service(
+                        DescribeVirtualRouterError::NotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(DescribeVirtualRouterError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeVirtualRouterError::ServiceUnavailable(err.msg),
+                        &res,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(DescribeVirtualRouterError::TooManyRequests(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeVirtualRouterError::TooManyRequests(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DescribeVirtualRouterError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2688,12 +2852,14 @@ impl fmt::Display for DescribeVirtualRouterError {
             DescribeVirtualRouterError::NotFound(ref cause) => write!(f, "{}", cause),
             DescribeVirtualRouterError::ServiceUnavailable(ref cause) => write!(f, "{}", cause),
             DescribeVirtualRouterError::TooManyRequests(ref cause) => write!(f, "{}", cause),
+            DescribeVirtualRouterError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeVirtualRouterError {}
 /// Errors returned by DescribeVirtualService
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeVirtualServiceError {
     /// <p>The request syntax was malformed. Check your request syntax and try again.</p>
     BadRequest(String),
@@ -2710,6 +2876,8 @@ pub enum DescribeVirtualServiceError {
     /// account. For best results, use an increasing or variable sleep interval between
     /// requests.</p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeVirtualServiceError {
@@ -2717,31 +2885,43 @@ impl DescribeVirtualServiceError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(DescribeVirtualServiceError::BadRequest(err.msg))
+                    return RusotoError::service(
+                        DescribeVirtualServiceError::BadRequest(err.msg),
+                        &res,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(DescribeVirtualServiceError::Forbidden(err.msg))
+                    return RusotoError::service(
+                        DescribeVirtualServiceError::Forbidden(err.msg),
+                        &res,
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(DescribeVirtualServiceError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeVirtualServiceError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DescribeVirtualServiceError::NotFound(err.msg))
+                    return RusotoError::service(
+                        DescribeVirtualServiceError::NotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(DescribeVirtualServiceError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeVirtualServiceError::ServiceUnavailable(err.msg),
+                        &res,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(DescribeVirtualServiceError::TooManyRequests(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeVirtualServiceError::TooManyRequests(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DescribeVirtualServiceError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2757,12 +2937,14 @@ impl fmt::Display for DescribeVirtualServiceError {
             DescribeVirtualServiceError::NotFound(ref cause) => write!(f, "{}", cause),
             DescribeVirtualServiceError::ServiceUnavailable(ref cause) => write!(f, "{}", cause),
             DescribeVirtualServiceError::TooManyRequests(ref cause) => write!(f, "{}", cause),
+            DescribeVirtualServiceError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeVirtualServiceError {}
 /// Errors returned by ListMeshes
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListMeshesError {
     /// <p>The request syntax was malformed. Check your request syntax and try again.</p>
     BadRequest(String),
@@ -2779,6 +2961,8 @@ pub enum ListMeshesError {
     /// account. For best results, use an increasing or variable sleep interval between
     /// requests.</p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListMeshesError {
@@ -2786,25 +2970,28 @@ impl ListMeshesError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(ListMeshesError::BadRequest(err.msg))
+                    return RusotoError::service(ListMeshesError::BadRequest(err.msg), &res)
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(ListMeshesError::Forbidden(err.msg))
+                    return RusotoError::service(ListMeshesError::Forbidden(err.msg), &res)
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(ListMeshesError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        ListMeshesError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(ListMeshesError::NotFound(err.msg))
+                    return RusotoError::service(ListMeshesError::NotFound(err.msg), &res)
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(ListMeshesError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(ListMeshesError::ServiceUnavailable(err.msg), &res)
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(ListMeshesError::TooManyRequests(err.msg))
+                    return RusotoError::service(ListMeshesError::TooManyRequests(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListMeshesError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2820,12 +3007,14 @@ impl fmt::Display for ListMeshesError {
             ListMeshesError::NotFound(ref cause) => write!(f, "{}", cause),
             ListMeshesError::ServiceUnavailable(ref cause) => write!(f, "{}", cause),
             ListMeshesError::TooManyRequests(ref cause) => write!(f, "{}", cause),
+            ListMeshesError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListMeshesError {}
 /// Errors returned by ListRoutes
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListRoutesError {
     /// <p>The request syntax was malformed. Check your request syntax and try again.</p>
     BadRequest(String),
@@ -2842,6 +3031,8 @@ pub enum ListRoutesError {
     /// account. For best results, use an increasing or variable sleep interval between
     /// requests.</p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListRoutesError {
@@ -2849,25 +3040,28 @@ impl ListRoutesError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(ListRoutesError::BadRequest(err.msg))
+                    return RusotoError::service(ListRoutesError::BadRequest(err.msg), &res)
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(ListRoutesError::Forbidden(err.msg))
+                    return RusotoError::service(ListRoutesError::Forbidden(err.msg), &res)
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(ListRoutesError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        ListRoutesError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(ListRoutesError::NotFound(err.msg))
+                    return RusotoError::service(ListRoutesError::NotFound(err.msg), &res)
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(ListRoutesError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(ListRoutesError::ServiceUnavailable(err.msg), &res)
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(ListRoutesError::TooManyRequests(err.msg))
+                    return RusotoError::service(ListRoutesError::TooManyRequests(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListRoutesError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2883,12 +3077,14 @@ impl fmt::Display for ListRoutesError {
             ListRoutesError::NotFound(ref cause) => write!(f, "{}", cause),
             ListRoutesError::ServiceUnavailable(ref cause) => write!(f, "{}", cause),
             ListRoutesError::TooManyRequests(ref cause) => write!(f, "{}", cause),
+            ListRoutesError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListRoutesError {}
 /// Errors returned by ListTagsForResource
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListTagsForResourceError {
     /// <p>The request syntax was malformed. Check your request syntax and try again.</p>
     BadRequest(String),
@@ -2905,6 +3101,8 @@ pub enum ListTagsForResourceError {
     /// account. For best results, use an increasing or variable sleep interval between
     /// requests.</p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListTagsForResourceError {
@@ -2912,29 +3110,37 @@ impl ListTagsForResourceError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(ListTagsForResourceError::BadRequest(err.msg))
+                    return RusotoError::service(
+                        ListTagsForResourceError::BadRequest(err.msg),
+                        &res,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(ListTagsForResourceError::Forbidden(err.msg))
+                    return RusotoError::service(ListTagsForResourceError::Forbidden(err.msg), &res)
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(ListTagsForResourceError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListTagsForResourceError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(ListTagsForResourceError::NotFound(err.msg))
+                    return RusotoError::service(ListTagsForResourceError::NotFound(err.msg), &res)
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(ListTagsForResourceError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListTagsForResourceError::ServiceUnavailable(err.msg),
+                        &res,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(ListTagsForResourceError::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        ListTagsForResourceError::TooManyRequests(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListTagsForResourceError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2950,12 +3156,14 @@ impl fmt::Display for ListTagsForResourceError {
             ListTagsForResourceError::NotFound(ref cause) => write!(f, "{}", cause),
             ListTagsForResourceError::ServiceUnavailable(ref cause) => write!(f, "{}", cause),
             ListTagsForResourceError::TooManyRequests(ref cause) => write!(f, "{}", cause),
+            ListTagsForResourceError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListTagsForResourceError {}
 /// Errors returned by ListVirtualNodes
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListVirtualNodesError {
     /// <p>The request syntax was malformed. Check your request syntax and try again.</p>
     BadRequest(String),
@@ -2972,6 +3180,8 @@ pub enum ListVirtualNodesError {
     /// account. For best results, use an increasing or variable sleep interval between
     /// requests.</p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListVirtualNodesError {
@@ -2979,27 +3189,34 @@ impl ListVirtualNodesError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(ListVirtualNodesError::BadRequest(err.msg))
+                    return RusotoError::service(ListVirtualNodesError::BadRequest(err.msg), &res)
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(ListVirtualNodesError::Forbidden(err.msg))
+                    return RusotoError::service(ListVirtualNodesError::Forbidden(err.msg), &res)
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(ListVirtualNodesError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListVirtualNodesError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(ListVirtualNodesError::NotFound(err.msg))
+                    return RusotoError::service(ListVirtualNodesError::NotFound(err.msg), &res)
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(ListVirtualNodesError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        ListVirtualNodesError::ServiceUnavailable(err.msg),
+                        &res,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(ListVirtualNodesError::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        ListVirtualNodesError::TooManyRequests(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListVirtualNodesError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3015,12 +3232,14 @@ impl fmt::Display for ListVirtualNodesError {
             ListVirtualNodesError::NotFound(ref cause) => write!(f, "{}", cause),
             ListVirtualNodesError::ServiceUnavailable(ref cause) => write!(f, "{}", cause),
             ListVirtualNodesError::TooManyRequests(ref cause) => write!(f, "{}", cause),
+            ListVirtualNodesError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListVirtualNodesError {}
 /// Errors returned by ListVirtualRouters
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListVirtualRoutersError {
     /// <p>The request syntax was malformed. Check your request syntax and try again.</p>
     BadRequest(String),
@@ -3037,6 +3256,8 @@ pub enum ListVirtualRoutersError {
     /// account. For best results, use an increasing or variable sleep interval between
     /// requests.</p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListVirtualRoutersError {
@@ -3044,29 +3265,34 @@ impl ListVirtualRoutersError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(ListVirtualRoutersError::BadRequest(err.msg))
+                    return RusotoError::service(ListVirtualRoutersError::BadRequest(err.msg), &res)
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(ListVirtualRoutersError::Forbidden(err.msg))
+                    return RusotoError::service(ListVirtualRoutersError::Forbidden(err.msg), &res)
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(ListVirtualRoutersError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListVirtualRoutersError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(ListVirtualRoutersError::NotFound(err.msg))
+                    return RusotoError::service(ListVirtualRoutersError::NotFound(err.msg), &res)
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(ListVirtualRoutersError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListVirtualRoutersError::ServiceUnavailable(err.msg),
+                        &res,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(ListVirtualRoutersError::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        ListVirtualRoutersError::TooManyRequests(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListVirtualRoutersError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3082,12 +3308,14 @@ impl fmt::Display for ListVirtualRoutersError {
             ListVirtualRoutersError::NotFound(ref cause) => write!(f, "{}", cause),
             ListVirtualRoutersError::ServiceUnavailable(ref cause) => write!(f, "{}", cause),
             ListVirtualRoutersError::TooManyRequests(ref cause) => write!(f, "{}", cause),
+            ListVirtualRoutersError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListVirtualRoutersError {}
 /// Errors returned by ListVirtualServices
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListVirtualServicesError {
     /// <p>The request syntax was malformed. Check your request syntax and try again.</p>
     BadRequest(String),
@@ -3104,6 +3332,8 @@ pub enum ListVirtualServicesError {
     /// account. For best results, use an increasing or variable sleep interval between
     /// requests.</p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListVirtualServicesError {
@@ -3111,29 +3341,37 @@ impl ListVirtualServicesError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(ListVirtualServicesError::BadRequest(err.msg))
+                    return RusotoError::service(
+                        ListVirtualServicesError::BadRequest(err.msg),
+                        &res,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(ListVirtualServicesError::Forbidden(err.msg))
+                    return RusotoError::service(ListVirtualServicesError::Forbidden(err.msg), &res)
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(ListVirtualServicesError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListVirtualServicesError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(ListVirtualServicesError::NotFound(err.msg))
+                    return RusotoError::service(ListVirtualServicesError::NotFound(err.msg), &res)
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(ListVirtualServicesError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListVirtualServicesError::ServiceUnavailable(err.msg),
+                        &res,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(ListVirtualServicesError::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        ListVirtualServicesError::TooManyRequests(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListVirtualServicesError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3149,12 +3387,14 @@ impl fmt::Display for ListVirtualServicesError {
             ListVirtualServicesError::NotFound(ref cause) => write!(f, "{}", cause),
             ListVirtualServicesError::ServiceUnavailable(ref cause) => write!(f, "{}", cause),
             ListVirtualServicesError::TooManyRequests(ref cause) => write!(f, "{}", cause),
+            ListVirtualServicesError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListVirtualServicesError {}
 /// Errors returned by TagResource
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum TagResourceError {
     /// <p>The request syntax was malformed. Check your request syntax and try again.</p>
     BadRequest(String),
@@ -3175,6 +3415,8 @@ pub enum TagResourceError {
     /// limit is 50 user tags per resource. You must reduce the number of tags in the request. None
     /// of the tags in this request were applied.</p>
     TooManyTags(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl TagResourceError {
@@ -3182,28 +3424,34 @@ impl TagResourceError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(TagResourceError::BadRequest(err.msg))
+                    return RusotoError::service(TagResourceError::BadRequest(err.msg), &res)
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(TagResourceError::Forbidden(err.msg))
+                    return RusotoError::service(TagResourceError::Forbidden(err.msg), &res)
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(TagResourceError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        TagResourceError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(TagResourceError::NotFound(err.msg))
+                    return RusotoError::service(TagResourceError::NotFound(err.msg), &res)
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(TagResourceError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        TagResourceError::ServiceUnavailable(err.msg),
+                        &res,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(TagResourceError::TooManyRequests(err.msg))
+                    return RusotoError::service(TagResourceError::TooManyRequests(err.msg), &res)
                 }
                 "TooManyTagsException" => {
-                    return RusotoError::Service(TagResourceError::TooManyTags(err.msg))
+                    return RusotoError::service(TagResourceError::TooManyTags(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(TagResourceError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3220,12 +3468,14 @@ impl fmt::Display for TagResourceError {
             TagResourceError::ServiceUnavailable(ref cause) => write!(f, "{}", cause),
             TagResourceError::TooManyRequests(ref cause) => write!(f, "{}", cause),
             TagResourceError::TooManyTags(ref cause) => write!(f, "{}", cause),
+            TagResourceError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for TagResourceError {}
 /// Errors returned by UntagResource
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UntagResourceError {
     /// <p>The request syntax was malformed. Check your request syntax and try again.</p>
     BadRequest(String),
@@ -3242,6 +3492,8 @@ pub enum UntagResourceError {
     /// account. For best results, use an increasing or variable sleep interval between
     /// requests.</p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UntagResourceError {
@@ -3249,25 +3501,31 @@ impl UntagResourceError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(UntagResourceError::BadRequest(err.msg))
+                    return RusotoError::service(UntagResourceError::BadRequest(err.msg), &res)
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(UntagResourceError::Forbidden(err.msg))
+                    return RusotoError::service(UntagResourceError::Forbidden(err.msg), &res)
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(UntagResourceError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        UntagResourceError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(UntagResourceError::NotFound(err.msg))
+                    return RusotoError::service(UntagResourceError::NotFound(err.msg), &res)
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(UntagResourceError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        UntagResourceError::ServiceUnavailable(err.msg),
+                        &res,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(UntagResourceError::TooManyRequests(err.msg))
+                    return RusotoError::service(UntagResourceError::TooManyRequests(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UntagResourceError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3283,12 +3541,14 @@ impl fmt::Display for UntagResourceError {
             UntagResourceError::NotFound(ref cause) => write!(f, "{}", cause),
             UntagResourceError::ServiceUnavailable(ref cause) => write!(f, "{}", cause),
             UntagResourceError::TooManyRequests(ref cause) => write!(f, "{}", cause),
+            UntagResourceError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for UntagResourceError {}
 /// Errors returned by UpdateMesh
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdateMeshError {
     /// <p>The request syntax was malformed. Check your request syntax and try again.</p>
     BadRequest(String),
@@ -3308,6 +3568,8 @@ pub enum UpdateMeshError {
     /// account. For best results, use an increasing or variable sleep interval between
     /// requests.</p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdateMeshError {
@@ -3315,28 +3577,31 @@ impl UpdateMeshError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(UpdateMeshError::BadRequest(err.msg))
+                    return RusotoError::service(UpdateMeshError::BadRequest(err.msg), &res)
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(UpdateMeshError::Conflict(err.msg))
+                    return RusotoError::service(UpdateMeshError::Conflict(err.msg), &res)
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(UpdateMeshError::Forbidden(err.msg))
+                    return RusotoError::service(UpdateMeshError::Forbidden(err.msg), &res)
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(UpdateMeshError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        UpdateMeshError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(UpdateMeshError::NotFound(err.msg))
+                    return RusotoError::service(UpdateMeshError::NotFound(err.msg), &res)
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(UpdateMeshError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(UpdateMeshError::ServiceUnavailable(err.msg), &res)
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(UpdateMeshError::TooManyRequests(err.msg))
+                    return RusotoError::service(UpdateMeshError::TooManyRequests(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UpdateMeshError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3353,12 +3618,14 @@ impl fmt::Display for UpdateMeshError {
             UpdateMeshError::NotFound(ref cause) => write!(f, "{}", cause),
             UpdateMeshError::ServiceUnavailable(ref cause) => write!(f, "{}", cause),
             UpdateMeshError::TooManyRequests(ref cause) => write!(f, "{}", cause),
+            UpdateMeshError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for UpdateMeshError {}
 /// Errors returned by UpdateRoute
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdateRouteError {
     /// <p>The request syntax was malformed. Check your request syntax and try again.</p>
     BadRequest(String),
@@ -3381,6 +3648,8 @@ pub enum UpdateRouteError {
     /// account. For best results, use an increasing or variable sleep interval between
     /// requests.</p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdateRouteError {
@@ -3388,31 +3657,37 @@ impl UpdateRouteError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(UpdateRouteError::BadRequest(err.msg))
+                    return RusotoError::service(UpdateRouteError::BadRequest(err.msg), &res)
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(UpdateRouteError::Conflict(err.msg))
+                    return RusotoError::service(UpdateRouteError::Conflict(err.msg), &res)
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(UpdateRouteError::Forbidden(err.msg))
+                    return RusotoError::service(UpdateRouteError::Forbidden(err.msg), &res)
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(UpdateRouteError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        UpdateRouteError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(UpdateRouteError::LimitExceeded(err.msg))
+                    return RusotoError::service(UpdateRouteError::LimitExceeded(err.msg), &res)
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(UpdateRouteError::NotFound(err.msg))
+                    return RusotoError::service(UpdateRouteError::NotFound(err.msg), &res)
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(UpdateRouteError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        UpdateRouteError::ServiceUnavailable(err.msg),
+                        &res,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(UpdateRouteError::TooManyRequests(err.msg))
+                    return RusotoError::service(UpdateRouteError::TooManyRequests(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UpdateRouteError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3430,12 +3705,14 @@ impl fmt::Display for UpdateRouteError {
             UpdateRouteError::NotFound(ref cause) => write!(f, "{}", cause),
             UpdateRouteError::ServiceUnavailable(ref cause) => write!(f, "{}", cause),
             UpdateRouteError::TooManyRequests(ref cause) => write!(f, "{}", cause),
+            UpdateRouteError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for UpdateRouteError {}
 /// Errors returned by UpdateVirtualNode
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdateVirtualNodeError {
     /// <p>The request syntax was malformed. Check your request syntax and try again.</p>
     BadRequest(String),
@@ -3458,6 +3735,8 @@ pub enum UpdateVirtualNodeError {
     /// account. For best results, use an increasing or variable sleep interval between
     /// requests.</p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdateVirtualNodeError {
@@ -3465,35 +3744,43 @@ impl UpdateVirtualNodeError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(UpdateVirtualNodeError::BadRequest(err.msg))
+                    return RusotoError::service(UpdateVirtualNodeError::BadRequest(err.msg), &res)
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(UpdateVirtualNodeError::Conflict(err.msg))
+                    return RusotoError::service(UpdateVirtualNodeError::Conflict(err.msg), &res)
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(UpdateVirtualNodeError::Forbidden(err.msg))
+                    return RusotoError::service(UpdateVirtualNodeError::Forbidden(err.msg), &res)
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(UpdateVirtualNodeError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateVirtualNodeError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(UpdateVirtualNodeError::LimitExceeded(err.msg))
+                    return RusotoError::service(
+                        UpdateVirtualNodeError::LimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(UpdateVirtualNodeError::NotFound(err.msg))
+                    return RusotoError::service(UpdateVirtualNodeError::NotFound(err.msg), &res)
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(UpdateVirtualNodeError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateVirtualNodeError::ServiceUnavailable(err.msg),
+                        &res,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(UpdateVirtualNodeError::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        UpdateVirtualNodeError::TooManyRequests(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UpdateVirtualNodeError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3511,12 +3798,14 @@ impl fmt::Display for UpdateVirtualNodeError {
             UpdateVirtualNodeError::NotFound(ref cause) => write!(f, "{}", cause),
             UpdateVirtualNodeError::ServiceUnavailable(ref cause) => write!(f, "{}", cause),
             UpdateVirtualNodeError::TooManyRequests(ref cause) => write!(f, "{}", cause),
+            UpdateVirtualNodeError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for UpdateVirtualNodeError {}
 /// Errors returned by UpdateVirtualRouter
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdateVirtualRouterError {
     /// <p>The request syntax was malformed. Check your request syntax and try again.</p>
     BadRequest(String),
@@ -3539,6 +3828,8 @@ pub enum UpdateVirtualRouterError {
     /// account. For best results, use an increasing or variable sleep interval between
     /// requests.</p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdateVirtualRouterError {
@@ -3546,35 +3837,46 @@ impl UpdateVirtualRouterError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(UpdateVirtualRouterError::BadRequest(err.msg))
+                    return RusotoError::service(
+                        UpdateVirtualRouterError::BadRequest(err.msg),
+                        &res,
+                    )
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(UpdateVirtualRouterError::Conflict(err.msg))
+                    return RusotoError::service(UpdateVirtualRouterError::Conflict(err.msg), &res)
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(UpdateVirtualRouterError::Forbidden(err.msg))
+                    return RusotoError::service(UpdateVirtualRouterError::Forbidden(err.msg), &res)
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(UpdateVirtualRouterError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateVirtualRouterError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(UpdateVirtualRouterError::LimitExceeded(err.msg))
+                    return RusotoError::service(
+                        UpdateVirtualRouterError::LimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(UpdateVirtualRouterError::NotFound(err.msg))
+                    return RusotoError::service(UpdateVirtualRouterError::NotFound(err.msg), &res)
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(UpdateVirtualRouterError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateVirtualRouterError::ServiceUnavailable(err.msg),
+                        &res,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(UpdateVirtualRouterError::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        UpdateVirtualRouterError::TooManyRequests(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UpdateVirtualRouterError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3592,12 +3894,14 @@ impl fmt::Display for UpdateVirtualRouterError {
             UpdateVirtualRouterError::NotFound(ref cause) => write!(f, "{}", cause),
             UpdateVirtualRouterError::ServiceUnavailable(ref cause) => write!(f, "{}", cause),
             UpdateVirtualRouterError::TooManyRequests(ref cause) => write!(f, "{}", cause),
+            UpdateVirtualRouterError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for UpdateVirtualRouterError {}
 /// Errors returned by UpdateVirtualService
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdateVirtualServiceError {
     /// <p>The request syntax was malformed. Check your request syntax and try again.</p>
     BadRequest(String),
@@ -3620,6 +3924,8 @@ pub enum UpdateVirtualServiceError {
     /// account. For best results, use an increasing or variable sleep interval between
     /// requests.</p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdateVirtualServiceError {
@@ -3627,37 +3933,49 @@ impl UpdateVirtualServiceError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(UpdateVirtualServiceError::BadRequest(err.msg))
+                    return RusotoError::service(
+                        UpdateVirtualServiceError::BadRequest(err.msg),
+                        &res,
+                    )
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(UpdateVirtualServiceError::Conflict(err.msg))
+                    return RusotoError::service(UpdateVirtualServiceError::Conflict(err.msg), &res)
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(UpdateVirtualServiceError::Forbidden(err.msg))
+                    return RusotoError::service(
+                        UpdateVirtualServiceError::Forbidden(err.msg),
+                        &res,
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(UpdateVirtualServiceError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateVirtualServiceError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(UpdateVirtualServiceError::LimitExceeded(err.msg))
+                    return RusotoError::service(
+                        UpdateVirtualServiceError::LimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(UpdateVirtualServiceError::NotFound(err.msg))
+                    return RusotoError::service(UpdateVirtualServiceError::NotFound(err.msg), &res)
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(UpdateVirtualServiceError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateVirtualServiceError::ServiceUnavailable(err.msg),
+                        &res,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(UpdateVirtualServiceError::TooManyRequests(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateVirtualServiceError::TooManyRequests(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UpdateVirtualServiceError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3675,6 +3993,7 @@ impl fmt::Display for UpdateVirtualServiceError {
             UpdateVirtualServiceError::NotFound(ref cause) => write!(f, "{}", cause),
             UpdateVirtualServiceError::ServiceUnavailable(ref cause) => write!(f, "{}", cause),
             UpdateVirtualServiceError::TooManyRequests(ref cause) => write!(f, "{}", cause),
+            UpdateVirtualServiceError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
@@ -3925,6 +4244,7 @@ impl AppMeshClient {
     /// Creates a client backed by the default tokio event loop.
     ///
     /// The client will use the default credentials provider and tls client.
+    #[cfg(not(target_arch = "wasm32"))]
     pub fn new(region: region::Region) -> AppMeshClient {
         AppMeshClient {
             client: Client::shared(),
@@ -3950,60 +4270,120 @@ impl AppMeshClient {
     pub fn new_with_client(client: Client, region: region::Region) -> AppMeshClient {
         AppMeshClient { client, region }
     }
+
+    /// Returns a builder of a client, setting any of its region, credentials provider,
+    /// dispatcher, retry policy, endpoint and timeouts.
+    pub fn builder() -> rusoto_core::ClientBuilder<AppMeshClient> {
+        rusoto_core::ClientBuilder::new(AppMeshClient::new_with_client)
+    }
+
+    /// Returns a clone of this client with the overrides of `config` applied to its
+    /// operations, sharing its connections.
+    pub fn customize(&self, config: &rusoto_core::OperationConfig) -> AppMeshClient {
+        AppMeshClient {
+            client: self.client.customize(config),
+            region: config.resolve_region(&self.region),
+        }
+    }
 }
 
-#[async_trait]
-impl AppMesh for AppMeshClient {
-    /// <p>Creates a service mesh. A service mesh is a logical boundary for network traffic between
-    /// the services that reside within it.</p>
-    ///
-    /// <pre><code>     &lt;p&gt;After you create your service mesh, you can create virtual services, virtual nodes,
-    /// virtual routers, and routes to distribute traffic between the applications in your
-    /// mesh.&lt;/p&gt;
-    /// </code></pre>
-    async fn create_mesh(
+impl AppMeshClient {
+    /// Calls `create_mesh`, also returning the metadata of the response.
+    pub async fn create_mesh_with_metadata(
         &self,
         input: CreateMeshInput,
-    ) -> Result<CreateMeshOutput, RusotoError<CreateMeshError>> {
+    ) -> Result<(CreateMeshOutput, ResponseMetadata), RusotoError<CreateMeshError>> {
+        let request = self.build_create_mesh_request(input);
+
+        let mut response = self
+            .client
+            .sign_and_dispatch(request)
+            .await
+            .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
+        if response.status.as_u16() == 200 {
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<CreateMeshOutput, _>()?;
+
+            Ok((result, metadata))
+        } else {
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(CreateMeshError::from_response(response))
+        }
+    }
+
+    /// Presigns the request `create_mesh` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_mesh(
+        &self,
+        presigner: &rusoto_core::Presigner,
+        input: CreateMeshInput,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_mesh_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_mesh_request(&self, input: CreateMeshInput) -> SignedRequest {
         let request_uri = "/v20190125/meshes";
 
         let mut request = SignedRequest::new("PUT", "appmesh", &self.region, &request_uri);
+        request.set_operation("CreateMesh");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `create_route`, also returning the metadata of the response.
+    pub async fn create_route_with_metadata(
+        &self,
+        input: CreateRouteInput,
+    ) -> Result<(CreateRouteOutput, ResponseMetadata), RusotoError<CreateRouteError>> {
+        let request = self.build_create_route_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<CreateMeshOutput, _>()?;
+                .deserialize::<CreateRouteOutput, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(CreateMeshError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(CreateRouteError::from_response(response))
         }
     }
 
-    /// <p>Creates a route that is associated with a virtual router.</p>
-    ///
-    /// <pre><code>     &lt;p&gt;You can use the &lt;code&gt;prefix&lt;/code&gt; parameter in your route specification for path-based
-    /// routing of requests. For example, if your virtual service name is
-    /// &lt;code&gt;my-service.local&lt;/code&gt; and you want the route to match requests to
-    /// &lt;code&gt;my-service.local/metrics&lt;/code&gt;, your prefix should be
-    /// &lt;code&gt;/metrics&lt;/code&gt;.&lt;/p&gt;
-    /// &lt;p&gt;If your route matches a request, you can distribute traffic to one or more target
-    /// virtual nodes with relative weighting.&lt;/p&gt;
-    /// </code></pre>
-    async fn create_route(
+    /// Presigns the request `create_route` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_route(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: CreateRouteInput,
-    ) -> Result<CreateRouteOutput, RusotoError<CreateRouteError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_route_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_route_request(&self, input: CreateRouteInput) -> SignedRequest {
         let request_uri = format!(
             "/v20190125/meshes/{mesh_name}/virtualRouter/{virtual_router_name}/routes",
             mesh_name = input.mesh_name,
@@ -4011,197 +4391,290 @@ impl AppMesh for AppMeshClient {
         );
 
         let mut request = SignedRequest::new("PUT", "appmesh", &self.region, &request_uri);
+        request.set_operation("CreateRoute");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `create_virtual_node`, also returning the metadata of the response.
+    pub async fn create_virtual_node_with_metadata(
+        &self,
+        input: CreateVirtualNodeInput,
+    ) -> Result<(CreateVirtualNodeOutput, ResponseMetadata), RusotoError<CreateVirtualNodeError>>
+    {
+        let request = self.build_create_virtual_node_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<CreateRouteOutput, _>()?;
+                .deserialize::<CreateVirtualNodeOutput, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(CreateRouteError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(CreateVirtualNodeError::from_response(response))
         }
     }
 
-    /// <p>Creates a virtual node within a service mesh.</p>
-    ///
-    /// <pre><code>     &lt;p&gt;A virtual node acts as a logical pointer to a particular task group, such as an Amazon ECS
-    /// service or a Kubernetes deployment. When you create a virtual node, you can specify the
-    /// service discovery information for your task group.&lt;/p&gt;
-    /// &lt;p&gt;Any inbound traffic that your virtual node expects should be specified as a
-    /// &lt;code&gt;listener&lt;/code&gt;. Any outbound traffic that your virtual node expects to reach
-    /// should be specified as a &lt;code&gt;backend&lt;/code&gt;.&lt;/p&gt;
-    /// &lt;p&gt;The response metadata for your new virtual node contains the &lt;code&gt;arn&lt;/code&gt; that is
-    /// associated with the virtual node. Set this value (either the full ARN or the truncated
-    /// resource name: for example, &lt;code&gt;mesh/default/virtualNode/simpleapp&lt;/code&gt;) as the
-    /// &lt;code&gt;APPMESH_VIRTUAL_NODE_NAME&lt;/code&gt; environment variable for your task group&#39;s Envoy
-    /// proxy container in your task definition or pod spec. This is then mapped to the
-    /// &lt;code&gt;node.id&lt;/code&gt; and &lt;code&gt;node.cluster&lt;/code&gt; Envoy parameters.&lt;/p&gt;
-    /// &lt;note&gt;
-    /// &lt;p&gt;If you require your Envoy stats or tracing to use a different name, you can override
-    /// the &lt;code&gt;node.cluster&lt;/code&gt; value that is set by
-    /// &lt;code&gt;APPMESH_VIRTUAL_NODE_NAME&lt;/code&gt; with the
-    /// &lt;code&gt;APPMESH_VIRTUAL_NODE_CLUSTER&lt;/code&gt; environment variable.&lt;/p&gt;
-    /// &lt;/note&gt;
-    /// </code></pre>
-    async fn create_virtual_node(
+    /// Presigns the request `create_virtual_node` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_virtual_node(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: CreateVirtualNodeInput,
-    ) -> Result<CreateVirtualNodeOutput, RusotoError<CreateVirtualNodeError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_virtual_node_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_virtual_node_request(&self, input: CreateVirtualNodeInput) -> SignedRequest {
         let request_uri = format!(
             "/v20190125/meshes/{mesh_name}/virtualNodes",
             mesh_name = input.mesh_name
         );
 
         let mut request = SignedRequest::new("PUT", "appmesh", &self.region, &request_uri);
+        request.set_operation("CreateVirtualNode");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `create_virtual_router`, also returning the metadata of the response.
+    pub async fn create_virtual_router_with_metadata(
+        &self,
+        input: CreateVirtualRouterInput,
+    ) -> Result<(CreateVirtualRouterOutput, ResponseMetadata), RusotoError<CreateVirtualRouterError>>
+    {
+        let request = self.build_create_virtual_router_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<CreateVirtualNodeOutput, _>()?;
+                .deserialize::<CreateVirtualRouterOutput, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(CreateVirtualNodeError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(CreateVirtualRouterError::from_response(response))
         }
     }
 
-    /// <p>Creates a virtual router within a service mesh.</p>
-    ///
-    /// <pre><code>     &lt;p&gt;Any inbound traffic that your virtual router expects should be specified as a
-    /// &lt;code&gt;listener&lt;/code&gt;. &lt;/p&gt;
-    /// &lt;p&gt;Virtual routers handle traffic for one or more virtual services within your mesh. After
-    /// you create your virtual router, create and associate routes for your virtual router that
-    /// direct incoming requests to different virtual nodes.&lt;/p&gt;
-    /// </code></pre>
-    async fn create_virtual_router(
+    /// Presigns the request `create_virtual_router` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_virtual_router(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: CreateVirtualRouterInput,
-    ) -> Result<CreateVirtualRouterOutput, RusotoError<CreateVirtualRouterError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_virtual_router_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_virtual_router_request(
+        &self,
+        input: CreateVirtualRouterInput,
+    ) -> SignedRequest {
         let request_uri = format!(
             "/v20190125/meshes/{mesh_name}/virtualRouters",
             mesh_name = input.mesh_name
         );
 
         let mut request = SignedRequest::new("PUT", "appmesh", &self.region, &request_uri);
+        request.set_operation("CreateVirtualRouter");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `create_virtual_service`, also returning the metadata of the response.
+    pub async fn create_virtual_service_with_metadata(
+        &self,
+        input: CreateVirtualServiceInput,
+    ) -> Result<
+        (CreateVirtualServiceOutput, ResponseMetadata),
+        RusotoError<CreateVirtualServiceError>,
+    > {
+        let request = self.build_create_virtual_service_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<CreateVirtualRouterOutput, _>()?;
+                .deserialize::<CreateVirtualServiceOutput, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(CreateVirtualRouterError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(CreateVirtualServiceError::from_response(response))
         }
     }
 
-    /// <p>Creates a virtual service within a service mesh.</p>
-    ///
-    /// <pre><code>     &lt;p&gt;A virtual service is an abstraction of a real service that is provided by a virtual node
-    /// directly or indirectly by means of a virtual router. Dependent services call your virtual
-    /// service by its &lt;code&gt;virtualServiceName&lt;/code&gt;, and those requests are routed to the
-    /// virtual node or virtual router that is specified as the provider for the virtual
-    /// service.&lt;/p&gt;
-    /// </code></pre>
-    async fn create_virtual_service(
+    /// Presigns the request `create_virtual_service` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_virtual_service(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: CreateVirtualServiceInput,
-    ) -> Result<CreateVirtualServiceOutput, RusotoError<CreateVirtualServiceError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_virtual_service_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_virtual_service_request(
+        &self,
+        input: CreateVirtualServiceInput,
+    ) -> SignedRequest {
         let request_uri = format!(
             "/v20190125/meshes/{mesh_name}/virtualServices",
             mesh_name = input.mesh_name
         );
 
         let mut request = SignedRequest::new("PUT", "appmesh", &self.region, &request_uri);
+        request.set_operation("CreateVirtualService");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `delete_mesh`, also returning the metadata of the response.
+    pub async fn delete_mesh_with_metadata(
+        &self,
+        input: DeleteMeshInput,
+    ) -> Result<(DeleteMeshOutput, ResponseMetadata), RusotoError<DeleteMeshError>> {
+        let request = self.build_delete_mesh_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<CreateVirtualServiceOutput, _>()?;
+                .deserialize::<DeleteMeshOutput, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(CreateVirtualServiceError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(DeleteMeshError::from_response(response))
         }
     }
 
-    /// <p>Deletes an existing service mesh.</p>
-    ///
-    /// <pre><code>     &lt;p&gt;You must delete all resources (virtual services, routes, virtual routers, and virtual
-    /// nodes) in the service mesh before you can delete the mesh itself.&lt;/p&gt;
-    /// </code></pre>
-    async fn delete_mesh(
+    /// Presigns the request `delete_mesh` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_mesh(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteMeshInput,
-    ) -> Result<DeleteMeshOutput, RusotoError<DeleteMeshError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_mesh_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_mesh_request(&self, input: DeleteMeshInput) -> SignedRequest {
         let request_uri = format!("/v20190125/meshes/{mesh_name}", mesh_name = input.mesh_name);
 
         let mut request = SignedRequest::new("DELETE", "appmesh", &self.region, &request_uri);
+        request.set_operation("DeleteMesh");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
+        request
+    }
+
+    /// Calls `delete_route`, also returning the metadata of the response.
+    pub async fn delete_route_with_metadata(
+        &self,
+        input: DeleteRouteInput,
+    ) -> Result<(DeleteRouteOutput, ResponseMetadata), RusotoError<DeleteRouteError>> {
+        let request = self.build_delete_route_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<DeleteMeshOutput, _>()?;
+                .deserialize::<DeleteRouteOutput, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteMeshError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(DeleteRouteError::from_response(response))
         }
     }
 
-    /// <p>Deletes an existing route.</p>
-    async fn delete_route(
+    /// Presigns the request `delete_route` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_route(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteRouteInput,
-    ) -> Result<DeleteRouteOutput, RusotoError<DeleteRouteError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_route_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_route_request(&self, input: DeleteRouteInput) -> SignedRequest {
         let request_uri = format!(
             "/v20190125/meshes/{mesh_name}/virtualRouter/{virtual_router_name}/routes/{route_name}",
             mesh_name = input.mesh_name,
@@ -4210,34 +4683,55 @@ impl AppMesh for AppMeshClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "appmesh", &self.region, &request_uri);
+        request.set_operation("DeleteRoute");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
+        request
+    }
+
+    /// Calls `delete_virtual_node`, also returning the metadata of the response.
+    pub async fn delete_virtual_node_with_metadata(
+        &self,
+        input: DeleteVirtualNodeInput,
+    ) -> Result<(DeleteVirtualNodeOutput, ResponseMetadata), RusotoError<DeleteVirtualNodeError>>
+    {
+        let request = self.build_delete_virtual_node_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<DeleteRouteOutput, _>()?;
+                .deserialize::<DeleteVirtualNodeOutput, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteRouteError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(DeleteVirtualNodeError::from_response(response))
         }
     }
 
-    /// <p>Deletes an existing virtual node.</p>
-    ///
-    /// <pre><code>     &lt;p&gt;You must delete any virtual services that list a virtual node as a service provider
-    /// before you can delete the virtual node itself.&lt;/p&gt;
-    /// </code></pre>
-    async fn delete_virtual_node(
+    /// Presigns the request `delete_virtual_node` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_virtual_node(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteVirtualNodeInput,
-    ) -> Result<DeleteVirtualNodeOutput, RusotoError<DeleteVirtualNodeError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_virtual_node_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_virtual_node_request(&self, input: DeleteVirtualNodeInput) -> SignedRequest {
         let request_uri = format!(
             "/v20190125/meshes/{mesh_name}/virtualNodes/{virtual_node_name}",
             mesh_name = input.mesh_name,
@@ -4245,34 +4739,58 @@ impl AppMesh for AppMeshClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "appmesh", &self.region, &request_uri);
+        request.set_operation("DeleteVirtualNode");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
+        request
+    }
+
+    /// Calls `delete_virtual_router`, also returning the metadata of the response.
+    pub async fn delete_virtual_router_with_metadata(
+        &self,
+        input: DeleteVirtualRouterInput,
+    ) -> Result<(DeleteVirtualRouterOutput, ResponseMetadata), RusotoError<DeleteVirtualRouterError>>
+    {
+        let request = self.build_delete_virtual_router_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<DeleteVirtualNodeOutput, _>()?;
+                .deserialize::<DeleteVirtualRouterOutput, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteVirtualNodeError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(DeleteVirtualRouterError::from_response(response))
         }
     }
 
-    /// <p>Deletes an existing virtual router.</p>
-    ///
-    /// <pre><code>     &lt;p&gt;You must delete any routes associated with the virtual router before you can delete the
-    /// router itself.&lt;/p&gt;
-    /// </code></pre>
-    async fn delete_virtual_router(
+    /// Presigns the request `delete_virtual_router` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_virtual_router(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteVirtualRouterInput,
-    ) -> Result<DeleteVirtualRouterOutput, RusotoError<DeleteVirtualRouterError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_virtual_router_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_virtual_router_request(
+        &self,
+        input: DeleteVirtualRouterInput,
+    ) -> SignedRequest {
         let request_uri = format!(
             "/v20190125/meshes/{mesh_name}/virtualRouters/{virtual_router_name}",
             mesh_name = input.mesh_name,
@@ -4280,30 +4798,60 @@ impl AppMesh for AppMeshClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "appmesh", &self.region, &request_uri);
+        request.set_operation("DeleteVirtualRouter");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
+        request
+    }
+
+    /// Calls `delete_virtual_service`, also returning the metadata of the response.
+    pub async fn delete_virtual_service_with_metadata(
+        &self,
+        input: DeleteVirtualServiceInput,
+    ) -> Result<
+        (DeleteVirtualServiceOutput, ResponseMetadata),
+        RusotoError<DeleteVirtualServiceError>,
+    > {
+        let request = self.build_delete_virtual_service_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<DeleteVirtualRouterOutput, _>()?;
+                .deserialize::<DeleteVirtualServiceOutput, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteVirtualRouterError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(DeleteVirtualServiceError::from_response(response))
         }
     }
 
-    /// <p>Deletes an existing virtual service.</p>
-    async fn delete_virtual_service(
+    /// Presigns the request `delete_virtual_service` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_virtual_service(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteVirtualServiceInput,
-    ) -> Result<DeleteVirtualServiceOutput, RusotoError<DeleteVirtualServiceError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_virtual_service_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_virtual_service_request(
+        &self,
+        input: DeleteVirtualServiceInput,
+    ) -> SignedRequest {
         let request_uri = format!(
             "/v20190125/meshes/{mesh_name}/virtualServices/{virtual_service_name}",
             mesh_name = input.mesh_name,
@@ -4311,57 +4859,105 @@ impl AppMesh for AppMeshClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "appmesh", &self.region, &request_uri);
+        request.set_operation("DeleteVirtualService");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
+        request
+    }
+
+    /// Calls `describe_mesh`, also returning the metadata of the response.
+    pub async fn describe_mesh_with_metadata(
+        &self,
+        input: DescribeMeshInput,
+    ) -> Result<(DescribeMeshOutput, ResponseMetadata), RusotoError<DescribeMeshError>> {
+        let request = self.build_describe_mesh_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<DeleteVirtualServiceOutput, _>()?;
+                .deserialize::<DescribeMeshOutput, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteVirtualServiceError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(DescribeMeshError::from_response(response))
         }
     }
 
-    /// <p>Describes an existing service mesh.</p>
-    async fn describe_mesh(
+    /// Presigns the request `describe_mesh` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_mesh(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeMeshInput,
-    ) -> Result<DescribeMeshOutput, RusotoError<DescribeMeshError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_mesh_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_mesh_request(&self, input: DescribeMeshInput) -> SignedRequest {
         let request_uri = format!("/v20190125/meshes/{mesh_name}", mesh_name = input.mesh_name);
 
         let mut request = SignedRequest::new("GET", "appmesh", &self.region, &request_uri);
+        request.set_operation("DescribeMesh");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
+        request
+    }
+
+    /// Calls `describe_route`, also returning the metadata of the response.
+    pub async fn describe_route_with_metadata(
+        &self,
+        input: DescribeRouteInput,
+    ) -> Result<(DescribeRouteOutput, ResponseMetadata), RusotoError<DescribeRouteError>> {
+        let request = self.build_describe_route_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<DescribeMeshOutput, _>()?;
+                .deserialize::<DescribeRouteOutput, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeMeshError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(DescribeRouteError::from_response(response))
         }
     }
 
-    /// <p>Describes an existing route.</p>
-    async fn describe_route(
+    /// Presigns the request `describe_route` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_route(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeRouteInput,
-    ) -> Result<DescribeRouteOutput, RusotoError<DescribeRouteError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_route_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_route_request(&self, input: DescribeRouteInput) -> SignedRequest {
         let request_uri = format!(
             "/v20190125/meshes/{mesh_name}/virtualRouter/{virtual_router_name}/routes/{route_name}",
             mesh_name = input.mesh_name,
@@ -4370,30 +4966,58 @@ impl AppMesh for AppMeshClient {
         );
 
         let mut request = SignedRequest::new("GET", "appmesh", &self.region, &request_uri);
+        request.set_operation("DescribeRoute");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
+        request
+    }
+
+    /// Calls `describe_virtual_node`, also returning the metadata of the response.
+    pub async fn describe_virtual_node_with_metadata(
+        &self,
+        input: DescribeVirtualNodeInput,
+    ) -> Result<(DescribeVirtualNodeOutput, ResponseMetadata), RusotoError<DescribeVirtualNodeError>>
+    {
+        let request = self.build_describe_virtual_node_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<DescribeRouteOutput, _>()?;
+                .deserialize::<DescribeVirtualNodeOutput, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeRouteError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(DescribeVirtualNodeError::from_response(response))
         }
     }
 
-    /// <p>Describes an existing virtual node.</p>
-    async fn describe_virtual_node(
+    /// Presigns the request `describe_virtual_node` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_virtual_node(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeVirtualNodeInput,
-    ) -> Result<DescribeVirtualNodeOutput, RusotoError<DescribeVirtualNodeError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_virtual_node_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_virtual_node_request(
+        &self,
+        input: DescribeVirtualNodeInput,
+    ) -> SignedRequest {
         let request_uri = format!(
             "/v20190125/meshes/{mesh_name}/virtualNodes/{virtual_node_name}",
             mesh_name = input.mesh_name,
@@ -4401,30 +5025,60 @@ impl AppMesh for AppMeshClient {
         );
 
         let mut request = SignedRequest::new("GET", "appmesh", &self.region, &request_uri);
+        request.set_operation("DescribeVirtualNode");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
+        request
+    }
+
+    /// Calls `describe_virtual_router`, also returning the metadata of the response.
+    pub async fn describe_virtual_router_with_metadata(
+        &self,
+        input: DescribeVirtualRouterInput,
+    ) -> Result<
+        (DescribeVirtualRouterOutput, ResponseMetadata),
+        RusotoError<DescribeVirtualRouterError>,
+    > {
+        let request = self.build_describe_virtual_router_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<DescribeVirtualNodeOutput, _>()?;
+                .deserialize::<DescribeVirtualRouterOutput, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeVirtualNodeError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(DescribeVirtualRouterError::from_response(response))
         }
     }
 
-    /// <p>Describes an existing virtual router.</p>
-    async fn describe_virtual_router(
+    /// Presigns the request `describe_virtual_router` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_virtual_router(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeVirtualRouterInput,
-    ) -> Result<DescribeVirtualRouterOutput, RusotoError<DescribeVirtualRouterError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_virtual_router_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_virtual_router_request(
+        &self,
+        input: DescribeVirtualRouterInput,
+    ) -> SignedRequest {
         let request_uri = format!(
             "/v20190125/meshes/{mesh_name}/virtualRouters/{virtual_router_name}",
             mesh_name = input.mesh_name,
@@ -4432,30 +5086,60 @@ impl AppMesh for AppMeshClient {
         );
 
         let mut request = SignedRequest::new("GET", "appmesh", &self.region, &request_uri);
+        request.set_operation("DescribeVirtualRouter");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
+        request
+    }
+
+    /// Calls `describe_virtual_service`, also returning the metadata of the response.
+    pub async fn describe_virtual_service_with_metadata(
+        &self,
+        input: DescribeVirtualServiceInput,
+    ) -> Result<
+        (DescribeVirtualServiceOutput, ResponseMetadata),
+        RusotoError<DescribeVirtualServiceError>,
+    > {
+        let request = self.build_describe_virtual_service_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<DescribeVirtualRouterOutput, _>()?;
+                .deserialize::<DescribeVirtualServiceOutput, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeVirtualRouterError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(DescribeVirtualServiceError::from_response(response))
         }
     }
 
-    /// <p>Describes an existing virtual service.</p>
-    async fn describe_virtual_service(
+    /// Presigns the request `describe_virtual_service` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_virtual_service(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeVirtualServiceInput,
-    ) -> Result<DescribeVirtualServiceOutput, RusotoError<DescribeVirtualServiceError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_virtual_service_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_virtual_service_request(
+        &self,
+        input: DescribeVirtualServiceInput,
+    ) -> SignedRequest {
         let request_uri = format!(
             "/v20190125/meshes/{mesh_name}/virtualServices/{virtual_service_name}",
             mesh_name = input.mesh_name,
@@ -4463,33 +5147,58 @@ impl AppMesh for AppMeshClient {
         );
 
         let mut request = SignedRequest::new("GET", "appmesh", &self.region, &request_uri);
+        request.set_operation("DescribeVirtualService");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
+        request
+    }
+
+    /// Calls `list_meshes`, also returning the metadata of the response.
+    pub async fn list_meshes_with_metadata(
+        &self,
+        input: ListMeshesInput,
+    ) -> Result<(ListMeshesOutput, ResponseMetadata), RusotoError<ListMeshesError>> {
+        let request = self.build_list_meshes_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<DescribeVirtualServiceOutput, _>()?;
+                .deserialize::<ListMeshesOutput, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeVirtualServiceError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(ListMeshesError::from_response(response))
         }
     }
 
-    /// <p>Returns a list of existing service meshes.</p>
-    async fn list_meshes(
+    /// Presigns the request `list_meshes` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_meshes(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListMeshesInput,
-    ) -> Result<ListMeshesOutput, RusotoError<ListMeshesError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_meshes_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_meshes_request(&self, input: ListMeshesInput) -> SignedRequest {
         let request_uri = "/v20190125/meshes";
 
         let mut request = SignedRequest::new("GET", "appmesh", &self.region, &request_uri);
+        request.set_operation("ListMeshes");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -4501,28 +5210,51 @@ impl AppMesh for AppMeshClient {
         }
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `list_routes`, also returning the metadata of the response.
+    pub async fn list_routes_with_metadata(
+        &self,
+        input: ListRoutesInput,
+    ) -> Result<(ListRoutesOutput, ResponseMetadata), RusotoError<ListRoutesError>> {
+        let request = self.build_list_routes_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListMeshesOutput, _>()?;
+                .deserialize::<ListRoutesOutput, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(ListMeshesError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(ListRoutesError::from_response(response))
         }
     }
 
-    /// <p>Returns a list of existing routes in a service mesh.</p>
-    async fn list_routes(
+    /// Presigns the request `list_routes` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_routes(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListRoutesInput,
-    ) -> Result<ListRoutesOutput, RusotoError<ListRoutesError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_routes_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_routes_request(&self, input: ListRoutesInput) -> SignedRequest {
         let request_uri = format!(
             "/v20190125/meshes/{mesh_name}/virtualRouter/{virtual_router_name}/routes",
             mesh_name = input.mesh_name,
@@ -4530,6 +5262,7 @@ impl AppMesh for AppMeshClient {
         );
 
         let mut request = SignedRequest::new("GET", "appmesh", &self.region, &request_uri);
+        request.set_operation("ListRoutes");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -4541,31 +5274,59 @@ impl AppMesh for AppMeshClient {
         }
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `list_tags_for_resource`, also returning the metadata of the response.
+    pub async fn list_tags_for_resource_with_metadata(
+        &self,
+        input: ListTagsForResourceInput,
+    ) -> Result<(ListTagsForResourceOutput, ResponseMetadata), RusotoError<ListTagsForResourceError>>
+    {
+        let request = self.build_list_tags_for_resource_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListRoutesOutput, _>()?;
+                .deserialize::<ListTagsForResourceOutput, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(ListRoutesError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(ListTagsForResourceError::from_response(response))
         }
     }
 
-    /// <p>List the tags for an App Mesh resource.</p>
-    async fn list_tags_for_resource(
+    /// Presigns the request `list_tags_for_resource` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_tags_for_resource(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListTagsForResourceInput,
-    ) -> Result<ListTagsForResourceOutput, RusotoError<ListTagsForResourceError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_tags_for_resource_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_tags_for_resource_request(
+        &self,
+        input: ListTagsForResourceInput,
+    ) -> SignedRequest {
         let request_uri = "/v20190125/tags";
 
         let mut request = SignedRequest::new("GET", "appmesh", &self.region, &request_uri);
+        request.set_operation("ListTagsForResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -4578,34 +5339,59 @@ impl AppMesh for AppMeshClient {
         params.put("resourceArn", &input.resource_arn);
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `list_virtual_nodes`, also returning the metadata of the response.
+    pub async fn list_virtual_nodes_with_metadata(
+        &self,
+        input: ListVirtualNodesInput,
+    ) -> Result<(ListVirtualNodesOutput, ResponseMetadata), RusotoError<ListVirtualNodesError>>
+    {
+        let request = self.build_list_virtual_nodes_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListTagsForResourceOutput, _>()?;
+                .deserialize::<ListVirtualNodesOutput, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(ListTagsForResourceError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(ListVirtualNodesError::from_response(response))
         }
     }
 
-    /// <p>Returns a list of existing virtual nodes.</p>
-    async fn list_virtual_nodes(
+    /// Presigns the request `list_virtual_nodes` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_virtual_nodes(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListVirtualNodesInput,
-    ) -> Result<ListVirtualNodesOutput, RusotoError<ListVirtualNodesError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_virtual_nodes_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_virtual_nodes_request(&self, input: ListVirtualNodesInput) -> SignedRequest {
         let request_uri = format!(
             "/v20190125/meshes/{mesh_name}/virtualNodes",
             mesh_name = input.mesh_name
         );
 
         let mut request = SignedRequest::new("GET", "appmesh", &self.region, &request_uri);
+        request.set_operation("ListVirtualNodes");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -4617,34 +5403,59 @@ impl AppMesh for AppMeshClient {
         }
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `list_virtual_routers`, also returning the metadata of the response.
+    pub async fn list_virtual_routers_with_metadata(
+        &self,
+        input: ListVirtualRoutersInput,
+    ) -> Result<(ListVirtualRoutersOutput, ResponseMetadata), RusotoError<ListVirtualRoutersError>>
+    {
+        let request = self.build_list_virtual_routers_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListVirtualNodesOutput, _>()?;
+                .deserialize::<ListVirtualRoutersOutput, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(ListVirtualNodesError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(ListVirtualRoutersError::from_response(response))
         }
     }
 
-    /// <p>Returns a list of existing virtual routers in a service mesh.</p>
-    async fn list_virtual_routers(
+    /// Presigns the request `list_virtual_routers` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_virtual_routers(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListVirtualRoutersInput,
-    ) -> Result<ListVirtualRoutersOutput, RusotoError<ListVirtualRoutersError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_virtual_routers_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_virtual_routers_request(&self, input: ListVirtualRoutersInput) -> SignedRequest {
         let request_uri = format!(
             "/v20190125/meshes/{mesh_name}/virtualRouters",
             mesh_name = input.mesh_name
         );
 
         let mut request = SignedRequest::new("GET", "appmesh", &self.region, &request_uri);
+        request.set_operation("ListVirtualRouters");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -4656,34 +5467,62 @@ impl AppMesh for AppMeshClient {
         }
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `list_virtual_services`, also returning the metadata of the response.
+    pub async fn list_virtual_services_with_metadata(
+        &self,
+        input: ListVirtualServicesInput,
+    ) -> Result<(ListVirtualServicesOutput, ResponseMetadata), RusotoError<ListVirtualServicesError>>
+    {
+        let request = self.build_list_virtual_services_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListVirtualRoutersOutput, _>()?;
+                .deserialize::<ListVirtualServicesOutput, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(ListVirtualRoutersError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(ListVirtualServicesError::from_response(response))
         }
     }
 
-    /// <p>Returns a list of existing virtual services in a service mesh.</p>
-    async fn list_virtual_services(
+    /// Presigns the request `list_virtual_services` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_virtual_services(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListVirtualServicesInput,
-    ) -> Result<ListVirtualServicesOutput, RusotoError<ListVirtualServicesError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_virtual_services_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_virtual_services_request(
+        &self,
+        input: ListVirtualServicesInput,
+    ) -> SignedRequest {
         let request_uri = format!(
             "/v20190125/meshes/{mesh_name}/virtualServices",
             mesh_name = input.mesh_name
         );
 
         let mut request = SignedRequest::new("GET", "appmesh", &self.region, &request_uri);
+        request.set_operation("ListVirtualServices");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -4695,34 +5534,55 @@ impl AppMesh for AppMeshClient {
         }
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `tag_resource`, also returning the metadata of the response.
+    pub async fn tag_resource_with_metadata(
+        &self,
+        input: TagResourceInput,
+    ) -> Result<(TagResourceOutput, ResponseMetadata), RusotoError<TagResourceError>> {
+        let request = self.build_tag_resource_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListVirtualServicesOutput, _>()?;
+                .deserialize::<TagResourceOutput, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(ListVirtualServicesError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(TagResourceError::from_response(response))
         }
     }
 
-    /// <p>Associates the specified tags to a resource with the specified <code>resourceArn</code>.
-    /// If existing tags on a resource aren't specified in the request parameters, they aren't
-    /// changed. When a resource is deleted, the tags associated with that resource are also
-    /// deleted.</p>
-    async fn tag_resource(
+    /// Presigns the request `tag_resource` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_tag_resource(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: TagResourceInput,
-    ) -> Result<TagResourceOutput, RusotoError<TagResourceError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_tag_resource_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_tag_resource_request(&self, input: TagResourceInput) -> SignedRequest {
         let request_uri = "/v20190125/tag";
 
         let mut request = SignedRequest::new("PUT", "appmesh", &self.region, &request_uri);
+        request.set_operation("TagResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -4732,31 +5592,55 @@ impl AppMesh for AppMeshClient {
         params.put("resourceArn", &input.resource_arn);
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `untag_resource`, also returning the metadata of the response.
+    pub async fn untag_resource_with_metadata(
+        &self,
+        input: UntagResourceInput,
+    ) -> Result<(UntagResourceOutput, ResponseMetadata), RusotoError<UntagResourceError>> {
+        let request = self.build_untag_resource_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<TagResourceOutput, _>()?;
+                .deserialize::<UntagResourceOutput, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(TagResourceError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(UntagResourceError::from_response(response))
         }
     }
 
-    /// <p>Deletes specified tags from a resource.</p>
-    async fn untag_resource(
+    /// Presigns the request `untag_resource` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_untag_resource(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UntagResourceInput,
-    ) -> Result<UntagResourceOutput, RusotoError<UntagResourceError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_untag_resource_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_untag_resource_request(&self, input: UntagResourceInput) -> SignedRequest {
         let request_uri = "/v20190125/untag";
 
         let mut request = SignedRequest::new("PUT", "appmesh", &self.region, &request_uri);
+        request.set_operation("UntagResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -4766,58 +5650,105 @@ impl AppMesh for AppMeshClient {
         params.put("resourceArn", &input.resource_arn);
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `update_mesh`, also returning the metadata of the response.
+    pub async fn update_mesh_with_metadata(
+        &self,
+        input: UpdateMeshInput,
+    ) -> Result<(UpdateMeshOutput, ResponseMetadata), RusotoError<UpdateMeshError>> {
+        let request = self.build_update_mesh_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<UntagResourceOutput, _>()?;
+                .deserialize::<UpdateMeshOutput, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(UntagResourceError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(UpdateMeshError::from_response(response))
         }
     }
 
-    /// <p>Updates an existing service mesh.</p>
-    async fn update_mesh(
+    /// Presigns the request `update_mesh` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_mesh(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UpdateMeshInput,
-    ) -> Result<UpdateMeshOutput, RusotoError<UpdateMeshError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_mesh_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_mesh_request(&self, input: UpdateMeshInput) -> SignedRequest {
         let request_uri = format!("/v20190125/meshes/{mesh_name}", mesh_name = input.mesh_name);
 
         let mut request = SignedRequest::new("PUT", "appmesh", &self.region, &request_uri);
+        request.set_operation("UpdateMesh");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `update_route`, also returning the metadata of the response.
+    pub async fn update_route_with_metadata(
+        &self,
+        input: UpdateRouteInput,
+    ) -> Result<(UpdateRouteOutput, ResponseMetadata), RusotoError<UpdateRouteError>> {
+        let request = self.build_update_route_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<UpdateMeshOutput, _>()?;
+                .deserialize::<UpdateRouteOutput, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(UpdateMeshError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(UpdateRouteError::from_response(response))
         }
     }
 
-    /// <p>Updates an existing route for a specified service mesh and virtual router.</p>
-    async fn update_route(
+    /// Presigns the request `update_route` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_route(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UpdateRouteInput,
-    ) -> Result<UpdateRouteOutput, RusotoError<UpdateRouteError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_route_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_route_request(&self, input: UpdateRouteInput) -> SignedRequest {
         let request_uri = format!(
             "/v20190125/meshes/{mesh_name}/virtualRouter/{virtual_router_name}/routes/{route_name}",
             mesh_name = input.mesh_name,
@@ -4826,33 +5757,58 @@ impl AppMesh for AppMeshClient {
         );
 
         let mut request = SignedRequest::new("PUT", "appmesh", &self.region, &request_uri);
+        request.set_operation("UpdateRoute");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `update_virtual_node`, also returning the metadata of the response.
+    pub async fn update_virtual_node_with_metadata(
+        &self,
+        input: UpdateVirtualNodeInput,
+    ) -> Result<(UpdateVirtualNodeOutput, ResponseMetadata), RusotoError<UpdateVirtualNodeError>>
+    {
+        let request = self.build_update_virtual_node_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<UpdateRouteOutput, _>()?;
+                .deserialize::<UpdateVirtualNodeOutput, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(UpdateRouteError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(UpdateVirtualNodeError::from_response(response))
         }
     }
 
-    /// <p>Updates an existing virtual node in a specified service mesh.</p>
-    async fn update_virtual_node(
+    /// Presigns the request `update_virtual_node` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_virtual_node(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UpdateVirtualNodeInput,
-    ) -> Result<UpdateVirtualNodeOutput, RusotoError<UpdateVirtualNodeError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_virtual_node_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_virtual_node_request(&self, input: UpdateVirtualNodeInput) -> SignedRequest {
         let request_uri = format!(
             "/v20190125/meshes/{mesh_name}/virtualNodes/{virtual_node_name}",
             mesh_name = input.mesh_name,
@@ -4860,33 +5816,61 @@ impl AppMesh for AppMeshClient {
         );
 
         let mut request = SignedRequest::new("PUT", "appmesh", &self.region, &request_uri);
+        request.set_operation("UpdateVirtualNode");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `update_virtual_router`, also returning the metadata of the response.
+    pub async fn update_virtual_router_with_metadata(
+        &self,
+        input: UpdateVirtualRouterInput,
+    ) -> Result<(UpdateVirtualRouterOutput, ResponseMetadata), RusotoError<UpdateVirtualRouterError>>
+    {
+        let request = self.build_update_virtual_router_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<UpdateVirtualNodeOutput, _>()?;
+                .deserialize::<UpdateVirtualRouterOutput, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(UpdateVirtualNodeError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(UpdateVirtualRouterError::from_response(response))
         }
     }
 
-    /// <p>Updates an existing virtual router in a specified service mesh.</p>
-    async fn update_virtual_router(
+    /// Presigns the request `update_virtual_router` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_virtual_router(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UpdateVirtualRouterInput,
-    ) -> Result<UpdateVirtualRouterOutput, RusotoError<UpdateVirtualRouterError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_virtual_router_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_virtual_router_request(
+        &self,
+        input: UpdateVirtualRouterInput,
+    ) -> SignedRequest {
         let request_uri = format!(
             "/v20190125/meshes/{mesh_name}/virtualRouters/{virtual_router_name}",
             mesh_name = input.mesh_name,
@@ -4894,33 +5878,63 @@ impl AppMesh for AppMeshClient {
         );
 
         let mut request = SignedRequest::new("PUT", "appmesh", &self.region, &request_uri);
+        request.set_operation("UpdateVirtualRouter");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `update_virtual_service`, also returning the metadata of the response.
+    pub async fn update_virtual_service_with_metadata(
+        &self,
+        input: UpdateVirtualServiceInput,
+    ) -> Result<
+        (UpdateVirtualServiceOutput, ResponseMetadata),
+        RusotoError<UpdateVirtualServiceError>,
+    > {
+        let request = self.build_update_virtual_service_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<UpdateVirtualRouterOutput, _>()?;
+                .deserialize::<UpdateVirtualServiceOutput, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(UpdateVirtualRouterError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(UpdateVirtualServiceError::from_response(response))
         }
     }
 
-    /// <p>Updates an existing virtual service in a specified service mesh.</p>
-    async fn update_virtual_service(
+    /// Presigns the request `update_virtual_service` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_virtual_service(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UpdateVirtualServiceInput,
-    ) -> Result<UpdateVirtualServiceOutput, RusotoError<UpdateVirtualServiceError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_virtual_service_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_virtual_service_request(
+        &self,
+        input: UpdateVirtualServiceInput,
+    ) -> SignedRequest {
         let request_uri = format!(
             "/v20190125/meshes/{mesh_name}/virtualServices/{virtual_service_name}",
             mesh_name = input.mesh_name,
@@ -4928,25 +5942,571 @@ impl AppMesh for AppMeshClient {
         );
 
         let mut request = SignedRequest::new("PUT", "appmesh", &self.region, &request_uri);
+        request.set_operation("UpdateVirtualService");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
-        let mut response = self
-            .client
-            .sign_and_dispatch(request)
-            .await
-            .map_err(RusotoError::from)?;
-        if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<UpdateVirtualServiceOutput, _>()?;
+        request
+    }
 
-            Ok(result)
-        } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(UpdateVirtualServiceError::from_response(response))
-        }
+    /// The pages of `ListMeshes` results for `input`, requesting each next page with the `nextToken` of the last one as its `nextToken`.
+    /// The page size is the `limit` of `input`.
+    pub fn list_meshes_pages(
+        &self,
+        input: ListMeshesInput,
+    ) -> impl futures::Stream<Item = Result<ListMeshesOutput, RusotoError<ListMeshesError>>> + Send
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: AppMeshClient, input| async move { client.list_meshes(input).await },
+            |input: &ListMeshesInput, output: &ListMeshesOutput| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListMeshesInput {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `meshes` of every page of `ListMeshes` results for `input`, as `list_meshes_pages` requests them.
+    pub fn list_meshes_stream(
+        &self,
+        input: ListMeshesInput,
+    ) -> impl futures::Stream<Item = Result<MeshRef, RusotoError<ListMeshesError>>> + Send {
+        rusoto_core::pagination::items(self.list_meshes_pages(input), |page| page.meshes)
+    }
+
+    /// The pages of `ListRoutes` results for `input`, requesting each next page with the `nextToken` of the last one as its `nextToken`.
+    /// The page size is the `limit` of `input`.
+    pub fn list_routes_pages(
+        &self,
+        input: ListRoutesInput,
+    ) -> impl futures::Stream<Item = Result<ListRoutesOutput, RusotoError<ListRoutesError>>> + Send
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: AppMeshClient, input| async move { client.list_routes(input).await },
+            |input: &ListRoutesInput, output: &ListRoutesOutput| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListRoutesInput {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `routes` of every page of `ListRoutes` results for `input`, as `list_routes_pages` requests them.
+    pub fn list_routes_stream(
+        &self,
+        input: ListRoutesInput,
+    ) -> impl futures::Stream<Item = Result<RouteRef, RusotoError<ListRoutesError>>> + Send {
+        rusoto_core::pagination::items(self.list_routes_pages(input), |page| page.routes)
+    }
+
+    /// The pages of `ListTagsForResource` results for `input`, requesting each next page with the `nextToken` of the last one as its `nextToken`.
+    /// The page size is the `limit` of `input`.
+    pub fn list_tags_for_resource_pages(
+        &self,
+        input: ListTagsForResourceInput,
+    ) -> impl futures::Stream<
+        Item = Result<ListTagsForResourceOutput, RusotoError<ListTagsForResourceError>>,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: AppMeshClient, input| async move { client.list_tags_for_resource(input).await },
+            |input: &ListTagsForResourceInput, output: &ListTagsForResourceOutput| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListTagsForResourceInput {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `tags` of every page of `ListTagsForResource` results for `input`, as `list_tags_for_resource_pages` requests them.
+    pub fn list_tags_for_resource_stream(
+        &self,
+        input: ListTagsForResourceInput,
+    ) -> impl futures::Stream<Item = Result<TagRef, RusotoError<ListTagsForResourceError>>> + Send
+    {
+        rusoto_core::pagination::items(self.list_tags_for_resource_pages(input), |page| page.tags)
+    }
+
+    /// The pages of `ListVirtualNodes` results for `input`, requesting each next page with the `nextToken` of the last one as its `nextToken`.
+    /// The page size is the `limit` of `input`.
+    pub fn list_virtual_nodes_pages(
+        &self,
+        input: ListVirtualNodesInput,
+    ) -> impl futures::Stream<
+        Item = Result<ListVirtualNodesOutput, RusotoError<ListVirtualNodesError>>,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: AppMeshClient, input| async move { client.list_virtual_nodes(input).await },
+            |input: &ListVirtualNodesInput, output: &ListVirtualNodesOutput| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListVirtualNodesInput {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `virtualNodes` of every page of `ListVirtualNodes` results for `input`, as `list_virtual_nodes_pages` requests them.
+    pub fn list_virtual_nodes_stream(
+        &self,
+        input: ListVirtualNodesInput,
+    ) -> impl futures::Stream<Item = Result<VirtualNodeRef, RusotoError<ListVirtualNodesError>>> + Send
+    {
+        rusoto_core::pagination::items(self.list_virtual_nodes_pages(input), |page| {
+            page.virtual_nodes
+        })
+    }
+
+    /// The pages of `ListVirtualRouters` results for `input`, requesting each next page with the `nextToken` of the last one as its `nextToken`.
+    /// The page size is the `limit` of `input`.
+    pub fn list_virtual_routers_pages(
+        &self,
+        input: ListVirtualRoutersInput,
+    ) -> impl futures::Stream<
+        Item = Result<ListVirtualRoutersOutput, RusotoError<ListVirtualRoutersError>>,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: AppMeshClient, input| async move { client.list_virtual_routers(input).await },
+            |input: &ListVirtualRoutersInput, output: &ListVirtualRoutersOutput| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListVirtualRoutersInput {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `virtualRouters` of every page of `ListVirtualRouters` results for `input`, as `list_virtual_routers_pages` requests them.
+    pub fn list_virtual_routers_stream(
+        &self,
+        input: ListVirtualRoutersInput,
+    ) -> impl futures::Stream<Item = Result<VirtualRouterRef, RusotoError<ListVirtualRoutersError>>> + Send
+    {
+        rusoto_core::pagination::items(self.list_virtual_routers_pages(input), |page| {
+            page.virtual_routers
+        })
+    }
+
+    /// The pages of `ListVirtualServices` results for `input`, requesting each next page with the `nextToken` of the last one as its `nextToken`.
+    /// The page size is the `limit` of `input`.
+    pub fn list_virtual_services_pages(
+        &self,
+        input: ListVirtualServicesInput,
+    ) -> impl futures::Stream<
+        Item = Result<ListVirtualServicesOutput, RusotoError<ListVirtualServicesError>>,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: AppMeshClient, input| async move { client.list_virtual_services(input).await },
+            |input: &ListVirtualServicesInput, output: &ListVirtualServicesOutput| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListVirtualServicesInput {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `virtualServices` of every page of `ListVirtualServices` results for `input`, as `list_virtual_services_pages` requests them.
+    pub fn list_virtual_services_stream(
+        &self,
+        input: ListVirtualServicesInput,
+    ) -> impl futures::Stream<Item = Result<VirtualServiceRef, RusotoError<ListVirtualServicesError>>>
+           + Send {
+        rusoto_core::pagination::items(self.list_virtual_services_pages(input), |page| {
+            page.virtual_services
+        })
+    }
+}
+
+#[async_trait]
+impl AppMesh for AppMeshClient {
+    /// <p>Creates a service mesh. A service mesh is a logical boundary for network traffic between
+    /// the services that reside within it.</p>
+    ///
+    /// <pre><code>     &lt;p&gt;After you create your service mesh, you can create virtual services, virtual nodes,
+    /// virtual routers, and routes to distribute traffic between the applications in your
+    /// mesh.&lt;/p&gt;
+    /// </code></pre>
+    async fn create_mesh(
+        &self,
+        input: CreateMeshInput,
+    ) -> Result<CreateMeshOutput, RusotoError<CreateMeshError>> {
+        self.create_mesh_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Creates a route that is associated with a virtual router.</p>
+    ///
+    /// <pre><code>     &lt;p&gt;You can use the &lt;code&gt;prefix&lt;/code&gt; parameter in your route specification for path-based
+    /// routing of requests. For example, if your virtual service name is
+    /// &lt;code&gt;my-service.local&lt;/code&gt; and you want the route to match requests to
+    /// &lt;code&gt;my-service.local/metrics&lt;/code&gt;, your prefix should be
+    /// &lt;code&gt;/metrics&lt;/code&gt;.&lt;/p&gt;
+    /// &lt;p&gt;If your route matches a request, you can distribute traffic to one or more target
+    /// virtual nodes with relative weighting.&lt;/p&gt;
+    /// </code></pre>
+    async fn create_route(
+        &self,
+        input: CreateRouteInput,
+    ) -> Result<CreateRouteOutput, RusotoError<CreateRouteError>> {
+        self.create_route_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Creates a virtual node within a service mesh.</p>
+    ///
+    /// <pre><code>     &lt;p&gt;A virtual node acts as a logical pointer to a particular task group, such as an Amazon ECS
+    /// service or a Kubernetes deployment. When you create a virtual node, you can specify the
+    /// service discovery information for your task group.&lt;/p&gt;
+    /// &lt;p&gt;Any inbound traffic that your virtual node expects should be specified as a
+    /// &lt;code&gt;listener&lt;/code&gt;. Any outbound traffic that your virtual node expects to reach
+    /// should be specified as a &lt;code&gt;backend&lt;/code&gt;.&lt;/p&gt;
+    /// &lt;p&gt;The response metadata for your new virtual node contains the &lt;code&gt;arn&lt;/code&gt; that is
+    /// associated with the virtual node. Set this value (either the full ARN or the truncated
+    /// resource name: for example, &lt;code&gt;mesh/default/virtualNode/simpleapp&lt;/code&gt;) as the
+    /// &lt;code&gt;APPMESH_VIRTUAL_NODE_NAME&lt;/code&gt; environment variable for your task group&#39;s Envoy
+    /// proxy container in your task definition or pod spec. This is then mapped to the
+    /// &lt;code&gt;node.id&lt;/code&gt; and &lt;code&gt;node.cluster&lt;/code&gt; Envoy parameters.&lt;/p&gt;
+    /// &lt;note&gt;
+    /// &lt;p&gt;If you require your Envoy stats or tracing to use a different name, you can override
+    /// the &lt;code&gt;node.cluster&lt;/code&gt; value that is set by
+    /// &lt;code&gt;APPMESH_VIRTUAL_NODE_NAME&lt;/code&gt; with the
+    /// &lt;code&gt;APPMESH_VIRTUAL_NODE_CLUSTER&lt;/code&gt; environment variable.&lt;/p&gt;
+    /// &lt;/note&gt;
+    /// </code></pre>
+    async fn create_virtual_node(
+        &self,
+        input: CreateVirtualNodeInput,
+    ) -> Result<CreateVirtualNodeOutput, RusotoError<CreateVirtualNodeError>> {
+        self.create_virtual_node_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Creates a virtual router within a service mesh.</p>
+    ///
+    /// <pre><code>     &lt;p&gt;Any inbound traffic that your virtual router expects should be specified as a
+    /// &lt;code&gt;listener&lt;/code&gt;. &lt;/p&gt;
+    /// &lt;p&gt;Virtual routers handle traffic for one or more virtual services within your mesh. After
+    /// you create your virtual router, create and associate routes for your virtual router that
+    /// direct incoming requests to different virtual nodes.&lt;/p&gt;
+    /// </code></pre>
+    async fn create_virtual_router(
+        &self,
+        input: CreateVirtualRouterInput,
+    ) -> Result<CreateVirtualRouterOutput, RusotoError<CreateVirtualRouterError>> {
+        self.create_virtual_router_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Creates a virtual service within a service mesh.</p>
+    ///
+    /// <pre><code>     &lt;p&gt;A virtual service is an abstraction of a real service that is provided by a virtual node
+    /// directly or indirectly by means of a virtual router. Dependent services call your virtual
+    /// service by its &lt;code&gt;virtualServiceName&lt;/code&gt;, and those requests are routed to the
+    /// virtual node or virtual router that is specified as the provider for the virtual
+    /// service.&lt;/p&gt;
+    /// </code></pre>
+    async fn create_virtual_service(
+        &self,
+        input: CreateVirtualServiceInput,
+    ) -> Result<CreateVirtualServiceOutput, RusotoError<CreateVirtualServiceError>> {
+        self.create_virtual_service_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes an existing service mesh.</p>
+    ///
+    /// <pre><code>     &lt;p&gt;You must delete all resources (virtual services, routes, virtual routers, and virtual
+    /// nodes) in the service mesh before you can delete the mesh itself.&lt;/p&gt;
+    /// </code></pre>
+    async fn delete_mesh(
+        &self,
+        input: DeleteMeshInput,
+    ) -> Result<DeleteMeshOutput, RusotoError<DeleteMeshError>> {
+        self.delete_mesh_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes an existing route.</p>
+    async fn delete_route(
+        &self,
+        input: DeleteRouteInput,
+    ) -> Result<DeleteRouteOutput, RusotoError<DeleteRouteError>> {
+        self.delete_route_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes an existing virtual node.</p>
+    ///
+    /// <pre><code>     &lt;p&gt;You must delete any virtual services that list a virtual node as a service provider
+    /// before you can delete the virtual node itself.&lt;/p&gt;
+    /// </code></pre>
+    async fn delete_virtual_node(
+        &self,
+        input: DeleteVirtualNodeInput,
+    ) -> Result<DeleteVirtualNodeOutput, RusotoError<DeleteVirtualNodeError>> {
+        self.delete_virtual_node_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes an existing virtual router.</p>
+    ///
+    /// <pre><code>     &lt;p&gt;You must delete any routes associated with the virtual router before you can delete the
+    /// router itself.&lt;/p&gt;
+    /// </code></pre>
+    async fn delete_virtual_router(
+        &self,
+        input: DeleteVirtualRouterInput,
+    ) -> Result<DeleteVirtualRouterOutput, RusotoError<DeleteVirtualRouterError>> {
+        self.delete_virtual_router_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes an existing virtual service.</p>
+    async fn delete_virtual_service(
+        &self,
+        input: DeleteVirtualServiceInput,
+    ) -> Result<DeleteVirtualServiceOutput, RusotoError<DeleteVirtualServiceError>> {
+        self.delete_virtual_service_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Describes an existing service mesh.</p>
+    async fn describe_mesh(
+        &self,
+        input: DescribeMeshInput,
+    ) -> Result<DescribeMeshOutput, RusotoError<DescribeMeshError>> {
+        self.describe_mesh_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Describes an existing route.</p>
+    async fn describe_route(
+        &self,
+        input: DescribeRouteInput,
+    ) -> Result<DescribeRouteOutput, RusotoError<DescribeRouteError>> {
+        self.describe_route_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Describes an existing virtual node.</p>
+    async fn describe_virtual_node(
+        &self,
+        input: DescribeVirtualNodeInput,
+    ) -> Result<DescribeVirtualNodeOutput, RusotoError<DescribeVirtualNodeError>> {
+        self.describe_virtual_node_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Describes an existing virtual router.</p>
+    async fn describe_virtual_router(
+        &self,
+        input: DescribeVirtualRouterInput,
+    ) -> Result<DescribeVirtualRouterOutput, RusotoError<DescribeVirtualRouterError>> {
+        self.describe_virtual_router_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Describes an existing virtual service.</p>
+    async fn describe_virtual_service(
+        &self,
+        input: DescribeVirtualServiceInput,
+    ) -> Result<DescribeVirtualServiceOutput, RusotoError<DescribeVirtualServiceError>> {
+        self.describe_virtual_service_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns a list of existing service meshes.</p>
+    async fn list_meshes(
+        &self,
+        input: ListMeshesInput,
+    ) -> Result<ListMeshesOutput, RusotoError<ListMeshesError>> {
+        self.list_meshes_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns a list of existing routes in a service mesh.</p>
+    async fn list_routes(
+        &self,
+        input: ListRoutesInput,
+    ) -> Result<ListRoutesOutput, RusotoError<ListRoutesError>> {
+        self.list_routes_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>List the tags for an App Mesh resource.</p>
+    async fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceInput,
+    ) -> Result<ListTagsForResourceOutput, RusotoError<ListTagsForResourceError>> {
+        self.list_tags_for_resource_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns a list of existing virtual nodes.</p>
+    async fn list_virtual_nodes(
+        &self,
+        input: ListVirtualNodesInput,
+    ) -> Result<ListVirtualNodesOutput, RusotoError<ListVirtualNodesError>> {
+        self.list_virtual_nodes_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns a list of existing virtual routers in a service mesh.</p>
+    async fn list_virtual_routers(
+        &self,
+        input: ListVirtualRoutersInput,
+    ) -> Result<ListVirtualRoutersOutput, RusotoError<ListVirtualRoutersError>> {
+        self.list_virtual_routers_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns a list of existing virtual services in a service mesh.</p>
+    async fn list_virtual_services(
+        &self,
+        input: ListVirtualServicesInput,
+    ) -> Result<ListVirtualServicesOutput, RusotoError<ListVirtualServicesError>> {
+        self.list_virtual_services_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Associates the specified tags to a resource with the specified <code>resourceArn</code>.
+    /// If existing tags on a resource aren't specified in the request parameters, they aren't
+    /// changed. When a resource is deleted, the tags associated with that resource are also
+    /// deleted.</p>
+    async fn tag_resource(
+        &self,
+        input: TagResourceInput,
+    ) -> Result<TagResourceOutput, RusotoError<TagResourceError>> {
+        self.tag_resource_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes specified tags from a resource.</p>
+    async fn untag_resource(
+        &self,
+        input: UntagResourceInput,
+    ) -> Result<UntagResourceOutput, RusotoError<UntagResourceError>> {
+        self.untag_resource_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Updates an existing service mesh.</p>
+    async fn update_mesh(
+        &self,
+        input: UpdateMeshInput,
+    ) -> Result<UpdateMeshOutput, RusotoError<UpdateMeshError>> {
+        self.update_mesh_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Updates an existing route for a specified service mesh and virtual router.</p>
+    async fn update_route(
+        &self,
+        input: UpdateRouteInput,
+    ) -> Result<UpdateRouteOutput, RusotoError<UpdateRouteError>> {
+        self.update_route_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Updates an existing virtual node in a specified service mesh.</p>
+    async fn update_virtual_node(
+        &self,
+        input: UpdateVirtualNodeInput,
+    ) -> Result<UpdateVirtualNodeOutput, RusotoError<UpdateVirtualNodeError>> {
+        self.update_virtual_node_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Updates an existing virtual router in a specified service mesh.</p>
+    async fn update_virtual_router(
+        &self,
+        input: UpdateVirtualRouterInput,
+    ) -> Result<UpdateVirtualRouterOutput, RusotoError<UpdateVirtualRouterError>> {
+        self.update_virtual_router_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Updates an existing virtual service in a specified service mesh.</p>
+    async fn update_virtual_service(
+        &self,
+        input: UpdateVirtualServiceInput,
+    ) -> Result<UpdateVirtualServiceOutput, RusotoError<UpdateVirtualServiceError>> {
+        self.update_virtual_service_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
     }
 }
@@ -16,8 +16,8 @@ use std::fmt;
 use async_trait::async_trait;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
-use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest, ResponseMetadata};
+use rusoto_core::{Client, ErrorMetadata, RusotoError};
 
 use rusoto_core::proto;
 use rusoto_core::signature::SignedRequest;
@@ -1981,6 +1981,7 @@ pub struct VpcConfig {
 
 /// Errors returned by AssociateFleet
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum AssociateFleetError {
     /// <p>An API error occurred. Wait a few minutes and try again.</p>
     ConcurrentModification(String),
@@ -1994,6 +1995,8 @@ pub enum AssociateFleetError {
     OperationNotPermitted(String),
     /// <p>The specified resource was not found.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl AssociateFleetError {
@@ -2001,29 +2004,40 @@ impl AssociateFleetError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(AssociateFleetError::ConcurrentModification(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        AssociateFleetError::ConcurrentModification(err.msg),
+                        &res,
+                    )
                 }
                 "IncompatibleImageException" => {
-                    return RusotoError::Service(AssociateFleetError::IncompatibleImage(err.msg))
+                    return RusotoError::service(
+                        AssociateFleetError::IncompatibleImage(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidAccountStatusException" => {
-                    return RusotoError::Service(AssociateFleetError::InvalidAccountStatus(err.msg))
+                    return RusotoError::service(
+                        AssociateFleetError::InvalidAccountStatus(err.msg),
+                        &res,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(AssociateFleetError::LimitExceeded(err.msg))
+                    return RusotoError::service(AssociateFleetError::LimitExceeded(err.msg), &res)
                 }
                 "OperationNotPermittedException" => {
-                    return RusotoError::Service(AssociateFleetError::OperationNotPermitted(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        AssociateFleetError::OperationNotPermitted(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(AssociateFleetError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        AssociateFleetError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(AssociateFleetError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2039,15 +2053,19 @@ impl fmt::Display for AssociateFleetError {
             AssociateFleetError::LimitExceeded(ref cause) => write!(f, "{}", cause),
             AssociateFleetError::OperationNotPermitted(ref cause) => write!(f, "{}", cause),
             AssociateFleetError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            AssociateFleetError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for AssociateFleetError {}
 /// Errors returned by BatchAssociateUserStack
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum BatchAssociateUserStackError {
     /// <p>The attempted operation is not permitted.</p>
     OperationNotPermitted(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl BatchAssociateUserStackError {
@@ -2055,12 +2073,13 @@ impl BatchAssociateUserStackError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "OperationNotPermittedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         BatchAssociateUserStackError::OperationNotPermitted(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(BatchAssociateUserStackError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2073,13 +2092,18 @@ impl fmt::Display for BatchAssociateUserStackError {
             BatchAssociateUserStackError::OperationNotPermitted(ref cause) => {
                 write!(f, "{}", cause)
             }
+            BatchAssociateUserStackError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for BatchAssociateUserStackError {}
 /// Errors returned by BatchDisassociateUserStack
 #[derive(Debug, PartialEq)]
-pub enum BatchDisassociateUserStackError {}
+#[non_exhaustive]
+pub enum BatchDisassociateUserStackError {
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
+}
 
 impl BatchDisassociateUserStackError {
     pub fn from_response(
@@ -2088,7 +2112,9 @@ impl BatchDisassociateUserStackError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(BatchDisassociateUserStackError::Unhandled, &res)
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -2097,12 +2123,15 @@ impl BatchDisassociateUserStackError {
 impl fmt::Display for BatchDisassociateUserStackError {
     #[allow(unused_variables)]
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        match *self {}
+        match *self {
+            BatchDisassociateUserStackError::Unhandled(ref metadata) => write!(f, "{}", metadata),
+        }
     }
 }
 impl Error for BatchDisassociateUserStackError {}
 /// Errors returned by CopyImage
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CopyImageError {
     /// <p>The image does not support storage connectors.</p>
     IncompatibleImage(String),
@@ -2116,6 +2145,8 @@ pub enum CopyImageError {
     ResourceNotAvailable(String),
     /// <p>The specified resource was not found.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CopyImageError {
@@ -2123,25 +2154,34 @@ impl CopyImageError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "IncompatibleImageException" => {
-                    return RusotoError::Service(CopyImageError::IncompatibleImage(err.msg))
+                    return RusotoError::service(CopyImageError::IncompatibleImage(err.msg), &res)
                 }
                 "InvalidAccountStatusException" => {
-                    return RusotoError::Service(CopyImageError::InvalidAccountStatus(err.msg))
+                    return RusotoError::service(
+                        CopyImageError::InvalidAccountStatus(err.msg),
+                        &res,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CopyImageError::LimitExceeded(err.msg))
+                    return RusotoError::service(CopyImageError::LimitExceeded(err.msg), &res)
                 }
                 "ResourceAlreadyExistsException" => {
-                    return RusotoError::Service(CopyImageError::ResourceAlreadyExists(err.msg))
+                    return RusotoError::service(
+                        CopyImageError::ResourceAlreadyExists(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotAvailableException" => {
-                    return RusotoError::Service(CopyImageError::ResourceNotAvailable(err.msg))
+                    return RusotoError::service(
+                        CopyImageError::ResourceNotAvailable(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(CopyImageError::ResourceNotFound(err.msg))
+                    return RusotoError::service(CopyImageError::ResourceNotFound(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CopyImageError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2157,12 +2197,14 @@ impl fmt::Display for CopyImageError {
             CopyImageError::ResourceAlreadyExists(ref cause) => write!(f, "{}", cause),
             CopyImageError::ResourceNotAvailable(ref cause) => write!(f, "{}", cause),
             CopyImageError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            CopyImageError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CopyImageError {}
 /// Errors returned by CreateDirectoryConfig
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateDirectoryConfigError {
     /// <p>The resource cannot be created because your AWS account is suspended. For assistance, contact AWS Support. </p>
     InvalidAccountStatus(String),
@@ -2170,6 +2212,8 @@ pub enum CreateDirectoryConfigError {
     LimitExceeded(String),
     /// <p>The specified resource already exists.</p>
     ResourceAlreadyExists(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateDirectoryConfigError {
@@ -2177,20 +2221,25 @@ impl CreateDirectoryConfigError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidAccountStatusException" => {
-                    return RusotoError::Service(CreateDirectoryConfigError::InvalidAccountStatus(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateDirectoryConfigError::InvalidAccountStatus(err.msg),
+                        &res,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateDirectoryConfigError::LimitExceeded(err.msg))
+                    return RusotoError::service(
+                        CreateDirectoryConfigError::LimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceAlreadyExistsException" => {
-                    return RusotoError::Service(CreateDirectoryConfigError::ResourceAlreadyExists(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateDirectoryConfigError::ResourceAlreadyExists(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreateDirectoryConfigError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2203,12 +2252,14 @@ impl fmt::Display for CreateDirectoryConfigError {
             CreateDirectoryConfigError::InvalidAccountStatus(ref cause) => write!(f, "{}", cause),
             CreateDirectoryConfigError::LimitExceeded(ref cause) => write!(f, "{}", cause),
             CreateDirectoryConfigError::ResourceAlreadyExists(ref cause) => write!(f, "{}", cause),
+            CreateDirectoryConfigError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateDirectoryConfigError {}
 /// Errors returned by CreateFleet
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateFleetError {
     /// <p>An API error occurred. Wait a few minutes and try again.</p>
     ConcurrentModification(String),
@@ -2230,6 +2281,8 @@ pub enum CreateFleetError {
     ResourceNotAvailable(String),
     /// <p>The specified resource was not found.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateFleetError {
@@ -2237,39 +2290,55 @@ impl CreateFleetError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(CreateFleetError::ConcurrentModification(err.msg))
+                    return RusotoError::service(
+                        CreateFleetError::ConcurrentModification(err.msg),
+                        &res,
+                    )
                 }
                 "IncompatibleImageException" => {
-                    return RusotoError::Service(CreateFleetError::IncompatibleImage(err.msg))
+                    return RusotoError::service(CreateFleetError::IncompatibleImage(err.msg), &res)
                 }
                 "InvalidAccountStatusException" => {
-                    return RusotoError::Service(CreateFleetError::InvalidAccountStatus(err.msg))
+                    return RusotoError::service(
+                        CreateFleetError::InvalidAccountStatus(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidParameterCombinationException" => {
-                    return RusotoError::Service(CreateFleetError::InvalidParameterCombination(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateFleetError::InvalidParameterCombination(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidRoleException" => {
-                    return RusotoError::Service(CreateFleetError::InvalidRole(err.msg))
+                    return RusotoError::service(CreateFleetError::InvalidRole(err.msg), &res)
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateFleetError::LimitExceeded(err.msg))
+                    return RusotoError::service(CreateFleetError::LimitExceeded(err.msg), &res)
                 }
                 "OperationNotPermittedException" => {
-                    return RusotoError::Service(CreateFleetError::OperationNotPermitted(err.msg))
+                    return RusotoError::service(
+                        CreateFleetError::OperationNotPermitted(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceAlreadyExistsException" => {
-                    return RusotoError::Service(CreateFleetError::ResourceAlreadyExists(err.msg))
+                    return RusotoError::service(
+                        CreateFleetError::ResourceAlreadyExists(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotAvailableException" => {
-                    return RusotoError::Service(CreateFleetError::ResourceNotAvailable(err.msg))
+                    return RusotoError::service(
+                        CreateFleetError::ResourceNotAvailable(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(CreateFleetError::ResourceNotFound(err.msg))
+                    return RusotoError::service(CreateFleetError::ResourceNotFound(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreateFleetError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2289,12 +2358,14 @@ impl fmt::Display for CreateFleetError {
             CreateFleetError::ResourceAlreadyExists(ref cause) => write!(f, "{}", cause),
             CreateFleetError::ResourceNotAvailable(ref cause) => write!(f, "{}", cause),
             CreateFleetError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            CreateFleetError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateFleetError {}
 /// Errors returned by CreateImageBuilder
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateImageBuilderError {
     /// <p>An API error occurred. Wait a few minutes and try again.</p>
     ConcurrentModification(String),
@@ -2316,6 +2387,8 @@ pub enum CreateImageBuilderError {
     ResourceNotAvailable(String),
     /// <p>The specified resource was not found.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateImageBuilderError {
@@ -2323,51 +2396,67 @@ impl CreateImageBuilderError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(CreateImageBuilderError::ConcurrentModification(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateImageBuilderError::ConcurrentModification(err.msg),
+                        &res,
+                    )
                 }
                 "IncompatibleImageException" => {
-                    return RusotoError::Service(CreateImageBuilderError::IncompatibleImage(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateImageBuilderError::IncompatibleImage(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidAccountStatusException" => {
-                    return RusotoError::Service(CreateImageBuilderError::InvalidAccountStatus(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateImageBuilderError::InvalidAccountStatus(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidParameterCombinationException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         CreateImageBuilderError::InvalidParameterCombination(err.msg),
+                        &res,
                     )
                 }
                 "InvalidRoleException" => {
-                    return RusotoError::Service(CreateImageBuilderError::InvalidRole(err.msg))
+                    return RusotoError::service(
+                        CreateImageBuilderError::InvalidRole(err.msg),
+                        &res,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateImageBuilderError::LimitExceeded(err.msg))
+                    return RusotoError::service(
+                        CreateImageBuilderError::LimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "OperationNotPermittedException" => {
-                    return RusotoError::Service(CreateImageBuilderError::OperationNotPermitted(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateImageBuilderError::OperationNotPermitted(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceAlreadyExistsException" => {
-                    return RusotoError::Service(CreateImageBuilderError::ResourceAlreadyExists(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateImageBuilderError::ResourceAlreadyExists(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotAvailableException" => {
-                    return RusotoError::Service(CreateImageBuilderError::ResourceNotAvailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateImageBuilderError::ResourceNotAvailable(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(CreateImageBuilderError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        CreateImageBuilderError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreateImageBuilderError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2389,17 +2478,21 @@ impl fmt::Display for CreateImageBuilderError {
             CreateImageBuilderError::ResourceAlreadyExists(ref cause) => write!(f, "{}", cause),
             CreateImageBuilderError::ResourceNotAvailable(ref cause) => write!(f, "{}", cause),
             CreateImageBuilderError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            CreateImageBuilderError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateImageBuilderError {}
 /// Errors returned by CreateImageBuilderStreamingURL
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateImageBuilderStreamingURLError {
     /// <p>The attempted operation is not permitted.</p>
     OperationNotPermitted(String),
     /// <p>The specified resource was not found.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateImageBuilderStreamingURLError {
@@ -2409,17 +2502,24 @@ impl CreateImageBuilderStreamingURLError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "OperationNotPermittedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         CreateImageBuilderStreamingURLError::OperationNotPermitted(err.msg),
+                        &res,
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         CreateImageBuilderStreamingURLError::ResourceNotFound(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        CreateImageBuilderStreamingURLError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -2435,12 +2535,16 @@ impl fmt::Display for CreateImageBuilderStreamingURLError {
             CreateImageBuilderStreamingURLError::ResourceNotFound(ref cause) => {
                 write!(f, "{}", cause)
             }
+            CreateImageBuilderStreamingURLError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for CreateImageBuilderStreamingURLError {}
 /// Errors returned by CreateStack
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateStackError {
     /// <p>An API error occurred. Wait a few minutes and try again.</p>
     ConcurrentModification(String),
@@ -2456,6 +2560,8 @@ pub enum CreateStackError {
     ResourceAlreadyExists(String),
     /// <p>The specified resource was not found.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateStackError {
@@ -2463,30 +2569,40 @@ impl CreateStackError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(CreateStackError::ConcurrentModification(err.msg))
+                    return RusotoError::service(
+                        CreateStackError::ConcurrentModification(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidAccountStatusException" => {
-                    return RusotoError::Service(CreateStackError::InvalidAccountStatus(err.msg))
+                    return RusotoError::service(
+                        CreateStackError::InvalidAccountStatus(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidParameterCombinationException" => {
-                    return RusotoError::Service(CreateStackError::InvalidParameterCombination(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateStackError::InvalidParameterCombination(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidRoleException" => {
-                    return RusotoError::Service(CreateStackError::InvalidRole(err.msg))
+                    return RusotoError::service(CreateStackError::InvalidRole(err.msg), &res)
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateStackError::LimitExceeded(err.msg))
+                    return RusotoError::service(CreateStackError::LimitExceeded(err.msg), &res)
                 }
                 "ResourceAlreadyExistsException" => {
-                    return RusotoError::Service(CreateStackError::ResourceAlreadyExists(err.msg))
+                    return RusotoError::service(
+                        CreateStackError::ResourceAlreadyExists(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(CreateStackError::ResourceNotFound(err.msg))
+                    return RusotoError::service(CreateStackError::ResourceNotFound(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreateStackError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2503,12 +2619,14 @@ impl fmt::Display for CreateStackError {
             CreateStackError::LimitExceeded(ref cause) => write!(f, "{}", cause),
             CreateStackError::ResourceAlreadyExists(ref cause) => write!(f, "{}", cause),
             CreateStackError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            CreateStackError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateStackError {}
 /// Errors returned by CreateStreamingURL
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateStreamingURLError {
     /// <p>Indicates an incorrect combination of parameters, or a missing parameter.</p>
     InvalidParameterCombination(String),
@@ -2518,6 +2636,8 @@ pub enum CreateStreamingURLError {
     ResourceNotAvailable(String),
     /// <p>The specified resource was not found.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateStreamingURLError {
@@ -2525,25 +2645,31 @@ impl CreateStreamingURLError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParameterCombinationException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         CreateStreamingURLError::InvalidParameterCombination(err.msg),
+                        &res,
                     )
                 }
                 "OperationNotPermittedException" => {
-                    return RusotoError::Service(CreateStreamingURLError::OperationNotPermitted(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateStreamingURLError::OperationNotPermitted(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotAvailableException" => {
-                    return RusotoError::Service(CreateStreamingURLError::ResourceNotAvailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateStreamingURLError::ResourceNotAvailable(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(CreateStreamingURLError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        CreateStreamingURLError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreateStreamingURLError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2559,12 +2685,14 @@ impl fmt::Display for CreateStreamingURLError {
             CreateStreamingURLError::OperationNotPermitted(ref cause) => write!(f, "{}", cause),
             CreateStreamingURLError::ResourceNotAvailable(ref cause) => write!(f, "{}", cause),
             CreateStreamingURLError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            CreateStreamingURLError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateStreamingURLError {}
 /// Errors returned by CreateUsageReportSubscription
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateUsageReportSubscriptionError {
     /// <p>The resource cannot be created because your AWS account is suspended. For assistance, contact AWS Support. </p>
     InvalidAccountStatus(String),
@@ -2572,6 +2700,8 @@ pub enum CreateUsageReportSubscriptionError {
     InvalidRole(String),
     /// <p>The requested limit exceeds the permitted limit for an account.</p>
     LimitExceeded(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateUsageReportSubscriptionError {
@@ -2581,22 +2711,30 @@ impl CreateUsageReportSubscriptionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidAccountStatusException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         CreateUsageReportSubscriptionError::InvalidAccountStatus(err.msg),
+                        &res,
                     )
                 }
                 "InvalidRoleException" => {
-                    return RusotoError::Service(CreateUsageReportSubscriptionError::InvalidRole(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateUsageReportSubscriptionError::InvalidRole(err.msg),
+                        &res,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateUsageReportSubscriptionError::LimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateUsageReportSubscriptionError::LimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        CreateUsageReportSubscriptionError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -2611,12 +2749,16 @@ impl fmt::Display for CreateUsageReportSubscriptionError {
             }
             CreateUsageReportSubscriptionError::InvalidRole(ref cause) => write!(f, "{}", cause),
             CreateUsageReportSubscriptionError::LimitExceeded(ref cause) => write!(f, "{}", cause),
+            CreateUsageReportSubscriptionError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for CreateUsageReportSubscriptionError {}
 /// Errors returned by CreateUser
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateUserError {
     /// <p>The resource cannot be created because your AWS account is suspended. For assistance, contact AWS Support. </p>
     InvalidAccountStatus(String),
@@ -2628,6 +2770,8 @@ pub enum CreateUserError {
     OperationNotPermitted(String),
     /// <p>The specified resource already exists.</p>
     ResourceAlreadyExists(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateUserError {
@@ -2635,24 +2779,34 @@ impl CreateUserError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidAccountStatusException" => {
-                    return RusotoError::Service(CreateUserError::InvalidAccountStatus(err.msg))
+                    return RusotoError::service(
+                        CreateUserError::InvalidAccountStatus(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidParameterCombinationException" => {
-                    return RusotoError::Service(CreateUserError::InvalidParameterCombination(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateUserError::InvalidParameterCombination(err.msg),
+                        &res,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateUserError::LimitExceeded(err.msg))
+                    return RusotoError::service(CreateUserError::LimitExceeded(err.msg), &res)
                 }
                 "OperationNotPermittedException" => {
-                    return RusotoError::Service(CreateUserError::OperationNotPermitted(err.msg))
+                    return RusotoError::service(
+                        CreateUserError::OperationNotPermitted(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceAlreadyExistsException" => {
-                    return RusotoError::Service(CreateUserError::ResourceAlreadyExists(err.msg))
+                    return RusotoError::service(
+                        CreateUserError::ResourceAlreadyExists(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreateUserError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2667,17 +2821,21 @@ impl fmt::Display for CreateUserError {
             CreateUserError::LimitExceeded(ref cause) => write!(f, "{}", cause),
             CreateUserError::OperationNotPermitted(ref cause) => write!(f, "{}", cause),
             CreateUserError::ResourceAlreadyExists(ref cause) => write!(f, "{}", cause),
+            CreateUserError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateUserError {}
 /// Errors returned by DeleteDirectoryConfig
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteDirectoryConfigError {
     /// <p>The specified resource is in use.</p>
     ResourceInUse(String),
     /// <p>The specified resource was not found.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteDirectoryConfigError {
@@ -2685,15 +2843,19 @@ impl DeleteDirectoryConfigError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceInUseException" => {
-                    return RusotoError::Service(DeleteDirectoryConfigError::ResourceInUse(err.msg))
+                    return RusotoError::service(
+                        DeleteDirectoryConfigError::ResourceInUse(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteDirectoryConfigError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteDirectoryConfigError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteDirectoryConfigError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2705,12 +2867,14 @@ impl fmt::Display for DeleteDirectoryConfigError {
         match *self {
             DeleteDirectoryConfigError::ResourceInUse(ref cause) => write!(f, "{}", cause),
             DeleteDirectoryConfigError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            DeleteDirectoryConfigError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteDirectoryConfigError {}
 /// Errors returned by DeleteFleet
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteFleetError {
     /// <p>An API error occurred. Wait a few minutes and try again.</p>
     ConcurrentModification(String),
@@ -2718,6 +2882,8 @@ pub enum DeleteFleetError {
     ResourceInUse(String),
     /// <p>The specified resource was not found.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteFleetError {
@@ -2725,16 +2891,19 @@ impl DeleteFleetError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(DeleteFleetError::ConcurrentModification(err.msg))
+                    return RusotoError::service(
+                        DeleteFleetError::ConcurrentModification(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(DeleteFleetError::ResourceInUse(err.msg))
+                    return RusotoError::service(DeleteFleetError::ResourceInUse(err.msg), &res)
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteFleetError::ResourceNotFound(err.msg))
+                    return RusotoError::service(DeleteFleetError::ResourceNotFound(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteFleetError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2747,12 +2916,14 @@ impl fmt::Display for DeleteFleetError {
             DeleteFleetError::ConcurrentModification(ref cause) => write!(f, "{}", cause),
             DeleteFleetError::ResourceInUse(ref cause) => write!(f, "{}", cause),
             DeleteFleetError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            DeleteFleetError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteFleetError {}
 /// Errors returned by DeleteImage
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteImageError {
     /// <p>An API error occurred. Wait a few minutes and try again.</p>
     ConcurrentModification(String),
@@ -2762,6 +2933,8 @@ pub enum DeleteImageError {
     ResourceInUse(String),
     /// <p>The specified resource was not found.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteImageError {
@@ -2769,19 +2942,25 @@ impl DeleteImageError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(DeleteImageError::ConcurrentModification(err.msg))
+                    return RusotoError::service(
+                        DeleteImageError::ConcurrentModification(err.msg),
+                        &res,
+                    )
                 }
                 "OperationNotPermittedException" => {
-                    return RusotoError::Service(DeleteImageError::OperationNotPermitted(err.msg))
+                    return RusotoError::service(
+                        DeleteImageError::OperationNotPermitted(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(DeleteImageError::ResourceInUse(err.msg))
+                    return RusotoError::service(DeleteImageError::ResourceInUse(err.msg), &res)
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteImageError::ResourceNotFound(err.msg))
+                    return RusotoError::service(DeleteImageError::ResourceNotFound(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteImageError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2795,12 +2974,14 @@ impl fmt::Display for DeleteImageError {
             DeleteImageError::OperationNotPermitted(ref cause) => write!(f, "{}", cause),
             DeleteImageError::ResourceInUse(ref cause) => write!(f, "{}", cause),
             DeleteImageError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            DeleteImageError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteImageError {}
 /// Errors returned by DeleteImageBuilder
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteImageBuilderError {
     /// <p>An API error occurred. Wait a few minutes and try again.</p>
     ConcurrentModification(String),
@@ -2808,6 +2989,8 @@ pub enum DeleteImageBuilderError {
     OperationNotPermitted(String),
     /// <p>The specified resource was not found.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteImageBuilderError {
@@ -2815,20 +2998,25 @@ impl DeleteImageBuilderError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(DeleteImageBuilderError::ConcurrentModification(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteImageBuilderError::ConcurrentModification(err.msg),
+                        &res,
+                    )
                 }
                 "OperationNotPermittedException" => {
-                    return RusotoError::Service(DeleteImageBuilderError::OperationNotPermitted(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteImageBuilderError::OperationNotPermitted(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteImageBuilderError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        DeleteImageBuilderError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteImageBuilderError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2841,17 +3029,21 @@ impl fmt::Display for DeleteImageBuilderError {
             DeleteImageBuilderError::ConcurrentModification(ref cause) => write!(f, "{}", cause),
             DeleteImageBuilderError::OperationNotPermitted(ref cause) => write!(f, "{}", cause),
             DeleteImageBuilderError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            DeleteImageBuilderError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteImageBuilderError {}
 /// Errors returned by DeleteImagePermissions
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteImagePermissionsError {
     /// <p>The specified resource exists and is not in use, but isn't available.</p>
     ResourceNotAvailable(String),
     /// <p>The specified resource was not found.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteImagePermissionsError {
@@ -2859,17 +3051,19 @@ impl DeleteImagePermissionsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotAvailableException" => {
-                    return RusotoError::Service(DeleteImagePermissionsError::ResourceNotAvailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteImagePermissionsError::ResourceNotAvailable(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteImagePermissionsError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteImagePermissionsError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteImagePermissionsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2881,12 +3075,14 @@ impl fmt::Display for DeleteImagePermissionsError {
         match *self {
             DeleteImagePermissionsError::ResourceNotAvailable(ref cause) => write!(f, "{}", cause),
             DeleteImagePermissionsError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            DeleteImagePermissionsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteImagePermissionsError {}
 /// Errors returned by DeleteStack
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteStackError {
     /// <p>An API error occurred. Wait a few minutes and try again.</p>
     ConcurrentModification(String),
@@ -2894,6 +3090,8 @@ pub enum DeleteStackError {
     ResourceInUse(String),
     /// <p>The specified resource was not found.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteStackError {
@@ -2901,16 +3099,19 @@ impl DeleteStackError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(DeleteStackError::ConcurrentModification(err.msg))
+                    return RusotoError::service(
+                        DeleteStackError::ConcurrentModification(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(DeleteStackError::ResourceInUse(err.msg))
+                    return RusotoError::service(DeleteStackError::ResourceInUse(err.msg), &res)
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteStackError::ResourceNotFound(err.msg))
+                    return RusotoError::service(DeleteStackError::ResourceNotFound(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteStackError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2923,17 +3124,21 @@ impl fmt::Display for DeleteStackError {
             DeleteStackError::ConcurrentModification(ref cause) => write!(f, "{}", cause),
             DeleteStackError::ResourceInUse(ref cause) => write!(f, "{}", cause),
             DeleteStackError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            DeleteStackError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteStackError {}
 /// Errors returned by DeleteUsageReportSubscription
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteUsageReportSubscriptionError {
     /// <p>The resource cannot be created because your AWS account is suspended. For assistance, contact AWS Support. </p>
     InvalidAccountStatus(String),
     /// <p>The specified resource was not found.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteUsageReportSubscriptionError {
@@ -2943,17 +3148,24 @@ impl DeleteUsageReportSubscriptionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidAccountStatusException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DeleteUsageReportSubscriptionError::InvalidAccountStatus(err.msg),
+                        &res,
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DeleteUsageReportSubscriptionError::ResourceNotFound(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        DeleteUsageReportSubscriptionError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -2969,15 +3181,21 @@ impl fmt::Display for DeleteUsageReportSubscriptionError {
             DeleteUsageReportSubscriptionError::ResourceNotFound(ref cause) => {
                 write!(f, "{}", cause)
             }
+            DeleteUsageReportSubscriptionError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for DeleteUsageReportSubscriptionError {}
 /// Errors returned by DeleteUser
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteUserError {
     /// <p>The specified resource was not found.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteUserError {
@@ -2985,10 +3203,10 @@ impl DeleteUserError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteUserError::ResourceNotFound(err.msg))
+                    return RusotoError::service(DeleteUserError::ResourceNotFound(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteUserError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2999,15 +3217,19 @@ impl fmt::Display for DeleteUserError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             DeleteUserError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            DeleteUserError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteUserError {}
 /// Errors returned by DescribeDirectoryConfigs
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeDirectoryConfigsError {
     /// <p>The specified resource was not found.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeDirectoryConfigsError {
@@ -3015,12 +3237,13 @@ impl DescribeDirectoryConfigsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeDirectoryConfigsError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeDirectoryConfigsError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DescribeDirectoryConfigsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3031,15 +3254,19 @@ impl fmt::Display for DescribeDirectoryConfigsError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             DescribeDirectoryConfigsError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            DescribeDirectoryConfigsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeDirectoryConfigsError {}
 /// Errors returned by DescribeFleets
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeFleetsError {
     /// <p>The specified resource was not found.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeFleetsError {
@@ -3047,10 +3274,13 @@ impl DescribeFleetsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeFleetsError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        DescribeFleetsError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DescribeFleetsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3061,15 +3291,19 @@ impl fmt::Display for DescribeFleetsError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             DescribeFleetsError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            DescribeFleetsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeFleetsError {}
 /// Errors returned by DescribeImageBuilders
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeImageBuildersError {
     /// <p>The specified resource was not found.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeImageBuildersError {
@@ -3077,12 +3311,13 @@ impl DescribeImageBuildersError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeImageBuildersError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeImageBuildersError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DescribeImageBuildersError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3093,15 +3328,19 @@ impl fmt::Display for DescribeImageBuildersError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             DescribeImageBuildersError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            DescribeImageBuildersError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeImageBuildersError {}
 /// Errors returned by DescribeImagePermissions
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeImagePermissionsError {
     /// <p>The specified resource was not found.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeImagePermissionsError {
@@ -3109,12 +3348,13 @@ impl DescribeImagePermissionsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeImagePermissionsError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeImagePermissionsError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DescribeImagePermissionsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3125,17 +3365,21 @@ impl fmt::Display for DescribeImagePermissionsError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             DescribeImagePermissionsError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            DescribeImagePermissionsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeImagePermissionsError {}
 /// Errors returned by DescribeImages
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeImagesError {
     /// <p>Indicates an incorrect combination of parameters, or a missing parameter.</p>
     InvalidParameterCombination(String),
     /// <p>The specified resource was not found.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeImagesError {
@@ -3143,15 +3387,19 @@ impl DescribeImagesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParameterCombinationException" => {
-                    return RusotoError::Service(DescribeImagesError::InvalidParameterCombination(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeImagesError::InvalidParameterCombination(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeImagesError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        DescribeImagesError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DescribeImagesError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3163,15 +3411,19 @@ impl fmt::Display for DescribeImagesError {
         match *self {
             DescribeImagesError::InvalidParameterCombination(ref cause) => write!(f, "{}", cause),
             DescribeImagesError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            DescribeImagesError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeImagesError {}
 /// Errors returned by DescribeSessions
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeSessionsError {
     /// <p>Indicates an incorrect combination of parameters, or a missing parameter.</p>
     InvalidParameterCombination(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeSessionsError {
@@ -3179,12 +3431,13 @@ impl DescribeSessionsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParameterCombinationException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DescribeSessionsError::InvalidParameterCombination(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DescribeSessionsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3195,15 +3448,19 @@ impl fmt::Display for DescribeSessionsError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             DescribeSessionsError::InvalidParameterCombination(ref cause) => write!(f, "{}", cause),
+            DescribeSessionsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeSessionsError {}
 /// Errors returned by DescribeStacks
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeStacksError {
     /// <p>The specified resource was not found.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeStacksError {
@@ -3211,10 +3468,13 @@ impl DescribeStacksError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeStacksError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        DescribeStacksError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DescribeStacksError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3225,17 +3485,21 @@ impl fmt::Display for DescribeStacksError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             DescribeStacksError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            DescribeStacksError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeStacksError {}
 /// Errors returned by DescribeUsageReportSubscriptions
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeUsageReportSubscriptionsError {
     /// <p>The resource cannot be created because your AWS account is suspended. For assistance, contact AWS Support. </p>
     InvalidAccountStatus(String),
     /// <p>The specified resource was not found.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeUsageReportSubscriptionsError {
@@ -3245,17 +3509,24 @@ impl DescribeUsageReportSubscriptionsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidAccountStatusException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DescribeUsageReportSubscriptionsError::InvalidAccountStatus(err.msg),
+                        &res,
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DescribeUsageReportSubscriptionsError::ResourceNotFound(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        DescribeUsageReportSubscriptionsError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -3271,15 +3542,21 @@ impl fmt::Display for DescribeUsageReportSubscriptionsError {
             DescribeUsageReportSubscriptionsError::ResourceNotFound(ref cause) => {
                 write!(f, "{}", cause)
             }
+            DescribeUsageReportSubscriptionsError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for DescribeUsageReportSubscriptionsError {}
 /// Errors returned by DescribeUserStackAssociations
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeUserStackAssociationsError {
     /// <p>Indicates an incorrect combination of parameters, or a missing parameter.</p>
     InvalidParameterCombination(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeUserStackAssociationsError {
@@ -3289,12 +3566,18 @@ impl DescribeUserStackAssociationsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParameterCombinationException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DescribeUserStackAssociationsError::InvalidParameterCombination(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        DescribeUserStackAssociationsError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -3307,17 +3590,23 @@ impl fmt::Display for DescribeUserStackAssociationsError {
             DescribeUserStackAssociationsError::InvalidParameterCombination(ref cause) => {
                 write!(f, "{}", cause)
             }
+            DescribeUserStackAssociationsError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for DescribeUserStackAssociationsError {}
 /// Errors returned by DescribeUsers
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeUsersError {
     /// <p>Indicates an incorrect combination of parameters, or a missing parameter.</p>
     InvalidParameterCombination(String),
     /// <p>The specified resource was not found.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeUsersError {
@@ -3325,15 +3614,19 @@ impl DescribeUsersError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParameterCombinationException" => {
-                    return RusotoError::Service(DescribeUsersError::InvalidParameterCombination(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeUsersError::InvalidParameterCombination(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeUsersError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        DescribeUsersError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DescribeUsersError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3345,15 +3638,19 @@ impl fmt::Display for DescribeUsersError {
         match *self {
             DescribeUsersError::InvalidParameterCombination(ref cause) => write!(f, "{}", cause),
             DescribeUsersError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            DescribeUsersError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeUsersError {}
 /// Errors returned by DisableUser
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DisableUserError {
     /// <p>The specified resource was not found.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DisableUserError {
@@ -3361,10 +3658,10 @@ impl DisableUserError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DisableUserError::ResourceNotFound(err.msg))
+                    return RusotoError::service(DisableUserError::ResourceNotFound(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DisableUserError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3375,12 +3672,14 @@ impl fmt::Display for DisableUserError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             DisableUserError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            DisableUserError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DisableUserError {}
 /// Errors returned by DisassociateFleet
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DisassociateFleetError {
     /// <p>An API error occurred. Wait a few minutes and try again.</p>
     ConcurrentModification(String),
@@ -3388,6 +3687,8 @@ pub enum DisassociateFleetError {
     ResourceInUse(String),
     /// <p>The specified resource was not found.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DisassociateFleetError {
@@ -3395,18 +3696,25 @@ impl DisassociateFleetError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(DisassociateFleetError::ConcurrentModification(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DisassociateFleetError::ConcurrentModification(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(DisassociateFleetError::ResourceInUse(err.msg))
+                    return RusotoError::service(
+                        DisassociateFleetError::ResourceInUse(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DisassociateFleetError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        DisassociateFleetError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DisassociateFleetError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3419,17 +3727,21 @@ impl fmt::Display for DisassociateFleetError {
             DisassociateFleetError::ConcurrentModification(ref cause) => write!(f, "{}", cause),
             DisassociateFleetError::ResourceInUse(ref cause) => write!(f, "{}", cause),
             DisassociateFleetError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            DisassociateFleetError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DisassociateFleetError {}
 /// Errors returned by EnableUser
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum EnableUserError {
     /// <p>The resource cannot be created because your AWS account is suspended. For assistance, contact AWS Support. </p>
     InvalidAccountStatus(String),
     /// <p>The specified resource was not found.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl EnableUserError {
@@ -3437,13 +3749,16 @@ impl EnableUserError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidAccountStatusException" => {
-                    return RusotoError::Service(EnableUserError::InvalidAccountStatus(err.msg))
+                    return RusotoError::service(
+                        EnableUserError::InvalidAccountStatus(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(EnableUserError::ResourceNotFound(err.msg))
+                    return RusotoError::service(EnableUserError::ResourceNotFound(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(EnableUserError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3455,20 +3770,25 @@ impl fmt::Display for EnableUserError {
         match *self {
             EnableUserError::InvalidAccountStatus(ref cause) => write!(f, "{}", cause),
             EnableUserError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            EnableUserError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for EnableUserError {}
 /// Errors returned by ExpireSession
 #[derive(Debug, PartialEq)]
-pub enum ExpireSessionError {}
+#[non_exhaustive]
+pub enum ExpireSessionError {
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
+}
 
 impl ExpireSessionError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<ExpireSessionError> {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ExpireSessionError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3477,20 +3797,26 @@ impl ExpireSessionError {
 impl fmt::Display for ExpireSessionError {
     #[allow(unused_variables)]
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        match *self {}
+        match *self {
+            ExpireSessionError::Unhandled(ref metadata) => write!(f, "{}", metadata),
+        }
     }
 }
 impl Error for ExpireSessionError {}
 /// Errors returned by ListAssociatedFleets
 #[derive(Debug, PartialEq)]
-pub enum ListAssociatedFleetsError {}
+#[non_exhaustive]
+pub enum ListAssociatedFleetsError {
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
+}
 
 impl ListAssociatedFleetsError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<ListAssociatedFleetsError> {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListAssociatedFleetsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3499,20 +3825,26 @@ impl ListAssociatedFleetsError {
 impl fmt::Display for ListAssociatedFleetsError {
     #[allow(unused_variables)]
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        match *self {}
+        match *self {
+            ListAssociatedFleetsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
+        }
     }
 }
 impl Error for ListAssociatedFleetsError {}
 /// Errors returned by ListAssociatedStacks
 #[derive(Debug, PartialEq)]
-pub enum ListAssociatedStacksError {}
+#[non_exhaustive]
+pub enum ListAssociatedStacksError {
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
+}
 
 impl ListAssociatedStacksError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<ListAssociatedStacksError> {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListAssociatedStacksError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3521,15 +3853,20 @@ impl ListAssociatedStacksError {
 impl fmt::Display for ListAssociatedStacksError {
     #[allow(unused_variables)]
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        match *self {}
+        match *self {
+            ListAssociatedStacksError::Unhandled(ref metadata) => write!(f, "{}", metadata),
+        }
     }
 }
 impl Error for ListAssociatedStacksError {}
 /// Errors returned by ListTagsForResource
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListTagsForResourceError {
     /// <p>The specified resource was not found.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListTagsForResourceError {
@@ -3537,12 +3874,13 @@ impl ListTagsForResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListTagsForResourceError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListTagsForResourceError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListTagsForResourceError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3553,12 +3891,14 @@ impl fmt::Display for ListTagsForResourceError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             ListTagsForResourceError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            ListTagsForResourceError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListTagsForResourceError {}
 /// Errors returned by StartFleet
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum StartFleetError {
     /// <p>An API error occurred. Wait a few minutes and try again.</p>
     ConcurrentModification(String),
@@ -3574,6 +3914,8 @@ pub enum StartFleetError {
     ResourceNotAvailable(String),
     /// <p>The specified resource was not found.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl StartFleetError {
@@ -3581,28 +3923,40 @@ impl StartFleetError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(StartFleetError::ConcurrentModification(err.msg))
+                    return RusotoError::service(
+                        StartFleetError::ConcurrentModification(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidAccountStatusException" => {
-                    return RusotoError::Service(StartFleetError::InvalidAccountStatus(err.msg))
+                    return RusotoError::service(
+                        StartFleetError::InvalidAccountStatus(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidRoleException" => {
-                    return RusotoError::Service(StartFleetError::InvalidRole(err.msg))
+                    return RusotoError::service(StartFleetError::InvalidRole(err.msg), &res)
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(StartFleetError::LimitExceeded(err.msg))
+                    return RusotoError::service(StartFleetError::LimitExceeded(err.msg), &res)
                 }
                 "OperationNotPermittedException" => {
-                    return RusotoError::Service(StartFleetError::OperationNotPermitted(err.msg))
+                    return RusotoError::service(
+                        StartFleetError::OperationNotPermitted(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotAvailableException" => {
-                    return RusotoError::Service(StartFleetError::ResourceNotAvailable(err.msg))
+                    return RusotoError::service(
+                        StartFleetError::ResourceNotAvailable(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(StartFleetError::ResourceNotFound(err.msg))
+                    return RusotoError::service(StartFleetError::ResourceNotFound(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(StartFleetError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3619,12 +3973,14 @@ impl fmt::Display for StartFleetError {
             StartFleetError::OperationNotPermitted(ref cause) => write!(f, "{}", cause),
             StartFleetError::ResourceNotAvailable(ref cause) => write!(f, "{}", cause),
             StartFleetError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            StartFleetError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for StartFleetError {}
 /// Errors returned by StartImageBuilder
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum StartImageBuilderError {
     /// <p>An API error occurred. Wait a few minutes and try again.</p>
     ConcurrentModification(String),
@@ -3636,6 +3992,8 @@ pub enum StartImageBuilderError {
     ResourceNotAvailable(String),
     /// <p>The specified resource was not found.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl StartImageBuilderError {
@@ -3643,28 +4001,37 @@ impl StartImageBuilderError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(StartImageBuilderError::ConcurrentModification(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        StartImageBuilderError::ConcurrentModification(err.msg),
+                        &res,
+                    )
                 }
                 "IncompatibleImageException" => {
-                    return RusotoError::Service(StartImageBuilderError::IncompatibleImage(err.msg))
+                    return RusotoError::service(
+                        StartImageBuilderError::IncompatibleImage(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidAccountStatusException" => {
-                    return RusotoError::Service(StartImageBuilderError::InvalidAccountStatus(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        StartImageBuilderError::InvalidAccountStatus(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotAvailableException" => {
-                    return RusotoError::Service(StartImageBuilderError::ResourceNotAvailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        StartImageBuilderError::ResourceNotAvailable(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(StartImageBuilderError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        StartImageBuilderError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(StartImageBuilderError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3679,17 +4046,21 @@ impl fmt::Display for StartImageBuilderError {
             StartImageBuilderError::InvalidAccountStatus(ref cause) => write!(f, "{}", cause),
             StartImageBuilderError::ResourceNotAvailable(ref cause) => write!(f, "{}", cause),
             StartImageBuilderError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            StartImageBuilderError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for StartImageBuilderError {}
 /// Errors returned by StopFleet
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum StopFleetError {
     /// <p>An API error occurred. Wait a few minutes and try again.</p>
     ConcurrentModification(String),
     /// <p>The specified resource was not found.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl StopFleetError {
@@ -3697,13 +4068,16 @@ impl StopFleetError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(StopFleetError::ConcurrentModification(err.msg))
+                    return RusotoError::service(
+                        StopFleetError::ConcurrentModification(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(StopFleetError::ResourceNotFound(err.msg))
+                    return RusotoError::service(StopFleetError::ResourceNotFound(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(StopFleetError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3715,12 +4089,14 @@ impl fmt::Display for StopFleetError {
         match *self {
             StopFleetError::ConcurrentModification(ref cause) => write!(f, "{}", cause),
             StopFleetError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            StopFleetError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for StopFleetError {}
 /// Errors returned by StopImageBuilder
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum StopImageBuilderError {
     /// <p>An API error occurred. Wait a few minutes and try again.</p>
     ConcurrentModification(String),
@@ -3728,6 +4104,8 @@ pub enum StopImageBuilderError {
     OperationNotPermitted(String),
     /// <p>The specified resource was not found.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl StopImageBuilderError {
@@ -3735,20 +4113,25 @@ impl StopImageBuilderError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(StopImageBuilderError::ConcurrentModification(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        StopImageBuilderError::ConcurrentModification(err.msg),
+                        &res,
+                    )
                 }
                 "OperationNotPermittedException" => {
-                    return RusotoError::Service(StopImageBuilderError::OperationNotPermitted(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        StopImageBuilderError::OperationNotPermitted(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(StopImageBuilderError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        StopImageBuilderError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(StopImageBuilderError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3761,12 +4144,14 @@ impl fmt::Display for StopImageBuilderError {
             StopImageBuilderError::ConcurrentModification(ref cause) => write!(f, "{}", cause),
             StopImageBuilderError::OperationNotPermitted(ref cause) => write!(f, "{}", cause),
             StopImageBuilderError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            StopImageBuilderError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for StopImageBuilderError {}
 /// Errors returned by TagResource
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum TagResourceError {
     /// <p>The resource cannot be created because your AWS account is suspended. For assistance, contact AWS Support. </p>
     InvalidAccountStatus(String),
@@ -3774,6 +4159,8 @@ pub enum TagResourceError {
     LimitExceeded(String),
     /// <p>The specified resource was not found.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl TagResourceError {
@@ -3781,16 +4168,19 @@ impl TagResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidAccountStatusException" => {
-                    return RusotoError::Service(TagResourceError::InvalidAccountStatus(err.msg))
+                    return RusotoError::service(
+                        TagResourceError::InvalidAccountStatus(err.msg),
+                        &res,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(TagResourceError::LimitExceeded(err.msg))
+                    return RusotoError::service(TagResourceError::LimitExceeded(err.msg), &res)
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(TagResourceError::ResourceNotFound(err.msg))
+                    return RusotoError::service(TagResourceError::ResourceNotFound(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(TagResourceError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3803,15 +4193,19 @@ impl fmt::Display for TagResourceError {
             TagResourceError::InvalidAccountStatus(ref cause) => write!(f, "{}", cause),
             TagResourceError::LimitExceeded(ref cause) => write!(f, "{}", cause),
             TagResourceError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            TagResourceError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for TagResourceError {}
 /// Errors returned by UntagResource
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UntagResourceError {
     /// <p>The specified resource was not found.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UntagResourceError {
@@ -3819,10 +4213,13 @@ impl UntagResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UntagResourceError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        UntagResourceError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UntagResourceError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3833,12 +4230,14 @@ impl fmt::Display for UntagResourceError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             UntagResourceError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            UntagResourceError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for UntagResourceError {}
 /// Errors returned by UpdateDirectoryConfig
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdateDirectoryConfigError {
     /// <p>An API error occurred. Wait a few minutes and try again.</p>
     ConcurrentModification(String),
@@ -3846,6 +4245,8 @@ pub enum UpdateDirectoryConfigError {
     ResourceInUse(String),
     /// <p>The specified resource was not found.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdateDirectoryConfigError {
@@ -3853,20 +4254,25 @@ impl UpdateDirectoryConfigError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         UpdateDirectoryConfigError::ConcurrentModification(err.msg),
+                        &res,
                     )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(UpdateDirectoryConfigError::ResourceInUse(err.msg))
+                    return RusotoError::service(
+                        UpdateDirectoryConfigError::ResourceInUse(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateDirectoryConfigError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateDirectoryConfigError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UpdateDirectoryConfigError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3879,12 +4285,14 @@ impl fmt::Display for UpdateDirectoryConfigError {
             UpdateDirectoryConfigError::ConcurrentModification(ref cause) => write!(f, "{}", cause),
             UpdateDirectoryConfigError::ResourceInUse(ref cause) => write!(f, "{}", cause),
             UpdateDirectoryConfigError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            UpdateDirectoryConfigError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for UpdateDirectoryConfigError {}
 /// Errors returned by UpdateFleet
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdateFleetError {
     /// <p>An API error occurred. Wait a few minutes and try again.</p>
     ConcurrentModification(String),
@@ -3906,6 +4314,8 @@ pub enum UpdateFleetError {
     ResourceNotAvailable(String),
     /// <p>The specified resource was not found.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdateFleetError {
@@ -3913,39 +4323,52 @@ impl UpdateFleetError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(UpdateFleetError::ConcurrentModification(err.msg))
+                    return RusotoError::service(
+                        UpdateFleetError::ConcurrentModification(err.msg),
+                        &res,
+                    )
                 }
                 "IncompatibleImageException" => {
-                    return RusotoError::Service(UpdateFleetError::IncompatibleImage(err.msg))
+                    return RusotoError::service(UpdateFleetError::IncompatibleImage(err.msg), &res)
                 }
                 "InvalidAccountStatusException" => {
-                    return RusotoError::Service(UpdateFleetError::InvalidAccountStatus(err.msg))
+                    return RusotoError::service(
+                        UpdateFleetError::InvalidAccountStatus(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidParameterCombinationException" => {
-                    return RusotoError::Service(UpdateFleetError::InvalidParameterCombination(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateFleetError::InvalidParameterCombination(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidRoleException" => {
-                    return RusotoError::Service(UpdateFleetError::InvalidRole(err.msg))
+                    return RusotoError::service(UpdateFleetError::InvalidRole(err.msg), &res)
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(UpdateFleetError::LimitExceeded(err.msg))
+                    return RusotoError::service(UpdateFleetError::LimitExceeded(err.msg), &res)
                 }
                 "OperationNotPermittedException" => {
-                    return RusotoError::Service(UpdateFleetError::OperationNotPermitted(err.msg))
+                    return RusotoError::service(
+                        UpdateFleetError::OperationNotPermitted(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(UpdateFleetError::ResourceInUse(err.msg))
+                    return RusotoError::service(UpdateFleetError::ResourceInUse(err.msg), &res)
                 }
                 "ResourceNotAvailableException" => {
-                    return RusotoError::Service(UpdateFleetError::ResourceNotAvailable(err.msg))
+                    return RusotoError::service(
+                        UpdateFleetError::ResourceNotAvailable(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateFleetError::ResourceNotFound(err.msg))
+                    return RusotoError::service(UpdateFleetError::ResourceNotFound(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UpdateFleetError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3965,12 +4388,14 @@ impl fmt::Display for UpdateFleetError {
             UpdateFleetError::ResourceInUse(ref cause) => write!(f, "{}", cause),
             UpdateFleetError::ResourceNotAvailable(ref cause) => write!(f, "{}", cause),
             UpdateFleetError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            UpdateFleetError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for UpdateFleetError {}
 /// Errors returned by UpdateImagePermissions
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdateImagePermissionsError {
     /// <p>The requested limit exceeds the permitted limit for an account.</p>
     LimitExceeded(String),
@@ -3978,6 +4403,8 @@ pub enum UpdateImagePermissionsError {
     ResourceNotAvailable(String),
     /// <p>The specified resource was not found.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdateImagePermissionsError {
@@ -3985,22 +4412,25 @@ impl UpdateImagePermissionsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "LimitExceededException" => {
-                    return RusotoError::Service(UpdateImagePermissionsError::LimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateImagePermissionsError::LimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotAvailableException" => {
-                    return RusotoError::Service(UpdateImagePermissionsError::ResourceNotAvailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateImagePermissionsError::ResourceNotAvailable(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateImagePermissionsError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateImagePermissionsError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UpdateImagePermissionsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -4013,12 +4443,14 @@ impl fmt::Display for UpdateImagePermissionsError {
             UpdateImagePermissionsError::LimitExceeded(ref cause) => write!(f, "{}", cause),
             UpdateImagePermissionsError::ResourceNotAvailable(ref cause) => write!(f, "{}", cause),
             UpdateImagePermissionsError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            UpdateImagePermissionsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for UpdateImagePermissionsError {}
 /// Errors returned by UpdateStack
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdateStackError {
     /// <p>An API error occurred. Wait a few minutes and try again.</p>
     ConcurrentModification(String),
@@ -4038,6 +4470,8 @@ pub enum UpdateStackError {
     ResourceInUse(String),
     /// <p>The specified resource was not found.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdateStackError {
@@ -4045,36 +4479,46 @@ impl UpdateStackError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(UpdateStackError::ConcurrentModification(err.msg))
+                    return RusotoError::service(
+                        UpdateStackError::ConcurrentModification(err.msg),
+                        &res,
+                    )
                 }
                 "IncompatibleImageException" => {
-                    return RusotoError::Service(UpdateStackError::IncompatibleImage(err.msg))
+                    return RusotoError::service(UpdateStackError::IncompatibleImage(err.msg), &res)
                 }
                 "InvalidAccountStatusException" => {
-                    return RusotoError::Service(UpdateStackError::InvalidAccountStatus(err.msg))
+                    return RusotoError::service(
+                        UpdateStackError::InvalidAccountStatus(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidParameterCombinationException" => {
-                    return RusotoError::Service(UpdateStackError::InvalidParameterCombination(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateStackError::InvalidParameterCombination(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidRoleException" => {
-                    return RusotoError::Service(UpdateStackError::InvalidRole(err.msg))
+                    return RusotoError::service(UpdateStackError::InvalidRole(err.msg), &res)
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(UpdateStackError::LimitExceeded(err.msg))
+                    return RusotoError::service(UpdateStackError::LimitExceeded(err.msg), &res)
                 }
                 "OperationNotPermittedException" => {
-                    return RusotoError::Service(UpdateStackError::OperationNotPermitted(err.msg))
+                    return RusotoError::service(
+                        UpdateStackError::OperationNotPermitted(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(UpdateStackError::ResourceInUse(err.msg))
+                    return RusotoError::service(UpdateStackError::ResourceInUse(err.msg), &res)
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateStackError::ResourceNotFound(err.msg))
+                    return RusotoError::service(UpdateStackError::ResourceNotFound(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UpdateStackError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -4093,6 +4537,7 @@ impl fmt::Display for UpdateStackError {
             UpdateStackError::OperationNotPermitted(ref cause) => write!(f, "{}", cause),
             UpdateStackError::ResourceInUse(ref cause) => write!(f, "{}", cause),
             UpdateStackError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            UpdateStackError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
@@ -4397,6 +4842,7 @@ impl AppStreamClient {
     /// Creates a client backed by the default tokio event loop.
     ///
     /// The client will use the default credentials provider and tls client.
+    #[cfg(not(target_arch = "wasm32"))]
     pub fn new(region: region::Region) -> AppStreamClient {
         AppStreamClient {
             client: Client::shared(),
@@ -4422,43 +4868,125 @@ impl AppStreamClient {
     pub fn new_with_client(client: Client, region: region::Region) -> AppStreamClient {
         AppStreamClient { client, region }
     }
+
+    /// Returns a builder of a client, setting any of its region, credentials provider,
+    /// dispatcher, retry policy, endpoint and timeouts.
+    pub fn builder() -> rusoto_core::ClientBuilder<AppStreamClient> {
+        rusoto_core::ClientBuilder::new(AppStreamClient::new_with_client)
+    }
+
+    /// Returns a clone of this client with the overrides of `config` applied to its
+    /// operations, sharing its connections.
+    pub fn customize(&self, config: &rusoto_core::OperationConfig) -> AppStreamClient {
+        AppStreamClient {
+            client: self.client.customize(config),
+            region: config.resolve_region(&self.region),
+        }
+    }
 }
 
-#[async_trait]
-impl AppStream for AppStreamClient {
-    /// <p>Associates the specified fleet with the specified stack.</p>
-    async fn associate_fleet(
+impl AppStreamClient {
+    /// Calls `associate_fleet`, also returning the metadata of the response.
+    pub async fn associate_fleet_with_metadata(
         &self,
         input: AssociateFleetRequest,
-    ) -> Result<AssociateFleetResult, RusotoError<AssociateFleetError>> {
+    ) -> Result<(AssociateFleetResult, ResponseMetadata), RusotoError<AssociateFleetError>> {
+        let request = self.build_associate_fleet_request(input);
+
+        let mut response = self
+            .client
+            .sign_and_dispatch(request)
+            .await
+            .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
+        if response.status.is_success() {
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<AssociateFleetResult, _>()?;
+            Ok((result, metadata))
+        } else {
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(AssociateFleetError::from_response(response))
+        }
+    }
+
+    /// Presigns the request `associate_fleet` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_associate_fleet(
+        &self,
+        presigner: &rusoto_core::Presigner,
+        input: AssociateFleetRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_associate_fleet_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_associate_fleet_request(&self, input: AssociateFleetRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "appstream", &self.region, "/");
+        request.set_operation("AssociateFleet");
         request.set_endpoint_prefix("appstream2".to_string());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "PhotonAdminProxyService.AssociateFleet");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `batch_associate_user_stack`, also returning the metadata of the response.
+    pub async fn batch_associate_user_stack_with_metadata(
+        &self,
+        input: BatchAssociateUserStackRequest,
+    ) -> Result<
+        (BatchAssociateUserStackResult, ResponseMetadata),
+        RusotoError<BatchAssociateUserStackError>,
+    > {
+        let request = self.build_batch_associate_user_stack_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<AssociateFleetResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<BatchAssociateUserStackResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(AssociateFleetError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(BatchAssociateUserStackError::from_response(response))
         }
     }
 
-    /// <p>Associates the specified users with the specified stacks. Users in a user pool cannot be assigned to stacks with fleets that are joined to an Active Directory domain.</p>
-    async fn batch_associate_user_stack(
+    /// Presigns the request `batch_associate_user_stack` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_batch_associate_user_stack(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: BatchAssociateUserStackRequest,
-    ) -> Result<BatchAssociateUserStackResult, RusotoError<BatchAssociateUserStackError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_batch_associate_user_stack_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_batch_associate_user_stack_request(
+        &self,
+        input: BatchAssociateUserStackRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "appstream", &self.region, "/");
+        request.set_operation("BatchAssociateUserStack");
         request.set_endpoint_prefix("appstream2".to_string());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -4468,29 +4996,58 @@ impl AppStream for AppStreamClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `batch_disassociate_user_stack`, also returning the metadata of the response.
+    pub async fn batch_disassociate_user_stack_with_metadata(
+        &self,
+        input: BatchDisassociateUserStackRequest,
+    ) -> Result<
+        (BatchDisassociateUserStackResult, ResponseMetadata),
+        RusotoError<BatchDisassociateUserStackError>,
+    > {
+        let request = self.build_batch_disassociate_user_stack_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<BatchAssociateUserStackResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<BatchDisassociateUserStackResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(BatchAssociateUserStackError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(BatchDisassociateUserStackError::from_response(response))
         }
     }
 
-    /// <p>Disassociates the specified users from the specified stacks.</p>
-    async fn batch_disassociate_user_stack(
+    /// Presigns the request `batch_disassociate_user_stack` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_batch_disassociate_user_stack(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: BatchDisassociateUserStackRequest,
-    ) -> Result<BatchDisassociateUserStackResult, RusotoError<BatchDisassociateUserStackError>>
-    {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_batch_disassociate_user_stack_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_batch_disassociate_user_stack_request(
+        &self,
+        input: BatchDisassociateUserStackRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "appstream", &self.region, "/");
+        request.set_operation("BatchDisassociateUserStack");
         request.set_endpoint_prefix("appstream2".to_string());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -4500,55 +5057,110 @@ impl AppStream for AppStreamClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `copy_image`, also returning the metadata of the response.
+    pub async fn copy_image_with_metadata(
+        &self,
+        input: CopyImageRequest,
+    ) -> Result<(CopyImageResponse, ResponseMetadata), RusotoError<CopyImageError>> {
+        let request = self.build_copy_image_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<BatchDisassociateUserStackResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<CopyImageResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(BatchDisassociateUserStackError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(CopyImageError::from_response(response))
         }
     }
 
-    /// <p>Copies the image within the same region or to a new region within the same AWS account. Note that any tags you added to the image will not be copied.</p>
-    async fn copy_image(
+    /// Presigns the request `copy_image` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_copy_image(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: CopyImageRequest,
-    ) -> Result<CopyImageResponse, RusotoError<CopyImageError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_copy_image_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_copy_image_request(&self, input: CopyImageRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "appstream", &self.region, "/");
+        request.set_operation("CopyImage");
         request.set_endpoint_prefix("appstream2".to_string());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "PhotonAdminProxyService.CopyImage");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `create_directory_config`, also returning the metadata of the response.
+    pub async fn create_directory_config_with_metadata(
+        &self,
+        input: CreateDirectoryConfigRequest,
+    ) -> Result<
+        (CreateDirectoryConfigResult, ResponseMetadata),
+        RusotoError<CreateDirectoryConfigError>,
+    > {
+        let request = self.build_create_directory_config_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<CopyImageResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<CreateDirectoryConfigResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(CopyImageError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(CreateDirectoryConfigError::from_response(response))
         }
     }
 
-    /// <p>Creates a Directory Config object in AppStream 2.0. This object includes the configuration information required to join fleets and image builders to Microsoft Active Directory domains.</p>
-    async fn create_directory_config(
+    /// Presigns the request `create_directory_config` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_directory_config(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: CreateDirectoryConfigRequest,
-    ) -> Result<CreateDirectoryConfigResult, RusotoError<CreateDirectoryConfigError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_directory_config_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_directory_config_request(
+        &self,
+        input: CreateDirectoryConfigRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "appstream", &self.region, "/");
+        request.set_operation("CreateDirectoryConfig");
         request.set_endpoint_prefix("appstream2".to_string());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -4558,88 +5170,168 @@ impl AppStream for AppStreamClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `create_fleet`, also returning the metadata of the response.
+    pub async fn create_fleet_with_metadata(
+        &self,
+        input: CreateFleetRequest,
+    ) -> Result<(CreateFleetResult, ResponseMetadata), RusotoError<CreateFleetError>> {
+        let request = self.build_create_fleet_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<CreateDirectoryConfigResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<CreateFleetResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(CreateDirectoryConfigError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(CreateFleetError::from_response(response))
         }
     }
 
-    /// <p>Creates a fleet. A fleet consists of streaming instances that run a specified image.</p>
-    async fn create_fleet(
+    /// Presigns the request `create_fleet` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_fleet(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: CreateFleetRequest,
-    ) -> Result<CreateFleetResult, RusotoError<CreateFleetError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_fleet_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_fleet_request(&self, input: CreateFleetRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "appstream", &self.region, "/");
+        request.set_operation("CreateFleet");
         request.set_endpoint_prefix("appstream2".to_string());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "PhotonAdminProxyService.CreateFleet");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `create_image_builder`, also returning the metadata of the response.
+    pub async fn create_image_builder_with_metadata(
+        &self,
+        input: CreateImageBuilderRequest,
+    ) -> Result<(CreateImageBuilderResult, ResponseMetadata), RusotoError<CreateImageBuilderError>>
+    {
+        let request = self.build_create_image_builder_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<CreateFleetResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<CreateImageBuilderResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(CreateFleetError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(CreateImageBuilderError::from_response(response))
         }
     }
 
-    /// <p>Creates an image builder. An image builder is a virtual machine that is used to create an image.</p> <p>The initial state of the builder is <code>PENDING</code>. When it is ready, the state is <code>RUNNING</code>.</p>
-    async fn create_image_builder(
+    /// Presigns the request `create_image_builder` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_image_builder(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: CreateImageBuilderRequest,
-    ) -> Result<CreateImageBuilderResult, RusotoError<CreateImageBuilderError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_image_builder_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_image_builder_request(
+        &self,
+        input: CreateImageBuilderRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "appstream", &self.region, "/");
+        request.set_operation("CreateImageBuilder");
         request.set_endpoint_prefix("appstream2".to_string());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "PhotonAdminProxyService.CreateImageBuilder");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `create_image_builder_streaming_url`, also returning the metadata of the response.
+    pub async fn create_image_builder_streaming_url_with_metadata(
+        &self,
+        input: CreateImageBuilderStreamingURLRequest,
+    ) -> Result<
+        (CreateImageBuilderStreamingURLResult, ResponseMetadata),
+        RusotoError<CreateImageBuilderStreamingURLError>,
+    > {
+        let request = self.build_create_image_builder_streaming_url_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<CreateImageBuilderResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<CreateImageBuilderStreamingURLResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(CreateImageBuilderError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(CreateImageBuilderStreamingURLError::from_response(response))
         }
     }
 
-    /// <p>Creates a URL to start an image builder streaming session.</p>
-    async fn create_image_builder_streaming_url(
+    /// Presigns the request `create_image_builder_streaming_url` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_image_builder_streaming_url(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: CreateImageBuilderStreamingURLRequest,
-    ) -> Result<
-        CreateImageBuilderStreamingURLResult,
-        RusotoError<CreateImageBuilderStreamingURLError>,
-    > {
-        let mut request = SignedRequest::new("POST", "appstream", &self.region, "/");
-        request.set_endpoint_prefix("appstream2".to_string());
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_image_builder_streaming_url_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_image_builder_streaming_url_request(
+        &self,
+        input: CreateImageBuilderStreamingURLRequest,
+    ) -> SignedRequest {
+        let mut request = SignedRequest::new("POST", "appstream", &self.region, "/");
+        request.set_operation("CreateImageBuilderStreamingURL");
+        request.set_endpoint_prefix("appstream2".to_string());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
             "x-amz-target",
             "PhotonAdminProxyService.CreateImageBuilderStreamingURL",
@@ -4647,83 +5339,161 @@ impl AppStream for AppStreamClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `create_stack`, also returning the metadata of the response.
+    pub async fn create_stack_with_metadata(
+        &self,
+        input: CreateStackRequest,
+    ) -> Result<(CreateStackResult, ResponseMetadata), RusotoError<CreateStackError>> {
+        let request = self.build_create_stack_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<CreateImageBuilderStreamingURLResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<CreateStackResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(CreateImageBuilderStreamingURLError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(CreateStackError::from_response(response))
         }
     }
 
-    /// <p>Creates a stack to start streaming applications to users. A stack consists of an associated fleet, user access policies, and storage configurations. </p>
-    async fn create_stack(
+    /// Presigns the request `create_stack` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_stack(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: CreateStackRequest,
-    ) -> Result<CreateStackResult, RusotoError<CreateStackError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_stack_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_stack_request(&self, input: CreateStackRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "appstream", &self.region, "/");
+        request.set_operation("CreateStack");
         request.set_endpoint_prefix("appstream2".to_string());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "PhotonAdminProxyService.CreateStack");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `create_streaming_url`, also returning the metadata of the response.
+    pub async fn create_streaming_url_with_metadata(
+        &self,
+        input: CreateStreamingURLRequest,
+    ) -> Result<(CreateStreamingURLResult, ResponseMetadata), RusotoError<CreateStreamingURLError>>
+    {
+        let request = self.build_create_streaming_url_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<CreateStackResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<CreateStreamingURLResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(CreateStackError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(CreateStreamingURLError::from_response(response))
         }
     }
 
-    /// <p>Creates a temporary URL to start an AppStream 2.0 streaming session for the specified user. A streaming URL enables application streaming to be tested without user setup. </p>
-    async fn create_streaming_url(
+    /// Presigns the request `create_streaming_url` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_streaming_url(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: CreateStreamingURLRequest,
-    ) -> Result<CreateStreamingURLResult, RusotoError<CreateStreamingURLError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_streaming_url_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_streaming_url_request(
+        &self,
+        input: CreateStreamingURLRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "appstream", &self.region, "/");
+        request.set_operation("CreateStreamingURL");
         request.set_endpoint_prefix("appstream2".to_string());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "PhotonAdminProxyService.CreateStreamingURL");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `create_usage_report_subscription`, also returning the metadata of the response.
+    pub async fn create_usage_report_subscription_with_metadata(
+        &self,
+    ) -> Result<
+        (CreateUsageReportSubscriptionResult, ResponseMetadata),
+        RusotoError<CreateUsageReportSubscriptionError>,
+    > {
+        let request = self.build_create_usage_report_subscription_request();
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<CreateStreamingURLResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<CreateUsageReportSubscriptionResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(CreateStreamingURLError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(CreateUsageReportSubscriptionError::from_response(response))
         }
     }
 
-    /// <p>Creates a usage report subscription. Usage reports are generated daily.</p>
-    async fn create_usage_report_subscription(
+    /// Presigns the request `create_usage_report_subscription` would send with `presigner` instead of sending it.
+    pub async fn presign_create_usage_report_subscription(
         &self,
-    ) -> Result<CreateUsageReportSubscriptionResult, RusotoError<CreateUsageReportSubscriptionError>>
-    {
+        presigner: &rusoto_core::Presigner,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_usage_report_subscription_request();
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_usage_report_subscription_request(&self) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "appstream", &self.region, "/");
+        request.set_operation("CreateUsageReportSubscription");
         request.set_endpoint_prefix("appstream2".to_string());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -4732,55 +5502,110 @@ impl AppStream for AppStreamClient {
         );
         request.set_payload(Some(bytes::Bytes::from_static(b"{}")));
 
+        request
+    }
+
+    /// Calls `create_user`, also returning the metadata of the response.
+    pub async fn create_user_with_metadata(
+        &self,
+        input: CreateUserRequest,
+    ) -> Result<(CreateUserResult, ResponseMetadata), RusotoError<CreateUserError>> {
+        let request = self.build_create_user_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<CreateUsageReportSubscriptionResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<CreateUserResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(CreateUsageReportSubscriptionError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(CreateUserError::from_response(response))
         }
     }
 
-    /// <p>Creates a new user in the user pool.</p>
-    async fn create_user(
+    /// Presigns the request `create_user` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_user(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: CreateUserRequest,
-    ) -> Result<CreateUserResult, RusotoError<CreateUserError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_user_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_user_request(&self, input: CreateUserRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "appstream", &self.region, "/");
+        request.set_operation("CreateUser");
         request.set_endpoint_prefix("appstream2".to_string());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "PhotonAdminProxyService.CreateUser");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `delete_directory_config`, also returning the metadata of the response.
+    pub async fn delete_directory_config_with_metadata(
+        &self,
+        input: DeleteDirectoryConfigRequest,
+    ) -> Result<
+        (DeleteDirectoryConfigResult, ResponseMetadata),
+        RusotoError<DeleteDirectoryConfigError>,
+    > {
+        let request = self.build_delete_directory_config_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<CreateUserResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DeleteDirectoryConfigResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(CreateUserError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DeleteDirectoryConfigError::from_response(response))
         }
     }
 
-    /// <p>Deletes the specified Directory Config object from AppStream 2.0. This object includes the information required to join streaming instances to an Active Directory domain.</p>
-    async fn delete_directory_config(
+    /// Presigns the request `delete_directory_config` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_directory_config(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteDirectoryConfigRequest,
-    ) -> Result<DeleteDirectoryConfigResult, RusotoError<DeleteDirectoryConfigError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_directory_config_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_directory_config_request(
+        &self,
+        input: DeleteDirectoryConfigRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "appstream", &self.region, "/");
+        request.set_operation("DeleteDirectoryConfig");
         request.set_endpoint_prefix("appstream2".to_string());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -4790,110 +5615,218 @@ impl AppStream for AppStreamClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `delete_fleet`, also returning the metadata of the response.
+    pub async fn delete_fleet_with_metadata(
+        &self,
+        input: DeleteFleetRequest,
+    ) -> Result<(DeleteFleetResult, ResponseMetadata), RusotoError<DeleteFleetError>> {
+        let request = self.build_delete_fleet_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DeleteDirectoryConfigResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DeleteFleetResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteDirectoryConfigError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DeleteFleetError::from_response(response))
         }
     }
 
-    /// <p>Deletes the specified fleet.</p>
-    async fn delete_fleet(
+    /// Presigns the request `delete_fleet` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_fleet(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteFleetRequest,
-    ) -> Result<DeleteFleetResult, RusotoError<DeleteFleetError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_fleet_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_fleet_request(&self, input: DeleteFleetRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "appstream", &self.region, "/");
+        request.set_operation("DeleteFleet");
         request.set_endpoint_prefix("appstream2".to_string());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "PhotonAdminProxyService.DeleteFleet");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `delete_image`, also returning the metadata of the response.
+    pub async fn delete_image_with_metadata(
+        &self,
+        input: DeleteImageRequest,
+    ) -> Result<(DeleteImageResult, ResponseMetadata), RusotoError<DeleteImageError>> {
+        let request = self.build_delete_image_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<DeleteFleetResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DeleteImageResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteFleetError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DeleteImageError::from_response(response))
         }
     }
 
-    /// <p>Deletes the specified image. You cannot delete an image when it is in use. After you delete an image, you cannot provision new capacity using the image.</p>
-    async fn delete_image(
+    /// Presigns the request `delete_image` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_image(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteImageRequest,
-    ) -> Result<DeleteImageResult, RusotoError<DeleteImageError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_image_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_image_request(&self, input: DeleteImageRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "appstream", &self.region, "/");
+        request.set_operation("DeleteImage");
         request.set_endpoint_prefix("appstream2".to_string());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "PhotonAdminProxyService.DeleteImage");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `delete_image_builder`, also returning the metadata of the response.
+    pub async fn delete_image_builder_with_metadata(
+        &self,
+        input: DeleteImageBuilderRequest,
+    ) -> Result<(DeleteImageBuilderResult, ResponseMetadata), RusotoError<DeleteImageBuilderError>>
+    {
+        let request = self.build_delete_image_builder_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<DeleteImageResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DeleteImageBuilderResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteImageError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DeleteImageBuilderError::from_response(response))
         }
     }
 
-    /// <p>Deletes the specified image builder and releases the capacity.</p>
-    async fn delete_image_builder(
+    /// Presigns the request `delete_image_builder` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_image_builder(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteImageBuilderRequest,
-    ) -> Result<DeleteImageBuilderResult, RusotoError<DeleteImageBuilderError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_image_builder_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_image_builder_request(
+        &self,
+        input: DeleteImageBuilderRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "appstream", &self.region, "/");
+        request.set_operation("DeleteImageBuilder");
         request.set_endpoint_prefix("appstream2".to_string());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "PhotonAdminProxyService.DeleteImageBuilder");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `delete_image_permissions`, also returning the metadata of the response.
+    pub async fn delete_image_permissions_with_metadata(
+        &self,
+        input: DeleteImagePermissionsRequest,
+    ) -> Result<
+        (DeleteImagePermissionsResult, ResponseMetadata),
+        RusotoError<DeleteImagePermissionsError>,
+    > {
+        let request = self.build_delete_image_permissions_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DeleteImageBuilderResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DeleteImagePermissionsResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteImageBuilderError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DeleteImagePermissionsError::from_response(response))
         }
     }
 
-    /// <p>Deletes permissions for the specified private image. After you delete permissions for an image, AWS accounts to which you previously granted these permissions can no longer use the image.</p>
-    async fn delete_image_permissions(
+    /// Presigns the request `delete_image_permissions` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_image_permissions(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteImagePermissionsRequest,
-    ) -> Result<DeleteImagePermissionsResult, RusotoError<DeleteImagePermissionsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_image_permissions_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_image_permissions_request(
+        &self,
+        input: DeleteImagePermissionsRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "appstream", &self.region, "/");
+        request.set_operation("DeleteImagePermissions");
         request.set_endpoint_prefix("appstream2".to_string());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -4903,55 +5836,105 @@ impl AppStream for AppStreamClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `delete_stack`, also returning the metadata of the response.
+    pub async fn delete_stack_with_metadata(
+        &self,
+        input: DeleteStackRequest,
+    ) -> Result<(DeleteStackResult, ResponseMetadata), RusotoError<DeleteStackError>> {
+        let request = self.build_delete_stack_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DeleteImagePermissionsResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DeleteStackResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteImagePermissionsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DeleteStackError::from_response(response))
         }
     }
 
-    /// <p>Deletes the specified stack. After the stack is deleted, the application streaming environment provided by the stack is no longer available to users. Also, any reservations made for application streaming sessions for the stack are released.</p>
-    async fn delete_stack(
+    /// Presigns the request `delete_stack` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_stack(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteStackRequest,
-    ) -> Result<DeleteStackResult, RusotoError<DeleteStackError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_stack_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_stack_request(&self, input: DeleteStackRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "appstream", &self.region, "/");
+        request.set_operation("DeleteStack");
         request.set_endpoint_prefix("appstream2".to_string());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "PhotonAdminProxyService.DeleteStack");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `delete_usage_report_subscription`, also returning the metadata of the response.
+    pub async fn delete_usage_report_subscription_with_metadata(
+        &self,
+    ) -> Result<
+        (DeleteUsageReportSubscriptionResult, ResponseMetadata),
+        RusotoError<DeleteUsageReportSubscriptionError>,
+    > {
+        let request = self.build_delete_usage_report_subscription_request();
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<DeleteStackResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DeleteUsageReportSubscriptionResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteStackError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DeleteUsageReportSubscriptionError::from_response(response))
         }
     }
 
-    /// <p>Disables usage report generation.</p>
-    async fn delete_usage_report_subscription(
+    /// Presigns the request `delete_usage_report_subscription` would send with `presigner` instead of sending it.
+    pub async fn presign_delete_usage_report_subscription(
         &self,
-    ) -> Result<DeleteUsageReportSubscriptionResult, RusotoError<DeleteUsageReportSubscriptionError>>
-    {
+        presigner: &rusoto_core::Presigner,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_usage_report_subscription_request();
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_usage_report_subscription_request(&self) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "appstream", &self.region, "/");
+        request.set_operation("DeleteUsageReportSubscription");
         request.set_endpoint_prefix("appstream2".to_string());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -4960,55 +5943,110 @@ impl AppStream for AppStreamClient {
         );
         request.set_payload(Some(bytes::Bytes::from_static(b"{}")));
 
+        request
+    }
+
+    /// Calls `delete_user`, also returning the metadata of the response.
+    pub async fn delete_user_with_metadata(
+        &self,
+        input: DeleteUserRequest,
+    ) -> Result<(DeleteUserResult, ResponseMetadata), RusotoError<DeleteUserError>> {
+        let request = self.build_delete_user_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DeleteUsageReportSubscriptionResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DeleteUserResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteUsageReportSubscriptionError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DeleteUserError::from_response(response))
         }
     }
 
-    /// <p>Deletes a user from the user pool.</p>
-    async fn delete_user(
+    /// Presigns the request `delete_user` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_user(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteUserRequest,
-    ) -> Result<DeleteUserResult, RusotoError<DeleteUserError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_user_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_user_request(&self, input: DeleteUserRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "appstream", &self.region, "/");
+        request.set_operation("DeleteUser");
         request.set_endpoint_prefix("appstream2".to_string());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "PhotonAdminProxyService.DeleteUser");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `describe_directory_configs`, also returning the metadata of the response.
+    pub async fn describe_directory_configs_with_metadata(
+        &self,
+        input: DescribeDirectoryConfigsRequest,
+    ) -> Result<
+        (DescribeDirectoryConfigsResult, ResponseMetadata),
+        RusotoError<DescribeDirectoryConfigsError>,
+    > {
+        let request = self.build_describe_directory_configs_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<DeleteUserResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DescribeDirectoryConfigsResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteUserError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DescribeDirectoryConfigsError::from_response(response))
         }
     }
 
-    /// <p>Retrieves a list that describes one or more specified Directory Config objects for AppStream 2.0, if the names for these objects are provided. Otherwise, all Directory Config objects in the account are described. These objects include the configuration information required to join fleets and image builders to Microsoft Active Directory domains. </p> <p>Although the response syntax in this topic includes the account password, this password is not returned in the actual response.</p>
-    async fn describe_directory_configs(
+    /// Presigns the request `describe_directory_configs` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_directory_configs(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeDirectoryConfigsRequest,
-    ) -> Result<DescribeDirectoryConfigsResult, RusotoError<DescribeDirectoryConfigsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_directory_configs_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_directory_configs_request(
+        &self,
+        input: DescribeDirectoryConfigsRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "appstream", &self.region, "/");
+        request.set_operation("DescribeDirectoryConfigs");
         request.set_endpoint_prefix("appstream2".to_string());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -5018,55 +6056,110 @@ impl AppStream for AppStreamClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `describe_fleets`, also returning the metadata of the response.
+    pub async fn describe_fleets_with_metadata(
+        &self,
+        input: DescribeFleetsRequest,
+    ) -> Result<(DescribeFleetsResult, ResponseMetadata), RusotoError<DescribeFleetsError>> {
+        let request = self.build_describe_fleets_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DescribeDirectoryConfigsResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DescribeFleetsResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeDirectoryConfigsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DescribeFleetsError::from_response(response))
         }
     }
 
-    /// <p>Retrieves a list that describes one or more specified fleets, if the fleet names are provided. Otherwise, all fleets in the account are described.</p>
-    async fn describe_fleets(
+    /// Presigns the request `describe_fleets` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_fleets(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeFleetsRequest,
-    ) -> Result<DescribeFleetsResult, RusotoError<DescribeFleetsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_fleets_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_fleets_request(&self, input: DescribeFleetsRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "appstream", &self.region, "/");
+        request.set_operation("DescribeFleets");
         request.set_endpoint_prefix("appstream2".to_string());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "PhotonAdminProxyService.DescribeFleets");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `describe_image_builders`, also returning the metadata of the response.
+    pub async fn describe_image_builders_with_metadata(
+        &self,
+        input: DescribeImageBuildersRequest,
+    ) -> Result<
+        (DescribeImageBuildersResult, ResponseMetadata),
+        RusotoError<DescribeImageBuildersError>,
+    > {
+        let request = self.build_describe_image_builders_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<DescribeFleetsResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DescribeImageBuildersResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeFleetsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DescribeImageBuildersError::from_response(response))
         }
     }
 
-    /// <p>Retrieves a list that describes one or more specified image builders, if the image builder names are provided. Otherwise, all image builders in the account are described.</p>
-    async fn describe_image_builders(
+    /// Presigns the request `describe_image_builders` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_image_builders(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeImageBuildersRequest,
-    ) -> Result<DescribeImageBuildersResult, RusotoError<DescribeImageBuildersError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_image_builders_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_image_builders_request(
+        &self,
+        input: DescribeImageBuildersRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "appstream", &self.region, "/");
+        request.set_operation("DescribeImageBuilders");
         request.set_endpoint_prefix("appstream2".to_string());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -5076,28 +6169,58 @@ impl AppStream for AppStreamClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `describe_image_permissions`, also returning the metadata of the response.
+    pub async fn describe_image_permissions_with_metadata(
+        &self,
+        input: DescribeImagePermissionsRequest,
+    ) -> Result<
+        (DescribeImagePermissionsResult, ResponseMetadata),
+        RusotoError<DescribeImagePermissionsError>,
+    > {
+        let request = self.build_describe_image_permissions_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DescribeImageBuildersResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DescribeImagePermissionsResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeImageBuildersError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DescribeImagePermissionsError::from_response(response))
         }
     }
 
-    /// <p>Retrieves a list that describes the permissions for shared AWS account IDs on a private image that you own. </p>
-    async fn describe_image_permissions(
+    /// Presigns the request `describe_image_permissions` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_image_permissions(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeImagePermissionsRequest,
-    ) -> Result<DescribeImagePermissionsResult, RusotoError<DescribeImagePermissionsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_image_permissions_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_image_permissions_request(
+        &self,
+        input: DescribeImagePermissionsRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "appstream", &self.region, "/");
+        request.set_operation("DescribeImagePermissions");
         request.set_endpoint_prefix("appstream2".to_string());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -5107,112 +6230,217 @@ impl AppStream for AppStreamClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `describe_images`, also returning the metadata of the response.
+    pub async fn describe_images_with_metadata(
+        &self,
+        input: DescribeImagesRequest,
+    ) -> Result<(DescribeImagesResult, ResponseMetadata), RusotoError<DescribeImagesError>> {
+        let request = self.build_describe_images_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DescribeImagePermissionsResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DescribeImagesResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeImagePermissionsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DescribeImagesError::from_response(response))
         }
     }
 
-    /// <p>Retrieves a list that describes one or more specified images, if the image names or image ARNs are provided. Otherwise, all images in the account are described.</p>
-    async fn describe_images(
+    /// Presigns the request `describe_images` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_images(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeImagesRequest,
-    ) -> Result<DescribeImagesResult, RusotoError<DescribeImagesError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_images_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_images_request(&self, input: DescribeImagesRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "appstream", &self.region, "/");
+        request.set_operation("DescribeImages");
         request.set_endpoint_prefix("appstream2".to_string());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "PhotonAdminProxyService.DescribeImages");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `describe_sessions`, also returning the metadata of the response.
+    pub async fn describe_sessions_with_metadata(
+        &self,
+        input: DescribeSessionsRequest,
+    ) -> Result<(DescribeSessionsResult, ResponseMetadata), RusotoError<DescribeSessionsError>>
+    {
+        let request = self.build_describe_sessions_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<DescribeImagesResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DescribeSessionsResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeImagesError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DescribeSessionsError::from_response(response))
         }
     }
 
-    /// <p>Retrieves a list that describes the streaming sessions for a specified stack and fleet. If a UserId is provided for the stack and fleet, only streaming sessions for that user are described. If an authentication type is not provided, the default is to authenticate users using a streaming URL.</p>
-    async fn describe_sessions(
+    /// Presigns the request `describe_sessions` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_sessions(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeSessionsRequest,
-    ) -> Result<DescribeSessionsResult, RusotoError<DescribeSessionsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_sessions_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_sessions_request(&self, input: DescribeSessionsRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "appstream", &self.region, "/");
+        request.set_operation("DescribeSessions");
         request.set_endpoint_prefix("appstream2".to_string());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "PhotonAdminProxyService.DescribeSessions");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
-        let mut response = self
-            .client
+        request
+    }
+
+    /// Calls `describe_stacks`, also returning the metadata of the response.
+    pub async fn describe_stacks_with_metadata(
+        &self,
+        input: DescribeStacksRequest,
+    ) -> Result<(DescribeStacksResult, ResponseMetadata), RusotoError<DescribeStacksError>> {
+        let request = self.build_describe_stacks_request(input);
+
+        let mut response = self
+            .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<DescribeSessionsResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DescribeStacksResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeSessionsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DescribeStacksError::from_response(response))
         }
     }
 
-    /// <p>Retrieves a list that describes one or more specified stacks, if the stack names are provided. Otherwise, all stacks in the account are described.</p>
-    async fn describe_stacks(
+    /// Presigns the request `describe_stacks` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_stacks(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeStacksRequest,
-    ) -> Result<DescribeStacksResult, RusotoError<DescribeStacksError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_stacks_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_stacks_request(&self, input: DescribeStacksRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "appstream", &self.region, "/");
+        request.set_operation("DescribeStacks");
         request.set_endpoint_prefix("appstream2".to_string());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "PhotonAdminProxyService.DescribeStacks");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `describe_usage_report_subscriptions`, also returning the metadata of the response.
+    pub async fn describe_usage_report_subscriptions_with_metadata(
+        &self,
+        input: DescribeUsageReportSubscriptionsRequest,
+    ) -> Result<
+        (DescribeUsageReportSubscriptionsResult, ResponseMetadata),
+        RusotoError<DescribeUsageReportSubscriptionsError>,
+    > {
+        let request = self.build_describe_usage_report_subscriptions_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<DescribeStacksResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DescribeUsageReportSubscriptionsResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeStacksError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DescribeUsageReportSubscriptionsError::from_response(
+                response,
+            ))
         }
     }
 
-    /// <p>Retrieves a list that describes one or more usage report subscriptions.</p>
-    async fn describe_usage_report_subscriptions(
+    /// Presigns the request `describe_usage_report_subscriptions` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_usage_report_subscriptions(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeUsageReportSubscriptionsRequest,
-    ) -> Result<
-        DescribeUsageReportSubscriptionsResult,
-        RusotoError<DescribeUsageReportSubscriptionsError>,
-    > {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_usage_report_subscriptions_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_usage_report_subscriptions_request(
+        &self,
+        input: DescribeUsageReportSubscriptionsRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "appstream", &self.region, "/");
+        request.set_operation("DescribeUsageReportSubscriptions");
         request.set_endpoint_prefix("appstream2".to_string());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -5222,31 +6450,58 @@ impl AppStream for AppStreamClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `describe_user_stack_associations`, also returning the metadata of the response.
+    pub async fn describe_user_stack_associations_with_metadata(
+        &self,
+        input: DescribeUserStackAssociationsRequest,
+    ) -> Result<
+        (DescribeUserStackAssociationsResult, ResponseMetadata),
+        RusotoError<DescribeUserStackAssociationsError>,
+    > {
+        let request = self.build_describe_user_stack_associations_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DescribeUsageReportSubscriptionsResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DescribeUserStackAssociationsResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeUsageReportSubscriptionsError::from_response(
-                response,
-            ))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DescribeUserStackAssociationsError::from_response(response))
         }
     }
 
-    /// <p><p>Retrieves a list that describes the UserStackAssociation objects. You must specify either or both of the following:</p> <ul> <li> <p>The stack name</p> </li> <li> <p>The user name (email address of the user associated with the stack) and the authentication type for the user</p> </li> </ul></p>
-    async fn describe_user_stack_associations(
+    /// Presigns the request `describe_user_stack_associations` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_user_stack_associations(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeUserStackAssociationsRequest,
-    ) -> Result<DescribeUserStackAssociationsResult, RusotoError<DescribeUserStackAssociationsError>>
-    {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_user_stack_associations_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_user_stack_associations_request(
+        &self,
+        input: DescribeUserStackAssociationsRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "appstream", &self.region, "/");
+        request.set_operation("DescribeUserStackAssociations");
         request.set_endpoint_prefix("appstream2".to_string());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -5256,163 +6511,319 @@ impl AppStream for AppStreamClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `describe_users`, also returning the metadata of the response.
+    pub async fn describe_users_with_metadata(
+        &self,
+        input: DescribeUsersRequest,
+    ) -> Result<(DescribeUsersResult, ResponseMetadata), RusotoError<DescribeUsersError>> {
+        let request = self.build_describe_users_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DescribeUserStackAssociationsResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DescribeUsersResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeUserStackAssociationsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DescribeUsersError::from_response(response))
         }
     }
 
-    /// <p>Retrieves a list that describes one or more specified users in the user pool.</p>
-    async fn describe_users(
+    /// Presigns the request `describe_users` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_users(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeUsersRequest,
-    ) -> Result<DescribeUsersResult, RusotoError<DescribeUsersError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_users_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_users_request(&self, input: DescribeUsersRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "appstream", &self.region, "/");
+        request.set_operation("DescribeUsers");
         request.set_endpoint_prefix("appstream2".to_string());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "PhotonAdminProxyService.DescribeUsers");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `disable_user`, also returning the metadata of the response.
+    pub async fn disable_user_with_metadata(
+        &self,
+        input: DisableUserRequest,
+    ) -> Result<(DisableUserResult, ResponseMetadata), RusotoError<DisableUserError>> {
+        let request = self.build_disable_user_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<DescribeUsersResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DisableUserResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeUsersError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DisableUserError::from_response(response))
         }
     }
 
-    /// <p>Disables the specified user in the user pool. Users can't sign in to AppStream 2.0 until they are re-enabled. This action does not delete the user. </p>
-    async fn disable_user(
+    /// Presigns the request `disable_user` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_disable_user(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DisableUserRequest,
-    ) -> Result<DisableUserResult, RusotoError<DisableUserError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_disable_user_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_disable_user_request(&self, input: DisableUserRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "appstream", &self.region, "/");
+        request.set_operation("DisableUser");
         request.set_endpoint_prefix("appstream2".to_string());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "PhotonAdminProxyService.DisableUser");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `disassociate_fleet`, also returning the metadata of the response.
+    pub async fn disassociate_fleet_with_metadata(
+        &self,
+        input: DisassociateFleetRequest,
+    ) -> Result<(DisassociateFleetResult, ResponseMetadata), RusotoError<DisassociateFleetError>>
+    {
+        let request = self.build_disassociate_fleet_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<DisableUserResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DisassociateFleetResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DisableUserError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DisassociateFleetError::from_response(response))
         }
     }
 
-    /// <p>Disassociates the specified fleet from the specified stack.</p>
-    async fn disassociate_fleet(
+    /// Presigns the request `disassociate_fleet` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_disassociate_fleet(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DisassociateFleetRequest,
-    ) -> Result<DisassociateFleetResult, RusotoError<DisassociateFleetError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_disassociate_fleet_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_disassociate_fleet_request(&self, input: DisassociateFleetRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "appstream", &self.region, "/");
+        request.set_operation("DisassociateFleet");
         request.set_endpoint_prefix("appstream2".to_string());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "PhotonAdminProxyService.DisassociateFleet");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `enable_user`, also returning the metadata of the response.
+    pub async fn enable_user_with_metadata(
+        &self,
+        input: EnableUserRequest,
+    ) -> Result<(EnableUserResult, ResponseMetadata), RusotoError<EnableUserError>> {
+        let request = self.build_enable_user_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<DisassociateFleetResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<EnableUserResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DisassociateFleetError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(EnableUserError::from_response(response))
         }
     }
 
-    /// <p>Enables a user in the user pool. After being enabled, users can sign in to AppStream 2.0 and open applications from the stacks to which they are assigned.</p>
-    async fn enable_user(
+    /// Presigns the request `enable_user` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_enable_user(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: EnableUserRequest,
-    ) -> Result<EnableUserResult, RusotoError<EnableUserError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_enable_user_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_enable_user_request(&self, input: EnableUserRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "appstream", &self.region, "/");
+        request.set_operation("EnableUser");
         request.set_endpoint_prefix("appstream2".to_string());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "PhotonAdminProxyService.EnableUser");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `expire_session`, also returning the metadata of the response.
+    pub async fn expire_session_with_metadata(
+        &self,
+        input: ExpireSessionRequest,
+    ) -> Result<(ExpireSessionResult, ResponseMetadata), RusotoError<ExpireSessionError>> {
+        let request = self.build_expire_session_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<EnableUserResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ExpireSessionResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(EnableUserError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ExpireSessionError::from_response(response))
         }
     }
 
-    /// <p>Immediately stops the specified streaming session.</p>
-    async fn expire_session(
+    /// Presigns the request `expire_session` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_expire_session(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ExpireSessionRequest,
-    ) -> Result<ExpireSessionResult, RusotoError<ExpireSessionError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_expire_session_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_expire_session_request(&self, input: ExpireSessionRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "appstream", &self.region, "/");
+        request.set_operation("ExpireSession");
         request.set_endpoint_prefix("appstream2".to_string());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "PhotonAdminProxyService.ExpireSession");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `list_associated_fleets`, also returning the metadata of the response.
+    pub async fn list_associated_fleets_with_metadata(
+        &self,
+        input: ListAssociatedFleetsRequest,
+    ) -> Result<
+        (ListAssociatedFleetsResult, ResponseMetadata),
+        RusotoError<ListAssociatedFleetsError>,
+    > {
+        let request = self.build_list_associated_fleets_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<ExpireSessionResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ListAssociatedFleetsResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ExpireSessionError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ListAssociatedFleetsError::from_response(response))
         }
     }
 
-    /// <p>Retrieves the name of the fleet that is associated with the specified stack.</p>
-    async fn list_associated_fleets(
+    /// Presigns the request `list_associated_fleets` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_associated_fleets(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListAssociatedFleetsRequest,
-    ) -> Result<ListAssociatedFleetsResult, RusotoError<ListAssociatedFleetsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_associated_fleets_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_associated_fleets_request(
+        &self,
+        input: ListAssociatedFleetsRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "appstream", &self.region, "/");
+        request.set_operation("ListAssociatedFleets");
         request.set_endpoint_prefix("appstream2".to_string());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -5422,28 +6833,58 @@ impl AppStream for AppStreamClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `list_associated_stacks`, also returning the metadata of the response.
+    pub async fn list_associated_stacks_with_metadata(
+        &self,
+        input: ListAssociatedStacksRequest,
+    ) -> Result<
+        (ListAssociatedStacksResult, ResponseMetadata),
+        RusotoError<ListAssociatedStacksError>,
+    > {
+        let request = self.build_list_associated_stacks_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListAssociatedFleetsResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ListAssociatedStacksResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ListAssociatedFleetsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ListAssociatedStacksError::from_response(response))
         }
     }
 
-    /// <p>Retrieves the name of the stack with which the specified fleet is associated.</p>
-    async fn list_associated_stacks(
+    /// Presigns the request `list_associated_stacks` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_associated_stacks(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListAssociatedStacksRequest,
-    ) -> Result<ListAssociatedStacksResult, RusotoError<ListAssociatedStacksError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_associated_stacks_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_associated_stacks_request(
+        &self,
+        input: ListAssociatedStacksRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "appstream", &self.region, "/");
+        request.set_operation("ListAssociatedStacks");
         request.set_endpoint_prefix("appstream2".to_string());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -5453,28 +6894,58 @@ impl AppStream for AppStreamClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `list_tags_for_resource`, also returning the metadata of the response.
+    pub async fn list_tags_for_resource_with_metadata(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> Result<
+        (ListTagsForResourceResponse, ResponseMetadata),
+        RusotoError<ListTagsForResourceError>,
+    > {
+        let request = self.build_list_tags_for_resource_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListAssociatedStacksResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ListTagsForResourceResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ListAssociatedStacksError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ListTagsForResourceError::from_response(response))
         }
     }
 
-    /// <p>Retrieves a list of all tags for the specified AppStream 2.0 resource. You can tag AppStream 2.0 image builders, images, fleets, and stacks.</p> <p>For more information about tags, see <a href="https://docs.aws.amazon.com/appstream2/latest/developerguide/tagging-basic.html">Tagging Your Resources</a> in the <i>Amazon AppStream 2.0 Administration Guide</i>.</p>
-    async fn list_tags_for_resource(
+    /// Presigns the request `list_tags_for_resource` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_tags_for_resource(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListTagsForResourceRequest,
-    ) -> Result<ListTagsForResourceResponse, RusotoError<ListTagsForResourceError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_tags_for_resource_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_tags_for_resource_request(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "appstream", &self.region, "/");
+        request.set_operation("ListTagsForResource");
         request.set_endpoint_prefix("appstream2".to_string());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -5484,190 +6955,372 @@ impl AppStream for AppStreamClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `start_fleet`, also returning the metadata of the response.
+    pub async fn start_fleet_with_metadata(
+        &self,
+        input: StartFleetRequest,
+    ) -> Result<(StartFleetResult, ResponseMetadata), RusotoError<StartFleetError>> {
+        let request = self.build_start_fleet_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListTagsForResourceResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<StartFleetResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ListTagsForResourceError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(StartFleetError::from_response(response))
         }
     }
 
-    /// <p>Starts the specified fleet.</p>
-    async fn start_fleet(
+    /// Presigns the request `start_fleet` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_start_fleet(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: StartFleetRequest,
-    ) -> Result<StartFleetResult, RusotoError<StartFleetError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_start_fleet_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_start_fleet_request(&self, input: StartFleetRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "appstream", &self.region, "/");
+        request.set_operation("StartFleet");
         request.set_endpoint_prefix("appstream2".to_string());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "PhotonAdminProxyService.StartFleet");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `start_image_builder`, also returning the metadata of the response.
+    pub async fn start_image_builder_with_metadata(
+        &self,
+        input: StartImageBuilderRequest,
+    ) -> Result<(StartImageBuilderResult, ResponseMetadata), RusotoError<StartImageBuilderError>>
+    {
+        let request = self.build_start_image_builder_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<StartFleetResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<StartImageBuilderResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(StartFleetError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(StartImageBuilderError::from_response(response))
         }
     }
 
-    /// <p>Starts the specified image builder.</p>
-    async fn start_image_builder(
+    /// Presigns the request `start_image_builder` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_start_image_builder(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: StartImageBuilderRequest,
-    ) -> Result<StartImageBuilderResult, RusotoError<StartImageBuilderError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_start_image_builder_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_start_image_builder_request(&self, input: StartImageBuilderRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "appstream", &self.region, "/");
+        request.set_operation("StartImageBuilder");
         request.set_endpoint_prefix("appstream2".to_string());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "PhotonAdminProxyService.StartImageBuilder");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `stop_fleet`, also returning the metadata of the response.
+    pub async fn stop_fleet_with_metadata(
+        &self,
+        input: StopFleetRequest,
+    ) -> Result<(StopFleetResult, ResponseMetadata), RusotoError<StopFleetError>> {
+        let request = self.build_stop_fleet_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<StartImageBuilderResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result =
+                proto::json::ResponsePayload::new(&response).deserialize::<StopFleetResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(StartImageBuilderError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(StopFleetError::from_response(response))
         }
     }
 
-    /// <p>Stops the specified fleet.</p>
-    async fn stop_fleet(
+    /// Presigns the request `stop_fleet` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_stop_fleet(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: StopFleetRequest,
-    ) -> Result<StopFleetResult, RusotoError<StopFleetError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_stop_fleet_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_stop_fleet_request(&self, input: StopFleetRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "appstream", &self.region, "/");
+        request.set_operation("StopFleet");
         request.set_endpoint_prefix("appstream2".to_string());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "PhotonAdminProxyService.StopFleet");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `stop_image_builder`, also returning the metadata of the response.
+    pub async fn stop_image_builder_with_metadata(
+        &self,
+        input: StopImageBuilderRequest,
+    ) -> Result<(StopImageBuilderResult, ResponseMetadata), RusotoError<StopImageBuilderError>>
+    {
+        let request = self.build_stop_image_builder_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<StopFleetResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<StopImageBuilderResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(StopFleetError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(StopImageBuilderError::from_response(response))
         }
     }
 
-    /// <p>Stops the specified image builder.</p>
-    async fn stop_image_builder(
+    /// Presigns the request `stop_image_builder` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_stop_image_builder(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: StopImageBuilderRequest,
-    ) -> Result<StopImageBuilderResult, RusotoError<StopImageBuilderError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_stop_image_builder_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_stop_image_builder_request(&self, input: StopImageBuilderRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "appstream", &self.region, "/");
+        request.set_operation("StopImageBuilder");
         request.set_endpoint_prefix("appstream2".to_string());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "PhotonAdminProxyService.StopImageBuilder");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `tag_resource`, also returning the metadata of the response.
+    pub async fn tag_resource_with_metadata(
+        &self,
+        input: TagResourceRequest,
+    ) -> Result<(TagResourceResponse, ResponseMetadata), RusotoError<TagResourceError>> {
+        let request = self.build_tag_resource_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<StopImageBuilderResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<TagResourceResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(StopImageBuilderError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(TagResourceError::from_response(response))
         }
     }
 
-    /// <p>Adds or overwrites one or more tags for the specified AppStream 2.0 resource. You can tag AppStream 2.0 image builders, images, fleets, and stacks.</p> <p>Each tag consists of a key and an optional value. If a resource already has a tag with the same key, this operation updates its value.</p> <p>To list the current tags for your resources, use <a>ListTagsForResource</a>. To disassociate tags from your resources, use <a>UntagResource</a>.</p> <p>For more information about tags, see <a href="https://docs.aws.amazon.com/appstream2/latest/developerguide/tagging-basic.html">Tagging Your Resources</a> in the <i>Amazon AppStream 2.0 Administration Guide</i>.</p>
-    async fn tag_resource(
+    /// Presigns the request `tag_resource` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_tag_resource(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: TagResourceRequest,
-    ) -> Result<TagResourceResponse, RusotoError<TagResourceError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_tag_resource_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_tag_resource_request(&self, input: TagResourceRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "appstream", &self.region, "/");
+        request.set_operation("TagResource");
         request.set_endpoint_prefix("appstream2".to_string());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "PhotonAdminProxyService.TagResource");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `untag_resource`, also returning the metadata of the response.
+    pub async fn untag_resource_with_metadata(
+        &self,
+        input: UntagResourceRequest,
+    ) -> Result<(UntagResourceResponse, ResponseMetadata), RusotoError<UntagResourceError>> {
+        let request = self.build_untag_resource_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<TagResourceResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<UntagResourceResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(TagResourceError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(UntagResourceError::from_response(response))
         }
     }
 
-    /// <p>Disassociates one or more specified tags from the specified AppStream 2.0 resource.</p> <p>To list the current tags for your resources, use <a>ListTagsForResource</a>.</p> <p>For more information about tags, see <a href="https://docs.aws.amazon.com/appstream2/latest/developerguide/tagging-basic.html">Tagging Your Resources</a> in the <i>Amazon AppStream 2.0 Administration Guide</i>.</p>
-    async fn untag_resource(
+    /// Presigns the request `untag_resource` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_untag_resource(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UntagResourceRequest,
-    ) -> Result<UntagResourceResponse, RusotoError<UntagResourceError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_untag_resource_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_untag_resource_request(&self, input: UntagResourceRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "appstream", &self.region, "/");
+        request.set_operation("UntagResource");
         request.set_endpoint_prefix("appstream2".to_string());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "PhotonAdminProxyService.UntagResource");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `update_directory_config`, also returning the metadata of the response.
+    pub async fn update_directory_config_with_metadata(
+        &self,
+        input: UpdateDirectoryConfigRequest,
+    ) -> Result<
+        (UpdateDirectoryConfigResult, ResponseMetadata),
+        RusotoError<UpdateDirectoryConfigError>,
+    > {
+        let request = self.build_update_directory_config_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<UntagResourceResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<UpdateDirectoryConfigResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(UntagResourceError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(UpdateDirectoryConfigError::from_response(response))
         }
     }
 
-    /// <p>Updates the specified Directory Config object in AppStream 2.0. This object includes the configuration information required to join fleets and image builders to Microsoft Active Directory domains.</p>
-    async fn update_directory_config(
+    /// Presigns the request `update_directory_config` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_directory_config(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UpdateDirectoryConfigRequest,
-    ) -> Result<UpdateDirectoryConfigResult, RusotoError<UpdateDirectoryConfigError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_directory_config_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_directory_config_request(
+        &self,
+        input: UpdateDirectoryConfigRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "appstream", &self.region, "/");
+        request.set_operation("UpdateDirectoryConfig");
         request.set_endpoint_prefix("appstream2".to_string());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -5677,55 +7330,110 @@ impl AppStream for AppStreamClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
-        let mut response = self
-            .client
+        request
+    }
+
+    /// Calls `update_fleet`, also returning the metadata of the response.
+    pub async fn update_fleet_with_metadata(
+        &self,
+        input: UpdateFleetRequest,
+    ) -> Result<(UpdateFleetResult, ResponseMetadata), RusotoError<UpdateFleetError>> {
+        let request = self.build_update_fleet_request(input);
+
+        let mut response = self
+            .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<UpdateDirectoryConfigResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<UpdateFleetResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(UpdateDirectoryConfigError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(UpdateFleetError::from_response(response))
         }
     }
 
-    /// <p>Updates the specified fleet.</p> <p>If the fleet is in the <code>STOPPED</code> state, you can update any attribute except the fleet name. If the fleet is in the <code>RUNNING</code> state, you can update the <code>DisplayName</code>, <code>ComputeCapacity</code>, <code>ImageARN</code>, <code>ImageName</code>, <code>IdleDisconnectTimeoutInSeconds</code>, and <code>DisconnectTimeoutInSeconds</code> attributes. If the fleet is in the <code>STARTING</code> or <code>STOPPING</code> state, you can't update it.</p>
-    async fn update_fleet(
+    /// Presigns the request `update_fleet` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_fleet(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UpdateFleetRequest,
-    ) -> Result<UpdateFleetResult, RusotoError<UpdateFleetError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_fleet_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_fleet_request(&self, input: UpdateFleetRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "appstream", &self.region, "/");
+        request.set_operation("UpdateFleet");
         request.set_endpoint_prefix("appstream2".to_string());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "PhotonAdminProxyService.UpdateFleet");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `update_image_permissions`, also returning the metadata of the response.
+    pub async fn update_image_permissions_with_metadata(
+        &self,
+        input: UpdateImagePermissionsRequest,
+    ) -> Result<
+        (UpdateImagePermissionsResult, ResponseMetadata),
+        RusotoError<UpdateImagePermissionsError>,
+    > {
+        let request = self.build_update_image_permissions_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<UpdateFleetResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<UpdateImagePermissionsResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(UpdateFleetError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(UpdateImagePermissionsError::from_response(response))
         }
     }
 
-    /// <p>Adds or updates permissions for the specified private image. </p>
-    async fn update_image_permissions(
+    /// Presigns the request `update_image_permissions` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_image_permissions(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UpdateImagePermissionsRequest,
-    ) -> Result<UpdateImagePermissionsResult, RusotoError<UpdateImagePermissionsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_image_permissions_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_image_permissions_request(
+        &self,
+        input: UpdateImagePermissionsRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "appstream", &self.region, "/");
+        request.set_operation("UpdateImagePermissions");
         request.set_endpoint_prefix("appstream2".to_string());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -5735,46 +7443,1023 @@ impl AppStream for AppStreamClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `update_stack`, also returning the metadata of the response.
+    pub async fn update_stack_with_metadata(
+        &self,
+        input: UpdateStackRequest,
+    ) -> Result<(UpdateStackResult, ResponseMetadata), RusotoError<UpdateStackError>> {
+        let request = self.build_update_stack_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<UpdateImagePermissionsResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<UpdateStackResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(UpdateImagePermissionsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(UpdateStackError::from_response(response))
         }
     }
 
-    /// <p>Updates the specified fields for the specified stack.</p>
-    async fn update_stack(
+    /// Presigns the request `update_stack` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_stack(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UpdateStackRequest,
-    ) -> Result<UpdateStackResult, RusotoError<UpdateStackError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_stack_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_stack_request(&self, input: UpdateStackRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "appstream", &self.region, "/");
+        request.set_operation("UpdateStack");
         request.set_endpoint_prefix("appstream2".to_string());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "PhotonAdminProxyService.UpdateStack");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
-        let mut response = self
-            .client
-            .sign_and_dispatch(request)
+        request
+    }
+
+    /// The pages of `DescribeDirectoryConfigs` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `MaxResults` of `input`.
+    pub fn describe_directory_configs_pages(
+        &self,
+        input: DescribeDirectoryConfigsRequest,
+    ) -> impl futures::Stream<
+        Item = Result<DescribeDirectoryConfigsResult, RusotoError<DescribeDirectoryConfigsError>>,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: AppStreamClient, input| async move {
+                client.describe_directory_configs(input).await
+            },
+            |input: &DescribeDirectoryConfigsRequest, output: &DescribeDirectoryConfigsResult| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(DescribeDirectoryConfigsRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `DirectoryConfigs` of every page of `DescribeDirectoryConfigs` results for `input`, as `describe_directory_configs_pages` requests them.
+    pub fn describe_directory_configs_stream(
+        &self,
+        input: DescribeDirectoryConfigsRequest,
+    ) -> impl futures::Stream<
+        Item = Result<DirectoryConfig, RusotoError<DescribeDirectoryConfigsError>>,
+    > + Send {
+        rusoto_core::pagination::items(self.describe_directory_configs_pages(input), |page| {
+            page.directory_configs.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `DescribeFleets` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    pub fn describe_fleets_pages(
+        &self,
+        input: DescribeFleetsRequest,
+    ) -> impl futures::Stream<Item = Result<DescribeFleetsResult, RusotoError<DescribeFleetsError>>> + Send
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: AppStreamClient, input| async move { client.describe_fleets(input).await },
+            |input: &DescribeFleetsRequest, output: &DescribeFleetsResult| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(DescribeFleetsRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `Fleets` of every page of `DescribeFleets` results for `input`, as `describe_fleets_pages` requests them.
+    pub fn describe_fleets_stream(
+        &self,
+        input: DescribeFleetsRequest,
+    ) -> impl futures::Stream<Item = Result<Fleet, RusotoError<DescribeFleetsError>>> + Send {
+        rusoto_core::pagination::items(self.describe_fleets_pages(input), |page| {
+            page.fleets.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `DescribeImageBuilders` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `MaxResults` of `input`.
+    pub fn describe_image_builders_pages(
+        &self,
+        input: DescribeImageBuildersRequest,
+    ) -> impl futures::Stream<
+        Item = Result<DescribeImageBuildersResult, RusotoError<DescribeImageBuildersError>>,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: AppStreamClient, input| async move { client.describe_image_builders(input).await },
+            |input: &DescribeImageBuildersRequest, output: &DescribeImageBuildersResult| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(DescribeImageBuildersRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `ImageBuilders` of every page of `DescribeImageBuilders` results for `input`, as `describe_image_builders_pages` requests them.
+    pub fn describe_image_builders_stream(
+        &self,
+        input: DescribeImageBuildersRequest,
+    ) -> impl futures::Stream<Item = Result<ImageBuilder, RusotoError<DescribeImageBuildersError>>> + Send
+    {
+        rusoto_core::pagination::items(self.describe_image_builders_pages(input), |page| {
+            page.image_builders.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `DescribeImages` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `MaxResults` of `input`.
+    pub fn describe_images_pages(
+        &self,
+        input: DescribeImagesRequest,
+    ) -> impl futures::Stream<Item = Result<DescribeImagesResult, RusotoError<DescribeImagesError>>> + Send
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: AppStreamClient, input| async move { client.describe_images(input).await },
+            |input: &DescribeImagesRequest, output: &DescribeImagesResult| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(DescribeImagesRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `Images` of every page of `DescribeImages` results for `input`, as `describe_images_pages` requests them.
+    pub fn describe_images_stream(
+        &self,
+        input: DescribeImagesRequest,
+    ) -> impl futures::Stream<Item = Result<Image, RusotoError<DescribeImagesError>>> + Send {
+        rusoto_core::pagination::items(self.describe_images_pages(input), |page| {
+            page.images.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `DescribeSessions` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `Limit` of `input`.
+    pub fn describe_sessions_pages(
+        &self,
+        input: DescribeSessionsRequest,
+    ) -> impl futures::Stream<
+        Item = Result<DescribeSessionsResult, RusotoError<DescribeSessionsError>>,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: AppStreamClient, input| async move { client.describe_sessions(input).await },
+            |input: &DescribeSessionsRequest, output: &DescribeSessionsResult| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(DescribeSessionsRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `Sessions` of every page of `DescribeSessions` results for `input`, as `describe_sessions_pages` requests them.
+    pub fn describe_sessions_stream(
+        &self,
+        input: DescribeSessionsRequest,
+    ) -> impl futures::Stream<Item = Result<Session, RusotoError<DescribeSessionsError>>> + Send
+    {
+        rusoto_core::pagination::items(self.describe_sessions_pages(input), |page| {
+            page.sessions.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `DescribeStacks` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    pub fn describe_stacks_pages(
+        &self,
+        input: DescribeStacksRequest,
+    ) -> impl futures::Stream<Item = Result<DescribeStacksResult, RusotoError<DescribeStacksError>>> + Send
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: AppStreamClient, input| async move { client.describe_stacks(input).await },
+            |input: &DescribeStacksRequest, output: &DescribeStacksResult| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(DescribeStacksRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `Stacks` of every page of `DescribeStacks` results for `input`, as `describe_stacks_pages` requests them.
+    pub fn describe_stacks_stream(
+        &self,
+        input: DescribeStacksRequest,
+    ) -> impl futures::Stream<Item = Result<Stack, RusotoError<DescribeStacksError>>> + Send {
+        rusoto_core::pagination::items(self.describe_stacks_pages(input), |page| {
+            page.stacks.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `DescribeUserStackAssociations` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `MaxResults` of `input`.
+    pub fn describe_user_stack_associations_pages(
+        &self,
+        input: DescribeUserStackAssociationsRequest,
+    ) -> impl futures::Stream<
+        Item = Result<
+            DescribeUserStackAssociationsResult,
+            RusotoError<DescribeUserStackAssociationsError>,
+        >,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: AppStreamClient, input| async move {
+                client.describe_user_stack_associations(input).await
+            },
+            |input: &DescribeUserStackAssociationsRequest,
+             output: &DescribeUserStackAssociationsResult| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(DescribeUserStackAssociationsRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `UserStackAssociations` of every page of `DescribeUserStackAssociations` results for `input`, as `describe_user_stack_associations_pages` requests them.
+    pub fn describe_user_stack_associations_stream(
+        &self,
+        input: DescribeUserStackAssociationsRequest,
+    ) -> impl futures::Stream<
+        Item = Result<UserStackAssociation, RusotoError<DescribeUserStackAssociationsError>>,
+    > + Send {
+        rusoto_core::pagination::items(self.describe_user_stack_associations_pages(input), |page| {
+            page.user_stack_associations.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `DescribeUsers` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `MaxResults` of `input`.
+    pub fn describe_users_pages(
+        &self,
+        input: DescribeUsersRequest,
+    ) -> impl futures::Stream<Item = Result<DescribeUsersResult, RusotoError<DescribeUsersError>>> + Send
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: AppStreamClient, input| async move { client.describe_users(input).await },
+            |input: &DescribeUsersRequest, output: &DescribeUsersResult| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(DescribeUsersRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `Users` of every page of `DescribeUsers` results for `input`, as `describe_users_pages` requests them.
+    pub fn describe_users_stream(
+        &self,
+        input: DescribeUsersRequest,
+    ) -> impl futures::Stream<Item = Result<User, RusotoError<DescribeUsersError>>> + Send {
+        rusoto_core::pagination::items(self.describe_users_pages(input), |page| {
+            page.users.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `ListAssociatedFleets` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    pub fn list_associated_fleets_pages(
+        &self,
+        input: ListAssociatedFleetsRequest,
+    ) -> impl futures::Stream<
+        Item = Result<ListAssociatedFleetsResult, RusotoError<ListAssociatedFleetsError>>,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: AppStreamClient, input| async move { client.list_associated_fleets(input).await },
+            |input: &ListAssociatedFleetsRequest, output: &ListAssociatedFleetsResult| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListAssociatedFleetsRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `Names` of every page of `ListAssociatedFleets` results for `input`, as `list_associated_fleets_pages` requests them.
+    pub fn list_associated_fleets_stream(
+        &self,
+        input: ListAssociatedFleetsRequest,
+    ) -> impl futures::Stream<Item = Result<String, RusotoError<ListAssociatedFleetsError>>> + Send
+    {
+        rusoto_core::pagination::items(self.list_associated_fleets_pages(input), |page| {
+            page.names.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `ListAssociatedStacks` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    pub fn list_associated_stacks_pages(
+        &self,
+        input: ListAssociatedStacksRequest,
+    ) -> impl futures::Stream<
+        Item = Result<ListAssociatedStacksResult, RusotoError<ListAssociatedStacksError>>,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: AppStreamClient, input| async move { client.list_associated_stacks(input).await },
+            |input: &ListAssociatedStacksRequest, output: &ListAssociatedStacksResult| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListAssociatedStacksRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `Names` of every page of `ListAssociatedStacks` results for `input`, as `list_associated_stacks_pages` requests them.
+    pub fn list_associated_stacks_stream(
+        &self,
+        input: ListAssociatedStacksRequest,
+    ) -> impl futures::Stream<Item = Result<String, RusotoError<ListAssociatedStacksError>>> + Send
+    {
+        rusoto_core::pagination::items(self.list_associated_stacks_pages(input), |page| {
+            page.names.unwrap_or_default()
+        })
+    }
+
+    /// Waits for `FleetStarted`, polling `DescribeFleets` with `input` every 30 seconds, up to 40 times.
+    pub async fn wait_until_fleet_started(
+        &self,
+        input: DescribeFleetsRequest,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<DescribeFleetsError>> {
+        let waiter = rusoto_core::waiter::Waiter::new(std::time::Duration::from_secs(30), 40);
+        self.wait_until_fleet_started_with(input, &waiter).await
+    }
+
+    /// Waits for `FleetStarted` like `wait_until_fleet_started`, with the delay, attempts and runtime of `waiter`.
+    pub async fn wait_until_fleet_started_with(
+        &self,
+        input: DescribeFleetsRequest,
+        waiter: &rusoto_core::waiter::Waiter,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<DescribeFleetsError>> {
+        rusoto_core::waiter::wait(
+            waiter,
+            || self.describe_fleets(input.clone()),
+            |result| {
+                if match result {
+                    Ok(output) => {
+                        let mut values = std::iter::once(output)
+                            .filter_map(|value| value.fleets.as_ref())
+                            .flat_map(|value| value.iter())
+                            .map(|value| &value.state)
+                            .peekable();
+                        values.peek().is_some() && values.all(|value| value == "RUNNING")
+                    }
+                    Err(_) => false,
+                } {
+                    return Some(rusoto_core::waiter::WaiterState::Success);
+                }
+                if match result {
+                    Ok(output) => std::iter::once(output)
+                        .filter_map(|value| value.fleets.as_ref())
+                        .flat_map(|value| value.iter())
+                        .map(|value| &value.state)
+                        .any(|value| value == "STOPPING"),
+                    Err(_) => false,
+                } {
+                    return Some(rusoto_core::waiter::WaiterState::Failure);
+                }
+                if match result {
+                    Ok(output) => std::iter::once(output)
+                        .filter_map(|value| value.fleets.as_ref())
+                        .flat_map(|value| value.iter())
+                        .map(|value| &value.state)
+                        .any(|value| value == "STOPPED"),
+                    Err(_) => false,
+                } {
+                    return Some(rusoto_core::waiter::WaiterState::Failure);
+                }
+                None
+            },
+        )
+        .await
+    }
+
+    /// Waits for `FleetStopped`, polling `DescribeFleets` with `input` every 30 seconds, up to 40 times.
+    pub async fn wait_until_fleet_stopped(
+        &self,
+        input: DescribeFleetsRequest,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<DescribeFleetsError>> {
+        let waiter = rusoto_core::waiter::Waiter::new(std::time::Duration::from_secs(30), 40);
+        self.wait_until_fleet_stopped_with(input, &waiter).await
+    }
+
+    /// Waits for `FleetStopped` like `wait_until_fleet_stopped`, with the delay, attempts and runtime of `waiter`.
+    pub async fn wait_until_fleet_stopped_with(
+        &self,
+        input: DescribeFleetsRequest,
+        waiter: &rusoto_core::waiter::Waiter,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<DescribeFleetsError>> {
+        rusoto_core::waiter::wait(
+            waiter,
+            || self.describe_fleets(input.clone()),
+            |result| {
+                if match result {
+                    Ok(output) => {
+                        let mut values = std::iter::once(output)
+                            .filter_map(|value| value.fleets.as_ref())
+                            .flat_map(|value| value.iter())
+                            .map(|value| &value.state)
+                            .peekable();
+                        values.peek().is_some() && values.all(|value| value == "STOPPED")
+                    }
+                    Err(_) => false,
+                } {
+                    return Some(rusoto_core::waiter::WaiterState::Success);
+                }
+                if match result {
+                    Ok(output) => std::iter::once(output)
+                        .filter_map(|value| value.fleets.as_ref())
+                        .flat_map(|value| value.iter())
+                        .map(|value| &value.state)
+                        .any(|value| value == "STARTING"),
+                    Err(_) => false,
+                } {
+                    return Some(rusoto_core::waiter::WaiterState::Failure);
+                }
+                if match result {
+                    Ok(output) => std::iter::once(output)
+                        .filter_map(|value| value.fleets.as_ref())
+                        .flat_map(|value| value.iter())
+                        .map(|value| &value.state)
+                        .any(|value| value == "RUNNING"),
+                    Err(_) => false,
+                } {
+                    return Some(rusoto_core::waiter::WaiterState::Failure);
+                }
+                None
+            },
+        )
+        .await
+    }
+}
+
+#[async_trait]
+impl AppStream for AppStreamClient {
+    /// <p>Associates the specified fleet with the specified stack.</p>
+    async fn associate_fleet(
+        &self,
+        input: AssociateFleetRequest,
+    ) -> Result<AssociateFleetResult, RusotoError<AssociateFleetError>> {
+        self.associate_fleet_with_metadata(input)
             .await
-            .map_err(RusotoError::from)?;
-        if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<UpdateStackResult, _>()
-        } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(UpdateStackError::from_response(response))
-        }
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Associates the specified users with the specified stacks. Users in a user pool cannot be assigned to stacks with fleets that are joined to an Active Directory domain.</p>
+    async fn batch_associate_user_stack(
+        &self,
+        input: BatchAssociateUserStackRequest,
+    ) -> Result<BatchAssociateUserStackResult, RusotoError<BatchAssociateUserStackError>> {
+        self.batch_associate_user_stack_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Disassociates the specified users from the specified stacks.</p>
+    async fn batch_disassociate_user_stack(
+        &self,
+        input: BatchDisassociateUserStackRequest,
+    ) -> Result<BatchDisassociateUserStackResult, RusotoError<BatchDisassociateUserStackError>>
+    {
+        self.batch_disassociate_user_stack_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Copies the image within the same region or to a new region within the same AWS account. Note that any tags you added to the image will not be copied.</p>
+    async fn copy_image(
+        &self,
+        input: CopyImageRequest,
+    ) -> Result<CopyImageResponse, RusotoError<CopyImageError>> {
+        self.copy_image_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Creates a Directory Config object in AppStream 2.0. This object includes the configuration information required to join fleets and image builders to Microsoft Active Directory domains.</p>
+    async fn create_directory_config(
+        &self,
+        input: CreateDirectoryConfigRequest,
+    ) -> Result<CreateDirectoryConfigResult, RusotoError<CreateDirectoryConfigError>> {
+        self.create_directory_config_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Creates a fleet. A fleet consists of streaming instances that run a specified image.</p>
+    async fn create_fleet(
+        &self,
+        input: CreateFleetRequest,
+    ) -> Result<CreateFleetResult, RusotoError<CreateFleetError>> {
+        self.create_fleet_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Creates an image builder. An image builder is a virtual machine that is used to create an image.</p> <p>The initial state of the builder is <code>PENDING</code>. When it is ready, the state is <code>RUNNING</code>.</p>
+    async fn create_image_builder(
+        &self,
+        input: CreateImageBuilderRequest,
+    ) -> Result<CreateImageBuilderResult, RusotoError<CreateImageBuilderError>> {
+        self.create_image_builder_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Creates a URL to start an image builder streaming session.</p>
+    async fn create_image_builder_streaming_url(
+        &self,
+        input: CreateImageBuilderStreamingURLRequest,
+    ) -> Result<
+        CreateImageBuilderStreamingURLResult,
+        RusotoError<CreateImageBuilderStreamingURLError>,
+    > {
+        self.create_image_builder_streaming_url_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Creates a stack to start streaming applications to users. A stack consists of an associated fleet, user access policies, and storage configurations. </p>
+    async fn create_stack(
+        &self,
+        input: CreateStackRequest,
+    ) -> Result<CreateStackResult, RusotoError<CreateStackError>> {
+        self.create_stack_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Creates a temporary URL to start an AppStream 2.0 streaming session for the specified user. A streaming URL enables application streaming to be tested without user setup. </p>
+    async fn create_streaming_url(
+        &self,
+        input: CreateStreamingURLRequest,
+    ) -> Result<CreateStreamingURLResult, RusotoError<CreateStreamingURLError>> {
+        self.create_streaming_url_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Creates a usage report subscription. Usage reports are generated daily.</p>
+    async fn create_usage_report_subscription(
+        &self,
+    ) -> Result<CreateUsageReportSubscriptionResult, RusotoError<CreateUsageReportSubscriptionError>>
+    {
+        self.create_usage_report_subscription_with_metadata()
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Creates a new user in the user pool.</p>
+    async fn create_user(
+        &self,
+        input: CreateUserRequest,
+    ) -> Result<CreateUserResult, RusotoError<CreateUserError>> {
+        self.create_user_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes the specified Directory Config object from AppStream 2.0. This object includes the information required to join streaming instances to an Active Directory domain.</p>
+    async fn delete_directory_config(
+        &self,
+        input: DeleteDirectoryConfigRequest,
+    ) -> Result<DeleteDirectoryConfigResult, RusotoError<DeleteDirectoryConfigError>> {
+        self.delete_directory_config_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes the specified fleet.</p>
+    async fn delete_fleet(
+        &self,
+        input: DeleteFleetRequest,
+    ) -> Result<DeleteFleetResult, RusotoError<DeleteFleetError>> {
+        self.delete_fleet_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes the specified image. You cannot delete an image when it is in use. After you delete an image, you cannot provision new capacity using the image.</p>
+    async fn delete_image(
+        &self,
+        input: DeleteImageRequest,
+    ) -> Result<DeleteImageResult, RusotoError<DeleteImageError>> {
+        self.delete_image_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes the specified image builder and releases the capacity.</p>
+    async fn delete_image_builder(
+        &self,
+        input: DeleteImageBuilderRequest,
+    ) -> Result<DeleteImageBuilderResult, RusotoError<DeleteImageBuilderError>> {
+        self.delete_image_builder_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes permissions for the specified private image. After you delete permissions for an image, AWS accounts to which you previously granted these permissions can no longer use the image.</p>
+    async fn delete_image_permissions(
+        &self,
+        input: DeleteImagePermissionsRequest,
+    ) -> Result<DeleteImagePermissionsResult, RusotoError<DeleteImagePermissionsError>> {
+        self.delete_image_permissions_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes the specified stack. After the stack is deleted, the application streaming environment provided by the stack is no longer available to users. Also, any reservations made for application streaming sessions for the stack are released.</p>
+    async fn delete_stack(
+        &self,
+        input: DeleteStackRequest,
+    ) -> Result<DeleteStackResult, RusotoError<DeleteStackError>> {
+        self.delete_stack_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Disables usage report generation.</p>
+    async fn delete_usage_report_subscription(
+        &self,
+    ) -> Result<DeleteUsageReportSubscriptionResult, RusotoError<DeleteUsageReportSubscriptionError>>
+    {
+        self.delete_usage_report_subscription_with_metadata()
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes a user from the user pool.</p>
+    async fn delete_user(
+        &self,
+        input: DeleteUserRequest,
+    ) -> Result<DeleteUserResult, RusotoError<DeleteUserError>> {
+        self.delete_user_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Retrieves a list that describes one or more specified Directory Config objects for AppStream 2.0, if the names for these objects are provided. Otherwise, all Directory Config objects in the account are described. These objects include the configuration information required to join fleets and image builders to Microsoft Active Directory domains. </p> <p>Although the response syntax in this topic includes the account password, this password is not returned in the actual response.</p>
+    async fn describe_directory_configs(
+        &self,
+        input: DescribeDirectoryConfigsRequest,
+    ) -> Result<DescribeDirectoryConfigsResult, RusotoError<DescribeDirectoryConfigsError>> {
+        self.describe_directory_configs_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Retrieves a list that describes one or more specified fleets, if the fleet names are provided. Otherwise, all fleets in the account are described.</p>
+    async fn describe_fleets(
+        &self,
+        input: DescribeFleetsRequest,
+    ) -> Result<DescribeFleetsResult, RusotoError<DescribeFleetsError>> {
+        self.describe_fleets_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Retrieves a list that describes one or more specified image builders, if the image builder names are provided. Otherwise, all image builders in the account are described.</p>
+    async fn describe_image_builders(
+        &self,
+        input: DescribeImageBuildersRequest,
+    ) -> Result<DescribeImageBuildersResult, RusotoError<DescribeImageBuildersError>> {
+        self.describe_image_builders_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Retrieves a list that describes the permissions for shared AWS account IDs on a private image that you own. </p>
+    async fn describe_image_permissions(
+        &self,
+        input: DescribeImagePermissionsRequest,
+    ) -> Result<DescribeImagePermissionsResult, RusotoError<DescribeImagePermissionsError>> {
+        self.describe_image_permissions_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Retrieves a list that describes one or more specified images, if the image names or image ARNs are provided. Otherwise, all images in the account are described.</p>
+    async fn describe_images(
+        &self,
+        input: DescribeImagesRequest,
+    ) -> Result<DescribeImagesResult, RusotoError<DescribeImagesError>> {
+        self.describe_images_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Retrieves a list that describes the streaming sessions for a specified stack and fleet. If a UserId is provided for the stack and fleet, only streaming sessions for that user are described. If an authentication type is not provided, the default is to authenticate users using a streaming URL.</p>
+    async fn describe_sessions(
+        &self,
+        input: DescribeSessionsRequest,
+    ) -> Result<DescribeSessionsResult, RusotoError<DescribeSessionsError>> {
+        self.describe_sessions_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Retrieves a list that describes one or more specified stacks, if the stack names are provided. Otherwise, all stacks in the account are described.</p>
+    async fn describe_stacks(
+        &self,
+        input: DescribeStacksRequest,
+    ) -> Result<DescribeStacksResult, RusotoError<DescribeStacksError>> {
+        self.describe_stacks_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Retrieves a list that describes one or more usage report subscriptions.</p>
+    async fn describe_usage_report_subscriptions(
+        &self,
+        input: DescribeUsageReportSubscriptionsRequest,
+    ) -> Result<
+        DescribeUsageReportSubscriptionsResult,
+        RusotoError<DescribeUsageReportSubscriptionsError>,
+    > {
+        self.describe_usage_report_subscriptions_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Retrieves a list that describes the UserStackAssociation objects. You must specify either or both of the following:</p> <ul> <li> <p>The stack name</p> </li> <li> <p>The user name (email address of the user associated with the stack) and the authentication type for the user</p> </li> </ul></p>
+    async fn describe_user_stack_associations(
+        &self,
+        input: DescribeUserStackAssociationsRequest,
+    ) -> Result<DescribeUserStackAssociationsResult, RusotoError<DescribeUserStackAssociationsError>>
+    {
+        self.describe_user_stack_associations_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Retrieves a list that describes one or more specified users in the user pool.</p>
+    async fn describe_users(
+        &self,
+        input: DescribeUsersRequest,
+    ) -> Result<DescribeUsersResult, RusotoError<DescribeUsersError>> {
+        self.describe_users_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Disables the specified user in the user pool. Users can't sign in to AppStream 2.0 until they are re-enabled. This action does not delete the user. </p>
+    async fn disable_user(
+        &self,
+        input: DisableUserRequest,
+    ) -> Result<DisableUserResult, RusotoError<DisableUserError>> {
+        self.disable_user_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Disassociates the specified fleet from the specified stack.</p>
+    async fn disassociate_fleet(
+        &self,
+        input: DisassociateFleetRequest,
+    ) -> Result<DisassociateFleetResult, RusotoError<DisassociateFleetError>> {
+        self.disassociate_fleet_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Enables a user in the user pool. After being enabled, users can sign in to AppStream 2.0 and open applications from the stacks to which they are assigned.</p>
+    async fn enable_user(
+        &self,
+        input: EnableUserRequest,
+    ) -> Result<EnableUserResult, RusotoError<EnableUserError>> {
+        self.enable_user_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Immediately stops the specified streaming session.</p>
+    async fn expire_session(
+        &self,
+        input: ExpireSessionRequest,
+    ) -> Result<ExpireSessionResult, RusotoError<ExpireSessionError>> {
+        self.expire_session_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Retrieves the name of the fleet that is associated with the specified stack.</p>
+    async fn list_associated_fleets(
+        &self,
+        input: ListAssociatedFleetsRequest,
+    ) -> Result<ListAssociatedFleetsResult, RusotoError<ListAssociatedFleetsError>> {
+        self.list_associated_fleets_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Retrieves the name of the stack with which the specified fleet is associated.</p>
+    async fn list_associated_stacks(
+        &self,
+        input: ListAssociatedStacksRequest,
+    ) -> Result<ListAssociatedStacksResult, RusotoError<ListAssociatedStacksError>> {
+        self.list_associated_stacks_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Retrieves a list of all tags for the specified AppStream 2.0 resource. You can tag AppStream 2.0 image builders, images, fleets, and stacks.</p> <p>For more information about tags, see <a href="https://docs.aws.amazon.com/appstream2/latest/developerguide/tagging-basic.html">Tagging Your Resources</a> in the <i>Amazon AppStream 2.0 Administration Guide</i>.</p>
+    async fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> Result<ListTagsForResourceResponse, RusotoError<ListTagsForResourceError>> {
+        self.list_tags_for_resource_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Starts the specified fleet.</p>
+    async fn start_fleet(
+        &self,
+        input: StartFleetRequest,
+    ) -> Result<StartFleetResult, RusotoError<StartFleetError>> {
+        self.start_fleet_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Starts the specified image builder.</p>
+    async fn start_image_builder(
+        &self,
+        input: StartImageBuilderRequest,
+    ) -> Result<StartImageBuilderResult, RusotoError<StartImageBuilderError>> {
+        self.start_image_builder_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Stops the specified fleet.</p>
+    async fn stop_fleet(
+        &self,
+        input: StopFleetRequest,
+    ) -> Result<StopFleetResult, RusotoError<StopFleetError>> {
+        self.stop_fleet_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Stops the specified image builder.</p>
+    async fn stop_image_builder(
+        &self,
+        input: StopImageBuilderRequest,
+    ) -> Result<StopImageBuilderResult, RusotoError<StopImageBuilderError>> {
+        self.stop_image_builder_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Adds or overwrites one or more tags for the specified AppStream 2.0 resource. You can tag AppStream 2.0 image builders, images, fleets, and stacks.</p> <p>Each tag consists of a key and an optional value. If a resource already has a tag with the same key, this operation updates its value.</p> <p>To list the current tags for your resources, use <a>ListTagsForResource</a>. To disassociate tags from your resources, use <a>UntagResource</a>.</p> <p>For more information about tags, see <a href="https://docs.aws.amazon.com/appstream2/latest/developerguide/tagging-basic.html">Tagging Your Resources</a> in the <i>Amazon AppStream 2.0 Administration Guide</i>.</p>
+    async fn tag_resource(
+        &self,
+        input: TagResourceRequest,
+    ) -> Result<TagResourceResponse, RusotoError<TagResourceError>> {
+        self.tag_resource_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Disassociates one or more specified tags from the specified AppStream 2.0 resource.</p> <p>To list the current tags for your resources, use <a>ListTagsForResource</a>.</p> <p>For more information about tags, see <a href="https://docs.aws.amazon.com/appstream2/latest/developerguide/tagging-basic.html">Tagging Your Resources</a> in the <i>Amazon AppStream 2.0 Administration Guide</i>.</p>
+    async fn untag_resource(
+        &self,
+        input: UntagResourceRequest,
+    ) -> Result<UntagResourceResponse, RusotoError<UntagResourceError>> {
+        self.untag_resource_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Updates the specified Directory Config object in AppStream 2.0. This object includes the configuration information required to join fleets and image builders to Microsoft Active Directory domains.</p>
+    async fn update_directory_config(
+        &self,
+        input: UpdateDirectoryConfigRequest,
+    ) -> Result<UpdateDirectoryConfigResult, RusotoError<UpdateDirectoryConfigError>> {
+        self.update_directory_config_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Updates the specified fleet.</p> <p>If the fleet is in the <code>STOPPED</code> state, you can update any attribute except the fleet name. If the fleet is in the <code>RUNNING</code> state, you can update the <code>DisplayName</code>, <code>ComputeCapacity</code>, <code>ImageARN</code>, <code>ImageName</code>, <code>IdleDisconnectTimeoutInSeconds</code>, and <code>DisconnectTimeoutInSeconds</code> attributes. If the fleet is in the <code>STARTING</code> or <code>STOPPING</code> state, you can't update it.</p>
+    async fn update_fleet(
+        &self,
+        input: UpdateFleetRequest,
+    ) -> Result<UpdateFleetResult, RusotoError<UpdateFleetError>> {
+        self.update_fleet_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Adds or updates permissions for the specified private image. </p>
+    async fn update_image_permissions(
+        &self,
+        input: UpdateImagePermissionsRequest,
+    ) -> Result<UpdateImagePermissionsResult, RusotoError<UpdateImagePermissionsError>> {
+        self.update_image_permissions_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Updates the specified fields for the specified stack.</p>
+    async fn update_stack(
+        &self,
+        input: UpdateStackRequest,
+    ) -> Result<UpdateStackResult, RusotoError<UpdateStackError>> {
+        self.update_stack_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
     }
 }
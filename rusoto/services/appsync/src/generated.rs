@@ -16,8 +16,8 @@ use std::fmt;
 use async_trait::async_trait;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
-use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest, ResponseMetadata};
+use rusoto_core::{Client, ErrorMetadata, RusotoError};
 
 use rusoto_core::param::{Params, ServiceParams};
 use rusoto_core::proto;
@@ -1631,6 +1631,7 @@ pub struct UserPoolConfig {
 
 /// Errors returned by CreateApiCache
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateApiCacheError {
     /// <p>The request is not well formed. For example, a value is invalid or a required field is missing. Check the field values, and then try again. </p>
     BadRequest(String),
@@ -1642,6 +1643,8 @@ pub enum CreateApiCacheError {
     NotFound(String),
     /// <p>You are not authorized to perform this operation.</p>
     Unauthorized(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateApiCacheError {
@@ -1649,24 +1652,28 @@ impl CreateApiCacheError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(CreateApiCacheError::BadRequest(err.msg))
+                    return RusotoError::service(CreateApiCacheError::BadRequest(err.msg), &res)
                 }
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(CreateApiCacheError::ConcurrentModification(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateApiCacheError::ConcurrentModification(err.msg),
+                        &res,
+                    )
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(CreateApiCacheError::InternalFailure(err.msg))
+                    return RusotoError::service(
+                        CreateApiCacheError::InternalFailure(err.msg),
+                        &res,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(CreateApiCacheError::NotFound(err.msg))
+                    return RusotoError::service(CreateApiCacheError::NotFound(err.msg), &res)
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(CreateApiCacheError::Unauthorized(err.msg))
+                    return RusotoError::service(CreateApiCacheError::Unauthorized(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreateApiCacheError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1681,12 +1688,14 @@ impl fmt::Display for CreateApiCacheError {
             CreateApiCacheError::InternalFailure(ref cause) => write!(f, "{}", cause),
             CreateApiCacheError::NotFound(ref cause) => write!(f, "{}", cause),
             CreateApiCacheError::Unauthorized(ref cause) => write!(f, "{}", cause),
+            CreateApiCacheError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateApiCacheError {}
 /// Errors returned by CreateApiKey
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateApiKeyError {
     /// <p>The API key exceeded a limit. Try your request again.</p>
     ApiKeyLimitExceeded(String),
@@ -1702,6 +1711,8 @@ pub enum CreateApiKeyError {
     NotFound(String),
     /// <p>You are not authorized to perform this operation.</p>
     Unauthorized(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateApiKeyError {
@@ -1709,30 +1720,34 @@ impl CreateApiKeyError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ApiKeyLimitExceededException" => {
-                    return RusotoError::Service(CreateApiKeyError::ApiKeyLimitExceeded(err.msg))
+                    return RusotoError::service(
+                        CreateApiKeyError::ApiKeyLimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "ApiKeyValidityOutOfBoundsException" => {
-                    return RusotoError::Service(CreateApiKeyError::ApiKeyValidityOutOfBounds(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateApiKeyError::ApiKeyValidityOutOfBounds(err.msg),
+                        &res,
+                    )
                 }
                 "BadRequestException" => {
-                    return RusotoError::Service(CreateApiKeyError::BadRequest(err.msg))
+                    return RusotoError::service(CreateApiKeyError::BadRequest(err.msg), &res)
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(CreateApiKeyError::InternalFailure(err.msg))
+                    return RusotoError::service(CreateApiKeyError::InternalFailure(err.msg), &res)
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateApiKeyError::LimitExceeded(err.msg))
+                    return RusotoError::service(CreateApiKeyError::LimitExceeded(err.msg), &res)
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(CreateApiKeyError::NotFound(err.msg))
+                    return RusotoError::service(CreateApiKeyError::NotFound(err.msg), &res)
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(CreateApiKeyError::Unauthorized(err.msg))
+                    return RusotoError::service(CreateApiKeyError::Unauthorized(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreateApiKeyError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1749,12 +1764,14 @@ impl fmt::Display for CreateApiKeyError {
             CreateApiKeyError::LimitExceeded(ref cause) => write!(f, "{}", cause),
             CreateApiKeyError::NotFound(ref cause) => write!(f, "{}", cause),
             CreateApiKeyError::Unauthorized(ref cause) => write!(f, "{}", cause),
+            CreateApiKeyError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateApiKeyError {}
 /// Errors returned by CreateDataSource
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateDataSourceError {
     /// <p>The request is not well formed. For example, a value is invalid or a required field is missing. Check the field values, and then try again. </p>
     BadRequest(String),
@@ -1766,6 +1783,8 @@ pub enum CreateDataSourceError {
     NotFound(String),
     /// <p>You are not authorized to perform this operation.</p>
     Unauthorized(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateDataSourceError {
@@ -1773,24 +1792,28 @@ impl CreateDataSourceError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(CreateDataSourceError::BadRequest(err.msg))
+                    return RusotoError::service(CreateDataSourceError::BadRequest(err.msg), &res)
                 }
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(CreateDataSourceError::ConcurrentModification(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateDataSourceError::ConcurrentModification(err.msg),
+                        &res,
+                    )
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(CreateDataSourceError::InternalFailure(err.msg))
+                    return RusotoError::service(
+                        CreateDataSourceError::InternalFailure(err.msg),
+                        &res,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(CreateDataSourceError::NotFound(err.msg))
+                    return RusotoError::service(CreateDataSourceError::NotFound(err.msg), &res)
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(CreateDataSourceError::Unauthorized(err.msg))
+                    return RusotoError::service(CreateDataSourceError::Unauthorized(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreateDataSourceError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1805,12 +1828,14 @@ impl fmt::Display for CreateDataSourceError {
             CreateDataSourceError::InternalFailure(ref cause) => write!(f, "{}", cause),
             CreateDataSourceError::NotFound(ref cause) => write!(f, "{}", cause),
             CreateDataSourceError::Unauthorized(ref cause) => write!(f, "{}", cause),
+            CreateDataSourceError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateDataSourceError {}
 /// Errors returned by CreateFunction
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateFunctionError {
     /// <p>Another modification is in progress at this time and it must complete before you can make your change. </p>
     ConcurrentModification(String),
@@ -1820,6 +1845,8 @@ pub enum CreateFunctionError {
     NotFound(String),
     /// <p>You are not authorized to perform this operation.</p>
     Unauthorized(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateFunctionError {
@@ -1827,21 +1854,25 @@ impl CreateFunctionError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(CreateFunctionError::ConcurrentModification(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateFunctionError::ConcurrentModification(err.msg),
+                        &res,
+                    )
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(CreateFunctionError::InternalFailure(err.msg))
+                    return RusotoError::service(
+                        CreateFunctionError::InternalFailure(err.msg),
+                        &res,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(CreateFunctionError::NotFound(err.msg))
+                    return RusotoError::service(CreateFunctionError::NotFound(err.msg), &res)
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(CreateFunctionError::Unauthorized(err.msg))
+                    return RusotoError::service(CreateFunctionError::Unauthorized(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreateFunctionError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1855,12 +1886,14 @@ impl fmt::Display for CreateFunctionError {
             CreateFunctionError::InternalFailure(ref cause) => write!(f, "{}", cause),
             CreateFunctionError::NotFound(ref cause) => write!(f, "{}", cause),
             CreateFunctionError::Unauthorized(ref cause) => write!(f, "{}", cause),
+            CreateFunctionError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateFunctionError {}
 /// Errors returned by CreateGraphqlApi
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateGraphqlApiError {
     /// <p>The GraphQL API exceeded a limit. Try your request again.</p>
     ApiLimitExceeded(String),
@@ -1874,6 +1907,8 @@ pub enum CreateGraphqlApiError {
     LimitExceeded(String),
     /// <p>You are not authorized to perform this operation.</p>
     Unauthorized(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateGraphqlApiError {
@@ -1881,27 +1916,37 @@ impl CreateGraphqlApiError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ApiLimitExceededException" => {
-                    return RusotoError::Service(CreateGraphqlApiError::ApiLimitExceeded(err.msg))
+                    return RusotoError::service(
+                        CreateGraphqlApiError::ApiLimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "BadRequestException" => {
-                    return RusotoError::Service(CreateGraphqlApiError::BadRequest(err.msg))
+                    return RusotoError::service(CreateGraphqlApiError::BadRequest(err.msg), &res)
                 }
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(CreateGraphqlApiError::ConcurrentModification(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateGraphqlApiError::ConcurrentModification(err.msg),
+                        &res,
+                    )
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(CreateGraphqlApiError::InternalFailure(err.msg))
+                    return RusotoError::service(
+                        CreateGraphqlApiError::InternalFailure(err.msg),
+                        &res,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateGraphqlApiError::LimitExceeded(err.msg))
+                    return RusotoError::service(
+                        CreateGraphqlApiError::LimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(CreateGraphqlApiError::Unauthorized(err.msg))
+                    return RusotoError::service(CreateGraphqlApiError::Unauthorized(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreateGraphqlApiError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1917,12 +1962,14 @@ impl fmt::Display for CreateGraphqlApiError {
             CreateGraphqlApiError::InternalFailure(ref cause) => write!(f, "{}", cause),
             CreateGraphqlApiError::LimitExceeded(ref cause) => write!(f, "{}", cause),
             CreateGraphqlApiError::Unauthorized(ref cause) => write!(f, "{}", cause),
+            CreateGraphqlApiError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateGraphqlApiError {}
 /// Errors returned by CreateResolver
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateResolverError {
     /// <p>Another modification is in progress at this time and it must complete before you can make your change. </p>
     ConcurrentModification(String),
@@ -1932,6 +1979,8 @@ pub enum CreateResolverError {
     NotFound(String),
     /// <p>You are not authorized to perform this operation.</p>
     Unauthorized(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateResolverError {
@@ -1939,21 +1988,25 @@ impl CreateResolverError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(CreateResolverError::ConcurrentModification(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateResolverError::ConcurrentModification(err.msg),
+                        &res,
+                    )
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(CreateResolverError::InternalFailure(err.msg))
+                    return RusotoError::service(
+                        CreateResolverError::InternalFailure(err.msg),
+                        &res,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(CreateResolverError::NotFound(err.msg))
+                    return RusotoError::service(CreateResolverError::NotFound(err.msg), &res)
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(CreateResolverError::Unauthorized(err.msg))
+                    return RusotoError::service(CreateResolverError::Unauthorized(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreateResolverError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1967,12 +2020,14 @@ impl fmt::Display for CreateResolverError {
             CreateResolverError::InternalFailure(ref cause) => write!(f, "{}", cause),
             CreateResolverError::NotFound(ref cause) => write!(f, "{}", cause),
             CreateResolverError::Unauthorized(ref cause) => write!(f, "{}", cause),
+            CreateResolverError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateResolverError {}
 /// Errors returned by CreateType
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateTypeError {
     /// <p>The request is not well formed. For example, a value is invalid or a required field is missing. Check the field values, and then try again. </p>
     BadRequest(String),
@@ -1984,6 +2039,8 @@ pub enum CreateTypeError {
     NotFound(String),
     /// <p>You are not authorized to perform this operation.</p>
     Unauthorized(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateTypeError {
@@ -1991,22 +2048,25 @@ impl CreateTypeError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(CreateTypeError::BadRequest(err.msg))
+                    return RusotoError::service(CreateTypeError::BadRequest(err.msg), &res)
                 }
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(CreateTypeError::ConcurrentModification(err.msg))
+                    return RusotoError::service(
+                        CreateTypeError::ConcurrentModification(err.msg),
+                        &res,
+                    )
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(CreateTypeError::InternalFailure(err.msg))
+                    return RusotoError::service(CreateTypeError::InternalFailure(err.msg), &res)
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(CreateTypeError::NotFound(err.msg))
+                    return RusotoError::service(CreateTypeError::NotFound(err.msg), &res)
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(CreateTypeError::Unauthorized(err.msg))
+                    return RusotoError::service(CreateTypeError::Unauthorized(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreateTypeError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2021,12 +2081,14 @@ impl fmt::Display for CreateTypeError {
             CreateTypeError::InternalFailure(ref cause) => write!(f, "{}", cause),
             CreateTypeError::NotFound(ref cause) => write!(f, "{}", cause),
             CreateTypeError::Unauthorized(ref cause) => write!(f, "{}", cause),
+            CreateTypeError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateTypeError {}
 /// Errors returned by DeleteApiCache
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteApiCacheError {
     /// <p>The request is not well formed. For example, a value is invalid or a required field is missing. Check the field values, and then try again. </p>
     BadRequest(String),
@@ -2038,6 +2100,8 @@ pub enum DeleteApiCacheError {
     NotFound(String),
     /// <p>You are not authorized to perform this operation.</p>
     Unauthorized(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteApiCacheError {
@@ -2045,24 +2109,28 @@ impl DeleteApiCacheError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(DeleteApiCacheError::BadRequest(err.msg))
+                    return RusotoError::service(DeleteApiCacheError::BadRequest(err.msg), &res)
                 }
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(DeleteApiCacheError::ConcurrentModification(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteApiCacheError::ConcurrentModification(err.msg),
+                        &res,
+                    )
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(DeleteApiCacheError::InternalFailure(err.msg))
+                    return RusotoError::service(
+                        DeleteApiCacheError::InternalFailure(err.msg),
+                        &res,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteApiCacheError::NotFound(err.msg))
+                    return RusotoError::service(DeleteApiCacheError::NotFound(err.msg), &res)
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(DeleteApiCacheError::Unauthorized(err.msg))
+                    return RusotoError::service(DeleteApiCacheError::Unauthorized(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteApiCacheError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2077,12 +2145,14 @@ impl fmt::Display for DeleteApiCacheError {
             DeleteApiCacheError::InternalFailure(ref cause) => write!(f, "{}", cause),
             DeleteApiCacheError::NotFound(ref cause) => write!(f, "{}", cause),
             DeleteApiCacheError::Unauthorized(ref cause) => write!(f, "{}", cause),
+            DeleteApiCacheError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteApiCacheError {}
 /// Errors returned by DeleteApiKey
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteApiKeyError {
     /// <p>The request is not well formed. For example, a value is invalid or a required field is missing. Check the field values, and then try again. </p>
     BadRequest(String),
@@ -2092,6 +2162,8 @@ pub enum DeleteApiKeyError {
     NotFound(String),
     /// <p>You are not authorized to perform this operation.</p>
     Unauthorized(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteApiKeyError {
@@ -2099,19 +2171,19 @@ impl DeleteApiKeyError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(DeleteApiKeyError::BadRequest(err.msg))
+                    return RusotoError::service(DeleteApiKeyError::BadRequest(err.msg), &res)
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(DeleteApiKeyError::InternalFailure(err.msg))
+                    return RusotoError::service(DeleteApiKeyError::InternalFailure(err.msg), &res)
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteApiKeyError::NotFound(err.msg))
+                    return RusotoError::service(DeleteApiKeyError::NotFound(err.msg), &res)
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(DeleteApiKeyError::Unauthorized(err.msg))
+                    return RusotoError::service(DeleteApiKeyError::Unauthorized(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteApiKeyError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2125,12 +2197,14 @@ impl fmt::Display for DeleteApiKeyError {
             DeleteApiKeyError::InternalFailure(ref cause) => write!(f, "{}", cause),
             DeleteApiKeyError::NotFound(ref cause) => write!(f, "{}", cause),
             DeleteApiKeyError::Unauthorized(ref cause) => write!(f, "{}", cause),
+            DeleteApiKeyError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteApiKeyError {}
 /// Errors returned by DeleteDataSource
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteDataSourceError {
     /// <p>The request is not well formed. For example, a value is invalid or a required field is missing. Check the field values, and then try again. </p>
     BadRequest(String),
@@ -2142,6 +2216,8 @@ pub enum DeleteDataSourceError {
     NotFound(String),
     /// <p>You are not authorized to perform this operation.</p>
     Unauthorized(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteDataSourceError {
@@ -2149,24 +2225,28 @@ impl DeleteDataSourceError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(DeleteDataSourceError::BadRequest(err.msg))
+                    return RusotoError::service(DeleteDataSourceError::BadRequest(err.msg), &res)
                 }
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(DeleteDataSourceError::ConcurrentModification(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteDataSourceError::ConcurrentModification(err.msg),
+                        &res,
+                    )
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(DeleteDataSourceError::InternalFailure(err.msg))
+                    return RusotoError::service(
+                        DeleteDataSourceError::InternalFailure(err.msg),
+                        &res,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteDataSourceError::NotFound(err.msg))
+                    return RusotoError::service(DeleteDataSourceError::NotFound(err.msg), &res)
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(DeleteDataSourceError::Unauthorized(err.msg))
+                    return RusotoError::service(DeleteDataSourceError::Unauthorized(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteDataSourceError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2181,12 +2261,14 @@ impl fmt::Display for DeleteDataSourceError {
             DeleteDataSourceError::InternalFailure(ref cause) => write!(f, "{}", cause),
             DeleteDataSourceError::NotFound(ref cause) => write!(f, "{}", cause),
             DeleteDataSourceError::Unauthorized(ref cause) => write!(f, "{}", cause),
+            DeleteDataSourceError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteDataSourceError {}
 /// Errors returned by DeleteFunction
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteFunctionError {
     /// <p>Another modification is in progress at this time and it must complete before you can make your change. </p>
     ConcurrentModification(String),
@@ -2196,6 +2278,8 @@ pub enum DeleteFunctionError {
     NotFound(String),
     /// <p>You are not authorized to perform this operation.</p>
     Unauthorized(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteFunctionError {
@@ -2203,21 +2287,25 @@ impl DeleteFunctionError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(DeleteFunctionError::ConcurrentModification(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteFunctionError::ConcurrentModification(err.msg),
+                        &res,
+                    )
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(DeleteFunctionError::InternalFailure(err.msg))
+                    return RusotoError::service(
+                        DeleteFunctionError::InternalFailure(err.msg),
+                        &res,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteFunctionError::NotFound(err.msg))
+                    return RusotoError::service(DeleteFunctionError::NotFound(err.msg), &res)
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(DeleteFunctionError::Unauthorized(err.msg))
+                    return RusotoError::service(DeleteFunctionError::Unauthorized(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteFunctionError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2231,12 +2319,14 @@ impl fmt::Display for DeleteFunctionError {
             DeleteFunctionError::InternalFailure(ref cause) => write!(f, "{}", cause),
             DeleteFunctionError::NotFound(ref cause) => write!(f, "{}", cause),
             DeleteFunctionError::Unauthorized(ref cause) => write!(f, "{}", cause),
+            DeleteFunctionError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteFunctionError {}
 /// Errors returned by DeleteGraphqlApi
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteGraphqlApiError {
     /// <p>You do not have access to perform this operation on this resource.</p>
     AccessDenied(String),
@@ -2250,6 +2340,8 @@ pub enum DeleteGraphqlApiError {
     NotFound(String),
     /// <p>You are not authorized to perform this operation.</p>
     Unauthorized(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteGraphqlApiError {
@@ -2257,27 +2349,31 @@ impl DeleteGraphqlApiError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(DeleteGraphqlApiError::AccessDenied(err.msg))
+                    return RusotoError::service(DeleteGraphqlApiError::AccessDenied(err.msg), &res)
                 }
                 "BadRequestException" => {
-                    return RusotoError::Service(DeleteGraphqlApiError::BadRequest(err.msg))
+                    return RusotoError::service(DeleteGraphqlApiError::BadRequest(err.msg), &res)
                 }
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(DeleteGraphqlApiError::ConcurrentModification(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteGraphqlApiError::ConcurrentModification(err.msg),
+                        &res,
+                    )
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(DeleteGraphqlApiError::InternalFailure(err.msg))
+                    return RusotoError::service(
+                        DeleteGraphqlApiError::InternalFailure(err.msg),
+                        &res,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteGraphqlApiError::NotFound(err.msg))
+                    return RusotoError::service(DeleteGraphqlApiError::NotFound(err.msg), &res)
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(DeleteGraphqlApiError::Unauthorized(err.msg))
+                    return RusotoError::service(DeleteGraphqlApiError::Unauthorized(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteGraphqlApiError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2293,12 +2389,14 @@ impl fmt::Display for DeleteGraphqlApiError {
             DeleteGraphqlApiError::InternalFailure(ref cause) => write!(f, "{}", cause),
             DeleteGraphqlApiError::NotFound(ref cause) => write!(f, "{}", cause),
             DeleteGraphqlApiError::Unauthorized(ref cause) => write!(f, "{}", cause),
+            DeleteGraphqlApiError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteGraphqlApiError {}
 /// Errors returned by DeleteResolver
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteResolverError {
     /// <p>Another modification is in progress at this time and it must complete before you can make your change. </p>
     ConcurrentModification(String),
@@ -2308,6 +2406,8 @@ pub enum DeleteResolverError {
     NotFound(String),
     /// <p>You are not authorized to perform this operation.</p>
     Unauthorized(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteResolverError {
@@ -2315,21 +2415,25 @@ impl DeleteResolverError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(DeleteResolverError::ConcurrentModification(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteResolverError::ConcurrentModification(err.msg),
+                        &res,
+                    )
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(DeleteResolverError::InternalFailure(err.msg))
+                    return RusotoError::service(
+                        DeleteResolverError::InternalFailure(err.msg),
+                        &res,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteResolverError::NotFound(err.msg))
+                    return RusotoError::service(DeleteResolverError::NotFound(err.msg), &res)
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(DeleteResolverError::Unauthorized(err.msg))
+                    return RusotoError::service(DeleteResolverError::Unauthorized(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteResolverError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2343,12 +2447,14 @@ impl fmt::Display for DeleteResolverError {
             DeleteResolverError::InternalFailure(ref cause) => write!(f, "{}", cause),
             DeleteResolverError::NotFound(ref cause) => write!(f, "{}", cause),
             DeleteResolverError::Unauthorized(ref cause) => write!(f, "{}", cause),
+            DeleteResolverError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteResolverError {}
 /// Errors returned by DeleteType
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteTypeError {
     /// <p>The request is not well formed. For example, a value is invalid or a required field is missing. Check the field values, and then try again. </p>
     BadRequest(String),
@@ -2360,6 +2466,8 @@ pub enum DeleteTypeError {
     NotFound(String),
     /// <p>You are not authorized to perform this operation.</p>
     Unauthorized(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteTypeError {
@@ -2367,22 +2475,25 @@ impl DeleteTypeError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(DeleteTypeError::BadRequest(err.msg))
+                    return RusotoError::service(DeleteTypeError::BadRequest(err.msg), &res)
                 }
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(DeleteTypeError::ConcurrentModification(err.msg))
+                    return RusotoError::service(
+                        DeleteTypeError::ConcurrentModification(err.msg),
+                        &res,
+                    )
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(DeleteTypeError::InternalFailure(err.msg))
+                    return RusotoError::service(DeleteTypeError::InternalFailure(err.msg), &res)
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteTypeError::NotFound(err.msg))
+                    return RusotoError::service(DeleteTypeError::NotFound(err.msg), &res)
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(DeleteTypeError::Unauthorized(err.msg))
+                    return RusotoError::service(DeleteTypeError::Unauthorized(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteTypeError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2397,12 +2508,14 @@ impl fmt::Display for DeleteTypeError {
             DeleteTypeError::InternalFailure(ref cause) => write!(f, "{}", cause),
             DeleteTypeError::NotFound(ref cause) => write!(f, "{}", cause),
             DeleteTypeError::Unauthorized(ref cause) => write!(f, "{}", cause),
+            DeleteTypeError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteTypeError {}
 /// Errors returned by FlushApiCache
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum FlushApiCacheError {
     /// <p>The request is not well formed. For example, a value is invalid or a required field is missing. Check the field values, and then try again. </p>
     BadRequest(String),
@@ -2414,6 +2527,8 @@ pub enum FlushApiCacheError {
     NotFound(String),
     /// <p>You are not authorized to perform this operation.</p>
     Unauthorized(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl FlushApiCacheError {
@@ -2421,24 +2536,25 @@ impl FlushApiCacheError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(FlushApiCacheError::BadRequest(err.msg))
+                    return RusotoError::service(FlushApiCacheError::BadRequest(err.msg), &res)
                 }
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(FlushApiCacheError::ConcurrentModification(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        FlushApiCacheError::ConcurrentModification(err.msg),
+                        &res,
+                    )
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(FlushApiCacheError::InternalFailure(err.msg))
+                    return RusotoError::service(FlushApiCacheError::InternalFailure(err.msg), &res)
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(FlushApiCacheError::NotFound(err.msg))
+                    return RusotoError::service(FlushApiCacheError::NotFound(err.msg), &res)
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(FlushApiCacheError::Unauthorized(err.msg))
+                    return RusotoError::service(FlushApiCacheError::Unauthorized(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(FlushApiCacheError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2453,12 +2569,14 @@ impl fmt::Display for FlushApiCacheError {
             FlushApiCacheError::InternalFailure(ref cause) => write!(f, "{}", cause),
             FlushApiCacheError::NotFound(ref cause) => write!(f, "{}", cause),
             FlushApiCacheError::Unauthorized(ref cause) => write!(f, "{}", cause),
+            FlushApiCacheError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for FlushApiCacheError {}
 /// Errors returned by GetApiCache
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetApiCacheError {
     /// <p>The request is not well formed. For example, a value is invalid or a required field is missing. Check the field values, and then try again. </p>
     BadRequest(String),
@@ -2470,6 +2588,8 @@ pub enum GetApiCacheError {
     NotFound(String),
     /// <p>You are not authorized to perform this operation.</p>
     Unauthorized(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetApiCacheError {
@@ -2477,22 +2597,25 @@ impl GetApiCacheError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetApiCacheError::BadRequest(err.msg))
+                    return RusotoError::service(GetApiCacheError::BadRequest(err.msg), &res)
                 }
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(GetApiCacheError::ConcurrentModification(err.msg))
+                    return RusotoError::service(
+                        GetApiCacheError::ConcurrentModification(err.msg),
+                        &res,
+                    )
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(GetApiCacheError::InternalFailure(err.msg))
+                    return RusotoError::service(GetApiCacheError::InternalFailure(err.msg), &res)
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetApiCacheError::NotFound(err.msg))
+                    return RusotoError::service(GetApiCacheError::NotFound(err.msg), &res)
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(GetApiCacheError::Unauthorized(err.msg))
+                    return RusotoError::service(GetApiCacheError::Unauthorized(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetApiCacheError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2507,12 +2630,14 @@ impl fmt::Display for GetApiCacheError {
             GetApiCacheError::InternalFailure(ref cause) => write!(f, "{}", cause),
             GetApiCacheError::NotFound(ref cause) => write!(f, "{}", cause),
             GetApiCacheError::Unauthorized(ref cause) => write!(f, "{}", cause),
+            GetApiCacheError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetApiCacheError {}
 /// Errors returned by GetDataSource
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetDataSourceError {
     /// <p>The request is not well formed. For example, a value is invalid or a required field is missing. Check the field values, and then try again. </p>
     BadRequest(String),
@@ -2524,6 +2649,8 @@ pub enum GetDataSourceError {
     NotFound(String),
     /// <p>You are not authorized to perform this operation.</p>
     Unauthorized(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetDataSourceError {
@@ -2531,24 +2658,25 @@ impl GetDataSourceError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetDataSourceError::BadRequest(err.msg))
+                    return RusotoError::service(GetDataSourceError::BadRequest(err.msg), &res)
                 }
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(GetDataSourceError::ConcurrentModification(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetDataSourceError::ConcurrentModification(err.msg),
+                        &res,
+                    )
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(GetDataSourceError::InternalFailure(err.msg))
+                    return RusotoError::service(GetDataSourceError::InternalFailure(err.msg), &res)
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetDataSourceError::NotFound(err.msg))
+                    return RusotoError::service(GetDataSourceError::NotFound(err.msg), &res)
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(GetDataSourceError::Unauthorized(err.msg))
+                    return RusotoError::service(GetDataSourceError::Unauthorized(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetDataSourceError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2563,12 +2691,14 @@ impl fmt::Display for GetDataSourceError {
             GetDataSourceError::InternalFailure(ref cause) => write!(f, "{}", cause),
             GetDataSourceError::NotFound(ref cause) => write!(f, "{}", cause),
             GetDataSourceError::Unauthorized(ref cause) => write!(f, "{}", cause),
+            GetDataSourceError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetDataSourceError {}
 /// Errors returned by GetFunction
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetFunctionError {
     /// <p>Another modification is in progress at this time and it must complete before you can make your change. </p>
     ConcurrentModification(String),
@@ -2576,6 +2706,8 @@ pub enum GetFunctionError {
     NotFound(String),
     /// <p>You are not authorized to perform this operation.</p>
     Unauthorized(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetFunctionError {
@@ -2583,16 +2715,19 @@ impl GetFunctionError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(GetFunctionError::ConcurrentModification(err.msg))
+                    return RusotoError::service(
+                        GetFunctionError::ConcurrentModification(err.msg),
+                        &res,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetFunctionError::NotFound(err.msg))
+                    return RusotoError::service(GetFunctionError::NotFound(err.msg), &res)
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(GetFunctionError::Unauthorized(err.msg))
+                    return RusotoError::service(GetFunctionError::Unauthorized(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetFunctionError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2605,12 +2740,14 @@ impl fmt::Display for GetFunctionError {
             GetFunctionError::ConcurrentModification(ref cause) => write!(f, "{}", cause),
             GetFunctionError::NotFound(ref cause) => write!(f, "{}", cause),
             GetFunctionError::Unauthorized(ref cause) => write!(f, "{}", cause),
+            GetFunctionError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetFunctionError {}
 /// Errors returned by GetGraphqlApi
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetGraphqlApiError {
     /// <p>You do not have access to perform this operation on this resource.</p>
     AccessDenied(String),
@@ -2622,6 +2759,8 @@ pub enum GetGraphqlApiError {
     NotFound(String),
     /// <p>You are not authorized to perform this operation.</p>
     Unauthorized(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetGraphqlApiError {
@@ -2629,22 +2768,22 @@ impl GetGraphqlApiError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(GetGraphqlApiError::AccessDenied(err.msg))
+                    return RusotoError::service(GetGraphqlApiError::AccessDenied(err.msg), &res)
                 }
                 "BadRequestException" => {
-                    return RusotoError::Service(GetGraphqlApiError::BadRequest(err.msg))
+                    return RusotoError::service(GetGraphqlApiError::BadRequest(err.msg), &res)
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(GetGraphqlApiError::InternalFailure(err.msg))
+                    return RusotoError::service(GetGraphqlApiError::InternalFailure(err.msg), &res)
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetGraphqlApiError::NotFound(err.msg))
+                    return RusotoError::service(GetGraphqlApiError::NotFound(err.msg), &res)
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(GetGraphqlApiError::Unauthorized(err.msg))
+                    return RusotoError::service(GetGraphqlApiError::Unauthorized(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetGraphqlApiError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2659,12 +2798,14 @@ impl fmt::Display for GetGraphqlApiError {
             GetGraphqlApiError::InternalFailure(ref cause) => write!(f, "{}", cause),
             GetGraphqlApiError::NotFound(ref cause) => write!(f, "{}", cause),
             GetGraphqlApiError::Unauthorized(ref cause) => write!(f, "{}", cause),
+            GetGraphqlApiError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetGraphqlApiError {}
 /// Errors returned by GetIntrospectionSchema
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetIntrospectionSchemaError {
     /// <p>The GraphQL schema is not valid.</p>
     GraphQLSchema(String),
@@ -2674,6 +2815,8 @@ pub enum GetIntrospectionSchemaError {
     NotFound(String),
     /// <p>You are not authorized to perform this operation.</p>
     Unauthorized(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetIntrospectionSchemaError {
@@ -2681,23 +2824,31 @@ impl GetIntrospectionSchemaError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "GraphQLSchemaException" => {
-                    return RusotoError::Service(GetIntrospectionSchemaError::GraphQLSchema(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetIntrospectionSchemaError::GraphQLSchema(err.msg),
+                        &res,
+                    )
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(GetIntrospectionSchemaError::InternalFailure(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetIntrospectionSchemaError::InternalFailure(err.msg),
+                        &res,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetIntrospectionSchemaError::NotFound(err.msg))
+                    return RusotoError::service(
+                        GetIntrospectionSchemaError::NotFound(err.msg),
+                        &res,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(GetIntrospectionSchemaError::Unauthorized(err.msg))
+                    return RusotoError::service(
+                        GetIntrospectionSchemaError::Unauthorized(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetIntrospectionSchemaError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2711,12 +2862,14 @@ impl fmt::Display for GetIntrospectionSchemaError {
             GetIntrospectionSchemaError::InternalFailure(ref cause) => write!(f, "{}", cause),
             GetIntrospectionSchemaError::NotFound(ref cause) => write!(f, "{}", cause),
             GetIntrospectionSchemaError::Unauthorized(ref cause) => write!(f, "{}", cause),
+            GetIntrospectionSchemaError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetIntrospectionSchemaError {}
 /// Errors returned by GetResolver
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetResolverError {
     /// <p>Another modification is in progress at this time and it must complete before you can make your change. </p>
     ConcurrentModification(String),
@@ -2724,6 +2877,8 @@ pub enum GetResolverError {
     NotFound(String),
     /// <p>You are not authorized to perform this operation.</p>
     Unauthorized(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetResolverError {
@@ -2731,16 +2886,19 @@ impl GetResolverError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(GetResolverError::ConcurrentModification(err.msg))
+                    return RusotoError::service(
+                        GetResolverError::ConcurrentModification(err.msg),
+                        &res,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetResolverError::NotFound(err.msg))
+                    return RusotoError::service(GetResolverError::NotFound(err.msg), &res)
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(GetResolverError::Unauthorized(err.msg))
+                    return RusotoError::service(GetResolverError::Unauthorized(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetResolverError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2753,12 +2911,14 @@ impl fmt::Display for GetResolverError {
             GetResolverError::ConcurrentModification(ref cause) => write!(f, "{}", cause),
             GetResolverError::NotFound(ref cause) => write!(f, "{}", cause),
             GetResolverError::Unauthorized(ref cause) => write!(f, "{}", cause),
+            GetResolverError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetResolverError {}
 /// Errors returned by GetSchemaCreationStatus
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetSchemaCreationStatusError {
     /// <p>The request is not well formed. For example, a value is invalid or a required field is missing. Check the field values, and then try again. </p>
     BadRequest(String),
@@ -2768,6 +2928,8 @@ pub enum GetSchemaCreationStatusError {
     NotFound(String),
     /// <p>You are not authorized to perform this operation.</p>
     Unauthorized(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetSchemaCreationStatusError {
@@ -2775,23 +2937,31 @@ impl GetSchemaCreationStatusError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetSchemaCreationStatusError::BadRequest(err.msg))
+                    return RusotoError::service(
+                        GetSchemaCreationStatusError::BadRequest(err.msg),
+                        &res,
+                    )
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(GetSchemaCreationStatusError::InternalFailure(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetSchemaCreationStatusError::InternalFailure(err.msg),
+                        &res,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetSchemaCreationStatusError::NotFound(err.msg))
+                    return RusotoError::service(
+                        GetSchemaCreationStatusError::NotFound(err.msg),
+                        &res,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(GetSchemaCreationStatusError::Unauthorized(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetSchemaCreationStatusError::Unauthorized(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetSchemaCreationStatusError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2805,12 +2975,14 @@ impl fmt::Display for GetSchemaCreationStatusError {
             GetSchemaCreationStatusError::InternalFailure(ref cause) => write!(f, "{}", cause),
             GetSchemaCreationStatusError::NotFound(ref cause) => write!(f, "{}", cause),
             GetSchemaCreationStatusError::Unauthorized(ref cause) => write!(f, "{}", cause),
+            GetSchemaCreationStatusError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetSchemaCreationStatusError {}
 /// Errors returned by GetType
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetTypeError {
     /// <p>The request is not well formed. For example, a value is invalid or a required field is missing. Check the field values, and then try again. </p>
     BadRequest(String),
@@ -2822,6 +2994,8 @@ pub enum GetTypeError {
     NotFound(String),
     /// <p>You are not authorized to perform this operation.</p>
     Unauthorized(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetTypeError {
@@ -2829,22 +3003,25 @@ impl GetTypeError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetTypeError::BadRequest(err.msg))
+                    return RusotoError::service(GetTypeError::BadRequest(err.msg), &res)
                 }
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(GetTypeError::ConcurrentModification(err.msg))
+                    return RusotoError::service(
+                        GetTypeError::ConcurrentModification(err.msg),
+                        &res,
+                    )
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(GetTypeError::InternalFailure(err.msg))
+                    return RusotoError::service(GetTypeError::InternalFailure(err.msg), &res)
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetTypeError::NotFound(err.msg))
+                    return RusotoError::service(GetTypeError::NotFound(err.msg), &res)
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(GetTypeError::Unauthorized(err.msg))
+                    return RusotoError::service(GetTypeError::Unauthorized(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetTypeError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2859,12 +3036,14 @@ impl fmt::Display for GetTypeError {
             GetTypeError::InternalFailure(ref cause) => write!(f, "{}", cause),
             GetTypeError::NotFound(ref cause) => write!(f, "{}", cause),
             GetTypeError::Unauthorized(ref cause) => write!(f, "{}", cause),
+            GetTypeError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetTypeError {}
 /// Errors returned by ListApiKeys
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListApiKeysError {
     /// <p>The request is not well formed. For example, a value is invalid or a required field is missing. Check the field values, and then try again. </p>
     BadRequest(String),
@@ -2874,6 +3053,8 @@ pub enum ListApiKeysError {
     NotFound(String),
     /// <p>You are not authorized to perform this operation.</p>
     Unauthorized(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListApiKeysError {
@@ -2881,19 +3062,19 @@ impl ListApiKeysError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(ListApiKeysError::BadRequest(err.msg))
+                    return RusotoError::service(ListApiKeysError::BadRequest(err.msg), &res)
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(ListApiKeysError::InternalFailure(err.msg))
+                    return RusotoError::service(ListApiKeysError::InternalFailure(err.msg), &res)
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(ListApiKeysError::NotFound(err.msg))
+                    return RusotoError::service(ListApiKeysError::NotFound(err.msg), &res)
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(ListApiKeysError::Unauthorized(err.msg))
+                    return RusotoError::service(ListApiKeysError::Unauthorized(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListApiKeysError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2907,12 +3088,14 @@ impl fmt::Display for ListApiKeysError {
             ListApiKeysError::InternalFailure(ref cause) => write!(f, "{}", cause),
             ListApiKeysError::NotFound(ref cause) => write!(f, "{}", cause),
             ListApiKeysError::Unauthorized(ref cause) => write!(f, "{}", cause),
+            ListApiKeysError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListApiKeysError {}
 /// Errors returned by ListDataSources
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListDataSourcesError {
     /// <p>The request is not well formed. For example, a value is invalid or a required field is missing. Check the field values, and then try again. </p>
     BadRequest(String),
@@ -2922,6 +3105,8 @@ pub enum ListDataSourcesError {
     NotFound(String),
     /// <p>You are not authorized to perform this operation.</p>
     Unauthorized(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListDataSourcesError {
@@ -2929,19 +3114,22 @@ impl ListDataSourcesError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(ListDataSourcesError::BadRequest(err.msg))
+                    return RusotoError::service(ListDataSourcesError::BadRequest(err.msg), &res)
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(ListDataSourcesError::InternalFailure(err.msg))
+                    return RusotoError::service(
+                        ListDataSourcesError::InternalFailure(err.msg),
+                        &res,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(ListDataSourcesError::NotFound(err.msg))
+                    return RusotoError::service(ListDataSourcesError::NotFound(err.msg), &res)
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(ListDataSourcesError::Unauthorized(err.msg))
+                    return RusotoError::service(ListDataSourcesError::Unauthorized(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListDataSourcesError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2955,12 +3143,14 @@ impl fmt::Display for ListDataSourcesError {
             ListDataSourcesError::InternalFailure(ref cause) => write!(f, "{}", cause),
             ListDataSourcesError::NotFound(ref cause) => write!(f, "{}", cause),
             ListDataSourcesError::Unauthorized(ref cause) => write!(f, "{}", cause),
+            ListDataSourcesError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListDataSourcesError {}
 /// Errors returned by ListFunctions
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListFunctionsError {
     /// <p>The request is not well formed. For example, a value is invalid or a required field is missing. Check the field values, and then try again. </p>
     BadRequest(String),
@@ -2970,6 +3160,8 @@ pub enum ListFunctionsError {
     NotFound(String),
     /// <p>You are not authorized to perform this operation.</p>
     Unauthorized(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListFunctionsError {
@@ -2977,19 +3169,19 @@ impl ListFunctionsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(ListFunctionsError::BadRequest(err.msg))
+                    return RusotoError::service(ListFunctionsError::BadRequest(err.msg), &res)
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(ListFunctionsError::InternalFailure(err.msg))
+                    return RusotoError::service(ListFunctionsError::InternalFailure(err.msg), &res)
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(ListFunctionsError::NotFound(err.msg))
+                    return RusotoError::service(ListFunctionsError::NotFound(err.msg), &res)
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(ListFunctionsError::Unauthorized(err.msg))
+                    return RusotoError::service(ListFunctionsError::Unauthorized(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListFunctionsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3003,12 +3195,14 @@ impl fmt::Display for ListFunctionsError {
             ListFunctionsError::InternalFailure(ref cause) => write!(f, "{}", cause),
             ListFunctionsError::NotFound(ref cause) => write!(f, "{}", cause),
             ListFunctionsError::Unauthorized(ref cause) => write!(f, "{}", cause),
+            ListFunctionsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListFunctionsError {}
 /// Errors returned by ListGraphqlApis
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListGraphqlApisError {
     /// <p>The request is not well formed. For example, a value is invalid or a required field is missing. Check the field values, and then try again. </p>
     BadRequest(String),
@@ -3016,6 +3210,8 @@ pub enum ListGraphqlApisError {
     InternalFailure(String),
     /// <p>You are not authorized to perform this operation.</p>
     Unauthorized(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListGraphqlApisError {
@@ -3023,16 +3219,19 @@ impl ListGraphqlApisError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(ListGraphqlApisError::BadRequest(err.msg))
+                    return RusotoError::service(ListGraphqlApisError::BadRequest(err.msg), &res)
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(ListGraphqlApisError::InternalFailure(err.msg))
+                    return RusotoError::service(
+                        ListGraphqlApisError::InternalFailure(err.msg),
+                        &res,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(ListGraphqlApisError::Unauthorized(err.msg))
+                    return RusotoError::service(ListGraphqlApisError::Unauthorized(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListGraphqlApisError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3045,12 +3244,14 @@ impl fmt::Display for ListGraphqlApisError {
             ListGraphqlApisError::BadRequest(ref cause) => write!(f, "{}", cause),
             ListGraphqlApisError::InternalFailure(ref cause) => write!(f, "{}", cause),
             ListGraphqlApisError::Unauthorized(ref cause) => write!(f, "{}", cause),
+            ListGraphqlApisError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListGraphqlApisError {}
 /// Errors returned by ListResolvers
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListResolversError {
     /// <p>The request is not well formed. For example, a value is invalid or a required field is missing. Check the field values, and then try again. </p>
     BadRequest(String),
@@ -3060,6 +3261,8 @@ pub enum ListResolversError {
     NotFound(String),
     /// <p>You are not authorized to perform this operation.</p>
     Unauthorized(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListResolversError {
@@ -3067,19 +3270,19 @@ impl ListResolversError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(ListResolversError::BadRequest(err.msg))
+                    return RusotoError::service(ListResolversError::BadRequest(err.msg), &res)
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(ListResolversError::InternalFailure(err.msg))
+                    return RusotoError::service(ListResolversError::InternalFailure(err.msg), &res)
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(ListResolversError::NotFound(err.msg))
+                    return RusotoError::service(ListResolversError::NotFound(err.msg), &res)
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(ListResolversError::Unauthorized(err.msg))
+                    return RusotoError::service(ListResolversError::Unauthorized(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListResolversError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3093,12 +3296,14 @@ impl fmt::Display for ListResolversError {
             ListResolversError::InternalFailure(ref cause) => write!(f, "{}", cause),
             ListResolversError::NotFound(ref cause) => write!(f, "{}", cause),
             ListResolversError::Unauthorized(ref cause) => write!(f, "{}", cause),
+            ListResolversError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListResolversError {}
 /// Errors returned by ListResolversByFunction
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListResolversByFunctionError {
     /// <p>The request is not well formed. For example, a value is invalid or a required field is missing. Check the field values, and then try again. </p>
     BadRequest(String),
@@ -3108,6 +3313,8 @@ pub enum ListResolversByFunctionError {
     NotFound(String),
     /// <p>You are not authorized to perform this operation.</p>
     Unauthorized(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListResolversByFunctionError {
@@ -3115,23 +3322,31 @@ impl ListResolversByFunctionError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(ListResolversByFunctionError::BadRequest(err.msg))
+                    return RusotoError::service(
+                        ListResolversByFunctionError::BadRequest(err.msg),
+                        &res,
+                    )
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(ListResolversByFunctionError::InternalFailure(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListResolversByFunctionError::InternalFailure(err.msg),
+                        &res,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(ListResolversByFunctionError::NotFound(err.msg))
+                    return RusotoError::service(
+                        ListResolversByFunctionError::NotFound(err.msg),
+                        &res,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(ListResolversByFunctionError::Unauthorized(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListResolversByFunctionError::Unauthorized(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListResolversByFunctionError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3145,12 +3360,14 @@ impl fmt::Display for ListResolversByFunctionError {
             ListResolversByFunctionError::InternalFailure(ref cause) => write!(f, "{}", cause),
             ListResolversByFunctionError::NotFound(ref cause) => write!(f, "{}", cause),
             ListResolversByFunctionError::Unauthorized(ref cause) => write!(f, "{}", cause),
+            ListResolversByFunctionError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListResolversByFunctionError {}
 /// Errors returned by ListTagsForResource
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListTagsForResourceError {
     /// <p>You do not have access to perform this operation on this resource.</p>
     AccessDenied(String),
@@ -3164,6 +3381,8 @@ pub enum ListTagsForResourceError {
     NotFound(String),
     /// <p>You are not authorized to perform this operation.</p>
     Unauthorized(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListTagsForResourceError {
@@ -3171,25 +3390,40 @@ impl ListTagsForResourceError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(ListTagsForResourceError::AccessDenied(err.msg))
+                    return RusotoError::service(
+                        ListTagsForResourceError::AccessDenied(err.msg),
+                        &res,
+                    )
                 }
                 "BadRequestException" => {
-                    return RusotoError::Service(ListTagsForResourceError::BadRequest(err.msg))
+                    return RusotoError::service(
+                        ListTagsForResourceError::BadRequest(err.msg),
+                        &res,
+                    )
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(ListTagsForResourceError::InternalFailure(err.msg))
+                    return RusotoError::service(
+                        ListTagsForResourceError::InternalFailure(err.msg),
+                        &res,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(ListTagsForResourceError::LimitExceeded(err.msg))
+                    return RusotoError::service(
+                        ListTagsForResourceError::LimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(ListTagsForResourceError::NotFound(err.msg))
+                    return RusotoError::service(ListTagsForResourceError::NotFound(err.msg), &res)
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(ListTagsForResourceError::Unauthorized(err.msg))
+                    return RusotoError::service(
+                        ListTagsForResourceError::Unauthorized(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListTagsForResourceError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3205,12 +3439,14 @@ impl fmt::Display for ListTagsForResourceError {
             ListTagsForResourceError::LimitExceeded(ref cause) => write!(f, "{}", cause),
             ListTagsForResourceError::NotFound(ref cause) => write!(f, "{}", cause),
             ListTagsForResourceError::Unauthorized(ref cause) => write!(f, "{}", cause),
+            ListTagsForResourceError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListTagsForResourceError {}
 /// Errors returned by ListTypes
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListTypesError {
     /// <p>The request is not well formed. For example, a value is invalid or a required field is missing. Check the field values, and then try again. </p>
     BadRequest(String),
@@ -3222,6 +3458,8 @@ pub enum ListTypesError {
     NotFound(String),
     /// <p>You are not authorized to perform this operation.</p>
     Unauthorized(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListTypesError {
@@ -3229,22 +3467,25 @@ impl ListTypesError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(ListTypesError::BadRequest(err.msg))
+                    return RusotoError::service(ListTypesError::BadRequest(err.msg), &res)
                 }
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(ListTypesError::ConcurrentModification(err.msg))
+                    return RusotoError::service(
+                        ListTypesError::ConcurrentModification(err.msg),
+                        &res,
+                    )
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(ListTypesError::InternalFailure(err.msg))
+                    return RusotoError::service(ListTypesError::InternalFailure(err.msg), &res)
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(ListTypesError::NotFound(err.msg))
+                    return RusotoError::service(ListTypesError::NotFound(err.msg), &res)
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(ListTypesError::Unauthorized(err.msg))
+                    return RusotoError::service(ListTypesError::Unauthorized(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListTypesError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3259,12 +3500,14 @@ impl fmt::Display for ListTypesError {
             ListTypesError::InternalFailure(ref cause) => write!(f, "{}", cause),
             ListTypesError::NotFound(ref cause) => write!(f, "{}", cause),
             ListTypesError::Unauthorized(ref cause) => write!(f, "{}", cause),
+            ListTypesError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListTypesError {}
 /// Errors returned by StartSchemaCreation
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum StartSchemaCreationError {
     /// <p>The request is not well formed. For example, a value is invalid or a required field is missing. Check the field values, and then try again. </p>
     BadRequest(String),
@@ -3276,6 +3519,8 @@ pub enum StartSchemaCreationError {
     NotFound(String),
     /// <p>You are not authorized to perform this operation.</p>
     Unauthorized(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl StartSchemaCreationError {
@@ -3283,24 +3528,34 @@ impl StartSchemaCreationError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(StartSchemaCreationError::BadRequest(err.msg))
+                    return RusotoError::service(
+                        StartSchemaCreationError::BadRequest(err.msg),
+                        &res,
+                    )
                 }
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(StartSchemaCreationError::ConcurrentModification(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        StartSchemaCreationError::ConcurrentModification(err.msg),
+                        &res,
+                    )
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(StartSchemaCreationError::InternalFailure(err.msg))
+                    return RusotoError::service(
+                        StartSchemaCreationError::InternalFailure(err.msg),
+                        &res,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(StartSchemaCreationError::NotFound(err.msg))
+                    return RusotoError::service(StartSchemaCreationError::NotFound(err.msg), &res)
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(StartSchemaCreationError::Unauthorized(err.msg))
+                    return RusotoError::service(
+                        StartSchemaCreationError::Unauthorized(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(StartSchemaCreationError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3315,12 +3570,14 @@ impl fmt::Display for StartSchemaCreationError {
             StartSchemaCreationError::InternalFailure(ref cause) => write!(f, "{}", cause),
             StartSchemaCreationError::NotFound(ref cause) => write!(f, "{}", cause),
             StartSchemaCreationError::Unauthorized(ref cause) => write!(f, "{}", cause),
+            StartSchemaCreationError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for StartSchemaCreationError {}
 /// Errors returned by TagResource
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum TagResourceError {
     /// <p>You do not have access to perform this operation on this resource.</p>
     AccessDenied(String),
@@ -3334,6 +3591,8 @@ pub enum TagResourceError {
     NotFound(String),
     /// <p>You are not authorized to perform this operation.</p>
     Unauthorized(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl TagResourceError {
@@ -3341,25 +3600,25 @@ impl TagResourceError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(TagResourceError::AccessDenied(err.msg))
+                    return RusotoError::service(TagResourceError::AccessDenied(err.msg), &res)
                 }
                 "BadRequestException" => {
-                    return RusotoError::Service(TagResourceError::BadRequest(err.msg))
+                    return RusotoError::service(TagResourceError::BadRequest(err.msg), &res)
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(TagResourceError::InternalFailure(err.msg))
+                    return RusotoError::service(TagResourceError::InternalFailure(err.msg), &res)
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(TagResourceError::LimitExceeded(err.msg))
+                    return RusotoError::service(TagResourceError::LimitExceeded(err.msg), &res)
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(TagResourceError::NotFound(err.msg))
+                    return RusotoError::service(TagResourceError::NotFound(err.msg), &res)
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(TagResourceError::Unauthorized(err.msg))
+                    return RusotoError::service(TagResourceError::Unauthorized(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(TagResourceError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3375,12 +3634,14 @@ impl fmt::Display for TagResourceError {
             TagResourceError::LimitExceeded(ref cause) => write!(f, "{}", cause),
             TagResourceError::NotFound(ref cause) => write!(f, "{}", cause),
             TagResourceError::Unauthorized(ref cause) => write!(f, "{}", cause),
+            TagResourceError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for TagResourceError {}
 /// Errors returned by UntagResource
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UntagResourceError {
     /// <p>You do not have access to perform this operation on this resource.</p>
     AccessDenied(String),
@@ -3394,6 +3655,8 @@ pub enum UntagResourceError {
     NotFound(String),
     /// <p>You are not authorized to perform this operation.</p>
     Unauthorized(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UntagResourceError {
@@ -3401,25 +3664,25 @@ impl UntagResourceError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(UntagResourceError::AccessDenied(err.msg))
+                    return RusotoError::service(UntagResourceError::AccessDenied(err.msg), &res)
                 }
                 "BadRequestException" => {
-                    return RusotoError::Service(UntagResourceError::BadRequest(err.msg))
+                    return RusotoError::service(UntagResourceError::BadRequest(err.msg), &res)
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(UntagResourceError::InternalFailure(err.msg))
+                    return RusotoError::service(UntagResourceError::InternalFailure(err.msg), &res)
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(UntagResourceError::LimitExceeded(err.msg))
+                    return RusotoError::service(UntagResourceError::LimitExceeded(err.msg), &res)
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(UntagResourceError::NotFound(err.msg))
+                    return RusotoError::service(UntagResourceError::NotFound(err.msg), &res)
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(UntagResourceError::Unauthorized(err.msg))
+                    return RusotoError::service(UntagResourceError::Unauthorized(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UntagResourceError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3435,12 +3698,14 @@ impl fmt::Display for UntagResourceError {
             UntagResourceError::LimitExceeded(ref cause) => write!(f, "{}", cause),
             UntagResourceError::NotFound(ref cause) => write!(f, "{}", cause),
             UntagResourceError::Unauthorized(ref cause) => write!(f, "{}", cause),
+            UntagResourceError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for UntagResourceError {}
 /// Errors returned by UpdateApiCache
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdateApiCacheError {
     /// <p>The request is not well formed. For example, a value is invalid or a required field is missing. Check the field values, and then try again. </p>
     BadRequest(String),
@@ -3452,6 +3717,8 @@ pub enum UpdateApiCacheError {
     NotFound(String),
     /// <p>You are not authorized to perform this operation.</p>
     Unauthorized(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdateApiCacheError {
@@ -3459,24 +3726,28 @@ impl UpdateApiCacheError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(UpdateApiCacheError::BadRequest(err.msg))
+                    return RusotoError::service(UpdateApiCacheError::BadRequest(err.msg), &res)
                 }
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(UpdateApiCacheError::ConcurrentModification(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateApiCacheError::ConcurrentModification(err.msg),
+                        &res,
+                    )
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(UpdateApiCacheError::InternalFailure(err.msg))
+                    return RusotoError::service(
+                        UpdateApiCacheError::InternalFailure(err.msg),
+                        &res,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(UpdateApiCacheError::NotFound(err.msg))
+                    return RusotoError::service(UpdateApiCacheError::NotFound(err.msg), &res)
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(UpdateApiCacheError::Unauthorized(err.msg))
+                    return RusotoError::service(UpdateApiCacheError::Unauthorized(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UpdateApiCacheError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3491,12 +3762,14 @@ impl fmt::Display for UpdateApiCacheError {
             UpdateApiCacheError::InternalFailure(ref cause) => write!(f, "{}", cause),
             UpdateApiCacheError::NotFound(ref cause) => write!(f, "{}", cause),
             UpdateApiCacheError::Unauthorized(ref cause) => write!(f, "{}", cause),
+            UpdateApiCacheError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for UpdateApiCacheError {}
 /// Errors returned by UpdateApiKey
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdateApiKeyError {
     /// <p>The API key expiration must be set to a value between 1 and 365 days from creation (for <code>CreateApiKey</code>) or from update (for <code>UpdateApiKey</code>).</p>
     ApiKeyValidityOutOfBounds(String),
@@ -3510,6 +3783,8 @@ pub enum UpdateApiKeyError {
     NotFound(String),
     /// <p>You are not authorized to perform this operation.</p>
     Unauthorized(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdateApiKeyError {
@@ -3517,27 +3792,28 @@ impl UpdateApiKeyError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ApiKeyValidityOutOfBoundsException" => {
-                    return RusotoError::Service(UpdateApiKeyError::ApiKeyValidityOutOfBounds(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateApiKeyError::ApiKeyValidityOutOfBounds(err.msg),
+                        &res,
+                    )
                 }
                 "BadRequestException" => {
-                    return RusotoError::Service(UpdateApiKeyError::BadRequest(err.msg))
+                    return RusotoError::service(UpdateApiKeyError::BadRequest(err.msg), &res)
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(UpdateApiKeyError::InternalFailure(err.msg))
+                    return RusotoError::service(UpdateApiKeyError::InternalFailure(err.msg), &res)
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(UpdateApiKeyError::LimitExceeded(err.msg))
+                    return RusotoError::service(UpdateApiKeyError::LimitExceeded(err.msg), &res)
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(UpdateApiKeyError::NotFound(err.msg))
+                    return RusotoError::service(UpdateApiKeyError::NotFound(err.msg), &res)
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(UpdateApiKeyError::Unauthorized(err.msg))
+                    return RusotoError::service(UpdateApiKeyError::Unauthorized(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UpdateApiKeyError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3553,12 +3829,14 @@ impl fmt::Display for UpdateApiKeyError {
             UpdateApiKeyError::LimitExceeded(ref cause) => write!(f, "{}", cause),
             UpdateApiKeyError::NotFound(ref cause) => write!(f, "{}", cause),
             UpdateApiKeyError::Unauthorized(ref cause) => write!(f, "{}", cause),
+            UpdateApiKeyError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for UpdateApiKeyError {}
 /// Errors returned by UpdateDataSource
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdateDataSourceError {
     /// <p>The request is not well formed. For example, a value is invalid or a required field is missing. Check the field values, and then try again. </p>
     BadRequest(String),
@@ -3570,6 +3848,8 @@ pub enum UpdateDataSourceError {
     NotFound(String),
     /// <p>You are not authorized to perform this operation.</p>
     Unauthorized(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdateDataSourceError {
@@ -3577,24 +3857,28 @@ impl UpdateDataSourceError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(UpdateDataSourceError::BadRequest(err.msg))
+                    return RusotoError::service(UpdateDataSourceError::BadRequest(err.msg), &res)
                 }
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(UpdateDataSourceError::ConcurrentModification(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateDataSourceError::ConcurrentModification(err.msg),
+                        &res,
+                    )
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(UpdateDataSourceError::InternalFailure(err.msg))
+                    return RusotoError::service(
+                        UpdateDataSourceError::InternalFailure(err.msg),
+                        &res,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(UpdateDataSourceError::NotFound(err.msg))
+                    return RusotoError::service(UpdateDataSourceError::NotFound(err.msg), &res)
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(UpdateDataSourceError::Unauthorized(err.msg))
+                    return RusotoError::service(UpdateDataSourceError::Unauthorized(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UpdateDataSourceError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3609,12 +3893,14 @@ impl fmt::Display for UpdateDataSourceError {
             UpdateDataSourceError::InternalFailure(ref cause) => write!(f, "{}", cause),
             UpdateDataSourceError::NotFound(ref cause) => write!(f, "{}", cause),
             UpdateDataSourceError::Unauthorized(ref cause) => write!(f, "{}", cause),
+            UpdateDataSourceError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for UpdateDataSourceError {}
 /// Errors returned by UpdateFunction
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdateFunctionError {
     /// <p>Another modification is in progress at this time and it must complete before you can make your change. </p>
     ConcurrentModification(String),
@@ -3624,6 +3910,8 @@ pub enum UpdateFunctionError {
     NotFound(String),
     /// <p>You are not authorized to perform this operation.</p>
     Unauthorized(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdateFunctionError {
@@ -3631,21 +3919,25 @@ impl UpdateFunctionError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(UpdateFunctionError::ConcurrentModification(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateFunctionError::ConcurrentModification(err.msg),
+                        &res,
+                    )
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(UpdateFunctionError::InternalFailure(err.msg))
+                    return RusotoError::service(
+                        UpdateFunctionError::InternalFailure(err.msg),
+                        &res,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(UpdateFunctionError::NotFound(err.msg))
+                    return RusotoError::service(UpdateFunctionError::NotFound(err.msg), &res)
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(UpdateFunctionError::Unauthorized(err.msg))
+                    return RusotoError::service(UpdateFunctionError::Unauthorized(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UpdateFunctionError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3659,12 +3951,14 @@ impl fmt::Display for UpdateFunctionError {
             UpdateFunctionError::InternalFailure(ref cause) => write!(f, "{}", cause),
             UpdateFunctionError::NotFound(ref cause) => write!(f, "{}", cause),
             UpdateFunctionError::Unauthorized(ref cause) => write!(f, "{}", cause),
+            UpdateFunctionError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for UpdateFunctionError {}
 /// Errors returned by UpdateGraphqlApi
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdateGraphqlApiError {
     /// <p>You do not have access to perform this operation on this resource.</p>
     AccessDenied(String),
@@ -3678,6 +3972,8 @@ pub enum UpdateGraphqlApiError {
     NotFound(String),
     /// <p>You are not authorized to perform this operation.</p>
     Unauthorized(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdateGraphqlApiError {
@@ -3685,27 +3981,31 @@ impl UpdateGraphqlApiError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(UpdateGraphqlApiError::AccessDenied(err.msg))
+                    return RusotoError::service(UpdateGraphqlApiError::AccessDenied(err.msg), &res)
                 }
                 "BadRequestException" => {
-                    return RusotoError::Service(UpdateGraphqlApiError::BadRequest(err.msg))
+                    return RusotoError::service(UpdateGraphqlApiError::BadRequest(err.msg), &res)
                 }
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(UpdateGraphqlApiError::ConcurrentModification(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateGraphqlApiError::ConcurrentModification(err.msg),
+                        &res,
+                    )
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(UpdateGraphqlApiError::InternalFailure(err.msg))
+                    return RusotoError::service(
+                        UpdateGraphqlApiError::InternalFailure(err.msg),
+                        &res,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(UpdateGraphqlApiError::NotFound(err.msg))
+                    return RusotoError::service(UpdateGraphqlApiError::NotFound(err.msg), &res)
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(UpdateGraphqlApiError::Unauthorized(err.msg))
+                    return RusotoError::service(UpdateGraphqlApiError::Unauthorized(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UpdateGraphqlApiError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3721,12 +4021,14 @@ impl fmt::Display for UpdateGraphqlApiError {
             UpdateGraphqlApiError::InternalFailure(ref cause) => write!(f, "{}", cause),
             UpdateGraphqlApiError::NotFound(ref cause) => write!(f, "{}", cause),
             UpdateGraphqlApiError::Unauthorized(ref cause) => write!(f, "{}", cause),
+            UpdateGraphqlApiError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for UpdateGraphqlApiError {}
 /// Errors returned by UpdateResolver
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdateResolverError {
     /// <p>Another modification is in progress at this time and it must complete before you can make your change. </p>
     ConcurrentModification(String),
@@ -3736,6 +4038,8 @@ pub enum UpdateResolverError {
     NotFound(String),
     /// <p>You are not authorized to perform this operation.</p>
     Unauthorized(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdateResolverError {
@@ -3743,21 +4047,25 @@ impl UpdateResolverError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(UpdateResolverError::ConcurrentModification(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateResolverError::ConcurrentModification(err.msg),
+                        &res,
+                    )
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(UpdateResolverError::InternalFailure(err.msg))
+                    return RusotoError::service(
+                        UpdateResolverError::InternalFailure(err.msg),
+                        &res,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(UpdateResolverError::NotFound(err.msg))
+                    return RusotoError::service(UpdateResolverError::NotFound(err.msg), &res)
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(UpdateResolverError::Unauthorized(err.msg))
+                    return RusotoError::service(UpdateResolverError::Unauthorized(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UpdateResolverError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3771,12 +4079,14 @@ impl fmt::Display for UpdateResolverError {
             UpdateResolverError::InternalFailure(ref cause) => write!(f, "{}", cause),
             UpdateResolverError::NotFound(ref cause) => write!(f, "{}", cause),
             UpdateResolverError::Unauthorized(ref cause) => write!(f, "{}", cause),
+            UpdateResolverError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for UpdateResolverError {}
 /// Errors returned by UpdateType
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdateTypeError {
     /// <p>The request is not well formed. For example, a value is invalid or a required field is missing. Check the field values, and then try again. </p>
     BadRequest(String),
@@ -3788,6 +4098,8 @@ pub enum UpdateTypeError {
     NotFound(String),
     /// <p>You are not authorized to perform this operation.</p>
     Unauthorized(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdateTypeError {
@@ -3795,22 +4107,25 @@ impl UpdateTypeError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(UpdateTypeError::BadRequest(err.msg))
+                    return RusotoError::service(UpdateTypeError::BadRequest(err.msg), &res)
                 }
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(UpdateTypeError::ConcurrentModification(err.msg))
+                    return RusotoError::service(
+                        UpdateTypeError::ConcurrentModification(err.msg),
+                        &res,
+                    )
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(UpdateTypeError::InternalFailure(err.msg))
+                    return RusotoError::service(UpdateTypeError::InternalFailure(err.msg), &res)
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(UpdateTypeError::NotFound(err.msg))
+                    return RusotoError::service(UpdateTypeError::NotFound(err.msg), &res)
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(UpdateTypeError::Unauthorized(err.msg))
+                    return RusotoError::service(UpdateTypeError::Unauthorized(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UpdateTypeError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3825,6 +4140,7 @@ impl fmt::Display for UpdateTypeError {
             UpdateTypeError::InternalFailure(ref cause) => write!(f, "{}", cause),
             UpdateTypeError::NotFound(ref cause) => write!(f, "{}", cause),
             UpdateTypeError::Unauthorized(ref cause) => write!(f, "{}", cause),
+            UpdateTypeError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
@@ -4089,6 +4405,7 @@ impl AppSyncClient {
     /// Creates a client backed by the default tokio event loop.
     ///
     /// The client will use the default credentials provider and tls client.
+    #[cfg(not(target_arch = "wasm32"))]
     pub fn new(region: region::Region) -> AppSyncClient {
         AppSyncClient {
             client: Client::shared(),
@@ -4114,165 +4431,338 @@ impl AppSyncClient {
     pub fn new_with_client(client: Client, region: region::Region) -> AppSyncClient {
         AppSyncClient { client, region }
     }
+
+    /// Returns a builder of a client, setting any of its region, credentials provider,
+    /// dispatcher, retry policy, endpoint and timeouts.
+    pub fn builder() -> rusoto_core::ClientBuilder<AppSyncClient> {
+        rusoto_core::ClientBuilder::new(AppSyncClient::new_with_client)
+    }
+
+    /// Returns a clone of this client with the overrides of `config` applied to its
+    /// operations, sharing its connections.
+    pub fn customize(&self, config: &rusoto_core::OperationConfig) -> AppSyncClient {
+        AppSyncClient {
+            client: self.client.customize(config),
+            region: config.resolve_region(&self.region),
+        }
+    }
 }
 
-#[async_trait]
-impl AppSync for AppSyncClient {
-    /// <p>Creates a cache for the GraphQL API.</p>
-    async fn create_api_cache(
+impl AppSyncClient {
+    /// Calls `create_api_cache`, also returning the metadata of the response.
+    pub async fn create_api_cache_with_metadata(
         &self,
         input: CreateApiCacheRequest,
-    ) -> Result<CreateApiCacheResponse, RusotoError<CreateApiCacheError>> {
+    ) -> Result<(CreateApiCacheResponse, ResponseMetadata), RusotoError<CreateApiCacheError>> {
+        let request = self.build_create_api_cache_request(input);
+
+        let mut response = self
+            .client
+            .sign_and_dispatch(request)
+            .await
+            .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
+        if response.status.is_success() {
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<CreateApiCacheResponse, _>()?;
+
+            Ok((result, metadata))
+        } else {
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(CreateApiCacheError::from_response(response))
+        }
+    }
+
+    /// Presigns the request `create_api_cache` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_api_cache(
+        &self,
+        presigner: &rusoto_core::Presigner,
+        input: CreateApiCacheRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_api_cache_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_api_cache_request(&self, input: CreateApiCacheRequest) -> SignedRequest {
         let request_uri = format!("/v1/apis/{api_id}/ApiCaches", api_id = input.api_id);
 
         let mut request = SignedRequest::new("POST", "appsync", &self.region, &request_uri);
+        request.set_operation("CreateApiCache");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `create_api_key`, also returning the metadata of the response.
+    pub async fn create_api_key_with_metadata(
+        &self,
+        input: CreateApiKeyRequest,
+    ) -> Result<(CreateApiKeyResponse, ResponseMetadata), RusotoError<CreateApiKeyError>> {
+        let request = self.build_create_api_key_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<CreateApiCacheResponse, _>()?;
+                .deserialize::<CreateApiKeyResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(CreateApiCacheError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(CreateApiKeyError::from_response(response))
         }
     }
 
-    /// <p>Creates a unique key that you can distribute to clients who are executing your API.</p>
-    async fn create_api_key(
+    /// Presigns the request `create_api_key` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_api_key(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: CreateApiKeyRequest,
-    ) -> Result<CreateApiKeyResponse, RusotoError<CreateApiKeyError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_api_key_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_api_key_request(&self, input: CreateApiKeyRequest) -> SignedRequest {
         let request_uri = format!("/v1/apis/{api_id}/apikeys", api_id = input.api_id);
 
         let mut request = SignedRequest::new("POST", "appsync", &self.region, &request_uri);
+        request.set_operation("CreateApiKey");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `create_data_source`, also returning the metadata of the response.
+    pub async fn create_data_source_with_metadata(
+        &self,
+        input: CreateDataSourceRequest,
+    ) -> Result<(CreateDataSourceResponse, ResponseMetadata), RusotoError<CreateDataSourceError>>
+    {
+        let request = self.build_create_data_source_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<CreateApiKeyResponse, _>()?;
+                .deserialize::<CreateDataSourceResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(CreateApiKeyError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(CreateDataSourceError::from_response(response))
         }
     }
 
-    /// <p>Creates a <code>DataSource</code> object.</p>
-    async fn create_data_source(
+    /// Presigns the request `create_data_source` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_data_source(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: CreateDataSourceRequest,
-    ) -> Result<CreateDataSourceResponse, RusotoError<CreateDataSourceError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_data_source_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_data_source_request(&self, input: CreateDataSourceRequest) -> SignedRequest {
         let request_uri = format!("/v1/apis/{api_id}/datasources", api_id = input.api_id);
 
         let mut request = SignedRequest::new("POST", "appsync", &self.region, &request_uri);
+        request.set_operation("CreateDataSource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `create_function`, also returning the metadata of the response.
+    pub async fn create_function_with_metadata(
+        &self,
+        input: CreateFunctionRequest,
+    ) -> Result<(CreateFunctionResponse, ResponseMetadata), RusotoError<CreateFunctionError>> {
+        let request = self.build_create_function_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<CreateDataSourceResponse, _>()?;
+                .deserialize::<CreateFunctionResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(CreateDataSourceError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(CreateFunctionError::from_response(response))
         }
     }
 
-    /// <p>Creates a <code>Function</code> object.</p> <p>A function is a reusable entity. Multiple functions can be used to compose the resolver logic.</p>
-    async fn create_function(
+    /// Presigns the request `create_function` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_function(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: CreateFunctionRequest,
-    ) -> Result<CreateFunctionResponse, RusotoError<CreateFunctionError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_function_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_function_request(&self, input: CreateFunctionRequest) -> SignedRequest {
         let request_uri = format!("/v1/apis/{api_id}/functions", api_id = input.api_id);
 
         let mut request = SignedRequest::new("POST", "appsync", &self.region, &request_uri);
+        request.set_operation("CreateFunction");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `create_graphql_api`, also returning the metadata of the response.
+    pub async fn create_graphql_api_with_metadata(
+        &self,
+        input: CreateGraphqlApiRequest,
+    ) -> Result<(CreateGraphqlApiResponse, ResponseMetadata), RusotoError<CreateGraphqlApiError>>
+    {
+        let request = self.build_create_graphql_api_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<CreateFunctionResponse, _>()?;
+                .deserialize::<CreateGraphqlApiResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(CreateFunctionError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(CreateGraphqlApiError::from_response(response))
         }
     }
 
-    /// <p>Creates a <code>GraphqlApi</code> object.</p>
-    async fn create_graphql_api(
+    /// Presigns the request `create_graphql_api` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_graphql_api(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: CreateGraphqlApiRequest,
-    ) -> Result<CreateGraphqlApiResponse, RusotoError<CreateGraphqlApiError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_graphql_api_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_graphql_api_request(&self, input: CreateGraphqlApiRequest) -> SignedRequest {
         let request_uri = "/v1/apis";
 
         let mut request = SignedRequest::new("POST", "appsync", &self.region, &request_uri);
+        request.set_operation("CreateGraphqlApi");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `create_resolver`, also returning the metadata of the response.
+    pub async fn create_resolver_with_metadata(
+        &self,
+        input: CreateResolverRequest,
+    ) -> Result<(CreateResolverResponse, ResponseMetadata), RusotoError<CreateResolverError>> {
+        let request = self.build_create_resolver_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<CreateGraphqlApiResponse, _>()?;
+                .deserialize::<CreateResolverResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(CreateGraphqlApiError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(CreateResolverError::from_response(response))
         }
     }
 
-    /// <p>Creates a <code>Resolver</code> object.</p> <p>A resolver converts incoming requests into a format that a data source can understand and converts the data source's responses into GraphQL.</p>
-    async fn create_resolver(
+    /// Presigns the request `create_resolver` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_resolver(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: CreateResolverRequest,
-    ) -> Result<CreateResolverResponse, RusotoError<CreateResolverError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_resolver_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_resolver_request(&self, input: CreateResolverRequest) -> SignedRequest {
         let request_uri = format!(
             "/v1/apis/{api_id}/types/{type_name}/resolvers",
             api_id = input.api_id,
@@ -4280,90 +4770,162 @@ impl AppSync for AppSyncClient {
         );
 
         let mut request = SignedRequest::new("POST", "appsync", &self.region, &request_uri);
+        request.set_operation("CreateResolver");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `create_type`, also returning the metadata of the response.
+    pub async fn create_type_with_metadata(
+        &self,
+        input: CreateTypeRequest,
+    ) -> Result<(CreateTypeResponse, ResponseMetadata), RusotoError<CreateTypeError>> {
+        let request = self.build_create_type_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<CreateResolverResponse, _>()?;
+                .deserialize::<CreateTypeResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(CreateResolverError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(CreateTypeError::from_response(response))
         }
     }
 
-    /// <p>Creates a <code>Type</code> object.</p>
-    async fn create_type(
+    /// Presigns the request `create_type` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_type(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: CreateTypeRequest,
-    ) -> Result<CreateTypeResponse, RusotoError<CreateTypeError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_type_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_type_request(&self, input: CreateTypeRequest) -> SignedRequest {
         let request_uri = format!("/v1/apis/{api_id}/types", api_id = input.api_id);
 
         let mut request = SignedRequest::new("POST", "appsync", &self.region, &request_uri);
+        request.set_operation("CreateType");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `delete_api_cache`, also returning the metadata of the response.
+    pub async fn delete_api_cache_with_metadata(
+        &self,
+        input: DeleteApiCacheRequest,
+    ) -> Result<(DeleteApiCacheResponse, ResponseMetadata), RusotoError<DeleteApiCacheError>> {
+        let request = self.build_delete_api_cache_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<CreateTypeResponse, _>()?;
+                .deserialize::<DeleteApiCacheResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(CreateTypeError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(DeleteApiCacheError::from_response(response))
         }
     }
 
-    /// <p>Deletes an <code>ApiCache</code> object.</p>
-    async fn delete_api_cache(
+    /// Presigns the request `delete_api_cache` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_api_cache(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteApiCacheRequest,
-    ) -> Result<DeleteApiCacheResponse, RusotoError<DeleteApiCacheError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_api_cache_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_api_cache_request(&self, input: DeleteApiCacheRequest) -> SignedRequest {
         let request_uri = format!("/v1/apis/{api_id}/ApiCaches", api_id = input.api_id);
 
         let mut request = SignedRequest::new("DELETE", "appsync", &self.region, &request_uri);
+        request.set_operation("DeleteApiCache");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
+        request
+    }
+
+    /// Calls `delete_api_key`, also returning the metadata of the response.
+    pub async fn delete_api_key_with_metadata(
+        &self,
+        input: DeleteApiKeyRequest,
+    ) -> Result<(DeleteApiKeyResponse, ResponseMetadata), RusotoError<DeleteApiKeyError>> {
+        let request = self.build_delete_api_key_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<DeleteApiCacheResponse, _>()?;
+                .deserialize::<DeleteApiKeyResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteApiCacheError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(DeleteApiKeyError::from_response(response))
         }
     }
 
-    /// <p>Deletes an API key.</p>
-    async fn delete_api_key(
+    /// Presigns the request `delete_api_key` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_api_key(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteApiKeyRequest,
-    ) -> Result<DeleteApiKeyResponse, RusotoError<DeleteApiKeyError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_api_key_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_api_key_request(&self, input: DeleteApiKeyRequest) -> SignedRequest {
         let request_uri = format!(
             "/v1/apis/{api_id}/apikeys/{id}",
             api_id = input.api_id,
@@ -4371,30 +4933,55 @@ impl AppSync for AppSyncClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "appsync", &self.region, &request_uri);
+        request.set_operation("DeleteApiKey");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
+        request
+    }
+
+    /// Calls `delete_data_source`, also returning the metadata of the response.
+    pub async fn delete_data_source_with_metadata(
+        &self,
+        input: DeleteDataSourceRequest,
+    ) -> Result<(DeleteDataSourceResponse, ResponseMetadata), RusotoError<DeleteDataSourceError>>
+    {
+        let request = self.build_delete_data_source_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<DeleteApiKeyResponse, _>()?;
+                .deserialize::<DeleteDataSourceResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteApiKeyError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(DeleteDataSourceError::from_response(response))
         }
     }
 
-    /// <p>Deletes a <code>DataSource</code> object.</p>
-    async fn delete_data_source(
+    /// Presigns the request `delete_data_source` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_data_source(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteDataSourceRequest,
-    ) -> Result<DeleteDataSourceResponse, RusotoError<DeleteDataSourceError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_data_source_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_data_source_request(&self, input: DeleteDataSourceRequest) -> SignedRequest {
         let request_uri = format!(
             "/v1/apis/{api_id}/datasources/{name}",
             api_id = input.api_id,
@@ -4402,30 +4989,54 @@ impl AppSync for AppSyncClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "appsync", &self.region, &request_uri);
+        request.set_operation("DeleteDataSource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
+        request
+    }
+
+    /// Calls `delete_function`, also returning the metadata of the response.
+    pub async fn delete_function_with_metadata(
+        &self,
+        input: DeleteFunctionRequest,
+    ) -> Result<(DeleteFunctionResponse, ResponseMetadata), RusotoError<DeleteFunctionError>> {
+        let request = self.build_delete_function_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<DeleteDataSourceResponse, _>()?;
+                .deserialize::<DeleteFunctionResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteDataSourceError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(DeleteFunctionError::from_response(response))
         }
     }
 
-    /// <p>Deletes a <code>Function</code>.</p>
-    async fn delete_function(
+    /// Presigns the request `delete_function` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_function(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteFunctionRequest,
-    ) -> Result<DeleteFunctionResponse, RusotoError<DeleteFunctionError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_function_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_function_request(&self, input: DeleteFunctionRequest) -> SignedRequest {
         let request_uri = format!(
             "/v1/apis/{api_id}/functions/{function_id}",
             api_id = input.api_id,
@@ -4433,57 +5044,106 @@ impl AppSync for AppSyncClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "appsync", &self.region, &request_uri);
+        request.set_operation("DeleteFunction");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
+        request
+    }
+
+    /// Calls `delete_graphql_api`, also returning the metadata of the response.
+    pub async fn delete_graphql_api_with_metadata(
+        &self,
+        input: DeleteGraphqlApiRequest,
+    ) -> Result<(DeleteGraphqlApiResponse, ResponseMetadata), RusotoError<DeleteGraphqlApiError>>
+    {
+        let request = self.build_delete_graphql_api_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<DeleteFunctionResponse, _>()?;
+                .deserialize::<DeleteGraphqlApiResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteFunctionError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(DeleteGraphqlApiError::from_response(response))
         }
     }
 
-    /// <p>Deletes a <code>GraphqlApi</code> object.</p>
-    async fn delete_graphql_api(
+    /// Presigns the request `delete_graphql_api` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_graphql_api(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteGraphqlApiRequest,
-    ) -> Result<DeleteGraphqlApiResponse, RusotoError<DeleteGraphqlApiError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_graphql_api_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_graphql_api_request(&self, input: DeleteGraphqlApiRequest) -> SignedRequest {
         let request_uri = format!("/v1/apis/{api_id}", api_id = input.api_id);
 
         let mut request = SignedRequest::new("DELETE", "appsync", &self.region, &request_uri);
+        request.set_operation("DeleteGraphqlApi");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
+        request
+    }
+
+    /// Calls `delete_resolver`, also returning the metadata of the response.
+    pub async fn delete_resolver_with_metadata(
+        &self,
+        input: DeleteResolverRequest,
+    ) -> Result<(DeleteResolverResponse, ResponseMetadata), RusotoError<DeleteResolverError>> {
+        let request = self.build_delete_resolver_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<DeleteGraphqlApiResponse, _>()?;
+                .deserialize::<DeleteResolverResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteGraphqlApiError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(DeleteResolverError::from_response(response))
         }
     }
 
-    /// <p>Deletes a <code>Resolver</code> object.</p>
-    async fn delete_resolver(
+    /// Presigns the request `delete_resolver` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_resolver(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteResolverRequest,
-    ) -> Result<DeleteResolverResponse, RusotoError<DeleteResolverError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_resolver_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_resolver_request(&self, input: DeleteResolverRequest) -> SignedRequest {
         let request_uri = format!(
             "/v1/apis/{api_id}/types/{type_name}/resolvers/{field_name}",
             api_id = input.api_id,
@@ -4492,30 +5152,54 @@ impl AppSync for AppSyncClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "appsync", &self.region, &request_uri);
+        request.set_operation("DeleteResolver");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
+        request
+    }
+
+    /// Calls `delete_type`, also returning the metadata of the response.
+    pub async fn delete_type_with_metadata(
+        &self,
+        input: DeleteTypeRequest,
+    ) -> Result<(DeleteTypeResponse, ResponseMetadata), RusotoError<DeleteTypeError>> {
+        let request = self.build_delete_type_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<DeleteResolverResponse, _>()?;
+                .deserialize::<DeleteTypeResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteResolverError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(DeleteTypeError::from_response(response))
         }
     }
 
-    /// <p>Deletes a <code>Type</code> object.</p>
-    async fn delete_type(
+    /// Presigns the request `delete_type` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_type(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteTypeRequest,
-    ) -> Result<DeleteTypeResponse, RusotoError<DeleteTypeError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_type_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_type_request(&self, input: DeleteTypeRequest) -> SignedRequest {
         let request_uri = format!(
             "/v1/apis/{api_id}/types/{type_name}",
             api_id = input.api_id,
@@ -4523,84 +5207,156 @@ impl AppSync for AppSyncClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "appsync", &self.region, &request_uri);
+        request.set_operation("DeleteType");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
+        request
+    }
+
+    /// Calls `flush_api_cache`, also returning the metadata of the response.
+    pub async fn flush_api_cache_with_metadata(
+        &self,
+        input: FlushApiCacheRequest,
+    ) -> Result<(FlushApiCacheResponse, ResponseMetadata), RusotoError<FlushApiCacheError>> {
+        let request = self.build_flush_api_cache_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<DeleteTypeResponse, _>()?;
+                .deserialize::<FlushApiCacheResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteTypeError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(FlushApiCacheError::from_response(response))
         }
     }
 
-    /// <p>Flushes an <code>ApiCache</code> object.</p>
-    async fn flush_api_cache(
+    /// Presigns the request `flush_api_cache` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_flush_api_cache(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: FlushApiCacheRequest,
-    ) -> Result<FlushApiCacheResponse, RusotoError<FlushApiCacheError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_flush_api_cache_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_flush_api_cache_request(&self, input: FlushApiCacheRequest) -> SignedRequest {
         let request_uri = format!("/v1/apis/{api_id}/FlushCache", api_id = input.api_id);
 
         let mut request = SignedRequest::new("DELETE", "appsync", &self.region, &request_uri);
+        request.set_operation("FlushApiCache");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
+        request
+    }
+
+    /// Calls `get_api_cache`, also returning the metadata of the response.
+    pub async fn get_api_cache_with_metadata(
+        &self,
+        input: GetApiCacheRequest,
+    ) -> Result<(GetApiCacheResponse, ResponseMetadata), RusotoError<GetApiCacheError>> {
+        let request = self.build_get_api_cache_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<FlushApiCacheResponse, _>()?;
+                .deserialize::<GetApiCacheResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(FlushApiCacheError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(GetApiCacheError::from_response(response))
         }
     }
 
-    /// <p>Retrieves an <code>ApiCache</code> object.</p>
-    async fn get_api_cache(
+    /// Presigns the request `get_api_cache` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_api_cache(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetApiCacheRequest,
-    ) -> Result<GetApiCacheResponse, RusotoError<GetApiCacheError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_api_cache_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_api_cache_request(&self, input: GetApiCacheRequest) -> SignedRequest {
         let request_uri = format!("/v1/apis/{api_id}/ApiCaches", api_id = input.api_id);
 
         let mut request = SignedRequest::new("GET", "appsync", &self.region, &request_uri);
+        request.set_operation("GetApiCache");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
+        request
+    }
+
+    /// Calls `get_data_source`, also returning the metadata of the response.
+    pub async fn get_data_source_with_metadata(
+        &self,
+        input: GetDataSourceRequest,
+    ) -> Result<(GetDataSourceResponse, ResponseMetadata), RusotoError<GetDataSourceError>> {
+        let request = self.build_get_data_source_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<GetApiCacheResponse, _>()?;
+                .deserialize::<GetDataSourceResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(GetApiCacheError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(GetDataSourceError::from_response(response))
         }
     }
 
-    /// <p>Retrieves a <code>DataSource</code> object.</p>
-    async fn get_data_source(
+    /// Presigns the request `get_data_source` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_data_source(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetDataSourceRequest,
-    ) -> Result<GetDataSourceResponse, RusotoError<GetDataSourceError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_data_source_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_data_source_request(&self, input: GetDataSourceRequest) -> SignedRequest {
         let request_uri = format!(
             "/v1/apis/{api_id}/datasources/{name}",
             api_id = input.api_id,
@@ -4608,30 +5364,54 @@ impl AppSync for AppSyncClient {
         );
 
         let mut request = SignedRequest::new("GET", "appsync", &self.region, &request_uri);
+        request.set_operation("GetDataSource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
+        request
+    }
+
+    /// Calls `get_function`, also returning the metadata of the response.
+    pub async fn get_function_with_metadata(
+        &self,
+        input: GetFunctionRequest,
+    ) -> Result<(GetFunctionResponse, ResponseMetadata), RusotoError<GetFunctionError>> {
+        let request = self.build_get_function_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<GetDataSourceResponse, _>()?;
+                .deserialize::<GetFunctionResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(GetDataSourceError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(GetFunctionError::from_response(response))
         }
     }
 
-    /// <p>Get a <code>Function</code>.</p>
-    async fn get_function(
+    /// Presigns the request `get_function` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_function(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetFunctionRequest,
-    ) -> Result<GetFunctionResponse, RusotoError<GetFunctionError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_function_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_function_request(&self, input: GetFunctionRequest) -> SignedRequest {
         let request_uri = format!(
             "/v1/apis/{api_id}/functions/{function_id}",
             api_id = input.api_id,
@@ -4639,60 +5419,116 @@ impl AppSync for AppSyncClient {
         );
 
         let mut request = SignedRequest::new("GET", "appsync", &self.region, &request_uri);
+        request.set_operation("GetFunction");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
+        request
+    }
+
+    /// Calls `get_graphql_api`, also returning the metadata of the response.
+    pub async fn get_graphql_api_with_metadata(
+        &self,
+        input: GetGraphqlApiRequest,
+    ) -> Result<(GetGraphqlApiResponse, ResponseMetadata), RusotoError<GetGraphqlApiError>> {
+        let request = self.build_get_graphql_api_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<GetFunctionResponse, _>()?;
+                .deserialize::<GetGraphqlApiResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(GetFunctionError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(GetGraphqlApiError::from_response(response))
         }
     }
 
-    /// <p>Retrieves a <code>GraphqlApi</code> object.</p>
-    async fn get_graphql_api(
+    /// Presigns the request `get_graphql_api` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_graphql_api(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetGraphqlApiRequest,
-    ) -> Result<GetGraphqlApiResponse, RusotoError<GetGraphqlApiError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_graphql_api_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_graphql_api_request(&self, input: GetGraphqlApiRequest) -> SignedRequest {
         let request_uri = format!("/v1/apis/{api_id}", api_id = input.api_id);
 
         let mut request = SignedRequest::new("GET", "appsync", &self.region, &request_uri);
+        request.set_operation("GetGraphqlApi");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
+        request
+    }
+
+    /// Calls `get_introspection_schema`, also returning the metadata of the response.
+    pub async fn get_introspection_schema_with_metadata(
+        &self,
+        input: GetIntrospectionSchemaRequest,
+    ) -> Result<
+        (GetIntrospectionSchemaResponse, ResponseMetadata),
+        RusotoError<GetIntrospectionSchemaError>,
+    > {
+        let request = self.build_get_introspection_schema_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<GetGraphqlApiResponse, _>()?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+
+            let mut result = GetIntrospectionSchemaResponse::default();
+            result.schema = Some(response.body);
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(GetGraphqlApiError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(GetIntrospectionSchemaError::from_response(response))
         }
     }
 
-    /// <p>Retrieves the introspection schema for a GraphQL API.</p>
-    async fn get_introspection_schema(
+    /// Presigns the request `get_introspection_schema` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_introspection_schema(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetIntrospectionSchemaRequest,
-    ) -> Result<GetIntrospectionSchemaResponse, RusotoError<GetIntrospectionSchemaError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_introspection_schema_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_introspection_schema_request(
+        &self,
+        input: GetIntrospectionSchemaRequest,
+    ) -> SignedRequest {
         let request_uri = format!("/v1/apis/{api_id}/schema", api_id = input.api_id);
 
         let mut request = SignedRequest::new("GET", "appsync", &self.region, &request_uri);
+        request.set_operation("GetIntrospectionSchema");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -4702,29 +5538,51 @@ impl AppSync for AppSyncClient {
         }
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `get_resolver`, also returning the metadata of the response.
+    pub async fn get_resolver_with_metadata(
+        &self,
+        input: GetResolverRequest,
+    ) -> Result<(GetResolverResponse, ResponseMetadata), RusotoError<GetResolverError>> {
+        let request = self.build_get_resolver_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-
-            let mut result = GetIntrospectionSchemaResponse::default();
-            result.schema = Some(response.body);
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<GetResolverResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(GetIntrospectionSchemaError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(GetResolverError::from_response(response))
         }
     }
 
-    /// <p>Retrieves a <code>Resolver</code> object.</p>
-    async fn get_resolver(
+    /// Presigns the request `get_resolver` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_resolver(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetResolverRequest,
-    ) -> Result<GetResolverResponse, RusotoError<GetResolverError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_resolver_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_resolver_request(&self, input: GetResolverRequest) -> SignedRequest {
         let request_uri = format!(
             "/v1/apis/{api_id}/types/{type_name}/resolvers/{field_name}",
             api_id = input.api_id,
@@ -4733,57 +5591,111 @@ impl AppSync for AppSyncClient {
         );
 
         let mut request = SignedRequest::new("GET", "appsync", &self.region, &request_uri);
+        request.set_operation("GetResolver");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
+        request
+    }
+
+    /// Calls `get_schema_creation_status`, also returning the metadata of the response.
+    pub async fn get_schema_creation_status_with_metadata(
+        &self,
+        input: GetSchemaCreationStatusRequest,
+    ) -> Result<
+        (GetSchemaCreationStatusResponse, ResponseMetadata),
+        RusotoError<GetSchemaCreationStatusError>,
+    > {
+        let request = self.build_get_schema_creation_status_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<GetResolverResponse, _>()?;
+                .deserialize::<GetSchemaCreationStatusResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(GetResolverError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(GetSchemaCreationStatusError::from_response(response))
         }
     }
 
-    /// <p>Retrieves the current status of a schema creation operation.</p>
-    async fn get_schema_creation_status(
+    /// Presigns the request `get_schema_creation_status` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_schema_creation_status(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetSchemaCreationStatusRequest,
-    ) -> Result<GetSchemaCreationStatusResponse, RusotoError<GetSchemaCreationStatusError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_schema_creation_status_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_schema_creation_status_request(
+        &self,
+        input: GetSchemaCreationStatusRequest,
+    ) -> SignedRequest {
         let request_uri = format!("/v1/apis/{api_id}/schemacreation", api_id = input.api_id);
 
         let mut request = SignedRequest::new("GET", "appsync", &self.region, &request_uri);
+        request.set_operation("GetSchemaCreationStatus");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
+        request
+    }
+
+    /// Calls `get_type`, also returning the metadata of the response.
+    pub async fn get_type_with_metadata(
+        &self,
+        input: GetTypeRequest,
+    ) -> Result<(GetTypeResponse, ResponseMetadata), RusotoError<GetTypeError>> {
+        let request = self.build_get_type_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<GetSchemaCreationStatusResponse, _>()?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result =
+                proto::json::ResponsePayload::new(&response).deserialize::<GetTypeResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(GetSchemaCreationStatusError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(GetTypeError::from_response(response))
         }
     }
 
-    /// <p>Retrieves a <code>Type</code> object.</p>
-    async fn get_type(
+    /// Presigns the request `get_type` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_type(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetTypeRequest,
-    ) -> Result<GetTypeResponse, RusotoError<GetTypeError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_type_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_type_request(&self, input: GetTypeRequest) -> SignedRequest {
         let request_uri = format!(
             "/v1/apis/{api_id}/types/{type_name}",
             api_id = input.api_id,
@@ -4791,37 +5703,62 @@ impl AppSync for AppSyncClient {
         );
 
         let mut request = SignedRequest::new("GET", "appsync", &self.region, &request_uri);
+        request.set_operation("GetType");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
         params.put("format", &input.format);
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `list_api_keys`, also returning the metadata of the response.
+    pub async fn list_api_keys_with_metadata(
+        &self,
+        input: ListApiKeysRequest,
+    ) -> Result<(ListApiKeysResponse, ResponseMetadata), RusotoError<ListApiKeysError>> {
+        let request = self.build_list_api_keys_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            let result =
-                proto::json::ResponsePayload::new(&response).deserialize::<GetTypeResponse, _>()?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ListApiKeysResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(GetTypeError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(ListApiKeysError::from_response(response))
         }
     }
 
-    /// <p><p>Lists the API keys for a given API.</p> <note> <p>API keys are deleted automatically sometime after they expire. However, they may still be included in the response until they have actually been deleted. You can safely call <code>DeleteApiKey</code> to manually delete a key before it&#39;s automatically deleted.</p> </note></p>
-    async fn list_api_keys(
+    /// Presigns the request `list_api_keys` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_api_keys(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListApiKeysRequest,
-    ) -> Result<ListApiKeysResponse, RusotoError<ListApiKeysError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_api_keys_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_api_keys_request(&self, input: ListApiKeysRequest) -> SignedRequest {
         let request_uri = format!("/v1/apis/{api_id}/apikeys", api_id = input.api_id);
 
         let mut request = SignedRequest::new("GET", "appsync", &self.region, &request_uri);
+        request.set_operation("ListApiKeys");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -4833,31 +5770,56 @@ impl AppSync for AppSyncClient {
         }
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `list_data_sources`, also returning the metadata of the response.
+    pub async fn list_data_sources_with_metadata(
+        &self,
+        input: ListDataSourcesRequest,
+    ) -> Result<(ListDataSourcesResponse, ResponseMetadata), RusotoError<ListDataSourcesError>>
+    {
+        let request = self.build_list_data_sources_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListApiKeysResponse, _>()?;
+                .deserialize::<ListDataSourcesResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(ListApiKeysError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(ListDataSourcesError::from_response(response))
         }
     }
 
-    /// <p>Lists the data sources for a given API.</p>
-    async fn list_data_sources(
+    /// Presigns the request `list_data_sources` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_data_sources(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListDataSourcesRequest,
-    ) -> Result<ListDataSourcesResponse, RusotoError<ListDataSourcesError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_data_sources_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_data_sources_request(&self, input: ListDataSourcesRequest) -> SignedRequest {
         let request_uri = format!("/v1/apis/{api_id}/datasources", api_id = input.api_id);
 
         let mut request = SignedRequest::new("GET", "appsync", &self.region, &request_uri);
+        request.set_operation("ListDataSources");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -4869,31 +5831,55 @@ impl AppSync for AppSyncClient {
         }
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `list_functions`, also returning the metadata of the response.
+    pub async fn list_functions_with_metadata(
+        &self,
+        input: ListFunctionsRequest,
+    ) -> Result<(ListFunctionsResponse, ResponseMetadata), RusotoError<ListFunctionsError>> {
+        let request = self.build_list_functions_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListDataSourcesResponse, _>()?;
+                .deserialize::<ListFunctionsResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(ListDataSourcesError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(ListFunctionsError::from_response(response))
         }
     }
 
-    /// <p>List multiple functions.</p>
-    async fn list_functions(
+    /// Presigns the request `list_functions` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_functions(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListFunctionsRequest,
-    ) -> Result<ListFunctionsResponse, RusotoError<ListFunctionsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_functions_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_functions_request(&self, input: ListFunctionsRequest) -> SignedRequest {
         let request_uri = format!("/v1/apis/{api_id}/functions", api_id = input.api_id);
 
         let mut request = SignedRequest::new("GET", "appsync", &self.region, &request_uri);
+        request.set_operation("ListFunctions");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -4905,31 +5891,56 @@ impl AppSync for AppSyncClient {
         }
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `list_graphql_apis`, also returning the metadata of the response.
+    pub async fn list_graphql_apis_with_metadata(
+        &self,
+        input: ListGraphqlApisRequest,
+    ) -> Result<(ListGraphqlApisResponse, ResponseMetadata), RusotoError<ListGraphqlApisError>>
+    {
+        let request = self.build_list_graphql_apis_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListFunctionsResponse, _>()?;
+                .deserialize::<ListGraphqlApisResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(ListFunctionsError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(ListGraphqlApisError::from_response(response))
         }
     }
 
-    /// <p>Lists your GraphQL APIs.</p>
-    async fn list_graphql_apis(
+    /// Presigns the request `list_graphql_apis` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_graphql_apis(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListGraphqlApisRequest,
-    ) -> Result<ListGraphqlApisResponse, RusotoError<ListGraphqlApisError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_graphql_apis_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_graphql_apis_request(&self, input: ListGraphqlApisRequest) -> SignedRequest {
         let request_uri = "/v1/apis";
 
         let mut request = SignedRequest::new("GET", "appsync", &self.region, &request_uri);
+        request.set_operation("ListGraphqlApis");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -4941,28 +5952,51 @@ impl AppSync for AppSyncClient {
         }
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `list_resolvers`, also returning the metadata of the response.
+    pub async fn list_resolvers_with_metadata(
+        &self,
+        input: ListResolversRequest,
+    ) -> Result<(ListResolversResponse, ResponseMetadata), RusotoError<ListResolversError>> {
+        let request = self.build_list_resolvers_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListGraphqlApisResponse, _>()?;
+                .deserialize::<ListResolversResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(ListGraphqlApisError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(ListResolversError::from_response(response))
         }
     }
 
-    /// <p>Lists the resolvers for a given API and type.</p>
-    async fn list_resolvers(
+    /// Presigns the request `list_resolvers` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_resolvers(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListResolversRequest,
-    ) -> Result<ListResolversResponse, RusotoError<ListResolversError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_resolvers_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_resolvers_request(&self, input: ListResolversRequest) -> SignedRequest {
         let request_uri = format!(
             "/v1/apis/{api_id}/types/{type_name}/resolvers",
             api_id = input.api_id,
@@ -4970,6 +6004,7 @@ impl AppSync for AppSyncClient {
         );
 
         let mut request = SignedRequest::new("GET", "appsync", &self.region, &request_uri);
+        request.set_operation("ListResolvers");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -4981,28 +6016,57 @@ impl AppSync for AppSyncClient {
         }
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `list_resolvers_by_function`, also returning the metadata of the response.
+    pub async fn list_resolvers_by_function_with_metadata(
+        &self,
+        input: ListResolversByFunctionRequest,
+    ) -> Result<
+        (ListResolversByFunctionResponse, ResponseMetadata),
+        RusotoError<ListResolversByFunctionError>,
+    > {
+        let request = self.build_list_resolvers_by_function_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListResolversResponse, _>()?;
+                .deserialize::<ListResolversByFunctionResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(ListResolversError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(ListResolversByFunctionError::from_response(response))
         }
     }
 
-    /// <p>List the resolvers that are associated with a specific function.</p>
-    async fn list_resolvers_by_function(
+    /// Presigns the request `list_resolvers_by_function` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_resolvers_by_function(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListResolversByFunctionRequest,
-    ) -> Result<ListResolversByFunctionResponse, RusotoError<ListResolversByFunctionError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_resolvers_by_function_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_resolvers_by_function_request(
+        &self,
+        input: ListResolversByFunctionRequest,
+    ) -> SignedRequest {
         let request_uri = format!(
             "/v1/apis/{api_id}/functions/{function_id}/resolvers",
             api_id = input.api_id,
@@ -5010,6 +6074,7 @@ impl AppSync for AppSyncClient {
         );
 
         let mut request = SignedRequest::new("GET", "appsync", &self.region, &request_uri);
+        request.set_operation("ListResolversByFunction");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -5021,58 +6086,112 @@ impl AppSync for AppSyncClient {
         }
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `list_tags_for_resource`, also returning the metadata of the response.
+    pub async fn list_tags_for_resource_with_metadata(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> Result<
+        (ListTagsForResourceResponse, ResponseMetadata),
+        RusotoError<ListTagsForResourceError>,
+    > {
+        let request = self.build_list_tags_for_resource_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListResolversByFunctionResponse, _>()?;
+                .deserialize::<ListTagsForResourceResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(ListResolversByFunctionError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(ListTagsForResourceError::from_response(response))
         }
     }
 
-    /// <p>Lists the tags for a resource.</p>
-    async fn list_tags_for_resource(
+    /// Presigns the request `list_tags_for_resource` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_tags_for_resource(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListTagsForResourceRequest,
-    ) -> Result<ListTagsForResourceResponse, RusotoError<ListTagsForResourceError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_tags_for_resource_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_tags_for_resource_request(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> SignedRequest {
         let request_uri = format!("/v1/tags/{resource_arn}", resource_arn = input.resource_arn);
 
         let mut request = SignedRequest::new("GET", "appsync", &self.region, &request_uri);
+        request.set_operation("ListTagsForResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
+        request
+    }
+
+    /// Calls `list_types`, also returning the metadata of the response.
+    pub async fn list_types_with_metadata(
+        &self,
+        input: ListTypesRequest,
+    ) -> Result<(ListTypesResponse, ResponseMetadata), RusotoError<ListTypesError>> {
+        let request = self.build_list_types_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListTagsForResourceResponse, _>()?;
+                .deserialize::<ListTypesResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(ListTagsForResourceError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(ListTypesError::from_response(response))
         }
     }
 
-    /// <p>Lists the types for a given API.</p>
-    async fn list_types(
+    /// Presigns the request `list_types` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_types(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListTypesRequest,
-    ) -> Result<ListTypesResponse, RusotoError<ListTypesError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_types_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_types_request(&self, input: ListTypesRequest) -> SignedRequest {
         let request_uri = format!("/v1/apis/{api_id}/types", api_id = input.api_id);
 
         let mut request = SignedRequest::new("GET", "appsync", &self.region, &request_uri);
+        request.set_operation("ListTypes");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -5085,91 +6204,169 @@ impl AppSync for AppSyncClient {
         }
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `start_schema_creation`, also returning the metadata of the response.
+    pub async fn start_schema_creation_with_metadata(
+        &self,
+        input: StartSchemaCreationRequest,
+    ) -> Result<
+        (StartSchemaCreationResponse, ResponseMetadata),
+        RusotoError<StartSchemaCreationError>,
+    > {
+        let request = self.build_start_schema_creation_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListTypesResponse, _>()?;
+                .deserialize::<StartSchemaCreationResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(ListTypesError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(StartSchemaCreationError::from_response(response))
         }
     }
 
-    /// <p>Adds a new schema to your GraphQL API.</p> <p>This operation is asynchronous. Use to determine when it has completed.</p>
-    async fn start_schema_creation(
+    /// Presigns the request `start_schema_creation` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_start_schema_creation(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: StartSchemaCreationRequest,
-    ) -> Result<StartSchemaCreationResponse, RusotoError<StartSchemaCreationError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_start_schema_creation_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_start_schema_creation_request(
+        &self,
+        input: StartSchemaCreationRequest,
+    ) -> SignedRequest {
         let request_uri = format!("/v1/apis/{api_id}/schemacreation", api_id = input.api_id);
 
         let mut request = SignedRequest::new("POST", "appsync", &self.region, &request_uri);
+        request.set_operation("StartSchemaCreation");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `tag_resource`, also returning the metadata of the response.
+    pub async fn tag_resource_with_metadata(
+        &self,
+        input: TagResourceRequest,
+    ) -> Result<(TagResourceResponse, ResponseMetadata), RusotoError<TagResourceError>> {
+        let request = self.build_tag_resource_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<StartSchemaCreationResponse, _>()?;
+                .deserialize::<TagResourceResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(StartSchemaCreationError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(TagResourceError::from_response(response))
         }
     }
 
-    /// <p>Tags a resource with user-supplied tags.</p>
-    async fn tag_resource(
+    /// Presigns the request `tag_resource` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_tag_resource(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: TagResourceRequest,
-    ) -> Result<TagResourceResponse, RusotoError<TagResourceError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_tag_resource_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_tag_resource_request(&self, input: TagResourceRequest) -> SignedRequest {
         let request_uri = format!("/v1/tags/{resource_arn}", resource_arn = input.resource_arn);
 
         let mut request = SignedRequest::new("POST", "appsync", &self.region, &request_uri);
+        request.set_operation("TagResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `untag_resource`, also returning the metadata of the response.
+    pub async fn untag_resource_with_metadata(
+        &self,
+        input: UntagResourceRequest,
+    ) -> Result<(UntagResourceResponse, ResponseMetadata), RusotoError<UntagResourceError>> {
+        let request = self.build_untag_resource_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<TagResourceResponse, _>()?;
+                .deserialize::<UntagResourceResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(TagResourceError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(UntagResourceError::from_response(response))
         }
     }
 
-    /// <p>Untags a resource.</p>
-    async fn untag_resource(
+    /// Presigns the request `untag_resource` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_untag_resource(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UntagResourceRequest,
-    ) -> Result<UntagResourceResponse, RusotoError<UntagResourceError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_untag_resource_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_untag_resource_request(&self, input: UntagResourceRequest) -> SignedRequest {
         let request_uri = format!("/v1/tags/{resource_arn}", resource_arn = input.resource_arn);
 
         let mut request = SignedRequest::new("DELETE", "appsync", &self.region, &request_uri);
+        request.set_operation("UntagResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -5178,58 +6375,105 @@ impl AppSync for AppSyncClient {
         }
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `update_api_cache`, also returning the metadata of the response.
+    pub async fn update_api_cache_with_metadata(
+        &self,
+        input: UpdateApiCacheRequest,
+    ) -> Result<(UpdateApiCacheResponse, ResponseMetadata), RusotoError<UpdateApiCacheError>> {
+        let request = self.build_update_api_cache_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<UntagResourceResponse, _>()?;
+                .deserialize::<UpdateApiCacheResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(UntagResourceError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(UpdateApiCacheError::from_response(response))
         }
     }
 
-    /// <p>Updates the cache for the GraphQL API.</p>
-    async fn update_api_cache(
+    /// Presigns the request `update_api_cache` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_api_cache(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UpdateApiCacheRequest,
-    ) -> Result<UpdateApiCacheResponse, RusotoError<UpdateApiCacheError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_api_cache_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_api_cache_request(&self, input: UpdateApiCacheRequest) -> SignedRequest {
         let request_uri = format!("/v1/apis/{api_id}/ApiCaches/update", api_id = input.api_id);
 
         let mut request = SignedRequest::new("POST", "appsync", &self.region, &request_uri);
+        request.set_operation("UpdateApiCache");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `update_api_key`, also returning the metadata of the response.
+    pub async fn update_api_key_with_metadata(
+        &self,
+        input: UpdateApiKeyRequest,
+    ) -> Result<(UpdateApiKeyResponse, ResponseMetadata), RusotoError<UpdateApiKeyError>> {
+        let request = self.build_update_api_key_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<UpdateApiCacheResponse, _>()?;
+                .deserialize::<UpdateApiKeyResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(UpdateApiCacheError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(UpdateApiKeyError::from_response(response))
         }
     }
 
-    /// <p>Updates an API key.</p>
-    async fn update_api_key(
+    /// Presigns the request `update_api_key` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_api_key(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UpdateApiKeyRequest,
-    ) -> Result<UpdateApiKeyResponse, RusotoError<UpdateApiKeyError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_api_key_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_api_key_request(&self, input: UpdateApiKeyRequest) -> SignedRequest {
         let request_uri = format!(
             "/v1/apis/{api_id}/apikeys/{id}",
             api_id = input.api_id,
@@ -5237,33 +6481,58 @@ impl AppSync for AppSyncClient {
         );
 
         let mut request = SignedRequest::new("POST", "appsync", &self.region, &request_uri);
+        request.set_operation("UpdateApiKey");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `update_data_source`, also returning the metadata of the response.
+    pub async fn update_data_source_with_metadata(
+        &self,
+        input: UpdateDataSourceRequest,
+    ) -> Result<(UpdateDataSourceResponse, ResponseMetadata), RusotoError<UpdateDataSourceError>>
+    {
+        let request = self.build_update_data_source_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<UpdateApiKeyResponse, _>()?;
+                .deserialize::<UpdateDataSourceResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(UpdateApiKeyError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(UpdateDataSourceError::from_response(response))
         }
     }
 
-    /// <p>Updates a <code>DataSource</code> object.</p>
-    async fn update_data_source(
+    /// Presigns the request `update_data_source` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_data_source(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UpdateDataSourceRequest,
-    ) -> Result<UpdateDataSourceResponse, RusotoError<UpdateDataSourceError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_data_source_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_data_source_request(&self, input: UpdateDataSourceRequest) -> SignedRequest {
         let request_uri = format!(
             "/v1/apis/{api_id}/datasources/{name}",
             api_id = input.api_id,
@@ -5271,33 +6540,57 @@ impl AppSync for AppSyncClient {
         );
 
         let mut request = SignedRequest::new("POST", "appsync", &self.region, &request_uri);
+        request.set_operation("UpdateDataSource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `update_function`, also returning the metadata of the response.
+    pub async fn update_function_with_metadata(
+        &self,
+        input: UpdateFunctionRequest,
+    ) -> Result<(UpdateFunctionResponse, ResponseMetadata), RusotoError<UpdateFunctionError>> {
+        let request = self.build_update_function_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<UpdateDataSourceResponse, _>()?;
+                .deserialize::<UpdateFunctionResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(UpdateDataSourceError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(UpdateFunctionError::from_response(response))
         }
     }
 
-    /// <p>Updates a <code>Function</code> object.</p>
-    async fn update_function(
+    /// Presigns the request `update_function` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_function(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UpdateFunctionRequest,
-    ) -> Result<UpdateFunctionResponse, RusotoError<UpdateFunctionError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_function_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_function_request(&self, input: UpdateFunctionRequest) -> SignedRequest {
         let request_uri = format!(
             "/v1/apis/{api_id}/functions/{function_id}",
             api_id = input.api_id,
@@ -5305,63 +6598,112 @@ impl AppSync for AppSyncClient {
         );
 
         let mut request = SignedRequest::new("POST", "appsync", &self.region, &request_uri);
+        request.set_operation("UpdateFunction");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `update_graphql_api`, also returning the metadata of the response.
+    pub async fn update_graphql_api_with_metadata(
+        &self,
+        input: UpdateGraphqlApiRequest,
+    ) -> Result<(UpdateGraphqlApiResponse, ResponseMetadata), RusotoError<UpdateGraphqlApiError>>
+    {
+        let request = self.build_update_graphql_api_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<UpdateFunctionResponse, _>()?;
+                .deserialize::<UpdateGraphqlApiResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(UpdateFunctionError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(UpdateGraphqlApiError::from_response(response))
         }
     }
 
-    /// <p>Updates a <code>GraphqlApi</code> object.</p>
-    async fn update_graphql_api(
+    /// Presigns the request `update_graphql_api` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_graphql_api(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UpdateGraphqlApiRequest,
-    ) -> Result<UpdateGraphqlApiResponse, RusotoError<UpdateGraphqlApiError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_graphql_api_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_graphql_api_request(&self, input: UpdateGraphqlApiRequest) -> SignedRequest {
         let request_uri = format!("/v1/apis/{api_id}", api_id = input.api_id);
 
         let mut request = SignedRequest::new("POST", "appsync", &self.region, &request_uri);
+        request.set_operation("UpdateGraphqlApi");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `update_resolver`, also returning the metadata of the response.
+    pub async fn update_resolver_with_metadata(
+        &self,
+        input: UpdateResolverRequest,
+    ) -> Result<(UpdateResolverResponse, ResponseMetadata), RusotoError<UpdateResolverError>> {
+        let request = self.build_update_resolver_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<UpdateGraphqlApiResponse, _>()?;
+                .deserialize::<UpdateResolverResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(UpdateGraphqlApiError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(UpdateResolverError::from_response(response))
         }
     }
 
-    /// <p>Updates a <code>Resolver</code> object.</p>
-    async fn update_resolver(
+    /// Presigns the request `update_resolver` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_resolver(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UpdateResolverRequest,
-    ) -> Result<UpdateResolverResponse, RusotoError<UpdateResolverError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_resolver_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_resolver_request(&self, input: UpdateResolverRequest) -> SignedRequest {
         let request_uri = format!(
             "/v1/apis/{api_id}/types/{type_name}/resolvers/{field_name}",
             api_id = input.api_id,
@@ -5370,33 +6712,57 @@ impl AppSync for AppSyncClient {
         );
 
         let mut request = SignedRequest::new("POST", "appsync", &self.region, &request_uri);
+        request.set_operation("UpdateResolver");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `update_type`, also returning the metadata of the response.
+    pub async fn update_type_with_metadata(
+        &self,
+        input: UpdateTypeRequest,
+    ) -> Result<(UpdateTypeResponse, ResponseMetadata), RusotoError<UpdateTypeError>> {
+        let request = self.build_update_type_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<UpdateResolverResponse, _>()?;
+                .deserialize::<UpdateTypeResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(UpdateResolverError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(UpdateTypeError::from_response(response))
         }
     }
 
-    /// <p>Updates a <code>Type</code> object.</p>
-    async fn update_type(
+    /// Presigns the request `update_type` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_type(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UpdateTypeRequest,
-    ) -> Result<UpdateTypeResponse, RusotoError<UpdateTypeError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_type_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_type_request(&self, input: UpdateTypeRequest) -> SignedRequest {
         let request_uri = format!(
             "/v1/apis/{api_id}/types/{type_name}",
             api_id = input.api_id,
@@ -5404,25 +6770,677 @@ impl AppSync for AppSyncClient {
         );
 
         let mut request = SignedRequest::new("POST", "appsync", &self.region, &request_uri);
+        request.set_operation("UpdateType");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
-        let mut response = self
-            .client
-            .sign_and_dispatch(request)
+        request
+    }
+
+    /// The pages of `ListApiKeys` results for `input`, requesting each next page with the `nextToken` of the last one as its `nextToken`.
+    /// The page size is the `maxResults` of `input`.
+    pub fn list_api_keys_pages(
+        &self,
+        input: ListApiKeysRequest,
+    ) -> impl futures::Stream<Item = Result<ListApiKeysResponse, RusotoError<ListApiKeysError>>> + Send
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: AppSyncClient, input| async move { client.list_api_keys(input).await },
+            |input: &ListApiKeysRequest, output: &ListApiKeysResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListApiKeysRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `apiKeys` of every page of `ListApiKeys` results for `input`, as `list_api_keys_pages` requests them.
+    pub fn list_api_keys_stream(
+        &self,
+        input: ListApiKeysRequest,
+    ) -> impl futures::Stream<Item = Result<ApiKey, RusotoError<ListApiKeysError>>> + Send {
+        rusoto_core::pagination::items(self.list_api_keys_pages(input), |page| {
+            page.api_keys.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `ListDataSources` results for `input`, requesting each next page with the `nextToken` of the last one as its `nextToken`.
+    /// The page size is the `maxResults` of `input`.
+    pub fn list_data_sources_pages(
+        &self,
+        input: ListDataSourcesRequest,
+    ) -> impl futures::Stream<
+        Item = Result<ListDataSourcesResponse, RusotoError<ListDataSourcesError>>,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: AppSyncClient, input| async move { client.list_data_sources(input).await },
+            |input: &ListDataSourcesRequest, output: &ListDataSourcesResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListDataSourcesRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `dataSources` of every page of `ListDataSources` results for `input`, as `list_data_sources_pages` requests them.
+    pub fn list_data_sources_stream(
+        &self,
+        input: ListDataSourcesRequest,
+    ) -> impl futures::Stream<Item = Result<DataSource, RusotoError<ListDataSourcesError>>> + Send
+    {
+        rusoto_core::pagination::items(self.list_data_sources_pages(input), |page| {
+            page.data_sources.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `ListFunctions` results for `input`, requesting each next page with the `nextToken` of the last one as its `nextToken`.
+    /// The page size is the `maxResults` of `input`.
+    pub fn list_functions_pages(
+        &self,
+        input: ListFunctionsRequest,
+    ) -> impl futures::Stream<Item = Result<ListFunctionsResponse, RusotoError<ListFunctionsError>>> + Send
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: AppSyncClient, input| async move { client.list_functions(input).await },
+            |input: &ListFunctionsRequest, output: &ListFunctionsResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListFunctionsRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `functions` of every page of `ListFunctions` results for `input`, as `list_functions_pages` requests them.
+    pub fn list_functions_stream(
+        &self,
+        input: ListFunctionsRequest,
+    ) -> impl futures::Stream<Item = Result<FunctionConfiguration, RusotoError<ListFunctionsError>>> + Send
+    {
+        rusoto_core::pagination::items(self.list_functions_pages(input), |page| {
+            page.functions.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `ListGraphqlApis` results for `input`, requesting each next page with the `nextToken` of the last one as its `nextToken`.
+    /// The page size is the `maxResults` of `input`.
+    pub fn list_graphql_apis_pages(
+        &self,
+        input: ListGraphqlApisRequest,
+    ) -> impl futures::Stream<
+        Item = Result<ListGraphqlApisResponse, RusotoError<ListGraphqlApisError>>,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: AppSyncClient, input| async move { client.list_graphql_apis(input).await },
+            |input: &ListGraphqlApisRequest, output: &ListGraphqlApisResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListGraphqlApisRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `graphqlApis` of every page of `ListGraphqlApis` results for `input`, as `list_graphql_apis_pages` requests them.
+    pub fn list_graphql_apis_stream(
+        &self,
+        input: ListGraphqlApisRequest,
+    ) -> impl futures::Stream<Item = Result<GraphqlApi, RusotoError<ListGraphqlApisError>>> + Send
+    {
+        rusoto_core::pagination::items(self.list_graphql_apis_pages(input), |page| {
+            page.graphql_apis.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `ListResolvers` results for `input`, requesting each next page with the `nextToken` of the last one as its `nextToken`.
+    /// The page size is the `maxResults` of `input`.
+    pub fn list_resolvers_pages(
+        &self,
+        input: ListResolversRequest,
+    ) -> impl futures::Stream<Item = Result<ListResolversResponse, RusotoError<ListResolversError>>> + Send
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: AppSyncClient, input| async move { client.list_resolvers(input).await },
+            |input: &ListResolversRequest, output: &ListResolversResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListResolversRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `resolvers` of every page of `ListResolvers` results for `input`, as `list_resolvers_pages` requests them.
+    pub fn list_resolvers_stream(
+        &self,
+        input: ListResolversRequest,
+    ) -> impl futures::Stream<Item = Result<Resolver, RusotoError<ListResolversError>>> + Send {
+        rusoto_core::pagination::items(self.list_resolvers_pages(input), |page| {
+            page.resolvers.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `ListResolversByFunction` results for `input`, requesting each next page with the `nextToken` of the last one as its `nextToken`.
+    /// The page size is the `maxResults` of `input`.
+    pub fn list_resolvers_by_function_pages(
+        &self,
+        input: ListResolversByFunctionRequest,
+    ) -> impl futures::Stream<
+        Item = Result<ListResolversByFunctionResponse, RusotoError<ListResolversByFunctionError>>,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: AppSyncClient, input| async move { client.list_resolvers_by_function(input).await },
+            |input: &ListResolversByFunctionRequest, output: &ListResolversByFunctionResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListResolversByFunctionRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `resolvers` of every page of `ListResolversByFunction` results for `input`, as `list_resolvers_by_function_pages` requests them.
+    pub fn list_resolvers_by_function_stream(
+        &self,
+        input: ListResolversByFunctionRequest,
+    ) -> impl futures::Stream<Item = Result<Resolver, RusotoError<ListResolversByFunctionError>>> + Send
+    {
+        rusoto_core::pagination::items(self.list_resolvers_by_function_pages(input), |page| {
+            page.resolvers.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `ListTypes` results for `input`, requesting each next page with the `nextToken` of the last one as its `nextToken`.
+    /// The page size is the `maxResults` of `input`.
+    pub fn list_types_pages(
+        &self,
+        input: ListTypesRequest,
+    ) -> impl futures::Stream<Item = Result<ListTypesResponse, RusotoError<ListTypesError>>> + Send
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: AppSyncClient, input| async move { client.list_types(input).await },
+            |input: &ListTypesRequest, output: &ListTypesResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListTypesRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `types` of every page of `ListTypes` results for `input`, as `list_types_pages` requests them.
+    pub fn list_types_stream(
+        &self,
+        input: ListTypesRequest,
+    ) -> impl futures::Stream<Item = Result<Type, RusotoError<ListTypesError>>> + Send {
+        rusoto_core::pagination::items(self.list_types_pages(input), |page| {
+            page.types.unwrap_or_default()
+        })
+    }
+}
+
+#[async_trait]
+impl AppSync for AppSyncClient {
+    /// <p>Creates a cache for the GraphQL API.</p>
+    async fn create_api_cache(
+        &self,
+        input: CreateApiCacheRequest,
+    ) -> Result<CreateApiCacheResponse, RusotoError<CreateApiCacheError>> {
+        self.create_api_cache_with_metadata(input)
             .await
-            .map_err(RusotoError::from)?;
-        if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<UpdateTypeResponse, _>()?;
+            .map(|(output, _)| output)
+    }
 
-            Ok(result)
-        } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(UpdateTypeError::from_response(response))
-        }
+    /// <p>Creates a unique key that you can distribute to clients who are executing your API.</p>
+    async fn create_api_key(
+        &self,
+        input: CreateApiKeyRequest,
+    ) -> Result<CreateApiKeyResponse, RusotoError<CreateApiKeyError>> {
+        self.create_api_key_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Creates a <code>DataSource</code> object.</p>
+    async fn create_data_source(
+        &self,
+        input: CreateDataSourceRequest,
+    ) -> Result<CreateDataSourceResponse, RusotoError<CreateDataSourceError>> {
+        self.create_data_source_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Creates a <code>Function</code> object.</p> <p>A function is a reusable entity. Multiple functions can be used to compose the resolver logic.</p>
+    async fn create_function(
+        &self,
+        input: CreateFunctionRequest,
+    ) -> Result<CreateFunctionResponse, RusotoError<CreateFunctionError>> {
+        self.create_function_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Creates a <code>GraphqlApi</code> object.</p>
+    async fn create_graphql_api(
+        &self,
+        input: CreateGraphqlApiRequest,
+    ) -> Result<CreateGraphqlApiResponse, RusotoError<CreateGraphqlApiError>> {
+        self.create_graphql_api_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Creates a <code>Resolver</code> object.</p> <p>A resolver converts incoming requests into a format that a data source can understand and converts the data source's responses into GraphQL.</p>
+    async fn create_resolver(
+        &self,
+        input: CreateResolverRequest,
+    ) -> Result<CreateResolverResponse, RusotoError<CreateResolverError>> {
+        self.create_resolver_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Creates a <code>Type</code> object.</p>
+    async fn create_type(
+        &self,
+        input: CreateTypeRequest,
+    ) -> Result<CreateTypeResponse, RusotoError<CreateTypeError>> {
+        self.create_type_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes an <code>ApiCache</code> object.</p>
+    async fn delete_api_cache(
+        &self,
+        input: DeleteApiCacheRequest,
+    ) -> Result<DeleteApiCacheResponse, RusotoError<DeleteApiCacheError>> {
+        self.delete_api_cache_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes an API key.</p>
+    async fn delete_api_key(
+        &self,
+        input: DeleteApiKeyRequest,
+    ) -> Result<DeleteApiKeyResponse, RusotoError<DeleteApiKeyError>> {
+        self.delete_api_key_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes a <code>DataSource</code> object.</p>
+    async fn delete_data_source(
+        &self,
+        input: DeleteDataSourceRequest,
+    ) -> Result<DeleteDataSourceResponse, RusotoError<DeleteDataSourceError>> {
+        self.delete_data_source_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes a <code>Function</code>.</p>
+    async fn delete_function(
+        &self,
+        input: DeleteFunctionRequest,
+    ) -> Result<DeleteFunctionResponse, RusotoError<DeleteFunctionError>> {
+        self.delete_function_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes a <code>GraphqlApi</code> object.</p>
+    async fn delete_graphql_api(
+        &self,
+        input: DeleteGraphqlApiRequest,
+    ) -> Result<DeleteGraphqlApiResponse, RusotoError<DeleteGraphqlApiError>> {
+        self.delete_graphql_api_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes a <code>Resolver</code> object.</p>
+    async fn delete_resolver(
+        &self,
+        input: DeleteResolverRequest,
+    ) -> Result<DeleteResolverResponse, RusotoError<DeleteResolverError>> {
+        self.delete_resolver_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes a <code>Type</code> object.</p>
+    async fn delete_type(
+        &self,
+        input: DeleteTypeRequest,
+    ) -> Result<DeleteTypeResponse, RusotoError<DeleteTypeError>> {
+        self.delete_type_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Flushes an <code>ApiCache</code> object.</p>
+    async fn flush_api_cache(
+        &self,
+        input: FlushApiCacheRequest,
+    ) -> Result<FlushApiCacheResponse, RusotoError<FlushApiCacheError>> {
+        self.flush_api_cache_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Retrieves an <code>ApiCache</code> object.</p>
+    async fn get_api_cache(
+        &self,
+        input: GetApiCacheRequest,
+    ) -> Result<GetApiCacheResponse, RusotoError<GetApiCacheError>> {
+        self.get_api_cache_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Retrieves a <code>DataSource</code> object.</p>
+    async fn get_data_source(
+        &self,
+        input: GetDataSourceRequest,
+    ) -> Result<GetDataSourceResponse, RusotoError<GetDataSourceError>> {
+        self.get_data_source_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Get a <code>Function</code>.</p>
+    async fn get_function(
+        &self,
+        input: GetFunctionRequest,
+    ) -> Result<GetFunctionResponse, RusotoError<GetFunctionError>> {
+        self.get_function_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Retrieves a <code>GraphqlApi</code> object.</p>
+    async fn get_graphql_api(
+        &self,
+        input: GetGraphqlApiRequest,
+    ) -> Result<GetGraphqlApiResponse, RusotoError<GetGraphqlApiError>> {
+        self.get_graphql_api_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Retrieves the introspection schema for a GraphQL API.</p>
+    async fn get_introspection_schema(
+        &self,
+        input: GetIntrospectionSchemaRequest,
+    ) -> Result<GetIntrospectionSchemaResponse, RusotoError<GetIntrospectionSchemaError>> {
+        self.get_introspection_schema_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Retrieves a <code>Resolver</code> object.</p>
+    async fn get_resolver(
+        &self,
+        input: GetResolverRequest,
+    ) -> Result<GetResolverResponse, RusotoError<GetResolverError>> {
+        self.get_resolver_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Retrieves the current status of a schema creation operation.</p>
+    async fn get_schema_creation_status(
+        &self,
+        input: GetSchemaCreationStatusRequest,
+    ) -> Result<GetSchemaCreationStatusResponse, RusotoError<GetSchemaCreationStatusError>> {
+        self.get_schema_creation_status_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Retrieves a <code>Type</code> object.</p>
+    async fn get_type(
+        &self,
+        input: GetTypeRequest,
+    ) -> Result<GetTypeResponse, RusotoError<GetTypeError>> {
+        self.get_type_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Lists the API keys for a given API.</p> <note> <p>API keys are deleted automatically sometime after they expire. However, they may still be included in the response until they have actually been deleted. You can safely call <code>DeleteApiKey</code> to manually delete a key before it&#39;s automatically deleted.</p> </note></p>
+    async fn list_api_keys(
+        &self,
+        input: ListApiKeysRequest,
+    ) -> Result<ListApiKeysResponse, RusotoError<ListApiKeysError>> {
+        self.list_api_keys_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Lists the data sources for a given API.</p>
+    async fn list_data_sources(
+        &self,
+        input: ListDataSourcesRequest,
+    ) -> Result<ListDataSourcesResponse, RusotoError<ListDataSourcesError>> {
+        self.list_data_sources_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>List multiple functions.</p>
+    async fn list_functions(
+        &self,
+        input: ListFunctionsRequest,
+    ) -> Result<ListFunctionsResponse, RusotoError<ListFunctionsError>> {
+        self.list_functions_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Lists your GraphQL APIs.</p>
+    async fn list_graphql_apis(
+        &self,
+        input: ListGraphqlApisRequest,
+    ) -> Result<ListGraphqlApisResponse, RusotoError<ListGraphqlApisError>> {
+        self.list_graphql_apis_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Lists the resolvers for a given API and type.</p>
+    async fn list_resolvers(
+        &self,
+        input: ListResolversRequest,
+    ) -> Result<ListResolversResponse, RusotoError<ListResolversError>> {
+        self.list_resolvers_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>List the resolvers that are associated with a specific function.</p>
+    async fn list_resolvers_by_function(
+        &self,
+        input: ListResolversByFunctionRequest,
+    ) -> Result<ListResolversByFunctionResponse, RusotoError<ListResolversByFunctionError>> {
+        self.list_resolvers_by_function_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Lists the tags for a resource.</p>
+    async fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> Result<ListTagsForResourceResponse, RusotoError<ListTagsForResourceError>> {
+        self.list_tags_for_resource_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Lists the types for a given API.</p>
+    async fn list_types(
+        &self,
+        input: ListTypesRequest,
+    ) -> Result<ListTypesResponse, RusotoError<ListTypesError>> {
+        self.list_types_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Adds a new schema to your GraphQL API.</p> <p>This operation is asynchronous. Use to determine when it has completed.</p>
+    async fn start_schema_creation(
+        &self,
+        input: StartSchemaCreationRequest,
+    ) -> Result<StartSchemaCreationResponse, RusotoError<StartSchemaCreationError>> {
+        self.start_schema_creation_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Tags a resource with user-supplied tags.</p>
+    async fn tag_resource(
+        &self,
+        input: TagResourceRequest,
+    ) -> Result<TagResourceResponse, RusotoError<TagResourceError>> {
+        self.tag_resource_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Untags a resource.</p>
+    async fn untag_resource(
+        &self,
+        input: UntagResourceRequest,
+    ) -> Result<UntagResourceResponse, RusotoError<UntagResourceError>> {
+        self.untag_resource_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Updates the cache for the GraphQL API.</p>
+    async fn update_api_cache(
+        &self,
+        input: UpdateApiCacheRequest,
+    ) -> Result<UpdateApiCacheResponse, RusotoError<UpdateApiCacheError>> {
+        self.update_api_cache_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Updates an API key.</p>
+    async fn update_api_key(
+        &self,
+        input: UpdateApiKeyRequest,
+    ) -> Result<UpdateApiKeyResponse, RusotoError<UpdateApiKeyError>> {
+        self.update_api_key_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Updates a <code>DataSource</code> object.</p>
+    async fn update_data_source(
+        &self,
+        input: UpdateDataSourceRequest,
+    ) -> Result<UpdateDataSourceResponse, RusotoError<UpdateDataSourceError>> {
+        self.update_data_source_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Updates a <code>Function</code> object.</p>
+    async fn update_function(
+        &self,
+        input: UpdateFunctionRequest,
+    ) -> Result<UpdateFunctionResponse, RusotoError<UpdateFunctionError>> {
+        self.update_function_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Updates a <code>GraphqlApi</code> object.</p>
+    async fn update_graphql_api(
+        &self,
+        input: UpdateGraphqlApiRequest,
+    ) -> Result<UpdateGraphqlApiResponse, RusotoError<UpdateGraphqlApiError>> {
+        self.update_graphql_api_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Updates a <code>Resolver</code> object.</p>
+    async fn update_resolver(
+        &self,
+        input: UpdateResolverRequest,
+    ) -> Result<UpdateResolverResponse, RusotoError<UpdateResolverError>> {
+        self.update_resolver_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Updates a <code>Type</code> object.</p>
+    async fn update_type(
+        &self,
+        input: UpdateTypeRequest,
+    ) -> Result<UpdateTypeResponse, RusotoError<UpdateTypeError>> {
+        self.update_type_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
     }
 }
@@ -16,8 +16,8 @@ use std::fmt;
 use async_trait::async_trait;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
-use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest, ResponseMetadata};
+use rusoto_core::{Client, ErrorMetadata, RusotoError};
 
 use rusoto_core::proto;
 use rusoto_core::signature::SignedRequest;
@@ -869,11 +869,14 @@ pub struct WorkGroupSummary {
 
 /// Errors returned by BatchGetNamedQuery
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum BatchGetNamedQueryError {
     /// <p>Indicates a platform issue, which may be due to a transient condition or outage.</p>
     InternalServer(String),
     /// <p>Indicates that something is wrong with the input to the request. For example, a required parameter may be missing or out of range.</p>
     InvalidRequest(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl BatchGetNamedQueryError {
@@ -881,13 +884,19 @@ impl BatchGetNamedQueryError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(BatchGetNamedQueryError::InternalServer(err.msg))
+                    return RusotoError::service(
+                        BatchGetNamedQueryError::InternalServer(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(BatchGetNamedQueryError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        BatchGetNamedQueryError::InvalidRequest(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(BatchGetNamedQueryError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -899,17 +908,21 @@ impl fmt::Display for BatchGetNamedQueryError {
         match *self {
             BatchGetNamedQueryError::InternalServer(ref cause) => write!(f, "{}", cause),
             BatchGetNamedQueryError::InvalidRequest(ref cause) => write!(f, "{}", cause),
+            BatchGetNamedQueryError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for BatchGetNamedQueryError {}
 /// Errors returned by BatchGetQueryExecution
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum BatchGetQueryExecutionError {
     /// <p>Indicates a platform issue, which may be due to a transient condition or outage.</p>
     InternalServer(String),
     /// <p>Indicates that something is wrong with the input to the request. For example, a required parameter may be missing or out of range.</p>
     InvalidRequest(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl BatchGetQueryExecutionError {
@@ -917,17 +930,19 @@ impl BatchGetQueryExecutionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(BatchGetQueryExecutionError::InternalServer(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        BatchGetQueryExecutionError::InternalServer(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(BatchGetQueryExecutionError::InvalidRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        BatchGetQueryExecutionError::InvalidRequest(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(BatchGetQueryExecutionError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -939,17 +954,21 @@ impl fmt::Display for BatchGetQueryExecutionError {
         match *self {
             BatchGetQueryExecutionError::InternalServer(ref cause) => write!(f, "{}", cause),
             BatchGetQueryExecutionError::InvalidRequest(ref cause) => write!(f, "{}", cause),
+            BatchGetQueryExecutionError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for BatchGetQueryExecutionError {}
 /// Errors returned by CreateNamedQuery
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateNamedQueryError {
     /// <p>Indicates a platform issue, which may be due to a transient condition or outage.</p>
     InternalServer(String),
     /// <p>Indicates that something is wrong with the input to the request. For example, a required parameter may be missing or out of range.</p>
     InvalidRequest(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateNamedQueryError {
@@ -957,13 +976,19 @@ impl CreateNamedQueryError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(CreateNamedQueryError::InternalServer(err.msg))
+                    return RusotoError::service(
+                        CreateNamedQueryError::InternalServer(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(CreateNamedQueryError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        CreateNamedQueryError::InvalidRequest(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreateNamedQueryError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -975,17 +1000,21 @@ impl fmt::Display for CreateNamedQueryError {
         match *self {
             CreateNamedQueryError::InternalServer(ref cause) => write!(f, "{}", cause),
             CreateNamedQueryError::InvalidRequest(ref cause) => write!(f, "{}", cause),
+            CreateNamedQueryError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateNamedQueryError {}
 /// Errors returned by CreateWorkGroup
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateWorkGroupError {
     /// <p>Indicates a platform issue, which may be due to a transient condition or outage.</p>
     InternalServer(String),
     /// <p>Indicates that something is wrong with the input to the request. For example, a required parameter may be missing or out of range.</p>
     InvalidRequest(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateWorkGroupError {
@@ -993,13 +1022,19 @@ impl CreateWorkGroupError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(CreateWorkGroupError::InternalServer(err.msg))
+                    return RusotoError::service(
+                        CreateWorkGroupError::InternalServer(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(CreateWorkGroupError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        CreateWorkGroupError::InvalidRequest(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreateWorkGroupError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1011,17 +1046,21 @@ impl fmt::Display for CreateWorkGroupError {
         match *self {
             CreateWorkGroupError::InternalServer(ref cause) => write!(f, "{}", cause),
             CreateWorkGroupError::InvalidRequest(ref cause) => write!(f, "{}", cause),
+            CreateWorkGroupError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateWorkGroupError {}
 /// Errors returned by DeleteNamedQuery
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteNamedQueryError {
     /// <p>Indicates a platform issue, which may be due to a transient condition or outage.</p>
     InternalServer(String),
     /// <p>Indicates that something is wrong with the input to the request. For example, a required parameter may be missing or out of range.</p>
     InvalidRequest(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteNamedQueryError {
@@ -1029,13 +1068,19 @@ impl DeleteNamedQueryError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(DeleteNamedQueryError::InternalServer(err.msg))
+                    return RusotoError::service(
+                        DeleteNamedQueryError::InternalServer(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DeleteNamedQueryError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        DeleteNamedQueryError::InvalidRequest(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteNamedQueryError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1047,17 +1092,21 @@ impl fmt::Display for DeleteNamedQueryError {
         match *self {
             DeleteNamedQueryError::InternalServer(ref cause) => write!(f, "{}", cause),
             DeleteNamedQueryError::InvalidRequest(ref cause) => write!(f, "{}", cause),
+            DeleteNamedQueryError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteNamedQueryError {}
 /// Errors returned by DeleteWorkGroup
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteWorkGroupError {
     /// <p>Indicates a platform issue, which may be due to a transient condition or outage.</p>
     InternalServer(String),
     /// <p>Indicates that something is wrong with the input to the request. For example, a required parameter may be missing or out of range.</p>
     InvalidRequest(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteWorkGroupError {
@@ -1065,13 +1114,19 @@ impl DeleteWorkGroupError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(DeleteWorkGroupError::InternalServer(err.msg))
+                    return RusotoError::service(
+                        DeleteWorkGroupError::InternalServer(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DeleteWorkGroupError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        DeleteWorkGroupError::InvalidRequest(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteWorkGroupError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1083,17 +1138,21 @@ impl fmt::Display for DeleteWorkGroupError {
         match *self {
             DeleteWorkGroupError::InternalServer(ref cause) => write!(f, "{}", cause),
             DeleteWorkGroupError::InvalidRequest(ref cause) => write!(f, "{}", cause),
+            DeleteWorkGroupError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteWorkGroupError {}
 /// Errors returned by GetNamedQuery
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetNamedQueryError {
     /// <p>Indicates a platform issue, which may be due to a transient condition or outage.</p>
     InternalServer(String),
     /// <p>Indicates that something is wrong with the input to the request. For example, a required parameter may be missing or out of range.</p>
     InvalidRequest(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetNamedQueryError {
@@ -1101,13 +1160,13 @@ impl GetNamedQueryError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(GetNamedQueryError::InternalServer(err.msg))
+                    return RusotoError::service(GetNamedQueryError::InternalServer(err.msg), &res)
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(GetNamedQueryError::InvalidRequest(err.msg))
+                    return RusotoError::service(GetNamedQueryError::InvalidRequest(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetNamedQueryError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1119,17 +1178,21 @@ impl fmt::Display for GetNamedQueryError {
         match *self {
             GetNamedQueryError::InternalServer(ref cause) => write!(f, "{}", cause),
             GetNamedQueryError::InvalidRequest(ref cause) => write!(f, "{}", cause),
+            GetNamedQueryError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetNamedQueryError {}
 /// Errors returned by GetQueryExecution
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetQueryExecutionError {
     /// <p>Indicates a platform issue, which may be due to a transient condition or outage.</p>
     InternalServer(String),
     /// <p>Indicates that something is wrong with the input to the request. For example, a required parameter may be missing or out of range.</p>
     InvalidRequest(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetQueryExecutionError {
@@ -1137,13 +1200,19 @@ impl GetQueryExecutionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(GetQueryExecutionError::InternalServer(err.msg))
+                    return RusotoError::service(
+                        GetQueryExecutionError::InternalServer(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(GetQueryExecutionError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        GetQueryExecutionError::InvalidRequest(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetQueryExecutionError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1155,17 +1224,21 @@ impl fmt::Display for GetQueryExecutionError {
         match *self {
             GetQueryExecutionError::InternalServer(ref cause) => write!(f, "{}", cause),
             GetQueryExecutionError::InvalidRequest(ref cause) => write!(f, "{}", cause),
+            GetQueryExecutionError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetQueryExecutionError {}
 /// Errors returned by GetQueryResults
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetQueryResultsError {
     /// <p>Indicates a platform issue, which may be due to a transient condition or outage.</p>
     InternalServer(String),
     /// <p>Indicates that something is wrong with the input to the request. For example, a required parameter may be missing or out of range.</p>
     InvalidRequest(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetQueryResultsError {
@@ -1173,13 +1246,19 @@ impl GetQueryResultsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(GetQueryResultsError::InternalServer(err.msg))
+                    return RusotoError::service(
+                        GetQueryResultsError::InternalServer(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(GetQueryResultsError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        GetQueryResultsError::InvalidRequest(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetQueryResultsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1191,17 +1270,21 @@ impl fmt::Display for GetQueryResultsError {
         match *self {
             GetQueryResultsError::InternalServer(ref cause) => write!(f, "{}", cause),
             GetQueryResultsError::InvalidRequest(ref cause) => write!(f, "{}", cause),
+            GetQueryResultsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetQueryResultsError {}
 /// Errors returned by GetWorkGroup
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetWorkGroupError {
     /// <p>Indicates a platform issue, which may be due to a transient condition or outage.</p>
     InternalServer(String),
     /// <p>Indicates that something is wrong with the input to the request. For example, a required parameter may be missing or out of range.</p>
     InvalidRequest(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetWorkGroupError {
@@ -1209,13 +1292,13 @@ impl GetWorkGroupError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(GetWorkGroupError::InternalServer(err.msg))
+                    return RusotoError::service(GetWorkGroupError::InternalServer(err.msg), &res)
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(GetWorkGroupError::InvalidRequest(err.msg))
+                    return RusotoError::service(GetWorkGroupError::InvalidRequest(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetWorkGroupError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1227,17 +1310,21 @@ impl fmt::Display for GetWorkGroupError {
         match *self {
             GetWorkGroupError::InternalServer(ref cause) => write!(f, "{}", cause),
             GetWorkGroupError::InvalidRequest(ref cause) => write!(f, "{}", cause),
+            GetWorkGroupError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetWorkGroupError {}
 /// Errors returned by ListNamedQueries
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListNamedQueriesError {
     /// <p>Indicates a platform issue, which may be due to a transient condition or outage.</p>
     InternalServer(String),
     /// <p>Indicates that something is wrong with the input to the request. For example, a required parameter may be missing or out of range.</p>
     InvalidRequest(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListNamedQueriesError {
@@ -1245,13 +1332,19 @@ impl ListNamedQueriesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(ListNamedQueriesError::InternalServer(err.msg))
+                    return RusotoError::service(
+                        ListNamedQueriesError::InternalServer(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(ListNamedQueriesError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        ListNamedQueriesError::InvalidRequest(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListNamedQueriesError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1263,17 +1356,21 @@ impl fmt::Display for ListNamedQueriesError {
         match *self {
             ListNamedQueriesError::InternalServer(ref cause) => write!(f, "{}", cause),
             ListNamedQueriesError::InvalidRequest(ref cause) => write!(f, "{}", cause),
+            ListNamedQueriesError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListNamedQueriesError {}
 /// Errors returned by ListQueryExecutions
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListQueryExecutionsError {
     /// <p>Indicates a platform issue, which may be due to a transient condition or outage.</p>
     InternalServer(String),
     /// <p>Indicates that something is wrong with the input to the request. For example, a required parameter may be missing or out of range.</p>
     InvalidRequest(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListQueryExecutionsError {
@@ -1281,13 +1378,19 @@ impl ListQueryExecutionsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(ListQueryExecutionsError::InternalServer(err.msg))
+                    return RusotoError::service(
+                        ListQueryExecutionsError::InternalServer(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(ListQueryExecutionsError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        ListQueryExecutionsError::InvalidRequest(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListQueryExecutionsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1299,12 +1402,14 @@ impl fmt::Display for ListQueryExecutionsError {
         match *self {
             ListQueryExecutionsError::InternalServer(ref cause) => write!(f, "{}", cause),
             ListQueryExecutionsError::InvalidRequest(ref cause) => write!(f, "{}", cause),
+            ListQueryExecutionsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListQueryExecutionsError {}
 /// Errors returned by ListTagsForResource
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListTagsForResourceError {
     /// <p>Indicates a platform issue, which may be due to a transient condition or outage.</p>
     InternalServer(String),
@@ -1312,6 +1417,8 @@ pub enum ListTagsForResourceError {
     InvalidRequest(String),
     /// <p>A resource, such as a workgroup, was not found.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListTagsForResourceError {
@@ -1319,18 +1426,25 @@ impl ListTagsForResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(ListTagsForResourceError::InternalServer(err.msg))
+                    return RusotoError::service(
+                        ListTagsForResourceError::InternalServer(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(ListTagsForResourceError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        ListTagsForResourceError::InvalidRequest(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListTagsForResourceError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListTagsForResourceError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListTagsForResourceError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1343,17 +1457,21 @@ impl fmt::Display for ListTagsForResourceError {
             ListTagsForResourceError::InternalServer(ref cause) => write!(f, "{}", cause),
             ListTagsForResourceError::InvalidRequest(ref cause) => write!(f, "{}", cause),
             ListTagsForResourceError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            ListTagsForResourceError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListTagsForResourceError {}
 /// Errors returned by ListWorkGroups
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListWorkGroupsError {
     /// <p>Indicates a platform issue, which may be due to a transient condition or outage.</p>
     InternalServer(String),
     /// <p>Indicates that something is wrong with the input to the request. For example, a required parameter may be missing or out of range.</p>
     InvalidRequest(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListWorkGroupsError {
@@ -1361,13 +1479,13 @@ impl ListWorkGroupsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(ListWorkGroupsError::InternalServer(err.msg))
+                    return RusotoError::service(ListWorkGroupsError::InternalServer(err.msg), &res)
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(ListWorkGroupsError::InvalidRequest(err.msg))
+                    return RusotoError::service(ListWorkGroupsError::InvalidRequest(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListWorkGroupsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1379,12 +1497,14 @@ impl fmt::Display for ListWorkGroupsError {
         match *self {
             ListWorkGroupsError::InternalServer(ref cause) => write!(f, "{}", cause),
             ListWorkGroupsError::InvalidRequest(ref cause) => write!(f, "{}", cause),
+            ListWorkGroupsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListWorkGroupsError {}
 /// Errors returned by StartQueryExecution
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum StartQueryExecutionError {
     /// <p>Indicates a platform issue, which may be due to a transient condition or outage.</p>
     InternalServer(String),
@@ -1392,6 +1512,8 @@ pub enum StartQueryExecutionError {
     InvalidRequest(String),
     /// <p>Indicates that the request was throttled.</p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl StartQueryExecutionError {
@@ -1399,16 +1521,25 @@ impl StartQueryExecutionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(StartQueryExecutionError::InternalServer(err.msg))
+                    return RusotoError::service(
+                        StartQueryExecutionError::InternalServer(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(StartQueryExecutionError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        StartQueryExecutionError::InvalidRequest(err.msg),
+                        &res,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(StartQueryExecutionError::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        StartQueryExecutionError::TooManyRequests(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(StartQueryExecutionError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1421,17 +1552,21 @@ impl fmt::Display for StartQueryExecutionError {
             StartQueryExecutionError::InternalServer(ref cause) => write!(f, "{}", cause),
             StartQueryExecutionError::InvalidRequest(ref cause) => write!(f, "{}", cause),
             StartQueryExecutionError::TooManyRequests(ref cause) => write!(f, "{}", cause),
+            StartQueryExecutionError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for StartQueryExecutionError {}
 /// Errors returned by StopQueryExecution
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum StopQueryExecutionError {
     /// <p>Indicates a platform issue, which may be due to a transient condition or outage.</p>
     InternalServer(String),
     /// <p>Indicates that something is wrong with the input to the request. For example, a required parameter may be missing or out of range.</p>
     InvalidRequest(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl StopQueryExecutionError {
@@ -1439,13 +1574,19 @@ impl StopQueryExecutionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(StopQueryExecutionError::InternalServer(err.msg))
+                    return RusotoError::service(
+                        StopQueryExecutionError::InternalServer(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(StopQueryExecutionError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        StopQueryExecutionError::InvalidRequest(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(StopQueryExecutionError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1457,12 +1598,14 @@ impl fmt::Display for StopQueryExecutionError {
         match *self {
             StopQueryExecutionError::InternalServer(ref cause) => write!(f, "{}", cause),
             StopQueryExecutionError::InvalidRequest(ref cause) => write!(f, "{}", cause),
+            StopQueryExecutionError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for StopQueryExecutionError {}
 /// Errors returned by TagResource
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum TagResourceError {
     /// <p>Indicates a platform issue, which may be due to a transient condition or outage.</p>
     InternalServer(String),
@@ -1470,6 +1613,8 @@ pub enum TagResourceError {
     InvalidRequest(String),
     /// <p>A resource, such as a workgroup, was not found.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl TagResourceError {
@@ -1477,16 +1622,16 @@ impl TagResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(TagResourceError::InternalServer(err.msg))
+                    return RusotoError::service(TagResourceError::InternalServer(err.msg), &res)
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(TagResourceError::InvalidRequest(err.msg))
+                    return RusotoError::service(TagResourceError::InvalidRequest(err.msg), &res)
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(TagResourceError::ResourceNotFound(err.msg))
+                    return RusotoError::service(TagResourceError::ResourceNotFound(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(TagResourceError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1499,12 +1644,14 @@ impl fmt::Display for TagResourceError {
             TagResourceError::InternalServer(ref cause) => write!(f, "{}", cause),
             TagResourceError::InvalidRequest(ref cause) => write!(f, "{}", cause),
             TagResourceError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            TagResourceError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for TagResourceError {}
 /// Errors returned by UntagResource
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UntagResourceError {
     /// <p>Indicates a platform issue, which may be due to a transient condition or outage.</p>
     InternalServer(String),
@@ -1512,6 +1659,8 @@ pub enum UntagResourceError {
     InvalidRequest(String),
     /// <p>A resource, such as a workgroup, was not found.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UntagResourceError {
@@ -1519,16 +1668,19 @@ impl UntagResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(UntagResourceError::InternalServer(err.msg))
+                    return RusotoError::service(UntagResourceError::InternalServer(err.msg), &res)
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(UntagResourceError::InvalidRequest(err.msg))
+                    return RusotoError::service(UntagResourceError::InvalidRequest(err.msg), &res)
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UntagResourceError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        UntagResourceError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UntagResourceError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1541,17 +1693,21 @@ impl fmt::Display for UntagResourceError {
             UntagResourceError::InternalServer(ref cause) => write!(f, "{}", cause),
             UntagResourceError::InvalidRequest(ref cause) => write!(f, "{}", cause),
             UntagResourceError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            UntagResourceError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for UntagResourceError {}
 /// Errors returned by UpdateWorkGroup
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdateWorkGroupError {
     /// <p>Indicates a platform issue, which may be due to a transient condition or outage.</p>
     InternalServer(String),
     /// <p>Indicates that something is wrong with the input to the request. For example, a required parameter may be missing or out of range.</p>
     InvalidRequest(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdateWorkGroupError {
@@ -1559,13 +1715,19 @@ impl UpdateWorkGroupError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(UpdateWorkGroupError::InternalServer(err.msg))
+                    return RusotoError::service(
+                        UpdateWorkGroupError::InternalServer(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(UpdateWorkGroupError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        UpdateWorkGroupError::InvalidRequest(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UpdateWorkGroupError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1577,6 +1739,7 @@ impl fmt::Display for UpdateWorkGroupError {
         match *self {
             UpdateWorkGroupError::InternalServer(ref cause) => write!(f, "{}", cause),
             UpdateWorkGroupError::InvalidRequest(ref cause) => write!(f, "{}", cause),
+            UpdateWorkGroupError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
@@ -1709,6 +1872,7 @@ impl AthenaClient {
     /// Creates a client backed by the default tokio event loop.
     ///
     /// The client will use the default credentials provider and tls client.
+    #[cfg(not(target_arch = "wasm32"))]
     pub fn new(region: region::Region) -> AthenaClient {
         AthenaClient {
             client: Client::shared(),
@@ -1734,526 +1898,1362 @@ impl AthenaClient {
     pub fn new_with_client(client: Client, region: region::Region) -> AthenaClient {
         AthenaClient { client, region }
     }
+
+    /// Returns a builder of a client, setting any of its region, credentials provider,
+    /// dispatcher, retry policy, endpoint and timeouts.
+    pub fn builder() -> rusoto_core::ClientBuilder<AthenaClient> {
+        rusoto_core::ClientBuilder::new(AthenaClient::new_with_client)
+    }
+
+    /// Returns a clone of this client with the overrides of `config` applied to its
+    /// operations, sharing its connections.
+    pub fn customize(&self, config: &rusoto_core::OperationConfig) -> AthenaClient {
+        AthenaClient {
+            client: self.client.customize(config),
+            region: config.resolve_region(&self.region),
+        }
+    }
 }
 
-#[async_trait]
-impl Athena for AthenaClient {
-    /// <p>Returns the details of a single named query or a list of up to 50 queries, which you provide as an array of query ID strings. Requires you to have access to the workgroup in which the queries were saved. Use <a>ListNamedQueriesInput</a> to get the list of named query IDs in the specified workgroup. If information could not be retrieved for a submitted query ID, information about the query ID submitted is listed under <a>UnprocessedNamedQueryId</a>. Named queries differ from executed queries. Use <a>BatchGetQueryExecutionInput</a> to get details about each unique query execution, and <a>ListQueryExecutionsInput</a> to get a list of query execution IDs.</p>
-    async fn batch_get_named_query(
+impl AthenaClient {
+    /// Calls `batch_get_named_query`, also returning the metadata of the response.
+    pub async fn batch_get_named_query_with_metadata(
         &self,
         input: BatchGetNamedQueryInput,
-    ) -> Result<BatchGetNamedQueryOutput, RusotoError<BatchGetNamedQueryError>> {
+    ) -> Result<(BatchGetNamedQueryOutput, ResponseMetadata), RusotoError<BatchGetNamedQueryError>>
+    {
+        let request = self.build_batch_get_named_query_request(input);
+
+        let mut response = self
+            .client
+            .sign_and_dispatch(request)
+            .await
+            .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
+        if response.status.is_success() {
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<BatchGetNamedQueryOutput, _>()?;
+            Ok((result, metadata))
+        } else {
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(BatchGetNamedQueryError::from_response(response))
+        }
+    }
+
+    /// Presigns the request `batch_get_named_query` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_batch_get_named_query(
+        &self,
+        presigner: &rusoto_core::Presigner,
+        input: BatchGetNamedQueryInput,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_batch_get_named_query_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_batch_get_named_query_request(&self, input: BatchGetNamedQueryInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "athena", &self.region, "/");
+        request.set_operation("BatchGetNamedQuery");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonAthena.BatchGetNamedQuery");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `batch_get_query_execution`, also returning the metadata of the response.
+    pub async fn batch_get_query_execution_with_metadata(
+        &self,
+        input: BatchGetQueryExecutionInput,
+    ) -> Result<
+        (BatchGetQueryExecutionOutput, ResponseMetadata),
+        RusotoError<BatchGetQueryExecutionError>,
+    > {
+        let request = self.build_batch_get_query_execution_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<BatchGetNamedQueryOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<BatchGetQueryExecutionOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(BatchGetNamedQueryError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(BatchGetQueryExecutionError::from_response(response))
         }
     }
 
-    /// <p>Returns the details of a single query execution or a list of up to 50 query executions, which you provide as an array of query execution ID strings. Requires you to have access to the workgroup in which the queries ran. To get a list of query execution IDs, use <a>ListQueryExecutionsInput$WorkGroup</a>. Query executions differ from named (saved) queries. Use <a>BatchGetNamedQueryInput</a> to get details about named queries.</p>
-    async fn batch_get_query_execution(
+    /// Presigns the request `batch_get_query_execution` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_batch_get_query_execution(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: BatchGetQueryExecutionInput,
-    ) -> Result<BatchGetQueryExecutionOutput, RusotoError<BatchGetQueryExecutionError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_batch_get_query_execution_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_batch_get_query_execution_request(
+        &self,
+        input: BatchGetQueryExecutionInput,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "athena", &self.region, "/");
+        request.set_operation("BatchGetQueryExecution");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonAthena.BatchGetQueryExecution");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `create_named_query`, also returning the metadata of the response.
+    pub async fn create_named_query_with_metadata(
+        &self,
+        input: CreateNamedQueryInput,
+    ) -> Result<(CreateNamedQueryOutput, ResponseMetadata), RusotoError<CreateNamedQueryError>>
+    {
+        let request = self.build_create_named_query_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<BatchGetQueryExecutionOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<CreateNamedQueryOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(BatchGetQueryExecutionError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(CreateNamedQueryError::from_response(response))
         }
     }
 
-    /// <p>Creates a named query in the specified workgroup. Requires that you have access to the workgroup.</p> <p>For code samples using the AWS SDK for Java, see <a href="http://docs.aws.amazon.com/athena/latest/ug/code-samples.html">Examples and Code Samples</a> in the <i>Amazon Athena User Guide</i>.</p>
-    async fn create_named_query(
+    /// Presigns the request `create_named_query` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_named_query(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: CreateNamedQueryInput,
-    ) -> Result<CreateNamedQueryOutput, RusotoError<CreateNamedQueryError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_named_query_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_named_query_request(&self, input: CreateNamedQueryInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "athena", &self.region, "/");
+        request.set_operation("CreateNamedQuery");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonAthena.CreateNamedQuery");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `create_work_group`, also returning the metadata of the response.
+    pub async fn create_work_group_with_metadata(
+        &self,
+        input: CreateWorkGroupInput,
+    ) -> Result<(CreateWorkGroupOutput, ResponseMetadata), RusotoError<CreateWorkGroupError>> {
+        let request = self.build_create_work_group_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<CreateNamedQueryOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<CreateWorkGroupOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(CreateNamedQueryError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(CreateWorkGroupError::from_response(response))
         }
     }
 
-    /// <p>Creates a workgroup with the specified name.</p>
-    async fn create_work_group(
+    /// Presigns the request `create_work_group` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_work_group(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: CreateWorkGroupInput,
-    ) -> Result<CreateWorkGroupOutput, RusotoError<CreateWorkGroupError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_work_group_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_work_group_request(&self, input: CreateWorkGroupInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "athena", &self.region, "/");
+        request.set_operation("CreateWorkGroup");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonAthena.CreateWorkGroup");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `delete_named_query`, also returning the metadata of the response.
+    pub async fn delete_named_query_with_metadata(
+        &self,
+        input: DeleteNamedQueryInput,
+    ) -> Result<(DeleteNamedQueryOutput, ResponseMetadata), RusotoError<DeleteNamedQueryError>>
+    {
+        let request = self.build_delete_named_query_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<CreateWorkGroupOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DeleteNamedQueryOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(CreateWorkGroupError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DeleteNamedQueryError::from_response(response))
         }
     }
 
-    /// <p>Deletes the named query if you have access to the workgroup in which the query was saved.</p> <p>For code samples using the AWS SDK for Java, see <a href="http://docs.aws.amazon.com/athena/latest/ug/code-samples.html">Examples and Code Samples</a> in the <i>Amazon Athena User Guide</i>.</p>
-    async fn delete_named_query(
+    /// Presigns the request `delete_named_query` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_named_query(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteNamedQueryInput,
-    ) -> Result<DeleteNamedQueryOutput, RusotoError<DeleteNamedQueryError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_named_query_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_named_query_request(&self, input: DeleteNamedQueryInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "athena", &self.region, "/");
+        request.set_operation("DeleteNamedQuery");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonAthena.DeleteNamedQuery");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `delete_work_group`, also returning the metadata of the response.
+    pub async fn delete_work_group_with_metadata(
+        &self,
+        input: DeleteWorkGroupInput,
+    ) -> Result<(DeleteWorkGroupOutput, ResponseMetadata), RusotoError<DeleteWorkGroupError>> {
+        let request = self.build_delete_work_group_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<DeleteNamedQueryOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DeleteWorkGroupOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteNamedQueryError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DeleteWorkGroupError::from_response(response))
         }
     }
 
-    /// <p>Deletes the workgroup with the specified name. The primary workgroup cannot be deleted.</p>
-    async fn delete_work_group(
+    /// Presigns the request `delete_work_group` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_work_group(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteWorkGroupInput,
-    ) -> Result<DeleteWorkGroupOutput, RusotoError<DeleteWorkGroupError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_work_group_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_work_group_request(&self, input: DeleteWorkGroupInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "athena", &self.region, "/");
+        request.set_operation("DeleteWorkGroup");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonAthena.DeleteWorkGroup");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `get_named_query`, also returning the metadata of the response.
+    pub async fn get_named_query_with_metadata(
+        &self,
+        input: GetNamedQueryInput,
+    ) -> Result<(GetNamedQueryOutput, ResponseMetadata), RusotoError<GetNamedQueryError>> {
+        let request = self.build_get_named_query_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<DeleteWorkGroupOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<GetNamedQueryOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteWorkGroupError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(GetNamedQueryError::from_response(response))
         }
     }
 
-    /// <p>Returns information about a single query. Requires that you have access to the workgroup in which the query was saved.</p>
-    async fn get_named_query(
+    /// Presigns the request `get_named_query` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_named_query(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetNamedQueryInput,
-    ) -> Result<GetNamedQueryOutput, RusotoError<GetNamedQueryError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_named_query_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_named_query_request(&self, input: GetNamedQueryInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "athena", &self.region, "/");
+        request.set_operation("GetNamedQuery");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonAthena.GetNamedQuery");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `get_query_execution`, also returning the metadata of the response.
+    pub async fn get_query_execution_with_metadata(
+        &self,
+        input: GetQueryExecutionInput,
+    ) -> Result<(GetQueryExecutionOutput, ResponseMetadata), RusotoError<GetQueryExecutionError>>
+    {
+        let request = self.build_get_query_execution_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<GetNamedQueryOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<GetQueryExecutionOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(GetNamedQueryError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(GetQueryExecutionError::from_response(response))
         }
     }
 
-    /// <p>Returns information about a single execution of a query if you have access to the workgroup in which the query ran. Each time a query executes, information about the query execution is saved with a unique ID.</p>
-    async fn get_query_execution(
+    /// Presigns the request `get_query_execution` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_query_execution(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetQueryExecutionInput,
-    ) -> Result<GetQueryExecutionOutput, RusotoError<GetQueryExecutionError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_query_execution_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_query_execution_request(&self, input: GetQueryExecutionInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "athena", &self.region, "/");
+        request.set_operation("GetQueryExecution");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonAthena.GetQueryExecution");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `get_query_results`, also returning the metadata of the response.
+    pub async fn get_query_results_with_metadata(
+        &self,
+        input: GetQueryResultsInput,
+    ) -> Result<(GetQueryResultsOutput, ResponseMetadata), RusotoError<GetQueryResultsError>> {
+        let request = self.build_get_query_results_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<GetQueryExecutionOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<GetQueryResultsOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(GetQueryExecutionError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(GetQueryResultsError::from_response(response))
         }
     }
 
-    /// <p><p>Streams the results of a single query execution specified by <code>QueryExecutionId</code> from the Athena query results location in Amazon S3. For more information, see <a href="https://docs.aws.amazon.com/athena/latest/ug/querying.html">Query Results</a> in the <i>Amazon Athena User Guide</i>. This request does not execute the query but returns results. Use <a>StartQueryExecution</a> to run a query.</p> <p>To stream query results successfully, the IAM principal with permission to call <code>GetQueryResults</code> also must have permissions to the Amazon S3 <code>GetObject</code> action for the Athena query results location.</p> <important> <p>IAM principals with permission to the Amazon S3 <code>GetObject</code> action for the query results location are able to retrieve query results from Amazon S3 even if permission to the <code>GetQueryResults</code> action is denied. To restrict user or role access, ensure that Amazon S3 permissions to the Athena query location are denied.</p> </important></p>
-    async fn get_query_results(
+    /// Presigns the request `get_query_results` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_query_results(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetQueryResultsInput,
-    ) -> Result<GetQueryResultsOutput, RusotoError<GetQueryResultsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_query_results_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_query_results_request(&self, input: GetQueryResultsInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "athena", &self.region, "/");
+        request.set_operation("GetQueryResults");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonAthena.GetQueryResults");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `get_work_group`, also returning the metadata of the response.
+    pub async fn get_work_group_with_metadata(
+        &self,
+        input: GetWorkGroupInput,
+    ) -> Result<(GetWorkGroupOutput, ResponseMetadata), RusotoError<GetWorkGroupError>> {
+        let request = self.build_get_work_group_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<GetQueryResultsOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<GetWorkGroupOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(GetQueryResultsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(GetWorkGroupError::from_response(response))
         }
     }
 
-    /// <p>Returns information about the workgroup with the specified name.</p>
-    async fn get_work_group(
+    /// Presigns the request `get_work_group` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_work_group(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetWorkGroupInput,
-    ) -> Result<GetWorkGroupOutput, RusotoError<GetWorkGroupError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_work_group_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_work_group_request(&self, input: GetWorkGroupInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "athena", &self.region, "/");
+        request.set_operation("GetWorkGroup");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonAthena.GetWorkGroup");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `list_named_queries`, also returning the metadata of the response.
+    pub async fn list_named_queries_with_metadata(
+        &self,
+        input: ListNamedQueriesInput,
+    ) -> Result<(ListNamedQueriesOutput, ResponseMetadata), RusotoError<ListNamedQueriesError>>
+    {
+        let request = self.build_list_named_queries_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<GetWorkGroupOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ListNamedQueriesOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(GetWorkGroupError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ListNamedQueriesError::from_response(response))
         }
     }
 
-    /// <p>Provides a list of available query IDs only for queries saved in the specified workgroup. Requires that you have access to the workgroup.</p> <p>For code samples using the AWS SDK for Java, see <a href="http://docs.aws.amazon.com/athena/latest/ug/code-samples.html">Examples and Code Samples</a> in the <i>Amazon Athena User Guide</i>.</p>
-    async fn list_named_queries(
+    /// Presigns the request `list_named_queries` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_named_queries(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListNamedQueriesInput,
-    ) -> Result<ListNamedQueriesOutput, RusotoError<ListNamedQueriesError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_named_queries_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_named_queries_request(&self, input: ListNamedQueriesInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "athena", &self.region, "/");
+        request.set_operation("ListNamedQueries");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonAthena.ListNamedQueries");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `list_query_executions`, also returning the metadata of the response.
+    pub async fn list_query_executions_with_metadata(
+        &self,
+        input: ListQueryExecutionsInput,
+    ) -> Result<(ListQueryExecutionsOutput, ResponseMetadata), RusotoError<ListQueryExecutionsError>>
+    {
+        let request = self.build_list_query_executions_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<ListNamedQueriesOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ListQueryExecutionsOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ListNamedQueriesError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ListQueryExecutionsError::from_response(response))
         }
     }
 
-    /// <p>Provides a list of available query execution IDs for the queries in the specified workgroup. Requires you to have access to the workgroup in which the queries ran.</p> <p>For code samples using the AWS SDK for Java, see <a href="http://docs.aws.amazon.com/athena/latest/ug/code-samples.html">Examples and Code Samples</a> in the <i>Amazon Athena User Guide</i>.</p>
-    async fn list_query_executions(
+    /// Presigns the request `list_query_executions` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_query_executions(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListQueryExecutionsInput,
-    ) -> Result<ListQueryExecutionsOutput, RusotoError<ListQueryExecutionsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_query_executions_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_query_executions_request(
+        &self,
+        input: ListQueryExecutionsInput,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "athena", &self.region, "/");
+        request.set_operation("ListQueryExecutions");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonAthena.ListQueryExecutions");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `list_tags_for_resource`, also returning the metadata of the response.
+    pub async fn list_tags_for_resource_with_metadata(
+        &self,
+        input: ListTagsForResourceInput,
+    ) -> Result<(ListTagsForResourceOutput, ResponseMetadata), RusotoError<ListTagsForResourceError>>
+    {
+        let request = self.build_list_tags_for_resource_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListQueryExecutionsOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ListTagsForResourceOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ListQueryExecutionsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ListTagsForResourceError::from_response(response))
         }
     }
 
-    /// <p>Lists the tags associated with this workgroup.</p>
-    async fn list_tags_for_resource(
+    /// Presigns the request `list_tags_for_resource` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_tags_for_resource(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListTagsForResourceInput,
-    ) -> Result<ListTagsForResourceOutput, RusotoError<ListTagsForResourceError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_tags_for_resource_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_tags_for_resource_request(
+        &self,
+        input: ListTagsForResourceInput,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "athena", &self.region, "/");
+        request.set_operation("ListTagsForResource");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonAthena.ListTagsForResource");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `list_work_groups`, also returning the metadata of the response.
+    pub async fn list_work_groups_with_metadata(
+        &self,
+        input: ListWorkGroupsInput,
+    ) -> Result<(ListWorkGroupsOutput, ResponseMetadata), RusotoError<ListWorkGroupsError>> {
+        let request = self.build_list_work_groups_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListTagsForResourceOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ListWorkGroupsOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ListTagsForResourceError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ListWorkGroupsError::from_response(response))
         }
     }
 
-    /// <p>Lists available workgroups for the account.</p>
-    async fn list_work_groups(
+    /// Presigns the request `list_work_groups` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_work_groups(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListWorkGroupsInput,
-    ) -> Result<ListWorkGroupsOutput, RusotoError<ListWorkGroupsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_work_groups_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_work_groups_request(&self, input: ListWorkGroupsInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "athena", &self.region, "/");
+        request.set_operation("ListWorkGroups");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonAthena.ListWorkGroups");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `start_query_execution`, also returning the metadata of the response.
+    pub async fn start_query_execution_with_metadata(
+        &self,
+        input: StartQueryExecutionInput,
+    ) -> Result<(StartQueryExecutionOutput, ResponseMetadata), RusotoError<StartQueryExecutionError>>
+    {
+        let request = self.build_start_query_execution_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<ListWorkGroupsOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<StartQueryExecutionOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ListWorkGroupsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(StartQueryExecutionError::from_response(response))
         }
     }
 
-    /// <p>Runs the SQL query statements contained in the <code>Query</code>. Requires you to have access to the workgroup in which the query ran.</p> <p>For code samples using the AWS SDK for Java, see <a href="http://docs.aws.amazon.com/athena/latest/ug/code-samples.html">Examples and Code Samples</a> in the <i>Amazon Athena User Guide</i>.</p>
-    async fn start_query_execution(
+    /// Presigns the request `start_query_execution` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_start_query_execution(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: StartQueryExecutionInput,
-    ) -> Result<StartQueryExecutionOutput, RusotoError<StartQueryExecutionError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_start_query_execution_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_start_query_execution_request(
+        &self,
+        input: StartQueryExecutionInput,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "athena", &self.region, "/");
+        request.set_operation("StartQueryExecution");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonAthena.StartQueryExecution");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `stop_query_execution`, also returning the metadata of the response.
+    pub async fn stop_query_execution_with_metadata(
+        &self,
+        input: StopQueryExecutionInput,
+    ) -> Result<(StopQueryExecutionOutput, ResponseMetadata), RusotoError<StopQueryExecutionError>>
+    {
+        let request = self.build_stop_query_execution_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<StartQueryExecutionOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<StopQueryExecutionOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(StartQueryExecutionError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(StopQueryExecutionError::from_response(response))
         }
     }
 
-    /// <p>Stops a query execution. Requires you to have access to the workgroup in which the query ran.</p> <p>For code samples using the AWS SDK for Java, see <a href="http://docs.aws.amazon.com/athena/latest/ug/code-samples.html">Examples and Code Samples</a> in the <i>Amazon Athena User Guide</i>.</p>
-    async fn stop_query_execution(
+    /// Presigns the request `stop_query_execution` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_stop_query_execution(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: StopQueryExecutionInput,
-    ) -> Result<StopQueryExecutionOutput, RusotoError<StopQueryExecutionError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_stop_query_execution_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_stop_query_execution_request(&self, input: StopQueryExecutionInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "athena", &self.region, "/");
+        request.set_operation("StopQueryExecution");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonAthena.StopQueryExecution");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `tag_resource`, also returning the metadata of the response.
+    pub async fn tag_resource_with_metadata(
+        &self,
+        input: TagResourceInput,
+    ) -> Result<(TagResourceOutput, ResponseMetadata), RusotoError<TagResourceError>> {
+        let request = self.build_tag_resource_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<StopQueryExecutionOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<TagResourceOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(StopQueryExecutionError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(TagResourceError::from_response(response))
         }
     }
 
-    /// <p>Adds one or more tags to the resource, such as a workgroup. A tag is a label that you assign to an AWS Athena resource (a workgroup). Each tag consists of a key and an optional value, both of which you define. Tags enable you to categorize resources (workgroups) in Athena, for example, by purpose, owner, or environment. Use a consistent set of tag keys to make it easier to search and filter workgroups in your account. For best practices, see <a href="https://aws.amazon.com/answers/account-management/aws-tagging-strategies/">AWS Tagging Strategies</a>. The key length is from 1 (minimum) to 128 (maximum) Unicode characters in UTF-8. The tag value length is from 0 (minimum) to 256 (maximum) Unicode characters in UTF-8. You can use letters and numbers representable in UTF-8, and the following characters: + - = . _ : / @. Tag keys and values are case-sensitive. Tag keys must be unique per resource. If you specify more than one, separate them by commas.</p>
-    async fn tag_resource(
+    /// Presigns the request `tag_resource` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_tag_resource(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: TagResourceInput,
-    ) -> Result<TagResourceOutput, RusotoError<TagResourceError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_tag_resource_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_tag_resource_request(&self, input: TagResourceInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "athena", &self.region, "/");
+        request.set_operation("TagResource");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonAthena.TagResource");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `untag_resource`, also returning the metadata of the response.
+    pub async fn untag_resource_with_metadata(
+        &self,
+        input: UntagResourceInput,
+    ) -> Result<(UntagResourceOutput, ResponseMetadata), RusotoError<UntagResourceError>> {
+        let request = self.build_untag_resource_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<TagResourceOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<UntagResourceOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(TagResourceError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(UntagResourceError::from_response(response))
         }
     }
 
-    /// <p>Removes one or more tags from the workgroup resource. Takes as an input a list of TagKey Strings separated by commas, and removes their tags at the same time.</p>
-    async fn untag_resource(
+    /// Presigns the request `untag_resource` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_untag_resource(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UntagResourceInput,
-    ) -> Result<UntagResourceOutput, RusotoError<UntagResourceError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_untag_resource_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_untag_resource_request(&self, input: UntagResourceInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "athena", &self.region, "/");
+        request.set_operation("UntagResource");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonAthena.UntagResource");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `update_work_group`, also returning the metadata of the response.
+    pub async fn update_work_group_with_metadata(
+        &self,
+        input: UpdateWorkGroupInput,
+    ) -> Result<(UpdateWorkGroupOutput, ResponseMetadata), RusotoError<UpdateWorkGroupError>> {
+        let request = self.build_update_work_group_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<UntagResourceOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<UpdateWorkGroupOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(UntagResourceError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(UpdateWorkGroupError::from_response(response))
         }
     }
 
-    /// <p>Updates the workgroup with the specified name. The workgroup's name cannot be changed.</p>
-    async fn update_work_group(
+    /// Presigns the request `update_work_group` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_work_group(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UpdateWorkGroupInput,
-    ) -> Result<UpdateWorkGroupOutput, RusotoError<UpdateWorkGroupError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_work_group_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_work_group_request(&self, input: UpdateWorkGroupInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "athena", &self.region, "/");
+        request.set_operation("UpdateWorkGroup");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonAthena.UpdateWorkGroup");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
-        let mut response = self
-            .client
-            .sign_and_dispatch(request)
+        request
+    }
+
+    /// The pages of `GetQueryResults` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `MaxResults` of `input`.
+    pub fn get_query_results_pages(
+        &self,
+        input: GetQueryResultsInput,
+    ) -> impl futures::Stream<Item = Result<GetQueryResultsOutput, RusotoError<GetQueryResultsError>>>
+           + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: AthenaClient, input| async move { client.get_query_results(input).await },
+            |input: &GetQueryResultsInput, output: &GetQueryResultsOutput| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(GetQueryResultsInput {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The pages of `ListNamedQueries` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `MaxResults` of `input`.
+    pub fn list_named_queries_pages(
+        &self,
+        input: ListNamedQueriesInput,
+    ) -> impl futures::Stream<
+        Item = Result<ListNamedQueriesOutput, RusotoError<ListNamedQueriesError>>,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: AthenaClient, input| async move { client.list_named_queries(input).await },
+            |input: &ListNamedQueriesInput, output: &ListNamedQueriesOutput| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListNamedQueriesInput {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `NamedQueryIds` of every page of `ListNamedQueries` results for `input`, as `list_named_queries_pages` requests them.
+    pub fn list_named_queries_stream(
+        &self,
+        input: ListNamedQueriesInput,
+    ) -> impl futures::Stream<Item = Result<String, RusotoError<ListNamedQueriesError>>> + Send
+    {
+        rusoto_core::pagination::items(self.list_named_queries_pages(input), |page| {
+            page.named_query_ids.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `ListQueryExecutions` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `MaxResults` of `input`.
+    pub fn list_query_executions_pages(
+        &self,
+        input: ListQueryExecutionsInput,
+    ) -> impl futures::Stream<
+        Item = Result<ListQueryExecutionsOutput, RusotoError<ListQueryExecutionsError>>,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: AthenaClient, input| async move { client.list_query_executions(input).await },
+            |input: &ListQueryExecutionsInput, output: &ListQueryExecutionsOutput| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListQueryExecutionsInput {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `QueryExecutionIds` of every page of `ListQueryExecutions` results for `input`, as `list_query_executions_pages` requests them.
+    pub fn list_query_executions_stream(
+        &self,
+        input: ListQueryExecutionsInput,
+    ) -> impl futures::Stream<Item = Result<String, RusotoError<ListQueryExecutionsError>>> + Send
+    {
+        rusoto_core::pagination::items(self.list_query_executions_pages(input), |page| {
+            page.query_execution_ids.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `ListTagsForResource` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `MaxResults` of `input`.
+    pub fn list_tags_for_resource_pages(
+        &self,
+        input: ListTagsForResourceInput,
+    ) -> impl futures::Stream<
+        Item = Result<ListTagsForResourceOutput, RusotoError<ListTagsForResourceError>>,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: AthenaClient, input| async move { client.list_tags_for_resource(input).await },
+            |input: &ListTagsForResourceInput, output: &ListTagsForResourceOutput| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListTagsForResourceInput {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `Tags` of every page of `ListTagsForResource` results for `input`, as `list_tags_for_resource_pages` requests them.
+    pub fn list_tags_for_resource_stream(
+        &self,
+        input: ListTagsForResourceInput,
+    ) -> impl futures::Stream<Item = Result<Tag, RusotoError<ListTagsForResourceError>>> + Send
+    {
+        rusoto_core::pagination::items(self.list_tags_for_resource_pages(input), |page| {
+            page.tags.unwrap_or_default()
+        })
+    }
+}
+
+#[async_trait]
+impl Athena for AthenaClient {
+    /// <p>Returns the details of a single named query or a list of up to 50 queries, which you provide as an array of query ID strings. Requires you to have access to the workgroup in which the queries were saved. Use <a>ListNamedQueriesInput</a> to get the list of named query IDs in the specified workgroup. If information could not be retrieved for a submitted query ID, information about the query ID submitted is listed under <a>UnprocessedNamedQueryId</a>. Named queries differ from executed queries. Use <a>BatchGetQueryExecutionInput</a> to get details about each unique query execution, and <a>ListQueryExecutionsInput</a> to get a list of query execution IDs.</p>
+    async fn batch_get_named_query(
+        &self,
+        input: BatchGetNamedQueryInput,
+    ) -> Result<BatchGetNamedQueryOutput, RusotoError<BatchGetNamedQueryError>> {
+        self.batch_get_named_query_with_metadata(input)
             .await
-            .map_err(RusotoError::from)?;
-        if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<UpdateWorkGroupOutput, _>()
-        } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(UpdateWorkGroupError::from_response(response))
-        }
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns the details of a single query execution or a list of up to 50 query executions, which you provide as an array of query execution ID strings. Requires you to have access to the workgroup in which the queries ran. To get a list of query execution IDs, use <a>ListQueryExecutionsInput$WorkGroup</a>. Query executions differ from named (saved) queries. Use <a>BatchGetNamedQueryInput</a> to get details about named queries.</p>
+    async fn batch_get_query_execution(
+        &self,
+        input: BatchGetQueryExecutionInput,
+    ) -> Result<BatchGetQueryExecutionOutput, RusotoError<BatchGetQueryExecutionError>> {
+        self.batch_get_query_execution_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Creates a named query in the specified workgroup. Requires that you have access to the workgroup.</p> <p>For code samples using the AWS SDK for Java, see <a href="http://docs.aws.amazon.com/athena/latest/ug/code-samples.html">Examples and Code Samples</a> in the <i>Amazon Athena User Guide</i>.</p>
+    async fn create_named_query(
+        &self,
+        input: CreateNamedQueryInput,
+    ) -> Result<CreateNamedQueryOutput, RusotoError<CreateNamedQueryError>> {
+        self.create_named_query_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Creates a workgroup with the specified name.</p>
+    async fn create_work_group(
+        &self,
+        input: CreateWorkGroupInput,
+    ) -> Result<CreateWorkGroupOutput, RusotoError<CreateWorkGroupError>> {
+        self.create_work_group_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes the named query if you have access to the workgroup in which the query was saved.</p> <p>For code samples using the AWS SDK for Java, see <a href="http://docs.aws.amazon.com/athena/latest/ug/code-samples.html">Examples and Code Samples</a> in the <i>Amazon Athena User Guide</i>.</p>
+    async fn delete_named_query(
+        &self,
+        input: DeleteNamedQueryInput,
+    ) -> Result<DeleteNamedQueryOutput, RusotoError<DeleteNamedQueryError>> {
+        self.delete_named_query_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes the workgroup with the specified name. The primary workgroup cannot be deleted.</p>
+    async fn delete_work_group(
+        &self,
+        input: DeleteWorkGroupInput,
+    ) -> Result<DeleteWorkGroupOutput, RusotoError<DeleteWorkGroupError>> {
+        self.delete_work_group_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns information about a single query. Requires that you have access to the workgroup in which the query was saved.</p>
+    async fn get_named_query(
+        &self,
+        input: GetNamedQueryInput,
+    ) -> Result<GetNamedQueryOutput, RusotoError<GetNamedQueryError>> {
+        self.get_named_query_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns information about a single execution of a query if you have access to the workgroup in which the query ran. Each time a query executes, information about the query execution is saved with a unique ID.</p>
+    async fn get_query_execution(
+        &self,
+        input: GetQueryExecutionInput,
+    ) -> Result<GetQueryExecutionOutput, RusotoError<GetQueryExecutionError>> {
+        self.get_query_execution_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Streams the results of a single query execution specified by <code>QueryExecutionId</code> from the Athena query results location in Amazon S3. For more information, see <a href="https://docs.aws.amazon.com/athena/latest/ug/querying.html">Query Results</a> in the <i>Amazon Athena User Guide</i>. This request does not execute the query but returns results. Use <a>StartQueryExecution</a> to run a query.</p> <p>To stream query results successfully, the IAM principal with permission to call <code>GetQueryResults</code> also must have permissions to the Amazon S3 <code>GetObject</code> action for the Athena query results location.</p> <important> <p>IAM principals with permission to the Amazon S3 <code>GetObject</code> action for the query results location are able to retrieve query results from Amazon S3 even if permission to the <code>GetQueryResults</code> action is denied. To restrict user or role access, ensure that Amazon S3 permissions to the Athena query location are denied.</p> </important></p>
+    async fn get_query_results(
+        &self,
+        input: GetQueryResultsInput,
+    ) -> Result<GetQueryResultsOutput, RusotoError<GetQueryResultsError>> {
+        self.get_query_results_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns information about the workgroup with the specified name.</p>
+    async fn get_work_group(
+        &self,
+        input: GetWorkGroupInput,
+    ) -> Result<GetWorkGroupOutput, RusotoError<GetWorkGroupError>> {
+        self.get_work_group_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Provides a list of available query IDs only for queries saved in the specified workgroup. Requires that you have access to the workgroup.</p> <p>For code samples using the AWS SDK for Java, see <a href="http://docs.aws.amazon.com/athena/latest/ug/code-samples.html">Examples and Code Samples</a> in the <i>Amazon Athena User Guide</i>.</p>
+    async fn list_named_queries(
+        &self,
+        input: ListNamedQueriesInput,
+    ) -> Result<ListNamedQueriesOutput, RusotoError<ListNamedQueriesError>> {
+        self.list_named_queries_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Provides a list of available query execution IDs for the queries in the specified workgroup. Requires you to have access to the workgroup in which the queries ran.</p> <p>For code samples using the AWS SDK for Java, see <a href="http://docs.aws.amazon.com/athena/latest/ug/code-samples.html">Examples and Code Samples</a> in the <i>Amazon Athena User Guide</i>.</p>
+    async fn list_query_executions(
+        &self,
+        input: ListQueryExecutionsInput,
+    ) -> Result<ListQueryExecutionsOutput, RusotoError<ListQueryExecutionsError>> {
+        self.list_query_executions_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Lists the tags associated with this workgroup.</p>
+    async fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceInput,
+    ) -> Result<ListTagsForResourceOutput, RusotoError<ListTagsForResourceError>> {
+        self.list_tags_for_resource_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Lists available workgroups for the account.</p>
+    async fn list_work_groups(
+        &self,
+        input: ListWorkGroupsInput,
+    ) -> Result<ListWorkGroupsOutput, RusotoError<ListWorkGroupsError>> {
+        self.list_work_groups_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Runs the SQL query statements contained in the <code>Query</code>. Requires you to have access to the workgroup in which the query ran.</p> <p>For code samples using the AWS SDK for Java, see <a href="http://docs.aws.amazon.com/athena/latest/ug/code-samples.html">Examples and Code Samples</a> in the <i>Amazon Athena User Guide</i>.</p>
+    async fn start_query_execution(
+        &self,
+        input: StartQueryExecutionInput,
+    ) -> Result<StartQueryExecutionOutput, RusotoError<StartQueryExecutionError>> {
+        self.start_query_execution_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Stops a query execution. Requires you to have access to the workgroup in which the query ran.</p> <p>For code samples using the AWS SDK for Java, see <a href="http://docs.aws.amazon.com/athena/latest/ug/code-samples.html">Examples and Code Samples</a> in the <i>Amazon Athena User Guide</i>.</p>
+    async fn stop_query_execution(
+        &self,
+        input: StopQueryExecutionInput,
+    ) -> Result<StopQueryExecutionOutput, RusotoError<StopQueryExecutionError>> {
+        self.stop_query_execution_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Adds one or more tags to the resource, such as a workgroup. A tag is a label that you assign to an AWS Athena resource (a workgroup). Each tag consists of a key and an optional value, both of which you define. Tags enable you to categorize resources (workgroups) in Athena, for example, by purpose, owner, or environment. Use a consistent set of tag keys to make it easier to search and filter workgroups in your account. For best practices, see <a href="https://aws.amazon.com/answers/account-management/aws-tagging-strategies/">AWS Tagging Strategies</a>. The key length is from 1 (minimum) to 128 (maximum) Unicode characters in UTF-8. The tag value length is from 0 (minimum) to 256 (maximum) Unicode characters in UTF-8. You can use letters and numbers representable in UTF-8, and the following characters: + - = . _ : / @. Tag keys and values are case-sensitive. Tag keys must be unique per resource. If you specify more than one, separate them by commas.</p>
+    async fn tag_resource(
+        &self,
+        input: TagResourceInput,
+    ) -> Result<TagResourceOutput, RusotoError<TagResourceError>> {
+        self.tag_resource_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Removes one or more tags from the workgroup resource. Takes as an input a list of TagKey Strings separated by commas, and removes their tags at the same time.</p>
+    async fn untag_resource(
+        &self,
+        input: UntagResourceInput,
+    ) -> Result<UntagResourceOutput, RusotoError<UntagResourceError>> {
+        self.untag_resource_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Updates the workgroup with the specified name. The workgroup's name cannot be changed.</p>
+    async fn update_work_group(
+        &self,
+        input: UpdateWorkGroupInput,
+    ) -> Result<UpdateWorkGroupOutput, RusotoError<UpdateWorkGroupError>> {
+        self.update_work_group_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
     }
 }
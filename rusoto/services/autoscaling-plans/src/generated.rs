@@ -16,8 +16,8 @@ use std::fmt;
 use async_trait::async_trait;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
-use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest, ResponseMetadata};
+use rusoto_core::{Client, ErrorMetadata, RusotoError};
 
 use rusoto_core::proto;
 use rusoto_core::signature::SignedRequest;
@@ -480,6 +480,7 @@ pub struct UpdateScalingPlanResponse {}
 
 /// Errors returned by CreateScalingPlan
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateScalingPlanError {
     /// <p>Concurrent updates caused an exception, for example, if you request an update to a scaling plan that already has a pending update.</p>
     ConcurrentUpdate(String),
@@ -487,6 +488,8 @@ pub enum CreateScalingPlanError {
     InternalService(String),
     /// <p>Your account exceeded a limit. This exception is thrown when a per-account resource limit is exceeded.</p>
     LimitExceeded(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateScalingPlanError {
@@ -494,16 +497,25 @@ impl CreateScalingPlanError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentUpdateException" => {
-                    return RusotoError::Service(CreateScalingPlanError::ConcurrentUpdate(err.msg))
+                    return RusotoError::service(
+                        CreateScalingPlanError::ConcurrentUpdate(err.msg),
+                        &res,
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(CreateScalingPlanError::InternalService(err.msg))
+                    return RusotoError::service(
+                        CreateScalingPlanError::InternalService(err.msg),
+                        &res,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateScalingPlanError::LimitExceeded(err.msg))
+                    return RusotoError::service(
+                        CreateScalingPlanError::LimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreateScalingPlanError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -516,12 +528,14 @@ impl fmt::Display for CreateScalingPlanError {
             CreateScalingPlanError::ConcurrentUpdate(ref cause) => write!(f, "{}", cause),
             CreateScalingPlanError::InternalService(ref cause) => write!(f, "{}", cause),
             CreateScalingPlanError::LimitExceeded(ref cause) => write!(f, "{}", cause),
+            CreateScalingPlanError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateScalingPlanError {}
 /// Errors returned by DeleteScalingPlan
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteScalingPlanError {
     /// <p>Concurrent updates caused an exception, for example, if you request an update to a scaling plan that already has a pending update.</p>
     ConcurrentUpdate(String),
@@ -529,6 +543,8 @@ pub enum DeleteScalingPlanError {
     InternalService(String),
     /// <p>The specified object could not be found.</p>
     ObjectNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteScalingPlanError {
@@ -536,16 +552,25 @@ impl DeleteScalingPlanError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentUpdateException" => {
-                    return RusotoError::Service(DeleteScalingPlanError::ConcurrentUpdate(err.msg))
+                    return RusotoError::service(
+                        DeleteScalingPlanError::ConcurrentUpdate(err.msg),
+                        &res,
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(DeleteScalingPlanError::InternalService(err.msg))
+                    return RusotoError::service(
+                        DeleteScalingPlanError::InternalService(err.msg),
+                        &res,
+                    )
                 }
                 "ObjectNotFoundException" => {
-                    return RusotoError::Service(DeleteScalingPlanError::ObjectNotFound(err.msg))
+                    return RusotoError::service(
+                        DeleteScalingPlanError::ObjectNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteScalingPlanError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -558,12 +583,14 @@ impl fmt::Display for DeleteScalingPlanError {
             DeleteScalingPlanError::ConcurrentUpdate(ref cause) => write!(f, "{}", cause),
             DeleteScalingPlanError::InternalService(ref cause) => write!(f, "{}", cause),
             DeleteScalingPlanError::ObjectNotFound(ref cause) => write!(f, "{}", cause),
+            DeleteScalingPlanError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteScalingPlanError {}
 /// Errors returned by DescribeScalingPlanResources
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeScalingPlanResourcesError {
     /// <p>Concurrent updates caused an exception, for example, if you request an update to a scaling plan that already has a pending update.</p>
     ConcurrentUpdate(String),
@@ -571,6 +598,8 @@ pub enum DescribeScalingPlanResourcesError {
     InternalService(String),
     /// <p>The token provided is not valid.</p>
     InvalidNextToken(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeScalingPlanResourcesError {
@@ -580,22 +609,30 @@ impl DescribeScalingPlanResourcesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentUpdateException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DescribeScalingPlanResourcesError::ConcurrentUpdate(err.msg),
+                        &res,
                     )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DescribeScalingPlanResourcesError::InternalService(err.msg),
+                        &res,
                     )
                 }
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DescribeScalingPlanResourcesError::InvalidNextToken(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        DescribeScalingPlanResourcesError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -612,12 +649,14 @@ impl fmt::Display for DescribeScalingPlanResourcesError {
             DescribeScalingPlanResourcesError::InvalidNextToken(ref cause) => {
                 write!(f, "{}", cause)
             }
+            DescribeScalingPlanResourcesError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeScalingPlanResourcesError {}
 /// Errors returned by DescribeScalingPlans
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeScalingPlansError {
     /// <p>Concurrent updates caused an exception, for example, if you request an update to a scaling plan that already has a pending update.</p>
     ConcurrentUpdate(String),
@@ -625,6 +664,8 @@ pub enum DescribeScalingPlansError {
     InternalService(String),
     /// <p>The token provided is not valid.</p>
     InvalidNextToken(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeScalingPlansError {
@@ -632,22 +673,25 @@ impl DescribeScalingPlansError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentUpdateException" => {
-                    return RusotoError::Service(DescribeScalingPlansError::ConcurrentUpdate(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeScalingPlansError::ConcurrentUpdate(err.msg),
+                        &res,
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(DescribeScalingPlansError::InternalService(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeScalingPlansError::InternalService(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(DescribeScalingPlansError::InvalidNextToken(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeScalingPlansError::InvalidNextToken(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DescribeScalingPlansError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -660,15 +704,19 @@ impl fmt::Display for DescribeScalingPlansError {
             DescribeScalingPlansError::ConcurrentUpdate(ref cause) => write!(f, "{}", cause),
             DescribeScalingPlansError::InternalService(ref cause) => write!(f, "{}", cause),
             DescribeScalingPlansError::InvalidNextToken(ref cause) => write!(f, "{}", cause),
+            DescribeScalingPlansError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeScalingPlansError {}
 /// Errors returned by GetScalingPlanResourceForecastData
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetScalingPlanResourceForecastDataError {
     /// <p>The service encountered an internal error.</p>
     InternalService(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetScalingPlanResourceForecastDataError {
@@ -678,12 +726,18 @@ impl GetScalingPlanResourceForecastDataError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         GetScalingPlanResourceForecastDataError::InternalService(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        GetScalingPlanResourceForecastDataError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -696,12 +750,16 @@ impl fmt::Display for GetScalingPlanResourceForecastDataError {
             GetScalingPlanResourceForecastDataError::InternalService(ref cause) => {
                 write!(f, "{}", cause)
             }
+            GetScalingPlanResourceForecastDataError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for GetScalingPlanResourceForecastDataError {}
 /// Errors returned by UpdateScalingPlan
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdateScalingPlanError {
     /// <p>Concurrent updates caused an exception, for example, if you request an update to a scaling plan that already has a pending update.</p>
     ConcurrentUpdate(String),
@@ -709,6 +767,8 @@ pub enum UpdateScalingPlanError {
     InternalService(String),
     /// <p>The specified object could not be found.</p>
     ObjectNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdateScalingPlanError {
@@ -716,16 +776,25 @@ impl UpdateScalingPlanError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentUpdateException" => {
-                    return RusotoError::Service(UpdateScalingPlanError::ConcurrentUpdate(err.msg))
+                    return RusotoError::service(
+                        UpdateScalingPlanError::ConcurrentUpdate(err.msg),
+                        &res,
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(UpdateScalingPlanError::InternalService(err.msg))
+                    return RusotoError::service(
+                        UpdateScalingPlanError::InternalService(err.msg),
+                        &res,
+                    )
                 }
                 "ObjectNotFoundException" => {
-                    return RusotoError::Service(UpdateScalingPlanError::ObjectNotFound(err.msg))
+                    return RusotoError::service(
+                        UpdateScalingPlanError::ObjectNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UpdateScalingPlanError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -738,6 +807,7 @@ impl fmt::Display for UpdateScalingPlanError {
             UpdateScalingPlanError::ConcurrentUpdate(ref cause) => write!(f, "{}", cause),
             UpdateScalingPlanError::InternalService(ref cause) => write!(f, "{}", cause),
             UpdateScalingPlanError::ObjectNotFound(ref cause) => write!(f, "{}", cause),
+            UpdateScalingPlanError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
@@ -795,6 +865,7 @@ impl AutoscalingPlansClient {
     /// Creates a client backed by the default tokio event loop.
     ///
     /// The client will use the default credentials provider and tls client.
+    #[cfg(not(target_arch = "wasm32"))]
     pub fn new(region: region::Region) -> AutoscalingPlansClient {
         AutoscalingPlansClient {
             client: Client::shared(),
@@ -820,16 +891,68 @@ impl AutoscalingPlansClient {
     pub fn new_with_client(client: Client, region: region::Region) -> AutoscalingPlansClient {
         AutoscalingPlansClient { client, region }
     }
+
+    /// Returns a builder of a client, setting any of its region, credentials provider,
+    /// dispatcher, retry policy, endpoint and timeouts.
+    pub fn builder() -> rusoto_core::ClientBuilder<AutoscalingPlansClient> {
+        rusoto_core::ClientBuilder::new(AutoscalingPlansClient::new_with_client)
+    }
+
+    /// Returns a clone of this client with the overrides of `config` applied to its
+    /// operations, sharing its connections.
+    pub fn customize(&self, config: &rusoto_core::OperationConfig) -> AutoscalingPlansClient {
+        AutoscalingPlansClient {
+            client: self.client.customize(config),
+            region: config.resolve_region(&self.region),
+        }
+    }
 }
 
-#[async_trait]
-impl AutoscalingPlans for AutoscalingPlansClient {
-    /// <p>Creates a scaling plan.</p>
-    async fn create_scaling_plan(
+impl AutoscalingPlansClient {
+    /// Calls `create_scaling_plan`, also returning the metadata of the response.
+    pub async fn create_scaling_plan_with_metadata(
         &self,
         input: CreateScalingPlanRequest,
-    ) -> Result<CreateScalingPlanResponse, RusotoError<CreateScalingPlanError>> {
+    ) -> Result<(CreateScalingPlanResponse, ResponseMetadata), RusotoError<CreateScalingPlanError>>
+    {
+        let request = self.build_create_scaling_plan_request(input);
+
+        let mut response = self
+            .client
+            .sign_and_dispatch(request)
+            .await
+            .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
+        if response.status.is_success() {
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<CreateScalingPlanResponse, _>()?;
+            Ok((result, metadata))
+        } else {
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(CreateScalingPlanError::from_response(response))
+        }
+    }
+
+    /// Presigns the request `create_scaling_plan` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_scaling_plan(
+        &self,
+        presigner: &rusoto_core::Presigner,
+        input: CreateScalingPlanRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_scaling_plan_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_scaling_plan_request(&self, input: CreateScalingPlanRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "autoscaling-plans", &self.region, "/");
+        request.set_operation("CreateScalingPlan");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -839,28 +962,53 @@ impl AutoscalingPlans for AutoscalingPlansClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `delete_scaling_plan`, also returning the metadata of the response.
+    pub async fn delete_scaling_plan_with_metadata(
+        &self,
+        input: DeleteScalingPlanRequest,
+    ) -> Result<(DeleteScalingPlanResponse, ResponseMetadata), RusotoError<DeleteScalingPlanError>>
+    {
+        let request = self.build_delete_scaling_plan_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<CreateScalingPlanResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DeleteScalingPlanResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(CreateScalingPlanError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DeleteScalingPlanError::from_response(response))
         }
     }
 
-    /// <p>Deletes the specified scaling plan.</p> <p>Deleting a scaling plan deletes the underlying <a>ScalingInstruction</a> for all of the scalable resources that are covered by the plan.</p> <p>If the plan has launched resources or has scaling activities in progress, you must delete those resources separately.</p>
-    async fn delete_scaling_plan(
+    /// Presigns the request `delete_scaling_plan` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_scaling_plan(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteScalingPlanRequest,
-    ) -> Result<DeleteScalingPlanResponse, RusotoError<DeleteScalingPlanError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_scaling_plan_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_scaling_plan_request(&self, input: DeleteScalingPlanRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "autoscaling-plans", &self.region, "/");
+        request.set_operation("DeleteScalingPlan");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -870,29 +1018,58 @@ impl AutoscalingPlans for AutoscalingPlansClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `describe_scaling_plan_resources`, also returning the metadata of the response.
+    pub async fn describe_scaling_plan_resources_with_metadata(
+        &self,
+        input: DescribeScalingPlanResourcesRequest,
+    ) -> Result<
+        (DescribeScalingPlanResourcesResponse, ResponseMetadata),
+        RusotoError<DescribeScalingPlanResourcesError>,
+    > {
+        let request = self.build_describe_scaling_plan_resources_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DeleteScalingPlanResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DescribeScalingPlanResourcesResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteScalingPlanError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DescribeScalingPlanResourcesError::from_response(response))
         }
     }
 
-    /// <p>Describes the scalable resources in the specified scaling plan.</p>
-    async fn describe_scaling_plan_resources(
+    /// Presigns the request `describe_scaling_plan_resources` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_scaling_plan_resources(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeScalingPlanResourcesRequest,
-    ) -> Result<DescribeScalingPlanResourcesResponse, RusotoError<DescribeScalingPlanResourcesError>>
-    {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_scaling_plan_resources_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_scaling_plan_resources_request(
+        &self,
+        input: DescribeScalingPlanResourcesRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "autoscaling-plans", &self.region, "/");
+        request.set_operation("DescribeScalingPlanResources");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -902,28 +1079,58 @@ impl AutoscalingPlans for AutoscalingPlansClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `describe_scaling_plans`, also returning the metadata of the response.
+    pub async fn describe_scaling_plans_with_metadata(
+        &self,
+        input: DescribeScalingPlansRequest,
+    ) -> Result<
+        (DescribeScalingPlansResponse, ResponseMetadata),
+        RusotoError<DescribeScalingPlansError>,
+    > {
+        let request = self.build_describe_scaling_plans_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DescribeScalingPlanResourcesResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DescribeScalingPlansResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeScalingPlanResourcesError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DescribeScalingPlansError::from_response(response))
         }
     }
 
-    /// <p>Describes one or more of your scaling plans.</p>
-    async fn describe_scaling_plans(
+    /// Presigns the request `describe_scaling_plans` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_scaling_plans(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeScalingPlansRequest,
-    ) -> Result<DescribeScalingPlansResponse, RusotoError<DescribeScalingPlansError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_scaling_plans_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_scaling_plans_request(
+        &self,
+        input: DescribeScalingPlansRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "autoscaling-plans", &self.region, "/");
+        request.set_operation("DescribeScalingPlans");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -933,31 +1140,60 @@ impl AutoscalingPlans for AutoscalingPlansClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `get_scaling_plan_resource_forecast_data`, also returning the metadata of the response.
+    pub async fn get_scaling_plan_resource_forecast_data_with_metadata(
+        &self,
+        input: GetScalingPlanResourceForecastDataRequest,
+    ) -> Result<
+        (GetScalingPlanResourceForecastDataResponse, ResponseMetadata),
+        RusotoError<GetScalingPlanResourceForecastDataError>,
+    > {
+        let request = self.build_get_scaling_plan_resource_forecast_data_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DescribeScalingPlansResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<GetScalingPlanResourceForecastDataResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeScalingPlansError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(GetScalingPlanResourceForecastDataError::from_response(
+                response,
+            ))
         }
     }
 
-    /// <p>Retrieves the forecast data for a scalable resource.</p> <p>Capacity forecasts are represented as predicted values, or data points, that are calculated using historical data points from a specified CloudWatch load metric. Data points are available for up to 56 days. </p>
-    async fn get_scaling_plan_resource_forecast_data(
+    /// Presigns the request `get_scaling_plan_resource_forecast_data` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_scaling_plan_resource_forecast_data(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetScalingPlanResourceForecastDataRequest,
-    ) -> Result<
-        GetScalingPlanResourceForecastDataResponse,
-        RusotoError<GetScalingPlanResourceForecastDataError>,
-    > {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_scaling_plan_resource_forecast_data_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_scaling_plan_resource_forecast_data_request(
+        &self,
+        input: GetScalingPlanResourceForecastDataRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "autoscaling-plans", &self.region, "/");
+        request.set_operation("GetScalingPlanResourceForecastData");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -967,30 +1203,53 @@ impl AutoscalingPlans for AutoscalingPlansClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `update_scaling_plan`, also returning the metadata of the response.
+    pub async fn update_scaling_plan_with_metadata(
+        &self,
+        input: UpdateScalingPlanRequest,
+    ) -> Result<(UpdateScalingPlanResponse, ResponseMetadata), RusotoError<UpdateScalingPlanError>>
+    {
+        let request = self.build_update_scaling_plan_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<GetScalingPlanResourceForecastDataResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<UpdateScalingPlanResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(GetScalingPlanResourceForecastDataError::from_response(
-                response,
-            ))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(UpdateScalingPlanError::from_response(response))
         }
     }
 
-    /// <p>Updates the specified scaling plan.</p> <p>You cannot update a scaling plan if it is in the process of being created, updated, or deleted.</p>
-    async fn update_scaling_plan(
+    /// Presigns the request `update_scaling_plan` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_scaling_plan(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UpdateScalingPlanRequest,
-    ) -> Result<UpdateScalingPlanResponse, RusotoError<UpdateScalingPlanError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_scaling_plan_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_scaling_plan_request(&self, input: UpdateScalingPlanRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "autoscaling-plans", &self.region, "/");
+        request.set_operation("UpdateScalingPlan");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -1000,19 +1259,156 @@ impl AutoscalingPlans for AutoscalingPlansClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
-        let mut response = self
-            .client
-            .sign_and_dispatch(request)
+        request
+    }
+
+    /// The pages of `DescribeScalingPlanResources` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `MaxResults` of `input`.
+    pub fn describe_scaling_plan_resources_pages(
+        &self,
+        input: DescribeScalingPlanResourcesRequest,
+    ) -> impl futures::Stream<
+        Item = Result<
+            DescribeScalingPlanResourcesResponse,
+            RusotoError<DescribeScalingPlanResourcesError>,
+        >,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: AutoscalingPlansClient, input| async move {
+                client.describe_scaling_plan_resources(input).await
+            },
+            |input: &DescribeScalingPlanResourcesRequest,
+             output: &DescribeScalingPlanResourcesResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(DescribeScalingPlanResourcesRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `ScalingPlanResources` of every page of `DescribeScalingPlanResources` results for `input`, as `describe_scaling_plan_resources_pages` requests them.
+    pub fn describe_scaling_plan_resources_stream(
+        &self,
+        input: DescribeScalingPlanResourcesRequest,
+    ) -> impl futures::Stream<
+        Item = Result<ScalingPlanResource, RusotoError<DescribeScalingPlanResourcesError>>,
+    > + Send {
+        rusoto_core::pagination::items(self.describe_scaling_plan_resources_pages(input), |page| {
+            page.scaling_plan_resources.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `DescribeScalingPlans` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `MaxResults` of `input`.
+    pub fn describe_scaling_plans_pages(
+        &self,
+        input: DescribeScalingPlansRequest,
+    ) -> impl futures::Stream<
+        Item = Result<DescribeScalingPlansResponse, RusotoError<DescribeScalingPlansError>>,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: AutoscalingPlansClient, input| async move {
+                client.describe_scaling_plans(input).await
+            },
+            |input: &DescribeScalingPlansRequest, output: &DescribeScalingPlansResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(DescribeScalingPlansRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `ScalingPlans` of every page of `DescribeScalingPlans` results for `input`, as `describe_scaling_plans_pages` requests them.
+    pub fn describe_scaling_plans_stream(
+        &self,
+        input: DescribeScalingPlansRequest,
+    ) -> impl futures::Stream<Item = Result<ScalingPlan, RusotoError<DescribeScalingPlansError>>> + Send
+    {
+        rusoto_core::pagination::items(self.describe_scaling_plans_pages(input), |page| {
+            page.scaling_plans.unwrap_or_default()
+        })
+    }
+}
+
+#[async_trait]
+impl AutoscalingPlans for AutoscalingPlansClient {
+    /// <p>Creates a scaling plan.</p>
+    async fn create_scaling_plan(
+        &self,
+        input: CreateScalingPlanRequest,
+    ) -> Result<CreateScalingPlanResponse, RusotoError<CreateScalingPlanError>> {
+        self.create_scaling_plan_with_metadata(input)
             .await
-            .map_err(RusotoError::from)?;
-        if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<UpdateScalingPlanResponse, _>()
-        } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(UpdateScalingPlanError::from_response(response))
-        }
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes the specified scaling plan.</p> <p>Deleting a scaling plan deletes the underlying <a>ScalingInstruction</a> for all of the scalable resources that are covered by the plan.</p> <p>If the plan has launched resources or has scaling activities in progress, you must delete those resources separately.</p>
+    async fn delete_scaling_plan(
+        &self,
+        input: DeleteScalingPlanRequest,
+    ) -> Result<DeleteScalingPlanResponse, RusotoError<DeleteScalingPlanError>> {
+        self.delete_scaling_plan_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Describes the scalable resources in the specified scaling plan.</p>
+    async fn describe_scaling_plan_resources(
+        &self,
+        input: DescribeScalingPlanResourcesRequest,
+    ) -> Result<DescribeScalingPlanResourcesResponse, RusotoError<DescribeScalingPlanResourcesError>>
+    {
+        self.describe_scaling_plan_resources_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Describes one or more of your scaling plans.</p>
+    async fn describe_scaling_plans(
+        &self,
+        input: DescribeScalingPlansRequest,
+    ) -> Result<DescribeScalingPlansResponse, RusotoError<DescribeScalingPlansError>> {
+        self.describe_scaling_plans_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Retrieves the forecast data for a scalable resource.</p> <p>Capacity forecasts are represented as predicted values, or data points, that are calculated using historical data points from a specified CloudWatch load metric. Data points are available for up to 56 days. </p>
+    async fn get_scaling_plan_resource_forecast_data(
+        &self,
+        input: GetScalingPlanResourceForecastDataRequest,
+    ) -> Result<
+        GetScalingPlanResourceForecastDataResponse,
+        RusotoError<GetScalingPlanResourceForecastDataError>,
+    > {
+        self.get_scaling_plan_resource_forecast_data_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Updates the specified scaling plan.</p> <p>You cannot update a scaling plan if it is in the process of being created, updated, or deleted.</p>
+    async fn update_scaling_plan(
+        &self,
+        input: UpdateScalingPlanRequest,
+    ) -> Result<UpdateScalingPlanResponse, RusotoError<UpdateScalingPlanError>> {
+        self.update_scaling_plan_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
     }
 }
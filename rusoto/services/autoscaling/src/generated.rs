@@ -16,8 +16,8 @@ use std::fmt;
 use async_trait::async_trait;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
-use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest, ResponseMetadata};
+use rusoto_core::{Client, ErrorMetadata, RusotoError};
 
 use rusoto_core::param::{Params, ServiceParams};
 use rusoto_core::proto::xml::error::*;
@@ -7127,11 +7127,14 @@ impl XmlStringUserDataDeserializer {
 }
 /// Errors returned by AttachInstances
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum AttachInstancesError {
     /// <p>You already have a pending update to an Amazon EC2 Auto Scaling resource (for example, an Auto Scaling group, instance, or load balancer).</p>
     ResourceContentionFault(String),
     /// <p>The service-linked role is not yet ready for use.</p>
     ServiceLinkedRoleFailure(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl AttachInstancesError {
@@ -7143,16 +7146,18 @@ impl AttachInstancesError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "ResourceContention" => {
-                        return RusotoError::Service(AttachInstancesError::ResourceContentionFault(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            AttachInstancesError::ResourceContentionFault(parsed_error.message),
+                            &res,
+                        )
                     }
                     "ServiceLinkedRoleFailure" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             AttachInstancesError::ServiceLinkedRoleFailure(parsed_error.message),
+                            &res,
                         )
                     }
-                    _ => {}
+                    _ => return RusotoError::unhandled(AttachInstancesError::Unhandled, &res),
                 }
             }
         }
@@ -7173,17 +7178,21 @@ impl fmt::Display for AttachInstancesError {
         match *self {
             AttachInstancesError::ResourceContentionFault(ref cause) => write!(f, "{}", cause),
             AttachInstancesError::ServiceLinkedRoleFailure(ref cause) => write!(f, "{}", cause),
+            AttachInstancesError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for AttachInstancesError {}
 /// Errors returned by AttachLoadBalancerTargetGroups
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum AttachLoadBalancerTargetGroupsError {
     /// <p>You already have a pending update to an Amazon EC2 Auto Scaling resource (for example, an Auto Scaling group, instance, or load balancer).</p>
     ResourceContentionFault(String),
     /// <p>The service-linked role is not yet ready for use.</p>
     ServiceLinkedRoleFailure(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl AttachLoadBalancerTargetGroupsError {
@@ -7197,20 +7206,27 @@ impl AttachLoadBalancerTargetGroupsError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "ResourceContention" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             AttachLoadBalancerTargetGroupsError::ResourceContentionFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "ServiceLinkedRoleFailure" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             AttachLoadBalancerTargetGroupsError::ServiceLinkedRoleFailure(
                                 parsed_error.message,
                             ),
+                            &res,
+                        )
+                    }
+                    _ => {
+                        return RusotoError::unhandled(
+                            AttachLoadBalancerTargetGroupsError::Unhandled,
+                            &res,
                         )
                     }
-                    _ => {}
                 }
             }
         }
@@ -7235,17 +7251,23 @@ impl fmt::Display for AttachLoadBalancerTargetGroupsError {
             AttachLoadBalancerTargetGroupsError::ServiceLinkedRoleFailure(ref cause) => {
                 write!(f, "{}", cause)
             }
+            AttachLoadBalancerTargetGroupsError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for AttachLoadBalancerTargetGroupsError {}
 /// Errors returned by AttachLoadBalancers
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum AttachLoadBalancersError {
     /// <p>You already have a pending update to an Amazon EC2 Auto Scaling resource (for example, an Auto Scaling group, instance, or load balancer).</p>
     ResourceContentionFault(String),
     /// <p>The service-linked role is not yet ready for use.</p>
     ServiceLinkedRoleFailure(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl AttachLoadBalancersError {
@@ -7257,18 +7279,20 @@ impl AttachLoadBalancersError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "ResourceContention" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             AttachLoadBalancersError::ResourceContentionFault(parsed_error.message),
+                            &res,
                         )
                     }
                     "ServiceLinkedRoleFailure" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             AttachLoadBalancersError::ServiceLinkedRoleFailure(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
-                    _ => {}
+                    _ => return RusotoError::unhandled(AttachLoadBalancersError::Unhandled, &res),
                 }
             }
         }
@@ -7289,15 +7313,19 @@ impl fmt::Display for AttachLoadBalancersError {
         match *self {
             AttachLoadBalancersError::ResourceContentionFault(ref cause) => write!(f, "{}", cause),
             AttachLoadBalancersError::ServiceLinkedRoleFailure(ref cause) => write!(f, "{}", cause),
+            AttachLoadBalancersError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for AttachLoadBalancersError {}
 /// Errors returned by BatchDeleteScheduledAction
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum BatchDeleteScheduledActionError {
     /// <p>You already have a pending update to an Amazon EC2 Auto Scaling resource (for example, an Auto Scaling group, instance, or load balancer).</p>
     ResourceContentionFault(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl BatchDeleteScheduledActionError {
@@ -7311,13 +7339,19 @@ impl BatchDeleteScheduledActionError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "ResourceContention" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             BatchDeleteScheduledActionError::ResourceContentionFault(
                                 parsed_error.message,
                             ),
+                            &res,
+                        )
+                    }
+                    _ => {
+                        return RusotoError::unhandled(
+                            BatchDeleteScheduledActionError::Unhandled,
+                            &res,
                         )
                     }
-                    _ => {}
                 }
             }
         }
@@ -7339,12 +7373,14 @@ impl fmt::Display for BatchDeleteScheduledActionError {
             BatchDeleteScheduledActionError::ResourceContentionFault(ref cause) => {
                 write!(f, "{}", cause)
             }
+            BatchDeleteScheduledActionError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for BatchDeleteScheduledActionError {}
 /// Errors returned by BatchPutScheduledUpdateGroupAction
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum BatchPutScheduledUpdateGroupActionError {
     /// <p>You already have an Auto Scaling group or launch configuration with this name.</p>
     AlreadyExistsFault(String),
@@ -7352,6 +7388,8 @@ pub enum BatchPutScheduledUpdateGroupActionError {
     LimitExceededFault(String),
     /// <p>You already have a pending update to an Amazon EC2 Auto Scaling resource (for example, an Auto Scaling group, instance, or load balancer).</p>
     ResourceContentionFault(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl BatchPutScheduledUpdateGroupActionError {
@@ -7365,27 +7403,35 @@ impl BatchPutScheduledUpdateGroupActionError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "AlreadyExists" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             BatchPutScheduledUpdateGroupActionError::AlreadyExistsFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "LimitExceeded" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             BatchPutScheduledUpdateGroupActionError::LimitExceededFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "ResourceContention" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             BatchPutScheduledUpdateGroupActionError::ResourceContentionFault(
                                 parsed_error.message,
                             ),
+                            &res,
+                        )
+                    }
+                    _ => {
+                        return RusotoError::unhandled(
+                            BatchPutScheduledUpdateGroupActionError::Unhandled,
+                            &res,
                         )
                     }
-                    _ => {}
                 }
             }
         }
@@ -7413,15 +7459,21 @@ impl fmt::Display for BatchPutScheduledUpdateGroupActionError {
             BatchPutScheduledUpdateGroupActionError::ResourceContentionFault(ref cause) => {
                 write!(f, "{}", cause)
             }
+            BatchPutScheduledUpdateGroupActionError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for BatchPutScheduledUpdateGroupActionError {}
 /// Errors returned by CompleteLifecycleAction
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CompleteLifecycleActionError {
     /// <p>You already have a pending update to an Amazon EC2 Auto Scaling resource (for example, an Auto Scaling group, instance, or load balancer).</p>
     ResourceContentionFault(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CompleteLifecycleActionError {
@@ -7433,13 +7485,19 @@ impl CompleteLifecycleActionError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "ResourceContention" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             CompleteLifecycleActionError::ResourceContentionFault(
                                 parsed_error.message,
                             ),
+                            &res,
+                        )
+                    }
+                    _ => {
+                        return RusotoError::unhandled(
+                            CompleteLifecycleActionError::Unhandled,
+                            &res,
                         )
                     }
-                    _ => {}
                 }
             }
         }
@@ -7461,12 +7519,14 @@ impl fmt::Display for CompleteLifecycleActionError {
             CompleteLifecycleActionError::ResourceContentionFault(ref cause) => {
                 write!(f, "{}", cause)
             }
+            CompleteLifecycleActionError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CompleteLifecycleActionError {}
 /// Errors returned by CreateAutoScalingGroup
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateAutoScalingGroupError {
     /// <p>You already have an Auto Scaling group or launch configuration with this name.</p>
     AlreadyExistsFault(String),
@@ -7476,6 +7536,8 @@ pub enum CreateAutoScalingGroupError {
     ResourceContentionFault(String),
     /// <p>The service-linked role is not yet ready for use.</p>
     ServiceLinkedRoleFailure(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateAutoScalingGroupError {
@@ -7487,30 +7549,36 @@ impl CreateAutoScalingGroupError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "AlreadyExists" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             CreateAutoScalingGroupError::AlreadyExistsFault(parsed_error.message),
+                            &res,
                         )
                     }
                     "LimitExceeded" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             CreateAutoScalingGroupError::LimitExceededFault(parsed_error.message),
+                            &res,
                         )
                     }
                     "ResourceContention" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             CreateAutoScalingGroupError::ResourceContentionFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "ServiceLinkedRoleFailure" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             CreateAutoScalingGroupError::ServiceLinkedRoleFailure(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
-                    _ => {}
+                    _ => {
+                        return RusotoError::unhandled(CreateAutoScalingGroupError::Unhandled, &res)
+                    }
                 }
             }
         }
@@ -7537,12 +7605,14 @@ impl fmt::Display for CreateAutoScalingGroupError {
             CreateAutoScalingGroupError::ServiceLinkedRoleFailure(ref cause) => {
                 write!(f, "{}", cause)
             }
+            CreateAutoScalingGroupError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateAutoScalingGroupError {}
 /// Errors returned by CreateLaunchConfiguration
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateLaunchConfigurationError {
     /// <p>You already have an Auto Scaling group or launch configuration with this name.</p>
     AlreadyExistsFault(String),
@@ -7550,6 +7620,8 @@ pub enum CreateLaunchConfigurationError {
     LimitExceededFault(String),
     /// <p>You already have a pending update to an Amazon EC2 Auto Scaling resource (for example, an Auto Scaling group, instance, or load balancer).</p>
     ResourceContentionFault(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateLaunchConfigurationError {
@@ -7561,27 +7633,35 @@ impl CreateLaunchConfigurationError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "AlreadyExists" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             CreateLaunchConfigurationError::AlreadyExistsFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "LimitExceeded" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             CreateLaunchConfigurationError::LimitExceededFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "ResourceContention" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             CreateLaunchConfigurationError::ResourceContentionFault(
                                 parsed_error.message,
                             ),
+                            &res,
+                        )
+                    }
+                    _ => {
+                        return RusotoError::unhandled(
+                            CreateLaunchConfigurationError::Unhandled,
+                            &res,
                         )
                     }
-                    _ => {}
                 }
             }
         }
@@ -7605,12 +7685,14 @@ impl fmt::Display for CreateLaunchConfigurationError {
             CreateLaunchConfigurationError::ResourceContentionFault(ref cause) => {
                 write!(f, "{}", cause)
             }
+            CreateLaunchConfigurationError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateLaunchConfigurationError {}
 /// Errors returned by CreateOrUpdateTags
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateOrUpdateTagsError {
     /// <p>You already have an Auto Scaling group or launch configuration with this name.</p>
     AlreadyExistsFault(String),
@@ -7620,6 +7702,8 @@ pub enum CreateOrUpdateTagsError {
     ResourceContentionFault(String),
     /// <p>The operation can't be performed because the resource is in use.</p>
     ResourceInUseFault(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateOrUpdateTagsError {
@@ -7631,26 +7715,30 @@ impl CreateOrUpdateTagsError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "AlreadyExists" => {
-                        return RusotoError::Service(CreateOrUpdateTagsError::AlreadyExistsFault(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            CreateOrUpdateTagsError::AlreadyExistsFault(parsed_error.message),
+                            &res,
+                        )
                     }
                     "LimitExceeded" => {
-                        return RusotoError::Service(CreateOrUpdateTagsError::LimitExceededFault(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            CreateOrUpdateTagsError::LimitExceededFault(parsed_error.message),
+                            &res,
+                        )
                     }
                     "ResourceContention" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             CreateOrUpdateTagsError::ResourceContentionFault(parsed_error.message),
+                            &res,
                         )
                     }
                     "ResourceInUse" => {
-                        return RusotoError::Service(CreateOrUpdateTagsError::ResourceInUseFault(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            CreateOrUpdateTagsError::ResourceInUseFault(parsed_error.message),
+                            &res,
+                        )
                     }
-                    _ => {}
+                    _ => return RusotoError::unhandled(CreateOrUpdateTagsError::Unhandled, &res),
                 }
             }
         }
@@ -7673,12 +7761,14 @@ impl fmt::Display for CreateOrUpdateTagsError {
             CreateOrUpdateTagsError::LimitExceededFault(ref cause) => write!(f, "{}", cause),
             CreateOrUpdateTagsError::ResourceContentionFault(ref cause) => write!(f, "{}", cause),
             CreateOrUpdateTagsError::ResourceInUseFault(ref cause) => write!(f, "{}", cause),
+            CreateOrUpdateTagsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateOrUpdateTagsError {}
 /// Errors returned by DeleteAutoScalingGroup
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteAutoScalingGroupError {
     /// <p>You already have a pending update to an Amazon EC2 Auto Scaling resource (for example, an Auto Scaling group, instance, or load balancer).</p>
     ResourceContentionFault(String),
@@ -7686,6 +7776,8 @@ pub enum DeleteAutoScalingGroupError {
     ResourceInUseFault(String),
     /// <p>The operation can't be performed because there are scaling activities in progress.</p>
     ScalingActivityInProgressFault(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteAutoScalingGroupError {
@@ -7697,25 +7789,30 @@ impl DeleteAutoScalingGroupError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "ResourceContention" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             DeleteAutoScalingGroupError::ResourceContentionFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "ResourceInUse" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             DeleteAutoScalingGroupError::ResourceInUseFault(parsed_error.message),
+                            &res,
                         )
                     }
                     "ScalingActivityInProgress" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             DeleteAutoScalingGroupError::ScalingActivityInProgressFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
-                    _ => {}
+                    _ => {
+                        return RusotoError::unhandled(DeleteAutoScalingGroupError::Unhandled, &res)
+                    }
                 }
             }
         }
@@ -7741,17 +7838,21 @@ impl fmt::Display for DeleteAutoScalingGroupError {
             DeleteAutoScalingGroupError::ScalingActivityInProgressFault(ref cause) => {
                 write!(f, "{}", cause)
             }
+            DeleteAutoScalingGroupError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteAutoScalingGroupError {}
 /// Errors returned by DeleteLaunchConfiguration
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteLaunchConfigurationError {
     /// <p>You already have a pending update to an Amazon EC2 Auto Scaling resource (for example, an Auto Scaling group, instance, or load balancer).</p>
     ResourceContentionFault(String),
     /// <p>The operation can't be performed because the resource is in use.</p>
     ResourceInUseFault(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteLaunchConfigurationError {
@@ -7763,20 +7864,27 @@ impl DeleteLaunchConfigurationError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "ResourceContention" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             DeleteLaunchConfigurationError::ResourceContentionFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "ResourceInUse" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             DeleteLaunchConfigurationError::ResourceInUseFault(
                                 parsed_error.message,
                             ),
+                            &res,
+                        )
+                    }
+                    _ => {
+                        return RusotoError::unhandled(
+                            DeleteLaunchConfigurationError::Unhandled,
+                            &res,
                         )
                     }
-                    _ => {}
                 }
             }
         }
@@ -7799,15 +7907,19 @@ impl fmt::Display for DeleteLaunchConfigurationError {
                 write!(f, "{}", cause)
             }
             DeleteLaunchConfigurationError::ResourceInUseFault(ref cause) => write!(f, "{}", cause),
+            DeleteLaunchConfigurationError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteLaunchConfigurationError {}
 /// Errors returned by DeleteLifecycleHook
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteLifecycleHookError {
     /// <p>You already have a pending update to an Amazon EC2 Auto Scaling resource (for example, an Auto Scaling group, instance, or load balancer).</p>
     ResourceContentionFault(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteLifecycleHookError {
@@ -7819,11 +7931,12 @@ impl DeleteLifecycleHookError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "ResourceContention" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             DeleteLifecycleHookError::ResourceContentionFault(parsed_error.message),
+                            &res,
                         )
                     }
-                    _ => {}
+                    _ => return RusotoError::unhandled(DeleteLifecycleHookError::Unhandled, &res),
                 }
             }
         }
@@ -7843,15 +7956,19 @@ impl fmt::Display for DeleteLifecycleHookError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             DeleteLifecycleHookError::ResourceContentionFault(ref cause) => write!(f, "{}", cause),
+            DeleteLifecycleHookError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteLifecycleHookError {}
 /// Errors returned by DeleteNotificationConfiguration
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteNotificationConfigurationError {
     /// <p>You already have a pending update to an Amazon EC2 Auto Scaling resource (for example, an Auto Scaling group, instance, or load balancer).</p>
     ResourceContentionFault(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteNotificationConfigurationError {
@@ -7865,13 +7982,19 @@ impl DeleteNotificationConfigurationError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "ResourceContention" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             DeleteNotificationConfigurationError::ResourceContentionFault(
                                 parsed_error.message,
                             ),
+                            &res,
+                        )
+                    }
+                    _ => {
+                        return RusotoError::unhandled(
+                            DeleteNotificationConfigurationError::Unhandled,
+                            &res,
                         )
                     }
-                    _ => {}
                 }
             }
         }
@@ -7893,17 +8016,23 @@ impl fmt::Display for DeleteNotificationConfigurationError {
             DeleteNotificationConfigurationError::ResourceContentionFault(ref cause) => {
                 write!(f, "{}", cause)
             }
+            DeleteNotificationConfigurationError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for DeleteNotificationConfigurationError {}
 /// Errors returned by DeletePolicy
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeletePolicyError {
     /// <p>You already have a pending update to an Amazon EC2 Auto Scaling resource (for example, an Auto Scaling group, instance, or load balancer).</p>
     ResourceContentionFault(String),
     /// <p>The service-linked role is not yet ready for use.</p>
     ServiceLinkedRoleFailure(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeletePolicyError {
@@ -7915,16 +8044,18 @@ impl DeletePolicyError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "ResourceContention" => {
-                        return RusotoError::Service(DeletePolicyError::ResourceContentionFault(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            DeletePolicyError::ResourceContentionFault(parsed_error.message),
+                            &res,
+                        )
                     }
                     "ServiceLinkedRoleFailure" => {
-                        return RusotoError::Service(DeletePolicyError::ServiceLinkedRoleFailure(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            DeletePolicyError::ServiceLinkedRoleFailure(parsed_error.message),
+                            &res,
+                        )
                     }
-                    _ => {}
+                    _ => return RusotoError::unhandled(DeletePolicyError::Unhandled, &res),
                 }
             }
         }
@@ -7945,15 +8076,19 @@ impl fmt::Display for DeletePolicyError {
         match *self {
             DeletePolicyError::ResourceContentionFault(ref cause) => write!(f, "{}", cause),
             DeletePolicyError::ServiceLinkedRoleFailure(ref cause) => write!(f, "{}", cause),
+            DeletePolicyError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeletePolicyError {}
 /// Errors returned by DeleteScheduledAction
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteScheduledActionError {
     /// <p>You already have a pending update to an Amazon EC2 Auto Scaling resource (for example, an Auto Scaling group, instance, or load balancer).</p>
     ResourceContentionFault(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteScheduledActionError {
@@ -7965,13 +8100,16 @@ impl DeleteScheduledActionError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "ResourceContention" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             DeleteScheduledActionError::ResourceContentionFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
-                    _ => {}
+                    _ => {
+                        return RusotoError::unhandled(DeleteScheduledActionError::Unhandled, &res)
+                    }
                 }
             }
         }
@@ -7993,17 +8131,21 @@ impl fmt::Display for DeleteScheduledActionError {
             DeleteScheduledActionError::ResourceContentionFault(ref cause) => {
                 write!(f, "{}", cause)
             }
+            DeleteScheduledActionError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteScheduledActionError {}
 /// Errors returned by DeleteTags
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteTagsError {
     /// <p>You already have a pending update to an Amazon EC2 Auto Scaling resource (for example, an Auto Scaling group, instance, or load balancer).</p>
     ResourceContentionFault(String),
     /// <p>The operation can't be performed because the resource is in use.</p>
     ResourceInUseFault(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteTagsError {
@@ -8015,16 +8157,18 @@ impl DeleteTagsError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "ResourceContention" => {
-                        return RusotoError::Service(DeleteTagsError::ResourceContentionFault(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            DeleteTagsError::ResourceContentionFault(parsed_error.message),
+                            &res,
+                        )
                     }
                     "ResourceInUse" => {
-                        return RusotoError::Service(DeleteTagsError::ResourceInUseFault(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            DeleteTagsError::ResourceInUseFault(parsed_error.message),
+                            &res,
+                        )
                     }
-                    _ => {}
+                    _ => return RusotoError::unhandled(DeleteTagsError::Unhandled, &res),
                 }
             }
         }
@@ -8045,15 +8189,19 @@ impl fmt::Display for DeleteTagsError {
         match *self {
             DeleteTagsError::ResourceContentionFault(ref cause) => write!(f, "{}", cause),
             DeleteTagsError::ResourceInUseFault(ref cause) => write!(f, "{}", cause),
+            DeleteTagsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteTagsError {}
 /// Errors returned by DescribeAccountLimits
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeAccountLimitsError {
     /// <p>You already have a pending update to an Amazon EC2 Auto Scaling resource (for example, an Auto Scaling group, instance, or load balancer).</p>
     ResourceContentionFault(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeAccountLimitsError {
@@ -8065,13 +8213,16 @@ impl DescribeAccountLimitsError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "ResourceContention" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             DescribeAccountLimitsError::ResourceContentionFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
-                    _ => {}
+                    _ => {
+                        return RusotoError::unhandled(DescribeAccountLimitsError::Unhandled, &res)
+                    }
                 }
             }
         }
@@ -8093,15 +8244,19 @@ impl fmt::Display for DescribeAccountLimitsError {
             DescribeAccountLimitsError::ResourceContentionFault(ref cause) => {
                 write!(f, "{}", cause)
             }
+            DescribeAccountLimitsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeAccountLimitsError {}
 /// Errors returned by DescribeAdjustmentTypes
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeAdjustmentTypesError {
     /// <p>You already have a pending update to an Amazon EC2 Auto Scaling resource (for example, an Auto Scaling group, instance, or load balancer).</p>
     ResourceContentionFault(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeAdjustmentTypesError {
@@ -8113,13 +8268,19 @@ impl DescribeAdjustmentTypesError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "ResourceContention" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             DescribeAdjustmentTypesError::ResourceContentionFault(
                                 parsed_error.message,
                             ),
+                            &res,
+                        )
+                    }
+                    _ => {
+                        return RusotoError::unhandled(
+                            DescribeAdjustmentTypesError::Unhandled,
+                            &res,
                         )
                     }
-                    _ => {}
                 }
             }
         }
@@ -8141,17 +8302,21 @@ impl fmt::Display for DescribeAdjustmentTypesError {
             DescribeAdjustmentTypesError::ResourceContentionFault(ref cause) => {
                 write!(f, "{}", cause)
             }
+            DescribeAdjustmentTypesError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeAdjustmentTypesError {}
 /// Errors returned by DescribeAutoScalingGroups
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeAutoScalingGroupsError {
     /// <p>The <code>NextToken</code> value is not valid.</p>
     InvalidNextToken(String),
     /// <p>You already have a pending update to an Amazon EC2 Auto Scaling resource (for example, an Auto Scaling group, instance, or load balancer).</p>
     ResourceContentionFault(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeAutoScalingGroupsError {
@@ -8163,18 +8328,25 @@ impl DescribeAutoScalingGroupsError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InvalidNextToken" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             DescribeAutoScalingGroupsError::InvalidNextToken(parsed_error.message),
+                            &res,
                         )
                     }
                     "ResourceContention" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             DescribeAutoScalingGroupsError::ResourceContentionFault(
                                 parsed_error.message,
                             ),
+                            &res,
+                        )
+                    }
+                    _ => {
+                        return RusotoError::unhandled(
+                            DescribeAutoScalingGroupsError::Unhandled,
+                            &res,
                         )
                     }
-                    _ => {}
                 }
             }
         }
@@ -8197,17 +8369,21 @@ impl fmt::Display for DescribeAutoScalingGroupsError {
             DescribeAutoScalingGroupsError::ResourceContentionFault(ref cause) => {
                 write!(f, "{}", cause)
             }
+            DescribeAutoScalingGroupsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeAutoScalingGroupsError {}
 /// Errors returned by DescribeAutoScalingInstances
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeAutoScalingInstancesError {
     /// <p>The <code>NextToken</code> value is not valid.</p>
     InvalidNextToken(String),
     /// <p>You already have a pending update to an Amazon EC2 Auto Scaling resource (for example, an Auto Scaling group, instance, or load balancer).</p>
     ResourceContentionFault(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeAutoScalingInstancesError {
@@ -8221,20 +8397,27 @@ impl DescribeAutoScalingInstancesError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InvalidNextToken" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             DescribeAutoScalingInstancesError::InvalidNextToken(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "ResourceContention" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             DescribeAutoScalingInstancesError::ResourceContentionFault(
                                 parsed_error.message,
                             ),
+                            &res,
+                        )
+                    }
+                    _ => {
+                        return RusotoError::unhandled(
+                            DescribeAutoScalingInstancesError::Unhandled,
+                            &res,
                         )
                     }
-                    _ => {}
                 }
             }
         }
@@ -8259,15 +8442,19 @@ impl fmt::Display for DescribeAutoScalingInstancesError {
             DescribeAutoScalingInstancesError::ResourceContentionFault(ref cause) => {
                 write!(f, "{}", cause)
             }
+            DescribeAutoScalingInstancesError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeAutoScalingInstancesError {}
 /// Errors returned by DescribeAutoScalingNotificationTypes
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeAutoScalingNotificationTypesError {
     /// <p>You already have a pending update to an Amazon EC2 Auto Scaling resource (for example, an Auto Scaling group, instance, or load balancer).</p>
     ResourceContentionFault(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeAutoScalingNotificationTypesError {
@@ -8281,13 +8468,19 @@ impl DescribeAutoScalingNotificationTypesError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "ResourceContention" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             DescribeAutoScalingNotificationTypesError::ResourceContentionFault(
                                 parsed_error.message,
                             ),
+                            &res,
+                        )
+                    }
+                    _ => {
+                        return RusotoError::unhandled(
+                            DescribeAutoScalingNotificationTypesError::Unhandled,
+                            &res,
                         )
                     }
-                    _ => {}
                 }
             }
         }
@@ -8309,17 +8502,23 @@ impl fmt::Display for DescribeAutoScalingNotificationTypesError {
             DescribeAutoScalingNotificationTypesError::ResourceContentionFault(ref cause) => {
                 write!(f, "{}", cause)
             }
+            DescribeAutoScalingNotificationTypesError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for DescribeAutoScalingNotificationTypesError {}
 /// Errors returned by DescribeLaunchConfigurations
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeLaunchConfigurationsError {
     /// <p>The <code>NextToken</code> value is not valid.</p>
     InvalidNextToken(String),
     /// <p>You already have a pending update to an Amazon EC2 Auto Scaling resource (for example, an Auto Scaling group, instance, or load balancer).</p>
     ResourceContentionFault(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeLaunchConfigurationsError {
@@ -8333,20 +8532,27 @@ impl DescribeLaunchConfigurationsError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InvalidNextToken" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             DescribeLaunchConfigurationsError::InvalidNextToken(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "ResourceContention" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             DescribeLaunchConfigurationsError::ResourceContentionFault(
                                 parsed_error.message,
                             ),
+                            &res,
+                        )
+                    }
+                    _ => {
+                        return RusotoError::unhandled(
+                            DescribeLaunchConfigurationsError::Unhandled,
+                            &res,
                         )
                     }
-                    _ => {}
                 }
             }
         }
@@ -8371,15 +8577,19 @@ impl fmt::Display for DescribeLaunchConfigurationsError {
             DescribeLaunchConfigurationsError::ResourceContentionFault(ref cause) => {
                 write!(f, "{}", cause)
             }
+            DescribeLaunchConfigurationsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeLaunchConfigurationsError {}
 /// Errors returned by DescribeLifecycleHookTypes
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeLifecycleHookTypesError {
     /// <p>You already have a pending update to an Amazon EC2 Auto Scaling resource (for example, an Auto Scaling group, instance, or load balancer).</p>
     ResourceContentionFault(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeLifecycleHookTypesError {
@@ -8393,13 +8603,19 @@ impl DescribeLifecycleHookTypesError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "ResourceContention" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             DescribeLifecycleHookTypesError::ResourceContentionFault(
                                 parsed_error.message,
                             ),
+                            &res,
+                        )
+                    }
+                    _ => {
+                        return RusotoError::unhandled(
+                            DescribeLifecycleHookTypesError::Unhandled,
+                            &res,
                         )
                     }
-                    _ => {}
                 }
             }
         }
@@ -8421,15 +8637,19 @@ impl fmt::Display for DescribeLifecycleHookTypesError {
             DescribeLifecycleHookTypesError::ResourceContentionFault(ref cause) => {
                 write!(f, "{}", cause)
             }
+            DescribeLifecycleHookTypesError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeLifecycleHookTypesError {}
 /// Errors returned by DescribeLifecycleHooks
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeLifecycleHooksError {
     /// <p>You already have a pending update to an Amazon EC2 Auto Scaling resource (for example, an Auto Scaling group, instance, or load balancer).</p>
     ResourceContentionFault(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeLifecycleHooksError {
@@ -8441,13 +8661,16 @@ impl DescribeLifecycleHooksError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "ResourceContention" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             DescribeLifecycleHooksError::ResourceContentionFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
-                    _ => {}
+                    _ => {
+                        return RusotoError::unhandled(DescribeLifecycleHooksError::Unhandled, &res)
+                    }
                 }
             }
         }
@@ -8469,15 +8692,19 @@ impl fmt::Display for DescribeLifecycleHooksError {
             DescribeLifecycleHooksError::ResourceContentionFault(ref cause) => {
                 write!(f, "{}", cause)
             }
+            DescribeLifecycleHooksError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeLifecycleHooksError {}
 /// Errors returned by DescribeLoadBalancerTargetGroups
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeLoadBalancerTargetGroupsError {
     /// <p>You already have a pending update to an Amazon EC2 Auto Scaling resource (for example, an Auto Scaling group, instance, or load balancer).</p>
     ResourceContentionFault(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeLoadBalancerTargetGroupsError {
@@ -8491,13 +8718,19 @@ impl DescribeLoadBalancerTargetGroupsError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "ResourceContention" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             DescribeLoadBalancerTargetGroupsError::ResourceContentionFault(
                                 parsed_error.message,
                             ),
+                            &res,
+                        )
+                    }
+                    _ => {
+                        return RusotoError::unhandled(
+                            DescribeLoadBalancerTargetGroupsError::Unhandled,
+                            &res,
                         )
                     }
-                    _ => {}
                 }
             }
         }
@@ -8519,15 +8752,21 @@ impl fmt::Display for DescribeLoadBalancerTargetGroupsError {
             DescribeLoadBalancerTargetGroupsError::ResourceContentionFault(ref cause) => {
                 write!(f, "{}", cause)
             }
+            DescribeLoadBalancerTargetGroupsError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for DescribeLoadBalancerTargetGroupsError {}
 /// Errors returned by DescribeLoadBalancers
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeLoadBalancersError {
     /// <p>You already have a pending update to an Amazon EC2 Auto Scaling resource (for example, an Auto Scaling group, instance, or load balancer).</p>
     ResourceContentionFault(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeLoadBalancersError {
@@ -8539,13 +8778,16 @@ impl DescribeLoadBalancersError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "ResourceContention" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             DescribeLoadBalancersError::ResourceContentionFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
-                    _ => {}
+                    _ => {
+                        return RusotoError::unhandled(DescribeLoadBalancersError::Unhandled, &res)
+                    }
                 }
             }
         }
@@ -8567,15 +8809,19 @@ impl fmt::Display for DescribeLoadBalancersError {
             DescribeLoadBalancersError::ResourceContentionFault(ref cause) => {
                 write!(f, "{}", cause)
             }
+            DescribeLoadBalancersError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeLoadBalancersError {}
 /// Errors returned by DescribeMetricCollectionTypes
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeMetricCollectionTypesError {
     /// <p>You already have a pending update to an Amazon EC2 Auto Scaling resource (for example, an Auto Scaling group, instance, or load balancer).</p>
     ResourceContentionFault(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeMetricCollectionTypesError {
@@ -8589,13 +8835,19 @@ impl DescribeMetricCollectionTypesError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "ResourceContention" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             DescribeMetricCollectionTypesError::ResourceContentionFault(
                                 parsed_error.message,
                             ),
+                            &res,
+                        )
+                    }
+                    _ => {
+                        return RusotoError::unhandled(
+                            DescribeMetricCollectionTypesError::Unhandled,
+                            &res,
                         )
                     }
-                    _ => {}
                 }
             }
         }
@@ -8617,17 +8869,23 @@ impl fmt::Display for DescribeMetricCollectionTypesError {
             DescribeMetricCollectionTypesError::ResourceContentionFault(ref cause) => {
                 write!(f, "{}", cause)
             }
+            DescribeMetricCollectionTypesError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for DescribeMetricCollectionTypesError {}
 /// Errors returned by DescribeNotificationConfigurations
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeNotificationConfigurationsError {
     /// <p>The <code>NextToken</code> value is not valid.</p>
     InvalidNextToken(String),
     /// <p>You already have a pending update to an Amazon EC2 Auto Scaling resource (for example, an Auto Scaling group, instance, or load balancer).</p>
     ResourceContentionFault(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeNotificationConfigurationsError {
@@ -8641,20 +8899,27 @@ impl DescribeNotificationConfigurationsError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InvalidNextToken" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             DescribeNotificationConfigurationsError::InvalidNextToken(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "ResourceContention" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             DescribeNotificationConfigurationsError::ResourceContentionFault(
                                 parsed_error.message,
                             ),
+                            &res,
+                        )
+                    }
+                    _ => {
+                        return RusotoError::unhandled(
+                            DescribeNotificationConfigurationsError::Unhandled,
+                            &res,
                         )
                     }
-                    _ => {}
                 }
             }
         }
@@ -8679,12 +8944,16 @@ impl fmt::Display for DescribeNotificationConfigurationsError {
             DescribeNotificationConfigurationsError::ResourceContentionFault(ref cause) => {
                 write!(f, "{}", cause)
             }
+            DescribeNotificationConfigurationsError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for DescribeNotificationConfigurationsError {}
 /// Errors returned by DescribePolicies
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribePoliciesError {
     /// <p>The <code>NextToken</code> value is not valid.</p>
     InvalidNextToken(String),
@@ -8692,6 +8961,8 @@ pub enum DescribePoliciesError {
     ResourceContentionFault(String),
     /// <p>The service-linked role is not yet ready for use.</p>
     ServiceLinkedRoleFailure(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribePoliciesError {
@@ -8703,21 +8974,24 @@ impl DescribePoliciesError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InvalidNextToken" => {
-                        return RusotoError::Service(DescribePoliciesError::InvalidNextToken(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            DescribePoliciesError::InvalidNextToken(parsed_error.message),
+                            &res,
+                        )
                     }
                     "ResourceContention" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             DescribePoliciesError::ResourceContentionFault(parsed_error.message),
+                            &res,
                         )
                     }
                     "ServiceLinkedRoleFailure" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             DescribePoliciesError::ServiceLinkedRoleFailure(parsed_error.message),
+                            &res,
                         )
                     }
-                    _ => {}
+                    _ => return RusotoError::unhandled(DescribePoliciesError::Unhandled, &res),
                 }
             }
         }
@@ -8739,17 +9013,21 @@ impl fmt::Display for DescribePoliciesError {
             DescribePoliciesError::InvalidNextToken(ref cause) => write!(f, "{}", cause),
             DescribePoliciesError::ResourceContentionFault(ref cause) => write!(f, "{}", cause),
             DescribePoliciesError::ServiceLinkedRoleFailure(ref cause) => write!(f, "{}", cause),
+            DescribePoliciesError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribePoliciesError {}
 /// Errors returned by DescribeScalingActivities
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeScalingActivitiesError {
     /// <p>The <code>NextToken</code> value is not valid.</p>
     InvalidNextToken(String),
     /// <p>You already have a pending update to an Amazon EC2 Auto Scaling resource (for example, an Auto Scaling group, instance, or load balancer).</p>
     ResourceContentionFault(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeScalingActivitiesError {
@@ -8761,18 +9039,25 @@ impl DescribeScalingActivitiesError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InvalidNextToken" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             DescribeScalingActivitiesError::InvalidNextToken(parsed_error.message),
+                            &res,
                         )
                     }
                     "ResourceContention" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             DescribeScalingActivitiesError::ResourceContentionFault(
                                 parsed_error.message,
                             ),
+                            &res,
+                        )
+                    }
+                    _ => {
+                        return RusotoError::unhandled(
+                            DescribeScalingActivitiesError::Unhandled,
+                            &res,
                         )
                     }
-                    _ => {}
                 }
             }
         }
@@ -8795,15 +9080,19 @@ impl fmt::Display for DescribeScalingActivitiesError {
             DescribeScalingActivitiesError::ResourceContentionFault(ref cause) => {
                 write!(f, "{}", cause)
             }
+            DescribeScalingActivitiesError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeScalingActivitiesError {}
 /// Errors returned by DescribeScalingProcessTypes
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeScalingProcessTypesError {
     /// <p>You already have a pending update to an Amazon EC2 Auto Scaling resource (for example, an Auto Scaling group, instance, or load balancer).</p>
     ResourceContentionFault(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeScalingProcessTypesError {
@@ -8817,13 +9106,19 @@ impl DescribeScalingProcessTypesError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "ResourceContention" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             DescribeScalingProcessTypesError::ResourceContentionFault(
                                 parsed_error.message,
                             ),
+                            &res,
+                        )
+                    }
+                    _ => {
+                        return RusotoError::unhandled(
+                            DescribeScalingProcessTypesError::Unhandled,
+                            &res,
                         )
                     }
-                    _ => {}
                 }
             }
         }
@@ -8845,17 +9140,21 @@ impl fmt::Display for DescribeScalingProcessTypesError {
             DescribeScalingProcessTypesError::ResourceContentionFault(ref cause) => {
                 write!(f, "{}", cause)
             }
+            DescribeScalingProcessTypesError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeScalingProcessTypesError {}
 /// Errors returned by DescribeScheduledActions
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeScheduledActionsError {
     /// <p>The <code>NextToken</code> value is not valid.</p>
     InvalidNextToken(String),
     /// <p>You already have a pending update to an Amazon EC2 Auto Scaling resource (for example, an Auto Scaling group, instance, or load balancer).</p>
     ResourceContentionFault(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeScheduledActionsError {
@@ -8867,18 +9166,25 @@ impl DescribeScheduledActionsError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InvalidNextToken" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             DescribeScheduledActionsError::InvalidNextToken(parsed_error.message),
+                            &res,
                         )
                     }
                     "ResourceContention" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             DescribeScheduledActionsError::ResourceContentionFault(
                                 parsed_error.message,
                             ),
+                            &res,
+                        )
+                    }
+                    _ => {
+                        return RusotoError::unhandled(
+                            DescribeScheduledActionsError::Unhandled,
+                            &res,
                         )
                     }
-                    _ => {}
                 }
             }
         }
@@ -8901,17 +9207,21 @@ impl fmt::Display for DescribeScheduledActionsError {
             DescribeScheduledActionsError::ResourceContentionFault(ref cause) => {
                 write!(f, "{}", cause)
             }
+            DescribeScheduledActionsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeScheduledActionsError {}
 /// Errors returned by DescribeTags
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeTagsError {
     /// <p>The <code>NextToken</code> value is not valid.</p>
     InvalidNextToken(String),
     /// <p>You already have a pending update to an Amazon EC2 Auto Scaling resource (for example, an Auto Scaling group, instance, or load balancer).</p>
     ResourceContentionFault(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeTagsError {
@@ -8923,16 +9233,18 @@ impl DescribeTagsError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InvalidNextToken" => {
-                        return RusotoError::Service(DescribeTagsError::InvalidNextToken(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            DescribeTagsError::InvalidNextToken(parsed_error.message),
+                            &res,
+                        )
                     }
                     "ResourceContention" => {
-                        return RusotoError::Service(DescribeTagsError::ResourceContentionFault(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            DescribeTagsError::ResourceContentionFault(parsed_error.message),
+                            &res,
+                        )
                     }
-                    _ => {}
+                    _ => return RusotoError::unhandled(DescribeTagsError::Unhandled, &res),
                 }
             }
         }
@@ -8953,15 +9265,19 @@ impl fmt::Display for DescribeTagsError {
         match *self {
             DescribeTagsError::InvalidNextToken(ref cause) => write!(f, "{}", cause),
             DescribeTagsError::ResourceContentionFault(ref cause) => write!(f, "{}", cause),
+            DescribeTagsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeTagsError {}
 /// Errors returned by DescribeTerminationPolicyTypes
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeTerminationPolicyTypesError {
     /// <p>You already have a pending update to an Amazon EC2 Auto Scaling resource (for example, an Auto Scaling group, instance, or load balancer).</p>
     ResourceContentionFault(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeTerminationPolicyTypesError {
@@ -8975,13 +9291,19 @@ impl DescribeTerminationPolicyTypesError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "ResourceContention" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             DescribeTerminationPolicyTypesError::ResourceContentionFault(
                                 parsed_error.message,
                             ),
+                            &res,
+                        )
+                    }
+                    _ => {
+                        return RusotoError::unhandled(
+                            DescribeTerminationPolicyTypesError::Unhandled,
+                            &res,
                         )
                     }
-                    _ => {}
                 }
             }
         }
@@ -9003,15 +9325,21 @@ impl fmt::Display for DescribeTerminationPolicyTypesError {
             DescribeTerminationPolicyTypesError::ResourceContentionFault(ref cause) => {
                 write!(f, "{}", cause)
             }
+            DescribeTerminationPolicyTypesError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for DescribeTerminationPolicyTypesError {}
 /// Errors returned by DetachInstances
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DetachInstancesError {
     /// <p>You already have a pending update to an Amazon EC2 Auto Scaling resource (for example, an Auto Scaling group, instance, or load balancer).</p>
     ResourceContentionFault(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DetachInstancesError {
@@ -9023,11 +9351,12 @@ impl DetachInstancesError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "ResourceContention" => {
-                        return RusotoError::Service(DetachInstancesError::ResourceContentionFault(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            DetachInstancesError::ResourceContentionFault(parsed_error.message),
+                            &res,
+                        )
                     }
-                    _ => {}
+                    _ => return RusotoError::unhandled(DetachInstancesError::Unhandled, &res),
                 }
             }
         }
@@ -9047,15 +9376,19 @@ impl fmt::Display for DetachInstancesError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             DetachInstancesError::ResourceContentionFault(ref cause) => write!(f, "{}", cause),
+            DetachInstancesError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DetachInstancesError {}
 /// Errors returned by DetachLoadBalancerTargetGroups
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DetachLoadBalancerTargetGroupsError {
     /// <p>You already have a pending update to an Amazon EC2 Auto Scaling resource (for example, an Auto Scaling group, instance, or load balancer).</p>
     ResourceContentionFault(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DetachLoadBalancerTargetGroupsError {
@@ -9069,13 +9402,19 @@ impl DetachLoadBalancerTargetGroupsError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "ResourceContention" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             DetachLoadBalancerTargetGroupsError::ResourceContentionFault(
                                 parsed_error.message,
                             ),
+                            &res,
+                        )
+                    }
+                    _ => {
+                        return RusotoError::unhandled(
+                            DetachLoadBalancerTargetGroupsError::Unhandled,
+                            &res,
                         )
                     }
-                    _ => {}
                 }
             }
         }
@@ -9097,15 +9436,21 @@ impl fmt::Display for DetachLoadBalancerTargetGroupsError {
             DetachLoadBalancerTargetGroupsError::ResourceContentionFault(ref cause) => {
                 write!(f, "{}", cause)
             }
+            DetachLoadBalancerTargetGroupsError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for DetachLoadBalancerTargetGroupsError {}
 /// Errors returned by DetachLoadBalancers
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DetachLoadBalancersError {
     /// <p>You already have a pending update to an Amazon EC2 Auto Scaling resource (for example, an Auto Scaling group, instance, or load balancer).</p>
     ResourceContentionFault(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DetachLoadBalancersError {
@@ -9117,11 +9462,12 @@ impl DetachLoadBalancersError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "ResourceContention" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             DetachLoadBalancersError::ResourceContentionFault(parsed_error.message),
+                            &res,
                         )
                     }
-                    _ => {}
+                    _ => return RusotoError::unhandled(DetachLoadBalancersError::Unhandled, &res),
                 }
             }
         }
@@ -9141,15 +9487,19 @@ impl fmt::Display for DetachLoadBalancersError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             DetachLoadBalancersError::ResourceContentionFault(ref cause) => write!(f, "{}", cause),
+            DetachLoadBalancersError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DetachLoadBalancersError {}
 /// Errors returned by DisableMetricsCollection
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DisableMetricsCollectionError {
     /// <p>You already have a pending update to an Amazon EC2 Auto Scaling resource (for example, an Auto Scaling group, instance, or load balancer).</p>
     ResourceContentionFault(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DisableMetricsCollectionError {
@@ -9161,13 +9511,19 @@ impl DisableMetricsCollectionError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "ResourceContention" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             DisableMetricsCollectionError::ResourceContentionFault(
                                 parsed_error.message,
                             ),
+                            &res,
+                        )
+                    }
+                    _ => {
+                        return RusotoError::unhandled(
+                            DisableMetricsCollectionError::Unhandled,
+                            &res,
                         )
                     }
-                    _ => {}
                 }
             }
         }
@@ -9189,15 +9545,19 @@ impl fmt::Display for DisableMetricsCollectionError {
             DisableMetricsCollectionError::ResourceContentionFault(ref cause) => {
                 write!(f, "{}", cause)
             }
+            DisableMetricsCollectionError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DisableMetricsCollectionError {}
 /// Errors returned by EnableMetricsCollection
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum EnableMetricsCollectionError {
     /// <p>You already have a pending update to an Amazon EC2 Auto Scaling resource (for example, an Auto Scaling group, instance, or load balancer).</p>
     ResourceContentionFault(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl EnableMetricsCollectionError {
@@ -9209,13 +9569,19 @@ impl EnableMetricsCollectionError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "ResourceContention" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             EnableMetricsCollectionError::ResourceContentionFault(
                                 parsed_error.message,
                             ),
+                            &res,
+                        )
+                    }
+                    _ => {
+                        return RusotoError::unhandled(
+                            EnableMetricsCollectionError::Unhandled,
+                            &res,
                         )
                     }
-                    _ => {}
                 }
             }
         }
@@ -9237,15 +9603,19 @@ impl fmt::Display for EnableMetricsCollectionError {
             EnableMetricsCollectionError::ResourceContentionFault(ref cause) => {
                 write!(f, "{}", cause)
             }
+            EnableMetricsCollectionError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for EnableMetricsCollectionError {}
 /// Errors returned by EnterStandby
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum EnterStandbyError {
     /// <p>You already have a pending update to an Amazon EC2 Auto Scaling resource (for example, an Auto Scaling group, instance, or load balancer).</p>
     ResourceContentionFault(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl EnterStandbyError {
@@ -9257,11 +9627,12 @@ impl EnterStandbyError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "ResourceContention" => {
-                        return RusotoError::Service(EnterStandbyError::ResourceContentionFault(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            EnterStandbyError::ResourceContentionFault(parsed_error.message),
+                            &res,
+                        )
                     }
-                    _ => {}
+                    _ => return RusotoError::unhandled(EnterStandbyError::Unhandled, &res),
                 }
             }
         }
@@ -9281,17 +9652,21 @@ impl fmt::Display for EnterStandbyError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             EnterStandbyError::ResourceContentionFault(ref cause) => write!(f, "{}", cause),
+            EnterStandbyError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for EnterStandbyError {}
 /// Errors returned by ExecutePolicy
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ExecutePolicyError {
     /// <p>You already have a pending update to an Amazon EC2 Auto Scaling resource (for example, an Auto Scaling group, instance, or load balancer).</p>
     ResourceContentionFault(String),
     /// <p>The operation can't be performed because there are scaling activities in progress.</p>
     ScalingActivityInProgressFault(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ExecutePolicyError {
@@ -9303,18 +9678,20 @@ impl ExecutePolicyError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "ResourceContention" => {
-                        return RusotoError::Service(ExecutePolicyError::ResourceContentionFault(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            ExecutePolicyError::ResourceContentionFault(parsed_error.message),
+                            &res,
+                        )
                     }
                     "ScalingActivityInProgress" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             ExecutePolicyError::ScalingActivityInProgressFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
-                    _ => {}
+                    _ => return RusotoError::unhandled(ExecutePolicyError::Unhandled, &res),
                 }
             }
         }
@@ -9335,15 +9712,19 @@ impl fmt::Display for ExecutePolicyError {
         match *self {
             ExecutePolicyError::ResourceContentionFault(ref cause) => write!(f, "{}", cause),
             ExecutePolicyError::ScalingActivityInProgressFault(ref cause) => write!(f, "{}", cause),
+            ExecutePolicyError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ExecutePolicyError {}
 /// Errors returned by ExitStandby
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ExitStandbyError {
     /// <p>You already have a pending update to an Amazon EC2 Auto Scaling resource (for example, an Auto Scaling group, instance, or load balancer).</p>
     ResourceContentionFault(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ExitStandbyError {
@@ -9355,11 +9736,12 @@ impl ExitStandbyError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "ResourceContention" => {
-                        return RusotoError::Service(ExitStandbyError::ResourceContentionFault(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            ExitStandbyError::ResourceContentionFault(parsed_error.message),
+                            &res,
+                        )
                     }
-                    _ => {}
+                    _ => return RusotoError::unhandled(ExitStandbyError::Unhandled, &res),
                 }
             }
         }
@@ -9379,17 +9761,21 @@ impl fmt::Display for ExitStandbyError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             ExitStandbyError::ResourceContentionFault(ref cause) => write!(f, "{}", cause),
+            ExitStandbyError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ExitStandbyError {}
 /// Errors returned by PutLifecycleHook
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum PutLifecycleHookError {
     /// <p>You have already reached a limit for your Amazon EC2 Auto Scaling resources (for example, Auto Scaling groups, launch configurations, or lifecycle hooks). For more information, see <a>DescribeAccountLimits</a>.</p>
     LimitExceededFault(String),
     /// <p>You already have a pending update to an Amazon EC2 Auto Scaling resource (for example, an Auto Scaling group, instance, or load balancer).</p>
     ResourceContentionFault(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl PutLifecycleHookError {
@@ -9401,16 +9787,18 @@ impl PutLifecycleHookError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "LimitExceeded" => {
-                        return RusotoError::Service(PutLifecycleHookError::LimitExceededFault(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            PutLifecycleHookError::LimitExceededFault(parsed_error.message),
+                            &res,
+                        )
                     }
                     "ResourceContention" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             PutLifecycleHookError::ResourceContentionFault(parsed_error.message),
+                            &res,
                         )
                     }
-                    _ => {}
+                    _ => return RusotoError::unhandled(PutLifecycleHookError::Unhandled, &res),
                 }
             }
         }
@@ -9431,12 +9819,14 @@ impl fmt::Display for PutLifecycleHookError {
         match *self {
             PutLifecycleHookError::LimitExceededFault(ref cause) => write!(f, "{}", cause),
             PutLifecycleHookError::ResourceContentionFault(ref cause) => write!(f, "{}", cause),
+            PutLifecycleHookError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for PutLifecycleHookError {}
 /// Errors returned by PutNotificationConfiguration
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum PutNotificationConfigurationError {
     /// <p>You have already reached a limit for your Amazon EC2 Auto Scaling resources (for example, Auto Scaling groups, launch configurations, or lifecycle hooks). For more information, see <a>DescribeAccountLimits</a>.</p>
     LimitExceededFault(String),
@@ -9444,6 +9834,8 @@ pub enum PutNotificationConfigurationError {
     ResourceContentionFault(String),
     /// <p>The service-linked role is not yet ready for use.</p>
     ServiceLinkedRoleFailure(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl PutNotificationConfigurationError {
@@ -9457,27 +9849,35 @@ impl PutNotificationConfigurationError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "LimitExceeded" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             PutNotificationConfigurationError::LimitExceededFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "ResourceContention" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             PutNotificationConfigurationError::ResourceContentionFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "ServiceLinkedRoleFailure" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             PutNotificationConfigurationError::ServiceLinkedRoleFailure(
                                 parsed_error.message,
                             ),
+                            &res,
+                        )
+                    }
+                    _ => {
+                        return RusotoError::unhandled(
+                            PutNotificationConfigurationError::Unhandled,
+                            &res,
                         )
                     }
-                    _ => {}
                 }
             }
         }
@@ -9505,12 +9905,14 @@ impl fmt::Display for PutNotificationConfigurationError {
             PutNotificationConfigurationError::ServiceLinkedRoleFailure(ref cause) => {
                 write!(f, "{}", cause)
             }
+            PutNotificationConfigurationError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for PutNotificationConfigurationError {}
 /// Errors returned by PutScalingPolicy
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum PutScalingPolicyError {
     /// <p>You have already reached a limit for your Amazon EC2 Auto Scaling resources (for example, Auto Scaling groups, launch configurations, or lifecycle hooks). For more information, see <a>DescribeAccountLimits</a>.</p>
     LimitExceededFault(String),
@@ -9518,6 +9920,8 @@ pub enum PutScalingPolicyError {
     ResourceContentionFault(String),
     /// <p>The service-linked role is not yet ready for use.</p>
     ServiceLinkedRoleFailure(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl PutScalingPolicyError {
@@ -9529,21 +9933,24 @@ impl PutScalingPolicyError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "LimitExceeded" => {
-                        return RusotoError::Service(PutScalingPolicyError::LimitExceededFault(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            PutScalingPolicyError::LimitExceededFault(parsed_error.message),
+                            &res,
+                        )
                     }
                     "ResourceContention" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             PutScalingPolicyError::ResourceContentionFault(parsed_error.message),
+                            &res,
                         )
                     }
                     "ServiceLinkedRoleFailure" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             PutScalingPolicyError::ServiceLinkedRoleFailure(parsed_error.message),
+                            &res,
                         )
                     }
-                    _ => {}
+                    _ => return RusotoError::unhandled(PutScalingPolicyError::Unhandled, &res),
                 }
             }
         }
@@ -9565,12 +9972,14 @@ impl fmt::Display for PutScalingPolicyError {
             PutScalingPolicyError::LimitExceededFault(ref cause) => write!(f, "{}", cause),
             PutScalingPolicyError::ResourceContentionFault(ref cause) => write!(f, "{}", cause),
             PutScalingPolicyError::ServiceLinkedRoleFailure(ref cause) => write!(f, "{}", cause),
+            PutScalingPolicyError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for PutScalingPolicyError {}
 /// Errors returned by PutScheduledUpdateGroupAction
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum PutScheduledUpdateGroupActionError {
     /// <p>You already have an Auto Scaling group or launch configuration with this name.</p>
     AlreadyExistsFault(String),
@@ -9578,6 +9987,8 @@ pub enum PutScheduledUpdateGroupActionError {
     LimitExceededFault(String),
     /// <p>You already have a pending update to an Amazon EC2 Auto Scaling resource (for example, an Auto Scaling group, instance, or load balancer).</p>
     ResourceContentionFault(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl PutScheduledUpdateGroupActionError {
@@ -9591,27 +10002,35 @@ impl PutScheduledUpdateGroupActionError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "AlreadyExists" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             PutScheduledUpdateGroupActionError::AlreadyExistsFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "LimitExceeded" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             PutScheduledUpdateGroupActionError::LimitExceededFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "ResourceContention" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             PutScheduledUpdateGroupActionError::ResourceContentionFault(
                                 parsed_error.message,
                             ),
+                            &res,
+                        )
+                    }
+                    _ => {
+                        return RusotoError::unhandled(
+                            PutScheduledUpdateGroupActionError::Unhandled,
+                            &res,
                         )
                     }
-                    _ => {}
                 }
             }
         }
@@ -9639,15 +10058,21 @@ impl fmt::Display for PutScheduledUpdateGroupActionError {
             PutScheduledUpdateGroupActionError::ResourceContentionFault(ref cause) => {
                 write!(f, "{}", cause)
             }
+            PutScheduledUpdateGroupActionError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for PutScheduledUpdateGroupActionError {}
 /// Errors returned by RecordLifecycleActionHeartbeat
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum RecordLifecycleActionHeartbeatError {
     /// <p>You already have a pending update to an Amazon EC2 Auto Scaling resource (for example, an Auto Scaling group, instance, or load balancer).</p>
     ResourceContentionFault(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl RecordLifecycleActionHeartbeatError {
@@ -9661,13 +10086,19 @@ impl RecordLifecycleActionHeartbeatError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "ResourceContention" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             RecordLifecycleActionHeartbeatError::ResourceContentionFault(
                                 parsed_error.message,
                             ),
+                            &res,
+                        )
+                    }
+                    _ => {
+                        return RusotoError::unhandled(
+                            RecordLifecycleActionHeartbeatError::Unhandled,
+                            &res,
                         )
                     }
-                    _ => {}
                 }
             }
         }
@@ -9689,17 +10120,23 @@ impl fmt::Display for RecordLifecycleActionHeartbeatError {
             RecordLifecycleActionHeartbeatError::ResourceContentionFault(ref cause) => {
                 write!(f, "{}", cause)
             }
+            RecordLifecycleActionHeartbeatError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for RecordLifecycleActionHeartbeatError {}
 /// Errors returned by ResumeProcesses
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ResumeProcessesError {
     /// <p>You already have a pending update to an Amazon EC2 Auto Scaling resource (for example, an Auto Scaling group, instance, or load balancer).</p>
     ResourceContentionFault(String),
     /// <p>The operation can't be performed because the resource is in use.</p>
     ResourceInUseFault(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ResumeProcessesError {
@@ -9711,16 +10148,18 @@ impl ResumeProcessesError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "ResourceContention" => {
-                        return RusotoError::Service(ResumeProcessesError::ResourceContentionFault(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            ResumeProcessesError::ResourceContentionFault(parsed_error.message),
+                            &res,
+                        )
                     }
                     "ResourceInUse" => {
-                        return RusotoError::Service(ResumeProcessesError::ResourceInUseFault(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            ResumeProcessesError::ResourceInUseFault(parsed_error.message),
+                            &res,
+                        )
                     }
-                    _ => {}
+                    _ => return RusotoError::unhandled(ResumeProcessesError::Unhandled, &res),
                 }
             }
         }
@@ -9741,17 +10180,21 @@ impl fmt::Display for ResumeProcessesError {
         match *self {
             ResumeProcessesError::ResourceContentionFault(ref cause) => write!(f, "{}", cause),
             ResumeProcessesError::ResourceInUseFault(ref cause) => write!(f, "{}", cause),
+            ResumeProcessesError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ResumeProcessesError {}
 /// Errors returned by SetDesiredCapacity
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum SetDesiredCapacityError {
     /// <p>You already have a pending update to an Amazon EC2 Auto Scaling resource (for example, an Auto Scaling group, instance, or load balancer).</p>
     ResourceContentionFault(String),
     /// <p>The operation can't be performed because there are scaling activities in progress.</p>
     ScalingActivityInProgressFault(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl SetDesiredCapacityError {
@@ -9763,18 +10206,20 @@ impl SetDesiredCapacityError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "ResourceContention" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             SetDesiredCapacityError::ResourceContentionFault(parsed_error.message),
+                            &res,
                         )
                     }
                     "ScalingActivityInProgress" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             SetDesiredCapacityError::ScalingActivityInProgressFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
-                    _ => {}
+                    _ => return RusotoError::unhandled(SetDesiredCapacityError::Unhandled, &res),
                 }
             }
         }
@@ -9797,15 +10242,19 @@ impl fmt::Display for SetDesiredCapacityError {
             SetDesiredCapacityError::ScalingActivityInProgressFault(ref cause) => {
                 write!(f, "{}", cause)
             }
+            SetDesiredCapacityError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for SetDesiredCapacityError {}
 /// Errors returned by SetInstanceHealth
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum SetInstanceHealthError {
     /// <p>You already have a pending update to an Amazon EC2 Auto Scaling resource (for example, an Auto Scaling group, instance, or load balancer).</p>
     ResourceContentionFault(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl SetInstanceHealthError {
@@ -9817,11 +10266,12 @@ impl SetInstanceHealthError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "ResourceContention" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             SetInstanceHealthError::ResourceContentionFault(parsed_error.message),
+                            &res,
                         )
                     }
-                    _ => {}
+                    _ => return RusotoError::unhandled(SetInstanceHealthError::Unhandled, &res),
                 }
             }
         }
@@ -9841,17 +10291,21 @@ impl fmt::Display for SetInstanceHealthError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             SetInstanceHealthError::ResourceContentionFault(ref cause) => write!(f, "{}", cause),
+            SetInstanceHealthError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for SetInstanceHealthError {}
 /// Errors returned by SetInstanceProtection
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum SetInstanceProtectionError {
     /// <p>You have already reached a limit for your Amazon EC2 Auto Scaling resources (for example, Auto Scaling groups, launch configurations, or lifecycle hooks). For more information, see <a>DescribeAccountLimits</a>.</p>
     LimitExceededFault(String),
     /// <p>You already have a pending update to an Amazon EC2 Auto Scaling resource (for example, an Auto Scaling group, instance, or load balancer).</p>
     ResourceContentionFault(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl SetInstanceProtectionError {
@@ -9863,18 +10317,22 @@ impl SetInstanceProtectionError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "LimitExceeded" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             SetInstanceProtectionError::LimitExceededFault(parsed_error.message),
+                            &res,
                         )
                     }
                     "ResourceContention" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             SetInstanceProtectionError::ResourceContentionFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
-                    _ => {}
+                    _ => {
+                        return RusotoError::unhandled(SetInstanceProtectionError::Unhandled, &res)
+                    }
                 }
             }
         }
@@ -9897,17 +10355,21 @@ impl fmt::Display for SetInstanceProtectionError {
             SetInstanceProtectionError::ResourceContentionFault(ref cause) => {
                 write!(f, "{}", cause)
             }
+            SetInstanceProtectionError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for SetInstanceProtectionError {}
 /// Errors returned by SuspendProcesses
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum SuspendProcessesError {
     /// <p>You already have a pending update to an Amazon EC2 Auto Scaling resource (for example, an Auto Scaling group, instance, or load balancer).</p>
     ResourceContentionFault(String),
     /// <p>The operation can't be performed because the resource is in use.</p>
     ResourceInUseFault(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl SuspendProcessesError {
@@ -9919,16 +10381,18 @@ impl SuspendProcessesError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "ResourceContention" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             SuspendProcessesError::ResourceContentionFault(parsed_error.message),
+                            &res,
                         )
                     }
                     "ResourceInUse" => {
-                        return RusotoError::Service(SuspendProcessesError::ResourceInUseFault(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            SuspendProcessesError::ResourceInUseFault(parsed_error.message),
+                            &res,
+                        )
                     }
-                    _ => {}
+                    _ => return RusotoError::unhandled(SuspendProcessesError::Unhandled, &res),
                 }
             }
         }
@@ -9949,17 +10413,21 @@ impl fmt::Display for SuspendProcessesError {
         match *self {
             SuspendProcessesError::ResourceContentionFault(ref cause) => write!(f, "{}", cause),
             SuspendProcessesError::ResourceInUseFault(ref cause) => write!(f, "{}", cause),
+            SuspendProcessesError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for SuspendProcessesError {}
 /// Errors returned by TerminateInstanceInAutoScalingGroup
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum TerminateInstanceInAutoScalingGroupError {
     /// <p>You already have a pending update to an Amazon EC2 Auto Scaling resource (for example, an Auto Scaling group, instance, or load balancer).</p>
     ResourceContentionFault(String),
     /// <p>The operation can't be performed because there are scaling activities in progress.</p>
     ScalingActivityInProgressFault(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl TerminateInstanceInAutoScalingGroupError {
@@ -9973,18 +10441,25 @@ impl TerminateInstanceInAutoScalingGroupError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "ResourceContention" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             TerminateInstanceInAutoScalingGroupError::ResourceContentionFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
-                    "ScalingActivityInProgress" => return RusotoError::Service(
+                    "ScalingActivityInProgress" => return RusotoError::service(
                         TerminateInstanceInAutoScalingGroupError::ScalingActivityInProgressFault(
                             parsed_error.message,
                         ),
+                        &res,
                     ),
-                    _ => {}
+                    _ => {
+                        return RusotoError::unhandled(
+                            TerminateInstanceInAutoScalingGroupError::Unhandled,
+                            &res,
+                        )
+                    }
                 }
             }
         }
@@ -10009,12 +10484,16 @@ impl fmt::Display for TerminateInstanceInAutoScalingGroupError {
             TerminateInstanceInAutoScalingGroupError::ScalingActivityInProgressFault(ref cause) => {
                 write!(f, "{}", cause)
             }
+            TerminateInstanceInAutoScalingGroupError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for TerminateInstanceInAutoScalingGroupError {}
 /// Errors returned by UpdateAutoScalingGroup
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdateAutoScalingGroupError {
     /// <p>You already have a pending update to an Amazon EC2 Auto Scaling resource (for example, an Auto Scaling group, instance, or load balancer).</p>
     ResourceContentionFault(String),
@@ -10022,6 +10501,8 @@ pub enum UpdateAutoScalingGroupError {
     ScalingActivityInProgressFault(String),
     /// <p>The service-linked role is not yet ready for use.</p>
     ServiceLinkedRoleFailure(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdateAutoScalingGroupError {
@@ -10033,27 +10514,32 @@ impl UpdateAutoScalingGroupError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "ResourceContention" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             UpdateAutoScalingGroupError::ResourceContentionFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "ScalingActivityInProgress" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             UpdateAutoScalingGroupError::ScalingActivityInProgressFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "ServiceLinkedRoleFailure" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             UpdateAutoScalingGroupError::ServiceLinkedRoleFailure(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
-                    _ => {}
+                    _ => {
+                        return RusotoError::unhandled(UpdateAutoScalingGroupError::Unhandled, &res)
+                    }
                 }
             }
         }
@@ -10081,6 +10567,7 @@ impl fmt::Display for UpdateAutoScalingGroupError {
             UpdateAutoScalingGroupError::ServiceLinkedRoleFailure(ref cause) => {
                 write!(f, "{}", cause)
             }
+            UpdateAutoScalingGroupError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
@@ -10437,6 +10924,7 @@ impl AutoscalingClient {
     /// Creates a client backed by the default tokio event loop.
     ///
     /// The client will use the default credentials provider and tls client.
+    #[cfg(not(target_arch = "wasm32"))]
     pub fn new(region: region::Region) -> AutoscalingClient {
         AutoscalingClient {
             client: Client::shared(),
@@ -10462,66 +10950,101 @@ impl AutoscalingClient {
     pub fn new_with_client(client: Client, region: region::Region) -> AutoscalingClient {
         AutoscalingClient { client, region }
     }
+
+    /// Returns a builder of a client, setting any of its region, credentials provider,
+    /// dispatcher, retry policy, endpoint and timeouts.
+    pub fn builder() -> rusoto_core::ClientBuilder<AutoscalingClient> {
+        rusoto_core::ClientBuilder::new(AutoscalingClient::new_with_client)
+    }
+
+    /// Returns a clone of this client with the overrides of `config` applied to its
+    /// operations, sharing its connections.
+    pub fn customize(&self, config: &rusoto_core::OperationConfig) -> AutoscalingClient {
+        AutoscalingClient {
+            client: self.client.customize(config),
+            region: config.resolve_region(&self.region),
+        }
+    }
 }
 
-#[async_trait]
-impl Autoscaling for AutoscalingClient {
-    /// <p>Attaches one or more EC2 instances to the specified Auto Scaling group.</p> <p>When you attach instances, Amazon EC2 Auto Scaling increases the desired capacity of the group by the number of instances being attached. If the number of instances being attached plus the desired capacity of the group exceeds the maximum size of the group, the operation fails.</p> <p>If there is a Classic Load Balancer attached to your Auto Scaling group, the instances are also registered with the load balancer. If there are target groups attached to your Auto Scaling group, the instances are also registered with the target groups.</p> <p>For more information, see <a href="https://docs.aws.amazon.com/autoscaling/ec2/userguide/attach-instance-asg.html">Attach EC2 Instances to Your Auto Scaling Group</a> in the <i>Amazon EC2 Auto Scaling User Guide</i>.</p>
-    async fn attach_instances(
+impl AutoscalingClient {
+    /// Calls `attach_instances`, also returning the metadata of the response.
+    pub async fn attach_instances_with_metadata(
         &self,
         input: AttachInstancesQuery,
-    ) -> Result<(), RusotoError<AttachInstancesError>> {
-        let mut request = SignedRequest::new("POST", "autoscaling", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "AttachInstances");
-        params.put("Version", "2011-01-01");
-        AttachInstancesQuerySerializer::serialize(&mut params, "", &input);
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+    ) -> Result<((), ResponseMetadata), RusotoError<AttachInstancesError>> {
+        let request = self.build_attach_instances_request(input);
 
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(AttachInstancesError::from_response(response));
         }
 
         std::mem::drop(response);
-        Ok(())
+        Ok(((), metadata))
     }
 
-    /// <p>Attaches one or more target groups to the specified Auto Scaling group.</p> <p>To describe the target groups for an Auto Scaling group, use <a>DescribeLoadBalancerTargetGroups</a>. To detach the target group from the Auto Scaling group, use <a>DetachLoadBalancerTargetGroups</a>.</p> <p>With Application Load Balancers and Network Load Balancers, instances are registered as targets with a target group. With Classic Load Balancers, instances are registered with the load balancer. For more information, see <a href="https://docs.aws.amazon.com/autoscaling/ec2/userguide/attach-load-balancer-asg.html">Attaching a Load Balancer to Your Auto Scaling Group</a> in the <i>Amazon EC2 Auto Scaling User Guide</i>.</p>
-    async fn attach_load_balancer_target_groups(
+    /// Presigns the request `attach_instances` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_attach_instances(
         &self,
-        input: AttachLoadBalancerTargetGroupsType,
-    ) -> Result<
-        AttachLoadBalancerTargetGroupsResultType,
-        RusotoError<AttachLoadBalancerTargetGroupsError>,
-    > {
+        presigner: &rusoto_core::Presigner,
+        input: AttachInstancesQuery,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_attach_instances_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_attach_instances_request(&self, input: AttachInstancesQuery) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "autoscaling", &self.region, "/");
+        request.set_operation("AttachInstances");
         let mut params = Params::new();
 
-        params.put("Action", "AttachLoadBalancerTargetGroups");
+        params.put("Action", "AttachInstances");
         params.put("Version", "2011-01-01");
-        AttachLoadBalancerTargetGroupsTypeSerializer::serialize(&mut params, "", &input);
+        AttachInstancesQuerySerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `attach_load_balancer_target_groups`, also returning the metadata of the response.
+    pub async fn attach_load_balancer_target_groups_with_metadata(
+        &self,
+        input: AttachLoadBalancerTargetGroupsType,
+    ) -> Result<
+        (AttachLoadBalancerTargetGroupsResultType, ResponseMetadata),
+        RusotoError<AttachLoadBalancerTargetGroupsError>,
+    > {
+        let request = self.build_attach_load_balancer_target_groups_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(AttachLoadBalancerTargetGroupsError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -10543,34 +11066,64 @@ impl Autoscaling for AutoscalingClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Attaches one or more Classic Load Balancers to the specified Auto Scaling group.</p> <p>To attach an Application Load Balancer or a Network Load Balancer instead, see <a>AttachLoadBalancerTargetGroups</a>.</p> <p>To describe the load balancers for an Auto Scaling group, use <a>DescribeLoadBalancers</a>. To detach the load balancer from the Auto Scaling group, use <a>DetachLoadBalancers</a>.</p> <p>For more information, see <a href="https://docs.aws.amazon.com/autoscaling/ec2/userguide/attach-load-balancer-asg.html">Attaching a Load Balancer to Your Auto Scaling Group</a> in the <i>Amazon EC2 Auto Scaling User Guide</i>.</p>
-    async fn attach_load_balancers(
+    /// Presigns the request `attach_load_balancer_target_groups` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_attach_load_balancer_target_groups(
         &self,
-        input: AttachLoadBalancersType,
-    ) -> Result<AttachLoadBalancersResultType, RusotoError<AttachLoadBalancersError>> {
+        presigner: &rusoto_core::Presigner,
+        input: AttachLoadBalancerTargetGroupsType,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_attach_load_balancer_target_groups_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_attach_load_balancer_target_groups_request(
+        &self,
+        input: AttachLoadBalancerTargetGroupsType,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "autoscaling", &self.region, "/");
+        request.set_operation("AttachLoadBalancerTargetGroups");
         let mut params = Params::new();
 
-        params.put("Action", "AttachLoadBalancers");
+        params.put("Action", "AttachLoadBalancerTargetGroups");
         params.put("Version", "2011-01-01");
-        AttachLoadBalancersTypeSerializer::serialize(&mut params, "", &input);
+        AttachLoadBalancerTargetGroupsTypeSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `attach_load_balancers`, also returning the metadata of the response.
+    pub async fn attach_load_balancers_with_metadata(
+        &self,
+        input: AttachLoadBalancersType,
+    ) -> Result<
+        (AttachLoadBalancersResultType, ResponseMetadata),
+        RusotoError<AttachLoadBalancersError>,
+    > {
+        let request = self.build_attach_load_balancers_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(AttachLoadBalancersError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -10592,35 +11145,61 @@ impl Autoscaling for AutoscalingClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Deletes one or more scheduled actions for the specified Auto Scaling group.</p>
-    async fn batch_delete_scheduled_action(
+    /// Presigns the request `attach_load_balancers` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_attach_load_balancers(
         &self,
-        input: BatchDeleteScheduledActionType,
-    ) -> Result<BatchDeleteScheduledActionAnswer, RusotoError<BatchDeleteScheduledActionError>>
-    {
+        presigner: &rusoto_core::Presigner,
+        input: AttachLoadBalancersType,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_attach_load_balancers_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_attach_load_balancers_request(&self, input: AttachLoadBalancersType) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "autoscaling", &self.region, "/");
+        request.set_operation("AttachLoadBalancers");
         let mut params = Params::new();
 
-        params.put("Action", "BatchDeleteScheduledAction");
+        params.put("Action", "AttachLoadBalancers");
         params.put("Version", "2011-01-01");
-        BatchDeleteScheduledActionTypeSerializer::serialize(&mut params, "", &input);
+        AttachLoadBalancersTypeSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `batch_delete_scheduled_action`, also returning the metadata of the response.
+    pub async fn batch_delete_scheduled_action_with_metadata(
+        &self,
+        input: BatchDeleteScheduledActionType,
+    ) -> Result<
+        (BatchDeleteScheduledActionAnswer, ResponseMetadata),
+        RusotoError<BatchDeleteScheduledActionError>,
+    > {
+        let request = self.build_batch_delete_scheduled_action_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(BatchDeleteScheduledActionError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -10642,39 +11221,66 @@ impl Autoscaling for AutoscalingClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Creates or updates one or more scheduled scaling actions for an Auto Scaling group. If you leave a parameter unspecified when updating a scheduled scaling action, the corresponding value remains unchanged.</p>
-    async fn batch_put_scheduled_update_group_action(
+    /// Presigns the request `batch_delete_scheduled_action` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_batch_delete_scheduled_action(
         &self,
-        input: BatchPutScheduledUpdateGroupActionType,
-    ) -> Result<
-        BatchPutScheduledUpdateGroupActionAnswer,
-        RusotoError<BatchPutScheduledUpdateGroupActionError>,
-    > {
+        presigner: &rusoto_core::Presigner,
+        input: BatchDeleteScheduledActionType,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_batch_delete_scheduled_action_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_batch_delete_scheduled_action_request(
+        &self,
+        input: BatchDeleteScheduledActionType,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "autoscaling", &self.region, "/");
+        request.set_operation("BatchDeleteScheduledAction");
         let mut params = Params::new();
 
-        params.put("Action", "BatchPutScheduledUpdateGroupAction");
+        params.put("Action", "BatchDeleteScheduledAction");
         params.put("Version", "2011-01-01");
-        BatchPutScheduledUpdateGroupActionTypeSerializer::serialize(&mut params, "", &input);
+        BatchDeleteScheduledActionTypeSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `batch_put_scheduled_update_group_action`, also returning the metadata of the response.
+    pub async fn batch_put_scheduled_update_group_action_with_metadata(
+        &self,
+        input: BatchPutScheduledUpdateGroupActionType,
+    ) -> Result<
+        (BatchPutScheduledUpdateGroupActionAnswer, ResponseMetadata),
+        RusotoError<BatchPutScheduledUpdateGroupActionError>,
+    > {
+        let request = self.build_batch_put_scheduled_update_group_action_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(BatchPutScheduledUpdateGroupActionError::from_response(
                 response,
             ));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -10696,34 +11302,64 @@ impl Autoscaling for AutoscalingClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Completes the lifecycle action for the specified token or instance with the specified result.</p> <p>This step is a part of the procedure for adding a lifecycle hook to an Auto Scaling group:</p> <ol> <li> <p>(Optional) Create a Lambda function and a rule that allows CloudWatch Events to invoke your Lambda function when Amazon EC2 Auto Scaling launches or terminates instances.</p> </li> <li> <p>(Optional) Create a notification target and an IAM role. The target can be either an Amazon SQS queue or an Amazon SNS topic. The role allows Amazon EC2 Auto Scaling to publish lifecycle notifications to the target.</p> </li> <li> <p>Create the lifecycle hook. Specify whether the hook is used when the instances launch or terminate.</p> </li> <li> <p>If you need more time, record the lifecycle action heartbeat to keep the instance in a pending state.</p> </li> <li> <p> <b>If you finish before the timeout period ends, complete the lifecycle action.</b> </p> </li> </ol> <p>For more information, see <a href="https://docs.aws.amazon.com/autoscaling/ec2/userguide/lifecycle-hooks.html">Amazon EC2 Auto Scaling Lifecycle Hooks</a> in the <i>Amazon EC2 Auto Scaling User Guide</i>.</p>
-    async fn complete_lifecycle_action(
+    /// Presigns the request `batch_put_scheduled_update_group_action` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_batch_put_scheduled_update_group_action(
         &self,
-        input: CompleteLifecycleActionType,
-    ) -> Result<CompleteLifecycleActionAnswer, RusotoError<CompleteLifecycleActionError>> {
+        presigner: &rusoto_core::Presigner,
+        input: BatchPutScheduledUpdateGroupActionType,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_batch_put_scheduled_update_group_action_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_batch_put_scheduled_update_group_action_request(
+        &self,
+        input: BatchPutScheduledUpdateGroupActionType,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "autoscaling", &self.region, "/");
+        request.set_operation("BatchPutScheduledUpdateGroupAction");
         let mut params = Params::new();
 
-        params.put("Action", "CompleteLifecycleAction");
+        params.put("Action", "BatchPutScheduledUpdateGroupAction");
         params.put("Version", "2011-01-01");
-        CompleteLifecycleActionTypeSerializer::serialize(&mut params, "", &input);
+        BatchPutScheduledUpdateGroupActionTypeSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `complete_lifecycle_action`, also returning the metadata of the response.
+    pub async fn complete_lifecycle_action_with_metadata(
+        &self,
+        input: CompleteLifecycleActionType,
+    ) -> Result<
+        (CompleteLifecycleActionAnswer, ResponseMetadata),
+        RusotoError<CompleteLifecycleActionError>,
+    > {
+        let request = self.build_complete_lifecycle_action_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(CompleteLifecycleActionError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -10745,174 +11381,319 @@ impl Autoscaling for AutoscalingClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Creates an Auto Scaling group with the specified name and attributes.</p> <p>If you exceed your maximum limit of Auto Scaling groups, the call fails. For information about viewing this limit, see <a>DescribeAccountLimits</a>. For information about updating this limit, see <a href="https://docs.aws.amazon.com/autoscaling/ec2/userguide/as-account-limits.html">Amazon EC2 Auto Scaling Limits</a> in the <i>Amazon EC2 Auto Scaling User Guide</i>.</p>
-    async fn create_auto_scaling_group(
+    /// Presigns the request `complete_lifecycle_action` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_complete_lifecycle_action(
         &self,
-        input: CreateAutoScalingGroupType,
-    ) -> Result<(), RusotoError<CreateAutoScalingGroupError>> {
-        let mut request = SignedRequest::new("POST", "autoscaling", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "CreateAutoScalingGroup");
+        presigner: &rusoto_core::Presigner,
+        input: CompleteLifecycleActionType,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_complete_lifecycle_action_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_complete_lifecycle_action_request(
+        &self,
+        input: CompleteLifecycleActionType,
+    ) -> SignedRequest {
+        let mut request = SignedRequest::new("POST", "autoscaling", &self.region, "/");
+        request.set_operation("CompleteLifecycleAction");
+        let mut params = Params::new();
+
+        params.put("Action", "CompleteLifecycleAction");
         params.put("Version", "2011-01-01");
-        CreateAutoScalingGroupTypeSerializer::serialize(&mut params, "", &input);
+        CompleteLifecycleActionTypeSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `create_auto_scaling_group`, also returning the metadata of the response.
+    pub async fn create_auto_scaling_group_with_metadata(
+        &self,
+        input: CreateAutoScalingGroupType,
+    ) -> Result<((), ResponseMetadata), RusotoError<CreateAutoScalingGroupError>> {
+        let request = self.build_create_auto_scaling_group_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(CreateAutoScalingGroupError::from_response(response));
         }
 
         std::mem::drop(response);
-        Ok(())
+        Ok(((), metadata))
     }
 
-    /// <p>Creates a launch configuration.</p> <p>If you exceed your maximum limit of launch configurations, the call fails. For information about viewing this limit, see <a>DescribeAccountLimits</a>. For information about updating this limit, see <a href="https://docs.aws.amazon.com/autoscaling/ec2/userguide/as-account-limits.html">Amazon EC2 Auto Scaling Limits</a> in the <i>Amazon EC2 Auto Scaling User Guide</i>.</p> <p>For more information, see <a href="https://docs.aws.amazon.com/autoscaling/ec2/userguide/LaunchConfiguration.html">Launch Configurations</a> in the <i>Amazon EC2 Auto Scaling User Guide</i>.</p>
-    async fn create_launch_configuration(
+    /// Presigns the request `create_auto_scaling_group` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_auto_scaling_group(
         &self,
-        input: CreateLaunchConfigurationType,
-    ) -> Result<(), RusotoError<CreateLaunchConfigurationError>> {
+        presigner: &rusoto_core::Presigner,
+        input: CreateAutoScalingGroupType,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_auto_scaling_group_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_auto_scaling_group_request(
+        &self,
+        input: CreateAutoScalingGroupType,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "autoscaling", &self.region, "/");
+        request.set_operation("CreateAutoScalingGroup");
         let mut params = Params::new();
 
-        params.put("Action", "CreateLaunchConfiguration");
+        params.put("Action", "CreateAutoScalingGroup");
         params.put("Version", "2011-01-01");
-        CreateLaunchConfigurationTypeSerializer::serialize(&mut params, "", &input);
+        CreateAutoScalingGroupTypeSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `create_launch_configuration`, also returning the metadata of the response.
+    pub async fn create_launch_configuration_with_metadata(
+        &self,
+        input: CreateLaunchConfigurationType,
+    ) -> Result<((), ResponseMetadata), RusotoError<CreateLaunchConfigurationError>> {
+        let request = self.build_create_launch_configuration_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(CreateLaunchConfigurationError::from_response(response));
         }
 
         std::mem::drop(response);
-        Ok(())
+        Ok(((), metadata))
     }
 
-    /// <p>Creates or updates tags for the specified Auto Scaling group.</p> <p>When you specify a tag with a key that already exists, the operation overwrites the previous tag definition, and you do not get an error message.</p> <p>For more information, see <a href="https://docs.aws.amazon.com/autoscaling/ec2/userguide/autoscaling-tagging.html">Tagging Auto Scaling Groups and Instances</a> in the <i>Amazon EC2 Auto Scaling User Guide</i>.</p>
-    async fn create_or_update_tags(
+    /// Presigns the request `create_launch_configuration` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_launch_configuration(
         &self,
-        input: CreateOrUpdateTagsType,
-    ) -> Result<(), RusotoError<CreateOrUpdateTagsError>> {
+        presigner: &rusoto_core::Presigner,
+        input: CreateLaunchConfigurationType,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_launch_configuration_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_launch_configuration_request(
+        &self,
+        input: CreateLaunchConfigurationType,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "autoscaling", &self.region, "/");
+        request.set_operation("CreateLaunchConfiguration");
         let mut params = Params::new();
 
-        params.put("Action", "CreateOrUpdateTags");
+        params.put("Action", "CreateLaunchConfiguration");
         params.put("Version", "2011-01-01");
-        CreateOrUpdateTagsTypeSerializer::serialize(&mut params, "", &input);
+        CreateLaunchConfigurationTypeSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `create_or_update_tags`, also returning the metadata of the response.
+    pub async fn create_or_update_tags_with_metadata(
+        &self,
+        input: CreateOrUpdateTagsType,
+    ) -> Result<((), ResponseMetadata), RusotoError<CreateOrUpdateTagsError>> {
+        let request = self.build_create_or_update_tags_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(CreateOrUpdateTagsError::from_response(response));
         }
 
         std::mem::drop(response);
-        Ok(())
+        Ok(((), metadata))
     }
 
-    /// <p>Deletes the specified Auto Scaling group.</p> <p>If the group has instances or scaling activities in progress, you must specify the option to force the deletion in order for it to succeed.</p> <p>If the group has policies, deleting the group deletes the policies, the underlying alarm actions, and any alarm that no longer has an associated action.</p> <p>To remove instances from the Auto Scaling group before deleting it, call <a>DetachInstances</a> with the list of instances and the option to decrement the desired capacity. This ensures that Amazon EC2 Auto Scaling does not launch replacement instances.</p> <p>To terminate all instances before deleting the Auto Scaling group, call <a>UpdateAutoScalingGroup</a> and set the minimum size and desired capacity of the Auto Scaling group to zero.</p>
-    async fn delete_auto_scaling_group(
+    /// Presigns the request `create_or_update_tags` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_or_update_tags(
         &self,
-        input: DeleteAutoScalingGroupType,
-    ) -> Result<(), RusotoError<DeleteAutoScalingGroupError>> {
+        presigner: &rusoto_core::Presigner,
+        input: CreateOrUpdateTagsType,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_or_update_tags_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_or_update_tags_request(&self, input: CreateOrUpdateTagsType) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "autoscaling", &self.region, "/");
+        request.set_operation("CreateOrUpdateTags");
         let mut params = Params::new();
 
-        params.put("Action", "DeleteAutoScalingGroup");
+        params.put("Action", "CreateOrUpdateTags");
         params.put("Version", "2011-01-01");
-        DeleteAutoScalingGroupTypeSerializer::serialize(&mut params, "", &input);
+        CreateOrUpdateTagsTypeSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `delete_auto_scaling_group`, also returning the metadata of the response.
+    pub async fn delete_auto_scaling_group_with_metadata(
+        &self,
+        input: DeleteAutoScalingGroupType,
+    ) -> Result<((), ResponseMetadata), RusotoError<DeleteAutoScalingGroupError>> {
+        let request = self.build_delete_auto_scaling_group_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(DeleteAutoScalingGroupError::from_response(response));
         }
 
         std::mem::drop(response);
-        Ok(())
+        Ok(((), metadata))
     }
 
-    /// <p>Deletes the specified launch configuration.</p> <p>The launch configuration must not be attached to an Auto Scaling group. When this call completes, the launch configuration is no longer available for use.</p>
-    async fn delete_launch_configuration(
+    /// Presigns the request `delete_auto_scaling_group` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_auto_scaling_group(
         &self,
-        input: LaunchConfigurationNameType,
-    ) -> Result<(), RusotoError<DeleteLaunchConfigurationError>> {
+        presigner: &rusoto_core::Presigner,
+        input: DeleteAutoScalingGroupType,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_auto_scaling_group_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_auto_scaling_group_request(
+        &self,
+        input: DeleteAutoScalingGroupType,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "autoscaling", &self.region, "/");
+        request.set_operation("DeleteAutoScalingGroup");
         let mut params = Params::new();
 
-        params.put("Action", "DeleteLaunchConfiguration");
+        params.put("Action", "DeleteAutoScalingGroup");
         params.put("Version", "2011-01-01");
-        LaunchConfigurationNameTypeSerializer::serialize(&mut params, "", &input);
+        DeleteAutoScalingGroupTypeSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `delete_launch_configuration`, also returning the metadata of the response.
+    pub async fn delete_launch_configuration_with_metadata(
+        &self,
+        input: LaunchConfigurationNameType,
+    ) -> Result<((), ResponseMetadata), RusotoError<DeleteLaunchConfigurationError>> {
+        let request = self.build_delete_launch_configuration_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(DeleteLaunchConfigurationError::from_response(response));
         }
 
         std::mem::drop(response);
-        Ok(())
+        Ok(((), metadata))
     }
 
-    /// <p>Deletes the specified lifecycle hook.</p> <p>If there are any outstanding lifecycle actions, they are completed first (<code>ABANDON</code> for launching instances, <code>CONTINUE</code> for terminating instances).</p>
-    async fn delete_lifecycle_hook(
+    /// Presigns the request `delete_launch_configuration` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_launch_configuration(
         &self,
-        input: DeleteLifecycleHookType,
-    ) -> Result<DeleteLifecycleHookAnswer, RusotoError<DeleteLifecycleHookError>> {
+        presigner: &rusoto_core::Presigner,
+        input: LaunchConfigurationNameType,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_launch_configuration_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_launch_configuration_request(
+        &self,
+        input: LaunchConfigurationNameType,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "autoscaling", &self.region, "/");
+        request.set_operation("DeleteLaunchConfiguration");
         let mut params = Params::new();
 
-        params.put("Action", "DeleteLifecycleHook");
+        params.put("Action", "DeleteLaunchConfiguration");
         params.put("Version", "2011-01-01");
-        DeleteLifecycleHookTypeSerializer::serialize(&mut params, "", &input);
+        LaunchConfigurationNameTypeSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `delete_lifecycle_hook`, also returning the metadata of the response.
+    pub async fn delete_lifecycle_hook_with_metadata(
+        &self,
+        input: DeleteLifecycleHookType,
+    ) -> Result<(DeleteLifecycleHookAnswer, ResponseMetadata), RusotoError<DeleteLifecycleHookError>>
+    {
+        let request = self.build_delete_lifecycle_hook_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(DeleteLifecycleHookError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -10934,144 +11715,264 @@ impl Autoscaling for AutoscalingClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Deletes the specified notification.</p>
-    async fn delete_notification_configuration(
+    /// Presigns the request `delete_lifecycle_hook` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_lifecycle_hook(
         &self,
-        input: DeleteNotificationConfigurationType,
-    ) -> Result<(), RusotoError<DeleteNotificationConfigurationError>> {
+        presigner: &rusoto_core::Presigner,
+        input: DeleteLifecycleHookType,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_lifecycle_hook_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_lifecycle_hook_request(&self, input: DeleteLifecycleHookType) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "autoscaling", &self.region, "/");
+        request.set_operation("DeleteLifecycleHook");
         let mut params = Params::new();
 
-        params.put("Action", "DeleteNotificationConfiguration");
+        params.put("Action", "DeleteLifecycleHook");
         params.put("Version", "2011-01-01");
-        DeleteNotificationConfigurationTypeSerializer::serialize(&mut params, "", &input);
+        DeleteLifecycleHookTypeSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `delete_notification_configuration`, also returning the metadata of the response.
+    pub async fn delete_notification_configuration_with_metadata(
+        &self,
+        input: DeleteNotificationConfigurationType,
+    ) -> Result<((), ResponseMetadata), RusotoError<DeleteNotificationConfigurationError>> {
+        let request = self.build_delete_notification_configuration_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(DeleteNotificationConfigurationError::from_response(
                 response,
             ));
         }
 
         std::mem::drop(response);
-        Ok(())
+        Ok(((), metadata))
     }
 
-    /// <p>Deletes the specified scaling policy.</p> <p>Deleting either a step scaling policy or a simple scaling policy deletes the underlying alarm action, but does not delete the alarm, even if it no longer has an associated action.</p> <p>For more information, see <a href="https://docs.aws.amazon.com/autoscaling/ec2/userguide/deleting-scaling-policy.html">Deleting a Scaling Policy</a> in the <i>Amazon EC2 Auto Scaling User Guide</i>.</p>
-    async fn delete_policy(
+    /// Presigns the request `delete_notification_configuration` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_notification_configuration(
         &self,
-        input: DeletePolicyType,
-    ) -> Result<(), RusotoError<DeletePolicyError>> {
+        presigner: &rusoto_core::Presigner,
+        input: DeleteNotificationConfigurationType,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_notification_configuration_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_notification_configuration_request(
+        &self,
+        input: DeleteNotificationConfigurationType,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "autoscaling", &self.region, "/");
+        request.set_operation("DeleteNotificationConfiguration");
         let mut params = Params::new();
 
-        params.put("Action", "DeletePolicy");
+        params.put("Action", "DeleteNotificationConfiguration");
         params.put("Version", "2011-01-01");
-        DeletePolicyTypeSerializer::serialize(&mut params, "", &input);
+        DeleteNotificationConfigurationTypeSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `delete_policy`, also returning the metadata of the response.
+    pub async fn delete_policy_with_metadata(
+        &self,
+        input: DeletePolicyType,
+    ) -> Result<((), ResponseMetadata), RusotoError<DeletePolicyError>> {
+        let request = self.build_delete_policy_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(DeletePolicyError::from_response(response));
         }
 
         std::mem::drop(response);
-        Ok(())
+        Ok(((), metadata))
     }
 
-    /// <p>Deletes the specified scheduled action.</p>
-    async fn delete_scheduled_action(
+    /// Presigns the request `delete_policy` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_policy(
         &self,
-        input: DeleteScheduledActionType,
-    ) -> Result<(), RusotoError<DeleteScheduledActionError>> {
+        presigner: &rusoto_core::Presigner,
+        input: DeletePolicyType,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_policy_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_policy_request(&self, input: DeletePolicyType) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "autoscaling", &self.region, "/");
+        request.set_operation("DeletePolicy");
         let mut params = Params::new();
 
-        params.put("Action", "DeleteScheduledAction");
+        params.put("Action", "DeletePolicy");
         params.put("Version", "2011-01-01");
-        DeleteScheduledActionTypeSerializer::serialize(&mut params, "", &input);
+        DeletePolicyTypeSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `delete_scheduled_action`, also returning the metadata of the response.
+    pub async fn delete_scheduled_action_with_metadata(
+        &self,
+        input: DeleteScheduledActionType,
+    ) -> Result<((), ResponseMetadata), RusotoError<DeleteScheduledActionError>> {
+        let request = self.build_delete_scheduled_action_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(DeleteScheduledActionError::from_response(response));
         }
 
         std::mem::drop(response);
-        Ok(())
+        Ok(((), metadata))
     }
 
-    /// <p>Deletes the specified tags.</p>
-    async fn delete_tags(&self, input: DeleteTagsType) -> Result<(), RusotoError<DeleteTagsError>> {
+    /// Presigns the request `delete_scheduled_action` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_scheduled_action(
+        &self,
+        presigner: &rusoto_core::Presigner,
+        input: DeleteScheduledActionType,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_scheduled_action_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_scheduled_action_request(
+        &self,
+        input: DeleteScheduledActionType,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "autoscaling", &self.region, "/");
+        request.set_operation("DeleteScheduledAction");
         let mut params = Params::new();
 
-        params.put("Action", "DeleteTags");
+        params.put("Action", "DeleteScheduledAction");
         params.put("Version", "2011-01-01");
-        DeleteTagsTypeSerializer::serialize(&mut params, "", &input);
+        DeleteScheduledActionTypeSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `delete_tags`, also returning the metadata of the response.
+    pub async fn delete_tags_with_metadata(
+        &self,
+        input: DeleteTagsType,
+    ) -> Result<((), ResponseMetadata), RusotoError<DeleteTagsError>> {
+        let request = self.build_delete_tags_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(DeleteTagsError::from_response(response));
         }
 
         std::mem::drop(response);
-        Ok(())
+        Ok(((), metadata))
     }
 
-    /// <p>Describes the current Amazon EC2 Auto Scaling resource limits for your AWS account.</p> <p>For information about requesting an increase in these limits, see <a href="https://docs.aws.amazon.com/autoscaling/ec2/userguide/as-account-limits.html">Amazon EC2 Auto Scaling Limits</a> in the <i>Amazon EC2 Auto Scaling User Guide</i>.</p>
-    async fn describe_account_limits(
+    /// Presigns the request `delete_tags` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_tags(
         &self,
-    ) -> Result<DescribeAccountLimitsAnswer, RusotoError<DescribeAccountLimitsError>> {
+        presigner: &rusoto_core::Presigner,
+        input: DeleteTagsType,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_tags_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_tags_request(&self, input: DeleteTagsType) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "autoscaling", &self.region, "/");
+        request.set_operation("DeleteTags");
         let mut params = Params::new();
 
-        params.put("Action", "DescribeAccountLimits");
+        params.put("Action", "DeleteTags");
         params.put("Version", "2011-01-01");
-
+        DeleteTagsTypeSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `describe_account_limits`, also returning the metadata of the response.
+    pub async fn describe_account_limits_with_metadata(
+        &self,
+    ) -> Result<
+        (DescribeAccountLimitsAnswer, ResponseMetadata),
+        RusotoError<DescribeAccountLimitsError>,
+    > {
+        let request = self.build_describe_account_limits_request();
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(DescribeAccountLimitsError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -11093,33 +11994,59 @@ impl Autoscaling for AutoscalingClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Describes the policy adjustment types for use with <a>PutScalingPolicy</a>.</p>
-    async fn describe_adjustment_types(
+    /// Presigns the request `describe_account_limits` would send with `presigner` instead of sending it.
+    pub async fn presign_describe_account_limits(
         &self,
-    ) -> Result<DescribeAdjustmentTypesAnswer, RusotoError<DescribeAdjustmentTypesError>> {
+        presigner: &rusoto_core::Presigner,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_account_limits_request();
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_account_limits_request(&self) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "autoscaling", &self.region, "/");
+        request.set_operation("DescribeAccountLimits");
         let mut params = Params::new();
 
-        params.put("Action", "DescribeAdjustmentTypes");
+        params.put("Action", "DescribeAccountLimits");
         params.put("Version", "2011-01-01");
 
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `describe_adjustment_types`, also returning the metadata of the response.
+    pub async fn describe_adjustment_types_with_metadata(
+        &self,
+    ) -> Result<
+        (DescribeAdjustmentTypesAnswer, ResponseMetadata),
+        RusotoError<DescribeAdjustmentTypesError>,
+    > {
+        let request = self.build_describe_adjustment_types_request();
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(DescribeAdjustmentTypesError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -11141,34 +12068,60 @@ impl Autoscaling for AutoscalingClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Describes one or more Auto Scaling groups.</p>
-    async fn describe_auto_scaling_groups(
+    /// Presigns the request `describe_adjustment_types` would send with `presigner` instead of sending it.
+    pub async fn presign_describe_adjustment_types(
         &self,
-        input: AutoScalingGroupNamesType,
-    ) -> Result<AutoScalingGroupsType, RusotoError<DescribeAutoScalingGroupsError>> {
+        presigner: &rusoto_core::Presigner,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_adjustment_types_request();
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_adjustment_types_request(&self) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "autoscaling", &self.region, "/");
+        request.set_operation("DescribeAdjustmentTypes");
         let mut params = Params::new();
 
-        params.put("Action", "DescribeAutoScalingGroups");
+        params.put("Action", "DescribeAdjustmentTypes");
         params.put("Version", "2011-01-01");
-        AutoScalingGroupNamesTypeSerializer::serialize(&mut params, "", &input);
+
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `describe_auto_scaling_groups`, also returning the metadata of the response.
+    pub async fn describe_auto_scaling_groups_with_metadata(
+        &self,
+        input: AutoScalingGroupNamesType,
+    ) -> Result<
+        (AutoScalingGroupsType, ResponseMetadata),
+        RusotoError<DescribeAutoScalingGroupsError>,
+    > {
+        let request = self.build_describe_auto_scaling_groups_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(DescribeAutoScalingGroupsError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -11190,34 +12143,64 @@ impl Autoscaling for AutoscalingClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Describes one or more Auto Scaling instances.</p>
-    async fn describe_auto_scaling_instances(
+    /// Presigns the request `describe_auto_scaling_groups` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_auto_scaling_groups(
         &self,
-        input: DescribeAutoScalingInstancesType,
-    ) -> Result<AutoScalingInstancesType, RusotoError<DescribeAutoScalingInstancesError>> {
+        presigner: &rusoto_core::Presigner,
+        input: AutoScalingGroupNamesType,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_auto_scaling_groups_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_auto_scaling_groups_request(
+        &self,
+        input: AutoScalingGroupNamesType,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "autoscaling", &self.region, "/");
+        request.set_operation("DescribeAutoScalingGroups");
         let mut params = Params::new();
 
-        params.put("Action", "DescribeAutoScalingInstances");
+        params.put("Action", "DescribeAutoScalingGroups");
         params.put("Version", "2011-01-01");
-        DescribeAutoScalingInstancesTypeSerializer::serialize(&mut params, "", &input);
+        AutoScalingGroupNamesTypeSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `describe_auto_scaling_instances`, also returning the metadata of the response.
+    pub async fn describe_auto_scaling_instances_with_metadata(
+        &self,
+        input: DescribeAutoScalingInstancesType,
+    ) -> Result<
+        (AutoScalingInstancesType, ResponseMetadata),
+        RusotoError<DescribeAutoScalingInstancesError>,
+    > {
+        let request = self.build_describe_auto_scaling_instances_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(DescribeAutoScalingInstancesError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -11239,38 +12222,65 @@ impl Autoscaling for AutoscalingClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Describes the notification types that are supported by Amazon EC2 Auto Scaling.</p>
-    async fn describe_auto_scaling_notification_types(
+    /// Presigns the request `describe_auto_scaling_instances` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_auto_scaling_instances(
         &self,
-    ) -> Result<
-        DescribeAutoScalingNotificationTypesAnswer,
-        RusotoError<DescribeAutoScalingNotificationTypesError>,
-    > {
-        let mut request = SignedRequest::new("POST", "autoscaling", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "DescribeAutoScalingNotificationTypes");
-        params.put("Version", "2011-01-01");
+        presigner: &rusoto_core::Presigner,
+        input: DescribeAutoScalingInstancesType,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_auto_scaling_instances_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_auto_scaling_instances_request(
+        &self,
+        input: DescribeAutoScalingInstancesType,
+    ) -> SignedRequest {
+        let mut request = SignedRequest::new("POST", "autoscaling", &self.region, "/");
+        request.set_operation("DescribeAutoScalingInstances");
+        let mut params = Params::new();
 
+        params.put("Action", "DescribeAutoScalingInstances");
+        params.put("Version", "2011-01-01");
+        DescribeAutoScalingInstancesTypeSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `describe_auto_scaling_notification_types`, also returning the metadata of the response.
+    pub async fn describe_auto_scaling_notification_types_with_metadata(
+        &self,
+    ) -> Result<
+        (DescribeAutoScalingNotificationTypesAnswer, ResponseMetadata),
+        RusotoError<DescribeAutoScalingNotificationTypesError>,
+    > {
+        let request = self.build_describe_auto_scaling_notification_types_request();
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(DescribeAutoScalingNotificationTypesError::from_response(
                 response,
             ));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -11292,34 +12302,60 @@ impl Autoscaling for AutoscalingClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Describes one or more launch configurations.</p>
-    async fn describe_launch_configurations(
+    /// Presigns the request `describe_auto_scaling_notification_types` would send with `presigner` instead of sending it.
+    pub async fn presign_describe_auto_scaling_notification_types(
         &self,
-        input: LaunchConfigurationNamesType,
-    ) -> Result<LaunchConfigurationsType, RusotoError<DescribeLaunchConfigurationsError>> {
+        presigner: &rusoto_core::Presigner,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_auto_scaling_notification_types_request();
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_auto_scaling_notification_types_request(&self) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "autoscaling", &self.region, "/");
+        request.set_operation("DescribeAutoScalingNotificationTypes");
         let mut params = Params::new();
 
-        params.put("Action", "DescribeLaunchConfigurations");
+        params.put("Action", "DescribeAutoScalingNotificationTypes");
         params.put("Version", "2011-01-01");
-        LaunchConfigurationNamesTypeSerializer::serialize(&mut params, "", &input);
+
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `describe_launch_configurations`, also returning the metadata of the response.
+    pub async fn describe_launch_configurations_with_metadata(
+        &self,
+        input: LaunchConfigurationNamesType,
+    ) -> Result<
+        (LaunchConfigurationsType, ResponseMetadata),
+        RusotoError<DescribeLaunchConfigurationsError>,
+    > {
+        let request = self.build_describe_launch_configurations_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(DescribeLaunchConfigurationsError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -11341,34 +12377,63 @@ impl Autoscaling for AutoscalingClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p><p>Describes the available types of lifecycle hooks.</p> <p>The following hook types are supported:</p> <ul> <li> <p>autoscaling:EC2<em>INSTANCE</em>LAUNCHING</p> </li> <li> <p>autoscaling:EC2<em>INSTANCE</em>TERMINATING</p> </li> </ul></p>
-    async fn describe_lifecycle_hook_types(
+    /// Presigns the request `describe_launch_configurations` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_launch_configurations(
         &self,
-    ) -> Result<DescribeLifecycleHookTypesAnswer, RusotoError<DescribeLifecycleHookTypesError>>
-    {
+        presigner: &rusoto_core::Presigner,
+        input: LaunchConfigurationNamesType,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_launch_configurations_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_launch_configurations_request(
+        &self,
+        input: LaunchConfigurationNamesType,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "autoscaling", &self.region, "/");
+        request.set_operation("DescribeLaunchConfigurations");
         let mut params = Params::new();
 
-        params.put("Action", "DescribeLifecycleHookTypes");
+        params.put("Action", "DescribeLaunchConfigurations");
         params.put("Version", "2011-01-01");
-
+        LaunchConfigurationNamesTypeSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `describe_lifecycle_hook_types`, also returning the metadata of the response.
+    pub async fn describe_lifecycle_hook_types_with_metadata(
+        &self,
+    ) -> Result<
+        (DescribeLifecycleHookTypesAnswer, ResponseMetadata),
+        RusotoError<DescribeLifecycleHookTypesError>,
+    > {
+        let request = self.build_describe_lifecycle_hook_types_request();
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(DescribeLifecycleHookTypesError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -11390,34 +12455,60 @@ impl Autoscaling for AutoscalingClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Describes the lifecycle hooks for the specified Auto Scaling group.</p>
-    async fn describe_lifecycle_hooks(
+    /// Presigns the request `describe_lifecycle_hook_types` would send with `presigner` instead of sending it.
+    pub async fn presign_describe_lifecycle_hook_types(
         &self,
-        input: DescribeLifecycleHooksType,
-    ) -> Result<DescribeLifecycleHooksAnswer, RusotoError<DescribeLifecycleHooksError>> {
+        presigner: &rusoto_core::Presigner,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_lifecycle_hook_types_request();
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_lifecycle_hook_types_request(&self) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "autoscaling", &self.region, "/");
+        request.set_operation("DescribeLifecycleHookTypes");
         let mut params = Params::new();
 
-        params.put("Action", "DescribeLifecycleHooks");
+        params.put("Action", "DescribeLifecycleHookTypes");
         params.put("Version", "2011-01-01");
-        DescribeLifecycleHooksTypeSerializer::serialize(&mut params, "", &input);
+
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `describe_lifecycle_hooks`, also returning the metadata of the response.
+    pub async fn describe_lifecycle_hooks_with_metadata(
+        &self,
+        input: DescribeLifecycleHooksType,
+    ) -> Result<
+        (DescribeLifecycleHooksAnswer, ResponseMetadata),
+        RusotoError<DescribeLifecycleHooksError>,
+    > {
+        let request = self.build_describe_lifecycle_hooks_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(DescribeLifecycleHooksError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -11439,39 +12530,66 @@ impl Autoscaling for AutoscalingClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Describes the target groups for the specified Auto Scaling group.</p>
-    async fn describe_load_balancer_target_groups(
+    /// Presigns the request `describe_lifecycle_hooks` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_lifecycle_hooks(
         &self,
-        input: DescribeLoadBalancerTargetGroupsRequest,
-    ) -> Result<
-        DescribeLoadBalancerTargetGroupsResponse,
-        RusotoError<DescribeLoadBalancerTargetGroupsError>,
-    > {
+        presigner: &rusoto_core::Presigner,
+        input: DescribeLifecycleHooksType,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_lifecycle_hooks_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_lifecycle_hooks_request(
+        &self,
+        input: DescribeLifecycleHooksType,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "autoscaling", &self.region, "/");
+        request.set_operation("DescribeLifecycleHooks");
         let mut params = Params::new();
 
-        params.put("Action", "DescribeLoadBalancerTargetGroups");
+        params.put("Action", "DescribeLifecycleHooks");
         params.put("Version", "2011-01-01");
-        DescribeLoadBalancerTargetGroupsRequestSerializer::serialize(&mut params, "", &input);
+        DescribeLifecycleHooksTypeSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `describe_load_balancer_target_groups`, also returning the metadata of the response.
+    pub async fn describe_load_balancer_target_groups_with_metadata(
+        &self,
+        input: DescribeLoadBalancerTargetGroupsRequest,
+    ) -> Result<
+        (DescribeLoadBalancerTargetGroupsResponse, ResponseMetadata),
+        RusotoError<DescribeLoadBalancerTargetGroupsError>,
+    > {
+        let request = self.build_describe_load_balancer_target_groups_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(DescribeLoadBalancerTargetGroupsError::from_response(
                 response,
             ));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -11493,34 +12611,64 @@ impl Autoscaling for AutoscalingClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Describes the load balancers for the specified Auto Scaling group.</p> <p>This operation describes only Classic Load Balancers. If you have Application Load Balancers or Network Load Balancers, use <a>DescribeLoadBalancerTargetGroups</a> instead.</p>
-    async fn describe_load_balancers(
+    /// Presigns the request `describe_load_balancer_target_groups` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_load_balancer_target_groups(
         &self,
-        input: DescribeLoadBalancersRequest,
-    ) -> Result<DescribeLoadBalancersResponse, RusotoError<DescribeLoadBalancersError>> {
+        presigner: &rusoto_core::Presigner,
+        input: DescribeLoadBalancerTargetGroupsRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_load_balancer_target_groups_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_load_balancer_target_groups_request(
+        &self,
+        input: DescribeLoadBalancerTargetGroupsRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "autoscaling", &self.region, "/");
+        request.set_operation("DescribeLoadBalancerTargetGroups");
         let mut params = Params::new();
 
-        params.put("Action", "DescribeLoadBalancers");
+        params.put("Action", "DescribeLoadBalancerTargetGroups");
         params.put("Version", "2011-01-01");
-        DescribeLoadBalancersRequestSerializer::serialize(&mut params, "", &input);
+        DescribeLoadBalancerTargetGroupsRequestSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `describe_load_balancers`, also returning the metadata of the response.
+    pub async fn describe_load_balancers_with_metadata(
+        &self,
+        input: DescribeLoadBalancersRequest,
+    ) -> Result<
+        (DescribeLoadBalancersResponse, ResponseMetadata),
+        RusotoError<DescribeLoadBalancersError>,
+    > {
+        let request = self.build_describe_load_balancers_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(DescribeLoadBalancersError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -11542,34 +12690,63 @@ impl Autoscaling for AutoscalingClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Describes the available CloudWatch metrics for Amazon EC2 Auto Scaling.</p> <p>The <code>GroupStandbyInstances</code> metric is not returned by default. You must explicitly request this metric when calling <a>EnableMetricsCollection</a>.</p>
-    async fn describe_metric_collection_types(
+    /// Presigns the request `describe_load_balancers` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_load_balancers(
         &self,
-    ) -> Result<DescribeMetricCollectionTypesAnswer, RusotoError<DescribeMetricCollectionTypesError>>
-    {
+        presigner: &rusoto_core::Presigner,
+        input: DescribeLoadBalancersRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_load_balancers_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_load_balancers_request(
+        &self,
+        input: DescribeLoadBalancersRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "autoscaling", &self.region, "/");
+        request.set_operation("DescribeLoadBalancers");
         let mut params = Params::new();
 
-        params.put("Action", "DescribeMetricCollectionTypes");
+        params.put("Action", "DescribeLoadBalancers");
         params.put("Version", "2011-01-01");
-
+        DescribeLoadBalancersRequestSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `describe_metric_collection_types`, also returning the metadata of the response.
+    pub async fn describe_metric_collection_types_with_metadata(
+        &self,
+    ) -> Result<
+        (DescribeMetricCollectionTypesAnswer, ResponseMetadata),
+        RusotoError<DescribeMetricCollectionTypesError>,
+    > {
+        let request = self.build_describe_metric_collection_types_request();
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(DescribeMetricCollectionTypesError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -11591,39 +12768,62 @@ impl Autoscaling for AutoscalingClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Describes the notification actions associated with the specified Auto Scaling group.</p>
-    async fn describe_notification_configurations(
+    /// Presigns the request `describe_metric_collection_types` would send with `presigner` instead of sending it.
+    pub async fn presign_describe_metric_collection_types(
         &self,
-        input: DescribeNotificationConfigurationsType,
-    ) -> Result<
-        DescribeNotificationConfigurationsAnswer,
-        RusotoError<DescribeNotificationConfigurationsError>,
-    > {
+        presigner: &rusoto_core::Presigner,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_metric_collection_types_request();
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_metric_collection_types_request(&self) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "autoscaling", &self.region, "/");
+        request.set_operation("DescribeMetricCollectionTypes");
         let mut params = Params::new();
 
-        params.put("Action", "DescribeNotificationConfigurations");
+        params.put("Action", "DescribeMetricCollectionTypes");
         params.put("Version", "2011-01-01");
-        DescribeNotificationConfigurationsTypeSerializer::serialize(&mut params, "", &input);
+
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `describe_notification_configurations`, also returning the metadata of the response.
+    pub async fn describe_notification_configurations_with_metadata(
+        &self,
+        input: DescribeNotificationConfigurationsType,
+    ) -> Result<
+        (DescribeNotificationConfigurationsAnswer, ResponseMetadata),
+        RusotoError<DescribeNotificationConfigurationsError>,
+    > {
+        let request = self.build_describe_notification_configurations_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(DescribeNotificationConfigurationsError::from_response(
                 response,
             ));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -11645,34 +12845,61 @@ impl Autoscaling for AutoscalingClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Describes the policies for the specified Auto Scaling group.</p>
-    async fn describe_policies(
+    /// Presigns the request `describe_notification_configurations` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_notification_configurations(
         &self,
-        input: DescribePoliciesType,
-    ) -> Result<PoliciesType, RusotoError<DescribePoliciesError>> {
+        presigner: &rusoto_core::Presigner,
+        input: DescribeNotificationConfigurationsType,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_notification_configurations_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_notification_configurations_request(
+        &self,
+        input: DescribeNotificationConfigurationsType,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "autoscaling", &self.region, "/");
+        request.set_operation("DescribeNotificationConfigurations");
         let mut params = Params::new();
 
-        params.put("Action", "DescribePolicies");
+        params.put("Action", "DescribeNotificationConfigurations");
         params.put("Version", "2011-01-01");
-        DescribePoliciesTypeSerializer::serialize(&mut params, "", &input);
+        DescribeNotificationConfigurationsTypeSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `describe_policies`, also returning the metadata of the response.
+    pub async fn describe_policies_with_metadata(
+        &self,
+        input: DescribePoliciesType,
+    ) -> Result<(PoliciesType, ResponseMetadata), RusotoError<DescribePoliciesError>> {
+        let request = self.build_describe_policies_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(DescribePoliciesError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -11691,34 +12918,59 @@ impl Autoscaling for AutoscalingClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Describes one or more scaling activities for the specified Auto Scaling group.</p>
-    async fn describe_scaling_activities(
+    /// Presigns the request `describe_policies` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_policies(
         &self,
-        input: DescribeScalingActivitiesType,
-    ) -> Result<ActivitiesType, RusotoError<DescribeScalingActivitiesError>> {
+        presigner: &rusoto_core::Presigner,
+        input: DescribePoliciesType,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_policies_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_policies_request(&self, input: DescribePoliciesType) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "autoscaling", &self.region, "/");
+        request.set_operation("DescribePolicies");
         let mut params = Params::new();
 
-        params.put("Action", "DescribeScalingActivities");
+        params.put("Action", "DescribePolicies");
         params.put("Version", "2011-01-01");
-        DescribeScalingActivitiesTypeSerializer::serialize(&mut params, "", &input);
+        DescribePoliciesTypeSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `describe_scaling_activities`, also returning the metadata of the response.
+    pub async fn describe_scaling_activities_with_metadata(
+        &self,
+        input: DescribeScalingActivitiesType,
+    ) -> Result<(ActivitiesType, ResponseMetadata), RusotoError<DescribeScalingActivitiesError>>
+    {
+        let request = self.build_describe_scaling_activities_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(DescribeScalingActivitiesError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -11740,33 +12992,61 @@ impl Autoscaling for AutoscalingClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Describes the scaling process types for use with <a>ResumeProcesses</a> and <a>SuspendProcesses</a>.</p>
-    async fn describe_scaling_process_types(
+    /// Presigns the request `describe_scaling_activities` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_scaling_activities(
         &self,
-    ) -> Result<ProcessesType, RusotoError<DescribeScalingProcessTypesError>> {
+        presigner: &rusoto_core::Presigner,
+        input: DescribeScalingActivitiesType,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_scaling_activities_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_scaling_activities_request(
+        &self,
+        input: DescribeScalingActivitiesType,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "autoscaling", &self.region, "/");
+        request.set_operation("DescribeScalingActivities");
         let mut params = Params::new();
 
-        params.put("Action", "DescribeScalingProcessTypes");
+        params.put("Action", "DescribeScalingActivities");
         params.put("Version", "2011-01-01");
-
+        DescribeScalingActivitiesTypeSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `describe_scaling_process_types`, also returning the metadata of the response.
+    pub async fn describe_scaling_process_types_with_metadata(
+        &self,
+    ) -> Result<(ProcessesType, ResponseMetadata), RusotoError<DescribeScalingProcessTypesError>>
+    {
+        let request = self.build_describe_scaling_process_types_request();
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(DescribeScalingProcessTypesError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -11788,34 +13068,58 @@ impl Autoscaling for AutoscalingClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Describes the actions scheduled for your Auto Scaling group that haven't run or that have not reached their end time. To describe the actions that have already run, use <a>DescribeScalingActivities</a>.</p>
-    async fn describe_scheduled_actions(
+    /// Presigns the request `describe_scaling_process_types` would send with `presigner` instead of sending it.
+    pub async fn presign_describe_scaling_process_types(
         &self,
-        input: DescribeScheduledActionsType,
-    ) -> Result<ScheduledActionsType, RusotoError<DescribeScheduledActionsError>> {
+        presigner: &rusoto_core::Presigner,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_scaling_process_types_request();
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_scaling_process_types_request(&self) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "autoscaling", &self.region, "/");
+        request.set_operation("DescribeScalingProcessTypes");
         let mut params = Params::new();
 
-        params.put("Action", "DescribeScheduledActions");
+        params.put("Action", "DescribeScalingProcessTypes");
         params.put("Version", "2011-01-01");
-        DescribeScheduledActionsTypeSerializer::serialize(&mut params, "", &input);
+
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `describe_scheduled_actions`, also returning the metadata of the response.
+    pub async fn describe_scheduled_actions_with_metadata(
+        &self,
+        input: DescribeScheduledActionsType,
+    ) -> Result<(ScheduledActionsType, ResponseMetadata), RusotoError<DescribeScheduledActionsError>>
+    {
+        let request = self.build_describe_scheduled_actions_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(DescribeScheduledActionsError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -11837,34 +13141,61 @@ impl Autoscaling for AutoscalingClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Describes the specified tags.</p> <p>You can use filters to limit the results. For example, you can query for the tags for a specific Auto Scaling group. You can specify multiple values for a filter. A tag must match at least one of the specified values for it to be included in the results.</p> <p>You can also specify multiple filters. The result includes information for a particular tag only if it matches all the filters. If there's no match, no special message is returned.</p>
-    async fn describe_tags(
+    /// Presigns the request `describe_scheduled_actions` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_scheduled_actions(
         &self,
-        input: DescribeTagsType,
-    ) -> Result<TagsType, RusotoError<DescribeTagsError>> {
-        let mut request = SignedRequest::new("POST", "autoscaling", &self.region, "/");
-        let mut params = Params::new();
+        presigner: &rusoto_core::Presigner,
+        input: DescribeScheduledActionsType,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_scheduled_actions_request(input);
+        self.client.presign(presigner, request).await
+    }
 
-        params.put("Action", "DescribeTags");
+    fn build_describe_scheduled_actions_request(
+        &self,
+        input: DescribeScheduledActionsType,
+    ) -> SignedRequest {
+        let mut request = SignedRequest::new("POST", "autoscaling", &self.region, "/");
+        request.set_operation("DescribeScheduledActions");
+        let mut params = Params::new();
+
+        params.put("Action", "DescribeScheduledActions");
         params.put("Version", "2011-01-01");
-        DescribeTagsTypeSerializer::serialize(&mut params, "", &input);
+        DescribeScheduledActionsTypeSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `describe_tags`, also returning the metadata of the response.
+    pub async fn describe_tags_with_metadata(
+        &self,
+        input: DescribeTagsType,
+    ) -> Result<(TagsType, ResponseMetadata), RusotoError<DescribeTagsError>> {
+        let request = self.build_describe_tags_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(DescribeTagsError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -11883,36 +13214,60 @@ impl Autoscaling for AutoscalingClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Describes the termination policies supported by Amazon EC2 Auto Scaling.</p> <p>For more information, see <a href="https://docs.aws.amazon.com/autoscaling/ec2/userguide/as-instance-termination.html">Controlling Which Auto Scaling Instances Terminate During Scale In</a> in the <i>Amazon EC2 Auto Scaling User Guide</i>.</p>
-    async fn describe_termination_policy_types(
+    /// Presigns the request `describe_tags` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_tags(
         &self,
-    ) -> Result<
-        DescribeTerminationPolicyTypesAnswer,
-        RusotoError<DescribeTerminationPolicyTypesError>,
-    > {
+        presigner: &rusoto_core::Presigner,
+        input: DescribeTagsType,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_tags_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_tags_request(&self, input: DescribeTagsType) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "autoscaling", &self.region, "/");
+        request.set_operation("DescribeTags");
         let mut params = Params::new();
 
-        params.put("Action", "DescribeTerminationPolicyTypes");
+        params.put("Action", "DescribeTags");
         params.put("Version", "2011-01-01");
-
+        DescribeTagsTypeSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `describe_termination_policy_types`, also returning the metadata of the response.
+    pub async fn describe_termination_policy_types_with_metadata(
+        &self,
+    ) -> Result<
+        (DescribeTerminationPolicyTypesAnswer, ResponseMetadata),
+        RusotoError<DescribeTerminationPolicyTypesError>,
+    > {
+        let request = self.build_describe_termination_policy_types_request();
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(DescribeTerminationPolicyTypesError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -11934,34 +13289,57 @@ impl Autoscaling for AutoscalingClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Removes one or more instances from the specified Auto Scaling group.</p> <p>After the instances are detached, you can manage them independent of the Auto Scaling group.</p> <p>If you do not specify the option to decrement the desired capacity, Amazon EC2 Auto Scaling launches instances to replace the ones that are detached.</p> <p>If there is a Classic Load Balancer attached to the Auto Scaling group, the instances are deregistered from the load balancer. If there are target groups attached to the Auto Scaling group, the instances are deregistered from the target groups.</p> <p>For more information, see <a href="https://docs.aws.amazon.com/autoscaling/ec2/userguide/detach-instance-asg.html">Detach EC2 Instances from Your Auto Scaling Group</a> in the <i>Amazon EC2 Auto Scaling User Guide</i>.</p>
-    async fn detach_instances(
+    /// Presigns the request `describe_termination_policy_types` would send with `presigner` instead of sending it.
+    pub async fn presign_describe_termination_policy_types(
         &self,
-        input: DetachInstancesQuery,
-    ) -> Result<DetachInstancesAnswer, RusotoError<DetachInstancesError>> {
+        presigner: &rusoto_core::Presigner,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_termination_policy_types_request();
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_termination_policy_types_request(&self) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "autoscaling", &self.region, "/");
+        request.set_operation("DescribeTerminationPolicyTypes");
         let mut params = Params::new();
 
-        params.put("Action", "DetachInstances");
+        params.put("Action", "DescribeTerminationPolicyTypes");
         params.put("Version", "2011-01-01");
-        DetachInstancesQuerySerializer::serialize(&mut params, "", &input);
+
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `detach_instances`, also returning the metadata of the response.
+    pub async fn detach_instances_with_metadata(
+        &self,
+        input: DetachInstancesQuery,
+    ) -> Result<(DetachInstancesAnswer, ResponseMetadata), RusotoError<DetachInstancesError>> {
+        let request = self.build_detach_instances_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(DetachInstancesError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -11983,37 +13361,61 @@ impl Autoscaling for AutoscalingClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Detaches one or more target groups from the specified Auto Scaling group.</p>
-    async fn detach_load_balancer_target_groups(
+    /// Presigns the request `detach_instances` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_detach_instances(
         &self,
-        input: DetachLoadBalancerTargetGroupsType,
-    ) -> Result<
-        DetachLoadBalancerTargetGroupsResultType,
-        RusotoError<DetachLoadBalancerTargetGroupsError>,
-    > {
+        presigner: &rusoto_core::Presigner,
+        input: DetachInstancesQuery,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_detach_instances_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_detach_instances_request(&self, input: DetachInstancesQuery) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "autoscaling", &self.region, "/");
+        request.set_operation("DetachInstances");
         let mut params = Params::new();
 
-        params.put("Action", "DetachLoadBalancerTargetGroups");
+        params.put("Action", "DetachInstances");
         params.put("Version", "2011-01-01");
-        DetachLoadBalancerTargetGroupsTypeSerializer::serialize(&mut params, "", &input);
+        DetachInstancesQuerySerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `detach_load_balancer_target_groups`, also returning the metadata of the response.
+    pub async fn detach_load_balancer_target_groups_with_metadata(
+        &self,
+        input: DetachLoadBalancerTargetGroupsType,
+    ) -> Result<
+        (DetachLoadBalancerTargetGroupsResultType, ResponseMetadata),
+        RusotoError<DetachLoadBalancerTargetGroupsError>,
+    > {
+        let request = self.build_detach_load_balancer_target_groups_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(DetachLoadBalancerTargetGroupsError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -12035,34 +13437,64 @@ impl Autoscaling for AutoscalingClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Detaches one or more Classic Load Balancers from the specified Auto Scaling group.</p> <p>This operation detaches only Classic Load Balancers. If you have Application Load Balancers or Network Load Balancers, use <a>DetachLoadBalancerTargetGroups</a> instead.</p> <p>When you detach a load balancer, it enters the <code>Removing</code> state while deregistering the instances in the group. When all instances are deregistered, then you can no longer describe the load balancer using <a>DescribeLoadBalancers</a>. The instances remain running.</p>
-    async fn detach_load_balancers(
+    /// Presigns the request `detach_load_balancer_target_groups` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_detach_load_balancer_target_groups(
         &self,
-        input: DetachLoadBalancersType,
-    ) -> Result<DetachLoadBalancersResultType, RusotoError<DetachLoadBalancersError>> {
+        presigner: &rusoto_core::Presigner,
+        input: DetachLoadBalancerTargetGroupsType,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_detach_load_balancer_target_groups_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_detach_load_balancer_target_groups_request(
+        &self,
+        input: DetachLoadBalancerTargetGroupsType,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "autoscaling", &self.region, "/");
+        request.set_operation("DetachLoadBalancerTargetGroups");
         let mut params = Params::new();
 
-        params.put("Action", "DetachLoadBalancers");
+        params.put("Action", "DetachLoadBalancerTargetGroups");
         params.put("Version", "2011-01-01");
-        DetachLoadBalancersTypeSerializer::serialize(&mut params, "", &input);
+        DetachLoadBalancerTargetGroupsTypeSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `detach_load_balancers`, also returning the metadata of the response.
+    pub async fn detach_load_balancers_with_metadata(
+        &self,
+        input: DetachLoadBalancersType,
+    ) -> Result<
+        (DetachLoadBalancersResultType, ResponseMetadata),
+        RusotoError<DetachLoadBalancersError>,
+    > {
+        let request = self.build_detach_load_balancers_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(DetachLoadBalancersError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -12084,90 +13516,162 @@ impl Autoscaling for AutoscalingClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Disables group metrics for the specified Auto Scaling group.</p>
-    async fn disable_metrics_collection(
+    /// Presigns the request `detach_load_balancers` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_detach_load_balancers(
         &self,
-        input: DisableMetricsCollectionQuery,
-    ) -> Result<(), RusotoError<DisableMetricsCollectionError>> {
+        presigner: &rusoto_core::Presigner,
+        input: DetachLoadBalancersType,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_detach_load_balancers_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_detach_load_balancers_request(&self, input: DetachLoadBalancersType) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "autoscaling", &self.region, "/");
+        request.set_operation("DetachLoadBalancers");
         let mut params = Params::new();
 
-        params.put("Action", "DisableMetricsCollection");
+        params.put("Action", "DetachLoadBalancers");
         params.put("Version", "2011-01-01");
-        DisableMetricsCollectionQuerySerializer::serialize(&mut params, "", &input);
+        DetachLoadBalancersTypeSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `disable_metrics_collection`, also returning the metadata of the response.
+    pub async fn disable_metrics_collection_with_metadata(
+        &self,
+        input: DisableMetricsCollectionQuery,
+    ) -> Result<((), ResponseMetadata), RusotoError<DisableMetricsCollectionError>> {
+        let request = self.build_disable_metrics_collection_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(DisableMetricsCollectionError::from_response(response));
         }
 
         std::mem::drop(response);
-        Ok(())
+        Ok(((), metadata))
     }
 
-    /// <p>Enables group metrics for the specified Auto Scaling group. For more information, see <a href="https://docs.aws.amazon.com/autoscaling/ec2/userguide/as-instance-monitoring.html">Monitoring Your Auto Scaling Groups and Instances</a> in the <i>Amazon EC2 Auto Scaling User Guide</i>.</p>
-    async fn enable_metrics_collection(
+    /// Presigns the request `disable_metrics_collection` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_disable_metrics_collection(
         &self,
-        input: EnableMetricsCollectionQuery,
-    ) -> Result<(), RusotoError<EnableMetricsCollectionError>> {
+        presigner: &rusoto_core::Presigner,
+        input: DisableMetricsCollectionQuery,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_disable_metrics_collection_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_disable_metrics_collection_request(
+        &self,
+        input: DisableMetricsCollectionQuery,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "autoscaling", &self.region, "/");
+        request.set_operation("DisableMetricsCollection");
         let mut params = Params::new();
 
-        params.put("Action", "EnableMetricsCollection");
+        params.put("Action", "DisableMetricsCollection");
         params.put("Version", "2011-01-01");
-        EnableMetricsCollectionQuerySerializer::serialize(&mut params, "", &input);
+        DisableMetricsCollectionQuerySerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `enable_metrics_collection`, also returning the metadata of the response.
+    pub async fn enable_metrics_collection_with_metadata(
+        &self,
+        input: EnableMetricsCollectionQuery,
+    ) -> Result<((), ResponseMetadata), RusotoError<EnableMetricsCollectionError>> {
+        let request = self.build_enable_metrics_collection_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(EnableMetricsCollectionError::from_response(response));
         }
 
         std::mem::drop(response);
-        Ok(())
+        Ok(((), metadata))
     }
 
-    /// <p>Moves the specified instances into the standby state.</p> <p>If you choose to decrement the desired capacity of the Auto Scaling group, the instances can enter standby as long as the desired capacity of the Auto Scaling group after the instances are placed into standby is equal to or greater than the minimum capacity of the group.</p> <p>If you choose not to decrement the desired capacity of the Auto Scaling group, the Auto Scaling group launches new instances to replace the instances on standby.</p> <p>For more information, see <a href="https://docs.aws.amazon.com/autoscaling/ec2/userguide/as-enter-exit-standby.html">Temporarily Removing Instances from Your Auto Scaling Group</a> in the <i>Amazon EC2 Auto Scaling User Guide</i>.</p>
-    async fn enter_standby(
+    /// Presigns the request `enable_metrics_collection` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_enable_metrics_collection(
         &self,
-        input: EnterStandbyQuery,
-    ) -> Result<EnterStandbyAnswer, RusotoError<EnterStandbyError>> {
+        presigner: &rusoto_core::Presigner,
+        input: EnableMetricsCollectionQuery,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_enable_metrics_collection_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_enable_metrics_collection_request(
+        &self,
+        input: EnableMetricsCollectionQuery,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "autoscaling", &self.region, "/");
+        request.set_operation("EnableMetricsCollection");
         let mut params = Params::new();
 
-        params.put("Action", "EnterStandby");
+        params.put("Action", "EnableMetricsCollection");
         params.put("Version", "2011-01-01");
-        EnterStandbyQuerySerializer::serialize(&mut params, "", &input);
+        EnableMetricsCollectionQuerySerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `enter_standby`, also returning the metadata of the response.
+    pub async fn enter_standby_with_metadata(
+        &self,
+        input: EnterStandbyQuery,
+    ) -> Result<(EnterStandbyAnswer, ResponseMetadata), RusotoError<EnterStandbyError>> {
+        let request = self.build_enter_standby_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(EnterStandbyError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -12186,62 +13690,107 @@ impl Autoscaling for AutoscalingClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Executes the specified policy.</p>
-    async fn execute_policy(
+    /// Presigns the request `enter_standby` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_enter_standby(
         &self,
-        input: ExecutePolicyType,
-    ) -> Result<(), RusotoError<ExecutePolicyError>> {
+        presigner: &rusoto_core::Presigner,
+        input: EnterStandbyQuery,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_enter_standby_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_enter_standby_request(&self, input: EnterStandbyQuery) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "autoscaling", &self.region, "/");
+        request.set_operation("EnterStandby");
         let mut params = Params::new();
 
-        params.put("Action", "ExecutePolicy");
+        params.put("Action", "EnterStandby");
         params.put("Version", "2011-01-01");
-        ExecutePolicyTypeSerializer::serialize(&mut params, "", &input);
+        EnterStandbyQuerySerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `execute_policy`, also returning the metadata of the response.
+    pub async fn execute_policy_with_metadata(
+        &self,
+        input: ExecutePolicyType,
+    ) -> Result<((), ResponseMetadata), RusotoError<ExecutePolicyError>> {
+        let request = self.build_execute_policy_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(ExecutePolicyError::from_response(response));
         }
 
         std::mem::drop(response);
-        Ok(())
+        Ok(((), metadata))
     }
 
-    /// <p>Moves the specified instances out of the standby state.</p> <p>After you put the instances back in service, the desired capacity is incremented.</p> <p>For more information, see <a href="https://docs.aws.amazon.com/autoscaling/ec2/userguide/as-enter-exit-standby.html">Temporarily Removing Instances from Your Auto Scaling Group</a> in the <i>Amazon EC2 Auto Scaling User Guide</i>.</p>
-    async fn exit_standby(
+    /// Presigns the request `execute_policy` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_execute_policy(
         &self,
-        input: ExitStandbyQuery,
-    ) -> Result<ExitStandbyAnswer, RusotoError<ExitStandbyError>> {
+        presigner: &rusoto_core::Presigner,
+        input: ExecutePolicyType,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_execute_policy_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_execute_policy_request(&self, input: ExecutePolicyType) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "autoscaling", &self.region, "/");
+        request.set_operation("ExecutePolicy");
         let mut params = Params::new();
 
-        params.put("Action", "ExitStandby");
+        params.put("Action", "ExecutePolicy");
         params.put("Version", "2011-01-01");
-        ExitStandbyQuerySerializer::serialize(&mut params, "", &input);
+        ExecutePolicyTypeSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `exit_standby`, also returning the metadata of the response.
+    pub async fn exit_standby_with_metadata(
+        &self,
+        input: ExitStandbyQuery,
+    ) -> Result<(ExitStandbyAnswer, ResponseMetadata), RusotoError<ExitStandbyError>> {
+        let request = self.build_exit_standby_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(ExitStandbyError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -12260,34 +13809,59 @@ impl Autoscaling for AutoscalingClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Creates or updates a lifecycle hook for the specified Auto Scaling group.</p> <p>A lifecycle hook tells Amazon EC2 Auto Scaling to perform an action on an instance when the instance launches (before it is put into service) or as the instance terminates (before it is fully terminated).</p> <p>This step is a part of the procedure for adding a lifecycle hook to an Auto Scaling group:</p> <ol> <li> <p>(Optional) Create a Lambda function and a rule that allows CloudWatch Events to invoke your Lambda function when Amazon EC2 Auto Scaling launches or terminates instances.</p> </li> <li> <p>(Optional) Create a notification target and an IAM role. The target can be either an Amazon SQS queue or an Amazon SNS topic. The role allows Amazon EC2 Auto Scaling to publish lifecycle notifications to the target.</p> </li> <li> <p> <b>Create the lifecycle hook. Specify whether the hook is used when the instances launch or terminate.</b> </p> </li> <li> <p>If you need more time, record the lifecycle action heartbeat to keep the instance in a pending state using <a>RecordLifecycleActionHeartbeat</a>.</p> </li> <li> <p>If you finish before the timeout period ends, complete the lifecycle action using <a>CompleteLifecycleAction</a>.</p> </li> </ol> <p>For more information, see <a href="https://docs.aws.amazon.com/autoscaling/ec2/userguide/lifecycle-hooks.html">Amazon EC2 Auto Scaling Lifecycle Hooks</a> in the <i>Amazon EC2 Auto Scaling User Guide</i>.</p> <p>If you exceed your maximum limit of lifecycle hooks, which by default is 50 per Auto Scaling group, the call fails.</p> <p>You can view the lifecycle hooks for an Auto Scaling group using <a>DescribeLifecycleHooks</a>. If you are no longer using a lifecycle hook, you can delete it using <a>DeleteLifecycleHook</a>.</p>
-    async fn put_lifecycle_hook(
+    /// Presigns the request `exit_standby` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_exit_standby(
         &self,
-        input: PutLifecycleHookType,
-    ) -> Result<PutLifecycleHookAnswer, RusotoError<PutLifecycleHookError>> {
+        presigner: &rusoto_core::Presigner,
+        input: ExitStandbyQuery,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_exit_standby_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_exit_standby_request(&self, input: ExitStandbyQuery) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "autoscaling", &self.region, "/");
+        request.set_operation("ExitStandby");
         let mut params = Params::new();
 
-        params.put("Action", "PutLifecycleHook");
+        params.put("Action", "ExitStandby");
         params.put("Version", "2011-01-01");
-        PutLifecycleHookTypeSerializer::serialize(&mut params, "", &input);
+        ExitStandbyQuerySerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `put_lifecycle_hook`, also returning the metadata of the response.
+    pub async fn put_lifecycle_hook_with_metadata(
+        &self,
+        input: PutLifecycleHookType,
+    ) -> Result<(PutLifecycleHookAnswer, ResponseMetadata), RusotoError<PutLifecycleHookError>>
+    {
+        let request = self.build_put_lifecycle_hook_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(PutLifecycleHookError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -12309,62 +13883,110 @@ impl Autoscaling for AutoscalingClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Configures an Auto Scaling group to send notifications when specified events take place. Subscribers to the specified topic can have messages delivered to an endpoint such as a web server or an email address.</p> <p>This configuration overwrites any existing configuration.</p> <p>For more information, see <a href="https://docs.aws.amazon.com/autoscaling/ec2/userguide/ASGettingNotifications.html">Getting Amazon SNS Notifications When Your Auto Scaling Group Scales</a> in the <i>Amazon EC2 Auto Scaling User Guide</i>.</p>
-    async fn put_notification_configuration(
+    /// Presigns the request `put_lifecycle_hook` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_put_lifecycle_hook(
         &self,
-        input: PutNotificationConfigurationType,
-    ) -> Result<(), RusotoError<PutNotificationConfigurationError>> {
+        presigner: &rusoto_core::Presigner,
+        input: PutLifecycleHookType,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_put_lifecycle_hook_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_put_lifecycle_hook_request(&self, input: PutLifecycleHookType) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "autoscaling", &self.region, "/");
+        request.set_operation("PutLifecycleHook");
         let mut params = Params::new();
 
-        params.put("Action", "PutNotificationConfiguration");
+        params.put("Action", "PutLifecycleHook");
         params.put("Version", "2011-01-01");
-        PutNotificationConfigurationTypeSerializer::serialize(&mut params, "", &input);
+        PutLifecycleHookTypeSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `put_notification_configuration`, also returning the metadata of the response.
+    pub async fn put_notification_configuration_with_metadata(
+        &self,
+        input: PutNotificationConfigurationType,
+    ) -> Result<((), ResponseMetadata), RusotoError<PutNotificationConfigurationError>> {
+        let request = self.build_put_notification_configuration_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(PutNotificationConfigurationError::from_response(response));
         }
 
         std::mem::drop(response);
-        Ok(())
+        Ok(((), metadata))
     }
 
-    /// <p>Creates or updates a scaling policy for an Auto Scaling group. To update an existing scaling policy, use the existing policy name and set the parameters to change. Any existing parameter not changed in an update to an existing policy is not changed in this update request.</p> <p>For more information about using scaling policies to scale your Auto Scaling group automatically, see <a href="https://docs.aws.amazon.com/autoscaling/ec2/userguide/as-scale-based-on-demand.html">Dynamic Scaling</a> in the <i>Amazon EC2 Auto Scaling User Guide</i>.</p>
-    async fn put_scaling_policy(
+    /// Presigns the request `put_notification_configuration` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_put_notification_configuration(
         &self,
-        input: PutScalingPolicyType,
-    ) -> Result<PolicyARNType, RusotoError<PutScalingPolicyError>> {
+        presigner: &rusoto_core::Presigner,
+        input: PutNotificationConfigurationType,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_put_notification_configuration_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_put_notification_configuration_request(
+        &self,
+        input: PutNotificationConfigurationType,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "autoscaling", &self.region, "/");
+        request.set_operation("PutNotificationConfiguration");
         let mut params = Params::new();
 
-        params.put("Action", "PutScalingPolicy");
+        params.put("Action", "PutNotificationConfiguration");
         params.put("Version", "2011-01-01");
-        PutScalingPolicyTypeSerializer::serialize(&mut params, "", &input);
+        PutNotificationConfigurationTypeSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `put_scaling_policy`, also returning the metadata of the response.
+    pub async fn put_scaling_policy_with_metadata(
+        &self,
+        input: PutScalingPolicyType,
+    ) -> Result<(PolicyARNType, ResponseMetadata), RusotoError<PutScalingPolicyError>> {
+        let request = self.build_put_scaling_policy_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(PutScalingPolicyError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -12383,65 +14005,113 @@ impl Autoscaling for AutoscalingClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Creates or updates a scheduled scaling action for an Auto Scaling group. If you leave a parameter unspecified when updating a scheduled scaling action, the corresponding value remains unchanged.</p> <p>For more information, see <a href="https://docs.aws.amazon.com/autoscaling/ec2/userguide/schedule_time.html">Scheduled Scaling</a> in the <i>Amazon EC2 Auto Scaling User Guide</i>.</p>
-    async fn put_scheduled_update_group_action(
+    /// Presigns the request `put_scaling_policy` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_put_scaling_policy(
         &self,
-        input: PutScheduledUpdateGroupActionType,
-    ) -> Result<(), RusotoError<PutScheduledUpdateGroupActionError>> {
+        presigner: &rusoto_core::Presigner,
+        input: PutScalingPolicyType,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_put_scaling_policy_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_put_scaling_policy_request(&self, input: PutScalingPolicyType) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "autoscaling", &self.region, "/");
+        request.set_operation("PutScalingPolicy");
         let mut params = Params::new();
 
-        params.put("Action", "PutScheduledUpdateGroupAction");
+        params.put("Action", "PutScalingPolicy");
         params.put("Version", "2011-01-01");
-        PutScheduledUpdateGroupActionTypeSerializer::serialize(&mut params, "", &input);
+        PutScalingPolicyTypeSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
-        let mut response = self
+        request
+    }
+
+    /// Calls `put_scheduled_update_group_action`, also returning the metadata of the response.
+    pub async fn put_scheduled_update_group_action_with_metadata(
+        &self,
+        input: PutScheduledUpdateGroupActionType,
+    ) -> Result<((), ResponseMetadata), RusotoError<PutScheduledUpdateGroupActionError>> {
+        let request = self.build_put_scheduled_update_group_action_request(input);
+
+        let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(PutScheduledUpdateGroupActionError::from_response(response));
         }
 
         std::mem::drop(response);
-        Ok(())
+        Ok(((), metadata))
     }
 
-    /// <p>Records a heartbeat for the lifecycle action associated with the specified token or instance. This extends the timeout by the length of time defined using <a>PutLifecycleHook</a>.</p> <p>This step is a part of the procedure for adding a lifecycle hook to an Auto Scaling group:</p> <ol> <li> <p>(Optional) Create a Lambda function and a rule that allows CloudWatch Events to invoke your Lambda function when Amazon EC2 Auto Scaling launches or terminates instances.</p> </li> <li> <p>(Optional) Create a notification target and an IAM role. The target can be either an Amazon SQS queue or an Amazon SNS topic. The role allows Amazon EC2 Auto Scaling to publish lifecycle notifications to the target.</p> </li> <li> <p>Create the lifecycle hook. Specify whether the hook is used when the instances launch or terminate.</p> </li> <li> <p> <b>If you need more time, record the lifecycle action heartbeat to keep the instance in a pending state.</b> </p> </li> <li> <p>If you finish before the timeout period ends, complete the lifecycle action.</p> </li> </ol> <p>For more information, see <a href="https://docs.aws.amazon.com/autoscaling/ec2/userguide/AutoScalingGroupLifecycle.html">Auto Scaling Lifecycle</a> in the <i>Amazon EC2 Auto Scaling User Guide</i>.</p>
-    async fn record_lifecycle_action_heartbeat(
+    /// Presigns the request `put_scheduled_update_group_action` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_put_scheduled_update_group_action(
         &self,
-        input: RecordLifecycleActionHeartbeatType,
-    ) -> Result<
-        RecordLifecycleActionHeartbeatAnswer,
-        RusotoError<RecordLifecycleActionHeartbeatError>,
-    > {
+        presigner: &rusoto_core::Presigner,
+        input: PutScheduledUpdateGroupActionType,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_put_scheduled_update_group_action_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_put_scheduled_update_group_action_request(
+        &self,
+        input: PutScheduledUpdateGroupActionType,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "autoscaling", &self.region, "/");
+        request.set_operation("PutScheduledUpdateGroupAction");
         let mut params = Params::new();
 
-        params.put("Action", "RecordLifecycleActionHeartbeat");
+        params.put("Action", "PutScheduledUpdateGroupAction");
         params.put("Version", "2011-01-01");
-        RecordLifecycleActionHeartbeatTypeSerializer::serialize(&mut params, "", &input);
+        PutScheduledUpdateGroupActionTypeSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `record_lifecycle_action_heartbeat`, also returning the metadata of the response.
+    pub async fn record_lifecycle_action_heartbeat_with_metadata(
+        &self,
+        input: RecordLifecycleActionHeartbeatType,
+    ) -> Result<
+        (RecordLifecycleActionHeartbeatAnswer, ResponseMetadata),
+        RusotoError<RecordLifecycleActionHeartbeatError>,
+    > {
+        let request = self.build_record_lifecycle_action_heartbeat_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(RecordLifecycleActionHeartbeatError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -12463,118 +14133,211 @@ impl Autoscaling for AutoscalingClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Resumes the specified suspended automatic scaling processes, or all suspended process, for the specified Auto Scaling group.</p> <p>For more information, see <a href="https://docs.aws.amazon.com/autoscaling/ec2/userguide/as-suspend-resume-processes.html">Suspending and Resuming Scaling Processes</a> in the <i>Amazon EC2 Auto Scaling User Guide</i>.</p>
-    async fn resume_processes(
+    /// Presigns the request `record_lifecycle_action_heartbeat` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_record_lifecycle_action_heartbeat(
         &self,
-        input: ScalingProcessQuery,
-    ) -> Result<(), RusotoError<ResumeProcessesError>> {
+        presigner: &rusoto_core::Presigner,
+        input: RecordLifecycleActionHeartbeatType,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_record_lifecycle_action_heartbeat_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_record_lifecycle_action_heartbeat_request(
+        &self,
+        input: RecordLifecycleActionHeartbeatType,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "autoscaling", &self.region, "/");
+        request.set_operation("RecordLifecycleActionHeartbeat");
         let mut params = Params::new();
 
-        params.put("Action", "ResumeProcesses");
+        params.put("Action", "RecordLifecycleActionHeartbeat");
         params.put("Version", "2011-01-01");
-        ScalingProcessQuerySerializer::serialize(&mut params, "", &input);
+        RecordLifecycleActionHeartbeatTypeSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `resume_processes`, also returning the metadata of the response.
+    pub async fn resume_processes_with_metadata(
+        &self,
+        input: ScalingProcessQuery,
+    ) -> Result<((), ResponseMetadata), RusotoError<ResumeProcessesError>> {
+        let request = self.build_resume_processes_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(ResumeProcessesError::from_response(response));
         }
 
         std::mem::drop(response);
-        Ok(())
+        Ok(((), metadata))
     }
 
-    /// <p>Sets the size of the specified Auto Scaling group.</p> <p>For more information about desired capacity, see <a href="https://docs.aws.amazon.com/autoscaling/ec2/userguide/what-is-amazon-ec2-auto-scaling.html">What Is Amazon EC2 Auto Scaling?</a> in the <i>Amazon EC2 Auto Scaling User Guide</i>.</p>
-    async fn set_desired_capacity(
+    /// Presigns the request `resume_processes` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_resume_processes(
         &self,
-        input: SetDesiredCapacityType,
-    ) -> Result<(), RusotoError<SetDesiredCapacityError>> {
+        presigner: &rusoto_core::Presigner,
+        input: ScalingProcessQuery,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_resume_processes_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_resume_processes_request(&self, input: ScalingProcessQuery) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "autoscaling", &self.region, "/");
+        request.set_operation("ResumeProcesses");
         let mut params = Params::new();
 
-        params.put("Action", "SetDesiredCapacity");
+        params.put("Action", "ResumeProcesses");
         params.put("Version", "2011-01-01");
-        SetDesiredCapacityTypeSerializer::serialize(&mut params, "", &input);
+        ScalingProcessQuerySerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `set_desired_capacity`, also returning the metadata of the response.
+    pub async fn set_desired_capacity_with_metadata(
+        &self,
+        input: SetDesiredCapacityType,
+    ) -> Result<((), ResponseMetadata), RusotoError<SetDesiredCapacityError>> {
+        let request = self.build_set_desired_capacity_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(SetDesiredCapacityError::from_response(response));
         }
 
         std::mem::drop(response);
-        Ok(())
+        Ok(((), metadata))
     }
 
-    /// <p>Sets the health status of the specified instance.</p> <p>For more information, see <a href="https://docs.aws.amazon.com/autoscaling/ec2/userguide/healthcheck.html">Health Checks for Auto Scaling Instances</a> in the <i>Amazon EC2 Auto Scaling User Guide</i>.</p>
-    async fn set_instance_health(
+    /// Presigns the request `set_desired_capacity` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_set_desired_capacity(
         &self,
-        input: SetInstanceHealthQuery,
-    ) -> Result<(), RusotoError<SetInstanceHealthError>> {
+        presigner: &rusoto_core::Presigner,
+        input: SetDesiredCapacityType,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_set_desired_capacity_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_set_desired_capacity_request(&self, input: SetDesiredCapacityType) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "autoscaling", &self.region, "/");
+        request.set_operation("SetDesiredCapacity");
         let mut params = Params::new();
 
-        params.put("Action", "SetInstanceHealth");
+        params.put("Action", "SetDesiredCapacity");
         params.put("Version", "2011-01-01");
-        SetInstanceHealthQuerySerializer::serialize(&mut params, "", &input);
+        SetDesiredCapacityTypeSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `set_instance_health`, also returning the metadata of the response.
+    pub async fn set_instance_health_with_metadata(
+        &self,
+        input: SetInstanceHealthQuery,
+    ) -> Result<((), ResponseMetadata), RusotoError<SetInstanceHealthError>> {
+        let request = self.build_set_instance_health_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(SetInstanceHealthError::from_response(response));
         }
 
         std::mem::drop(response);
-        Ok(())
+        Ok(((), metadata))
     }
 
-    /// <p>Updates the instance protection settings of the specified instances.</p> <p>For more information about preventing instances that are part of an Auto Scaling group from terminating on scale in, see <a href="https://docs.aws.amazon.com/autoscaling/ec2/userguide/as-instance-termination.html#instance-protection">Instance Protection</a> in the <i>Amazon EC2 Auto Scaling User Guide</i>.</p>
-    async fn set_instance_protection(
+    /// Presigns the request `set_instance_health` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_set_instance_health(
         &self,
-        input: SetInstanceProtectionQuery,
-    ) -> Result<SetInstanceProtectionAnswer, RusotoError<SetInstanceProtectionError>> {
+        presigner: &rusoto_core::Presigner,
+        input: SetInstanceHealthQuery,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_set_instance_health_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_set_instance_health_request(&self, input: SetInstanceHealthQuery) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "autoscaling", &self.region, "/");
+        request.set_operation("SetInstanceHealth");
         let mut params = Params::new();
 
-        params.put("Action", "SetInstanceProtection");
+        params.put("Action", "SetInstanceHealth");
         params.put("Version", "2011-01-01");
-        SetInstanceProtectionQuerySerializer::serialize(&mut params, "", &input);
+        SetInstanceHealthQuerySerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `set_instance_protection`, also returning the metadata of the response.
+    pub async fn set_instance_protection_with_metadata(
+        &self,
+        input: SetInstanceProtectionQuery,
+    ) -> Result<
+        (SetInstanceProtectionAnswer, ResponseMetadata),
+        RusotoError<SetInstanceProtectionError>,
+    > {
+        let request = self.build_set_instance_protection_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(SetInstanceProtectionError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -12596,64 +14359,115 @@ impl Autoscaling for AutoscalingClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Suspends the specified automatic scaling processes, or all processes, for the specified Auto Scaling group.</p> <p>If you suspend either the <code>Launch</code> or <code>Terminate</code> process types, it can prevent other process types from functioning properly.</p> <p>To resume processes that have been suspended, use <a>ResumeProcesses</a>.</p> <p>For more information, see <a href="https://docs.aws.amazon.com/autoscaling/ec2/userguide/as-suspend-resume-processes.html">Suspending and Resuming Scaling Processes</a> in the <i>Amazon EC2 Auto Scaling User Guide</i>.</p>
-    async fn suspend_processes(
+    /// Presigns the request `set_instance_protection` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_set_instance_protection(
         &self,
-        input: ScalingProcessQuery,
-    ) -> Result<(), RusotoError<SuspendProcessesError>> {
+        presigner: &rusoto_core::Presigner,
+        input: SetInstanceProtectionQuery,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_set_instance_protection_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_set_instance_protection_request(
+        &self,
+        input: SetInstanceProtectionQuery,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "autoscaling", &self.region, "/");
+        request.set_operation("SetInstanceProtection");
         let mut params = Params::new();
 
-        params.put("Action", "SuspendProcesses");
+        params.put("Action", "SetInstanceProtection");
         params.put("Version", "2011-01-01");
-        ScalingProcessQuerySerializer::serialize(&mut params, "", &input);
+        SetInstanceProtectionQuerySerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `suspend_processes`, also returning the metadata of the response.
+    pub async fn suspend_processes_with_metadata(
+        &self,
+        input: ScalingProcessQuery,
+    ) -> Result<((), ResponseMetadata), RusotoError<SuspendProcessesError>> {
+        let request = self.build_suspend_processes_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(SuspendProcessesError::from_response(response));
         }
 
         std::mem::drop(response);
-        Ok(())
+        Ok(((), metadata))
     }
 
-    /// <p>Terminates the specified instance and optionally adjusts the desired group size.</p> <p>This call simply makes a termination request. The instance is not terminated immediately.</p>
-    async fn terminate_instance_in_auto_scaling_group(
+    /// Presigns the request `suspend_processes` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_suspend_processes(
         &self,
-        input: TerminateInstanceInAutoScalingGroupType,
-    ) -> Result<ActivityType, RusotoError<TerminateInstanceInAutoScalingGroupError>> {
+        presigner: &rusoto_core::Presigner,
+        input: ScalingProcessQuery,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_suspend_processes_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_suspend_processes_request(&self, input: ScalingProcessQuery) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "autoscaling", &self.region, "/");
+        request.set_operation("SuspendProcesses");
         let mut params = Params::new();
 
-        params.put("Action", "TerminateInstanceInAutoScalingGroup");
+        params.put("Action", "SuspendProcesses");
         params.put("Version", "2011-01-01");
-        TerminateInstanceInAutoScalingGroupTypeSerializer::serialize(&mut params, "", &input);
+        ScalingProcessQuerySerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `terminate_instance_in_auto_scaling_group`, also returning the metadata of the response.
+    pub async fn terminate_instance_in_auto_scaling_group_with_metadata(
+        &self,
+        input: TerminateInstanceInAutoScalingGroupType,
+    ) -> Result<
+        (ActivityType, ResponseMetadata),
+        RusotoError<TerminateInstanceInAutoScalingGroupError>,
+    > {
+        let request = self.build_terminate_instance_in_auto_scaling_group_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(TerminateInstanceInAutoScalingGroupError::from_response(
                 response,
             ));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -12675,35 +14489,1052 @@ impl Autoscaling for AutoscalingClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Updates the configuration for the specified Auto Scaling group.</p> <p>To update an Auto Scaling group, specify the name of the group and the parameter that you want to change. Any parameters that you don't specify are not changed by this update request. The new settings take effect on any scaling activities after this call returns. </p> <p>If you associate a new launch configuration or template with an Auto Scaling group, all new instances will get the updated configuration. Existing instances continue to run with the configuration that they were originally launched with. When you update a group to specify a mixed instances policy instead of a launch configuration or template, existing instances may be replaced to match the new purchasing options that you specified in the policy. For example, if the group currently has 100% On-Demand capacity and the policy specifies 50% Spot capacity, this means that half of your instances will be gradually terminated and relaunched as Spot Instances. When replacing instances, Amazon EC2 Auto Scaling launches new instances before terminating the old ones, so that updating your group does not compromise the performance or availability of your application.</p> <p>Note the following about changing <code>DesiredCapacity</code>, <code>MaxSize</code>, or <code>MinSize</code>:</p> <ul> <li> <p>If a scale-in event occurs as a result of a new <code>DesiredCapacity</code> value that is lower than the current size of the group, the Auto Scaling group uses its termination policy to determine which instances to terminate.</p> </li> <li> <p>If you specify a new value for <code>MinSize</code> without specifying a value for <code>DesiredCapacity</code>, and the new <code>MinSize</code> is larger than the current size of the group, this sets the group's <code>DesiredCapacity</code> to the new <code>MinSize</code> value.</p> </li> <li> <p>If you specify a new value for <code>MaxSize</code> without specifying a value for <code>DesiredCapacity</code>, and the new <code>MaxSize</code> is smaller than the current size of the group, this sets the group's <code>DesiredCapacity</code> to the new <code>MaxSize</code> value.</p> </li> </ul> <p>To see which parameters have been set, use <a>DescribeAutoScalingGroups</a>. You can also view the scaling policies for an Auto Scaling group using <a>DescribePolicies</a>. If the group has scaling policies, you can update them using <a>PutScalingPolicy</a>.</p>
-    async fn update_auto_scaling_group(
+    /// Presigns the request `terminate_instance_in_auto_scaling_group` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_terminate_instance_in_auto_scaling_group(
         &self,
-        input: UpdateAutoScalingGroupType,
-    ) -> Result<(), RusotoError<UpdateAutoScalingGroupError>> {
+        presigner: &rusoto_core::Presigner,
+        input: TerminateInstanceInAutoScalingGroupType,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_terminate_instance_in_auto_scaling_group_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_terminate_instance_in_auto_scaling_group_request(
+        &self,
+        input: TerminateInstanceInAutoScalingGroupType,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "autoscaling", &self.region, "/");
+        request.set_operation("TerminateInstanceInAutoScalingGroup");
         let mut params = Params::new();
 
-        params.put("Action", "UpdateAutoScalingGroup");
+        params.put("Action", "TerminateInstanceInAutoScalingGroup");
         params.put("Version", "2011-01-01");
-        UpdateAutoScalingGroupTypeSerializer::serialize(&mut params, "", &input);
+        TerminateInstanceInAutoScalingGroupTypeSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `update_auto_scaling_group`, also returning the metadata of the response.
+    pub async fn update_auto_scaling_group_with_metadata(
+        &self,
+        input: UpdateAutoScalingGroupType,
+    ) -> Result<((), ResponseMetadata), RusotoError<UpdateAutoScalingGroupError>> {
+        let request = self.build_update_auto_scaling_group_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(UpdateAutoScalingGroupError::from_response(response));
         }
 
         std::mem::drop(response);
-        Ok(())
+        Ok(((), metadata))
+    }
+
+    /// Presigns the request `update_auto_scaling_group` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_auto_scaling_group(
+        &self,
+        presigner: &rusoto_core::Presigner,
+        input: UpdateAutoScalingGroupType,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_auto_scaling_group_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_auto_scaling_group_request(
+        &self,
+        input: UpdateAutoScalingGroupType,
+    ) -> SignedRequest {
+        let mut request = SignedRequest::new("POST", "autoscaling", &self.region, "/");
+        request.set_operation("UpdateAutoScalingGroup");
+        let mut params = Params::new();
+
+        params.put("Action", "UpdateAutoScalingGroup");
+        params.put("Version", "2011-01-01");
+        UpdateAutoScalingGroupTypeSerializer::serialize(&mut params, "", &input);
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        request
+    }
+
+    /// The pages of `DescribeAutoScalingGroups` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `MaxRecords` of `input`.
+    pub fn describe_auto_scaling_groups_pages(
+        &self,
+        input: AutoScalingGroupNamesType,
+    ) -> impl futures::Stream<
+        Item = Result<AutoScalingGroupsType, RusotoError<DescribeAutoScalingGroupsError>>,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: AutoscalingClient, input| async move {
+                client.describe_auto_scaling_groups(input).await
+            },
+            |input: &AutoScalingGroupNamesType, output: &AutoScalingGroupsType| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(AutoScalingGroupNamesType {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `AutoScalingGroups` of every page of `DescribeAutoScalingGroups` results for `input`, as `describe_auto_scaling_groups_pages` requests them.
+    pub fn describe_auto_scaling_groups_stream(
+        &self,
+        input: AutoScalingGroupNamesType,
+    ) -> impl futures::Stream<
+        Item = Result<AutoScalingGroup, RusotoError<DescribeAutoScalingGroupsError>>,
+    > + Send {
+        rusoto_core::pagination::items(self.describe_auto_scaling_groups_pages(input), |page| {
+            page.auto_scaling_groups
+        })
+    }
+
+    /// The pages of `DescribeAutoScalingInstances` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `MaxRecords` of `input`.
+    pub fn describe_auto_scaling_instances_pages(
+        &self,
+        input: DescribeAutoScalingInstancesType,
+    ) -> impl futures::Stream<
+        Item = Result<AutoScalingInstancesType, RusotoError<DescribeAutoScalingInstancesError>>,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: AutoscalingClient, input| async move {
+                client.describe_auto_scaling_instances(input).await
+            },
+            |input: &DescribeAutoScalingInstancesType, output: &AutoScalingInstancesType| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(DescribeAutoScalingInstancesType {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `AutoScalingInstances` of every page of `DescribeAutoScalingInstances` results for `input`, as `describe_auto_scaling_instances_pages` requests them.
+    pub fn describe_auto_scaling_instances_stream(
+        &self,
+        input: DescribeAutoScalingInstancesType,
+    ) -> impl futures::Stream<
+        Item = Result<AutoScalingInstanceDetails, RusotoError<DescribeAutoScalingInstancesError>>,
+    > + Send {
+        rusoto_core::pagination::items(self.describe_auto_scaling_instances_pages(input), |page| {
+            page.auto_scaling_instances.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `DescribeLaunchConfigurations` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `MaxRecords` of `input`.
+    pub fn describe_launch_configurations_pages(
+        &self,
+        input: LaunchConfigurationNamesType,
+    ) -> impl futures::Stream<
+        Item = Result<LaunchConfigurationsType, RusotoError<DescribeLaunchConfigurationsError>>,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: AutoscalingClient, input| async move {
+                client.describe_launch_configurations(input).await
+            },
+            |input: &LaunchConfigurationNamesType, output: &LaunchConfigurationsType| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(LaunchConfigurationNamesType {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `LaunchConfigurations` of every page of `DescribeLaunchConfigurations` results for `input`, as `describe_launch_configurations_pages` requests them.
+    pub fn describe_launch_configurations_stream(
+        &self,
+        input: LaunchConfigurationNamesType,
+    ) -> impl futures::Stream<
+        Item = Result<LaunchConfiguration, RusotoError<DescribeLaunchConfigurationsError>>,
+    > + Send {
+        rusoto_core::pagination::items(self.describe_launch_configurations_pages(input), |page| {
+            page.launch_configurations
+        })
+    }
+
+    /// The pages of `DescribeLoadBalancerTargetGroups` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `MaxRecords` of `input`.
+    pub fn describe_load_balancer_target_groups_pages(
+        &self,
+        input: DescribeLoadBalancerTargetGroupsRequest,
+    ) -> impl futures::Stream<
+        Item = Result<
+            DescribeLoadBalancerTargetGroupsResponse,
+            RusotoError<DescribeLoadBalancerTargetGroupsError>,
+        >,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: AutoscalingClient, input| async move {
+                client.describe_load_balancer_target_groups(input).await
+            },
+            |input: &DescribeLoadBalancerTargetGroupsRequest,
+             output: &DescribeLoadBalancerTargetGroupsResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(DescribeLoadBalancerTargetGroupsRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `LoadBalancerTargetGroups` of every page of `DescribeLoadBalancerTargetGroups` results for `input`, as `describe_load_balancer_target_groups_pages` requests them.
+    pub fn describe_load_balancer_target_groups_stream(
+        &self,
+        input: DescribeLoadBalancerTargetGroupsRequest,
+    ) -> impl futures::Stream<
+        Item = Result<
+            LoadBalancerTargetGroupState,
+            RusotoError<DescribeLoadBalancerTargetGroupsError>,
+        >,
+    > + Send {
+        rusoto_core::pagination::items(
+            self.describe_load_balancer_target_groups_pages(input),
+            |page| page.load_balancer_target_groups.unwrap_or_default(),
+        )
+    }
+
+    /// The pages of `DescribeLoadBalancers` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `MaxRecords` of `input`.
+    pub fn describe_load_balancers_pages(
+        &self,
+        input: DescribeLoadBalancersRequest,
+    ) -> impl futures::Stream<
+        Item = Result<DescribeLoadBalancersResponse, RusotoError<DescribeLoadBalancersError>>,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: AutoscalingClient, input| async move {
+                client.describe_load_balancers(input).await
+            },
+            |input: &DescribeLoadBalancersRequest, output: &DescribeLoadBalancersResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(DescribeLoadBalancersRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `LoadBalancers` of every page of `DescribeLoadBalancers` results for `input`, as `describe_load_balancers_pages` requests them.
+    pub fn describe_load_balancers_stream(
+        &self,
+        input: DescribeLoadBalancersRequest,
+    ) -> impl futures::Stream<
+        Item = Result<LoadBalancerState, RusotoError<DescribeLoadBalancersError>>,
+    > + Send {
+        rusoto_core::pagination::items(self.describe_load_balancers_pages(input), |page| {
+            page.load_balancers.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `DescribeNotificationConfigurations` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `MaxRecords` of `input`.
+    pub fn describe_notification_configurations_pages(
+        &self,
+        input: DescribeNotificationConfigurationsType,
+    ) -> impl futures::Stream<
+        Item = Result<
+            DescribeNotificationConfigurationsAnswer,
+            RusotoError<DescribeNotificationConfigurationsError>,
+        >,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: AutoscalingClient, input| async move {
+                client.describe_notification_configurations(input).await
+            },
+            |input: &DescribeNotificationConfigurationsType,
+             output: &DescribeNotificationConfigurationsAnswer| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(DescribeNotificationConfigurationsType {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `NotificationConfigurations` of every page of `DescribeNotificationConfigurations` results for `input`, as `describe_notification_configurations_pages` requests them.
+    pub fn describe_notification_configurations_stream(
+        &self,
+        input: DescribeNotificationConfigurationsType,
+    ) -> impl futures::Stream<
+        Item = Result<
+            NotificationConfiguration,
+            RusotoError<DescribeNotificationConfigurationsError>,
+        >,
+    > + Send {
+        rusoto_core::pagination::items(
+            self.describe_notification_configurations_pages(input),
+            |page| page.notification_configurations,
+        )
+    }
+
+    /// The pages of `DescribePolicies` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `MaxRecords` of `input`.
+    pub fn describe_policies_pages(
+        &self,
+        input: DescribePoliciesType,
+    ) -> impl futures::Stream<Item = Result<PoliciesType, RusotoError<DescribePoliciesError>>> + Send
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: AutoscalingClient, input| async move { client.describe_policies(input).await },
+            |input: &DescribePoliciesType, output: &PoliciesType| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(DescribePoliciesType {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `ScalingPolicies` of every page of `DescribePolicies` results for `input`, as `describe_policies_pages` requests them.
+    pub fn describe_policies_stream(
+        &self,
+        input: DescribePoliciesType,
+    ) -> impl futures::Stream<Item = Result<ScalingPolicy, RusotoError<DescribePoliciesError>>> + Send
+    {
+        rusoto_core::pagination::items(self.describe_policies_pages(input), |page| {
+            page.scaling_policies.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `DescribeScalingActivities` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `MaxRecords` of `input`.
+    pub fn describe_scaling_activities_pages(
+        &self,
+        input: DescribeScalingActivitiesType,
+    ) -> impl futures::Stream<
+        Item = Result<ActivitiesType, RusotoError<DescribeScalingActivitiesError>>,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: AutoscalingClient, input| async move {
+                client.describe_scaling_activities(input).await
+            },
+            |input: &DescribeScalingActivitiesType, output: &ActivitiesType| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(DescribeScalingActivitiesType {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `Activities` of every page of `DescribeScalingActivities` results for `input`, as `describe_scaling_activities_pages` requests them.
+    pub fn describe_scaling_activities_stream(
+        &self,
+        input: DescribeScalingActivitiesType,
+    ) -> impl futures::Stream<Item = Result<Activity, RusotoError<DescribeScalingActivitiesError>>> + Send
+    {
+        rusoto_core::pagination::items(self.describe_scaling_activities_pages(input), |page| {
+            page.activities
+        })
+    }
+
+    /// The pages of `DescribeScheduledActions` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `MaxRecords` of `input`.
+    pub fn describe_scheduled_actions_pages(
+        &self,
+        input: DescribeScheduledActionsType,
+    ) -> impl futures::Stream<
+        Item = Result<ScheduledActionsType, RusotoError<DescribeScheduledActionsError>>,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: AutoscalingClient, input| async move {
+                client.describe_scheduled_actions(input).await
+            },
+            |input: &DescribeScheduledActionsType, output: &ScheduledActionsType| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(DescribeScheduledActionsType {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `ScheduledUpdateGroupActions` of every page of `DescribeScheduledActions` results for `input`, as `describe_scheduled_actions_pages` requests them.
+    pub fn describe_scheduled_actions_stream(
+        &self,
+        input: DescribeScheduledActionsType,
+    ) -> impl futures::Stream<
+        Item = Result<ScheduledUpdateGroupAction, RusotoError<DescribeScheduledActionsError>>,
+    > + Send {
+        rusoto_core::pagination::items(self.describe_scheduled_actions_pages(input), |page| {
+            page.scheduled_update_group_actions.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `DescribeTags` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `MaxRecords` of `input`.
+    pub fn describe_tags_pages(
+        &self,
+        input: DescribeTagsType,
+    ) -> impl futures::Stream<Item = Result<TagsType, RusotoError<DescribeTagsError>>> + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: AutoscalingClient, input| async move { client.describe_tags(input).await },
+            |input: &DescribeTagsType, output: &TagsType| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(DescribeTagsType {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `Tags` of every page of `DescribeTags` results for `input`, as `describe_tags_pages` requests them.
+    pub fn describe_tags_stream(
+        &self,
+        input: DescribeTagsType,
+    ) -> impl futures::Stream<Item = Result<TagDescription, RusotoError<DescribeTagsError>>> + Send
+    {
+        rusoto_core::pagination::items(self.describe_tags_pages(input), |page| {
+            page.tags.unwrap_or_default()
+        })
+    }
+}
+
+#[async_trait]
+impl Autoscaling for AutoscalingClient {
+    /// <p>Attaches one or more EC2 instances to the specified Auto Scaling group.</p> <p>When you attach instances, Amazon EC2 Auto Scaling increases the desired capacity of the group by the number of instances being attached. If the number of instances being attached plus the desired capacity of the group exceeds the maximum size of the group, the operation fails.</p> <p>If there is a Classic Load Balancer attached to your Auto Scaling group, the instances are also registered with the load balancer. If there are target groups attached to your Auto Scaling group, the instances are also registered with the target groups.</p> <p>For more information, see <a href="https://docs.aws.amazon.com/autoscaling/ec2/userguide/attach-instance-asg.html">Attach EC2 Instances to Your Auto Scaling Group</a> in the <i>Amazon EC2 Auto Scaling User Guide</i>.</p>
+    async fn attach_instances(
+        &self,
+        input: AttachInstancesQuery,
+    ) -> Result<(), RusotoError<AttachInstancesError>> {
+        self.attach_instances_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Attaches one or more target groups to the specified Auto Scaling group.</p> <p>To describe the target groups for an Auto Scaling group, use <a>DescribeLoadBalancerTargetGroups</a>. To detach the target group from the Auto Scaling group, use <a>DetachLoadBalancerTargetGroups</a>.</p> <p>With Application Load Balancers and Network Load Balancers, instances are registered as targets with a target group. With Classic Load Balancers, instances are registered with the load balancer. For more information, see <a href="https://docs.aws.amazon.com/autoscaling/ec2/userguide/attach-load-balancer-asg.html">Attaching a Load Balancer to Your Auto Scaling Group</a> in the <i>Amazon EC2 Auto Scaling User Guide</i>.</p>
+    async fn attach_load_balancer_target_groups(
+        &self,
+        input: AttachLoadBalancerTargetGroupsType,
+    ) -> Result<
+        AttachLoadBalancerTargetGroupsResultType,
+        RusotoError<AttachLoadBalancerTargetGroupsError>,
+    > {
+        self.attach_load_balancer_target_groups_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Attaches one or more Classic Load Balancers to the specified Auto Scaling group.</p> <p>To attach an Application Load Balancer or a Network Load Balancer instead, see <a>AttachLoadBalancerTargetGroups</a>.</p> <p>To describe the load balancers for an Auto Scaling group, use <a>DescribeLoadBalancers</a>. To detach the load balancer from the Auto Scaling group, use <a>DetachLoadBalancers</a>.</p> <p>For more information, see <a href="https://docs.aws.amazon.com/autoscaling/ec2/userguide/attach-load-balancer-asg.html">Attaching a Load Balancer to Your Auto Scaling Group</a> in the <i>Amazon EC2 Auto Scaling User Guide</i>.</p>
+    async fn attach_load_balancers(
+        &self,
+        input: AttachLoadBalancersType,
+    ) -> Result<AttachLoadBalancersResultType, RusotoError<AttachLoadBalancersError>> {
+        self.attach_load_balancers_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes one or more scheduled actions for the specified Auto Scaling group.</p>
+    async fn batch_delete_scheduled_action(
+        &self,
+        input: BatchDeleteScheduledActionType,
+    ) -> Result<BatchDeleteScheduledActionAnswer, RusotoError<BatchDeleteScheduledActionError>>
+    {
+        self.batch_delete_scheduled_action_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Creates or updates one or more scheduled scaling actions for an Auto Scaling group. If you leave a parameter unspecified when updating a scheduled scaling action, the corresponding value remains unchanged.</p>
+    async fn batch_put_scheduled_update_group_action(
+        &self,
+        input: BatchPutScheduledUpdateGroupActionType,
+    ) -> Result<
+        BatchPutScheduledUpdateGroupActionAnswer,
+        RusotoError<BatchPutScheduledUpdateGroupActionError>,
+    > {
+        self.batch_put_scheduled_update_group_action_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Completes the lifecycle action for the specified token or instance with the specified result.</p> <p>This step is a part of the procedure for adding a lifecycle hook to an Auto Scaling group:</p> <ol> <li> <p>(Optional) Create a Lambda function and a rule that allows CloudWatch Events to invoke your Lambda function when Amazon EC2 Auto Scaling launches or terminates instances.</p> </li> <li> <p>(Optional) Create a notification target and an IAM role. The target can be either an Amazon SQS queue or an Amazon SNS topic. The role allows Amazon EC2 Auto Scaling to publish lifecycle notifications to the target.</p> </li> <li> <p>Create the lifecycle hook. Specify whether the hook is used when the instances launch or terminate.</p> </li> <li> <p>If you need more time, record the lifecycle action heartbeat to keep the instance in a pending state.</p> </li> <li> <p> <b>If you finish before the timeout period ends, complete the lifecycle action.</b> </p> </li> </ol> <p>For more information, see <a href="https://docs.aws.amazon.com/autoscaling/ec2/userguide/lifecycle-hooks.html">Amazon EC2 Auto Scaling Lifecycle Hooks</a> in the <i>Amazon EC2 Auto Scaling User Guide</i>.</p>
+    async fn complete_lifecycle_action(
+        &self,
+        input: CompleteLifecycleActionType,
+    ) -> Result<CompleteLifecycleActionAnswer, RusotoError<CompleteLifecycleActionError>> {
+        self.complete_lifecycle_action_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Creates an Auto Scaling group with the specified name and attributes.</p> <p>If you exceed your maximum limit of Auto Scaling groups, the call fails. For information about viewing this limit, see <a>DescribeAccountLimits</a>. For information about updating this limit, see <a href="https://docs.aws.amazon.com/autoscaling/ec2/userguide/as-account-limits.html">Amazon EC2 Auto Scaling Limits</a> in the <i>Amazon EC2 Auto Scaling User Guide</i>.</p>
+    async fn create_auto_scaling_group(
+        &self,
+        input: CreateAutoScalingGroupType,
+    ) -> Result<(), RusotoError<CreateAutoScalingGroupError>> {
+        self.create_auto_scaling_group_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Creates a launch configuration.</p> <p>If you exceed your maximum limit of launch configurations, the call fails. For information about viewing this limit, see <a>DescribeAccountLimits</a>. For information about updating this limit, see <a href="https://docs.aws.amazon.com/autoscaling/ec2/userguide/as-account-limits.html">Amazon EC2 Auto Scaling Limits</a> in the <i>Amazon EC2 Auto Scaling User Guide</i>.</p> <p>For more information, see <a href="https://docs.aws.amazon.com/autoscaling/ec2/userguide/LaunchConfiguration.html">Launch Configurations</a> in the <i>Amazon EC2 Auto Scaling User Guide</i>.</p>
+    async fn create_launch_configuration(
+        &self,
+        input: CreateLaunchConfigurationType,
+    ) -> Result<(), RusotoError<CreateLaunchConfigurationError>> {
+        self.create_launch_configuration_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Creates or updates tags for the specified Auto Scaling group.</p> <p>When you specify a tag with a key that already exists, the operation overwrites the previous tag definition, and you do not get an error message.</p> <p>For more information, see <a href="https://docs.aws.amazon.com/autoscaling/ec2/userguide/autoscaling-tagging.html">Tagging Auto Scaling Groups and Instances</a> in the <i>Amazon EC2 Auto Scaling User Guide</i>.</p>
+    async fn create_or_update_tags(
+        &self,
+        input: CreateOrUpdateTagsType,
+    ) -> Result<(), RusotoError<CreateOrUpdateTagsError>> {
+        self.create_or_update_tags_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes the specified Auto Scaling group.</p> <p>If the group has instances or scaling activities in progress, you must specify the option to force the deletion in order for it to succeed.</p> <p>If the group has policies, deleting the group deletes the policies, the underlying alarm actions, and any alarm that no longer has an associated action.</p> <p>To remove instances from the Auto Scaling group before deleting it, call <a>DetachInstances</a> with the list of instances and the option to decrement the desired capacity. This ensures that Amazon EC2 Auto Scaling does not launch replacement instances.</p> <p>To terminate all instances before deleting the Auto Scaling group, call <a>UpdateAutoScalingGroup</a> and set the minimum size and desired capacity of the Auto Scaling group to zero.</p>
+    async fn delete_auto_scaling_group(
+        &self,
+        input: DeleteAutoScalingGroupType,
+    ) -> Result<(), RusotoError<DeleteAutoScalingGroupError>> {
+        self.delete_auto_scaling_group_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes the specified launch configuration.</p> <p>The launch configuration must not be attached to an Auto Scaling group. When this call completes, the launch configuration is no longer available for use.</p>
+    async fn delete_launch_configuration(
+        &self,
+        input: LaunchConfigurationNameType,
+    ) -> Result<(), RusotoError<DeleteLaunchConfigurationError>> {
+        self.delete_launch_configuration_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes the specified lifecycle hook.</p> <p>If there are any outstanding lifecycle actions, they are completed first (<code>ABANDON</code> for launching instances, <code>CONTINUE</code> for terminating instances).</p>
+    async fn delete_lifecycle_hook(
+        &self,
+        input: DeleteLifecycleHookType,
+    ) -> Result<DeleteLifecycleHookAnswer, RusotoError<DeleteLifecycleHookError>> {
+        self.delete_lifecycle_hook_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes the specified notification.</p>
+    async fn delete_notification_configuration(
+        &self,
+        input: DeleteNotificationConfigurationType,
+    ) -> Result<(), RusotoError<DeleteNotificationConfigurationError>> {
+        self.delete_notification_configuration_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes the specified scaling policy.</p> <p>Deleting either a step scaling policy or a simple scaling policy deletes the underlying alarm action, but does not delete the alarm, even if it no longer has an associated action.</p> <p>For more information, see <a href="https://docs.aws.amazon.com/autoscaling/ec2/userguide/deleting-scaling-policy.html">Deleting a Scaling Policy</a> in the <i>Amazon EC2 Auto Scaling User Guide</i>.</p>
+    async fn delete_policy(
+        &self,
+        input: DeletePolicyType,
+    ) -> Result<(), RusotoError<DeletePolicyError>> {
+        self.delete_policy_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes the specified scheduled action.</p>
+    async fn delete_scheduled_action(
+        &self,
+        input: DeleteScheduledActionType,
+    ) -> Result<(), RusotoError<DeleteScheduledActionError>> {
+        self.delete_scheduled_action_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes the specified tags.</p>
+    async fn delete_tags(&self, input: DeleteTagsType) -> Result<(), RusotoError<DeleteTagsError>> {
+        self.delete_tags_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Describes the current Amazon EC2 Auto Scaling resource limits for your AWS account.</p> <p>For information about requesting an increase in these limits, see <a href="https://docs.aws.amazon.com/autoscaling/ec2/userguide/as-account-limits.html">Amazon EC2 Auto Scaling Limits</a> in the <i>Amazon EC2 Auto Scaling User Guide</i>.</p>
+    async fn describe_account_limits(
+        &self,
+    ) -> Result<DescribeAccountLimitsAnswer, RusotoError<DescribeAccountLimitsError>> {
+        self.describe_account_limits_with_metadata()
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Describes the policy adjustment types for use with <a>PutScalingPolicy</a>.</p>
+    async fn describe_adjustment_types(
+        &self,
+    ) -> Result<DescribeAdjustmentTypesAnswer, RusotoError<DescribeAdjustmentTypesError>> {
+        self.describe_adjustment_types_with_metadata()
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Describes one or more Auto Scaling groups.</p>
+    async fn describe_auto_scaling_groups(
+        &self,
+        input: AutoScalingGroupNamesType,
+    ) -> Result<AutoScalingGroupsType, RusotoError<DescribeAutoScalingGroupsError>> {
+        self.describe_auto_scaling_groups_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Describes one or more Auto Scaling instances.</p>
+    async fn describe_auto_scaling_instances(
+        &self,
+        input: DescribeAutoScalingInstancesType,
+    ) -> Result<AutoScalingInstancesType, RusotoError<DescribeAutoScalingInstancesError>> {
+        self.describe_auto_scaling_instances_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Describes the notification types that are supported by Amazon EC2 Auto Scaling.</p>
+    async fn describe_auto_scaling_notification_types(
+        &self,
+    ) -> Result<
+        DescribeAutoScalingNotificationTypesAnswer,
+        RusotoError<DescribeAutoScalingNotificationTypesError>,
+    > {
+        self.describe_auto_scaling_notification_types_with_metadata()
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Describes one or more launch configurations.</p>
+    async fn describe_launch_configurations(
+        &self,
+        input: LaunchConfigurationNamesType,
+    ) -> Result<LaunchConfigurationsType, RusotoError<DescribeLaunchConfigurationsError>> {
+        self.describe_launch_configurations_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Describes the available types of lifecycle hooks.</p> <p>The following hook types are supported:</p> <ul> <li> <p>autoscaling:EC2<em>INSTANCE</em>LAUNCHING</p> </li> <li> <p>autoscaling:EC2<em>INSTANCE</em>TERMINATING</p> </li> </ul></p>
+    async fn describe_lifecycle_hook_types(
+        &self,
+    ) -> Result<DescribeLifecycleHookTypesAnswer, RusotoError<DescribeLifecycleHookTypesError>>
+    {
+        self.describe_lifecycle_hook_types_with_metadata()
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Describes the lifecycle hooks for the specified Auto Scaling group.</p>
+    async fn describe_lifecycle_hooks(
+        &self,
+        input: DescribeLifecycleHooksType,
+    ) -> Result<DescribeLifecycleHooksAnswer, RusotoError<DescribeLifecycleHooksError>> {
+        self.describe_lifecycle_hooks_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Describes the target groups for the specified Auto Scaling group.</p>
+    async fn describe_load_balancer_target_groups(
+        &self,
+        input: DescribeLoadBalancerTargetGroupsRequest,
+    ) -> Result<
+        DescribeLoadBalancerTargetGroupsResponse,
+        RusotoError<DescribeLoadBalancerTargetGroupsError>,
+    > {
+        self.describe_load_balancer_target_groups_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Describes the load balancers for the specified Auto Scaling group.</p> <p>This operation describes only Classic Load Balancers. If you have Application Load Balancers or Network Load Balancers, use <a>DescribeLoadBalancerTargetGroups</a> instead.</p>
+    async fn describe_load_balancers(
+        &self,
+        input: DescribeLoadBalancersRequest,
+    ) -> Result<DescribeLoadBalancersResponse, RusotoError<DescribeLoadBalancersError>> {
+        self.describe_load_balancers_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Describes the available CloudWatch metrics for Amazon EC2 Auto Scaling.</p> <p>The <code>GroupStandbyInstances</code> metric is not returned by default. You must explicitly request this metric when calling <a>EnableMetricsCollection</a>.</p>
+    async fn describe_metric_collection_types(
+        &self,
+    ) -> Result<DescribeMetricCollectionTypesAnswer, RusotoError<DescribeMetricCollectionTypesError>>
+    {
+        self.describe_metric_collection_types_with_metadata()
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Describes the notification actions associated with the specified Auto Scaling group.</p>
+    async fn describe_notification_configurations(
+        &self,
+        input: DescribeNotificationConfigurationsType,
+    ) -> Result<
+        DescribeNotificationConfigurationsAnswer,
+        RusotoError<DescribeNotificationConfigurationsError>,
+    > {
+        self.describe_notification_configurations_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Describes the policies for the specified Auto Scaling group.</p>
+    async fn describe_policies(
+        &self,
+        input: DescribePoliciesType,
+    ) -> Result<PoliciesType, RusotoError<DescribePoliciesError>> {
+        self.describe_policies_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Describes one or more scaling activities for the specified Auto Scaling group.</p>
+    async fn describe_scaling_activities(
+        &self,
+        input: DescribeScalingActivitiesType,
+    ) -> Result<ActivitiesType, RusotoError<DescribeScalingActivitiesError>> {
+        self.describe_scaling_activities_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Describes the scaling process types for use with <a>ResumeProcesses</a> and <a>SuspendProcesses</a>.</p>
+    async fn describe_scaling_process_types(
+        &self,
+    ) -> Result<ProcessesType, RusotoError<DescribeScalingProcessTypesError>> {
+        self.describe_scaling_process_types_with_metadata()
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Describes the actions scheduled for your Auto Scaling group that haven't run or that have not reached their end time. To describe the actions that have already run, use <a>DescribeScalingActivities</a>.</p>
+    async fn describe_scheduled_actions(
+        &self,
+        input: DescribeScheduledActionsType,
+    ) -> Result<ScheduledActionsType, RusotoError<DescribeScheduledActionsError>> {
+        self.describe_scheduled_actions_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Describes the specified tags.</p> <p>You can use filters to limit the results. For example, you can query for the tags for a specific Auto Scaling group. You can specify multiple values for a filter. A tag must match at least one of the specified values for it to be included in the results.</p> <p>You can also specify multiple filters. The result includes information for a particular tag only if it matches all the filters. If there's no match, no special message is returned.</p>
+    async fn describe_tags(
+        &self,
+        input: DescribeTagsType,
+    ) -> Result<TagsType, RusotoError<DescribeTagsError>> {
+        self.describe_tags_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Describes the termination policies supported by Amazon EC2 Auto Scaling.</p> <p>For more information, see <a href="https://docs.aws.amazon.com/autoscaling/ec2/userguide/as-instance-termination.html">Controlling Which Auto Scaling Instances Terminate During Scale In</a> in the <i>Amazon EC2 Auto Scaling User Guide</i>.</p>
+    async fn describe_termination_policy_types(
+        &self,
+    ) -> Result<
+        DescribeTerminationPolicyTypesAnswer,
+        RusotoError<DescribeTerminationPolicyTypesError>,
+    > {
+        self.describe_termination_policy_types_with_metadata()
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Removes one or more instances from the specified Auto Scaling group.</p> <p>After the instances are detached, you can manage them independent of the Auto Scaling group.</p> <p>If you do not specify the option to decrement the desired capacity, Amazon EC2 Auto Scaling launches instances to replace the ones that are detached.</p> <p>If there is a Classic Load Balancer attached to the Auto Scaling group, the instances are deregistered from the load balancer. If there are target groups attached to the Auto Scaling group, the instances are deregistered from the target groups.</p> <p>For more information, see <a href="https://docs.aws.amazon.com/autoscaling/ec2/userguide/detach-instance-asg.html">Detach EC2 Instances from Your Auto Scaling Group</a> in the <i>Amazon EC2 Auto Scaling User Guide</i>.</p>
+    async fn detach_instances(
+        &self,
+        input: DetachInstancesQuery,
+    ) -> Result<DetachInstancesAnswer, RusotoError<DetachInstancesError>> {
+        self.detach_instances_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Detaches one or more target groups from the specified Auto Scaling group.</p>
+    async fn detach_load_balancer_target_groups(
+        &self,
+        input: DetachLoadBalancerTargetGroupsType,
+    ) -> Result<
+        DetachLoadBalancerTargetGroupsResultType,
+        RusotoError<DetachLoadBalancerTargetGroupsError>,
+    > {
+        self.detach_load_balancer_target_groups_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Detaches one or more Classic Load Balancers from the specified Auto Scaling group.</p> <p>This operation detaches only Classic Load Balancers. If you have Application Load Balancers or Network Load Balancers, use <a>DetachLoadBalancerTargetGroups</a> instead.</p> <p>When you detach a load balancer, it enters the <code>Removing</code> state while deregistering the instances in the group. When all instances are deregistered, then you can no longer describe the load balancer using <a>DescribeLoadBalancers</a>. The instances remain running.</p>
+    async fn detach_load_balancers(
+        &self,
+        input: DetachLoadBalancersType,
+    ) -> Result<DetachLoadBalancersResultType, RusotoError<DetachLoadBalancersError>> {
+        self.detach_load_balancers_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Disables group metrics for the specified Auto Scaling group.</p>
+    async fn disable_metrics_collection(
+        &self,
+        input: DisableMetricsCollectionQuery,
+    ) -> Result<(), RusotoError<DisableMetricsCollectionError>> {
+        self.disable_metrics_collection_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Enables group metrics for the specified Auto Scaling group. For more information, see <a href="https://docs.aws.amazon.com/autoscaling/ec2/userguide/as-instance-monitoring.html">Monitoring Your Auto Scaling Groups and Instances</a> in the <i>Amazon EC2 Auto Scaling User Guide</i>.</p>
+    async fn enable_metrics_collection(
+        &self,
+        input: EnableMetricsCollectionQuery,
+    ) -> Result<(), RusotoError<EnableMetricsCollectionError>> {
+        self.enable_metrics_collection_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Moves the specified instances into the standby state.</p> <p>If you choose to decrement the desired capacity of the Auto Scaling group, the instances can enter standby as long as the desired capacity of the Auto Scaling group after the instances are placed into standby is equal to or greater than the minimum capacity of the group.</p> <p>If you choose not to decrement the desired capacity of the Auto Scaling group, the Auto Scaling group launches new instances to replace the instances on standby.</p> <p>For more information, see <a href="https://docs.aws.amazon.com/autoscaling/ec2/userguide/as-enter-exit-standby.html">Temporarily Removing Instances from Your Auto Scaling Group</a> in the <i>Amazon EC2 Auto Scaling User Guide</i>.</p>
+    async fn enter_standby(
+        &self,
+        input: EnterStandbyQuery,
+    ) -> Result<EnterStandbyAnswer, RusotoError<EnterStandbyError>> {
+        self.enter_standby_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Executes the specified policy.</p>
+    async fn execute_policy(
+        &self,
+        input: ExecutePolicyType,
+    ) -> Result<(), RusotoError<ExecutePolicyError>> {
+        self.execute_policy_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Moves the specified instances out of the standby state.</p> <p>After you put the instances back in service, the desired capacity is incremented.</p> <p>For more information, see <a href="https://docs.aws.amazon.com/autoscaling/ec2/userguide/as-enter-exit-standby.html">Temporarily Removing Instances from Your Auto Scaling Group</a> in the <i>Amazon EC2 Auto Scaling User Guide</i>.</p>
+    async fn exit_standby(
+        &self,
+        input: ExitStandbyQuery,
+    ) -> Result<ExitStandbyAnswer, RusotoError<ExitStandbyError>> {
+        self.exit_standby_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Creates or updates a lifecycle hook for the specified Auto Scaling group.</p> <p>A lifecycle hook tells Amazon EC2 Auto Scaling to perform an action on an instance when the instance launches (before it is put into service) or as the instance terminates (before it is fully terminated).</p> <p>This step is a part of the procedure for adding a lifecycle hook to an Auto Scaling group:</p> <ol> <li> <p>(Optional) Create a Lambda function and a rule that allows CloudWatch Events to invoke your Lambda function when Amazon EC2 Auto Scaling launches or terminates instances.</p> </li> <li> <p>(Optional) Create a notification target and an IAM role. The target can be either an Amazon SQS queue or an Amazon SNS topic. The role allows Amazon EC2 Auto Scaling to publish lifecycle notifications to the target.</p> </li> <li> <p> <b>Create the lifecycle hook. Specify whether the hook is used when the instances launch or terminate.</b> </p> </li> <li> <p>If you need more time, record the lifecycle action heartbeat to keep the instance in a pending state using <a>RecordLifecycleActionHeartbeat</a>.</p> </li> <li> <p>If you finish before the timeout period ends, complete the lifecycle action using <a>CompleteLifecycleAction</a>.</p> </li> </ol> <p>For more information, see <a href="https://docs.aws.amazon.com/autoscaling/ec2/userguide/lifecycle-hooks.html">Amazon EC2 Auto Scaling Lifecycle Hooks</a> in the <i>Amazon EC2 Auto Scaling User Guide</i>.</p> <p>If you exceed your maximum limit of lifecycle hooks, which by default is 50 per Auto Scaling group, the call fails.</p> <p>You can view the lifecycle hooks for an Auto Scaling group using <a>DescribeLifecycleHooks</a>. If you are no longer using a lifecycle hook, you can delete it using <a>DeleteLifecycleHook</a>.</p>
+    async fn put_lifecycle_hook(
+        &self,
+        input: PutLifecycleHookType,
+    ) -> Result<PutLifecycleHookAnswer, RusotoError<PutLifecycleHookError>> {
+        self.put_lifecycle_hook_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Configures an Auto Scaling group to send notifications when specified events take place. Subscribers to the specified topic can have messages delivered to an endpoint such as a web server or an email address.</p> <p>This configuration overwrites any existing configuration.</p> <p>For more information, see <a href="https://docs.aws.amazon.com/autoscaling/ec2/userguide/ASGettingNotifications.html">Getting Amazon SNS Notifications When Your Auto Scaling Group Scales</a> in the <i>Amazon EC2 Auto Scaling User Guide</i>.</p>
+    async fn put_notification_configuration(
+        &self,
+        input: PutNotificationConfigurationType,
+    ) -> Result<(), RusotoError<PutNotificationConfigurationError>> {
+        self.put_notification_configuration_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Creates or updates a scaling policy for an Auto Scaling group. To update an existing scaling policy, use the existing policy name and set the parameters to change. Any existing parameter not changed in an update to an existing policy is not changed in this update request.</p> <p>For more information about using scaling policies to scale your Auto Scaling group automatically, see <a href="https://docs.aws.amazon.com/autoscaling/ec2/userguide/as-scale-based-on-demand.html">Dynamic Scaling</a> in the <i>Amazon EC2 Auto Scaling User Guide</i>.</p>
+    async fn put_scaling_policy(
+        &self,
+        input: PutScalingPolicyType,
+    ) -> Result<PolicyARNType, RusotoError<PutScalingPolicyError>> {
+        self.put_scaling_policy_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Creates or updates a scheduled scaling action for an Auto Scaling group. If you leave a parameter unspecified when updating a scheduled scaling action, the corresponding value remains unchanged.</p> <p>For more information, see <a href="https://docs.aws.amazon.com/autoscaling/ec2/userguide/schedule_time.html">Scheduled Scaling</a> in the <i>Amazon EC2 Auto Scaling User Guide</i>.</p>
+    async fn put_scheduled_update_group_action(
+        &self,
+        input: PutScheduledUpdateGroupActionType,
+    ) -> Result<(), RusotoError<PutScheduledUpdateGroupActionError>> {
+        self.put_scheduled_update_group_action_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Records a heartbeat for the lifecycle action associated with the specified token or instance. This extends the timeout by the length of time defined using <a>PutLifecycleHook</a>.</p> <p>This step is a part of the procedure for adding a lifecycle hook to an Auto Scaling group:</p> <ol> <li> <p>(Optional) Create a Lambda function and a rule that allows CloudWatch Events to invoke your Lambda function when Amazon EC2 Auto Scaling launches or terminates instances.</p> </li> <li> <p>(Optional) Create a notification target and an IAM role. The target can be either an Amazon SQS queue or an Amazon SNS topic. The role allows Amazon EC2 Auto Scaling to publish lifecycle notifications to the target.</p> </li> <li> <p>Create the lifecycle hook. Specify whether the hook is used when the instances launch or terminate.</p> </li> <li> <p> <b>If you need more time, record the lifecycle action heartbeat to keep the instance in a pending state.</b> </p> </li> <li> <p>If you finish before the timeout period ends, complete the lifecycle action.</p> </li> </ol> <p>For more information, see <a href="https://docs.aws.amazon.com/autoscaling/ec2/userguide/AutoScalingGroupLifecycle.html">Auto Scaling Lifecycle</a> in the <i>Amazon EC2 Auto Scaling User Guide</i>.</p>
+    async fn record_lifecycle_action_heartbeat(
+        &self,
+        input: RecordLifecycleActionHeartbeatType,
+    ) -> Result<
+        RecordLifecycleActionHeartbeatAnswer,
+        RusotoError<RecordLifecycleActionHeartbeatError>,
+    > {
+        self.record_lifecycle_action_heartbeat_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Resumes the specified suspended automatic scaling processes, or all suspended process, for the specified Auto Scaling group.</p> <p>For more information, see <a href="https://docs.aws.amazon.com/autoscaling/ec2/userguide/as-suspend-resume-processes.html">Suspending and Resuming Scaling Processes</a> in the <i>Amazon EC2 Auto Scaling User Guide</i>.</p>
+    async fn resume_processes(
+        &self,
+        input: ScalingProcessQuery,
+    ) -> Result<(), RusotoError<ResumeProcessesError>> {
+        self.resume_processes_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Sets the size of the specified Auto Scaling group.</p> <p>For more information about desired capacity, see <a href="https://docs.aws.amazon.com/autoscaling/ec2/userguide/what-is-amazon-ec2-auto-scaling.html">What Is Amazon EC2 Auto Scaling?</a> in the <i>Amazon EC2 Auto Scaling User Guide</i>.</p>
+    async fn set_desired_capacity(
+        &self,
+        input: SetDesiredCapacityType,
+    ) -> Result<(), RusotoError<SetDesiredCapacityError>> {
+        self.set_desired_capacity_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Sets the health status of the specified instance.</p> <p>For more information, see <a href="https://docs.aws.amazon.com/autoscaling/ec2/userguide/healthcheck.html">Health Checks for Auto Scaling Instances</a> in the <i>Amazon EC2 Auto Scaling User Guide</i>.</p>
+    async fn set_instance_health(
+        &self,
+        input: SetInstanceHealthQuery,
+    ) -> Result<(), RusotoError<SetInstanceHealthError>> {
+        self.set_instance_health_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Updates the instance protection settings of the specified instances.</p> <p>For more information about preventing instances that are part of an Auto Scaling group from terminating on scale in, see <a href="https://docs.aws.amazon.com/autoscaling/ec2/userguide/as-instance-termination.html#instance-protection">Instance Protection</a> in the <i>Amazon EC2 Auto Scaling User Guide</i>.</p>
+    async fn set_instance_protection(
+        &self,
+        input: SetInstanceProtectionQuery,
+    ) -> Result<SetInstanceProtectionAnswer, RusotoError<SetInstanceProtectionError>> {
+        self.set_instance_protection_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Suspends the specified automatic scaling processes, or all processes, for the specified Auto Scaling group.</p> <p>If you suspend either the <code>Launch</code> or <code>Terminate</code> process types, it can prevent other process types from functioning properly.</p> <p>To resume processes that have been suspended, use <a>ResumeProcesses</a>.</p> <p>For more information, see <a href="https://docs.aws.amazon.com/autoscaling/ec2/userguide/as-suspend-resume-processes.html">Suspending and Resuming Scaling Processes</a> in the <i>Amazon EC2 Auto Scaling User Guide</i>.</p>
+    async fn suspend_processes(
+        &self,
+        input: ScalingProcessQuery,
+    ) -> Result<(), RusotoError<SuspendProcessesError>> {
+        self.suspend_processes_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Terminates the specified instance and optionally adjusts the desired group size.</p> <p>This call simply makes a termination request. The instance is not terminated immediately.</p>
+    async fn terminate_instance_in_auto_scaling_group(
+        &self,
+        input: TerminateInstanceInAutoScalingGroupType,
+    ) -> Result<ActivityType, RusotoError<TerminateInstanceInAutoScalingGroupError>> {
+        self.terminate_instance_in_auto_scaling_group_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Updates the configuration for the specified Auto Scaling group.</p> <p>To update an Auto Scaling group, specify the name of the group and the parameter that you want to change. Any parameters that you don't specify are not changed by this update request. The new settings take effect on any scaling activities after this call returns. </p> <p>If you associate a new launch configuration or template with an Auto Scaling group, all new instances will get the updated configuration. Existing instances continue to run with the configuration that they were originally launched with. When you update a group to specify a mixed instances policy instead of a launch configuration or template, existing instances may be replaced to match the new purchasing options that you specified in the policy. For example, if the group currently has 100% On-Demand capacity and the policy specifies 50% Spot capacity, this means that half of your instances will be gradually terminated and relaunched as Spot Instances. When replacing instances, Amazon EC2 Auto Scaling launches new instances before terminating the old ones, so that updating your group does not compromise the performance or availability of your application.</p> <p>Note the following about changing <code>DesiredCapacity</code>, <code>MaxSize</code>, or <code>MinSize</code>:</p> <ul> <li> <p>If a scale-in event occurs as a result of a new <code>DesiredCapacity</code> value that is lower than the current size of the group, the Auto Scaling group uses its termination policy to determine which instances to terminate.</p> </li> <li> <p>If you specify a new value for <code>MinSize</code> without specifying a value for <code>DesiredCapacity</code>, and the new <code>MinSize</code> is larger than the current size of the group, this sets the group's <code>DesiredCapacity</code> to the new <code>MinSize</code> value.</p> </li> <li> <p>If you specify a new value for <code>MaxSize</code> without specifying a value for <code>DesiredCapacity</code>, and the new <code>MaxSize</code> is smaller than the current size of the group, this sets the group's <code>DesiredCapacity</code> to the new <code>MaxSize</code> value.</p> </li> </ul> <p>To see which parameters have been set, use <a>DescribeAutoScalingGroups</a>. You can also view the scaling policies for an Auto Scaling group using <a>DescribePolicies</a>. If the group has scaling policies, you can update them using <a>PutScalingPolicy</a>.</p>
+    async fn update_auto_scaling_group(
+        &self,
+        input: UpdateAutoScalingGroupType,
+    ) -> Result<(), RusotoError<UpdateAutoScalingGroupError>> {
+        self.update_auto_scaling_group_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
     }
 }
 
@@ -16,8 +16,8 @@ use std::fmt;
 use async_trait::async_trait;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
-use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest, ResponseMetadata};
+use rusoto_core::{Client, ErrorMetadata, RusotoError};
 
 use rusoto_core::param::{Params, ServiceParams};
 use rusoto_core::proto;
@@ -1986,6 +1986,7 @@ pub struct UpdateRecoveryPointLifecycleOutput {
 
 /// Errors returned by CreateBackupPlan
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateBackupPlanError {
     /// <p>The required resource already exists.</p>
     AlreadyExists(String),
@@ -1997,6 +1998,8 @@ pub enum CreateBackupPlanError {
     MissingParameterValue(String),
     /// <p>The request failed due to a temporary failure of the server.</p>
     ServiceUnavailable(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateBackupPlanError {
@@ -2004,26 +2007,37 @@ impl CreateBackupPlanError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AlreadyExistsException" => {
-                    return RusotoError::Service(CreateBackupPlanError::AlreadyExists(err.msg))
+                    return RusotoError::service(
+                        CreateBackupPlanError::AlreadyExists(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(CreateBackupPlanError::InvalidParameterValue(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateBackupPlanError::InvalidParameterValue(err.msg),
+                        &res,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateBackupPlanError::LimitExceeded(err.msg))
+                    return RusotoError::service(
+                        CreateBackupPlanError::LimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "MissingParameterValueException" => {
-                    return RusotoError::Service(CreateBackupPlanError::MissingParameterValue(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateBackupPlanError::MissingParameterValue(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(CreateBackupPlanError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        CreateBackupPlanError::ServiceUnavailable(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreateBackupPlanError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2038,12 +2052,14 @@ impl fmt::Display for CreateBackupPlanError {
             CreateBackupPlanError::LimitExceeded(ref cause) => write!(f, "{}", cause),
             CreateBackupPlanError::MissingParameterValue(ref cause) => write!(f, "{}", cause),
             CreateBackupPlanError::ServiceUnavailable(ref cause) => write!(f, "{}", cause),
+            CreateBackupPlanError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateBackupPlanError {}
 /// Errors returned by CreateBackupSelection
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateBackupSelectionError {
     /// <p>The required resource already exists.</p>
     AlreadyExists(String),
@@ -2055,6 +2071,8 @@ pub enum CreateBackupSelectionError {
     MissingParameterValue(String),
     /// <p>The request failed due to a temporary failure of the server.</p>
     ServiceUnavailable(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateBackupSelectionError {
@@ -2062,28 +2080,37 @@ impl CreateBackupSelectionError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AlreadyExistsException" => {
-                    return RusotoError::Service(CreateBackupSelectionError::AlreadyExists(err.msg))
+                    return RusotoError::service(
+                        CreateBackupSelectionError::AlreadyExists(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(CreateBackupSelectionError::InvalidParameterValue(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateBackupSelectionError::InvalidParameterValue(err.msg),
+                        &res,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateBackupSelectionError::LimitExceeded(err.msg))
+                    return RusotoError::service(
+                        CreateBackupSelectionError::LimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "MissingParameterValueException" => {
-                    return RusotoError::Service(CreateBackupSelectionError::MissingParameterValue(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateBackupSelectionError::MissingParameterValue(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(CreateBackupSelectionError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateBackupSelectionError::ServiceUnavailable(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreateBackupSelectionError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2098,12 +2125,14 @@ impl fmt::Display for CreateBackupSelectionError {
             CreateBackupSelectionError::LimitExceeded(ref cause) => write!(f, "{}", cause),
             CreateBackupSelectionError::MissingParameterValue(ref cause) => write!(f, "{}", cause),
             CreateBackupSelectionError::ServiceUnavailable(ref cause) => write!(f, "{}", cause),
+            CreateBackupSelectionError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateBackupSelectionError {}
 /// Errors returned by CreateBackupVault
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateBackupVaultError {
     /// <p>The required resource already exists.</p>
     AlreadyExists(String),
@@ -2115,6 +2144,8 @@ pub enum CreateBackupVaultError {
     MissingParameterValue(String),
     /// <p>The request failed due to a temporary failure of the server.</p>
     ServiceUnavailable(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateBackupVaultError {
@@ -2122,28 +2153,37 @@ impl CreateBackupVaultError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AlreadyExistsException" => {
-                    return RusotoError::Service(CreateBackupVaultError::AlreadyExists(err.msg))
+                    return RusotoError::service(
+                        CreateBackupVaultError::AlreadyExists(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(CreateBackupVaultError::InvalidParameterValue(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateBackupVaultError::InvalidParameterValue(err.msg),
+                        &res,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateBackupVaultError::LimitExceeded(err.msg))
+                    return RusotoError::service(
+                        CreateBackupVaultError::LimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "MissingParameterValueException" => {
-                    return RusotoError::Service(CreateBackupVaultError::MissingParameterValue(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateBackupVaultError::MissingParameterValue(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(CreateBackupVaultError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateBackupVaultError::ServiceUnavailable(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreateBackupVaultError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2158,12 +2198,14 @@ impl fmt::Display for CreateBackupVaultError {
             CreateBackupVaultError::LimitExceeded(ref cause) => write!(f, "{}", cause),
             CreateBackupVaultError::MissingParameterValue(ref cause) => write!(f, "{}", cause),
             CreateBackupVaultError::ServiceUnavailable(ref cause) => write!(f, "{}", cause),
+            CreateBackupVaultError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateBackupVaultError {}
 /// Errors returned by DeleteBackupPlan
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteBackupPlanError {
     /// <p>Indicates that something is wrong with a parameter's value. For example, the value is out of range.</p>
     InvalidParameterValue(String),
@@ -2175,6 +2217,8 @@ pub enum DeleteBackupPlanError {
     ResourceNotFound(String),
     /// <p>The request failed due to a temporary failure of the server.</p>
     ServiceUnavailable(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteBackupPlanError {
@@ -2182,26 +2226,37 @@ impl DeleteBackupPlanError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(DeleteBackupPlanError::InvalidParameterValue(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteBackupPlanError::InvalidParameterValue(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DeleteBackupPlanError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        DeleteBackupPlanError::InvalidRequest(err.msg),
+                        &res,
+                    )
                 }
                 "MissingParameterValueException" => {
-                    return RusotoError::Service(DeleteBackupPlanError::MissingParameterValue(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteBackupPlanError::MissingParameterValue(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteBackupPlanError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        DeleteBackupPlanError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(DeleteBackupPlanError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        DeleteBackupPlanError::ServiceUnavailable(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteBackupPlanError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2216,12 +2271,14 @@ impl fmt::Display for DeleteBackupPlanError {
             DeleteBackupPlanError::MissingParameterValue(ref cause) => write!(f, "{}", cause),
             DeleteBackupPlanError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             DeleteBackupPlanError::ServiceUnavailable(ref cause) => write!(f, "{}", cause),
+            DeleteBackupPlanError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteBackupPlanError {}
 /// Errors returned by DeleteBackupSelection
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteBackupSelectionError {
     /// <p>Indicates that something is wrong with a parameter's value. For example, the value is out of range.</p>
     InvalidParameterValue(String),
@@ -2231,6 +2288,8 @@ pub enum DeleteBackupSelectionError {
     ResourceNotFound(String),
     /// <p>The request failed due to a temporary failure of the server.</p>
     ServiceUnavailable(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteBackupSelectionError {
@@ -2238,27 +2297,31 @@ impl DeleteBackupSelectionError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(DeleteBackupSelectionError::InvalidParameterValue(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteBackupSelectionError::InvalidParameterValue(err.msg),
+                        &res,
+                    )
                 }
                 "MissingParameterValueException" => {
-                    return RusotoError::Service(DeleteBackupSelectionError::MissingParameterValue(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteBackupSelectionError::MissingParameterValue(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteBackupSelectionError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteBackupSelectionError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(DeleteBackupSelectionError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteBackupSelectionError::ServiceUnavailable(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteBackupSelectionError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2272,12 +2335,14 @@ impl fmt::Display for DeleteBackupSelectionError {
             DeleteBackupSelectionError::MissingParameterValue(ref cause) => write!(f, "{}", cause),
             DeleteBackupSelectionError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             DeleteBackupSelectionError::ServiceUnavailable(ref cause) => write!(f, "{}", cause),
+            DeleteBackupSelectionError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteBackupSelectionError {}
 /// Errors returned by DeleteBackupVault
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteBackupVaultError {
     /// <p>Indicates that something is wrong with a parameter's value. For example, the value is out of range.</p>
     InvalidParameterValue(String),
@@ -2289,6 +2354,8 @@ pub enum DeleteBackupVaultError {
     ResourceNotFound(String),
     /// <p>The request failed due to a temporary failure of the server.</p>
     ServiceUnavailable(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteBackupVaultError {
@@ -2296,28 +2363,37 @@ impl DeleteBackupVaultError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(DeleteBackupVaultError::InvalidParameterValue(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteBackupVaultError::InvalidParameterValue(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DeleteBackupVaultError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        DeleteBackupVaultError::InvalidRequest(err.msg),
+                        &res,
+                    )
                 }
                 "MissingParameterValueException" => {
-                    return RusotoError::Service(DeleteBackupVaultError::MissingParameterValue(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteBackupVaultError::MissingParameterValue(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteBackupVaultError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        DeleteBackupVaultError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(DeleteBackupVaultError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteBackupVaultError::ServiceUnavailable(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteBackupVaultError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2332,12 +2408,14 @@ impl fmt::Display for DeleteBackupVaultError {
             DeleteBackupVaultError::MissingParameterValue(ref cause) => write!(f, "{}", cause),
             DeleteBackupVaultError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             DeleteBackupVaultError::ServiceUnavailable(ref cause) => write!(f, "{}", cause),
+            DeleteBackupVaultError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteBackupVaultError {}
 /// Errors returned by DeleteBackupVaultAccessPolicy
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteBackupVaultAccessPolicyError {
     /// <p>Indicates that something is wrong with a parameter's value. For example, the value is out of range.</p>
     InvalidParameterValue(String),
@@ -2347,6 +2425,8 @@ pub enum DeleteBackupVaultAccessPolicyError {
     ResourceNotFound(String),
     /// <p>The request failed due to a temporary failure of the server.</p>
     ServiceUnavailable(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteBackupVaultAccessPolicyError {
@@ -2356,27 +2436,36 @@ impl DeleteBackupVaultAccessPolicyError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DeleteBackupVaultAccessPolicyError::InvalidParameterValue(err.msg),
+                        &res,
                     )
                 }
                 "MissingParameterValueException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DeleteBackupVaultAccessPolicyError::MissingParameterValue(err.msg),
+                        &res,
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DeleteBackupVaultAccessPolicyError::ResourceNotFound(err.msg),
+                        &res,
                     )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DeleteBackupVaultAccessPolicyError::ServiceUnavailable(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        DeleteBackupVaultAccessPolicyError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -2398,12 +2487,16 @@ impl fmt::Display for DeleteBackupVaultAccessPolicyError {
             DeleteBackupVaultAccessPolicyError::ServiceUnavailable(ref cause) => {
                 write!(f, "{}", cause)
             }
+            DeleteBackupVaultAccessPolicyError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for DeleteBackupVaultAccessPolicyError {}
 /// Errors returned by DeleteBackupVaultNotifications
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteBackupVaultNotificationsError {
     /// <p>Indicates that something is wrong with a parameter's value. For example, the value is out of range.</p>
     InvalidParameterValue(String),
@@ -2413,6 +2506,8 @@ pub enum DeleteBackupVaultNotificationsError {
     ResourceNotFound(String),
     /// <p>The request failed due to a temporary failure of the server.</p>
     ServiceUnavailable(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteBackupVaultNotificationsError {
@@ -2422,27 +2517,36 @@ impl DeleteBackupVaultNotificationsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DeleteBackupVaultNotificationsError::InvalidParameterValue(err.msg),
+                        &res,
                     )
                 }
                 "MissingParameterValueException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DeleteBackupVaultNotificationsError::MissingParameterValue(err.msg),
+                        &res,
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DeleteBackupVaultNotificationsError::ResourceNotFound(err.msg),
+                        &res,
                     )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DeleteBackupVaultNotificationsError::ServiceUnavailable(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        DeleteBackupVaultNotificationsError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -2464,12 +2568,16 @@ impl fmt::Display for DeleteBackupVaultNotificationsError {
             DeleteBackupVaultNotificationsError::ServiceUnavailable(ref cause) => {
                 write!(f, "{}", cause)
             }
+            DeleteBackupVaultNotificationsError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for DeleteBackupVaultNotificationsError {}
 /// Errors returned by DeleteRecoveryPoint
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteRecoveryPointError {
     /// <p>Indicates that something is wrong with a parameter's value. For example, the value is out of range.</p>
     InvalidParameterValue(String),
@@ -2481,6 +2589,8 @@ pub enum DeleteRecoveryPointError {
     ResourceNotFound(String),
     /// <p>The request failed due to a temporary failure of the server.</p>
     ServiceUnavailable(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteRecoveryPointError {
@@ -2488,30 +2598,37 @@ impl DeleteRecoveryPointError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(DeleteRecoveryPointError::InvalidParameterValue(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteRecoveryPointError::InvalidParameterValue(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DeleteRecoveryPointError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        DeleteRecoveryPointError::InvalidRequest(err.msg),
+                        &res,
+                    )
                 }
                 "MissingParameterValueException" => {
-                    return RusotoError::Service(DeleteRecoveryPointError::MissingParameterValue(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteRecoveryPointError::MissingParameterValue(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteRecoveryPointError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteRecoveryPointError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(DeleteRecoveryPointError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteRecoveryPointError::ServiceUnavailable(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteRecoveryPointError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2526,12 +2643,14 @@ impl fmt::Display for DeleteRecoveryPointError {
             DeleteRecoveryPointError::MissingParameterValue(ref cause) => write!(f, "{}", cause),
             DeleteRecoveryPointError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             DeleteRecoveryPointError::ServiceUnavailable(ref cause) => write!(f, "{}", cause),
+            DeleteRecoveryPointError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteRecoveryPointError {}
 /// Errors returned by DescribeBackupJob
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeBackupJobError {
     /// <p>A dependent AWS service or resource returned an error to the AWS Backup service, and the action cannot be completed.</p>
     DependencyFailure(String),
@@ -2543,6 +2662,8 @@ pub enum DescribeBackupJobError {
     ResourceNotFound(String),
     /// <p>The request failed due to a temporary failure of the server.</p>
     ServiceUnavailable(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeBackupJobError {
@@ -2550,28 +2671,37 @@ impl DescribeBackupJobError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "DependencyFailureException" => {
-                    return RusotoError::Service(DescribeBackupJobError::DependencyFailure(err.msg))
+                    return RusotoError::service(
+                        DescribeBackupJobError::DependencyFailure(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(DescribeBackupJobError::InvalidParameterValue(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeBackupJobError::InvalidParameterValue(err.msg),
+                        &res,
+                    )
                 }
                 "MissingParameterValueException" => {
-                    return RusotoError::Service(DescribeBackupJobError::MissingParameterValue(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeBackupJobError::MissingParameterValue(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeBackupJobError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        DescribeBackupJobError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(DescribeBackupJobError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeBackupJobError::ServiceUnavailable(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DescribeBackupJobError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2586,12 +2716,14 @@ impl fmt::Display for DescribeBackupJobError {
             DescribeBackupJobError::MissingParameterValue(ref cause) => write!(f, "{}", cause),
             DescribeBackupJobError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             DescribeBackupJobError::ServiceUnavailable(ref cause) => write!(f, "{}", cause),
+            DescribeBackupJobError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeBackupJobError {}
 /// Errors returned by DescribeBackupVault
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeBackupVaultError {
     /// <p>Indicates that something is wrong with a parameter's value. For example, the value is out of range.</p>
     InvalidParameterValue(String),
@@ -2601,6 +2733,8 @@ pub enum DescribeBackupVaultError {
     ResourceNotFound(String),
     /// <p>The request failed due to a temporary failure of the server.</p>
     ServiceUnavailable(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeBackupVaultError {
@@ -2608,27 +2742,31 @@ impl DescribeBackupVaultError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(DescribeBackupVaultError::InvalidParameterValue(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeBackupVaultError::InvalidParameterValue(err.msg),
+                        &res,
+                    )
                 }
                 "MissingParameterValueException" => {
-                    return RusotoError::Service(DescribeBackupVaultError::MissingParameterValue(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeBackupVaultError::MissingParameterValue(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeBackupVaultError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeBackupVaultError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(DescribeBackupVaultError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeBackupVaultError::ServiceUnavailable(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DescribeBackupVaultError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2642,12 +2780,14 @@ impl fmt::Display for DescribeBackupVaultError {
             DescribeBackupVaultError::MissingParameterValue(ref cause) => write!(f, "{}", cause),
             DescribeBackupVaultError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             DescribeBackupVaultError::ServiceUnavailable(ref cause) => write!(f, "{}", cause),
+            DescribeBackupVaultError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeBackupVaultError {}
 /// Errors returned by DescribeCopyJob
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeCopyJobError {
     /// <p>Indicates that something is wrong with a parameter's value. For example, the value is out of range.</p>
     InvalidParameterValue(String),
@@ -2657,6 +2797,8 @@ pub enum DescribeCopyJobError {
     ResourceNotFound(String),
     /// <p>The request failed due to a temporary failure of the server.</p>
     ServiceUnavailable(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeCopyJobError {
@@ -2664,23 +2806,31 @@ impl DescribeCopyJobError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(DescribeCopyJobError::InvalidParameterValue(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeCopyJobError::InvalidParameterValue(err.msg),
+                        &res,
+                    )
                 }
                 "MissingParameterValueException" => {
-                    return RusotoError::Service(DescribeCopyJobError::MissingParameterValue(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeCopyJobError::MissingParameterValue(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeCopyJobError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        DescribeCopyJobError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(DescribeCopyJobError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        DescribeCopyJobError::ServiceUnavailable(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DescribeCopyJobError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2694,12 +2844,14 @@ impl fmt::Display for DescribeCopyJobError {
             DescribeCopyJobError::MissingParameterValue(ref cause) => write!(f, "{}", cause),
             DescribeCopyJobError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             DescribeCopyJobError::ServiceUnavailable(ref cause) => write!(f, "{}", cause),
+            DescribeCopyJobError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeCopyJobError {}
 /// Errors returned by DescribeProtectedResource
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeProtectedResourceError {
     /// <p>Indicates that something is wrong with a parameter's value. For example, the value is out of range.</p>
     InvalidParameterValue(String),
@@ -2709,6 +2861,8 @@ pub enum DescribeProtectedResourceError {
     ResourceNotFound(String),
     /// <p>The request failed due to a temporary failure of the server.</p>
     ServiceUnavailable(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeProtectedResourceError {
@@ -2716,27 +2870,33 @@ impl DescribeProtectedResourceError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DescribeProtectedResourceError::InvalidParameterValue(err.msg),
+                        &res,
                     )
                 }
                 "MissingParameterValueException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DescribeProtectedResourceError::MissingParameterValue(err.msg),
+                        &res,
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeProtectedResourceError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeProtectedResourceError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DescribeProtectedResourceError::ServiceUnavailable(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(DescribeProtectedResourceError::Unhandled, &res)
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -2754,12 +2914,14 @@ impl fmt::Display for DescribeProtectedResourceError {
             }
             DescribeProtectedResourceError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             DescribeProtectedResourceError::ServiceUnavailable(ref cause) => write!(f, "{}", cause),
+            DescribeProtectedResourceError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeProtectedResourceError {}
 /// Errors returned by DescribeRecoveryPoint
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeRecoveryPointError {
     /// <p>Indicates that something is wrong with a parameter's value. For example, the value is out of range.</p>
     InvalidParameterValue(String),
@@ -2769,6 +2931,8 @@ pub enum DescribeRecoveryPointError {
     ResourceNotFound(String),
     /// <p>The request failed due to a temporary failure of the server.</p>
     ServiceUnavailable(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeRecoveryPointError {
@@ -2776,27 +2940,31 @@ impl DescribeRecoveryPointError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(DescribeRecoveryPointError::InvalidParameterValue(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeRecoveryPointError::InvalidParameterValue(err.msg),
+                        &res,
+                    )
                 }
                 "MissingParameterValueException" => {
-                    return RusotoError::Service(DescribeRecoveryPointError::MissingParameterValue(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeRecoveryPointError::MissingParameterValue(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeRecoveryPointError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeRecoveryPointError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(DescribeRecoveryPointError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeRecoveryPointError::ServiceUnavailable(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DescribeRecoveryPointError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2810,12 +2978,14 @@ impl fmt::Display for DescribeRecoveryPointError {
             DescribeRecoveryPointError::MissingParameterValue(ref cause) => write!(f, "{}", cause),
             DescribeRecoveryPointError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             DescribeRecoveryPointError::ServiceUnavailable(ref cause) => write!(f, "{}", cause),
+            DescribeRecoveryPointError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeRecoveryPointError {}
 /// Errors returned by DescribeRestoreJob
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeRestoreJobError {
     /// <p>A dependent AWS service or resource returned an error to the AWS Backup service, and the action cannot be completed.</p>
     DependencyFailure(String),
@@ -2827,6 +2997,8 @@ pub enum DescribeRestoreJobError {
     ResourceNotFound(String),
     /// <p>The request failed due to a temporary failure of the server.</p>
     ServiceUnavailable(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeRestoreJobError {
@@ -2834,30 +3006,37 @@ impl DescribeRestoreJobError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "DependencyFailureException" => {
-                    return RusotoError::Service(DescribeRestoreJobError::DependencyFailure(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeRestoreJobError::DependencyFailure(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(DescribeRestoreJobError::InvalidParameterValue(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeRestoreJobError::InvalidParameterValue(err.msg),
+                        &res,
+                    )
                 }
                 "MissingParameterValueException" => {
-                    return RusotoError::Service(DescribeRestoreJobError::MissingParameterValue(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeRestoreJobError::MissingParameterValue(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeRestoreJobError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        DescribeRestoreJobError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(DescribeRestoreJobError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeRestoreJobError::ServiceUnavailable(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DescribeRestoreJobError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2872,12 +3051,14 @@ impl fmt::Display for DescribeRestoreJobError {
             DescribeRestoreJobError::MissingParameterValue(ref cause) => write!(f, "{}", cause),
             DescribeRestoreJobError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             DescribeRestoreJobError::ServiceUnavailable(ref cause) => write!(f, "{}", cause),
+            DescribeRestoreJobError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeRestoreJobError {}
 /// Errors returned by ExportBackupPlanTemplate
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ExportBackupPlanTemplateError {
     /// <p>Indicates that something is wrong with a parameter's value. For example, the value is out of range.</p>
     InvalidParameterValue(String),
@@ -2887,6 +3068,8 @@ pub enum ExportBackupPlanTemplateError {
     ResourceNotFound(String),
     /// <p>The request failed due to a temporary failure of the server.</p>
     ServiceUnavailable(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ExportBackupPlanTemplateError {
@@ -2894,27 +3077,31 @@ impl ExportBackupPlanTemplateError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         ExportBackupPlanTemplateError::InvalidParameterValue(err.msg),
+                        &res,
                     )
                 }
                 "MissingParameterValueException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         ExportBackupPlanTemplateError::MissingParameterValue(err.msg),
+                        &res,
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ExportBackupPlanTemplateError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ExportBackupPlanTemplateError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(ExportBackupPlanTemplateError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ExportBackupPlanTemplateError::ServiceUnavailable(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ExportBackupPlanTemplateError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2932,12 +3119,14 @@ impl fmt::Display for ExportBackupPlanTemplateError {
             }
             ExportBackupPlanTemplateError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             ExportBackupPlanTemplateError::ServiceUnavailable(ref cause) => write!(f, "{}", cause),
+            ExportBackupPlanTemplateError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ExportBackupPlanTemplateError {}
 /// Errors returned by GetBackupPlan
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetBackupPlanError {
     /// <p>Indicates that something is wrong with a parameter's value. For example, the value is out of range.</p>
     InvalidParameterValue(String),
@@ -2947,6 +3136,8 @@ pub enum GetBackupPlanError {
     ResourceNotFound(String),
     /// <p>The request failed due to a temporary failure of the server.</p>
     ServiceUnavailable(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetBackupPlanError {
@@ -2954,19 +3145,31 @@ impl GetBackupPlanError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(GetBackupPlanError::InvalidParameterValue(err.msg))
+                    return RusotoError::service(
+                        GetBackupPlanError::InvalidParameterValue(err.msg),
+                        &res,
+                    )
                 }
                 "MissingParameterValueException" => {
-                    return RusotoError::Service(GetBackupPlanError::MissingParameterValue(err.msg))
+                    return RusotoError::service(
+                        GetBackupPlanError::MissingParameterValue(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetBackupPlanError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        GetBackupPlanError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(GetBackupPlanError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        GetBackupPlanError::ServiceUnavailable(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetBackupPlanError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2980,12 +3183,14 @@ impl fmt::Display for GetBackupPlanError {
             GetBackupPlanError::MissingParameterValue(ref cause) => write!(f, "{}", cause),
             GetBackupPlanError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             GetBackupPlanError::ServiceUnavailable(ref cause) => write!(f, "{}", cause),
+            GetBackupPlanError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetBackupPlanError {}
 /// Errors returned by GetBackupPlanFromJSON
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetBackupPlanFromJSONError {
     /// <p>Indicates that something is wrong with a parameter's value. For example, the value is out of range.</p>
     InvalidParameterValue(String),
@@ -2997,6 +3202,8 @@ pub enum GetBackupPlanFromJSONError {
     MissingParameterValue(String),
     /// <p>The request failed due to a temporary failure of the server.</p>
     ServiceUnavailable(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetBackupPlanFromJSONError {
@@ -3004,30 +3211,37 @@ impl GetBackupPlanFromJSONError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(GetBackupPlanFromJSONError::InvalidParameterValue(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetBackupPlanFromJSONError::InvalidParameterValue(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(GetBackupPlanFromJSONError::InvalidRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetBackupPlanFromJSONError::InvalidRequest(err.msg),
+                        &res,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(GetBackupPlanFromJSONError::LimitExceeded(err.msg))
+                    return RusotoError::service(
+                        GetBackupPlanFromJSONError::LimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "MissingParameterValueException" => {
-                    return RusotoError::Service(GetBackupPlanFromJSONError::MissingParameterValue(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetBackupPlanFromJSONError::MissingParameterValue(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(GetBackupPlanFromJSONError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetBackupPlanFromJSONError::ServiceUnavailable(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetBackupPlanFromJSONError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3042,12 +3256,14 @@ impl fmt::Display for GetBackupPlanFromJSONError {
             GetBackupPlanFromJSONError::LimitExceeded(ref cause) => write!(f, "{}", cause),
             GetBackupPlanFromJSONError::MissingParameterValue(ref cause) => write!(f, "{}", cause),
             GetBackupPlanFromJSONError::ServiceUnavailable(ref cause) => write!(f, "{}", cause),
+            GetBackupPlanFromJSONError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetBackupPlanFromJSONError {}
 /// Errors returned by GetBackupPlanFromTemplate
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetBackupPlanFromTemplateError {
     /// <p>Indicates that something is wrong with a parameter's value. For example, the value is out of range.</p>
     InvalidParameterValue(String),
@@ -3057,6 +3273,8 @@ pub enum GetBackupPlanFromTemplateError {
     ResourceNotFound(String),
     /// <p>The request failed due to a temporary failure of the server.</p>
     ServiceUnavailable(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetBackupPlanFromTemplateError {
@@ -3064,27 +3282,33 @@ impl GetBackupPlanFromTemplateError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         GetBackupPlanFromTemplateError::InvalidParameterValue(err.msg),
+                        &res,
                     )
                 }
                 "MissingParameterValueException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         GetBackupPlanFromTemplateError::MissingParameterValue(err.msg),
+                        &res,
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetBackupPlanFromTemplateError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetBackupPlanFromTemplateError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         GetBackupPlanFromTemplateError::ServiceUnavailable(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(GetBackupPlanFromTemplateError::Unhandled, &res)
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -3102,12 +3326,14 @@ impl fmt::Display for GetBackupPlanFromTemplateError {
             }
             GetBackupPlanFromTemplateError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             GetBackupPlanFromTemplateError::ServiceUnavailable(ref cause) => write!(f, "{}", cause),
+            GetBackupPlanFromTemplateError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetBackupPlanFromTemplateError {}
 /// Errors returned by GetBackupSelection
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetBackupSelectionError {
     /// <p>Indicates that something is wrong with a parameter's value. For example, the value is out of range.</p>
     InvalidParameterValue(String),
@@ -3117,6 +3343,8 @@ pub enum GetBackupSelectionError {
     ResourceNotFound(String),
     /// <p>The request failed due to a temporary failure of the server.</p>
     ServiceUnavailable(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetBackupSelectionError {
@@ -3124,25 +3352,31 @@ impl GetBackupSelectionError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(GetBackupSelectionError::InvalidParameterValue(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetBackupSelectionError::InvalidParameterValue(err.msg),
+                        &res,
+                    )
                 }
                 "MissingParameterValueException" => {
-                    return RusotoError::Service(GetBackupSelectionError::MissingParameterValue(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetBackupSelectionError::MissingParameterValue(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetBackupSelectionError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        GetBackupSelectionError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(GetBackupSelectionError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetBackupSelectionError::ServiceUnavailable(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetBackupSelectionError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3156,12 +3390,14 @@ impl fmt::Display for GetBackupSelectionError {
             GetBackupSelectionError::MissingParameterValue(ref cause) => write!(f, "{}", cause),
             GetBackupSelectionError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             GetBackupSelectionError::ServiceUnavailable(ref cause) => write!(f, "{}", cause),
+            GetBackupSelectionError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetBackupSelectionError {}
 /// Errors returned by GetBackupVaultAccessPolicy
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetBackupVaultAccessPolicyError {
     /// <p>Indicates that something is wrong with a parameter's value. For example, the value is out of range.</p>
     InvalidParameterValue(String),
@@ -3171,6 +3407,8 @@ pub enum GetBackupVaultAccessPolicyError {
     ResourceNotFound(String),
     /// <p>The request failed due to a temporary failure of the server.</p>
     ServiceUnavailable(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetBackupVaultAccessPolicyError {
@@ -3180,27 +3418,33 @@ impl GetBackupVaultAccessPolicyError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         GetBackupVaultAccessPolicyError::InvalidParameterValue(err.msg),
+                        &res,
                     )
                 }
                 "MissingParameterValueException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         GetBackupVaultAccessPolicyError::MissingParameterValue(err.msg),
+                        &res,
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetBackupVaultAccessPolicyError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetBackupVaultAccessPolicyError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         GetBackupVaultAccessPolicyError::ServiceUnavailable(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(GetBackupVaultAccessPolicyError::Unhandled, &res)
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -3220,12 +3464,14 @@ impl fmt::Display for GetBackupVaultAccessPolicyError {
             GetBackupVaultAccessPolicyError::ServiceUnavailable(ref cause) => {
                 write!(f, "{}", cause)
             }
+            GetBackupVaultAccessPolicyError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetBackupVaultAccessPolicyError {}
 /// Errors returned by GetBackupVaultNotifications
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetBackupVaultNotificationsError {
     /// <p>Indicates that something is wrong with a parameter's value. For example, the value is out of range.</p>
     InvalidParameterValue(String),
@@ -3235,6 +3481,8 @@ pub enum GetBackupVaultNotificationsError {
     ResourceNotFound(String),
     /// <p>The request failed due to a temporary failure of the server.</p>
     ServiceUnavailable(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetBackupVaultNotificationsError {
@@ -3244,27 +3492,36 @@ impl GetBackupVaultNotificationsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         GetBackupVaultNotificationsError::InvalidParameterValue(err.msg),
+                        &res,
                     )
                 }
                 "MissingParameterValueException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         GetBackupVaultNotificationsError::MissingParameterValue(err.msg),
+                        &res,
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         GetBackupVaultNotificationsError::ResourceNotFound(err.msg),
+                        &res,
                     )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         GetBackupVaultNotificationsError::ServiceUnavailable(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        GetBackupVaultNotificationsError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -3284,12 +3541,14 @@ impl fmt::Display for GetBackupVaultNotificationsError {
             GetBackupVaultNotificationsError::ServiceUnavailable(ref cause) => {
                 write!(f, "{}", cause)
             }
+            GetBackupVaultNotificationsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetBackupVaultNotificationsError {}
 /// Errors returned by GetRecoveryPointRestoreMetadata
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetRecoveryPointRestoreMetadataError {
     /// <p>Indicates that something is wrong with a parameter's value. For example, the value is out of range.</p>
     InvalidParameterValue(String),
@@ -3299,6 +3558,8 @@ pub enum GetRecoveryPointRestoreMetadataError {
     ResourceNotFound(String),
     /// <p>The request failed due to a temporary failure of the server.</p>
     ServiceUnavailable(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetRecoveryPointRestoreMetadataError {
@@ -3308,27 +3569,36 @@ impl GetRecoveryPointRestoreMetadataError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         GetRecoveryPointRestoreMetadataError::InvalidParameterValue(err.msg),
+                        &res,
                     )
                 }
                 "MissingParameterValueException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         GetRecoveryPointRestoreMetadataError::MissingParameterValue(err.msg),
+                        &res,
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         GetRecoveryPointRestoreMetadataError::ResourceNotFound(err.msg),
+                        &res,
                     )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         GetRecoveryPointRestoreMetadataError::ServiceUnavailable(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        GetRecoveryPointRestoreMetadataError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -3350,15 +3620,21 @@ impl fmt::Display for GetRecoveryPointRestoreMetadataError {
             GetRecoveryPointRestoreMetadataError::ServiceUnavailable(ref cause) => {
                 write!(f, "{}", cause)
             }
+            GetRecoveryPointRestoreMetadataError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for GetRecoveryPointRestoreMetadataError {}
 /// Errors returned by GetSupportedResourceTypes
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetSupportedResourceTypesError {
     /// <p>The request failed due to a temporary failure of the server.</p>
     ServiceUnavailable(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetSupportedResourceTypesError {
@@ -3366,12 +3642,15 @@ impl GetSupportedResourceTypesError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         GetSupportedResourceTypesError::ServiceUnavailable(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(GetSupportedResourceTypesError::Unhandled, &res)
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -3382,12 +3661,14 @@ impl fmt::Display for GetSupportedResourceTypesError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             GetSupportedResourceTypesError::ServiceUnavailable(ref cause) => write!(f, "{}", cause),
+            GetSupportedResourceTypesError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetSupportedResourceTypesError {}
 /// Errors returned by ListBackupJobs
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListBackupJobsError {
     /// <p>Indicates that something is wrong with a parameter's value. For example, the value is out of range.</p>
     InvalidParameterValue(String),
@@ -3395,6 +3676,8 @@ pub enum ListBackupJobsError {
     InvalidRequest(String),
     /// <p>The request failed due to a temporary failure of the server.</p>
     ServiceUnavailable(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListBackupJobsError {
@@ -3402,18 +3685,22 @@ impl ListBackupJobsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(ListBackupJobsError::InvalidParameterValue(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListBackupJobsError::InvalidParameterValue(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(ListBackupJobsError::InvalidRequest(err.msg))
+                    return RusotoError::service(ListBackupJobsError::InvalidRequest(err.msg), &res)
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(ListBackupJobsError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        ListBackupJobsError::ServiceUnavailable(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListBackupJobsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3426,12 +3713,14 @@ impl fmt::Display for ListBackupJobsError {
             ListBackupJobsError::InvalidParameterValue(ref cause) => write!(f, "{}", cause),
             ListBackupJobsError::InvalidRequest(ref cause) => write!(f, "{}", cause),
             ListBackupJobsError::ServiceUnavailable(ref cause) => write!(f, "{}", cause),
+            ListBackupJobsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListBackupJobsError {}
 /// Errors returned by ListBackupPlanTemplates
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListBackupPlanTemplatesError {
     /// <p>Indicates that something is wrong with a parameter's value. For example, the value is out of range.</p>
     InvalidParameterValue(String),
@@ -3441,6 +3730,8 @@ pub enum ListBackupPlanTemplatesError {
     ResourceNotFound(String),
     /// <p>The request failed due to a temporary failure of the server.</p>
     ServiceUnavailable(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListBackupPlanTemplatesError {
@@ -3448,27 +3739,31 @@ impl ListBackupPlanTemplatesError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         ListBackupPlanTemplatesError::InvalidParameterValue(err.msg),
+                        &res,
                     )
                 }
                 "MissingParameterValueException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         ListBackupPlanTemplatesError::MissingParameterValue(err.msg),
+                        &res,
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListBackupPlanTemplatesError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListBackupPlanTemplatesError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(ListBackupPlanTemplatesError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListBackupPlanTemplatesError::ServiceUnavailable(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListBackupPlanTemplatesError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3486,12 +3781,14 @@ impl fmt::Display for ListBackupPlanTemplatesError {
             }
             ListBackupPlanTemplatesError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             ListBackupPlanTemplatesError::ServiceUnavailable(ref cause) => write!(f, "{}", cause),
+            ListBackupPlanTemplatesError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListBackupPlanTemplatesError {}
 /// Errors returned by ListBackupPlanVersions
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListBackupPlanVersionsError {
     /// <p>Indicates that something is wrong with a parameter's value. For example, the value is out of range.</p>
     InvalidParameterValue(String),
@@ -3501,6 +3798,8 @@ pub enum ListBackupPlanVersionsError {
     ResourceNotFound(String),
     /// <p>The request failed due to a temporary failure of the server.</p>
     ServiceUnavailable(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListBackupPlanVersionsError {
@@ -3508,27 +3807,31 @@ impl ListBackupPlanVersionsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         ListBackupPlanVersionsError::InvalidParameterValue(err.msg),
+                        &res,
                     )
                 }
                 "MissingParameterValueException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         ListBackupPlanVersionsError::MissingParameterValue(err.msg),
+                        &res,
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListBackupPlanVersionsError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListBackupPlanVersionsError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(ListBackupPlanVersionsError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListBackupPlanVersionsError::ServiceUnavailable(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListBackupPlanVersionsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3542,12 +3845,14 @@ impl fmt::Display for ListBackupPlanVersionsError {
             ListBackupPlanVersionsError::MissingParameterValue(ref cause) => write!(f, "{}", cause),
             ListBackupPlanVersionsError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             ListBackupPlanVersionsError::ServiceUnavailable(ref cause) => write!(f, "{}", cause),
+            ListBackupPlanVersionsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListBackupPlanVersionsError {}
 /// Errors returned by ListBackupPlans
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListBackupPlansError {
     /// <p>Indicates that something is wrong with a parameter's value. For example, the value is out of range.</p>
     InvalidParameterValue(String),
@@ -3557,6 +3862,8 @@ pub enum ListBackupPlansError {
     ResourceNotFound(String),
     /// <p>The request failed due to a temporary failure of the server.</p>
     ServiceUnavailable(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListBackupPlansError {
@@ -3564,23 +3871,31 @@ impl ListBackupPlansError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(ListBackupPlansError::InvalidParameterValue(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListBackupPlansError::InvalidParameterValue(err.msg),
+                        &res,
+                    )
                 }
                 "MissingParameterValueException" => {
-                    return RusotoError::Service(ListBackupPlansError::MissingParameterValue(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListBackupPlansError::MissingParameterValue(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListBackupPlansError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        ListBackupPlansError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(ListBackupPlansError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        ListBackupPlansError::ServiceUnavailable(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListBackupPlansError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3594,12 +3909,14 @@ impl fmt::Display for ListBackupPlansError {
             ListBackupPlansError::MissingParameterValue(ref cause) => write!(f, "{}", cause),
             ListBackupPlansError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             ListBackupPlansError::ServiceUnavailable(ref cause) => write!(f, "{}", cause),
+            ListBackupPlansError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListBackupPlansError {}
 /// Errors returned by ListBackupSelections
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListBackupSelectionsError {
     /// <p>Indicates that something is wrong with a parameter's value. For example, the value is out of range.</p>
     InvalidParameterValue(String),
@@ -3609,6 +3926,8 @@ pub enum ListBackupSelectionsError {
     ResourceNotFound(String),
     /// <p>The request failed due to a temporary failure of the server.</p>
     ServiceUnavailable(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListBackupSelectionsError {
@@ -3616,27 +3935,31 @@ impl ListBackupSelectionsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(ListBackupSelectionsError::InvalidParameterValue(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListBackupSelectionsError::InvalidParameterValue(err.msg),
+                        &res,
+                    )
                 }
                 "MissingParameterValueException" => {
-                    return RusotoError::Service(ListBackupSelectionsError::MissingParameterValue(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListBackupSelectionsError::MissingParameterValue(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListBackupSelectionsError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListBackupSelectionsError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(ListBackupSelectionsError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListBackupSelectionsError::ServiceUnavailable(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListBackupSelectionsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3650,12 +3973,14 @@ impl fmt::Display for ListBackupSelectionsError {
             ListBackupSelectionsError::MissingParameterValue(ref cause) => write!(f, "{}", cause),
             ListBackupSelectionsError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             ListBackupSelectionsError::ServiceUnavailable(ref cause) => write!(f, "{}", cause),
+            ListBackupSelectionsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListBackupSelectionsError {}
 /// Errors returned by ListBackupVaults
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListBackupVaultsError {
     /// <p>Indicates that something is wrong with a parameter's value. For example, the value is out of range.</p>
     InvalidParameterValue(String),
@@ -3665,6 +3990,8 @@ pub enum ListBackupVaultsError {
     ResourceNotFound(String),
     /// <p>The request failed due to a temporary failure of the server.</p>
     ServiceUnavailable(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListBackupVaultsError {
@@ -3672,23 +3999,31 @@ impl ListBackupVaultsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(ListBackupVaultsError::InvalidParameterValue(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListBackupVaultsError::InvalidParameterValue(err.msg),
+                        &res,
+                    )
                 }
                 "MissingParameterValueException" => {
-                    return RusotoError::Service(ListBackupVaultsError::MissingParameterValue(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListBackupVaultsError::MissingParameterValue(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListBackupVaultsError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        ListBackupVaultsError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(ListBackupVaultsError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        ListBackupVaultsError::ServiceUnavailable(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListBackupVaultsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3702,17 +4037,21 @@ impl fmt::Display for ListBackupVaultsError {
             ListBackupVaultsError::MissingParameterValue(ref cause) => write!(f, "{}", cause),
             ListBackupVaultsError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             ListBackupVaultsError::ServiceUnavailable(ref cause) => write!(f, "{}", cause),
+            ListBackupVaultsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListBackupVaultsError {}
 /// Errors returned by ListCopyJobs
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListCopyJobsError {
     /// <p>Indicates that something is wrong with a parameter's value. For example, the value is out of range.</p>
     InvalidParameterValue(String),
     /// <p>The request failed due to a temporary failure of the server.</p>
     ServiceUnavailable(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListCopyJobsError {
@@ -3720,13 +4059,19 @@ impl ListCopyJobsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(ListCopyJobsError::InvalidParameterValue(err.msg))
+                    return RusotoError::service(
+                        ListCopyJobsError::InvalidParameterValue(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(ListCopyJobsError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        ListCopyJobsError::ServiceUnavailable(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListCopyJobsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3738,17 +4083,21 @@ impl fmt::Display for ListCopyJobsError {
         match *self {
             ListCopyJobsError::InvalidParameterValue(ref cause) => write!(f, "{}", cause),
             ListCopyJobsError::ServiceUnavailable(ref cause) => write!(f, "{}", cause),
+            ListCopyJobsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListCopyJobsError {}
 /// Errors returned by ListProtectedResources
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListProtectedResourcesError {
     /// <p>Indicates that something is wrong with a parameter's value. For example, the value is out of range.</p>
     InvalidParameterValue(String),
     /// <p>The request failed due to a temporary failure of the server.</p>
     ServiceUnavailable(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListProtectedResourcesError {
@@ -3756,17 +4105,19 @@ impl ListProtectedResourcesError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         ListProtectedResourcesError::InvalidParameterValue(err.msg),
+                        &res,
                     )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(ListProtectedResourcesError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListProtectedResourcesError::ServiceUnavailable(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListProtectedResourcesError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3778,12 +4129,14 @@ impl fmt::Display for ListProtectedResourcesError {
         match *self {
             ListProtectedResourcesError::InvalidParameterValue(ref cause) => write!(f, "{}", cause),
             ListProtectedResourcesError::ServiceUnavailable(ref cause) => write!(f, "{}", cause),
+            ListProtectedResourcesError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListProtectedResourcesError {}
 /// Errors returned by ListRecoveryPointsByBackupVault
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListRecoveryPointsByBackupVaultError {
     /// <p>Indicates that something is wrong with a parameter's value. For example, the value is out of range.</p>
     InvalidParameterValue(String),
@@ -3793,6 +4146,8 @@ pub enum ListRecoveryPointsByBackupVaultError {
     ResourceNotFound(String),
     /// <p>The request failed due to a temporary failure of the server.</p>
     ServiceUnavailable(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListRecoveryPointsByBackupVaultError {
@@ -3802,27 +4157,36 @@ impl ListRecoveryPointsByBackupVaultError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         ListRecoveryPointsByBackupVaultError::InvalidParameterValue(err.msg),
+                        &res,
                     )
                 }
                 "MissingParameterValueException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         ListRecoveryPointsByBackupVaultError::MissingParameterValue(err.msg),
+                        &res,
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         ListRecoveryPointsByBackupVaultError::ResourceNotFound(err.msg),
+                        &res,
                     )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         ListRecoveryPointsByBackupVaultError::ServiceUnavailable(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        ListRecoveryPointsByBackupVaultError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -3844,12 +4208,16 @@ impl fmt::Display for ListRecoveryPointsByBackupVaultError {
             ListRecoveryPointsByBackupVaultError::ServiceUnavailable(ref cause) => {
                 write!(f, "{}", cause)
             }
+            ListRecoveryPointsByBackupVaultError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for ListRecoveryPointsByBackupVaultError {}
 /// Errors returned by ListRecoveryPointsByResource
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListRecoveryPointsByResourceError {
     /// <p>Indicates that something is wrong with a parameter's value. For example, the value is out of range.</p>
     InvalidParameterValue(String),
@@ -3859,6 +4227,8 @@ pub enum ListRecoveryPointsByResourceError {
     ResourceNotFound(String),
     /// <p>The request failed due to a temporary failure of the server.</p>
     ServiceUnavailable(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListRecoveryPointsByResourceError {
@@ -3868,27 +4238,36 @@ impl ListRecoveryPointsByResourceError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         ListRecoveryPointsByResourceError::InvalidParameterValue(err.msg),
+                        &res,
                     )
                 }
                 "MissingParameterValueException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         ListRecoveryPointsByResourceError::MissingParameterValue(err.msg),
+                        &res,
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         ListRecoveryPointsByResourceError::ResourceNotFound(err.msg),
+                        &res,
                     )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         ListRecoveryPointsByResourceError::ServiceUnavailable(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        ListRecoveryPointsByResourceError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -3910,12 +4289,14 @@ impl fmt::Display for ListRecoveryPointsByResourceError {
             ListRecoveryPointsByResourceError::ServiceUnavailable(ref cause) => {
                 write!(f, "{}", cause)
             }
+            ListRecoveryPointsByResourceError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListRecoveryPointsByResourceError {}
 /// Errors returned by ListRestoreJobs
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListRestoreJobsError {
     /// <p>Indicates that something is wrong with a parameter's value. For example, the value is out of range.</p>
     InvalidParameterValue(String),
@@ -3925,6 +4306,8 @@ pub enum ListRestoreJobsError {
     ResourceNotFound(String),
     /// <p>The request failed due to a temporary failure of the server.</p>
     ServiceUnavailable(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListRestoreJobsError {
@@ -3932,23 +4315,31 @@ impl ListRestoreJobsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(ListRestoreJobsError::InvalidParameterValue(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListRestoreJobsError::InvalidParameterValue(err.msg),
+                        &res,
+                    )
                 }
                 "MissingParameterValueException" => {
-                    return RusotoError::Service(ListRestoreJobsError::MissingParameterValue(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListRestoreJobsError::MissingParameterValue(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListRestoreJobsError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        ListRestoreJobsError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(ListRestoreJobsError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        ListRestoreJobsError::ServiceUnavailable(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListRestoreJobsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3962,12 +4353,14 @@ impl fmt::Display for ListRestoreJobsError {
             ListRestoreJobsError::MissingParameterValue(ref cause) => write!(f, "{}", cause),
             ListRestoreJobsError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             ListRestoreJobsError::ServiceUnavailable(ref cause) => write!(f, "{}", cause),
+            ListRestoreJobsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListRestoreJobsError {}
 /// Errors returned by ListTags
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListTagsError {
     /// <p>Indicates that something is wrong with a parameter's value. For example, the value is out of range.</p>
     InvalidParameterValue(String),
@@ -3977,6 +4370,8 @@ pub enum ListTagsError {
     ResourceNotFound(String),
     /// <p>The request failed due to a temporary failure of the server.</p>
     ServiceUnavailable(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListTagsError {
@@ -3984,19 +4379,25 @@ impl ListTagsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(ListTagsError::InvalidParameterValue(err.msg))
+                    return RusotoError::service(
+                        ListTagsError::InvalidParameterValue(err.msg),
+                        &res,
+                    )
                 }
                 "MissingParameterValueException" => {
-                    return RusotoError::Service(ListTagsError::MissingParameterValue(err.msg))
+                    return RusotoError::service(
+                        ListTagsError::MissingParameterValue(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListTagsError::ResourceNotFound(err.msg))
+                    return RusotoError::service(ListTagsError::ResourceNotFound(err.msg), &res)
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(ListTagsError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(ListTagsError::ServiceUnavailable(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListTagsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -4010,12 +4411,14 @@ impl fmt::Display for ListTagsError {
             ListTagsError::MissingParameterValue(ref cause) => write!(f, "{}", cause),
             ListTagsError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             ListTagsError::ServiceUnavailable(ref cause) => write!(f, "{}", cause),
+            ListTagsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListTagsError {}
 /// Errors returned by PutBackupVaultAccessPolicy
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum PutBackupVaultAccessPolicyError {
     /// <p>Indicates that something is wrong with a parameter's value. For example, the value is out of range.</p>
     InvalidParameterValue(String),
@@ -4025,6 +4428,8 @@ pub enum PutBackupVaultAccessPolicyError {
     ResourceNotFound(String),
     /// <p>The request failed due to a temporary failure of the server.</p>
     ServiceUnavailable(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl PutBackupVaultAccessPolicyError {
@@ -4034,27 +4439,33 @@ impl PutBackupVaultAccessPolicyError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         PutBackupVaultAccessPolicyError::InvalidParameterValue(err.msg),
+                        &res,
                     )
                 }
                 "MissingParameterValueException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         PutBackupVaultAccessPolicyError::MissingParameterValue(err.msg),
+                        &res,
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(PutBackupVaultAccessPolicyError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        PutBackupVaultAccessPolicyError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         PutBackupVaultAccessPolicyError::ServiceUnavailable(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(PutBackupVaultAccessPolicyError::Unhandled, &res)
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -4074,12 +4485,14 @@ impl fmt::Display for PutBackupVaultAccessPolicyError {
             PutBackupVaultAccessPolicyError::ServiceUnavailable(ref cause) => {
                 write!(f, "{}", cause)
             }
+            PutBackupVaultAccessPolicyError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for PutBackupVaultAccessPolicyError {}
 /// Errors returned by PutBackupVaultNotifications
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum PutBackupVaultNotificationsError {
     /// <p>Indicates that something is wrong with a parameter's value. For example, the value is out of range.</p>
     InvalidParameterValue(String),
@@ -4089,6 +4502,8 @@ pub enum PutBackupVaultNotificationsError {
     ResourceNotFound(String),
     /// <p>The request failed due to a temporary failure of the server.</p>
     ServiceUnavailable(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl PutBackupVaultNotificationsError {
@@ -4098,27 +4513,36 @@ impl PutBackupVaultNotificationsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         PutBackupVaultNotificationsError::InvalidParameterValue(err.msg),
+                        &res,
                     )
                 }
                 "MissingParameterValueException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         PutBackupVaultNotificationsError::MissingParameterValue(err.msg),
+                        &res,
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         PutBackupVaultNotificationsError::ResourceNotFound(err.msg),
+                        &res,
                     )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         PutBackupVaultNotificationsError::ServiceUnavailable(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        PutBackupVaultNotificationsError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -4138,12 +4562,14 @@ impl fmt::Display for PutBackupVaultNotificationsError {
             PutBackupVaultNotificationsError::ServiceUnavailable(ref cause) => {
                 write!(f, "{}", cause)
             }
+            PutBackupVaultNotificationsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for PutBackupVaultNotificationsError {}
 /// Errors returned by StartBackupJob
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum StartBackupJobError {
     /// <p>Indicates that something is wrong with a parameter's value. For example, the value is out of range.</p>
     InvalidParameterValue(String),
@@ -4155,6 +4581,8 @@ pub enum StartBackupJobError {
     ResourceNotFound(String),
     /// <p>The request failed due to a temporary failure of the server.</p>
     ServiceUnavailable(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl StartBackupJobError {
@@ -4162,26 +4590,34 @@ impl StartBackupJobError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(StartBackupJobError::InvalidParameterValue(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        StartBackupJobError::InvalidParameterValue(err.msg),
+                        &res,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(StartBackupJobError::LimitExceeded(err.msg))
+                    return RusotoError::service(StartBackupJobError::LimitExceeded(err.msg), &res)
                 }
                 "MissingParameterValueException" => {
-                    return RusotoError::Service(StartBackupJobError::MissingParameterValue(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        StartBackupJobError::MissingParameterValue(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(StartBackupJobError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        StartBackupJobError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(StartBackupJobError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        StartBackupJobError::ServiceUnavailable(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(StartBackupJobError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -4196,12 +4632,14 @@ impl fmt::Display for StartBackupJobError {
             StartBackupJobError::MissingParameterValue(ref cause) => write!(f, "{}", cause),
             StartBackupJobError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             StartBackupJobError::ServiceUnavailable(ref cause) => write!(f, "{}", cause),
+            StartBackupJobError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for StartBackupJobError {}
 /// Errors returned by StartCopyJob
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum StartCopyJobError {
     /// <p>Indicates that something is wrong with a parameter's value. For example, the value is out of range.</p>
     InvalidParameterValue(String),
@@ -4213,6 +4651,8 @@ pub enum StartCopyJobError {
     ResourceNotFound(String),
     /// <p>The request failed due to a temporary failure of the server.</p>
     ServiceUnavailable(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl StartCopyJobError {
@@ -4220,22 +4660,31 @@ impl StartCopyJobError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(StartCopyJobError::InvalidParameterValue(err.msg))
+                    return RusotoError::service(
+                        StartCopyJobError::InvalidParameterValue(err.msg),
+                        &res,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(StartCopyJobError::LimitExceeded(err.msg))
+                    return RusotoError::service(StartCopyJobError::LimitExceeded(err.msg), &res)
                 }
                 "MissingParameterValueException" => {
-                    return RusotoError::Service(StartCopyJobError::MissingParameterValue(err.msg))
+                    return RusotoError::service(
+                        StartCopyJobError::MissingParameterValue(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(StartCopyJobError::ResourceNotFound(err.msg))
+                    return RusotoError::service(StartCopyJobError::ResourceNotFound(err.msg), &res)
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(StartCopyJobError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        StartCopyJobError::ServiceUnavailable(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(StartCopyJobError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -4250,12 +4699,14 @@ impl fmt::Display for StartCopyJobError {
             StartCopyJobError::MissingParameterValue(ref cause) => write!(f, "{}", cause),
             StartCopyJobError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             StartCopyJobError::ServiceUnavailable(ref cause) => write!(f, "{}", cause),
+            StartCopyJobError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for StartCopyJobError {}
 /// Errors returned by StartRestoreJob
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum StartRestoreJobError {
     /// <p>Indicates that something is wrong with a parameter's value. For example, the value is out of range.</p>
     InvalidParameterValue(String),
@@ -4265,6 +4716,8 @@ pub enum StartRestoreJobError {
     ResourceNotFound(String),
     /// <p>The request failed due to a temporary failure of the server.</p>
     ServiceUnavailable(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl StartRestoreJobError {
@@ -4272,23 +4725,31 @@ impl StartRestoreJobError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(StartRestoreJobError::InvalidParameterValue(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        StartRestoreJobError::InvalidParameterValue(err.msg),
+                        &res,
+                    )
                 }
                 "MissingParameterValueException" => {
-                    return RusotoError::Service(StartRestoreJobError::MissingParameterValue(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        StartRestoreJobError::MissingParameterValue(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(StartRestoreJobError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        StartRestoreJobError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(StartRestoreJobError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        StartRestoreJobError::ServiceUnavailable(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(StartRestoreJobError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -4302,12 +4763,14 @@ impl fmt::Display for StartRestoreJobError {
             StartRestoreJobError::MissingParameterValue(ref cause) => write!(f, "{}", cause),
             StartRestoreJobError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             StartRestoreJobError::ServiceUnavailable(ref cause) => write!(f, "{}", cause),
+            StartRestoreJobError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for StartRestoreJobError {}
 /// Errors returned by StopBackupJob
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum StopBackupJobError {
     /// <p>Indicates that something is wrong with a parameter's value. For example, the value is out of range.</p>
     InvalidParameterValue(String),
@@ -4319,6 +4782,8 @@ pub enum StopBackupJobError {
     ResourceNotFound(String),
     /// <p>The request failed due to a temporary failure of the server.</p>
     ServiceUnavailable(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl StopBackupJobError {
@@ -4326,22 +4791,34 @@ impl StopBackupJobError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(StopBackupJobError::InvalidParameterValue(err.msg))
+                    return RusotoError::service(
+                        StopBackupJobError::InvalidParameterValue(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(StopBackupJobError::InvalidRequest(err.msg))
+                    return RusotoError::service(StopBackupJobError::InvalidRequest(err.msg), &res)
                 }
                 "MissingParameterValueException" => {
-                    return RusotoError::Service(StopBackupJobError::MissingParameterValue(err.msg))
+                    return RusotoError::service(
+                        StopBackupJobError::MissingParameterValue(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(StopBackupJobError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        StopBackupJobError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(StopBackupJobError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        StopBackupJobError::ServiceUnavailable(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(StopBackupJobError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -4356,12 +4833,14 @@ impl fmt::Display for StopBackupJobError {
             StopBackupJobError::MissingParameterValue(ref cause) => write!(f, "{}", cause),
             StopBackupJobError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             StopBackupJobError::ServiceUnavailable(ref cause) => write!(f, "{}", cause),
+            StopBackupJobError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for StopBackupJobError {}
 /// Errors returned by TagResource
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum TagResourceError {
     /// <p>Indicates that something is wrong with a parameter's value. For example, the value is out of range.</p>
     InvalidParameterValue(String),
@@ -4373,6 +4852,8 @@ pub enum TagResourceError {
     ResourceNotFound(String),
     /// <p>The request failed due to a temporary failure of the server.</p>
     ServiceUnavailable(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl TagResourceError {
@@ -4380,22 +4861,31 @@ impl TagResourceError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(TagResourceError::InvalidParameterValue(err.msg))
+                    return RusotoError::service(
+                        TagResourceError::InvalidParameterValue(err.msg),
+                        &res,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(TagResourceError::LimitExceeded(err.msg))
+                    return RusotoError::service(TagResourceError::LimitExceeded(err.msg), &res)
                 }
                 "MissingParameterValueException" => {
-                    return RusotoError::Service(TagResourceError::MissingParameterValue(err.msg))
+                    return RusotoError::service(
+                        TagResourceError::MissingParameterValue(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(TagResourceError::ResourceNotFound(err.msg))
+                    return RusotoError::service(TagResourceError::ResourceNotFound(err.msg), &res)
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(TagResourceError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        TagResourceError::ServiceUnavailable(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(TagResourceError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -4410,12 +4900,14 @@ impl fmt::Display for TagResourceError {
             TagResourceError::MissingParameterValue(ref cause) => write!(f, "{}", cause),
             TagResourceError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             TagResourceError::ServiceUnavailable(ref cause) => write!(f, "{}", cause),
+            TagResourceError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for TagResourceError {}
 /// Errors returned by UntagResource
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UntagResourceError {
     /// <p>Indicates that something is wrong with a parameter's value. For example, the value is out of range.</p>
     InvalidParameterValue(String),
@@ -4425,6 +4917,8 @@ pub enum UntagResourceError {
     ResourceNotFound(String),
     /// <p>The request failed due to a temporary failure of the server.</p>
     ServiceUnavailable(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UntagResourceError {
@@ -4432,19 +4926,31 @@ impl UntagResourceError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(UntagResourceError::InvalidParameterValue(err.msg))
+                    return RusotoError::service(
+                        UntagResourceError::InvalidParameterValue(err.msg),
+                        &res,
+                    )
                 }
                 "MissingParameterValueException" => {
-                    return RusotoError::Service(UntagResourceError::MissingParameterValue(err.msg))
+                    return RusotoError::service(
+                        UntagResourceError::MissingParameterValue(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UntagResourceError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        UntagResourceError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(UntagResourceError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        UntagResourceError::ServiceUnavailable(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UntagResourceError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -4458,12 +4964,14 @@ impl fmt::Display for UntagResourceError {
             UntagResourceError::MissingParameterValue(ref cause) => write!(f, "{}", cause),
             UntagResourceError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             UntagResourceError::ServiceUnavailable(ref cause) => write!(f, "{}", cause),
+            UntagResourceError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for UntagResourceError {}
 /// Errors returned by UpdateBackupPlan
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdateBackupPlanError {
     /// <p>Indicates that something is wrong with a parameter's value. For example, the value is out of range.</p>
     InvalidParameterValue(String),
@@ -4473,6 +4981,8 @@ pub enum UpdateBackupPlanError {
     ResourceNotFound(String),
     /// <p>The request failed due to a temporary failure of the server.</p>
     ServiceUnavailable(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdateBackupPlanError {
@@ -4480,23 +4990,31 @@ impl UpdateBackupPlanError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(UpdateBackupPlanError::InvalidParameterValue(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateBackupPlanError::InvalidParameterValue(err.msg),
+                        &res,
+                    )
                 }
                 "MissingParameterValueException" => {
-                    return RusotoError::Service(UpdateBackupPlanError::MissingParameterValue(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateBackupPlanError::MissingParameterValue(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateBackupPlanError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        UpdateBackupPlanError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(UpdateBackupPlanError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        UpdateBackupPlanError::ServiceUnavailable(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UpdateBackupPlanError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -4510,12 +5028,14 @@ impl fmt::Display for UpdateBackupPlanError {
             UpdateBackupPlanError::MissingParameterValue(ref cause) => write!(f, "{}", cause),
             UpdateBackupPlanError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             UpdateBackupPlanError::ServiceUnavailable(ref cause) => write!(f, "{}", cause),
+            UpdateBackupPlanError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for UpdateBackupPlanError {}
 /// Errors returned by UpdateRecoveryPointLifecycle
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdateRecoveryPointLifecycleError {
     /// <p>Indicates that something is wrong with a parameter's value. For example, the value is out of range.</p>
     InvalidParameterValue(String),
@@ -4525,6 +5045,8 @@ pub enum UpdateRecoveryPointLifecycleError {
     ResourceNotFound(String),
     /// <p>The request failed due to a temporary failure of the server.</p>
     ServiceUnavailable(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdateRecoveryPointLifecycleError {
@@ -4534,27 +5056,36 @@ impl UpdateRecoveryPointLifecycleError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         UpdateRecoveryPointLifecycleError::InvalidParameterValue(err.msg),
+                        &res,
                     )
                 }
                 "MissingParameterValueException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         UpdateRecoveryPointLifecycleError::MissingParameterValue(err.msg),
+                        &res,
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         UpdateRecoveryPointLifecycleError::ResourceNotFound(err.msg),
+                        &res,
                     )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         UpdateRecoveryPointLifecycleError::ServiceUnavailable(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        UpdateRecoveryPointLifecycleError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -4576,6 +5107,7 @@ impl fmt::Display for UpdateRecoveryPointLifecycleError {
             UpdateRecoveryPointLifecycleError::ServiceUnavailable(ref cause) => {
                 write!(f, "{}", cause)
             }
+            UpdateRecoveryPointLifecycleError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
@@ -4875,6 +5407,7 @@ impl BackupClient {
     /// Creates a client backed by the default tokio event loop.
     ///
     /// The client will use the default credentials provider and tls client.
+    #[cfg(not(target_arch = "wasm32"))]
     pub fn new(region: region::Region) -> BackupClient {
         BackupClient {
             client: Client::shared(),
@@ -4900,141 +5433,299 @@ impl BackupClient {
     pub fn new_with_client(client: Client, region: region::Region) -> BackupClient {
         BackupClient { client, region }
     }
+
+    /// Returns a builder of a client, setting any of its region, credentials provider,
+    /// dispatcher, retry policy, endpoint and timeouts.
+    pub fn builder() -> rusoto_core::ClientBuilder<BackupClient> {
+        rusoto_core::ClientBuilder::new(BackupClient::new_with_client)
+    }
+
+    /// Returns a clone of this client with the overrides of `config` applied to its
+    /// operations, sharing its connections.
+    pub fn customize(&self, config: &rusoto_core::OperationConfig) -> BackupClient {
+        BackupClient {
+            client: self.client.customize(config),
+            region: config.resolve_region(&self.region),
+        }
+    }
 }
 
-#[async_trait]
-impl Backup for BackupClient {
-    /// <p>Backup plans are documents that contain information that AWS Backup uses to schedule tasks that create recovery points of resources.</p> <p>If you call <code>CreateBackupPlan</code> with a plan that already exists, an <code>AlreadyExistsException</code> is returned.</p>
-    async fn create_backup_plan(
+impl BackupClient {
+    /// Calls `create_backup_plan`, also returning the metadata of the response.
+    pub async fn create_backup_plan_with_metadata(
         &self,
         input: CreateBackupPlanInput,
-    ) -> Result<CreateBackupPlanOutput, RusotoError<CreateBackupPlanError>> {
+    ) -> Result<(CreateBackupPlanOutput, ResponseMetadata), RusotoError<CreateBackupPlanError>>
+    {
+        let request = self.build_create_backup_plan_request(input);
+
+        let mut response = self
+            .client
+            .sign_and_dispatch(request)
+            .await
+            .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
+        if response.status.is_success() {
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<CreateBackupPlanOutput, _>()?;
+
+            Ok((result, metadata))
+        } else {
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(CreateBackupPlanError::from_response(response))
+        }
+    }
+
+    /// Presigns the request `create_backup_plan` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_backup_plan(
+        &self,
+        presigner: &rusoto_core::Presigner,
+        input: CreateBackupPlanInput,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_backup_plan_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_backup_plan_request(&self, input: CreateBackupPlanInput) -> SignedRequest {
         let request_uri = "/backup/plans/";
 
         let mut request = SignedRequest::new("PUT", "backup", &self.region, &request_uri);
+        request.set_operation("CreateBackupPlan");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `create_backup_selection`, also returning the metadata of the response.
+    pub async fn create_backup_selection_with_metadata(
+        &self,
+        input: CreateBackupSelectionInput,
+    ) -> Result<
+        (CreateBackupSelectionOutput, ResponseMetadata),
+        RusotoError<CreateBackupSelectionError>,
+    > {
+        let request = self.build_create_backup_selection_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<CreateBackupPlanOutput, _>()?;
+                .deserialize::<CreateBackupSelectionOutput, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(CreateBackupPlanError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(CreateBackupSelectionError::from_response(response))
         }
     }
 
-    /// <p>Creates a JSON document that specifies a set of resources to assign to a backup plan. Resources can be included by specifying patterns for a <code>ListOfTags</code> and selected <code>Resources</code>. </p> <p>For example, consider the following patterns:</p> <ul> <li> <p> <code>Resources: "arn:aws:ec2:region:account-id:volume/volume-id"</code> </p> </li> <li> <p> <code>ConditionKey:"department"</code> </p> <p> <code>ConditionValue:"finance"</code> </p> <p> <code>ConditionType:"STRINGEQUALS"</code> </p> </li> <li> <p> <code>ConditionKey:"importance"</code> </p> <p> <code>ConditionValue:"critical"</code> </p> <p> <code>ConditionType:"STRINGEQUALS"</code> </p> </li> </ul> <p>Using these patterns would back up all Amazon Elastic Block Store (Amazon EBS) volumes that are tagged as <code>"department=finance"</code>, <code>"importance=critical"</code>, in addition to an EBS volume with the specified volume Id.</p> <p>Resources and conditions are additive in that all resources that match the pattern are selected. This shouldn't be confused with a logical AND, where all conditions must match. The matching patterns are logically 'put together using the OR operator. In other words, all patterns that match are selected for backup.</p>
-    async fn create_backup_selection(
+    /// Presigns the request `create_backup_selection` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_backup_selection(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: CreateBackupSelectionInput,
-    ) -> Result<CreateBackupSelectionOutput, RusotoError<CreateBackupSelectionError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_backup_selection_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_backup_selection_request(
+        &self,
+        input: CreateBackupSelectionInput,
+    ) -> SignedRequest {
         let request_uri = format!(
             "/backup/plans/{backup_plan_id}/selections/",
             backup_plan_id = input.backup_plan_id
         );
 
         let mut request = SignedRequest::new("PUT", "backup", &self.region, &request_uri);
+        request.set_operation("CreateBackupSelection");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `create_backup_vault`, also returning the metadata of the response.
+    pub async fn create_backup_vault_with_metadata(
+        &self,
+        input: CreateBackupVaultInput,
+    ) -> Result<(CreateBackupVaultOutput, ResponseMetadata), RusotoError<CreateBackupVaultError>>
+    {
+        let request = self.build_create_backup_vault_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<CreateBackupSelectionOutput, _>()?;
+                .deserialize::<CreateBackupVaultOutput, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(CreateBackupSelectionError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(CreateBackupVaultError::from_response(response))
         }
     }
 
-    /// <p><p>Creates a logical container where backups are stored. A <code>CreateBackupVault</code> request includes a name, optionally one or more resource tags, an encryption key, and a request ID.</p> <note> <p>Sensitive data, such as passport numbers, should not be included the name of a backup vault.</p> </note></p>
-    async fn create_backup_vault(
+    /// Presigns the request `create_backup_vault` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_backup_vault(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: CreateBackupVaultInput,
-    ) -> Result<CreateBackupVaultOutput, RusotoError<CreateBackupVaultError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_backup_vault_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_backup_vault_request(&self, input: CreateBackupVaultInput) -> SignedRequest {
         let request_uri = format!(
             "/backup-vaults/{backup_vault_name}",
             backup_vault_name = input.backup_vault_name
         );
 
         let mut request = SignedRequest::new("PUT", "backup", &self.region, &request_uri);
+        request.set_operation("CreateBackupVault");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `delete_backup_plan`, also returning the metadata of the response.
+    pub async fn delete_backup_plan_with_metadata(
+        &self,
+        input: DeleteBackupPlanInput,
+    ) -> Result<(DeleteBackupPlanOutput, ResponseMetadata), RusotoError<DeleteBackupPlanError>>
+    {
+        let request = self.build_delete_backup_plan_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<CreateBackupVaultOutput, _>()?;
+                .deserialize::<DeleteBackupPlanOutput, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(CreateBackupVaultError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(DeleteBackupPlanError::from_response(response))
         }
     }
 
-    /// <p>Deletes a backup plan. A backup plan can only be deleted after all associated selections of resources have been deleted. Deleting a backup plan deletes the current version of a backup plan. Previous versions, if any, will still exist.</p>
-    async fn delete_backup_plan(
+    /// Presigns the request `delete_backup_plan` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_backup_plan(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteBackupPlanInput,
-    ) -> Result<DeleteBackupPlanOutput, RusotoError<DeleteBackupPlanError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_backup_plan_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_backup_plan_request(&self, input: DeleteBackupPlanInput) -> SignedRequest {
         let request_uri = format!(
             "/backup/plans/{backup_plan_id}",
             backup_plan_id = input.backup_plan_id
         );
 
         let mut request = SignedRequest::new("DELETE", "backup", &self.region, &request_uri);
+        request.set_operation("DeleteBackupPlan");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
+        request
+    }
+
+    /// Calls `delete_backup_selection`, also returning the metadata of the response.
+    pub async fn delete_backup_selection_with_metadata(
+        &self,
+        input: DeleteBackupSelectionInput,
+    ) -> Result<((), ResponseMetadata), RusotoError<DeleteBackupSelectionError>> {
+        let request = self.build_delete_backup_selection_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<DeleteBackupPlanOutput, _>()?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = ::std::mem::drop(response);
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteBackupPlanError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(DeleteBackupSelectionError::from_response(response))
         }
     }
 
-    /// <p>Deletes the resource selection associated with a backup plan that is specified by the <code>SelectionId</code>.</p>
-    async fn delete_backup_selection(
+    /// Presigns the request `delete_backup_selection` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_backup_selection(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteBackupSelectionInput,
-    ) -> Result<(), RusotoError<DeleteBackupSelectionError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_backup_selection_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_backup_selection_request(
+        &self,
+        input: DeleteBackupSelectionInput,
+    ) -> SignedRequest {
         let request_uri = format!(
             "/backup/plans/{backup_plan_id}/selections/{selection_id}",
             backup_plan_id = input.backup_plan_id,
@@ -5042,116 +5733,221 @@ impl Backup for BackupClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "backup", &self.region, &request_uri);
+        request.set_operation("DeleteBackupSelection");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
+        request
+    }
+
+    /// Calls `delete_backup_vault`, also returning the metadata of the response.
+    pub async fn delete_backup_vault_with_metadata(
+        &self,
+        input: DeleteBackupVaultInput,
+    ) -> Result<((), ResponseMetadata), RusotoError<DeleteBackupVaultError>> {
+        let request = self.build_delete_backup_vault_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = ::std::mem::drop(response);
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteBackupSelectionError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(DeleteBackupVaultError::from_response(response))
         }
     }
 
-    /// <p>Deletes the backup vault identified by its name. A vault can be deleted only if it is empty.</p>
-    async fn delete_backup_vault(
+    /// Presigns the request `delete_backup_vault` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_backup_vault(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteBackupVaultInput,
-    ) -> Result<(), RusotoError<DeleteBackupVaultError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_backup_vault_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_backup_vault_request(&self, input: DeleteBackupVaultInput) -> SignedRequest {
         let request_uri = format!(
             "/backup-vaults/{backup_vault_name}",
             backup_vault_name = input.backup_vault_name
         );
 
         let mut request = SignedRequest::new("DELETE", "backup", &self.region, &request_uri);
+        request.set_operation("DeleteBackupVault");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
+        request
+    }
+
+    /// Calls `delete_backup_vault_access_policy`, also returning the metadata of the response.
+    pub async fn delete_backup_vault_access_policy_with_metadata(
+        &self,
+        input: DeleteBackupVaultAccessPolicyInput,
+    ) -> Result<((), ResponseMetadata), RusotoError<DeleteBackupVaultAccessPolicyError>> {
+        let request = self.build_delete_backup_vault_access_policy_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = ::std::mem::drop(response);
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteBackupVaultError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(DeleteBackupVaultAccessPolicyError::from_response(response))
         }
     }
 
-    /// <p>Deletes the policy document that manages permissions on a backup vault.</p>
-    async fn delete_backup_vault_access_policy(
+    /// Presigns the request `delete_backup_vault_access_policy` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_backup_vault_access_policy(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteBackupVaultAccessPolicyInput,
-    ) -> Result<(), RusotoError<DeleteBackupVaultAccessPolicyError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_backup_vault_access_policy_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_backup_vault_access_policy_request(
+        &self,
+        input: DeleteBackupVaultAccessPolicyInput,
+    ) -> SignedRequest {
         let request_uri = format!(
             "/backup-vaults/{backup_vault_name}/access-policy",
             backup_vault_name = input.backup_vault_name
         );
 
         let mut request = SignedRequest::new("DELETE", "backup", &self.region, &request_uri);
+        request.set_operation("DeleteBackupVaultAccessPolicy");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
+        request
+    }
+
+    /// Calls `delete_backup_vault_notifications`, also returning the metadata of the response.
+    pub async fn delete_backup_vault_notifications_with_metadata(
+        &self,
+        input: DeleteBackupVaultNotificationsInput,
+    ) -> Result<((), ResponseMetadata), RusotoError<DeleteBackupVaultNotificationsError>> {
+        let request = self.build_delete_backup_vault_notifications_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = ::std::mem::drop(response);
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteBackupVaultAccessPolicyError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(DeleteBackupVaultNotificationsError::from_response(response))
         }
     }
 
-    /// <p>Deletes event notifications for the specified backup vault.</p>
-    async fn delete_backup_vault_notifications(
+    /// Presigns the request `delete_backup_vault_notifications` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_backup_vault_notifications(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteBackupVaultNotificationsInput,
-    ) -> Result<(), RusotoError<DeleteBackupVaultNotificationsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_backup_vault_notifications_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_backup_vault_notifications_request(
+        &self,
+        input: DeleteBackupVaultNotificationsInput,
+    ) -> SignedRequest {
         let request_uri = format!(
             "/backup-vaults/{backup_vault_name}/notification-configuration",
             backup_vault_name = input.backup_vault_name
         );
 
         let mut request = SignedRequest::new("DELETE", "backup", &self.region, &request_uri);
+        request.set_operation("DeleteBackupVaultNotifications");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
+        request
+    }
+
+    /// Calls `delete_recovery_point`, also returning the metadata of the response.
+    pub async fn delete_recovery_point_with_metadata(
+        &self,
+        input: DeleteRecoveryPointInput,
+    ) -> Result<((), ResponseMetadata), RusotoError<DeleteRecoveryPointError>> {
+        let request = self.build_delete_recovery_point_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = ::std::mem::drop(response);
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteBackupVaultNotificationsError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(DeleteRecoveryPointError::from_response(response))
         }
     }
 
-    /// <p>Deletes the recovery point specified by a recovery point ID.</p>
-    async fn delete_recovery_point(
+    /// Presigns the request `delete_recovery_point` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_recovery_point(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteRecoveryPointInput,
-    ) -> Result<(), RusotoError<DeleteRecoveryPointError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_recovery_point_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_recovery_point_request(
+        &self,
+        input: DeleteRecoveryPointInput,
+    ) -> SignedRequest {
         let request_uri = format!(
             "/backup-vaults/{backup_vault_name}/recovery-points/{recovery_point_arn}",
             backup_vault_name = input.backup_vault_name,
@@ -5159,146 +5955,284 @@ impl Backup for BackupClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "backup", &self.region, &request_uri);
+        request.set_operation("DeleteRecoveryPoint");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
+        request
+    }
+
+    /// Calls `describe_backup_job`, also returning the metadata of the response.
+    pub async fn describe_backup_job_with_metadata(
+        &self,
+        input: DescribeBackupJobInput,
+    ) -> Result<(DescribeBackupJobOutput, ResponseMetadata), RusotoError<DescribeBackupJobError>>
+    {
+        let request = self.build_describe_backup_job_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            let result = ::std::mem::drop(response);
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DescribeBackupJobOutput, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteRecoveryPointError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(DescribeBackupJobError::from_response(response))
         }
     }
 
-    /// <p>Returns metadata associated with creating a backup of a resource.</p>
-    async fn describe_backup_job(
+    /// Presigns the request `describe_backup_job` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_backup_job(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeBackupJobInput,
-    ) -> Result<DescribeBackupJobOutput, RusotoError<DescribeBackupJobError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_backup_job_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_backup_job_request(&self, input: DescribeBackupJobInput) -> SignedRequest {
         let request_uri = format!(
             "/backup-jobs/{backup_job_id}",
             backup_job_id = input.backup_job_id
         );
 
         let mut request = SignedRequest::new("GET", "backup", &self.region, &request_uri);
+        request.set_operation("DescribeBackupJob");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
+        request
+    }
+
+    /// Calls `describe_backup_vault`, also returning the metadata of the response.
+    pub async fn describe_backup_vault_with_metadata(
+        &self,
+        input: DescribeBackupVaultInput,
+    ) -> Result<(DescribeBackupVaultOutput, ResponseMetadata), RusotoError<DescribeBackupVaultError>>
+    {
+        let request = self.build_describe_backup_vault_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<DescribeBackupJobOutput, _>()?;
+                .deserialize::<DescribeBackupVaultOutput, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeBackupJobError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(DescribeBackupVaultError::from_response(response))
         }
     }
 
-    /// <p>Returns metadata about a backup vault specified by its name.</p>
-    async fn describe_backup_vault(
+    /// Presigns the request `describe_backup_vault` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_backup_vault(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeBackupVaultInput,
-    ) -> Result<DescribeBackupVaultOutput, RusotoError<DescribeBackupVaultError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_backup_vault_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_backup_vault_request(
+        &self,
+        input: DescribeBackupVaultInput,
+    ) -> SignedRequest {
         let request_uri = format!(
             "/backup-vaults/{backup_vault_name}",
             backup_vault_name = input.backup_vault_name
         );
 
         let mut request = SignedRequest::new("GET", "backup", &self.region, &request_uri);
+        request.set_operation("DescribeBackupVault");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
+        request
+    }
+
+    /// Calls `describe_copy_job`, also returning the metadata of the response.
+    pub async fn describe_copy_job_with_metadata(
+        &self,
+        input: DescribeCopyJobInput,
+    ) -> Result<(DescribeCopyJobOutput, ResponseMetadata), RusotoError<DescribeCopyJobError>> {
+        let request = self.build_describe_copy_job_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<DescribeBackupVaultOutput, _>()?;
+                .deserialize::<DescribeCopyJobOutput, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeBackupVaultError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(DescribeCopyJobError::from_response(response))
         }
     }
 
-    /// <p>Returns metadata associated with creating a copy of a resource.</p>
-    async fn describe_copy_job(
+    /// Presigns the request `describe_copy_job` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_copy_job(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeCopyJobInput,
-    ) -> Result<DescribeCopyJobOutput, RusotoError<DescribeCopyJobError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_copy_job_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_copy_job_request(&self, input: DescribeCopyJobInput) -> SignedRequest {
         let request_uri = format!("/copy-jobs/{copy_job_id}", copy_job_id = input.copy_job_id);
 
         let mut request = SignedRequest::new("GET", "backup", &self.region, &request_uri);
+        request.set_operation("DescribeCopyJob");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
+        request
+    }
+
+    /// Calls `describe_protected_resource`, also returning the metadata of the response.
+    pub async fn describe_protected_resource_with_metadata(
+        &self,
+        input: DescribeProtectedResourceInput,
+    ) -> Result<
+        (DescribeProtectedResourceOutput, ResponseMetadata),
+        RusotoError<DescribeProtectedResourceError>,
+    > {
+        let request = self.build_describe_protected_resource_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<DescribeCopyJobOutput, _>()?;
+                .deserialize::<DescribeProtectedResourceOutput, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeCopyJobError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(DescribeProtectedResourceError::from_response(response))
         }
     }
 
-    /// <p>Returns information about a saved resource, including the last time it was backed-up, its Amazon Resource Name (ARN), and the AWS service type of the saved resource.</p>
-    async fn describe_protected_resource(
+    /// Presigns the request `describe_protected_resource` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_protected_resource(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeProtectedResourceInput,
-    ) -> Result<DescribeProtectedResourceOutput, RusotoError<DescribeProtectedResourceError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_protected_resource_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_protected_resource_request(
+        &self,
+        input: DescribeProtectedResourceInput,
+    ) -> SignedRequest {
         let request_uri = format!(
             "/resources/{resource_arn}",
             resource_arn = input.resource_arn
         );
 
         let mut request = SignedRequest::new("GET", "backup", &self.region, &request_uri);
+        request.set_operation("DescribeProtectedResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
+        request
+    }
+
+    /// Calls `describe_recovery_point`, also returning the metadata of the response.
+    pub async fn describe_recovery_point_with_metadata(
+        &self,
+        input: DescribeRecoveryPointInput,
+    ) -> Result<
+        (DescribeRecoveryPointOutput, ResponseMetadata),
+        RusotoError<DescribeRecoveryPointError>,
+    > {
+        let request = self.build_describe_recovery_point_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<DescribeProtectedResourceOutput, _>()?;
+                .deserialize::<DescribeRecoveryPointOutput, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeProtectedResourceError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(DescribeRecoveryPointError::from_response(response))
         }
     }
 
-    /// <p>Returns metadata associated with a recovery point, including ID, status, encryption, and lifecycle.</p>
-    async fn describe_recovery_point(
+    /// Presigns the request `describe_recovery_point` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_recovery_point(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeRecoveryPointInput,
-    ) -> Result<DescribeRecoveryPointOutput, RusotoError<DescribeRecoveryPointError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_recovery_point_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_recovery_point_request(
+        &self,
+        input: DescribeRecoveryPointInput,
+    ) -> SignedRequest {
         let request_uri = format!(
             "/backup-vaults/{backup_vault_name}/recovery-points/{recovery_point_arn}",
             backup_vault_name = input.backup_vault_name,
@@ -5306,96 +6240,176 @@ impl Backup for BackupClient {
         );
 
         let mut request = SignedRequest::new("GET", "backup", &self.region, &request_uri);
+        request.set_operation("DescribeRecoveryPoint");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
+        request
+    }
+
+    /// Calls `describe_restore_job`, also returning the metadata of the response.
+    pub async fn describe_restore_job_with_metadata(
+        &self,
+        input: DescribeRestoreJobInput,
+    ) -> Result<(DescribeRestoreJobOutput, ResponseMetadata), RusotoError<DescribeRestoreJobError>>
+    {
+        let request = self.build_describe_restore_job_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<DescribeRecoveryPointOutput, _>()?;
+                .deserialize::<DescribeRestoreJobOutput, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeRecoveryPointError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(DescribeRestoreJobError::from_response(response))
         }
     }
 
-    /// <p>Returns metadata associated with a restore job that is specified by a job ID.</p>
-    async fn describe_restore_job(
+    /// Presigns the request `describe_restore_job` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_restore_job(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeRestoreJobInput,
-    ) -> Result<DescribeRestoreJobOutput, RusotoError<DescribeRestoreJobError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_restore_job_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_restore_job_request(&self, input: DescribeRestoreJobInput) -> SignedRequest {
         let request_uri = format!(
             "/restore-jobs/{restore_job_id}",
             restore_job_id = input.restore_job_id
         );
 
         let mut request = SignedRequest::new("GET", "backup", &self.region, &request_uri);
+        request.set_operation("DescribeRestoreJob");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
+        request
+    }
+
+    /// Calls `export_backup_plan_template`, also returning the metadata of the response.
+    pub async fn export_backup_plan_template_with_metadata(
+        &self,
+        input: ExportBackupPlanTemplateInput,
+    ) -> Result<
+        (ExportBackupPlanTemplateOutput, ResponseMetadata),
+        RusotoError<ExportBackupPlanTemplateError>,
+    > {
+        let request = self.build_export_backup_plan_template_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<DescribeRestoreJobOutput, _>()?;
+                .deserialize::<ExportBackupPlanTemplateOutput, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeRestoreJobError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(ExportBackupPlanTemplateError::from_response(response))
         }
     }
 
-    /// <p>Returns the backup plan that is specified by the plan ID as a backup template.</p>
-    async fn export_backup_plan_template(
+    /// Presigns the request `export_backup_plan_template` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_export_backup_plan_template(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ExportBackupPlanTemplateInput,
-    ) -> Result<ExportBackupPlanTemplateOutput, RusotoError<ExportBackupPlanTemplateError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_export_backup_plan_template_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_export_backup_plan_template_request(
+        &self,
+        input: ExportBackupPlanTemplateInput,
+    ) -> SignedRequest {
         let request_uri = format!(
             "/backup/plans/{backup_plan_id}/toTemplate/",
             backup_plan_id = input.backup_plan_id
         );
 
         let mut request = SignedRequest::new("GET", "backup", &self.region, &request_uri);
+        request.set_operation("ExportBackupPlanTemplate");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
+        request
+    }
+
+    /// Calls `get_backup_plan`, also returning the metadata of the response.
+    pub async fn get_backup_plan_with_metadata(
+        &self,
+        input: GetBackupPlanInput,
+    ) -> Result<(GetBackupPlanOutput, ResponseMetadata), RusotoError<GetBackupPlanError>> {
+        let request = self.build_get_backup_plan_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<ExportBackupPlanTemplateOutput, _>()?;
+                .deserialize::<GetBackupPlanOutput, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(ExportBackupPlanTemplateError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(GetBackupPlanError::from_response(response))
         }
     }
 
-    /// <p>Returns the body of a backup plan in JSON format, in addition to plan metadata.</p>
-    async fn get_backup_plan(
+    /// Presigns the request `get_backup_plan` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_backup_plan(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetBackupPlanInput,
-    ) -> Result<GetBackupPlanOutput, RusotoError<GetBackupPlanError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_backup_plan_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_backup_plan_request(&self, input: GetBackupPlanInput) -> SignedRequest {
         let request_uri = format!(
             "/backup/plans/{backup_plan_id}/",
             backup_plan_id = input.backup_plan_id
         );
 
         let mut request = SignedRequest::new("GET", "backup", &self.region, &request_uri);
+        request.set_operation("GetBackupPlan");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -5404,88 +6418,172 @@ impl Backup for BackupClient {
         }
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `get_backup_plan_from_json`, also returning the metadata of the response.
+    pub async fn get_backup_plan_from_json_with_metadata(
+        &self,
+        input: GetBackupPlanFromJSONInput,
+    ) -> Result<
+        (GetBackupPlanFromJSONOutput, ResponseMetadata),
+        RusotoError<GetBackupPlanFromJSONError>,
+    > {
+        let request = self.build_get_backup_plan_from_json_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<GetBackupPlanOutput, _>()?;
+                .deserialize::<GetBackupPlanFromJSONOutput, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(GetBackupPlanError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(GetBackupPlanFromJSONError::from_response(response))
         }
     }
 
-    /// <p>Returns a valid JSON document specifying a backup plan or an error.</p>
-    async fn get_backup_plan_from_json(
+    /// Presigns the request `get_backup_plan_from_json` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_backup_plan_from_json(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetBackupPlanFromJSONInput,
-    ) -> Result<GetBackupPlanFromJSONOutput, RusotoError<GetBackupPlanFromJSONError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_backup_plan_from_json_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_backup_plan_from_json_request(
+        &self,
+        input: GetBackupPlanFromJSONInput,
+    ) -> SignedRequest {
         let request_uri = "/backup/template/json/toPlan";
 
         let mut request = SignedRequest::new("POST", "backup", &self.region, &request_uri);
+        request.set_operation("GetBackupPlanFromJSON");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `get_backup_plan_from_template`, also returning the metadata of the response.
+    pub async fn get_backup_plan_from_template_with_metadata(
+        &self,
+        input: GetBackupPlanFromTemplateInput,
+    ) -> Result<
+        (GetBackupPlanFromTemplateOutput, ResponseMetadata),
+        RusotoError<GetBackupPlanFromTemplateError>,
+    > {
+        let request = self.build_get_backup_plan_from_template_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<GetBackupPlanFromJSONOutput, _>()?;
+                .deserialize::<GetBackupPlanFromTemplateOutput, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(GetBackupPlanFromJSONError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(GetBackupPlanFromTemplateError::from_response(response))
         }
     }
 
-    /// <p>Returns the template specified by its <code>templateId</code> as a backup plan.</p>
-    async fn get_backup_plan_from_template(
+    /// Presigns the request `get_backup_plan_from_template` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_backup_plan_from_template(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetBackupPlanFromTemplateInput,
-    ) -> Result<GetBackupPlanFromTemplateOutput, RusotoError<GetBackupPlanFromTemplateError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_backup_plan_from_template_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_backup_plan_from_template_request(
+        &self,
+        input: GetBackupPlanFromTemplateInput,
+    ) -> SignedRequest {
         let request_uri = format!(
             "/backup/template/plans/{template_id}/toPlan",
             template_id = input.backup_plan_template_id
         );
 
         let mut request = SignedRequest::new("GET", "backup", &self.region, &request_uri);
+        request.set_operation("GetBackupPlanFromTemplate");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
+        request
+    }
+
+    /// Calls `get_backup_selection`, also returning the metadata of the response.
+    pub async fn get_backup_selection_with_metadata(
+        &self,
+        input: GetBackupSelectionInput,
+    ) -> Result<(GetBackupSelectionOutput, ResponseMetadata), RusotoError<GetBackupSelectionError>>
+    {
+        let request = self.build_get_backup_selection_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<GetBackupPlanFromTemplateOutput, _>()?;
+                .deserialize::<GetBackupSelectionOutput, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(GetBackupPlanFromTemplateError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(GetBackupSelectionError::from_response(response))
         }
     }
 
-    /// <p>Returns selection metadata and a document in JSON format that specifies a list of resources that are associated with a backup plan.</p>
-    async fn get_backup_selection(
+    /// Presigns the request `get_backup_selection` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_backup_selection(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetBackupSelectionInput,
-    ) -> Result<GetBackupSelectionOutput, RusotoError<GetBackupSelectionError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_backup_selection_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_backup_selection_request(&self, input: GetBackupSelectionInput) -> SignedRequest {
         let request_uri = format!(
             "/backup/plans/{backup_plan_id}/selections/{selection_id}",
             backup_plan_id = input.backup_plan_id,
@@ -5493,153 +6591,289 @@ impl Backup for BackupClient {
         );
 
         let mut request = SignedRequest::new("GET", "backup", &self.region, &request_uri);
+        request.set_operation("GetBackupSelection");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
+        request
+    }
+
+    /// Calls `get_backup_vault_access_policy`, also returning the metadata of the response.
+    pub async fn get_backup_vault_access_policy_with_metadata(
+        &self,
+        input: GetBackupVaultAccessPolicyInput,
+    ) -> Result<
+        (GetBackupVaultAccessPolicyOutput, ResponseMetadata),
+        RusotoError<GetBackupVaultAccessPolicyError>,
+    > {
+        let request = self.build_get_backup_vault_access_policy_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<GetBackupSelectionOutput, _>()?;
+                .deserialize::<GetBackupVaultAccessPolicyOutput, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(GetBackupSelectionError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(GetBackupVaultAccessPolicyError::from_response(response))
         }
     }
 
-    /// <p>Returns the access policy document that is associated with the named backup vault.</p>
-    async fn get_backup_vault_access_policy(
+    /// Presigns the request `get_backup_vault_access_policy` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_backup_vault_access_policy(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetBackupVaultAccessPolicyInput,
-    ) -> Result<GetBackupVaultAccessPolicyOutput, RusotoError<GetBackupVaultAccessPolicyError>>
-    {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_backup_vault_access_policy_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_backup_vault_access_policy_request(
+        &self,
+        input: GetBackupVaultAccessPolicyInput,
+    ) -> SignedRequest {
         let request_uri = format!(
             "/backup-vaults/{backup_vault_name}/access-policy",
             backup_vault_name = input.backup_vault_name
         );
 
         let mut request = SignedRequest::new("GET", "backup", &self.region, &request_uri);
+        request.set_operation("GetBackupVaultAccessPolicy");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
+        request
+    }
+
+    /// Calls `get_backup_vault_notifications`, also returning the metadata of the response.
+    pub async fn get_backup_vault_notifications_with_metadata(
+        &self,
+        input: GetBackupVaultNotificationsInput,
+    ) -> Result<
+        (GetBackupVaultNotificationsOutput, ResponseMetadata),
+        RusotoError<GetBackupVaultNotificationsError>,
+    > {
+        let request = self.build_get_backup_vault_notifications_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<GetBackupVaultAccessPolicyOutput, _>()?;
+                .deserialize::<GetBackupVaultNotificationsOutput, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(GetBackupVaultAccessPolicyError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(GetBackupVaultNotificationsError::from_response(response))
         }
     }
 
-    /// <p>Returns event notifications for the specified backup vault.</p>
-    async fn get_backup_vault_notifications(
+    /// Presigns the request `get_backup_vault_notifications` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_backup_vault_notifications(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetBackupVaultNotificationsInput,
-    ) -> Result<GetBackupVaultNotificationsOutput, RusotoError<GetBackupVaultNotificationsError>>
-    {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_backup_vault_notifications_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_backup_vault_notifications_request(
+        &self,
+        input: GetBackupVaultNotificationsInput,
+    ) -> SignedRequest {
         let request_uri = format!(
             "/backup-vaults/{backup_vault_name}/notification-configuration",
             backup_vault_name = input.backup_vault_name
         );
 
         let mut request = SignedRequest::new("GET", "backup", &self.region, &request_uri);
+        request.set_operation("GetBackupVaultNotifications");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
+        request
+    }
+
+    /// Calls `get_recovery_point_restore_metadata`, also returning the metadata of the response.
+    pub async fn get_recovery_point_restore_metadata_with_metadata(
+        &self,
+        input: GetRecoveryPointRestoreMetadataInput,
+    ) -> Result<
+        (GetRecoveryPointRestoreMetadataOutput, ResponseMetadata),
+        RusotoError<GetRecoveryPointRestoreMetadataError>,
+    > {
+        let request = self.build_get_recovery_point_restore_metadata_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<GetBackupVaultNotificationsOutput, _>()?;
+                .deserialize::<GetRecoveryPointRestoreMetadataOutput, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(GetBackupVaultNotificationsError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(GetRecoveryPointRestoreMetadataError::from_response(
+                response,
+            ))
         }
     }
 
-    /// <p>Returns a set of metadata key-value pairs that were used to create the backup.</p>
-    async fn get_recovery_point_restore_metadata(
+    /// Presigns the request `get_recovery_point_restore_metadata` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_recovery_point_restore_metadata(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetRecoveryPointRestoreMetadataInput,
-    ) -> Result<
-        GetRecoveryPointRestoreMetadataOutput,
-        RusotoError<GetRecoveryPointRestoreMetadataError>,
-    > {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_recovery_point_restore_metadata_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_recovery_point_restore_metadata_request(
+        &self,
+        input: GetRecoveryPointRestoreMetadataInput,
+    ) -> SignedRequest {
         let request_uri = format!("/backup-vaults/{backup_vault_name}/recovery-points/{recovery_point_arn}/restore-metadata", backup_vault_name = input.backup_vault_name, recovery_point_arn = input.recovery_point_arn);
 
         let mut request = SignedRequest::new("GET", "backup", &self.region, &request_uri);
+        request.set_operation("GetRecoveryPointRestoreMetadata");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
+        request
+    }
+
+    /// Calls `get_supported_resource_types`, also returning the metadata of the response.
+    pub async fn get_supported_resource_types_with_metadata(
+        &self,
+    ) -> Result<
+        (GetSupportedResourceTypesOutput, ResponseMetadata),
+        RusotoError<GetSupportedResourceTypesError>,
+    > {
+        let request = self.build_get_supported_resource_types_request();
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<GetRecoveryPointRestoreMetadataOutput, _>()?;
+                .deserialize::<GetSupportedResourceTypesOutput, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(GetRecoveryPointRestoreMetadataError::from_response(
-                response,
-            ))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(GetSupportedResourceTypesError::from_response(response))
         }
     }
 
-    /// <p>Returns the AWS resource types supported by AWS Backup.</p>
-    async fn get_supported_resource_types(
+    /// Presigns the request `get_supported_resource_types` would send with `presigner` instead of sending it.
+    pub async fn presign_get_supported_resource_types(
         &self,
-    ) -> Result<GetSupportedResourceTypesOutput, RusotoError<GetSupportedResourceTypesError>> {
+        presigner: &rusoto_core::Presigner,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_supported_resource_types_request();
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_supported_resource_types_request(&self) -> SignedRequest {
         let request_uri = "/supported-resource-types";
 
         let mut request = SignedRequest::new("GET", "backup", &self.region, &request_uri);
+        request.set_operation("GetSupportedResourceTypes");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
+        request
+    }
+
+    /// Calls `list_backup_jobs`, also returning the metadata of the response.
+    pub async fn list_backup_jobs_with_metadata(
+        &self,
+        input: ListBackupJobsInput,
+    ) -> Result<(ListBackupJobsOutput, ResponseMetadata), RusotoError<ListBackupJobsError>> {
+        let request = self.build_list_backup_jobs_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<GetSupportedResourceTypesOutput, _>()?;
+                .deserialize::<ListBackupJobsOutput, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(GetSupportedResourceTypesError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(ListBackupJobsError::from_response(response))
         }
     }
 
-    /// <p>Returns metadata about your backup jobs.</p>
-    async fn list_backup_jobs(
+    /// Presigns the request `list_backup_jobs` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_backup_jobs(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListBackupJobsInput,
-    ) -> Result<ListBackupJobsOutput, RusotoError<ListBackupJobsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_backup_jobs_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_backup_jobs_request(&self, input: ListBackupJobsInput) -> SignedRequest {
         let request_uri = "/backup-jobs/";
 
         let mut request = SignedRequest::new("GET", "backup", &self.region, &request_uri);
+        request.set_operation("ListBackupJobs");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -5669,31 +6903,61 @@ impl Backup for BackupClient {
         }
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `list_backup_plan_templates`, also returning the metadata of the response.
+    pub async fn list_backup_plan_templates_with_metadata(
+        &self,
+        input: ListBackupPlanTemplatesInput,
+    ) -> Result<
+        (ListBackupPlanTemplatesOutput, ResponseMetadata),
+        RusotoError<ListBackupPlanTemplatesError>,
+    > {
+        let request = self.build_list_backup_plan_templates_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListBackupJobsOutput, _>()?;
+                .deserialize::<ListBackupPlanTemplatesOutput, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(ListBackupJobsError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(ListBackupPlanTemplatesError::from_response(response))
         }
     }
 
-    /// <p>Returns metadata of your saved backup plan templates, including the template ID, name, and the creation and deletion dates.</p>
-    async fn list_backup_plan_templates(
+    /// Presigns the request `list_backup_plan_templates` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_backup_plan_templates(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListBackupPlanTemplatesInput,
-    ) -> Result<ListBackupPlanTemplatesOutput, RusotoError<ListBackupPlanTemplatesError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_backup_plan_templates_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_backup_plan_templates_request(
+        &self,
+        input: ListBackupPlanTemplatesInput,
+    ) -> SignedRequest {
         let request_uri = "/backup/template/plans";
 
         let mut request = SignedRequest::new("GET", "backup", &self.region, &request_uri);
+        request.set_operation("ListBackupPlanTemplates");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -5705,34 +6969,64 @@ impl Backup for BackupClient {
         }
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `list_backup_plan_versions`, also returning the metadata of the response.
+    pub async fn list_backup_plan_versions_with_metadata(
+        &self,
+        input: ListBackupPlanVersionsInput,
+    ) -> Result<
+        (ListBackupPlanVersionsOutput, ResponseMetadata),
+        RusotoError<ListBackupPlanVersionsError>,
+    > {
+        let request = self.build_list_backup_plan_versions_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListBackupPlanTemplatesOutput, _>()?;
+                .deserialize::<ListBackupPlanVersionsOutput, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(ListBackupPlanTemplatesError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(ListBackupPlanVersionsError::from_response(response))
         }
     }
 
-    /// <p>Returns version metadata of your backup plans, including Amazon Resource Names (ARNs), backup plan IDs, creation and deletion dates, plan names, and version IDs.</p>
-    async fn list_backup_plan_versions(
+    /// Presigns the request `list_backup_plan_versions` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_backup_plan_versions(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListBackupPlanVersionsInput,
-    ) -> Result<ListBackupPlanVersionsOutput, RusotoError<ListBackupPlanVersionsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_backup_plan_versions_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_backup_plan_versions_request(
+        &self,
+        input: ListBackupPlanVersionsInput,
+    ) -> SignedRequest {
         let request_uri = format!(
             "/backup/plans/{backup_plan_id}/versions/",
             backup_plan_id = input.backup_plan_id
         );
 
         let mut request = SignedRequest::new("GET", "backup", &self.region, &request_uri);
+        request.set_operation("ListBackupPlanVersions");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -5744,31 +7038,55 @@ impl Backup for BackupClient {
         }
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `list_backup_plans`, also returning the metadata of the response.
+    pub async fn list_backup_plans_with_metadata(
+        &self,
+        input: ListBackupPlansInput,
+    ) -> Result<(ListBackupPlansOutput, ResponseMetadata), RusotoError<ListBackupPlansError>> {
+        let request = self.build_list_backup_plans_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListBackupPlanVersionsOutput, _>()?;
+                .deserialize::<ListBackupPlansOutput, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(ListBackupPlanVersionsError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(ListBackupPlansError::from_response(response))
         }
     }
 
-    /// <p>Returns metadata of your saved backup plans, including Amazon Resource Names (ARNs), plan IDs, creation and deletion dates, version IDs, plan names, and creator request IDs.</p>
-    async fn list_backup_plans(
+    /// Presigns the request `list_backup_plans` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_backup_plans(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListBackupPlansInput,
-    ) -> Result<ListBackupPlansOutput, RusotoError<ListBackupPlansError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_backup_plans_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_backup_plans_request(&self, input: ListBackupPlansInput) -> SignedRequest {
         let request_uri = "/backup/plans/";
 
         let mut request = SignedRequest::new("GET", "backup", &self.region, &request_uri);
+        request.set_operation("ListBackupPlans");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -5783,34 +7101,64 @@ impl Backup for BackupClient {
         }
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `list_backup_selections`, also returning the metadata of the response.
+    pub async fn list_backup_selections_with_metadata(
+        &self,
+        input: ListBackupSelectionsInput,
+    ) -> Result<
+        (ListBackupSelectionsOutput, ResponseMetadata),
+        RusotoError<ListBackupSelectionsError>,
+    > {
+        let request = self.build_list_backup_selections_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListBackupPlansOutput, _>()?;
+                .deserialize::<ListBackupSelectionsOutput, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(ListBackupPlansError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(ListBackupSelectionsError::from_response(response))
         }
     }
 
-    /// <p>Returns an array containing metadata of the resources associated with the target backup plan.</p>
-    async fn list_backup_selections(
+    /// Presigns the request `list_backup_selections` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_backup_selections(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListBackupSelectionsInput,
-    ) -> Result<ListBackupSelectionsOutput, RusotoError<ListBackupSelectionsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_backup_selections_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_backup_selections_request(
+        &self,
+        input: ListBackupSelectionsInput,
+    ) -> SignedRequest {
         let request_uri = format!(
             "/backup/plans/{backup_plan_id}/selections/",
             backup_plan_id = input.backup_plan_id
         );
 
         let mut request = SignedRequest::new("GET", "backup", &self.region, &request_uri);
+        request.set_operation("ListBackupSelections");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -5822,31 +7170,56 @@ impl Backup for BackupClient {
         }
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `list_backup_vaults`, also returning the metadata of the response.
+    pub async fn list_backup_vaults_with_metadata(
+        &self,
+        input: ListBackupVaultsInput,
+    ) -> Result<(ListBackupVaultsOutput, ResponseMetadata), RusotoError<ListBackupVaultsError>>
+    {
+        let request = self.build_list_backup_vaults_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListBackupSelectionsOutput, _>()?;
+                .deserialize::<ListBackupVaultsOutput, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(ListBackupSelectionsError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(ListBackupVaultsError::from_response(response))
         }
     }
 
-    /// <p>Returns a list of recovery point storage containers along with information about them.</p>
-    async fn list_backup_vaults(
+    /// Presigns the request `list_backup_vaults` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_backup_vaults(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListBackupVaultsInput,
-    ) -> Result<ListBackupVaultsOutput, RusotoError<ListBackupVaultsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_backup_vaults_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_backup_vaults_request(&self, input: ListBackupVaultsInput) -> SignedRequest {
         let request_uri = "/backup-vaults/";
 
         let mut request = SignedRequest::new("GET", "backup", &self.region, &request_uri);
+        request.set_operation("ListBackupVaults");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -5858,31 +7231,55 @@ impl Backup for BackupClient {
         }
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `list_copy_jobs`, also returning the metadata of the response.
+    pub async fn list_copy_jobs_with_metadata(
+        &self,
+        input: ListCopyJobsInput,
+    ) -> Result<(ListCopyJobsOutput, ResponseMetadata), RusotoError<ListCopyJobsError>> {
+        let request = self.build_list_copy_jobs_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListBackupVaultsOutput, _>()?;
+                .deserialize::<ListCopyJobsOutput, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(ListBackupVaultsError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(ListCopyJobsError::from_response(response))
         }
     }
 
-    /// <p>Returns metadata about your copy jobs.</p>
-    async fn list_copy_jobs(
+    /// Presigns the request `list_copy_jobs` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_copy_jobs(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListCopyJobsInput,
-    ) -> Result<ListCopyJobsOutput, RusotoError<ListCopyJobsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_copy_jobs_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_copy_jobs_request(&self, input: ListCopyJobsInput) -> SignedRequest {
         let request_uri = "/copy-jobs/";
 
         let mut request = SignedRequest::new("GET", "backup", &self.region, &request_uri);
+        request.set_operation("ListCopyJobs");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -5912,31 +7309,61 @@ impl Backup for BackupClient {
         }
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `list_protected_resources`, also returning the metadata of the response.
+    pub async fn list_protected_resources_with_metadata(
+        &self,
+        input: ListProtectedResourcesInput,
+    ) -> Result<
+        (ListProtectedResourcesOutput, ResponseMetadata),
+        RusotoError<ListProtectedResourcesError>,
+    > {
+        let request = self.build_list_protected_resources_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListCopyJobsOutput, _>()?;
+                .deserialize::<ListProtectedResourcesOutput, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(ListCopyJobsError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(ListProtectedResourcesError::from_response(response))
         }
     }
 
-    /// <p>Returns an array of resources successfully backed up by AWS Backup, including the time the resource was saved, an Amazon Resource Name (ARN) of the resource, and a resource type.</p>
-    async fn list_protected_resources(
+    /// Presigns the request `list_protected_resources` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_protected_resources(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListProtectedResourcesInput,
-    ) -> Result<ListProtectedResourcesOutput, RusotoError<ListProtectedResourcesError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_protected_resources_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_protected_resources_request(
+        &self,
+        input: ListProtectedResourcesInput,
+    ) -> SignedRequest {
         let request_uri = "/resources/";
 
         let mut request = SignedRequest::new("GET", "backup", &self.region, &request_uri);
+        request.set_operation("ListProtectedResources");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -5948,37 +7375,66 @@ impl Backup for BackupClient {
         }
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `list_recovery_points_by_backup_vault`, also returning the metadata of the response.
+    pub async fn list_recovery_points_by_backup_vault_with_metadata(
+        &self,
+        input: ListRecoveryPointsByBackupVaultInput,
+    ) -> Result<
+        (ListRecoveryPointsByBackupVaultOutput, ResponseMetadata),
+        RusotoError<ListRecoveryPointsByBackupVaultError>,
+    > {
+        let request = self.build_list_recovery_points_by_backup_vault_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListProtectedResourcesOutput, _>()?;
+                .deserialize::<ListRecoveryPointsByBackupVaultOutput, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(ListProtectedResourcesError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(ListRecoveryPointsByBackupVaultError::from_response(
+                response,
+            ))
         }
     }
 
-    /// <p>Returns detailed information about the recovery points stored in a backup vault.</p>
-    async fn list_recovery_points_by_backup_vault(
+    /// Presigns the request `list_recovery_points_by_backup_vault` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_recovery_points_by_backup_vault(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListRecoveryPointsByBackupVaultInput,
-    ) -> Result<
-        ListRecoveryPointsByBackupVaultOutput,
-        RusotoError<ListRecoveryPointsByBackupVaultError>,
-    > {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_recovery_points_by_backup_vault_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_recovery_points_by_backup_vault_request(
+        &self,
+        input: ListRecoveryPointsByBackupVaultInput,
+    ) -> SignedRequest {
         let request_uri = format!(
             "/backup-vaults/{backup_vault_name}/recovery-points/",
             backup_vault_name = input.backup_vault_name
         );
 
         let mut request = SignedRequest::new("GET", "backup", &self.region, &request_uri);
+        request.set_operation("ListRecoveryPointsByBackupVault");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -6005,37 +7461,64 @@ impl Backup for BackupClient {
         }
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `list_recovery_points_by_resource`, also returning the metadata of the response.
+    pub async fn list_recovery_points_by_resource_with_metadata(
+        &self,
+        input: ListRecoveryPointsByResourceInput,
+    ) -> Result<
+        (ListRecoveryPointsByResourceOutput, ResponseMetadata),
+        RusotoError<ListRecoveryPointsByResourceError>,
+    > {
+        let request = self.build_list_recovery_points_by_resource_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListRecoveryPointsByBackupVaultOutput, _>()?;
+                .deserialize::<ListRecoveryPointsByResourceOutput, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(ListRecoveryPointsByBackupVaultError::from_response(
-                response,
-            ))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(ListRecoveryPointsByResourceError::from_response(response))
         }
     }
 
-    /// <p>Returns detailed information about recovery points of the type specified by a resource Amazon Resource Name (ARN).</p>
-    async fn list_recovery_points_by_resource(
+    /// Presigns the request `list_recovery_points_by_resource` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_recovery_points_by_resource(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListRecoveryPointsByResourceInput,
-    ) -> Result<ListRecoveryPointsByResourceOutput, RusotoError<ListRecoveryPointsByResourceError>>
-    {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_recovery_points_by_resource_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_recovery_points_by_resource_request(
+        &self,
+        input: ListRecoveryPointsByResourceInput,
+    ) -> SignedRequest {
         let request_uri = format!(
             "/resources/{resource_arn}/recovery-points/",
             resource_arn = input.resource_arn
         );
 
         let mut request = SignedRequest::new("GET", "backup", &self.region, &request_uri);
+        request.set_operation("ListRecoveryPointsByResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -6047,31 +7530,55 @@ impl Backup for BackupClient {
         }
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `list_restore_jobs`, also returning the metadata of the response.
+    pub async fn list_restore_jobs_with_metadata(
+        &self,
+        input: ListRestoreJobsInput,
+    ) -> Result<(ListRestoreJobsOutput, ResponseMetadata), RusotoError<ListRestoreJobsError>> {
+        let request = self.build_list_restore_jobs_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListRecoveryPointsByResourceOutput, _>()?;
+                .deserialize::<ListRestoreJobsOutput, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(ListRecoveryPointsByResourceError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(ListRestoreJobsError::from_response(response))
         }
     }
 
-    /// <p>Returns a list of jobs that AWS Backup initiated to restore a saved resource, including metadata about the recovery process.</p>
-    async fn list_restore_jobs(
+    /// Presigns the request `list_restore_jobs` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_restore_jobs(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListRestoreJobsInput,
-    ) -> Result<ListRestoreJobsOutput, RusotoError<ListRestoreJobsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_restore_jobs_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_restore_jobs_request(&self, input: ListRestoreJobsInput) -> SignedRequest {
         let request_uri = "/restore-jobs/";
 
         let mut request = SignedRequest::new("GET", "backup", &self.region, &request_uri);
+        request.set_operation("ListRestoreJobs");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -6083,31 +7590,55 @@ impl Backup for BackupClient {
         }
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `list_tags`, also returning the metadata of the response.
+    pub async fn list_tags_with_metadata(
+        &self,
+        input: ListTagsInput,
+    ) -> Result<(ListTagsOutput, ResponseMetadata), RusotoError<ListTagsError>> {
+        let request = self.build_list_tags_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListRestoreJobsOutput, _>()?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result =
+                proto::json::ResponsePayload::new(&response).deserialize::<ListTagsOutput, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(ListRestoreJobsError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(ListTagsError::from_response(response))
         }
     }
 
-    /// <p>Returns a list of key-value pairs assigned to a target recovery point, backup plan, or backup vault.</p>
-    async fn list_tags(
+    /// Presigns the request `list_tags` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_tags(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListTagsInput,
-    ) -> Result<ListTagsOutput, RusotoError<ListTagsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_tags_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_tags_request(&self, input: ListTagsInput) -> SignedRequest {
         let request_uri = format!("/tags/{resource_arn}/", resource_arn = input.resource_arn);
 
         let mut request = SignedRequest::new("GET", "backup", &self.region, &request_uri);
+        request.set_operation("ListTags");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -6119,303 +7650,554 @@ impl Backup for BackupClient {
         }
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `put_backup_vault_access_policy`, also returning the metadata of the response.
+    pub async fn put_backup_vault_access_policy_with_metadata(
+        &self,
+        input: PutBackupVaultAccessPolicyInput,
+    ) -> Result<((), ResponseMetadata), RusotoError<PutBackupVaultAccessPolicyError>> {
+        let request = self.build_put_backup_vault_access_policy_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            let result =
-                proto::json::ResponsePayload::new(&response).deserialize::<ListTagsOutput, _>()?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = ::std::mem::drop(response);
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(ListTagsError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(PutBackupVaultAccessPolicyError::from_response(response))
         }
     }
 
-    /// <p>Sets a resource-based policy that is used to manage access permissions on the target backup vault. Requires a backup vault name and an access policy document in JSON format.</p>
-    async fn put_backup_vault_access_policy(
+    /// Presigns the request `put_backup_vault_access_policy` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_put_backup_vault_access_policy(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: PutBackupVaultAccessPolicyInput,
-    ) -> Result<(), RusotoError<PutBackupVaultAccessPolicyError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_put_backup_vault_access_policy_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_put_backup_vault_access_policy_request(
+        &self,
+        input: PutBackupVaultAccessPolicyInput,
+    ) -> SignedRequest {
         let request_uri = format!(
             "/backup-vaults/{backup_vault_name}/access-policy",
             backup_vault_name = input.backup_vault_name
         );
 
         let mut request = SignedRequest::new("PUT", "backup", &self.region, &request_uri);
+        request.set_operation("PutBackupVaultAccessPolicy");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `put_backup_vault_notifications`, also returning the metadata of the response.
+    pub async fn put_backup_vault_notifications_with_metadata(
+        &self,
+        input: PutBackupVaultNotificationsInput,
+    ) -> Result<((), ResponseMetadata), RusotoError<PutBackupVaultNotificationsError>> {
+        let request = self.build_put_backup_vault_notifications_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = ::std::mem::drop(response);
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(PutBackupVaultAccessPolicyError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(PutBackupVaultNotificationsError::from_response(response))
         }
     }
 
-    /// <p>Turns on notifications on a backup vault for the specified topic and events.</p>
-    async fn put_backup_vault_notifications(
+    /// Presigns the request `put_backup_vault_notifications` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_put_backup_vault_notifications(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: PutBackupVaultNotificationsInput,
-    ) -> Result<(), RusotoError<PutBackupVaultNotificationsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_put_backup_vault_notifications_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_put_backup_vault_notifications_request(
+        &self,
+        input: PutBackupVaultNotificationsInput,
+    ) -> SignedRequest {
         let request_uri = format!(
             "/backup-vaults/{backup_vault_name}/notification-configuration",
             backup_vault_name = input.backup_vault_name
         );
 
         let mut request = SignedRequest::new("PUT", "backup", &self.region, &request_uri);
+        request.set_operation("PutBackupVaultNotifications");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `start_backup_job`, also returning the metadata of the response.
+    pub async fn start_backup_job_with_metadata(
+        &self,
+        input: StartBackupJobInput,
+    ) -> Result<(StartBackupJobOutput, ResponseMetadata), RusotoError<StartBackupJobError>> {
+        let request = self.build_start_backup_job_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            let result = ::std::mem::drop(response);
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<StartBackupJobOutput, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(PutBackupVaultNotificationsError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(StartBackupJobError::from_response(response))
         }
     }
 
-    /// <p>Starts a job to create a one-time backup of the specified resource.</p>
-    async fn start_backup_job(
+    /// Presigns the request `start_backup_job` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_start_backup_job(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: StartBackupJobInput,
-    ) -> Result<StartBackupJobOutput, RusotoError<StartBackupJobError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_start_backup_job_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_start_backup_job_request(&self, input: StartBackupJobInput) -> SignedRequest {
         let request_uri = "/backup-jobs";
 
         let mut request = SignedRequest::new("PUT", "backup", &self.region, &request_uri);
+        request.set_operation("StartBackupJob");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `start_copy_job`, also returning the metadata of the response.
+    pub async fn start_copy_job_with_metadata(
+        &self,
+        input: StartCopyJobInput,
+    ) -> Result<(StartCopyJobOutput, ResponseMetadata), RusotoError<StartCopyJobError>> {
+        let request = self.build_start_copy_job_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<StartBackupJobOutput, _>()?;
+                .deserialize::<StartCopyJobOutput, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(StartBackupJobError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(StartCopyJobError::from_response(response))
         }
     }
 
-    /// <p>Starts a job to create a one-time copy of the specified resource.</p>
-    async fn start_copy_job(
+    /// Presigns the request `start_copy_job` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_start_copy_job(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: StartCopyJobInput,
-    ) -> Result<StartCopyJobOutput, RusotoError<StartCopyJobError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_start_copy_job_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_start_copy_job_request(&self, input: StartCopyJobInput) -> SignedRequest {
         let request_uri = "/copy-jobs";
 
         let mut request = SignedRequest::new("PUT", "backup", &self.region, &request_uri);
+        request.set_operation("StartCopyJob");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `start_restore_job`, also returning the metadata of the response.
+    pub async fn start_restore_job_with_metadata(
+        &self,
+        input: StartRestoreJobInput,
+    ) -> Result<(StartRestoreJobOutput, ResponseMetadata), RusotoError<StartRestoreJobError>> {
+        let request = self.build_start_restore_job_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<StartCopyJobOutput, _>()?;
+                .deserialize::<StartRestoreJobOutput, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(StartCopyJobError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(StartRestoreJobError::from_response(response))
         }
     }
 
-    /// <p>Recovers the saved resource identified by an Amazon Resource Name (ARN). </p> <p>If the resource ARN is included in the request, then the last complete backup of that resource is recovered. If the ARN of a recovery point is supplied, then that recovery point is restored.</p>
-    async fn start_restore_job(
+    /// Presigns the request `start_restore_job` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_start_restore_job(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: StartRestoreJobInput,
-    ) -> Result<StartRestoreJobOutput, RusotoError<StartRestoreJobError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_start_restore_job_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_start_restore_job_request(&self, input: StartRestoreJobInput) -> SignedRequest {
         let request_uri = "/restore-jobs";
 
         let mut request = SignedRequest::new("PUT", "backup", &self.region, &request_uri);
+        request.set_operation("StartRestoreJob");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `stop_backup_job`, also returning the metadata of the response.
+    pub async fn stop_backup_job_with_metadata(
+        &self,
+        input: StopBackupJobInput,
+    ) -> Result<((), ResponseMetadata), RusotoError<StopBackupJobError>> {
+        let request = self.build_stop_backup_job_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<StartRestoreJobOutput, _>()?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = ::std::mem::drop(response);
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(StartRestoreJobError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(StopBackupJobError::from_response(response))
         }
     }
 
-    /// <p>Attempts to cancel a job to create a one-time backup of a resource.</p>
-    async fn stop_backup_job(
+    /// Presigns the request `stop_backup_job` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_stop_backup_job(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: StopBackupJobInput,
-    ) -> Result<(), RusotoError<StopBackupJobError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_stop_backup_job_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_stop_backup_job_request(&self, input: StopBackupJobInput) -> SignedRequest {
         let request_uri = format!(
             "/backup-jobs/{backup_job_id}",
             backup_job_id = input.backup_job_id
         );
 
         let mut request = SignedRequest::new("POST", "backup", &self.region, &request_uri);
+        request.set_operation("StopBackupJob");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
+        request
+    }
+
+    /// Calls `tag_resource`, also returning the metadata of the response.
+    pub async fn tag_resource_with_metadata(
+        &self,
+        input: TagResourceInput,
+    ) -> Result<((), ResponseMetadata), RusotoError<TagResourceError>> {
+        let request = self.build_tag_resource_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = ::std::mem::drop(response);
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(StopBackupJobError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(TagResourceError::from_response(response))
         }
     }
 
-    /// <p>Assigns a set of key-value pairs to a recovery point, backup plan, or backup vault identified by an Amazon Resource Name (ARN).</p>
-    async fn tag_resource(
+    /// Presigns the request `tag_resource` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_tag_resource(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: TagResourceInput,
-    ) -> Result<(), RusotoError<TagResourceError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_tag_resource_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_tag_resource_request(&self, input: TagResourceInput) -> SignedRequest {
         let request_uri = format!("/tags/{resource_arn}", resource_arn = input.resource_arn);
 
         let mut request = SignedRequest::new("POST", "backup", &self.region, &request_uri);
+        request.set_operation("TagResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `untag_resource`, also returning the metadata of the response.
+    pub async fn untag_resource_with_metadata(
+        &self,
+        input: UntagResourceInput,
+    ) -> Result<((), ResponseMetadata), RusotoError<UntagResourceError>> {
+        let request = self.build_untag_resource_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = ::std::mem::drop(response);
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(TagResourceError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(UntagResourceError::from_response(response))
         }
     }
 
-    /// <p>Removes a set of key-value pairs from a recovery point, backup plan, or backup vault identified by an Amazon Resource Name (ARN)</p>
-    async fn untag_resource(
+    /// Presigns the request `untag_resource` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_untag_resource(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UntagResourceInput,
-    ) -> Result<(), RusotoError<UntagResourceError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_untag_resource_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_untag_resource_request(&self, input: UntagResourceInput) -> SignedRequest {
         let request_uri = format!("/untag/{resource_arn}", resource_arn = input.resource_arn);
 
         let mut request = SignedRequest::new("POST", "backup", &self.region, &request_uri);
+        request.set_operation("UntagResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `update_backup_plan`, also returning the metadata of the response.
+    pub async fn update_backup_plan_with_metadata(
+        &self,
+        input: UpdateBackupPlanInput,
+    ) -> Result<(UpdateBackupPlanOutput, ResponseMetadata), RusotoError<UpdateBackupPlanError>>
+    {
+        let request = self.build_update_backup_plan_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            let result = ::std::mem::drop(response);
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<UpdateBackupPlanOutput, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(UntagResourceError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(UpdateBackupPlanError::from_response(response))
         }
     }
 
-    /// <p>Replaces the body of a saved backup plan identified by its <code>backupPlanId</code> with the input document in JSON format. The new version is uniquely identified by a <code>VersionId</code>.</p>
-    async fn update_backup_plan(
+    /// Presigns the request `update_backup_plan` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_backup_plan(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UpdateBackupPlanInput,
-    ) -> Result<UpdateBackupPlanOutput, RusotoError<UpdateBackupPlanError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_backup_plan_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_backup_plan_request(&self, input: UpdateBackupPlanInput) -> SignedRequest {
         let request_uri = format!(
             "/backup/plans/{backup_plan_id}",
             backup_plan_id = input.backup_plan_id
         );
 
         let mut request = SignedRequest::new("POST", "backup", &self.region, &request_uri);
+        request.set_operation("UpdateBackupPlan");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `update_recovery_point_lifecycle`, also returning the metadata of the response.
+    pub async fn update_recovery_point_lifecycle_with_metadata(
+        &self,
+        input: UpdateRecoveryPointLifecycleInput,
+    ) -> Result<
+        (UpdateRecoveryPointLifecycleOutput, ResponseMetadata),
+        RusotoError<UpdateRecoveryPointLifecycleError>,
+    > {
+        let request = self.build_update_recovery_point_lifecycle_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<UpdateBackupPlanOutput, _>()?;
+                .deserialize::<UpdateRecoveryPointLifecycleOutput, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(UpdateBackupPlanError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(UpdateRecoveryPointLifecycleError::from_response(response))
         }
     }
 
-    /// <p>Sets the transition lifecycle of a recovery point.</p> <p>The lifecycle defines when a protected resource is transitioned to cold storage and when it expires. AWS Backup transitions and expires backups automatically according to the lifecycle that you define. </p> <p>Backups transitioned to cold storage must be stored in cold storage for a minimum of 90 days. Therefore, the “expire after days” setting must be 90 days greater than the “transition to cold after days” setting. The “transition to cold after days” setting cannot be changed after a backup has been transitioned to cold. </p>
-    async fn update_recovery_point_lifecycle(
+    /// Presigns the request `update_recovery_point_lifecycle` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_recovery_point_lifecycle(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UpdateRecoveryPointLifecycleInput,
-    ) -> Result<UpdateRecoveryPointLifecycleOutput, RusotoError<UpdateRecoveryPointLifecycleError>>
-    {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_recovery_point_lifecycle_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_recovery_point_lifecycle_request(
+        &self,
+        input: UpdateRecoveryPointLifecycleInput,
+    ) -> SignedRequest {
         let request_uri = format!(
             "/backup-vaults/{backup_vault_name}/recovery-points/{recovery_point_arn}",
             backup_vault_name = input.backup_vault_name,
@@ -6423,25 +8205,908 @@ impl Backup for BackupClient {
         );
 
         let mut request = SignedRequest::new("POST", "backup", &self.region, &request_uri);
+        request.set_operation("UpdateRecoveryPointLifecycle");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
-        let mut response = self
-            .client
-            .sign_and_dispatch(request)
+        request
+    }
+
+    /// The pages of `ListBackupJobs` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `MaxResults` of `input`.
+    pub fn list_backup_jobs_pages(
+        &self,
+        input: ListBackupJobsInput,
+    ) -> impl futures::Stream<Item = Result<ListBackupJobsOutput, RusotoError<ListBackupJobsError>>> + Send
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: BackupClient, input| async move { client.list_backup_jobs(input).await },
+            |input: &ListBackupJobsInput, output: &ListBackupJobsOutput| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListBackupJobsInput {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `BackupJobs` of every page of `ListBackupJobs` results for `input`, as `list_backup_jobs_pages` requests them.
+    pub fn list_backup_jobs_stream(
+        &self,
+        input: ListBackupJobsInput,
+    ) -> impl futures::Stream<Item = Result<BackupJob, RusotoError<ListBackupJobsError>>> + Send
+    {
+        rusoto_core::pagination::items(self.list_backup_jobs_pages(input), |page| {
+            page.backup_jobs.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `ListBackupPlanTemplates` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `MaxResults` of `input`.
+    pub fn list_backup_plan_templates_pages(
+        &self,
+        input: ListBackupPlanTemplatesInput,
+    ) -> impl futures::Stream<
+        Item = Result<ListBackupPlanTemplatesOutput, RusotoError<ListBackupPlanTemplatesError>>,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: BackupClient, input| async move { client.list_backup_plan_templates(input).await },
+            |input: &ListBackupPlanTemplatesInput, output: &ListBackupPlanTemplatesOutput| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListBackupPlanTemplatesInput {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `BackupPlanTemplatesList` of every page of `ListBackupPlanTemplates` results for `input`, as `list_backup_plan_templates_pages` requests them.
+    pub fn list_backup_plan_templates_stream(
+        &self,
+        input: ListBackupPlanTemplatesInput,
+    ) -> impl futures::Stream<
+        Item = Result<BackupPlanTemplatesListMember, RusotoError<ListBackupPlanTemplatesError>>,
+    > + Send {
+        rusoto_core::pagination::items(self.list_backup_plan_templates_pages(input), |page| {
+            page.backup_plan_templates_list.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `ListBackupPlanVersions` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `MaxResults` of `input`.
+    pub fn list_backup_plan_versions_pages(
+        &self,
+        input: ListBackupPlanVersionsInput,
+    ) -> impl futures::Stream<
+        Item = Result<ListBackupPlanVersionsOutput, RusotoError<ListBackupPlanVersionsError>>,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: BackupClient, input| async move { client.list_backup_plan_versions(input).await },
+            |input: &ListBackupPlanVersionsInput, output: &ListBackupPlanVersionsOutput| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListBackupPlanVersionsInput {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `BackupPlanVersionsList` of every page of `ListBackupPlanVersions` results for `input`, as `list_backup_plan_versions_pages` requests them.
+    pub fn list_backup_plan_versions_stream(
+        &self,
+        input: ListBackupPlanVersionsInput,
+    ) -> impl futures::Stream<
+        Item = Result<BackupPlansListMember, RusotoError<ListBackupPlanVersionsError>>,
+    > + Send {
+        rusoto_core::pagination::items(self.list_backup_plan_versions_pages(input), |page| {
+            page.backup_plan_versions_list.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `ListBackupPlans` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `MaxResults` of `input`.
+    pub fn list_backup_plans_pages(
+        &self,
+        input: ListBackupPlansInput,
+    ) -> impl futures::Stream<Item = Result<ListBackupPlansOutput, RusotoError<ListBackupPlansError>>>
+           + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: BackupClient, input| async move { client.list_backup_plans(input).await },
+            |input: &ListBackupPlansInput, output: &ListBackupPlansOutput| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListBackupPlansInput {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `BackupPlansList` of every page of `ListBackupPlans` results for `input`, as `list_backup_plans_pages` requests them.
+    pub fn list_backup_plans_stream(
+        &self,
+        input: ListBackupPlansInput,
+    ) -> impl futures::Stream<Item = Result<BackupPlansListMember, RusotoError<ListBackupPlansError>>>
+           + Send {
+        rusoto_core::pagination::items(self.list_backup_plans_pages(input), |page| {
+            page.backup_plans_list.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `ListBackupSelections` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `MaxResults` of `input`.
+    pub fn list_backup_selections_pages(
+        &self,
+        input: ListBackupSelectionsInput,
+    ) -> impl futures::Stream<
+        Item = Result<ListBackupSelectionsOutput, RusotoError<ListBackupSelectionsError>>,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: BackupClient, input| async move { client.list_backup_selections(input).await },
+            |input: &ListBackupSelectionsInput, output: &ListBackupSelectionsOutput| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListBackupSelectionsInput {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `BackupSelectionsList` of every page of `ListBackupSelections` results for `input`, as `list_backup_selections_pages` requests them.
+    pub fn list_backup_selections_stream(
+        &self,
+        input: ListBackupSelectionsInput,
+    ) -> impl futures::Stream<
+        Item = Result<BackupSelectionsListMember, RusotoError<ListBackupSelectionsError>>,
+    > + Send {
+        rusoto_core::pagination::items(self.list_backup_selections_pages(input), |page| {
+            page.backup_selections_list.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `ListBackupVaults` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `MaxResults` of `input`.
+    pub fn list_backup_vaults_pages(
+        &self,
+        input: ListBackupVaultsInput,
+    ) -> impl futures::Stream<
+        Item = Result<ListBackupVaultsOutput, RusotoError<ListBackupVaultsError>>,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: BackupClient, input| async move { client.list_backup_vaults(input).await },
+            |input: &ListBackupVaultsInput, output: &ListBackupVaultsOutput| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListBackupVaultsInput {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `BackupVaultList` of every page of `ListBackupVaults` results for `input`, as `list_backup_vaults_pages` requests them.
+    pub fn list_backup_vaults_stream(
+        &self,
+        input: ListBackupVaultsInput,
+    ) -> impl futures::Stream<Item = Result<BackupVaultListMember, RusotoError<ListBackupVaultsError>>>
+           + Send {
+        rusoto_core::pagination::items(self.list_backup_vaults_pages(input), |page| {
+            page.backup_vault_list.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `ListCopyJobs` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `MaxResults` of `input`.
+    pub fn list_copy_jobs_pages(
+        &self,
+        input: ListCopyJobsInput,
+    ) -> impl futures::Stream<Item = Result<ListCopyJobsOutput, RusotoError<ListCopyJobsError>>> + Send
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: BackupClient, input| async move { client.list_copy_jobs(input).await },
+            |input: &ListCopyJobsInput, output: &ListCopyJobsOutput| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListCopyJobsInput {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `CopyJobs` of every page of `ListCopyJobs` results for `input`, as `list_copy_jobs_pages` requests them.
+    pub fn list_copy_jobs_stream(
+        &self,
+        input: ListCopyJobsInput,
+    ) -> impl futures::Stream<Item = Result<CopyJob, RusotoError<ListCopyJobsError>>> + Send {
+        rusoto_core::pagination::items(self.list_copy_jobs_pages(input), |page| {
+            page.copy_jobs.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `ListProtectedResources` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `MaxResults` of `input`.
+    pub fn list_protected_resources_pages(
+        &self,
+        input: ListProtectedResourcesInput,
+    ) -> impl futures::Stream<
+        Item = Result<ListProtectedResourcesOutput, RusotoError<ListProtectedResourcesError>>,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: BackupClient, input| async move { client.list_protected_resources(input).await },
+            |input: &ListProtectedResourcesInput, output: &ListProtectedResourcesOutput| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListProtectedResourcesInput {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `Results` of every page of `ListProtectedResources` results for `input`, as `list_protected_resources_pages` requests them.
+    pub fn list_protected_resources_stream(
+        &self,
+        input: ListProtectedResourcesInput,
+    ) -> impl futures::Stream<
+        Item = Result<ProtectedResource, RusotoError<ListProtectedResourcesError>>,
+    > + Send {
+        rusoto_core::pagination::items(self.list_protected_resources_pages(input), |page| {
+            page.results.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `ListRecoveryPointsByBackupVault` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `MaxResults` of `input`.
+    pub fn list_recovery_points_by_backup_vault_pages(
+        &self,
+        input: ListRecoveryPointsByBackupVaultInput,
+    ) -> impl futures::Stream<
+        Item = Result<
+            ListRecoveryPointsByBackupVaultOutput,
+            RusotoError<ListRecoveryPointsByBackupVaultError>,
+        >,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: BackupClient, input| async move {
+                client.list_recovery_points_by_backup_vault(input).await
+            },
+            |input: &ListRecoveryPointsByBackupVaultInput,
+             output: &ListRecoveryPointsByBackupVaultOutput| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListRecoveryPointsByBackupVaultInput {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `RecoveryPoints` of every page of `ListRecoveryPointsByBackupVault` results for `input`, as `list_recovery_points_by_backup_vault_pages` requests them.
+    pub fn list_recovery_points_by_backup_vault_stream(
+        &self,
+        input: ListRecoveryPointsByBackupVaultInput,
+    ) -> impl futures::Stream<
+        Item = Result<
+            RecoveryPointByBackupVault,
+            RusotoError<ListRecoveryPointsByBackupVaultError>,
+        >,
+    > + Send {
+        rusoto_core::pagination::items(
+            self.list_recovery_points_by_backup_vault_pages(input),
+            |page| page.recovery_points.unwrap_or_default(),
+        )
+    }
+
+    /// The pages of `ListRecoveryPointsByResource` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `MaxResults` of `input`.
+    pub fn list_recovery_points_by_resource_pages(
+        &self,
+        input: ListRecoveryPointsByResourceInput,
+    ) -> impl futures::Stream<
+        Item = Result<
+            ListRecoveryPointsByResourceOutput,
+            RusotoError<ListRecoveryPointsByResourceError>,
+        >,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: BackupClient, input| async move {
+                client.list_recovery_points_by_resource(input).await
+            },
+            |input: &ListRecoveryPointsByResourceInput,
+             output: &ListRecoveryPointsByResourceOutput| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListRecoveryPointsByResourceInput {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `RecoveryPoints` of every page of `ListRecoveryPointsByResource` results for `input`, as `list_recovery_points_by_resource_pages` requests them.
+    pub fn list_recovery_points_by_resource_stream(
+        &self,
+        input: ListRecoveryPointsByResourceInput,
+    ) -> impl futures::Stream<
+        Item = Result<RecoveryPointByResource, RusotoError<ListRecoveryPointsByResourceError>>,
+    > + Send {
+        rusoto_core::pagination::items(self.list_recovery_points_by_resource_pages(input), |page| {
+            page.recovery_points.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `ListRestoreJobs` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `MaxResults` of `input`.
+    pub fn list_restore_jobs_pages(
+        &self,
+        input: ListRestoreJobsInput,
+    ) -> impl futures::Stream<Item = Result<ListRestoreJobsOutput, RusotoError<ListRestoreJobsError>>>
+           + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: BackupClient, input| async move { client.list_restore_jobs(input).await },
+            |input: &ListRestoreJobsInput, output: &ListRestoreJobsOutput| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListRestoreJobsInput {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `RestoreJobs` of every page of `ListRestoreJobs` results for `input`, as `list_restore_jobs_pages` requests them.
+    pub fn list_restore_jobs_stream(
+        &self,
+        input: ListRestoreJobsInput,
+    ) -> impl futures::Stream<Item = Result<RestoreJobsListMember, RusotoError<ListRestoreJobsError>>>
+           + Send {
+        rusoto_core::pagination::items(self.list_restore_jobs_pages(input), |page| {
+            page.restore_jobs.unwrap_or_default()
+        })
+    }
+}
+
+#[async_trait]
+impl Backup for BackupClient {
+    /// <p>Backup plans are documents that contain information that AWS Backup uses to schedule tasks that create recovery points of resources.</p> <p>If you call <code>CreateBackupPlan</code> with a plan that already exists, an <code>AlreadyExistsException</code> is returned.</p>
+    async fn create_backup_plan(
+        &self,
+        input: CreateBackupPlanInput,
+    ) -> Result<CreateBackupPlanOutput, RusotoError<CreateBackupPlanError>> {
+        self.create_backup_plan_with_metadata(input)
             .await
-            .map_err(RusotoError::from)?;
-        if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<UpdateRecoveryPointLifecycleOutput, _>()?;
+            .map(|(output, _)| output)
+    }
 
-            Ok(result)
-        } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(UpdateRecoveryPointLifecycleError::from_response(response))
-        }
+    /// <p>Creates a JSON document that specifies a set of resources to assign to a backup plan. Resources can be included by specifying patterns for a <code>ListOfTags</code> and selected <code>Resources</code>. </p> <p>For example, consider the following patterns:</p> <ul> <li> <p> <code>Resources: "arn:aws:ec2:region:account-id:volume/volume-id"</code> </p> </li> <li> <p> <code>ConditionKey:"department"</code> </p> <p> <code>ConditionValue:"finance"</code> </p> <p> <code>ConditionType:"STRINGEQUALS"</code> </p> </li> <li> <p> <code>ConditionKey:"importance"</code> </p> <p> <code>ConditionValue:"critical"</code> </p> <p> <code>ConditionType:"STRINGEQUALS"</code> </p> </li> </ul> <p>Using these patterns would back up all Amazon Elastic Block Store (Amazon EBS) volumes that are tagged as <code>"department=finance"</code>, <code>"importance=critical"</code>, in addition to an EBS volume with the specified volume Id.</p> <p>Resources and conditions are additive in that all resources that match the pattern are selected. This shouldn't be confused with a logical AND, where all conditions must match. The matching patterns are logically 'put together using the OR operator. In other words, all patterns that match are selected for backup.</p>
+    async fn create_backup_selection(
+        &self,
+        input: CreateBackupSelectionInput,
+    ) -> Result<CreateBackupSelectionOutput, RusotoError<CreateBackupSelectionError>> {
+        self.create_backup_selection_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Creates a logical container where backups are stored. A <code>CreateBackupVault</code> request includes a name, optionally one or more resource tags, an encryption key, and a request ID.</p> <note> <p>Sensitive data, such as passport numbers, should not be included the name of a backup vault.</p> </note></p>
+    async fn create_backup_vault(
+        &self,
+        input: CreateBackupVaultInput,
+    ) -> Result<CreateBackupVaultOutput, RusotoError<CreateBackupVaultError>> {
+        self.create_backup_vault_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes a backup plan. A backup plan can only be deleted after all associated selections of resources have been deleted. Deleting a backup plan deletes the current version of a backup plan. Previous versions, if any, will still exist.</p>
+    async fn delete_backup_plan(
+        &self,
+        input: DeleteBackupPlanInput,
+    ) -> Result<DeleteBackupPlanOutput, RusotoError<DeleteBackupPlanError>> {
+        self.delete_backup_plan_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes the resource selection associated with a backup plan that is specified by the <code>SelectionId</code>.</p>
+    async fn delete_backup_selection(
+        &self,
+        input: DeleteBackupSelectionInput,
+    ) -> Result<(), RusotoError<DeleteBackupSelectionError>> {
+        self.delete_backup_selection_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes the backup vault identified by its name. A vault can be deleted only if it is empty.</p>
+    async fn delete_backup_vault(
+        &self,
+        input: DeleteBackupVaultInput,
+    ) -> Result<(), RusotoError<DeleteBackupVaultError>> {
+        self.delete_backup_vault_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes the policy document that manages permissions on a backup vault.</p>
+    async fn delete_backup_vault_access_policy(
+        &self,
+        input: DeleteBackupVaultAccessPolicyInput,
+    ) -> Result<(), RusotoError<DeleteBackupVaultAccessPolicyError>> {
+        self.delete_backup_vault_access_policy_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes event notifications for the specified backup vault.</p>
+    async fn delete_backup_vault_notifications(
+        &self,
+        input: DeleteBackupVaultNotificationsInput,
+    ) -> Result<(), RusotoError<DeleteBackupVaultNotificationsError>> {
+        self.delete_backup_vault_notifications_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes the recovery point specified by a recovery point ID.</p>
+    async fn delete_recovery_point(
+        &self,
+        input: DeleteRecoveryPointInput,
+    ) -> Result<(), RusotoError<DeleteRecoveryPointError>> {
+        self.delete_recovery_point_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns metadata associated with creating a backup of a resource.</p>
+    async fn describe_backup_job(
+        &self,
+        input: DescribeBackupJobInput,
+    ) -> Result<DescribeBackupJobOutput, RusotoError<DescribeBackupJobError>> {
+        self.describe_backup_job_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns metadata about a backup vault specified by its name.</p>
+    async fn describe_backup_vault(
+        &self,
+        input: DescribeBackupVaultInput,
+    ) -> Result<DescribeBackupVaultOutput, RusotoError<DescribeBackupVaultError>> {
+        self.describe_backup_vault_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns metadata associated with creating a copy of a resource.</p>
+    async fn describe_copy_job(
+        &self,
+        input: DescribeCopyJobInput,
+    ) -> Result<DescribeCopyJobOutput, RusotoError<DescribeCopyJobError>> {
+        self.describe_copy_job_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns information about a saved resource, including the last time it was backed-up, its Amazon Resource Name (ARN), and the AWS service type of the saved resource.</p>
+    async fn describe_protected_resource(
+        &self,
+        input: DescribeProtectedResourceInput,
+    ) -> Result<DescribeProtectedResourceOutput, RusotoError<DescribeProtectedResourceError>> {
+        self.describe_protected_resource_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns metadata associated with a recovery point, including ID, status, encryption, and lifecycle.</p>
+    async fn describe_recovery_point(
+        &self,
+        input: DescribeRecoveryPointInput,
+    ) -> Result<DescribeRecoveryPointOutput, RusotoError<DescribeRecoveryPointError>> {
+        self.describe_recovery_point_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns metadata associated with a restore job that is specified by a job ID.</p>
+    async fn describe_restore_job(
+        &self,
+        input: DescribeRestoreJobInput,
+    ) -> Result<DescribeRestoreJobOutput, RusotoError<DescribeRestoreJobError>> {
+        self.describe_restore_job_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns the backup plan that is specified by the plan ID as a backup template.</p>
+    async fn export_backup_plan_template(
+        &self,
+        input: ExportBackupPlanTemplateInput,
+    ) -> Result<ExportBackupPlanTemplateOutput, RusotoError<ExportBackupPlanTemplateError>> {
+        self.export_backup_plan_template_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns the body of a backup plan in JSON format, in addition to plan metadata.</p>
+    async fn get_backup_plan(
+        &self,
+        input: GetBackupPlanInput,
+    ) -> Result<GetBackupPlanOutput, RusotoError<GetBackupPlanError>> {
+        self.get_backup_plan_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns a valid JSON document specifying a backup plan or an error.</p>
+    async fn get_backup_plan_from_json(
+        &self,
+        input: GetBackupPlanFromJSONInput,
+    ) -> Result<GetBackupPlanFromJSONOutput, RusotoError<GetBackupPlanFromJSONError>> {
+        self.get_backup_plan_from_json_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns the template specified by its <code>templateId</code> as a backup plan.</p>
+    async fn get_backup_plan_from_template(
+        &self,
+        input: GetBackupPlanFromTemplateInput,
+    ) -> Result<GetBackupPlanFromTemplateOutput, RusotoError<GetBackupPlanFromTemplateError>> {
+        self.get_backup_plan_from_template_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns selection metadata and a document in JSON format that specifies a list of resources that are associated with a backup plan.</p>
+    async fn get_backup_selection(
+        &self,
+        input: GetBackupSelectionInput,
+    ) -> Result<GetBackupSelectionOutput, RusotoError<GetBackupSelectionError>> {
+        self.get_backup_selection_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns the access policy document that is associated with the named backup vault.</p>
+    async fn get_backup_vault_access_policy(
+        &self,
+        input: GetBackupVaultAccessPolicyInput,
+    ) -> Result<GetBackupVaultAccessPolicyOutput, RusotoError<GetBackupVaultAccessPolicyError>>
+    {
+        self.get_backup_vault_access_policy_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns event notifications for the specified backup vault.</p>
+    async fn get_backup_vault_notifications(
+        &self,
+        input: GetBackupVaultNotificationsInput,
+    ) -> Result<GetBackupVaultNotificationsOutput, RusotoError<GetBackupVaultNotificationsError>>
+    {
+        self.get_backup_vault_notifications_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns a set of metadata key-value pairs that were used to create the backup.</p>
+    async fn get_recovery_point_restore_metadata(
+        &self,
+        input: GetRecoveryPointRestoreMetadataInput,
+    ) -> Result<
+        GetRecoveryPointRestoreMetadataOutput,
+        RusotoError<GetRecoveryPointRestoreMetadataError>,
+    > {
+        self.get_recovery_point_restore_metadata_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns the AWS resource types supported by AWS Backup.</p>
+    async fn get_supported_resource_types(
+        &self,
+    ) -> Result<GetSupportedResourceTypesOutput, RusotoError<GetSupportedResourceTypesError>> {
+        self.get_supported_resource_types_with_metadata()
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns metadata about your backup jobs.</p>
+    async fn list_backup_jobs(
+        &self,
+        input: ListBackupJobsInput,
+    ) -> Result<ListBackupJobsOutput, RusotoError<ListBackupJobsError>> {
+        self.list_backup_jobs_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns metadata of your saved backup plan templates, including the template ID, name, and the creation and deletion dates.</p>
+    async fn list_backup_plan_templates(
+        &self,
+        input: ListBackupPlanTemplatesInput,
+    ) -> Result<ListBackupPlanTemplatesOutput, RusotoError<ListBackupPlanTemplatesError>> {
+        self.list_backup_plan_templates_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns version metadata of your backup plans, including Amazon Resource Names (ARNs), backup plan IDs, creation and deletion dates, plan names, and version IDs.</p>
+    async fn list_backup_plan_versions(
+        &self,
+        input: ListBackupPlanVersionsInput,
+    ) -> Result<ListBackupPlanVersionsOutput, RusotoError<ListBackupPlanVersionsError>> {
+        self.list_backup_plan_versions_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns metadata of your saved backup plans, including Amazon Resource Names (ARNs), plan IDs, creation and deletion dates, version IDs, plan names, and creator request IDs.</p>
+    async fn list_backup_plans(
+        &self,
+        input: ListBackupPlansInput,
+    ) -> Result<ListBackupPlansOutput, RusotoError<ListBackupPlansError>> {
+        self.list_backup_plans_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns an array containing metadata of the resources associated with the target backup plan.</p>
+    async fn list_backup_selections(
+        &self,
+        input: ListBackupSelectionsInput,
+    ) -> Result<ListBackupSelectionsOutput, RusotoError<ListBackupSelectionsError>> {
+        self.list_backup_selections_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns a list of recovery point storage containers along with information about them.</p>
+    async fn list_backup_vaults(
+        &self,
+        input: ListBackupVaultsInput,
+    ) -> Result<ListBackupVaultsOutput, RusotoError<ListBackupVaultsError>> {
+        self.list_backup_vaults_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns metadata about your copy jobs.</p>
+    async fn list_copy_jobs(
+        &self,
+        input: ListCopyJobsInput,
+    ) -> Result<ListCopyJobsOutput, RusotoError<ListCopyJobsError>> {
+        self.list_copy_jobs_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns an array of resources successfully backed up by AWS Backup, including the time the resource was saved, an Amazon Resource Name (ARN) of the resource, and a resource type.</p>
+    async fn list_protected_resources(
+        &self,
+        input: ListProtectedResourcesInput,
+    ) -> Result<ListProtectedResourcesOutput, RusotoError<ListProtectedResourcesError>> {
+        self.list_protected_resources_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns detailed information about the recovery points stored in a backup vault.</p>
+    async fn list_recovery_points_by_backup_vault(
+        &self,
+        input: ListRecoveryPointsByBackupVaultInput,
+    ) -> Result<
+        ListRecoveryPointsByBackupVaultOutput,
+        RusotoError<ListRecoveryPointsByBackupVaultError>,
+    > {
+        self.list_recovery_points_by_backup_vault_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns detailed information about recovery points of the type specified by a resource Amazon Resource Name (ARN).</p>
+    async fn list_recovery_points_by_resource(
+        &self,
+        input: ListRecoveryPointsByResourceInput,
+    ) -> Result<ListRecoveryPointsByResourceOutput, RusotoError<ListRecoveryPointsByResourceError>>
+    {
+        self.list_recovery_points_by_resource_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns a list of jobs that AWS Backup initiated to restore a saved resource, including metadata about the recovery process.</p>
+    async fn list_restore_jobs(
+        &self,
+        input: ListRestoreJobsInput,
+    ) -> Result<ListRestoreJobsOutput, RusotoError<ListRestoreJobsError>> {
+        self.list_restore_jobs_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns a list of key-value pairs assigned to a target recovery point, backup plan, or backup vault.</p>
+    async fn list_tags(
+        &self,
+        input: ListTagsInput,
+    ) -> Result<ListTagsOutput, RusotoError<ListTagsError>> {
+        self.list_tags_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Sets a resource-based policy that is used to manage access permissions on the target backup vault. Requires a backup vault name and an access policy document in JSON format.</p>
+    async fn put_backup_vault_access_policy(
+        &self,
+        input: PutBackupVaultAccessPolicyInput,
+    ) -> Result<(), RusotoError<PutBackupVaultAccessPolicyError>> {
+        self.put_backup_vault_access_policy_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Turns on notifications on a backup vault for the specified topic and events.</p>
+    async fn put_backup_vault_notifications(
+        &self,
+        input: PutBackupVaultNotificationsInput,
+    ) -> Result<(), RusotoError<PutBackupVaultNotificationsError>> {
+        self.put_backup_vault_notifications_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Starts a job to create a one-time backup of the specified resource.</p>
+    async fn start_backup_job(
+        &self,
+        input: StartBackupJobInput,
+    ) -> Result<StartBackupJobOutput, RusotoError<StartBackupJobError>> {
+        self.start_backup_job_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Starts a job to create a one-time copy of the specified resource.</p>
+    async fn start_copy_job(
+        &self,
+        input: StartCopyJobInput,
+    ) -> Result<StartCopyJobOutput, RusotoError<StartCopyJobError>> {
+        self.start_copy_job_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Recovers the saved resource identified by an Amazon Resource Name (ARN). </p> <p>If the resource ARN is included in the request, then the last complete backup of that resource is recovered. If the ARN of a recovery point is supplied, then that recovery point is restored.</p>
+    async fn start_restore_job(
+        &self,
+        input: StartRestoreJobInput,
+    ) -> Result<StartRestoreJobOutput, RusotoError<StartRestoreJobError>> {
+        self.start_restore_job_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Attempts to cancel a job to create a one-time backup of a resource.</p>
+    async fn stop_backup_job(
+        &self,
+        input: StopBackupJobInput,
+    ) -> Result<(), RusotoError<StopBackupJobError>> {
+        self.stop_backup_job_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Assigns a set of key-value pairs to a recovery point, backup plan, or backup vault identified by an Amazon Resource Name (ARN).</p>
+    async fn tag_resource(
+        &self,
+        input: TagResourceInput,
+    ) -> Result<(), RusotoError<TagResourceError>> {
+        self.tag_resource_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Removes a set of key-value pairs from a recovery point, backup plan, or backup vault identified by an Amazon Resource Name (ARN)</p>
+    async fn untag_resource(
+        &self,
+        input: UntagResourceInput,
+    ) -> Result<(), RusotoError<UntagResourceError>> {
+        self.untag_resource_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Replaces the body of a saved backup plan identified by its <code>backupPlanId</code> with the input document in JSON format. The new version is uniquely identified by a <code>VersionId</code>.</p>
+    async fn update_backup_plan(
+        &self,
+        input: UpdateBackupPlanInput,
+    ) -> Result<UpdateBackupPlanOutput, RusotoError<UpdateBackupPlanError>> {
+        self.update_backup_plan_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Sets the transition lifecycle of a recovery point.</p> <p>The lifecycle defines when a protected resource is transitioned to cold storage and when it expires. AWS Backup transitions and expires backups automatically according to the lifecycle that you define. </p> <p>Backups transitioned to cold storage must be stored in cold storage for a minimum of 90 days. Therefore, the “expire after days” setting must be 90 days greater than the “transition to cold after days” setting. The “transition to cold after days” setting cannot be changed after a backup has been transitioned to cold. </p>
+    async fn update_recovery_point_lifecycle(
+        &self,
+        input: UpdateRecoveryPointLifecycleInput,
+    ) -> Result<UpdateRecoveryPointLifecycleOutput, RusotoError<UpdateRecoveryPointLifecycleError>>
+    {
+        self.update_recovery_point_lifecycle_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
     }
 }
@@ -16,8 +16,8 @@ use std::fmt;
 use async_trait::async_trait;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
-use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest, ResponseMetadata};
+use rusoto_core::{Client, ErrorMetadata, RusotoError};
 
 use rusoto_core::proto;
 use rusoto_core::signature::SignedRequest;
@@ -1340,21 +1340,28 @@ pub struct Volume {
 
 /// Errors returned by CancelJob
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CancelJobError {
     /// <p>These errors are usually caused by a client action, such as using an action or resource on behalf of a user that doesn't have permissions to use the action or resource, or specifying an identifier that is not valid.</p>
     Client(String),
     /// <p>These errors are usually caused by a server issue.</p>
     Server(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CancelJobError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<CancelJobError> {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
-                "ClientException" => return RusotoError::Service(CancelJobError::Client(err.msg)),
-                "ServerException" => return RusotoError::Service(CancelJobError::Server(err.msg)),
+                "ClientException" => {
+                    return RusotoError::service(CancelJobError::Client(err.msg), &res)
+                }
+                "ServerException" => {
+                    return RusotoError::service(CancelJobError::Server(err.msg), &res)
+                }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CancelJobError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1366,17 +1373,21 @@ impl fmt::Display for CancelJobError {
         match *self {
             CancelJobError::Client(ref cause) => write!(f, "{}", cause),
             CancelJobError::Server(ref cause) => write!(f, "{}", cause),
+            CancelJobError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CancelJobError {}
 /// Errors returned by CreateComputeEnvironment
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateComputeEnvironmentError {
     /// <p>These errors are usually caused by a client action, such as using an action or resource on behalf of a user that doesn't have permissions to use the action or resource, or specifying an identifier that is not valid.</p>
     Client(String),
     /// <p>These errors are usually caused by a server issue.</p>
     Server(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateComputeEnvironmentError {
@@ -1384,13 +1395,19 @@ impl CreateComputeEnvironmentError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ClientException" => {
-                    return RusotoError::Service(CreateComputeEnvironmentError::Client(err.msg))
+                    return RusotoError::service(
+                        CreateComputeEnvironmentError::Client(err.msg),
+                        &res,
+                    )
                 }
                 "ServerException" => {
-                    return RusotoError::Service(CreateComputeEnvironmentError::Server(err.msg))
+                    return RusotoError::service(
+                        CreateComputeEnvironmentError::Server(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreateComputeEnvironmentError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1402,17 +1419,21 @@ impl fmt::Display for CreateComputeEnvironmentError {
         match *self {
             CreateComputeEnvironmentError::Client(ref cause) => write!(f, "{}", cause),
             CreateComputeEnvironmentError::Server(ref cause) => write!(f, "{}", cause),
+            CreateComputeEnvironmentError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateComputeEnvironmentError {}
 /// Errors returned by CreateJobQueue
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateJobQueueError {
     /// <p>These errors are usually caused by a client action, such as using an action or resource on behalf of a user that doesn't have permissions to use the action or resource, or specifying an identifier that is not valid.</p>
     Client(String),
     /// <p>These errors are usually caused by a server issue.</p>
     Server(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateJobQueueError {
@@ -1420,13 +1441,13 @@ impl CreateJobQueueError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ClientException" => {
-                    return RusotoError::Service(CreateJobQueueError::Client(err.msg))
+                    return RusotoError::service(CreateJobQueueError::Client(err.msg), &res)
                 }
                 "ServerException" => {
-                    return RusotoError::Service(CreateJobQueueError::Server(err.msg))
+                    return RusotoError::service(CreateJobQueueError::Server(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreateJobQueueError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1438,17 +1459,21 @@ impl fmt::Display for CreateJobQueueError {
         match *self {
             CreateJobQueueError::Client(ref cause) => write!(f, "{}", cause),
             CreateJobQueueError::Server(ref cause) => write!(f, "{}", cause),
+            CreateJobQueueError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateJobQueueError {}
 /// Errors returned by DeleteComputeEnvironment
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteComputeEnvironmentError {
     /// <p>These errors are usually caused by a client action, such as using an action or resource on behalf of a user that doesn't have permissions to use the action or resource, or specifying an identifier that is not valid.</p>
     Client(String),
     /// <p>These errors are usually caused by a server issue.</p>
     Server(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteComputeEnvironmentError {
@@ -1456,13 +1481,19 @@ impl DeleteComputeEnvironmentError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ClientException" => {
-                    return RusotoError::Service(DeleteComputeEnvironmentError::Client(err.msg))
+                    return RusotoError::service(
+                        DeleteComputeEnvironmentError::Client(err.msg),
+                        &res,
+                    )
                 }
                 "ServerException" => {
-                    return RusotoError::Service(DeleteComputeEnvironmentError::Server(err.msg))
+                    return RusotoError::service(
+                        DeleteComputeEnvironmentError::Server(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteComputeEnvironmentError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1474,17 +1505,21 @@ impl fmt::Display for DeleteComputeEnvironmentError {
         match *self {
             DeleteComputeEnvironmentError::Client(ref cause) => write!(f, "{}", cause),
             DeleteComputeEnvironmentError::Server(ref cause) => write!(f, "{}", cause),
+            DeleteComputeEnvironmentError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteComputeEnvironmentError {}
 /// Errors returned by DeleteJobQueue
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteJobQueueError {
     /// <p>These errors are usually caused by a client action, such as using an action or resource on behalf of a user that doesn't have permissions to use the action or resource, or specifying an identifier that is not valid.</p>
     Client(String),
     /// <p>These errors are usually caused by a server issue.</p>
     Server(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteJobQueueError {
@@ -1492,13 +1527,13 @@ impl DeleteJobQueueError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ClientException" => {
-                    return RusotoError::Service(DeleteJobQueueError::Client(err.msg))
+                    return RusotoError::service(DeleteJobQueueError::Client(err.msg), &res)
                 }
                 "ServerException" => {
-                    return RusotoError::Service(DeleteJobQueueError::Server(err.msg))
+                    return RusotoError::service(DeleteJobQueueError::Server(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteJobQueueError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1510,17 +1545,21 @@ impl fmt::Display for DeleteJobQueueError {
         match *self {
             DeleteJobQueueError::Client(ref cause) => write!(f, "{}", cause),
             DeleteJobQueueError::Server(ref cause) => write!(f, "{}", cause),
+            DeleteJobQueueError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteJobQueueError {}
 /// Errors returned by DeregisterJobDefinition
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeregisterJobDefinitionError {
     /// <p>These errors are usually caused by a client action, such as using an action or resource on behalf of a user that doesn't have permissions to use the action or resource, or specifying an identifier that is not valid.</p>
     Client(String),
     /// <p>These errors are usually caused by a server issue.</p>
     Server(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeregisterJobDefinitionError {
@@ -1528,13 +1567,19 @@ impl DeregisterJobDefinitionError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ClientException" => {
-                    return RusotoError::Service(DeregisterJobDefinitionError::Client(err.msg))
+                    return RusotoError::service(
+                        DeregisterJobDefinitionError::Client(err.msg),
+                        &res,
+                    )
                 }
                 "ServerException" => {
-                    return RusotoError::Service(DeregisterJobDefinitionError::Server(err.msg))
+                    return RusotoError::service(
+                        DeregisterJobDefinitionError::Server(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeregisterJobDefinitionError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1546,17 +1591,21 @@ impl fmt::Display for DeregisterJobDefinitionError {
         match *self {
             DeregisterJobDefinitionError::Client(ref cause) => write!(f, "{}", cause),
             DeregisterJobDefinitionError::Server(ref cause) => write!(f, "{}", cause),
+            DeregisterJobDefinitionError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeregisterJobDefinitionError {}
 /// Errors returned by DescribeComputeEnvironments
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeComputeEnvironmentsError {
     /// <p>These errors are usually caused by a client action, such as using an action or resource on behalf of a user that doesn't have permissions to use the action or resource, or specifying an identifier that is not valid.</p>
     Client(String),
     /// <p>These errors are usually caused by a server issue.</p>
     Server(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeComputeEnvironmentsError {
@@ -1566,13 +1615,24 @@ impl DescribeComputeEnvironmentsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ClientException" => {
-                    return RusotoError::Service(DescribeComputeEnvironmentsError::Client(err.msg))
+                    return RusotoError::service(
+                        DescribeComputeEnvironmentsError::Client(err.msg),
+                        &res,
+                    )
                 }
                 "ServerException" => {
-                    return RusotoError::Service(DescribeComputeEnvironmentsError::Server(err.msg))
+                    return RusotoError::service(
+                        DescribeComputeEnvironmentsError::Server(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        DescribeComputeEnvironmentsError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -1584,17 +1644,21 @@ impl fmt::Display for DescribeComputeEnvironmentsError {
         match *self {
             DescribeComputeEnvironmentsError::Client(ref cause) => write!(f, "{}", cause),
             DescribeComputeEnvironmentsError::Server(ref cause) => write!(f, "{}", cause),
+            DescribeComputeEnvironmentsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeComputeEnvironmentsError {}
 /// Errors returned by DescribeJobDefinitions
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeJobDefinitionsError {
     /// <p>These errors are usually caused by a client action, such as using an action or resource on behalf of a user that doesn't have permissions to use the action or resource, or specifying an identifier that is not valid.</p>
     Client(String),
     /// <p>These errors are usually caused by a server issue.</p>
     Server(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeJobDefinitionsError {
@@ -1602,13 +1666,13 @@ impl DescribeJobDefinitionsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ClientException" => {
-                    return RusotoError::Service(DescribeJobDefinitionsError::Client(err.msg))
+                    return RusotoError::service(DescribeJobDefinitionsError::Client(err.msg), &res)
                 }
                 "ServerException" => {
-                    return RusotoError::Service(DescribeJobDefinitionsError::Server(err.msg))
+                    return RusotoError::service(DescribeJobDefinitionsError::Server(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DescribeJobDefinitionsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1620,17 +1684,21 @@ impl fmt::Display for DescribeJobDefinitionsError {
         match *self {
             DescribeJobDefinitionsError::Client(ref cause) => write!(f, "{}", cause),
             DescribeJobDefinitionsError::Server(ref cause) => write!(f, "{}", cause),
+            DescribeJobDefinitionsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeJobDefinitionsError {}
 /// Errors returned by DescribeJobQueues
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeJobQueuesError {
     /// <p>These errors are usually caused by a client action, such as using an action or resource on behalf of a user that doesn't have permissions to use the action or resource, or specifying an identifier that is not valid.</p>
     Client(String),
     /// <p>These errors are usually caused by a server issue.</p>
     Server(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeJobQueuesError {
@@ -1638,13 +1706,13 @@ impl DescribeJobQueuesError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ClientException" => {
-                    return RusotoError::Service(DescribeJobQueuesError::Client(err.msg))
+                    return RusotoError::service(DescribeJobQueuesError::Client(err.msg), &res)
                 }
                 "ServerException" => {
-                    return RusotoError::Service(DescribeJobQueuesError::Server(err.msg))
+                    return RusotoError::service(DescribeJobQueuesError::Server(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DescribeJobQueuesError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1656,17 +1724,21 @@ impl fmt::Display for DescribeJobQueuesError {
         match *self {
             DescribeJobQueuesError::Client(ref cause) => write!(f, "{}", cause),
             DescribeJobQueuesError::Server(ref cause) => write!(f, "{}", cause),
+            DescribeJobQueuesError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeJobQueuesError {}
 /// Errors returned by DescribeJobs
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeJobsError {
     /// <p>These errors are usually caused by a client action, such as using an action or resource on behalf of a user that doesn't have permissions to use the action or resource, or specifying an identifier that is not valid.</p>
     Client(String),
     /// <p>These errors are usually caused by a server issue.</p>
     Server(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeJobsError {
@@ -1674,13 +1746,13 @@ impl DescribeJobsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ClientException" => {
-                    return RusotoError::Service(DescribeJobsError::Client(err.msg))
+                    return RusotoError::service(DescribeJobsError::Client(err.msg), &res)
                 }
                 "ServerException" => {
-                    return RusotoError::Service(DescribeJobsError::Server(err.msg))
+                    return RusotoError::service(DescribeJobsError::Server(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DescribeJobsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1692,27 +1764,35 @@ impl fmt::Display for DescribeJobsError {
         match *self {
             DescribeJobsError::Client(ref cause) => write!(f, "{}", cause),
             DescribeJobsError::Server(ref cause) => write!(f, "{}", cause),
+            DescribeJobsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeJobsError {}
 /// Errors returned by ListJobs
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListJobsError {
     /// <p>These errors are usually caused by a client action, such as using an action or resource on behalf of a user that doesn't have permissions to use the action or resource, or specifying an identifier that is not valid.</p>
     Client(String),
     /// <p>These errors are usually caused by a server issue.</p>
     Server(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListJobsError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<ListJobsError> {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
-                "ClientException" => return RusotoError::Service(ListJobsError::Client(err.msg)),
-                "ServerException" => return RusotoError::Service(ListJobsError::Server(err.msg)),
+                "ClientException" => {
+                    return RusotoError::service(ListJobsError::Client(err.msg), &res)
+                }
+                "ServerException" => {
+                    return RusotoError::service(ListJobsError::Server(err.msg), &res)
+                }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListJobsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1724,17 +1804,21 @@ impl fmt::Display for ListJobsError {
         match *self {
             ListJobsError::Client(ref cause) => write!(f, "{}", cause),
             ListJobsError::Server(ref cause) => write!(f, "{}", cause),
+            ListJobsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListJobsError {}
 /// Errors returned by RegisterJobDefinition
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum RegisterJobDefinitionError {
     /// <p>These errors are usually caused by a client action, such as using an action or resource on behalf of a user that doesn't have permissions to use the action or resource, or specifying an identifier that is not valid.</p>
     Client(String),
     /// <p>These errors are usually caused by a server issue.</p>
     Server(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl RegisterJobDefinitionError {
@@ -1742,13 +1826,13 @@ impl RegisterJobDefinitionError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ClientException" => {
-                    return RusotoError::Service(RegisterJobDefinitionError::Client(err.msg))
+                    return RusotoError::service(RegisterJobDefinitionError::Client(err.msg), &res)
                 }
                 "ServerException" => {
-                    return RusotoError::Service(RegisterJobDefinitionError::Server(err.msg))
+                    return RusotoError::service(RegisterJobDefinitionError::Server(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(RegisterJobDefinitionError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1760,27 +1844,35 @@ impl fmt::Display for RegisterJobDefinitionError {
         match *self {
             RegisterJobDefinitionError::Client(ref cause) => write!(f, "{}", cause),
             RegisterJobDefinitionError::Server(ref cause) => write!(f, "{}", cause),
+            RegisterJobDefinitionError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for RegisterJobDefinitionError {}
 /// Errors returned by SubmitJob
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum SubmitJobError {
     /// <p>These errors are usually caused by a client action, such as using an action or resource on behalf of a user that doesn't have permissions to use the action or resource, or specifying an identifier that is not valid.</p>
     Client(String),
     /// <p>These errors are usually caused by a server issue.</p>
     Server(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl SubmitJobError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<SubmitJobError> {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
-                "ClientException" => return RusotoError::Service(SubmitJobError::Client(err.msg)),
-                "ServerException" => return RusotoError::Service(SubmitJobError::Server(err.msg)),
+                "ClientException" => {
+                    return RusotoError::service(SubmitJobError::Client(err.msg), &res)
+                }
+                "ServerException" => {
+                    return RusotoError::service(SubmitJobError::Server(err.msg), &res)
+                }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(SubmitJobError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1792,17 +1884,21 @@ impl fmt::Display for SubmitJobError {
         match *self {
             SubmitJobError::Client(ref cause) => write!(f, "{}", cause),
             SubmitJobError::Server(ref cause) => write!(f, "{}", cause),
+            SubmitJobError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for SubmitJobError {}
 /// Errors returned by TerminateJob
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum TerminateJobError {
     /// <p>These errors are usually caused by a client action, such as using an action or resource on behalf of a user that doesn't have permissions to use the action or resource, or specifying an identifier that is not valid.</p>
     Client(String),
     /// <p>These errors are usually caused by a server issue.</p>
     Server(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl TerminateJobError {
@@ -1810,13 +1906,13 @@ impl TerminateJobError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ClientException" => {
-                    return RusotoError::Service(TerminateJobError::Client(err.msg))
+                    return RusotoError::service(TerminateJobError::Client(err.msg), &res)
                 }
                 "ServerException" => {
-                    return RusotoError::Service(TerminateJobError::Server(err.msg))
+                    return RusotoError::service(TerminateJobError::Server(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(TerminateJobError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1828,17 +1924,21 @@ impl fmt::Display for TerminateJobError {
         match *self {
             TerminateJobError::Client(ref cause) => write!(f, "{}", cause),
             TerminateJobError::Server(ref cause) => write!(f, "{}", cause),
+            TerminateJobError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for TerminateJobError {}
 /// Errors returned by UpdateComputeEnvironment
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdateComputeEnvironmentError {
     /// <p>These errors are usually caused by a client action, such as using an action or resource on behalf of a user that doesn't have permissions to use the action or resource, or specifying an identifier that is not valid.</p>
     Client(String),
     /// <p>These errors are usually caused by a server issue.</p>
     Server(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdateComputeEnvironmentError {
@@ -1846,13 +1946,19 @@ impl UpdateComputeEnvironmentError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ClientException" => {
-                    return RusotoError::Service(UpdateComputeEnvironmentError::Client(err.msg))
+                    return RusotoError::service(
+                        UpdateComputeEnvironmentError::Client(err.msg),
+                        &res,
+                    )
                 }
                 "ServerException" => {
-                    return RusotoError::Service(UpdateComputeEnvironmentError::Server(err.msg))
+                    return RusotoError::service(
+                        UpdateComputeEnvironmentError::Server(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UpdateComputeEnvironmentError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1864,17 +1970,21 @@ impl fmt::Display for UpdateComputeEnvironmentError {
         match *self {
             UpdateComputeEnvironmentError::Client(ref cause) => write!(f, "{}", cause),
             UpdateComputeEnvironmentError::Server(ref cause) => write!(f, "{}", cause),
+            UpdateComputeEnvironmentError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for UpdateComputeEnvironmentError {}
 /// Errors returned by UpdateJobQueue
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdateJobQueueError {
     /// <p>These errors are usually caused by a client action, such as using an action or resource on behalf of a user that doesn't have permissions to use the action or resource, or specifying an identifier that is not valid.</p>
     Client(String),
     /// <p>These errors are usually caused by a server issue.</p>
     Server(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdateJobQueueError {
@@ -1882,13 +1992,13 @@ impl UpdateJobQueueError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ClientException" => {
-                    return RusotoError::Service(UpdateJobQueueError::Client(err.msg))
+                    return RusotoError::service(UpdateJobQueueError::Client(err.msg), &res)
                 }
                 "ServerException" => {
-                    return RusotoError::Service(UpdateJobQueueError::Server(err.msg))
+                    return RusotoError::service(UpdateJobQueueError::Server(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UpdateJobQueueError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1900,6 +2010,7 @@ impl fmt::Display for UpdateJobQueueError {
         match *self {
             UpdateJobQueueError::Client(ref cause) => write!(f, "{}", cause),
             UpdateJobQueueError::Server(ref cause) => write!(f, "{}", cause),
+            UpdateJobQueueError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
@@ -2014,6 +2125,7 @@ impl BatchClient {
     /// Creates a client backed by the default tokio event loop.
     ///
     /// The client will use the default credentials provider and tls client.
+    #[cfg(not(target_arch = "wasm32"))]
     pub fn new(region: region::Region) -> BatchClient {
         BatchClient {
             client: Client::shared(),
@@ -2039,488 +2151,1243 @@ impl BatchClient {
     pub fn new_with_client(client: Client, region: region::Region) -> BatchClient {
         BatchClient { client, region }
     }
+
+    /// Returns a builder of a client, setting any of its region, credentials provider,
+    /// dispatcher, retry policy, endpoint and timeouts.
+    pub fn builder() -> rusoto_core::ClientBuilder<BatchClient> {
+        rusoto_core::ClientBuilder::new(BatchClient::new_with_client)
+    }
+
+    /// Returns a clone of this client with the overrides of `config` applied to its
+    /// operations, sharing its connections.
+    pub fn customize(&self, config: &rusoto_core::OperationConfig) -> BatchClient {
+        BatchClient {
+            client: self.client.customize(config),
+            region: config.resolve_region(&self.region),
+        }
+    }
 }
 
-#[async_trait]
-impl Batch for BatchClient {
-    /// <p>Cancels a job in an AWS Batch job queue. Jobs that are in the <code>SUBMITTED</code>, <code>PENDING</code>, or <code>RUNNABLE</code> state are cancelled. Jobs that have progressed to <code>STARTING</code> or <code>RUNNING</code> are not cancelled (but the API operation still succeeds, even if no job is cancelled); these jobs must be terminated with the <a>TerminateJob</a> operation.</p>
-    async fn cancel_job(
+impl BatchClient {
+    /// Calls `cancel_job`, also returning the metadata of the response.
+    pub async fn cancel_job_with_metadata(
         &self,
         input: CancelJobRequest,
-    ) -> Result<CancelJobResponse, RusotoError<CancelJobError>> {
-        let request_uri = "/v1/canceljob";
-
-        let mut request = SignedRequest::new("POST", "batch", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let encoded = Some(serde_json::to_vec(&input).unwrap());
-        request.set_payload(encoded);
+    ) -> Result<(CancelJobResponse, ResponseMetadata), RusotoError<CancelJobError>> {
+        let request = self.build_cancel_job_request(input);
 
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<CancelJobResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             Err(CancelJobError::from_response(response))
         }
     }
 
-    /// <p><p>Creates an AWS Batch compute environment. You can create <code>MANAGED</code> or <code>UNMANAGED</code> compute environments.</p> <p>In a managed compute environment, AWS Batch manages the capacity and instance types of the compute resources within the environment. This is based on the compute resource specification that you define or the <a href="https://docs.aws.amazon.com/AWSEC2/latest/UserGuide/ec2-launch-templates.html">launch template</a> that you specify when you create the compute environment. You can choose to use Amazon EC2 On-Demand Instances or Spot Instances in your managed compute environment. You can optionally set a maximum price so that Spot Instances only launch when the Spot Instance price is below a specified percentage of the On-Demand price.</p> <note> <p>Multi-node parallel jobs are not supported on Spot Instances.</p> </note> <p>In an unmanaged compute environment, you can manage your own compute resources. This provides more compute resource configuration options, such as using a custom AMI, but you must ensure that your AMI meets the Amazon ECS container instance AMI specification. For more information, see <a href="https://docs.aws.amazon.com/AmazonECS/latest/developerguide/container_instance_AMIs.html">Container Instance AMIs</a> in the <i>Amazon Elastic Container Service Developer Guide</i>. After you have created your unmanaged compute environment, you can use the <a>DescribeComputeEnvironments</a> operation to find the Amazon ECS cluster that is associated with it. Then, manually launch your container instances into that Amazon ECS cluster. For more information, see <a href="https://docs.aws.amazon.com/AmazonECS/latest/developerguide/launch_container_instance.html">Launching an Amazon ECS Container Instance</a> in the <i>Amazon Elastic Container Service Developer Guide</i>.</p> <note> <p>AWS Batch does not upgrade the AMIs in a compute environment after it is created (for example, when a newer version of the Amazon ECS-optimized AMI is available). You are responsible for the management of the guest operating system (including updates and security patches) and any additional application software or utilities that you install on the compute resources. To use a new AMI for your AWS Batch jobs:</p> <ol> <li> <p>Create a new compute environment with the new AMI.</p> </li> <li> <p>Add the compute environment to an existing job queue.</p> </li> <li> <p>Remove the old compute environment from your job queue.</p> </li> <li> <p>Delete the old compute environment.</p> </li> </ol> </note></p>
-    async fn create_compute_environment(
+    /// Presigns the request `cancel_job` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_cancel_job(
         &self,
-        input: CreateComputeEnvironmentRequest,
-    ) -> Result<CreateComputeEnvironmentResponse, RusotoError<CreateComputeEnvironmentError>> {
-        let request_uri = "/v1/createcomputeenvironment";
+        presigner: &rusoto_core::Presigner,
+        input: CancelJobRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_cancel_job_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_cancel_job_request(&self, input: CancelJobRequest) -> SignedRequest {
+        let request_uri = "/v1/canceljob";
 
         let mut request = SignedRequest::new("POST", "batch", &self.region, &request_uri);
+        request.set_operation("CancelJob");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `create_compute_environment`, also returning the metadata of the response.
+    pub async fn create_compute_environment_with_metadata(
+        &self,
+        input: CreateComputeEnvironmentRequest,
+    ) -> Result<
+        (CreateComputeEnvironmentResponse, ResponseMetadata),
+        RusotoError<CreateComputeEnvironmentError>,
+    > {
+        let request = self.build_create_compute_environment_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<CreateComputeEnvironmentResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             Err(CreateComputeEnvironmentError::from_response(response))
         }
     }
 
-    /// <p>Creates an AWS Batch job queue. When you create a job queue, you associate one or more compute environments to the queue and assign an order of preference for the compute environments.</p> <p>You also set a priority to the job queue that determines the order in which the AWS Batch scheduler places jobs onto its associated compute environments. For example, if a compute environment is associated with more than one job queue, the job queue with a higher priority is given preference for scheduling jobs to that compute environment.</p>
-    async fn create_job_queue(
+    /// Presigns the request `create_compute_environment` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_compute_environment(
         &self,
-        input: CreateJobQueueRequest,
-    ) -> Result<CreateJobQueueResponse, RusotoError<CreateJobQueueError>> {
-        let request_uri = "/v1/createjobqueue";
+        presigner: &rusoto_core::Presigner,
+        input: CreateComputeEnvironmentRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_compute_environment_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_compute_environment_request(
+        &self,
+        input: CreateComputeEnvironmentRequest,
+    ) -> SignedRequest {
+        let request_uri = "/v1/createcomputeenvironment";
 
         let mut request = SignedRequest::new("POST", "batch", &self.region, &request_uri);
+        request.set_operation("CreateComputeEnvironment");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `create_job_queue`, also returning the metadata of the response.
+    pub async fn create_job_queue_with_metadata(
+        &self,
+        input: CreateJobQueueRequest,
+    ) -> Result<(CreateJobQueueResponse, ResponseMetadata), RusotoError<CreateJobQueueError>> {
+        let request = self.build_create_job_queue_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<CreateJobQueueResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             Err(CreateJobQueueError::from_response(response))
         }
     }
 
-    /// <p>Deletes an AWS Batch compute environment.</p> <p>Before you can delete a compute environment, you must set its state to <code>DISABLED</code> with the <a>UpdateComputeEnvironment</a> API operation and disassociate it from any job queues with the <a>UpdateJobQueue</a> API operation.</p>
-    async fn delete_compute_environment(
+    /// Presigns the request `create_job_queue` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_job_queue(
         &self,
-        input: DeleteComputeEnvironmentRequest,
-    ) -> Result<DeleteComputeEnvironmentResponse, RusotoError<DeleteComputeEnvironmentError>> {
-        let request_uri = "/v1/deletecomputeenvironment";
+        presigner: &rusoto_core::Presigner,
+        input: CreateJobQueueRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_job_queue_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_job_queue_request(&self, input: CreateJobQueueRequest) -> SignedRequest {
+        let request_uri = "/v1/createjobqueue";
 
         let mut request = SignedRequest::new("POST", "batch", &self.region, &request_uri);
+        request.set_operation("CreateJobQueue");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `delete_compute_environment`, also returning the metadata of the response.
+    pub async fn delete_compute_environment_with_metadata(
+        &self,
+        input: DeleteComputeEnvironmentRequest,
+    ) -> Result<
+        (DeleteComputeEnvironmentResponse, ResponseMetadata),
+        RusotoError<DeleteComputeEnvironmentError>,
+    > {
+        let request = self.build_delete_compute_environment_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<DeleteComputeEnvironmentResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             Err(DeleteComputeEnvironmentError::from_response(response))
         }
     }
 
-    /// <p>Deletes the specified job queue. You must first disable submissions for a queue with the <a>UpdateJobQueue</a> operation. All jobs in the queue are terminated when you delete a job queue.</p> <p>It is not necessary to disassociate compute environments from a queue before submitting a <code>DeleteJobQueue</code> request.</p>
-    async fn delete_job_queue(
+    /// Presigns the request `delete_compute_environment` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_compute_environment(
         &self,
-        input: DeleteJobQueueRequest,
-    ) -> Result<DeleteJobQueueResponse, RusotoError<DeleteJobQueueError>> {
-        let request_uri = "/v1/deletejobqueue";
+        presigner: &rusoto_core::Presigner,
+        input: DeleteComputeEnvironmentRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_compute_environment_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_compute_environment_request(
+        &self,
+        input: DeleteComputeEnvironmentRequest,
+    ) -> SignedRequest {
+        let request_uri = "/v1/deletecomputeenvironment";
 
         let mut request = SignedRequest::new("POST", "batch", &self.region, &request_uri);
+        request.set_operation("DeleteComputeEnvironment");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `delete_job_queue`, also returning the metadata of the response.
+    pub async fn delete_job_queue_with_metadata(
+        &self,
+        input: DeleteJobQueueRequest,
+    ) -> Result<(DeleteJobQueueResponse, ResponseMetadata), RusotoError<DeleteJobQueueError>> {
+        let request = self.build_delete_job_queue_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<DeleteJobQueueResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             Err(DeleteJobQueueError::from_response(response))
         }
     }
 
-    /// <p>Deregisters an AWS Batch job definition. Job definitions will be permanently deleted after 180 days.</p>
-    async fn deregister_job_definition(
+    /// Presigns the request `delete_job_queue` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_job_queue(
         &self,
-        input: DeregisterJobDefinitionRequest,
-    ) -> Result<DeregisterJobDefinitionResponse, RusotoError<DeregisterJobDefinitionError>> {
-        let request_uri = "/v1/deregisterjobdefinition";
+        presigner: &rusoto_core::Presigner,
+        input: DeleteJobQueueRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_job_queue_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_job_queue_request(&self, input: DeleteJobQueueRequest) -> SignedRequest {
+        let request_uri = "/v1/deletejobqueue";
 
         let mut request = SignedRequest::new("POST", "batch", &self.region, &request_uri);
+        request.set_operation("DeleteJobQueue");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `deregister_job_definition`, also returning the metadata of the response.
+    pub async fn deregister_job_definition_with_metadata(
+        &self,
+        input: DeregisterJobDefinitionRequest,
+    ) -> Result<
+        (DeregisterJobDefinitionResponse, ResponseMetadata),
+        RusotoError<DeregisterJobDefinitionError>,
+    > {
+        let request = self.build_deregister_job_definition_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<DeregisterJobDefinitionResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             Err(DeregisterJobDefinitionError::from_response(response))
         }
     }
 
-    /// <p>Describes one or more of your compute environments.</p> <p>If you are using an unmanaged compute environment, you can use the <code>DescribeComputeEnvironment</code> operation to determine the <code>ecsClusterArn</code> that you should launch your Amazon ECS container instances into.</p>
-    async fn describe_compute_environments(
+    /// Presigns the request `deregister_job_definition` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_deregister_job_definition(
         &self,
-        input: DescribeComputeEnvironmentsRequest,
-    ) -> Result<DescribeComputeEnvironmentsResponse, RusotoError<DescribeComputeEnvironmentsError>>
-    {
-        let request_uri = "/v1/describecomputeenvironments";
+        presigner: &rusoto_core::Presigner,
+        input: DeregisterJobDefinitionRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_deregister_job_definition_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_deregister_job_definition_request(
+        &self,
+        input: DeregisterJobDefinitionRequest,
+    ) -> SignedRequest {
+        let request_uri = "/v1/deregisterjobdefinition";
 
         let mut request = SignedRequest::new("POST", "batch", &self.region, &request_uri);
+        request.set_operation("DeregisterJobDefinition");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `describe_compute_environments`, also returning the metadata of the response.
+    pub async fn describe_compute_environments_with_metadata(
+        &self,
+        input: DescribeComputeEnvironmentsRequest,
+    ) -> Result<
+        (DescribeComputeEnvironmentsResponse, ResponseMetadata),
+        RusotoError<DescribeComputeEnvironmentsError>,
+    > {
+        let request = self.build_describe_compute_environments_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<DescribeComputeEnvironmentsResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             Err(DescribeComputeEnvironmentsError::from_response(response))
         }
     }
 
-    /// <p>Describes a list of job definitions. You can specify a <code>status</code> (such as <code>ACTIVE</code>) to only return job definitions that match that status.</p>
-    async fn describe_job_definitions(
+    /// Presigns the request `describe_compute_environments` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_compute_environments(
         &self,
-        input: DescribeJobDefinitionsRequest,
-    ) -> Result<DescribeJobDefinitionsResponse, RusotoError<DescribeJobDefinitionsError>> {
-        let request_uri = "/v1/describejobdefinitions";
+        presigner: &rusoto_core::Presigner,
+        input: DescribeComputeEnvironmentsRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_compute_environments_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_compute_environments_request(
+        &self,
+        input: DescribeComputeEnvironmentsRequest,
+    ) -> SignedRequest {
+        let request_uri = "/v1/describecomputeenvironments";
 
         let mut request = SignedRequest::new("POST", "batch", &self.region, &request_uri);
+        request.set_operation("DescribeComputeEnvironments");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `describe_job_definitions`, also returning the metadata of the response.
+    pub async fn describe_job_definitions_with_metadata(
+        &self,
+        input: DescribeJobDefinitionsRequest,
+    ) -> Result<
+        (DescribeJobDefinitionsResponse, ResponseMetadata),
+        RusotoError<DescribeJobDefinitionsError>,
+    > {
+        let request = self.build_describe_job_definitions_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<DescribeJobDefinitionsResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             Err(DescribeJobDefinitionsError::from_response(response))
         }
     }
 
-    /// <p>Describes one or more of your job queues.</p>
-    async fn describe_job_queues(
+    /// Presigns the request `describe_job_definitions` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_job_definitions(
         &self,
-        input: DescribeJobQueuesRequest,
-    ) -> Result<DescribeJobQueuesResponse, RusotoError<DescribeJobQueuesError>> {
-        let request_uri = "/v1/describejobqueues";
+        presigner: &rusoto_core::Presigner,
+        input: DescribeJobDefinitionsRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_job_definitions_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_job_definitions_request(
+        &self,
+        input: DescribeJobDefinitionsRequest,
+    ) -> SignedRequest {
+        let request_uri = "/v1/describejobdefinitions";
 
         let mut request = SignedRequest::new("POST", "batch", &self.region, &request_uri);
+        request.set_operation("DescribeJobDefinitions");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `describe_job_queues`, also returning the metadata of the response.
+    pub async fn describe_job_queues_with_metadata(
+        &self,
+        input: DescribeJobQueuesRequest,
+    ) -> Result<(DescribeJobQueuesResponse, ResponseMetadata), RusotoError<DescribeJobQueuesError>>
+    {
+        let request = self.build_describe_job_queues_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<DescribeJobQueuesResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             Err(DescribeJobQueuesError::from_response(response))
         }
     }
 
-    /// <p>Describes a list of AWS Batch jobs.</p>
-    async fn describe_jobs(
+    /// Presigns the request `describe_job_queues` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_job_queues(
         &self,
-        input: DescribeJobsRequest,
-    ) -> Result<DescribeJobsResponse, RusotoError<DescribeJobsError>> {
-        let request_uri = "/v1/describejobs";
+        presigner: &rusoto_core::Presigner,
+        input: DescribeJobQueuesRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_job_queues_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_job_queues_request(&self, input: DescribeJobQueuesRequest) -> SignedRequest {
+        let request_uri = "/v1/describejobqueues";
 
         let mut request = SignedRequest::new("POST", "batch", &self.region, &request_uri);
+        request.set_operation("DescribeJobQueues");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `describe_jobs`, also returning the metadata of the response.
+    pub async fn describe_jobs_with_metadata(
+        &self,
+        input: DescribeJobsRequest,
+    ) -> Result<(DescribeJobsResponse, ResponseMetadata), RusotoError<DescribeJobsError>> {
+        let request = self.build_describe_jobs_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<DescribeJobsResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             Err(DescribeJobsError::from_response(response))
         }
     }
 
-    /// <p>Returns a list of AWS Batch jobs.</p> <p>You must specify only one of the following:</p> <ul> <li> <p>a job queue ID to return a list of jobs in that job queue</p> </li> <li> <p>a multi-node parallel job ID to return a list of that job's nodes</p> </li> <li> <p>an array job ID to return a list of that job's children</p> </li> </ul> <p>You can filter the results by job status with the <code>jobStatus</code> parameter. If you do not specify a status, only <code>RUNNING</code> jobs are returned.</p>
-    async fn list_jobs(
+    /// Presigns the request `describe_jobs` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_jobs(
         &self,
-        input: ListJobsRequest,
-    ) -> Result<ListJobsResponse, RusotoError<ListJobsError>> {
-        let request_uri = "/v1/listjobs";
+        presigner: &rusoto_core::Presigner,
+        input: DescribeJobsRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_jobs_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_jobs_request(&self, input: DescribeJobsRequest) -> SignedRequest {
+        let request_uri = "/v1/describejobs";
 
         let mut request = SignedRequest::new("POST", "batch", &self.region, &request_uri);
+        request.set_operation("DescribeJobs");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `list_jobs`, also returning the metadata of the response.
+    pub async fn list_jobs_with_metadata(
+        &self,
+        input: ListJobsRequest,
+    ) -> Result<(ListJobsResponse, ResponseMetadata), RusotoError<ListJobsError>> {
+        let request = self.build_list_jobs_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<ListJobsResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             Err(ListJobsError::from_response(response))
         }
     }
 
-    /// <p>Registers an AWS Batch job definition.</p>
-    async fn register_job_definition(
+    /// Presigns the request `list_jobs` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_jobs(
         &self,
-        input: RegisterJobDefinitionRequest,
-    ) -> Result<RegisterJobDefinitionResponse, RusotoError<RegisterJobDefinitionError>> {
-        let request_uri = "/v1/registerjobdefinition";
+        presigner: &rusoto_core::Presigner,
+        input: ListJobsRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_jobs_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_jobs_request(&self, input: ListJobsRequest) -> SignedRequest {
+        let request_uri = "/v1/listjobs";
 
         let mut request = SignedRequest::new("POST", "batch", &self.region, &request_uri);
+        request.set_operation("ListJobs");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `register_job_definition`, also returning the metadata of the response.
+    pub async fn register_job_definition_with_metadata(
+        &self,
+        input: RegisterJobDefinitionRequest,
+    ) -> Result<
+        (RegisterJobDefinitionResponse, ResponseMetadata),
+        RusotoError<RegisterJobDefinitionError>,
+    > {
+        let request = self.build_register_job_definition_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<RegisterJobDefinitionResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             Err(RegisterJobDefinitionError::from_response(response))
         }
     }
 
-    /// <p>Submits an AWS Batch job from a job definition. Parameters specified during <a>SubmitJob</a> override parameters defined in the job definition.</p>
-    async fn submit_job(
+    /// Presigns the request `register_job_definition` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_register_job_definition(
         &self,
-        input: SubmitJobRequest,
-    ) -> Result<SubmitJobResponse, RusotoError<SubmitJobError>> {
-        let request_uri = "/v1/submitjob";
+        presigner: &rusoto_core::Presigner,
+        input: RegisterJobDefinitionRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_register_job_definition_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_register_job_definition_request(
+        &self,
+        input: RegisterJobDefinitionRequest,
+    ) -> SignedRequest {
+        let request_uri = "/v1/registerjobdefinition";
 
         let mut request = SignedRequest::new("POST", "batch", &self.region, &request_uri);
+        request.set_operation("RegisterJobDefinition");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `submit_job`, also returning the metadata of the response.
+    pub async fn submit_job_with_metadata(
+        &self,
+        input: SubmitJobRequest,
+    ) -> Result<(SubmitJobResponse, ResponseMetadata), RusotoError<SubmitJobError>> {
+        let request = self.build_submit_job_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<SubmitJobResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             Err(SubmitJobError::from_response(response))
         }
     }
 
-    /// <p>Terminates a job in a job queue. Jobs that are in the <code>STARTING</code> or <code>RUNNING</code> state are terminated, which causes them to transition to <code>FAILED</code>. Jobs that have not progressed to the <code>STARTING</code> state are cancelled.</p>
-    async fn terminate_job(
+    /// Presigns the request `submit_job` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_submit_job(
         &self,
-        input: TerminateJobRequest,
-    ) -> Result<TerminateJobResponse, RusotoError<TerminateJobError>> {
-        let request_uri = "/v1/terminatejob";
+        presigner: &rusoto_core::Presigner,
+        input: SubmitJobRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_submit_job_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_submit_job_request(&self, input: SubmitJobRequest) -> SignedRequest {
+        let request_uri = "/v1/submitjob";
 
         let mut request = SignedRequest::new("POST", "batch", &self.region, &request_uri);
+        request.set_operation("SubmitJob");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `terminate_job`, also returning the metadata of the response.
+    pub async fn terminate_job_with_metadata(
+        &self,
+        input: TerminateJobRequest,
+    ) -> Result<(TerminateJobResponse, ResponseMetadata), RusotoError<TerminateJobError>> {
+        let request = self.build_terminate_job_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<TerminateJobResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             Err(TerminateJobError::from_response(response))
         }
     }
 
-    /// <p>Updates an AWS Batch compute environment.</p>
-    async fn update_compute_environment(
+    /// Presigns the request `terminate_job` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_terminate_job(
         &self,
-        input: UpdateComputeEnvironmentRequest,
-    ) -> Result<UpdateComputeEnvironmentResponse, RusotoError<UpdateComputeEnvironmentError>> {
-        let request_uri = "/v1/updatecomputeenvironment";
+        presigner: &rusoto_core::Presigner,
+        input: TerminateJobRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_terminate_job_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_terminate_job_request(&self, input: TerminateJobRequest) -> SignedRequest {
+        let request_uri = "/v1/terminatejob";
 
         let mut request = SignedRequest::new("POST", "batch", &self.region, &request_uri);
+        request.set_operation("TerminateJob");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `update_compute_environment`, also returning the metadata of the response.
+    pub async fn update_compute_environment_with_metadata(
+        &self,
+        input: UpdateComputeEnvironmentRequest,
+    ) -> Result<
+        (UpdateComputeEnvironmentResponse, ResponseMetadata),
+        RusotoError<UpdateComputeEnvironmentError>,
+    > {
+        let request = self.build_update_compute_environment_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<UpdateComputeEnvironmentResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             Err(UpdateComputeEnvironmentError::from_response(response))
         }
     }
 
-    /// <p>Updates a job queue.</p>
-    async fn update_job_queue(
+    /// Presigns the request `update_compute_environment` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_compute_environment(
         &self,
-        input: UpdateJobQueueRequest,
-    ) -> Result<UpdateJobQueueResponse, RusotoError<UpdateJobQueueError>> {
-        let request_uri = "/v1/updatejobqueue";
+        presigner: &rusoto_core::Presigner,
+        input: UpdateComputeEnvironmentRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_compute_environment_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_compute_environment_request(
+        &self,
+        input: UpdateComputeEnvironmentRequest,
+    ) -> SignedRequest {
+        let request_uri = "/v1/updatecomputeenvironment";
 
         let mut request = SignedRequest::new("POST", "batch", &self.region, &request_uri);
+        request.set_operation("UpdateComputeEnvironment");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `update_job_queue`, also returning the metadata of the response.
+    pub async fn update_job_queue_with_metadata(
+        &self,
+        input: UpdateJobQueueRequest,
+    ) -> Result<(UpdateJobQueueResponse, ResponseMetadata), RusotoError<UpdateJobQueueError>> {
+        let request = self.build_update_job_queue_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<UpdateJobQueueResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             Err(UpdateJobQueueError::from_response(response))
         }
     }
+
+    /// Presigns the request `update_job_queue` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_job_queue(
+        &self,
+        presigner: &rusoto_core::Presigner,
+        input: UpdateJobQueueRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_job_queue_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_job_queue_request(&self, input: UpdateJobQueueRequest) -> SignedRequest {
+        let request_uri = "/v1/updatejobqueue";
+
+        let mut request = SignedRequest::new("POST", "batch", &self.region, &request_uri);
+        request.set_operation("UpdateJobQueue");
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let encoded = Some(serde_json::to_vec(&input).unwrap());
+        request.set_payload(encoded);
+
+        request
+    }
+
+    /// The pages of `DescribeComputeEnvironments` results for `input`, requesting each next page with the `nextToken` of the last one as its `nextToken`.
+    /// The page size is the `maxResults` of `input`.
+    pub fn describe_compute_environments_pages(
+        &self,
+        input: DescribeComputeEnvironmentsRequest,
+    ) -> impl futures::Stream<
+        Item = Result<
+            DescribeComputeEnvironmentsResponse,
+            RusotoError<DescribeComputeEnvironmentsError>,
+        >,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: BatchClient, input| async move {
+                client.describe_compute_environments(input).await
+            },
+            |input: &DescribeComputeEnvironmentsRequest,
+             output: &DescribeComputeEnvironmentsResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(DescribeComputeEnvironmentsRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `computeEnvironments` of every page of `DescribeComputeEnvironments` results for `input`, as `describe_compute_environments_pages` requests them.
+    pub fn describe_compute_environments_stream(
+        &self,
+        input: DescribeComputeEnvironmentsRequest,
+    ) -> impl futures::Stream<
+        Item = Result<ComputeEnvironmentDetail, RusotoError<DescribeComputeEnvironmentsError>>,
+    > + Send {
+        rusoto_core::pagination::items(self.describe_compute_environments_pages(input), |page| {
+            page.compute_environments.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `DescribeJobDefinitions` results for `input`, requesting each next page with the `nextToken` of the last one as its `nextToken`.
+    /// The page size is the `maxResults` of `input`.
+    pub fn describe_job_definitions_pages(
+        &self,
+        input: DescribeJobDefinitionsRequest,
+    ) -> impl futures::Stream<
+        Item = Result<DescribeJobDefinitionsResponse, RusotoError<DescribeJobDefinitionsError>>,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: BatchClient, input| async move { client.describe_job_definitions(input).await },
+            |input: &DescribeJobDefinitionsRequest, output: &DescribeJobDefinitionsResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(DescribeJobDefinitionsRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `jobDefinitions` of every page of `DescribeJobDefinitions` results for `input`, as `describe_job_definitions_pages` requests them.
+    pub fn describe_job_definitions_stream(
+        &self,
+        input: DescribeJobDefinitionsRequest,
+    ) -> impl futures::Stream<Item = Result<JobDefinition, RusotoError<DescribeJobDefinitionsError>>>
+           + Send {
+        rusoto_core::pagination::items(self.describe_job_definitions_pages(input), |page| {
+            page.job_definitions.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `DescribeJobQueues` results for `input`, requesting each next page with the `nextToken` of the last one as its `nextToken`.
+    /// The page size is the `maxResults` of `input`.
+    pub fn describe_job_queues_pages(
+        &self,
+        input: DescribeJobQueuesRequest,
+    ) -> impl futures::Stream<
+        Item = Result<DescribeJobQueuesResponse, RusotoError<DescribeJobQueuesError>>,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: BatchClient, input| async move { client.describe_job_queues(input).await },
+            |input: &DescribeJobQueuesRequest, output: &DescribeJobQueuesResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(DescribeJobQueuesRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `jobQueues` of every page of `DescribeJobQueues` results for `input`, as `describe_job_queues_pages` requests them.
+    pub fn describe_job_queues_stream(
+        &self,
+        input: DescribeJobQueuesRequest,
+    ) -> impl futures::Stream<Item = Result<JobQueueDetail, RusotoError<DescribeJobQueuesError>>> + Send
+    {
+        rusoto_core::pagination::items(self.describe_job_queues_pages(input), |page| {
+            page.job_queues.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `ListJobs` results for `input`, requesting each next page with the `nextToken` of the last one as its `nextToken`.
+    /// The page size is the `maxResults` of `input`.
+    pub fn list_jobs_pages(
+        &self,
+        input: ListJobsRequest,
+    ) -> impl futures::Stream<Item = Result<ListJobsResponse, RusotoError<ListJobsError>>> + Send
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: BatchClient, input| async move { client.list_jobs(input).await },
+            |input: &ListJobsRequest, output: &ListJobsResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListJobsRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `jobSummaryList` of every page of `ListJobs` results for `input`, as `list_jobs_pages` requests them.
+    pub fn list_jobs_stream(
+        &self,
+        input: ListJobsRequest,
+    ) -> impl futures::Stream<Item = Result<JobSummary, RusotoError<ListJobsError>>> + Send {
+        rusoto_core::pagination::items(self.list_jobs_pages(input), |page| page.job_summary_list)
+    }
+}
+
+#[async_trait]
+impl Batch for BatchClient {
+    /// <p>Cancels a job in an AWS Batch job queue. Jobs that are in the <code>SUBMITTED</code>, <code>PENDING</code>, or <code>RUNNABLE</code> state are cancelled. Jobs that have progressed to <code>STARTING</code> or <code>RUNNING</code> are not cancelled (but the API operation still succeeds, even if no job is cancelled); these jobs must be terminated with the <a>TerminateJob</a> operation.</p>
+    async fn cancel_job(
+        &self,
+        input: CancelJobRequest,
+    ) -> Result<CancelJobResponse, RusotoError<CancelJobError>> {
+        self.cancel_job_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Creates an AWS Batch compute environment. You can create <code>MANAGED</code> or <code>UNMANAGED</code> compute environments.</p> <p>In a managed compute environment, AWS Batch manages the capacity and instance types of the compute resources within the environment. This is based on the compute resource specification that you define or the <a href="https://docs.aws.amazon.com/AWSEC2/latest/UserGuide/ec2-launch-templates.html">launch template</a> that you specify when you create the compute environment. You can choose to use Amazon EC2 On-Demand Instances or Spot Instances in your managed compute environment. You can optionally set a maximum price so that Spot Instances only launch when the Spot Instance price is below a specified percentage of the On-Demand price.</p> <note> <p>Multi-node parallel jobs are not supported on Spot Instances.</p> </note> <p>In an unmanaged compute environment, you can manage your own compute resources. This provides more compute resource configuration options, such as using a custom AMI, but you must ensure that your AMI meets the Amazon ECS container instance AMI specification. For more information, see <a href="https://docs.aws.amazon.com/AmazonECS/latest/developerguide/container_instance_AMIs.html">Container Instance AMIs</a> in the <i>Amazon Elastic Container Service Developer Guide</i>. After you have created your unmanaged compute environment, you can use the <a>DescribeComputeEnvironments</a> operation to find the Amazon ECS cluster that is associated with it. Then, manually launch your container instances into that Amazon ECS cluster. For more information, see <a href="https://docs.aws.amazon.com/AmazonECS/latest/developerguide/launch_container_instance.html">Launching an Amazon ECS Container Instance</a> in the <i>Amazon Elastic Container Service Developer Guide</i>.</p> <note> <p>AWS Batch does not upgrade the AMIs in a compute environment after it is created (for example, when a newer version of the Amazon ECS-optimized AMI is available). You are responsible for the management of the guest operating system (including updates and security patches) and any additional application software or utilities that you install on the compute resources. To use a new AMI for your AWS Batch jobs:</p> <ol> <li> <p>Create a new compute environment with the new AMI.</p> </li> <li> <p>Add the compute environment to an existing job queue.</p> </li> <li> <p>Remove the old compute environment from your job queue.</p> </li> <li> <p>Delete the old compute environment.</p> </li> </ol> </note></p>
+    async fn create_compute_environment(
+        &self,
+        input: CreateComputeEnvironmentRequest,
+    ) -> Result<CreateComputeEnvironmentResponse, RusotoError<CreateComputeEnvironmentError>> {
+        self.create_compute_environment_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Creates an AWS Batch job queue. When you create a job queue, you associate one or more compute environments to the queue and assign an order of preference for the compute environments.</p> <p>You also set a priority to the job queue that determines the order in which the AWS Batch scheduler places jobs onto its associated compute environments. For example, if a compute environment is associated with more than one job queue, the job queue with a higher priority is given preference for scheduling jobs to that compute environment.</p>
+    async fn create_job_queue(
+        &self,
+        input: CreateJobQueueRequest,
+    ) -> Result<CreateJobQueueResponse, RusotoError<CreateJobQueueError>> {
+        self.create_job_queue_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes an AWS Batch compute environment.</p> <p>Before you can delete a compute environment, you must set its state to <code>DISABLED</code> with the <a>UpdateComputeEnvironment</a> API operation and disassociate it from any job queues with the <a>UpdateJobQueue</a> API operation.</p>
+    async fn delete_compute_environment(
+        &self,
+        input: DeleteComputeEnvironmentRequest,
+    ) -> Result<DeleteComputeEnvironmentResponse, RusotoError<DeleteComputeEnvironmentError>> {
+        self.delete_compute_environment_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes the specified job queue. You must first disable submissions for a queue with the <a>UpdateJobQueue</a> operation. All jobs in the queue are terminated when you delete a job queue.</p> <p>It is not necessary to disassociate compute environments from a queue before submitting a <code>DeleteJobQueue</code> request.</p>
+    async fn delete_job_queue(
+        &self,
+        input: DeleteJobQueueRequest,
+    ) -> Result<DeleteJobQueueResponse, RusotoError<DeleteJobQueueError>> {
+        self.delete_job_queue_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deregisters an AWS Batch job definition. Job definitions will be permanently deleted after 180 days.</p>
+    async fn deregister_job_definition(
+        &self,
+        input: DeregisterJobDefinitionRequest,
+    ) -> Result<DeregisterJobDefinitionResponse, RusotoError<DeregisterJobDefinitionError>> {
+        self.deregister_job_definition_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Describes one or more of your compute environments.</p> <p>If you are using an unmanaged compute environment, you can use the <code>DescribeComputeEnvironment</code> operation to determine the <code>ecsClusterArn</code> that you should launch your Amazon ECS container instances into.</p>
+    async fn describe_compute_environments(
+        &self,
+        input: DescribeComputeEnvironmentsRequest,
+    ) -> Result<DescribeComputeEnvironmentsResponse, RusotoError<DescribeComputeEnvironmentsError>>
+    {
+        self.describe_compute_environments_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Describes a list of job definitions. You can specify a <code>status</code> (such as <code>ACTIVE</code>) to only return job definitions that match that status.</p>
+    async fn describe_job_definitions(
+        &self,
+        input: DescribeJobDefinitionsRequest,
+    ) -> Result<DescribeJobDefinitionsResponse, RusotoError<DescribeJobDefinitionsError>> {
+        self.describe_job_definitions_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Describes one or more of your job queues.</p>
+    async fn describe_job_queues(
+        &self,
+        input: DescribeJobQueuesRequest,
+    ) -> Result<DescribeJobQueuesResponse, RusotoError<DescribeJobQueuesError>> {
+        self.describe_job_queues_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Describes a list of AWS Batch jobs.</p>
+    async fn describe_jobs(
+        &self,
+        input: DescribeJobsRequest,
+    ) -> Result<DescribeJobsResponse, RusotoError<DescribeJobsError>> {
+        self.describe_jobs_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns a list of AWS Batch jobs.</p> <p>You must specify only one of the following:</p> <ul> <li> <p>a job queue ID to return a list of jobs in that job queue</p> </li> <li> <p>a multi-node parallel job ID to return a list of that job's nodes</p> </li> <li> <p>an array job ID to return a list of that job's children</p> </li> </ul> <p>You can filter the results by job status with the <code>jobStatus</code> parameter. If you do not specify a status, only <code>RUNNING</code> jobs are returned.</p>
+    async fn list_jobs(
+        &self,
+        input: ListJobsRequest,
+    ) -> Result<ListJobsResponse, RusotoError<ListJobsError>> {
+        self.list_jobs_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Registers an AWS Batch job definition.</p>
+    async fn register_job_definition(
+        &self,
+        input: RegisterJobDefinitionRequest,
+    ) -> Result<RegisterJobDefinitionResponse, RusotoError<RegisterJobDefinitionError>> {
+        self.register_job_definition_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Submits an AWS Batch job from a job definition. Parameters specified during <a>SubmitJob</a> override parameters defined in the job definition.</p>
+    async fn submit_job(
+        &self,
+        input: SubmitJobRequest,
+    ) -> Result<SubmitJobResponse, RusotoError<SubmitJobError>> {
+        self.submit_job_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Terminates a job in a job queue. Jobs that are in the <code>STARTING</code> or <code>RUNNING</code> state are terminated, which causes them to transition to <code>FAILED</code>. Jobs that have not progressed to the <code>STARTING</code> state are cancelled.</p>
+    async fn terminate_job(
+        &self,
+        input: TerminateJobRequest,
+    ) -> Result<TerminateJobResponse, RusotoError<TerminateJobError>> {
+        self.terminate_job_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Updates an AWS Batch compute environment.</p>
+    async fn update_compute_environment(
+        &self,
+        input: UpdateComputeEnvironmentRequest,
+    ) -> Result<UpdateComputeEnvironmentResponse, RusotoError<UpdateComputeEnvironmentError>> {
+        self.update_compute_environment_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Updates a job queue.</p>
+    async fn update_job_queue(
+        &self,
+        input: UpdateJobQueueRequest,
+    ) -> Result<UpdateJobQueueResponse, RusotoError<UpdateJobQueueError>> {
+        self.update_job_queue_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
 }
@@ -16,8 +16,8 @@ use std::fmt;
 use async_trait::async_trait;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
-use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest, ResponseMetadata};
+use rusoto_core::{Client, ErrorMetadata, RusotoError};
 
 use rusoto_core::proto;
 use rusoto_core::signature::SignedRequest;
@@ -591,6 +591,7 @@ pub struct UpdateSubscriberResponse {}
 
 /// Errors returned by CreateBudget
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateBudgetError {
     /// <p>You are not authorized to use this operation with the given parameters.</p>
     AccessDenied(String),
@@ -602,6 +603,8 @@ pub enum CreateBudgetError {
     InternalError(String),
     /// <p>An error on the client occurred. Typically, the cause is an invalid input value.</p>
     InvalidParameter(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateBudgetError {
@@ -609,22 +612,25 @@ impl CreateBudgetError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(CreateBudgetError::AccessDenied(err.msg))
+                    return RusotoError::service(CreateBudgetError::AccessDenied(err.msg), &res)
                 }
                 "CreationLimitExceededException" => {
-                    return RusotoError::Service(CreateBudgetError::CreationLimitExceeded(err.msg))
+                    return RusotoError::service(
+                        CreateBudgetError::CreationLimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "DuplicateRecordException" => {
-                    return RusotoError::Service(CreateBudgetError::DuplicateRecord(err.msg))
+                    return RusotoError::service(CreateBudgetError::DuplicateRecord(err.msg), &res)
                 }
                 "InternalErrorException" => {
-                    return RusotoError::Service(CreateBudgetError::InternalError(err.msg))
+                    return RusotoError::service(CreateBudgetError::InternalError(err.msg), &res)
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(CreateBudgetError::InvalidParameter(err.msg))
+                    return RusotoError::service(CreateBudgetError::InvalidParameter(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreateBudgetError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -639,12 +645,14 @@ impl fmt::Display for CreateBudgetError {
             CreateBudgetError::DuplicateRecord(ref cause) => write!(f, "{}", cause),
             CreateBudgetError::InternalError(ref cause) => write!(f, "{}", cause),
             CreateBudgetError::InvalidParameter(ref cause) => write!(f, "{}", cause),
+            CreateBudgetError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateBudgetError {}
 /// Errors returned by CreateNotification
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateNotificationError {
     /// <p>You are not authorized to use this operation with the given parameters.</p>
     AccessDenied(String),
@@ -658,6 +666,8 @@ pub enum CreateNotificationError {
     InvalidParameter(String),
     /// <p>We can’t locate the resource that you specified.</p>
     NotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateNotificationError {
@@ -665,27 +675,40 @@ impl CreateNotificationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(CreateNotificationError::AccessDenied(err.msg))
+                    return RusotoError::service(
+                        CreateNotificationError::AccessDenied(err.msg),
+                        &res,
+                    )
                 }
                 "CreationLimitExceededException" => {
-                    return RusotoError::Service(CreateNotificationError::CreationLimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateNotificationError::CreationLimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "DuplicateRecordException" => {
-                    return RusotoError::Service(CreateNotificationError::DuplicateRecord(err.msg))
+                    return RusotoError::service(
+                        CreateNotificationError::DuplicateRecord(err.msg),
+                        &res,
+                    )
                 }
                 "InternalErrorException" => {
-                    return RusotoError::Service(CreateNotificationError::InternalError(err.msg))
+                    return RusotoError::service(
+                        CreateNotificationError::InternalError(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(CreateNotificationError::InvalidParameter(err.msg))
+                    return RusotoError::service(
+                        CreateNotificationError::InvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(CreateNotificationError::NotFound(err.msg))
+                    return RusotoError::service(CreateNotificationError::NotFound(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreateNotificationError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -701,12 +724,14 @@ impl fmt::Display for CreateNotificationError {
             CreateNotificationError::InternalError(ref cause) => write!(f, "{}", cause),
             CreateNotificationError::InvalidParameter(ref cause) => write!(f, "{}", cause),
             CreateNotificationError::NotFound(ref cause) => write!(f, "{}", cause),
+            CreateNotificationError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateNotificationError {}
 /// Errors returned by CreateSubscriber
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateSubscriberError {
     /// <p>You are not authorized to use this operation with the given parameters.</p>
     AccessDenied(String),
@@ -720,6 +745,8 @@ pub enum CreateSubscriberError {
     InvalidParameter(String),
     /// <p>We can’t locate the resource that you specified.</p>
     NotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateSubscriberError {
@@ -727,27 +754,37 @@ impl CreateSubscriberError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(CreateSubscriberError::AccessDenied(err.msg))
+                    return RusotoError::service(CreateSubscriberError::AccessDenied(err.msg), &res)
                 }
                 "CreationLimitExceededException" => {
-                    return RusotoError::Service(CreateSubscriberError::CreationLimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateSubscriberError::CreationLimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "DuplicateRecordException" => {
-                    return RusotoError::Service(CreateSubscriberError::DuplicateRecord(err.msg))
+                    return RusotoError::service(
+                        CreateSubscriberError::DuplicateRecord(err.msg),
+                        &res,
+                    )
                 }
                 "InternalErrorException" => {
-                    return RusotoError::Service(CreateSubscriberError::InternalError(err.msg))
+                    return RusotoError::service(
+                        CreateSubscriberError::InternalError(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(CreateSubscriberError::InvalidParameter(err.msg))
+                    return RusotoError::service(
+                        CreateSubscriberError::InvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(CreateSubscriberError::NotFound(err.msg))
+                    return RusotoError::service(CreateSubscriberError::NotFound(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreateSubscriberError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -763,12 +800,14 @@ impl fmt::Display for CreateSubscriberError {
             CreateSubscriberError::InternalError(ref cause) => write!(f, "{}", cause),
             CreateSubscriberError::InvalidParameter(ref cause) => write!(f, "{}", cause),
             CreateSubscriberError::NotFound(ref cause) => write!(f, "{}", cause),
+            CreateSubscriberError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateSubscriberError {}
 /// Errors returned by DeleteBudget
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteBudgetError {
     /// <p>You are not authorized to use this operation with the given parameters.</p>
     AccessDenied(String),
@@ -778,6 +817,8 @@ pub enum DeleteBudgetError {
     InvalidParameter(String),
     /// <p>We can’t locate the resource that you specified.</p>
     NotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteBudgetError {
@@ -785,19 +826,19 @@ impl DeleteBudgetError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(DeleteBudgetError::AccessDenied(err.msg))
+                    return RusotoError::service(DeleteBudgetError::AccessDenied(err.msg), &res)
                 }
                 "InternalErrorException" => {
-                    return RusotoError::Service(DeleteBudgetError::InternalError(err.msg))
+                    return RusotoError::service(DeleteBudgetError::InternalError(err.msg), &res)
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DeleteBudgetError::InvalidParameter(err.msg))
+                    return RusotoError::service(DeleteBudgetError::InvalidParameter(err.msg), &res)
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteBudgetError::NotFound(err.msg))
+                    return RusotoError::service(DeleteBudgetError::NotFound(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteBudgetError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -811,12 +852,14 @@ impl fmt::Display for DeleteBudgetError {
             DeleteBudgetError::InternalError(ref cause) => write!(f, "{}", cause),
             DeleteBudgetError::InvalidParameter(ref cause) => write!(f, "{}", cause),
             DeleteBudgetError::NotFound(ref cause) => write!(f, "{}", cause),
+            DeleteBudgetError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteBudgetError {}
 /// Errors returned by DeleteNotification
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteNotificationError {
     /// <p>You are not authorized to use this operation with the given parameters.</p>
     AccessDenied(String),
@@ -826,6 +869,8 @@ pub enum DeleteNotificationError {
     InvalidParameter(String),
     /// <p>We can’t locate the resource that you specified.</p>
     NotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteNotificationError {
@@ -833,19 +878,28 @@ impl DeleteNotificationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(DeleteNotificationError::AccessDenied(err.msg))
+                    return RusotoError::service(
+                        DeleteNotificationError::AccessDenied(err.msg),
+                        &res,
+                    )
                 }
                 "InternalErrorException" => {
-                    return RusotoError::Service(DeleteNotificationError::InternalError(err.msg))
+                    return RusotoError::service(
+                        DeleteNotificationError::InternalError(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DeleteNotificationError::InvalidParameter(err.msg))
+                    return RusotoError::service(
+                        DeleteNotificationError::InvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteNotificationError::NotFound(err.msg))
+                    return RusotoError::service(DeleteNotificationError::NotFound(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteNotificationError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -859,12 +913,14 @@ impl fmt::Display for DeleteNotificationError {
             DeleteNotificationError::InternalError(ref cause) => write!(f, "{}", cause),
             DeleteNotificationError::InvalidParameter(ref cause) => write!(f, "{}", cause),
             DeleteNotificationError::NotFound(ref cause) => write!(f, "{}", cause),
+            DeleteNotificationError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteNotificationError {}
 /// Errors returned by DeleteSubscriber
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteSubscriberError {
     /// <p>You are not authorized to use this operation with the given parameters.</p>
     AccessDenied(String),
@@ -874,6 +930,8 @@ pub enum DeleteSubscriberError {
     InvalidParameter(String),
     /// <p>We can’t locate the resource that you specified.</p>
     NotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteSubscriberError {
@@ -881,19 +939,25 @@ impl DeleteSubscriberError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(DeleteSubscriberError::AccessDenied(err.msg))
+                    return RusotoError::service(DeleteSubscriberError::AccessDenied(err.msg), &res)
                 }
                 "InternalErrorException" => {
-                    return RusotoError::Service(DeleteSubscriberError::InternalError(err.msg))
+                    return RusotoError::service(
+                        DeleteSubscriberError::InternalError(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DeleteSubscriberError::InvalidParameter(err.msg))
+                    return RusotoError::service(
+                        DeleteSubscriberError::InvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteSubscriberError::NotFound(err.msg))
+                    return RusotoError::service(DeleteSubscriberError::NotFound(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteSubscriberError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -907,12 +971,14 @@ impl fmt::Display for DeleteSubscriberError {
             DeleteSubscriberError::InternalError(ref cause) => write!(f, "{}", cause),
             DeleteSubscriberError::InvalidParameter(ref cause) => write!(f, "{}", cause),
             DeleteSubscriberError::NotFound(ref cause) => write!(f, "{}", cause),
+            DeleteSubscriberError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteSubscriberError {}
 /// Errors returned by DescribeBudget
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeBudgetError {
     /// <p>You are not authorized to use this operation with the given parameters.</p>
     AccessDenied(String),
@@ -922,6 +988,8 @@ pub enum DescribeBudgetError {
     InvalidParameter(String),
     /// <p>We can’t locate the resource that you specified.</p>
     NotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeBudgetError {
@@ -929,19 +997,22 @@ impl DescribeBudgetError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(DescribeBudgetError::AccessDenied(err.msg))
+                    return RusotoError::service(DescribeBudgetError::AccessDenied(err.msg), &res)
                 }
                 "InternalErrorException" => {
-                    return RusotoError::Service(DescribeBudgetError::InternalError(err.msg))
+                    return RusotoError::service(DescribeBudgetError::InternalError(err.msg), &res)
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DescribeBudgetError::InvalidParameter(err.msg))
+                    return RusotoError::service(
+                        DescribeBudgetError::InvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DescribeBudgetError::NotFound(err.msg))
+                    return RusotoError::service(DescribeBudgetError::NotFound(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DescribeBudgetError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -955,12 +1026,14 @@ impl fmt::Display for DescribeBudgetError {
             DescribeBudgetError::InternalError(ref cause) => write!(f, "{}", cause),
             DescribeBudgetError::InvalidParameter(ref cause) => write!(f, "{}", cause),
             DescribeBudgetError::NotFound(ref cause) => write!(f, "{}", cause),
+            DescribeBudgetError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeBudgetError {}
 /// Errors returned by DescribeBudgetPerformanceHistory
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeBudgetPerformanceHistoryError {
     /// <p>You are not authorized to use this operation with the given parameters.</p>
     AccessDenied(String),
@@ -974,6 +1047,8 @@ pub enum DescribeBudgetPerformanceHistoryError {
     InvalidParameter(String),
     /// <p>We can’t locate the resource that you specified.</p>
     NotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeBudgetPerformanceHistoryError {
@@ -983,37 +1058,48 @@ impl DescribeBudgetPerformanceHistoryError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DescribeBudgetPerformanceHistoryError::AccessDenied(err.msg),
+                        &res,
                     )
                 }
                 "ExpiredNextTokenException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DescribeBudgetPerformanceHistoryError::ExpiredNextToken(err.msg),
+                        &res,
                     )
                 }
                 "InternalErrorException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DescribeBudgetPerformanceHistoryError::InternalError(err.msg),
+                        &res,
                     )
                 }
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DescribeBudgetPerformanceHistoryError::InvalidNextToken(err.msg),
+                        &res,
                     )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DescribeBudgetPerformanceHistoryError::InvalidParameter(err.msg),
+                        &res,
                     )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DescribeBudgetPerformanceHistoryError::NotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeBudgetPerformanceHistoryError::NotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        DescribeBudgetPerformanceHistoryError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -1039,12 +1125,16 @@ impl fmt::Display for DescribeBudgetPerformanceHistoryError {
                 write!(f, "{}", cause)
             }
             DescribeBudgetPerformanceHistoryError::NotFound(ref cause) => write!(f, "{}", cause),
+            DescribeBudgetPerformanceHistoryError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for DescribeBudgetPerformanceHistoryError {}
 /// Errors returned by DescribeBudgets
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeBudgetsError {
     /// <p>You are not authorized to use this operation with the given parameters.</p>
     AccessDenied(String),
@@ -1058,6 +1148,8 @@ pub enum DescribeBudgetsError {
     InvalidParameter(String),
     /// <p>We can’t locate the resource that you specified.</p>
     NotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeBudgetsError {
@@ -1065,25 +1157,34 @@ impl DescribeBudgetsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(DescribeBudgetsError::AccessDenied(err.msg))
+                    return RusotoError::service(DescribeBudgetsError::AccessDenied(err.msg), &res)
                 }
                 "ExpiredNextTokenException" => {
-                    return RusotoError::Service(DescribeBudgetsError::ExpiredNextToken(err.msg))
+                    return RusotoError::service(
+                        DescribeBudgetsError::ExpiredNextToken(err.msg),
+                        &res,
+                    )
                 }
                 "InternalErrorException" => {
-                    return RusotoError::Service(DescribeBudgetsError::InternalError(err.msg))
+                    return RusotoError::service(DescribeBudgetsError::InternalError(err.msg), &res)
                 }
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(DescribeBudgetsError::InvalidNextToken(err.msg))
+                    return RusotoError::service(
+                        DescribeBudgetsError::InvalidNextToken(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DescribeBudgetsError::InvalidParameter(err.msg))
+                    return RusotoError::service(
+                        DescribeBudgetsError::InvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DescribeBudgetsError::NotFound(err.msg))
+                    return RusotoError::service(DescribeBudgetsError::NotFound(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DescribeBudgetsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1099,12 +1200,14 @@ impl fmt::Display for DescribeBudgetsError {
             DescribeBudgetsError::InvalidNextToken(ref cause) => write!(f, "{}", cause),
             DescribeBudgetsError::InvalidParameter(ref cause) => write!(f, "{}", cause),
             DescribeBudgetsError::NotFound(ref cause) => write!(f, "{}", cause),
+            DescribeBudgetsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeBudgetsError {}
 /// Errors returned by DescribeNotificationsForBudget
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeNotificationsForBudgetError {
     /// <p>You are not authorized to use this operation with the given parameters.</p>
     AccessDenied(String),
@@ -1118,6 +1221,8 @@ pub enum DescribeNotificationsForBudgetError {
     InvalidParameter(String),
     /// <p>We can’t locate the resource that you specified.</p>
     NotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeNotificationsForBudgetError {
@@ -1127,37 +1232,48 @@ impl DescribeNotificationsForBudgetError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(DescribeNotificationsForBudgetError::AccessDenied(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeNotificationsForBudgetError::AccessDenied(err.msg),
+                        &res,
+                    )
                 }
                 "ExpiredNextTokenException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DescribeNotificationsForBudgetError::ExpiredNextToken(err.msg),
+                        &res,
                     )
                 }
                 "InternalErrorException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DescribeNotificationsForBudgetError::InternalError(err.msg),
+                        &res,
                     )
                 }
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DescribeNotificationsForBudgetError::InvalidNextToken(err.msg),
+                        &res,
                     )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DescribeNotificationsForBudgetError::InvalidParameter(err.msg),
+                        &res,
                     )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DescribeNotificationsForBudgetError::NotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeNotificationsForBudgetError::NotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        DescribeNotificationsForBudgetError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -1179,12 +1295,16 @@ impl fmt::Display for DescribeNotificationsForBudgetError {
                 write!(f, "{}", cause)
             }
             DescribeNotificationsForBudgetError::NotFound(ref cause) => write!(f, "{}", cause),
+            DescribeNotificationsForBudgetError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for DescribeNotificationsForBudgetError {}
 /// Errors returned by DescribeSubscribersForNotification
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeSubscribersForNotificationError {
     /// <p>You are not authorized to use this operation with the given parameters.</p>
     AccessDenied(String),
@@ -1198,6 +1318,8 @@ pub enum DescribeSubscribersForNotificationError {
     InvalidParameter(String),
     /// <p>We can’t locate the resource that you specified.</p>
     NotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeSubscribersForNotificationError {
@@ -1207,37 +1329,48 @@ impl DescribeSubscribersForNotificationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DescribeSubscribersForNotificationError::AccessDenied(err.msg),
+                        &res,
                     )
                 }
                 "ExpiredNextTokenException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DescribeSubscribersForNotificationError::ExpiredNextToken(err.msg),
+                        &res,
                     )
                 }
                 "InternalErrorException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DescribeSubscribersForNotificationError::InternalError(err.msg),
+                        &res,
                     )
                 }
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DescribeSubscribersForNotificationError::InvalidNextToken(err.msg),
+                        &res,
                     )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DescribeSubscribersForNotificationError::InvalidParameter(err.msg),
+                        &res,
                     )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DescribeSubscribersForNotificationError::NotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeSubscribersForNotificationError::NotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        DescribeSubscribersForNotificationError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -1263,12 +1396,16 @@ impl fmt::Display for DescribeSubscribersForNotificationError {
                 write!(f, "{}", cause)
             }
             DescribeSubscribersForNotificationError::NotFound(ref cause) => write!(f, "{}", cause),
+            DescribeSubscribersForNotificationError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for DescribeSubscribersForNotificationError {}
 /// Errors returned by UpdateBudget
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdateBudgetError {
     /// <p>You are not authorized to use this operation with the given parameters.</p>
     AccessDenied(String),
@@ -1278,6 +1415,8 @@ pub enum UpdateBudgetError {
     InvalidParameter(String),
     /// <p>We can’t locate the resource that you specified.</p>
     NotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdateBudgetError {
@@ -1285,19 +1424,19 @@ impl UpdateBudgetError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(UpdateBudgetError::AccessDenied(err.msg))
+                    return RusotoError::service(UpdateBudgetError::AccessDenied(err.msg), &res)
                 }
                 "InternalErrorException" => {
-                    return RusotoError::Service(UpdateBudgetError::InternalError(err.msg))
+                    return RusotoError::service(UpdateBudgetError::InternalError(err.msg), &res)
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(UpdateBudgetError::InvalidParameter(err.msg))
+                    return RusotoError::service(UpdateBudgetError::InvalidParameter(err.msg), &res)
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(UpdateBudgetError::NotFound(err.msg))
+                    return RusotoError::service(UpdateBudgetError::NotFound(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UpdateBudgetError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1311,12 +1450,14 @@ impl fmt::Display for UpdateBudgetError {
             UpdateBudgetError::InternalError(ref cause) => write!(f, "{}", cause),
             UpdateBudgetError::InvalidParameter(ref cause) => write!(f, "{}", cause),
             UpdateBudgetError::NotFound(ref cause) => write!(f, "{}", cause),
+            UpdateBudgetError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for UpdateBudgetError {}
 /// Errors returned by UpdateNotification
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdateNotificationError {
     /// <p>You are not authorized to use this operation with the given parameters.</p>
     AccessDenied(String),
@@ -1328,6 +1469,8 @@ pub enum UpdateNotificationError {
     InvalidParameter(String),
     /// <p>We can’t locate the resource that you specified.</p>
     NotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdateNotificationError {
@@ -1335,22 +1478,34 @@ impl UpdateNotificationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(UpdateNotificationError::AccessDenied(err.msg))
+                    return RusotoError::service(
+                        UpdateNotificationError::AccessDenied(err.msg),
+                        &res,
+                    )
                 }
                 "DuplicateRecordException" => {
-                    return RusotoError::Service(UpdateNotificationError::DuplicateRecord(err.msg))
+                    return RusotoError::service(
+                        UpdateNotificationError::DuplicateRecord(err.msg),
+                        &res,
+                    )
                 }
                 "InternalErrorException" => {
-                    return RusotoError::Service(UpdateNotificationError::InternalError(err.msg))
+                    return RusotoError::service(
+                        UpdateNotificationError::InternalError(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(UpdateNotificationError::InvalidParameter(err.msg))
+                    return RusotoError::service(
+                        UpdateNotificationError::InvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(UpdateNotificationError::NotFound(err.msg))
+                    return RusotoError::service(UpdateNotificationError::NotFound(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UpdateNotificationError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1365,12 +1520,14 @@ impl fmt::Display for UpdateNotificationError {
             UpdateNotificationError::InternalError(ref cause) => write!(f, "{}", cause),
             UpdateNotificationError::InvalidParameter(ref cause) => write!(f, "{}", cause),
             UpdateNotificationError::NotFound(ref cause) => write!(f, "{}", cause),
+            UpdateNotificationError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for UpdateNotificationError {}
 /// Errors returned by UpdateSubscriber
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdateSubscriberError {
     /// <p>You are not authorized to use this operation with the given parameters.</p>
     AccessDenied(String),
@@ -1382,6 +1539,8 @@ pub enum UpdateSubscriberError {
     InvalidParameter(String),
     /// <p>We can’t locate the resource that you specified.</p>
     NotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdateSubscriberError {
@@ -1389,22 +1548,31 @@ impl UpdateSubscriberError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(UpdateSubscriberError::AccessDenied(err.msg))
+                    return RusotoError::service(UpdateSubscriberError::AccessDenied(err.msg), &res)
                 }
                 "DuplicateRecordException" => {
-                    return RusotoError::Service(UpdateSubscriberError::DuplicateRecord(err.msg))
+                    return RusotoError::service(
+                        UpdateSubscriberError::DuplicateRecord(err.msg),
+                        &res,
+                    )
                 }
                 "InternalErrorException" => {
-                    return RusotoError::Service(UpdateSubscriberError::InternalError(err.msg))
+                    return RusotoError::service(
+                        UpdateSubscriberError::InternalError(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(UpdateSubscriberError::InvalidParameter(err.msg))
+                    return RusotoError::service(
+                        UpdateSubscriberError::InvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(UpdateSubscriberError::NotFound(err.msg))
+                    return RusotoError::service(UpdateSubscriberError::NotFound(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UpdateSubscriberError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1419,6 +1587,7 @@ impl fmt::Display for UpdateSubscriberError {
             UpdateSubscriberError::InternalError(ref cause) => write!(f, "{}", cause),
             UpdateSubscriberError::InvalidParameter(ref cause) => write!(f, "{}", cause),
             UpdateSubscriberError::NotFound(ref cause) => write!(f, "{}", cause),
+            UpdateSubscriberError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
@@ -1530,6 +1699,7 @@ impl BudgetsClient {
     /// Creates a client backed by the default tokio event loop.
     ///
     /// The client will use the default credentials provider and tls client.
+    #[cfg(not(target_arch = "wasm32"))]
     pub fn new(region: region::Region) -> BudgetsClient {
         BudgetsClient {
             client: Client::shared(),
@@ -1555,212 +1725,443 @@ impl BudgetsClient {
     pub fn new_with_client(client: Client, region: region::Region) -> BudgetsClient {
         BudgetsClient { client, region }
     }
+
+    /// Returns a builder of a client, setting any of its region, credentials provider,
+    /// dispatcher, retry policy, endpoint and timeouts.
+    pub fn builder() -> rusoto_core::ClientBuilder<BudgetsClient> {
+        rusoto_core::ClientBuilder::new(BudgetsClient::new_with_client)
+    }
+
+    /// Returns a clone of this client with the overrides of `config` applied to its
+    /// operations, sharing its connections.
+    pub fn customize(&self, config: &rusoto_core::OperationConfig) -> BudgetsClient {
+        BudgetsClient {
+            client: self.client.customize(config),
+            region: config.resolve_region(&self.region),
+        }
+    }
 }
 
-#[async_trait]
-impl Budgets for BudgetsClient {
-    /// <p><p>Creates a budget and, if included, notifications and subscribers. </p> <important> <p>Only one of <code>BudgetLimit</code> or <code>PlannedBudgetLimits</code> can be present in the syntax at one time. Use the syntax that matches your case. The Request Syntax section shows the <code>BudgetLimit</code> syntax. For <code>PlannedBudgetLimits</code>, see the <a href="https://docs.aws.amazon.com/aws-cost-management/latest/APIReference/API_budgets_CreateBudget.html#API_CreateBudget_Examples">Examples</a> section. </p> </important></p>
-    async fn create_budget(
+impl BudgetsClient {
+    /// Calls `create_budget`, also returning the metadata of the response.
+    pub async fn create_budget_with_metadata(
         &self,
         input: CreateBudgetRequest,
-    ) -> Result<CreateBudgetResponse, RusotoError<CreateBudgetError>> {
+    ) -> Result<(CreateBudgetResponse, ResponseMetadata), RusotoError<CreateBudgetError>> {
+        let request = self.build_create_budget_request(input);
+
+        let mut response = self
+            .client
+            .sign_and_dispatch(request)
+            .await
+            .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
+        if response.status.is_success() {
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<CreateBudgetResponse, _>()?;
+            Ok((result, metadata))
+        } else {
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(CreateBudgetError::from_response(response))
+        }
+    }
+
+    /// Presigns the request `create_budget` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_budget(
+        &self,
+        presigner: &rusoto_core::Presigner,
+        input: CreateBudgetRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_budget_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_budget_request(&self, input: CreateBudgetRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "budgets", &self.region, "/");
+        request.set_operation("CreateBudget");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSBudgetServiceGateway.CreateBudget");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `create_notification`, also returning the metadata of the response.
+    pub async fn create_notification_with_metadata(
+        &self,
+        input: CreateNotificationRequest,
+    ) -> Result<(CreateNotificationResponse, ResponseMetadata), RusotoError<CreateNotificationError>>
+    {
+        let request = self.build_create_notification_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<CreateBudgetResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<CreateNotificationResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(CreateBudgetError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(CreateNotificationError::from_response(response))
         }
     }
 
-    /// <p>Creates a notification. You must create the budget before you create the associated notification.</p>
-    async fn create_notification(
+    /// Presigns the request `create_notification` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_notification(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: CreateNotificationRequest,
-    ) -> Result<CreateNotificationResponse, RusotoError<CreateNotificationError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_notification_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_notification_request(&self, input: CreateNotificationRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "budgets", &self.region, "/");
+        request.set_operation("CreateNotification");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSBudgetServiceGateway.CreateNotification");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `create_subscriber`, also returning the metadata of the response.
+    pub async fn create_subscriber_with_metadata(
+        &self,
+        input: CreateSubscriberRequest,
+    ) -> Result<(CreateSubscriberResponse, ResponseMetadata), RusotoError<CreateSubscriberError>>
+    {
+        let request = self.build_create_subscriber_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<CreateNotificationResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<CreateSubscriberResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(CreateNotificationError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(CreateSubscriberError::from_response(response))
         }
     }
 
-    /// <p>Creates a subscriber. You must create the associated budget and notification before you create the subscriber.</p>
-    async fn create_subscriber(
+    /// Presigns the request `create_subscriber` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_subscriber(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: CreateSubscriberRequest,
-    ) -> Result<CreateSubscriberResponse, RusotoError<CreateSubscriberError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_subscriber_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_subscriber_request(&self, input: CreateSubscriberRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "budgets", &self.region, "/");
+        request.set_operation("CreateSubscriber");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSBudgetServiceGateway.CreateSubscriber");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `delete_budget`, also returning the metadata of the response.
+    pub async fn delete_budget_with_metadata(
+        &self,
+        input: DeleteBudgetRequest,
+    ) -> Result<(DeleteBudgetResponse, ResponseMetadata), RusotoError<DeleteBudgetError>> {
+        let request = self.build_delete_budget_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<CreateSubscriberResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DeleteBudgetResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(CreateSubscriberError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DeleteBudgetError::from_response(response))
         }
     }
 
-    /// <p><p>Deletes a budget. You can delete your budget at any time.</p> <important> <p>Deleting a budget also deletes the notifications and subscribers that are associated with that budget.</p> </important></p>
-    async fn delete_budget(
+    /// Presigns the request `delete_budget` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_budget(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteBudgetRequest,
-    ) -> Result<DeleteBudgetResponse, RusotoError<DeleteBudgetError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_budget_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_budget_request(&self, input: DeleteBudgetRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "budgets", &self.region, "/");
+        request.set_operation("DeleteBudget");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSBudgetServiceGateway.DeleteBudget");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `delete_notification`, also returning the metadata of the response.
+    pub async fn delete_notification_with_metadata(
+        &self,
+        input: DeleteNotificationRequest,
+    ) -> Result<(DeleteNotificationResponse, ResponseMetadata), RusotoError<DeleteNotificationError>>
+    {
+        let request = self.build_delete_notification_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<DeleteBudgetResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DeleteNotificationResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteBudgetError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DeleteNotificationError::from_response(response))
         }
     }
 
-    /// <p><p>Deletes a notification.</p> <important> <p>Deleting a notification also deletes the subscribers that are associated with the notification.</p> </important></p>
-    async fn delete_notification(
+    /// Presigns the request `delete_notification` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_notification(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteNotificationRequest,
-    ) -> Result<DeleteNotificationResponse, RusotoError<DeleteNotificationError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_notification_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_notification_request(&self, input: DeleteNotificationRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "budgets", &self.region, "/");
+        request.set_operation("DeleteNotification");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSBudgetServiceGateway.DeleteNotification");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `delete_subscriber`, also returning the metadata of the response.
+    pub async fn delete_subscriber_with_metadata(
+        &self,
+        input: DeleteSubscriberRequest,
+    ) -> Result<(DeleteSubscriberResponse, ResponseMetadata), RusotoError<DeleteSubscriberError>>
+    {
+        let request = self.build_delete_subscriber_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DeleteNotificationResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DeleteSubscriberResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteNotificationError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DeleteSubscriberError::from_response(response))
         }
     }
 
-    /// <p><p>Deletes a subscriber.</p> <important> <p>Deleting the last subscriber to a notification also deletes the notification.</p> </important></p>
-    async fn delete_subscriber(
+    /// Presigns the request `delete_subscriber` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_subscriber(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteSubscriberRequest,
-    ) -> Result<DeleteSubscriberResponse, RusotoError<DeleteSubscriberError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_subscriber_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_subscriber_request(&self, input: DeleteSubscriberRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "budgets", &self.region, "/");
+        request.set_operation("DeleteSubscriber");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSBudgetServiceGateway.DeleteSubscriber");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `describe_budget`, also returning the metadata of the response.
+    pub async fn describe_budget_with_metadata(
+        &self,
+        input: DescribeBudgetRequest,
+    ) -> Result<(DescribeBudgetResponse, ResponseMetadata), RusotoError<DescribeBudgetError>> {
+        let request = self.build_describe_budget_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DeleteSubscriberResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DescribeBudgetResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteSubscriberError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DescribeBudgetError::from_response(response))
         }
     }
 
-    /// <p><p>Describes a budget.</p> <important> <p>The Request Syntax section shows the <code>BudgetLimit</code> syntax. For <code>PlannedBudgetLimits</code>, see the <a href="https://docs.aws.amazon.com/aws-cost-management/latest/APIReference/API_budgets_DescribeBudget.html#API_DescribeBudget_Examples">Examples</a> section. </p> </important></p>
-    async fn describe_budget(
+    /// Presigns the request `describe_budget` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_budget(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeBudgetRequest,
-    ) -> Result<DescribeBudgetResponse, RusotoError<DescribeBudgetError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_budget_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_budget_request(&self, input: DescribeBudgetRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "budgets", &self.region, "/");
+        request.set_operation("DescribeBudget");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSBudgetServiceGateway.DescribeBudget");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `describe_budget_performance_history`, also returning the metadata of the response.
+    pub async fn describe_budget_performance_history_with_metadata(
+        &self,
+        input: DescribeBudgetPerformanceHistoryRequest,
+    ) -> Result<
+        (DescribeBudgetPerformanceHistoryResponse, ResponseMetadata),
+        RusotoError<DescribeBudgetPerformanceHistoryError>,
+    > {
+        let request = self.build_describe_budget_performance_history_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<DescribeBudgetResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DescribeBudgetPerformanceHistoryResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeBudgetError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DescribeBudgetPerformanceHistoryError::from_response(
+                response,
+            ))
         }
     }
 
-    /// <p>Describes the history for <code>DAILY</code>, <code>MONTHLY</code>, and <code>QUARTERLY</code> budgets. Budget history isn't available for <code>ANNUAL</code> budgets.</p>
-    async fn describe_budget_performance_history(
+    /// Presigns the request `describe_budget_performance_history` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_budget_performance_history(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeBudgetPerformanceHistoryRequest,
-    ) -> Result<
-        DescribeBudgetPerformanceHistoryResponse,
-        RusotoError<DescribeBudgetPerformanceHistoryError>,
-    > {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_budget_performance_history_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_budget_performance_history_request(
+        &self,
+        input: DescribeBudgetPerformanceHistoryRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "budgets", &self.region, "/");
+        request.set_operation("DescribeBudgetPerformanceHistory");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -1770,60 +2171,111 @@ impl Budgets for BudgetsClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `describe_budgets`, also returning the metadata of the response.
+    pub async fn describe_budgets_with_metadata(
+        &self,
+        input: DescribeBudgetsRequest,
+    ) -> Result<(DescribeBudgetsResponse, ResponseMetadata), RusotoError<DescribeBudgetsError>>
+    {
+        let request = self.build_describe_budgets_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DescribeBudgetPerformanceHistoryResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DescribeBudgetsResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeBudgetPerformanceHistoryError::from_response(
-                response,
-            ))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DescribeBudgetsError::from_response(response))
         }
     }
 
-    /// <p><p>Lists the budgets that are associated with an account.</p> <important> <p>The Request Syntax section shows the <code>BudgetLimit</code> syntax. For <code>PlannedBudgetLimits</code>, see the <a href="https://docs.aws.amazon.com/aws-cost-management/latest/APIReference/API_budgets_DescribeBudgets.html#API_DescribeBudgets_Examples">Examples</a> section. </p> </important></p>
-    async fn describe_budgets(
+    /// Presigns the request `describe_budgets` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_budgets(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeBudgetsRequest,
-    ) -> Result<DescribeBudgetsResponse, RusotoError<DescribeBudgetsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_budgets_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_budgets_request(&self, input: DescribeBudgetsRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "budgets", &self.region, "/");
+        request.set_operation("DescribeBudgets");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSBudgetServiceGateway.DescribeBudgets");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `describe_notifications_for_budget`, also returning the metadata of the response.
+    pub async fn describe_notifications_for_budget_with_metadata(
+        &self,
+        input: DescribeNotificationsForBudgetRequest,
+    ) -> Result<
+        (DescribeNotificationsForBudgetResponse, ResponseMetadata),
+        RusotoError<DescribeNotificationsForBudgetError>,
+    > {
+        let request = self.build_describe_notifications_for_budget_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<DescribeBudgetsResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DescribeNotificationsForBudgetResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeBudgetsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DescribeNotificationsForBudgetError::from_response(response))
         }
     }
 
-    /// <p>Lists the notifications that are associated with a budget.</p>
-    async fn describe_notifications_for_budget(
+    /// Presigns the request `describe_notifications_for_budget` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_notifications_for_budget(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeNotificationsForBudgetRequest,
-    ) -> Result<
-        DescribeNotificationsForBudgetResponse,
-        RusotoError<DescribeNotificationsForBudgetError>,
-    > {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_notifications_for_budget_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_notifications_for_budget_request(
+        &self,
+        input: DescribeNotificationsForBudgetRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "budgets", &self.region, "/");
+        request.set_operation("DescribeNotificationsForBudget");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -1833,31 +2285,60 @@ impl Budgets for BudgetsClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `describe_subscribers_for_notification`, also returning the metadata of the response.
+    pub async fn describe_subscribers_for_notification_with_metadata(
+        &self,
+        input: DescribeSubscribersForNotificationRequest,
+    ) -> Result<
+        (DescribeSubscribersForNotificationResponse, ResponseMetadata),
+        RusotoError<DescribeSubscribersForNotificationError>,
+    > {
+        let request = self.build_describe_subscribers_for_notification_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DescribeNotificationsForBudgetResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DescribeSubscribersForNotificationResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeNotificationsForBudgetError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DescribeSubscribersForNotificationError::from_response(
+                response,
+            ))
         }
     }
 
-    /// <p>Lists the subscribers that are associated with a notification.</p>
-    async fn describe_subscribers_for_notification(
+    /// Presigns the request `describe_subscribers_for_notification` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_subscribers_for_notification(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeSubscribersForNotificationRequest,
-    ) -> Result<
-        DescribeSubscribersForNotificationResponse,
-        RusotoError<DescribeSubscribersForNotificationError>,
-    > {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_subscribers_for_notification_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_subscribers_for_notification_request(
+        &self,
+        input: DescribeSubscribersForNotificationRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "budgets", &self.region, "/");
+        request.set_operation("DescribeSubscribersForNotification");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -1867,104 +2348,474 @@ impl Budgets for BudgetsClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `update_budget`, also returning the metadata of the response.
+    pub async fn update_budget_with_metadata(
+        &self,
+        input: UpdateBudgetRequest,
+    ) -> Result<(UpdateBudgetResponse, ResponseMetadata), RusotoError<UpdateBudgetError>> {
+        let request = self.build_update_budget_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DescribeSubscribersForNotificationResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<UpdateBudgetResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeSubscribersForNotificationError::from_response(
-                response,
-            ))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(UpdateBudgetError::from_response(response))
         }
     }
 
-    /// <p><p>Updates a budget. You can change every part of a budget except for the <code>budgetName</code> and the <code>calculatedSpend</code>. When you modify a budget, the <code>calculatedSpend</code> drops to zero until AWS has new usage data to use for forecasting.</p> <important> <p>Only one of <code>BudgetLimit</code> or <code>PlannedBudgetLimits</code> can be present in the syntax at one time. Use the syntax that matches your case. The Request Syntax section shows the <code>BudgetLimit</code> syntax. For <code>PlannedBudgetLimits</code>, see the <a href="https://docs.aws.amazon.com/aws-cost-management/latest/APIReference/API_budgets_UpdateBudget.html#API_UpdateBudget_Examples">Examples</a> section. </p> </important></p>
-    async fn update_budget(
+    /// Presigns the request `update_budget` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_budget(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UpdateBudgetRequest,
-    ) -> Result<UpdateBudgetResponse, RusotoError<UpdateBudgetError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_budget_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_budget_request(&self, input: UpdateBudgetRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "budgets", &self.region, "/");
+        request.set_operation("UpdateBudget");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSBudgetServiceGateway.UpdateBudget");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `update_notification`, also returning the metadata of the response.
+    pub async fn update_notification_with_metadata(
+        &self,
+        input: UpdateNotificationRequest,
+    ) -> Result<(UpdateNotificationResponse, ResponseMetadata), RusotoError<UpdateNotificationError>>
+    {
+        let request = self.build_update_notification_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<UpdateBudgetResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<UpdateNotificationResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(UpdateBudgetError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(UpdateNotificationError::from_response(response))
         }
     }
 
-    /// <p>Updates a notification.</p>
-    async fn update_notification(
+    /// Presigns the request `update_notification` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_notification(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UpdateNotificationRequest,
-    ) -> Result<UpdateNotificationResponse, RusotoError<UpdateNotificationError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_notification_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_notification_request(&self, input: UpdateNotificationRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "budgets", &self.region, "/");
+        request.set_operation("UpdateNotification");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSBudgetServiceGateway.UpdateNotification");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `update_subscriber`, also returning the metadata of the response.
+    pub async fn update_subscriber_with_metadata(
+        &self,
+        input: UpdateSubscriberRequest,
+    ) -> Result<(UpdateSubscriberResponse, ResponseMetadata), RusotoError<UpdateSubscriberError>>
+    {
+        let request = self.build_update_subscriber_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<UpdateNotificationResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<UpdateSubscriberResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(UpdateNotificationError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(UpdateSubscriberError::from_response(response))
         }
     }
 
-    /// <p>Updates a subscriber.</p>
-    async fn update_subscriber(
+    /// Presigns the request `update_subscriber` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_subscriber(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UpdateSubscriberRequest,
-    ) -> Result<UpdateSubscriberResponse, RusotoError<UpdateSubscriberError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_subscriber_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_subscriber_request(&self, input: UpdateSubscriberRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "budgets", &self.region, "/");
+        request.set_operation("UpdateSubscriber");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSBudgetServiceGateway.UpdateSubscriber");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
-        let mut response = self
-            .client
-            .sign_and_dispatch(request)
+        request
+    }
+
+    /// The pages of `DescribeBudgetPerformanceHistory` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `MaxResults` of `input`.
+    pub fn describe_budget_performance_history_pages(
+        &self,
+        input: DescribeBudgetPerformanceHistoryRequest,
+    ) -> impl futures::Stream<
+        Item = Result<
+            DescribeBudgetPerformanceHistoryResponse,
+            RusotoError<DescribeBudgetPerformanceHistoryError>,
+        >,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: BudgetsClient, input| async move {
+                client.describe_budget_performance_history(input).await
+            },
+            |input: &DescribeBudgetPerformanceHistoryRequest,
+             output: &DescribeBudgetPerformanceHistoryResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(DescribeBudgetPerformanceHistoryRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The pages of `DescribeBudgets` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `MaxResults` of `input`.
+    pub fn describe_budgets_pages(
+        &self,
+        input: DescribeBudgetsRequest,
+    ) -> impl futures::Stream<
+        Item = Result<DescribeBudgetsResponse, RusotoError<DescribeBudgetsError>>,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: BudgetsClient, input| async move { client.describe_budgets(input).await },
+            |input: &DescribeBudgetsRequest, output: &DescribeBudgetsResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(DescribeBudgetsRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `Budgets` of every page of `DescribeBudgets` results for `input`, as `describe_budgets_pages` requests them.
+    pub fn describe_budgets_stream(
+        &self,
+        input: DescribeBudgetsRequest,
+    ) -> impl futures::Stream<Item = Result<Budget, RusotoError<DescribeBudgetsError>>> + Send {
+        rusoto_core::pagination::items(self.describe_budgets_pages(input), |page| {
+            page.budgets.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `DescribeNotificationsForBudget` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `MaxResults` of `input`.
+    pub fn describe_notifications_for_budget_pages(
+        &self,
+        input: DescribeNotificationsForBudgetRequest,
+    ) -> impl futures::Stream<
+        Item = Result<
+            DescribeNotificationsForBudgetResponse,
+            RusotoError<DescribeNotificationsForBudgetError>,
+        >,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: BudgetsClient, input| async move {
+                client.describe_notifications_for_budget(input).await
+            },
+            |input: &DescribeNotificationsForBudgetRequest,
+             output: &DescribeNotificationsForBudgetResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(DescribeNotificationsForBudgetRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `Notifications` of every page of `DescribeNotificationsForBudget` results for `input`, as `describe_notifications_for_budget_pages` requests them.
+    pub fn describe_notifications_for_budget_stream(
+        &self,
+        input: DescribeNotificationsForBudgetRequest,
+    ) -> impl futures::Stream<
+        Item = Result<Notification, RusotoError<DescribeNotificationsForBudgetError>>,
+    > + Send {
+        rusoto_core::pagination::items(
+            self.describe_notifications_for_budget_pages(input),
+            |page| page.notifications.unwrap_or_default(),
+        )
+    }
+
+    /// The pages of `DescribeSubscribersForNotification` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `MaxResults` of `input`.
+    pub fn describe_subscribers_for_notification_pages(
+        &self,
+        input: DescribeSubscribersForNotificationRequest,
+    ) -> impl futures::Stream<
+        Item = Result<
+            DescribeSubscribersForNotificationResponse,
+            RusotoError<DescribeSubscribersForNotificationError>,
+        >,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: BudgetsClient, input| async move {
+                client.describe_subscribers_for_notification(input).await
+            },
+            |input: &DescribeSubscribersForNotificationRequest,
+             output: &DescribeSubscribersForNotificationResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(DescribeSubscribersForNotificationRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `Subscribers` of every page of `DescribeSubscribersForNotification` results for `input`, as `describe_subscribers_for_notification_pages` requests them.
+    pub fn describe_subscribers_for_notification_stream(
+        &self,
+        input: DescribeSubscribersForNotificationRequest,
+    ) -> impl futures::Stream<
+        Item = Result<Subscriber, RusotoError<DescribeSubscribersForNotificationError>>,
+    > + Send {
+        rusoto_core::pagination::items(
+            self.describe_subscribers_for_notification_pages(input),
+            |page| page.subscribers.unwrap_or_default(),
+        )
+    }
+}
+
+#[async_trait]
+impl Budgets for BudgetsClient {
+    /// <p><p>Creates a budget and, if included, notifications and subscribers. </p> <important> <p>Only one of <code>BudgetLimit</code> or <code>PlannedBudgetLimits</code> can be present in the syntax at one time. Use the syntax that matches your case. The Request Syntax section shows the <code>BudgetLimit</code> syntax. For <code>PlannedBudgetLimits</code>, see the <a href="https://docs.aws.amazon.com/aws-cost-management/latest/APIReference/API_budgets_CreateBudget.html#API_CreateBudget_Examples">Examples</a> section. </p> </important></p>
+    async fn create_budget(
+        &self,
+        input: CreateBudgetRequest,
+    ) -> Result<CreateBudgetResponse, RusotoError<CreateBudgetError>> {
+        self.create_budget_with_metadata(input)
             .await
-            .map_err(RusotoError::from)?;
-        if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<UpdateSubscriberResponse, _>()
-        } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(UpdateSubscriberError::from_response(response))
-        }
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Creates a notification. You must create the budget before you create the associated notification.</p>
+    async fn create_notification(
+        &self,
+        input: CreateNotificationRequest,
+    ) -> Result<CreateNotificationResponse, RusotoError<CreateNotificationError>> {
+        self.create_notification_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Creates a subscriber. You must create the associated budget and notification before you create the subscriber.</p>
+    async fn create_subscriber(
+        &self,
+        input: CreateSubscriberRequest,
+    ) -> Result<CreateSubscriberResponse, RusotoError<CreateSubscriberError>> {
+        self.create_subscriber_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Deletes a budget. You can delete your budget at any time.</p> <important> <p>Deleting a budget also deletes the notifications and subscribers that are associated with that budget.</p> </important></p>
+    async fn delete_budget(
+        &self,
+        input: DeleteBudgetRequest,
+    ) -> Result<DeleteBudgetResponse, RusotoError<DeleteBudgetError>> {
+        self.delete_budget_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Deletes a notification.</p> <important> <p>Deleting a notification also deletes the subscribers that are associated with the notification.</p> </important></p>
+    async fn delete_notification(
+        &self,
+        input: DeleteNotificationRequest,
+    ) -> Result<DeleteNotificationResponse, RusotoError<DeleteNotificationError>> {
+        self.delete_notification_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Deletes a subscriber.</p> <important> <p>Deleting the last subscriber to a notification also deletes the notification.</p> </important></p>
+    async fn delete_subscriber(
+        &self,
+        input: DeleteSubscriberRequest,
+    ) -> Result<DeleteSubscriberResponse, RusotoError<DeleteSubscriberError>> {
+        self.delete_subscriber_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Describes a budget.</p> <important> <p>The Request Syntax section shows the <code>BudgetLimit</code> syntax. For <code>PlannedBudgetLimits</code>, see the <a href="https://docs.aws.amazon.com/aws-cost-management/latest/APIReference/API_budgets_DescribeBudget.html#API_DescribeBudget_Examples">Examples</a> section. </p> </important></p>
+    async fn describe_budget(
+        &self,
+        input: DescribeBudgetRequest,
+    ) -> Result<DescribeBudgetResponse, RusotoError<DescribeBudgetError>> {
+        self.describe_budget_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Describes the history for <code>DAILY</code>, <code>MONTHLY</code>, and <code>QUARTERLY</code> budgets. Budget history isn't available for <code>ANNUAL</code> budgets.</p>
+    async fn describe_budget_performance_history(
+        &self,
+        input: DescribeBudgetPerformanceHistoryRequest,
+    ) -> Result<
+        DescribeBudgetPerformanceHistoryResponse,
+        RusotoError<DescribeBudgetPerformanceHistoryError>,
+    > {
+        self.describe_budget_performance_history_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Lists the budgets that are associated with an account.</p> <important> <p>The Request Syntax section shows the <code>BudgetLimit</code> syntax. For <code>PlannedBudgetLimits</code>, see the <a href="https://docs.aws.amazon.com/aws-cost-management/latest/APIReference/API_budgets_DescribeBudgets.html#API_DescribeBudgets_Examples">Examples</a> section. </p> </important></p>
+    async fn describe_budgets(
+        &self,
+        input: DescribeBudgetsRequest,
+    ) -> Result<DescribeBudgetsResponse, RusotoError<DescribeBudgetsError>> {
+        self.describe_budgets_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Lists the notifications that are associated with a budget.</p>
+    async fn describe_notifications_for_budget(
+        &self,
+        input: DescribeNotificationsForBudgetRequest,
+    ) -> Result<
+        DescribeNotificationsForBudgetResponse,
+        RusotoError<DescribeNotificationsForBudgetError>,
+    > {
+        self.describe_notifications_for_budget_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Lists the subscribers that are associated with a notification.</p>
+    async fn describe_subscribers_for_notification(
+        &self,
+        input: DescribeSubscribersForNotificationRequest,
+    ) -> Result<
+        DescribeSubscribersForNotificationResponse,
+        RusotoError<DescribeSubscribersForNotificationError>,
+    > {
+        self.describe_subscribers_for_notification_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Updates a budget. You can change every part of a budget except for the <code>budgetName</code> and the <code>calculatedSpend</code>. When you modify a budget, the <code>calculatedSpend</code> drops to zero until AWS has new usage data to use for forecasting.</p> <important> <p>Only one of <code>BudgetLimit</code> or <code>PlannedBudgetLimits</code> can be present in the syntax at one time. Use the syntax that matches your case. The Request Syntax section shows the <code>BudgetLimit</code> syntax. For <code>PlannedBudgetLimits</code>, see the <a href="https://docs.aws.amazon.com/aws-cost-management/latest/APIReference/API_budgets_UpdateBudget.html#API_UpdateBudget_Examples">Examples</a> section. </p> </important></p>
+    async fn update_budget(
+        &self,
+        input: UpdateBudgetRequest,
+    ) -> Result<UpdateBudgetResponse, RusotoError<UpdateBudgetError>> {
+        self.update_budget_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Updates a notification.</p>
+    async fn update_notification(
+        &self,
+        input: UpdateNotificationRequest,
+    ) -> Result<UpdateNotificationResponse, RusotoError<UpdateNotificationError>> {
+        self.update_notification_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Updates a subscriber.</p>
+    async fn update_subscriber(
+        &self,
+        input: UpdateSubscriberRequest,
+    ) -> Result<UpdateSubscriberResponse, RusotoError<UpdateSubscriberError>> {
+        self.update_subscriber_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
     }
 }
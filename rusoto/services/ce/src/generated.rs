@@ -16,8 +16,8 @@ use std::fmt;
 use async_trait::async_trait;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
-use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest, ResponseMetadata};
+use rusoto_core::{Client, ErrorMetadata, RusotoError};
 
 use rusoto_core::proto;
 use rusoto_core::signature::SignedRequest;
@@ -2093,11 +2093,14 @@ pub struct UtilizationByTime {
 
 /// Errors returned by CreateCostCategoryDefinition
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateCostCategoryDefinitionError {
     /// <p>You made too many calls in a short period of time. Try again later.</p>
     LimitExceeded(String),
     /// <p> You've reached the limit on the number of resources you can create, or exceeded the size of an individual resources. </p>
     ServiceQuotaExceeded(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateCostCategoryDefinitionError {
@@ -2107,17 +2110,24 @@ impl CreateCostCategoryDefinitionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateCostCategoryDefinitionError::LimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateCostCategoryDefinitionError::LimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceQuotaExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         CreateCostCategoryDefinitionError::ServiceQuotaExceeded(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        CreateCostCategoryDefinitionError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -2131,17 +2141,21 @@ impl fmt::Display for CreateCostCategoryDefinitionError {
             CreateCostCategoryDefinitionError::ServiceQuotaExceeded(ref cause) => {
                 write!(f, "{}", cause)
             }
+            CreateCostCategoryDefinitionError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateCostCategoryDefinitionError {}
 /// Errors returned by DeleteCostCategoryDefinition
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteCostCategoryDefinitionError {
     /// <p>You made too many calls in a short period of time. Try again later.</p>
     LimitExceeded(String),
     /// <p> The specified ARN in the request doesn't exist. </p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteCostCategoryDefinitionError {
@@ -2151,17 +2165,24 @@ impl DeleteCostCategoryDefinitionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "LimitExceededException" => {
-                    return RusotoError::Service(DeleteCostCategoryDefinitionError::LimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteCostCategoryDefinitionError::LimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DeleteCostCategoryDefinitionError::ResourceNotFound(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        DeleteCostCategoryDefinitionError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -2175,17 +2196,21 @@ impl fmt::Display for DeleteCostCategoryDefinitionError {
             DeleteCostCategoryDefinitionError::ResourceNotFound(ref cause) => {
                 write!(f, "{}", cause)
             }
+            DeleteCostCategoryDefinitionError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteCostCategoryDefinitionError {}
 /// Errors returned by DescribeCostCategoryDefinition
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeCostCategoryDefinitionError {
     /// <p>You made too many calls in a short period of time. Try again later.</p>
     LimitExceeded(String),
     /// <p> The specified ARN in the request doesn't exist. </p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeCostCategoryDefinitionError {
@@ -2195,17 +2220,24 @@ impl DescribeCostCategoryDefinitionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "LimitExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DescribeCostCategoryDefinitionError::LimitExceeded(err.msg),
+                        &res,
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DescribeCostCategoryDefinitionError::ResourceNotFound(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        DescribeCostCategoryDefinitionError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -2219,12 +2251,16 @@ impl fmt::Display for DescribeCostCategoryDefinitionError {
             DescribeCostCategoryDefinitionError::ResourceNotFound(ref cause) => {
                 write!(f, "{}", cause)
             }
+            DescribeCostCategoryDefinitionError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for DescribeCostCategoryDefinitionError {}
 /// Errors returned by GetCostAndUsage
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetCostAndUsageError {
     /// <p>The requested report expired. Update the date interval and try again.</p>
     BillExpiration(String),
@@ -2236,6 +2272,8 @@ pub enum GetCostAndUsageError {
     LimitExceeded(String),
     /// <p>Your request parameters changed between pages. Try again with the old parameters or without a pagination token.</p>
     RequestChanged(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetCostAndUsageError {
@@ -2243,22 +2281,34 @@ impl GetCostAndUsageError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "BillExpirationException" => {
-                    return RusotoError::Service(GetCostAndUsageError::BillExpiration(err.msg))
+                    return RusotoError::service(
+                        GetCostAndUsageError::BillExpiration(err.msg),
+                        &res,
+                    )
                 }
                 "DataUnavailableException" => {
-                    return RusotoError::Service(GetCostAndUsageError::DataUnavailable(err.msg))
+                    return RusotoError::service(
+                        GetCostAndUsageError::DataUnavailable(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(GetCostAndUsageError::InvalidNextToken(err.msg))
+                    return RusotoError::service(
+                        GetCostAndUsageError::InvalidNextToken(err.msg),
+                        &res,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(GetCostAndUsageError::LimitExceeded(err.msg))
+                    return RusotoError::service(GetCostAndUsageError::LimitExceeded(err.msg), &res)
                 }
                 "RequestChangedException" => {
-                    return RusotoError::Service(GetCostAndUsageError::RequestChanged(err.msg))
+                    return RusotoError::service(
+                        GetCostAndUsageError::RequestChanged(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetCostAndUsageError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2273,12 +2323,14 @@ impl fmt::Display for GetCostAndUsageError {
             GetCostAndUsageError::InvalidNextToken(ref cause) => write!(f, "{}", cause),
             GetCostAndUsageError::LimitExceeded(ref cause) => write!(f, "{}", cause),
             GetCostAndUsageError::RequestChanged(ref cause) => write!(f, "{}", cause),
+            GetCostAndUsageError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetCostAndUsageError {}
 /// Errors returned by GetCostAndUsageWithResources
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetCostAndUsageWithResourcesError {
     /// <p>The requested report expired. Update the date interval and try again.</p>
     BillExpiration(String),
@@ -2290,6 +2342,8 @@ pub enum GetCostAndUsageWithResourcesError {
     LimitExceeded(String),
     /// <p>Your request parameters changed between pages. Try again with the old parameters or without a pagination token.</p>
     RequestChanged(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetCostAndUsageWithResourcesError {
@@ -2299,32 +2353,42 @@ impl GetCostAndUsageWithResourcesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "BillExpirationException" => {
-                    return RusotoError::Service(GetCostAndUsageWithResourcesError::BillExpiration(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetCostAndUsageWithResourcesError::BillExpiration(err.msg),
+                        &res,
+                    )
                 }
                 "DataUnavailableException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         GetCostAndUsageWithResourcesError::DataUnavailable(err.msg),
+                        &res,
                     )
                 }
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         GetCostAndUsageWithResourcesError::InvalidNextToken(err.msg),
+                        &res,
                     )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(GetCostAndUsageWithResourcesError::LimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetCostAndUsageWithResourcesError::LimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "RequestChangedException" => {
-                    return RusotoError::Service(GetCostAndUsageWithResourcesError::RequestChanged(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetCostAndUsageWithResourcesError::RequestChanged(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        GetCostAndUsageWithResourcesError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -2341,17 +2405,21 @@ impl fmt::Display for GetCostAndUsageWithResourcesError {
             }
             GetCostAndUsageWithResourcesError::LimitExceeded(ref cause) => write!(f, "{}", cause),
             GetCostAndUsageWithResourcesError::RequestChanged(ref cause) => write!(f, "{}", cause),
+            GetCostAndUsageWithResourcesError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetCostAndUsageWithResourcesError {}
 /// Errors returned by GetCostForecast
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetCostForecastError {
     /// <p>The requested data is unavailable.</p>
     DataUnavailable(String),
     /// <p>You made too many calls in a short period of time. Try again later.</p>
     LimitExceeded(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetCostForecastError {
@@ -2359,13 +2427,16 @@ impl GetCostForecastError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DataUnavailableException" => {
-                    return RusotoError::Service(GetCostForecastError::DataUnavailable(err.msg))
+                    return RusotoError::service(
+                        GetCostForecastError::DataUnavailable(err.msg),
+                        &res,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(GetCostForecastError::LimitExceeded(err.msg))
+                    return RusotoError::service(GetCostForecastError::LimitExceeded(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetCostForecastError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2377,12 +2448,14 @@ impl fmt::Display for GetCostForecastError {
         match *self {
             GetCostForecastError::DataUnavailable(ref cause) => write!(f, "{}", cause),
             GetCostForecastError::LimitExceeded(ref cause) => write!(f, "{}", cause),
+            GetCostForecastError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetCostForecastError {}
 /// Errors returned by GetDimensionValues
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetDimensionValuesError {
     /// <p>The requested report expired. Update the date interval and try again.</p>
     BillExpiration(String),
@@ -2394,6 +2467,8 @@ pub enum GetDimensionValuesError {
     LimitExceeded(String),
     /// <p>Your request parameters changed between pages. Try again with the old parameters or without a pagination token.</p>
     RequestChanged(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetDimensionValuesError {
@@ -2401,22 +2476,37 @@ impl GetDimensionValuesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "BillExpirationException" => {
-                    return RusotoError::Service(GetDimensionValuesError::BillExpiration(err.msg))
+                    return RusotoError::service(
+                        GetDimensionValuesError::BillExpiration(err.msg),
+                        &res,
+                    )
                 }
                 "DataUnavailableException" => {
-                    return RusotoError::Service(GetDimensionValuesError::DataUnavailable(err.msg))
+                    return RusotoError::service(
+                        GetDimensionValuesError::DataUnavailable(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(GetDimensionValuesError::InvalidNextToken(err.msg))
+                    return RusotoError::service(
+                        GetDimensionValuesError::InvalidNextToken(err.msg),
+                        &res,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(GetDimensionValuesError::LimitExceeded(err.msg))
+                    return RusotoError::service(
+                        GetDimensionValuesError::LimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "RequestChangedException" => {
-                    return RusotoError::Service(GetDimensionValuesError::RequestChanged(err.msg))
+                    return RusotoError::service(
+                        GetDimensionValuesError::RequestChanged(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetDimensionValuesError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2431,12 +2521,14 @@ impl fmt::Display for GetDimensionValuesError {
             GetDimensionValuesError::InvalidNextToken(ref cause) => write!(f, "{}", cause),
             GetDimensionValuesError::LimitExceeded(ref cause) => write!(f, "{}", cause),
             GetDimensionValuesError::RequestChanged(ref cause) => write!(f, "{}", cause),
+            GetDimensionValuesError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetDimensionValuesError {}
 /// Errors returned by GetReservationCoverage
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetReservationCoverageError {
     /// <p>The requested data is unavailable.</p>
     DataUnavailable(String),
@@ -2444,6 +2536,8 @@ pub enum GetReservationCoverageError {
     InvalidNextToken(String),
     /// <p>You made too many calls in a short period of time. Try again later.</p>
     LimitExceeded(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetReservationCoverageError {
@@ -2451,22 +2545,25 @@ impl GetReservationCoverageError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DataUnavailableException" => {
-                    return RusotoError::Service(GetReservationCoverageError::DataUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetReservationCoverageError::DataUnavailable(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(GetReservationCoverageError::InvalidNextToken(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetReservationCoverageError::InvalidNextToken(err.msg),
+                        &res,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(GetReservationCoverageError::LimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetReservationCoverageError::LimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetReservationCoverageError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2479,12 +2576,14 @@ impl fmt::Display for GetReservationCoverageError {
             GetReservationCoverageError::DataUnavailable(ref cause) => write!(f, "{}", cause),
             GetReservationCoverageError::InvalidNextToken(ref cause) => write!(f, "{}", cause),
             GetReservationCoverageError::LimitExceeded(ref cause) => write!(f, "{}", cause),
+            GetReservationCoverageError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetReservationCoverageError {}
 /// Errors returned by GetReservationPurchaseRecommendation
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetReservationPurchaseRecommendationError {
     /// <p>The requested data is unavailable.</p>
     DataUnavailable(String),
@@ -2492,6 +2591,8 @@ pub enum GetReservationPurchaseRecommendationError {
     InvalidNextToken(String),
     /// <p>You made too many calls in a short period of time. Try again later.</p>
     LimitExceeded(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetReservationPurchaseRecommendationError {
@@ -2501,22 +2602,30 @@ impl GetReservationPurchaseRecommendationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DataUnavailableException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         GetReservationPurchaseRecommendationError::DataUnavailable(err.msg),
+                        &res,
                     )
                 }
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         GetReservationPurchaseRecommendationError::InvalidNextToken(err.msg),
+                        &res,
                     )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         GetReservationPurchaseRecommendationError::LimitExceeded(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        GetReservationPurchaseRecommendationError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -2535,12 +2644,16 @@ impl fmt::Display for GetReservationPurchaseRecommendationError {
             GetReservationPurchaseRecommendationError::LimitExceeded(ref cause) => {
                 write!(f, "{}", cause)
             }
+            GetReservationPurchaseRecommendationError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for GetReservationPurchaseRecommendationError {}
 /// Errors returned by GetReservationUtilization
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetReservationUtilizationError {
     /// <p>The requested data is unavailable.</p>
     DataUnavailable(String),
@@ -2548,6 +2661,8 @@ pub enum GetReservationUtilizationError {
     InvalidNextToken(String),
     /// <p>You made too many calls in a short period of time. Try again later.</p>
     LimitExceeded(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetReservationUtilizationError {
@@ -2555,22 +2670,27 @@ impl GetReservationUtilizationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DataUnavailableException" => {
-                    return RusotoError::Service(GetReservationUtilizationError::DataUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetReservationUtilizationError::DataUnavailable(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(GetReservationUtilizationError::InvalidNextToken(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetReservationUtilizationError::InvalidNextToken(err.msg),
+                        &res,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(GetReservationUtilizationError::LimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetReservationUtilizationError::LimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(GetReservationUtilizationError::Unhandled, &res)
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -2583,17 +2703,21 @@ impl fmt::Display for GetReservationUtilizationError {
             GetReservationUtilizationError::DataUnavailable(ref cause) => write!(f, "{}", cause),
             GetReservationUtilizationError::InvalidNextToken(ref cause) => write!(f, "{}", cause),
             GetReservationUtilizationError::LimitExceeded(ref cause) => write!(f, "{}", cause),
+            GetReservationUtilizationError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetReservationUtilizationError {}
 /// Errors returned by GetRightsizingRecommendation
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetRightsizingRecommendationError {
     /// <p>The pagination token is invalid. Try again without a pagination token.</p>
     InvalidNextToken(String),
     /// <p>You made too many calls in a short period of time. Try again later.</p>
     LimitExceeded(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetRightsizingRecommendationError {
@@ -2603,17 +2727,24 @@ impl GetRightsizingRecommendationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         GetRightsizingRecommendationError::InvalidNextToken(err.msg),
+                        &res,
                     )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(GetRightsizingRecommendationError::LimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetRightsizingRecommendationError::LimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        GetRightsizingRecommendationError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -2627,12 +2758,14 @@ impl fmt::Display for GetRightsizingRecommendationError {
                 write!(f, "{}", cause)
             }
             GetRightsizingRecommendationError::LimitExceeded(ref cause) => write!(f, "{}", cause),
+            GetRightsizingRecommendationError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetRightsizingRecommendationError {}
 /// Errors returned by GetSavingsPlansCoverage
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetSavingsPlansCoverageError {
     /// <p>The requested data is unavailable.</p>
     DataUnavailable(String),
@@ -2640,6 +2773,8 @@ pub enum GetSavingsPlansCoverageError {
     InvalidNextToken(String),
     /// <p>You made too many calls in a short period of time. Try again later.</p>
     LimitExceeded(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetSavingsPlansCoverageError {
@@ -2647,22 +2782,25 @@ impl GetSavingsPlansCoverageError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DataUnavailableException" => {
-                    return RusotoError::Service(GetSavingsPlansCoverageError::DataUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetSavingsPlansCoverageError::DataUnavailable(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(GetSavingsPlansCoverageError::InvalidNextToken(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetSavingsPlansCoverageError::InvalidNextToken(err.msg),
+                        &res,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(GetSavingsPlansCoverageError::LimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetSavingsPlansCoverageError::LimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetSavingsPlansCoverageError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2675,17 +2813,21 @@ impl fmt::Display for GetSavingsPlansCoverageError {
             GetSavingsPlansCoverageError::DataUnavailable(ref cause) => write!(f, "{}", cause),
             GetSavingsPlansCoverageError::InvalidNextToken(ref cause) => write!(f, "{}", cause),
             GetSavingsPlansCoverageError::LimitExceeded(ref cause) => write!(f, "{}", cause),
+            GetSavingsPlansCoverageError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetSavingsPlansCoverageError {}
 /// Errors returned by GetSavingsPlansPurchaseRecommendation
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetSavingsPlansPurchaseRecommendationError {
     /// <p>The pagination token is invalid. Try again without a pagination token.</p>
     InvalidNextToken(String),
     /// <p>You made too many calls in a short period of time. Try again later.</p>
     LimitExceeded(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetSavingsPlansPurchaseRecommendationError {
@@ -2695,17 +2837,24 @@ impl GetSavingsPlansPurchaseRecommendationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         GetSavingsPlansPurchaseRecommendationError::InvalidNextToken(err.msg),
+                        &res,
                     )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         GetSavingsPlansPurchaseRecommendationError::LimitExceeded(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        GetSavingsPlansPurchaseRecommendationError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -2721,17 +2870,23 @@ impl fmt::Display for GetSavingsPlansPurchaseRecommendationError {
             GetSavingsPlansPurchaseRecommendationError::LimitExceeded(ref cause) => {
                 write!(f, "{}", cause)
             }
+            GetSavingsPlansPurchaseRecommendationError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for GetSavingsPlansPurchaseRecommendationError {}
 /// Errors returned by GetSavingsPlansUtilization
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetSavingsPlansUtilizationError {
     /// <p>The requested data is unavailable.</p>
     DataUnavailable(String),
     /// <p>You made too many calls in a short period of time. Try again later.</p>
     LimitExceeded(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetSavingsPlansUtilizationError {
@@ -2741,17 +2896,21 @@ impl GetSavingsPlansUtilizationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DataUnavailableException" => {
-                    return RusotoError::Service(GetSavingsPlansUtilizationError::DataUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetSavingsPlansUtilizationError::DataUnavailable(err.msg),
+                        &res,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(GetSavingsPlansUtilizationError::LimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetSavingsPlansUtilizationError::LimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(GetSavingsPlansUtilizationError::Unhandled, &res)
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -2763,12 +2922,14 @@ impl fmt::Display for GetSavingsPlansUtilizationError {
         match *self {
             GetSavingsPlansUtilizationError::DataUnavailable(ref cause) => write!(f, "{}", cause),
             GetSavingsPlansUtilizationError::LimitExceeded(ref cause) => write!(f, "{}", cause),
+            GetSavingsPlansUtilizationError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetSavingsPlansUtilizationError {}
 /// Errors returned by GetSavingsPlansUtilizationDetails
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetSavingsPlansUtilizationDetailsError {
     /// <p>The requested data is unavailable.</p>
     DataUnavailable(String),
@@ -2776,6 +2937,8 @@ pub enum GetSavingsPlansUtilizationDetailsError {
     InvalidNextToken(String),
     /// <p>You made too many calls in a short period of time. Try again later.</p>
     LimitExceeded(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetSavingsPlansUtilizationDetailsError {
@@ -2785,22 +2948,30 @@ impl GetSavingsPlansUtilizationDetailsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DataUnavailableException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         GetSavingsPlansUtilizationDetailsError::DataUnavailable(err.msg),
+                        &res,
                     )
                 }
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         GetSavingsPlansUtilizationDetailsError::InvalidNextToken(err.msg),
+                        &res,
                     )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         GetSavingsPlansUtilizationDetailsError::LimitExceeded(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        GetSavingsPlansUtilizationDetailsError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -2819,12 +2990,16 @@ impl fmt::Display for GetSavingsPlansUtilizationDetailsError {
             GetSavingsPlansUtilizationDetailsError::LimitExceeded(ref cause) => {
                 write!(f, "{}", cause)
             }
+            GetSavingsPlansUtilizationDetailsError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for GetSavingsPlansUtilizationDetailsError {}
 /// Errors returned by GetTags
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetTagsError {
     /// <p>The requested report expired. Update the date interval and try again.</p>
     BillExpiration(String),
@@ -2836,6 +3011,8 @@ pub enum GetTagsError {
     LimitExceeded(String),
     /// <p>Your request parameters changed between pages. Try again with the old parameters or without a pagination token.</p>
     RequestChanged(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetTagsError {
@@ -2843,22 +3020,22 @@ impl GetTagsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "BillExpirationException" => {
-                    return RusotoError::Service(GetTagsError::BillExpiration(err.msg))
+                    return RusotoError::service(GetTagsError::BillExpiration(err.msg), &res)
                 }
                 "DataUnavailableException" => {
-                    return RusotoError::Service(GetTagsError::DataUnavailable(err.msg))
+                    return RusotoError::service(GetTagsError::DataUnavailable(err.msg), &res)
                 }
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(GetTagsError::InvalidNextToken(err.msg))
+                    return RusotoError::service(GetTagsError::InvalidNextToken(err.msg), &res)
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(GetTagsError::LimitExceeded(err.msg))
+                    return RusotoError::service(GetTagsError::LimitExceeded(err.msg), &res)
                 }
                 "RequestChangedException" => {
-                    return RusotoError::Service(GetTagsError::RequestChanged(err.msg))
+                    return RusotoError::service(GetTagsError::RequestChanged(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetTagsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2873,12 +3050,14 @@ impl fmt::Display for GetTagsError {
             GetTagsError::InvalidNextToken(ref cause) => write!(f, "{}", cause),
             GetTagsError::LimitExceeded(ref cause) => write!(f, "{}", cause),
             GetTagsError::RequestChanged(ref cause) => write!(f, "{}", cause),
+            GetTagsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetTagsError {}
 /// Errors returned by GetUsageForecast
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetUsageForecastError {
     /// <p>The requested data is unavailable.</p>
     DataUnavailable(String),
@@ -2886,6 +3065,8 @@ pub enum GetUsageForecastError {
     LimitExceeded(String),
     /// <p>Cost Explorer was unable to identify the usage unit. Provide <code>UsageType/UsageTypeGroup</code> filter selections that contain matching units, for example: <code>hours</code>.</p>
     UnresolvableUsageUnit(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetUsageForecastError {
@@ -2893,18 +3074,25 @@ impl GetUsageForecastError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DataUnavailableException" => {
-                    return RusotoError::Service(GetUsageForecastError::DataUnavailable(err.msg))
+                    return RusotoError::service(
+                        GetUsageForecastError::DataUnavailable(err.msg),
+                        &res,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(GetUsageForecastError::LimitExceeded(err.msg))
+                    return RusotoError::service(
+                        GetUsageForecastError::LimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "UnresolvableUsageUnitException" => {
-                    return RusotoError::Service(GetUsageForecastError::UnresolvableUsageUnit(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetUsageForecastError::UnresolvableUsageUnit(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetUsageForecastError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2917,15 +3105,19 @@ impl fmt::Display for GetUsageForecastError {
             GetUsageForecastError::DataUnavailable(ref cause) => write!(f, "{}", cause),
             GetUsageForecastError::LimitExceeded(ref cause) => write!(f, "{}", cause),
             GetUsageForecastError::UnresolvableUsageUnit(ref cause) => write!(f, "{}", cause),
+            GetUsageForecastError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetUsageForecastError {}
 /// Errors returned by ListCostCategoryDefinitions
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListCostCategoryDefinitionsError {
     /// <p>You made too many calls in a short period of time. Try again later.</p>
     LimitExceeded(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListCostCategoryDefinitionsError {
@@ -2935,12 +3127,18 @@ impl ListCostCategoryDefinitionsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "LimitExceededException" => {
-                    return RusotoError::Service(ListCostCategoryDefinitionsError::LimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListCostCategoryDefinitionsError::LimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        ListCostCategoryDefinitionsError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -2951,12 +3149,14 @@ impl fmt::Display for ListCostCategoryDefinitionsError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             ListCostCategoryDefinitionsError::LimitExceeded(ref cause) => write!(f, "{}", cause),
+            ListCostCategoryDefinitionsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListCostCategoryDefinitionsError {}
 /// Errors returned by UpdateCostCategoryDefinition
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdateCostCategoryDefinitionError {
     /// <p>You made too many calls in a short period of time. Try again later.</p>
     LimitExceeded(String),
@@ -2964,6 +3164,8 @@ pub enum UpdateCostCategoryDefinitionError {
     ResourceNotFound(String),
     /// <p> You've reached the limit on the number of resources you can create, or exceeded the size of an individual resources. </p>
     ServiceQuotaExceeded(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdateCostCategoryDefinitionError {
@@ -2973,22 +3175,30 @@ impl UpdateCostCategoryDefinitionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "LimitExceededException" => {
-                    return RusotoError::Service(UpdateCostCategoryDefinitionError::LimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateCostCategoryDefinitionError::LimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         UpdateCostCategoryDefinitionError::ResourceNotFound(err.msg),
+                        &res,
                     )
                 }
                 "ServiceQuotaExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         UpdateCostCategoryDefinitionError::ServiceQuotaExceeded(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        UpdateCostCategoryDefinitionError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -3005,6 +3215,7 @@ impl fmt::Display for UpdateCostCategoryDefinitionError {
             UpdateCostCategoryDefinitionError::ServiceQuotaExceeded(ref cause) => {
                 write!(f, "{}", cause)
             }
+            UpdateCostCategoryDefinitionError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
@@ -3149,6 +3360,7 @@ impl CostExplorerClient {
     /// Creates a client backed by the default tokio event loop.
     ///
     /// The client will use the default credentials provider and tls client.
+    #[cfg(not(target_arch = "wasm32"))]
     pub fn new(region: region::Region) -> CostExplorerClient {
         CostExplorerClient {
             client: Client::shared(),
@@ -3174,17 +3386,73 @@ impl CostExplorerClient {
     pub fn new_with_client(client: Client, region: region::Region) -> CostExplorerClient {
         CostExplorerClient { client, region }
     }
+
+    /// Returns a builder of a client, setting any of its region, credentials provider,
+    /// dispatcher, retry policy, endpoint and timeouts.
+    pub fn builder() -> rusoto_core::ClientBuilder<CostExplorerClient> {
+        rusoto_core::ClientBuilder::new(CostExplorerClient::new_with_client)
+    }
+
+    /// Returns a clone of this client with the overrides of `config` applied to its
+    /// operations, sharing its connections.
+    pub fn customize(&self, config: &rusoto_core::OperationConfig) -> CostExplorerClient {
+        CostExplorerClient {
+            client: self.client.customize(config),
+            region: config.resolve_region(&self.region),
+        }
+    }
 }
 
-#[async_trait]
-impl CostExplorer for CostExplorerClient {
-    /// <p><important> <p> <i> <b>Cost Category is in public beta for AWS Billing and Cost Management and is subject to change. Your use of Cost Categories is subject to the Beta Service Participation terms of the <a href="https://aws.amazon.com/service-terms/">AWS Service Terms</a> (Section 1.10).</b> </i> </p> </important> <p>Creates a new Cost Category with the requested name and rules.</p></p>
-    async fn create_cost_category_definition(
+impl CostExplorerClient {
+    /// Calls `create_cost_category_definition`, also returning the metadata of the response.
+    pub async fn create_cost_category_definition_with_metadata(
         &self,
         input: CreateCostCategoryDefinitionRequest,
-    ) -> Result<CreateCostCategoryDefinitionResponse, RusotoError<CreateCostCategoryDefinitionError>>
-    {
+    ) -> Result<
+        (CreateCostCategoryDefinitionResponse, ResponseMetadata),
+        RusotoError<CreateCostCategoryDefinitionError>,
+    > {
+        let request = self.build_create_cost_category_definition_request(input);
+
+        let mut response = self
+            .client
+            .sign_and_dispatch(request)
+            .await
+            .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
+        if response.status.is_success() {
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<CreateCostCategoryDefinitionResponse, _>()?;
+            Ok((result, metadata))
+        } else {
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(CreateCostCategoryDefinitionError::from_response(response))
+        }
+    }
+
+    /// Presigns the request `create_cost_category_definition` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_cost_category_definition(
+        &self,
+        presigner: &rusoto_core::Presigner,
+        input: CreateCostCategoryDefinitionRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_cost_category_definition_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_cost_category_definition_request(
+        &self,
+        input: CreateCostCategoryDefinitionRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "ce", &self.region, "/");
+        request.set_operation("CreateCostCategoryDefinition");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -3194,29 +3462,58 @@ impl CostExplorer for CostExplorerClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `delete_cost_category_definition`, also returning the metadata of the response.
+    pub async fn delete_cost_category_definition_with_metadata(
+        &self,
+        input: DeleteCostCategoryDefinitionRequest,
+    ) -> Result<
+        (DeleteCostCategoryDefinitionResponse, ResponseMetadata),
+        RusotoError<DeleteCostCategoryDefinitionError>,
+    > {
+        let request = self.build_delete_cost_category_definition_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<CreateCostCategoryDefinitionResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DeleteCostCategoryDefinitionResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(CreateCostCategoryDefinitionError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DeleteCostCategoryDefinitionError::from_response(response))
         }
     }
 
-    /// <p><important> <p> <i> <b>Cost Category is in public beta for AWS Billing and Cost Management and is subject to change. Your use of Cost Categories is subject to the Beta Service Participation terms of the <a href="https://aws.amazon.com/service-terms/">AWS Service Terms</a> (Section 1.10).</b> </i> </p> </important> <p>Deletes a Cost Category. Expenses from this month going forward will no longer be categorized with this Cost Category.</p></p>
-    async fn delete_cost_category_definition(
+    /// Presigns the request `delete_cost_category_definition` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_cost_category_definition(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteCostCategoryDefinitionRequest,
-    ) -> Result<DeleteCostCategoryDefinitionResponse, RusotoError<DeleteCostCategoryDefinitionError>>
-    {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_cost_category_definition_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_cost_category_definition_request(
+        &self,
+        input: DeleteCostCategoryDefinitionRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "ce", &self.region, "/");
+        request.set_operation("DeleteCostCategoryDefinition");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -3226,31 +3523,58 @@ impl CostExplorer for CostExplorerClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `describe_cost_category_definition`, also returning the metadata of the response.
+    pub async fn describe_cost_category_definition_with_metadata(
+        &self,
+        input: DescribeCostCategoryDefinitionRequest,
+    ) -> Result<
+        (DescribeCostCategoryDefinitionResponse, ResponseMetadata),
+        RusotoError<DescribeCostCategoryDefinitionError>,
+    > {
+        let request = self.build_describe_cost_category_definition_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DeleteCostCategoryDefinitionResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DescribeCostCategoryDefinitionResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteCostCategoryDefinitionError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DescribeCostCategoryDefinitionError::from_response(response))
         }
     }
 
-    /// <p><important> <p> <i> <b>Cost Category is in public beta for AWS Billing and Cost Management and is subject to change. Your use of Cost Categories is subject to the Beta Service Participation terms of the <a href="https://aws.amazon.com/service-terms/">AWS Service Terms</a> (Section 1.10).</b> </i> </p> </important> <p>Returns the name, ARN, rules, definition, and effective dates of a Cost Category that&#39;s defined in the account.</p> <p>You have the option to use <code>EffectiveOn</code> to return a Cost Category that is active on a specific date. If there is no <code>EffectiveOn</code> specified, you’ll see a Cost Category that is effective on the current date. If Cost Category is still effective, <code>EffectiveEnd</code> is omitted in the response. </p></p>
-    async fn describe_cost_category_definition(
+    /// Presigns the request `describe_cost_category_definition` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_cost_category_definition(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeCostCategoryDefinitionRequest,
-    ) -> Result<
-        DescribeCostCategoryDefinitionResponse,
-        RusotoError<DescribeCostCategoryDefinitionError>,
-    > {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_cost_category_definition_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_cost_category_definition_request(
+        &self,
+        input: DescribeCostCategoryDefinitionRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "ce", &self.region, "/");
+        request.set_operation("DescribeCostCategoryDefinition");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -3260,56 +3584,111 @@ impl CostExplorer for CostExplorerClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `get_cost_and_usage`, also returning the metadata of the response.
+    pub async fn get_cost_and_usage_with_metadata(
+        &self,
+        input: GetCostAndUsageRequest,
+    ) -> Result<(GetCostAndUsageResponse, ResponseMetadata), RusotoError<GetCostAndUsageError>>
+    {
+        let request = self.build_get_cost_and_usage_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DescribeCostCategoryDefinitionResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<GetCostAndUsageResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeCostCategoryDefinitionError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(GetCostAndUsageError::from_response(response))
         }
     }
 
-    /// <p>Retrieves cost and usage metrics for your account. You can specify which cost and usage-related metric, such as <code>BlendedCosts</code> or <code>UsageQuantity</code>, that you want the request to return. You can also filter and group your data by various dimensions, such as <code>SERVICE</code> or <code>AZ</code>, in a specific time range. For a complete list of valid dimensions, see the <a href="http://docs.aws.amazon.com/aws-cost-management/latest/APIReference/API_GetDimensionValues.html">GetDimensionValues</a> operation. Master accounts in an organization in AWS Organizations have access to all member accounts.</p>
-    async fn get_cost_and_usage(
+    /// Presigns the request `get_cost_and_usage` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_cost_and_usage(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetCostAndUsageRequest,
-    ) -> Result<GetCostAndUsageResponse, RusotoError<GetCostAndUsageError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_cost_and_usage_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_cost_and_usage_request(&self, input: GetCostAndUsageRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "ce", &self.region, "/");
+        request.set_operation("GetCostAndUsage");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSInsightsIndexService.GetCostAndUsage");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `get_cost_and_usage_with_resources`, also returning the metadata of the response.
+    pub async fn get_cost_and_usage_with_resources_with_metadata(
+        &self,
+        input: GetCostAndUsageWithResourcesRequest,
+    ) -> Result<
+        (GetCostAndUsageWithResourcesResponse, ResponseMetadata),
+        RusotoError<GetCostAndUsageWithResourcesError>,
+    > {
+        let request = self.build_get_cost_and_usage_with_resources_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<GetCostAndUsageResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<GetCostAndUsageWithResourcesResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(GetCostAndUsageError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(GetCostAndUsageWithResourcesError::from_response(response))
         }
     }
 
-    /// <p><p>Retrieves cost and usage metrics with resources for your account. You can specify which cost and usage-related metric, such as <code>BlendedCosts</code> or <code>UsageQuantity</code>, that you want the request to return. You can also filter and group your data by various dimensions, such as <code>SERVICE</code> or <code>AZ</code>, in a specific time range. For a complete list of valid dimensions, see the <a href="http://docs.aws.amazon.com/aws-cost-management/latest/APIReference/API_GetDimensionValues.html">GetDimensionValues</a> operation. Master accounts in an organization in AWS Organizations have access to all member accounts. This API is currently available for the Amazon Elastic Compute Cloud – Compute service only.</p> <note> <p>This is an opt-in only feature. You can enable this feature from the Cost Explorer Settings page. For information on how to access the Settings page, see <a href="https://docs.aws.amazon.com/awsaccountbilling/latest/aboutv2/ce-access.html">Controlling Access for Cost Explorer</a> in the <i>AWS Billing and Cost Management User Guide</i>.</p> </note></p>
-    async fn get_cost_and_usage_with_resources(
+    /// Presigns the request `get_cost_and_usage_with_resources` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_cost_and_usage_with_resources(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetCostAndUsageWithResourcesRequest,
-    ) -> Result<GetCostAndUsageWithResourcesResponse, RusotoError<GetCostAndUsageWithResourcesError>>
-    {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_cost_and_usage_with_resources_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_cost_and_usage_with_resources_request(
+        &self,
+        input: GetCostAndUsageWithResourcesRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "ce", &self.region, "/");
+        request.set_operation("GetCostAndUsageWithResources");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -3319,83 +3698,167 @@ impl CostExplorer for CostExplorerClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `get_cost_forecast`, also returning the metadata of the response.
+    pub async fn get_cost_forecast_with_metadata(
+        &self,
+        input: GetCostForecastRequest,
+    ) -> Result<(GetCostForecastResponse, ResponseMetadata), RusotoError<GetCostForecastError>>
+    {
+        let request = self.build_get_cost_forecast_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<GetCostAndUsageWithResourcesResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<GetCostForecastResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(GetCostAndUsageWithResourcesError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(GetCostForecastError::from_response(response))
         }
     }
 
-    /// <p>Retrieves a forecast for how much Amazon Web Services predicts that you will spend over the forecast time period that you select, based on your past costs. </p>
-    async fn get_cost_forecast(
+    /// Presigns the request `get_cost_forecast` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_cost_forecast(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetCostForecastRequest,
-    ) -> Result<GetCostForecastResponse, RusotoError<GetCostForecastError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_cost_forecast_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_cost_forecast_request(&self, input: GetCostForecastRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "ce", &self.region, "/");
+        request.set_operation("GetCostForecast");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSInsightsIndexService.GetCostForecast");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `get_dimension_values`, also returning the metadata of the response.
+    pub async fn get_dimension_values_with_metadata(
+        &self,
+        input: GetDimensionValuesRequest,
+    ) -> Result<(GetDimensionValuesResponse, ResponseMetadata), RusotoError<GetDimensionValuesError>>
+    {
+        let request = self.build_get_dimension_values_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<GetCostForecastResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<GetDimensionValuesResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(GetCostForecastError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(GetDimensionValuesError::from_response(response))
         }
     }
 
-    /// <p>Retrieves all available filter values for a specified filter over a period of time. You can search the dimension values for an arbitrary string. </p>
-    async fn get_dimension_values(
+    /// Presigns the request `get_dimension_values` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_dimension_values(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetDimensionValuesRequest,
-    ) -> Result<GetDimensionValuesResponse, RusotoError<GetDimensionValuesError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_dimension_values_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_dimension_values_request(
+        &self,
+        input: GetDimensionValuesRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "ce", &self.region, "/");
+        request.set_operation("GetDimensionValues");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSInsightsIndexService.GetDimensionValues");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `get_reservation_coverage`, also returning the metadata of the response.
+    pub async fn get_reservation_coverage_with_metadata(
+        &self,
+        input: GetReservationCoverageRequest,
+    ) -> Result<
+        (GetReservationCoverageResponse, ResponseMetadata),
+        RusotoError<GetReservationCoverageError>,
+    > {
+        let request = self.build_get_reservation_coverage_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<GetDimensionValuesResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<GetReservationCoverageResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(GetDimensionValuesError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(GetReservationCoverageError::from_response(response))
         }
     }
 
-    /// <p>Retrieves the reservation coverage for your account. This enables you to see how much of your Amazon Elastic Compute Cloud, Amazon ElastiCache, Amazon Relational Database Service, or Amazon Redshift usage is covered by a reservation. An organization's master account can see the coverage of the associated member accounts. For any time period, you can filter data about reservation usage by the following dimensions:</p> <ul> <li> <p>AZ</p> </li> <li> <p>CACHE_ENGINE</p> </li> <li> <p>DATABASE_ENGINE</p> </li> <li> <p>DEPLOYMENT_OPTION</p> </li> <li> <p>INSTANCE_TYPE</p> </li> <li> <p>LINKED_ACCOUNT</p> </li> <li> <p>OPERATING_SYSTEM</p> </li> <li> <p>PLATFORM</p> </li> <li> <p>REGION</p> </li> <li> <p>SERVICE</p> </li> <li> <p>TAG</p> </li> <li> <p>TENANCY</p> </li> </ul> <p>To determine valid values for a dimension, use the <code>GetDimensionValues</code> operation. </p>
-    async fn get_reservation_coverage(
+    /// Presigns the request `get_reservation_coverage` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_reservation_coverage(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetReservationCoverageRequest,
-    ) -> Result<GetReservationCoverageResponse, RusotoError<GetReservationCoverageError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_reservation_coverage_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_reservation_coverage_request(
+        &self,
+        input: GetReservationCoverageRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "ce", &self.region, "/");
+        request.set_operation("GetReservationCoverage");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -3405,31 +3868,63 @@ impl CostExplorer for CostExplorerClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `get_reservation_purchase_recommendation`, also returning the metadata of the response.
+    pub async fn get_reservation_purchase_recommendation_with_metadata(
+        &self,
+        input: GetReservationPurchaseRecommendationRequest,
+    ) -> Result<
+        (
+            GetReservationPurchaseRecommendationResponse,
+            ResponseMetadata,
+        ),
+        RusotoError<GetReservationPurchaseRecommendationError>,
+    > {
+        let request = self.build_get_reservation_purchase_recommendation_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<GetReservationCoverageResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<GetReservationPurchaseRecommendationResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(GetReservationCoverageError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(GetReservationPurchaseRecommendationError::from_response(
+                response,
+            ))
         }
     }
 
-    /// <p>Gets recommendations for which reservations to purchase. These recommendations could help you reduce your costs. Reservations provide a discounted hourly rate (up to 75%) compared to On-Demand pricing.</p> <p>AWS generates your recommendations by identifying your On-Demand usage during a specific time period and collecting your usage into categories that are eligible for a reservation. After AWS has these categories, it simulates every combination of reservations in each category of usage to identify the best number of each type of RI to purchase to maximize your estimated savings. </p> <p>For example, AWS automatically aggregates your Amazon EC2 Linux, shared tenancy, and c4 family usage in the US West (Oregon) Region and recommends that you buy size-flexible regional reservations to apply to the c4 family usage. AWS recommends the smallest size instance in an instance family. This makes it easier to purchase a size-flexible RI. AWS also shows the equal number of normalized units so that you can purchase any instance size that you want. For this example, your RI recommendation would be for <code>c4.large</code> because that is the smallest size instance in the c4 instance family.</p>
-    async fn get_reservation_purchase_recommendation(
+    /// Presigns the request `get_reservation_purchase_recommendation` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_reservation_purchase_recommendation(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetReservationPurchaseRecommendationRequest,
-    ) -> Result<
-        GetReservationPurchaseRecommendationResponse,
-        RusotoError<GetReservationPurchaseRecommendationError>,
-    > {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_reservation_purchase_recommendation_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_reservation_purchase_recommendation_request(
+        &self,
+        input: GetReservationPurchaseRecommendationRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "ce", &self.region, "/");
+        request.set_operation("GetReservationPurchaseRecommendation");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -3439,31 +3934,58 @@ impl CostExplorer for CostExplorerClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `get_reservation_utilization`, also returning the metadata of the response.
+    pub async fn get_reservation_utilization_with_metadata(
+        &self,
+        input: GetReservationUtilizationRequest,
+    ) -> Result<
+        (GetReservationUtilizationResponse, ResponseMetadata),
+        RusotoError<GetReservationUtilizationError>,
+    > {
+        let request = self.build_get_reservation_utilization_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<GetReservationPurchaseRecommendationResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<GetReservationUtilizationResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(GetReservationPurchaseRecommendationError::from_response(
-                response,
-            ))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(GetReservationUtilizationError::from_response(response))
         }
     }
 
-    /// <p>Retrieves the reservation utilization for your account. Master accounts in an organization have access to member accounts. You can filter data by dimensions in a time period. You can use <code>GetDimensionValues</code> to determine the possible dimension values. Currently, you can group only by <code>SUBSCRIPTION_ID</code>. </p>
-    async fn get_reservation_utilization(
+    /// Presigns the request `get_reservation_utilization` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_reservation_utilization(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetReservationUtilizationRequest,
-    ) -> Result<GetReservationUtilizationResponse, RusotoError<GetReservationUtilizationError>>
-    {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_reservation_utilization_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_reservation_utilization_request(
+        &self,
+        input: GetReservationUtilizationRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "ce", &self.region, "/");
+        request.set_operation("GetReservationUtilization");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -3473,29 +3995,58 @@ impl CostExplorer for CostExplorerClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `get_rightsizing_recommendation`, also returning the metadata of the response.
+    pub async fn get_rightsizing_recommendation_with_metadata(
+        &self,
+        input: GetRightsizingRecommendationRequest,
+    ) -> Result<
+        (GetRightsizingRecommendationResponse, ResponseMetadata),
+        RusotoError<GetRightsizingRecommendationError>,
+    > {
+        let request = self.build_get_rightsizing_recommendation_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<GetReservationUtilizationResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<GetRightsizingRecommendationResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(GetReservationUtilizationError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(GetRightsizingRecommendationError::from_response(response))
         }
     }
 
-    /// <p>Creates recommendations that helps you save cost by identifying idle and underutilized Amazon EC2 instances.</p> <p>Recommendations are generated to either downsize or terminate instances, along with providing savings detail and metrics. For details on calculation and function, see <a href="https://docs.aws.amazon.com/awsaccountbilling/latest/aboutv2/ce-what-is.html">Optimizing Your Cost with Rightsizing Recommendations</a>.</p>
-    async fn get_rightsizing_recommendation(
+    /// Presigns the request `get_rightsizing_recommendation` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_rightsizing_recommendation(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetRightsizingRecommendationRequest,
-    ) -> Result<GetRightsizingRecommendationResponse, RusotoError<GetRightsizingRecommendationError>>
-    {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_rightsizing_recommendation_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_rightsizing_recommendation_request(
+        &self,
+        input: GetRightsizingRecommendationRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "ce", &self.region, "/");
+        request.set_operation("GetRightsizingRecommendation");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -3505,28 +4056,58 @@ impl CostExplorer for CostExplorerClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `get_savings_plans_coverage`, also returning the metadata of the response.
+    pub async fn get_savings_plans_coverage_with_metadata(
+        &self,
+        input: GetSavingsPlansCoverageRequest,
+    ) -> Result<
+        (GetSavingsPlansCoverageResponse, ResponseMetadata),
+        RusotoError<GetSavingsPlansCoverageError>,
+    > {
+        let request = self.build_get_savings_plans_coverage_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<GetRightsizingRecommendationResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<GetSavingsPlansCoverageResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(GetRightsizingRecommendationError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(GetSavingsPlansCoverageError::from_response(response))
         }
     }
 
-    /// <p>Retrieves the Savings Plans covered for your account. This enables you to see how much of your cost is covered by a Savings Plan. An organization’s master account can see the coverage of the associated member accounts. For any time period, you can filter data for Savings Plans usage with the following dimensions:</p> <ul> <li> <p> <code>LINKED_ACCOUNT</code> </p> </li> <li> <p> <code>REGION</code> </p> </li> <li> <p> <code>SERVICE</code> </p> </li> <li> <p> <code>INSTANCE_FAMILY</code> </p> </li> </ul> <p>To determine valid values for a dimension, use the <code>GetDimensionValues</code> operation.</p>
-    async fn get_savings_plans_coverage(
+    /// Presigns the request `get_savings_plans_coverage` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_savings_plans_coverage(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetSavingsPlansCoverageRequest,
-    ) -> Result<GetSavingsPlansCoverageResponse, RusotoError<GetSavingsPlansCoverageError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_savings_plans_coverage_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_savings_plans_coverage_request(
+        &self,
+        input: GetSavingsPlansCoverageRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "ce", &self.region, "/");
+        request.set_operation("GetSavingsPlansCoverage");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -3536,31 +4117,63 @@ impl CostExplorer for CostExplorerClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `get_savings_plans_purchase_recommendation`, also returning the metadata of the response.
+    pub async fn get_savings_plans_purchase_recommendation_with_metadata(
+        &self,
+        input: GetSavingsPlansPurchaseRecommendationRequest,
+    ) -> Result<
+        (
+            GetSavingsPlansPurchaseRecommendationResponse,
+            ResponseMetadata,
+        ),
+        RusotoError<GetSavingsPlansPurchaseRecommendationError>,
+    > {
+        let request = self.build_get_savings_plans_purchase_recommendation_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<GetSavingsPlansCoverageResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<GetSavingsPlansPurchaseRecommendationResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(GetSavingsPlansCoverageError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(GetSavingsPlansPurchaseRecommendationError::from_response(
+                response,
+            ))
         }
     }
 
-    /// <p>Retrieves your request parameters, Savings Plan Recommendations Summary and Details.</p>
-    async fn get_savings_plans_purchase_recommendation(
+    /// Presigns the request `get_savings_plans_purchase_recommendation` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_savings_plans_purchase_recommendation(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetSavingsPlansPurchaseRecommendationRequest,
-    ) -> Result<
-        GetSavingsPlansPurchaseRecommendationResponse,
-        RusotoError<GetSavingsPlansPurchaseRecommendationError>,
-    > {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_savings_plans_purchase_recommendation_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_savings_plans_purchase_recommendation_request(
+        &self,
+        input: GetSavingsPlansPurchaseRecommendationRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "ce", &self.region, "/");
+        request.set_operation("GetSavingsPlansPurchaseRecommendation");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -3570,31 +4183,58 @@ impl CostExplorer for CostExplorerClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `get_savings_plans_utilization`, also returning the metadata of the response.
+    pub async fn get_savings_plans_utilization_with_metadata(
+        &self,
+        input: GetSavingsPlansUtilizationRequest,
+    ) -> Result<
+        (GetSavingsPlansUtilizationResponse, ResponseMetadata),
+        RusotoError<GetSavingsPlansUtilizationError>,
+    > {
+        let request = self.build_get_savings_plans_utilization_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<GetSavingsPlansPurchaseRecommendationResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<GetSavingsPlansUtilizationResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(GetSavingsPlansPurchaseRecommendationError::from_response(
-                response,
-            ))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(GetSavingsPlansUtilizationError::from_response(response))
         }
     }
 
-    /// <p><p>Retrieves the Savings Plans utilization for your account across date ranges with daily or monthly granularity. Master accounts in an organization have access to member accounts. You can use <code>GetDimensionValues</code> in <code>SAVINGS_PLANS</code> to determine the possible dimension values.</p> <note> <p>You cannot group by any dimension values for <code>GetSavingsPlansUtilization</code>.</p> </note></p>
-    async fn get_savings_plans_utilization(
+    /// Presigns the request `get_savings_plans_utilization` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_savings_plans_utilization(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetSavingsPlansUtilizationRequest,
-    ) -> Result<GetSavingsPlansUtilizationResponse, RusotoError<GetSavingsPlansUtilizationError>>
-    {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_savings_plans_utilization_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_savings_plans_utilization_request(
+        &self,
+        input: GetSavingsPlansUtilizationRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "ce", &self.region, "/");
+        request.set_operation("GetSavingsPlansUtilization");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -3604,31 +4244,60 @@ impl CostExplorer for CostExplorerClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `get_savings_plans_utilization_details`, also returning the metadata of the response.
+    pub async fn get_savings_plans_utilization_details_with_metadata(
+        &self,
+        input: GetSavingsPlansUtilizationDetailsRequest,
+    ) -> Result<
+        (GetSavingsPlansUtilizationDetailsResponse, ResponseMetadata),
+        RusotoError<GetSavingsPlansUtilizationDetailsError>,
+    > {
+        let request = self.build_get_savings_plans_utilization_details_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<GetSavingsPlansUtilizationResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<GetSavingsPlansUtilizationDetailsResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(GetSavingsPlansUtilizationError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(GetSavingsPlansUtilizationDetailsError::from_response(
+                response,
+            ))
         }
     }
 
-    /// <p><p>Retrieves attribute data along with aggregate utilization and savings data for a given time period. This doesn&#39;t support granular or grouped data (daily/monthly) in response. You can&#39;t retrieve data by dates in a single response similar to <code>GetSavingsPlanUtilization</code>, but you have the option to make multiple calls to <code>GetSavingsPlanUtilizationDetails</code> by providing individual dates. You can use <code>GetDimensionValues</code> in <code>SAVINGS_PLANS</code> to determine the possible dimension values.</p> <note> <p> <code>GetSavingsPlanUtilizationDetails</code> internally groups data by <code>SavingsPlansArn</code>.</p> </note></p>
-    async fn get_savings_plans_utilization_details(
+    /// Presigns the request `get_savings_plans_utilization_details` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_savings_plans_utilization_details(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetSavingsPlansUtilizationDetailsRequest,
-    ) -> Result<
-        GetSavingsPlansUtilizationDetailsResponse,
-        RusotoError<GetSavingsPlansUtilizationDetailsError>,
-    > {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_savings_plans_utilization_details_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_savings_plans_utilization_details_request(
+        &self,
+        input: GetSavingsPlansUtilizationDetailsRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "ce", &self.region, "/");
+        request.set_operation("GetSavingsPlansUtilizationDetails");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -3638,86 +4307,163 @@ impl CostExplorer for CostExplorerClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `get_tags`, also returning the metadata of the response.
+    pub async fn get_tags_with_metadata(
+        &self,
+        input: GetTagsRequest,
+    ) -> Result<(GetTagsResponse, ResponseMetadata), RusotoError<GetTagsError>> {
+        let request = self.build_get_tags_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<GetSavingsPlansUtilizationDetailsResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result =
+                proto::json::ResponsePayload::new(&response).deserialize::<GetTagsResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(GetSavingsPlansUtilizationDetailsError::from_response(
-                response,
-            ))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(GetTagsError::from_response(response))
         }
     }
 
-    /// <p>Queries for available tag keys and tag values for a specified period. You can search the tag values for an arbitrary string. </p>
-    async fn get_tags(
+    /// Presigns the request `get_tags` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_tags(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetTagsRequest,
-    ) -> Result<GetTagsResponse, RusotoError<GetTagsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_tags_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_tags_request(&self, input: GetTagsRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "ce", &self.region, "/");
+        request.set_operation("GetTags");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSInsightsIndexService.GetTags");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `get_usage_forecast`, also returning the metadata of the response.
+    pub async fn get_usage_forecast_with_metadata(
+        &self,
+        input: GetUsageForecastRequest,
+    ) -> Result<(GetUsageForecastResponse, ResponseMetadata), RusotoError<GetUsageForecastError>>
+    {
+        let request = self.build_get_usage_forecast_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<GetTagsResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<GetUsageForecastResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(GetTagsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(GetUsageForecastError::from_response(response))
         }
     }
 
-    /// <p>Retrieves a forecast for how much Amazon Web Services predicts that you will use over the forecast time period that you select, based on your past usage. </p>
-    async fn get_usage_forecast(
+    /// Presigns the request `get_usage_forecast` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_usage_forecast(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetUsageForecastRequest,
-    ) -> Result<GetUsageForecastResponse, RusotoError<GetUsageForecastError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_usage_forecast_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_usage_forecast_request(&self, input: GetUsageForecastRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "ce", &self.region, "/");
+        request.set_operation("GetUsageForecast");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSInsightsIndexService.GetUsageForecast");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `list_cost_category_definitions`, also returning the metadata of the response.
+    pub async fn list_cost_category_definitions_with_metadata(
+        &self,
+        input: ListCostCategoryDefinitionsRequest,
+    ) -> Result<
+        (ListCostCategoryDefinitionsResponse, ResponseMetadata),
+        RusotoError<ListCostCategoryDefinitionsError>,
+    > {
+        let request = self.build_list_cost_category_definitions_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<GetUsageForecastResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ListCostCategoryDefinitionsResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(GetUsageForecastError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ListCostCategoryDefinitionsError::from_response(response))
         }
     }
 
-    /// <p><important> <p> <i> <b>Cost Category is in public beta for AWS Billing and Cost Management and is subject to change. Your use of Cost Categories is subject to the Beta Service Participation terms of the <a href="https://aws.amazon.com/service-terms/">AWS Service Terms</a> (Section 1.10).</b> </i> </p> </important> <p>Returns the name, ARN and effective dates of all Cost Categories defined in the account. You have the option to use <code>EffectiveOn</code> to return a list of Cost Categories that were active on a specific date. If there is no <code>EffectiveOn</code> specified, you’ll see Cost Categories that are effective on the current date. If Cost Category is still effective, <code>EffectiveEnd</code> is omitted in the response. </p></p>
-    async fn list_cost_category_definitions(
+    /// Presigns the request `list_cost_category_definitions` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_cost_category_definitions(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListCostCategoryDefinitionsRequest,
-    ) -> Result<ListCostCategoryDefinitionsResponse, RusotoError<ListCostCategoryDefinitionsError>>
-    {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_cost_category_definitions_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_cost_category_definitions_request(
+        &self,
+        input: ListCostCategoryDefinitionsRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "ce", &self.region, "/");
+        request.set_operation("ListCostCategoryDefinitions");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -3727,29 +4473,58 @@ impl CostExplorer for CostExplorerClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `update_cost_category_definition`, also returning the metadata of the response.
+    pub async fn update_cost_category_definition_with_metadata(
+        &self,
+        input: UpdateCostCategoryDefinitionRequest,
+    ) -> Result<
+        (UpdateCostCategoryDefinitionResponse, ResponseMetadata),
+        RusotoError<UpdateCostCategoryDefinitionError>,
+    > {
+        let request = self.build_update_cost_category_definition_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListCostCategoryDefinitionsResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<UpdateCostCategoryDefinitionResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ListCostCategoryDefinitionsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(UpdateCostCategoryDefinitionError::from_response(response))
         }
     }
 
-    /// <p><important> <p> <i> <b>Cost Category is in public beta for AWS Billing and Cost Management and is subject to change. Your use of Cost Categories is subject to the Beta Service Participation terms of the <a href="https://aws.amazon.com/service-terms/">AWS Service Terms</a> (Section 1.10).</b> </i> </p> </important> <p>Updates an existing Cost Category. Changes made to the Cost Category rules will be used to categorize the current month’s expenses and future expenses. This won’t change categorization for the previous months.</p></p>
-    async fn update_cost_category_definition(
+    /// Presigns the request `update_cost_category_definition` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_cost_category_definition(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UpdateCostCategoryDefinitionRequest,
-    ) -> Result<UpdateCostCategoryDefinitionResponse, RusotoError<UpdateCostCategoryDefinitionError>>
-    {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_cost_category_definition_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_cost_category_definition_request(
+        &self,
+        input: UpdateCostCategoryDefinitionRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "ce", &self.region, "/");
+        request.set_operation("UpdateCostCategoryDefinition");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -3759,19 +4534,355 @@ impl CostExplorer for CostExplorerClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
-        let mut response = self
-            .client
-            .sign_and_dispatch(request)
+        request
+    }
+
+    /// The pages of `GetReservationPurchaseRecommendation` results for `input`, requesting each next page with the `NextPageToken` of the last one as its `NextPageToken`.
+    /// The page size is the `PageSize` of `input`.
+    pub fn get_reservation_purchase_recommendation_pages(
+        &self,
+        input: GetReservationPurchaseRecommendationRequest,
+    ) -> impl futures::Stream<
+        Item = Result<
+            GetReservationPurchaseRecommendationResponse,
+            RusotoError<GetReservationPurchaseRecommendationError>,
+        >,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: CostExplorerClient, input| async move {
+                client.get_reservation_purchase_recommendation(input).await
+            },
+            |input: &GetReservationPurchaseRecommendationRequest,
+             output: &GetReservationPurchaseRecommendationResponse| {
+                let next_page_token = output.next_page_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_page_token.is_none() || next_page_token == input.next_page_token {
+                    return None;
+                }
+                Some(GetReservationPurchaseRecommendationRequest {
+                    next_page_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `Recommendations` of every page of `GetReservationPurchaseRecommendation` results for `input`, as `get_reservation_purchase_recommendation_pages` requests them.
+    pub fn get_reservation_purchase_recommendation_stream(
+        &self,
+        input: GetReservationPurchaseRecommendationRequest,
+    ) -> impl futures::Stream<
+        Item = Result<
+            ReservationPurchaseRecommendation,
+            RusotoError<GetReservationPurchaseRecommendationError>,
+        >,
+    > + Send {
+        rusoto_core::pagination::items(
+            self.get_reservation_purchase_recommendation_pages(input),
+            |page| page.recommendations.unwrap_or_default(),
+        )
+    }
+
+    /// The pages of `GetRightsizingRecommendation` results for `input`, requesting each next page with the `NextPageToken` of the last one as its `NextPageToken`.
+    /// The page size is the `PageSize` of `input`.
+    pub fn get_rightsizing_recommendation_pages(
+        &self,
+        input: GetRightsizingRecommendationRequest,
+    ) -> impl futures::Stream<
+        Item = Result<
+            GetRightsizingRecommendationResponse,
+            RusotoError<GetRightsizingRecommendationError>,
+        >,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: CostExplorerClient, input| async move {
+                client.get_rightsizing_recommendation(input).await
+            },
+            |input: &GetRightsizingRecommendationRequest,
+             output: &GetRightsizingRecommendationResponse| {
+                let next_page_token = output.next_page_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_page_token.is_none() || next_page_token == input.next_page_token {
+                    return None;
+                }
+                Some(GetRightsizingRecommendationRequest {
+                    next_page_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `RightsizingRecommendations` of every page of `GetRightsizingRecommendation` results for `input`, as `get_rightsizing_recommendation_pages` requests them.
+    pub fn get_rightsizing_recommendation_stream(
+        &self,
+        input: GetRightsizingRecommendationRequest,
+    ) -> impl futures::Stream<
+        Item = Result<RightsizingRecommendation, RusotoError<GetRightsizingRecommendationError>>,
+    > + Send {
+        rusoto_core::pagination::items(self.get_rightsizing_recommendation_pages(input), |page| {
+            page.rightsizing_recommendations.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `ListCostCategoryDefinitions` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `MaxResults` of `input`.
+    pub fn list_cost_category_definitions_pages(
+        &self,
+        input: ListCostCategoryDefinitionsRequest,
+    ) -> impl futures::Stream<
+        Item = Result<
+            ListCostCategoryDefinitionsResponse,
+            RusotoError<ListCostCategoryDefinitionsError>,
+        >,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: CostExplorerClient, input| async move {
+                client.list_cost_category_definitions(input).await
+            },
+            |input: &ListCostCategoryDefinitionsRequest,
+             output: &ListCostCategoryDefinitionsResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListCostCategoryDefinitionsRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `CostCategoryReferences` of every page of `ListCostCategoryDefinitions` results for `input`, as `list_cost_category_definitions_pages` requests them.
+    pub fn list_cost_category_definitions_stream(
+        &self,
+        input: ListCostCategoryDefinitionsRequest,
+    ) -> impl futures::Stream<
+        Item = Result<CostCategoryReference, RusotoError<ListCostCategoryDefinitionsError>>,
+    > + Send {
+        rusoto_core::pagination::items(self.list_cost_category_definitions_pages(input), |page| {
+            page.cost_category_references.unwrap_or_default()
+        })
+    }
+}
+
+#[async_trait]
+impl CostExplorer for CostExplorerClient {
+    /// <p><important> <p> <i> <b>Cost Category is in public beta for AWS Billing and Cost Management and is subject to change. Your use of Cost Categories is subject to the Beta Service Participation terms of the <a href="https://aws.amazon.com/service-terms/">AWS Service Terms</a> (Section 1.10).</b> </i> </p> </important> <p>Creates a new Cost Category with the requested name and rules.</p></p>
+    async fn create_cost_category_definition(
+        &self,
+        input: CreateCostCategoryDefinitionRequest,
+    ) -> Result<CreateCostCategoryDefinitionResponse, RusotoError<CreateCostCategoryDefinitionError>>
+    {
+        self.create_cost_category_definition_with_metadata(input)
             .await
-            .map_err(RusotoError::from)?;
-        if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<UpdateCostCategoryDefinitionResponse, _>()
-        } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(UpdateCostCategoryDefinitionError::from_response(response))
-        }
+            .map(|(output, _)| output)
+    }
+
+    /// <p><important> <p> <i> <b>Cost Category is in public beta for AWS Billing and Cost Management and is subject to change. Your use of Cost Categories is subject to the Beta Service Participation terms of the <a href="https://aws.amazon.com/service-terms/">AWS Service Terms</a> (Section 1.10).</b> </i> </p> </important> <p>Deletes a Cost Category. Expenses from this month going forward will no longer be categorized with this Cost Category.</p></p>
+    async fn delete_cost_category_definition(
+        &self,
+        input: DeleteCostCategoryDefinitionRequest,
+    ) -> Result<DeleteCostCategoryDefinitionResponse, RusotoError<DeleteCostCategoryDefinitionError>>
+    {
+        self.delete_cost_category_definition_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><important> <p> <i> <b>Cost Category is in public beta for AWS Billing and Cost Management and is subject to change. Your use of Cost Categories is subject to the Beta Service Participation terms of the <a href="https://aws.amazon.com/service-terms/">AWS Service Terms</a> (Section 1.10).</b> </i> </p> </important> <p>Returns the name, ARN, rules, definition, and effective dates of a Cost Category that&#39;s defined in the account.</p> <p>You have the option to use <code>EffectiveOn</code> to return a Cost Category that is active on a specific date. If there is no <code>EffectiveOn</code> specified, you’ll see a Cost Category that is effective on the current date. If Cost Category is still effective, <code>EffectiveEnd</code> is omitted in the response. </p></p>
+    async fn describe_cost_category_definition(
+        &self,
+        input: DescribeCostCategoryDefinitionRequest,
+    ) -> Result<
+        DescribeCostCategoryDefinitionResponse,
+        RusotoError<DescribeCostCategoryDefinitionError>,
+    > {
+        self.describe_cost_category_definition_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Retrieves cost and usage metrics for your account. You can specify which cost and usage-related metric, such as <code>BlendedCosts</code> or <code>UsageQuantity</code>, that you want the request to return. You can also filter and group your data by various dimensions, such as <code>SERVICE</code> or <code>AZ</code>, in a specific time range. For a complete list of valid dimensions, see the <a href="http://docs.aws.amazon.com/aws-cost-management/latest/APIReference/API_GetDimensionValues.html">GetDimensionValues</a> operation. Master accounts in an organization in AWS Organizations have access to all member accounts.</p>
+    async fn get_cost_and_usage(
+        &self,
+        input: GetCostAndUsageRequest,
+    ) -> Result<GetCostAndUsageResponse, RusotoError<GetCostAndUsageError>> {
+        self.get_cost_and_usage_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Retrieves cost and usage metrics with resources for your account. You can specify which cost and usage-related metric, such as <code>BlendedCosts</code> or <code>UsageQuantity</code>, that you want the request to return. You can also filter and group your data by various dimensions, such as <code>SERVICE</code> or <code>AZ</code>, in a specific time range. For a complete list of valid dimensions, see the <a href="http://docs.aws.amazon.com/aws-cost-management/latest/APIReference/API_GetDimensionValues.html">GetDimensionValues</a> operation. Master accounts in an organization in AWS Organizations have access to all member accounts. This API is currently available for the Amazon Elastic Compute Cloud – Compute service only.</p> <note> <p>This is an opt-in only feature. You can enable this feature from the Cost Explorer Settings page. For information on how to access the Settings page, see <a href="https://docs.aws.amazon.com/awsaccountbilling/latest/aboutv2/ce-access.html">Controlling Access for Cost Explorer</a> in the <i>AWS Billing and Cost Management User Guide</i>.</p> </note></p>
+    async fn get_cost_and_usage_with_resources(
+        &self,
+        input: GetCostAndUsageWithResourcesRequest,
+    ) -> Result<GetCostAndUsageWithResourcesResponse, RusotoError<GetCostAndUsageWithResourcesError>>
+    {
+        self.get_cost_and_usage_with_resources_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Retrieves a forecast for how much Amazon Web Services predicts that you will spend over the forecast time period that you select, based on your past costs. </p>
+    async fn get_cost_forecast(
+        &self,
+        input: GetCostForecastRequest,
+    ) -> Result<GetCostForecastResponse, RusotoError<GetCostForecastError>> {
+        self.get_cost_forecast_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Retrieves all available filter values for a specified filter over a period of time. You can search the dimension values for an arbitrary string. </p>
+    async fn get_dimension_values(
+        &self,
+        input: GetDimensionValuesRequest,
+    ) -> Result<GetDimensionValuesResponse, RusotoError<GetDimensionValuesError>> {
+        self.get_dimension_values_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Retrieves the reservation coverage for your account. This enables you to see how much of your Amazon Elastic Compute Cloud, Amazon ElastiCache, Amazon Relational Database Service, or Amazon Redshift usage is covered by a reservation. An organization's master account can see the coverage of the associated member accounts. For any time period, you can filter data about reservation usage by the following dimensions:</p> <ul> <li> <p>AZ</p> </li> <li> <p>CACHE_ENGINE</p> </li> <li> <p>DATABASE_ENGINE</p> </li> <li> <p>DEPLOYMENT_OPTION</p> </li> <li> <p>INSTANCE_TYPE</p> </li> <li> <p>LINKED_ACCOUNT</p> </li> <li> <p>OPERATING_SYSTEM</p> </li> <li> <p>PLATFORM</p> </li> <li> <p>REGION</p> </li> <li> <p>SERVICE</p> </li> <li> <p>TAG</p> </li> <li> <p>TENANCY</p> </li> </ul> <p>To determine valid values for a dimension, use the <code>GetDimensionValues</code> operation. </p>
+    async fn get_reservation_coverage(
+        &self,
+        input: GetReservationCoverageRequest,
+    ) -> Result<GetReservationCoverageResponse, RusotoError<GetReservationCoverageError>> {
+        self.get_reservation_coverage_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Gets recommendations for which reservations to purchase. These recommendations could help you reduce your costs. Reservations provide a discounted hourly rate (up to 75%) compared to On-Demand pricing.</p> <p>AWS generates your recommendations by identifying your On-Demand usage during a specific time period and collecting your usage into categories that are eligible for a reservation. After AWS has these categories, it simulates every combination of reservations in each category of usage to identify the best number of each type of RI to purchase to maximize your estimated savings. </p> <p>For example, AWS automatically aggregates your Amazon EC2 Linux, shared tenancy, and c4 family usage in the US West (Oregon) Region and recommends that you buy size-flexible regional reservations to apply to the c4 family usage. AWS recommends the smallest size instance in an instance family. This makes it easier to purchase a size-flexible RI. AWS also shows the equal number of normalized units so that you can purchase any instance size that you want. For this example, your RI recommendation would be for <code>c4.large</code> because that is the smallest size instance in the c4 instance family.</p>
+    async fn get_reservation_purchase_recommendation(
+        &self,
+        input: GetReservationPurchaseRecommendationRequest,
+    ) -> Result<
+        GetReservationPurchaseRecommendationResponse,
+        RusotoError<GetReservationPurchaseRecommendationError>,
+    > {
+        self.get_reservation_purchase_recommendation_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Retrieves the reservation utilization for your account. Master accounts in an organization have access to member accounts. You can filter data by dimensions in a time period. You can use <code>GetDimensionValues</code> to determine the possible dimension values. Currently, you can group only by <code>SUBSCRIPTION_ID</code>. </p>
+    async fn get_reservation_utilization(
+        &self,
+        input: GetReservationUtilizationRequest,
+    ) -> Result<GetReservationUtilizationResponse, RusotoError<GetReservationUtilizationError>>
+    {
+        self.get_reservation_utilization_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Creates recommendations that helps you save cost by identifying idle and underutilized Amazon EC2 instances.</p> <p>Recommendations are generated to either downsize or terminate instances, along with providing savings detail and metrics. For details on calculation and function, see <a href="https://docs.aws.amazon.com/awsaccountbilling/latest/aboutv2/ce-what-is.html">Optimizing Your Cost with Rightsizing Recommendations</a>.</p>
+    async fn get_rightsizing_recommendation(
+        &self,
+        input: GetRightsizingRecommendationRequest,
+    ) -> Result<GetRightsizingRecommendationResponse, RusotoError<GetRightsizingRecommendationError>>
+    {
+        self.get_rightsizing_recommendation_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Retrieves the Savings Plans covered for your account. This enables you to see how much of your cost is covered by a Savings Plan. An organization’s master account can see the coverage of the associated member accounts. For any time period, you can filter data for Savings Plans usage with the following dimensions:</p> <ul> <li> <p> <code>LINKED_ACCOUNT</code> </p> </li> <li> <p> <code>REGION</code> </p> </li> <li> <p> <code>SERVICE</code> </p> </li> <li> <p> <code>INSTANCE_FAMILY</code> </p> </li> </ul> <p>To determine valid values for a dimension, use the <code>GetDimensionValues</code> operation.</p>
+    async fn get_savings_plans_coverage(
+        &self,
+        input: GetSavingsPlansCoverageRequest,
+    ) -> Result<GetSavingsPlansCoverageResponse, RusotoError<GetSavingsPlansCoverageError>> {
+        self.get_savings_plans_coverage_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Retrieves your request parameters, Savings Plan Recommendations Summary and Details.</p>
+    async fn get_savings_plans_purchase_recommendation(
+        &self,
+        input: GetSavingsPlansPurchaseRecommendationRequest,
+    ) -> Result<
+        GetSavingsPlansPurchaseRecommendationResponse,
+        RusotoError<GetSavingsPlansPurchaseRecommendationError>,
+    > {
+        self.get_savings_plans_purchase_recommendation_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Retrieves the Savings Plans utilization for your account across date ranges with daily or monthly granularity. Master accounts in an organization have access to member accounts. You can use <code>GetDimensionValues</code> in <code>SAVINGS_PLANS</code> to determine the possible dimension values.</p> <note> <p>You cannot group by any dimension values for <code>GetSavingsPlansUtilization</code>.</p> </note></p>
+    async fn get_savings_plans_utilization(
+        &self,
+        input: GetSavingsPlansUtilizationRequest,
+    ) -> Result<GetSavingsPlansUtilizationResponse, RusotoError<GetSavingsPlansUtilizationError>>
+    {
+        self.get_savings_plans_utilization_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Retrieves attribute data along with aggregate utilization and savings data for a given time period. This doesn&#39;t support granular or grouped data (daily/monthly) in response. You can&#39;t retrieve data by dates in a single response similar to <code>GetSavingsPlanUtilization</code>, but you have the option to make multiple calls to <code>GetSavingsPlanUtilizationDetails</code> by providing individual dates. You can use <code>GetDimensionValues</code> in <code>SAVINGS_PLANS</code> to determine the possible dimension values.</p> <note> <p> <code>GetSavingsPlanUtilizationDetails</code> internally groups data by <code>SavingsPlansArn</code>.</p> </note></p>
+    async fn get_savings_plans_utilization_details(
+        &self,
+        input: GetSavingsPlansUtilizationDetailsRequest,
+    ) -> Result<
+        GetSavingsPlansUtilizationDetailsResponse,
+        RusotoError<GetSavingsPlansUtilizationDetailsError>,
+    > {
+        self.get_savings_plans_utilization_details_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Queries for available tag keys and tag values for a specified period. You can search the tag values for an arbitrary string. </p>
+    async fn get_tags(
+        &self,
+        input: GetTagsRequest,
+    ) -> Result<GetTagsResponse, RusotoError<GetTagsError>> {
+        self.get_tags_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Retrieves a forecast for how much Amazon Web Services predicts that you will use over the forecast time period that you select, based on your past usage. </p>
+    async fn get_usage_forecast(
+        &self,
+        input: GetUsageForecastRequest,
+    ) -> Result<GetUsageForecastResponse, RusotoError<GetUsageForecastError>> {
+        self.get_usage_forecast_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><important> <p> <i> <b>Cost Category is in public beta for AWS Billing and Cost Management and is subject to change. Your use of Cost Categories is subject to the Beta Service Participation terms of the <a href="https://aws.amazon.com/service-terms/">AWS Service Terms</a> (Section 1.10).</b> </i> </p> </important> <p>Returns the name, ARN and effective dates of all Cost Categories defined in the account. You have the option to use <code>EffectiveOn</code> to return a list of Cost Categories that were active on a specific date. If there is no <code>EffectiveOn</code> specified, you’ll see Cost Categories that are effective on the current date. If Cost Category is still effective, <code>EffectiveEnd</code> is omitted in the response. </p></p>
+    async fn list_cost_category_definitions(
+        &self,
+        input: ListCostCategoryDefinitionsRequest,
+    ) -> Result<ListCostCategoryDefinitionsResponse, RusotoError<ListCostCategoryDefinitionsError>>
+    {
+        self.list_cost_category_definitions_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><important> <p> <i> <b>Cost Category is in public beta for AWS Billing and Cost Management and is subject to change. Your use of Cost Categories is subject to the Beta Service Participation terms of the <a href="https://aws.amazon.com/service-terms/">AWS Service Terms</a> (Section 1.10).</b> </i> </p> </important> <p>Updates an existing Cost Category. Changes made to the Cost Category rules will be used to categorize the current month’s expenses and future expenses. This won’t change categorization for the previous months.</p></p>
+    async fn update_cost_category_definition(
+        &self,
+        input: UpdateCostCategoryDefinitionRequest,
+    ) -> Result<UpdateCostCategoryDefinitionResponse, RusotoError<UpdateCostCategoryDefinitionError>>
+    {
+        self.update_cost_category_definition_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
     }
 }
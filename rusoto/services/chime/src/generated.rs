@@ -16,8 +16,8 @@ use std::fmt;
 use async_trait::async_trait;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
-use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest, ResponseMetadata};
+use rusoto_core::{Client, ErrorMetadata, RusotoError};
 
 use rusoto_core::param::{Params, ServiceParams};
 use rusoto_core::proto;
@@ -2766,6 +2766,7 @@ pub struct VoiceConnectorSettings {
 
 /// Errors returned by AssociatePhoneNumberWithUser
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum AssociatePhoneNumberWithUserError {
     /// <p>You don't have permissions to perform the requested operation.</p>
     AccessDenied(String),
@@ -2783,6 +2784,8 @@ pub enum AssociatePhoneNumberWithUserError {
     ThrottledClient(String),
     /// <p>The client is not currently authorized to make the request.</p>
     UnauthorizedClient(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl AssociatePhoneNumberWithUserError {
@@ -2792,47 +2795,60 @@ impl AssociatePhoneNumberWithUserError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(AssociatePhoneNumberWithUserError::AccessDenied(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        AssociatePhoneNumberWithUserError::AccessDenied(err.msg),
+                        &res,
+                    )
                 }
                 "BadRequestException" => {
-                    return RusotoError::Service(AssociatePhoneNumberWithUserError::BadRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        AssociatePhoneNumberWithUserError::BadRequest(err.msg),
+                        &res,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(AssociatePhoneNumberWithUserError::Forbidden(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        AssociatePhoneNumberWithUserError::Forbidden(err.msg),
+                        &res,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(AssociatePhoneNumberWithUserError::NotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        AssociatePhoneNumberWithUserError::NotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(AssociatePhoneNumberWithUserError::ServiceFailure(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        AssociatePhoneNumberWithUserError::ServiceFailure(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         AssociatePhoneNumberWithUserError::ServiceUnavailable(err.msg),
+                        &res,
                     )
                 }
                 "ThrottledClientException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         AssociatePhoneNumberWithUserError::ThrottledClient(err.msg),
+                        &res,
                     )
                 }
                 "UnauthorizedClientException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         AssociatePhoneNumberWithUserError::UnauthorizedClient(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        AssociatePhoneNumberWithUserError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -2854,12 +2870,14 @@ impl fmt::Display for AssociatePhoneNumberWithUserError {
             AssociatePhoneNumberWithUserError::UnauthorizedClient(ref cause) => {
                 write!(f, "{}", cause)
             }
+            AssociatePhoneNumberWithUserError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for AssociatePhoneNumberWithUserError {}
 /// Errors returned by AssociatePhoneNumbersWithVoiceConnector
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum AssociatePhoneNumbersWithVoiceConnectorError {
     /// <p>You don't have permissions to perform the requested operation.</p>
     AccessDenied(String),
@@ -2877,6 +2895,8 @@ pub enum AssociatePhoneNumbersWithVoiceConnectorError {
     ThrottledClient(String),
     /// <p>The client is not currently authorized to make the request.</p>
     UnauthorizedClient(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl AssociatePhoneNumbersWithVoiceConnectorError {
@@ -2886,47 +2906,60 @@ impl AssociatePhoneNumbersWithVoiceConnectorError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         AssociatePhoneNumbersWithVoiceConnectorError::AccessDenied(err.msg),
+                        &res,
                     )
                 }
                 "BadRequestException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         AssociatePhoneNumbersWithVoiceConnectorError::BadRequest(err.msg),
+                        &res,
                     )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         AssociatePhoneNumbersWithVoiceConnectorError::Forbidden(err.msg),
+                        &res,
                     )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         AssociatePhoneNumbersWithVoiceConnectorError::NotFound(err.msg),
+                        &res,
                     )
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         AssociatePhoneNumbersWithVoiceConnectorError::ServiceFailure(err.msg),
+                        &res,
                     )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         AssociatePhoneNumbersWithVoiceConnectorError::ServiceUnavailable(err.msg),
+                        &res,
                     )
                 }
                 "ThrottledClientException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         AssociatePhoneNumbersWithVoiceConnectorError::ThrottledClient(err.msg),
+                        &res,
                     )
                 }
                 "UnauthorizedClientException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         AssociatePhoneNumbersWithVoiceConnectorError::UnauthorizedClient(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        AssociatePhoneNumbersWithVoiceConnectorError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -2960,12 +2993,16 @@ impl fmt::Display for AssociatePhoneNumbersWithVoiceConnectorError {
             AssociatePhoneNumbersWithVoiceConnectorError::UnauthorizedClient(ref cause) => {
                 write!(f, "{}", cause)
             }
+            AssociatePhoneNumbersWithVoiceConnectorError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for AssociatePhoneNumbersWithVoiceConnectorError {}
 /// Errors returned by AssociatePhoneNumbersWithVoiceConnectorGroup
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum AssociatePhoneNumbersWithVoiceConnectorGroupError {
     /// <p>You don't have permissions to perform the requested operation.</p>
     AccessDenied(String),
@@ -2983,6 +3020,8 @@ pub enum AssociatePhoneNumbersWithVoiceConnectorGroupError {
     ThrottledClient(String),
     /// <p>The client is not currently authorized to make the request.</p>
     UnauthorizedClient(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl AssociatePhoneNumbersWithVoiceConnectorGroupError {
@@ -2992,51 +3031,64 @@ impl AssociatePhoneNumbersWithVoiceConnectorGroupError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         AssociatePhoneNumbersWithVoiceConnectorGroupError::AccessDenied(err.msg),
+                        &res,
                     )
                 }
                 "BadRequestException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         AssociatePhoneNumbersWithVoiceConnectorGroupError::BadRequest(err.msg),
+                        &res,
                     )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         AssociatePhoneNumbersWithVoiceConnectorGroupError::Forbidden(err.msg),
+                        &res,
                     )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         AssociatePhoneNumbersWithVoiceConnectorGroupError::NotFound(err.msg),
+                        &res,
                     )
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         AssociatePhoneNumbersWithVoiceConnectorGroupError::ServiceFailure(err.msg),
+                        &res,
                     )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         AssociatePhoneNumbersWithVoiceConnectorGroupError::ServiceUnavailable(
                             err.msg,
                         ),
+                        &res,
                     )
                 }
                 "ThrottledClientException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         AssociatePhoneNumbersWithVoiceConnectorGroupError::ThrottledClient(err.msg),
+                        &res,
                     )
                 }
                 "UnauthorizedClientException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         AssociatePhoneNumbersWithVoiceConnectorGroupError::UnauthorizedClient(
                             err.msg,
                         ),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        AssociatePhoneNumbersWithVoiceConnectorGroupError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -3070,12 +3122,16 @@ impl fmt::Display for AssociatePhoneNumbersWithVoiceConnectorGroupError {
             AssociatePhoneNumbersWithVoiceConnectorGroupError::UnauthorizedClient(ref cause) => {
                 write!(f, "{}", cause)
             }
+            AssociatePhoneNumbersWithVoiceConnectorGroupError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for AssociatePhoneNumbersWithVoiceConnectorGroupError {}
 /// Errors returned by AssociateSigninDelegateGroupsWithAccount
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum AssociateSigninDelegateGroupsWithAccountError {
     /// <p>The input parameters don't match the service's restrictions.</p>
     BadRequest(String),
@@ -3091,6 +3147,8 @@ pub enum AssociateSigninDelegateGroupsWithAccountError {
     ThrottledClient(String),
     /// <p>The client is not currently authorized to make the request.</p>
     UnauthorizedClient(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl AssociateSigninDelegateGroupsWithAccountError {
@@ -3100,42 +3158,54 @@ impl AssociateSigninDelegateGroupsWithAccountError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         AssociateSigninDelegateGroupsWithAccountError::BadRequest(err.msg),
+                        &res,
                     )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         AssociateSigninDelegateGroupsWithAccountError::Forbidden(err.msg),
+                        &res,
                     )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         AssociateSigninDelegateGroupsWithAccountError::NotFound(err.msg),
+                        &res,
                     )
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         AssociateSigninDelegateGroupsWithAccountError::ServiceFailure(err.msg),
+                        &res,
                     )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         AssociateSigninDelegateGroupsWithAccountError::ServiceUnavailable(err.msg),
+                        &res,
                     )
                 }
                 "ThrottledClientException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         AssociateSigninDelegateGroupsWithAccountError::ThrottledClient(err.msg),
+                        &res,
                     )
                 }
                 "UnauthorizedClientException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         AssociateSigninDelegateGroupsWithAccountError::UnauthorizedClient(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        AssociateSigninDelegateGroupsWithAccountError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -3166,12 +3236,16 @@ impl fmt::Display for AssociateSigninDelegateGroupsWithAccountError {
             AssociateSigninDelegateGroupsWithAccountError::UnauthorizedClient(ref cause) => {
                 write!(f, "{}", cause)
             }
+            AssociateSigninDelegateGroupsWithAccountError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for AssociateSigninDelegateGroupsWithAccountError {}
 /// Errors returned by BatchCreateAttendee
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum BatchCreateAttendeeError {
     /// <p>The input parameters don't match the service's restrictions.</p>
     BadRequest(String),
@@ -3189,6 +3263,8 @@ pub enum BatchCreateAttendeeError {
     ThrottledClient(String),
     /// <p>The client is not currently authorized to make the request.</p>
     UnauthorizedClient(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl BatchCreateAttendeeError {
@@ -3196,37 +3272,49 @@ impl BatchCreateAttendeeError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(BatchCreateAttendeeError::BadRequest(err.msg))
+                    return RusotoError::service(
+                        BatchCreateAttendeeError::BadRequest(err.msg),
+                        &res,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(BatchCreateAttendeeError::Forbidden(err.msg))
+                    return RusotoError::service(BatchCreateAttendeeError::Forbidden(err.msg), &res)
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(BatchCreateAttendeeError::NotFound(err.msg))
+                    return RusotoError::service(BatchCreateAttendeeError::NotFound(err.msg), &res)
                 }
                 "ResourceLimitExceededException" => {
-                    return RusotoError::Service(BatchCreateAttendeeError::ResourceLimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        BatchCreateAttendeeError::ResourceLimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(BatchCreateAttendeeError::ServiceFailure(err.msg))
+                    return RusotoError::service(
+                        BatchCreateAttendeeError::ServiceFailure(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(BatchCreateAttendeeError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        BatchCreateAttendeeError::ServiceUnavailable(err.msg),
+                        &res,
+                    )
                 }
                 "ThrottledClientException" => {
-                    return RusotoError::Service(BatchCreateAttendeeError::ThrottledClient(err.msg))
+                    return RusotoError::service(
+                        BatchCreateAttendeeError::ThrottledClient(err.msg),
+                        &res,
+                    )
                 }
                 "UnauthorizedClientException" => {
-                    return RusotoError::Service(BatchCreateAttendeeError::UnauthorizedClient(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        BatchCreateAttendeeError::UnauthorizedClient(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(BatchCreateAttendeeError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3244,12 +3332,14 @@ impl fmt::Display for BatchCreateAttendeeError {
             BatchCreateAttendeeError::ServiceUnavailable(ref cause) => write!(f, "{}", cause),
             BatchCreateAttendeeError::ThrottledClient(ref cause) => write!(f, "{}", cause),
             BatchCreateAttendeeError::UnauthorizedClient(ref cause) => write!(f, "{}", cause),
+            BatchCreateAttendeeError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for BatchCreateAttendeeError {}
 /// Errors returned by BatchCreateRoomMembership
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum BatchCreateRoomMembershipError {
     /// <p>The input parameters don't match the service's restrictions.</p>
     BadRequest(String),
@@ -3265,6 +3355,8 @@ pub enum BatchCreateRoomMembershipError {
     ThrottledClient(String),
     /// <p>The client is not currently authorized to make the request.</p>
     UnauthorizedClient(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl BatchCreateRoomMembershipError {
@@ -3272,38 +3364,51 @@ impl BatchCreateRoomMembershipError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(BatchCreateRoomMembershipError::BadRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        BatchCreateRoomMembershipError::BadRequest(err.msg),
+                        &res,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(BatchCreateRoomMembershipError::Forbidden(err.msg))
+                    return RusotoError::service(
+                        BatchCreateRoomMembershipError::Forbidden(err.msg),
+                        &res,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(BatchCreateRoomMembershipError::NotFound(err.msg))
+                    return RusotoError::service(
+                        BatchCreateRoomMembershipError::NotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(BatchCreateRoomMembershipError::ServiceFailure(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        BatchCreateRoomMembershipError::ServiceFailure(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         BatchCreateRoomMembershipError::ServiceUnavailable(err.msg),
+                        &res,
                     )
                 }
                 "ThrottledClientException" => {
-                    return RusotoError::Service(BatchCreateRoomMembershipError::ThrottledClient(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        BatchCreateRoomMembershipError::ThrottledClient(err.msg),
+                        &res,
+                    )
                 }
                 "UnauthorizedClientException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         BatchCreateRoomMembershipError::UnauthorizedClient(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(BatchCreateRoomMembershipError::Unhandled, &res)
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -3320,12 +3425,14 @@ impl fmt::Display for BatchCreateRoomMembershipError {
             BatchCreateRoomMembershipError::ServiceUnavailable(ref cause) => write!(f, "{}", cause),
             BatchCreateRoomMembershipError::ThrottledClient(ref cause) => write!(f, "{}", cause),
             BatchCreateRoomMembershipError::UnauthorizedClient(ref cause) => write!(f, "{}", cause),
+            BatchCreateRoomMembershipError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for BatchCreateRoomMembershipError {}
 /// Errors returned by BatchDeletePhoneNumber
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum BatchDeletePhoneNumberError {
     /// <p>The input parameters don't match the service's restrictions.</p>
     BadRequest(String),
@@ -3341,6 +3448,8 @@ pub enum BatchDeletePhoneNumberError {
     ThrottledClient(String),
     /// <p>The client is not currently authorized to make the request.</p>
     UnauthorizedClient(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl BatchDeletePhoneNumberError {
@@ -3348,36 +3457,49 @@ impl BatchDeletePhoneNumberError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(BatchDeletePhoneNumberError::BadRequest(err.msg))
+                    return RusotoError::service(
+                        BatchDeletePhoneNumberError::BadRequest(err.msg),
+                        &res,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(BatchDeletePhoneNumberError::Forbidden(err.msg))
+                    return RusotoError::service(
+                        BatchDeletePhoneNumberError::Forbidden(err.msg),
+                        &res,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(BatchDeletePhoneNumberError::NotFound(err.msg))
+                    return RusotoError::service(
+                        BatchDeletePhoneNumberError::NotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(BatchDeletePhoneNumberError::ServiceFailure(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        BatchDeletePhoneNumberError::ServiceFailure(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(BatchDeletePhoneNumberError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        BatchDeletePhoneNumberError::ServiceUnavailable(err.msg),
+                        &res,
+                    )
                 }
                 "ThrottledClientException" => {
-                    return RusotoError::Service(BatchDeletePhoneNumberError::ThrottledClient(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        BatchDeletePhoneNumberError::ThrottledClient(err.msg),
+                        &res,
+                    )
                 }
                 "UnauthorizedClientException" => {
-                    return RusotoError::Service(BatchDeletePhoneNumberError::UnauthorizedClient(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        BatchDeletePhoneNumberError::UnauthorizedClient(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(BatchDeletePhoneNumberError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3394,12 +3516,14 @@ impl fmt::Display for BatchDeletePhoneNumberError {
             BatchDeletePhoneNumberError::ServiceUnavailable(ref cause) => write!(f, "{}", cause),
             BatchDeletePhoneNumberError::ThrottledClient(ref cause) => write!(f, "{}", cause),
             BatchDeletePhoneNumberError::UnauthorizedClient(ref cause) => write!(f, "{}", cause),
+            BatchDeletePhoneNumberError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for BatchDeletePhoneNumberError {}
 /// Errors returned by BatchSuspendUser
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum BatchSuspendUserError {
     /// <p>The input parameters don't match the service's restrictions.</p>
     BadRequest(String),
@@ -3415,6 +3539,8 @@ pub enum BatchSuspendUserError {
     ThrottledClient(String),
     /// <p>The client is not currently authorized to make the request.</p>
     UnauthorizedClient(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl BatchSuspendUserError {
@@ -3422,28 +3548,40 @@ impl BatchSuspendUserError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(BatchSuspendUserError::BadRequest(err.msg))
+                    return RusotoError::service(BatchSuspendUserError::BadRequest(err.msg), &res)
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(BatchSuspendUserError::Forbidden(err.msg))
+                    return RusotoError::service(BatchSuspendUserError::Forbidden(err.msg), &res)
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(BatchSuspendUserError::NotFound(err.msg))
+                    return RusotoError::service(BatchSuspendUserError::NotFound(err.msg), &res)
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(BatchSuspendUserError::ServiceFailure(err.msg))
+                    return RusotoError::service(
+                        BatchSuspendUserError::ServiceFailure(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(BatchSuspendUserError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        BatchSuspendUserError::ServiceUnavailable(err.msg),
+                        &res,
+                    )
                 }
                 "ThrottledClientException" => {
-                    return RusotoError::Service(BatchSuspendUserError::ThrottledClient(err.msg))
+                    return RusotoError::service(
+                        BatchSuspendUserError::ThrottledClient(err.msg),
+                        &res,
+                    )
                 }
                 "UnauthorizedClientException" => {
-                    return RusotoError::Service(BatchSuspendUserError::UnauthorizedClient(err.msg))
+                    return RusotoError::service(
+                        BatchSuspendUserError::UnauthorizedClient(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(BatchSuspendUserError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3460,12 +3598,14 @@ impl fmt::Display for BatchSuspendUserError {
             BatchSuspendUserError::ServiceUnavailable(ref cause) => write!(f, "{}", cause),
             BatchSuspendUserError::ThrottledClient(ref cause) => write!(f, "{}", cause),
             BatchSuspendUserError::UnauthorizedClient(ref cause) => write!(f, "{}", cause),
+            BatchSuspendUserError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for BatchSuspendUserError {}
 /// Errors returned by BatchUnsuspendUser
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum BatchUnsuspendUserError {
     /// <p>The input parameters don't match the service's restrictions.</p>
     BadRequest(String),
@@ -3481,6 +3621,8 @@ pub enum BatchUnsuspendUserError {
     ThrottledClient(String),
     /// <p>The client is not currently authorized to make the request.</p>
     UnauthorizedClient(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl BatchUnsuspendUserError {
@@ -3488,32 +3630,40 @@ impl BatchUnsuspendUserError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(BatchUnsuspendUserError::BadRequest(err.msg))
+                    return RusotoError::service(BatchUnsuspendUserError::BadRequest(err.msg), &res)
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(BatchUnsuspendUserError::Forbidden(err.msg))
+                    return RusotoError::service(BatchUnsuspendUserError::Forbidden(err.msg), &res)
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(BatchUnsuspendUserError::NotFound(err.msg))
+                    return RusotoError::service(BatchUnsuspendUserError::NotFound(err.msg), &res)
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(BatchUnsuspendUserError::ServiceFailure(err.msg))
+                    return RusotoError::service(
+                        BatchUnsuspendUserError::ServiceFailure(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(BatchUnsuspendUserError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        BatchUnsuspendUserError::ServiceUnavailable(err.msg),
+                        &res,
+                    )
                 }
                 "ThrottledClientException" => {
-                    return RusotoError::Service(BatchUnsuspendUserError::ThrottledClient(err.msg))
+                    return RusotoError::service(
+                        BatchUnsuspendUserError::ThrottledClient(err.msg),
+                        &res,
+                    )
                 }
                 "UnauthorizedClientException" => {
-                    return RusotoError::Service(BatchUnsuspendUserError::UnauthorizedClient(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        BatchUnsuspendUserError::UnauthorizedClient(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(BatchUnsuspendUserError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3530,12 +3680,14 @@ impl fmt::Display for BatchUnsuspendUserError {
             BatchUnsuspendUserError::ServiceUnavailable(ref cause) => write!(f, "{}", cause),
             BatchUnsuspendUserError::ThrottledClient(ref cause) => write!(f, "{}", cause),
             BatchUnsuspendUserError::UnauthorizedClient(ref cause) => write!(f, "{}", cause),
+            BatchUnsuspendUserError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for BatchUnsuspendUserError {}
 /// Errors returned by BatchUpdatePhoneNumber
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum BatchUpdatePhoneNumberError {
     /// <p>The input parameters don't match the service's restrictions.</p>
     BadRequest(String),
@@ -3551,6 +3703,8 @@ pub enum BatchUpdatePhoneNumberError {
     ThrottledClient(String),
     /// <p>The client is not currently authorized to make the request.</p>
     UnauthorizedClient(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl BatchUpdatePhoneNumberError {
@@ -3558,36 +3712,49 @@ impl BatchUpdatePhoneNumberError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(BatchUpdatePhoneNumberError::BadRequest(err.msg))
+                    return RusotoError::service(
+                        BatchUpdatePhoneNumberError::BadRequest(err.msg),
+                        &res,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(BatchUpdatePhoneNumberError::Forbidden(err.msg))
+                    return RusotoError::service(
+                        BatchUpdatePhoneNumberError::Forbidden(err.msg),
+                        &res,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(BatchUpdatePhoneNumberError::NotFound(err.msg))
+                    return RusotoError::service(
+                        BatchUpdatePhoneNumberError::NotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(BatchUpdatePhoneNumberError::ServiceFailure(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        BatchUpdatePhoneNumberError::ServiceFailure(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(BatchUpdatePhoneNumberError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        BatchUpdatePhoneNumberError::ServiceUnavailable(err.msg),
+                        &res,
+                    )
                 }
                 "ThrottledClientException" => {
-                    return RusotoError::Service(BatchUpdatePhoneNumberError::ThrottledClient(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        BatchUpdatePhoneNumberError::ThrottledClient(err.msg),
+                        &res,
+                    )
                 }
                 "UnauthorizedClientException" => {
-                    return RusotoError::Service(BatchUpdatePhoneNumberError::UnauthorizedClient(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        BatchUpdatePhoneNumberError::UnauthorizedClient(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(BatchUpdatePhoneNumberError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3604,12 +3771,14 @@ impl fmt::Display for BatchUpdatePhoneNumberError {
             BatchUpdatePhoneNumberError::ServiceUnavailable(ref cause) => write!(f, "{}", cause),
             BatchUpdatePhoneNumberError::ThrottledClient(ref cause) => write!(f, "{}", cause),
             BatchUpdatePhoneNumberError::UnauthorizedClient(ref cause) => write!(f, "{}", cause),
+            BatchUpdatePhoneNumberError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for BatchUpdatePhoneNumberError {}
 /// Errors returned by BatchUpdateUser
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum BatchUpdateUserError {
     /// <p>The input parameters don't match the service's restrictions.</p>
     BadRequest(String),
@@ -3625,6 +3794,8 @@ pub enum BatchUpdateUserError {
     ThrottledClient(String),
     /// <p>The client is not currently authorized to make the request.</p>
     UnauthorizedClient(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl BatchUpdateUserError {
@@ -3632,28 +3803,40 @@ impl BatchUpdateUserError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(BatchUpdateUserError::BadRequest(err.msg))
+                    return RusotoError::service(BatchUpdateUserError::BadRequest(err.msg), &res)
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(BatchUpdateUserError::Forbidden(err.msg))
+                    return RusotoError::service(BatchUpdateUserError::Forbidden(err.msg), &res)
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(BatchUpdateUserError::NotFound(err.msg))
+                    return RusotoError::service(BatchUpdateUserError::NotFound(err.msg), &res)
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(BatchUpdateUserError::ServiceFailure(err.msg))
+                    return RusotoError::service(
+                        BatchUpdateUserError::ServiceFailure(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(BatchUpdateUserError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        BatchUpdateUserError::ServiceUnavailable(err.msg),
+                        &res,
+                    )
                 }
                 "ThrottledClientException" => {
-                    return RusotoError::Service(BatchUpdateUserError::ThrottledClient(err.msg))
+                    return RusotoError::service(
+                        BatchUpdateUserError::ThrottledClient(err.msg),
+                        &res,
+                    )
                 }
                 "UnauthorizedClientException" => {
-                    return RusotoError::Service(BatchUpdateUserError::UnauthorizedClient(err.msg))
+                    return RusotoError::service(
+                        BatchUpdateUserError::UnauthorizedClient(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(BatchUpdateUserError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3670,12 +3853,14 @@ impl fmt::Display for BatchUpdateUserError {
             BatchUpdateUserError::ServiceUnavailable(ref cause) => write!(f, "{}", cause),
             BatchUpdateUserError::ThrottledClient(ref cause) => write!(f, "{}", cause),
             BatchUpdateUserError::UnauthorizedClient(ref cause) => write!(f, "{}", cause),
+            BatchUpdateUserError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for BatchUpdateUserError {}
 /// Errors returned by CreateAccount
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateAccountError {
     /// <p>The input parameters don't match the service's restrictions.</p>
     BadRequest(String),
@@ -3691,6 +3876,8 @@ pub enum CreateAccountError {
     ThrottledClient(String),
     /// <p>The client is not currently authorized to make the request.</p>
     UnauthorizedClient(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateAccountError {
@@ -3698,28 +3885,34 @@ impl CreateAccountError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(CreateAccountError::BadRequest(err.msg))
+                    return RusotoError::service(CreateAccountError::BadRequest(err.msg), &res)
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(CreateAccountError::Forbidden(err.msg))
+                    return RusotoError::service(CreateAccountError::Forbidden(err.msg), &res)
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(CreateAccountError::NotFound(err.msg))
+                    return RusotoError::service(CreateAccountError::NotFound(err.msg), &res)
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(CreateAccountError::ServiceFailure(err.msg))
+                    return RusotoError::service(CreateAccountError::ServiceFailure(err.msg), &res)
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(CreateAccountError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        CreateAccountError::ServiceUnavailable(err.msg),
+                        &res,
+                    )
                 }
                 "ThrottledClientException" => {
-                    return RusotoError::Service(CreateAccountError::ThrottledClient(err.msg))
+                    return RusotoError::service(CreateAccountError::ThrottledClient(err.msg), &res)
                 }
                 "UnauthorizedClientException" => {
-                    return RusotoError::Service(CreateAccountError::UnauthorizedClient(err.msg))
+                    return RusotoError::service(
+                        CreateAccountError::UnauthorizedClient(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreateAccountError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3736,12 +3929,14 @@ impl fmt::Display for CreateAccountError {
             CreateAccountError::ServiceUnavailable(ref cause) => write!(f, "{}", cause),
             CreateAccountError::ThrottledClient(ref cause) => write!(f, "{}", cause),
             CreateAccountError::UnauthorizedClient(ref cause) => write!(f, "{}", cause),
+            CreateAccountError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateAccountError {}
 /// Errors returned by CreateAttendee
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateAttendeeError {
     /// <p>The input parameters don't match the service's restrictions.</p>
     BadRequest(String),
@@ -3759,6 +3954,8 @@ pub enum CreateAttendeeError {
     ThrottledClient(String),
     /// <p>The client is not currently authorized to make the request.</p>
     UnauthorizedClient(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateAttendeeError {
@@ -3766,33 +3963,43 @@ impl CreateAttendeeError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(CreateAttendeeError::BadRequest(err.msg))
+                    return RusotoError::service(CreateAttendeeError::BadRequest(err.msg), &res)
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(CreateAttendeeError::Forbidden(err.msg))
+                    return RusotoError::service(CreateAttendeeError::Forbidden(err.msg), &res)
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(CreateAttendeeError::NotFound(err.msg))
+                    return RusotoError::service(CreateAttendeeError::NotFound(err.msg), &res)
                 }
                 "ResourceLimitExceededException" => {
-                    return RusotoError::Service(CreateAttendeeError::ResourceLimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateAttendeeError::ResourceLimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(CreateAttendeeError::ServiceFailure(err.msg))
+                    return RusotoError::service(CreateAttendeeError::ServiceFailure(err.msg), &res)
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(CreateAttendeeError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        CreateAttendeeError::ServiceUnavailable(err.msg),
+                        &res,
+                    )
                 }
                 "ThrottledClientException" => {
-                    return RusotoError::Service(CreateAttendeeError::ThrottledClient(err.msg))
+                    return RusotoError::service(
+                        CreateAttendeeError::ThrottledClient(err.msg),
+                        &res,
+                    )
                 }
                 "UnauthorizedClientException" => {
-                    return RusotoError::Service(CreateAttendeeError::UnauthorizedClient(err.msg))
+                    return RusotoError::service(
+                        CreateAttendeeError::UnauthorizedClient(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreateAttendeeError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3810,12 +4017,14 @@ impl fmt::Display for CreateAttendeeError {
             CreateAttendeeError::ServiceUnavailable(ref cause) => write!(f, "{}", cause),
             CreateAttendeeError::ThrottledClient(ref cause) => write!(f, "{}", cause),
             CreateAttendeeError::UnauthorizedClient(ref cause) => write!(f, "{}", cause),
+            CreateAttendeeError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateAttendeeError {}
 /// Errors returned by CreateBot
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateBotError {
     /// <p>The input parameters don't match the service's restrictions.</p>
     BadRequest(String),
@@ -3833,6 +4042,8 @@ pub enum CreateBotError {
     ThrottledClient(String),
     /// <p>The client is not currently authorized to make the request.</p>
     UnauthorizedClient(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateBotError {
@@ -3840,31 +4051,34 @@ impl CreateBotError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(CreateBotError::BadRequest(err.msg))
+                    return RusotoError::service(CreateBotError::BadRequest(err.msg), &res)
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(CreateBotError::Forbidden(err.msg))
+                    return RusotoError::service(CreateBotError::Forbidden(err.msg), &res)
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(CreateBotError::NotFound(err.msg))
+                    return RusotoError::service(CreateBotError::NotFound(err.msg), &res)
                 }
                 "ResourceLimitExceededException" => {
-                    return RusotoError::Service(CreateBotError::ResourceLimitExceeded(err.msg))
+                    return RusotoError::service(
+                        CreateBotError::ResourceLimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(CreateBotError::ServiceFailure(err.msg))
+                    return RusotoError::service(CreateBotError::ServiceFailure(err.msg), &res)
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(CreateBotError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(CreateBotError::ServiceUnavailable(err.msg), &res)
                 }
                 "ThrottledClientException" => {
-                    return RusotoError::Service(CreateBotError::ThrottledClient(err.msg))
+                    return RusotoError::service(CreateBotError::ThrottledClient(err.msg), &res)
                 }
                 "UnauthorizedClientException" => {
-                    return RusotoError::Service(CreateBotError::UnauthorizedClient(err.msg))
+                    return RusotoError::service(CreateBotError::UnauthorizedClient(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreateBotError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3882,12 +4096,14 @@ impl fmt::Display for CreateBotError {
             CreateBotError::ServiceUnavailable(ref cause) => write!(f, "{}", cause),
             CreateBotError::ThrottledClient(ref cause) => write!(f, "{}", cause),
             CreateBotError::UnauthorizedClient(ref cause) => write!(f, "{}", cause),
+            CreateBotError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateBotError {}
 /// Errors returned by CreateMeeting
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateMeetingError {
     /// <p>The input parameters don't match the service's restrictions.</p>
     BadRequest(String),
@@ -3903,6 +4119,8 @@ pub enum CreateMeetingError {
     ThrottledClient(String),
     /// <p>The client is not currently authorized to make the request.</p>
     UnauthorizedClient(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateMeetingError {
@@ -3910,28 +4128,37 @@ impl CreateMeetingError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(CreateMeetingError::BadRequest(err.msg))
+                    return RusotoError::service(CreateMeetingError::BadRequest(err.msg), &res)
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(CreateMeetingError::Forbidden(err.msg))
+                    return RusotoError::service(CreateMeetingError::Forbidden(err.msg), &res)
                 }
                 "ResourceLimitExceededException" => {
-                    return RusotoError::Service(CreateMeetingError::ResourceLimitExceeded(err.msg))
+                    return RusotoError::service(
+                        CreateMeetingError::ResourceLimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(CreateMeetingError::ServiceFailure(err.msg))
+                    return RusotoError::service(CreateMeetingError::ServiceFailure(err.msg), &res)
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(CreateMeetingError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        CreateMeetingError::ServiceUnavailable(err.msg),
+                        &res,
+                    )
                 }
                 "ThrottledClientException" => {
-                    return RusotoError::Service(CreateMeetingError::ThrottledClient(err.msg))
+                    return RusotoError::service(CreateMeetingError::ThrottledClient(err.msg), &res)
                 }
                 "UnauthorizedClientException" => {
-                    return RusotoError::Service(CreateMeetingError::UnauthorizedClient(err.msg))
+                    return RusotoError::service(
+                        CreateMeetingError::UnauthorizedClient(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreateMeetingError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3948,12 +4175,14 @@ impl fmt::Display for CreateMeetingError {
             CreateMeetingError::ServiceUnavailable(ref cause) => write!(f, "{}", cause),
             CreateMeetingError::ThrottledClient(ref cause) => write!(f, "{}", cause),
             CreateMeetingError::UnauthorizedClient(ref cause) => write!(f, "{}", cause),
+            CreateMeetingError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateMeetingError {}
 /// Errors returned by CreatePhoneNumberOrder
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreatePhoneNumberOrderError {
     /// <p>You don't have permissions to perform the requested operation.</p>
     AccessDenied(String),
@@ -3971,6 +4200,8 @@ pub enum CreatePhoneNumberOrderError {
     ThrottledClient(String),
     /// <p>The client is not currently authorized to make the request.</p>
     UnauthorizedClient(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreatePhoneNumberOrderError {
@@ -3978,41 +4209,55 @@ impl CreatePhoneNumberOrderError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(CreatePhoneNumberOrderError::AccessDenied(err.msg))
+                    return RusotoError::service(
+                        CreatePhoneNumberOrderError::AccessDenied(err.msg),
+                        &res,
+                    )
                 }
                 "BadRequestException" => {
-                    return RusotoError::Service(CreatePhoneNumberOrderError::BadRequest(err.msg))
+                    return RusotoError::service(
+                        CreatePhoneNumberOrderError::BadRequest(err.msg),
+                        &res,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(CreatePhoneNumberOrderError::Forbidden(err.msg))
+                    return RusotoError::service(
+                        CreatePhoneNumberOrderError::Forbidden(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceLimitExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         CreatePhoneNumberOrderError::ResourceLimitExceeded(err.msg),
+                        &res,
                     )
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(CreatePhoneNumberOrderError::ServiceFailure(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreatePhoneNumberOrderError::ServiceFailure(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(CreatePhoneNumberOrderError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreatePhoneNumberOrderError::ServiceUnavailable(err.msg),
+                        &res,
+                    )
                 }
                 "ThrottledClientException" => {
-                    return RusotoError::Service(CreatePhoneNumberOrderError::ThrottledClient(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreatePhoneNumberOrderError::ThrottledClient(err.msg),
+                        &res,
+                    )
                 }
                 "UnauthorizedClientException" => {
-                    return RusotoError::Service(CreatePhoneNumberOrderError::UnauthorizedClient(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreatePhoneNumberOrderError::UnauthorizedClient(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreatePhoneNumberOrderError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -4030,12 +4275,14 @@ impl fmt::Display for CreatePhoneNumberOrderError {
             CreatePhoneNumberOrderError::ServiceUnavailable(ref cause) => write!(f, "{}", cause),
             CreatePhoneNumberOrderError::ThrottledClient(ref cause) => write!(f, "{}", cause),
             CreatePhoneNumberOrderError::UnauthorizedClient(ref cause) => write!(f, "{}", cause),
+            CreatePhoneNumberOrderError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreatePhoneNumberOrderError {}
 /// Errors returned by CreateRoom
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateRoomError {
     /// <p>The input parameters don't match the service's restrictions.</p>
     BadRequest(String),
@@ -4053,6 +4300,8 @@ pub enum CreateRoomError {
     ThrottledClient(String),
     /// <p>The client is not currently authorized to make the request.</p>
     UnauthorizedClient(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateRoomError {
@@ -4060,31 +4309,34 @@ impl CreateRoomError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(CreateRoomError::BadRequest(err.msg))
+                    return RusotoError::service(CreateRoomError::BadRequest(err.msg), &res)
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(CreateRoomError::Forbidden(err.msg))
+                    return RusotoError::service(CreateRoomError::Forbidden(err.msg), &res)
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(CreateRoomError::NotFound(err.msg))
+                    return RusotoError::service(CreateRoomError::NotFound(err.msg), &res)
                 }
                 "ResourceLimitExceededException" => {
-                    return RusotoError::Service(CreateRoomError::ResourceLimitExceeded(err.msg))
+                    return RusotoError::service(
+                        CreateRoomError::ResourceLimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(CreateRoomError::ServiceFailure(err.msg))
+                    return RusotoError::service(CreateRoomError::ServiceFailure(err.msg), &res)
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(CreateRoomError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(CreateRoomError::ServiceUnavailable(err.msg), &res)
                 }
                 "ThrottledClientException" => {
-                    return RusotoError::Service(CreateRoomError::ThrottledClient(err.msg))
+                    return RusotoError::service(CreateRoomError::ThrottledClient(err.msg), &res)
                 }
                 "UnauthorizedClientException" => {
-                    return RusotoError::Service(CreateRoomError::UnauthorizedClient(err.msg))
+                    return RusotoError::service(CreateRoomError::UnauthorizedClient(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreateRoomError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -4102,12 +4354,14 @@ impl fmt::Display for CreateRoomError {
             CreateRoomError::ServiceUnavailable(ref cause) => write!(f, "{}", cause),
             CreateRoomError::ThrottledClient(ref cause) => write!(f, "{}", cause),
             CreateRoomError::UnauthorizedClient(ref cause) => write!(f, "{}", cause),
+            CreateRoomError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateRoomError {}
 /// Errors returned by CreateRoomMembership
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateRoomMembershipError {
     /// <p>The input parameters don't match the service's restrictions.</p>
     BadRequest(String),
@@ -4127,6 +4381,8 @@ pub enum CreateRoomMembershipError {
     ThrottledClient(String),
     /// <p>The client is not currently authorized to make the request.</p>
     UnauthorizedClient(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateRoomMembershipError {
@@ -4134,42 +4390,55 @@ impl CreateRoomMembershipError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(CreateRoomMembershipError::BadRequest(err.msg))
+                    return RusotoError::service(
+                        CreateRoomMembershipError::BadRequest(err.msg),
+                        &res,
+                    )
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(CreateRoomMembershipError::Conflict(err.msg))
+                    return RusotoError::service(CreateRoomMembershipError::Conflict(err.msg), &res)
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(CreateRoomMembershipError::Forbidden(err.msg))
+                    return RusotoError::service(
+                        CreateRoomMembershipError::Forbidden(err.msg),
+                        &res,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(CreateRoomMembershipError::NotFound(err.msg))
+                    return RusotoError::service(CreateRoomMembershipError::NotFound(err.msg), &res)
                 }
                 "ResourceLimitExceededException" => {
-                    return RusotoError::Service(CreateRoomMembershipError::ResourceLimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateRoomMembershipError::ResourceLimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(CreateRoomMembershipError::ServiceFailure(err.msg))
+                    return RusotoError::service(
+                        CreateRoomMembershipError::ServiceFailure(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(CreateRoomMembershipError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateRoomMembershipError::ServiceUnavailable(err.msg),
+                        &res,
+                    )
                 }
                 "ThrottledClientException" => {
-                    return RusotoError::Service(CreateRoomMembershipError::ThrottledClient(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateRoomMembershipError::ThrottledClient(err.msg),
+                        &res,
+                    )
                 }
                 "UnauthorizedClientException" => {
-                    return RusotoError::Service(CreateRoomMembershipError::UnauthorizedClient(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateRoomMembershipError::UnauthorizedClient(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreateRoomMembershipError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -4188,12 +4457,14 @@ impl fmt::Display for CreateRoomMembershipError {
             CreateRoomMembershipError::ServiceUnavailable(ref cause) => write!(f, "{}", cause),
             CreateRoomMembershipError::ThrottledClient(ref cause) => write!(f, "{}", cause),
             CreateRoomMembershipError::UnauthorizedClient(ref cause) => write!(f, "{}", cause),
+            CreateRoomMembershipError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateRoomMembershipError {}
 /// Errors returned by CreateUser
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateUserError {
     /// <p>The input parameters don't match the service's restrictions.</p>
     BadRequest(String),
@@ -4211,6 +4482,8 @@ pub enum CreateUserError {
     ThrottledClient(String),
     /// <p>The client is not currently authorized to make the request.</p>
     UnauthorizedClient(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateUserError {
@@ -4218,31 +4491,31 @@ impl CreateUserError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(CreateUserError::BadRequest(err.msg))
+                    return RusotoError::service(CreateUserError::BadRequest(err.msg), &res)
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(CreateUserError::Conflict(err.msg))
+                    return RusotoError::service(CreateUserError::Conflict(err.msg), &res)
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(CreateUserError::Forbidden(err.msg))
+                    return RusotoError::service(CreateUserError::Forbidden(err.msg), &res)
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(CreateUserError::NotFound(err.msg))
+                    return RusotoError::service(CreateUserError::NotFound(err.msg), &res)
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(CreateUserError::ServiceFailure(err.msg))
+                    return RusotoError::service(CreateUserError::ServiceFailure(err.msg), &res)
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(CreateUserError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(CreateUserError::ServiceUnavailable(err.msg), &res)
                 }
                 "ThrottledClientException" => {
-                    return RusotoError::Service(CreateUserError::ThrottledClient(err.msg))
+                    return RusotoError::service(CreateUserError::ThrottledClient(err.msg), &res)
                 }
                 "UnauthorizedClientException" => {
-                    return RusotoError::Service(CreateUserError::UnauthorizedClient(err.msg))
+                    return RusotoError::service(CreateUserError::UnauthorizedClient(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreateUserError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -4260,12 +4533,14 @@ impl fmt::Display for CreateUserError {
             CreateUserError::ServiceUnavailable(ref cause) => write!(f, "{}", cause),
             CreateUserError::ThrottledClient(ref cause) => write!(f, "{}", cause),
             CreateUserError::UnauthorizedClient(ref cause) => write!(f, "{}", cause),
+            CreateUserError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateUserError {}
 /// Errors returned by CreateVoiceConnector
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateVoiceConnectorError {
     /// <p>You don't have permissions to perform the requested operation.</p>
     AccessDenied(String),
@@ -4283,6 +4558,8 @@ pub enum CreateVoiceConnectorError {
     ThrottledClient(String),
     /// <p>The client is not currently authorized to make the request.</p>
     UnauthorizedClient(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateVoiceConnectorError {
@@ -4290,39 +4567,55 @@ impl CreateVoiceConnectorError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(CreateVoiceConnectorError::AccessDenied(err.msg))
+                    return RusotoError::service(
+                        CreateVoiceConnectorError::AccessDenied(err.msg),
+                        &res,
+                    )
                 }
                 "BadRequestException" => {
-                    return RusotoError::Service(CreateVoiceConnectorError::BadRequest(err.msg))
+                    return RusotoError::service(
+                        CreateVoiceConnectorError::BadRequest(err.msg),
+                        &res,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(CreateVoiceConnectorError::Forbidden(err.msg))
+                    return RusotoError::service(
+                        CreateVoiceConnectorError::Forbidden(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceLimitExceededException" => {
-                    return RusotoError::Service(CreateVoiceConnectorError::ResourceLimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateVoiceConnectorError::ResourceLimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(CreateVoiceConnectorError::ServiceFailure(err.msg))
+                    return RusotoError::service(
+                        CreateVoiceConnectorError::ServiceFailure(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(CreateVoiceConnectorError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateVoiceConnectorError::ServiceUnavailable(err.msg),
+                        &res,
+                    )
                 }
                 "ThrottledClientException" => {
-                    return RusotoError::Service(CreateVoiceConnectorError::ThrottledClient(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateVoiceConnectorError::ThrottledClient(err.msg),
+                        &res,
+                    )
                 }
                 "UnauthorizedClientException" => {
-                    return RusotoError::Service(CreateVoiceConnectorError::UnauthorizedClient(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateVoiceConnectorError::UnauthorizedClient(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreateVoiceConnectorError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -4340,12 +4633,14 @@ impl fmt::Display for CreateVoiceConnectorError {
             CreateVoiceConnectorError::ServiceUnavailable(ref cause) => write!(f, "{}", cause),
             CreateVoiceConnectorError::ThrottledClient(ref cause) => write!(f, "{}", cause),
             CreateVoiceConnectorError::UnauthorizedClient(ref cause) => write!(f, "{}", cause),
+            CreateVoiceConnectorError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateVoiceConnectorError {}
 /// Errors returned by CreateVoiceConnectorGroup
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateVoiceConnectorGroupError {
     /// <p>You don't have permissions to perform the requested operation.</p>
     AccessDenied(String),
@@ -4363,6 +4658,8 @@ pub enum CreateVoiceConnectorGroupError {
     ThrottledClient(String),
     /// <p>The client is not currently authorized to make the request.</p>
     UnauthorizedClient(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateVoiceConnectorGroupError {
@@ -4370,45 +4667,57 @@ impl CreateVoiceConnectorGroupError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(CreateVoiceConnectorGroupError::AccessDenied(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateVoiceConnectorGroupError::AccessDenied(err.msg),
+                        &res,
+                    )
                 }
                 "BadRequestException" => {
-                    return RusotoError::Service(CreateVoiceConnectorGroupError::BadRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateVoiceConnectorGroupError::BadRequest(err.msg),
+                        &res,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(CreateVoiceConnectorGroupError::Forbidden(err.msg))
+                    return RusotoError::service(
+                        CreateVoiceConnectorGroupError::Forbidden(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceLimitExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         CreateVoiceConnectorGroupError::ResourceLimitExceeded(err.msg),
+                        &res,
                     )
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(CreateVoiceConnectorGroupError::ServiceFailure(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateVoiceConnectorGroupError::ServiceFailure(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         CreateVoiceConnectorGroupError::ServiceUnavailable(err.msg),
+                        &res,
                     )
                 }
                 "ThrottledClientException" => {
-                    return RusotoError::Service(CreateVoiceConnectorGroupError::ThrottledClient(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateVoiceConnectorGroupError::ThrottledClient(err.msg),
+                        &res,
+                    )
                 }
                 "UnauthorizedClientException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         CreateVoiceConnectorGroupError::UnauthorizedClient(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(CreateVoiceConnectorGroupError::Unhandled, &res)
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -4428,12 +4737,14 @@ impl fmt::Display for CreateVoiceConnectorGroupError {
             CreateVoiceConnectorGroupError::ServiceUnavailable(ref cause) => write!(f, "{}", cause),
             CreateVoiceConnectorGroupError::ThrottledClient(ref cause) => write!(f, "{}", cause),
             CreateVoiceConnectorGroupError::UnauthorizedClient(ref cause) => write!(f, "{}", cause),
+            CreateVoiceConnectorGroupError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateVoiceConnectorGroupError {}
 /// Errors returned by DeleteAccount
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteAccountError {
     /// <p>The input parameters don't match the service's restrictions.</p>
     BadRequest(String),
@@ -4451,6 +4762,8 @@ pub enum DeleteAccountError {
     UnauthorizedClient(String),
     /// <p>The request was well-formed but was unable to be followed due to semantic errors.</p>
     UnprocessableEntity(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteAccountError {
@@ -4458,31 +4771,40 @@ impl DeleteAccountError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(DeleteAccountError::BadRequest(err.msg))
+                    return RusotoError::service(DeleteAccountError::BadRequest(err.msg), &res)
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(DeleteAccountError::Forbidden(err.msg))
+                    return RusotoError::service(DeleteAccountError::Forbidden(err.msg), &res)
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteAccountError::NotFound(err.msg))
+                    return RusotoError::service(DeleteAccountError::NotFound(err.msg), &res)
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(DeleteAccountError::ServiceFailure(err.msg))
+                    return RusotoError::service(DeleteAccountError::ServiceFailure(err.msg), &res)
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(DeleteAccountError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        DeleteAccountError::ServiceUnavailable(err.msg),
+                        &res,
+                    )
                 }
                 "ThrottledClientException" => {
-                    return RusotoError::Service(DeleteAccountError::ThrottledClient(err.msg))
+                    return RusotoError::service(DeleteAccountError::ThrottledClient(err.msg), &res)
                 }
                 "UnauthorizedClientException" => {
-                    return RusotoError::Service(DeleteAccountError::UnauthorizedClient(err.msg))
+                    return RusotoError::service(
+                        DeleteAccountError::UnauthorizedClient(err.msg),
+                        &res,
+                    )
                 }
                 "UnprocessableEntityException" => {
-                    return RusotoError::Service(DeleteAccountError::UnprocessableEntity(err.msg))
+                    return RusotoError::service(
+                        DeleteAccountError::UnprocessableEntity(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteAccountError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -4500,12 +4822,14 @@ impl fmt::Display for DeleteAccountError {
             DeleteAccountError::ThrottledClient(ref cause) => write!(f, "{}", cause),
             DeleteAccountError::UnauthorizedClient(ref cause) => write!(f, "{}", cause),
             DeleteAccountError::UnprocessableEntity(ref cause) => write!(f, "{}", cause),
+            DeleteAccountError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteAccountError {}
 /// Errors returned by DeleteAttendee
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteAttendeeError {
     /// <p>The input parameters don't match the service's restrictions.</p>
     BadRequest(String),
@@ -4521,6 +4845,8 @@ pub enum DeleteAttendeeError {
     ThrottledClient(String),
     /// <p>The client is not currently authorized to make the request.</p>
     UnauthorizedClient(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteAttendeeError {
@@ -4528,28 +4854,37 @@ impl DeleteAttendeeError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(DeleteAttendeeError::BadRequest(err.msg))
+                    return RusotoError::service(DeleteAttendeeError::BadRequest(err.msg), &res)
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(DeleteAttendeeError::Forbidden(err.msg))
+                    return RusotoError::service(DeleteAttendeeError::Forbidden(err.msg), &res)
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteAttendeeError::NotFound(err.msg))
+                    return RusotoError::service(DeleteAttendeeError::NotFound(err.msg), &res)
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(DeleteAttendeeError::ServiceFailure(err.msg))
+                    return RusotoError::service(DeleteAttendeeError::ServiceFailure(err.msg), &res)
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(DeleteAttendeeError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        DeleteAttendeeError::ServiceUnavailable(err.msg),
+                        &res,
+                    )
                 }
                 "ThrottledClientException" => {
-                    return RusotoError::Service(DeleteAttendeeError::ThrottledClient(err.msg))
+                    return RusotoError::service(
+                        DeleteAttendeeError::ThrottledClient(err.msg),
+                        &res,
+                    )
                 }
                 "UnauthorizedClientException" => {
-                    return RusotoError::Service(DeleteAttendeeError::UnauthorizedClient(err.msg))
+                    return RusotoError::service(
+                        DeleteAttendeeError::UnauthorizedClient(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteAttendeeError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -4566,12 +4901,14 @@ impl fmt::Display for DeleteAttendeeError {
             DeleteAttendeeError::ServiceUnavailable(ref cause) => write!(f, "{}", cause),
             DeleteAttendeeError::ThrottledClient(ref cause) => write!(f, "{}", cause),
             DeleteAttendeeError::UnauthorizedClient(ref cause) => write!(f, "{}", cause),
+            DeleteAttendeeError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteAttendeeError {}
 /// Errors returned by DeleteEventsConfiguration
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteEventsConfigurationError {
     /// <p>The input parameters don't match the service's restrictions.</p>
     BadRequest(String),
@@ -4585,6 +4922,8 @@ pub enum DeleteEventsConfigurationError {
     ServiceUnavailable(String),
     /// <p>The client is not currently authorized to make the request.</p>
     UnauthorizedClient(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteEventsConfigurationError {
@@ -4592,35 +4931,45 @@ impl DeleteEventsConfigurationError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(DeleteEventsConfigurationError::BadRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteEventsConfigurationError::BadRequest(err.msg),
+                        &res,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(DeleteEventsConfigurationError::Forbidden(err.msg))
+                    return RusotoError::service(
+                        DeleteEventsConfigurationError::Forbidden(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceLimitExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DeleteEventsConfigurationError::ResourceLimitExceeded(err.msg),
+                        &res,
                     )
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(DeleteEventsConfigurationError::ServiceFailure(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteEventsConfigurationError::ServiceFailure(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DeleteEventsConfigurationError::ServiceUnavailable(err.msg),
+                        &res,
                     )
                 }
                 "UnauthorizedClientException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DeleteEventsConfigurationError::UnauthorizedClient(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(DeleteEventsConfigurationError::Unhandled, &res)
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -4638,12 +4987,14 @@ impl fmt::Display for DeleteEventsConfigurationError {
             DeleteEventsConfigurationError::ServiceFailure(ref cause) => write!(f, "{}", cause),
             DeleteEventsConfigurationError::ServiceUnavailable(ref cause) => write!(f, "{}", cause),
             DeleteEventsConfigurationError::UnauthorizedClient(ref cause) => write!(f, "{}", cause),
+            DeleteEventsConfigurationError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteEventsConfigurationError {}
 /// Errors returned by DeleteMeeting
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteMeetingError {
     /// <p>The input parameters don't match the service's restrictions.</p>
     BadRequest(String),
@@ -4659,6 +5010,8 @@ pub enum DeleteMeetingError {
     ThrottledClient(String),
     /// <p>The client is not currently authorized to make the request.</p>
     UnauthorizedClient(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteMeetingError {
@@ -4666,28 +5019,34 @@ impl DeleteMeetingError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(DeleteMeetingError::BadRequest(err.msg))
+                    return RusotoError::service(DeleteMeetingError::BadRequest(err.msg), &res)
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(DeleteMeetingError::Forbidden(err.msg))
+                    return RusotoError::service(DeleteMeetingError::Forbidden(err.msg), &res)
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteMeetingError::NotFound(err.msg))
+                    return RusotoError::service(DeleteMeetingError::NotFound(err.msg), &res)
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(DeleteMeetingError::ServiceFailure(err.msg))
+                    return RusotoError::service(DeleteMeetingError::ServiceFailure(err.msg), &res)
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(DeleteMeetingError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        DeleteMeetingError::ServiceUnavailable(err.msg),
+                        &res,
+                    )
                 }
                 "ThrottledClientException" => {
-                    return RusotoError::Service(DeleteMeetingError::ThrottledClient(err.msg))
+                    return RusotoError::service(DeleteMeetingError::ThrottledClient(err.msg), &res)
                 }
                 "UnauthorizedClientException" => {
-                    return RusotoError::Service(DeleteMeetingError::UnauthorizedClient(err.msg))
+                    return RusotoError::service(
+                        DeleteMeetingError::UnauthorizedClient(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteMeetingError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -4704,12 +5063,14 @@ impl fmt::Display for DeleteMeetingError {
             DeleteMeetingError::ServiceUnavailable(ref cause) => write!(f, "{}", cause),
             DeleteMeetingError::ThrottledClient(ref cause) => write!(f, "{}", cause),
             DeleteMeetingError::UnauthorizedClient(ref cause) => write!(f, "{}", cause),
+            DeleteMeetingError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteMeetingError {}
 /// Errors returned by DeletePhoneNumber
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeletePhoneNumberError {
     /// <p>The input parameters don't match the service's restrictions.</p>
     BadRequest(String),
@@ -4725,6 +5086,8 @@ pub enum DeletePhoneNumberError {
     ThrottledClient(String),
     /// <p>The client is not currently authorized to make the request.</p>
     UnauthorizedClient(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeletePhoneNumberError {
@@ -4732,32 +5095,40 @@ impl DeletePhoneNumberError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(DeletePhoneNumberError::BadRequest(err.msg))
+                    return RusotoError::service(DeletePhoneNumberError::BadRequest(err.msg), &res)
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(DeletePhoneNumberError::Forbidden(err.msg))
+                    return RusotoError::service(DeletePhoneNumberError::Forbidden(err.msg), &res)
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DeletePhoneNumberError::NotFound(err.msg))
+                    return RusotoError::service(DeletePhoneNumberError::NotFound(err.msg), &res)
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(DeletePhoneNumberError::ServiceFailure(err.msg))
+                    return RusotoError::service(
+                        DeletePhoneNumberError::ServiceFailure(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(DeletePhoneNumberError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeletePhoneNumberError::ServiceUnavailable(err.msg),
+                        &res,
+                    )
                 }
                 "ThrottledClientException" => {
-                    return RusotoError::Service(DeletePhoneNumberError::ThrottledClient(err.msg))
+                    return RusotoError::service(
+                        DeletePhoneNumberError::ThrottledClient(err.msg),
+                        &res,
+                    )
                 }
                 "UnauthorizedClientException" => {
-                    return RusotoError::Service(DeletePhoneNumberError::UnauthorizedClient(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeletePhoneNumberError::UnauthorizedClient(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeletePhoneNumberError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -4774,12 +5145,14 @@ impl fmt::Display for DeletePhoneNumberError {
             DeletePhoneNumberError::ServiceUnavailable(ref cause) => write!(f, "{}", cause),
             DeletePhoneNumberError::ThrottledClient(ref cause) => write!(f, "{}", cause),
             DeletePhoneNumberError::UnauthorizedClient(ref cause) => write!(f, "{}", cause),
+            DeletePhoneNumberError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeletePhoneNumberError {}
 /// Errors returned by DeleteRoom
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteRoomError {
     /// <p>The input parameters don't match the service's restrictions.</p>
     BadRequest(String),
@@ -4795,6 +5168,8 @@ pub enum DeleteRoomError {
     ThrottledClient(String),
     /// <p>The client is not currently authorized to make the request.</p>
     UnauthorizedClient(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteRoomError {
@@ -4802,28 +5177,28 @@ impl DeleteRoomError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(DeleteRoomError::BadRequest(err.msg))
+                    return RusotoError::service(DeleteRoomError::BadRequest(err.msg), &res)
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(DeleteRoomError::Forbidden(err.msg))
+                    return RusotoError::service(DeleteRoomError::Forbidden(err.msg), &res)
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteRoomError::NotFound(err.msg))
+                    return RusotoError::service(DeleteRoomError::NotFound(err.msg), &res)
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(DeleteRoomError::ServiceFailure(err.msg))
+                    return RusotoError::service(DeleteRoomError::ServiceFailure(err.msg), &res)
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(DeleteRoomError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(DeleteRoomError::ServiceUnavailable(err.msg), &res)
                 }
                 "ThrottledClientException" => {
-                    return RusotoError::Service(DeleteRoomError::ThrottledClient(err.msg))
+                    return RusotoError::service(DeleteRoomError::ThrottledClient(err.msg), &res)
                 }
                 "UnauthorizedClientException" => {
-                    return RusotoError::Service(DeleteRoomError::UnauthorizedClient(err.msg))
+                    return RusotoError::service(DeleteRoomError::UnauthorizedClient(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteRoomError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -4840,12 +5215,14 @@ impl fmt::Display for DeleteRoomError {
             DeleteRoomError::ServiceUnavailable(ref cause) => write!(f, "{}", cause),
             DeleteRoomError::ThrottledClient(ref cause) => write!(f, "{}", cause),
             DeleteRoomError::UnauthorizedClient(ref cause) => write!(f, "{}", cause),
+            DeleteRoomError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteRoomError {}
 /// Errors returned by DeleteRoomMembership
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteRoomMembershipError {
     /// <p>The input parameters don't match the service's restrictions.</p>
     BadRequest(String),
@@ -4861,6 +5238,8 @@ pub enum DeleteRoomMembershipError {
     ThrottledClient(String),
     /// <p>The client is not currently authorized to make the request.</p>
     UnauthorizedClient(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteRoomMembershipError {
@@ -4868,34 +5247,46 @@ impl DeleteRoomMembershipError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(DeleteRoomMembershipError::BadRequest(err.msg))
+                    return RusotoError::service(
+                        DeleteRoomMembershipError::BadRequest(err.msg),
+                        &res,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(DeleteRoomMembershipError::Forbidden(err.msg))
+                    return RusotoError::service(
+                        DeleteRoomMembershipError::Forbidden(err.msg),
+                        &res,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteRoomMembershipError::NotFound(err.msg))
+                    return RusotoError::service(DeleteRoomMembershipError::NotFound(err.msg), &res)
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(DeleteRoomMembershipError::ServiceFailure(err.msg))
+                    return RusotoError::service(
+                        DeleteRoomMembershipError::ServiceFailure(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(DeleteRoomMembershipError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteRoomMembershipError::ServiceUnavailable(err.msg),
+                        &res,
+                    )
                 }
                 "ThrottledClientException" => {
-                    return RusotoError::Service(DeleteRoomMembershipError::ThrottledClient(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteRoomMembershipError::ThrottledClient(err.msg),
+                        &res,
+                    )
                 }
                 "UnauthorizedClientException" => {
-                    return RusotoError::Service(DeleteRoomMembershipError::UnauthorizedClient(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteRoomMembershipError::UnauthorizedClient(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteRoomMembershipError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -4912,12 +5303,14 @@ impl fmt::Display for DeleteRoomMembershipError {
             DeleteRoomMembershipError::ServiceUnavailable(ref cause) => write!(f, "{}", cause),
             DeleteRoomMembershipError::ThrottledClient(ref cause) => write!(f, "{}", cause),
             DeleteRoomMembershipError::UnauthorizedClient(ref cause) => write!(f, "{}", cause),
+            DeleteRoomMembershipError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteRoomMembershipError {}
 /// Errors returned by DeleteVoiceConnector
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteVoiceConnectorError {
     /// <p>The input parameters don't match the service's restrictions.</p>
     BadRequest(String),
@@ -4935,6 +5328,8 @@ pub enum DeleteVoiceConnectorError {
     ThrottledClient(String),
     /// <p>The client is not currently authorized to make the request.</p>
     UnauthorizedClient(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteVoiceConnectorError {
@@ -4942,37 +5337,49 @@ impl DeleteVoiceConnectorError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(DeleteVoiceConnectorError::BadRequest(err.msg))
+                    return RusotoError::service(
+                        DeleteVoiceConnectorError::BadRequest(err.msg),
+                        &res,
+                    )
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(DeleteVoiceConnectorError::Conflict(err.msg))
+                    return RusotoError::service(DeleteVoiceConnectorError::Conflict(err.msg), &res)
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(DeleteVoiceConnectorError::Forbidden(err.msg))
+                    return RusotoError::service(
+                        DeleteVoiceConnectorError::Forbidden(err.msg),
+                        &res,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteVoiceConnectorError::NotFound(err.msg))
+                    return RusotoError::service(DeleteVoiceConnectorError::NotFound(err.msg), &res)
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(DeleteVoiceConnectorError::ServiceFailure(err.msg))
+                    return RusotoError::service(
+                        DeleteVoiceConnectorError::ServiceFailure(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(DeleteVoiceConnectorError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteVoiceConnectorError::ServiceUnavailable(err.msg),
+                        &res,
+                    )
                 }
                 "ThrottledClientException" => {
-                    return RusotoError::Service(DeleteVoiceConnectorError::ThrottledClient(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteVoiceConnectorError::ThrottledClient(err.msg),
+                        &res,
+                    )
                 }
                 "UnauthorizedClientException" => {
-                    return RusotoError::Service(DeleteVoiceConnectorError::UnauthorizedClient(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteVoiceConnectorError::UnauthorizedClient(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteVoiceConnectorError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -4990,12 +5397,14 @@ impl fmt::Display for DeleteVoiceConnectorError {
             DeleteVoiceConnectorError::ServiceUnavailable(ref cause) => write!(f, "{}", cause),
             DeleteVoiceConnectorError::ThrottledClient(ref cause) => write!(f, "{}", cause),
             DeleteVoiceConnectorError::UnauthorizedClient(ref cause) => write!(f, "{}", cause),
+            DeleteVoiceConnectorError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteVoiceConnectorError {}
 /// Errors returned by DeleteVoiceConnectorGroup
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteVoiceConnectorGroupError {
     /// <p>The input parameters don't match the service's restrictions.</p>
     BadRequest(String),
@@ -5013,6 +5422,8 @@ pub enum DeleteVoiceConnectorGroupError {
     ThrottledClient(String),
     /// <p>The client is not currently authorized to make the request.</p>
     UnauthorizedClient(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteVoiceConnectorGroupError {
@@ -5020,41 +5431,57 @@ impl DeleteVoiceConnectorGroupError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(DeleteVoiceConnectorGroupError::BadRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteVoiceConnectorGroupError::BadRequest(err.msg),
+                        &res,
+                    )
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(DeleteVoiceConnectorGroupError::Conflict(err.msg))
+                    return RusotoError::service(
+                        DeleteVoiceConnectorGroupError::Conflict(err.msg),
+                        &res,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(DeleteVoiceConnectorGroupError::Forbidden(err.msg))
+                    return RusotoError::service(
+                        DeleteVoiceConnectorGroupError::Forbidden(err.msg),
+                        &res,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteVoiceConnectorGroupError::NotFound(err.msg))
+                    return RusotoError::service(
+                        DeleteVoiceConnectorGroupError::NotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(DeleteVoiceConnectorGroupError::ServiceFailure(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteVoiceConnectorGroupError::ServiceFailure(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DeleteVoiceConnectorGroupError::ServiceUnavailable(err.msg),
+                        &res,
                     )
                 }
                 "ThrottledClientException" => {
-                    return RusotoError::Service(DeleteVoiceConnectorGroupError::ThrottledClient(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteVoiceConnectorGroupError::ThrottledClient(err.msg),
+                        &res,
+                    )
                 }
                 "UnauthorizedClientException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DeleteVoiceConnectorGroupError::UnauthorizedClient(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(DeleteVoiceConnectorGroupError::Unhandled, &res)
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -5072,12 +5499,14 @@ impl fmt::Display for DeleteVoiceConnectorGroupError {
             DeleteVoiceConnectorGroupError::ServiceUnavailable(ref cause) => write!(f, "{}", cause),
             DeleteVoiceConnectorGroupError::ThrottledClient(ref cause) => write!(f, "{}", cause),
             DeleteVoiceConnectorGroupError::UnauthorizedClient(ref cause) => write!(f, "{}", cause),
+            DeleteVoiceConnectorGroupError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteVoiceConnectorGroupError {}
 /// Errors returned by DeleteVoiceConnectorOrigination
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteVoiceConnectorOriginationError {
     /// <p>The input parameters don't match the service's restrictions.</p>
     BadRequest(String),
@@ -5093,6 +5522,8 @@ pub enum DeleteVoiceConnectorOriginationError {
     ThrottledClient(String),
     /// <p>The client is not currently authorized to make the request.</p>
     UnauthorizedClient(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteVoiceConnectorOriginationError {
@@ -5102,42 +5533,54 @@ impl DeleteVoiceConnectorOriginationError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(DeleteVoiceConnectorOriginationError::BadRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteVoiceConnectorOriginationError::BadRequest(err.msg),
+                        &res,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(DeleteVoiceConnectorOriginationError::Forbidden(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteVoiceConnectorOriginationError::Forbidden(err.msg),
+                        &res,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteVoiceConnectorOriginationError::NotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteVoiceConnectorOriginationError::NotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DeleteVoiceConnectorOriginationError::ServiceFailure(err.msg),
+                        &res,
                     )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DeleteVoiceConnectorOriginationError::ServiceUnavailable(err.msg),
+                        &res,
                     )
                 }
                 "ThrottledClientException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DeleteVoiceConnectorOriginationError::ThrottledClient(err.msg),
+                        &res,
                     )
                 }
                 "UnauthorizedClientException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DeleteVoiceConnectorOriginationError::UnauthorizedClient(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        DeleteVoiceConnectorOriginationError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -5162,12 +5605,16 @@ impl fmt::Display for DeleteVoiceConnectorOriginationError {
             DeleteVoiceConnectorOriginationError::UnauthorizedClient(ref cause) => {
                 write!(f, "{}", cause)
             }
+            DeleteVoiceConnectorOriginationError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for DeleteVoiceConnectorOriginationError {}
 /// Errors returned by DeleteVoiceConnectorStreamingConfiguration
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteVoiceConnectorStreamingConfigurationError {
     /// <p>The input parameters don't match the service's restrictions.</p>
     BadRequest(String),
@@ -5183,6 +5630,8 @@ pub enum DeleteVoiceConnectorStreamingConfigurationError {
     ThrottledClient(String),
     /// <p>The client is not currently authorized to make the request.</p>
     UnauthorizedClient(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteVoiceConnectorStreamingConfigurationError {
@@ -5192,46 +5641,58 @@ impl DeleteVoiceConnectorStreamingConfigurationError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DeleteVoiceConnectorStreamingConfigurationError::BadRequest(err.msg),
+                        &res,
                     )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DeleteVoiceConnectorStreamingConfigurationError::Forbidden(err.msg),
+                        &res,
                     )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DeleteVoiceConnectorStreamingConfigurationError::NotFound(err.msg),
+                        &res,
                     )
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DeleteVoiceConnectorStreamingConfigurationError::ServiceFailure(err.msg),
+                        &res,
                     )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DeleteVoiceConnectorStreamingConfigurationError::ServiceUnavailable(
                             err.msg,
                         ),
+                        &res,
                     )
                 }
                 "ThrottledClientException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DeleteVoiceConnectorStreamingConfigurationError::ThrottledClient(err.msg),
+                        &res,
                     )
                 }
                 "UnauthorizedClientException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DeleteVoiceConnectorStreamingConfigurationError::UnauthorizedClient(
                             err.msg,
                         ),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        DeleteVoiceConnectorStreamingConfigurationError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -5262,12 +5723,16 @@ impl fmt::Display for DeleteVoiceConnectorStreamingConfigurationError {
             DeleteVoiceConnectorStreamingConfigurationError::UnauthorizedClient(ref cause) => {
                 write!(f, "{}", cause)
             }
+            DeleteVoiceConnectorStreamingConfigurationError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for DeleteVoiceConnectorStreamingConfigurationError {}
 /// Errors returned by DeleteVoiceConnectorTermination
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteVoiceConnectorTerminationError {
     /// <p>The input parameters don't match the service's restrictions.</p>
     BadRequest(String),
@@ -5283,6 +5748,8 @@ pub enum DeleteVoiceConnectorTerminationError {
     ThrottledClient(String),
     /// <p>The client is not currently authorized to make the request.</p>
     UnauthorizedClient(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteVoiceConnectorTerminationError {
@@ -5292,42 +5759,54 @@ impl DeleteVoiceConnectorTerminationError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(DeleteVoiceConnectorTerminationError::BadRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteVoiceConnectorTerminationError::BadRequest(err.msg),
+                        &res,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(DeleteVoiceConnectorTerminationError::Forbidden(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteVoiceConnectorTerminationError::Forbidden(err.msg),
+                        &res,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteVoiceConnectorTerminationError::NotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteVoiceConnectorTerminationError::NotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DeleteVoiceConnectorTerminationError::ServiceFailure(err.msg),
+                        &res,
                     )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DeleteVoiceConnectorTerminationError::ServiceUnavailable(err.msg),
+                        &res,
                     )
                 }
                 "ThrottledClientException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DeleteVoiceConnectorTerminationError::ThrottledClient(err.msg),
+                        &res,
                     )
                 }
                 "UnauthorizedClientException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DeleteVoiceConnectorTerminationError::UnauthorizedClient(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        DeleteVoiceConnectorTerminationError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -5352,12 +5831,16 @@ impl fmt::Display for DeleteVoiceConnectorTerminationError {
             DeleteVoiceConnectorTerminationError::UnauthorizedClient(ref cause) => {
                 write!(f, "{}", cause)
             }
+            DeleteVoiceConnectorTerminationError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for DeleteVoiceConnectorTerminationError {}
 /// Errors returned by DeleteVoiceConnectorTerminationCredentials
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteVoiceConnectorTerminationCredentialsError {
     /// <p>The input parameters don't match the service's restrictions.</p>
     BadRequest(String),
@@ -5373,6 +5856,8 @@ pub enum DeleteVoiceConnectorTerminationCredentialsError {
     ThrottledClient(String),
     /// <p>The client is not currently authorized to make the request.</p>
     UnauthorizedClient(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteVoiceConnectorTerminationCredentialsError {
@@ -5382,46 +5867,58 @@ impl DeleteVoiceConnectorTerminationCredentialsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DeleteVoiceConnectorTerminationCredentialsError::BadRequest(err.msg),
+                        &res,
                     )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DeleteVoiceConnectorTerminationCredentialsError::Forbidden(err.msg),
+                        &res,
                     )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DeleteVoiceConnectorTerminationCredentialsError::NotFound(err.msg),
+                        &res,
                     )
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DeleteVoiceConnectorTerminationCredentialsError::ServiceFailure(err.msg),
+                        &res,
                     )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DeleteVoiceConnectorTerminationCredentialsError::ServiceUnavailable(
                             err.msg,
                         ),
+                        &res,
                     )
                 }
                 "ThrottledClientException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DeleteVoiceConnectorTerminationCredentialsError::ThrottledClient(err.msg),
+                        &res,
                     )
                 }
                 "UnauthorizedClientException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DeleteVoiceConnectorTerminationCredentialsError::UnauthorizedClient(
                             err.msg,
                         ),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        DeleteVoiceConnectorTerminationCredentialsError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -5452,12 +5949,16 @@ impl fmt::Display for DeleteVoiceConnectorTerminationCredentialsError {
             DeleteVoiceConnectorTerminationCredentialsError::UnauthorizedClient(ref cause) => {
                 write!(f, "{}", cause)
             }
+            DeleteVoiceConnectorTerminationCredentialsError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for DeleteVoiceConnectorTerminationCredentialsError {}
 /// Errors returned by DisassociatePhoneNumberFromUser
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DisassociatePhoneNumberFromUserError {
     /// <p>The input parameters don't match the service's restrictions.</p>
     BadRequest(String),
@@ -5473,6 +5974,8 @@ pub enum DisassociatePhoneNumberFromUserError {
     ThrottledClient(String),
     /// <p>The client is not currently authorized to make the request.</p>
     UnauthorizedClient(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DisassociatePhoneNumberFromUserError {
@@ -5482,42 +5985,54 @@ impl DisassociatePhoneNumberFromUserError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(DisassociatePhoneNumberFromUserError::BadRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DisassociatePhoneNumberFromUserError::BadRequest(err.msg),
+                        &res,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(DisassociatePhoneNumberFromUserError::Forbidden(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DisassociatePhoneNumberFromUserError::Forbidden(err.msg),
+                        &res,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DisassociatePhoneNumberFromUserError::NotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DisassociatePhoneNumberFromUserError::NotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DisassociatePhoneNumberFromUserError::ServiceFailure(err.msg),
+                        &res,
                     )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DisassociatePhoneNumberFromUserError::ServiceUnavailable(err.msg),
+                        &res,
                     )
                 }
                 "ThrottledClientException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DisassociatePhoneNumberFromUserError::ThrottledClient(err.msg),
+                        &res,
                     )
                 }
                 "UnauthorizedClientException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DisassociatePhoneNumberFromUserError::UnauthorizedClient(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        DisassociatePhoneNumberFromUserError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -5542,12 +6057,16 @@ impl fmt::Display for DisassociatePhoneNumberFromUserError {
             DisassociatePhoneNumberFromUserError::UnauthorizedClient(ref cause) => {
                 write!(f, "{}", cause)
             }
+            DisassociatePhoneNumberFromUserError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for DisassociatePhoneNumberFromUserError {}
 /// Errors returned by DisassociatePhoneNumbersFromVoiceConnector
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DisassociatePhoneNumbersFromVoiceConnectorError {
     /// <p>The input parameters don't match the service's restrictions.</p>
     BadRequest(String),
@@ -5563,6 +6082,8 @@ pub enum DisassociatePhoneNumbersFromVoiceConnectorError {
     ThrottledClient(String),
     /// <p>The client is not currently authorized to make the request.</p>
     UnauthorizedClient(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DisassociatePhoneNumbersFromVoiceConnectorError {
@@ -5572,46 +6093,58 @@ impl DisassociatePhoneNumbersFromVoiceConnectorError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DisassociatePhoneNumbersFromVoiceConnectorError::BadRequest(err.msg),
+                        &res,
                     )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DisassociatePhoneNumbersFromVoiceConnectorError::Forbidden(err.msg),
+                        &res,
                     )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DisassociatePhoneNumbersFromVoiceConnectorError::NotFound(err.msg),
+                        &res,
                     )
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DisassociatePhoneNumbersFromVoiceConnectorError::ServiceFailure(err.msg),
+                        &res,
                     )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DisassociatePhoneNumbersFromVoiceConnectorError::ServiceUnavailable(
                             err.msg,
                         ),
+                        &res,
                     )
                 }
                 "ThrottledClientException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DisassociatePhoneNumbersFromVoiceConnectorError::ThrottledClient(err.msg),
+                        &res,
                     )
                 }
                 "UnauthorizedClientException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DisassociatePhoneNumbersFromVoiceConnectorError::UnauthorizedClient(
                             err.msg,
                         ),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        DisassociatePhoneNumbersFromVoiceConnectorError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -5642,12 +6175,16 @@ impl fmt::Display for DisassociatePhoneNumbersFromVoiceConnectorError {
             DisassociatePhoneNumbersFromVoiceConnectorError::UnauthorizedClient(ref cause) => {
                 write!(f, "{}", cause)
             }
+            DisassociatePhoneNumbersFromVoiceConnectorError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for DisassociatePhoneNumbersFromVoiceConnectorError {}
 /// Errors returned by DisassociatePhoneNumbersFromVoiceConnectorGroup
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DisassociatePhoneNumbersFromVoiceConnectorGroupError {
     /// <p>The input parameters don't match the service's restrictions.</p>
     BadRequest(String),
@@ -5663,6 +6200,8 @@ pub enum DisassociatePhoneNumbersFromVoiceConnectorGroupError {
     ThrottledClient(String),
     /// <p>The client is not currently authorized to make the request.</p>
     UnauthorizedClient(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DisassociatePhoneNumbersFromVoiceConnectorGroupError {
@@ -5672,50 +6211,62 @@ impl DisassociatePhoneNumbersFromVoiceConnectorGroupError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DisassociatePhoneNumbersFromVoiceConnectorGroupError::BadRequest(err.msg),
+                        &res,
                     )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DisassociatePhoneNumbersFromVoiceConnectorGroupError::Forbidden(err.msg),
+                        &res,
                     )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DisassociatePhoneNumbersFromVoiceConnectorGroupError::NotFound(err.msg),
+                        &res,
                     )
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DisassociatePhoneNumbersFromVoiceConnectorGroupError::ServiceFailure(
                             err.msg,
                         ),
+                        &res,
                     )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DisassociatePhoneNumbersFromVoiceConnectorGroupError::ServiceUnavailable(
                             err.msg,
                         ),
+                        &res,
                     )
                 }
                 "ThrottledClientException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DisassociatePhoneNumbersFromVoiceConnectorGroupError::ThrottledClient(
                             err.msg,
                         ),
+                        &res,
                     )
                 }
                 "UnauthorizedClientException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DisassociatePhoneNumbersFromVoiceConnectorGroupError::UnauthorizedClient(
                             err.msg,
                         ),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        DisassociatePhoneNumbersFromVoiceConnectorGroupError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -5746,12 +6297,16 @@ impl fmt::Display for DisassociatePhoneNumbersFromVoiceConnectorGroupError {
             DisassociatePhoneNumbersFromVoiceConnectorGroupError::UnauthorizedClient(ref cause) => {
                 write!(f, "{}", cause)
             }
+            DisassociatePhoneNumbersFromVoiceConnectorGroupError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for DisassociatePhoneNumbersFromVoiceConnectorGroupError {}
 /// Errors returned by DisassociateSigninDelegateGroupsFromAccount
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DisassociateSigninDelegateGroupsFromAccountError {
     /// <p>The input parameters don't match the service's restrictions.</p>
     BadRequest(String),
@@ -5767,6 +6322,8 @@ pub enum DisassociateSigninDelegateGroupsFromAccountError {
     ThrottledClient(String),
     /// <p>The client is not currently authorized to make the request.</p>
     UnauthorizedClient(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DisassociateSigninDelegateGroupsFromAccountError {
@@ -5776,46 +6333,58 @@ impl DisassociateSigninDelegateGroupsFromAccountError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DisassociateSigninDelegateGroupsFromAccountError::BadRequest(err.msg),
+                        &res,
                     )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DisassociateSigninDelegateGroupsFromAccountError::Forbidden(err.msg),
+                        &res,
                     )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DisassociateSigninDelegateGroupsFromAccountError::NotFound(err.msg),
+                        &res,
                     )
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DisassociateSigninDelegateGroupsFromAccountError::ServiceFailure(err.msg),
+                        &res,
                     )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DisassociateSigninDelegateGroupsFromAccountError::ServiceUnavailable(
                             err.msg,
                         ),
+                        &res,
                     )
                 }
                 "ThrottledClientException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DisassociateSigninDelegateGroupsFromAccountError::ThrottledClient(err.msg),
+                        &res,
                     )
                 }
                 "UnauthorizedClientException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DisassociateSigninDelegateGroupsFromAccountError::UnauthorizedClient(
                             err.msg,
                         ),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        DisassociateSigninDelegateGroupsFromAccountError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -5846,12 +6415,16 @@ impl fmt::Display for DisassociateSigninDelegateGroupsFromAccountError {
             DisassociateSigninDelegateGroupsFromAccountError::UnauthorizedClient(ref cause) => {
                 write!(f, "{}", cause)
             }
+            DisassociateSigninDelegateGroupsFromAccountError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for DisassociateSigninDelegateGroupsFromAccountError {}
 /// Errors returned by GetAccount
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetAccountError {
     /// <p>The input parameters don't match the service's restrictions.</p>
     BadRequest(String),
@@ -5867,6 +6440,8 @@ pub enum GetAccountError {
     ThrottledClient(String),
     /// <p>The client is not currently authorized to make the request.</p>
     UnauthorizedClient(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetAccountError {
@@ -5874,28 +6449,28 @@ impl GetAccountError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetAccountError::BadRequest(err.msg))
+                    return RusotoError::service(GetAccountError::BadRequest(err.msg), &res)
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(GetAccountError::Forbidden(err.msg))
+                    return RusotoError::service(GetAccountError::Forbidden(err.msg), &res)
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetAccountError::NotFound(err.msg))
+                    return RusotoError::service(GetAccountError::NotFound(err.msg), &res)
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(GetAccountError::ServiceFailure(err.msg))
+                    return RusotoError::service(GetAccountError::ServiceFailure(err.msg), &res)
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(GetAccountError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(GetAccountError::ServiceUnavailable(err.msg), &res)
                 }
                 "ThrottledClientException" => {
-                    return RusotoError::Service(GetAccountError::ThrottledClient(err.msg))
+                    return RusotoError::service(GetAccountError::ThrottledClient(err.msg), &res)
                 }
                 "UnauthorizedClientException" => {
-                    return RusotoError::Service(GetAccountError::UnauthorizedClient(err.msg))
+                    return RusotoError::service(GetAccountError::UnauthorizedClient(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetAccountError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -5912,12 +6487,14 @@ impl fmt::Display for GetAccountError {
             GetAccountError::ServiceUnavailable(ref cause) => write!(f, "{}", cause),
             GetAccountError::ThrottledClient(ref cause) => write!(f, "{}", cause),
             GetAccountError::UnauthorizedClient(ref cause) => write!(f, "{}", cause),
+            GetAccountError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetAccountError {}
 /// Errors returned by GetAccountSettings
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetAccountSettingsError {
     /// <p>The input parameters don't match the service's restrictions.</p>
     BadRequest(String),
@@ -5933,6 +6510,8 @@ pub enum GetAccountSettingsError {
     ThrottledClient(String),
     /// <p>The client is not currently authorized to make the request.</p>
     UnauthorizedClient(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetAccountSettingsError {
@@ -5940,32 +6519,40 @@ impl GetAccountSettingsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetAccountSettingsError::BadRequest(err.msg))
+                    return RusotoError::service(GetAccountSettingsError::BadRequest(err.msg), &res)
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(GetAccountSettingsError::Forbidden(err.msg))
+                    return RusotoError::service(GetAccountSettingsError::Forbidden(err.msg), &res)
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetAccountSettingsError::NotFound(err.msg))
+                    return RusotoError::service(GetAccountSettingsError::NotFound(err.msg), &res)
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(GetAccountSettingsError::ServiceFailure(err.msg))
+                    return RusotoError::service(
+                        GetAccountSettingsError::ServiceFailure(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(GetAccountSettingsError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetAccountSettingsError::ServiceUnavailable(err.msg),
+                        &res,
+                    )
                 }
                 "ThrottledClientException" => {
-                    return RusotoError::Service(GetAccountSettingsError::ThrottledClient(err.msg))
+                    return RusotoError::service(
+                        GetAccountSettingsError::ThrottledClient(err.msg),
+                        &res,
+                    )
                 }
                 "UnauthorizedClientException" => {
-                    return RusotoError::Service(GetAccountSettingsError::UnauthorizedClient(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetAccountSettingsError::UnauthorizedClient(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetAccountSettingsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -5982,12 +6569,14 @@ impl fmt::Display for GetAccountSettingsError {
             GetAccountSettingsError::ServiceUnavailable(ref cause) => write!(f, "{}", cause),
             GetAccountSettingsError::ThrottledClient(ref cause) => write!(f, "{}", cause),
             GetAccountSettingsError::UnauthorizedClient(ref cause) => write!(f, "{}", cause),
+            GetAccountSettingsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetAccountSettingsError {}
 /// Errors returned by GetAttendee
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetAttendeeError {
     /// <p>The input parameters don't match the service's restrictions.</p>
     BadRequest(String),
@@ -6003,6 +6592,8 @@ pub enum GetAttendeeError {
     ThrottledClient(String),
     /// <p>The client is not currently authorized to make the request.</p>
     UnauthorizedClient(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetAttendeeError {
@@ -6010,28 +6601,34 @@ impl GetAttendeeError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetAttendeeError::BadRequest(err.msg))
+                    return RusotoError::service(GetAttendeeError::BadRequest(err.msg), &res)
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(GetAttendeeError::Forbidden(err.msg))
+                    return RusotoError::service(GetAttendeeError::Forbidden(err.msg), &res)
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetAttendeeError::NotFound(err.msg))
+                    return RusotoError::service(GetAttendeeError::NotFound(err.msg), &res)
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(GetAttendeeError::ServiceFailure(err.msg))
+                    return RusotoError::service(GetAttendeeError::ServiceFailure(err.msg), &res)
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(GetAttendeeError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        GetAttendeeError::ServiceUnavailable(err.msg),
+                        &res,
+                    )
                 }
                 "ThrottledClientException" => {
-                    return RusotoError::Service(GetAttendeeError::ThrottledClient(err.msg))
+                    return RusotoError::service(GetAttendeeError::ThrottledClient(err.msg), &res)
                 }
                 "UnauthorizedClientException" => {
-                    return RusotoError::Service(GetAttendeeError::UnauthorizedClient(err.msg))
+                    return RusotoError::service(
+                        GetAttendeeError::UnauthorizedClient(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetAttendeeError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -6048,12 +6645,14 @@ impl fmt::Display for GetAttendeeError {
             GetAttendeeError::ServiceUnavailable(ref cause) => write!(f, "{}", cause),
             GetAttendeeError::ThrottledClient(ref cause) => write!(f, "{}", cause),
             GetAttendeeError::UnauthorizedClient(ref cause) => write!(f, "{}", cause),
+            GetAttendeeError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetAttendeeError {}
 /// Errors returned by GetBot
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetBotError {
     /// <p>The input parameters don't match the service's restrictions.</p>
     BadRequest(String),
@@ -6069,6 +6668,8 @@ pub enum GetBotError {
     ThrottledClient(String),
     /// <p>The client is not currently authorized to make the request.</p>
     UnauthorizedClient(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetBotError {
@@ -6076,26 +6677,28 @@ impl GetBotError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetBotError::BadRequest(err.msg))
+                    return RusotoError::service(GetBotError::BadRequest(err.msg), &res)
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(GetBotError::Forbidden(err.msg))
+                    return RusotoError::service(GetBotError::Forbidden(err.msg), &res)
+                }
+                "NotFoundException" => {
+                    return RusotoError::service(GetBotError::NotFound(err.msg), &res)
                 }
-                "NotFoundException" => return RusotoError::Service(GetBotError::NotFound(err.msg)),
                 "ServiceFailureException" => {
-                    return RusotoError::Service(GetBotError::ServiceFailure(err.msg))
+                    return RusotoError::service(GetBotError::ServiceFailure(err.msg), &res)
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(GetBotError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(GetBotError::ServiceUnavailable(err.msg), &res)
                 }
                 "ThrottledClientException" => {
-                    return RusotoError::Service(GetBotError::ThrottledClient(err.msg))
+                    return RusotoError::service(GetBotError::ThrottledClient(err.msg), &res)
                 }
                 "UnauthorizedClientException" => {
-                    return RusotoError::Service(GetBotError::UnauthorizedClient(err.msg))
+                    return RusotoError::service(GetBotError::UnauthorizedClient(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetBotError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -6112,12 +6715,14 @@ impl fmt::Display for GetBotError {
             GetBotError::ServiceUnavailable(ref cause) => write!(f, "{}", cause),
             GetBotError::ThrottledClient(ref cause) => write!(f, "{}", cause),
             GetBotError::UnauthorizedClient(ref cause) => write!(f, "{}", cause),
+            GetBotError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetBotError {}
 /// Errors returned by GetEventsConfiguration
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetEventsConfigurationError {
     /// <p>The input parameters don't match the service's restrictions.</p>
     BadRequest(String),
@@ -6133,6 +6738,8 @@ pub enum GetEventsConfigurationError {
     ServiceUnavailable(String),
     /// <p>The client is not currently authorized to make the request.</p>
     UnauthorizedClient(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetEventsConfigurationError {
@@ -6140,36 +6747,49 @@ impl GetEventsConfigurationError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetEventsConfigurationError::BadRequest(err.msg))
+                    return RusotoError::service(
+                        GetEventsConfigurationError::BadRequest(err.msg),
+                        &res,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(GetEventsConfigurationError::Forbidden(err.msg))
+                    return RusotoError::service(
+                        GetEventsConfigurationError::Forbidden(err.msg),
+                        &res,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetEventsConfigurationError::NotFound(err.msg))
+                    return RusotoError::service(
+                        GetEventsConfigurationError::NotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceLimitExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         GetEventsConfigurationError::ResourceLimitExceeded(err.msg),
+                        &res,
                     )
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(GetEventsConfigurationError::ServiceFailure(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetEventsConfigurationError::ServiceFailure(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(GetEventsConfigurationError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetEventsConfigurationError::ServiceUnavailable(err.msg),
+                        &res,
+                    )
                 }
                 "UnauthorizedClientException" => {
-                    return RusotoError::Service(GetEventsConfigurationError::UnauthorizedClient(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetEventsConfigurationError::UnauthorizedClient(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetEventsConfigurationError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -6186,12 +6806,14 @@ impl fmt::Display for GetEventsConfigurationError {
             GetEventsConfigurationError::ServiceFailure(ref cause) => write!(f, "{}", cause),
             GetEventsConfigurationError::ServiceUnavailable(ref cause) => write!(f, "{}", cause),
             GetEventsConfigurationError::UnauthorizedClient(ref cause) => write!(f, "{}", cause),
+            GetEventsConfigurationError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetEventsConfigurationError {}
 /// Errors returned by GetGlobalSettings
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetGlobalSettingsError {
     /// <p>The input parameters don't match the service's restrictions.</p>
     BadRequest(String),
@@ -6205,6 +6827,8 @@ pub enum GetGlobalSettingsError {
     ThrottledClient(String),
     /// <p>The client is not currently authorized to make the request.</p>
     UnauthorizedClient(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetGlobalSettingsError {
@@ -6212,29 +6836,37 @@ impl GetGlobalSettingsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetGlobalSettingsError::BadRequest(err.msg))
+                    return RusotoError::service(GetGlobalSettingsError::BadRequest(err.msg), &res)
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(GetGlobalSettingsError::Forbidden(err.msg))
+                    return RusotoError::service(GetGlobalSettingsError::Forbidden(err.msg), &res)
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(GetGlobalSettingsError::ServiceFailure(err.msg))
+                    return RusotoError::service(
+                        GetGlobalSettingsError::ServiceFailure(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(GetGlobalSettingsError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetGlobalSettingsError::ServiceUnavailable(err.msg),
+                        &res,
+                    )
                 }
                 "ThrottledClientException" => {
-                    return RusotoError::Service(GetGlobalSettingsError::ThrottledClient(err.msg))
+                    return RusotoError::service(
+                        GetGlobalSettingsError::ThrottledClient(err.msg),
+                        &res,
+                    )
                 }
                 "UnauthorizedClientException" => {
-                    return RusotoError::Service(GetGlobalSettingsError::UnauthorizedClient(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetGlobalSettingsError::UnauthorizedClient(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetGlobalSettingsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -6250,12 +6882,14 @@ impl fmt::Display for GetGlobalSettingsError {
             GetGlobalSettingsError::ServiceUnavailable(ref cause) => write!(f, "{}", cause),
             GetGlobalSettingsError::ThrottledClient(ref cause) => write!(f, "{}", cause),
             GetGlobalSettingsError::UnauthorizedClient(ref cause) => write!(f, "{}", cause),
+            GetGlobalSettingsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetGlobalSettingsError {}
 /// Errors returned by GetMeeting
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetMeetingError {
     /// <p>The input parameters don't match the service's restrictions.</p>
     BadRequest(String),
@@ -6271,6 +6905,8 @@ pub enum GetMeetingError {
     ThrottledClient(String),
     /// <p>The client is not currently authorized to make the request.</p>
     UnauthorizedClient(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetMeetingError {
@@ -6278,28 +6914,28 @@ impl GetMeetingError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetMeetingError::BadRequest(err.msg))
+                    return RusotoError::service(GetMeetingError::BadRequest(err.msg), &res)
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(GetMeetingError::Forbidden(err.msg))
+                    return RusotoError::service(GetMeetingError::Forbidden(err.msg), &res)
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetMeetingError::NotFound(err.msg))
+                    return RusotoError::service(GetMeetingError::NotFound(err.msg), &res)
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(GetMeetingError::ServiceFailure(err.msg))
+                    return RusotoError::service(GetMeetingError::ServiceFailure(err.msg), &res)
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(GetMeetingError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(GetMeetingError::ServiceUnavailable(err.msg), &res)
                 }
                 "ThrottledClientException" => {
-                    return RusotoError::Service(GetMeetingError::ThrottledClient(err.msg))
+                    return RusotoError::service(GetMeetingError::ThrottledClient(err.msg), &res)
                 }
                 "UnauthorizedClientException" => {
-                    return RusotoError::Service(GetMeetingError::UnauthorizedClient(err.msg))
+                    return RusotoError::service(GetMeetingError::UnauthorizedClient(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetMeetingError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -6316,12 +6952,14 @@ impl fmt::Display for GetMeetingError {
             GetMeetingError::ServiceUnavailable(ref cause) => write!(f, "{}", cause),
             GetMeetingError::ThrottledClient(ref cause) => write!(f, "{}", cause),
             GetMeetingError::UnauthorizedClient(ref cause) => write!(f, "{}", cause),
+            GetMeetingError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetMeetingError {}
 /// Errors returned by GetPhoneNumber
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetPhoneNumberError {
     /// <p>The input parameters don't match the service's restrictions.</p>
     BadRequest(String),
@@ -6337,6 +6975,8 @@ pub enum GetPhoneNumberError {
     ThrottledClient(String),
     /// <p>The client is not currently authorized to make the request.</p>
     UnauthorizedClient(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetPhoneNumberError {
@@ -6344,28 +6984,37 @@ impl GetPhoneNumberError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetPhoneNumberError::BadRequest(err.msg))
+                    return RusotoError::service(GetPhoneNumberError::BadRequest(err.msg), &res)
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(GetPhoneNumberError::Forbidden(err.msg))
+                    return RusotoError::service(GetPhoneNumberError::Forbidden(err.msg), &res)
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetPhoneNumberError::NotFound(err.msg))
+                    return RusotoError::service(GetPhoneNumberError::NotFound(err.msg), &res)
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(GetPhoneNumberError::ServiceFailure(err.msg))
+                    return RusotoError::service(GetPhoneNumberError::ServiceFailure(err.msg), &res)
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(GetPhoneNumberError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        GetPhoneNumberError::ServiceUnavailable(err.msg),
+                        &res,
+                    )
                 }
                 "ThrottledClientException" => {
-                    return RusotoError::Service(GetPhoneNumberError::ThrottledClient(err.msg))
+                    return RusotoError::service(
+                        GetPhoneNumberError::ThrottledClient(err.msg),
+                        &res,
+                    )
                 }
                 "UnauthorizedClientException" => {
-                    return RusotoError::Service(GetPhoneNumberError::UnauthorizedClient(err.msg))
+                    return RusotoError::service(
+                        GetPhoneNumberError::UnauthorizedClient(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetPhoneNumberError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -6382,12 +7031,14 @@ impl fmt::Display for GetPhoneNumberError {
             GetPhoneNumberError::ServiceUnavailable(ref cause) => write!(f, "{}", cause),
             GetPhoneNumberError::ThrottledClient(ref cause) => write!(f, "{}", cause),
             GetPhoneNumberError::UnauthorizedClient(ref cause) => write!(f, "{}", cause),
+            GetPhoneNumberError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetPhoneNumberError {}
 /// Errors returned by GetPhoneNumberOrder
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetPhoneNumberOrderError {
     /// <p>The input parameters don't match the service's restrictions.</p>
     BadRequest(String),
@@ -6403,6 +7054,8 @@ pub enum GetPhoneNumberOrderError {
     ThrottledClient(String),
     /// <p>The client is not currently authorized to make the request.</p>
     UnauthorizedClient(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetPhoneNumberOrderError {
@@ -6410,32 +7063,43 @@ impl GetPhoneNumberOrderError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetPhoneNumberOrderError::BadRequest(err.msg))
+                    return RusotoError::service(
+                        GetPhoneNumberOrderError::BadRequest(err.msg),
+                        &res,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(GetPhoneNumberOrderError::Forbidden(err.msg))
+                    return RusotoError::service(GetPhoneNumberOrderError::Forbidden(err.msg), &res)
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetPhoneNumberOrderError::NotFound(err.msg))
+                    return RusotoError::service(GetPhoneNumberOrderError::NotFound(err.msg), &res)
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(GetPhoneNumberOrderError::ServiceFailure(err.msg))
+                    return RusotoError::service(
+                        GetPhoneNumberOrderError::ServiceFailure(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(GetPhoneNumberOrderError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetPhoneNumberOrderError::ServiceUnavailable(err.msg),
+                        &res,
+                    )
                 }
                 "ThrottledClientException" => {
-                    return RusotoError::Service(GetPhoneNumberOrderError::ThrottledClient(err.msg))
+                    return RusotoError::service(
+                        GetPhoneNumberOrderError::ThrottledClient(err.msg),
+                        &res,
+                    )
                 }
                 "UnauthorizedClientException" => {
-                    return RusotoError::Service(GetPhoneNumberOrderError::UnauthorizedClient(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetPhoneNumberOrderError::UnauthorizedClient(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetPhoneNumberOrderError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -6452,12 +7116,14 @@ impl fmt::Display for GetPhoneNumberOrderError {
             GetPhoneNumberOrderError::ServiceUnavailable(ref cause) => write!(f, "{}", cause),
             GetPhoneNumberOrderError::ThrottledClient(ref cause) => write!(f, "{}", cause),
             GetPhoneNumberOrderError::UnauthorizedClient(ref cause) => write!(f, "{}", cause),
+            GetPhoneNumberOrderError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetPhoneNumberOrderError {}
 /// Errors returned by GetPhoneNumberSettings
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetPhoneNumberSettingsError {
     /// <p>The input parameters don't match the service's restrictions.</p>
     BadRequest(String),
@@ -6471,6 +7137,8 @@ pub enum GetPhoneNumberSettingsError {
     ThrottledClient(String),
     /// <p>The client is not currently authorized to make the request.</p>
     UnauthorizedClient(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetPhoneNumberSettingsError {
@@ -6478,33 +7146,43 @@ impl GetPhoneNumberSettingsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetPhoneNumberSettingsError::BadRequest(err.msg))
+                    return RusotoError::service(
+                        GetPhoneNumberSettingsError::BadRequest(err.msg),
+                        &res,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(GetPhoneNumberSettingsError::Forbidden(err.msg))
+                    return RusotoError::service(
+                        GetPhoneNumberSettingsError::Forbidden(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(GetPhoneNumberSettingsError::ServiceFailure(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetPhoneNumberSettingsError::ServiceFailure(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(GetPhoneNumberSettingsError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetPhoneNumberSettingsError::ServiceUnavailable(err.msg),
+                        &res,
+                    )
                 }
                 "ThrottledClientException" => {
-                    return RusotoError::Service(GetPhoneNumberSettingsError::ThrottledClient(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetPhoneNumberSettingsError::ThrottledClient(err.msg),
+                        &res,
+                    )
                 }
                 "UnauthorizedClientException" => {
-                    return RusotoError::Service(GetPhoneNumberSettingsError::UnauthorizedClient(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetPhoneNumberSettingsError::UnauthorizedClient(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetPhoneNumberSettingsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -6520,12 +7198,14 @@ impl fmt::Display for GetPhoneNumberSettingsError {
             GetPhoneNumberSettingsError::ServiceUnavailable(ref cause) => write!(f, "{}", cause),
             GetPhoneNumberSettingsError::ThrottledClient(ref cause) => write!(f, "{}", cause),
             GetPhoneNumberSettingsError::UnauthorizedClient(ref cause) => write!(f, "{}", cause),
+            GetPhoneNumberSettingsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetPhoneNumberSettingsError {}
 /// Errors returned by GetRoom
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetRoomError {
     /// <p>The input parameters don't match the service's restrictions.</p>
     BadRequest(String),
@@ -6541,6 +7221,8 @@ pub enum GetRoomError {
     ThrottledClient(String),
     /// <p>The client is not currently authorized to make the request.</p>
     UnauthorizedClient(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetRoomError {
@@ -6548,28 +7230,28 @@ impl GetRoomError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetRoomError::BadRequest(err.msg))
+                    return RusotoError::service(GetRoomError::BadRequest(err.msg), &res)
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(GetRoomError::Forbidden(err.msg))
+                    return RusotoError::service(GetRoomError::Forbidden(err.msg), &res)
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetRoomError::NotFound(err.msg))
+                    return RusotoError::service(GetRoomError::NotFound(err.msg), &res)
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(GetRoomError::ServiceFailure(err.msg))
+                    return RusotoError::service(GetRoomError::ServiceFailure(err.msg), &res)
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(GetRoomError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(GetRoomError::ServiceUnavailable(err.msg), &res)
                 }
                 "ThrottledClientException" => {
-                    return RusotoError::Service(GetRoomError::ThrottledClient(err.msg))
+                    return RusotoError::service(GetRoomError::ThrottledClient(err.msg), &res)
                 }
                 "UnauthorizedClientException" => {
-                    return RusotoError::Service(GetRoomError::UnauthorizedClient(err.msg))
+                    return RusotoError::service(GetRoomError::UnauthorizedClient(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetRoomError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -6586,12 +7268,14 @@ impl fmt::Display for GetRoomError {
             GetRoomError::ServiceUnavailable(ref cause) => write!(f, "{}", cause),
             GetRoomError::ThrottledClient(ref cause) => write!(f, "{}", cause),
             GetRoomError::UnauthorizedClient(ref cause) => write!(f, "{}", cause),
+            GetRoomError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetRoomError {}
 /// Errors returned by GetUser
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetUserError {
     /// <p>The input parameters don't match the service's restrictions.</p>
     BadRequest(String),
@@ -6607,6 +7291,8 @@ pub enum GetUserError {
     ThrottledClient(String),
     /// <p>The client is not currently authorized to make the request.</p>
     UnauthorizedClient(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetUserError {
@@ -6614,28 +7300,28 @@ impl GetUserError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetUserError::BadRequest(err.msg))
+                    return RusotoError::service(GetUserError::BadRequest(err.msg), &res)
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(GetUserError::Forbidden(err.msg))
+                    return RusotoError::service(GetUserError::Forbidden(err.msg), &res)
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetUserError::NotFound(err.msg))
+                    return RusotoError::service(GetUserError::NotFound(err.msg), &res)
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(GetUserError::ServiceFailure(err.msg))
+                    return RusotoError::service(GetUserError::ServiceFailure(err.msg), &res)
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(GetUserError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(GetUserError::ServiceUnavailable(err.msg), &res)
                 }
                 "ThrottledClientException" => {
-                    return RusotoError::Service(GetUserError::ThrottledClient(err.msg))
+                    return RusotoError::service(GetUserError::ThrottledClient(err.msg), &res)
                 }
                 "UnauthorizedClientException" => {
-                    return RusotoError::Service(GetUserError::UnauthorizedClient(err.msg))
+                    return RusotoError::service(GetUserError::UnauthorizedClient(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetUserError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -6652,12 +7338,14 @@ impl fmt::Display for GetUserError {
             GetUserError::ServiceUnavailable(ref cause) => write!(f, "{}", cause),
             GetUserError::ThrottledClient(ref cause) => write!(f, "{}", cause),
             GetUserError::UnauthorizedClient(ref cause) => write!(f, "{}", cause),
+            GetUserError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetUserError {}
 /// Errors returned by GetUserSettings
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetUserSettingsError {
     /// <p>The input parameters don't match the service's restrictions.</p>
     BadRequest(String),
@@ -6673,6 +7361,8 @@ pub enum GetUserSettingsError {
     ThrottledClient(String),
     /// <p>The client is not currently authorized to make the request.</p>
     UnauthorizedClient(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetUserSettingsError {
@@ -6680,28 +7370,40 @@ impl GetUserSettingsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetUserSettingsError::BadRequest(err.msg))
+                    return RusotoError::service(GetUserSettingsError::BadRequest(err.msg), &res)
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(GetUserSettingsError::Forbidden(err.msg))
+                    return RusotoError::service(GetUserSettingsError::Forbidden(err.msg), &res)
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetUserSettingsError::NotFound(err.msg))
+                    return RusotoError::service(GetUserSettingsError::NotFound(err.msg), &res)
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(GetUserSettingsError::ServiceFailure(err.msg))
+                    return RusotoError::service(
+                        GetUserSettingsError::ServiceFailure(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(GetUserSettingsError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        GetUserSettingsError::ServiceUnavailable(err.msg),
+                        &res,
+                    )
                 }
                 "ThrottledClientException" => {
-                    return RusotoError::Service(GetUserSettingsError::ThrottledClient(err.msg))
+                    return RusotoError::service(
+                        GetUserSettingsError::ThrottledClient(err.msg),
+                        &res,
+                    )
                 }
                 "UnauthorizedClientException" => {
-                    return RusotoError::Service(GetUserSettingsError::UnauthorizedClient(err.msg))
+                    return RusotoError::service(
+                        GetUserSettingsError::UnauthorizedClient(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetUserSettingsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -6718,12 +7420,14 @@ impl fmt::Display for GetUserSettingsError {
             GetUserSettingsError::ServiceUnavailable(ref cause) => write!(f, "{}", cause),
             GetUserSettingsError::ThrottledClient(ref cause) => write!(f, "{}", cause),
             GetUserSettingsError::UnauthorizedClient(ref cause) => write!(f, "{}", cause),
+            GetUserSettingsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetUserSettingsError {}
 /// Errors returned by GetVoiceConnector
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetVoiceConnectorError {
     /// <p>The input parameters don't match the service's restrictions.</p>
     BadRequest(String),
@@ -6739,6 +7443,8 @@ pub enum GetVoiceConnectorError {
     ThrottledClient(String),
     /// <p>The client is not currently authorized to make the request.</p>
     UnauthorizedClient(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetVoiceConnectorError {
@@ -6746,32 +7452,40 @@ impl GetVoiceConnectorError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetVoiceConnectorError::BadRequest(err.msg))
+                    return RusotoError::service(GetVoiceConnectorError::BadRequest(err.msg), &res)
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(GetVoiceConnectorError::Forbidden(err.msg))
+                    return RusotoError::service(GetVoiceConnectorError::Forbidden(err.msg), &res)
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetVoiceConnectorError::NotFound(err.msg))
+                    return RusotoError::service(GetVoiceConnectorError::NotFound(err.msg), &res)
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(GetVoiceConnectorError::ServiceFailure(err.msg))
+                    return RusotoError::service(
+                        GetVoiceConnectorError::ServiceFailure(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(GetVoiceConnectorError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetVoiceConnectorError::ServiceUnavailable(err.msg),
+                        &res,
+                    )
                 }
                 "ThrottledClientException" => {
-                    return RusotoError::Service(GetVoiceConnectorError::ThrottledClient(err.msg))
+                    return RusotoError::service(
+                        GetVoiceConnectorError::ThrottledClient(err.msg),
+                        &res,
+                    )
                 }
                 "UnauthorizedClientException" => {
-                    return RusotoError::Service(GetVoiceConnectorError::UnauthorizedClient(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetVoiceConnectorError::UnauthorizedClient(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetVoiceConnectorError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -6788,12 +7502,14 @@ impl fmt::Display for GetVoiceConnectorError {
             GetVoiceConnectorError::ServiceUnavailable(ref cause) => write!(f, "{}", cause),
             GetVoiceConnectorError::ThrottledClient(ref cause) => write!(f, "{}", cause),
             GetVoiceConnectorError::UnauthorizedClient(ref cause) => write!(f, "{}", cause),
+            GetVoiceConnectorError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetVoiceConnectorError {}
 /// Errors returned by GetVoiceConnectorGroup
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetVoiceConnectorGroupError {
     /// <p>The input parameters don't match the service's restrictions.</p>
     BadRequest(String),
@@ -6809,6 +7525,8 @@ pub enum GetVoiceConnectorGroupError {
     ThrottledClient(String),
     /// <p>The client is not currently authorized to make the request.</p>
     UnauthorizedClient(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetVoiceConnectorGroupError {
@@ -6816,36 +7534,49 @@ impl GetVoiceConnectorGroupError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetVoiceConnectorGroupError::BadRequest(err.msg))
+                    return RusotoError::service(
+                        GetVoiceConnectorGroupError::BadRequest(err.msg),
+                        &res,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(GetVoiceConnectorGroupError::Forbidden(err.msg))
+                    return RusotoError::service(
+                        GetVoiceConnectorGroupError::Forbidden(err.msg),
+                        &res,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetVoiceConnectorGroupError::NotFound(err.msg))
+                    return RusotoError::service(
+                        GetVoiceConnectorGroupError::NotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(GetVoiceConnectorGroupError::ServiceFailure(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetVoiceConnectorGroupError::ServiceFailure(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(GetVoiceConnectorGroupError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetVoiceConnectorGroupError::ServiceUnavailable(err.msg),
+                        &res,
+                    )
                 }
                 "ThrottledClientException" => {
-                    return RusotoError::Service(GetVoiceConnectorGroupError::ThrottledClient(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetVoiceConnectorGroupError::ThrottledClient(err.msg),
+                        &res,
+                    )
                 }
                 "UnauthorizedClientException" => {
-                    return RusotoError::Service(GetVoiceConnectorGroupError::UnauthorizedClient(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetVoiceConnectorGroupError::UnauthorizedClient(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetVoiceConnectorGroupError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -6862,12 +7593,14 @@ impl fmt::Display for GetVoiceConnectorGroupError {
             GetVoiceConnectorGroupError::ServiceUnavailable(ref cause) => write!(f, "{}", cause),
             GetVoiceConnectorGroupError::ThrottledClient(ref cause) => write!(f, "{}", cause),
             GetVoiceConnectorGroupError::UnauthorizedClient(ref cause) => write!(f, "{}", cause),
+            GetVoiceConnectorGroupError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetVoiceConnectorGroupError {}
 /// Errors returned by GetVoiceConnectorLoggingConfiguration
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetVoiceConnectorLoggingConfigurationError {
     /// <p>The input parameters don't match the service's restrictions.</p>
     BadRequest(String),
@@ -6883,6 +7616,8 @@ pub enum GetVoiceConnectorLoggingConfigurationError {
     ThrottledClient(String),
     /// <p>The client is not currently authorized to make the request.</p>
     UnauthorizedClient(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetVoiceConnectorLoggingConfigurationError {
@@ -6892,42 +7627,54 @@ impl GetVoiceConnectorLoggingConfigurationError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         GetVoiceConnectorLoggingConfigurationError::BadRequest(err.msg),
+                        &res,
                     )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         GetVoiceConnectorLoggingConfigurationError::Forbidden(err.msg),
+                        &res,
                     )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         GetVoiceConnectorLoggingConfigurationError::NotFound(err.msg),
+                        &res,
                     )
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         GetVoiceConnectorLoggingConfigurationError::ServiceFailure(err.msg),
+                        &res,
                     )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         GetVoiceConnectorLoggingConfigurationError::ServiceUnavailable(err.msg),
+                        &res,
                     )
                 }
                 "ThrottledClientException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         GetVoiceConnectorLoggingConfigurationError::ThrottledClient(err.msg),
+                        &res,
                     )
                 }
                 "UnauthorizedClientException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         GetVoiceConnectorLoggingConfigurationError::UnauthorizedClient(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        GetVoiceConnectorLoggingConfigurationError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -6958,12 +7705,16 @@ impl fmt::Display for GetVoiceConnectorLoggingConfigurationError {
             GetVoiceConnectorLoggingConfigurationError::UnauthorizedClient(ref cause) => {
                 write!(f, "{}", cause)
             }
+            GetVoiceConnectorLoggingConfigurationError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for GetVoiceConnectorLoggingConfigurationError {}
 /// Errors returned by GetVoiceConnectorOrigination
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetVoiceConnectorOriginationError {
     /// <p>The input parameters don't match the service's restrictions.</p>
     BadRequest(String),
@@ -6979,6 +7730,8 @@ pub enum GetVoiceConnectorOriginationError {
     ThrottledClient(String),
     /// <p>The client is not currently authorized to make the request.</p>
     UnauthorizedClient(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetVoiceConnectorOriginationError {
@@ -6988,42 +7741,54 @@ impl GetVoiceConnectorOriginationError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetVoiceConnectorOriginationError::BadRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetVoiceConnectorOriginationError::BadRequest(err.msg),
+                        &res,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(GetVoiceConnectorOriginationError::Forbidden(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetVoiceConnectorOriginationError::Forbidden(err.msg),
+                        &res,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetVoiceConnectorOriginationError::NotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetVoiceConnectorOriginationError::NotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(GetVoiceConnectorOriginationError::ServiceFailure(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetVoiceConnectorOriginationError::ServiceFailure(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         GetVoiceConnectorOriginationError::ServiceUnavailable(err.msg),
+                        &res,
                     )
                 }
                 "ThrottledClientException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         GetVoiceConnectorOriginationError::ThrottledClient(err.msg),
+                        &res,
                     )
                 }
                 "UnauthorizedClientException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         GetVoiceConnectorOriginationError::UnauthorizedClient(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        GetVoiceConnectorOriginationError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -7044,12 +7809,14 @@ impl fmt::Display for GetVoiceConnectorOriginationError {
             GetVoiceConnectorOriginationError::UnauthorizedClient(ref cause) => {
                 write!(f, "{}", cause)
             }
+            GetVoiceConnectorOriginationError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetVoiceConnectorOriginationError {}
 /// Errors returned by GetVoiceConnectorStreamingConfiguration
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetVoiceConnectorStreamingConfigurationError {
     /// <p>The input parameters don't match the service's restrictions.</p>
     BadRequest(String),
@@ -7065,6 +7832,8 @@ pub enum GetVoiceConnectorStreamingConfigurationError {
     ThrottledClient(String),
     /// <p>The client is not currently authorized to make the request.</p>
     UnauthorizedClient(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetVoiceConnectorStreamingConfigurationError {
@@ -7074,42 +7843,54 @@ impl GetVoiceConnectorStreamingConfigurationError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         GetVoiceConnectorStreamingConfigurationError::BadRequest(err.msg),
+                        &res,
                     )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         GetVoiceConnectorStreamingConfigurationError::Forbidden(err.msg),
+                        &res,
                     )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         GetVoiceConnectorStreamingConfigurationError::NotFound(err.msg),
+                        &res,
                     )
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         GetVoiceConnectorStreamingConfigurationError::ServiceFailure(err.msg),
+                        &res,
                     )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         GetVoiceConnectorStreamingConfigurationError::ServiceUnavailable(err.msg),
+                        &res,
                     )
                 }
                 "ThrottledClientException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         GetVoiceConnectorStreamingConfigurationError::ThrottledClient(err.msg),
+                        &res,
                     )
                 }
                 "UnauthorizedClientException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         GetVoiceConnectorStreamingConfigurationError::UnauthorizedClient(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        GetVoiceConnectorStreamingConfigurationError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -7140,12 +7921,16 @@ impl fmt::Display for GetVoiceConnectorStreamingConfigurationError {
             GetVoiceConnectorStreamingConfigurationError::UnauthorizedClient(ref cause) => {
                 write!(f, "{}", cause)
             }
+            GetVoiceConnectorStreamingConfigurationError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for GetVoiceConnectorStreamingConfigurationError {}
 /// Errors returned by GetVoiceConnectorTermination
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetVoiceConnectorTerminationError {
     /// <p>The input parameters don't match the service's restrictions.</p>
     BadRequest(String),
@@ -7161,6 +7946,8 @@ pub enum GetVoiceConnectorTerminationError {
     ThrottledClient(String),
     /// <p>The client is not currently authorized to make the request.</p>
     UnauthorizedClient(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetVoiceConnectorTerminationError {
@@ -7170,42 +7957,54 @@ impl GetVoiceConnectorTerminationError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetVoiceConnectorTerminationError::BadRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetVoiceConnectorTerminationError::BadRequest(err.msg),
+                        &res,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(GetVoiceConnectorTerminationError::Forbidden(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetVoiceConnectorTerminationError::Forbidden(err.msg),
+                        &res,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetVoiceConnectorTerminationError::NotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetVoiceConnectorTerminationError::NotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(GetVoiceConnectorTerminationError::ServiceFailure(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetVoiceConnectorTerminationError::ServiceFailure(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         GetVoiceConnectorTerminationError::ServiceUnavailable(err.msg),
+                        &res,
                     )
                 }
                 "ThrottledClientException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         GetVoiceConnectorTerminationError::ThrottledClient(err.msg),
+                        &res,
                     )
                 }
                 "UnauthorizedClientException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         GetVoiceConnectorTerminationError::UnauthorizedClient(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        GetVoiceConnectorTerminationError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -7226,12 +8025,14 @@ impl fmt::Display for GetVoiceConnectorTerminationError {
             GetVoiceConnectorTerminationError::UnauthorizedClient(ref cause) => {
                 write!(f, "{}", cause)
             }
+            GetVoiceConnectorTerminationError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetVoiceConnectorTerminationError {}
 /// Errors returned by GetVoiceConnectorTerminationHealth
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetVoiceConnectorTerminationHealthError {
     /// <p>The input parameters don't match the service's restrictions.</p>
     BadRequest(String),
@@ -7247,6 +8048,8 @@ pub enum GetVoiceConnectorTerminationHealthError {
     ThrottledClient(String),
     /// <p>The client is not currently authorized to make the request.</p>
     UnauthorizedClient(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetVoiceConnectorTerminationHealthError {
@@ -7256,42 +8059,54 @@ impl GetVoiceConnectorTerminationHealthError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         GetVoiceConnectorTerminationHealthError::BadRequest(err.msg),
+                        &res,
                     )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         GetVoiceConnectorTerminationHealthError::Forbidden(err.msg),
+                        &res,
                     )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetVoiceConnectorTerminationHealthError::NotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetVoiceConnectorTerminationHealthError::NotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         GetVoiceConnectorTerminationHealthError::ServiceFailure(err.msg),
+                        &res,
                     )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         GetVoiceConnectorTerminationHealthError::ServiceUnavailable(err.msg),
+                        &res,
                     )
                 }
                 "ThrottledClientException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         GetVoiceConnectorTerminationHealthError::ThrottledClient(err.msg),
+                        &res,
                     )
                 }
                 "UnauthorizedClientException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         GetVoiceConnectorTerminationHealthError::UnauthorizedClient(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        GetVoiceConnectorTerminationHealthError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -7318,12 +8133,16 @@ impl fmt::Display for GetVoiceConnectorTerminationHealthError {
             GetVoiceConnectorTerminationHealthError::UnauthorizedClient(ref cause) => {
                 write!(f, "{}", cause)
             }
+            GetVoiceConnectorTerminationHealthError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for GetVoiceConnectorTerminationHealthError {}
 /// Errors returned by InviteUsers
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum InviteUsersError {
     /// <p>The input parameters don't match the service's restrictions.</p>
     BadRequest(String),
@@ -7339,6 +8158,8 @@ pub enum InviteUsersError {
     ThrottledClient(String),
     /// <p>The client is not currently authorized to make the request.</p>
     UnauthorizedClient(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl InviteUsersError {
@@ -7346,28 +8167,34 @@ impl InviteUsersError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(InviteUsersError::BadRequest(err.msg))
+                    return RusotoError::service(InviteUsersError::BadRequest(err.msg), &res)
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(InviteUsersError::Forbidden(err.msg))
+                    return RusotoError::service(InviteUsersError::Forbidden(err.msg), &res)
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(InviteUsersError::NotFound(err.msg))
+                    return RusotoError::service(InviteUsersError::NotFound(err.msg), &res)
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(InviteUsersError::ServiceFailure(err.msg))
+                    return RusotoError::service(InviteUsersError::ServiceFailure(err.msg), &res)
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(InviteUsersError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        InviteUsersError::ServiceUnavailable(err.msg),
+                        &res,
+                    )
                 }
                 "ThrottledClientException" => {
-                    return RusotoError::Service(InviteUsersError::ThrottledClient(err.msg))
+                    return RusotoError::service(InviteUsersError::ThrottledClient(err.msg), &res)
                 }
                 "UnauthorizedClientException" => {
-                    return RusotoError::Service(InviteUsersError::UnauthorizedClient(err.msg))
+                    return RusotoError::service(
+                        InviteUsersError::UnauthorizedClient(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(InviteUsersError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -7384,12 +8211,14 @@ impl fmt::Display for InviteUsersError {
             InviteUsersError::ServiceUnavailable(ref cause) => write!(f, "{}", cause),
             InviteUsersError::ThrottledClient(ref cause) => write!(f, "{}", cause),
             InviteUsersError::UnauthorizedClient(ref cause) => write!(f, "{}", cause),
+            InviteUsersError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for InviteUsersError {}
 /// Errors returned by ListAccounts
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListAccountsError {
     /// <p>The input parameters don't match the service's restrictions.</p>
     BadRequest(String),
@@ -7405,6 +8234,8 @@ pub enum ListAccountsError {
     ThrottledClient(String),
     /// <p>The client is not currently authorized to make the request.</p>
     UnauthorizedClient(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListAccountsError {
@@ -7412,28 +8243,34 @@ impl ListAccountsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(ListAccountsError::BadRequest(err.msg))
+                    return RusotoError::service(ListAccountsError::BadRequest(err.msg), &res)
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(ListAccountsError::Forbidden(err.msg))
+                    return RusotoError::service(ListAccountsError::Forbidden(err.msg), &res)
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(ListAccountsError::NotFound(err.msg))
+                    return RusotoError::service(ListAccountsError::NotFound(err.msg), &res)
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(ListAccountsError::ServiceFailure(err.msg))
+                    return RusotoError::service(ListAccountsError::ServiceFailure(err.msg), &res)
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(ListAccountsError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        ListAccountsError::ServiceUnavailable(err.msg),
+                        &res,
+                    )
                 }
                 "ThrottledClientException" => {
-                    return RusotoError::Service(ListAccountsError::ThrottledClient(err.msg))
+                    return RusotoError::service(ListAccountsError::ThrottledClient(err.msg), &res)
                 }
                 "UnauthorizedClientException" => {
-                    return RusotoError::Service(ListAccountsError::UnauthorizedClient(err.msg))
+                    return RusotoError::service(
+                        ListAccountsError::UnauthorizedClient(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListAccountsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -7450,12 +8287,14 @@ impl fmt::Display for ListAccountsError {
             ListAccountsError::ServiceUnavailable(ref cause) => write!(f, "{}", cause),
             ListAccountsError::ThrottledClient(ref cause) => write!(f, "{}", cause),
             ListAccountsError::UnauthorizedClient(ref cause) => write!(f, "{}", cause),
+            ListAccountsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListAccountsError {}
 /// Errors returned by ListAttendees
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListAttendeesError {
     /// <p>The input parameters don't match the service's restrictions.</p>
     BadRequest(String),
@@ -7471,6 +8310,8 @@ pub enum ListAttendeesError {
     ThrottledClient(String),
     /// <p>The client is not currently authorized to make the request.</p>
     UnauthorizedClient(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListAttendeesError {
@@ -7478,28 +8319,34 @@ impl ListAttendeesError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(ListAttendeesError::BadRequest(err.msg))
+                    return RusotoError::service(ListAttendeesError::BadRequest(err.msg), &res)
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(ListAttendeesError::Forbidden(err.msg))
+                    return RusotoError::service(ListAttendeesError::Forbidden(err.msg), &res)
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(ListAttendeesError::NotFound(err.msg))
+                    return RusotoError::service(ListAttendeesError::NotFound(err.msg), &res)
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(ListAttendeesError::ServiceFailure(err.msg))
+                    return RusotoError::service(ListAttendeesError::ServiceFailure(err.msg), &res)
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(ListAttendeesError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        ListAttendeesError::ServiceUnavailable(err.msg),
+                        &res,
+                    )
                 }
                 "ThrottledClientException" => {
-                    return RusotoError::Service(ListAttendeesError::ThrottledClient(err.msg))
+                    return RusotoError::service(ListAttendeesError::ThrottledClient(err.msg), &res)
                 }
                 "UnauthorizedClientException" => {
-                    return RusotoError::Service(ListAttendeesError::UnauthorizedClient(err.msg))
+                    return RusotoError::service(
+                        ListAttendeesError::UnauthorizedClient(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListAttendeesError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -7516,12 +8363,14 @@ impl fmt::Display for ListAttendeesError {
             ListAttendeesError::ServiceUnavailable(ref cause) => write!(f, "{}", cause),
             ListAttendeesError::ThrottledClient(ref cause) => write!(f, "{}", cause),
             ListAttendeesError::UnauthorizedClient(ref cause) => write!(f, "{}", cause),
+            ListAttendeesError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListAttendeesError {}
 /// Errors returned by ListBots
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListBotsError {
     /// <p>The input parameters don't match the service's restrictions.</p>
     BadRequest(String),
@@ -7537,6 +8386,8 @@ pub enum ListBotsError {
     ThrottledClient(String),
     /// <p>The client is not currently authorized to make the request.</p>
     UnauthorizedClient(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListBotsError {
@@ -7544,28 +8395,28 @@ impl ListBotsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(ListBotsError::BadRequest(err.msg))
+                    return RusotoError::service(ListBotsError::BadRequest(err.msg), &res)
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(ListBotsError::Forbidden(err.msg))
+                    return RusotoError::service(ListBotsError::Forbidden(err.msg), &res)
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(ListBotsError::NotFound(err.msg))
+                    return RusotoError::service(ListBotsError::NotFound(err.msg), &res)
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(ListBotsError::ServiceFailure(err.msg))
+                    return RusotoError::service(ListBotsError::ServiceFailure(err.msg), &res)
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(ListBotsError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(ListBotsError::ServiceUnavailable(err.msg), &res)
                 }
                 "ThrottledClientException" => {
-                    return RusotoError::Service(ListBotsError::ThrottledClient(err.msg))
+                    return RusotoError::service(ListBotsError::ThrottledClient(err.msg), &res)
                 }
                 "UnauthorizedClientException" => {
-                    return RusotoError::Service(ListBotsError::UnauthorizedClient(err.msg))
+                    return RusotoError::service(ListBotsError::UnauthorizedClient(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListBotsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -7582,12 +8433,14 @@ impl fmt::Display for ListBotsError {
             ListBotsError::ServiceUnavailable(ref cause) => write!(f, "{}", cause),
             ListBotsError::ThrottledClient(ref cause) => write!(f, "{}", cause),
             ListBotsError::UnauthorizedClient(ref cause) => write!(f, "{}", cause),
+            ListBotsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListBotsError {}
 /// Errors returned by ListMeetings
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListMeetingsError {
     /// <p>The input parameters don't match the service's restrictions.</p>
     BadRequest(String),
@@ -7601,6 +8454,8 @@ pub enum ListMeetingsError {
     ThrottledClient(String),
     /// <p>The client is not currently authorized to make the request.</p>
     UnauthorizedClient(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListMeetingsError {
@@ -7608,25 +8463,31 @@ impl ListMeetingsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(ListMeetingsError::BadRequest(err.msg))
+                    return RusotoError::service(ListMeetingsError::BadRequest(err.msg), &res)
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(ListMeetingsError::Forbidden(err.msg))
+                    return RusotoError::service(ListMeetingsError::Forbidden(err.msg), &res)
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(ListMeetingsError::ServiceFailure(err.msg))
+                    return RusotoError::service(ListMeetingsError::ServiceFailure(err.msg), &res)
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(ListMeetingsError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        ListMeetingsError::ServiceUnavailable(err.msg),
+                        &res,
+                    )
                 }
                 "ThrottledClientException" => {
-                    return RusotoError::Service(ListMeetingsError::ThrottledClient(err.msg))
+                    return RusotoError::service(ListMeetingsError::ThrottledClient(err.msg), &res)
                 }
                 "UnauthorizedClientException" => {
-                    return RusotoError::Service(ListMeetingsError::UnauthorizedClient(err.msg))
+                    return RusotoError::service(
+                        ListMeetingsError::UnauthorizedClient(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListMeetingsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -7642,12 +8503,14 @@ impl fmt::Display for ListMeetingsError {
             ListMeetingsError::ServiceUnavailable(ref cause) => write!(f, "{}", cause),
             ListMeetingsError::ThrottledClient(ref cause) => write!(f, "{}", cause),
             ListMeetingsError::UnauthorizedClient(ref cause) => write!(f, "{}", cause),
+            ListMeetingsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListMeetingsError {}
 /// Errors returned by ListPhoneNumberOrders
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListPhoneNumberOrdersError {
     /// <p>The input parameters don't match the service's restrictions.</p>
     BadRequest(String),
@@ -7661,6 +8524,8 @@ pub enum ListPhoneNumberOrdersError {
     ThrottledClient(String),
     /// <p>The client is not currently authorized to make the request.</p>
     UnauthorizedClient(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListPhoneNumberOrdersError {
@@ -7668,33 +8533,43 @@ impl ListPhoneNumberOrdersError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(ListPhoneNumberOrdersError::BadRequest(err.msg))
+                    return RusotoError::service(
+                        ListPhoneNumberOrdersError::BadRequest(err.msg),
+                        &res,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(ListPhoneNumberOrdersError::Forbidden(err.msg))
+                    return RusotoError::service(
+                        ListPhoneNumberOrdersError::Forbidden(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(ListPhoneNumberOrdersError::ServiceFailure(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListPhoneNumberOrdersError::ServiceFailure(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(ListPhoneNumberOrdersError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListPhoneNumberOrdersError::ServiceUnavailable(err.msg),
+                        &res,
+                    )
                 }
                 "ThrottledClientException" => {
-                    return RusotoError::Service(ListPhoneNumberOrdersError::ThrottledClient(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListPhoneNumberOrdersError::ThrottledClient(err.msg),
+                        &res,
+                    )
                 }
                 "UnauthorizedClientException" => {
-                    return RusotoError::Service(ListPhoneNumberOrdersError::UnauthorizedClient(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListPhoneNumberOrdersError::UnauthorizedClient(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListPhoneNumberOrdersError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -7710,12 +8585,14 @@ impl fmt::Display for ListPhoneNumberOrdersError {
             ListPhoneNumberOrdersError::ServiceUnavailable(ref cause) => write!(f, "{}", cause),
             ListPhoneNumberOrdersError::ThrottledClient(ref cause) => write!(f, "{}", cause),
             ListPhoneNumberOrdersError::UnauthorizedClient(ref cause) => write!(f, "{}", cause),
+            ListPhoneNumberOrdersError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListPhoneNumberOrdersError {}
 /// Errors returned by ListPhoneNumbers
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListPhoneNumbersError {
     /// <p>The input parameters don't match the service's restrictions.</p>
     BadRequest(String),
@@ -7729,6 +8606,8 @@ pub enum ListPhoneNumbersError {
     ThrottledClient(String),
     /// <p>The client is not currently authorized to make the request.</p>
     UnauthorizedClient(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListPhoneNumbersError {
@@ -7736,25 +8615,37 @@ impl ListPhoneNumbersError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(ListPhoneNumbersError::BadRequest(err.msg))
+                    return RusotoError::service(ListPhoneNumbersError::BadRequest(err.msg), &res)
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(ListPhoneNumbersError::Forbidden(err.msg))
+                    return RusotoError::service(ListPhoneNumbersError::Forbidden(err.msg), &res)
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(ListPhoneNumbersError::ServiceFailure(err.msg))
+                    return RusotoError::service(
+                        ListPhoneNumbersError::ServiceFailure(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(ListPhoneNumbersError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        ListPhoneNumbersError::ServiceUnavailable(err.msg),
+                        &res,
+                    )
                 }
                 "ThrottledClientException" => {
-                    return RusotoError::Service(ListPhoneNumbersError::ThrottledClient(err.msg))
+                    return RusotoError::service(
+                        ListPhoneNumbersError::ThrottledClient(err.msg),
+                        &res,
+                    )
                 }
                 "UnauthorizedClientException" => {
-                    return RusotoError::Service(ListPhoneNumbersError::UnauthorizedClient(err.msg))
+                    return RusotoError::service(
+                        ListPhoneNumbersError::UnauthorizedClient(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListPhoneNumbersError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -7770,12 +8661,14 @@ impl fmt::Display for ListPhoneNumbersError {
             ListPhoneNumbersError::ServiceUnavailable(ref cause) => write!(f, "{}", cause),
             ListPhoneNumbersError::ThrottledClient(ref cause) => write!(f, "{}", cause),
             ListPhoneNumbersError::UnauthorizedClient(ref cause) => write!(f, "{}", cause),
+            ListPhoneNumbersError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListPhoneNumbersError {}
 /// Errors returned by ListRoomMemberships
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListRoomMembershipsError {
     /// <p>The input parameters don't match the service's restrictions.</p>
     BadRequest(String),
@@ -7791,6 +8684,8 @@ pub enum ListRoomMembershipsError {
     ThrottledClient(String),
     /// <p>The client is not currently authorized to make the request.</p>
     UnauthorizedClient(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListRoomMembershipsError {
@@ -7798,32 +8693,43 @@ impl ListRoomMembershipsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(ListRoomMembershipsError::BadRequest(err.msg))
+                    return RusotoError::service(
+                        ListRoomMembershipsError::BadRequest(err.msg),
+                        &res,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(ListRoomMembershipsError::Forbidden(err.msg))
+                    return RusotoError::service(ListRoomMembershipsError::Forbidden(err.msg), &res)
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(ListRoomMembershipsError::NotFound(err.msg))
+                    return RusotoError::service(ListRoomMembershipsError::NotFound(err.msg), &res)
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(ListRoomMembershipsError::ServiceFailure(err.msg))
+                    return RusotoError::service(
+                        ListRoomMembershipsError::ServiceFailure(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(ListRoomMembershipsError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListRoomMembershipsError::ServiceUnavailable(err.msg),
+                        &res,
+                    )
                 }
                 "ThrottledClientException" => {
-                    return RusotoError::Service(ListRoomMembershipsError::ThrottledClient(err.msg))
+                    return RusotoError::service(
+                        ListRoomMembershipsError::ThrottledClient(err.msg),
+                        &res,
+                    )
                 }
                 "UnauthorizedClientException" => {
-                    return RusotoError::Service(ListRoomMembershipsError::UnauthorizedClient(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListRoomMembershipsError::UnauthorizedClient(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListRoomMembershipsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -7840,12 +8746,14 @@ impl fmt::Display for ListRoomMembershipsError {
             ListRoomMembershipsError::ServiceUnavailable(ref cause) => write!(f, "{}", cause),
             ListRoomMembershipsError::ThrottledClient(ref cause) => write!(f, "{}", cause),
             ListRoomMembershipsError::UnauthorizedClient(ref cause) => write!(f, "{}", cause),
+            ListRoomMembershipsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListRoomMembershipsError {}
 /// Errors returned by ListRooms
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListRoomsError {
     /// <p>The input parameters don't match the service's restrictions.</p>
     BadRequest(String),
@@ -7861,6 +8769,8 @@ pub enum ListRoomsError {
     ThrottledClient(String),
     /// <p>The client is not currently authorized to make the request.</p>
     UnauthorizedClient(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListRoomsError {
@@ -7868,28 +8778,28 @@ impl ListRoomsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(ListRoomsError::BadRequest(err.msg))
+                    return RusotoError::service(ListRoomsError::BadRequest(err.msg), &res)
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(ListRoomsError::Forbidden(err.msg))
+                    return RusotoError::service(ListRoomsError::Forbidden(err.msg), &res)
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(ListRoomsError::NotFound(err.msg))
+                    return RusotoError::service(ListRoomsError::NotFound(err.msg), &res)
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(ListRoomsError::ServiceFailure(err.msg))
+                    return RusotoError::service(ListRoomsError::ServiceFailure(err.msg), &res)
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(ListRoomsError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(ListRoomsError::ServiceUnavailable(err.msg), &res)
                 }
                 "ThrottledClientException" => {
-                    return RusotoError::Service(ListRoomsError::ThrottledClient(err.msg))
+                    return RusotoError::service(ListRoomsError::ThrottledClient(err.msg), &res)
                 }
                 "UnauthorizedClientException" => {
-                    return RusotoError::Service(ListRoomsError::UnauthorizedClient(err.msg))
+                    return RusotoError::service(ListRoomsError::UnauthorizedClient(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListRoomsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -7906,12 +8816,14 @@ impl fmt::Display for ListRoomsError {
             ListRoomsError::ServiceUnavailable(ref cause) => write!(f, "{}", cause),
             ListRoomsError::ThrottledClient(ref cause) => write!(f, "{}", cause),
             ListRoomsError::UnauthorizedClient(ref cause) => write!(f, "{}", cause),
+            ListRoomsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListRoomsError {}
 /// Errors returned by ListUsers
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListUsersError {
     /// <p>The input parameters don't match the service's restrictions.</p>
     BadRequest(String),
@@ -7927,6 +8839,8 @@ pub enum ListUsersError {
     ThrottledClient(String),
     /// <p>The client is not currently authorized to make the request.</p>
     UnauthorizedClient(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListUsersError {
@@ -7934,28 +8848,28 @@ impl ListUsersError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(ListUsersError::BadRequest(err.msg))
+                    return RusotoError::service(ListUsersError::BadRequest(err.msg), &res)
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(ListUsersError::Forbidden(err.msg))
+                    return RusotoError::service(ListUsersError::Forbidden(err.msg), &res)
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(ListUsersError::NotFound(err.msg))
+                    return RusotoError::service(ListUsersError::NotFound(err.msg), &res)
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(ListUsersError::ServiceFailure(err.msg))
+                    return RusotoError::service(ListUsersError::ServiceFailure(err.msg), &res)
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(ListUsersError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(ListUsersError::ServiceUnavailable(err.msg), &res)
                 }
                 "ThrottledClientException" => {
-                    return RusotoError::Service(ListUsersError::ThrottledClient(err.msg))
+                    return RusotoError::service(ListUsersError::ThrottledClient(err.msg), &res)
                 }
                 "UnauthorizedClientException" => {
-                    return RusotoError::Service(ListUsersError::UnauthorizedClient(err.msg))
+                    return RusotoError::service(ListUsersError::UnauthorizedClient(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListUsersError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -7972,12 +8886,14 @@ impl fmt::Display for ListUsersError {
             ListUsersError::ServiceUnavailable(ref cause) => write!(f, "{}", cause),
             ListUsersError::ThrottledClient(ref cause) => write!(f, "{}", cause),
             ListUsersError::UnauthorizedClient(ref cause) => write!(f, "{}", cause),
+            ListUsersError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListUsersError {}
 /// Errors returned by ListVoiceConnectorGroups
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListVoiceConnectorGroupsError {
     /// <p>The input parameters don't match the service's restrictions.</p>
     BadRequest(String),
@@ -7991,6 +8907,8 @@ pub enum ListVoiceConnectorGroupsError {
     ThrottledClient(String),
     /// <p>The client is not currently authorized to make the request.</p>
     UnauthorizedClient(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListVoiceConnectorGroupsError {
@@ -7998,33 +8916,43 @@ impl ListVoiceConnectorGroupsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(ListVoiceConnectorGroupsError::BadRequest(err.msg))
+                    return RusotoError::service(
+                        ListVoiceConnectorGroupsError::BadRequest(err.msg),
+                        &res,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(ListVoiceConnectorGroupsError::Forbidden(err.msg))
+                    return RusotoError::service(
+                        ListVoiceConnectorGroupsError::Forbidden(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(ListVoiceConnectorGroupsError::ServiceFailure(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListVoiceConnectorGroupsError::ServiceFailure(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(ListVoiceConnectorGroupsError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListVoiceConnectorGroupsError::ServiceUnavailable(err.msg),
+                        &res,
+                    )
                 }
                 "ThrottledClientException" => {
-                    return RusotoError::Service(ListVoiceConnectorGroupsError::ThrottledClient(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListVoiceConnectorGroupsError::ThrottledClient(err.msg),
+                        &res,
+                    )
                 }
                 "UnauthorizedClientException" => {
-                    return RusotoError::Service(ListVoiceConnectorGroupsError::UnauthorizedClient(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListVoiceConnectorGroupsError::UnauthorizedClient(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListVoiceConnectorGroupsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -8040,12 +8968,14 @@ impl fmt::Display for ListVoiceConnectorGroupsError {
             ListVoiceConnectorGroupsError::ServiceUnavailable(ref cause) => write!(f, "{}", cause),
             ListVoiceConnectorGroupsError::ThrottledClient(ref cause) => write!(f, "{}", cause),
             ListVoiceConnectorGroupsError::UnauthorizedClient(ref cause) => write!(f, "{}", cause),
+            ListVoiceConnectorGroupsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListVoiceConnectorGroupsError {}
 /// Errors returned by ListVoiceConnectorTerminationCredentials
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListVoiceConnectorTerminationCredentialsError {
     /// <p>The input parameters don't match the service's restrictions.</p>
     BadRequest(String),
@@ -8061,6 +8991,8 @@ pub enum ListVoiceConnectorTerminationCredentialsError {
     ThrottledClient(String),
     /// <p>The client is not currently authorized to make the request.</p>
     UnauthorizedClient(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListVoiceConnectorTerminationCredentialsError {
@@ -8070,42 +9002,54 @@ impl ListVoiceConnectorTerminationCredentialsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         ListVoiceConnectorTerminationCredentialsError::BadRequest(err.msg),
+                        &res,
                     )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         ListVoiceConnectorTerminationCredentialsError::Forbidden(err.msg),
+                        &res,
                     )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         ListVoiceConnectorTerminationCredentialsError::NotFound(err.msg),
+                        &res,
                     )
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         ListVoiceConnectorTerminationCredentialsError::ServiceFailure(err.msg),
+                        &res,
                     )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         ListVoiceConnectorTerminationCredentialsError::ServiceUnavailable(err.msg),
+                        &res,
                     )
                 }
                 "ThrottledClientException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         ListVoiceConnectorTerminationCredentialsError::ThrottledClient(err.msg),
+                        &res,
                     )
                 }
                 "UnauthorizedClientException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         ListVoiceConnectorTerminationCredentialsError::UnauthorizedClient(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        ListVoiceConnectorTerminationCredentialsError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -8136,12 +9080,16 @@ impl fmt::Display for ListVoiceConnectorTerminationCredentialsError {
             ListVoiceConnectorTerminationCredentialsError::UnauthorizedClient(ref cause) => {
                 write!(f, "{}", cause)
             }
+            ListVoiceConnectorTerminationCredentialsError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for ListVoiceConnectorTerminationCredentialsError {}
 /// Errors returned by ListVoiceConnectors
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListVoiceConnectorsError {
     /// <p>The input parameters don't match the service's restrictions.</p>
     BadRequest(String),
@@ -8155,6 +9103,8 @@ pub enum ListVoiceConnectorsError {
     ThrottledClient(String),
     /// <p>The client is not currently authorized to make the request.</p>
     UnauthorizedClient(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListVoiceConnectorsError {
@@ -8162,29 +9112,40 @@ impl ListVoiceConnectorsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(ListVoiceConnectorsError::BadRequest(err.msg))
+                    return RusotoError::service(
+                        ListVoiceConnectorsError::BadRequest(err.msg),
+                        &res,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(ListVoiceConnectorsError::Forbidden(err.msg))
+                    return RusotoError::service(ListVoiceConnectorsError::Forbidden(err.msg), &res)
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(ListVoiceConnectorsError::ServiceFailure(err.msg))
+                    return RusotoError::service(
+                        ListVoiceConnectorsError::ServiceFailure(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(ListVoiceConnectorsError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListVoiceConnectorsError::ServiceUnavailable(err.msg),
+                        &res,
+                    )
                 }
                 "ThrottledClientException" => {
-                    return RusotoError::Service(ListVoiceConnectorsError::ThrottledClient(err.msg))
+                    return RusotoError::service(
+                        ListVoiceConnectorsError::ThrottledClient(err.msg),
+                        &res,
+                    )
                 }
                 "UnauthorizedClientException" => {
-                    return RusotoError::Service(ListVoiceConnectorsError::UnauthorizedClient(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListVoiceConnectorsError::UnauthorizedClient(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListVoiceConnectorsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -8200,12 +9161,14 @@ impl fmt::Display for ListVoiceConnectorsError {
             ListVoiceConnectorsError::ServiceUnavailable(ref cause) => write!(f, "{}", cause),
             ListVoiceConnectorsError::ThrottledClient(ref cause) => write!(f, "{}", cause),
             ListVoiceConnectorsError::UnauthorizedClient(ref cause) => write!(f, "{}", cause),
+            ListVoiceConnectorsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListVoiceConnectorsError {}
 /// Errors returned by LogoutUser
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum LogoutUserError {
     /// <p>The input parameters don't match the service's restrictions.</p>
     BadRequest(String),
@@ -8221,6 +9184,8 @@ pub enum LogoutUserError {
     ThrottledClient(String),
     /// <p>The client is not currently authorized to make the request.</p>
     UnauthorizedClient(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl LogoutUserError {
@@ -8228,28 +9193,28 @@ impl LogoutUserError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(LogoutUserError::BadRequest(err.msg))
+                    return RusotoError::service(LogoutUserError::BadRequest(err.msg), &res)
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(LogoutUserError::Forbidden(err.msg))
+                    return RusotoError::service(LogoutUserError::Forbidden(err.msg), &res)
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(LogoutUserError::NotFound(err.msg))
+                    return RusotoError::service(LogoutUserError::NotFound(err.msg), &res)
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(LogoutUserError::ServiceFailure(err.msg))
+                    return RusotoError::service(LogoutUserError::ServiceFailure(err.msg), &res)
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(LogoutUserError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(LogoutUserError::ServiceUnavailable(err.msg), &res)
                 }
                 "ThrottledClientException" => {
-                    return RusotoError::Service(LogoutUserError::ThrottledClient(err.msg))
+                    return RusotoError::service(LogoutUserError::ThrottledClient(err.msg), &res)
                 }
                 "UnauthorizedClientException" => {
-                    return RusotoError::Service(LogoutUserError::UnauthorizedClient(err.msg))
+                    return RusotoError::service(LogoutUserError::UnauthorizedClient(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(LogoutUserError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -8266,12 +9231,14 @@ impl fmt::Display for LogoutUserError {
             LogoutUserError::ServiceUnavailable(ref cause) => write!(f, "{}", cause),
             LogoutUserError::ThrottledClient(ref cause) => write!(f, "{}", cause),
             LogoutUserError::UnauthorizedClient(ref cause) => write!(f, "{}", cause),
+            LogoutUserError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for LogoutUserError {}
 /// Errors returned by PutEventsConfiguration
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum PutEventsConfigurationError {
     /// <p>The input parameters don't match the service's restrictions.</p>
     BadRequest(String),
@@ -8287,6 +9254,8 @@ pub enum PutEventsConfigurationError {
     ServiceUnavailable(String),
     /// <p>The client is not currently authorized to make the request.</p>
     UnauthorizedClient(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl PutEventsConfigurationError {
@@ -8294,36 +9263,49 @@ impl PutEventsConfigurationError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(PutEventsConfigurationError::BadRequest(err.msg))
+                    return RusotoError::service(
+                        PutEventsConfigurationError::BadRequest(err.msg),
+                        &res,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(PutEventsConfigurationError::Forbidden(err.msg))
+                    return RusotoError::service(
+                        PutEventsConfigurationError::Forbidden(err.msg),
+                        &res,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(PutEventsConfigurationError::NotFound(err.msg))
+                    return RusotoError::service(
+                        PutEventsConfigurationError::NotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceLimitExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         PutEventsConfigurationError::ResourceLimitExceeded(err.msg),
+                        &res,
                     )
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(PutEventsConfigurationError::ServiceFailure(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        PutEventsConfigurationError::ServiceFailure(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(PutEventsConfigurationError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        PutEventsConfigurationError::ServiceUnavailable(err.msg),
+                        &res,
+                    )
                 }
                 "UnauthorizedClientException" => {
-                    return RusotoError::Service(PutEventsConfigurationError::UnauthorizedClient(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        PutEventsConfigurationError::UnauthorizedClient(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(PutEventsConfigurationError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -8340,12 +9322,14 @@ impl fmt::Display for PutEventsConfigurationError {
             PutEventsConfigurationError::ServiceFailure(ref cause) => write!(f, "{}", cause),
             PutEventsConfigurationError::ServiceUnavailable(ref cause) => write!(f, "{}", cause),
             PutEventsConfigurationError::UnauthorizedClient(ref cause) => write!(f, "{}", cause),
+            PutEventsConfigurationError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for PutEventsConfigurationError {}
 /// Errors returned by PutVoiceConnectorLoggingConfiguration
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum PutVoiceConnectorLoggingConfigurationError {
     /// <p>The input parameters don't match the service's restrictions.</p>
     BadRequest(String),
@@ -8361,6 +9345,8 @@ pub enum PutVoiceConnectorLoggingConfigurationError {
     ThrottledClient(String),
     /// <p>The client is not currently authorized to make the request.</p>
     UnauthorizedClient(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl PutVoiceConnectorLoggingConfigurationError {
@@ -8370,42 +9356,54 @@ impl PutVoiceConnectorLoggingConfigurationError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         PutVoiceConnectorLoggingConfigurationError::BadRequest(err.msg),
+                        &res,
                     )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         PutVoiceConnectorLoggingConfigurationError::Forbidden(err.msg),
+                        &res,
                     )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         PutVoiceConnectorLoggingConfigurationError::NotFound(err.msg),
+                        &res,
                     )
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         PutVoiceConnectorLoggingConfigurationError::ServiceFailure(err.msg),
+                        &res,
                     )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         PutVoiceConnectorLoggingConfigurationError::ServiceUnavailable(err.msg),
+                        &res,
                     )
                 }
                 "ThrottledClientException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         PutVoiceConnectorLoggingConfigurationError::ThrottledClient(err.msg),
+                        &res,
                     )
                 }
                 "UnauthorizedClientException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         PutVoiceConnectorLoggingConfigurationError::UnauthorizedClient(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        PutVoiceConnectorLoggingConfigurationError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -8436,12 +9434,16 @@ impl fmt::Display for PutVoiceConnectorLoggingConfigurationError {
             PutVoiceConnectorLoggingConfigurationError::UnauthorizedClient(ref cause) => {
                 write!(f, "{}", cause)
             }
+            PutVoiceConnectorLoggingConfigurationError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for PutVoiceConnectorLoggingConfigurationError {}
 /// Errors returned by PutVoiceConnectorOrigination
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum PutVoiceConnectorOriginationError {
     /// <p>The input parameters don't match the service's restrictions.</p>
     BadRequest(String),
@@ -8457,6 +9459,8 @@ pub enum PutVoiceConnectorOriginationError {
     ThrottledClient(String),
     /// <p>The client is not currently authorized to make the request.</p>
     UnauthorizedClient(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl PutVoiceConnectorOriginationError {
@@ -8466,42 +9470,54 @@ impl PutVoiceConnectorOriginationError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(PutVoiceConnectorOriginationError::BadRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        PutVoiceConnectorOriginationError::BadRequest(err.msg),
+                        &res,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(PutVoiceConnectorOriginationError::Forbidden(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        PutVoiceConnectorOriginationError::Forbidden(err.msg),
+                        &res,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(PutVoiceConnectorOriginationError::NotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        PutVoiceConnectorOriginationError::NotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(PutVoiceConnectorOriginationError::ServiceFailure(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        PutVoiceConnectorOriginationError::ServiceFailure(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         PutVoiceConnectorOriginationError::ServiceUnavailable(err.msg),
+                        &res,
                     )
                 }
                 "ThrottledClientException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         PutVoiceConnectorOriginationError::ThrottledClient(err.msg),
+                        &res,
                     )
                 }
                 "UnauthorizedClientException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         PutVoiceConnectorOriginationError::UnauthorizedClient(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        PutVoiceConnectorOriginationError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -8522,12 +9538,14 @@ impl fmt::Display for PutVoiceConnectorOriginationError {
             PutVoiceConnectorOriginationError::UnauthorizedClient(ref cause) => {
                 write!(f, "{}", cause)
             }
+            PutVoiceConnectorOriginationError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for PutVoiceConnectorOriginationError {}
 /// Errors returned by PutVoiceConnectorStreamingConfiguration
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum PutVoiceConnectorStreamingConfigurationError {
     /// <p>The input parameters don't match the service's restrictions.</p>
     BadRequest(String),
@@ -8543,6 +9561,8 @@ pub enum PutVoiceConnectorStreamingConfigurationError {
     ThrottledClient(String),
     /// <p>The client is not currently authorized to make the request.</p>
     UnauthorizedClient(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl PutVoiceConnectorStreamingConfigurationError {
@@ -8552,42 +9572,54 @@ impl PutVoiceConnectorStreamingConfigurationError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         PutVoiceConnectorStreamingConfigurationError::BadRequest(err.msg),
+                        &res,
                     )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         PutVoiceConnectorStreamingConfigurationError::Forbidden(err.msg),
+                        &res,
                     )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         PutVoiceConnectorStreamingConfigurationError::NotFound(err.msg),
+                        &res,
                     )
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         PutVoiceConnectorStreamingConfigurationError::ServiceFailure(err.msg),
+                        &res,
                     )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         PutVoiceConnectorStreamingConfigurationError::ServiceUnavailable(err.msg),
+                        &res,
                     )
                 }
                 "ThrottledClientException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         PutVoiceConnectorStreamingConfigurationError::ThrottledClient(err.msg),
+                        &res,
                     )
                 }
                 "UnauthorizedClientException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         PutVoiceConnectorStreamingConfigurationError::UnauthorizedClient(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        PutVoiceConnectorStreamingConfigurationError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -8618,12 +9650,16 @@ impl fmt::Display for PutVoiceConnectorStreamingConfigurationError {
             PutVoiceConnectorStreamingConfigurationError::UnauthorizedClient(ref cause) => {
                 write!(f, "{}", cause)
             }
+            PutVoiceConnectorStreamingConfigurationError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for PutVoiceConnectorStreamingConfigurationError {}
 /// Errors returned by PutVoiceConnectorTermination
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum PutVoiceConnectorTerminationError {
     /// <p>You don't have permissions to perform the requested operation.</p>
     AccessDenied(String),
@@ -8641,6 +9677,8 @@ pub enum PutVoiceConnectorTerminationError {
     ThrottledClient(String),
     /// <p>The client is not currently authorized to make the request.</p>
     UnauthorizedClient(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl PutVoiceConnectorTerminationError {
@@ -8650,47 +9688,60 @@ impl PutVoiceConnectorTerminationError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(PutVoiceConnectorTerminationError::AccessDenied(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        PutVoiceConnectorTerminationError::AccessDenied(err.msg),
+                        &res,
+                    )
                 }
                 "BadRequestException" => {
-                    return RusotoError::Service(PutVoiceConnectorTerminationError::BadRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        PutVoiceConnectorTerminationError::BadRequest(err.msg),
+                        &res,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(PutVoiceConnectorTerminationError::Forbidden(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        PutVoiceConnectorTerminationError::Forbidden(err.msg),
+                        &res,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(PutVoiceConnectorTerminationError::NotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        PutVoiceConnectorTerminationError::NotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(PutVoiceConnectorTerminationError::ServiceFailure(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        PutVoiceConnectorTerminationError::ServiceFailure(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         PutVoiceConnectorTerminationError::ServiceUnavailable(err.msg),
+                        &res,
                     )
                 }
                 "ThrottledClientException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         PutVoiceConnectorTerminationError::ThrottledClient(err.msg),
+                        &res,
                     )
                 }
                 "UnauthorizedClientException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         PutVoiceConnectorTerminationError::UnauthorizedClient(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        PutVoiceConnectorTerminationError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -8712,12 +9763,14 @@ impl fmt::Display for PutVoiceConnectorTerminationError {
             PutVoiceConnectorTerminationError::UnauthorizedClient(ref cause) => {
                 write!(f, "{}", cause)
             }
+            PutVoiceConnectorTerminationError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for PutVoiceConnectorTerminationError {}
 /// Errors returned by PutVoiceConnectorTerminationCredentials
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum PutVoiceConnectorTerminationCredentialsError {
     /// <p>The input parameters don't match the service's restrictions.</p>
     BadRequest(String),
@@ -8733,6 +9786,8 @@ pub enum PutVoiceConnectorTerminationCredentialsError {
     ThrottledClient(String),
     /// <p>The client is not currently authorized to make the request.</p>
     UnauthorizedClient(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl PutVoiceConnectorTerminationCredentialsError {
@@ -8742,42 +9797,54 @@ impl PutVoiceConnectorTerminationCredentialsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         PutVoiceConnectorTerminationCredentialsError::BadRequest(err.msg),
+                        &res,
                     )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         PutVoiceConnectorTerminationCredentialsError::Forbidden(err.msg),
+                        &res,
                     )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         PutVoiceConnectorTerminationCredentialsError::NotFound(err.msg),
+                        &res,
                     )
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         PutVoiceConnectorTerminationCredentialsError::ServiceFailure(err.msg),
+                        &res,
                     )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         PutVoiceConnectorTerminationCredentialsError::ServiceUnavailable(err.msg),
+                        &res,
                     )
                 }
                 "ThrottledClientException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         PutVoiceConnectorTerminationCredentialsError::ThrottledClient(err.msg),
+                        &res,
                     )
                 }
                 "UnauthorizedClientException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         PutVoiceConnectorTerminationCredentialsError::UnauthorizedClient(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        PutVoiceConnectorTerminationCredentialsError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -8808,12 +9875,16 @@ impl fmt::Display for PutVoiceConnectorTerminationCredentialsError {
             PutVoiceConnectorTerminationCredentialsError::UnauthorizedClient(ref cause) => {
                 write!(f, "{}", cause)
             }
+            PutVoiceConnectorTerminationCredentialsError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for PutVoiceConnectorTerminationCredentialsError {}
 /// Errors returned by RegenerateSecurityToken
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum RegenerateSecurityTokenError {
     /// <p>The input parameters don't match the service's restrictions.</p>
     BadRequest(String),
@@ -8829,6 +9900,8 @@ pub enum RegenerateSecurityTokenError {
     ThrottledClient(String),
     /// <p>The client is not currently authorized to make the request.</p>
     UnauthorizedClient(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl RegenerateSecurityTokenError {
@@ -8836,36 +9909,49 @@ impl RegenerateSecurityTokenError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(RegenerateSecurityTokenError::BadRequest(err.msg))
+                    return RusotoError::service(
+                        RegenerateSecurityTokenError::BadRequest(err.msg),
+                        &res,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(RegenerateSecurityTokenError::Forbidden(err.msg))
+                    return RusotoError::service(
+                        RegenerateSecurityTokenError::Forbidden(err.msg),
+                        &res,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(RegenerateSecurityTokenError::NotFound(err.msg))
+                    return RusotoError::service(
+                        RegenerateSecurityTokenError::NotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(RegenerateSecurityTokenError::ServiceFailure(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        RegenerateSecurityTokenError::ServiceFailure(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(RegenerateSecurityTokenError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        RegenerateSecurityTokenError::ServiceUnavailable(err.msg),
+                        &res,
+                    )
                 }
                 "ThrottledClientException" => {
-                    return RusotoError::Service(RegenerateSecurityTokenError::ThrottledClient(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        RegenerateSecurityTokenError::ThrottledClient(err.msg),
+                        &res,
+                    )
                 }
                 "UnauthorizedClientException" => {
-                    return RusotoError::Service(RegenerateSecurityTokenError::UnauthorizedClient(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        RegenerateSecurityTokenError::UnauthorizedClient(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(RegenerateSecurityTokenError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -8882,12 +9968,14 @@ impl fmt::Display for RegenerateSecurityTokenError {
             RegenerateSecurityTokenError::ServiceUnavailable(ref cause) => write!(f, "{}", cause),
             RegenerateSecurityTokenError::ThrottledClient(ref cause) => write!(f, "{}", cause),
             RegenerateSecurityTokenError::UnauthorizedClient(ref cause) => write!(f, "{}", cause),
+            RegenerateSecurityTokenError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for RegenerateSecurityTokenError {}
 /// Errors returned by ResetPersonalPIN
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ResetPersonalPINError {
     /// <p>The input parameters don't match the service's restrictions.</p>
     BadRequest(String),
@@ -8903,6 +9991,8 @@ pub enum ResetPersonalPINError {
     ThrottledClient(String),
     /// <p>The client is not currently authorized to make the request.</p>
     UnauthorizedClient(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ResetPersonalPINError {
@@ -8910,28 +10000,40 @@ impl ResetPersonalPINError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(ResetPersonalPINError::BadRequest(err.msg))
+                    return RusotoError::service(ResetPersonalPINError::BadRequest(err.msg), &res)
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(ResetPersonalPINError::Forbidden(err.msg))
+                    return RusotoError::service(ResetPersonalPINError::Forbidden(err.msg), &res)
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(ResetPersonalPINError::NotFound(err.msg))
+                    return RusotoError::service(ResetPersonalPINError::NotFound(err.msg), &res)
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(ResetPersonalPINError::ServiceFailure(err.msg))
+                    return RusotoError::service(
+                        ResetPersonalPINError::ServiceFailure(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(ResetPersonalPINError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        ResetPersonalPINError::ServiceUnavailable(err.msg),
+                        &res,
+                    )
                 }
                 "ThrottledClientException" => {
-                    return RusotoError::Service(ResetPersonalPINError::ThrottledClient(err.msg))
+                    return RusotoError::service(
+                        ResetPersonalPINError::ThrottledClient(err.msg),
+                        &res,
+                    )
                 }
                 "UnauthorizedClientException" => {
-                    return RusotoError::Service(ResetPersonalPINError::UnauthorizedClient(err.msg))
+                    return RusotoError::service(
+                        ResetPersonalPINError::UnauthorizedClient(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ResetPersonalPINError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -8948,12 +10050,14 @@ impl fmt::Display for ResetPersonalPINError {
             ResetPersonalPINError::ServiceUnavailable(ref cause) => write!(f, "{}", cause),
             ResetPersonalPINError::ThrottledClient(ref cause) => write!(f, "{}", cause),
             ResetPersonalPINError::UnauthorizedClient(ref cause) => write!(f, "{}", cause),
+            ResetPersonalPINError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ResetPersonalPINError {}
 /// Errors returned by RestorePhoneNumber
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum RestorePhoneNumberError {
     /// <p>The input parameters don't match the service's restrictions.</p>
     BadRequest(String),
@@ -8971,6 +10075,8 @@ pub enum RestorePhoneNumberError {
     ThrottledClient(String),
     /// <p>The client is not currently authorized to make the request.</p>
     UnauthorizedClient(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl RestorePhoneNumberError {
@@ -8978,37 +10084,46 @@ impl RestorePhoneNumberError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(RestorePhoneNumberError::BadRequest(err.msg))
+                    return RusotoError::service(RestorePhoneNumberError::BadRequest(err.msg), &res)
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(RestorePhoneNumberError::Forbidden(err.msg))
+                    return RusotoError::service(RestorePhoneNumberError::Forbidden(err.msg), &res)
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(RestorePhoneNumberError::NotFound(err.msg))
+                    return RusotoError::service(RestorePhoneNumberError::NotFound(err.msg), &res)
                 }
                 "ResourceLimitExceededException" => {
-                    return RusotoError::Service(RestorePhoneNumberError::ResourceLimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        RestorePhoneNumberError::ResourceLimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(RestorePhoneNumberError::ServiceFailure(err.msg))
+                    return RusotoError::service(
+                        RestorePhoneNumberError::ServiceFailure(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(RestorePhoneNumberError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        RestorePhoneNumberError::ServiceUnavailable(err.msg),
+                        &res,
+                    )
                 }
                 "ThrottledClientException" => {
-                    return RusotoError::Service(RestorePhoneNumberError::ThrottledClient(err.msg))
+                    return RusotoError::service(
+                        RestorePhoneNumberError::ThrottledClient(err.msg),
+                        &res,
+                    )
                 }
                 "UnauthorizedClientException" => {
-                    return RusotoError::Service(RestorePhoneNumberError::UnauthorizedClient(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        RestorePhoneNumberError::UnauthorizedClient(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(RestorePhoneNumberError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -9026,12 +10141,14 @@ impl fmt::Display for RestorePhoneNumberError {
             RestorePhoneNumberError::ServiceUnavailable(ref cause) => write!(f, "{}", cause),
             RestorePhoneNumberError::ThrottledClient(ref cause) => write!(f, "{}", cause),
             RestorePhoneNumberError::UnauthorizedClient(ref cause) => write!(f, "{}", cause),
+            RestorePhoneNumberError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for RestorePhoneNumberError {}
 /// Errors returned by SearchAvailablePhoneNumbers
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum SearchAvailablePhoneNumbersError {
     /// <p>You don't have permissions to perform the requested operation.</p>
     AccessDenied(String),
@@ -9047,6 +10164,8 @@ pub enum SearchAvailablePhoneNumbersError {
     ThrottledClient(String),
     /// <p>The client is not currently authorized to make the request.</p>
     UnauthorizedClient(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl SearchAvailablePhoneNumbersError {
@@ -9056,42 +10175,54 @@ impl SearchAvailablePhoneNumbersError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(SearchAvailablePhoneNumbersError::AccessDenied(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        SearchAvailablePhoneNumbersError::AccessDenied(err.msg),
+                        &res,
+                    )
                 }
                 "BadRequestException" => {
-                    return RusotoError::Service(SearchAvailablePhoneNumbersError::BadRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        SearchAvailablePhoneNumbersError::BadRequest(err.msg),
+                        &res,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(SearchAvailablePhoneNumbersError::Forbidden(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        SearchAvailablePhoneNumbersError::Forbidden(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(SearchAvailablePhoneNumbersError::ServiceFailure(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        SearchAvailablePhoneNumbersError::ServiceFailure(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         SearchAvailablePhoneNumbersError::ServiceUnavailable(err.msg),
+                        &res,
                     )
                 }
                 "ThrottledClientException" => {
-                    return RusotoError::Service(SearchAvailablePhoneNumbersError::ThrottledClient(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        SearchAvailablePhoneNumbersError::ThrottledClient(err.msg),
+                        &res,
+                    )
                 }
                 "UnauthorizedClientException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         SearchAvailablePhoneNumbersError::UnauthorizedClient(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        SearchAvailablePhoneNumbersError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -9112,12 +10243,14 @@ impl fmt::Display for SearchAvailablePhoneNumbersError {
             SearchAvailablePhoneNumbersError::UnauthorizedClient(ref cause) => {
                 write!(f, "{}", cause)
             }
+            SearchAvailablePhoneNumbersError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for SearchAvailablePhoneNumbersError {}
 /// Errors returned by UpdateAccount
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdateAccountError {
     /// <p>The input parameters don't match the service's restrictions.</p>
     BadRequest(String),
@@ -9133,6 +10266,8 @@ pub enum UpdateAccountError {
     ThrottledClient(String),
     /// <p>The client is not currently authorized to make the request.</p>
     UnauthorizedClient(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdateAccountError {
@@ -9140,28 +10275,34 @@ impl UpdateAccountError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(UpdateAccountError::BadRequest(err.msg))
+                    return RusotoError::service(UpdateAccountError::BadRequest(err.msg), &res)
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(UpdateAccountError::Forbidden(err.msg))
+                    return RusotoError::service(UpdateAccountError::Forbidden(err.msg), &res)
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(UpdateAccountError::NotFound(err.msg))
+                    return RusotoError::service(UpdateAccountError::NotFound(err.msg), &res)
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(UpdateAccountError::ServiceFailure(err.msg))
+                    return RusotoError::service(UpdateAccountError::ServiceFailure(err.msg), &res)
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(UpdateAccountError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        UpdateAccountError::ServiceUnavailable(err.msg),
+                        &res,
+                    )
                 }
                 "ThrottledClientException" => {
-                    return RusotoError::Service(UpdateAccountError::ThrottledClient(err.msg))
+                    return RusotoError::service(UpdateAccountError::ThrottledClient(err.msg), &res)
                 }
                 "UnauthorizedClientException" => {
-                    return RusotoError::Service(UpdateAccountError::UnauthorizedClient(err.msg))
+                    return RusotoError::service(
+                        UpdateAccountError::UnauthorizedClient(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UpdateAccountError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -9178,12 +10319,14 @@ impl fmt::Display for UpdateAccountError {
             UpdateAccountError::ServiceUnavailable(ref cause) => write!(f, "{}", cause),
             UpdateAccountError::ThrottledClient(ref cause) => write!(f, "{}", cause),
             UpdateAccountError::UnauthorizedClient(ref cause) => write!(f, "{}", cause),
+            UpdateAccountError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for UpdateAccountError {}
 /// Errors returned by UpdateAccountSettings
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdateAccountSettingsError {
     /// <p>The input parameters don't match the service's restrictions.</p>
     BadRequest(String),
@@ -9201,6 +10344,8 @@ pub enum UpdateAccountSettingsError {
     ThrottledClient(String),
     /// <p>The client is not currently authorized to make the request.</p>
     UnauthorizedClient(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdateAccountSettingsError {
@@ -9208,39 +10353,55 @@ impl UpdateAccountSettingsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(UpdateAccountSettingsError::BadRequest(err.msg))
+                    return RusotoError::service(
+                        UpdateAccountSettingsError::BadRequest(err.msg),
+                        &res,
+                    )
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(UpdateAccountSettingsError::Conflict(err.msg))
+                    return RusotoError::service(
+                        UpdateAccountSettingsError::Conflict(err.msg),
+                        &res,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(UpdateAccountSettingsError::Forbidden(err.msg))
+                    return RusotoError::service(
+                        UpdateAccountSettingsError::Forbidden(err.msg),
+                        &res,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(UpdateAccountSettingsError::NotFound(err.msg))
+                    return RusotoError::service(
+                        UpdateAccountSettingsError::NotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(UpdateAccountSettingsError::ServiceFailure(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateAccountSettingsError::ServiceFailure(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(UpdateAccountSettingsError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateAccountSettingsError::ServiceUnavailable(err.msg),
+                        &res,
+                    )
                 }
                 "ThrottledClientException" => {
-                    return RusotoError::Service(UpdateAccountSettingsError::ThrottledClient(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateAccountSettingsError::ThrottledClient(err.msg),
+                        &res,
+                    )
                 }
                 "UnauthorizedClientException" => {
-                    return RusotoError::Service(UpdateAccountSettingsError::UnauthorizedClient(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateAccountSettingsError::UnauthorizedClient(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UpdateAccountSettingsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -9258,12 +10419,14 @@ impl fmt::Display for UpdateAccountSettingsError {
             UpdateAccountSettingsError::ServiceUnavailable(ref cause) => write!(f, "{}", cause),
             UpdateAccountSettingsError::ThrottledClient(ref cause) => write!(f, "{}", cause),
             UpdateAccountSettingsError::UnauthorizedClient(ref cause) => write!(f, "{}", cause),
+            UpdateAccountSettingsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for UpdateAccountSettingsError {}
 /// Errors returned by UpdateBot
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdateBotError {
     /// <p>The input parameters don't match the service's restrictions.</p>
     BadRequest(String),
@@ -9279,6 +10442,8 @@ pub enum UpdateBotError {
     ThrottledClient(String),
     /// <p>The client is not currently authorized to make the request.</p>
     UnauthorizedClient(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdateBotError {
@@ -9286,28 +10451,28 @@ impl UpdateBotError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(UpdateBotError::BadRequest(err.msg))
+                    return RusotoError::service(UpdateBotError::BadRequest(err.msg), &res)
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(UpdateBotError::Forbidden(err.msg))
+                    return RusotoError::service(UpdateBotError::Forbidden(err.msg), &res)
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(UpdateBotError::NotFound(err.msg))
+                    return RusotoError::service(UpdateBotError::NotFound(err.msg), &res)
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(UpdateBotError::ServiceFailure(err.msg))
+                    return RusotoError::service(UpdateBotError::ServiceFailure(err.msg), &res)
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(UpdateBotError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(UpdateBotError::ServiceUnavailable(err.msg), &res)
                 }
                 "ThrottledClientException" => {
-                    return RusotoError::Service(UpdateBotError::ThrottledClient(err.msg))
+                    return RusotoError::service(UpdateBotError::ThrottledClient(err.msg), &res)
                 }
                 "UnauthorizedClientException" => {
-                    return RusotoError::Service(UpdateBotError::UnauthorizedClient(err.msg))
+                    return RusotoError::service(UpdateBotError::UnauthorizedClient(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UpdateBotError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -9324,12 +10489,14 @@ impl fmt::Display for UpdateBotError {
             UpdateBotError::ServiceUnavailable(ref cause) => write!(f, "{}", cause),
             UpdateBotError::ThrottledClient(ref cause) => write!(f, "{}", cause),
             UpdateBotError::UnauthorizedClient(ref cause) => write!(f, "{}", cause),
+            UpdateBotError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for UpdateBotError {}
 /// Errors returned by UpdateGlobalSettings
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdateGlobalSettingsError {
     /// <p>The input parameters don't match the service's restrictions.</p>
     BadRequest(String),
@@ -9343,6 +10510,8 @@ pub enum UpdateGlobalSettingsError {
     ThrottledClient(String),
     /// <p>The client is not currently authorized to make the request.</p>
     UnauthorizedClient(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdateGlobalSettingsError {
@@ -9350,31 +10519,43 @@ impl UpdateGlobalSettingsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(UpdateGlobalSettingsError::BadRequest(err.msg))
+                    return RusotoError::service(
+                        UpdateGlobalSettingsError::BadRequest(err.msg),
+                        &res,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(UpdateGlobalSettingsError::Forbidden(err.msg))
+                    return RusotoError::service(
+                        UpdateGlobalSettingsError::Forbidden(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(UpdateGlobalSettingsError::ServiceFailure(err.msg))
+                    return RusotoError::service(
+                        UpdateGlobalSettingsError::ServiceFailure(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(UpdateGlobalSettingsError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateGlobalSettingsError::ServiceUnavailable(err.msg),
+                        &res,
+                    )
                 }
                 "ThrottledClientException" => {
-                    return RusotoError::Service(UpdateGlobalSettingsError::ThrottledClient(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateGlobalSettingsError::ThrottledClient(err.msg),
+                        &res,
+                    )
                 }
                 "UnauthorizedClientException" => {
-                    return RusotoError::Service(UpdateGlobalSettingsError::UnauthorizedClient(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateGlobalSettingsError::UnauthorizedClient(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UpdateGlobalSettingsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -9390,12 +10571,14 @@ impl fmt::Display for UpdateGlobalSettingsError {
             UpdateGlobalSettingsError::ServiceUnavailable(ref cause) => write!(f, "{}", cause),
             UpdateGlobalSettingsError::ThrottledClient(ref cause) => write!(f, "{}", cause),
             UpdateGlobalSettingsError::UnauthorizedClient(ref cause) => write!(f, "{}", cause),
+            UpdateGlobalSettingsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for UpdateGlobalSettingsError {}
 /// Errors returned by UpdatePhoneNumber
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdatePhoneNumberError {
     /// <p>The input parameters don't match the service's restrictions.</p>
     BadRequest(String),
@@ -9411,6 +10594,8 @@ pub enum UpdatePhoneNumberError {
     ThrottledClient(String),
     /// <p>The client is not currently authorized to make the request.</p>
     UnauthorizedClient(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdatePhoneNumberError {
@@ -9418,32 +10603,40 @@ impl UpdatePhoneNumberError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(UpdatePhoneNumberError::BadRequest(err.msg))
+                    return RusotoError::service(UpdatePhoneNumberError::BadRequest(err.msg), &res)
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(UpdatePhoneNumberError::Forbidden(err.msg))
+                    return RusotoError::service(UpdatePhoneNumberError::Forbidden(err.msg), &res)
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(UpdatePhoneNumberError::NotFound(err.msg))
+                    return RusotoError::service(UpdatePhoneNumberError::NotFound(err.msg), &res)
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(UpdatePhoneNumberError::ServiceFailure(err.msg))
+                    return RusotoError::service(
+                        UpdatePhoneNumberError::ServiceFailure(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(UpdatePhoneNumberError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdatePhoneNumberError::ServiceUnavailable(err.msg),
+                        &res,
+                    )
                 }
                 "ThrottledClientException" => {
-                    return RusotoError::Service(UpdatePhoneNumberError::ThrottledClient(err.msg))
+                    return RusotoError::service(
+                        UpdatePhoneNumberError::ThrottledClient(err.msg),
+                        &res,
+                    )
                 }
                 "UnauthorizedClientException" => {
-                    return RusotoError::Service(UpdatePhoneNumberError::UnauthorizedClient(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdatePhoneNumberError::UnauthorizedClient(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UpdatePhoneNumberError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -9460,12 +10653,14 @@ impl fmt::Display for UpdatePhoneNumberError {
             UpdatePhoneNumberError::ServiceUnavailable(ref cause) => write!(f, "{}", cause),
             UpdatePhoneNumberError::ThrottledClient(ref cause) => write!(f, "{}", cause),
             UpdatePhoneNumberError::UnauthorizedClient(ref cause) => write!(f, "{}", cause),
+            UpdatePhoneNumberError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for UpdatePhoneNumberError {}
 /// Errors returned by UpdatePhoneNumberSettings
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdatePhoneNumberSettingsError {
     /// <p>The input parameters don't match the service's restrictions.</p>
     BadRequest(String),
@@ -9479,6 +10674,8 @@ pub enum UpdatePhoneNumberSettingsError {
     ThrottledClient(String),
     /// <p>The client is not currently authorized to make the request.</p>
     UnauthorizedClient(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdatePhoneNumberSettingsError {
@@ -9486,35 +10683,45 @@ impl UpdatePhoneNumberSettingsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(UpdatePhoneNumberSettingsError::BadRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdatePhoneNumberSettingsError::BadRequest(err.msg),
+                        &res,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(UpdatePhoneNumberSettingsError::Forbidden(err.msg))
+                    return RusotoError::service(
+                        UpdatePhoneNumberSettingsError::Forbidden(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(UpdatePhoneNumberSettingsError::ServiceFailure(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdatePhoneNumberSettingsError::ServiceFailure(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         UpdatePhoneNumberSettingsError::ServiceUnavailable(err.msg),
+                        &res,
                     )
                 }
                 "ThrottledClientException" => {
-                    return RusotoError::Service(UpdatePhoneNumberSettingsError::ThrottledClient(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdatePhoneNumberSettingsError::ThrottledClient(err.msg),
+                        &res,
+                    )
                 }
                 "UnauthorizedClientException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         UpdatePhoneNumberSettingsError::UnauthorizedClient(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(UpdatePhoneNumberSettingsError::Unhandled, &res)
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -9530,12 +10737,14 @@ impl fmt::Display for UpdatePhoneNumberSettingsError {
             UpdatePhoneNumberSettingsError::ServiceUnavailable(ref cause) => write!(f, "{}", cause),
             UpdatePhoneNumberSettingsError::ThrottledClient(ref cause) => write!(f, "{}", cause),
             UpdatePhoneNumberSettingsError::UnauthorizedClient(ref cause) => write!(f, "{}", cause),
+            UpdatePhoneNumberSettingsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for UpdatePhoneNumberSettingsError {}
 /// Errors returned by UpdateRoom
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdateRoomError {
     /// <p>The input parameters don't match the service's restrictions.</p>
     BadRequest(String),
@@ -9551,6 +10760,8 @@ pub enum UpdateRoomError {
     ThrottledClient(String),
     /// <p>The client is not currently authorized to make the request.</p>
     UnauthorizedClient(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdateRoomError {
@@ -9558,28 +10769,28 @@ impl UpdateRoomError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(UpdateRoomError::BadRequest(err.msg))
+                    return RusotoError::service(UpdateRoomError::BadRequest(err.msg), &res)
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(UpdateRoomError::Forbidden(err.msg))
+                    return RusotoError::service(UpdateRoomError::Forbidden(err.msg), &res)
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(UpdateRoomError::NotFound(err.msg))
+                    return RusotoError::service(UpdateRoomError::NotFound(err.msg), &res)
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(UpdateRoomError::ServiceFailure(err.msg))
+                    return RusotoError::service(UpdateRoomError::ServiceFailure(err.msg), &res)
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(UpdateRoomError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(UpdateRoomError::ServiceUnavailable(err.msg), &res)
                 }
                 "ThrottledClientException" => {
-                    return RusotoError::Service(UpdateRoomError::ThrottledClient(err.msg))
+                    return RusotoError::service(UpdateRoomError::ThrottledClient(err.msg), &res)
                 }
                 "UnauthorizedClientException" => {
-                    return RusotoError::Service(UpdateRoomError::UnauthorizedClient(err.msg))
+                    return RusotoError::service(UpdateRoomError::UnauthorizedClient(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UpdateRoomError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -9596,12 +10807,14 @@ impl fmt::Display for UpdateRoomError {
             UpdateRoomError::ServiceUnavailable(ref cause) => write!(f, "{}", cause),
             UpdateRoomError::ThrottledClient(ref cause) => write!(f, "{}", cause),
             UpdateRoomError::UnauthorizedClient(ref cause) => write!(f, "{}", cause),
+            UpdateRoomError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for UpdateRoomError {}
 /// Errors returned by UpdateRoomMembership
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdateRoomMembershipError {
     /// <p>The input parameters don't match the service's restrictions.</p>
     BadRequest(String),
@@ -9617,6 +10830,8 @@ pub enum UpdateRoomMembershipError {
     ThrottledClient(String),
     /// <p>The client is not currently authorized to make the request.</p>
     UnauthorizedClient(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdateRoomMembershipError {
@@ -9624,34 +10839,46 @@ impl UpdateRoomMembershipError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(UpdateRoomMembershipError::BadRequest(err.msg))
+                    return RusotoError::service(
+                        UpdateRoomMembershipError::BadRequest(err.msg),
+                        &res,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(UpdateRoomMembershipError::Forbidden(err.msg))
+                    return RusotoError::service(
+                        UpdateRoomMembershipError::Forbidden(err.msg),
+                        &res,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(UpdateRoomMembershipError::NotFound(err.msg))
+                    return RusotoError::service(UpdateRoomMembershipError::NotFound(err.msg), &res)
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(UpdateRoomMembershipError::ServiceFailure(err.msg))
+                    return RusotoError::service(
+                        UpdateRoomMembershipError::ServiceFailure(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(UpdateRoomMembershipError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateRoomMembershipError::ServiceUnavailable(err.msg),
+                        &res,
+                    )
                 }
                 "ThrottledClientException" => {
-                    return RusotoError::Service(UpdateRoomMembershipError::ThrottledClient(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateRoomMembershipError::ThrottledClient(err.msg),
+                        &res,
+                    )
                 }
                 "UnauthorizedClientException" => {
-                    return RusotoError::Service(UpdateRoomMembershipError::UnauthorizedClient(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateRoomMembershipError::UnauthorizedClient(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UpdateRoomMembershipError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -9668,12 +10895,14 @@ impl fmt::Display for UpdateRoomMembershipError {
             UpdateRoomMembershipError::ServiceUnavailable(ref cause) => write!(f, "{}", cause),
             UpdateRoomMembershipError::ThrottledClient(ref cause) => write!(f, "{}", cause),
             UpdateRoomMembershipError::UnauthorizedClient(ref cause) => write!(f, "{}", cause),
+            UpdateRoomMembershipError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for UpdateRoomMembershipError {}
 /// Errors returned by UpdateUser
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdateUserError {
     /// <p>The input parameters don't match the service's restrictions.</p>
     BadRequest(String),
@@ -9689,6 +10918,8 @@ pub enum UpdateUserError {
     ThrottledClient(String),
     /// <p>The client is not currently authorized to make the request.</p>
     UnauthorizedClient(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdateUserError {
@@ -9696,28 +10927,28 @@ impl UpdateUserError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(UpdateUserError::BadRequest(err.msg))
+                    return RusotoError::service(UpdateUserError::BadRequest(err.msg), &res)
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(UpdateUserError::Forbidden(err.msg))
+                    return RusotoError::service(UpdateUserError::Forbidden(err.msg), &res)
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(UpdateUserError::NotFound(err.msg))
+                    return RusotoError::service(UpdateUserError::NotFound(err.msg), &res)
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(UpdateUserError::ServiceFailure(err.msg))
+                    return RusotoError::service(UpdateUserError::ServiceFailure(err.msg), &res)
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(UpdateUserError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(UpdateUserError::ServiceUnavailable(err.msg), &res)
                 }
                 "ThrottledClientException" => {
-                    return RusotoError::Service(UpdateUserError::ThrottledClient(err.msg))
+                    return RusotoError::service(UpdateUserError::ThrottledClient(err.msg), &res)
                 }
                 "UnauthorizedClientException" => {
-                    return RusotoError::Service(UpdateUserError::UnauthorizedClient(err.msg))
+                    return RusotoError::service(UpdateUserError::UnauthorizedClient(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UpdateUserError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -9734,12 +10965,14 @@ impl fmt::Display for UpdateUserError {
             UpdateUserError::ServiceUnavailable(ref cause) => write!(f, "{}", cause),
             UpdateUserError::ThrottledClient(ref cause) => write!(f, "{}", cause),
             UpdateUserError::UnauthorizedClient(ref cause) => write!(f, "{}", cause),
+            UpdateUserError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for UpdateUserError {}
 /// Errors returned by UpdateUserSettings
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdateUserSettingsError {
     /// <p>The input parameters don't match the service's restrictions.</p>
     BadRequest(String),
@@ -9755,6 +10988,8 @@ pub enum UpdateUserSettingsError {
     ThrottledClient(String),
     /// <p>The client is not currently authorized to make the request.</p>
     UnauthorizedClient(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdateUserSettingsError {
@@ -9762,32 +10997,40 @@ impl UpdateUserSettingsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(UpdateUserSettingsError::BadRequest(err.msg))
+                    return RusotoError::service(UpdateUserSettingsError::BadRequest(err.msg), &res)
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(UpdateUserSettingsError::Forbidden(err.msg))
+                    return RusotoError::service(UpdateUserSettingsError::Forbidden(err.msg), &res)
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(UpdateUserSettingsError::NotFound(err.msg))
+                    return RusotoError::service(UpdateUserSettingsError::NotFound(err.msg), &res)
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(UpdateUserSettingsError::ServiceFailure(err.msg))
+                    return RusotoError::service(
+                        UpdateUserSettingsError::ServiceFailure(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(UpdateUserSettingsError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateUserSettingsError::ServiceUnavailable(err.msg),
+                        &res,
+                    )
                 }
                 "ThrottledClientException" => {
-                    return RusotoError::Service(UpdateUserSettingsError::ThrottledClient(err.msg))
+                    return RusotoError::service(
+                        UpdateUserSettingsError::ThrottledClient(err.msg),
+                        &res,
+                    )
                 }
                 "UnauthorizedClientException" => {
-                    return RusotoError::Service(UpdateUserSettingsError::UnauthorizedClient(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateUserSettingsError::UnauthorizedClient(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UpdateUserSettingsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -9804,12 +11047,14 @@ impl fmt::Display for UpdateUserSettingsError {
             UpdateUserSettingsError::ServiceUnavailable(ref cause) => write!(f, "{}", cause),
             UpdateUserSettingsError::ThrottledClient(ref cause) => write!(f, "{}", cause),
             UpdateUserSettingsError::UnauthorizedClient(ref cause) => write!(f, "{}", cause),
+            UpdateUserSettingsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for UpdateUserSettingsError {}
 /// Errors returned by UpdateVoiceConnector
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdateVoiceConnectorError {
     /// <p>The input parameters don't match the service's restrictions.</p>
     BadRequest(String),
@@ -9825,6 +11070,8 @@ pub enum UpdateVoiceConnectorError {
     ThrottledClient(String),
     /// <p>The client is not currently authorized to make the request.</p>
     UnauthorizedClient(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdateVoiceConnectorError {
@@ -9832,34 +11079,46 @@ impl UpdateVoiceConnectorError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(UpdateVoiceConnectorError::BadRequest(err.msg))
+                    return RusotoError::service(
+                        UpdateVoiceConnectorError::BadRequest(err.msg),
+                        &res,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(UpdateVoiceConnectorError::Forbidden(err.msg))
+                    return RusotoError::service(
+                        UpdateVoiceConnectorError::Forbidden(err.msg),
+                        &res,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(UpdateVoiceConnectorError::NotFound(err.msg))
+                    return RusotoError::service(UpdateVoiceConnectorError::NotFound(err.msg), &res)
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(UpdateVoiceConnectorError::ServiceFailure(err.msg))
+                    return RusotoError::service(
+                        UpdateVoiceConnectorError::ServiceFailure(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(UpdateVoiceConnectorError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateVoiceConnectorError::ServiceUnavailable(err.msg),
+                        &res,
+                    )
                 }
                 "ThrottledClientException" => {
-                    return RusotoError::Service(UpdateVoiceConnectorError::ThrottledClient(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateVoiceConnectorError::ThrottledClient(err.msg),
+                        &res,
+                    )
                 }
                 "UnauthorizedClientException" => {
-                    return RusotoError::Service(UpdateVoiceConnectorError::UnauthorizedClient(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateVoiceConnectorError::UnauthorizedClient(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UpdateVoiceConnectorError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -9876,12 +11135,14 @@ impl fmt::Display for UpdateVoiceConnectorError {
             UpdateVoiceConnectorError::ServiceUnavailable(ref cause) => write!(f, "{}", cause),
             UpdateVoiceConnectorError::ThrottledClient(ref cause) => write!(f, "{}", cause),
             UpdateVoiceConnectorError::UnauthorizedClient(ref cause) => write!(f, "{}", cause),
+            UpdateVoiceConnectorError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for UpdateVoiceConnectorError {}
 /// Errors returned by UpdateVoiceConnectorGroup
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdateVoiceConnectorGroupError {
     /// <p>The input parameters don't match the service's restrictions.</p>
     BadRequest(String),
@@ -9899,6 +11160,8 @@ pub enum UpdateVoiceConnectorGroupError {
     ThrottledClient(String),
     /// <p>The client is not currently authorized to make the request.</p>
     UnauthorizedClient(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdateVoiceConnectorGroupError {
@@ -9906,41 +11169,57 @@ impl UpdateVoiceConnectorGroupError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(UpdateVoiceConnectorGroupError::BadRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateVoiceConnectorGroupError::BadRequest(err.msg),
+                        &res,
+                    )
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(UpdateVoiceConnectorGroupError::Conflict(err.msg))
+                    return RusotoError::service(
+                        UpdateVoiceConnectorGroupError::Conflict(err.msg),
+                        &res,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(UpdateVoiceConnectorGroupError::Forbidden(err.msg))
+                    return RusotoError::service(
+                        UpdateVoiceConnectorGroupError::Forbidden(err.msg),
+                        &res,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(UpdateVoiceConnectorGroupError::NotFound(err.msg))
+                    return RusotoError::service(
+                        UpdateVoiceConnectorGroupError::NotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(UpdateVoiceConnectorGroupError::ServiceFailure(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateVoiceConnectorGroupError::ServiceFailure(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         UpdateVoiceConnectorGroupError::ServiceUnavailable(err.msg),
+                        &res,
                     )
                 }
                 "ThrottledClientException" => {
-                    return RusotoError::Service(UpdateVoiceConnectorGroupError::ThrottledClient(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateVoiceConnectorGroupError::ThrottledClient(err.msg),
+                        &res,
+                    )
                 }
                 "UnauthorizedClientException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         UpdateVoiceConnectorGroupError::UnauthorizedClient(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(UpdateVoiceConnectorGroupError::Unhandled, &res)
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -9958,6 +11237,7 @@ impl fmt::Display for UpdateVoiceConnectorGroupError {
             UpdateVoiceConnectorGroupError::ServiceUnavailable(ref cause) => write!(f, "{}", cause),
             UpdateVoiceConnectorGroupError::ThrottledClient(ref cause) => write!(f, "{}", cause),
             UpdateVoiceConnectorGroupError::UnauthorizedClient(ref cause) => write!(f, "{}", cause),
+            UpdateVoiceConnectorGroupError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
@@ -10577,6 +11857,7 @@ impl ChimeClient {
     /// Creates a client backed by the default tokio event loop.
     ///
     /// The client will use the default credentials provider and tls client.
+    #[cfg(not(target_arch = "wasm32"))]
     pub fn new(region: region::Region) -> ChimeClient {
         ChimeClient {
             client: Client::shared(),
@@ -10602,16 +11883,72 @@ impl ChimeClient {
     pub fn new_with_client(client: Client, region: region::Region) -> ChimeClient {
         ChimeClient { client, region }
     }
+
+    /// Returns a builder of a client, setting any of its region, credentials provider,
+    /// dispatcher, retry policy, endpoint and timeouts.
+    pub fn builder() -> rusoto_core::ClientBuilder<ChimeClient> {
+        rusoto_core::ClientBuilder::new(ChimeClient::new_with_client)
+    }
+
+    /// Returns a clone of this client with the overrides of `config` applied to its
+    /// operations, sharing its connections.
+    pub fn customize(&self, config: &rusoto_core::OperationConfig) -> ChimeClient {
+        ChimeClient {
+            client: self.client.customize(config),
+            region: config.resolve_region(&self.region),
+        }
+    }
 }
 
-#[async_trait]
-impl Chime for ChimeClient {
-    /// <p>Associates a phone number with the specified Amazon Chime user.</p>
-    async fn associate_phone_number_with_user(
+impl ChimeClient {
+    /// Calls `associate_phone_number_with_user`, also returning the metadata of the response.
+    pub async fn associate_phone_number_with_user_with_metadata(
         &self,
         input: AssociatePhoneNumberWithUserRequest,
-    ) -> Result<AssociatePhoneNumberWithUserResponse, RusotoError<AssociatePhoneNumberWithUserError>>
-    {
+    ) -> Result<
+        (AssociatePhoneNumberWithUserResponse, ResponseMetadata),
+        RusotoError<AssociatePhoneNumberWithUserError>,
+    > {
+        let request = self.build_associate_phone_number_with_user_request(input);
+
+        let mut response = self
+            .client
+            .sign_and_dispatch(request)
+            .await
+            .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
+        if response.status.as_u16() == 200 {
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<AssociatePhoneNumberWithUserResponse, _>()?;
+
+            Ok((result, metadata))
+        } else {
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(AssociatePhoneNumberWithUserError::from_response(response))
+        }
+    }
+
+    /// Presigns the request `associate_phone_number_with_user` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_associate_phone_number_with_user(
+        &self,
+        presigner: &rusoto_core::Presigner,
+        input: AssociatePhoneNumberWithUserRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_associate_phone_number_with_user_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_associate_phone_number_with_user_request(
+        &self,
+        input: AssociatePhoneNumberWithUserRequest,
+    ) -> SignedRequest {
         let request_uri = format!(
             "/accounts/{account_id}/users/{user_id}",
             account_id = input.account_id,
@@ -10619,6 +11956,7 @@ impl Chime for ChimeClient {
         );
 
         let mut request = SignedRequest::new("POST", "chime", &self.region, &request_uri);
+        request.set_operation("AssociatePhoneNumberWithUser");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -10628,37 +11966,69 @@ impl Chime for ChimeClient {
         params.put("operation", "associate-phone-number");
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `associate_phone_numbers_with_voice_connector`, also returning the metadata of the response.
+    pub async fn associate_phone_numbers_with_voice_connector_with_metadata(
+        &self,
+        input: AssociatePhoneNumbersWithVoiceConnectorRequest,
+    ) -> Result<
+        (
+            AssociatePhoneNumbersWithVoiceConnectorResponse,
+            ResponseMetadata,
+        ),
+        RusotoError<AssociatePhoneNumbersWithVoiceConnectorError>,
+    > {
+        let request = self.build_associate_phone_numbers_with_voice_connector_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<AssociatePhoneNumberWithUserResponse, _>()?;
+                .deserialize::<AssociatePhoneNumbersWithVoiceConnectorResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(AssociatePhoneNumberWithUserError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(AssociatePhoneNumbersWithVoiceConnectorError::from_response(
+                response,
+            ))
         }
     }
 
-    /// <p>Associates phone numbers with the specified Amazon Chime Voice Connector.</p>
-    async fn associate_phone_numbers_with_voice_connector(
+    /// Presigns the request `associate_phone_numbers_with_voice_connector` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_associate_phone_numbers_with_voice_connector(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: AssociatePhoneNumbersWithVoiceConnectorRequest,
-    ) -> Result<
-        AssociatePhoneNumbersWithVoiceConnectorResponse,
-        RusotoError<AssociatePhoneNumbersWithVoiceConnectorError>,
-    > {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_associate_phone_numbers_with_voice_connector_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_associate_phone_numbers_with_voice_connector_request(
+        &self,
+        input: AssociatePhoneNumbersWithVoiceConnectorRequest,
+    ) -> SignedRequest {
         let request_uri = format!(
             "/voice-connectors/{voice_connector_id}",
             voice_connector_id = input.voice_connector_id
         );
 
         let mut request = SignedRequest::new("POST", "chime", &self.region, &request_uri);
+        request.set_operation("AssociatePhoneNumbersWithVoiceConnector");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -10668,39 +12038,68 @@ impl Chime for ChimeClient {
         params.put("operation", "associate-phone-numbers");
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `associate_phone_numbers_with_voice_connector_group`, also returning the metadata of the response.
+    pub async fn associate_phone_numbers_with_voice_connector_group_with_metadata(
+        &self,
+        input: AssociatePhoneNumbersWithVoiceConnectorGroupRequest,
+    ) -> Result<
+        (
+            AssociatePhoneNumbersWithVoiceConnectorGroupResponse,
+            ResponseMetadata,
+        ),
+        RusotoError<AssociatePhoneNumbersWithVoiceConnectorGroupError>,
+    > {
+        let request = self.build_associate_phone_numbers_with_voice_connector_group_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<AssociatePhoneNumbersWithVoiceConnectorResponse, _>()?;
+                .deserialize::<AssociatePhoneNumbersWithVoiceConnectorGroupResponse, _>(
+            )?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(AssociatePhoneNumbersWithVoiceConnectorError::from_response(
-                response,
-            ))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(AssociatePhoneNumbersWithVoiceConnectorGroupError::from_response(response))
         }
     }
 
-    /// <p>Associates phone numbers with the specified Amazon Chime Voice Connector group.</p>
-    async fn associate_phone_numbers_with_voice_connector_group(
+    /// Presigns the request `associate_phone_numbers_with_voice_connector_group` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_associate_phone_numbers_with_voice_connector_group(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: AssociatePhoneNumbersWithVoiceConnectorGroupRequest,
-    ) -> Result<
-        AssociatePhoneNumbersWithVoiceConnectorGroupResponse,
-        RusotoError<AssociatePhoneNumbersWithVoiceConnectorGroupError>,
-    > {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_associate_phone_numbers_with_voice_connector_group_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_associate_phone_numbers_with_voice_connector_group_request(
+        &self,
+        input: AssociatePhoneNumbersWithVoiceConnectorGroupRequest,
+    ) -> SignedRequest {
         let request_uri = format!(
             "/voice-connector-groups/{voice_connector_group_id}",
             voice_connector_group_id = input.voice_connector_group_id
         );
 
         let mut request = SignedRequest::new("POST", "chime", &self.region, &request_uri);
+        request.set_operation("AssociatePhoneNumbersWithVoiceConnectorGroup");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -10710,35 +12109,64 @@ impl Chime for ChimeClient {
         params.put("operation", "associate-phone-numbers");
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `associate_signin_delegate_groups_with_account`, also returning the metadata of the response.
+    pub async fn associate_signin_delegate_groups_with_account_with_metadata(
+        &self,
+        input: AssociateSigninDelegateGroupsWithAccountRequest,
+    ) -> Result<
+        (
+            AssociateSigninDelegateGroupsWithAccountResponse,
+            ResponseMetadata,
+        ),
+        RusotoError<AssociateSigninDelegateGroupsWithAccountError>,
+    > {
+        let request = self.build_associate_signin_delegate_groups_with_account_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<AssociatePhoneNumbersWithVoiceConnectorGroupResponse, _>(
-            )?;
+                .deserialize::<AssociateSigninDelegateGroupsWithAccountResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(AssociatePhoneNumbersWithVoiceConnectorGroupError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(AssociateSigninDelegateGroupsWithAccountError::from_response(response))
         }
     }
 
-    /// <p>Associates the specified sign-in delegate groups with the specified Amazon Chime account.</p>
-    async fn associate_signin_delegate_groups_with_account(
+    /// Presigns the request `associate_signin_delegate_groups_with_account` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_associate_signin_delegate_groups_with_account(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: AssociateSigninDelegateGroupsWithAccountRequest,
-    ) -> Result<
-        AssociateSigninDelegateGroupsWithAccountResponse,
-        RusotoError<AssociateSigninDelegateGroupsWithAccountError>,
-    > {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_associate_signin_delegate_groups_with_account_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_associate_signin_delegate_groups_with_account_request(
+        &self,
+        input: AssociateSigninDelegateGroupsWithAccountRequest,
+    ) -> SignedRequest {
         let request_uri = format!("/accounts/{account_id}", account_id = input.account_id);
 
         let mut request = SignedRequest::new("POST", "chime", &self.region, &request_uri);
+        request.set_operation("AssociateSigninDelegateGroupsWithAccount");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -10748,34 +12176,64 @@ impl Chime for ChimeClient {
         params.put("operation", "associate-signin-delegate-groups");
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `batch_create_attendee`, also returning the metadata of the response.
+    pub async fn batch_create_attendee_with_metadata(
+        &self,
+        input: BatchCreateAttendeeRequest,
+    ) -> Result<
+        (BatchCreateAttendeeResponse, ResponseMetadata),
+        RusotoError<BatchCreateAttendeeError>,
+    > {
+        let request = self.build_batch_create_attendee_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
-        if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let metadata = ResponseMetadata::from_response(&response);
+        if response.status.as_u16() == 201 {
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<AssociateSigninDelegateGroupsWithAccountResponse, _>()?;
+                .deserialize::<BatchCreateAttendeeResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(AssociateSigninDelegateGroupsWithAccountError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(BatchCreateAttendeeError::from_response(response))
         }
     }
 
-    /// <p>Creates up to 100 new attendees for an active Amazon Chime SDK meeting. For more information about the Amazon Chime SDK, see <a href="https://docs.aws.amazon.com/chime/latest/dg/meetings-sdk.html">Using the Amazon Chime SDK</a> in the <i>Amazon Chime Developer Guide</i>. </p>
-    async fn batch_create_attendee(
+    /// Presigns the request `batch_create_attendee` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_batch_create_attendee(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: BatchCreateAttendeeRequest,
-    ) -> Result<BatchCreateAttendeeResponse, RusotoError<BatchCreateAttendeeError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_batch_create_attendee_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_batch_create_attendee_request(
+        &self,
+        input: BatchCreateAttendeeRequest,
+    ) -> SignedRequest {
         let request_uri = format!(
             "/meetings/{meeting_id}/attendees",
             meeting_id = input.meeting_id
         );
 
         let mut request = SignedRequest::new("POST", "chime", &self.region, &request_uri);
+        request.set_operation("BatchCreateAttendee");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -10785,36 +12243,65 @@ impl Chime for ChimeClient {
         params.put("operation", "batch-create");
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `batch_create_room_membership`, also returning the metadata of the response.
+    pub async fn batch_create_room_membership_with_metadata(
+        &self,
+        input: BatchCreateRoomMembershipRequest,
+    ) -> Result<
+        (BatchCreateRoomMembershipResponse, ResponseMetadata),
+        RusotoError<BatchCreateRoomMembershipError>,
+    > {
+        let request = self.build_batch_create_room_membership_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 201 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<BatchCreateAttendeeResponse, _>()?;
+                .deserialize::<BatchCreateRoomMembershipResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(BatchCreateAttendeeError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(BatchCreateRoomMembershipError::from_response(response))
         }
     }
 
-    /// <p>Adds up to 50 members to a chat room. Members can be either users or bots. The member role designates whether the member is a chat room administrator or a general chat room member.</p>
-    async fn batch_create_room_membership(
+    /// Presigns the request `batch_create_room_membership` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_batch_create_room_membership(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: BatchCreateRoomMembershipRequest,
-    ) -> Result<BatchCreateRoomMembershipResponse, RusotoError<BatchCreateRoomMembershipError>>
-    {
-        let request_uri = format!(
-            "/accounts/{account_id}/rooms/{room_id}/memberships",
-            account_id = input.account_id,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_batch_create_room_membership_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_batch_create_room_membership_request(
+        &self,
+        input: BatchCreateRoomMembershipRequest,
+    ) -> SignedRequest {
+        let request_uri = format!(
+            "/accounts/{account_id}/rooms/{room_id}/memberships",
+            account_id = input.account_id,
             room_id = input.room_id
         );
 
         let mut request = SignedRequest::new("POST", "chime", &self.region, &request_uri);
+        request.set_operation("BatchCreateRoomMembership");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -10824,31 +12311,61 @@ impl Chime for ChimeClient {
         params.put("operation", "batch-create");
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `batch_delete_phone_number`, also returning the metadata of the response.
+    pub async fn batch_delete_phone_number_with_metadata(
+        &self,
+        input: BatchDeletePhoneNumberRequest,
+    ) -> Result<
+        (BatchDeletePhoneNumberResponse, ResponseMetadata),
+        RusotoError<BatchDeletePhoneNumberError>,
+    > {
+        let request = self.build_batch_delete_phone_number_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
-        if response.status.as_u16() == 201 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let metadata = ResponseMetadata::from_response(&response);
+        if response.status.as_u16() == 200 {
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<BatchCreateRoomMembershipResponse, _>()?;
+                .deserialize::<BatchDeletePhoneNumberResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(BatchCreateRoomMembershipError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(BatchDeletePhoneNumberError::from_response(response))
         }
     }
 
-    /// <p>Moves phone numbers into the <b>Deletion queue</b>. Phone numbers must be disassociated from any users or Amazon Chime Voice Connectors before they can be deleted.</p> <p>Phone numbers remain in the <b>Deletion queue</b> for 7 days before they are deleted permanently.</p>
-    async fn batch_delete_phone_number(
+    /// Presigns the request `batch_delete_phone_number` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_batch_delete_phone_number(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: BatchDeletePhoneNumberRequest,
-    ) -> Result<BatchDeletePhoneNumberResponse, RusotoError<BatchDeletePhoneNumberError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_batch_delete_phone_number_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_batch_delete_phone_number_request(
+        &self,
+        input: BatchDeletePhoneNumberRequest,
+    ) -> SignedRequest {
         let request_uri = "/phone-numbers";
 
         let mut request = SignedRequest::new("POST", "chime", &self.region, &request_uri);
+        request.set_operation("BatchDeletePhoneNumber");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -10858,34 +12375,59 @@ impl Chime for ChimeClient {
         params.put("operation", "batch-delete");
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `batch_suspend_user`, also returning the metadata of the response.
+    pub async fn batch_suspend_user_with_metadata(
+        &self,
+        input: BatchSuspendUserRequest,
+    ) -> Result<(BatchSuspendUserResponse, ResponseMetadata), RusotoError<BatchSuspendUserError>>
+    {
+        let request = self.build_batch_suspend_user_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<BatchDeletePhoneNumberResponse, _>()?;
+                .deserialize::<BatchSuspendUserResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(BatchDeletePhoneNumberError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(BatchSuspendUserError::from_response(response))
         }
     }
 
-    /// <p>Suspends up to 50 users from a <code>Team</code> or <code>EnterpriseLWA</code> Amazon Chime account. For more information about different account types, see <a href="https://docs.aws.amazon.com/chime/latest/ag/manage-chime-account.html">Managing Your Amazon Chime Accounts</a> in the <i>Amazon Chime Administration Guide</i>.</p> <p>Users suspended from a <code>Team</code> account are disassociated from the account, but they can continue to use Amazon Chime as free users. To remove the suspension from suspended <code>Team</code> account users, invite them to the <code>Team</code> account again. You can use the <a>InviteUsers</a> action to do so.</p> <p>Users suspended from an <code>EnterpriseLWA</code> account are immediately signed out of Amazon Chime and can no longer sign in. To remove the suspension from suspended <code>EnterpriseLWA</code> account users, use the <a>BatchUnsuspendUser</a> action. </p> <p>To sign out users without suspending them, use the <a>LogoutUser</a> action.</p>
-    async fn batch_suspend_user(
+    /// Presigns the request `batch_suspend_user` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_batch_suspend_user(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: BatchSuspendUserRequest,
-    ) -> Result<BatchSuspendUserResponse, RusotoError<BatchSuspendUserError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_batch_suspend_user_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_batch_suspend_user_request(&self, input: BatchSuspendUserRequest) -> SignedRequest {
         let request_uri = format!(
             "/accounts/{account_id}/users",
             account_id = input.account_id
         );
 
         let mut request = SignedRequest::new("POST", "chime", &self.region, &request_uri);
+        request.set_operation("BatchSuspendUser");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -10895,34 +12437,62 @@ impl Chime for ChimeClient {
         params.put("operation", "suspend");
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `batch_unsuspend_user`, also returning the metadata of the response.
+    pub async fn batch_unsuspend_user_with_metadata(
+        &self,
+        input: BatchUnsuspendUserRequest,
+    ) -> Result<(BatchUnsuspendUserResponse, ResponseMetadata), RusotoError<BatchUnsuspendUserError>>
+    {
+        let request = self.build_batch_unsuspend_user_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<BatchSuspendUserResponse, _>()?;
+                .deserialize::<BatchUnsuspendUserResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(BatchSuspendUserError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(BatchUnsuspendUserError::from_response(response))
         }
     }
 
-    /// <p>Removes the suspension from up to 50 previously suspended users for the specified Amazon Chime <code>EnterpriseLWA</code> account. Only users on <code>EnterpriseLWA</code> accounts can be unsuspended using this action. For more information about different account types, see <a href="https://docs.aws.amazon.com/chime/latest/ag/manage-chime-account.html">Managing Your Amazon Chime Accounts</a> in the <i>Amazon Chime Administration Guide</i>.</p> <p>Previously suspended users who are unsuspended using this action are returned to <code>Registered</code> status. Users who are not previously suspended are ignored.</p>
-    async fn batch_unsuspend_user(
+    /// Presigns the request `batch_unsuspend_user` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_batch_unsuspend_user(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: BatchUnsuspendUserRequest,
-    ) -> Result<BatchUnsuspendUserResponse, RusotoError<BatchUnsuspendUserError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_batch_unsuspend_user_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_batch_unsuspend_user_request(
+        &self,
+        input: BatchUnsuspendUserRequest,
+    ) -> SignedRequest {
         let request_uri = format!(
             "/accounts/{account_id}/users",
             account_id = input.account_id
         );
 
         let mut request = SignedRequest::new("POST", "chime", &self.region, &request_uri);
+        request.set_operation("BatchUnsuspendUser");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -10932,31 +12502,61 @@ impl Chime for ChimeClient {
         params.put("operation", "unsuspend");
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `batch_update_phone_number`, also returning the metadata of the response.
+    pub async fn batch_update_phone_number_with_metadata(
+        &self,
+        input: BatchUpdatePhoneNumberRequest,
+    ) -> Result<
+        (BatchUpdatePhoneNumberResponse, ResponseMetadata),
+        RusotoError<BatchUpdatePhoneNumberError>,
+    > {
+        let request = self.build_batch_update_phone_number_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<BatchUnsuspendUserResponse, _>()?;
+                .deserialize::<BatchUpdatePhoneNumberResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(BatchUnsuspendUserError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(BatchUpdatePhoneNumberError::from_response(response))
         }
     }
 
-    /// <p>Updates phone number product types or calling names. You can update one attribute at a time for each <code>UpdatePhoneNumberRequestItem</code>. For example, you can update either the product type or the calling name.</p> <p>For product types, choose from Amazon Chime Business Calling and Amazon Chime Voice Connector. For toll-free numbers, you must use the Amazon Chime Voice Connector product type.</p> <p>Updates to outbound calling names can take up to 72 hours to complete. Pending updates to outbound calling names must be complete before you can request another update.</p>
-    async fn batch_update_phone_number(
+    /// Presigns the request `batch_update_phone_number` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_batch_update_phone_number(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: BatchUpdatePhoneNumberRequest,
-    ) -> Result<BatchUpdatePhoneNumberResponse, RusotoError<BatchUpdatePhoneNumberError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_batch_update_phone_number_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_batch_update_phone_number_request(
+        &self,
+        input: BatchUpdatePhoneNumberRequest,
+    ) -> SignedRequest {
         let request_uri = "/phone-numbers";
 
         let mut request = SignedRequest::new("POST", "chime", &self.region, &request_uri);
+        request.set_operation("BatchUpdatePhoneNumber");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -10966,247 +12566,451 @@ impl Chime for ChimeClient {
         params.put("operation", "batch-update");
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `batch_update_user`, also returning the metadata of the response.
+    pub async fn batch_update_user_with_metadata(
+        &self,
+        input: BatchUpdateUserRequest,
+    ) -> Result<(BatchUpdateUserResponse, ResponseMetadata), RusotoError<BatchUpdateUserError>>
+    {
+        let request = self.build_batch_update_user_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<BatchUpdatePhoneNumberResponse, _>()?;
+                .deserialize::<BatchUpdateUserResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(BatchUpdatePhoneNumberError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(BatchUpdateUserError::from_response(response))
         }
     }
 
-    /// <p>Updates user details within the <a>UpdateUserRequestItem</a> object for up to 20 users for the specified Amazon Chime account. Currently, only <code>LicenseType</code> updates are supported for this action.</p>
-    async fn batch_update_user(
+    /// Presigns the request `batch_update_user` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_batch_update_user(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: BatchUpdateUserRequest,
-    ) -> Result<BatchUpdateUserResponse, RusotoError<BatchUpdateUserError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_batch_update_user_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_batch_update_user_request(&self, input: BatchUpdateUserRequest) -> SignedRequest {
         let request_uri = format!(
             "/accounts/{account_id}/users",
             account_id = input.account_id
         );
 
         let mut request = SignedRequest::new("POST", "chime", &self.region, &request_uri);
+        request.set_operation("BatchUpdateUser");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `create_account`, also returning the metadata of the response.
+    pub async fn create_account_with_metadata(
+        &self,
+        input: CreateAccountRequest,
+    ) -> Result<(CreateAccountResponse, ResponseMetadata), RusotoError<CreateAccountError>> {
+        let request = self.build_create_account_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
-        if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let metadata = ResponseMetadata::from_response(&response);
+        if response.status.as_u16() == 201 {
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<BatchUpdateUserResponse, _>()?;
+                .deserialize::<CreateAccountResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(BatchUpdateUserError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(CreateAccountError::from_response(response))
         }
     }
 
-    /// <p>Creates an Amazon Chime account under the administrator's AWS account. Only <code>Team</code> account types are currently supported for this action. For more information about different account types, see <a href="https://docs.aws.amazon.com/chime/latest/ag/manage-chime-account.html">Managing Your Amazon Chime Accounts</a> in the <i>Amazon Chime Administration Guide</i>.</p>
-    async fn create_account(
+    /// Presigns the request `create_account` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_account(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: CreateAccountRequest,
-    ) -> Result<CreateAccountResponse, RusotoError<CreateAccountError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_account_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_account_request(&self, input: CreateAccountRequest) -> SignedRequest {
         let request_uri = "/accounts";
 
         let mut request = SignedRequest::new("POST", "chime", &self.region, &request_uri);
+        request.set_operation("CreateAccount");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `create_attendee`, also returning the metadata of the response.
+    pub async fn create_attendee_with_metadata(
+        &self,
+        input: CreateAttendeeRequest,
+    ) -> Result<(CreateAttendeeResponse, ResponseMetadata), RusotoError<CreateAttendeeError>> {
+        let request = self.build_create_attendee_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 201 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<CreateAccountResponse, _>()?;
+                .deserialize::<CreateAttendeeResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(CreateAccountError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(CreateAttendeeError::from_response(response))
         }
     }
 
-    /// <p>Creates a new attendee for an active Amazon Chime SDK meeting. For more information about the Amazon Chime SDK, see <a href="https://docs.aws.amazon.com/chime/latest/dg/meetings-sdk.html">Using the Amazon Chime SDK</a> in the <i>Amazon Chime Developer Guide</i>.</p>
-    async fn create_attendee(
+    /// Presigns the request `create_attendee` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_attendee(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: CreateAttendeeRequest,
-    ) -> Result<CreateAttendeeResponse, RusotoError<CreateAttendeeError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_attendee_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_attendee_request(&self, input: CreateAttendeeRequest) -> SignedRequest {
         let request_uri = format!(
             "/meetings/{meeting_id}/attendees",
             meeting_id = input.meeting_id
         );
 
         let mut request = SignedRequest::new("POST", "chime", &self.region, &request_uri);
+        request.set_operation("CreateAttendee");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `create_bot`, also returning the metadata of the response.
+    pub async fn create_bot_with_metadata(
+        &self,
+        input: CreateBotRequest,
+    ) -> Result<(CreateBotResponse, ResponseMetadata), RusotoError<CreateBotError>> {
+        let request = self.build_create_bot_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 201 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<CreateAttendeeResponse, _>()?;
+                .deserialize::<CreateBotResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(CreateAttendeeError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(CreateBotError::from_response(response))
         }
     }
 
-    /// <p>Creates a bot for an Amazon Chime Enterprise account.</p>
-    async fn create_bot(
+    /// Presigns the request `create_bot` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_bot(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: CreateBotRequest,
-    ) -> Result<CreateBotResponse, RusotoError<CreateBotError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_bot_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_bot_request(&self, input: CreateBotRequest) -> SignedRequest {
         let request_uri = format!("/accounts/{account_id}/bots", account_id = input.account_id);
 
         let mut request = SignedRequest::new("POST", "chime", &self.region, &request_uri);
+        request.set_operation("CreateBot");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `create_meeting`, also returning the metadata of the response.
+    pub async fn create_meeting_with_metadata(
+        &self,
+        input: CreateMeetingRequest,
+    ) -> Result<(CreateMeetingResponse, ResponseMetadata), RusotoError<CreateMeetingError>> {
+        let request = self.build_create_meeting_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 201 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<CreateBotResponse, _>()?;
+                .deserialize::<CreateMeetingResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(CreateBotError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(CreateMeetingError::from_response(response))
         }
     }
 
-    /// <p>Creates a new Amazon Chime SDK meeting in the specified media Region with no initial attendees. For more information about the Amazon Chime SDK, see <a href="https://docs.aws.amazon.com/chime/latest/dg/meetings-sdk.html">Using the Amazon Chime SDK</a> in the <i>Amazon Chime Developer Guide</i>.</p>
-    async fn create_meeting(
+    /// Presigns the request `create_meeting` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_meeting(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: CreateMeetingRequest,
-    ) -> Result<CreateMeetingResponse, RusotoError<CreateMeetingError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_meeting_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_meeting_request(&self, input: CreateMeetingRequest) -> SignedRequest {
         let request_uri = "/meetings";
 
         let mut request = SignedRequest::new("POST", "chime", &self.region, &request_uri);
+        request.set_operation("CreateMeeting");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `create_phone_number_order`, also returning the metadata of the response.
+    pub async fn create_phone_number_order_with_metadata(
+        &self,
+        input: CreatePhoneNumberOrderRequest,
+    ) -> Result<
+        (CreatePhoneNumberOrderResponse, ResponseMetadata),
+        RusotoError<CreatePhoneNumberOrderError>,
+    > {
+        let request = self.build_create_phone_number_order_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 201 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<CreateMeetingResponse, _>()?;
+                .deserialize::<CreatePhoneNumberOrderResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(CreateMeetingError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(CreatePhoneNumberOrderError::from_response(response))
         }
     }
 
-    /// <p>Creates an order for phone numbers to be provisioned. Choose from Amazon Chime Business Calling and Amazon Chime Voice Connector product types. For toll-free numbers, you must use the Amazon Chime Voice Connector product type.</p>
-    async fn create_phone_number_order(
+    /// Presigns the request `create_phone_number_order` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_phone_number_order(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: CreatePhoneNumberOrderRequest,
-    ) -> Result<CreatePhoneNumberOrderResponse, RusotoError<CreatePhoneNumberOrderError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_phone_number_order_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_phone_number_order_request(
+        &self,
+        input: CreatePhoneNumberOrderRequest,
+    ) -> SignedRequest {
         let request_uri = "/phone-number-orders";
 
         let mut request = SignedRequest::new("POST", "chime", &self.region, &request_uri);
+        request.set_operation("CreatePhoneNumberOrder");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `create_room`, also returning the metadata of the response.
+    pub async fn create_room_with_metadata(
+        &self,
+        input: CreateRoomRequest,
+    ) -> Result<(CreateRoomResponse, ResponseMetadata), RusotoError<CreateRoomError>> {
+        let request = self.build_create_room_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 201 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<CreatePhoneNumberOrderResponse, _>()?;
+                .deserialize::<CreateRoomResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(CreatePhoneNumberOrderError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(CreateRoomError::from_response(response))
         }
     }
 
-    /// <p>Creates a chat room for the specified Amazon Chime account.</p>
-    async fn create_room(
+    /// Presigns the request `create_room` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_room(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: CreateRoomRequest,
-    ) -> Result<CreateRoomResponse, RusotoError<CreateRoomError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_room_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_room_request(&self, input: CreateRoomRequest) -> SignedRequest {
         let request_uri = format!(
             "/accounts/{account_id}/rooms",
             account_id = input.account_id
         );
 
         let mut request = SignedRequest::new("POST", "chime", &self.region, &request_uri);
+        request.set_operation("CreateRoom");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `create_room_membership`, also returning the metadata of the response.
+    pub async fn create_room_membership_with_metadata(
+        &self,
+        input: CreateRoomMembershipRequest,
+    ) -> Result<
+        (CreateRoomMembershipResponse, ResponseMetadata),
+        RusotoError<CreateRoomMembershipError>,
+    > {
+        let request = self.build_create_room_membership_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 201 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<CreateRoomResponse, _>()?;
+                .deserialize::<CreateRoomMembershipResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(CreateRoomError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(CreateRoomMembershipError::from_response(response))
         }
     }
 
-    /// <p>Adds a member to a chat room. A member can be either a user or a bot. The member role designates whether the member is a chat room administrator or a general chat room member.</p>
-    async fn create_room_membership(
+    /// Presigns the request `create_room_membership` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_room_membership(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: CreateRoomMembershipRequest,
-    ) -> Result<CreateRoomMembershipResponse, RusotoError<CreateRoomMembershipError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_room_membership_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_room_membership_request(
+        &self,
+        input: CreateRoomMembershipRequest,
+    ) -> SignedRequest {
         let request_uri = format!(
             "/accounts/{account_id}/rooms/{room_id}/memberships",
             account_id = input.account_id,
@@ -11214,39 +13018,64 @@ impl Chime for ChimeClient {
         );
 
         let mut request = SignedRequest::new("POST", "chime", &self.region, &request_uri);
+        request.set_operation("CreateRoomMembership");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `create_user`, also returning the metadata of the response.
+    pub async fn create_user_with_metadata(
+        &self,
+        input: CreateUserRequest,
+    ) -> Result<(CreateUserResponse, ResponseMetadata), RusotoError<CreateUserError>> {
+        let request = self.build_create_user_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 201 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<CreateRoomMembershipResponse, _>()?;
+                .deserialize::<CreateUserResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(CreateRoomMembershipError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(CreateUserError::from_response(response))
         }
     }
 
-    /// <p>Creates a user under the specified Amazon Chime account.</p>
-    async fn create_user(
+    /// Presigns the request `create_user` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_user(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: CreateUserRequest,
-    ) -> Result<CreateUserResponse, RusotoError<CreateUserError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_user_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_user_request(&self, input: CreateUserRequest) -> SignedRequest {
         let request_uri = format!(
             "/accounts/{account_id}/users",
             account_id = input.account_id
         );
 
         let mut request = SignedRequest::new("POST", "chime", &self.region, &request_uri);
+        request.set_operation("CreateUser");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -11256,116 +13085,221 @@ impl Chime for ChimeClient {
         params.put("operation", "create");
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `create_voice_connector`, also returning the metadata of the response.
+    pub async fn create_voice_connector_with_metadata(
+        &self,
+        input: CreateVoiceConnectorRequest,
+    ) -> Result<
+        (CreateVoiceConnectorResponse, ResponseMetadata),
+        RusotoError<CreateVoiceConnectorError>,
+    > {
+        let request = self.build_create_voice_connector_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 201 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<CreateUserResponse, _>()?;
+                .deserialize::<CreateVoiceConnectorResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(CreateUserError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(CreateVoiceConnectorError::from_response(response))
         }
     }
 
-    /// <p>Creates an Amazon Chime Voice Connector under the administrator's AWS account. You can choose to create an Amazon Chime Voice Connector in a specific AWS Region.</p> <p>Enabling <a>CreateVoiceConnectorRequest$RequireEncryption</a> configures your Amazon Chime Voice Connector to use TLS transport for SIP signaling and Secure RTP (SRTP) for media. Inbound calls use TLS transport, and unencrypted outbound calls are blocked.</p>
-    async fn create_voice_connector(
+    /// Presigns the request `create_voice_connector` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_voice_connector(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: CreateVoiceConnectorRequest,
-    ) -> Result<CreateVoiceConnectorResponse, RusotoError<CreateVoiceConnectorError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_voice_connector_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_voice_connector_request(
+        &self,
+        input: CreateVoiceConnectorRequest,
+    ) -> SignedRequest {
         let request_uri = "/voice-connectors";
 
         let mut request = SignedRequest::new("POST", "chime", &self.region, &request_uri);
+        request.set_operation("CreateVoiceConnector");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `create_voice_connector_group`, also returning the metadata of the response.
+    pub async fn create_voice_connector_group_with_metadata(
+        &self,
+        input: CreateVoiceConnectorGroupRequest,
+    ) -> Result<
+        (CreateVoiceConnectorGroupResponse, ResponseMetadata),
+        RusotoError<CreateVoiceConnectorGroupError>,
+    > {
+        let request = self.build_create_voice_connector_group_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 201 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<CreateVoiceConnectorResponse, _>()?;
+                .deserialize::<CreateVoiceConnectorGroupResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(CreateVoiceConnectorError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(CreateVoiceConnectorGroupError::from_response(response))
         }
     }
 
-    /// <p>Creates an Amazon Chime Voice Connector group under the administrator's AWS account. You can associate up to three existing Amazon Chime Voice Connectors with the Amazon Chime Voice Connector group by including <code>VoiceConnectorItems</code> in the request.</p> <p>You can include Amazon Chime Voice Connectors from different AWS Regions in your group. This creates a fault tolerant mechanism for fallback in case of availability events.</p>
-    async fn create_voice_connector_group(
+    /// Presigns the request `create_voice_connector_group` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_voice_connector_group(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: CreateVoiceConnectorGroupRequest,
-    ) -> Result<CreateVoiceConnectorGroupResponse, RusotoError<CreateVoiceConnectorGroupError>>
-    {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_voice_connector_group_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_voice_connector_group_request(
+        &self,
+        input: CreateVoiceConnectorGroupRequest,
+    ) -> SignedRequest {
         let request_uri = "/voice-connector-groups";
 
         let mut request = SignedRequest::new("POST", "chime", &self.region, &request_uri);
+        request.set_operation("CreateVoiceConnectorGroup");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `delete_account`, also returning the metadata of the response.
+    pub async fn delete_account_with_metadata(
+        &self,
+        input: DeleteAccountRequest,
+    ) -> Result<(DeleteAccountResponse, ResponseMetadata), RusotoError<DeleteAccountError>> {
+        let request = self.build_delete_account_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
-        if response.status.as_u16() == 201 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let metadata = ResponseMetadata::from_response(&response);
+        if response.status.as_u16() == 204 {
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<CreateVoiceConnectorGroupResponse, _>()?;
+                .deserialize::<DeleteAccountResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(CreateVoiceConnectorGroupError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(DeleteAccountError::from_response(response))
         }
     }
 
-    /// <p>Deletes the specified Amazon Chime account. You must suspend all users before deleting a <code>Team</code> account. You can use the <a>BatchSuspendUser</a> action to do so.</p> <p>For <code>EnterpriseLWA</code> and <code>EnterpriseAD</code> accounts, you must release the claimed domains for your Amazon Chime account before deletion. As soon as you release the domain, all users under that account are suspended.</p> <p>Deleted accounts appear in your <code>Disabled</code> accounts list for 90 days. To restore a deleted account from your <code>Disabled</code> accounts list, you must contact AWS Support.</p> <p>After 90 days, deleted accounts are permanently removed from your <code>Disabled</code> accounts list.</p>
-    async fn delete_account(
+    /// Presigns the request `delete_account` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_account(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteAccountRequest,
-    ) -> Result<DeleteAccountResponse, RusotoError<DeleteAccountError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_account_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_account_request(&self, input: DeleteAccountRequest) -> SignedRequest {
         let request_uri = format!("/accounts/{account_id}", account_id = input.account_id);
 
         let mut request = SignedRequest::new("DELETE", "chime", &self.region, &request_uri);
+        request.set_operation("DeleteAccount");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
+        request
+    }
+
+    /// Calls `delete_attendee`, also returning the metadata of the response.
+    pub async fn delete_attendee_with_metadata(
+        &self,
+        input: DeleteAttendeeRequest,
+    ) -> Result<((), ResponseMetadata), RusotoError<DeleteAttendeeError>> {
+        let request = self.build_delete_attendee_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 204 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<DeleteAccountResponse, _>()?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = ::std::mem::drop(response);
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteAccountError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(DeleteAttendeeError::from_response(response))
         }
     }
 
-    /// <p>Deletes an attendee from the specified Amazon Chime SDK meeting and deletes their <code>JoinToken</code>. Attendees are automatically deleted when a Amazon Chime SDK meeting is deleted. For more information about the Amazon Chime SDK, see <a href="https://docs.aws.amazon.com/chime/latest/dg/meetings-sdk.html">Using the Amazon Chime SDK</a> in the <i>Amazon Chime Developer Guide</i>.</p>
-    async fn delete_attendee(
+    /// Presigns the request `delete_attendee` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_attendee(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteAttendeeRequest,
-    ) -> Result<(), RusotoError<DeleteAttendeeError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_attendee_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_attendee_request(&self, input: DeleteAttendeeRequest) -> SignedRequest {
         let request_uri = format!(
             "/meetings/{meeting_id}/attendees/{attendee_id}",
             attendee_id = input.attendee_id,
@@ -11373,29 +13307,56 @@ impl Chime for ChimeClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "chime", &self.region, &request_uri);
+        request.set_operation("DeleteAttendee");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
+        request
+    }
+
+    /// Calls `delete_events_configuration`, also returning the metadata of the response.
+    pub async fn delete_events_configuration_with_metadata(
+        &self,
+        input: DeleteEventsConfigurationRequest,
+    ) -> Result<((), ResponseMetadata), RusotoError<DeleteEventsConfigurationError>> {
+        let request = self.build_delete_events_configuration_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 204 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = ::std::mem::drop(response);
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteAttendeeError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(DeleteEventsConfigurationError::from_response(response))
         }
     }
 
-    /// <p>Deletes the events configuration that allows a bot to receive outgoing events.</p>
-    async fn delete_events_configuration(
+    /// Presigns the request `delete_events_configuration` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_events_configuration(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteEventsConfigurationRequest,
-    ) -> Result<(), RusotoError<DeleteEventsConfigurationError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_events_configuration_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_events_configuration_request(
+        &self,
+        input: DeleteEventsConfigurationRequest,
+    ) -> SignedRequest {
         let request_uri = format!(
             "/accounts/{account_id}/bots/{bot_id}/events-configuration",
             account_id = input.account_id,
@@ -11403,84 +13364,156 @@ impl Chime for ChimeClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "chime", &self.region, &request_uri);
+        request.set_operation("DeleteEventsConfiguration");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
+        request
+    }
+
+    /// Calls `delete_meeting`, also returning the metadata of the response.
+    pub async fn delete_meeting_with_metadata(
+        &self,
+        input: DeleteMeetingRequest,
+    ) -> Result<((), ResponseMetadata), RusotoError<DeleteMeetingError>> {
+        let request = self.build_delete_meeting_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 204 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = ::std::mem::drop(response);
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteEventsConfigurationError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(DeleteMeetingError::from_response(response))
         }
     }
 
-    /// <p>Deletes the specified Amazon Chime SDK meeting. When a meeting is deleted, its attendees are also deleted and clients can no longer join it. For more information about the Amazon Chime SDK, see <a href="https://docs.aws.amazon.com/chime/latest/dg/meetings-sdk.html">Using the Amazon Chime SDK</a> in the <i>Amazon Chime Developer Guide</i>.</p>
-    async fn delete_meeting(
+    /// Presigns the request `delete_meeting` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_meeting(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteMeetingRequest,
-    ) -> Result<(), RusotoError<DeleteMeetingError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_meeting_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_meeting_request(&self, input: DeleteMeetingRequest) -> SignedRequest {
         let request_uri = format!("/meetings/{meeting_id}", meeting_id = input.meeting_id);
 
         let mut request = SignedRequest::new("DELETE", "chime", &self.region, &request_uri);
+        request.set_operation("DeleteMeeting");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
+        request
+    }
+
+    /// Calls `delete_phone_number`, also returning the metadata of the response.
+    pub async fn delete_phone_number_with_metadata(
+        &self,
+        input: DeletePhoneNumberRequest,
+    ) -> Result<((), ResponseMetadata), RusotoError<DeletePhoneNumberError>> {
+        let request = self.build_delete_phone_number_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 204 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = ::std::mem::drop(response);
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteMeetingError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(DeletePhoneNumberError::from_response(response))
         }
     }
 
-    /// <p>Moves the specified phone number into the <b>Deletion queue</b>. A phone number must be disassociated from any users or Amazon Chime Voice Connectors before it can be deleted.</p> <p>Deleted phone numbers remain in the <b>Deletion queue</b> for 7 days before they are deleted permanently.</p>
-    async fn delete_phone_number(
+    /// Presigns the request `delete_phone_number` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_phone_number(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeletePhoneNumberRequest,
-    ) -> Result<(), RusotoError<DeletePhoneNumberError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_phone_number_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_phone_number_request(&self, input: DeletePhoneNumberRequest) -> SignedRequest {
         let request_uri = format!(
             "/phone-numbers/{phone_number_id}",
             phone_number_id = input.phone_number_id
         );
 
         let mut request = SignedRequest::new("DELETE", "chime", &self.region, &request_uri);
+        request.set_operation("DeletePhoneNumber");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
+        request
+    }
+
+    /// Calls `delete_room`, also returning the metadata of the response.
+    pub async fn delete_room_with_metadata(
+        &self,
+        input: DeleteRoomRequest,
+    ) -> Result<((), ResponseMetadata), RusotoError<DeleteRoomError>> {
+        let request = self.build_delete_room_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 204 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = ::std::mem::drop(response);
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(DeletePhoneNumberError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(DeleteRoomError::from_response(response))
         }
     }
 
-    /// <p>Deletes a chat room.</p>
-    async fn delete_room(
+    /// Presigns the request `delete_room` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_room(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteRoomRequest,
-    ) -> Result<(), RusotoError<DeleteRoomError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_room_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_room_request(&self, input: DeleteRoomRequest) -> SignedRequest {
         let request_uri = format!(
             "/accounts/{account_id}/rooms/{room_id}",
             account_id = input.account_id,
@@ -11488,29 +13521,56 @@ impl Chime for ChimeClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "chime", &self.region, &request_uri);
+        request.set_operation("DeleteRoom");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
+        request
+    }
+
+    /// Calls `delete_room_membership`, also returning the metadata of the response.
+    pub async fn delete_room_membership_with_metadata(
+        &self,
+        input: DeleteRoomMembershipRequest,
+    ) -> Result<((), ResponseMetadata), RusotoError<DeleteRoomMembershipError>> {
+        let request = self.build_delete_room_membership_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 204 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = ::std::mem::drop(response);
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteRoomError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(DeleteRoomMembershipError::from_response(response))
         }
     }
 
-    /// <p>Removes a member from a chat room.</p>
-    async fn delete_room_membership(
+    /// Presigns the request `delete_room_membership` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_room_membership(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteRoomMembershipRequest,
-    ) -> Result<(), RusotoError<DeleteRoomMembershipError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_room_membership_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_room_membership_request(
+        &self,
+        input: DeleteRoomMembershipRequest,
+    ) -> SignedRequest {
         let request_uri = format!(
             "/accounts/{account_id}/rooms/{room_id}/memberships/{member_id}",
             account_id = input.account_id,
@@ -11519,184 +13579,349 @@ impl Chime for ChimeClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "chime", &self.region, &request_uri);
+        request.set_operation("DeleteRoomMembership");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
+        request
+    }
+
+    /// Calls `delete_voice_connector`, also returning the metadata of the response.
+    pub async fn delete_voice_connector_with_metadata(
+        &self,
+        input: DeleteVoiceConnectorRequest,
+    ) -> Result<((), ResponseMetadata), RusotoError<DeleteVoiceConnectorError>> {
+        let request = self.build_delete_voice_connector_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 204 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = ::std::mem::drop(response);
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteRoomMembershipError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(DeleteVoiceConnectorError::from_response(response))
         }
     }
 
-    /// <p>Deletes the specified Amazon Chime Voice Connector. Any phone numbers associated with the Amazon Chime Voice Connector must be disassociated from it before it can be deleted.</p>
-    async fn delete_voice_connector(
+    /// Presigns the request `delete_voice_connector` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_voice_connector(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteVoiceConnectorRequest,
-    ) -> Result<(), RusotoError<DeleteVoiceConnectorError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_voice_connector_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_voice_connector_request(
+        &self,
+        input: DeleteVoiceConnectorRequest,
+    ) -> SignedRequest {
         let request_uri = format!(
             "/voice-connectors/{voice_connector_id}",
             voice_connector_id = input.voice_connector_id
         );
 
         let mut request = SignedRequest::new("DELETE", "chime", &self.region, &request_uri);
+        request.set_operation("DeleteVoiceConnector");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
+        request
+    }
+
+    /// Calls `delete_voice_connector_group`, also returning the metadata of the response.
+    pub async fn delete_voice_connector_group_with_metadata(
+        &self,
+        input: DeleteVoiceConnectorGroupRequest,
+    ) -> Result<((), ResponseMetadata), RusotoError<DeleteVoiceConnectorGroupError>> {
+        let request = self.build_delete_voice_connector_group_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 204 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = ::std::mem::drop(response);
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteVoiceConnectorError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(DeleteVoiceConnectorGroupError::from_response(response))
         }
     }
 
-    /// <p>Deletes the specified Amazon Chime Voice Connector group. Any <code>VoiceConnectorItems</code> and phone numbers associated with the group must be removed before it can be deleted.</p>
-    async fn delete_voice_connector_group(
+    /// Presigns the request `delete_voice_connector_group` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_voice_connector_group(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteVoiceConnectorGroupRequest,
-    ) -> Result<(), RusotoError<DeleteVoiceConnectorGroupError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_voice_connector_group_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_voice_connector_group_request(
+        &self,
+        input: DeleteVoiceConnectorGroupRequest,
+    ) -> SignedRequest {
         let request_uri = format!(
             "/voice-connector-groups/{voice_connector_group_id}",
             voice_connector_group_id = input.voice_connector_group_id
         );
 
         let mut request = SignedRequest::new("DELETE", "chime", &self.region, &request_uri);
+        request.set_operation("DeleteVoiceConnectorGroup");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
+        request
+    }
+
+    /// Calls `delete_voice_connector_origination`, also returning the metadata of the response.
+    pub async fn delete_voice_connector_origination_with_metadata(
+        &self,
+        input: DeleteVoiceConnectorOriginationRequest,
+    ) -> Result<((), ResponseMetadata), RusotoError<DeleteVoiceConnectorOriginationError>> {
+        let request = self.build_delete_voice_connector_origination_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 204 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = ::std::mem::drop(response);
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteVoiceConnectorGroupError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(DeleteVoiceConnectorOriginationError::from_response(
+                response,
+            ))
         }
     }
 
-    /// <p>Deletes the origination settings for the specified Amazon Chime Voice Connector.</p>
-    async fn delete_voice_connector_origination(
+    /// Presigns the request `delete_voice_connector_origination` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_voice_connector_origination(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteVoiceConnectorOriginationRequest,
-    ) -> Result<(), RusotoError<DeleteVoiceConnectorOriginationError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_voice_connector_origination_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_voice_connector_origination_request(
+        &self,
+        input: DeleteVoiceConnectorOriginationRequest,
+    ) -> SignedRequest {
         let request_uri = format!(
             "/voice-connectors/{voice_connector_id}/origination",
             voice_connector_id = input.voice_connector_id
         );
 
         let mut request = SignedRequest::new("DELETE", "chime", &self.region, &request_uri);
+        request.set_operation("DeleteVoiceConnectorOrigination");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
+        request
+    }
+
+    /// Calls `delete_voice_connector_streaming_configuration`, also returning the metadata of the response.
+    pub async fn delete_voice_connector_streaming_configuration_with_metadata(
+        &self,
+        input: DeleteVoiceConnectorStreamingConfigurationRequest,
+    ) -> Result<((), ResponseMetadata), RusotoError<DeleteVoiceConnectorStreamingConfigurationError>>
+    {
+        let request = self.build_delete_voice_connector_streaming_configuration_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 204 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = ::std::mem::drop(response);
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteVoiceConnectorOriginationError::from_response(
-                response,
-            ))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(DeleteVoiceConnectorStreamingConfigurationError::from_response(response))
         }
     }
 
-    /// <p>Deletes the streaming configuration for the specified Amazon Chime Voice Connector.</p>
-    async fn delete_voice_connector_streaming_configuration(
+    /// Presigns the request `delete_voice_connector_streaming_configuration` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_voice_connector_streaming_configuration(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteVoiceConnectorStreamingConfigurationRequest,
-    ) -> Result<(), RusotoError<DeleteVoiceConnectorStreamingConfigurationError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_voice_connector_streaming_configuration_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_voice_connector_streaming_configuration_request(
+        &self,
+        input: DeleteVoiceConnectorStreamingConfigurationRequest,
+    ) -> SignedRequest {
         let request_uri = format!(
             "/voice-connectors/{voice_connector_id}/streaming-configuration",
             voice_connector_id = input.voice_connector_id
         );
 
         let mut request = SignedRequest::new("DELETE", "chime", &self.region, &request_uri);
+        request.set_operation("DeleteVoiceConnectorStreamingConfiguration");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
+        request
+    }
+
+    /// Calls `delete_voice_connector_termination`, also returning the metadata of the response.
+    pub async fn delete_voice_connector_termination_with_metadata(
+        &self,
+        input: DeleteVoiceConnectorTerminationRequest,
+    ) -> Result<((), ResponseMetadata), RusotoError<DeleteVoiceConnectorTerminationError>> {
+        let request = self.build_delete_voice_connector_termination_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 204 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = ::std::mem::drop(response);
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteVoiceConnectorStreamingConfigurationError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(DeleteVoiceConnectorTerminationError::from_response(
+                response,
+            ))
         }
     }
 
-    /// <p>Deletes the termination settings for the specified Amazon Chime Voice Connector.</p>
-    async fn delete_voice_connector_termination(
+    /// Presigns the request `delete_voice_connector_termination` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_voice_connector_termination(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteVoiceConnectorTerminationRequest,
-    ) -> Result<(), RusotoError<DeleteVoiceConnectorTerminationError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_voice_connector_termination_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_voice_connector_termination_request(
+        &self,
+        input: DeleteVoiceConnectorTerminationRequest,
+    ) -> SignedRequest {
         let request_uri = format!(
             "/voice-connectors/{voice_connector_id}/termination",
             voice_connector_id = input.voice_connector_id
         );
 
         let mut request = SignedRequest::new("DELETE", "chime", &self.region, &request_uri);
+        request.set_operation("DeleteVoiceConnectorTermination");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
+        request
+    }
+
+    /// Calls `delete_voice_connector_termination_credentials`, also returning the metadata of the response.
+    pub async fn delete_voice_connector_termination_credentials_with_metadata(
+        &self,
+        input: DeleteVoiceConnectorTerminationCredentialsRequest,
+    ) -> Result<((), ResponseMetadata), RusotoError<DeleteVoiceConnectorTerminationCredentialsError>>
+    {
+        let request = self.build_delete_voice_connector_termination_credentials_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 204 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = ::std::mem::drop(response);
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteVoiceConnectorTerminationError::from_response(
-                response,
-            ))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(DeleteVoiceConnectorTerminationCredentialsError::from_response(response))
         }
     }
 
-    /// <p>Deletes the specified SIP credentials used by your equipment to authenticate during call termination.</p>
-    async fn delete_voice_connector_termination_credentials(
+    /// Presigns the request `delete_voice_connector_termination_credentials` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_voice_connector_termination_credentials(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteVoiceConnectorTerminationCredentialsRequest,
-    ) -> Result<(), RusotoError<DeleteVoiceConnectorTerminationCredentialsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_voice_connector_termination_credentials_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_voice_connector_termination_credentials_request(
+        &self,
+        input: DeleteVoiceConnectorTerminationCredentialsRequest,
+    ) -> SignedRequest {
         let request_uri = format!(
             "/voice-connectors/{voice_connector_id}/termination/credentials",
             voice_connector_id = input.voice_connector_id
         );
 
         let mut request = SignedRequest::new("POST", "chime", &self.region, &request_uri);
+        request.set_operation("DeleteVoiceConnectorTerminationCredentials");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -11706,30 +13931,59 @@ impl Chime for ChimeClient {
         params.put("operation", "delete");
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `disassociate_phone_number_from_user`, also returning the metadata of the response.
+    pub async fn disassociate_phone_number_from_user_with_metadata(
+        &self,
+        input: DisassociatePhoneNumberFromUserRequest,
+    ) -> Result<
+        (DisassociatePhoneNumberFromUserResponse, ResponseMetadata),
+        RusotoError<DisassociatePhoneNumberFromUserError>,
+    > {
+        let request = self.build_disassociate_phone_number_from_user_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
-        if response.status.as_u16() == 204 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            let result = ::std::mem::drop(response);
+        let metadata = ResponseMetadata::from_response(&response);
+        if response.status.as_u16() == 200 {
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DisassociatePhoneNumberFromUserResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteVoiceConnectorTerminationCredentialsError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(DisassociatePhoneNumberFromUserError::from_response(
+                response,
+            ))
         }
     }
 
-    /// <p>Disassociates the primary provisioned phone number from the specified Amazon Chime user.</p>
-    async fn disassociate_phone_number_from_user(
+    /// Presigns the request `disassociate_phone_number_from_user` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_disassociate_phone_number_from_user(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DisassociatePhoneNumberFromUserRequest,
-    ) -> Result<
-        DisassociatePhoneNumberFromUserResponse,
-        RusotoError<DisassociatePhoneNumberFromUserError>,
-    > {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_disassociate_phone_number_from_user_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_disassociate_phone_number_from_user_request(
+        &self,
+        input: DisassociatePhoneNumberFromUserRequest,
+    ) -> SignedRequest {
         let request_uri = format!(
             "/accounts/{account_id}/users/{user_id}",
             account_id = input.account_id,
@@ -11737,45 +13991,74 @@ impl Chime for ChimeClient {
         );
 
         let mut request = SignedRequest::new("POST", "chime", &self.region, &request_uri);
+        request.set_operation("DisassociatePhoneNumberFromUser");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
         params.put("operation", "disassociate-phone-number");
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `disassociate_phone_numbers_from_voice_connector`, also returning the metadata of the response.
+    pub async fn disassociate_phone_numbers_from_voice_connector_with_metadata(
+        &self,
+        input: DisassociatePhoneNumbersFromVoiceConnectorRequest,
+    ) -> Result<
+        (
+            DisassociatePhoneNumbersFromVoiceConnectorResponse,
+            ResponseMetadata,
+        ),
+        RusotoError<DisassociatePhoneNumbersFromVoiceConnectorError>,
+    > {
+        let request = self.build_disassociate_phone_numbers_from_voice_connector_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<DisassociatePhoneNumberFromUserResponse, _>()?;
+                .deserialize::<DisassociatePhoneNumbersFromVoiceConnectorResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(DisassociatePhoneNumberFromUserError::from_response(
-                response,
-            ))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(DisassociatePhoneNumbersFromVoiceConnectorError::from_response(response))
         }
     }
 
-    /// <p>Disassociates the specified phone numbers from the specified Amazon Chime Voice Connector.</p>
-    async fn disassociate_phone_numbers_from_voice_connector(
+    /// Presigns the request `disassociate_phone_numbers_from_voice_connector` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_disassociate_phone_numbers_from_voice_connector(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DisassociatePhoneNumbersFromVoiceConnectorRequest,
-    ) -> Result<
-        DisassociatePhoneNumbersFromVoiceConnectorResponse,
-        RusotoError<DisassociatePhoneNumbersFromVoiceConnectorError>,
-    > {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_disassociate_phone_numbers_from_voice_connector_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_disassociate_phone_numbers_from_voice_connector_request(
+        &self,
+        input: DisassociatePhoneNumbersFromVoiceConnectorRequest,
+    ) -> SignedRequest {
         let request_uri = format!(
             "/voice-connectors/{voice_connector_id}",
             voice_connector_id = input.voice_connector_id
         );
 
         let mut request = SignedRequest::new("POST", "chime", &self.region, &request_uri);
+        request.set_operation("DisassociatePhoneNumbersFromVoiceConnector");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -11785,37 +14068,70 @@ impl Chime for ChimeClient {
         params.put("operation", "disassociate-phone-numbers");
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `disassociate_phone_numbers_from_voice_connector_group`, also returning the metadata of the response.
+    pub async fn disassociate_phone_numbers_from_voice_connector_group_with_metadata(
+        &self,
+        input: DisassociatePhoneNumbersFromVoiceConnectorGroupRequest,
+    ) -> Result<
+        (
+            DisassociatePhoneNumbersFromVoiceConnectorGroupResponse,
+            ResponseMetadata,
+        ),
+        RusotoError<DisassociatePhoneNumbersFromVoiceConnectorGroupError>,
+    > {
+        let request =
+            self.build_disassociate_phone_numbers_from_voice_connector_group_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<DisassociatePhoneNumbersFromVoiceConnectorResponse, _>()?;
+                .deserialize::<DisassociatePhoneNumbersFromVoiceConnectorGroupResponse, _>(
+            )?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(DisassociatePhoneNumbersFromVoiceConnectorError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(DisassociatePhoneNumbersFromVoiceConnectorGroupError::from_response(response))
         }
     }
 
-    /// <p>Disassociates the specified phone numbers from the specified Amazon Chime Voice Connector group.</p>
-    async fn disassociate_phone_numbers_from_voice_connector_group(
+    /// Presigns the request `disassociate_phone_numbers_from_voice_connector_group` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_disassociate_phone_numbers_from_voice_connector_group(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DisassociatePhoneNumbersFromVoiceConnectorGroupRequest,
-    ) -> Result<
-        DisassociatePhoneNumbersFromVoiceConnectorGroupResponse,
-        RusotoError<DisassociatePhoneNumbersFromVoiceConnectorGroupError>,
-    > {
-        let request_uri = format!(
-            "/voice-connector-groups/{voice_connector_group_id}",
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request =
+            self.build_disassociate_phone_numbers_from_voice_connector_group_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_disassociate_phone_numbers_from_voice_connector_group_request(
+        &self,
+        input: DisassociatePhoneNumbersFromVoiceConnectorGroupRequest,
+    ) -> SignedRequest {
+        let request_uri = format!(
+            "/voice-connector-groups/{voice_connector_group_id}",
             voice_connector_group_id = input.voice_connector_group_id
         );
 
         let mut request = SignedRequest::new("POST", "chime", &self.region, &request_uri);
+        request.set_operation("DisassociatePhoneNumbersFromVoiceConnectorGroup");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -11825,35 +14141,64 @@ impl Chime for ChimeClient {
         params.put("operation", "disassociate-phone-numbers");
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `disassociate_signin_delegate_groups_from_account`, also returning the metadata of the response.
+    pub async fn disassociate_signin_delegate_groups_from_account_with_metadata(
+        &self,
+        input: DisassociateSigninDelegateGroupsFromAccountRequest,
+    ) -> Result<
+        (
+            DisassociateSigninDelegateGroupsFromAccountResponse,
+            ResponseMetadata,
+        ),
+        RusotoError<DisassociateSigninDelegateGroupsFromAccountError>,
+    > {
+        let request = self.build_disassociate_signin_delegate_groups_from_account_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<DisassociatePhoneNumbersFromVoiceConnectorGroupResponse, _>(
-            )?;
+                .deserialize::<DisassociateSigninDelegateGroupsFromAccountResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(DisassociatePhoneNumbersFromVoiceConnectorGroupError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(DisassociateSigninDelegateGroupsFromAccountError::from_response(response))
         }
     }
 
-    /// <p>Disassociates the specified sign-in delegate groups from the specified Amazon Chime account.</p>
-    async fn disassociate_signin_delegate_groups_from_account(
+    /// Presigns the request `disassociate_signin_delegate_groups_from_account` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_disassociate_signin_delegate_groups_from_account(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DisassociateSigninDelegateGroupsFromAccountRequest,
-    ) -> Result<
-        DisassociateSigninDelegateGroupsFromAccountResponse,
-        RusotoError<DisassociateSigninDelegateGroupsFromAccountError>,
-    > {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_disassociate_signin_delegate_groups_from_account_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_disassociate_signin_delegate_groups_from_account_request(
+        &self,
+        input: DisassociateSigninDelegateGroupsFromAccountRequest,
+    ) -> SignedRequest {
         let request_uri = format!("/accounts/{account_id}", account_id = input.account_id);
 
         let mut request = SignedRequest::new("POST", "chime", &self.region, &request_uri);
+        request.set_operation("DisassociateSigninDelegateGroupsFromAccount");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -11863,85 +14208,160 @@ impl Chime for ChimeClient {
         params.put("operation", "disassociate-signin-delegate-groups");
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `get_account`, also returning the metadata of the response.
+    pub async fn get_account_with_metadata(
+        &self,
+        input: GetAccountRequest,
+    ) -> Result<(GetAccountResponse, ResponseMetadata), RusotoError<GetAccountError>> {
+        let request = self.build_get_account_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
-        if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let metadata = ResponseMetadata::from_response(&response);
+        if response.status.is_success() {
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<DisassociateSigninDelegateGroupsFromAccountResponse, _>()?;
+                .deserialize::<GetAccountResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(DisassociateSigninDelegateGroupsFromAccountError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(GetAccountError::from_response(response))
         }
     }
 
-    /// <p>Retrieves details for the specified Amazon Chime account, such as account type and supported licenses.</p>
-    async fn get_account(
+    /// Presigns the request `get_account` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_account(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetAccountRequest,
-    ) -> Result<GetAccountResponse, RusotoError<GetAccountError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_account_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_account_request(&self, input: GetAccountRequest) -> SignedRequest {
         let request_uri = format!("/accounts/{account_id}", account_id = input.account_id);
 
         let mut request = SignedRequest::new("GET", "chime", &self.region, &request_uri);
+        request.set_operation("GetAccount");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
+        request
+    }
+
+    /// Calls `get_account_settings`, also returning the metadata of the response.
+    pub async fn get_account_settings_with_metadata(
+        &self,
+        input: GetAccountSettingsRequest,
+    ) -> Result<(GetAccountSettingsResponse, ResponseMetadata), RusotoError<GetAccountSettingsError>>
+    {
+        let request = self.build_get_account_settings_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<GetAccountResponse, _>()?;
+                .deserialize::<GetAccountSettingsResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(GetAccountError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(GetAccountSettingsError::from_response(response))
         }
     }
 
-    /// <p>Retrieves account settings for the specified Amazon Chime account ID, such as remote control and dial out settings. For more information about these settings, see <a href="https://docs.aws.amazon.com/chime/latest/ag/policies.html">Use the Policies Page</a> in the <i>Amazon Chime Administration Guide</i>.</p>
-    async fn get_account_settings(
+    /// Presigns the request `get_account_settings` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_account_settings(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetAccountSettingsRequest,
-    ) -> Result<GetAccountSettingsResponse, RusotoError<GetAccountSettingsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_account_settings_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_account_settings_request(
+        &self,
+        input: GetAccountSettingsRequest,
+    ) -> SignedRequest {
         let request_uri = format!(
             "/accounts/{account_id}/settings",
             account_id = input.account_id
         );
 
         let mut request = SignedRequest::new("GET", "chime", &self.region, &request_uri);
+        request.set_operation("GetAccountSettings");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
+        request
+    }
+
+    /// Calls `get_attendee`, also returning the metadata of the response.
+    pub async fn get_attendee_with_metadata(
+        &self,
+        input: GetAttendeeRequest,
+    ) -> Result<(GetAttendeeResponse, ResponseMetadata), RusotoError<GetAttendeeError>> {
+        let request = self.build_get_attendee_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
-        if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let metadata = ResponseMetadata::from_response(&response);
+        if response.status.as_u16() == 200 {
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<GetAccountSettingsResponse, _>()?;
+                .deserialize::<GetAttendeeResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(GetAccountSettingsError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(GetAttendeeError::from_response(response))
         }
     }
 
-    /// <p>Gets the Amazon Chime SDK attendee details for a specified meeting ID and attendee ID. For more information about the Amazon Chime SDK, see <a href="https://docs.aws.amazon.com/chime/latest/dg/meetings-sdk.html">Using the Amazon Chime SDK</a> in the <i>Amazon Chime Developer Guide</i>.</p>
-    async fn get_attendee(
+    /// Presigns the request `get_attendee` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_attendee(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetAttendeeRequest,
-    ) -> Result<GetAttendeeResponse, RusotoError<GetAttendeeError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_attendee_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_attendee_request(&self, input: GetAttendeeRequest) -> SignedRequest {
         let request_uri = format!(
             "/meetings/{meeting_id}/attendees/{attendee_id}",
             attendee_id = input.attendee_id,
@@ -11949,30 +14369,54 @@ impl Chime for ChimeClient {
         );
 
         let mut request = SignedRequest::new("GET", "chime", &self.region, &request_uri);
+        request.set_operation("GetAttendee");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
+        request
+    }
+
+    /// Calls `get_bot`, also returning the metadata of the response.
+    pub async fn get_bot_with_metadata(
+        &self,
+        input: GetBotRequest,
+    ) -> Result<(GetBotResponse, ResponseMetadata), RusotoError<GetBotError>> {
+        let request = self.build_get_bot_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<GetAttendeeResponse, _>()?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result =
+                proto::json::ResponsePayload::new(&response).deserialize::<GetBotResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(GetAttendeeError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(GetBotError::from_response(response))
         }
     }
 
-    /// <p>Retrieves details for the specified bot, such as bot email address, bot type, status, and display name.</p>
-    async fn get_bot(
+    /// Presigns the request `get_bot` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_bot(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetBotRequest,
-    ) -> Result<GetBotResponse, RusotoError<GetBotError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_bot_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_bot_request(&self, input: GetBotRequest) -> SignedRequest {
         let request_uri = format!(
             "/accounts/{account_id}/bots/{bot_id}",
             account_id = input.account_id,
@@ -11980,30 +14424,60 @@ impl Chime for ChimeClient {
         );
 
         let mut request = SignedRequest::new("GET", "chime", &self.region, &request_uri);
+        request.set_operation("GetBot");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
+        request
+    }
+
+    /// Calls `get_events_configuration`, also returning the metadata of the response.
+    pub async fn get_events_configuration_with_metadata(
+        &self,
+        input: GetEventsConfigurationRequest,
+    ) -> Result<
+        (GetEventsConfigurationResponse, ResponseMetadata),
+        RusotoError<GetEventsConfigurationError>,
+    > {
+        let request = self.build_get_events_configuration_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            let result =
-                proto::json::ResponsePayload::new(&response).deserialize::<GetBotResponse, _>()?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<GetEventsConfigurationResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(GetBotError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(GetEventsConfigurationError::from_response(response))
         }
     }
 
-    /// <p>Gets details for an events configuration that allows a bot to receive outgoing events, such as an HTTPS endpoint or Lambda function ARN. </p>
-    async fn get_events_configuration(
+    /// Presigns the request `get_events_configuration` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_events_configuration(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetEventsConfigurationRequest,
-    ) -> Result<GetEventsConfigurationResponse, RusotoError<GetEventsConfigurationError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_events_configuration_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_events_configuration_request(
+        &self,
+        input: GetEventsConfigurationRequest,
+    ) -> SignedRequest {
         let request_uri = format!(
             "/accounts/{account_id}/bots/{bot_id}/events-configuration",
             account_id = input.account_id,
@@ -12011,169 +14485,321 @@ impl Chime for ChimeClient {
         );
 
         let mut request = SignedRequest::new("GET", "chime", &self.region, &request_uri);
+        request.set_operation("GetEventsConfiguration");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
+        request
+    }
+
+    /// Calls `get_global_settings`, also returning the metadata of the response.
+    pub async fn get_global_settings_with_metadata(
+        &self,
+    ) -> Result<(GetGlobalSettingsResponse, ResponseMetadata), RusotoError<GetGlobalSettingsError>>
+    {
+        let request = self.build_get_global_settings_request();
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<GetEventsConfigurationResponse, _>()?;
+                .deserialize::<GetGlobalSettingsResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(GetEventsConfigurationError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(GetGlobalSettingsError::from_response(response))
         }
     }
 
-    /// <p>Retrieves global settings for the administrator's AWS account, such as Amazon Chime Business Calling and Amazon Chime Voice Connector settings.</p>
-    async fn get_global_settings(
+    /// Presigns the request `get_global_settings` would send with `presigner` instead of sending it.
+    pub async fn presign_get_global_settings(
         &self,
-    ) -> Result<GetGlobalSettingsResponse, RusotoError<GetGlobalSettingsError>> {
+        presigner: &rusoto_core::Presigner,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_global_settings_request();
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_global_settings_request(&self) -> SignedRequest {
         let request_uri = "/settings";
 
         let mut request = SignedRequest::new("GET", "chime", &self.region, &request_uri);
+        request.set_operation("GetGlobalSettings");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
+        request
+    }
+
+    /// Calls `get_meeting`, also returning the metadata of the response.
+    pub async fn get_meeting_with_metadata(
+        &self,
+        input: GetMeetingRequest,
+    ) -> Result<(GetMeetingResponse, ResponseMetadata), RusotoError<GetMeetingError>> {
+        let request = self.build_get_meeting_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<GetGlobalSettingsResponse, _>()?;
+                .deserialize::<GetMeetingResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(GetGlobalSettingsError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(GetMeetingError::from_response(response))
         }
     }
 
-    /// <p>Gets the Amazon Chime SDK meeting details for the specified meeting ID. For more information about the Amazon Chime SDK, see <a href="https://docs.aws.amazon.com/chime/latest/dg/meetings-sdk.html">Using the Amazon Chime SDK</a> in the <i>Amazon Chime Developer Guide</i>.</p>
-    async fn get_meeting(
+    /// Presigns the request `get_meeting` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_meeting(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetMeetingRequest,
-    ) -> Result<GetMeetingResponse, RusotoError<GetMeetingError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_meeting_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_meeting_request(&self, input: GetMeetingRequest) -> SignedRequest {
         let request_uri = format!("/meetings/{meeting_id}", meeting_id = input.meeting_id);
 
         let mut request = SignedRequest::new("GET", "chime", &self.region, &request_uri);
+        request.set_operation("GetMeeting");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
+        request
+    }
+
+    /// Calls `get_phone_number`, also returning the metadata of the response.
+    pub async fn get_phone_number_with_metadata(
+        &self,
+        input: GetPhoneNumberRequest,
+    ) -> Result<(GetPhoneNumberResponse, ResponseMetadata), RusotoError<GetPhoneNumberError>> {
+        let request = self.build_get_phone_number_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
-        if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let metadata = ResponseMetadata::from_response(&response);
+        if response.status.is_success() {
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<GetMeetingResponse, _>()?;
+                .deserialize::<GetPhoneNumberResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(GetMeetingError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(GetPhoneNumberError::from_response(response))
         }
     }
 
-    /// <p>Retrieves details for the specified phone number ID, such as associations, capabilities, and product type.</p>
-    async fn get_phone_number(
+    /// Presigns the request `get_phone_number` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_phone_number(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetPhoneNumberRequest,
-    ) -> Result<GetPhoneNumberResponse, RusotoError<GetPhoneNumberError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_phone_number_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_phone_number_request(&self, input: GetPhoneNumberRequest) -> SignedRequest {
         let request_uri = format!(
             "/phone-numbers/{phone_number_id}",
             phone_number_id = input.phone_number_id
         );
 
         let mut request = SignedRequest::new("GET", "chime", &self.region, &request_uri);
+        request.set_operation("GetPhoneNumber");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
+        request
+    }
+
+    /// Calls `get_phone_number_order`, also returning the metadata of the response.
+    pub async fn get_phone_number_order_with_metadata(
+        &self,
+        input: GetPhoneNumberOrderRequest,
+    ) -> Result<
+        (GetPhoneNumberOrderResponse, ResponseMetadata),
+        RusotoError<GetPhoneNumberOrderError>,
+    > {
+        let request = self.build_get_phone_number_order_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
-        if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let metadata = ResponseMetadata::from_response(&response);
+        if response.status.as_u16() == 200 {
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<GetPhoneNumberResponse, _>()?;
+                .deserialize::<GetPhoneNumberOrderResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(GetPhoneNumberError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(GetPhoneNumberOrderError::from_response(response))
         }
     }
 
-    /// <p>Retrieves details for the specified phone number order, such as order creation timestamp, phone numbers in E.164 format, product type, and order status.</p>
-    async fn get_phone_number_order(
+    /// Presigns the request `get_phone_number_order` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_phone_number_order(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetPhoneNumberOrderRequest,
-    ) -> Result<GetPhoneNumberOrderResponse, RusotoError<GetPhoneNumberOrderError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_phone_number_order_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_phone_number_order_request(
+        &self,
+        input: GetPhoneNumberOrderRequest,
+    ) -> SignedRequest {
         let request_uri = format!(
             "/phone-number-orders/{phone_number_order_id}",
             phone_number_order_id = input.phone_number_order_id
         );
 
         let mut request = SignedRequest::new("GET", "chime", &self.region, &request_uri);
+        request.set_operation("GetPhoneNumberOrder");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
+        request
+    }
+
+    /// Calls `get_phone_number_settings`, also returning the metadata of the response.
+    pub async fn get_phone_number_settings_with_metadata(
+        &self,
+    ) -> Result<
+        (GetPhoneNumberSettingsResponse, ResponseMetadata),
+        RusotoError<GetPhoneNumberSettingsError>,
+    > {
+        let request = self.build_get_phone_number_settings_request();
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<GetPhoneNumberOrderResponse, _>()?;
+                .deserialize::<GetPhoneNumberSettingsResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(GetPhoneNumberOrderError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(GetPhoneNumberSettingsError::from_response(response))
         }
     }
 
-    /// <p>Retrieves the phone number settings for the administrator's AWS account, such as the default outbound calling name.</p>
-    async fn get_phone_number_settings(
+    /// Presigns the request `get_phone_number_settings` would send with `presigner` instead of sending it.
+    pub async fn presign_get_phone_number_settings(
         &self,
-    ) -> Result<GetPhoneNumberSettingsResponse, RusotoError<GetPhoneNumberSettingsError>> {
+        presigner: &rusoto_core::Presigner,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_phone_number_settings_request();
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_phone_number_settings_request(&self) -> SignedRequest {
         let request_uri = "/settings/phone-number";
 
         let mut request = SignedRequest::new("GET", "chime", &self.region, &request_uri);
+        request.set_operation("GetPhoneNumberSettings");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
+        request
+    }
+
+    /// Calls `get_room`, also returning the metadata of the response.
+    pub async fn get_room_with_metadata(
+        &self,
+        input: GetRoomRequest,
+    ) -> Result<(GetRoomResponse, ResponseMetadata), RusotoError<GetRoomError>> {
+        let request = self.build_get_room_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<GetPhoneNumberSettingsResponse, _>()?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result =
+                proto::json::ResponsePayload::new(&response).deserialize::<GetRoomResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(GetPhoneNumberSettingsError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(GetRoomError::from_response(response))
         }
     }
 
-    /// <p>Retrieves room details, such as the room name.</p>
-    async fn get_room(
+    /// Presigns the request `get_room` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_room(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetRoomRequest,
-    ) -> Result<GetRoomResponse, RusotoError<GetRoomError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_room_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_room_request(&self, input: GetRoomRequest) -> SignedRequest {
         let request_uri = format!(
             "/accounts/{account_id}/rooms/{room_id}",
             account_id = input.account_id,
@@ -12181,30 +14807,54 @@ impl Chime for ChimeClient {
         );
 
         let mut request = SignedRequest::new("GET", "chime", &self.region, &request_uri);
+        request.set_operation("GetRoom");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
+        request
+    }
+
+    /// Calls `get_user`, also returning the metadata of the response.
+    pub async fn get_user_with_metadata(
+        &self,
+        input: GetUserRequest,
+    ) -> Result<(GetUserResponse, ResponseMetadata), RusotoError<GetUserError>> {
+        let request = self.build_get_user_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result =
-                proto::json::ResponsePayload::new(&response).deserialize::<GetRoomResponse, _>()?;
+                proto::json::ResponsePayload::new(&response).deserialize::<GetUserResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(GetRoomError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(GetUserError::from_response(response))
         }
     }
 
-    /// <p>Retrieves details for the specified user ID, such as primary email address, license type, and personal meeting PIN.</p> <p>To retrieve user details with an email address instead of a user ID, use the <a>ListUsers</a> action, and then filter by email address.</p>
-    async fn get_user(
+    /// Presigns the request `get_user` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_user(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetUserRequest,
-    ) -> Result<GetUserResponse, RusotoError<GetUserError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_user_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_user_request(&self, input: GetUserRequest) -> SignedRequest {
         let request_uri = format!(
             "/accounts/{account_id}/users/{user_id}",
             account_id = input.account_id,
@@ -12212,30 +14862,55 @@ impl Chime for ChimeClient {
         );
 
         let mut request = SignedRequest::new("GET", "chime", &self.region, &request_uri);
+        request.set_operation("GetUser");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
+        request
+    }
+
+    /// Calls `get_user_settings`, also returning the metadata of the response.
+    pub async fn get_user_settings_with_metadata(
+        &self,
+        input: GetUserSettingsRequest,
+    ) -> Result<(GetUserSettingsResponse, ResponseMetadata), RusotoError<GetUserSettingsError>>
+    {
+        let request = self.build_get_user_settings_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            let result =
-                proto::json::ResponsePayload::new(&response).deserialize::<GetUserResponse, _>()?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<GetUserSettingsResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(GetUserError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(GetUserSettingsError::from_response(response))
         }
     }
 
-    /// <p>Retrieves settings for the specified user ID, such as any associated phone number settings.</p>
-    async fn get_user_settings(
+    /// Presigns the request `get_user_settings` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_user_settings(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetUserSettingsRequest,
-    ) -> Result<GetUserSettingsResponse, RusotoError<GetUserSettingsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_user_settings_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_user_settings_request(&self, input: GetUserSettingsRequest) -> SignedRequest {
         let request_uri = format!(
             "/accounts/{account_id}/users/{user_id}/settings",
             account_id = input.account_id,
@@ -12243,263 +14918,488 @@ impl Chime for ChimeClient {
         );
 
         let mut request = SignedRequest::new("GET", "chime", &self.region, &request_uri);
+        request.set_operation("GetUserSettings");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
+        request
+    }
+
+    /// Calls `get_voice_connector`, also returning the metadata of the response.
+    pub async fn get_voice_connector_with_metadata(
+        &self,
+        input: GetVoiceConnectorRequest,
+    ) -> Result<(GetVoiceConnectorResponse, ResponseMetadata), RusotoError<GetVoiceConnectorError>>
+    {
+        let request = self.build_get_voice_connector_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<GetUserSettingsResponse, _>()?;
+                .deserialize::<GetVoiceConnectorResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(GetUserSettingsError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(GetVoiceConnectorError::from_response(response))
         }
     }
 
-    /// <p>Retrieves details for the specified Amazon Chime Voice Connector, such as timestamps, name, outbound host, and encryption requirements.</p>
-    async fn get_voice_connector(
+    /// Presigns the request `get_voice_connector` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_voice_connector(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetVoiceConnectorRequest,
-    ) -> Result<GetVoiceConnectorResponse, RusotoError<GetVoiceConnectorError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_voice_connector_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_voice_connector_request(&self, input: GetVoiceConnectorRequest) -> SignedRequest {
         let request_uri = format!(
             "/voice-connectors/{voice_connector_id}",
             voice_connector_id = input.voice_connector_id
         );
 
         let mut request = SignedRequest::new("GET", "chime", &self.region, &request_uri);
+        request.set_operation("GetVoiceConnector");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
-        let mut response = self
+        request
+    }
+
+    /// Calls `get_voice_connector_group`, also returning the metadata of the response.
+    pub async fn get_voice_connector_group_with_metadata(
+        &self,
+        input: GetVoiceConnectorGroupRequest,
+    ) -> Result<
+        (GetVoiceConnectorGroupResponse, ResponseMetadata),
+        RusotoError<GetVoiceConnectorGroupError>,
+    > {
+        let request = self.build_get_voice_connector_group_request(input);
+
+        let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<GetVoiceConnectorResponse, _>()?;
+                .deserialize::<GetVoiceConnectorGroupResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(GetVoiceConnectorError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(GetVoiceConnectorGroupError::from_response(response))
         }
     }
 
-    /// <p>Retrieves details for the specified Amazon Chime Voice Connector group, such as timestamps, name, and associated <code>VoiceConnectorItems</code>.</p>
-    async fn get_voice_connector_group(
+    /// Presigns the request `get_voice_connector_group` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_voice_connector_group(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetVoiceConnectorGroupRequest,
-    ) -> Result<GetVoiceConnectorGroupResponse, RusotoError<GetVoiceConnectorGroupError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_voice_connector_group_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_voice_connector_group_request(
+        &self,
+        input: GetVoiceConnectorGroupRequest,
+    ) -> SignedRequest {
         let request_uri = format!(
             "/voice-connector-groups/{voice_connector_group_id}",
             voice_connector_group_id = input.voice_connector_group_id
         );
 
         let mut request = SignedRequest::new("GET", "chime", &self.region, &request_uri);
+        request.set_operation("GetVoiceConnectorGroup");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
+        request
+    }
+
+    /// Calls `get_voice_connector_logging_configuration`, also returning the metadata of the response.
+    pub async fn get_voice_connector_logging_configuration_with_metadata(
+        &self,
+        input: GetVoiceConnectorLoggingConfigurationRequest,
+    ) -> Result<
+        (
+            GetVoiceConnectorLoggingConfigurationResponse,
+            ResponseMetadata,
+        ),
+        RusotoError<GetVoiceConnectorLoggingConfigurationError>,
+    > {
+        let request = self.build_get_voice_connector_logging_configuration_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<GetVoiceConnectorGroupResponse, _>()?;
+                .deserialize::<GetVoiceConnectorLoggingConfigurationResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(GetVoiceConnectorGroupError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(GetVoiceConnectorLoggingConfigurationError::from_response(
+                response,
+            ))
         }
     }
 
-    /// <p>Retrieves the logging configuration details for the specified Amazon Chime Voice Connector. Shows whether SIP message logs are enabled for sending to Amazon CloudWatch Logs.</p>
-    async fn get_voice_connector_logging_configuration(
+    /// Presigns the request `get_voice_connector_logging_configuration` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_voice_connector_logging_configuration(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetVoiceConnectorLoggingConfigurationRequest,
-    ) -> Result<
-        GetVoiceConnectorLoggingConfigurationResponse,
-        RusotoError<GetVoiceConnectorLoggingConfigurationError>,
-    > {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_voice_connector_logging_configuration_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_voice_connector_logging_configuration_request(
+        &self,
+        input: GetVoiceConnectorLoggingConfigurationRequest,
+    ) -> SignedRequest {
         let request_uri = format!(
             "/voice-connectors/{voice_connector_id}/logging-configuration",
             voice_connector_id = input.voice_connector_id
         );
 
         let mut request = SignedRequest::new("GET", "chime", &self.region, &request_uri);
+        request.set_operation("GetVoiceConnectorLoggingConfiguration");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
+        request
+    }
+
+    /// Calls `get_voice_connector_origination`, also returning the metadata of the response.
+    pub async fn get_voice_connector_origination_with_metadata(
+        &self,
+        input: GetVoiceConnectorOriginationRequest,
+    ) -> Result<
+        (GetVoiceConnectorOriginationResponse, ResponseMetadata),
+        RusotoError<GetVoiceConnectorOriginationError>,
+    > {
+        let request = self.build_get_voice_connector_origination_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<GetVoiceConnectorLoggingConfigurationResponse, _>()?;
+                .deserialize::<GetVoiceConnectorOriginationResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(GetVoiceConnectorLoggingConfigurationError::from_response(
-                response,
-            ))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(GetVoiceConnectorOriginationError::from_response(response))
         }
     }
 
-    /// <p>Retrieves origination setting details for the specified Amazon Chime Voice Connector.</p>
-    async fn get_voice_connector_origination(
+    /// Presigns the request `get_voice_connector_origination` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_voice_connector_origination(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetVoiceConnectorOriginationRequest,
-    ) -> Result<GetVoiceConnectorOriginationResponse, RusotoError<GetVoiceConnectorOriginationError>>
-    {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_voice_connector_origination_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_voice_connector_origination_request(
+        &self,
+        input: GetVoiceConnectorOriginationRequest,
+    ) -> SignedRequest {
         let request_uri = format!(
             "/voice-connectors/{voice_connector_id}/origination",
             voice_connector_id = input.voice_connector_id
         );
 
         let mut request = SignedRequest::new("GET", "chime", &self.region, &request_uri);
+        request.set_operation("GetVoiceConnectorOrigination");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
+        request
+    }
+
+    /// Calls `get_voice_connector_streaming_configuration`, also returning the metadata of the response.
+    pub async fn get_voice_connector_streaming_configuration_with_metadata(
+        &self,
+        input: GetVoiceConnectorStreamingConfigurationRequest,
+    ) -> Result<
+        (
+            GetVoiceConnectorStreamingConfigurationResponse,
+            ResponseMetadata,
+        ),
+        RusotoError<GetVoiceConnectorStreamingConfigurationError>,
+    > {
+        let request = self.build_get_voice_connector_streaming_configuration_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<GetVoiceConnectorOriginationResponse, _>()?;
+                .deserialize::<GetVoiceConnectorStreamingConfigurationResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(GetVoiceConnectorOriginationError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(GetVoiceConnectorStreamingConfigurationError::from_response(
+                response,
+            ))
         }
     }
 
-    /// <p>Retrieves the streaming configuration details for the specified Amazon Chime Voice Connector. Shows whether media streaming is enabled for sending to Amazon Kinesis. It also shows the retention period, in hours, for the Amazon Kinesis data.</p>
-    async fn get_voice_connector_streaming_configuration(
+    /// Presigns the request `get_voice_connector_streaming_configuration` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_voice_connector_streaming_configuration(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetVoiceConnectorStreamingConfigurationRequest,
-    ) -> Result<
-        GetVoiceConnectorStreamingConfigurationResponse,
-        RusotoError<GetVoiceConnectorStreamingConfigurationError>,
-    > {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_voice_connector_streaming_configuration_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_voice_connector_streaming_configuration_request(
+        &self,
+        input: GetVoiceConnectorStreamingConfigurationRequest,
+    ) -> SignedRequest {
         let request_uri = format!(
             "/voice-connectors/{voice_connector_id}/streaming-configuration",
             voice_connector_id = input.voice_connector_id
         );
 
         let mut request = SignedRequest::new("GET", "chime", &self.region, &request_uri);
+        request.set_operation("GetVoiceConnectorStreamingConfiguration");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
+        request
+    }
+
+    /// Calls `get_voice_connector_termination`, also returning the metadata of the response.
+    pub async fn get_voice_connector_termination_with_metadata(
+        &self,
+        input: GetVoiceConnectorTerminationRequest,
+    ) -> Result<
+        (GetVoiceConnectorTerminationResponse, ResponseMetadata),
+        RusotoError<GetVoiceConnectorTerminationError>,
+    > {
+        let request = self.build_get_voice_connector_termination_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<GetVoiceConnectorStreamingConfigurationResponse, _>()?;
+                .deserialize::<GetVoiceConnectorTerminationResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(GetVoiceConnectorStreamingConfigurationError::from_response(
-                response,
-            ))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(GetVoiceConnectorTerminationError::from_response(response))
         }
     }
 
-    /// <p>Retrieves termination setting details for the specified Amazon Chime Voice Connector.</p>
-    async fn get_voice_connector_termination(
+    /// Presigns the request `get_voice_connector_termination` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_voice_connector_termination(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetVoiceConnectorTerminationRequest,
-    ) -> Result<GetVoiceConnectorTerminationResponse, RusotoError<GetVoiceConnectorTerminationError>>
-    {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_voice_connector_termination_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_voice_connector_termination_request(
+        &self,
+        input: GetVoiceConnectorTerminationRequest,
+    ) -> SignedRequest {
         let request_uri = format!(
             "/voice-connectors/{voice_connector_id}/termination",
             voice_connector_id = input.voice_connector_id
         );
 
         let mut request = SignedRequest::new("GET", "chime", &self.region, &request_uri);
+        request.set_operation("GetVoiceConnectorTermination");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
+        request
+    }
+
+    /// Calls `get_voice_connector_termination_health`, also returning the metadata of the response.
+    pub async fn get_voice_connector_termination_health_with_metadata(
+        &self,
+        input: GetVoiceConnectorTerminationHealthRequest,
+    ) -> Result<
+        (GetVoiceConnectorTerminationHealthResponse, ResponseMetadata),
+        RusotoError<GetVoiceConnectorTerminationHealthError>,
+    > {
+        let request = self.build_get_voice_connector_termination_health_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<GetVoiceConnectorTerminationResponse, _>()?;
+                .deserialize::<GetVoiceConnectorTerminationHealthResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(GetVoiceConnectorTerminationError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(GetVoiceConnectorTerminationHealthError::from_response(
+                response,
+            ))
         }
     }
 
-    /// <p>Retrieves information about the last time a SIP <code>OPTIONS</code> ping was received from your SIP infrastructure for the specified Amazon Chime Voice Connector.</p>
-    async fn get_voice_connector_termination_health(
+    /// Presigns the request `get_voice_connector_termination_health` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_voice_connector_termination_health(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetVoiceConnectorTerminationHealthRequest,
-    ) -> Result<
-        GetVoiceConnectorTerminationHealthResponse,
-        RusotoError<GetVoiceConnectorTerminationHealthError>,
-    > {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_voice_connector_termination_health_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_voice_connector_termination_health_request(
+        &self,
+        input: GetVoiceConnectorTerminationHealthRequest,
+    ) -> SignedRequest {
         let request_uri = format!(
             "/voice-connectors/{voice_connector_id}/termination/health",
             voice_connector_id = input.voice_connector_id
         );
 
         let mut request = SignedRequest::new("GET", "chime", &self.region, &request_uri);
+        request.set_operation("GetVoiceConnectorTerminationHealth");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
+        request
+    }
+
+    /// Calls `invite_users`, also returning the metadata of the response.
+    pub async fn invite_users_with_metadata(
+        &self,
+        input: InviteUsersRequest,
+    ) -> Result<(InviteUsersResponse, ResponseMetadata), RusotoError<InviteUsersError>> {
+        let request = self.build_invite_users_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
-        if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let metadata = ResponseMetadata::from_response(&response);
+        if response.status.as_u16() == 201 {
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<GetVoiceConnectorTerminationHealthResponse, _>()?;
+                .deserialize::<InviteUsersResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(GetVoiceConnectorTerminationHealthError::from_response(
-                response,
-            ))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(InviteUsersError::from_response(response))
         }
     }
 
-    /// <p>Sends email to a maximum of 50 users, inviting them to the specified Amazon Chime <code>Team</code> account. Only <code>Team</code> account types are currently supported for this action. </p>
-    async fn invite_users(
+    /// Presigns the request `invite_users` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_invite_users(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: InviteUsersRequest,
-    ) -> Result<InviteUsersResponse, RusotoError<InviteUsersError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_invite_users_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_invite_users_request(&self, input: InviteUsersRequest) -> SignedRequest {
         let request_uri = format!(
             "/accounts/{account_id}/users",
             account_id = input.account_id
         );
 
         let mut request = SignedRequest::new("POST", "chime", &self.region, &request_uri);
+        request.set_operation("InviteUsers");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -12509,31 +15409,55 @@ impl Chime for ChimeClient {
         params.put("operation", "add");
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `list_accounts`, also returning the metadata of the response.
+    pub async fn list_accounts_with_metadata(
+        &self,
+        input: ListAccountsRequest,
+    ) -> Result<(ListAccountsResponse, ResponseMetadata), RusotoError<ListAccountsError>> {
+        let request = self.build_list_accounts_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
-        if response.status.as_u16() == 201 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let metadata = ResponseMetadata::from_response(&response);
+        if response.status.is_success() {
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<InviteUsersResponse, _>()?;
+                .deserialize::<ListAccountsResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(InviteUsersError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(ListAccountsError::from_response(response))
         }
     }
 
-    /// <p>Lists the Amazon Chime accounts under the administrator's AWS account. You can filter accounts by account name prefix. To find out which Amazon Chime account a user belongs to, you can filter by the user's email address, which returns one account result.</p>
-    async fn list_accounts(
+    /// Presigns the request `list_accounts` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_accounts(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListAccountsRequest,
-    ) -> Result<ListAccountsResponse, RusotoError<ListAccountsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_accounts_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_accounts_request(&self, input: ListAccountsRequest) -> SignedRequest {
         let request_uri = "/accounts";
 
         let mut request = SignedRequest::new("GET", "chime", &self.region, &request_uri);
+        request.set_operation("ListAccounts");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -12551,34 +15475,58 @@ impl Chime for ChimeClient {
         }
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `list_attendees`, also returning the metadata of the response.
+    pub async fn list_attendees_with_metadata(
+        &self,
+        input: ListAttendeesRequest,
+    ) -> Result<(ListAttendeesResponse, ResponseMetadata), RusotoError<ListAttendeesError>> {
+        let request = self.build_list_attendees_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
-        if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let metadata = ResponseMetadata::from_response(&response);
+        if response.status.as_u16() == 200 {
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListAccountsResponse, _>()?;
+                .deserialize::<ListAttendeesResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(ListAccountsError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(ListAttendeesError::from_response(response))
         }
     }
 
-    /// <p>Lists the attendees for the specified Amazon Chime SDK meeting. For more information about the Amazon Chime SDK, see <a href="https://docs.aws.amazon.com/chime/latest/dg/meetings-sdk.html">Using the Amazon Chime SDK</a> in the <i>Amazon Chime Developer Guide</i>.</p>
-    async fn list_attendees(
+    /// Presigns the request `list_attendees` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_attendees(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListAttendeesRequest,
-    ) -> Result<ListAttendeesResponse, RusotoError<ListAttendeesError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_attendees_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_attendees_request(&self, input: ListAttendeesRequest) -> SignedRequest {
         let request_uri = format!(
             "/meetings/{meeting_id}/attendees",
             meeting_id = input.meeting_id
         );
 
         let mut request = SignedRequest::new("GET", "chime", &self.region, &request_uri);
+        request.set_operation("ListAttendees");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -12590,31 +15538,55 @@ impl Chime for ChimeClient {
         }
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `list_bots`, also returning the metadata of the response.
+    pub async fn list_bots_with_metadata(
+        &self,
+        input: ListBotsRequest,
+    ) -> Result<(ListBotsResponse, ResponseMetadata), RusotoError<ListBotsError>> {
+        let request = self.build_list_bots_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListAttendeesResponse, _>()?;
+                .deserialize::<ListBotsResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(ListAttendeesError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(ListBotsError::from_response(response))
         }
     }
 
-    /// <p>Lists the bots associated with the administrator's Amazon Chime Enterprise account ID.</p>
-    async fn list_bots(
+    /// Presigns the request `list_bots` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_bots(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListBotsRequest,
-    ) -> Result<ListBotsResponse, RusotoError<ListBotsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_bots_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_bots_request(&self, input: ListBotsRequest) -> SignedRequest {
         let request_uri = format!("/accounts/{account_id}/bots", account_id = input.account_id);
 
         let mut request = SignedRequest::new("GET", "chime", &self.region, &request_uri);
+        request.set_operation("ListBots");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -12626,31 +15598,55 @@ impl Chime for ChimeClient {
         }
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `list_meetings`, also returning the metadata of the response.
+    pub async fn list_meetings_with_metadata(
+        &self,
+        input: ListMeetingsRequest,
+    ) -> Result<(ListMeetingsResponse, ResponseMetadata), RusotoError<ListMeetingsError>> {
+        let request = self.build_list_meetings_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListBotsResponse, _>()?;
+                .deserialize::<ListMeetingsResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(ListBotsError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(ListMeetingsError::from_response(response))
         }
     }
 
-    /// <p>Lists up to 100 active Amazon Chime SDK meetings. For more information about the Amazon Chime SDK, see <a href="https://docs.aws.amazon.com/chime/latest/dg/meetings-sdk.html">Using the Amazon Chime SDK</a> in the <i>Amazon Chime Developer Guide</i>.</p>
-    async fn list_meetings(
+    /// Presigns the request `list_meetings` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_meetings(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListMeetingsRequest,
-    ) -> Result<ListMeetingsResponse, RusotoError<ListMeetingsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_meetings_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_meetings_request(&self, input: ListMeetingsRequest) -> SignedRequest {
         let request_uri = "/meetings";
 
         let mut request = SignedRequest::new("GET", "chime", &self.region, &request_uri);
+        request.set_operation("ListMeetings");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -12662,31 +15658,61 @@ impl Chime for ChimeClient {
         }
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `list_phone_number_orders`, also returning the metadata of the response.
+    pub async fn list_phone_number_orders_with_metadata(
+        &self,
+        input: ListPhoneNumberOrdersRequest,
+    ) -> Result<
+        (ListPhoneNumberOrdersResponse, ResponseMetadata),
+        RusotoError<ListPhoneNumberOrdersError>,
+    > {
+        let request = self.build_list_phone_number_orders_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListMeetingsResponse, _>()?;
+                .deserialize::<ListPhoneNumberOrdersResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(ListMeetingsError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(ListPhoneNumberOrdersError::from_response(response))
         }
     }
 
-    /// <p>Lists the phone number orders for the administrator's Amazon Chime account.</p>
-    async fn list_phone_number_orders(
+    /// Presigns the request `list_phone_number_orders` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_phone_number_orders(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListPhoneNumberOrdersRequest,
-    ) -> Result<ListPhoneNumberOrdersResponse, RusotoError<ListPhoneNumberOrdersError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_phone_number_orders_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_phone_number_orders_request(
+        &self,
+        input: ListPhoneNumberOrdersRequest,
+    ) -> SignedRequest {
         let request_uri = "/phone-number-orders";
 
         let mut request = SignedRequest::new("GET", "chime", &self.region, &request_uri);
+        request.set_operation("ListPhoneNumberOrders");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -12698,31 +15724,56 @@ impl Chime for ChimeClient {
         }
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `list_phone_numbers`, also returning the metadata of the response.
+    pub async fn list_phone_numbers_with_metadata(
+        &self,
+        input: ListPhoneNumbersRequest,
+    ) -> Result<(ListPhoneNumbersResponse, ResponseMetadata), RusotoError<ListPhoneNumbersError>>
+    {
+        let request = self.build_list_phone_numbers_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
-        if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let metadata = ResponseMetadata::from_response(&response);
+        if response.status.is_success() {
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListPhoneNumberOrdersResponse, _>()?;
+                .deserialize::<ListPhoneNumbersResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(ListPhoneNumberOrdersError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(ListPhoneNumbersError::from_response(response))
         }
     }
 
-    /// <p>Lists the phone numbers for the specified Amazon Chime account, Amazon Chime user, Amazon Chime Voice Connector, or Amazon Chime Voice Connector group.</p>
-    async fn list_phone_numbers(
+    /// Presigns the request `list_phone_numbers` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_phone_numbers(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListPhoneNumbersRequest,
-    ) -> Result<ListPhoneNumbersResponse, RusotoError<ListPhoneNumbersError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_phone_numbers_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_phone_numbers_request(&self, input: ListPhoneNumbersRequest) -> SignedRequest {
         let request_uri = "/phone-numbers";
 
         let mut request = SignedRequest::new("GET", "chime", &self.region, &request_uri);
+        request.set_operation("ListPhoneNumbers");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -12746,28 +15797,57 @@ impl Chime for ChimeClient {
         }
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `list_room_memberships`, also returning the metadata of the response.
+    pub async fn list_room_memberships_with_metadata(
+        &self,
+        input: ListRoomMembershipsRequest,
+    ) -> Result<
+        (ListRoomMembershipsResponse, ResponseMetadata),
+        RusotoError<ListRoomMembershipsError>,
+    > {
+        let request = self.build_list_room_memberships_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
-        if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let metadata = ResponseMetadata::from_response(&response);
+        if response.status.as_u16() == 200 {
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListPhoneNumbersResponse, _>()?;
+                .deserialize::<ListRoomMembershipsResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(ListPhoneNumbersError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(ListRoomMembershipsError::from_response(response))
         }
     }
 
-    /// <p>Lists the membership details for the specified room, such as the members' IDs, email addresses, and names.</p>
-    async fn list_room_memberships(
+    /// Presigns the request `list_room_memberships` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_room_memberships(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListRoomMembershipsRequest,
-    ) -> Result<ListRoomMembershipsResponse, RusotoError<ListRoomMembershipsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_room_memberships_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_room_memberships_request(
+        &self,
+        input: ListRoomMembershipsRequest,
+    ) -> SignedRequest {
         let request_uri = format!(
             "/accounts/{account_id}/rooms/{room_id}/memberships",
             account_id = input.account_id,
@@ -12775,6 +15855,7 @@ impl Chime for ChimeClient {
         );
 
         let mut request = SignedRequest::new("GET", "chime", &self.region, &request_uri);
+        request.set_operation("ListRoomMemberships");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -12786,34 +15867,58 @@ impl Chime for ChimeClient {
         }
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `list_rooms`, also returning the metadata of the response.
+    pub async fn list_rooms_with_metadata(
+        &self,
+        input: ListRoomsRequest,
+    ) -> Result<(ListRoomsResponse, ResponseMetadata), RusotoError<ListRoomsError>> {
+        let request = self.build_list_rooms_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListRoomMembershipsResponse, _>()?;
+                .deserialize::<ListRoomsResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(ListRoomMembershipsError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(ListRoomsError::from_response(response))
         }
     }
 
-    /// <p>Lists the room details for the specified Amazon Chime account. Optionally, filter the results by a member ID (user ID or bot ID) to see a list of rooms that the member belongs to.</p>
-    async fn list_rooms(
+    /// Presigns the request `list_rooms` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_rooms(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListRoomsRequest,
-    ) -> Result<ListRoomsResponse, RusotoError<ListRoomsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_rooms_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_rooms_request(&self, input: ListRoomsRequest) -> SignedRequest {
         let request_uri = format!(
             "/accounts/{account_id}/rooms",
             account_id = input.account_id
         );
 
         let mut request = SignedRequest::new("GET", "chime", &self.region, &request_uri);
+        request.set_operation("ListRooms");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -12828,34 +15933,58 @@ impl Chime for ChimeClient {
         }
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `list_users`, also returning the metadata of the response.
+    pub async fn list_users_with_metadata(
+        &self,
+        input: ListUsersRequest,
+    ) -> Result<(ListUsersResponse, ResponseMetadata), RusotoError<ListUsersError>> {
+        let request = self.build_list_users_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListRoomsResponse, _>()?;
+                .deserialize::<ListUsersResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(ListRoomsError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(ListUsersError::from_response(response))
         }
     }
 
-    /// <p>Lists the users that belong to the specified Amazon Chime account. You can specify an email address to list only the user that the email address belongs to.</p>
-    async fn list_users(
+    /// Presigns the request `list_users` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_users(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListUsersRequest,
-    ) -> Result<ListUsersResponse, RusotoError<ListUsersError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_users_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_users_request(&self, input: ListUsersRequest) -> SignedRequest {
         let request_uri = format!(
             "/accounts/{account_id}/users",
             account_id = input.account_id
         );
 
         let mut request = SignedRequest::new("GET", "chime", &self.region, &request_uri);
+        request.set_operation("ListUsers");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -12873,31 +16002,61 @@ impl Chime for ChimeClient {
         }
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `list_voice_connector_groups`, also returning the metadata of the response.
+    pub async fn list_voice_connector_groups_with_metadata(
+        &self,
+        input: ListVoiceConnectorGroupsRequest,
+    ) -> Result<
+        (ListVoiceConnectorGroupsResponse, ResponseMetadata),
+        RusotoError<ListVoiceConnectorGroupsError>,
+    > {
+        let request = self.build_list_voice_connector_groups_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListUsersResponse, _>()?;
+                .deserialize::<ListVoiceConnectorGroupsResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(ListUsersError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(ListVoiceConnectorGroupsError::from_response(response))
         }
     }
 
-    /// <p>Lists the Amazon Chime Voice Connector groups for the administrator's AWS account.</p>
-    async fn list_voice_connector_groups(
+    /// Presigns the request `list_voice_connector_groups` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_voice_connector_groups(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListVoiceConnectorGroupsRequest,
-    ) -> Result<ListVoiceConnectorGroupsResponse, RusotoError<ListVoiceConnectorGroupsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_voice_connector_groups_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_voice_connector_groups_request(
+        &self,
+        input: ListVoiceConnectorGroupsRequest,
+    ) -> SignedRequest {
         let request_uri = "/voice-connector-groups";
 
         let mut request = SignedRequest::new("GET", "chime", &self.region, &request_uri);
+        request.set_operation("ListVoiceConnectorGroups");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -12909,64 +16068,124 @@ impl Chime for ChimeClient {
         }
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `list_voice_connector_termination_credentials`, also returning the metadata of the response.
+    pub async fn list_voice_connector_termination_credentials_with_metadata(
+        &self,
+        input: ListVoiceConnectorTerminationCredentialsRequest,
+    ) -> Result<
+        (
+            ListVoiceConnectorTerminationCredentialsResponse,
+            ResponseMetadata,
+        ),
+        RusotoError<ListVoiceConnectorTerminationCredentialsError>,
+    > {
+        let request = self.build_list_voice_connector_termination_credentials_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListVoiceConnectorGroupsResponse, _>()?;
+                .deserialize::<ListVoiceConnectorTerminationCredentialsResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(ListVoiceConnectorGroupsError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(ListVoiceConnectorTerminationCredentialsError::from_response(response))
         }
     }
 
-    /// <p>Lists the SIP credentials for the specified Amazon Chime Voice Connector.</p>
-    async fn list_voice_connector_termination_credentials(
+    /// Presigns the request `list_voice_connector_termination_credentials` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_voice_connector_termination_credentials(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListVoiceConnectorTerminationCredentialsRequest,
-    ) -> Result<
-        ListVoiceConnectorTerminationCredentialsResponse,
-        RusotoError<ListVoiceConnectorTerminationCredentialsError>,
-    > {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_voice_connector_termination_credentials_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_voice_connector_termination_credentials_request(
+        &self,
+        input: ListVoiceConnectorTerminationCredentialsRequest,
+    ) -> SignedRequest {
         let request_uri = format!(
             "/voice-connectors/{voice_connector_id}/termination/credentials",
             voice_connector_id = input.voice_connector_id
         );
 
         let mut request = SignedRequest::new("GET", "chime", &self.region, &request_uri);
+        request.set_operation("ListVoiceConnectorTerminationCredentials");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
+        request
+    }
+
+    /// Calls `list_voice_connectors`, also returning the metadata of the response.
+    pub async fn list_voice_connectors_with_metadata(
+        &self,
+        input: ListVoiceConnectorsRequest,
+    ) -> Result<
+        (ListVoiceConnectorsResponse, ResponseMetadata),
+        RusotoError<ListVoiceConnectorsError>,
+    > {
+        let request = self.build_list_voice_connectors_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListVoiceConnectorTerminationCredentialsResponse, _>()?;
+                .deserialize::<ListVoiceConnectorsResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(ListVoiceConnectorTerminationCredentialsError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(ListVoiceConnectorsError::from_response(response))
         }
     }
 
-    /// <p>Lists the Amazon Chime Voice Connectors for the administrator's AWS account.</p>
-    async fn list_voice_connectors(
+    /// Presigns the request `list_voice_connectors` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_voice_connectors(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListVoiceConnectorsRequest,
-    ) -> Result<ListVoiceConnectorsResponse, RusotoError<ListVoiceConnectorsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_voice_connectors_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_voice_connectors_request(
+        &self,
+        input: ListVoiceConnectorsRequest,
+    ) -> SignedRequest {
         let request_uri = "/voice-connectors";
 
         let mut request = SignedRequest::new("GET", "chime", &self.region, &request_uri);
+        request.set_operation("ListVoiceConnectors");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -12978,28 +16197,51 @@ impl Chime for ChimeClient {
         }
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `logout_user`, also returning the metadata of the response.
+    pub async fn logout_user_with_metadata(
+        &self,
+        input: LogoutUserRequest,
+    ) -> Result<(LogoutUserResponse, ResponseMetadata), RusotoError<LogoutUserError>> {
+        let request = self.build_logout_user_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
-        if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let metadata = ResponseMetadata::from_response(&response);
+        if response.status.as_u16() == 204 {
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListVoiceConnectorsResponse, _>()?;
+                .deserialize::<LogoutUserResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(ListVoiceConnectorsError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(LogoutUserError::from_response(response))
         }
     }
 
-    /// <p>Logs out the specified user from all of the devices they are currently logged into.</p>
-    async fn logout_user(
+    /// Presigns the request `logout_user` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_logout_user(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: LogoutUserRequest,
-    ) -> Result<LogoutUserResponse, RusotoError<LogoutUserError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_logout_user_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_logout_user_request(&self, input: LogoutUserRequest) -> SignedRequest {
         let request_uri = format!(
             "/accounts/{account_id}/users/{user_id}",
             account_id = input.account_id,
@@ -13007,34 +16249,64 @@ impl Chime for ChimeClient {
         );
 
         let mut request = SignedRequest::new("POST", "chime", &self.region, &request_uri);
+        request.set_operation("LogoutUser");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
         params.put("operation", "logout");
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `put_events_configuration`, also returning the metadata of the response.
+    pub async fn put_events_configuration_with_metadata(
+        &self,
+        input: PutEventsConfigurationRequest,
+    ) -> Result<
+        (PutEventsConfigurationResponse, ResponseMetadata),
+        RusotoError<PutEventsConfigurationError>,
+    > {
+        let request = self.build_put_events_configuration_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
-        if response.status.as_u16() == 204 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let metadata = ResponseMetadata::from_response(&response);
+        if response.status.as_u16() == 201 {
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<LogoutUserResponse, _>()?;
+                .deserialize::<PutEventsConfigurationResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(LogoutUserError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(PutEventsConfigurationError::from_response(response))
         }
     }
 
-    /// <p>Creates an events configuration that allows a bot to receive outgoing events sent by Amazon Chime. Choose either an HTTPS endpoint or a Lambda function ARN. For more information, see <a>Bot</a>.</p>
-    async fn put_events_configuration(
+    /// Presigns the request `put_events_configuration` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_put_events_configuration(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: PutEventsConfigurationRequest,
-    ) -> Result<PutEventsConfigurationResponse, RusotoError<PutEventsConfigurationError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_put_events_configuration_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_put_events_configuration_request(
+        &self,
+        input: PutEventsConfigurationRequest,
+    ) -> SignedRequest {
         let request_uri = format!(
             "/accounts/{account_id}/bots/{bot_id}/events-configuration",
             account_id = input.account_id,
@@ -13042,250 +16314,451 @@ impl Chime for ChimeClient {
         );
 
         let mut request = SignedRequest::new("PUT", "chime", &self.region, &request_uri);
+        request.set_operation("PutEventsConfiguration");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
-        let mut response = self
-            .client
-            .sign_and_dispatch(request)
-            .await
-            .map_err(RusotoError::from)?;
-        if response.status.as_u16() == 201 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<PutEventsConfigurationResponse, _>()?;
-
-            Ok(result)
-        } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(PutEventsConfigurationError::from_response(response))
-        }
+        request
     }
 
-    /// <p>Adds a logging configuration for the specified Amazon Chime Voice Connector. The logging configuration specifies whether SIP message logs are enabled for sending to Amazon CloudWatch Logs.</p>
-    async fn put_voice_connector_logging_configuration(
+    /// Calls `put_voice_connector_logging_configuration`, also returning the metadata of the response.
+    pub async fn put_voice_connector_logging_configuration_with_metadata(
         &self,
         input: PutVoiceConnectorLoggingConfigurationRequest,
     ) -> Result<
-        PutVoiceConnectorLoggingConfigurationResponse,
+        (
+            PutVoiceConnectorLoggingConfigurationResponse,
+            ResponseMetadata,
+        ),
         RusotoError<PutVoiceConnectorLoggingConfigurationError>,
     > {
-        let request_uri = format!(
-            "/voice-connectors/{voice_connector_id}/logging-configuration",
-            voice_connector_id = input.voice_connector_id
-        );
-
-        let mut request = SignedRequest::new("PUT", "chime", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let encoded = Some(serde_json::to_vec(&input).unwrap());
-        request.set_payload(encoded);
+        let request = self.build_put_voice_connector_logging_configuration_request(input);
 
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<PutVoiceConnectorLoggingConfigurationResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             Err(PutVoiceConnectorLoggingConfigurationError::from_response(
                 response,
             ))
         }
     }
 
-    /// <p>Adds origination settings for the specified Amazon Chime Voice Connector.</p>
-    async fn put_voice_connector_origination(
+    /// Presigns the request `put_voice_connector_logging_configuration` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_put_voice_connector_logging_configuration(
         &self,
-        input: PutVoiceConnectorOriginationRequest,
-    ) -> Result<PutVoiceConnectorOriginationResponse, RusotoError<PutVoiceConnectorOriginationError>>
-    {
+        presigner: &rusoto_core::Presigner,
+        input: PutVoiceConnectorLoggingConfigurationRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_put_voice_connector_logging_configuration_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_put_voice_connector_logging_configuration_request(
+        &self,
+        input: PutVoiceConnectorLoggingConfigurationRequest,
+    ) -> SignedRequest {
         let request_uri = format!(
-            "/voice-connectors/{voice_connector_id}/origination",
+            "/voice-connectors/{voice_connector_id}/logging-configuration",
             voice_connector_id = input.voice_connector_id
         );
 
         let mut request = SignedRequest::new("PUT", "chime", &self.region, &request_uri);
+        request.set_operation("PutVoiceConnectorLoggingConfiguration");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `put_voice_connector_origination`, also returning the metadata of the response.
+    pub async fn put_voice_connector_origination_with_metadata(
+        &self,
+        input: PutVoiceConnectorOriginationRequest,
+    ) -> Result<
+        (PutVoiceConnectorOriginationResponse, ResponseMetadata),
+        RusotoError<PutVoiceConnectorOriginationError>,
+    > {
+        let request = self.build_put_voice_connector_origination_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<PutVoiceConnectorOriginationResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             Err(PutVoiceConnectorOriginationError::from_response(response))
         }
     }
 
-    /// <p>Adds a streaming configuration for the specified Amazon Chime Voice Connector. The streaming configuration specifies whether media streaming is enabled for sending to Amazon Kinesis. It also sets the retention period, in hours, for the Amazon Kinesis data.</p>
-    async fn put_voice_connector_streaming_configuration(
+    /// Presigns the request `put_voice_connector_origination` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_put_voice_connector_origination(
         &self,
-        input: PutVoiceConnectorStreamingConfigurationRequest,
-    ) -> Result<
-        PutVoiceConnectorStreamingConfigurationResponse,
-        RusotoError<PutVoiceConnectorStreamingConfigurationError>,
-    > {
+        presigner: &rusoto_core::Presigner,
+        input: PutVoiceConnectorOriginationRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_put_voice_connector_origination_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_put_voice_connector_origination_request(
+        &self,
+        input: PutVoiceConnectorOriginationRequest,
+    ) -> SignedRequest {
         let request_uri = format!(
-            "/voice-connectors/{voice_connector_id}/streaming-configuration",
+            "/voice-connectors/{voice_connector_id}/origination",
             voice_connector_id = input.voice_connector_id
         );
 
         let mut request = SignedRequest::new("PUT", "chime", &self.region, &request_uri);
+        request.set_operation("PutVoiceConnectorOrigination");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `put_voice_connector_streaming_configuration`, also returning the metadata of the response.
+    pub async fn put_voice_connector_streaming_configuration_with_metadata(
+        &self,
+        input: PutVoiceConnectorStreamingConfigurationRequest,
+    ) -> Result<
+        (
+            PutVoiceConnectorStreamingConfigurationResponse,
+            ResponseMetadata,
+        ),
+        RusotoError<PutVoiceConnectorStreamingConfigurationError>,
+    > {
+        let request = self.build_put_voice_connector_streaming_configuration_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<PutVoiceConnectorStreamingConfigurationResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             Err(PutVoiceConnectorStreamingConfigurationError::from_response(
                 response,
             ))
         }
     }
 
-    /// <p>Adds termination settings for the specified Amazon Chime Voice Connector.</p>
-    async fn put_voice_connector_termination(
+    /// Presigns the request `put_voice_connector_streaming_configuration` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_put_voice_connector_streaming_configuration(
         &self,
-        input: PutVoiceConnectorTerminationRequest,
-    ) -> Result<PutVoiceConnectorTerminationResponse, RusotoError<PutVoiceConnectorTerminationError>>
-    {
+        presigner: &rusoto_core::Presigner,
+        input: PutVoiceConnectorStreamingConfigurationRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_put_voice_connector_streaming_configuration_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_put_voice_connector_streaming_configuration_request(
+        &self,
+        input: PutVoiceConnectorStreamingConfigurationRequest,
+    ) -> SignedRequest {
         let request_uri = format!(
-            "/voice-connectors/{voice_connector_id}/termination",
+            "/voice-connectors/{voice_connector_id}/streaming-configuration",
             voice_connector_id = input.voice_connector_id
         );
 
         let mut request = SignedRequest::new("PUT", "chime", &self.region, &request_uri);
+        request.set_operation("PutVoiceConnectorStreamingConfiguration");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `put_voice_connector_termination`, also returning the metadata of the response.
+    pub async fn put_voice_connector_termination_with_metadata(
+        &self,
+        input: PutVoiceConnectorTerminationRequest,
+    ) -> Result<
+        (PutVoiceConnectorTerminationResponse, ResponseMetadata),
+        RusotoError<PutVoiceConnectorTerminationError>,
+    > {
+        let request = self.build_put_voice_connector_termination_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<PutVoiceConnectorTerminationResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             Err(PutVoiceConnectorTerminationError::from_response(response))
         }
     }
 
-    /// <p>Adds termination SIP credentials for the specified Amazon Chime Voice Connector.</p>
-    async fn put_voice_connector_termination_credentials(
+    /// Presigns the request `put_voice_connector_termination` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_put_voice_connector_termination(
         &self,
-        input: PutVoiceConnectorTerminationCredentialsRequest,
-    ) -> Result<(), RusotoError<PutVoiceConnectorTerminationCredentialsError>> {
+        presigner: &rusoto_core::Presigner,
+        input: PutVoiceConnectorTerminationRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_put_voice_connector_termination_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_put_voice_connector_termination_request(
+        &self,
+        input: PutVoiceConnectorTerminationRequest,
+    ) -> SignedRequest {
         let request_uri = format!(
-            "/voice-connectors/{voice_connector_id}/termination/credentials",
+            "/voice-connectors/{voice_connector_id}/termination",
             voice_connector_id = input.voice_connector_id
         );
 
-        let mut request = SignedRequest::new("POST", "chime", &self.region, &request_uri);
+        let mut request = SignedRequest::new("PUT", "chime", &self.region, &request_uri);
+        request.set_operation("PutVoiceConnectorTermination");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
-        let mut params = Params::new();
-        params.put("operation", "put");
-        request.set_params(params);
+        request
+    }
+
+    /// Calls `put_voice_connector_termination_credentials`, also returning the metadata of the response.
+    pub async fn put_voice_connector_termination_credentials_with_metadata(
+        &self,
+        input: PutVoiceConnectorTerminationCredentialsRequest,
+    ) -> Result<((), ResponseMetadata), RusotoError<PutVoiceConnectorTerminationCredentialsError>>
+    {
+        let request = self.build_put_voice_connector_termination_credentials_request(input);
 
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 204 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = ::std::mem::drop(response);
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             Err(PutVoiceConnectorTerminationCredentialsError::from_response(
                 response,
             ))
         }
     }
 
-    /// <p>Regenerates the security token for a bot.</p>
-    async fn regenerate_security_token(
+    /// Presigns the request `put_voice_connector_termination_credentials` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_put_voice_connector_termination_credentials(
         &self,
-        input: RegenerateSecurityTokenRequest,
-    ) -> Result<RegenerateSecurityTokenResponse, RusotoError<RegenerateSecurityTokenError>> {
+        presigner: &rusoto_core::Presigner,
+        input: PutVoiceConnectorTerminationCredentialsRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_put_voice_connector_termination_credentials_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_put_voice_connector_termination_credentials_request(
+        &self,
+        input: PutVoiceConnectorTerminationCredentialsRequest,
+    ) -> SignedRequest {
         let request_uri = format!(
-            "/accounts/{account_id}/bots/{bot_id}",
-            account_id = input.account_id,
-            bot_id = input.bot_id
+            "/voice-connectors/{voice_connector_id}/termination/credentials",
+            voice_connector_id = input.voice_connector_id
         );
 
         let mut request = SignedRequest::new("POST", "chime", &self.region, &request_uri);
+        request.set_operation("PutVoiceConnectorTerminationCredentials");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
+        let encoded = Some(serde_json::to_vec(&input).unwrap());
+        request.set_payload(encoded);
+
         let mut params = Params::new();
-        params.put("operation", "regenerate-security-token");
+        params.put("operation", "put");
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `regenerate_security_token`, also returning the metadata of the response.
+    pub async fn regenerate_security_token_with_metadata(
+        &self,
+        input: RegenerateSecurityTokenRequest,
+    ) -> Result<
+        (RegenerateSecurityTokenResponse, ResponseMetadata),
+        RusotoError<RegenerateSecurityTokenError>,
+    > {
+        let request = self.build_regenerate_security_token_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<RegenerateSecurityTokenResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             Err(RegenerateSecurityTokenError::from_response(response))
         }
     }
 
-    /// <p>Resets the personal meeting PIN for the specified user on an Amazon Chime account. Returns the <a>User</a> object with the updated personal meeting PIN.</p>
-    async fn reset_personal_pin(
+    /// Presigns the request `regenerate_security_token` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_regenerate_security_token(
+        &self,
+        presigner: &rusoto_core::Presigner,
+        input: RegenerateSecurityTokenRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_regenerate_security_token_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_regenerate_security_token_request(
+        &self,
+        input: RegenerateSecurityTokenRequest,
+    ) -> SignedRequest {
+        let request_uri = format!(
+            "/accounts/{account_id}/bots/{bot_id}",
+            account_id = input.account_id,
+            bot_id = input.bot_id
+        );
+
+        let mut request = SignedRequest::new("POST", "chime", &self.region, &request_uri);
+        request.set_operation("RegenerateSecurityToken");
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let mut params = Params::new();
+        params.put("operation", "regenerate-security-token");
+        request.set_params(params);
+
+        request
+    }
+
+    /// Calls `reset_personal_pin`, also returning the metadata of the response.
+    pub async fn reset_personal_pin_with_metadata(
         &self,
         input: ResetPersonalPINRequest,
-    ) -> Result<ResetPersonalPINResponse, RusotoError<ResetPersonalPINError>> {
+    ) -> Result<(ResetPersonalPINResponse, ResponseMetadata), RusotoError<ResetPersonalPINError>>
+    {
+        let request = self.build_reset_personal_pin_request(input);
+
+        let mut response = self
+            .client
+            .sign_and_dispatch(request)
+            .await
+            .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
+        if response.status.as_u16() == 200 {
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ResetPersonalPINResponse, _>()?;
+
+            Ok((result, metadata))
+        } else {
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(ResetPersonalPINError::from_response(response))
+        }
+    }
+
+    /// Presigns the request `reset_personal_pin` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_reset_personal_pin(
+        &self,
+        presigner: &rusoto_core::Presigner,
+        input: ResetPersonalPINRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_reset_personal_pin_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_reset_personal_pin_request(&self, input: ResetPersonalPINRequest) -> SignedRequest {
         let request_uri = format!(
             "/accounts/{account_id}/users/{user_id}",
             account_id = input.account_id,
@@ -13293,72 +16766,130 @@ impl Chime for ChimeClient {
         );
 
         let mut request = SignedRequest::new("POST", "chime", &self.region, &request_uri);
+        request.set_operation("ResetPersonalPIN");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
         params.put("operation", "reset-personal-pin");
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `restore_phone_number`, also returning the metadata of the response.
+    pub async fn restore_phone_number_with_metadata(
+        &self,
+        input: RestorePhoneNumberRequest,
+    ) -> Result<(RestorePhoneNumberResponse, ResponseMetadata), RusotoError<RestorePhoneNumberError>>
+    {
+        let request = self.build_restore_phone_number_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<ResetPersonalPINResponse, _>()?;
+                .deserialize::<RestorePhoneNumberResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(ResetPersonalPINError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(RestorePhoneNumberError::from_response(response))
         }
     }
 
-    /// <p>Moves a phone number from the <b>Deletion queue</b> back into the phone number <b>Inventory</b>.</p>
-    async fn restore_phone_number(
+    /// Presigns the request `restore_phone_number` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_restore_phone_number(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: RestorePhoneNumberRequest,
-    ) -> Result<RestorePhoneNumberResponse, RusotoError<RestorePhoneNumberError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_restore_phone_number_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_restore_phone_number_request(
+        &self,
+        input: RestorePhoneNumberRequest,
+    ) -> SignedRequest {
         let request_uri = format!(
             "/phone-numbers/{phone_number_id}",
             phone_number_id = input.phone_number_id
         );
 
         let mut request = SignedRequest::new("POST", "chime", &self.region, &request_uri);
+        request.set_operation("RestorePhoneNumber");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
         params.put("operation", "restore");
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `search_available_phone_numbers`, also returning the metadata of the response.
+    pub async fn search_available_phone_numbers_with_metadata(
+        &self,
+        input: SearchAvailablePhoneNumbersRequest,
+    ) -> Result<
+        (SearchAvailablePhoneNumbersResponse, ResponseMetadata),
+        RusotoError<SearchAvailablePhoneNumbersError>,
+    > {
+        let request = self.build_search_available_phone_numbers_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
-        if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let metadata = ResponseMetadata::from_response(&response);
+        if response.status.is_success() {
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<RestorePhoneNumberResponse, _>()?;
+                .deserialize::<SearchAvailablePhoneNumbersResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(RestorePhoneNumberError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(SearchAvailablePhoneNumbersError::from_response(response))
         }
     }
 
-    /// <p>Searches phone numbers that can be ordered.</p>
-    async fn search_available_phone_numbers(
+    /// Presigns the request `search_available_phone_numbers` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_search_available_phone_numbers(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: SearchAvailablePhoneNumbersRequest,
-    ) -> Result<SearchAvailablePhoneNumbersResponse, RusotoError<SearchAvailablePhoneNumbersError>>
-    {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_search_available_phone_numbers_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_search_available_phone_numbers_request(
+        &self,
+        input: SearchAvailablePhoneNumbersRequest,
+    ) -> SignedRequest {
         let request_uri = "/search";
 
         let mut request = SignedRequest::new("GET", "chime", &self.region, &request_uri);
+        request.set_operation("SearchAvailablePhoneNumbers");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -13386,91 +16917,168 @@ impl Chime for ChimeClient {
         params.put("type", "phone-numbers");
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `update_account`, also returning the metadata of the response.
+    pub async fn update_account_with_metadata(
+        &self,
+        input: UpdateAccountRequest,
+    ) -> Result<(UpdateAccountResponse, ResponseMetadata), RusotoError<UpdateAccountError>> {
+        let request = self.build_update_account_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
-        if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let metadata = ResponseMetadata::from_response(&response);
+        if response.status.as_u16() == 200 {
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<SearchAvailablePhoneNumbersResponse, _>()?;
+                .deserialize::<UpdateAccountResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(SearchAvailablePhoneNumbersError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(UpdateAccountError::from_response(response))
         }
     }
 
-    /// <p>Updates account details for the specified Amazon Chime account. Currently, only account name updates are supported for this action.</p>
-    async fn update_account(
+    /// Presigns the request `update_account` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_account(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UpdateAccountRequest,
-    ) -> Result<UpdateAccountResponse, RusotoError<UpdateAccountError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_account_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_account_request(&self, input: UpdateAccountRequest) -> SignedRequest {
         let request_uri = format!("/accounts/{account_id}", account_id = input.account_id);
 
         let mut request = SignedRequest::new("POST", "chime", &self.region, &request_uri);
+        request.set_operation("UpdateAccount");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `update_account_settings`, also returning the metadata of the response.
+    pub async fn update_account_settings_with_metadata(
+        &self,
+        input: UpdateAccountSettingsRequest,
+    ) -> Result<
+        (UpdateAccountSettingsResponse, ResponseMetadata),
+        RusotoError<UpdateAccountSettingsError>,
+    > {
+        let request = self.build_update_account_settings_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
-        if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let metadata = ResponseMetadata::from_response(&response);
+        if response.status.as_u16() == 204 {
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<UpdateAccountResponse, _>()?;
+                .deserialize::<UpdateAccountSettingsResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(UpdateAccountError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(UpdateAccountSettingsError::from_response(response))
         }
     }
 
-    /// <p>Updates the settings for the specified Amazon Chime account. You can update settings for remote control of shared screens, or for the dial-out option. For more information about these settings, see <a href="https://docs.aws.amazon.com/chime/latest/ag/policies.html">Use the Policies Page</a> in the <i>Amazon Chime Administration Guide</i>.</p>
-    async fn update_account_settings(
+    /// Presigns the request `update_account_settings` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_account_settings(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UpdateAccountSettingsRequest,
-    ) -> Result<UpdateAccountSettingsResponse, RusotoError<UpdateAccountSettingsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_account_settings_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_account_settings_request(
+        &self,
+        input: UpdateAccountSettingsRequest,
+    ) -> SignedRequest {
         let request_uri = format!(
             "/accounts/{account_id}/settings",
             account_id = input.account_id
         );
 
         let mut request = SignedRequest::new("PUT", "chime", &self.region, &request_uri);
+        request.set_operation("UpdateAccountSettings");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `update_bot`, also returning the metadata of the response.
+    pub async fn update_bot_with_metadata(
+        &self,
+        input: UpdateBotRequest,
+    ) -> Result<(UpdateBotResponse, ResponseMetadata), RusotoError<UpdateBotError>> {
+        let request = self.build_update_bot_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
-        if response.status.as_u16() == 204 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let metadata = ResponseMetadata::from_response(&response);
+        if response.status.as_u16() == 200 {
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<UpdateAccountSettingsResponse, _>()?;
+                .deserialize::<UpdateBotResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(UpdateAccountSettingsError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(UpdateBotError::from_response(response))
         }
     }
 
-    /// <p>Updates the status of the specified bot, such as starting or stopping the bot from running in your Amazon Chime Enterprise account.</p>
-    async fn update_bot(
+    /// Presigns the request `update_bot` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_bot(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UpdateBotRequest,
-    ) -> Result<UpdateBotResponse, RusotoError<UpdateBotError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_bot_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_bot_request(&self, input: UpdateBotRequest) -> SignedRequest {
         let request_uri = format!(
             "/accounts/{account_id}/bots/{bot_id}",
             account_id = input.account_id,
@@ -13478,124 +17086,227 @@ impl Chime for ChimeClient {
         );
 
         let mut request = SignedRequest::new("POST", "chime", &self.region, &request_uri);
+        request.set_operation("UpdateBot");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `update_global_settings`, also returning the metadata of the response.
+    pub async fn update_global_settings_with_metadata(
+        &self,
+        input: UpdateGlobalSettingsRequest,
+    ) -> Result<((), ResponseMetadata), RusotoError<UpdateGlobalSettingsError>> {
+        let request = self.build_update_global_settings_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
-        if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<UpdateBotResponse, _>()?;
+        let metadata = ResponseMetadata::from_response(&response);
+        if response.status.as_u16() == 204 {
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = ::std::mem::drop(response);
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(UpdateBotError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(UpdateGlobalSettingsError::from_response(response))
         }
     }
 
-    /// <p>Updates global settings for the administrator's AWS account, such as Amazon Chime Business Calling and Amazon Chime Voice Connector settings.</p>
-    async fn update_global_settings(
+    /// Presigns the request `update_global_settings` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_global_settings(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UpdateGlobalSettingsRequest,
-    ) -> Result<(), RusotoError<UpdateGlobalSettingsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_global_settings_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_global_settings_request(
+        &self,
+        input: UpdateGlobalSettingsRequest,
+    ) -> SignedRequest {
         let request_uri = "/settings";
 
         let mut request = SignedRequest::new("PUT", "chime", &self.region, &request_uri);
+        request.set_operation("UpdateGlobalSettings");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `update_phone_number`, also returning the metadata of the response.
+    pub async fn update_phone_number_with_metadata(
+        &self,
+        input: UpdatePhoneNumberRequest,
+    ) -> Result<(UpdatePhoneNumberResponse, ResponseMetadata), RusotoError<UpdatePhoneNumberError>>
+    {
+        let request = self.build_update_phone_number_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
-        if response.status.as_u16() == 204 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            let result = ::std::mem::drop(response);
+        let metadata = ResponseMetadata::from_response(&response);
+        if response.status.as_u16() == 200 {
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<UpdatePhoneNumberResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(UpdateGlobalSettingsError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(UpdatePhoneNumberError::from_response(response))
         }
     }
 
-    /// <p>Updates phone number details, such as product type or calling name, for the specified phone number ID. You can update one phone number detail at a time. For example, you can update either the product type or the calling name in one action.</p> <p>For toll-free numbers, you must use the Amazon Chime Voice Connector product type.</p> <p>Updates to outbound calling names can take up to 72 hours to complete. Pending updates to outbound calling names must be complete before you can request another update.</p>
-    async fn update_phone_number(
+    /// Presigns the request `update_phone_number` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_phone_number(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UpdatePhoneNumberRequest,
-    ) -> Result<UpdatePhoneNumberResponse, RusotoError<UpdatePhoneNumberError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_phone_number_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_phone_number_request(&self, input: UpdatePhoneNumberRequest) -> SignedRequest {
         let request_uri = format!(
             "/phone-numbers/{phone_number_id}",
             phone_number_id = input.phone_number_id
         );
 
         let mut request = SignedRequest::new("POST", "chime", &self.region, &request_uri);
+        request.set_operation("UpdatePhoneNumber");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `update_phone_number_settings`, also returning the metadata of the response.
+    pub async fn update_phone_number_settings_with_metadata(
+        &self,
+        input: UpdatePhoneNumberSettingsRequest,
+    ) -> Result<((), ResponseMetadata), RusotoError<UpdatePhoneNumberSettingsError>> {
+        let request = self.build_update_phone_number_settings_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
-        if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<UpdatePhoneNumberResponse, _>()?;
+        let metadata = ResponseMetadata::from_response(&response);
+        if response.status.as_u16() == 204 {
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = ::std::mem::drop(response);
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(UpdatePhoneNumberError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(UpdatePhoneNumberSettingsError::from_response(response))
         }
     }
 
-    /// <p>Updates the phone number settings for the administrator's AWS account, such as the default outbound calling name. You can update the default outbound calling name once every seven days. Outbound calling names can take up to 72 hours to update.</p>
-    async fn update_phone_number_settings(
+    /// Presigns the request `update_phone_number_settings` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_phone_number_settings(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UpdatePhoneNumberSettingsRequest,
-    ) -> Result<(), RusotoError<UpdatePhoneNumberSettingsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_phone_number_settings_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_phone_number_settings_request(
+        &self,
+        input: UpdatePhoneNumberSettingsRequest,
+    ) -> SignedRequest {
         let request_uri = "/settings/phone-number";
 
         let mut request = SignedRequest::new("PUT", "chime", &self.region, &request_uri);
+        request.set_operation("UpdatePhoneNumberSettings");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `update_room`, also returning the metadata of the response.
+    pub async fn update_room_with_metadata(
+        &self,
+        input: UpdateRoomRequest,
+    ) -> Result<(UpdateRoomResponse, ResponseMetadata), RusotoError<UpdateRoomError>> {
+        let request = self.build_update_room_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
-        if response.status.as_u16() == 204 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            let result = ::std::mem::drop(response);
+        let metadata = ResponseMetadata::from_response(&response);
+        if response.status.as_u16() == 200 {
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<UpdateRoomResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(UpdatePhoneNumberSettingsError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(UpdateRoomError::from_response(response))
         }
     }
 
-    /// <p>Updates room details, such as the room name.</p>
-    async fn update_room(
+    /// Presigns the request `update_room` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_room(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UpdateRoomRequest,
-    ) -> Result<UpdateRoomResponse, RusotoError<UpdateRoomError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_room_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_room_request(&self, input: UpdateRoomRequest) -> SignedRequest {
         let request_uri = format!(
             "/accounts/{account_id}/rooms/{room_id}",
             account_id = input.account_id,
@@ -13603,33 +17314,63 @@ impl Chime for ChimeClient {
         );
 
         let mut request = SignedRequest::new("POST", "chime", &self.region, &request_uri);
+        request.set_operation("UpdateRoom");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `update_room_membership`, also returning the metadata of the response.
+    pub async fn update_room_membership_with_metadata(
+        &self,
+        input: UpdateRoomMembershipRequest,
+    ) -> Result<
+        (UpdateRoomMembershipResponse, ResponseMetadata),
+        RusotoError<UpdateRoomMembershipError>,
+    > {
+        let request = self.build_update_room_membership_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<UpdateRoomResponse, _>()?;
+                .deserialize::<UpdateRoomMembershipResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(UpdateRoomError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(UpdateRoomMembershipError::from_response(response))
         }
     }
 
-    /// <p>Updates room membership details, such as the member role. The member role designates whether the member is a chat room administrator or a general chat room member. The member role can be updated only for user IDs.</p>
-    async fn update_room_membership(
+    /// Presigns the request `update_room_membership` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_room_membership(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UpdateRoomMembershipRequest,
-    ) -> Result<UpdateRoomMembershipResponse, RusotoError<UpdateRoomMembershipError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_room_membership_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_room_membership_request(
+        &self,
+        input: UpdateRoomMembershipRequest,
+    ) -> SignedRequest {
         let request_uri = format!(
             "/accounts/{account_id}/rooms/{room_id}/memberships/{member_id}",
             account_id = input.account_id,
@@ -13638,33 +17379,57 @@ impl Chime for ChimeClient {
         );
 
         let mut request = SignedRequest::new("POST", "chime", &self.region, &request_uri);
+        request.set_operation("UpdateRoomMembership");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `update_user`, also returning the metadata of the response.
+    pub async fn update_user_with_metadata(
+        &self,
+        input: UpdateUserRequest,
+    ) -> Result<(UpdateUserResponse, ResponseMetadata), RusotoError<UpdateUserError>> {
+        let request = self.build_update_user_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<UpdateRoomMembershipResponse, _>()?;
+                .deserialize::<UpdateUserResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(UpdateRoomMembershipError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(UpdateUserError::from_response(response))
         }
     }
 
-    /// <p>Updates user details for a specified user ID. Currently, only <code>LicenseType</code> updates are supported for this action.</p>
-    async fn update_user(
+    /// Presigns the request `update_user` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_user(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UpdateUserRequest,
-    ) -> Result<UpdateUserResponse, RusotoError<UpdateUserError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_user_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_user_request(&self, input: UpdateUserRequest) -> SignedRequest {
         let request_uri = format!(
             "/accounts/{account_id}/users/{user_id}",
             account_id = input.account_id,
@@ -13672,33 +17437,59 @@ impl Chime for ChimeClient {
         );
 
         let mut request = SignedRequest::new("POST", "chime", &self.region, &request_uri);
+        request.set_operation("UpdateUser");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `update_user_settings`, also returning the metadata of the response.
+    pub async fn update_user_settings_with_metadata(
+        &self,
+        input: UpdateUserSettingsRequest,
+    ) -> Result<((), ResponseMetadata), RusotoError<UpdateUserSettingsError>> {
+        let request = self.build_update_user_settings_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
-        if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<UpdateUserResponse, _>()?;
-
-            Ok(result)
+        let metadata = ResponseMetadata::from_response(&response);
+        if response.status.as_u16() == 204 {
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = ::std::mem::drop(response);
+
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(UpdateUserError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(UpdateUserSettingsError::from_response(response))
         }
     }
 
-    /// <p>Updates the settings for the specified user, such as phone number settings.</p>
-    async fn update_user_settings(
+    /// Presigns the request `update_user_settings` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_user_settings(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UpdateUserSettingsRequest,
-    ) -> Result<(), RusotoError<UpdateUserSettingsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_user_settings_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_user_settings_request(
+        &self,
+        input: UpdateUserSettingsRequest,
+    ) -> SignedRequest {
         let request_uri = format!(
             "/accounts/{account_id}/users/{user_id}/settings",
             account_id = input.account_id,
@@ -13706,91 +17497,1197 @@ impl Chime for ChimeClient {
         );
 
         let mut request = SignedRequest::new("PUT", "chime", &self.region, &request_uri);
+        request.set_operation("UpdateUserSettings");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `update_voice_connector`, also returning the metadata of the response.
+    pub async fn update_voice_connector_with_metadata(
+        &self,
+        input: UpdateVoiceConnectorRequest,
+    ) -> Result<
+        (UpdateVoiceConnectorResponse, ResponseMetadata),
+        RusotoError<UpdateVoiceConnectorError>,
+    > {
+        let request = self.build_update_voice_connector_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
-        if response.status.as_u16() == 204 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            let result = ::std::mem::drop(response);
+        let metadata = ResponseMetadata::from_response(&response);
+        if response.status.as_u16() == 200 {
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<UpdateVoiceConnectorResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(UpdateUserSettingsError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(UpdateVoiceConnectorError::from_response(response))
         }
     }
 
-    /// <p>Updates details for the specified Amazon Chime Voice Connector.</p>
-    async fn update_voice_connector(
+    /// Presigns the request `update_voice_connector` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_voice_connector(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UpdateVoiceConnectorRequest,
-    ) -> Result<UpdateVoiceConnectorResponse, RusotoError<UpdateVoiceConnectorError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_voice_connector_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_voice_connector_request(
+        &self,
+        input: UpdateVoiceConnectorRequest,
+    ) -> SignedRequest {
         let request_uri = format!(
             "/voice-connectors/{voice_connector_id}",
             voice_connector_id = input.voice_connector_id
         );
 
         let mut request = SignedRequest::new("PUT", "chime", &self.region, &request_uri);
+        request.set_operation("UpdateVoiceConnector");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `update_voice_connector_group`, also returning the metadata of the response.
+    pub async fn update_voice_connector_group_with_metadata(
+        &self,
+        input: UpdateVoiceConnectorGroupRequest,
+    ) -> Result<
+        (UpdateVoiceConnectorGroupResponse, ResponseMetadata),
+        RusotoError<UpdateVoiceConnectorGroupError>,
+    > {
+        let request = self.build_update_voice_connector_group_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
-        if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let metadata = ResponseMetadata::from_response(&response);
+        if response.status.as_u16() == 202 {
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<UpdateVoiceConnectorResponse, _>()?;
+                .deserialize::<UpdateVoiceConnectorGroupResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(UpdateVoiceConnectorError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(UpdateVoiceConnectorGroupError::from_response(response))
         }
     }
 
-    /// <p>Updates details for the specified Amazon Chime Voice Connector group, such as the name and Amazon Chime Voice Connector priority ranking.</p>
-    async fn update_voice_connector_group(
+    /// Presigns the request `update_voice_connector_group` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_voice_connector_group(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UpdateVoiceConnectorGroupRequest,
-    ) -> Result<UpdateVoiceConnectorGroupResponse, RusotoError<UpdateVoiceConnectorGroupError>>
-    {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_voice_connector_group_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_voice_connector_group_request(
+        &self,
+        input: UpdateVoiceConnectorGroupRequest,
+    ) -> SignedRequest {
         let request_uri = format!(
             "/voice-connector-groups/{voice_connector_group_id}",
             voice_connector_group_id = input.voice_connector_group_id
         );
 
         let mut request = SignedRequest::new("PUT", "chime", &self.region, &request_uri);
+        request.set_operation("UpdateVoiceConnectorGroup");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
-        let mut response = self
-            .client
-            .sign_and_dispatch(request)
+        request
+    }
+
+    /// The pages of `ListAccounts` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `MaxResults` of `input`.
+    pub fn list_accounts_pages(
+        &self,
+        input: ListAccountsRequest,
+    ) -> impl futures::Stream<Item = Result<ListAccountsResponse, RusotoError<ListAccountsError>>> + Send
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: ChimeClient, input| async move { client.list_accounts(input).await },
+            |input: &ListAccountsRequest, output: &ListAccountsResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListAccountsRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `Accounts` of every page of `ListAccounts` results for `input`, as `list_accounts_pages` requests them.
+    pub fn list_accounts_stream(
+        &self,
+        input: ListAccountsRequest,
+    ) -> impl futures::Stream<Item = Result<Account, RusotoError<ListAccountsError>>> + Send {
+        rusoto_core::pagination::items(self.list_accounts_pages(input), |page| {
+            page.accounts.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `ListUsers` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `MaxResults` of `input`.
+    pub fn list_users_pages(
+        &self,
+        input: ListUsersRequest,
+    ) -> impl futures::Stream<Item = Result<ListUsersResponse, RusotoError<ListUsersError>>> + Send
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: ChimeClient, input| async move { client.list_users(input).await },
+            |input: &ListUsersRequest, output: &ListUsersResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListUsersRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `Users` of every page of `ListUsers` results for `input`, as `list_users_pages` requests them.
+    pub fn list_users_stream(
+        &self,
+        input: ListUsersRequest,
+    ) -> impl futures::Stream<Item = Result<User, RusotoError<ListUsersError>>> + Send {
+        rusoto_core::pagination::items(self.list_users_pages(input), |page| {
+            page.users.unwrap_or_default()
+        })
+    }
+}
+
+#[async_trait]
+impl Chime for ChimeClient {
+    /// <p>Associates a phone number with the specified Amazon Chime user.</p>
+    async fn associate_phone_number_with_user(
+        &self,
+        input: AssociatePhoneNumberWithUserRequest,
+    ) -> Result<AssociatePhoneNumberWithUserResponse, RusotoError<AssociatePhoneNumberWithUserError>>
+    {
+        self.associate_phone_number_with_user_with_metadata(input)
             .await
-            .map_err(RusotoError::from)?;
-        if response.status.as_u16() == 202 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<UpdateVoiceConnectorGroupResponse, _>()?;
+            .map(|(output, _)| output)
+    }
 
-            Ok(result)
-        } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(UpdateVoiceConnectorGroupError::from_response(response))
-        }
+    /// <p>Associates phone numbers with the specified Amazon Chime Voice Connector.</p>
+    async fn associate_phone_numbers_with_voice_connector(
+        &self,
+        input: AssociatePhoneNumbersWithVoiceConnectorRequest,
+    ) -> Result<
+        AssociatePhoneNumbersWithVoiceConnectorResponse,
+        RusotoError<AssociatePhoneNumbersWithVoiceConnectorError>,
+    > {
+        self.associate_phone_numbers_with_voice_connector_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Associates phone numbers with the specified Amazon Chime Voice Connector group.</p>
+    async fn associate_phone_numbers_with_voice_connector_group(
+        &self,
+        input: AssociatePhoneNumbersWithVoiceConnectorGroupRequest,
+    ) -> Result<
+        AssociatePhoneNumbersWithVoiceConnectorGroupResponse,
+        RusotoError<AssociatePhoneNumbersWithVoiceConnectorGroupError>,
+    > {
+        self.associate_phone_numbers_with_voice_connector_group_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Associates the specified sign-in delegate groups with the specified Amazon Chime account.</p>
+    async fn associate_signin_delegate_groups_with_account(
+        &self,
+        input: AssociateSigninDelegateGroupsWithAccountRequest,
+    ) -> Result<
+        AssociateSigninDelegateGroupsWithAccountResponse,
+        RusotoError<AssociateSigninDelegateGroupsWithAccountError>,
+    > {
+        self.associate_signin_delegate_groups_with_account_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Creates up to 100 new attendees for an active Amazon Chime SDK meeting. For more information about the Amazon Chime SDK, see <a href="https://docs.aws.amazon.com/chime/latest/dg/meetings-sdk.html">Using the Amazon Chime SDK</a> in the <i>Amazon Chime Developer Guide</i>. </p>
+    async fn batch_create_attendee(
+        &self,
+        input: BatchCreateAttendeeRequest,
+    ) -> Result<BatchCreateAttendeeResponse, RusotoError<BatchCreateAttendeeError>> {
+        self.batch_create_attendee_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Adds up to 50 members to a chat room. Members can be either users or bots. The member role designates whether the member is a chat room administrator or a general chat room member.</p>
+    async fn batch_create_room_membership(
+        &self,
+        input: BatchCreateRoomMembershipRequest,
+    ) -> Result<BatchCreateRoomMembershipResponse, RusotoError<BatchCreateRoomMembershipError>>
+    {
+        self.batch_create_room_membership_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Moves phone numbers into the <b>Deletion queue</b>. Phone numbers must be disassociated from any users or Amazon Chime Voice Connectors before they can be deleted.</p> <p>Phone numbers remain in the <b>Deletion queue</b> for 7 days before they are deleted permanently.</p>
+    async fn batch_delete_phone_number(
+        &self,
+        input: BatchDeletePhoneNumberRequest,
+    ) -> Result<BatchDeletePhoneNumberResponse, RusotoError<BatchDeletePhoneNumberError>> {
+        self.batch_delete_phone_number_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Suspends up to 50 users from a <code>Team</code> or <code>EnterpriseLWA</code> Amazon Chime account. For more information about different account types, see <a href="https://docs.aws.amazon.com/chime/latest/ag/manage-chime-account.html">Managing Your Amazon Chime Accounts</a> in the <i>Amazon Chime Administration Guide</i>.</p> <p>Users suspended from a <code>Team</code> account are disassociated from the account, but they can continue to use Amazon Chime as free users. To remove the suspension from suspended <code>Team</code> account users, invite them to the <code>Team</code> account again. You can use the <a>InviteUsers</a> action to do so.</p> <p>Users suspended from an <code>EnterpriseLWA</code> account are immediately signed out of Amazon Chime and can no longer sign in. To remove the suspension from suspended <code>EnterpriseLWA</code> account users, use the <a>BatchUnsuspendUser</a> action. </p> <p>To sign out users without suspending them, use the <a>LogoutUser</a> action.</p>
+    async fn batch_suspend_user(
+        &self,
+        input: BatchSuspendUserRequest,
+    ) -> Result<BatchSuspendUserResponse, RusotoError<BatchSuspendUserError>> {
+        self.batch_suspend_user_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Removes the suspension from up to 50 previously suspended users for the specified Amazon Chime <code>EnterpriseLWA</code> account. Only users on <code>EnterpriseLWA</code> accounts can be unsuspended using this action. For more information about different account types, see <a href="https://docs.aws.amazon.com/chime/latest/ag/manage-chime-account.html">Managing Your Amazon Chime Accounts</a> in the <i>Amazon Chime Administration Guide</i>.</p> <p>Previously suspended users who are unsuspended using this action are returned to <code>Registered</code> status. Users who are not previously suspended are ignored.</p>
+    async fn batch_unsuspend_user(
+        &self,
+        input: BatchUnsuspendUserRequest,
+    ) -> Result<BatchUnsuspendUserResponse, RusotoError<BatchUnsuspendUserError>> {
+        self.batch_unsuspend_user_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Updates phone number product types or calling names. You can update one attribute at a time for each <code>UpdatePhoneNumberRequestItem</code>. For example, you can update either the product type or the calling name.</p> <p>For product types, choose from Amazon Chime Business Calling and Amazon Chime Voice Connector. For toll-free numbers, you must use the Amazon Chime Voice Connector product type.</p> <p>Updates to outbound calling names can take up to 72 hours to complete. Pending updates to outbound calling names must be complete before you can request another update.</p>
+    async fn batch_update_phone_number(
+        &self,
+        input: BatchUpdatePhoneNumberRequest,
+    ) -> Result<BatchUpdatePhoneNumberResponse, RusotoError<BatchUpdatePhoneNumberError>> {
+        self.batch_update_phone_number_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Updates user details within the <a>UpdateUserRequestItem</a> object for up to 20 users for the specified Amazon Chime account. Currently, only <code>LicenseType</code> updates are supported for this action.</p>
+    async fn batch_update_user(
+        &self,
+        input: BatchUpdateUserRequest,
+    ) -> Result<BatchUpdateUserResponse, RusotoError<BatchUpdateUserError>> {
+        self.batch_update_user_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Creates an Amazon Chime account under the administrator's AWS account. Only <code>Team</code> account types are currently supported for this action. For more information about different account types, see <a href="https://docs.aws.amazon.com/chime/latest/ag/manage-chime-account.html">Managing Your Amazon Chime Accounts</a> in the <i>Amazon Chime Administration Guide</i>.</p>
+    async fn create_account(
+        &self,
+        input: CreateAccountRequest,
+    ) -> Result<CreateAccountResponse, RusotoError<CreateAccountError>> {
+        self.create_account_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Creates a new attendee for an active Amazon Chime SDK meeting. For more information about the Amazon Chime SDK, see <a href="https://docs.aws.amazon.com/chime/latest/dg/meetings-sdk.html">Using the Amazon Chime SDK</a> in the <i>Amazon Chime Developer Guide</i>.</p>
+    async fn create_attendee(
+        &self,
+        input: CreateAttendeeRequest,
+    ) -> Result<CreateAttendeeResponse, RusotoError<CreateAttendeeError>> {
+        self.create_attendee_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Creates a bot for an Amazon Chime Enterprise account.</p>
+    async fn create_bot(
+        &self,
+        input: CreateBotRequest,
+    ) -> Result<CreateBotResponse, RusotoError<CreateBotError>> {
+        self.create_bot_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Creates a new Amazon Chime SDK meeting in the specified media Region with no initial attendees. For more information about the Amazon Chime SDK, see <a href="https://docs.aws.amazon.com/chime/latest/dg/meetings-sdk.html">Using the Amazon Chime SDK</a> in the <i>Amazon Chime Developer Guide</i>.</p>
+    async fn create_meeting(
+        &self,
+        input: CreateMeetingRequest,
+    ) -> Result<CreateMeetingResponse, RusotoError<CreateMeetingError>> {
+        self.create_meeting_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Creates an order for phone numbers to be provisioned. Choose from Amazon Chime Business Calling and Amazon Chime Voice Connector product types. For toll-free numbers, you must use the Amazon Chime Voice Connector product type.</p>
+    async fn create_phone_number_order(
+        &self,
+        input: CreatePhoneNumberOrderRequest,
+    ) -> Result<CreatePhoneNumberOrderResponse, RusotoError<CreatePhoneNumberOrderError>> {
+        self.create_phone_number_order_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Creates a chat room for the specified Amazon Chime account.</p>
+    async fn create_room(
+        &self,
+        input: CreateRoomRequest,
+    ) -> Result<CreateRoomResponse, RusotoError<CreateRoomError>> {
+        self.create_room_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Adds a member to a chat room. A member can be either a user or a bot. The member role designates whether the member is a chat room administrator or a general chat room member.</p>
+    async fn create_room_membership(
+        &self,
+        input: CreateRoomMembershipRequest,
+    ) -> Result<CreateRoomMembershipResponse, RusotoError<CreateRoomMembershipError>> {
+        self.create_room_membership_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Creates a user under the specified Amazon Chime account.</p>
+    async fn create_user(
+        &self,
+        input: CreateUserRequest,
+    ) -> Result<CreateUserResponse, RusotoError<CreateUserError>> {
+        self.create_user_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Creates an Amazon Chime Voice Connector under the administrator's AWS account. You can choose to create an Amazon Chime Voice Connector in a specific AWS Region.</p> <p>Enabling <a>CreateVoiceConnectorRequest$RequireEncryption</a> configures your Amazon Chime Voice Connector to use TLS transport for SIP signaling and Secure RTP (SRTP) for media. Inbound calls use TLS transport, and unencrypted outbound calls are blocked.</p>
+    async fn create_voice_connector(
+        &self,
+        input: CreateVoiceConnectorRequest,
+    ) -> Result<CreateVoiceConnectorResponse, RusotoError<CreateVoiceConnectorError>> {
+        self.create_voice_connector_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Creates an Amazon Chime Voice Connector group under the administrator's AWS account. You can associate up to three existing Amazon Chime Voice Connectors with the Amazon Chime Voice Connector group by including <code>VoiceConnectorItems</code> in the request.</p> <p>You can include Amazon Chime Voice Connectors from different AWS Regions in your group. This creates a fault tolerant mechanism for fallback in case of availability events.</p>
+    async fn create_voice_connector_group(
+        &self,
+        input: CreateVoiceConnectorGroupRequest,
+    ) -> Result<CreateVoiceConnectorGroupResponse, RusotoError<CreateVoiceConnectorGroupError>>
+    {
+        self.create_voice_connector_group_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes the specified Amazon Chime account. You must suspend all users before deleting a <code>Team</code> account. You can use the <a>BatchSuspendUser</a> action to do so.</p> <p>For <code>EnterpriseLWA</code> and <code>EnterpriseAD</code> accounts, you must release the claimed domains for your Amazon Chime account before deletion. As soon as you release the domain, all users under that account are suspended.</p> <p>Deleted accounts appear in your <code>Disabled</code> accounts list for 90 days. To restore a deleted account from your <code>Disabled</code> accounts list, you must contact AWS Support.</p> <p>After 90 days, deleted accounts are permanently removed from your <code>Disabled</code> accounts list.</p>
+    async fn delete_account(
+        &self,
+        input: DeleteAccountRequest,
+    ) -> Result<DeleteAccountResponse, RusotoError<DeleteAccountError>> {
+        self.delete_account_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes an attendee from the specified Amazon Chime SDK meeting and deletes their <code>JoinToken</code>. Attendees are automatically deleted when a Amazon Chime SDK meeting is deleted. For more information about the Amazon Chime SDK, see <a href="https://docs.aws.amazon.com/chime/latest/dg/meetings-sdk.html">Using the Amazon Chime SDK</a> in the <i>Amazon Chime Developer Guide</i>.</p>
+    async fn delete_attendee(
+        &self,
+        input: DeleteAttendeeRequest,
+    ) -> Result<(), RusotoError<DeleteAttendeeError>> {
+        self.delete_attendee_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes the events configuration that allows a bot to receive outgoing events.</p>
+    async fn delete_events_configuration(
+        &self,
+        input: DeleteEventsConfigurationRequest,
+    ) -> Result<(), RusotoError<DeleteEventsConfigurationError>> {
+        self.delete_events_configuration_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes the specified Amazon Chime SDK meeting. When a meeting is deleted, its attendees are also deleted and clients can no longer join it. For more information about the Amazon Chime SDK, see <a href="https://docs.aws.amazon.com/chime/latest/dg/meetings-sdk.html">Using the Amazon Chime SDK</a> in the <i>Amazon Chime Developer Guide</i>.</p>
+    async fn delete_meeting(
+        &self,
+        input: DeleteMeetingRequest,
+    ) -> Result<(), RusotoError<DeleteMeetingError>> {
+        self.delete_meeting_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Moves the specified phone number into the <b>Deletion queue</b>. A phone number must be disassociated from any users or Amazon Chime Voice Connectors before it can be deleted.</p> <p>Deleted phone numbers remain in the <b>Deletion queue</b> for 7 days before they are deleted permanently.</p>
+    async fn delete_phone_number(
+        &self,
+        input: DeletePhoneNumberRequest,
+    ) -> Result<(), RusotoError<DeletePhoneNumberError>> {
+        self.delete_phone_number_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes a chat room.</p>
+    async fn delete_room(
+        &self,
+        input: DeleteRoomRequest,
+    ) -> Result<(), RusotoError<DeleteRoomError>> {
+        self.delete_room_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Removes a member from a chat room.</p>
+    async fn delete_room_membership(
+        &self,
+        input: DeleteRoomMembershipRequest,
+    ) -> Result<(), RusotoError<DeleteRoomMembershipError>> {
+        self.delete_room_membership_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes the specified Amazon Chime Voice Connector. Any phone numbers associated with the Amazon Chime Voice Connector must be disassociated from it before it can be deleted.</p>
+    async fn delete_voice_connector(
+        &self,
+        input: DeleteVoiceConnectorRequest,
+    ) -> Result<(), RusotoError<DeleteVoiceConnectorError>> {
+        self.delete_voice_connector_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes the specified Amazon Chime Voice Connector group. Any <code>VoiceConnectorItems</code> and phone numbers associated with the group must be removed before it can be deleted.</p>
+    async fn delete_voice_connector_group(
+        &self,
+        input: DeleteVoiceConnectorGroupRequest,
+    ) -> Result<(), RusotoError<DeleteVoiceConnectorGroupError>> {
+        self.delete_voice_connector_group_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes the origination settings for the specified Amazon Chime Voice Connector.</p>
+    async fn delete_voice_connector_origination(
+        &self,
+        input: DeleteVoiceConnectorOriginationRequest,
+    ) -> Result<(), RusotoError<DeleteVoiceConnectorOriginationError>> {
+        self.delete_voice_connector_origination_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes the streaming configuration for the specified Amazon Chime Voice Connector.</p>
+    async fn delete_voice_connector_streaming_configuration(
+        &self,
+        input: DeleteVoiceConnectorStreamingConfigurationRequest,
+    ) -> Result<(), RusotoError<DeleteVoiceConnectorStreamingConfigurationError>> {
+        self.delete_voice_connector_streaming_configuration_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes the termination settings for the specified Amazon Chime Voice Connector.</p>
+    async fn delete_voice_connector_termination(
+        &self,
+        input: DeleteVoiceConnectorTerminationRequest,
+    ) -> Result<(), RusotoError<DeleteVoiceConnectorTerminationError>> {
+        self.delete_voice_connector_termination_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes the specified SIP credentials used by your equipment to authenticate during call termination.</p>
+    async fn delete_voice_connector_termination_credentials(
+        &self,
+        input: DeleteVoiceConnectorTerminationCredentialsRequest,
+    ) -> Result<(), RusotoError<DeleteVoiceConnectorTerminationCredentialsError>> {
+        self.delete_voice_connector_termination_credentials_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Disassociates the primary provisioned phone number from the specified Amazon Chime user.</p>
+    async fn disassociate_phone_number_from_user(
+        &self,
+        input: DisassociatePhoneNumberFromUserRequest,
+    ) -> Result<
+        DisassociatePhoneNumberFromUserResponse,
+        RusotoError<DisassociatePhoneNumberFromUserError>,
+    > {
+        self.disassociate_phone_number_from_user_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Disassociates the specified phone numbers from the specified Amazon Chime Voice Connector.</p>
+    async fn disassociate_phone_numbers_from_voice_connector(
+        &self,
+        input: DisassociatePhoneNumbersFromVoiceConnectorRequest,
+    ) -> Result<
+        DisassociatePhoneNumbersFromVoiceConnectorResponse,
+        RusotoError<DisassociatePhoneNumbersFromVoiceConnectorError>,
+    > {
+        self.disassociate_phone_numbers_from_voice_connector_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Disassociates the specified phone numbers from the specified Amazon Chime Voice Connector group.</p>
+    async fn disassociate_phone_numbers_from_voice_connector_group(
+        &self,
+        input: DisassociatePhoneNumbersFromVoiceConnectorGroupRequest,
+    ) -> Result<
+        DisassociatePhoneNumbersFromVoiceConnectorGroupResponse,
+        RusotoError<DisassociatePhoneNumbersFromVoiceConnectorGroupError>,
+    > {
+        self.disassociate_phone_numbers_from_voice_connector_group_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Disassociates the specified sign-in delegate groups from the specified Amazon Chime account.</p>
+    async fn disassociate_signin_delegate_groups_from_account(
+        &self,
+        input: DisassociateSigninDelegateGroupsFromAccountRequest,
+    ) -> Result<
+        DisassociateSigninDelegateGroupsFromAccountResponse,
+        RusotoError<DisassociateSigninDelegateGroupsFromAccountError>,
+    > {
+        self.disassociate_signin_delegate_groups_from_account_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Retrieves details for the specified Amazon Chime account, such as account type and supported licenses.</p>
+    async fn get_account(
+        &self,
+        input: GetAccountRequest,
+    ) -> Result<GetAccountResponse, RusotoError<GetAccountError>> {
+        self.get_account_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Retrieves account settings for the specified Amazon Chime account ID, such as remote control and dial out settings. For more information about these settings, see <a href="https://docs.aws.amazon.com/chime/latest/ag/policies.html">Use the Policies Page</a> in the <i>Amazon Chime Administration Guide</i>.</p>
+    async fn get_account_settings(
+        &self,
+        input: GetAccountSettingsRequest,
+    ) -> Result<GetAccountSettingsResponse, RusotoError<GetAccountSettingsError>> {
+        self.get_account_settings_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Gets the Amazon Chime SDK attendee details for a specified meeting ID and attendee ID. For more information about the Amazon Chime SDK, see <a href="https://docs.aws.amazon.com/chime/latest/dg/meetings-sdk.html">Using the Amazon Chime SDK</a> in the <i>Amazon Chime Developer Guide</i>.</p>
+    async fn get_attendee(
+        &self,
+        input: GetAttendeeRequest,
+    ) -> Result<GetAttendeeResponse, RusotoError<GetAttendeeError>> {
+        self.get_attendee_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Retrieves details for the specified bot, such as bot email address, bot type, status, and display name.</p>
+    async fn get_bot(
+        &self,
+        input: GetBotRequest,
+    ) -> Result<GetBotResponse, RusotoError<GetBotError>> {
+        self.get_bot_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Gets details for an events configuration that allows a bot to receive outgoing events, such as an HTTPS endpoint or Lambda function ARN. </p>
+    async fn get_events_configuration(
+        &self,
+        input: GetEventsConfigurationRequest,
+    ) -> Result<GetEventsConfigurationResponse, RusotoError<GetEventsConfigurationError>> {
+        self.get_events_configuration_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Retrieves global settings for the administrator's AWS account, such as Amazon Chime Business Calling and Amazon Chime Voice Connector settings.</p>
+    async fn get_global_settings(
+        &self,
+    ) -> Result<GetGlobalSettingsResponse, RusotoError<GetGlobalSettingsError>> {
+        self.get_global_settings_with_metadata()
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Gets the Amazon Chime SDK meeting details for the specified meeting ID. For more information about the Amazon Chime SDK, see <a href="https://docs.aws.amazon.com/chime/latest/dg/meetings-sdk.html">Using the Amazon Chime SDK</a> in the <i>Amazon Chime Developer Guide</i>.</p>
+    async fn get_meeting(
+        &self,
+        input: GetMeetingRequest,
+    ) -> Result<GetMeetingResponse, RusotoError<GetMeetingError>> {
+        self.get_meeting_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Retrieves details for the specified phone number ID, such as associations, capabilities, and product type.</p>
+    async fn get_phone_number(
+        &self,
+        input: GetPhoneNumberRequest,
+    ) -> Result<GetPhoneNumberResponse, RusotoError<GetPhoneNumberError>> {
+        self.get_phone_number_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Retrieves details for the specified phone number order, such as order creation timestamp, phone numbers in E.164 format, product type, and order status.</p>
+    async fn get_phone_number_order(
+        &self,
+        input: GetPhoneNumberOrderRequest,
+    ) -> Result<GetPhoneNumberOrderResponse, RusotoError<GetPhoneNumberOrderError>> {
+        self.get_phone_number_order_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Retrieves the phone number settings for the administrator's AWS account, such as the default outbound calling name.</p>
+    async fn get_phone_number_settings(
+        &self,
+    ) -> Result<GetPhoneNumberSettingsResponse, RusotoError<GetPhoneNumberSettingsError>> {
+        self.get_phone_number_settings_with_metadata()
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Retrieves room details, such as the room name.</p>
+    async fn get_room(
+        &self,
+        input: GetRoomRequest,
+    ) -> Result<GetRoomResponse, RusotoError<GetRoomError>> {
+        self.get_room_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Retrieves details for the specified user ID, such as primary email address, license type, and personal meeting PIN.</p> <p>To retrieve user details with an email address instead of a user ID, use the <a>ListUsers</a> action, and then filter by email address.</p>
+    async fn get_user(
+        &self,
+        input: GetUserRequest,
+    ) -> Result<GetUserResponse, RusotoError<GetUserError>> {
+        self.get_user_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Retrieves settings for the specified user ID, such as any associated phone number settings.</p>
+    async fn get_user_settings(
+        &self,
+        input: GetUserSettingsRequest,
+    ) -> Result<GetUserSettingsResponse, RusotoError<GetUserSettingsError>> {
+        self.get_user_settings_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Retrieves details for the specified Amazon Chime Voice Connector, such as timestamps, name, outbound host, and encryption requirements.</p>
+    async fn get_voice_connector(
+        &self,
+        input: GetVoiceConnectorRequest,
+    ) -> Result<GetVoiceConnectorResponse, RusotoError<GetVoiceConnectorError>> {
+        self.get_voice_connector_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Retrieves details for the specified Amazon Chime Voice Connector group, such as timestamps, name, and associated <code>VoiceConnectorItems</code>.</p>
+    async fn get_voice_connector_group(
+        &self,
+        input: GetVoiceConnectorGroupRequest,
+    ) -> Result<GetVoiceConnectorGroupResponse, RusotoError<GetVoiceConnectorGroupError>> {
+        self.get_voice_connector_group_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Retrieves the logging configuration details for the specified Amazon Chime Voice Connector. Shows whether SIP message logs are enabled for sending to Amazon CloudWatch Logs.</p>
+    async fn get_voice_connector_logging_configuration(
+        &self,
+        input: GetVoiceConnectorLoggingConfigurationRequest,
+    ) -> Result<
+        GetVoiceConnectorLoggingConfigurationResponse,
+        RusotoError<GetVoiceConnectorLoggingConfigurationError>,
+    > {
+        self.get_voice_connector_logging_configuration_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Retrieves origination setting details for the specified Amazon Chime Voice Connector.</p>
+    async fn get_voice_connector_origination(
+        &self,
+        input: GetVoiceConnectorOriginationRequest,
+    ) -> Result<GetVoiceConnectorOriginationResponse, RusotoError<GetVoiceConnectorOriginationError>>
+    {
+        self.get_voice_connector_origination_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Retrieves the streaming configuration details for the specified Amazon Chime Voice Connector. Shows whether media streaming is enabled for sending to Amazon Kinesis. It also shows the retention period, in hours, for the Amazon Kinesis data.</p>
+    async fn get_voice_connector_streaming_configuration(
+        &self,
+        input: GetVoiceConnectorStreamingConfigurationRequest,
+    ) -> Result<
+        GetVoiceConnectorStreamingConfigurationResponse,
+        RusotoError<GetVoiceConnectorStreamingConfigurationError>,
+    > {
+        self.get_voice_connector_streaming_configuration_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Retrieves termination setting details for the specified Amazon Chime Voice Connector.</p>
+    async fn get_voice_connector_termination(
+        &self,
+        input: GetVoiceConnectorTerminationRequest,
+    ) -> Result<GetVoiceConnectorTerminationResponse, RusotoError<GetVoiceConnectorTerminationError>>
+    {
+        self.get_voice_connector_termination_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Retrieves information about the last time a SIP <code>OPTIONS</code> ping was received from your SIP infrastructure for the specified Amazon Chime Voice Connector.</p>
+    async fn get_voice_connector_termination_health(
+        &self,
+        input: GetVoiceConnectorTerminationHealthRequest,
+    ) -> Result<
+        GetVoiceConnectorTerminationHealthResponse,
+        RusotoError<GetVoiceConnectorTerminationHealthError>,
+    > {
+        self.get_voice_connector_termination_health_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Sends email to a maximum of 50 users, inviting them to the specified Amazon Chime <code>Team</code> account. Only <code>Team</code> account types are currently supported for this action. </p>
+    async fn invite_users(
+        &self,
+        input: InviteUsersRequest,
+    ) -> Result<InviteUsersResponse, RusotoError<InviteUsersError>> {
+        self.invite_users_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Lists the Amazon Chime accounts under the administrator's AWS account. You can filter accounts by account name prefix. To find out which Amazon Chime account a user belongs to, you can filter by the user's email address, which returns one account result.</p>
+    async fn list_accounts(
+        &self,
+        input: ListAccountsRequest,
+    ) -> Result<ListAccountsResponse, RusotoError<ListAccountsError>> {
+        self.list_accounts_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Lists the attendees for the specified Amazon Chime SDK meeting. For more information about the Amazon Chime SDK, see <a href="https://docs.aws.amazon.com/chime/latest/dg/meetings-sdk.html">Using the Amazon Chime SDK</a> in the <i>Amazon Chime Developer Guide</i>.</p>
+    async fn list_attendees(
+        &self,
+        input: ListAttendeesRequest,
+    ) -> Result<ListAttendeesResponse, RusotoError<ListAttendeesError>> {
+        self.list_attendees_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Lists the bots associated with the administrator's Amazon Chime Enterprise account ID.</p>
+    async fn list_bots(
+        &self,
+        input: ListBotsRequest,
+    ) -> Result<ListBotsResponse, RusotoError<ListBotsError>> {
+        self.list_bots_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Lists up to 100 active Amazon Chime SDK meetings. For more information about the Amazon Chime SDK, see <a href="https://docs.aws.amazon.com/chime/latest/dg/meetings-sdk.html">Using the Amazon Chime SDK</a> in the <i>Amazon Chime Developer Guide</i>.</p>
+    async fn list_meetings(
+        &self,
+        input: ListMeetingsRequest,
+    ) -> Result<ListMeetingsResponse, RusotoError<ListMeetingsError>> {
+        self.list_meetings_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Lists the phone number orders for the administrator's Amazon Chime account.</p>
+    async fn list_phone_number_orders(
+        &self,
+        input: ListPhoneNumberOrdersRequest,
+    ) -> Result<ListPhoneNumberOrdersResponse, RusotoError<ListPhoneNumberOrdersError>> {
+        self.list_phone_number_orders_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Lists the phone numbers for the specified Amazon Chime account, Amazon Chime user, Amazon Chime Voice Connector, or Amazon Chime Voice Connector group.</p>
+    async fn list_phone_numbers(
+        &self,
+        input: ListPhoneNumbersRequest,
+    ) -> Result<ListPhoneNumbersResponse, RusotoError<ListPhoneNumbersError>> {
+        self.list_phone_numbers_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Lists the membership details for the specified room, such as the members' IDs, email addresses, and names.</p>
+    async fn list_room_memberships(
+        &self,
+        input: ListRoomMembershipsRequest,
+    ) -> Result<ListRoomMembershipsResponse, RusotoError<ListRoomMembershipsError>> {
+        self.list_room_memberships_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Lists the room details for the specified Amazon Chime account. Optionally, filter the results by a member ID (user ID or bot ID) to see a list of rooms that the member belongs to.</p>
+    async fn list_rooms(
+        &self,
+        input: ListRoomsRequest,
+    ) -> Result<ListRoomsResponse, RusotoError<ListRoomsError>> {
+        self.list_rooms_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Lists the users that belong to the specified Amazon Chime account. You can specify an email address to list only the user that the email address belongs to.</p>
+    async fn list_users(
+        &self,
+        input: ListUsersRequest,
+    ) -> Result<ListUsersResponse, RusotoError<ListUsersError>> {
+        self.list_users_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Lists the Amazon Chime Voice Connector groups for the administrator's AWS account.</p>
+    async fn list_voice_connector_groups(
+        &self,
+        input: ListVoiceConnectorGroupsRequest,
+    ) -> Result<ListVoiceConnectorGroupsResponse, RusotoError<ListVoiceConnectorGroupsError>> {
+        self.list_voice_connector_groups_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Lists the SIP credentials for the specified Amazon Chime Voice Connector.</p>
+    async fn list_voice_connector_termination_credentials(
+        &self,
+        input: ListVoiceConnectorTerminationCredentialsRequest,
+    ) -> Result<
+        ListVoiceConnectorTerminationCredentialsResponse,
+        RusotoError<ListVoiceConnectorTerminationCredentialsError>,
+    > {
+        self.list_voice_connector_termination_credentials_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Lists the Amazon Chime Voice Connectors for the administrator's AWS account.</p>
+    async fn list_voice_connectors(
+        &self,
+        input: ListVoiceConnectorsRequest,
+    ) -> Result<ListVoiceConnectorsResponse, RusotoError<ListVoiceConnectorsError>> {
+        self.list_voice_connectors_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Logs out the specified user from all of the devices they are currently logged into.</p>
+    async fn logout_user(
+        &self,
+        input: LogoutUserRequest,
+    ) -> Result<LogoutUserResponse, RusotoError<LogoutUserError>> {
+        self.logout_user_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Creates an events configuration that allows a bot to receive outgoing events sent by Amazon Chime. Choose either an HTTPS endpoint or a Lambda function ARN. For more information, see <a>Bot</a>.</p>
+    async fn put_events_configuration(
+        &self,
+        input: PutEventsConfigurationRequest,
+    ) -> Result<PutEventsConfigurationResponse, RusotoError<PutEventsConfigurationError>> {
+        self.put_events_configuration_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Adds a logging configuration for the specified Amazon Chime Voice Connector. The logging configuration specifies whether SIP message logs are enabled for sending to Amazon CloudWatch Logs.</p>
+    async fn put_voice_connector_logging_configuration(
+        &self,
+        input: PutVoiceConnectorLoggingConfigurationRequest,
+    ) -> Result<
+        PutVoiceConnectorLoggingConfigurationResponse,
+        RusotoError<PutVoiceConnectorLoggingConfigurationError>,
+    > {
+        self.put_voice_connector_logging_configuration_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Adds origination settings for the specified Amazon Chime Voice Connector.</p>
+    async fn put_voice_connector_origination(
+        &self,
+        input: PutVoiceConnectorOriginationRequest,
+    ) -> Result<PutVoiceConnectorOriginationResponse, RusotoError<PutVoiceConnectorOriginationError>>
+    {
+        self.put_voice_connector_origination_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Adds a streaming configuration for the specified Amazon Chime Voice Connector. The streaming configuration specifies whether media streaming is enabled for sending to Amazon Kinesis. It also sets the retention period, in hours, for the Amazon Kinesis data.</p>
+    async fn put_voice_connector_streaming_configuration(
+        &self,
+        input: PutVoiceConnectorStreamingConfigurationRequest,
+    ) -> Result<
+        PutVoiceConnectorStreamingConfigurationResponse,
+        RusotoError<PutVoiceConnectorStreamingConfigurationError>,
+    > {
+        self.put_voice_connector_streaming_configuration_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Adds termination settings for the specified Amazon Chime Voice Connector.</p>
+    async fn put_voice_connector_termination(
+        &self,
+        input: PutVoiceConnectorTerminationRequest,
+    ) -> Result<PutVoiceConnectorTerminationResponse, RusotoError<PutVoiceConnectorTerminationError>>
+    {
+        self.put_voice_connector_termination_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Adds termination SIP credentials for the specified Amazon Chime Voice Connector.</p>
+    async fn put_voice_connector_termination_credentials(
+        &self,
+        input: PutVoiceConnectorTerminationCredentialsRequest,
+    ) -> Result<(), RusotoError<PutVoiceConnectorTerminationCredentialsError>> {
+        self.put_voice_connector_termination_credentials_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Regenerates the security token for a bot.</p>
+    async fn regenerate_security_token(
+        &self,
+        input: RegenerateSecurityTokenRequest,
+    ) -> Result<RegenerateSecurityTokenResponse, RusotoError<RegenerateSecurityTokenError>> {
+        self.regenerate_security_token_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Resets the personal meeting PIN for the specified user on an Amazon Chime account. Returns the <a>User</a> object with the updated personal meeting PIN.</p>
+    async fn reset_personal_pin(
+        &self,
+        input: ResetPersonalPINRequest,
+    ) -> Result<ResetPersonalPINResponse, RusotoError<ResetPersonalPINError>> {
+        self.reset_personal_pin_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Moves a phone number from the <b>Deletion queue</b> back into the phone number <b>Inventory</b>.</p>
+    async fn restore_phone_number(
+        &self,
+        input: RestorePhoneNumberRequest,
+    ) -> Result<RestorePhoneNumberResponse, RusotoError<RestorePhoneNumberError>> {
+        self.restore_phone_number_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Searches phone numbers that can be ordered.</p>
+    async fn search_available_phone_numbers(
+        &self,
+        input: SearchAvailablePhoneNumbersRequest,
+    ) -> Result<SearchAvailablePhoneNumbersResponse, RusotoError<SearchAvailablePhoneNumbersError>>
+    {
+        self.search_available_phone_numbers_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Updates account details for the specified Amazon Chime account. Currently, only account name updates are supported for this action.</p>
+    async fn update_account(
+        &self,
+        input: UpdateAccountRequest,
+    ) -> Result<UpdateAccountResponse, RusotoError<UpdateAccountError>> {
+        self.update_account_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Updates the settings for the specified Amazon Chime account. You can update settings for remote control of shared screens, or for the dial-out option. For more information about these settings, see <a href="https://docs.aws.amazon.com/chime/latest/ag/policies.html">Use the Policies Page</a> in the <i>Amazon Chime Administration Guide</i>.</p>
+    async fn update_account_settings(
+        &self,
+        input: UpdateAccountSettingsRequest,
+    ) -> Result<UpdateAccountSettingsResponse, RusotoError<UpdateAccountSettingsError>> {
+        self.update_account_settings_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Updates the status of the specified bot, such as starting or stopping the bot from running in your Amazon Chime Enterprise account.</p>
+    async fn update_bot(
+        &self,
+        input: UpdateBotRequest,
+    ) -> Result<UpdateBotResponse, RusotoError<UpdateBotError>> {
+        self.update_bot_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Updates global settings for the administrator's AWS account, such as Amazon Chime Business Calling and Amazon Chime Voice Connector settings.</p>
+    async fn update_global_settings(
+        &self,
+        input: UpdateGlobalSettingsRequest,
+    ) -> Result<(), RusotoError<UpdateGlobalSettingsError>> {
+        self.update_global_settings_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Updates phone number details, such as product type or calling name, for the specified phone number ID. You can update one phone number detail at a time. For example, you can update either the product type or the calling name in one action.</p> <p>For toll-free numbers, you must use the Amazon Chime Voice Connector product type.</p> <p>Updates to outbound calling names can take up to 72 hours to complete. Pending updates to outbound calling names must be complete before you can request another update.</p>
+    async fn update_phone_number(
+        &self,
+        input: UpdatePhoneNumberRequest,
+    ) -> Result<UpdatePhoneNumberResponse, RusotoError<UpdatePhoneNumberError>> {
+        self.update_phone_number_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Updates the phone number settings for the administrator's AWS account, such as the default outbound calling name. You can update the default outbound calling name once every seven days. Outbound calling names can take up to 72 hours to update.</p>
+    async fn update_phone_number_settings(
+        &self,
+        input: UpdatePhoneNumberSettingsRequest,
+    ) -> Result<(), RusotoError<UpdatePhoneNumberSettingsError>> {
+        self.update_phone_number_settings_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Updates room details, such as the room name.</p>
+    async fn update_room(
+        &self,
+        input: UpdateRoomRequest,
+    ) -> Result<UpdateRoomResponse, RusotoError<UpdateRoomError>> {
+        self.update_room_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Updates room membership details, such as the member role. The member role designates whether the member is a chat room administrator or a general chat room member. The member role can be updated only for user IDs.</p>
+    async fn update_room_membership(
+        &self,
+        input: UpdateRoomMembershipRequest,
+    ) -> Result<UpdateRoomMembershipResponse, RusotoError<UpdateRoomMembershipError>> {
+        self.update_room_membership_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Updates user details for a specified user ID. Currently, only <code>LicenseType</code> updates are supported for this action.</p>
+    async fn update_user(
+        &self,
+        input: UpdateUserRequest,
+    ) -> Result<UpdateUserResponse, RusotoError<UpdateUserError>> {
+        self.update_user_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Updates the settings for the specified user, such as phone number settings.</p>
+    async fn update_user_settings(
+        &self,
+        input: UpdateUserSettingsRequest,
+    ) -> Result<(), RusotoError<UpdateUserSettingsError>> {
+        self.update_user_settings_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Updates details for the specified Amazon Chime Voice Connector.</p>
+    async fn update_voice_connector(
+        &self,
+        input: UpdateVoiceConnectorRequest,
+    ) -> Result<UpdateVoiceConnectorResponse, RusotoError<UpdateVoiceConnectorError>> {
+        self.update_voice_connector_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Updates details for the specified Amazon Chime Voice Connector group, such as the name and Amazon Chime Voice Connector priority ranking.</p>
+    async fn update_voice_connector_group(
+        &self,
+        input: UpdateVoiceConnectorGroupRequest,
+    ) -> Result<UpdateVoiceConnectorGroupResponse, RusotoError<UpdateVoiceConnectorGroupError>>
+    {
+        self.update_voice_connector_group_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
     }
 }
@@ -16,8 +16,8 @@ use std::fmt;
 use async_trait::async_trait;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
-use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest, ResponseMetadata};
+use rusoto_core::{Client, ErrorMetadata, RusotoError};
 
 use rusoto_core::proto;
 use rusoto_core::signature::SignedRequest;
@@ -339,6 +339,7 @@ pub struct UpdateEnvironmentResult {}
 
 /// Errors returned by CreateEnvironmentEC2
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateEnvironmentEC2Error {
     /// <p>The target request is invalid.</p>
     BadRequest(String),
@@ -354,6 +355,8 @@ pub enum CreateEnvironmentEC2Error {
     NotFound(String),
     /// <p>Too many service requests were made over the given time period.</p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateEnvironmentEC2Error {
@@ -361,32 +364,43 @@ impl CreateEnvironmentEC2Error {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(CreateEnvironmentEC2Error::BadRequest(err.msg))
+                    return RusotoError::service(
+                        CreateEnvironmentEC2Error::BadRequest(err.msg),
+                        &res,
+                    )
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(CreateEnvironmentEC2Error::Conflict(err.msg))
+                    return RusotoError::service(CreateEnvironmentEC2Error::Conflict(err.msg), &res)
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(CreateEnvironmentEC2Error::Forbidden(err.msg))
+                    return RusotoError::service(
+                        CreateEnvironmentEC2Error::Forbidden(err.msg),
+                        &res,
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(CreateEnvironmentEC2Error::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateEnvironmentEC2Error::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateEnvironmentEC2Error::LimitExceeded(err.msg))
+                    return RusotoError::service(
+                        CreateEnvironmentEC2Error::LimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(CreateEnvironmentEC2Error::NotFound(err.msg))
+                    return RusotoError::service(CreateEnvironmentEC2Error::NotFound(err.msg), &res)
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(CreateEnvironmentEC2Error::TooManyRequests(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateEnvironmentEC2Error::TooManyRequests(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreateEnvironmentEC2Error::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -403,12 +417,14 @@ impl fmt::Display for CreateEnvironmentEC2Error {
             CreateEnvironmentEC2Error::LimitExceeded(ref cause) => write!(f, "{}", cause),
             CreateEnvironmentEC2Error::NotFound(ref cause) => write!(f, "{}", cause),
             CreateEnvironmentEC2Error::TooManyRequests(ref cause) => write!(f, "{}", cause),
+            CreateEnvironmentEC2Error::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateEnvironmentEC2Error {}
 /// Errors returned by CreateEnvironmentMembership
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateEnvironmentMembershipError {
     /// <p>The target request is invalid.</p>
     BadRequest(String),
@@ -424,6 +440,8 @@ pub enum CreateEnvironmentMembershipError {
     NotFound(String),
     /// <p>Too many service requests were made over the given time period.</p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateEnvironmentMembershipError {
@@ -433,42 +451,54 @@ impl CreateEnvironmentMembershipError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(CreateEnvironmentMembershipError::BadRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateEnvironmentMembershipError::BadRequest(err.msg),
+                        &res,
+                    )
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(CreateEnvironmentMembershipError::Conflict(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateEnvironmentMembershipError::Conflict(err.msg),
+                        &res,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(CreateEnvironmentMembershipError::Forbidden(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateEnvironmentMembershipError::Forbidden(err.msg),
+                        &res,
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         CreateEnvironmentMembershipError::InternalServerError(err.msg),
+                        &res,
                     )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateEnvironmentMembershipError::LimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateEnvironmentMembershipError::LimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(CreateEnvironmentMembershipError::NotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateEnvironmentMembershipError::NotFound(err.msg),
+                        &res,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(CreateEnvironmentMembershipError::TooManyRequests(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateEnvironmentMembershipError::TooManyRequests(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        CreateEnvironmentMembershipError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -487,12 +517,14 @@ impl fmt::Display for CreateEnvironmentMembershipError {
             CreateEnvironmentMembershipError::LimitExceeded(ref cause) => write!(f, "{}", cause),
             CreateEnvironmentMembershipError::NotFound(ref cause) => write!(f, "{}", cause),
             CreateEnvironmentMembershipError::TooManyRequests(ref cause) => write!(f, "{}", cause),
+            CreateEnvironmentMembershipError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateEnvironmentMembershipError {}
 /// Errors returned by DeleteEnvironment
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteEnvironmentError {
     /// <p>The target request is invalid.</p>
     BadRequest(String),
@@ -508,6 +540,8 @@ pub enum DeleteEnvironmentError {
     NotFound(String),
     /// <p>Too many service requests were made over the given time period.</p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteEnvironmentError {
@@ -515,30 +549,37 @@ impl DeleteEnvironmentError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(DeleteEnvironmentError::BadRequest(err.msg))
+                    return RusotoError::service(DeleteEnvironmentError::BadRequest(err.msg), &res)
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(DeleteEnvironmentError::Conflict(err.msg))
+                    return RusotoError::service(DeleteEnvironmentError::Conflict(err.msg), &res)
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(DeleteEnvironmentError::Forbidden(err.msg))
+                    return RusotoError::service(DeleteEnvironmentError::Forbidden(err.msg), &res)
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(DeleteEnvironmentError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteEnvironmentError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(DeleteEnvironmentError::LimitExceeded(err.msg))
+                    return RusotoError::service(
+                        DeleteEnvironmentError::LimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteEnvironmentError::NotFound(err.msg))
+                    return RusotoError::service(DeleteEnvironmentError::NotFound(err.msg), &res)
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(DeleteEnvironmentError::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        DeleteEnvironmentError::TooManyRequests(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteEnvironmentError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -555,12 +596,14 @@ impl fmt::Display for DeleteEnvironmentError {
             DeleteEnvironmentError::LimitExceeded(ref cause) => write!(f, "{}", cause),
             DeleteEnvironmentError::NotFound(ref cause) => write!(f, "{}", cause),
             DeleteEnvironmentError::TooManyRequests(ref cause) => write!(f, "{}", cause),
+            DeleteEnvironmentError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteEnvironmentError {}
 /// Errors returned by DeleteEnvironmentMembership
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteEnvironmentMembershipError {
     /// <p>The target request is invalid.</p>
     BadRequest(String),
@@ -576,6 +619,8 @@ pub enum DeleteEnvironmentMembershipError {
     NotFound(String),
     /// <p>Too many service requests were made over the given time period.</p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteEnvironmentMembershipError {
@@ -585,42 +630,54 @@ impl DeleteEnvironmentMembershipError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(DeleteEnvironmentMembershipError::BadRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteEnvironmentMembershipError::BadRequest(err.msg),
+                        &res,
+                    )
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(DeleteEnvironmentMembershipError::Conflict(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteEnvironmentMembershipError::Conflict(err.msg),
+                        &res,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(DeleteEnvironmentMembershipError::Forbidden(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteEnvironmentMembershipError::Forbidden(err.msg),
+                        &res,
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DeleteEnvironmentMembershipError::InternalServerError(err.msg),
+                        &res,
                     )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(DeleteEnvironmentMembershipError::LimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteEnvironmentMembershipError::LimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteEnvironmentMembershipError::NotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteEnvironmentMembershipError::NotFound(err.msg),
+                        &res,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(DeleteEnvironmentMembershipError::TooManyRequests(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteEnvironmentMembershipError::TooManyRequests(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        DeleteEnvironmentMembershipError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -639,12 +696,14 @@ impl fmt::Display for DeleteEnvironmentMembershipError {
             DeleteEnvironmentMembershipError::LimitExceeded(ref cause) => write!(f, "{}", cause),
             DeleteEnvironmentMembershipError::NotFound(ref cause) => write!(f, "{}", cause),
             DeleteEnvironmentMembershipError::TooManyRequests(ref cause) => write!(f, "{}", cause),
+            DeleteEnvironmentMembershipError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteEnvironmentMembershipError {}
 /// Errors returned by DescribeEnvironmentMemberships
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeEnvironmentMembershipsError {
     /// <p>The target request is invalid.</p>
     BadRequest(String),
@@ -660,6 +719,8 @@ pub enum DescribeEnvironmentMembershipsError {
     NotFound(String),
     /// <p>Too many service requests were made over the given time period.</p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeEnvironmentMembershipsError {
@@ -669,42 +730,54 @@ impl DescribeEnvironmentMembershipsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(DescribeEnvironmentMembershipsError::BadRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeEnvironmentMembershipsError::BadRequest(err.msg),
+                        &res,
+                    )
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(DescribeEnvironmentMembershipsError::Conflict(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeEnvironmentMembershipsError::Conflict(err.msg),
+                        &res,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(DescribeEnvironmentMembershipsError::Forbidden(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeEnvironmentMembershipsError::Forbidden(err.msg),
+                        &res,
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DescribeEnvironmentMembershipsError::InternalServerError(err.msg),
+                        &res,
                     )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DescribeEnvironmentMembershipsError::LimitExceeded(err.msg),
+                        &res,
                     )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DescribeEnvironmentMembershipsError::NotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeEnvironmentMembershipsError::NotFound(err.msg),
+                        &res,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DescribeEnvironmentMembershipsError::TooManyRequests(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        DescribeEnvironmentMembershipsError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -725,12 +798,16 @@ impl fmt::Display for DescribeEnvironmentMembershipsError {
             DescribeEnvironmentMembershipsError::TooManyRequests(ref cause) => {
                 write!(f, "{}", cause)
             }
+            DescribeEnvironmentMembershipsError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for DescribeEnvironmentMembershipsError {}
 /// Errors returned by DescribeEnvironmentStatus
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeEnvironmentStatusError {
     /// <p>The target request is invalid.</p>
     BadRequest(String),
@@ -746,6 +823,8 @@ pub enum DescribeEnvironmentStatusError {
     NotFound(String),
     /// <p>Too many service requests were made over the given time period.</p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeEnvironmentStatusError {
@@ -753,36 +832,51 @@ impl DescribeEnvironmentStatusError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(DescribeEnvironmentStatusError::BadRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeEnvironmentStatusError::BadRequest(err.msg),
+                        &res,
+                    )
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(DescribeEnvironmentStatusError::Conflict(err.msg))
+                    return RusotoError::service(
+                        DescribeEnvironmentStatusError::Conflict(err.msg),
+                        &res,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(DescribeEnvironmentStatusError::Forbidden(err.msg))
+                    return RusotoError::service(
+                        DescribeEnvironmentStatusError::Forbidden(err.msg),
+                        &res,
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DescribeEnvironmentStatusError::InternalServerError(err.msg),
+                        &res,
                     )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(DescribeEnvironmentStatusError::LimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeEnvironmentStatusError::LimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DescribeEnvironmentStatusError::NotFound(err.msg))
+                    return RusotoError::service(
+                        DescribeEnvironmentStatusError::NotFound(err.msg),
+                        &res,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(DescribeEnvironmentStatusError::TooManyRequests(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeEnvironmentStatusError::TooManyRequests(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(DescribeEnvironmentStatusError::Unhandled, &res)
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -801,12 +895,14 @@ impl fmt::Display for DescribeEnvironmentStatusError {
             DescribeEnvironmentStatusError::LimitExceeded(ref cause) => write!(f, "{}", cause),
             DescribeEnvironmentStatusError::NotFound(ref cause) => write!(f, "{}", cause),
             DescribeEnvironmentStatusError::TooManyRequests(ref cause) => write!(f, "{}", cause),
+            DescribeEnvironmentStatusError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeEnvironmentStatusError {}
 /// Errors returned by DescribeEnvironments
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeEnvironmentsError {
     /// <p>The target request is invalid.</p>
     BadRequest(String),
@@ -822,6 +918,8 @@ pub enum DescribeEnvironmentsError {
     NotFound(String),
     /// <p>Too many service requests were made over the given time period.</p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeEnvironmentsError {
@@ -829,32 +927,43 @@ impl DescribeEnvironmentsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(DescribeEnvironmentsError::BadRequest(err.msg))
+                    return RusotoError::service(
+                        DescribeEnvironmentsError::BadRequest(err.msg),
+                        &res,
+                    )
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(DescribeEnvironmentsError::Conflict(err.msg))
+                    return RusotoError::service(DescribeEnvironmentsError::Conflict(err.msg), &res)
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(DescribeEnvironmentsError::Forbidden(err.msg))
+                    return RusotoError::service(
+                        DescribeEnvironmentsError::Forbidden(err.msg),
+                        &res,
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(DescribeEnvironmentsError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeEnvironmentsError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(DescribeEnvironmentsError::LimitExceeded(err.msg))
+                    return RusotoError::service(
+                        DescribeEnvironmentsError::LimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DescribeEnvironmentsError::NotFound(err.msg))
+                    return RusotoError::service(DescribeEnvironmentsError::NotFound(err.msg), &res)
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(DescribeEnvironmentsError::TooManyRequests(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeEnvironmentsError::TooManyRequests(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DescribeEnvironmentsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -871,12 +980,14 @@ impl fmt::Display for DescribeEnvironmentsError {
             DescribeEnvironmentsError::LimitExceeded(ref cause) => write!(f, "{}", cause),
             DescribeEnvironmentsError::NotFound(ref cause) => write!(f, "{}", cause),
             DescribeEnvironmentsError::TooManyRequests(ref cause) => write!(f, "{}", cause),
+            DescribeEnvironmentsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeEnvironmentsError {}
 /// Errors returned by ListEnvironments
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListEnvironmentsError {
     /// <p>The target request is invalid.</p>
     BadRequest(String),
@@ -892,6 +1003,8 @@ pub enum ListEnvironmentsError {
     NotFound(String),
     /// <p>Too many service requests were made over the given time period.</p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListEnvironmentsError {
@@ -899,30 +1012,37 @@ impl ListEnvironmentsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(ListEnvironmentsError::BadRequest(err.msg))
+                    return RusotoError::service(ListEnvironmentsError::BadRequest(err.msg), &res)
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(ListEnvironmentsError::Conflict(err.msg))
+                    return RusotoError::service(ListEnvironmentsError::Conflict(err.msg), &res)
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(ListEnvironmentsError::Forbidden(err.msg))
+                    return RusotoError::service(ListEnvironmentsError::Forbidden(err.msg), &res)
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(ListEnvironmentsError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListEnvironmentsError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(ListEnvironmentsError::LimitExceeded(err.msg))
+                    return RusotoError::service(
+                        ListEnvironmentsError::LimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(ListEnvironmentsError::NotFound(err.msg))
+                    return RusotoError::service(ListEnvironmentsError::NotFound(err.msg), &res)
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(ListEnvironmentsError::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        ListEnvironmentsError::TooManyRequests(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListEnvironmentsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -939,12 +1059,14 @@ impl fmt::Display for ListEnvironmentsError {
             ListEnvironmentsError::LimitExceeded(ref cause) => write!(f, "{}", cause),
             ListEnvironmentsError::NotFound(ref cause) => write!(f, "{}", cause),
             ListEnvironmentsError::TooManyRequests(ref cause) => write!(f, "{}", cause),
+            ListEnvironmentsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListEnvironmentsError {}
 /// Errors returned by UpdateEnvironment
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdateEnvironmentError {
     /// <p>The target request is invalid.</p>
     BadRequest(String),
@@ -960,6 +1082,8 @@ pub enum UpdateEnvironmentError {
     NotFound(String),
     /// <p>Too many service requests were made over the given time period.</p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdateEnvironmentError {
@@ -967,30 +1091,37 @@ impl UpdateEnvironmentError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(UpdateEnvironmentError::BadRequest(err.msg))
+                    return RusotoError::service(UpdateEnvironmentError::BadRequest(err.msg), &res)
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(UpdateEnvironmentError::Conflict(err.msg))
+                    return RusotoError::service(UpdateEnvironmentError::Conflict(err.msg), &res)
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(UpdateEnvironmentError::Forbidden(err.msg))
+                    return RusotoError::service(UpdateEnvironmentError::Forbidden(err.msg), &res)
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(UpdateEnvironmentError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateEnvironmentError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(UpdateEnvironmentError::LimitExceeded(err.msg))
+                    return RusotoError::service(
+                        UpdateEnvironmentError::LimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(UpdateEnvironmentError::NotFound(err.msg))
+                    return RusotoError::service(UpdateEnvironmentError::NotFound(err.msg), &res)
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(UpdateEnvironmentError::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        UpdateEnvironmentError::TooManyRequests(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UpdateEnvironmentError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1007,12 +1138,14 @@ impl fmt::Display for UpdateEnvironmentError {
             UpdateEnvironmentError::LimitExceeded(ref cause) => write!(f, "{}", cause),
             UpdateEnvironmentError::NotFound(ref cause) => write!(f, "{}", cause),
             UpdateEnvironmentError::TooManyRequests(ref cause) => write!(f, "{}", cause),
+            UpdateEnvironmentError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for UpdateEnvironmentError {}
 /// Errors returned by UpdateEnvironmentMembership
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdateEnvironmentMembershipError {
     /// <p>The target request is invalid.</p>
     BadRequest(String),
@@ -1028,6 +1161,8 @@ pub enum UpdateEnvironmentMembershipError {
     NotFound(String),
     /// <p>Too many service requests were made over the given time period.</p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdateEnvironmentMembershipError {
@@ -1037,42 +1172,54 @@ impl UpdateEnvironmentMembershipError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(UpdateEnvironmentMembershipError::BadRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateEnvironmentMembershipError::BadRequest(err.msg),
+                        &res,
+                    )
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(UpdateEnvironmentMembershipError::Conflict(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateEnvironmentMembershipError::Conflict(err.msg),
+                        &res,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(UpdateEnvironmentMembershipError::Forbidden(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateEnvironmentMembershipError::Forbidden(err.msg),
+                        &res,
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         UpdateEnvironmentMembershipError::InternalServerError(err.msg),
+                        &res,
                     )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(UpdateEnvironmentMembershipError::LimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateEnvironmentMembershipError::LimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(UpdateEnvironmentMembershipError::NotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateEnvironmentMembershipError::NotFound(err.msg),
+                        &res,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(UpdateEnvironmentMembershipError::TooManyRequests(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateEnvironmentMembershipError::TooManyRequests(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        UpdateEnvironmentMembershipError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -1091,6 +1238,7 @@ impl fmt::Display for UpdateEnvironmentMembershipError {
             UpdateEnvironmentMembershipError::LimitExceeded(ref cause) => write!(f, "{}", cause),
             UpdateEnvironmentMembershipError::NotFound(ref cause) => write!(f, "{}", cause),
             UpdateEnvironmentMembershipError::TooManyRequests(ref cause) => write!(f, "{}", cause),
+            UpdateEnvironmentMembershipError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
@@ -1172,6 +1320,7 @@ impl Cloud9Client {
     /// Creates a client backed by the default tokio event loop.
     ///
     /// The client will use the default credentials provider and tls client.
+    #[cfg(not(target_arch = "wasm32"))]
     pub fn new(region: region::Region) -> Cloud9Client {
         Cloud9Client {
             client: Client::shared(),
@@ -1197,16 +1346,73 @@ impl Cloud9Client {
     pub fn new_with_client(client: Client, region: region::Region) -> Cloud9Client {
         Cloud9Client { client, region }
     }
+
+    /// Returns a builder of a client, setting any of its region, credentials provider,
+    /// dispatcher, retry policy, endpoint and timeouts.
+    pub fn builder() -> rusoto_core::ClientBuilder<Cloud9Client> {
+        rusoto_core::ClientBuilder::new(Cloud9Client::new_with_client)
+    }
+
+    /// Returns a clone of this client with the overrides of `config` applied to its
+    /// operations, sharing its connections.
+    pub fn customize(&self, config: &rusoto_core::OperationConfig) -> Cloud9Client {
+        Cloud9Client {
+            client: self.client.customize(config),
+            region: config.resolve_region(&self.region),
+        }
+    }
 }
 
-#[async_trait]
-impl Cloud9 for Cloud9Client {
-    /// <p>Creates an AWS Cloud9 development environment, launches an Amazon Elastic Compute Cloud (Amazon EC2) instance, and then connects from the instance to the environment.</p>
-    async fn create_environment_ec2(
+impl Cloud9Client {
+    /// Calls `create_environment_ec2`, also returning the metadata of the response.
+    pub async fn create_environment_ec2_with_metadata(
         &self,
         input: CreateEnvironmentEC2Request,
-    ) -> Result<CreateEnvironmentEC2Result, RusotoError<CreateEnvironmentEC2Error>> {
+    ) -> Result<
+        (CreateEnvironmentEC2Result, ResponseMetadata),
+        RusotoError<CreateEnvironmentEC2Error>,
+    > {
+        let request = self.build_create_environment_ec2_request(input);
+
+        let mut response = self
+            .client
+            .sign_and_dispatch(request)
+            .await
+            .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
+        if response.status.is_success() {
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<CreateEnvironmentEC2Result, _>()?;
+            Ok((result, metadata))
+        } else {
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(CreateEnvironmentEC2Error::from_response(response))
+        }
+    }
+
+    /// Presigns the request `create_environment_ec2` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_environment_ec2(
+        &self,
+        presigner: &rusoto_core::Presigner,
+        input: CreateEnvironmentEC2Request,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_environment_ec2_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_environment_ec2_request(
+        &self,
+        input: CreateEnvironmentEC2Request,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "cloud9", &self.region, "/");
+        request.set_operation("CreateEnvironmentEC2");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -1216,29 +1422,58 @@ impl Cloud9 for Cloud9Client {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `create_environment_membership`, also returning the metadata of the response.
+    pub async fn create_environment_membership_with_metadata(
+        &self,
+        input: CreateEnvironmentMembershipRequest,
+    ) -> Result<
+        (CreateEnvironmentMembershipResult, ResponseMetadata),
+        RusotoError<CreateEnvironmentMembershipError>,
+    > {
+        let request = self.build_create_environment_membership_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<CreateEnvironmentEC2Result, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<CreateEnvironmentMembershipResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(CreateEnvironmentEC2Error::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(CreateEnvironmentMembershipError::from_response(response))
         }
     }
 
-    /// <p>Adds an environment member to an AWS Cloud9 development environment.</p>
-    async fn create_environment_membership(
+    /// Presigns the request `create_environment_membership` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_environment_membership(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: CreateEnvironmentMembershipRequest,
-    ) -> Result<CreateEnvironmentMembershipResult, RusotoError<CreateEnvironmentMembershipError>>
-    {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_environment_membership_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_environment_membership_request(
+        &self,
+        input: CreateEnvironmentMembershipRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "cloud9", &self.region, "/");
+        request.set_operation("CreateEnvironmentMembership");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -1248,28 +1483,53 @@ impl Cloud9 for Cloud9Client {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `delete_environment`, also returning the metadata of the response.
+    pub async fn delete_environment_with_metadata(
+        &self,
+        input: DeleteEnvironmentRequest,
+    ) -> Result<(DeleteEnvironmentResult, ResponseMetadata), RusotoError<DeleteEnvironmentError>>
+    {
+        let request = self.build_delete_environment_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<CreateEnvironmentMembershipResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DeleteEnvironmentResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(CreateEnvironmentMembershipError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DeleteEnvironmentError::from_response(response))
         }
     }
 
-    /// <p>Deletes an AWS Cloud9 development environment. If an Amazon EC2 instance is connected to the environment, also terminates the instance.</p>
-    async fn delete_environment(
+    /// Presigns the request `delete_environment` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_environment(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteEnvironmentRequest,
-    ) -> Result<DeleteEnvironmentResult, RusotoError<DeleteEnvironmentError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_environment_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_environment_request(&self, input: DeleteEnvironmentRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "cloud9", &self.region, "/");
+        request.set_operation("DeleteEnvironment");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -1279,28 +1539,58 @@ impl Cloud9 for Cloud9Client {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `delete_environment_membership`, also returning the metadata of the response.
+    pub async fn delete_environment_membership_with_metadata(
+        &self,
+        input: DeleteEnvironmentMembershipRequest,
+    ) -> Result<
+        (DeleteEnvironmentMembershipResult, ResponseMetadata),
+        RusotoError<DeleteEnvironmentMembershipError>,
+    > {
+        let request = self.build_delete_environment_membership_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<DeleteEnvironmentResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DeleteEnvironmentMembershipResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteEnvironmentError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DeleteEnvironmentMembershipError::from_response(response))
         }
     }
 
-    /// <p>Deletes an environment member from an AWS Cloud9 development environment.</p>
-    async fn delete_environment_membership(
+    /// Presigns the request `delete_environment_membership` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_environment_membership(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteEnvironmentMembershipRequest,
-    ) -> Result<DeleteEnvironmentMembershipResult, RusotoError<DeleteEnvironmentMembershipError>>
-    {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_environment_membership_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_environment_membership_request(
+        &self,
+        input: DeleteEnvironmentMembershipRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "cloud9", &self.region, "/");
+        request.set_operation("DeleteEnvironmentMembership");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -1310,31 +1600,58 @@ impl Cloud9 for Cloud9Client {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `describe_environment_memberships`, also returning the metadata of the response.
+    pub async fn describe_environment_memberships_with_metadata(
+        &self,
+        input: DescribeEnvironmentMembershipsRequest,
+    ) -> Result<
+        (DescribeEnvironmentMembershipsResult, ResponseMetadata),
+        RusotoError<DescribeEnvironmentMembershipsError>,
+    > {
+        let request = self.build_describe_environment_memberships_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DeleteEnvironmentMembershipResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DescribeEnvironmentMembershipsResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteEnvironmentMembershipError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DescribeEnvironmentMembershipsError::from_response(response))
         }
     }
 
-    /// <p>Gets information about environment members for an AWS Cloud9 development environment.</p>
-    async fn describe_environment_memberships(
+    /// Presigns the request `describe_environment_memberships` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_environment_memberships(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeEnvironmentMembershipsRequest,
-    ) -> Result<
-        DescribeEnvironmentMembershipsResult,
-        RusotoError<DescribeEnvironmentMembershipsError>,
-    > {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_environment_memberships_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_environment_memberships_request(
+        &self,
+        input: DescribeEnvironmentMembershipsRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "cloud9", &self.region, "/");
+        request.set_operation("DescribeEnvironmentMemberships");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -1344,28 +1661,58 @@ impl Cloud9 for Cloud9Client {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `describe_environment_status`, also returning the metadata of the response.
+    pub async fn describe_environment_status_with_metadata(
+        &self,
+        input: DescribeEnvironmentStatusRequest,
+    ) -> Result<
+        (DescribeEnvironmentStatusResult, ResponseMetadata),
+        RusotoError<DescribeEnvironmentStatusError>,
+    > {
+        let request = self.build_describe_environment_status_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DescribeEnvironmentMembershipsResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DescribeEnvironmentStatusResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeEnvironmentMembershipsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DescribeEnvironmentStatusError::from_response(response))
         }
     }
 
-    /// <p>Gets status information for an AWS Cloud9 development environment.</p>
-    async fn describe_environment_status(
+    /// Presigns the request `describe_environment_status` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_environment_status(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeEnvironmentStatusRequest,
-    ) -> Result<DescribeEnvironmentStatusResult, RusotoError<DescribeEnvironmentStatusError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_environment_status_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_environment_status_request(
+        &self,
+        input: DescribeEnvironmentStatusRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "cloud9", &self.region, "/");
+        request.set_operation("DescribeEnvironmentStatus");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -1375,28 +1722,58 @@ impl Cloud9 for Cloud9Client {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `describe_environments`, also returning the metadata of the response.
+    pub async fn describe_environments_with_metadata(
+        &self,
+        input: DescribeEnvironmentsRequest,
+    ) -> Result<
+        (DescribeEnvironmentsResult, ResponseMetadata),
+        RusotoError<DescribeEnvironmentsError>,
+    > {
+        let request = self.build_describe_environments_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DescribeEnvironmentStatusResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DescribeEnvironmentsResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeEnvironmentStatusError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DescribeEnvironmentsError::from_response(response))
         }
     }
 
-    /// <p>Gets information about AWS Cloud9 development environments.</p>
-    async fn describe_environments(
+    /// Presigns the request `describe_environments` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_environments(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeEnvironmentsRequest,
-    ) -> Result<DescribeEnvironmentsResult, RusotoError<DescribeEnvironmentsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_environments_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_environments_request(
+        &self,
+        input: DescribeEnvironmentsRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "cloud9", &self.region, "/");
+        request.set_operation("DescribeEnvironments");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -1406,28 +1783,53 @@ impl Cloud9 for Cloud9Client {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `list_environments`, also returning the metadata of the response.
+    pub async fn list_environments_with_metadata(
+        &self,
+        input: ListEnvironmentsRequest,
+    ) -> Result<(ListEnvironmentsResult, ResponseMetadata), RusotoError<ListEnvironmentsError>>
+    {
+        let request = self.build_list_environments_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DescribeEnvironmentsResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ListEnvironmentsResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeEnvironmentsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ListEnvironmentsError::from_response(response))
         }
     }
 
-    /// <p>Gets a list of AWS Cloud9 development environment identifiers.</p>
-    async fn list_environments(
+    /// Presigns the request `list_environments` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_environments(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListEnvironmentsRequest,
-    ) -> Result<ListEnvironmentsResult, RusotoError<ListEnvironmentsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_environments_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_environments_request(&self, input: ListEnvironmentsRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "cloud9", &self.region, "/");
+        request.set_operation("ListEnvironments");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -1437,27 +1839,53 @@ impl Cloud9 for Cloud9Client {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `update_environment`, also returning the metadata of the response.
+    pub async fn update_environment_with_metadata(
+        &self,
+        input: UpdateEnvironmentRequest,
+    ) -> Result<(UpdateEnvironmentResult, ResponseMetadata), RusotoError<UpdateEnvironmentError>>
+    {
+        let request = self.build_update_environment_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<ListEnvironmentsResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<UpdateEnvironmentResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ListEnvironmentsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(UpdateEnvironmentError::from_response(response))
         }
     }
 
-    /// <p>Changes the settings of an existing AWS Cloud9 development environment.</p>
-    async fn update_environment(
+    /// Presigns the request `update_environment` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_environment(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UpdateEnvironmentRequest,
-    ) -> Result<UpdateEnvironmentResult, RusotoError<UpdateEnvironmentError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_environment_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_environment_request(&self, input: UpdateEnvironmentRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "cloud9", &self.region, "/");
+        request.set_operation("UpdateEnvironment");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -1467,28 +1895,58 @@ impl Cloud9 for Cloud9Client {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `update_environment_membership`, also returning the metadata of the response.
+    pub async fn update_environment_membership_with_metadata(
+        &self,
+        input: UpdateEnvironmentMembershipRequest,
+    ) -> Result<
+        (UpdateEnvironmentMembershipResult, ResponseMetadata),
+        RusotoError<UpdateEnvironmentMembershipError>,
+    > {
+        let request = self.build_update_environment_membership_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<UpdateEnvironmentResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<UpdateEnvironmentMembershipResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(UpdateEnvironmentError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(UpdateEnvironmentMembershipError::from_response(response))
         }
     }
 
-    /// <p>Changes the settings of an existing environment member for an AWS Cloud9 development environment.</p>
-    async fn update_environment_membership(
+    /// Presigns the request `update_environment_membership` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_environment_membership(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UpdateEnvironmentMembershipRequest,
-    ) -> Result<UpdateEnvironmentMembershipResult, RusotoError<UpdateEnvironmentMembershipError>>
-    {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_environment_membership_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_environment_membership_request(
+        &self,
+        input: UpdateEnvironmentMembershipRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "cloud9", &self.region, "/");
+        request.set_operation("UpdateEnvironmentMembership");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -1498,19 +1956,196 @@ impl Cloud9 for Cloud9Client {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
-        let mut response = self
-            .client
-            .sign_and_dispatch(request)
+        request
+    }
+
+    /// The pages of `DescribeEnvironmentMemberships` results for `input`, requesting each next page with the `nextToken` of the last one as its `nextToken`.
+    /// The page size is the `maxResults` of `input`.
+    pub fn describe_environment_memberships_pages(
+        &self,
+        input: DescribeEnvironmentMembershipsRequest,
+    ) -> impl futures::Stream<
+        Item = Result<
+            DescribeEnvironmentMembershipsResult,
+            RusotoError<DescribeEnvironmentMembershipsError>,
+        >,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: Cloud9Client, input| async move {
+                client.describe_environment_memberships(input).await
+            },
+            |input: &DescribeEnvironmentMembershipsRequest,
+             output: &DescribeEnvironmentMembershipsResult| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(DescribeEnvironmentMembershipsRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `memberships` of every page of `DescribeEnvironmentMemberships` results for `input`, as `describe_environment_memberships_pages` requests them.
+    pub fn describe_environment_memberships_stream(
+        &self,
+        input: DescribeEnvironmentMembershipsRequest,
+    ) -> impl futures::Stream<
+        Item = Result<EnvironmentMember, RusotoError<DescribeEnvironmentMembershipsError>>,
+    > + Send {
+        rusoto_core::pagination::items(self.describe_environment_memberships_pages(input), |page| {
+            page.memberships.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `ListEnvironments` results for `input`, requesting each next page with the `nextToken` of the last one as its `nextToken`.
+    /// The page size is the `maxResults` of `input`.
+    pub fn list_environments_pages(
+        &self,
+        input: ListEnvironmentsRequest,
+    ) -> impl futures::Stream<
+        Item = Result<ListEnvironmentsResult, RusotoError<ListEnvironmentsError>>,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: Cloud9Client, input| async move { client.list_environments(input).await },
+            |input: &ListEnvironmentsRequest, output: &ListEnvironmentsResult| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListEnvironmentsRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `environmentIds` of every page of `ListEnvironments` results for `input`, as `list_environments_pages` requests them.
+    pub fn list_environments_stream(
+        &self,
+        input: ListEnvironmentsRequest,
+    ) -> impl futures::Stream<Item = Result<String, RusotoError<ListEnvironmentsError>>> + Send
+    {
+        rusoto_core::pagination::items(self.list_environments_pages(input), |page| {
+            page.environment_ids.unwrap_or_default()
+        })
+    }
+}
+
+#[async_trait]
+impl Cloud9 for Cloud9Client {
+    /// <p>Creates an AWS Cloud9 development environment, launches an Amazon Elastic Compute Cloud (Amazon EC2) instance, and then connects from the instance to the environment.</p>
+    async fn create_environment_ec2(
+        &self,
+        input: CreateEnvironmentEC2Request,
+    ) -> Result<CreateEnvironmentEC2Result, RusotoError<CreateEnvironmentEC2Error>> {
+        self.create_environment_ec2_with_metadata(input)
             .await
-            .map_err(RusotoError::from)?;
-        if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<UpdateEnvironmentMembershipResult, _>()
-        } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(UpdateEnvironmentMembershipError::from_response(response))
-        }
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Adds an environment member to an AWS Cloud9 development environment.</p>
+    async fn create_environment_membership(
+        &self,
+        input: CreateEnvironmentMembershipRequest,
+    ) -> Result<CreateEnvironmentMembershipResult, RusotoError<CreateEnvironmentMembershipError>>
+    {
+        self.create_environment_membership_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes an AWS Cloud9 development environment. If an Amazon EC2 instance is connected to the environment, also terminates the instance.</p>
+    async fn delete_environment(
+        &self,
+        input: DeleteEnvironmentRequest,
+    ) -> Result<DeleteEnvironmentResult, RusotoError<DeleteEnvironmentError>> {
+        self.delete_environment_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes an environment member from an AWS Cloud9 development environment.</p>
+    async fn delete_environment_membership(
+        &self,
+        input: DeleteEnvironmentMembershipRequest,
+    ) -> Result<DeleteEnvironmentMembershipResult, RusotoError<DeleteEnvironmentMembershipError>>
+    {
+        self.delete_environment_membership_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Gets information about environment members for an AWS Cloud9 development environment.</p>
+    async fn describe_environment_memberships(
+        &self,
+        input: DescribeEnvironmentMembershipsRequest,
+    ) -> Result<
+        DescribeEnvironmentMembershipsResult,
+        RusotoError<DescribeEnvironmentMembershipsError>,
+    > {
+        self.describe_environment_memberships_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Gets status information for an AWS Cloud9 development environment.</p>
+    async fn describe_environment_status(
+        &self,
+        input: DescribeEnvironmentStatusRequest,
+    ) -> Result<DescribeEnvironmentStatusResult, RusotoError<DescribeEnvironmentStatusError>> {
+        self.describe_environment_status_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Gets information about AWS Cloud9 development environments.</p>
+    async fn describe_environments(
+        &self,
+        input: DescribeEnvironmentsRequest,
+    ) -> Result<DescribeEnvironmentsResult, RusotoError<DescribeEnvironmentsError>> {
+        self.describe_environments_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Gets a list of AWS Cloud9 development environment identifiers.</p>
+    async fn list_environments(
+        &self,
+        input: ListEnvironmentsRequest,
+    ) -> Result<ListEnvironmentsResult, RusotoError<ListEnvironmentsError>> {
+        self.list_environments_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Changes the settings of an existing AWS Cloud9 development environment.</p>
+    async fn update_environment(
+        &self,
+        input: UpdateEnvironmentRequest,
+    ) -> Result<UpdateEnvironmentResult, RusotoError<UpdateEnvironmentError>> {
+        self.update_environment_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Changes the settings of an existing environment member for an AWS Cloud9 development environment.</p>
+    async fn update_environment_membership(
+        &self,
+        input: UpdateEnvironmentMembershipRequest,
+    ) -> Result<UpdateEnvironmentMembershipResult, RusotoError<UpdateEnvironmentMembershipError>>
+    {
+        self.update_environment_membership_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
     }
 }
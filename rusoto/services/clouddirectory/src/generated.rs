@@ -16,8 +16,8 @@ use std::fmt;
 use async_trait::async_trait;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
-use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest, ResponseMetadata};
+use rusoto_core::{Client, ErrorMetadata, RusotoError};
 
 use rusoto_core::proto;
 use rusoto_core::signature::SignedRequest;
@@ -3217,6 +3217,7 @@ pub struct UpgradePublishedSchemaResponse {
 
 /// Errors returned by AddFacetToObject
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum AddFacetToObjectError {
     /// <p>Access denied. Check your permissions.</p>
     AccessDenied(String),
@@ -3234,6 +3235,8 @@ pub enum AddFacetToObjectError {
     ResourceNotFound(String),
     /// <p>Occurs when a conflict with a previous successful write is detected. For example, if a write operation occurs on an object and then an attempt is made to read the object using “SERIALIZABLE” consistency, this exception may result. This generally occurs when the previous write did not have time to propagate to the host serving the current request. A retry (with appropriate backoff logic) is the recommended response to this exception.</p>
     RetryableConflict(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl AddFacetToObjectError {
@@ -3241,33 +3244,49 @@ impl AddFacetToObjectError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(AddFacetToObjectError::AccessDenied(err.msg))
+                    return RusotoError::service(AddFacetToObjectError::AccessDenied(err.msg), &res)
                 }
                 "DirectoryNotEnabledException" => {
-                    return RusotoError::Service(AddFacetToObjectError::DirectoryNotEnabled(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        AddFacetToObjectError::DirectoryNotEnabled(err.msg),
+                        &res,
+                    )
                 }
                 "FacetValidationException" => {
-                    return RusotoError::Service(AddFacetToObjectError::FacetValidation(err.msg))
+                    return RusotoError::service(
+                        AddFacetToObjectError::FacetValidation(err.msg),
+                        &res,
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(AddFacetToObjectError::InternalService(err.msg))
+                    return RusotoError::service(
+                        AddFacetToObjectError::InternalService(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidArnException" => {
-                    return RusotoError::Service(AddFacetToObjectError::InvalidArn(err.msg))
+                    return RusotoError::service(AddFacetToObjectError::InvalidArn(err.msg), &res)
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(AddFacetToObjectError::LimitExceeded(err.msg))
+                    return RusotoError::service(
+                        AddFacetToObjectError::LimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(AddFacetToObjectError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        AddFacetToObjectError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "RetryableConflictException" => {
-                    return RusotoError::Service(AddFacetToObjectError::RetryableConflict(err.msg))
+                    return RusotoError::service(
+                        AddFacetToObjectError::RetryableConflict(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(AddFacetToObjectError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3285,12 +3304,14 @@ impl fmt::Display for AddFacetToObjectError {
             AddFacetToObjectError::LimitExceeded(ref cause) => write!(f, "{}", cause),
             AddFacetToObjectError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             AddFacetToObjectError::RetryableConflict(ref cause) => write!(f, "{}", cause),
+            AddFacetToObjectError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for AddFacetToObjectError {}
 /// Errors returned by ApplySchema
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ApplySchemaError {
     /// <p>Access denied. Check your permissions.</p>
     AccessDenied(String),
@@ -3308,6 +3329,8 @@ pub enum ApplySchemaError {
     RetryableConflict(String),
     /// <p>Indicates that a schema could not be created due to a naming conflict. Please select a different name and then try again.</p>
     SchemaAlreadyExists(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ApplySchemaError {
@@ -3315,31 +3338,34 @@ impl ApplySchemaError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(ApplySchemaError::AccessDenied(err.msg))
+                    return RusotoError::service(ApplySchemaError::AccessDenied(err.msg), &res)
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(ApplySchemaError::InternalService(err.msg))
+                    return RusotoError::service(ApplySchemaError::InternalService(err.msg), &res)
                 }
                 "InvalidArnException" => {
-                    return RusotoError::Service(ApplySchemaError::InvalidArn(err.msg))
+                    return RusotoError::service(ApplySchemaError::InvalidArn(err.msg), &res)
                 }
                 "InvalidAttachmentException" => {
-                    return RusotoError::Service(ApplySchemaError::InvalidAttachment(err.msg))
+                    return RusotoError::service(ApplySchemaError::InvalidAttachment(err.msg), &res)
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(ApplySchemaError::LimitExceeded(err.msg))
+                    return RusotoError::service(ApplySchemaError::LimitExceeded(err.msg), &res)
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ApplySchemaError::ResourceNotFound(err.msg))
+                    return RusotoError::service(ApplySchemaError::ResourceNotFound(err.msg), &res)
                 }
                 "RetryableConflictException" => {
-                    return RusotoError::Service(ApplySchemaError::RetryableConflict(err.msg))
+                    return RusotoError::service(ApplySchemaError::RetryableConflict(err.msg), &res)
                 }
                 "SchemaAlreadyExistsException" => {
-                    return RusotoError::Service(ApplySchemaError::SchemaAlreadyExists(err.msg))
+                    return RusotoError::service(
+                        ApplySchemaError::SchemaAlreadyExists(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ApplySchemaError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3357,12 +3383,14 @@ impl fmt::Display for ApplySchemaError {
             ApplySchemaError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             ApplySchemaError::RetryableConflict(ref cause) => write!(f, "{}", cause),
             ApplySchemaError::SchemaAlreadyExists(ref cause) => write!(f, "{}", cause),
+            ApplySchemaError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ApplySchemaError {}
 /// Errors returned by AttachObject
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum AttachObjectError {
     /// <p>Access denied. Check your permissions.</p>
     AccessDenied(String),
@@ -3384,6 +3412,8 @@ pub enum AttachObjectError {
     ResourceNotFound(String),
     /// <p>Occurs when a conflict with a previous successful write is detected. For example, if a write operation occurs on an object and then an attempt is made to read the object using “SERIALIZABLE” consistency, this exception may result. This generally occurs when the previous write did not have time to propagate to the host serving the current request. A retry (with appropriate backoff logic) is the recommended response to this exception.</p>
     RetryableConflict(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl AttachObjectError {
@@ -3391,37 +3421,49 @@ impl AttachObjectError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(AttachObjectError::AccessDenied(err.msg))
+                    return RusotoError::service(AttachObjectError::AccessDenied(err.msg), &res)
                 }
                 "DirectoryNotEnabledException" => {
-                    return RusotoError::Service(AttachObjectError::DirectoryNotEnabled(err.msg))
+                    return RusotoError::service(
+                        AttachObjectError::DirectoryNotEnabled(err.msg),
+                        &res,
+                    )
                 }
                 "FacetValidationException" => {
-                    return RusotoError::Service(AttachObjectError::FacetValidation(err.msg))
+                    return RusotoError::service(AttachObjectError::FacetValidation(err.msg), &res)
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(AttachObjectError::InternalService(err.msg))
+                    return RusotoError::service(AttachObjectError::InternalService(err.msg), &res)
                 }
                 "InvalidArnException" => {
-                    return RusotoError::Service(AttachObjectError::InvalidArn(err.msg))
+                    return RusotoError::service(AttachObjectError::InvalidArn(err.msg), &res)
                 }
                 "InvalidAttachmentException" => {
-                    return RusotoError::Service(AttachObjectError::InvalidAttachment(err.msg))
+                    return RusotoError::service(
+                        AttachObjectError::InvalidAttachment(err.msg),
+                        &res,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(AttachObjectError::LimitExceeded(err.msg))
+                    return RusotoError::service(AttachObjectError::LimitExceeded(err.msg), &res)
                 }
                 "LinkNameAlreadyInUseException" => {
-                    return RusotoError::Service(AttachObjectError::LinkNameAlreadyInUse(err.msg))
+                    return RusotoError::service(
+                        AttachObjectError::LinkNameAlreadyInUse(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(AttachObjectError::ResourceNotFound(err.msg))
+                    return RusotoError::service(AttachObjectError::ResourceNotFound(err.msg), &res)
                 }
                 "RetryableConflictException" => {
-                    return RusotoError::Service(AttachObjectError::RetryableConflict(err.msg))
+                    return RusotoError::service(
+                        AttachObjectError::RetryableConflict(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(AttachObjectError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3441,12 +3483,14 @@ impl fmt::Display for AttachObjectError {
             AttachObjectError::LinkNameAlreadyInUse(ref cause) => write!(f, "{}", cause),
             AttachObjectError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             AttachObjectError::RetryableConflict(ref cause) => write!(f, "{}", cause),
+            AttachObjectError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for AttachObjectError {}
 /// Errors returned by AttachPolicy
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum AttachPolicyError {
     /// <p>Access denied. Check your permissions.</p>
     AccessDenied(String),
@@ -3464,6 +3508,8 @@ pub enum AttachPolicyError {
     ResourceNotFound(String),
     /// <p>Occurs when a conflict with a previous successful write is detected. For example, if a write operation occurs on an object and then an attempt is made to read the object using “SERIALIZABLE” consistency, this exception may result. This generally occurs when the previous write did not have time to propagate to the host serving the current request. A retry (with appropriate backoff logic) is the recommended response to this exception.</p>
     RetryableConflict(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl AttachPolicyError {
@@ -3471,31 +3517,37 @@ impl AttachPolicyError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(AttachPolicyError::AccessDenied(err.msg))
+                    return RusotoError::service(AttachPolicyError::AccessDenied(err.msg), &res)
                 }
                 "DirectoryNotEnabledException" => {
-                    return RusotoError::Service(AttachPolicyError::DirectoryNotEnabled(err.msg))
+                    return RusotoError::service(
+                        AttachPolicyError::DirectoryNotEnabled(err.msg),
+                        &res,
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(AttachPolicyError::InternalService(err.msg))
+                    return RusotoError::service(AttachPolicyError::InternalService(err.msg), &res)
                 }
                 "InvalidArnException" => {
-                    return RusotoError::Service(AttachPolicyError::InvalidArn(err.msg))
+                    return RusotoError::service(AttachPolicyError::InvalidArn(err.msg), &res)
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(AttachPolicyError::LimitExceeded(err.msg))
+                    return RusotoError::service(AttachPolicyError::LimitExceeded(err.msg), &res)
                 }
                 "NotPolicyException" => {
-                    return RusotoError::Service(AttachPolicyError::NotPolicy(err.msg))
+                    return RusotoError::service(AttachPolicyError::NotPolicy(err.msg), &res)
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(AttachPolicyError::ResourceNotFound(err.msg))
+                    return RusotoError::service(AttachPolicyError::ResourceNotFound(err.msg), &res)
                 }
                 "RetryableConflictException" => {
-                    return RusotoError::Service(AttachPolicyError::RetryableConflict(err.msg))
+                    return RusotoError::service(
+                        AttachPolicyError::RetryableConflict(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(AttachPolicyError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3513,12 +3565,14 @@ impl fmt::Display for AttachPolicyError {
             AttachPolicyError::NotPolicy(ref cause) => write!(f, "{}", cause),
             AttachPolicyError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             AttachPolicyError::RetryableConflict(ref cause) => write!(f, "{}", cause),
+            AttachPolicyError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for AttachPolicyError {}
 /// Errors returned by AttachToIndex
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum AttachToIndexError {
     /// <p>Access denied. Check your permissions.</p>
     AccessDenied(String),
@@ -3542,6 +3596,8 @@ pub enum AttachToIndexError {
     ResourceNotFound(String),
     /// <p>Occurs when a conflict with a previous successful write is detected. For example, if a write operation occurs on an object and then an attempt is made to read the object using “SERIALIZABLE” consistency, this exception may result. This generally occurs when the previous write did not have time to propagate to the host serving the current request. A retry (with appropriate backoff logic) is the recommended response to this exception.</p>
     RetryableConflict(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl AttachToIndexError {
@@ -3549,42 +3605,58 @@ impl AttachToIndexError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(AttachToIndexError::AccessDenied(err.msg))
+                    return RusotoError::service(AttachToIndexError::AccessDenied(err.msg), &res)
                 }
                 "DirectoryNotEnabledException" => {
-                    return RusotoError::Service(AttachToIndexError::DirectoryNotEnabled(err.msg))
+                    return RusotoError::service(
+                        AttachToIndexError::DirectoryNotEnabled(err.msg),
+                        &res,
+                    )
                 }
                 "IndexedAttributeMissingException" => {
-                    return RusotoError::Service(AttachToIndexError::IndexedAttributeMissing(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        AttachToIndexError::IndexedAttributeMissing(err.msg),
+                        &res,
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(AttachToIndexError::InternalService(err.msg))
+                    return RusotoError::service(AttachToIndexError::InternalService(err.msg), &res)
                 }
                 "InvalidArnException" => {
-                    return RusotoError::Service(AttachToIndexError::InvalidArn(err.msg))
+                    return RusotoError::service(AttachToIndexError::InvalidArn(err.msg), &res)
                 }
                 "InvalidAttachmentException" => {
-                    return RusotoError::Service(AttachToIndexError::InvalidAttachment(err.msg))
+                    return RusotoError::service(
+                        AttachToIndexError::InvalidAttachment(err.msg),
+                        &res,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(AttachToIndexError::LimitExceeded(err.msg))
+                    return RusotoError::service(AttachToIndexError::LimitExceeded(err.msg), &res)
                 }
                 "LinkNameAlreadyInUseException" => {
-                    return RusotoError::Service(AttachToIndexError::LinkNameAlreadyInUse(err.msg))
+                    return RusotoError::service(
+                        AttachToIndexError::LinkNameAlreadyInUse(err.msg),
+                        &res,
+                    )
                 }
                 "NotIndexException" => {
-                    return RusotoError::Service(AttachToIndexError::NotIndex(err.msg))
+                    return RusotoError::service(AttachToIndexError::NotIndex(err.msg), &res)
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(AttachToIndexError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        AttachToIndexError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "RetryableConflictException" => {
-                    return RusotoError::Service(AttachToIndexError::RetryableConflict(err.msg))
+                    return RusotoError::service(
+                        AttachToIndexError::RetryableConflict(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(AttachToIndexError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3605,12 +3677,14 @@ impl fmt::Display for AttachToIndexError {
             AttachToIndexError::NotIndex(ref cause) => write!(f, "{}", cause),
             AttachToIndexError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             AttachToIndexError::RetryableConflict(ref cause) => write!(f, "{}", cause),
+            AttachToIndexError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for AttachToIndexError {}
 /// Errors returned by AttachTypedLink
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum AttachTypedLinkError {
     /// <p>Access denied. Check your permissions.</p>
     AccessDenied(String),
@@ -3630,6 +3704,8 @@ pub enum AttachTypedLinkError {
     ResourceNotFound(String),
     /// <p>Occurs when a conflict with a previous successful write is detected. For example, if a write operation occurs on an object and then an attempt is made to read the object using “SERIALIZABLE” consistency, this exception may result. This generally occurs when the previous write did not have time to propagate to the host serving the current request. A retry (with appropriate backoff logic) is the recommended response to this exception.</p>
     RetryableConflict(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl AttachTypedLinkError {
@@ -3637,34 +3713,52 @@ impl AttachTypedLinkError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(AttachTypedLinkError::AccessDenied(err.msg))
+                    return RusotoError::service(AttachTypedLinkError::AccessDenied(err.msg), &res)
                 }
                 "DirectoryNotEnabledException" => {
-                    return RusotoError::Service(AttachTypedLinkError::DirectoryNotEnabled(err.msg))
+                    return RusotoError::service(
+                        AttachTypedLinkError::DirectoryNotEnabled(err.msg),
+                        &res,
+                    )
                 }
                 "FacetValidationException" => {
-                    return RusotoError::Service(AttachTypedLinkError::FacetValidation(err.msg))
+                    return RusotoError::service(
+                        AttachTypedLinkError::FacetValidation(err.msg),
+                        &res,
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(AttachTypedLinkError::InternalService(err.msg))
+                    return RusotoError::service(
+                        AttachTypedLinkError::InternalService(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidArnException" => {
-                    return RusotoError::Service(AttachTypedLinkError::InvalidArn(err.msg))
+                    return RusotoError::service(AttachTypedLinkError::InvalidArn(err.msg), &res)
                 }
                 "InvalidAttachmentException" => {
-                    return RusotoError::Service(AttachTypedLinkError::InvalidAttachment(err.msg))
+                    return RusotoError::service(
+                        AttachTypedLinkError::InvalidAttachment(err.msg),
+                        &res,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(AttachTypedLinkError::LimitExceeded(err.msg))
+                    return RusotoError::service(AttachTypedLinkError::LimitExceeded(err.msg), &res)
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(AttachTypedLinkError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        AttachTypedLinkError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "RetryableConflictException" => {
-                    return RusotoError::Service(AttachTypedLinkError::RetryableConflict(err.msg))
+                    return RusotoError::service(
+                        AttachTypedLinkError::RetryableConflict(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(AttachTypedLinkError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3683,12 +3777,14 @@ impl fmt::Display for AttachTypedLinkError {
             AttachTypedLinkError::LimitExceeded(ref cause) => write!(f, "{}", cause),
             AttachTypedLinkError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             AttachTypedLinkError::RetryableConflict(ref cause) => write!(f, "{}", cause),
+            AttachTypedLinkError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for AttachTypedLinkError {}
 /// Errors returned by BatchRead
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum BatchReadError {
     /// <p>Access denied. Check your permissions.</p>
     AccessDenied(String),
@@ -3702,6 +3798,8 @@ pub enum BatchReadError {
     LimitExceeded(String),
     /// <p>Occurs when a conflict with a previous successful write is detected. For example, if a write operation occurs on an object and then an attempt is made to read the object using “SERIALIZABLE” consistency, this exception may result. This generally occurs when the previous write did not have time to propagate to the host serving the current request. A retry (with appropriate backoff logic) is the recommended response to this exception.</p>
     RetryableConflict(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl BatchReadError {
@@ -3709,25 +3807,25 @@ impl BatchReadError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(BatchReadError::AccessDenied(err.msg))
+                    return RusotoError::service(BatchReadError::AccessDenied(err.msg), &res)
                 }
                 "DirectoryNotEnabledException" => {
-                    return RusotoError::Service(BatchReadError::DirectoryNotEnabled(err.msg))
+                    return RusotoError::service(BatchReadError::DirectoryNotEnabled(err.msg), &res)
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(BatchReadError::InternalService(err.msg))
+                    return RusotoError::service(BatchReadError::InternalService(err.msg), &res)
                 }
                 "InvalidArnException" => {
-                    return RusotoError::Service(BatchReadError::InvalidArn(err.msg))
+                    return RusotoError::service(BatchReadError::InvalidArn(err.msg), &res)
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(BatchReadError::LimitExceeded(err.msg))
+                    return RusotoError::service(BatchReadError::LimitExceeded(err.msg), &res)
                 }
                 "RetryableConflictException" => {
-                    return RusotoError::Service(BatchReadError::RetryableConflict(err.msg))
+                    return RusotoError::service(BatchReadError::RetryableConflict(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(BatchReadError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3743,12 +3841,14 @@ impl fmt::Display for BatchReadError {
             BatchReadError::InvalidArn(ref cause) => write!(f, "{}", cause),
             BatchReadError::LimitExceeded(ref cause) => write!(f, "{}", cause),
             BatchReadError::RetryableConflict(ref cause) => write!(f, "{}", cause),
+            BatchReadError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for BatchReadError {}
 /// Errors returned by BatchWrite
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum BatchWriteError {
     /// <p>Access denied. Check your permissions.</p>
     AccessDenied(String),
@@ -3764,6 +3864,8 @@ pub enum BatchWriteError {
     LimitExceeded(String),
     /// <p>Occurs when a conflict with a previous successful write is detected. For example, if a write operation occurs on an object and then an attempt is made to read the object using “SERIALIZABLE” consistency, this exception may result. This generally occurs when the previous write did not have time to propagate to the host serving the current request. A retry (with appropriate backoff logic) is the recommended response to this exception.</p>
     RetryableConflict(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl BatchWriteError {
@@ -3771,28 +3873,31 @@ impl BatchWriteError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(BatchWriteError::AccessDenied(err.msg))
+                    return RusotoError::service(BatchWriteError::AccessDenied(err.msg), &res)
                 }
                 "BatchWriteException" => {
-                    return RusotoError::Service(BatchWriteError::BatchWrite(err.msg))
+                    return RusotoError::service(BatchWriteError::BatchWrite(err.msg), &res)
                 }
                 "DirectoryNotEnabledException" => {
-                    return RusotoError::Service(BatchWriteError::DirectoryNotEnabled(err.msg))
+                    return RusotoError::service(
+                        BatchWriteError::DirectoryNotEnabled(err.msg),
+                        &res,
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(BatchWriteError::InternalService(err.msg))
+                    return RusotoError::service(BatchWriteError::InternalService(err.msg), &res)
                 }
                 "InvalidArnException" => {
-                    return RusotoError::Service(BatchWriteError::InvalidArn(err.msg))
+                    return RusotoError::service(BatchWriteError::InvalidArn(err.msg), &res)
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(BatchWriteError::LimitExceeded(err.msg))
+                    return RusotoError::service(BatchWriteError::LimitExceeded(err.msg), &res)
                 }
                 "RetryableConflictException" => {
-                    return RusotoError::Service(BatchWriteError::RetryableConflict(err.msg))
+                    return RusotoError::service(BatchWriteError::RetryableConflict(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(BatchWriteError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3809,12 +3914,14 @@ impl fmt::Display for BatchWriteError {
             BatchWriteError::InvalidArn(ref cause) => write!(f, "{}", cause),
             BatchWriteError::LimitExceeded(ref cause) => write!(f, "{}", cause),
             BatchWriteError::RetryableConflict(ref cause) => write!(f, "{}", cause),
+            BatchWriteError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for BatchWriteError {}
 /// Errors returned by CreateDirectory
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateDirectoryError {
     /// <p>Access denied. Check your permissions.</p>
     AccessDenied(String),
@@ -3830,6 +3937,8 @@ pub enum CreateDirectoryError {
     ResourceNotFound(String),
     /// <p>Occurs when a conflict with a previous successful write is detected. For example, if a write operation occurs on an object and then an attempt is made to read the object using “SERIALIZABLE” consistency, this exception may result. This generally occurs when the previous write did not have time to propagate to the host serving the current request. A retry (with appropriate backoff logic) is the recommended response to this exception.</p>
     RetryableConflict(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateDirectoryError {
@@ -3837,30 +3946,40 @@ impl CreateDirectoryError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(CreateDirectoryError::AccessDenied(err.msg))
+                    return RusotoError::service(CreateDirectoryError::AccessDenied(err.msg), &res)
                 }
                 "DirectoryAlreadyExistsException" => {
-                    return RusotoError::Service(CreateDirectoryError::DirectoryAlreadyExists(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateDirectoryError::DirectoryAlreadyExists(err.msg),
+                        &res,
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(CreateDirectoryError::InternalService(err.msg))
+                    return RusotoError::service(
+                        CreateDirectoryError::InternalService(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidArnException" => {
-                    return RusotoError::Service(CreateDirectoryError::InvalidArn(err.msg))
+                    return RusotoError::service(CreateDirectoryError::InvalidArn(err.msg), &res)
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateDirectoryError::LimitExceeded(err.msg))
+                    return RusotoError::service(CreateDirectoryError::LimitExceeded(err.msg), &res)
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(CreateDirectoryError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        CreateDirectoryError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "RetryableConflictException" => {
-                    return RusotoError::Service(CreateDirectoryError::RetryableConflict(err.msg))
+                    return RusotoError::service(
+                        CreateDirectoryError::RetryableConflict(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreateDirectoryError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3877,12 +3996,14 @@ impl fmt::Display for CreateDirectoryError {
             CreateDirectoryError::LimitExceeded(ref cause) => write!(f, "{}", cause),
             CreateDirectoryError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             CreateDirectoryError::RetryableConflict(ref cause) => write!(f, "{}", cause),
+            CreateDirectoryError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateDirectoryError {}
 /// Errors returned by CreateFacet
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateFacetError {
     /// <p>Access denied. Check your permissions.</p>
     AccessDenied(String),
@@ -3902,6 +4023,8 @@ pub enum CreateFacetError {
     ResourceNotFound(String),
     /// <p>Occurs when a conflict with a previous successful write is detected. For example, if a write operation occurs on an object and then an attempt is made to read the object using “SERIALIZABLE” consistency, this exception may result. This generally occurs when the previous write did not have time to propagate to the host serving the current request. A retry (with appropriate backoff logic) is the recommended response to this exception.</p>
     RetryableConflict(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateFacetError {
@@ -3909,34 +4032,37 @@ impl CreateFacetError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(CreateFacetError::AccessDenied(err.msg))
+                    return RusotoError::service(CreateFacetError::AccessDenied(err.msg), &res)
                 }
                 "FacetAlreadyExistsException" => {
-                    return RusotoError::Service(CreateFacetError::FacetAlreadyExists(err.msg))
+                    return RusotoError::service(
+                        CreateFacetError::FacetAlreadyExists(err.msg),
+                        &res,
+                    )
                 }
                 "FacetValidationException" => {
-                    return RusotoError::Service(CreateFacetError::FacetValidation(err.msg))
+                    return RusotoError::service(CreateFacetError::FacetValidation(err.msg), &res)
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(CreateFacetError::InternalService(err.msg))
+                    return RusotoError::service(CreateFacetError::InternalService(err.msg), &res)
                 }
                 "InvalidArnException" => {
-                    return RusotoError::Service(CreateFacetError::InvalidArn(err.msg))
+                    return RusotoError::service(CreateFacetError::InvalidArn(err.msg), &res)
                 }
                 "InvalidRuleException" => {
-                    return RusotoError::Service(CreateFacetError::InvalidRule(err.msg))
+                    return RusotoError::service(CreateFacetError::InvalidRule(err.msg), &res)
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateFacetError::LimitExceeded(err.msg))
+                    return RusotoError::service(CreateFacetError::LimitExceeded(err.msg), &res)
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(CreateFacetError::ResourceNotFound(err.msg))
+                    return RusotoError::service(CreateFacetError::ResourceNotFound(err.msg), &res)
                 }
                 "RetryableConflictException" => {
-                    return RusotoError::Service(CreateFacetError::RetryableConflict(err.msg))
+                    return RusotoError::service(CreateFacetError::RetryableConflict(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreateFacetError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3955,12 +4081,14 @@ impl fmt::Display for CreateFacetError {
             CreateFacetError::LimitExceeded(ref cause) => write!(f, "{}", cause),
             CreateFacetError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             CreateFacetError::RetryableConflict(ref cause) => write!(f, "{}", cause),
+            CreateFacetError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateFacetError {}
 /// Errors returned by CreateIndex
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateIndexError {
     /// <p>Access denied. Check your permissions.</p>
     AccessDenied(String),
@@ -3982,6 +4110,8 @@ pub enum CreateIndexError {
     RetryableConflict(String),
     /// <p>Indicates that the requested index type is not supported.</p>
     UnsupportedIndexType(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateIndexError {
@@ -3989,37 +4119,46 @@ impl CreateIndexError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(CreateIndexError::AccessDenied(err.msg))
+                    return RusotoError::service(CreateIndexError::AccessDenied(err.msg), &res)
                 }
                 "DirectoryNotEnabledException" => {
-                    return RusotoError::Service(CreateIndexError::DirectoryNotEnabled(err.msg))
+                    return RusotoError::service(
+                        CreateIndexError::DirectoryNotEnabled(err.msg),
+                        &res,
+                    )
                 }
                 "FacetValidationException" => {
-                    return RusotoError::Service(CreateIndexError::FacetValidation(err.msg))
+                    return RusotoError::service(CreateIndexError::FacetValidation(err.msg), &res)
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(CreateIndexError::InternalService(err.msg))
+                    return RusotoError::service(CreateIndexError::InternalService(err.msg), &res)
                 }
                 "InvalidArnException" => {
-                    return RusotoError::Service(CreateIndexError::InvalidArn(err.msg))
+                    return RusotoError::service(CreateIndexError::InvalidArn(err.msg), &res)
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateIndexError::LimitExceeded(err.msg))
+                    return RusotoError::service(CreateIndexError::LimitExceeded(err.msg), &res)
                 }
                 "LinkNameAlreadyInUseException" => {
-                    return RusotoError::Service(CreateIndexError::LinkNameAlreadyInUse(err.msg))
+                    return RusotoError::service(
+                        CreateIndexError::LinkNameAlreadyInUse(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(CreateIndexError::ResourceNotFound(err.msg))
+                    return RusotoError::service(CreateIndexError::ResourceNotFound(err.msg), &res)
                 }
                 "RetryableConflictException" => {
-                    return RusotoError::Service(CreateIndexError::RetryableConflict(err.msg))
+                    return RusotoError::service(CreateIndexError::RetryableConflict(err.msg), &res)
                 }
                 "UnsupportedIndexTypeException" => {
-                    return RusotoError::Service(CreateIndexError::UnsupportedIndexType(err.msg))
+                    return RusotoError::service(
+                        CreateIndexError::UnsupportedIndexType(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreateIndexError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -4039,12 +4178,14 @@ impl fmt::Display for CreateIndexError {
             CreateIndexError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             CreateIndexError::RetryableConflict(ref cause) => write!(f, "{}", cause),
             CreateIndexError::UnsupportedIndexType(ref cause) => write!(f, "{}", cause),
+            CreateIndexError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateIndexError {}
 /// Errors returned by CreateObject
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateObjectError {
     /// <p>Access denied. Check your permissions.</p>
     AccessDenied(String),
@@ -4066,6 +4207,8 @@ pub enum CreateObjectError {
     RetryableConflict(String),
     /// <p>Indicates that the requested index type is not supported.</p>
     UnsupportedIndexType(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateObjectError {
@@ -4073,37 +4216,49 @@ impl CreateObjectError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(CreateObjectError::AccessDenied(err.msg))
+                    return RusotoError::service(CreateObjectError::AccessDenied(err.msg), &res)
                 }
                 "DirectoryNotEnabledException" => {
-                    return RusotoError::Service(CreateObjectError::DirectoryNotEnabled(err.msg))
+                    return RusotoError::service(
+                        CreateObjectError::DirectoryNotEnabled(err.msg),
+                        &res,
+                    )
                 }
                 "FacetValidationException" => {
-                    return RusotoError::Service(CreateObjectError::FacetValidation(err.msg))
+                    return RusotoError::service(CreateObjectError::FacetValidation(err.msg), &res)
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(CreateObjectError::InternalService(err.msg))
+                    return RusotoError::service(CreateObjectError::InternalService(err.msg), &res)
                 }
                 "InvalidArnException" => {
-                    return RusotoError::Service(CreateObjectError::InvalidArn(err.msg))
+                    return RusotoError::service(CreateObjectError::InvalidArn(err.msg), &res)
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateObjectError::LimitExceeded(err.msg))
+                    return RusotoError::service(CreateObjectError::LimitExceeded(err.msg), &res)
                 }
                 "LinkNameAlreadyInUseException" => {
-                    return RusotoError::Service(CreateObjectError::LinkNameAlreadyInUse(err.msg))
+                    return RusotoError::service(
+                        CreateObjectError::LinkNameAlreadyInUse(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(CreateObjectError::ResourceNotFound(err.msg))
+                    return RusotoError::service(CreateObjectError::ResourceNotFound(err.msg), &res)
                 }
                 "RetryableConflictException" => {
-                    return RusotoError::Service(CreateObjectError::RetryableConflict(err.msg))
+                    return RusotoError::service(
+                        CreateObjectError::RetryableConflict(err.msg),
+                        &res,
+                    )
                 }
                 "UnsupportedIndexTypeException" => {
-                    return RusotoError::Service(CreateObjectError::UnsupportedIndexType(err.msg))
+                    return RusotoError::service(
+                        CreateObjectError::UnsupportedIndexType(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreateObjectError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -4123,12 +4278,14 @@ impl fmt::Display for CreateObjectError {
             CreateObjectError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             CreateObjectError::RetryableConflict(ref cause) => write!(f, "{}", cause),
             CreateObjectError::UnsupportedIndexType(ref cause) => write!(f, "{}", cause),
+            CreateObjectError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateObjectError {}
 /// Errors returned by CreateSchema
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateSchemaError {
     /// <p>Access denied. Check your permissions.</p>
     AccessDenied(String),
@@ -4142,6 +4299,8 @@ pub enum CreateSchemaError {
     RetryableConflict(String),
     /// <p>Indicates that a schema could not be created due to a naming conflict. Please select a different name and then try again.</p>
     SchemaAlreadyExists(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateSchemaError {
@@ -4149,25 +4308,31 @@ impl CreateSchemaError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(CreateSchemaError::AccessDenied(err.msg))
+                    return RusotoError::service(CreateSchemaError::AccessDenied(err.msg), &res)
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(CreateSchemaError::InternalService(err.msg))
+                    return RusotoError::service(CreateSchemaError::InternalService(err.msg), &res)
                 }
                 "InvalidArnException" => {
-                    return RusotoError::Service(CreateSchemaError::InvalidArn(err.msg))
+                    return RusotoError::service(CreateSchemaError::InvalidArn(err.msg), &res)
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateSchemaError::LimitExceeded(err.msg))
+                    return RusotoError::service(CreateSchemaError::LimitExceeded(err.msg), &res)
                 }
                 "RetryableConflictException" => {
-                    return RusotoError::Service(CreateSchemaError::RetryableConflict(err.msg))
+                    return RusotoError::service(
+                        CreateSchemaError::RetryableConflict(err.msg),
+                        &res,
+                    )
                 }
                 "SchemaAlreadyExistsException" => {
-                    return RusotoError::Service(CreateSchemaError::SchemaAlreadyExists(err.msg))
+                    return RusotoError::service(
+                        CreateSchemaError::SchemaAlreadyExists(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreateSchemaError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -4183,12 +4348,14 @@ impl fmt::Display for CreateSchemaError {
             CreateSchemaError::LimitExceeded(ref cause) => write!(f, "{}", cause),
             CreateSchemaError::RetryableConflict(ref cause) => write!(f, "{}", cause),
             CreateSchemaError::SchemaAlreadyExists(ref cause) => write!(f, "{}", cause),
+            CreateSchemaError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateSchemaError {}
 /// Errors returned by CreateTypedLinkFacet
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateTypedLinkFacetError {
     /// <p>Access denied. Check your permissions.</p>
     AccessDenied(String),
@@ -4208,6 +4375,8 @@ pub enum CreateTypedLinkFacetError {
     ResourceNotFound(String),
     /// <p>Occurs when a conflict with a previous successful write is detected. For example, if a write operation occurs on an object and then an attempt is made to read the object using “SERIALIZABLE” consistency, this exception may result. This generally occurs when the previous write did not have time to propagate to the host serving the current request. A retry (with appropriate backoff logic) is the recommended response to this exception.</p>
     RetryableConflict(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateTypedLinkFacetError {
@@ -4215,44 +4384,61 @@ impl CreateTypedLinkFacetError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(CreateTypedLinkFacetError::AccessDenied(err.msg))
+                    return RusotoError::service(
+                        CreateTypedLinkFacetError::AccessDenied(err.msg),
+                        &res,
+                    )
                 }
                 "FacetAlreadyExistsException" => {
-                    return RusotoError::Service(CreateTypedLinkFacetError::FacetAlreadyExists(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateTypedLinkFacetError::FacetAlreadyExists(err.msg),
+                        &res,
+                    )
                 }
                 "FacetValidationException" => {
-                    return RusotoError::Service(CreateTypedLinkFacetError::FacetValidation(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateTypedLinkFacetError::FacetValidation(err.msg),
+                        &res,
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(CreateTypedLinkFacetError::InternalService(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateTypedLinkFacetError::InternalService(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidArnException" => {
-                    return RusotoError::Service(CreateTypedLinkFacetError::InvalidArn(err.msg))
+                    return RusotoError::service(
+                        CreateTypedLinkFacetError::InvalidArn(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidRuleException" => {
-                    return RusotoError::Service(CreateTypedLinkFacetError::InvalidRule(err.msg))
+                    return RusotoError::service(
+                        CreateTypedLinkFacetError::InvalidRule(err.msg),
+                        &res,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateTypedLinkFacetError::LimitExceeded(err.msg))
+                    return RusotoError::service(
+                        CreateTypedLinkFacetError::LimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(CreateTypedLinkFacetError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateTypedLinkFacetError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "RetryableConflictException" => {
-                    return RusotoError::Service(CreateTypedLinkFacetError::RetryableConflict(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateTypedLinkFacetError::RetryableConflict(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreateTypedLinkFacetError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -4271,12 +4457,14 @@ impl fmt::Display for CreateTypedLinkFacetError {
             CreateTypedLinkFacetError::LimitExceeded(ref cause) => write!(f, "{}", cause),
             CreateTypedLinkFacetError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             CreateTypedLinkFacetError::RetryableConflict(ref cause) => write!(f, "{}", cause),
+            CreateTypedLinkFacetError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateTypedLinkFacetError {}
 /// Errors returned by DeleteDirectory
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteDirectoryError {
     /// <p>Access denied. Check your permissions.</p>
     AccessDenied(String),
@@ -4294,6 +4482,8 @@ pub enum DeleteDirectoryError {
     ResourceNotFound(String),
     /// <p>Occurs when a conflict with a previous successful write is detected. For example, if a write operation occurs on an object and then an attempt is made to read the object using “SERIALIZABLE” consistency, this exception may result. This generally occurs when the previous write did not have time to propagate to the host serving the current request. A retry (with appropriate backoff logic) is the recommended response to this exception.</p>
     RetryableConflict(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteDirectoryError {
@@ -4301,33 +4491,46 @@ impl DeleteDirectoryError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(DeleteDirectoryError::AccessDenied(err.msg))
+                    return RusotoError::service(DeleteDirectoryError::AccessDenied(err.msg), &res)
                 }
                 "DirectoryDeletedException" => {
-                    return RusotoError::Service(DeleteDirectoryError::DirectoryDeleted(err.msg))
+                    return RusotoError::service(
+                        DeleteDirectoryError::DirectoryDeleted(err.msg),
+                        &res,
+                    )
                 }
                 "DirectoryNotDisabledException" => {
-                    return RusotoError::Service(DeleteDirectoryError::DirectoryNotDisabled(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteDirectoryError::DirectoryNotDisabled(err.msg),
+                        &res,
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(DeleteDirectoryError::InternalService(err.msg))
+                    return RusotoError::service(
+                        DeleteDirectoryError::InternalService(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidArnException" => {
-                    return RusotoError::Service(DeleteDirectoryError::InvalidArn(err.msg))
+                    return RusotoError::service(DeleteDirectoryError::InvalidArn(err.msg), &res)
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(DeleteDirectoryError::LimitExceeded(err.msg))
+                    return RusotoError::service(DeleteDirectoryError::LimitExceeded(err.msg), &res)
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteDirectoryError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        DeleteDirectoryError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "RetryableConflictException" => {
-                    return RusotoError::Service(DeleteDirectoryError::RetryableConflict(err.msg))
+                    return RusotoError::service(
+                        DeleteDirectoryError::RetryableConflict(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteDirectoryError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -4345,12 +4548,14 @@ impl fmt::Display for DeleteDirectoryError {
             DeleteDirectoryError::LimitExceeded(ref cause) => write!(f, "{}", cause),
             DeleteDirectoryError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             DeleteDirectoryError::RetryableConflict(ref cause) => write!(f, "{}", cause),
+            DeleteDirectoryError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteDirectoryError {}
 /// Errors returned by DeleteFacet
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteFacetError {
     /// <p>Access denied. Check your permissions.</p>
     AccessDenied(String),
@@ -4368,6 +4573,8 @@ pub enum DeleteFacetError {
     ResourceNotFound(String),
     /// <p>Occurs when a conflict with a previous successful write is detected. For example, if a write operation occurs on an object and then an attempt is made to read the object using “SERIALIZABLE” consistency, this exception may result. This generally occurs when the previous write did not have time to propagate to the host serving the current request. A retry (with appropriate backoff logic) is the recommended response to this exception.</p>
     RetryableConflict(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteFacetError {
@@ -4375,31 +4582,31 @@ impl DeleteFacetError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(DeleteFacetError::AccessDenied(err.msg))
+                    return RusotoError::service(DeleteFacetError::AccessDenied(err.msg), &res)
                 }
                 "FacetInUseException" => {
-                    return RusotoError::Service(DeleteFacetError::FacetInUse(err.msg))
+                    return RusotoError::service(DeleteFacetError::FacetInUse(err.msg), &res)
                 }
                 "FacetNotFoundException" => {
-                    return RusotoError::Service(DeleteFacetError::FacetNotFound(err.msg))
+                    return RusotoError::service(DeleteFacetError::FacetNotFound(err.msg), &res)
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(DeleteFacetError::InternalService(err.msg))
+                    return RusotoError::service(DeleteFacetError::InternalService(err.msg), &res)
                 }
                 "InvalidArnException" => {
-                    return RusotoError::Service(DeleteFacetError::InvalidArn(err.msg))
+                    return RusotoError::service(DeleteFacetError::InvalidArn(err.msg), &res)
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(DeleteFacetError::LimitExceeded(err.msg))
+                    return RusotoError::service(DeleteFacetError::LimitExceeded(err.msg), &res)
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteFacetError::ResourceNotFound(err.msg))
+                    return RusotoError::service(DeleteFacetError::ResourceNotFound(err.msg), &res)
                 }
                 "RetryableConflictException" => {
-                    return RusotoError::Service(DeleteFacetError::RetryableConflict(err.msg))
+                    return RusotoError::service(DeleteFacetError::RetryableConflict(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteFacetError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -4417,12 +4624,14 @@ impl fmt::Display for DeleteFacetError {
             DeleteFacetError::LimitExceeded(ref cause) => write!(f, "{}", cause),
             DeleteFacetError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             DeleteFacetError::RetryableConflict(ref cause) => write!(f, "{}", cause),
+            DeleteFacetError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteFacetError {}
 /// Errors returned by DeleteObject
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteObjectError {
     /// <p>Access denied. Check your permissions.</p>
     AccessDenied(String),
@@ -4440,6 +4649,8 @@ pub enum DeleteObjectError {
     ResourceNotFound(String),
     /// <p>Occurs when a conflict with a previous successful write is detected. For example, if a write operation occurs on an object and then an attempt is made to read the object using “SERIALIZABLE” consistency, this exception may result. This generally occurs when the previous write did not have time to propagate to the host serving the current request. A retry (with appropriate backoff logic) is the recommended response to this exception.</p>
     RetryableConflict(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteObjectError {
@@ -4447,31 +4658,40 @@ impl DeleteObjectError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(DeleteObjectError::AccessDenied(err.msg))
+                    return RusotoError::service(DeleteObjectError::AccessDenied(err.msg), &res)
                 }
                 "DirectoryNotEnabledException" => {
-                    return RusotoError::Service(DeleteObjectError::DirectoryNotEnabled(err.msg))
+                    return RusotoError::service(
+                        DeleteObjectError::DirectoryNotEnabled(err.msg),
+                        &res,
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(DeleteObjectError::InternalService(err.msg))
+                    return RusotoError::service(DeleteObjectError::InternalService(err.msg), &res)
                 }
                 "InvalidArnException" => {
-                    return RusotoError::Service(DeleteObjectError::InvalidArn(err.msg))
+                    return RusotoError::service(DeleteObjectError::InvalidArn(err.msg), &res)
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(DeleteObjectError::LimitExceeded(err.msg))
+                    return RusotoError::service(DeleteObjectError::LimitExceeded(err.msg), &res)
                 }
                 "ObjectNotDetachedException" => {
-                    return RusotoError::Service(DeleteObjectError::ObjectNotDetached(err.msg))
+                    return RusotoError::service(
+                        DeleteObjectError::ObjectNotDetached(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteObjectError::ResourceNotFound(err.msg))
+                    return RusotoError::service(DeleteObjectError::ResourceNotFound(err.msg), &res)
                 }
                 "RetryableConflictException" => {
-                    return RusotoError::Service(DeleteObjectError::RetryableConflict(err.msg))
+                    return RusotoError::service(
+                        DeleteObjectError::RetryableConflict(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteObjectError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -4489,12 +4709,14 @@ impl fmt::Display for DeleteObjectError {
             DeleteObjectError::ObjectNotDetached(ref cause) => write!(f, "{}", cause),
             DeleteObjectError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             DeleteObjectError::RetryableConflict(ref cause) => write!(f, "{}", cause),
+            DeleteObjectError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteObjectError {}
 /// Errors returned by DeleteSchema
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteSchemaError {
     /// <p>Access denied. Check your permissions.</p>
     AccessDenied(String),
@@ -4510,6 +4732,8 @@ pub enum DeleteSchemaError {
     RetryableConflict(String),
     /// <p>The object could not be deleted because links still exist. Remove the links and then try the operation again.</p>
     StillContainsLinks(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteSchemaError {
@@ -4517,28 +4741,34 @@ impl DeleteSchemaError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(DeleteSchemaError::AccessDenied(err.msg))
+                    return RusotoError::service(DeleteSchemaError::AccessDenied(err.msg), &res)
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(DeleteSchemaError::InternalService(err.msg))
+                    return RusotoError::service(DeleteSchemaError::InternalService(err.msg), &res)
                 }
                 "InvalidArnException" => {
-                    return RusotoError::Service(DeleteSchemaError::InvalidArn(err.msg))
+                    return RusotoError::service(DeleteSchemaError::InvalidArn(err.msg), &res)
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(DeleteSchemaError::LimitExceeded(err.msg))
+                    return RusotoError::service(DeleteSchemaError::LimitExceeded(err.msg), &res)
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteSchemaError::ResourceNotFound(err.msg))
+                    return RusotoError::service(DeleteSchemaError::ResourceNotFound(err.msg), &res)
                 }
                 "RetryableConflictException" => {
-                    return RusotoError::Service(DeleteSchemaError::RetryableConflict(err.msg))
+                    return RusotoError::service(
+                        DeleteSchemaError::RetryableConflict(err.msg),
+                        &res,
+                    )
                 }
                 "StillContainsLinksException" => {
-                    return RusotoError::Service(DeleteSchemaError::StillContainsLinks(err.msg))
+                    return RusotoError::service(
+                        DeleteSchemaError::StillContainsLinks(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteSchemaError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -4555,12 +4785,14 @@ impl fmt::Display for DeleteSchemaError {
             DeleteSchemaError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             DeleteSchemaError::RetryableConflict(ref cause) => write!(f, "{}", cause),
             DeleteSchemaError::StillContainsLinks(ref cause) => write!(f, "{}", cause),
+            DeleteSchemaError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteSchemaError {}
 /// Errors returned by DeleteTypedLinkFacet
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteTypedLinkFacetError {
     /// <p>Access denied. Check your permissions.</p>
     AccessDenied(String),
@@ -4576,6 +4808,8 @@ pub enum DeleteTypedLinkFacetError {
     ResourceNotFound(String),
     /// <p>Occurs when a conflict with a previous successful write is detected. For example, if a write operation occurs on an object and then an attempt is made to read the object using “SERIALIZABLE” consistency, this exception may result. This generally occurs when the previous write did not have time to propagate to the host serving the current request. A retry (with appropriate backoff logic) is the recommended response to this exception.</p>
     RetryableConflict(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteTypedLinkFacetError {
@@ -4583,34 +4817,49 @@ impl DeleteTypedLinkFacetError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(DeleteTypedLinkFacetError::AccessDenied(err.msg))
+                    return RusotoError::service(
+                        DeleteTypedLinkFacetError::AccessDenied(err.msg),
+                        &res,
+                    )
                 }
                 "FacetNotFoundException" => {
-                    return RusotoError::Service(DeleteTypedLinkFacetError::FacetNotFound(err.msg))
+                    return RusotoError::service(
+                        DeleteTypedLinkFacetError::FacetNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(DeleteTypedLinkFacetError::InternalService(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteTypedLinkFacetError::InternalService(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidArnException" => {
-                    return RusotoError::Service(DeleteTypedLinkFacetError::InvalidArn(err.msg))
+                    return RusotoError::service(
+                        DeleteTypedLinkFacetError::InvalidArn(err.msg),
+                        &res,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(DeleteTypedLinkFacetError::LimitExceeded(err.msg))
+                    return RusotoError::service(
+                        DeleteTypedLinkFacetError::LimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteTypedLinkFacetError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteTypedLinkFacetError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "RetryableConflictException" => {
-                    return RusotoError::Service(DeleteTypedLinkFacetError::RetryableConflict(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteTypedLinkFacetError::RetryableConflict(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteTypedLinkFacetError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -4627,12 +4876,14 @@ impl fmt::Display for DeleteTypedLinkFacetError {
             DeleteTypedLinkFacetError::LimitExceeded(ref cause) => write!(f, "{}", cause),
             DeleteTypedLinkFacetError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             DeleteTypedLinkFacetError::RetryableConflict(ref cause) => write!(f, "{}", cause),
+            DeleteTypedLinkFacetError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteTypedLinkFacetError {}
 /// Errors returned by DetachFromIndex
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DetachFromIndexError {
     /// <p>Access denied. Check your permissions.</p>
     AccessDenied(String),
@@ -4652,6 +4903,8 @@ pub enum DetachFromIndexError {
     ResourceNotFound(String),
     /// <p>Occurs when a conflict with a previous successful write is detected. For example, if a write operation occurs on an object and then an attempt is made to read the object using “SERIALIZABLE” consistency, this exception may result. This generally occurs when the previous write did not have time to propagate to the host serving the current request. A retry (with appropriate backoff logic) is the recommended response to this exception.</p>
     RetryableConflict(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DetachFromIndexError {
@@ -4659,36 +4912,49 @@ impl DetachFromIndexError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(DetachFromIndexError::AccessDenied(err.msg))
+                    return RusotoError::service(DetachFromIndexError::AccessDenied(err.msg), &res)
                 }
                 "DirectoryNotEnabledException" => {
-                    return RusotoError::Service(DetachFromIndexError::DirectoryNotEnabled(err.msg))
+                    return RusotoError::service(
+                        DetachFromIndexError::DirectoryNotEnabled(err.msg),
+                        &res,
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(DetachFromIndexError::InternalService(err.msg))
+                    return RusotoError::service(
+                        DetachFromIndexError::InternalService(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidArnException" => {
-                    return RusotoError::Service(DetachFromIndexError::InvalidArn(err.msg))
+                    return RusotoError::service(DetachFromIndexError::InvalidArn(err.msg), &res)
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(DetachFromIndexError::LimitExceeded(err.msg))
+                    return RusotoError::service(DetachFromIndexError::LimitExceeded(err.msg), &res)
                 }
                 "NotIndexException" => {
-                    return RusotoError::Service(DetachFromIndexError::NotIndex(err.msg))
+                    return RusotoError::service(DetachFromIndexError::NotIndex(err.msg), &res)
                 }
                 "ObjectAlreadyDetachedException" => {
-                    return RusotoError::Service(DetachFromIndexError::ObjectAlreadyDetached(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DetachFromIndexError::ObjectAlreadyDetached(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DetachFromIndexError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        DetachFromIndexError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "RetryableConflictException" => {
-                    return RusotoError::Service(DetachFromIndexError::RetryableConflict(err.msg))
+                    return RusotoError::service(
+                        DetachFromIndexError::RetryableConflict(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DetachFromIndexError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -4707,12 +4973,14 @@ impl fmt::Display for DetachFromIndexError {
             DetachFromIndexError::ObjectAlreadyDetached(ref cause) => write!(f, "{}", cause),
             DetachFromIndexError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             DetachFromIndexError::RetryableConflict(ref cause) => write!(f, "{}", cause),
+            DetachFromIndexError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DetachFromIndexError {}
 /// Errors returned by DetachObject
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DetachObjectError {
     /// <p>Access denied. Check your permissions.</p>
     AccessDenied(String),
@@ -4730,6 +4998,8 @@ pub enum DetachObjectError {
     ResourceNotFound(String),
     /// <p>Occurs when a conflict with a previous successful write is detected. For example, if a write operation occurs on an object and then an attempt is made to read the object using “SERIALIZABLE” consistency, this exception may result. This generally occurs when the previous write did not have time to propagate to the host serving the current request. A retry (with appropriate backoff logic) is the recommended response to this exception.</p>
     RetryableConflict(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DetachObjectError {
@@ -4737,31 +5007,37 @@ impl DetachObjectError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(DetachObjectError::AccessDenied(err.msg))
+                    return RusotoError::service(DetachObjectError::AccessDenied(err.msg), &res)
                 }
                 "DirectoryNotEnabledException" => {
-                    return RusotoError::Service(DetachObjectError::DirectoryNotEnabled(err.msg))
+                    return RusotoError::service(
+                        DetachObjectError::DirectoryNotEnabled(err.msg),
+                        &res,
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(DetachObjectError::InternalService(err.msg))
+                    return RusotoError::service(DetachObjectError::InternalService(err.msg), &res)
                 }
                 "InvalidArnException" => {
-                    return RusotoError::Service(DetachObjectError::InvalidArn(err.msg))
+                    return RusotoError::service(DetachObjectError::InvalidArn(err.msg), &res)
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(DetachObjectError::LimitExceeded(err.msg))
+                    return RusotoError::service(DetachObjectError::LimitExceeded(err.msg), &res)
                 }
                 "NotNodeException" => {
-                    return RusotoError::Service(DetachObjectError::NotNode(err.msg))
+                    return RusotoError::service(DetachObjectError::NotNode(err.msg), &res)
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DetachObjectError::ResourceNotFound(err.msg))
+                    return RusotoError::service(DetachObjectError::ResourceNotFound(err.msg), &res)
                 }
                 "RetryableConflictException" => {
-                    return RusotoError::Service(DetachObjectError::RetryableConflict(err.msg))
+                    return RusotoError::service(
+                        DetachObjectError::RetryableConflict(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DetachObjectError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -4779,12 +5055,14 @@ impl fmt::Display for DetachObjectError {
             DetachObjectError::NotNode(ref cause) => write!(f, "{}", cause),
             DetachObjectError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             DetachObjectError::RetryableConflict(ref cause) => write!(f, "{}", cause),
+            DetachObjectError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DetachObjectError {}
 /// Errors returned by DetachPolicy
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DetachPolicyError {
     /// <p>Access denied. Check your permissions.</p>
     AccessDenied(String),
@@ -4802,6 +5080,8 @@ pub enum DetachPolicyError {
     ResourceNotFound(String),
     /// <p>Occurs when a conflict with a previous successful write is detected. For example, if a write operation occurs on an object and then an attempt is made to read the object using “SERIALIZABLE” consistency, this exception may result. This generally occurs when the previous write did not have time to propagate to the host serving the current request. A retry (with appropriate backoff logic) is the recommended response to this exception.</p>
     RetryableConflict(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DetachPolicyError {
@@ -4809,31 +5089,37 @@ impl DetachPolicyError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(DetachPolicyError::AccessDenied(err.msg))
+                    return RusotoError::service(DetachPolicyError::AccessDenied(err.msg), &res)
                 }
                 "DirectoryNotEnabledException" => {
-                    return RusotoError::Service(DetachPolicyError::DirectoryNotEnabled(err.msg))
+                    return RusotoError::service(
+                        DetachPolicyError::DirectoryNotEnabled(err.msg),
+                        &res,
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(DetachPolicyError::InternalService(err.msg))
+                    return RusotoError::service(DetachPolicyError::InternalService(err.msg), &res)
                 }
                 "InvalidArnException" => {
-                    return RusotoError::Service(DetachPolicyError::InvalidArn(err.msg))
+                    return RusotoError::service(DetachPolicyError::InvalidArn(err.msg), &res)
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(DetachPolicyError::LimitExceeded(err.msg))
+                    return RusotoError::service(DetachPolicyError::LimitExceeded(err.msg), &res)
                 }
                 "NotPolicyException" => {
-                    return RusotoError::Service(DetachPolicyError::NotPolicy(err.msg))
+                    return RusotoError::service(DetachPolicyError::NotPolicy(err.msg), &res)
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DetachPolicyError::ResourceNotFound(err.msg))
+                    return RusotoError::service(DetachPolicyError::ResourceNotFound(err.msg), &res)
                 }
                 "RetryableConflictException" => {
-                    return RusotoError::Service(DetachPolicyError::RetryableConflict(err.msg))
+                    return RusotoError::service(
+                        DetachPolicyError::RetryableConflict(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DetachPolicyError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -4851,12 +5137,14 @@ impl fmt::Display for DetachPolicyError {
             DetachPolicyError::NotPolicy(ref cause) => write!(f, "{}", cause),
             DetachPolicyError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             DetachPolicyError::RetryableConflict(ref cause) => write!(f, "{}", cause),
+            DetachPolicyError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DetachPolicyError {}
 /// Errors returned by DetachTypedLink
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DetachTypedLinkError {
     /// <p>Access denied. Check your permissions.</p>
     AccessDenied(String),
@@ -4874,6 +5162,8 @@ pub enum DetachTypedLinkError {
     ResourceNotFound(String),
     /// <p>Occurs when a conflict with a previous successful write is detected. For example, if a write operation occurs on an object and then an attempt is made to read the object using “SERIALIZABLE” consistency, this exception may result. This generally occurs when the previous write did not have time to propagate to the host serving the current request. A retry (with appropriate backoff logic) is the recommended response to this exception.</p>
     RetryableConflict(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DetachTypedLinkError {
@@ -4881,31 +5171,46 @@ impl DetachTypedLinkError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(DetachTypedLinkError::AccessDenied(err.msg))
+                    return RusotoError::service(DetachTypedLinkError::AccessDenied(err.msg), &res)
                 }
                 "DirectoryNotEnabledException" => {
-                    return RusotoError::Service(DetachTypedLinkError::DirectoryNotEnabled(err.msg))
+                    return RusotoError::service(
+                        DetachTypedLinkError::DirectoryNotEnabled(err.msg),
+                        &res,
+                    )
                 }
                 "FacetValidationException" => {
-                    return RusotoError::Service(DetachTypedLinkError::FacetValidation(err.msg))
+                    return RusotoError::service(
+                        DetachTypedLinkError::FacetValidation(err.msg),
+                        &res,
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(DetachTypedLinkError::InternalService(err.msg))
+                    return RusotoError::service(
+                        DetachTypedLinkError::InternalService(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidArnException" => {
-                    return RusotoError::Service(DetachTypedLinkError::InvalidArn(err.msg))
+                    return RusotoError::service(DetachTypedLinkError::InvalidArn(err.msg), &res)
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(DetachTypedLinkError::LimitExceeded(err.msg))
+                    return RusotoError::service(DetachTypedLinkError::LimitExceeded(err.msg), &res)
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DetachTypedLinkError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        DetachTypedLinkError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "RetryableConflictException" => {
-                    return RusotoError::Service(DetachTypedLinkError::RetryableConflict(err.msg))
+                    return RusotoError::service(
+                        DetachTypedLinkError::RetryableConflict(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DetachTypedLinkError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -4923,12 +5228,14 @@ impl fmt::Display for DetachTypedLinkError {
             DetachTypedLinkError::LimitExceeded(ref cause) => write!(f, "{}", cause),
             DetachTypedLinkError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             DetachTypedLinkError::RetryableConflict(ref cause) => write!(f, "{}", cause),
+            DetachTypedLinkError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DetachTypedLinkError {}
 /// Errors returned by DisableDirectory
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DisableDirectoryError {
     /// <p>Access denied. Check your permissions.</p>
     AccessDenied(String),
@@ -4944,6 +5251,8 @@ pub enum DisableDirectoryError {
     ResourceNotFound(String),
     /// <p>Occurs when a conflict with a previous successful write is detected. For example, if a write operation occurs on an object and then an attempt is made to read the object using “SERIALIZABLE” consistency, this exception may result. This generally occurs when the previous write did not have time to propagate to the host serving the current request. A retry (with appropriate backoff logic) is the recommended response to this exception.</p>
     RetryableConflict(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DisableDirectoryError {
@@ -4951,28 +5260,43 @@ impl DisableDirectoryError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(DisableDirectoryError::AccessDenied(err.msg))
+                    return RusotoError::service(DisableDirectoryError::AccessDenied(err.msg), &res)
                 }
                 "DirectoryDeletedException" => {
-                    return RusotoError::Service(DisableDirectoryError::DirectoryDeleted(err.msg))
+                    return RusotoError::service(
+                        DisableDirectoryError::DirectoryDeleted(err.msg),
+                        &res,
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(DisableDirectoryError::InternalService(err.msg))
+                    return RusotoError::service(
+                        DisableDirectoryError::InternalService(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidArnException" => {
-                    return RusotoError::Service(DisableDirectoryError::InvalidArn(err.msg))
+                    return RusotoError::service(DisableDirectoryError::InvalidArn(err.msg), &res)
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(DisableDirectoryError::LimitExceeded(err.msg))
+                    return RusotoError::service(
+                        DisableDirectoryError::LimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DisableDirectoryError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        DisableDirectoryError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "RetryableConflictException" => {
-                    return RusotoError::Service(DisableDirectoryError::RetryableConflict(err.msg))
+                    return RusotoError::service(
+                        DisableDirectoryError::RetryableConflict(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DisableDirectoryError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -4989,12 +5313,14 @@ impl fmt::Display for DisableDirectoryError {
             DisableDirectoryError::LimitExceeded(ref cause) => write!(f, "{}", cause),
             DisableDirectoryError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             DisableDirectoryError::RetryableConflict(ref cause) => write!(f, "{}", cause),
+            DisableDirectoryError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DisableDirectoryError {}
 /// Errors returned by EnableDirectory
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum EnableDirectoryError {
     /// <p>Access denied. Check your permissions.</p>
     AccessDenied(String),
@@ -5010,6 +5336,8 @@ pub enum EnableDirectoryError {
     ResourceNotFound(String),
     /// <p>Occurs when a conflict with a previous successful write is detected. For example, if a write operation occurs on an object and then an attempt is made to read the object using “SERIALIZABLE” consistency, this exception may result. This generally occurs when the previous write did not have time to propagate to the host serving the current request. A retry (with appropriate backoff logic) is the recommended response to this exception.</p>
     RetryableConflict(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl EnableDirectoryError {
@@ -5017,28 +5345,40 @@ impl EnableDirectoryError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(EnableDirectoryError::AccessDenied(err.msg))
+                    return RusotoError::service(EnableDirectoryError::AccessDenied(err.msg), &res)
                 }
                 "DirectoryDeletedException" => {
-                    return RusotoError::Service(EnableDirectoryError::DirectoryDeleted(err.msg))
+                    return RusotoError::service(
+                        EnableDirectoryError::DirectoryDeleted(err.msg),
+                        &res,
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(EnableDirectoryError::InternalService(err.msg))
+                    return RusotoError::service(
+                        EnableDirectoryError::InternalService(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidArnException" => {
-                    return RusotoError::Service(EnableDirectoryError::InvalidArn(err.msg))
+                    return RusotoError::service(EnableDirectoryError::InvalidArn(err.msg), &res)
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(EnableDirectoryError::LimitExceeded(err.msg))
+                    return RusotoError::service(EnableDirectoryError::LimitExceeded(err.msg), &res)
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(EnableDirectoryError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        EnableDirectoryError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "RetryableConflictException" => {
-                    return RusotoError::Service(EnableDirectoryError::RetryableConflict(err.msg))
+                    return RusotoError::service(
+                        EnableDirectoryError::RetryableConflict(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(EnableDirectoryError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -5055,12 +5395,14 @@ impl fmt::Display for EnableDirectoryError {
             EnableDirectoryError::LimitExceeded(ref cause) => write!(f, "{}", cause),
             EnableDirectoryError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             EnableDirectoryError::RetryableConflict(ref cause) => write!(f, "{}", cause),
+            EnableDirectoryError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for EnableDirectoryError {}
 /// Errors returned by GetAppliedSchemaVersion
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetAppliedSchemaVersionError {
     /// <p>Access denied. Check your permissions.</p>
     AccessDenied(String),
@@ -5074,6 +5416,8 @@ pub enum GetAppliedSchemaVersionError {
     ResourceNotFound(String),
     /// <p>Occurs when a conflict with a previous successful write is detected. For example, if a write operation occurs on an object and then an attempt is made to read the object using “SERIALIZABLE” consistency, this exception may result. This generally occurs when the previous write did not have time to propagate to the host serving the current request. A retry (with appropriate backoff logic) is the recommended response to this exception.</p>
     RetryableConflict(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetAppliedSchemaVersionError {
@@ -5081,35 +5425,43 @@ impl GetAppliedSchemaVersionError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(GetAppliedSchemaVersionError::AccessDenied(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetAppliedSchemaVersionError::AccessDenied(err.msg),
+                        &res,
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(GetAppliedSchemaVersionError::InternalService(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetAppliedSchemaVersionError::InternalService(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidArnException" => {
-                    return RusotoError::Service(GetAppliedSchemaVersionError::InvalidArn(err.msg))
+                    return RusotoError::service(
+                        GetAppliedSchemaVersionError::InvalidArn(err.msg),
+                        &res,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(GetAppliedSchemaVersionError::LimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetAppliedSchemaVersionError::LimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetAppliedSchemaVersionError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetAppliedSchemaVersionError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "RetryableConflictException" => {
-                    return RusotoError::Service(GetAppliedSchemaVersionError::RetryableConflict(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetAppliedSchemaVersionError::RetryableConflict(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetAppliedSchemaVersionError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -5125,12 +5477,14 @@ impl fmt::Display for GetAppliedSchemaVersionError {
             GetAppliedSchemaVersionError::LimitExceeded(ref cause) => write!(f, "{}", cause),
             GetAppliedSchemaVersionError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             GetAppliedSchemaVersionError::RetryableConflict(ref cause) => write!(f, "{}", cause),
+            GetAppliedSchemaVersionError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetAppliedSchemaVersionError {}
 /// Errors returned by GetDirectory
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetDirectoryError {
     /// <p>Access denied. Check your permissions.</p>
     AccessDenied(String),
@@ -5142,6 +5496,8 @@ pub enum GetDirectoryError {
     LimitExceeded(String),
     /// <p>Occurs when a conflict with a previous successful write is detected. For example, if a write operation occurs on an object and then an attempt is made to read the object using “SERIALIZABLE” consistency, this exception may result. This generally occurs when the previous write did not have time to propagate to the host serving the current request. A retry (with appropriate backoff logic) is the recommended response to this exception.</p>
     RetryableConflict(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetDirectoryError {
@@ -5149,22 +5505,25 @@ impl GetDirectoryError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(GetDirectoryError::AccessDenied(err.msg))
+                    return RusotoError::service(GetDirectoryError::AccessDenied(err.msg), &res)
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(GetDirectoryError::InternalService(err.msg))
+                    return RusotoError::service(GetDirectoryError::InternalService(err.msg), &res)
                 }
                 "InvalidArnException" => {
-                    return RusotoError::Service(GetDirectoryError::InvalidArn(err.msg))
+                    return RusotoError::service(GetDirectoryError::InvalidArn(err.msg), &res)
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(GetDirectoryError::LimitExceeded(err.msg))
+                    return RusotoError::service(GetDirectoryError::LimitExceeded(err.msg), &res)
                 }
                 "RetryableConflictException" => {
-                    return RusotoError::Service(GetDirectoryError::RetryableConflict(err.msg))
+                    return RusotoError::service(
+                        GetDirectoryError::RetryableConflict(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetDirectoryError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -5179,12 +5538,14 @@ impl fmt::Display for GetDirectoryError {
             GetDirectoryError::InvalidArn(ref cause) => write!(f, "{}", cause),
             GetDirectoryError::LimitExceeded(ref cause) => write!(f, "{}", cause),
             GetDirectoryError::RetryableConflict(ref cause) => write!(f, "{}", cause),
+            GetDirectoryError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetDirectoryError {}
 /// Errors returned by GetFacet
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetFacetError {
     /// <p>Access denied. Check your permissions.</p>
     AccessDenied(String),
@@ -5200,6 +5561,8 @@ pub enum GetFacetError {
     ResourceNotFound(String),
     /// <p>Occurs when a conflict with a previous successful write is detected. For example, if a write operation occurs on an object and then an attempt is made to read the object using “SERIALIZABLE” consistency, this exception may result. This generally occurs when the previous write did not have time to propagate to the host serving the current request. A retry (with appropriate backoff logic) is the recommended response to this exception.</p>
     RetryableConflict(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetFacetError {
@@ -5207,28 +5570,28 @@ impl GetFacetError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(GetFacetError::AccessDenied(err.msg))
+                    return RusotoError::service(GetFacetError::AccessDenied(err.msg), &res)
                 }
                 "FacetNotFoundException" => {
-                    return RusotoError::Service(GetFacetError::FacetNotFound(err.msg))
+                    return RusotoError::service(GetFacetError::FacetNotFound(err.msg), &res)
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(GetFacetError::InternalService(err.msg))
+                    return RusotoError::service(GetFacetError::InternalService(err.msg), &res)
                 }
                 "InvalidArnException" => {
-                    return RusotoError::Service(GetFacetError::InvalidArn(err.msg))
+                    return RusotoError::service(GetFacetError::InvalidArn(err.msg), &res)
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(GetFacetError::LimitExceeded(err.msg))
+                    return RusotoError::service(GetFacetError::LimitExceeded(err.msg), &res)
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetFacetError::ResourceNotFound(err.msg))
+                    return RusotoError::service(GetFacetError::ResourceNotFound(err.msg), &res)
                 }
                 "RetryableConflictException" => {
-                    return RusotoError::Service(GetFacetError::RetryableConflict(err.msg))
+                    return RusotoError::service(GetFacetError::RetryableConflict(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetFacetError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -5245,12 +5608,14 @@ impl fmt::Display for GetFacetError {
             GetFacetError::LimitExceeded(ref cause) => write!(f, "{}", cause),
             GetFacetError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             GetFacetError::RetryableConflict(ref cause) => write!(f, "{}", cause),
+            GetFacetError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetFacetError {}
 /// Errors returned by GetLinkAttributes
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetLinkAttributesError {
     /// <p>Access denied. Check your permissions.</p>
     AccessDenied(String),
@@ -5268,6 +5633,8 @@ pub enum GetLinkAttributesError {
     ResourceNotFound(String),
     /// <p>Occurs when a conflict with a previous successful write is detected. For example, if a write operation occurs on an object and then an attempt is made to read the object using “SERIALIZABLE” consistency, this exception may result. This generally occurs when the previous write did not have time to propagate to the host serving the current request. A retry (with appropriate backoff logic) is the recommended response to this exception.</p>
     RetryableConflict(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetLinkAttributesError {
@@ -5275,33 +5642,52 @@ impl GetLinkAttributesError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(GetLinkAttributesError::AccessDenied(err.msg))
+                    return RusotoError::service(
+                        GetLinkAttributesError::AccessDenied(err.msg),
+                        &res,
+                    )
                 }
                 "DirectoryNotEnabledException" => {
-                    return RusotoError::Service(GetLinkAttributesError::DirectoryNotEnabled(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetLinkAttributesError::DirectoryNotEnabled(err.msg),
+                        &res,
+                    )
                 }
                 "FacetValidationException" => {
-                    return RusotoError::Service(GetLinkAttributesError::FacetValidation(err.msg))
+                    return RusotoError::service(
+                        GetLinkAttributesError::FacetValidation(err.msg),
+                        &res,
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(GetLinkAttributesError::InternalService(err.msg))
+                    return RusotoError::service(
+                        GetLinkAttributesError::InternalService(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidArnException" => {
-                    return RusotoError::Service(GetLinkAttributesError::InvalidArn(err.msg))
+                    return RusotoError::service(GetLinkAttributesError::InvalidArn(err.msg), &res)
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(GetLinkAttributesError::LimitExceeded(err.msg))
+                    return RusotoError::service(
+                        GetLinkAttributesError::LimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetLinkAttributesError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        GetLinkAttributesError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "RetryableConflictException" => {
-                    return RusotoError::Service(GetLinkAttributesError::RetryableConflict(err.msg))
+                    return RusotoError::service(
+                        GetLinkAttributesError::RetryableConflict(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetLinkAttributesError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -5319,12 +5705,14 @@ impl fmt::Display for GetLinkAttributesError {
             GetLinkAttributesError::LimitExceeded(ref cause) => write!(f, "{}", cause),
             GetLinkAttributesError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             GetLinkAttributesError::RetryableConflict(ref cause) => write!(f, "{}", cause),
+            GetLinkAttributesError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetLinkAttributesError {}
 /// Errors returned by GetObjectAttributes
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetObjectAttributesError {
     /// <p>Access denied. Check your permissions.</p>
     AccessDenied(String),
@@ -5342,6 +5730,8 @@ pub enum GetObjectAttributesError {
     ResourceNotFound(String),
     /// <p>Occurs when a conflict with a previous successful write is detected. For example, if a write operation occurs on an object and then an attempt is made to read the object using “SERIALIZABLE” consistency, this exception may result. This generally occurs when the previous write did not have time to propagate to the host serving the current request. A retry (with appropriate backoff logic) is the recommended response to this exception.</p>
     RetryableConflict(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetObjectAttributesError {
@@ -5349,37 +5739,55 @@ impl GetObjectAttributesError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(GetObjectAttributesError::AccessDenied(err.msg))
+                    return RusotoError::service(
+                        GetObjectAttributesError::AccessDenied(err.msg),
+                        &res,
+                    )
                 }
                 "DirectoryNotEnabledException" => {
-                    return RusotoError::Service(GetObjectAttributesError::DirectoryNotEnabled(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetObjectAttributesError::DirectoryNotEnabled(err.msg),
+                        &res,
+                    )
                 }
                 "FacetValidationException" => {
-                    return RusotoError::Service(GetObjectAttributesError::FacetValidation(err.msg))
+                    return RusotoError::service(
+                        GetObjectAttributesError::FacetValidation(err.msg),
+                        &res,
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(GetObjectAttributesError::InternalService(err.msg))
+                    return RusotoError::service(
+                        GetObjectAttributesError::InternalService(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidArnException" => {
-                    return RusotoError::Service(GetObjectAttributesError::InvalidArn(err.msg))
+                    return RusotoError::service(
+                        GetObjectAttributesError::InvalidArn(err.msg),
+                        &res,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(GetObjectAttributesError::LimitExceeded(err.msg))
+                    return RusotoError::service(
+                        GetObjectAttributesError::LimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetObjectAttributesError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetObjectAttributesError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "RetryableConflictException" => {
-                    return RusotoError::Service(GetObjectAttributesError::RetryableConflict(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetObjectAttributesError::RetryableConflict(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetObjectAttributesError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -5397,12 +5805,14 @@ impl fmt::Display for GetObjectAttributesError {
             GetObjectAttributesError::LimitExceeded(ref cause) => write!(f, "{}", cause),
             GetObjectAttributesError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             GetObjectAttributesError::RetryableConflict(ref cause) => write!(f, "{}", cause),
+            GetObjectAttributesError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetObjectAttributesError {}
 /// Errors returned by GetObjectInformation
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetObjectInformationError {
     /// <p>Access denied. Check your permissions.</p>
     AccessDenied(String),
@@ -5418,6 +5828,8 @@ pub enum GetObjectInformationError {
     ResourceNotFound(String),
     /// <p>Occurs when a conflict with a previous successful write is detected. For example, if a write operation occurs on an object and then an attempt is made to read the object using “SERIALIZABLE” consistency, this exception may result. This generally occurs when the previous write did not have time to propagate to the host serving the current request. A retry (with appropriate backoff logic) is the recommended response to this exception.</p>
     RetryableConflict(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetObjectInformationError {
@@ -5425,36 +5837,49 @@ impl GetObjectInformationError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(GetObjectInformationError::AccessDenied(err.msg))
+                    return RusotoError::service(
+                        GetObjectInformationError::AccessDenied(err.msg),
+                        &res,
+                    )
                 }
                 "DirectoryNotEnabledException" => {
-                    return RusotoError::Service(GetObjectInformationError::DirectoryNotEnabled(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetObjectInformationError::DirectoryNotEnabled(err.msg),
+                        &res,
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(GetObjectInformationError::InternalService(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetObjectInformationError::InternalService(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidArnException" => {
-                    return RusotoError::Service(GetObjectInformationError::InvalidArn(err.msg))
+                    return RusotoError::service(
+                        GetObjectInformationError::InvalidArn(err.msg),
+                        &res,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(GetObjectInformationError::LimitExceeded(err.msg))
+                    return RusotoError::service(
+                        GetObjectInformationError::LimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetObjectInformationError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetObjectInformationError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "RetryableConflictException" => {
-                    return RusotoError::Service(GetObjectInformationError::RetryableConflict(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetObjectInformationError::RetryableConflict(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetObjectInformationError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -5471,12 +5896,14 @@ impl fmt::Display for GetObjectInformationError {
             GetObjectInformationError::LimitExceeded(ref cause) => write!(f, "{}", cause),
             GetObjectInformationError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             GetObjectInformationError::RetryableConflict(ref cause) => write!(f, "{}", cause),
+            GetObjectInformationError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetObjectInformationError {}
 /// Errors returned by GetSchemaAsJson
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetSchemaAsJsonError {
     /// <p>Access denied. Check your permissions.</p>
     AccessDenied(String),
@@ -5490,6 +5917,8 @@ pub enum GetSchemaAsJsonError {
     ResourceNotFound(String),
     /// <p>Occurs when a conflict with a previous successful write is detected. For example, if a write operation occurs on an object and then an attempt is made to read the object using “SERIALIZABLE” consistency, this exception may result. This generally occurs when the previous write did not have time to propagate to the host serving the current request. A retry (with appropriate backoff logic) is the recommended response to this exception.</p>
     RetryableConflict(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetSchemaAsJsonError {
@@ -5497,25 +5926,34 @@ impl GetSchemaAsJsonError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(GetSchemaAsJsonError::AccessDenied(err.msg))
+                    return RusotoError::service(GetSchemaAsJsonError::AccessDenied(err.msg), &res)
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(GetSchemaAsJsonError::InternalService(err.msg))
+                    return RusotoError::service(
+                        GetSchemaAsJsonError::InternalService(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidArnException" => {
-                    return RusotoError::Service(GetSchemaAsJsonError::InvalidArn(err.msg))
+                    return RusotoError::service(GetSchemaAsJsonError::InvalidArn(err.msg), &res)
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(GetSchemaAsJsonError::LimitExceeded(err.msg))
+                    return RusotoError::service(GetSchemaAsJsonError::LimitExceeded(err.msg), &res)
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetSchemaAsJsonError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        GetSchemaAsJsonError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "RetryableConflictException" => {
-                    return RusotoError::Service(GetSchemaAsJsonError::RetryableConflict(err.msg))
+                    return RusotoError::service(
+                        GetSchemaAsJsonError::RetryableConflict(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetSchemaAsJsonError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -5531,12 +5969,14 @@ impl fmt::Display for GetSchemaAsJsonError {
             GetSchemaAsJsonError::LimitExceeded(ref cause) => write!(f, "{}", cause),
             GetSchemaAsJsonError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             GetSchemaAsJsonError::RetryableConflict(ref cause) => write!(f, "{}", cause),
+            GetSchemaAsJsonError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetSchemaAsJsonError {}
 /// Errors returned by GetTypedLinkFacetInformation
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetTypedLinkFacetInformationError {
     /// <p>Access denied. Check your permissions.</p>
     AccessDenied(String),
@@ -5554,6 +5994,8 @@ pub enum GetTypedLinkFacetInformationError {
     ResourceNotFound(String),
     /// <p>Occurs when a conflict with a previous successful write is detected. For example, if a write operation occurs on an object and then an attempt is made to read the object using “SERIALIZABLE” consistency, this exception may result. This generally occurs when the previous write did not have time to propagate to the host serving the current request. A retry (with appropriate backoff logic) is the recommended response to this exception.</p>
     RetryableConflict(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetTypedLinkFacetInformationError {
@@ -5563,47 +6005,60 @@ impl GetTypedLinkFacetInformationError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(GetTypedLinkFacetInformationError::AccessDenied(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetTypedLinkFacetInformationError::AccessDenied(err.msg),
+                        &res,
+                    )
                 }
                 "FacetNotFoundException" => {
-                    return RusotoError::Service(GetTypedLinkFacetInformationError::FacetNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetTypedLinkFacetInformationError::FacetNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         GetTypedLinkFacetInformationError::InternalService(err.msg),
+                        &res,
                     )
                 }
                 "InvalidArnException" => {
-                    return RusotoError::Service(GetTypedLinkFacetInformationError::InvalidArn(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetTypedLinkFacetInformationError::InvalidArn(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         GetTypedLinkFacetInformationError::InvalidNextToken(err.msg),
+                        &res,
                     )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(GetTypedLinkFacetInformationError::LimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetTypedLinkFacetInformationError::LimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         GetTypedLinkFacetInformationError::ResourceNotFound(err.msg),
+                        &res,
                     )
                 }
                 "RetryableConflictException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         GetTypedLinkFacetInformationError::RetryableConflict(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        GetTypedLinkFacetInformationError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -5627,12 +6082,14 @@ impl fmt::Display for GetTypedLinkFacetInformationError {
             GetTypedLinkFacetInformationError::RetryableConflict(ref cause) => {
                 write!(f, "{}", cause)
             }
+            GetTypedLinkFacetInformationError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetTypedLinkFacetInformationError {}
 /// Errors returned by ListAppliedSchemaArns
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListAppliedSchemaArnsError {
     /// <p>Access denied. Check your permissions.</p>
     AccessDenied(String),
@@ -5648,6 +6105,8 @@ pub enum ListAppliedSchemaArnsError {
     ResourceNotFound(String),
     /// <p>Occurs when a conflict with a previous successful write is detected. For example, if a write operation occurs on an object and then an attempt is made to read the object using “SERIALIZABLE” consistency, this exception may result. This generally occurs when the previous write did not have time to propagate to the host serving the current request. A retry (with appropriate backoff logic) is the recommended response to this exception.</p>
     RetryableConflict(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListAppliedSchemaArnsError {
@@ -5655,36 +6114,49 @@ impl ListAppliedSchemaArnsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(ListAppliedSchemaArnsError::AccessDenied(err.msg))
+                    return RusotoError::service(
+                        ListAppliedSchemaArnsError::AccessDenied(err.msg),
+                        &res,
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(ListAppliedSchemaArnsError::InternalService(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListAppliedSchemaArnsError::InternalService(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidArnException" => {
-                    return RusotoError::Service(ListAppliedSchemaArnsError::InvalidArn(err.msg))
+                    return RusotoError::service(
+                        ListAppliedSchemaArnsError::InvalidArn(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(ListAppliedSchemaArnsError::InvalidNextToken(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListAppliedSchemaArnsError::InvalidNextToken(err.msg),
+                        &res,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(ListAppliedSchemaArnsError::LimitExceeded(err.msg))
+                    return RusotoError::service(
+                        ListAppliedSchemaArnsError::LimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListAppliedSchemaArnsError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListAppliedSchemaArnsError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "RetryableConflictException" => {
-                    return RusotoError::Service(ListAppliedSchemaArnsError::RetryableConflict(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListAppliedSchemaArnsError::RetryableConflict(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListAppliedSchemaArnsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -5701,12 +6173,14 @@ impl fmt::Display for ListAppliedSchemaArnsError {
             ListAppliedSchemaArnsError::LimitExceeded(ref cause) => write!(f, "{}", cause),
             ListAppliedSchemaArnsError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             ListAppliedSchemaArnsError::RetryableConflict(ref cause) => write!(f, "{}", cause),
+            ListAppliedSchemaArnsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListAppliedSchemaArnsError {}
 /// Errors returned by ListAttachedIndices
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListAttachedIndicesError {
     /// <p>Access denied. Check your permissions.</p>
     AccessDenied(String),
@@ -5722,6 +6196,8 @@ pub enum ListAttachedIndicesError {
     ResourceNotFound(String),
     /// <p>Occurs when a conflict with a previous successful write is detected. For example, if a write operation occurs on an object and then an attempt is made to read the object using “SERIALIZABLE” consistency, this exception may result. This generally occurs when the previous write did not have time to propagate to the host serving the current request. A retry (with appropriate backoff logic) is the recommended response to this exception.</p>
     RetryableConflict(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListAttachedIndicesError {
@@ -5729,34 +6205,49 @@ impl ListAttachedIndicesError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(ListAttachedIndicesError::AccessDenied(err.msg))
+                    return RusotoError::service(
+                        ListAttachedIndicesError::AccessDenied(err.msg),
+                        &res,
+                    )
                 }
                 "DirectoryNotEnabledException" => {
-                    return RusotoError::Service(ListAttachedIndicesError::DirectoryNotEnabled(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListAttachedIndicesError::DirectoryNotEnabled(err.msg),
+                        &res,
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(ListAttachedIndicesError::InternalService(err.msg))
+                    return RusotoError::service(
+                        ListAttachedIndicesError::InternalService(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidArnException" => {
-                    return RusotoError::Service(ListAttachedIndicesError::InvalidArn(err.msg))
+                    return RusotoError::service(
+                        ListAttachedIndicesError::InvalidArn(err.msg),
+                        &res,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(ListAttachedIndicesError::LimitExceeded(err.msg))
+                    return RusotoError::service(
+                        ListAttachedIndicesError::LimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListAttachedIndicesError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListAttachedIndicesError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "RetryableConflictException" => {
-                    return RusotoError::Service(ListAttachedIndicesError::RetryableConflict(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListAttachedIndicesError::RetryableConflict(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListAttachedIndicesError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -5773,12 +6264,14 @@ impl fmt::Display for ListAttachedIndicesError {
             ListAttachedIndicesError::LimitExceeded(ref cause) => write!(f, "{}", cause),
             ListAttachedIndicesError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             ListAttachedIndicesError::RetryableConflict(ref cause) => write!(f, "{}", cause),
+            ListAttachedIndicesError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListAttachedIndicesError {}
 /// Errors returned by ListDevelopmentSchemaArns
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListDevelopmentSchemaArnsError {
     /// <p>Access denied. Check your permissions.</p>
     AccessDenied(String),
@@ -5794,6 +6287,8 @@ pub enum ListDevelopmentSchemaArnsError {
     ResourceNotFound(String),
     /// <p>Occurs when a conflict with a previous successful write is detected. For example, if a write operation occurs on an object and then an attempt is made to read the object using “SERIALIZABLE” consistency, this exception may result. This generally occurs when the previous write did not have time to propagate to the host serving the current request. A retry (with appropriate backoff logic) is the recommended response to this exception.</p>
     RetryableConflict(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListDevelopmentSchemaArnsError {
@@ -5801,42 +6296,51 @@ impl ListDevelopmentSchemaArnsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(ListDevelopmentSchemaArnsError::AccessDenied(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListDevelopmentSchemaArnsError::AccessDenied(err.msg),
+                        &res,
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(ListDevelopmentSchemaArnsError::InternalService(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListDevelopmentSchemaArnsError::InternalService(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidArnException" => {
-                    return RusotoError::Service(ListDevelopmentSchemaArnsError::InvalidArn(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListDevelopmentSchemaArnsError::InvalidArn(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(ListDevelopmentSchemaArnsError::InvalidNextToken(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListDevelopmentSchemaArnsError::InvalidNextToken(err.msg),
+                        &res,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(ListDevelopmentSchemaArnsError::LimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListDevelopmentSchemaArnsError::LimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListDevelopmentSchemaArnsError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListDevelopmentSchemaArnsError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "RetryableConflictException" => {
-                    return RusotoError::Service(ListDevelopmentSchemaArnsError::RetryableConflict(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListDevelopmentSchemaArnsError::RetryableConflict(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(ListDevelopmentSchemaArnsError::Unhandled, &res)
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -5853,12 +6357,14 @@ impl fmt::Display for ListDevelopmentSchemaArnsError {
             ListDevelopmentSchemaArnsError::LimitExceeded(ref cause) => write!(f, "{}", cause),
             ListDevelopmentSchemaArnsError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             ListDevelopmentSchemaArnsError::RetryableConflict(ref cause) => write!(f, "{}", cause),
+            ListDevelopmentSchemaArnsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListDevelopmentSchemaArnsError {}
 /// Errors returned by ListDirectories
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListDirectoriesError {
     /// <p>Access denied. Check your permissions.</p>
     AccessDenied(String),
@@ -5872,6 +6378,8 @@ pub enum ListDirectoriesError {
     LimitExceeded(String),
     /// <p>Occurs when a conflict with a previous successful write is detected. For example, if a write operation occurs on an object and then an attempt is made to read the object using “SERIALIZABLE” consistency, this exception may result. This generally occurs when the previous write did not have time to propagate to the host serving the current request. A retry (with appropriate backoff logic) is the recommended response to this exception.</p>
     RetryableConflict(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListDirectoriesError {
@@ -5879,25 +6387,34 @@ impl ListDirectoriesError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(ListDirectoriesError::AccessDenied(err.msg))
+                    return RusotoError::service(ListDirectoriesError::AccessDenied(err.msg), &res)
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(ListDirectoriesError::InternalService(err.msg))
+                    return RusotoError::service(
+                        ListDirectoriesError::InternalService(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidArnException" => {
-                    return RusotoError::Service(ListDirectoriesError::InvalidArn(err.msg))
+                    return RusotoError::service(ListDirectoriesError::InvalidArn(err.msg), &res)
                 }
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(ListDirectoriesError::InvalidNextToken(err.msg))
+                    return RusotoError::service(
+                        ListDirectoriesError::InvalidNextToken(err.msg),
+                        &res,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(ListDirectoriesError::LimitExceeded(err.msg))
+                    return RusotoError::service(ListDirectoriesError::LimitExceeded(err.msg), &res)
                 }
                 "RetryableConflictException" => {
-                    return RusotoError::Service(ListDirectoriesError::RetryableConflict(err.msg))
+                    return RusotoError::service(
+                        ListDirectoriesError::RetryableConflict(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListDirectoriesError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -5913,12 +6430,14 @@ impl fmt::Display for ListDirectoriesError {
             ListDirectoriesError::InvalidNextToken(ref cause) => write!(f, "{}", cause),
             ListDirectoriesError::LimitExceeded(ref cause) => write!(f, "{}", cause),
             ListDirectoriesError::RetryableConflict(ref cause) => write!(f, "{}", cause),
+            ListDirectoriesError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListDirectoriesError {}
 /// Errors returned by ListFacetAttributes
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListFacetAttributesError {
     /// <p>Access denied. Check your permissions.</p>
     AccessDenied(String),
@@ -5936,6 +6455,8 @@ pub enum ListFacetAttributesError {
     ResourceNotFound(String),
     /// <p>Occurs when a conflict with a previous successful write is detected. For example, if a write operation occurs on an object and then an attempt is made to read the object using “SERIALIZABLE” consistency, this exception may result. This generally occurs when the previous write did not have time to propagate to the host serving the current request. A retry (with appropriate backoff logic) is the recommended response to this exception.</p>
     RetryableConflict(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListFacetAttributesError {
@@ -5943,37 +6464,55 @@ impl ListFacetAttributesError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(ListFacetAttributesError::AccessDenied(err.msg))
+                    return RusotoError::service(
+                        ListFacetAttributesError::AccessDenied(err.msg),
+                        &res,
+                    )
                 }
                 "FacetNotFoundException" => {
-                    return RusotoError::Service(ListFacetAttributesError::FacetNotFound(err.msg))
+                    return RusotoError::service(
+                        ListFacetAttributesError::FacetNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(ListFacetAttributesError::InternalService(err.msg))
+                    return RusotoError::service(
+                        ListFacetAttributesError::InternalService(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidArnException" => {
-                    return RusotoError::Service(ListFacetAttributesError::InvalidArn(err.msg))
+                    return RusotoError::service(
+                        ListFacetAttributesError::InvalidArn(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(ListFacetAttributesError::InvalidNextToken(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListFacetAttributesError::InvalidNextToken(err.msg),
+                        &res,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(ListFacetAttributesError::LimitExceeded(err.msg))
+                    return RusotoError::service(
+                        ListFacetAttributesError::LimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListFacetAttributesError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListFacetAttributesError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "RetryableConflictException" => {
-                    return RusotoError::Service(ListFacetAttributesError::RetryableConflict(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListFacetAttributesError::RetryableConflict(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListFacetAttributesError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -5991,12 +6530,14 @@ impl fmt::Display for ListFacetAttributesError {
             ListFacetAttributesError::LimitExceeded(ref cause) => write!(f, "{}", cause),
             ListFacetAttributesError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             ListFacetAttributesError::RetryableConflict(ref cause) => write!(f, "{}", cause),
+            ListFacetAttributesError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListFacetAttributesError {}
 /// Errors returned by ListFacetNames
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListFacetNamesError {
     /// <p>Access denied. Check your permissions.</p>
     AccessDenied(String),
@@ -6012,6 +6553,8 @@ pub enum ListFacetNamesError {
     ResourceNotFound(String),
     /// <p>Occurs when a conflict with a previous successful write is detected. For example, if a write operation occurs on an object and then an attempt is made to read the object using “SERIALIZABLE” consistency, this exception may result. This generally occurs when the previous write did not have time to propagate to the host serving the current request. A retry (with appropriate backoff logic) is the recommended response to this exception.</p>
     RetryableConflict(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListFacetNamesError {
@@ -6019,28 +6562,40 @@ impl ListFacetNamesError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(ListFacetNamesError::AccessDenied(err.msg))
+                    return RusotoError::service(ListFacetNamesError::AccessDenied(err.msg), &res)
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(ListFacetNamesError::InternalService(err.msg))
+                    return RusotoError::service(
+                        ListFacetNamesError::InternalService(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidArnException" => {
-                    return RusotoError::Service(ListFacetNamesError::InvalidArn(err.msg))
+                    return RusotoError::service(ListFacetNamesError::InvalidArn(err.msg), &res)
                 }
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(ListFacetNamesError::InvalidNextToken(err.msg))
+                    return RusotoError::service(
+                        ListFacetNamesError::InvalidNextToken(err.msg),
+                        &res,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(ListFacetNamesError::LimitExceeded(err.msg))
+                    return RusotoError::service(ListFacetNamesError::LimitExceeded(err.msg), &res)
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListFacetNamesError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        ListFacetNamesError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "RetryableConflictException" => {
-                    return RusotoError::Service(ListFacetNamesError::RetryableConflict(err.msg))
+                    return RusotoError::service(
+                        ListFacetNamesError::RetryableConflict(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListFacetNamesError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -6057,12 +6612,14 @@ impl fmt::Display for ListFacetNamesError {
             ListFacetNamesError::LimitExceeded(ref cause) => write!(f, "{}", cause),
             ListFacetNamesError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             ListFacetNamesError::RetryableConflict(ref cause) => write!(f, "{}", cause),
+            ListFacetNamesError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListFacetNamesError {}
 /// Errors returned by ListIncomingTypedLinks
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListIncomingTypedLinksError {
     /// <p>Access denied. Check your permissions.</p>
     AccessDenied(String),
@@ -6082,6 +6639,8 @@ pub enum ListIncomingTypedLinksError {
     ResourceNotFound(String),
     /// <p>Occurs when a conflict with a previous successful write is detected. For example, if a write operation occurs on an object and then an attempt is made to read the object using “SERIALIZABLE” consistency, this exception may result. This generally occurs when the previous write did not have time to propagate to the host serving the current request. A retry (with appropriate backoff logic) is the recommended response to this exception.</p>
     RetryableConflict(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListIncomingTypedLinksError {
@@ -6089,48 +6648,61 @@ impl ListIncomingTypedLinksError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(ListIncomingTypedLinksError::AccessDenied(err.msg))
+                    return RusotoError::service(
+                        ListIncomingTypedLinksError::AccessDenied(err.msg),
+                        &res,
+                    )
                 }
                 "DirectoryNotEnabledException" => {
-                    return RusotoError::Service(ListIncomingTypedLinksError::DirectoryNotEnabled(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListIncomingTypedLinksError::DirectoryNotEnabled(err.msg),
+                        &res,
+                    )
                 }
                 "FacetValidationException" => {
-                    return RusotoError::Service(ListIncomingTypedLinksError::FacetValidation(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListIncomingTypedLinksError::FacetValidation(err.msg),
+                        &res,
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(ListIncomingTypedLinksError::InternalService(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListIncomingTypedLinksError::InternalService(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidArnException" => {
-                    return RusotoError::Service(ListIncomingTypedLinksError::InvalidArn(err.msg))
+                    return RusotoError::service(
+                        ListIncomingTypedLinksError::InvalidArn(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(ListIncomingTypedLinksError::InvalidNextToken(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListIncomingTypedLinksError::InvalidNextToken(err.msg),
+                        &res,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(ListIncomingTypedLinksError::LimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListIncomingTypedLinksError::LimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListIncomingTypedLinksError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListIncomingTypedLinksError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "RetryableConflictException" => {
-                    return RusotoError::Service(ListIncomingTypedLinksError::RetryableConflict(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListIncomingTypedLinksError::RetryableConflict(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListIncomingTypedLinksError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -6149,12 +6721,14 @@ impl fmt::Display for ListIncomingTypedLinksError {
             ListIncomingTypedLinksError::LimitExceeded(ref cause) => write!(f, "{}", cause),
             ListIncomingTypedLinksError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             ListIncomingTypedLinksError::RetryableConflict(ref cause) => write!(f, "{}", cause),
+            ListIncomingTypedLinksError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListIncomingTypedLinksError {}
 /// Errors returned by ListIndex
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListIndexError {
     /// <p>Access denied. Check your permissions.</p>
     AccessDenied(String),
@@ -6176,6 +6750,8 @@ pub enum ListIndexError {
     ResourceNotFound(String),
     /// <p>Occurs when a conflict with a previous successful write is detected. For example, if a write operation occurs on an object and then an attempt is made to read the object using “SERIALIZABLE” consistency, this exception may result. This generally occurs when the previous write did not have time to propagate to the host serving the current request. A retry (with appropriate backoff logic) is the recommended response to this exception.</p>
     RetryableConflict(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListIndexError {
@@ -6183,37 +6759,37 @@ impl ListIndexError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(ListIndexError::AccessDenied(err.msg))
+                    return RusotoError::service(ListIndexError::AccessDenied(err.msg), &res)
                 }
                 "DirectoryNotEnabledException" => {
-                    return RusotoError::Service(ListIndexError::DirectoryNotEnabled(err.msg))
+                    return RusotoError::service(ListIndexError::DirectoryNotEnabled(err.msg), &res)
                 }
                 "FacetValidationException" => {
-                    return RusotoError::Service(ListIndexError::FacetValidation(err.msg))
+                    return RusotoError::service(ListIndexError::FacetValidation(err.msg), &res)
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(ListIndexError::InternalService(err.msg))
+                    return RusotoError::service(ListIndexError::InternalService(err.msg), &res)
                 }
                 "InvalidArnException" => {
-                    return RusotoError::Service(ListIndexError::InvalidArn(err.msg))
+                    return RusotoError::service(ListIndexError::InvalidArn(err.msg), &res)
                 }
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(ListIndexError::InvalidNextToken(err.msg))
+                    return RusotoError::service(ListIndexError::InvalidNextToken(err.msg), &res)
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(ListIndexError::LimitExceeded(err.msg))
+                    return RusotoError::service(ListIndexError::LimitExceeded(err.msg), &res)
                 }
                 "NotIndexException" => {
-                    return RusotoError::Service(ListIndexError::NotIndex(err.msg))
+                    return RusotoError::service(ListIndexError::NotIndex(err.msg), &res)
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListIndexError::ResourceNotFound(err.msg))
+                    return RusotoError::service(ListIndexError::ResourceNotFound(err.msg), &res)
                 }
                 "RetryableConflictException" => {
-                    return RusotoError::Service(ListIndexError::RetryableConflict(err.msg))
+                    return RusotoError::service(ListIndexError::RetryableConflict(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListIndexError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -6233,12 +6809,14 @@ impl fmt::Display for ListIndexError {
             ListIndexError::NotIndex(ref cause) => write!(f, "{}", cause),
             ListIndexError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             ListIndexError::RetryableConflict(ref cause) => write!(f, "{}", cause),
+            ListIndexError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListIndexError {}
 /// Errors returned by ListManagedSchemaArns
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListManagedSchemaArnsError {
     /// <p>Access denied. Check your permissions.</p>
     AccessDenied(String),
@@ -6250,6 +6828,8 @@ pub enum ListManagedSchemaArnsError {
     InvalidNextToken(String),
     /// <p>The specified resource could not be found.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListManagedSchemaArnsError {
@@ -6257,28 +6837,37 @@ impl ListManagedSchemaArnsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(ListManagedSchemaArnsError::AccessDenied(err.msg))
+                    return RusotoError::service(
+                        ListManagedSchemaArnsError::AccessDenied(err.msg),
+                        &res,
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(ListManagedSchemaArnsError::InternalService(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListManagedSchemaArnsError::InternalService(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidArnException" => {
-                    return RusotoError::Service(ListManagedSchemaArnsError::InvalidArn(err.msg))
+                    return RusotoError::service(
+                        ListManagedSchemaArnsError::InvalidArn(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(ListManagedSchemaArnsError::InvalidNextToken(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListManagedSchemaArnsError::InvalidNextToken(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListManagedSchemaArnsError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListManagedSchemaArnsError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListManagedSchemaArnsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -6293,12 +6882,14 @@ impl fmt::Display for ListManagedSchemaArnsError {
             ListManagedSchemaArnsError::InvalidArn(ref cause) => write!(f, "{}", cause),
             ListManagedSchemaArnsError::InvalidNextToken(ref cause) => write!(f, "{}", cause),
             ListManagedSchemaArnsError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            ListManagedSchemaArnsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListManagedSchemaArnsError {}
 /// Errors returned by ListObjectAttributes
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListObjectAttributesError {
     /// <p>Access denied. Check your permissions.</p>
     AccessDenied(String),
@@ -6318,6 +6909,8 @@ pub enum ListObjectAttributesError {
     ResourceNotFound(String),
     /// <p>Occurs when a conflict with a previous successful write is detected. For example, if a write operation occurs on an object and then an attempt is made to read the object using “SERIALIZABLE” consistency, this exception may result. This generally occurs when the previous write did not have time to propagate to the host serving the current request. A retry (with appropriate backoff logic) is the recommended response to this exception.</p>
     RetryableConflict(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListObjectAttributesError {
@@ -6325,46 +6918,61 @@ impl ListObjectAttributesError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(ListObjectAttributesError::AccessDenied(err.msg))
+                    return RusotoError::service(
+                        ListObjectAttributesError::AccessDenied(err.msg),
+                        &res,
+                    )
                 }
                 "DirectoryNotEnabledException" => {
-                    return RusotoError::Service(ListObjectAttributesError::DirectoryNotEnabled(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListObjectAttributesError::DirectoryNotEnabled(err.msg),
+                        &res,
+                    )
                 }
                 "FacetValidationException" => {
-                    return RusotoError::Service(ListObjectAttributesError::FacetValidation(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListObjectAttributesError::FacetValidation(err.msg),
+                        &res,
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(ListObjectAttributesError::InternalService(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListObjectAttributesError::InternalService(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidArnException" => {
-                    return RusotoError::Service(ListObjectAttributesError::InvalidArn(err.msg))
+                    return RusotoError::service(
+                        ListObjectAttributesError::InvalidArn(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(ListObjectAttributesError::InvalidNextToken(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListObjectAttributesError::InvalidNextToken(err.msg),
+                        &res,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(ListObjectAttributesError::LimitExceeded(err.msg))
+                    return RusotoError::service(
+                        ListObjectAttributesError::LimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListObjectAttributesError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListObjectAttributesError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "RetryableConflictException" => {
-                    return RusotoError::Service(ListObjectAttributesError::RetryableConflict(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListObjectAttributesError::RetryableConflict(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListObjectAttributesError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -6383,12 +6991,14 @@ impl fmt::Display for ListObjectAttributesError {
             ListObjectAttributesError::LimitExceeded(ref cause) => write!(f, "{}", cause),
             ListObjectAttributesError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             ListObjectAttributesError::RetryableConflict(ref cause) => write!(f, "{}", cause),
+            ListObjectAttributesError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListObjectAttributesError {}
 /// Errors returned by ListObjectChildren
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListObjectChildrenError {
     /// <p>Access denied. Check your permissions.</p>
     AccessDenied(String),
@@ -6408,6 +7018,8 @@ pub enum ListObjectChildrenError {
     ResourceNotFound(String),
     /// <p>Occurs when a conflict with a previous successful write is detected. For example, if a write operation occurs on an object and then an attempt is made to read the object using “SERIALIZABLE” consistency, this exception may result. This generally occurs when the previous write did not have time to propagate to the host serving the current request. A retry (with appropriate backoff logic) is the recommended response to this exception.</p>
     RetryableConflict(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListObjectChildrenError {
@@ -6415,38 +7027,55 @@ impl ListObjectChildrenError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(ListObjectChildrenError::AccessDenied(err.msg))
+                    return RusotoError::service(
+                        ListObjectChildrenError::AccessDenied(err.msg),
+                        &res,
+                    )
                 }
                 "DirectoryNotEnabledException" => {
-                    return RusotoError::Service(ListObjectChildrenError::DirectoryNotEnabled(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListObjectChildrenError::DirectoryNotEnabled(err.msg),
+                        &res,
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(ListObjectChildrenError::InternalService(err.msg))
+                    return RusotoError::service(
+                        ListObjectChildrenError::InternalService(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidArnException" => {
-                    return RusotoError::Service(ListObjectChildrenError::InvalidArn(err.msg))
+                    return RusotoError::service(ListObjectChildrenError::InvalidArn(err.msg), &res)
                 }
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(ListObjectChildrenError::InvalidNextToken(err.msg))
+                    return RusotoError::service(
+                        ListObjectChildrenError::InvalidNextToken(err.msg),
+                        &res,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(ListObjectChildrenError::LimitExceeded(err.msg))
+                    return RusotoError::service(
+                        ListObjectChildrenError::LimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "NotNodeException" => {
-                    return RusotoError::Service(ListObjectChildrenError::NotNode(err.msg))
+                    return RusotoError::service(ListObjectChildrenError::NotNode(err.msg), &res)
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListObjectChildrenError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        ListObjectChildrenError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "RetryableConflictException" => {
-                    return RusotoError::Service(ListObjectChildrenError::RetryableConflict(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListObjectChildrenError::RetryableConflict(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListObjectChildrenError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -6465,12 +7094,14 @@ impl fmt::Display for ListObjectChildrenError {
             ListObjectChildrenError::NotNode(ref cause) => write!(f, "{}", cause),
             ListObjectChildrenError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             ListObjectChildrenError::RetryableConflict(ref cause) => write!(f, "{}", cause),
+            ListObjectChildrenError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListObjectChildrenError {}
 /// Errors returned by ListObjectParentPaths
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListObjectParentPathsError {
     /// <p>Access denied. Check your permissions.</p>
     AccessDenied(String),
@@ -6488,6 +7119,8 @@ pub enum ListObjectParentPathsError {
     ResourceNotFound(String),
     /// <p>Occurs when a conflict with a previous successful write is detected. For example, if a write operation occurs on an object and then an attempt is made to read the object using “SERIALIZABLE” consistency, this exception may result. This generally occurs when the previous write did not have time to propagate to the host serving the current request. A retry (with appropriate backoff logic) is the recommended response to this exception.</p>
     RetryableConflict(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListObjectParentPathsError {
@@ -6495,41 +7128,55 @@ impl ListObjectParentPathsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(ListObjectParentPathsError::AccessDenied(err.msg))
+                    return RusotoError::service(
+                        ListObjectParentPathsError::AccessDenied(err.msg),
+                        &res,
+                    )
                 }
                 "DirectoryNotEnabledException" => {
-                    return RusotoError::Service(ListObjectParentPathsError::DirectoryNotEnabled(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListObjectParentPathsError::DirectoryNotEnabled(err.msg),
+                        &res,
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(ListObjectParentPathsError::InternalService(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListObjectParentPathsError::InternalService(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidArnException" => {
-                    return RusotoError::Service(ListObjectParentPathsError::InvalidArn(err.msg))
+                    return RusotoError::service(
+                        ListObjectParentPathsError::InvalidArn(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(ListObjectParentPathsError::InvalidNextToken(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListObjectParentPathsError::InvalidNextToken(err.msg),
+                        &res,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(ListObjectParentPathsError::LimitExceeded(err.msg))
+                    return RusotoError::service(
+                        ListObjectParentPathsError::LimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListObjectParentPathsError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListObjectParentPathsError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "RetryableConflictException" => {
-                    return RusotoError::Service(ListObjectParentPathsError::RetryableConflict(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListObjectParentPathsError::RetryableConflict(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListObjectParentPathsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -6547,12 +7194,14 @@ impl fmt::Display for ListObjectParentPathsError {
             ListObjectParentPathsError::LimitExceeded(ref cause) => write!(f, "{}", cause),
             ListObjectParentPathsError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             ListObjectParentPathsError::RetryableConflict(ref cause) => write!(f, "{}", cause),
+            ListObjectParentPathsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListObjectParentPathsError {}
 /// Errors returned by ListObjectParents
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListObjectParentsError {
     /// <p>Access denied. Check your permissions.</p>
     AccessDenied(String),
@@ -6572,6 +7221,8 @@ pub enum ListObjectParentsError {
     ResourceNotFound(String),
     /// <p>Occurs when a conflict with a previous successful write is detected. For example, if a write operation occurs on an object and then an attempt is made to read the object using “SERIALIZABLE” consistency, this exception may result. This generally occurs when the previous write did not have time to propagate to the host serving the current request. A retry (with appropriate backoff logic) is the recommended response to this exception.</p>
     RetryableConflict(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListObjectParentsError {
@@ -6579,38 +7230,58 @@ impl ListObjectParentsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(ListObjectParentsError::AccessDenied(err.msg))
+                    return RusotoError::service(
+                        ListObjectParentsError::AccessDenied(err.msg),
+                        &res,
+                    )
                 }
                 "CannotListParentOfRootException" => {
-                    return RusotoError::Service(ListObjectParentsError::CannotListParentOfRoot(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListObjectParentsError::CannotListParentOfRoot(err.msg),
+                        &res,
+                    )
                 }
                 "DirectoryNotEnabledException" => {
-                    return RusotoError::Service(ListObjectParentsError::DirectoryNotEnabled(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListObjectParentsError::DirectoryNotEnabled(err.msg),
+                        &res,
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(ListObjectParentsError::InternalService(err.msg))
+                    return RusotoError::service(
+                        ListObjectParentsError::InternalService(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidArnException" => {
-                    return RusotoError::Service(ListObjectParentsError::InvalidArn(err.msg))
+                    return RusotoError::service(ListObjectParentsError::InvalidArn(err.msg), &res)
                 }
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(ListObjectParentsError::InvalidNextToken(err.msg))
+                    return RusotoError::service(
+                        ListObjectParentsError::InvalidNextToken(err.msg),
+                        &res,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(ListObjectParentsError::LimitExceeded(err.msg))
+                    return RusotoError::service(
+                        ListObjectParentsError::LimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListObjectParentsError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        ListObjectParentsError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "RetryableConflictException" => {
-                    return RusotoError::Service(ListObjectParentsError::RetryableConflict(err.msg))
+                    return RusotoError::service(
+                        ListObjectParentsError::RetryableConflict(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListObjectParentsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -6629,12 +7300,14 @@ impl fmt::Display for ListObjectParentsError {
             ListObjectParentsError::LimitExceeded(ref cause) => write!(f, "{}", cause),
             ListObjectParentsError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             ListObjectParentsError::RetryableConflict(ref cause) => write!(f, "{}", cause),
+            ListObjectParentsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListObjectParentsError {}
 /// Errors returned by ListObjectPolicies
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListObjectPoliciesError {
     /// <p>Access denied. Check your permissions.</p>
     AccessDenied(String),
@@ -6652,6 +7325,8 @@ pub enum ListObjectPoliciesError {
     ResourceNotFound(String),
     /// <p>Occurs when a conflict with a previous successful write is detected. For example, if a write operation occurs on an object and then an attempt is made to read the object using “SERIALIZABLE” consistency, this exception may result. This generally occurs when the previous write did not have time to propagate to the host serving the current request. A retry (with appropriate backoff logic) is the recommended response to this exception.</p>
     RetryableConflict(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListObjectPoliciesError {
@@ -6659,35 +7334,52 @@ impl ListObjectPoliciesError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(ListObjectPoliciesError::AccessDenied(err.msg))
+                    return RusotoError::service(
+                        ListObjectPoliciesError::AccessDenied(err.msg),
+                        &res,
+                    )
                 }
                 "DirectoryNotEnabledException" => {
-                    return RusotoError::Service(ListObjectPoliciesError::DirectoryNotEnabled(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListObjectPoliciesError::DirectoryNotEnabled(err.msg),
+                        &res,
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(ListObjectPoliciesError::InternalService(err.msg))
+                    return RusotoError::service(
+                        ListObjectPoliciesError::InternalService(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidArnException" => {
-                    return RusotoError::Service(ListObjectPoliciesError::InvalidArn(err.msg))
+                    return RusotoError::service(ListObjectPoliciesError::InvalidArn(err.msg), &res)
                 }
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(ListObjectPoliciesError::InvalidNextToken(err.msg))
+                    return RusotoError::service(
+                        ListObjectPoliciesError::InvalidNextToken(err.msg),
+                        &res,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(ListObjectPoliciesError::LimitExceeded(err.msg))
+                    return RusotoError::service(
+                        ListObjectPoliciesError::LimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListObjectPoliciesError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        ListObjectPoliciesError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "RetryableConflictException" => {
-                    return RusotoError::Service(ListObjectPoliciesError::RetryableConflict(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListObjectPoliciesError::RetryableConflict(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListObjectPoliciesError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -6705,12 +7397,14 @@ impl fmt::Display for ListObjectPoliciesError {
             ListObjectPoliciesError::LimitExceeded(ref cause) => write!(f, "{}", cause),
             ListObjectPoliciesError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             ListObjectPoliciesError::RetryableConflict(ref cause) => write!(f, "{}", cause),
+            ListObjectPoliciesError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListObjectPoliciesError {}
 /// Errors returned by ListOutgoingTypedLinks
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListOutgoingTypedLinksError {
     /// <p>Access denied. Check your permissions.</p>
     AccessDenied(String),
@@ -6730,6 +7424,8 @@ pub enum ListOutgoingTypedLinksError {
     ResourceNotFound(String),
     /// <p>Occurs when a conflict with a previous successful write is detected. For example, if a write operation occurs on an object and then an attempt is made to read the object using “SERIALIZABLE” consistency, this exception may result. This generally occurs when the previous write did not have time to propagate to the host serving the current request. A retry (with appropriate backoff logic) is the recommended response to this exception.</p>
     RetryableConflict(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListOutgoingTypedLinksError {
@@ -6737,48 +7433,61 @@ impl ListOutgoingTypedLinksError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(ListOutgoingTypedLinksError::AccessDenied(err.msg))
+                    return RusotoError::service(
+                        ListOutgoingTypedLinksError::AccessDenied(err.msg),
+                        &res,
+                    )
                 }
                 "DirectoryNotEnabledException" => {
-                    return RusotoError::Service(ListOutgoingTypedLinksError::DirectoryNotEnabled(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListOutgoingTypedLinksError::DirectoryNotEnabled(err.msg),
+                        &res,
+                    )
                 }
                 "FacetValidationException" => {
-                    return RusotoError::Service(ListOutgoingTypedLinksError::FacetValidation(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListOutgoingTypedLinksError::FacetValidation(err.msg),
+                        &res,
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(ListOutgoingTypedLinksError::InternalService(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListOutgoingTypedLinksError::InternalService(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidArnException" => {
-                    return RusotoError::Service(ListOutgoingTypedLinksError::InvalidArn(err.msg))
+                    return RusotoError::service(
+                        ListOutgoingTypedLinksError::InvalidArn(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(ListOutgoingTypedLinksError::InvalidNextToken(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListOutgoingTypedLinksError::InvalidNextToken(err.msg),
+                        &res,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(ListOutgoingTypedLinksError::LimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListOutgoingTypedLinksError::LimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListOutgoingTypedLinksError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListOutgoingTypedLinksError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "RetryableConflictException" => {
-                    return RusotoError::Service(ListOutgoingTypedLinksError::RetryableConflict(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListOutgoingTypedLinksError::RetryableConflict(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListOutgoingTypedLinksError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -6797,12 +7506,14 @@ impl fmt::Display for ListOutgoingTypedLinksError {
             ListOutgoingTypedLinksError::LimitExceeded(ref cause) => write!(f, "{}", cause),
             ListOutgoingTypedLinksError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             ListOutgoingTypedLinksError::RetryableConflict(ref cause) => write!(f, "{}", cause),
+            ListOutgoingTypedLinksError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListOutgoingTypedLinksError {}
 /// Errors returned by ListPolicyAttachments
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListPolicyAttachmentsError {
     /// <p>Access denied. Check your permissions.</p>
     AccessDenied(String),
@@ -6822,6 +7533,8 @@ pub enum ListPolicyAttachmentsError {
     ResourceNotFound(String),
     /// <p>Occurs when a conflict with a previous successful write is detected. For example, if a write operation occurs on an object and then an attempt is made to read the object using “SERIALIZABLE” consistency, this exception may result. This generally occurs when the previous write did not have time to propagate to the host serving the current request. A retry (with appropriate backoff logic) is the recommended response to this exception.</p>
     RetryableConflict(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListPolicyAttachmentsError {
@@ -6829,44 +7542,61 @@ impl ListPolicyAttachmentsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(ListPolicyAttachmentsError::AccessDenied(err.msg))
+                    return RusotoError::service(
+                        ListPolicyAttachmentsError::AccessDenied(err.msg),
+                        &res,
+                    )
                 }
                 "DirectoryNotEnabledException" => {
-                    return RusotoError::Service(ListPolicyAttachmentsError::DirectoryNotEnabled(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListPolicyAttachmentsError::DirectoryNotEnabled(err.msg),
+                        &res,
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(ListPolicyAttachmentsError::InternalService(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListPolicyAttachmentsError::InternalService(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidArnException" => {
-                    return RusotoError::Service(ListPolicyAttachmentsError::InvalidArn(err.msg))
+                    return RusotoError::service(
+                        ListPolicyAttachmentsError::InvalidArn(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(ListPolicyAttachmentsError::InvalidNextToken(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListPolicyAttachmentsError::InvalidNextToken(err.msg),
+                        &res,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(ListPolicyAttachmentsError::LimitExceeded(err.msg))
+                    return RusotoError::service(
+                        ListPolicyAttachmentsError::LimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "NotPolicyException" => {
-                    return RusotoError::Service(ListPolicyAttachmentsError::NotPolicy(err.msg))
+                    return RusotoError::service(
+                        ListPolicyAttachmentsError::NotPolicy(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListPolicyAttachmentsError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListPolicyAttachmentsError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "RetryableConflictException" => {
-                    return RusotoError::Service(ListPolicyAttachmentsError::RetryableConflict(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListPolicyAttachmentsError::RetryableConflict(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListPolicyAttachmentsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -6885,12 +7615,14 @@ impl fmt::Display for ListPolicyAttachmentsError {
             ListPolicyAttachmentsError::NotPolicy(ref cause) => write!(f, "{}", cause),
             ListPolicyAttachmentsError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             ListPolicyAttachmentsError::RetryableConflict(ref cause) => write!(f, "{}", cause),
+            ListPolicyAttachmentsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListPolicyAttachmentsError {}
 /// Errors returned by ListPublishedSchemaArns
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListPublishedSchemaArnsError {
     /// <p>Access denied. Check your permissions.</p>
     AccessDenied(String),
@@ -6906,6 +7638,8 @@ pub enum ListPublishedSchemaArnsError {
     ResourceNotFound(String),
     /// <p>Occurs when a conflict with a previous successful write is detected. For example, if a write operation occurs on an object and then an attempt is made to read the object using “SERIALIZABLE” consistency, this exception may result. This generally occurs when the previous write did not have time to propagate to the host serving the current request. A retry (with appropriate backoff logic) is the recommended response to this exception.</p>
     RetryableConflict(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListPublishedSchemaArnsError {
@@ -6913,40 +7647,49 @@ impl ListPublishedSchemaArnsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(ListPublishedSchemaArnsError::AccessDenied(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListPublishedSchemaArnsError::AccessDenied(err.msg),
+                        &res,
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(ListPublishedSchemaArnsError::InternalService(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListPublishedSchemaArnsError::InternalService(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidArnException" => {
-                    return RusotoError::Service(ListPublishedSchemaArnsError::InvalidArn(err.msg))
+                    return RusotoError::service(
+                        ListPublishedSchemaArnsError::InvalidArn(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(ListPublishedSchemaArnsError::InvalidNextToken(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListPublishedSchemaArnsError::InvalidNextToken(err.msg),
+                        &res,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(ListPublishedSchemaArnsError::LimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListPublishedSchemaArnsError::LimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListPublishedSchemaArnsError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListPublishedSchemaArnsError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "RetryableConflictException" => {
-                    return RusotoError::Service(ListPublishedSchemaArnsError::RetryableConflict(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListPublishedSchemaArnsError::RetryableConflict(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListPublishedSchemaArnsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -6963,12 +7706,14 @@ impl fmt::Display for ListPublishedSchemaArnsError {
             ListPublishedSchemaArnsError::LimitExceeded(ref cause) => write!(f, "{}", cause),
             ListPublishedSchemaArnsError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             ListPublishedSchemaArnsError::RetryableConflict(ref cause) => write!(f, "{}", cause),
+            ListPublishedSchemaArnsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListPublishedSchemaArnsError {}
 /// Errors returned by ListTagsForResource
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListTagsForResourceError {
     /// <p>Access denied. Check your permissions.</p>
     AccessDenied(String),
@@ -6984,6 +7729,8 @@ pub enum ListTagsForResourceError {
     ResourceNotFound(String),
     /// <p>Occurs when a conflict with a previous successful write is detected. For example, if a write operation occurs on an object and then an attempt is made to read the object using “SERIALIZABLE” consistency, this exception may result. This generally occurs when the previous write did not have time to propagate to the host serving the current request. A retry (with appropriate backoff logic) is the recommended response to this exception.</p>
     RetryableConflict(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListTagsForResourceError {
@@ -6991,34 +7738,49 @@ impl ListTagsForResourceError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(ListTagsForResourceError::AccessDenied(err.msg))
+                    return RusotoError::service(
+                        ListTagsForResourceError::AccessDenied(err.msg),
+                        &res,
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(ListTagsForResourceError::InternalService(err.msg))
+                    return RusotoError::service(
+                        ListTagsForResourceError::InternalService(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidArnException" => {
-                    return RusotoError::Service(ListTagsForResourceError::InvalidArn(err.msg))
+                    return RusotoError::service(
+                        ListTagsForResourceError::InvalidArn(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidTaggingRequestException" => {
-                    return RusotoError::Service(ListTagsForResourceError::InvalidTaggingRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListTagsForResourceError::InvalidTaggingRequest(err.msg),
+                        &res,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(ListTagsForResourceError::LimitExceeded(err.msg))
+                    return RusotoError::service(
+                        ListTagsForResourceError::LimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListTagsForResourceError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListTagsForResourceError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "RetryableConflictException" => {
-                    return RusotoError::Service(ListTagsForResourceError::RetryableConflict(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListTagsForResourceError::RetryableConflict(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListTagsForResourceError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -7035,12 +7797,14 @@ impl fmt::Display for ListTagsForResourceError {
             ListTagsForResourceError::LimitExceeded(ref cause) => write!(f, "{}", cause),
             ListTagsForResourceError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             ListTagsForResourceError::RetryableConflict(ref cause) => write!(f, "{}", cause),
+            ListTagsForResourceError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListTagsForResourceError {}
 /// Errors returned by ListTypedLinkFacetAttributes
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListTypedLinkFacetAttributesError {
     /// <p>Access denied. Check your permissions.</p>
     AccessDenied(String),
@@ -7058,6 +7822,8 @@ pub enum ListTypedLinkFacetAttributesError {
     ResourceNotFound(String),
     /// <p>Occurs when a conflict with a previous successful write is detected. For example, if a write operation occurs on an object and then an attempt is made to read the object using “SERIALIZABLE” consistency, this exception may result. This generally occurs when the previous write did not have time to propagate to the host serving the current request. A retry (with appropriate backoff logic) is the recommended response to this exception.</p>
     RetryableConflict(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListTypedLinkFacetAttributesError {
@@ -7067,47 +7833,60 @@ impl ListTypedLinkFacetAttributesError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(ListTypedLinkFacetAttributesError::AccessDenied(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListTypedLinkFacetAttributesError::AccessDenied(err.msg),
+                        &res,
+                    )
                 }
                 "FacetNotFoundException" => {
-                    return RusotoError::Service(ListTypedLinkFacetAttributesError::FacetNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListTypedLinkFacetAttributesError::FacetNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         ListTypedLinkFacetAttributesError::InternalService(err.msg),
+                        &res,
                     )
                 }
                 "InvalidArnException" => {
-                    return RusotoError::Service(ListTypedLinkFacetAttributesError::InvalidArn(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListTypedLinkFacetAttributesError::InvalidArn(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         ListTypedLinkFacetAttributesError::InvalidNextToken(err.msg),
+                        &res,
                     )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(ListTypedLinkFacetAttributesError::LimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListTypedLinkFacetAttributesError::LimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         ListTypedLinkFacetAttributesError::ResourceNotFound(err.msg),
+                        &res,
                     )
                 }
                 "RetryableConflictException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         ListTypedLinkFacetAttributesError::RetryableConflict(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        ListTypedLinkFacetAttributesError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -7131,12 +7910,14 @@ impl fmt::Display for ListTypedLinkFacetAttributesError {
             ListTypedLinkFacetAttributesError::RetryableConflict(ref cause) => {
                 write!(f, "{}", cause)
             }
+            ListTypedLinkFacetAttributesError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListTypedLinkFacetAttributesError {}
 /// Errors returned by ListTypedLinkFacetNames
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListTypedLinkFacetNamesError {
     /// <p>Access denied. Check your permissions.</p>
     AccessDenied(String),
@@ -7152,6 +7933,8 @@ pub enum ListTypedLinkFacetNamesError {
     ResourceNotFound(String),
     /// <p>Occurs when a conflict with a previous successful write is detected. For example, if a write operation occurs on an object and then an attempt is made to read the object using “SERIALIZABLE” consistency, this exception may result. This generally occurs when the previous write did not have time to propagate to the host serving the current request. A retry (with appropriate backoff logic) is the recommended response to this exception.</p>
     RetryableConflict(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListTypedLinkFacetNamesError {
@@ -7159,40 +7942,49 @@ impl ListTypedLinkFacetNamesError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(ListTypedLinkFacetNamesError::AccessDenied(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListTypedLinkFacetNamesError::AccessDenied(err.msg),
+                        &res,
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(ListTypedLinkFacetNamesError::InternalService(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListTypedLinkFacetNamesError::InternalService(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidArnException" => {
-                    return RusotoError::Service(ListTypedLinkFacetNamesError::InvalidArn(err.msg))
+                    return RusotoError::service(
+                        ListTypedLinkFacetNamesError::InvalidArn(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(ListTypedLinkFacetNamesError::InvalidNextToken(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListTypedLinkFacetNamesError::InvalidNextToken(err.msg),
+                        &res,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(ListTypedLinkFacetNamesError::LimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListTypedLinkFacetNamesError::LimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListTypedLinkFacetNamesError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListTypedLinkFacetNamesError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "RetryableConflictException" => {
-                    return RusotoError::Service(ListTypedLinkFacetNamesError::RetryableConflict(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListTypedLinkFacetNamesError::RetryableConflict(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListTypedLinkFacetNamesError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -7209,12 +8001,14 @@ impl fmt::Display for ListTypedLinkFacetNamesError {
             ListTypedLinkFacetNamesError::LimitExceeded(ref cause) => write!(f, "{}", cause),
             ListTypedLinkFacetNamesError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             ListTypedLinkFacetNamesError::RetryableConflict(ref cause) => write!(f, "{}", cause),
+            ListTypedLinkFacetNamesError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListTypedLinkFacetNamesError {}
 /// Errors returned by LookupPolicy
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum LookupPolicyError {
     /// <p>Access denied. Check your permissions.</p>
     AccessDenied(String),
@@ -7232,6 +8026,8 @@ pub enum LookupPolicyError {
     ResourceNotFound(String),
     /// <p>Occurs when a conflict with a previous successful write is detected. For example, if a write operation occurs on an object and then an attempt is made to read the object using “SERIALIZABLE” consistency, this exception may result. This generally occurs when the previous write did not have time to propagate to the host serving the current request. A retry (with appropriate backoff logic) is the recommended response to this exception.</p>
     RetryableConflict(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl LookupPolicyError {
@@ -7239,31 +8035,37 @@ impl LookupPolicyError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(LookupPolicyError::AccessDenied(err.msg))
+                    return RusotoError::service(LookupPolicyError::AccessDenied(err.msg), &res)
                 }
                 "DirectoryNotEnabledException" => {
-                    return RusotoError::Service(LookupPolicyError::DirectoryNotEnabled(err.msg))
+                    return RusotoError::service(
+                        LookupPolicyError::DirectoryNotEnabled(err.msg),
+                        &res,
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(LookupPolicyError::InternalService(err.msg))
+                    return RusotoError::service(LookupPolicyError::InternalService(err.msg), &res)
                 }
                 "InvalidArnException" => {
-                    return RusotoError::Service(LookupPolicyError::InvalidArn(err.msg))
+                    return RusotoError::service(LookupPolicyError::InvalidArn(err.msg), &res)
                 }
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(LookupPolicyError::InvalidNextToken(err.msg))
+                    return RusotoError::service(LookupPolicyError::InvalidNextToken(err.msg), &res)
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(LookupPolicyError::LimitExceeded(err.msg))
+                    return RusotoError::service(LookupPolicyError::LimitExceeded(err.msg), &res)
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(LookupPolicyError::ResourceNotFound(err.msg))
+                    return RusotoError::service(LookupPolicyError::ResourceNotFound(err.msg), &res)
                 }
                 "RetryableConflictException" => {
-                    return RusotoError::Service(LookupPolicyError::RetryableConflict(err.msg))
+                    return RusotoError::service(
+                        LookupPolicyError::RetryableConflict(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(LookupPolicyError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -7281,12 +8083,14 @@ impl fmt::Display for LookupPolicyError {
             LookupPolicyError::LimitExceeded(ref cause) => write!(f, "{}", cause),
             LookupPolicyError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             LookupPolicyError::RetryableConflict(ref cause) => write!(f, "{}", cause),
+            LookupPolicyError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for LookupPolicyError {}
 /// Errors returned by PublishSchema
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum PublishSchemaError {
     /// <p>Access denied. Check your permissions.</p>
     AccessDenied(String),
@@ -7302,6 +8106,8 @@ pub enum PublishSchemaError {
     RetryableConflict(String),
     /// <p>Indicates that a schema is already published.</p>
     SchemaAlreadyPublished(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl PublishSchemaError {
@@ -7309,30 +8115,37 @@ impl PublishSchemaError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(PublishSchemaError::AccessDenied(err.msg))
+                    return RusotoError::service(PublishSchemaError::AccessDenied(err.msg), &res)
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(PublishSchemaError::InternalService(err.msg))
+                    return RusotoError::service(PublishSchemaError::InternalService(err.msg), &res)
                 }
                 "InvalidArnException" => {
-                    return RusotoError::Service(PublishSchemaError::InvalidArn(err.msg))
+                    return RusotoError::service(PublishSchemaError::InvalidArn(err.msg), &res)
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(PublishSchemaError::LimitExceeded(err.msg))
+                    return RusotoError::service(PublishSchemaError::LimitExceeded(err.msg), &res)
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(PublishSchemaError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        PublishSchemaError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "RetryableConflictException" => {
-                    return RusotoError::Service(PublishSchemaError::RetryableConflict(err.msg))
+                    return RusotoError::service(
+                        PublishSchemaError::RetryableConflict(err.msg),
+                        &res,
+                    )
                 }
                 "SchemaAlreadyPublishedException" => {
-                    return RusotoError::Service(PublishSchemaError::SchemaAlreadyPublished(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        PublishSchemaError::SchemaAlreadyPublished(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(PublishSchemaError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -7349,12 +8162,14 @@ impl fmt::Display for PublishSchemaError {
             PublishSchemaError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             PublishSchemaError::RetryableConflict(ref cause) => write!(f, "{}", cause),
             PublishSchemaError::SchemaAlreadyPublished(ref cause) => write!(f, "{}", cause),
+            PublishSchemaError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for PublishSchemaError {}
 /// Errors returned by PutSchemaFromJson
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum PutSchemaFromJsonError {
     /// <p>Access denied. Check your permissions.</p>
     AccessDenied(String),
@@ -7370,6 +8185,8 @@ pub enum PutSchemaFromJsonError {
     LimitExceeded(String),
     /// <p>Occurs when a conflict with a previous successful write is detected. For example, if a write operation occurs on an object and then an attempt is made to read the object using “SERIALIZABLE” consistency, this exception may result. This generally occurs when the previous write did not have time to propagate to the host serving the current request. A retry (with appropriate backoff logic) is the recommended response to this exception.</p>
     RetryableConflict(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl PutSchemaFromJsonError {
@@ -7377,28 +8194,43 @@ impl PutSchemaFromJsonError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(PutSchemaFromJsonError::AccessDenied(err.msg))
+                    return RusotoError::service(
+                        PutSchemaFromJsonError::AccessDenied(err.msg),
+                        &res,
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(PutSchemaFromJsonError::InternalService(err.msg))
+                    return RusotoError::service(
+                        PutSchemaFromJsonError::InternalService(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidArnException" => {
-                    return RusotoError::Service(PutSchemaFromJsonError::InvalidArn(err.msg))
+                    return RusotoError::service(PutSchemaFromJsonError::InvalidArn(err.msg), &res)
                 }
                 "InvalidRuleException" => {
-                    return RusotoError::Service(PutSchemaFromJsonError::InvalidRule(err.msg))
+                    return RusotoError::service(PutSchemaFromJsonError::InvalidRule(err.msg), &res)
                 }
                 "InvalidSchemaDocException" => {
-                    return RusotoError::Service(PutSchemaFromJsonError::InvalidSchemaDoc(err.msg))
+                    return RusotoError::service(
+                        PutSchemaFromJsonError::InvalidSchemaDoc(err.msg),
+                        &res,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(PutSchemaFromJsonError::LimitExceeded(err.msg))
+                    return RusotoError::service(
+                        PutSchemaFromJsonError::LimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "RetryableConflictException" => {
-                    return RusotoError::Service(PutSchemaFromJsonError::RetryableConflict(err.msg))
+                    return RusotoError::service(
+                        PutSchemaFromJsonError::RetryableConflict(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(PutSchemaFromJsonError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -7415,12 +8247,14 @@ impl fmt::Display for PutSchemaFromJsonError {
             PutSchemaFromJsonError::InvalidSchemaDoc(ref cause) => write!(f, "{}", cause),
             PutSchemaFromJsonError::LimitExceeded(ref cause) => write!(f, "{}", cause),
             PutSchemaFromJsonError::RetryableConflict(ref cause) => write!(f, "{}", cause),
+            PutSchemaFromJsonError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for PutSchemaFromJsonError {}
 /// Errors returned by RemoveFacetFromObject
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum RemoveFacetFromObjectError {
     /// <p>Access denied. Check your permissions.</p>
     AccessDenied(String),
@@ -7438,6 +8272,8 @@ pub enum RemoveFacetFromObjectError {
     ResourceNotFound(String),
     /// <p>Occurs when a conflict with a previous successful write is detected. For example, if a write operation occurs on an object and then an attempt is made to read the object using “SERIALIZABLE” consistency, this exception may result. This generally occurs when the previous write did not have time to propagate to the host serving the current request. A retry (with appropriate backoff logic) is the recommended response to this exception.</p>
     RetryableConflict(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl RemoveFacetFromObjectError {
@@ -7445,41 +8281,55 @@ impl RemoveFacetFromObjectError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(RemoveFacetFromObjectError::AccessDenied(err.msg))
+                    return RusotoError::service(
+                        RemoveFacetFromObjectError::AccessDenied(err.msg),
+                        &res,
+                    )
                 }
                 "DirectoryNotEnabledException" => {
-                    return RusotoError::Service(RemoveFacetFromObjectError::DirectoryNotEnabled(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        RemoveFacetFromObjectError::DirectoryNotEnabled(err.msg),
+                        &res,
+                    )
                 }
                 "FacetValidationException" => {
-                    return RusotoError::Service(RemoveFacetFromObjectError::FacetValidation(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        RemoveFacetFromObjectError::FacetValidation(err.msg),
+                        &res,
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(RemoveFacetFromObjectError::InternalService(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        RemoveFacetFromObjectError::InternalService(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidArnException" => {
-                    return RusotoError::Service(RemoveFacetFromObjectError::InvalidArn(err.msg))
+                    return RusotoError::service(
+                        RemoveFacetFromObjectError::InvalidArn(err.msg),
+                        &res,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(RemoveFacetFromObjectError::LimitExceeded(err.msg))
+                    return RusotoError::service(
+                        RemoveFacetFromObjectError::LimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(RemoveFacetFromObjectError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        RemoveFacetFromObjectError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "RetryableConflictException" => {
-                    return RusotoError::Service(RemoveFacetFromObjectError::RetryableConflict(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        RemoveFacetFromObjectError::RetryableConflict(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(RemoveFacetFromObjectError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -7497,12 +8347,14 @@ impl fmt::Display for RemoveFacetFromObjectError {
             RemoveFacetFromObjectError::LimitExceeded(ref cause) => write!(f, "{}", cause),
             RemoveFacetFromObjectError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             RemoveFacetFromObjectError::RetryableConflict(ref cause) => write!(f, "{}", cause),
+            RemoveFacetFromObjectError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for RemoveFacetFromObjectError {}
 /// Errors returned by TagResource
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum TagResourceError {
     /// <p>Access denied. Check your permissions.</p>
     AccessDenied(String),
@@ -7518,6 +8370,8 @@ pub enum TagResourceError {
     ResourceNotFound(String),
     /// <p>Occurs when a conflict with a previous successful write is detected. For example, if a write operation occurs on an object and then an attempt is made to read the object using “SERIALIZABLE” consistency, this exception may result. This generally occurs when the previous write did not have time to propagate to the host serving the current request. A retry (with appropriate backoff logic) is the recommended response to this exception.</p>
     RetryableConflict(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl TagResourceError {
@@ -7525,28 +8379,31 @@ impl TagResourceError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(TagResourceError::AccessDenied(err.msg))
+                    return RusotoError::service(TagResourceError::AccessDenied(err.msg), &res)
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(TagResourceError::InternalService(err.msg))
+                    return RusotoError::service(TagResourceError::InternalService(err.msg), &res)
                 }
                 "InvalidArnException" => {
-                    return RusotoError::Service(TagResourceError::InvalidArn(err.msg))
+                    return RusotoError::service(TagResourceError::InvalidArn(err.msg), &res)
                 }
                 "InvalidTaggingRequestException" => {
-                    return RusotoError::Service(TagResourceError::InvalidTaggingRequest(err.msg))
+                    return RusotoError::service(
+                        TagResourceError::InvalidTaggingRequest(err.msg),
+                        &res,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(TagResourceError::LimitExceeded(err.msg))
+                    return RusotoError::service(TagResourceError::LimitExceeded(err.msg), &res)
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(TagResourceError::ResourceNotFound(err.msg))
+                    return RusotoError::service(TagResourceError::ResourceNotFound(err.msg), &res)
                 }
                 "RetryableConflictException" => {
-                    return RusotoError::Service(TagResourceError::RetryableConflict(err.msg))
+                    return RusotoError::service(TagResourceError::RetryableConflict(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(TagResourceError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -7563,12 +8420,14 @@ impl fmt::Display for TagResourceError {
             TagResourceError::LimitExceeded(ref cause) => write!(f, "{}", cause),
             TagResourceError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             TagResourceError::RetryableConflict(ref cause) => write!(f, "{}", cause),
+            TagResourceError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for TagResourceError {}
 /// Errors returned by UntagResource
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UntagResourceError {
     /// <p>Access denied. Check your permissions.</p>
     AccessDenied(String),
@@ -7584,6 +8443,8 @@ pub enum UntagResourceError {
     ResourceNotFound(String),
     /// <p>Occurs when a conflict with a previous successful write is detected. For example, if a write operation occurs on an object and then an attempt is made to read the object using “SERIALIZABLE” consistency, this exception may result. This generally occurs when the previous write did not have time to propagate to the host serving the current request. A retry (with appropriate backoff logic) is the recommended response to this exception.</p>
     RetryableConflict(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UntagResourceError {
@@ -7591,28 +8452,37 @@ impl UntagResourceError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(UntagResourceError::AccessDenied(err.msg))
+                    return RusotoError::service(UntagResourceError::AccessDenied(err.msg), &res)
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(UntagResourceError::InternalService(err.msg))
+                    return RusotoError::service(UntagResourceError::InternalService(err.msg), &res)
                 }
                 "InvalidArnException" => {
-                    return RusotoError::Service(UntagResourceError::InvalidArn(err.msg))
+                    return RusotoError::service(UntagResourceError::InvalidArn(err.msg), &res)
                 }
                 "InvalidTaggingRequestException" => {
-                    return RusotoError::Service(UntagResourceError::InvalidTaggingRequest(err.msg))
+                    return RusotoError::service(
+                        UntagResourceError::InvalidTaggingRequest(err.msg),
+                        &res,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(UntagResourceError::LimitExceeded(err.msg))
+                    return RusotoError::service(UntagResourceError::LimitExceeded(err.msg), &res)
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UntagResourceError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        UntagResourceError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "RetryableConflictException" => {
-                    return RusotoError::Service(UntagResourceError::RetryableConflict(err.msg))
+                    return RusotoError::service(
+                        UntagResourceError::RetryableConflict(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UntagResourceError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -7629,12 +8499,14 @@ impl fmt::Display for UntagResourceError {
             UntagResourceError::LimitExceeded(ref cause) => write!(f, "{}", cause),
             UntagResourceError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             UntagResourceError::RetryableConflict(ref cause) => write!(f, "{}", cause),
+            UntagResourceError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for UntagResourceError {}
 /// Errors returned by UpdateFacet
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdateFacetError {
     /// <p>Access denied. Check your permissions.</p>
     AccessDenied(String),
@@ -7656,6 +8528,8 @@ pub enum UpdateFacetError {
     ResourceNotFound(String),
     /// <p>Occurs when a conflict with a previous successful write is detected. For example, if a write operation occurs on an object and then an attempt is made to read the object using “SERIALIZABLE” consistency, this exception may result. This generally occurs when the previous write did not have time to propagate to the host serving the current request. A retry (with appropriate backoff logic) is the recommended response to this exception.</p>
     RetryableConflict(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdateFacetError {
@@ -7663,37 +8537,40 @@ impl UpdateFacetError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(UpdateFacetError::AccessDenied(err.msg))
+                    return RusotoError::service(UpdateFacetError::AccessDenied(err.msg), &res)
                 }
                 "FacetNotFoundException" => {
-                    return RusotoError::Service(UpdateFacetError::FacetNotFound(err.msg))
+                    return RusotoError::service(UpdateFacetError::FacetNotFound(err.msg), &res)
                 }
                 "FacetValidationException" => {
-                    return RusotoError::Service(UpdateFacetError::FacetValidation(err.msg))
+                    return RusotoError::service(UpdateFacetError::FacetValidation(err.msg), &res)
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(UpdateFacetError::InternalService(err.msg))
+                    return RusotoError::service(UpdateFacetError::InternalService(err.msg), &res)
                 }
                 "InvalidArnException" => {
-                    return RusotoError::Service(UpdateFacetError::InvalidArn(err.msg))
+                    return RusotoError::service(UpdateFacetError::InvalidArn(err.msg), &res)
                 }
                 "InvalidFacetUpdateException" => {
-                    return RusotoError::Service(UpdateFacetError::InvalidFacetUpdate(err.msg))
+                    return RusotoError::service(
+                        UpdateFacetError::InvalidFacetUpdate(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidRuleException" => {
-                    return RusotoError::Service(UpdateFacetError::InvalidRule(err.msg))
+                    return RusotoError::service(UpdateFacetError::InvalidRule(err.msg), &res)
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(UpdateFacetError::LimitExceeded(err.msg))
+                    return RusotoError::service(UpdateFacetError::LimitExceeded(err.msg), &res)
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateFacetError::ResourceNotFound(err.msg))
+                    return RusotoError::service(UpdateFacetError::ResourceNotFound(err.msg), &res)
                 }
                 "RetryableConflictException" => {
-                    return RusotoError::Service(UpdateFacetError::RetryableConflict(err.msg))
+                    return RusotoError::service(UpdateFacetError::RetryableConflict(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UpdateFacetError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -7713,12 +8590,14 @@ impl fmt::Display for UpdateFacetError {
             UpdateFacetError::LimitExceeded(ref cause) => write!(f, "{}", cause),
             UpdateFacetError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             UpdateFacetError::RetryableConflict(ref cause) => write!(f, "{}", cause),
+            UpdateFacetError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for UpdateFacetError {}
 /// Errors returned by UpdateLinkAttributes
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdateLinkAttributesError {
     /// <p>Access denied. Check your permissions.</p>
     AccessDenied(String),
@@ -7736,6 +8615,8 @@ pub enum UpdateLinkAttributesError {
     ResourceNotFound(String),
     /// <p>Occurs when a conflict with a previous successful write is detected. For example, if a write operation occurs on an object and then an attempt is made to read the object using “SERIALIZABLE” consistency, this exception may result. This generally occurs when the previous write did not have time to propagate to the host serving the current request. A retry (with appropriate backoff logic) is the recommended response to this exception.</p>
     RetryableConflict(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdateLinkAttributesError {
@@ -7743,41 +8624,55 @@ impl UpdateLinkAttributesError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(UpdateLinkAttributesError::AccessDenied(err.msg))
+                    return RusotoError::service(
+                        UpdateLinkAttributesError::AccessDenied(err.msg),
+                        &res,
+                    )
                 }
                 "DirectoryNotEnabledException" => {
-                    return RusotoError::Service(UpdateLinkAttributesError::DirectoryNotEnabled(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateLinkAttributesError::DirectoryNotEnabled(err.msg),
+                        &res,
+                    )
                 }
                 "FacetValidationException" => {
-                    return RusotoError::Service(UpdateLinkAttributesError::FacetValidation(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateLinkAttributesError::FacetValidation(err.msg),
+                        &res,
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(UpdateLinkAttributesError::InternalService(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateLinkAttributesError::InternalService(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidArnException" => {
-                    return RusotoError::Service(UpdateLinkAttributesError::InvalidArn(err.msg))
+                    return RusotoError::service(
+                        UpdateLinkAttributesError::InvalidArn(err.msg),
+                        &res,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(UpdateLinkAttributesError::LimitExceeded(err.msg))
+                    return RusotoError::service(
+                        UpdateLinkAttributesError::LimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateLinkAttributesError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateLinkAttributesError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "RetryableConflictException" => {
-                    return RusotoError::Service(UpdateLinkAttributesError::RetryableConflict(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateLinkAttributesError::RetryableConflict(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UpdateLinkAttributesError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -7795,12 +8690,14 @@ impl fmt::Display for UpdateLinkAttributesError {
             UpdateLinkAttributesError::LimitExceeded(ref cause) => write!(f, "{}", cause),
             UpdateLinkAttributesError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             UpdateLinkAttributesError::RetryableConflict(ref cause) => write!(f, "{}", cause),
+            UpdateLinkAttributesError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for UpdateLinkAttributesError {}
 /// Errors returned by UpdateObjectAttributes
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdateObjectAttributesError {
     /// <p>Access denied. Check your permissions.</p>
     AccessDenied(String),
@@ -7820,6 +8717,8 @@ pub enum UpdateObjectAttributesError {
     ResourceNotFound(String),
     /// <p>Occurs when a conflict with a previous successful write is detected. For example, if a write operation occurs on an object and then an attempt is made to read the object using “SERIALIZABLE” consistency, this exception may result. This generally occurs when the previous write did not have time to propagate to the host serving the current request. A retry (with appropriate backoff logic) is the recommended response to this exception.</p>
     RetryableConflict(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdateObjectAttributesError {
@@ -7827,48 +8726,61 @@ impl UpdateObjectAttributesError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(UpdateObjectAttributesError::AccessDenied(err.msg))
+                    return RusotoError::service(
+                        UpdateObjectAttributesError::AccessDenied(err.msg),
+                        &res,
+                    )
                 }
                 "DirectoryNotEnabledException" => {
-                    return RusotoError::Service(UpdateObjectAttributesError::DirectoryNotEnabled(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateObjectAttributesError::DirectoryNotEnabled(err.msg),
+                        &res,
+                    )
                 }
                 "FacetValidationException" => {
-                    return RusotoError::Service(UpdateObjectAttributesError::FacetValidation(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateObjectAttributesError::FacetValidation(err.msg),
+                        &res,
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(UpdateObjectAttributesError::InternalService(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateObjectAttributesError::InternalService(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidArnException" => {
-                    return RusotoError::Service(UpdateObjectAttributesError::InvalidArn(err.msg))
+                    return RusotoError::service(
+                        UpdateObjectAttributesError::InvalidArn(err.msg),
+                        &res,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(UpdateObjectAttributesError::LimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateObjectAttributesError::LimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "LinkNameAlreadyInUseException" => {
-                    return RusotoError::Service(UpdateObjectAttributesError::LinkNameAlreadyInUse(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateObjectAttributesError::LinkNameAlreadyInUse(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateObjectAttributesError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateObjectAttributesError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "RetryableConflictException" => {
-                    return RusotoError::Service(UpdateObjectAttributesError::RetryableConflict(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateObjectAttributesError::RetryableConflict(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UpdateObjectAttributesError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -7887,12 +8799,14 @@ impl fmt::Display for UpdateObjectAttributesError {
             UpdateObjectAttributesError::LinkNameAlreadyInUse(ref cause) => write!(f, "{}", cause),
             UpdateObjectAttributesError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             UpdateObjectAttributesError::RetryableConflict(ref cause) => write!(f, "{}", cause),
+            UpdateObjectAttributesError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for UpdateObjectAttributesError {}
 /// Errors returned by UpdateSchema
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdateSchemaError {
     /// <p>Access denied. Check your permissions.</p>
     AccessDenied(String),
@@ -7906,6 +8820,8 @@ pub enum UpdateSchemaError {
     ResourceNotFound(String),
     /// <p>Occurs when a conflict with a previous successful write is detected. For example, if a write operation occurs on an object and then an attempt is made to read the object using “SERIALIZABLE” consistency, this exception may result. This generally occurs when the previous write did not have time to propagate to the host serving the current request. A retry (with appropriate backoff logic) is the recommended response to this exception.</p>
     RetryableConflict(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdateSchemaError {
@@ -7913,25 +8829,28 @@ impl UpdateSchemaError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(UpdateSchemaError::AccessDenied(err.msg))
+                    return RusotoError::service(UpdateSchemaError::AccessDenied(err.msg), &res)
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(UpdateSchemaError::InternalService(err.msg))
+                    return RusotoError::service(UpdateSchemaError::InternalService(err.msg), &res)
                 }
                 "InvalidArnException" => {
-                    return RusotoError::Service(UpdateSchemaError::InvalidArn(err.msg))
+                    return RusotoError::service(UpdateSchemaError::InvalidArn(err.msg), &res)
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(UpdateSchemaError::LimitExceeded(err.msg))
+                    return RusotoError::service(UpdateSchemaError::LimitExceeded(err.msg), &res)
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateSchemaError::ResourceNotFound(err.msg))
+                    return RusotoError::service(UpdateSchemaError::ResourceNotFound(err.msg), &res)
                 }
                 "RetryableConflictException" => {
-                    return RusotoError::Service(UpdateSchemaError::RetryableConflict(err.msg))
+                    return RusotoError::service(
+                        UpdateSchemaError::RetryableConflict(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UpdateSchemaError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -7947,12 +8866,14 @@ impl fmt::Display for UpdateSchemaError {
             UpdateSchemaError::LimitExceeded(ref cause) => write!(f, "{}", cause),
             UpdateSchemaError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             UpdateSchemaError::RetryableConflict(ref cause) => write!(f, "{}", cause),
+            UpdateSchemaError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for UpdateSchemaError {}
 /// Errors returned by UpdateTypedLinkFacet
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdateTypedLinkFacetError {
     /// <p>Access denied. Check your permissions.</p>
     AccessDenied(String),
@@ -7974,6 +8895,8 @@ pub enum UpdateTypedLinkFacetError {
     ResourceNotFound(String),
     /// <p>Occurs when a conflict with a previous successful write is detected. For example, if a write operation occurs on an object and then an attempt is made to read the object using “SERIALIZABLE” consistency, this exception may result. This generally occurs when the previous write did not have time to propagate to the host serving the current request. A retry (with appropriate backoff logic) is the recommended response to this exception.</p>
     RetryableConflict(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdateTypedLinkFacetError {
@@ -7981,47 +8904,67 @@ impl UpdateTypedLinkFacetError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(UpdateTypedLinkFacetError::AccessDenied(err.msg))
+                    return RusotoError::service(
+                        UpdateTypedLinkFacetError::AccessDenied(err.msg),
+                        &res,
+                    )
                 }
                 "FacetNotFoundException" => {
-                    return RusotoError::Service(UpdateTypedLinkFacetError::FacetNotFound(err.msg))
+                    return RusotoError::service(
+                        UpdateTypedLinkFacetError::FacetNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "FacetValidationException" => {
-                    return RusotoError::Service(UpdateTypedLinkFacetError::FacetValidation(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateTypedLinkFacetError::FacetValidation(err.msg),
+                        &res,
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(UpdateTypedLinkFacetError::InternalService(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateTypedLinkFacetError::InternalService(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidArnException" => {
-                    return RusotoError::Service(UpdateTypedLinkFacetError::InvalidArn(err.msg))
+                    return RusotoError::service(
+                        UpdateTypedLinkFacetError::InvalidArn(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidFacetUpdateException" => {
-                    return RusotoError::Service(UpdateTypedLinkFacetError::InvalidFacetUpdate(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateTypedLinkFacetError::InvalidFacetUpdate(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidRuleException" => {
-                    return RusotoError::Service(UpdateTypedLinkFacetError::InvalidRule(err.msg))
+                    return RusotoError::service(
+                        UpdateTypedLinkFacetError::InvalidRule(err.msg),
+                        &res,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(UpdateTypedLinkFacetError::LimitExceeded(err.msg))
+                    return RusotoError::service(
+                        UpdateTypedLinkFacetError::LimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateTypedLinkFacetError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateTypedLinkFacetError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "RetryableConflictException" => {
-                    return RusotoError::Service(UpdateTypedLinkFacetError::RetryableConflict(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateTypedLinkFacetError::RetryableConflict(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UpdateTypedLinkFacetError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -8041,12 +8984,14 @@ impl fmt::Display for UpdateTypedLinkFacetError {
             UpdateTypedLinkFacetError::LimitExceeded(ref cause) => write!(f, "{}", cause),
             UpdateTypedLinkFacetError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             UpdateTypedLinkFacetError::RetryableConflict(ref cause) => write!(f, "{}", cause),
+            UpdateTypedLinkFacetError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for UpdateTypedLinkFacetError {}
 /// Errors returned by UpgradeAppliedSchema
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpgradeAppliedSchemaError {
     /// <p>Access denied. Check your permissions.</p>
     AccessDenied(String),
@@ -8064,6 +9009,8 @@ pub enum UpgradeAppliedSchemaError {
     RetryableConflict(String),
     /// <p>Indicates that a schema could not be created due to a naming conflict. Please select a different name and then try again.</p>
     SchemaAlreadyExists(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpgradeAppliedSchemaError {
@@ -8071,43 +9018,55 @@ impl UpgradeAppliedSchemaError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(UpgradeAppliedSchemaError::AccessDenied(err.msg))
+                    return RusotoError::service(
+                        UpgradeAppliedSchemaError::AccessDenied(err.msg),
+                        &res,
+                    )
                 }
                 "IncompatibleSchemaException" => {
-                    return RusotoError::Service(UpgradeAppliedSchemaError::IncompatibleSchema(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpgradeAppliedSchemaError::IncompatibleSchema(err.msg),
+                        &res,
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(UpgradeAppliedSchemaError::InternalService(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpgradeAppliedSchemaError::InternalService(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidArnException" => {
-                    return RusotoError::Service(UpgradeAppliedSchemaError::InvalidArn(err.msg))
+                    return RusotoError::service(
+                        UpgradeAppliedSchemaError::InvalidArn(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidAttachmentException" => {
-                    return RusotoError::Service(UpgradeAppliedSchemaError::InvalidAttachment(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpgradeAppliedSchemaError::InvalidAttachment(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpgradeAppliedSchemaError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpgradeAppliedSchemaError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "RetryableConflictException" => {
-                    return RusotoError::Service(UpgradeAppliedSchemaError::RetryableConflict(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpgradeAppliedSchemaError::RetryableConflict(err.msg),
+                        &res,
+                    )
                 }
                 "SchemaAlreadyExistsException" => {
-                    return RusotoError::Service(UpgradeAppliedSchemaError::SchemaAlreadyExists(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpgradeAppliedSchemaError::SchemaAlreadyExists(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UpgradeAppliedSchemaError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -8125,12 +9084,14 @@ impl fmt::Display for UpgradeAppliedSchemaError {
             UpgradeAppliedSchemaError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             UpgradeAppliedSchemaError::RetryableConflict(ref cause) => write!(f, "{}", cause),
             UpgradeAppliedSchemaError::SchemaAlreadyExists(ref cause) => write!(f, "{}", cause),
+            UpgradeAppliedSchemaError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for UpgradeAppliedSchemaError {}
 /// Errors returned by UpgradePublishedSchema
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpgradePublishedSchemaError {
     /// <p>Access denied. Check your permissions.</p>
     AccessDenied(String),
@@ -8148,6 +9109,8 @@ pub enum UpgradePublishedSchemaError {
     ResourceNotFound(String),
     /// <p>Occurs when a conflict with a previous successful write is detected. For example, if a write operation occurs on an object and then an attempt is made to read the object using “SERIALIZABLE” consistency, this exception may result. This generally occurs when the previous write did not have time to propagate to the host serving the current request. A retry (with appropriate backoff logic) is the recommended response to this exception.</p>
     RetryableConflict(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpgradePublishedSchemaError {
@@ -8155,43 +9118,55 @@ impl UpgradePublishedSchemaError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(UpgradePublishedSchemaError::AccessDenied(err.msg))
+                    return RusotoError::service(
+                        UpgradePublishedSchemaError::AccessDenied(err.msg),
+                        &res,
+                    )
                 }
                 "IncompatibleSchemaException" => {
-                    return RusotoError::Service(UpgradePublishedSchemaError::IncompatibleSchema(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpgradePublishedSchemaError::IncompatibleSchema(err.msg),
+                        &res,
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(UpgradePublishedSchemaError::InternalService(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpgradePublishedSchemaError::InternalService(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidArnException" => {
-                    return RusotoError::Service(UpgradePublishedSchemaError::InvalidArn(err.msg))
+                    return RusotoError::service(
+                        UpgradePublishedSchemaError::InvalidArn(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidAttachmentException" => {
-                    return RusotoError::Service(UpgradePublishedSchemaError::InvalidAttachment(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpgradePublishedSchemaError::InvalidAttachment(err.msg),
+                        &res,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(UpgradePublishedSchemaError::LimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpgradePublishedSchemaError::LimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpgradePublishedSchemaError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpgradePublishedSchemaError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "RetryableConflictException" => {
-                    return RusotoError::Service(UpgradePublishedSchemaError::RetryableConflict(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpgradePublishedSchemaError::RetryableConflict(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UpgradePublishedSchemaError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -8209,6 +9184,7 @@ impl fmt::Display for UpgradePublishedSchemaError {
             UpgradePublishedSchemaError::LimitExceeded(ref cause) => write!(f, "{}", cause),
             UpgradePublishedSchemaError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             UpgradePublishedSchemaError::RetryableConflict(ref cause) => write!(f, "{}", cause),
+            UpgradePublishedSchemaError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
@@ -8623,6 +9599,7 @@ impl CloudDirectoryClient {
     /// Creates a client backed by the default tokio event loop.
     ///
     /// The client will use the default credentials provider and tls client.
+    #[cfg(not(target_arch = "wasm32"))]
     pub fn new(region: region::Region) -> CloudDirectoryClient {
         CloudDirectoryClient {
             client: Client::shared(),
@@ -8648,204 +9625,402 @@ impl CloudDirectoryClient {
     pub fn new_with_client(client: Client, region: region::Region) -> CloudDirectoryClient {
         CloudDirectoryClient { client, region }
     }
+
+    /// Returns a builder of a client, setting any of its region, credentials provider,
+    /// dispatcher, retry policy, endpoint and timeouts.
+    pub fn builder() -> rusoto_core::ClientBuilder<CloudDirectoryClient> {
+        rusoto_core::ClientBuilder::new(CloudDirectoryClient::new_with_client)
+    }
+
+    /// Returns a clone of this client with the overrides of `config` applied to its
+    /// operations, sharing its connections.
+    pub fn customize(&self, config: &rusoto_core::OperationConfig) -> CloudDirectoryClient {
+        CloudDirectoryClient {
+            client: self.client.customize(config),
+            region: config.resolve_region(&self.region),
+        }
+    }
 }
 
-#[async_trait]
-impl CloudDirectory for CloudDirectoryClient {
-    /// <p>Adds a new <a>Facet</a> to an object. An object can have more than one facet applied on it.</p>
-    async fn add_facet_to_object(
+impl CloudDirectoryClient {
+    /// Calls `add_facet_to_object`, also returning the metadata of the response.
+    pub async fn add_facet_to_object_with_metadata(
         &self,
         input: AddFacetToObjectRequest,
-    ) -> Result<AddFacetToObjectResponse, RusotoError<AddFacetToObjectError>> {
+    ) -> Result<(AddFacetToObjectResponse, ResponseMetadata), RusotoError<AddFacetToObjectError>>
+    {
+        let request = self.build_add_facet_to_object_request(input);
+
+        let mut response = self
+            .client
+            .sign_and_dispatch(request)
+            .await
+            .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
+        if response.status.as_u16() == 200 {
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<AddFacetToObjectResponse, _>()?;
+
+            Ok((result, metadata))
+        } else {
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(AddFacetToObjectError::from_response(response))
+        }
+    }
+
+    /// Presigns the request `add_facet_to_object` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_add_facet_to_object(
+        &self,
+        presigner: &rusoto_core::Presigner,
+        input: AddFacetToObjectRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_add_facet_to_object_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_add_facet_to_object_request(&self, input: AddFacetToObjectRequest) -> SignedRequest {
         let request_uri = "/amazonclouddirectory/2017-01-11/object/facets";
 
         let mut request = SignedRequest::new("PUT", "clouddirectory", &self.region, &request_uri);
+        request.set_operation("AddFacetToObject");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
         request.add_header("x-amz-data-partition", &input.directory_arn);
 
+        request
+    }
+
+    /// Calls `apply_schema`, also returning the metadata of the response.
+    pub async fn apply_schema_with_metadata(
+        &self,
+        input: ApplySchemaRequest,
+    ) -> Result<(ApplySchemaResponse, ResponseMetadata), RusotoError<ApplySchemaError>> {
+        let request = self.build_apply_schema_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<AddFacetToObjectResponse, _>()?;
+                .deserialize::<ApplySchemaResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(AddFacetToObjectError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(ApplySchemaError::from_response(response))
         }
     }
 
-    /// <p>Copies the input published schema, at the specified version, into the <a>Directory</a> with the same name and version as that of the published schema.</p>
-    async fn apply_schema(
+    /// Presigns the request `apply_schema` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_apply_schema(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ApplySchemaRequest,
-    ) -> Result<ApplySchemaResponse, RusotoError<ApplySchemaError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_apply_schema_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_apply_schema_request(&self, input: ApplySchemaRequest) -> SignedRequest {
         let request_uri = "/amazonclouddirectory/2017-01-11/schema/apply";
 
         let mut request = SignedRequest::new("PUT", "clouddirectory", &self.region, &request_uri);
+        request.set_operation("ApplySchema");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
         request.add_header("x-amz-data-partition", &input.directory_arn);
 
+        request
+    }
+
+    /// Calls `attach_object`, also returning the metadata of the response.
+    pub async fn attach_object_with_metadata(
+        &self,
+        input: AttachObjectRequest,
+    ) -> Result<(AttachObjectResponse, ResponseMetadata), RusotoError<AttachObjectError>> {
+        let request = self.build_attach_object_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<ApplySchemaResponse, _>()?;
+                .deserialize::<AttachObjectResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(ApplySchemaError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(AttachObjectError::from_response(response))
         }
     }
 
-    /// <p><p>Attaches an existing object to another object. An object can be accessed in two ways:</p> <ol> <li> <p>Using the path</p> </li> <li> <p>Using <code>ObjectIdentifier</code> </p> </li> </ol></p>
-    async fn attach_object(
+    /// Presigns the request `attach_object` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_attach_object(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: AttachObjectRequest,
-    ) -> Result<AttachObjectResponse, RusotoError<AttachObjectError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_attach_object_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_attach_object_request(&self, input: AttachObjectRequest) -> SignedRequest {
         let request_uri = "/amazonclouddirectory/2017-01-11/object/attach";
 
         let mut request = SignedRequest::new("PUT", "clouddirectory", &self.region, &request_uri);
+        request.set_operation("AttachObject");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
         request.add_header("x-amz-data-partition", &input.directory_arn);
 
+        request
+    }
+
+    /// Calls `attach_policy`, also returning the metadata of the response.
+    pub async fn attach_policy_with_metadata(
+        &self,
+        input: AttachPolicyRequest,
+    ) -> Result<(AttachPolicyResponse, ResponseMetadata), RusotoError<AttachPolicyError>> {
+        let request = self.build_attach_policy_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<AttachObjectResponse, _>()?;
+                .deserialize::<AttachPolicyResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(AttachObjectError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(AttachPolicyError::from_response(response))
         }
     }
 
-    /// <p>Attaches a policy object to a regular object. An object can have a limited number of attached policies.</p>
-    async fn attach_policy(
+    /// Presigns the request `attach_policy` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_attach_policy(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: AttachPolicyRequest,
-    ) -> Result<AttachPolicyResponse, RusotoError<AttachPolicyError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_attach_policy_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_attach_policy_request(&self, input: AttachPolicyRequest) -> SignedRequest {
         let request_uri = "/amazonclouddirectory/2017-01-11/policy/attach";
 
         let mut request = SignedRequest::new("PUT", "clouddirectory", &self.region, &request_uri);
+        request.set_operation("AttachPolicy");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
         request.add_header("x-amz-data-partition", &input.directory_arn);
 
+        request
+    }
+
+    /// Calls `attach_to_index`, also returning the metadata of the response.
+    pub async fn attach_to_index_with_metadata(
+        &self,
+        input: AttachToIndexRequest,
+    ) -> Result<(AttachToIndexResponse, ResponseMetadata), RusotoError<AttachToIndexError>> {
+        let request = self.build_attach_to_index_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<AttachPolicyResponse, _>()?;
+                .deserialize::<AttachToIndexResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(AttachPolicyError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(AttachToIndexError::from_response(response))
         }
     }
 
-    /// <p>Attaches the specified object to the specified index.</p>
-    async fn attach_to_index(
+    /// Presigns the request `attach_to_index` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_attach_to_index(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: AttachToIndexRequest,
-    ) -> Result<AttachToIndexResponse, RusotoError<AttachToIndexError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_attach_to_index_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_attach_to_index_request(&self, input: AttachToIndexRequest) -> SignedRequest {
         let request_uri = "/amazonclouddirectory/2017-01-11/index/attach";
 
         let mut request = SignedRequest::new("PUT", "clouddirectory", &self.region, &request_uri);
+        request.set_operation("AttachToIndex");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
         request.add_header("x-amz-data-partition", &input.directory_arn);
 
+        request
+    }
+
+    /// Calls `attach_typed_link`, also returning the metadata of the response.
+    pub async fn attach_typed_link_with_metadata(
+        &self,
+        input: AttachTypedLinkRequest,
+    ) -> Result<(AttachTypedLinkResponse, ResponseMetadata), RusotoError<AttachTypedLinkError>>
+    {
+        let request = self.build_attach_typed_link_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<AttachToIndexResponse, _>()?;
+                .deserialize::<AttachTypedLinkResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(AttachToIndexError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(AttachTypedLinkError::from_response(response))
         }
     }
 
-    /// <p>Attaches a typed link to a specified source and target object. For more information, see <a href="https://docs.aws.amazon.com/clouddirectory/latest/developerguide/directory_objects_links.html#directory_objects_links_typedlink">Typed Links</a>.</p>
-    async fn attach_typed_link(
+    /// Presigns the request `attach_typed_link` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_attach_typed_link(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: AttachTypedLinkRequest,
-    ) -> Result<AttachTypedLinkResponse, RusotoError<AttachTypedLinkError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_attach_typed_link_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_attach_typed_link_request(&self, input: AttachTypedLinkRequest) -> SignedRequest {
         let request_uri = "/amazonclouddirectory/2017-01-11/typedlink/attach";
 
         let mut request = SignedRequest::new("PUT", "clouddirectory", &self.region, &request_uri);
+        request.set_operation("AttachTypedLink");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
         request.add_header("x-amz-data-partition", &input.directory_arn);
 
+        request
+    }
+
+    /// Calls `batch_read`, also returning the metadata of the response.
+    pub async fn batch_read_with_metadata(
+        &self,
+        input: BatchReadRequest,
+    ) -> Result<(BatchReadResponse, ResponseMetadata), RusotoError<BatchReadError>> {
+        let request = self.build_batch_read_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<AttachTypedLinkResponse, _>()?;
+                .deserialize::<BatchReadResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(AttachTypedLinkError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(BatchReadError::from_response(response))
         }
     }
 
-    /// <p>Performs all the read operations in a batch. </p>
-    async fn batch_read(
+    /// Presigns the request `batch_read` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_batch_read(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: BatchReadRequest,
-    ) -> Result<BatchReadResponse, RusotoError<BatchReadError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_batch_read_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_batch_read_request(&self, input: BatchReadRequest) -> SignedRequest {
         let request_uri = "/amazonclouddirectory/2017-01-11/batchread";
 
         let mut request = SignedRequest::new("POST", "clouddirectory", &self.region, &request_uri);
+        request.set_operation("BatchRead");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -8856,700 +10031,1282 @@ impl CloudDirectory for CloudDirectoryClient {
         }
         request.add_header("x-amz-data-partition", &input.directory_arn);
 
+        request
+    }
+
+    /// Calls `batch_write`, also returning the metadata of the response.
+    pub async fn batch_write_with_metadata(
+        &self,
+        input: BatchWriteRequest,
+    ) -> Result<(BatchWriteResponse, ResponseMetadata), RusotoError<BatchWriteError>> {
+        let request = self.build_batch_write_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<BatchReadResponse, _>()?;
+                .deserialize::<BatchWriteResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(BatchReadError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(BatchWriteError::from_response(response))
         }
     }
 
-    /// <p>Performs all the write operations in a batch. Either all the operations succeed or none.</p>
-    async fn batch_write(
+    /// Presigns the request `batch_write` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_batch_write(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: BatchWriteRequest,
-    ) -> Result<BatchWriteResponse, RusotoError<BatchWriteError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_batch_write_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_batch_write_request(&self, input: BatchWriteRequest) -> SignedRequest {
         let request_uri = "/amazonclouddirectory/2017-01-11/batchwrite";
 
         let mut request = SignedRequest::new("PUT", "clouddirectory", &self.region, &request_uri);
+        request.set_operation("BatchWrite");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
         request.add_header("x-amz-data-partition", &input.directory_arn);
 
+        request
+    }
+
+    /// Calls `create_directory`, also returning the metadata of the response.
+    pub async fn create_directory_with_metadata(
+        &self,
+        input: CreateDirectoryRequest,
+    ) -> Result<(CreateDirectoryResponse, ResponseMetadata), RusotoError<CreateDirectoryError>>
+    {
+        let request = self.build_create_directory_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<BatchWriteResponse, _>()?;
+                .deserialize::<CreateDirectoryResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(BatchWriteError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(CreateDirectoryError::from_response(response))
         }
     }
 
-    /// <p>Creates a <a>Directory</a> by copying the published schema into the directory. A directory cannot be created without a schema.</p> <p>You can also quickly create a directory using a managed schema, called the <code>QuickStartSchema</code>. For more information, see <a href="https://docs.aws.amazon.com/clouddirectory/latest/developerguide/schemas_managed.html">Managed Schema</a> in the <i>Amazon Cloud Directory Developer Guide</i>.</p>
-    async fn create_directory(
+    /// Presigns the request `create_directory` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_directory(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: CreateDirectoryRequest,
-    ) -> Result<CreateDirectoryResponse, RusotoError<CreateDirectoryError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_directory_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_directory_request(&self, input: CreateDirectoryRequest) -> SignedRequest {
         let request_uri = "/amazonclouddirectory/2017-01-11/directory/create";
 
         let mut request = SignedRequest::new("PUT", "clouddirectory", &self.region, &request_uri);
+        request.set_operation("CreateDirectory");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
         request.add_header("x-amz-data-partition", &input.schema_arn);
 
+        request
+    }
+
+    /// Calls `create_facet`, also returning the metadata of the response.
+    pub async fn create_facet_with_metadata(
+        &self,
+        input: CreateFacetRequest,
+    ) -> Result<(CreateFacetResponse, ResponseMetadata), RusotoError<CreateFacetError>> {
+        let request = self.build_create_facet_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<CreateDirectoryResponse, _>()?;
+                .deserialize::<CreateFacetResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(CreateDirectoryError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(CreateFacetError::from_response(response))
         }
     }
 
-    /// <p>Creates a new <a>Facet</a> in a schema. Facet creation is allowed only in development or applied schemas.</p>
-    async fn create_facet(
+    /// Presigns the request `create_facet` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_facet(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: CreateFacetRequest,
-    ) -> Result<CreateFacetResponse, RusotoError<CreateFacetError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_facet_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_facet_request(&self, input: CreateFacetRequest) -> SignedRequest {
         let request_uri = "/amazonclouddirectory/2017-01-11/facet/create";
 
         let mut request = SignedRequest::new("PUT", "clouddirectory", &self.region, &request_uri);
+        request.set_operation("CreateFacet");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
         request.add_header("x-amz-data-partition", &input.schema_arn);
 
+        request
+    }
+
+    /// Calls `create_index`, also returning the metadata of the response.
+    pub async fn create_index_with_metadata(
+        &self,
+        input: CreateIndexRequest,
+    ) -> Result<(CreateIndexResponse, ResponseMetadata), RusotoError<CreateIndexError>> {
+        let request = self.build_create_index_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<CreateFacetResponse, _>()?;
+                .deserialize::<CreateIndexResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(CreateFacetError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(CreateIndexError::from_response(response))
         }
     }
 
-    /// <p>Creates an index object. See <a href="https://docs.aws.amazon.com/clouddirectory/latest/developerguide/indexing_search.html">Indexing and search</a> for more information.</p>
-    async fn create_index(
+    /// Presigns the request `create_index` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_index(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: CreateIndexRequest,
-    ) -> Result<CreateIndexResponse, RusotoError<CreateIndexError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_index_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_index_request(&self, input: CreateIndexRequest) -> SignedRequest {
         let request_uri = "/amazonclouddirectory/2017-01-11/index";
 
         let mut request = SignedRequest::new("PUT", "clouddirectory", &self.region, &request_uri);
+        request.set_operation("CreateIndex");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
         request.add_header("x-amz-data-partition", &input.directory_arn);
 
+        request
+    }
+
+    /// Calls `create_object`, also returning the metadata of the response.
+    pub async fn create_object_with_metadata(
+        &self,
+        input: CreateObjectRequest,
+    ) -> Result<(CreateObjectResponse, ResponseMetadata), RusotoError<CreateObjectError>> {
+        let request = self.build_create_object_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<CreateIndexResponse, _>()?;
+                .deserialize::<CreateObjectResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(CreateIndexError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(CreateObjectError::from_response(response))
         }
     }
 
-    /// <p>Creates an object in a <a>Directory</a>. Additionally attaches the object to a parent, if a parent reference and <code>LinkName</code> is specified. An object is simply a collection of <a>Facet</a> attributes. You can also use this API call to create a policy object, if the facet from which you create the object is a policy facet. </p>
-    async fn create_object(
+    /// Presigns the request `create_object` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_object(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: CreateObjectRequest,
-    ) -> Result<CreateObjectResponse, RusotoError<CreateObjectError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_object_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_object_request(&self, input: CreateObjectRequest) -> SignedRequest {
         let request_uri = "/amazonclouddirectory/2017-01-11/object";
 
         let mut request = SignedRequest::new("PUT", "clouddirectory", &self.region, &request_uri);
+        request.set_operation("CreateObject");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
         request.add_header("x-amz-data-partition", &input.directory_arn);
 
+        request
+    }
+
+    /// Calls `create_schema`, also returning the metadata of the response.
+    pub async fn create_schema_with_metadata(
+        &self,
+        input: CreateSchemaRequest,
+    ) -> Result<(CreateSchemaResponse, ResponseMetadata), RusotoError<CreateSchemaError>> {
+        let request = self.build_create_schema_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<CreateObjectResponse, _>()?;
+                .deserialize::<CreateSchemaResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(CreateObjectError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(CreateSchemaError::from_response(response))
         }
     }
 
-    /// <p><p>Creates a new schema in a development state. A schema can exist in three phases:</p> <ul> <li> <p> <i>Development:</i> This is a mutable phase of the schema. All new schemas are in the development phase. Once the schema is finalized, it can be published.</p> </li> <li> <p> <i>Published:</i> Published schemas are immutable and have a version associated with them.</p> </li> <li> <p> <i>Applied:</i> Applied schemas are mutable in a way that allows you to add new schema facets. You can also add new, nonrequired attributes to existing schema facets. You can apply only published schemas to directories. </p> </li> </ul></p>
-    async fn create_schema(
+    /// Presigns the request `create_schema` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_schema(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: CreateSchemaRequest,
-    ) -> Result<CreateSchemaResponse, RusotoError<CreateSchemaError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_schema_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_schema_request(&self, input: CreateSchemaRequest) -> SignedRequest {
         let request_uri = "/amazonclouddirectory/2017-01-11/schema/create";
 
         let mut request = SignedRequest::new("PUT", "clouddirectory", &self.region, &request_uri);
+        request.set_operation("CreateSchema");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `create_typed_link_facet`, also returning the metadata of the response.
+    pub async fn create_typed_link_facet_with_metadata(
+        &self,
+        input: CreateTypedLinkFacetRequest,
+    ) -> Result<
+        (CreateTypedLinkFacetResponse, ResponseMetadata),
+        RusotoError<CreateTypedLinkFacetError>,
+    > {
+        let request = self.build_create_typed_link_facet_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<CreateSchemaResponse, _>()?;
+                .deserialize::<CreateTypedLinkFacetResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(CreateSchemaError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(CreateTypedLinkFacetError::from_response(response))
         }
     }
 
-    /// <p>Creates a <a>TypedLinkFacet</a>. For more information, see <a href="https://docs.aws.amazon.com/clouddirectory/latest/developerguide/directory_objects_links.html#directory_objects_links_typedlink">Typed Links</a>.</p>
-    async fn create_typed_link_facet(
+    /// Presigns the request `create_typed_link_facet` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_typed_link_facet(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: CreateTypedLinkFacetRequest,
-    ) -> Result<CreateTypedLinkFacetResponse, RusotoError<CreateTypedLinkFacetError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_typed_link_facet_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_typed_link_facet_request(
+        &self,
+        input: CreateTypedLinkFacetRequest,
+    ) -> SignedRequest {
         let request_uri = "/amazonclouddirectory/2017-01-11/typedlink/facet/create";
 
         let mut request = SignedRequest::new("PUT", "clouddirectory", &self.region, &request_uri);
+        request.set_operation("CreateTypedLinkFacet");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
         request.add_header("x-amz-data-partition", &input.schema_arn);
 
+        request
+    }
+
+    /// Calls `delete_directory`, also returning the metadata of the response.
+    pub async fn delete_directory_with_metadata(
+        &self,
+        input: DeleteDirectoryRequest,
+    ) -> Result<(DeleteDirectoryResponse, ResponseMetadata), RusotoError<DeleteDirectoryError>>
+    {
+        let request = self.build_delete_directory_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<CreateTypedLinkFacetResponse, _>()?;
+                .deserialize::<DeleteDirectoryResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(CreateTypedLinkFacetError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(DeleteDirectoryError::from_response(response))
         }
     }
 
-    /// <p>Deletes a directory. Only disabled directories can be deleted. A deleted directory cannot be undone. Exercise extreme caution when deleting directories.</p>
-    async fn delete_directory(
+    /// Presigns the request `delete_directory` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_directory(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteDirectoryRequest,
-    ) -> Result<DeleteDirectoryResponse, RusotoError<DeleteDirectoryError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_directory_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_directory_request(&self, input: DeleteDirectoryRequest) -> SignedRequest {
         let request_uri = "/amazonclouddirectory/2017-01-11/directory";
 
         let mut request = SignedRequest::new("PUT", "clouddirectory", &self.region, &request_uri);
+        request.set_operation("DeleteDirectory");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.add_header("x-amz-data-partition", &input.directory_arn);
 
+        request
+    }
+
+    /// Calls `delete_facet`, also returning the metadata of the response.
+    pub async fn delete_facet_with_metadata(
+        &self,
+        input: DeleteFacetRequest,
+    ) -> Result<(DeleteFacetResponse, ResponseMetadata), RusotoError<DeleteFacetError>> {
+        let request = self.build_delete_facet_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<DeleteDirectoryResponse, _>()?;
+                .deserialize::<DeleteFacetResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteDirectoryError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(DeleteFacetError::from_response(response))
         }
     }
 
-    /// <p>Deletes a given <a>Facet</a>. All attributes and <a>Rule</a>s that are associated with the facet will be deleted. Only development schema facets are allowed deletion.</p>
-    async fn delete_facet(
+    /// Presigns the request `delete_facet` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_facet(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteFacetRequest,
-    ) -> Result<DeleteFacetResponse, RusotoError<DeleteFacetError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_facet_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_facet_request(&self, input: DeleteFacetRequest) -> SignedRequest {
         let request_uri = "/amazonclouddirectory/2017-01-11/facet/delete";
 
         let mut request = SignedRequest::new("PUT", "clouddirectory", &self.region, &request_uri);
+        request.set_operation("DeleteFacet");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
         request.add_header("x-amz-data-partition", &input.schema_arn);
 
+        request
+    }
+
+    /// Calls `delete_object`, also returning the metadata of the response.
+    pub async fn delete_object_with_metadata(
+        &self,
+        input: DeleteObjectRequest,
+    ) -> Result<(DeleteObjectResponse, ResponseMetadata), RusotoError<DeleteObjectError>> {
+        let request = self.build_delete_object_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<DeleteFacetResponse, _>()?;
+                .deserialize::<DeleteObjectResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteFacetError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(DeleteObjectError::from_response(response))
         }
     }
 
-    /// <p>Deletes an object and its associated attributes. Only objects with no children and no parents can be deleted. The maximum number of attributes that can be deleted during an object deletion is 30. For more information, see <a href="https://docs.aws.amazon.com/clouddirectory/latest/developerguide/limits.html">Amazon Cloud Directory Limits</a>.</p>
-    async fn delete_object(
+    /// Presigns the request `delete_object` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_object(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteObjectRequest,
-    ) -> Result<DeleteObjectResponse, RusotoError<DeleteObjectError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_object_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_object_request(&self, input: DeleteObjectRequest) -> SignedRequest {
         let request_uri = "/amazonclouddirectory/2017-01-11/object/delete";
 
         let mut request = SignedRequest::new("PUT", "clouddirectory", &self.region, &request_uri);
+        request.set_operation("DeleteObject");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
         request.add_header("x-amz-data-partition", &input.directory_arn);
 
+        request
+    }
+
+    /// Calls `delete_schema`, also returning the metadata of the response.
+    pub async fn delete_schema_with_metadata(
+        &self,
+        input: DeleteSchemaRequest,
+    ) -> Result<(DeleteSchemaResponse, ResponseMetadata), RusotoError<DeleteSchemaError>> {
+        let request = self.build_delete_schema_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<DeleteObjectResponse, _>()?;
+                .deserialize::<DeleteSchemaResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteObjectError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(DeleteSchemaError::from_response(response))
         }
     }
 
-    /// <p>Deletes a given schema. Schemas in a development and published state can only be deleted. </p>
-    async fn delete_schema(
+    /// Presigns the request `delete_schema` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_schema(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteSchemaRequest,
-    ) -> Result<DeleteSchemaResponse, RusotoError<DeleteSchemaError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_schema_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_schema_request(&self, input: DeleteSchemaRequest) -> SignedRequest {
         let request_uri = "/amazonclouddirectory/2017-01-11/schema";
 
         let mut request = SignedRequest::new("PUT", "clouddirectory", &self.region, &request_uri);
+        request.set_operation("DeleteSchema");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.add_header("x-amz-data-partition", &input.schema_arn);
 
+        request
+    }
+
+    /// Calls `delete_typed_link_facet`, also returning the metadata of the response.
+    pub async fn delete_typed_link_facet_with_metadata(
+        &self,
+        input: DeleteTypedLinkFacetRequest,
+    ) -> Result<
+        (DeleteTypedLinkFacetResponse, ResponseMetadata),
+        RusotoError<DeleteTypedLinkFacetError>,
+    > {
+        let request = self.build_delete_typed_link_facet_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<DeleteSchemaResponse, _>()?;
+                .deserialize::<DeleteTypedLinkFacetResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteSchemaError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(DeleteTypedLinkFacetError::from_response(response))
         }
     }
 
-    /// <p>Deletes a <a>TypedLinkFacet</a>. For more information, see <a href="https://docs.aws.amazon.com/clouddirectory/latest/developerguide/directory_objects_links.html#directory_objects_links_typedlink">Typed Links</a>.</p>
-    async fn delete_typed_link_facet(
+    /// Presigns the request `delete_typed_link_facet` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_typed_link_facet(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteTypedLinkFacetRequest,
-    ) -> Result<DeleteTypedLinkFacetResponse, RusotoError<DeleteTypedLinkFacetError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_typed_link_facet_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_typed_link_facet_request(
+        &self,
+        input: DeleteTypedLinkFacetRequest,
+    ) -> SignedRequest {
         let request_uri = "/amazonclouddirectory/2017-01-11/typedlink/facet/delete";
 
         let mut request = SignedRequest::new("PUT", "clouddirectory", &self.region, &request_uri);
+        request.set_operation("DeleteTypedLinkFacet");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
         request.add_header("x-amz-data-partition", &input.schema_arn);
 
+        request
+    }
+
+    /// Calls `detach_from_index`, also returning the metadata of the response.
+    pub async fn detach_from_index_with_metadata(
+        &self,
+        input: DetachFromIndexRequest,
+    ) -> Result<(DetachFromIndexResponse, ResponseMetadata), RusotoError<DetachFromIndexError>>
+    {
+        let request = self.build_detach_from_index_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<DeleteTypedLinkFacetResponse, _>()?;
+                .deserialize::<DetachFromIndexResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteTypedLinkFacetError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(DetachFromIndexError::from_response(response))
         }
     }
 
-    /// <p>Detaches the specified object from the specified index.</p>
-    async fn detach_from_index(
+    /// Presigns the request `detach_from_index` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_detach_from_index(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DetachFromIndexRequest,
-    ) -> Result<DetachFromIndexResponse, RusotoError<DetachFromIndexError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_detach_from_index_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_detach_from_index_request(&self, input: DetachFromIndexRequest) -> SignedRequest {
         let request_uri = "/amazonclouddirectory/2017-01-11/index/detach";
 
         let mut request = SignedRequest::new("PUT", "clouddirectory", &self.region, &request_uri);
+        request.set_operation("DetachFromIndex");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
         request.add_header("x-amz-data-partition", &input.directory_arn);
 
+        request
+    }
+
+    /// Calls `detach_object`, also returning the metadata of the response.
+    pub async fn detach_object_with_metadata(
+        &self,
+        input: DetachObjectRequest,
+    ) -> Result<(DetachObjectResponse, ResponseMetadata), RusotoError<DetachObjectError>> {
+        let request = self.build_detach_object_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<DetachFromIndexResponse, _>()?;
+                .deserialize::<DetachObjectResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(DetachFromIndexError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(DetachObjectError::from_response(response))
         }
     }
 
-    /// <p>Detaches a given object from the parent object. The object that is to be detached from the parent is specified by the link name.</p>
-    async fn detach_object(
+    /// Presigns the request `detach_object` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_detach_object(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DetachObjectRequest,
-    ) -> Result<DetachObjectResponse, RusotoError<DetachObjectError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_detach_object_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_detach_object_request(&self, input: DetachObjectRequest) -> SignedRequest {
         let request_uri = "/amazonclouddirectory/2017-01-11/object/detach";
 
         let mut request = SignedRequest::new("PUT", "clouddirectory", &self.region, &request_uri);
+        request.set_operation("DetachObject");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
         request.add_header("x-amz-data-partition", &input.directory_arn);
 
+        request
+    }
+
+    /// Calls `detach_policy`, also returning the metadata of the response.
+    pub async fn detach_policy_with_metadata(
+        &self,
+        input: DetachPolicyRequest,
+    ) -> Result<(DetachPolicyResponse, ResponseMetadata), RusotoError<DetachPolicyError>> {
+        let request = self.build_detach_policy_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<DetachObjectResponse, _>()?;
+                .deserialize::<DetachPolicyResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(DetachObjectError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(DetachPolicyError::from_response(response))
         }
     }
 
-    /// <p>Detaches a policy from an object.</p>
-    async fn detach_policy(
+    /// Presigns the request `detach_policy` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_detach_policy(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DetachPolicyRequest,
-    ) -> Result<DetachPolicyResponse, RusotoError<DetachPolicyError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_detach_policy_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_detach_policy_request(&self, input: DetachPolicyRequest) -> SignedRequest {
         let request_uri = "/amazonclouddirectory/2017-01-11/policy/detach";
 
         let mut request = SignedRequest::new("PUT", "clouddirectory", &self.region, &request_uri);
+        request.set_operation("DetachPolicy");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
         request.add_header("x-amz-data-partition", &input.directory_arn);
 
+        request
+    }
+
+    /// Calls `detach_typed_link`, also returning the metadata of the response.
+    pub async fn detach_typed_link_with_metadata(
+        &self,
+        input: DetachTypedLinkRequest,
+    ) -> Result<((), ResponseMetadata), RusotoError<DetachTypedLinkError>> {
+        let request = self.build_detach_typed_link_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<DetachPolicyResponse, _>()?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = ::std::mem::drop(response);
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(DetachPolicyError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(DetachTypedLinkError::from_response(response))
         }
     }
 
-    /// <p>Detaches a typed link from a specified source and target object. For more information, see <a href="https://docs.aws.amazon.com/clouddirectory/latest/developerguide/directory_objects_links.html#directory_objects_links_typedlink">Typed Links</a>.</p>
-    async fn detach_typed_link(
+    /// Presigns the request `detach_typed_link` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_detach_typed_link(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DetachTypedLinkRequest,
-    ) -> Result<(), RusotoError<DetachTypedLinkError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_detach_typed_link_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_detach_typed_link_request(&self, input: DetachTypedLinkRequest) -> SignedRequest {
         let request_uri = "/amazonclouddirectory/2017-01-11/typedlink/detach";
 
         let mut request = SignedRequest::new("PUT", "clouddirectory", &self.region, &request_uri);
+        request.set_operation("DetachTypedLink");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
         request.add_header("x-amz-data-partition", &input.directory_arn);
 
+        request
+    }
+
+    /// Calls `disable_directory`, also returning the metadata of the response.
+    pub async fn disable_directory_with_metadata(
+        &self,
+        input: DisableDirectoryRequest,
+    ) -> Result<(DisableDirectoryResponse, ResponseMetadata), RusotoError<DisableDirectoryError>>
+    {
+        let request = self.build_disable_directory_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            let result = ::std::mem::drop(response);
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DisableDirectoryResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(DetachTypedLinkError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(DisableDirectoryError::from_response(response))
         }
     }
 
-    /// <p>Disables the specified directory. Disabled directories cannot be read or written to. Only enabled directories can be disabled. Disabled directories may be reenabled.</p>
-    async fn disable_directory(
+    /// Presigns the request `disable_directory` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_disable_directory(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DisableDirectoryRequest,
-    ) -> Result<DisableDirectoryResponse, RusotoError<DisableDirectoryError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_disable_directory_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_disable_directory_request(&self, input: DisableDirectoryRequest) -> SignedRequest {
         let request_uri = "/amazonclouddirectory/2017-01-11/directory/disable";
 
         let mut request = SignedRequest::new("PUT", "clouddirectory", &self.region, &request_uri);
+        request.set_operation("DisableDirectory");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.add_header("x-amz-data-partition", &input.directory_arn);
 
+        request
+    }
+
+    /// Calls `enable_directory`, also returning the metadata of the response.
+    pub async fn enable_directory_with_metadata(
+        &self,
+        input: EnableDirectoryRequest,
+    ) -> Result<(EnableDirectoryResponse, ResponseMetadata), RusotoError<EnableDirectoryError>>
+    {
+        let request = self.build_enable_directory_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<DisableDirectoryResponse, _>()?;
+                .deserialize::<EnableDirectoryResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(DisableDirectoryError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(EnableDirectoryError::from_response(response))
         }
     }
 
-    /// <p>Enables the specified directory. Only disabled directories can be enabled. Once enabled, the directory can then be read and written to.</p>
-    async fn enable_directory(
+    /// Presigns the request `enable_directory` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_enable_directory(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: EnableDirectoryRequest,
-    ) -> Result<EnableDirectoryResponse, RusotoError<EnableDirectoryError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_enable_directory_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_enable_directory_request(&self, input: EnableDirectoryRequest) -> SignedRequest {
         let request_uri = "/amazonclouddirectory/2017-01-11/directory/enable";
 
         let mut request = SignedRequest::new("PUT", "clouddirectory", &self.region, &request_uri);
+        request.set_operation("EnableDirectory");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.add_header("x-amz-data-partition", &input.directory_arn);
 
+        request
+    }
+
+    /// Calls `get_applied_schema_version`, also returning the metadata of the response.
+    pub async fn get_applied_schema_version_with_metadata(
+        &self,
+        input: GetAppliedSchemaVersionRequest,
+    ) -> Result<
+        (GetAppliedSchemaVersionResponse, ResponseMetadata),
+        RusotoError<GetAppliedSchemaVersionError>,
+    > {
+        let request = self.build_get_applied_schema_version_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<EnableDirectoryResponse, _>()?;
+                .deserialize::<GetAppliedSchemaVersionResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(EnableDirectoryError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(GetAppliedSchemaVersionError::from_response(response))
         }
     }
 
-    /// <p>Returns current applied schema version ARN, including the minor version in use.</p>
-    async fn get_applied_schema_version(
+    /// Presigns the request `get_applied_schema_version` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_applied_schema_version(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetAppliedSchemaVersionRequest,
-    ) -> Result<GetAppliedSchemaVersionResponse, RusotoError<GetAppliedSchemaVersionError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_applied_schema_version_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_applied_schema_version_request(
+        &self,
+        input: GetAppliedSchemaVersionRequest,
+    ) -> SignedRequest {
         let request_uri = "/amazonclouddirectory/2017-01-11/schema/getappliedschema";
 
         let mut request = SignedRequest::new("POST", "clouddirectory", &self.region, &request_uri);
+        request.set_operation("GetAppliedSchemaVersion");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `get_directory`, also returning the metadata of the response.
+    pub async fn get_directory_with_metadata(
+        &self,
+        input: GetDirectoryRequest,
+    ) -> Result<(GetDirectoryResponse, ResponseMetadata), RusotoError<GetDirectoryError>> {
+        let request = self.build_get_directory_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<GetAppliedSchemaVersionResponse, _>()?;
+                .deserialize::<GetDirectoryResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(GetAppliedSchemaVersionError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(GetDirectoryError::from_response(response))
         }
     }
 
-    /// <p>Retrieves metadata about a directory.</p>
-    async fn get_directory(
+    /// Presigns the request `get_directory` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_directory(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetDirectoryRequest,
-    ) -> Result<GetDirectoryResponse, RusotoError<GetDirectoryError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_directory_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_directory_request(&self, input: GetDirectoryRequest) -> SignedRequest {
         let request_uri = "/amazonclouddirectory/2017-01-11/directory/get";
 
         let mut request = SignedRequest::new("POST", "clouddirectory", &self.region, &request_uri);
+        request.set_operation("GetDirectory");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.add_header("x-amz-data-partition", &input.directory_arn);
 
+        request
+    }
+
+    /// Calls `get_facet`, also returning the metadata of the response.
+    pub async fn get_facet_with_metadata(
+        &self,
+        input: GetFacetRequest,
+    ) -> Result<(GetFacetResponse, ResponseMetadata), RusotoError<GetFacetError>> {
+        let request = self.build_get_facet_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<GetDirectoryResponse, _>()?;
+                .deserialize::<GetFacetResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(GetDirectoryError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(GetFacetError::from_response(response))
         }
     }
 
-    /// <p>Gets details of the <a>Facet</a>, such as facet name, attributes, <a>Rule</a>s, or <code>ObjectType</code>. You can call this on all kinds of schema facets -- published, development, or applied.</p>
-    async fn get_facet(
+    /// Presigns the request `get_facet` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_facet(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetFacetRequest,
-    ) -> Result<GetFacetResponse, RusotoError<GetFacetError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_facet_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_facet_request(&self, input: GetFacetRequest) -> SignedRequest {
         let request_uri = "/amazonclouddirectory/2017-01-11/facet";
 
         let mut request = SignedRequest::new("POST", "clouddirectory", &self.region, &request_uri);
+        request.set_operation("GetFacet");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
         request.add_header("x-amz-data-partition", &input.schema_arn);
 
+        request
+    }
+
+    /// Calls `get_link_attributes`, also returning the metadata of the response.
+    pub async fn get_link_attributes_with_metadata(
+        &self,
+        input: GetLinkAttributesRequest,
+    ) -> Result<(GetLinkAttributesResponse, ResponseMetadata), RusotoError<GetLinkAttributesError>>
+    {
+        let request = self.build_get_link_attributes_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<GetFacetResponse, _>()?;
+                .deserialize::<GetLinkAttributesResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(GetFacetError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(GetLinkAttributesError::from_response(response))
         }
     }
 
-    /// <p>Retrieves attributes that are associated with a typed link.</p>
-    async fn get_link_attributes(
+    /// Presigns the request `get_link_attributes` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_link_attributes(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetLinkAttributesRequest,
-    ) -> Result<GetLinkAttributesResponse, RusotoError<GetLinkAttributesError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_link_attributes_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_link_attributes_request(&self, input: GetLinkAttributesRequest) -> SignedRequest {
         let request_uri = "/amazonclouddirectory/2017-01-11/typedlink/attributes/get";
 
         let mut request = SignedRequest::new("POST", "clouddirectory", &self.region, &request_uri);
+        request.set_operation("GetLinkAttributes");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
         request.add_header("x-amz-data-partition", &input.directory_arn);
 
+        request
+    }
+
+    /// Calls `get_object_attributes`, also returning the metadata of the response.
+    pub async fn get_object_attributes_with_metadata(
+        &self,
+        input: GetObjectAttributesRequest,
+    ) -> Result<
+        (GetObjectAttributesResponse, ResponseMetadata),
+        RusotoError<GetObjectAttributesError>,
+    > {
+        let request = self.build_get_object_attributes_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<GetLinkAttributesResponse, _>()?;
+                .deserialize::<GetObjectAttributesResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(GetLinkAttributesError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(GetObjectAttributesError::from_response(response))
         }
     }
 
-    /// <p>Retrieves attributes within a facet that are associated with an object.</p>
-    async fn get_object_attributes(
+    /// Presigns the request `get_object_attributes` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_object_attributes(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetObjectAttributesRequest,
-    ) -> Result<GetObjectAttributesResponse, RusotoError<GetObjectAttributesError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_object_attributes_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_object_attributes_request(
+        &self,
+        input: GetObjectAttributesRequest,
+    ) -> SignedRequest {
         let request_uri = "/amazonclouddirectory/2017-01-11/object/attributes/get";
 
         let mut request = SignedRequest::new("POST", "clouddirectory", &self.region, &request_uri);
+        request.set_operation("GetObjectAttributes");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -9560,31 +11317,61 @@ impl CloudDirectory for CloudDirectoryClient {
         }
         request.add_header("x-amz-data-partition", &input.directory_arn);
 
+        request
+    }
+
+    /// Calls `get_object_information`, also returning the metadata of the response.
+    pub async fn get_object_information_with_metadata(
+        &self,
+        input: GetObjectInformationRequest,
+    ) -> Result<
+        (GetObjectInformationResponse, ResponseMetadata),
+        RusotoError<GetObjectInformationError>,
+    > {
+        let request = self.build_get_object_information_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<GetObjectAttributesResponse, _>()?;
+                .deserialize::<GetObjectInformationResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(GetObjectAttributesError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(GetObjectInformationError::from_response(response))
         }
     }
 
-    /// <p>Retrieves metadata about an object.</p>
-    async fn get_object_information(
+    /// Presigns the request `get_object_information` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_object_information(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetObjectInformationRequest,
-    ) -> Result<GetObjectInformationResponse, RusotoError<GetObjectInformationError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_object_information_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_object_information_request(
+        &self,
+        input: GetObjectInformationRequest,
+    ) -> SignedRequest {
         let request_uri = "/amazonclouddirectory/2017-01-11/object/information";
 
         let mut request = SignedRequest::new("POST", "clouddirectory", &self.region, &request_uri);
+        request.set_operation("GetObjectInformation");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -9595,122 +11382,236 @@ impl CloudDirectory for CloudDirectoryClient {
         }
         request.add_header("x-amz-data-partition", &input.directory_arn);
 
+        request
+    }
+
+    /// Calls `get_schema_as_json`, also returning the metadata of the response.
+    pub async fn get_schema_as_json_with_metadata(
+        &self,
+        input: GetSchemaAsJsonRequest,
+    ) -> Result<(GetSchemaAsJsonResponse, ResponseMetadata), RusotoError<GetSchemaAsJsonError>>
+    {
+        let request = self.build_get_schema_as_json_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<GetObjectInformationResponse, _>()?;
+                .deserialize::<GetSchemaAsJsonResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(GetObjectInformationError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(GetSchemaAsJsonError::from_response(response))
         }
     }
 
-    /// <p>Retrieves a JSON representation of the schema. See <a href="https://docs.aws.amazon.com/clouddirectory/latest/developerguide/schemas_jsonformat.html#schemas_json">JSON Schema Format</a> for more information.</p>
-    async fn get_schema_as_json(
+    /// Presigns the request `get_schema_as_json` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_schema_as_json(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetSchemaAsJsonRequest,
-    ) -> Result<GetSchemaAsJsonResponse, RusotoError<GetSchemaAsJsonError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_schema_as_json_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_schema_as_json_request(&self, input: GetSchemaAsJsonRequest) -> SignedRequest {
         let request_uri = "/amazonclouddirectory/2017-01-11/schema/json";
 
         let mut request = SignedRequest::new("POST", "clouddirectory", &self.region, &request_uri);
+        request.set_operation("GetSchemaAsJson");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.add_header("x-amz-data-partition", &input.schema_arn);
 
+        request
+    }
+
+    /// Calls `get_typed_link_facet_information`, also returning the metadata of the response.
+    pub async fn get_typed_link_facet_information_with_metadata(
+        &self,
+        input: GetTypedLinkFacetInformationRequest,
+    ) -> Result<
+        (GetTypedLinkFacetInformationResponse, ResponseMetadata),
+        RusotoError<GetTypedLinkFacetInformationError>,
+    > {
+        let request = self.build_get_typed_link_facet_information_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<GetSchemaAsJsonResponse, _>()?;
+                .deserialize::<GetTypedLinkFacetInformationResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(GetSchemaAsJsonError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(GetTypedLinkFacetInformationError::from_response(response))
         }
     }
 
-    /// <p>Returns the identity attribute order for a specific <a>TypedLinkFacet</a>. For more information, see <a href="https://docs.aws.amazon.com/clouddirectory/latest/developerguide/directory_objects_links.html#directory_objects_links_typedlink">Typed Links</a>.</p>
-    async fn get_typed_link_facet_information(
+    /// Presigns the request `get_typed_link_facet_information` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_typed_link_facet_information(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetTypedLinkFacetInformationRequest,
-    ) -> Result<GetTypedLinkFacetInformationResponse, RusotoError<GetTypedLinkFacetInformationError>>
-    {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_typed_link_facet_information_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_typed_link_facet_information_request(
+        &self,
+        input: GetTypedLinkFacetInformationRequest,
+    ) -> SignedRequest {
         let request_uri = "/amazonclouddirectory/2017-01-11/typedlink/facet/get";
 
         let mut request = SignedRequest::new("POST", "clouddirectory", &self.region, &request_uri);
+        request.set_operation("GetTypedLinkFacetInformation");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
         request.add_header("x-amz-data-partition", &input.schema_arn);
 
+        request
+    }
+
+    /// Calls `list_applied_schema_arns`, also returning the metadata of the response.
+    pub async fn list_applied_schema_arns_with_metadata(
+        &self,
+        input: ListAppliedSchemaArnsRequest,
+    ) -> Result<
+        (ListAppliedSchemaArnsResponse, ResponseMetadata),
+        RusotoError<ListAppliedSchemaArnsError>,
+    > {
+        let request = self.build_list_applied_schema_arns_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<GetTypedLinkFacetInformationResponse, _>()?;
+                .deserialize::<ListAppliedSchemaArnsResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(GetTypedLinkFacetInformationError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(ListAppliedSchemaArnsError::from_response(response))
         }
     }
 
-    /// <p>Lists schema major versions applied to a directory. If <code>SchemaArn</code> is provided, lists the minor version.</p>
-    async fn list_applied_schema_arns(
+    /// Presigns the request `list_applied_schema_arns` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_applied_schema_arns(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListAppliedSchemaArnsRequest,
-    ) -> Result<ListAppliedSchemaArnsResponse, RusotoError<ListAppliedSchemaArnsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_applied_schema_arns_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_applied_schema_arns_request(
+        &self,
+        input: ListAppliedSchemaArnsRequest,
+    ) -> SignedRequest {
         let request_uri = "/amazonclouddirectory/2017-01-11/schema/applied";
 
         let mut request = SignedRequest::new("POST", "clouddirectory", &self.region, &request_uri);
+        request.set_operation("ListAppliedSchemaArns");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `list_attached_indices`, also returning the metadata of the response.
+    pub async fn list_attached_indices_with_metadata(
+        &self,
+        input: ListAttachedIndicesRequest,
+    ) -> Result<
+        (ListAttachedIndicesResponse, ResponseMetadata),
+        RusotoError<ListAttachedIndicesError>,
+    > {
+        let request = self.build_list_attached_indices_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListAppliedSchemaArnsResponse, _>()?;
+                .deserialize::<ListAttachedIndicesResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(ListAppliedSchemaArnsError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(ListAttachedIndicesError::from_response(response))
         }
     }
 
-    /// <p>Lists indices attached to the specified object.</p>
-    async fn list_attached_indices(
+    /// Presigns the request `list_attached_indices` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_attached_indices(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListAttachedIndicesRequest,
-    ) -> Result<ListAttachedIndicesResponse, RusotoError<ListAttachedIndicesError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_attached_indices_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_attached_indices_request(
+        &self,
+        input: ListAttachedIndicesRequest,
+    ) -> SignedRequest {
         let request_uri = "/amazonclouddirectory/2017-01-11/object/indices";
 
         let mut request = SignedRequest::new("POST", "clouddirectory", &self.region, &request_uri);
+        request.set_operation("ListAttachedIndices");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -9721,185 +11622,347 @@ impl CloudDirectory for CloudDirectoryClient {
         }
         request.add_header("x-amz-data-partition", &input.directory_arn);
 
+        request
+    }
+
+    /// Calls `list_development_schema_arns`, also returning the metadata of the response.
+    pub async fn list_development_schema_arns_with_metadata(
+        &self,
+        input: ListDevelopmentSchemaArnsRequest,
+    ) -> Result<
+        (ListDevelopmentSchemaArnsResponse, ResponseMetadata),
+        RusotoError<ListDevelopmentSchemaArnsError>,
+    > {
+        let request = self.build_list_development_schema_arns_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListAttachedIndicesResponse, _>()?;
+                .deserialize::<ListDevelopmentSchemaArnsResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(ListAttachedIndicesError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(ListDevelopmentSchemaArnsError::from_response(response))
         }
     }
 
-    /// <p>Retrieves each Amazon Resource Name (ARN) of schemas in the development state.</p>
-    async fn list_development_schema_arns(
+    /// Presigns the request `list_development_schema_arns` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_development_schema_arns(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListDevelopmentSchemaArnsRequest,
-    ) -> Result<ListDevelopmentSchemaArnsResponse, RusotoError<ListDevelopmentSchemaArnsError>>
-    {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_development_schema_arns_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_development_schema_arns_request(
+        &self,
+        input: ListDevelopmentSchemaArnsRequest,
+    ) -> SignedRequest {
         let request_uri = "/amazonclouddirectory/2017-01-11/schema/development";
 
         let mut request = SignedRequest::new("POST", "clouddirectory", &self.region, &request_uri);
+        request.set_operation("ListDevelopmentSchemaArns");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `list_directories`, also returning the metadata of the response.
+    pub async fn list_directories_with_metadata(
+        &self,
+        input: ListDirectoriesRequest,
+    ) -> Result<(ListDirectoriesResponse, ResponseMetadata), RusotoError<ListDirectoriesError>>
+    {
+        let request = self.build_list_directories_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListDevelopmentSchemaArnsResponse, _>()?;
+                .deserialize::<ListDirectoriesResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(ListDevelopmentSchemaArnsError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(ListDirectoriesError::from_response(response))
         }
     }
 
-    /// <p>Lists directories created within an account.</p>
-    async fn list_directories(
+    /// Presigns the request `list_directories` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_directories(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListDirectoriesRequest,
-    ) -> Result<ListDirectoriesResponse, RusotoError<ListDirectoriesError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_directories_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_directories_request(&self, input: ListDirectoriesRequest) -> SignedRequest {
         let request_uri = "/amazonclouddirectory/2017-01-11/directory/list";
 
         let mut request = SignedRequest::new("POST", "clouddirectory", &self.region, &request_uri);
+        request.set_operation("ListDirectories");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `list_facet_attributes`, also returning the metadata of the response.
+    pub async fn list_facet_attributes_with_metadata(
+        &self,
+        input: ListFacetAttributesRequest,
+    ) -> Result<
+        (ListFacetAttributesResponse, ResponseMetadata),
+        RusotoError<ListFacetAttributesError>,
+    > {
+        let request = self.build_list_facet_attributes_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListDirectoriesResponse, _>()?;
+                .deserialize::<ListFacetAttributesResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(ListDirectoriesError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(ListFacetAttributesError::from_response(response))
         }
     }
 
-    /// <p>Retrieves attributes attached to the facet.</p>
-    async fn list_facet_attributes(
+    /// Presigns the request `list_facet_attributes` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_facet_attributes(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListFacetAttributesRequest,
-    ) -> Result<ListFacetAttributesResponse, RusotoError<ListFacetAttributesError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_facet_attributes_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_facet_attributes_request(
+        &self,
+        input: ListFacetAttributesRequest,
+    ) -> SignedRequest {
         let request_uri = "/amazonclouddirectory/2017-01-11/facet/attributes";
 
         let mut request = SignedRequest::new("POST", "clouddirectory", &self.region, &request_uri);
+        request.set_operation("ListFacetAttributes");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
         request.add_header("x-amz-data-partition", &input.schema_arn);
 
+        request
+    }
+
+    /// Calls `list_facet_names`, also returning the metadata of the response.
+    pub async fn list_facet_names_with_metadata(
+        &self,
+        input: ListFacetNamesRequest,
+    ) -> Result<(ListFacetNamesResponse, ResponseMetadata), RusotoError<ListFacetNamesError>> {
+        let request = self.build_list_facet_names_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListFacetAttributesResponse, _>()?;
+                .deserialize::<ListFacetNamesResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(ListFacetAttributesError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(ListFacetNamesError::from_response(response))
         }
     }
 
-    /// <p>Retrieves the names of facets that exist in a schema.</p>
-    async fn list_facet_names(
+    /// Presigns the request `list_facet_names` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_facet_names(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListFacetNamesRequest,
-    ) -> Result<ListFacetNamesResponse, RusotoError<ListFacetNamesError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_facet_names_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_facet_names_request(&self, input: ListFacetNamesRequest) -> SignedRequest {
         let request_uri = "/amazonclouddirectory/2017-01-11/facet/list";
 
         let mut request = SignedRequest::new("POST", "clouddirectory", &self.region, &request_uri);
+        request.set_operation("ListFacetNames");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
         request.add_header("x-amz-data-partition", &input.schema_arn);
 
+        request
+    }
+
+    /// Calls `list_incoming_typed_links`, also returning the metadata of the response.
+    pub async fn list_incoming_typed_links_with_metadata(
+        &self,
+        input: ListIncomingTypedLinksRequest,
+    ) -> Result<
+        (ListIncomingTypedLinksResponse, ResponseMetadata),
+        RusotoError<ListIncomingTypedLinksError>,
+    > {
+        let request = self.build_list_incoming_typed_links_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListFacetNamesResponse, _>()?;
+                .deserialize::<ListIncomingTypedLinksResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(ListFacetNamesError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(ListIncomingTypedLinksError::from_response(response))
         }
     }
 
-    /// <p>Returns a paginated list of all the incoming <a>TypedLinkSpecifier</a> information for an object. It also supports filtering by typed link facet and identity attributes. For more information, see <a href="https://docs.aws.amazon.com/clouddirectory/latest/developerguide/directory_objects_links.html#directory_objects_links_typedlink">Typed Links</a>.</p>
-    async fn list_incoming_typed_links(
+    /// Presigns the request `list_incoming_typed_links` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_incoming_typed_links(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListIncomingTypedLinksRequest,
-    ) -> Result<ListIncomingTypedLinksResponse, RusotoError<ListIncomingTypedLinksError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_incoming_typed_links_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_incoming_typed_links_request(
+        &self,
+        input: ListIncomingTypedLinksRequest,
+    ) -> SignedRequest {
         let request_uri = "/amazonclouddirectory/2017-01-11/typedlink/incoming";
 
         let mut request = SignedRequest::new("POST", "clouddirectory", &self.region, &request_uri);
+        request.set_operation("ListIncomingTypedLinks");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
         request.add_header("x-amz-data-partition", &input.directory_arn);
 
+        request
+    }
+
+    /// Calls `list_index`, also returning the metadata of the response.
+    pub async fn list_index_with_metadata(
+        &self,
+        input: ListIndexRequest,
+    ) -> Result<(ListIndexResponse, ResponseMetadata), RusotoError<ListIndexError>> {
+        let request = self.build_list_index_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListIncomingTypedLinksResponse, _>()?;
+                .deserialize::<ListIndexResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(ListIncomingTypedLinksError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(ListIndexError::from_response(response))
         }
     }
 
-    /// <p>Lists objects attached to the specified index.</p>
-    async fn list_index(
+    /// Presigns the request `list_index` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_index(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListIndexRequest,
-    ) -> Result<ListIndexResponse, RusotoError<ListIndexError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_index_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_index_request(&self, input: ListIndexRequest) -> SignedRequest {
         let request_uri = "/amazonclouddirectory/2017-01-11/index/targets";
 
         let mut request = SignedRequest::new("POST", "clouddirectory", &self.region, &request_uri);
+        request.set_operation("ListIndex");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -9910,61 +11973,121 @@ impl CloudDirectory for CloudDirectoryClient {
         }
         request.add_header("x-amz-data-partition", &input.directory_arn);
 
+        request
+    }
+
+    /// Calls `list_managed_schema_arns`, also returning the metadata of the response.
+    pub async fn list_managed_schema_arns_with_metadata(
+        &self,
+        input: ListManagedSchemaArnsRequest,
+    ) -> Result<
+        (ListManagedSchemaArnsResponse, ResponseMetadata),
+        RusotoError<ListManagedSchemaArnsError>,
+    > {
+        let request = self.build_list_managed_schema_arns_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListIndexResponse, _>()?;
+                .deserialize::<ListManagedSchemaArnsResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(ListIndexError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(ListManagedSchemaArnsError::from_response(response))
         }
     }
 
-    /// <p>Lists the major version families of each managed schema. If a major version ARN is provided as SchemaArn, the minor version revisions in that family are listed instead.</p>
-    async fn list_managed_schema_arns(
+    /// Presigns the request `list_managed_schema_arns` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_managed_schema_arns(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListManagedSchemaArnsRequest,
-    ) -> Result<ListManagedSchemaArnsResponse, RusotoError<ListManagedSchemaArnsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_managed_schema_arns_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_managed_schema_arns_request(
+        &self,
+        input: ListManagedSchemaArnsRequest,
+    ) -> SignedRequest {
         let request_uri = "/amazonclouddirectory/2017-01-11/schema/managed";
 
         let mut request = SignedRequest::new("POST", "clouddirectory", &self.region, &request_uri);
+        request.set_operation("ListManagedSchemaArns");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `list_object_attributes`, also returning the metadata of the response.
+    pub async fn list_object_attributes_with_metadata(
+        &self,
+        input: ListObjectAttributesRequest,
+    ) -> Result<
+        (ListObjectAttributesResponse, ResponseMetadata),
+        RusotoError<ListObjectAttributesError>,
+    > {
+        let request = self.build_list_object_attributes_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListManagedSchemaArnsResponse, _>()?;
+                .deserialize::<ListObjectAttributesResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(ListManagedSchemaArnsError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(ListObjectAttributesError::from_response(response))
         }
     }
 
-    /// <p>Lists all attributes that are associated with an object. </p>
-    async fn list_object_attributes(
+    /// Presigns the request `list_object_attributes` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_object_attributes(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListObjectAttributesRequest,
-    ) -> Result<ListObjectAttributesResponse, RusotoError<ListObjectAttributesError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_object_attributes_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_object_attributes_request(
+        &self,
+        input: ListObjectAttributesRequest,
+    ) -> SignedRequest {
         let request_uri = "/amazonclouddirectory/2017-01-11/object/attributes";
 
         let mut request = SignedRequest::new("POST", "clouddirectory", &self.region, &request_uri);
+        request.set_operation("ListObjectAttributes");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -9975,31 +12098,59 @@ impl CloudDirectory for CloudDirectoryClient {
         }
         request.add_header("x-amz-data-partition", &input.directory_arn);
 
+        request
+    }
+
+    /// Calls `list_object_children`, also returning the metadata of the response.
+    pub async fn list_object_children_with_metadata(
+        &self,
+        input: ListObjectChildrenRequest,
+    ) -> Result<(ListObjectChildrenResponse, ResponseMetadata), RusotoError<ListObjectChildrenError>>
+    {
+        let request = self.build_list_object_children_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListObjectAttributesResponse, _>()?;
+                .deserialize::<ListObjectChildrenResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(ListObjectAttributesError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(ListObjectChildrenError::from_response(response))
         }
     }
 
-    /// <p>Returns a paginated list of child objects that are associated with a given object.</p>
-    async fn list_object_children(
+    /// Presigns the request `list_object_children` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_object_children(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListObjectChildrenRequest,
-    ) -> Result<ListObjectChildrenResponse, RusotoError<ListObjectChildrenError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_object_children_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_object_children_request(
+        &self,
+        input: ListObjectChildrenRequest,
+    ) -> SignedRequest {
         let request_uri = "/amazonclouddirectory/2017-01-11/object/children";
 
         let mut request = SignedRequest::new("POST", "clouddirectory", &self.region, &request_uri);
+        request.set_operation("ListObjectChildren");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -10010,62 +12161,117 @@ impl CloudDirectory for CloudDirectoryClient {
         }
         request.add_header("x-amz-data-partition", &input.directory_arn);
 
+        request
+    }
+
+    /// Calls `list_object_parent_paths`, also returning the metadata of the response.
+    pub async fn list_object_parent_paths_with_metadata(
+        &self,
+        input: ListObjectParentPathsRequest,
+    ) -> Result<
+        (ListObjectParentPathsResponse, ResponseMetadata),
+        RusotoError<ListObjectParentPathsError>,
+    > {
+        let request = self.build_list_object_parent_paths_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListObjectChildrenResponse, _>()?;
+                .deserialize::<ListObjectParentPathsResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(ListObjectChildrenError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(ListObjectParentPathsError::from_response(response))
         }
     }
 
-    /// <p>Retrieves all available parent paths for any object type such as node, leaf node, policy node, and index node objects. For more information about objects, see <a href="https://docs.aws.amazon.com/clouddirectory/latest/developerguide/key_concepts_directorystructure.html">Directory Structure</a>.</p> <p>Use this API to evaluate all parents for an object. The call returns all objects from the root of the directory up to the requested object. The API returns the number of paths based on user-defined <code>MaxResults</code>, in case there are multiple paths to the parent. The order of the paths and nodes returned is consistent among multiple API calls unless the objects are deleted or moved. Paths not leading to the directory root are ignored from the target object.</p>
-    async fn list_object_parent_paths(
+    /// Presigns the request `list_object_parent_paths` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_object_parent_paths(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListObjectParentPathsRequest,
-    ) -> Result<ListObjectParentPathsResponse, RusotoError<ListObjectParentPathsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_object_parent_paths_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_object_parent_paths_request(
+        &self,
+        input: ListObjectParentPathsRequest,
+    ) -> SignedRequest {
         let request_uri = "/amazonclouddirectory/2017-01-11/object/parentpaths";
 
         let mut request = SignedRequest::new("POST", "clouddirectory", &self.region, &request_uri);
+        request.set_operation("ListObjectParentPaths");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
         request.add_header("x-amz-data-partition", &input.directory_arn);
 
-        let mut response = self
-            .client
-            .sign_and_dispatch(request)
+        request
+    }
+
+    /// Calls `list_object_parents`, also returning the metadata of the response.
+    pub async fn list_object_parents_with_metadata(
+        &self,
+        input: ListObjectParentsRequest,
+    ) -> Result<(ListObjectParentsResponse, ResponseMetadata), RusotoError<ListObjectParentsError>>
+    {
+        let request = self.build_list_object_parents_request(input);
+
+        let mut response = self
+            .client
+            .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListObjectParentPathsResponse, _>()?;
+                .deserialize::<ListObjectParentsResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(ListObjectParentPathsError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(ListObjectParentsError::from_response(response))
         }
     }
 
-    /// <p>Lists parent objects that are associated with a given object in pagination fashion.</p>
-    async fn list_object_parents(
+    /// Presigns the request `list_object_parents` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_object_parents(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListObjectParentsRequest,
-    ) -> Result<ListObjectParentsResponse, RusotoError<ListObjectParentsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_object_parents_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_object_parents_request(&self, input: ListObjectParentsRequest) -> SignedRequest {
         let request_uri = "/amazonclouddirectory/2017-01-11/object/parent";
 
         let mut request = SignedRequest::new("POST", "clouddirectory", &self.region, &request_uri);
+        request.set_operation("ListObjectParents");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -10076,31 +12282,59 @@ impl CloudDirectory for CloudDirectoryClient {
         }
         request.add_header("x-amz-data-partition", &input.directory_arn);
 
+        request
+    }
+
+    /// Calls `list_object_policies`, also returning the metadata of the response.
+    pub async fn list_object_policies_with_metadata(
+        &self,
+        input: ListObjectPoliciesRequest,
+    ) -> Result<(ListObjectPoliciesResponse, ResponseMetadata), RusotoError<ListObjectPoliciesError>>
+    {
+        let request = self.build_list_object_policies_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListObjectParentsResponse, _>()?;
+                .deserialize::<ListObjectPoliciesResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(ListObjectParentsError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(ListObjectPoliciesError::from_response(response))
         }
     }
 
-    /// <p>Returns policies attached to an object in pagination fashion.</p>
-    async fn list_object_policies(
+    /// Presigns the request `list_object_policies` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_object_policies(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListObjectPoliciesRequest,
-    ) -> Result<ListObjectPoliciesResponse, RusotoError<ListObjectPoliciesError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_object_policies_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_object_policies_request(
+        &self,
+        input: ListObjectPoliciesRequest,
+    ) -> SignedRequest {
         let request_uri = "/amazonclouddirectory/2017-01-11/object/policy";
 
         let mut request = SignedRequest::new("POST", "clouddirectory", &self.region, &request_uri);
+        request.set_operation("ListObjectPolicies");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -10111,62 +12345,122 @@ impl CloudDirectory for CloudDirectoryClient {
         }
         request.add_header("x-amz-data-partition", &input.directory_arn);
 
+        request
+    }
+
+    /// Calls `list_outgoing_typed_links`, also returning the metadata of the response.
+    pub async fn list_outgoing_typed_links_with_metadata(
+        &self,
+        input: ListOutgoingTypedLinksRequest,
+    ) -> Result<
+        (ListOutgoingTypedLinksResponse, ResponseMetadata),
+        RusotoError<ListOutgoingTypedLinksError>,
+    > {
+        let request = self.build_list_outgoing_typed_links_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListObjectPoliciesResponse, _>()?;
+                .deserialize::<ListOutgoingTypedLinksResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(ListObjectPoliciesError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(ListOutgoingTypedLinksError::from_response(response))
         }
     }
 
-    /// <p>Returns a paginated list of all the outgoing <a>TypedLinkSpecifier</a> information for an object. It also supports filtering by typed link facet and identity attributes. For more information, see <a href="https://docs.aws.amazon.com/clouddirectory/latest/developerguide/directory_objects_links.html#directory_objects_links_typedlink">Typed Links</a>.</p>
-    async fn list_outgoing_typed_links(
+    /// Presigns the request `list_outgoing_typed_links` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_outgoing_typed_links(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListOutgoingTypedLinksRequest,
-    ) -> Result<ListOutgoingTypedLinksResponse, RusotoError<ListOutgoingTypedLinksError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_outgoing_typed_links_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_outgoing_typed_links_request(
+        &self,
+        input: ListOutgoingTypedLinksRequest,
+    ) -> SignedRequest {
         let request_uri = "/amazonclouddirectory/2017-01-11/typedlink/outgoing";
 
         let mut request = SignedRequest::new("POST", "clouddirectory", &self.region, &request_uri);
+        request.set_operation("ListOutgoingTypedLinks");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
         request.add_header("x-amz-data-partition", &input.directory_arn);
 
+        request
+    }
+
+    /// Calls `list_policy_attachments`, also returning the metadata of the response.
+    pub async fn list_policy_attachments_with_metadata(
+        &self,
+        input: ListPolicyAttachmentsRequest,
+    ) -> Result<
+        (ListPolicyAttachmentsResponse, ResponseMetadata),
+        RusotoError<ListPolicyAttachmentsError>,
+    > {
+        let request = self.build_list_policy_attachments_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListOutgoingTypedLinksResponse, _>()?;
+                .deserialize::<ListPolicyAttachmentsResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(ListOutgoingTypedLinksError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(ListPolicyAttachmentsError::from_response(response))
         }
     }
 
-    /// <p>Returns all of the <code>ObjectIdentifiers</code> to which a given policy is attached.</p>
-    async fn list_policy_attachments(
+    /// Presigns the request `list_policy_attachments` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_policy_attachments(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListPolicyAttachmentsRequest,
-    ) -> Result<ListPolicyAttachmentsResponse, RusotoError<ListPolicyAttachmentsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_policy_attachments_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_policy_attachments_request(
+        &self,
+        input: ListPolicyAttachmentsRequest,
+    ) -> SignedRequest {
         let request_uri = "/amazonclouddirectory/2017-01-11/policy/attachment";
 
         let mut request = SignedRequest::new("POST", "clouddirectory", &self.region, &request_uri);
+        request.set_operation("ListPolicyAttachments");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -10177,542 +12471,2402 @@ impl CloudDirectory for CloudDirectoryClient {
         }
         request.add_header("x-amz-data-partition", &input.directory_arn);
 
+        request
+    }
+
+    /// Calls `list_published_schema_arns`, also returning the metadata of the response.
+    pub async fn list_published_schema_arns_with_metadata(
+        &self,
+        input: ListPublishedSchemaArnsRequest,
+    ) -> Result<
+        (ListPublishedSchemaArnsResponse, ResponseMetadata),
+        RusotoError<ListPublishedSchemaArnsError>,
+    > {
+        let request = self.build_list_published_schema_arns_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListPolicyAttachmentsResponse, _>()?;
+                .deserialize::<ListPublishedSchemaArnsResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(ListPolicyAttachmentsError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(ListPublishedSchemaArnsError::from_response(response))
         }
     }
 
-    /// <p>Lists the major version families of each published schema. If a major version ARN is provided as <code>SchemaArn</code>, the minor version revisions in that family are listed instead.</p>
-    async fn list_published_schema_arns(
+    /// Presigns the request `list_published_schema_arns` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_published_schema_arns(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListPublishedSchemaArnsRequest,
-    ) -> Result<ListPublishedSchemaArnsResponse, RusotoError<ListPublishedSchemaArnsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_published_schema_arns_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_published_schema_arns_request(
+        &self,
+        input: ListPublishedSchemaArnsRequest,
+    ) -> SignedRequest {
         let request_uri = "/amazonclouddirectory/2017-01-11/schema/published";
 
         let mut request = SignedRequest::new("POST", "clouddirectory", &self.region, &request_uri);
+        request.set_operation("ListPublishedSchemaArns");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `list_tags_for_resource`, also returning the metadata of the response.
+    pub async fn list_tags_for_resource_with_metadata(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> Result<
+        (ListTagsForResourceResponse, ResponseMetadata),
+        RusotoError<ListTagsForResourceError>,
+    > {
+        let request = self.build_list_tags_for_resource_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListPublishedSchemaArnsResponse, _>()?;
+                .deserialize::<ListTagsForResourceResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(ListPublishedSchemaArnsError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(ListTagsForResourceError::from_response(response))
         }
     }
 
-    /// <p>Returns tags for a resource. Tagging is currently supported only for directories with a limit of 50 tags per directory. All 50 tags are returned for a given directory with this API call.</p>
-    async fn list_tags_for_resource(
+    /// Presigns the request `list_tags_for_resource` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_tags_for_resource(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListTagsForResourceRequest,
-    ) -> Result<ListTagsForResourceResponse, RusotoError<ListTagsForResourceError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_tags_for_resource_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_tags_for_resource_request(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> SignedRequest {
         let request_uri = "/amazonclouddirectory/2017-01-11/tags";
 
         let mut request = SignedRequest::new("POST", "clouddirectory", &self.region, &request_uri);
+        request.set_operation("ListTagsForResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `list_typed_link_facet_attributes`, also returning the metadata of the response.
+    pub async fn list_typed_link_facet_attributes_with_metadata(
+        &self,
+        input: ListTypedLinkFacetAttributesRequest,
+    ) -> Result<
+        (ListTypedLinkFacetAttributesResponse, ResponseMetadata),
+        RusotoError<ListTypedLinkFacetAttributesError>,
+    > {
+        let request = self.build_list_typed_link_facet_attributes_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListTagsForResourceResponse, _>()?;
+                .deserialize::<ListTypedLinkFacetAttributesResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(ListTagsForResourceError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(ListTypedLinkFacetAttributesError::from_response(response))
         }
     }
 
-    /// <p>Returns a paginated list of all attribute definitions for a particular <a>TypedLinkFacet</a>. For more information, see <a href="https://docs.aws.amazon.com/clouddirectory/latest/developerguide/directory_objects_links.html#directory_objects_links_typedlink">Typed Links</a>.</p>
-    async fn list_typed_link_facet_attributes(
+    /// Presigns the request `list_typed_link_facet_attributes` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_typed_link_facet_attributes(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListTypedLinkFacetAttributesRequest,
-    ) -> Result<ListTypedLinkFacetAttributesResponse, RusotoError<ListTypedLinkFacetAttributesError>>
-    {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_typed_link_facet_attributes_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_typed_link_facet_attributes_request(
+        &self,
+        input: ListTypedLinkFacetAttributesRequest,
+    ) -> SignedRequest {
         let request_uri = "/amazonclouddirectory/2017-01-11/typedlink/facet/attributes";
 
         let mut request = SignedRequest::new("POST", "clouddirectory", &self.region, &request_uri);
+        request.set_operation("ListTypedLinkFacetAttributes");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
         request.add_header("x-amz-data-partition", &input.schema_arn);
 
+        request
+    }
+
+    /// Calls `list_typed_link_facet_names`, also returning the metadata of the response.
+    pub async fn list_typed_link_facet_names_with_metadata(
+        &self,
+        input: ListTypedLinkFacetNamesRequest,
+    ) -> Result<
+        (ListTypedLinkFacetNamesResponse, ResponseMetadata),
+        RusotoError<ListTypedLinkFacetNamesError>,
+    > {
+        let request = self.build_list_typed_link_facet_names_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListTypedLinkFacetAttributesResponse, _>()?;
+                .deserialize::<ListTypedLinkFacetNamesResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(ListTypedLinkFacetAttributesError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(ListTypedLinkFacetNamesError::from_response(response))
         }
     }
 
-    /// <p>Returns a paginated list of <code>TypedLink</code> facet names for a particular schema. For more information, see <a href="https://docs.aws.amazon.com/clouddirectory/latest/developerguide/directory_objects_links.html#directory_objects_links_typedlink">Typed Links</a>.</p>
-    async fn list_typed_link_facet_names(
+    /// Presigns the request `list_typed_link_facet_names` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_typed_link_facet_names(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListTypedLinkFacetNamesRequest,
-    ) -> Result<ListTypedLinkFacetNamesResponse, RusotoError<ListTypedLinkFacetNamesError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_typed_link_facet_names_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_typed_link_facet_names_request(
+        &self,
+        input: ListTypedLinkFacetNamesRequest,
+    ) -> SignedRequest {
         let request_uri = "/amazonclouddirectory/2017-01-11/typedlink/facet/list";
 
         let mut request = SignedRequest::new("POST", "clouddirectory", &self.region, &request_uri);
+        request.set_operation("ListTypedLinkFacetNames");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
         request.add_header("x-amz-data-partition", &input.schema_arn);
 
+        request
+    }
+
+    /// Calls `lookup_policy`, also returning the metadata of the response.
+    pub async fn lookup_policy_with_metadata(
+        &self,
+        input: LookupPolicyRequest,
+    ) -> Result<(LookupPolicyResponse, ResponseMetadata), RusotoError<LookupPolicyError>> {
+        let request = self.build_lookup_policy_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListTypedLinkFacetNamesResponse, _>()?;
+                .deserialize::<LookupPolicyResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(ListTypedLinkFacetNamesError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(LookupPolicyError::from_response(response))
         }
     }
 
-    /// <p>Lists all policies from the root of the <a>Directory</a> to the object specified. If there are no policies present, an empty list is returned. If policies are present, and if some objects don't have the policies attached, it returns the <code>ObjectIdentifier</code> for such objects. If policies are present, it returns <code>ObjectIdentifier</code>, <code>policyId</code>, and <code>policyType</code>. Paths that don't lead to the root from the target object are ignored. For more information, see <a href="https://docs.aws.amazon.com/clouddirectory/latest/developerguide/key_concepts_directory.html#key_concepts_policies">Policies</a>.</p>
-    async fn lookup_policy(
+    /// Presigns the request `lookup_policy` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_lookup_policy(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: LookupPolicyRequest,
-    ) -> Result<LookupPolicyResponse, RusotoError<LookupPolicyError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_lookup_policy_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_lookup_policy_request(&self, input: LookupPolicyRequest) -> SignedRequest {
         let request_uri = "/amazonclouddirectory/2017-01-11/policy/lookup";
 
         let mut request = SignedRequest::new("POST", "clouddirectory", &self.region, &request_uri);
+        request.set_operation("LookupPolicy");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
         request.add_header("x-amz-data-partition", &input.directory_arn);
 
+        request
+    }
+
+    /// Calls `publish_schema`, also returning the metadata of the response.
+    pub async fn publish_schema_with_metadata(
+        &self,
+        input: PublishSchemaRequest,
+    ) -> Result<(PublishSchemaResponse, ResponseMetadata), RusotoError<PublishSchemaError>> {
+        let request = self.build_publish_schema_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<LookupPolicyResponse, _>()?;
+                .deserialize::<PublishSchemaResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(LookupPolicyError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(PublishSchemaError::from_response(response))
         }
     }
 
-    /// <p>Publishes a development schema with a major version and a recommended minor version.</p>
-    async fn publish_schema(
+    /// Presigns the request `publish_schema` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_publish_schema(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: PublishSchemaRequest,
-    ) -> Result<PublishSchemaResponse, RusotoError<PublishSchemaError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_publish_schema_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_publish_schema_request(&self, input: PublishSchemaRequest) -> SignedRequest {
         let request_uri = "/amazonclouddirectory/2017-01-11/schema/publish";
 
         let mut request = SignedRequest::new("PUT", "clouddirectory", &self.region, &request_uri);
+        request.set_operation("PublishSchema");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
         request.add_header("x-amz-data-partition", &input.development_schema_arn);
 
+        request
+    }
+
+    /// Calls `put_schema_from_json`, also returning the metadata of the response.
+    pub async fn put_schema_from_json_with_metadata(
+        &self,
+        input: PutSchemaFromJsonRequest,
+    ) -> Result<(PutSchemaFromJsonResponse, ResponseMetadata), RusotoError<PutSchemaFromJsonError>>
+    {
+        let request = self.build_put_schema_from_json_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<PublishSchemaResponse, _>()?;
+                .deserialize::<PutSchemaFromJsonResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(PublishSchemaError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(PutSchemaFromJsonError::from_response(response))
         }
     }
 
-    /// <p>Allows a schema to be updated using JSON upload. Only available for development schemas. See <a href="https://docs.aws.amazon.com/clouddirectory/latest/developerguide/schemas_jsonformat.html#schemas_json">JSON Schema Format</a> for more information.</p>
-    async fn put_schema_from_json(
+    /// Presigns the request `put_schema_from_json` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_put_schema_from_json(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: PutSchemaFromJsonRequest,
-    ) -> Result<PutSchemaFromJsonResponse, RusotoError<PutSchemaFromJsonError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_put_schema_from_json_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_put_schema_from_json_request(&self, input: PutSchemaFromJsonRequest) -> SignedRequest {
         let request_uri = "/amazonclouddirectory/2017-01-11/schema/json";
 
         let mut request = SignedRequest::new("PUT", "clouddirectory", &self.region, &request_uri);
+        request.set_operation("PutSchemaFromJson");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
         request.add_header("x-amz-data-partition", &input.schema_arn);
 
+        request
+    }
+
+    /// Calls `remove_facet_from_object`, also returning the metadata of the response.
+    pub async fn remove_facet_from_object_with_metadata(
+        &self,
+        input: RemoveFacetFromObjectRequest,
+    ) -> Result<
+        (RemoveFacetFromObjectResponse, ResponseMetadata),
+        RusotoError<RemoveFacetFromObjectError>,
+    > {
+        let request = self.build_remove_facet_from_object_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<PutSchemaFromJsonResponse, _>()?;
+                .deserialize::<RemoveFacetFromObjectResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(PutSchemaFromJsonError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(RemoveFacetFromObjectError::from_response(response))
         }
     }
 
-    /// <p>Removes the specified facet from the specified object.</p>
-    async fn remove_facet_from_object(
+    /// Presigns the request `remove_facet_from_object` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_remove_facet_from_object(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: RemoveFacetFromObjectRequest,
-    ) -> Result<RemoveFacetFromObjectResponse, RusotoError<RemoveFacetFromObjectError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_remove_facet_from_object_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_remove_facet_from_object_request(
+        &self,
+        input: RemoveFacetFromObjectRequest,
+    ) -> SignedRequest {
         let request_uri = "/amazonclouddirectory/2017-01-11/object/facets/delete";
 
         let mut request = SignedRequest::new("PUT", "clouddirectory", &self.region, &request_uri);
+        request.set_operation("RemoveFacetFromObject");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
         request.add_header("x-amz-data-partition", &input.directory_arn);
 
+        request
+    }
+
+    /// Calls `tag_resource`, also returning the metadata of the response.
+    pub async fn tag_resource_with_metadata(
+        &self,
+        input: TagResourceRequest,
+    ) -> Result<(TagResourceResponse, ResponseMetadata), RusotoError<TagResourceError>> {
+        let request = self.build_tag_resource_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<RemoveFacetFromObjectResponse, _>()?;
+                .deserialize::<TagResourceResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(RemoveFacetFromObjectError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(TagResourceError::from_response(response))
         }
     }
 
-    /// <p>An API operation for adding tags to a resource.</p>
-    async fn tag_resource(
+    /// Presigns the request `tag_resource` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_tag_resource(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: TagResourceRequest,
-    ) -> Result<TagResourceResponse, RusotoError<TagResourceError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_tag_resource_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_tag_resource_request(&self, input: TagResourceRequest) -> SignedRequest {
         let request_uri = "/amazonclouddirectory/2017-01-11/tags/add";
 
         let mut request = SignedRequest::new("PUT", "clouddirectory", &self.region, &request_uri);
+        request.set_operation("TagResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `untag_resource`, also returning the metadata of the response.
+    pub async fn untag_resource_with_metadata(
+        &self,
+        input: UntagResourceRequest,
+    ) -> Result<(UntagResourceResponse, ResponseMetadata), RusotoError<UntagResourceError>> {
+        let request = self.build_untag_resource_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<TagResourceResponse, _>()?;
+                .deserialize::<UntagResourceResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(TagResourceError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(UntagResourceError::from_response(response))
         }
     }
 
-    /// <p>An API operation for removing tags from a resource.</p>
-    async fn untag_resource(
+    /// Presigns the request `untag_resource` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_untag_resource(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UntagResourceRequest,
-    ) -> Result<UntagResourceResponse, RusotoError<UntagResourceError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_untag_resource_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_untag_resource_request(&self, input: UntagResourceRequest) -> SignedRequest {
         let request_uri = "/amazonclouddirectory/2017-01-11/tags/remove";
 
         let mut request = SignedRequest::new("PUT", "clouddirectory", &self.region, &request_uri);
+        request.set_operation("UntagResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `update_facet`, also returning the metadata of the response.
+    pub async fn update_facet_with_metadata(
+        &self,
+        input: UpdateFacetRequest,
+    ) -> Result<(UpdateFacetResponse, ResponseMetadata), RusotoError<UpdateFacetError>> {
+        let request = self.build_update_facet_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<UntagResourceResponse, _>()?;
+                .deserialize::<UpdateFacetResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(UntagResourceError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(UpdateFacetError::from_response(response))
         }
     }
 
-    /// <p><p>Does the following:</p> <ol> <li> <p>Adds new <code>Attributes</code>, <code>Rules</code>, or <code>ObjectTypes</code>.</p> </li> <li> <p>Updates existing <code>Attributes</code>, <code>Rules</code>, or <code>ObjectTypes</code>.</p> </li> <li> <p>Deletes existing <code>Attributes</code>, <code>Rules</code>, or <code>ObjectTypes</code>.</p> </li> </ol></p>
-    async fn update_facet(
+    /// Presigns the request `update_facet` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_facet(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UpdateFacetRequest,
-    ) -> Result<UpdateFacetResponse, RusotoError<UpdateFacetError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_facet_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_facet_request(&self, input: UpdateFacetRequest) -> SignedRequest {
         let request_uri = "/amazonclouddirectory/2017-01-11/facet";
 
         let mut request = SignedRequest::new("PUT", "clouddirectory", &self.region, &request_uri);
+        request.set_operation("UpdateFacet");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
         request.add_header("x-amz-data-partition", &input.schema_arn);
 
+        request
+    }
+
+    /// Calls `update_link_attributes`, also returning the metadata of the response.
+    pub async fn update_link_attributes_with_metadata(
+        &self,
+        input: UpdateLinkAttributesRequest,
+    ) -> Result<
+        (UpdateLinkAttributesResponse, ResponseMetadata),
+        RusotoError<UpdateLinkAttributesError>,
+    > {
+        let request = self.build_update_link_attributes_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<UpdateFacetResponse, _>()?;
+                .deserialize::<UpdateLinkAttributesResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(UpdateFacetError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(UpdateLinkAttributesError::from_response(response))
         }
     }
 
-    /// <p>Updates a given typed link’s attributes. Attributes to be updated must not contribute to the typed link’s identity, as defined by its <code>IdentityAttributeOrder</code>.</p>
-    async fn update_link_attributes(
+    /// Presigns the request `update_link_attributes` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_link_attributes(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UpdateLinkAttributesRequest,
-    ) -> Result<UpdateLinkAttributesResponse, RusotoError<UpdateLinkAttributesError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_link_attributes_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_link_attributes_request(
+        &self,
+        input: UpdateLinkAttributesRequest,
+    ) -> SignedRequest {
         let request_uri = "/amazonclouddirectory/2017-01-11/typedlink/attributes/update";
 
         let mut request = SignedRequest::new("POST", "clouddirectory", &self.region, &request_uri);
+        request.set_operation("UpdateLinkAttributes");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
         request.add_header("x-amz-data-partition", &input.directory_arn);
 
+        request
+    }
+
+    /// Calls `update_object_attributes`, also returning the metadata of the response.
+    pub async fn update_object_attributes_with_metadata(
+        &self,
+        input: UpdateObjectAttributesRequest,
+    ) -> Result<
+        (UpdateObjectAttributesResponse, ResponseMetadata),
+        RusotoError<UpdateObjectAttributesError>,
+    > {
+        let request = self.build_update_object_attributes_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<UpdateLinkAttributesResponse, _>()?;
+                .deserialize::<UpdateObjectAttributesResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(UpdateLinkAttributesError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(UpdateObjectAttributesError::from_response(response))
         }
     }
 
-    /// <p>Updates a given object's attributes.</p>
-    async fn update_object_attributes(
+    /// Presigns the request `update_object_attributes` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_object_attributes(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UpdateObjectAttributesRequest,
-    ) -> Result<UpdateObjectAttributesResponse, RusotoError<UpdateObjectAttributesError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_object_attributes_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_object_attributes_request(
+        &self,
+        input: UpdateObjectAttributesRequest,
+    ) -> SignedRequest {
         let request_uri = "/amazonclouddirectory/2017-01-11/object/update";
 
         let mut request = SignedRequest::new("PUT", "clouddirectory", &self.region, &request_uri);
+        request.set_operation("UpdateObjectAttributes");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
         request.add_header("x-amz-data-partition", &input.directory_arn);
 
+        request
+    }
+
+    /// Calls `update_schema`, also returning the metadata of the response.
+    pub async fn update_schema_with_metadata(
+        &self,
+        input: UpdateSchemaRequest,
+    ) -> Result<(UpdateSchemaResponse, ResponseMetadata), RusotoError<UpdateSchemaError>> {
+        let request = self.build_update_schema_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<UpdateObjectAttributesResponse, _>()?;
+                .deserialize::<UpdateSchemaResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(UpdateObjectAttributesError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(UpdateSchemaError::from_response(response))
         }
     }
 
-    /// <p>Updates the schema name with a new name. Only development schema names can be updated.</p>
-    async fn update_schema(
+    /// Presigns the request `update_schema` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_schema(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UpdateSchemaRequest,
-    ) -> Result<UpdateSchemaResponse, RusotoError<UpdateSchemaError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_schema_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_schema_request(&self, input: UpdateSchemaRequest) -> SignedRequest {
         let request_uri = "/amazonclouddirectory/2017-01-11/schema/update";
 
         let mut request = SignedRequest::new("PUT", "clouddirectory", &self.region, &request_uri);
+        request.set_operation("UpdateSchema");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
         request.add_header("x-amz-data-partition", &input.schema_arn);
 
+        request
+    }
+
+    /// Calls `update_typed_link_facet`, also returning the metadata of the response.
+    pub async fn update_typed_link_facet_with_metadata(
+        &self,
+        input: UpdateTypedLinkFacetRequest,
+    ) -> Result<
+        (UpdateTypedLinkFacetResponse, ResponseMetadata),
+        RusotoError<UpdateTypedLinkFacetError>,
+    > {
+        let request = self.build_update_typed_link_facet_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<UpdateSchemaResponse, _>()?;
+                .deserialize::<UpdateTypedLinkFacetResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(UpdateSchemaError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(UpdateTypedLinkFacetError::from_response(response))
         }
     }
 
-    /// <p>Updates a <a>TypedLinkFacet</a>. For more information, see <a href="https://docs.aws.amazon.com/clouddirectory/latest/developerguide/directory_objects_links.html#directory_objects_links_typedlink">Typed Links</a>.</p>
-    async fn update_typed_link_facet(
+    /// Presigns the request `update_typed_link_facet` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_typed_link_facet(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UpdateTypedLinkFacetRequest,
-    ) -> Result<UpdateTypedLinkFacetResponse, RusotoError<UpdateTypedLinkFacetError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_typed_link_facet_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_typed_link_facet_request(
+        &self,
+        input: UpdateTypedLinkFacetRequest,
+    ) -> SignedRequest {
         let request_uri = "/amazonclouddirectory/2017-01-11/typedlink/facet";
 
         let mut request = SignedRequest::new("PUT", "clouddirectory", &self.region, &request_uri);
+        request.set_operation("UpdateTypedLinkFacet");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
         request.add_header("x-amz-data-partition", &input.schema_arn);
 
+        request
+    }
+
+    /// Calls `upgrade_applied_schema`, also returning the metadata of the response.
+    pub async fn upgrade_applied_schema_with_metadata(
+        &self,
+        input: UpgradeAppliedSchemaRequest,
+    ) -> Result<
+        (UpgradeAppliedSchemaResponse, ResponseMetadata),
+        RusotoError<UpgradeAppliedSchemaError>,
+    > {
+        let request = self.build_upgrade_applied_schema_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<UpdateTypedLinkFacetResponse, _>()?;
+                .deserialize::<UpgradeAppliedSchemaResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(UpdateTypedLinkFacetError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(UpgradeAppliedSchemaError::from_response(response))
         }
     }
 
-    /// <p>Upgrades a single directory in-place using the <code>PublishedSchemaArn</code> with schema updates found in <code>MinorVersion</code>. Backwards-compatible minor version upgrades are instantaneously available for readers on all objects in the directory. Note: This is a synchronous API call and upgrades only one schema on a given directory per call. To upgrade multiple directories from one schema, you would need to call this API on each directory.</p>
-    async fn upgrade_applied_schema(
+    /// Presigns the request `upgrade_applied_schema` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_upgrade_applied_schema(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UpgradeAppliedSchemaRequest,
-    ) -> Result<UpgradeAppliedSchemaResponse, RusotoError<UpgradeAppliedSchemaError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_upgrade_applied_schema_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_upgrade_applied_schema_request(
+        &self,
+        input: UpgradeAppliedSchemaRequest,
+    ) -> SignedRequest {
         let request_uri = "/amazonclouddirectory/2017-01-11/schema/upgradeapplied";
 
         let mut request = SignedRequest::new("PUT", "clouddirectory", &self.region, &request_uri);
+        request.set_operation("UpgradeAppliedSchema");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `upgrade_published_schema`, also returning the metadata of the response.
+    pub async fn upgrade_published_schema_with_metadata(
+        &self,
+        input: UpgradePublishedSchemaRequest,
+    ) -> Result<
+        (UpgradePublishedSchemaResponse, ResponseMetadata),
+        RusotoError<UpgradePublishedSchemaError>,
+    > {
+        let request = self.build_upgrade_published_schema_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<UpgradeAppliedSchemaResponse, _>()?;
+                .deserialize::<UpgradePublishedSchemaResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(UpgradeAppliedSchemaError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(UpgradePublishedSchemaError::from_response(response))
         }
     }
 
-    /// <p>Upgrades a published schema under a new minor version revision using the current contents of <code>DevelopmentSchemaArn</code>.</p>
-    async fn upgrade_published_schema(
+    /// Presigns the request `upgrade_published_schema` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_upgrade_published_schema(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UpgradePublishedSchemaRequest,
-    ) -> Result<UpgradePublishedSchemaResponse, RusotoError<UpgradePublishedSchemaError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_upgrade_published_schema_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_upgrade_published_schema_request(
+        &self,
+        input: UpgradePublishedSchemaRequest,
+    ) -> SignedRequest {
         let request_uri = "/amazonclouddirectory/2017-01-11/schema/upgradepublished";
 
         let mut request = SignedRequest::new("PUT", "clouddirectory", &self.region, &request_uri);
+        request.set_operation("UpgradePublishedSchema");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
-        let mut response = self
-            .client
-            .sign_and_dispatch(request)
+        request
+    }
+
+    /// The pages of `ListAppliedSchemaArns` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `MaxResults` of `input`.
+    pub fn list_applied_schema_arns_pages(
+        &self,
+        input: ListAppliedSchemaArnsRequest,
+    ) -> impl futures::Stream<
+        Item = Result<ListAppliedSchemaArnsResponse, RusotoError<ListAppliedSchemaArnsError>>,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: CloudDirectoryClient, input| async move {
+                client.list_applied_schema_arns(input).await
+            },
+            |input: &ListAppliedSchemaArnsRequest, output: &ListAppliedSchemaArnsResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListAppliedSchemaArnsRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `SchemaArns` of every page of `ListAppliedSchemaArns` results for `input`, as `list_applied_schema_arns_pages` requests them.
+    pub fn list_applied_schema_arns_stream(
+        &self,
+        input: ListAppliedSchemaArnsRequest,
+    ) -> impl futures::Stream<Item = Result<String, RusotoError<ListAppliedSchemaArnsError>>> + Send
+    {
+        rusoto_core::pagination::items(self.list_applied_schema_arns_pages(input), |page| {
+            page.schema_arns.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `ListAttachedIndices` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `MaxResults` of `input`.
+    pub fn list_attached_indices_pages(
+        &self,
+        input: ListAttachedIndicesRequest,
+    ) -> impl futures::Stream<
+        Item = Result<ListAttachedIndicesResponse, RusotoError<ListAttachedIndicesError>>,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: CloudDirectoryClient, input| async move {
+                client.list_attached_indices(input).await
+            },
+            |input: &ListAttachedIndicesRequest, output: &ListAttachedIndicesResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListAttachedIndicesRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `IndexAttachments` of every page of `ListAttachedIndices` results for `input`, as `list_attached_indices_pages` requests them.
+    pub fn list_attached_indices_stream(
+        &self,
+        input: ListAttachedIndicesRequest,
+    ) -> impl futures::Stream<Item = Result<IndexAttachment, RusotoError<ListAttachedIndicesError>>> + Send
+    {
+        rusoto_core::pagination::items(self.list_attached_indices_pages(input), |page| {
+            page.index_attachments.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `ListDevelopmentSchemaArns` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `MaxResults` of `input`.
+    pub fn list_development_schema_arns_pages(
+        &self,
+        input: ListDevelopmentSchemaArnsRequest,
+    ) -> impl futures::Stream<
+        Item = Result<
+            ListDevelopmentSchemaArnsResponse,
+            RusotoError<ListDevelopmentSchemaArnsError>,
+        >,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: CloudDirectoryClient, input| async move {
+                client.list_development_schema_arns(input).await
+            },
+            |input: &ListDevelopmentSchemaArnsRequest,
+             output: &ListDevelopmentSchemaArnsResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListDevelopmentSchemaArnsRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `SchemaArns` of every page of `ListDevelopmentSchemaArns` results for `input`, as `list_development_schema_arns_pages` requests them.
+    pub fn list_development_schema_arns_stream(
+        &self,
+        input: ListDevelopmentSchemaArnsRequest,
+    ) -> impl futures::Stream<Item = Result<String, RusotoError<ListDevelopmentSchemaArnsError>>> + Send
+    {
+        rusoto_core::pagination::items(self.list_development_schema_arns_pages(input), |page| {
+            page.schema_arns.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `ListDirectories` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `MaxResults` of `input`.
+    pub fn list_directories_pages(
+        &self,
+        input: ListDirectoriesRequest,
+    ) -> impl futures::Stream<
+        Item = Result<ListDirectoriesResponse, RusotoError<ListDirectoriesError>>,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: CloudDirectoryClient, input| async move { client.list_directories(input).await },
+            |input: &ListDirectoriesRequest, output: &ListDirectoriesResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListDirectoriesRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `Directories` of every page of `ListDirectories` results for `input`, as `list_directories_pages` requests them.
+    pub fn list_directories_stream(
+        &self,
+        input: ListDirectoriesRequest,
+    ) -> impl futures::Stream<Item = Result<Directory, RusotoError<ListDirectoriesError>>> + Send
+    {
+        rusoto_core::pagination::items(self.list_directories_pages(input), |page| page.directories)
+    }
+
+    /// The pages of `ListFacetAttributes` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `MaxResults` of `input`.
+    pub fn list_facet_attributes_pages(
+        &self,
+        input: ListFacetAttributesRequest,
+    ) -> impl futures::Stream<
+        Item = Result<ListFacetAttributesResponse, RusotoError<ListFacetAttributesError>>,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: CloudDirectoryClient, input| async move {
+                client.list_facet_attributes(input).await
+            },
+            |input: &ListFacetAttributesRequest, output: &ListFacetAttributesResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListFacetAttributesRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `Attributes` of every page of `ListFacetAttributes` results for `input`, as `list_facet_attributes_pages` requests them.
+    pub fn list_facet_attributes_stream(
+        &self,
+        input: ListFacetAttributesRequest,
+    ) -> impl futures::Stream<Item = Result<FacetAttribute, RusotoError<ListFacetAttributesError>>> + Send
+    {
+        rusoto_core::pagination::items(self.list_facet_attributes_pages(input), |page| {
+            page.attributes.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `ListFacetNames` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `MaxResults` of `input`.
+    pub fn list_facet_names_pages(
+        &self,
+        input: ListFacetNamesRequest,
+    ) -> impl futures::Stream<Item = Result<ListFacetNamesResponse, RusotoError<ListFacetNamesError>>>
+           + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: CloudDirectoryClient, input| async move { client.list_facet_names(input).await },
+            |input: &ListFacetNamesRequest, output: &ListFacetNamesResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListFacetNamesRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `FacetNames` of every page of `ListFacetNames` results for `input`, as `list_facet_names_pages` requests them.
+    pub fn list_facet_names_stream(
+        &self,
+        input: ListFacetNamesRequest,
+    ) -> impl futures::Stream<Item = Result<String, RusotoError<ListFacetNamesError>>> + Send {
+        rusoto_core::pagination::items(self.list_facet_names_pages(input), |page| {
+            page.facet_names.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `ListIncomingTypedLinks` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `MaxResults` of `input`.
+    pub fn list_incoming_typed_links_pages(
+        &self,
+        input: ListIncomingTypedLinksRequest,
+    ) -> impl futures::Stream<
+        Item = Result<ListIncomingTypedLinksResponse, RusotoError<ListIncomingTypedLinksError>>,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: CloudDirectoryClient, input| async move {
+                client.list_incoming_typed_links(input).await
+            },
+            |input: &ListIncomingTypedLinksRequest, output: &ListIncomingTypedLinksResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListIncomingTypedLinksRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `LinkSpecifiers` of every page of `ListIncomingTypedLinks` results for `input`, as `list_incoming_typed_links_pages` requests them.
+    pub fn list_incoming_typed_links_stream(
+        &self,
+        input: ListIncomingTypedLinksRequest,
+    ) -> impl futures::Stream<
+        Item = Result<TypedLinkSpecifier, RusotoError<ListIncomingTypedLinksError>>,
+    > + Send {
+        rusoto_core::pagination::items(self.list_incoming_typed_links_pages(input), |page| {
+            page.link_specifiers.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `ListIndex` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `MaxResults` of `input`.
+    pub fn list_index_pages(
+        &self,
+        input: ListIndexRequest,
+    ) -> impl futures::Stream<Item = Result<ListIndexResponse, RusotoError<ListIndexError>>> + Send
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: CloudDirectoryClient, input| async move { client.list_index(input).await },
+            |input: &ListIndexRequest, output: &ListIndexResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListIndexRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `IndexAttachments` of every page of `ListIndex` results for `input`, as `list_index_pages` requests them.
+    pub fn list_index_stream(
+        &self,
+        input: ListIndexRequest,
+    ) -> impl futures::Stream<Item = Result<IndexAttachment, RusotoError<ListIndexError>>> + Send
+    {
+        rusoto_core::pagination::items(self.list_index_pages(input), |page| {
+            page.index_attachments.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `ListManagedSchemaArns` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `MaxResults` of `input`.
+    pub fn list_managed_schema_arns_pages(
+        &self,
+        input: ListManagedSchemaArnsRequest,
+    ) -> impl futures::Stream<
+        Item = Result<ListManagedSchemaArnsResponse, RusotoError<ListManagedSchemaArnsError>>,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: CloudDirectoryClient, input| async move {
+                client.list_managed_schema_arns(input).await
+            },
+            |input: &ListManagedSchemaArnsRequest, output: &ListManagedSchemaArnsResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListManagedSchemaArnsRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `SchemaArns` of every page of `ListManagedSchemaArns` results for `input`, as `list_managed_schema_arns_pages` requests them.
+    pub fn list_managed_schema_arns_stream(
+        &self,
+        input: ListManagedSchemaArnsRequest,
+    ) -> impl futures::Stream<Item = Result<String, RusotoError<ListManagedSchemaArnsError>>> + Send
+    {
+        rusoto_core::pagination::items(self.list_managed_schema_arns_pages(input), |page| {
+            page.schema_arns.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `ListObjectAttributes` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `MaxResults` of `input`.
+    pub fn list_object_attributes_pages(
+        &self,
+        input: ListObjectAttributesRequest,
+    ) -> impl futures::Stream<
+        Item = Result<ListObjectAttributesResponse, RusotoError<ListObjectAttributesError>>,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: CloudDirectoryClient, input| async move {
+                client.list_object_attributes(input).await
+            },
+            |input: &ListObjectAttributesRequest, output: &ListObjectAttributesResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListObjectAttributesRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `Attributes` of every page of `ListObjectAttributes` results for `input`, as `list_object_attributes_pages` requests them.
+    pub fn list_object_attributes_stream(
+        &self,
+        input: ListObjectAttributesRequest,
+    ) -> impl futures::Stream<
+        Item = Result<AttributeKeyAndValue, RusotoError<ListObjectAttributesError>>,
+    > + Send {
+        rusoto_core::pagination::items(self.list_object_attributes_pages(input), |page| {
+            page.attributes.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `ListObjectParentPaths` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `MaxResults` of `input`.
+    pub fn list_object_parent_paths_pages(
+        &self,
+        input: ListObjectParentPathsRequest,
+    ) -> impl futures::Stream<
+        Item = Result<ListObjectParentPathsResponse, RusotoError<ListObjectParentPathsError>>,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: CloudDirectoryClient, input| async move {
+                client.list_object_parent_paths(input).await
+            },
+            |input: &ListObjectParentPathsRequest, output: &ListObjectParentPathsResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListObjectParentPathsRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `PathToObjectIdentifiersList` of every page of `ListObjectParentPaths` results for `input`, as `list_object_parent_paths_pages` requests them.
+    pub fn list_object_parent_paths_stream(
+        &self,
+        input: ListObjectParentPathsRequest,
+    ) -> impl futures::Stream<
+        Item = Result<PathToObjectIdentifiers, RusotoError<ListObjectParentPathsError>>,
+    > + Send {
+        rusoto_core::pagination::items(self.list_object_parent_paths_pages(input), |page| {
+            page.path_to_object_identifiers_list.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `ListObjectPolicies` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `MaxResults` of `input`.
+    pub fn list_object_policies_pages(
+        &self,
+        input: ListObjectPoliciesRequest,
+    ) -> impl futures::Stream<
+        Item = Result<ListObjectPoliciesResponse, RusotoError<ListObjectPoliciesError>>,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: CloudDirectoryClient, input| async move {
+                client.list_object_policies(input).await
+            },
+            |input: &ListObjectPoliciesRequest, output: &ListObjectPoliciesResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListObjectPoliciesRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `AttachedPolicyIds` of every page of `ListObjectPolicies` results for `input`, as `list_object_policies_pages` requests them.
+    pub fn list_object_policies_stream(
+        &self,
+        input: ListObjectPoliciesRequest,
+    ) -> impl futures::Stream<Item = Result<String, RusotoError<ListObjectPoliciesError>>> + Send
+    {
+        rusoto_core::pagination::items(self.list_object_policies_pages(input), |page| {
+            page.attached_policy_ids.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `ListOutgoingTypedLinks` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `MaxResults` of `input`.
+    pub fn list_outgoing_typed_links_pages(
+        &self,
+        input: ListOutgoingTypedLinksRequest,
+    ) -> impl futures::Stream<
+        Item = Result<ListOutgoingTypedLinksResponse, RusotoError<ListOutgoingTypedLinksError>>,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: CloudDirectoryClient, input| async move {
+                client.list_outgoing_typed_links(input).await
+            },
+            |input: &ListOutgoingTypedLinksRequest, output: &ListOutgoingTypedLinksResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListOutgoingTypedLinksRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `TypedLinkSpecifiers` of every page of `ListOutgoingTypedLinks` results for `input`, as `list_outgoing_typed_links_pages` requests them.
+    pub fn list_outgoing_typed_links_stream(
+        &self,
+        input: ListOutgoingTypedLinksRequest,
+    ) -> impl futures::Stream<
+        Item = Result<TypedLinkSpecifier, RusotoError<ListOutgoingTypedLinksError>>,
+    > + Send {
+        rusoto_core::pagination::items(self.list_outgoing_typed_links_pages(input), |page| {
+            page.typed_link_specifiers.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `ListPolicyAttachments` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `MaxResults` of `input`.
+    pub fn list_policy_attachments_pages(
+        &self,
+        input: ListPolicyAttachmentsRequest,
+    ) -> impl futures::Stream<
+        Item = Result<ListPolicyAttachmentsResponse, RusotoError<ListPolicyAttachmentsError>>,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: CloudDirectoryClient, input| async move {
+                client.list_policy_attachments(input).await
+            },
+            |input: &ListPolicyAttachmentsRequest, output: &ListPolicyAttachmentsResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListPolicyAttachmentsRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `ObjectIdentifiers` of every page of `ListPolicyAttachments` results for `input`, as `list_policy_attachments_pages` requests them.
+    pub fn list_policy_attachments_stream(
+        &self,
+        input: ListPolicyAttachmentsRequest,
+    ) -> impl futures::Stream<Item = Result<String, RusotoError<ListPolicyAttachmentsError>>> + Send
+    {
+        rusoto_core::pagination::items(self.list_policy_attachments_pages(input), |page| {
+            page.object_identifiers.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `ListPublishedSchemaArns` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `MaxResults` of `input`.
+    pub fn list_published_schema_arns_pages(
+        &self,
+        input: ListPublishedSchemaArnsRequest,
+    ) -> impl futures::Stream<
+        Item = Result<ListPublishedSchemaArnsResponse, RusotoError<ListPublishedSchemaArnsError>>,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: CloudDirectoryClient, input| async move {
+                client.list_published_schema_arns(input).await
+            },
+            |input: &ListPublishedSchemaArnsRequest, output: &ListPublishedSchemaArnsResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListPublishedSchemaArnsRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `SchemaArns` of every page of `ListPublishedSchemaArns` results for `input`, as `list_published_schema_arns_pages` requests them.
+    pub fn list_published_schema_arns_stream(
+        &self,
+        input: ListPublishedSchemaArnsRequest,
+    ) -> impl futures::Stream<Item = Result<String, RusotoError<ListPublishedSchemaArnsError>>> + Send
+    {
+        rusoto_core::pagination::items(self.list_published_schema_arns_pages(input), |page| {
+            page.schema_arns.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `ListTagsForResource` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `MaxResults` of `input`.
+    pub fn list_tags_for_resource_pages(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> impl futures::Stream<
+        Item = Result<ListTagsForResourceResponse, RusotoError<ListTagsForResourceError>>,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: CloudDirectoryClient, input| async move {
+                client.list_tags_for_resource(input).await
+            },
+            |input: &ListTagsForResourceRequest, output: &ListTagsForResourceResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListTagsForResourceRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `Tags` of every page of `ListTagsForResource` results for `input`, as `list_tags_for_resource_pages` requests them.
+    pub fn list_tags_for_resource_stream(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> impl futures::Stream<Item = Result<Tag, RusotoError<ListTagsForResourceError>>> + Send
+    {
+        rusoto_core::pagination::items(self.list_tags_for_resource_pages(input), |page| {
+            page.tags.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `ListTypedLinkFacetAttributes` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `MaxResults` of `input`.
+    pub fn list_typed_link_facet_attributes_pages(
+        &self,
+        input: ListTypedLinkFacetAttributesRequest,
+    ) -> impl futures::Stream<
+        Item = Result<
+            ListTypedLinkFacetAttributesResponse,
+            RusotoError<ListTypedLinkFacetAttributesError>,
+        >,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: CloudDirectoryClient, input| async move {
+                client.list_typed_link_facet_attributes(input).await
+            },
+            |input: &ListTypedLinkFacetAttributesRequest,
+             output: &ListTypedLinkFacetAttributesResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListTypedLinkFacetAttributesRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `Attributes` of every page of `ListTypedLinkFacetAttributes` results for `input`, as `list_typed_link_facet_attributes_pages` requests them.
+    pub fn list_typed_link_facet_attributes_stream(
+        &self,
+        input: ListTypedLinkFacetAttributesRequest,
+    ) -> impl futures::Stream<
+        Item = Result<TypedLinkAttributeDefinition, RusotoError<ListTypedLinkFacetAttributesError>>,
+    > + Send {
+        rusoto_core::pagination::items(self.list_typed_link_facet_attributes_pages(input), |page| {
+            page.attributes.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `ListTypedLinkFacetNames` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `MaxResults` of `input`.
+    pub fn list_typed_link_facet_names_pages(
+        &self,
+        input: ListTypedLinkFacetNamesRequest,
+    ) -> impl futures::Stream<
+        Item = Result<ListTypedLinkFacetNamesResponse, RusotoError<ListTypedLinkFacetNamesError>>,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: CloudDirectoryClient, input| async move {
+                client.list_typed_link_facet_names(input).await
+            },
+            |input: &ListTypedLinkFacetNamesRequest, output: &ListTypedLinkFacetNamesResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListTypedLinkFacetNamesRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `FacetNames` of every page of `ListTypedLinkFacetNames` results for `input`, as `list_typed_link_facet_names_pages` requests them.
+    pub fn list_typed_link_facet_names_stream(
+        &self,
+        input: ListTypedLinkFacetNamesRequest,
+    ) -> impl futures::Stream<Item = Result<String, RusotoError<ListTypedLinkFacetNamesError>>> + Send
+    {
+        rusoto_core::pagination::items(self.list_typed_link_facet_names_pages(input), |page| {
+            page.facet_names.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `LookupPolicy` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `MaxResults` of `input`.
+    pub fn lookup_policy_pages(
+        &self,
+        input: LookupPolicyRequest,
+    ) -> impl futures::Stream<Item = Result<LookupPolicyResponse, RusotoError<LookupPolicyError>>> + Send
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: CloudDirectoryClient, input| async move { client.lookup_policy(input).await },
+            |input: &LookupPolicyRequest, output: &LookupPolicyResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(LookupPolicyRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `PolicyToPathList` of every page of `LookupPolicy` results for `input`, as `lookup_policy_pages` requests them.
+    pub fn lookup_policy_stream(
+        &self,
+        input: LookupPolicyRequest,
+    ) -> impl futures::Stream<Item = Result<PolicyToPath, RusotoError<LookupPolicyError>>> + Send
+    {
+        rusoto_core::pagination::items(self.lookup_policy_pages(input), |page| {
+            page.policy_to_path_list.unwrap_or_default()
+        })
+    }
+}
+
+#[async_trait]
+impl CloudDirectory for CloudDirectoryClient {
+    /// <p>Adds a new <a>Facet</a> to an object. An object can have more than one facet applied on it.</p>
+    async fn add_facet_to_object(
+        &self,
+        input: AddFacetToObjectRequest,
+    ) -> Result<AddFacetToObjectResponse, RusotoError<AddFacetToObjectError>> {
+        self.add_facet_to_object_with_metadata(input)
             .await
-            .map_err(RusotoError::from)?;
-        if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<UpgradePublishedSchemaResponse, _>()?;
+            .map(|(output, _)| output)
+    }
 
-            Ok(result)
-        } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(UpgradePublishedSchemaError::from_response(response))
-        }
+    /// <p>Copies the input published schema, at the specified version, into the <a>Directory</a> with the same name and version as that of the published schema.</p>
+    async fn apply_schema(
+        &self,
+        input: ApplySchemaRequest,
+    ) -> Result<ApplySchemaResponse, RusotoError<ApplySchemaError>> {
+        self.apply_schema_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Attaches an existing object to another object. An object can be accessed in two ways:</p> <ol> <li> <p>Using the path</p> </li> <li> <p>Using <code>ObjectIdentifier</code> </p> </li> </ol></p>
+    async fn attach_object(
+        &self,
+        input: AttachObjectRequest,
+    ) -> Result<AttachObjectResponse, RusotoError<AttachObjectError>> {
+        self.attach_object_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Attaches a policy object to a regular object. An object can have a limited number of attached policies.</p>
+    async fn attach_policy(
+        &self,
+        input: AttachPolicyRequest,
+    ) -> Result<AttachPolicyResponse, RusotoError<AttachPolicyError>> {
+        self.attach_policy_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Attaches the specified object to the specified index.</p>
+    async fn attach_to_index(
+        &self,
+        input: AttachToIndexRequest,
+    ) -> Result<AttachToIndexResponse, RusotoError<AttachToIndexError>> {
+        self.attach_to_index_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Attaches a typed link to a specified source and target object. For more information, see <a href="https://docs.aws.amazon.com/clouddirectory/latest/developerguide/directory_objects_links.html#directory_objects_links_typedlink">Typed Links</a>.</p>
+    async fn attach_typed_link(
+        &self,
+        input: AttachTypedLinkRequest,
+    ) -> Result<AttachTypedLinkResponse, RusotoError<AttachTypedLinkError>> {
+        self.attach_typed_link_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Performs all the read operations in a batch. </p>
+    async fn batch_read(
+        &self,
+        input: BatchReadRequest,
+    ) -> Result<BatchReadResponse, RusotoError<BatchReadError>> {
+        self.batch_read_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Performs all the write operations in a batch. Either all the operations succeed or none.</p>
+    async fn batch_write(
+        &self,
+        input: BatchWriteRequest,
+    ) -> Result<BatchWriteResponse, RusotoError<BatchWriteError>> {
+        self.batch_write_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Creates a <a>Directory</a> by copying the published schema into the directory. A directory cannot be created without a schema.</p> <p>You can also quickly create a directory using a managed schema, called the <code>QuickStartSchema</code>. For more information, see <a href="https://docs.aws.amazon.com/clouddirectory/latest/developerguide/schemas_managed.html">Managed Schema</a> in the <i>Amazon Cloud Directory Developer Guide</i>.</p>
+    async fn create_directory(
+        &self,
+        input: CreateDirectoryRequest,
+    ) -> Result<CreateDirectoryResponse, RusotoError<CreateDirectoryError>> {
+        self.create_directory_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Creates a new <a>Facet</a> in a schema. Facet creation is allowed only in development or applied schemas.</p>
+    async fn create_facet(
+        &self,
+        input: CreateFacetRequest,
+    ) -> Result<CreateFacetResponse, RusotoError<CreateFacetError>> {
+        self.create_facet_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Creates an index object. See <a href="https://docs.aws.amazon.com/clouddirectory/latest/developerguide/indexing_search.html">Indexing and search</a> for more information.</p>
+    async fn create_index(
+        &self,
+        input: CreateIndexRequest,
+    ) -> Result<CreateIndexResponse, RusotoError<CreateIndexError>> {
+        self.create_index_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Creates an object in a <a>Directory</a>. Additionally attaches the object to a parent, if a parent reference and <code>LinkName</code> is specified. An object is simply a collection of <a>Facet</a> attributes. You can also use this API call to create a policy object, if the facet from which you create the object is a policy facet. </p>
+    async fn create_object(
+        &self,
+        input: CreateObjectRequest,
+    ) -> Result<CreateObjectResponse, RusotoError<CreateObjectError>> {
+        self.create_object_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Creates a new schema in a development state. A schema can exist in three phases:</p> <ul> <li> <p> <i>Development:</i> This is a mutable phase of the schema. All new schemas are in the development phase. Once the schema is finalized, it can be published.</p> </li> <li> <p> <i>Published:</i> Published schemas are immutable and have a version associated with them.</p> </li> <li> <p> <i>Applied:</i> Applied schemas are mutable in a way that allows you to add new schema facets. You can also add new, nonrequired attributes to existing schema facets. You can apply only published schemas to directories. </p> </li> </ul></p>
+    async fn create_schema(
+        &self,
+        input: CreateSchemaRequest,
+    ) -> Result<CreateSchemaResponse, RusotoError<CreateSchemaError>> {
+        self.create_schema_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Creates a <a>TypedLinkFacet</a>. For more information, see <a href="https://docs.aws.amazon.com/clouddirectory/latest/developerguide/directory_objects_links.html#directory_objects_links_typedlink">Typed Links</a>.</p>
+    async fn create_typed_link_facet(
+        &self,
+        input: CreateTypedLinkFacetRequest,
+    ) -> Result<CreateTypedLinkFacetResponse, RusotoError<CreateTypedLinkFacetError>> {
+        self.create_typed_link_facet_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes a directory. Only disabled directories can be deleted. A deleted directory cannot be undone. Exercise extreme caution when deleting directories.</p>
+    async fn delete_directory(
+        &self,
+        input: DeleteDirectoryRequest,
+    ) -> Result<DeleteDirectoryResponse, RusotoError<DeleteDirectoryError>> {
+        self.delete_directory_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes a given <a>Facet</a>. All attributes and <a>Rule</a>s that are associated with the facet will be deleted. Only development schema facets are allowed deletion.</p>
+    async fn delete_facet(
+        &self,
+        input: DeleteFacetRequest,
+    ) -> Result<DeleteFacetResponse, RusotoError<DeleteFacetError>> {
+        self.delete_facet_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes an object and its associated attributes. Only objects with no children and no parents can be deleted. The maximum number of attributes that can be deleted during an object deletion is 30. For more information, see <a href="https://docs.aws.amazon.com/clouddirectory/latest/developerguide/limits.html">Amazon Cloud Directory Limits</a>.</p>
+    async fn delete_object(
+        &self,
+        input: DeleteObjectRequest,
+    ) -> Result<DeleteObjectResponse, RusotoError<DeleteObjectError>> {
+        self.delete_object_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes a given schema. Schemas in a development and published state can only be deleted. </p>
+    async fn delete_schema(
+        &self,
+        input: DeleteSchemaRequest,
+    ) -> Result<DeleteSchemaResponse, RusotoError<DeleteSchemaError>> {
+        self.delete_schema_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes a <a>TypedLinkFacet</a>. For more information, see <a href="https://docs.aws.amazon.com/clouddirectory/latest/developerguide/directory_objects_links.html#directory_objects_links_typedlink">Typed Links</a>.</p>
+    async fn delete_typed_link_facet(
+        &self,
+        input: DeleteTypedLinkFacetRequest,
+    ) -> Result<DeleteTypedLinkFacetResponse, RusotoError<DeleteTypedLinkFacetError>> {
+        self.delete_typed_link_facet_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Detaches the specified object from the specified index.</p>
+    async fn detach_from_index(
+        &self,
+        input: DetachFromIndexRequest,
+    ) -> Result<DetachFromIndexResponse, RusotoError<DetachFromIndexError>> {
+        self.detach_from_index_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Detaches a given object from the parent object. The object that is to be detached from the parent is specified by the link name.</p>
+    async fn detach_object(
+        &self,
+        input: DetachObjectRequest,
+    ) -> Result<DetachObjectResponse, RusotoError<DetachObjectError>> {
+        self.detach_object_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Detaches a policy from an object.</p>
+    async fn detach_policy(
+        &self,
+        input: DetachPolicyRequest,
+    ) -> Result<DetachPolicyResponse, RusotoError<DetachPolicyError>> {
+        self.detach_policy_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Detaches a typed link from a specified source and target object. For more information, see <a href="https://docs.aws.amazon.com/clouddirectory/latest/developerguide/directory_objects_links.html#directory_objects_links_typedlink">Typed Links</a>.</p>
+    async fn detach_typed_link(
+        &self,
+        input: DetachTypedLinkRequest,
+    ) -> Result<(), RusotoError<DetachTypedLinkError>> {
+        self.detach_typed_link_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Disables the specified directory. Disabled directories cannot be read or written to. Only enabled directories can be disabled. Disabled directories may be reenabled.</p>
+    async fn disable_directory(
+        &self,
+        input: DisableDirectoryRequest,
+    ) -> Result<DisableDirectoryResponse, RusotoError<DisableDirectoryError>> {
+        self.disable_directory_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Enables the specified directory. Only disabled directories can be enabled. Once enabled, the directory can then be read and written to.</p>
+    async fn enable_directory(
+        &self,
+        input: EnableDirectoryRequest,
+    ) -> Result<EnableDirectoryResponse, RusotoError<EnableDirectoryError>> {
+        self.enable_directory_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns current applied schema version ARN, including the minor version in use.</p>
+    async fn get_applied_schema_version(
+        &self,
+        input: GetAppliedSchemaVersionRequest,
+    ) -> Result<GetAppliedSchemaVersionResponse, RusotoError<GetAppliedSchemaVersionError>> {
+        self.get_applied_schema_version_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Retrieves metadata about a directory.</p>
+    async fn get_directory(
+        &self,
+        input: GetDirectoryRequest,
+    ) -> Result<GetDirectoryResponse, RusotoError<GetDirectoryError>> {
+        self.get_directory_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Gets details of the <a>Facet</a>, such as facet name, attributes, <a>Rule</a>s, or <code>ObjectType</code>. You can call this on all kinds of schema facets -- published, development, or applied.</p>
+    async fn get_facet(
+        &self,
+        input: GetFacetRequest,
+    ) -> Result<GetFacetResponse, RusotoError<GetFacetError>> {
+        self.get_facet_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Retrieves attributes that are associated with a typed link.</p>
+    async fn get_link_attributes(
+        &self,
+        input: GetLinkAttributesRequest,
+    ) -> Result<GetLinkAttributesResponse, RusotoError<GetLinkAttributesError>> {
+        self.get_link_attributes_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Retrieves attributes within a facet that are associated with an object.</p>
+    async fn get_object_attributes(
+        &self,
+        input: GetObjectAttributesRequest,
+    ) -> Result<GetObjectAttributesResponse, RusotoError<GetObjectAttributesError>> {
+        self.get_object_attributes_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Retrieves metadata about an object.</p>
+    async fn get_object_information(
+        &self,
+        input: GetObjectInformationRequest,
+    ) -> Result<GetObjectInformationResponse, RusotoError<GetObjectInformationError>> {
+        self.get_object_information_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Retrieves a JSON representation of the schema. See <a href="https://docs.aws.amazon.com/clouddirectory/latest/developerguide/schemas_jsonformat.html#schemas_json">JSON Schema Format</a> for more information.</p>
+    async fn get_schema_as_json(
+        &self,
+        input: GetSchemaAsJsonRequest,
+    ) -> Result<GetSchemaAsJsonResponse, RusotoError<GetSchemaAsJsonError>> {
+        self.get_schema_as_json_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns the identity attribute order for a specific <a>TypedLinkFacet</a>. For more information, see <a href="https://docs.aws.amazon.com/clouddirectory/latest/developerguide/directory_objects_links.html#directory_objects_links_typedlink">Typed Links</a>.</p>
+    async fn get_typed_link_facet_information(
+        &self,
+        input: GetTypedLinkFacetInformationRequest,
+    ) -> Result<GetTypedLinkFacetInformationResponse, RusotoError<GetTypedLinkFacetInformationError>>
+    {
+        self.get_typed_link_facet_information_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Lists schema major versions applied to a directory. If <code>SchemaArn</code> is provided, lists the minor version.</p>
+    async fn list_applied_schema_arns(
+        &self,
+        input: ListAppliedSchemaArnsRequest,
+    ) -> Result<ListAppliedSchemaArnsResponse, RusotoError<ListAppliedSchemaArnsError>> {
+        self.list_applied_schema_arns_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Lists indices attached to the specified object.</p>
+    async fn list_attached_indices(
+        &self,
+        input: ListAttachedIndicesRequest,
+    ) -> Result<ListAttachedIndicesResponse, RusotoError<ListAttachedIndicesError>> {
+        self.list_attached_indices_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Retrieves each Amazon Resource Name (ARN) of schemas in the development state.</p>
+    async fn list_development_schema_arns(
+        &self,
+        input: ListDevelopmentSchemaArnsRequest,
+    ) -> Result<ListDevelopmentSchemaArnsResponse, RusotoError<ListDevelopmentSchemaArnsError>>
+    {
+        self.list_development_schema_arns_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Lists directories created within an account.</p>
+    async fn list_directories(
+        &self,
+        input: ListDirectoriesRequest,
+    ) -> Result<ListDirectoriesResponse, RusotoError<ListDirectoriesError>> {
+        self.list_directories_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Retrieves attributes attached to the facet.</p>
+    async fn list_facet_attributes(
+        &self,
+        input: ListFacetAttributesRequest,
+    ) -> Result<ListFacetAttributesResponse, RusotoError<ListFacetAttributesError>> {
+        self.list_facet_attributes_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Retrieves the names of facets that exist in a schema.</p>
+    async fn list_facet_names(
+        &self,
+        input: ListFacetNamesRequest,
+    ) -> Result<ListFacetNamesResponse, RusotoError<ListFacetNamesError>> {
+        self.list_facet_names_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns a paginated list of all the incoming <a>TypedLinkSpecifier</a> information for an object. It also supports filtering by typed link facet and identity attributes. For more information, see <a href="https://docs.aws.amazon.com/clouddirectory/latest/developerguide/directory_objects_links.html#directory_objects_links_typedlink">Typed Links</a>.</p>
+    async fn list_incoming_typed_links(
+        &self,
+        input: ListIncomingTypedLinksRequest,
+    ) -> Result<ListIncomingTypedLinksResponse, RusotoError<ListIncomingTypedLinksError>> {
+        self.list_incoming_typed_links_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Lists objects attached to the specified index.</p>
+    async fn list_index(
+        &self,
+        input: ListIndexRequest,
+    ) -> Result<ListIndexResponse, RusotoError<ListIndexError>> {
+        self.list_index_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Lists the major version families of each managed schema. If a major version ARN is provided as SchemaArn, the minor version revisions in that family are listed instead.</p>
+    async fn list_managed_schema_arns(
+        &self,
+        input: ListManagedSchemaArnsRequest,
+    ) -> Result<ListManagedSchemaArnsResponse, RusotoError<ListManagedSchemaArnsError>> {
+        self.list_managed_schema_arns_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Lists all attributes that are associated with an object. </p>
+    async fn list_object_attributes(
+        &self,
+        input: ListObjectAttributesRequest,
+    ) -> Result<ListObjectAttributesResponse, RusotoError<ListObjectAttributesError>> {
+        self.list_object_attributes_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns a paginated list of child objects that are associated with a given object.</p>
+    async fn list_object_children(
+        &self,
+        input: ListObjectChildrenRequest,
+    ) -> Result<ListObjectChildrenResponse, RusotoError<ListObjectChildrenError>> {
+        self.list_object_children_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Retrieves all available parent paths for any object type such as node, leaf node, policy node, and index node objects. For more information about objects, see <a href="https://docs.aws.amazon.com/clouddirectory/latest/developerguide/key_concepts_directorystructure.html">Directory Structure</a>.</p> <p>Use this API to evaluate all parents for an object. The call returns all objects from the root of the directory up to the requested object. The API returns the number of paths based on user-defined <code>MaxResults</code>, in case there are multiple paths to the parent. The order of the paths and nodes returned is consistent among multiple API calls unless the objects are deleted or moved. Paths not leading to the directory root are ignored from the target object.</p>
+    async fn list_object_parent_paths(
+        &self,
+        input: ListObjectParentPathsRequest,
+    ) -> Result<ListObjectParentPathsResponse, RusotoError<ListObjectParentPathsError>> {
+        self.list_object_parent_paths_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Lists parent objects that are associated with a given object in pagination fashion.</p>
+    async fn list_object_parents(
+        &self,
+        input: ListObjectParentsRequest,
+    ) -> Result<ListObjectParentsResponse, RusotoError<ListObjectParentsError>> {
+        self.list_object_parents_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns policies attached to an object in pagination fashion.</p>
+    async fn list_object_policies(
+        &self,
+        input: ListObjectPoliciesRequest,
+    ) -> Result<ListObjectPoliciesResponse, RusotoError<ListObjectPoliciesError>> {
+        self.list_object_policies_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns a paginated list of all the outgoing <a>TypedLinkSpecifier</a> information for an object. It also supports filtering by typed link facet and identity attributes. For more information, see <a href="https://docs.aws.amazon.com/clouddirectory/latest/developerguide/directory_objects_links.html#directory_objects_links_typedlink">Typed Links</a>.</p>
+    async fn list_outgoing_typed_links(
+        &self,
+        input: ListOutgoingTypedLinksRequest,
+    ) -> Result<ListOutgoingTypedLinksResponse, RusotoError<ListOutgoingTypedLinksError>> {
+        self.list_outgoing_typed_links_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns all of the <code>ObjectIdentifiers</code> to which a given policy is attached.</p>
+    async fn list_policy_attachments(
+        &self,
+        input: ListPolicyAttachmentsRequest,
+    ) -> Result<ListPolicyAttachmentsResponse, RusotoError<ListPolicyAttachmentsError>> {
+        self.list_policy_attachments_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Lists the major version families of each published schema. If a major version ARN is provided as <code>SchemaArn</code>, the minor version revisions in that family are listed instead.</p>
+    async fn list_published_schema_arns(
+        &self,
+        input: ListPublishedSchemaArnsRequest,
+    ) -> Result<ListPublishedSchemaArnsResponse, RusotoError<ListPublishedSchemaArnsError>> {
+        self.list_published_schema_arns_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns tags for a resource. Tagging is currently supported only for directories with a limit of 50 tags per directory. All 50 tags are returned for a given directory with this API call.</p>
+    async fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> Result<ListTagsForResourceResponse, RusotoError<ListTagsForResourceError>> {
+        self.list_tags_for_resource_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns a paginated list of all attribute definitions for a particular <a>TypedLinkFacet</a>. For more information, see <a href="https://docs.aws.amazon.com/clouddirectory/latest/developerguide/directory_objects_links.html#directory_objects_links_typedlink">Typed Links</a>.</p>
+    async fn list_typed_link_facet_attributes(
+        &self,
+        input: ListTypedLinkFacetAttributesRequest,
+    ) -> Result<ListTypedLinkFacetAttributesResponse, RusotoError<ListTypedLinkFacetAttributesError>>
+    {
+        self.list_typed_link_facet_attributes_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns a paginated list of <code>TypedLink</code> facet names for a particular schema. For more information, see <a href="https://docs.aws.amazon.com/clouddirectory/latest/developerguide/directory_objects_links.html#directory_objects_links_typedlink">Typed Links</a>.</p>
+    async fn list_typed_link_facet_names(
+        &self,
+        input: ListTypedLinkFacetNamesRequest,
+    ) -> Result<ListTypedLinkFacetNamesResponse, RusotoError<ListTypedLinkFacetNamesError>> {
+        self.list_typed_link_facet_names_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Lists all policies from the root of the <a>Directory</a> to the object specified. If there are no policies present, an empty list is returned. If policies are present, and if some objects don't have the policies attached, it returns the <code>ObjectIdentifier</code> for such objects. If policies are present, it returns <code>ObjectIdentifier</code>, <code>policyId</code>, and <code>policyType</code>. Paths that don't lead to the root from the target object are ignored. For more information, see <a href="https://docs.aws.amazon.com/clouddirectory/latest/developerguide/key_concepts_directory.html#key_concepts_policies">Policies</a>.</p>
+    async fn lookup_policy(
+        &self,
+        input: LookupPolicyRequest,
+    ) -> Result<LookupPolicyResponse, RusotoError<LookupPolicyError>> {
+        self.lookup_policy_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Publishes a development schema with a major version and a recommended minor version.</p>
+    async fn publish_schema(
+        &self,
+        input: PublishSchemaRequest,
+    ) -> Result<PublishSchemaResponse, RusotoError<PublishSchemaError>> {
+        self.publish_schema_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Allows a schema to be updated using JSON upload. Only available for development schemas. See <a href="https://docs.aws.amazon.com/clouddirectory/latest/developerguide/schemas_jsonformat.html#schemas_json">JSON Schema Format</a> for more information.</p>
+    async fn put_schema_from_json(
+        &self,
+        input: PutSchemaFromJsonRequest,
+    ) -> Result<PutSchemaFromJsonResponse, RusotoError<PutSchemaFromJsonError>> {
+        self.put_schema_from_json_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Removes the specified facet from the specified object.</p>
+    async fn remove_facet_from_object(
+        &self,
+        input: RemoveFacetFromObjectRequest,
+    ) -> Result<RemoveFacetFromObjectResponse, RusotoError<RemoveFacetFromObjectError>> {
+        self.remove_facet_from_object_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>An API operation for adding tags to a resource.</p>
+    async fn tag_resource(
+        &self,
+        input: TagResourceRequest,
+    ) -> Result<TagResourceResponse, RusotoError<TagResourceError>> {
+        self.tag_resource_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>An API operation for removing tags from a resource.</p>
+    async fn untag_resource(
+        &self,
+        input: UntagResourceRequest,
+    ) -> Result<UntagResourceResponse, RusotoError<UntagResourceError>> {
+        self.untag_resource_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Does the following:</p> <ol> <li> <p>Adds new <code>Attributes</code>, <code>Rules</code>, or <code>ObjectTypes</code>.</p> </li> <li> <p>Updates existing <code>Attributes</code>, <code>Rules</code>, or <code>ObjectTypes</code>.</p> </li> <li> <p>Deletes existing <code>Attributes</code>, <code>Rules</code>, or <code>ObjectTypes</code>.</p> </li> </ol></p>
+    async fn update_facet(
+        &self,
+        input: UpdateFacetRequest,
+    ) -> Result<UpdateFacetResponse, RusotoError<UpdateFacetError>> {
+        self.update_facet_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Updates a given typed link’s attributes. Attributes to be updated must not contribute to the typed link’s identity, as defined by its <code>IdentityAttributeOrder</code>.</p>
+    async fn update_link_attributes(
+        &self,
+        input: UpdateLinkAttributesRequest,
+    ) -> Result<UpdateLinkAttributesResponse, RusotoError<UpdateLinkAttributesError>> {
+        self.update_link_attributes_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Updates a given object's attributes.</p>
+    async fn update_object_attributes(
+        &self,
+        input: UpdateObjectAttributesRequest,
+    ) -> Result<UpdateObjectAttributesResponse, RusotoError<UpdateObjectAttributesError>> {
+        self.update_object_attributes_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Updates the schema name with a new name. Only development schema names can be updated.</p>
+    async fn update_schema(
+        &self,
+        input: UpdateSchemaRequest,
+    ) -> Result<UpdateSchemaResponse, RusotoError<UpdateSchemaError>> {
+        self.update_schema_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Updates a <a>TypedLinkFacet</a>. For more information, see <a href="https://docs.aws.amazon.com/clouddirectory/latest/developerguide/directory_objects_links.html#directory_objects_links_typedlink">Typed Links</a>.</p>
+    async fn update_typed_link_facet(
+        &self,
+        input: UpdateTypedLinkFacetRequest,
+    ) -> Result<UpdateTypedLinkFacetResponse, RusotoError<UpdateTypedLinkFacetError>> {
+        self.update_typed_link_facet_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Upgrades a single directory in-place using the <code>PublishedSchemaArn</code> with schema updates found in <code>MinorVersion</code>. Backwards-compatible minor version upgrades are instantaneously available for readers on all objects in the directory. Note: This is a synchronous API call and upgrades only one schema on a given directory per call. To upgrade multiple directories from one schema, you would need to call this API on each directory.</p>
+    async fn upgrade_applied_schema(
+        &self,
+        input: UpgradeAppliedSchemaRequest,
+    ) -> Result<UpgradeAppliedSchemaResponse, RusotoError<UpgradeAppliedSchemaError>> {
+        self.upgrade_applied_schema_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Upgrades a published schema under a new minor version revision using the current contents of <code>DevelopmentSchemaArn</code>.</p>
+    async fn upgrade_published_schema(
+        &self,
+        input: UpgradePublishedSchemaRequest,
+    ) -> Result<UpgradePublishedSchemaResponse, RusotoError<UpgradePublishedSchemaError>> {
+        self.upgrade_published_schema_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
     }
 }
@@ -16,8 +16,8 @@ use std::fmt;
 use async_trait::async_trait;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
-use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest, ResponseMetadata};
+use rusoto_core::{Client, ErrorMetadata, RusotoError};
 
 use rusoto_core::param::{Params, ServiceParams};
 use rusoto_core::proto::xml::error::*;
@@ -9046,9 +9046,12 @@ impl VisibilityDeserializer {
 }
 /// Errors returned by CancelUpdateStack
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CancelUpdateStackError {
     /// <p>A client request token already exists.</p>
     TokenAlreadyExists(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CancelUpdateStackError {
@@ -9060,11 +9063,12 @@ impl CancelUpdateStackError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "TokenAlreadyExistsException" => {
-                        return RusotoError::Service(CancelUpdateStackError::TokenAlreadyExists(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            CancelUpdateStackError::TokenAlreadyExists(parsed_error.message),
+                            &res,
+                        )
                     }
-                    _ => {}
+                    _ => return RusotoError::unhandled(CancelUpdateStackError::Unhandled, &res),
                 }
             }
         }
@@ -9084,15 +9088,19 @@ impl fmt::Display for CancelUpdateStackError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             CancelUpdateStackError::TokenAlreadyExists(ref cause) => write!(f, "{}", cause),
+            CancelUpdateStackError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CancelUpdateStackError {}
 /// Errors returned by ContinueUpdateRollback
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ContinueUpdateRollbackError {
     /// <p>A client request token already exists.</p>
     TokenAlreadyExists(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ContinueUpdateRollbackError {
@@ -9104,11 +9112,14 @@ impl ContinueUpdateRollbackError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "TokenAlreadyExistsException" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             ContinueUpdateRollbackError::TokenAlreadyExists(parsed_error.message),
+                            &res,
                         )
                     }
-                    _ => {}
+                    _ => {
+                        return RusotoError::unhandled(ContinueUpdateRollbackError::Unhandled, &res)
+                    }
                 }
             }
         }
@@ -9128,12 +9139,14 @@ impl fmt::Display for ContinueUpdateRollbackError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             ContinueUpdateRollbackError::TokenAlreadyExists(ref cause) => write!(f, "{}", cause),
+            ContinueUpdateRollbackError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ContinueUpdateRollbackError {}
 /// Errors returned by CreateChangeSet
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateChangeSetError {
     /// <p>The resource with the name requested already exists.</p>
     AlreadyExists(String),
@@ -9141,6 +9154,8 @@ pub enum CreateChangeSetError {
     InsufficientCapabilities(String),
     /// <p>The quota for the resource has already been reached.</p> <p>For information on resource and stack limitations, see <a href="https://docs.aws.amazon.com/AWSCloudFormation/latest/UserGuide/cloudformation-limits.html">Limits</a> in the <i>AWS CloudFormation User Guide</i>.</p>
     LimitExceeded(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateChangeSetError {
@@ -9152,21 +9167,24 @@ impl CreateChangeSetError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "AlreadyExistsException" => {
-                        return RusotoError::Service(CreateChangeSetError::AlreadyExists(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            CreateChangeSetError::AlreadyExists(parsed_error.message),
+                            &res,
+                        )
                     }
                     "InsufficientCapabilitiesException" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             CreateChangeSetError::InsufficientCapabilities(parsed_error.message),
+                            &res,
                         )
                     }
                     "LimitExceededException" => {
-                        return RusotoError::Service(CreateChangeSetError::LimitExceeded(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            CreateChangeSetError::LimitExceeded(parsed_error.message),
+                            &res,
+                        )
                     }
-                    _ => {}
+                    _ => return RusotoError::unhandled(CreateChangeSetError::Unhandled, &res),
                 }
             }
         }
@@ -9188,12 +9206,14 @@ impl fmt::Display for CreateChangeSetError {
             CreateChangeSetError::AlreadyExists(ref cause) => write!(f, "{}", cause),
             CreateChangeSetError::InsufficientCapabilities(ref cause) => write!(f, "{}", cause),
             CreateChangeSetError::LimitExceeded(ref cause) => write!(f, "{}", cause),
+            CreateChangeSetError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateChangeSetError {}
 /// Errors returned by CreateStack
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateStackError {
     /// <p>The resource with the name requested already exists.</p>
     AlreadyExists(String),
@@ -9203,6 +9223,8 @@ pub enum CreateStackError {
     LimitExceeded(String),
     /// <p>A client request token already exists.</p>
     TokenAlreadyExists(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateStackError {
@@ -9214,26 +9236,30 @@ impl CreateStackError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "AlreadyExistsException" => {
-                        return RusotoError::Service(CreateStackError::AlreadyExists(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            CreateStackError::AlreadyExists(parsed_error.message),
+                            &res,
+                        )
                     }
                     "InsufficientCapabilitiesException" => {
-                        return RusotoError::Service(CreateStackError::InsufficientCapabilities(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            CreateStackError::InsufficientCapabilities(parsed_error.message),
+                            &res,
+                        )
                     }
                     "LimitExceededException" => {
-                        return RusotoError::Service(CreateStackError::LimitExceeded(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            CreateStackError::LimitExceeded(parsed_error.message),
+                            &res,
+                        )
                     }
                     "TokenAlreadyExistsException" => {
-                        return RusotoError::Service(CreateStackError::TokenAlreadyExists(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            CreateStackError::TokenAlreadyExists(parsed_error.message),
+                            &res,
+                        )
                     }
-                    _ => {}
+                    _ => return RusotoError::unhandled(CreateStackError::Unhandled, &res),
                 }
             }
         }
@@ -9256,12 +9282,14 @@ impl fmt::Display for CreateStackError {
             CreateStackError::InsufficientCapabilities(ref cause) => write!(f, "{}", cause),
             CreateStackError::LimitExceeded(ref cause) => write!(f, "{}", cause),
             CreateStackError::TokenAlreadyExists(ref cause) => write!(f, "{}", cause),
+            CreateStackError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateStackError {}
 /// Errors returned by CreateStackInstances
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateStackInstancesError {
     /// <p>The specified operation isn't valid.</p>
     InvalidOperation(String),
@@ -9275,6 +9303,8 @@ pub enum CreateStackInstancesError {
     StackSetNotFound(String),
     /// <p>Another operation has been performed on this stack set since the specified operation was performed. </p>
     StaleRequest(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateStackInstancesError {
@@ -9286,38 +9316,44 @@ impl CreateStackInstancesError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InvalidOperationException" => {
-                        return RusotoError::Service(CreateStackInstancesError::InvalidOperation(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            CreateStackInstancesError::InvalidOperation(parsed_error.message),
+                            &res,
+                        )
                     }
                     "LimitExceededException" => {
-                        return RusotoError::Service(CreateStackInstancesError::LimitExceeded(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            CreateStackInstancesError::LimitExceeded(parsed_error.message),
+                            &res,
+                        )
                     }
                     "OperationIdAlreadyExistsException" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             CreateStackInstancesError::OperationIdAlreadyExists(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "OperationInProgressException" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             CreateStackInstancesError::OperationInProgress(parsed_error.message),
+                            &res,
                         )
                     }
                     "StackSetNotFoundException" => {
-                        return RusotoError::Service(CreateStackInstancesError::StackSetNotFound(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            CreateStackInstancesError::StackSetNotFound(parsed_error.message),
+                            &res,
+                        )
                     }
                     "StaleRequestException" => {
-                        return RusotoError::Service(CreateStackInstancesError::StaleRequest(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            CreateStackInstancesError::StaleRequest(parsed_error.message),
+                            &res,
+                        )
                     }
-                    _ => {}
+                    _ => return RusotoError::unhandled(CreateStackInstancesError::Unhandled, &res),
                 }
             }
         }
@@ -9344,12 +9380,14 @@ impl fmt::Display for CreateStackInstancesError {
             CreateStackInstancesError::OperationInProgress(ref cause) => write!(f, "{}", cause),
             CreateStackInstancesError::StackSetNotFound(ref cause) => write!(f, "{}", cause),
             CreateStackInstancesError::StaleRequest(ref cause) => write!(f, "{}", cause),
+            CreateStackInstancesError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateStackInstancesError {}
 /// Errors returned by CreateStackSet
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateStackSetError {
     /// <p>The specified resource exists, but has been changed.</p>
     CreatedButModified(String),
@@ -9357,6 +9395,8 @@ pub enum CreateStackSetError {
     LimitExceeded(String),
     /// <p>The specified name is already in use.</p>
     NameAlreadyExists(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateStackSetError {
@@ -9368,21 +9408,24 @@ impl CreateStackSetError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "CreatedButModifiedException" => {
-                        return RusotoError::Service(CreateStackSetError::CreatedButModified(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            CreateStackSetError::CreatedButModified(parsed_error.message),
+                            &res,
+                        )
                     }
                     "LimitExceededException" => {
-                        return RusotoError::Service(CreateStackSetError::LimitExceeded(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            CreateStackSetError::LimitExceeded(parsed_error.message),
+                            &res,
+                        )
                     }
                     "NameAlreadyExistsException" => {
-                        return RusotoError::Service(CreateStackSetError::NameAlreadyExists(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            CreateStackSetError::NameAlreadyExists(parsed_error.message),
+                            &res,
+                        )
                     }
-                    _ => {}
+                    _ => return RusotoError::unhandled(CreateStackSetError::Unhandled, &res),
                 }
             }
         }
@@ -9404,15 +9447,19 @@ impl fmt::Display for CreateStackSetError {
             CreateStackSetError::CreatedButModified(ref cause) => write!(f, "{}", cause),
             CreateStackSetError::LimitExceeded(ref cause) => write!(f, "{}", cause),
             CreateStackSetError::NameAlreadyExists(ref cause) => write!(f, "{}", cause),
+            CreateStackSetError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateStackSetError {}
 /// Errors returned by DeleteChangeSet
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteChangeSetError {
     /// <p>The specified change set can't be used to update the stack. For example, the change set status might be <code>CREATE_IN_PROGRESS</code>, or the stack status might be <code>UPDATE_IN_PROGRESS</code>.</p>
     InvalidChangeSetStatus(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteChangeSetError {
@@ -9424,11 +9471,12 @@ impl DeleteChangeSetError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InvalidChangeSetStatus" => {
-                        return RusotoError::Service(DeleteChangeSetError::InvalidChangeSetStatus(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            DeleteChangeSetError::InvalidChangeSetStatus(parsed_error.message),
+                            &res,
+                        )
                     }
-                    _ => {}
+                    _ => return RusotoError::unhandled(DeleteChangeSetError::Unhandled, &res),
                 }
             }
         }
@@ -9448,15 +9496,19 @@ impl fmt::Display for DeleteChangeSetError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             DeleteChangeSetError::InvalidChangeSetStatus(ref cause) => write!(f, "{}", cause),
+            DeleteChangeSetError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteChangeSetError {}
 /// Errors returned by DeleteStack
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteStackError {
     /// <p>A client request token already exists.</p>
     TokenAlreadyExists(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteStackError {
@@ -9468,11 +9520,12 @@ impl DeleteStackError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "TokenAlreadyExistsException" => {
-                        return RusotoError::Service(DeleteStackError::TokenAlreadyExists(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            DeleteStackError::TokenAlreadyExists(parsed_error.message),
+                            &res,
+                        )
                     }
-                    _ => {}
+                    _ => return RusotoError::unhandled(DeleteStackError::Unhandled, &res),
                 }
             }
         }
@@ -9492,12 +9545,14 @@ impl fmt::Display for DeleteStackError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             DeleteStackError::TokenAlreadyExists(ref cause) => write!(f, "{}", cause),
+            DeleteStackError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteStackError {}
 /// Errors returned by DeleteStackInstances
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteStackInstancesError {
     /// <p>The specified operation isn't valid.</p>
     InvalidOperation(String),
@@ -9509,6 +9564,8 @@ pub enum DeleteStackInstancesError {
     StackSetNotFound(String),
     /// <p>Another operation has been performed on this stack set since the specified operation was performed. </p>
     StaleRequest(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteStackInstancesError {
@@ -9520,33 +9577,38 @@ impl DeleteStackInstancesError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InvalidOperationException" => {
-                        return RusotoError::Service(DeleteStackInstancesError::InvalidOperation(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            DeleteStackInstancesError::InvalidOperation(parsed_error.message),
+                            &res,
+                        )
                     }
                     "OperationIdAlreadyExistsException" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             DeleteStackInstancesError::OperationIdAlreadyExists(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "OperationInProgressException" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             DeleteStackInstancesError::OperationInProgress(parsed_error.message),
+                            &res,
                         )
                     }
                     "StackSetNotFoundException" => {
-                        return RusotoError::Service(DeleteStackInstancesError::StackSetNotFound(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            DeleteStackInstancesError::StackSetNotFound(parsed_error.message),
+                            &res,
+                        )
                     }
                     "StaleRequestException" => {
-                        return RusotoError::Service(DeleteStackInstancesError::StaleRequest(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            DeleteStackInstancesError::StaleRequest(parsed_error.message),
+                            &res,
+                        )
                     }
-                    _ => {}
+                    _ => return RusotoError::unhandled(DeleteStackInstancesError::Unhandled, &res),
                 }
             }
         }
@@ -9572,17 +9634,21 @@ impl fmt::Display for DeleteStackInstancesError {
             DeleteStackInstancesError::OperationInProgress(ref cause) => write!(f, "{}", cause),
             DeleteStackInstancesError::StackSetNotFound(ref cause) => write!(f, "{}", cause),
             DeleteStackInstancesError::StaleRequest(ref cause) => write!(f, "{}", cause),
+            DeleteStackInstancesError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteStackInstancesError {}
 /// Errors returned by DeleteStackSet
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteStackSetError {
     /// <p>Another operation is currently in progress for this stack set. Only one operation can be performed for a stack set at a given time.</p>
     OperationInProgress(String),
     /// <p>You can't yet delete this stack set, because it still contains one or more stack instances. Delete all stack instances from the stack set before deleting the stack set.</p>
     StackSetNotEmpty(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteStackSetError {
@@ -9594,16 +9660,18 @@ impl DeleteStackSetError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "OperationInProgressException" => {
-                        return RusotoError::Service(DeleteStackSetError::OperationInProgress(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            DeleteStackSetError::OperationInProgress(parsed_error.message),
+                            &res,
+                        )
                     }
                     "StackSetNotEmptyException" => {
-                        return RusotoError::Service(DeleteStackSetError::StackSetNotEmpty(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            DeleteStackSetError::StackSetNotEmpty(parsed_error.message),
+                            &res,
+                        )
                     }
-                    _ => {}
+                    _ => return RusotoError::unhandled(DeleteStackSetError::Unhandled, &res),
                 }
             }
         }
@@ -9624,17 +9692,21 @@ impl fmt::Display for DeleteStackSetError {
         match *self {
             DeleteStackSetError::OperationInProgress(ref cause) => write!(f, "{}", cause),
             DeleteStackSetError::StackSetNotEmpty(ref cause) => write!(f, "{}", cause),
+            DeleteStackSetError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteStackSetError {}
 /// Errors returned by DeregisterType
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeregisterTypeError {
     /// <p>An error occurred during a CloudFormation registry operation.</p>
     CFNRegistry(String),
     /// <p>The specified type does not exist in the CloudFormation registry.</p>
     TypeNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeregisterTypeError {
@@ -9646,16 +9718,18 @@ impl DeregisterTypeError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "CFNRegistryException" => {
-                        return RusotoError::Service(DeregisterTypeError::CFNRegistry(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            DeregisterTypeError::CFNRegistry(parsed_error.message),
+                            &res,
+                        )
                     }
                     "TypeNotFoundException" => {
-                        return RusotoError::Service(DeregisterTypeError::TypeNotFound(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            DeregisterTypeError::TypeNotFound(parsed_error.message),
+                            &res,
+                        )
                     }
-                    _ => {}
+                    _ => return RusotoError::unhandled(DeregisterTypeError::Unhandled, &res),
                 }
             }
         }
@@ -9676,13 +9750,18 @@ impl fmt::Display for DeregisterTypeError {
         match *self {
             DeregisterTypeError::CFNRegistry(ref cause) => write!(f, "{}", cause),
             DeregisterTypeError::TypeNotFound(ref cause) => write!(f, "{}", cause),
+            DeregisterTypeError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeregisterTypeError {}
 /// Errors returned by DescribeAccountLimits
 #[derive(Debug, PartialEq)]
-pub enum DescribeAccountLimitsError {}
+#[non_exhaustive]
+pub enum DescribeAccountLimitsError {
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
+}
 
 impl DescribeAccountLimitsError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<DescribeAccountLimitsError> {
@@ -9690,10 +9769,8 @@ impl DescribeAccountLimitsError {
             let reader = EventReader::new(res.body.as_ref());
             let mut stack = XmlResponse::new(reader.into_iter().peekable());
             find_start_element(&mut stack);
-            if let Ok(parsed_error) = Self::deserialize(&mut stack) {
-                match &parsed_error.code[..] {
-                    _ => {}
-                }
+            if Self::deserialize(&mut stack).is_ok() {
+                return RusotoError::unhandled(DescribeAccountLimitsError::Unhandled, &res);
             }
         }
         RusotoError::Unknown(res)
@@ -9710,15 +9787,20 @@ impl DescribeAccountLimitsError {
 impl fmt::Display for DescribeAccountLimitsError {
     #[allow(unused_variables)]
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        match *self {}
+        match *self {
+            DescribeAccountLimitsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
+        }
     }
 }
 impl Error for DescribeAccountLimitsError {}
 /// Errors returned by DescribeChangeSet
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeChangeSetError {
     /// <p>The specified change set name or ID doesn't exit. To view valid change sets for a stack, use the <code>ListChangeSets</code> action.</p>
     ChangeSetNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeChangeSetError {
@@ -9730,11 +9812,12 @@ impl DescribeChangeSetError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "ChangeSetNotFound" => {
-                        return RusotoError::Service(DescribeChangeSetError::ChangeSetNotFound(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            DescribeChangeSetError::ChangeSetNotFound(parsed_error.message),
+                            &res,
+                        )
                     }
-                    _ => {}
+                    _ => return RusotoError::unhandled(DescribeChangeSetError::Unhandled, &res),
                 }
             }
         }
@@ -9754,13 +9837,18 @@ impl fmt::Display for DescribeChangeSetError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             DescribeChangeSetError::ChangeSetNotFound(ref cause) => write!(f, "{}", cause),
+            DescribeChangeSetError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeChangeSetError {}
 /// Errors returned by DescribeStackDriftDetectionStatus
 #[derive(Debug, PartialEq)]
-pub enum DescribeStackDriftDetectionStatusError {}
+#[non_exhaustive]
+pub enum DescribeStackDriftDetectionStatusError {
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
+}
 
 impl DescribeStackDriftDetectionStatusError {
     pub fn from_response(
@@ -9770,10 +9858,11 @@ impl DescribeStackDriftDetectionStatusError {
             let reader = EventReader::new(res.body.as_ref());
             let mut stack = XmlResponse::new(reader.into_iter().peekable());
             find_start_element(&mut stack);
-            if let Ok(parsed_error) = Self::deserialize(&mut stack) {
-                match &parsed_error.code[..] {
-                    _ => {}
-                }
+            if Self::deserialize(&mut stack).is_ok() {
+                return RusotoError::unhandled(
+                    DescribeStackDriftDetectionStatusError::Unhandled,
+                    &res,
+                );
             }
         }
         RusotoError::Unknown(res)
@@ -9790,13 +9879,21 @@ impl DescribeStackDriftDetectionStatusError {
 impl fmt::Display for DescribeStackDriftDetectionStatusError {
     #[allow(unused_variables)]
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        match *self {}
+        match *self {
+            DescribeStackDriftDetectionStatusError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
+        }
     }
 }
 impl Error for DescribeStackDriftDetectionStatusError {}
 /// Errors returned by DescribeStackEvents
 #[derive(Debug, PartialEq)]
-pub enum DescribeStackEventsError {}
+#[non_exhaustive]
+pub enum DescribeStackEventsError {
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
+}
 
 impl DescribeStackEventsError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<DescribeStackEventsError> {
@@ -9804,10 +9901,8 @@ impl DescribeStackEventsError {
             let reader = EventReader::new(res.body.as_ref());
             let mut stack = XmlResponse::new(reader.into_iter().peekable());
             find_start_element(&mut stack);
-            if let Ok(parsed_error) = Self::deserialize(&mut stack) {
-                match &parsed_error.code[..] {
-                    _ => {}
-                }
+            if Self::deserialize(&mut stack).is_ok() {
+                return RusotoError::unhandled(DescribeStackEventsError::Unhandled, &res);
             }
         }
         RusotoError::Unknown(res)
@@ -9824,17 +9919,22 @@ impl DescribeStackEventsError {
 impl fmt::Display for DescribeStackEventsError {
     #[allow(unused_variables)]
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        match *self {}
+        match *self {
+            DescribeStackEventsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
+        }
     }
 }
 impl Error for DescribeStackEventsError {}
 /// Errors returned by DescribeStackInstance
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeStackInstanceError {
     /// <p>The specified stack instance doesn't exist.</p>
     StackInstanceNotFound(String),
     /// <p>The specified stack set doesn't exist.</p>
     StackSetNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeStackInstanceError {
@@ -9846,16 +9946,20 @@ impl DescribeStackInstanceError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "StackInstanceNotFoundException" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             DescribeStackInstanceError::StackInstanceNotFound(parsed_error.message),
+                            &res,
                         )
                     }
                     "StackSetNotFoundException" => {
-                        return RusotoError::Service(DescribeStackInstanceError::StackSetNotFound(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            DescribeStackInstanceError::StackSetNotFound(parsed_error.message),
+                            &res,
+                        )
+                    }
+                    _ => {
+                        return RusotoError::unhandled(DescribeStackInstanceError::Unhandled, &res)
                     }
-                    _ => {}
                 }
             }
         }
@@ -9876,13 +9980,18 @@ impl fmt::Display for DescribeStackInstanceError {
         match *self {
             DescribeStackInstanceError::StackInstanceNotFound(ref cause) => write!(f, "{}", cause),
             DescribeStackInstanceError::StackSetNotFound(ref cause) => write!(f, "{}", cause),
+            DescribeStackInstanceError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeStackInstanceError {}
 /// Errors returned by DescribeStackResource
 #[derive(Debug, PartialEq)]
-pub enum DescribeStackResourceError {}
+#[non_exhaustive]
+pub enum DescribeStackResourceError {
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
+}
 
 impl DescribeStackResourceError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<DescribeStackResourceError> {
@@ -9890,10 +9999,8 @@ impl DescribeStackResourceError {
             let reader = EventReader::new(res.body.as_ref());
             let mut stack = XmlResponse::new(reader.into_iter().peekable());
             find_start_element(&mut stack);
-            if let Ok(parsed_error) = Self::deserialize(&mut stack) {
-                match &parsed_error.code[..] {
-                    _ => {}
-                }
+            if Self::deserialize(&mut stack).is_ok() {
+                return RusotoError::unhandled(DescribeStackResourceError::Unhandled, &res);
             }
         }
         RusotoError::Unknown(res)
@@ -9910,13 +10017,19 @@ impl DescribeStackResourceError {
 impl fmt::Display for DescribeStackResourceError {
     #[allow(unused_variables)]
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        match *self {}
+        match *self {
+            DescribeStackResourceError::Unhandled(ref metadata) => write!(f, "{}", metadata),
+        }
     }
 }
 impl Error for DescribeStackResourceError {}
 /// Errors returned by DescribeStackResourceDrifts
 #[derive(Debug, PartialEq)]
-pub enum DescribeStackResourceDriftsError {}
+#[non_exhaustive]
+pub enum DescribeStackResourceDriftsError {
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
+}
 
 impl DescribeStackResourceDriftsError {
     pub fn from_response(
@@ -9926,10 +10039,8 @@ impl DescribeStackResourceDriftsError {
             let reader = EventReader::new(res.body.as_ref());
             let mut stack = XmlResponse::new(reader.into_iter().peekable());
             find_start_element(&mut stack);
-            if let Ok(parsed_error) = Self::deserialize(&mut stack) {
-                match &parsed_error.code[..] {
-                    _ => {}
-                }
+            if Self::deserialize(&mut stack).is_ok() {
+                return RusotoError::unhandled(DescribeStackResourceDriftsError::Unhandled, &res);
             }
         }
         RusotoError::Unknown(res)
@@ -9946,13 +10057,19 @@ impl DescribeStackResourceDriftsError {
 impl fmt::Display for DescribeStackResourceDriftsError {
     #[allow(unused_variables)]
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        match *self {}
+        match *self {
+            DescribeStackResourceDriftsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
+        }
     }
 }
 impl Error for DescribeStackResourceDriftsError {}
 /// Errors returned by DescribeStackResources
 #[derive(Debug, PartialEq)]
-pub enum DescribeStackResourcesError {}
+#[non_exhaustive]
+pub enum DescribeStackResourcesError {
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
+}
 
 impl DescribeStackResourcesError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<DescribeStackResourcesError> {
@@ -9960,10 +10077,8 @@ impl DescribeStackResourcesError {
             let reader = EventReader::new(res.body.as_ref());
             let mut stack = XmlResponse::new(reader.into_iter().peekable());
             find_start_element(&mut stack);
-            if let Ok(parsed_error) = Self::deserialize(&mut stack) {
-                match &parsed_error.code[..] {
-                    _ => {}
-                }
+            if Self::deserialize(&mut stack).is_ok() {
+                return RusotoError::unhandled(DescribeStackResourcesError::Unhandled, &res);
             }
         }
         RusotoError::Unknown(res)
@@ -9980,15 +10095,20 @@ impl DescribeStackResourcesError {
 impl fmt::Display for DescribeStackResourcesError {
     #[allow(unused_variables)]
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        match *self {}
+        match *self {
+            DescribeStackResourcesError::Unhandled(ref metadata) => write!(f, "{}", metadata),
+        }
     }
 }
 impl Error for DescribeStackResourcesError {}
 /// Errors returned by DescribeStackSet
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeStackSetError {
     /// <p>The specified stack set doesn't exist.</p>
     StackSetNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeStackSetError {
@@ -10000,11 +10120,12 @@ impl DescribeStackSetError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "StackSetNotFoundException" => {
-                        return RusotoError::Service(DescribeStackSetError::StackSetNotFound(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            DescribeStackSetError::StackSetNotFound(parsed_error.message),
+                            &res,
+                        )
                     }
-                    _ => {}
+                    _ => return RusotoError::unhandled(DescribeStackSetError::Unhandled, &res),
                 }
             }
         }
@@ -10024,17 +10145,21 @@ impl fmt::Display for DescribeStackSetError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             DescribeStackSetError::StackSetNotFound(ref cause) => write!(f, "{}", cause),
+            DescribeStackSetError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeStackSetError {}
 /// Errors returned by DescribeStackSetOperation
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeStackSetOperationError {
     /// <p>The specified ID refers to an operation that doesn't exist.</p>
     OperationNotFound(String),
     /// <p>The specified stack set doesn't exist.</p>
     StackSetNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeStackSetOperationError {
@@ -10046,16 +10171,23 @@ impl DescribeStackSetOperationError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "OperationNotFoundException" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             DescribeStackSetOperationError::OperationNotFound(parsed_error.message),
+                            &res,
                         )
                     }
                     "StackSetNotFoundException" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             DescribeStackSetOperationError::StackSetNotFound(parsed_error.message),
+                            &res,
+                        )
+                    }
+                    _ => {
+                        return RusotoError::unhandled(
+                            DescribeStackSetOperationError::Unhandled,
+                            &res,
                         )
                     }
-                    _ => {}
                 }
             }
         }
@@ -10076,13 +10208,18 @@ impl fmt::Display for DescribeStackSetOperationError {
         match *self {
             DescribeStackSetOperationError::OperationNotFound(ref cause) => write!(f, "{}", cause),
             DescribeStackSetOperationError::StackSetNotFound(ref cause) => write!(f, "{}", cause),
+            DescribeStackSetOperationError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeStackSetOperationError {}
 /// Errors returned by DescribeStacks
 #[derive(Debug, PartialEq)]
-pub enum DescribeStacksError {}
+#[non_exhaustive]
+pub enum DescribeStacksError {
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
+}
 
 impl DescribeStacksError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<DescribeStacksError> {
@@ -10090,10 +10227,8 @@ impl DescribeStacksError {
             let reader = EventReader::new(res.body.as_ref());
             let mut stack = XmlResponse::new(reader.into_iter().peekable());
             find_start_element(&mut stack);
-            if let Ok(parsed_error) = Self::deserialize(&mut stack) {
-                match &parsed_error.code[..] {
-                    _ => {}
-                }
+            if Self::deserialize(&mut stack).is_ok() {
+                return RusotoError::unhandled(DescribeStacksError::Unhandled, &res);
             }
         }
         RusotoError::Unknown(res)
@@ -10110,17 +10245,22 @@ impl DescribeStacksError {
 impl fmt::Display for DescribeStacksError {
     #[allow(unused_variables)]
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        match *self {}
+        match *self {
+            DescribeStacksError::Unhandled(ref metadata) => write!(f, "{}", metadata),
+        }
     }
 }
 impl Error for DescribeStacksError {}
 /// Errors returned by DescribeType
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeTypeError {
     /// <p>An error occurred during a CloudFormation registry operation.</p>
     CFNRegistry(String),
     /// <p>The specified type does not exist in the CloudFormation registry.</p>
     TypeNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeTypeError {
@@ -10132,16 +10272,18 @@ impl DescribeTypeError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "CFNRegistryException" => {
-                        return RusotoError::Service(DescribeTypeError::CFNRegistry(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            DescribeTypeError::CFNRegistry(parsed_error.message),
+                            &res,
+                        )
                     }
                     "TypeNotFoundException" => {
-                        return RusotoError::Service(DescribeTypeError::TypeNotFound(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            DescribeTypeError::TypeNotFound(parsed_error.message),
+                            &res,
+                        )
                     }
-                    _ => {}
+                    _ => return RusotoError::unhandled(DescribeTypeError::Unhandled, &res),
                 }
             }
         }
@@ -10162,15 +10304,19 @@ impl fmt::Display for DescribeTypeError {
         match *self {
             DescribeTypeError::CFNRegistry(ref cause) => write!(f, "{}", cause),
             DescribeTypeError::TypeNotFound(ref cause) => write!(f, "{}", cause),
+            DescribeTypeError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeTypeError {}
 /// Errors returned by DescribeTypeRegistration
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeTypeRegistrationError {
     /// <p>An error occurred during a CloudFormation registry operation.</p>
     CFNRegistry(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeTypeRegistrationError {
@@ -10182,11 +10328,17 @@ impl DescribeTypeRegistrationError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "CFNRegistryException" => {
-                        return RusotoError::Service(DescribeTypeRegistrationError::CFNRegistry(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            DescribeTypeRegistrationError::CFNRegistry(parsed_error.message),
+                            &res,
+                        )
+                    }
+                    _ => {
+                        return RusotoError::unhandled(
+                            DescribeTypeRegistrationError::Unhandled,
+                            &res,
+                        )
                     }
-                    _ => {}
                 }
             }
         }
@@ -10206,13 +10358,18 @@ impl fmt::Display for DescribeTypeRegistrationError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             DescribeTypeRegistrationError::CFNRegistry(ref cause) => write!(f, "{}", cause),
+            DescribeTypeRegistrationError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeTypeRegistrationError {}
 /// Errors returned by DetectStackDrift
 #[derive(Debug, PartialEq)]
-pub enum DetectStackDriftError {}
+#[non_exhaustive]
+pub enum DetectStackDriftError {
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
+}
 
 impl DetectStackDriftError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<DetectStackDriftError> {
@@ -10220,10 +10377,8 @@ impl DetectStackDriftError {
             let reader = EventReader::new(res.body.as_ref());
             let mut stack = XmlResponse::new(reader.into_iter().peekable());
             find_start_element(&mut stack);
-            if let Ok(parsed_error) = Self::deserialize(&mut stack) {
-                match &parsed_error.code[..] {
-                    _ => {}
-                }
+            if Self::deserialize(&mut stack).is_ok() {
+                return RusotoError::unhandled(DetectStackDriftError::Unhandled, &res);
             }
         }
         RusotoError::Unknown(res)
@@ -10240,13 +10395,19 @@ impl DetectStackDriftError {
 impl fmt::Display for DetectStackDriftError {
     #[allow(unused_variables)]
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        match *self {}
+        match *self {
+            DetectStackDriftError::Unhandled(ref metadata) => write!(f, "{}", metadata),
+        }
     }
 }
 impl Error for DetectStackDriftError {}
 /// Errors returned by DetectStackResourceDrift
 #[derive(Debug, PartialEq)]
-pub enum DetectStackResourceDriftError {}
+#[non_exhaustive]
+pub enum DetectStackResourceDriftError {
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
+}
 
 impl DetectStackResourceDriftError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<DetectStackResourceDriftError> {
@@ -10254,10 +10415,8 @@ impl DetectStackResourceDriftError {
             let reader = EventReader::new(res.body.as_ref());
             let mut stack = XmlResponse::new(reader.into_iter().peekable());
             find_start_element(&mut stack);
-            if let Ok(parsed_error) = Self::deserialize(&mut stack) {
-                match &parsed_error.code[..] {
-                    _ => {}
-                }
+            if Self::deserialize(&mut stack).is_ok() {
+                return RusotoError::unhandled(DetectStackResourceDriftError::Unhandled, &res);
             }
         }
         RusotoError::Unknown(res)
@@ -10274,12 +10433,15 @@ impl DetectStackResourceDriftError {
 impl fmt::Display for DetectStackResourceDriftError {
     #[allow(unused_variables)]
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        match *self {}
+        match *self {
+            DetectStackResourceDriftError::Unhandled(ref metadata) => write!(f, "{}", metadata),
+        }
     }
 }
 impl Error for DetectStackResourceDriftError {}
 /// Errors returned by DetectStackSetDrift
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DetectStackSetDriftError {
     /// <p>The specified operation isn't valid.</p>
     InvalidOperation(String),
@@ -10287,6 +10449,8 @@ pub enum DetectStackSetDriftError {
     OperationInProgress(String),
     /// <p>The specified stack set doesn't exist.</p>
     StackSetNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DetectStackSetDriftError {
@@ -10298,21 +10462,24 @@ impl DetectStackSetDriftError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InvalidOperationException" => {
-                        return RusotoError::Service(DetectStackSetDriftError::InvalidOperation(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            DetectStackSetDriftError::InvalidOperation(parsed_error.message),
+                            &res,
+                        )
                     }
                     "OperationInProgressException" => {
-                        return RusotoError::Service(DetectStackSetDriftError::OperationInProgress(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            DetectStackSetDriftError::OperationInProgress(parsed_error.message),
+                            &res,
+                        )
                     }
                     "StackSetNotFoundException" => {
-                        return RusotoError::Service(DetectStackSetDriftError::StackSetNotFound(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            DetectStackSetDriftError::StackSetNotFound(parsed_error.message),
+                            &res,
+                        )
                     }
-                    _ => {}
+                    _ => return RusotoError::unhandled(DetectStackSetDriftError::Unhandled, &res),
                 }
             }
         }
@@ -10334,13 +10501,18 @@ impl fmt::Display for DetectStackSetDriftError {
             DetectStackSetDriftError::InvalidOperation(ref cause) => write!(f, "{}", cause),
             DetectStackSetDriftError::OperationInProgress(ref cause) => write!(f, "{}", cause),
             DetectStackSetDriftError::StackSetNotFound(ref cause) => write!(f, "{}", cause),
+            DetectStackSetDriftError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DetectStackSetDriftError {}
 /// Errors returned by EstimateTemplateCost
 #[derive(Debug, PartialEq)]
-pub enum EstimateTemplateCostError {}
+#[non_exhaustive]
+pub enum EstimateTemplateCostError {
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
+}
 
 impl EstimateTemplateCostError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<EstimateTemplateCostError> {
@@ -10348,10 +10520,8 @@ impl EstimateTemplateCostError {
             let reader = EventReader::new(res.body.as_ref());
             let mut stack = XmlResponse::new(reader.into_iter().peekable());
             find_start_element(&mut stack);
-            if let Ok(parsed_error) = Self::deserialize(&mut stack) {
-                match &parsed_error.code[..] {
-                    _ => {}
-                }
+            if Self::deserialize(&mut stack).is_ok() {
+                return RusotoError::unhandled(EstimateTemplateCostError::Unhandled, &res);
             }
         }
         RusotoError::Unknown(res)
@@ -10368,12 +10538,15 @@ impl EstimateTemplateCostError {
 impl fmt::Display for EstimateTemplateCostError {
     #[allow(unused_variables)]
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        match *self {}
+        match *self {
+            EstimateTemplateCostError::Unhandled(ref metadata) => write!(f, "{}", metadata),
+        }
     }
 }
 impl Error for EstimateTemplateCostError {}
 /// Errors returned by ExecuteChangeSet
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ExecuteChangeSetError {
     /// <p>The specified change set name or ID doesn't exit. To view valid change sets for a stack, use the <code>ListChangeSets</code> action.</p>
     ChangeSetNotFound(String),
@@ -10383,6 +10556,8 @@ pub enum ExecuteChangeSetError {
     InvalidChangeSetStatus(String),
     /// <p>A client request token already exists.</p>
     TokenAlreadyExists(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ExecuteChangeSetError {
@@ -10394,26 +10569,30 @@ impl ExecuteChangeSetError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "ChangeSetNotFound" => {
-                        return RusotoError::Service(ExecuteChangeSetError::ChangeSetNotFound(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            ExecuteChangeSetError::ChangeSetNotFound(parsed_error.message),
+                            &res,
+                        )
                     }
                     "InsufficientCapabilitiesException" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             ExecuteChangeSetError::InsufficientCapabilities(parsed_error.message),
+                            &res,
                         )
                     }
                     "InvalidChangeSetStatus" => {
-                        return RusotoError::Service(ExecuteChangeSetError::InvalidChangeSetStatus(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            ExecuteChangeSetError::InvalidChangeSetStatus(parsed_error.message),
+                            &res,
+                        )
                     }
                     "TokenAlreadyExistsException" => {
-                        return RusotoError::Service(ExecuteChangeSetError::TokenAlreadyExists(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            ExecuteChangeSetError::TokenAlreadyExists(parsed_error.message),
+                            &res,
+                        )
                     }
-                    _ => {}
+                    _ => return RusotoError::unhandled(ExecuteChangeSetError::Unhandled, &res),
                 }
             }
         }
@@ -10436,13 +10615,18 @@ impl fmt::Display for ExecuteChangeSetError {
             ExecuteChangeSetError::InsufficientCapabilities(ref cause) => write!(f, "{}", cause),
             ExecuteChangeSetError::InvalidChangeSetStatus(ref cause) => write!(f, "{}", cause),
             ExecuteChangeSetError::TokenAlreadyExists(ref cause) => write!(f, "{}", cause),
+            ExecuteChangeSetError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ExecuteChangeSetError {}
 /// Errors returned by GetStackPolicy
 #[derive(Debug, PartialEq)]
-pub enum GetStackPolicyError {}
+#[non_exhaustive]
+pub enum GetStackPolicyError {
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
+}
 
 impl GetStackPolicyError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<GetStackPolicyError> {
@@ -10450,10 +10634,8 @@ impl GetStackPolicyError {
             let reader = EventReader::new(res.body.as_ref());
             let mut stack = XmlResponse::new(reader.into_iter().peekable());
             find_start_element(&mut stack);
-            if let Ok(parsed_error) = Self::deserialize(&mut stack) {
-                match &parsed_error.code[..] {
-                    _ => {}
-                }
+            if Self::deserialize(&mut stack).is_ok() {
+                return RusotoError::unhandled(GetStackPolicyError::Unhandled, &res);
             }
         }
         RusotoError::Unknown(res)
@@ -10470,15 +10652,20 @@ impl GetStackPolicyError {
 impl fmt::Display for GetStackPolicyError {
     #[allow(unused_variables)]
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        match *self {}
+        match *self {
+            GetStackPolicyError::Unhandled(ref metadata) => write!(f, "{}", metadata),
+        }
     }
 }
 impl Error for GetStackPolicyError {}
 /// Errors returned by GetTemplate
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetTemplateError {
     /// <p>The specified change set name or ID doesn't exit. To view valid change sets for a stack, use the <code>ListChangeSets</code> action.</p>
     ChangeSetNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetTemplateError {
@@ -10490,11 +10677,12 @@ impl GetTemplateError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "ChangeSetNotFound" => {
-                        return RusotoError::Service(GetTemplateError::ChangeSetNotFound(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            GetTemplateError::ChangeSetNotFound(parsed_error.message),
+                            &res,
+                        )
                     }
-                    _ => {}
+                    _ => return RusotoError::unhandled(GetTemplateError::Unhandled, &res),
                 }
             }
         }
@@ -10514,15 +10702,19 @@ impl fmt::Display for GetTemplateError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             GetTemplateError::ChangeSetNotFound(ref cause) => write!(f, "{}", cause),
+            GetTemplateError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetTemplateError {}
 /// Errors returned by GetTemplateSummary
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetTemplateSummaryError {
     /// <p>The specified stack set doesn't exist.</p>
     StackSetNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetTemplateSummaryError {
@@ -10534,11 +10726,12 @@ impl GetTemplateSummaryError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "StackSetNotFoundException" => {
-                        return RusotoError::Service(GetTemplateSummaryError::StackSetNotFound(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            GetTemplateSummaryError::StackSetNotFound(parsed_error.message),
+                            &res,
+                        )
                     }
-                    _ => {}
+                    _ => return RusotoError::unhandled(GetTemplateSummaryError::Unhandled, &res),
                 }
             }
         }
@@ -10558,13 +10751,18 @@ impl fmt::Display for GetTemplateSummaryError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             GetTemplateSummaryError::StackSetNotFound(ref cause) => write!(f, "{}", cause),
+            GetTemplateSummaryError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetTemplateSummaryError {}
 /// Errors returned by ListChangeSets
 #[derive(Debug, PartialEq)]
-pub enum ListChangeSetsError {}
+#[non_exhaustive]
+pub enum ListChangeSetsError {
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
+}
 
 impl ListChangeSetsError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<ListChangeSetsError> {
@@ -10572,10 +10770,8 @@ impl ListChangeSetsError {
             let reader = EventReader::new(res.body.as_ref());
             let mut stack = XmlResponse::new(reader.into_iter().peekable());
             find_start_element(&mut stack);
-            if let Ok(parsed_error) = Self::deserialize(&mut stack) {
-                match &parsed_error.code[..] {
-                    _ => {}
-                }
+            if Self::deserialize(&mut stack).is_ok() {
+                return RusotoError::unhandled(ListChangeSetsError::Unhandled, &res);
             }
         }
         RusotoError::Unknown(res)
@@ -10592,13 +10788,19 @@ impl ListChangeSetsError {
 impl fmt::Display for ListChangeSetsError {
     #[allow(unused_variables)]
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        match *self {}
+        match *self {
+            ListChangeSetsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
+        }
     }
 }
 impl Error for ListChangeSetsError {}
 /// Errors returned by ListExports
 #[derive(Debug, PartialEq)]
-pub enum ListExportsError {}
+#[non_exhaustive]
+pub enum ListExportsError {
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
+}
 
 impl ListExportsError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<ListExportsError> {
@@ -10606,10 +10808,8 @@ impl ListExportsError {
             let reader = EventReader::new(res.body.as_ref());
             let mut stack = XmlResponse::new(reader.into_iter().peekable());
             find_start_element(&mut stack);
-            if let Ok(parsed_error) = Self::deserialize(&mut stack) {
-                match &parsed_error.code[..] {
-                    _ => {}
-                }
+            if Self::deserialize(&mut stack).is_ok() {
+                return RusotoError::unhandled(ListExportsError::Unhandled, &res);
             }
         }
         RusotoError::Unknown(res)
@@ -10626,13 +10826,19 @@ impl ListExportsError {
 impl fmt::Display for ListExportsError {
     #[allow(unused_variables)]
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        match *self {}
+        match *self {
+            ListExportsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
+        }
     }
 }
 impl Error for ListExportsError {}
 /// Errors returned by ListImports
 #[derive(Debug, PartialEq)]
-pub enum ListImportsError {}
+#[non_exhaustive]
+pub enum ListImportsError {
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
+}
 
 impl ListImportsError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<ListImportsError> {
@@ -10640,10 +10846,8 @@ impl ListImportsError {
             let reader = EventReader::new(res.body.as_ref());
             let mut stack = XmlResponse::new(reader.into_iter().peekable());
             find_start_element(&mut stack);
-            if let Ok(parsed_error) = Self::deserialize(&mut stack) {
-                match &parsed_error.code[..] {
-                    _ => {}
-                }
+            if Self::deserialize(&mut stack).is_ok() {
+                return RusotoError::unhandled(ListImportsError::Unhandled, &res);
             }
         }
         RusotoError::Unknown(res)
@@ -10660,15 +10864,20 @@ impl ListImportsError {
 impl fmt::Display for ListImportsError {
     #[allow(unused_variables)]
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        match *self {}
+        match *self {
+            ListImportsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
+        }
     }
 }
 impl Error for ListImportsError {}
 /// Errors returned by ListStackInstances
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListStackInstancesError {
     /// <p>The specified stack set doesn't exist.</p>
     StackSetNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListStackInstancesError {
@@ -10680,11 +10889,12 @@ impl ListStackInstancesError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "StackSetNotFoundException" => {
-                        return RusotoError::Service(ListStackInstancesError::StackSetNotFound(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            ListStackInstancesError::StackSetNotFound(parsed_error.message),
+                            &res,
+                        )
                     }
-                    _ => {}
+                    _ => return RusotoError::unhandled(ListStackInstancesError::Unhandled, &res),
                 }
             }
         }
@@ -10704,13 +10914,18 @@ impl fmt::Display for ListStackInstancesError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             ListStackInstancesError::StackSetNotFound(ref cause) => write!(f, "{}", cause),
+            ListStackInstancesError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListStackInstancesError {}
 /// Errors returned by ListStackResources
 #[derive(Debug, PartialEq)]
-pub enum ListStackResourcesError {}
+#[non_exhaustive]
+pub enum ListStackResourcesError {
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
+}
 
 impl ListStackResourcesError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<ListStackResourcesError> {
@@ -10718,10 +10933,8 @@ impl ListStackResourcesError {
             let reader = EventReader::new(res.body.as_ref());
             let mut stack = XmlResponse::new(reader.into_iter().peekable());
             find_start_element(&mut stack);
-            if let Ok(parsed_error) = Self::deserialize(&mut stack) {
-                match &parsed_error.code[..] {
-                    _ => {}
-                }
+            if Self::deserialize(&mut stack).is_ok() {
+                return RusotoError::unhandled(ListStackResourcesError::Unhandled, &res);
             }
         }
         RusotoError::Unknown(res)
@@ -10738,17 +10951,22 @@ impl ListStackResourcesError {
 impl fmt::Display for ListStackResourcesError {
     #[allow(unused_variables)]
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        match *self {}
+        match *self {
+            ListStackResourcesError::Unhandled(ref metadata) => write!(f, "{}", metadata),
+        }
     }
 }
 impl Error for ListStackResourcesError {}
 /// Errors returned by ListStackSetOperationResults
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListStackSetOperationResultsError {
     /// <p>The specified ID refers to an operation that doesn't exist.</p>
     OperationNotFound(String),
     /// <p>The specified stack set doesn't exist.</p>
     StackSetNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListStackSetOperationResultsError {
@@ -10762,20 +10980,27 @@ impl ListStackSetOperationResultsError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "OperationNotFoundException" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             ListStackSetOperationResultsError::OperationNotFound(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "StackSetNotFoundException" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             ListStackSetOperationResultsError::StackSetNotFound(
                                 parsed_error.message,
                             ),
+                            &res,
+                        )
+                    }
+                    _ => {
+                        return RusotoError::unhandled(
+                            ListStackSetOperationResultsError::Unhandled,
+                            &res,
                         )
                     }
-                    _ => {}
                 }
             }
         }
@@ -10800,15 +11025,19 @@ impl fmt::Display for ListStackSetOperationResultsError {
             ListStackSetOperationResultsError::StackSetNotFound(ref cause) => {
                 write!(f, "{}", cause)
             }
+            ListStackSetOperationResultsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListStackSetOperationResultsError {}
 /// Errors returned by ListStackSetOperations
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListStackSetOperationsError {
     /// <p>The specified stack set doesn't exist.</p>
     StackSetNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListStackSetOperationsError {
@@ -10820,11 +11049,14 @@ impl ListStackSetOperationsError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "StackSetNotFoundException" => {
-                        return RusotoError::Service(ListStackSetOperationsError::StackSetNotFound(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            ListStackSetOperationsError::StackSetNotFound(parsed_error.message),
+                            &res,
+                        )
+                    }
+                    _ => {
+                        return RusotoError::unhandled(ListStackSetOperationsError::Unhandled, &res)
                     }
-                    _ => {}
                 }
             }
         }
@@ -10844,13 +11076,18 @@ impl fmt::Display for ListStackSetOperationsError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             ListStackSetOperationsError::StackSetNotFound(ref cause) => write!(f, "{}", cause),
+            ListStackSetOperationsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListStackSetOperationsError {}
 /// Errors returned by ListStackSets
 #[derive(Debug, PartialEq)]
-pub enum ListStackSetsError {}
+#[non_exhaustive]
+pub enum ListStackSetsError {
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
+}
 
 impl ListStackSetsError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<ListStackSetsError> {
@@ -10858,10 +11095,8 @@ impl ListStackSetsError {
             let reader = EventReader::new(res.body.as_ref());
             let mut stack = XmlResponse::new(reader.into_iter().peekable());
             find_start_element(&mut stack);
-            if let Ok(parsed_error) = Self::deserialize(&mut stack) {
-                match &parsed_error.code[..] {
-                    _ => {}
-                }
+            if Self::deserialize(&mut stack).is_ok() {
+                return RusotoError::unhandled(ListStackSetsError::Unhandled, &res);
             }
         }
         RusotoError::Unknown(res)
@@ -10878,13 +11113,19 @@ impl ListStackSetsError {
 impl fmt::Display for ListStackSetsError {
     #[allow(unused_variables)]
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        match *self {}
+        match *self {
+            ListStackSetsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
+        }
     }
 }
 impl Error for ListStackSetsError {}
 /// Errors returned by ListStacks
 #[derive(Debug, PartialEq)]
-pub enum ListStacksError {}
+#[non_exhaustive]
+pub enum ListStacksError {
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
+}
 
 impl ListStacksError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<ListStacksError> {
@@ -10892,10 +11133,8 @@ impl ListStacksError {
             let reader = EventReader::new(res.body.as_ref());
             let mut stack = XmlResponse::new(reader.into_iter().peekable());
             find_start_element(&mut stack);
-            if let Ok(parsed_error) = Self::deserialize(&mut stack) {
-                match &parsed_error.code[..] {
-                    _ => {}
-                }
+            if Self::deserialize(&mut stack).is_ok() {
+                return RusotoError::unhandled(ListStacksError::Unhandled, &res);
             }
         }
         RusotoError::Unknown(res)
@@ -10912,15 +11151,20 @@ impl ListStacksError {
 impl fmt::Display for ListStacksError {
     #[allow(unused_variables)]
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        match *self {}
+        match *self {
+            ListStacksError::Unhandled(ref metadata) => write!(f, "{}", metadata),
+        }
     }
 }
 impl Error for ListStacksError {}
 /// Errors returned by ListTypeRegistrations
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListTypeRegistrationsError {
     /// <p>An error occurred during a CloudFormation registry operation.</p>
     CFNRegistry(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListTypeRegistrationsError {
@@ -10932,11 +11176,14 @@ impl ListTypeRegistrationsError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "CFNRegistryException" => {
-                        return RusotoError::Service(ListTypeRegistrationsError::CFNRegistry(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            ListTypeRegistrationsError::CFNRegistry(parsed_error.message),
+                            &res,
+                        )
+                    }
+                    _ => {
+                        return RusotoError::unhandled(ListTypeRegistrationsError::Unhandled, &res)
                     }
-                    _ => {}
                 }
             }
         }
@@ -10956,15 +11203,19 @@ impl fmt::Display for ListTypeRegistrationsError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             ListTypeRegistrationsError::CFNRegistry(ref cause) => write!(f, "{}", cause),
+            ListTypeRegistrationsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListTypeRegistrationsError {}
 /// Errors returned by ListTypeVersions
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListTypeVersionsError {
     /// <p>An error occurred during a CloudFormation registry operation.</p>
     CFNRegistry(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListTypeVersionsError {
@@ -10976,11 +11227,12 @@ impl ListTypeVersionsError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "CFNRegistryException" => {
-                        return RusotoError::Service(ListTypeVersionsError::CFNRegistry(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            ListTypeVersionsError::CFNRegistry(parsed_error.message),
+                            &res,
+                        )
                     }
-                    _ => {}
+                    _ => return RusotoError::unhandled(ListTypeVersionsError::Unhandled, &res),
                 }
             }
         }
@@ -11000,15 +11252,19 @@ impl fmt::Display for ListTypeVersionsError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             ListTypeVersionsError::CFNRegistry(ref cause) => write!(f, "{}", cause),
+            ListTypeVersionsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListTypeVersionsError {}
 /// Errors returned by ListTypes
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListTypesError {
     /// <p>An error occurred during a CloudFormation registry operation.</p>
     CFNRegistry(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListTypesError {
@@ -11020,11 +11276,12 @@ impl ListTypesError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "CFNRegistryException" => {
-                        return RusotoError::Service(ListTypesError::CFNRegistry(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            ListTypesError::CFNRegistry(parsed_error.message),
+                            &res,
+                        )
                     }
-                    _ => {}
+                    _ => return RusotoError::unhandled(ListTypesError::Unhandled, &res),
                 }
             }
         }
@@ -11044,17 +11301,21 @@ impl fmt::Display for ListTypesError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             ListTypesError::CFNRegistry(ref cause) => write!(f, "{}", cause),
+            ListTypesError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListTypesError {}
 /// Errors returned by RecordHandlerProgress
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum RecordHandlerProgressError {
     /// <p>Error reserved for use by the <a href="https://docs.aws.amazon.com/cloudformation-cli/latest/userguide/what-is-cloudformation-cli.html">CloudFormation CLI</a>. CloudFormation does not return this error to users.</p>
     InvalidStateTransition(String),
     /// <p>Error reserved for use by the <a href="https://docs.aws.amazon.com/cloudformation-cli/latest/userguide/what-is-cloudformation-cli.html">CloudFormation CLI</a>. CloudFormation does not return this error to users.</p>
     OperationStatusCheckFailed(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl RecordHandlerProgressError {
@@ -11066,20 +11327,24 @@ impl RecordHandlerProgressError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InvalidStateTransition" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             RecordHandlerProgressError::InvalidStateTransition(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "ConditionalCheckFailed" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             RecordHandlerProgressError::OperationStatusCheckFailed(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
-                    _ => {}
+                    _ => {
+                        return RusotoError::unhandled(RecordHandlerProgressError::Unhandled, &res)
+                    }
                 }
             }
         }
@@ -11102,15 +11367,19 @@ impl fmt::Display for RecordHandlerProgressError {
             RecordHandlerProgressError::OperationStatusCheckFailed(ref cause) => {
                 write!(f, "{}", cause)
             }
+            RecordHandlerProgressError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for RecordHandlerProgressError {}
 /// Errors returned by RegisterType
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum RegisterTypeError {
     /// <p>An error occurred during a CloudFormation registry operation.</p>
     CFNRegistry(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl RegisterTypeError {
@@ -11122,11 +11391,12 @@ impl RegisterTypeError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "CFNRegistryException" => {
-                        return RusotoError::Service(RegisterTypeError::CFNRegistry(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            RegisterTypeError::CFNRegistry(parsed_error.message),
+                            &res,
+                        )
                     }
-                    _ => {}
+                    _ => return RusotoError::unhandled(RegisterTypeError::Unhandled, &res),
                 }
             }
         }
@@ -11146,13 +11416,18 @@ impl fmt::Display for RegisterTypeError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             RegisterTypeError::CFNRegistry(ref cause) => write!(f, "{}", cause),
+            RegisterTypeError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for RegisterTypeError {}
 /// Errors returned by SetStackPolicy
 #[derive(Debug, PartialEq)]
-pub enum SetStackPolicyError {}
+#[non_exhaustive]
+pub enum SetStackPolicyError {
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
+}
 
 impl SetStackPolicyError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<SetStackPolicyError> {
@@ -11160,10 +11435,8 @@ impl SetStackPolicyError {
             let reader = EventReader::new(res.body.as_ref());
             let mut stack = XmlResponse::new(reader.into_iter().peekable());
             find_start_element(&mut stack);
-            if let Ok(parsed_error) = Self::deserialize(&mut stack) {
-                match &parsed_error.code[..] {
-                    _ => {}
-                }
+            if Self::deserialize(&mut stack).is_ok() {
+                return RusotoError::unhandled(SetStackPolicyError::Unhandled, &res);
             }
         }
         RusotoError::Unknown(res)
@@ -11180,17 +11453,22 @@ impl SetStackPolicyError {
 impl fmt::Display for SetStackPolicyError {
     #[allow(unused_variables)]
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        match *self {}
+        match *self {
+            SetStackPolicyError::Unhandled(ref metadata) => write!(f, "{}", metadata),
+        }
     }
 }
 impl Error for SetStackPolicyError {}
 /// Errors returned by SetTypeDefaultVersion
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum SetTypeDefaultVersionError {
     /// <p>An error occurred during a CloudFormation registry operation.</p>
     CFNRegistry(String),
     /// <p>The specified type does not exist in the CloudFormation registry.</p>
     TypeNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl SetTypeDefaultVersionError {
@@ -11202,16 +11480,20 @@ impl SetTypeDefaultVersionError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "CFNRegistryException" => {
-                        return RusotoError::Service(SetTypeDefaultVersionError::CFNRegistry(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            SetTypeDefaultVersionError::CFNRegistry(parsed_error.message),
+                            &res,
+                        )
                     }
                     "TypeNotFoundException" => {
-                        return RusotoError::Service(SetTypeDefaultVersionError::TypeNotFound(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            SetTypeDefaultVersionError::TypeNotFound(parsed_error.message),
+                            &res,
+                        )
+                    }
+                    _ => {
+                        return RusotoError::unhandled(SetTypeDefaultVersionError::Unhandled, &res)
                     }
-                    _ => {}
                 }
             }
         }
@@ -11232,13 +11514,18 @@ impl fmt::Display for SetTypeDefaultVersionError {
         match *self {
             SetTypeDefaultVersionError::CFNRegistry(ref cause) => write!(f, "{}", cause),
             SetTypeDefaultVersionError::TypeNotFound(ref cause) => write!(f, "{}", cause),
+            SetTypeDefaultVersionError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for SetTypeDefaultVersionError {}
 /// Errors returned by SignalResource
 #[derive(Debug, PartialEq)]
-pub enum SignalResourceError {}
+#[non_exhaustive]
+pub enum SignalResourceError {
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
+}
 
 impl SignalResourceError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<SignalResourceError> {
@@ -11246,10 +11533,8 @@ impl SignalResourceError {
             let reader = EventReader::new(res.body.as_ref());
             let mut stack = XmlResponse::new(reader.into_iter().peekable());
             find_start_element(&mut stack);
-            if let Ok(parsed_error) = Self::deserialize(&mut stack) {
-                match &parsed_error.code[..] {
-                    _ => {}
-                }
+            if Self::deserialize(&mut stack).is_ok() {
+                return RusotoError::unhandled(SignalResourceError::Unhandled, &res);
             }
         }
         RusotoError::Unknown(res)
@@ -11266,12 +11551,15 @@ impl SignalResourceError {
 impl fmt::Display for SignalResourceError {
     #[allow(unused_variables)]
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        match *self {}
+        match *self {
+            SignalResourceError::Unhandled(ref metadata) => write!(f, "{}", metadata),
+        }
     }
 }
 impl Error for SignalResourceError {}
 /// Errors returned by StopStackSetOperation
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum StopStackSetOperationError {
     /// <p>The specified operation isn't valid.</p>
     InvalidOperation(String),
@@ -11279,6 +11567,8 @@ pub enum StopStackSetOperationError {
     OperationNotFound(String),
     /// <p>The specified stack set doesn't exist.</p>
     StackSetNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl StopStackSetOperationError {
@@ -11290,21 +11580,26 @@ impl StopStackSetOperationError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InvalidOperationException" => {
-                        return RusotoError::Service(StopStackSetOperationError::InvalidOperation(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            StopStackSetOperationError::InvalidOperation(parsed_error.message),
+                            &res,
+                        )
                     }
                     "OperationNotFoundException" => {
-                        return RusotoError::Service(StopStackSetOperationError::OperationNotFound(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            StopStackSetOperationError::OperationNotFound(parsed_error.message),
+                            &res,
+                        )
                     }
                     "StackSetNotFoundException" => {
-                        return RusotoError::Service(StopStackSetOperationError::StackSetNotFound(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            StopStackSetOperationError::StackSetNotFound(parsed_error.message),
+                            &res,
+                        )
+                    }
+                    _ => {
+                        return RusotoError::unhandled(StopStackSetOperationError::Unhandled, &res)
                     }
-                    _ => {}
                 }
             }
         }
@@ -11326,17 +11621,21 @@ impl fmt::Display for StopStackSetOperationError {
             StopStackSetOperationError::InvalidOperation(ref cause) => write!(f, "{}", cause),
             StopStackSetOperationError::OperationNotFound(ref cause) => write!(f, "{}", cause),
             StopStackSetOperationError::StackSetNotFound(ref cause) => write!(f, "{}", cause),
+            StopStackSetOperationError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for StopStackSetOperationError {}
 /// Errors returned by UpdateStack
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdateStackError {
     /// <p>The template contains resources with capabilities that weren't specified in the Capabilities parameter.</p>
     InsufficientCapabilities(String),
     /// <p>A client request token already exists.</p>
     TokenAlreadyExists(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdateStackError {
@@ -11348,16 +11647,18 @@ impl UpdateStackError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InsufficientCapabilitiesException" => {
-                        return RusotoError::Service(UpdateStackError::InsufficientCapabilities(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            UpdateStackError::InsufficientCapabilities(parsed_error.message),
+                            &res,
+                        )
                     }
                     "TokenAlreadyExistsException" => {
-                        return RusotoError::Service(UpdateStackError::TokenAlreadyExists(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            UpdateStackError::TokenAlreadyExists(parsed_error.message),
+                            &res,
+                        )
                     }
-                    _ => {}
+                    _ => return RusotoError::unhandled(UpdateStackError::Unhandled, &res),
                 }
             }
         }
@@ -11378,12 +11679,14 @@ impl fmt::Display for UpdateStackError {
         match *self {
             UpdateStackError::InsufficientCapabilities(ref cause) => write!(f, "{}", cause),
             UpdateStackError::TokenAlreadyExists(ref cause) => write!(f, "{}", cause),
+            UpdateStackError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for UpdateStackError {}
 /// Errors returned by UpdateStackInstances
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdateStackInstancesError {
     /// <p>The specified operation isn't valid.</p>
     InvalidOperation(String),
@@ -11397,6 +11700,8 @@ pub enum UpdateStackInstancesError {
     StackSetNotFound(String),
     /// <p>Another operation has been performed on this stack set since the specified operation was performed. </p>
     StaleRequest(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdateStackInstancesError {
@@ -11408,38 +11713,44 @@ impl UpdateStackInstancesError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InvalidOperationException" => {
-                        return RusotoError::Service(UpdateStackInstancesError::InvalidOperation(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            UpdateStackInstancesError::InvalidOperation(parsed_error.message),
+                            &res,
+                        )
                     }
                     "OperationIdAlreadyExistsException" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             UpdateStackInstancesError::OperationIdAlreadyExists(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "OperationInProgressException" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             UpdateStackInstancesError::OperationInProgress(parsed_error.message),
+                            &res,
                         )
                     }
                     "StackInstanceNotFoundException" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             UpdateStackInstancesError::StackInstanceNotFound(parsed_error.message),
+                            &res,
                         )
                     }
                     "StackSetNotFoundException" => {
-                        return RusotoError::Service(UpdateStackInstancesError::StackSetNotFound(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            UpdateStackInstancesError::StackSetNotFound(parsed_error.message),
+                            &res,
+                        )
                     }
                     "StaleRequestException" => {
-                        return RusotoError::Service(UpdateStackInstancesError::StaleRequest(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            UpdateStackInstancesError::StaleRequest(parsed_error.message),
+                            &res,
+                        )
                     }
-                    _ => {}
+                    _ => return RusotoError::unhandled(UpdateStackInstancesError::Unhandled, &res),
                 }
             }
         }
@@ -11466,12 +11777,14 @@ impl fmt::Display for UpdateStackInstancesError {
             UpdateStackInstancesError::StackInstanceNotFound(ref cause) => write!(f, "{}", cause),
             UpdateStackInstancesError::StackSetNotFound(ref cause) => write!(f, "{}", cause),
             UpdateStackInstancesError::StaleRequest(ref cause) => write!(f, "{}", cause),
+            UpdateStackInstancesError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for UpdateStackInstancesError {}
 /// Errors returned by UpdateStackSet
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdateStackSetError {
     /// <p>The specified operation isn't valid.</p>
     InvalidOperation(String),
@@ -11485,6 +11798,8 @@ pub enum UpdateStackSetError {
     StackSetNotFound(String),
     /// <p>Another operation has been performed on this stack set since the specified operation was performed. </p>
     StaleRequest(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdateStackSetError {
@@ -11496,36 +11811,42 @@ impl UpdateStackSetError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InvalidOperationException" => {
-                        return RusotoError::Service(UpdateStackSetError::InvalidOperation(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            UpdateStackSetError::InvalidOperation(parsed_error.message),
+                            &res,
+                        )
                     }
                     "OperationIdAlreadyExistsException" => {
-                        return RusotoError::Service(UpdateStackSetError::OperationIdAlreadyExists(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            UpdateStackSetError::OperationIdAlreadyExists(parsed_error.message),
+                            &res,
+                        )
                     }
                     "OperationInProgressException" => {
-                        return RusotoError::Service(UpdateStackSetError::OperationInProgress(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            UpdateStackSetError::OperationInProgress(parsed_error.message),
+                            &res,
+                        )
                     }
                     "StackInstanceNotFoundException" => {
-                        return RusotoError::Service(UpdateStackSetError::StackInstanceNotFound(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            UpdateStackSetError::StackInstanceNotFound(parsed_error.message),
+                            &res,
+                        )
                     }
                     "StackSetNotFoundException" => {
-                        return RusotoError::Service(UpdateStackSetError::StackSetNotFound(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            UpdateStackSetError::StackSetNotFound(parsed_error.message),
+                            &res,
+                        )
                     }
                     "StaleRequestException" => {
-                        return RusotoError::Service(UpdateStackSetError::StaleRequest(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            UpdateStackSetError::StaleRequest(parsed_error.message),
+                            &res,
+                        )
                     }
-                    _ => {}
+                    _ => return RusotoError::unhandled(UpdateStackSetError::Unhandled, &res),
                 }
             }
         }
@@ -11550,13 +11871,18 @@ impl fmt::Display for UpdateStackSetError {
             UpdateStackSetError::StackInstanceNotFound(ref cause) => write!(f, "{}", cause),
             UpdateStackSetError::StackSetNotFound(ref cause) => write!(f, "{}", cause),
             UpdateStackSetError::StaleRequest(ref cause) => write!(f, "{}", cause),
+            UpdateStackSetError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for UpdateStackSetError {}
 /// Errors returned by UpdateTerminationProtection
 #[derive(Debug, PartialEq)]
-pub enum UpdateTerminationProtectionError {}
+#[non_exhaustive]
+pub enum UpdateTerminationProtectionError {
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
+}
 
 impl UpdateTerminationProtectionError {
     pub fn from_response(
@@ -11566,10 +11892,8 @@ impl UpdateTerminationProtectionError {
             let reader = EventReader::new(res.body.as_ref());
             let mut stack = XmlResponse::new(reader.into_iter().peekable());
             find_start_element(&mut stack);
-            if let Ok(parsed_error) = Self::deserialize(&mut stack) {
-                match &parsed_error.code[..] {
-                    _ => {}
-                }
+            if Self::deserialize(&mut stack).is_ok() {
+                return RusotoError::unhandled(UpdateTerminationProtectionError::Unhandled, &res);
             }
         }
         RusotoError::Unknown(res)
@@ -11586,13 +11910,19 @@ impl UpdateTerminationProtectionError {
 impl fmt::Display for UpdateTerminationProtectionError {
     #[allow(unused_variables)]
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        match *self {}
+        match *self {
+            UpdateTerminationProtectionError::Unhandled(ref metadata) => write!(f, "{}", metadata),
+        }
     }
 }
 impl Error for UpdateTerminationProtectionError {}
 /// Errors returned by ValidateTemplate
 #[derive(Debug, PartialEq)]
-pub enum ValidateTemplateError {}
+#[non_exhaustive]
+pub enum ValidateTemplateError {
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
+}
 
 impl ValidateTemplateError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<ValidateTemplateError> {
@@ -11600,10 +11930,8 @@ impl ValidateTemplateError {
             let reader = EventReader::new(res.body.as_ref());
             let mut stack = XmlResponse::new(reader.into_iter().peekable());
             find_start_element(&mut stack);
-            if let Ok(parsed_error) = Self::deserialize(&mut stack) {
-                match &parsed_error.code[..] {
-                    _ => {}
-                }
+            if Self::deserialize(&mut stack).is_ok() {
+                return RusotoError::unhandled(ValidateTemplateError::Unhandled, &res);
             }
         }
         RusotoError::Unknown(res)
@@ -11620,7 +11948,9 @@ impl ValidateTemplateError {
 impl fmt::Display for ValidateTemplateError {
     #[allow(unused_variables)]
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        match *self {}
+        match *self {
+            ValidateTemplateError::Unhandled(ref metadata) => write!(f, "{}", metadata),
+        }
     }
 }
 impl Error for ValidateTemplateError {}
@@ -11971,6 +12301,7 @@ impl CloudFormationClient {
     /// Creates a client backed by the default tokio event loop.
     ///
     /// The client will use the default credentials provider and tls client.
+    #[cfg(not(target_arch = "wasm32"))]
     pub fn new(region: region::Region) -> CloudFormationClient {
         CloudFormationClient {
             client: Client::shared(),
@@ -11996,63 +12327,101 @@ impl CloudFormationClient {
     pub fn new_with_client(client: Client, region: region::Region) -> CloudFormationClient {
         CloudFormationClient { client, region }
     }
+
+    /// Returns a builder of a client, setting any of its region, credentials provider,
+    /// dispatcher, retry policy, endpoint and timeouts.
+    pub fn builder() -> rusoto_core::ClientBuilder<CloudFormationClient> {
+        rusoto_core::ClientBuilder::new(CloudFormationClient::new_with_client)
+    }
+
+    /// Returns a clone of this client with the overrides of `config` applied to its
+    /// operations, sharing its connections.
+    pub fn customize(&self, config: &rusoto_core::OperationConfig) -> CloudFormationClient {
+        CloudFormationClient {
+            client: self.client.customize(config),
+            region: config.resolve_region(&self.region),
+        }
+    }
 }
 
-#[async_trait]
-impl CloudFormation for CloudFormationClient {
-    /// <p><p>Cancels an update on the specified stack. If the call completes successfully, the stack rolls back the update and reverts to the previous stack configuration.</p> <note> <p>You can cancel only stacks that are in the UPDATE<em>IN</em>PROGRESS state.</p> </note></p>
-    async fn cancel_update_stack(
+impl CloudFormationClient {
+    /// Calls `cancel_update_stack`, also returning the metadata of the response.
+    pub async fn cancel_update_stack_with_metadata(
         &self,
         input: CancelUpdateStackInput,
-    ) -> Result<(), RusotoError<CancelUpdateStackError>> {
-        let mut request = SignedRequest::new("POST", "cloudformation", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "CancelUpdateStack");
-        params.put("Version", "2010-05-15");
-        CancelUpdateStackInputSerializer::serialize(&mut params, "", &input);
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+    ) -> Result<((), ResponseMetadata), RusotoError<CancelUpdateStackError>> {
+        let request = self.build_cancel_update_stack_request(input);
 
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(CancelUpdateStackError::from_response(response));
         }
 
         std::mem::drop(response);
-        Ok(())
+        Ok(((), metadata))
     }
 
-    /// <p>For a specified stack that is in the <code>UPDATE_ROLLBACK_FAILED</code> state, continues rolling it back to the <code>UPDATE_ROLLBACK_COMPLETE</code> state. Depending on the cause of the failure, you can manually <a href="https://docs.aws.amazon.com/AWSCloudFormation/latest/UserGuide/troubleshooting.html#troubleshooting-errors-update-rollback-failed"> fix the error</a> and continue the rollback. By continuing the rollback, you can return your stack to a working state (the <code>UPDATE_ROLLBACK_COMPLETE</code> state), and then try to update the stack again.</p> <p>A stack goes into the <code>UPDATE_ROLLBACK_FAILED</code> state when AWS CloudFormation cannot roll back all changes after a failed stack update. For example, you might have a stack that is rolling back to an old database instance that was deleted outside of AWS CloudFormation. Because AWS CloudFormation doesn't know the database was deleted, it assumes that the database instance still exists and attempts to roll back to it, causing the update rollback to fail.</p>
-    async fn continue_update_rollback(
+    /// Presigns the request `cancel_update_stack` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_cancel_update_stack(
         &self,
-        input: ContinueUpdateRollbackInput,
-    ) -> Result<ContinueUpdateRollbackOutput, RusotoError<ContinueUpdateRollbackError>> {
+        presigner: &rusoto_core::Presigner,
+        input: CancelUpdateStackInput,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_cancel_update_stack_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_cancel_update_stack_request(&self, input: CancelUpdateStackInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "cloudformation", &self.region, "/");
+        request.set_operation("CancelUpdateStack");
         let mut params = Params::new();
 
-        params.put("Action", "ContinueUpdateRollback");
+        params.put("Action", "CancelUpdateStack");
         params.put("Version", "2010-05-15");
-        ContinueUpdateRollbackInputSerializer::serialize(&mut params, "", &input);
+        CancelUpdateStackInputSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `continue_update_rollback`, also returning the metadata of the response.
+    pub async fn continue_update_rollback_with_metadata(
+        &self,
+        input: ContinueUpdateRollbackInput,
+    ) -> Result<
+        (ContinueUpdateRollbackOutput, ResponseMetadata),
+        RusotoError<ContinueUpdateRollbackError>,
+    > {
+        let request = self.build_continue_update_rollback_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(ContinueUpdateRollbackError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -12074,34 +12443,61 @@ impl CloudFormation for CloudFormationClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Creates a list of changes that will be applied to a stack so that you can review the changes before executing them. You can create a change set for a stack that doesn't exist or an existing stack. If you create a change set for a stack that doesn't exist, the change set shows all of the resources that AWS CloudFormation will create. If you create a change set for an existing stack, AWS CloudFormation compares the stack's information with the information that you submit in the change set and lists the differences. Use change sets to understand which resources AWS CloudFormation will create or change, and how it will change resources in an existing stack, before you create or update a stack.</p> <p>To create a change set for a stack that doesn't exist, for the <code>ChangeSetType</code> parameter, specify <code>CREATE</code>. To create a change set for an existing stack, specify <code>UPDATE</code> for the <code>ChangeSetType</code> parameter. To create a change set for an import operation, specify <code>IMPORT</code> for the <code>ChangeSetType</code> parameter. After the <code>CreateChangeSet</code> call successfully completes, AWS CloudFormation starts creating the change set. To check the status of the change set or to review it, use the <a>DescribeChangeSet</a> action.</p> <p>When you are satisfied with the changes the change set will make, execute the change set by using the <a>ExecuteChangeSet</a> action. AWS CloudFormation doesn't make changes until you execute the change set.</p>
-    async fn create_change_set(
+    /// Presigns the request `continue_update_rollback` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_continue_update_rollback(
         &self,
-        input: CreateChangeSetInput,
-    ) -> Result<CreateChangeSetOutput, RusotoError<CreateChangeSetError>> {
+        presigner: &rusoto_core::Presigner,
+        input: ContinueUpdateRollbackInput,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_continue_update_rollback_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_continue_update_rollback_request(
+        &self,
+        input: ContinueUpdateRollbackInput,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "cloudformation", &self.region, "/");
+        request.set_operation("ContinueUpdateRollback");
         let mut params = Params::new();
 
-        params.put("Action", "CreateChangeSet");
+        params.put("Action", "ContinueUpdateRollback");
         params.put("Version", "2010-05-15");
-        CreateChangeSetInputSerializer::serialize(&mut params, "", &input);
+        ContinueUpdateRollbackInputSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
-        let mut response = self
-            .client
-            .sign_and_dispatch(request)
+        request
+    }
+
+    /// Calls `create_change_set`, also returning the metadata of the response.
+    pub async fn create_change_set_with_metadata(
+        &self,
+        input: CreateChangeSetInput,
+    ) -> Result<(CreateChangeSetOutput, ResponseMetadata), RusotoError<CreateChangeSetError>> {
+        let request = self.build_create_change_set_request(input);
+
+        let mut response = self
+            .client
+            .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(CreateChangeSetError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -12123,34 +12519,58 @@ impl CloudFormation for CloudFormationClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Creates a stack as specified in the template. After the call completes successfully, the stack creation starts. You can check the status of the stack via the <a>DescribeStacks</a> API.</p>
-    async fn create_stack(
+    /// Presigns the request `create_change_set` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_change_set(
         &self,
-        input: CreateStackInput,
-    ) -> Result<CreateStackOutput, RusotoError<CreateStackError>> {
+        presigner: &rusoto_core::Presigner,
+        input: CreateChangeSetInput,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_change_set_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_change_set_request(&self, input: CreateChangeSetInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "cloudformation", &self.region, "/");
+        request.set_operation("CreateChangeSet");
         let mut params = Params::new();
 
-        params.put("Action", "CreateStack");
+        params.put("Action", "CreateChangeSet");
         params.put("Version", "2010-05-15");
-        CreateStackInputSerializer::serialize(&mut params, "", &input);
+        CreateChangeSetInputSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `create_stack`, also returning the metadata of the response.
+    pub async fn create_stack_with_metadata(
+        &self,
+        input: CreateStackInput,
+    ) -> Result<(CreateStackOutput, ResponseMetadata), RusotoError<CreateStackError>> {
+        let request = self.build_create_stack_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(CreateStackError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -12169,34 +12589,61 @@ impl CloudFormation for CloudFormationClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Creates stack instances for the specified accounts, within the specified regions. A stack instance refers to a stack in a specific account and region. <code>Accounts</code> and <code>Regions</code> are required parameters—you must specify at least one account and one region. </p>
-    async fn create_stack_instances(
+    /// Presigns the request `create_stack` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_stack(
         &self,
-        input: CreateStackInstancesInput,
-    ) -> Result<CreateStackInstancesOutput, RusotoError<CreateStackInstancesError>> {
+        presigner: &rusoto_core::Presigner,
+        input: CreateStackInput,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_stack_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_stack_request(&self, input: CreateStackInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "cloudformation", &self.region, "/");
+        request.set_operation("CreateStack");
         let mut params = Params::new();
 
-        params.put("Action", "CreateStackInstances");
+        params.put("Action", "CreateStack");
         params.put("Version", "2010-05-15");
-        CreateStackInstancesInputSerializer::serialize(&mut params, "", &input);
+        CreateStackInputSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `create_stack_instances`, also returning the metadata of the response.
+    pub async fn create_stack_instances_with_metadata(
+        &self,
+        input: CreateStackInstancesInput,
+    ) -> Result<
+        (CreateStackInstancesOutput, ResponseMetadata),
+        RusotoError<CreateStackInstancesError>,
+    > {
+        let request = self.build_create_stack_instances_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(CreateStackInstancesError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -12218,34 +12665,61 @@ impl CloudFormation for CloudFormationClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Creates a stack set.</p>
-    async fn create_stack_set(
+    /// Presigns the request `create_stack_instances` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_stack_instances(
         &self,
-        input: CreateStackSetInput,
-    ) -> Result<CreateStackSetOutput, RusotoError<CreateStackSetError>> {
+        presigner: &rusoto_core::Presigner,
+        input: CreateStackInstancesInput,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_stack_instances_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_stack_instances_request(
+        &self,
+        input: CreateStackInstancesInput,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "cloudformation", &self.region, "/");
+        request.set_operation("CreateStackInstances");
         let mut params = Params::new();
 
-        params.put("Action", "CreateStackSet");
+        params.put("Action", "CreateStackInstances");
         params.put("Version", "2010-05-15");
-        CreateStackSetInputSerializer::serialize(&mut params, "", &input);
+        CreateStackInstancesInputSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `create_stack_set`, also returning the metadata of the response.
+    pub async fn create_stack_set_with_metadata(
+        &self,
+        input: CreateStackSetInput,
+    ) -> Result<(CreateStackSetOutput, ResponseMetadata), RusotoError<CreateStackSetError>> {
+        let request = self.build_create_stack_set_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(CreateStackSetError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -12265,34 +12739,58 @@ impl CloudFormation for CloudFormationClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Deletes the specified change set. Deleting change sets ensures that no one executes the wrong change set.</p> <p>If the call successfully completes, AWS CloudFormation successfully deleted the change set.</p>
-    async fn delete_change_set(
+    /// Presigns the request `create_stack_set` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_stack_set(
         &self,
-        input: DeleteChangeSetInput,
-    ) -> Result<DeleteChangeSetOutput, RusotoError<DeleteChangeSetError>> {
+        presigner: &rusoto_core::Presigner,
+        input: CreateStackSetInput,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_stack_set_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_stack_set_request(&self, input: CreateStackSetInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "cloudformation", &self.region, "/");
+        request.set_operation("CreateStackSet");
         let mut params = Params::new();
 
-        params.put("Action", "DeleteChangeSet");
+        params.put("Action", "CreateStackSet");
         params.put("Version", "2010-05-15");
-        DeleteChangeSetInputSerializer::serialize(&mut params, "", &input);
+        CreateStackSetInputSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `delete_change_set`, also returning the metadata of the response.
+    pub async fn delete_change_set_with_metadata(
+        &self,
+        input: DeleteChangeSetInput,
+    ) -> Result<(DeleteChangeSetOutput, ResponseMetadata), RusotoError<DeleteChangeSetError>> {
+        let request = self.build_delete_change_set_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(DeleteChangeSetError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -12314,62 +12812,110 @@ impl CloudFormation for CloudFormationClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Deletes a specified stack. Once the call completes successfully, stack deletion starts. Deleted stacks do not show up in the <a>DescribeStacks</a> API if the deletion has been completed successfully.</p>
-    async fn delete_stack(
+    /// Presigns the request `delete_change_set` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_change_set(
         &self,
-        input: DeleteStackInput,
-    ) -> Result<(), RusotoError<DeleteStackError>> {
+        presigner: &rusoto_core::Presigner,
+        input: DeleteChangeSetInput,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_change_set_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_change_set_request(&self, input: DeleteChangeSetInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "cloudformation", &self.region, "/");
+        request.set_operation("DeleteChangeSet");
         let mut params = Params::new();
 
-        params.put("Action", "DeleteStack");
+        params.put("Action", "DeleteChangeSet");
         params.put("Version", "2010-05-15");
-        DeleteStackInputSerializer::serialize(&mut params, "", &input);
+        DeleteChangeSetInputSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `delete_stack`, also returning the metadata of the response.
+    pub async fn delete_stack_with_metadata(
+        &self,
+        input: DeleteStackInput,
+    ) -> Result<((), ResponseMetadata), RusotoError<DeleteStackError>> {
+        let request = self.build_delete_stack_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(DeleteStackError::from_response(response));
         }
 
         std::mem::drop(response);
-        Ok(())
+        Ok(((), metadata))
     }
 
-    /// <p>Deletes stack instances for the specified accounts, in the specified regions. </p>
-    async fn delete_stack_instances(
+    /// Presigns the request `delete_stack` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_stack(
         &self,
-        input: DeleteStackInstancesInput,
-    ) -> Result<DeleteStackInstancesOutput, RusotoError<DeleteStackInstancesError>> {
+        presigner: &rusoto_core::Presigner,
+        input: DeleteStackInput,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_stack_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_stack_request(&self, input: DeleteStackInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "cloudformation", &self.region, "/");
+        request.set_operation("DeleteStack");
         let mut params = Params::new();
 
-        params.put("Action", "DeleteStackInstances");
+        params.put("Action", "DeleteStack");
         params.put("Version", "2010-05-15");
-        DeleteStackInstancesInputSerializer::serialize(&mut params, "", &input);
+        DeleteStackInputSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `delete_stack_instances`, also returning the metadata of the response.
+    pub async fn delete_stack_instances_with_metadata(
+        &self,
+        input: DeleteStackInstancesInput,
+    ) -> Result<
+        (DeleteStackInstancesOutput, ResponseMetadata),
+        RusotoError<DeleteStackInstancesError>,
+    > {
+        let request = self.build_delete_stack_instances_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(DeleteStackInstancesError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -12391,34 +12937,61 @@ impl CloudFormation for CloudFormationClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Deletes a stack set. Before you can delete a stack set, all of its member stack instances must be deleted. For more information about how to do this, see <a>DeleteStackInstances</a>. </p>
-    async fn delete_stack_set(
+    /// Presigns the request `delete_stack_instances` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_stack_instances(
         &self,
-        input: DeleteStackSetInput,
-    ) -> Result<DeleteStackSetOutput, RusotoError<DeleteStackSetError>> {
+        presigner: &rusoto_core::Presigner,
+        input: DeleteStackInstancesInput,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_stack_instances_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_stack_instances_request(
+        &self,
+        input: DeleteStackInstancesInput,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "cloudformation", &self.region, "/");
+        request.set_operation("DeleteStackInstances");
         let mut params = Params::new();
 
-        params.put("Action", "DeleteStackSet");
+        params.put("Action", "DeleteStackInstances");
         params.put("Version", "2010-05-15");
-        DeleteStackSetInputSerializer::serialize(&mut params, "", &input);
+        DeleteStackInstancesInputSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `delete_stack_set`, also returning the metadata of the response.
+    pub async fn delete_stack_set_with_metadata(
+        &self,
+        input: DeleteStackSetInput,
+    ) -> Result<(DeleteStackSetOutput, ResponseMetadata), RusotoError<DeleteStackSetError>> {
+        let request = self.build_delete_stack_set_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(DeleteStackSetError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -12438,34 +13011,58 @@ impl CloudFormation for CloudFormationClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Removes a type or type version from active use in the CloudFormation registry. If a type or type version is deregistered, it cannot be used in CloudFormation operations.</p> <p>To deregister a type, you must individually deregister all registered versions of that type. If a type has only a single registered version, deregistering that version results in the type itself being deregistered. </p> <p>You cannot deregister the default version of a type, unless it is the only registered version of that type, in which case the type itself is deregistered as well. </p>
-    async fn deregister_type(
+    /// Presigns the request `delete_stack_set` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_stack_set(
         &self,
-        input: DeregisterTypeInput,
-    ) -> Result<DeregisterTypeOutput, RusotoError<DeregisterTypeError>> {
+        presigner: &rusoto_core::Presigner,
+        input: DeleteStackSetInput,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_stack_set_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_stack_set_request(&self, input: DeleteStackSetInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "cloudformation", &self.region, "/");
+        request.set_operation("DeleteStackSet");
         let mut params = Params::new();
 
-        params.put("Action", "DeregisterType");
+        params.put("Action", "DeleteStackSet");
         params.put("Version", "2010-05-15");
-        DeregisterTypeInputSerializer::serialize(&mut params, "", &input);
+        DeleteStackSetInputSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `deregister_type`, also returning the metadata of the response.
+    pub async fn deregister_type_with_metadata(
+        &self,
+        input: DeregisterTypeInput,
+    ) -> Result<(DeregisterTypeOutput, ResponseMetadata), RusotoError<DeregisterTypeError>> {
+        let request = self.build_deregister_type_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(DeregisterTypeError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -12485,34 +13082,61 @@ impl CloudFormation for CloudFormationClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Retrieves your account's AWS CloudFormation limits, such as the maximum number of stacks that you can create in your account. For more information about account limits, see <a href="https://docs.aws.amazon.com/AWSCloudFormation/latest/UserGuide/cloudformation-limits.html">AWS CloudFormation Limits</a> in the <i>AWS CloudFormation User Guide</i>.</p>
-    async fn describe_account_limits(
+    /// Presigns the request `deregister_type` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_deregister_type(
         &self,
-        input: DescribeAccountLimitsInput,
-    ) -> Result<DescribeAccountLimitsOutput, RusotoError<DescribeAccountLimitsError>> {
+        presigner: &rusoto_core::Presigner,
+        input: DeregisterTypeInput,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_deregister_type_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_deregister_type_request(&self, input: DeregisterTypeInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "cloudformation", &self.region, "/");
+        request.set_operation("DeregisterType");
         let mut params = Params::new();
 
-        params.put("Action", "DescribeAccountLimits");
+        params.put("Action", "DeregisterType");
         params.put("Version", "2010-05-15");
-        DescribeAccountLimitsInputSerializer::serialize(&mut params, "", &input);
+        DeregisterTypeInputSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `describe_account_limits`, also returning the metadata of the response.
+    pub async fn describe_account_limits_with_metadata(
+        &self,
+        input: DescribeAccountLimitsInput,
+    ) -> Result<
+        (DescribeAccountLimitsOutput, ResponseMetadata),
+        RusotoError<DescribeAccountLimitsError>,
+    > {
+        let request = self.build_describe_account_limits_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(DescribeAccountLimitsError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -12534,34 +13158,62 @@ impl CloudFormation for CloudFormationClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Returns the inputs for the change set and a list of changes that AWS CloudFormation will make if you execute the change set. For more information, see <a href="https://docs.aws.amazon.com/AWSCloudFormation/latest/UserGuide/using-cfn-updating-stacks-changesets.html">Updating Stacks Using Change Sets</a> in the AWS CloudFormation User Guide.</p>
-    async fn describe_change_set(
+    /// Presigns the request `describe_account_limits` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_account_limits(
         &self,
-        input: DescribeChangeSetInput,
-    ) -> Result<DescribeChangeSetOutput, RusotoError<DescribeChangeSetError>> {
+        presigner: &rusoto_core::Presigner,
+        input: DescribeAccountLimitsInput,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_account_limits_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_account_limits_request(
+        &self,
+        input: DescribeAccountLimitsInput,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "cloudformation", &self.region, "/");
+        request.set_operation("DescribeAccountLimits");
         let mut params = Params::new();
 
-        params.put("Action", "DescribeChangeSet");
+        params.put("Action", "DescribeAccountLimits");
         params.put("Version", "2010-05-15");
-        DescribeChangeSetInputSerializer::serialize(&mut params, "", &input);
+        DescribeAccountLimitsInputSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `describe_change_set`, also returning the metadata of the response.
+    pub async fn describe_change_set_with_metadata(
+        &self,
+        input: DescribeChangeSetInput,
+    ) -> Result<(DescribeChangeSetOutput, ResponseMetadata), RusotoError<DescribeChangeSetError>>
+    {
+        let request = self.build_describe_change_set_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(DescribeChangeSetError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -12583,39 +13235,63 @@ impl CloudFormation for CloudFormationClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Returns information about a stack drift detection operation. A stack drift detection operation detects whether a stack's actual configuration differs, or has <i>drifted</i>, from it's expected configuration, as defined in the stack template and any values specified as template parameters. A stack is considered to have drifted if one or more of its resources have drifted. For more information on stack and resource drift, see <a href="https://docs.aws.amazon.com/AWSCloudFormation/latest/UserGuide/using-cfn-stack-drift.html">Detecting Unregulated Configuration Changes to Stacks and Resources</a>.</p> <p>Use <a>DetectStackDrift</a> to initiate a stack drift detection operation. <code>DetectStackDrift</code> returns a <code>StackDriftDetectionId</code> you can use to monitor the progress of the operation using <code>DescribeStackDriftDetectionStatus</code>. Once the drift detection operation has completed, use <a>DescribeStackResourceDrifts</a> to return drift information about the stack and its resources.</p>
-    async fn describe_stack_drift_detection_status(
+    /// Presigns the request `describe_change_set` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_change_set(
         &self,
-        input: DescribeStackDriftDetectionStatusInput,
-    ) -> Result<
-        DescribeStackDriftDetectionStatusOutput,
-        RusotoError<DescribeStackDriftDetectionStatusError>,
-    > {
+        presigner: &rusoto_core::Presigner,
+        input: DescribeChangeSetInput,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_change_set_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_change_set_request(&self, input: DescribeChangeSetInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "cloudformation", &self.region, "/");
+        request.set_operation("DescribeChangeSet");
         let mut params = Params::new();
 
-        params.put("Action", "DescribeStackDriftDetectionStatus");
+        params.put("Action", "DescribeChangeSet");
         params.put("Version", "2010-05-15");
-        DescribeStackDriftDetectionStatusInputSerializer::serialize(&mut params, "", &input);
+        DescribeChangeSetInputSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `describe_stack_drift_detection_status`, also returning the metadata of the response.
+    pub async fn describe_stack_drift_detection_status_with_metadata(
+        &self,
+        input: DescribeStackDriftDetectionStatusInput,
+    ) -> Result<
+        (DescribeStackDriftDetectionStatusOutput, ResponseMetadata),
+        RusotoError<DescribeStackDriftDetectionStatusError>,
+    > {
+        let request = self.build_describe_stack_drift_detection_status_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(DescribeStackDriftDetectionStatusError::from_response(
                 response,
             ));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -12637,34 +13313,62 @@ impl CloudFormation for CloudFormationClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p><p>Returns all stack related events for a specified stack in reverse chronological order. For more information about a stack&#39;s event history, go to <a href="https://docs.aws.amazon.com/AWSCloudFormation/latest/UserGuide/concept-stack.html">Stacks</a> in the AWS CloudFormation User Guide.</p> <note> <p>You can list events for stacks that have failed to create or have been deleted by specifying the unique stack identifier (stack ID).</p> </note></p>
-    async fn describe_stack_events(
+    /// Presigns the request `describe_stack_drift_detection_status` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_stack_drift_detection_status(
         &self,
-        input: DescribeStackEventsInput,
-    ) -> Result<DescribeStackEventsOutput, RusotoError<DescribeStackEventsError>> {
+        presigner: &rusoto_core::Presigner,
+        input: DescribeStackDriftDetectionStatusInput,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_stack_drift_detection_status_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_stack_drift_detection_status_request(
+        &self,
+        input: DescribeStackDriftDetectionStatusInput,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "cloudformation", &self.region, "/");
+        request.set_operation("DescribeStackDriftDetectionStatus");
         let mut params = Params::new();
 
-        params.put("Action", "DescribeStackEvents");
+        params.put("Action", "DescribeStackDriftDetectionStatus");
         params.put("Version", "2010-05-15");
-        DescribeStackEventsInputSerializer::serialize(&mut params, "", &input);
+        DescribeStackDriftDetectionStatusInputSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `describe_stack_events`, also returning the metadata of the response.
+    pub async fn describe_stack_events_with_metadata(
+        &self,
+        input: DescribeStackEventsInput,
+    ) -> Result<(DescribeStackEventsOutput, ResponseMetadata), RusotoError<DescribeStackEventsError>>
+    {
+        let request = self.build_describe_stack_events_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(DescribeStackEventsError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -12686,34 +13390,64 @@ impl CloudFormation for CloudFormationClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Returns the stack instance that's associated with the specified stack set, AWS account, and region.</p> <p>For a list of stack instances that are associated with a specific stack set, use <a>ListStackInstances</a>.</p>
-    async fn describe_stack_instance(
+    /// Presigns the request `describe_stack_events` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_stack_events(
         &self,
-        input: DescribeStackInstanceInput,
-    ) -> Result<DescribeStackInstanceOutput, RusotoError<DescribeStackInstanceError>> {
+        presigner: &rusoto_core::Presigner,
+        input: DescribeStackEventsInput,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_stack_events_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_stack_events_request(
+        &self,
+        input: DescribeStackEventsInput,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "cloudformation", &self.region, "/");
+        request.set_operation("DescribeStackEvents");
         let mut params = Params::new();
 
-        params.put("Action", "DescribeStackInstance");
+        params.put("Action", "DescribeStackEvents");
         params.put("Version", "2010-05-15");
-        DescribeStackInstanceInputSerializer::serialize(&mut params, "", &input);
+        DescribeStackEventsInputSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `describe_stack_instance`, also returning the metadata of the response.
+    pub async fn describe_stack_instance_with_metadata(
+        &self,
+        input: DescribeStackInstanceInput,
+    ) -> Result<
+        (DescribeStackInstanceOutput, ResponseMetadata),
+        RusotoError<DescribeStackInstanceError>,
+    > {
+        let request = self.build_describe_stack_instance_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(DescribeStackInstanceError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -12735,34 +13469,64 @@ impl CloudFormation for CloudFormationClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Returns a description of the specified resource in the specified stack.</p> <p>For deleted stacks, DescribeStackResource returns resource information for up to 90 days after the stack has been deleted.</p>
-    async fn describe_stack_resource(
+    /// Presigns the request `describe_stack_instance` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_stack_instance(
         &self,
-        input: DescribeStackResourceInput,
-    ) -> Result<DescribeStackResourceOutput, RusotoError<DescribeStackResourceError>> {
+        presigner: &rusoto_core::Presigner,
+        input: DescribeStackInstanceInput,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_stack_instance_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_stack_instance_request(
+        &self,
+        input: DescribeStackInstanceInput,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "cloudformation", &self.region, "/");
+        request.set_operation("DescribeStackInstance");
         let mut params = Params::new();
 
-        params.put("Action", "DescribeStackResource");
+        params.put("Action", "DescribeStackInstance");
         params.put("Version", "2010-05-15");
-        DescribeStackResourceInputSerializer::serialize(&mut params, "", &input);
+        DescribeStackInstanceInputSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `describe_stack_resource`, also returning the metadata of the response.
+    pub async fn describe_stack_resource_with_metadata(
+        &self,
+        input: DescribeStackResourceInput,
+    ) -> Result<
+        (DescribeStackResourceOutput, ResponseMetadata),
+        RusotoError<DescribeStackResourceError>,
+    > {
+        let request = self.build_describe_stack_resource_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(DescribeStackResourceError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -12784,35 +13548,64 @@ impl CloudFormation for CloudFormationClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Returns drift information for the resources that have been checked for drift in the specified stack. This includes actual and expected configuration values for resources where AWS CloudFormation detects configuration drift.</p> <p>For a given stack, there will be one <code>StackResourceDrift</code> for each stack resource that has been checked for drift. Resources that have not yet been checked for drift are not included. Resources that do not currently support drift detection are not checked, and so not included. For a list of resources that support drift detection, see <a href="https://docs.aws.amazon.com/AWSCloudFormation/latest/UserGuide/using-cfn-stack-drift-resource-list.html">Resources that Support Drift Detection</a>.</p> <p>Use <a>DetectStackResourceDrift</a> to detect drift on individual resources, or <a>DetectStackDrift</a> to detect drift on all supported resources for a given stack.</p>
-    async fn describe_stack_resource_drifts(
+    /// Presigns the request `describe_stack_resource` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_stack_resource(
         &self,
-        input: DescribeStackResourceDriftsInput,
-    ) -> Result<DescribeStackResourceDriftsOutput, RusotoError<DescribeStackResourceDriftsError>>
-    {
+        presigner: &rusoto_core::Presigner,
+        input: DescribeStackResourceInput,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_stack_resource_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_stack_resource_request(
+        &self,
+        input: DescribeStackResourceInput,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "cloudformation", &self.region, "/");
+        request.set_operation("DescribeStackResource");
         let mut params = Params::new();
 
-        params.put("Action", "DescribeStackResourceDrifts");
+        params.put("Action", "DescribeStackResource");
         params.put("Version", "2010-05-15");
-        DescribeStackResourceDriftsInputSerializer::serialize(&mut params, "", &input);
+        DescribeStackResourceInputSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `describe_stack_resource_drifts`, also returning the metadata of the response.
+    pub async fn describe_stack_resource_drifts_with_metadata(
+        &self,
+        input: DescribeStackResourceDriftsInput,
+    ) -> Result<
+        (DescribeStackResourceDriftsOutput, ResponseMetadata),
+        RusotoError<DescribeStackResourceDriftsError>,
+    > {
+        let request = self.build_describe_stack_resource_drifts_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(DescribeStackResourceDriftsError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -12834,34 +13627,64 @@ impl CloudFormation for CloudFormationClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p><p>Returns AWS resource descriptions for running and deleted stacks. If <code>StackName</code> is specified, all the associated resources that are part of the stack are returned. If <code>PhysicalResourceId</code> is specified, the associated resources of the stack that the resource belongs to are returned.</p> <note> <p>Only the first 100 resources will be returned. If your stack has more resources than this, you should use <code>ListStackResources</code> instead.</p> </note> <p>For deleted stacks, <code>DescribeStackResources</code> returns resource information for up to 90 days after the stack has been deleted.</p> <p>You must specify either <code>StackName</code> or <code>PhysicalResourceId</code>, but not both. In addition, you can specify <code>LogicalResourceId</code> to filter the returned result. For more information about resources, the <code>LogicalResourceId</code> and <code>PhysicalResourceId</code>, go to the <a href="https://docs.aws.amazon.com/AWSCloudFormation/latest/UserGuide/">AWS CloudFormation User Guide</a>.</p> <note> <p>A <code>ValidationError</code> is returned if you specify both <code>StackName</code> and <code>PhysicalResourceId</code> in the same request.</p> </note></p>
-    async fn describe_stack_resources(
+    /// Presigns the request `describe_stack_resource_drifts` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_stack_resource_drifts(
         &self,
-        input: DescribeStackResourcesInput,
-    ) -> Result<DescribeStackResourcesOutput, RusotoError<DescribeStackResourcesError>> {
+        presigner: &rusoto_core::Presigner,
+        input: DescribeStackResourceDriftsInput,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_stack_resource_drifts_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_stack_resource_drifts_request(
+        &self,
+        input: DescribeStackResourceDriftsInput,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "cloudformation", &self.region, "/");
+        request.set_operation("DescribeStackResourceDrifts");
         let mut params = Params::new();
 
-        params.put("Action", "DescribeStackResources");
+        params.put("Action", "DescribeStackResourceDrifts");
         params.put("Version", "2010-05-15");
-        DescribeStackResourcesInputSerializer::serialize(&mut params, "", &input);
+        DescribeStackResourceDriftsInputSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `describe_stack_resources`, also returning the metadata of the response.
+    pub async fn describe_stack_resources_with_metadata(
+        &self,
+        input: DescribeStackResourcesInput,
+    ) -> Result<
+        (DescribeStackResourcesOutput, ResponseMetadata),
+        RusotoError<DescribeStackResourcesError>,
+    > {
+        let request = self.build_describe_stack_resources_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(DescribeStackResourcesError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -12883,34 +13706,62 @@ impl CloudFormation for CloudFormationClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Returns the description of the specified stack set. </p>
-    async fn describe_stack_set(
+    /// Presigns the request `describe_stack_resources` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_stack_resources(
         &self,
-        input: DescribeStackSetInput,
-    ) -> Result<DescribeStackSetOutput, RusotoError<DescribeStackSetError>> {
+        presigner: &rusoto_core::Presigner,
+        input: DescribeStackResourcesInput,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_stack_resources_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_stack_resources_request(
+        &self,
+        input: DescribeStackResourcesInput,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "cloudformation", &self.region, "/");
+        request.set_operation("DescribeStackResources");
         let mut params = Params::new();
 
-        params.put("Action", "DescribeStackSet");
+        params.put("Action", "DescribeStackResources");
         params.put("Version", "2010-05-15");
-        DescribeStackSetInputSerializer::serialize(&mut params, "", &input);
+        DescribeStackResourcesInputSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `describe_stack_set`, also returning the metadata of the response.
+    pub async fn describe_stack_set_with_metadata(
+        &self,
+        input: DescribeStackSetInput,
+    ) -> Result<(DescribeStackSetOutput, ResponseMetadata), RusotoError<DescribeStackSetError>>
+    {
+        let request = self.build_describe_stack_set_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(DescribeStackSetError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -12932,34 +13783,61 @@ impl CloudFormation for CloudFormationClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Returns the description of the specified stack set operation. </p>
-    async fn describe_stack_set_operation(
+    /// Presigns the request `describe_stack_set` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_stack_set(
         &self,
-        input: DescribeStackSetOperationInput,
-    ) -> Result<DescribeStackSetOperationOutput, RusotoError<DescribeStackSetOperationError>> {
+        presigner: &rusoto_core::Presigner,
+        input: DescribeStackSetInput,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_stack_set_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_stack_set_request(&self, input: DescribeStackSetInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "cloudformation", &self.region, "/");
+        request.set_operation("DescribeStackSet");
         let mut params = Params::new();
 
-        params.put("Action", "DescribeStackSetOperation");
+        params.put("Action", "DescribeStackSet");
         params.put("Version", "2010-05-15");
-        DescribeStackSetOperationInputSerializer::serialize(&mut params, "", &input);
+        DescribeStackSetInputSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `describe_stack_set_operation`, also returning the metadata of the response.
+    pub async fn describe_stack_set_operation_with_metadata(
+        &self,
+        input: DescribeStackSetOperationInput,
+    ) -> Result<
+        (DescribeStackSetOperationOutput, ResponseMetadata),
+        RusotoError<DescribeStackSetOperationError>,
+    > {
+        let request = self.build_describe_stack_set_operation_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(DescribeStackSetOperationError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -12981,34 +13859,61 @@ impl CloudFormation for CloudFormationClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p><p>Returns the description for the specified stack; if no stack name was specified, then it returns the description for all the stacks created.</p> <note> <p>If the stack does not exist, an <code>AmazonCloudFormationException</code> is returned.</p> </note></p>
-    async fn describe_stacks(
+    /// Presigns the request `describe_stack_set_operation` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_stack_set_operation(
         &self,
-        input: DescribeStacksInput,
-    ) -> Result<DescribeStacksOutput, RusotoError<DescribeStacksError>> {
+        presigner: &rusoto_core::Presigner,
+        input: DescribeStackSetOperationInput,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_stack_set_operation_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_stack_set_operation_request(
+        &self,
+        input: DescribeStackSetOperationInput,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "cloudformation", &self.region, "/");
+        request.set_operation("DescribeStackSetOperation");
         let mut params = Params::new();
 
-        params.put("Action", "DescribeStacks");
+        params.put("Action", "DescribeStackSetOperation");
         params.put("Version", "2010-05-15");
-        DescribeStacksInputSerializer::serialize(&mut params, "", &input);
+        DescribeStackSetOperationInputSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `describe_stacks`, also returning the metadata of the response.
+    pub async fn describe_stacks_with_metadata(
+        &self,
+        input: DescribeStacksInput,
+    ) -> Result<(DescribeStacksOutput, ResponseMetadata), RusotoError<DescribeStacksError>> {
+        let request = self.build_describe_stacks_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(DescribeStacksError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -13028,34 +13933,58 @@ impl CloudFormation for CloudFormationClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Returns detailed information about a type that has been registered.</p> <p>If you specify a <code>VersionId</code>, <code>DescribeType</code> returns information about that specific type version. Otherwise, it returns information about the default type version.</p>
-    async fn describe_type(
+    /// Presigns the request `describe_stacks` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_stacks(
         &self,
-        input: DescribeTypeInput,
-    ) -> Result<DescribeTypeOutput, RusotoError<DescribeTypeError>> {
+        presigner: &rusoto_core::Presigner,
+        input: DescribeStacksInput,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_stacks_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_stacks_request(&self, input: DescribeStacksInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "cloudformation", &self.region, "/");
+        request.set_operation("DescribeStacks");
         let mut params = Params::new();
 
-        params.put("Action", "DescribeType");
+        params.put("Action", "DescribeStacks");
         params.put("Version", "2010-05-15");
-        DescribeTypeInputSerializer::serialize(&mut params, "", &input);
+        DescribeStacksInputSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `describe_type`, also returning the metadata of the response.
+    pub async fn describe_type_with_metadata(
+        &self,
+        input: DescribeTypeInput,
+    ) -> Result<(DescribeTypeOutput, ResponseMetadata), RusotoError<DescribeTypeError>> {
+        let request = self.build_describe_type_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(DescribeTypeError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -13074,34 +14003,61 @@ impl CloudFormation for CloudFormationClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Returns information about a type's registration, including its current status and type and version identifiers.</p> <p>When you initiate a registration request using <code> <a>RegisterType</a> </code>, you can then use <code> <a>DescribeTypeRegistration</a> </code> to monitor the progress of that registration request.</p> <p>Once the registration request has completed, use <code> <a>DescribeType</a> </code> to return detailed informaiton about a type.</p>
-    async fn describe_type_registration(
+    /// Presigns the request `describe_type` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_type(
         &self,
-        input: DescribeTypeRegistrationInput,
-    ) -> Result<DescribeTypeRegistrationOutput, RusotoError<DescribeTypeRegistrationError>> {
+        presigner: &rusoto_core::Presigner,
+        input: DescribeTypeInput,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_type_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_type_request(&self, input: DescribeTypeInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "cloudformation", &self.region, "/");
+        request.set_operation("DescribeType");
         let mut params = Params::new();
 
-        params.put("Action", "DescribeTypeRegistration");
+        params.put("Action", "DescribeType");
         params.put("Version", "2010-05-15");
-        DescribeTypeRegistrationInputSerializer::serialize(&mut params, "", &input);
+        DescribeTypeInputSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `describe_type_registration`, also returning the metadata of the response.
+    pub async fn describe_type_registration_with_metadata(
+        &self,
+        input: DescribeTypeRegistrationInput,
+    ) -> Result<
+        (DescribeTypeRegistrationOutput, ResponseMetadata),
+        RusotoError<DescribeTypeRegistrationError>,
+    > {
+        let request = self.build_describe_type_registration_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(DescribeTypeRegistrationError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -13123,34 +14079,62 @@ impl CloudFormation for CloudFormationClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Detects whether a stack's actual configuration differs, or has <i>drifted</i>, from it's expected configuration, as defined in the stack template and any values specified as template parameters. For each resource in the stack that supports drift detection, AWS CloudFormation compares the actual configuration of the resource with its expected template configuration. Only resource properties explicitly defined in the stack template are checked for drift. A stack is considered to have drifted if one or more of its resources differ from their expected template configurations. For more information, see <a href="https://docs.aws.amazon.com/AWSCloudFormation/latest/UserGuide/using-cfn-stack-drift.html">Detecting Unregulated Configuration Changes to Stacks and Resources</a>.</p> <p>Use <code>DetectStackDrift</code> to detect drift on all supported resources for a given stack, or <a>DetectStackResourceDrift</a> to detect drift on individual resources.</p> <p>For a list of stack resources that currently support drift detection, see <a href="https://docs.aws.amazon.com/AWSCloudFormation/latest/UserGuide/using-cfn-stack-drift-resource-list.html">Resources that Support Drift Detection</a>.</p> <p> <code>DetectStackDrift</code> can take up to several minutes, depending on the number of resources contained within the stack. Use <a>DescribeStackDriftDetectionStatus</a> to monitor the progress of a detect stack drift operation. Once the drift detection operation has completed, use <a>DescribeStackResourceDrifts</a> to return drift information about the stack and its resources.</p> <p>When detecting drift on a stack, AWS CloudFormation does not detect drift on any nested stacks belonging to that stack. Perform <code>DetectStackDrift</code> directly on the nested stack itself.</p>
-    async fn detect_stack_drift(
+    /// Presigns the request `describe_type_registration` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_type_registration(
         &self,
-        input: DetectStackDriftInput,
-    ) -> Result<DetectStackDriftOutput, RusotoError<DetectStackDriftError>> {
+        presigner: &rusoto_core::Presigner,
+        input: DescribeTypeRegistrationInput,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_type_registration_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_type_registration_request(
+        &self,
+        input: DescribeTypeRegistrationInput,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "cloudformation", &self.region, "/");
+        request.set_operation("DescribeTypeRegistration");
         let mut params = Params::new();
 
-        params.put("Action", "DetectStackDrift");
+        params.put("Action", "DescribeTypeRegistration");
         params.put("Version", "2010-05-15");
-        DetectStackDriftInputSerializer::serialize(&mut params, "", &input);
+        DescribeTypeRegistrationInputSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `detect_stack_drift`, also returning the metadata of the response.
+    pub async fn detect_stack_drift_with_metadata(
+        &self,
+        input: DetectStackDriftInput,
+    ) -> Result<(DetectStackDriftOutput, ResponseMetadata), RusotoError<DetectStackDriftError>>
+    {
+        let request = self.build_detect_stack_drift_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(DetectStackDriftError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -13172,34 +14156,61 @@ impl CloudFormation for CloudFormationClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Returns information about whether a resource's actual configuration differs, or has <i>drifted</i>, from it's expected configuration, as defined in the stack template and any values specified as template parameters. This information includes actual and expected property values for resources in which AWS CloudFormation detects drift. Only resource properties explicitly defined in the stack template are checked for drift. For more information about stack and resource drift, see <a href="https://docs.aws.amazon.com/AWSCloudFormation/latest/UserGuide/using-cfn-stack-drift.html">Detecting Unregulated Configuration Changes to Stacks and Resources</a>.</p> <p>Use <code>DetectStackResourceDrift</code> to detect drift on individual resources, or <a>DetectStackDrift</a> to detect drift on all resources in a given stack that support drift detection.</p> <p>Resources that do not currently support drift detection cannot be checked. For a list of resources that support drift detection, see <a href="https://docs.aws.amazon.com/AWSCloudFormation/latest/UserGuide/using-cfn-stack-drift-resource-list.html">Resources that Support Drift Detection</a>.</p>
-    async fn detect_stack_resource_drift(
+    /// Presigns the request `detect_stack_drift` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_detect_stack_drift(
         &self,
-        input: DetectStackResourceDriftInput,
-    ) -> Result<DetectStackResourceDriftOutput, RusotoError<DetectStackResourceDriftError>> {
+        presigner: &rusoto_core::Presigner,
+        input: DetectStackDriftInput,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_detect_stack_drift_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_detect_stack_drift_request(&self, input: DetectStackDriftInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "cloudformation", &self.region, "/");
+        request.set_operation("DetectStackDrift");
         let mut params = Params::new();
 
-        params.put("Action", "DetectStackResourceDrift");
+        params.put("Action", "DetectStackDrift");
         params.put("Version", "2010-05-15");
-        DetectStackResourceDriftInputSerializer::serialize(&mut params, "", &input);
+        DetectStackDriftInputSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `detect_stack_resource_drift`, also returning the metadata of the response.
+    pub async fn detect_stack_resource_drift_with_metadata(
+        &self,
+        input: DetectStackResourceDriftInput,
+    ) -> Result<
+        (DetectStackResourceDriftOutput, ResponseMetadata),
+        RusotoError<DetectStackResourceDriftError>,
+    > {
+        let request = self.build_detect_stack_resource_drift_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(DetectStackResourceDriftError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -13221,34 +14232,62 @@ impl CloudFormation for CloudFormationClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Detect drift on a stack set. When CloudFormation performs drift detection on a stack set, it performs drift detection on the stack associated with each stack instance in the stack set. For more information, see <a href="https://docs.aws.amazon.com/AWSCloudFormation/latest/UserGuide/stacksets-drift.html">How CloudFormation Performs Drift Detection on a Stack Set</a>.</p> <p> <code>DetectStackSetDrift</code> returns the <code>OperationId</code> of the stack set drift detection operation. Use this operation id with <code> <a>DescribeStackSetOperation</a> </code> to monitor the progress of the drift detection operation. The drift detection operation may take some time, depending on the number of stack instances included in the stack set, as well as the number of resources included in each stack.</p> <p>Once the operation has completed, use the following actions to return drift information:</p> <ul> <li> <p>Use <code> <a>DescribeStackSet</a> </code> to return detailed informaiton about the stack set, including detailed information about the last <i>completed</i> drift operation performed on the stack set. (Information about drift operations that are in progress is not included.)</p> </li> <li> <p>Use <code> <a>ListStackInstances</a> </code> to return a list of stack instances belonging to the stack set, including the drift status and last drift time checked of each instance.</p> </li> <li> <p>Use <code> <a>DescribeStackInstance</a> </code> to return detailed information about a specific stack instance, including its drift status and last drift time checked.</p> </li> </ul> <p>For more information on performing a drift detection operation on a stack set, see <a href="https://docs.aws.amazon.com/AWSCloudFormation/latest/UserGuide/stacksets-drift.html">Detecting Unmanaged Changes in Stack Sets</a>. </p> <p>You can only run a single drift detection operation on a given stack set at one time. </p> <p>To stop a drift detection stack set operation, use <code> <a>StopStackSetOperation</a> </code>.</p>
-    async fn detect_stack_set_drift(
+    /// Presigns the request `detect_stack_resource_drift` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_detect_stack_resource_drift(
         &self,
-        input: DetectStackSetDriftInput,
-    ) -> Result<DetectStackSetDriftOutput, RusotoError<DetectStackSetDriftError>> {
+        presigner: &rusoto_core::Presigner,
+        input: DetectStackResourceDriftInput,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_detect_stack_resource_drift_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_detect_stack_resource_drift_request(
+        &self,
+        input: DetectStackResourceDriftInput,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "cloudformation", &self.region, "/");
+        request.set_operation("DetectStackResourceDrift");
         let mut params = Params::new();
 
-        params.put("Action", "DetectStackSetDrift");
+        params.put("Action", "DetectStackResourceDrift");
         params.put("Version", "2010-05-15");
-        DetectStackSetDriftInputSerializer::serialize(&mut params, "", &input);
+        DetectStackResourceDriftInputSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `detect_stack_set_drift`, also returning the metadata of the response.
+    pub async fn detect_stack_set_drift_with_metadata(
+        &self,
+        input: DetectStackSetDriftInput,
+    ) -> Result<(DetectStackSetDriftOutput, ResponseMetadata), RusotoError<DetectStackSetDriftError>>
+    {
+        let request = self.build_detect_stack_set_drift_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(DetectStackSetDriftError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -13270,34 +14309,64 @@ impl CloudFormation for CloudFormationClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Returns the estimated monthly cost of a template. The return value is an AWS Simple Monthly Calculator URL with a query string that describes the resources required to run the template.</p>
-    async fn estimate_template_cost(
+    /// Presigns the request `detect_stack_set_drift` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_detect_stack_set_drift(
         &self,
-        input: EstimateTemplateCostInput,
-    ) -> Result<EstimateTemplateCostOutput, RusotoError<EstimateTemplateCostError>> {
+        presigner: &rusoto_core::Presigner,
+        input: DetectStackSetDriftInput,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_detect_stack_set_drift_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_detect_stack_set_drift_request(
+        &self,
+        input: DetectStackSetDriftInput,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "cloudformation", &self.region, "/");
+        request.set_operation("DetectStackSetDrift");
         let mut params = Params::new();
 
-        params.put("Action", "EstimateTemplateCost");
+        params.put("Action", "DetectStackSetDrift");
         params.put("Version", "2010-05-15");
-        EstimateTemplateCostInputSerializer::serialize(&mut params, "", &input);
+        DetectStackSetDriftInputSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
-        let mut response = self
-            .client
-            .sign_and_dispatch(request)
+        request
+    }
+
+    /// Calls `estimate_template_cost`, also returning the metadata of the response.
+    pub async fn estimate_template_cost_with_metadata(
+        &self,
+        input: EstimateTemplateCostInput,
+    ) -> Result<
+        (EstimateTemplateCostOutput, ResponseMetadata),
+        RusotoError<EstimateTemplateCostError>,
+    > {
+        let request = self.build_estimate_template_cost_request(input);
+
+        let mut response = self
+            .client
+            .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(EstimateTemplateCostError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -13319,34 +14388,62 @@ impl CloudFormation for CloudFormationClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Updates a stack using the input information that was provided when the specified change set was created. After the call successfully completes, AWS CloudFormation starts updating the stack. Use the <a>DescribeStacks</a> action to view the status of the update.</p> <p>When you execute a change set, AWS CloudFormation deletes all other change sets associated with the stack because they aren't valid for the updated stack.</p> <p>If a stack policy is associated with the stack, AWS CloudFormation enforces the policy during the update. You can't specify a temporary stack policy that overrides the current policy.</p>
-    async fn execute_change_set(
+    /// Presigns the request `estimate_template_cost` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_estimate_template_cost(
         &self,
-        input: ExecuteChangeSetInput,
-    ) -> Result<ExecuteChangeSetOutput, RusotoError<ExecuteChangeSetError>> {
+        presigner: &rusoto_core::Presigner,
+        input: EstimateTemplateCostInput,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_estimate_template_cost_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_estimate_template_cost_request(
+        &self,
+        input: EstimateTemplateCostInput,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "cloudformation", &self.region, "/");
+        request.set_operation("EstimateTemplateCost");
         let mut params = Params::new();
 
-        params.put("Action", "ExecuteChangeSet");
+        params.put("Action", "EstimateTemplateCost");
         params.put("Version", "2010-05-15");
-        ExecuteChangeSetInputSerializer::serialize(&mut params, "", &input);
+        EstimateTemplateCostInputSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `execute_change_set`, also returning the metadata of the response.
+    pub async fn execute_change_set_with_metadata(
+        &self,
+        input: ExecuteChangeSetInput,
+    ) -> Result<(ExecuteChangeSetOutput, ResponseMetadata), RusotoError<ExecuteChangeSetError>>
+    {
+        let request = self.build_execute_change_set_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(ExecuteChangeSetError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -13368,34 +14465,58 @@ impl CloudFormation for CloudFormationClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Returns the stack policy for a specified stack. If a stack doesn't have a policy, a null value is returned.</p>
-    async fn get_stack_policy(
+    /// Presigns the request `execute_change_set` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_execute_change_set(
         &self,
-        input: GetStackPolicyInput,
-    ) -> Result<GetStackPolicyOutput, RusotoError<GetStackPolicyError>> {
+        presigner: &rusoto_core::Presigner,
+        input: ExecuteChangeSetInput,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_execute_change_set_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_execute_change_set_request(&self, input: ExecuteChangeSetInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "cloudformation", &self.region, "/");
+        request.set_operation("ExecuteChangeSet");
         let mut params = Params::new();
 
-        params.put("Action", "GetStackPolicy");
+        params.put("Action", "ExecuteChangeSet");
         params.put("Version", "2010-05-15");
-        GetStackPolicyInputSerializer::serialize(&mut params, "", &input);
+        ExecuteChangeSetInputSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `get_stack_policy`, also returning the metadata of the response.
+    pub async fn get_stack_policy_with_metadata(
+        &self,
+        input: GetStackPolicyInput,
+    ) -> Result<(GetStackPolicyOutput, ResponseMetadata), RusotoError<GetStackPolicyError>> {
+        let request = self.build_get_stack_policy_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(GetStackPolicyError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -13415,34 +14536,58 @@ impl CloudFormation for CloudFormationClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p><p>Returns the template body for a specified stack. You can get the template for running or deleted stacks.</p> <p>For deleted stacks, GetTemplate returns the template for up to 90 days after the stack has been deleted.</p> <note> <p> If the template does not exist, a <code>ValidationError</code> is returned. </p> </note></p>
-    async fn get_template(
+    /// Presigns the request `get_stack_policy` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_stack_policy(
         &self,
-        input: GetTemplateInput,
-    ) -> Result<GetTemplateOutput, RusotoError<GetTemplateError>> {
+        presigner: &rusoto_core::Presigner,
+        input: GetStackPolicyInput,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_stack_policy_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_stack_policy_request(&self, input: GetStackPolicyInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "cloudformation", &self.region, "/");
+        request.set_operation("GetStackPolicy");
         let mut params = Params::new();
 
-        params.put("Action", "GetTemplate");
+        params.put("Action", "GetStackPolicy");
         params.put("Version", "2010-05-15");
-        GetTemplateInputSerializer::serialize(&mut params, "", &input);
+        GetStackPolicyInputSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `get_template`, also returning the metadata of the response.
+    pub async fn get_template_with_metadata(
+        &self,
+        input: GetTemplateInput,
+    ) -> Result<(GetTemplateOutput, ResponseMetadata), RusotoError<GetTemplateError>> {
+        let request = self.build_get_template_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(GetTemplateError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -13461,34 +14606,59 @@ impl CloudFormation for CloudFormationClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Returns information about a new or existing template. The <code>GetTemplateSummary</code> action is useful for viewing parameter information, such as default parameter values and parameter types, before you create or update a stack or stack set.</p> <p>You can use the <code>GetTemplateSummary</code> action when you submit a template, or you can get template information for a stack set, or a running or deleted stack.</p> <p>For deleted stacks, <code>GetTemplateSummary</code> returns the template information for up to 90 days after the stack has been deleted. If the template does not exist, a <code>ValidationError</code> is returned.</p>
-    async fn get_template_summary(
+    /// Presigns the request `get_template` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_template(
         &self,
-        input: GetTemplateSummaryInput,
-    ) -> Result<GetTemplateSummaryOutput, RusotoError<GetTemplateSummaryError>> {
+        presigner: &rusoto_core::Presigner,
+        input: GetTemplateInput,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_template_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_template_request(&self, input: GetTemplateInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "cloudformation", &self.region, "/");
+        request.set_operation("GetTemplate");
         let mut params = Params::new();
 
-        params.put("Action", "GetTemplateSummary");
+        params.put("Action", "GetTemplate");
         params.put("Version", "2010-05-15");
-        GetTemplateSummaryInputSerializer::serialize(&mut params, "", &input);
+        GetTemplateInputSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `get_template_summary`, also returning the metadata of the response.
+    pub async fn get_template_summary_with_metadata(
+        &self,
+        input: GetTemplateSummaryInput,
+    ) -> Result<(GetTemplateSummaryOutput, ResponseMetadata), RusotoError<GetTemplateSummaryError>>
+    {
+        let request = self.build_get_template_summary_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(GetTemplateSummaryError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -13510,34 +14680,58 @@ impl CloudFormation for CloudFormationClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Returns the ID and status of each active change set for a stack. For example, AWS CloudFormation lists change sets that are in the <code>CREATE_IN_PROGRESS</code> or <code>CREATE_PENDING</code> state.</p>
-    async fn list_change_sets(
+    /// Presigns the request `get_template_summary` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_template_summary(
         &self,
-        input: ListChangeSetsInput,
-    ) -> Result<ListChangeSetsOutput, RusotoError<ListChangeSetsError>> {
+        presigner: &rusoto_core::Presigner,
+        input: GetTemplateSummaryInput,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_template_summary_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_template_summary_request(&self, input: GetTemplateSummaryInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "cloudformation", &self.region, "/");
+        request.set_operation("GetTemplateSummary");
         let mut params = Params::new();
 
-        params.put("Action", "ListChangeSets");
+        params.put("Action", "GetTemplateSummary");
         params.put("Version", "2010-05-15");
-        ListChangeSetsInputSerializer::serialize(&mut params, "", &input);
+        GetTemplateSummaryInputSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `list_change_sets`, also returning the metadata of the response.
+    pub async fn list_change_sets_with_metadata(
+        &self,
+        input: ListChangeSetsInput,
+    ) -> Result<(ListChangeSetsOutput, ResponseMetadata), RusotoError<ListChangeSetsError>> {
+        let request = self.build_list_change_sets_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(ListChangeSetsError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -13557,34 +14751,58 @@ impl CloudFormation for CloudFormationClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Lists all exported output values in the account and region in which you call this action. Use this action to see the exported output values that you can import into other stacks. To import values, use the <a href="https://docs.aws.amazon.com/AWSCloudFormation/latest/UserGuide/intrinsic-function-reference-importvalue.html"> <code>Fn::ImportValue</code> </a> function. </p> <p>For more information, see <a href="https://docs.aws.amazon.com/AWSCloudFormation/latest/UserGuide/using-cfn-stack-exports.html"> AWS CloudFormation Export Stack Output Values</a>.</p>
-    async fn list_exports(
+    /// Presigns the request `list_change_sets` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_change_sets(
         &self,
-        input: ListExportsInput,
-    ) -> Result<ListExportsOutput, RusotoError<ListExportsError>> {
+        presigner: &rusoto_core::Presigner,
+        input: ListChangeSetsInput,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_change_sets_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_change_sets_request(&self, input: ListChangeSetsInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "cloudformation", &self.region, "/");
+        request.set_operation("ListChangeSets");
         let mut params = Params::new();
 
-        params.put("Action", "ListExports");
+        params.put("Action", "ListChangeSets");
         params.put("Version", "2010-05-15");
-        ListExportsInputSerializer::serialize(&mut params, "", &input);
+        ListChangeSetsInputSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `list_exports`, also returning the metadata of the response.
+    pub async fn list_exports_with_metadata(
+        &self,
+        input: ListExportsInput,
+    ) -> Result<(ListExportsOutput, ResponseMetadata), RusotoError<ListExportsError>> {
+        let request = self.build_list_exports_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(ListExportsError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -13603,34 +14821,58 @@ impl CloudFormation for CloudFormationClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Lists all stacks that are importing an exported output value. To modify or remove an exported output value, first use this action to see which stacks are using it. To see the exported output values in your account, see <a>ListExports</a>. </p> <p>For more information about importing an exported output value, see the <a href="https://docs.aws.amazon.com/AWSCloudFormation/latest/UserGuide/intrinsic-function-reference-importvalue.html"> <code>Fn::ImportValue</code> </a> function. </p>
-    async fn list_imports(
+    /// Presigns the request `list_exports` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_exports(
         &self,
-        input: ListImportsInput,
-    ) -> Result<ListImportsOutput, RusotoError<ListImportsError>> {
+        presigner: &rusoto_core::Presigner,
+        input: ListExportsInput,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_exports_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_exports_request(&self, input: ListExportsInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "cloudformation", &self.region, "/");
+        request.set_operation("ListExports");
         let mut params = Params::new();
 
-        params.put("Action", "ListImports");
+        params.put("Action", "ListExports");
         params.put("Version", "2010-05-15");
-        ListImportsInputSerializer::serialize(&mut params, "", &input);
+        ListExportsInputSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `list_imports`, also returning the metadata of the response.
+    pub async fn list_imports_with_metadata(
+        &self,
+        input: ListImportsInput,
+    ) -> Result<(ListImportsOutput, ResponseMetadata), RusotoError<ListImportsError>> {
+        let request = self.build_list_imports_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(ListImportsError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -13649,34 +14891,59 @@ impl CloudFormation for CloudFormationClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Returns summary information about stack instances that are associated with the specified stack set. You can filter for stack instances that are associated with a specific AWS account name or region.</p>
-    async fn list_stack_instances(
+    /// Presigns the request `list_imports` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_imports(
         &self,
-        input: ListStackInstancesInput,
-    ) -> Result<ListStackInstancesOutput, RusotoError<ListStackInstancesError>> {
+        presigner: &rusoto_core::Presigner,
+        input: ListImportsInput,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_imports_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_imports_request(&self, input: ListImportsInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "cloudformation", &self.region, "/");
+        request.set_operation("ListImports");
         let mut params = Params::new();
 
-        params.put("Action", "ListStackInstances");
+        params.put("Action", "ListImports");
         params.put("Version", "2010-05-15");
-        ListStackInstancesInputSerializer::serialize(&mut params, "", &input);
+        ListImportsInputSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `list_stack_instances`, also returning the metadata of the response.
+    pub async fn list_stack_instances_with_metadata(
+        &self,
+        input: ListStackInstancesInput,
+    ) -> Result<(ListStackInstancesOutput, ResponseMetadata), RusotoError<ListStackInstancesError>>
+    {
+        let request = self.build_list_stack_instances_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(ListStackInstancesError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -13698,34 +14965,59 @@ impl CloudFormation for CloudFormationClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Returns descriptions of all resources of the specified stack.</p> <p>For deleted stacks, ListStackResources returns resource information for up to 90 days after the stack has been deleted.</p>
-    async fn list_stack_resources(
+    /// Presigns the request `list_stack_instances` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_stack_instances(
         &self,
-        input: ListStackResourcesInput,
-    ) -> Result<ListStackResourcesOutput, RusotoError<ListStackResourcesError>> {
+        presigner: &rusoto_core::Presigner,
+        input: ListStackInstancesInput,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_stack_instances_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_stack_instances_request(&self, input: ListStackInstancesInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "cloudformation", &self.region, "/");
+        request.set_operation("ListStackInstances");
         let mut params = Params::new();
 
-        params.put("Action", "ListStackResources");
+        params.put("Action", "ListStackInstances");
         params.put("Version", "2010-05-15");
-        ListStackResourcesInputSerializer::serialize(&mut params, "", &input);
+        ListStackInstancesInputSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `list_stack_resources`, also returning the metadata of the response.
+    pub async fn list_stack_resources_with_metadata(
+        &self,
+        input: ListStackResourcesInput,
+    ) -> Result<(ListStackResourcesOutput, ResponseMetadata), RusotoError<ListStackResourcesError>>
+    {
+        let request = self.build_list_stack_resources_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(ListStackResourcesError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -13747,35 +15039,61 @@ impl CloudFormation for CloudFormationClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Returns summary information about the results of a stack set operation. </p>
-    async fn list_stack_set_operation_results(
+    /// Presigns the request `list_stack_resources` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_stack_resources(
         &self,
-        input: ListStackSetOperationResultsInput,
-    ) -> Result<ListStackSetOperationResultsOutput, RusotoError<ListStackSetOperationResultsError>>
-    {
+        presigner: &rusoto_core::Presigner,
+        input: ListStackResourcesInput,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_stack_resources_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_stack_resources_request(&self, input: ListStackResourcesInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "cloudformation", &self.region, "/");
+        request.set_operation("ListStackResources");
         let mut params = Params::new();
 
-        params.put("Action", "ListStackSetOperationResults");
+        params.put("Action", "ListStackResources");
         params.put("Version", "2010-05-15");
-        ListStackSetOperationResultsInputSerializer::serialize(&mut params, "", &input);
+        ListStackResourcesInputSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `list_stack_set_operation_results`, also returning the metadata of the response.
+    pub async fn list_stack_set_operation_results_with_metadata(
+        &self,
+        input: ListStackSetOperationResultsInput,
+    ) -> Result<
+        (ListStackSetOperationResultsOutput, ResponseMetadata),
+        RusotoError<ListStackSetOperationResultsError>,
+    > {
+        let request = self.build_list_stack_set_operation_results_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(ListStackSetOperationResultsError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -13797,34 +15115,64 @@ impl CloudFormation for CloudFormationClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Returns summary information about operations performed on a stack set. </p>
-    async fn list_stack_set_operations(
+    /// Presigns the request `list_stack_set_operation_results` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_stack_set_operation_results(
         &self,
-        input: ListStackSetOperationsInput,
-    ) -> Result<ListStackSetOperationsOutput, RusotoError<ListStackSetOperationsError>> {
+        presigner: &rusoto_core::Presigner,
+        input: ListStackSetOperationResultsInput,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_stack_set_operation_results_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_stack_set_operation_results_request(
+        &self,
+        input: ListStackSetOperationResultsInput,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "cloudformation", &self.region, "/");
+        request.set_operation("ListStackSetOperationResults");
         let mut params = Params::new();
 
-        params.put("Action", "ListStackSetOperations");
+        params.put("Action", "ListStackSetOperationResults");
         params.put("Version", "2010-05-15");
-        ListStackSetOperationsInputSerializer::serialize(&mut params, "", &input);
+        ListStackSetOperationResultsInputSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `list_stack_set_operations`, also returning the metadata of the response.
+    pub async fn list_stack_set_operations_with_metadata(
+        &self,
+        input: ListStackSetOperationsInput,
+    ) -> Result<
+        (ListStackSetOperationsOutput, ResponseMetadata),
+        RusotoError<ListStackSetOperationsError>,
+    > {
+        let request = self.build_list_stack_set_operations_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(ListStackSetOperationsError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -13846,34 +15194,61 @@ impl CloudFormation for CloudFormationClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Returns summary information about stack sets that are associated with the user.</p>
-    async fn list_stack_sets(
+    /// Presigns the request `list_stack_set_operations` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_stack_set_operations(
         &self,
-        input: ListStackSetsInput,
-    ) -> Result<ListStackSetsOutput, RusotoError<ListStackSetsError>> {
+        presigner: &rusoto_core::Presigner,
+        input: ListStackSetOperationsInput,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_stack_set_operations_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_stack_set_operations_request(
+        &self,
+        input: ListStackSetOperationsInput,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "cloudformation", &self.region, "/");
+        request.set_operation("ListStackSetOperations");
         let mut params = Params::new();
 
-        params.put("Action", "ListStackSets");
+        params.put("Action", "ListStackSetOperations");
         params.put("Version", "2010-05-15");
-        ListStackSetsInputSerializer::serialize(&mut params, "", &input);
+        ListStackSetOperationsInputSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `list_stack_sets`, also returning the metadata of the response.
+    pub async fn list_stack_sets_with_metadata(
+        &self,
+        input: ListStackSetsInput,
+    ) -> Result<(ListStackSetsOutput, ResponseMetadata), RusotoError<ListStackSetsError>> {
+        let request = self.build_list_stack_sets_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(ListStackSetsError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -13893,34 +15268,58 @@ impl CloudFormation for CloudFormationClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Returns the summary information for stacks whose status matches the specified StackStatusFilter. Summary information for stacks that have been deleted is kept for 90 days after the stack is deleted. If no StackStatusFilter is specified, summary information for all stacks is returned (including existing stacks and stacks that have been deleted).</p>
-    async fn list_stacks(
+    /// Presigns the request `list_stack_sets` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_stack_sets(
         &self,
-        input: ListStacksInput,
-    ) -> Result<ListStacksOutput, RusotoError<ListStacksError>> {
+        presigner: &rusoto_core::Presigner,
+        input: ListStackSetsInput,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_stack_sets_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_stack_sets_request(&self, input: ListStackSetsInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "cloudformation", &self.region, "/");
+        request.set_operation("ListStackSets");
         let mut params = Params::new();
 
-        params.put("Action", "ListStacks");
+        params.put("Action", "ListStackSets");
         params.put("Version", "2010-05-15");
-        ListStacksInputSerializer::serialize(&mut params, "", &input);
+        ListStackSetsInputSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
-        let mut response = self
-            .client
+        request
+    }
+
+    /// Calls `list_stacks`, also returning the metadata of the response.
+    pub async fn list_stacks_with_metadata(
+        &self,
+        input: ListStacksInput,
+    ) -> Result<(ListStacksOutput, ResponseMetadata), RusotoError<ListStacksError>> {
+        let request = self.build_list_stacks_request(input);
+
+        let mut response = self
+            .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(ListStacksError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -13939,34 +15338,61 @@ impl CloudFormation for CloudFormationClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Returns a list of registration tokens for the specified type.</p>
-    async fn list_type_registrations(
+    /// Presigns the request `list_stacks` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_stacks(
         &self,
-        input: ListTypeRegistrationsInput,
-    ) -> Result<ListTypeRegistrationsOutput, RusotoError<ListTypeRegistrationsError>> {
+        presigner: &rusoto_core::Presigner,
+        input: ListStacksInput,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_stacks_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_stacks_request(&self, input: ListStacksInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "cloudformation", &self.region, "/");
+        request.set_operation("ListStacks");
         let mut params = Params::new();
 
-        params.put("Action", "ListTypeRegistrations");
+        params.put("Action", "ListStacks");
         params.put("Version", "2010-05-15");
-        ListTypeRegistrationsInputSerializer::serialize(&mut params, "", &input);
+        ListStacksInputSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `list_type_registrations`, also returning the metadata of the response.
+    pub async fn list_type_registrations_with_metadata(
+        &self,
+        input: ListTypeRegistrationsInput,
+    ) -> Result<
+        (ListTypeRegistrationsOutput, ResponseMetadata),
+        RusotoError<ListTypeRegistrationsError>,
+    > {
+        let request = self.build_list_type_registrations_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(ListTypeRegistrationsError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -13988,34 +15414,62 @@ impl CloudFormation for CloudFormationClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Returns summary information about the versions of a type.</p>
-    async fn list_type_versions(
+    /// Presigns the request `list_type_registrations` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_type_registrations(
         &self,
-        input: ListTypeVersionsInput,
-    ) -> Result<ListTypeVersionsOutput, RusotoError<ListTypeVersionsError>> {
+        presigner: &rusoto_core::Presigner,
+        input: ListTypeRegistrationsInput,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_type_registrations_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_type_registrations_request(
+        &self,
+        input: ListTypeRegistrationsInput,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "cloudformation", &self.region, "/");
+        request.set_operation("ListTypeRegistrations");
         let mut params = Params::new();
 
-        params.put("Action", "ListTypeVersions");
+        params.put("Action", "ListTypeRegistrations");
         params.put("Version", "2010-05-15");
-        ListTypeVersionsInputSerializer::serialize(&mut params, "", &input);
+        ListTypeRegistrationsInputSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `list_type_versions`, also returning the metadata of the response.
+    pub async fn list_type_versions_with_metadata(
+        &self,
+        input: ListTypeVersionsInput,
+    ) -> Result<(ListTypeVersionsOutput, ResponseMetadata), RusotoError<ListTypeVersionsError>>
+    {
+        let request = self.build_list_type_versions_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(ListTypeVersionsError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -14037,34 +15491,58 @@ impl CloudFormation for CloudFormationClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Returns summary information about types that have been registered with CloudFormation.</p>
-    async fn list_types(
+    /// Presigns the request `list_type_versions` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_type_versions(
         &self,
-        input: ListTypesInput,
-    ) -> Result<ListTypesOutput, RusotoError<ListTypesError>> {
+        presigner: &rusoto_core::Presigner,
+        input: ListTypeVersionsInput,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_type_versions_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_type_versions_request(&self, input: ListTypeVersionsInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "cloudformation", &self.region, "/");
+        request.set_operation("ListTypeVersions");
         let mut params = Params::new();
 
-        params.put("Action", "ListTypes");
+        params.put("Action", "ListTypeVersions");
         params.put("Version", "2010-05-15");
-        ListTypesInputSerializer::serialize(&mut params, "", &input);
+        ListTypeVersionsInputSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `list_types`, also returning the metadata of the response.
+    pub async fn list_types_with_metadata(
+        &self,
+        input: ListTypesInput,
+    ) -> Result<(ListTypesOutput, ResponseMetadata), RusotoError<ListTypesError>> {
+        let request = self.build_list_types_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(ListTypesError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -14083,34 +15561,61 @@ impl CloudFormation for CloudFormationClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Reports progress of a resource handler to CloudFormation.</p> <p>Reserved for use by the <a href="https://docs.aws.amazon.com/cloudformation-cli/latest/userguide/what-is-cloudformation-cli.html">CloudFormation CLI</a>. Do not use this API in your code.</p>
-    async fn record_handler_progress(
+    /// Presigns the request `list_types` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_types(
         &self,
-        input: RecordHandlerProgressInput,
-    ) -> Result<RecordHandlerProgressOutput, RusotoError<RecordHandlerProgressError>> {
+        presigner: &rusoto_core::Presigner,
+        input: ListTypesInput,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_types_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_types_request(&self, input: ListTypesInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "cloudformation", &self.region, "/");
+        request.set_operation("ListTypes");
         let mut params = Params::new();
 
-        params.put("Action", "RecordHandlerProgress");
+        params.put("Action", "ListTypes");
         params.put("Version", "2010-05-15");
-        RecordHandlerProgressInputSerializer::serialize(&mut params, "", &input);
+        ListTypesInputSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `record_handler_progress`, also returning the metadata of the response.
+    pub async fn record_handler_progress_with_metadata(
+        &self,
+        input: RecordHandlerProgressInput,
+    ) -> Result<
+        (RecordHandlerProgressOutput, ResponseMetadata),
+        RusotoError<RecordHandlerProgressError>,
+    > {
+        let request = self.build_record_handler_progress_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(RecordHandlerProgressError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -14132,34 +15637,61 @@ impl CloudFormation for CloudFormationClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Registers a type with the CloudFormation service. Registering a type makes it available for use in CloudFormation templates in your AWS account, and includes:</p> <ul> <li> <p>Validating the resource schema</p> </li> <li> <p>Determining which handlers have been specified for the resource</p> </li> <li> <p>Making the resource type available for use in your account</p> </li> </ul> <p>For more information on how to develop types and ready them for registeration, see <a href="cloudformation-cli/latest/userguide/resource-types.html">Creating Resource Providers</a> in the <i>CloudFormation CLI User Guide</i>.</p> <p>Once you have initiated a registration request using <code> <a>RegisterType</a> </code>, you can use <code> <a>DescribeTypeRegistration</a> </code> to monitor the progress of the registration request.</p>
-    async fn register_type(
+    /// Presigns the request `record_handler_progress` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_record_handler_progress(
         &self,
-        input: RegisterTypeInput,
-    ) -> Result<RegisterTypeOutput, RusotoError<RegisterTypeError>> {
+        presigner: &rusoto_core::Presigner,
+        input: RecordHandlerProgressInput,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_record_handler_progress_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_record_handler_progress_request(
+        &self,
+        input: RecordHandlerProgressInput,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "cloudformation", &self.region, "/");
+        request.set_operation("RecordHandlerProgress");
         let mut params = Params::new();
 
-        params.put("Action", "RegisterType");
+        params.put("Action", "RecordHandlerProgress");
         params.put("Version", "2010-05-15");
-        RegisterTypeInputSerializer::serialize(&mut params, "", &input);
+        RecordHandlerProgressInputSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `register_type`, also returning the metadata of the response.
+    pub async fn register_type_with_metadata(
+        &self,
+        input: RegisterTypeInput,
+    ) -> Result<(RegisterTypeOutput, ResponseMetadata), RusotoError<RegisterTypeError>> {
+        let request = self.build_register_type_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(RegisterTypeError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -14178,62 +15710,110 @@ impl CloudFormation for CloudFormationClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Sets a stack policy for a specified stack.</p>
-    async fn set_stack_policy(
+    /// Presigns the request `register_type` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_register_type(
         &self,
-        input: SetStackPolicyInput,
-    ) -> Result<(), RusotoError<SetStackPolicyError>> {
+        presigner: &rusoto_core::Presigner,
+        input: RegisterTypeInput,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_register_type_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_register_type_request(&self, input: RegisterTypeInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "cloudformation", &self.region, "/");
+        request.set_operation("RegisterType");
         let mut params = Params::new();
 
-        params.put("Action", "SetStackPolicy");
+        params.put("Action", "RegisterType");
         params.put("Version", "2010-05-15");
-        SetStackPolicyInputSerializer::serialize(&mut params, "", &input);
+        RegisterTypeInputSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `set_stack_policy`, also returning the metadata of the response.
+    pub async fn set_stack_policy_with_metadata(
+        &self,
+        input: SetStackPolicyInput,
+    ) -> Result<((), ResponseMetadata), RusotoError<SetStackPolicyError>> {
+        let request = self.build_set_stack_policy_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(SetStackPolicyError::from_response(response));
         }
 
         std::mem::drop(response);
-        Ok(())
+        Ok(((), metadata))
     }
 
-    /// <p>Specify the default version of a type. The default version of a type will be used in CloudFormation operations.</p>
-    async fn set_type_default_version(
+    /// Presigns the request `set_stack_policy` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_set_stack_policy(
         &self,
-        input: SetTypeDefaultVersionInput,
-    ) -> Result<SetTypeDefaultVersionOutput, RusotoError<SetTypeDefaultVersionError>> {
+        presigner: &rusoto_core::Presigner,
+        input: SetStackPolicyInput,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_set_stack_policy_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_set_stack_policy_request(&self, input: SetStackPolicyInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "cloudformation", &self.region, "/");
+        request.set_operation("SetStackPolicy");
         let mut params = Params::new();
 
-        params.put("Action", "SetTypeDefaultVersion");
+        params.put("Action", "SetStackPolicy");
         params.put("Version", "2010-05-15");
-        SetTypeDefaultVersionInputSerializer::serialize(&mut params, "", &input);
+        SetStackPolicyInputSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `set_type_default_version`, also returning the metadata of the response.
+    pub async fn set_type_default_version_with_metadata(
+        &self,
+        input: SetTypeDefaultVersionInput,
+    ) -> Result<
+        (SetTypeDefaultVersionOutput, ResponseMetadata),
+        RusotoError<SetTypeDefaultVersionError>,
+    > {
+        let request = self.build_set_type_default_version_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(SetTypeDefaultVersionError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -14255,62 +15835,113 @@ impl CloudFormation for CloudFormationClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Sends a signal to the specified resource with a success or failure status. You can use the SignalResource API in conjunction with a creation policy or update policy. AWS CloudFormation doesn't proceed with a stack creation or update until resources receive the required number of signals or the timeout period is exceeded. The SignalResource API is useful in cases where you want to send signals from anywhere other than an Amazon EC2 instance.</p>
-    async fn signal_resource(
+    /// Presigns the request `set_type_default_version` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_set_type_default_version(
         &self,
-        input: SignalResourceInput,
-    ) -> Result<(), RusotoError<SignalResourceError>> {
+        presigner: &rusoto_core::Presigner,
+        input: SetTypeDefaultVersionInput,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_set_type_default_version_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_set_type_default_version_request(
+        &self,
+        input: SetTypeDefaultVersionInput,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "cloudformation", &self.region, "/");
+        request.set_operation("SetTypeDefaultVersion");
         let mut params = Params::new();
 
-        params.put("Action", "SignalResource");
+        params.put("Action", "SetTypeDefaultVersion");
         params.put("Version", "2010-05-15");
-        SignalResourceInputSerializer::serialize(&mut params, "", &input);
+        SetTypeDefaultVersionInputSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `signal_resource`, also returning the metadata of the response.
+    pub async fn signal_resource_with_metadata(
+        &self,
+        input: SignalResourceInput,
+    ) -> Result<((), ResponseMetadata), RusotoError<SignalResourceError>> {
+        let request = self.build_signal_resource_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(SignalResourceError::from_response(response));
         }
 
         std::mem::drop(response);
-        Ok(())
+        Ok(((), metadata))
     }
 
-    /// <p>Stops an in-progress operation on a stack set and its associated stack instances. </p>
-    async fn stop_stack_set_operation(
+    /// Presigns the request `signal_resource` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_signal_resource(
         &self,
-        input: StopStackSetOperationInput,
-    ) -> Result<StopStackSetOperationOutput, RusotoError<StopStackSetOperationError>> {
+        presigner: &rusoto_core::Presigner,
+        input: SignalResourceInput,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_signal_resource_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_signal_resource_request(&self, input: SignalResourceInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "cloudformation", &self.region, "/");
+        request.set_operation("SignalResource");
         let mut params = Params::new();
 
-        params.put("Action", "StopStackSetOperation");
+        params.put("Action", "SignalResource");
         params.put("Version", "2010-05-15");
-        StopStackSetOperationInputSerializer::serialize(&mut params, "", &input);
+        SignalResourceInputSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `stop_stack_set_operation`, also returning the metadata of the response.
+    pub async fn stop_stack_set_operation_with_metadata(
+        &self,
+        input: StopStackSetOperationInput,
+    ) -> Result<
+        (StopStackSetOperationOutput, ResponseMetadata),
+        RusotoError<StopStackSetOperationError>,
+    > {
+        let request = self.build_stop_stack_set_operation_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(StopStackSetOperationError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -14332,34 +15963,61 @@ impl CloudFormation for CloudFormationClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Updates a stack as specified in the template. After the call completes successfully, the stack update starts. You can check the status of the stack via the <a>DescribeStacks</a> action.</p> <p>To get a copy of the template for an existing stack, you can use the <a>GetTemplate</a> action.</p> <p>For more information about creating an update template, updating a stack, and monitoring the progress of the update, see <a href="https://docs.aws.amazon.com/AWSCloudFormation/latest/UserGuide/using-cfn-updating-stacks.html">Updating a Stack</a>.</p>
-    async fn update_stack(
+    /// Presigns the request `stop_stack_set_operation` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_stop_stack_set_operation(
         &self,
-        input: UpdateStackInput,
-    ) -> Result<UpdateStackOutput, RusotoError<UpdateStackError>> {
+        presigner: &rusoto_core::Presigner,
+        input: StopStackSetOperationInput,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_stop_stack_set_operation_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_stop_stack_set_operation_request(
+        &self,
+        input: StopStackSetOperationInput,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "cloudformation", &self.region, "/");
+        request.set_operation("StopStackSetOperation");
         let mut params = Params::new();
 
-        params.put("Action", "UpdateStack");
+        params.put("Action", "StopStackSetOperation");
         params.put("Version", "2010-05-15");
-        UpdateStackInputSerializer::serialize(&mut params, "", &input);
+        StopStackSetOperationInputSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `update_stack`, also returning the metadata of the response.
+    pub async fn update_stack_with_metadata(
+        &self,
+        input: UpdateStackInput,
+    ) -> Result<(UpdateStackOutput, ResponseMetadata), RusotoError<UpdateStackError>> {
+        let request = self.build_update_stack_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(UpdateStackError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -14378,34 +16036,61 @@ impl CloudFormation for CloudFormationClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Updates the parameter values for stack instances for the specified accounts, within the specified regions. A stack instance refers to a stack in a specific account and region. </p> <p>You can only update stack instances in regions and accounts where they already exist; to create additional stack instances, use <a href="https://docs.aws.amazon.com/AWSCloudFormation/latest/APIReference/API_CreateStackInstances.html">CreateStackInstances</a>. </p> <p>During stack set updates, any parameters overridden for a stack instance are not updated, but retain their overridden value.</p> <p>You can only update the parameter <i>values</i> that are specified in the stack set; to add or delete a parameter itself, use <a href="https://docs.aws.amazon.com/AWSCloudFormation/latest/APIReference/API_UpdateStackSet.html">UpdateStackSet</a> to update the stack set template. If you add a parameter to a template, before you can override the parameter value specified in the stack set you must first use <a href="https://docs.aws.amazon.com/AWSCloudFormation/latest/APIReference/API_UpdateStackSet.html">UpdateStackSet</a> to update all stack instances with the updated template and parameter value specified in the stack set. Once a stack instance has been updated with the new parameter, you can then override the parameter value using <code>UpdateStackInstances</code>.</p>
-    async fn update_stack_instances(
+    /// Presigns the request `update_stack` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_stack(
         &self,
-        input: UpdateStackInstancesInput,
-    ) -> Result<UpdateStackInstancesOutput, RusotoError<UpdateStackInstancesError>> {
+        presigner: &rusoto_core::Presigner,
+        input: UpdateStackInput,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_stack_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_stack_request(&self, input: UpdateStackInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "cloudformation", &self.region, "/");
+        request.set_operation("UpdateStack");
         let mut params = Params::new();
 
-        params.put("Action", "UpdateStackInstances");
+        params.put("Action", "UpdateStack");
         params.put("Version", "2010-05-15");
-        UpdateStackInstancesInputSerializer::serialize(&mut params, "", &input);
+        UpdateStackInputSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `update_stack_instances`, also returning the metadata of the response.
+    pub async fn update_stack_instances_with_metadata(
+        &self,
+        input: UpdateStackInstancesInput,
+    ) -> Result<
+        (UpdateStackInstancesOutput, ResponseMetadata),
+        RusotoError<UpdateStackInstancesError>,
+    > {
+        let request = self.build_update_stack_instances_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(UpdateStackInstancesError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -14427,34 +16112,61 @@ impl CloudFormation for CloudFormationClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Updates the stack set, and associated stack instances in the specified accounts and regions.</p> <p>Even if the stack set operation created by updating the stack set fails (completely or partially, below or above a specified failure tolerance), the stack set is updated with your changes. Subsequent <a>CreateStackInstances</a> calls on the specified stack set use the updated stack set.</p>
-    async fn update_stack_set(
+    /// Presigns the request `update_stack_instances` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_stack_instances(
         &self,
-        input: UpdateStackSetInput,
-    ) -> Result<UpdateStackSetOutput, RusotoError<UpdateStackSetError>> {
+        presigner: &rusoto_core::Presigner,
+        input: UpdateStackInstancesInput,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_stack_instances_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_stack_instances_request(
+        &self,
+        input: UpdateStackInstancesInput,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "cloudformation", &self.region, "/");
+        request.set_operation("UpdateStackInstances");
         let mut params = Params::new();
 
-        params.put("Action", "UpdateStackSet");
+        params.put("Action", "UpdateStackInstances");
         params.put("Version", "2010-05-15");
-        UpdateStackSetInputSerializer::serialize(&mut params, "", &input);
+        UpdateStackInstancesInputSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `update_stack_set`, also returning the metadata of the response.
+    pub async fn update_stack_set_with_metadata(
+        &self,
+        input: UpdateStackSetInput,
+    ) -> Result<(UpdateStackSetOutput, ResponseMetadata), RusotoError<UpdateStackSetError>> {
+        let request = self.build_update_stack_set_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(UpdateStackSetError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -14474,35 +16186,61 @@ impl CloudFormation for CloudFormationClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Updates termination protection for the specified stack. If a user attempts to delete a stack with termination protection enabled, the operation fails and the stack remains unchanged. For more information, see <a href="https://docs.aws.amazon.com/AWSCloudFormation/latest/UserGuide/using-cfn-protect-stacks.html">Protecting a Stack From Being Deleted</a> in the <i>AWS CloudFormation User Guide</i>.</p> <p> For <a href="https://docs.aws.amazon.com/AWSCloudFormation/latest/UserGuide/using-cfn-nested-stacks.html">nested stacks</a>, termination protection is set on the root stack and cannot be changed directly on the nested stack.</p>
-    async fn update_termination_protection(
+    /// Presigns the request `update_stack_set` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_stack_set(
         &self,
-        input: UpdateTerminationProtectionInput,
-    ) -> Result<UpdateTerminationProtectionOutput, RusotoError<UpdateTerminationProtectionError>>
-    {
+        presigner: &rusoto_core::Presigner,
+        input: UpdateStackSetInput,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_stack_set_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_stack_set_request(&self, input: UpdateStackSetInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "cloudformation", &self.region, "/");
+        request.set_operation("UpdateStackSet");
         let mut params = Params::new();
 
-        params.put("Action", "UpdateTerminationProtection");
+        params.put("Action", "UpdateStackSet");
         params.put("Version", "2010-05-15");
-        UpdateTerminationProtectionInputSerializer::serialize(&mut params, "", &input);
+        UpdateStackSetInputSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
-        let mut response = self
-            .client
+        request
+    }
+
+    /// Calls `update_termination_protection`, also returning the metadata of the response.
+    pub async fn update_termination_protection_with_metadata(
+        &self,
+        input: UpdateTerminationProtectionInput,
+    ) -> Result<
+        (UpdateTerminationProtectionOutput, ResponseMetadata),
+        RusotoError<UpdateTerminationProtectionError>,
+    > {
+        let request = self.build_update_termination_protection_request(input);
+
+        let mut response = self
+            .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(UpdateTerminationProtectionError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -14524,34 +16262,62 @@ impl CloudFormation for CloudFormationClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Validates a specified template. AWS CloudFormation first checks if the template is valid JSON. If it isn't, AWS CloudFormation checks if the template is valid YAML. If both these checks fail, AWS CloudFormation returns a template validation error.</p>
-    async fn validate_template(
+    /// Presigns the request `update_termination_protection` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_termination_protection(
         &self,
-        input: ValidateTemplateInput,
-    ) -> Result<ValidateTemplateOutput, RusotoError<ValidateTemplateError>> {
+        presigner: &rusoto_core::Presigner,
+        input: UpdateTerminationProtectionInput,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_termination_protection_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_termination_protection_request(
+        &self,
+        input: UpdateTerminationProtectionInput,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "cloudformation", &self.region, "/");
+        request.set_operation("UpdateTerminationProtection");
         let mut params = Params::new();
 
-        params.put("Action", "ValidateTemplate");
+        params.put("Action", "UpdateTerminationProtection");
         params.put("Version", "2010-05-15");
-        ValidateTemplateInputSerializer::serialize(&mut params, "", &input);
+        UpdateTerminationProtectionInputSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `validate_template`, also returning the metadata of the response.
+    pub async fn validate_template_with_metadata(
+        &self,
+        input: ValidateTemplateInput,
+    ) -> Result<(ValidateTemplateOutput, ResponseMetadata), RusotoError<ValidateTemplateError>>
+    {
+        let request = self.build_validate_template_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(ValidateTemplateError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -14573,7 +16339,1823 @@ impl CloudFormation for CloudFormationClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
+    }
+
+    /// Presigns the request `validate_template` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_validate_template(
+        &self,
+        presigner: &rusoto_core::Presigner,
+        input: ValidateTemplateInput,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_validate_template_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_validate_template_request(&self, input: ValidateTemplateInput) -> SignedRequest {
+        let mut request = SignedRequest::new("POST", "cloudformation", &self.region, "/");
+        request.set_operation("ValidateTemplate");
+        let mut params = Params::new();
+
+        params.put("Action", "ValidateTemplate");
+        params.put("Version", "2010-05-15");
+        ValidateTemplateInputSerializer::serialize(&mut params, "", &input);
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        request
+    }
+
+    /// The pages of `DescribeAccountLimits` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    pub fn describe_account_limits_pages(
+        &self,
+        input: DescribeAccountLimitsInput,
+    ) -> impl futures::Stream<
+        Item = Result<DescribeAccountLimitsOutput, RusotoError<DescribeAccountLimitsError>>,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: CloudFormationClient, input| async move {
+                client.describe_account_limits(input).await
+            },
+            |input: &DescribeAccountLimitsInput, output: &DescribeAccountLimitsOutput| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(DescribeAccountLimitsInput {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `AccountLimits` of every page of `DescribeAccountLimits` results for `input`, as `describe_account_limits_pages` requests them.
+    pub fn describe_account_limits_stream(
+        &self,
+        input: DescribeAccountLimitsInput,
+    ) -> impl futures::Stream<Item = Result<AccountLimit, RusotoError<DescribeAccountLimitsError>>> + Send
+    {
+        rusoto_core::pagination::items(self.describe_account_limits_pages(input), |page| {
+            page.account_limits.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `DescribeChangeSet` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    pub fn describe_change_set_pages(
+        &self,
+        input: DescribeChangeSetInput,
+    ) -> impl futures::Stream<
+        Item = Result<DescribeChangeSetOutput, RusotoError<DescribeChangeSetError>>,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: CloudFormationClient, input| async move { client.describe_change_set(input).await },
+            |input: &DescribeChangeSetInput, output: &DescribeChangeSetOutput| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(DescribeChangeSetInput {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `Changes` of every page of `DescribeChangeSet` results for `input`, as `describe_change_set_pages` requests them.
+    pub fn describe_change_set_stream(
+        &self,
+        input: DescribeChangeSetInput,
+    ) -> impl futures::Stream<Item = Result<Change, RusotoError<DescribeChangeSetError>>> + Send
+    {
+        rusoto_core::pagination::items(self.describe_change_set_pages(input), |page| {
+            page.changes.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `DescribeStackEvents` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    pub fn describe_stack_events_pages(
+        &self,
+        input: DescribeStackEventsInput,
+    ) -> impl futures::Stream<
+        Item = Result<DescribeStackEventsOutput, RusotoError<DescribeStackEventsError>>,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: CloudFormationClient, input| async move {
+                client.describe_stack_events(input).await
+            },
+            |input: &DescribeStackEventsInput, output: &DescribeStackEventsOutput| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(DescribeStackEventsInput {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `StackEvents` of every page of `DescribeStackEvents` results for `input`, as `describe_stack_events_pages` requests them.
+    pub fn describe_stack_events_stream(
+        &self,
+        input: DescribeStackEventsInput,
+    ) -> impl futures::Stream<Item = Result<StackEvent, RusotoError<DescribeStackEventsError>>> + Send
+    {
+        rusoto_core::pagination::items(self.describe_stack_events_pages(input), |page| {
+            page.stack_events.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `DescribeStacks` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    pub fn describe_stacks_pages(
+        &self,
+        input: DescribeStacksInput,
+    ) -> impl futures::Stream<Item = Result<DescribeStacksOutput, RusotoError<DescribeStacksError>>> + Send
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: CloudFormationClient, input| async move { client.describe_stacks(input).await },
+            |input: &DescribeStacksInput, output: &DescribeStacksOutput| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(DescribeStacksInput {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `Stacks` of every page of `DescribeStacks` results for `input`, as `describe_stacks_pages` requests them.
+    pub fn describe_stacks_stream(
+        &self,
+        input: DescribeStacksInput,
+    ) -> impl futures::Stream<Item = Result<Stack, RusotoError<DescribeStacksError>>> + Send {
+        rusoto_core::pagination::items(self.describe_stacks_pages(input), |page| {
+            page.stacks.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `ListChangeSets` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    pub fn list_change_sets_pages(
+        &self,
+        input: ListChangeSetsInput,
+    ) -> impl futures::Stream<Item = Result<ListChangeSetsOutput, RusotoError<ListChangeSetsError>>> + Send
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: CloudFormationClient, input| async move { client.list_change_sets(input).await },
+            |input: &ListChangeSetsInput, output: &ListChangeSetsOutput| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListChangeSetsInput {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `Summaries` of every page of `ListChangeSets` results for `input`, as `list_change_sets_pages` requests them.
+    pub fn list_change_sets_stream(
+        &self,
+        input: ListChangeSetsInput,
+    ) -> impl futures::Stream<Item = Result<ChangeSetSummary, RusotoError<ListChangeSetsError>>> + Send
+    {
+        rusoto_core::pagination::items(self.list_change_sets_pages(input), |page| {
+            page.summaries.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `ListExports` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    pub fn list_exports_pages(
+        &self,
+        input: ListExportsInput,
+    ) -> impl futures::Stream<Item = Result<ListExportsOutput, RusotoError<ListExportsError>>> + Send
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: CloudFormationClient, input| async move { client.list_exports(input).await },
+            |input: &ListExportsInput, output: &ListExportsOutput| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListExportsInput {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `Exports` of every page of `ListExports` results for `input`, as `list_exports_pages` requests them.
+    pub fn list_exports_stream(
+        &self,
+        input: ListExportsInput,
+    ) -> impl futures::Stream<Item = Result<Export, RusotoError<ListExportsError>>> + Send {
+        rusoto_core::pagination::items(self.list_exports_pages(input), |page| {
+            page.exports.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `ListImports` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    pub fn list_imports_pages(
+        &self,
+        input: ListImportsInput,
+    ) -> impl futures::Stream<Item = Result<ListImportsOutput, RusotoError<ListImportsError>>> + Send
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: CloudFormationClient, input| async move { client.list_imports(input).await },
+            |input: &ListImportsInput, output: &ListImportsOutput| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListImportsInput {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `Imports` of every page of `ListImports` results for `input`, as `list_imports_pages` requests them.
+    pub fn list_imports_stream(
+        &self,
+        input: ListImportsInput,
+    ) -> impl futures::Stream<Item = Result<String, RusotoError<ListImportsError>>> + Send {
+        rusoto_core::pagination::items(self.list_imports_pages(input), |page| {
+            page.imports.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `ListStackInstances` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `MaxResults` of `input`.
+    pub fn list_stack_instances_pages(
+        &self,
+        input: ListStackInstancesInput,
+    ) -> impl futures::Stream<
+        Item = Result<ListStackInstancesOutput, RusotoError<ListStackInstancesError>>,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: CloudFormationClient, input| async move {
+                client.list_stack_instances(input).await
+            },
+            |input: &ListStackInstancesInput, output: &ListStackInstancesOutput| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListStackInstancesInput {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `Summaries` of every page of `ListStackInstances` results for `input`, as `list_stack_instances_pages` requests them.
+    pub fn list_stack_instances_stream(
+        &self,
+        input: ListStackInstancesInput,
+    ) -> impl futures::Stream<
+        Item = Result<StackInstanceSummary, RusotoError<ListStackInstancesError>>,
+    > + Send {
+        rusoto_core::pagination::items(self.list_stack_instances_pages(input), |page| {
+            page.summaries.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `ListStackResources` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    pub fn list_stack_resources_pages(
+        &self,
+        input: ListStackResourcesInput,
+    ) -> impl futures::Stream<
+        Item = Result<ListStackResourcesOutput, RusotoError<ListStackResourcesError>>,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: CloudFormationClient, input| async move {
+                client.list_stack_resources(input).await
+            },
+            |input: &ListStackResourcesInput, output: &ListStackResourcesOutput| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListStackResourcesInput {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `StackResourceSummaries` of every page of `ListStackResources` results for `input`, as `list_stack_resources_pages` requests them.
+    pub fn list_stack_resources_stream(
+        &self,
+        input: ListStackResourcesInput,
+    ) -> impl futures::Stream<
+        Item = Result<StackResourceSummary, RusotoError<ListStackResourcesError>>,
+    > + Send {
+        rusoto_core::pagination::items(self.list_stack_resources_pages(input), |page| {
+            page.stack_resource_summaries.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `ListStackSetOperationResults` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `MaxResults` of `input`.
+    pub fn list_stack_set_operation_results_pages(
+        &self,
+        input: ListStackSetOperationResultsInput,
+    ) -> impl futures::Stream<
+        Item = Result<
+            ListStackSetOperationResultsOutput,
+            RusotoError<ListStackSetOperationResultsError>,
+        >,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: CloudFormationClient, input| async move {
+                client.list_stack_set_operation_results(input).await
+            },
+            |input: &ListStackSetOperationResultsInput,
+             output: &ListStackSetOperationResultsOutput| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListStackSetOperationResultsInput {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `Summaries` of every page of `ListStackSetOperationResults` results for `input`, as `list_stack_set_operation_results_pages` requests them.
+    pub fn list_stack_set_operation_results_stream(
+        &self,
+        input: ListStackSetOperationResultsInput,
+    ) -> impl futures::Stream<
+        Item = Result<
+            StackSetOperationResultSummary,
+            RusotoError<ListStackSetOperationResultsError>,
+        >,
+    > + Send {
+        rusoto_core::pagination::items(self.list_stack_set_operation_results_pages(input), |page| {
+            page.summaries.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `ListStackSetOperations` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `MaxResults` of `input`.
+    pub fn list_stack_set_operations_pages(
+        &self,
+        input: ListStackSetOperationsInput,
+    ) -> impl futures::Stream<
+        Item = Result<ListStackSetOperationsOutput, RusotoError<ListStackSetOperationsError>>,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: CloudFormationClient, input| async move {
+                client.list_stack_set_operations(input).await
+            },
+            |input: &ListStackSetOperationsInput, output: &ListStackSetOperationsOutput| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListStackSetOperationsInput {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `Summaries` of every page of `ListStackSetOperations` results for `input`, as `list_stack_set_operations_pages` requests them.
+    pub fn list_stack_set_operations_stream(
+        &self,
+        input: ListStackSetOperationsInput,
+    ) -> impl futures::Stream<
+        Item = Result<StackSetOperationSummary, RusotoError<ListStackSetOperationsError>>,
+    > + Send {
+        rusoto_core::pagination::items(self.list_stack_set_operations_pages(input), |page| {
+            page.summaries.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `ListStackSets` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `MaxResults` of `input`.
+    pub fn list_stack_sets_pages(
+        &self,
+        input: ListStackSetsInput,
+    ) -> impl futures::Stream<Item = Result<ListStackSetsOutput, RusotoError<ListStackSetsError>>> + Send
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: CloudFormationClient, input| async move { client.list_stack_sets(input).await },
+            |input: &ListStackSetsInput, output: &ListStackSetsOutput| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListStackSetsInput {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `Summaries` of every page of `ListStackSets` results for `input`, as `list_stack_sets_pages` requests them.
+    pub fn list_stack_sets_stream(
+        &self,
+        input: ListStackSetsInput,
+    ) -> impl futures::Stream<Item = Result<StackSetSummary, RusotoError<ListStackSetsError>>> + Send
+    {
+        rusoto_core::pagination::items(self.list_stack_sets_pages(input), |page| {
+            page.summaries.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `ListStacks` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    pub fn list_stacks_pages(
+        &self,
+        input: ListStacksInput,
+    ) -> impl futures::Stream<Item = Result<ListStacksOutput, RusotoError<ListStacksError>>> + Send
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: CloudFormationClient, input| async move { client.list_stacks(input).await },
+            |input: &ListStacksInput, output: &ListStacksOutput| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListStacksInput {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `StackSummaries` of every page of `ListStacks` results for `input`, as `list_stacks_pages` requests them.
+    pub fn list_stacks_stream(
+        &self,
+        input: ListStacksInput,
+    ) -> impl futures::Stream<Item = Result<StackSummary, RusotoError<ListStacksError>>> + Send
+    {
+        rusoto_core::pagination::items(self.list_stacks_pages(input), |page| {
+            page.stack_summaries.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `ListTypes` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `MaxResults` of `input`.
+    pub fn list_types_pages(
+        &self,
+        input: ListTypesInput,
+    ) -> impl futures::Stream<Item = Result<ListTypesOutput, RusotoError<ListTypesError>>> + Send
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: CloudFormationClient, input| async move { client.list_types(input).await },
+            |input: &ListTypesInput, output: &ListTypesOutput| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListTypesInput {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `TypeSummaries` of every page of `ListTypes` results for `input`, as `list_types_pages` requests them.
+    pub fn list_types_stream(
+        &self,
+        input: ListTypesInput,
+    ) -> impl futures::Stream<Item = Result<TypeSummary, RusotoError<ListTypesError>>> + Send {
+        rusoto_core::pagination::items(self.list_types_pages(input), |page| {
+            page.type_summaries.unwrap_or_default()
+        })
+    }
+
+    /// Waits for `ChangeSetCreateComplete`, polling `DescribeChangeSet` with `input` every 30 seconds, up to 120 times.
+    pub async fn wait_until_change_set_create_complete(
+        &self,
+        input: DescribeChangeSetInput,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<DescribeChangeSetError>> {
+        let waiter = rusoto_core::waiter::Waiter::new(std::time::Duration::from_secs(30), 120);
+        self.wait_until_change_set_create_complete_with(input, &waiter)
+            .await
+    }
+
+    /// Waits for `ChangeSetCreateComplete` like `wait_until_change_set_create_complete`, with the delay, attempts and runtime of `waiter`.
+    pub async fn wait_until_change_set_create_complete_with(
+        &self,
+        input: DescribeChangeSetInput,
+        waiter: &rusoto_core::waiter::Waiter,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<DescribeChangeSetError>> {
+        rusoto_core::waiter::wait(
+            waiter,
+            || self.describe_change_set(input.clone()),
+            |result| {
+                if match result {
+                    Ok(output) => std::iter::once(output)
+                        .filter_map(|value| value.status.as_ref())
+                        .any(|value| value == "CREATE_COMPLETE"),
+                    Err(_) => false,
+                } {
+                    return Some(rusoto_core::waiter::WaiterState::Success);
+                }
+                if match result {
+                    Ok(output) => std::iter::once(output)
+                        .filter_map(|value| value.status.as_ref())
+                        .any(|value| value == "FAILED"),
+                    Err(_) => false,
+                } {
+                    return Some(rusoto_core::waiter::WaiterState::Failure);
+                }
+                if result
+                    .as_ref()
+                    .err()
+                    .and_then(|error| error.error_code())
+                    .as_deref()
+                    == Some("ValidationError")
+                {
+                    return Some(rusoto_core::waiter::WaiterState::Failure);
+                }
+                None
+            },
+        )
+        .await
+    }
+
+    /// Waits for `StackCreateComplete`, polling `DescribeStacks` with `input` every 30 seconds, up to 120 times.
+    pub async fn wait_until_stack_create_complete(
+        &self,
+        input: DescribeStacksInput,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<DescribeStacksError>> {
+        let waiter = rusoto_core::waiter::Waiter::new(std::time::Duration::from_secs(30), 120);
+        self.wait_until_stack_create_complete_with(input, &waiter)
+            .await
+    }
+
+    /// Waits for `StackCreateComplete` like `wait_until_stack_create_complete`, with the delay, attempts and runtime of `waiter`.
+    pub async fn wait_until_stack_create_complete_with(
+        &self,
+        input: DescribeStacksInput,
+        waiter: &rusoto_core::waiter::Waiter,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<DescribeStacksError>> {
+        rusoto_core::waiter::wait(
+            waiter,
+            || self.describe_stacks(input.clone()),
+            |result| {
+                if match result {
+                    Ok(output) => {
+                        let mut values = std::iter::once(output)
+                            .filter_map(|value| value.stacks.as_ref())
+                            .flat_map(|value| value.iter())
+                            .map(|value| &value.stack_status)
+                            .peekable();
+                        values.peek().is_some() && values.all(|value| value == "CREATE_COMPLETE")
+                    }
+                    Err(_) => false,
+                } {
+                    return Some(rusoto_core::waiter::WaiterState::Success);
+                }
+                if match result {
+                    Ok(output) => {
+                        let mut values = std::iter::once(output)
+                            .filter_map(|value| value.stacks.as_ref())
+                            .flat_map(|value| value.iter())
+                            .map(|value| &value.stack_status)
+                            .peekable();
+                        values.peek().is_some() && values.all(|value| value == "UPDATE_COMPLETE")
+                    }
+                    Err(_) => false,
+                } {
+                    return Some(rusoto_core::waiter::WaiterState::Success);
+                }
+                if match result {
+                    Ok(output) => {
+                        let mut values = std::iter::once(output)
+                            .filter_map(|value| value.stacks.as_ref())
+                            .flat_map(|value| value.iter())
+                            .map(|value| &value.stack_status)
+                            .peekable();
+                        values.peek().is_some() && values.all(|value| value == "UPDATE_IN_PROGRESS")
+                    }
+                    Err(_) => false,
+                } {
+                    return Some(rusoto_core::waiter::WaiterState::Success);
+                }
+                if match result {
+                    Ok(output) => {
+                        let mut values = std::iter::once(output)
+                            .filter_map(|value| value.stacks.as_ref())
+                            .flat_map(|value| value.iter())
+                            .map(|value| &value.stack_status)
+                            .peekable();
+                        values.peek().is_some()
+                            && values.all(|value| value == "UPDATE_COMPLETE_CLEANUP_IN_PROGRESS")
+                    }
+                    Err(_) => false,
+                } {
+                    return Some(rusoto_core::waiter::WaiterState::Success);
+                }
+                if match result {
+                    Ok(output) => {
+                        let mut values = std::iter::once(output)
+                            .filter_map(|value| value.stacks.as_ref())
+                            .flat_map(|value| value.iter())
+                            .map(|value| &value.stack_status)
+                            .peekable();
+                        values.peek().is_some() && values.all(|value| value == "UPDATE_FAILED")
+                    }
+                    Err(_) => false,
+                } {
+                    return Some(rusoto_core::waiter::WaiterState::Success);
+                }
+                if match result {
+                    Ok(output) => {
+                        let mut values = std::iter::once(output)
+                            .filter_map(|value| value.stacks.as_ref())
+                            .flat_map(|value| value.iter())
+                            .map(|value| &value.stack_status)
+                            .peekable();
+                        values.peek().is_some()
+                            && values.all(|value| value == "UPDATE_ROLLBACK_IN_PROGRESS")
+                    }
+                    Err(_) => false,
+                } {
+                    return Some(rusoto_core::waiter::WaiterState::Success);
+                }
+                if match result {
+                    Ok(output) => {
+                        let mut values = std::iter::once(output)
+                            .filter_map(|value| value.stacks.as_ref())
+                            .flat_map(|value| value.iter())
+                            .map(|value| &value.stack_status)
+                            .peekable();
+                        values.peek().is_some()
+                            && values.all(|value| value == "UPDATE_ROLLBACK_FAILED")
+                    }
+                    Err(_) => false,
+                } {
+                    return Some(rusoto_core::waiter::WaiterState::Success);
+                }
+                if match result {
+                    Ok(output) => {
+                        let mut values = std::iter::once(output)
+                            .filter_map(|value| value.stacks.as_ref())
+                            .flat_map(|value| value.iter())
+                            .map(|value| &value.stack_status)
+                            .peekable();
+                        values.peek().is_some()
+                            && values.all(|value| {
+                                value == "UPDATE_ROLLBACK_COMPLETE_CLEANUP_IN_PROGRESS"
+                            })
+                    }
+                    Err(_) => false,
+                } {
+                    return Some(rusoto_core::waiter::WaiterState::Success);
+                }
+                if match result {
+                    Ok(output) => {
+                        let mut values = std::iter::once(output)
+                            .filter_map(|value| value.stacks.as_ref())
+                            .flat_map(|value| value.iter())
+                            .map(|value| &value.stack_status)
+                            .peekable();
+                        values.peek().is_some()
+                            && values.all(|value| value == "UPDATE_ROLLBACK_COMPLETE")
+                    }
+                    Err(_) => false,
+                } {
+                    return Some(rusoto_core::waiter::WaiterState::Success);
+                }
+                if match result {
+                    Ok(output) => std::iter::once(output)
+                        .filter_map(|value| value.stacks.as_ref())
+                        .flat_map(|value| value.iter())
+                        .map(|value| &value.stack_status)
+                        .any(|value| value == "CREATE_FAILED"),
+                    Err(_) => false,
+                } {
+                    return Some(rusoto_core::waiter::WaiterState::Failure);
+                }
+                if match result {
+                    Ok(output) => std::iter::once(output)
+                        .filter_map(|value| value.stacks.as_ref())
+                        .flat_map(|value| value.iter())
+                        .map(|value| &value.stack_status)
+                        .any(|value| value == "DELETE_COMPLETE"),
+                    Err(_) => false,
+                } {
+                    return Some(rusoto_core::waiter::WaiterState::Failure);
+                }
+                if match result {
+                    Ok(output) => std::iter::once(output)
+                        .filter_map(|value| value.stacks.as_ref())
+                        .flat_map(|value| value.iter())
+                        .map(|value| &value.stack_status)
+                        .any(|value| value == "DELETE_FAILED"),
+                    Err(_) => false,
+                } {
+                    return Some(rusoto_core::waiter::WaiterState::Failure);
+                }
+                if match result {
+                    Ok(output) => std::iter::once(output)
+                        .filter_map(|value| value.stacks.as_ref())
+                        .flat_map(|value| value.iter())
+                        .map(|value| &value.stack_status)
+                        .any(|value| value == "ROLLBACK_FAILED"),
+                    Err(_) => false,
+                } {
+                    return Some(rusoto_core::waiter::WaiterState::Failure);
+                }
+                if match result {
+                    Ok(output) => std::iter::once(output)
+                        .filter_map(|value| value.stacks.as_ref())
+                        .flat_map(|value| value.iter())
+                        .map(|value| &value.stack_status)
+                        .any(|value| value == "ROLLBACK_COMPLETE"),
+                    Err(_) => false,
+                } {
+                    return Some(rusoto_core::waiter::WaiterState::Failure);
+                }
+                if result
+                    .as_ref()
+                    .err()
+                    .and_then(|error| error.error_code())
+                    .as_deref()
+                    == Some("ValidationError")
+                {
+                    return Some(rusoto_core::waiter::WaiterState::Failure);
+                }
+                None
+            },
+        )
+        .await
+    }
+
+    /// Waits for `StackDeleteComplete`, polling `DescribeStacks` with `input` every 30 seconds, up to 120 times.
+    pub async fn wait_until_stack_delete_complete(
+        &self,
+        input: DescribeStacksInput,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<DescribeStacksError>> {
+        let waiter = rusoto_core::waiter::Waiter::new(std::time::Duration::from_secs(30), 120);
+        self.wait_until_stack_delete_complete_with(input, &waiter)
+            .await
+    }
+
+    /// Waits for `StackDeleteComplete` like `wait_until_stack_delete_complete`, with the delay, attempts and runtime of `waiter`.
+    pub async fn wait_until_stack_delete_complete_with(
+        &self,
+        input: DescribeStacksInput,
+        waiter: &rusoto_core::waiter::Waiter,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<DescribeStacksError>> {
+        rusoto_core::waiter::wait(
+            waiter,
+            || self.describe_stacks(input.clone()),
+            |result| {
+                if match result {
+                    Ok(output) => {
+                        let mut values = std::iter::once(output)
+                            .filter_map(|value| value.stacks.as_ref())
+                            .flat_map(|value| value.iter())
+                            .map(|value| &value.stack_status)
+                            .peekable();
+                        values.peek().is_some() && values.all(|value| value == "DELETE_COMPLETE")
+                    }
+                    Err(_) => false,
+                } {
+                    return Some(rusoto_core::waiter::WaiterState::Success);
+                }
+                if result
+                    .as_ref()
+                    .err()
+                    .and_then(|error| error.error_code())
+                    .as_deref()
+                    == Some("ValidationError")
+                {
+                    return Some(rusoto_core::waiter::WaiterState::Success);
+                }
+                if match result {
+                    Ok(output) => std::iter::once(output)
+                        .filter_map(|value| value.stacks.as_ref())
+                        .flat_map(|value| value.iter())
+                        .map(|value| &value.stack_status)
+                        .any(|value| value == "DELETE_FAILED"),
+                    Err(_) => false,
+                } {
+                    return Some(rusoto_core::waiter::WaiterState::Failure);
+                }
+                if match result {
+                    Ok(output) => std::iter::once(output)
+                        .filter_map(|value| value.stacks.as_ref())
+                        .flat_map(|value| value.iter())
+                        .map(|value| &value.stack_status)
+                        .any(|value| value == "CREATE_FAILED"),
+                    Err(_) => false,
+                } {
+                    return Some(rusoto_core::waiter::WaiterState::Failure);
+                }
+                if match result {
+                    Ok(output) => std::iter::once(output)
+                        .filter_map(|value| value.stacks.as_ref())
+                        .flat_map(|value| value.iter())
+                        .map(|value| &value.stack_status)
+                        .any(|value| value == "ROLLBACK_FAILED"),
+                    Err(_) => false,
+                } {
+                    return Some(rusoto_core::waiter::WaiterState::Failure);
+                }
+                if match result {
+                    Ok(output) => std::iter::once(output)
+                        .filter_map(|value| value.stacks.as_ref())
+                        .flat_map(|value| value.iter())
+                        .map(|value| &value.stack_status)
+                        .any(|value| value == "UPDATE_ROLLBACK_IN_PROGRESS"),
+                    Err(_) => false,
+                } {
+                    return Some(rusoto_core::waiter::WaiterState::Failure);
+                }
+                if match result {
+                    Ok(output) => std::iter::once(output)
+                        .filter_map(|value| value.stacks.as_ref())
+                        .flat_map(|value| value.iter())
+                        .map(|value| &value.stack_status)
+                        .any(|value| value == "UPDATE_ROLLBACK_FAILED"),
+                    Err(_) => false,
+                } {
+                    return Some(rusoto_core::waiter::WaiterState::Failure);
+                }
+                if match result {
+                    Ok(output) => std::iter::once(output)
+                        .filter_map(|value| value.stacks.as_ref())
+                        .flat_map(|value| value.iter())
+                        .map(|value| &value.stack_status)
+                        .any(|value| value == "UPDATE_ROLLBACK_COMPLETE"),
+                    Err(_) => false,
+                } {
+                    return Some(rusoto_core::waiter::WaiterState::Failure);
+                }
+                if match result {
+                    Ok(output) => std::iter::once(output)
+                        .filter_map(|value| value.stacks.as_ref())
+                        .flat_map(|value| value.iter())
+                        .map(|value| &value.stack_status)
+                        .any(|value| value == "UPDATE_COMPLETE"),
+                    Err(_) => false,
+                } {
+                    return Some(rusoto_core::waiter::WaiterState::Failure);
+                }
+                None
+            },
+        )
+        .await
+    }
+
+    /// Waits for `StackExists`, polling `DescribeStacks` with `input` every 5 seconds, up to 20 times.
+    pub async fn wait_until_stack_exists(
+        &self,
+        input: DescribeStacksInput,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<DescribeStacksError>> {
+        let waiter = rusoto_core::waiter::Waiter::new(std::time::Duration::from_secs(5), 20);
+        self.wait_until_stack_exists_with(input, &waiter).await
+    }
+
+    /// Waits for `StackExists` like `wait_until_stack_exists`, with the delay, attempts and runtime of `waiter`.
+    pub async fn wait_until_stack_exists_with(
+        &self,
+        input: DescribeStacksInput,
+        waiter: &rusoto_core::waiter::Waiter,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<DescribeStacksError>> {
+        rusoto_core::waiter::wait(
+            waiter,
+            || self.describe_stacks(input.clone()),
+            |result| {
+                if rusoto_core::waiter::status(result) == Some(200) {
+                    return Some(rusoto_core::waiter::WaiterState::Success);
+                }
+                if result
+                    .as_ref()
+                    .err()
+                    .and_then(|error| error.error_code())
+                    .as_deref()
+                    == Some("ValidationError")
+                {
+                    return Some(rusoto_core::waiter::WaiterState::Retry);
+                }
+                None
+            },
+        )
+        .await
+    }
+
+    /// Waits for `StackImportComplete`, polling `DescribeStacks` with `input` every 30 seconds, up to 120 times.
+    pub async fn wait_until_stack_import_complete(
+        &self,
+        input: DescribeStacksInput,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<DescribeStacksError>> {
+        let waiter = rusoto_core::waiter::Waiter::new(std::time::Duration::from_secs(30), 120);
+        self.wait_until_stack_import_complete_with(input, &waiter)
+            .await
+    }
+
+    /// Waits for `StackImportComplete` like `wait_until_stack_import_complete`, with the delay, attempts and runtime of `waiter`.
+    pub async fn wait_until_stack_import_complete_with(
+        &self,
+        input: DescribeStacksInput,
+        waiter: &rusoto_core::waiter::Waiter,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<DescribeStacksError>> {
+        rusoto_core::waiter::wait(
+            waiter,
+            || self.describe_stacks(input.clone()),
+            |result| {
+                if match result {
+                    Ok(output) => {
+                        let mut values = std::iter::once(output)
+                            .filter_map(|value| value.stacks.as_ref())
+                            .flat_map(|value| value.iter())
+                            .map(|value| &value.stack_status)
+                            .peekable();
+                        values.peek().is_some() && values.all(|value| value == "IMPORT_COMPLETE")
+                    }
+                    Err(_) => false,
+                } {
+                    return Some(rusoto_core::waiter::WaiterState::Success);
+                }
+                if match result {
+                    Ok(output) => std::iter::once(output)
+                        .filter_map(|value| value.stacks.as_ref())
+                        .flat_map(|value| value.iter())
+                        .map(|value| &value.stack_status)
+                        .any(|value| value == "ROLLBACK_COMPLETE"),
+                    Err(_) => false,
+                } {
+                    return Some(rusoto_core::waiter::WaiterState::Failure);
+                }
+                if match result {
+                    Ok(output) => std::iter::once(output)
+                        .filter_map(|value| value.stacks.as_ref())
+                        .flat_map(|value| value.iter())
+                        .map(|value| &value.stack_status)
+                        .any(|value| value == "ROLLBACK_FAILED"),
+                    Err(_) => false,
+                } {
+                    return Some(rusoto_core::waiter::WaiterState::Failure);
+                }
+                if match result {
+                    Ok(output) => std::iter::once(output)
+                        .filter_map(|value| value.stacks.as_ref())
+                        .flat_map(|value| value.iter())
+                        .map(|value| &value.stack_status)
+                        .any(|value| value == "IMPORT_ROLLBACK_IN_PROGRESS"),
+                    Err(_) => false,
+                } {
+                    return Some(rusoto_core::waiter::WaiterState::Failure);
+                }
+                if match result {
+                    Ok(output) => std::iter::once(output)
+                        .filter_map(|value| value.stacks.as_ref())
+                        .flat_map(|value| value.iter())
+                        .map(|value| &value.stack_status)
+                        .any(|value| value == "IMPORT_ROLLBACK_FAILED"),
+                    Err(_) => false,
+                } {
+                    return Some(rusoto_core::waiter::WaiterState::Failure);
+                }
+                if match result {
+                    Ok(output) => std::iter::once(output)
+                        .filter_map(|value| value.stacks.as_ref())
+                        .flat_map(|value| value.iter())
+                        .map(|value| &value.stack_status)
+                        .any(|value| value == "IMPORT_ROLLBACK_COMPLETE"),
+                    Err(_) => false,
+                } {
+                    return Some(rusoto_core::waiter::WaiterState::Failure);
+                }
+                if result
+                    .as_ref()
+                    .err()
+                    .and_then(|error| error.error_code())
+                    .as_deref()
+                    == Some("ValidationError")
+                {
+                    return Some(rusoto_core::waiter::WaiterState::Failure);
+                }
+                None
+            },
+        )
+        .await
+    }
+
+    /// Waits for `StackRollbackComplete`, polling `DescribeStacks` with `input` every 30 seconds, up to 120 times.
+    pub async fn wait_until_stack_rollback_complete(
+        &self,
+        input: DescribeStacksInput,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<DescribeStacksError>> {
+        let waiter = rusoto_core::waiter::Waiter::new(std::time::Duration::from_secs(30), 120);
+        self.wait_until_stack_rollback_complete_with(input, &waiter)
+            .await
+    }
+
+    /// Waits for `StackRollbackComplete` like `wait_until_stack_rollback_complete`, with the delay, attempts and runtime of `waiter`.
+    pub async fn wait_until_stack_rollback_complete_with(
+        &self,
+        input: DescribeStacksInput,
+        waiter: &rusoto_core::waiter::Waiter,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<DescribeStacksError>> {
+        rusoto_core::waiter::wait(
+            waiter,
+            || self.describe_stacks(input.clone()),
+            |result| {
+                if match result {
+                    Ok(output) => {
+                        let mut values = std::iter::once(output)
+                            .filter_map(|value| value.stacks.as_ref())
+                            .flat_map(|value| value.iter())
+                            .map(|value| &value.stack_status)
+                            .peekable();
+                        values.peek().is_some()
+                            && values.all(|value| value == "UPDATE_ROLLBACK_COMPLETE")
+                    }
+                    Err(_) => false,
+                } {
+                    return Some(rusoto_core::waiter::WaiterState::Success);
+                }
+                if match result {
+                    Ok(output) => std::iter::once(output)
+                        .filter_map(|value| value.stacks.as_ref())
+                        .flat_map(|value| value.iter())
+                        .map(|value| &value.stack_status)
+                        .any(|value| value == "UPDATE_FAILED"),
+                    Err(_) => false,
+                } {
+                    return Some(rusoto_core::waiter::WaiterState::Failure);
+                }
+                if match result {
+                    Ok(output) => std::iter::once(output)
+                        .filter_map(|value| value.stacks.as_ref())
+                        .flat_map(|value| value.iter())
+                        .map(|value| &value.stack_status)
+                        .any(|value| value == "UPDATE_ROLLBACK_FAILED"),
+                    Err(_) => false,
+                } {
+                    return Some(rusoto_core::waiter::WaiterState::Failure);
+                }
+                if match result {
+                    Ok(output) => std::iter::once(output)
+                        .filter_map(|value| value.stacks.as_ref())
+                        .flat_map(|value| value.iter())
+                        .map(|value| &value.stack_status)
+                        .any(|value| value == "DELETE_FAILED"),
+                    Err(_) => false,
+                } {
+                    return Some(rusoto_core::waiter::WaiterState::Failure);
+                }
+                if result
+                    .as_ref()
+                    .err()
+                    .and_then(|error| error.error_code())
+                    .as_deref()
+                    == Some("ValidationError")
+                {
+                    return Some(rusoto_core::waiter::WaiterState::Failure);
+                }
+                None
+            },
+        )
+        .await
+    }
+
+    /// Waits for `StackUpdateComplete`, polling `DescribeStacks` with `input` every 30 seconds, up to 120 times.
+    pub async fn wait_until_stack_update_complete(
+        &self,
+        input: DescribeStacksInput,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<DescribeStacksError>> {
+        let waiter = rusoto_core::waiter::Waiter::new(std::time::Duration::from_secs(30), 120);
+        self.wait_until_stack_update_complete_with(input, &waiter)
+            .await
+    }
+
+    /// Waits for `StackUpdateComplete` like `wait_until_stack_update_complete`, with the delay, attempts and runtime of `waiter`.
+    pub async fn wait_until_stack_update_complete_with(
+        &self,
+        input: DescribeStacksInput,
+        waiter: &rusoto_core::waiter::Waiter,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<DescribeStacksError>> {
+        rusoto_core::waiter::wait(
+            waiter,
+            || self.describe_stacks(input.clone()),
+            |result| {
+                if match result {
+                    Ok(output) => {
+                        let mut values = std::iter::once(output)
+                            .filter_map(|value| value.stacks.as_ref())
+                            .flat_map(|value| value.iter())
+                            .map(|value| &value.stack_status)
+                            .peekable();
+                        values.peek().is_some() && values.all(|value| value == "UPDATE_COMPLETE")
+                    }
+                    Err(_) => false,
+                } {
+                    return Some(rusoto_core::waiter::WaiterState::Success);
+                }
+                if match result {
+                    Ok(output) => std::iter::once(output)
+                        .filter_map(|value| value.stacks.as_ref())
+                        .flat_map(|value| value.iter())
+                        .map(|value| &value.stack_status)
+                        .any(|value| value == "UPDATE_FAILED"),
+                    Err(_) => false,
+                } {
+                    return Some(rusoto_core::waiter::WaiterState::Failure);
+                }
+                if match result {
+                    Ok(output) => std::iter::once(output)
+                        .filter_map(|value| value.stacks.as_ref())
+                        .flat_map(|value| value.iter())
+                        .map(|value| &value.stack_status)
+                        .any(|value| value == "UPDATE_ROLLBACK_FAILED"),
+                    Err(_) => false,
+                } {
+                    return Some(rusoto_core::waiter::WaiterState::Failure);
+                }
+                if match result {
+                    Ok(output) => std::iter::once(output)
+                        .filter_map(|value| value.stacks.as_ref())
+                        .flat_map(|value| value.iter())
+                        .map(|value| &value.stack_status)
+                        .any(|value| value == "UPDATE_ROLLBACK_COMPLETE"),
+                    Err(_) => false,
+                } {
+                    return Some(rusoto_core::waiter::WaiterState::Failure);
+                }
+                if result
+                    .as_ref()
+                    .err()
+                    .and_then(|error| error.error_code())
+                    .as_deref()
+                    == Some("ValidationError")
+                {
+                    return Some(rusoto_core::waiter::WaiterState::Failure);
+                }
+                None
+            },
+        )
+        .await
+    }
+
+    /// Waits for `TypeRegistrationComplete`, polling `DescribeTypeRegistration` with `input` every 30 seconds, up to 120 times.
+    pub async fn wait_until_type_registration_complete(
+        &self,
+        input: DescribeTypeRegistrationInput,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<DescribeTypeRegistrationError>> {
+        let waiter = rusoto_core::waiter::Waiter::new(std::time::Duration::from_secs(30), 120);
+        self.wait_until_type_registration_complete_with(input, &waiter)
+            .await
+    }
+
+    /// Waits for `TypeRegistrationComplete` like `wait_until_type_registration_complete`, with the delay, attempts and runtime of `waiter`.
+    pub async fn wait_until_type_registration_complete_with(
+        &self,
+        input: DescribeTypeRegistrationInput,
+        waiter: &rusoto_core::waiter::Waiter,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<DescribeTypeRegistrationError>> {
+        rusoto_core::waiter::wait(
+            waiter,
+            || self.describe_type_registration(input.clone()),
+            |result| {
+                if match result {
+                    Ok(output) => std::iter::once(output)
+                        .filter_map(|value| value.progress_status.as_ref())
+                        .any(|value| value == "COMPLETE"),
+                    Err(_) => false,
+                } {
+                    return Some(rusoto_core::waiter::WaiterState::Success);
+                }
+                if match result {
+                    Ok(output) => std::iter::once(output)
+                        .filter_map(|value| value.progress_status.as_ref())
+                        .any(|value| value == "FAILED"),
+                    Err(_) => false,
+                } {
+                    return Some(rusoto_core::waiter::WaiterState::Failure);
+                }
+                None
+            },
+        )
+        .await
+    }
+}
+
+#[async_trait]
+impl CloudFormation for CloudFormationClient {
+    /// <p><p>Cancels an update on the specified stack. If the call completes successfully, the stack rolls back the update and reverts to the previous stack configuration.</p> <note> <p>You can cancel only stacks that are in the UPDATE<em>IN</em>PROGRESS state.</p> </note></p>
+    async fn cancel_update_stack(
+        &self,
+        input: CancelUpdateStackInput,
+    ) -> Result<(), RusotoError<CancelUpdateStackError>> {
+        self.cancel_update_stack_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>For a specified stack that is in the <code>UPDATE_ROLLBACK_FAILED</code> state, continues rolling it back to the <code>UPDATE_ROLLBACK_COMPLETE</code> state. Depending on the cause of the failure, you can manually <a href="https://docs.aws.amazon.com/AWSCloudFormation/latest/UserGuide/troubleshooting.html#troubleshooting-errors-update-rollback-failed"> fix the error</a> and continue the rollback. By continuing the rollback, you can return your stack to a working state (the <code>UPDATE_ROLLBACK_COMPLETE</code> state), and then try to update the stack again.</p> <p>A stack goes into the <code>UPDATE_ROLLBACK_FAILED</code> state when AWS CloudFormation cannot roll back all changes after a failed stack update. For example, you might have a stack that is rolling back to an old database instance that was deleted outside of AWS CloudFormation. Because AWS CloudFormation doesn't know the database was deleted, it assumes that the database instance still exists and attempts to roll back to it, causing the update rollback to fail.</p>
+    async fn continue_update_rollback(
+        &self,
+        input: ContinueUpdateRollbackInput,
+    ) -> Result<ContinueUpdateRollbackOutput, RusotoError<ContinueUpdateRollbackError>> {
+        self.continue_update_rollback_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Creates a list of changes that will be applied to a stack so that you can review the changes before executing them. You can create a change set for a stack that doesn't exist or an existing stack. If you create a change set for a stack that doesn't exist, the change set shows all of the resources that AWS CloudFormation will create. If you create a change set for an existing stack, AWS CloudFormation compares the stack's information with the information that you submit in the change set and lists the differences. Use change sets to understand which resources AWS CloudFormation will create or change, and how it will change resources in an existing stack, before you create or update a stack.</p> <p>To create a change set for a stack that doesn't exist, for the <code>ChangeSetType</code> parameter, specify <code>CREATE</code>. To create a change set for an existing stack, specify <code>UPDATE</code> for the <code>ChangeSetType</code> parameter. To create a change set for an import operation, specify <code>IMPORT</code> for the <code>ChangeSetType</code> parameter. After the <code>CreateChangeSet</code> call successfully completes, AWS CloudFormation starts creating the change set. To check the status of the change set or to review it, use the <a>DescribeChangeSet</a> action.</p> <p>When you are satisfied with the changes the change set will make, execute the change set by using the <a>ExecuteChangeSet</a> action. AWS CloudFormation doesn't make changes until you execute the change set.</p>
+    async fn create_change_set(
+        &self,
+        input: CreateChangeSetInput,
+    ) -> Result<CreateChangeSetOutput, RusotoError<CreateChangeSetError>> {
+        self.create_change_set_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Creates a stack as specified in the template. After the call completes successfully, the stack creation starts. You can check the status of the stack via the <a>DescribeStacks</a> API.</p>
+    async fn create_stack(
+        &self,
+        input: CreateStackInput,
+    ) -> Result<CreateStackOutput, RusotoError<CreateStackError>> {
+        self.create_stack_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Creates stack instances for the specified accounts, within the specified regions. A stack instance refers to a stack in a specific account and region. <code>Accounts</code> and <code>Regions</code> are required parameters—you must specify at least one account and one region. </p>
+    async fn create_stack_instances(
+        &self,
+        input: CreateStackInstancesInput,
+    ) -> Result<CreateStackInstancesOutput, RusotoError<CreateStackInstancesError>> {
+        self.create_stack_instances_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Creates a stack set.</p>
+    async fn create_stack_set(
+        &self,
+        input: CreateStackSetInput,
+    ) -> Result<CreateStackSetOutput, RusotoError<CreateStackSetError>> {
+        self.create_stack_set_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes the specified change set. Deleting change sets ensures that no one executes the wrong change set.</p> <p>If the call successfully completes, AWS CloudFormation successfully deleted the change set.</p>
+    async fn delete_change_set(
+        &self,
+        input: DeleteChangeSetInput,
+    ) -> Result<DeleteChangeSetOutput, RusotoError<DeleteChangeSetError>> {
+        self.delete_change_set_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes a specified stack. Once the call completes successfully, stack deletion starts. Deleted stacks do not show up in the <a>DescribeStacks</a> API if the deletion has been completed successfully.</p>
+    async fn delete_stack(
+        &self,
+        input: DeleteStackInput,
+    ) -> Result<(), RusotoError<DeleteStackError>> {
+        self.delete_stack_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes stack instances for the specified accounts, in the specified regions. </p>
+    async fn delete_stack_instances(
+        &self,
+        input: DeleteStackInstancesInput,
+    ) -> Result<DeleteStackInstancesOutput, RusotoError<DeleteStackInstancesError>> {
+        self.delete_stack_instances_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes a stack set. Before you can delete a stack set, all of its member stack instances must be deleted. For more information about how to do this, see <a>DeleteStackInstances</a>. </p>
+    async fn delete_stack_set(
+        &self,
+        input: DeleteStackSetInput,
+    ) -> Result<DeleteStackSetOutput, RusotoError<DeleteStackSetError>> {
+        self.delete_stack_set_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Removes a type or type version from active use in the CloudFormation registry. If a type or type version is deregistered, it cannot be used in CloudFormation operations.</p> <p>To deregister a type, you must individually deregister all registered versions of that type. If a type has only a single registered version, deregistering that version results in the type itself being deregistered. </p> <p>You cannot deregister the default version of a type, unless it is the only registered version of that type, in which case the type itself is deregistered as well. </p>
+    async fn deregister_type(
+        &self,
+        input: DeregisterTypeInput,
+    ) -> Result<DeregisterTypeOutput, RusotoError<DeregisterTypeError>> {
+        self.deregister_type_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Retrieves your account's AWS CloudFormation limits, such as the maximum number of stacks that you can create in your account. For more information about account limits, see <a href="https://docs.aws.amazon.com/AWSCloudFormation/latest/UserGuide/cloudformation-limits.html">AWS CloudFormation Limits</a> in the <i>AWS CloudFormation User Guide</i>.</p>
+    async fn describe_account_limits(
+        &self,
+        input: DescribeAccountLimitsInput,
+    ) -> Result<DescribeAccountLimitsOutput, RusotoError<DescribeAccountLimitsError>> {
+        self.describe_account_limits_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns the inputs for the change set and a list of changes that AWS CloudFormation will make if you execute the change set. For more information, see <a href="https://docs.aws.amazon.com/AWSCloudFormation/latest/UserGuide/using-cfn-updating-stacks-changesets.html">Updating Stacks Using Change Sets</a> in the AWS CloudFormation User Guide.</p>
+    async fn describe_change_set(
+        &self,
+        input: DescribeChangeSetInput,
+    ) -> Result<DescribeChangeSetOutput, RusotoError<DescribeChangeSetError>> {
+        self.describe_change_set_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns information about a stack drift detection operation. A stack drift detection operation detects whether a stack's actual configuration differs, or has <i>drifted</i>, from it's expected configuration, as defined in the stack template and any values specified as template parameters. A stack is considered to have drifted if one or more of its resources have drifted. For more information on stack and resource drift, see <a href="https://docs.aws.amazon.com/AWSCloudFormation/latest/UserGuide/using-cfn-stack-drift.html">Detecting Unregulated Configuration Changes to Stacks and Resources</a>.</p> <p>Use <a>DetectStackDrift</a> to initiate a stack drift detection operation. <code>DetectStackDrift</code> returns a <code>StackDriftDetectionId</code> you can use to monitor the progress of the operation using <code>DescribeStackDriftDetectionStatus</code>. Once the drift detection operation has completed, use <a>DescribeStackResourceDrifts</a> to return drift information about the stack and its resources.</p>
+    async fn describe_stack_drift_detection_status(
+        &self,
+        input: DescribeStackDriftDetectionStatusInput,
+    ) -> Result<
+        DescribeStackDriftDetectionStatusOutput,
+        RusotoError<DescribeStackDriftDetectionStatusError>,
+    > {
+        self.describe_stack_drift_detection_status_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Returns all stack related events for a specified stack in reverse chronological order. For more information about a stack&#39;s event history, go to <a href="https://docs.aws.amazon.com/AWSCloudFormation/latest/UserGuide/concept-stack.html">Stacks</a> in the AWS CloudFormation User Guide.</p> <note> <p>You can list events for stacks that have failed to create or have been deleted by specifying the unique stack identifier (stack ID).</p> </note></p>
+    async fn describe_stack_events(
+        &self,
+        input: DescribeStackEventsInput,
+    ) -> Result<DescribeStackEventsOutput, RusotoError<DescribeStackEventsError>> {
+        self.describe_stack_events_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns the stack instance that's associated with the specified stack set, AWS account, and region.</p> <p>For a list of stack instances that are associated with a specific stack set, use <a>ListStackInstances</a>.</p>
+    async fn describe_stack_instance(
+        &self,
+        input: DescribeStackInstanceInput,
+    ) -> Result<DescribeStackInstanceOutput, RusotoError<DescribeStackInstanceError>> {
+        self.describe_stack_instance_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns a description of the specified resource in the specified stack.</p> <p>For deleted stacks, DescribeStackResource returns resource information for up to 90 days after the stack has been deleted.</p>
+    async fn describe_stack_resource(
+        &self,
+        input: DescribeStackResourceInput,
+    ) -> Result<DescribeStackResourceOutput, RusotoError<DescribeStackResourceError>> {
+        self.describe_stack_resource_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns drift information for the resources that have been checked for drift in the specified stack. This includes actual and expected configuration values for resources where AWS CloudFormation detects configuration drift.</p> <p>For a given stack, there will be one <code>StackResourceDrift</code> for each stack resource that has been checked for drift. Resources that have not yet been checked for drift are not included. Resources that do not currently support drift detection are not checked, and so not included. For a list of resources that support drift detection, see <a href="https://docs.aws.amazon.com/AWSCloudFormation/latest/UserGuide/using-cfn-stack-drift-resource-list.html">Resources that Support Drift Detection</a>.</p> <p>Use <a>DetectStackResourceDrift</a> to detect drift on individual resources, or <a>DetectStackDrift</a> to detect drift on all supported resources for a given stack.</p>
+    async fn describe_stack_resource_drifts(
+        &self,
+        input: DescribeStackResourceDriftsInput,
+    ) -> Result<DescribeStackResourceDriftsOutput, RusotoError<DescribeStackResourceDriftsError>>
+    {
+        self.describe_stack_resource_drifts_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Returns AWS resource descriptions for running and deleted stacks. If <code>StackName</code> is specified, all the associated resources that are part of the stack are returned. If <code>PhysicalResourceId</code> is specified, the associated resources of the stack that the resource belongs to are returned.</p> <note> <p>Only the first 100 resources will be returned. If your stack has more resources than this, you should use <code>ListStackResources</code> instead.</p> </note> <p>For deleted stacks, <code>DescribeStackResources</code> returns resource information for up to 90 days after the stack has been deleted.</p> <p>You must specify either <code>StackName</code> or <code>PhysicalResourceId</code>, but not both. In addition, you can specify <code>LogicalResourceId</code> to filter the returned result. For more information about resources, the <code>LogicalResourceId</code> and <code>PhysicalResourceId</code>, go to the <a href="https://docs.aws.amazon.com/AWSCloudFormation/latest/UserGuide/">AWS CloudFormation User Guide</a>.</p> <note> <p>A <code>ValidationError</code> is returned if you specify both <code>StackName</code> and <code>PhysicalResourceId</code> in the same request.</p> </note></p>
+    async fn describe_stack_resources(
+        &self,
+        input: DescribeStackResourcesInput,
+    ) -> Result<DescribeStackResourcesOutput, RusotoError<DescribeStackResourcesError>> {
+        self.describe_stack_resources_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns the description of the specified stack set. </p>
+    async fn describe_stack_set(
+        &self,
+        input: DescribeStackSetInput,
+    ) -> Result<DescribeStackSetOutput, RusotoError<DescribeStackSetError>> {
+        self.describe_stack_set_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns the description of the specified stack set operation. </p>
+    async fn describe_stack_set_operation(
+        &self,
+        input: DescribeStackSetOperationInput,
+    ) -> Result<DescribeStackSetOperationOutput, RusotoError<DescribeStackSetOperationError>> {
+        self.describe_stack_set_operation_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Returns the description for the specified stack; if no stack name was specified, then it returns the description for all the stacks created.</p> <note> <p>If the stack does not exist, an <code>AmazonCloudFormationException</code> is returned.</p> </note></p>
+    async fn describe_stacks(
+        &self,
+        input: DescribeStacksInput,
+    ) -> Result<DescribeStacksOutput, RusotoError<DescribeStacksError>> {
+        self.describe_stacks_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns detailed information about a type that has been registered.</p> <p>If you specify a <code>VersionId</code>, <code>DescribeType</code> returns information about that specific type version. Otherwise, it returns information about the default type version.</p>
+    async fn describe_type(
+        &self,
+        input: DescribeTypeInput,
+    ) -> Result<DescribeTypeOutput, RusotoError<DescribeTypeError>> {
+        self.describe_type_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns information about a type's registration, including its current status and type and version identifiers.</p> <p>When you initiate a registration request using <code> <a>RegisterType</a> </code>, you can then use <code> <a>DescribeTypeRegistration</a> </code> to monitor the progress of that registration request.</p> <p>Once the registration request has completed, use <code> <a>DescribeType</a> </code> to return detailed informaiton about a type.</p>
+    async fn describe_type_registration(
+        &self,
+        input: DescribeTypeRegistrationInput,
+    ) -> Result<DescribeTypeRegistrationOutput, RusotoError<DescribeTypeRegistrationError>> {
+        self.describe_type_registration_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Detects whether a stack's actual configuration differs, or has <i>drifted</i>, from it's expected configuration, as defined in the stack template and any values specified as template parameters. For each resource in the stack that supports drift detection, AWS CloudFormation compares the actual configuration of the resource with its expected template configuration. Only resource properties explicitly defined in the stack template are checked for drift. A stack is considered to have drifted if one or more of its resources differ from their expected template configurations. For more information, see <a href="https://docs.aws.amazon.com/AWSCloudFormation/latest/UserGuide/using-cfn-stack-drift.html">Detecting Unregulated Configuration Changes to Stacks and Resources</a>.</p> <p>Use <code>DetectStackDrift</code> to detect drift on all supported resources for a given stack, or <a>DetectStackResourceDrift</a> to detect drift on individual resources.</p> <p>For a list of stack resources that currently support drift detection, see <a href="https://docs.aws.amazon.com/AWSCloudFormation/latest/UserGuide/using-cfn-stack-drift-resource-list.html">Resources that Support Drift Detection</a>.</p> <p> <code>DetectStackDrift</code> can take up to several minutes, depending on the number of resources contained within the stack. Use <a>DescribeStackDriftDetectionStatus</a> to monitor the progress of a detect stack drift operation. Once the drift detection operation has completed, use <a>DescribeStackResourceDrifts</a> to return drift information about the stack and its resources.</p> <p>When detecting drift on a stack, AWS CloudFormation does not detect drift on any nested stacks belonging to that stack. Perform <code>DetectStackDrift</code> directly on the nested stack itself.</p>
+    async fn detect_stack_drift(
+        &self,
+        input: DetectStackDriftInput,
+    ) -> Result<DetectStackDriftOutput, RusotoError<DetectStackDriftError>> {
+        self.detect_stack_drift_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns information about whether a resource's actual configuration differs, or has <i>drifted</i>, from it's expected configuration, as defined in the stack template and any values specified as template parameters. This information includes actual and expected property values for resources in which AWS CloudFormation detects drift. Only resource properties explicitly defined in the stack template are checked for drift. For more information about stack and resource drift, see <a href="https://docs.aws.amazon.com/AWSCloudFormation/latest/UserGuide/using-cfn-stack-drift.html">Detecting Unregulated Configuration Changes to Stacks and Resources</a>.</p> <p>Use <code>DetectStackResourceDrift</code> to detect drift on individual resources, or <a>DetectStackDrift</a> to detect drift on all resources in a given stack that support drift detection.</p> <p>Resources that do not currently support drift detection cannot be checked. For a list of resources that support drift detection, see <a href="https://docs.aws.amazon.com/AWSCloudFormation/latest/UserGuide/using-cfn-stack-drift-resource-list.html">Resources that Support Drift Detection</a>.</p>
+    async fn detect_stack_resource_drift(
+        &self,
+        input: DetectStackResourceDriftInput,
+    ) -> Result<DetectStackResourceDriftOutput, RusotoError<DetectStackResourceDriftError>> {
+        self.detect_stack_resource_drift_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Detect drift on a stack set. When CloudFormation performs drift detection on a stack set, it performs drift detection on the stack associated with each stack instance in the stack set. For more information, see <a href="https://docs.aws.amazon.com/AWSCloudFormation/latest/UserGuide/stacksets-drift.html">How CloudFormation Performs Drift Detection on a Stack Set</a>.</p> <p> <code>DetectStackSetDrift</code> returns the <code>OperationId</code> of the stack set drift detection operation. Use this operation id with <code> <a>DescribeStackSetOperation</a> </code> to monitor the progress of the drift detection operation. The drift detection operation may take some time, depending on the number of stack instances included in the stack set, as well as the number of resources included in each stack.</p> <p>Once the operation has completed, use the following actions to return drift information:</p> <ul> <li> <p>Use <code> <a>DescribeStackSet</a> </code> to return detailed informaiton about the stack set, including detailed information about the last <i>completed</i> drift operation performed on the stack set. (Information about drift operations that are in progress is not included.)</p> </li> <li> <p>Use <code> <a>ListStackInstances</a> </code> to return a list of stack instances belonging to the stack set, including the drift status and last drift time checked of each instance.</p> </li> <li> <p>Use <code> <a>DescribeStackInstance</a> </code> to return detailed information about a specific stack instance, including its drift status and last drift time checked.</p> </li> </ul> <p>For more information on performing a drift detection operation on a stack set, see <a href="https://docs.aws.amazon.com/AWSCloudFormation/latest/UserGuide/stacksets-drift.html">Detecting Unmanaged Changes in Stack Sets</a>. </p> <p>You can only run a single drift detection operation on a given stack set at one time. </p> <p>To stop a drift detection stack set operation, use <code> <a>StopStackSetOperation</a> </code>.</p>
+    async fn detect_stack_set_drift(
+        &self,
+        input: DetectStackSetDriftInput,
+    ) -> Result<DetectStackSetDriftOutput, RusotoError<DetectStackSetDriftError>> {
+        self.detect_stack_set_drift_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns the estimated monthly cost of a template. The return value is an AWS Simple Monthly Calculator URL with a query string that describes the resources required to run the template.</p>
+    async fn estimate_template_cost(
+        &self,
+        input: EstimateTemplateCostInput,
+    ) -> Result<EstimateTemplateCostOutput, RusotoError<EstimateTemplateCostError>> {
+        self.estimate_template_cost_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Updates a stack using the input information that was provided when the specified change set was created. After the call successfully completes, AWS CloudFormation starts updating the stack. Use the <a>DescribeStacks</a> action to view the status of the update.</p> <p>When you execute a change set, AWS CloudFormation deletes all other change sets associated with the stack because they aren't valid for the updated stack.</p> <p>If a stack policy is associated with the stack, AWS CloudFormation enforces the policy during the update. You can't specify a temporary stack policy that overrides the current policy.</p>
+    async fn execute_change_set(
+        &self,
+        input: ExecuteChangeSetInput,
+    ) -> Result<ExecuteChangeSetOutput, RusotoError<ExecuteChangeSetError>> {
+        self.execute_change_set_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns the stack policy for a specified stack. If a stack doesn't have a policy, a null value is returned.</p>
+    async fn get_stack_policy(
+        &self,
+        input: GetStackPolicyInput,
+    ) -> Result<GetStackPolicyOutput, RusotoError<GetStackPolicyError>> {
+        self.get_stack_policy_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Returns the template body for a specified stack. You can get the template for running or deleted stacks.</p> <p>For deleted stacks, GetTemplate returns the template for up to 90 days after the stack has been deleted.</p> <note> <p> If the template does not exist, a <code>ValidationError</code> is returned. </p> </note></p>
+    async fn get_template(
+        &self,
+        input: GetTemplateInput,
+    ) -> Result<GetTemplateOutput, RusotoError<GetTemplateError>> {
+        self.get_template_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns information about a new or existing template. The <code>GetTemplateSummary</code> action is useful for viewing parameter information, such as default parameter values and parameter types, before you create or update a stack or stack set.</p> <p>You can use the <code>GetTemplateSummary</code> action when you submit a template, or you can get template information for a stack set, or a running or deleted stack.</p> <p>For deleted stacks, <code>GetTemplateSummary</code> returns the template information for up to 90 days after the stack has been deleted. If the template does not exist, a <code>ValidationError</code> is returned.</p>
+    async fn get_template_summary(
+        &self,
+        input: GetTemplateSummaryInput,
+    ) -> Result<GetTemplateSummaryOutput, RusotoError<GetTemplateSummaryError>> {
+        self.get_template_summary_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns the ID and status of each active change set for a stack. For example, AWS CloudFormation lists change sets that are in the <code>CREATE_IN_PROGRESS</code> or <code>CREATE_PENDING</code> state.</p>
+    async fn list_change_sets(
+        &self,
+        input: ListChangeSetsInput,
+    ) -> Result<ListChangeSetsOutput, RusotoError<ListChangeSetsError>> {
+        self.list_change_sets_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Lists all exported output values in the account and region in which you call this action. Use this action to see the exported output values that you can import into other stacks. To import values, use the <a href="https://docs.aws.amazon.com/AWSCloudFormation/latest/UserGuide/intrinsic-function-reference-importvalue.html"> <code>Fn::ImportValue</code> </a> function. </p> <p>For more information, see <a href="https://docs.aws.amazon.com/AWSCloudFormation/latest/UserGuide/using-cfn-stack-exports.html"> AWS CloudFormation Export Stack Output Values</a>.</p>
+    async fn list_exports(
+        &self,
+        input: ListExportsInput,
+    ) -> Result<ListExportsOutput, RusotoError<ListExportsError>> {
+        self.list_exports_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Lists all stacks that are importing an exported output value. To modify or remove an exported output value, first use this action to see which stacks are using it. To see the exported output values in your account, see <a>ListExports</a>. </p> <p>For more information about importing an exported output value, see the <a href="https://docs.aws.amazon.com/AWSCloudFormation/latest/UserGuide/intrinsic-function-reference-importvalue.html"> <code>Fn::ImportValue</code> </a> function. </p>
+    async fn list_imports(
+        &self,
+        input: ListImportsInput,
+    ) -> Result<ListImportsOutput, RusotoError<ListImportsError>> {
+        self.list_imports_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns summary information about stack instances that are associated with the specified stack set. You can filter for stack instances that are associated with a specific AWS account name or region.</p>
+    async fn list_stack_instances(
+        &self,
+        input: ListStackInstancesInput,
+    ) -> Result<ListStackInstancesOutput, RusotoError<ListStackInstancesError>> {
+        self.list_stack_instances_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns descriptions of all resources of the specified stack.</p> <p>For deleted stacks, ListStackResources returns resource information for up to 90 days after the stack has been deleted.</p>
+    async fn list_stack_resources(
+        &self,
+        input: ListStackResourcesInput,
+    ) -> Result<ListStackResourcesOutput, RusotoError<ListStackResourcesError>> {
+        self.list_stack_resources_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns summary information about the results of a stack set operation. </p>
+    async fn list_stack_set_operation_results(
+        &self,
+        input: ListStackSetOperationResultsInput,
+    ) -> Result<ListStackSetOperationResultsOutput, RusotoError<ListStackSetOperationResultsError>>
+    {
+        self.list_stack_set_operation_results_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns summary information about operations performed on a stack set. </p>
+    async fn list_stack_set_operations(
+        &self,
+        input: ListStackSetOperationsInput,
+    ) -> Result<ListStackSetOperationsOutput, RusotoError<ListStackSetOperationsError>> {
+        self.list_stack_set_operations_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns summary information about stack sets that are associated with the user.</p>
+    async fn list_stack_sets(
+        &self,
+        input: ListStackSetsInput,
+    ) -> Result<ListStackSetsOutput, RusotoError<ListStackSetsError>> {
+        self.list_stack_sets_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns the summary information for stacks whose status matches the specified StackStatusFilter. Summary information for stacks that have been deleted is kept for 90 days after the stack is deleted. If no StackStatusFilter is specified, summary information for all stacks is returned (including existing stacks and stacks that have been deleted).</p>
+    async fn list_stacks(
+        &self,
+        input: ListStacksInput,
+    ) -> Result<ListStacksOutput, RusotoError<ListStacksError>> {
+        self.list_stacks_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns a list of registration tokens for the specified type.</p>
+    async fn list_type_registrations(
+        &self,
+        input: ListTypeRegistrationsInput,
+    ) -> Result<ListTypeRegistrationsOutput, RusotoError<ListTypeRegistrationsError>> {
+        self.list_type_registrations_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns summary information about the versions of a type.</p>
+    async fn list_type_versions(
+        &self,
+        input: ListTypeVersionsInput,
+    ) -> Result<ListTypeVersionsOutput, RusotoError<ListTypeVersionsError>> {
+        self.list_type_versions_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns summary information about types that have been registered with CloudFormation.</p>
+    async fn list_types(
+        &self,
+        input: ListTypesInput,
+    ) -> Result<ListTypesOutput, RusotoError<ListTypesError>> {
+        self.list_types_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Reports progress of a resource handler to CloudFormation.</p> <p>Reserved for use by the <a href="https://docs.aws.amazon.com/cloudformation-cli/latest/userguide/what-is-cloudformation-cli.html">CloudFormation CLI</a>. Do not use this API in your code.</p>
+    async fn record_handler_progress(
+        &self,
+        input: RecordHandlerProgressInput,
+    ) -> Result<RecordHandlerProgressOutput, RusotoError<RecordHandlerProgressError>> {
+        self.record_handler_progress_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Registers a type with the CloudFormation service. Registering a type makes it available for use in CloudFormation templates in your AWS account, and includes:</p> <ul> <li> <p>Validating the resource schema</p> </li> <li> <p>Determining which handlers have been specified for the resource</p> </li> <li> <p>Making the resource type available for use in your account</p> </li> </ul> <p>For more information on how to develop types and ready them for registeration, see <a href="cloudformation-cli/latest/userguide/resource-types.html">Creating Resource Providers</a> in the <i>CloudFormation CLI User Guide</i>.</p> <p>Once you have initiated a registration request using <code> <a>RegisterType</a> </code>, you can use <code> <a>DescribeTypeRegistration</a> </code> to monitor the progress of the registration request.</p>
+    async fn register_type(
+        &self,
+        input: RegisterTypeInput,
+    ) -> Result<RegisterTypeOutput, RusotoError<RegisterTypeError>> {
+        self.register_type_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Sets a stack policy for a specified stack.</p>
+    async fn set_stack_policy(
+        &self,
+        input: SetStackPolicyInput,
+    ) -> Result<(), RusotoError<SetStackPolicyError>> {
+        self.set_stack_policy_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Specify the default version of a type. The default version of a type will be used in CloudFormation operations.</p>
+    async fn set_type_default_version(
+        &self,
+        input: SetTypeDefaultVersionInput,
+    ) -> Result<SetTypeDefaultVersionOutput, RusotoError<SetTypeDefaultVersionError>> {
+        self.set_type_default_version_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Sends a signal to the specified resource with a success or failure status. You can use the SignalResource API in conjunction with a creation policy or update policy. AWS CloudFormation doesn't proceed with a stack creation or update until resources receive the required number of signals or the timeout period is exceeded. The SignalResource API is useful in cases where you want to send signals from anywhere other than an Amazon EC2 instance.</p>
+    async fn signal_resource(
+        &self,
+        input: SignalResourceInput,
+    ) -> Result<(), RusotoError<SignalResourceError>> {
+        self.signal_resource_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Stops an in-progress operation on a stack set and its associated stack instances. </p>
+    async fn stop_stack_set_operation(
+        &self,
+        input: StopStackSetOperationInput,
+    ) -> Result<StopStackSetOperationOutput, RusotoError<StopStackSetOperationError>> {
+        self.stop_stack_set_operation_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Updates a stack as specified in the template. After the call completes successfully, the stack update starts. You can check the status of the stack via the <a>DescribeStacks</a> action.</p> <p>To get a copy of the template for an existing stack, you can use the <a>GetTemplate</a> action.</p> <p>For more information about creating an update template, updating a stack, and monitoring the progress of the update, see <a href="https://docs.aws.amazon.com/AWSCloudFormation/latest/UserGuide/using-cfn-updating-stacks.html">Updating a Stack</a>.</p>
+    async fn update_stack(
+        &self,
+        input: UpdateStackInput,
+    ) -> Result<UpdateStackOutput, RusotoError<UpdateStackError>> {
+        self.update_stack_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Updates the parameter values for stack instances for the specified accounts, within the specified regions. A stack instance refers to a stack in a specific account and region. </p> <p>You can only update stack instances in regions and accounts where they already exist; to create additional stack instances, use <a href="https://docs.aws.amazon.com/AWSCloudFormation/latest/APIReference/API_CreateStackInstances.html">CreateStackInstances</a>. </p> <p>During stack set updates, any parameters overridden for a stack instance are not updated, but retain their overridden value.</p> <p>You can only update the parameter <i>values</i> that are specified in the stack set; to add or delete a parameter itself, use <a href="https://docs.aws.amazon.com/AWSCloudFormation/latest/APIReference/API_UpdateStackSet.html">UpdateStackSet</a> to update the stack set template. If you add a parameter to a template, before you can override the parameter value specified in the stack set you must first use <a href="https://docs.aws.amazon.com/AWSCloudFormation/latest/APIReference/API_UpdateStackSet.html">UpdateStackSet</a> to update all stack instances with the updated template and parameter value specified in the stack set. Once a stack instance has been updated with the new parameter, you can then override the parameter value using <code>UpdateStackInstances</code>.</p>
+    async fn update_stack_instances(
+        &self,
+        input: UpdateStackInstancesInput,
+    ) -> Result<UpdateStackInstancesOutput, RusotoError<UpdateStackInstancesError>> {
+        self.update_stack_instances_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Updates the stack set, and associated stack instances in the specified accounts and regions.</p> <p>Even if the stack set operation created by updating the stack set fails (completely or partially, below or above a specified failure tolerance), the stack set is updated with your changes. Subsequent <a>CreateStackInstances</a> calls on the specified stack set use the updated stack set.</p>
+    async fn update_stack_set(
+        &self,
+        input: UpdateStackSetInput,
+    ) -> Result<UpdateStackSetOutput, RusotoError<UpdateStackSetError>> {
+        self.update_stack_set_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Updates termination protection for the specified stack. If a user attempts to delete a stack with termination protection enabled, the operation fails and the stack remains unchanged. For more information, see <a href="https://docs.aws.amazon.com/AWSCloudFormation/latest/UserGuide/using-cfn-protect-stacks.html">Protecting a Stack From Being Deleted</a> in the <i>AWS CloudFormation User Guide</i>.</p> <p> For <a href="https://docs.aws.amazon.com/AWSCloudFormation/latest/UserGuide/using-cfn-nested-stacks.html">nested stacks</a>, termination protection is set on the root stack and cannot be changed directly on the nested stack.</p>
+    async fn update_termination_protection(
+        &self,
+        input: UpdateTerminationProtectionInput,
+    ) -> Result<UpdateTerminationProtectionOutput, RusotoError<UpdateTerminationProtectionError>>
+    {
+        self.update_termination_protection_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Validates a specified template. AWS CloudFormation first checks if the template is valid JSON. If it isn't, AWS CloudFormation checks if the template is valid YAML. If both these checks fail, AWS CloudFormation returns a template validation error.</p>
+    async fn validate_template(
+        &self,
+        input: ValidateTemplateInput,
+    ) -> Result<ValidateTemplateOutput, RusotoError<ValidateTemplateError>> {
+        self.validate_template_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
     }
 }
 
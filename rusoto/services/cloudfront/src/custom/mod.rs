@@ -1,2 +1,6 @@
+mod signer;
+
+pub use self::signer::{CloudFrontSigner, InvalidPrivateKey, Policy};
+
 #[cfg(test)]
 mod custom_tests;
@@ -16,8 +16,8 @@ use std::fmt;
 use async_trait::async_trait;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
-use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest, ResponseMetadata};
+use rusoto_core::{Client, ErrorMetadata, RusotoError};
 
 use rusoto_core::param::{Params, ServiceParams};
 use rusoto_core::proto::xml::error::*;
@@ -8692,6 +8692,7 @@ impl ViewerProtocolPolicySerializer {
 
 /// Errors returned by CreateCloudFrontOriginAccessIdentity
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateCloudFrontOriginAccessIdentityError {
     /// <p>If the <code>CallerReference</code> is a value you already sent in a previous request to create an identity but the content of the <code>CloudFrontOriginAccessIdentityConfig</code> is different from the original request, CloudFront returns a <code>CloudFrontOriginAccessIdentityAlreadyExists</code> error. </p>
     CloudFrontOriginAccessIdentityAlreadyExists(String),
@@ -8703,6 +8704,8 @@ pub enum CreateCloudFrontOriginAccessIdentityError {
     MissingBody(String),
     /// <p>Processing your request would cause you to exceed the maximum number of origin access identities allowed.</p>
     TooManyCloudFrontOriginAccessIdentities(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateCloudFrontOriginAccessIdentityError {
@@ -8715,7 +8718,7 @@ impl CreateCloudFrontOriginAccessIdentityError {
             find_start_element(&mut stack);
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
-                                    "CloudFrontOriginAccessIdentityAlreadyExists" => return RusotoError::Service(CreateCloudFrontOriginAccessIdentityError::CloudFrontOriginAccessIdentityAlreadyExists(parsed_error.message)),"InconsistentQuantities" => return RusotoError::Service(CreateCloudFrontOriginAccessIdentityError::InconsistentQuantities(parsed_error.message)),"InvalidArgument" => return RusotoError::Service(CreateCloudFrontOriginAccessIdentityError::InvalidArgument(parsed_error.message)),"MissingBody" => return RusotoError::Service(CreateCloudFrontOriginAccessIdentityError::MissingBody(parsed_error.message)),"TooManyCloudFrontOriginAccessIdentities" => return RusotoError::Service(CreateCloudFrontOriginAccessIdentityError::TooManyCloudFrontOriginAccessIdentities(parsed_error.message)),_ => {}
+                                    "CloudFrontOriginAccessIdentityAlreadyExists" => return RusotoError::service(CreateCloudFrontOriginAccessIdentityError::CloudFrontOriginAccessIdentityAlreadyExists(parsed_error.message), &res),"InconsistentQuantities" => return RusotoError::service(CreateCloudFrontOriginAccessIdentityError::InconsistentQuantities(parsed_error.message), &res),"InvalidArgument" => return RusotoError::service(CreateCloudFrontOriginAccessIdentityError::InvalidArgument(parsed_error.message), &res),"MissingBody" => return RusotoError::service(CreateCloudFrontOriginAccessIdentityError::MissingBody(parsed_error.message), &res),"TooManyCloudFrontOriginAccessIdentities" => return RusotoError::service(CreateCloudFrontOriginAccessIdentityError::TooManyCloudFrontOriginAccessIdentities(parsed_error.message), &res),_ => return RusotoError::unhandled(CreateCloudFrontOriginAccessIdentityError::Unhandled, &res),
                                 }
             }
         }
@@ -8738,13 +8741,15 @@ impl fmt::Display for CreateCloudFrontOriginAccessIdentityError {
 CreateCloudFrontOriginAccessIdentityError::InconsistentQuantities(ref cause) => write!(f, "{}", cause),
 CreateCloudFrontOriginAccessIdentityError::InvalidArgument(ref cause) => write!(f, "{}", cause),
 CreateCloudFrontOriginAccessIdentityError::MissingBody(ref cause) => write!(f, "{}", cause),
-CreateCloudFrontOriginAccessIdentityError::TooManyCloudFrontOriginAccessIdentities(ref cause) => write!(f, "{}", cause)
-                        }
+CreateCloudFrontOriginAccessIdentityError::TooManyCloudFrontOriginAccessIdentities(ref cause) => write!(f, "{}", cause),
+            CreateCloudFrontOriginAccessIdentityError::Unhandled(ref metadata) => write!(f, "{}", metadata),
+        }
     }
 }
 impl Error for CreateCloudFrontOriginAccessIdentityError {}
 /// Errors returned by CreateDistribution
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateDistributionError {
     /// <p>Access denied.</p>
     AccessDenied(String),
@@ -8834,6 +8839,8 @@ pub enum CreateDistributionError {
     TooManyTrustedSigners(String),
     /// <p>One or more of your trusted signers don't exist.</p>
     TrustedSignerDoesNotExist(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateDistributionError {
@@ -8844,7 +8851,7 @@ impl CreateDistributionError {
             find_start_element(&mut stack);
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
-                                    "AccessDenied" => return RusotoError::Service(CreateDistributionError::AccessDenied(parsed_error.message)),"CNAMEAlreadyExists" => return RusotoError::Service(CreateDistributionError::CNAMEAlreadyExists(parsed_error.message)),"DistributionAlreadyExists" => return RusotoError::Service(CreateDistributionError::DistributionAlreadyExists(parsed_error.message)),"IllegalFieldLevelEncryptionConfigAssociationWithCacheBehavior" => return RusotoError::Service(CreateDistributionError::IllegalFieldLevelEncryptionConfigAssociationWithCacheBehavior(parsed_error.message)),"InconsistentQuantities" => return RusotoError::Service(CreateDistributionError::InconsistentQuantities(parsed_error.message)),"InvalidArgument" => return RusotoError::Service(CreateDistributionError::InvalidArgument(parsed_error.message)),"InvalidDefaultRootObject" => return RusotoError::Service(CreateDistributionError::InvalidDefaultRootObject(parsed_error.message)),"InvalidErrorCode" => return RusotoError::Service(CreateDistributionError::InvalidErrorCode(parsed_error.message)),"InvalidForwardCookies" => return RusotoError::Service(CreateDistributionError::InvalidForwardCookies(parsed_error.message)),"InvalidGeoRestrictionParameter" => return RusotoError::Service(CreateDistributionError::InvalidGeoRestrictionParameter(parsed_error.message)),"InvalidHeadersForS3Origin" => return RusotoError::Service(CreateDistributionError::InvalidHeadersForS3Origin(parsed_error.message)),"InvalidLambdaFunctionAssociation" => return RusotoError::Service(CreateDistributionError::InvalidLambdaFunctionAssociation(parsed_error.message)),"InvalidLocationCode" => return RusotoError::Service(CreateDistributionError::InvalidLocationCode(parsed_error.message)),"InvalidMinimumProtocolVersion" => return RusotoError::Service(CreateDistributionError::InvalidMinimumProtocolVersion(parsed_error.message)),"InvalidOrigin" => return RusotoError::Service(CreateDistributionError::InvalidOrigin(parsed_error.message)),"InvalidOriginAccessIdentity" => return RusotoError::Service(CreateDistributionError::InvalidOriginAccessIdentity(parsed_error.message)),"InvalidOriginKeepaliveTimeout" => return RusotoError::Service(CreateDistributionError::InvalidOriginKeepaliveTimeout(parsed_error.message)),"InvalidOriginReadTimeout" => return RusotoError::Service(CreateDistributionError::InvalidOriginReadTimeout(parsed_error.message)),"InvalidProtocolSettings" => return RusotoError::Service(CreateDistributionError::InvalidProtocolSettings(parsed_error.message)),"InvalidQueryStringParameters" => return RusotoError::Service(CreateDistributionError::InvalidQueryStringParameters(parsed_error.message)),"InvalidRelativePath" => return RusotoError::Service(CreateDistributionError::InvalidRelativePath(parsed_error.message)),"InvalidRequiredProtocol" => return RusotoError::Service(CreateDistributionError::InvalidRequiredProtocol(parsed_error.message)),"InvalidResponseCode" => return RusotoError::Service(CreateDistributionError::InvalidResponseCode(parsed_error.message)),"InvalidTTLOrder" => return RusotoError::Service(CreateDistributionError::InvalidTTLOrder(parsed_error.message)),"InvalidViewerCertificate" => return RusotoError::Service(CreateDistributionError::InvalidViewerCertificate(parsed_error.message)),"InvalidWebACLId" => return RusotoError::Service(CreateDistributionError::InvalidWebACLId(parsed_error.message)),"MissingBody" => return RusotoError::Service(CreateDistributionError::MissingBody(parsed_error.message)),"NoSuchFieldLevelEncryptionConfig" => return RusotoError::Service(CreateDistributionError::NoSuchFieldLevelEncryptionConfig(parsed_error.message)),"NoSuchOrigin" => return RusotoError::Service(CreateDistributionError::NoSuchOrigin(parsed_error.message)),"TooManyCacheBehaviors" => return RusotoError::Service(CreateDistributionError::TooManyCacheBehaviors(parsed_error.message)),"TooManyCertificates" => return RusotoError::Service(CreateDistributionError::TooManyCertificates(parsed_error.message)),"TooManyCookieNamesInWhiteList" => return RusotoError::Service(CreateDistributionError::TooManyCookieNamesInWhiteList(parsed_error.message)),"TooManyDistributionCNAMEs" => return RusotoError::Service(CreateDistributionError::TooManyDistributionCNAMEs(parsed_error.message)),"TooManyDistributions" => return RusotoError::Service(CreateDistributionError::TooManyDistributions(parsed_error.message)),"TooManyDistributionsAssociatedToFieldLevelEncryptionConfig" => return RusotoError::Service(CreateDistributionError::TooManyDistributionsAssociatedToFieldLevelEncryptionConfig(parsed_error.message)),"TooManyDistributionsWithLambdaAssociations" => return RusotoError::Service(CreateDistributionError::TooManyDistributionsWithLambdaAssociations(parsed_error.message)),"TooManyHeadersInForwardedValues" => return RusotoError::Service(CreateDistributionError::TooManyHeadersInForwardedValues(parsed_error.message)),"TooManyLambdaFunctionAssociations" => return RusotoError::Service(CreateDistributionError::TooManyLambdaFunctionAssociations(parsed_error.message)),"TooManyOriginCustomHeaders" => return RusotoError::Service(CreateDistributionError::TooManyOriginCustomHeaders(parsed_error.message)),"TooManyOriginGroupsPerDistribution" => return RusotoError::Service(CreateDistributionError::TooManyOriginGroupsPerDistribution(parsed_error.message)),"TooManyOrigins" => return RusotoError::Service(CreateDistributionError::TooManyOrigins(parsed_error.message)),"TooManyQueryStringParameters" => return RusotoError::Service(CreateDistributionError::TooManyQueryStringParameters(parsed_error.message)),"TooManyTrustedSigners" => return RusotoError::Service(CreateDistributionError::TooManyTrustedSigners(parsed_error.message)),"TrustedSignerDoesNotExist" => return RusotoError::Service(CreateDistributionError::TrustedSignerDoesNotExist(parsed_error.message)),_ => {}
+                                    "AccessDenied" => return RusotoError::service(CreateDistributionError::AccessDenied(parsed_error.message), &res),"CNAMEAlreadyExists" => return RusotoError::service(CreateDistributionError::CNAMEAlreadyExists(parsed_error.message), &res),"DistributionAlreadyExists" => return RusotoError::service(CreateDistributionError::DistributionAlreadyExists(parsed_error.message), &res),"IllegalFieldLevelEncryptionConfigAssociationWithCacheBehavior" => return RusotoError::service(CreateDistributionError::IllegalFieldLevelEncryptionConfigAssociationWithCacheBehavior(parsed_error.message), &res),"InconsistentQuantities" => return RusotoError::service(CreateDistributionError::InconsistentQuantities(parsed_error.message), &res),"InvalidArgument" => return RusotoError::service(CreateDistributionError::InvalidArgument(parsed_error.message), &res),"InvalidDefaultRootObject" => return RusotoError::service(CreateDistributionError::InvalidDefaultRootObject(parsed_error.message), &res),"InvalidErrorCode" => return RusotoError::service(CreateDistributionError::InvalidErrorCode(parsed_error.message), &res),"InvalidForwardCookies" => return RusotoError::service(CreateDistributionError::InvalidForwardCookies(parsed_error.message), &res),"InvalidGeoRestrictionParameter" => return RusotoError::service(CreateDistributionError::InvalidGeoRestrictionParameter(parsed_error.message), &res),"InvalidHeadersForS3Origin" => return RusotoError::service(CreateDistributionError::InvalidHeadersForS3Origin(parsed_error.message), &res),"InvalidLambdaFunctionAssociation" => return RusotoError::service(CreateDistributionError::InvalidLambdaFunctionAssociation(parsed_error.message), &res),"InvalidLocationCode" => return RusotoError::service(CreateDistributionError::InvalidLocationCode(parsed_error.message), &res),"InvalidMinimumProtocolVersion" => return RusotoError::service(CreateDistributionError::InvalidMinimumProtocolVersion(parsed_error.message), &res),"InvalidOrigin" => return RusotoError::service(CreateDistributionError::InvalidOrigin(parsed_error.message), &res),"InvalidOriginAccessIdentity" => return RusotoError::service(CreateDistributionError::InvalidOriginAccessIdentity(parsed_error.message), &res),"InvalidOriginKeepaliveTimeout" => return RusotoError::service(CreateDistributionError::InvalidOriginKeepaliveTimeout(parsed_error.message), &res),"InvalidOriginReadTimeout" => return RusotoError::service(CreateDistributionError::InvalidOriginReadTimeout(parsed_error.message), &res),"InvalidProtocolSettings" => return RusotoError::service(CreateDistributionError::InvalidProtocolSettings(parsed_error.message), &res),"InvalidQueryStringParameters" => return RusotoError::service(CreateDistributionError::InvalidQueryStringParameters(parsed_error.message), &res),"InvalidRelativePath" => return RusotoError::service(CreateDistributionError::InvalidRelativePath(parsed_error.message), &res),"InvalidRequiredProtocol" => return RusotoError::service(CreateDistributionError::InvalidRequiredProtocol(parsed_error.message), &res),"InvalidResponseCode" => return RusotoError::service(CreateDistributionError::InvalidResponseCode(parsed_error.message), &res),"InvalidTTLOrder" => return RusotoError::service(CreateDistributionError::InvalidTTLOrder(parsed_error.message), &res),"InvalidViewerCertificate" => return RusotoError::service(CreateDistributionError::InvalidViewerCertificate(parsed_error.message), &res),"InvalidWebACLId" => return RusotoError::service(CreateDistributionError::InvalidWebACLId(parsed_error.message), &res),"MissingBody" => return RusotoError::service(CreateDistributionError::MissingBody(parsed_error.message), &res),"NoSuchFieldLevelEncryptionConfig" => return RusotoError::service(CreateDistributionError::NoSuchFieldLevelEncryptionConfig(parsed_error.message), &res),"NoSuchOrigin" => return RusotoError::service(CreateDistributionError::NoSuchOrigin(parsed_error.message), &res),"TooManyCacheBehaviors" => return RusotoError::service(CreateDistributionError::TooManyCacheBehaviors(parsed_error.message), &res),"TooManyCertificates" => return RusotoError::service(CreateDistributionError::TooManyCertificates(parsed_error.message), &res),"TooManyCookieNamesInWhiteList" => return RusotoError::service(CreateDistributionError::TooManyCookieNamesInWhiteList(parsed_error.message), &res),"TooManyDistributionCNAMEs" => return RusotoError::service(CreateDistributionError::TooManyDistributionCNAMEs(parsed_error.message), &res),"TooManyDistributions" => return RusotoError::service(CreateDistributionError::TooManyDistributions(parsed_error.message), &res),"TooManyDistributionsAssociatedToFieldLevelEncryptionConfig" => return RusotoError::service(CreateDistributionError::TooManyDistributionsAssociatedToFieldLevelEncryptionConfig(parsed_error.message), &res),"TooManyDistributionsWithLambdaAssociations" => return RusotoError::service(CreateDistributionError::TooManyDistributionsWithLambdaAssociations(parsed_error.message), &res),"TooManyHeadersInForwardedValues" => return RusotoError::service(CreateDistributionError::TooManyHeadersInForwardedValues(parsed_error.message), &res),"TooManyLambdaFunctionAssociations" => return RusotoError::service(CreateDistributionError::TooManyLambdaFunctionAssociations(parsed_error.message), &res),"TooManyOriginCustomHeaders" => return RusotoError::service(CreateDistributionError::TooManyOriginCustomHeaders(parsed_error.message), &res),"TooManyOriginGroupsPerDistribution" => return RusotoError::service(CreateDistributionError::TooManyOriginGroupsPerDistribution(parsed_error.message), &res),"TooManyOrigins" => return RusotoError::service(CreateDistributionError::TooManyOrigins(parsed_error.message), &res),"TooManyQueryStringParameters" => return RusotoError::service(CreateDistributionError::TooManyQueryStringParameters(parsed_error.message), &res),"TooManyTrustedSigners" => return RusotoError::service(CreateDistributionError::TooManyTrustedSigners(parsed_error.message), &res),"TrustedSignerDoesNotExist" => return RusotoError::service(CreateDistributionError::TrustedSignerDoesNotExist(parsed_error.message), &res),_ => return RusotoError::unhandled(CreateDistributionError::Unhandled, &res),
                                 }
             }
         }
@@ -8906,13 +8913,15 @@ CreateDistributionError::TooManyOriginGroupsPerDistribution(ref cause) => write!
 CreateDistributionError::TooManyOrigins(ref cause) => write!(f, "{}", cause),
 CreateDistributionError::TooManyQueryStringParameters(ref cause) => write!(f, "{}", cause),
 CreateDistributionError::TooManyTrustedSigners(ref cause) => write!(f, "{}", cause),
-CreateDistributionError::TrustedSignerDoesNotExist(ref cause) => write!(f, "{}", cause)
-                        }
+CreateDistributionError::TrustedSignerDoesNotExist(ref cause) => write!(f, "{}", cause),
+            CreateDistributionError::Unhandled(ref metadata) => write!(f, "{}", metadata),
+        }
     }
 }
 impl Error for CreateDistributionError {}
 /// Errors returned by CreateDistributionWithTags
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateDistributionWithTagsError {
     /// <p>Access denied.</p>
     AccessDenied(String),
@@ -9004,6 +9013,8 @@ pub enum CreateDistributionWithTagsError {
     TooManyTrustedSigners(String),
     /// <p>One or more of your trusted signers don't exist.</p>
     TrustedSignerDoesNotExist(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateDistributionWithTagsError {
@@ -9016,7 +9027,7 @@ impl CreateDistributionWithTagsError {
             find_start_element(&mut stack);
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
-                                    "AccessDenied" => return RusotoError::Service(CreateDistributionWithTagsError::AccessDenied(parsed_error.message)),"CNAMEAlreadyExists" => return RusotoError::Service(CreateDistributionWithTagsError::CNAMEAlreadyExists(parsed_error.message)),"DistributionAlreadyExists" => return RusotoError::Service(CreateDistributionWithTagsError::DistributionAlreadyExists(parsed_error.message)),"IllegalFieldLevelEncryptionConfigAssociationWithCacheBehavior" => return RusotoError::Service(CreateDistributionWithTagsError::IllegalFieldLevelEncryptionConfigAssociationWithCacheBehavior(parsed_error.message)),"InconsistentQuantities" => return RusotoError::Service(CreateDistributionWithTagsError::InconsistentQuantities(parsed_error.message)),"InvalidArgument" => return RusotoError::Service(CreateDistributionWithTagsError::InvalidArgument(parsed_error.message)),"InvalidDefaultRootObject" => return RusotoError::Service(CreateDistributionWithTagsError::InvalidDefaultRootObject(parsed_error.message)),"InvalidErrorCode" => return RusotoError::Service(CreateDistributionWithTagsError::InvalidErrorCode(parsed_error.message)),"InvalidForwardCookies" => return RusotoError::Service(CreateDistributionWithTagsError::InvalidForwardCookies(parsed_error.message)),"InvalidGeoRestrictionParameter" => return RusotoError::Service(CreateDistributionWithTagsError::InvalidGeoRestrictionParameter(parsed_error.message)),"InvalidHeadersForS3Origin" => return RusotoError::Service(CreateDistributionWithTagsError::InvalidHeadersForS3Origin(parsed_error.message)),"InvalidLambdaFunctionAssociation" => return RusotoError::Service(CreateDistributionWithTagsError::InvalidLambdaFunctionAssociation(parsed_error.message)),"InvalidLocationCode" => return RusotoError::Service(CreateDistributionWithTagsError::InvalidLocationCode(parsed_error.message)),"InvalidMinimumProtocolVersion" => return RusotoError::Service(CreateDistributionWithTagsError::InvalidMinimumProtocolVersion(parsed_error.message)),"InvalidOrigin" => return RusotoError::Service(CreateDistributionWithTagsError::InvalidOrigin(parsed_error.message)),"InvalidOriginAccessIdentity" => return RusotoError::Service(CreateDistributionWithTagsError::InvalidOriginAccessIdentity(parsed_error.message)),"InvalidOriginKeepaliveTimeout" => return RusotoError::Service(CreateDistributionWithTagsError::InvalidOriginKeepaliveTimeout(parsed_error.message)),"InvalidOriginReadTimeout" => return RusotoError::Service(CreateDistributionWithTagsError::InvalidOriginReadTimeout(parsed_error.message)),"InvalidProtocolSettings" => return RusotoError::Service(CreateDistributionWithTagsError::InvalidProtocolSettings(parsed_error.message)),"InvalidQueryStringParameters" => return RusotoError::Service(CreateDistributionWithTagsError::InvalidQueryStringParameters(parsed_error.message)),"InvalidRelativePath" => return RusotoError::Service(CreateDistributionWithTagsError::InvalidRelativePath(parsed_error.message)),"InvalidRequiredProtocol" => return RusotoError::Service(CreateDistributionWithTagsError::InvalidRequiredProtocol(parsed_error.message)),"InvalidResponseCode" => return RusotoError::Service(CreateDistributionWithTagsError::InvalidResponseCode(parsed_error.message)),"InvalidTTLOrder" => return RusotoError::Service(CreateDistributionWithTagsError::InvalidTTLOrder(parsed_error.message)),"InvalidTagging" => return RusotoError::Service(CreateDistributionWithTagsError::InvalidTagging(parsed_error.message)),"InvalidViewerCertificate" => return RusotoError::Service(CreateDistributionWithTagsError::InvalidViewerCertificate(parsed_error.message)),"InvalidWebACLId" => return RusotoError::Service(CreateDistributionWithTagsError::InvalidWebACLId(parsed_error.message)),"MissingBody" => return RusotoError::Service(CreateDistributionWithTagsError::MissingBody(parsed_error.message)),"NoSuchFieldLevelEncryptionConfig" => return RusotoError::Service(CreateDistributionWithTagsError::NoSuchFieldLevelEncryptionConfig(parsed_error.message)),"NoSuchOrigin" => return RusotoError::Service(CreateDistributionWithTagsError::NoSuchOrigin(parsed_error.message)),"TooManyCacheBehaviors" => return RusotoError::Service(CreateDistributionWithTagsError::TooManyCacheBehaviors(parsed_error.message)),"TooManyCertificates" => return RusotoError::Service(CreateDistributionWithTagsError::TooManyCertificates(parsed_error.message)),"TooManyCookieNamesInWhiteList" => return RusotoError::Service(CreateDistributionWithTagsError::TooManyCookieNamesInWhiteList(parsed_error.message)),"TooManyDistributionCNAMEs" => return RusotoError::Service(CreateDistributionWithTagsError::TooManyDistributionCNAMEs(parsed_error.message)),"TooManyDistributions" => return RusotoError::Service(CreateDistributionWithTagsError::TooManyDistributions(parsed_error.message)),"TooManyDistributionsAssociatedToFieldLevelEncryptionConfig" => return RusotoError::Service(CreateDistributionWithTagsError::TooManyDistributionsAssociatedToFieldLevelEncryptionConfig(parsed_error.message)),"TooManyDistributionsWithLambdaAssociations" => return RusotoError::Service(CreateDistributionWithTagsError::TooManyDistributionsWithLambdaAssociations(parsed_error.message)),"TooManyHeadersInForwardedValues" => return RusotoError::Service(CreateDistributionWithTagsError::TooManyHeadersInForwardedValues(parsed_error.message)),"TooManyLambdaFunctionAssociations" => return RusotoError::Service(CreateDistributionWithTagsError::TooManyLambdaFunctionAssociations(parsed_error.message)),"TooManyOriginCustomHeaders" => return RusotoError::Service(CreateDistributionWithTagsError::TooManyOriginCustomHeaders(parsed_error.message)),"TooManyOriginGroupsPerDistribution" => return RusotoError::Service(CreateDistributionWithTagsError::TooManyOriginGroupsPerDistribution(parsed_error.message)),"TooManyOrigins" => return RusotoError::Service(CreateDistributionWithTagsError::TooManyOrigins(parsed_error.message)),"TooManyQueryStringParameters" => return RusotoError::Service(CreateDistributionWithTagsError::TooManyQueryStringParameters(parsed_error.message)),"TooManyTrustedSigners" => return RusotoError::Service(CreateDistributionWithTagsError::TooManyTrustedSigners(parsed_error.message)),"TrustedSignerDoesNotExist" => return RusotoError::Service(CreateDistributionWithTagsError::TrustedSignerDoesNotExist(parsed_error.message)),_ => {}
+                                    "AccessDenied" => return RusotoError::service(CreateDistributionWithTagsError::AccessDenied(parsed_error.message), &res),"CNAMEAlreadyExists" => return RusotoError::service(CreateDistributionWithTagsError::CNAMEAlreadyExists(parsed_error.message), &res),"DistributionAlreadyExists" => return RusotoError::service(CreateDistributionWithTagsError::DistributionAlreadyExists(parsed_error.message), &res),"IllegalFieldLevelEncryptionConfigAssociationWithCacheBehavior" => return RusotoError::service(CreateDistributionWithTagsError::IllegalFieldLevelEncryptionConfigAssociationWithCacheBehavior(parsed_error.message), &res),"InconsistentQuantities" => return RusotoError::service(CreateDistributionWithTagsError::InconsistentQuantities(parsed_error.message), &res),"InvalidArgument" => return RusotoError::service(CreateDistributionWithTagsError::InvalidArgument(parsed_error.message), &res),"InvalidDefaultRootObject" => return RusotoError::service(CreateDistributionWithTagsError::InvalidDefaultRootObject(parsed_error.message), &res),"InvalidErrorCode" => return RusotoError::service(CreateDistributionWithTagsError::InvalidErrorCode(parsed_error.message), &res),"InvalidForwardCookies" => return RusotoError::service(CreateDistributionWithTagsError::InvalidForwardCookies(parsed_error.message), &res),"InvalidGeoRestrictionParameter" => return RusotoError::service(CreateDistributionWithTagsError::InvalidGeoRestrictionParameter(parsed_error.message), &res),"InvalidHeadersForS3Origin" => return RusotoError::service(CreateDistributionWithTagsError::InvalidHeadersForS3Origin(parsed_error.message), &res),"InvalidLambdaFunctionAssociation" => return RusotoError::service(CreateDistributionWithTagsError::InvalidLambdaFunctionAssociation(parsed_error.message), &res),"InvalidLocationCode" => return RusotoError::service(CreateDistributionWithTagsError::InvalidLocationCode(parsed_error.message), &res),"InvalidMinimumProtocolVersion" => return RusotoError::service(CreateDistributionWithTagsError::InvalidMinimumProtocolVersion(parsed_error.message), &res),"InvalidOrigin" => return RusotoError::service(CreateDistributionWithTagsError::InvalidOrigin(parsed_error.message), &res),"InvalidOriginAccessIdentity" => return RusotoError::service(CreateDistributionWithTagsError::InvalidOriginAccessIdentity(parsed_error.message), &res),"InvalidOriginKeepaliveTimeout" => return RusotoError::service(CreateDistributionWithTagsError::InvalidOriginKeepaliveTimeout(parsed_error.message), &res),"InvalidOriginReadTimeout" => return RusotoError::service(CreateDistributionWithTagsError::InvalidOriginReadTimeout(parsed_error.message), &res),"InvalidProtocolSettings" => return RusotoError::service(CreateDistributionWithTagsError::InvalidProtocolSettings(parsed_error.message), &res),"InvalidQueryStringParameters" => return RusotoError::service(CreateDistributionWithTagsError::InvalidQueryStringParameters(parsed_error.message), &res),"InvalidRelativePath" => return RusotoError::service(CreateDistributionWithTagsError::InvalidRelativePath(parsed_error.message), &res),"InvalidRequiredProtocol" => return RusotoError::service(CreateDistributionWithTagsError::InvalidRequiredProtocol(parsed_error.message), &res),"InvalidResponseCode" => return RusotoError::service(CreateDistributionWithTagsError::InvalidResponseCode(parsed_error.message), &res),"InvalidTTLOrder" => return RusotoError::service(CreateDistributionWithTagsError::InvalidTTLOrder(parsed_error.message), &res),"InvalidTagging" => return RusotoError::service(CreateDistributionWithTagsError::InvalidTagging(parsed_error.message), &res),"InvalidViewerCertificate" => return RusotoError::service(CreateDistributionWithTagsError::InvalidViewerCertificate(parsed_error.message), &res),"InvalidWebACLId" => return RusotoError::service(CreateDistributionWithTagsError::InvalidWebACLId(parsed_error.message), &res),"MissingBody" => return RusotoError::service(CreateDistributionWithTagsError::MissingBody(parsed_error.message), &res),"NoSuchFieldLevelEncryptionConfig" => return RusotoError::service(CreateDistributionWithTagsError::NoSuchFieldLevelEncryptionConfig(parsed_error.message), &res),"NoSuchOrigin" => return RusotoError::service(CreateDistributionWithTagsError::NoSuchOrigin(parsed_error.message), &res),"TooManyCacheBehaviors" => return RusotoError::service(CreateDistributionWithTagsError::TooManyCacheBehaviors(parsed_error.message), &res),"TooManyCertificates" => return RusotoError::service(CreateDistributionWithTagsError::TooManyCertificates(parsed_error.message), &res),"TooManyCookieNamesInWhiteList" => return RusotoError::service(CreateDistributionWithTagsError::TooManyCookieNamesInWhiteList(parsed_error.message), &res),"TooManyDistributionCNAMEs" => return RusotoError::service(CreateDistributionWithTagsError::TooManyDistributionCNAMEs(parsed_error.message), &res),"TooManyDistributions" => return RusotoError::service(CreateDistributionWithTagsError::TooManyDistributions(parsed_error.message), &res),"TooManyDistributionsAssociatedToFieldLevelEncryptionConfig" => return RusotoError::service(CreateDistributionWithTagsError::TooManyDistributionsAssociatedToFieldLevelEncryptionConfig(parsed_error.message), &res),"TooManyDistributionsWithLambdaAssociations" => return RusotoError::service(CreateDistributionWithTagsError::TooManyDistributionsWithLambdaAssociations(parsed_error.message), &res),"TooManyHeadersInForwardedValues" => return RusotoError::service(CreateDistributionWithTagsError::TooManyHeadersInForwardedValues(parsed_error.message), &res),"TooManyLambdaFunctionAssociations" => return RusotoError::service(CreateDistributionWithTagsError::TooManyLambdaFunctionAssociations(parsed_error.message), &res),"TooManyOriginCustomHeaders" => return RusotoError::service(CreateDistributionWithTagsError::TooManyOriginCustomHeaders(parsed_error.message), &res),"TooManyOriginGroupsPerDistribution" => return RusotoError::service(CreateDistributionWithTagsError::TooManyOriginGroupsPerDistribution(parsed_error.message), &res),"TooManyOrigins" => return RusotoError::service(CreateDistributionWithTagsError::TooManyOrigins(parsed_error.message), &res),"TooManyQueryStringParameters" => return RusotoError::service(CreateDistributionWithTagsError::TooManyQueryStringParameters(parsed_error.message), &res),"TooManyTrustedSigners" => return RusotoError::service(CreateDistributionWithTagsError::TooManyTrustedSigners(parsed_error.message), &res),"TrustedSignerDoesNotExist" => return RusotoError::service(CreateDistributionWithTagsError::TrustedSignerDoesNotExist(parsed_error.message), &res),_ => return RusotoError::unhandled(CreateDistributionWithTagsError::Unhandled, &res),
                                 }
             }
         }
@@ -9079,13 +9090,15 @@ CreateDistributionWithTagsError::TooManyOriginGroupsPerDistribution(ref cause) =
 CreateDistributionWithTagsError::TooManyOrigins(ref cause) => write!(f, "{}", cause),
 CreateDistributionWithTagsError::TooManyQueryStringParameters(ref cause) => write!(f, "{}", cause),
 CreateDistributionWithTagsError::TooManyTrustedSigners(ref cause) => write!(f, "{}", cause),
-CreateDistributionWithTagsError::TrustedSignerDoesNotExist(ref cause) => write!(f, "{}", cause)
-                        }
+CreateDistributionWithTagsError::TrustedSignerDoesNotExist(ref cause) => write!(f, "{}", cause),
+            CreateDistributionWithTagsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
+        }
     }
 }
 impl Error for CreateDistributionWithTagsError {}
 /// Errors returned by CreateFieldLevelEncryptionConfig
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateFieldLevelEncryptionConfigError {
     /// <p>The specified configuration for field-level encryption already exists.</p>
     FieldLevelEncryptionConfigAlreadyExists(String),
@@ -9103,6 +9116,8 @@ pub enum CreateFieldLevelEncryptionConfigError {
     TooManyFieldLevelEncryptionContentTypeProfiles(String),
     /// <p>The maximum number of query arg profiles for field-level encryption have been created.</p>
     TooManyFieldLevelEncryptionQueryArgProfiles(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateFieldLevelEncryptionConfigError {
@@ -9115,7 +9130,7 @@ impl CreateFieldLevelEncryptionConfigError {
             find_start_element(&mut stack);
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
-                                    "FieldLevelEncryptionConfigAlreadyExists" => return RusotoError::Service(CreateFieldLevelEncryptionConfigError::FieldLevelEncryptionConfigAlreadyExists(parsed_error.message)),"InconsistentQuantities" => return RusotoError::Service(CreateFieldLevelEncryptionConfigError::InconsistentQuantities(parsed_error.message)),"InvalidArgument" => return RusotoError::Service(CreateFieldLevelEncryptionConfigError::InvalidArgument(parsed_error.message)),"NoSuchFieldLevelEncryptionProfile" => return RusotoError::Service(CreateFieldLevelEncryptionConfigError::NoSuchFieldLevelEncryptionProfile(parsed_error.message)),"QueryArgProfileEmpty" => return RusotoError::Service(CreateFieldLevelEncryptionConfigError::QueryArgProfileEmpty(parsed_error.message)),"TooManyFieldLevelEncryptionConfigs" => return RusotoError::Service(CreateFieldLevelEncryptionConfigError::TooManyFieldLevelEncryptionConfigs(parsed_error.message)),"TooManyFieldLevelEncryptionContentTypeProfiles" => return RusotoError::Service(CreateFieldLevelEncryptionConfigError::TooManyFieldLevelEncryptionContentTypeProfiles(parsed_error.message)),"TooManyFieldLevelEncryptionQueryArgProfiles" => return RusotoError::Service(CreateFieldLevelEncryptionConfigError::TooManyFieldLevelEncryptionQueryArgProfiles(parsed_error.message)),_ => {}
+                                    "FieldLevelEncryptionConfigAlreadyExists" => return RusotoError::service(CreateFieldLevelEncryptionConfigError::FieldLevelEncryptionConfigAlreadyExists(parsed_error.message), &res),"InconsistentQuantities" => return RusotoError::service(CreateFieldLevelEncryptionConfigError::InconsistentQuantities(parsed_error.message), &res),"InvalidArgument" => return RusotoError::service(CreateFieldLevelEncryptionConfigError::InvalidArgument(parsed_error.message), &res),"NoSuchFieldLevelEncryptionProfile" => return RusotoError::service(CreateFieldLevelEncryptionConfigError::NoSuchFieldLevelEncryptionProfile(parsed_error.message), &res),"QueryArgProfileEmpty" => return RusotoError::service(CreateFieldLevelEncryptionConfigError::QueryArgProfileEmpty(parsed_error.message), &res),"TooManyFieldLevelEncryptionConfigs" => return RusotoError::service(CreateFieldLevelEncryptionConfigError::TooManyFieldLevelEncryptionConfigs(parsed_error.message), &res),"TooManyFieldLevelEncryptionContentTypeProfiles" => return RusotoError::service(CreateFieldLevelEncryptionConfigError::TooManyFieldLevelEncryptionContentTypeProfiles(parsed_error.message), &res),"TooManyFieldLevelEncryptionQueryArgProfiles" => return RusotoError::service(CreateFieldLevelEncryptionConfigError::TooManyFieldLevelEncryptionQueryArgProfiles(parsed_error.message), &res),_ => return RusotoError::unhandled(CreateFieldLevelEncryptionConfigError::Unhandled, &res),
                                 }
             }
         }
@@ -9141,13 +9156,15 @@ CreateFieldLevelEncryptionConfigError::NoSuchFieldLevelEncryptionProfile(ref cau
 CreateFieldLevelEncryptionConfigError::QueryArgProfileEmpty(ref cause) => write!(f, "{}", cause),
 CreateFieldLevelEncryptionConfigError::TooManyFieldLevelEncryptionConfigs(ref cause) => write!(f, "{}", cause),
 CreateFieldLevelEncryptionConfigError::TooManyFieldLevelEncryptionContentTypeProfiles(ref cause) => write!(f, "{}", cause),
-CreateFieldLevelEncryptionConfigError::TooManyFieldLevelEncryptionQueryArgProfiles(ref cause) => write!(f, "{}", cause)
-                        }
+CreateFieldLevelEncryptionConfigError::TooManyFieldLevelEncryptionQueryArgProfiles(ref cause) => write!(f, "{}", cause),
+            CreateFieldLevelEncryptionConfigError::Unhandled(ref metadata) => write!(f, "{}", metadata),
+        }
     }
 }
 impl Error for CreateFieldLevelEncryptionConfigError {}
 /// Errors returned by CreateFieldLevelEncryptionProfile
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateFieldLevelEncryptionProfileError {
     /// <p>The specified profile for field-level encryption already exists.</p>
     FieldLevelEncryptionProfileAlreadyExists(String),
@@ -9165,6 +9182,8 @@ pub enum CreateFieldLevelEncryptionProfileError {
     TooManyFieldLevelEncryptionFieldPatterns(String),
     /// <p>The maximum number of profiles for field-level encryption have been created.</p>
     TooManyFieldLevelEncryptionProfiles(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateFieldLevelEncryptionProfileError {
@@ -9177,7 +9196,7 @@ impl CreateFieldLevelEncryptionProfileError {
             find_start_element(&mut stack);
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
-                                    "FieldLevelEncryptionProfileAlreadyExists" => return RusotoError::Service(CreateFieldLevelEncryptionProfileError::FieldLevelEncryptionProfileAlreadyExists(parsed_error.message)),"FieldLevelEncryptionProfileSizeExceeded" => return RusotoError::Service(CreateFieldLevelEncryptionProfileError::FieldLevelEncryptionProfileSizeExceeded(parsed_error.message)),"InconsistentQuantities" => return RusotoError::Service(CreateFieldLevelEncryptionProfileError::InconsistentQuantities(parsed_error.message)),"InvalidArgument" => return RusotoError::Service(CreateFieldLevelEncryptionProfileError::InvalidArgument(parsed_error.message)),"NoSuchPublicKey" => return RusotoError::Service(CreateFieldLevelEncryptionProfileError::NoSuchPublicKey(parsed_error.message)),"TooManyFieldLevelEncryptionEncryptionEntities" => return RusotoError::Service(CreateFieldLevelEncryptionProfileError::TooManyFieldLevelEncryptionEncryptionEntities(parsed_error.message)),"TooManyFieldLevelEncryptionFieldPatterns" => return RusotoError::Service(CreateFieldLevelEncryptionProfileError::TooManyFieldLevelEncryptionFieldPatterns(parsed_error.message)),"TooManyFieldLevelEncryptionProfiles" => return RusotoError::Service(CreateFieldLevelEncryptionProfileError::TooManyFieldLevelEncryptionProfiles(parsed_error.message)),_ => {}
+                                    "FieldLevelEncryptionProfileAlreadyExists" => return RusotoError::service(CreateFieldLevelEncryptionProfileError::FieldLevelEncryptionProfileAlreadyExists(parsed_error.message), &res),"FieldLevelEncryptionProfileSizeExceeded" => return RusotoError::service(CreateFieldLevelEncryptionProfileError::FieldLevelEncryptionProfileSizeExceeded(parsed_error.message), &res),"InconsistentQuantities" => return RusotoError::service(CreateFieldLevelEncryptionProfileError::InconsistentQuantities(parsed_error.message), &res),"InvalidArgument" => return RusotoError::service(CreateFieldLevelEncryptionProfileError::InvalidArgument(parsed_error.message), &res),"NoSuchPublicKey" => return RusotoError::service(CreateFieldLevelEncryptionProfileError::NoSuchPublicKey(parsed_error.message), &res),"TooManyFieldLevelEncryptionEncryptionEntities" => return RusotoError::service(CreateFieldLevelEncryptionProfileError::TooManyFieldLevelEncryptionEncryptionEntities(parsed_error.message), &res),"TooManyFieldLevelEncryptionFieldPatterns" => return RusotoError::service(CreateFieldLevelEncryptionProfileError::TooManyFieldLevelEncryptionFieldPatterns(parsed_error.message), &res),"TooManyFieldLevelEncryptionProfiles" => return RusotoError::service(CreateFieldLevelEncryptionProfileError::TooManyFieldLevelEncryptionProfiles(parsed_error.message), &res),_ => return RusotoError::unhandled(CreateFieldLevelEncryptionProfileError::Unhandled, &res),
                                 }
             }
         }
@@ -9203,13 +9222,15 @@ CreateFieldLevelEncryptionProfileError::InvalidArgument(ref cause) => write!(f,
 CreateFieldLevelEncryptionProfileError::NoSuchPublicKey(ref cause) => write!(f, "{}", cause),
 CreateFieldLevelEncryptionProfileError::TooManyFieldLevelEncryptionEncryptionEntities(ref cause) => write!(f, "{}", cause),
 CreateFieldLevelEncryptionProfileError::TooManyFieldLevelEncryptionFieldPatterns(ref cause) => write!(f, "{}", cause),
-CreateFieldLevelEncryptionProfileError::TooManyFieldLevelEncryptionProfiles(ref cause) => write!(f, "{}", cause)
-                        }
+CreateFieldLevelEncryptionProfileError::TooManyFieldLevelEncryptionProfiles(ref cause) => write!(f, "{}", cause),
+            CreateFieldLevelEncryptionProfileError::Unhandled(ref metadata) => write!(f, "{}", metadata),
+        }
     }
 }
 impl Error for CreateFieldLevelEncryptionProfileError {}
 /// Errors returned by CreateInvalidation
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateInvalidationError {
     /// <p>Access denied.</p>
     AccessDenied(String),
@@ -9225,6 +9246,8 @@ pub enum CreateInvalidationError {
     NoSuchDistribution(String),
     /// <p>You have exceeded the maximum number of allowable InProgress invalidation batch requests, or invalidation objects.</p>
     TooManyInvalidationsInProgress(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateInvalidationError {
@@ -9236,43 +9259,50 @@ impl CreateInvalidationError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "AccessDenied" => {
-                        return RusotoError::Service(CreateInvalidationError::AccessDenied(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            CreateInvalidationError::AccessDenied(parsed_error.message),
+                            &res,
+                        )
                     }
                     "BatchTooLarge" => {
-                        return RusotoError::Service(CreateInvalidationError::BatchTooLarge(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            CreateInvalidationError::BatchTooLarge(parsed_error.message),
+                            &res,
+                        )
                     }
                     "InconsistentQuantities" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             CreateInvalidationError::InconsistentQuantities(parsed_error.message),
+                            &res,
                         )
                     }
                     "InvalidArgument" => {
-                        return RusotoError::Service(CreateInvalidationError::InvalidArgument(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            CreateInvalidationError::InvalidArgument(parsed_error.message),
+                            &res,
+                        )
                     }
                     "MissingBody" => {
-                        return RusotoError::Service(CreateInvalidationError::MissingBody(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            CreateInvalidationError::MissingBody(parsed_error.message),
+                            &res,
+                        )
                     }
                     "NoSuchDistribution" => {
-                        return RusotoError::Service(CreateInvalidationError::NoSuchDistribution(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            CreateInvalidationError::NoSuchDistribution(parsed_error.message),
+                            &res,
+                        )
                     }
                     "TooManyInvalidationsInProgress" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             CreateInvalidationError::TooManyInvalidationsInProgress(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
-                    _ => {}
+                    _ => return RusotoError::unhandled(CreateInvalidationError::Unhandled, &res),
                 }
             }
         }
@@ -9300,12 +9330,14 @@ impl fmt::Display for CreateInvalidationError {
             CreateInvalidationError::TooManyInvalidationsInProgress(ref cause) => {
                 write!(f, "{}", cause)
             }
+            CreateInvalidationError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateInvalidationError {}
 /// Errors returned by CreatePublicKey
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreatePublicKeyError {
     /// <p>The argument is invalid.</p>
     InvalidArgument(String),
@@ -9313,6 +9345,8 @@ pub enum CreatePublicKeyError {
     PublicKeyAlreadyExists(String),
     /// <p>The maximum number of public keys for field-level encryption have been created. To create a new public key, delete one of the existing keys.</p>
     TooManyPublicKeys(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreatePublicKeyError {
@@ -9324,21 +9358,24 @@ impl CreatePublicKeyError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InvalidArgument" => {
-                        return RusotoError::Service(CreatePublicKeyError::InvalidArgument(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            CreatePublicKeyError::InvalidArgument(parsed_error.message),
+                            &res,
+                        )
                     }
                     "PublicKeyAlreadyExists" => {
-                        return RusotoError::Service(CreatePublicKeyError::PublicKeyAlreadyExists(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            CreatePublicKeyError::PublicKeyAlreadyExists(parsed_error.message),
+                            &res,
+                        )
                     }
                     "TooManyPublicKeys" => {
-                        return RusotoError::Service(CreatePublicKeyError::TooManyPublicKeys(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            CreatePublicKeyError::TooManyPublicKeys(parsed_error.message),
+                            &res,
+                        )
                     }
-                    _ => {}
+                    _ => return RusotoError::unhandled(CreatePublicKeyError::Unhandled, &res),
                 }
             }
         }
@@ -9360,12 +9397,14 @@ impl fmt::Display for CreatePublicKeyError {
             CreatePublicKeyError::InvalidArgument(ref cause) => write!(f, "{}", cause),
             CreatePublicKeyError::PublicKeyAlreadyExists(ref cause) => write!(f, "{}", cause),
             CreatePublicKeyError::TooManyPublicKeys(ref cause) => write!(f, "{}", cause),
+            CreatePublicKeyError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreatePublicKeyError {}
 /// Errors returned by CreateStreamingDistribution
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateStreamingDistributionError {
     /// <p>Access denied.</p>
     AccessDenied(String),
@@ -9391,6 +9430,8 @@ pub enum CreateStreamingDistributionError {
     TooManyTrustedSigners(String),
     /// <p>One or more of your trusted signers don't exist.</p>
     TrustedSignerDoesNotExist(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateStreamingDistributionError {
@@ -9404,82 +9445,99 @@ impl CreateStreamingDistributionError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "AccessDenied" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             CreateStreamingDistributionError::AccessDenied(parsed_error.message),
+                            &res,
                         )
                     }
                     "CNAMEAlreadyExists" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             CreateStreamingDistributionError::CNAMEAlreadyExists(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "InconsistentQuantities" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             CreateStreamingDistributionError::InconsistentQuantities(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "InvalidArgument" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             CreateStreamingDistributionError::InvalidArgument(parsed_error.message),
+                            &res,
                         )
                     }
                     "InvalidOrigin" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             CreateStreamingDistributionError::InvalidOrigin(parsed_error.message),
+                            &res,
                         )
                     }
                     "InvalidOriginAccessIdentity" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             CreateStreamingDistributionError::InvalidOriginAccessIdentity(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "MissingBody" => {
-                        return RusotoError::Service(CreateStreamingDistributionError::MissingBody(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            CreateStreamingDistributionError::MissingBody(parsed_error.message),
+                            &res,
+                        )
                     }
                     "StreamingDistributionAlreadyExists" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             CreateStreamingDistributionError::StreamingDistributionAlreadyExists(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "TooManyStreamingDistributionCNAMEs" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             CreateStreamingDistributionError::TooManyStreamingDistributionCNAMEs(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "TooManyStreamingDistributions" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             CreateStreamingDistributionError::TooManyStreamingDistributions(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "TooManyTrustedSigners" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             CreateStreamingDistributionError::TooManyTrustedSigners(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "TrustedSignerDoesNotExist" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             CreateStreamingDistributionError::TrustedSignerDoesNotExist(
                                 parsed_error.message,
                             ),
+                            &res,
+                        )
+                    }
+                    _ => {
+                        return RusotoError::unhandled(
+                            CreateStreamingDistributionError::Unhandled,
+                            &res,
                         )
                     }
-                    _ => {}
                 }
             }
         }
@@ -9526,12 +9584,14 @@ impl fmt::Display for CreateStreamingDistributionError {
             CreateStreamingDistributionError::TrustedSignerDoesNotExist(ref cause) => {
                 write!(f, "{}", cause)
             }
+            CreateStreamingDistributionError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateStreamingDistributionError {}
 /// Errors returned by CreateStreamingDistributionWithTags
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateStreamingDistributionWithTagsError {
     /// <p>Access denied.</p>
     AccessDenied(String),
@@ -9559,6 +9619,8 @@ pub enum CreateStreamingDistributionWithTagsError {
     TooManyTrustedSigners(String),
     /// <p>One or more of your trusted signers don't exist.</p>
     TrustedSignerDoesNotExist(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateStreamingDistributionWithTagsError {
@@ -9571,7 +9633,7 @@ impl CreateStreamingDistributionWithTagsError {
             find_start_element(&mut stack);
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
-                                    "AccessDenied" => return RusotoError::Service(CreateStreamingDistributionWithTagsError::AccessDenied(parsed_error.message)),"CNAMEAlreadyExists" => return RusotoError::Service(CreateStreamingDistributionWithTagsError::CNAMEAlreadyExists(parsed_error.message)),"InconsistentQuantities" => return RusotoError::Service(CreateStreamingDistributionWithTagsError::InconsistentQuantities(parsed_error.message)),"InvalidArgument" => return RusotoError::Service(CreateStreamingDistributionWithTagsError::InvalidArgument(parsed_error.message)),"InvalidOrigin" => return RusotoError::Service(CreateStreamingDistributionWithTagsError::InvalidOrigin(parsed_error.message)),"InvalidOriginAccessIdentity" => return RusotoError::Service(CreateStreamingDistributionWithTagsError::InvalidOriginAccessIdentity(parsed_error.message)),"InvalidTagging" => return RusotoError::Service(CreateStreamingDistributionWithTagsError::InvalidTagging(parsed_error.message)),"MissingBody" => return RusotoError::Service(CreateStreamingDistributionWithTagsError::MissingBody(parsed_error.message)),"StreamingDistributionAlreadyExists" => return RusotoError::Service(CreateStreamingDistributionWithTagsError::StreamingDistributionAlreadyExists(parsed_error.message)),"TooManyStreamingDistributionCNAMEs" => return RusotoError::Service(CreateStreamingDistributionWithTagsError::TooManyStreamingDistributionCNAMEs(parsed_error.message)),"TooManyStreamingDistributions" => return RusotoError::Service(CreateStreamingDistributionWithTagsError::TooManyStreamingDistributions(parsed_error.message)),"TooManyTrustedSigners" => return RusotoError::Service(CreateStreamingDistributionWithTagsError::TooManyTrustedSigners(parsed_error.message)),"TrustedSignerDoesNotExist" => return RusotoError::Service(CreateStreamingDistributionWithTagsError::TrustedSignerDoesNotExist(parsed_error.message)),_ => {}
+                                    "AccessDenied" => return RusotoError::service(CreateStreamingDistributionWithTagsError::AccessDenied(parsed_error.message), &res),"CNAMEAlreadyExists" => return RusotoError::service(CreateStreamingDistributionWithTagsError::CNAMEAlreadyExists(parsed_error.message), &res),"InconsistentQuantities" => return RusotoError::service(CreateStreamingDistributionWithTagsError::InconsistentQuantities(parsed_error.message), &res),"InvalidArgument" => return RusotoError::service(CreateStreamingDistributionWithTagsError::InvalidArgument(parsed_error.message), &res),"InvalidOrigin" => return RusotoError::service(CreateStreamingDistributionWithTagsError::InvalidOrigin(parsed_error.message), &res),"InvalidOriginAccessIdentity" => return RusotoError::service(CreateStreamingDistributionWithTagsError::InvalidOriginAccessIdentity(parsed_error.message), &res),"InvalidTagging" => return RusotoError::service(CreateStreamingDistributionWithTagsError::InvalidTagging(parsed_error.message), &res),"MissingBody" => return RusotoError::service(CreateStreamingDistributionWithTagsError::MissingBody(parsed_error.message), &res),"StreamingDistributionAlreadyExists" => return RusotoError::service(CreateStreamingDistributionWithTagsError::StreamingDistributionAlreadyExists(parsed_error.message), &res),"TooManyStreamingDistributionCNAMEs" => return RusotoError::service(CreateStreamingDistributionWithTagsError::TooManyStreamingDistributionCNAMEs(parsed_error.message), &res),"TooManyStreamingDistributions" => return RusotoError::service(CreateStreamingDistributionWithTagsError::TooManyStreamingDistributions(parsed_error.message), &res),"TooManyTrustedSigners" => return RusotoError::service(CreateStreamingDistributionWithTagsError::TooManyTrustedSigners(parsed_error.message), &res),"TrustedSignerDoesNotExist" => return RusotoError::service(CreateStreamingDistributionWithTagsError::TrustedSignerDoesNotExist(parsed_error.message), &res),_ => return RusotoError::unhandled(CreateStreamingDistributionWithTagsError::Unhandled, &res),
                                 }
             }
         }
@@ -9629,12 +9691,16 @@ impl fmt::Display for CreateStreamingDistributionWithTagsError {
             CreateStreamingDistributionWithTagsError::TrustedSignerDoesNotExist(ref cause) => {
                 write!(f, "{}", cause)
             }
+            CreateStreamingDistributionWithTagsError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for CreateStreamingDistributionWithTagsError {}
 /// Errors returned by DeleteCloudFrontOriginAccessIdentity
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteCloudFrontOriginAccessIdentityError {
     /// <p>Access denied.</p>
     AccessDenied(String),
@@ -9646,6 +9712,8 @@ pub enum DeleteCloudFrontOriginAccessIdentityError {
     NoSuchCloudFrontOriginAccessIdentity(String),
     /// <p>The precondition given in one or more of the request-header fields evaluated to <code>false</code>. </p>
     PreconditionFailed(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteCloudFrontOriginAccessIdentityError {
@@ -9658,7 +9726,7 @@ impl DeleteCloudFrontOriginAccessIdentityError {
             find_start_element(&mut stack);
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
-                                    "AccessDenied" => return RusotoError::Service(DeleteCloudFrontOriginAccessIdentityError::AccessDenied(parsed_error.message)),"CloudFrontOriginAccessIdentityInUse" => return RusotoError::Service(DeleteCloudFrontOriginAccessIdentityError::CloudFrontOriginAccessIdentityInUse(parsed_error.message)),"InvalidIfMatchVersion" => return RusotoError::Service(DeleteCloudFrontOriginAccessIdentityError::InvalidIfMatchVersion(parsed_error.message)),"NoSuchCloudFrontOriginAccessIdentity" => return RusotoError::Service(DeleteCloudFrontOriginAccessIdentityError::NoSuchCloudFrontOriginAccessIdentity(parsed_error.message)),"PreconditionFailed" => return RusotoError::Service(DeleteCloudFrontOriginAccessIdentityError::PreconditionFailed(parsed_error.message)),_ => {}
+                                    "AccessDenied" => return RusotoError::service(DeleteCloudFrontOriginAccessIdentityError::AccessDenied(parsed_error.message), &res),"CloudFrontOriginAccessIdentityInUse" => return RusotoError::service(DeleteCloudFrontOriginAccessIdentityError::CloudFrontOriginAccessIdentityInUse(parsed_error.message), &res),"InvalidIfMatchVersion" => return RusotoError::service(DeleteCloudFrontOriginAccessIdentityError::InvalidIfMatchVersion(parsed_error.message), &res),"NoSuchCloudFrontOriginAccessIdentity" => return RusotoError::service(DeleteCloudFrontOriginAccessIdentityError::NoSuchCloudFrontOriginAccessIdentity(parsed_error.message), &res),"PreconditionFailed" => return RusotoError::service(DeleteCloudFrontOriginAccessIdentityError::PreconditionFailed(parsed_error.message), &res),_ => return RusotoError::unhandled(DeleteCloudFrontOriginAccessIdentityError::Unhandled, &res),
                                 }
             }
         }
@@ -9692,12 +9760,16 @@ impl fmt::Display for DeleteCloudFrontOriginAccessIdentityError {
             DeleteCloudFrontOriginAccessIdentityError::PreconditionFailed(ref cause) => {
                 write!(f, "{}", cause)
             }
+            DeleteCloudFrontOriginAccessIdentityError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for DeleteCloudFrontOriginAccessIdentityError {}
 /// Errors returned by DeleteDistribution
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteDistributionError {
     /// <p>Access denied.</p>
     AccessDenied(String),
@@ -9709,6 +9781,8 @@ pub enum DeleteDistributionError {
     NoSuchDistribution(String),
     /// <p>The precondition given in one or more of the request-header fields evaluated to <code>false</code>. </p>
     PreconditionFailed(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteDistributionError {
@@ -9720,31 +9794,36 @@ impl DeleteDistributionError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "AccessDenied" => {
-                        return RusotoError::Service(DeleteDistributionError::AccessDenied(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            DeleteDistributionError::AccessDenied(parsed_error.message),
+                            &res,
+                        )
                     }
                     "DistributionNotDisabled" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             DeleteDistributionError::DistributionNotDisabled(parsed_error.message),
+                            &res,
                         )
                     }
                     "InvalidIfMatchVersion" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             DeleteDistributionError::InvalidIfMatchVersion(parsed_error.message),
+                            &res,
                         )
                     }
                     "NoSuchDistribution" => {
-                        return RusotoError::Service(DeleteDistributionError::NoSuchDistribution(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            DeleteDistributionError::NoSuchDistribution(parsed_error.message),
+                            &res,
+                        )
                     }
                     "PreconditionFailed" => {
-                        return RusotoError::Service(DeleteDistributionError::PreconditionFailed(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            DeleteDistributionError::PreconditionFailed(parsed_error.message),
+                            &res,
+                        )
                     }
-                    _ => {}
+                    _ => return RusotoError::unhandled(DeleteDistributionError::Unhandled, &res),
                 }
             }
         }
@@ -9768,12 +9847,14 @@ impl fmt::Display for DeleteDistributionError {
             DeleteDistributionError::InvalidIfMatchVersion(ref cause) => write!(f, "{}", cause),
             DeleteDistributionError::NoSuchDistribution(ref cause) => write!(f, "{}", cause),
             DeleteDistributionError::PreconditionFailed(ref cause) => write!(f, "{}", cause),
+            DeleteDistributionError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteDistributionError {}
 /// Errors returned by DeleteFieldLevelEncryptionConfig
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteFieldLevelEncryptionConfigError {
     /// <p>Access denied.</p>
     AccessDenied(String),
@@ -9785,6 +9866,8 @@ pub enum DeleteFieldLevelEncryptionConfigError {
     NoSuchFieldLevelEncryptionConfig(String),
     /// <p>The precondition given in one or more of the request-header fields evaluated to <code>false</code>. </p>
     PreconditionFailed(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteFieldLevelEncryptionConfigError {
@@ -9798,41 +9881,51 @@ impl DeleteFieldLevelEncryptionConfigError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "AccessDenied" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             DeleteFieldLevelEncryptionConfigError::AccessDenied(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "FieldLevelEncryptionConfigInUse" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             DeleteFieldLevelEncryptionConfigError::FieldLevelEncryptionConfigInUse(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "InvalidIfMatchVersion" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             DeleteFieldLevelEncryptionConfigError::InvalidIfMatchVersion(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "NoSuchFieldLevelEncryptionConfig" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             DeleteFieldLevelEncryptionConfigError::NoSuchFieldLevelEncryptionConfig(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "PreconditionFailed" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             DeleteFieldLevelEncryptionConfigError::PreconditionFailed(
                                 parsed_error.message,
                             ),
+                            &res,
+                        )
+                    }
+                    _ => {
+                        return RusotoError::unhandled(
+                            DeleteFieldLevelEncryptionConfigError::Unhandled,
+                            &res,
                         )
                     }
-                    _ => {}
                 }
             }
         }
@@ -9866,12 +9959,16 @@ impl fmt::Display for DeleteFieldLevelEncryptionConfigError {
             DeleteFieldLevelEncryptionConfigError::PreconditionFailed(ref cause) => {
                 write!(f, "{}", cause)
             }
+            DeleteFieldLevelEncryptionConfigError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for DeleteFieldLevelEncryptionConfigError {}
 /// Errors returned by DeleteFieldLevelEncryptionProfile
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteFieldLevelEncryptionProfileError {
     /// <p>Access denied.</p>
     AccessDenied(String),
@@ -9883,6 +9980,8 @@ pub enum DeleteFieldLevelEncryptionProfileError {
     NoSuchFieldLevelEncryptionProfile(String),
     /// <p>The precondition given in one or more of the request-header fields evaluated to <code>false</code>. </p>
     PreconditionFailed(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteFieldLevelEncryptionProfileError {
@@ -9896,37 +9995,47 @@ impl DeleteFieldLevelEncryptionProfileError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "AccessDenied" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             DeleteFieldLevelEncryptionProfileError::AccessDenied(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
-                    "FieldLevelEncryptionProfileInUse" => return RusotoError::Service(
+                    "FieldLevelEncryptionProfileInUse" => return RusotoError::service(
                         DeleteFieldLevelEncryptionProfileError::FieldLevelEncryptionProfileInUse(
                             parsed_error.message,
                         ),
+                        &res,
                     ),
                     "InvalidIfMatchVersion" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             DeleteFieldLevelEncryptionProfileError::InvalidIfMatchVersion(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
-                    "NoSuchFieldLevelEncryptionProfile" => return RusotoError::Service(
+                    "NoSuchFieldLevelEncryptionProfile" => return RusotoError::service(
                         DeleteFieldLevelEncryptionProfileError::NoSuchFieldLevelEncryptionProfile(
                             parsed_error.message,
                         ),
+                        &res,
                     ),
                     "PreconditionFailed" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             DeleteFieldLevelEncryptionProfileError::PreconditionFailed(
                                 parsed_error.message,
                             ),
+                            &res,
+                        )
+                    }
+                    _ => {
+                        return RusotoError::unhandled(
+                            DeleteFieldLevelEncryptionProfileError::Unhandled,
+                            &res,
                         )
                     }
-                    _ => {}
                 }
             }
         }
@@ -9960,12 +10069,16 @@ impl fmt::Display for DeleteFieldLevelEncryptionProfileError {
             DeleteFieldLevelEncryptionProfileError::PreconditionFailed(ref cause) => {
                 write!(f, "{}", cause)
             }
+            DeleteFieldLevelEncryptionProfileError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for DeleteFieldLevelEncryptionProfileError {}
 /// Errors returned by DeletePublicKey
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeletePublicKeyError {
     /// <p>Access denied.</p>
     AccessDenied(String),
@@ -9977,6 +10090,8 @@ pub enum DeletePublicKeyError {
     PreconditionFailed(String),
     /// <p>The specified public key is in use. </p>
     PublicKeyInUse(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeletePublicKeyError {
@@ -9988,31 +10103,36 @@ impl DeletePublicKeyError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "AccessDenied" => {
-                        return RusotoError::Service(DeletePublicKeyError::AccessDenied(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            DeletePublicKeyError::AccessDenied(parsed_error.message),
+                            &res,
+                        )
                     }
                     "InvalidIfMatchVersion" => {
-                        return RusotoError::Service(DeletePublicKeyError::InvalidIfMatchVersion(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            DeletePublicKeyError::InvalidIfMatchVersion(parsed_error.message),
+                            &res,
+                        )
                     }
                     "NoSuchPublicKey" => {
-                        return RusotoError::Service(DeletePublicKeyError::NoSuchPublicKey(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            DeletePublicKeyError::NoSuchPublicKey(parsed_error.message),
+                            &res,
+                        )
                     }
                     "PreconditionFailed" => {
-                        return RusotoError::Service(DeletePublicKeyError::PreconditionFailed(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            DeletePublicKeyError::PreconditionFailed(parsed_error.message),
+                            &res,
+                        )
                     }
                     "PublicKeyInUse" => {
-                        return RusotoError::Service(DeletePublicKeyError::PublicKeyInUse(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            DeletePublicKeyError::PublicKeyInUse(parsed_error.message),
+                            &res,
+                        )
                     }
-                    _ => {}
+                    _ => return RusotoError::unhandled(DeletePublicKeyError::Unhandled, &res),
                 }
             }
         }
@@ -10036,12 +10156,14 @@ impl fmt::Display for DeletePublicKeyError {
             DeletePublicKeyError::NoSuchPublicKey(ref cause) => write!(f, "{}", cause),
             DeletePublicKeyError::PreconditionFailed(ref cause) => write!(f, "{}", cause),
             DeletePublicKeyError::PublicKeyInUse(ref cause) => write!(f, "{}", cause),
+            DeletePublicKeyError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeletePublicKeyError {}
 /// Errors returned by DeleteStreamingDistribution
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteStreamingDistributionError {
     /// <p>Access denied.</p>
     AccessDenied(String),
@@ -10053,6 +10175,8 @@ pub enum DeleteStreamingDistributionError {
     PreconditionFailed(String),
     /// <p>The specified CloudFront distribution is not disabled. You must disable the distribution before you can delete it.</p>
     StreamingDistributionNotDisabled(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteStreamingDistributionError {
@@ -10066,39 +10190,49 @@ impl DeleteStreamingDistributionError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "AccessDenied" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             DeleteStreamingDistributionError::AccessDenied(parsed_error.message),
+                            &res,
                         )
                     }
                     "InvalidIfMatchVersion" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             DeleteStreamingDistributionError::InvalidIfMatchVersion(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "NoSuchStreamingDistribution" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             DeleteStreamingDistributionError::NoSuchStreamingDistribution(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "PreconditionFailed" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             DeleteStreamingDistributionError::PreconditionFailed(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "StreamingDistributionNotDisabled" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             DeleteStreamingDistributionError::StreamingDistributionNotDisabled(
                                 parsed_error.message,
                             ),
+                            &res,
+                        )
+                    }
+                    _ => {
+                        return RusotoError::unhandled(
+                            DeleteStreamingDistributionError::Unhandled,
+                            &res,
                         )
                     }
-                    _ => {}
                 }
             }
         }
@@ -10130,17 +10264,21 @@ impl fmt::Display for DeleteStreamingDistributionError {
             DeleteStreamingDistributionError::StreamingDistributionNotDisabled(ref cause) => {
                 write!(f, "{}", cause)
             }
+            DeleteStreamingDistributionError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteStreamingDistributionError {}
 /// Errors returned by GetCloudFrontOriginAccessIdentity
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetCloudFrontOriginAccessIdentityError {
     /// <p>Access denied.</p>
     AccessDenied(String),
     /// <p>The specified origin access identity does not exist.</p>
     NoSuchCloudFrontOriginAccessIdentity(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetCloudFrontOriginAccessIdentityError {
@@ -10153,7 +10291,7 @@ impl GetCloudFrontOriginAccessIdentityError {
             find_start_element(&mut stack);
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
-                                    "AccessDenied" => return RusotoError::Service(GetCloudFrontOriginAccessIdentityError::AccessDenied(parsed_error.message)),"NoSuchCloudFrontOriginAccessIdentity" => return RusotoError::Service(GetCloudFrontOriginAccessIdentityError::NoSuchCloudFrontOriginAccessIdentity(parsed_error.message)),_ => {}
+                                    "AccessDenied" => return RusotoError::service(GetCloudFrontOriginAccessIdentityError::AccessDenied(parsed_error.message), &res),"NoSuchCloudFrontOriginAccessIdentity" => return RusotoError::service(GetCloudFrontOriginAccessIdentityError::NoSuchCloudFrontOriginAccessIdentity(parsed_error.message), &res),_ => return RusotoError::unhandled(GetCloudFrontOriginAccessIdentityError::Unhandled, &res),
                                 }
             }
         }
@@ -10178,17 +10316,23 @@ impl fmt::Display for GetCloudFrontOriginAccessIdentityError {
             GetCloudFrontOriginAccessIdentityError::NoSuchCloudFrontOriginAccessIdentity(
                 ref cause,
             ) => write!(f, "{}", cause),
+            GetCloudFrontOriginAccessIdentityError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for GetCloudFrontOriginAccessIdentityError {}
 /// Errors returned by GetCloudFrontOriginAccessIdentityConfig
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetCloudFrontOriginAccessIdentityConfigError {
     /// <p>Access denied.</p>
     AccessDenied(String),
     /// <p>The specified origin access identity does not exist.</p>
     NoSuchCloudFrontOriginAccessIdentity(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetCloudFrontOriginAccessIdentityConfigError {
@@ -10201,7 +10345,7 @@ impl GetCloudFrontOriginAccessIdentityConfigError {
             find_start_element(&mut stack);
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
-                                    "AccessDenied" => return RusotoError::Service(GetCloudFrontOriginAccessIdentityConfigError::AccessDenied(parsed_error.message)),"NoSuchCloudFrontOriginAccessIdentity" => return RusotoError::Service(GetCloudFrontOriginAccessIdentityConfigError::NoSuchCloudFrontOriginAccessIdentity(parsed_error.message)),_ => {}
+                                    "AccessDenied" => return RusotoError::service(GetCloudFrontOriginAccessIdentityConfigError::AccessDenied(parsed_error.message), &res),"NoSuchCloudFrontOriginAccessIdentity" => return RusotoError::service(GetCloudFrontOriginAccessIdentityConfigError::NoSuchCloudFrontOriginAccessIdentity(parsed_error.message), &res),_ => return RusotoError::unhandled(GetCloudFrontOriginAccessIdentityConfigError::Unhandled, &res),
                                 }
             }
         }
@@ -10226,17 +10370,23 @@ impl fmt::Display for GetCloudFrontOriginAccessIdentityConfigError {
             GetCloudFrontOriginAccessIdentityConfigError::NoSuchCloudFrontOriginAccessIdentity(
                 ref cause,
             ) => write!(f, "{}", cause),
+            GetCloudFrontOriginAccessIdentityConfigError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for GetCloudFrontOriginAccessIdentityConfigError {}
 /// Errors returned by GetDistribution
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetDistributionError {
     /// <p>Access denied.</p>
     AccessDenied(String),
     /// <p>The specified distribution does not exist.</p>
     NoSuchDistribution(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetDistributionError {
@@ -10248,16 +10398,18 @@ impl GetDistributionError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "AccessDenied" => {
-                        return RusotoError::Service(GetDistributionError::AccessDenied(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            GetDistributionError::AccessDenied(parsed_error.message),
+                            &res,
+                        )
                     }
                     "NoSuchDistribution" => {
-                        return RusotoError::Service(GetDistributionError::NoSuchDistribution(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            GetDistributionError::NoSuchDistribution(parsed_error.message),
+                            &res,
+                        )
                     }
-                    _ => {}
+                    _ => return RusotoError::unhandled(GetDistributionError::Unhandled, &res),
                 }
             }
         }
@@ -10278,17 +10430,21 @@ impl fmt::Display for GetDistributionError {
         match *self {
             GetDistributionError::AccessDenied(ref cause) => write!(f, "{}", cause),
             GetDistributionError::NoSuchDistribution(ref cause) => write!(f, "{}", cause),
+            GetDistributionError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetDistributionError {}
 /// Errors returned by GetDistributionConfig
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetDistributionConfigError {
     /// <p>Access denied.</p>
     AccessDenied(String),
     /// <p>The specified distribution does not exist.</p>
     NoSuchDistribution(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetDistributionConfigError {
@@ -10300,16 +10456,20 @@ impl GetDistributionConfigError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "AccessDenied" => {
-                        return RusotoError::Service(GetDistributionConfigError::AccessDenied(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            GetDistributionConfigError::AccessDenied(parsed_error.message),
+                            &res,
+                        )
                     }
                     "NoSuchDistribution" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             GetDistributionConfigError::NoSuchDistribution(parsed_error.message),
+                            &res,
                         )
                     }
-                    _ => {}
+                    _ => {
+                        return RusotoError::unhandled(GetDistributionConfigError::Unhandled, &res)
+                    }
                 }
             }
         }
@@ -10330,17 +10490,21 @@ impl fmt::Display for GetDistributionConfigError {
         match *self {
             GetDistributionConfigError::AccessDenied(ref cause) => write!(f, "{}", cause),
             GetDistributionConfigError::NoSuchDistribution(ref cause) => write!(f, "{}", cause),
+            GetDistributionConfigError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetDistributionConfigError {}
 /// Errors returned by GetFieldLevelEncryption
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetFieldLevelEncryptionError {
     /// <p>Access denied.</p>
     AccessDenied(String),
     /// <p>The specified configuration for field-level encryption doesn't exist.</p>
     NoSuchFieldLevelEncryptionConfig(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetFieldLevelEncryptionError {
@@ -10352,18 +10516,25 @@ impl GetFieldLevelEncryptionError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "AccessDenied" => {
-                        return RusotoError::Service(GetFieldLevelEncryptionError::AccessDenied(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            GetFieldLevelEncryptionError::AccessDenied(parsed_error.message),
+                            &res,
+                        )
                     }
                     "NoSuchFieldLevelEncryptionConfig" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             GetFieldLevelEncryptionError::NoSuchFieldLevelEncryptionConfig(
                                 parsed_error.message,
                             ),
+                            &res,
+                        )
+                    }
+                    _ => {
+                        return RusotoError::unhandled(
+                            GetFieldLevelEncryptionError::Unhandled,
+                            &res,
                         )
                     }
-                    _ => {}
                 }
             }
         }
@@ -10386,17 +10557,21 @@ impl fmt::Display for GetFieldLevelEncryptionError {
             GetFieldLevelEncryptionError::NoSuchFieldLevelEncryptionConfig(ref cause) => {
                 write!(f, "{}", cause)
             }
+            GetFieldLevelEncryptionError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetFieldLevelEncryptionError {}
 /// Errors returned by GetFieldLevelEncryptionConfig
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetFieldLevelEncryptionConfigError {
     /// <p>Access denied.</p>
     AccessDenied(String),
     /// <p>The specified configuration for field-level encryption doesn't exist.</p>
     NoSuchFieldLevelEncryptionConfig(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetFieldLevelEncryptionConfigError {
@@ -10410,18 +10585,25 @@ impl GetFieldLevelEncryptionConfigError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "AccessDenied" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             GetFieldLevelEncryptionConfigError::AccessDenied(parsed_error.message),
+                            &res,
                         )
                     }
                     "NoSuchFieldLevelEncryptionConfig" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             GetFieldLevelEncryptionConfigError::NoSuchFieldLevelEncryptionConfig(
                                 parsed_error.message,
                             ),
+                            &res,
+                        )
+                    }
+                    _ => {
+                        return RusotoError::unhandled(
+                            GetFieldLevelEncryptionConfigError::Unhandled,
+                            &res,
                         )
                     }
-                    _ => {}
                 }
             }
         }
@@ -10444,17 +10626,23 @@ impl fmt::Display for GetFieldLevelEncryptionConfigError {
             GetFieldLevelEncryptionConfigError::NoSuchFieldLevelEncryptionConfig(ref cause) => {
                 write!(f, "{}", cause)
             }
+            GetFieldLevelEncryptionConfigError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for GetFieldLevelEncryptionConfigError {}
 /// Errors returned by GetFieldLevelEncryptionProfile
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetFieldLevelEncryptionProfileError {
     /// <p>Access denied.</p>
     AccessDenied(String),
     /// <p>The specified profile for field-level encryption doesn't exist.</p>
     NoSuchFieldLevelEncryptionProfile(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetFieldLevelEncryptionProfileError {
@@ -10468,18 +10656,25 @@ impl GetFieldLevelEncryptionProfileError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "AccessDenied" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             GetFieldLevelEncryptionProfileError::AccessDenied(parsed_error.message),
+                            &res,
                         )
                     }
                     "NoSuchFieldLevelEncryptionProfile" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             GetFieldLevelEncryptionProfileError::NoSuchFieldLevelEncryptionProfile(
                                 parsed_error.message,
                             ),
+                            &res,
+                        )
+                    }
+                    _ => {
+                        return RusotoError::unhandled(
+                            GetFieldLevelEncryptionProfileError::Unhandled,
+                            &res,
                         )
                     }
-                    _ => {}
                 }
             }
         }
@@ -10502,17 +10697,23 @@ impl fmt::Display for GetFieldLevelEncryptionProfileError {
             GetFieldLevelEncryptionProfileError::NoSuchFieldLevelEncryptionProfile(ref cause) => {
                 write!(f, "{}", cause)
             }
+            GetFieldLevelEncryptionProfileError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for GetFieldLevelEncryptionProfileError {}
 /// Errors returned by GetFieldLevelEncryptionProfileConfig
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetFieldLevelEncryptionProfileConfigError {
     /// <p>Access denied.</p>
     AccessDenied(String),
     /// <p>The specified profile for field-level encryption doesn't exist.</p>
     NoSuchFieldLevelEncryptionProfile(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetFieldLevelEncryptionProfileConfigError {
@@ -10525,7 +10726,7 @@ impl GetFieldLevelEncryptionProfileConfigError {
             find_start_element(&mut stack);
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
-                                    "AccessDenied" => return RusotoError::Service(GetFieldLevelEncryptionProfileConfigError::AccessDenied(parsed_error.message)),"NoSuchFieldLevelEncryptionProfile" => return RusotoError::Service(GetFieldLevelEncryptionProfileConfigError::NoSuchFieldLevelEncryptionProfile(parsed_error.message)),_ => {}
+                                    "AccessDenied" => return RusotoError::service(GetFieldLevelEncryptionProfileConfigError::AccessDenied(parsed_error.message), &res),"NoSuchFieldLevelEncryptionProfile" => return RusotoError::service(GetFieldLevelEncryptionProfileConfigError::NoSuchFieldLevelEncryptionProfile(parsed_error.message), &res),_ => return RusotoError::unhandled(GetFieldLevelEncryptionProfileConfigError::Unhandled, &res),
                                 }
             }
         }
@@ -10550,12 +10751,16 @@ impl fmt::Display for GetFieldLevelEncryptionProfileConfigError {
             GetFieldLevelEncryptionProfileConfigError::NoSuchFieldLevelEncryptionProfile(
                 ref cause,
             ) => write!(f, "{}", cause),
+            GetFieldLevelEncryptionProfileConfigError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for GetFieldLevelEncryptionProfileConfigError {}
 /// Errors returned by GetInvalidation
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetInvalidationError {
     /// <p>Access denied.</p>
     AccessDenied(String),
@@ -10563,6 +10768,8 @@ pub enum GetInvalidationError {
     NoSuchDistribution(String),
     /// <p>The specified invalidation does not exist.</p>
     NoSuchInvalidation(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetInvalidationError {
@@ -10574,21 +10781,24 @@ impl GetInvalidationError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "AccessDenied" => {
-                        return RusotoError::Service(GetInvalidationError::AccessDenied(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            GetInvalidationError::AccessDenied(parsed_error.message),
+                            &res,
+                        )
                     }
                     "NoSuchDistribution" => {
-                        return RusotoError::Service(GetInvalidationError::NoSuchDistribution(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            GetInvalidationError::NoSuchDistribution(parsed_error.message),
+                            &res,
+                        )
                     }
                     "NoSuchInvalidation" => {
-                        return RusotoError::Service(GetInvalidationError::NoSuchInvalidation(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            GetInvalidationError::NoSuchInvalidation(parsed_error.message),
+                            &res,
+                        )
                     }
-                    _ => {}
+                    _ => return RusotoError::unhandled(GetInvalidationError::Unhandled, &res),
                 }
             }
         }
@@ -10610,17 +10820,21 @@ impl fmt::Display for GetInvalidationError {
             GetInvalidationError::AccessDenied(ref cause) => write!(f, "{}", cause),
             GetInvalidationError::NoSuchDistribution(ref cause) => write!(f, "{}", cause),
             GetInvalidationError::NoSuchInvalidation(ref cause) => write!(f, "{}", cause),
+            GetInvalidationError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetInvalidationError {}
 /// Errors returned by GetPublicKey
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetPublicKeyError {
     /// <p>Access denied.</p>
     AccessDenied(String),
     /// <p>The specified public key doesn't exist.</p>
     NoSuchPublicKey(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetPublicKeyError {
@@ -10632,16 +10846,18 @@ impl GetPublicKeyError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "AccessDenied" => {
-                        return RusotoError::Service(GetPublicKeyError::AccessDenied(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            GetPublicKeyError::AccessDenied(parsed_error.message),
+                            &res,
+                        )
                     }
                     "NoSuchPublicKey" => {
-                        return RusotoError::Service(GetPublicKeyError::NoSuchPublicKey(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            GetPublicKeyError::NoSuchPublicKey(parsed_error.message),
+                            &res,
+                        )
                     }
-                    _ => {}
+                    _ => return RusotoError::unhandled(GetPublicKeyError::Unhandled, &res),
                 }
             }
         }
@@ -10662,17 +10878,21 @@ impl fmt::Display for GetPublicKeyError {
         match *self {
             GetPublicKeyError::AccessDenied(ref cause) => write!(f, "{}", cause),
             GetPublicKeyError::NoSuchPublicKey(ref cause) => write!(f, "{}", cause),
+            GetPublicKeyError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetPublicKeyError {}
 /// Errors returned by GetPublicKeyConfig
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetPublicKeyConfigError {
     /// <p>Access denied.</p>
     AccessDenied(String),
     /// <p>The specified public key doesn't exist.</p>
     NoSuchPublicKey(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetPublicKeyConfigError {
@@ -10684,16 +10904,18 @@ impl GetPublicKeyConfigError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "AccessDenied" => {
-                        return RusotoError::Service(GetPublicKeyConfigError::AccessDenied(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            GetPublicKeyConfigError::AccessDenied(parsed_error.message),
+                            &res,
+                        )
                     }
                     "NoSuchPublicKey" => {
-                        return RusotoError::Service(GetPublicKeyConfigError::NoSuchPublicKey(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            GetPublicKeyConfigError::NoSuchPublicKey(parsed_error.message),
+                            &res,
+                        )
                     }
-                    _ => {}
+                    _ => return RusotoError::unhandled(GetPublicKeyConfigError::Unhandled, &res),
                 }
             }
         }
@@ -10714,17 +10936,21 @@ impl fmt::Display for GetPublicKeyConfigError {
         match *self {
             GetPublicKeyConfigError::AccessDenied(ref cause) => write!(f, "{}", cause),
             GetPublicKeyConfigError::NoSuchPublicKey(ref cause) => write!(f, "{}", cause),
+            GetPublicKeyConfigError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetPublicKeyConfigError {}
 /// Errors returned by GetStreamingDistribution
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetStreamingDistributionError {
     /// <p>Access denied.</p>
     AccessDenied(String),
     /// <p>The specified streaming distribution does not exist.</p>
     NoSuchStreamingDistribution(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetStreamingDistributionError {
@@ -10736,18 +10962,25 @@ impl GetStreamingDistributionError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "AccessDenied" => {
-                        return RusotoError::Service(GetStreamingDistributionError::AccessDenied(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            GetStreamingDistributionError::AccessDenied(parsed_error.message),
+                            &res,
+                        )
                     }
                     "NoSuchStreamingDistribution" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             GetStreamingDistributionError::NoSuchStreamingDistribution(
                                 parsed_error.message,
                             ),
+                            &res,
+                        )
+                    }
+                    _ => {
+                        return RusotoError::unhandled(
+                            GetStreamingDistributionError::Unhandled,
+                            &res,
                         )
                     }
-                    _ => {}
                 }
             }
         }
@@ -10770,17 +11003,21 @@ impl fmt::Display for GetStreamingDistributionError {
             GetStreamingDistributionError::NoSuchStreamingDistribution(ref cause) => {
                 write!(f, "{}", cause)
             }
+            GetStreamingDistributionError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetStreamingDistributionError {}
 /// Errors returned by GetStreamingDistributionConfig
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetStreamingDistributionConfigError {
     /// <p>Access denied.</p>
     AccessDenied(String),
     /// <p>The specified streaming distribution does not exist.</p>
     NoSuchStreamingDistribution(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetStreamingDistributionConfigError {
@@ -10794,18 +11031,25 @@ impl GetStreamingDistributionConfigError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "AccessDenied" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             GetStreamingDistributionConfigError::AccessDenied(parsed_error.message),
+                            &res,
                         )
                     }
                     "NoSuchStreamingDistribution" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             GetStreamingDistributionConfigError::NoSuchStreamingDistribution(
                                 parsed_error.message,
                             ),
+                            &res,
+                        )
+                    }
+                    _ => {
+                        return RusotoError::unhandled(
+                            GetStreamingDistributionConfigError::Unhandled,
+                            &res,
                         )
                     }
-                    _ => {}
                 }
             }
         }
@@ -10828,15 +11072,21 @@ impl fmt::Display for GetStreamingDistributionConfigError {
             GetStreamingDistributionConfigError::NoSuchStreamingDistribution(ref cause) => {
                 write!(f, "{}", cause)
             }
+            GetStreamingDistributionConfigError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for GetStreamingDistributionConfigError {}
 /// Errors returned by ListCloudFrontOriginAccessIdentities
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListCloudFrontOriginAccessIdentitiesError {
     /// <p>The argument is invalid.</p>
     InvalidArgument(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListCloudFrontOriginAccessIdentitiesError {
@@ -10850,13 +11100,19 @@ impl ListCloudFrontOriginAccessIdentitiesError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InvalidArgument" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             ListCloudFrontOriginAccessIdentitiesError::InvalidArgument(
                                 parsed_error.message,
                             ),
+                            &res,
+                        )
+                    }
+                    _ => {
+                        return RusotoError::unhandled(
+                            ListCloudFrontOriginAccessIdentitiesError::Unhandled,
+                            &res,
                         )
                     }
-                    _ => {}
                 }
             }
         }
@@ -10878,15 +11134,21 @@ impl fmt::Display for ListCloudFrontOriginAccessIdentitiesError {
             ListCloudFrontOriginAccessIdentitiesError::InvalidArgument(ref cause) => {
                 write!(f, "{}", cause)
             }
+            ListCloudFrontOriginAccessIdentitiesError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for ListCloudFrontOriginAccessIdentitiesError {}
 /// Errors returned by ListDistributions
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListDistributionsError {
     /// <p>The argument is invalid.</p>
     InvalidArgument(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListDistributionsError {
@@ -10898,11 +11160,12 @@ impl ListDistributionsError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InvalidArgument" => {
-                        return RusotoError::Service(ListDistributionsError::InvalidArgument(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            ListDistributionsError::InvalidArgument(parsed_error.message),
+                            &res,
+                        )
                     }
-                    _ => {}
+                    _ => return RusotoError::unhandled(ListDistributionsError::Unhandled, &res),
                 }
             }
         }
@@ -10922,17 +11185,21 @@ impl fmt::Display for ListDistributionsError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             ListDistributionsError::InvalidArgument(ref cause) => write!(f, "{}", cause),
+            ListDistributionsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListDistributionsError {}
 /// Errors returned by ListDistributionsByWebACLId
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListDistributionsByWebACLIdError {
     /// <p>The argument is invalid.</p>
     InvalidArgument(String),
     /// <p>A web ACL ID specified in the response body is not valid. To specify a web ACL created using the latest version of AWS WAF, use the ACL ARN, for example <code>arn:aws:wafv2:us-east-1:123456789012:global/webacl/ExampleWebACL/473e64fd-f30b-4765-81a0-62ad96dd167a</code>. To specify a web ACL created using AWS WAF Classic, use the ACL ID, for example <code>473e64fd-f30b-4765-81a0-62ad96dd167a</code>.</p>
     InvalidWebACLId(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListDistributionsByWebACLIdError {
@@ -10946,16 +11213,23 @@ impl ListDistributionsByWebACLIdError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InvalidArgument" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             ListDistributionsByWebACLIdError::InvalidArgument(parsed_error.message),
+                            &res,
                         )
                     }
                     "InvalidWebACLId" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             ListDistributionsByWebACLIdError::InvalidWebACLId(parsed_error.message),
+                            &res,
+                        )
+                    }
+                    _ => {
+                        return RusotoError::unhandled(
+                            ListDistributionsByWebACLIdError::Unhandled,
+                            &res,
                         )
                     }
-                    _ => {}
                 }
             }
         }
@@ -10976,15 +11250,19 @@ impl fmt::Display for ListDistributionsByWebACLIdError {
         match *self {
             ListDistributionsByWebACLIdError::InvalidArgument(ref cause) => write!(f, "{}", cause),
             ListDistributionsByWebACLIdError::InvalidWebACLId(ref cause) => write!(f, "{}", cause),
+            ListDistributionsByWebACLIdError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListDistributionsByWebACLIdError {}
 /// Errors returned by ListFieldLevelEncryptionConfigs
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListFieldLevelEncryptionConfigsError {
     /// <p>The argument is invalid.</p>
     InvalidArgument(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListFieldLevelEncryptionConfigsError {
@@ -10998,13 +11276,19 @@ impl ListFieldLevelEncryptionConfigsError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InvalidArgument" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             ListFieldLevelEncryptionConfigsError::InvalidArgument(
                                 parsed_error.message,
                             ),
+                            &res,
+                        )
+                    }
+                    _ => {
+                        return RusotoError::unhandled(
+                            ListFieldLevelEncryptionConfigsError::Unhandled,
+                            &res,
                         )
                     }
-                    _ => {}
                 }
             }
         }
@@ -11026,15 +11310,21 @@ impl fmt::Display for ListFieldLevelEncryptionConfigsError {
             ListFieldLevelEncryptionConfigsError::InvalidArgument(ref cause) => {
                 write!(f, "{}", cause)
             }
+            ListFieldLevelEncryptionConfigsError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for ListFieldLevelEncryptionConfigsError {}
 /// Errors returned by ListFieldLevelEncryptionProfiles
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListFieldLevelEncryptionProfilesError {
     /// <p>The argument is invalid.</p>
     InvalidArgument(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListFieldLevelEncryptionProfilesError {
@@ -11048,13 +11338,19 @@ impl ListFieldLevelEncryptionProfilesError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InvalidArgument" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             ListFieldLevelEncryptionProfilesError::InvalidArgument(
                                 parsed_error.message,
                             ),
+                            &res,
+                        )
+                    }
+                    _ => {
+                        return RusotoError::unhandled(
+                            ListFieldLevelEncryptionProfilesError::Unhandled,
+                            &res,
                         )
                     }
-                    _ => {}
                 }
             }
         }
@@ -11076,12 +11372,16 @@ impl fmt::Display for ListFieldLevelEncryptionProfilesError {
             ListFieldLevelEncryptionProfilesError::InvalidArgument(ref cause) => {
                 write!(f, "{}", cause)
             }
+            ListFieldLevelEncryptionProfilesError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for ListFieldLevelEncryptionProfilesError {}
 /// Errors returned by ListInvalidations
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListInvalidationsError {
     /// <p>Access denied.</p>
     AccessDenied(String),
@@ -11089,6 +11389,8 @@ pub enum ListInvalidationsError {
     InvalidArgument(String),
     /// <p>The specified distribution does not exist.</p>
     NoSuchDistribution(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListInvalidationsError {
@@ -11100,21 +11402,24 @@ impl ListInvalidationsError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "AccessDenied" => {
-                        return RusotoError::Service(ListInvalidationsError::AccessDenied(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            ListInvalidationsError::AccessDenied(parsed_error.message),
+                            &res,
+                        )
                     }
                     "InvalidArgument" => {
-                        return RusotoError::Service(ListInvalidationsError::InvalidArgument(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            ListInvalidationsError::InvalidArgument(parsed_error.message),
+                            &res,
+                        )
                     }
                     "NoSuchDistribution" => {
-                        return RusotoError::Service(ListInvalidationsError::NoSuchDistribution(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            ListInvalidationsError::NoSuchDistribution(parsed_error.message),
+                            &res,
+                        )
                     }
-                    _ => {}
+                    _ => return RusotoError::unhandled(ListInvalidationsError::Unhandled, &res),
                 }
             }
         }
@@ -11136,15 +11441,19 @@ impl fmt::Display for ListInvalidationsError {
             ListInvalidationsError::AccessDenied(ref cause) => write!(f, "{}", cause),
             ListInvalidationsError::InvalidArgument(ref cause) => write!(f, "{}", cause),
             ListInvalidationsError::NoSuchDistribution(ref cause) => write!(f, "{}", cause),
+            ListInvalidationsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListInvalidationsError {}
 /// Errors returned by ListPublicKeys
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListPublicKeysError {
     /// <p>The argument is invalid.</p>
     InvalidArgument(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListPublicKeysError {
@@ -11156,11 +11465,12 @@ impl ListPublicKeysError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InvalidArgument" => {
-                        return RusotoError::Service(ListPublicKeysError::InvalidArgument(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            ListPublicKeysError::InvalidArgument(parsed_error.message),
+                            &res,
+                        )
                     }
-                    _ => {}
+                    _ => return RusotoError::unhandled(ListPublicKeysError::Unhandled, &res),
                 }
             }
         }
@@ -11180,15 +11490,19 @@ impl fmt::Display for ListPublicKeysError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             ListPublicKeysError::InvalidArgument(ref cause) => write!(f, "{}", cause),
+            ListPublicKeysError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListPublicKeysError {}
 /// Errors returned by ListStreamingDistributions
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListStreamingDistributionsError {
     /// <p>The argument is invalid.</p>
     InvalidArgument(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListStreamingDistributionsError {
@@ -11202,11 +11516,17 @@ impl ListStreamingDistributionsError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InvalidArgument" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             ListStreamingDistributionsError::InvalidArgument(parsed_error.message),
+                            &res,
+                        )
+                    }
+                    _ => {
+                        return RusotoError::unhandled(
+                            ListStreamingDistributionsError::Unhandled,
+                            &res,
                         )
                     }
-                    _ => {}
                 }
             }
         }
@@ -11226,12 +11546,14 @@ impl fmt::Display for ListStreamingDistributionsError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             ListStreamingDistributionsError::InvalidArgument(ref cause) => write!(f, "{}", cause),
+            ListStreamingDistributionsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListStreamingDistributionsError {}
 /// Errors returned by ListTagsForResource
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListTagsForResourceError {
     /// <p>Access denied.</p>
     AccessDenied(String),
@@ -11241,6 +11563,8 @@ pub enum ListTagsForResourceError {
     InvalidTagging(String),
     /// <p>A resource that was specified is not valid.</p>
     NoSuchResource(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListTagsForResourceError {
@@ -11252,26 +11576,30 @@ impl ListTagsForResourceError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "AccessDenied" => {
-                        return RusotoError::Service(ListTagsForResourceError::AccessDenied(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            ListTagsForResourceError::AccessDenied(parsed_error.message),
+                            &res,
+                        )
                     }
                     "InvalidArgument" => {
-                        return RusotoError::Service(ListTagsForResourceError::InvalidArgument(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            ListTagsForResourceError::InvalidArgument(parsed_error.message),
+                            &res,
+                        )
                     }
                     "InvalidTagging" => {
-                        return RusotoError::Service(ListTagsForResourceError::InvalidTagging(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            ListTagsForResourceError::InvalidTagging(parsed_error.message),
+                            &res,
+                        )
                     }
                     "NoSuchResource" => {
-                        return RusotoError::Service(ListTagsForResourceError::NoSuchResource(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            ListTagsForResourceError::NoSuchResource(parsed_error.message),
+                            &res,
+                        )
                     }
-                    _ => {}
+                    _ => return RusotoError::unhandled(ListTagsForResourceError::Unhandled, &res),
                 }
             }
         }
@@ -11294,12 +11622,14 @@ impl fmt::Display for ListTagsForResourceError {
             ListTagsForResourceError::InvalidArgument(ref cause) => write!(f, "{}", cause),
             ListTagsForResourceError::InvalidTagging(ref cause) => write!(f, "{}", cause),
             ListTagsForResourceError::NoSuchResource(ref cause) => write!(f, "{}", cause),
+            ListTagsForResourceError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListTagsForResourceError {}
 /// Errors returned by TagResource
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum TagResourceError {
     /// <p>Access denied.</p>
     AccessDenied(String),
@@ -11309,6 +11639,8 @@ pub enum TagResourceError {
     InvalidTagging(String),
     /// <p>A resource that was specified is not valid.</p>
     NoSuchResource(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl TagResourceError {
@@ -11320,26 +11652,30 @@ impl TagResourceError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "AccessDenied" => {
-                        return RusotoError::Service(TagResourceError::AccessDenied(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            TagResourceError::AccessDenied(parsed_error.message),
+                            &res,
+                        )
                     }
                     "InvalidArgument" => {
-                        return RusotoError::Service(TagResourceError::InvalidArgument(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            TagResourceError::InvalidArgument(parsed_error.message),
+                            &res,
+                        )
                     }
                     "InvalidTagging" => {
-                        return RusotoError::Service(TagResourceError::InvalidTagging(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            TagResourceError::InvalidTagging(parsed_error.message),
+                            &res,
+                        )
                     }
                     "NoSuchResource" => {
-                        return RusotoError::Service(TagResourceError::NoSuchResource(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            TagResourceError::NoSuchResource(parsed_error.message),
+                            &res,
+                        )
                     }
-                    _ => {}
+                    _ => return RusotoError::unhandled(TagResourceError::Unhandled, &res),
                 }
             }
         }
@@ -11362,12 +11698,14 @@ impl fmt::Display for TagResourceError {
             TagResourceError::InvalidArgument(ref cause) => write!(f, "{}", cause),
             TagResourceError::InvalidTagging(ref cause) => write!(f, "{}", cause),
             TagResourceError::NoSuchResource(ref cause) => write!(f, "{}", cause),
+            TagResourceError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for TagResourceError {}
 /// Errors returned by UntagResource
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UntagResourceError {
     /// <p>Access denied.</p>
     AccessDenied(String),
@@ -11377,6 +11715,8 @@ pub enum UntagResourceError {
     InvalidTagging(String),
     /// <p>A resource that was specified is not valid.</p>
     NoSuchResource(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UntagResourceError {
@@ -11388,26 +11728,30 @@ impl UntagResourceError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "AccessDenied" => {
-                        return RusotoError::Service(UntagResourceError::AccessDenied(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            UntagResourceError::AccessDenied(parsed_error.message),
+                            &res,
+                        )
                     }
                     "InvalidArgument" => {
-                        return RusotoError::Service(UntagResourceError::InvalidArgument(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            UntagResourceError::InvalidArgument(parsed_error.message),
+                            &res,
+                        )
                     }
                     "InvalidTagging" => {
-                        return RusotoError::Service(UntagResourceError::InvalidTagging(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            UntagResourceError::InvalidTagging(parsed_error.message),
+                            &res,
+                        )
                     }
                     "NoSuchResource" => {
-                        return RusotoError::Service(UntagResourceError::NoSuchResource(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            UntagResourceError::NoSuchResource(parsed_error.message),
+                            &res,
+                        )
                     }
-                    _ => {}
+                    _ => return RusotoError::unhandled(UntagResourceError::Unhandled, &res),
                 }
             }
         }
@@ -11430,12 +11774,14 @@ impl fmt::Display for UntagResourceError {
             UntagResourceError::InvalidArgument(ref cause) => write!(f, "{}", cause),
             UntagResourceError::InvalidTagging(ref cause) => write!(f, "{}", cause),
             UntagResourceError::NoSuchResource(ref cause) => write!(f, "{}", cause),
+            UntagResourceError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for UntagResourceError {}
 /// Errors returned by UpdateCloudFrontOriginAccessIdentity
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdateCloudFrontOriginAccessIdentityError {
     /// <p>Access denied.</p>
     AccessDenied(String),
@@ -11453,6 +11799,8 @@ pub enum UpdateCloudFrontOriginAccessIdentityError {
     NoSuchCloudFrontOriginAccessIdentity(String),
     /// <p>The precondition given in one or more of the request-header fields evaluated to <code>false</code>. </p>
     PreconditionFailed(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdateCloudFrontOriginAccessIdentityError {
@@ -11465,7 +11813,7 @@ impl UpdateCloudFrontOriginAccessIdentityError {
             find_start_element(&mut stack);
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
-                                    "AccessDenied" => return RusotoError::Service(UpdateCloudFrontOriginAccessIdentityError::AccessDenied(parsed_error.message)),"IllegalUpdate" => return RusotoError::Service(UpdateCloudFrontOriginAccessIdentityError::IllegalUpdate(parsed_error.message)),"InconsistentQuantities" => return RusotoError::Service(UpdateCloudFrontOriginAccessIdentityError::InconsistentQuantities(parsed_error.message)),"InvalidArgument" => return RusotoError::Service(UpdateCloudFrontOriginAccessIdentityError::InvalidArgument(parsed_error.message)),"InvalidIfMatchVersion" => return RusotoError::Service(UpdateCloudFrontOriginAccessIdentityError::InvalidIfMatchVersion(parsed_error.message)),"MissingBody" => return RusotoError::Service(UpdateCloudFrontOriginAccessIdentityError::MissingBody(parsed_error.message)),"NoSuchCloudFrontOriginAccessIdentity" => return RusotoError::Service(UpdateCloudFrontOriginAccessIdentityError::NoSuchCloudFrontOriginAccessIdentity(parsed_error.message)),"PreconditionFailed" => return RusotoError::Service(UpdateCloudFrontOriginAccessIdentityError::PreconditionFailed(parsed_error.message)),_ => {}
+                                    "AccessDenied" => return RusotoError::service(UpdateCloudFrontOriginAccessIdentityError::AccessDenied(parsed_error.message), &res),"IllegalUpdate" => return RusotoError::service(UpdateCloudFrontOriginAccessIdentityError::IllegalUpdate(parsed_error.message), &res),"InconsistentQuantities" => return RusotoError::service(UpdateCloudFrontOriginAccessIdentityError::InconsistentQuantities(parsed_error.message), &res),"InvalidArgument" => return RusotoError::service(UpdateCloudFrontOriginAccessIdentityError::InvalidArgument(parsed_error.message), &res),"InvalidIfMatchVersion" => return RusotoError::service(UpdateCloudFrontOriginAccessIdentityError::InvalidIfMatchVersion(parsed_error.message), &res),"MissingBody" => return RusotoError::service(UpdateCloudFrontOriginAccessIdentityError::MissingBody(parsed_error.message), &res),"NoSuchCloudFrontOriginAccessIdentity" => return RusotoError::service(UpdateCloudFrontOriginAccessIdentityError::NoSuchCloudFrontOriginAccessIdentity(parsed_error.message), &res),"PreconditionFailed" => return RusotoError::service(UpdateCloudFrontOriginAccessIdentityError::PreconditionFailed(parsed_error.message), &res),_ => return RusotoError::unhandled(UpdateCloudFrontOriginAccessIdentityError::Unhandled, &res),
                                 }
             }
         }
@@ -11508,12 +11856,16 @@ impl fmt::Display for UpdateCloudFrontOriginAccessIdentityError {
             UpdateCloudFrontOriginAccessIdentityError::PreconditionFailed(ref cause) => {
                 write!(f, "{}", cause)
             }
+            UpdateCloudFrontOriginAccessIdentityError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for UpdateCloudFrontOriginAccessIdentityError {}
 /// Errors returned by UpdateDistribution
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdateDistributionError {
     /// <p>Access denied.</p>
     AccessDenied(String),
@@ -11603,6 +11955,8 @@ pub enum UpdateDistributionError {
     TooManyTrustedSigners(String),
     /// <p>One or more of your trusted signers don't exist.</p>
     TrustedSignerDoesNotExist(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdateDistributionError {
@@ -11613,7 +11967,7 @@ impl UpdateDistributionError {
             find_start_element(&mut stack);
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
-                                    "AccessDenied" => return RusotoError::Service(UpdateDistributionError::AccessDenied(parsed_error.message)),"CNAMEAlreadyExists" => return RusotoError::Service(UpdateDistributionError::CNAMEAlreadyExists(parsed_error.message)),"IllegalFieldLevelEncryptionConfigAssociationWithCacheBehavior" => return RusotoError::Service(UpdateDistributionError::IllegalFieldLevelEncryptionConfigAssociationWithCacheBehavior(parsed_error.message)),"IllegalUpdate" => return RusotoError::Service(UpdateDistributionError::IllegalUpdate(parsed_error.message)),"InconsistentQuantities" => return RusotoError::Service(UpdateDistributionError::InconsistentQuantities(parsed_error.message)),"InvalidArgument" => return RusotoError::Service(UpdateDistributionError::InvalidArgument(parsed_error.message)),"InvalidDefaultRootObject" => return RusotoError::Service(UpdateDistributionError::InvalidDefaultRootObject(parsed_error.message)),"InvalidErrorCode" => return RusotoError::Service(UpdateDistributionError::InvalidErrorCode(parsed_error.message)),"InvalidForwardCookies" => return RusotoError::Service(UpdateDistributionError::InvalidForwardCookies(parsed_error.message)),"InvalidGeoRestrictionParameter" => return RusotoError::Service(UpdateDistributionError::InvalidGeoRestrictionParameter(parsed_error.message)),"InvalidHeadersForS3Origin" => return RusotoError::Service(UpdateDistributionError::InvalidHeadersForS3Origin(parsed_error.message)),"InvalidIfMatchVersion" => return RusotoError::Service(UpdateDistributionError::InvalidIfMatchVersion(parsed_error.message)),"InvalidLambdaFunctionAssociation" => return RusotoError::Service(UpdateDistributionError::InvalidLambdaFunctionAssociation(parsed_error.message)),"InvalidLocationCode" => return RusotoError::Service(UpdateDistributionError::InvalidLocationCode(parsed_error.message)),"InvalidMinimumProtocolVersion" => return RusotoError::Service(UpdateDistributionError::InvalidMinimumProtocolVersion(parsed_error.message)),"InvalidOriginAccessIdentity" => return RusotoError::Service(UpdateDistributionError::InvalidOriginAccessIdentity(parsed_error.message)),"InvalidOriginKeepaliveTimeout" => return RusotoError::Service(UpdateDistributionError::InvalidOriginKeepaliveTimeout(parsed_error.message)),"InvalidOriginReadTimeout" => return RusotoError::Service(UpdateDistributionError::InvalidOriginReadTimeout(parsed_error.message)),"InvalidQueryStringParameters" => return RusotoError::Service(UpdateDistributionError::InvalidQueryStringParameters(parsed_error.message)),"InvalidRelativePath" => return RusotoError::Service(UpdateDistributionError::InvalidRelativePath(parsed_error.message)),"InvalidRequiredProtocol" => return RusotoError::Service(UpdateDistributionError::InvalidRequiredProtocol(parsed_error.message)),"InvalidResponseCode" => return RusotoError::Service(UpdateDistributionError::InvalidResponseCode(parsed_error.message)),"InvalidTTLOrder" => return RusotoError::Service(UpdateDistributionError::InvalidTTLOrder(parsed_error.message)),"InvalidViewerCertificate" => return RusotoError::Service(UpdateDistributionError::InvalidViewerCertificate(parsed_error.message)),"InvalidWebACLId" => return RusotoError::Service(UpdateDistributionError::InvalidWebACLId(parsed_error.message)),"MissingBody" => return RusotoError::Service(UpdateDistributionError::MissingBody(parsed_error.message)),"NoSuchDistribution" => return RusotoError::Service(UpdateDistributionError::NoSuchDistribution(parsed_error.message)),"NoSuchFieldLevelEncryptionConfig" => return RusotoError::Service(UpdateDistributionError::NoSuchFieldLevelEncryptionConfig(parsed_error.message)),"NoSuchOrigin" => return RusotoError::Service(UpdateDistributionError::NoSuchOrigin(parsed_error.message)),"PreconditionFailed" => return RusotoError::Service(UpdateDistributionError::PreconditionFailed(parsed_error.message)),"TooManyCacheBehaviors" => return RusotoError::Service(UpdateDistributionError::TooManyCacheBehaviors(parsed_error.message)),"TooManyCertificates" => return RusotoError::Service(UpdateDistributionError::TooManyCertificates(parsed_error.message)),"TooManyCookieNamesInWhiteList" => return RusotoError::Service(UpdateDistributionError::TooManyCookieNamesInWhiteList(parsed_error.message)),"TooManyDistributionCNAMEs" => return RusotoError::Service(UpdateDistributionError::TooManyDistributionCNAMEs(parsed_error.message)),"TooManyDistributionsAssociatedToFieldLevelEncryptionConfig" => return RusotoError::Service(UpdateDistributionError::TooManyDistributionsAssociatedToFieldLevelEncryptionConfig(parsed_error.message)),"TooManyDistributionsWithLambdaAssociations" => return RusotoError::Service(UpdateDistributionError::TooManyDistributionsWithLambdaAssociations(parsed_error.message)),"TooManyHeadersInForwardedValues" => return RusotoError::Service(UpdateDistributionError::TooManyHeadersInForwardedValues(parsed_error.message)),"TooManyLambdaFunctionAssociations" => return RusotoError::Service(UpdateDistributionError::TooManyLambdaFunctionAssociations(parsed_error.message)),"TooManyOriginCustomHeaders" => return RusotoError::Service(UpdateDistributionError::TooManyOriginCustomHeaders(parsed_error.message)),"TooManyOriginGroupsPerDistribution" => return RusotoError::Service(UpdateDistributionError::TooManyOriginGroupsPerDistribution(parsed_error.message)),"TooManyOrigins" => return RusotoError::Service(UpdateDistributionError::TooManyOrigins(parsed_error.message)),"TooManyQueryStringParameters" => return RusotoError::Service(UpdateDistributionError::TooManyQueryStringParameters(parsed_error.message)),"TooManyTrustedSigners" => return RusotoError::Service(UpdateDistributionError::TooManyTrustedSigners(parsed_error.message)),"TrustedSignerDoesNotExist" => return RusotoError::Service(UpdateDistributionError::TrustedSignerDoesNotExist(parsed_error.message)),_ => {}
+                                    "AccessDenied" => return RusotoError::service(UpdateDistributionError::AccessDenied(parsed_error.message), &res),"CNAMEAlreadyExists" => return RusotoError::service(UpdateDistributionError::CNAMEAlreadyExists(parsed_error.message), &res),"IllegalFieldLevelEncryptionConfigAssociationWithCacheBehavior" => return RusotoError::service(UpdateDistributionError::IllegalFieldLevelEncryptionConfigAssociationWithCacheBehavior(parsed_error.message), &res),"IllegalUpdate" => return RusotoError::service(UpdateDistributionError::IllegalUpdate(parsed_error.message), &res),"InconsistentQuantities" => return RusotoError::service(UpdateDistributionError::InconsistentQuantities(parsed_error.message), &res),"InvalidArgument" => return RusotoError::service(UpdateDistributionError::InvalidArgument(parsed_error.message), &res),"InvalidDefaultRootObject" => return RusotoError::service(UpdateDistributionError::InvalidDefaultRootObject(parsed_error.message), &res),"InvalidErrorCode" => return RusotoError::service(UpdateDistributionError::InvalidErrorCode(parsed_error.message), &res),"InvalidForwardCookies" => return RusotoError::service(UpdateDistributionError::InvalidForwardCookies(parsed_error.message), &res),"InvalidGeoRestrictionParameter" => return RusotoError::service(UpdateDistributionError::InvalidGeoRestrictionParameter(parsed_error.message), &res),"InvalidHeadersForS3Origin" => return RusotoError::service(UpdateDistributionError::InvalidHeadersForS3Origin(parsed_error.message), &res),"InvalidIfMatchVersion" => return RusotoError::service(UpdateDistributionError::InvalidIfMatchVersion(parsed_error.message), &res),"InvalidLambdaFunctionAssociation" => return RusotoError::service(UpdateDistributionError::InvalidLambdaFunctionAssociation(parsed_error.message), &res),"InvalidLocationCode" => return RusotoError::service(UpdateDistributionError::InvalidLocationCode(parsed_error.message), &res),"InvalidMinimumProtocolVersion" => return RusotoError::service(UpdateDistributionError::InvalidMinimumProtocolVersion(parsed_error.message), &res),"InvalidOriginAccessIdentity" => return RusotoError::service(UpdateDistributionError::InvalidOriginAccessIdentity(parsed_error.message), &res),"InvalidOriginKeepaliveTimeout" => return RusotoError::service(UpdateDistributionError::InvalidOriginKeepaliveTimeout(parsed_error.message), &res),"InvalidOriginReadTimeout" => return RusotoError::service(UpdateDistributionError::InvalidOriginReadTimeout(parsed_error.message), &res),"InvalidQueryStringParameters" => return RusotoError::service(UpdateDistributionError::InvalidQueryStringParameters(parsed_error.message), &res),"InvalidRelativePath" => return RusotoError::service(UpdateDistributionError::InvalidRelativePath(parsed_error.message), &res),"InvalidRequiredProtocol" => return RusotoError::service(UpdateDistributionError::InvalidRequiredProtocol(parsed_error.message), &res),"InvalidResponseCode" => return RusotoError::service(UpdateDistributionError::InvalidResponseCode(parsed_error.message), &res),"InvalidTTLOrder" => return RusotoError::service(UpdateDistributionError::InvalidTTLOrder(parsed_error.message), &res),"InvalidViewerCertificate" => return RusotoError::service(UpdateDistributionError::InvalidViewerCertificate(parsed_error.message), &res),"InvalidWebACLId" => return RusotoError::service(UpdateDistributionError::InvalidWebACLId(parsed_error.message), &res),"MissingBody" => return RusotoError::service(UpdateDistributionError::MissingBody(parsed_error.message), &res),"NoSuchDistribution" => return RusotoError::service(UpdateDistributionError::NoSuchDistribution(parsed_error.message), &res),"NoSuchFieldLevelEncryptionConfig" => return RusotoError::service(UpdateDistributionError::NoSuchFieldLevelEncryptionConfig(parsed_error.message), &res),"NoSuchOrigin" => return RusotoError::service(UpdateDistributionError::NoSuchOrigin(parsed_error.message), &res),"PreconditionFailed" => return RusotoError::service(UpdateDistributionError::PreconditionFailed(parsed_error.message), &res),"TooManyCacheBehaviors" => return RusotoError::service(UpdateDistributionError::TooManyCacheBehaviors(parsed_error.message), &res),"TooManyCertificates" => return RusotoError::service(UpdateDistributionError::TooManyCertificates(parsed_error.message), &res),"TooManyCookieNamesInWhiteList" => return RusotoError::service(UpdateDistributionError::TooManyCookieNamesInWhiteList(parsed_error.message), &res),"TooManyDistributionCNAMEs" => return RusotoError::service(UpdateDistributionError::TooManyDistributionCNAMEs(parsed_error.message), &res),"TooManyDistributionsAssociatedToFieldLevelEncryptionConfig" => return RusotoError::service(UpdateDistributionError::TooManyDistributionsAssociatedToFieldLevelEncryptionConfig(parsed_error.message), &res),"TooManyDistributionsWithLambdaAssociations" => return RusotoError::service(UpdateDistributionError::TooManyDistributionsWithLambdaAssociations(parsed_error.message), &res),"TooManyHeadersInForwardedValues" => return RusotoError::service(UpdateDistributionError::TooManyHeadersInForwardedValues(parsed_error.message), &res),"TooManyLambdaFunctionAssociations" => return RusotoError::service(UpdateDistributionError::TooManyLambdaFunctionAssociations(parsed_error.message), &res),"TooManyOriginCustomHeaders" => return RusotoError::service(UpdateDistributionError::TooManyOriginCustomHeaders(parsed_error.message), &res),"TooManyOriginGroupsPerDistribution" => return RusotoError::service(UpdateDistributionError::TooManyOriginGroupsPerDistribution(parsed_error.message), &res),"TooManyOrigins" => return RusotoError::service(UpdateDistributionError::TooManyOrigins(parsed_error.message), &res),"TooManyQueryStringParameters" => return RusotoError::service(UpdateDistributionError::TooManyQueryStringParameters(parsed_error.message), &res),"TooManyTrustedSigners" => return RusotoError::service(UpdateDistributionError::TooManyTrustedSigners(parsed_error.message), &res),"TrustedSignerDoesNotExist" => return RusotoError::service(UpdateDistributionError::TrustedSignerDoesNotExist(parsed_error.message), &res),_ => return RusotoError::unhandled(UpdateDistributionError::Unhandled, &res),
                                 }
             }
         }
@@ -11675,13 +12029,15 @@ UpdateDistributionError::TooManyOriginGroupsPerDistribution(ref cause) => write!
 UpdateDistributionError::TooManyOrigins(ref cause) => write!(f, "{}", cause),
 UpdateDistributionError::TooManyQueryStringParameters(ref cause) => write!(f, "{}", cause),
 UpdateDistributionError::TooManyTrustedSigners(ref cause) => write!(f, "{}", cause),
-UpdateDistributionError::TrustedSignerDoesNotExist(ref cause) => write!(f, "{}", cause)
-                        }
+UpdateDistributionError::TrustedSignerDoesNotExist(ref cause) => write!(f, "{}", cause),
+            UpdateDistributionError::Unhandled(ref metadata) => write!(f, "{}", metadata),
+        }
     }
 }
 impl Error for UpdateDistributionError {}
 /// Errors returned by UpdateFieldLevelEncryptionConfig
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdateFieldLevelEncryptionConfigError {
     /// <p>Access denied.</p>
     AccessDenied(String),
@@ -11705,6 +12061,8 @@ pub enum UpdateFieldLevelEncryptionConfigError {
     TooManyFieldLevelEncryptionContentTypeProfiles(String),
     /// <p>The maximum number of query arg profiles for field-level encryption have been created.</p>
     TooManyFieldLevelEncryptionQueryArgProfiles(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdateFieldLevelEncryptionConfigError {
@@ -11717,7 +12075,7 @@ impl UpdateFieldLevelEncryptionConfigError {
             find_start_element(&mut stack);
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
-                                    "AccessDenied" => return RusotoError::Service(UpdateFieldLevelEncryptionConfigError::AccessDenied(parsed_error.message)),"IllegalUpdate" => return RusotoError::Service(UpdateFieldLevelEncryptionConfigError::IllegalUpdate(parsed_error.message)),"InconsistentQuantities" => return RusotoError::Service(UpdateFieldLevelEncryptionConfigError::InconsistentQuantities(parsed_error.message)),"InvalidArgument" => return RusotoError::Service(UpdateFieldLevelEncryptionConfigError::InvalidArgument(parsed_error.message)),"InvalidIfMatchVersion" => return RusotoError::Service(UpdateFieldLevelEncryptionConfigError::InvalidIfMatchVersion(parsed_error.message)),"NoSuchFieldLevelEncryptionConfig" => return RusotoError::Service(UpdateFieldLevelEncryptionConfigError::NoSuchFieldLevelEncryptionConfig(parsed_error.message)),"NoSuchFieldLevelEncryptionProfile" => return RusotoError::Service(UpdateFieldLevelEncryptionConfigError::NoSuchFieldLevelEncryptionProfile(parsed_error.message)),"PreconditionFailed" => return RusotoError::Service(UpdateFieldLevelEncryptionConfigError::PreconditionFailed(parsed_error.message)),"QueryArgProfileEmpty" => return RusotoError::Service(UpdateFieldLevelEncryptionConfigError::QueryArgProfileEmpty(parsed_error.message)),"TooManyFieldLevelEncryptionContentTypeProfiles" => return RusotoError::Service(UpdateFieldLevelEncryptionConfigError::TooManyFieldLevelEncryptionContentTypeProfiles(parsed_error.message)),"TooManyFieldLevelEncryptionQueryArgProfiles" => return RusotoError::Service(UpdateFieldLevelEncryptionConfigError::TooManyFieldLevelEncryptionQueryArgProfiles(parsed_error.message)),_ => {}
+                                    "AccessDenied" => return RusotoError::service(UpdateFieldLevelEncryptionConfigError::AccessDenied(parsed_error.message), &res),"IllegalUpdate" => return RusotoError::service(UpdateFieldLevelEncryptionConfigError::IllegalUpdate(parsed_error.message), &res),"InconsistentQuantities" => return RusotoError::service(UpdateFieldLevelEncryptionConfigError::InconsistentQuantities(parsed_error.message), &res),"InvalidArgument" => return RusotoError::service(UpdateFieldLevelEncryptionConfigError::InvalidArgument(parsed_error.message), &res),"InvalidIfMatchVersion" => return RusotoError::service(UpdateFieldLevelEncryptionConfigError::InvalidIfMatchVersion(parsed_error.message), &res),"NoSuchFieldLevelEncryptionConfig" => return RusotoError::service(UpdateFieldLevelEncryptionConfigError::NoSuchFieldLevelEncryptionConfig(parsed_error.message), &res),"NoSuchFieldLevelEncryptionProfile" => return RusotoError::service(UpdateFieldLevelEncryptionConfigError::NoSuchFieldLevelEncryptionProfile(parsed_error.message), &res),"PreconditionFailed" => return RusotoError::service(UpdateFieldLevelEncryptionConfigError::PreconditionFailed(parsed_error.message), &res),"QueryArgProfileEmpty" => return RusotoError::service(UpdateFieldLevelEncryptionConfigError::QueryArgProfileEmpty(parsed_error.message), &res),"TooManyFieldLevelEncryptionContentTypeProfiles" => return RusotoError::service(UpdateFieldLevelEncryptionConfigError::TooManyFieldLevelEncryptionContentTypeProfiles(parsed_error.message), &res),"TooManyFieldLevelEncryptionQueryArgProfiles" => return RusotoError::service(UpdateFieldLevelEncryptionConfigError::TooManyFieldLevelEncryptionQueryArgProfiles(parsed_error.message), &res),_ => return RusotoError::unhandled(UpdateFieldLevelEncryptionConfigError::Unhandled, &res),
                                 }
             }
         }
@@ -11746,13 +12104,15 @@ UpdateFieldLevelEncryptionConfigError::NoSuchFieldLevelEncryptionProfile(ref cau
 UpdateFieldLevelEncryptionConfigError::PreconditionFailed(ref cause) => write!(f, "{}", cause),
 UpdateFieldLevelEncryptionConfigError::QueryArgProfileEmpty(ref cause) => write!(f, "{}", cause),
 UpdateFieldLevelEncryptionConfigError::TooManyFieldLevelEncryptionContentTypeProfiles(ref cause) => write!(f, "{}", cause),
-UpdateFieldLevelEncryptionConfigError::TooManyFieldLevelEncryptionQueryArgProfiles(ref cause) => write!(f, "{}", cause)
-                        }
+UpdateFieldLevelEncryptionConfigError::TooManyFieldLevelEncryptionQueryArgProfiles(ref cause) => write!(f, "{}", cause),
+            UpdateFieldLevelEncryptionConfigError::Unhandled(ref metadata) => write!(f, "{}", metadata),
+        }
     }
 }
 impl Error for UpdateFieldLevelEncryptionConfigError {}
 /// Errors returned by UpdateFieldLevelEncryptionProfile
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdateFieldLevelEncryptionProfileError {
     /// <p>Access denied.</p>
     AccessDenied(String),
@@ -11778,6 +12138,8 @@ pub enum UpdateFieldLevelEncryptionProfileError {
     TooManyFieldLevelEncryptionEncryptionEntities(String),
     /// <p>The maximum number of field patterns for field-level encryption have been created.</p>
     TooManyFieldLevelEncryptionFieldPatterns(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdateFieldLevelEncryptionProfileError {
@@ -11790,7 +12152,7 @@ impl UpdateFieldLevelEncryptionProfileError {
             find_start_element(&mut stack);
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
-                                    "AccessDenied" => return RusotoError::Service(UpdateFieldLevelEncryptionProfileError::AccessDenied(parsed_error.message)),"FieldLevelEncryptionProfileAlreadyExists" => return RusotoError::Service(UpdateFieldLevelEncryptionProfileError::FieldLevelEncryptionProfileAlreadyExists(parsed_error.message)),"FieldLevelEncryptionProfileSizeExceeded" => return RusotoError::Service(UpdateFieldLevelEncryptionProfileError::FieldLevelEncryptionProfileSizeExceeded(parsed_error.message)),"IllegalUpdate" => return RusotoError::Service(UpdateFieldLevelEncryptionProfileError::IllegalUpdate(parsed_error.message)),"InconsistentQuantities" => return RusotoError::Service(UpdateFieldLevelEncryptionProfileError::InconsistentQuantities(parsed_error.message)),"InvalidArgument" => return RusotoError::Service(UpdateFieldLevelEncryptionProfileError::InvalidArgument(parsed_error.message)),"InvalidIfMatchVersion" => return RusotoError::Service(UpdateFieldLevelEncryptionProfileError::InvalidIfMatchVersion(parsed_error.message)),"NoSuchFieldLevelEncryptionProfile" => return RusotoError::Service(UpdateFieldLevelEncryptionProfileError::NoSuchFieldLevelEncryptionProfile(parsed_error.message)),"NoSuchPublicKey" => return RusotoError::Service(UpdateFieldLevelEncryptionProfileError::NoSuchPublicKey(parsed_error.message)),"PreconditionFailed" => return RusotoError::Service(UpdateFieldLevelEncryptionProfileError::PreconditionFailed(parsed_error.message)),"TooManyFieldLevelEncryptionEncryptionEntities" => return RusotoError::Service(UpdateFieldLevelEncryptionProfileError::TooManyFieldLevelEncryptionEncryptionEntities(parsed_error.message)),"TooManyFieldLevelEncryptionFieldPatterns" => return RusotoError::Service(UpdateFieldLevelEncryptionProfileError::TooManyFieldLevelEncryptionFieldPatterns(parsed_error.message)),_ => {}
+                                    "AccessDenied" => return RusotoError::service(UpdateFieldLevelEncryptionProfileError::AccessDenied(parsed_error.message), &res),"FieldLevelEncryptionProfileAlreadyExists" => return RusotoError::service(UpdateFieldLevelEncryptionProfileError::FieldLevelEncryptionProfileAlreadyExists(parsed_error.message), &res),"FieldLevelEncryptionProfileSizeExceeded" => return RusotoError::service(UpdateFieldLevelEncryptionProfileError::FieldLevelEncryptionProfileSizeExceeded(parsed_error.message), &res),"IllegalUpdate" => return RusotoError::service(UpdateFieldLevelEncryptionProfileError::IllegalUpdate(parsed_error.message), &res),"InconsistentQuantities" => return RusotoError::service(UpdateFieldLevelEncryptionProfileError::InconsistentQuantities(parsed_error.message), &res),"InvalidArgument" => return RusotoError::service(UpdateFieldLevelEncryptionProfileError::InvalidArgument(parsed_error.message), &res),"InvalidIfMatchVersion" => return RusotoError::service(UpdateFieldLevelEncryptionProfileError::InvalidIfMatchVersion(parsed_error.message), &res),"NoSuchFieldLevelEncryptionProfile" => return RusotoError::service(UpdateFieldLevelEncryptionProfileError::NoSuchFieldLevelEncryptionProfile(parsed_error.message), &res),"NoSuchPublicKey" => return RusotoError::service(UpdateFieldLevelEncryptionProfileError::NoSuchPublicKey(parsed_error.message), &res),"PreconditionFailed" => return RusotoError::service(UpdateFieldLevelEncryptionProfileError::PreconditionFailed(parsed_error.message), &res),"TooManyFieldLevelEncryptionEncryptionEntities" => return RusotoError::service(UpdateFieldLevelEncryptionProfileError::TooManyFieldLevelEncryptionEncryptionEntities(parsed_error.message), &res),"TooManyFieldLevelEncryptionFieldPatterns" => return RusotoError::service(UpdateFieldLevelEncryptionProfileError::TooManyFieldLevelEncryptionFieldPatterns(parsed_error.message), &res),_ => return RusotoError::unhandled(UpdateFieldLevelEncryptionProfileError::Unhandled, &res),
                                 }
             }
         }
@@ -11820,13 +12182,15 @@ UpdateFieldLevelEncryptionProfileError::NoSuchFieldLevelEncryptionProfile(ref ca
 UpdateFieldLevelEncryptionProfileError::NoSuchPublicKey(ref cause) => write!(f, "{}", cause),
 UpdateFieldLevelEncryptionProfileError::PreconditionFailed(ref cause) => write!(f, "{}", cause),
 UpdateFieldLevelEncryptionProfileError::TooManyFieldLevelEncryptionEncryptionEntities(ref cause) => write!(f, "{}", cause),
-UpdateFieldLevelEncryptionProfileError::TooManyFieldLevelEncryptionFieldPatterns(ref cause) => write!(f, "{}", cause)
-                        }
+UpdateFieldLevelEncryptionProfileError::TooManyFieldLevelEncryptionFieldPatterns(ref cause) => write!(f, "{}", cause),
+            UpdateFieldLevelEncryptionProfileError::Unhandled(ref metadata) => write!(f, "{}", metadata),
+        }
     }
 }
 impl Error for UpdateFieldLevelEncryptionProfileError {}
 /// Errors returned by UpdatePublicKey
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdatePublicKeyError {
     /// <p>Access denied.</p>
     AccessDenied(String),
@@ -11842,6 +12206,8 @@ pub enum UpdatePublicKeyError {
     NoSuchPublicKey(String),
     /// <p>The precondition given in one or more of the request-header fields evaluated to <code>false</code>. </p>
     PreconditionFailed(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdatePublicKeyError {
@@ -11853,43 +12219,50 @@ impl UpdatePublicKeyError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "AccessDenied" => {
-                        return RusotoError::Service(UpdatePublicKeyError::AccessDenied(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            UpdatePublicKeyError::AccessDenied(parsed_error.message),
+                            &res,
+                        )
                     }
                     "CannotChangeImmutablePublicKeyFields" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             UpdatePublicKeyError::CannotChangeImmutablePublicKeyFields(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "IllegalUpdate" => {
-                        return RusotoError::Service(UpdatePublicKeyError::IllegalUpdate(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            UpdatePublicKeyError::IllegalUpdate(parsed_error.message),
+                            &res,
+                        )
                     }
                     "InvalidArgument" => {
-                        return RusotoError::Service(UpdatePublicKeyError::InvalidArgument(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            UpdatePublicKeyError::InvalidArgument(parsed_error.message),
+                            &res,
+                        )
                     }
                     "InvalidIfMatchVersion" => {
-                        return RusotoError::Service(UpdatePublicKeyError::InvalidIfMatchVersion(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            UpdatePublicKeyError::InvalidIfMatchVersion(parsed_error.message),
+                            &res,
+                        )
                     }
                     "NoSuchPublicKey" => {
-                        return RusotoError::Service(UpdatePublicKeyError::NoSuchPublicKey(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            UpdatePublicKeyError::NoSuchPublicKey(parsed_error.message),
+                            &res,
+                        )
                     }
                     "PreconditionFailed" => {
-                        return RusotoError::Service(UpdatePublicKeyError::PreconditionFailed(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            UpdatePublicKeyError::PreconditionFailed(parsed_error.message),
+                            &res,
+                        )
                     }
-                    _ => {}
+                    _ => return RusotoError::unhandled(UpdatePublicKeyError::Unhandled, &res),
                 }
             }
         }
@@ -11917,12 +12290,14 @@ impl fmt::Display for UpdatePublicKeyError {
             UpdatePublicKeyError::InvalidIfMatchVersion(ref cause) => write!(f, "{}", cause),
             UpdatePublicKeyError::NoSuchPublicKey(ref cause) => write!(f, "{}", cause),
             UpdatePublicKeyError::PreconditionFailed(ref cause) => write!(f, "{}", cause),
+            UpdatePublicKeyError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for UpdatePublicKeyError {}
 /// Errors returned by UpdateStreamingDistribution
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdateStreamingDistributionError {
     /// <p>Access denied.</p>
     AccessDenied(String),
@@ -11950,6 +12325,8 @@ pub enum UpdateStreamingDistributionError {
     TooManyTrustedSigners(String),
     /// <p>One or more of your trusted signers don't exist.</p>
     TrustedSignerDoesNotExist(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdateStreamingDistributionError {
@@ -11963,89 +12340,107 @@ impl UpdateStreamingDistributionError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "AccessDenied" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             UpdateStreamingDistributionError::AccessDenied(parsed_error.message),
+                            &res,
                         )
                     }
                     "CNAMEAlreadyExists" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             UpdateStreamingDistributionError::CNAMEAlreadyExists(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "IllegalUpdate" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             UpdateStreamingDistributionError::IllegalUpdate(parsed_error.message),
+                            &res,
                         )
                     }
                     "InconsistentQuantities" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             UpdateStreamingDistributionError::InconsistentQuantities(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "InvalidArgument" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             UpdateStreamingDistributionError::InvalidArgument(parsed_error.message),
+                            &res,
                         )
                     }
                     "InvalidIfMatchVersion" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             UpdateStreamingDistributionError::InvalidIfMatchVersion(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "InvalidOriginAccessIdentity" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             UpdateStreamingDistributionError::InvalidOriginAccessIdentity(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "MissingBody" => {
-                        return RusotoError::Service(UpdateStreamingDistributionError::MissingBody(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            UpdateStreamingDistributionError::MissingBody(parsed_error.message),
+                            &res,
+                        )
                     }
                     "NoSuchStreamingDistribution" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             UpdateStreamingDistributionError::NoSuchStreamingDistribution(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "PreconditionFailed" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             UpdateStreamingDistributionError::PreconditionFailed(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "TooManyStreamingDistributionCNAMEs" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             UpdateStreamingDistributionError::TooManyStreamingDistributionCNAMEs(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "TooManyTrustedSigners" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             UpdateStreamingDistributionError::TooManyTrustedSigners(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "TrustedSignerDoesNotExist" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             UpdateStreamingDistributionError::TrustedSignerDoesNotExist(
                                 parsed_error.message,
                             ),
+                            &res,
+                        )
+                    }
+                    _ => {
+                        return RusotoError::unhandled(
+                            UpdateStreamingDistributionError::Unhandled,
+                            &res,
                         )
                     }
-                    _ => {}
                 }
             }
         }
@@ -12095,6 +12490,7 @@ impl fmt::Display for UpdateStreamingDistributionError {
             UpdateStreamingDistributionError::TrustedSignerDoesNotExist(ref cause) => {
                 write!(f, "{}", cause)
             }
+            UpdateStreamingDistributionError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
@@ -12428,6 +12824,7 @@ impl CloudFrontClient {
     /// Creates a client backed by the default tokio event loop.
     ///
     /// The client will use the default credentials provider and tls client.
+    #[cfg(not(target_arch = "wasm32"))]
     pub fn new(region: region::Region) -> CloudFrontClient {
         CloudFrontClient {
             client: Client::shared(),
@@ -12453,44 +12850,55 @@ impl CloudFrontClient {
     pub fn new_with_client(client: Client, region: region::Region) -> CloudFrontClient {
         CloudFrontClient { client, region }
     }
+
+    /// Returns a builder of a client, setting any of its region, credentials provider,
+    /// dispatcher, retry policy, endpoint and timeouts.
+    pub fn builder() -> rusoto_core::ClientBuilder<CloudFrontClient> {
+        rusoto_core::ClientBuilder::new(CloudFrontClient::new_with_client)
+    }
+
+    /// Returns a clone of this client with the overrides of `config` applied to its
+    /// operations, sharing its connections.
+    pub fn customize(&self, config: &rusoto_core::OperationConfig) -> CloudFrontClient {
+        CloudFrontClient {
+            client: self.client.customize(config),
+            region: config.resolve_region(&self.region),
+        }
+    }
 }
 
-#[async_trait]
-impl CloudFront for CloudFrontClient {
-    /// <p>Creates a new origin access identity. If you're using Amazon S3 for your origin, you can use an origin access identity to require users to access your content using a CloudFront URL instead of the Amazon S3 URL. For more information about how to use origin access identities, see <a href="https://docs.aws.amazon.com/AmazonCloudFront/latest/DeveloperGuide/PrivateContent.html">Serving Private Content through CloudFront</a> in the <i>Amazon CloudFront Developer Guide</i>.</p>
+impl CloudFrontClient {
+    /// Calls `create_cloud_front_origin_access_identity`, also returning the metadata of the response.
     #[allow(unused_variables, warnings)]
-    async fn create_cloud_front_origin_access_identity(
+    pub async fn create_cloud_front_origin_access_identity_with_metadata(
         &self,
         input: CreateCloudFrontOriginAccessIdentityRequest,
     ) -> Result<
-        CreateCloudFrontOriginAccessIdentityResult,
+        (CreateCloudFrontOriginAccessIdentityResult, ResponseMetadata),
         RusotoError<CreateCloudFrontOriginAccessIdentityError>,
     > {
-        let request_uri = "/2019-03-26/origin-access-identity/cloudfront";
-
-        let mut request = SignedRequest::new("POST", "cloudfront", &self.region, &request_uri);
-
-        let mut writer = EventWriter::new(Vec::new());
-        CloudFrontOriginAccessIdentityConfigSerializer::serialize(
-            &mut writer,
-            "CloudFrontOriginAccessIdentityConfig",
-            &input.cloud_front_origin_access_identity_config,
-        );
-        request.set_payload(Some(writer.into_inner()));
+        let request = self.build_create_cloud_front_origin_access_identity_request(input);
 
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(CreateCloudFrontOriginAccessIdentityError::from_response(
                 response,
             ));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let mut result;
 
         if xml_response.body.is_empty() {
@@ -12516,38 +12924,67 @@ impl CloudFront for CloudFrontClient {
             let value = location.to_owned();
             result.location = Some(value)
         }; // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
+    }
+
+    /// Presigns the request `create_cloud_front_origin_access_identity` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_cloud_front_origin_access_identity(
+        &self,
+        presigner: &rusoto_core::Presigner,
+        input: CreateCloudFrontOriginAccessIdentityRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_cloud_front_origin_access_identity_request(input);
+        self.client.presign(presigner, request).await
     }
 
-    /// <p><p>Creates a new web distribution. You create a CloudFront distribution to tell CloudFront where you want content to be delivered from, and the details about how to track and manage content delivery. Send a <code>POST</code> request to the <code>/<i>CloudFront API version</i>/distribution</code>/<code>distribution ID</code> resource.</p> <important> <p>When you update a distribution, there are more required fields than when you create a distribution. When you update your distribution by using <a href="https://docs.aws.amazon.com/cloudfront/latest/APIReference/API_UpdateDistribution.html">UpdateDistribution</a>, follow the steps included in the documentation to get the current configuration and then make your updates. This helps to make sure that you include all of the required fields. To view a summary, see <a href="https://docs.aws.amazon.com/AmazonCloudFront/latest/DeveloperGuide/distribution-overview-required-fields.html">Required Fields for Create Distribution and Update Distribution</a> in the <i>Amazon CloudFront Developer Guide</i>.</p> </important></p>
     #[allow(unused_variables, warnings)]
-    async fn create_distribution(
+    fn build_create_cloud_front_origin_access_identity_request(
         &self,
-        input: CreateDistributionRequest,
-    ) -> Result<CreateDistributionResult, RusotoError<CreateDistributionError>> {
-        let request_uri = "/2019-03-26/distribution";
+        input: CreateCloudFrontOriginAccessIdentityRequest,
+    ) -> SignedRequest {
+        let request_uri = "/2019-03-26/origin-access-identity/cloudfront";
 
         let mut request = SignedRequest::new("POST", "cloudfront", &self.region, &request_uri);
+        request.set_operation("CreateCloudFrontOriginAccessIdentity");
 
         let mut writer = EventWriter::new(Vec::new());
-        DistributionConfigSerializer::serialize(
+        CloudFrontOriginAccessIdentityConfigSerializer::serialize(
             &mut writer,
-            "DistributionConfig",
-            &input.distribution_config,
+            "CloudFrontOriginAccessIdentityConfig",
+            &input.cloud_front_origin_access_identity_config,
         );
         request.set_payload(Some(writer.into_inner()));
 
+        request
+    }
+
+    /// Calls `create_distribution`, also returning the metadata of the response.
+    #[allow(unused_variables, warnings)]
+    pub async fn create_distribution_with_metadata(
+        &self,
+        input: CreateDistributionRequest,
+    ) -> Result<(CreateDistributionResult, ResponseMetadata), RusotoError<CreateDistributionError>>
+    {
+        let request = self.build_create_distribution_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(CreateDistributionError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let mut result;
 
         if xml_response.body.is_empty() {
@@ -12571,42 +13008,66 @@ impl CloudFront for CloudFrontClient {
             let value = location.to_owned();
             result.location = Some(value)
         }; // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Create a new distribution with tags.</p>
-    #[allow(unused_variables, warnings)]
-    async fn create_distribution_with_tags(
+    /// Presigns the request `create_distribution` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_distribution(
         &self,
-        input: CreateDistributionWithTagsRequest,
-    ) -> Result<CreateDistributionWithTagsResult, RusotoError<CreateDistributionWithTagsError>>
-    {
+        presigner: &rusoto_core::Presigner,
+        input: CreateDistributionRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_distribution_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    #[allow(unused_variables, warnings)]
+    fn build_create_distribution_request(&self, input: CreateDistributionRequest) -> SignedRequest {
         let request_uri = "/2019-03-26/distribution";
 
         let mut request = SignedRequest::new("POST", "cloudfront", &self.region, &request_uri);
+        request.set_operation("CreateDistribution");
 
-        let mut params = Params::new();
-        params.put_key("WithTags");
-        request.set_params(params);
         let mut writer = EventWriter::new(Vec::new());
-        DistributionConfigWithTagsSerializer::serialize(
+        DistributionConfigSerializer::serialize(
             &mut writer,
-            "DistributionConfigWithTags",
-            &input.distribution_config_with_tags,
+            "DistributionConfig",
+            &input.distribution_config,
         );
         request.set_payload(Some(writer.into_inner()));
 
+        request
+    }
+
+    /// Calls `create_distribution_with_tags`, also returning the metadata of the response.
+    #[allow(unused_variables, warnings)]
+    pub async fn create_distribution_with_tags_with_metadata(
+        &self,
+        input: CreateDistributionWithTagsRequest,
+    ) -> Result<
+        (CreateDistributionWithTagsResult, ResponseMetadata),
+        RusotoError<CreateDistributionWithTagsError>,
+    > {
+        let request = self.build_create_distribution_with_tags_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(CreateDistributionWithTagsError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let mut result;
 
         if xml_response.body.is_empty() {
@@ -12632,43 +13093,74 @@ impl CloudFront for CloudFrontClient {
             let value = location.to_owned();
             result.location = Some(value)
         }; // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
+    }
+
+    /// Presigns the request `create_distribution_with_tags` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_distribution_with_tags(
+        &self,
+        presigner: &rusoto_core::Presigner,
+        input: CreateDistributionWithTagsRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_distribution_with_tags_request(input);
+        self.client.presign(presigner, request).await
     }
 
-    /// <p>Create a new field-level encryption configuration.</p>
     #[allow(unused_variables, warnings)]
-    async fn create_field_level_encryption_config(
+    fn build_create_distribution_with_tags_request(
         &self,
-        input: CreateFieldLevelEncryptionConfigRequest,
-    ) -> Result<
-        CreateFieldLevelEncryptionConfigResult,
-        RusotoError<CreateFieldLevelEncryptionConfigError>,
-    > {
-        let request_uri = "/2019-03-26/field-level-encryption";
+        input: CreateDistributionWithTagsRequest,
+    ) -> SignedRequest {
+        let request_uri = "/2019-03-26/distribution";
 
         let mut request = SignedRequest::new("POST", "cloudfront", &self.region, &request_uri);
+        request.set_operation("CreateDistributionWithTags");
 
+        let mut params = Params::new();
+        params.put_key("WithTags");
+        request.set_params(params);
         let mut writer = EventWriter::new(Vec::new());
-        FieldLevelEncryptionConfigSerializer::serialize(
+        DistributionConfigWithTagsSerializer::serialize(
             &mut writer,
-            "FieldLevelEncryptionConfig",
-            &input.field_level_encryption_config,
+            "DistributionConfigWithTags",
+            &input.distribution_config_with_tags,
         );
         request.set_payload(Some(writer.into_inner()));
 
+        request
+    }
+
+    /// Calls `create_field_level_encryption_config`, also returning the metadata of the response.
+    #[allow(unused_variables, warnings)]
+    pub async fn create_field_level_encryption_config_with_metadata(
+        &self,
+        input: CreateFieldLevelEncryptionConfigRequest,
+    ) -> Result<
+        (CreateFieldLevelEncryptionConfigResult, ResponseMetadata),
+        RusotoError<CreateFieldLevelEncryptionConfigError>,
+    > {
+        let request = self.build_create_field_level_encryption_config_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(CreateFieldLevelEncryptionConfigError::from_response(
                 response,
             ));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let mut result;
 
         if xml_response.body.is_empty() {
@@ -12694,43 +13186,71 @@ impl CloudFront for CloudFrontClient {
             let value = location.to_owned();
             result.location = Some(value)
         }; // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
+    }
+
+    /// Presigns the request `create_field_level_encryption_config` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_field_level_encryption_config(
+        &self,
+        presigner: &rusoto_core::Presigner,
+        input: CreateFieldLevelEncryptionConfigRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_field_level_encryption_config_request(input);
+        self.client.presign(presigner, request).await
     }
 
-    /// <p>Create a field-level encryption profile.</p>
     #[allow(unused_variables, warnings)]
-    async fn create_field_level_encryption_profile(
+    fn build_create_field_level_encryption_config_request(
         &self,
-        input: CreateFieldLevelEncryptionProfileRequest,
-    ) -> Result<
-        CreateFieldLevelEncryptionProfileResult,
-        RusotoError<CreateFieldLevelEncryptionProfileError>,
-    > {
-        let request_uri = "/2019-03-26/field-level-encryption-profile";
+        input: CreateFieldLevelEncryptionConfigRequest,
+    ) -> SignedRequest {
+        let request_uri = "/2019-03-26/field-level-encryption";
 
         let mut request = SignedRequest::new("POST", "cloudfront", &self.region, &request_uri);
+        request.set_operation("CreateFieldLevelEncryptionConfig");
 
         let mut writer = EventWriter::new(Vec::new());
-        FieldLevelEncryptionProfileConfigSerializer::serialize(
+        FieldLevelEncryptionConfigSerializer::serialize(
             &mut writer,
-            "FieldLevelEncryptionProfileConfig",
-            &input.field_level_encryption_profile_config,
+            "FieldLevelEncryptionConfig",
+            &input.field_level_encryption_config,
         );
         request.set_payload(Some(writer.into_inner()));
 
+        request
+    }
+
+    /// Calls `create_field_level_encryption_profile`, also returning the metadata of the response.
+    #[allow(unused_variables, warnings)]
+    pub async fn create_field_level_encryption_profile_with_metadata(
+        &self,
+        input: CreateFieldLevelEncryptionProfileRequest,
+    ) -> Result<
+        (CreateFieldLevelEncryptionProfileResult, ResponseMetadata),
+        RusotoError<CreateFieldLevelEncryptionProfileError>,
+    > {
+        let request = self.build_create_field_level_encryption_profile_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(CreateFieldLevelEncryptionProfileError::from_response(
                 response,
             ));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let mut result;
 
         if xml_response.body.is_empty() {
@@ -12756,41 +13276,67 @@ impl CloudFront for CloudFrontClient {
             let value = location.to_owned();
             result.location = Some(value)
         }; // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
+    }
+
+    /// Presigns the request `create_field_level_encryption_profile` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_field_level_encryption_profile(
+        &self,
+        presigner: &rusoto_core::Presigner,
+        input: CreateFieldLevelEncryptionProfileRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_field_level_encryption_profile_request(input);
+        self.client.presign(presigner, request).await
     }
 
-    /// <p>Create a new invalidation. </p>
     #[allow(unused_variables, warnings)]
-    async fn create_invalidation(
+    fn build_create_field_level_encryption_profile_request(
         &self,
-        input: CreateInvalidationRequest,
-    ) -> Result<CreateInvalidationResult, RusotoError<CreateInvalidationError>> {
-        let request_uri = format!(
-            "/2019-03-26/distribution/{distribution_id}/invalidation",
-            distribution_id = input.distribution_id
-        );
+        input: CreateFieldLevelEncryptionProfileRequest,
+    ) -> SignedRequest {
+        let request_uri = "/2019-03-26/field-level-encryption-profile";
 
         let mut request = SignedRequest::new("POST", "cloudfront", &self.region, &request_uri);
+        request.set_operation("CreateFieldLevelEncryptionProfile");
 
         let mut writer = EventWriter::new(Vec::new());
-        InvalidationBatchSerializer::serialize(
+        FieldLevelEncryptionProfileConfigSerializer::serialize(
             &mut writer,
-            "InvalidationBatch",
-            &input.invalidation_batch,
+            "FieldLevelEncryptionProfileConfig",
+            &input.field_level_encryption_profile_config,
         );
         request.set_payload(Some(writer.into_inner()));
 
+        request
+    }
+
+    /// Calls `create_invalidation`, also returning the metadata of the response.
+    #[allow(unused_variables, warnings)]
+    pub async fn create_invalidation_with_metadata(
+        &self,
+        input: CreateInvalidationRequest,
+    ) -> Result<(CreateInvalidationResult, ResponseMetadata), RusotoError<CreateInvalidationError>>
+    {
+        let request = self.build_create_invalidation_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(CreateInvalidationError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let mut result;
 
         if xml_response.body.is_empty() {
@@ -12810,38 +13356,66 @@ impl CloudFront for CloudFrontClient {
             let value = location.to_owned();
             result.location = Some(value)
         }; // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Add a new public key to CloudFront to use, for example, for field-level encryption. You can add a maximum of 10 public keys with one AWS account.</p>
-    #[allow(unused_variables, warnings)]
-    async fn create_public_key(
+    /// Presigns the request `create_invalidation` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_invalidation(
         &self,
-        input: CreatePublicKeyRequest,
-    ) -> Result<CreatePublicKeyResult, RusotoError<CreatePublicKeyError>> {
-        let request_uri = "/2019-03-26/public-key";
+        presigner: &rusoto_core::Presigner,
+        input: CreateInvalidationRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_invalidation_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    #[allow(unused_variables, warnings)]
+    fn build_create_invalidation_request(&self, input: CreateInvalidationRequest) -> SignedRequest {
+        let request_uri = format!(
+            "/2019-03-26/distribution/{distribution_id}/invalidation",
+            distribution_id = input.distribution_id
+        );
 
         let mut request = SignedRequest::new("POST", "cloudfront", &self.region, &request_uri);
+        request.set_operation("CreateInvalidation");
 
         let mut writer = EventWriter::new(Vec::new());
-        PublicKeyConfigSerializer::serialize(
+        InvalidationBatchSerializer::serialize(
             &mut writer,
-            "PublicKeyConfig",
-            &input.public_key_config,
+            "InvalidationBatch",
+            &input.invalidation_batch,
         );
         request.set_payload(Some(writer.into_inner()));
 
+        request
+    }
+
+    /// Calls `create_public_key`, also returning the metadata of the response.
+    #[allow(unused_variables, warnings)]
+    pub async fn create_public_key_with_metadata(
+        &self,
+        input: CreatePublicKeyRequest,
+    ) -> Result<(CreatePublicKeyResult, ResponseMetadata), RusotoError<CreatePublicKeyError>> {
+        let request = self.build_create_public_key_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(CreatePublicKeyError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let mut result;
 
         if xml_response.body.is_empty() {
@@ -12864,39 +13438,66 @@ impl CloudFront for CloudFrontClient {
             let value = location.to_owned();
             result.location = Some(value)
         }; // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p><p>Creates a new RTMP distribution. An RTMP distribution is similar to a web distribution, but an RTMP distribution streams media files using the Adobe Real-Time Messaging Protocol (RTMP) instead of serving files using HTTP. </p> <p>To create a new distribution, submit a <code>POST</code> request to the <i>CloudFront API version</i>/distribution resource. The request body must include a document with a <i>StreamingDistributionConfig</i> element. The response echoes the <code>StreamingDistributionConfig</code> element and returns other information about the RTMP distribution.</p> <p>To get the status of your request, use the <i>GET StreamingDistribution</i> API action. When the value of <code>Enabled</code> is <code>true</code> and the value of <code>Status</code> is <code>Deployed</code>, your distribution is ready. A distribution usually deploys in less than 15 minutes.</p> <p>For more information about web distributions, see <a href="https://docs.aws.amazon.com/AmazonCloudFront/latest/DeveloperGuide/distribution-rtmp.html">Working with RTMP Distributions</a> in the <i>Amazon CloudFront Developer Guide</i>.</p> <important> <p>Beginning with the 2012-05-05 version of the CloudFront API, we made substantial changes to the format of the XML document that you include in the request body when you create or update a web distribution or an RTMP distribution, and when you invalidate objects. With previous versions of the API, we discovered that it was too easy to accidentally delete one or more values for an element that accepts multiple values, for example, CNAMEs and trusted signers. Our changes for the 2012-05-05 release are intended to prevent these accidental deletions and to notify you when there&#39;s a mismatch between the number of values you say you&#39;re specifying in the <code>Quantity</code> element and the number of values specified.</p> </important></p>
-    #[allow(unused_variables, warnings)]
-    async fn create_streaming_distribution(
+    /// Presigns the request `create_public_key` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_public_key(
         &self,
-        input: CreateStreamingDistributionRequest,
-    ) -> Result<CreateStreamingDistributionResult, RusotoError<CreateStreamingDistributionError>>
-    {
-        let request_uri = "/2019-03-26/streaming-distribution";
+        presigner: &rusoto_core::Presigner,
+        input: CreatePublicKeyRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_public_key_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    #[allow(unused_variables, warnings)]
+    fn build_create_public_key_request(&self, input: CreatePublicKeyRequest) -> SignedRequest {
+        let request_uri = "/2019-03-26/public-key";
 
         let mut request = SignedRequest::new("POST", "cloudfront", &self.region, &request_uri);
+        request.set_operation("CreatePublicKey");
 
         let mut writer = EventWriter::new(Vec::new());
-        StreamingDistributionConfigSerializer::serialize(
+        PublicKeyConfigSerializer::serialize(
             &mut writer,
-            "StreamingDistributionConfig",
-            &input.streaming_distribution_config,
+            "PublicKeyConfig",
+            &input.public_key_config,
         );
         request.set_payload(Some(writer.into_inner()));
 
+        request
+    }
+
+    /// Calls `create_streaming_distribution`, also returning the metadata of the response.
+    #[allow(unused_variables, warnings)]
+    pub async fn create_streaming_distribution_with_metadata(
+        &self,
+        input: CreateStreamingDistributionRequest,
+    ) -> Result<
+        (CreateStreamingDistributionResult, ResponseMetadata),
+        RusotoError<CreateStreamingDistributionError>,
+    > {
+        let request = self.build_create_streaming_distribution_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(CreateStreamingDistributionError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let mut result;
 
         if xml_response.body.is_empty() {
@@ -12922,46 +13523,71 @@ impl CloudFront for CloudFrontClient {
             let value = location.to_owned();
             result.location = Some(value)
         }; // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
+    }
+
+    /// Presigns the request `create_streaming_distribution` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_streaming_distribution(
+        &self,
+        presigner: &rusoto_core::Presigner,
+        input: CreateStreamingDistributionRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_streaming_distribution_request(input);
+        self.client.presign(presigner, request).await
     }
 
-    /// <p>Create a new streaming distribution with tags.</p>
     #[allow(unused_variables, warnings)]
-    async fn create_streaming_distribution_with_tags(
+    fn build_create_streaming_distribution_request(
         &self,
-        input: CreateStreamingDistributionWithTagsRequest,
-    ) -> Result<
-        CreateStreamingDistributionWithTagsResult,
-        RusotoError<CreateStreamingDistributionWithTagsError>,
-    > {
+        input: CreateStreamingDistributionRequest,
+    ) -> SignedRequest {
         let request_uri = "/2019-03-26/streaming-distribution";
 
         let mut request = SignedRequest::new("POST", "cloudfront", &self.region, &request_uri);
+        request.set_operation("CreateStreamingDistribution");
 
-        let mut params = Params::new();
-        params.put_key("WithTags");
-        request.set_params(params);
         let mut writer = EventWriter::new(Vec::new());
-        StreamingDistributionConfigWithTagsSerializer::serialize(
+        StreamingDistributionConfigSerializer::serialize(
             &mut writer,
-            "StreamingDistributionConfigWithTags",
-            &input.streaming_distribution_config_with_tags,
+            "StreamingDistributionConfig",
+            &input.streaming_distribution_config,
         );
         request.set_payload(Some(writer.into_inner()));
 
+        request
+    }
+
+    /// Calls `create_streaming_distribution_with_tags`, also returning the metadata of the response.
+    #[allow(unused_variables, warnings)]
+    pub async fn create_streaming_distribution_with_tags_with_metadata(
+        &self,
+        input: CreateStreamingDistributionWithTagsRequest,
+    ) -> Result<
+        (CreateStreamingDistributionWithTagsResult, ResponseMetadata),
+        RusotoError<CreateStreamingDistributionWithTagsError>,
+    > {
+        let request = self.build_create_streaming_distribution_with_tags_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(CreateStreamingDistributionWithTagsError::from_response(
                 response,
             ));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let mut result;
 
         if xml_response.body.is_empty() {
@@ -12987,218 +13613,399 @@ impl CloudFront for CloudFrontClient {
             let value = location.to_owned();
             result.location = Some(value)
         }; // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
+    }
+
+    /// Presigns the request `create_streaming_distribution_with_tags` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_streaming_distribution_with_tags(
+        &self,
+        presigner: &rusoto_core::Presigner,
+        input: CreateStreamingDistributionWithTagsRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_streaming_distribution_with_tags_request(input);
+        self.client.presign(presigner, request).await
     }
 
-    /// <p>Delete an origin access identity. </p>
     #[allow(unused_variables, warnings)]
-    async fn delete_cloud_front_origin_access_identity(
+    fn build_create_streaming_distribution_with_tags_request(
         &self,
-        input: DeleteCloudFrontOriginAccessIdentityRequest,
-    ) -> Result<(), RusotoError<DeleteCloudFrontOriginAccessIdentityError>> {
-        let request_uri = format!(
-            "/2019-03-26/origin-access-identity/cloudfront/{id}",
-            id = input.id
+        input: CreateStreamingDistributionWithTagsRequest,
+    ) -> SignedRequest {
+        let request_uri = "/2019-03-26/streaming-distribution";
+
+        let mut request = SignedRequest::new("POST", "cloudfront", &self.region, &request_uri);
+        request.set_operation("CreateStreamingDistributionWithTags");
+
+        let mut params = Params::new();
+        params.put_key("WithTags");
+        request.set_params(params);
+        let mut writer = EventWriter::new(Vec::new());
+        StreamingDistributionConfigWithTagsSerializer::serialize(
+            &mut writer,
+            "StreamingDistributionConfigWithTags",
+            &input.streaming_distribution_config_with_tags,
         );
+        request.set_payload(Some(writer.into_inner()));
 
-        let mut request = SignedRequest::new("DELETE", "cloudfront", &self.region, &request_uri);
+        request
+    }
 
-        if let Some(ref if_match) = input.if_match {
-            request.add_header("If-Match", &if_match.to_string());
-        }
+    /// Calls `delete_cloud_front_origin_access_identity`, also returning the metadata of the response.
+    #[allow(unused_variables, warnings)]
+    pub async fn delete_cloud_front_origin_access_identity_with_metadata(
+        &self,
+        input: DeleteCloudFrontOriginAccessIdentityRequest,
+    ) -> Result<((), ResponseMetadata), RusotoError<DeleteCloudFrontOriginAccessIdentityError>>
+    {
+        let request = self.build_delete_cloud_front_origin_access_identity_request(input);
 
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(DeleteCloudFrontOriginAccessIdentityError::from_response(
                 response,
             ));
         }
 
         std::mem::drop(response);
-        Ok(())
+        Ok(((), metadata))
+    }
+
+    /// Presigns the request `delete_cloud_front_origin_access_identity` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_cloud_front_origin_access_identity(
+        &self,
+        presigner: &rusoto_core::Presigner,
+        input: DeleteCloudFrontOriginAccessIdentityRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_cloud_front_origin_access_identity_request(input);
+        self.client.presign(presigner, request).await
     }
 
-    /// <p>Delete a distribution. </p>
     #[allow(unused_variables, warnings)]
-    async fn delete_distribution(
+    fn build_delete_cloud_front_origin_access_identity_request(
         &self,
-        input: DeleteDistributionRequest,
-    ) -> Result<(), RusotoError<DeleteDistributionError>> {
-        let request_uri = format!("/2019-03-26/distribution/{id}", id = input.id);
+        input: DeleteCloudFrontOriginAccessIdentityRequest,
+    ) -> SignedRequest {
+        let request_uri = format!(
+            "/2019-03-26/origin-access-identity/cloudfront/{id}",
+            id = input.id
+        );
 
         let mut request = SignedRequest::new("DELETE", "cloudfront", &self.region, &request_uri);
+        request.set_operation("DeleteCloudFrontOriginAccessIdentity");
 
         if let Some(ref if_match) = input.if_match {
             request.add_header("If-Match", &if_match.to_string());
         }
 
+        request
+    }
+
+    /// Calls `delete_distribution`, also returning the metadata of the response.
+    #[allow(unused_variables, warnings)]
+    pub async fn delete_distribution_with_metadata(
+        &self,
+        input: DeleteDistributionRequest,
+    ) -> Result<((), ResponseMetadata), RusotoError<DeleteDistributionError>> {
+        let request = self.build_delete_distribution_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(DeleteDistributionError::from_response(response));
         }
 
         std::mem::drop(response);
-        Ok(())
+        Ok(((), metadata))
     }
 
-    /// <p>Remove a field-level encryption configuration.</p>
-    #[allow(unused_variables, warnings)]
-    async fn delete_field_level_encryption_config(
+    /// Presigns the request `delete_distribution` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_distribution(
         &self,
-        input: DeleteFieldLevelEncryptionConfigRequest,
-    ) -> Result<(), RusotoError<DeleteFieldLevelEncryptionConfigError>> {
-        let request_uri = format!("/2019-03-26/field-level-encryption/{id}", id = input.id);
+        presigner: &rusoto_core::Presigner,
+        input: DeleteDistributionRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_distribution_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    #[allow(unused_variables, warnings)]
+    fn build_delete_distribution_request(&self, input: DeleteDistributionRequest) -> SignedRequest {
+        let request_uri = format!("/2019-03-26/distribution/{id}", id = input.id);
 
         let mut request = SignedRequest::new("DELETE", "cloudfront", &self.region, &request_uri);
+        request.set_operation("DeleteDistribution");
 
         if let Some(ref if_match) = input.if_match {
             request.add_header("If-Match", &if_match.to_string());
         }
 
+        request
+    }
+
+    /// Calls `delete_field_level_encryption_config`, also returning the metadata of the response.
+    #[allow(unused_variables, warnings)]
+    pub async fn delete_field_level_encryption_config_with_metadata(
+        &self,
+        input: DeleteFieldLevelEncryptionConfigRequest,
+    ) -> Result<((), ResponseMetadata), RusotoError<DeleteFieldLevelEncryptionConfigError>> {
+        let request = self.build_delete_field_level_encryption_config_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(DeleteFieldLevelEncryptionConfigError::from_response(
                 response,
             ));
         }
 
         std::mem::drop(response);
-        Ok(())
+        Ok(((), metadata))
+    }
+
+    /// Presigns the request `delete_field_level_encryption_config` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_field_level_encryption_config(
+        &self,
+        presigner: &rusoto_core::Presigner,
+        input: DeleteFieldLevelEncryptionConfigRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_field_level_encryption_config_request(input);
+        self.client.presign(presigner, request).await
     }
 
-    /// <p>Remove a field-level encryption profile.</p>
     #[allow(unused_variables, warnings)]
-    async fn delete_field_level_encryption_profile(
+    fn build_delete_field_level_encryption_config_request(
         &self,
-        input: DeleteFieldLevelEncryptionProfileRequest,
-    ) -> Result<(), RusotoError<DeleteFieldLevelEncryptionProfileError>> {
-        let request_uri = format!(
-            "/2019-03-26/field-level-encryption-profile/{id}",
-            id = input.id
-        );
+        input: DeleteFieldLevelEncryptionConfigRequest,
+    ) -> SignedRequest {
+        let request_uri = format!("/2019-03-26/field-level-encryption/{id}", id = input.id);
 
         let mut request = SignedRequest::new("DELETE", "cloudfront", &self.region, &request_uri);
+        request.set_operation("DeleteFieldLevelEncryptionConfig");
 
         if let Some(ref if_match) = input.if_match {
             request.add_header("If-Match", &if_match.to_string());
         }
 
-        let mut response = self
-            .client
-            .sign_and_dispatch(request)
+        request
+    }
+
+    /// Calls `delete_field_level_encryption_profile`, also returning the metadata of the response.
+    #[allow(unused_variables, warnings)]
+    pub async fn delete_field_level_encryption_profile_with_metadata(
+        &self,
+        input: DeleteFieldLevelEncryptionProfileRequest,
+    ) -> Result<((), ResponseMetadata), RusotoError<DeleteFieldLevelEncryptionProfileError>> {
+        let request = self.build_delete_field_level_encryption_profile_request(input);
+
+        let mut response = self
+            .client
+            .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(DeleteFieldLevelEncryptionProfileError::from_response(
                 response,
             ));
         }
 
         std::mem::drop(response);
-        Ok(())
+        Ok(((), metadata))
+    }
+
+    /// Presigns the request `delete_field_level_encryption_profile` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_field_level_encryption_profile(
+        &self,
+        presigner: &rusoto_core::Presigner,
+        input: DeleteFieldLevelEncryptionProfileRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_field_level_encryption_profile_request(input);
+        self.client.presign(presigner, request).await
     }
 
-    /// <p>Remove a public key you previously added to CloudFront.</p>
     #[allow(unused_variables, warnings)]
-    async fn delete_public_key(
+    fn build_delete_field_level_encryption_profile_request(
         &self,
-        input: DeletePublicKeyRequest,
-    ) -> Result<(), RusotoError<DeletePublicKeyError>> {
-        let request_uri = format!("/2019-03-26/public-key/{id}", id = input.id);
+        input: DeleteFieldLevelEncryptionProfileRequest,
+    ) -> SignedRequest {
+        let request_uri = format!(
+            "/2019-03-26/field-level-encryption-profile/{id}",
+            id = input.id
+        );
 
         let mut request = SignedRequest::new("DELETE", "cloudfront", &self.region, &request_uri);
+        request.set_operation("DeleteFieldLevelEncryptionProfile");
 
         if let Some(ref if_match) = input.if_match {
             request.add_header("If-Match", &if_match.to_string());
         }
 
+        request
+    }
+
+    /// Calls `delete_public_key`, also returning the metadata of the response.
+    #[allow(unused_variables, warnings)]
+    pub async fn delete_public_key_with_metadata(
+        &self,
+        input: DeletePublicKeyRequest,
+    ) -> Result<((), ResponseMetadata), RusotoError<DeletePublicKeyError>> {
+        let request = self.build_delete_public_key_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(DeletePublicKeyError::from_response(response));
         }
 
         std::mem::drop(response);
-        Ok(())
+        Ok(((), metadata))
     }
 
-    /// <p>Delete a streaming distribution. To delete an RTMP distribution using the CloudFront API, perform the following steps.</p> <p> <b>To delete an RTMP distribution using the CloudFront API</b>:</p> <ol> <li> <p>Disable the RTMP distribution.</p> </li> <li> <p>Submit a <code>GET Streaming Distribution Config</code> request to get the current configuration and the <code>Etag</code> header for the distribution. </p> </li> <li> <p>Update the XML document that was returned in the response to your <code>GET Streaming Distribution Config</code> request to change the value of <code>Enabled</code> to <code>false</code>.</p> </li> <li> <p>Submit a <code>PUT Streaming Distribution Config</code> request to update the configuration for your distribution. In the request body, include the XML document that you updated in Step 3. Then set the value of the HTTP <code>If-Match</code> header to the value of the <code>ETag</code> header that CloudFront returned when you submitted the <code>GET Streaming Distribution Config</code> request in Step 2.</p> </li> <li> <p>Review the response to the <code>PUT Streaming Distribution Config</code> request to confirm that the distribution was successfully disabled.</p> </li> <li> <p>Submit a <code>GET Streaming Distribution Config</code> request to confirm that your changes have propagated. When propagation is complete, the value of <code>Status</code> is <code>Deployed</code>.</p> </li> <li> <p>Submit a <code>DELETE Streaming Distribution</code> request. Set the value of the HTTP <code>If-Match</code> header to the value of the <code>ETag</code> header that CloudFront returned when you submitted the <code>GET Streaming Distribution Config</code> request in Step 2.</p> </li> <li> <p>Review the response to your <code>DELETE Streaming Distribution</code> request to confirm that the distribution was successfully deleted.</p> </li> </ol> <p>For information about deleting a distribution using the CloudFront console, see <a href="https://docs.aws.amazon.com/AmazonCloudFront/latest/DeveloperGuide/HowToDeleteDistribution.html">Deleting a Distribution</a> in the <i>Amazon CloudFront Developer Guide</i>.</p>
-    #[allow(unused_variables, warnings)]
-    async fn delete_streaming_distribution(
+    /// Presigns the request `delete_public_key` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_public_key(
         &self,
-        input: DeleteStreamingDistributionRequest,
-    ) -> Result<(), RusotoError<DeleteStreamingDistributionError>> {
-        let request_uri = format!("/2019-03-26/streaming-distribution/{id}", id = input.id);
+        presigner: &rusoto_core::Presigner,
+        input: DeletePublicKeyRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_public_key_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    #[allow(unused_variables, warnings)]
+    fn build_delete_public_key_request(&self, input: DeletePublicKeyRequest) -> SignedRequest {
+        let request_uri = format!("/2019-03-26/public-key/{id}", id = input.id);
 
         let mut request = SignedRequest::new("DELETE", "cloudfront", &self.region, &request_uri);
+        request.set_operation("DeletePublicKey");
 
         if let Some(ref if_match) = input.if_match {
             request.add_header("If-Match", &if_match.to_string());
         }
 
+        request
+    }
+
+    /// Calls `delete_streaming_distribution`, also returning the metadata of the response.
+    #[allow(unused_variables, warnings)]
+    pub async fn delete_streaming_distribution_with_metadata(
+        &self,
+        input: DeleteStreamingDistributionRequest,
+    ) -> Result<((), ResponseMetadata), RusotoError<DeleteStreamingDistributionError>> {
+        let request = self.build_delete_streaming_distribution_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(DeleteStreamingDistributionError::from_response(response));
         }
 
         std::mem::drop(response);
-        Ok(())
+        Ok(((), metadata))
+    }
+
+    /// Presigns the request `delete_streaming_distribution` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_streaming_distribution(
+        &self,
+        presigner: &rusoto_core::Presigner,
+        input: DeleteStreamingDistributionRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_streaming_distribution_request(input);
+        self.client.presign(presigner, request).await
     }
 
-    /// <p>Get the information about an origin access identity. </p>
     #[allow(unused_variables, warnings)]
-    async fn get_cloud_front_origin_access_identity(
+    fn build_delete_streaming_distribution_request(
+        &self,
+        input: DeleteStreamingDistributionRequest,
+    ) -> SignedRequest {
+        let request_uri = format!("/2019-03-26/streaming-distribution/{id}", id = input.id);
+
+        let mut request = SignedRequest::new("DELETE", "cloudfront", &self.region, &request_uri);
+        request.set_operation("DeleteStreamingDistribution");
+
+        if let Some(ref if_match) = input.if_match {
+            request.add_header("If-Match", &if_match.to_string());
+        }
+
+        request
+    }
+
+    /// Calls `get_cloud_front_origin_access_identity`, also returning the metadata of the response.
+    #[allow(unused_variables, warnings)]
+    pub async fn get_cloud_front_origin_access_identity_with_metadata(
         &self,
         input: GetCloudFrontOriginAccessIdentityRequest,
     ) -> Result<
-        GetCloudFrontOriginAccessIdentityResult,
+        (GetCloudFrontOriginAccessIdentityResult, ResponseMetadata),
         RusotoError<GetCloudFrontOriginAccessIdentityError>,
     > {
-        let request_uri = format!(
-            "/2019-03-26/origin-access-identity/cloudfront/{id}",
-            id = input.id
-        );
-
-        let mut request = SignedRequest::new("GET", "cloudfront", &self.region, &request_uri);
+        let request = self.build_get_cloud_front_origin_access_identity_request(input);
 
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(GetCloudFrontOriginAccessIdentityError::from_response(
                 response,
             ));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let mut result;
 
         if xml_response.body.is_empty() {
@@ -13220,38 +14027,69 @@ impl CloudFront for CloudFrontClient {
             let value = e_tag.to_owned();
             result.e_tag = Some(value)
         }; // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
+    }
+
+    /// Presigns the request `get_cloud_front_origin_access_identity` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_cloud_front_origin_access_identity(
+        &self,
+        presigner: &rusoto_core::Presigner,
+        input: GetCloudFrontOriginAccessIdentityRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_cloud_front_origin_access_identity_request(input);
+        self.client.presign(presigner, request).await
     }
 
-    /// <p>Get the configuration information about an origin access identity. </p>
     #[allow(unused_variables, warnings)]
-    async fn get_cloud_front_origin_access_identity_config(
+    fn build_get_cloud_front_origin_access_identity_request(
         &self,
-        input: GetCloudFrontOriginAccessIdentityConfigRequest,
-    ) -> Result<
-        GetCloudFrontOriginAccessIdentityConfigResult,
-        RusotoError<GetCloudFrontOriginAccessIdentityConfigError>,
-    > {
+        input: GetCloudFrontOriginAccessIdentityRequest,
+    ) -> SignedRequest {
         let request_uri = format!(
-            "/2019-03-26/origin-access-identity/cloudfront/{id}/config",
+            "/2019-03-26/origin-access-identity/cloudfront/{id}",
             id = input.id
         );
 
         let mut request = SignedRequest::new("GET", "cloudfront", &self.region, &request_uri);
+        request.set_operation("GetCloudFrontOriginAccessIdentity");
+
+        request
+    }
+
+    /// Calls `get_cloud_front_origin_access_identity_config`, also returning the metadata of the response.
+    #[allow(unused_variables, warnings)]
+    pub async fn get_cloud_front_origin_access_identity_config_with_metadata(
+        &self,
+        input: GetCloudFrontOriginAccessIdentityConfigRequest,
+    ) -> Result<
+        (
+            GetCloudFrontOriginAccessIdentityConfigResult,
+            ResponseMetadata,
+        ),
+        RusotoError<GetCloudFrontOriginAccessIdentityConfigError>,
+    > {
+        let request = self.build_get_cloud_front_origin_access_identity_config_request(input);
 
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(GetCloudFrontOriginAccessIdentityConfigError::from_response(
                 response,
             ));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let mut result;
 
         if xml_response.body.is_empty() {
@@ -13273,30 +14111,61 @@ impl CloudFront for CloudFrontClient {
             let value = e_tag.to_owned();
             result.e_tag = Some(value)
         }; // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
+    }
+
+    /// Presigns the request `get_cloud_front_origin_access_identity_config` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_cloud_front_origin_access_identity_config(
+        &self,
+        presigner: &rusoto_core::Presigner,
+        input: GetCloudFrontOriginAccessIdentityConfigRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_cloud_front_origin_access_identity_config_request(input);
+        self.client.presign(presigner, request).await
     }
 
-    /// <p>Get the information about a distribution.</p>
     #[allow(unused_variables, warnings)]
-    async fn get_distribution(
+    fn build_get_cloud_front_origin_access_identity_config_request(
         &self,
-        input: GetDistributionRequest,
-    ) -> Result<GetDistributionResult, RusotoError<GetDistributionError>> {
-        let request_uri = format!("/2019-03-26/distribution/{id}", id = input.id);
+        input: GetCloudFrontOriginAccessIdentityConfigRequest,
+    ) -> SignedRequest {
+        let request_uri = format!(
+            "/2019-03-26/origin-access-identity/cloudfront/{id}/config",
+            id = input.id
+        );
 
         let mut request = SignedRequest::new("GET", "cloudfront", &self.region, &request_uri);
+        request.set_operation("GetCloudFrontOriginAccessIdentityConfig");
+
+        request
+    }
+
+    /// Calls `get_distribution`, also returning the metadata of the response.
+    #[allow(unused_variables, warnings)]
+    pub async fn get_distribution_with_metadata(
+        &self,
+        input: GetDistributionRequest,
+    ) -> Result<(GetDistributionResult, ResponseMetadata), RusotoError<GetDistributionError>> {
+        let request = self.build_get_distribution_request(input);
 
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(GetDistributionError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let mut result;
 
         if xml_response.body.is_empty() {
@@ -13315,30 +14184,58 @@ impl CloudFront for CloudFrontClient {
             let value = e_tag.to_owned();
             result.e_tag = Some(value)
         }; // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Get the configuration information about a distribution. </p>
-    #[allow(unused_variables, warnings)]
-    async fn get_distribution_config(
+    /// Presigns the request `get_distribution` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_distribution(
         &self,
-        input: GetDistributionConfigRequest,
-    ) -> Result<GetDistributionConfigResult, RusotoError<GetDistributionConfigError>> {
-        let request_uri = format!("/2019-03-26/distribution/{id}/config", id = input.id);
+        presigner: &rusoto_core::Presigner,
+        input: GetDistributionRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_distribution_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    #[allow(unused_variables, warnings)]
+    fn build_get_distribution_request(&self, input: GetDistributionRequest) -> SignedRequest {
+        let request_uri = format!("/2019-03-26/distribution/{id}", id = input.id);
 
         let mut request = SignedRequest::new("GET", "cloudfront", &self.region, &request_uri);
+        request.set_operation("GetDistribution");
+
+        request
+    }
+
+    /// Calls `get_distribution_config`, also returning the metadata of the response.
+    #[allow(unused_variables, warnings)]
+    pub async fn get_distribution_config_with_metadata(
+        &self,
+        input: GetDistributionConfigRequest,
+    ) -> Result<
+        (GetDistributionConfigResult, ResponseMetadata),
+        RusotoError<GetDistributionConfigError>,
+    > {
+        let request = self.build_get_distribution_config_request(input);
 
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(GetDistributionConfigError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let mut result;
 
         if xml_response.body.is_empty() {
@@ -13358,30 +14255,61 @@ impl CloudFront for CloudFrontClient {
             let value = e_tag.to_owned();
             result.e_tag = Some(value)
         }; // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
+    }
+
+    /// Presigns the request `get_distribution_config` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_distribution_config(
+        &self,
+        presigner: &rusoto_core::Presigner,
+        input: GetDistributionConfigRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_distribution_config_request(input);
+        self.client.presign(presigner, request).await
     }
 
-    /// <p>Get the field-level encryption configuration information.</p>
     #[allow(unused_variables, warnings)]
-    async fn get_field_level_encryption(
+    fn build_get_distribution_config_request(
         &self,
-        input: GetFieldLevelEncryptionRequest,
-    ) -> Result<GetFieldLevelEncryptionResult, RusotoError<GetFieldLevelEncryptionError>> {
-        let request_uri = format!("/2019-03-26/field-level-encryption/{id}", id = input.id);
+        input: GetDistributionConfigRequest,
+    ) -> SignedRequest {
+        let request_uri = format!("/2019-03-26/distribution/{id}/config", id = input.id);
 
         let mut request = SignedRequest::new("GET", "cloudfront", &self.region, &request_uri);
+        request.set_operation("GetDistributionConfig");
+
+        request
+    }
+
+    /// Calls `get_field_level_encryption`, also returning the metadata of the response.
+    #[allow(unused_variables, warnings)]
+    pub async fn get_field_level_encryption_with_metadata(
+        &self,
+        input: GetFieldLevelEncryptionRequest,
+    ) -> Result<
+        (GetFieldLevelEncryptionResult, ResponseMetadata),
+        RusotoError<GetFieldLevelEncryptionError>,
+    > {
+        let request = self.build_get_field_level_encryption_request(input);
 
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(GetFieldLevelEncryptionError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let mut result;
 
         if xml_response.body.is_empty() {
@@ -13403,34 +14331,61 @@ impl CloudFront for CloudFrontClient {
             let value = e_tag.to_owned();
             result.e_tag = Some(value)
         }; // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
+    }
+
+    /// Presigns the request `get_field_level_encryption` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_field_level_encryption(
+        &self,
+        presigner: &rusoto_core::Presigner,
+        input: GetFieldLevelEncryptionRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_field_level_encryption_request(input);
+        self.client.presign(presigner, request).await
     }
 
-    /// <p>Get the field-level encryption configuration information.</p>
     #[allow(unused_variables, warnings)]
-    async fn get_field_level_encryption_config(
+    fn build_get_field_level_encryption_request(
         &self,
-        input: GetFieldLevelEncryptionConfigRequest,
-    ) -> Result<GetFieldLevelEncryptionConfigResult, RusotoError<GetFieldLevelEncryptionConfigError>>
-    {
-        let request_uri = format!(
-            "/2019-03-26/field-level-encryption/{id}/config",
-            id = input.id
-        );
+        input: GetFieldLevelEncryptionRequest,
+    ) -> SignedRequest {
+        let request_uri = format!("/2019-03-26/field-level-encryption/{id}", id = input.id);
 
         let mut request = SignedRequest::new("GET", "cloudfront", &self.region, &request_uri);
+        request.set_operation("GetFieldLevelEncryption");
+
+        request
+    }
+
+    /// Calls `get_field_level_encryption_config`, also returning the metadata of the response.
+    #[allow(unused_variables, warnings)]
+    pub async fn get_field_level_encryption_config_with_metadata(
+        &self,
+        input: GetFieldLevelEncryptionConfigRequest,
+    ) -> Result<
+        (GetFieldLevelEncryptionConfigResult, ResponseMetadata),
+        RusotoError<GetFieldLevelEncryptionConfigError>,
+    > {
+        let request = self.build_get_field_level_encryption_config_request(input);
 
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(GetFieldLevelEncryptionConfigError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let mut result;
 
         if xml_response.body.is_empty() {
@@ -13452,36 +14407,64 @@ impl CloudFront for CloudFrontClient {
             let value = e_tag.to_owned();
             result.e_tag = Some(value)
         }; // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
+    }
+
+    /// Presigns the request `get_field_level_encryption_config` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_field_level_encryption_config(
+        &self,
+        presigner: &rusoto_core::Presigner,
+        input: GetFieldLevelEncryptionConfigRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_field_level_encryption_config_request(input);
+        self.client.presign(presigner, request).await
     }
 
-    /// <p>Get the field-level encryption profile information.</p>
     #[allow(unused_variables, warnings)]
-    async fn get_field_level_encryption_profile(
+    fn build_get_field_level_encryption_config_request(
         &self,
-        input: GetFieldLevelEncryptionProfileRequest,
-    ) -> Result<
-        GetFieldLevelEncryptionProfileResult,
-        RusotoError<GetFieldLevelEncryptionProfileError>,
-    > {
+        input: GetFieldLevelEncryptionConfigRequest,
+    ) -> SignedRequest {
         let request_uri = format!(
-            "/2019-03-26/field-level-encryption-profile/{id}",
+            "/2019-03-26/field-level-encryption/{id}/config",
             id = input.id
         );
 
         let mut request = SignedRequest::new("GET", "cloudfront", &self.region, &request_uri);
+        request.set_operation("GetFieldLevelEncryptionConfig");
+
+        request
+    }
+
+    /// Calls `get_field_level_encryption_profile`, also returning the metadata of the response.
+    #[allow(unused_variables, warnings)]
+    pub async fn get_field_level_encryption_profile_with_metadata(
+        &self,
+        input: GetFieldLevelEncryptionProfileRequest,
+    ) -> Result<
+        (GetFieldLevelEncryptionProfileResult, ResponseMetadata),
+        RusotoError<GetFieldLevelEncryptionProfileError>,
+    > {
+        let request = self.build_get_field_level_encryption_profile_request(input);
 
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(GetFieldLevelEncryptionProfileError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let mut result;
 
         if xml_response.body.is_empty() {
@@ -13503,38 +14486,66 @@ impl CloudFront for CloudFrontClient {
             let value = e_tag.to_owned();
             result.e_tag = Some(value)
         }; // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
+    }
+
+    /// Presigns the request `get_field_level_encryption_profile` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_field_level_encryption_profile(
+        &self,
+        presigner: &rusoto_core::Presigner,
+        input: GetFieldLevelEncryptionProfileRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_field_level_encryption_profile_request(input);
+        self.client.presign(presigner, request).await
     }
 
-    /// <p>Get the field-level encryption profile configuration information.</p>
     #[allow(unused_variables, warnings)]
-    async fn get_field_level_encryption_profile_config(
+    fn build_get_field_level_encryption_profile_request(
         &self,
-        input: GetFieldLevelEncryptionProfileConfigRequest,
-    ) -> Result<
-        GetFieldLevelEncryptionProfileConfigResult,
-        RusotoError<GetFieldLevelEncryptionProfileConfigError>,
-    > {
+        input: GetFieldLevelEncryptionProfileRequest,
+    ) -> SignedRequest {
         let request_uri = format!(
-            "/2019-03-26/field-level-encryption-profile/{id}/config",
+            "/2019-03-26/field-level-encryption-profile/{id}",
             id = input.id
         );
 
         let mut request = SignedRequest::new("GET", "cloudfront", &self.region, &request_uri);
+        request.set_operation("GetFieldLevelEncryptionProfile");
+
+        request
+    }
+
+    /// Calls `get_field_level_encryption_profile_config`, also returning the metadata of the response.
+    #[allow(unused_variables, warnings)]
+    pub async fn get_field_level_encryption_profile_config_with_metadata(
+        &self,
+        input: GetFieldLevelEncryptionProfileConfigRequest,
+    ) -> Result<
+        (GetFieldLevelEncryptionProfileConfigResult, ResponseMetadata),
+        RusotoError<GetFieldLevelEncryptionProfileConfigError>,
+    > {
+        let request = self.build_get_field_level_encryption_profile_config_request(input);
 
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(GetFieldLevelEncryptionProfileConfigError::from_response(
                 response,
             ));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let mut result;
 
         if xml_response.body.is_empty() {
@@ -13556,34 +14567,61 @@ impl CloudFront for CloudFrontClient {
             let value = e_tag.to_owned();
             result.e_tag = Some(value)
         }; // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
+    }
+
+    /// Presigns the request `get_field_level_encryption_profile_config` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_field_level_encryption_profile_config(
+        &self,
+        presigner: &rusoto_core::Presigner,
+        input: GetFieldLevelEncryptionProfileConfigRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_field_level_encryption_profile_config_request(input);
+        self.client.presign(presigner, request).await
     }
 
-    /// <p>Get the information about an invalidation. </p>
     #[allow(unused_variables, warnings)]
-    async fn get_invalidation(
+    fn build_get_field_level_encryption_profile_config_request(
         &self,
-        input: GetInvalidationRequest,
-    ) -> Result<GetInvalidationResult, RusotoError<GetInvalidationError>> {
+        input: GetFieldLevelEncryptionProfileConfigRequest,
+    ) -> SignedRequest {
         let request_uri = format!(
-            "/2019-03-26/distribution/{distribution_id}/invalidation/{id}",
-            distribution_id = input.distribution_id,
+            "/2019-03-26/field-level-encryption-profile/{id}/config",
             id = input.id
         );
 
         let mut request = SignedRequest::new("GET", "cloudfront", &self.region, &request_uri);
+        request.set_operation("GetFieldLevelEncryptionProfileConfig");
 
-        let mut response = self
-            .client
-            .sign_and_dispatch(request)
+        request
+    }
+
+    /// Calls `get_invalidation`, also returning the metadata of the response.
+    #[allow(unused_variables, warnings)]
+    pub async fn get_invalidation_with_metadata(
+        &self,
+        input: GetInvalidationRequest,
+    ) -> Result<(GetInvalidationResult, ResponseMetadata), RusotoError<GetInvalidationError>> {
+        let request = self.build_get_invalidation_request(input);
+
+        let mut response = self
+            .client
+            .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(GetInvalidationError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let mut result;
 
         if xml_response.body.is_empty() {
@@ -13599,30 +14637,59 @@ impl CloudFront for CloudFrontClient {
             result = GetInvalidationResultDeserializer::deserialize(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Get the public key information.</p>
-    #[allow(unused_variables, warnings)]
-    async fn get_public_key(
+    /// Presigns the request `get_invalidation` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_invalidation(
         &self,
-        input: GetPublicKeyRequest,
-    ) -> Result<GetPublicKeyResult, RusotoError<GetPublicKeyError>> {
-        let request_uri = format!("/2019-03-26/public-key/{id}", id = input.id);
+        presigner: &rusoto_core::Presigner,
+        input: GetInvalidationRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_invalidation_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    #[allow(unused_variables, warnings)]
+    fn build_get_invalidation_request(&self, input: GetInvalidationRequest) -> SignedRequest {
+        let request_uri = format!(
+            "/2019-03-26/distribution/{distribution_id}/invalidation/{id}",
+            distribution_id = input.distribution_id,
+            id = input.id
+        );
 
         let mut request = SignedRequest::new("GET", "cloudfront", &self.region, &request_uri);
+        request.set_operation("GetInvalidation");
+
+        request
+    }
+
+    /// Calls `get_public_key`, also returning the metadata of the response.
+    #[allow(unused_variables, warnings)]
+    pub async fn get_public_key_with_metadata(
+        &self,
+        input: GetPublicKeyRequest,
+    ) -> Result<(GetPublicKeyResult, ResponseMetadata), RusotoError<GetPublicKeyError>> {
+        let request = self.build_get_public_key_request(input);
 
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(GetPublicKeyError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let mut result;
 
         if xml_response.body.is_empty() {
@@ -13641,30 +14708,56 @@ impl CloudFront for CloudFrontClient {
             let value = e_tag.to_owned();
             result.e_tag = Some(value)
         }; // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Return public key configuration informaation</p>
-    #[allow(unused_variables, warnings)]
-    async fn get_public_key_config(
+    /// Presigns the request `get_public_key` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_public_key(
         &self,
-        input: GetPublicKeyConfigRequest,
-    ) -> Result<GetPublicKeyConfigResult, RusotoError<GetPublicKeyConfigError>> {
-        let request_uri = format!("/2019-03-26/public-key/{id}/config", id = input.id);
+        presigner: &rusoto_core::Presigner,
+        input: GetPublicKeyRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_public_key_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    #[allow(unused_variables, warnings)]
+    fn build_get_public_key_request(&self, input: GetPublicKeyRequest) -> SignedRequest {
+        let request_uri = format!("/2019-03-26/public-key/{id}", id = input.id);
 
         let mut request = SignedRequest::new("GET", "cloudfront", &self.region, &request_uri);
+        request.set_operation("GetPublicKey");
+
+        request
+    }
+
+    /// Calls `get_public_key_config`, also returning the metadata of the response.
+    #[allow(unused_variables, warnings)]
+    pub async fn get_public_key_config_with_metadata(
+        &self,
+        input: GetPublicKeyConfigRequest,
+    ) -> Result<(GetPublicKeyConfigResult, ResponseMetadata), RusotoError<GetPublicKeyConfigError>>
+    {
+        let request = self.build_get_public_key_config_request(input);
 
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(GetPublicKeyConfigError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let mut result;
 
         if xml_response.body.is_empty() {
@@ -13684,30 +14777,61 @@ impl CloudFront for CloudFrontClient {
             let value = e_tag.to_owned();
             result.e_tag = Some(value)
         }; // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
+    }
+
+    /// Presigns the request `get_public_key_config` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_public_key_config(
+        &self,
+        presigner: &rusoto_core::Presigner,
+        input: GetPublicKeyConfigRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_public_key_config_request(input);
+        self.client.presign(presigner, request).await
     }
 
-    /// <p>Gets information about a specified RTMP distribution, including the distribution configuration.</p>
     #[allow(unused_variables, warnings)]
-    async fn get_streaming_distribution(
+    fn build_get_public_key_config_request(
         &self,
-        input: GetStreamingDistributionRequest,
-    ) -> Result<GetStreamingDistributionResult, RusotoError<GetStreamingDistributionError>> {
-        let request_uri = format!("/2019-03-26/streaming-distribution/{id}", id = input.id);
+        input: GetPublicKeyConfigRequest,
+    ) -> SignedRequest {
+        let request_uri = format!("/2019-03-26/public-key/{id}/config", id = input.id);
 
         let mut request = SignedRequest::new("GET", "cloudfront", &self.region, &request_uri);
+        request.set_operation("GetPublicKeyConfig");
+
+        request
+    }
+
+    /// Calls `get_streaming_distribution`, also returning the metadata of the response.
+    #[allow(unused_variables, warnings)]
+    pub async fn get_streaming_distribution_with_metadata(
+        &self,
+        input: GetStreamingDistributionRequest,
+    ) -> Result<
+        (GetStreamingDistributionResult, ResponseMetadata),
+        RusotoError<GetStreamingDistributionError>,
+    > {
+        let request = self.build_get_streaming_distribution_request(input);
 
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(GetStreamingDistributionError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let mut result;
 
         if xml_response.body.is_empty() {
@@ -13729,36 +14853,61 @@ impl CloudFront for CloudFrontClient {
             let value = e_tag.to_owned();
             result.e_tag = Some(value)
         }; // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
+    }
+
+    /// Presigns the request `get_streaming_distribution` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_streaming_distribution(
+        &self,
+        presigner: &rusoto_core::Presigner,
+        input: GetStreamingDistributionRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_streaming_distribution_request(input);
+        self.client.presign(presigner, request).await
     }
 
-    /// <p>Get the configuration information about a streaming distribution. </p>
     #[allow(unused_variables, warnings)]
-    async fn get_streaming_distribution_config(
+    fn build_get_streaming_distribution_request(
+        &self,
+        input: GetStreamingDistributionRequest,
+    ) -> SignedRequest {
+        let request_uri = format!("/2019-03-26/streaming-distribution/{id}", id = input.id);
+
+        let mut request = SignedRequest::new("GET", "cloudfront", &self.region, &request_uri);
+        request.set_operation("GetStreamingDistribution");
+
+        request
+    }
+
+    /// Calls `get_streaming_distribution_config`, also returning the metadata of the response.
+    #[allow(unused_variables, warnings)]
+    pub async fn get_streaming_distribution_config_with_metadata(
         &self,
         input: GetStreamingDistributionConfigRequest,
     ) -> Result<
-        GetStreamingDistributionConfigResult,
+        (GetStreamingDistributionConfigResult, ResponseMetadata),
         RusotoError<GetStreamingDistributionConfigError>,
     > {
-        let request_uri = format!(
-            "/2019-03-26/streaming-distribution/{id}/config",
-            id = input.id
-        );
-
-        let mut request = SignedRequest::new("GET", "cloudfront", &self.region, &request_uri);
+        let request = self.build_get_streaming_distribution_config_request(input);
 
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(GetStreamingDistributionConfigError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let mut result;
 
         if xml_response.body.is_empty() {
@@ -13780,44 +14929,66 @@ impl CloudFront for CloudFrontClient {
             let value = e_tag.to_owned();
             result.e_tag = Some(value)
         }; // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
+    }
+
+    /// Presigns the request `get_streaming_distribution_config` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_streaming_distribution_config(
+        &self,
+        presigner: &rusoto_core::Presigner,
+        input: GetStreamingDistributionConfigRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_streaming_distribution_config_request(input);
+        self.client.presign(presigner, request).await
     }
 
-    /// <p>Lists origin access identities.</p>
     #[allow(unused_variables, warnings)]
-    async fn list_cloud_front_origin_access_identities(
+    fn build_get_streaming_distribution_config_request(
+        &self,
+        input: GetStreamingDistributionConfigRequest,
+    ) -> SignedRequest {
+        let request_uri = format!(
+            "/2019-03-26/streaming-distribution/{id}/config",
+            id = input.id
+        );
+
+        let mut request = SignedRequest::new("GET", "cloudfront", &self.region, &request_uri);
+        request.set_operation("GetStreamingDistributionConfig");
+
+        request
+    }
+
+    /// Calls `list_cloud_front_origin_access_identities`, also returning the metadata of the response.
+    #[allow(unused_variables, warnings)]
+    pub async fn list_cloud_front_origin_access_identities_with_metadata(
         &self,
         input: ListCloudFrontOriginAccessIdentitiesRequest,
     ) -> Result<
-        ListCloudFrontOriginAccessIdentitiesResult,
+        (ListCloudFrontOriginAccessIdentitiesResult, ResponseMetadata),
         RusotoError<ListCloudFrontOriginAccessIdentitiesError>,
     > {
-        let request_uri = "/2019-03-26/origin-access-identity/cloudfront";
-
-        let mut request = SignedRequest::new("GET", "cloudfront", &self.region, &request_uri);
-
-        let mut params = Params::new();
-        if let Some(ref x) = input.marker {
-            params.put("Marker", x);
-        }
-        if let Some(ref x) = input.max_items {
-            params.put("MaxItems", x);
-        }
-        request.set_params(params);
+        let request = self.build_list_cloud_front_origin_access_identities_request(input);
 
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(ListCloudFrontOriginAccessIdentitiesError::from_response(
                 response,
             ));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let mut result;
 
         if xml_response.body.is_empty() {
@@ -13836,18 +15007,28 @@ impl CloudFront for CloudFrontClient {
             )?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
+    }
+
+    /// Presigns the request `list_cloud_front_origin_access_identities` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_cloud_front_origin_access_identities(
+        &self,
+        presigner: &rusoto_core::Presigner,
+        input: ListCloudFrontOriginAccessIdentitiesRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_cloud_front_origin_access_identities_request(input);
+        self.client.presign(presigner, request).await
     }
 
-    /// <p>List CloudFront distributions.</p>
     #[allow(unused_variables, warnings)]
-    async fn list_distributions(
+    fn build_list_cloud_front_origin_access_identities_request(
         &self,
-        input: ListDistributionsRequest,
-    ) -> Result<ListDistributionsResult, RusotoError<ListDistributionsError>> {
-        let request_uri = "/2019-03-26/distribution";
+        input: ListCloudFrontOriginAccessIdentitiesRequest,
+    ) -> SignedRequest {
+        let request_uri = "/2019-03-26/origin-access-identity/cloudfront";
 
         let mut request = SignedRequest::new("GET", "cloudfront", &self.region, &request_uri);
+        request.set_operation("ListCloudFrontOriginAccessIdentities");
 
         let mut params = Params::new();
         if let Some(ref x) = input.marker {
@@ -13858,17 +15039,36 @@ impl CloudFront for CloudFrontClient {
         }
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `list_distributions`, also returning the metadata of the response.
+    #[allow(unused_variables, warnings)]
+    pub async fn list_distributions_with_metadata(
+        &self,
+        input: ListDistributionsRequest,
+    ) -> Result<(ListDistributionsResult, ResponseMetadata), RusotoError<ListDistributionsError>>
+    {
+        let request = self.build_list_distributions_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(ListDistributionsError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let mut result;
 
         if xml_response.body.is_empty() {
@@ -13885,22 +15085,25 @@ impl CloudFront for CloudFrontClient {
                 ListDistributionsResultDeserializer::deserialize(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>List the distributions that are associated with a specified AWS WAF web ACL. </p>
-    #[allow(unused_variables, warnings)]
-    async fn list_distributions_by_web_acl_id(
+    /// Presigns the request `list_distributions` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_distributions(
         &self,
-        input: ListDistributionsByWebACLIdRequest,
-    ) -> Result<ListDistributionsByWebACLIdResult, RusotoError<ListDistributionsByWebACLIdError>>
-    {
-        let request_uri = format!(
-            "/2019-03-26/distributionsByWebACLId/{web_acl_id}",
-            web_acl_id = input.web_acl_id
-        );
+        presigner: &rusoto_core::Presigner,
+        input: ListDistributionsRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_distributions_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    #[allow(unused_variables, warnings)]
+    fn build_list_distributions_request(&self, input: ListDistributionsRequest) -> SignedRequest {
+        let request_uri = "/2019-03-26/distribution";
 
         let mut request = SignedRequest::new("GET", "cloudfront", &self.region, &request_uri);
+        request.set_operation("ListDistributions");
 
         let mut params = Params::new();
         if let Some(ref x) = input.marker {
@@ -13911,17 +15114,38 @@ impl CloudFront for CloudFrontClient {
         }
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `list_distributions_by_web_acl_id`, also returning the metadata of the response.
+    #[allow(unused_variables, warnings)]
+    pub async fn list_distributions_by_web_acl_id_with_metadata(
+        &self,
+        input: ListDistributionsByWebACLIdRequest,
+    ) -> Result<
+        (ListDistributionsByWebACLIdResult, ResponseMetadata),
+        RusotoError<ListDistributionsByWebACLIdError>,
+    > {
+        let request = self.build_list_distributions_by_web_acl_id_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(ListDistributionsByWebACLIdError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let mut result;
 
         if xml_response.body.is_empty() {
@@ -13940,21 +15164,31 @@ impl CloudFront for CloudFrontClient {
             )?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
+    }
+
+    /// Presigns the request `list_distributions_by_web_acl_id` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_distributions_by_web_acl_id(
+        &self,
+        presigner: &rusoto_core::Presigner,
+        input: ListDistributionsByWebACLIdRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_distributions_by_web_acl_id_request(input);
+        self.client.presign(presigner, request).await
     }
 
-    /// <p>List all field-level encryption configurations that have been created in CloudFront for this account.</p>
     #[allow(unused_variables, warnings)]
-    async fn list_field_level_encryption_configs(
+    fn build_list_distributions_by_web_acl_id_request(
         &self,
-        input: ListFieldLevelEncryptionConfigsRequest,
-    ) -> Result<
-        ListFieldLevelEncryptionConfigsResult,
-        RusotoError<ListFieldLevelEncryptionConfigsError>,
-    > {
-        let request_uri = "/2019-03-26/field-level-encryption";
+        input: ListDistributionsByWebACLIdRequest,
+    ) -> SignedRequest {
+        let request_uri = format!(
+            "/2019-03-26/distributionsByWebACLId/{web_acl_id}",
+            web_acl_id = input.web_acl_id
+        );
 
         let mut request = SignedRequest::new("GET", "cloudfront", &self.region, &request_uri);
+        request.set_operation("ListDistributionsByWebACLId");
 
         let mut params = Params::new();
         if let Some(ref x) = input.marker {
@@ -13965,19 +15199,40 @@ impl CloudFront for CloudFrontClient {
         }
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `list_field_level_encryption_configs`, also returning the metadata of the response.
+    #[allow(unused_variables, warnings)]
+    pub async fn list_field_level_encryption_configs_with_metadata(
+        &self,
+        input: ListFieldLevelEncryptionConfigsRequest,
+    ) -> Result<
+        (ListFieldLevelEncryptionConfigsResult, ResponseMetadata),
+        RusotoError<ListFieldLevelEncryptionConfigsError>,
+    > {
+        let request = self.build_list_field_level_encryption_configs_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(ListFieldLevelEncryptionConfigsError::from_response(
                 response,
             ));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let mut result;
 
         if xml_response.body.is_empty() {
@@ -13996,21 +15251,28 @@ impl CloudFront for CloudFrontClient {
             )?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
+    }
+
+    /// Presigns the request `list_field_level_encryption_configs` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_field_level_encryption_configs(
+        &self,
+        presigner: &rusoto_core::Presigner,
+        input: ListFieldLevelEncryptionConfigsRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_field_level_encryption_configs_request(input);
+        self.client.presign(presigner, request).await
     }
 
-    /// <p>Request a list of field-level encryption profiles that have been created in CloudFront for this account.</p>
     #[allow(unused_variables, warnings)]
-    async fn list_field_level_encryption_profiles(
+    fn build_list_field_level_encryption_configs_request(
         &self,
-        input: ListFieldLevelEncryptionProfilesRequest,
-    ) -> Result<
-        ListFieldLevelEncryptionProfilesResult,
-        RusotoError<ListFieldLevelEncryptionProfilesError>,
-    > {
-        let request_uri = "/2019-03-26/field-level-encryption-profile";
+        input: ListFieldLevelEncryptionConfigsRequest,
+    ) -> SignedRequest {
+        let request_uri = "/2019-03-26/field-level-encryption";
 
         let mut request = SignedRequest::new("GET", "cloudfront", &self.region, &request_uri);
+        request.set_operation("ListFieldLevelEncryptionConfigs");
 
         let mut params = Params::new();
         if let Some(ref x) = input.marker {
@@ -14021,19 +15283,40 @@ impl CloudFront for CloudFrontClient {
         }
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `list_field_level_encryption_profiles`, also returning the metadata of the response.
+    #[allow(unused_variables, warnings)]
+    pub async fn list_field_level_encryption_profiles_with_metadata(
+        &self,
+        input: ListFieldLevelEncryptionProfilesRequest,
+    ) -> Result<
+        (ListFieldLevelEncryptionProfilesResult, ResponseMetadata),
+        RusotoError<ListFieldLevelEncryptionProfilesError>,
+    > {
+        let request = self.build_list_field_level_encryption_profiles_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(ListFieldLevelEncryptionProfilesError::from_response(
                 response,
             ));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let mut result;
 
         if xml_response.body.is_empty() {
@@ -14052,21 +15335,28 @@ impl CloudFront for CloudFrontClient {
             )?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
+    }
+
+    /// Presigns the request `list_field_level_encryption_profiles` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_field_level_encryption_profiles(
+        &self,
+        presigner: &rusoto_core::Presigner,
+        input: ListFieldLevelEncryptionProfilesRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_field_level_encryption_profiles_request(input);
+        self.client.presign(presigner, request).await
     }
 
-    /// <p>Lists invalidation batches. </p>
     #[allow(unused_variables, warnings)]
-    async fn list_invalidations(
+    fn build_list_field_level_encryption_profiles_request(
         &self,
-        input: ListInvalidationsRequest,
-    ) -> Result<ListInvalidationsResult, RusotoError<ListInvalidationsError>> {
-        let request_uri = format!(
-            "/2019-03-26/distribution/{distribution_id}/invalidation",
-            distribution_id = input.distribution_id
-        );
+        input: ListFieldLevelEncryptionProfilesRequest,
+    ) -> SignedRequest {
+        let request_uri = "/2019-03-26/field-level-encryption-profile";
 
         let mut request = SignedRequest::new("GET", "cloudfront", &self.region, &request_uri);
+        request.set_operation("ListFieldLevelEncryptionProfiles");
 
         let mut params = Params::new();
         if let Some(ref x) = input.marker {
@@ -14077,17 +15367,36 @@ impl CloudFront for CloudFrontClient {
         }
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `list_invalidations`, also returning the metadata of the response.
+    #[allow(unused_variables, warnings)]
+    pub async fn list_invalidations_with_metadata(
+        &self,
+        input: ListInvalidationsRequest,
+    ) -> Result<(ListInvalidationsResult, ResponseMetadata), RusotoError<ListInvalidationsError>>
+    {
+        let request = self.build_list_invalidations_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(ListInvalidationsError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let mut result;
 
         if xml_response.body.is_empty() {
@@ -14104,18 +15413,28 @@ impl CloudFront for CloudFrontClient {
                 ListInvalidationsResultDeserializer::deserialize(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>List all public keys that have been added to CloudFront for this account.</p>
-    #[allow(unused_variables, warnings)]
-    async fn list_public_keys(
+    /// Presigns the request `list_invalidations` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_invalidations(
         &self,
-        input: ListPublicKeysRequest,
-    ) -> Result<ListPublicKeysResult, RusotoError<ListPublicKeysError>> {
-        let request_uri = "/2019-03-26/public-key";
+        presigner: &rusoto_core::Presigner,
+        input: ListInvalidationsRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_invalidations_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    #[allow(unused_variables, warnings)]
+    fn build_list_invalidations_request(&self, input: ListInvalidationsRequest) -> SignedRequest {
+        let request_uri = format!(
+            "/2019-03-26/distribution/{distribution_id}/invalidation",
+            distribution_id = input.distribution_id
+        );
 
         let mut request = SignedRequest::new("GET", "cloudfront", &self.region, &request_uri);
+        request.set_operation("ListInvalidations");
 
         let mut params = Params::new();
         if let Some(ref x) = input.marker {
@@ -14126,17 +15445,35 @@ impl CloudFront for CloudFrontClient {
         }
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `list_public_keys`, also returning the metadata of the response.
+    #[allow(unused_variables, warnings)]
+    pub async fn list_public_keys_with_metadata(
+        &self,
+        input: ListPublicKeysRequest,
+    ) -> Result<(ListPublicKeysResult, ResponseMetadata), RusotoError<ListPublicKeysError>> {
+        let request = self.build_list_public_keys_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(ListPublicKeysError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let mut result;
 
         if xml_response.body.is_empty() {
@@ -14152,19 +15489,25 @@ impl CloudFront for CloudFrontClient {
             result = ListPublicKeysResultDeserializer::deserialize(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>List streaming distributions. </p>
-    #[allow(unused_variables, warnings)]
-    async fn list_streaming_distributions(
+    /// Presigns the request `list_public_keys` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_public_keys(
         &self,
-        input: ListStreamingDistributionsRequest,
-    ) -> Result<ListStreamingDistributionsResult, RusotoError<ListStreamingDistributionsError>>
-    {
-        let request_uri = "/2019-03-26/streaming-distribution";
+        presigner: &rusoto_core::Presigner,
+        input: ListPublicKeysRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_public_keys_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    #[allow(unused_variables, warnings)]
+    fn build_list_public_keys_request(&self, input: ListPublicKeysRequest) -> SignedRequest {
+        let request_uri = "/2019-03-26/public-key";
 
         let mut request = SignedRequest::new("GET", "cloudfront", &self.region, &request_uri);
+        request.set_operation("ListPublicKeys");
 
         let mut params = Params::new();
         if let Some(ref x) = input.marker {
@@ -14175,17 +15518,38 @@ impl CloudFront for CloudFrontClient {
         }
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `list_streaming_distributions`, also returning the metadata of the response.
+    #[allow(unused_variables, warnings)]
+    pub async fn list_streaming_distributions_with_metadata(
+        &self,
+        input: ListStreamingDistributionsRequest,
+    ) -> Result<
+        (ListStreamingDistributionsResult, ResponseMetadata),
+        RusotoError<ListStreamingDistributionsError>,
+    > {
+        let request = self.build_list_streaming_distributions_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(ListStreamingDistributionsError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let mut result;
 
         if xml_response.body.is_empty() {
@@ -14204,34 +15568,68 @@ impl CloudFront for CloudFrontClient {
             )?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
+    }
+
+    /// Presigns the request `list_streaming_distributions` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_streaming_distributions(
+        &self,
+        presigner: &rusoto_core::Presigner,
+        input: ListStreamingDistributionsRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_streaming_distributions_request(input);
+        self.client.presign(presigner, request).await
     }
 
-    /// <p>List tags for a CloudFront resource.</p>
     #[allow(unused_variables, warnings)]
-    async fn list_tags_for_resource(
+    fn build_list_streaming_distributions_request(
         &self,
-        input: ListTagsForResourceRequest,
-    ) -> Result<ListTagsForResourceResult, RusotoError<ListTagsForResourceError>> {
-        let request_uri = "/2019-03-26/tagging";
+        input: ListStreamingDistributionsRequest,
+    ) -> SignedRequest {
+        let request_uri = "/2019-03-26/streaming-distribution";
 
         let mut request = SignedRequest::new("GET", "cloudfront", &self.region, &request_uri);
+        request.set_operation("ListStreamingDistributions");
 
         let mut params = Params::new();
-        params.put("Resource", &input.resource);
+        if let Some(ref x) = input.marker {
+            params.put("Marker", x);
+        }
+        if let Some(ref x) = input.max_items {
+            params.put("MaxItems", x);
+        }
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `list_tags_for_resource`, also returning the metadata of the response.
+    #[allow(unused_variables, warnings)]
+    pub async fn list_tags_for_resource_with_metadata(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> Result<(ListTagsForResourceResult, ResponseMetadata), RusotoError<ListTagsForResourceError>>
+    {
+        let request = self.build_list_tags_for_resource_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(ListTagsForResourceError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let mut result;
 
         if xml_response.body.is_empty() {
@@ -14248,114 +15646,175 @@ impl CloudFront for CloudFrontClient {
                 ListTagsForResourceResultDeserializer::deserialize(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
+    }
+
+    /// Presigns the request `list_tags_for_resource` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_tags_for_resource(
+        &self,
+        presigner: &rusoto_core::Presigner,
+        input: ListTagsForResourceRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_tags_for_resource_request(input);
+        self.client.presign(presigner, request).await
     }
 
-    /// <p>Add tags to a CloudFront resource.</p>
     #[allow(unused_variables, warnings)]
-    async fn tag_resource(
+    fn build_list_tags_for_resource_request(
         &self,
-        input: TagResourceRequest,
-    ) -> Result<(), RusotoError<TagResourceError>> {
+        input: ListTagsForResourceRequest,
+    ) -> SignedRequest {
         let request_uri = "/2019-03-26/tagging";
 
-        let mut request = SignedRequest::new("POST", "cloudfront", &self.region, &request_uri);
+        let mut request = SignedRequest::new("GET", "cloudfront", &self.region, &request_uri);
+        request.set_operation("ListTagsForResource");
 
         let mut params = Params::new();
         params.put("Resource", &input.resource);
-        params.put("Operation", "Tag");
         request.set_params(params);
-        let mut writer = EventWriter::new(Vec::new());
-        TagsSerializer::serialize(&mut writer, "Tags", &input.tags);
-        request.set_payload(Some(writer.into_inner()));
+
+        request
+    }
+
+    /// Calls `tag_resource`, also returning the metadata of the response.
+    #[allow(unused_variables, warnings)]
+    pub async fn tag_resource_with_metadata(
+        &self,
+        input: TagResourceRequest,
+    ) -> Result<((), ResponseMetadata), RusotoError<TagResourceError>> {
+        let request = self.build_tag_resource_request(input);
 
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(TagResourceError::from_response(response));
         }
 
         std::mem::drop(response);
-        Ok(())
+        Ok(((), metadata))
     }
 
-    /// <p>Remove tags from a CloudFront resource.</p>
-    #[allow(unused_variables, warnings)]
-    async fn untag_resource(
+    /// Presigns the request `tag_resource` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_tag_resource(
         &self,
-        input: UntagResourceRequest,
-    ) -> Result<(), RusotoError<UntagResourceError>> {
+        presigner: &rusoto_core::Presigner,
+        input: TagResourceRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_tag_resource_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    #[allow(unused_variables, warnings)]
+    fn build_tag_resource_request(&self, input: TagResourceRequest) -> SignedRequest {
         let request_uri = "/2019-03-26/tagging";
 
         let mut request = SignedRequest::new("POST", "cloudfront", &self.region, &request_uri);
+        request.set_operation("TagResource");
 
         let mut params = Params::new();
         params.put("Resource", &input.resource);
-        params.put("Operation", "Untag");
+        params.put("Operation", "Tag");
         request.set_params(params);
         let mut writer = EventWriter::new(Vec::new());
-        TagKeysSerializer::serialize(&mut writer, "TagKeys", &input.tag_keys);
+        TagsSerializer::serialize(&mut writer, "Tags", &input.tags);
         request.set_payload(Some(writer.into_inner()));
 
+        request
+    }
+
+    /// Calls `untag_resource`, also returning the metadata of the response.
+    #[allow(unused_variables, warnings)]
+    pub async fn untag_resource_with_metadata(
+        &self,
+        input: UntagResourceRequest,
+    ) -> Result<((), ResponseMetadata), RusotoError<UntagResourceError>> {
+        let request = self.build_untag_resource_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(UntagResourceError::from_response(response));
         }
 
         std::mem::drop(response);
-        Ok(())
+        Ok(((), metadata))
     }
 
-    /// <p>Update an origin access identity. </p>
-    #[allow(unused_variables, warnings)]
-    async fn update_cloud_front_origin_access_identity(
+    /// Presigns the request `untag_resource` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_untag_resource(
         &self,
-        input: UpdateCloudFrontOriginAccessIdentityRequest,
-    ) -> Result<
-        UpdateCloudFrontOriginAccessIdentityResult,
-        RusotoError<UpdateCloudFrontOriginAccessIdentityError>,
-    > {
-        let request_uri = format!(
-            "/2019-03-26/origin-access-identity/cloudfront/{id}/config",
-            id = input.id
-        );
+        presigner: &rusoto_core::Presigner,
+        input: UntagResourceRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_untag_resource_request(input);
+        self.client.presign(presigner, request).await
+    }
 
-        let mut request = SignedRequest::new("PUT", "cloudfront", &self.region, &request_uri);
+    #[allow(unused_variables, warnings)]
+    fn build_untag_resource_request(&self, input: UntagResourceRequest) -> SignedRequest {
+        let request_uri = "/2019-03-26/tagging";
 
-        if let Some(ref if_match) = input.if_match {
-            request.add_header("If-Match", &if_match.to_string());
-        }
+        let mut request = SignedRequest::new("POST", "cloudfront", &self.region, &request_uri);
+        request.set_operation("UntagResource");
 
+        let mut params = Params::new();
+        params.put("Resource", &input.resource);
+        params.put("Operation", "Untag");
+        request.set_params(params);
         let mut writer = EventWriter::new(Vec::new());
-        CloudFrontOriginAccessIdentityConfigSerializer::serialize(
-            &mut writer,
-            "CloudFrontOriginAccessIdentityConfig",
-            &input.cloud_front_origin_access_identity_config,
-        );
+        TagKeysSerializer::serialize(&mut writer, "TagKeys", &input.tag_keys);
         request.set_payload(Some(writer.into_inner()));
 
+        request
+    }
+
+    /// Calls `update_cloud_front_origin_access_identity`, also returning the metadata of the response.
+    #[allow(unused_variables, warnings)]
+    pub async fn update_cloud_front_origin_access_identity_with_metadata(
+        &self,
+        input: UpdateCloudFrontOriginAccessIdentityRequest,
+    ) -> Result<
+        (UpdateCloudFrontOriginAccessIdentityResult, ResponseMetadata),
+        RusotoError<UpdateCloudFrontOriginAccessIdentityError>,
+    > {
+        let request = self.build_update_cloud_front_origin_access_identity_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(UpdateCloudFrontOriginAccessIdentityError::from_response(
                 response,
             ));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let mut result;
 
         if xml_response.body.is_empty() {
@@ -14377,42 +15836,74 @@ impl CloudFront for CloudFrontClient {
             let value = e_tag.to_owned();
             result.e_tag = Some(value)
         }; // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
+    }
+
+    /// Presigns the request `update_cloud_front_origin_access_identity` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_cloud_front_origin_access_identity(
+        &self,
+        presigner: &rusoto_core::Presigner,
+        input: UpdateCloudFrontOriginAccessIdentityRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_cloud_front_origin_access_identity_request(input);
+        self.client.presign(presigner, request).await
     }
 
-    /// <p><p>Updates the configuration for a web distribution. </p> <important> <p>When you update a distribution, there are more required fields than when you create a distribution. When you update your distribution by using this API action, follow the steps here to get the current configuration and then make your updates, to make sure that you include all of the required fields. To view a summary, see <a href="https://docs.aws.amazon.com/AmazonCloudFront/latest/DeveloperGuide/distribution-overview-required-fields.html">Required Fields for Create Distribution and Update Distribution</a> in the <i>Amazon CloudFront Developer Guide</i>.</p> </important> <p>The update process includes getting the current distribution configuration, updating the XML document that is returned to make your changes, and then submitting an <code>UpdateDistribution</code> request to make the updates.</p> <p>For information about updating a distribution using the CloudFront console instead, see <a href="https://docs.aws.amazon.com/AmazonCloudFront/latest/DeveloperGuide/distribution-web-creating-console.html">Creating a Distribution</a> in the <i>Amazon CloudFront Developer Guide</i>.</p> <p> <b>To update a web distribution using the CloudFront API</b> </p> <ol> <li> <p>Submit a <a href="https://docs.aws.amazon.com/cloudfront/latest/APIReference/API_GetDistributionConfig.html">GetDistributionConfig</a> request to get the current configuration and an <code>Etag</code> header for the distribution.</p> <note> <p>If you update the distribution again, you must get a new <code>Etag</code> header.</p> </note> </li> <li> <p>Update the XML document that was returned in the response to your <code>GetDistributionConfig</code> request to include your changes. </p> <important> <p>When you edit the XML file, be aware of the following:</p> <ul> <li> <p>You must strip out the ETag parameter that is returned.</p> </li> <li> <p>Additional fields are required when you update a distribution. There may be fields included in the XML file for features that you haven&#39;t configured for your distribution. This is expected and required to successfully update the distribution.</p> </li> <li> <p>You can&#39;t change the value of <code>CallerReference</code>. If you try to change this value, CloudFront returns an <code>IllegalUpdate</code> error. </p> </li> <li> <p>The new configuration replaces the existing configuration; the values that you specify in an <code>UpdateDistribution</code> request are not merged into your existing configuration. When you add, delete, or replace values in an element that allows multiple values (for example, <code>CNAME</code>), you must specify all of the values that you want to appear in the updated distribution. In addition, you must update the corresponding <code>Quantity</code> element.</p> </li> </ul> </important> </li> <li> <p>Submit an <code>UpdateDistribution</code> request to update the configuration for your distribution:</p> <ul> <li> <p>In the request body, include the XML document that you updated in Step 2. The request body must include an XML document with a <code>DistributionConfig</code> element.</p> </li> <li> <p>Set the value of the HTTP <code>If-Match</code> header to the value of the <code>ETag</code> header that CloudFront returned when you submitted the <code>GetDistributionConfig</code> request in Step 1.</p> </li> </ul> </li> <li> <p>Review the response to the <code>UpdateDistribution</code> request to confirm that the configuration was successfully updated.</p> </li> <li> <p>Optional: Submit a <a href="https://docs.aws.amazon.com/cloudfront/latest/APIReference/API_GetDistribution.html">GetDistribution</a> request to confirm that your changes have propagated. When propagation is complete, the value of <code>Status</code> is <code>Deployed</code>.</p> </li> </ol></p>
     #[allow(unused_variables, warnings)]
-    async fn update_distribution(
+    fn build_update_cloud_front_origin_access_identity_request(
         &self,
-        input: UpdateDistributionRequest,
-    ) -> Result<UpdateDistributionResult, RusotoError<UpdateDistributionError>> {
-        let request_uri = format!("/2019-03-26/distribution/{id}/config", id = input.id);
+        input: UpdateCloudFrontOriginAccessIdentityRequest,
+    ) -> SignedRequest {
+        let request_uri = format!(
+            "/2019-03-26/origin-access-identity/cloudfront/{id}/config",
+            id = input.id
+        );
 
         let mut request = SignedRequest::new("PUT", "cloudfront", &self.region, &request_uri);
+        request.set_operation("UpdateCloudFrontOriginAccessIdentity");
 
         if let Some(ref if_match) = input.if_match {
             request.add_header("If-Match", &if_match.to_string());
         }
 
         let mut writer = EventWriter::new(Vec::new());
-        DistributionConfigSerializer::serialize(
+        CloudFrontOriginAccessIdentityConfigSerializer::serialize(
             &mut writer,
-            "DistributionConfig",
-            &input.distribution_config,
+            "CloudFrontOriginAccessIdentityConfig",
+            &input.cloud_front_origin_access_identity_config,
         );
         request.set_payload(Some(writer.into_inner()));
 
+        request
+    }
+
+    /// Calls `update_distribution`, also returning the metadata of the response.
+    #[allow(unused_variables, warnings)]
+    pub async fn update_distribution_with_metadata(
+        &self,
+        input: UpdateDistributionRequest,
+    ) -> Result<(UpdateDistributionResult, ResponseMetadata), RusotoError<UpdateDistributionError>>
+    {
+        let request = self.build_update_distribution_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(UpdateDistributionError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let mut result;
 
         if xml_response.body.is_empty() {
@@ -14432,50 +15923,72 @@ impl CloudFront for CloudFrontClient {
             let value = e_tag.to_owned();
             result.e_tag = Some(value)
         }; // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Update a field-level encryption configuration. </p>
-    #[allow(unused_variables, warnings)]
-    async fn update_field_level_encryption_config(
+    /// Presigns the request `update_distribution` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_distribution(
         &self,
-        input: UpdateFieldLevelEncryptionConfigRequest,
-    ) -> Result<
-        UpdateFieldLevelEncryptionConfigResult,
-        RusotoError<UpdateFieldLevelEncryptionConfigError>,
-    > {
-        let request_uri = format!(
-            "/2019-03-26/field-level-encryption/{id}/config",
-            id = input.id
-        );
+        presigner: &rusoto_core::Presigner,
+        input: UpdateDistributionRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_distribution_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    #[allow(unused_variables, warnings)]
+    fn build_update_distribution_request(&self, input: UpdateDistributionRequest) -> SignedRequest {
+        let request_uri = format!("/2019-03-26/distribution/{id}/config", id = input.id);
 
         let mut request = SignedRequest::new("PUT", "cloudfront", &self.region, &request_uri);
+        request.set_operation("UpdateDistribution");
 
         if let Some(ref if_match) = input.if_match {
             request.add_header("If-Match", &if_match.to_string());
         }
 
         let mut writer = EventWriter::new(Vec::new());
-        FieldLevelEncryptionConfigSerializer::serialize(
+        DistributionConfigSerializer::serialize(
             &mut writer,
-            "FieldLevelEncryptionConfig",
-            &input.field_level_encryption_config,
+            "DistributionConfig",
+            &input.distribution_config,
         );
         request.set_payload(Some(writer.into_inner()));
 
+        request
+    }
+
+    /// Calls `update_field_level_encryption_config`, also returning the metadata of the response.
+    #[allow(unused_variables, warnings)]
+    pub async fn update_field_level_encryption_config_with_metadata(
+        &self,
+        input: UpdateFieldLevelEncryptionConfigRequest,
+    ) -> Result<
+        (UpdateFieldLevelEncryptionConfigResult, ResponseMetadata),
+        RusotoError<UpdateFieldLevelEncryptionConfigError>,
+    > {
+        let request = self.build_update_field_level_encryption_config_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(UpdateFieldLevelEncryptionConfigError::from_response(
                 response,
             ));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let mut result;
 
         if xml_response.body.is_empty() {
@@ -14497,50 +16010,78 @@ impl CloudFront for CloudFrontClient {
             let value = e_tag.to_owned();
             result.e_tag = Some(value)
         }; // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
+    }
+
+    /// Presigns the request `update_field_level_encryption_config` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_field_level_encryption_config(
+        &self,
+        presigner: &rusoto_core::Presigner,
+        input: UpdateFieldLevelEncryptionConfigRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_field_level_encryption_config_request(input);
+        self.client.presign(presigner, request).await
     }
 
-    /// <p>Update a field-level encryption profile. </p>
     #[allow(unused_variables, warnings)]
-    async fn update_field_level_encryption_profile(
+    fn build_update_field_level_encryption_config_request(
         &self,
-        input: UpdateFieldLevelEncryptionProfileRequest,
-    ) -> Result<
-        UpdateFieldLevelEncryptionProfileResult,
-        RusotoError<UpdateFieldLevelEncryptionProfileError>,
-    > {
+        input: UpdateFieldLevelEncryptionConfigRequest,
+    ) -> SignedRequest {
         let request_uri = format!(
-            "/2019-03-26/field-level-encryption-profile/{id}/config",
+            "/2019-03-26/field-level-encryption/{id}/config",
             id = input.id
         );
 
         let mut request = SignedRequest::new("PUT", "cloudfront", &self.region, &request_uri);
+        request.set_operation("UpdateFieldLevelEncryptionConfig");
 
         if let Some(ref if_match) = input.if_match {
             request.add_header("If-Match", &if_match.to_string());
         }
 
         let mut writer = EventWriter::new(Vec::new());
-        FieldLevelEncryptionProfileConfigSerializer::serialize(
+        FieldLevelEncryptionConfigSerializer::serialize(
             &mut writer,
-            "FieldLevelEncryptionProfileConfig",
-            &input.field_level_encryption_profile_config,
+            "FieldLevelEncryptionConfig",
+            &input.field_level_encryption_config,
         );
         request.set_payload(Some(writer.into_inner()));
 
+        request
+    }
+
+    /// Calls `update_field_level_encryption_profile`, also returning the metadata of the response.
+    #[allow(unused_variables, warnings)]
+    pub async fn update_field_level_encryption_profile_with_metadata(
+        &self,
+        input: UpdateFieldLevelEncryptionProfileRequest,
+    ) -> Result<
+        (UpdateFieldLevelEncryptionProfileResult, ResponseMetadata),
+        RusotoError<UpdateFieldLevelEncryptionProfileError>,
+    > {
+        let request = self.build_update_field_level_encryption_profile_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(UpdateFieldLevelEncryptionProfileError::from_response(
                 response,
             ));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let mut result;
 
         if xml_response.body.is_empty() {
@@ -14562,42 +16103,73 @@ impl CloudFront for CloudFrontClient {
             let value = e_tag.to_owned();
             result.e_tag = Some(value)
         }; // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Update public key information. Note that the only value you can change is the comment.</p>
-    #[allow(unused_variables, warnings)]
-    async fn update_public_key(
+    /// Presigns the request `update_field_level_encryption_profile` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_field_level_encryption_profile(
         &self,
-        input: UpdatePublicKeyRequest,
-    ) -> Result<UpdatePublicKeyResult, RusotoError<UpdatePublicKeyError>> {
-        let request_uri = format!("/2019-03-26/public-key/{id}/config", id = input.id);
-
-        let mut request = SignedRequest::new("PUT", "cloudfront", &self.region, &request_uri);
+        presigner: &rusoto_core::Presigner,
+        input: UpdateFieldLevelEncryptionProfileRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_field_level_encryption_profile_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    #[allow(unused_variables, warnings)]
+    fn build_update_field_level_encryption_profile_request(
+        &self,
+        input: UpdateFieldLevelEncryptionProfileRequest,
+    ) -> SignedRequest {
+        let request_uri = format!(
+            "/2019-03-26/field-level-encryption-profile/{id}/config",
+            id = input.id
+        );
+
+        let mut request = SignedRequest::new("PUT", "cloudfront", &self.region, &request_uri);
+        request.set_operation("UpdateFieldLevelEncryptionProfile");
 
         if let Some(ref if_match) = input.if_match {
             request.add_header("If-Match", &if_match.to_string());
         }
 
         let mut writer = EventWriter::new(Vec::new());
-        PublicKeyConfigSerializer::serialize(
+        FieldLevelEncryptionProfileConfigSerializer::serialize(
             &mut writer,
-            "PublicKeyConfig",
-            &input.public_key_config,
+            "FieldLevelEncryptionProfileConfig",
+            &input.field_level_encryption_profile_config,
         );
         request.set_payload(Some(writer.into_inner()));
 
+        request
+    }
+
+    /// Calls `update_public_key`, also returning the metadata of the response.
+    #[allow(unused_variables, warnings)]
+    pub async fn update_public_key_with_metadata(
+        &self,
+        input: UpdatePublicKeyRequest,
+    ) -> Result<(UpdatePublicKeyResult, ResponseMetadata), RusotoError<UpdatePublicKeyError>> {
+        let request = self.build_update_public_key_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(UpdatePublicKeyError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let mut result;
 
         if xml_response.body.is_empty() {
@@ -14616,46 +16188,70 @@ impl CloudFront for CloudFrontClient {
             let value = e_tag.to_owned();
             result.e_tag = Some(value)
         }; // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Update a streaming distribution. </p>
-    #[allow(unused_variables, warnings)]
-    async fn update_streaming_distribution(
+    /// Presigns the request `update_public_key` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_public_key(
         &self,
-        input: UpdateStreamingDistributionRequest,
-    ) -> Result<UpdateStreamingDistributionResult, RusotoError<UpdateStreamingDistributionError>>
-    {
-        let request_uri = format!(
-            "/2019-03-26/streaming-distribution/{id}/config",
-            id = input.id
-        );
+        presigner: &rusoto_core::Presigner,
+        input: UpdatePublicKeyRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_public_key_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    #[allow(unused_variables, warnings)]
+    fn build_update_public_key_request(&self, input: UpdatePublicKeyRequest) -> SignedRequest {
+        let request_uri = format!("/2019-03-26/public-key/{id}/config", id = input.id);
 
         let mut request = SignedRequest::new("PUT", "cloudfront", &self.region, &request_uri);
+        request.set_operation("UpdatePublicKey");
 
         if let Some(ref if_match) = input.if_match {
             request.add_header("If-Match", &if_match.to_string());
         }
 
         let mut writer = EventWriter::new(Vec::new());
-        StreamingDistributionConfigSerializer::serialize(
+        PublicKeyConfigSerializer::serialize(
             &mut writer,
-            "StreamingDistributionConfig",
-            &input.streaming_distribution_config,
+            "PublicKeyConfig",
+            &input.public_key_config,
         );
         request.set_payload(Some(writer.into_inner()));
 
+        request
+    }
+
+    /// Calls `update_streaming_distribution`, also returning the metadata of the response.
+    #[allow(unused_variables, warnings)]
+    pub async fn update_streaming_distribution_with_metadata(
+        &self,
+        input: UpdateStreamingDistributionRequest,
+    ) -> Result<
+        (UpdateStreamingDistributionResult, ResponseMetadata),
+        RusotoError<UpdateStreamingDistributionError>,
+    > {
+        let request = self.build_update_streaming_distribution_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(UpdateStreamingDistributionError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let mut result;
 
         if xml_response.body.is_empty() {
@@ -14677,7 +16273,699 @@ impl CloudFront for CloudFrontClient {
             let value = e_tag.to_owned();
             result.e_tag = Some(value)
         }; // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
+    }
+
+    /// Presigns the request `update_streaming_distribution` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_streaming_distribution(
+        &self,
+        presigner: &rusoto_core::Presigner,
+        input: UpdateStreamingDistributionRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_streaming_distribution_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    #[allow(unused_variables, warnings)]
+    fn build_update_streaming_distribution_request(
+        &self,
+        input: UpdateStreamingDistributionRequest,
+    ) -> SignedRequest {
+        let request_uri = format!(
+            "/2019-03-26/streaming-distribution/{id}/config",
+            id = input.id
+        );
+
+        let mut request = SignedRequest::new("PUT", "cloudfront", &self.region, &request_uri);
+        request.set_operation("UpdateStreamingDistribution");
+
+        if let Some(ref if_match) = input.if_match {
+            request.add_header("If-Match", &if_match.to_string());
+        }
+
+        let mut writer = EventWriter::new(Vec::new());
+        StreamingDistributionConfigSerializer::serialize(
+            &mut writer,
+            "StreamingDistributionConfig",
+            &input.streaming_distribution_config,
+        );
+        request.set_payload(Some(writer.into_inner()));
+
+        request
+    }
+
+    /// Waits for `DistributionDeployed`, polling `GetDistribution` with `input` every 60 seconds, up to 35 times.
+    pub async fn wait_until_distribution_deployed(
+        &self,
+        input: GetDistributionRequest,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<GetDistributionError>> {
+        let waiter = rusoto_core::waiter::Waiter::new(std::time::Duration::from_secs(60), 35);
+        self.wait_until_distribution_deployed_with(input, &waiter)
+            .await
+    }
+
+    /// Waits for `DistributionDeployed` like `wait_until_distribution_deployed`, with the delay, attempts and runtime of `waiter`.
+    pub async fn wait_until_distribution_deployed_with(
+        &self,
+        input: GetDistributionRequest,
+        waiter: &rusoto_core::waiter::Waiter,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<GetDistributionError>> {
+        rusoto_core::waiter::wait(
+            waiter,
+            || self.get_distribution(input.clone()),
+            |result| {
+                if match result {
+                    Ok(output) => std::iter::once(output)
+                        .filter_map(|value| value.distribution.as_ref())
+                        .map(|value| &value.status)
+                        .any(|value| value == "Deployed"),
+                    Err(_) => false,
+                } {
+                    return Some(rusoto_core::waiter::WaiterState::Success);
+                }
+                None
+            },
+        )
+        .await
+    }
+
+    /// Waits for `InvalidationCompleted`, polling `GetInvalidation` with `input` every 20 seconds, up to 30 times.
+    pub async fn wait_until_invalidation_completed(
+        &self,
+        input: GetInvalidationRequest,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<GetInvalidationError>> {
+        let waiter = rusoto_core::waiter::Waiter::new(std::time::Duration::from_secs(20), 30);
+        self.wait_until_invalidation_completed_with(input, &waiter)
+            .await
+    }
+
+    /// Waits for `InvalidationCompleted` like `wait_until_invalidation_completed`, with the delay, attempts and runtime of `waiter`.
+    pub async fn wait_until_invalidation_completed_with(
+        &self,
+        input: GetInvalidationRequest,
+        waiter: &rusoto_core::waiter::Waiter,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<GetInvalidationError>> {
+        rusoto_core::waiter::wait(
+            waiter,
+            || self.get_invalidation(input.clone()),
+            |result| {
+                if match result {
+                    Ok(output) => std::iter::once(output)
+                        .filter_map(|value| value.invalidation.as_ref())
+                        .map(|value| &value.status)
+                        .any(|value| value == "Completed"),
+                    Err(_) => false,
+                } {
+                    return Some(rusoto_core::waiter::WaiterState::Success);
+                }
+                None
+            },
+        )
+        .await
+    }
+
+    /// Waits for `StreamingDistributionDeployed`, polling `GetStreamingDistribution` with `input` every 60 seconds, up to 25 times.
+    pub async fn wait_until_streaming_distribution_deployed(
+        &self,
+        input: GetStreamingDistributionRequest,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<GetStreamingDistributionError>> {
+        let waiter = rusoto_core::waiter::Waiter::new(std::time::Duration::from_secs(60), 25);
+        self.wait_until_streaming_distribution_deployed_with(input, &waiter)
+            .await
+    }
+
+    /// Waits for `StreamingDistributionDeployed` like `wait_until_streaming_distribution_deployed`, with the delay, attempts and runtime of `waiter`.
+    pub async fn wait_until_streaming_distribution_deployed_with(
+        &self,
+        input: GetStreamingDistributionRequest,
+        waiter: &rusoto_core::waiter::Waiter,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<GetStreamingDistributionError>> {
+        rusoto_core::waiter::wait(
+            waiter,
+            || self.get_streaming_distribution(input.clone()),
+            |result| {
+                if match result {
+                    Ok(output) => std::iter::once(output)
+                        .filter_map(|value| value.streaming_distribution.as_ref())
+                        .map(|value| &value.status)
+                        .any(|value| value == "Deployed"),
+                    Err(_) => false,
+                } {
+                    return Some(rusoto_core::waiter::WaiterState::Success);
+                }
+                None
+            },
+        )
+        .await
+    }
+}
+
+#[async_trait]
+impl CloudFront for CloudFrontClient {
+    /// <p>Creates a new origin access identity. If you're using Amazon S3 for your origin, you can use an origin access identity to require users to access your content using a CloudFront URL instead of the Amazon S3 URL. For more information about how to use origin access identities, see <a href="https://docs.aws.amazon.com/AmazonCloudFront/latest/DeveloperGuide/PrivateContent.html">Serving Private Content through CloudFront</a> in the <i>Amazon CloudFront Developer Guide</i>.</p>
+    #[allow(unused_variables, warnings)]
+    async fn create_cloud_front_origin_access_identity(
+        &self,
+        input: CreateCloudFrontOriginAccessIdentityRequest,
+    ) -> Result<
+        CreateCloudFrontOriginAccessIdentityResult,
+        RusotoError<CreateCloudFrontOriginAccessIdentityError>,
+    > {
+        self.create_cloud_front_origin_access_identity_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Creates a new web distribution. You create a CloudFront distribution to tell CloudFront where you want content to be delivered from, and the details about how to track and manage content delivery. Send a <code>POST</code> request to the <code>/<i>CloudFront API version</i>/distribution</code>/<code>distribution ID</code> resource.</p> <important> <p>When you update a distribution, there are more required fields than when you create a distribution. When you update your distribution by using <a href="https://docs.aws.amazon.com/cloudfront/latest/APIReference/API_UpdateDistribution.html">UpdateDistribution</a>, follow the steps included in the documentation to get the current configuration and then make your updates. This helps to make sure that you include all of the required fields. To view a summary, see <a href="https://docs.aws.amazon.com/AmazonCloudFront/latest/DeveloperGuide/distribution-overview-required-fields.html">Required Fields for Create Distribution and Update Distribution</a> in the <i>Amazon CloudFront Developer Guide</i>.</p> </important></p>
+    #[allow(unused_variables, warnings)]
+    async fn create_distribution(
+        &self,
+        input: CreateDistributionRequest,
+    ) -> Result<CreateDistributionResult, RusotoError<CreateDistributionError>> {
+        self.create_distribution_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Create a new distribution with tags.</p>
+    #[allow(unused_variables, warnings)]
+    async fn create_distribution_with_tags(
+        &self,
+        input: CreateDistributionWithTagsRequest,
+    ) -> Result<CreateDistributionWithTagsResult, RusotoError<CreateDistributionWithTagsError>>
+    {
+        self.create_distribution_with_tags_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Create a new field-level encryption configuration.</p>
+    #[allow(unused_variables, warnings)]
+    async fn create_field_level_encryption_config(
+        &self,
+        input: CreateFieldLevelEncryptionConfigRequest,
+    ) -> Result<
+        CreateFieldLevelEncryptionConfigResult,
+        RusotoError<CreateFieldLevelEncryptionConfigError>,
+    > {
+        self.create_field_level_encryption_config_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Create a field-level encryption profile.</p>
+    #[allow(unused_variables, warnings)]
+    async fn create_field_level_encryption_profile(
+        &self,
+        input: CreateFieldLevelEncryptionProfileRequest,
+    ) -> Result<
+        CreateFieldLevelEncryptionProfileResult,
+        RusotoError<CreateFieldLevelEncryptionProfileError>,
+    > {
+        self.create_field_level_encryption_profile_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Create a new invalidation. </p>
+    #[allow(unused_variables, warnings)]
+    async fn create_invalidation(
+        &self,
+        input: CreateInvalidationRequest,
+    ) -> Result<CreateInvalidationResult, RusotoError<CreateInvalidationError>> {
+        self.create_invalidation_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Add a new public key to CloudFront to use, for example, for field-level encryption. You can add a maximum of 10 public keys with one AWS account.</p>
+    #[allow(unused_variables, warnings)]
+    async fn create_public_key(
+        &self,
+        input: CreatePublicKeyRequest,
+    ) -> Result<CreatePublicKeyResult, RusotoError<CreatePublicKeyError>> {
+        self.create_public_key_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Creates a new RTMP distribution. An RTMP distribution is similar to a web distribution, but an RTMP distribution streams media files using the Adobe Real-Time Messaging Protocol (RTMP) instead of serving files using HTTP. </p> <p>To create a new distribution, submit a <code>POST</code> request to the <i>CloudFront API version</i>/distribution resource. The request body must include a document with a <i>StreamingDistributionConfig</i> element. The response echoes the <code>StreamingDistributionConfig</code> element and returns other information about the RTMP distribution.</p> <p>To get the status of your request, use the <i>GET StreamingDistribution</i> API action. When the value of <code>Enabled</code> is <code>true</code> and the value of <code>Status</code> is <code>Deployed</code>, your distribution is ready. A distribution usually deploys in less than 15 minutes.</p> <p>For more information about web distributions, see <a href="https://docs.aws.amazon.com/AmazonCloudFront/latest/DeveloperGuide/distribution-rtmp.html">Working with RTMP Distributions</a> in the <i>Amazon CloudFront Developer Guide</i>.</p> <important> <p>Beginning with the 2012-05-05 version of the CloudFront API, we made substantial changes to the format of the XML document that you include in the request body when you create or update a web distribution or an RTMP distribution, and when you invalidate objects. With previous versions of the API, we discovered that it was too easy to accidentally delete one or more values for an element that accepts multiple values, for example, CNAMEs and trusted signers. Our changes for the 2012-05-05 release are intended to prevent these accidental deletions and to notify you when there&#39;s a mismatch between the number of values you say you&#39;re specifying in the <code>Quantity</code> element and the number of values specified.</p> </important></p>
+    #[allow(unused_variables, warnings)]
+    async fn create_streaming_distribution(
+        &self,
+        input: CreateStreamingDistributionRequest,
+    ) -> Result<CreateStreamingDistributionResult, RusotoError<CreateStreamingDistributionError>>
+    {
+        self.create_streaming_distribution_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Create a new streaming distribution with tags.</p>
+    #[allow(unused_variables, warnings)]
+    async fn create_streaming_distribution_with_tags(
+        &self,
+        input: CreateStreamingDistributionWithTagsRequest,
+    ) -> Result<
+        CreateStreamingDistributionWithTagsResult,
+        RusotoError<CreateStreamingDistributionWithTagsError>,
+    > {
+        self.create_streaming_distribution_with_tags_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Delete an origin access identity. </p>
+    #[allow(unused_variables, warnings)]
+    async fn delete_cloud_front_origin_access_identity(
+        &self,
+        input: DeleteCloudFrontOriginAccessIdentityRequest,
+    ) -> Result<(), RusotoError<DeleteCloudFrontOriginAccessIdentityError>> {
+        self.delete_cloud_front_origin_access_identity_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Delete a distribution. </p>
+    #[allow(unused_variables, warnings)]
+    async fn delete_distribution(
+        &self,
+        input: DeleteDistributionRequest,
+    ) -> Result<(), RusotoError<DeleteDistributionError>> {
+        self.delete_distribution_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Remove a field-level encryption configuration.</p>
+    #[allow(unused_variables, warnings)]
+    async fn delete_field_level_encryption_config(
+        &self,
+        input: DeleteFieldLevelEncryptionConfigRequest,
+    ) -> Result<(), RusotoError<DeleteFieldLevelEncryptionConfigError>> {
+        self.delete_field_level_encryption_config_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Remove a field-level encryption profile.</p>
+    #[allow(unused_variables, warnings)]
+    async fn delete_field_level_encryption_profile(
+        &self,
+        input: DeleteFieldLevelEncryptionProfileRequest,
+    ) -> Result<(), RusotoError<DeleteFieldLevelEncryptionProfileError>> {
+        self.delete_field_level_encryption_profile_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Remove a public key you previously added to CloudFront.</p>
+    #[allow(unused_variables, warnings)]
+    async fn delete_public_key(
+        &self,
+        input: DeletePublicKeyRequest,
+    ) -> Result<(), RusotoError<DeletePublicKeyError>> {
+        self.delete_public_key_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Delete a streaming distribution. To delete an RTMP distribution using the CloudFront API, perform the following steps.</p> <p> <b>To delete an RTMP distribution using the CloudFront API</b>:</p> <ol> <li> <p>Disable the RTMP distribution.</p> </li> <li> <p>Submit a <code>GET Streaming Distribution Config</code> request to get the current configuration and the <code>Etag</code> header for the distribution. </p> </li> <li> <p>Update the XML document that was returned in the response to your <code>GET Streaming Distribution Config</code> request to change the value of <code>Enabled</code> to <code>false</code>.</p> </li> <li> <p>Submit a <code>PUT Streaming Distribution Config</code> request to update the configuration for your distribution. In the request body, include the XML document that you updated in Step 3. Then set the value of the HTTP <code>If-Match</code> header to the value of the <code>ETag</code> header that CloudFront returned when you submitted the <code>GET Streaming Distribution Config</code> request in Step 2.</p> </li> <li> <p>Review the response to the <code>PUT Streaming Distribution Config</code> request to confirm that the distribution was successfully disabled.</p> </li> <li> <p>Submit a <code>GET Streaming Distribution Config</code> request to confirm that your changes have propagated. When propagation is complete, the value of <code>Status</code> is <code>Deployed</code>.</p> </li> <li> <p>Submit a <code>DELETE Streaming Distribution</code> request. Set the value of the HTTP <code>If-Match</code> header to the value of the <code>ETag</code> header that CloudFront returned when you submitted the <code>GET Streaming Distribution Config</code> request in Step 2.</p> </li> <li> <p>Review the response to your <code>DELETE Streaming Distribution</code> request to confirm that the distribution was successfully deleted.</p> </li> </ol> <p>For information about deleting a distribution using the CloudFront console, see <a href="https://docs.aws.amazon.com/AmazonCloudFront/latest/DeveloperGuide/HowToDeleteDistribution.html">Deleting a Distribution</a> in the <i>Amazon CloudFront Developer Guide</i>.</p>
+    #[allow(unused_variables, warnings)]
+    async fn delete_streaming_distribution(
+        &self,
+        input: DeleteStreamingDistributionRequest,
+    ) -> Result<(), RusotoError<DeleteStreamingDistributionError>> {
+        self.delete_streaming_distribution_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Get the information about an origin access identity. </p>
+    #[allow(unused_variables, warnings)]
+    async fn get_cloud_front_origin_access_identity(
+        &self,
+        input: GetCloudFrontOriginAccessIdentityRequest,
+    ) -> Result<
+        GetCloudFrontOriginAccessIdentityResult,
+        RusotoError<GetCloudFrontOriginAccessIdentityError>,
+    > {
+        self.get_cloud_front_origin_access_identity_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Get the configuration information about an origin access identity. </p>
+    #[allow(unused_variables, warnings)]
+    async fn get_cloud_front_origin_access_identity_config(
+        &self,
+        input: GetCloudFrontOriginAccessIdentityConfigRequest,
+    ) -> Result<
+        GetCloudFrontOriginAccessIdentityConfigResult,
+        RusotoError<GetCloudFrontOriginAccessIdentityConfigError>,
+    > {
+        self.get_cloud_front_origin_access_identity_config_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Get the information about a distribution.</p>
+    #[allow(unused_variables, warnings)]
+    async fn get_distribution(
+        &self,
+        input: GetDistributionRequest,
+    ) -> Result<GetDistributionResult, RusotoError<GetDistributionError>> {
+        self.get_distribution_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Get the configuration information about a distribution. </p>
+    #[allow(unused_variables, warnings)]
+    async fn get_distribution_config(
+        &self,
+        input: GetDistributionConfigRequest,
+    ) -> Result<GetDistributionConfigResult, RusotoError<GetDistributionConfigError>> {
+        self.get_distribution_config_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Get the field-level encryption configuration information.</p>
+    #[allow(unused_variables, warnings)]
+    async fn get_field_level_encryption(
+        &self,
+        input: GetFieldLevelEncryptionRequest,
+    ) -> Result<GetFieldLevelEncryptionResult, RusotoError<GetFieldLevelEncryptionError>> {
+        self.get_field_level_encryption_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Get the field-level encryption configuration information.</p>
+    #[allow(unused_variables, warnings)]
+    async fn get_field_level_encryption_config(
+        &self,
+        input: GetFieldLevelEncryptionConfigRequest,
+    ) -> Result<GetFieldLevelEncryptionConfigResult, RusotoError<GetFieldLevelEncryptionConfigError>>
+    {
+        self.get_field_level_encryption_config_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Get the field-level encryption profile information.</p>
+    #[allow(unused_variables, warnings)]
+    async fn get_field_level_encryption_profile(
+        &self,
+        input: GetFieldLevelEncryptionProfileRequest,
+    ) -> Result<
+        GetFieldLevelEncryptionProfileResult,
+        RusotoError<GetFieldLevelEncryptionProfileError>,
+    > {
+        self.get_field_level_encryption_profile_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Get the field-level encryption profile configuration information.</p>
+    #[allow(unused_variables, warnings)]
+    async fn get_field_level_encryption_profile_config(
+        &self,
+        input: GetFieldLevelEncryptionProfileConfigRequest,
+    ) -> Result<
+        GetFieldLevelEncryptionProfileConfigResult,
+        RusotoError<GetFieldLevelEncryptionProfileConfigError>,
+    > {
+        self.get_field_level_encryption_profile_config_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Get the information about an invalidation. </p>
+    #[allow(unused_variables, warnings)]
+    async fn get_invalidation(
+        &self,
+        input: GetInvalidationRequest,
+    ) -> Result<GetInvalidationResult, RusotoError<GetInvalidationError>> {
+        self.get_invalidation_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Get the public key information.</p>
+    #[allow(unused_variables, warnings)]
+    async fn get_public_key(
+        &self,
+        input: GetPublicKeyRequest,
+    ) -> Result<GetPublicKeyResult, RusotoError<GetPublicKeyError>> {
+        self.get_public_key_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Return public key configuration informaation</p>
+    #[allow(unused_variables, warnings)]
+    async fn get_public_key_config(
+        &self,
+        input: GetPublicKeyConfigRequest,
+    ) -> Result<GetPublicKeyConfigResult, RusotoError<GetPublicKeyConfigError>> {
+        self.get_public_key_config_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Gets information about a specified RTMP distribution, including the distribution configuration.</p>
+    #[allow(unused_variables, warnings)]
+    async fn get_streaming_distribution(
+        &self,
+        input: GetStreamingDistributionRequest,
+    ) -> Result<GetStreamingDistributionResult, RusotoError<GetStreamingDistributionError>> {
+        self.get_streaming_distribution_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Get the configuration information about a streaming distribution. </p>
+    #[allow(unused_variables, warnings)]
+    async fn get_streaming_distribution_config(
+        &self,
+        input: GetStreamingDistributionConfigRequest,
+    ) -> Result<
+        GetStreamingDistributionConfigResult,
+        RusotoError<GetStreamingDistributionConfigError>,
+    > {
+        self.get_streaming_distribution_config_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Lists origin access identities.</p>
+    #[allow(unused_variables, warnings)]
+    async fn list_cloud_front_origin_access_identities(
+        &self,
+        input: ListCloudFrontOriginAccessIdentitiesRequest,
+    ) -> Result<
+        ListCloudFrontOriginAccessIdentitiesResult,
+        RusotoError<ListCloudFrontOriginAccessIdentitiesError>,
+    > {
+        self.list_cloud_front_origin_access_identities_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>List CloudFront distributions.</p>
+    #[allow(unused_variables, warnings)]
+    async fn list_distributions(
+        &self,
+        input: ListDistributionsRequest,
+    ) -> Result<ListDistributionsResult, RusotoError<ListDistributionsError>> {
+        self.list_distributions_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>List the distributions that are associated with a specified AWS WAF web ACL. </p>
+    #[allow(unused_variables, warnings)]
+    async fn list_distributions_by_web_acl_id(
+        &self,
+        input: ListDistributionsByWebACLIdRequest,
+    ) -> Result<ListDistributionsByWebACLIdResult, RusotoError<ListDistributionsByWebACLIdError>>
+    {
+        self.list_distributions_by_web_acl_id_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>List all field-level encryption configurations that have been created in CloudFront for this account.</p>
+    #[allow(unused_variables, warnings)]
+    async fn list_field_level_encryption_configs(
+        &self,
+        input: ListFieldLevelEncryptionConfigsRequest,
+    ) -> Result<
+        ListFieldLevelEncryptionConfigsResult,
+        RusotoError<ListFieldLevelEncryptionConfigsError>,
+    > {
+        self.list_field_level_encryption_configs_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Request a list of field-level encryption profiles that have been created in CloudFront for this account.</p>
+    #[allow(unused_variables, warnings)]
+    async fn list_field_level_encryption_profiles(
+        &self,
+        input: ListFieldLevelEncryptionProfilesRequest,
+    ) -> Result<
+        ListFieldLevelEncryptionProfilesResult,
+        RusotoError<ListFieldLevelEncryptionProfilesError>,
+    > {
+        self.list_field_level_encryption_profiles_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Lists invalidation batches. </p>
+    #[allow(unused_variables, warnings)]
+    async fn list_invalidations(
+        &self,
+        input: ListInvalidationsRequest,
+    ) -> Result<ListInvalidationsResult, RusotoError<ListInvalidationsError>> {
+        self.list_invalidations_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>List all public keys that have been added to CloudFront for this account.</p>
+    #[allow(unused_variables, warnings)]
+    async fn list_public_keys(
+        &self,
+        input: ListPublicKeysRequest,
+    ) -> Result<ListPublicKeysResult, RusotoError<ListPublicKeysError>> {
+        self.list_public_keys_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>List streaming distributions. </p>
+    #[allow(unused_variables, warnings)]
+    async fn list_streaming_distributions(
+        &self,
+        input: ListStreamingDistributionsRequest,
+    ) -> Result<ListStreamingDistributionsResult, RusotoError<ListStreamingDistributionsError>>
+    {
+        self.list_streaming_distributions_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>List tags for a CloudFront resource.</p>
+    #[allow(unused_variables, warnings)]
+    async fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> Result<ListTagsForResourceResult, RusotoError<ListTagsForResourceError>> {
+        self.list_tags_for_resource_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Add tags to a CloudFront resource.</p>
+    #[allow(unused_variables, warnings)]
+    async fn tag_resource(
+        &self,
+        input: TagResourceRequest,
+    ) -> Result<(), RusotoError<TagResourceError>> {
+        self.tag_resource_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Remove tags from a CloudFront resource.</p>
+    #[allow(unused_variables, warnings)]
+    async fn untag_resource(
+        &self,
+        input: UntagResourceRequest,
+    ) -> Result<(), RusotoError<UntagResourceError>> {
+        self.untag_resource_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Update an origin access identity. </p>
+    #[allow(unused_variables, warnings)]
+    async fn update_cloud_front_origin_access_identity(
+        &self,
+        input: UpdateCloudFrontOriginAccessIdentityRequest,
+    ) -> Result<
+        UpdateCloudFrontOriginAccessIdentityResult,
+        RusotoError<UpdateCloudFrontOriginAccessIdentityError>,
+    > {
+        self.update_cloud_front_origin_access_identity_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Updates the configuration for a web distribution. </p> <important> <p>When you update a distribution, there are more required fields than when you create a distribution. When you update your distribution by using this API action, follow the steps here to get the current configuration and then make your updates, to make sure that you include all of the required fields. To view a summary, see <a href="https://docs.aws.amazon.com/AmazonCloudFront/latest/DeveloperGuide/distribution-overview-required-fields.html">Required Fields for Create Distribution and Update Distribution</a> in the <i>Amazon CloudFront Developer Guide</i>.</p> </important> <p>The update process includes getting the current distribution configuration, updating the XML document that is returned to make your changes, and then submitting an <code>UpdateDistribution</code> request to make the updates.</p> <p>For information about updating a distribution using the CloudFront console instead, see <a href="https://docs.aws.amazon.com/AmazonCloudFront/latest/DeveloperGuide/distribution-web-creating-console.html">Creating a Distribution</a> in the <i>Amazon CloudFront Developer Guide</i>.</p> <p> <b>To update a web distribution using the CloudFront API</b> </p> <ol> <li> <p>Submit a <a href="https://docs.aws.amazon.com/cloudfront/latest/APIReference/API_GetDistributionConfig.html">GetDistributionConfig</a> request to get the current configuration and an <code>Etag</code> header for the distribution.</p> <note> <p>If you update the distribution again, you must get a new <code>Etag</code> header.</p> </note> </li> <li> <p>Update the XML document that was returned in the response to your <code>GetDistributionConfig</code> request to include your changes. </p> <important> <p>When you edit the XML file, be aware of the following:</p> <ul> <li> <p>You must strip out the ETag parameter that is returned.</p> </li> <li> <p>Additional fields are required when you update a distribution. There may be fields included in the XML file for features that you haven&#39;t configured for your distribution. This is expected and required to successfully update the distribution.</p> </li> <li> <p>You can&#39;t change the value of <code>CallerReference</code>. If you try to change this value, CloudFront returns an <code>IllegalUpdate</code> error. </p> </li> <li> <p>The new configuration replaces the existing configuration; the values that you specify in an <code>UpdateDistribution</code> request are not merged into your existing configuration. When you add, delete, or replace values in an element that allows multiple values (for example, <code>CNAME</code>), you must specify all of the values that you want to appear in the updated distribution. In addition, you must update the corresponding <code>Quantity</code> element.</p> </li> </ul> </important> </li> <li> <p>Submit an <code>UpdateDistribution</code> request to update the configuration for your distribution:</p> <ul> <li> <p>In the request body, include the XML document that you updated in Step 2. The request body must include an XML document with a <code>DistributionConfig</code> element.</p> </li> <li> <p>Set the value of the HTTP <code>If-Match</code> header to the value of the <code>ETag</code> header that CloudFront returned when you submitted the <code>GetDistributionConfig</code> request in Step 1.</p> </li> </ul> </li> <li> <p>Review the response to the <code>UpdateDistribution</code> request to confirm that the configuration was successfully updated.</p> </li> <li> <p>Optional: Submit a <a href="https://docs.aws.amazon.com/cloudfront/latest/APIReference/API_GetDistribution.html">GetDistribution</a> request to confirm that your changes have propagated. When propagation is complete, the value of <code>Status</code> is <code>Deployed</code>.</p> </li> </ol></p>
+    #[allow(unused_variables, warnings)]
+    async fn update_distribution(
+        &self,
+        input: UpdateDistributionRequest,
+    ) -> Result<UpdateDistributionResult, RusotoError<UpdateDistributionError>> {
+        self.update_distribution_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Update a field-level encryption configuration. </p>
+    #[allow(unused_variables, warnings)]
+    async fn update_field_level_encryption_config(
+        &self,
+        input: UpdateFieldLevelEncryptionConfigRequest,
+    ) -> Result<
+        UpdateFieldLevelEncryptionConfigResult,
+        RusotoError<UpdateFieldLevelEncryptionConfigError>,
+    > {
+        self.update_field_level_encryption_config_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Update a field-level encryption profile. </p>
+    #[allow(unused_variables, warnings)]
+    async fn update_field_level_encryption_profile(
+        &self,
+        input: UpdateFieldLevelEncryptionProfileRequest,
+    ) -> Result<
+        UpdateFieldLevelEncryptionProfileResult,
+        RusotoError<UpdateFieldLevelEncryptionProfileError>,
+    > {
+        self.update_field_level_encryption_profile_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Update public key information. Note that the only value you can change is the comment.</p>
+    #[allow(unused_variables, warnings)]
+    async fn update_public_key(
+        &self,
+        input: UpdatePublicKeyRequest,
+    ) -> Result<UpdatePublicKeyResult, RusotoError<UpdatePublicKeyError>> {
+        self.update_public_key_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Update a streaming distribution. </p>
+    #[allow(unused_variables, warnings)]
+    async fn update_streaming_distribution(
+        &self,
+        input: UpdateStreamingDistributionRequest,
+    ) -> Result<UpdateStreamingDistributionResult, RusotoError<UpdateStreamingDistributionError>>
+    {
+        self.update_streaming_distribution_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
     }
 }
 
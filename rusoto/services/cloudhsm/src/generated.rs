@@ -16,8 +16,8 @@ use std::fmt;
 use async_trait::async_trait;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
-use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest, ResponseMetadata};
+use rusoto_core::{Client, ErrorMetadata, RusotoError};
 
 use rusoto_core::proto;
 use rusoto_core::signature::SignedRequest;
@@ -614,6 +614,7 @@ pub struct Tag {
 
 /// Errors returned by AddTagsToResource
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum AddTagsToResourceError {
     /// <p>Indicates that an internal error occurred.</p>
     CloudHsmInternal(String),
@@ -621,6 +622,8 @@ pub enum AddTagsToResourceError {
     CloudHsmService(String),
     /// <p>Indicates that one or more of the request parameters are not valid.</p>
     InvalidRequest(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl AddTagsToResourceError {
@@ -628,16 +631,25 @@ impl AddTagsToResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "CloudHsmInternalException" => {
-                    return RusotoError::Service(AddTagsToResourceError::CloudHsmInternal(err.msg))
+                    return RusotoError::service(
+                        AddTagsToResourceError::CloudHsmInternal(err.msg),
+                        &res,
+                    )
                 }
                 "CloudHsmServiceException" => {
-                    return RusotoError::Service(AddTagsToResourceError::CloudHsmService(err.msg))
+                    return RusotoError::service(
+                        AddTagsToResourceError::CloudHsmService(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(AddTagsToResourceError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        AddTagsToResourceError::InvalidRequest(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(AddTagsToResourceError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -650,12 +662,14 @@ impl fmt::Display for AddTagsToResourceError {
             AddTagsToResourceError::CloudHsmInternal(ref cause) => write!(f, "{}", cause),
             AddTagsToResourceError::CloudHsmService(ref cause) => write!(f, "{}", cause),
             AddTagsToResourceError::InvalidRequest(ref cause) => write!(f, "{}", cause),
+            AddTagsToResourceError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for AddTagsToResourceError {}
 /// Errors returned by CreateHapg
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateHapgError {
     /// <p>Indicates that an internal error occurred.</p>
     CloudHsmInternal(String),
@@ -663,6 +677,8 @@ pub enum CreateHapgError {
     CloudHsmService(String),
     /// <p>Indicates that one or more of the request parameters are not valid.</p>
     InvalidRequest(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateHapgError {
@@ -670,16 +686,16 @@ impl CreateHapgError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "CloudHsmInternalException" => {
-                    return RusotoError::Service(CreateHapgError::CloudHsmInternal(err.msg))
+                    return RusotoError::service(CreateHapgError::CloudHsmInternal(err.msg), &res)
                 }
                 "CloudHsmServiceException" => {
-                    return RusotoError::Service(CreateHapgError::CloudHsmService(err.msg))
+                    return RusotoError::service(CreateHapgError::CloudHsmService(err.msg), &res)
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(CreateHapgError::InvalidRequest(err.msg))
+                    return RusotoError::service(CreateHapgError::InvalidRequest(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreateHapgError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -692,12 +708,14 @@ impl fmt::Display for CreateHapgError {
             CreateHapgError::CloudHsmInternal(ref cause) => write!(f, "{}", cause),
             CreateHapgError::CloudHsmService(ref cause) => write!(f, "{}", cause),
             CreateHapgError::InvalidRequest(ref cause) => write!(f, "{}", cause),
+            CreateHapgError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateHapgError {}
 /// Errors returned by CreateHsm
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateHsmError {
     /// <p>Indicates that an internal error occurred.</p>
     CloudHsmInternal(String),
@@ -705,6 +723,8 @@ pub enum CreateHsmError {
     CloudHsmService(String),
     /// <p>Indicates that one or more of the request parameters are not valid.</p>
     InvalidRequest(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateHsmError {
@@ -712,16 +732,16 @@ impl CreateHsmError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "CloudHsmInternalException" => {
-                    return RusotoError::Service(CreateHsmError::CloudHsmInternal(err.msg))
+                    return RusotoError::service(CreateHsmError::CloudHsmInternal(err.msg), &res)
                 }
                 "CloudHsmServiceException" => {
-                    return RusotoError::Service(CreateHsmError::CloudHsmService(err.msg))
+                    return RusotoError::service(CreateHsmError::CloudHsmService(err.msg), &res)
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(CreateHsmError::InvalidRequest(err.msg))
+                    return RusotoError::service(CreateHsmError::InvalidRequest(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreateHsmError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -734,12 +754,14 @@ impl fmt::Display for CreateHsmError {
             CreateHsmError::CloudHsmInternal(ref cause) => write!(f, "{}", cause),
             CreateHsmError::CloudHsmService(ref cause) => write!(f, "{}", cause),
             CreateHsmError::InvalidRequest(ref cause) => write!(f, "{}", cause),
+            CreateHsmError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateHsmError {}
 /// Errors returned by CreateLunaClient
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateLunaClientError {
     /// <p>Indicates that an internal error occurred.</p>
     CloudHsmInternal(String),
@@ -747,6 +769,8 @@ pub enum CreateLunaClientError {
     CloudHsmService(String),
     /// <p>Indicates that one or more of the request parameters are not valid.</p>
     InvalidRequest(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateLunaClientError {
@@ -754,16 +778,25 @@ impl CreateLunaClientError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "CloudHsmInternalException" => {
-                    return RusotoError::Service(CreateLunaClientError::CloudHsmInternal(err.msg))
+                    return RusotoError::service(
+                        CreateLunaClientError::CloudHsmInternal(err.msg),
+                        &res,
+                    )
                 }
                 "CloudHsmServiceException" => {
-                    return RusotoError::Service(CreateLunaClientError::CloudHsmService(err.msg))
+                    return RusotoError::service(
+                        CreateLunaClientError::CloudHsmService(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(CreateLunaClientError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        CreateLunaClientError::InvalidRequest(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreateLunaClientError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -776,12 +809,14 @@ impl fmt::Display for CreateLunaClientError {
             CreateLunaClientError::CloudHsmInternal(ref cause) => write!(f, "{}", cause),
             CreateLunaClientError::CloudHsmService(ref cause) => write!(f, "{}", cause),
             CreateLunaClientError::InvalidRequest(ref cause) => write!(f, "{}", cause),
+            CreateLunaClientError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateLunaClientError {}
 /// Errors returned by DeleteHapg
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteHapgError {
     /// <p>Indicates that an internal error occurred.</p>
     CloudHsmInternal(String),
@@ -789,6 +824,8 @@ pub enum DeleteHapgError {
     CloudHsmService(String),
     /// <p>Indicates that one or more of the request parameters are not valid.</p>
     InvalidRequest(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteHapgError {
@@ -796,16 +833,16 @@ impl DeleteHapgError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "CloudHsmInternalException" => {
-                    return RusotoError::Service(DeleteHapgError::CloudHsmInternal(err.msg))
+                    return RusotoError::service(DeleteHapgError::CloudHsmInternal(err.msg), &res)
                 }
                 "CloudHsmServiceException" => {
-                    return RusotoError::Service(DeleteHapgError::CloudHsmService(err.msg))
+                    return RusotoError::service(DeleteHapgError::CloudHsmService(err.msg), &res)
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DeleteHapgError::InvalidRequest(err.msg))
+                    return RusotoError::service(DeleteHapgError::InvalidRequest(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteHapgError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -818,12 +855,14 @@ impl fmt::Display for DeleteHapgError {
             DeleteHapgError::CloudHsmInternal(ref cause) => write!(f, "{}", cause),
             DeleteHapgError::CloudHsmService(ref cause) => write!(f, "{}", cause),
             DeleteHapgError::InvalidRequest(ref cause) => write!(f, "{}", cause),
+            DeleteHapgError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteHapgError {}
 /// Errors returned by DeleteHsm
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteHsmError {
     /// <p>Indicates that an internal error occurred.</p>
     CloudHsmInternal(String),
@@ -831,6 +870,8 @@ pub enum DeleteHsmError {
     CloudHsmService(String),
     /// <p>Indicates that one or more of the request parameters are not valid.</p>
     InvalidRequest(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteHsmError {
@@ -838,16 +879,16 @@ impl DeleteHsmError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "CloudHsmInternalException" => {
-                    return RusotoError::Service(DeleteHsmError::CloudHsmInternal(err.msg))
+                    return RusotoError::service(DeleteHsmError::CloudHsmInternal(err.msg), &res)
                 }
                 "CloudHsmServiceException" => {
-                    return RusotoError::Service(DeleteHsmError::CloudHsmService(err.msg))
+                    return RusotoError::service(DeleteHsmError::CloudHsmService(err.msg), &res)
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DeleteHsmError::InvalidRequest(err.msg))
+                    return RusotoError::service(DeleteHsmError::InvalidRequest(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteHsmError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -860,12 +901,14 @@ impl fmt::Display for DeleteHsmError {
             DeleteHsmError::CloudHsmInternal(ref cause) => write!(f, "{}", cause),
             DeleteHsmError::CloudHsmService(ref cause) => write!(f, "{}", cause),
             DeleteHsmError::InvalidRequest(ref cause) => write!(f, "{}", cause),
+            DeleteHsmError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteHsmError {}
 /// Errors returned by DeleteLunaClient
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteLunaClientError {
     /// <p>Indicates that an internal error occurred.</p>
     CloudHsmInternal(String),
@@ -873,6 +916,8 @@ pub enum DeleteLunaClientError {
     CloudHsmService(String),
     /// <p>Indicates that one or more of the request parameters are not valid.</p>
     InvalidRequest(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteLunaClientError {
@@ -880,16 +925,25 @@ impl DeleteLunaClientError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "CloudHsmInternalException" => {
-                    return RusotoError::Service(DeleteLunaClientError::CloudHsmInternal(err.msg))
+                    return RusotoError::service(
+                        DeleteLunaClientError::CloudHsmInternal(err.msg),
+                        &res,
+                    )
                 }
                 "CloudHsmServiceException" => {
-                    return RusotoError::Service(DeleteLunaClientError::CloudHsmService(err.msg))
+                    return RusotoError::service(
+                        DeleteLunaClientError::CloudHsmService(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DeleteLunaClientError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        DeleteLunaClientError::InvalidRequest(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteLunaClientError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -902,12 +956,14 @@ impl fmt::Display for DeleteLunaClientError {
             DeleteLunaClientError::CloudHsmInternal(ref cause) => write!(f, "{}", cause),
             DeleteLunaClientError::CloudHsmService(ref cause) => write!(f, "{}", cause),
             DeleteLunaClientError::InvalidRequest(ref cause) => write!(f, "{}", cause),
+            DeleteLunaClientError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteLunaClientError {}
 /// Errors returned by DescribeHapg
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeHapgError {
     /// <p>Indicates that an internal error occurred.</p>
     CloudHsmInternal(String),
@@ -915,6 +971,8 @@ pub enum DescribeHapgError {
     CloudHsmService(String),
     /// <p>Indicates that one or more of the request parameters are not valid.</p>
     InvalidRequest(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeHapgError {
@@ -922,16 +980,16 @@ impl DescribeHapgError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "CloudHsmInternalException" => {
-                    return RusotoError::Service(DescribeHapgError::CloudHsmInternal(err.msg))
+                    return RusotoError::service(DescribeHapgError::CloudHsmInternal(err.msg), &res)
                 }
                 "CloudHsmServiceException" => {
-                    return RusotoError::Service(DescribeHapgError::CloudHsmService(err.msg))
+                    return RusotoError::service(DescribeHapgError::CloudHsmService(err.msg), &res)
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DescribeHapgError::InvalidRequest(err.msg))
+                    return RusotoError::service(DescribeHapgError::InvalidRequest(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DescribeHapgError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -944,12 +1002,14 @@ impl fmt::Display for DescribeHapgError {
             DescribeHapgError::CloudHsmInternal(ref cause) => write!(f, "{}", cause),
             DescribeHapgError::CloudHsmService(ref cause) => write!(f, "{}", cause),
             DescribeHapgError::InvalidRequest(ref cause) => write!(f, "{}", cause),
+            DescribeHapgError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeHapgError {}
 /// Errors returned by DescribeHsm
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeHsmError {
     /// <p>Indicates that an internal error occurred.</p>
     CloudHsmInternal(String),
@@ -957,6 +1017,8 @@ pub enum DescribeHsmError {
     CloudHsmService(String),
     /// <p>Indicates that one or more of the request parameters are not valid.</p>
     InvalidRequest(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeHsmError {
@@ -964,16 +1026,16 @@ impl DescribeHsmError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "CloudHsmInternalException" => {
-                    return RusotoError::Service(DescribeHsmError::CloudHsmInternal(err.msg))
+                    return RusotoError::service(DescribeHsmError::CloudHsmInternal(err.msg), &res)
                 }
                 "CloudHsmServiceException" => {
-                    return RusotoError::Service(DescribeHsmError::CloudHsmService(err.msg))
+                    return RusotoError::service(DescribeHsmError::CloudHsmService(err.msg), &res)
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DescribeHsmError::InvalidRequest(err.msg))
+                    return RusotoError::service(DescribeHsmError::InvalidRequest(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DescribeHsmError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -986,12 +1048,14 @@ impl fmt::Display for DescribeHsmError {
             DescribeHsmError::CloudHsmInternal(ref cause) => write!(f, "{}", cause),
             DescribeHsmError::CloudHsmService(ref cause) => write!(f, "{}", cause),
             DescribeHsmError::InvalidRequest(ref cause) => write!(f, "{}", cause),
+            DescribeHsmError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeHsmError {}
 /// Errors returned by DescribeLunaClient
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeLunaClientError {
     /// <p>Indicates that an internal error occurred.</p>
     CloudHsmInternal(String),
@@ -999,6 +1063,8 @@ pub enum DescribeLunaClientError {
     CloudHsmService(String),
     /// <p>Indicates that one or more of the request parameters are not valid.</p>
     InvalidRequest(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeLunaClientError {
@@ -1006,16 +1072,25 @@ impl DescribeLunaClientError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "CloudHsmInternalException" => {
-                    return RusotoError::Service(DescribeLunaClientError::CloudHsmInternal(err.msg))
+                    return RusotoError::service(
+                        DescribeLunaClientError::CloudHsmInternal(err.msg),
+                        &res,
+                    )
                 }
                 "CloudHsmServiceException" => {
-                    return RusotoError::Service(DescribeLunaClientError::CloudHsmService(err.msg))
+                    return RusotoError::service(
+                        DescribeLunaClientError::CloudHsmService(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DescribeLunaClientError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        DescribeLunaClientError::InvalidRequest(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DescribeLunaClientError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1028,12 +1103,14 @@ impl fmt::Display for DescribeLunaClientError {
             DescribeLunaClientError::CloudHsmInternal(ref cause) => write!(f, "{}", cause),
             DescribeLunaClientError::CloudHsmService(ref cause) => write!(f, "{}", cause),
             DescribeLunaClientError::InvalidRequest(ref cause) => write!(f, "{}", cause),
+            DescribeLunaClientError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeLunaClientError {}
 /// Errors returned by GetConfig
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetConfigError {
     /// <p>Indicates that an internal error occurred.</p>
     CloudHsmInternal(String),
@@ -1041,6 +1118,8 @@ pub enum GetConfigError {
     CloudHsmService(String),
     /// <p>Indicates that one or more of the request parameters are not valid.</p>
     InvalidRequest(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetConfigError {
@@ -1048,16 +1127,16 @@ impl GetConfigError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "CloudHsmInternalException" => {
-                    return RusotoError::Service(GetConfigError::CloudHsmInternal(err.msg))
+                    return RusotoError::service(GetConfigError::CloudHsmInternal(err.msg), &res)
                 }
                 "CloudHsmServiceException" => {
-                    return RusotoError::Service(GetConfigError::CloudHsmService(err.msg))
+                    return RusotoError::service(GetConfigError::CloudHsmService(err.msg), &res)
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(GetConfigError::InvalidRequest(err.msg))
+                    return RusotoError::service(GetConfigError::InvalidRequest(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetConfigError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1070,12 +1149,14 @@ impl fmt::Display for GetConfigError {
             GetConfigError::CloudHsmInternal(ref cause) => write!(f, "{}", cause),
             GetConfigError::CloudHsmService(ref cause) => write!(f, "{}", cause),
             GetConfigError::InvalidRequest(ref cause) => write!(f, "{}", cause),
+            GetConfigError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetConfigError {}
 /// Errors returned by ListAvailableZones
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListAvailableZonesError {
     /// <p>Indicates that an internal error occurred.</p>
     CloudHsmInternal(String),
@@ -1083,6 +1164,8 @@ pub enum ListAvailableZonesError {
     CloudHsmService(String),
     /// <p>Indicates that one or more of the request parameters are not valid.</p>
     InvalidRequest(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListAvailableZonesError {
@@ -1090,16 +1173,25 @@ impl ListAvailableZonesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "CloudHsmInternalException" => {
-                    return RusotoError::Service(ListAvailableZonesError::CloudHsmInternal(err.msg))
+                    return RusotoError::service(
+                        ListAvailableZonesError::CloudHsmInternal(err.msg),
+                        &res,
+                    )
                 }
                 "CloudHsmServiceException" => {
-                    return RusotoError::Service(ListAvailableZonesError::CloudHsmService(err.msg))
+                    return RusotoError::service(
+                        ListAvailableZonesError::CloudHsmService(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(ListAvailableZonesError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        ListAvailableZonesError::InvalidRequest(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListAvailableZonesError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1112,12 +1204,14 @@ impl fmt::Display for ListAvailableZonesError {
             ListAvailableZonesError::CloudHsmInternal(ref cause) => write!(f, "{}", cause),
             ListAvailableZonesError::CloudHsmService(ref cause) => write!(f, "{}", cause),
             ListAvailableZonesError::InvalidRequest(ref cause) => write!(f, "{}", cause),
+            ListAvailableZonesError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListAvailableZonesError {}
 /// Errors returned by ListHapgs
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListHapgsError {
     /// <p>Indicates that an internal error occurred.</p>
     CloudHsmInternal(String),
@@ -1125,6 +1219,8 @@ pub enum ListHapgsError {
     CloudHsmService(String),
     /// <p>Indicates that one or more of the request parameters are not valid.</p>
     InvalidRequest(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListHapgsError {
@@ -1132,16 +1228,16 @@ impl ListHapgsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "CloudHsmInternalException" => {
-                    return RusotoError::Service(ListHapgsError::CloudHsmInternal(err.msg))
+                    return RusotoError::service(ListHapgsError::CloudHsmInternal(err.msg), &res)
                 }
                 "CloudHsmServiceException" => {
-                    return RusotoError::Service(ListHapgsError::CloudHsmService(err.msg))
+                    return RusotoError::service(ListHapgsError::CloudHsmService(err.msg), &res)
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(ListHapgsError::InvalidRequest(err.msg))
+                    return RusotoError::service(ListHapgsError::InvalidRequest(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListHapgsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1154,12 +1250,14 @@ impl fmt::Display for ListHapgsError {
             ListHapgsError::CloudHsmInternal(ref cause) => write!(f, "{}", cause),
             ListHapgsError::CloudHsmService(ref cause) => write!(f, "{}", cause),
             ListHapgsError::InvalidRequest(ref cause) => write!(f, "{}", cause),
+            ListHapgsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListHapgsError {}
 /// Errors returned by ListHsms
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListHsmsError {
     /// <p>Indicates that an internal error occurred.</p>
     CloudHsmInternal(String),
@@ -1167,6 +1265,8 @@ pub enum ListHsmsError {
     CloudHsmService(String),
     /// <p>Indicates that one or more of the request parameters are not valid.</p>
     InvalidRequest(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListHsmsError {
@@ -1174,16 +1274,16 @@ impl ListHsmsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "CloudHsmInternalException" => {
-                    return RusotoError::Service(ListHsmsError::CloudHsmInternal(err.msg))
+                    return RusotoError::service(ListHsmsError::CloudHsmInternal(err.msg), &res)
                 }
                 "CloudHsmServiceException" => {
-                    return RusotoError::Service(ListHsmsError::CloudHsmService(err.msg))
+                    return RusotoError::service(ListHsmsError::CloudHsmService(err.msg), &res)
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(ListHsmsError::InvalidRequest(err.msg))
+                    return RusotoError::service(ListHsmsError::InvalidRequest(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListHsmsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1196,12 +1296,14 @@ impl fmt::Display for ListHsmsError {
             ListHsmsError::CloudHsmInternal(ref cause) => write!(f, "{}", cause),
             ListHsmsError::CloudHsmService(ref cause) => write!(f, "{}", cause),
             ListHsmsError::InvalidRequest(ref cause) => write!(f, "{}", cause),
+            ListHsmsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListHsmsError {}
 /// Errors returned by ListLunaClients
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListLunaClientsError {
     /// <p>Indicates that an internal error occurred.</p>
     CloudHsmInternal(String),
@@ -1209,6 +1311,8 @@ pub enum ListLunaClientsError {
     CloudHsmService(String),
     /// <p>Indicates that one or more of the request parameters are not valid.</p>
     InvalidRequest(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListLunaClientsError {
@@ -1216,16 +1320,25 @@ impl ListLunaClientsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "CloudHsmInternalException" => {
-                    return RusotoError::Service(ListLunaClientsError::CloudHsmInternal(err.msg))
+                    return RusotoError::service(
+                        ListLunaClientsError::CloudHsmInternal(err.msg),
+                        &res,
+                    )
                 }
                 "CloudHsmServiceException" => {
-                    return RusotoError::Service(ListLunaClientsError::CloudHsmService(err.msg))
+                    return RusotoError::service(
+                        ListLunaClientsError::CloudHsmService(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(ListLunaClientsError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        ListLunaClientsError::InvalidRequest(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListLunaClientsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1238,12 +1351,14 @@ impl fmt::Display for ListLunaClientsError {
             ListLunaClientsError::CloudHsmInternal(ref cause) => write!(f, "{}", cause),
             ListLunaClientsError::CloudHsmService(ref cause) => write!(f, "{}", cause),
             ListLunaClientsError::InvalidRequest(ref cause) => write!(f, "{}", cause),
+            ListLunaClientsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListLunaClientsError {}
 /// Errors returned by ListTagsForResource
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListTagsForResourceError {
     /// <p>Indicates that an internal error occurred.</p>
     CloudHsmInternal(String),
@@ -1251,6 +1366,8 @@ pub enum ListTagsForResourceError {
     CloudHsmService(String),
     /// <p>Indicates that one or more of the request parameters are not valid.</p>
     InvalidRequest(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListTagsForResourceError {
@@ -1258,18 +1375,25 @@ impl ListTagsForResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "CloudHsmInternalException" => {
-                    return RusotoError::Service(ListTagsForResourceError::CloudHsmInternal(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListTagsForResourceError::CloudHsmInternal(err.msg),
+                        &res,
+                    )
                 }
                 "CloudHsmServiceException" => {
-                    return RusotoError::Service(ListTagsForResourceError::CloudHsmService(err.msg))
+                    return RusotoError::service(
+                        ListTagsForResourceError::CloudHsmService(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(ListTagsForResourceError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        ListTagsForResourceError::InvalidRequest(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListTagsForResourceError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1282,12 +1406,14 @@ impl fmt::Display for ListTagsForResourceError {
             ListTagsForResourceError::CloudHsmInternal(ref cause) => write!(f, "{}", cause),
             ListTagsForResourceError::CloudHsmService(ref cause) => write!(f, "{}", cause),
             ListTagsForResourceError::InvalidRequest(ref cause) => write!(f, "{}", cause),
+            ListTagsForResourceError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListTagsForResourceError {}
 /// Errors returned by ModifyHapg
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ModifyHapgError {
     /// <p>Indicates that an internal error occurred.</p>
     CloudHsmInternal(String),
@@ -1295,6 +1421,8 @@ pub enum ModifyHapgError {
     CloudHsmService(String),
     /// <p>Indicates that one or more of the request parameters are not valid.</p>
     InvalidRequest(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ModifyHapgError {
@@ -1302,16 +1430,16 @@ impl ModifyHapgError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "CloudHsmInternalException" => {
-                    return RusotoError::Service(ModifyHapgError::CloudHsmInternal(err.msg))
+                    return RusotoError::service(ModifyHapgError::CloudHsmInternal(err.msg), &res)
                 }
                 "CloudHsmServiceException" => {
-                    return RusotoError::Service(ModifyHapgError::CloudHsmService(err.msg))
+                    return RusotoError::service(ModifyHapgError::CloudHsmService(err.msg), &res)
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(ModifyHapgError::InvalidRequest(err.msg))
+                    return RusotoError::service(ModifyHapgError::InvalidRequest(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ModifyHapgError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1324,12 +1452,14 @@ impl fmt::Display for ModifyHapgError {
             ModifyHapgError::CloudHsmInternal(ref cause) => write!(f, "{}", cause),
             ModifyHapgError::CloudHsmService(ref cause) => write!(f, "{}", cause),
             ModifyHapgError::InvalidRequest(ref cause) => write!(f, "{}", cause),
+            ModifyHapgError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ModifyHapgError {}
 /// Errors returned by ModifyHsm
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ModifyHsmError {
     /// <p>Indicates that an internal error occurred.</p>
     CloudHsmInternal(String),
@@ -1337,6 +1467,8 @@ pub enum ModifyHsmError {
     CloudHsmService(String),
     /// <p>Indicates that one or more of the request parameters are not valid.</p>
     InvalidRequest(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ModifyHsmError {
@@ -1344,16 +1476,16 @@ impl ModifyHsmError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "CloudHsmInternalException" => {
-                    return RusotoError::Service(ModifyHsmError::CloudHsmInternal(err.msg))
+                    return RusotoError::service(ModifyHsmError::CloudHsmInternal(err.msg), &res)
                 }
                 "CloudHsmServiceException" => {
-                    return RusotoError::Service(ModifyHsmError::CloudHsmService(err.msg))
+                    return RusotoError::service(ModifyHsmError::CloudHsmService(err.msg), &res)
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(ModifyHsmError::InvalidRequest(err.msg))
+                    return RusotoError::service(ModifyHsmError::InvalidRequest(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ModifyHsmError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1366,15 +1498,19 @@ impl fmt::Display for ModifyHsmError {
             ModifyHsmError::CloudHsmInternal(ref cause) => write!(f, "{}", cause),
             ModifyHsmError::CloudHsmService(ref cause) => write!(f, "{}", cause),
             ModifyHsmError::InvalidRequest(ref cause) => write!(f, "{}", cause),
+            ModifyHsmError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ModifyHsmError {}
 /// Errors returned by ModifyLunaClient
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ModifyLunaClientError {
     /// <p>Indicates that an exception occurred in the AWS CloudHSM service.</p>
     CloudHsmService(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ModifyLunaClientError {
@@ -1382,10 +1518,13 @@ impl ModifyLunaClientError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "CloudHsmServiceException" => {
-                    return RusotoError::Service(ModifyLunaClientError::CloudHsmService(err.msg))
+                    return RusotoError::service(
+                        ModifyLunaClientError::CloudHsmService(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ModifyLunaClientError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1396,12 +1535,14 @@ impl fmt::Display for ModifyLunaClientError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             ModifyLunaClientError::CloudHsmService(ref cause) => write!(f, "{}", cause),
+            ModifyLunaClientError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ModifyLunaClientError {}
 /// Errors returned by RemoveTagsFromResource
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum RemoveTagsFromResourceError {
     /// <p>Indicates that an internal error occurred.</p>
     CloudHsmInternal(String),
@@ -1409,6 +1550,8 @@ pub enum RemoveTagsFromResourceError {
     CloudHsmService(String),
     /// <p>Indicates that one or more of the request parameters are not valid.</p>
     InvalidRequest(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl RemoveTagsFromResourceError {
@@ -1416,22 +1559,25 @@ impl RemoveTagsFromResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "CloudHsmInternalException" => {
-                    return RusotoError::Service(RemoveTagsFromResourceError::CloudHsmInternal(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        RemoveTagsFromResourceError::CloudHsmInternal(err.msg),
+                        &res,
+                    )
                 }
                 "CloudHsmServiceException" => {
-                    return RusotoError::Service(RemoveTagsFromResourceError::CloudHsmService(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        RemoveTagsFromResourceError::CloudHsmService(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(RemoveTagsFromResourceError::InvalidRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        RemoveTagsFromResourceError::InvalidRequest(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(RemoveTagsFromResourceError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1444,6 +1590,7 @@ impl fmt::Display for RemoveTagsFromResourceError {
             RemoveTagsFromResourceError::CloudHsmInternal(ref cause) => write!(f, "{}", cause),
             RemoveTagsFromResourceError::CloudHsmService(ref cause) => write!(f, "{}", cause),
             RemoveTagsFromResourceError::InvalidRequest(ref cause) => write!(f, "{}", cause),
+            RemoveTagsFromResourceError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
@@ -1581,6 +1728,7 @@ impl CloudHsmClient {
     /// Creates a client backed by the default tokio event loop.
     ///
     /// The client will use the default credentials provider and tls client.
+    #[cfg(not(target_arch = "wasm32"))]
     pub fn new(region: region::Region) -> CloudHsmClient {
         CloudHsmClient {
             client: Client::shared(),
@@ -1606,424 +1754,859 @@ impl CloudHsmClient {
     pub fn new_with_client(client: Client, region: region::Region) -> CloudHsmClient {
         CloudHsmClient { client, region }
     }
+
+    /// Returns a builder of a client, setting any of its region, credentials provider,
+    /// dispatcher, retry policy, endpoint and timeouts.
+    pub fn builder() -> rusoto_core::ClientBuilder<CloudHsmClient> {
+        rusoto_core::ClientBuilder::new(CloudHsmClient::new_with_client)
+    }
+
+    /// Returns a clone of this client with the overrides of `config` applied to its
+    /// operations, sharing its connections.
+    pub fn customize(&self, config: &rusoto_core::OperationConfig) -> CloudHsmClient {
+        CloudHsmClient {
+            client: self.client.customize(config),
+            region: config.resolve_region(&self.region),
+        }
+    }
 }
 
-#[async_trait]
-impl CloudHsm for CloudHsmClient {
-    /// <p>This is documentation for <b>AWS CloudHSM Classic</b>. For more information, see <a href="http://aws.amazon.com/cloudhsm/faqs-classic/">AWS CloudHSM Classic FAQs</a>, the <a href="http://docs.aws.amazon.com/cloudhsm/classic/userguide/">AWS CloudHSM Classic User Guide</a>, and the <a href="http://docs.aws.amazon.com/cloudhsm/classic/APIReference/">AWS CloudHSM Classic API Reference</a>.</p> <p> <b>For information about the current version of AWS CloudHSM</b>, see <a href="http://aws.amazon.com/cloudhsm/">AWS CloudHSM</a>, the <a href="http://docs.aws.amazon.com/cloudhsm/latest/userguide/">AWS CloudHSM User Guide</a>, and the <a href="http://docs.aws.amazon.com/cloudhsm/latest/APIReference/">AWS CloudHSM API Reference</a>.</p> <p>Adds or overwrites one or more tags for the specified AWS CloudHSM resource.</p> <p>Each tag consists of a key and a value. Tag keys must be unique to each resource.</p>
-    async fn add_tags_to_resource(
+impl CloudHsmClient {
+    /// Calls `add_tags_to_resource`, also returning the metadata of the response.
+    pub async fn add_tags_to_resource_with_metadata(
         &self,
         input: AddTagsToResourceRequest,
-    ) -> Result<AddTagsToResourceResponse, RusotoError<AddTagsToResourceError>> {
+    ) -> Result<(AddTagsToResourceResponse, ResponseMetadata), RusotoError<AddTagsToResourceError>>
+    {
+        let request = self.build_add_tags_to_resource_request(input);
+
+        let mut response = self
+            .client
+            .sign_and_dispatch(request)
+            .await
+            .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
+        if response.status.is_success() {
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<AddTagsToResourceResponse, _>()?;
+            Ok((result, metadata))
+        } else {
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(AddTagsToResourceError::from_response(response))
+        }
+    }
+
+    /// Presigns the request `add_tags_to_resource` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_add_tags_to_resource(
+        &self,
+        presigner: &rusoto_core::Presigner,
+        input: AddTagsToResourceRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_add_tags_to_resource_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_add_tags_to_resource_request(&self, input: AddTagsToResourceRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "cloudhsm", &self.region, "/");
+        request.set_operation("AddTagsToResource");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "CloudHsmFrontendService.AddTagsToResource");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `create_hapg`, also returning the metadata of the response.
+    pub async fn create_hapg_with_metadata(
+        &self,
+        input: CreateHapgRequest,
+    ) -> Result<(CreateHapgResponse, ResponseMetadata), RusotoError<CreateHapgError>> {
+        let request = self.build_create_hapg_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<AddTagsToResourceResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<CreateHapgResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(AddTagsToResourceError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(CreateHapgError::from_response(response))
         }
     }
 
-    /// <p>This is documentation for <b>AWS CloudHSM Classic</b>. For more information, see <a href="http://aws.amazon.com/cloudhsm/faqs-classic/">AWS CloudHSM Classic FAQs</a>, the <a href="http://docs.aws.amazon.com/cloudhsm/classic/userguide/">AWS CloudHSM Classic User Guide</a>, and the <a href="http://docs.aws.amazon.com/cloudhsm/classic/APIReference/">AWS CloudHSM Classic API Reference</a>.</p> <p> <b>For information about the current version of AWS CloudHSM</b>, see <a href="http://aws.amazon.com/cloudhsm/">AWS CloudHSM</a>, the <a href="http://docs.aws.amazon.com/cloudhsm/latest/userguide/">AWS CloudHSM User Guide</a>, and the <a href="http://docs.aws.amazon.com/cloudhsm/latest/APIReference/">AWS CloudHSM API Reference</a>.</p> <p>Creates a high-availability partition group. A high-availability partition group is a group of partitions that spans multiple physical HSMs.</p>
-    async fn create_hapg(
+    /// Presigns the request `create_hapg` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_hapg(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: CreateHapgRequest,
-    ) -> Result<CreateHapgResponse, RusotoError<CreateHapgError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_hapg_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_hapg_request(&self, input: CreateHapgRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "cloudhsm", &self.region, "/");
+        request.set_operation("CreateHapg");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "CloudHsmFrontendService.CreateHapg");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `create_hsm`, also returning the metadata of the response.
+    pub async fn create_hsm_with_metadata(
+        &self,
+        input: CreateHsmRequest,
+    ) -> Result<(CreateHsmResponse, ResponseMetadata), RusotoError<CreateHsmError>> {
+        let request = self.build_create_hsm_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<CreateHapgResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<CreateHsmResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(CreateHapgError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(CreateHsmError::from_response(response))
         }
     }
 
-    /// <p><p>This is documentation for <b>AWS CloudHSM Classic</b>. For more information, see <a href="http://aws.amazon.com/cloudhsm/faqs-classic/">AWS CloudHSM Classic FAQs</a>, the <a href="http://docs.aws.amazon.com/cloudhsm/classic/userguide/">AWS CloudHSM Classic User Guide</a>, and the <a href="http://docs.aws.amazon.com/cloudhsm/classic/APIReference/">AWS CloudHSM Classic API Reference</a>.</p> <p> <b>For information about the current version of AWS CloudHSM</b>, see <a href="http://aws.amazon.com/cloudhsm/">AWS CloudHSM</a>, the <a href="http://docs.aws.amazon.com/cloudhsm/latest/userguide/">AWS CloudHSM User Guide</a>, and the <a href="http://docs.aws.amazon.com/cloudhsm/latest/APIReference/">AWS CloudHSM API Reference</a>.</p> <p>Creates an uninitialized HSM instance.</p> <p>There is an upfront fee charged for each HSM instance that you create with the <code>CreateHsm</code> operation. If you accidentally provision an HSM and want to request a refund, delete the instance using the <a>DeleteHsm</a> operation, go to the <a href="https://console.aws.amazon.com/support/home">AWS Support Center</a>, create a new case, and select <b>Account and Billing Support</b>.</p> <important> <p>It can take up to 20 minutes to create and provision an HSM. You can monitor the status of the HSM with the <a>DescribeHsm</a> operation. The HSM is ready to be initialized when the status changes to <code>RUNNING</code>.</p> </important></p>
-    async fn create_hsm(
+    /// Presigns the request `create_hsm` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_hsm(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: CreateHsmRequest,
-    ) -> Result<CreateHsmResponse, RusotoError<CreateHsmError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_hsm_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_hsm_request(&self, input: CreateHsmRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "cloudhsm", &self.region, "/");
+        request.set_operation("CreateHsm");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "CloudHsmFrontendService.CreateHsm");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `create_luna_client`, also returning the metadata of the response.
+    pub async fn create_luna_client_with_metadata(
+        &self,
+        input: CreateLunaClientRequest,
+    ) -> Result<(CreateLunaClientResponse, ResponseMetadata), RusotoError<CreateLunaClientError>>
+    {
+        let request = self.build_create_luna_client_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<CreateHsmResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<CreateLunaClientResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(CreateHsmError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(CreateLunaClientError::from_response(response))
         }
     }
 
-    /// <p>This is documentation for <b>AWS CloudHSM Classic</b>. For more information, see <a href="http://aws.amazon.com/cloudhsm/faqs-classic/">AWS CloudHSM Classic FAQs</a>, the <a href="http://docs.aws.amazon.com/cloudhsm/classic/userguide/">AWS CloudHSM Classic User Guide</a>, and the <a href="http://docs.aws.amazon.com/cloudhsm/classic/APIReference/">AWS CloudHSM Classic API Reference</a>.</p> <p> <b>For information about the current version of AWS CloudHSM</b>, see <a href="http://aws.amazon.com/cloudhsm/">AWS CloudHSM</a>, the <a href="http://docs.aws.amazon.com/cloudhsm/latest/userguide/">AWS CloudHSM User Guide</a>, and the <a href="http://docs.aws.amazon.com/cloudhsm/latest/APIReference/">AWS CloudHSM API Reference</a>.</p> <p>Creates an HSM client.</p>
-    async fn create_luna_client(
+    /// Presigns the request `create_luna_client` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_luna_client(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: CreateLunaClientRequest,
-    ) -> Result<CreateLunaClientResponse, RusotoError<CreateLunaClientError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_luna_client_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_luna_client_request(&self, input: CreateLunaClientRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "cloudhsm", &self.region, "/");
+        request.set_operation("CreateLunaClient");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "CloudHsmFrontendService.CreateLunaClient");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `delete_hapg`, also returning the metadata of the response.
+    pub async fn delete_hapg_with_metadata(
+        &self,
+        input: DeleteHapgRequest,
+    ) -> Result<(DeleteHapgResponse, ResponseMetadata), RusotoError<DeleteHapgError>> {
+        let request = self.build_delete_hapg_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<CreateLunaClientResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DeleteHapgResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(CreateLunaClientError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DeleteHapgError::from_response(response))
         }
     }
 
-    /// <p>This is documentation for <b>AWS CloudHSM Classic</b>. For more information, see <a href="http://aws.amazon.com/cloudhsm/faqs-classic/">AWS CloudHSM Classic FAQs</a>, the <a href="http://docs.aws.amazon.com/cloudhsm/classic/userguide/">AWS CloudHSM Classic User Guide</a>, and the <a href="http://docs.aws.amazon.com/cloudhsm/classic/APIReference/">AWS CloudHSM Classic API Reference</a>.</p> <p> <b>For information about the current version of AWS CloudHSM</b>, see <a href="http://aws.amazon.com/cloudhsm/">AWS CloudHSM</a>, the <a href="http://docs.aws.amazon.com/cloudhsm/latest/userguide/">AWS CloudHSM User Guide</a>, and the <a href="http://docs.aws.amazon.com/cloudhsm/latest/APIReference/">AWS CloudHSM API Reference</a>.</p> <p>Deletes a high-availability partition group.</p>
-    async fn delete_hapg(
+    /// Presigns the request `delete_hapg` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_hapg(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteHapgRequest,
-    ) -> Result<DeleteHapgResponse, RusotoError<DeleteHapgError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_hapg_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_hapg_request(&self, input: DeleteHapgRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "cloudhsm", &self.region, "/");
+        request.set_operation("DeleteHapg");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "CloudHsmFrontendService.DeleteHapg");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `delete_hsm`, also returning the metadata of the response.
+    pub async fn delete_hsm_with_metadata(
+        &self,
+        input: DeleteHsmRequest,
+    ) -> Result<(DeleteHsmResponse, ResponseMetadata), RusotoError<DeleteHsmError>> {
+        let request = self.build_delete_hsm_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<DeleteHapgResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DeleteHsmResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteHapgError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DeleteHsmError::from_response(response))
         }
     }
 
-    /// <p>This is documentation for <b>AWS CloudHSM Classic</b>. For more information, see <a href="http://aws.amazon.com/cloudhsm/faqs-classic/">AWS CloudHSM Classic FAQs</a>, the <a href="http://docs.aws.amazon.com/cloudhsm/classic/userguide/">AWS CloudHSM Classic User Guide</a>, and the <a href="http://docs.aws.amazon.com/cloudhsm/classic/APIReference/">AWS CloudHSM Classic API Reference</a>.</p> <p> <b>For information about the current version of AWS CloudHSM</b>, see <a href="http://aws.amazon.com/cloudhsm/">AWS CloudHSM</a>, the <a href="http://docs.aws.amazon.com/cloudhsm/latest/userguide/">AWS CloudHSM User Guide</a>, and the <a href="http://docs.aws.amazon.com/cloudhsm/latest/APIReference/">AWS CloudHSM API Reference</a>.</p> <p>Deletes an HSM. After completion, this operation cannot be undone and your key material cannot be recovered.</p>
-    async fn delete_hsm(
+    /// Presigns the request `delete_hsm` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_hsm(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteHsmRequest,
-    ) -> Result<DeleteHsmResponse, RusotoError<DeleteHsmError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_hsm_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_hsm_request(&self, input: DeleteHsmRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "cloudhsm", &self.region, "/");
+        request.set_operation("DeleteHsm");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "CloudHsmFrontendService.DeleteHsm");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `delete_luna_client`, also returning the metadata of the response.
+    pub async fn delete_luna_client_with_metadata(
+        &self,
+        input: DeleteLunaClientRequest,
+    ) -> Result<(DeleteLunaClientResponse, ResponseMetadata), RusotoError<DeleteLunaClientError>>
+    {
+        let request = self.build_delete_luna_client_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<DeleteHsmResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DeleteLunaClientResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteHsmError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DeleteLunaClientError::from_response(response))
         }
     }
 
-    /// <p>This is documentation for <b>AWS CloudHSM Classic</b>. For more information, see <a href="http://aws.amazon.com/cloudhsm/faqs-classic/">AWS CloudHSM Classic FAQs</a>, the <a href="http://docs.aws.amazon.com/cloudhsm/classic/userguide/">AWS CloudHSM Classic User Guide</a>, and the <a href="http://docs.aws.amazon.com/cloudhsm/classic/APIReference/">AWS CloudHSM Classic API Reference</a>.</p> <p> <b>For information about the current version of AWS CloudHSM</b>, see <a href="http://aws.amazon.com/cloudhsm/">AWS CloudHSM</a>, the <a href="http://docs.aws.amazon.com/cloudhsm/latest/userguide/">AWS CloudHSM User Guide</a>, and the <a href="http://docs.aws.amazon.com/cloudhsm/latest/APIReference/">AWS CloudHSM API Reference</a>.</p> <p>Deletes a client.</p>
-    async fn delete_luna_client(
+    /// Presigns the request `delete_luna_client` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_luna_client(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteLunaClientRequest,
-    ) -> Result<DeleteLunaClientResponse, RusotoError<DeleteLunaClientError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_luna_client_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_luna_client_request(&self, input: DeleteLunaClientRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "cloudhsm", &self.region, "/");
+        request.set_operation("DeleteLunaClient");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "CloudHsmFrontendService.DeleteLunaClient");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `describe_hapg`, also returning the metadata of the response.
+    pub async fn describe_hapg_with_metadata(
+        &self,
+        input: DescribeHapgRequest,
+    ) -> Result<(DescribeHapgResponse, ResponseMetadata), RusotoError<DescribeHapgError>> {
+        let request = self.build_describe_hapg_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DeleteLunaClientResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DescribeHapgResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteLunaClientError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DescribeHapgError::from_response(response))
         }
     }
 
-    /// <p>This is documentation for <b>AWS CloudHSM Classic</b>. For more information, see <a href="http://aws.amazon.com/cloudhsm/faqs-classic/">AWS CloudHSM Classic FAQs</a>, the <a href="http://docs.aws.amazon.com/cloudhsm/classic/userguide/">AWS CloudHSM Classic User Guide</a>, and the <a href="http://docs.aws.amazon.com/cloudhsm/classic/APIReference/">AWS CloudHSM Classic API Reference</a>.</p> <p> <b>For information about the current version of AWS CloudHSM</b>, see <a href="http://aws.amazon.com/cloudhsm/">AWS CloudHSM</a>, the <a href="http://docs.aws.amazon.com/cloudhsm/latest/userguide/">AWS CloudHSM User Guide</a>, and the <a href="http://docs.aws.amazon.com/cloudhsm/latest/APIReference/">AWS CloudHSM API Reference</a>.</p> <p>Retrieves information about a high-availability partition group.</p>
-    async fn describe_hapg(
+    /// Presigns the request `describe_hapg` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_hapg(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeHapgRequest,
-    ) -> Result<DescribeHapgResponse, RusotoError<DescribeHapgError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_hapg_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_hapg_request(&self, input: DescribeHapgRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "cloudhsm", &self.region, "/");
+        request.set_operation("DescribeHapg");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "CloudHsmFrontendService.DescribeHapg");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `describe_hsm`, also returning the metadata of the response.
+    pub async fn describe_hsm_with_metadata(
+        &self,
+        input: DescribeHsmRequest,
+    ) -> Result<(DescribeHsmResponse, ResponseMetadata), RusotoError<DescribeHsmError>> {
+        let request = self.build_describe_hsm_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<DescribeHapgResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DescribeHsmResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeHapgError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DescribeHsmError::from_response(response))
         }
     }
 
-    /// <p>This is documentation for <b>AWS CloudHSM Classic</b>. For more information, see <a href="http://aws.amazon.com/cloudhsm/faqs-classic/">AWS CloudHSM Classic FAQs</a>, the <a href="http://docs.aws.amazon.com/cloudhsm/classic/userguide/">AWS CloudHSM Classic User Guide</a>, and the <a href="http://docs.aws.amazon.com/cloudhsm/classic/APIReference/">AWS CloudHSM Classic API Reference</a>.</p> <p> <b>For information about the current version of AWS CloudHSM</b>, see <a href="http://aws.amazon.com/cloudhsm/">AWS CloudHSM</a>, the <a href="http://docs.aws.amazon.com/cloudhsm/latest/userguide/">AWS CloudHSM User Guide</a>, and the <a href="http://docs.aws.amazon.com/cloudhsm/latest/APIReference/">AWS CloudHSM API Reference</a>.</p> <p>Retrieves information about an HSM. You can identify the HSM by its ARN or its serial number.</p>
-    async fn describe_hsm(
+    /// Presigns the request `describe_hsm` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_hsm(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeHsmRequest,
-    ) -> Result<DescribeHsmResponse, RusotoError<DescribeHsmError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_hsm_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_hsm_request(&self, input: DescribeHsmRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "cloudhsm", &self.region, "/");
+        request.set_operation("DescribeHsm");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "CloudHsmFrontendService.DescribeHsm");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `describe_luna_client`, also returning the metadata of the response.
+    pub async fn describe_luna_client_with_metadata(
+        &self,
+        input: DescribeLunaClientRequest,
+    ) -> Result<(DescribeLunaClientResponse, ResponseMetadata), RusotoError<DescribeLunaClientError>>
+    {
+        let request = self.build_describe_luna_client_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<DescribeHsmResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DescribeLunaClientResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeHsmError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DescribeLunaClientError::from_response(response))
         }
     }
 
-    /// <p>This is documentation for <b>AWS CloudHSM Classic</b>. For more information, see <a href="http://aws.amazon.com/cloudhsm/faqs-classic/">AWS CloudHSM Classic FAQs</a>, the <a href="http://docs.aws.amazon.com/cloudhsm/classic/userguide/">AWS CloudHSM Classic User Guide</a>, and the <a href="http://docs.aws.amazon.com/cloudhsm/classic/APIReference/">AWS CloudHSM Classic API Reference</a>.</p> <p> <b>For information about the current version of AWS CloudHSM</b>, see <a href="http://aws.amazon.com/cloudhsm/">AWS CloudHSM</a>, the <a href="http://docs.aws.amazon.com/cloudhsm/latest/userguide/">AWS CloudHSM User Guide</a>, and the <a href="http://docs.aws.amazon.com/cloudhsm/latest/APIReference/">AWS CloudHSM API Reference</a>.</p> <p>Retrieves information about an HSM client.</p>
-    async fn describe_luna_client(
+    /// Presigns the request `describe_luna_client` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_luna_client(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeLunaClientRequest,
-    ) -> Result<DescribeLunaClientResponse, RusotoError<DescribeLunaClientError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_luna_client_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_luna_client_request(
+        &self,
+        input: DescribeLunaClientRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "cloudhsm", &self.region, "/");
+        request.set_operation("DescribeLunaClient");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "CloudHsmFrontendService.DescribeLunaClient");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `get_config`, also returning the metadata of the response.
+    pub async fn get_config_with_metadata(
+        &self,
+        input: GetConfigRequest,
+    ) -> Result<(GetConfigResponse, ResponseMetadata), RusotoError<GetConfigError>> {
+        let request = self.build_get_config_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DescribeLunaClientResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<GetConfigResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeLunaClientError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(GetConfigError::from_response(response))
         }
     }
 
-    /// <p>This is documentation for <b>AWS CloudHSM Classic</b>. For more information, see <a href="http://aws.amazon.com/cloudhsm/faqs-classic/">AWS CloudHSM Classic FAQs</a>, the <a href="http://docs.aws.amazon.com/cloudhsm/classic/userguide/">AWS CloudHSM Classic User Guide</a>, and the <a href="http://docs.aws.amazon.com/cloudhsm/classic/APIReference/">AWS CloudHSM Classic API Reference</a>.</p> <p> <b>For information about the current version of AWS CloudHSM</b>, see <a href="http://aws.amazon.com/cloudhsm/">AWS CloudHSM</a>, the <a href="http://docs.aws.amazon.com/cloudhsm/latest/userguide/">AWS CloudHSM User Guide</a>, and the <a href="http://docs.aws.amazon.com/cloudhsm/latest/APIReference/">AWS CloudHSM API Reference</a>.</p> <p>Gets the configuration files necessary to connect to all high availability partition groups the client is associated with.</p>
-    async fn get_config(
+    /// Presigns the request `get_config` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_config(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetConfigRequest,
-    ) -> Result<GetConfigResponse, RusotoError<GetConfigError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_config_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_config_request(&self, input: GetConfigRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "cloudhsm", &self.region, "/");
+        request.set_operation("GetConfig");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "CloudHsmFrontendService.GetConfig");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `list_available_zones`, also returning the metadata of the response.
+    pub async fn list_available_zones_with_metadata(
+        &self,
+    ) -> Result<(ListAvailableZonesResponse, ResponseMetadata), RusotoError<ListAvailableZonesError>>
+    {
+        let request = self.build_list_available_zones_request();
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<GetConfigResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ListAvailableZonesResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(GetConfigError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ListAvailableZonesError::from_response(response))
         }
     }
 
-    /// <p>This is documentation for <b>AWS CloudHSM Classic</b>. For more information, see <a href="http://aws.amazon.com/cloudhsm/faqs-classic/">AWS CloudHSM Classic FAQs</a>, the <a href="http://docs.aws.amazon.com/cloudhsm/classic/userguide/">AWS CloudHSM Classic User Guide</a>, and the <a href="http://docs.aws.amazon.com/cloudhsm/classic/APIReference/">AWS CloudHSM Classic API Reference</a>.</p> <p> <b>For information about the current version of AWS CloudHSM</b>, see <a href="http://aws.amazon.com/cloudhsm/">AWS CloudHSM</a>, the <a href="http://docs.aws.amazon.com/cloudhsm/latest/userguide/">AWS CloudHSM User Guide</a>, and the <a href="http://docs.aws.amazon.com/cloudhsm/latest/APIReference/">AWS CloudHSM API Reference</a>.</p> <p>Lists the Availability Zones that have available AWS CloudHSM capacity.</p>
-    async fn list_available_zones(
+    /// Presigns the request `list_available_zones` would send with `presigner` instead of sending it.
+    pub async fn presign_list_available_zones(
         &self,
-    ) -> Result<ListAvailableZonesResponse, RusotoError<ListAvailableZonesError>> {
+        presigner: &rusoto_core::Presigner,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_available_zones_request();
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_available_zones_request(&self) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "cloudhsm", &self.region, "/");
+        request.set_operation("ListAvailableZones");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "CloudHsmFrontendService.ListAvailableZones");
         request.set_payload(Some(bytes::Bytes::from_static(b"{}")));
 
+        request
+    }
+
+    /// Calls `list_hapgs`, also returning the metadata of the response.
+    pub async fn list_hapgs_with_metadata(
+        &self,
+        input: ListHapgsRequest,
+    ) -> Result<(ListHapgsResponse, ResponseMetadata), RusotoError<ListHapgsError>> {
+        let request = self.build_list_hapgs_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListAvailableZonesResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ListHapgsResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ListAvailableZonesError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ListHapgsError::from_response(response))
         }
     }
 
-    /// <p>This is documentation for <b>AWS CloudHSM Classic</b>. For more information, see <a href="http://aws.amazon.com/cloudhsm/faqs-classic/">AWS CloudHSM Classic FAQs</a>, the <a href="http://docs.aws.amazon.com/cloudhsm/classic/userguide/">AWS CloudHSM Classic User Guide</a>, and the <a href="http://docs.aws.amazon.com/cloudhsm/classic/APIReference/">AWS CloudHSM Classic API Reference</a>.</p> <p> <b>For information about the current version of AWS CloudHSM</b>, see <a href="http://aws.amazon.com/cloudhsm/">AWS CloudHSM</a>, the <a href="http://docs.aws.amazon.com/cloudhsm/latest/userguide/">AWS CloudHSM User Guide</a>, and the <a href="http://docs.aws.amazon.com/cloudhsm/latest/APIReference/">AWS CloudHSM API Reference</a>.</p> <p>Lists the high-availability partition groups for the account.</p> <p>This operation supports pagination with the use of the <code>NextToken</code> member. If more results are available, the <code>NextToken</code> member of the response contains a token that you pass in the next call to <code>ListHapgs</code> to retrieve the next set of items.</p>
-    async fn list_hapgs(
+    /// Presigns the request `list_hapgs` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_hapgs(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListHapgsRequest,
-    ) -> Result<ListHapgsResponse, RusotoError<ListHapgsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_hapgs_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_hapgs_request(&self, input: ListHapgsRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "cloudhsm", &self.region, "/");
+        request.set_operation("ListHapgs");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "CloudHsmFrontendService.ListHapgs");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `list_hsms`, also returning the metadata of the response.
+    pub async fn list_hsms_with_metadata(
+        &self,
+        input: ListHsmsRequest,
+    ) -> Result<(ListHsmsResponse, ResponseMetadata), RusotoError<ListHsmsError>> {
+        let request = self.build_list_hsms_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<ListHapgsResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ListHsmsResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ListHapgsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ListHsmsError::from_response(response))
         }
     }
 
-    /// <p>This is documentation for <b>AWS CloudHSM Classic</b>. For more information, see <a href="http://aws.amazon.com/cloudhsm/faqs-classic/">AWS CloudHSM Classic FAQs</a>, the <a href="http://docs.aws.amazon.com/cloudhsm/classic/userguide/">AWS CloudHSM Classic User Guide</a>, and the <a href="http://docs.aws.amazon.com/cloudhsm/classic/APIReference/">AWS CloudHSM Classic API Reference</a>.</p> <p> <b>For information about the current version of AWS CloudHSM</b>, see <a href="http://aws.amazon.com/cloudhsm/">AWS CloudHSM</a>, the <a href="http://docs.aws.amazon.com/cloudhsm/latest/userguide/">AWS CloudHSM User Guide</a>, and the <a href="http://docs.aws.amazon.com/cloudhsm/latest/APIReference/">AWS CloudHSM API Reference</a>.</p> <p>Retrieves the identifiers of all of the HSMs provisioned for the current customer.</p> <p>This operation supports pagination with the use of the <code>NextToken</code> member. If more results are available, the <code>NextToken</code> member of the response contains a token that you pass in the next call to <code>ListHsms</code> to retrieve the next set of items.</p>
-    async fn list_hsms(
+    /// Presigns the request `list_hsms` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_hsms(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListHsmsRequest,
-    ) -> Result<ListHsmsResponse, RusotoError<ListHsmsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_hsms_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_hsms_request(&self, input: ListHsmsRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "cloudhsm", &self.region, "/");
+        request.set_operation("ListHsms");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "CloudHsmFrontendService.ListHsms");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `list_luna_clients`, also returning the metadata of the response.
+    pub async fn list_luna_clients_with_metadata(
+        &self,
+        input: ListLunaClientsRequest,
+    ) -> Result<(ListLunaClientsResponse, ResponseMetadata), RusotoError<ListLunaClientsError>>
+    {
+        let request = self.build_list_luna_clients_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<ListHsmsResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ListLunaClientsResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ListHsmsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ListLunaClientsError::from_response(response))
         }
     }
 
-    /// <p>This is documentation for <b>AWS CloudHSM Classic</b>. For more information, see <a href="http://aws.amazon.com/cloudhsm/faqs-classic/">AWS CloudHSM Classic FAQs</a>, the <a href="http://docs.aws.amazon.com/cloudhsm/classic/userguide/">AWS CloudHSM Classic User Guide</a>, and the <a href="http://docs.aws.amazon.com/cloudhsm/classic/APIReference/">AWS CloudHSM Classic API Reference</a>.</p> <p> <b>For information about the current version of AWS CloudHSM</b>, see <a href="http://aws.amazon.com/cloudhsm/">AWS CloudHSM</a>, the <a href="http://docs.aws.amazon.com/cloudhsm/latest/userguide/">AWS CloudHSM User Guide</a>, and the <a href="http://docs.aws.amazon.com/cloudhsm/latest/APIReference/">AWS CloudHSM API Reference</a>.</p> <p>Lists all of the clients.</p> <p>This operation supports pagination with the use of the <code>NextToken</code> member. If more results are available, the <code>NextToken</code> member of the response contains a token that you pass in the next call to <code>ListLunaClients</code> to retrieve the next set of items.</p>
-    async fn list_luna_clients(
+    /// Presigns the request `list_luna_clients` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_luna_clients(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListLunaClientsRequest,
-    ) -> Result<ListLunaClientsResponse, RusotoError<ListLunaClientsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_luna_clients_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_luna_clients_request(&self, input: ListLunaClientsRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "cloudhsm", &self.region, "/");
+        request.set_operation("ListLunaClients");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "CloudHsmFrontendService.ListLunaClients");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `list_tags_for_resource`, also returning the metadata of the response.
+    pub async fn list_tags_for_resource_with_metadata(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> Result<
+        (ListTagsForResourceResponse, ResponseMetadata),
+        RusotoError<ListTagsForResourceError>,
+    > {
+        let request = self.build_list_tags_for_resource_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<ListLunaClientsResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ListTagsForResourceResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ListLunaClientsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ListTagsForResourceError::from_response(response))
         }
     }
 
-    /// <p>This is documentation for <b>AWS CloudHSM Classic</b>. For more information, see <a href="http://aws.amazon.com/cloudhsm/faqs-classic/">AWS CloudHSM Classic FAQs</a>, the <a href="http://docs.aws.amazon.com/cloudhsm/classic/userguide/">AWS CloudHSM Classic User Guide</a>, and the <a href="http://docs.aws.amazon.com/cloudhsm/classic/APIReference/">AWS CloudHSM Classic API Reference</a>.</p> <p> <b>For information about the current version of AWS CloudHSM</b>, see <a href="http://aws.amazon.com/cloudhsm/">AWS CloudHSM</a>, the <a href="http://docs.aws.amazon.com/cloudhsm/latest/userguide/">AWS CloudHSM User Guide</a>, and the <a href="http://docs.aws.amazon.com/cloudhsm/latest/APIReference/">AWS CloudHSM API Reference</a>.</p> <p>Returns a list of all tags for the specified AWS CloudHSM resource.</p>
-    async fn list_tags_for_resource(
+    /// Presigns the request `list_tags_for_resource` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_tags_for_resource(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListTagsForResourceRequest,
-    ) -> Result<ListTagsForResourceResponse, RusotoError<ListTagsForResourceError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_tags_for_resource_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_tags_for_resource_request(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "cloudhsm", &self.region, "/");
+        request.set_operation("ListTagsForResource");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -2033,110 +2616,215 @@ impl CloudHsm for CloudHsmClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `modify_hapg`, also returning the metadata of the response.
+    pub async fn modify_hapg_with_metadata(
+        &self,
+        input: ModifyHapgRequest,
+    ) -> Result<(ModifyHapgResponse, ResponseMetadata), RusotoError<ModifyHapgError>> {
+        let request = self.build_modify_hapg_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListTagsForResourceResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ModifyHapgResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ListTagsForResourceError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ModifyHapgError::from_response(response))
         }
     }
 
-    /// <p>This is documentation for <b>AWS CloudHSM Classic</b>. For more information, see <a href="http://aws.amazon.com/cloudhsm/faqs-classic/">AWS CloudHSM Classic FAQs</a>, the <a href="http://docs.aws.amazon.com/cloudhsm/classic/userguide/">AWS CloudHSM Classic User Guide</a>, and the <a href="http://docs.aws.amazon.com/cloudhsm/classic/APIReference/">AWS CloudHSM Classic API Reference</a>.</p> <p> <b>For information about the current version of AWS CloudHSM</b>, see <a href="http://aws.amazon.com/cloudhsm/">AWS CloudHSM</a>, the <a href="http://docs.aws.amazon.com/cloudhsm/latest/userguide/">AWS CloudHSM User Guide</a>, and the <a href="http://docs.aws.amazon.com/cloudhsm/latest/APIReference/">AWS CloudHSM API Reference</a>.</p> <p>Modifies an existing high-availability partition group.</p>
-    async fn modify_hapg(
+    /// Presigns the request `modify_hapg` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_modify_hapg(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ModifyHapgRequest,
-    ) -> Result<ModifyHapgResponse, RusotoError<ModifyHapgError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_modify_hapg_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_modify_hapg_request(&self, input: ModifyHapgRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "cloudhsm", &self.region, "/");
+        request.set_operation("ModifyHapg");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "CloudHsmFrontendService.ModifyHapg");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `modify_hsm`, also returning the metadata of the response.
+    pub async fn modify_hsm_with_metadata(
+        &self,
+        input: ModifyHsmRequest,
+    ) -> Result<(ModifyHsmResponse, ResponseMetadata), RusotoError<ModifyHsmError>> {
+        let request = self.build_modify_hsm_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<ModifyHapgResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ModifyHsmResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ModifyHapgError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ModifyHsmError::from_response(response))
         }
     }
 
-    /// <p><p>This is documentation for <b>AWS CloudHSM Classic</b>. For more information, see <a href="http://aws.amazon.com/cloudhsm/faqs-classic/">AWS CloudHSM Classic FAQs</a>, the <a href="http://docs.aws.amazon.com/cloudhsm/classic/userguide/">AWS CloudHSM Classic User Guide</a>, and the <a href="http://docs.aws.amazon.com/cloudhsm/classic/APIReference/">AWS CloudHSM Classic API Reference</a>.</p> <p> <b>For information about the current version of AWS CloudHSM</b>, see <a href="http://aws.amazon.com/cloudhsm/">AWS CloudHSM</a>, the <a href="http://docs.aws.amazon.com/cloudhsm/latest/userguide/">AWS CloudHSM User Guide</a>, and the <a href="http://docs.aws.amazon.com/cloudhsm/latest/APIReference/">AWS CloudHSM API Reference</a>.</p> <p>Modifies an HSM.</p> <important> <p>This operation can result in the HSM being offline for up to 15 minutes while the AWS CloudHSM service is reconfigured. If you are modifying a production HSM, you should ensure that your AWS CloudHSM service is configured for high availability, and consider executing this operation during a maintenance window.</p> </important></p>
-    async fn modify_hsm(
+    /// Presigns the request `modify_hsm` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_modify_hsm(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ModifyHsmRequest,
-    ) -> Result<ModifyHsmResponse, RusotoError<ModifyHsmError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_modify_hsm_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_modify_hsm_request(&self, input: ModifyHsmRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "cloudhsm", &self.region, "/");
+        request.set_operation("ModifyHsm");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "CloudHsmFrontendService.ModifyHsm");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `modify_luna_client`, also returning the metadata of the response.
+    pub async fn modify_luna_client_with_metadata(
+        &self,
+        input: ModifyLunaClientRequest,
+    ) -> Result<(ModifyLunaClientResponse, ResponseMetadata), RusotoError<ModifyLunaClientError>>
+    {
+        let request = self.build_modify_luna_client_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<ModifyHsmResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ModifyLunaClientResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ModifyHsmError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ModifyLunaClientError::from_response(response))
         }
     }
 
-    /// <p>This is documentation for <b>AWS CloudHSM Classic</b>. For more information, see <a href="http://aws.amazon.com/cloudhsm/faqs-classic/">AWS CloudHSM Classic FAQs</a>, the <a href="http://docs.aws.amazon.com/cloudhsm/classic/userguide/">AWS CloudHSM Classic User Guide</a>, and the <a href="http://docs.aws.amazon.com/cloudhsm/classic/APIReference/">AWS CloudHSM Classic API Reference</a>.</p> <p> <b>For information about the current version of AWS CloudHSM</b>, see <a href="http://aws.amazon.com/cloudhsm/">AWS CloudHSM</a>, the <a href="http://docs.aws.amazon.com/cloudhsm/latest/userguide/">AWS CloudHSM User Guide</a>, and the <a href="http://docs.aws.amazon.com/cloudhsm/latest/APIReference/">AWS CloudHSM API Reference</a>.</p> <p>Modifies the certificate used by the client.</p> <p>This action can potentially start a workflow to install the new certificate on the client's HSMs.</p>
-    async fn modify_luna_client(
+    /// Presigns the request `modify_luna_client` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_modify_luna_client(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ModifyLunaClientRequest,
-    ) -> Result<ModifyLunaClientResponse, RusotoError<ModifyLunaClientError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_modify_luna_client_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_modify_luna_client_request(&self, input: ModifyLunaClientRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "cloudhsm", &self.region, "/");
+        request.set_operation("ModifyLunaClient");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "CloudHsmFrontendService.ModifyLunaClient");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `remove_tags_from_resource`, also returning the metadata of the response.
+    pub async fn remove_tags_from_resource_with_metadata(
+        &self,
+        input: RemoveTagsFromResourceRequest,
+    ) -> Result<
+        (RemoveTagsFromResourceResponse, ResponseMetadata),
+        RusotoError<RemoveTagsFromResourceError>,
+    > {
+        let request = self.build_remove_tags_from_resource_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<ModifyLunaClientResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<RemoveTagsFromResourceResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ModifyLunaClientError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(RemoveTagsFromResourceError::from_response(response))
         }
     }
 
-    /// <p>This is documentation for <b>AWS CloudHSM Classic</b>. For more information, see <a href="http://aws.amazon.com/cloudhsm/faqs-classic/">AWS CloudHSM Classic FAQs</a>, the <a href="http://docs.aws.amazon.com/cloudhsm/classic/userguide/">AWS CloudHSM Classic User Guide</a>, and the <a href="http://docs.aws.amazon.com/cloudhsm/classic/APIReference/">AWS CloudHSM Classic API Reference</a>.</p> <p> <b>For information about the current version of AWS CloudHSM</b>, see <a href="http://aws.amazon.com/cloudhsm/">AWS CloudHSM</a>, the <a href="http://docs.aws.amazon.com/cloudhsm/latest/userguide/">AWS CloudHSM User Guide</a>, and the <a href="http://docs.aws.amazon.com/cloudhsm/latest/APIReference/">AWS CloudHSM API Reference</a>.</p> <p>Removes one or more tags from the specified AWS CloudHSM resource.</p> <p>To remove a tag, specify only the tag key to remove (not the value). To overwrite the value for an existing tag, use <a>AddTagsToResource</a>.</p>
-    async fn remove_tags_from_resource(
+    /// Presigns the request `remove_tags_from_resource` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_remove_tags_from_resource(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: RemoveTagsFromResourceRequest,
-    ) -> Result<RemoveTagsFromResourceResponse, RusotoError<RemoveTagsFromResourceError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_remove_tags_from_resource_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_remove_tags_from_resource_request(
+        &self,
+        input: RemoveTagsFromResourceRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "cloudhsm", &self.region, "/");
+        request.set_operation("RemoveTagsFromResource");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -2146,19 +2834,307 @@ impl CloudHsm for CloudHsmClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
-        let mut response = self
-            .client
-            .sign_and_dispatch(request)
+        request
+    }
+
+    /// The pages of `ListHapgs` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    pub fn list_hapgs_pages(
+        &self,
+        input: ListHapgsRequest,
+    ) -> impl futures::Stream<Item = Result<ListHapgsResponse, RusotoError<ListHapgsError>>> + Send
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: CloudHsmClient, input| async move { client.list_hapgs(input).await },
+            |input: &ListHapgsRequest, output: &ListHapgsResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListHapgsRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `HapgList` of every page of `ListHapgs` results for `input`, as `list_hapgs_pages` requests them.
+    pub fn list_hapgs_stream(
+        &self,
+        input: ListHapgsRequest,
+    ) -> impl futures::Stream<Item = Result<String, RusotoError<ListHapgsError>>> + Send {
+        rusoto_core::pagination::items(self.list_hapgs_pages(input), |page| page.hapg_list)
+    }
+
+    /// The pages of `ListHsms` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    pub fn list_hsms_pages(
+        &self,
+        input: ListHsmsRequest,
+    ) -> impl futures::Stream<Item = Result<ListHsmsResponse, RusotoError<ListHsmsError>>> + Send
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: CloudHsmClient, input| async move { client.list_hsms(input).await },
+            |input: &ListHsmsRequest, output: &ListHsmsResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListHsmsRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `HsmList` of every page of `ListHsms` results for `input`, as `list_hsms_pages` requests them.
+    pub fn list_hsms_stream(
+        &self,
+        input: ListHsmsRequest,
+    ) -> impl futures::Stream<Item = Result<String, RusotoError<ListHsmsError>>> + Send {
+        rusoto_core::pagination::items(self.list_hsms_pages(input), |page| {
+            page.hsm_list.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `ListLunaClients` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    pub fn list_luna_clients_pages(
+        &self,
+        input: ListLunaClientsRequest,
+    ) -> impl futures::Stream<
+        Item = Result<ListLunaClientsResponse, RusotoError<ListLunaClientsError>>,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: CloudHsmClient, input| async move { client.list_luna_clients(input).await },
+            |input: &ListLunaClientsRequest, output: &ListLunaClientsResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListLunaClientsRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `ClientList` of every page of `ListLunaClients` results for `input`, as `list_luna_clients_pages` requests them.
+    pub fn list_luna_clients_stream(
+        &self,
+        input: ListLunaClientsRequest,
+    ) -> impl futures::Stream<Item = Result<String, RusotoError<ListLunaClientsError>>> + Send {
+        rusoto_core::pagination::items(self.list_luna_clients_pages(input), |page| page.client_list)
+    }
+}
+
+#[async_trait]
+impl CloudHsm for CloudHsmClient {
+    /// <p>This is documentation for <b>AWS CloudHSM Classic</b>. For more information, see <a href="http://aws.amazon.com/cloudhsm/faqs-classic/">AWS CloudHSM Classic FAQs</a>, the <a href="http://docs.aws.amazon.com/cloudhsm/classic/userguide/">AWS CloudHSM Classic User Guide</a>, and the <a href="http://docs.aws.amazon.com/cloudhsm/classic/APIReference/">AWS CloudHSM Classic API Reference</a>.</p> <p> <b>For information about the current version of AWS CloudHSM</b>, see <a href="http://aws.amazon.com/cloudhsm/">AWS CloudHSM</a>, the <a href="http://docs.aws.amazon.com/cloudhsm/latest/userguide/">AWS CloudHSM User Guide</a>, and the <a href="http://docs.aws.amazon.com/cloudhsm/latest/APIReference/">AWS CloudHSM API Reference</a>.</p> <p>Adds or overwrites one or more tags for the specified AWS CloudHSM resource.</p> <p>Each tag consists of a key and a value. Tag keys must be unique to each resource.</p>
+    async fn add_tags_to_resource(
+        &self,
+        input: AddTagsToResourceRequest,
+    ) -> Result<AddTagsToResourceResponse, RusotoError<AddTagsToResourceError>> {
+        self.add_tags_to_resource_with_metadata(input)
             .await
-            .map_err(RusotoError::from)?;
-        if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<RemoveTagsFromResourceResponse, _>()
-        } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(RemoveTagsFromResourceError::from_response(response))
-        }
+            .map(|(output, _)| output)
+    }
+
+    /// <p>This is documentation for <b>AWS CloudHSM Classic</b>. For more information, see <a href="http://aws.amazon.com/cloudhsm/faqs-classic/">AWS CloudHSM Classic FAQs</a>, the <a href="http://docs.aws.amazon.com/cloudhsm/classic/userguide/">AWS CloudHSM Classic User Guide</a>, and the <a href="http://docs.aws.amazon.com/cloudhsm/classic/APIReference/">AWS CloudHSM Classic API Reference</a>.</p> <p> <b>For information about the current version of AWS CloudHSM</b>, see <a href="http://aws.amazon.com/cloudhsm/">AWS CloudHSM</a>, the <a href="http://docs.aws.amazon.com/cloudhsm/latest/userguide/">AWS CloudHSM User Guide</a>, and the <a href="http://docs.aws.amazon.com/cloudhsm/latest/APIReference/">AWS CloudHSM API Reference</a>.</p> <p>Creates a high-availability partition group. A high-availability partition group is a group of partitions that spans multiple physical HSMs.</p>
+    async fn create_hapg(
+        &self,
+        input: CreateHapgRequest,
+    ) -> Result<CreateHapgResponse, RusotoError<CreateHapgError>> {
+        self.create_hapg_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>This is documentation for <b>AWS CloudHSM Classic</b>. For more information, see <a href="http://aws.amazon.com/cloudhsm/faqs-classic/">AWS CloudHSM Classic FAQs</a>, the <a href="http://docs.aws.amazon.com/cloudhsm/classic/userguide/">AWS CloudHSM Classic User Guide</a>, and the <a href="http://docs.aws.amazon.com/cloudhsm/classic/APIReference/">AWS CloudHSM Classic API Reference</a>.</p> <p> <b>For information about the current version of AWS CloudHSM</b>, see <a href="http://aws.amazon.com/cloudhsm/">AWS CloudHSM</a>, the <a href="http://docs.aws.amazon.com/cloudhsm/latest/userguide/">AWS CloudHSM User Guide</a>, and the <a href="http://docs.aws.amazon.com/cloudhsm/latest/APIReference/">AWS CloudHSM API Reference</a>.</p> <p>Creates an uninitialized HSM instance.</p> <p>There is an upfront fee charged for each HSM instance that you create with the <code>CreateHsm</code> operation. If you accidentally provision an HSM and want to request a refund, delete the instance using the <a>DeleteHsm</a> operation, go to the <a href="https://console.aws.amazon.com/support/home">AWS Support Center</a>, create a new case, and select <b>Account and Billing Support</b>.</p> <important> <p>It can take up to 20 minutes to create and provision an HSM. You can monitor the status of the HSM with the <a>DescribeHsm</a> operation. The HSM is ready to be initialized when the status changes to <code>RUNNING</code>.</p> </important></p>
+    async fn create_hsm(
+        &self,
+        input: CreateHsmRequest,
+    ) -> Result<CreateHsmResponse, RusotoError<CreateHsmError>> {
+        self.create_hsm_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>This is documentation for <b>AWS CloudHSM Classic</b>. For more information, see <a href="http://aws.amazon.com/cloudhsm/faqs-classic/">AWS CloudHSM Classic FAQs</a>, the <a href="http://docs.aws.amazon.com/cloudhsm/classic/userguide/">AWS CloudHSM Classic User Guide</a>, and the <a href="http://docs.aws.amazon.com/cloudhsm/classic/APIReference/">AWS CloudHSM Classic API Reference</a>.</p> <p> <b>For information about the current version of AWS CloudHSM</b>, see <a href="http://aws.amazon.com/cloudhsm/">AWS CloudHSM</a>, the <a href="http://docs.aws.amazon.com/cloudhsm/latest/userguide/">AWS CloudHSM User Guide</a>, and the <a href="http://docs.aws.amazon.com/cloudhsm/latest/APIReference/">AWS CloudHSM API Reference</a>.</p> <p>Creates an HSM client.</p>
+    async fn create_luna_client(
+        &self,
+        input: CreateLunaClientRequest,
+    ) -> Result<CreateLunaClientResponse, RusotoError<CreateLunaClientError>> {
+        self.create_luna_client_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>This is documentation for <b>AWS CloudHSM Classic</b>. For more information, see <a href="http://aws.amazon.com/cloudhsm/faqs-classic/">AWS CloudHSM Classic FAQs</a>, the <a href="http://docs.aws.amazon.com/cloudhsm/classic/userguide/">AWS CloudHSM Classic User Guide</a>, and the <a href="http://docs.aws.amazon.com/cloudhsm/classic/APIReference/">AWS CloudHSM Classic API Reference</a>.</p> <p> <b>For information about the current version of AWS CloudHSM</b>, see <a href="http://aws.amazon.com/cloudhsm/">AWS CloudHSM</a>, the <a href="http://docs.aws.amazon.com/cloudhsm/latest/userguide/">AWS CloudHSM User Guide</a>, and the <a href="http://docs.aws.amazon.com/cloudhsm/latest/APIReference/">AWS CloudHSM API Reference</a>.</p> <p>Deletes a high-availability partition group.</p>
+    async fn delete_hapg(
+        &self,
+        input: DeleteHapgRequest,
+    ) -> Result<DeleteHapgResponse, RusotoError<DeleteHapgError>> {
+        self.delete_hapg_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>This is documentation for <b>AWS CloudHSM Classic</b>. For more information, see <a href="http://aws.amazon.com/cloudhsm/faqs-classic/">AWS CloudHSM Classic FAQs</a>, the <a href="http://docs.aws.amazon.com/cloudhsm/classic/userguide/">AWS CloudHSM Classic User Guide</a>, and the <a href="http://docs.aws.amazon.com/cloudhsm/classic/APIReference/">AWS CloudHSM Classic API Reference</a>.</p> <p> <b>For information about the current version of AWS CloudHSM</b>, see <a href="http://aws.amazon.com/cloudhsm/">AWS CloudHSM</a>, the <a href="http://docs.aws.amazon.com/cloudhsm/latest/userguide/">AWS CloudHSM User Guide</a>, and the <a href="http://docs.aws.amazon.com/cloudhsm/latest/APIReference/">AWS CloudHSM API Reference</a>.</p> <p>Deletes an HSM. After completion, this operation cannot be undone and your key material cannot be recovered.</p>
+    async fn delete_hsm(
+        &self,
+        input: DeleteHsmRequest,
+    ) -> Result<DeleteHsmResponse, RusotoError<DeleteHsmError>> {
+        self.delete_hsm_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>This is documentation for <b>AWS CloudHSM Classic</b>. For more information, see <a href="http://aws.amazon.com/cloudhsm/faqs-classic/">AWS CloudHSM Classic FAQs</a>, the <a href="http://docs.aws.amazon.com/cloudhsm/classic/userguide/">AWS CloudHSM Classic User Guide</a>, and the <a href="http://docs.aws.amazon.com/cloudhsm/classic/APIReference/">AWS CloudHSM Classic API Reference</a>.</p> <p> <b>For information about the current version of AWS CloudHSM</b>, see <a href="http://aws.amazon.com/cloudhsm/">AWS CloudHSM</a>, the <a href="http://docs.aws.amazon.com/cloudhsm/latest/userguide/">AWS CloudHSM User Guide</a>, and the <a href="http://docs.aws.amazon.com/cloudhsm/latest/APIReference/">AWS CloudHSM API Reference</a>.</p> <p>Deletes a client.</p>
+    async fn delete_luna_client(
+        &self,
+        input: DeleteLunaClientRequest,
+    ) -> Result<DeleteLunaClientResponse, RusotoError<DeleteLunaClientError>> {
+        self.delete_luna_client_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>This is documentation for <b>AWS CloudHSM Classic</b>. For more information, see <a href="http://aws.amazon.com/cloudhsm/faqs-classic/">AWS CloudHSM Classic FAQs</a>, the <a href="http://docs.aws.amazon.com/cloudhsm/classic/userguide/">AWS CloudHSM Classic User Guide</a>, and the <a href="http://docs.aws.amazon.com/cloudhsm/classic/APIReference/">AWS CloudHSM Classic API Reference</a>.</p> <p> <b>For information about the current version of AWS CloudHSM</b>, see <a href="http://aws.amazon.com/cloudhsm/">AWS CloudHSM</a>, the <a href="http://docs.aws.amazon.com/cloudhsm/latest/userguide/">AWS CloudHSM User Guide</a>, and the <a href="http://docs.aws.amazon.com/cloudhsm/latest/APIReference/">AWS CloudHSM API Reference</a>.</p> <p>Retrieves information about a high-availability partition group.</p>
+    async fn describe_hapg(
+        &self,
+        input: DescribeHapgRequest,
+    ) -> Result<DescribeHapgResponse, RusotoError<DescribeHapgError>> {
+        self.describe_hapg_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>This is documentation for <b>AWS CloudHSM Classic</b>. For more information, see <a href="http://aws.amazon.com/cloudhsm/faqs-classic/">AWS CloudHSM Classic FAQs</a>, the <a href="http://docs.aws.amazon.com/cloudhsm/classic/userguide/">AWS CloudHSM Classic User Guide</a>, and the <a href="http://docs.aws.amazon.com/cloudhsm/classic/APIReference/">AWS CloudHSM Classic API Reference</a>.</p> <p> <b>For information about the current version of AWS CloudHSM</b>, see <a href="http://aws.amazon.com/cloudhsm/">AWS CloudHSM</a>, the <a href="http://docs.aws.amazon.com/cloudhsm/latest/userguide/">AWS CloudHSM User Guide</a>, and the <a href="http://docs.aws.amazon.com/cloudhsm/latest/APIReference/">AWS CloudHSM API Reference</a>.</p> <p>Retrieves information about an HSM. You can identify the HSM by its ARN or its serial number.</p>
+    async fn describe_hsm(
+        &self,
+        input: DescribeHsmRequest,
+    ) -> Result<DescribeHsmResponse, RusotoError<DescribeHsmError>> {
+        self.describe_hsm_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>This is documentation for <b>AWS CloudHSM Classic</b>. For more information, see <a href="http://aws.amazon.com/cloudhsm/faqs-classic/">AWS CloudHSM Classic FAQs</a>, the <a href="http://docs.aws.amazon.com/cloudhsm/classic/userguide/">AWS CloudHSM Classic User Guide</a>, and the <a href="http://docs.aws.amazon.com/cloudhsm/classic/APIReference/">AWS CloudHSM Classic API Reference</a>.</p> <p> <b>For information about the current version of AWS CloudHSM</b>, see <a href="http://aws.amazon.com/cloudhsm/">AWS CloudHSM</a>, the <a href="http://docs.aws.amazon.com/cloudhsm/latest/userguide/">AWS CloudHSM User Guide</a>, and the <a href="http://docs.aws.amazon.com/cloudhsm/latest/APIReference/">AWS CloudHSM API Reference</a>.</p> <p>Retrieves information about an HSM client.</p>
+    async fn describe_luna_client(
+        &self,
+        input: DescribeLunaClientRequest,
+    ) -> Result<DescribeLunaClientResponse, RusotoError<DescribeLunaClientError>> {
+        self.describe_luna_client_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>This is documentation for <b>AWS CloudHSM Classic</b>. For more information, see <a href="http://aws.amazon.com/cloudhsm/faqs-classic/">AWS CloudHSM Classic FAQs</a>, the <a href="http://docs.aws.amazon.com/cloudhsm/classic/userguide/">AWS CloudHSM Classic User Guide</a>, and the <a href="http://docs.aws.amazon.com/cloudhsm/classic/APIReference/">AWS CloudHSM Classic API Reference</a>.</p> <p> <b>For information about the current version of AWS CloudHSM</b>, see <a href="http://aws.amazon.com/cloudhsm/">AWS CloudHSM</a>, the <a href="http://docs.aws.amazon.com/cloudhsm/latest/userguide/">AWS CloudHSM User Guide</a>, and the <a href="http://docs.aws.amazon.com/cloudhsm/latest/APIReference/">AWS CloudHSM API Reference</a>.</p> <p>Gets the configuration files necessary to connect to all high availability partition groups the client is associated with.</p>
+    async fn get_config(
+        &self,
+        input: GetConfigRequest,
+    ) -> Result<GetConfigResponse, RusotoError<GetConfigError>> {
+        self.get_config_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>This is documentation for <b>AWS CloudHSM Classic</b>. For more information, see <a href="http://aws.amazon.com/cloudhsm/faqs-classic/">AWS CloudHSM Classic FAQs</a>, the <a href="http://docs.aws.amazon.com/cloudhsm/classic/userguide/">AWS CloudHSM Classic User Guide</a>, and the <a href="http://docs.aws.amazon.com/cloudhsm/classic/APIReference/">AWS CloudHSM Classic API Reference</a>.</p> <p> <b>For information about the current version of AWS CloudHSM</b>, see <a href="http://aws.amazon.com/cloudhsm/">AWS CloudHSM</a>, the <a href="http://docs.aws.amazon.com/cloudhsm/latest/userguide/">AWS CloudHSM User Guide</a>, and the <a href="http://docs.aws.amazon.com/cloudhsm/latest/APIReference/">AWS CloudHSM API Reference</a>.</p> <p>Lists the Availability Zones that have available AWS CloudHSM capacity.</p>
+    async fn list_available_zones(
+        &self,
+    ) -> Result<ListAvailableZonesResponse, RusotoError<ListAvailableZonesError>> {
+        self.list_available_zones_with_metadata()
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>This is documentation for <b>AWS CloudHSM Classic</b>. For more information, see <a href="http://aws.amazon.com/cloudhsm/faqs-classic/">AWS CloudHSM Classic FAQs</a>, the <a href="http://docs.aws.amazon.com/cloudhsm/classic/userguide/">AWS CloudHSM Classic User Guide</a>, and the <a href="http://docs.aws.amazon.com/cloudhsm/classic/APIReference/">AWS CloudHSM Classic API Reference</a>.</p> <p> <b>For information about the current version of AWS CloudHSM</b>, see <a href="http://aws.amazon.com/cloudhsm/">AWS CloudHSM</a>, the <a href="http://docs.aws.amazon.com/cloudhsm/latest/userguide/">AWS CloudHSM User Guide</a>, and the <a href="http://docs.aws.amazon.com/cloudhsm/latest/APIReference/">AWS CloudHSM API Reference</a>.</p> <p>Lists the high-availability partition groups for the account.</p> <p>This operation supports pagination with the use of the <code>NextToken</code> member. If more results are available, the <code>NextToken</code> member of the response contains a token that you pass in the next call to <code>ListHapgs</code> to retrieve the next set of items.</p>
+    async fn list_hapgs(
+        &self,
+        input: ListHapgsRequest,
+    ) -> Result<ListHapgsResponse, RusotoError<ListHapgsError>> {
+        self.list_hapgs_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>This is documentation for <b>AWS CloudHSM Classic</b>. For more information, see <a href="http://aws.amazon.com/cloudhsm/faqs-classic/">AWS CloudHSM Classic FAQs</a>, the <a href="http://docs.aws.amazon.com/cloudhsm/classic/userguide/">AWS CloudHSM Classic User Guide</a>, and the <a href="http://docs.aws.amazon.com/cloudhsm/classic/APIReference/">AWS CloudHSM Classic API Reference</a>.</p> <p> <b>For information about the current version of AWS CloudHSM</b>, see <a href="http://aws.amazon.com/cloudhsm/">AWS CloudHSM</a>, the <a href="http://docs.aws.amazon.com/cloudhsm/latest/userguide/">AWS CloudHSM User Guide</a>, and the <a href="http://docs.aws.amazon.com/cloudhsm/latest/APIReference/">AWS CloudHSM API Reference</a>.</p> <p>Retrieves the identifiers of all of the HSMs provisioned for the current customer.</p> <p>This operation supports pagination with the use of the <code>NextToken</code> member. If more results are available, the <code>NextToken</code> member of the response contains a token that you pass in the next call to <code>ListHsms</code> to retrieve the next set of items.</p>
+    async fn list_hsms(
+        &self,
+        input: ListHsmsRequest,
+    ) -> Result<ListHsmsResponse, RusotoError<ListHsmsError>> {
+        self.list_hsms_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>This is documentation for <b>AWS CloudHSM Classic</b>. For more information, see <a href="http://aws.amazon.com/cloudhsm/faqs-classic/">AWS CloudHSM Classic FAQs</a>, the <a href="http://docs.aws.amazon.com/cloudhsm/classic/userguide/">AWS CloudHSM Classic User Guide</a>, and the <a href="http://docs.aws.amazon.com/cloudhsm/classic/APIReference/">AWS CloudHSM Classic API Reference</a>.</p> <p> <b>For information about the current version of AWS CloudHSM</b>, see <a href="http://aws.amazon.com/cloudhsm/">AWS CloudHSM</a>, the <a href="http://docs.aws.amazon.com/cloudhsm/latest/userguide/">AWS CloudHSM User Guide</a>, and the <a href="http://docs.aws.amazon.com/cloudhsm/latest/APIReference/">AWS CloudHSM API Reference</a>.</p> <p>Lists all of the clients.</p> <p>This operation supports pagination with the use of the <code>NextToken</code> member. If more results are available, the <code>NextToken</code> member of the response contains a token that you pass in the next call to <code>ListLunaClients</code> to retrieve the next set of items.</p>
+    async fn list_luna_clients(
+        &self,
+        input: ListLunaClientsRequest,
+    ) -> Result<ListLunaClientsResponse, RusotoError<ListLunaClientsError>> {
+        self.list_luna_clients_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>This is documentation for <b>AWS CloudHSM Classic</b>. For more information, see <a href="http://aws.amazon.com/cloudhsm/faqs-classic/">AWS CloudHSM Classic FAQs</a>, the <a href="http://docs.aws.amazon.com/cloudhsm/classic/userguide/">AWS CloudHSM Classic User Guide</a>, and the <a href="http://docs.aws.amazon.com/cloudhsm/classic/APIReference/">AWS CloudHSM Classic API Reference</a>.</p> <p> <b>For information about the current version of AWS CloudHSM</b>, see <a href="http://aws.amazon.com/cloudhsm/">AWS CloudHSM</a>, the <a href="http://docs.aws.amazon.com/cloudhsm/latest/userguide/">AWS CloudHSM User Guide</a>, and the <a href="http://docs.aws.amazon.com/cloudhsm/latest/APIReference/">AWS CloudHSM API Reference</a>.</p> <p>Returns a list of all tags for the specified AWS CloudHSM resource.</p>
+    async fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> Result<ListTagsForResourceResponse, RusotoError<ListTagsForResourceError>> {
+        self.list_tags_for_resource_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>This is documentation for <b>AWS CloudHSM Classic</b>. For more information, see <a href="http://aws.amazon.com/cloudhsm/faqs-classic/">AWS CloudHSM Classic FAQs</a>, the <a href="http://docs.aws.amazon.com/cloudhsm/classic/userguide/">AWS CloudHSM Classic User Guide</a>, and the <a href="http://docs.aws.amazon.com/cloudhsm/classic/APIReference/">AWS CloudHSM Classic API Reference</a>.</p> <p> <b>For information about the current version of AWS CloudHSM</b>, see <a href="http://aws.amazon.com/cloudhsm/">AWS CloudHSM</a>, the <a href="http://docs.aws.amazon.com/cloudhsm/latest/userguide/">AWS CloudHSM User Guide</a>, and the <a href="http://docs.aws.amazon.com/cloudhsm/latest/APIReference/">AWS CloudHSM API Reference</a>.</p> <p>Modifies an existing high-availability partition group.</p>
+    async fn modify_hapg(
+        &self,
+        input: ModifyHapgRequest,
+    ) -> Result<ModifyHapgResponse, RusotoError<ModifyHapgError>> {
+        self.modify_hapg_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>This is documentation for <b>AWS CloudHSM Classic</b>. For more information, see <a href="http://aws.amazon.com/cloudhsm/faqs-classic/">AWS CloudHSM Classic FAQs</a>, the <a href="http://docs.aws.amazon.com/cloudhsm/classic/userguide/">AWS CloudHSM Classic User Guide</a>, and the <a href="http://docs.aws.amazon.com/cloudhsm/classic/APIReference/">AWS CloudHSM Classic API Reference</a>.</p> <p> <b>For information about the current version of AWS CloudHSM</b>, see <a href="http://aws.amazon.com/cloudhsm/">AWS CloudHSM</a>, the <a href="http://docs.aws.amazon.com/cloudhsm/latest/userguide/">AWS CloudHSM User Guide</a>, and the <a href="http://docs.aws.amazon.com/cloudhsm/latest/APIReference/">AWS CloudHSM API Reference</a>.</p> <p>Modifies an HSM.</p> <important> <p>This operation can result in the HSM being offline for up to 15 minutes while the AWS CloudHSM service is reconfigured. If you are modifying a production HSM, you should ensure that your AWS CloudHSM service is configured for high availability, and consider executing this operation during a maintenance window.</p> </important></p>
+    async fn modify_hsm(
+        &self,
+        input: ModifyHsmRequest,
+    ) -> Result<ModifyHsmResponse, RusotoError<ModifyHsmError>> {
+        self.modify_hsm_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>This is documentation for <b>AWS CloudHSM Classic</b>. For more information, see <a href="http://aws.amazon.com/cloudhsm/faqs-classic/">AWS CloudHSM Classic FAQs</a>, the <a href="http://docs.aws.amazon.com/cloudhsm/classic/userguide/">AWS CloudHSM Classic User Guide</a>, and the <a href="http://docs.aws.amazon.com/cloudhsm/classic/APIReference/">AWS CloudHSM Classic API Reference</a>.</p> <p> <b>For information about the current version of AWS CloudHSM</b>, see <a href="http://aws.amazon.com/cloudhsm/">AWS CloudHSM</a>, the <a href="http://docs.aws.amazon.com/cloudhsm/latest/userguide/">AWS CloudHSM User Guide</a>, and the <a href="http://docs.aws.amazon.com/cloudhsm/latest/APIReference/">AWS CloudHSM API Reference</a>.</p> <p>Modifies the certificate used by the client.</p> <p>This action can potentially start a workflow to install the new certificate on the client's HSMs.</p>
+    async fn modify_luna_client(
+        &self,
+        input: ModifyLunaClientRequest,
+    ) -> Result<ModifyLunaClientResponse, RusotoError<ModifyLunaClientError>> {
+        self.modify_luna_client_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>This is documentation for <b>AWS CloudHSM Classic</b>. For more information, see <a href="http://aws.amazon.com/cloudhsm/faqs-classic/">AWS CloudHSM Classic FAQs</a>, the <a href="http://docs.aws.amazon.com/cloudhsm/classic/userguide/">AWS CloudHSM Classic User Guide</a>, and the <a href="http://docs.aws.amazon.com/cloudhsm/classic/APIReference/">AWS CloudHSM Classic API Reference</a>.</p> <p> <b>For information about the current version of AWS CloudHSM</b>, see <a href="http://aws.amazon.com/cloudhsm/">AWS CloudHSM</a>, the <a href="http://docs.aws.amazon.com/cloudhsm/latest/userguide/">AWS CloudHSM User Guide</a>, and the <a href="http://docs.aws.amazon.com/cloudhsm/latest/APIReference/">AWS CloudHSM API Reference</a>.</p> <p>Removes one or more tags from the specified AWS CloudHSM resource.</p> <p>To remove a tag, specify only the tag key to remove (not the value). To overwrite the value for an existing tag, use <a>AddTagsToResource</a>.</p>
+    async fn remove_tags_from_resource(
+        &self,
+        input: RemoveTagsFromResourceRequest,
+    ) -> Result<RemoveTagsFromResourceResponse, RusotoError<RemoveTagsFromResourceError>> {
+        self.remove_tags_from_resource_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
     }
 }
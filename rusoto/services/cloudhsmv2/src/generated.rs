@@ -16,8 +16,8 @@ use std::fmt;
 use async_trait::async_trait;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
-use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest, ResponseMetadata};
+use rusoto_core::{Client, ErrorMetadata, RusotoError};
 
 use rusoto_core::proto;
 use rusoto_core::signature::SignedRequest;
@@ -530,6 +530,7 @@ pub struct UntagResourceResponse {}
 
 /// Errors returned by CopyBackupToRegion
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CopyBackupToRegionError {
     /// <p>The request was rejected because the requester does not have permission to perform the requested operation.</p>
     CloudHsmAccessDenied(String),
@@ -543,6 +544,8 @@ pub enum CopyBackupToRegionError {
     CloudHsmService(String),
 
     CloudHsmTag(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CopyBackupToRegionError {
@@ -550,33 +553,43 @@ impl CopyBackupToRegionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "CloudHsmAccessDeniedException" => {
-                    return RusotoError::Service(CopyBackupToRegionError::CloudHsmAccessDenied(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CopyBackupToRegionError::CloudHsmAccessDenied(err.msg),
+                        &res,
+                    )
                 }
                 "CloudHsmInternalFailureException" => {
-                    return RusotoError::Service(CopyBackupToRegionError::CloudHsmInternalFailure(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CopyBackupToRegionError::CloudHsmInternalFailure(err.msg),
+                        &res,
+                    )
                 }
                 "CloudHsmInvalidRequestException" => {
-                    return RusotoError::Service(CopyBackupToRegionError::CloudHsmInvalidRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CopyBackupToRegionError::CloudHsmInvalidRequest(err.msg),
+                        &res,
+                    )
                 }
                 "CloudHsmResourceNotFoundException" => {
-                    return RusotoError::Service(CopyBackupToRegionError::CloudHsmResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CopyBackupToRegionError::CloudHsmResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "CloudHsmServiceException" => {
-                    return RusotoError::Service(CopyBackupToRegionError::CloudHsmService(err.msg))
+                    return RusotoError::service(
+                        CopyBackupToRegionError::CloudHsmService(err.msg),
+                        &res,
+                    )
                 }
                 "CloudHsmTagException" => {
-                    return RusotoError::Service(CopyBackupToRegionError::CloudHsmTag(err.msg))
+                    return RusotoError::service(
+                        CopyBackupToRegionError::CloudHsmTag(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CopyBackupToRegionError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -592,12 +605,14 @@ impl fmt::Display for CopyBackupToRegionError {
             CopyBackupToRegionError::CloudHsmResourceNotFound(ref cause) => write!(f, "{}", cause),
             CopyBackupToRegionError::CloudHsmService(ref cause) => write!(f, "{}", cause),
             CopyBackupToRegionError::CloudHsmTag(ref cause) => write!(f, "{}", cause),
+            CopyBackupToRegionError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CopyBackupToRegionError {}
 /// Errors returned by CreateCluster
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateClusterError {
     /// <p>The request was rejected because the requester does not have permission to perform the requested operation.</p>
     CloudHsmAccessDenied(String),
@@ -611,6 +626,8 @@ pub enum CreateClusterError {
     CloudHsmService(String),
 
     CloudHsmTag(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateClusterError {
@@ -618,31 +635,37 @@ impl CreateClusterError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "CloudHsmAccessDeniedException" => {
-                    return RusotoError::Service(CreateClusterError::CloudHsmAccessDenied(err.msg))
+                    return RusotoError::service(
+                        CreateClusterError::CloudHsmAccessDenied(err.msg),
+                        &res,
+                    )
                 }
                 "CloudHsmInternalFailureException" => {
-                    return RusotoError::Service(CreateClusterError::CloudHsmInternalFailure(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateClusterError::CloudHsmInternalFailure(err.msg),
+                        &res,
+                    )
                 }
                 "CloudHsmInvalidRequestException" => {
-                    return RusotoError::Service(CreateClusterError::CloudHsmInvalidRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateClusterError::CloudHsmInvalidRequest(err.msg),
+                        &res,
+                    )
                 }
                 "CloudHsmResourceNotFoundException" => {
-                    return RusotoError::Service(CreateClusterError::CloudHsmResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateClusterError::CloudHsmResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "CloudHsmServiceException" => {
-                    return RusotoError::Service(CreateClusterError::CloudHsmService(err.msg))
+                    return RusotoError::service(CreateClusterError::CloudHsmService(err.msg), &res)
                 }
                 "CloudHsmTagException" => {
-                    return RusotoError::Service(CreateClusterError::CloudHsmTag(err.msg))
+                    return RusotoError::service(CreateClusterError::CloudHsmTag(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreateClusterError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -658,12 +681,14 @@ impl fmt::Display for CreateClusterError {
             CreateClusterError::CloudHsmResourceNotFound(ref cause) => write!(f, "{}", cause),
             CreateClusterError::CloudHsmService(ref cause) => write!(f, "{}", cause),
             CreateClusterError::CloudHsmTag(ref cause) => write!(f, "{}", cause),
+            CreateClusterError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateClusterError {}
 /// Errors returned by CreateHsm
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateHsmError {
     /// <p>The request was rejected because the requester does not have permission to perform the requested operation.</p>
     CloudHsmAccessDenied(String),
@@ -675,6 +700,8 @@ pub enum CreateHsmError {
     CloudHsmResourceNotFound(String),
     /// <p>The request was rejected because an error occurred.</p>
     CloudHsmService(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateHsmError {
@@ -682,22 +709,34 @@ impl CreateHsmError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "CloudHsmAccessDeniedException" => {
-                    return RusotoError::Service(CreateHsmError::CloudHsmAccessDenied(err.msg))
+                    return RusotoError::service(
+                        CreateHsmError::CloudHsmAccessDenied(err.msg),
+                        &res,
+                    )
                 }
                 "CloudHsmInternalFailureException" => {
-                    return RusotoError::Service(CreateHsmError::CloudHsmInternalFailure(err.msg))
+                    return RusotoError::service(
+                        CreateHsmError::CloudHsmInternalFailure(err.msg),
+                        &res,
+                    )
                 }
                 "CloudHsmInvalidRequestException" => {
-                    return RusotoError::Service(CreateHsmError::CloudHsmInvalidRequest(err.msg))
+                    return RusotoError::service(
+                        CreateHsmError::CloudHsmInvalidRequest(err.msg),
+                        &res,
+                    )
                 }
                 "CloudHsmResourceNotFoundException" => {
-                    return RusotoError::Service(CreateHsmError::CloudHsmResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        CreateHsmError::CloudHsmResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "CloudHsmServiceException" => {
-                    return RusotoError::Service(CreateHsmError::CloudHsmService(err.msg))
+                    return RusotoError::service(CreateHsmError::CloudHsmService(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreateHsmError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -712,12 +751,14 @@ impl fmt::Display for CreateHsmError {
             CreateHsmError::CloudHsmInvalidRequest(ref cause) => write!(f, "{}", cause),
             CreateHsmError::CloudHsmResourceNotFound(ref cause) => write!(f, "{}", cause),
             CreateHsmError::CloudHsmService(ref cause) => write!(f, "{}", cause),
+            CreateHsmError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateHsmError {}
 /// Errors returned by DeleteBackup
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteBackupError {
     /// <p>The request was rejected because the requester does not have permission to perform the requested operation.</p>
     CloudHsmAccessDenied(String),
@@ -729,6 +770,8 @@ pub enum DeleteBackupError {
     CloudHsmResourceNotFound(String),
     /// <p>The request was rejected because an error occurred.</p>
     CloudHsmService(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteBackupError {
@@ -736,26 +779,34 @@ impl DeleteBackupError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "CloudHsmAccessDeniedException" => {
-                    return RusotoError::Service(DeleteBackupError::CloudHsmAccessDenied(err.msg))
+                    return RusotoError::service(
+                        DeleteBackupError::CloudHsmAccessDenied(err.msg),
+                        &res,
+                    )
                 }
                 "CloudHsmInternalFailureException" => {
-                    return RusotoError::Service(DeleteBackupError::CloudHsmInternalFailure(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteBackupError::CloudHsmInternalFailure(err.msg),
+                        &res,
+                    )
                 }
                 "CloudHsmInvalidRequestException" => {
-                    return RusotoError::Service(DeleteBackupError::CloudHsmInvalidRequest(err.msg))
+                    return RusotoError::service(
+                        DeleteBackupError::CloudHsmInvalidRequest(err.msg),
+                        &res,
+                    )
                 }
                 "CloudHsmResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteBackupError::CloudHsmResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteBackupError::CloudHsmResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "CloudHsmServiceException" => {
-                    return RusotoError::Service(DeleteBackupError::CloudHsmService(err.msg))
+                    return RusotoError::service(DeleteBackupError::CloudHsmService(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteBackupError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -770,12 +821,14 @@ impl fmt::Display for DeleteBackupError {
             DeleteBackupError::CloudHsmInvalidRequest(ref cause) => write!(f, "{}", cause),
             DeleteBackupError::CloudHsmResourceNotFound(ref cause) => write!(f, "{}", cause),
             DeleteBackupError::CloudHsmService(ref cause) => write!(f, "{}", cause),
+            DeleteBackupError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteBackupError {}
 /// Errors returned by DeleteCluster
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteClusterError {
     /// <p>The request was rejected because the requester does not have permission to perform the requested operation.</p>
     CloudHsmAccessDenied(String),
@@ -789,6 +842,8 @@ pub enum DeleteClusterError {
     CloudHsmService(String),
 
     CloudHsmTag(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteClusterError {
@@ -796,31 +851,37 @@ impl DeleteClusterError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "CloudHsmAccessDeniedException" => {
-                    return RusotoError::Service(DeleteClusterError::CloudHsmAccessDenied(err.msg))
+                    return RusotoError::service(
+                        DeleteClusterError::CloudHsmAccessDenied(err.msg),
+                        &res,
+                    )
                 }
                 "CloudHsmInternalFailureException" => {
-                    return RusotoError::Service(DeleteClusterError::CloudHsmInternalFailure(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteClusterError::CloudHsmInternalFailure(err.msg),
+                        &res,
+                    )
                 }
                 "CloudHsmInvalidRequestException" => {
-                    return RusotoError::Service(DeleteClusterError::CloudHsmInvalidRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteClusterError::CloudHsmInvalidRequest(err.msg),
+                        &res,
+                    )
                 }
                 "CloudHsmResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteClusterError::CloudHsmResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteClusterError::CloudHsmResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "CloudHsmServiceException" => {
-                    return RusotoError::Service(DeleteClusterError::CloudHsmService(err.msg))
+                    return RusotoError::service(DeleteClusterError::CloudHsmService(err.msg), &res)
                 }
                 "CloudHsmTagException" => {
-                    return RusotoError::Service(DeleteClusterError::CloudHsmTag(err.msg))
+                    return RusotoError::service(DeleteClusterError::CloudHsmTag(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteClusterError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -836,12 +897,14 @@ impl fmt::Display for DeleteClusterError {
             DeleteClusterError::CloudHsmResourceNotFound(ref cause) => write!(f, "{}", cause),
             DeleteClusterError::CloudHsmService(ref cause) => write!(f, "{}", cause),
             DeleteClusterError::CloudHsmTag(ref cause) => write!(f, "{}", cause),
+            DeleteClusterError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteClusterError {}
 /// Errors returned by DeleteHsm
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteHsmError {
     /// <p>The request was rejected because the requester does not have permission to perform the requested operation.</p>
     CloudHsmAccessDenied(String),
@@ -853,6 +916,8 @@ pub enum DeleteHsmError {
     CloudHsmResourceNotFound(String),
     /// <p>The request was rejected because an error occurred.</p>
     CloudHsmService(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteHsmError {
@@ -860,22 +925,34 @@ impl DeleteHsmError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "CloudHsmAccessDeniedException" => {
-                    return RusotoError::Service(DeleteHsmError::CloudHsmAccessDenied(err.msg))
+                    return RusotoError::service(
+                        DeleteHsmError::CloudHsmAccessDenied(err.msg),
+                        &res,
+                    )
                 }
                 "CloudHsmInternalFailureException" => {
-                    return RusotoError::Service(DeleteHsmError::CloudHsmInternalFailure(err.msg))
+                    return RusotoError::service(
+                        DeleteHsmError::CloudHsmInternalFailure(err.msg),
+                        &res,
+                    )
                 }
                 "CloudHsmInvalidRequestException" => {
-                    return RusotoError::Service(DeleteHsmError::CloudHsmInvalidRequest(err.msg))
+                    return RusotoError::service(
+                        DeleteHsmError::CloudHsmInvalidRequest(err.msg),
+                        &res,
+                    )
                 }
                 "CloudHsmResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteHsmError::CloudHsmResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        DeleteHsmError::CloudHsmResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "CloudHsmServiceException" => {
-                    return RusotoError::Service(DeleteHsmError::CloudHsmService(err.msg))
+                    return RusotoError::service(DeleteHsmError::CloudHsmService(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteHsmError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -890,12 +967,14 @@ impl fmt::Display for DeleteHsmError {
             DeleteHsmError::CloudHsmInvalidRequest(ref cause) => write!(f, "{}", cause),
             DeleteHsmError::CloudHsmResourceNotFound(ref cause) => write!(f, "{}", cause),
             DeleteHsmError::CloudHsmService(ref cause) => write!(f, "{}", cause),
+            DeleteHsmError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteHsmError {}
 /// Errors returned by DescribeBackups
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeBackupsError {
     /// <p>The request was rejected because the requester does not have permission to perform the requested operation.</p>
     CloudHsmAccessDenied(String),
@@ -909,6 +988,8 @@ pub enum DescribeBackupsError {
     CloudHsmService(String),
 
     CloudHsmTag(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeBackupsError {
@@ -916,33 +997,40 @@ impl DescribeBackupsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "CloudHsmAccessDeniedException" => {
-                    return RusotoError::Service(DescribeBackupsError::CloudHsmAccessDenied(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeBackupsError::CloudHsmAccessDenied(err.msg),
+                        &res,
+                    )
                 }
                 "CloudHsmInternalFailureException" => {
-                    return RusotoError::Service(DescribeBackupsError::CloudHsmInternalFailure(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeBackupsError::CloudHsmInternalFailure(err.msg),
+                        &res,
+                    )
                 }
                 "CloudHsmInvalidRequestException" => {
-                    return RusotoError::Service(DescribeBackupsError::CloudHsmInvalidRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeBackupsError::CloudHsmInvalidRequest(err.msg),
+                        &res,
+                    )
                 }
                 "CloudHsmResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeBackupsError::CloudHsmResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeBackupsError::CloudHsmResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "CloudHsmServiceException" => {
-                    return RusotoError::Service(DescribeBackupsError::CloudHsmService(err.msg))
+                    return RusotoError::service(
+                        DescribeBackupsError::CloudHsmService(err.msg),
+                        &res,
+                    )
                 }
                 "CloudHsmTagException" => {
-                    return RusotoError::Service(DescribeBackupsError::CloudHsmTag(err.msg))
+                    return RusotoError::service(DescribeBackupsError::CloudHsmTag(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DescribeBackupsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -958,12 +1046,14 @@ impl fmt::Display for DescribeBackupsError {
             DescribeBackupsError::CloudHsmResourceNotFound(ref cause) => write!(f, "{}", cause),
             DescribeBackupsError::CloudHsmService(ref cause) => write!(f, "{}", cause),
             DescribeBackupsError::CloudHsmTag(ref cause) => write!(f, "{}", cause),
+            DescribeBackupsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeBackupsError {}
 /// Errors returned by DescribeClusters
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeClustersError {
     /// <p>The request was rejected because the requester does not have permission to perform the requested operation.</p>
     CloudHsmAccessDenied(String),
@@ -975,6 +1065,8 @@ pub enum DescribeClustersError {
     CloudHsmService(String),
 
     CloudHsmTag(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeClustersError {
@@ -982,28 +1074,34 @@ impl DescribeClustersError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "CloudHsmAccessDeniedException" => {
-                    return RusotoError::Service(DescribeClustersError::CloudHsmAccessDenied(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeClustersError::CloudHsmAccessDenied(err.msg),
+                        &res,
+                    )
                 }
                 "CloudHsmInternalFailureException" => {
-                    return RusotoError::Service(DescribeClustersError::CloudHsmInternalFailure(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeClustersError::CloudHsmInternalFailure(err.msg),
+                        &res,
+                    )
                 }
                 "CloudHsmInvalidRequestException" => {
-                    return RusotoError::Service(DescribeClustersError::CloudHsmInvalidRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeClustersError::CloudHsmInvalidRequest(err.msg),
+                        &res,
+                    )
                 }
                 "CloudHsmServiceException" => {
-                    return RusotoError::Service(DescribeClustersError::CloudHsmService(err.msg))
+                    return RusotoError::service(
+                        DescribeClustersError::CloudHsmService(err.msg),
+                        &res,
+                    )
                 }
                 "CloudHsmTagException" => {
-                    return RusotoError::Service(DescribeClustersError::CloudHsmTag(err.msg))
+                    return RusotoError::service(DescribeClustersError::CloudHsmTag(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DescribeClustersError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1018,12 +1116,14 @@ impl fmt::Display for DescribeClustersError {
             DescribeClustersError::CloudHsmInvalidRequest(ref cause) => write!(f, "{}", cause),
             DescribeClustersError::CloudHsmService(ref cause) => write!(f, "{}", cause),
             DescribeClustersError::CloudHsmTag(ref cause) => write!(f, "{}", cause),
+            DescribeClustersError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeClustersError {}
 /// Errors returned by InitializeCluster
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum InitializeClusterError {
     /// <p>The request was rejected because the requester does not have permission to perform the requested operation.</p>
     CloudHsmAccessDenied(String),
@@ -1035,6 +1135,8 @@ pub enum InitializeClusterError {
     CloudHsmResourceNotFound(String),
     /// <p>The request was rejected because an error occurred.</p>
     CloudHsmService(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl InitializeClusterError {
@@ -1042,30 +1144,37 @@ impl InitializeClusterError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "CloudHsmAccessDeniedException" => {
-                    return RusotoError::Service(InitializeClusterError::CloudHsmAccessDenied(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        InitializeClusterError::CloudHsmAccessDenied(err.msg),
+                        &res,
+                    )
                 }
                 "CloudHsmInternalFailureException" => {
-                    return RusotoError::Service(InitializeClusterError::CloudHsmInternalFailure(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        InitializeClusterError::CloudHsmInternalFailure(err.msg),
+                        &res,
+                    )
                 }
                 "CloudHsmInvalidRequestException" => {
-                    return RusotoError::Service(InitializeClusterError::CloudHsmInvalidRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        InitializeClusterError::CloudHsmInvalidRequest(err.msg),
+                        &res,
+                    )
                 }
                 "CloudHsmResourceNotFoundException" => {
-                    return RusotoError::Service(InitializeClusterError::CloudHsmResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        InitializeClusterError::CloudHsmResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "CloudHsmServiceException" => {
-                    return RusotoError::Service(InitializeClusterError::CloudHsmService(err.msg))
+                    return RusotoError::service(
+                        InitializeClusterError::CloudHsmService(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(InitializeClusterError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1080,12 +1189,14 @@ impl fmt::Display for InitializeClusterError {
             InitializeClusterError::CloudHsmInvalidRequest(ref cause) => write!(f, "{}", cause),
             InitializeClusterError::CloudHsmResourceNotFound(ref cause) => write!(f, "{}", cause),
             InitializeClusterError::CloudHsmService(ref cause) => write!(f, "{}", cause),
+            InitializeClusterError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for InitializeClusterError {}
 /// Errors returned by ListTags
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListTagsError {
     /// <p>The request was rejected because the requester does not have permission to perform the requested operation.</p>
     CloudHsmAccessDenied(String),
@@ -1099,6 +1210,8 @@ pub enum ListTagsError {
     CloudHsmService(String),
 
     CloudHsmTag(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListTagsError {
@@ -1106,25 +1219,34 @@ impl ListTagsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "CloudHsmAccessDeniedException" => {
-                    return RusotoError::Service(ListTagsError::CloudHsmAccessDenied(err.msg))
+                    return RusotoError::service(ListTagsError::CloudHsmAccessDenied(err.msg), &res)
                 }
                 "CloudHsmInternalFailureException" => {
-                    return RusotoError::Service(ListTagsError::CloudHsmInternalFailure(err.msg))
+                    return RusotoError::service(
+                        ListTagsError::CloudHsmInternalFailure(err.msg),
+                        &res,
+                    )
                 }
                 "CloudHsmInvalidRequestException" => {
-                    return RusotoError::Service(ListTagsError::CloudHsmInvalidRequest(err.msg))
+                    return RusotoError::service(
+                        ListTagsError::CloudHsmInvalidRequest(err.msg),
+                        &res,
+                    )
                 }
                 "CloudHsmResourceNotFoundException" => {
-                    return RusotoError::Service(ListTagsError::CloudHsmResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        ListTagsError::CloudHsmResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "CloudHsmServiceException" => {
-                    return RusotoError::Service(ListTagsError::CloudHsmService(err.msg))
+                    return RusotoError::service(ListTagsError::CloudHsmService(err.msg), &res)
                 }
                 "CloudHsmTagException" => {
-                    return RusotoError::Service(ListTagsError::CloudHsmTag(err.msg))
+                    return RusotoError::service(ListTagsError::CloudHsmTag(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListTagsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1140,12 +1262,14 @@ impl fmt::Display for ListTagsError {
             ListTagsError::CloudHsmResourceNotFound(ref cause) => write!(f, "{}", cause),
             ListTagsError::CloudHsmService(ref cause) => write!(f, "{}", cause),
             ListTagsError::CloudHsmTag(ref cause) => write!(f, "{}", cause),
+            ListTagsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListTagsError {}
 /// Errors returned by RestoreBackup
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum RestoreBackupError {
     /// <p>The request was rejected because the requester does not have permission to perform the requested operation.</p>
     CloudHsmAccessDenied(String),
@@ -1157,6 +1281,8 @@ pub enum RestoreBackupError {
     CloudHsmResourceNotFound(String),
     /// <p>The request was rejected because an error occurred.</p>
     CloudHsmService(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl RestoreBackupError {
@@ -1164,28 +1290,34 @@ impl RestoreBackupError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "CloudHsmAccessDeniedException" => {
-                    return RusotoError::Service(RestoreBackupError::CloudHsmAccessDenied(err.msg))
+                    return RusotoError::service(
+                        RestoreBackupError::CloudHsmAccessDenied(err.msg),
+                        &res,
+                    )
                 }
                 "CloudHsmInternalFailureException" => {
-                    return RusotoError::Service(RestoreBackupError::CloudHsmInternalFailure(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        RestoreBackupError::CloudHsmInternalFailure(err.msg),
+                        &res,
+                    )
                 }
                 "CloudHsmInvalidRequestException" => {
-                    return RusotoError::Service(RestoreBackupError::CloudHsmInvalidRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        RestoreBackupError::CloudHsmInvalidRequest(err.msg),
+                        &res,
+                    )
                 }
                 "CloudHsmResourceNotFoundException" => {
-                    return RusotoError::Service(RestoreBackupError::CloudHsmResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        RestoreBackupError::CloudHsmResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "CloudHsmServiceException" => {
-                    return RusotoError::Service(RestoreBackupError::CloudHsmService(err.msg))
+                    return RusotoError::service(RestoreBackupError::CloudHsmService(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(RestoreBackupError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1200,12 +1332,14 @@ impl fmt::Display for RestoreBackupError {
             RestoreBackupError::CloudHsmInvalidRequest(ref cause) => write!(f, "{}", cause),
             RestoreBackupError::CloudHsmResourceNotFound(ref cause) => write!(f, "{}", cause),
             RestoreBackupError::CloudHsmService(ref cause) => write!(f, "{}", cause),
+            RestoreBackupError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for RestoreBackupError {}
 /// Errors returned by TagResource
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum TagResourceError {
     /// <p>The request was rejected because the requester does not have permission to perform the requested operation.</p>
     CloudHsmAccessDenied(String),
@@ -1219,6 +1353,8 @@ pub enum TagResourceError {
     CloudHsmService(String),
 
     CloudHsmTag(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl TagResourceError {
@@ -1226,27 +1362,37 @@ impl TagResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "CloudHsmAccessDeniedException" => {
-                    return RusotoError::Service(TagResourceError::CloudHsmAccessDenied(err.msg))
+                    return RusotoError::service(
+                        TagResourceError::CloudHsmAccessDenied(err.msg),
+                        &res,
+                    )
                 }
                 "CloudHsmInternalFailureException" => {
-                    return RusotoError::Service(TagResourceError::CloudHsmInternalFailure(err.msg))
+                    return RusotoError::service(
+                        TagResourceError::CloudHsmInternalFailure(err.msg),
+                        &res,
+                    )
                 }
                 "CloudHsmInvalidRequestException" => {
-                    return RusotoError::Service(TagResourceError::CloudHsmInvalidRequest(err.msg))
+                    return RusotoError::service(
+                        TagResourceError::CloudHsmInvalidRequest(err.msg),
+                        &res,
+                    )
                 }
                 "CloudHsmResourceNotFoundException" => {
-                    return RusotoError::Service(TagResourceError::CloudHsmResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        TagResourceError::CloudHsmResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "CloudHsmServiceException" => {
-                    return RusotoError::Service(TagResourceError::CloudHsmService(err.msg))
+                    return RusotoError::service(TagResourceError::CloudHsmService(err.msg), &res)
                 }
                 "CloudHsmTagException" => {
-                    return RusotoError::Service(TagResourceError::CloudHsmTag(err.msg))
+                    return RusotoError::service(TagResourceError::CloudHsmTag(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(TagResourceError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1262,12 +1408,14 @@ impl fmt::Display for TagResourceError {
             TagResourceError::CloudHsmResourceNotFound(ref cause) => write!(f, "{}", cause),
             TagResourceError::CloudHsmService(ref cause) => write!(f, "{}", cause),
             TagResourceError::CloudHsmTag(ref cause) => write!(f, "{}", cause),
+            TagResourceError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for TagResourceError {}
 /// Errors returned by UntagResource
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UntagResourceError {
     /// <p>The request was rejected because the requester does not have permission to perform the requested operation.</p>
     CloudHsmAccessDenied(String),
@@ -1281,6 +1429,8 @@ pub enum UntagResourceError {
     CloudHsmService(String),
 
     CloudHsmTag(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UntagResourceError {
@@ -1288,31 +1438,37 @@ impl UntagResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "CloudHsmAccessDeniedException" => {
-                    return RusotoError::Service(UntagResourceError::CloudHsmAccessDenied(err.msg))
+                    return RusotoError::service(
+                        UntagResourceError::CloudHsmAccessDenied(err.msg),
+                        &res,
+                    )
                 }
                 "CloudHsmInternalFailureException" => {
-                    return RusotoError::Service(UntagResourceError::CloudHsmInternalFailure(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UntagResourceError::CloudHsmInternalFailure(err.msg),
+                        &res,
+                    )
                 }
                 "CloudHsmInvalidRequestException" => {
-                    return RusotoError::Service(UntagResourceError::CloudHsmInvalidRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UntagResourceError::CloudHsmInvalidRequest(err.msg),
+                        &res,
+                    )
                 }
                 "CloudHsmResourceNotFoundException" => {
-                    return RusotoError::Service(UntagResourceError::CloudHsmResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UntagResourceError::CloudHsmResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "CloudHsmServiceException" => {
-                    return RusotoError::Service(UntagResourceError::CloudHsmService(err.msg))
+                    return RusotoError::service(UntagResourceError::CloudHsmService(err.msg), &res)
                 }
                 "CloudHsmTagException" => {
-                    return RusotoError::Service(UntagResourceError::CloudHsmTag(err.msg))
+                    return RusotoError::service(UntagResourceError::CloudHsmTag(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UntagResourceError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1328,6 +1484,7 @@ impl fmt::Display for UntagResourceError {
             UntagResourceError::CloudHsmResourceNotFound(ref cause) => write!(f, "{}", cause),
             UntagResourceError::CloudHsmService(ref cause) => write!(f, "{}", cause),
             UntagResourceError::CloudHsmTag(ref cause) => write!(f, "{}", cause),
+            UntagResourceError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
@@ -1424,6 +1581,7 @@ impl CloudHsmv2Client {
     /// Creates a client backed by the default tokio event loop.
     ///
     /// The client will use the default credentials provider and tls client.
+    #[cfg(not(target_arch = "wasm32"))]
     pub fn new(region: region::Region) -> CloudHsmv2Client {
         CloudHsmv2Client {
             client: Client::shared(),
@@ -1449,361 +1607,943 @@ impl CloudHsmv2Client {
     pub fn new_with_client(client: Client, region: region::Region) -> CloudHsmv2Client {
         CloudHsmv2Client { client, region }
     }
+
+    /// Returns a builder of a client, setting any of its region, credentials provider,
+    /// dispatcher, retry policy, endpoint and timeouts.
+    pub fn builder() -> rusoto_core::ClientBuilder<CloudHsmv2Client> {
+        rusoto_core::ClientBuilder::new(CloudHsmv2Client::new_with_client)
+    }
+
+    /// Returns a clone of this client with the overrides of `config` applied to its
+    /// operations, sharing its connections.
+    pub fn customize(&self, config: &rusoto_core::OperationConfig) -> CloudHsmv2Client {
+        CloudHsmv2Client {
+            client: self.client.customize(config),
+            region: config.resolve_region(&self.region),
+        }
+    }
 }
 
-#[async_trait]
-impl CloudHsmv2 for CloudHsmv2Client {
-    /// <p>Copy an AWS CloudHSM cluster backup to a different region.</p>
-    async fn copy_backup_to_region(
+impl CloudHsmv2Client {
+    /// Calls `copy_backup_to_region`, also returning the metadata of the response.
+    pub async fn copy_backup_to_region_with_metadata(
         &self,
         input: CopyBackupToRegionRequest,
-    ) -> Result<CopyBackupToRegionResponse, RusotoError<CopyBackupToRegionError>> {
+    ) -> Result<(CopyBackupToRegionResponse, ResponseMetadata), RusotoError<CopyBackupToRegionError>>
+    {
+        let request = self.build_copy_backup_to_region_request(input);
+
+        let mut response = self
+            .client
+            .sign_and_dispatch(request)
+            .await
+            .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
+        if response.status.is_success() {
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<CopyBackupToRegionResponse, _>()?;
+            Ok((result, metadata))
+        } else {
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(CopyBackupToRegionError::from_response(response))
+        }
+    }
+
+    /// Presigns the request `copy_backup_to_region` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_copy_backup_to_region(
+        &self,
+        presigner: &rusoto_core::Presigner,
+        input: CopyBackupToRegionRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_copy_backup_to_region_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_copy_backup_to_region_request(
+        &self,
+        input: CopyBackupToRegionRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "cloudhsm", &self.region, "/");
+        request.set_operation("CopyBackupToRegion");
         request.set_endpoint_prefix("cloudhsmv2".to_string());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "BaldrApiService.CopyBackupToRegion");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `create_cluster`, also returning the metadata of the response.
+    pub async fn create_cluster_with_metadata(
+        &self,
+        input: CreateClusterRequest,
+    ) -> Result<(CreateClusterResponse, ResponseMetadata), RusotoError<CreateClusterError>> {
+        let request = self.build_create_cluster_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<CopyBackupToRegionResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<CreateClusterResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(CopyBackupToRegionError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(CreateClusterError::from_response(response))
         }
     }
 
-    /// <p>Creates a new AWS CloudHSM cluster.</p>
-    async fn create_cluster(
+    /// Presigns the request `create_cluster` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_cluster(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: CreateClusterRequest,
-    ) -> Result<CreateClusterResponse, RusotoError<CreateClusterError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_cluster_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_cluster_request(&self, input: CreateClusterRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "cloudhsm", &self.region, "/");
+        request.set_operation("CreateCluster");
         request.set_endpoint_prefix("cloudhsmv2".to_string());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "BaldrApiService.CreateCluster");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `create_hsm`, also returning the metadata of the response.
+    pub async fn create_hsm_with_metadata(
+        &self,
+        input: CreateHsmRequest,
+    ) -> Result<(CreateHsmResponse, ResponseMetadata), RusotoError<CreateHsmError>> {
+        let request = self.build_create_hsm_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<CreateClusterResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<CreateHsmResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(CreateClusterError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(CreateHsmError::from_response(response))
         }
     }
 
-    /// <p>Creates a new hardware security module (HSM) in the specified AWS CloudHSM cluster.</p>
-    async fn create_hsm(
+    /// Presigns the request `create_hsm` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_hsm(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: CreateHsmRequest,
-    ) -> Result<CreateHsmResponse, RusotoError<CreateHsmError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_hsm_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_hsm_request(&self, input: CreateHsmRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "cloudhsm", &self.region, "/");
+        request.set_operation("CreateHsm");
         request.set_endpoint_prefix("cloudhsmv2".to_string());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "BaldrApiService.CreateHsm");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `delete_backup`, also returning the metadata of the response.
+    pub async fn delete_backup_with_metadata(
+        &self,
+        input: DeleteBackupRequest,
+    ) -> Result<(DeleteBackupResponse, ResponseMetadata), RusotoError<DeleteBackupError>> {
+        let request = self.build_delete_backup_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<CreateHsmResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DeleteBackupResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(CreateHsmError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DeleteBackupError::from_response(response))
         }
     }
 
-    /// <p>Deletes a specified AWS CloudHSM backup. A backup can be restored up to 7 days after the DeleteBackup request is made. For more information on restoring a backup, see <a>RestoreBackup</a>.</p>
-    async fn delete_backup(
+    /// Presigns the request `delete_backup` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_backup(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteBackupRequest,
-    ) -> Result<DeleteBackupResponse, RusotoError<DeleteBackupError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_backup_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_backup_request(&self, input: DeleteBackupRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "cloudhsm", &self.region, "/");
+        request.set_operation("DeleteBackup");
         request.set_endpoint_prefix("cloudhsmv2".to_string());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "BaldrApiService.DeleteBackup");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `delete_cluster`, also returning the metadata of the response.
+    pub async fn delete_cluster_with_metadata(
+        &self,
+        input: DeleteClusterRequest,
+    ) -> Result<(DeleteClusterResponse, ResponseMetadata), RusotoError<DeleteClusterError>> {
+        let request = self.build_delete_cluster_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<DeleteBackupResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DeleteClusterResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteBackupError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DeleteClusterError::from_response(response))
         }
     }
 
-    /// <p>Deletes the specified AWS CloudHSM cluster. Before you can delete a cluster, you must delete all HSMs in the cluster. To see if the cluster contains any HSMs, use <a>DescribeClusters</a>. To delete an HSM, use <a>DeleteHsm</a>.</p>
-    async fn delete_cluster(
+    /// Presigns the request `delete_cluster` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_cluster(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteClusterRequest,
-    ) -> Result<DeleteClusterResponse, RusotoError<DeleteClusterError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_cluster_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_cluster_request(&self, input: DeleteClusterRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "cloudhsm", &self.region, "/");
+        request.set_operation("DeleteCluster");
         request.set_endpoint_prefix("cloudhsmv2".to_string());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "BaldrApiService.DeleteCluster");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `delete_hsm`, also returning the metadata of the response.
+    pub async fn delete_hsm_with_metadata(
+        &self,
+        input: DeleteHsmRequest,
+    ) -> Result<(DeleteHsmResponse, ResponseMetadata), RusotoError<DeleteHsmError>> {
+        let request = self.build_delete_hsm_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<DeleteClusterResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DeleteHsmResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteClusterError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DeleteHsmError::from_response(response))
         }
     }
 
-    /// <p>Deletes the specified HSM. To specify an HSM, you can use its identifier (ID), the IP address of the HSM's elastic network interface (ENI), or the ID of the HSM's ENI. You need to specify only one of these values. To find these values, use <a>DescribeClusters</a>.</p>
-    async fn delete_hsm(
+    /// Presigns the request `delete_hsm` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_hsm(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteHsmRequest,
-    ) -> Result<DeleteHsmResponse, RusotoError<DeleteHsmError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_hsm_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_hsm_request(&self, input: DeleteHsmRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "cloudhsm", &self.region, "/");
+        request.set_operation("DeleteHsm");
         request.set_endpoint_prefix("cloudhsmv2".to_string());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "BaldrApiService.DeleteHsm");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `describe_backups`, also returning the metadata of the response.
+    pub async fn describe_backups_with_metadata(
+        &self,
+        input: DescribeBackupsRequest,
+    ) -> Result<(DescribeBackupsResponse, ResponseMetadata), RusotoError<DescribeBackupsError>>
+    {
+        let request = self.build_describe_backups_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<DeleteHsmResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DescribeBackupsResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteHsmError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DescribeBackupsError::from_response(response))
         }
     }
 
-    /// <p>Gets information about backups of AWS CloudHSM clusters.</p> <p>This is a paginated operation, which means that each response might contain only a subset of all the backups. When the response contains only a subset of backups, it includes a <code>NextToken</code> value. Use this value in a subsequent <code>DescribeBackups</code> request to get more backups. When you receive a response with no <code>NextToken</code> (or an empty or null value), that means there are no more backups to get.</p>
-    async fn describe_backups(
+    /// Presigns the request `describe_backups` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_backups(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeBackupsRequest,
-    ) -> Result<DescribeBackupsResponse, RusotoError<DescribeBackupsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_backups_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_backups_request(&self, input: DescribeBackupsRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "cloudhsm", &self.region, "/");
+        request.set_operation("DescribeBackups");
         request.set_endpoint_prefix("cloudhsmv2".to_string());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "BaldrApiService.DescribeBackups");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `describe_clusters`, also returning the metadata of the response.
+    pub async fn describe_clusters_with_metadata(
+        &self,
+        input: DescribeClustersRequest,
+    ) -> Result<(DescribeClustersResponse, ResponseMetadata), RusotoError<DescribeClustersError>>
+    {
+        let request = self.build_describe_clusters_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<DescribeBackupsResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DescribeClustersResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeBackupsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DescribeClustersError::from_response(response))
         }
     }
 
-    /// <p>Gets information about AWS CloudHSM clusters.</p> <p>This is a paginated operation, which means that each response might contain only a subset of all the clusters. When the response contains only a subset of clusters, it includes a <code>NextToken</code> value. Use this value in a subsequent <code>DescribeClusters</code> request to get more clusters. When you receive a response with no <code>NextToken</code> (or an empty or null value), that means there are no more clusters to get.</p>
-    async fn describe_clusters(
+    /// Presigns the request `describe_clusters` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_clusters(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeClustersRequest,
-    ) -> Result<DescribeClustersResponse, RusotoError<DescribeClustersError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_clusters_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_clusters_request(&self, input: DescribeClustersRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "cloudhsm", &self.region, "/");
+        request.set_operation("DescribeClusters");
         request.set_endpoint_prefix("cloudhsmv2".to_string());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "BaldrApiService.DescribeClusters");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `initialize_cluster`, also returning the metadata of the response.
+    pub async fn initialize_cluster_with_metadata(
+        &self,
+        input: InitializeClusterRequest,
+    ) -> Result<(InitializeClusterResponse, ResponseMetadata), RusotoError<InitializeClusterError>>
+    {
+        let request = self.build_initialize_cluster_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DescribeClustersResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<InitializeClusterResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeClustersError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(InitializeClusterError::from_response(response))
         }
     }
 
-    /// <p>Claims an AWS CloudHSM cluster by submitting the cluster certificate issued by your issuing certificate authority (CA) and the CA's root certificate. Before you can claim a cluster, you must sign the cluster's certificate signing request (CSR) with your issuing CA. To get the cluster's CSR, use <a>DescribeClusters</a>.</p>
-    async fn initialize_cluster(
+    /// Presigns the request `initialize_cluster` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_initialize_cluster(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: InitializeClusterRequest,
-    ) -> Result<InitializeClusterResponse, RusotoError<InitializeClusterError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_initialize_cluster_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_initialize_cluster_request(&self, input: InitializeClusterRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "cloudhsm", &self.region, "/");
+        request.set_operation("InitializeCluster");
         request.set_endpoint_prefix("cloudhsmv2".to_string());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "BaldrApiService.InitializeCluster");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `list_tags`, also returning the metadata of the response.
+    pub async fn list_tags_with_metadata(
+        &self,
+        input: ListTagsRequest,
+    ) -> Result<(ListTagsResponse, ResponseMetadata), RusotoError<ListTagsError>> {
+        let request = self.build_list_tags_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<InitializeClusterResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ListTagsResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(InitializeClusterError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ListTagsError::from_response(response))
         }
     }
 
-    /// <p>Gets a list of tags for the specified AWS CloudHSM cluster.</p> <p>This is a paginated operation, which means that each response might contain only a subset of all the tags. When the response contains only a subset of tags, it includes a <code>NextToken</code> value. Use this value in a subsequent <code>ListTags</code> request to get more tags. When you receive a response with no <code>NextToken</code> (or an empty or null value), that means there are no more tags to get.</p>
-    async fn list_tags(
+    /// Presigns the request `list_tags` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_tags(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListTagsRequest,
-    ) -> Result<ListTagsResponse, RusotoError<ListTagsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_tags_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_tags_request(&self, input: ListTagsRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "cloudhsm", &self.region, "/");
+        request.set_operation("ListTags");
         request.set_endpoint_prefix("cloudhsmv2".to_string());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "BaldrApiService.ListTags");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `restore_backup`, also returning the metadata of the response.
+    pub async fn restore_backup_with_metadata(
+        &self,
+        input: RestoreBackupRequest,
+    ) -> Result<(RestoreBackupResponse, ResponseMetadata), RusotoError<RestoreBackupError>> {
+        let request = self.build_restore_backup_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<ListTagsResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<RestoreBackupResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ListTagsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(RestoreBackupError::from_response(response))
         }
     }
 
-    /// <p>Restores a specified AWS CloudHSM backup that is in the <code>PENDING_DELETION</code> state. For mor information on deleting a backup, see <a>DeleteBackup</a>.</p>
-    async fn restore_backup(
+    /// Presigns the request `restore_backup` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_restore_backup(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: RestoreBackupRequest,
-    ) -> Result<RestoreBackupResponse, RusotoError<RestoreBackupError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_restore_backup_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_restore_backup_request(&self, input: RestoreBackupRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "cloudhsm", &self.region, "/");
+        request.set_operation("RestoreBackup");
         request.set_endpoint_prefix("cloudhsmv2".to_string());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "BaldrApiService.RestoreBackup");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `tag_resource`, also returning the metadata of the response.
+    pub async fn tag_resource_with_metadata(
+        &self,
+        input: TagResourceRequest,
+    ) -> Result<(TagResourceResponse, ResponseMetadata), RusotoError<TagResourceError>> {
+        let request = self.build_tag_resource_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<RestoreBackupResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<TagResourceResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(RestoreBackupError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(TagResourceError::from_response(response))
         }
     }
 
-    /// <p>Adds or overwrites one or more tags for the specified AWS CloudHSM cluster.</p>
-    async fn tag_resource(
+    /// Presigns the request `tag_resource` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_tag_resource(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: TagResourceRequest,
-    ) -> Result<TagResourceResponse, RusotoError<TagResourceError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_tag_resource_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_tag_resource_request(&self, input: TagResourceRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "cloudhsm", &self.region, "/");
+        request.set_operation("TagResource");
         request.set_endpoint_prefix("cloudhsmv2".to_string());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "BaldrApiService.TagResource");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `untag_resource`, also returning the metadata of the response.
+    pub async fn untag_resource_with_metadata(
+        &self,
+        input: UntagResourceRequest,
+    ) -> Result<(UntagResourceResponse, ResponseMetadata), RusotoError<UntagResourceError>> {
+        let request = self.build_untag_resource_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<TagResourceResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<UntagResourceResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(TagResourceError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(UntagResourceError::from_response(response))
         }
     }
 
-    /// <p>Removes the specified tag or tags from the specified AWS CloudHSM cluster.</p>
-    async fn untag_resource(
+    /// Presigns the request `untag_resource` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_untag_resource(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UntagResourceRequest,
-    ) -> Result<UntagResourceResponse, RusotoError<UntagResourceError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_untag_resource_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_untag_resource_request(&self, input: UntagResourceRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "cloudhsm", &self.region, "/");
+        request.set_operation("UntagResource");
         request.set_endpoint_prefix("cloudhsmv2".to_string());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "BaldrApiService.UntagResource");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
-        let mut response = self
-            .client
-            .sign_and_dispatch(request)
+        request
+    }
+
+    /// The pages of `DescribeBackups` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `MaxResults` of `input`.
+    pub fn describe_backups_pages(
+        &self,
+        input: DescribeBackupsRequest,
+    ) -> impl futures::Stream<
+        Item = Result<DescribeBackupsResponse, RusotoError<DescribeBackupsError>>,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: CloudHsmv2Client, input| async move { client.describe_backups(input).await },
+            |input: &DescribeBackupsRequest, output: &DescribeBackupsResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(DescribeBackupsRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `Backups` of every page of `DescribeBackups` results for `input`, as `describe_backups_pages` requests them.
+    pub fn describe_backups_stream(
+        &self,
+        input: DescribeBackupsRequest,
+    ) -> impl futures::Stream<Item = Result<Backup, RusotoError<DescribeBackupsError>>> + Send {
+        rusoto_core::pagination::items(self.describe_backups_pages(input), |page| {
+            page.backups.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `DescribeClusters` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `MaxResults` of `input`.
+    pub fn describe_clusters_pages(
+        &self,
+        input: DescribeClustersRequest,
+    ) -> impl futures::Stream<
+        Item = Result<DescribeClustersResponse, RusotoError<DescribeClustersError>>,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: CloudHsmv2Client, input| async move { client.describe_clusters(input).await },
+            |input: &DescribeClustersRequest, output: &DescribeClustersResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(DescribeClustersRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `Clusters` of every page of `DescribeClusters` results for `input`, as `describe_clusters_pages` requests them.
+    pub fn describe_clusters_stream(
+        &self,
+        input: DescribeClustersRequest,
+    ) -> impl futures::Stream<Item = Result<Cluster, RusotoError<DescribeClustersError>>> + Send
+    {
+        rusoto_core::pagination::items(self.describe_clusters_pages(input), |page| {
+            page.clusters.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `ListTags` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `MaxResults` of `input`.
+    pub fn list_tags_pages(
+        &self,
+        input: ListTagsRequest,
+    ) -> impl futures::Stream<Item = Result<ListTagsResponse, RusotoError<ListTagsError>>> + Send
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: CloudHsmv2Client, input| async move { client.list_tags(input).await },
+            |input: &ListTagsRequest, output: &ListTagsResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListTagsRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `TagList` of every page of `ListTags` results for `input`, as `list_tags_pages` requests them.
+    pub fn list_tags_stream(
+        &self,
+        input: ListTagsRequest,
+    ) -> impl futures::Stream<Item = Result<Tag, RusotoError<ListTagsError>>> + Send {
+        rusoto_core::pagination::items(self.list_tags_pages(input), |page| page.tag_list)
+    }
+}
+
+#[async_trait]
+impl CloudHsmv2 for CloudHsmv2Client {
+    /// <p>Copy an AWS CloudHSM cluster backup to a different region.</p>
+    async fn copy_backup_to_region(
+        &self,
+        input: CopyBackupToRegionRequest,
+    ) -> Result<CopyBackupToRegionResponse, RusotoError<CopyBackupToRegionError>> {
+        self.copy_backup_to_region_with_metadata(input)
             .await
-            .map_err(RusotoError::from)?;
-        if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<UntagResourceResponse, _>()
-        } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(UntagResourceError::from_response(response))
-        }
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Creates a new AWS CloudHSM cluster.</p>
+    async fn create_cluster(
+        &self,
+        input: CreateClusterRequest,
+    ) -> Result<CreateClusterResponse, RusotoError<CreateClusterError>> {
+        self.create_cluster_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Creates a new hardware security module (HSM) in the specified AWS CloudHSM cluster.</p>
+    async fn create_hsm(
+        &self,
+        input: CreateHsmRequest,
+    ) -> Result<CreateHsmResponse, RusotoError<CreateHsmError>> {
+        self.create_hsm_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes a specified AWS CloudHSM backup. A backup can be restored up to 7 days after the DeleteBackup request is made. For more information on restoring a backup, see <a>RestoreBackup</a>.</p>
+    async fn delete_backup(
+        &self,
+        input: DeleteBackupRequest,
+    ) -> Result<DeleteBackupResponse, RusotoError<DeleteBackupError>> {
+        self.delete_backup_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes the specified AWS CloudHSM cluster. Before you can delete a cluster, you must delete all HSMs in the cluster. To see if the cluster contains any HSMs, use <a>DescribeClusters</a>. To delete an HSM, use <a>DeleteHsm</a>.</p>
+    async fn delete_cluster(
+        &self,
+        input: DeleteClusterRequest,
+    ) -> Result<DeleteClusterResponse, RusotoError<DeleteClusterError>> {
+        self.delete_cluster_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes the specified HSM. To specify an HSM, you can use its identifier (ID), the IP address of the HSM's elastic network interface (ENI), or the ID of the HSM's ENI. You need to specify only one of these values. To find these values, use <a>DescribeClusters</a>.</p>
+    async fn delete_hsm(
+        &self,
+        input: DeleteHsmRequest,
+    ) -> Result<DeleteHsmResponse, RusotoError<DeleteHsmError>> {
+        self.delete_hsm_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Gets information about backups of AWS CloudHSM clusters.</p> <p>This is a paginated operation, which means that each response might contain only a subset of all the backups. When the response contains only a subset of backups, it includes a <code>NextToken</code> value. Use this value in a subsequent <code>DescribeBackups</code> request to get more backups. When you receive a response with no <code>NextToken</code> (or an empty or null value), that means there are no more backups to get.</p>
+    async fn describe_backups(
+        &self,
+        input: DescribeBackupsRequest,
+    ) -> Result<DescribeBackupsResponse, RusotoError<DescribeBackupsError>> {
+        self.describe_backups_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Gets information about AWS CloudHSM clusters.</p> <p>This is a paginated operation, which means that each response might contain only a subset of all the clusters. When the response contains only a subset of clusters, it includes a <code>NextToken</code> value. Use this value in a subsequent <code>DescribeClusters</code> request to get more clusters. When you receive a response with no <code>NextToken</code> (or an empty or null value), that means there are no more clusters to get.</p>
+    async fn describe_clusters(
+        &self,
+        input: DescribeClustersRequest,
+    ) -> Result<DescribeClustersResponse, RusotoError<DescribeClustersError>> {
+        self.describe_clusters_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Claims an AWS CloudHSM cluster by submitting the cluster certificate issued by your issuing certificate authority (CA) and the CA's root certificate. Before you can claim a cluster, you must sign the cluster's certificate signing request (CSR) with your issuing CA. To get the cluster's CSR, use <a>DescribeClusters</a>.</p>
+    async fn initialize_cluster(
+        &self,
+        input: InitializeClusterRequest,
+    ) -> Result<InitializeClusterResponse, RusotoError<InitializeClusterError>> {
+        self.initialize_cluster_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Gets a list of tags for the specified AWS CloudHSM cluster.</p> <p>This is a paginated operation, which means that each response might contain only a subset of all the tags. When the response contains only a subset of tags, it includes a <code>NextToken</code> value. Use this value in a subsequent <code>ListTags</code> request to get more tags. When you receive a response with no <code>NextToken</code> (or an empty or null value), that means there are no more tags to get.</p>
+    async fn list_tags(
+        &self,
+        input: ListTagsRequest,
+    ) -> Result<ListTagsResponse, RusotoError<ListTagsError>> {
+        self.list_tags_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Restores a specified AWS CloudHSM backup that is in the <code>PENDING_DELETION</code> state. For mor information on deleting a backup, see <a>DeleteBackup</a>.</p>
+    async fn restore_backup(
+        &self,
+        input: RestoreBackupRequest,
+    ) -> Result<RestoreBackupResponse, RusotoError<RestoreBackupError>> {
+        self.restore_backup_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Adds or overwrites one or more tags for the specified AWS CloudHSM cluster.</p>
+    async fn tag_resource(
+        &self,
+        input: TagResourceRequest,
+    ) -> Result<TagResourceResponse, RusotoError<TagResourceError>> {
+        self.tag_resource_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Removes the specified tag or tags from the specified AWS CloudHSM cluster.</p>
+    async fn untag_resource(
+        &self,
+        input: UntagResourceRequest,
+    ) -> Result<UntagResourceResponse, RusotoError<UntagResourceError>> {
+        self.untag_resource_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
     }
 }
@@ -16,8 +16,8 @@ use std::fmt;
 use async_trait::async_trait;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
-use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest, ResponseMetadata};
+use rusoto_core::{Client, ErrorMetadata, RusotoError};
 
 use rusoto_core::param::{Params, ServiceParams};
 use rusoto_core::proto::xml::error::*;
@@ -4094,6 +4094,7 @@ impl WordDeserializer {
 }
 /// Errors returned by BuildSuggesters
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum BuildSuggestersError {
     /// <p>An error occurred while processing the request.</p>
     Base(String),
@@ -4101,6 +4102,8 @@ pub enum BuildSuggestersError {
     Internal(String),
     /// <p>The request was rejected because it attempted to reference a resource that does not exist.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl BuildSuggestersError {
@@ -4112,21 +4115,24 @@ impl BuildSuggestersError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "BaseException" => {
-                        return RusotoError::Service(BuildSuggestersError::Base(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            BuildSuggestersError::Base(parsed_error.message),
+                            &res,
+                        )
                     }
                     "InternalException" => {
-                        return RusotoError::Service(BuildSuggestersError::Internal(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            BuildSuggestersError::Internal(parsed_error.message),
+                            &res,
+                        )
                     }
                     "ResourceNotFound" => {
-                        return RusotoError::Service(BuildSuggestersError::ResourceNotFound(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            BuildSuggestersError::ResourceNotFound(parsed_error.message),
+                            &res,
+                        )
                     }
-                    _ => {}
+                    _ => return RusotoError::unhandled(BuildSuggestersError::Unhandled, &res),
                 }
             }
         }
@@ -4148,12 +4154,14 @@ impl fmt::Display for BuildSuggestersError {
             BuildSuggestersError::Base(ref cause) => write!(f, "{}", cause),
             BuildSuggestersError::Internal(ref cause) => write!(f, "{}", cause),
             BuildSuggestersError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            BuildSuggestersError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for BuildSuggestersError {}
 /// Errors returned by CreateDomain
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateDomainError {
     /// <p>An error occurred while processing the request.</p>
     Base(String),
@@ -4161,6 +4169,8 @@ pub enum CreateDomainError {
     Internal(String),
     /// <p>The request was rejected because a resource limit has already been met.</p>
     LimitExceeded(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateDomainError {
@@ -4172,19 +4182,24 @@ impl CreateDomainError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "BaseException" => {
-                        return RusotoError::Service(CreateDomainError::Base(parsed_error.message))
+                        return RusotoError::service(
+                            CreateDomainError::Base(parsed_error.message),
+                            &res,
+                        )
                     }
                     "InternalException" => {
-                        return RusotoError::Service(CreateDomainError::Internal(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            CreateDomainError::Internal(parsed_error.message),
+                            &res,
+                        )
                     }
                     "LimitExceeded" => {
-                        return RusotoError::Service(CreateDomainError::LimitExceeded(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            CreateDomainError::LimitExceeded(parsed_error.message),
+                            &res,
+                        )
                     }
-                    _ => {}
+                    _ => return RusotoError::unhandled(CreateDomainError::Unhandled, &res),
                 }
             }
         }
@@ -4206,12 +4221,14 @@ impl fmt::Display for CreateDomainError {
             CreateDomainError::Base(ref cause) => write!(f, "{}", cause),
             CreateDomainError::Internal(ref cause) => write!(f, "{}", cause),
             CreateDomainError::LimitExceeded(ref cause) => write!(f, "{}", cause),
+            CreateDomainError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateDomainError {}
 /// Errors returned by DefineAnalysisScheme
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DefineAnalysisSchemeError {
     /// <p>An error occurred while processing the request.</p>
     Base(String),
@@ -4223,6 +4240,8 @@ pub enum DefineAnalysisSchemeError {
     LimitExceeded(String),
     /// <p>The request was rejected because it attempted to reference a resource that does not exist.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DefineAnalysisSchemeError {
@@ -4234,31 +4253,36 @@ impl DefineAnalysisSchemeError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "BaseException" => {
-                        return RusotoError::Service(DefineAnalysisSchemeError::Base(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            DefineAnalysisSchemeError::Base(parsed_error.message),
+                            &res,
+                        )
                     }
                     "InternalException" => {
-                        return RusotoError::Service(DefineAnalysisSchemeError::Internal(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            DefineAnalysisSchemeError::Internal(parsed_error.message),
+                            &res,
+                        )
                     }
                     "InvalidType" => {
-                        return RusotoError::Service(DefineAnalysisSchemeError::InvalidType(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            DefineAnalysisSchemeError::InvalidType(parsed_error.message),
+                            &res,
+                        )
                     }
                     "LimitExceeded" => {
-                        return RusotoError::Service(DefineAnalysisSchemeError::LimitExceeded(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            DefineAnalysisSchemeError::LimitExceeded(parsed_error.message),
+                            &res,
+                        )
                     }
                     "ResourceNotFound" => {
-                        return RusotoError::Service(DefineAnalysisSchemeError::ResourceNotFound(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            DefineAnalysisSchemeError::ResourceNotFound(parsed_error.message),
+                            &res,
+                        )
                     }
-                    _ => {}
+                    _ => return RusotoError::unhandled(DefineAnalysisSchemeError::Unhandled, &res),
                 }
             }
         }
@@ -4282,12 +4306,14 @@ impl fmt::Display for DefineAnalysisSchemeError {
             DefineAnalysisSchemeError::InvalidType(ref cause) => write!(f, "{}", cause),
             DefineAnalysisSchemeError::LimitExceeded(ref cause) => write!(f, "{}", cause),
             DefineAnalysisSchemeError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            DefineAnalysisSchemeError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DefineAnalysisSchemeError {}
 /// Errors returned by DefineExpression
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DefineExpressionError {
     /// <p>An error occurred while processing the request.</p>
     Base(String),
@@ -4299,6 +4325,8 @@ pub enum DefineExpressionError {
     LimitExceeded(String),
     /// <p>The request was rejected because it attempted to reference a resource that does not exist.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DefineExpressionError {
@@ -4310,31 +4338,36 @@ impl DefineExpressionError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "BaseException" => {
-                        return RusotoError::Service(DefineExpressionError::Base(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            DefineExpressionError::Base(parsed_error.message),
+                            &res,
+                        )
                     }
                     "InternalException" => {
-                        return RusotoError::Service(DefineExpressionError::Internal(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            DefineExpressionError::Internal(parsed_error.message),
+                            &res,
+                        )
                     }
                     "InvalidType" => {
-                        return RusotoError::Service(DefineExpressionError::InvalidType(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            DefineExpressionError::InvalidType(parsed_error.message),
+                            &res,
+                        )
                     }
                     "LimitExceeded" => {
-                        return RusotoError::Service(DefineExpressionError::LimitExceeded(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            DefineExpressionError::LimitExceeded(parsed_error.message),
+                            &res,
+                        )
                     }
                     "ResourceNotFound" => {
-                        return RusotoError::Service(DefineExpressionError::ResourceNotFound(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            DefineExpressionError::ResourceNotFound(parsed_error.message),
+                            &res,
+                        )
                     }
-                    _ => {}
+                    _ => return RusotoError::unhandled(DefineExpressionError::Unhandled, &res),
                 }
             }
         }
@@ -4358,12 +4391,14 @@ impl fmt::Display for DefineExpressionError {
             DefineExpressionError::InvalidType(ref cause) => write!(f, "{}", cause),
             DefineExpressionError::LimitExceeded(ref cause) => write!(f, "{}", cause),
             DefineExpressionError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            DefineExpressionError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DefineExpressionError {}
 /// Errors returned by DefineIndexField
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DefineIndexFieldError {
     /// <p>An error occurred while processing the request.</p>
     Base(String),
@@ -4375,6 +4410,8 @@ pub enum DefineIndexFieldError {
     LimitExceeded(String),
     /// <p>The request was rejected because it attempted to reference a resource that does not exist.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DefineIndexFieldError {
@@ -4386,31 +4423,36 @@ impl DefineIndexFieldError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "BaseException" => {
-                        return RusotoError::Service(DefineIndexFieldError::Base(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            DefineIndexFieldError::Base(parsed_error.message),
+                            &res,
+                        )
                     }
                     "InternalException" => {
-                        return RusotoError::Service(DefineIndexFieldError::Internal(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            DefineIndexFieldError::Internal(parsed_error.message),
+                            &res,
+                        )
                     }
                     "InvalidType" => {
-                        return RusotoError::Service(DefineIndexFieldError::InvalidType(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            DefineIndexFieldError::InvalidType(parsed_error.message),
+                            &res,
+                        )
                     }
                     "LimitExceeded" => {
-                        return RusotoError::Service(DefineIndexFieldError::LimitExceeded(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            DefineIndexFieldError::LimitExceeded(parsed_error.message),
+                            &res,
+                        )
                     }
                     "ResourceNotFound" => {
-                        return RusotoError::Service(DefineIndexFieldError::ResourceNotFound(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            DefineIndexFieldError::ResourceNotFound(parsed_error.message),
+                            &res,
+                        )
                     }
-                    _ => {}
+                    _ => return RusotoError::unhandled(DefineIndexFieldError::Unhandled, &res),
                 }
             }
         }
@@ -4434,12 +4476,14 @@ impl fmt::Display for DefineIndexFieldError {
             DefineIndexFieldError::InvalidType(ref cause) => write!(f, "{}", cause),
             DefineIndexFieldError::LimitExceeded(ref cause) => write!(f, "{}", cause),
             DefineIndexFieldError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            DefineIndexFieldError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DefineIndexFieldError {}
 /// Errors returned by DefineSuggester
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DefineSuggesterError {
     /// <p>An error occurred while processing the request.</p>
     Base(String),
@@ -4451,6 +4495,8 @@ pub enum DefineSuggesterError {
     LimitExceeded(String),
     /// <p>The request was rejected because it attempted to reference a resource that does not exist.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DefineSuggesterError {
@@ -4462,31 +4508,36 @@ impl DefineSuggesterError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "BaseException" => {
-                        return RusotoError::Service(DefineSuggesterError::Base(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            DefineSuggesterError::Base(parsed_error.message),
+                            &res,
+                        )
                     }
                     "InternalException" => {
-                        return RusotoError::Service(DefineSuggesterError::Internal(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            DefineSuggesterError::Internal(parsed_error.message),
+                            &res,
+                        )
                     }
                     "InvalidType" => {
-                        return RusotoError::Service(DefineSuggesterError::InvalidType(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            DefineSuggesterError::InvalidType(parsed_error.message),
+                            &res,
+                        )
                     }
                     "LimitExceeded" => {
-                        return RusotoError::Service(DefineSuggesterError::LimitExceeded(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            DefineSuggesterError::LimitExceeded(parsed_error.message),
+                            &res,
+                        )
                     }
                     "ResourceNotFound" => {
-                        return RusotoError::Service(DefineSuggesterError::ResourceNotFound(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            DefineSuggesterError::ResourceNotFound(parsed_error.message),
+                            &res,
+                        )
                     }
-                    _ => {}
+                    _ => return RusotoError::unhandled(DefineSuggesterError::Unhandled, &res),
                 }
             }
         }
@@ -4510,12 +4561,14 @@ impl fmt::Display for DefineSuggesterError {
             DefineSuggesterError::InvalidType(ref cause) => write!(f, "{}", cause),
             DefineSuggesterError::LimitExceeded(ref cause) => write!(f, "{}", cause),
             DefineSuggesterError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            DefineSuggesterError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DefineSuggesterError {}
 /// Errors returned by DeleteAnalysisScheme
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteAnalysisSchemeError {
     /// <p>An error occurred while processing the request.</p>
     Base(String),
@@ -4525,6 +4578,8 @@ pub enum DeleteAnalysisSchemeError {
     InvalidType(String),
     /// <p>The request was rejected because it attempted to reference a resource that does not exist.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteAnalysisSchemeError {
@@ -4536,26 +4591,30 @@ impl DeleteAnalysisSchemeError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "BaseException" => {
-                        return RusotoError::Service(DeleteAnalysisSchemeError::Base(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            DeleteAnalysisSchemeError::Base(parsed_error.message),
+                            &res,
+                        )
                     }
                     "InternalException" => {
-                        return RusotoError::Service(DeleteAnalysisSchemeError::Internal(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            DeleteAnalysisSchemeError::Internal(parsed_error.message),
+                            &res,
+                        )
                     }
                     "InvalidType" => {
-                        return RusotoError::Service(DeleteAnalysisSchemeError::InvalidType(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            DeleteAnalysisSchemeError::InvalidType(parsed_error.message),
+                            &res,
+                        )
                     }
                     "ResourceNotFound" => {
-                        return RusotoError::Service(DeleteAnalysisSchemeError::ResourceNotFound(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            DeleteAnalysisSchemeError::ResourceNotFound(parsed_error.message),
+                            &res,
+                        )
                     }
-                    _ => {}
+                    _ => return RusotoError::unhandled(DeleteAnalysisSchemeError::Unhandled, &res),
                 }
             }
         }
@@ -4578,17 +4637,21 @@ impl fmt::Display for DeleteAnalysisSchemeError {
             DeleteAnalysisSchemeError::Internal(ref cause) => write!(f, "{}", cause),
             DeleteAnalysisSchemeError::InvalidType(ref cause) => write!(f, "{}", cause),
             DeleteAnalysisSchemeError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            DeleteAnalysisSchemeError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteAnalysisSchemeError {}
 /// Errors returned by DeleteDomain
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteDomainError {
     /// <p>An error occurred while processing the request.</p>
     Base(String),
     /// <p>An internal error occurred while processing the request. If this problem persists, report an issue from the <a href="http://status.aws.amazon.com/" target="_blank">Service Health Dashboard</a>.</p>
     Internal(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteDomainError {
@@ -4600,14 +4663,18 @@ impl DeleteDomainError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "BaseException" => {
-                        return RusotoError::Service(DeleteDomainError::Base(parsed_error.message))
+                        return RusotoError::service(
+                            DeleteDomainError::Base(parsed_error.message),
+                            &res,
+                        )
                     }
                     "InternalException" => {
-                        return RusotoError::Service(DeleteDomainError::Internal(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            DeleteDomainError::Internal(parsed_error.message),
+                            &res,
+                        )
                     }
-                    _ => {}
+                    _ => return RusotoError::unhandled(DeleteDomainError::Unhandled, &res),
                 }
             }
         }
@@ -4628,12 +4695,14 @@ impl fmt::Display for DeleteDomainError {
         match *self {
             DeleteDomainError::Base(ref cause) => write!(f, "{}", cause),
             DeleteDomainError::Internal(ref cause) => write!(f, "{}", cause),
+            DeleteDomainError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteDomainError {}
 /// Errors returned by DeleteExpression
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteExpressionError {
     /// <p>An error occurred while processing the request.</p>
     Base(String),
@@ -4643,6 +4712,8 @@ pub enum DeleteExpressionError {
     InvalidType(String),
     /// <p>The request was rejected because it attempted to reference a resource that does not exist.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteExpressionError {
@@ -4654,26 +4725,30 @@ impl DeleteExpressionError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "BaseException" => {
-                        return RusotoError::Service(DeleteExpressionError::Base(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            DeleteExpressionError::Base(parsed_error.message),
+                            &res,
+                        )
                     }
                     "InternalException" => {
-                        return RusotoError::Service(DeleteExpressionError::Internal(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            DeleteExpressionError::Internal(parsed_error.message),
+                            &res,
+                        )
                     }
                     "InvalidType" => {
-                        return RusotoError::Service(DeleteExpressionError::InvalidType(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            DeleteExpressionError::InvalidType(parsed_error.message),
+                            &res,
+                        )
                     }
                     "ResourceNotFound" => {
-                        return RusotoError::Service(DeleteExpressionError::ResourceNotFound(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            DeleteExpressionError::ResourceNotFound(parsed_error.message),
+                            &res,
+                        )
                     }
-                    _ => {}
+                    _ => return RusotoError::unhandled(DeleteExpressionError::Unhandled, &res),
                 }
             }
         }
@@ -4696,12 +4771,14 @@ impl fmt::Display for DeleteExpressionError {
             DeleteExpressionError::Internal(ref cause) => write!(f, "{}", cause),
             DeleteExpressionError::InvalidType(ref cause) => write!(f, "{}", cause),
             DeleteExpressionError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            DeleteExpressionError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteExpressionError {}
 /// Errors returned by DeleteIndexField
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteIndexFieldError {
     /// <p>An error occurred while processing the request.</p>
     Base(String),
@@ -4711,6 +4788,8 @@ pub enum DeleteIndexFieldError {
     InvalidType(String),
     /// <p>The request was rejected because it attempted to reference a resource that does not exist.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteIndexFieldError {
@@ -4722,26 +4801,30 @@ impl DeleteIndexFieldError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "BaseException" => {
-                        return RusotoError::Service(DeleteIndexFieldError::Base(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            DeleteIndexFieldError::Base(parsed_error.message),
+                            &res,
+                        )
                     }
                     "InternalException" => {
-                        return RusotoError::Service(DeleteIndexFieldError::Internal(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            DeleteIndexFieldError::Internal(parsed_error.message),
+                            &res,
+                        )
                     }
                     "InvalidType" => {
-                        return RusotoError::Service(DeleteIndexFieldError::InvalidType(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            DeleteIndexFieldError::InvalidType(parsed_error.message),
+                            &res,
+                        )
                     }
                     "ResourceNotFound" => {
-                        return RusotoError::Service(DeleteIndexFieldError::ResourceNotFound(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            DeleteIndexFieldError::ResourceNotFound(parsed_error.message),
+                            &res,
+                        )
                     }
-                    _ => {}
+                    _ => return RusotoError::unhandled(DeleteIndexFieldError::Unhandled, &res),
                 }
             }
         }
@@ -4764,12 +4847,14 @@ impl fmt::Display for DeleteIndexFieldError {
             DeleteIndexFieldError::Internal(ref cause) => write!(f, "{}", cause),
             DeleteIndexFieldError::InvalidType(ref cause) => write!(f, "{}", cause),
             DeleteIndexFieldError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            DeleteIndexFieldError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteIndexFieldError {}
 /// Errors returned by DeleteSuggester
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteSuggesterError {
     /// <p>An error occurred while processing the request.</p>
     Base(String),
@@ -4779,6 +4864,8 @@ pub enum DeleteSuggesterError {
     InvalidType(String),
     /// <p>The request was rejected because it attempted to reference a resource that does not exist.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteSuggesterError {
@@ -4790,26 +4877,30 @@ impl DeleteSuggesterError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "BaseException" => {
-                        return RusotoError::Service(DeleteSuggesterError::Base(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            DeleteSuggesterError::Base(parsed_error.message),
+                            &res,
+                        )
                     }
                     "InternalException" => {
-                        return RusotoError::Service(DeleteSuggesterError::Internal(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            DeleteSuggesterError::Internal(parsed_error.message),
+                            &res,
+                        )
                     }
                     "InvalidType" => {
-                        return RusotoError::Service(DeleteSuggesterError::InvalidType(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            DeleteSuggesterError::InvalidType(parsed_error.message),
+                            &res,
+                        )
                     }
                     "ResourceNotFound" => {
-                        return RusotoError::Service(DeleteSuggesterError::ResourceNotFound(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            DeleteSuggesterError::ResourceNotFound(parsed_error.message),
+                            &res,
+                        )
                     }
-                    _ => {}
+                    _ => return RusotoError::unhandled(DeleteSuggesterError::Unhandled, &res),
                 }
             }
         }
@@ -4832,12 +4923,14 @@ impl fmt::Display for DeleteSuggesterError {
             DeleteSuggesterError::Internal(ref cause) => write!(f, "{}", cause),
             DeleteSuggesterError::InvalidType(ref cause) => write!(f, "{}", cause),
             DeleteSuggesterError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            DeleteSuggesterError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteSuggesterError {}
 /// Errors returned by DescribeAnalysisSchemes
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeAnalysisSchemesError {
     /// <p>An error occurred while processing the request.</p>
     Base(String),
@@ -4845,6 +4938,8 @@ pub enum DescribeAnalysisSchemesError {
     Internal(String),
     /// <p>The request was rejected because it attempted to reference a resource that does not exist.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeAnalysisSchemesError {
@@ -4856,21 +4951,29 @@ impl DescribeAnalysisSchemesError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "BaseException" => {
-                        return RusotoError::Service(DescribeAnalysisSchemesError::Base(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            DescribeAnalysisSchemesError::Base(parsed_error.message),
+                            &res,
+                        )
                     }
                     "InternalException" => {
-                        return RusotoError::Service(DescribeAnalysisSchemesError::Internal(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            DescribeAnalysisSchemesError::Internal(parsed_error.message),
+                            &res,
+                        )
                     }
                     "ResourceNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             DescribeAnalysisSchemesError::ResourceNotFound(parsed_error.message),
+                            &res,
+                        )
+                    }
+                    _ => {
+                        return RusotoError::unhandled(
+                            DescribeAnalysisSchemesError::Unhandled,
+                            &res,
                         )
                     }
-                    _ => {}
                 }
             }
         }
@@ -4892,12 +4995,14 @@ impl fmt::Display for DescribeAnalysisSchemesError {
             DescribeAnalysisSchemesError::Base(ref cause) => write!(f, "{}", cause),
             DescribeAnalysisSchemesError::Internal(ref cause) => write!(f, "{}", cause),
             DescribeAnalysisSchemesError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            DescribeAnalysisSchemesError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeAnalysisSchemesError {}
 /// Errors returned by DescribeAvailabilityOptions
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeAvailabilityOptionsError {
     /// <p>An error occurred while processing the request.</p>
     Base(String),
@@ -4911,6 +5016,8 @@ pub enum DescribeAvailabilityOptionsError {
     LimitExceeded(String),
     /// <p>The request was rejected because it attempted to reference a resource that does not exist.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeAvailabilityOptionsError {
@@ -4924,40 +5031,51 @@ impl DescribeAvailabilityOptionsError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "BaseException" => {
-                        return RusotoError::Service(DescribeAvailabilityOptionsError::Base(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            DescribeAvailabilityOptionsError::Base(parsed_error.message),
+                            &res,
+                        )
                     }
                     "DisabledAction" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             DescribeAvailabilityOptionsError::DisabledOperation(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "InternalException" => {
-                        return RusotoError::Service(DescribeAvailabilityOptionsError::Internal(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            DescribeAvailabilityOptionsError::Internal(parsed_error.message),
+                            &res,
+                        )
                     }
                     "InvalidType" => {
-                        return RusotoError::Service(DescribeAvailabilityOptionsError::InvalidType(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            DescribeAvailabilityOptionsError::InvalidType(parsed_error.message),
+                            &res,
+                        )
                     }
                     "LimitExceeded" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             DescribeAvailabilityOptionsError::LimitExceeded(parsed_error.message),
+                            &res,
                         )
                     }
                     "ResourceNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             DescribeAvailabilityOptionsError::ResourceNotFound(
                                 parsed_error.message,
                             ),
+                            &res,
+                        )
+                    }
+                    _ => {
+                        return RusotoError::unhandled(
+                            DescribeAvailabilityOptionsError::Unhandled,
+                            &res,
                         )
                     }
-                    _ => {}
                 }
             }
         }
@@ -4984,12 +5102,14 @@ impl fmt::Display for DescribeAvailabilityOptionsError {
             DescribeAvailabilityOptionsError::InvalidType(ref cause) => write!(f, "{}", cause),
             DescribeAvailabilityOptionsError::LimitExceeded(ref cause) => write!(f, "{}", cause),
             DescribeAvailabilityOptionsError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            DescribeAvailabilityOptionsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeAvailabilityOptionsError {}
 /// Errors returned by DescribeDomainEndpointOptions
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeDomainEndpointOptionsError {
     /// <p>An error occurred while processing the request.</p>
     Base(String),
@@ -5001,6 +5121,8 @@ pub enum DescribeDomainEndpointOptionsError {
     LimitExceeded(String),
     /// <p>The request was rejected because it attempted to reference a resource that does not exist.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeDomainEndpointOptionsError {
@@ -5014,35 +5136,45 @@ impl DescribeDomainEndpointOptionsError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "BaseException" => {
-                        return RusotoError::Service(DescribeDomainEndpointOptionsError::Base(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            DescribeDomainEndpointOptionsError::Base(parsed_error.message),
+                            &res,
+                        )
                     }
                     "DisabledAction" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             DescribeDomainEndpointOptionsError::DisabledOperation(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "InternalException" => {
-                        return RusotoError::Service(DescribeDomainEndpointOptionsError::Internal(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            DescribeDomainEndpointOptionsError::Internal(parsed_error.message),
+                            &res,
+                        )
                     }
                     "LimitExceeded" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             DescribeDomainEndpointOptionsError::LimitExceeded(parsed_error.message),
+                            &res,
                         )
                     }
                     "ResourceNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             DescribeDomainEndpointOptionsError::ResourceNotFound(
                                 parsed_error.message,
                             ),
+                            &res,
+                        )
+                    }
+                    _ => {
+                        return RusotoError::unhandled(
+                            DescribeDomainEndpointOptionsError::Unhandled,
+                            &res,
                         )
                     }
-                    _ => {}
                 }
             }
         }
@@ -5070,17 +5202,23 @@ impl fmt::Display for DescribeDomainEndpointOptionsError {
             DescribeDomainEndpointOptionsError::ResourceNotFound(ref cause) => {
                 write!(f, "{}", cause)
             }
+            DescribeDomainEndpointOptionsError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for DescribeDomainEndpointOptionsError {}
 /// Errors returned by DescribeDomains
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeDomainsError {
     /// <p>An error occurred while processing the request.</p>
     Base(String),
     /// <p>An internal error occurred while processing the request. If this problem persists, report an issue from the <a href="http://status.aws.amazon.com/" target="_blank">Service Health Dashboard</a>.</p>
     Internal(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeDomainsError {
@@ -5092,16 +5230,18 @@ impl DescribeDomainsError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "BaseException" => {
-                        return RusotoError::Service(DescribeDomainsError::Base(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            DescribeDomainsError::Base(parsed_error.message),
+                            &res,
+                        )
                     }
                     "InternalException" => {
-                        return RusotoError::Service(DescribeDomainsError::Internal(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            DescribeDomainsError::Internal(parsed_error.message),
+                            &res,
+                        )
                     }
-                    _ => {}
+                    _ => return RusotoError::unhandled(DescribeDomainsError::Unhandled, &res),
                 }
             }
         }
@@ -5122,12 +5262,14 @@ impl fmt::Display for DescribeDomainsError {
         match *self {
             DescribeDomainsError::Base(ref cause) => write!(f, "{}", cause),
             DescribeDomainsError::Internal(ref cause) => write!(f, "{}", cause),
+            DescribeDomainsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeDomainsError {}
 /// Errors returned by DescribeExpressions
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeExpressionsError {
     /// <p>An error occurred while processing the request.</p>
     Base(String),
@@ -5135,6 +5277,8 @@ pub enum DescribeExpressionsError {
     Internal(String),
     /// <p>The request was rejected because it attempted to reference a resource that does not exist.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeExpressionsError {
@@ -5146,21 +5290,24 @@ impl DescribeExpressionsError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "BaseException" => {
-                        return RusotoError::Service(DescribeExpressionsError::Base(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            DescribeExpressionsError::Base(parsed_error.message),
+                            &res,
+                        )
                     }
                     "InternalException" => {
-                        return RusotoError::Service(DescribeExpressionsError::Internal(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            DescribeExpressionsError::Internal(parsed_error.message),
+                            &res,
+                        )
                     }
                     "ResourceNotFound" => {
-                        return RusotoError::Service(DescribeExpressionsError::ResourceNotFound(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            DescribeExpressionsError::ResourceNotFound(parsed_error.message),
+                            &res,
+                        )
                     }
-                    _ => {}
+                    _ => return RusotoError::unhandled(DescribeExpressionsError::Unhandled, &res),
                 }
             }
         }
@@ -5182,12 +5329,14 @@ impl fmt::Display for DescribeExpressionsError {
             DescribeExpressionsError::Base(ref cause) => write!(f, "{}", cause),
             DescribeExpressionsError::Internal(ref cause) => write!(f, "{}", cause),
             DescribeExpressionsError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            DescribeExpressionsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeExpressionsError {}
 /// Errors returned by DescribeIndexFields
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeIndexFieldsError {
     /// <p>An error occurred while processing the request.</p>
     Base(String),
@@ -5195,6 +5344,8 @@ pub enum DescribeIndexFieldsError {
     Internal(String),
     /// <p>The request was rejected because it attempted to reference a resource that does not exist.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeIndexFieldsError {
@@ -5206,21 +5357,24 @@ impl DescribeIndexFieldsError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "BaseException" => {
-                        return RusotoError::Service(DescribeIndexFieldsError::Base(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            DescribeIndexFieldsError::Base(parsed_error.message),
+                            &res,
+                        )
                     }
                     "InternalException" => {
-                        return RusotoError::Service(DescribeIndexFieldsError::Internal(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            DescribeIndexFieldsError::Internal(parsed_error.message),
+                            &res,
+                        )
                     }
                     "ResourceNotFound" => {
-                        return RusotoError::Service(DescribeIndexFieldsError::ResourceNotFound(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            DescribeIndexFieldsError::ResourceNotFound(parsed_error.message),
+                            &res,
+                        )
                     }
-                    _ => {}
+                    _ => return RusotoError::unhandled(DescribeIndexFieldsError::Unhandled, &res),
                 }
             }
         }
@@ -5242,12 +5396,14 @@ impl fmt::Display for DescribeIndexFieldsError {
             DescribeIndexFieldsError::Base(ref cause) => write!(f, "{}", cause),
             DescribeIndexFieldsError::Internal(ref cause) => write!(f, "{}", cause),
             DescribeIndexFieldsError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            DescribeIndexFieldsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeIndexFieldsError {}
 /// Errors returned by DescribeScalingParameters
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeScalingParametersError {
     /// <p>An error occurred while processing the request.</p>
     Base(String),
@@ -5255,6 +5411,8 @@ pub enum DescribeScalingParametersError {
     Internal(String),
     /// <p>The request was rejected because it attempted to reference a resource that does not exist.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeScalingParametersError {
@@ -5266,21 +5424,29 @@ impl DescribeScalingParametersError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "BaseException" => {
-                        return RusotoError::Service(DescribeScalingParametersError::Base(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            DescribeScalingParametersError::Base(parsed_error.message),
+                            &res,
+                        )
                     }
                     "InternalException" => {
-                        return RusotoError::Service(DescribeScalingParametersError::Internal(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            DescribeScalingParametersError::Internal(parsed_error.message),
+                            &res,
+                        )
                     }
                     "ResourceNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             DescribeScalingParametersError::ResourceNotFound(parsed_error.message),
+                            &res,
+                        )
+                    }
+                    _ => {
+                        return RusotoError::unhandled(
+                            DescribeScalingParametersError::Unhandled,
+                            &res,
                         )
                     }
-                    _ => {}
                 }
             }
         }
@@ -5302,12 +5468,14 @@ impl fmt::Display for DescribeScalingParametersError {
             DescribeScalingParametersError::Base(ref cause) => write!(f, "{}", cause),
             DescribeScalingParametersError::Internal(ref cause) => write!(f, "{}", cause),
             DescribeScalingParametersError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            DescribeScalingParametersError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeScalingParametersError {}
 /// Errors returned by DescribeServiceAccessPolicies
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeServiceAccessPoliciesError {
     /// <p>An error occurred while processing the request.</p>
     Base(String),
@@ -5315,6 +5483,8 @@ pub enum DescribeServiceAccessPoliciesError {
     Internal(String),
     /// <p>The request was rejected because it attempted to reference a resource that does not exist.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeServiceAccessPoliciesError {
@@ -5328,23 +5498,31 @@ impl DescribeServiceAccessPoliciesError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "BaseException" => {
-                        return RusotoError::Service(DescribeServiceAccessPoliciesError::Base(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            DescribeServiceAccessPoliciesError::Base(parsed_error.message),
+                            &res,
+                        )
                     }
                     "InternalException" => {
-                        return RusotoError::Service(DescribeServiceAccessPoliciesError::Internal(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            DescribeServiceAccessPoliciesError::Internal(parsed_error.message),
+                            &res,
+                        )
                     }
                     "ResourceNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             DescribeServiceAccessPoliciesError::ResourceNotFound(
                                 parsed_error.message,
                             ),
+                            &res,
+                        )
+                    }
+                    _ => {
+                        return RusotoError::unhandled(
+                            DescribeServiceAccessPoliciesError::Unhandled,
+                            &res,
                         )
                     }
-                    _ => {}
                 }
             }
         }
@@ -5368,12 +5546,16 @@ impl fmt::Display for DescribeServiceAccessPoliciesError {
             DescribeServiceAccessPoliciesError::ResourceNotFound(ref cause) => {
                 write!(f, "{}", cause)
             }
+            DescribeServiceAccessPoliciesError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for DescribeServiceAccessPoliciesError {}
 /// Errors returned by DescribeSuggesters
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeSuggestersError {
     /// <p>An error occurred while processing the request.</p>
     Base(String),
@@ -5381,6 +5563,8 @@ pub enum DescribeSuggestersError {
     Internal(String),
     /// <p>The request was rejected because it attempted to reference a resource that does not exist.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeSuggestersError {
@@ -5392,21 +5576,24 @@ impl DescribeSuggestersError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "BaseException" => {
-                        return RusotoError::Service(DescribeSuggestersError::Base(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            DescribeSuggestersError::Base(parsed_error.message),
+                            &res,
+                        )
                     }
                     "InternalException" => {
-                        return RusotoError::Service(DescribeSuggestersError::Internal(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            DescribeSuggestersError::Internal(parsed_error.message),
+                            &res,
+                        )
                     }
                     "ResourceNotFound" => {
-                        return RusotoError::Service(DescribeSuggestersError::ResourceNotFound(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            DescribeSuggestersError::ResourceNotFound(parsed_error.message),
+                            &res,
+                        )
                     }
-                    _ => {}
+                    _ => return RusotoError::unhandled(DescribeSuggestersError::Unhandled, &res),
                 }
             }
         }
@@ -5428,12 +5615,14 @@ impl fmt::Display for DescribeSuggestersError {
             DescribeSuggestersError::Base(ref cause) => write!(f, "{}", cause),
             DescribeSuggestersError::Internal(ref cause) => write!(f, "{}", cause),
             DescribeSuggestersError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            DescribeSuggestersError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeSuggestersError {}
 /// Errors returned by IndexDocuments
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum IndexDocumentsError {
     /// <p>An error occurred while processing the request.</p>
     Base(String),
@@ -5441,6 +5630,8 @@ pub enum IndexDocumentsError {
     Internal(String),
     /// <p>The request was rejected because it attempted to reference a resource that does not exist.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl IndexDocumentsError {
@@ -5452,21 +5643,24 @@ impl IndexDocumentsError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "BaseException" => {
-                        return RusotoError::Service(IndexDocumentsError::Base(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            IndexDocumentsError::Base(parsed_error.message),
+                            &res,
+                        )
                     }
                     "InternalException" => {
-                        return RusotoError::Service(IndexDocumentsError::Internal(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            IndexDocumentsError::Internal(parsed_error.message),
+                            &res,
+                        )
                     }
                     "ResourceNotFound" => {
-                        return RusotoError::Service(IndexDocumentsError::ResourceNotFound(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            IndexDocumentsError::ResourceNotFound(parsed_error.message),
+                            &res,
+                        )
                     }
-                    _ => {}
+                    _ => return RusotoError::unhandled(IndexDocumentsError::Unhandled, &res),
                 }
             }
         }
@@ -5488,15 +5682,19 @@ impl fmt::Display for IndexDocumentsError {
             IndexDocumentsError::Base(ref cause) => write!(f, "{}", cause),
             IndexDocumentsError::Internal(ref cause) => write!(f, "{}", cause),
             IndexDocumentsError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            IndexDocumentsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for IndexDocumentsError {}
 /// Errors returned by ListDomainNames
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListDomainNamesError {
     /// <p>An error occurred while processing the request.</p>
     Base(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListDomainNamesError {
@@ -5508,11 +5706,12 @@ impl ListDomainNamesError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "BaseException" => {
-                        return RusotoError::Service(ListDomainNamesError::Base(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            ListDomainNamesError::Base(parsed_error.message),
+                            &res,
+                        )
                     }
-                    _ => {}
+                    _ => return RusotoError::unhandled(ListDomainNamesError::Unhandled, &res),
                 }
             }
         }
@@ -5532,12 +5731,14 @@ impl fmt::Display for ListDomainNamesError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             ListDomainNamesError::Base(ref cause) => write!(f, "{}", cause),
+            ListDomainNamesError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListDomainNamesError {}
 /// Errors returned by UpdateAvailabilityOptions
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdateAvailabilityOptionsError {
     /// <p>An error occurred while processing the request.</p>
     Base(String),
@@ -5553,6 +5754,8 @@ pub enum UpdateAvailabilityOptionsError {
     ResourceNotFound(String),
     /// <p>The request was rejected because it has invalid parameters.</p>
     Validation(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdateAvailabilityOptionsError {
@@ -5564,41 +5767,53 @@ impl UpdateAvailabilityOptionsError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "BaseException" => {
-                        return RusotoError::Service(UpdateAvailabilityOptionsError::Base(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            UpdateAvailabilityOptionsError::Base(parsed_error.message),
+                            &res,
+                        )
                     }
                     "DisabledAction" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             UpdateAvailabilityOptionsError::DisabledOperation(parsed_error.message),
+                            &res,
                         )
                     }
                     "InternalException" => {
-                        return RusotoError::Service(UpdateAvailabilityOptionsError::Internal(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            UpdateAvailabilityOptionsError::Internal(parsed_error.message),
+                            &res,
+                        )
                     }
                     "InvalidType" => {
-                        return RusotoError::Service(UpdateAvailabilityOptionsError::InvalidType(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            UpdateAvailabilityOptionsError::InvalidType(parsed_error.message),
+                            &res,
+                        )
                     }
                     "LimitExceeded" => {
-                        return RusotoError::Service(UpdateAvailabilityOptionsError::LimitExceeded(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            UpdateAvailabilityOptionsError::LimitExceeded(parsed_error.message),
+                            &res,
+                        )
                     }
                     "ResourceNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             UpdateAvailabilityOptionsError::ResourceNotFound(parsed_error.message),
+                            &res,
                         )
                     }
                     "ValidationException" => {
-                        return RusotoError::Service(UpdateAvailabilityOptionsError::Validation(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            UpdateAvailabilityOptionsError::Validation(parsed_error.message),
+                            &res,
+                        )
+                    }
+                    _ => {
+                        return RusotoError::unhandled(
+                            UpdateAvailabilityOptionsError::Unhandled,
+                            &res,
+                        )
                     }
-                    _ => {}
                 }
             }
         }
@@ -5624,12 +5839,14 @@ impl fmt::Display for UpdateAvailabilityOptionsError {
             UpdateAvailabilityOptionsError::LimitExceeded(ref cause) => write!(f, "{}", cause),
             UpdateAvailabilityOptionsError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             UpdateAvailabilityOptionsError::Validation(ref cause) => write!(f, "{}", cause),
+            UpdateAvailabilityOptionsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for UpdateAvailabilityOptionsError {}
 /// Errors returned by UpdateDomainEndpointOptions
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdateDomainEndpointOptionsError {
     /// <p>An error occurred while processing the request.</p>
     Base(String),
@@ -5645,6 +5862,8 @@ pub enum UpdateDomainEndpointOptionsError {
     ResourceNotFound(String),
     /// <p>The request was rejected because it has invalid parameters.</p>
     Validation(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdateDomainEndpointOptionsError {
@@ -5658,45 +5877,57 @@ impl UpdateDomainEndpointOptionsError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "BaseException" => {
-                        return RusotoError::Service(UpdateDomainEndpointOptionsError::Base(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            UpdateDomainEndpointOptionsError::Base(parsed_error.message),
+                            &res,
+                        )
                     }
                     "DisabledAction" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             UpdateDomainEndpointOptionsError::DisabledOperation(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "InternalException" => {
-                        return RusotoError::Service(UpdateDomainEndpointOptionsError::Internal(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            UpdateDomainEndpointOptionsError::Internal(parsed_error.message),
+                            &res,
+                        )
                     }
                     "InvalidType" => {
-                        return RusotoError::Service(UpdateDomainEndpointOptionsError::InvalidType(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            UpdateDomainEndpointOptionsError::InvalidType(parsed_error.message),
+                            &res,
+                        )
                     }
                     "LimitExceeded" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             UpdateDomainEndpointOptionsError::LimitExceeded(parsed_error.message),
+                            &res,
                         )
                     }
                     "ResourceNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             UpdateDomainEndpointOptionsError::ResourceNotFound(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "ValidationException" => {
-                        return RusotoError::Service(UpdateDomainEndpointOptionsError::Validation(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            UpdateDomainEndpointOptionsError::Validation(parsed_error.message),
+                            &res,
+                        )
+                    }
+                    _ => {
+                        return RusotoError::unhandled(
+                            UpdateDomainEndpointOptionsError::Unhandled,
+                            &res,
+                        )
                     }
-                    _ => {}
                 }
             }
         }
@@ -5724,12 +5955,14 @@ impl fmt::Display for UpdateDomainEndpointOptionsError {
             UpdateDomainEndpointOptionsError::LimitExceeded(ref cause) => write!(f, "{}", cause),
             UpdateDomainEndpointOptionsError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             UpdateDomainEndpointOptionsError::Validation(ref cause) => write!(f, "{}", cause),
+            UpdateDomainEndpointOptionsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for UpdateDomainEndpointOptionsError {}
 /// Errors returned by UpdateScalingParameters
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdateScalingParametersError {
     /// <p>An error occurred while processing the request.</p>
     Base(String),
@@ -5741,6 +5974,8 @@ pub enum UpdateScalingParametersError {
     LimitExceeded(String),
     /// <p>The request was rejected because it attempted to reference a resource that does not exist.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdateScalingParametersError {
@@ -5752,31 +5987,41 @@ impl UpdateScalingParametersError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "BaseException" => {
-                        return RusotoError::Service(UpdateScalingParametersError::Base(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            UpdateScalingParametersError::Base(parsed_error.message),
+                            &res,
+                        )
                     }
                     "InternalException" => {
-                        return RusotoError::Service(UpdateScalingParametersError::Internal(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            UpdateScalingParametersError::Internal(parsed_error.message),
+                            &res,
+                        )
                     }
                     "InvalidType" => {
-                        return RusotoError::Service(UpdateScalingParametersError::InvalidType(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            UpdateScalingParametersError::InvalidType(parsed_error.message),
+                            &res,
+                        )
                     }
                     "LimitExceeded" => {
-                        return RusotoError::Service(UpdateScalingParametersError::LimitExceeded(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            UpdateScalingParametersError::LimitExceeded(parsed_error.message),
+                            &res,
+                        )
                     }
                     "ResourceNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             UpdateScalingParametersError::ResourceNotFound(parsed_error.message),
+                            &res,
+                        )
+                    }
+                    _ => {
+                        return RusotoError::unhandled(
+                            UpdateScalingParametersError::Unhandled,
+                            &res,
                         )
                     }
-                    _ => {}
                 }
             }
         }
@@ -5800,12 +6045,14 @@ impl fmt::Display for UpdateScalingParametersError {
             UpdateScalingParametersError::InvalidType(ref cause) => write!(f, "{}", cause),
             UpdateScalingParametersError::LimitExceeded(ref cause) => write!(f, "{}", cause),
             UpdateScalingParametersError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            UpdateScalingParametersError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for UpdateScalingParametersError {}
 /// Errors returned by UpdateServiceAccessPolicies
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdateServiceAccessPoliciesError {
     /// <p>An error occurred while processing the request.</p>
     Base(String),
@@ -5817,6 +6064,8 @@ pub enum UpdateServiceAccessPoliciesError {
     LimitExceeded(String),
     /// <p>The request was rejected because it attempted to reference a resource that does not exist.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdateServiceAccessPoliciesError {
@@ -5830,33 +6079,43 @@ impl UpdateServiceAccessPoliciesError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "BaseException" => {
-                        return RusotoError::Service(UpdateServiceAccessPoliciesError::Base(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            UpdateServiceAccessPoliciesError::Base(parsed_error.message),
+                            &res,
+                        )
                     }
                     "InternalException" => {
-                        return RusotoError::Service(UpdateServiceAccessPoliciesError::Internal(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            UpdateServiceAccessPoliciesError::Internal(parsed_error.message),
+                            &res,
+                        )
                     }
                     "InvalidType" => {
-                        return RusotoError::Service(UpdateServiceAccessPoliciesError::InvalidType(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            UpdateServiceAccessPoliciesError::InvalidType(parsed_error.message),
+                            &res,
+                        )
                     }
                     "LimitExceeded" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             UpdateServiceAccessPoliciesError::LimitExceeded(parsed_error.message),
+                            &res,
                         )
                     }
                     "ResourceNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             UpdateServiceAccessPoliciesError::ResourceNotFound(
                                 parsed_error.message,
                             ),
+                            &res,
+                        )
+                    }
+                    _ => {
+                        return RusotoError::unhandled(
+                            UpdateServiceAccessPoliciesError::Unhandled,
+                            &res,
                         )
                     }
-                    _ => {}
                 }
             }
         }
@@ -5880,6 +6139,7 @@ impl fmt::Display for UpdateServiceAccessPoliciesError {
             UpdateServiceAccessPoliciesError::InvalidType(ref cause) => write!(f, "{}", cause),
             UpdateServiceAccessPoliciesError::LimitExceeded(ref cause) => write!(f, "{}", cause),
             UpdateServiceAccessPoliciesError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            UpdateServiceAccessPoliciesError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
@@ -6059,6 +6319,7 @@ impl CloudSearchClient {
     /// Creates a client backed by the default tokio event loop.
     ///
     /// The client will use the default credentials provider and tls client.
+    #[cfg(not(target_arch = "wasm32"))]
     pub fn new(region: region::Region) -> CloudSearchClient {
         CloudSearchClient {
             client: Client::shared(),
@@ -6084,35 +6345,50 @@ impl CloudSearchClient {
     pub fn new_with_client(client: Client, region: region::Region) -> CloudSearchClient {
         CloudSearchClient { client, region }
     }
+
+    /// Returns a builder of a client, setting any of its region, credentials provider,
+    /// dispatcher, retry policy, endpoint and timeouts.
+    pub fn builder() -> rusoto_core::ClientBuilder<CloudSearchClient> {
+        rusoto_core::ClientBuilder::new(CloudSearchClient::new_with_client)
+    }
+
+    /// Returns a clone of this client with the overrides of `config` applied to its
+    /// operations, sharing its connections.
+    pub fn customize(&self, config: &rusoto_core::OperationConfig) -> CloudSearchClient {
+        CloudSearchClient {
+            client: self.client.customize(config),
+            region: config.resolve_region(&self.region),
+        }
+    }
 }
 
-#[async_trait]
-impl CloudSearch for CloudSearchClient {
-    /// <p>Indexes the search suggestions. For more information, see <a href="http://docs.aws.amazon.com/cloudsearch/latest/developerguide/getting-suggestions.html#configuring-suggesters">Configuring Suggesters</a> in the <i>Amazon CloudSearch Developer Guide</i>.</p>
-    async fn build_suggesters(
+impl CloudSearchClient {
+    /// Calls `build_suggesters`, also returning the metadata of the response.
+    pub async fn build_suggesters_with_metadata(
         &self,
         input: BuildSuggestersRequest,
-    ) -> Result<BuildSuggestersResponse, RusotoError<BuildSuggestersError>> {
-        let mut request = SignedRequest::new("POST", "cloudsearch", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "BuildSuggesters");
-        params.put("Version", "2013-01-01");
-        BuildSuggestersRequestSerializer::serialize(&mut params, "", &input);
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+    ) -> Result<(BuildSuggestersResponse, ResponseMetadata), RusotoError<BuildSuggestersError>>
+    {
+        let request = self.build_build_suggesters_request(input);
 
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(BuildSuggestersError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -6134,34 +6410,58 @@ impl CloudSearch for CloudSearchClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Creates a new search domain. For more information, see <a href="http://docs.aws.amazon.com/cloudsearch/latest/developerguide/creating-domains.html" target="_blank">Creating a Search Domain</a> in the <i>Amazon CloudSearch Developer Guide</i>.</p>
-    async fn create_domain(
+    /// Presigns the request `build_suggesters` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_build_suggesters(
         &self,
-        input: CreateDomainRequest,
-    ) -> Result<CreateDomainResponse, RusotoError<CreateDomainError>> {
+        presigner: &rusoto_core::Presigner,
+        input: BuildSuggestersRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_build_suggesters_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_build_suggesters_request(&self, input: BuildSuggestersRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "cloudsearch", &self.region, "/");
+        request.set_operation("BuildSuggesters");
         let mut params = Params::new();
 
-        params.put("Action", "CreateDomain");
+        params.put("Action", "BuildSuggesters");
         params.put("Version", "2013-01-01");
-        CreateDomainRequestSerializer::serialize(&mut params, "", &input);
+        BuildSuggestersRequestSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `create_domain`, also returning the metadata of the response.
+    pub async fn create_domain_with_metadata(
+        &self,
+        input: CreateDomainRequest,
+    ) -> Result<(CreateDomainResponse, ResponseMetadata), RusotoError<CreateDomainError>> {
+        let request = self.build_create_domain_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(CreateDomainError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -6181,34 +6481,61 @@ impl CloudSearch for CloudSearchClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Configures an analysis scheme that can be applied to a <code>text</code> or <code>text-array</code> field to define language-specific text processing options. For more information, see <a href="http://docs.aws.amazon.com/cloudsearch/latest/developerguide/configuring-analysis-schemes.html" target="_blank">Configuring Analysis Schemes</a> in the <i>Amazon CloudSearch Developer Guide</i>.</p>
-    async fn define_analysis_scheme(
+    /// Presigns the request `create_domain` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_domain(
         &self,
-        input: DefineAnalysisSchemeRequest,
-    ) -> Result<DefineAnalysisSchemeResponse, RusotoError<DefineAnalysisSchemeError>> {
+        presigner: &rusoto_core::Presigner,
+        input: CreateDomainRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_domain_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_domain_request(&self, input: CreateDomainRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "cloudsearch", &self.region, "/");
+        request.set_operation("CreateDomain");
         let mut params = Params::new();
 
-        params.put("Action", "DefineAnalysisScheme");
+        params.put("Action", "CreateDomain");
         params.put("Version", "2013-01-01");
-        DefineAnalysisSchemeRequestSerializer::serialize(&mut params, "", &input);
+        CreateDomainRequestSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `define_analysis_scheme`, also returning the metadata of the response.
+    pub async fn define_analysis_scheme_with_metadata(
+        &self,
+        input: DefineAnalysisSchemeRequest,
+    ) -> Result<
+        (DefineAnalysisSchemeResponse, ResponseMetadata),
+        RusotoError<DefineAnalysisSchemeError>,
+    > {
+        let request = self.build_define_analysis_scheme_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(DefineAnalysisSchemeError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -6230,34 +6557,62 @@ impl CloudSearch for CloudSearchClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Configures an <code><a>Expression</a></code> for the search domain. Used to create new expressions and modify existing ones. If the expression exists, the new configuration replaces the old one. For more information, see <a href="http://docs.aws.amazon.com/cloudsearch/latest/developerguide/configuring-expressions.html" target="_blank">Configuring Expressions</a> in the <i>Amazon CloudSearch Developer Guide</i>.</p>
-    async fn define_expression(
+    /// Presigns the request `define_analysis_scheme` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_define_analysis_scheme(
         &self,
-        input: DefineExpressionRequest,
-    ) -> Result<DefineExpressionResponse, RusotoError<DefineExpressionError>> {
+        presigner: &rusoto_core::Presigner,
+        input: DefineAnalysisSchemeRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_define_analysis_scheme_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_define_analysis_scheme_request(
+        &self,
+        input: DefineAnalysisSchemeRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "cloudsearch", &self.region, "/");
+        request.set_operation("DefineAnalysisScheme");
         let mut params = Params::new();
 
-        params.put("Action", "DefineExpression");
+        params.put("Action", "DefineAnalysisScheme");
         params.put("Version", "2013-01-01");
-        DefineExpressionRequestSerializer::serialize(&mut params, "", &input);
+        DefineAnalysisSchemeRequestSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `define_expression`, also returning the metadata of the response.
+    pub async fn define_expression_with_metadata(
+        &self,
+        input: DefineExpressionRequest,
+    ) -> Result<(DefineExpressionResponse, ResponseMetadata), RusotoError<DefineExpressionError>>
+    {
+        let request = self.build_define_expression_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(DefineExpressionError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -6279,34 +6634,59 @@ impl CloudSearch for CloudSearchClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Configures an <code><a>IndexField</a></code> for the search domain. Used to create new fields and modify existing ones. You must specify the name of the domain you are configuring and an index field configuration. The index field configuration specifies a unique name, the index field type, and the options you want to configure for the field. The options you can specify depend on the <code><a>IndexFieldType</a></code>. If the field exists, the new configuration replaces the old one. For more information, see <a href="http://docs.aws.amazon.com/cloudsearch/latest/developerguide/configuring-index-fields.html" target="_blank">Configuring Index Fields</a> in the <i>Amazon CloudSearch Developer Guide</i>. </p>
-    async fn define_index_field(
+    /// Presigns the request `define_expression` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_define_expression(
         &self,
-        input: DefineIndexFieldRequest,
-    ) -> Result<DefineIndexFieldResponse, RusotoError<DefineIndexFieldError>> {
+        presigner: &rusoto_core::Presigner,
+        input: DefineExpressionRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_define_expression_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_define_expression_request(&self, input: DefineExpressionRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "cloudsearch", &self.region, "/");
+        request.set_operation("DefineExpression");
         let mut params = Params::new();
 
-        params.put("Action", "DefineIndexField");
+        params.put("Action", "DefineExpression");
         params.put("Version", "2013-01-01");
-        DefineIndexFieldRequestSerializer::serialize(&mut params, "", &input);
+        DefineExpressionRequestSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `define_index_field`, also returning the metadata of the response.
+    pub async fn define_index_field_with_metadata(
+        &self,
+        input: DefineIndexFieldRequest,
+    ) -> Result<(DefineIndexFieldResponse, ResponseMetadata), RusotoError<DefineIndexFieldError>>
+    {
+        let request = self.build_define_index_field_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(DefineIndexFieldError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -6328,34 +6708,59 @@ impl CloudSearch for CloudSearchClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Configures a suggester for a domain. A suggester enables you to display possible matches before users finish typing their queries. When you configure a suggester, you must specify the name of the text field you want to search for possible matches and a unique name for the suggester. For more information, see <a href="http://docs.aws.amazon.com/cloudsearch/latest/developerguide/getting-suggestions.html" target="_blank">Getting Search Suggestions</a> in the <i>Amazon CloudSearch Developer Guide</i>.</p>
-    async fn define_suggester(
+    /// Presigns the request `define_index_field` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_define_index_field(
         &self,
-        input: DefineSuggesterRequest,
-    ) -> Result<DefineSuggesterResponse, RusotoError<DefineSuggesterError>> {
+        presigner: &rusoto_core::Presigner,
+        input: DefineIndexFieldRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_define_index_field_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_define_index_field_request(&self, input: DefineIndexFieldRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "cloudsearch", &self.region, "/");
+        request.set_operation("DefineIndexField");
         let mut params = Params::new();
 
-        params.put("Action", "DefineSuggester");
+        params.put("Action", "DefineIndexField");
         params.put("Version", "2013-01-01");
-        DefineSuggesterRequestSerializer::serialize(&mut params, "", &input);
+        DefineIndexFieldRequestSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `define_suggester`, also returning the metadata of the response.
+    pub async fn define_suggester_with_metadata(
+        &self,
+        input: DefineSuggesterRequest,
+    ) -> Result<(DefineSuggesterResponse, ResponseMetadata), RusotoError<DefineSuggesterError>>
+    {
+        let request = self.build_define_suggester_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(DefineSuggesterError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -6377,34 +6782,61 @@ impl CloudSearch for CloudSearchClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Deletes an analysis scheme. For more information, see <a href="http://docs.aws.amazon.com/cloudsearch/latest/developerguide/configuring-analysis-schemes.html" target="_blank">Configuring Analysis Schemes</a> in the <i>Amazon CloudSearch Developer Guide</i>. </p>
-    async fn delete_analysis_scheme(
+    /// Presigns the request `define_suggester` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_define_suggester(
         &self,
-        input: DeleteAnalysisSchemeRequest,
-    ) -> Result<DeleteAnalysisSchemeResponse, RusotoError<DeleteAnalysisSchemeError>> {
+        presigner: &rusoto_core::Presigner,
+        input: DefineSuggesterRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_define_suggester_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_define_suggester_request(&self, input: DefineSuggesterRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "cloudsearch", &self.region, "/");
+        request.set_operation("DefineSuggester");
         let mut params = Params::new();
 
-        params.put("Action", "DeleteAnalysisScheme");
+        params.put("Action", "DefineSuggester");
         params.put("Version", "2013-01-01");
-        DeleteAnalysisSchemeRequestSerializer::serialize(&mut params, "", &input);
+        DefineSuggesterRequestSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `delete_analysis_scheme`, also returning the metadata of the response.
+    pub async fn delete_analysis_scheme_with_metadata(
+        &self,
+        input: DeleteAnalysisSchemeRequest,
+    ) -> Result<
+        (DeleteAnalysisSchemeResponse, ResponseMetadata),
+        RusotoError<DeleteAnalysisSchemeError>,
+    > {
+        let request = self.build_delete_analysis_scheme_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(DeleteAnalysisSchemeError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -6426,34 +6858,61 @@ impl CloudSearch for CloudSearchClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Permanently deletes a search domain and all of its data. Once a domain has been deleted, it cannot be recovered. For more information, see <a href="http://docs.aws.amazon.com/cloudsearch/latest/developerguide/deleting-domains.html" target="_blank">Deleting a Search Domain</a> in the <i>Amazon CloudSearch Developer Guide</i>. </p>
-    async fn delete_domain(
+    /// Presigns the request `delete_analysis_scheme` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_analysis_scheme(
         &self,
-        input: DeleteDomainRequest,
-    ) -> Result<DeleteDomainResponse, RusotoError<DeleteDomainError>> {
+        presigner: &rusoto_core::Presigner,
+        input: DeleteAnalysisSchemeRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_analysis_scheme_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_analysis_scheme_request(
+        &self,
+        input: DeleteAnalysisSchemeRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "cloudsearch", &self.region, "/");
+        request.set_operation("DeleteAnalysisScheme");
         let mut params = Params::new();
 
-        params.put("Action", "DeleteDomain");
+        params.put("Action", "DeleteAnalysisScheme");
         params.put("Version", "2013-01-01");
-        DeleteDomainRequestSerializer::serialize(&mut params, "", &input);
+        DeleteAnalysisSchemeRequestSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `delete_domain`, also returning the metadata of the response.
+    pub async fn delete_domain_with_metadata(
+        &self,
+        input: DeleteDomainRequest,
+    ) -> Result<(DeleteDomainResponse, ResponseMetadata), RusotoError<DeleteDomainError>> {
+        let request = self.build_delete_domain_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(DeleteDomainError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -6473,34 +6932,59 @@ impl CloudSearch for CloudSearchClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Removes an <code><a>Expression</a></code> from the search domain. For more information, see <a href="http://docs.aws.amazon.com/cloudsearch/latest/developerguide/configuring-expressions.html" target="_blank">Configuring Expressions</a> in the <i>Amazon CloudSearch Developer Guide</i>.</p>
-    async fn delete_expression(
+    /// Presigns the request `delete_domain` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_domain(
         &self,
-        input: DeleteExpressionRequest,
-    ) -> Result<DeleteExpressionResponse, RusotoError<DeleteExpressionError>> {
+        presigner: &rusoto_core::Presigner,
+        input: DeleteDomainRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_domain_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_domain_request(&self, input: DeleteDomainRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "cloudsearch", &self.region, "/");
+        request.set_operation("DeleteDomain");
         let mut params = Params::new();
 
-        params.put("Action", "DeleteExpression");
+        params.put("Action", "DeleteDomain");
         params.put("Version", "2013-01-01");
-        DeleteExpressionRequestSerializer::serialize(&mut params, "", &input);
+        DeleteDomainRequestSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `delete_expression`, also returning the metadata of the response.
+    pub async fn delete_expression_with_metadata(
+        &self,
+        input: DeleteExpressionRequest,
+    ) -> Result<(DeleteExpressionResponse, ResponseMetadata), RusotoError<DeleteExpressionError>>
+    {
+        let request = self.build_delete_expression_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(DeleteExpressionError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -6522,34 +7006,59 @@ impl CloudSearch for CloudSearchClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Removes an <code><a>IndexField</a></code> from the search domain. For more information, see <a href="http://docs.aws.amazon.com/cloudsearch/latest/developerguide/configuring-index-fields.html" target="_blank">Configuring Index Fields</a> in the <i>Amazon CloudSearch Developer Guide</i>.</p>
-    async fn delete_index_field(
+    /// Presigns the request `delete_expression` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_expression(
         &self,
-        input: DeleteIndexFieldRequest,
-    ) -> Result<DeleteIndexFieldResponse, RusotoError<DeleteIndexFieldError>> {
+        presigner: &rusoto_core::Presigner,
+        input: DeleteExpressionRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_expression_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_expression_request(&self, input: DeleteExpressionRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "cloudsearch", &self.region, "/");
+        request.set_operation("DeleteExpression");
         let mut params = Params::new();
 
-        params.put("Action", "DeleteIndexField");
+        params.put("Action", "DeleteExpression");
         params.put("Version", "2013-01-01");
-        DeleteIndexFieldRequestSerializer::serialize(&mut params, "", &input);
+        DeleteExpressionRequestSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `delete_index_field`, also returning the metadata of the response.
+    pub async fn delete_index_field_with_metadata(
+        &self,
+        input: DeleteIndexFieldRequest,
+    ) -> Result<(DeleteIndexFieldResponse, ResponseMetadata), RusotoError<DeleteIndexFieldError>>
+    {
+        let request = self.build_delete_index_field_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(DeleteIndexFieldError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -6571,34 +7080,59 @@ impl CloudSearch for CloudSearchClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Deletes a suggester. For more information, see <a href="http://docs.aws.amazon.com/cloudsearch/latest/developerguide/getting-suggestions.html" target="_blank">Getting Search Suggestions</a> in the <i>Amazon CloudSearch Developer Guide</i>.</p>
-    async fn delete_suggester(
+    /// Presigns the request `delete_index_field` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_index_field(
         &self,
-        input: DeleteSuggesterRequest,
-    ) -> Result<DeleteSuggesterResponse, RusotoError<DeleteSuggesterError>> {
+        presigner: &rusoto_core::Presigner,
+        input: DeleteIndexFieldRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_index_field_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_index_field_request(&self, input: DeleteIndexFieldRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "cloudsearch", &self.region, "/");
+        request.set_operation("DeleteIndexField");
         let mut params = Params::new();
 
-        params.put("Action", "DeleteSuggester");
+        params.put("Action", "DeleteIndexField");
         params.put("Version", "2013-01-01");
-        DeleteSuggesterRequestSerializer::serialize(&mut params, "", &input);
+        DeleteIndexFieldRequestSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `delete_suggester`, also returning the metadata of the response.
+    pub async fn delete_suggester_with_metadata(
+        &self,
+        input: DeleteSuggesterRequest,
+    ) -> Result<(DeleteSuggesterResponse, ResponseMetadata), RusotoError<DeleteSuggesterError>>
+    {
+        let request = self.build_delete_suggester_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(DeleteSuggesterError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -6620,34 +7154,61 @@ impl CloudSearch for CloudSearchClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Gets the analysis schemes configured for a domain. An analysis scheme defines language-specific text processing options for a <code>text</code> field. Can be limited to specific analysis schemes by name. By default, shows all analysis schemes and includes any pending changes to the configuration. Set the <code>Deployed</code> option to <code>true</code> to show the active configuration and exclude pending changes. For more information, see <a href="http://docs.aws.amazon.com/cloudsearch/latest/developerguide/configuring-analysis-schemes.html" target="_blank">Configuring Analysis Schemes</a> in the <i>Amazon CloudSearch Developer Guide</i>.</p>
-    async fn describe_analysis_schemes(
+    /// Presigns the request `delete_suggester` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_suggester(
         &self,
-        input: DescribeAnalysisSchemesRequest,
-    ) -> Result<DescribeAnalysisSchemesResponse, RusotoError<DescribeAnalysisSchemesError>> {
+        presigner: &rusoto_core::Presigner,
+        input: DeleteSuggesterRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_suggester_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_suggester_request(&self, input: DeleteSuggesterRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "cloudsearch", &self.region, "/");
+        request.set_operation("DeleteSuggester");
         let mut params = Params::new();
 
-        params.put("Action", "DescribeAnalysisSchemes");
+        params.put("Action", "DeleteSuggester");
         params.put("Version", "2013-01-01");
-        DescribeAnalysisSchemesRequestSerializer::serialize(&mut params, "", &input);
+        DeleteSuggesterRequestSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `describe_analysis_schemes`, also returning the metadata of the response.
+    pub async fn describe_analysis_schemes_with_metadata(
+        &self,
+        input: DescribeAnalysisSchemesRequest,
+    ) -> Result<
+        (DescribeAnalysisSchemesResponse, ResponseMetadata),
+        RusotoError<DescribeAnalysisSchemesError>,
+    > {
+        let request = self.build_describe_analysis_schemes_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(DescribeAnalysisSchemesError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -6669,35 +7230,64 @@ impl CloudSearch for CloudSearchClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Gets the availability options configured for a domain. By default, shows the configuration with any pending changes. Set the <code>Deployed</code> option to <code>true</code> to show the active configuration and exclude pending changes. For more information, see <a href="http://docs.aws.amazon.com/cloudsearch/latest/developerguide/configuring-availability-options.html" target="_blank">Configuring Availability Options</a> in the <i>Amazon CloudSearch Developer Guide</i>.</p>
-    async fn describe_availability_options(
+    /// Presigns the request `describe_analysis_schemes` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_analysis_schemes(
         &self,
-        input: DescribeAvailabilityOptionsRequest,
-    ) -> Result<DescribeAvailabilityOptionsResponse, RusotoError<DescribeAvailabilityOptionsError>>
-    {
+        presigner: &rusoto_core::Presigner,
+        input: DescribeAnalysisSchemesRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_analysis_schemes_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_analysis_schemes_request(
+        &self,
+        input: DescribeAnalysisSchemesRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "cloudsearch", &self.region, "/");
+        request.set_operation("DescribeAnalysisSchemes");
         let mut params = Params::new();
 
-        params.put("Action", "DescribeAvailabilityOptions");
+        params.put("Action", "DescribeAnalysisSchemes");
         params.put("Version", "2013-01-01");
-        DescribeAvailabilityOptionsRequestSerializer::serialize(&mut params, "", &input);
+        DescribeAnalysisSchemesRequestSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `describe_availability_options`, also returning the metadata of the response.
+    pub async fn describe_availability_options_with_metadata(
+        &self,
+        input: DescribeAvailabilityOptionsRequest,
+    ) -> Result<
+        (DescribeAvailabilityOptionsResponse, ResponseMetadata),
+        RusotoError<DescribeAvailabilityOptionsError>,
+    > {
+        let request = self.build_describe_availability_options_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(DescribeAvailabilityOptionsError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -6719,37 +7309,64 @@ impl CloudSearch for CloudSearchClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Returns the domain's endpoint options, specifically whether all requests to the domain must arrive over HTTPS. For more information, see <a href="http://docs.aws.amazon.com/cloudsearch/latest/developerguide/configuring-domain-endpoint-options.html" target="_blank">Configuring Domain Endpoint Options</a> in the <i>Amazon CloudSearch Developer Guide</i>.</p>
-    async fn describe_domain_endpoint_options(
+    /// Presigns the request `describe_availability_options` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_availability_options(
         &self,
-        input: DescribeDomainEndpointOptionsRequest,
-    ) -> Result<
-        DescribeDomainEndpointOptionsResponse,
-        RusotoError<DescribeDomainEndpointOptionsError>,
-    > {
+        presigner: &rusoto_core::Presigner,
+        input: DescribeAvailabilityOptionsRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_availability_options_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_availability_options_request(
+        &self,
+        input: DescribeAvailabilityOptionsRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "cloudsearch", &self.region, "/");
+        request.set_operation("DescribeAvailabilityOptions");
         let mut params = Params::new();
 
-        params.put("Action", "DescribeDomainEndpointOptions");
+        params.put("Action", "DescribeAvailabilityOptions");
         params.put("Version", "2013-01-01");
-        DescribeDomainEndpointOptionsRequestSerializer::serialize(&mut params, "", &input);
+        DescribeAvailabilityOptionsRequestSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `describe_domain_endpoint_options`, also returning the metadata of the response.
+    pub async fn describe_domain_endpoint_options_with_metadata(
+        &self,
+        input: DescribeDomainEndpointOptionsRequest,
+    ) -> Result<
+        (DescribeDomainEndpointOptionsResponse, ResponseMetadata),
+        RusotoError<DescribeDomainEndpointOptionsError>,
+    > {
+        let request = self.build_describe_domain_endpoint_options_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(DescribeDomainEndpointOptionsError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -6771,34 +7388,62 @@ impl CloudSearch for CloudSearchClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Gets information about the search domains owned by this account. Can be limited to specific domains. Shows all domains by default. To get the number of searchable documents in a domain, use the console or submit a <code>matchall</code> request to your domain's search endpoint: <code>q=matchall&amp;amp;q.parser=structured&amp;amp;size=0</code>. For more information, see <a href="http://docs.aws.amazon.com/cloudsearch/latest/developerguide/getting-domain-info.html" target="_blank">Getting Information about a Search Domain</a> in the <i>Amazon CloudSearch Developer Guide</i>.</p>
-    async fn describe_domains(
+    /// Presigns the request `describe_domain_endpoint_options` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_domain_endpoint_options(
         &self,
-        input: DescribeDomainsRequest,
-    ) -> Result<DescribeDomainsResponse, RusotoError<DescribeDomainsError>> {
+        presigner: &rusoto_core::Presigner,
+        input: DescribeDomainEndpointOptionsRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_domain_endpoint_options_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_domain_endpoint_options_request(
+        &self,
+        input: DescribeDomainEndpointOptionsRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "cloudsearch", &self.region, "/");
+        request.set_operation("DescribeDomainEndpointOptions");
         let mut params = Params::new();
 
-        params.put("Action", "DescribeDomains");
+        params.put("Action", "DescribeDomainEndpointOptions");
         params.put("Version", "2013-01-01");
-        DescribeDomainsRequestSerializer::serialize(&mut params, "", &input);
+        DescribeDomainEndpointOptionsRequestSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `describe_domains`, also returning the metadata of the response.
+    pub async fn describe_domains_with_metadata(
+        &self,
+        input: DescribeDomainsRequest,
+    ) -> Result<(DescribeDomainsResponse, ResponseMetadata), RusotoError<DescribeDomainsError>>
+    {
+        let request = self.build_describe_domains_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(DescribeDomainsError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -6820,34 +7465,61 @@ impl CloudSearch for CloudSearchClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Gets the expressions configured for the search domain. Can be limited to specific expressions by name. By default, shows all expressions and includes any pending changes to the configuration. Set the <code>Deployed</code> option to <code>true</code> to show the active configuration and exclude pending changes. For more information, see <a href="http://docs.aws.amazon.com/cloudsearch/latest/developerguide/configuring-expressions.html" target="_blank">Configuring Expressions</a> in the <i>Amazon CloudSearch Developer Guide</i>.</p>
-    async fn describe_expressions(
+    /// Presigns the request `describe_domains` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_domains(
         &self,
-        input: DescribeExpressionsRequest,
-    ) -> Result<DescribeExpressionsResponse, RusotoError<DescribeExpressionsError>> {
+        presigner: &rusoto_core::Presigner,
+        input: DescribeDomainsRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_domains_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_domains_request(&self, input: DescribeDomainsRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "cloudsearch", &self.region, "/");
+        request.set_operation("DescribeDomains");
         let mut params = Params::new();
 
-        params.put("Action", "DescribeExpressions");
+        params.put("Action", "DescribeDomains");
         params.put("Version", "2013-01-01");
-        DescribeExpressionsRequestSerializer::serialize(&mut params, "", &input);
+        DescribeDomainsRequestSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `describe_expressions`, also returning the metadata of the response.
+    pub async fn describe_expressions_with_metadata(
+        &self,
+        input: DescribeExpressionsRequest,
+    ) -> Result<
+        (DescribeExpressionsResponse, ResponseMetadata),
+        RusotoError<DescribeExpressionsError>,
+    > {
+        let request = self.build_describe_expressions_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(DescribeExpressionsError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -6869,34 +7541,64 @@ impl CloudSearch for CloudSearchClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Gets information about the index fields configured for the search domain. Can be limited to specific fields by name. By default, shows all fields and includes any pending changes to the configuration. Set the <code>Deployed</code> option to <code>true</code> to show the active configuration and exclude pending changes. For more information, see <a href="http://docs.aws.amazon.com/cloudsearch/latest/developerguide/getting-domain-info.html" target="_blank">Getting Domain Information</a> in the <i>Amazon CloudSearch Developer Guide</i>.</p>
-    async fn describe_index_fields(
+    /// Presigns the request `describe_expressions` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_expressions(
         &self,
-        input: DescribeIndexFieldsRequest,
-    ) -> Result<DescribeIndexFieldsResponse, RusotoError<DescribeIndexFieldsError>> {
+        presigner: &rusoto_core::Presigner,
+        input: DescribeExpressionsRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_expressions_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_expressions_request(
+        &self,
+        input: DescribeExpressionsRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "cloudsearch", &self.region, "/");
+        request.set_operation("DescribeExpressions");
         let mut params = Params::new();
 
-        params.put("Action", "DescribeIndexFields");
+        params.put("Action", "DescribeExpressions");
         params.put("Version", "2013-01-01");
-        DescribeIndexFieldsRequestSerializer::serialize(&mut params, "", &input);
+        DescribeExpressionsRequestSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `describe_index_fields`, also returning the metadata of the response.
+    pub async fn describe_index_fields_with_metadata(
+        &self,
+        input: DescribeIndexFieldsRequest,
+    ) -> Result<
+        (DescribeIndexFieldsResponse, ResponseMetadata),
+        RusotoError<DescribeIndexFieldsError>,
+    > {
+        let request = self.build_describe_index_fields_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(DescribeIndexFieldsError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -6918,35 +7620,64 @@ impl CloudSearch for CloudSearchClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Gets the scaling parameters configured for a domain. A domain's scaling parameters specify the desired search instance type and replication count. For more information, see <a href="http://docs.aws.amazon.com/cloudsearch/latest/developerguide/configuring-scaling-options.html" target="_blank">Configuring Scaling Options</a> in the <i>Amazon CloudSearch Developer Guide</i>.</p>
-    async fn describe_scaling_parameters(
+    /// Presigns the request `describe_index_fields` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_index_fields(
         &self,
-        input: DescribeScalingParametersRequest,
-    ) -> Result<DescribeScalingParametersResponse, RusotoError<DescribeScalingParametersError>>
-    {
+        presigner: &rusoto_core::Presigner,
+        input: DescribeIndexFieldsRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_index_fields_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_index_fields_request(
+        &self,
+        input: DescribeIndexFieldsRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "cloudsearch", &self.region, "/");
+        request.set_operation("DescribeIndexFields");
         let mut params = Params::new();
 
-        params.put("Action", "DescribeScalingParameters");
+        params.put("Action", "DescribeIndexFields");
         params.put("Version", "2013-01-01");
-        DescribeScalingParametersRequestSerializer::serialize(&mut params, "", &input);
+        DescribeIndexFieldsRequestSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `describe_scaling_parameters`, also returning the metadata of the response.
+    pub async fn describe_scaling_parameters_with_metadata(
+        &self,
+        input: DescribeScalingParametersRequest,
+    ) -> Result<
+        (DescribeScalingParametersResponse, ResponseMetadata),
+        RusotoError<DescribeScalingParametersError>,
+    > {
+        let request = self.build_describe_scaling_parameters_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(DescribeScalingParametersError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -6968,37 +7699,64 @@ impl CloudSearch for CloudSearchClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Gets information about the access policies that control access to the domain's document and search endpoints. By default, shows the configuration with any pending changes. Set the <code>Deployed</code> option to <code>true</code> to show the active configuration and exclude pending changes. For more information, see <a href="http://docs.aws.amazon.com/cloudsearch/latest/developerguide/configuring-access.html" target="_blank">Configuring Access for a Search Domain</a> in the <i>Amazon CloudSearch Developer Guide</i>.</p>
-    async fn describe_service_access_policies(
+    /// Presigns the request `describe_scaling_parameters` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_scaling_parameters(
         &self,
-        input: DescribeServiceAccessPoliciesRequest,
-    ) -> Result<
-        DescribeServiceAccessPoliciesResponse,
-        RusotoError<DescribeServiceAccessPoliciesError>,
-    > {
+        presigner: &rusoto_core::Presigner,
+        input: DescribeScalingParametersRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_scaling_parameters_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_scaling_parameters_request(
+        &self,
+        input: DescribeScalingParametersRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "cloudsearch", &self.region, "/");
+        request.set_operation("DescribeScalingParameters");
         let mut params = Params::new();
 
-        params.put("Action", "DescribeServiceAccessPolicies");
+        params.put("Action", "DescribeScalingParameters");
         params.put("Version", "2013-01-01");
-        DescribeServiceAccessPoliciesRequestSerializer::serialize(&mut params, "", &input);
+        DescribeScalingParametersRequestSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `describe_service_access_policies`, also returning the metadata of the response.
+    pub async fn describe_service_access_policies_with_metadata(
+        &self,
+        input: DescribeServiceAccessPoliciesRequest,
+    ) -> Result<
+        (DescribeServiceAccessPoliciesResponse, ResponseMetadata),
+        RusotoError<DescribeServiceAccessPoliciesError>,
+    > {
+        let request = self.build_describe_service_access_policies_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(DescribeServiceAccessPoliciesError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -7020,34 +7778,62 @@ impl CloudSearch for CloudSearchClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Gets the suggesters configured for a domain. A suggester enables you to display possible matches before users finish typing their queries. Can be limited to specific suggesters by name. By default, shows all suggesters and includes any pending changes to the configuration. Set the <code>Deployed</code> option to <code>true</code> to show the active configuration and exclude pending changes. For more information, see <a href="http://docs.aws.amazon.com/cloudsearch/latest/developerguide/getting-suggestions.html" target="_blank">Getting Search Suggestions</a> in the <i>Amazon CloudSearch Developer Guide</i>.</p>
-    async fn describe_suggesters(
+    /// Presigns the request `describe_service_access_policies` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_service_access_policies(
         &self,
-        input: DescribeSuggestersRequest,
-    ) -> Result<DescribeSuggestersResponse, RusotoError<DescribeSuggestersError>> {
-        let mut request = SignedRequest::new("POST", "cloudsearch", &self.region, "/");
-        let mut params = Params::new();
+        presigner: &rusoto_core::Presigner,
+        input: DescribeServiceAccessPoliciesRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_service_access_policies_request(input);
+        self.client.presign(presigner, request).await
+    }
 
-        params.put("Action", "DescribeSuggesters");
+    fn build_describe_service_access_policies_request(
+        &self,
+        input: DescribeServiceAccessPoliciesRequest,
+    ) -> SignedRequest {
+        let mut request = SignedRequest::new("POST", "cloudsearch", &self.region, "/");
+        request.set_operation("DescribeServiceAccessPolicies");
+        let mut params = Params::new();
+
+        params.put("Action", "DescribeServiceAccessPolicies");
         params.put("Version", "2013-01-01");
-        DescribeSuggestersRequestSerializer::serialize(&mut params, "", &input);
+        DescribeServiceAccessPoliciesRequestSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `describe_suggesters`, also returning the metadata of the response.
+    pub async fn describe_suggesters_with_metadata(
+        &self,
+        input: DescribeSuggestersRequest,
+    ) -> Result<(DescribeSuggestersResponse, ResponseMetadata), RusotoError<DescribeSuggestersError>>
+    {
+        let request = self.build_describe_suggesters_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(DescribeSuggestersError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -7069,34 +7855,58 @@ impl CloudSearch for CloudSearchClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Tells the search domain to start indexing its documents using the latest indexing options. This operation must be invoked to activate options whose <a>OptionStatus</a> is <code>RequiresIndexDocuments</code>.</p>
-    async fn index_documents(
+    /// Presigns the request `describe_suggesters` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_suggesters(
         &self,
-        input: IndexDocumentsRequest,
-    ) -> Result<IndexDocumentsResponse, RusotoError<IndexDocumentsError>> {
+        presigner: &rusoto_core::Presigner,
+        input: DescribeSuggestersRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_suggesters_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_suggesters_request(&self, input: DescribeSuggestersRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "cloudsearch", &self.region, "/");
+        request.set_operation("DescribeSuggesters");
         let mut params = Params::new();
 
-        params.put("Action", "IndexDocuments");
+        params.put("Action", "DescribeSuggesters");
         params.put("Version", "2013-01-01");
-        IndexDocumentsRequestSerializer::serialize(&mut params, "", &input);
+        DescribeSuggestersRequestSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `index_documents`, also returning the metadata of the response.
+    pub async fn index_documents_with_metadata(
+        &self,
+        input: IndexDocumentsRequest,
+    ) -> Result<(IndexDocumentsResponse, ResponseMetadata), RusotoError<IndexDocumentsError>> {
+        let request = self.build_index_documents_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(IndexDocumentsError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -7118,33 +7928,58 @@ impl CloudSearch for CloudSearchClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Lists all search domains owned by an account.</p>
-    async fn list_domain_names(
+    /// Presigns the request `index_documents` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_index_documents(
         &self,
-    ) -> Result<ListDomainNamesResponse, RusotoError<ListDomainNamesError>> {
+        presigner: &rusoto_core::Presigner,
+        input: IndexDocumentsRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_index_documents_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_index_documents_request(&self, input: IndexDocumentsRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "cloudsearch", &self.region, "/");
+        request.set_operation("IndexDocuments");
         let mut params = Params::new();
 
-        params.put("Action", "ListDomainNames");
+        params.put("Action", "IndexDocuments");
         params.put("Version", "2013-01-01");
-
+        IndexDocumentsRequestSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `list_domain_names`, also returning the metadata of the response.
+    pub async fn list_domain_names_with_metadata(
+        &self,
+    ) -> Result<(ListDomainNamesResponse, ResponseMetadata), RusotoError<ListDomainNamesError>>
+    {
+        let request = self.build_list_domain_names_request();
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(ListDomainNamesError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -7166,35 +8001,60 @@ impl CloudSearch for CloudSearchClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Configures the availability options for a domain. Enabling the Multi-AZ option expands an Amazon CloudSearch domain to an additional Availability Zone in the same Region to increase fault tolerance in the event of a service disruption. Changes to the Multi-AZ option can take about half an hour to become active. For more information, see <a href="http://docs.aws.amazon.com/cloudsearch/latest/developerguide/configuring-availability-options.html" target="_blank">Configuring Availability Options</a> in the <i>Amazon CloudSearch Developer Guide</i>.</p>
-    async fn update_availability_options(
+    /// Presigns the request `list_domain_names` would send with `presigner` instead of sending it.
+    pub async fn presign_list_domain_names(
         &self,
-        input: UpdateAvailabilityOptionsRequest,
-    ) -> Result<UpdateAvailabilityOptionsResponse, RusotoError<UpdateAvailabilityOptionsError>>
-    {
+        presigner: &rusoto_core::Presigner,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_domain_names_request();
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_domain_names_request(&self) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "cloudsearch", &self.region, "/");
+        request.set_operation("ListDomainNames");
         let mut params = Params::new();
 
-        params.put("Action", "UpdateAvailabilityOptions");
+        params.put("Action", "ListDomainNames");
         params.put("Version", "2013-01-01");
-        UpdateAvailabilityOptionsRequestSerializer::serialize(&mut params, "", &input);
+
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `update_availability_options`, also returning the metadata of the response.
+    pub async fn update_availability_options_with_metadata(
+        &self,
+        input: UpdateAvailabilityOptionsRequest,
+    ) -> Result<
+        (UpdateAvailabilityOptionsResponse, ResponseMetadata),
+        RusotoError<UpdateAvailabilityOptionsError>,
+    > {
+        let request = self.build_update_availability_options_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(UpdateAvailabilityOptionsError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -7216,35 +8076,64 @@ impl CloudSearch for CloudSearchClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Updates the domain's endpoint options, specifically whether all requests to the domain must arrive over HTTPS. For more information, see <a href="http://docs.aws.amazon.com/cloudsearch/latest/developerguide/configuring-domain-endpoint-options.html" target="_blank">Configuring Domain Endpoint Options</a> in the <i>Amazon CloudSearch Developer Guide</i>.</p>
-    async fn update_domain_endpoint_options(
+    /// Presigns the request `update_availability_options` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_availability_options(
         &self,
-        input: UpdateDomainEndpointOptionsRequest,
-    ) -> Result<UpdateDomainEndpointOptionsResponse, RusotoError<UpdateDomainEndpointOptionsError>>
-    {
+        presigner: &rusoto_core::Presigner,
+        input: UpdateAvailabilityOptionsRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_availability_options_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_availability_options_request(
+        &self,
+        input: UpdateAvailabilityOptionsRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "cloudsearch", &self.region, "/");
+        request.set_operation("UpdateAvailabilityOptions");
         let mut params = Params::new();
 
-        params.put("Action", "UpdateDomainEndpointOptions");
+        params.put("Action", "UpdateAvailabilityOptions");
         params.put("Version", "2013-01-01");
-        UpdateDomainEndpointOptionsRequestSerializer::serialize(&mut params, "", &input);
+        UpdateAvailabilityOptionsRequestSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `update_domain_endpoint_options`, also returning the metadata of the response.
+    pub async fn update_domain_endpoint_options_with_metadata(
+        &self,
+        input: UpdateDomainEndpointOptionsRequest,
+    ) -> Result<
+        (UpdateDomainEndpointOptionsResponse, ResponseMetadata),
+        RusotoError<UpdateDomainEndpointOptionsError>,
+    > {
+        let request = self.build_update_domain_endpoint_options_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(UpdateDomainEndpointOptionsError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -7266,34 +8155,64 @@ impl CloudSearch for CloudSearchClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Configures scaling parameters for a domain. A domain's scaling parameters specify the desired search instance type and replication count. Amazon CloudSearch will still automatically scale your domain based on the volume of data and traffic, but not below the desired instance type and replication count. If the Multi-AZ option is enabled, these values control the resources used per Availability Zone. For more information, see <a href="http://docs.aws.amazon.com/cloudsearch/latest/developerguide/configuring-scaling-options.html" target="_blank">Configuring Scaling Options</a> in the <i>Amazon CloudSearch Developer Guide</i>. </p>
-    async fn update_scaling_parameters(
+    /// Presigns the request `update_domain_endpoint_options` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_domain_endpoint_options(
         &self,
-        input: UpdateScalingParametersRequest,
-    ) -> Result<UpdateScalingParametersResponse, RusotoError<UpdateScalingParametersError>> {
+        presigner: &rusoto_core::Presigner,
+        input: UpdateDomainEndpointOptionsRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_domain_endpoint_options_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_domain_endpoint_options_request(
+        &self,
+        input: UpdateDomainEndpointOptionsRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "cloudsearch", &self.region, "/");
+        request.set_operation("UpdateDomainEndpointOptions");
         let mut params = Params::new();
 
-        params.put("Action", "UpdateScalingParameters");
+        params.put("Action", "UpdateDomainEndpointOptions");
         params.put("Version", "2013-01-01");
-        UpdateScalingParametersRequestSerializer::serialize(&mut params, "", &input);
+        UpdateDomainEndpointOptionsRequestSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `update_scaling_parameters`, also returning the metadata of the response.
+    pub async fn update_scaling_parameters_with_metadata(
+        &self,
+        input: UpdateScalingParametersRequest,
+    ) -> Result<
+        (UpdateScalingParametersResponse, ResponseMetadata),
+        RusotoError<UpdateScalingParametersError>,
+    > {
+        let request = self.build_update_scaling_parameters_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(UpdateScalingParametersError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -7315,35 +8234,64 @@ impl CloudSearch for CloudSearchClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Configures the access rules that control access to the domain's document and search endpoints. For more information, see <a href="http://docs.aws.amazon.com/cloudsearch/latest/developerguide/configuring-access.html" target="_blank"> Configuring Access for an Amazon CloudSearch Domain</a>.</p>
-    async fn update_service_access_policies(
+    /// Presigns the request `update_scaling_parameters` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_scaling_parameters(
         &self,
-        input: UpdateServiceAccessPoliciesRequest,
-    ) -> Result<UpdateServiceAccessPoliciesResponse, RusotoError<UpdateServiceAccessPoliciesError>>
-    {
+        presigner: &rusoto_core::Presigner,
+        input: UpdateScalingParametersRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_scaling_parameters_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_scaling_parameters_request(
+        &self,
+        input: UpdateScalingParametersRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "cloudsearch", &self.region, "/");
+        request.set_operation("UpdateScalingParameters");
         let mut params = Params::new();
 
-        params.put("Action", "UpdateServiceAccessPolicies");
+        params.put("Action", "UpdateScalingParameters");
         params.put("Version", "2013-01-01");
-        UpdateServiceAccessPoliciesRequestSerializer::serialize(&mut params, "", &input);
+        UpdateScalingParametersRequestSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `update_service_access_policies`, also returning the metadata of the response.
+    pub async fn update_service_access_policies_with_metadata(
+        &self,
+        input: UpdateServiceAccessPoliciesRequest,
+    ) -> Result<
+        (UpdateServiceAccessPoliciesResponse, ResponseMetadata),
+        RusotoError<UpdateServiceAccessPoliciesError>,
+    > {
+        let request = self.build_update_service_access_policies_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(UpdateServiceAccessPoliciesError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -7365,6 +8313,306 @@ impl CloudSearch for CloudSearchClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
+    }
+
+    /// Presigns the request `update_service_access_policies` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_service_access_policies(
+        &self,
+        presigner: &rusoto_core::Presigner,
+        input: UpdateServiceAccessPoliciesRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_service_access_policies_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_service_access_policies_request(
+        &self,
+        input: UpdateServiceAccessPoliciesRequest,
+    ) -> SignedRequest {
+        let mut request = SignedRequest::new("POST", "cloudsearch", &self.region, "/");
+        request.set_operation("UpdateServiceAccessPolicies");
+        let mut params = Params::new();
+
+        params.put("Action", "UpdateServiceAccessPolicies");
+        params.put("Version", "2013-01-01");
+        UpdateServiceAccessPoliciesRequestSerializer::serialize(&mut params, "", &input);
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        request
+    }
+}
+
+#[async_trait]
+impl CloudSearch for CloudSearchClient {
+    /// <p>Indexes the search suggestions. For more information, see <a href="http://docs.aws.amazon.com/cloudsearch/latest/developerguide/getting-suggestions.html#configuring-suggesters">Configuring Suggesters</a> in the <i>Amazon CloudSearch Developer Guide</i>.</p>
+    async fn build_suggesters(
+        &self,
+        input: BuildSuggestersRequest,
+    ) -> Result<BuildSuggestersResponse, RusotoError<BuildSuggestersError>> {
+        self.build_suggesters_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Creates a new search domain. For more information, see <a href="http://docs.aws.amazon.com/cloudsearch/latest/developerguide/creating-domains.html" target="_blank">Creating a Search Domain</a> in the <i>Amazon CloudSearch Developer Guide</i>.</p>
+    async fn create_domain(
+        &self,
+        input: CreateDomainRequest,
+    ) -> Result<CreateDomainResponse, RusotoError<CreateDomainError>> {
+        self.create_domain_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Configures an analysis scheme that can be applied to a <code>text</code> or <code>text-array</code> field to define language-specific text processing options. For more information, see <a href="http://docs.aws.amazon.com/cloudsearch/latest/developerguide/configuring-analysis-schemes.html" target="_blank">Configuring Analysis Schemes</a> in the <i>Amazon CloudSearch Developer Guide</i>.</p>
+    async fn define_analysis_scheme(
+        &self,
+        input: DefineAnalysisSchemeRequest,
+    ) -> Result<DefineAnalysisSchemeResponse, RusotoError<DefineAnalysisSchemeError>> {
+        self.define_analysis_scheme_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Configures an <code><a>Expression</a></code> for the search domain. Used to create new expressions and modify existing ones. If the expression exists, the new configuration replaces the old one. For more information, see <a href="http://docs.aws.amazon.com/cloudsearch/latest/developerguide/configuring-expressions.html" target="_blank">Configuring Expressions</a> in the <i>Amazon CloudSearch Developer Guide</i>.</p>
+    async fn define_expression(
+        &self,
+        input: DefineExpressionRequest,
+    ) -> Result<DefineExpressionResponse, RusotoError<DefineExpressionError>> {
+        self.define_expression_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Configures an <code><a>IndexField</a></code> for the search domain. Used to create new fields and modify existing ones. You must specify the name of the domain you are configuring and an index field configuration. The index field configuration specifies a unique name, the index field type, and the options you want to configure for the field. The options you can specify depend on the <code><a>IndexFieldType</a></code>. If the field exists, the new configuration replaces the old one. For more information, see <a href="http://docs.aws.amazon.com/cloudsearch/latest/developerguide/configuring-index-fields.html" target="_blank">Configuring Index Fields</a> in the <i>Amazon CloudSearch Developer Guide</i>. </p>
+    async fn define_index_field(
+        &self,
+        input: DefineIndexFieldRequest,
+    ) -> Result<DefineIndexFieldResponse, RusotoError<DefineIndexFieldError>> {
+        self.define_index_field_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Configures a suggester for a domain. A suggester enables you to display possible matches before users finish typing their queries. When you configure a suggester, you must specify the name of the text field you want to search for possible matches and a unique name for the suggester. For more information, see <a href="http://docs.aws.amazon.com/cloudsearch/latest/developerguide/getting-suggestions.html" target="_blank">Getting Search Suggestions</a> in the <i>Amazon CloudSearch Developer Guide</i>.</p>
+    async fn define_suggester(
+        &self,
+        input: DefineSuggesterRequest,
+    ) -> Result<DefineSuggesterResponse, RusotoError<DefineSuggesterError>> {
+        self.define_suggester_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes an analysis scheme. For more information, see <a href="http://docs.aws.amazon.com/cloudsearch/latest/developerguide/configuring-analysis-schemes.html" target="_blank">Configuring Analysis Schemes</a> in the <i>Amazon CloudSearch Developer Guide</i>. </p>
+    async fn delete_analysis_scheme(
+        &self,
+        input: DeleteAnalysisSchemeRequest,
+    ) -> Result<DeleteAnalysisSchemeResponse, RusotoError<DeleteAnalysisSchemeError>> {
+        self.delete_analysis_scheme_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Permanently deletes a search domain and all of its data. Once a domain has been deleted, it cannot be recovered. For more information, see <a href="http://docs.aws.amazon.com/cloudsearch/latest/developerguide/deleting-domains.html" target="_blank">Deleting a Search Domain</a> in the <i>Amazon CloudSearch Developer Guide</i>. </p>
+    async fn delete_domain(
+        &self,
+        input: DeleteDomainRequest,
+    ) -> Result<DeleteDomainResponse, RusotoError<DeleteDomainError>> {
+        self.delete_domain_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Removes an <code><a>Expression</a></code> from the search domain. For more information, see <a href="http://docs.aws.amazon.com/cloudsearch/latest/developerguide/configuring-expressions.html" target="_blank">Configuring Expressions</a> in the <i>Amazon CloudSearch Developer Guide</i>.</p>
+    async fn delete_expression(
+        &self,
+        input: DeleteExpressionRequest,
+    ) -> Result<DeleteExpressionResponse, RusotoError<DeleteExpressionError>> {
+        self.delete_expression_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Removes an <code><a>IndexField</a></code> from the search domain. For more information, see <a href="http://docs.aws.amazon.com/cloudsearch/latest/developerguide/configuring-index-fields.html" target="_blank">Configuring Index Fields</a> in the <i>Amazon CloudSearch Developer Guide</i>.</p>
+    async fn delete_index_field(
+        &self,
+        input: DeleteIndexFieldRequest,
+    ) -> Result<DeleteIndexFieldResponse, RusotoError<DeleteIndexFieldError>> {
+        self.delete_index_field_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes a suggester. For more information, see <a href="http://docs.aws.amazon.com/cloudsearch/latest/developerguide/getting-suggestions.html" target="_blank">Getting Search Suggestions</a> in the <i>Amazon CloudSearch Developer Guide</i>.</p>
+    async fn delete_suggester(
+        &self,
+        input: DeleteSuggesterRequest,
+    ) -> Result<DeleteSuggesterResponse, RusotoError<DeleteSuggesterError>> {
+        self.delete_suggester_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Gets the analysis schemes configured for a domain. An analysis scheme defines language-specific text processing options for a <code>text</code> field. Can be limited to specific analysis schemes by name. By default, shows all analysis schemes and includes any pending changes to the configuration. Set the <code>Deployed</code> option to <code>true</code> to show the active configuration and exclude pending changes. For more information, see <a href="http://docs.aws.amazon.com/cloudsearch/latest/developerguide/configuring-analysis-schemes.html" target="_blank">Configuring Analysis Schemes</a> in the <i>Amazon CloudSearch Developer Guide</i>.</p>
+    async fn describe_analysis_schemes(
+        &self,
+        input: DescribeAnalysisSchemesRequest,
+    ) -> Result<DescribeAnalysisSchemesResponse, RusotoError<DescribeAnalysisSchemesError>> {
+        self.describe_analysis_schemes_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Gets the availability options configured for a domain. By default, shows the configuration with any pending changes. Set the <code>Deployed</code> option to <code>true</code> to show the active configuration and exclude pending changes. For more information, see <a href="http://docs.aws.amazon.com/cloudsearch/latest/developerguide/configuring-availability-options.html" target="_blank">Configuring Availability Options</a> in the <i>Amazon CloudSearch Developer Guide</i>.</p>
+    async fn describe_availability_options(
+        &self,
+        input: DescribeAvailabilityOptionsRequest,
+    ) -> Result<DescribeAvailabilityOptionsResponse, RusotoError<DescribeAvailabilityOptionsError>>
+    {
+        self.describe_availability_options_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns the domain's endpoint options, specifically whether all requests to the domain must arrive over HTTPS. For more information, see <a href="http://docs.aws.amazon.com/cloudsearch/latest/developerguide/configuring-domain-endpoint-options.html" target="_blank">Configuring Domain Endpoint Options</a> in the <i>Amazon CloudSearch Developer Guide</i>.</p>
+    async fn describe_domain_endpoint_options(
+        &self,
+        input: DescribeDomainEndpointOptionsRequest,
+    ) -> Result<
+        DescribeDomainEndpointOptionsResponse,
+        RusotoError<DescribeDomainEndpointOptionsError>,
+    > {
+        self.describe_domain_endpoint_options_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Gets information about the search domains owned by this account. Can be limited to specific domains. Shows all domains by default. To get the number of searchable documents in a domain, use the console or submit a <code>matchall</code> request to your domain's search endpoint: <code>q=matchall&amp;amp;q.parser=structured&amp;amp;size=0</code>. For more information, see <a href="http://docs.aws.amazon.com/cloudsearch/latest/developerguide/getting-domain-info.html" target="_blank">Getting Information about a Search Domain</a> in the <i>Amazon CloudSearch Developer Guide</i>.</p>
+    async fn describe_domains(
+        &self,
+        input: DescribeDomainsRequest,
+    ) -> Result<DescribeDomainsResponse, RusotoError<DescribeDomainsError>> {
+        self.describe_domains_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Gets the expressions configured for the search domain. Can be limited to specific expressions by name. By default, shows all expressions and includes any pending changes to the configuration. Set the <code>Deployed</code> option to <code>true</code> to show the active configuration and exclude pending changes. For more information, see <a href="http://docs.aws.amazon.com/cloudsearch/latest/developerguide/configuring-expressions.html" target="_blank">Configuring Expressions</a> in the <i>Amazon CloudSearch Developer Guide</i>.</p>
+    async fn describe_expressions(
+        &self,
+        input: DescribeExpressionsRequest,
+    ) -> Result<DescribeExpressionsResponse, RusotoError<DescribeExpressionsError>> {
+        self.describe_expressions_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Gets information about the index fields configured for the search domain. Can be limited to specific fields by name. By default, shows all fields and includes any pending changes to the configuration. Set the <code>Deployed</code> option to <code>true</code> to show the active configuration and exclude pending changes. For more information, see <a href="http://docs.aws.amazon.com/cloudsearch/latest/developerguide/getting-domain-info.html" target="_blank">Getting Domain Information</a> in the <i>Amazon CloudSearch Developer Guide</i>.</p>
+    async fn describe_index_fields(
+        &self,
+        input: DescribeIndexFieldsRequest,
+    ) -> Result<DescribeIndexFieldsResponse, RusotoError<DescribeIndexFieldsError>> {
+        self.describe_index_fields_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Gets the scaling parameters configured for a domain. A domain's scaling parameters specify the desired search instance type and replication count. For more information, see <a href="http://docs.aws.amazon.com/cloudsearch/latest/developerguide/configuring-scaling-options.html" target="_blank">Configuring Scaling Options</a> in the <i>Amazon CloudSearch Developer Guide</i>.</p>
+    async fn describe_scaling_parameters(
+        &self,
+        input: DescribeScalingParametersRequest,
+    ) -> Result<DescribeScalingParametersResponse, RusotoError<DescribeScalingParametersError>>
+    {
+        self.describe_scaling_parameters_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Gets information about the access policies that control access to the domain's document and search endpoints. By default, shows the configuration with any pending changes. Set the <code>Deployed</code> option to <code>true</code> to show the active configuration and exclude pending changes. For more information, see <a href="http://docs.aws.amazon.com/cloudsearch/latest/developerguide/configuring-access.html" target="_blank">Configuring Access for a Search Domain</a> in the <i>Amazon CloudSearch Developer Guide</i>.</p>
+    async fn describe_service_access_policies(
+        &self,
+        input: DescribeServiceAccessPoliciesRequest,
+    ) -> Result<
+        DescribeServiceAccessPoliciesResponse,
+        RusotoError<DescribeServiceAccessPoliciesError>,
+    > {
+        self.describe_service_access_policies_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Gets the suggesters configured for a domain. A suggester enables you to display possible matches before users finish typing their queries. Can be limited to specific suggesters by name. By default, shows all suggesters and includes any pending changes to the configuration. Set the <code>Deployed</code> option to <code>true</code> to show the active configuration and exclude pending changes. For more information, see <a href="http://docs.aws.amazon.com/cloudsearch/latest/developerguide/getting-suggestions.html" target="_blank">Getting Search Suggestions</a> in the <i>Amazon CloudSearch Developer Guide</i>.</p>
+    async fn describe_suggesters(
+        &self,
+        input: DescribeSuggestersRequest,
+    ) -> Result<DescribeSuggestersResponse, RusotoError<DescribeSuggestersError>> {
+        self.describe_suggesters_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Tells the search domain to start indexing its documents using the latest indexing options. This operation must be invoked to activate options whose <a>OptionStatus</a> is <code>RequiresIndexDocuments</code>.</p>
+    async fn index_documents(
+        &self,
+        input: IndexDocumentsRequest,
+    ) -> Result<IndexDocumentsResponse, RusotoError<IndexDocumentsError>> {
+        self.index_documents_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Lists all search domains owned by an account.</p>
+    async fn list_domain_names(
+        &self,
+    ) -> Result<ListDomainNamesResponse, RusotoError<ListDomainNamesError>> {
+        self.list_domain_names_with_metadata()
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Configures the availability options for a domain. Enabling the Multi-AZ option expands an Amazon CloudSearch domain to an additional Availability Zone in the same Region to increase fault tolerance in the event of a service disruption. Changes to the Multi-AZ option can take about half an hour to become active. For more information, see <a href="http://docs.aws.amazon.com/cloudsearch/latest/developerguide/configuring-availability-options.html" target="_blank">Configuring Availability Options</a> in the <i>Amazon CloudSearch Developer Guide</i>.</p>
+    async fn update_availability_options(
+        &self,
+        input: UpdateAvailabilityOptionsRequest,
+    ) -> Result<UpdateAvailabilityOptionsResponse, RusotoError<UpdateAvailabilityOptionsError>>
+    {
+        self.update_availability_options_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Updates the domain's endpoint options, specifically whether all requests to the domain must arrive over HTTPS. For more information, see <a href="http://docs.aws.amazon.com/cloudsearch/latest/developerguide/configuring-domain-endpoint-options.html" target="_blank">Configuring Domain Endpoint Options</a> in the <i>Amazon CloudSearch Developer Guide</i>.</p>
+    async fn update_domain_endpoint_options(
+        &self,
+        input: UpdateDomainEndpointOptionsRequest,
+    ) -> Result<UpdateDomainEndpointOptionsResponse, RusotoError<UpdateDomainEndpointOptionsError>>
+    {
+        self.update_domain_endpoint_options_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Configures scaling parameters for a domain. A domain's scaling parameters specify the desired search instance type and replication count. Amazon CloudSearch will still automatically scale your domain based on the volume of data and traffic, but not below the desired instance type and replication count. If the Multi-AZ option is enabled, these values control the resources used per Availability Zone. For more information, see <a href="http://docs.aws.amazon.com/cloudsearch/latest/developerguide/configuring-scaling-options.html" target="_blank">Configuring Scaling Options</a> in the <i>Amazon CloudSearch Developer Guide</i>. </p>
+    async fn update_scaling_parameters(
+        &self,
+        input: UpdateScalingParametersRequest,
+    ) -> Result<UpdateScalingParametersResponse, RusotoError<UpdateScalingParametersError>> {
+        self.update_scaling_parameters_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Configures the access rules that control access to the domain's document and search endpoints. For more information, see <a href="http://docs.aws.amazon.com/cloudsearch/latest/developerguide/configuring-access.html" target="_blank"> Configuring Access for an Amazon CloudSearch Domain</a>.</p>
+    async fn update_service_access_policies(
+        &self,
+        input: UpdateServiceAccessPoliciesRequest,
+    ) -> Result<UpdateServiceAccessPoliciesResponse, RusotoError<UpdateServiceAccessPoliciesError>>
+    {
+        self.update_service_access_policies_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
     }
 }
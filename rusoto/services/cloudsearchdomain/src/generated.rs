@@ -16,8 +16,8 @@ use std::fmt;
 use async_trait::async_trait;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
-use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest, ResponseMetadata};
+use rusoto_core::{Client, ErrorMetadata, RusotoError};
 
 use rusoto_core::param::{Params, ServiceParams};
 use rusoto_core::proto;
@@ -358,18 +358,23 @@ pub struct UploadDocumentsResponse {
 
 /// Errors returned by Search
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum SearchError {
     /// <p>Information about any problems encountered while processing a search request.</p>
     Search(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl SearchError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<SearchError> {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
-                "SearchException" => return RusotoError::Service(SearchError::Search(err.msg)),
+                "SearchException" => {
+                    return RusotoError::service(SearchError::Search(err.msg), &res)
+                }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(SearchError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -380,24 +385,30 @@ impl fmt::Display for SearchError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             SearchError::Search(ref cause) => write!(f, "{}", cause),
+            SearchError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for SearchError {}
 /// Errors returned by Suggest
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum SuggestError {
     /// <p>Information about any problems encountered while processing a search request.</p>
     Search(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl SuggestError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<SuggestError> {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
-                "SearchException" => return RusotoError::Service(SuggestError::Search(err.msg)),
+                "SearchException" => {
+                    return RusotoError::service(SuggestError::Search(err.msg), &res)
+                }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(SuggestError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -408,15 +419,19 @@ impl fmt::Display for SuggestError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             SuggestError::Search(ref cause) => write!(f, "{}", cause),
+            SuggestError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for SuggestError {}
 /// Errors returned by UploadDocuments
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UploadDocumentsError {
     /// <p>Information about any problems encountered while processing an upload request.</p>
     DocumentService(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UploadDocumentsError {
@@ -424,10 +439,13 @@ impl UploadDocumentsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "DocumentServiceException" => {
-                    return RusotoError::Service(UploadDocumentsError::DocumentService(err.msg))
+                    return RusotoError::service(
+                        UploadDocumentsError::DocumentService(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UploadDocumentsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -438,6 +456,7 @@ impl fmt::Display for UploadDocumentsError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             UploadDocumentsError::DocumentService(ref cause) => write!(f, "{}", cause),
+            UploadDocumentsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
@@ -474,6 +493,7 @@ impl CloudSearchDomainClient {
     /// Creates a client backed by the default tokio event loop.
     ///
     /// The client will use the default credentials provider and tls client.
+    #[cfg(not(target_arch = "wasm32"))]
     pub fn new(region: region::Region) -> CloudSearchDomainClient {
         CloudSearchDomainClient {
             client: Client::shared(),
@@ -499,18 +519,70 @@ impl CloudSearchDomainClient {
     pub fn new_with_client(client: Client, region: region::Region) -> CloudSearchDomainClient {
         CloudSearchDomainClient { client, region }
     }
+
+    /// Returns a builder of a client, setting any of its region, credentials provider,
+    /// dispatcher, retry policy, endpoint and timeouts.
+    pub fn builder() -> rusoto_core::ClientBuilder<CloudSearchDomainClient> {
+        rusoto_core::ClientBuilder::new(CloudSearchDomainClient::new_with_client)
+    }
+
+    /// Returns a clone of this client with the overrides of `config` applied to its
+    /// operations, sharing its connections.
+    pub fn customize(&self, config: &rusoto_core::OperationConfig) -> CloudSearchDomainClient {
+        CloudSearchDomainClient {
+            client: self.client.customize(config),
+            region: config.resolve_region(&self.region),
+        }
+    }
 }
 
-#[async_trait]
-impl CloudSearchDomain for CloudSearchDomainClient {
-    /// <p>Retrieves a list of documents that match the specified search criteria. How you specify the search criteria depends on which query parser you use. Amazon CloudSearch supports four query parsers:</p> <ul> <li><code>simple</code>: search all <code>text</code> and <code>text-array</code> fields for the specified string. Search for phrases, individual terms, and prefixes. </li> <li><code>structured</code>: search specific fields, construct compound queries using Boolean operators, and use advanced features such as term boosting and proximity searching.</li> <li><code>lucene</code>: specify search criteria using the Apache Lucene query parser syntax.</li> <li><code>dismax</code>: specify search criteria using the simplified subset of the Apache Lucene query parser syntax defined by the DisMax query parser.</li> </ul> <p>For more information, see <a href="http://docs.aws.amazon.com/cloudsearch/latest/developerguide/searching.html">Searching Your Data</a> in the <i>Amazon CloudSearch Developer Guide</i>.</p> <p>The endpoint for submitting <code>Search</code> requests is domain-specific. You submit search requests to a domain's search endpoint. To get the search endpoint for your domain, use the Amazon CloudSearch configuration service <code>DescribeDomains</code> action. A domain's endpoints are also displayed on the domain dashboard in the Amazon CloudSearch console. </p>
-    async fn search(
+impl CloudSearchDomainClient {
+    /// Calls `search`, also returning the metadata of the response.
+    pub async fn search_with_metadata(
         &self,
         input: SearchRequest,
-    ) -> Result<SearchResponse, RusotoError<SearchError>> {
+    ) -> Result<(SearchResponse, ResponseMetadata), RusotoError<SearchError>> {
+        let request = self.build_search_request(input);
+
+        let mut response = self
+            .client
+            .sign_and_dispatch(request)
+            .await
+            .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
+        if response.status.is_success() {
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result =
+                proto::json::ResponsePayload::new(&response).deserialize::<SearchResponse, _>()?;
+
+            Ok((result, metadata))
+        } else {
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(SearchError::from_response(response))
+        }
+    }
+
+    /// Presigns the request `search` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_search(
+        &self,
+        presigner: &rusoto_core::Presigner,
+        input: SearchRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_search_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_search_request(&self, input: SearchRequest) -> SignedRequest {
         let request_uri = "/2013-01-01/search";
 
         let mut request = SignedRequest::new("GET", "cloudsearch", &self.region, &request_uri);
+        request.set_operation("Search");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("cloudsearchdomain".to_string());
@@ -560,31 +632,55 @@ impl CloudSearchDomain for CloudSearchDomainClient {
         params.put("pretty", "true");
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `suggest`, also returning the metadata of the response.
+    pub async fn suggest_with_metadata(
+        &self,
+        input: SuggestRequest,
+    ) -> Result<(SuggestResponse, ResponseMetadata), RusotoError<SuggestError>> {
+        let request = self.build_suggest_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result =
-                proto::json::ResponsePayload::new(&response).deserialize::<SearchResponse, _>()?;
+                proto::json::ResponsePayload::new(&response).deserialize::<SuggestResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(SearchError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(SuggestError::from_response(response))
         }
     }
 
-    /// <p>Retrieves autocomplete suggestions for a partial query string. You can use suggestions enable you to display likely matches before users finish typing. In Amazon CloudSearch, suggestions are based on the contents of a particular text field. When you request suggestions, Amazon CloudSearch finds all of the documents whose values in the suggester field start with the specified query string. The beginning of the field must match the query string to be considered a match. </p> <p>For more information about configuring suggesters and retrieving suggestions, see <a href="http://docs.aws.amazon.com/cloudsearch/latest/developerguide/getting-suggestions.html">Getting Suggestions</a> in the <i>Amazon CloudSearch Developer Guide</i>. </p> <p>The endpoint for submitting <code>Suggest</code> requests is domain-specific. You submit suggest requests to a domain's search endpoint. To get the search endpoint for your domain, use the Amazon CloudSearch configuration service <code>DescribeDomains</code> action. A domain's endpoints are also displayed on the domain dashboard in the Amazon CloudSearch console. </p>
-    async fn suggest(
+    /// Presigns the request `suggest` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_suggest(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: SuggestRequest,
-    ) -> Result<SuggestResponse, RusotoError<SuggestError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_suggest_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_suggest_request(&self, input: SuggestRequest) -> SignedRequest {
         let request_uri = "/2013-01-01/suggest";
 
         let mut request = SignedRequest::new("GET", "cloudsearch", &self.region, &request_uri);
+        request.set_operation("Suggest");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("cloudsearchdomain".to_string());
@@ -599,31 +695,56 @@ impl CloudSearchDomain for CloudSearchDomainClient {
         params.put("pretty", "true");
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `upload_documents`, also returning the metadata of the response.
+    pub async fn upload_documents_with_metadata(
+        &self,
+        input: UploadDocumentsRequest,
+    ) -> Result<(UploadDocumentsResponse, ResponseMetadata), RusotoError<UploadDocumentsError>>
+    {
+        let request = self.build_upload_documents_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            let result =
-                proto::json::ResponsePayload::new(&response).deserialize::<SuggestResponse, _>()?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<UploadDocumentsResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(SuggestError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(UploadDocumentsError::from_response(response))
         }
     }
 
-    /// <p>Posts a batch of documents to a search domain for indexing. A document batch is a collection of add and delete operations that represent the documents you want to add, update, or delete from your domain. Batches can be described in either JSON or XML. Each item that you want Amazon CloudSearch to return as a search result (such as a product) is represented as a document. Every document has a unique ID and one or more fields that contain the data that you want to search and return in results. Individual documents cannot contain more than 1 MB of data. The entire batch cannot exceed 5 MB. To get the best possible upload performance, group add and delete operations in batches that are close the 5 MB limit. Submitting a large volume of single-document batches can overload a domain's document service. </p> <p>The endpoint for submitting <code>UploadDocuments</code> requests is domain-specific. To get the document endpoint for your domain, use the Amazon CloudSearch configuration service <code>DescribeDomains</code> action. A domain's endpoints are also displayed on the domain dashboard in the Amazon CloudSearch console. </p> <p>For more information about formatting your data for Amazon CloudSearch, see <a href="http://docs.aws.amazon.com/cloudsearch/latest/developerguide/preparing-data.html">Preparing Your Data</a> in the <i>Amazon CloudSearch Developer Guide</i>. For more information about uploading data for indexing, see <a href="http://docs.aws.amazon.com/cloudsearch/latest/developerguide/uploading-data.html">Uploading Data</a> in the <i>Amazon CloudSearch Developer Guide</i>. </p>
-    async fn upload_documents(
+    /// Presigns the request `upload_documents` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_upload_documents(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UploadDocumentsRequest,
-    ) -> Result<UploadDocumentsResponse, RusotoError<UploadDocumentsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_upload_documents_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_upload_documents_request(&self, input: UploadDocumentsRequest) -> SignedRequest {
         let request_uri = "/2013-01-01/documents/batch";
 
         let mut request = SignedRequest::new("POST", "cloudsearch", &self.region, &request_uri);
+        request.set_operation("UploadDocuments");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("cloudsearchdomain".to_string());
@@ -634,20 +755,39 @@ impl CloudSearchDomain for CloudSearchDomainClient {
         params.put("format", "sdk");
         request.set_params(params);
 
-        let mut response = self
-            .client
-            .sign_and_dispatch(request)
+        request
+    }
+}
+
+#[async_trait]
+impl CloudSearchDomain for CloudSearchDomainClient {
+    /// <p>Retrieves a list of documents that match the specified search criteria. How you specify the search criteria depends on which query parser you use. Amazon CloudSearch supports four query parsers:</p> <ul> <li><code>simple</code>: search all <code>text</code> and <code>text-array</code> fields for the specified string. Search for phrases, individual terms, and prefixes. </li> <li><code>structured</code>: search specific fields, construct compound queries using Boolean operators, and use advanced features such as term boosting and proximity searching.</li> <li><code>lucene</code>: specify search criteria using the Apache Lucene query parser syntax.</li> <li><code>dismax</code>: specify search criteria using the simplified subset of the Apache Lucene query parser syntax defined by the DisMax query parser.</li> </ul> <p>For more information, see <a href="http://docs.aws.amazon.com/cloudsearch/latest/developerguide/searching.html">Searching Your Data</a> in the <i>Amazon CloudSearch Developer Guide</i>.</p> <p>The endpoint for submitting <code>Search</code> requests is domain-specific. You submit search requests to a domain's search endpoint. To get the search endpoint for your domain, use the Amazon CloudSearch configuration service <code>DescribeDomains</code> action. A domain's endpoints are also displayed on the domain dashboard in the Amazon CloudSearch console. </p>
+    async fn search(
+        &self,
+        input: SearchRequest,
+    ) -> Result<SearchResponse, RusotoError<SearchError>> {
+        self.search_with_metadata(input)
             .await
-            .map_err(RusotoError::from)?;
-        if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<UploadDocumentsResponse, _>()?;
+            .map(|(output, _)| output)
+    }
 
-            Ok(result)
-        } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(UploadDocumentsError::from_response(response))
-        }
+    /// <p>Retrieves autocomplete suggestions for a partial query string. You can use suggestions enable you to display likely matches before users finish typing. In Amazon CloudSearch, suggestions are based on the contents of a particular text field. When you request suggestions, Amazon CloudSearch finds all of the documents whose values in the suggester field start with the specified query string. The beginning of the field must match the query string to be considered a match. </p> <p>For more information about configuring suggesters and retrieving suggestions, see <a href="http://docs.aws.amazon.com/cloudsearch/latest/developerguide/getting-suggestions.html">Getting Suggestions</a> in the <i>Amazon CloudSearch Developer Guide</i>. </p> <p>The endpoint for submitting <code>Suggest</code> requests is domain-specific. You submit suggest requests to a domain's search endpoint. To get the search endpoint for your domain, use the Amazon CloudSearch configuration service <code>DescribeDomains</code> action. A domain's endpoints are also displayed on the domain dashboard in the Amazon CloudSearch console. </p>
+    async fn suggest(
+        &self,
+        input: SuggestRequest,
+    ) -> Result<SuggestResponse, RusotoError<SuggestError>> {
+        self.suggest_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Posts a batch of documents to a search domain for indexing. A document batch is a collection of add and delete operations that represent the documents you want to add, update, or delete from your domain. Batches can be described in either JSON or XML. Each item that you want Amazon CloudSearch to return as a search result (such as a product) is represented as a document. Every document has a unique ID and one or more fields that contain the data that you want to search and return in results. Individual documents cannot contain more than 1 MB of data. The entire batch cannot exceed 5 MB. To get the best possible upload performance, group add and delete operations in batches that are close the 5 MB limit. Submitting a large volume of single-document batches can overload a domain's document service. </p> <p>The endpoint for submitting <code>UploadDocuments</code> requests is domain-specific. To get the document endpoint for your domain, use the Amazon CloudSearch configuration service <code>DescribeDomains</code> action. A domain's endpoints are also displayed on the domain dashboard in the Amazon CloudSearch console. </p> <p>For more information about formatting your data for Amazon CloudSearch, see <a href="http://docs.aws.amazon.com/cloudsearch/latest/developerguide/preparing-data.html">Preparing Your Data</a> in the <i>Amazon CloudSearch Developer Guide</i>. For more information about uploading data for indexing, see <a href="http://docs.aws.amazon.com/cloudsearch/latest/developerguide/uploading-data.html">Uploading Data</a> in the <i>Amazon CloudSearch Developer Guide</i>. </p>
+    async fn upload_documents(
+        &self,
+        input: UploadDocumentsRequest,
+    ) -> Result<UploadDocumentsResponse, RusotoError<UploadDocumentsError>> {
+        self.upload_documents_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
     }
 }
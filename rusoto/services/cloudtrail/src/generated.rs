@@ -16,8 +16,8 @@ use std::fmt;
 use async_trait::async_trait;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
-use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest, ResponseMetadata};
+use rusoto_core::{Client, ErrorMetadata, RusotoError};
 
 use rusoto_core::proto;
 use rusoto_core::signature::SignedRequest;
@@ -898,6 +898,7 @@ pub struct UpdateTrailResponse {
 
 /// Errors returned by AddTags
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum AddTagsError {
     /// <p>This exception is thrown when an operation is called with an invalid trail ARN. The format of a trail ARN is:</p> <p> <code>arn:aws:cloudtrail:us-east-2:123456789012:trail/MyTrail</code> </p>
     CloudTrailARNInvalid(String),
@@ -917,6 +918,8 @@ pub enum AddTagsError {
     TagsLimitExceeded(String),
     /// <p>This exception is thrown when the requested operation is not supported.</p>
     UnsupportedOperation(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl AddTagsError {
@@ -924,36 +927,40 @@ impl AddTagsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "CloudTrailARNInvalidException" => {
-                    return RusotoError::Service(AddTagsError::CloudTrailARNInvalid(err.msg))
+                    return RusotoError::service(AddTagsError::CloudTrailARNInvalid(err.msg), &res)
                 }
                 "InvalidTagParameterException" => {
-                    return RusotoError::Service(AddTagsError::InvalidTagParameter(err.msg))
+                    return RusotoError::service(AddTagsError::InvalidTagParameter(err.msg), &res)
                 }
                 "InvalidTrailNameException" => {
-                    return RusotoError::Service(AddTagsError::InvalidTrailName(err.msg))
+                    return RusotoError::service(AddTagsError::InvalidTrailName(err.msg), &res)
                 }
                 "NotOrganizationMasterAccountException" => {
-                    return RusotoError::Service(AddTagsError::NotOrganizationMasterAccount(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        AddTagsError::NotOrganizationMasterAccount(err.msg),
+                        &res,
+                    )
                 }
                 "OperationNotPermittedException" => {
-                    return RusotoError::Service(AddTagsError::OperationNotPermitted(err.msg))
+                    return RusotoError::service(AddTagsError::OperationNotPermitted(err.msg), &res)
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(AddTagsError::ResourceNotFound(err.msg))
+                    return RusotoError::service(AddTagsError::ResourceNotFound(err.msg), &res)
                 }
                 "ResourceTypeNotSupportedException" => {
-                    return RusotoError::Service(AddTagsError::ResourceTypeNotSupported(err.msg))
+                    return RusotoError::service(
+                        AddTagsError::ResourceTypeNotSupported(err.msg),
+                        &res,
+                    )
                 }
                 "TagsLimitExceededException" => {
-                    return RusotoError::Service(AddTagsError::TagsLimitExceeded(err.msg))
+                    return RusotoError::service(AddTagsError::TagsLimitExceeded(err.msg), &res)
                 }
                 "UnsupportedOperationException" => {
-                    return RusotoError::Service(AddTagsError::UnsupportedOperation(err.msg))
+                    return RusotoError::service(AddTagsError::UnsupportedOperation(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(AddTagsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -972,12 +979,14 @@ impl fmt::Display for AddTagsError {
             AddTagsError::ResourceTypeNotSupported(ref cause) => write!(f, "{}", cause),
             AddTagsError::TagsLimitExceeded(ref cause) => write!(f, "{}", cause),
             AddTagsError::UnsupportedOperation(ref cause) => write!(f, "{}", cause),
+            AddTagsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for AddTagsError {}
 /// Errors returned by CreateTrail
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateTrailError {
     /// <p>This exception is thrown when trusted access has not been enabled between AWS CloudTrail and AWS Organizations. For more information, see <a href="https://docs.aws.amazon.com/organizations/latest/userguide/orgs_integrate_services.html">Enabling Trusted Access with Other AWS Services</a> and <a href="https://docs.aws.amazon.com/awscloudtrail/latest/userguide/creating-an-organizational-trail-prepare.html">Prepare For Creating a Trail For Your Organization</a>. </p>
     CloudTrailAccessNotEnabled(String),
@@ -1033,6 +1042,8 @@ pub enum CreateTrailError {
     TrailNotProvided(String),
     /// <p>This exception is thrown when the requested operation is not supported.</p>
     UnsupportedOperation(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateTrailError {
@@ -1040,110 +1051,148 @@ impl CreateTrailError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "CloudTrailAccessNotEnabledException" => {
-                    return RusotoError::Service(CreateTrailError::CloudTrailAccessNotEnabled(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateTrailError::CloudTrailAccessNotEnabled(err.msg),
+                        &res,
+                    )
                 }
                 "CloudWatchLogsDeliveryUnavailableException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         CreateTrailError::CloudWatchLogsDeliveryUnavailable(err.msg),
+                        &res,
                     )
                 }
                 "InsufficientDependencyServiceAccessPermissionException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         CreateTrailError::InsufficientDependencyServiceAccessPermission(err.msg),
+                        &res,
                     )
                 }
                 "InsufficientEncryptionPolicyException" => {
-                    return RusotoError::Service(CreateTrailError::InsufficientEncryptionPolicy(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateTrailError::InsufficientEncryptionPolicy(err.msg),
+                        &res,
+                    )
                 }
                 "InsufficientS3BucketPolicyException" => {
-                    return RusotoError::Service(CreateTrailError::InsufficientS3BucketPolicy(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateTrailError::InsufficientS3BucketPolicy(err.msg),
+                        &res,
+                    )
                 }
                 "InsufficientSnsTopicPolicyException" => {
-                    return RusotoError::Service(CreateTrailError::InsufficientSnsTopicPolicy(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateTrailError::InsufficientSnsTopicPolicy(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidCloudWatchLogsLogGroupArnException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         CreateTrailError::InvalidCloudWatchLogsLogGroupArn(err.msg),
+                        &res,
                     )
                 }
                 "InvalidCloudWatchLogsRoleArnException" => {
-                    return RusotoError::Service(CreateTrailError::InvalidCloudWatchLogsRoleArn(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateTrailError::InvalidCloudWatchLogsRoleArn(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidKmsKeyIdException" => {
-                    return RusotoError::Service(CreateTrailError::InvalidKmsKeyId(err.msg))
+                    return RusotoError::service(CreateTrailError::InvalidKmsKeyId(err.msg), &res)
                 }
                 "InvalidParameterCombinationException" => {
-                    return RusotoError::Service(CreateTrailError::InvalidParameterCombination(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateTrailError::InvalidParameterCombination(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidS3BucketNameException" => {
-                    return RusotoError::Service(CreateTrailError::InvalidS3BucketName(err.msg))
+                    return RusotoError::service(
+                        CreateTrailError::InvalidS3BucketName(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidS3PrefixException" => {
-                    return RusotoError::Service(CreateTrailError::InvalidS3Prefix(err.msg))
+                    return RusotoError::service(CreateTrailError::InvalidS3Prefix(err.msg), &res)
                 }
                 "InvalidSnsTopicNameException" => {
-                    return RusotoError::Service(CreateTrailError::InvalidSnsTopicName(err.msg))
+                    return RusotoError::service(
+                        CreateTrailError::InvalidSnsTopicName(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidTagParameterException" => {
-                    return RusotoError::Service(CreateTrailError::InvalidTagParameter(err.msg))
+                    return RusotoError::service(
+                        CreateTrailError::InvalidTagParameter(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidTrailNameException" => {
-                    return RusotoError::Service(CreateTrailError::InvalidTrailName(err.msg))
+                    return RusotoError::service(CreateTrailError::InvalidTrailName(err.msg), &res)
+                }
+                "KmsException" => {
+                    return RusotoError::service(CreateTrailError::Kms(err.msg), &res)
                 }
-                "KmsException" => return RusotoError::Service(CreateTrailError::Kms(err.msg)),
                 "KmsKeyDisabledException" => {
-                    return RusotoError::Service(CreateTrailError::KmsKeyDisabled(err.msg))
+                    return RusotoError::service(CreateTrailError::KmsKeyDisabled(err.msg), &res)
                 }
                 "KmsKeyNotFoundException" => {
-                    return RusotoError::Service(CreateTrailError::KmsKeyNotFound(err.msg))
+                    return RusotoError::service(CreateTrailError::KmsKeyNotFound(err.msg), &res)
                 }
                 "MaximumNumberOfTrailsExceededException" => {
-                    return RusotoError::Service(CreateTrailError::MaximumNumberOfTrailsExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateTrailError::MaximumNumberOfTrailsExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "NotOrganizationMasterAccountException" => {
-                    return RusotoError::Service(CreateTrailError::NotOrganizationMasterAccount(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateTrailError::NotOrganizationMasterAccount(err.msg),
+                        &res,
+                    )
                 }
                 "OperationNotPermittedException" => {
-                    return RusotoError::Service(CreateTrailError::OperationNotPermitted(err.msg))
+                    return RusotoError::service(
+                        CreateTrailError::OperationNotPermitted(err.msg),
+                        &res,
+                    )
                 }
                 "OrganizationNotInAllFeaturesModeException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         CreateTrailError::OrganizationNotInAllFeaturesMode(err.msg),
+                        &res,
                     )
                 }
                 "OrganizationsNotInUseException" => {
-                    return RusotoError::Service(CreateTrailError::OrganizationsNotInUse(err.msg))
+                    return RusotoError::service(
+                        CreateTrailError::OrganizationsNotInUse(err.msg),
+                        &res,
+                    )
                 }
                 "S3BucketDoesNotExistException" => {
-                    return RusotoError::Service(CreateTrailError::S3BucketDoesNotExist(err.msg))
+                    return RusotoError::service(
+                        CreateTrailError::S3BucketDoesNotExist(err.msg),
+                        &res,
+                    )
                 }
                 "TrailAlreadyExistsException" => {
-                    return RusotoError::Service(CreateTrailError::TrailAlreadyExists(err.msg))
+                    return RusotoError::service(
+                        CreateTrailError::TrailAlreadyExists(err.msg),
+                        &res,
+                    )
                 }
                 "TrailNotProvidedException" => {
-                    return RusotoError::Service(CreateTrailError::TrailNotProvided(err.msg))
+                    return RusotoError::service(CreateTrailError::TrailNotProvided(err.msg), &res)
                 }
                 "UnsupportedOperationException" => {
-                    return RusotoError::Service(CreateTrailError::UnsupportedOperation(err.msg))
+                    return RusotoError::service(
+                        CreateTrailError::UnsupportedOperation(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreateTrailError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1184,12 +1233,14 @@ impl fmt::Display for CreateTrailError {
             CreateTrailError::TrailAlreadyExists(ref cause) => write!(f, "{}", cause),
             CreateTrailError::TrailNotProvided(ref cause) => write!(f, "{}", cause),
             CreateTrailError::UnsupportedOperation(ref cause) => write!(f, "{}", cause),
+            CreateTrailError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateTrailError {}
 /// Errors returned by DeleteTrail
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteTrailError {
     /// <p>This exception is thrown when the IAM user or role that is used to create the organization trail is lacking one or more required permissions for creating an organization trail in a required service. For more information, see <a href="https://docs.aws.amazon.com/awscloudtrail/latest/userguide/creating-an-organizational-trail-prepare.html">Prepare For Creating a Trail For Your Organization</a>.</p>
     InsufficientDependencyServiceAccessPermission(String),
@@ -1205,6 +1256,8 @@ pub enum DeleteTrailError {
     TrailNotFound(String),
     /// <p>This exception is thrown when the requested operation is not supported.</p>
     UnsupportedOperation(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteTrailError {
@@ -1212,32 +1265,40 @@ impl DeleteTrailError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InsufficientDependencyServiceAccessPermissionException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DeleteTrailError::InsufficientDependencyServiceAccessPermission(err.msg),
+                        &res,
                     )
                 }
                 "InvalidHomeRegionException" => {
-                    return RusotoError::Service(DeleteTrailError::InvalidHomeRegion(err.msg))
+                    return RusotoError::service(DeleteTrailError::InvalidHomeRegion(err.msg), &res)
                 }
                 "InvalidTrailNameException" => {
-                    return RusotoError::Service(DeleteTrailError::InvalidTrailName(err.msg))
+                    return RusotoError::service(DeleteTrailError::InvalidTrailName(err.msg), &res)
                 }
                 "NotOrganizationMasterAccountException" => {
-                    return RusotoError::Service(DeleteTrailError::NotOrganizationMasterAccount(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteTrailError::NotOrganizationMasterAccount(err.msg),
+                        &res,
+                    )
                 }
                 "OperationNotPermittedException" => {
-                    return RusotoError::Service(DeleteTrailError::OperationNotPermitted(err.msg))
+                    return RusotoError::service(
+                        DeleteTrailError::OperationNotPermitted(err.msg),
+                        &res,
+                    )
                 }
                 "TrailNotFoundException" => {
-                    return RusotoError::Service(DeleteTrailError::TrailNotFound(err.msg))
+                    return RusotoError::service(DeleteTrailError::TrailNotFound(err.msg), &res)
                 }
                 "UnsupportedOperationException" => {
-                    return RusotoError::Service(DeleteTrailError::UnsupportedOperation(err.msg))
+                    return RusotoError::service(
+                        DeleteTrailError::UnsupportedOperation(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteTrailError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1256,12 +1317,14 @@ impl fmt::Display for DeleteTrailError {
             DeleteTrailError::OperationNotPermitted(ref cause) => write!(f, "{}", cause),
             DeleteTrailError::TrailNotFound(ref cause) => write!(f, "{}", cause),
             DeleteTrailError::UnsupportedOperation(ref cause) => write!(f, "{}", cause),
+            DeleteTrailError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteTrailError {}
 /// Errors returned by DescribeTrails
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeTrailsError {
     /// <p><p>This exception is thrown when the provided trail name is not valid. Trail names must meet the following requirements:</p> <ul> <li> <p>Contain only ASCII letters (a-z, A-Z), numbers (0-9), periods (.), underscores (<em>), or dashes (-)</p> </li> <li> <p>Start with a letter or number, and end with a letter or number</p> </li> <li> <p>Be between 3 and 128 characters</p> </li> <li> <p>Have no adjacent periods, underscores or dashes. Names like <code>my-</em>namespace</code> and <code>my--namespace</code> are invalid.</p> </li> <li> <p>Not be in IP address format (for example, 192.168.5.4)</p> </li> </ul></p>
     InvalidTrailName(String),
@@ -1269,6 +1332,8 @@ pub enum DescribeTrailsError {
     OperationNotPermitted(String),
     /// <p>This exception is thrown when the requested operation is not supported.</p>
     UnsupportedOperation(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeTrailsError {
@@ -1276,18 +1341,25 @@ impl DescribeTrailsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidTrailNameException" => {
-                    return RusotoError::Service(DescribeTrailsError::InvalidTrailName(err.msg))
+                    return RusotoError::service(
+                        DescribeTrailsError::InvalidTrailName(err.msg),
+                        &res,
+                    )
                 }
                 "OperationNotPermittedException" => {
-                    return RusotoError::Service(DescribeTrailsError::OperationNotPermitted(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeTrailsError::OperationNotPermitted(err.msg),
+                        &res,
+                    )
                 }
                 "UnsupportedOperationException" => {
-                    return RusotoError::Service(DescribeTrailsError::UnsupportedOperation(err.msg))
+                    return RusotoError::service(
+                        DescribeTrailsError::UnsupportedOperation(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DescribeTrailsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1300,12 +1372,14 @@ impl fmt::Display for DescribeTrailsError {
             DescribeTrailsError::InvalidTrailName(ref cause) => write!(f, "{}", cause),
             DescribeTrailsError::OperationNotPermitted(ref cause) => write!(f, "{}", cause),
             DescribeTrailsError::UnsupportedOperation(ref cause) => write!(f, "{}", cause),
+            DescribeTrailsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeTrailsError {}
 /// Errors returned by GetEventSelectors
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetEventSelectorsError {
     /// <p><p>This exception is thrown when the provided trail name is not valid. Trail names must meet the following requirements:</p> <ul> <li> <p>Contain only ASCII letters (a-z, A-Z), numbers (0-9), periods (.), underscores (<em>), or dashes (-)</p> </li> <li> <p>Start with a letter or number, and end with a letter or number</p> </li> <li> <p>Be between 3 and 128 characters</p> </li> <li> <p>Have no adjacent periods, underscores or dashes. Names like <code>my-</em>namespace</code> and <code>my--namespace</code> are invalid.</p> </li> <li> <p>Not be in IP address format (for example, 192.168.5.4)</p> </li> </ul></p>
     InvalidTrailName(String),
@@ -1315,6 +1389,8 @@ pub enum GetEventSelectorsError {
     TrailNotFound(String),
     /// <p>This exception is thrown when the requested operation is not supported.</p>
     UnsupportedOperation(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetEventSelectorsError {
@@ -1322,23 +1398,31 @@ impl GetEventSelectorsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidTrailNameException" => {
-                    return RusotoError::Service(GetEventSelectorsError::InvalidTrailName(err.msg))
+                    return RusotoError::service(
+                        GetEventSelectorsError::InvalidTrailName(err.msg),
+                        &res,
+                    )
                 }
                 "OperationNotPermittedException" => {
-                    return RusotoError::Service(GetEventSelectorsError::OperationNotPermitted(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetEventSelectorsError::OperationNotPermitted(err.msg),
+                        &res,
+                    )
                 }
                 "TrailNotFoundException" => {
-                    return RusotoError::Service(GetEventSelectorsError::TrailNotFound(err.msg))
+                    return RusotoError::service(
+                        GetEventSelectorsError::TrailNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "UnsupportedOperationException" => {
-                    return RusotoError::Service(GetEventSelectorsError::UnsupportedOperation(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetEventSelectorsError::UnsupportedOperation(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetEventSelectorsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1352,12 +1436,14 @@ impl fmt::Display for GetEventSelectorsError {
             GetEventSelectorsError::OperationNotPermitted(ref cause) => write!(f, "{}", cause),
             GetEventSelectorsError::TrailNotFound(ref cause) => write!(f, "{}", cause),
             GetEventSelectorsError::UnsupportedOperation(ref cause) => write!(f, "{}", cause),
+            GetEventSelectorsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetEventSelectorsError {}
 /// Errors returned by GetInsightSelectors
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetInsightSelectorsError {
     /// <p>If you run <code>GetInsightSelectors</code> on a trail that does not have Insights events enabled, the operation throws the exception <code>InsightNotEnabledException</code>.</p>
     InsightNotEnabled(String),
@@ -1369,6 +1455,8 @@ pub enum GetInsightSelectorsError {
     TrailNotFound(String),
     /// <p>This exception is thrown when the requested operation is not supported.</p>
     UnsupportedOperation(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetInsightSelectorsError {
@@ -1376,30 +1464,37 @@ impl GetInsightSelectorsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InsightNotEnabledException" => {
-                    return RusotoError::Service(GetInsightSelectorsError::InsightNotEnabled(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetInsightSelectorsError::InsightNotEnabled(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidTrailNameException" => {
-                    return RusotoError::Service(GetInsightSelectorsError::InvalidTrailName(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetInsightSelectorsError::InvalidTrailName(err.msg),
+                        &res,
+                    )
                 }
                 "OperationNotPermittedException" => {
-                    return RusotoError::Service(GetInsightSelectorsError::OperationNotPermitted(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetInsightSelectorsError::OperationNotPermitted(err.msg),
+                        &res,
+                    )
                 }
                 "TrailNotFoundException" => {
-                    return RusotoError::Service(GetInsightSelectorsError::TrailNotFound(err.msg))
+                    return RusotoError::service(
+                        GetInsightSelectorsError::TrailNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "UnsupportedOperationException" => {
-                    return RusotoError::Service(GetInsightSelectorsError::UnsupportedOperation(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetInsightSelectorsError::UnsupportedOperation(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetInsightSelectorsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1414,12 +1509,14 @@ impl fmt::Display for GetInsightSelectorsError {
             GetInsightSelectorsError::OperationNotPermitted(ref cause) => write!(f, "{}", cause),
             GetInsightSelectorsError::TrailNotFound(ref cause) => write!(f, "{}", cause),
             GetInsightSelectorsError::UnsupportedOperation(ref cause) => write!(f, "{}", cause),
+            GetInsightSelectorsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetInsightSelectorsError {}
 /// Errors returned by GetTrail
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetTrailError {
     /// <p><p>This exception is thrown when the provided trail name is not valid. Trail names must meet the following requirements:</p> <ul> <li> <p>Contain only ASCII letters (a-z, A-Z), numbers (0-9), periods (.), underscores (<em>), or dashes (-)</p> </li> <li> <p>Start with a letter or number, and end with a letter or number</p> </li> <li> <p>Be between 3 and 128 characters</p> </li> <li> <p>Have no adjacent periods, underscores or dashes. Names like <code>my-</em>namespace</code> and <code>my--namespace</code> are invalid.</p> </li> <li> <p>Not be in IP address format (for example, 192.168.5.4)</p> </li> </ul></p>
     InvalidTrailName(String),
@@ -1429,6 +1526,8 @@ pub enum GetTrailError {
     TrailNotFound(String),
     /// <p>This exception is thrown when the requested operation is not supported.</p>
     UnsupportedOperation(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetTrailError {
@@ -1436,19 +1535,22 @@ impl GetTrailError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidTrailNameException" => {
-                    return RusotoError::Service(GetTrailError::InvalidTrailName(err.msg))
+                    return RusotoError::service(GetTrailError::InvalidTrailName(err.msg), &res)
                 }
                 "OperationNotPermittedException" => {
-                    return RusotoError::Service(GetTrailError::OperationNotPermitted(err.msg))
+                    return RusotoError::service(
+                        GetTrailError::OperationNotPermitted(err.msg),
+                        &res,
+                    )
                 }
                 "TrailNotFoundException" => {
-                    return RusotoError::Service(GetTrailError::TrailNotFound(err.msg))
+                    return RusotoError::service(GetTrailError::TrailNotFound(err.msg), &res)
                 }
                 "UnsupportedOperationException" => {
-                    return RusotoError::Service(GetTrailError::UnsupportedOperation(err.msg))
+                    return RusotoError::service(GetTrailError::UnsupportedOperation(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetTrailError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1462,12 +1564,14 @@ impl fmt::Display for GetTrailError {
             GetTrailError::OperationNotPermitted(ref cause) => write!(f, "{}", cause),
             GetTrailError::TrailNotFound(ref cause) => write!(f, "{}", cause),
             GetTrailError::UnsupportedOperation(ref cause) => write!(f, "{}", cause),
+            GetTrailError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetTrailError {}
 /// Errors returned by GetTrailStatus
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetTrailStatusError {
     /// <p><p>This exception is thrown when the provided trail name is not valid. Trail names must meet the following requirements:</p> <ul> <li> <p>Contain only ASCII letters (a-z, A-Z), numbers (0-9), periods (.), underscores (<em>), or dashes (-)</p> </li> <li> <p>Start with a letter or number, and end with a letter or number</p> </li> <li> <p>Be between 3 and 128 characters</p> </li> <li> <p>Have no adjacent periods, underscores or dashes. Names like <code>my-</em>namespace</code> and <code>my--namespace</code> are invalid.</p> </li> <li> <p>Not be in IP address format (for example, 192.168.5.4)</p> </li> </ul></p>
     InvalidTrailName(String),
@@ -1477,6 +1581,8 @@ pub enum GetTrailStatusError {
     TrailNotFound(String),
     /// <p>This exception is thrown when the requested operation is not supported.</p>
     UnsupportedOperation(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetTrailStatusError {
@@ -1484,21 +1590,28 @@ impl GetTrailStatusError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidTrailNameException" => {
-                    return RusotoError::Service(GetTrailStatusError::InvalidTrailName(err.msg))
+                    return RusotoError::service(
+                        GetTrailStatusError::InvalidTrailName(err.msg),
+                        &res,
+                    )
                 }
                 "OperationNotPermittedException" => {
-                    return RusotoError::Service(GetTrailStatusError::OperationNotPermitted(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetTrailStatusError::OperationNotPermitted(err.msg),
+                        &res,
+                    )
                 }
                 "TrailNotFoundException" => {
-                    return RusotoError::Service(GetTrailStatusError::TrailNotFound(err.msg))
+                    return RusotoError::service(GetTrailStatusError::TrailNotFound(err.msg), &res)
                 }
                 "UnsupportedOperationException" => {
-                    return RusotoError::Service(GetTrailStatusError::UnsupportedOperation(err.msg))
+                    return RusotoError::service(
+                        GetTrailStatusError::UnsupportedOperation(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetTrailStatusError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1512,12 +1625,14 @@ impl fmt::Display for GetTrailStatusError {
             GetTrailStatusError::OperationNotPermitted(ref cause) => write!(f, "{}", cause),
             GetTrailStatusError::TrailNotFound(ref cause) => write!(f, "{}", cause),
             GetTrailStatusError::UnsupportedOperation(ref cause) => write!(f, "{}", cause),
+            GetTrailStatusError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetTrailStatusError {}
 /// Errors returned by ListPublicKeys
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListPublicKeysError {
     /// <p>Occurs if the timestamp values are invalid. Either the start time occurs after the end time or the time range is outside the range of possible values.</p>
     InvalidTimeRange(String),
@@ -1527,6 +1642,8 @@ pub enum ListPublicKeysError {
     OperationNotPermitted(String),
     /// <p>This exception is thrown when the requested operation is not supported.</p>
     UnsupportedOperation(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListPublicKeysError {
@@ -1534,21 +1651,28 @@ impl ListPublicKeysError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidTimeRangeException" => {
-                    return RusotoError::Service(ListPublicKeysError::InvalidTimeRange(err.msg))
+                    return RusotoError::service(
+                        ListPublicKeysError::InvalidTimeRange(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidTokenException" => {
-                    return RusotoError::Service(ListPublicKeysError::InvalidToken(err.msg))
+                    return RusotoError::service(ListPublicKeysError::InvalidToken(err.msg), &res)
                 }
                 "OperationNotPermittedException" => {
-                    return RusotoError::Service(ListPublicKeysError::OperationNotPermitted(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListPublicKeysError::OperationNotPermitted(err.msg),
+                        &res,
+                    )
                 }
                 "UnsupportedOperationException" => {
-                    return RusotoError::Service(ListPublicKeysError::UnsupportedOperation(err.msg))
+                    return RusotoError::service(
+                        ListPublicKeysError::UnsupportedOperation(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListPublicKeysError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1562,12 +1686,14 @@ impl fmt::Display for ListPublicKeysError {
             ListPublicKeysError::InvalidToken(ref cause) => write!(f, "{}", cause),
             ListPublicKeysError::OperationNotPermitted(ref cause) => write!(f, "{}", cause),
             ListPublicKeysError::UnsupportedOperation(ref cause) => write!(f, "{}", cause),
+            ListPublicKeysError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListPublicKeysError {}
 /// Errors returned by ListTags
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListTagsError {
     /// <p>This exception is thrown when an operation is called with an invalid trail ARN. The format of a trail ARN is:</p> <p> <code>arn:aws:cloudtrail:us-east-2:123456789012:trail/MyTrail</code> </p>
     CloudTrailARNInvalid(String),
@@ -1583,6 +1709,8 @@ pub enum ListTagsError {
     ResourceTypeNotSupported(String),
     /// <p>This exception is thrown when the requested operation is not supported.</p>
     UnsupportedOperation(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListTagsError {
@@ -1590,28 +1718,34 @@ impl ListTagsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "CloudTrailARNInvalidException" => {
-                    return RusotoError::Service(ListTagsError::CloudTrailARNInvalid(err.msg))
+                    return RusotoError::service(ListTagsError::CloudTrailARNInvalid(err.msg), &res)
                 }
                 "InvalidTokenException" => {
-                    return RusotoError::Service(ListTagsError::InvalidToken(err.msg))
+                    return RusotoError::service(ListTagsError::InvalidToken(err.msg), &res)
                 }
                 "InvalidTrailNameException" => {
-                    return RusotoError::Service(ListTagsError::InvalidTrailName(err.msg))
+                    return RusotoError::service(ListTagsError::InvalidTrailName(err.msg), &res)
                 }
                 "OperationNotPermittedException" => {
-                    return RusotoError::Service(ListTagsError::OperationNotPermitted(err.msg))
+                    return RusotoError::service(
+                        ListTagsError::OperationNotPermitted(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListTagsError::ResourceNotFound(err.msg))
+                    return RusotoError::service(ListTagsError::ResourceNotFound(err.msg), &res)
                 }
                 "ResourceTypeNotSupportedException" => {
-                    return RusotoError::Service(ListTagsError::ResourceTypeNotSupported(err.msg))
+                    return RusotoError::service(
+                        ListTagsError::ResourceTypeNotSupported(err.msg),
+                        &res,
+                    )
                 }
                 "UnsupportedOperationException" => {
-                    return RusotoError::Service(ListTagsError::UnsupportedOperation(err.msg))
+                    return RusotoError::service(ListTagsError::UnsupportedOperation(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListTagsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1628,17 +1762,21 @@ impl fmt::Display for ListTagsError {
             ListTagsError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             ListTagsError::ResourceTypeNotSupported(ref cause) => write!(f, "{}", cause),
             ListTagsError::UnsupportedOperation(ref cause) => write!(f, "{}", cause),
+            ListTagsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListTagsError {}
 /// Errors returned by ListTrails
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListTrailsError {
     /// <p>This exception is thrown when the requested operation is not permitted.</p>
     OperationNotPermitted(String),
     /// <p>This exception is thrown when the requested operation is not supported.</p>
     UnsupportedOperation(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListTrailsError {
@@ -1646,13 +1784,19 @@ impl ListTrailsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "OperationNotPermittedException" => {
-                    return RusotoError::Service(ListTrailsError::OperationNotPermitted(err.msg))
+                    return RusotoError::service(
+                        ListTrailsError::OperationNotPermitted(err.msg),
+                        &res,
+                    )
                 }
                 "UnsupportedOperationException" => {
-                    return RusotoError::Service(ListTrailsError::UnsupportedOperation(err.msg))
+                    return RusotoError::service(
+                        ListTrailsError::UnsupportedOperation(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListTrailsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1664,12 +1808,14 @@ impl fmt::Display for ListTrailsError {
         match *self {
             ListTrailsError::OperationNotPermitted(ref cause) => write!(f, "{}", cause),
             ListTrailsError::UnsupportedOperation(ref cause) => write!(f, "{}", cause),
+            ListTrailsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListTrailsError {}
 /// Errors returned by LookupEvents
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum LookupEventsError {
     /// <p>Occurs if an event category that is not valid is specified as a value of <code>EventCategory</code>.</p>
     InvalidEventCategory(String),
@@ -1685,6 +1831,8 @@ pub enum LookupEventsError {
     OperationNotPermitted(String),
     /// <p>This exception is thrown when the requested operation is not supported.</p>
     UnsupportedOperation(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl LookupEventsError {
@@ -1692,30 +1840,43 @@ impl LookupEventsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidEventCategoryException" => {
-                    return RusotoError::Service(LookupEventsError::InvalidEventCategory(err.msg))
+                    return RusotoError::service(
+                        LookupEventsError::InvalidEventCategory(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidLookupAttributesException" => {
-                    return RusotoError::Service(LookupEventsError::InvalidLookupAttributes(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        LookupEventsError::InvalidLookupAttributes(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidMaxResultsException" => {
-                    return RusotoError::Service(LookupEventsError::InvalidMaxResults(err.msg))
+                    return RusotoError::service(
+                        LookupEventsError::InvalidMaxResults(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(LookupEventsError::InvalidNextToken(err.msg))
+                    return RusotoError::service(LookupEventsError::InvalidNextToken(err.msg), &res)
                 }
                 "InvalidTimeRangeException" => {
-                    return RusotoError::Service(LookupEventsError::InvalidTimeRange(err.msg))
+                    return RusotoError::service(LookupEventsError::InvalidTimeRange(err.msg), &res)
                 }
                 "OperationNotPermittedException" => {
-                    return RusotoError::Service(LookupEventsError::OperationNotPermitted(err.msg))
+                    return RusotoError::service(
+                        LookupEventsError::OperationNotPermitted(err.msg),
+                        &res,
+                    )
                 }
                 "UnsupportedOperationException" => {
-                    return RusotoError::Service(LookupEventsError::UnsupportedOperation(err.msg))
+                    return RusotoError::service(
+                        LookupEventsError::UnsupportedOperation(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(LookupEventsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1732,12 +1893,14 @@ impl fmt::Display for LookupEventsError {
             LookupEventsError::InvalidTimeRange(ref cause) => write!(f, "{}", cause),
             LookupEventsError::OperationNotPermitted(ref cause) => write!(f, "{}", cause),
             LookupEventsError::UnsupportedOperation(ref cause) => write!(f, "{}", cause),
+            LookupEventsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for LookupEventsError {}
 /// Errors returned by PutEventSelectors
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum PutEventSelectorsError {
     /// <p>This exception is thrown when the IAM user or role that is used to create the organization trail is lacking one or more required permissions for creating an organization trail in a required service. For more information, see <a href="https://docs.aws.amazon.com/awscloudtrail/latest/userguide/creating-an-organizational-trail-prepare.html">Prepare For Creating a Trail For Your Organization</a>.</p>
     InsufficientDependencyServiceAccessPermission(String),
@@ -1755,6 +1918,8 @@ pub enum PutEventSelectorsError {
     TrailNotFound(String),
     /// <p>This exception is thrown when the requested operation is not supported.</p>
     UnsupportedOperation(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl PutEventSelectorsError {
@@ -1762,43 +1927,57 @@ impl PutEventSelectorsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InsufficientDependencyServiceAccessPermissionException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         PutEventSelectorsError::InsufficientDependencyServiceAccessPermission(
                             err.msg,
                         ),
+                        &res,
                     )
                 }
                 "InvalidEventSelectorsException" => {
-                    return RusotoError::Service(PutEventSelectorsError::InvalidEventSelectors(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        PutEventSelectorsError::InvalidEventSelectors(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidHomeRegionException" => {
-                    return RusotoError::Service(PutEventSelectorsError::InvalidHomeRegion(err.msg))
+                    return RusotoError::service(
+                        PutEventSelectorsError::InvalidHomeRegion(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidTrailNameException" => {
-                    return RusotoError::Service(PutEventSelectorsError::InvalidTrailName(err.msg))
+                    return RusotoError::service(
+                        PutEventSelectorsError::InvalidTrailName(err.msg),
+                        &res,
+                    )
                 }
                 "NotOrganizationMasterAccountException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         PutEventSelectorsError::NotOrganizationMasterAccount(err.msg),
+                        &res,
                     )
                 }
                 "OperationNotPermittedException" => {
-                    return RusotoError::Service(PutEventSelectorsError::OperationNotPermitted(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        PutEventSelectorsError::OperationNotPermitted(err.msg),
+                        &res,
+                    )
                 }
                 "TrailNotFoundException" => {
-                    return RusotoError::Service(PutEventSelectorsError::TrailNotFound(err.msg))
+                    return RusotoError::service(
+                        PutEventSelectorsError::TrailNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "UnsupportedOperationException" => {
-                    return RusotoError::Service(PutEventSelectorsError::UnsupportedOperation(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        PutEventSelectorsError::UnsupportedOperation(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(PutEventSelectorsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1820,12 +1999,14 @@ impl fmt::Display for PutEventSelectorsError {
             PutEventSelectorsError::OperationNotPermitted(ref cause) => write!(f, "{}", cause),
             PutEventSelectorsError::TrailNotFound(ref cause) => write!(f, "{}", cause),
             PutEventSelectorsError::UnsupportedOperation(ref cause) => write!(f, "{}", cause),
+            PutEventSelectorsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for PutEventSelectorsError {}
 /// Errors returned by PutInsightSelectors
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum PutInsightSelectorsError {
     /// <p>This exception is thrown when the policy on the S3 bucket or KMS key is not sufficient.</p>
     InsufficientEncryptionPolicy(String),
@@ -1845,6 +2026,8 @@ pub enum PutInsightSelectorsError {
     TrailNotFound(String),
     /// <p>This exception is thrown when the requested operation is not supported.</p>
     UnsupportedOperation(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl PutInsightSelectorsError {
@@ -1852,50 +2035,61 @@ impl PutInsightSelectorsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InsufficientEncryptionPolicyException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         PutInsightSelectorsError::InsufficientEncryptionPolicy(err.msg),
+                        &res,
                     )
                 }
                 "InsufficientS3BucketPolicyException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         PutInsightSelectorsError::InsufficientS3BucketPolicy(err.msg),
+                        &res,
                     )
                 }
                 "InvalidHomeRegionException" => {
-                    return RusotoError::Service(PutInsightSelectorsError::InvalidHomeRegion(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        PutInsightSelectorsError::InvalidHomeRegion(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidInsightSelectorsException" => {
-                    return RusotoError::Service(PutInsightSelectorsError::InvalidInsightSelectors(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        PutInsightSelectorsError::InvalidInsightSelectors(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidTrailNameException" => {
-                    return RusotoError::Service(PutInsightSelectorsError::InvalidTrailName(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        PutInsightSelectorsError::InvalidTrailName(err.msg),
+                        &res,
+                    )
                 }
                 "NotOrganizationMasterAccountException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         PutInsightSelectorsError::NotOrganizationMasterAccount(err.msg),
+                        &res,
                     )
                 }
                 "OperationNotPermittedException" => {
-                    return RusotoError::Service(PutInsightSelectorsError::OperationNotPermitted(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        PutInsightSelectorsError::OperationNotPermitted(err.msg),
+                        &res,
+                    )
                 }
                 "TrailNotFoundException" => {
-                    return RusotoError::Service(PutInsightSelectorsError::TrailNotFound(err.msg))
+                    return RusotoError::service(
+                        PutInsightSelectorsError::TrailNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "UnsupportedOperationException" => {
-                    return RusotoError::Service(PutInsightSelectorsError::UnsupportedOperation(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        PutInsightSelectorsError::UnsupportedOperation(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(PutInsightSelectorsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1920,12 +2114,14 @@ impl fmt::Display for PutInsightSelectorsError {
             PutInsightSelectorsError::OperationNotPermitted(ref cause) => write!(f, "{}", cause),
             PutInsightSelectorsError::TrailNotFound(ref cause) => write!(f, "{}", cause),
             PutInsightSelectorsError::UnsupportedOperation(ref cause) => write!(f, "{}", cause),
+            PutInsightSelectorsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for PutInsightSelectorsError {}
 /// Errors returned by RemoveTags
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum RemoveTagsError {
     /// <p>This exception is thrown when an operation is called with an invalid trail ARN. The format of a trail ARN is:</p> <p> <code>arn:aws:cloudtrail:us-east-2:123456789012:trail/MyTrail</code> </p>
     CloudTrailARNInvalid(String),
@@ -1943,6 +2139,8 @@ pub enum RemoveTagsError {
     ResourceTypeNotSupported(String),
     /// <p>This exception is thrown when the requested operation is not supported.</p>
     UnsupportedOperation(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl RemoveTagsError {
@@ -1950,33 +2148,49 @@ impl RemoveTagsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "CloudTrailARNInvalidException" => {
-                    return RusotoError::Service(RemoveTagsError::CloudTrailARNInvalid(err.msg))
+                    return RusotoError::service(
+                        RemoveTagsError::CloudTrailARNInvalid(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidTagParameterException" => {
-                    return RusotoError::Service(RemoveTagsError::InvalidTagParameter(err.msg))
+                    return RusotoError::service(
+                        RemoveTagsError::InvalidTagParameter(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidTrailNameException" => {
-                    return RusotoError::Service(RemoveTagsError::InvalidTrailName(err.msg))
+                    return RusotoError::service(RemoveTagsError::InvalidTrailName(err.msg), &res)
                 }
                 "NotOrganizationMasterAccountException" => {
-                    return RusotoError::Service(RemoveTagsError::NotOrganizationMasterAccount(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        RemoveTagsError::NotOrganizationMasterAccount(err.msg),
+                        &res,
+                    )
                 }
                 "OperationNotPermittedException" => {
-                    return RusotoError::Service(RemoveTagsError::OperationNotPermitted(err.msg))
+                    return RusotoError::service(
+                        RemoveTagsError::OperationNotPermitted(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(RemoveTagsError::ResourceNotFound(err.msg))
+                    return RusotoError::service(RemoveTagsError::ResourceNotFound(err.msg), &res)
                 }
                 "ResourceTypeNotSupportedException" => {
-                    return RusotoError::Service(RemoveTagsError::ResourceTypeNotSupported(err.msg))
+                    return RusotoError::service(
+                        RemoveTagsError::ResourceTypeNotSupported(err.msg),
+                        &res,
+                    )
                 }
                 "UnsupportedOperationException" => {
-                    return RusotoError::Service(RemoveTagsError::UnsupportedOperation(err.msg))
+                    return RusotoError::service(
+                        RemoveTagsError::UnsupportedOperation(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(RemoveTagsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1994,12 +2208,14 @@ impl fmt::Display for RemoveTagsError {
             RemoveTagsError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             RemoveTagsError::ResourceTypeNotSupported(ref cause) => write!(f, "{}", cause),
             RemoveTagsError::UnsupportedOperation(ref cause) => write!(f, "{}", cause),
+            RemoveTagsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for RemoveTagsError {}
 /// Errors returned by StartLogging
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum StartLoggingError {
     /// <p>This exception is thrown when the IAM user or role that is used to create the organization trail is lacking one or more required permissions for creating an organization trail in a required service. For more information, see <a href="https://docs.aws.amazon.com/awscloudtrail/latest/userguide/creating-an-organizational-trail-prepare.html">Prepare For Creating a Trail For Your Organization</a>.</p>
     InsufficientDependencyServiceAccessPermission(String),
@@ -2015,6 +2231,8 @@ pub enum StartLoggingError {
     TrailNotFound(String),
     /// <p>This exception is thrown when the requested operation is not supported.</p>
     UnsupportedOperation(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl StartLoggingError {
@@ -2022,32 +2240,43 @@ impl StartLoggingError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InsufficientDependencyServiceAccessPermissionException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         StartLoggingError::InsufficientDependencyServiceAccessPermission(err.msg),
+                        &res,
                     )
                 }
                 "InvalidHomeRegionException" => {
-                    return RusotoError::Service(StartLoggingError::InvalidHomeRegion(err.msg))
+                    return RusotoError::service(
+                        StartLoggingError::InvalidHomeRegion(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidTrailNameException" => {
-                    return RusotoError::Service(StartLoggingError::InvalidTrailName(err.msg))
+                    return RusotoError::service(StartLoggingError::InvalidTrailName(err.msg), &res)
                 }
                 "NotOrganizationMasterAccountException" => {
-                    return RusotoError::Service(StartLoggingError::NotOrganizationMasterAccount(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        StartLoggingError::NotOrganizationMasterAccount(err.msg),
+                        &res,
+                    )
                 }
                 "OperationNotPermittedException" => {
-                    return RusotoError::Service(StartLoggingError::OperationNotPermitted(err.msg))
+                    return RusotoError::service(
+                        StartLoggingError::OperationNotPermitted(err.msg),
+                        &res,
+                    )
                 }
                 "TrailNotFoundException" => {
-                    return RusotoError::Service(StartLoggingError::TrailNotFound(err.msg))
+                    return RusotoError::service(StartLoggingError::TrailNotFound(err.msg), &res)
                 }
                 "UnsupportedOperationException" => {
-                    return RusotoError::Service(StartLoggingError::UnsupportedOperation(err.msg))
+                    return RusotoError::service(
+                        StartLoggingError::UnsupportedOperation(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(StartLoggingError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2066,12 +2295,14 @@ impl fmt::Display for StartLoggingError {
             StartLoggingError::OperationNotPermitted(ref cause) => write!(f, "{}", cause),
             StartLoggingError::TrailNotFound(ref cause) => write!(f, "{}", cause),
             StartLoggingError::UnsupportedOperation(ref cause) => write!(f, "{}", cause),
+            StartLoggingError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for StartLoggingError {}
 /// Errors returned by StopLogging
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum StopLoggingError {
     /// <p>This exception is thrown when the IAM user or role that is used to create the organization trail is lacking one or more required permissions for creating an organization trail in a required service. For more information, see <a href="https://docs.aws.amazon.com/awscloudtrail/latest/userguide/creating-an-organizational-trail-prepare.html">Prepare For Creating a Trail For Your Organization</a>.</p>
     InsufficientDependencyServiceAccessPermission(String),
@@ -2087,6 +2318,8 @@ pub enum StopLoggingError {
     TrailNotFound(String),
     /// <p>This exception is thrown when the requested operation is not supported.</p>
     UnsupportedOperation(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl StopLoggingError {
@@ -2094,32 +2327,40 @@ impl StopLoggingError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InsufficientDependencyServiceAccessPermissionException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         StopLoggingError::InsufficientDependencyServiceAccessPermission(err.msg),
+                        &res,
                     )
                 }
                 "InvalidHomeRegionException" => {
-                    return RusotoError::Service(StopLoggingError::InvalidHomeRegion(err.msg))
+                    return RusotoError::service(StopLoggingError::InvalidHomeRegion(err.msg), &res)
                 }
                 "InvalidTrailNameException" => {
-                    return RusotoError::Service(StopLoggingError::InvalidTrailName(err.msg))
+                    return RusotoError::service(StopLoggingError::InvalidTrailName(err.msg), &res)
                 }
                 "NotOrganizationMasterAccountException" => {
-                    return RusotoError::Service(StopLoggingError::NotOrganizationMasterAccount(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        StopLoggingError::NotOrganizationMasterAccount(err.msg),
+                        &res,
+                    )
                 }
                 "OperationNotPermittedException" => {
-                    return RusotoError::Service(StopLoggingError::OperationNotPermitted(err.msg))
+                    return RusotoError::service(
+                        StopLoggingError::OperationNotPermitted(err.msg),
+                        &res,
+                    )
                 }
                 "TrailNotFoundException" => {
-                    return RusotoError::Service(StopLoggingError::TrailNotFound(err.msg))
+                    return RusotoError::service(StopLoggingError::TrailNotFound(err.msg), &res)
                 }
                 "UnsupportedOperationException" => {
-                    return RusotoError::Service(StopLoggingError::UnsupportedOperation(err.msg))
+                    return RusotoError::service(
+                        StopLoggingError::UnsupportedOperation(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(StopLoggingError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2138,12 +2379,14 @@ impl fmt::Display for StopLoggingError {
             StopLoggingError::OperationNotPermitted(ref cause) => write!(f, "{}", cause),
             StopLoggingError::TrailNotFound(ref cause) => write!(f, "{}", cause),
             StopLoggingError::UnsupportedOperation(ref cause) => write!(f, "{}", cause),
+            StopLoggingError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for StopLoggingError {}
 /// Errors returned by UpdateTrail
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdateTrailError {
     /// <p>This exception is thrown when trusted access has not been enabled between AWS CloudTrail and AWS Organizations. For more information, see <a href="https://docs.aws.amazon.com/organizations/latest/userguide/orgs_integrate_services.html">Enabling Trusted Access with Other AWS Services</a> and <a href="https://docs.aws.amazon.com/awscloudtrail/latest/userguide/creating-an-organizational-trail-prepare.html">Prepare For Creating a Trail For Your Organization</a>. </p>
     CloudTrailAccessNotEnabled(String),
@@ -2199,6 +2442,8 @@ pub enum UpdateTrailError {
     TrailNotProvided(String),
     /// <p>This exception is thrown when the requested operation is not supported.</p>
     UnsupportedOperation(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdateTrailError {
@@ -2206,108 +2451,142 @@ impl UpdateTrailError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "CloudTrailAccessNotEnabledException" => {
-                    return RusotoError::Service(UpdateTrailError::CloudTrailAccessNotEnabled(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateTrailError::CloudTrailAccessNotEnabled(err.msg),
+                        &res,
+                    )
                 }
                 "CloudWatchLogsDeliveryUnavailableException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         UpdateTrailError::CloudWatchLogsDeliveryUnavailable(err.msg),
+                        &res,
                     )
                 }
                 "InsufficientDependencyServiceAccessPermissionException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         UpdateTrailError::InsufficientDependencyServiceAccessPermission(err.msg),
+                        &res,
                     )
                 }
                 "InsufficientEncryptionPolicyException" => {
-                    return RusotoError::Service(UpdateTrailError::InsufficientEncryptionPolicy(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateTrailError::InsufficientEncryptionPolicy(err.msg),
+                        &res,
+                    )
                 }
                 "InsufficientS3BucketPolicyException" => {
-                    return RusotoError::Service(UpdateTrailError::InsufficientS3BucketPolicy(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateTrailError::InsufficientS3BucketPolicy(err.msg),
+                        &res,
+                    )
                 }
                 "InsufficientSnsTopicPolicyException" => {
-                    return RusotoError::Service(UpdateTrailError::InsufficientSnsTopicPolicy(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateTrailError::InsufficientSnsTopicPolicy(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidCloudWatchLogsLogGroupArnException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         UpdateTrailError::InvalidCloudWatchLogsLogGroupArn(err.msg),
+                        &res,
                     )
                 }
                 "InvalidCloudWatchLogsRoleArnException" => {
-                    return RusotoError::Service(UpdateTrailError::InvalidCloudWatchLogsRoleArn(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateTrailError::InvalidCloudWatchLogsRoleArn(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidEventSelectorsException" => {
-                    return RusotoError::Service(UpdateTrailError::InvalidEventSelectors(err.msg))
+                    return RusotoError::service(
+                        UpdateTrailError::InvalidEventSelectors(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidHomeRegionException" => {
-                    return RusotoError::Service(UpdateTrailError::InvalidHomeRegion(err.msg))
+                    return RusotoError::service(UpdateTrailError::InvalidHomeRegion(err.msg), &res)
                 }
                 "InvalidKmsKeyIdException" => {
-                    return RusotoError::Service(UpdateTrailError::InvalidKmsKeyId(err.msg))
+                    return RusotoError::service(UpdateTrailError::InvalidKmsKeyId(err.msg), &res)
                 }
                 "InvalidParameterCombinationException" => {
-                    return RusotoError::Service(UpdateTrailError::InvalidParameterCombination(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateTrailError::InvalidParameterCombination(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidS3BucketNameException" => {
-                    return RusotoError::Service(UpdateTrailError::InvalidS3BucketName(err.msg))
+                    return RusotoError::service(
+                        UpdateTrailError::InvalidS3BucketName(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidS3PrefixException" => {
-                    return RusotoError::Service(UpdateTrailError::InvalidS3Prefix(err.msg))
+                    return RusotoError::service(UpdateTrailError::InvalidS3Prefix(err.msg), &res)
                 }
                 "InvalidSnsTopicNameException" => {
-                    return RusotoError::Service(UpdateTrailError::InvalidSnsTopicName(err.msg))
+                    return RusotoError::service(
+                        UpdateTrailError::InvalidSnsTopicName(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidTrailNameException" => {
-                    return RusotoError::Service(UpdateTrailError::InvalidTrailName(err.msg))
+                    return RusotoError::service(UpdateTrailError::InvalidTrailName(err.msg), &res)
+                }
+                "KmsException" => {
+                    return RusotoError::service(UpdateTrailError::Kms(err.msg), &res)
                 }
-                "KmsException" => return RusotoError::Service(UpdateTrailError::Kms(err.msg)),
                 "KmsKeyDisabledException" => {
-                    return RusotoError::Service(UpdateTrailError::KmsKeyDisabled(err.msg))
+                    return RusotoError::service(UpdateTrailError::KmsKeyDisabled(err.msg), &res)
                 }
                 "KmsKeyNotFoundException" => {
-                    return RusotoError::Service(UpdateTrailError::KmsKeyNotFound(err.msg))
+                    return RusotoError::service(UpdateTrailError::KmsKeyNotFound(err.msg), &res)
                 }
                 "NotOrganizationMasterAccountException" => {
-                    return RusotoError::Service(UpdateTrailError::NotOrganizationMasterAccount(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateTrailError::NotOrganizationMasterAccount(err.msg),
+                        &res,
+                    )
                 }
                 "OperationNotPermittedException" => {
-                    return RusotoError::Service(UpdateTrailError::OperationNotPermitted(err.msg))
+                    return RusotoError::service(
+                        UpdateTrailError::OperationNotPermitted(err.msg),
+                        &res,
+                    )
                 }
                 "OrganizationNotInAllFeaturesModeException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         UpdateTrailError::OrganizationNotInAllFeaturesMode(err.msg),
+                        &res,
                     )
                 }
                 "OrganizationsNotInUseException" => {
-                    return RusotoError::Service(UpdateTrailError::OrganizationsNotInUse(err.msg))
+                    return RusotoError::service(
+                        UpdateTrailError::OrganizationsNotInUse(err.msg),
+                        &res,
+                    )
                 }
                 "S3BucketDoesNotExistException" => {
-                    return RusotoError::Service(UpdateTrailError::S3BucketDoesNotExist(err.msg))
+                    return RusotoError::service(
+                        UpdateTrailError::S3BucketDoesNotExist(err.msg),
+                        &res,
+                    )
                 }
                 "TrailNotFoundException" => {
-                    return RusotoError::Service(UpdateTrailError::TrailNotFound(err.msg))
+                    return RusotoError::service(UpdateTrailError::TrailNotFound(err.msg), &res)
                 }
                 "TrailNotProvidedException" => {
-                    return RusotoError::Service(UpdateTrailError::TrailNotProvided(err.msg))
+                    return RusotoError::service(UpdateTrailError::TrailNotProvided(err.msg), &res)
                 }
                 "UnsupportedOperationException" => {
-                    return RusotoError::Service(UpdateTrailError::UnsupportedOperation(err.msg))
+                    return RusotoError::service(
+                        UpdateTrailError::UnsupportedOperation(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UpdateTrailError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2348,6 +2627,7 @@ impl fmt::Display for UpdateTrailError {
             UpdateTrailError::TrailNotFound(ref cause) => write!(f, "{}", cause),
             UpdateTrailError::TrailNotProvided(ref cause) => write!(f, "{}", cause),
             UpdateTrailError::UnsupportedOperation(ref cause) => write!(f, "{}", cause),
+            UpdateTrailError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
@@ -2474,6 +2754,7 @@ impl CloudTrailClient {
     /// Creates a client backed by the default tokio event loop.
     ///
     /// The client will use the default credentials provider and tls client.
+    #[cfg(not(target_arch = "wasm32"))]
     pub fn new(region: region::Region) -> CloudTrailClient {
         CloudTrailClient {
             client: Client::shared(),
@@ -2499,16 +2780,67 @@ impl CloudTrailClient {
     pub fn new_with_client(client: Client, region: region::Region) -> CloudTrailClient {
         CloudTrailClient { client, region }
     }
+
+    /// Returns a builder of a client, setting any of its region, credentials provider,
+    /// dispatcher, retry policy, endpoint and timeouts.
+    pub fn builder() -> rusoto_core::ClientBuilder<CloudTrailClient> {
+        rusoto_core::ClientBuilder::new(CloudTrailClient::new_with_client)
+    }
+
+    /// Returns a clone of this client with the overrides of `config` applied to its
+    /// operations, sharing its connections.
+    pub fn customize(&self, config: &rusoto_core::OperationConfig) -> CloudTrailClient {
+        CloudTrailClient {
+            client: self.client.customize(config),
+            region: config.resolve_region(&self.region),
+        }
+    }
 }
 
-#[async_trait]
-impl CloudTrail for CloudTrailClient {
-    /// <p>Adds one or more tags to a trail, up to a limit of 50. Overwrites an existing tag's value when a new value is specified for an existing tag key. Tag key names must be unique for a trail; you cannot have two keys with the same name but different values. If you specify a key without a value, the tag will be created with the specified key and a value of null. You can tag a trail that applies to all AWS Regions only from the Region in which the trail was created (also known as its home region).</p>
-    async fn add_tags(
+impl CloudTrailClient {
+    /// Calls `add_tags`, also returning the metadata of the response.
+    pub async fn add_tags_with_metadata(
         &self,
         input: AddTagsRequest,
-    ) -> Result<AddTagsResponse, RusotoError<AddTagsError>> {
+    ) -> Result<(AddTagsResponse, ResponseMetadata), RusotoError<AddTagsError>> {
+        let request = self.build_add_tags_request(input);
+
+        let mut response = self
+            .client
+            .sign_and_dispatch(request)
+            .await
+            .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
+        if response.status.is_success() {
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result =
+                proto::json::ResponsePayload::new(&response).deserialize::<AddTagsResponse, _>()?;
+            Ok((result, metadata))
+        } else {
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(AddTagsError::from_response(response))
+        }
+    }
+
+    /// Presigns the request `add_tags` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_add_tags(
+        &self,
+        presigner: &rusoto_core::Presigner,
+        input: AddTagsRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_add_tags_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_add_tags_request(&self, input: AddTagsRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "cloudtrail", &self.region, "/");
+        request.set_operation("AddTags");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -2518,27 +2850,52 @@ impl CloudTrail for CloudTrailClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `create_trail`, also returning the metadata of the response.
+    pub async fn create_trail_with_metadata(
+        &self,
+        input: CreateTrailRequest,
+    ) -> Result<(CreateTrailResponse, ResponseMetadata), RusotoError<CreateTrailError>> {
+        let request = self.build_create_trail_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<AddTagsResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<CreateTrailResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(AddTagsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(CreateTrailError::from_response(response))
         }
     }
 
-    /// <p>Creates a trail that specifies the settings for delivery of log data to an Amazon S3 bucket. </p>
-    async fn create_trail(
+    /// Presigns the request `create_trail` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_trail(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: CreateTrailRequest,
-    ) -> Result<CreateTrailResponse, RusotoError<CreateTrailError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_trail_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_trail_request(&self, input: CreateTrailRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "cloudtrail", &self.region, "/");
+        request.set_operation("CreateTrail");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -2548,27 +2905,52 @@ impl CloudTrail for CloudTrailClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `delete_trail`, also returning the metadata of the response.
+    pub async fn delete_trail_with_metadata(
+        &self,
+        input: DeleteTrailRequest,
+    ) -> Result<(DeleteTrailResponse, ResponseMetadata), RusotoError<DeleteTrailError>> {
+        let request = self.build_delete_trail_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<CreateTrailResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DeleteTrailResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(CreateTrailError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DeleteTrailError::from_response(response))
         }
     }
 
-    /// <p>Deletes a trail. This operation must be called from the region in which the trail was created. <code>DeleteTrail</code> cannot be called on the shadow trails (replicated trails in other regions) of a trail that is enabled in all regions.</p>
-    async fn delete_trail(
+    /// Presigns the request `delete_trail` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_trail(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteTrailRequest,
-    ) -> Result<DeleteTrailResponse, RusotoError<DeleteTrailError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_trail_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_trail_request(&self, input: DeleteTrailRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "cloudtrail", &self.region, "/");
+        request.set_operation("DeleteTrail");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -2578,27 +2960,52 @@ impl CloudTrail for CloudTrailClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `describe_trails`, also returning the metadata of the response.
+    pub async fn describe_trails_with_metadata(
+        &self,
+        input: DescribeTrailsRequest,
+    ) -> Result<(DescribeTrailsResponse, ResponseMetadata), RusotoError<DescribeTrailsError>> {
+        let request = self.build_describe_trails_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<DeleteTrailResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DescribeTrailsResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteTrailError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DescribeTrailsError::from_response(response))
         }
     }
 
-    /// <p>Retrieves settings for one or more trails associated with the current region for your account.</p>
-    async fn describe_trails(
+    /// Presigns the request `describe_trails` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_trails(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeTrailsRequest,
-    ) -> Result<DescribeTrailsResponse, RusotoError<DescribeTrailsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_trails_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_trails_request(&self, input: DescribeTrailsRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "cloudtrail", &self.region, "/");
+        request.set_operation("DescribeTrails");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -2608,27 +3015,53 @@ impl CloudTrail for CloudTrailClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `get_event_selectors`, also returning the metadata of the response.
+    pub async fn get_event_selectors_with_metadata(
+        &self,
+        input: GetEventSelectorsRequest,
+    ) -> Result<(GetEventSelectorsResponse, ResponseMetadata), RusotoError<GetEventSelectorsError>>
+    {
+        let request = self.build_get_event_selectors_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<DescribeTrailsResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<GetEventSelectorsResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeTrailsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(GetEventSelectorsError::from_response(response))
         }
     }
 
-    /// <p>Describes the settings for the event selectors that you configured for your trail. The information returned for your event selectors includes the following:</p> <ul> <li> <p>If your event selector includes read-only events, write-only events, or all events. This applies to both management events and data events.</p> </li> <li> <p>If your event selector includes management events.</p> </li> <li> <p>If your event selector includes data events, the Amazon S3 objects or AWS Lambda functions that you are logging for data events.</p> </li> </ul> <p>For more information, see <a href="https://docs.aws.amazon.com/awscloudtrail/latest/userguide/logging-management-and-data-events-with-cloudtrail.html">Logging Data and Management Events for Trails </a> in the <i>AWS CloudTrail User Guide</i>.</p>
-    async fn get_event_selectors(
+    /// Presigns the request `get_event_selectors` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_event_selectors(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetEventSelectorsRequest,
-    ) -> Result<GetEventSelectorsResponse, RusotoError<GetEventSelectorsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_event_selectors_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_event_selectors_request(&self, input: GetEventSelectorsRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "cloudtrail", &self.region, "/");
+        request.set_operation("GetEventSelectors");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -2638,28 +3071,58 @@ impl CloudTrail for CloudTrailClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `get_insight_selectors`, also returning the metadata of the response.
+    pub async fn get_insight_selectors_with_metadata(
+        &self,
+        input: GetInsightSelectorsRequest,
+    ) -> Result<
+        (GetInsightSelectorsResponse, ResponseMetadata),
+        RusotoError<GetInsightSelectorsError>,
+    > {
+        let request = self.build_get_insight_selectors_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<GetEventSelectorsResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<GetInsightSelectorsResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(GetEventSelectorsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(GetInsightSelectorsError::from_response(response))
         }
     }
 
-    /// <p>Describes the settings for the Insights event selectors that you configured for your trail. <code>GetInsightSelectors</code> shows if CloudTrail Insights event logging is enabled on the trail, and if it is, which insight types are enabled. If you run <code>GetInsightSelectors</code> on a trail that does not have Insights events enabled, the operation throws the exception <code>InsightNotEnabledException</code> </p> <p>For more information, see <a href="https://docs.aws.amazon.com/awscloudtrail/latest/userguide/logging-insights-events-with-cloudtrail.html">Logging CloudTrail Insights Events for Trails </a> in the <i>AWS CloudTrail User Guide</i>.</p>
-    async fn get_insight_selectors(
+    /// Presigns the request `get_insight_selectors` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_insight_selectors(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetInsightSelectorsRequest,
-    ) -> Result<GetInsightSelectorsResponse, RusotoError<GetInsightSelectorsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_insight_selectors_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_insight_selectors_request(
+        &self,
+        input: GetInsightSelectorsRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "cloudtrail", &self.region, "/");
+        request.set_operation("GetInsightSelectors");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -2669,28 +3132,52 @@ impl CloudTrail for CloudTrailClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `get_trail`, also returning the metadata of the response.
+    pub async fn get_trail_with_metadata(
+        &self,
+        input: GetTrailRequest,
+    ) -> Result<(GetTrailResponse, ResponseMetadata), RusotoError<GetTrailError>> {
+        let request = self.build_get_trail_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<GetInsightSelectorsResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<GetTrailResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(GetInsightSelectorsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(GetTrailError::from_response(response))
         }
     }
 
-    /// <p>Returns settings information for a specified trail.</p>
-    async fn get_trail(
+    /// Presigns the request `get_trail` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_trail(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetTrailRequest,
-    ) -> Result<GetTrailResponse, RusotoError<GetTrailError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_trail_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_trail_request(&self, input: GetTrailRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "cloudtrail", &self.region, "/");
+        request.set_operation("GetTrail");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -2700,27 +3187,52 @@ impl CloudTrail for CloudTrailClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `get_trail_status`, also returning the metadata of the response.
+    pub async fn get_trail_status_with_metadata(
+        &self,
+        input: GetTrailStatusRequest,
+    ) -> Result<(GetTrailStatusResponse, ResponseMetadata), RusotoError<GetTrailStatusError>> {
+        let request = self.build_get_trail_status_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<GetTrailResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<GetTrailStatusResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(GetTrailError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(GetTrailStatusError::from_response(response))
         }
     }
 
-    /// <p>Returns a JSON-formatted list of information about the specified trail. Fields include information on delivery errors, Amazon SNS and Amazon S3 errors, and start and stop logging times for each trail. This operation returns trail status from a single region. To return trail status from all regions, you must call the operation on each region.</p>
-    async fn get_trail_status(
+    /// Presigns the request `get_trail_status` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_trail_status(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetTrailStatusRequest,
-    ) -> Result<GetTrailStatusResponse, RusotoError<GetTrailStatusError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_trail_status_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_trail_status_request(&self, input: GetTrailStatusRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "cloudtrail", &self.region, "/");
+        request.set_operation("GetTrailStatus");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -2730,27 +3242,52 @@ impl CloudTrail for CloudTrailClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `list_public_keys`, also returning the metadata of the response.
+    pub async fn list_public_keys_with_metadata(
+        &self,
+        input: ListPublicKeysRequest,
+    ) -> Result<(ListPublicKeysResponse, ResponseMetadata), RusotoError<ListPublicKeysError>> {
+        let request = self.build_list_public_keys_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<GetTrailStatusResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ListPublicKeysResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(GetTrailStatusError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ListPublicKeysError::from_response(response))
         }
     }
 
-    /// <p><p>Returns all public keys whose private keys were used to sign the digest files within the specified time range. The public key is needed to validate digest files that were signed with its corresponding private key.</p> <note> <p>CloudTrail uses different private/public key pairs per region. Each digest file is signed with a private key unique to its region. Therefore, when you validate a digest file from a particular region, you must look in the same region for its corresponding public key.</p> </note></p>
-    async fn list_public_keys(
+    /// Presigns the request `list_public_keys` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_public_keys(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListPublicKeysRequest,
-    ) -> Result<ListPublicKeysResponse, RusotoError<ListPublicKeysError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_public_keys_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_public_keys_request(&self, input: ListPublicKeysRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "cloudtrail", &self.region, "/");
+        request.set_operation("ListPublicKeys");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -2760,27 +3297,52 @@ impl CloudTrail for CloudTrailClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `list_tags`, also returning the metadata of the response.
+    pub async fn list_tags_with_metadata(
+        &self,
+        input: ListTagsRequest,
+    ) -> Result<(ListTagsResponse, ResponseMetadata), RusotoError<ListTagsError>> {
+        let request = self.build_list_tags_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<ListPublicKeysResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ListTagsResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ListPublicKeysError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ListTagsError::from_response(response))
         }
     }
 
-    /// <p>Lists the tags for the trail in the current region.</p>
-    async fn list_tags(
+    /// Presigns the request `list_tags` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_tags(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListTagsRequest,
-    ) -> Result<ListTagsResponse, RusotoError<ListTagsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_tags_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_tags_request(&self, input: ListTagsRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "cloudtrail", &self.region, "/");
+        request.set_operation("ListTags");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -2790,27 +3352,52 @@ impl CloudTrail for CloudTrailClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `list_trails`, also returning the metadata of the response.
+    pub async fn list_trails_with_metadata(
+        &self,
+        input: ListTrailsRequest,
+    ) -> Result<(ListTrailsResponse, ResponseMetadata), RusotoError<ListTrailsError>> {
+        let request = self.build_list_trails_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<ListTagsResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ListTrailsResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ListTagsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ListTrailsError::from_response(response))
         }
     }
 
-    /// <p>Lists trails that are in the current account.</p>
-    async fn list_trails(
+    /// Presigns the request `list_trails` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_trails(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListTrailsRequest,
-    ) -> Result<ListTrailsResponse, RusotoError<ListTrailsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_trails_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_trails_request(&self, input: ListTrailsRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "cloudtrail", &self.region, "/");
+        request.set_operation("ListTrails");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -2820,27 +3407,52 @@ impl CloudTrail for CloudTrailClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `lookup_events`, also returning the metadata of the response.
+    pub async fn lookup_events_with_metadata(
+        &self,
+        input: LookupEventsRequest,
+    ) -> Result<(LookupEventsResponse, ResponseMetadata), RusotoError<LookupEventsError>> {
+        let request = self.build_lookup_events_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<ListTrailsResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<LookupEventsResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ListTrailsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(LookupEventsError::from_response(response))
         }
     }
 
-    /// <p><p>Looks up <a href="https://docs.aws.amazon.com/awscloudtrail/latest/userguide/cloudtrail-concepts.html#cloudtrail-concepts-management-events">management events</a> or <a href="https://docs.aws.amazon.com/awscloudtrail/latest/userguide/cloudtrail-concepts.html#cloudtrail-concepts-insights-events">CloudTrail Insights events</a> that are captured by CloudTrail. You can look up events that occurred in a region within the last 90 days. Lookup supports the following attributes for management events:</p> <ul> <li> <p>AWS access key</p> </li> <li> <p>Event ID</p> </li> <li> <p>Event name</p> </li> <li> <p>Event source</p> </li> <li> <p>Read only</p> </li> <li> <p>Resource name</p> </li> <li> <p>Resource type</p> </li> <li> <p>User name</p> </li> </ul> <p>Lookup supports the following attributes for Insights events:</p> <ul> <li> <p>Event ID</p> </li> <li> <p>Event name</p> </li> <li> <p>Event source</p> </li> </ul> <p>All attributes are optional. The default number of results returned is 50, with a maximum of 50 possible. The response includes a token that you can use to get the next page of results.</p> <important> <p>The rate of lookup requests is limited to two per second per account. If this limit is exceeded, a throttling error occurs.</p> </important></p>
-    async fn lookup_events(
+    /// Presigns the request `lookup_events` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_lookup_events(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: LookupEventsRequest,
-    ) -> Result<LookupEventsResponse, RusotoError<LookupEventsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_lookup_events_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_lookup_events_request(&self, input: LookupEventsRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "cloudtrail", &self.region, "/");
+        request.set_operation("LookupEvents");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -2850,27 +3462,53 @@ impl CloudTrail for CloudTrailClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
-        let mut response = self
-            .client
-            .sign_and_dispatch(request)
+        request
+    }
+
+    /// Calls `put_event_selectors`, also returning the metadata of the response.
+    pub async fn put_event_selectors_with_metadata(
+        &self,
+        input: PutEventSelectorsRequest,
+    ) -> Result<(PutEventSelectorsResponse, ResponseMetadata), RusotoError<PutEventSelectorsError>>
+    {
+        let request = self.build_put_event_selectors_request(input);
+
+        let mut response = self
+            .client
+            .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<LookupEventsResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<PutEventSelectorsResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(LookupEventsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(PutEventSelectorsError::from_response(response))
         }
     }
 
-    /// <p>Configures an event selector for your trail. Use event selectors to further specify the management and data event settings for your trail. By default, trails created without specific event selectors will be configured to log all read and write management events, and no data events. </p> <p>When an event occurs in your account, CloudTrail evaluates the event selectors in all trails. For each trail, if the event matches any event selector, the trail processes and logs the event. If the event doesn't match any event selector, the trail doesn't log the event. </p> <p>Example</p> <ol> <li> <p>You create an event selector for a trail and specify that you want write-only events.</p> </li> <li> <p>The EC2 <code>GetConsoleOutput</code> and <code>RunInstances</code> API operations occur in your account.</p> </li> <li> <p>CloudTrail evaluates whether the events match your event selectors.</p> </li> <li> <p>The <code>RunInstances</code> is a write-only event and it matches your event selector. The trail logs the event.</p> </li> <li> <p>The <code>GetConsoleOutput</code> is a read-only event but it doesn't match your event selector. The trail doesn't log the event. </p> </li> </ol> <p>The <code>PutEventSelectors</code> operation must be called from the region in which the trail was created; otherwise, an <code>InvalidHomeRegionException</code> is thrown.</p> <p>You can configure up to five event selectors for each trail. For more information, see <a href="https://docs.aws.amazon.com/awscloudtrail/latest/userguide/logging-management-and-data-events-with-cloudtrail.html">Logging Data and Management Events for Trails </a> and <a href="https://docs.aws.amazon.com/awscloudtrail/latest/userguide/WhatIsCloudTrail-Limits.html">Limits in AWS CloudTrail</a> in the <i>AWS CloudTrail User Guide</i>.</p>
-    async fn put_event_selectors(
+    /// Presigns the request `put_event_selectors` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_put_event_selectors(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: PutEventSelectorsRequest,
-    ) -> Result<PutEventSelectorsResponse, RusotoError<PutEventSelectorsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_put_event_selectors_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_put_event_selectors_request(&self, input: PutEventSelectorsRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "cloudtrail", &self.region, "/");
+        request.set_operation("PutEventSelectors");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -2880,28 +3518,58 @@ impl CloudTrail for CloudTrailClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `put_insight_selectors`, also returning the metadata of the response.
+    pub async fn put_insight_selectors_with_metadata(
+        &self,
+        input: PutInsightSelectorsRequest,
+    ) -> Result<
+        (PutInsightSelectorsResponse, ResponseMetadata),
+        RusotoError<PutInsightSelectorsError>,
+    > {
+        let request = self.build_put_insight_selectors_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<PutEventSelectorsResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<PutInsightSelectorsResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(PutEventSelectorsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(PutInsightSelectorsError::from_response(response))
         }
     }
 
-    /// <p>Lets you enable Insights event logging by specifying the Insights selectors that you want to enable on an existing trail. You also use <code>PutInsightSelectors</code> to turn off Insights event logging, by passing an empty list of insight types. In this release, only <code>ApiCallRateInsight</code> is supported as an Insights selector.</p>
-    async fn put_insight_selectors(
+    /// Presigns the request `put_insight_selectors` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_put_insight_selectors(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: PutInsightSelectorsRequest,
-    ) -> Result<PutInsightSelectorsResponse, RusotoError<PutInsightSelectorsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_put_insight_selectors_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_put_insight_selectors_request(
+        &self,
+        input: PutInsightSelectorsRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "cloudtrail", &self.region, "/");
+        request.set_operation("PutInsightSelectors");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -2911,28 +3579,52 @@ impl CloudTrail for CloudTrailClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `remove_tags`, also returning the metadata of the response.
+    pub async fn remove_tags_with_metadata(
+        &self,
+        input: RemoveTagsRequest,
+    ) -> Result<(RemoveTagsResponse, ResponseMetadata), RusotoError<RemoveTagsError>> {
+        let request = self.build_remove_tags_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<PutInsightSelectorsResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<RemoveTagsResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(PutInsightSelectorsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(RemoveTagsError::from_response(response))
         }
     }
 
-    /// <p>Removes the specified tags from a trail.</p>
-    async fn remove_tags(
+    /// Presigns the request `remove_tags` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_remove_tags(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: RemoveTagsRequest,
-    ) -> Result<RemoveTagsResponse, RusotoError<RemoveTagsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_remove_tags_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_remove_tags_request(&self, input: RemoveTagsRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "cloudtrail", &self.region, "/");
+        request.set_operation("RemoveTags");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -2942,27 +3634,52 @@ impl CloudTrail for CloudTrailClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `start_logging`, also returning the metadata of the response.
+    pub async fn start_logging_with_metadata(
+        &self,
+        input: StartLoggingRequest,
+    ) -> Result<(StartLoggingResponse, ResponseMetadata), RusotoError<StartLoggingError>> {
+        let request = self.build_start_logging_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<RemoveTagsResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<StartLoggingResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(RemoveTagsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(StartLoggingError::from_response(response))
         }
     }
 
-    /// <p>Starts the recording of AWS API calls and log file delivery for a trail. For a trail that is enabled in all regions, this operation must be called from the region in which the trail was created. This operation cannot be called on the shadow trails (replicated trails in other regions) of a trail that is enabled in all regions.</p>
-    async fn start_logging(
+    /// Presigns the request `start_logging` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_start_logging(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: StartLoggingRequest,
-    ) -> Result<StartLoggingResponse, RusotoError<StartLoggingError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_start_logging_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_start_logging_request(&self, input: StartLoggingRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "cloudtrail", &self.region, "/");
+        request.set_operation("StartLogging");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -2972,27 +3689,52 @@ impl CloudTrail for CloudTrailClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `stop_logging`, also returning the metadata of the response.
+    pub async fn stop_logging_with_metadata(
+        &self,
+        input: StopLoggingRequest,
+    ) -> Result<(StopLoggingResponse, ResponseMetadata), RusotoError<StopLoggingError>> {
+        let request = self.build_stop_logging_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<StartLoggingResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<StopLoggingResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(StartLoggingError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(StopLoggingError::from_response(response))
         }
     }
 
-    /// <p>Suspends the recording of AWS API calls and log file delivery for the specified trail. Under most circumstances, there is no need to use this action. You can update a trail without stopping it first. This action is the only way to stop recording. For a trail enabled in all regions, this operation must be called from the region in which the trail was created, or an <code>InvalidHomeRegionException</code> will occur. This operation cannot be called on the shadow trails (replicated trails in other regions) of a trail enabled in all regions.</p>
-    async fn stop_logging(
+    /// Presigns the request `stop_logging` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_stop_logging(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: StopLoggingRequest,
-    ) -> Result<StopLoggingResponse, RusotoError<StopLoggingError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_stop_logging_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_stop_logging_request(&self, input: StopLoggingRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "cloudtrail", &self.region, "/");
+        request.set_operation("StopLogging");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -3002,27 +3744,52 @@ impl CloudTrail for CloudTrailClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `update_trail`, also returning the metadata of the response.
+    pub async fn update_trail_with_metadata(
+        &self,
+        input: UpdateTrailRequest,
+    ) -> Result<(UpdateTrailResponse, ResponseMetadata), RusotoError<UpdateTrailError>> {
+        let request = self.build_update_trail_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<StopLoggingResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<UpdateTrailResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(StopLoggingError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(UpdateTrailError::from_response(response))
         }
     }
 
-    /// <p>Updates the settings that specify delivery of log files. Changes to a trail do not require stopping the CloudTrail service. Use this action to designate an existing bucket for log delivery. If the existing bucket has previously been a target for CloudTrail log files, an IAM policy exists for the bucket. <code>UpdateTrail</code> must be called from the region in which the trail was created; otherwise, an <code>InvalidHomeRegionException</code> is thrown.</p>
-    async fn update_trail(
+    /// Presigns the request `update_trail` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_trail(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UpdateTrailRequest,
-    ) -> Result<UpdateTrailResponse, RusotoError<UpdateTrailError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_trail_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_trail_request(&self, input: UpdateTrailRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "cloudtrail", &self.region, "/");
+        request.set_operation("UpdateTrail");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -3032,18 +3799,327 @@ impl CloudTrail for CloudTrailClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
-        let mut response = self
-            .client
-            .sign_and_dispatch(request)
+        request
+    }
+
+    /// The pages of `ListPublicKeys` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    pub fn list_public_keys_pages(
+        &self,
+        input: ListPublicKeysRequest,
+    ) -> impl futures::Stream<Item = Result<ListPublicKeysResponse, RusotoError<ListPublicKeysError>>>
+           + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: CloudTrailClient, input| async move { client.list_public_keys(input).await },
+            |input: &ListPublicKeysRequest, output: &ListPublicKeysResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListPublicKeysRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `PublicKeyList` of every page of `ListPublicKeys` results for `input`, as `list_public_keys_pages` requests them.
+    pub fn list_public_keys_stream(
+        &self,
+        input: ListPublicKeysRequest,
+    ) -> impl futures::Stream<Item = Result<PublicKey, RusotoError<ListPublicKeysError>>> + Send
+    {
+        rusoto_core::pagination::items(self.list_public_keys_pages(input), |page| {
+            page.public_key_list.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `ListTags` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    pub fn list_tags_pages(
+        &self,
+        input: ListTagsRequest,
+    ) -> impl futures::Stream<Item = Result<ListTagsResponse, RusotoError<ListTagsError>>> + Send
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: CloudTrailClient, input| async move { client.list_tags(input).await },
+            |input: &ListTagsRequest, output: &ListTagsResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListTagsRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `ResourceTagList` of every page of `ListTags` results for `input`, as `list_tags_pages` requests them.
+    pub fn list_tags_stream(
+        &self,
+        input: ListTagsRequest,
+    ) -> impl futures::Stream<Item = Result<ResourceTag, RusotoError<ListTagsError>>> + Send {
+        rusoto_core::pagination::items(self.list_tags_pages(input), |page| {
+            page.resource_tag_list.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `ListTrails` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    pub fn list_trails_pages(
+        &self,
+        input: ListTrailsRequest,
+    ) -> impl futures::Stream<Item = Result<ListTrailsResponse, RusotoError<ListTrailsError>>> + Send
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: CloudTrailClient, input| async move { client.list_trails(input).await },
+            |input: &ListTrailsRequest, output: &ListTrailsResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListTrailsRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `Trails` of every page of `ListTrails` results for `input`, as `list_trails_pages` requests them.
+    pub fn list_trails_stream(
+        &self,
+        input: ListTrailsRequest,
+    ) -> impl futures::Stream<Item = Result<TrailInfo, RusotoError<ListTrailsError>>> + Send {
+        rusoto_core::pagination::items(self.list_trails_pages(input), |page| {
+            page.trails.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `LookupEvents` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `MaxResults` of `input`.
+    pub fn lookup_events_pages(
+        &self,
+        input: LookupEventsRequest,
+    ) -> impl futures::Stream<Item = Result<LookupEventsResponse, RusotoError<LookupEventsError>>> + Send
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: CloudTrailClient, input| async move { client.lookup_events(input).await },
+            |input: &LookupEventsRequest, output: &LookupEventsResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(LookupEventsRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `Events` of every page of `LookupEvents` results for `input`, as `lookup_events_pages` requests them.
+    pub fn lookup_events_stream(
+        &self,
+        input: LookupEventsRequest,
+    ) -> impl futures::Stream<Item = Result<Event, RusotoError<LookupEventsError>>> + Send {
+        rusoto_core::pagination::items(self.lookup_events_pages(input), |page| {
+            page.events.unwrap_or_default()
+        })
+    }
+}
+
+#[async_trait]
+impl CloudTrail for CloudTrailClient {
+    /// <p>Adds one or more tags to a trail, up to a limit of 50. Overwrites an existing tag's value when a new value is specified for an existing tag key. Tag key names must be unique for a trail; you cannot have two keys with the same name but different values. If you specify a key without a value, the tag will be created with the specified key and a value of null. You can tag a trail that applies to all AWS Regions only from the Region in which the trail was created (also known as its home region).</p>
+    async fn add_tags(
+        &self,
+        input: AddTagsRequest,
+    ) -> Result<AddTagsResponse, RusotoError<AddTagsError>> {
+        self.add_tags_with_metadata(input)
             .await
-            .map_err(RusotoError::from)?;
-        if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<UpdateTrailResponse, _>()
-        } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(UpdateTrailError::from_response(response))
-        }
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Creates a trail that specifies the settings for delivery of log data to an Amazon S3 bucket. </p>
+    async fn create_trail(
+        &self,
+        input: CreateTrailRequest,
+    ) -> Result<CreateTrailResponse, RusotoError<CreateTrailError>> {
+        self.create_trail_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes a trail. This operation must be called from the region in which the trail was created. <code>DeleteTrail</code> cannot be called on the shadow trails (replicated trails in other regions) of a trail that is enabled in all regions.</p>
+    async fn delete_trail(
+        &self,
+        input: DeleteTrailRequest,
+    ) -> Result<DeleteTrailResponse, RusotoError<DeleteTrailError>> {
+        self.delete_trail_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Retrieves settings for one or more trails associated with the current region for your account.</p>
+    async fn describe_trails(
+        &self,
+        input: DescribeTrailsRequest,
+    ) -> Result<DescribeTrailsResponse, RusotoError<DescribeTrailsError>> {
+        self.describe_trails_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Describes the settings for the event selectors that you configured for your trail. The information returned for your event selectors includes the following:</p> <ul> <li> <p>If your event selector includes read-only events, write-only events, or all events. This applies to both management events and data events.</p> </li> <li> <p>If your event selector includes management events.</p> </li> <li> <p>If your event selector includes data events, the Amazon S3 objects or AWS Lambda functions that you are logging for data events.</p> </li> </ul> <p>For more information, see <a href="https://docs.aws.amazon.com/awscloudtrail/latest/userguide/logging-management-and-data-events-with-cloudtrail.html">Logging Data and Management Events for Trails </a> in the <i>AWS CloudTrail User Guide</i>.</p>
+    async fn get_event_selectors(
+        &self,
+        input: GetEventSelectorsRequest,
+    ) -> Result<GetEventSelectorsResponse, RusotoError<GetEventSelectorsError>> {
+        self.get_event_selectors_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Describes the settings for the Insights event selectors that you configured for your trail. <code>GetInsightSelectors</code> shows if CloudTrail Insights event logging is enabled on the trail, and if it is, which insight types are enabled. If you run <code>GetInsightSelectors</code> on a trail that does not have Insights events enabled, the operation throws the exception <code>InsightNotEnabledException</code> </p> <p>For more information, see <a href="https://docs.aws.amazon.com/awscloudtrail/latest/userguide/logging-insights-events-with-cloudtrail.html">Logging CloudTrail Insights Events for Trails </a> in the <i>AWS CloudTrail User Guide</i>.</p>
+    async fn get_insight_selectors(
+        &self,
+        input: GetInsightSelectorsRequest,
+    ) -> Result<GetInsightSelectorsResponse, RusotoError<GetInsightSelectorsError>> {
+        self.get_insight_selectors_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns settings information for a specified trail.</p>
+    async fn get_trail(
+        &self,
+        input: GetTrailRequest,
+    ) -> Result<GetTrailResponse, RusotoError<GetTrailError>> {
+        self.get_trail_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns a JSON-formatted list of information about the specified trail. Fields include information on delivery errors, Amazon SNS and Amazon S3 errors, and start and stop logging times for each trail. This operation returns trail status from a single region. To return trail status from all regions, you must call the operation on each region.</p>
+    async fn get_trail_status(
+        &self,
+        input: GetTrailStatusRequest,
+    ) -> Result<GetTrailStatusResponse, RusotoError<GetTrailStatusError>> {
+        self.get_trail_status_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Returns all public keys whose private keys were used to sign the digest files within the specified time range. The public key is needed to validate digest files that were signed with its corresponding private key.</p> <note> <p>CloudTrail uses different private/public key pairs per region. Each digest file is signed with a private key unique to its region. Therefore, when you validate a digest file from a particular region, you must look in the same region for its corresponding public key.</p> </note></p>
+    async fn list_public_keys(
+        &self,
+        input: ListPublicKeysRequest,
+    ) -> Result<ListPublicKeysResponse, RusotoError<ListPublicKeysError>> {
+        self.list_public_keys_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Lists the tags for the trail in the current region.</p>
+    async fn list_tags(
+        &self,
+        input: ListTagsRequest,
+    ) -> Result<ListTagsResponse, RusotoError<ListTagsError>> {
+        self.list_tags_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Lists trails that are in the current account.</p>
+    async fn list_trails(
+        &self,
+        input: ListTrailsRequest,
+    ) -> Result<ListTrailsResponse, RusotoError<ListTrailsError>> {
+        self.list_trails_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Looks up <a href="https://docs.aws.amazon.com/awscloudtrail/latest/userguide/cloudtrail-concepts.html#cloudtrail-concepts-management-events">management events</a> or <a href="https://docs.aws.amazon.com/awscloudtrail/latest/userguide/cloudtrail-concepts.html#cloudtrail-concepts-insights-events">CloudTrail Insights events</a> that are captured by CloudTrail. You can look up events that occurred in a region within the last 90 days. Lookup supports the following attributes for management events:</p> <ul> <li> <p>AWS access key</p> </li> <li> <p>Event ID</p> </li> <li> <p>Event name</p> </li> <li> <p>Event source</p> </li> <li> <p>Read only</p> </li> <li> <p>Resource name</p> </li> <li> <p>Resource type</p> </li> <li> <p>User name</p> </li> </ul> <p>Lookup supports the following attributes for Insights events:</p> <ul> <li> <p>Event ID</p> </li> <li> <p>Event name</p> </li> <li> <p>Event source</p> </li> </ul> <p>All attributes are optional. The default number of results returned is 50, with a maximum of 50 possible. The response includes a token that you can use to get the next page of results.</p> <important> <p>The rate of lookup requests is limited to two per second per account. If this limit is exceeded, a throttling error occurs.</p> </important></p>
+    async fn lookup_events(
+        &self,
+        input: LookupEventsRequest,
+    ) -> Result<LookupEventsResponse, RusotoError<LookupEventsError>> {
+        self.lookup_events_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Configures an event selector for your trail. Use event selectors to further specify the management and data event settings for your trail. By default, trails created without specific event selectors will be configured to log all read and write management events, and no data events. </p> <p>When an event occurs in your account, CloudTrail evaluates the event selectors in all trails. For each trail, if the event matches any event selector, the trail processes and logs the event. If the event doesn't match any event selector, the trail doesn't log the event. </p> <p>Example</p> <ol> <li> <p>You create an event selector for a trail and specify that you want write-only events.</p> </li> <li> <p>The EC2 <code>GetConsoleOutput</code> and <code>RunInstances</code> API operations occur in your account.</p> </li> <li> <p>CloudTrail evaluates whether the events match your event selectors.</p> </li> <li> <p>The <code>RunInstances</code> is a write-only event and it matches your event selector. The trail logs the event.</p> </li> <li> <p>The <code>GetConsoleOutput</code> is a read-only event but it doesn't match your event selector. The trail doesn't log the event. </p> </li> </ol> <p>The <code>PutEventSelectors</code> operation must be called from the region in which the trail was created; otherwise, an <code>InvalidHomeRegionException</code> is thrown.</p> <p>You can configure up to five event selectors for each trail. For more information, see <a href="https://docs.aws.amazon.com/awscloudtrail/latest/userguide/logging-management-and-data-events-with-cloudtrail.html">Logging Data and Management Events for Trails </a> and <a href="https://docs.aws.amazon.com/awscloudtrail/latest/userguide/WhatIsCloudTrail-Limits.html">Limits in AWS CloudTrail</a> in the <i>AWS CloudTrail User Guide</i>.</p>
+    async fn put_event_selectors(
+        &self,
+        input: PutEventSelectorsRequest,
+    ) -> Result<PutEventSelectorsResponse, RusotoError<PutEventSelectorsError>> {
+        self.put_event_selectors_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Lets you enable Insights event logging by specifying the Insights selectors that you want to enable on an existing trail. You also use <code>PutInsightSelectors</code> to turn off Insights event logging, by passing an empty list of insight types. In this release, only <code>ApiCallRateInsight</code> is supported as an Insights selector.</p>
+    async fn put_insight_selectors(
+        &self,
+        input: PutInsightSelectorsRequest,
+    ) -> Result<PutInsightSelectorsResponse, RusotoError<PutInsightSelectorsError>> {
+        self.put_insight_selectors_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Removes the specified tags from a trail.</p>
+    async fn remove_tags(
+        &self,
+        input: RemoveTagsRequest,
+    ) -> Result<RemoveTagsResponse, RusotoError<RemoveTagsError>> {
+        self.remove_tags_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Starts the recording of AWS API calls and log file delivery for a trail. For a trail that is enabled in all regions, this operation must be called from the region in which the trail was created. This operation cannot be called on the shadow trails (replicated trails in other regions) of a trail that is enabled in all regions.</p>
+    async fn start_logging(
+        &self,
+        input: StartLoggingRequest,
+    ) -> Result<StartLoggingResponse, RusotoError<StartLoggingError>> {
+        self.start_logging_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Suspends the recording of AWS API calls and log file delivery for the specified trail. Under most circumstances, there is no need to use this action. You can update a trail without stopping it first. This action is the only way to stop recording. For a trail enabled in all regions, this operation must be called from the region in which the trail was created, or an <code>InvalidHomeRegionException</code> will occur. This operation cannot be called on the shadow trails (replicated trails in other regions) of a trail enabled in all regions.</p>
+    async fn stop_logging(
+        &self,
+        input: StopLoggingRequest,
+    ) -> Result<StopLoggingResponse, RusotoError<StopLoggingError>> {
+        self.stop_logging_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Updates the settings that specify delivery of log files. Changes to a trail do not require stopping the CloudTrail service. Use this action to designate an existing bucket for log delivery. If the existing bucket has previously been a target for CloudTrail log files, an IAM policy exists for the bucket. <code>UpdateTrail</code> must be called from the region in which the trail was created; otherwise, an <code>InvalidHomeRegionException</code> is thrown.</p>
+    async fn update_trail(
+        &self,
+        input: UpdateTrailRequest,
+    ) -> Result<UpdateTrailResponse, RusotoError<UpdateTrailError>> {
+        self.update_trail_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
     }
 }
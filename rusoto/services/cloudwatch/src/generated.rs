@@ -16,8 +16,8 @@ use std::fmt;
 use async_trait::async_trait;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
-use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest, ResponseMetadata};
+use rusoto_core::{Client, ErrorMetadata, RusotoError};
 
 use rusoto_core::param::{Params, ServiceParams};
 use rusoto_core::proto::xml::error::*;
@@ -4518,9 +4518,12 @@ impl ValuesSerializer {
 
 /// Errors returned by DeleteAlarms
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteAlarmsError {
     /// <p>The named resource does not exist.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteAlarmsError {
@@ -4532,11 +4535,12 @@ impl DeleteAlarmsError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "ResourceNotFound" => {
-                        return RusotoError::Service(DeleteAlarmsError::ResourceNotFound(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            DeleteAlarmsError::ResourceNotFound(parsed_error.message),
+                            &res,
+                        )
                     }
-                    _ => {}
+                    _ => return RusotoError::unhandled(DeleteAlarmsError::Unhandled, &res),
                 }
             }
         }
@@ -4556,12 +4560,14 @@ impl fmt::Display for DeleteAlarmsError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             DeleteAlarmsError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            DeleteAlarmsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteAlarmsError {}
 /// Errors returned by DeleteAnomalyDetector
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteAnomalyDetectorError {
     /// <p>Request processing has failed due to some unknown error, exception, or failure.</p>
     InternalServiceFault(String),
@@ -4571,6 +4577,8 @@ pub enum DeleteAnomalyDetectorError {
     MissingRequiredParameter(String),
     /// <p>The named resource does not exist.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteAnomalyDetectorError {
@@ -4582,28 +4590,34 @@ impl DeleteAnomalyDetectorError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InternalServiceError" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             DeleteAnomalyDetectorError::InternalServiceFault(parsed_error.message),
+                            &res,
                         )
                     }
                     "InvalidParameterValue" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             DeleteAnomalyDetectorError::InvalidParameterValue(parsed_error.message),
+                            &res,
                         )
                     }
                     "MissingParameter" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             DeleteAnomalyDetectorError::MissingRequiredParameter(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "ResourceNotFoundException" => {
-                        return RusotoError::Service(DeleteAnomalyDetectorError::ResourceNotFound(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            DeleteAnomalyDetectorError::ResourceNotFound(parsed_error.message),
+                            &res,
+                        )
+                    }
+                    _ => {
+                        return RusotoError::unhandled(DeleteAnomalyDetectorError::Unhandled, &res)
                     }
-                    _ => {}
                 }
             }
         }
@@ -4628,12 +4642,14 @@ impl fmt::Display for DeleteAnomalyDetectorError {
                 write!(f, "{}", cause)
             }
             DeleteAnomalyDetectorError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            DeleteAnomalyDetectorError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteAnomalyDetectorError {}
 /// Errors returned by DeleteDashboards
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteDashboardsError {
     /// <p>The specified dashboard does not exist.</p>
     DashboardNotFoundError(String),
@@ -4641,6 +4657,8 @@ pub enum DeleteDashboardsError {
     InternalServiceFault(String),
     /// <p>The value of an input parameter is bad or out-of-range.</p>
     InvalidParameterValue(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteDashboardsError {
@@ -4652,21 +4670,24 @@ impl DeleteDashboardsError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "ResourceNotFound" => {
-                        return RusotoError::Service(DeleteDashboardsError::DashboardNotFoundError(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            DeleteDashboardsError::DashboardNotFoundError(parsed_error.message),
+                            &res,
+                        )
                     }
                     "InternalServiceError" => {
-                        return RusotoError::Service(DeleteDashboardsError::InternalServiceFault(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            DeleteDashboardsError::InternalServiceFault(parsed_error.message),
+                            &res,
+                        )
                     }
                     "InvalidParameterValue" => {
-                        return RusotoError::Service(DeleteDashboardsError::InvalidParameterValue(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            DeleteDashboardsError::InvalidParameterValue(parsed_error.message),
+                            &res,
+                        )
                     }
-                    _ => {}
+                    _ => return RusotoError::unhandled(DeleteDashboardsError::Unhandled, &res),
                 }
             }
         }
@@ -4688,17 +4709,21 @@ impl fmt::Display for DeleteDashboardsError {
             DeleteDashboardsError::DashboardNotFoundError(ref cause) => write!(f, "{}", cause),
             DeleteDashboardsError::InternalServiceFault(ref cause) => write!(f, "{}", cause),
             DeleteDashboardsError::InvalidParameterValue(ref cause) => write!(f, "{}", cause),
+            DeleteDashboardsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteDashboardsError {}
 /// Errors returned by DeleteInsightRules
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteInsightRulesError {
     /// <p>The value of an input parameter is bad or out-of-range.</p>
     InvalidParameterValue(String),
     /// <p>An input parameter that is required is missing.</p>
     MissingRequiredParameter(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteInsightRulesError {
@@ -4710,16 +4735,18 @@ impl DeleteInsightRulesError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InvalidParameterValue" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             DeleteInsightRulesError::InvalidParameterValue(parsed_error.message),
+                            &res,
                         )
                     }
                     "MissingParameter" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             DeleteInsightRulesError::MissingRequiredParameter(parsed_error.message),
+                            &res,
                         )
                     }
-                    _ => {}
+                    _ => return RusotoError::unhandled(DeleteInsightRulesError::Unhandled, &res),
                 }
             }
         }
@@ -4740,15 +4767,19 @@ impl fmt::Display for DeleteInsightRulesError {
         match *self {
             DeleteInsightRulesError::InvalidParameterValue(ref cause) => write!(f, "{}", cause),
             DeleteInsightRulesError::MissingRequiredParameter(ref cause) => write!(f, "{}", cause),
+            DeleteInsightRulesError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteInsightRulesError {}
 /// Errors returned by DescribeAlarmHistory
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeAlarmHistoryError {
     /// <p>The next token specified is invalid.</p>
     InvalidNextToken(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeAlarmHistoryError {
@@ -4760,11 +4791,12 @@ impl DescribeAlarmHistoryError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InvalidNextToken" => {
-                        return RusotoError::Service(DescribeAlarmHistoryError::InvalidNextToken(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            DescribeAlarmHistoryError::InvalidNextToken(parsed_error.message),
+                            &res,
+                        )
                     }
-                    _ => {}
+                    _ => return RusotoError::unhandled(DescribeAlarmHistoryError::Unhandled, &res),
                 }
             }
         }
@@ -4784,15 +4816,19 @@ impl fmt::Display for DescribeAlarmHistoryError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             DescribeAlarmHistoryError::InvalidNextToken(ref cause) => write!(f, "{}", cause),
+            DescribeAlarmHistoryError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeAlarmHistoryError {}
 /// Errors returned by DescribeAlarms
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeAlarmsError {
     /// <p>The next token specified is invalid.</p>
     InvalidNextToken(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeAlarmsError {
@@ -4804,11 +4840,12 @@ impl DescribeAlarmsError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InvalidNextToken" => {
-                        return RusotoError::Service(DescribeAlarmsError::InvalidNextToken(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            DescribeAlarmsError::InvalidNextToken(parsed_error.message),
+                            &res,
+                        )
                     }
-                    _ => {}
+                    _ => return RusotoError::unhandled(DescribeAlarmsError::Unhandled, &res),
                 }
             }
         }
@@ -4828,13 +4865,18 @@ impl fmt::Display for DescribeAlarmsError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             DescribeAlarmsError::InvalidNextToken(ref cause) => write!(f, "{}", cause),
+            DescribeAlarmsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeAlarmsError {}
 /// Errors returned by DescribeAlarmsForMetric
 #[derive(Debug, PartialEq)]
-pub enum DescribeAlarmsForMetricError {}
+#[non_exhaustive]
+pub enum DescribeAlarmsForMetricError {
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
+}
 
 impl DescribeAlarmsForMetricError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<DescribeAlarmsForMetricError> {
@@ -4842,10 +4884,8 @@ impl DescribeAlarmsForMetricError {
             let reader = EventReader::new(res.body.as_ref());
             let mut stack = XmlResponse::new(reader.into_iter().peekable());
             find_start_element(&mut stack);
-            if let Ok(parsed_error) = Self::deserialize(&mut stack) {
-                match &parsed_error.code[..] {
-                    _ => {}
-                }
+            if Self::deserialize(&mut stack).is_ok() {
+                return RusotoError::unhandled(DescribeAlarmsForMetricError::Unhandled, &res);
             }
         }
         RusotoError::Unknown(res)
@@ -4862,12 +4902,15 @@ impl DescribeAlarmsForMetricError {
 impl fmt::Display for DescribeAlarmsForMetricError {
     #[allow(unused_variables)]
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        match *self {}
+        match *self {
+            DescribeAlarmsForMetricError::Unhandled(ref metadata) => write!(f, "{}", metadata),
+        }
     }
 }
 impl Error for DescribeAlarmsForMetricError {}
 /// Errors returned by DescribeAnomalyDetectors
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeAnomalyDetectorsError {
     /// <p>Request processing has failed due to some unknown error, exception, or failure.</p>
     InternalServiceFault(String),
@@ -4875,6 +4918,8 @@ pub enum DescribeAnomalyDetectorsError {
     InvalidNextToken(String),
     /// <p>The value of an input parameter is bad or out-of-range.</p>
     InvalidParameterValue(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeAnomalyDetectorsError {
@@ -4886,25 +4931,33 @@ impl DescribeAnomalyDetectorsError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InternalServiceError" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             DescribeAnomalyDetectorsError::InternalServiceFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "InvalidNextToken" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             DescribeAnomalyDetectorsError::InvalidNextToken(parsed_error.message),
+                            &res,
                         )
                     }
                     "InvalidParameterValue" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             DescribeAnomalyDetectorsError::InvalidParameterValue(
                                 parsed_error.message,
                             ),
+                            &res,
+                        )
+                    }
+                    _ => {
+                        return RusotoError::unhandled(
+                            DescribeAnomalyDetectorsError::Unhandled,
+                            &res,
                         )
                     }
-                    _ => {}
                 }
             }
         }
@@ -4930,15 +4983,19 @@ impl fmt::Display for DescribeAnomalyDetectorsError {
             DescribeAnomalyDetectorsError::InvalidParameterValue(ref cause) => {
                 write!(f, "{}", cause)
             }
+            DescribeAnomalyDetectorsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeAnomalyDetectorsError {}
 /// Errors returned by DescribeInsightRules
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeInsightRulesError {
     /// <p>The next token specified is invalid.</p>
     InvalidNextToken(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeInsightRulesError {
@@ -4950,11 +5007,12 @@ impl DescribeInsightRulesError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InvalidNextToken" => {
-                        return RusotoError::Service(DescribeInsightRulesError::InvalidNextToken(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            DescribeInsightRulesError::InvalidNextToken(parsed_error.message),
+                            &res,
+                        )
                     }
-                    _ => {}
+                    _ => return RusotoError::unhandled(DescribeInsightRulesError::Unhandled, &res),
                 }
             }
         }
@@ -4974,13 +5032,18 @@ impl fmt::Display for DescribeInsightRulesError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             DescribeInsightRulesError::InvalidNextToken(ref cause) => write!(f, "{}", cause),
+            DescribeInsightRulesError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeInsightRulesError {}
 /// Errors returned by DisableAlarmActions
 #[derive(Debug, PartialEq)]
-pub enum DisableAlarmActionsError {}
+#[non_exhaustive]
+pub enum DisableAlarmActionsError {
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
+}
 
 impl DisableAlarmActionsError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<DisableAlarmActionsError> {
@@ -4988,10 +5051,8 @@ impl DisableAlarmActionsError {
             let reader = EventReader::new(res.body.as_ref());
             let mut stack = XmlResponse::new(reader.into_iter().peekable());
             find_start_element(&mut stack);
-            if let Ok(parsed_error) = Self::deserialize(&mut stack) {
-                match &parsed_error.code[..] {
-                    _ => {}
-                }
+            if Self::deserialize(&mut stack).is_ok() {
+                return RusotoError::unhandled(DisableAlarmActionsError::Unhandled, &res);
             }
         }
         RusotoError::Unknown(res)
@@ -5008,17 +5069,22 @@ impl DisableAlarmActionsError {
 impl fmt::Display for DisableAlarmActionsError {
     #[allow(unused_variables)]
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        match *self {}
+        match *self {
+            DisableAlarmActionsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
+        }
     }
 }
 impl Error for DisableAlarmActionsError {}
 /// Errors returned by DisableInsightRules
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DisableInsightRulesError {
     /// <p>The value of an input parameter is bad or out-of-range.</p>
     InvalidParameterValue(String),
     /// <p>An input parameter that is required is missing.</p>
     MissingRequiredParameter(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DisableInsightRulesError {
@@ -5030,18 +5096,20 @@ impl DisableInsightRulesError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InvalidParameterValue" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             DisableInsightRulesError::InvalidParameterValue(parsed_error.message),
+                            &res,
                         )
                     }
                     "MissingParameter" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             DisableInsightRulesError::MissingRequiredParameter(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
-                    _ => {}
+                    _ => return RusotoError::unhandled(DisableInsightRulesError::Unhandled, &res),
                 }
             }
         }
@@ -5062,13 +5130,18 @@ impl fmt::Display for DisableInsightRulesError {
         match *self {
             DisableInsightRulesError::InvalidParameterValue(ref cause) => write!(f, "{}", cause),
             DisableInsightRulesError::MissingRequiredParameter(ref cause) => write!(f, "{}", cause),
+            DisableInsightRulesError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DisableInsightRulesError {}
 /// Errors returned by EnableAlarmActions
 #[derive(Debug, PartialEq)]
-pub enum EnableAlarmActionsError {}
+#[non_exhaustive]
+pub enum EnableAlarmActionsError {
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
+}
 
 impl EnableAlarmActionsError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<EnableAlarmActionsError> {
@@ -5076,10 +5149,8 @@ impl EnableAlarmActionsError {
             let reader = EventReader::new(res.body.as_ref());
             let mut stack = XmlResponse::new(reader.into_iter().peekable());
             find_start_element(&mut stack);
-            if let Ok(parsed_error) = Self::deserialize(&mut stack) {
-                match &parsed_error.code[..] {
-                    _ => {}
-                }
+            if Self::deserialize(&mut stack).is_ok() {
+                return RusotoError::unhandled(EnableAlarmActionsError::Unhandled, &res);
             }
         }
         RusotoError::Unknown(res)
@@ -5096,12 +5167,15 @@ impl EnableAlarmActionsError {
 impl fmt::Display for EnableAlarmActionsError {
     #[allow(unused_variables)]
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        match *self {}
+        match *self {
+            EnableAlarmActionsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
+        }
     }
 }
 impl Error for EnableAlarmActionsError {}
 /// Errors returned by EnableInsightRules
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum EnableInsightRulesError {
     /// <p>The value of an input parameter is bad or out-of-range.</p>
     InvalidParameterValue(String),
@@ -5109,6 +5183,8 @@ pub enum EnableInsightRulesError {
     LimitExceeded(String),
     /// <p>An input parameter that is required is missing.</p>
     MissingRequiredParameter(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl EnableInsightRulesError {
@@ -5120,21 +5196,24 @@ impl EnableInsightRulesError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InvalidParameterValue" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             EnableInsightRulesError::InvalidParameterValue(parsed_error.message),
+                            &res,
                         )
                     }
                     "LimitExceededException" => {
-                        return RusotoError::Service(EnableInsightRulesError::LimitExceeded(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            EnableInsightRulesError::LimitExceeded(parsed_error.message),
+                            &res,
+                        )
                     }
                     "MissingParameter" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             EnableInsightRulesError::MissingRequiredParameter(parsed_error.message),
+                            &res,
                         )
                     }
-                    _ => {}
+                    _ => return RusotoError::unhandled(EnableInsightRulesError::Unhandled, &res),
                 }
             }
         }
@@ -5156,12 +5235,14 @@ impl fmt::Display for EnableInsightRulesError {
             EnableInsightRulesError::InvalidParameterValue(ref cause) => write!(f, "{}", cause),
             EnableInsightRulesError::LimitExceeded(ref cause) => write!(f, "{}", cause),
             EnableInsightRulesError::MissingRequiredParameter(ref cause) => write!(f, "{}", cause),
+            EnableInsightRulesError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for EnableInsightRulesError {}
 /// Errors returned by GetDashboard
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetDashboardError {
     /// <p>The specified dashboard does not exist.</p>
     DashboardNotFoundError(String),
@@ -5169,6 +5250,8 @@ pub enum GetDashboardError {
     InternalServiceFault(String),
     /// <p>The value of an input parameter is bad or out-of-range.</p>
     InvalidParameterValue(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetDashboardError {
@@ -5180,21 +5263,24 @@ impl GetDashboardError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "ResourceNotFound" => {
-                        return RusotoError::Service(GetDashboardError::DashboardNotFoundError(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            GetDashboardError::DashboardNotFoundError(parsed_error.message),
+                            &res,
+                        )
                     }
                     "InternalServiceError" => {
-                        return RusotoError::Service(GetDashboardError::InternalServiceFault(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            GetDashboardError::InternalServiceFault(parsed_error.message),
+                            &res,
+                        )
                     }
                     "InvalidParameterValue" => {
-                        return RusotoError::Service(GetDashboardError::InvalidParameterValue(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            GetDashboardError::InvalidParameterValue(parsed_error.message),
+                            &res,
+                        )
                     }
-                    _ => {}
+                    _ => return RusotoError::unhandled(GetDashboardError::Unhandled, &res),
                 }
             }
         }
@@ -5216,12 +5302,14 @@ impl fmt::Display for GetDashboardError {
             GetDashboardError::DashboardNotFoundError(ref cause) => write!(f, "{}", cause),
             GetDashboardError::InternalServiceFault(ref cause) => write!(f, "{}", cause),
             GetDashboardError::InvalidParameterValue(ref cause) => write!(f, "{}", cause),
+            GetDashboardError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetDashboardError {}
 /// Errors returned by GetInsightRuleReport
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetInsightRuleReportError {
     /// <p>The value of an input parameter is bad or out-of-range.</p>
     InvalidParameterValue(String),
@@ -5229,6 +5317,8 @@ pub enum GetInsightRuleReportError {
     MissingRequiredParameter(String),
     /// <p>The named resource does not exist.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetInsightRuleReportError {
@@ -5240,23 +5330,26 @@ impl GetInsightRuleReportError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InvalidParameterValue" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             GetInsightRuleReportError::InvalidParameterValue(parsed_error.message),
+                            &res,
                         )
                     }
                     "MissingParameter" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             GetInsightRuleReportError::MissingRequiredParameter(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "ResourceNotFoundException" => {
-                        return RusotoError::Service(GetInsightRuleReportError::ResourceNotFound(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            GetInsightRuleReportError::ResourceNotFound(parsed_error.message),
+                            &res,
+                        )
                     }
-                    _ => {}
+                    _ => return RusotoError::unhandled(GetInsightRuleReportError::Unhandled, &res),
                 }
             }
         }
@@ -5280,15 +5373,19 @@ impl fmt::Display for GetInsightRuleReportError {
                 write!(f, "{}", cause)
             }
             GetInsightRuleReportError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            GetInsightRuleReportError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetInsightRuleReportError {}
 /// Errors returned by GetMetricData
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetMetricDataError {
     /// <p>The next token specified is invalid.</p>
     InvalidNextToken(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetMetricDataError {
@@ -5300,11 +5397,12 @@ impl GetMetricDataError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InvalidNextToken" => {
-                        return RusotoError::Service(GetMetricDataError::InvalidNextToken(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            GetMetricDataError::InvalidNextToken(parsed_error.message),
+                            &res,
+                        )
                     }
-                    _ => {}
+                    _ => return RusotoError::unhandled(GetMetricDataError::Unhandled, &res),
                 }
             }
         }
@@ -5324,12 +5422,14 @@ impl fmt::Display for GetMetricDataError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             GetMetricDataError::InvalidNextToken(ref cause) => write!(f, "{}", cause),
+            GetMetricDataError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetMetricDataError {}
 /// Errors returned by GetMetricStatistics
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetMetricStatisticsError {
     /// <p>Request processing has failed due to some unknown error, exception, or failure.</p>
     InternalServiceFault(String),
@@ -5339,6 +5439,8 @@ pub enum GetMetricStatisticsError {
     InvalidParameterValue(String),
     /// <p>An input parameter that is required is missing.</p>
     MissingRequiredParameter(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetMetricStatisticsError {
@@ -5350,30 +5452,34 @@ impl GetMetricStatisticsError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InternalServiceError" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             GetMetricStatisticsError::InternalServiceFault(parsed_error.message),
+                            &res,
                         )
                     }
                     "InvalidParameterCombination" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             GetMetricStatisticsError::InvalidParameterCombination(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "InvalidParameterValue" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             GetMetricStatisticsError::InvalidParameterValue(parsed_error.message),
+                            &res,
                         )
                     }
                     "MissingParameter" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             GetMetricStatisticsError::MissingRequiredParameter(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
-                    _ => {}
+                    _ => return RusotoError::unhandled(GetMetricStatisticsError::Unhandled, &res),
                 }
             }
         }
@@ -5398,13 +5504,18 @@ impl fmt::Display for GetMetricStatisticsError {
             }
             GetMetricStatisticsError::InvalidParameterValue(ref cause) => write!(f, "{}", cause),
             GetMetricStatisticsError::MissingRequiredParameter(ref cause) => write!(f, "{}", cause),
+            GetMetricStatisticsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetMetricStatisticsError {}
 /// Errors returned by GetMetricWidgetImage
 #[derive(Debug, PartialEq)]
-pub enum GetMetricWidgetImageError {}
+#[non_exhaustive]
+pub enum GetMetricWidgetImageError {
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
+}
 
 impl GetMetricWidgetImageError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<GetMetricWidgetImageError> {
@@ -5412,10 +5523,8 @@ impl GetMetricWidgetImageError {
             let reader = EventReader::new(res.body.as_ref());
             let mut stack = XmlResponse::new(reader.into_iter().peekable());
             find_start_element(&mut stack);
-            if let Ok(parsed_error) = Self::deserialize(&mut stack) {
-                match &parsed_error.code[..] {
-                    _ => {}
-                }
+            if Self::deserialize(&mut stack).is_ok() {
+                return RusotoError::unhandled(GetMetricWidgetImageError::Unhandled, &res);
             }
         }
         RusotoError::Unknown(res)
@@ -5432,17 +5541,22 @@ impl GetMetricWidgetImageError {
 impl fmt::Display for GetMetricWidgetImageError {
     #[allow(unused_variables)]
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        match *self {}
+        match *self {
+            GetMetricWidgetImageError::Unhandled(ref metadata) => write!(f, "{}", metadata),
+        }
     }
 }
 impl Error for GetMetricWidgetImageError {}
 /// Errors returned by ListDashboards
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListDashboardsError {
     /// <p>Request processing has failed due to some unknown error, exception, or failure.</p>
     InternalServiceFault(String),
     /// <p>The value of an input parameter is bad or out-of-range.</p>
     InvalidParameterValue(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListDashboardsError {
@@ -5454,16 +5568,18 @@ impl ListDashboardsError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InternalServiceError" => {
-                        return RusotoError::Service(ListDashboardsError::InternalServiceFault(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            ListDashboardsError::InternalServiceFault(parsed_error.message),
+                            &res,
+                        )
                     }
                     "InvalidParameterValue" => {
-                        return RusotoError::Service(ListDashboardsError::InvalidParameterValue(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            ListDashboardsError::InvalidParameterValue(parsed_error.message),
+                            &res,
+                        )
                     }
-                    _ => {}
+                    _ => return RusotoError::unhandled(ListDashboardsError::Unhandled, &res),
                 }
             }
         }
@@ -5484,17 +5600,21 @@ impl fmt::Display for ListDashboardsError {
         match *self {
             ListDashboardsError::InternalServiceFault(ref cause) => write!(f, "{}", cause),
             ListDashboardsError::InvalidParameterValue(ref cause) => write!(f, "{}", cause),
+            ListDashboardsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListDashboardsError {}
 /// Errors returned by ListMetrics
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListMetricsError {
     /// <p>Request processing has failed due to some unknown error, exception, or failure.</p>
     InternalServiceFault(String),
     /// <p>The value of an input parameter is bad or out-of-range.</p>
     InvalidParameterValue(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListMetricsError {
@@ -5506,16 +5626,18 @@ impl ListMetricsError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InternalServiceError" => {
-                        return RusotoError::Service(ListMetricsError::InternalServiceFault(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            ListMetricsError::InternalServiceFault(parsed_error.message),
+                            &res,
+                        )
                     }
                     "InvalidParameterValue" => {
-                        return RusotoError::Service(ListMetricsError::InvalidParameterValue(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            ListMetricsError::InvalidParameterValue(parsed_error.message),
+                            &res,
+                        )
                     }
-                    _ => {}
+                    _ => return RusotoError::unhandled(ListMetricsError::Unhandled, &res),
                 }
             }
         }
@@ -5536,12 +5658,14 @@ impl fmt::Display for ListMetricsError {
         match *self {
             ListMetricsError::InternalServiceFault(ref cause) => write!(f, "{}", cause),
             ListMetricsError::InvalidParameterValue(ref cause) => write!(f, "{}", cause),
+            ListMetricsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListMetricsError {}
 /// Errors returned by ListTagsForResource
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListTagsForResourceError {
     /// <p>Request processing has failed due to some unknown error, exception, or failure.</p>
     InternalServiceFault(String),
@@ -5549,6 +5673,8 @@ pub enum ListTagsForResourceError {
     InvalidParameterValue(String),
     /// <p>The named resource does not exist.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListTagsForResourceError {
@@ -5560,21 +5686,24 @@ impl ListTagsForResourceError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InternalServiceError" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             ListTagsForResourceError::InternalServiceFault(parsed_error.message),
+                            &res,
                         )
                     }
                     "InvalidParameterValue" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             ListTagsForResourceError::InvalidParameterValue(parsed_error.message),
+                            &res,
                         )
                     }
                     "ResourceNotFoundException" => {
-                        return RusotoError::Service(ListTagsForResourceError::ResourceNotFound(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            ListTagsForResourceError::ResourceNotFound(parsed_error.message),
+                            &res,
+                        )
                     }
-                    _ => {}
+                    _ => return RusotoError::unhandled(ListTagsForResourceError::Unhandled, &res),
                 }
             }
         }
@@ -5596,12 +5725,14 @@ impl fmt::Display for ListTagsForResourceError {
             ListTagsForResourceError::InternalServiceFault(ref cause) => write!(f, "{}", cause),
             ListTagsForResourceError::InvalidParameterValue(ref cause) => write!(f, "{}", cause),
             ListTagsForResourceError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            ListTagsForResourceError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListTagsForResourceError {}
 /// Errors returned by PutAnomalyDetector
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum PutAnomalyDetectorError {
     /// <p>Request processing has failed due to some unknown error, exception, or failure.</p>
     InternalServiceFault(String),
@@ -5611,6 +5742,8 @@ pub enum PutAnomalyDetectorError {
     LimitExceeded(String),
     /// <p>An input parameter that is required is missing.</p>
     MissingRequiredParameter(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl PutAnomalyDetectorError {
@@ -5622,26 +5755,30 @@ impl PutAnomalyDetectorError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InternalServiceError" => {
-                        return RusotoError::Service(PutAnomalyDetectorError::InternalServiceFault(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            PutAnomalyDetectorError::InternalServiceFault(parsed_error.message),
+                            &res,
+                        )
                     }
                     "InvalidParameterValue" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             PutAnomalyDetectorError::InvalidParameterValue(parsed_error.message),
+                            &res,
                         )
                     }
                     "LimitExceededException" => {
-                        return RusotoError::Service(PutAnomalyDetectorError::LimitExceeded(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            PutAnomalyDetectorError::LimitExceeded(parsed_error.message),
+                            &res,
+                        )
                     }
                     "MissingParameter" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             PutAnomalyDetectorError::MissingRequiredParameter(parsed_error.message),
+                            &res,
                         )
                     }
-                    _ => {}
+                    _ => return RusotoError::unhandled(PutAnomalyDetectorError::Unhandled, &res),
                 }
             }
         }
@@ -5664,17 +5801,21 @@ impl fmt::Display for PutAnomalyDetectorError {
             PutAnomalyDetectorError::InvalidParameterValue(ref cause) => write!(f, "{}", cause),
             PutAnomalyDetectorError::LimitExceeded(ref cause) => write!(f, "{}", cause),
             PutAnomalyDetectorError::MissingRequiredParameter(ref cause) => write!(f, "{}", cause),
+            PutAnomalyDetectorError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for PutAnomalyDetectorError {}
 /// Errors returned by PutDashboard
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum PutDashboardError {
     /// <p>Some part of the dashboard data is invalid.</p>
     DashboardInvalidInputError(String),
     /// <p>Request processing has failed due to some unknown error, exception, or failure.</p>
     InternalServiceFault(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl PutDashboardError {
@@ -5686,16 +5827,18 @@ impl PutDashboardError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InvalidParameterInput" => {
-                        return RusotoError::Service(PutDashboardError::DashboardInvalidInputError(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            PutDashboardError::DashboardInvalidInputError(parsed_error.message),
+                            &res,
+                        )
                     }
                     "InternalServiceError" => {
-                        return RusotoError::Service(PutDashboardError::InternalServiceFault(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            PutDashboardError::InternalServiceFault(parsed_error.message),
+                            &res,
+                        )
                     }
-                    _ => {}
+                    _ => return RusotoError::unhandled(PutDashboardError::Unhandled, &res),
                 }
             }
         }
@@ -5716,12 +5859,14 @@ impl fmt::Display for PutDashboardError {
         match *self {
             PutDashboardError::DashboardInvalidInputError(ref cause) => write!(f, "{}", cause),
             PutDashboardError::InternalServiceFault(ref cause) => write!(f, "{}", cause),
+            PutDashboardError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for PutDashboardError {}
 /// Errors returned by PutInsightRule
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum PutInsightRuleError {
     /// <p>The value of an input parameter is bad or out-of-range.</p>
     InvalidParameterValue(String),
@@ -5729,6 +5874,8 @@ pub enum PutInsightRuleError {
     LimitExceeded(String),
     /// <p>An input parameter that is required is missing.</p>
     MissingRequiredParameter(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl PutInsightRuleError {
@@ -5740,21 +5887,24 @@ impl PutInsightRuleError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InvalidParameterValue" => {
-                        return RusotoError::Service(PutInsightRuleError::InvalidParameterValue(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            PutInsightRuleError::InvalidParameterValue(parsed_error.message),
+                            &res,
+                        )
                     }
                     "LimitExceededException" => {
-                        return RusotoError::Service(PutInsightRuleError::LimitExceeded(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            PutInsightRuleError::LimitExceeded(parsed_error.message),
+                            &res,
+                        )
                     }
                     "MissingParameter" => {
-                        return RusotoError::Service(PutInsightRuleError::MissingRequiredParameter(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            PutInsightRuleError::MissingRequiredParameter(parsed_error.message),
+                            &res,
+                        )
                     }
-                    _ => {}
+                    _ => return RusotoError::unhandled(PutInsightRuleError::Unhandled, &res),
                 }
             }
         }
@@ -5776,15 +5926,19 @@ impl fmt::Display for PutInsightRuleError {
             PutInsightRuleError::InvalidParameterValue(ref cause) => write!(f, "{}", cause),
             PutInsightRuleError::LimitExceeded(ref cause) => write!(f, "{}", cause),
             PutInsightRuleError::MissingRequiredParameter(ref cause) => write!(f, "{}", cause),
+            PutInsightRuleError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for PutInsightRuleError {}
 /// Errors returned by PutMetricAlarm
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum PutMetricAlarmError {
     /// <p>The quota for alarms for this customer has already been reached.</p>
     LimitExceededFault(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl PutMetricAlarmError {
@@ -5796,11 +5950,12 @@ impl PutMetricAlarmError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "LimitExceeded" => {
-                        return RusotoError::Service(PutMetricAlarmError::LimitExceededFault(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            PutMetricAlarmError::LimitExceededFault(parsed_error.message),
+                            &res,
+                        )
                     }
-                    _ => {}
+                    _ => return RusotoError::unhandled(PutMetricAlarmError::Unhandled, &res),
                 }
             }
         }
@@ -5820,12 +5975,14 @@ impl fmt::Display for PutMetricAlarmError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             PutMetricAlarmError::LimitExceededFault(ref cause) => write!(f, "{}", cause),
+            PutMetricAlarmError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for PutMetricAlarmError {}
 /// Errors returned by PutMetricData
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum PutMetricDataError {
     /// <p>Request processing has failed due to some unknown error, exception, or failure.</p>
     InternalServiceFault(String),
@@ -5835,6 +5992,8 @@ pub enum PutMetricDataError {
     InvalidParameterValue(String),
     /// <p>An input parameter that is required is missing.</p>
     MissingRequiredParameter(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl PutMetricDataError {
@@ -5846,26 +6005,30 @@ impl PutMetricDataError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InternalServiceError" => {
-                        return RusotoError::Service(PutMetricDataError::InternalServiceFault(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            PutMetricDataError::InternalServiceFault(parsed_error.message),
+                            &res,
+                        )
                     }
                     "InvalidParameterCombination" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             PutMetricDataError::InvalidParameterCombination(parsed_error.message),
+                            &res,
                         )
                     }
                     "InvalidParameterValue" => {
-                        return RusotoError::Service(PutMetricDataError::InvalidParameterValue(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            PutMetricDataError::InvalidParameterValue(parsed_error.message),
+                            &res,
+                        )
                     }
                     "MissingParameter" => {
-                        return RusotoError::Service(PutMetricDataError::MissingRequiredParameter(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            PutMetricDataError::MissingRequiredParameter(parsed_error.message),
+                            &res,
+                        )
                     }
-                    _ => {}
+                    _ => return RusotoError::unhandled(PutMetricDataError::Unhandled, &res),
                 }
             }
         }
@@ -5888,17 +6051,21 @@ impl fmt::Display for PutMetricDataError {
             PutMetricDataError::InvalidParameterCombination(ref cause) => write!(f, "{}", cause),
             PutMetricDataError::InvalidParameterValue(ref cause) => write!(f, "{}", cause),
             PutMetricDataError::MissingRequiredParameter(ref cause) => write!(f, "{}", cause),
+            PutMetricDataError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for PutMetricDataError {}
 /// Errors returned by SetAlarmState
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum SetAlarmStateError {
     /// <p>Data was not syntactically valid JSON.</p>
     InvalidFormatFault(String),
     /// <p>The named resource does not exist.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl SetAlarmStateError {
@@ -5910,16 +6077,18 @@ impl SetAlarmStateError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InvalidFormat" => {
-                        return RusotoError::Service(SetAlarmStateError::InvalidFormatFault(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            SetAlarmStateError::InvalidFormatFault(parsed_error.message),
+                            &res,
+                        )
                     }
                     "ResourceNotFound" => {
-                        return RusotoError::Service(SetAlarmStateError::ResourceNotFound(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            SetAlarmStateError::ResourceNotFound(parsed_error.message),
+                            &res,
+                        )
                     }
-                    _ => {}
+                    _ => return RusotoError::unhandled(SetAlarmStateError::Unhandled, &res),
                 }
             }
         }
@@ -5940,12 +6109,14 @@ impl fmt::Display for SetAlarmStateError {
         match *self {
             SetAlarmStateError::InvalidFormatFault(ref cause) => write!(f, "{}", cause),
             SetAlarmStateError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            SetAlarmStateError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for SetAlarmStateError {}
 /// Errors returned by TagResource
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum TagResourceError {
     /// <p>More than one process tried to modify a resource at the same time.</p>
     ConcurrentModification(String),
@@ -5955,6 +6126,8 @@ pub enum TagResourceError {
     InvalidParameterValue(String),
     /// <p>The named resource does not exist.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl TagResourceError {
@@ -5966,26 +6139,30 @@ impl TagResourceError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "ConcurrentModificationException" => {
-                        return RusotoError::Service(TagResourceError::ConcurrentModification(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            TagResourceError::ConcurrentModification(parsed_error.message),
+                            &res,
+                        )
                     }
                     "InternalServiceError" => {
-                        return RusotoError::Service(TagResourceError::InternalServiceFault(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            TagResourceError::InternalServiceFault(parsed_error.message),
+                            &res,
+                        )
                     }
                     "InvalidParameterValue" => {
-                        return RusotoError::Service(TagResourceError::InvalidParameterValue(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            TagResourceError::InvalidParameterValue(parsed_error.message),
+                            &res,
+                        )
                     }
                     "ResourceNotFoundException" => {
-                        return RusotoError::Service(TagResourceError::ResourceNotFound(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            TagResourceError::ResourceNotFound(parsed_error.message),
+                            &res,
+                        )
                     }
-                    _ => {}
+                    _ => return RusotoError::unhandled(TagResourceError::Unhandled, &res),
                 }
             }
         }
@@ -6008,12 +6185,14 @@ impl fmt::Display for TagResourceError {
             TagResourceError::InternalServiceFault(ref cause) => write!(f, "{}", cause),
             TagResourceError::InvalidParameterValue(ref cause) => write!(f, "{}", cause),
             TagResourceError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            TagResourceError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for TagResourceError {}
 /// Errors returned by UntagResource
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UntagResourceError {
     /// <p>More than one process tried to modify a resource at the same time.</p>
     ConcurrentModification(String),
@@ -6023,6 +6202,8 @@ pub enum UntagResourceError {
     InvalidParameterValue(String),
     /// <p>The named resource does not exist.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UntagResourceError {
@@ -6034,26 +6215,30 @@ impl UntagResourceError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "ConcurrentModificationException" => {
-                        return RusotoError::Service(UntagResourceError::ConcurrentModification(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            UntagResourceError::ConcurrentModification(parsed_error.message),
+                            &res,
+                        )
                     }
                     "InternalServiceError" => {
-                        return RusotoError::Service(UntagResourceError::InternalServiceFault(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            UntagResourceError::InternalServiceFault(parsed_error.message),
+                            &res,
+                        )
                     }
                     "InvalidParameterValue" => {
-                        return RusotoError::Service(UntagResourceError::InvalidParameterValue(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            UntagResourceError::InvalidParameterValue(parsed_error.message),
+                            &res,
+                        )
                     }
                     "ResourceNotFoundException" => {
-                        return RusotoError::Service(UntagResourceError::ResourceNotFound(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            UntagResourceError::ResourceNotFound(parsed_error.message),
+                            &res,
+                        )
                     }
-                    _ => {}
+                    _ => return RusotoError::unhandled(UntagResourceError::Unhandled, &res),
                 }
             }
         }
@@ -6076,6 +6261,7 @@ impl fmt::Display for UntagResourceError {
             UntagResourceError::InternalServiceFault(ref cause) => write!(f, "{}", cause),
             UntagResourceError::InvalidParameterValue(ref cause) => write!(f, "{}", cause),
             UntagResourceError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            UntagResourceError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
@@ -6268,6 +6454,7 @@ impl CloudWatchClient {
     /// Creates a client backed by the default tokio event loop.
     ///
     /// The client will use the default credentials provider and tls client.
+    #[cfg(not(target_arch = "wasm32"))]
     pub fn new(region: region::Region) -> CloudWatchClient {
         CloudWatchClient {
             client: Client::shared(),
@@ -6293,63 +6480,101 @@ impl CloudWatchClient {
     pub fn new_with_client(client: Client, region: region::Region) -> CloudWatchClient {
         CloudWatchClient { client, region }
     }
+
+    /// Returns a builder of a client, setting any of its region, credentials provider,
+    /// dispatcher, retry policy, endpoint and timeouts.
+    pub fn builder() -> rusoto_core::ClientBuilder<CloudWatchClient> {
+        rusoto_core::ClientBuilder::new(CloudWatchClient::new_with_client)
+    }
+
+    /// Returns a clone of this client with the overrides of `config` applied to its
+    /// operations, sharing its connections.
+    pub fn customize(&self, config: &rusoto_core::OperationConfig) -> CloudWatchClient {
+        CloudWatchClient {
+            client: self.client.customize(config),
+            region: config.resolve_region(&self.region),
+        }
+    }
 }
 
-#[async_trait]
-impl CloudWatch for CloudWatchClient {
-    /// <p>Deletes the specified alarms. You can delete up to 50 alarms in one operation. In the event of an error, no alarms are deleted.</p>
-    async fn delete_alarms(
+impl CloudWatchClient {
+    /// Calls `delete_alarms`, also returning the metadata of the response.
+    pub async fn delete_alarms_with_metadata(
         &self,
         input: DeleteAlarmsInput,
-    ) -> Result<(), RusotoError<DeleteAlarmsError>> {
-        let mut request = SignedRequest::new("POST", "monitoring", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "DeleteAlarms");
-        params.put("Version", "2010-08-01");
-        DeleteAlarmsInputSerializer::serialize(&mut params, "", &input);
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+    ) -> Result<((), ResponseMetadata), RusotoError<DeleteAlarmsError>> {
+        let request = self.build_delete_alarms_request(input);
 
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(DeleteAlarmsError::from_response(response));
         }
 
         std::mem::drop(response);
-        Ok(())
+        Ok(((), metadata))
     }
 
-    /// <p>Deletes the specified anomaly detection model from your account.</p>
-    async fn delete_anomaly_detector(
+    /// Presigns the request `delete_alarms` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_alarms(
         &self,
-        input: DeleteAnomalyDetectorInput,
-    ) -> Result<DeleteAnomalyDetectorOutput, RusotoError<DeleteAnomalyDetectorError>> {
+        presigner: &rusoto_core::Presigner,
+        input: DeleteAlarmsInput,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_alarms_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_alarms_request(&self, input: DeleteAlarmsInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "monitoring", &self.region, "/");
+        request.set_operation("DeleteAlarms");
         let mut params = Params::new();
 
-        params.put("Action", "DeleteAnomalyDetector");
+        params.put("Action", "DeleteAlarms");
         params.put("Version", "2010-08-01");
-        DeleteAnomalyDetectorInputSerializer::serialize(&mut params, "", &input);
+        DeleteAlarmsInputSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `delete_anomaly_detector`, also returning the metadata of the response.
+    pub async fn delete_anomaly_detector_with_metadata(
+        &self,
+        input: DeleteAnomalyDetectorInput,
+    ) -> Result<
+        (DeleteAnomalyDetectorOutput, ResponseMetadata),
+        RusotoError<DeleteAnomalyDetectorError>,
+    > {
+        let request = self.build_delete_anomaly_detector_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(DeleteAnomalyDetectorError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -6371,34 +6596,62 @@ impl CloudWatch for CloudWatchClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Deletes all dashboards that you specify. You may specify up to 100 dashboards to delete. If there is an error during this call, no dashboards are deleted.</p>
-    async fn delete_dashboards(
+    /// Presigns the request `delete_anomaly_detector` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_anomaly_detector(
         &self,
-        input: DeleteDashboardsInput,
-    ) -> Result<DeleteDashboardsOutput, RusotoError<DeleteDashboardsError>> {
+        presigner: &rusoto_core::Presigner,
+        input: DeleteAnomalyDetectorInput,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_anomaly_detector_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_anomaly_detector_request(
+        &self,
+        input: DeleteAnomalyDetectorInput,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "monitoring", &self.region, "/");
+        request.set_operation("DeleteAnomalyDetector");
         let mut params = Params::new();
 
-        params.put("Action", "DeleteDashboards");
+        params.put("Action", "DeleteAnomalyDetector");
         params.put("Version", "2010-08-01");
-        DeleteDashboardsInputSerializer::serialize(&mut params, "", &input);
+        DeleteAnomalyDetectorInputSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `delete_dashboards`, also returning the metadata of the response.
+    pub async fn delete_dashboards_with_metadata(
+        &self,
+        input: DeleteDashboardsInput,
+    ) -> Result<(DeleteDashboardsOutput, ResponseMetadata), RusotoError<DeleteDashboardsError>>
+    {
+        let request = self.build_delete_dashboards_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(DeleteDashboardsError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -6420,34 +6673,59 @@ impl CloudWatch for CloudWatchClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Permanently deletes the specified Contributor Insights rules.</p> <p>If you create a rule, delete it, and then re-create it with the same name, historical data from the first time the rule was created may or may not be available.</p>
-    async fn delete_insight_rules(
+    /// Presigns the request `delete_dashboards` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_dashboards(
         &self,
-        input: DeleteInsightRulesInput,
-    ) -> Result<DeleteInsightRulesOutput, RusotoError<DeleteInsightRulesError>> {
+        presigner: &rusoto_core::Presigner,
+        input: DeleteDashboardsInput,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_dashboards_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_dashboards_request(&self, input: DeleteDashboardsInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "monitoring", &self.region, "/");
+        request.set_operation("DeleteDashboards");
         let mut params = Params::new();
 
-        params.put("Action", "DeleteInsightRules");
+        params.put("Action", "DeleteDashboards");
         params.put("Version", "2010-08-01");
-        DeleteInsightRulesInputSerializer::serialize(&mut params, "", &input);
+        DeleteDashboardsInputSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `delete_insight_rules`, also returning the metadata of the response.
+    pub async fn delete_insight_rules_with_metadata(
+        &self,
+        input: DeleteInsightRulesInput,
+    ) -> Result<(DeleteInsightRulesOutput, ResponseMetadata), RusotoError<DeleteInsightRulesError>>
+    {
+        let request = self.build_delete_insight_rules_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(DeleteInsightRulesError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -6469,34 +6747,61 @@ impl CloudWatch for CloudWatchClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Retrieves the history for the specified alarm. You can filter the results by date range or item type. If an alarm name is not specified, the histories for all alarms are returned.</p> <p>CloudWatch retains the history of an alarm even if you delete the alarm.</p>
-    async fn describe_alarm_history(
+    /// Presigns the request `delete_insight_rules` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_insight_rules(
         &self,
-        input: DescribeAlarmHistoryInput,
-    ) -> Result<DescribeAlarmHistoryOutput, RusotoError<DescribeAlarmHistoryError>> {
+        presigner: &rusoto_core::Presigner,
+        input: DeleteInsightRulesInput,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_insight_rules_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_insight_rules_request(&self, input: DeleteInsightRulesInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "monitoring", &self.region, "/");
+        request.set_operation("DeleteInsightRules");
         let mut params = Params::new();
 
-        params.put("Action", "DescribeAlarmHistory");
+        params.put("Action", "DeleteInsightRules");
         params.put("Version", "2010-08-01");
-        DescribeAlarmHistoryInputSerializer::serialize(&mut params, "", &input);
+        DeleteInsightRulesInputSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `describe_alarm_history`, also returning the metadata of the response.
+    pub async fn describe_alarm_history_with_metadata(
+        &self,
+        input: DescribeAlarmHistoryInput,
+    ) -> Result<
+        (DescribeAlarmHistoryOutput, ResponseMetadata),
+        RusotoError<DescribeAlarmHistoryError>,
+    > {
+        let request = self.build_describe_alarm_history_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(DescribeAlarmHistoryError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -6518,34 +6823,61 @@ impl CloudWatch for CloudWatchClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Retrieves the specified alarms. If no alarms are specified, all alarms are returned. Alarms can be retrieved by using only a prefix for the alarm name, the alarm state, or a prefix for any action.</p>
-    async fn describe_alarms(
+    /// Presigns the request `describe_alarm_history` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_alarm_history(
         &self,
-        input: DescribeAlarmsInput,
-    ) -> Result<DescribeAlarmsOutput, RusotoError<DescribeAlarmsError>> {
+        presigner: &rusoto_core::Presigner,
+        input: DescribeAlarmHistoryInput,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_alarm_history_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_alarm_history_request(
+        &self,
+        input: DescribeAlarmHistoryInput,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "monitoring", &self.region, "/");
+        request.set_operation("DescribeAlarmHistory");
         let mut params = Params::new();
 
-        params.put("Action", "DescribeAlarms");
+        params.put("Action", "DescribeAlarmHistory");
         params.put("Version", "2010-08-01");
-        DescribeAlarmsInputSerializer::serialize(&mut params, "", &input);
+        DescribeAlarmHistoryInputSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `describe_alarms`, also returning the metadata of the response.
+    pub async fn describe_alarms_with_metadata(
+        &self,
+        input: DescribeAlarmsInput,
+    ) -> Result<(DescribeAlarmsOutput, ResponseMetadata), RusotoError<DescribeAlarmsError>> {
+        let request = self.build_describe_alarms_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(DescribeAlarmsError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -6565,34 +6897,61 @@ impl CloudWatch for CloudWatchClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Retrieves the alarms for the specified metric. To filter the results, specify a statistic, period, or unit.</p>
-    async fn describe_alarms_for_metric(
+    /// Presigns the request `describe_alarms` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_alarms(
         &self,
-        input: DescribeAlarmsForMetricInput,
-    ) -> Result<DescribeAlarmsForMetricOutput, RusotoError<DescribeAlarmsForMetricError>> {
+        presigner: &rusoto_core::Presigner,
+        input: DescribeAlarmsInput,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_alarms_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_alarms_request(&self, input: DescribeAlarmsInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "monitoring", &self.region, "/");
+        request.set_operation("DescribeAlarms");
         let mut params = Params::new();
 
-        params.put("Action", "DescribeAlarmsForMetric");
+        params.put("Action", "DescribeAlarms");
         params.put("Version", "2010-08-01");
-        DescribeAlarmsForMetricInputSerializer::serialize(&mut params, "", &input);
+        DescribeAlarmsInputSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `describe_alarms_for_metric`, also returning the metadata of the response.
+    pub async fn describe_alarms_for_metric_with_metadata(
+        &self,
+        input: DescribeAlarmsForMetricInput,
+    ) -> Result<
+        (DescribeAlarmsForMetricOutput, ResponseMetadata),
+        RusotoError<DescribeAlarmsForMetricError>,
+    > {
+        let request = self.build_describe_alarms_for_metric_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(DescribeAlarmsForMetricError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -6614,34 +6973,64 @@ impl CloudWatch for CloudWatchClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Lists the anomaly detection models that you have created in your account. You can list all models in your account or filter the results to only the models that are related to a certain namespace, metric name, or metric dimension.</p>
-    async fn describe_anomaly_detectors(
+    /// Presigns the request `describe_alarms_for_metric` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_alarms_for_metric(
         &self,
-        input: DescribeAnomalyDetectorsInput,
-    ) -> Result<DescribeAnomalyDetectorsOutput, RusotoError<DescribeAnomalyDetectorsError>> {
+        presigner: &rusoto_core::Presigner,
+        input: DescribeAlarmsForMetricInput,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_alarms_for_metric_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_alarms_for_metric_request(
+        &self,
+        input: DescribeAlarmsForMetricInput,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "monitoring", &self.region, "/");
+        request.set_operation("DescribeAlarmsForMetric");
         let mut params = Params::new();
 
-        params.put("Action", "DescribeAnomalyDetectors");
+        params.put("Action", "DescribeAlarmsForMetric");
         params.put("Version", "2010-08-01");
-        DescribeAnomalyDetectorsInputSerializer::serialize(&mut params, "", &input);
+        DescribeAlarmsForMetricInputSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `describe_anomaly_detectors`, also returning the metadata of the response.
+    pub async fn describe_anomaly_detectors_with_metadata(
+        &self,
+        input: DescribeAnomalyDetectorsInput,
+    ) -> Result<
+        (DescribeAnomalyDetectorsOutput, ResponseMetadata),
+        RusotoError<DescribeAnomalyDetectorsError>,
+    > {
+        let request = self.build_describe_anomaly_detectors_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(DescribeAnomalyDetectorsError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -6663,34 +7052,64 @@ impl CloudWatch for CloudWatchClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Returns a list of all the Contributor Insights rules in your account. All rules in your account are returned with a single operation.</p> <p>For more information about Contributor Insights, see <a href="https://docs.aws.amazon.com/AmazonCloudWatch/latest/monitoring/ContributorInsights.html">Using Contributor Insights to Analyze High-Cardinality Data</a>.</p>
-    async fn describe_insight_rules(
+    /// Presigns the request `describe_anomaly_detectors` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_anomaly_detectors(
         &self,
-        input: DescribeInsightRulesInput,
-    ) -> Result<DescribeInsightRulesOutput, RusotoError<DescribeInsightRulesError>> {
+        presigner: &rusoto_core::Presigner,
+        input: DescribeAnomalyDetectorsInput,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_anomaly_detectors_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_anomaly_detectors_request(
+        &self,
+        input: DescribeAnomalyDetectorsInput,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "monitoring", &self.region, "/");
+        request.set_operation("DescribeAnomalyDetectors");
         let mut params = Params::new();
 
-        params.put("Action", "DescribeInsightRules");
+        params.put("Action", "DescribeAnomalyDetectors");
         params.put("Version", "2010-08-01");
-        DescribeInsightRulesInputSerializer::serialize(&mut params, "", &input);
+        DescribeAnomalyDetectorsInputSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `describe_insight_rules`, also returning the metadata of the response.
+    pub async fn describe_insight_rules_with_metadata(
+        &self,
+        input: DescribeInsightRulesInput,
+    ) -> Result<
+        (DescribeInsightRulesOutput, ResponseMetadata),
+        RusotoError<DescribeInsightRulesError>,
+    > {
+        let request = self.build_describe_insight_rules_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(DescribeInsightRulesError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -6712,62 +7131,114 @@ impl CloudWatch for CloudWatchClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Disables the actions for the specified alarms. When an alarm's actions are disabled, the alarm actions do not execute when the alarm state changes.</p>
-    async fn disable_alarm_actions(
+    /// Presigns the request `describe_insight_rules` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_insight_rules(
         &self,
-        input: DisableAlarmActionsInput,
-    ) -> Result<(), RusotoError<DisableAlarmActionsError>> {
+        presigner: &rusoto_core::Presigner,
+        input: DescribeInsightRulesInput,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_insight_rules_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_insight_rules_request(
+        &self,
+        input: DescribeInsightRulesInput,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "monitoring", &self.region, "/");
+        request.set_operation("DescribeInsightRules");
         let mut params = Params::new();
 
-        params.put("Action", "DisableAlarmActions");
+        params.put("Action", "DescribeInsightRules");
         params.put("Version", "2010-08-01");
-        DisableAlarmActionsInputSerializer::serialize(&mut params, "", &input);
+        DescribeInsightRulesInputSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
-        let mut response = self
-            .client
+        request
+    }
+
+    /// Calls `disable_alarm_actions`, also returning the metadata of the response.
+    pub async fn disable_alarm_actions_with_metadata(
+        &self,
+        input: DisableAlarmActionsInput,
+    ) -> Result<((), ResponseMetadata), RusotoError<DisableAlarmActionsError>> {
+        let request = self.build_disable_alarm_actions_request(input);
+
+        let mut response = self
+            .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(DisableAlarmActionsError::from_response(response));
         }
 
         std::mem::drop(response);
-        Ok(())
+        Ok(((), metadata))
     }
 
-    /// <p>Disables the specified Contributor Insights rules. When rules are disabled, they do not analyze log groups and do not incur costs.</p>
-    async fn disable_insight_rules(
+    /// Presigns the request `disable_alarm_actions` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_disable_alarm_actions(
         &self,
-        input: DisableInsightRulesInput,
-    ) -> Result<DisableInsightRulesOutput, RusotoError<DisableInsightRulesError>> {
+        presigner: &rusoto_core::Presigner,
+        input: DisableAlarmActionsInput,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_disable_alarm_actions_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_disable_alarm_actions_request(
+        &self,
+        input: DisableAlarmActionsInput,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "monitoring", &self.region, "/");
+        request.set_operation("DisableAlarmActions");
         let mut params = Params::new();
 
-        params.put("Action", "DisableInsightRules");
+        params.put("Action", "DisableAlarmActions");
         params.put("Version", "2010-08-01");
-        DisableInsightRulesInputSerializer::serialize(&mut params, "", &input);
+        DisableAlarmActionsInputSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `disable_insight_rules`, also returning the metadata of the response.
+    pub async fn disable_insight_rules_with_metadata(
+        &self,
+        input: DisableInsightRulesInput,
+    ) -> Result<(DisableInsightRulesOutput, ResponseMetadata), RusotoError<DisableInsightRulesError>>
+    {
+        let request = self.build_disable_insight_rules_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(DisableInsightRulesError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -6789,62 +7260,111 @@ impl CloudWatch for CloudWatchClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Enables the actions for the specified alarms.</p>
-    async fn enable_alarm_actions(
+    /// Presigns the request `disable_insight_rules` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_disable_insight_rules(
         &self,
-        input: EnableAlarmActionsInput,
-    ) -> Result<(), RusotoError<EnableAlarmActionsError>> {
+        presigner: &rusoto_core::Presigner,
+        input: DisableInsightRulesInput,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_disable_insight_rules_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_disable_insight_rules_request(
+        &self,
+        input: DisableInsightRulesInput,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "monitoring", &self.region, "/");
+        request.set_operation("DisableInsightRules");
         let mut params = Params::new();
 
-        params.put("Action", "EnableAlarmActions");
+        params.put("Action", "DisableInsightRules");
         params.put("Version", "2010-08-01");
-        EnableAlarmActionsInputSerializer::serialize(&mut params, "", &input);
+        DisableInsightRulesInputSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `enable_alarm_actions`, also returning the metadata of the response.
+    pub async fn enable_alarm_actions_with_metadata(
+        &self,
+        input: EnableAlarmActionsInput,
+    ) -> Result<((), ResponseMetadata), RusotoError<EnableAlarmActionsError>> {
+        let request = self.build_enable_alarm_actions_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(EnableAlarmActionsError::from_response(response));
         }
 
         std::mem::drop(response);
-        Ok(())
+        Ok(((), metadata))
     }
 
-    /// <p>Enables the specified Contributor Insights rules. When rules are enabled, they immediately begin analyzing log data.</p>
-    async fn enable_insight_rules(
+    /// Presigns the request `enable_alarm_actions` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_enable_alarm_actions(
         &self,
-        input: EnableInsightRulesInput,
-    ) -> Result<EnableInsightRulesOutput, RusotoError<EnableInsightRulesError>> {
+        presigner: &rusoto_core::Presigner,
+        input: EnableAlarmActionsInput,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_enable_alarm_actions_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_enable_alarm_actions_request(&self, input: EnableAlarmActionsInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "monitoring", &self.region, "/");
+        request.set_operation("EnableAlarmActions");
         let mut params = Params::new();
 
-        params.put("Action", "EnableInsightRules");
+        params.put("Action", "EnableAlarmActions");
         params.put("Version", "2010-08-01");
-        EnableInsightRulesInputSerializer::serialize(&mut params, "", &input);
+        EnableAlarmActionsInputSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `enable_insight_rules`, also returning the metadata of the response.
+    pub async fn enable_insight_rules_with_metadata(
+        &self,
+        input: EnableInsightRulesInput,
+    ) -> Result<(EnableInsightRulesOutput, ResponseMetadata), RusotoError<EnableInsightRulesError>>
+    {
+        let request = self.build_enable_insight_rules_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(EnableInsightRulesError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -6866,34 +7386,58 @@ impl CloudWatch for CloudWatchClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Displays the details of the dashboard that you specify.</p> <p>To copy an existing dashboard, use <code>GetDashboard</code>, and then use the data returned within <code>DashboardBody</code> as the template for the new dashboard when you call <code>PutDashboard</code> to create the copy.</p>
-    async fn get_dashboard(
+    /// Presigns the request `enable_insight_rules` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_enable_insight_rules(
         &self,
-        input: GetDashboardInput,
-    ) -> Result<GetDashboardOutput, RusotoError<GetDashboardError>> {
+        presigner: &rusoto_core::Presigner,
+        input: EnableInsightRulesInput,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_enable_insight_rules_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_enable_insight_rules_request(&self, input: EnableInsightRulesInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "monitoring", &self.region, "/");
+        request.set_operation("EnableInsightRules");
         let mut params = Params::new();
 
-        params.put("Action", "GetDashboard");
+        params.put("Action", "EnableInsightRules");
         params.put("Version", "2010-08-01");
-        GetDashboardInputSerializer::serialize(&mut params, "", &input);
+        EnableInsightRulesInputSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `get_dashboard`, also returning the metadata of the response.
+    pub async fn get_dashboard_with_metadata(
+        &self,
+        input: GetDashboardInput,
+    ) -> Result<(GetDashboardOutput, ResponseMetadata), RusotoError<GetDashboardError>> {
+        let request = self.build_get_dashboard_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(GetDashboardError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -6912,34 +7456,61 @@ impl CloudWatch for CloudWatchClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p><p>This operation returns the time series data collected by a Contributor Insights rule. The data includes the identity and number of contributors to the log group.</p> <p>You can also optionally return one or more statistics about each data point in the time series. These statistics can include the following:</p> <ul> <li> <p> <code>UniqueContributors</code> -- the number of unique contributors for each data point.</p> </li> <li> <p> <code>MaxContributorValue</code> -- the value of the top contributor for each data point. The identity of the contributor may change for each data point in the graph.</p> <p>If this rule aggregates by COUNT, the top contributor for each data point is the contributor with the most occurrences in that period. If the rule aggregates by SUM, the top contributor is the contributor with the highest sum in the log field specified by the rule&#39;s <code>Value</code>, during that period.</p> </li> <li> <p> <code>SampleCount</code> -- the number of data points matched by the rule.</p> </li> <li> <p> <code>Sum</code> -- the sum of the values from all contributors during the time period represented by that data point.</p> </li> <li> <p> <code>Minimum</code> -- the minimum value from a single observation during the time period represented by that data point.</p> </li> <li> <p> <code>Maximum</code> -- the maximum value from a single observation during the time period represented by that data point.</p> </li> <li> <p> <code>Average</code> -- the average value from all contributors during the time period represented by that data point.</p> </li> </ul></p>
-    async fn get_insight_rule_report(
+    /// Presigns the request `get_dashboard` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_dashboard(
         &self,
-        input: GetInsightRuleReportInput,
-    ) -> Result<GetInsightRuleReportOutput, RusotoError<GetInsightRuleReportError>> {
+        presigner: &rusoto_core::Presigner,
+        input: GetDashboardInput,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_dashboard_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_dashboard_request(&self, input: GetDashboardInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "monitoring", &self.region, "/");
+        request.set_operation("GetDashboard");
         let mut params = Params::new();
 
-        params.put("Action", "GetInsightRuleReport");
+        params.put("Action", "GetDashboard");
         params.put("Version", "2010-08-01");
-        GetInsightRuleReportInputSerializer::serialize(&mut params, "", &input);
+        GetDashboardInputSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `get_insight_rule_report`, also returning the metadata of the response.
+    pub async fn get_insight_rule_report_with_metadata(
+        &self,
+        input: GetInsightRuleReportInput,
+    ) -> Result<
+        (GetInsightRuleReportOutput, ResponseMetadata),
+        RusotoError<GetInsightRuleReportError>,
+    > {
+        let request = self.build_get_insight_rule_report_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(GetInsightRuleReportError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -6961,34 +7532,61 @@ impl CloudWatch for CloudWatchClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>You can use the <code>GetMetricData</code> API to retrieve as many as 100 different metrics in a single request, with a total of as many as 100,800 data points. You can also optionally perform math expressions on the values of the returned statistics, to create new time series that represent new insights into your data. For example, using Lambda metrics, you could divide the Errors metric by the Invocations metric to get an error rate time series. For more information about metric math expressions, see <a href="https://docs.aws.amazon.com/AmazonCloudWatch/latest/monitoring/using-metric-math.html#metric-math-syntax">Metric Math Syntax and Functions</a> in the <i>Amazon CloudWatch User Guide</i>.</p> <p>Calls to the <code>GetMetricData</code> API have a different pricing structure than calls to <code>GetMetricStatistics</code>. For more information about pricing, see <a href="https://aws.amazon.com/cloudwatch/pricing/">Amazon CloudWatch Pricing</a>.</p> <p>Amazon CloudWatch retains metric data as follows:</p> <ul> <li> <p>Data points with a period of less than 60 seconds are available for 3 hours. These data points are high-resolution metrics and are available only for custom metrics that have been defined with a <code>StorageResolution</code> of 1.</p> </li> <li> <p>Data points with a period of 60 seconds (1-minute) are available for 15 days.</p> </li> <li> <p>Data points with a period of 300 seconds (5-minute) are available for 63 days.</p> </li> <li> <p>Data points with a period of 3600 seconds (1 hour) are available for 455 days (15 months).</p> </li> </ul> <p>Data points that are initially published with a shorter period are aggregated together for long-term storage. For example, if you collect data using a period of 1 minute, the data remains available for 15 days with 1-minute resolution. After 15 days, this data is still available, but is aggregated and retrievable only with a resolution of 5 minutes. After 63 days, the data is further aggregated and is available with a resolution of 1 hour.</p> <p>If you omit <code>Unit</code> in your request, all data that was collected with any unit is returned, along with the corresponding units that were specified when the data was reported to CloudWatch. If you specify a unit, the operation returns only data data that was collected with that unit specified. If you specify a unit that does not match the data collected, the results of the operation are null. CloudWatch does not perform unit conversions.</p>
-    async fn get_metric_data(
+    /// Presigns the request `get_insight_rule_report` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_insight_rule_report(
         &self,
-        input: GetMetricDataInput,
-    ) -> Result<GetMetricDataOutput, RusotoError<GetMetricDataError>> {
+        presigner: &rusoto_core::Presigner,
+        input: GetInsightRuleReportInput,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_insight_rule_report_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_insight_rule_report_request(
+        &self,
+        input: GetInsightRuleReportInput,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "monitoring", &self.region, "/");
+        request.set_operation("GetInsightRuleReport");
         let mut params = Params::new();
 
-        params.put("Action", "GetMetricData");
+        params.put("Action", "GetInsightRuleReport");
         params.put("Version", "2010-08-01");
-        GetMetricDataInputSerializer::serialize(&mut params, "", &input);
+        GetInsightRuleReportInputSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `get_metric_data`, also returning the metadata of the response.
+    pub async fn get_metric_data_with_metadata(
+        &self,
+        input: GetMetricDataInput,
+    ) -> Result<(GetMetricDataOutput, ResponseMetadata), RusotoError<GetMetricDataError>> {
+        let request = self.build_get_metric_data_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(GetMetricDataError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -7008,34 +7606,59 @@ impl CloudWatch for CloudWatchClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Gets statistics for the specified metric.</p> <p>The maximum number of data points returned from a single call is 1,440. If you request more than 1,440 data points, CloudWatch returns an error. To reduce the number of data points, you can narrow the specified time range and make multiple requests across adjacent time ranges, or you can increase the specified period. Data points are not returned in chronological order.</p> <p>CloudWatch aggregates data points based on the length of the period that you specify. For example, if you request statistics with a one-hour period, CloudWatch aggregates all data points with time stamps that fall within each one-hour period. Therefore, the number of values aggregated by CloudWatch is larger than the number of data points returned.</p> <p>CloudWatch needs raw data points to calculate percentile statistics. If you publish data using a statistic set instead, you can only retrieve percentile statistics for this data if one of the following conditions is true:</p> <ul> <li> <p>The SampleCount value of the statistic set is 1.</p> </li> <li> <p>The Min and the Max values of the statistic set are equal.</p> </li> </ul> <p>Percentile statistics are not available for metrics when any of the metric values are negative numbers.</p> <p>Amazon CloudWatch retains metric data as follows:</p> <ul> <li> <p>Data points with a period of less than 60 seconds are available for 3 hours. These data points are high-resolution metrics and are available only for custom metrics that have been defined with a <code>StorageResolution</code> of 1.</p> </li> <li> <p>Data points with a period of 60 seconds (1-minute) are available for 15 days.</p> </li> <li> <p>Data points with a period of 300 seconds (5-minute) are available for 63 days.</p> </li> <li> <p>Data points with a period of 3600 seconds (1 hour) are available for 455 days (15 months).</p> </li> </ul> <p>Data points that are initially published with a shorter period are aggregated together for long-term storage. For example, if you collect data using a period of 1 minute, the data remains available for 15 days with 1-minute resolution. After 15 days, this data is still available, but is aggregated and retrievable only with a resolution of 5 minutes. After 63 days, the data is further aggregated and is available with a resolution of 1 hour.</p> <p>CloudWatch started retaining 5-minute and 1-hour metric data as of July 9, 2016.</p> <p>For information about metrics and dimensions supported by AWS services, see the <a href="https://docs.aws.amazon.com/AmazonCloudWatch/latest/monitoring/CW_Support_For_AWS.html">Amazon CloudWatch Metrics and Dimensions Reference</a> in the <i>Amazon CloudWatch User Guide</i>.</p>
-    async fn get_metric_statistics(
+    /// Presigns the request `get_metric_data` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_metric_data(
         &self,
-        input: GetMetricStatisticsInput,
-    ) -> Result<GetMetricStatisticsOutput, RusotoError<GetMetricStatisticsError>> {
+        presigner: &rusoto_core::Presigner,
+        input: GetMetricDataInput,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_metric_data_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_metric_data_request(&self, input: GetMetricDataInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "monitoring", &self.region, "/");
+        request.set_operation("GetMetricData");
         let mut params = Params::new();
 
-        params.put("Action", "GetMetricStatistics");
+        params.put("Action", "GetMetricData");
         params.put("Version", "2010-08-01");
-        GetMetricStatisticsInputSerializer::serialize(&mut params, "", &input);
+        GetMetricDataInputSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `get_metric_statistics`, also returning the metadata of the response.
+    pub async fn get_metric_statistics_with_metadata(
+        &self,
+        input: GetMetricStatisticsInput,
+    ) -> Result<(GetMetricStatisticsOutput, ResponseMetadata), RusotoError<GetMetricStatisticsError>>
+    {
+        let request = self.build_get_metric_statistics_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(GetMetricStatisticsError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -7057,34 +7680,64 @@ impl CloudWatch for CloudWatchClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p><p>You can use the <code>GetMetricWidgetImage</code> API to retrieve a snapshot graph of one or more Amazon CloudWatch metrics as a bitmap image. You can then embed this image into your services and products, such as wiki pages, reports, and documents. You could also retrieve images regularly, such as every minute, and create your own custom live dashboard.</p> <p>The graph you retrieve can include all CloudWatch metric graph features, including metric math and horizontal and vertical annotations.</p> <p>There is a limit of 20 transactions per second for this API. Each <code>GetMetricWidgetImage</code> action has the following limits:</p> <ul> <li> <p>As many as 100 metrics in the graph.</p> </li> <li> <p>Up to 100 KB uncompressed payload.</p> </li> </ul></p>
-    async fn get_metric_widget_image(
+    /// Presigns the request `get_metric_statistics` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_metric_statistics(
         &self,
-        input: GetMetricWidgetImageInput,
-    ) -> Result<GetMetricWidgetImageOutput, RusotoError<GetMetricWidgetImageError>> {
+        presigner: &rusoto_core::Presigner,
+        input: GetMetricStatisticsInput,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_metric_statistics_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_metric_statistics_request(
+        &self,
+        input: GetMetricStatisticsInput,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "monitoring", &self.region, "/");
+        request.set_operation("GetMetricStatistics");
         let mut params = Params::new();
 
-        params.put("Action", "GetMetricWidgetImage");
+        params.put("Action", "GetMetricStatistics");
         params.put("Version", "2010-08-01");
-        GetMetricWidgetImageInputSerializer::serialize(&mut params, "", &input);
+        GetMetricStatisticsInputSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `get_metric_widget_image`, also returning the metadata of the response.
+    pub async fn get_metric_widget_image_with_metadata(
+        &self,
+        input: GetMetricWidgetImageInput,
+    ) -> Result<
+        (GetMetricWidgetImageOutput, ResponseMetadata),
+        RusotoError<GetMetricWidgetImageError>,
+    > {
+        let request = self.build_get_metric_widget_image_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(GetMetricWidgetImageError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -7106,34 +7759,61 @@ impl CloudWatch for CloudWatchClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Returns a list of the dashboards for your account. If you include <code>DashboardNamePrefix</code>, only those dashboards with names starting with the prefix are listed. Otherwise, all dashboards in your account are listed. </p> <p> <code>ListDashboards</code> returns up to 1000 results on one page. If there are more than 1000 dashboards, you can call <code>ListDashboards</code> again and include the value you received for <code>NextToken</code> in the first call, to receive the next 1000 results.</p>
-    async fn list_dashboards(
+    /// Presigns the request `get_metric_widget_image` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_metric_widget_image(
         &self,
-        input: ListDashboardsInput,
-    ) -> Result<ListDashboardsOutput, RusotoError<ListDashboardsError>> {
+        presigner: &rusoto_core::Presigner,
+        input: GetMetricWidgetImageInput,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_metric_widget_image_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_metric_widget_image_request(
+        &self,
+        input: GetMetricWidgetImageInput,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "monitoring", &self.region, "/");
+        request.set_operation("GetMetricWidgetImage");
         let mut params = Params::new();
 
-        params.put("Action", "ListDashboards");
+        params.put("Action", "GetMetricWidgetImage");
         params.put("Version", "2010-08-01");
-        ListDashboardsInputSerializer::serialize(&mut params, "", &input);
+        GetMetricWidgetImageInputSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `list_dashboards`, also returning the metadata of the response.
+    pub async fn list_dashboards_with_metadata(
+        &self,
+        input: ListDashboardsInput,
+    ) -> Result<(ListDashboardsOutput, ResponseMetadata), RusotoError<ListDashboardsError>> {
+        let request = self.build_list_dashboards_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(ListDashboardsError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -7153,34 +7833,58 @@ impl CloudWatch for CloudWatchClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>List the specified metrics. You can use the returned metrics with <a>GetMetricData</a> or <a>GetMetricStatistics</a> to obtain statistical data.</p> <p>Up to 500 results are returned for any one call. To retrieve additional results, use the returned token with subsequent calls.</p> <p>After you create a metric, allow up to fifteen minutes before the metric appears. Statistics about the metric, however, are available sooner using <a>GetMetricData</a> or <a>GetMetricStatistics</a>.</p>
-    async fn list_metrics(
+    /// Presigns the request `list_dashboards` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_dashboards(
         &self,
-        input: ListMetricsInput,
-    ) -> Result<ListMetricsOutput, RusotoError<ListMetricsError>> {
+        presigner: &rusoto_core::Presigner,
+        input: ListDashboardsInput,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_dashboards_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_dashboards_request(&self, input: ListDashboardsInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "monitoring", &self.region, "/");
+        request.set_operation("ListDashboards");
         let mut params = Params::new();
 
-        params.put("Action", "ListMetrics");
+        params.put("Action", "ListDashboards");
         params.put("Version", "2010-08-01");
-        ListMetricsInputSerializer::serialize(&mut params, "", &input);
+        ListDashboardsInputSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `list_metrics`, also returning the metadata of the response.
+    pub async fn list_metrics_with_metadata(
+        &self,
+        input: ListMetricsInput,
+    ) -> Result<(ListMetricsOutput, ResponseMetadata), RusotoError<ListMetricsError>> {
+        let request = self.build_list_metrics_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(ListMetricsError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -7199,34 +7903,59 @@ impl CloudWatch for CloudWatchClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Displays the tags associated with a CloudWatch resource. Alarms support tagging.</p>
-    async fn list_tags_for_resource(
+    /// Presigns the request `list_metrics` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_metrics(
         &self,
-        input: ListTagsForResourceInput,
-    ) -> Result<ListTagsForResourceOutput, RusotoError<ListTagsForResourceError>> {
+        presigner: &rusoto_core::Presigner,
+        input: ListMetricsInput,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_metrics_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_metrics_request(&self, input: ListMetricsInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "monitoring", &self.region, "/");
+        request.set_operation("ListMetrics");
         let mut params = Params::new();
 
-        params.put("Action", "ListTagsForResource");
+        params.put("Action", "ListMetrics");
         params.put("Version", "2010-08-01");
-        ListTagsForResourceInputSerializer::serialize(&mut params, "", &input);
+        ListMetricsInputSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `list_tags_for_resource`, also returning the metadata of the response.
+    pub async fn list_tags_for_resource_with_metadata(
+        &self,
+        input: ListTagsForResourceInput,
+    ) -> Result<(ListTagsForResourceOutput, ResponseMetadata), RusotoError<ListTagsForResourceError>>
+    {
+        let request = self.build_list_tags_for_resource_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(ListTagsForResourceError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -7248,34 +7977,62 @@ impl CloudWatch for CloudWatchClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Creates an anomaly detection model for a CloudWatch metric. You can use the model to display a band of expected normal values when the metric is graphed.</p> <p>For more information, see <a href="https://docs.aws.amazon.com/AmazonCloudWatch/latest/monitoring/CloudWatch_Anomaly_Detection.html">CloudWatch Anomaly Detection</a>.</p>
-    async fn put_anomaly_detector(
+    /// Presigns the request `list_tags_for_resource` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_tags_for_resource(
         &self,
-        input: PutAnomalyDetectorInput,
-    ) -> Result<PutAnomalyDetectorOutput, RusotoError<PutAnomalyDetectorError>> {
+        presigner: &rusoto_core::Presigner,
+        input: ListTagsForResourceInput,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_tags_for_resource_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_tags_for_resource_request(
+        &self,
+        input: ListTagsForResourceInput,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "monitoring", &self.region, "/");
+        request.set_operation("ListTagsForResource");
         let mut params = Params::new();
 
-        params.put("Action", "PutAnomalyDetector");
+        params.put("Action", "ListTagsForResource");
         params.put("Version", "2010-08-01");
-        PutAnomalyDetectorInputSerializer::serialize(&mut params, "", &input);
+        ListTagsForResourceInputSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `put_anomaly_detector`, also returning the metadata of the response.
+    pub async fn put_anomaly_detector_with_metadata(
+        &self,
+        input: PutAnomalyDetectorInput,
+    ) -> Result<(PutAnomalyDetectorOutput, ResponseMetadata), RusotoError<PutAnomalyDetectorError>>
+    {
+        let request = self.build_put_anomaly_detector_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(PutAnomalyDetectorError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -7297,34 +8054,58 @@ impl CloudWatch for CloudWatchClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Creates a dashboard if it does not already exist, or updates an existing dashboard. If you update a dashboard, the entire contents are replaced with what you specify here.</p> <p>All dashboards in your account are global, not region-specific.</p> <p>A simple way to create a dashboard using <code>PutDashboard</code> is to copy an existing dashboard. To copy an existing dashboard using the console, you can load the dashboard and then use the View/edit source command in the Actions menu to display the JSON block for that dashboard. Another way to copy a dashboard is to use <code>GetDashboard</code>, and then use the data returned within <code>DashboardBody</code> as the template for the new dashboard when you call <code>PutDashboard</code>.</p> <p>When you create a dashboard with <code>PutDashboard</code>, a good practice is to add a text widget at the top of the dashboard with a message that the dashboard was created by script and should not be changed in the console. This message could also point console users to the location of the <code>DashboardBody</code> script or the CloudFormation template used to create the dashboard.</p>
-    async fn put_dashboard(
+    /// Presigns the request `put_anomaly_detector` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_put_anomaly_detector(
         &self,
-        input: PutDashboardInput,
-    ) -> Result<PutDashboardOutput, RusotoError<PutDashboardError>> {
+        presigner: &rusoto_core::Presigner,
+        input: PutAnomalyDetectorInput,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_put_anomaly_detector_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_put_anomaly_detector_request(&self, input: PutAnomalyDetectorInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "monitoring", &self.region, "/");
+        request.set_operation("PutAnomalyDetector");
         let mut params = Params::new();
 
-        params.put("Action", "PutDashboard");
+        params.put("Action", "PutAnomalyDetector");
         params.put("Version", "2010-08-01");
-        PutDashboardInputSerializer::serialize(&mut params, "", &input);
+        PutAnomalyDetectorInputSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `put_dashboard`, also returning the metadata of the response.
+    pub async fn put_dashboard_with_metadata(
+        &self,
+        input: PutDashboardInput,
+    ) -> Result<(PutDashboardOutput, ResponseMetadata), RusotoError<PutDashboardError>> {
+        let request = self.build_put_dashboard_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(PutDashboardError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -7343,34 +8124,58 @@ impl CloudWatch for CloudWatchClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Creates a Contributor Insights rule. Rules evaluate log events in a CloudWatch Logs log group, enabling you to find contributor data for the log events in that log group. For more information, see <a href="https://docs.aws.amazon.com/AmazonCloudWatch/latest/monitoring/ContributorInsights.html">Using Contributor Insights to Analyze High-Cardinality Data</a>.</p> <p>If you create a rule, delete it, and then re-create it with the same name, historical data from the first time the rule was created may or may not be available.</p>
-    async fn put_insight_rule(
+    /// Presigns the request `put_dashboard` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_put_dashboard(
         &self,
-        input: PutInsightRuleInput,
-    ) -> Result<PutInsightRuleOutput, RusotoError<PutInsightRuleError>> {
+        presigner: &rusoto_core::Presigner,
+        input: PutDashboardInput,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_put_dashboard_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_put_dashboard_request(&self, input: PutDashboardInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "monitoring", &self.region, "/");
+        request.set_operation("PutDashboard");
         let mut params = Params::new();
 
-        params.put("Action", "PutInsightRule");
+        params.put("Action", "PutDashboard");
         params.put("Version", "2010-08-01");
-        PutInsightRuleInputSerializer::serialize(&mut params, "", &input);
+        PutDashboardInputSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `put_insight_rule`, also returning the metadata of the response.
+    pub async fn put_insight_rule_with_metadata(
+        &self,
+        input: PutInsightRuleInput,
+    ) -> Result<(PutInsightRuleOutput, ResponseMetadata), RusotoError<PutInsightRuleError>> {
+        let request = self.build_put_insight_rule_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(PutInsightRuleError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -7390,118 +8195,205 @@ impl CloudWatch for CloudWatchClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Creates or updates an alarm and associates it with the specified metric, metric math expression, or anomaly detection model.</p> <p>Alarms based on anomaly detection models cannot have Auto Scaling actions.</p> <p>When this operation creates an alarm, the alarm state is immediately set to <code>INSUFFICIENT_DATA</code>. The alarm is then evaluated and its state is set appropriately. Any actions associated with the new state are then executed.</p> <p>When you update an existing alarm, its state is left unchanged, but the update completely overwrites the previous configuration of the alarm.</p> <p>If you are an IAM user, you must have Amazon EC2 permissions for some alarm operations:</p> <ul> <li> <p> <code>iam:CreateServiceLinkedRole</code> for all alarms with EC2 actions</p> </li> <li> <p> <code>ec2:DescribeInstanceStatus</code> and <code>ec2:DescribeInstances</code> for all alarms on EC2 instance status metrics</p> </li> <li> <p> <code>ec2:StopInstances</code> for alarms with stop actions</p> </li> <li> <p> <code>ec2:TerminateInstances</code> for alarms with terminate actions</p> </li> <li> <p>No specific permissions are needed for alarms with recover actions</p> </li> </ul> <p>If you have read/write permissions for Amazon CloudWatch but not for Amazon EC2, you can still create an alarm, but the stop or terminate actions are not performed. However, if you are later granted the required permissions, the alarm actions that you created earlier are performed.</p> <p>If you are using an IAM role (for example, an EC2 instance profile), you cannot stop or terminate the instance using alarm actions. However, you can still see the alarm state and perform any other actions such as Amazon SNS notifications or Auto Scaling policies.</p> <p>If you are using temporary security credentials granted using AWS STS, you cannot stop or terminate an EC2 instance using alarm actions.</p> <p>The first time you create an alarm in the AWS Management Console, the CLI, or by using the PutMetricAlarm API, CloudWatch creates the necessary service-linked role for you. The service-linked role is called <code>AWSServiceRoleForCloudWatchEvents</code>. For more information, see <a href="https://docs.aws.amazon.com/IAM/latest/UserGuide/id_roles_terms-and-concepts.html#iam-term-service-linked-role">AWS service-linked role</a>.</p>
-    async fn put_metric_alarm(
+    /// Presigns the request `put_insight_rule` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_put_insight_rule(
         &self,
-        input: PutMetricAlarmInput,
-    ) -> Result<(), RusotoError<PutMetricAlarmError>> {
+        presigner: &rusoto_core::Presigner,
+        input: PutInsightRuleInput,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_put_insight_rule_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_put_insight_rule_request(&self, input: PutInsightRuleInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "monitoring", &self.region, "/");
+        request.set_operation("PutInsightRule");
         let mut params = Params::new();
 
-        params.put("Action", "PutMetricAlarm");
+        params.put("Action", "PutInsightRule");
         params.put("Version", "2010-08-01");
-        PutMetricAlarmInputSerializer::serialize(&mut params, "", &input);
+        PutInsightRuleInputSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `put_metric_alarm`, also returning the metadata of the response.
+    pub async fn put_metric_alarm_with_metadata(
+        &self,
+        input: PutMetricAlarmInput,
+    ) -> Result<((), ResponseMetadata), RusotoError<PutMetricAlarmError>> {
+        let request = self.build_put_metric_alarm_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(PutMetricAlarmError::from_response(response));
         }
 
         std::mem::drop(response);
-        Ok(())
+        Ok(((), metadata))
     }
 
-    /// <p><p>Publishes metric data points to Amazon CloudWatch. CloudWatch associates the data points with the specified metric. If the specified metric does not exist, CloudWatch creates the metric. When CloudWatch creates a metric, it can take up to fifteen minutes for the metric to appear in calls to <a>ListMetrics</a>.</p> <p>You can publish either individual data points in the <code>Value</code> field, or arrays of values and the number of times each value occurred during the period by using the <code>Values</code> and <code>Counts</code> fields in the <code>MetricDatum</code> structure. Using the <code>Values</code> and <code>Counts</code> method enables you to publish up to 150 values per metric with one <code>PutMetricData</code> request, and supports retrieving percentile statistics on this data.</p> <p>Each <code>PutMetricData</code> request is limited to 40 KB in size for HTTP POST requests. You can send a payload compressed by gzip. Each request is also limited to no more than 20 different metrics.</p> <p>Although the <code>Value</code> parameter accepts numbers of type <code>Double</code>, CloudWatch rejects values that are either too small or too large. Values must be in the range of -2^360 to 2^360. In addition, special values (for example, NaN, +Infinity, -Infinity) are not supported.</p> <p>You can use up to 10 dimensions per metric to further clarify what data the metric collects. Each dimension consists of a Name and Value pair. For more information about specifying dimensions, see <a href="https://docs.aws.amazon.com/AmazonCloudWatch/latest/monitoring/publishingMetrics.html">Publishing Metrics</a> in the <i>Amazon CloudWatch User Guide</i>.</p> <p>Data points with time stamps from 24 hours ago or longer can take at least 48 hours to become available for <a>GetMetricData</a> or <a>GetMetricStatistics</a> from the time they are submitted.</p> <p>CloudWatch needs raw data points to calculate percentile statistics. If you publish data using a statistic set instead, you can only retrieve percentile statistics for this data if one of the following conditions is true:</p> <ul> <li> <p>The <code>SampleCount</code> value of the statistic set is 1 and <code>Min</code>, <code>Max</code>, and <code>Sum</code> are all equal.</p> </li> <li> <p>The <code>Min</code> and <code>Max</code> are equal, and <code>Sum</code> is equal to <code>Min</code> multiplied by <code>SampleCount</code>.</p> </li> </ul></p>
-    async fn put_metric_data(
+    /// Presigns the request `put_metric_alarm` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_put_metric_alarm(
         &self,
-        input: PutMetricDataInput,
-    ) -> Result<(), RusotoError<PutMetricDataError>> {
+        presigner: &rusoto_core::Presigner,
+        input: PutMetricAlarmInput,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_put_metric_alarm_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_put_metric_alarm_request(&self, input: PutMetricAlarmInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "monitoring", &self.region, "/");
+        request.set_operation("PutMetricAlarm");
         let mut params = Params::new();
 
-        params.put("Action", "PutMetricData");
+        params.put("Action", "PutMetricAlarm");
         params.put("Version", "2010-08-01");
-        PutMetricDataInputSerializer::serialize(&mut params, "", &input);
+        PutMetricAlarmInputSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `put_metric_data`, also returning the metadata of the response.
+    pub async fn put_metric_data_with_metadata(
+        &self,
+        input: PutMetricDataInput,
+    ) -> Result<((), ResponseMetadata), RusotoError<PutMetricDataError>> {
+        let request = self.build_put_metric_data_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(PutMetricDataError::from_response(response));
         }
 
         std::mem::drop(response);
-        Ok(())
+        Ok(((), metadata))
     }
 
-    /// <p>Temporarily sets the state of an alarm for testing purposes. When the updated state differs from the previous value, the action configured for the appropriate state is invoked. For example, if your alarm is configured to send an Amazon SNS message when an alarm is triggered, temporarily changing the alarm state to <code>ALARM</code> sends an SNS message. The alarm returns to its actual state (often within seconds). Because the alarm state change happens quickly, it is typically only visible in the alarm's <b>History</b> tab in the Amazon CloudWatch console or through <a>DescribeAlarmHistory</a>.</p>
-    async fn set_alarm_state(
+    /// Presigns the request `put_metric_data` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_put_metric_data(
         &self,
-        input: SetAlarmStateInput,
-    ) -> Result<(), RusotoError<SetAlarmStateError>> {
+        presigner: &rusoto_core::Presigner,
+        input: PutMetricDataInput,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_put_metric_data_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_put_metric_data_request(&self, input: PutMetricDataInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "monitoring", &self.region, "/");
+        request.set_operation("PutMetricData");
         let mut params = Params::new();
 
-        params.put("Action", "SetAlarmState");
+        params.put("Action", "PutMetricData");
         params.put("Version", "2010-08-01");
-        SetAlarmStateInputSerializer::serialize(&mut params, "", &input);
+        PutMetricDataInputSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `set_alarm_state`, also returning the metadata of the response.
+    pub async fn set_alarm_state_with_metadata(
+        &self,
+        input: SetAlarmStateInput,
+    ) -> Result<((), ResponseMetadata), RusotoError<SetAlarmStateError>> {
+        let request = self.build_set_alarm_state_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(SetAlarmStateError::from_response(response));
         }
 
         std::mem::drop(response);
-        Ok(())
+        Ok(((), metadata))
     }
 
-    /// <p>Assigns one or more tags (key-value pairs) to the specified CloudWatch resource. Currently, the only CloudWatch resources that can be tagged are alarms.</p> <p>Tags can help you organize and categorize your resources. You can also use them to scope user permissions, by granting a user permission to access or change only resources with certain tag values.</p> <p>Tags don't have any semantic meaning to AWS and are interpreted strictly as strings of characters.</p> <p>You can use the <code>TagResource</code> action with an alarm that already has tags. If you specify a new tag key for the alarm, this tag is appended to the list of tags associated with the alarm. If you specify a tag key that is already associated with the alarm, the new tag value that you specify replaces the previous value for that tag.</p> <p>You can associate as many as 50 tags with a resource.</p>
-    async fn tag_resource(
+    /// Presigns the request `set_alarm_state` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_set_alarm_state(
         &self,
-        input: TagResourceInput,
-    ) -> Result<TagResourceOutput, RusotoError<TagResourceError>> {
+        presigner: &rusoto_core::Presigner,
+        input: SetAlarmStateInput,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_set_alarm_state_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_set_alarm_state_request(&self, input: SetAlarmStateInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "monitoring", &self.region, "/");
+        request.set_operation("SetAlarmState");
         let mut params = Params::new();
 
-        params.put("Action", "TagResource");
+        params.put("Action", "SetAlarmState");
         params.put("Version", "2010-08-01");
-        TagResourceInputSerializer::serialize(&mut params, "", &input);
+        SetAlarmStateInputSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `tag_resource`, also returning the metadata of the response.
+    pub async fn tag_resource_with_metadata(
+        &self,
+        input: TagResourceInput,
+    ) -> Result<(TagResourceOutput, ResponseMetadata), RusotoError<TagResourceError>> {
+        let request = self.build_tag_resource_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(TagResourceError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -7520,34 +8412,58 @@ impl CloudWatch for CloudWatchClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Removes one or more tags from the specified resource.</p>
-    async fn untag_resource(
+    /// Presigns the request `tag_resource` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_tag_resource(
         &self,
-        input: UntagResourceInput,
-    ) -> Result<UntagResourceOutput, RusotoError<UntagResourceError>> {
+        presigner: &rusoto_core::Presigner,
+        input: TagResourceInput,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_tag_resource_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_tag_resource_request(&self, input: TagResourceInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "monitoring", &self.region, "/");
+        request.set_operation("TagResource");
         let mut params = Params::new();
 
-        params.put("Action", "UntagResource");
+        params.put("Action", "TagResource");
         params.put("Version", "2010-08-01");
-        UntagResourceInputSerializer::serialize(&mut params, "", &input);
+        TagResourceInputSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `untag_resource`, also returning the metadata of the response.
+    pub async fn untag_resource_with_metadata(
+        &self,
+        input: UntagResourceInput,
+    ) -> Result<(UntagResourceOutput, ResponseMetadata), RusotoError<UntagResourceError>> {
+        let request = self.build_untag_resource_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(UntagResourceError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -7567,7 +8483,510 @@ impl CloudWatch for CloudWatchClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
+    }
+
+    /// Presigns the request `untag_resource` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_untag_resource(
+        &self,
+        presigner: &rusoto_core::Presigner,
+        input: UntagResourceInput,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_untag_resource_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_untag_resource_request(&self, input: UntagResourceInput) -> SignedRequest {
+        let mut request = SignedRequest::new("POST", "monitoring", &self.region, "/");
+        request.set_operation("UntagResource");
+        let mut params = Params::new();
+
+        params.put("Action", "UntagResource");
+        params.put("Version", "2010-08-01");
+        UntagResourceInputSerializer::serialize(&mut params, "", &input);
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        request
+    }
+
+    /// The pages of `DescribeAlarmHistory` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `MaxRecords` of `input`.
+    pub fn describe_alarm_history_pages(
+        &self,
+        input: DescribeAlarmHistoryInput,
+    ) -> impl futures::Stream<
+        Item = Result<DescribeAlarmHistoryOutput, RusotoError<DescribeAlarmHistoryError>>,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: CloudWatchClient, input| async move { client.describe_alarm_history(input).await },
+            |input: &DescribeAlarmHistoryInput, output: &DescribeAlarmHistoryOutput| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(DescribeAlarmHistoryInput {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `AlarmHistoryItems` of every page of `DescribeAlarmHistory` results for `input`, as `describe_alarm_history_pages` requests them.
+    pub fn describe_alarm_history_stream(
+        &self,
+        input: DescribeAlarmHistoryInput,
+    ) -> impl futures::Stream<Item = Result<AlarmHistoryItem, RusotoError<DescribeAlarmHistoryError>>>
+           + Send {
+        rusoto_core::pagination::items(self.describe_alarm_history_pages(input), |page| {
+            page.alarm_history_items.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `DescribeAlarms` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `MaxRecords` of `input`.
+    pub fn describe_alarms_pages(
+        &self,
+        input: DescribeAlarmsInput,
+    ) -> impl futures::Stream<Item = Result<DescribeAlarmsOutput, RusotoError<DescribeAlarmsError>>> + Send
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: CloudWatchClient, input| async move { client.describe_alarms(input).await },
+            |input: &DescribeAlarmsInput, output: &DescribeAlarmsOutput| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(DescribeAlarmsInput {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The pages of `DescribeAnomalyDetectors` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `MaxResults` of `input`.
+    pub fn describe_anomaly_detectors_pages(
+        &self,
+        input: DescribeAnomalyDetectorsInput,
+    ) -> impl futures::Stream<
+        Item = Result<DescribeAnomalyDetectorsOutput, RusotoError<DescribeAnomalyDetectorsError>>,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: CloudWatchClient, input| async move {
+                client.describe_anomaly_detectors(input).await
+            },
+            |input: &DescribeAnomalyDetectorsInput, output: &DescribeAnomalyDetectorsOutput| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(DescribeAnomalyDetectorsInput {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `AnomalyDetectors` of every page of `DescribeAnomalyDetectors` results for `input`, as `describe_anomaly_detectors_pages` requests them.
+    pub fn describe_anomaly_detectors_stream(
+        &self,
+        input: DescribeAnomalyDetectorsInput,
+    ) -> impl futures::Stream<
+        Item = Result<AnomalyDetector, RusotoError<DescribeAnomalyDetectorsError>>,
+    > + Send {
+        rusoto_core::pagination::items(self.describe_anomaly_detectors_pages(input), |page| {
+            page.anomaly_detectors.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `GetMetricData` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `MaxDatapoints` of `input`.
+    pub fn get_metric_data_pages(
+        &self,
+        input: GetMetricDataInput,
+    ) -> impl futures::Stream<Item = Result<GetMetricDataOutput, RusotoError<GetMetricDataError>>> + Send
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: CloudWatchClient, input| async move { client.get_metric_data(input).await },
+            |input: &GetMetricDataInput, output: &GetMetricDataOutput| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(GetMetricDataInput {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The pages of `ListDashboards` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    pub fn list_dashboards_pages(
+        &self,
+        input: ListDashboardsInput,
+    ) -> impl futures::Stream<Item = Result<ListDashboardsOutput, RusotoError<ListDashboardsError>>> + Send
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: CloudWatchClient, input| async move { client.list_dashboards(input).await },
+            |input: &ListDashboardsInput, output: &ListDashboardsOutput| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListDashboardsInput {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `DashboardEntries` of every page of `ListDashboards` results for `input`, as `list_dashboards_pages` requests them.
+    pub fn list_dashboards_stream(
+        &self,
+        input: ListDashboardsInput,
+    ) -> impl futures::Stream<Item = Result<DashboardEntry, RusotoError<ListDashboardsError>>> + Send
+    {
+        rusoto_core::pagination::items(self.list_dashboards_pages(input), |page| {
+            page.dashboard_entries.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `ListMetrics` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    pub fn list_metrics_pages(
+        &self,
+        input: ListMetricsInput,
+    ) -> impl futures::Stream<Item = Result<ListMetricsOutput, RusotoError<ListMetricsError>>> + Send
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: CloudWatchClient, input| async move { client.list_metrics(input).await },
+            |input: &ListMetricsInput, output: &ListMetricsOutput| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListMetricsInput {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+}
+
+#[async_trait]
+impl CloudWatch for CloudWatchClient {
+    /// <p>Deletes the specified alarms. You can delete up to 50 alarms in one operation. In the event of an error, no alarms are deleted.</p>
+    async fn delete_alarms(
+        &self,
+        input: DeleteAlarmsInput,
+    ) -> Result<(), RusotoError<DeleteAlarmsError>> {
+        self.delete_alarms_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes the specified anomaly detection model from your account.</p>
+    async fn delete_anomaly_detector(
+        &self,
+        input: DeleteAnomalyDetectorInput,
+    ) -> Result<DeleteAnomalyDetectorOutput, RusotoError<DeleteAnomalyDetectorError>> {
+        self.delete_anomaly_detector_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes all dashboards that you specify. You may specify up to 100 dashboards to delete. If there is an error during this call, no dashboards are deleted.</p>
+    async fn delete_dashboards(
+        &self,
+        input: DeleteDashboardsInput,
+    ) -> Result<DeleteDashboardsOutput, RusotoError<DeleteDashboardsError>> {
+        self.delete_dashboards_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Permanently deletes the specified Contributor Insights rules.</p> <p>If you create a rule, delete it, and then re-create it with the same name, historical data from the first time the rule was created may or may not be available.</p>
+    async fn delete_insight_rules(
+        &self,
+        input: DeleteInsightRulesInput,
+    ) -> Result<DeleteInsightRulesOutput, RusotoError<DeleteInsightRulesError>> {
+        self.delete_insight_rules_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Retrieves the history for the specified alarm. You can filter the results by date range or item type. If an alarm name is not specified, the histories for all alarms are returned.</p> <p>CloudWatch retains the history of an alarm even if you delete the alarm.</p>
+    async fn describe_alarm_history(
+        &self,
+        input: DescribeAlarmHistoryInput,
+    ) -> Result<DescribeAlarmHistoryOutput, RusotoError<DescribeAlarmHistoryError>> {
+        self.describe_alarm_history_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Retrieves the specified alarms. If no alarms are specified, all alarms are returned. Alarms can be retrieved by using only a prefix for the alarm name, the alarm state, or a prefix for any action.</p>
+    async fn describe_alarms(
+        &self,
+        input: DescribeAlarmsInput,
+    ) -> Result<DescribeAlarmsOutput, RusotoError<DescribeAlarmsError>> {
+        self.describe_alarms_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Retrieves the alarms for the specified metric. To filter the results, specify a statistic, period, or unit.</p>
+    async fn describe_alarms_for_metric(
+        &self,
+        input: DescribeAlarmsForMetricInput,
+    ) -> Result<DescribeAlarmsForMetricOutput, RusotoError<DescribeAlarmsForMetricError>> {
+        self.describe_alarms_for_metric_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Lists the anomaly detection models that you have created in your account. You can list all models in your account or filter the results to only the models that are related to a certain namespace, metric name, or metric dimension.</p>
+    async fn describe_anomaly_detectors(
+        &self,
+        input: DescribeAnomalyDetectorsInput,
+    ) -> Result<DescribeAnomalyDetectorsOutput, RusotoError<DescribeAnomalyDetectorsError>> {
+        self.describe_anomaly_detectors_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns a list of all the Contributor Insights rules in your account. All rules in your account are returned with a single operation.</p> <p>For more information about Contributor Insights, see <a href="https://docs.aws.amazon.com/AmazonCloudWatch/latest/monitoring/ContributorInsights.html">Using Contributor Insights to Analyze High-Cardinality Data</a>.</p>
+    async fn describe_insight_rules(
+        &self,
+        input: DescribeInsightRulesInput,
+    ) -> Result<DescribeInsightRulesOutput, RusotoError<DescribeInsightRulesError>> {
+        self.describe_insight_rules_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Disables the actions for the specified alarms. When an alarm's actions are disabled, the alarm actions do not execute when the alarm state changes.</p>
+    async fn disable_alarm_actions(
+        &self,
+        input: DisableAlarmActionsInput,
+    ) -> Result<(), RusotoError<DisableAlarmActionsError>> {
+        self.disable_alarm_actions_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Disables the specified Contributor Insights rules. When rules are disabled, they do not analyze log groups and do not incur costs.</p>
+    async fn disable_insight_rules(
+        &self,
+        input: DisableInsightRulesInput,
+    ) -> Result<DisableInsightRulesOutput, RusotoError<DisableInsightRulesError>> {
+        self.disable_insight_rules_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Enables the actions for the specified alarms.</p>
+    async fn enable_alarm_actions(
+        &self,
+        input: EnableAlarmActionsInput,
+    ) -> Result<(), RusotoError<EnableAlarmActionsError>> {
+        self.enable_alarm_actions_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Enables the specified Contributor Insights rules. When rules are enabled, they immediately begin analyzing log data.</p>
+    async fn enable_insight_rules(
+        &self,
+        input: EnableInsightRulesInput,
+    ) -> Result<EnableInsightRulesOutput, RusotoError<EnableInsightRulesError>> {
+        self.enable_insight_rules_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Displays the details of the dashboard that you specify.</p> <p>To copy an existing dashboard, use <code>GetDashboard</code>, and then use the data returned within <code>DashboardBody</code> as the template for the new dashboard when you call <code>PutDashboard</code> to create the copy.</p>
+    async fn get_dashboard(
+        &self,
+        input: GetDashboardInput,
+    ) -> Result<GetDashboardOutput, RusotoError<GetDashboardError>> {
+        self.get_dashboard_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>This operation returns the time series data collected by a Contributor Insights rule. The data includes the identity and number of contributors to the log group.</p> <p>You can also optionally return one or more statistics about each data point in the time series. These statistics can include the following:</p> <ul> <li> <p> <code>UniqueContributors</code> -- the number of unique contributors for each data point.</p> </li> <li> <p> <code>MaxContributorValue</code> -- the value of the top contributor for each data point. The identity of the contributor may change for each data point in the graph.</p> <p>If this rule aggregates by COUNT, the top contributor for each data point is the contributor with the most occurrences in that period. If the rule aggregates by SUM, the top contributor is the contributor with the highest sum in the log field specified by the rule&#39;s <code>Value</code>, during that period.</p> </li> <li> <p> <code>SampleCount</code> -- the number of data points matched by the rule.</p> </li> <li> <p> <code>Sum</code> -- the sum of the values from all contributors during the time period represented by that data point.</p> </li> <li> <p> <code>Minimum</code> -- the minimum value from a single observation during the time period represented by that data point.</p> </li> <li> <p> <code>Maximum</code> -- the maximum value from a single observation during the time period represented by that data point.</p> </li> <li> <p> <code>Average</code> -- the average value from all contributors during the time period represented by that data point.</p> </li> </ul></p>
+    async fn get_insight_rule_report(
+        &self,
+        input: GetInsightRuleReportInput,
+    ) -> Result<GetInsightRuleReportOutput, RusotoError<GetInsightRuleReportError>> {
+        self.get_insight_rule_report_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>You can use the <code>GetMetricData</code> API to retrieve as many as 100 different metrics in a single request, with a total of as many as 100,800 data points. You can also optionally perform math expressions on the values of the returned statistics, to create new time series that represent new insights into your data. For example, using Lambda metrics, you could divide the Errors metric by the Invocations metric to get an error rate time series. For more information about metric math expressions, see <a href="https://docs.aws.amazon.com/AmazonCloudWatch/latest/monitoring/using-metric-math.html#metric-math-syntax">Metric Math Syntax and Functions</a> in the <i>Amazon CloudWatch User Guide</i>.</p> <p>Calls to the <code>GetMetricData</code> API have a different pricing structure than calls to <code>GetMetricStatistics</code>. For more information about pricing, see <a href="https://aws.amazon.com/cloudwatch/pricing/">Amazon CloudWatch Pricing</a>.</p> <p>Amazon CloudWatch retains metric data as follows:</p> <ul> <li> <p>Data points with a period of less than 60 seconds are available for 3 hours. These data points are high-resolution metrics and are available only for custom metrics that have been defined with a <code>StorageResolution</code> of 1.</p> </li> <li> <p>Data points with a period of 60 seconds (1-minute) are available for 15 days.</p> </li> <li> <p>Data points with a period of 300 seconds (5-minute) are available for 63 days.</p> </li> <li> <p>Data points with a period of 3600 seconds (1 hour) are available for 455 days (15 months).</p> </li> </ul> <p>Data points that are initially published with a shorter period are aggregated together for long-term storage. For example, if you collect data using a period of 1 minute, the data remains available for 15 days with 1-minute resolution. After 15 days, this data is still available, but is aggregated and retrievable only with a resolution of 5 minutes. After 63 days, the data is further aggregated and is available with a resolution of 1 hour.</p> <p>If you omit <code>Unit</code> in your request, all data that was collected with any unit is returned, along with the corresponding units that were specified when the data was reported to CloudWatch. If you specify a unit, the operation returns only data data that was collected with that unit specified. If you specify a unit that does not match the data collected, the results of the operation are null. CloudWatch does not perform unit conversions.</p>
+    async fn get_metric_data(
+        &self,
+        input: GetMetricDataInput,
+    ) -> Result<GetMetricDataOutput, RusotoError<GetMetricDataError>> {
+        self.get_metric_data_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Gets statistics for the specified metric.</p> <p>The maximum number of data points returned from a single call is 1,440. If you request more than 1,440 data points, CloudWatch returns an error. To reduce the number of data points, you can narrow the specified time range and make multiple requests across adjacent time ranges, or you can increase the specified period. Data points are not returned in chronological order.</p> <p>CloudWatch aggregates data points based on the length of the period that you specify. For example, if you request statistics with a one-hour period, CloudWatch aggregates all data points with time stamps that fall within each one-hour period. Therefore, the number of values aggregated by CloudWatch is larger than the number of data points returned.</p> <p>CloudWatch needs raw data points to calculate percentile statistics. If you publish data using a statistic set instead, you can only retrieve percentile statistics for this data if one of the following conditions is true:</p> <ul> <li> <p>The SampleCount value of the statistic set is 1.</p> </li> <li> <p>The Min and the Max values of the statistic set are equal.</p> </li> </ul> <p>Percentile statistics are not available for metrics when any of the metric values are negative numbers.</p> <p>Amazon CloudWatch retains metric data as follows:</p> <ul> <li> <p>Data points with a period of less than 60 seconds are available for 3 hours. These data points are high-resolution metrics and are available only for custom metrics that have been defined with a <code>StorageResolution</code> of 1.</p> </li> <li> <p>Data points with a period of 60 seconds (1-minute) are available for 15 days.</p> </li> <li> <p>Data points with a period of 300 seconds (5-minute) are available for 63 days.</p> </li> <li> <p>Data points with a period of 3600 seconds (1 hour) are available for 455 days (15 months).</p> </li> </ul> <p>Data points that are initially published with a shorter period are aggregated together for long-term storage. For example, if you collect data using a period of 1 minute, the data remains available for 15 days with 1-minute resolution. After 15 days, this data is still available, but is aggregated and retrievable only with a resolution of 5 minutes. After 63 days, the data is further aggregated and is available with a resolution of 1 hour.</p> <p>CloudWatch started retaining 5-minute and 1-hour metric data as of July 9, 2016.</p> <p>For information about metrics and dimensions supported by AWS services, see the <a href="https://docs.aws.amazon.com/AmazonCloudWatch/latest/monitoring/CW_Support_For_AWS.html">Amazon CloudWatch Metrics and Dimensions Reference</a> in the <i>Amazon CloudWatch User Guide</i>.</p>
+    async fn get_metric_statistics(
+        &self,
+        input: GetMetricStatisticsInput,
+    ) -> Result<GetMetricStatisticsOutput, RusotoError<GetMetricStatisticsError>> {
+        self.get_metric_statistics_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>You can use the <code>GetMetricWidgetImage</code> API to retrieve a snapshot graph of one or more Amazon CloudWatch metrics as a bitmap image. You can then embed this image into your services and products, such as wiki pages, reports, and documents. You could also retrieve images regularly, such as every minute, and create your own custom live dashboard.</p> <p>The graph you retrieve can include all CloudWatch metric graph features, including metric math and horizontal and vertical annotations.</p> <p>There is a limit of 20 transactions per second for this API. Each <code>GetMetricWidgetImage</code> action has the following limits:</p> <ul> <li> <p>As many as 100 metrics in the graph.</p> </li> <li> <p>Up to 100 KB uncompressed payload.</p> </li> </ul></p>
+    async fn get_metric_widget_image(
+        &self,
+        input: GetMetricWidgetImageInput,
+    ) -> Result<GetMetricWidgetImageOutput, RusotoError<GetMetricWidgetImageError>> {
+        self.get_metric_widget_image_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns a list of the dashboards for your account. If you include <code>DashboardNamePrefix</code>, only those dashboards with names starting with the prefix are listed. Otherwise, all dashboards in your account are listed. </p> <p> <code>ListDashboards</code> returns up to 1000 results on one page. If there are more than 1000 dashboards, you can call <code>ListDashboards</code> again and include the value you received for <code>NextToken</code> in the first call, to receive the next 1000 results.</p>
+    async fn list_dashboards(
+        &self,
+        input: ListDashboardsInput,
+    ) -> Result<ListDashboardsOutput, RusotoError<ListDashboardsError>> {
+        self.list_dashboards_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>List the specified metrics. You can use the returned metrics with <a>GetMetricData</a> or <a>GetMetricStatistics</a> to obtain statistical data.</p> <p>Up to 500 results are returned for any one call. To retrieve additional results, use the returned token with subsequent calls.</p> <p>After you create a metric, allow up to fifteen minutes before the metric appears. Statistics about the metric, however, are available sooner using <a>GetMetricData</a> or <a>GetMetricStatistics</a>.</p>
+    async fn list_metrics(
+        &self,
+        input: ListMetricsInput,
+    ) -> Result<ListMetricsOutput, RusotoError<ListMetricsError>> {
+        self.list_metrics_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Displays the tags associated with a CloudWatch resource. Alarms support tagging.</p>
+    async fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceInput,
+    ) -> Result<ListTagsForResourceOutput, RusotoError<ListTagsForResourceError>> {
+        self.list_tags_for_resource_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Creates an anomaly detection model for a CloudWatch metric. You can use the model to display a band of expected normal values when the metric is graphed.</p> <p>For more information, see <a href="https://docs.aws.amazon.com/AmazonCloudWatch/latest/monitoring/CloudWatch_Anomaly_Detection.html">CloudWatch Anomaly Detection</a>.</p>
+    async fn put_anomaly_detector(
+        &self,
+        input: PutAnomalyDetectorInput,
+    ) -> Result<PutAnomalyDetectorOutput, RusotoError<PutAnomalyDetectorError>> {
+        self.put_anomaly_detector_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Creates a dashboard if it does not already exist, or updates an existing dashboard. If you update a dashboard, the entire contents are replaced with what you specify here.</p> <p>All dashboards in your account are global, not region-specific.</p> <p>A simple way to create a dashboard using <code>PutDashboard</code> is to copy an existing dashboard. To copy an existing dashboard using the console, you can load the dashboard and then use the View/edit source command in the Actions menu to display the JSON block for that dashboard. Another way to copy a dashboard is to use <code>GetDashboard</code>, and then use the data returned within <code>DashboardBody</code> as the template for the new dashboard when you call <code>PutDashboard</code>.</p> <p>When you create a dashboard with <code>PutDashboard</code>, a good practice is to add a text widget at the top of the dashboard with a message that the dashboard was created by script and should not be changed in the console. This message could also point console users to the location of the <code>DashboardBody</code> script or the CloudFormation template used to create the dashboard.</p>
+    async fn put_dashboard(
+        &self,
+        input: PutDashboardInput,
+    ) -> Result<PutDashboardOutput, RusotoError<PutDashboardError>> {
+        self.put_dashboard_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Creates a Contributor Insights rule. Rules evaluate log events in a CloudWatch Logs log group, enabling you to find contributor data for the log events in that log group. For more information, see <a href="https://docs.aws.amazon.com/AmazonCloudWatch/latest/monitoring/ContributorInsights.html">Using Contributor Insights to Analyze High-Cardinality Data</a>.</p> <p>If you create a rule, delete it, and then re-create it with the same name, historical data from the first time the rule was created may or may not be available.</p>
+    async fn put_insight_rule(
+        &self,
+        input: PutInsightRuleInput,
+    ) -> Result<PutInsightRuleOutput, RusotoError<PutInsightRuleError>> {
+        self.put_insight_rule_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Creates or updates an alarm and associates it with the specified metric, metric math expression, or anomaly detection model.</p> <p>Alarms based on anomaly detection models cannot have Auto Scaling actions.</p> <p>When this operation creates an alarm, the alarm state is immediately set to <code>INSUFFICIENT_DATA</code>. The alarm is then evaluated and its state is set appropriately. Any actions associated with the new state are then executed.</p> <p>When you update an existing alarm, its state is left unchanged, but the update completely overwrites the previous configuration of the alarm.</p> <p>If you are an IAM user, you must have Amazon EC2 permissions for some alarm operations:</p> <ul> <li> <p> <code>iam:CreateServiceLinkedRole</code> for all alarms with EC2 actions</p> </li> <li> <p> <code>ec2:DescribeInstanceStatus</code> and <code>ec2:DescribeInstances</code> for all alarms on EC2 instance status metrics</p> </li> <li> <p> <code>ec2:StopInstances</code> for alarms with stop actions</p> </li> <li> <p> <code>ec2:TerminateInstances</code> for alarms with terminate actions</p> </li> <li> <p>No specific permissions are needed for alarms with recover actions</p> </li> </ul> <p>If you have read/write permissions for Amazon CloudWatch but not for Amazon EC2, you can still create an alarm, but the stop or terminate actions are not performed. However, if you are later granted the required permissions, the alarm actions that you created earlier are performed.</p> <p>If you are using an IAM role (for example, an EC2 instance profile), you cannot stop or terminate the instance using alarm actions. However, you can still see the alarm state and perform any other actions such as Amazon SNS notifications or Auto Scaling policies.</p> <p>If you are using temporary security credentials granted using AWS STS, you cannot stop or terminate an EC2 instance using alarm actions.</p> <p>The first time you create an alarm in the AWS Management Console, the CLI, or by using the PutMetricAlarm API, CloudWatch creates the necessary service-linked role for you. The service-linked role is called <code>AWSServiceRoleForCloudWatchEvents</code>. For more information, see <a href="https://docs.aws.amazon.com/IAM/latest/UserGuide/id_roles_terms-and-concepts.html#iam-term-service-linked-role">AWS service-linked role</a>.</p>
+    async fn put_metric_alarm(
+        &self,
+        input: PutMetricAlarmInput,
+    ) -> Result<(), RusotoError<PutMetricAlarmError>> {
+        self.put_metric_alarm_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Publishes metric data points to Amazon CloudWatch. CloudWatch associates the data points with the specified metric. If the specified metric does not exist, CloudWatch creates the metric. When CloudWatch creates a metric, it can take up to fifteen minutes for the metric to appear in calls to <a>ListMetrics</a>.</p> <p>You can publish either individual data points in the <code>Value</code> field, or arrays of values and the number of times each value occurred during the period by using the <code>Values</code> and <code>Counts</code> fields in the <code>MetricDatum</code> structure. Using the <code>Values</code> and <code>Counts</code> method enables you to publish up to 150 values per metric with one <code>PutMetricData</code> request, and supports retrieving percentile statistics on this data.</p> <p>Each <code>PutMetricData</code> request is limited to 40 KB in size for HTTP POST requests. You can send a payload compressed by gzip. Each request is also limited to no more than 20 different metrics.</p> <p>Although the <code>Value</code> parameter accepts numbers of type <code>Double</code>, CloudWatch rejects values that are either too small or too large. Values must be in the range of -2^360 to 2^360. In addition, special values (for example, NaN, +Infinity, -Infinity) are not supported.</p> <p>You can use up to 10 dimensions per metric to further clarify what data the metric collects. Each dimension consists of a Name and Value pair. For more information about specifying dimensions, see <a href="https://docs.aws.amazon.com/AmazonCloudWatch/latest/monitoring/publishingMetrics.html">Publishing Metrics</a> in the <i>Amazon CloudWatch User Guide</i>.</p> <p>Data points with time stamps from 24 hours ago or longer can take at least 48 hours to become available for <a>GetMetricData</a> or <a>GetMetricStatistics</a> from the time they are submitted.</p> <p>CloudWatch needs raw data points to calculate percentile statistics. If you publish data using a statistic set instead, you can only retrieve percentile statistics for this data if one of the following conditions is true:</p> <ul> <li> <p>The <code>SampleCount</code> value of the statistic set is 1 and <code>Min</code>, <code>Max</code>, and <code>Sum</code> are all equal.</p> </li> <li> <p>The <code>Min</code> and <code>Max</code> are equal, and <code>Sum</code> is equal to <code>Min</code> multiplied by <code>SampleCount</code>.</p> </li> </ul></p>
+    async fn put_metric_data(
+        &self,
+        input: PutMetricDataInput,
+    ) -> Result<(), RusotoError<PutMetricDataError>> {
+        self.put_metric_data_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Temporarily sets the state of an alarm for testing purposes. When the updated state differs from the previous value, the action configured for the appropriate state is invoked. For example, if your alarm is configured to send an Amazon SNS message when an alarm is triggered, temporarily changing the alarm state to <code>ALARM</code> sends an SNS message. The alarm returns to its actual state (often within seconds). Because the alarm state change happens quickly, it is typically only visible in the alarm's <b>History</b> tab in the Amazon CloudWatch console or through <a>DescribeAlarmHistory</a>.</p>
+    async fn set_alarm_state(
+        &self,
+        input: SetAlarmStateInput,
+    ) -> Result<(), RusotoError<SetAlarmStateError>> {
+        self.set_alarm_state_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Assigns one or more tags (key-value pairs) to the specified CloudWatch resource. Currently, the only CloudWatch resources that can be tagged are alarms.</p> <p>Tags can help you organize and categorize your resources. You can also use them to scope user permissions, by granting a user permission to access or change only resources with certain tag values.</p> <p>Tags don't have any semantic meaning to AWS and are interpreted strictly as strings of characters.</p> <p>You can use the <code>TagResource</code> action with an alarm that already has tags. If you specify a new tag key for the alarm, this tag is appended to the list of tags associated with the alarm. If you specify a tag key that is already associated with the alarm, the new tag value that you specify replaces the previous value for that tag.</p> <p>You can associate as many as 50 tags with a resource.</p>
+    async fn tag_resource(
+        &self,
+        input: TagResourceInput,
+    ) -> Result<TagResourceOutput, RusotoError<TagResourceError>> {
+        self.tag_resource_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Removes one or more tags from the specified resource.</p>
+    async fn untag_resource(
+        &self,
+        input: UntagResourceInput,
+    ) -> Result<UntagResourceOutput, RusotoError<UntagResourceError>> {
+        self.untag_resource_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
     }
 }
 
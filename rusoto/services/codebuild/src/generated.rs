@@ -16,8 +16,8 @@ use std::fmt;
 use async_trait::async_trait;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
-use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest, ResponseMetadata};
+use rusoto_core::{Client, ErrorMetadata, RusotoError};
 
 use rusoto_core::proto;
 use rusoto_core::signature::SignedRequest;
@@ -1961,9 +1961,12 @@ pub struct WebhookFilter {
 
 /// Errors returned by BatchDeleteBuilds
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum BatchDeleteBuildsError {
     /// <p>The input value that was provided is not valid.</p>
     InvalidInput(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl BatchDeleteBuildsError {
@@ -1971,10 +1974,13 @@ impl BatchDeleteBuildsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidInputException" => {
-                    return RusotoError::Service(BatchDeleteBuildsError::InvalidInput(err.msg))
+                    return RusotoError::service(
+                        BatchDeleteBuildsError::InvalidInput(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(BatchDeleteBuildsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1985,15 +1991,19 @@ impl fmt::Display for BatchDeleteBuildsError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             BatchDeleteBuildsError::InvalidInput(ref cause) => write!(f, "{}", cause),
+            BatchDeleteBuildsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for BatchDeleteBuildsError {}
 /// Errors returned by BatchGetBuilds
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum BatchGetBuildsError {
     /// <p>The input value that was provided is not valid.</p>
     InvalidInput(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl BatchGetBuildsError {
@@ -2001,10 +2011,10 @@ impl BatchGetBuildsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidInputException" => {
-                    return RusotoError::Service(BatchGetBuildsError::InvalidInput(err.msg))
+                    return RusotoError::service(BatchGetBuildsError::InvalidInput(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(BatchGetBuildsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2015,15 +2025,19 @@ impl fmt::Display for BatchGetBuildsError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             BatchGetBuildsError::InvalidInput(ref cause) => write!(f, "{}", cause),
+            BatchGetBuildsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for BatchGetBuildsError {}
 /// Errors returned by BatchGetProjects
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum BatchGetProjectsError {
     /// <p>The input value that was provided is not valid.</p>
     InvalidInput(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl BatchGetProjectsError {
@@ -2031,10 +2045,10 @@ impl BatchGetProjectsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidInputException" => {
-                    return RusotoError::Service(BatchGetProjectsError::InvalidInput(err.msg))
+                    return RusotoError::service(BatchGetProjectsError::InvalidInput(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(BatchGetProjectsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2045,15 +2059,19 @@ impl fmt::Display for BatchGetProjectsError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             BatchGetProjectsError::InvalidInput(ref cause) => write!(f, "{}", cause),
+            BatchGetProjectsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for BatchGetProjectsError {}
 /// Errors returned by BatchGetReportGroups
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum BatchGetReportGroupsError {
     /// <p>The input value that was provided is not valid.</p>
     InvalidInput(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl BatchGetReportGroupsError {
@@ -2061,10 +2079,13 @@ impl BatchGetReportGroupsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidInputException" => {
-                    return RusotoError::Service(BatchGetReportGroupsError::InvalidInput(err.msg))
+                    return RusotoError::service(
+                        BatchGetReportGroupsError::InvalidInput(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(BatchGetReportGroupsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2075,15 +2096,19 @@ impl fmt::Display for BatchGetReportGroupsError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             BatchGetReportGroupsError::InvalidInput(ref cause) => write!(f, "{}", cause),
+            BatchGetReportGroupsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for BatchGetReportGroupsError {}
 /// Errors returned by BatchGetReports
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum BatchGetReportsError {
     /// <p>The input value that was provided is not valid.</p>
     InvalidInput(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl BatchGetReportsError {
@@ -2091,10 +2116,10 @@ impl BatchGetReportsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidInputException" => {
-                    return RusotoError::Service(BatchGetReportsError::InvalidInput(err.msg))
+                    return RusotoError::service(BatchGetReportsError::InvalidInput(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(BatchGetReportsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2105,12 +2130,14 @@ impl fmt::Display for BatchGetReportsError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             BatchGetReportsError::InvalidInput(ref cause) => write!(f, "{}", cause),
+            BatchGetReportsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for BatchGetReportsError {}
 /// Errors returned by CreateProject
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateProjectError {
     /// <p>An AWS service limit was exceeded for the calling AWS account.</p>
     AccountLimitExceeded(String),
@@ -2118,6 +2145,8 @@ pub enum CreateProjectError {
     InvalidInput(String),
     /// <p>The specified AWS resource cannot be created, because an AWS resource with the same settings already exists.</p>
     ResourceAlreadyExists(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateProjectError {
@@ -2125,16 +2154,22 @@ impl CreateProjectError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccountLimitExceededException" => {
-                    return RusotoError::Service(CreateProjectError::AccountLimitExceeded(err.msg))
+                    return RusotoError::service(
+                        CreateProjectError::AccountLimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(CreateProjectError::InvalidInput(err.msg))
+                    return RusotoError::service(CreateProjectError::InvalidInput(err.msg), &res)
                 }
                 "ResourceAlreadyExistsException" => {
-                    return RusotoError::Service(CreateProjectError::ResourceAlreadyExists(err.msg))
+                    return RusotoError::service(
+                        CreateProjectError::ResourceAlreadyExists(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreateProjectError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2147,12 +2182,14 @@ impl fmt::Display for CreateProjectError {
             CreateProjectError::AccountLimitExceeded(ref cause) => write!(f, "{}", cause),
             CreateProjectError::InvalidInput(ref cause) => write!(f, "{}", cause),
             CreateProjectError::ResourceAlreadyExists(ref cause) => write!(f, "{}", cause),
+            CreateProjectError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateProjectError {}
 /// Errors returned by CreateReportGroup
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateReportGroupError {
     /// <p>An AWS service limit was exceeded for the calling AWS account.</p>
     AccountLimitExceeded(String),
@@ -2160,6 +2197,8 @@ pub enum CreateReportGroupError {
     InvalidInput(String),
     /// <p>The specified AWS resource cannot be created, because an AWS resource with the same settings already exists.</p>
     ResourceAlreadyExists(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateReportGroupError {
@@ -2167,20 +2206,25 @@ impl CreateReportGroupError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccountLimitExceededException" => {
-                    return RusotoError::Service(CreateReportGroupError::AccountLimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateReportGroupError::AccountLimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(CreateReportGroupError::InvalidInput(err.msg))
+                    return RusotoError::service(
+                        CreateReportGroupError::InvalidInput(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceAlreadyExistsException" => {
-                    return RusotoError::Service(CreateReportGroupError::ResourceAlreadyExists(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateReportGroupError::ResourceAlreadyExists(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreateReportGroupError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2193,12 +2237,14 @@ impl fmt::Display for CreateReportGroupError {
             CreateReportGroupError::AccountLimitExceeded(ref cause) => write!(f, "{}", cause),
             CreateReportGroupError::InvalidInput(ref cause) => write!(f, "{}", cause),
             CreateReportGroupError::ResourceAlreadyExists(ref cause) => write!(f, "{}", cause),
+            CreateReportGroupError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateReportGroupError {}
 /// Errors returned by CreateWebhook
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateWebhookError {
     /// <p>The input value that was provided is not valid.</p>
     InvalidInput(String),
@@ -2208,6 +2254,8 @@ pub enum CreateWebhookError {
     ResourceAlreadyExists(String),
     /// <p>The specified AWS resource cannot be found.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateWebhookError {
@@ -2215,19 +2263,25 @@ impl CreateWebhookError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidInputException" => {
-                    return RusotoError::Service(CreateWebhookError::InvalidInput(err.msg))
+                    return RusotoError::service(CreateWebhookError::InvalidInput(err.msg), &res)
                 }
                 "OAuthProviderException" => {
-                    return RusotoError::Service(CreateWebhookError::OAuthProvider(err.msg))
+                    return RusotoError::service(CreateWebhookError::OAuthProvider(err.msg), &res)
                 }
                 "ResourceAlreadyExistsException" => {
-                    return RusotoError::Service(CreateWebhookError::ResourceAlreadyExists(err.msg))
+                    return RusotoError::service(
+                        CreateWebhookError::ResourceAlreadyExists(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(CreateWebhookError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        CreateWebhookError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreateWebhookError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2241,15 +2295,19 @@ impl fmt::Display for CreateWebhookError {
             CreateWebhookError::OAuthProvider(ref cause) => write!(f, "{}", cause),
             CreateWebhookError::ResourceAlreadyExists(ref cause) => write!(f, "{}", cause),
             CreateWebhookError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            CreateWebhookError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateWebhookError {}
 /// Errors returned by DeleteProject
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteProjectError {
     /// <p>The input value that was provided is not valid.</p>
     InvalidInput(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteProjectError {
@@ -2257,10 +2315,10 @@ impl DeleteProjectError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidInputException" => {
-                    return RusotoError::Service(DeleteProjectError::InvalidInput(err.msg))
+                    return RusotoError::service(DeleteProjectError::InvalidInput(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteProjectError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2271,15 +2329,19 @@ impl fmt::Display for DeleteProjectError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             DeleteProjectError::InvalidInput(ref cause) => write!(f, "{}", cause),
+            DeleteProjectError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteProjectError {}
 /// Errors returned by DeleteReport
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteReportError {
     /// <p>The input value that was provided is not valid.</p>
     InvalidInput(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteReportError {
@@ -2287,10 +2349,10 @@ impl DeleteReportError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidInputException" => {
-                    return RusotoError::Service(DeleteReportError::InvalidInput(err.msg))
+                    return RusotoError::service(DeleteReportError::InvalidInput(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteReportError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2301,15 +2363,19 @@ impl fmt::Display for DeleteReportError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             DeleteReportError::InvalidInput(ref cause) => write!(f, "{}", cause),
+            DeleteReportError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteReportError {}
 /// Errors returned by DeleteReportGroup
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteReportGroupError {
     /// <p>The input value that was provided is not valid.</p>
     InvalidInput(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteReportGroupError {
@@ -2317,10 +2383,13 @@ impl DeleteReportGroupError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidInputException" => {
-                    return RusotoError::Service(DeleteReportGroupError::InvalidInput(err.msg))
+                    return RusotoError::service(
+                        DeleteReportGroupError::InvalidInput(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteReportGroupError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2331,15 +2400,19 @@ impl fmt::Display for DeleteReportGroupError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             DeleteReportGroupError::InvalidInput(ref cause) => write!(f, "{}", cause),
+            DeleteReportGroupError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteReportGroupError {}
 /// Errors returned by DeleteResourcePolicy
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteResourcePolicyError {
     /// <p>The input value that was provided is not valid.</p>
     InvalidInput(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteResourcePolicyError {
@@ -2347,10 +2420,13 @@ impl DeleteResourcePolicyError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidInputException" => {
-                    return RusotoError::Service(DeleteResourcePolicyError::InvalidInput(err.msg))
+                    return RusotoError::service(
+                        DeleteResourcePolicyError::InvalidInput(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteResourcePolicyError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2361,17 +2437,21 @@ impl fmt::Display for DeleteResourcePolicyError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             DeleteResourcePolicyError::InvalidInput(ref cause) => write!(f, "{}", cause),
+            DeleteResourcePolicyError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteResourcePolicyError {}
 /// Errors returned by DeleteSourceCredentials
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteSourceCredentialsError {
     /// <p>The input value that was provided is not valid.</p>
     InvalidInput(String),
     /// <p>The specified AWS resource cannot be found.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteSourceCredentialsError {
@@ -2379,17 +2459,19 @@ impl DeleteSourceCredentialsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidInputException" => {
-                    return RusotoError::Service(DeleteSourceCredentialsError::InvalidInput(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteSourceCredentialsError::InvalidInput(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteSourceCredentialsError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteSourceCredentialsError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteSourceCredentialsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2401,12 +2483,14 @@ impl fmt::Display for DeleteSourceCredentialsError {
         match *self {
             DeleteSourceCredentialsError::InvalidInput(ref cause) => write!(f, "{}", cause),
             DeleteSourceCredentialsError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            DeleteSourceCredentialsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteSourceCredentialsError {}
 /// Errors returned by DeleteWebhook
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteWebhookError {
     /// <p>The input value that was provided is not valid.</p>
     InvalidInput(String),
@@ -2414,6 +2498,8 @@ pub enum DeleteWebhookError {
     OAuthProvider(String),
     /// <p>The specified AWS resource cannot be found.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteWebhookError {
@@ -2421,16 +2507,19 @@ impl DeleteWebhookError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidInputException" => {
-                    return RusotoError::Service(DeleteWebhookError::InvalidInput(err.msg))
+                    return RusotoError::service(DeleteWebhookError::InvalidInput(err.msg), &res)
                 }
                 "OAuthProviderException" => {
-                    return RusotoError::Service(DeleteWebhookError::OAuthProvider(err.msg))
+                    return RusotoError::service(DeleteWebhookError::OAuthProvider(err.msg), &res)
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteWebhookError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        DeleteWebhookError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteWebhookError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2443,17 +2532,21 @@ impl fmt::Display for DeleteWebhookError {
             DeleteWebhookError::InvalidInput(ref cause) => write!(f, "{}", cause),
             DeleteWebhookError::OAuthProvider(ref cause) => write!(f, "{}", cause),
             DeleteWebhookError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            DeleteWebhookError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteWebhookError {}
 /// Errors returned by DescribeTestCases
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeTestCasesError {
     /// <p>The input value that was provided is not valid.</p>
     InvalidInput(String),
     /// <p>The specified AWS resource cannot be found.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeTestCasesError {
@@ -2461,13 +2554,19 @@ impl DescribeTestCasesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidInputException" => {
-                    return RusotoError::Service(DescribeTestCasesError::InvalidInput(err.msg))
+                    return RusotoError::service(
+                        DescribeTestCasesError::InvalidInput(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeTestCasesError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        DescribeTestCasesError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DescribeTestCasesError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2479,17 +2578,21 @@ impl fmt::Display for DescribeTestCasesError {
         match *self {
             DescribeTestCasesError::InvalidInput(ref cause) => write!(f, "{}", cause),
             DescribeTestCasesError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            DescribeTestCasesError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeTestCasesError {}
 /// Errors returned by GetResourcePolicy
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetResourcePolicyError {
     /// <p>The input value that was provided is not valid.</p>
     InvalidInput(String),
     /// <p>The specified AWS resource cannot be found.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetResourcePolicyError {
@@ -2497,13 +2600,19 @@ impl GetResourcePolicyError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidInputException" => {
-                    return RusotoError::Service(GetResourcePolicyError::InvalidInput(err.msg))
+                    return RusotoError::service(
+                        GetResourcePolicyError::InvalidInput(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetResourcePolicyError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        GetResourcePolicyError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetResourcePolicyError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2515,12 +2624,14 @@ impl fmt::Display for GetResourcePolicyError {
         match *self {
             GetResourcePolicyError::InvalidInput(ref cause) => write!(f, "{}", cause),
             GetResourcePolicyError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            GetResourcePolicyError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetResourcePolicyError {}
 /// Errors returned by ImportSourceCredentials
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ImportSourceCredentialsError {
     /// <p>An AWS service limit was exceeded for the calling AWS account.</p>
     AccountLimitExceeded(String),
@@ -2528,6 +2639,8 @@ pub enum ImportSourceCredentialsError {
     InvalidInput(String),
     /// <p>The specified AWS resource cannot be created, because an AWS resource with the same settings already exists.</p>
     ResourceAlreadyExists(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ImportSourceCredentialsError {
@@ -2535,22 +2648,25 @@ impl ImportSourceCredentialsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccountLimitExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         ImportSourceCredentialsError::AccountLimitExceeded(err.msg),
+                        &res,
                     )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(ImportSourceCredentialsError::InvalidInput(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ImportSourceCredentialsError::InvalidInput(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceAlreadyExistsException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         ImportSourceCredentialsError::ResourceAlreadyExists(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ImportSourceCredentialsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2565,17 +2681,21 @@ impl fmt::Display for ImportSourceCredentialsError {
             ImportSourceCredentialsError::ResourceAlreadyExists(ref cause) => {
                 write!(f, "{}", cause)
             }
+            ImportSourceCredentialsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ImportSourceCredentialsError {}
 /// Errors returned by InvalidateProjectCache
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum InvalidateProjectCacheError {
     /// <p>The input value that was provided is not valid.</p>
     InvalidInput(String),
     /// <p>The specified AWS resource cannot be found.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl InvalidateProjectCacheError {
@@ -2583,15 +2703,19 @@ impl InvalidateProjectCacheError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidInputException" => {
-                    return RusotoError::Service(InvalidateProjectCacheError::InvalidInput(err.msg))
+                    return RusotoError::service(
+                        InvalidateProjectCacheError::InvalidInput(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(InvalidateProjectCacheError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        InvalidateProjectCacheError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(InvalidateProjectCacheError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2603,15 +2727,19 @@ impl fmt::Display for InvalidateProjectCacheError {
         match *self {
             InvalidateProjectCacheError::InvalidInput(ref cause) => write!(f, "{}", cause),
             InvalidateProjectCacheError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            InvalidateProjectCacheError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for InvalidateProjectCacheError {}
 /// Errors returned by ListBuilds
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListBuildsError {
     /// <p>The input value that was provided is not valid.</p>
     InvalidInput(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListBuildsError {
@@ -2619,10 +2747,10 @@ impl ListBuildsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidInputException" => {
-                    return RusotoError::Service(ListBuildsError::InvalidInput(err.msg))
+                    return RusotoError::service(ListBuildsError::InvalidInput(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListBuildsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2633,17 +2761,21 @@ impl fmt::Display for ListBuildsError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             ListBuildsError::InvalidInput(ref cause) => write!(f, "{}", cause),
+            ListBuildsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListBuildsError {}
 /// Errors returned by ListBuildsForProject
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListBuildsForProjectError {
     /// <p>The input value that was provided is not valid.</p>
     InvalidInput(String),
     /// <p>The specified AWS resource cannot be found.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListBuildsForProjectError {
@@ -2651,15 +2783,19 @@ impl ListBuildsForProjectError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidInputException" => {
-                    return RusotoError::Service(ListBuildsForProjectError::InvalidInput(err.msg))
+                    return RusotoError::service(
+                        ListBuildsForProjectError::InvalidInput(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListBuildsForProjectError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListBuildsForProjectError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListBuildsForProjectError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2671,13 +2807,18 @@ impl fmt::Display for ListBuildsForProjectError {
         match *self {
             ListBuildsForProjectError::InvalidInput(ref cause) => write!(f, "{}", cause),
             ListBuildsForProjectError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            ListBuildsForProjectError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListBuildsForProjectError {}
 /// Errors returned by ListCuratedEnvironmentImages
 #[derive(Debug, PartialEq)]
-pub enum ListCuratedEnvironmentImagesError {}
+#[non_exhaustive]
+pub enum ListCuratedEnvironmentImagesError {
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
+}
 
 impl ListCuratedEnvironmentImagesError {
     pub fn from_response(
@@ -2686,7 +2827,12 @@ impl ListCuratedEnvironmentImagesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        ListCuratedEnvironmentImagesError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -2695,15 +2841,20 @@ impl ListCuratedEnvironmentImagesError {
 impl fmt::Display for ListCuratedEnvironmentImagesError {
     #[allow(unused_variables)]
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        match *self {}
+        match *self {
+            ListCuratedEnvironmentImagesError::Unhandled(ref metadata) => write!(f, "{}", metadata),
+        }
     }
 }
 impl Error for ListCuratedEnvironmentImagesError {}
 /// Errors returned by ListProjects
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListProjectsError {
     /// <p>The input value that was provided is not valid.</p>
     InvalidInput(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListProjectsError {
@@ -2711,10 +2862,10 @@ impl ListProjectsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidInputException" => {
-                    return RusotoError::Service(ListProjectsError::InvalidInput(err.msg))
+                    return RusotoError::service(ListProjectsError::InvalidInput(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListProjectsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2725,15 +2876,19 @@ impl fmt::Display for ListProjectsError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             ListProjectsError::InvalidInput(ref cause) => write!(f, "{}", cause),
+            ListProjectsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListProjectsError {}
 /// Errors returned by ListReportGroups
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListReportGroupsError {
     /// <p>The input value that was provided is not valid.</p>
     InvalidInput(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListReportGroupsError {
@@ -2741,10 +2896,10 @@ impl ListReportGroupsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidInputException" => {
-                    return RusotoError::Service(ListReportGroupsError::InvalidInput(err.msg))
+                    return RusotoError::service(ListReportGroupsError::InvalidInput(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListReportGroupsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2755,15 +2910,19 @@ impl fmt::Display for ListReportGroupsError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             ListReportGroupsError::InvalidInput(ref cause) => write!(f, "{}", cause),
+            ListReportGroupsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListReportGroupsError {}
 /// Errors returned by ListReports
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListReportsError {
     /// <p>The input value that was provided is not valid.</p>
     InvalidInput(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListReportsError {
@@ -2771,10 +2930,10 @@ impl ListReportsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidInputException" => {
-                    return RusotoError::Service(ListReportsError::InvalidInput(err.msg))
+                    return RusotoError::service(ListReportsError::InvalidInput(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListReportsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2785,17 +2944,21 @@ impl fmt::Display for ListReportsError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             ListReportsError::InvalidInput(ref cause) => write!(f, "{}", cause),
+            ListReportsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListReportsError {}
 /// Errors returned by ListReportsForReportGroup
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListReportsForReportGroupError {
     /// <p>The input value that was provided is not valid.</p>
     InvalidInput(String),
     /// <p>The specified AWS resource cannot be found.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListReportsForReportGroupError {
@@ -2803,17 +2966,21 @@ impl ListReportsForReportGroupError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidInputException" => {
-                    return RusotoError::Service(ListReportsForReportGroupError::InvalidInput(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListReportsForReportGroupError::InvalidInput(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListReportsForReportGroupError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListReportsForReportGroupError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(ListReportsForReportGroupError::Unhandled, &res)
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -2825,15 +2992,19 @@ impl fmt::Display for ListReportsForReportGroupError {
         match *self {
             ListReportsForReportGroupError::InvalidInput(ref cause) => write!(f, "{}", cause),
             ListReportsForReportGroupError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            ListReportsForReportGroupError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListReportsForReportGroupError {}
 /// Errors returned by ListSharedProjects
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListSharedProjectsError {
     /// <p>The input value that was provided is not valid.</p>
     InvalidInput(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListSharedProjectsError {
@@ -2841,10 +3012,13 @@ impl ListSharedProjectsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidInputException" => {
-                    return RusotoError::Service(ListSharedProjectsError::InvalidInput(err.msg))
+                    return RusotoError::service(
+                        ListSharedProjectsError::InvalidInput(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListSharedProjectsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2855,15 +3029,19 @@ impl fmt::Display for ListSharedProjectsError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             ListSharedProjectsError::InvalidInput(ref cause) => write!(f, "{}", cause),
+            ListSharedProjectsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListSharedProjectsError {}
 /// Errors returned by ListSharedReportGroups
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListSharedReportGroupsError {
     /// <p>The input value that was provided is not valid.</p>
     InvalidInput(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListSharedReportGroupsError {
@@ -2871,10 +3049,13 @@ impl ListSharedReportGroupsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidInputException" => {
-                    return RusotoError::Service(ListSharedReportGroupsError::InvalidInput(err.msg))
+                    return RusotoError::service(
+                        ListSharedReportGroupsError::InvalidInput(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListSharedReportGroupsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2885,20 +3066,25 @@ impl fmt::Display for ListSharedReportGroupsError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             ListSharedReportGroupsError::InvalidInput(ref cause) => write!(f, "{}", cause),
+            ListSharedReportGroupsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListSharedReportGroupsError {}
 /// Errors returned by ListSourceCredentials
 #[derive(Debug, PartialEq)]
-pub enum ListSourceCredentialsError {}
+#[non_exhaustive]
+pub enum ListSourceCredentialsError {
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
+}
 
 impl ListSourceCredentialsError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<ListSourceCredentialsError> {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListSourceCredentialsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2907,17 +3093,22 @@ impl ListSourceCredentialsError {
 impl fmt::Display for ListSourceCredentialsError {
     #[allow(unused_variables)]
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        match *self {}
+        match *self {
+            ListSourceCredentialsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
+        }
     }
 }
 impl Error for ListSourceCredentialsError {}
 /// Errors returned by PutResourcePolicy
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum PutResourcePolicyError {
     /// <p>The input value that was provided is not valid.</p>
     InvalidInput(String),
     /// <p>The specified AWS resource cannot be found.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl PutResourcePolicyError {
@@ -2925,13 +3116,19 @@ impl PutResourcePolicyError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidInputException" => {
-                    return RusotoError::Service(PutResourcePolicyError::InvalidInput(err.msg))
+                    return RusotoError::service(
+                        PutResourcePolicyError::InvalidInput(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(PutResourcePolicyError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        PutResourcePolicyError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(PutResourcePolicyError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2943,12 +3140,14 @@ impl fmt::Display for PutResourcePolicyError {
         match *self {
             PutResourcePolicyError::InvalidInput(ref cause) => write!(f, "{}", cause),
             PutResourcePolicyError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            PutResourcePolicyError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for PutResourcePolicyError {}
 /// Errors returned by StartBuild
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum StartBuildError {
     /// <p>An AWS service limit was exceeded for the calling AWS account.</p>
     AccountLimitExceeded(String),
@@ -2956,6 +3155,8 @@ pub enum StartBuildError {
     InvalidInput(String),
     /// <p>The specified AWS resource cannot be found.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl StartBuildError {
@@ -2963,16 +3164,19 @@ impl StartBuildError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccountLimitExceededException" => {
-                    return RusotoError::Service(StartBuildError::AccountLimitExceeded(err.msg))
+                    return RusotoError::service(
+                        StartBuildError::AccountLimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(StartBuildError::InvalidInput(err.msg))
+                    return RusotoError::service(StartBuildError::InvalidInput(err.msg), &res)
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(StartBuildError::ResourceNotFound(err.msg))
+                    return RusotoError::service(StartBuildError::ResourceNotFound(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(StartBuildError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2985,17 +3189,21 @@ impl fmt::Display for StartBuildError {
             StartBuildError::AccountLimitExceeded(ref cause) => write!(f, "{}", cause),
             StartBuildError::InvalidInput(ref cause) => write!(f, "{}", cause),
             StartBuildError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            StartBuildError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for StartBuildError {}
 /// Errors returned by StopBuild
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum StopBuildError {
     /// <p>The input value that was provided is not valid.</p>
     InvalidInput(String),
     /// <p>The specified AWS resource cannot be found.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl StopBuildError {
@@ -3003,13 +3211,13 @@ impl StopBuildError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidInputException" => {
-                    return RusotoError::Service(StopBuildError::InvalidInput(err.msg))
+                    return RusotoError::service(StopBuildError::InvalidInput(err.msg), &res)
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(StopBuildError::ResourceNotFound(err.msg))
+                    return RusotoError::service(StopBuildError::ResourceNotFound(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(StopBuildError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3021,17 +3229,21 @@ impl fmt::Display for StopBuildError {
         match *self {
             StopBuildError::InvalidInput(ref cause) => write!(f, "{}", cause),
             StopBuildError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            StopBuildError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for StopBuildError {}
 /// Errors returned by UpdateProject
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdateProjectError {
     /// <p>The input value that was provided is not valid.</p>
     InvalidInput(String),
     /// <p>The specified AWS resource cannot be found.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdateProjectError {
@@ -3039,13 +3251,16 @@ impl UpdateProjectError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidInputException" => {
-                    return RusotoError::Service(UpdateProjectError::InvalidInput(err.msg))
+                    return RusotoError::service(UpdateProjectError::InvalidInput(err.msg), &res)
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateProjectError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        UpdateProjectError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UpdateProjectError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3057,17 +3272,21 @@ impl fmt::Display for UpdateProjectError {
         match *self {
             UpdateProjectError::InvalidInput(ref cause) => write!(f, "{}", cause),
             UpdateProjectError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            UpdateProjectError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for UpdateProjectError {}
 /// Errors returned by UpdateReportGroup
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdateReportGroupError {
     /// <p>The input value that was provided is not valid.</p>
     InvalidInput(String),
     /// <p>The specified AWS resource cannot be found.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdateReportGroupError {
@@ -3075,13 +3294,19 @@ impl UpdateReportGroupError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidInputException" => {
-                    return RusotoError::Service(UpdateReportGroupError::InvalidInput(err.msg))
+                    return RusotoError::service(
+                        UpdateReportGroupError::InvalidInput(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateReportGroupError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        UpdateReportGroupError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UpdateReportGroupError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3093,12 +3318,14 @@ impl fmt::Display for UpdateReportGroupError {
         match *self {
             UpdateReportGroupError::InvalidInput(ref cause) => write!(f, "{}", cause),
             UpdateReportGroupError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            UpdateReportGroupError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for UpdateReportGroupError {}
 /// Errors returned by UpdateWebhook
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdateWebhookError {
     /// <p>The input value that was provided is not valid.</p>
     InvalidInput(String),
@@ -3106,6 +3333,8 @@ pub enum UpdateWebhookError {
     OAuthProvider(String),
     /// <p>The specified AWS resource cannot be found.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdateWebhookError {
@@ -3113,16 +3342,19 @@ impl UpdateWebhookError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidInputException" => {
-                    return RusotoError::Service(UpdateWebhookError::InvalidInput(err.msg))
+                    return RusotoError::service(UpdateWebhookError::InvalidInput(err.msg), &res)
                 }
                 "OAuthProviderException" => {
-                    return RusotoError::Service(UpdateWebhookError::OAuthProvider(err.msg))
+                    return RusotoError::service(UpdateWebhookError::OAuthProvider(err.msg), &res)
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateWebhookError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        UpdateWebhookError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UpdateWebhookError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3135,6 +3367,7 @@ impl fmt::Display for UpdateWebhookError {
             UpdateWebhookError::InvalidInput(ref cause) => write!(f, "{}", cause),
             UpdateWebhookError::OAuthProvider(ref cause) => write!(f, "{}", cause),
             UpdateWebhookError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            UpdateWebhookError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
@@ -3355,6 +3588,7 @@ impl CodeBuildClient {
     /// Creates a client backed by the default tokio event loop.
     ///
     /// The client will use the default credentials provider and tls client.
+    #[cfg(not(target_arch = "wasm32"))]
     pub fn new(region: region::Region) -> CodeBuildClient {
         CodeBuildClient {
             client: Client::shared(),
@@ -3380,562 +3614,1150 @@ impl CodeBuildClient {
     pub fn new_with_client(client: Client, region: region::Region) -> CodeBuildClient {
         CodeBuildClient { client, region }
     }
+
+    /// Returns a builder of a client, setting any of its region, credentials provider,
+    /// dispatcher, retry policy, endpoint and timeouts.
+    pub fn builder() -> rusoto_core::ClientBuilder<CodeBuildClient> {
+        rusoto_core::ClientBuilder::new(CodeBuildClient::new_with_client)
+    }
+
+    /// Returns a clone of this client with the overrides of `config` applied to its
+    /// operations, sharing its connections.
+    pub fn customize(&self, config: &rusoto_core::OperationConfig) -> CodeBuildClient {
+        CodeBuildClient {
+            client: self.client.customize(config),
+            region: config.resolve_region(&self.region),
+        }
+    }
 }
 
-#[async_trait]
-impl CodeBuild for CodeBuildClient {
-    /// <p>Deletes one or more builds.</p>
-    async fn batch_delete_builds(
+impl CodeBuildClient {
+    /// Calls `batch_delete_builds`, also returning the metadata of the response.
+    pub async fn batch_delete_builds_with_metadata(
         &self,
         input: BatchDeleteBuildsInput,
-    ) -> Result<BatchDeleteBuildsOutput, RusotoError<BatchDeleteBuildsError>> {
+    ) -> Result<(BatchDeleteBuildsOutput, ResponseMetadata), RusotoError<BatchDeleteBuildsError>>
+    {
+        let request = self.build_batch_delete_builds_request(input);
+
+        let mut response = self
+            .client
+            .sign_and_dispatch(request)
+            .await
+            .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
+        if response.status.is_success() {
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<BatchDeleteBuildsOutput, _>()?;
+            Ok((result, metadata))
+        } else {
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(BatchDeleteBuildsError::from_response(response))
+        }
+    }
+
+    /// Presigns the request `batch_delete_builds` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_batch_delete_builds(
+        &self,
+        presigner: &rusoto_core::Presigner,
+        input: BatchDeleteBuildsInput,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_batch_delete_builds_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_batch_delete_builds_request(&self, input: BatchDeleteBuildsInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "codebuild", &self.region, "/");
+        request.set_operation("BatchDeleteBuilds");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "CodeBuild_20161006.BatchDeleteBuilds");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `batch_get_builds`, also returning the metadata of the response.
+    pub async fn batch_get_builds_with_metadata(
+        &self,
+        input: BatchGetBuildsInput,
+    ) -> Result<(BatchGetBuildsOutput, ResponseMetadata), RusotoError<BatchGetBuildsError>> {
+        let request = self.build_batch_get_builds_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<BatchDeleteBuildsOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<BatchGetBuildsOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(BatchDeleteBuildsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(BatchGetBuildsError::from_response(response))
         }
     }
 
-    /// <p>Gets information about one or more builds.</p>
-    async fn batch_get_builds(
+    /// Presigns the request `batch_get_builds` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_batch_get_builds(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: BatchGetBuildsInput,
-    ) -> Result<BatchGetBuildsOutput, RusotoError<BatchGetBuildsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_batch_get_builds_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_batch_get_builds_request(&self, input: BatchGetBuildsInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "codebuild", &self.region, "/");
+        request.set_operation("BatchGetBuilds");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "CodeBuild_20161006.BatchGetBuilds");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `batch_get_projects`, also returning the metadata of the response.
+    pub async fn batch_get_projects_with_metadata(
+        &self,
+        input: BatchGetProjectsInput,
+    ) -> Result<(BatchGetProjectsOutput, ResponseMetadata), RusotoError<BatchGetProjectsError>>
+    {
+        let request = self.build_batch_get_projects_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<BatchGetBuildsOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<BatchGetProjectsOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(BatchGetBuildsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(BatchGetProjectsError::from_response(response))
         }
     }
 
-    /// <p>Gets information about one or more build projects.</p>
-    async fn batch_get_projects(
+    /// Presigns the request `batch_get_projects` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_batch_get_projects(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: BatchGetProjectsInput,
-    ) -> Result<BatchGetProjectsOutput, RusotoError<BatchGetProjectsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_batch_get_projects_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_batch_get_projects_request(&self, input: BatchGetProjectsInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "codebuild", &self.region, "/");
+        request.set_operation("BatchGetProjects");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "CodeBuild_20161006.BatchGetProjects");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `batch_get_report_groups`, also returning the metadata of the response.
+    pub async fn batch_get_report_groups_with_metadata(
+        &self,
+        input: BatchGetReportGroupsInput,
+    ) -> Result<
+        (BatchGetReportGroupsOutput, ResponseMetadata),
+        RusotoError<BatchGetReportGroupsError>,
+    > {
+        let request = self.build_batch_get_report_groups_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<BatchGetProjectsOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<BatchGetReportGroupsOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(BatchGetProjectsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(BatchGetReportGroupsError::from_response(response))
         }
     }
 
-    /// <p> Returns an array of report groups. </p>
-    async fn batch_get_report_groups(
+    /// Presigns the request `batch_get_report_groups` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_batch_get_report_groups(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: BatchGetReportGroupsInput,
-    ) -> Result<BatchGetReportGroupsOutput, RusotoError<BatchGetReportGroupsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_batch_get_report_groups_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_batch_get_report_groups_request(
+        &self,
+        input: BatchGetReportGroupsInput,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "codebuild", &self.region, "/");
+        request.set_operation("BatchGetReportGroups");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "CodeBuild_20161006.BatchGetReportGroups");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `batch_get_reports`, also returning the metadata of the response.
+    pub async fn batch_get_reports_with_metadata(
+        &self,
+        input: BatchGetReportsInput,
+    ) -> Result<(BatchGetReportsOutput, ResponseMetadata), RusotoError<BatchGetReportsError>> {
+        let request = self.build_batch_get_reports_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<BatchGetReportGroupsOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<BatchGetReportsOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(BatchGetReportGroupsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(BatchGetReportsError::from_response(response))
         }
     }
 
-    /// <p> Returns an array of reports. </p>
-    async fn batch_get_reports(
+    /// Presigns the request `batch_get_reports` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_batch_get_reports(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: BatchGetReportsInput,
-    ) -> Result<BatchGetReportsOutput, RusotoError<BatchGetReportsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_batch_get_reports_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_batch_get_reports_request(&self, input: BatchGetReportsInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "codebuild", &self.region, "/");
+        request.set_operation("BatchGetReports");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "CodeBuild_20161006.BatchGetReports");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `create_project`, also returning the metadata of the response.
+    pub async fn create_project_with_metadata(
+        &self,
+        input: CreateProjectInput,
+    ) -> Result<(CreateProjectOutput, ResponseMetadata), RusotoError<CreateProjectError>> {
+        let request = self.build_create_project_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<BatchGetReportsOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<CreateProjectOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(BatchGetReportsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(CreateProjectError::from_response(response))
         }
     }
 
-    /// <p>Creates a build project.</p>
-    async fn create_project(
+    /// Presigns the request `create_project` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_project(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: CreateProjectInput,
-    ) -> Result<CreateProjectOutput, RusotoError<CreateProjectError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_project_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_project_request(&self, input: CreateProjectInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "codebuild", &self.region, "/");
+        request.set_operation("CreateProject");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "CodeBuild_20161006.CreateProject");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `create_report_group`, also returning the metadata of the response.
+    pub async fn create_report_group_with_metadata(
+        &self,
+        input: CreateReportGroupInput,
+    ) -> Result<(CreateReportGroupOutput, ResponseMetadata), RusotoError<CreateReportGroupError>>
+    {
+        let request = self.build_create_report_group_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<CreateProjectOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<CreateReportGroupOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(CreateProjectError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(CreateReportGroupError::from_response(response))
         }
     }
 
-    /// <p> Creates a report group. A report group contains a collection of reports. </p>
-    async fn create_report_group(
+    /// Presigns the request `create_report_group` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_report_group(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: CreateReportGroupInput,
-    ) -> Result<CreateReportGroupOutput, RusotoError<CreateReportGroupError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_report_group_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_report_group_request(&self, input: CreateReportGroupInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "codebuild", &self.region, "/");
+        request.set_operation("CreateReportGroup");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "CodeBuild_20161006.CreateReportGroup");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `create_webhook`, also returning the metadata of the response.
+    pub async fn create_webhook_with_metadata(
+        &self,
+        input: CreateWebhookInput,
+    ) -> Result<(CreateWebhookOutput, ResponseMetadata), RusotoError<CreateWebhookError>> {
+        let request = self.build_create_webhook_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<CreateReportGroupOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<CreateWebhookOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(CreateReportGroupError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(CreateWebhookError::from_response(response))
         }
     }
 
-    /// <p><p>For an existing AWS CodeBuild build project that has its source code stored in a GitHub or Bitbucket repository, enables AWS CodeBuild to start rebuilding the source code every time a code change is pushed to the repository.</p> <important> <p>If you enable webhooks for an AWS CodeBuild project, and the project is used as a build step in AWS CodePipeline, then two identical builds are created for each commit. One build is triggered through webhooks, and one through AWS CodePipeline. Because billing is on a per-build basis, you are billed for both builds. Therefore, if you are using AWS CodePipeline, we recommend that you disable webhooks in AWS CodeBuild. In the AWS CodeBuild console, clear the Webhook box. For more information, see step 5 in <a href="https://docs.aws.amazon.com/codebuild/latest/userguide/change-project.html#change-project-console">Change a Build Project&#39;s Settings</a>.</p> </important></p>
-    async fn create_webhook(
+    /// Presigns the request `create_webhook` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_webhook(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: CreateWebhookInput,
-    ) -> Result<CreateWebhookOutput, RusotoError<CreateWebhookError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_webhook_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_webhook_request(&self, input: CreateWebhookInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "codebuild", &self.region, "/");
+        request.set_operation("CreateWebhook");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "CodeBuild_20161006.CreateWebhook");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `delete_project`, also returning the metadata of the response.
+    pub async fn delete_project_with_metadata(
+        &self,
+        input: DeleteProjectInput,
+    ) -> Result<(DeleteProjectOutput, ResponseMetadata), RusotoError<DeleteProjectError>> {
+        let request = self.build_delete_project_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<CreateWebhookOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DeleteProjectOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(CreateWebhookError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DeleteProjectError::from_response(response))
         }
     }
 
-    /// <p> Deletes a build project. When you delete a project, its builds are not deleted. </p>
-    async fn delete_project(
+    /// Presigns the request `delete_project` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_project(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteProjectInput,
-    ) -> Result<DeleteProjectOutput, RusotoError<DeleteProjectError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_project_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_project_request(&self, input: DeleteProjectInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "codebuild", &self.region, "/");
+        request.set_operation("DeleteProject");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "CodeBuild_20161006.DeleteProject");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `delete_report`, also returning the metadata of the response.
+    pub async fn delete_report_with_metadata(
+        &self,
+        input: DeleteReportInput,
+    ) -> Result<(DeleteReportOutput, ResponseMetadata), RusotoError<DeleteReportError>> {
+        let request = self.build_delete_report_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<DeleteProjectOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DeleteReportOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteProjectError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DeleteReportError::from_response(response))
         }
     }
 
-    /// <p> Deletes a report. </p>
-    async fn delete_report(
+    /// Presigns the request `delete_report` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_report(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteReportInput,
-    ) -> Result<DeleteReportOutput, RusotoError<DeleteReportError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_report_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_report_request(&self, input: DeleteReportInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "codebuild", &self.region, "/");
+        request.set_operation("DeleteReport");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "CodeBuild_20161006.DeleteReport");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `delete_report_group`, also returning the metadata of the response.
+    pub async fn delete_report_group_with_metadata(
+        &self,
+        input: DeleteReportGroupInput,
+    ) -> Result<(DeleteReportGroupOutput, ResponseMetadata), RusotoError<DeleteReportGroupError>>
+    {
+        let request = self.build_delete_report_group_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<DeleteReportOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DeleteReportGroupOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteReportError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DeleteReportGroupError::from_response(response))
         }
     }
 
-    /// <p> <code>DeleteReportGroup</code>: Deletes a report group. Before you delete a report group, you must delete its reports. Use <a href="https://docs.aws.amazon.com/codebuild/latest/APIReference/API_ListReportsForReportGroup.html">ListReportsForReportGroup</a> to get the reports in a report group. Use <a href="https://docs.aws.amazon.com/codebuild/latest/APIReference/API_DeleteReport.html">DeleteReport</a> to delete the reports. If you call <code>DeleteReportGroup</code> for a report group that contains one or more reports, an exception is thrown. </p>
-    async fn delete_report_group(
+    /// Presigns the request `delete_report_group` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_report_group(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteReportGroupInput,
-    ) -> Result<DeleteReportGroupOutput, RusotoError<DeleteReportGroupError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_report_group_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_report_group_request(&self, input: DeleteReportGroupInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "codebuild", &self.region, "/");
+        request.set_operation("DeleteReportGroup");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "CodeBuild_20161006.DeleteReportGroup");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `delete_resource_policy`, also returning the metadata of the response.
+    pub async fn delete_resource_policy_with_metadata(
+        &self,
+        input: DeleteResourcePolicyInput,
+    ) -> Result<
+        (DeleteResourcePolicyOutput, ResponseMetadata),
+        RusotoError<DeleteResourcePolicyError>,
+    > {
+        let request = self.build_delete_resource_policy_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<DeleteReportGroupOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DeleteResourcePolicyOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteReportGroupError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DeleteResourcePolicyError::from_response(response))
         }
     }
 
-    /// <p> Deletes a resource policy that is identified by its resource ARN. </p>
-    async fn delete_resource_policy(
+    /// Presigns the request `delete_resource_policy` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_resource_policy(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteResourcePolicyInput,
-    ) -> Result<DeleteResourcePolicyOutput, RusotoError<DeleteResourcePolicyError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_resource_policy_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_resource_policy_request(
+        &self,
+        input: DeleteResourcePolicyInput,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "codebuild", &self.region, "/");
+        request.set_operation("DeleteResourcePolicy");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "CodeBuild_20161006.DeleteResourcePolicy");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `delete_source_credentials`, also returning the metadata of the response.
+    pub async fn delete_source_credentials_with_metadata(
+        &self,
+        input: DeleteSourceCredentialsInput,
+    ) -> Result<
+        (DeleteSourceCredentialsOutput, ResponseMetadata),
+        RusotoError<DeleteSourceCredentialsError>,
+    > {
+        let request = self.build_delete_source_credentials_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DeleteResourcePolicyOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DeleteSourceCredentialsOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteResourcePolicyError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DeleteSourceCredentialsError::from_response(response))
         }
     }
 
-    /// <p> Deletes a set of GitHub, GitHub Enterprise, or Bitbucket source credentials. </p>
-    async fn delete_source_credentials(
+    /// Presigns the request `delete_source_credentials` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_source_credentials(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteSourceCredentialsInput,
-    ) -> Result<DeleteSourceCredentialsOutput, RusotoError<DeleteSourceCredentialsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_source_credentials_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_source_credentials_request(
+        &self,
+        input: DeleteSourceCredentialsInput,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "codebuild", &self.region, "/");
+        request.set_operation("DeleteSourceCredentials");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "CodeBuild_20161006.DeleteSourceCredentials");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `delete_webhook`, also returning the metadata of the response.
+    pub async fn delete_webhook_with_metadata(
+        &self,
+        input: DeleteWebhookInput,
+    ) -> Result<(DeleteWebhookOutput, ResponseMetadata), RusotoError<DeleteWebhookError>> {
+        let request = self.build_delete_webhook_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DeleteSourceCredentialsOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DeleteWebhookOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteSourceCredentialsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DeleteWebhookError::from_response(response))
         }
     }
 
-    /// <p>For an existing AWS CodeBuild build project that has its source code stored in a GitHub or Bitbucket repository, stops AWS CodeBuild from rebuilding the source code every time a code change is pushed to the repository.</p>
-    async fn delete_webhook(
+    /// Presigns the request `delete_webhook` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_webhook(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteWebhookInput,
-    ) -> Result<DeleteWebhookOutput, RusotoError<DeleteWebhookError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_webhook_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_webhook_request(&self, input: DeleteWebhookInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "codebuild", &self.region, "/");
+        request.set_operation("DeleteWebhook");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "CodeBuild_20161006.DeleteWebhook");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `describe_test_cases`, also returning the metadata of the response.
+    pub async fn describe_test_cases_with_metadata(
+        &self,
+        input: DescribeTestCasesInput,
+    ) -> Result<(DescribeTestCasesOutput, ResponseMetadata), RusotoError<DescribeTestCasesError>>
+    {
+        let request = self.build_describe_test_cases_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<DeleteWebhookOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DescribeTestCasesOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteWebhookError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DescribeTestCasesError::from_response(response))
         }
     }
 
-    /// <p> Returns a list of details about test cases for a report. </p>
-    async fn describe_test_cases(
+    /// Presigns the request `describe_test_cases` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_test_cases(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeTestCasesInput,
-    ) -> Result<DescribeTestCasesOutput, RusotoError<DescribeTestCasesError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_test_cases_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_test_cases_request(&self, input: DescribeTestCasesInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "codebuild", &self.region, "/");
+        request.set_operation("DescribeTestCases");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "CodeBuild_20161006.DescribeTestCases");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `get_resource_policy`, also returning the metadata of the response.
+    pub async fn get_resource_policy_with_metadata(
+        &self,
+        input: GetResourcePolicyInput,
+    ) -> Result<(GetResourcePolicyOutput, ResponseMetadata), RusotoError<GetResourcePolicyError>>
+    {
+        let request = self.build_get_resource_policy_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<DescribeTestCasesOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<GetResourcePolicyOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeTestCasesError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(GetResourcePolicyError::from_response(response))
         }
     }
 
-    /// <p> Gets a resource policy that is identified by its resource ARN. </p>
-    async fn get_resource_policy(
+    /// Presigns the request `get_resource_policy` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_resource_policy(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetResourcePolicyInput,
-    ) -> Result<GetResourcePolicyOutput, RusotoError<GetResourcePolicyError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_resource_policy_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_resource_policy_request(&self, input: GetResourcePolicyInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "codebuild", &self.region, "/");
+        request.set_operation("GetResourcePolicy");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "CodeBuild_20161006.GetResourcePolicy");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `import_source_credentials`, also returning the metadata of the response.
+    pub async fn import_source_credentials_with_metadata(
+        &self,
+        input: ImportSourceCredentialsInput,
+    ) -> Result<
+        (ImportSourceCredentialsOutput, ResponseMetadata),
+        RusotoError<ImportSourceCredentialsError>,
+    > {
+        let request = self.build_import_source_credentials_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<GetResourcePolicyOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ImportSourceCredentialsOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(GetResourcePolicyError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ImportSourceCredentialsError::from_response(response))
         }
     }
 
-    /// <p> Imports the source repository credentials for an AWS CodeBuild project that has its source code stored in a GitHub, GitHub Enterprise, or Bitbucket repository. </p>
-    async fn import_source_credentials(
+    /// Presigns the request `import_source_credentials` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_import_source_credentials(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ImportSourceCredentialsInput,
-    ) -> Result<ImportSourceCredentialsOutput, RusotoError<ImportSourceCredentialsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_import_source_credentials_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_import_source_credentials_request(
+        &self,
+        input: ImportSourceCredentialsInput,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "codebuild", &self.region, "/");
+        request.set_operation("ImportSourceCredentials");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "CodeBuild_20161006.ImportSourceCredentials");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `invalidate_project_cache`, also returning the metadata of the response.
+    pub async fn invalidate_project_cache_with_metadata(
+        &self,
+        input: InvalidateProjectCacheInput,
+    ) -> Result<
+        (InvalidateProjectCacheOutput, ResponseMetadata),
+        RusotoError<InvalidateProjectCacheError>,
+    > {
+        let request = self.build_invalidate_project_cache_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<ImportSourceCredentialsOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<InvalidateProjectCacheOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ImportSourceCredentialsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(InvalidateProjectCacheError::from_response(response))
         }
     }
 
-    /// <p>Resets the cache for a project.</p>
-    async fn invalidate_project_cache(
+    /// Presigns the request `invalidate_project_cache` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_invalidate_project_cache(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: InvalidateProjectCacheInput,
-    ) -> Result<InvalidateProjectCacheOutput, RusotoError<InvalidateProjectCacheError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_invalidate_project_cache_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_invalidate_project_cache_request(
+        &self,
+        input: InvalidateProjectCacheInput,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "codebuild", &self.region, "/");
+        request.set_operation("InvalidateProjectCache");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "CodeBuild_20161006.InvalidateProjectCache");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `list_builds`, also returning the metadata of the response.
+    pub async fn list_builds_with_metadata(
+        &self,
+        input: ListBuildsInput,
+    ) -> Result<(ListBuildsOutput, ResponseMetadata), RusotoError<ListBuildsError>> {
+        let request = self.build_list_builds_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<InvalidateProjectCacheOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ListBuildsOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(InvalidateProjectCacheError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ListBuildsError::from_response(response))
         }
     }
 
-    /// <p>Gets a list of build IDs, with each build ID representing a single build.</p>
-    async fn list_builds(
+    /// Presigns the request `list_builds` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_builds(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListBuildsInput,
-    ) -> Result<ListBuildsOutput, RusotoError<ListBuildsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_builds_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_builds_request(&self, input: ListBuildsInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "codebuild", &self.region, "/");
+        request.set_operation("ListBuilds");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "CodeBuild_20161006.ListBuilds");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `list_builds_for_project`, also returning the metadata of the response.
+    pub async fn list_builds_for_project_with_metadata(
+        &self,
+        input: ListBuildsForProjectInput,
+    ) -> Result<
+        (ListBuildsForProjectOutput, ResponseMetadata),
+        RusotoError<ListBuildsForProjectError>,
+    > {
+        let request = self.build_list_builds_for_project_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<ListBuildsOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ListBuildsForProjectOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ListBuildsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ListBuildsForProjectError::from_response(response))
         }
     }
 
-    /// <p>Gets a list of build IDs for the specified build project, with each build ID representing a single build.</p>
-    async fn list_builds_for_project(
+    /// Presigns the request `list_builds_for_project` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_builds_for_project(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListBuildsForProjectInput,
-    ) -> Result<ListBuildsForProjectOutput, RusotoError<ListBuildsForProjectError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_builds_for_project_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_builds_for_project_request(
+        &self,
+        input: ListBuildsForProjectInput,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "codebuild", &self.region, "/");
+        request.set_operation("ListBuildsForProject");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "CodeBuild_20161006.ListBuildsForProject");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `list_curated_environment_images`, also returning the metadata of the response.
+    pub async fn list_curated_environment_images_with_metadata(
+        &self,
+    ) -> Result<
+        (ListCuratedEnvironmentImagesOutput, ResponseMetadata),
+        RusotoError<ListCuratedEnvironmentImagesError>,
+    > {
+        let request = self.build_list_curated_environment_images_request();
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListBuildsForProjectOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ListCuratedEnvironmentImagesOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ListBuildsForProjectError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ListCuratedEnvironmentImagesError::from_response(response))
         }
     }
 
-    /// <p>Gets information about Docker images that are managed by AWS CodeBuild.</p>
-    async fn list_curated_environment_images(
+    /// Presigns the request `list_curated_environment_images` would send with `presigner` instead of sending it.
+    pub async fn presign_list_curated_environment_images(
         &self,
-    ) -> Result<ListCuratedEnvironmentImagesOutput, RusotoError<ListCuratedEnvironmentImagesError>>
-    {
+        presigner: &rusoto_core::Presigner,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_curated_environment_images_request();
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_curated_environment_images_request(&self) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "codebuild", &self.region, "/");
+        request.set_operation("ListCuratedEnvironmentImages");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -3944,109 +4766,215 @@ impl CodeBuild for CodeBuildClient {
         );
         request.set_payload(Some(bytes::Bytes::from_static(b"{}")));
 
+        request
+    }
+
+    /// Calls `list_projects`, also returning the metadata of the response.
+    pub async fn list_projects_with_metadata(
+        &self,
+        input: ListProjectsInput,
+    ) -> Result<(ListProjectsOutput, ResponseMetadata), RusotoError<ListProjectsError>> {
+        let request = self.build_list_projects_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListCuratedEnvironmentImagesOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ListProjectsOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ListCuratedEnvironmentImagesError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ListProjectsError::from_response(response))
         }
     }
 
-    /// <p>Gets a list of build project names, with each build project name representing a single build project.</p>
-    async fn list_projects(
+    /// Presigns the request `list_projects` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_projects(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListProjectsInput,
-    ) -> Result<ListProjectsOutput, RusotoError<ListProjectsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_projects_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_projects_request(&self, input: ListProjectsInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "codebuild", &self.region, "/");
+        request.set_operation("ListProjects");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "CodeBuild_20161006.ListProjects");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `list_report_groups`, also returning the metadata of the response.
+    pub async fn list_report_groups_with_metadata(
+        &self,
+        input: ListReportGroupsInput,
+    ) -> Result<(ListReportGroupsOutput, ResponseMetadata), RusotoError<ListReportGroupsError>>
+    {
+        let request = self.build_list_report_groups_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<ListProjectsOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ListReportGroupsOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ListProjectsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ListReportGroupsError::from_response(response))
         }
     }
 
-    /// <p> Gets a list ARNs for the report groups in the current AWS account. </p>
-    async fn list_report_groups(
+    /// Presigns the request `list_report_groups` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_report_groups(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListReportGroupsInput,
-    ) -> Result<ListReportGroupsOutput, RusotoError<ListReportGroupsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_report_groups_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_report_groups_request(&self, input: ListReportGroupsInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "codebuild", &self.region, "/");
+        request.set_operation("ListReportGroups");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "CodeBuild_20161006.ListReportGroups");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `list_reports`, also returning the metadata of the response.
+    pub async fn list_reports_with_metadata(
+        &self,
+        input: ListReportsInput,
+    ) -> Result<(ListReportsOutput, ResponseMetadata), RusotoError<ListReportsError>> {
+        let request = self.build_list_reports_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<ListReportGroupsOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ListReportsOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ListReportGroupsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ListReportsError::from_response(response))
         }
     }
 
-    /// <p> Returns a list of ARNs for the reports in the current AWS account. </p>
-    async fn list_reports(
+    /// Presigns the request `list_reports` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_reports(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListReportsInput,
-    ) -> Result<ListReportsOutput, RusotoError<ListReportsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_reports_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_reports_request(&self, input: ListReportsInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "codebuild", &self.region, "/");
+        request.set_operation("ListReports");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "CodeBuild_20161006.ListReports");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `list_reports_for_report_group`, also returning the metadata of the response.
+    pub async fn list_reports_for_report_group_with_metadata(
+        &self,
+        input: ListReportsForReportGroupInput,
+    ) -> Result<
+        (ListReportsForReportGroupOutput, ResponseMetadata),
+        RusotoError<ListReportsForReportGroupError>,
+    > {
+        let request = self.build_list_reports_for_report_group_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<ListReportsOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ListReportsForReportGroupOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ListReportsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ListReportsForReportGroupError::from_response(response))
         }
     }
 
-    /// <p> Returns a list of ARNs for the reports that belong to a <code>ReportGroup</code>. </p>
-    async fn list_reports_for_report_group(
+    /// Presigns the request `list_reports_for_report_group` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_reports_for_report_group(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListReportsForReportGroupInput,
-    ) -> Result<ListReportsForReportGroupOutput, RusotoError<ListReportsForReportGroupError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_reports_for_report_group_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_reports_for_report_group_request(
+        &self,
+        input: ListReportsForReportGroupInput,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "codebuild", &self.region, "/");
+        request.set_operation("ListReportsForReportGroup");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -4056,263 +4984,1153 @@ impl CodeBuild for CodeBuildClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `list_shared_projects`, also returning the metadata of the response.
+    pub async fn list_shared_projects_with_metadata(
+        &self,
+        input: ListSharedProjectsInput,
+    ) -> Result<(ListSharedProjectsOutput, ResponseMetadata), RusotoError<ListSharedProjectsError>>
+    {
+        let request = self.build_list_shared_projects_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListReportsForReportGroupOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ListSharedProjectsOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ListReportsForReportGroupError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ListSharedProjectsError::from_response(response))
         }
     }
 
-    /// <p> Gets a list of projects that are shared with other AWS accounts or users. </p>
-    async fn list_shared_projects(
+    /// Presigns the request `list_shared_projects` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_shared_projects(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListSharedProjectsInput,
-    ) -> Result<ListSharedProjectsOutput, RusotoError<ListSharedProjectsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_shared_projects_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_shared_projects_request(&self, input: ListSharedProjectsInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "codebuild", &self.region, "/");
+        request.set_operation("ListSharedProjects");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "CodeBuild_20161006.ListSharedProjects");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `list_shared_report_groups`, also returning the metadata of the response.
+    pub async fn list_shared_report_groups_with_metadata(
+        &self,
+        input: ListSharedReportGroupsInput,
+    ) -> Result<
+        (ListSharedReportGroupsOutput, ResponseMetadata),
+        RusotoError<ListSharedReportGroupsError>,
+    > {
+        let request = self.build_list_shared_report_groups_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListSharedProjectsOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ListSharedReportGroupsOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ListSharedProjectsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ListSharedReportGroupsError::from_response(response))
         }
     }
 
-    /// <p> Gets a list of report groups that are shared with other AWS accounts or users. </p>
-    async fn list_shared_report_groups(
+    /// Presigns the request `list_shared_report_groups` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_shared_report_groups(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListSharedReportGroupsInput,
-    ) -> Result<ListSharedReportGroupsOutput, RusotoError<ListSharedReportGroupsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_shared_report_groups_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_shared_report_groups_request(
+        &self,
+        input: ListSharedReportGroupsInput,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "codebuild", &self.region, "/");
+        request.set_operation("ListSharedReportGroups");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "CodeBuild_20161006.ListSharedReportGroups");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `list_source_credentials`, also returning the metadata of the response.
+    pub async fn list_source_credentials_with_metadata(
+        &self,
+    ) -> Result<
+        (ListSourceCredentialsOutput, ResponseMetadata),
+        RusotoError<ListSourceCredentialsError>,
+    > {
+        let request = self.build_list_source_credentials_request();
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListSharedReportGroupsOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ListSourceCredentialsOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ListSharedReportGroupsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ListSourceCredentialsError::from_response(response))
         }
     }
 
-    /// <p> Returns a list of <code>SourceCredentialsInfo</code> objects. </p>
-    async fn list_source_credentials(
+    /// Presigns the request `list_source_credentials` would send with `presigner` instead of sending it.
+    pub async fn presign_list_source_credentials(
         &self,
-    ) -> Result<ListSourceCredentialsOutput, RusotoError<ListSourceCredentialsError>> {
+        presigner: &rusoto_core::Presigner,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_source_credentials_request();
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_source_credentials_request(&self) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "codebuild", &self.region, "/");
+        request.set_operation("ListSourceCredentials");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "CodeBuild_20161006.ListSourceCredentials");
         request.set_payload(Some(bytes::Bytes::from_static(b"{}")));
 
+        request
+    }
+
+    /// Calls `put_resource_policy`, also returning the metadata of the response.
+    pub async fn put_resource_policy_with_metadata(
+        &self,
+        input: PutResourcePolicyInput,
+    ) -> Result<(PutResourcePolicyOutput, ResponseMetadata), RusotoError<PutResourcePolicyError>>
+    {
+        let request = self.build_put_resource_policy_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListSourceCredentialsOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<PutResourcePolicyOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ListSourceCredentialsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(PutResourcePolicyError::from_response(response))
         }
     }
 
-    /// <p> Stores a resource policy for the ARN of a <code>Project</code> or <code>ReportGroup</code> object. </p>
-    async fn put_resource_policy(
+    /// Presigns the request `put_resource_policy` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_put_resource_policy(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: PutResourcePolicyInput,
-    ) -> Result<PutResourcePolicyOutput, RusotoError<PutResourcePolicyError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_put_resource_policy_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_put_resource_policy_request(&self, input: PutResourcePolicyInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "codebuild", &self.region, "/");
+        request.set_operation("PutResourcePolicy");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "CodeBuild_20161006.PutResourcePolicy");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `start_build`, also returning the metadata of the response.
+    pub async fn start_build_with_metadata(
+        &self,
+        input: StartBuildInput,
+    ) -> Result<(StartBuildOutput, ResponseMetadata), RusotoError<StartBuildError>> {
+        let request = self.build_start_build_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<PutResourcePolicyOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<StartBuildOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(PutResourcePolicyError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(StartBuildError::from_response(response))
         }
     }
 
-    /// <p>Starts running a build.</p>
-    async fn start_build(
+    /// Presigns the request `start_build` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_start_build(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: StartBuildInput,
-    ) -> Result<StartBuildOutput, RusotoError<StartBuildError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_start_build_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_start_build_request(&self, input: StartBuildInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "codebuild", &self.region, "/");
+        request.set_operation("StartBuild");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "CodeBuild_20161006.StartBuild");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `stop_build`, also returning the metadata of the response.
+    pub async fn stop_build_with_metadata(
+        &self,
+        input: StopBuildInput,
+    ) -> Result<(StopBuildOutput, ResponseMetadata), RusotoError<StopBuildError>> {
+        let request = self.build_stop_build_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<StartBuildOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result =
+                proto::json::ResponsePayload::new(&response).deserialize::<StopBuildOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(StartBuildError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(StopBuildError::from_response(response))
         }
     }
 
-    /// <p>Attempts to stop running a build.</p>
-    async fn stop_build(
+    /// Presigns the request `stop_build` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_stop_build(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: StopBuildInput,
-    ) -> Result<StopBuildOutput, RusotoError<StopBuildError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_stop_build_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_stop_build_request(&self, input: StopBuildInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "codebuild", &self.region, "/");
+        request.set_operation("StopBuild");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "CodeBuild_20161006.StopBuild");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `update_project`, also returning the metadata of the response.
+    pub async fn update_project_with_metadata(
+        &self,
+        input: UpdateProjectInput,
+    ) -> Result<(UpdateProjectOutput, ResponseMetadata), RusotoError<UpdateProjectError>> {
+        let request = self.build_update_project_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<StopBuildOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<UpdateProjectOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(StopBuildError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(UpdateProjectError::from_response(response))
         }
     }
 
-    /// <p>Changes the settings of a build project.</p>
-    async fn update_project(
+    /// Presigns the request `update_project` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_project(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UpdateProjectInput,
-    ) -> Result<UpdateProjectOutput, RusotoError<UpdateProjectError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_project_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_project_request(&self, input: UpdateProjectInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "codebuild", &self.region, "/");
+        request.set_operation("UpdateProject");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "CodeBuild_20161006.UpdateProject");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `update_report_group`, also returning the metadata of the response.
+    pub async fn update_report_group_with_metadata(
+        &self,
+        input: UpdateReportGroupInput,
+    ) -> Result<(UpdateReportGroupOutput, ResponseMetadata), RusotoError<UpdateReportGroupError>>
+    {
+        let request = self.build_update_report_group_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<UpdateProjectOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<UpdateReportGroupOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(UpdateProjectError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(UpdateReportGroupError::from_response(response))
         }
     }
 
-    /// <p> Updates a report group. </p>
-    async fn update_report_group(
+    /// Presigns the request `update_report_group` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_report_group(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UpdateReportGroupInput,
-    ) -> Result<UpdateReportGroupOutput, RusotoError<UpdateReportGroupError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_report_group_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_report_group_request(&self, input: UpdateReportGroupInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "codebuild", &self.region, "/");
+        request.set_operation("UpdateReportGroup");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "CodeBuild_20161006.UpdateReportGroup");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `update_webhook`, also returning the metadata of the response.
+    pub async fn update_webhook_with_metadata(
+        &self,
+        input: UpdateWebhookInput,
+    ) -> Result<(UpdateWebhookOutput, ResponseMetadata), RusotoError<UpdateWebhookError>> {
+        let request = self.build_update_webhook_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<UpdateReportGroupOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<UpdateWebhookOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(UpdateReportGroupError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(UpdateWebhookError::from_response(response))
         }
     }
 
-    /// <p><p> Updates the webhook associated with an AWS CodeBuild build project. </p> <note> <p> If you use Bitbucket for your repository, <code>rotateSecret</code> is ignored. </p> </note></p>
-    async fn update_webhook(
+    /// Presigns the request `update_webhook` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_webhook(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UpdateWebhookInput,
-    ) -> Result<UpdateWebhookOutput, RusotoError<UpdateWebhookError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_webhook_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_webhook_request(&self, input: UpdateWebhookInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "codebuild", &self.region, "/");
+        request.set_operation("UpdateWebhook");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "CodeBuild_20161006.UpdateWebhook");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
-        let mut response = self
-            .client
-            .sign_and_dispatch(request)
-            .await
-            .map_err(RusotoError::from)?;
-        if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<UpdateWebhookOutput, _>()
-        } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(UpdateWebhookError::from_response(response))
-        }
+        request
+    }
+
+    /// The pages of `DescribeTestCases` results for `input`, requesting each next page with the `nextToken` of the last one as its `nextToken`.
+    /// The page size is the `maxResults` of `input`.
+    pub fn describe_test_cases_pages(
+        &self,
+        input: DescribeTestCasesInput,
+    ) -> impl futures::Stream<
+        Item = Result<DescribeTestCasesOutput, RusotoError<DescribeTestCasesError>>,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: CodeBuildClient, input| async move { client.describe_test_cases(input).await },
+            |input: &DescribeTestCasesInput, output: &DescribeTestCasesOutput| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(DescribeTestCasesInput {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `testCases` of every page of `DescribeTestCases` results for `input`, as `describe_test_cases_pages` requests them.
+    pub fn describe_test_cases_stream(
+        &self,
+        input: DescribeTestCasesInput,
+    ) -> impl futures::Stream<Item = Result<TestCase, RusotoError<DescribeTestCasesError>>> + Send
+    {
+        rusoto_core::pagination::items(self.describe_test_cases_pages(input), |page| {
+            page.test_cases.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `ListBuilds` results for `input`, requesting each next page with the `nextToken` of the last one as its `nextToken`.
+    pub fn list_builds_pages(
+        &self,
+        input: ListBuildsInput,
+    ) -> impl futures::Stream<Item = Result<ListBuildsOutput, RusotoError<ListBuildsError>>> + Send
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: CodeBuildClient, input| async move { client.list_builds(input).await },
+            |input: &ListBuildsInput, output: &ListBuildsOutput| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListBuildsInput {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `ids` of every page of `ListBuilds` results for `input`, as `list_builds_pages` requests them.
+    pub fn list_builds_stream(
+        &self,
+        input: ListBuildsInput,
+    ) -> impl futures::Stream<Item = Result<String, RusotoError<ListBuildsError>>> + Send {
+        rusoto_core::pagination::items(self.list_builds_pages(input), |page| {
+            page.ids.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `ListBuildsForProject` results for `input`, requesting each next page with the `nextToken` of the last one as its `nextToken`.
+    pub fn list_builds_for_project_pages(
+        &self,
+        input: ListBuildsForProjectInput,
+    ) -> impl futures::Stream<
+        Item = Result<ListBuildsForProjectOutput, RusotoError<ListBuildsForProjectError>>,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: CodeBuildClient, input| async move { client.list_builds_for_project(input).await },
+            |input: &ListBuildsForProjectInput, output: &ListBuildsForProjectOutput| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListBuildsForProjectInput {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `ids` of every page of `ListBuildsForProject` results for `input`, as `list_builds_for_project_pages` requests them.
+    pub fn list_builds_for_project_stream(
+        &self,
+        input: ListBuildsForProjectInput,
+    ) -> impl futures::Stream<Item = Result<String, RusotoError<ListBuildsForProjectError>>> + Send
+    {
+        rusoto_core::pagination::items(self.list_builds_for_project_pages(input), |page| {
+            page.ids.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `ListProjects` results for `input`, requesting each next page with the `nextToken` of the last one as its `nextToken`.
+    pub fn list_projects_pages(
+        &self,
+        input: ListProjectsInput,
+    ) -> impl futures::Stream<Item = Result<ListProjectsOutput, RusotoError<ListProjectsError>>> + Send
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: CodeBuildClient, input| async move { client.list_projects(input).await },
+            |input: &ListProjectsInput, output: &ListProjectsOutput| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListProjectsInput {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `projects` of every page of `ListProjects` results for `input`, as `list_projects_pages` requests them.
+    pub fn list_projects_stream(
+        &self,
+        input: ListProjectsInput,
+    ) -> impl futures::Stream<Item = Result<String, RusotoError<ListProjectsError>>> + Send {
+        rusoto_core::pagination::items(self.list_projects_pages(input), |page| {
+            page.projects.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `ListReportGroups` results for `input`, requesting each next page with the `nextToken` of the last one as its `nextToken`.
+    /// The page size is the `maxResults` of `input`.
+    pub fn list_report_groups_pages(
+        &self,
+        input: ListReportGroupsInput,
+    ) -> impl futures::Stream<
+        Item = Result<ListReportGroupsOutput, RusotoError<ListReportGroupsError>>,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: CodeBuildClient, input| async move { client.list_report_groups(input).await },
+            |input: &ListReportGroupsInput, output: &ListReportGroupsOutput| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListReportGroupsInput {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `reportGroups` of every page of `ListReportGroups` results for `input`, as `list_report_groups_pages` requests them.
+    pub fn list_report_groups_stream(
+        &self,
+        input: ListReportGroupsInput,
+    ) -> impl futures::Stream<Item = Result<String, RusotoError<ListReportGroupsError>>> + Send
+    {
+        rusoto_core::pagination::items(self.list_report_groups_pages(input), |page| {
+            page.report_groups.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `ListReports` results for `input`, requesting each next page with the `nextToken` of the last one as its `nextToken`.
+    /// The page size is the `maxResults` of `input`.
+    pub fn list_reports_pages(
+        &self,
+        input: ListReportsInput,
+    ) -> impl futures::Stream<Item = Result<ListReportsOutput, RusotoError<ListReportsError>>> + Send
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: CodeBuildClient, input| async move { client.list_reports(input).await },
+            |input: &ListReportsInput, output: &ListReportsOutput| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListReportsInput {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `reports` of every page of `ListReports` results for `input`, as `list_reports_pages` requests them.
+    pub fn list_reports_stream(
+        &self,
+        input: ListReportsInput,
+    ) -> impl futures::Stream<Item = Result<String, RusotoError<ListReportsError>>> + Send {
+        rusoto_core::pagination::items(self.list_reports_pages(input), |page| {
+            page.reports.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `ListReportsForReportGroup` results for `input`, requesting each next page with the `nextToken` of the last one as its `nextToken`.
+    /// The page size is the `maxResults` of `input`.
+    pub fn list_reports_for_report_group_pages(
+        &self,
+        input: ListReportsForReportGroupInput,
+    ) -> impl futures::Stream<
+        Item = Result<ListReportsForReportGroupOutput, RusotoError<ListReportsForReportGroupError>>,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: CodeBuildClient, input| async move {
+                client.list_reports_for_report_group(input).await
+            },
+            |input: &ListReportsForReportGroupInput, output: &ListReportsForReportGroupOutput| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListReportsForReportGroupInput {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `reports` of every page of `ListReportsForReportGroup` results for `input`, as `list_reports_for_report_group_pages` requests them.
+    pub fn list_reports_for_report_group_stream(
+        &self,
+        input: ListReportsForReportGroupInput,
+    ) -> impl futures::Stream<Item = Result<String, RusotoError<ListReportsForReportGroupError>>> + Send
+    {
+        rusoto_core::pagination::items(self.list_reports_for_report_group_pages(input), |page| {
+            page.reports.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `ListSharedProjects` results for `input`, requesting each next page with the `nextToken` of the last one as its `nextToken`.
+    /// The page size is the `maxResults` of `input`.
+    pub fn list_shared_projects_pages(
+        &self,
+        input: ListSharedProjectsInput,
+    ) -> impl futures::Stream<
+        Item = Result<ListSharedProjectsOutput, RusotoError<ListSharedProjectsError>>,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: CodeBuildClient, input| async move { client.list_shared_projects(input).await },
+            |input: &ListSharedProjectsInput, output: &ListSharedProjectsOutput| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListSharedProjectsInput {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `projects` of every page of `ListSharedProjects` results for `input`, as `list_shared_projects_pages` requests them.
+    pub fn list_shared_projects_stream(
+        &self,
+        input: ListSharedProjectsInput,
+    ) -> impl futures::Stream<Item = Result<String, RusotoError<ListSharedProjectsError>>> + Send
+    {
+        rusoto_core::pagination::items(self.list_shared_projects_pages(input), |page| {
+            page.projects.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `ListSharedReportGroups` results for `input`, requesting each next page with the `nextToken` of the last one as its `nextToken`.
+    /// The page size is the `maxResults` of `input`.
+    pub fn list_shared_report_groups_pages(
+        &self,
+        input: ListSharedReportGroupsInput,
+    ) -> impl futures::Stream<
+        Item = Result<ListSharedReportGroupsOutput, RusotoError<ListSharedReportGroupsError>>,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: CodeBuildClient, input| async move {
+                client.list_shared_report_groups(input).await
+            },
+            |input: &ListSharedReportGroupsInput, output: &ListSharedReportGroupsOutput| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListSharedReportGroupsInput {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `reportGroups` of every page of `ListSharedReportGroups` results for `input`, as `list_shared_report_groups_pages` requests them.
+    pub fn list_shared_report_groups_stream(
+        &self,
+        input: ListSharedReportGroupsInput,
+    ) -> impl futures::Stream<Item = Result<String, RusotoError<ListSharedReportGroupsError>>> + Send
+    {
+        rusoto_core::pagination::items(self.list_shared_report_groups_pages(input), |page| {
+            page.report_groups.unwrap_or_default()
+        })
+    }
+}
+
+#[async_trait]
+impl CodeBuild for CodeBuildClient {
+    /// <p>Deletes one or more builds.</p>
+    async fn batch_delete_builds(
+        &self,
+        input: BatchDeleteBuildsInput,
+    ) -> Result<BatchDeleteBuildsOutput, RusotoError<BatchDeleteBuildsError>> {
+        self.batch_delete_builds_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Gets information about one or more builds.</p>
+    async fn batch_get_builds(
+        &self,
+        input: BatchGetBuildsInput,
+    ) -> Result<BatchGetBuildsOutput, RusotoError<BatchGetBuildsError>> {
+        self.batch_get_builds_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Gets information about one or more build projects.</p>
+    async fn batch_get_projects(
+        &self,
+        input: BatchGetProjectsInput,
+    ) -> Result<BatchGetProjectsOutput, RusotoError<BatchGetProjectsError>> {
+        self.batch_get_projects_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p> Returns an array of report groups. </p>
+    async fn batch_get_report_groups(
+        &self,
+        input: BatchGetReportGroupsInput,
+    ) -> Result<BatchGetReportGroupsOutput, RusotoError<BatchGetReportGroupsError>> {
+        self.batch_get_report_groups_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p> Returns an array of reports. </p>
+    async fn batch_get_reports(
+        &self,
+        input: BatchGetReportsInput,
+    ) -> Result<BatchGetReportsOutput, RusotoError<BatchGetReportsError>> {
+        self.batch_get_reports_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Creates a build project.</p>
+    async fn create_project(
+        &self,
+        input: CreateProjectInput,
+    ) -> Result<CreateProjectOutput, RusotoError<CreateProjectError>> {
+        self.create_project_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p> Creates a report group. A report group contains a collection of reports. </p>
+    async fn create_report_group(
+        &self,
+        input: CreateReportGroupInput,
+    ) -> Result<CreateReportGroupOutput, RusotoError<CreateReportGroupError>> {
+        self.create_report_group_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>For an existing AWS CodeBuild build project that has its source code stored in a GitHub or Bitbucket repository, enables AWS CodeBuild to start rebuilding the source code every time a code change is pushed to the repository.</p> <important> <p>If you enable webhooks for an AWS CodeBuild project, and the project is used as a build step in AWS CodePipeline, then two identical builds are created for each commit. One build is triggered through webhooks, and one through AWS CodePipeline. Because billing is on a per-build basis, you are billed for both builds. Therefore, if you are using AWS CodePipeline, we recommend that you disable webhooks in AWS CodeBuild. In the AWS CodeBuild console, clear the Webhook box. For more information, see step 5 in <a href="https://docs.aws.amazon.com/codebuild/latest/userguide/change-project.html#change-project-console">Change a Build Project&#39;s Settings</a>.</p> </important></p>
+    async fn create_webhook(
+        &self,
+        input: CreateWebhookInput,
+    ) -> Result<CreateWebhookOutput, RusotoError<CreateWebhookError>> {
+        self.create_webhook_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p> Deletes a build project. When you delete a project, its builds are not deleted. </p>
+    async fn delete_project(
+        &self,
+        input: DeleteProjectInput,
+    ) -> Result<DeleteProjectOutput, RusotoError<DeleteProjectError>> {
+        self.delete_project_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p> Deletes a report. </p>
+    async fn delete_report(
+        &self,
+        input: DeleteReportInput,
+    ) -> Result<DeleteReportOutput, RusotoError<DeleteReportError>> {
+        self.delete_report_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p> <code>DeleteReportGroup</code>: Deletes a report group. Before you delete a report group, you must delete its reports. Use <a href="https://docs.aws.amazon.com/codebuild/latest/APIReference/API_ListReportsForReportGroup.html">ListReportsForReportGroup</a> to get the reports in a report group. Use <a href="https://docs.aws.amazon.com/codebuild/latest/APIReference/API_DeleteReport.html">DeleteReport</a> to delete the reports. If you call <code>DeleteReportGroup</code> for a report group that contains one or more reports, an exception is thrown. </p>
+    async fn delete_report_group(
+        &self,
+        input: DeleteReportGroupInput,
+    ) -> Result<DeleteReportGroupOutput, RusotoError<DeleteReportGroupError>> {
+        self.delete_report_group_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p> Deletes a resource policy that is identified by its resource ARN. </p>
+    async fn delete_resource_policy(
+        &self,
+        input: DeleteResourcePolicyInput,
+    ) -> Result<DeleteResourcePolicyOutput, RusotoError<DeleteResourcePolicyError>> {
+        self.delete_resource_policy_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p> Deletes a set of GitHub, GitHub Enterprise, or Bitbucket source credentials. </p>
+    async fn delete_source_credentials(
+        &self,
+        input: DeleteSourceCredentialsInput,
+    ) -> Result<DeleteSourceCredentialsOutput, RusotoError<DeleteSourceCredentialsError>> {
+        self.delete_source_credentials_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>For an existing AWS CodeBuild build project that has its source code stored in a GitHub or Bitbucket repository, stops AWS CodeBuild from rebuilding the source code every time a code change is pushed to the repository.</p>
+    async fn delete_webhook(
+        &self,
+        input: DeleteWebhookInput,
+    ) -> Result<DeleteWebhookOutput, RusotoError<DeleteWebhookError>> {
+        self.delete_webhook_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p> Returns a list of details about test cases for a report. </p>
+    async fn describe_test_cases(
+        &self,
+        input: DescribeTestCasesInput,
+    ) -> Result<DescribeTestCasesOutput, RusotoError<DescribeTestCasesError>> {
+        self.describe_test_cases_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p> Gets a resource policy that is identified by its resource ARN. </p>
+    async fn get_resource_policy(
+        &self,
+        input: GetResourcePolicyInput,
+    ) -> Result<GetResourcePolicyOutput, RusotoError<GetResourcePolicyError>> {
+        self.get_resource_policy_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p> Imports the source repository credentials for an AWS CodeBuild project that has its source code stored in a GitHub, GitHub Enterprise, or Bitbucket repository. </p>
+    async fn import_source_credentials(
+        &self,
+        input: ImportSourceCredentialsInput,
+    ) -> Result<ImportSourceCredentialsOutput, RusotoError<ImportSourceCredentialsError>> {
+        self.import_source_credentials_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Resets the cache for a project.</p>
+    async fn invalidate_project_cache(
+        &self,
+        input: InvalidateProjectCacheInput,
+    ) -> Result<InvalidateProjectCacheOutput, RusotoError<InvalidateProjectCacheError>> {
+        self.invalidate_project_cache_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Gets a list of build IDs, with each build ID representing a single build.</p>
+    async fn list_builds(
+        &self,
+        input: ListBuildsInput,
+    ) -> Result<ListBuildsOutput, RusotoError<ListBuildsError>> {
+        self.list_builds_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Gets a list of build IDs for the specified build project, with each build ID representing a single build.</p>
+    async fn list_builds_for_project(
+        &self,
+        input: ListBuildsForProjectInput,
+    ) -> Result<ListBuildsForProjectOutput, RusotoError<ListBuildsForProjectError>> {
+        self.list_builds_for_project_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Gets information about Docker images that are managed by AWS CodeBuild.</p>
+    async fn list_curated_environment_images(
+        &self,
+    ) -> Result<ListCuratedEnvironmentImagesOutput, RusotoError<ListCuratedEnvironmentImagesError>>
+    {
+        self.list_curated_environment_images_with_metadata()
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Gets a list of build project names, with each build project name representing a single build project.</p>
+    async fn list_projects(
+        &self,
+        input: ListProjectsInput,
+    ) -> Result<ListProjectsOutput, RusotoError<ListProjectsError>> {
+        self.list_projects_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p> Gets a list ARNs for the report groups in the current AWS account. </p>
+    async fn list_report_groups(
+        &self,
+        input: ListReportGroupsInput,
+    ) -> Result<ListReportGroupsOutput, RusotoError<ListReportGroupsError>> {
+        self.list_report_groups_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p> Returns a list of ARNs for the reports in the current AWS account. </p>
+    async fn list_reports(
+        &self,
+        input: ListReportsInput,
+    ) -> Result<ListReportsOutput, RusotoError<ListReportsError>> {
+        self.list_reports_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p> Returns a list of ARNs for the reports that belong to a <code>ReportGroup</code>. </p>
+    async fn list_reports_for_report_group(
+        &self,
+        input: ListReportsForReportGroupInput,
+    ) -> Result<ListReportsForReportGroupOutput, RusotoError<ListReportsForReportGroupError>> {
+        self.list_reports_for_report_group_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p> Gets a list of projects that are shared with other AWS accounts or users. </p>
+    async fn list_shared_projects(
+        &self,
+        input: ListSharedProjectsInput,
+    ) -> Result<ListSharedProjectsOutput, RusotoError<ListSharedProjectsError>> {
+        self.list_shared_projects_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p> Gets a list of report groups that are shared with other AWS accounts or users. </p>
+    async fn list_shared_report_groups(
+        &self,
+        input: ListSharedReportGroupsInput,
+    ) -> Result<ListSharedReportGroupsOutput, RusotoError<ListSharedReportGroupsError>> {
+        self.list_shared_report_groups_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p> Returns a list of <code>SourceCredentialsInfo</code> objects. </p>
+    async fn list_source_credentials(
+        &self,
+    ) -> Result<ListSourceCredentialsOutput, RusotoError<ListSourceCredentialsError>> {
+        self.list_source_credentials_with_metadata()
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p> Stores a resource policy for the ARN of a <code>Project</code> or <code>ReportGroup</code> object. </p>
+    async fn put_resource_policy(
+        &self,
+        input: PutResourcePolicyInput,
+    ) -> Result<PutResourcePolicyOutput, RusotoError<PutResourcePolicyError>> {
+        self.put_resource_policy_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Starts running a build.</p>
+    async fn start_build(
+        &self,
+        input: StartBuildInput,
+    ) -> Result<StartBuildOutput, RusotoError<StartBuildError>> {
+        self.start_build_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Attempts to stop running a build.</p>
+    async fn stop_build(
+        &self,
+        input: StopBuildInput,
+    ) -> Result<StopBuildOutput, RusotoError<StopBuildError>> {
+        self.stop_build_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Changes the settings of a build project.</p>
+    async fn update_project(
+        &self,
+        input: UpdateProjectInput,
+    ) -> Result<UpdateProjectOutput, RusotoError<UpdateProjectError>> {
+        self.update_project_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p> Updates a report group. </p>
+    async fn update_report_group(
+        &self,
+        input: UpdateReportGroupInput,
+    ) -> Result<UpdateReportGroupOutput, RusotoError<UpdateReportGroupError>> {
+        self.update_report_group_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p> Updates the webhook associated with an AWS CodeBuild build project. </p> <note> <p> If you use Bitbucket for your repository, <code>rotateSecret</code> is ignored. </p> </note></p>
+    async fn update_webhook(
+        &self,
+        input: UpdateWebhookInput,
+    ) -> Result<UpdateWebhookOutput, RusotoError<UpdateWebhookError>> {
+        self.update_webhook_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
     }
 }
@@ -16,8 +16,8 @@ use std::fmt;
 use async_trait::async_trait;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
-use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest, ResponseMetadata};
+use rusoto_core::{Client, ErrorMetadata, RusotoError};
 
 use rusoto_core::proto;
 use rusoto_core::signature::SignedRequest;
@@ -3441,6 +3441,7 @@ pub struct UserInfo {
 
 /// Errors returned by AssociateApprovalRuleTemplateWithRepository
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum AssociateApprovalRuleTemplateWithRepositoryError {
     /// <p>The specified approval rule template does not exist. Verify that the name is correct and that you are signed in to the AWS Region where the template was created, and then try again.</p>
     ApprovalRuleTemplateDoesNotExist(String),
@@ -3466,6 +3467,8 @@ pub enum AssociateApprovalRuleTemplateWithRepositoryError {
     RepositoryDoesNotExist(String),
     /// <p>A repository name is required, but was not specified.</p>
     RepositoryNameRequired(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl AssociateApprovalRuleTemplateWithRepositoryError {
@@ -3474,20 +3477,20 @@ impl AssociateApprovalRuleTemplateWithRepositoryError {
     ) -> RusotoError<AssociateApprovalRuleTemplateWithRepositoryError> {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
-                                "ApprovalRuleTemplateDoesNotExistException" => return RusotoError::Service(AssociateApprovalRuleTemplateWithRepositoryError::ApprovalRuleTemplateDoesNotExist(err.msg)),
-"ApprovalRuleTemplateNameRequiredException" => return RusotoError::Service(AssociateApprovalRuleTemplateWithRepositoryError::ApprovalRuleTemplateNameRequired(err.msg)),
-"EncryptionIntegrityChecksFailedException" => return RusotoError::Service(AssociateApprovalRuleTemplateWithRepositoryError::EncryptionIntegrityChecksFailed(err.msg)),
-"EncryptionKeyAccessDeniedException" => return RusotoError::Service(AssociateApprovalRuleTemplateWithRepositoryError::EncryptionKeyAccessDenied(err.msg)),
-"EncryptionKeyDisabledException" => return RusotoError::Service(AssociateApprovalRuleTemplateWithRepositoryError::EncryptionKeyDisabled(err.msg)),
-"EncryptionKeyNotFoundException" => return RusotoError::Service(AssociateApprovalRuleTemplateWithRepositoryError::EncryptionKeyNotFound(err.msg)),
-"EncryptionKeyUnavailableException" => return RusotoError::Service(AssociateApprovalRuleTemplateWithRepositoryError::EncryptionKeyUnavailable(err.msg)),
-"InvalidApprovalRuleTemplateNameException" => return RusotoError::Service(AssociateApprovalRuleTemplateWithRepositoryError::InvalidApprovalRuleTemplateName(err.msg)),
-"InvalidRepositoryNameException" => return RusotoError::Service(AssociateApprovalRuleTemplateWithRepositoryError::InvalidRepositoryName(err.msg)),
-"MaximumRuleTemplatesAssociatedWithRepositoryException" => return RusotoError::Service(AssociateApprovalRuleTemplateWithRepositoryError::MaximumRuleTemplatesAssociatedWithRepository(err.msg)),
-"RepositoryDoesNotExistException" => return RusotoError::Service(AssociateApprovalRuleTemplateWithRepositoryError::RepositoryDoesNotExist(err.msg)),
-"RepositoryNameRequiredException" => return RusotoError::Service(AssociateApprovalRuleTemplateWithRepositoryError::RepositoryNameRequired(err.msg)),
+                                "ApprovalRuleTemplateDoesNotExistException" => return RusotoError::service(AssociateApprovalRuleTemplateWithRepositoryError::ApprovalRuleTemplateDoesNotExist(err.msg), &res),
+"ApprovalRuleTemplateNameRequiredException" => return RusotoError::service(AssociateApprovalRuleTemplateWithRepositoryError::ApprovalRuleTemplateNameRequired(err.msg), &res),
+"EncryptionIntegrityChecksFailedException" => return RusotoError::service(AssociateApprovalRuleTemplateWithRepositoryError::EncryptionIntegrityChecksFailed(err.msg), &res),
+"EncryptionKeyAccessDeniedException" => return RusotoError::service(AssociateApprovalRuleTemplateWithRepositoryError::EncryptionKeyAccessDenied(err.msg), &res),
+"EncryptionKeyDisabledException" => return RusotoError::service(AssociateApprovalRuleTemplateWithRepositoryError::EncryptionKeyDisabled(err.msg), &res),
+"EncryptionKeyNotFoundException" => return RusotoError::service(AssociateApprovalRuleTemplateWithRepositoryError::EncryptionKeyNotFound(err.msg), &res),
+"EncryptionKeyUnavailableException" => return RusotoError::service(AssociateApprovalRuleTemplateWithRepositoryError::EncryptionKeyUnavailable(err.msg), &res),
+"InvalidApprovalRuleTemplateNameException" => return RusotoError::service(AssociateApprovalRuleTemplateWithRepositoryError::InvalidApprovalRuleTemplateName(err.msg), &res),
+"InvalidRepositoryNameException" => return RusotoError::service(AssociateApprovalRuleTemplateWithRepositoryError::InvalidRepositoryName(err.msg), &res),
+"MaximumRuleTemplatesAssociatedWithRepositoryException" => return RusotoError::service(AssociateApprovalRuleTemplateWithRepositoryError::MaximumRuleTemplatesAssociatedWithRepository(err.msg), &res),
+"RepositoryDoesNotExistException" => return RusotoError::service(AssociateApprovalRuleTemplateWithRepositoryError::RepositoryDoesNotExist(err.msg), &res),
+"RepositoryNameRequiredException" => return RusotoError::service(AssociateApprovalRuleTemplateWithRepositoryError::RepositoryNameRequired(err.msg), &res),
 "ValidationException" => return RusotoError::Validation(err.msg),
-_ => {}
+_ => return RusotoError::unhandled(AssociateApprovalRuleTemplateWithRepositoryError::Unhandled, &res),
                             }
         }
         RusotoError::Unknown(res)
@@ -3508,13 +3511,15 @@ AssociateApprovalRuleTemplateWithRepositoryError::InvalidApprovalRuleTemplateNam
 AssociateApprovalRuleTemplateWithRepositoryError::InvalidRepositoryName(ref cause) => write!(f, "{}", cause),
 AssociateApprovalRuleTemplateWithRepositoryError::MaximumRuleTemplatesAssociatedWithRepository(ref cause) => write!(f, "{}", cause),
 AssociateApprovalRuleTemplateWithRepositoryError::RepositoryDoesNotExist(ref cause) => write!(f, "{}", cause),
-AssociateApprovalRuleTemplateWithRepositoryError::RepositoryNameRequired(ref cause) => write!(f, "{}", cause)
-                        }
+AssociateApprovalRuleTemplateWithRepositoryError::RepositoryNameRequired(ref cause) => write!(f, "{}", cause),
+            AssociateApprovalRuleTemplateWithRepositoryError::Unhandled(ref metadata) => write!(f, "{}", metadata),
+        }
     }
 }
 impl Error for AssociateApprovalRuleTemplateWithRepositoryError {}
 /// Errors returned by BatchAssociateApprovalRuleTemplateWithRepositories
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum BatchAssociateApprovalRuleTemplateWithRepositoriesError {
     /// <p>The specified approval rule template does not exist. Verify that the name is correct and that you are signed in to the AWS Region where the template was created, and then try again.</p>
     ApprovalRuleTemplateDoesNotExist(String),
@@ -3536,6 +3541,8 @@ pub enum BatchAssociateApprovalRuleTemplateWithRepositoriesError {
     MaximumRepositoryNamesExceeded(String),
     /// <p>At least one repository name object is required, but was not specified.</p>
     RepositoryNamesRequired(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl BatchAssociateApprovalRuleTemplateWithRepositoriesError {
@@ -3544,18 +3551,18 @@ impl BatchAssociateApprovalRuleTemplateWithRepositoriesError {
     ) -> RusotoError<BatchAssociateApprovalRuleTemplateWithRepositoriesError> {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
-                                "ApprovalRuleTemplateDoesNotExistException" => return RusotoError::Service(BatchAssociateApprovalRuleTemplateWithRepositoriesError::ApprovalRuleTemplateDoesNotExist(err.msg)),
-"ApprovalRuleTemplateNameRequiredException" => return RusotoError::Service(BatchAssociateApprovalRuleTemplateWithRepositoriesError::ApprovalRuleTemplateNameRequired(err.msg)),
-"EncryptionIntegrityChecksFailedException" => return RusotoError::Service(BatchAssociateApprovalRuleTemplateWithRepositoriesError::EncryptionIntegrityChecksFailed(err.msg)),
-"EncryptionKeyAccessDeniedException" => return RusotoError::Service(BatchAssociateApprovalRuleTemplateWithRepositoriesError::EncryptionKeyAccessDenied(err.msg)),
-"EncryptionKeyDisabledException" => return RusotoError::Service(BatchAssociateApprovalRuleTemplateWithRepositoriesError::EncryptionKeyDisabled(err.msg)),
-"EncryptionKeyNotFoundException" => return RusotoError::Service(BatchAssociateApprovalRuleTemplateWithRepositoriesError::EncryptionKeyNotFound(err.msg)),
-"EncryptionKeyUnavailableException" => return RusotoError::Service(BatchAssociateApprovalRuleTemplateWithRepositoriesError::EncryptionKeyUnavailable(err.msg)),
-"InvalidApprovalRuleTemplateNameException" => return RusotoError::Service(BatchAssociateApprovalRuleTemplateWithRepositoriesError::InvalidApprovalRuleTemplateName(err.msg)),
-"MaximumRepositoryNamesExceededException" => return RusotoError::Service(BatchAssociateApprovalRuleTemplateWithRepositoriesError::MaximumRepositoryNamesExceeded(err.msg)),
-"RepositoryNamesRequiredException" => return RusotoError::Service(BatchAssociateApprovalRuleTemplateWithRepositoriesError::RepositoryNamesRequired(err.msg)),
+                                "ApprovalRuleTemplateDoesNotExistException" => return RusotoError::service(BatchAssociateApprovalRuleTemplateWithRepositoriesError::ApprovalRuleTemplateDoesNotExist(err.msg), &res),
+"ApprovalRuleTemplateNameRequiredException" => return RusotoError::service(BatchAssociateApprovalRuleTemplateWithRepositoriesError::ApprovalRuleTemplateNameRequired(err.msg), &res),
+"EncryptionIntegrityChecksFailedException" => return RusotoError::service(BatchAssociateApprovalRuleTemplateWithRepositoriesError::EncryptionIntegrityChecksFailed(err.msg), &res),
+"EncryptionKeyAccessDeniedException" => return RusotoError::service(BatchAssociateApprovalRuleTemplateWithRepositoriesError::EncryptionKeyAccessDenied(err.msg), &res),
+"EncryptionKeyDisabledException" => return RusotoError::service(BatchAssociateApprovalRuleTemplateWithRepositoriesError::EncryptionKeyDisabled(err.msg), &res),
+"EncryptionKeyNotFoundException" => return RusotoError::service(BatchAssociateApprovalRuleTemplateWithRepositoriesError::EncryptionKeyNotFound(err.msg), &res),
+"EncryptionKeyUnavailableException" => return RusotoError::service(BatchAssociateApprovalRuleTemplateWithRepositoriesError::EncryptionKeyUnavailable(err.msg), &res),
+"InvalidApprovalRuleTemplateNameException" => return RusotoError::service(BatchAssociateApprovalRuleTemplateWithRepositoriesError::InvalidApprovalRuleTemplateName(err.msg), &res),
+"MaximumRepositoryNamesExceededException" => return RusotoError::service(BatchAssociateApprovalRuleTemplateWithRepositoriesError::MaximumRepositoryNamesExceeded(err.msg), &res),
+"RepositoryNamesRequiredException" => return RusotoError::service(BatchAssociateApprovalRuleTemplateWithRepositoriesError::RepositoryNamesRequired(err.msg), &res),
 "ValidationException" => return RusotoError::Validation(err.msg),
-_ => {}
+_ => return RusotoError::unhandled(BatchAssociateApprovalRuleTemplateWithRepositoriesError::Unhandled, &res),
                             }
         }
         RusotoError::Unknown(res)
@@ -3574,13 +3581,15 @@ BatchAssociateApprovalRuleTemplateWithRepositoriesError::EncryptionKeyNotFound(r
 BatchAssociateApprovalRuleTemplateWithRepositoriesError::EncryptionKeyUnavailable(ref cause) => write!(f, "{}", cause),
 BatchAssociateApprovalRuleTemplateWithRepositoriesError::InvalidApprovalRuleTemplateName(ref cause) => write!(f, "{}", cause),
 BatchAssociateApprovalRuleTemplateWithRepositoriesError::MaximumRepositoryNamesExceeded(ref cause) => write!(f, "{}", cause),
-BatchAssociateApprovalRuleTemplateWithRepositoriesError::RepositoryNamesRequired(ref cause) => write!(f, "{}", cause)
-                        }
+BatchAssociateApprovalRuleTemplateWithRepositoriesError::RepositoryNamesRequired(ref cause) => write!(f, "{}", cause),
+            BatchAssociateApprovalRuleTemplateWithRepositoriesError::Unhandled(ref metadata) => write!(f, "{}", metadata),
+        }
     }
 }
 impl Error for BatchAssociateApprovalRuleTemplateWithRepositoriesError {}
 /// Errors returned by BatchDescribeMergeConflicts
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum BatchDescribeMergeConflictsError {
     /// <p>The specified commit does not exist or no commit was specified, and the specified repository has no default branch.</p>
     CommitDoesNotExist(String),
@@ -3624,6 +3633,8 @@ pub enum BatchDescribeMergeConflictsError {
     RepositoryNameRequired(String),
     /// <p>The divergence between the tips of the provided commit specifiers is too great to determine whether there might be any merge conflicts. Locally compare the specifiers using <code>git diff</code> or a diff tool.</p>
     TipsDivergenceExceeded(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl BatchDescribeMergeConflictsError {
@@ -3633,114 +3644,140 @@ impl BatchDescribeMergeConflictsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "CommitDoesNotExistException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         BatchDescribeMergeConflictsError::CommitDoesNotExist(err.msg),
+                        &res,
                     )
                 }
                 "CommitRequiredException" => {
-                    return RusotoError::Service(BatchDescribeMergeConflictsError::CommitRequired(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        BatchDescribeMergeConflictsError::CommitRequired(err.msg),
+                        &res,
+                    )
                 }
                 "EncryptionIntegrityChecksFailedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         BatchDescribeMergeConflictsError::EncryptionIntegrityChecksFailed(err.msg),
+                        &res,
                     )
                 }
                 "EncryptionKeyAccessDeniedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         BatchDescribeMergeConflictsError::EncryptionKeyAccessDenied(err.msg),
+                        &res,
                     )
                 }
                 "EncryptionKeyDisabledException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         BatchDescribeMergeConflictsError::EncryptionKeyDisabled(err.msg),
+                        &res,
                     )
                 }
                 "EncryptionKeyNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         BatchDescribeMergeConflictsError::EncryptionKeyNotFound(err.msg),
+                        &res,
                     )
                 }
                 "EncryptionKeyUnavailableException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         BatchDescribeMergeConflictsError::EncryptionKeyUnavailable(err.msg),
+                        &res,
                     )
                 }
                 "InvalidCommitException" => {
-                    return RusotoError::Service(BatchDescribeMergeConflictsError::InvalidCommit(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        BatchDescribeMergeConflictsError::InvalidCommit(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidConflictDetailLevelException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         BatchDescribeMergeConflictsError::InvalidConflictDetailLevel(err.msg),
+                        &res,
                     )
                 }
                 "InvalidConflictResolutionStrategyException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         BatchDescribeMergeConflictsError::InvalidConflictResolutionStrategy(
                             err.msg,
                         ),
+                        &res,
                     )
                 }
                 "InvalidContinuationTokenException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         BatchDescribeMergeConflictsError::InvalidContinuationToken(err.msg),
+                        &res,
                     )
                 }
                 "InvalidMaxConflictFilesException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         BatchDescribeMergeConflictsError::InvalidMaxConflictFiles(err.msg),
+                        &res,
                     )
                 }
                 "InvalidMaxMergeHunksException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         BatchDescribeMergeConflictsError::InvalidMaxMergeHunks(err.msg),
+                        &res,
                     )
                 }
                 "InvalidMergeOptionException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         BatchDescribeMergeConflictsError::InvalidMergeOption(err.msg),
+                        &res,
                     )
                 }
                 "InvalidRepositoryNameException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         BatchDescribeMergeConflictsError::InvalidRepositoryName(err.msg),
+                        &res,
                     )
                 }
                 "MaximumFileContentToLoadExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         BatchDescribeMergeConflictsError::MaximumFileContentToLoadExceeded(err.msg),
+                        &res,
                     )
                 }
                 "MaximumItemsToCompareExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         BatchDescribeMergeConflictsError::MaximumItemsToCompareExceeded(err.msg),
+                        &res,
                     )
                 }
                 "MergeOptionRequiredException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         BatchDescribeMergeConflictsError::MergeOptionRequired(err.msg),
+                        &res,
                     )
                 }
                 "RepositoryDoesNotExistException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         BatchDescribeMergeConflictsError::RepositoryDoesNotExist(err.msg),
+                        &res,
                     )
                 }
                 "RepositoryNameRequiredException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         BatchDescribeMergeConflictsError::RepositoryNameRequired(err.msg),
+                        &res,
                     )
                 }
                 "TipsDivergenceExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         BatchDescribeMergeConflictsError::TipsDivergenceExceeded(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        BatchDescribeMergeConflictsError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -3809,12 +3846,14 @@ impl fmt::Display for BatchDescribeMergeConflictsError {
             BatchDescribeMergeConflictsError::TipsDivergenceExceeded(ref cause) => {
                 write!(f, "{}", cause)
             }
+            BatchDescribeMergeConflictsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for BatchDescribeMergeConflictsError {}
 /// Errors returned by BatchDisassociateApprovalRuleTemplateFromRepositories
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum BatchDisassociateApprovalRuleTemplateFromRepositoriesError {
     /// <p>The specified approval rule template does not exist. Verify that the name is correct and that you are signed in to the AWS Region where the template was created, and then try again.</p>
     ApprovalRuleTemplateDoesNotExist(String),
@@ -3836,6 +3875,8 @@ pub enum BatchDisassociateApprovalRuleTemplateFromRepositoriesError {
     MaximumRepositoryNamesExceeded(String),
     /// <p>At least one repository name object is required, but was not specified.</p>
     RepositoryNamesRequired(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl BatchDisassociateApprovalRuleTemplateFromRepositoriesError {
@@ -3844,18 +3885,18 @@ impl BatchDisassociateApprovalRuleTemplateFromRepositoriesError {
     ) -> RusotoError<BatchDisassociateApprovalRuleTemplateFromRepositoriesError> {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
-                                "ApprovalRuleTemplateDoesNotExistException" => return RusotoError::Service(BatchDisassociateApprovalRuleTemplateFromRepositoriesError::ApprovalRuleTemplateDoesNotExist(err.msg)),
-"ApprovalRuleTemplateNameRequiredException" => return RusotoError::Service(BatchDisassociateApprovalRuleTemplateFromRepositoriesError::ApprovalRuleTemplateNameRequired(err.msg)),
-"EncryptionIntegrityChecksFailedException" => return RusotoError::Service(BatchDisassociateApprovalRuleTemplateFromRepositoriesError::EncryptionIntegrityChecksFailed(err.msg)),
-"EncryptionKeyAccessDeniedException" => return RusotoError::Service(BatchDisassociateApprovalRuleTemplateFromRepositoriesError::EncryptionKeyAccessDenied(err.msg)),
-"EncryptionKeyDisabledException" => return RusotoError::Service(BatchDisassociateApprovalRuleTemplateFromRepositoriesError::EncryptionKeyDisabled(err.msg)),
-"EncryptionKeyNotFoundException" => return RusotoError::Service(BatchDisassociateApprovalRuleTemplateFromRepositoriesError::EncryptionKeyNotFound(err.msg)),
-"EncryptionKeyUnavailableException" => return RusotoError::Service(BatchDisassociateApprovalRuleTemplateFromRepositoriesError::EncryptionKeyUnavailable(err.msg)),
-"InvalidApprovalRuleTemplateNameException" => return RusotoError::Service(BatchDisassociateApprovalRuleTemplateFromRepositoriesError::InvalidApprovalRuleTemplateName(err.msg)),
-"MaximumRepositoryNamesExceededException" => return RusotoError::Service(BatchDisassociateApprovalRuleTemplateFromRepositoriesError::MaximumRepositoryNamesExceeded(err.msg)),
-"RepositoryNamesRequiredException" => return RusotoError::Service(BatchDisassociateApprovalRuleTemplateFromRepositoriesError::RepositoryNamesRequired(err.msg)),
+                                "ApprovalRuleTemplateDoesNotExistException" => return RusotoError::service(BatchDisassociateApprovalRuleTemplateFromRepositoriesError::ApprovalRuleTemplateDoesNotExist(err.msg), &res),
+"ApprovalRuleTemplateNameRequiredException" => return RusotoError::service(BatchDisassociateApprovalRuleTemplateFromRepositoriesError::ApprovalRuleTemplateNameRequired(err.msg), &res),
+"EncryptionIntegrityChecksFailedException" => return RusotoError::service(BatchDisassociateApprovalRuleTemplateFromRepositoriesError::EncryptionIntegrityChecksFailed(err.msg), &res),
+"EncryptionKeyAccessDeniedException" => return RusotoError::service(BatchDisassociateApprovalRuleTemplateFromRepositoriesError::EncryptionKeyAccessDenied(err.msg), &res),
+"EncryptionKeyDisabledException" => return RusotoError::service(BatchDisassociateApprovalRuleTemplateFromRepositoriesError::EncryptionKeyDisabled(err.msg), &res),
+"EncryptionKeyNotFoundException" => return RusotoError::service(BatchDisassociateApprovalRuleTemplateFromRepositoriesError::EncryptionKeyNotFound(err.msg), &res),
+"EncryptionKeyUnavailableException" => return RusotoError::service(BatchDisassociateApprovalRuleTemplateFromRepositoriesError::EncryptionKeyUnavailable(err.msg), &res),
+"InvalidApprovalRuleTemplateNameException" => return RusotoError::service(BatchDisassociateApprovalRuleTemplateFromRepositoriesError::InvalidApprovalRuleTemplateName(err.msg), &res),
+"MaximumRepositoryNamesExceededException" => return RusotoError::service(BatchDisassociateApprovalRuleTemplateFromRepositoriesError::MaximumRepositoryNamesExceeded(err.msg), &res),
+"RepositoryNamesRequiredException" => return RusotoError::service(BatchDisassociateApprovalRuleTemplateFromRepositoriesError::RepositoryNamesRequired(err.msg), &res),
 "ValidationException" => return RusotoError::Validation(err.msg),
-_ => {}
+_ => return RusotoError::unhandled(BatchDisassociateApprovalRuleTemplateFromRepositoriesError::Unhandled, &res),
                             }
         }
         RusotoError::Unknown(res)
@@ -3874,13 +3915,15 @@ BatchDisassociateApprovalRuleTemplateFromRepositoriesError::EncryptionKeyNotFoun
 BatchDisassociateApprovalRuleTemplateFromRepositoriesError::EncryptionKeyUnavailable(ref cause) => write!(f, "{}", cause),
 BatchDisassociateApprovalRuleTemplateFromRepositoriesError::InvalidApprovalRuleTemplateName(ref cause) => write!(f, "{}", cause),
 BatchDisassociateApprovalRuleTemplateFromRepositoriesError::MaximumRepositoryNamesExceeded(ref cause) => write!(f, "{}", cause),
-BatchDisassociateApprovalRuleTemplateFromRepositoriesError::RepositoryNamesRequired(ref cause) => write!(f, "{}", cause)
-                        }
+BatchDisassociateApprovalRuleTemplateFromRepositoriesError::RepositoryNamesRequired(ref cause) => write!(f, "{}", cause),
+            BatchDisassociateApprovalRuleTemplateFromRepositoriesError::Unhandled(ref metadata) => write!(f, "{}", metadata),
+        }
     }
 }
 impl Error for BatchDisassociateApprovalRuleTemplateFromRepositoriesError {}
 /// Errors returned by BatchGetCommits
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum BatchGetCommitsError {
     /// <p>The maximum number of allowed commit IDs in a batch request is 100. Verify that your batch requests contains no more than 100 commit IDs, and then try again.</p>
     CommitIdsLimitExceeded(String),
@@ -3902,6 +3945,8 @@ pub enum BatchGetCommitsError {
     RepositoryDoesNotExist(String),
     /// <p>A repository name is required, but was not specified.</p>
     RepositoryNameRequired(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl BatchGetCommitsError {
@@ -3909,57 +3954,67 @@ impl BatchGetCommitsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "CommitIdsLimitExceededException" => {
-                    return RusotoError::Service(BatchGetCommitsError::CommitIdsLimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        BatchGetCommitsError::CommitIdsLimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "CommitIdsListRequiredException" => {
-                    return RusotoError::Service(BatchGetCommitsError::CommitIdsListRequired(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        BatchGetCommitsError::CommitIdsListRequired(err.msg),
+                        &res,
+                    )
                 }
                 "EncryptionIntegrityChecksFailedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         BatchGetCommitsError::EncryptionIntegrityChecksFailed(err.msg),
+                        &res,
                     )
                 }
                 "EncryptionKeyAccessDeniedException" => {
-                    return RusotoError::Service(BatchGetCommitsError::EncryptionKeyAccessDenied(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        BatchGetCommitsError::EncryptionKeyAccessDenied(err.msg),
+                        &res,
+                    )
                 }
                 "EncryptionKeyDisabledException" => {
-                    return RusotoError::Service(BatchGetCommitsError::EncryptionKeyDisabled(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        BatchGetCommitsError::EncryptionKeyDisabled(err.msg),
+                        &res,
+                    )
                 }
                 "EncryptionKeyNotFoundException" => {
-                    return RusotoError::Service(BatchGetCommitsError::EncryptionKeyNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        BatchGetCommitsError::EncryptionKeyNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "EncryptionKeyUnavailableException" => {
-                    return RusotoError::Service(BatchGetCommitsError::EncryptionKeyUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        BatchGetCommitsError::EncryptionKeyUnavailable(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidRepositoryNameException" => {
-                    return RusotoError::Service(BatchGetCommitsError::InvalidRepositoryName(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        BatchGetCommitsError::InvalidRepositoryName(err.msg),
+                        &res,
+                    )
                 }
                 "RepositoryDoesNotExistException" => {
-                    return RusotoError::Service(BatchGetCommitsError::RepositoryDoesNotExist(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        BatchGetCommitsError::RepositoryDoesNotExist(err.msg),
+                        &res,
+                    )
                 }
                 "RepositoryNameRequiredException" => {
-                    return RusotoError::Service(BatchGetCommitsError::RepositoryNameRequired(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        BatchGetCommitsError::RepositoryNameRequired(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(BatchGetCommitsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3981,12 +4036,14 @@ impl fmt::Display for BatchGetCommitsError {
             BatchGetCommitsError::InvalidRepositoryName(ref cause) => write!(f, "{}", cause),
             BatchGetCommitsError::RepositoryDoesNotExist(ref cause) => write!(f, "{}", cause),
             BatchGetCommitsError::RepositoryNameRequired(ref cause) => write!(f, "{}", cause),
+            BatchGetCommitsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for BatchGetCommitsError {}
 /// Errors returned by BatchGetRepositories
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum BatchGetRepositoriesError {
     /// <p>An encryption integrity check failed.</p>
     EncryptionIntegrityChecksFailed(String),
@@ -4004,6 +4061,8 @@ pub enum BatchGetRepositoriesError {
     MaximumRepositoryNamesExceeded(String),
     /// <p>At least one repository name object is required, but was not specified.</p>
     RepositoryNamesRequired(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl BatchGetRepositoriesError {
@@ -4011,47 +4070,55 @@ impl BatchGetRepositoriesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "EncryptionIntegrityChecksFailedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         BatchGetRepositoriesError::EncryptionIntegrityChecksFailed(err.msg),
+                        &res,
                     )
                 }
                 "EncryptionKeyAccessDeniedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         BatchGetRepositoriesError::EncryptionKeyAccessDenied(err.msg),
+                        &res,
                     )
                 }
                 "EncryptionKeyDisabledException" => {
-                    return RusotoError::Service(BatchGetRepositoriesError::EncryptionKeyDisabled(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        BatchGetRepositoriesError::EncryptionKeyDisabled(err.msg),
+                        &res,
+                    )
                 }
                 "EncryptionKeyNotFoundException" => {
-                    return RusotoError::Service(BatchGetRepositoriesError::EncryptionKeyNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        BatchGetRepositoriesError::EncryptionKeyNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "EncryptionKeyUnavailableException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         BatchGetRepositoriesError::EncryptionKeyUnavailable(err.msg),
+                        &res,
                     )
                 }
                 "InvalidRepositoryNameException" => {
-                    return RusotoError::Service(BatchGetRepositoriesError::InvalidRepositoryName(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        BatchGetRepositoriesError::InvalidRepositoryName(err.msg),
+                        &res,
+                    )
                 }
                 "MaximumRepositoryNamesExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         BatchGetRepositoriesError::MaximumRepositoryNamesExceeded(err.msg),
+                        &res,
                     )
                 }
                 "RepositoryNamesRequiredException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         BatchGetRepositoriesError::RepositoryNamesRequired(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(BatchGetRepositoriesError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -4077,12 +4144,14 @@ impl fmt::Display for BatchGetRepositoriesError {
                 write!(f, "{}", cause)
             }
             BatchGetRepositoriesError::RepositoryNamesRequired(ref cause) => write!(f, "{}", cause),
+            BatchGetRepositoriesError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for BatchGetRepositoriesError {}
 /// Errors returned by CreateApprovalRuleTemplate
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateApprovalRuleTemplateError {
     /// <p>The content for the approval rule template is empty. You must provide some content for an approval rule template. The content cannot be null.</p>
     ApprovalRuleTemplateContentRequired(String),
@@ -4098,6 +4167,8 @@ pub enum CreateApprovalRuleTemplateError {
     InvalidApprovalRuleTemplateName(String),
     /// <p>The maximum number of approval rule templates has been exceeded for this AWS Region. </p>
     NumberOfRuleTemplatesExceeded(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateApprovalRuleTemplateError {
@@ -4107,50 +4178,59 @@ impl CreateApprovalRuleTemplateError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ApprovalRuleTemplateContentRequiredException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         CreateApprovalRuleTemplateError::ApprovalRuleTemplateContentRequired(
                             err.msg,
                         ),
+                        &res,
                     )
                 }
                 "ApprovalRuleTemplateNameAlreadyExistsException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         CreateApprovalRuleTemplateError::ApprovalRuleTemplateNameAlreadyExists(
                             err.msg,
                         ),
+                        &res,
                     )
                 }
                 "ApprovalRuleTemplateNameRequiredException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         CreateApprovalRuleTemplateError::ApprovalRuleTemplateNameRequired(err.msg),
+                        &res,
                     )
                 }
                 "InvalidApprovalRuleTemplateContentException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         CreateApprovalRuleTemplateError::InvalidApprovalRuleTemplateContent(
                             err.msg,
                         ),
+                        &res,
                     )
                 }
                 "InvalidApprovalRuleTemplateDescriptionException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         CreateApprovalRuleTemplateError::InvalidApprovalRuleTemplateDescription(
                             err.msg,
                         ),
+                        &res,
                     )
                 }
                 "InvalidApprovalRuleTemplateNameException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         CreateApprovalRuleTemplateError::InvalidApprovalRuleTemplateName(err.msg),
+                        &res,
                     )
                 }
                 "NumberOfRuleTemplatesExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         CreateApprovalRuleTemplateError::NumberOfRuleTemplatesExceeded(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(CreateApprovalRuleTemplateError::Unhandled, &res)
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -4181,12 +4261,14 @@ impl fmt::Display for CreateApprovalRuleTemplateError {
             CreateApprovalRuleTemplateError::NumberOfRuleTemplatesExceeded(ref cause) => {
                 write!(f, "{}", cause)
             }
+            CreateApprovalRuleTemplateError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateApprovalRuleTemplateError {}
 /// Errors returned by CreateBranch
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateBranchError {
     /// <p>The specified branch name already exists.</p>
     BranchNameExists(String),
@@ -4216,6 +4298,8 @@ pub enum CreateBranchError {
     RepositoryDoesNotExist(String),
     /// <p>A repository name is required, but was not specified.</p>
     RepositoryNameRequired(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateBranchError {
@@ -4223,55 +4307,82 @@ impl CreateBranchError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "BranchNameExistsException" => {
-                    return RusotoError::Service(CreateBranchError::BranchNameExists(err.msg))
+                    return RusotoError::service(CreateBranchError::BranchNameExists(err.msg), &res)
                 }
                 "BranchNameRequiredException" => {
-                    return RusotoError::Service(CreateBranchError::BranchNameRequired(err.msg))
+                    return RusotoError::service(
+                        CreateBranchError::BranchNameRequired(err.msg),
+                        &res,
+                    )
                 }
                 "CommitDoesNotExistException" => {
-                    return RusotoError::Service(CreateBranchError::CommitDoesNotExist(err.msg))
+                    return RusotoError::service(
+                        CreateBranchError::CommitDoesNotExist(err.msg),
+                        &res,
+                    )
                 }
                 "CommitIdRequiredException" => {
-                    return RusotoError::Service(CreateBranchError::CommitIdRequired(err.msg))
+                    return RusotoError::service(CreateBranchError::CommitIdRequired(err.msg), &res)
                 }
                 "EncryptionIntegrityChecksFailedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         CreateBranchError::EncryptionIntegrityChecksFailed(err.msg),
+                        &res,
                     )
                 }
                 "EncryptionKeyAccessDeniedException" => {
-                    return RusotoError::Service(CreateBranchError::EncryptionKeyAccessDenied(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateBranchError::EncryptionKeyAccessDenied(err.msg),
+                        &res,
+                    )
                 }
                 "EncryptionKeyDisabledException" => {
-                    return RusotoError::Service(CreateBranchError::EncryptionKeyDisabled(err.msg))
+                    return RusotoError::service(
+                        CreateBranchError::EncryptionKeyDisabled(err.msg),
+                        &res,
+                    )
                 }
                 "EncryptionKeyNotFoundException" => {
-                    return RusotoError::Service(CreateBranchError::EncryptionKeyNotFound(err.msg))
+                    return RusotoError::service(
+                        CreateBranchError::EncryptionKeyNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "EncryptionKeyUnavailableException" => {
-                    return RusotoError::Service(CreateBranchError::EncryptionKeyUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateBranchError::EncryptionKeyUnavailable(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidBranchNameException" => {
-                    return RusotoError::Service(CreateBranchError::InvalidBranchName(err.msg))
+                    return RusotoError::service(
+                        CreateBranchError::InvalidBranchName(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidCommitIdException" => {
-                    return RusotoError::Service(CreateBranchError::InvalidCommitId(err.msg))
+                    return RusotoError::service(CreateBranchError::InvalidCommitId(err.msg), &res)
                 }
                 "InvalidRepositoryNameException" => {
-                    return RusotoError::Service(CreateBranchError::InvalidRepositoryName(err.msg))
+                    return RusotoError::service(
+                        CreateBranchError::InvalidRepositoryName(err.msg),
+                        &res,
+                    )
                 }
                 "RepositoryDoesNotExistException" => {
-                    return RusotoError::Service(CreateBranchError::RepositoryDoesNotExist(err.msg))
+                    return RusotoError::service(
+                        CreateBranchError::RepositoryDoesNotExist(err.msg),
+                        &res,
+                    )
                 }
                 "RepositoryNameRequiredException" => {
-                    return RusotoError::Service(CreateBranchError::RepositoryNameRequired(err.msg))
+                    return RusotoError::service(
+                        CreateBranchError::RepositoryNameRequired(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreateBranchError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -4295,12 +4406,14 @@ impl fmt::Display for CreateBranchError {
             CreateBranchError::InvalidRepositoryName(ref cause) => write!(f, "{}", cause),
             CreateBranchError::RepositoryDoesNotExist(ref cause) => write!(f, "{}", cause),
             CreateBranchError::RepositoryNameRequired(ref cause) => write!(f, "{}", cause),
+            CreateBranchError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateBranchError {}
 /// Errors returned by CreateCommit
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateCommitError {
     /// <p>The specified branch does not exist.</p>
     BranchDoesNotExist(String),
@@ -4378,6 +4491,8 @@ pub enum CreateCommitError {
     SamePathRequest(String),
     /// <p>The commit cannot be created because no source files or file content have been specified for the commit.</p>
     SourceFileOrContentRequired(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateCommitError {
@@ -4385,149 +4500,211 @@ impl CreateCommitError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "BranchDoesNotExistException" => {
-                    return RusotoError::Service(CreateCommitError::BranchDoesNotExist(err.msg))
+                    return RusotoError::service(
+                        CreateCommitError::BranchDoesNotExist(err.msg),
+                        &res,
+                    )
                 }
                 "BranchNameIsTagNameException" => {
-                    return RusotoError::Service(CreateCommitError::BranchNameIsTagName(err.msg))
+                    return RusotoError::service(
+                        CreateCommitError::BranchNameIsTagName(err.msg),
+                        &res,
+                    )
                 }
                 "BranchNameRequiredException" => {
-                    return RusotoError::Service(CreateCommitError::BranchNameRequired(err.msg))
+                    return RusotoError::service(
+                        CreateCommitError::BranchNameRequired(err.msg),
+                        &res,
+                    )
                 }
                 "CommitMessageLengthExceededException" => {
-                    return RusotoError::Service(CreateCommitError::CommitMessageLengthExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateCommitError::CommitMessageLengthExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "DirectoryNameConflictsWithFileNameException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         CreateCommitError::DirectoryNameConflictsWithFileName(err.msg),
+                        &res,
                     )
                 }
                 "EncryptionIntegrityChecksFailedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         CreateCommitError::EncryptionIntegrityChecksFailed(err.msg),
+                        &res,
                     )
                 }
                 "EncryptionKeyAccessDeniedException" => {
-                    return RusotoError::Service(CreateCommitError::EncryptionKeyAccessDenied(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateCommitError::EncryptionKeyAccessDenied(err.msg),
+                        &res,
+                    )
                 }
                 "EncryptionKeyDisabledException" => {
-                    return RusotoError::Service(CreateCommitError::EncryptionKeyDisabled(err.msg))
+                    return RusotoError::service(
+                        CreateCommitError::EncryptionKeyDisabled(err.msg),
+                        &res,
+                    )
                 }
                 "EncryptionKeyNotFoundException" => {
-                    return RusotoError::Service(CreateCommitError::EncryptionKeyNotFound(err.msg))
+                    return RusotoError::service(
+                        CreateCommitError::EncryptionKeyNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "EncryptionKeyUnavailableException" => {
-                    return RusotoError::Service(CreateCommitError::EncryptionKeyUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateCommitError::EncryptionKeyUnavailable(err.msg),
+                        &res,
+                    )
                 }
                 "FileContentAndSourceFileSpecifiedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         CreateCommitError::FileContentAndSourceFileSpecified(err.msg),
+                        &res,
                     )
                 }
                 "FileContentSizeLimitExceededException" => {
-                    return RusotoError::Service(CreateCommitError::FileContentSizeLimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateCommitError::FileContentSizeLimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "FileDoesNotExistException" => {
-                    return RusotoError::Service(CreateCommitError::FileDoesNotExist(err.msg))
+                    return RusotoError::service(CreateCommitError::FileDoesNotExist(err.msg), &res)
                 }
                 "FileEntryRequiredException" => {
-                    return RusotoError::Service(CreateCommitError::FileEntryRequired(err.msg))
+                    return RusotoError::service(
+                        CreateCommitError::FileEntryRequired(err.msg),
+                        &res,
+                    )
                 }
                 "FileModeRequiredException" => {
-                    return RusotoError::Service(CreateCommitError::FileModeRequired(err.msg))
+                    return RusotoError::service(CreateCommitError::FileModeRequired(err.msg), &res)
                 }
                 "FileNameConflictsWithDirectoryNameException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         CreateCommitError::FileNameConflictsWithDirectoryName(err.msg),
+                        &res,
                     )
                 }
                 "FilePathConflictsWithSubmodulePathException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         CreateCommitError::FilePathConflictsWithSubmodulePath(err.msg),
+                        &res,
                     )
                 }
                 "FolderContentSizeLimitExceededException" => {
-                    return RusotoError::Service(CreateCommitError::FolderContentSizeLimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateCommitError::FolderContentSizeLimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidBranchNameException" => {
-                    return RusotoError::Service(CreateCommitError::InvalidBranchName(err.msg))
+                    return RusotoError::service(
+                        CreateCommitError::InvalidBranchName(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidDeletionParameterException" => {
-                    return RusotoError::Service(CreateCommitError::InvalidDeletionParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateCommitError::InvalidDeletionParameter(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidEmailException" => {
-                    return RusotoError::Service(CreateCommitError::InvalidEmail(err.msg))
+                    return RusotoError::service(CreateCommitError::InvalidEmail(err.msg), &res)
                 }
                 "InvalidFileModeException" => {
-                    return RusotoError::Service(CreateCommitError::InvalidFileMode(err.msg))
+                    return RusotoError::service(CreateCommitError::InvalidFileMode(err.msg), &res)
                 }
                 "InvalidParentCommitIdException" => {
-                    return RusotoError::Service(CreateCommitError::InvalidParentCommitId(err.msg))
+                    return RusotoError::service(
+                        CreateCommitError::InvalidParentCommitId(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidPathException" => {
-                    return RusotoError::Service(CreateCommitError::InvalidPath(err.msg))
+                    return RusotoError::service(CreateCommitError::InvalidPath(err.msg), &res)
                 }
                 "InvalidRepositoryNameException" => {
-                    return RusotoError::Service(CreateCommitError::InvalidRepositoryName(err.msg))
+                    return RusotoError::service(
+                        CreateCommitError::InvalidRepositoryName(err.msg),
+                        &res,
+                    )
                 }
                 "MaximumFileEntriesExceededException" => {
-                    return RusotoError::Service(CreateCommitError::MaximumFileEntriesExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateCommitError::MaximumFileEntriesExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "NameLengthExceededException" => {
-                    return RusotoError::Service(CreateCommitError::NameLengthExceeded(err.msg))
+                    return RusotoError::service(
+                        CreateCommitError::NameLengthExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "NoChangeException" => {
-                    return RusotoError::Service(CreateCommitError::NoChange(err.msg))
+                    return RusotoError::service(CreateCommitError::NoChange(err.msg), &res)
                 }
                 "ParentCommitDoesNotExistException" => {
-                    return RusotoError::Service(CreateCommitError::ParentCommitDoesNotExist(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateCommitError::ParentCommitDoesNotExist(err.msg),
+                        &res,
+                    )
                 }
                 "ParentCommitIdOutdatedException" => {
-                    return RusotoError::Service(CreateCommitError::ParentCommitIdOutdated(err.msg))
+                    return RusotoError::service(
+                        CreateCommitError::ParentCommitIdOutdated(err.msg),
+                        &res,
+                    )
                 }
                 "ParentCommitIdRequiredException" => {
-                    return RusotoError::Service(CreateCommitError::ParentCommitIdRequired(err.msg))
+                    return RusotoError::service(
+                        CreateCommitError::ParentCommitIdRequired(err.msg),
+                        &res,
+                    )
                 }
                 "PathRequiredException" => {
-                    return RusotoError::Service(CreateCommitError::PathRequired(err.msg))
+                    return RusotoError::service(CreateCommitError::PathRequired(err.msg), &res)
                 }
                 "PutFileEntryConflictException" => {
-                    return RusotoError::Service(CreateCommitError::PutFileEntryConflict(err.msg))
+                    return RusotoError::service(
+                        CreateCommitError::PutFileEntryConflict(err.msg),
+                        &res,
+                    )
                 }
                 "RepositoryDoesNotExistException" => {
-                    return RusotoError::Service(CreateCommitError::RepositoryDoesNotExist(err.msg))
+                    return RusotoError::service(
+                        CreateCommitError::RepositoryDoesNotExist(err.msg),
+                        &res,
+                    )
                 }
                 "RepositoryNameRequiredException" => {
-                    return RusotoError::Service(CreateCommitError::RepositoryNameRequired(err.msg))
+                    return RusotoError::service(
+                        CreateCommitError::RepositoryNameRequired(err.msg),
+                        &res,
+                    )
                 }
                 "RestrictedSourceFileException" => {
-                    return RusotoError::Service(CreateCommitError::RestrictedSourceFile(err.msg))
+                    return RusotoError::service(
+                        CreateCommitError::RestrictedSourceFile(err.msg),
+                        &res,
+                    )
                 }
                 "SamePathRequestException" => {
-                    return RusotoError::Service(CreateCommitError::SamePathRequest(err.msg))
+                    return RusotoError::service(CreateCommitError::SamePathRequest(err.msg), &res)
                 }
                 "SourceFileOrContentRequiredException" => {
-                    return RusotoError::Service(CreateCommitError::SourceFileOrContentRequired(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateCommitError::SourceFileOrContentRequired(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreateCommitError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -4583,12 +4760,14 @@ impl fmt::Display for CreateCommitError {
             CreateCommitError::RestrictedSourceFile(ref cause) => write!(f, "{}", cause),
             CreateCommitError::SamePathRequest(ref cause) => write!(f, "{}", cause),
             CreateCommitError::SourceFileOrContentRequired(ref cause) => write!(f, "{}", cause),
+            CreateCommitError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateCommitError {}
 /// Errors returned by CreatePullRequest
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreatePullRequestError {
     /// <p>A client request token is required. A client request token is an unique, client-generated idempotency token that, when provided in a request, ensures the request cannot be repeated with a changed parameter. If a request is received with the same parameters and a token is included, the request returns information about the initial request that used that token.</p>
     ClientRequestTokenRequired(String),
@@ -4640,6 +4819,8 @@ pub enum CreatePullRequestError {
     TargetsRequired(String),
     /// <p>A pull request title is required. It cannot be empty or null.</p>
     TitleRequired(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreatePullRequestError {
@@ -4647,120 +4828,157 @@ impl CreatePullRequestError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ClientRequestTokenRequiredException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         CreatePullRequestError::ClientRequestTokenRequired(err.msg),
+                        &res,
                     )
                 }
                 "EncryptionIntegrityChecksFailedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         CreatePullRequestError::EncryptionIntegrityChecksFailed(err.msg),
+                        &res,
                     )
                 }
                 "EncryptionKeyAccessDeniedException" => {
-                    return RusotoError::Service(CreatePullRequestError::EncryptionKeyAccessDenied(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreatePullRequestError::EncryptionKeyAccessDenied(err.msg),
+                        &res,
+                    )
                 }
                 "EncryptionKeyDisabledException" => {
-                    return RusotoError::Service(CreatePullRequestError::EncryptionKeyDisabled(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreatePullRequestError::EncryptionKeyDisabled(err.msg),
+                        &res,
+                    )
                 }
                 "EncryptionKeyNotFoundException" => {
-                    return RusotoError::Service(CreatePullRequestError::EncryptionKeyNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreatePullRequestError::EncryptionKeyNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "EncryptionKeyUnavailableException" => {
-                    return RusotoError::Service(CreatePullRequestError::EncryptionKeyUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreatePullRequestError::EncryptionKeyUnavailable(err.msg),
+                        &res,
+                    )
                 }
                 "IdempotencyParameterMismatchException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         CreatePullRequestError::IdempotencyParameterMismatch(err.msg),
+                        &res,
                     )
                 }
                 "InvalidClientRequestTokenException" => {
-                    return RusotoError::Service(CreatePullRequestError::InvalidClientRequestToken(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreatePullRequestError::InvalidClientRequestToken(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidDescriptionException" => {
-                    return RusotoError::Service(CreatePullRequestError::InvalidDescription(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreatePullRequestError::InvalidDescription(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidReferenceNameException" => {
-                    return RusotoError::Service(CreatePullRequestError::InvalidReferenceName(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreatePullRequestError::InvalidReferenceName(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidRepositoryNameException" => {
-                    return RusotoError::Service(CreatePullRequestError::InvalidRepositoryName(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreatePullRequestError::InvalidRepositoryName(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidTargetException" => {
-                    return RusotoError::Service(CreatePullRequestError::InvalidTarget(err.msg))
+                    return RusotoError::service(
+                        CreatePullRequestError::InvalidTarget(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidTargetsException" => {
-                    return RusotoError::Service(CreatePullRequestError::InvalidTargets(err.msg))
+                    return RusotoError::service(
+                        CreatePullRequestError::InvalidTargets(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidTitleException" => {
-                    return RusotoError::Service(CreatePullRequestError::InvalidTitle(err.msg))
+                    return RusotoError::service(
+                        CreatePullRequestError::InvalidTitle(err.msg),
+                        &res,
+                    )
                 }
                 "MaximumOpenPullRequestsExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         CreatePullRequestError::MaximumOpenPullRequestsExceeded(err.msg),
+                        &res,
                     )
                 }
                 "MultipleRepositoriesInPullRequestException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         CreatePullRequestError::MultipleRepositoriesInPullRequest(err.msg),
+                        &res,
                     )
                 }
                 "ReferenceDoesNotExistException" => {
-                    return RusotoError::Service(CreatePullRequestError::ReferenceDoesNotExist(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreatePullRequestError::ReferenceDoesNotExist(err.msg),
+                        &res,
+                    )
                 }
                 "ReferenceNameRequiredException" => {
-                    return RusotoError::Service(CreatePullRequestError::ReferenceNameRequired(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreatePullRequestError::ReferenceNameRequired(err.msg),
+                        &res,
+                    )
                 }
                 "ReferenceTypeNotSupportedException" => {
-                    return RusotoError::Service(CreatePullRequestError::ReferenceTypeNotSupported(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreatePullRequestError::ReferenceTypeNotSupported(err.msg),
+                        &res,
+                    )
                 }
                 "RepositoryDoesNotExistException" => {
-                    return RusotoError::Service(CreatePullRequestError::RepositoryDoesNotExist(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreatePullRequestError::RepositoryDoesNotExist(err.msg),
+                        &res,
+                    )
                 }
                 "RepositoryNameRequiredException" => {
-                    return RusotoError::Service(CreatePullRequestError::RepositoryNameRequired(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreatePullRequestError::RepositoryNameRequired(err.msg),
+                        &res,
+                    )
                 }
                 "SourceAndDestinationAreSameException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         CreatePullRequestError::SourceAndDestinationAreSame(err.msg),
+                        &res,
                     )
                 }
                 "TargetRequiredException" => {
-                    return RusotoError::Service(CreatePullRequestError::TargetRequired(err.msg))
+                    return RusotoError::service(
+                        CreatePullRequestError::TargetRequired(err.msg),
+                        &res,
+                    )
                 }
                 "TargetsRequiredException" => {
-                    return RusotoError::Service(CreatePullRequestError::TargetsRequired(err.msg))
+                    return RusotoError::service(
+                        CreatePullRequestError::TargetsRequired(err.msg),
+                        &res,
+                    )
                 }
                 "TitleRequiredException" => {
-                    return RusotoError::Service(CreatePullRequestError::TitleRequired(err.msg))
+                    return RusotoError::service(
+                        CreatePullRequestError::TitleRequired(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreatePullRequestError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -4805,12 +5023,14 @@ impl fmt::Display for CreatePullRequestError {
             CreatePullRequestError::TargetRequired(ref cause) => write!(f, "{}", cause),
             CreatePullRequestError::TargetsRequired(ref cause) => write!(f, "{}", cause),
             CreatePullRequestError::TitleRequired(ref cause) => write!(f, "{}", cause),
+            CreatePullRequestError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreatePullRequestError {}
 /// Errors returned by CreatePullRequestApprovalRule
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreatePullRequestApprovalRuleError {
     /// <p>The content for the approval rule is empty. You must provide some content for an approval rule. The content cannot be null.</p>
     ApprovalRuleContentRequired(String),
@@ -4842,6 +5062,8 @@ pub enum CreatePullRequestApprovalRuleError {
     PullRequestDoesNotExist(String),
     /// <p>A pull request ID is required, but none was provided.</p>
     PullRequestIdRequired(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreatePullRequestApprovalRuleError {
@@ -4851,84 +5073,104 @@ impl CreatePullRequestApprovalRuleError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ApprovalRuleContentRequiredException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         CreatePullRequestApprovalRuleError::ApprovalRuleContentRequired(err.msg),
+                        &res,
                     )
                 }
                 "ApprovalRuleNameAlreadyExistsException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         CreatePullRequestApprovalRuleError::ApprovalRuleNameAlreadyExists(err.msg),
+                        &res,
                     )
                 }
                 "ApprovalRuleNameRequiredException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         CreatePullRequestApprovalRuleError::ApprovalRuleNameRequired(err.msg),
+                        &res,
                     )
                 }
                 "EncryptionIntegrityChecksFailedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         CreatePullRequestApprovalRuleError::EncryptionIntegrityChecksFailed(
                             err.msg,
                         ),
+                        &res,
                     )
                 }
                 "EncryptionKeyAccessDeniedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         CreatePullRequestApprovalRuleError::EncryptionKeyAccessDenied(err.msg),
+                        &res,
                     )
                 }
                 "EncryptionKeyDisabledException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         CreatePullRequestApprovalRuleError::EncryptionKeyDisabled(err.msg),
+                        &res,
                     )
                 }
                 "EncryptionKeyNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         CreatePullRequestApprovalRuleError::EncryptionKeyNotFound(err.msg),
+                        &res,
                     )
                 }
                 "EncryptionKeyUnavailableException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         CreatePullRequestApprovalRuleError::EncryptionKeyUnavailable(err.msg),
+                        &res,
                     )
                 }
                 "InvalidApprovalRuleContentException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         CreatePullRequestApprovalRuleError::InvalidApprovalRuleContent(err.msg),
+                        &res,
                     )
                 }
                 "InvalidApprovalRuleNameException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         CreatePullRequestApprovalRuleError::InvalidApprovalRuleName(err.msg),
+                        &res,
                     )
                 }
                 "InvalidPullRequestIdException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         CreatePullRequestApprovalRuleError::InvalidPullRequestId(err.msg),
+                        &res,
                     )
                 }
                 "NumberOfRulesExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         CreatePullRequestApprovalRuleError::NumberOfRulesExceeded(err.msg),
+                        &res,
                     )
                 }
                 "PullRequestAlreadyClosedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         CreatePullRequestApprovalRuleError::PullRequestAlreadyClosed(err.msg),
+                        &res,
                     )
                 }
                 "PullRequestDoesNotExistException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         CreatePullRequestApprovalRuleError::PullRequestDoesNotExist(err.msg),
+                        &res,
                     )
                 }
                 "PullRequestIdRequiredException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         CreatePullRequestApprovalRuleError::PullRequestIdRequired(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        CreatePullRequestApprovalRuleError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -4983,12 +5225,16 @@ impl fmt::Display for CreatePullRequestApprovalRuleError {
             CreatePullRequestApprovalRuleError::PullRequestIdRequired(ref cause) => {
                 write!(f, "{}", cause)
             }
+            CreatePullRequestApprovalRuleError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for CreatePullRequestApprovalRuleError {}
 /// Errors returned by CreateRepository
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateRepositoryError {
     /// <p>An encryption integrity check failed.</p>
     EncryptionIntegrityChecksFailed(String),
@@ -5018,6 +5264,8 @@ pub enum CreateRepositoryError {
     TagPolicy(String),
     /// <p>The maximum number of tags for an AWS CodeCommit resource has been exceeded.</p>
     TooManyTags(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateRepositoryError {
@@ -5025,71 +5273,85 @@ impl CreateRepositoryError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "EncryptionIntegrityChecksFailedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         CreateRepositoryError::EncryptionIntegrityChecksFailed(err.msg),
+                        &res,
                     )
                 }
                 "EncryptionKeyAccessDeniedException" => {
-                    return RusotoError::Service(CreateRepositoryError::EncryptionKeyAccessDenied(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateRepositoryError::EncryptionKeyAccessDenied(err.msg),
+                        &res,
+                    )
                 }
                 "EncryptionKeyDisabledException" => {
-                    return RusotoError::Service(CreateRepositoryError::EncryptionKeyDisabled(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateRepositoryError::EncryptionKeyDisabled(err.msg),
+                        &res,
+                    )
                 }
                 "EncryptionKeyNotFoundException" => {
-                    return RusotoError::Service(CreateRepositoryError::EncryptionKeyNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateRepositoryError::EncryptionKeyNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "EncryptionKeyUnavailableException" => {
-                    return RusotoError::Service(CreateRepositoryError::EncryptionKeyUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateRepositoryError::EncryptionKeyUnavailable(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidRepositoryDescriptionException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         CreateRepositoryError::InvalidRepositoryDescription(err.msg),
+                        &res,
                     )
                 }
                 "InvalidRepositoryNameException" => {
-                    return RusotoError::Service(CreateRepositoryError::InvalidRepositoryName(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateRepositoryError::InvalidRepositoryName(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidSystemTagUsageException" => {
-                    return RusotoError::Service(CreateRepositoryError::InvalidSystemTagUsage(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateRepositoryError::InvalidSystemTagUsage(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidTagsMapException" => {
-                    return RusotoError::Service(CreateRepositoryError::InvalidTagsMap(err.msg))
+                    return RusotoError::service(
+                        CreateRepositoryError::InvalidTagsMap(err.msg),
+                        &res,
+                    )
                 }
                 "RepositoryLimitExceededException" => {
-                    return RusotoError::Service(CreateRepositoryError::RepositoryLimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateRepositoryError::RepositoryLimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "RepositoryNameExistsException" => {
-                    return RusotoError::Service(CreateRepositoryError::RepositoryNameExists(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateRepositoryError::RepositoryNameExists(err.msg),
+                        &res,
+                    )
                 }
                 "RepositoryNameRequiredException" => {
-                    return RusotoError::Service(CreateRepositoryError::RepositoryNameRequired(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateRepositoryError::RepositoryNameRequired(err.msg),
+                        &res,
+                    )
                 }
                 "TagPolicyException" => {
-                    return RusotoError::Service(CreateRepositoryError::TagPolicy(err.msg))
+                    return RusotoError::service(CreateRepositoryError::TagPolicy(err.msg), &res)
                 }
                 "TooManyTagsException" => {
-                    return RusotoError::Service(CreateRepositoryError::TooManyTags(err.msg))
+                    return RusotoError::service(CreateRepositoryError::TooManyTags(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreateRepositoryError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -5117,12 +5379,14 @@ impl fmt::Display for CreateRepositoryError {
             CreateRepositoryError::RepositoryNameRequired(ref cause) => write!(f, "{}", cause),
             CreateRepositoryError::TagPolicy(ref cause) => write!(f, "{}", cause),
             CreateRepositoryError::TooManyTags(ref cause) => write!(f, "{}", cause),
+            CreateRepositoryError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateRepositoryError {}
 /// Errors returned by CreateUnreferencedMergeCommit
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateUnreferencedMergeCommitError {
     /// <p>The specified commit does not exist or no commit was specified, and the specified repository has no default branch.</p>
     CommitDoesNotExist(String),
@@ -5196,6 +5460,8 @@ pub enum CreateUnreferencedMergeCommitError {
     RepositoryNameRequired(String),
     /// <p>The divergence between the tips of the provided commit specifiers is too great to determine whether there might be any merge conflicts. Locally compare the specifiers using <code>git diff</code> or a diff tool.</p>
     TipsDivergenceExceeded(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateUnreferencedMergeCommitError {
@@ -5205,195 +5471,236 @@ impl CreateUnreferencedMergeCommitError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "CommitDoesNotExistException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         CreateUnreferencedMergeCommitError::CommitDoesNotExist(err.msg),
+                        &res,
                     )
                 }
                 "CommitMessageLengthExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         CreateUnreferencedMergeCommitError::CommitMessageLengthExceeded(err.msg),
+                        &res,
                     )
                 }
                 "CommitRequiredException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         CreateUnreferencedMergeCommitError::CommitRequired(err.msg),
+                        &res,
                     )
                 }
                 "ConcurrentReferenceUpdateException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         CreateUnreferencedMergeCommitError::ConcurrentReferenceUpdate(err.msg),
+                        &res,
                     )
                 }
                 "EncryptionIntegrityChecksFailedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         CreateUnreferencedMergeCommitError::EncryptionIntegrityChecksFailed(
                             err.msg,
                         ),
+                        &res,
                     )
                 }
                 "EncryptionKeyAccessDeniedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         CreateUnreferencedMergeCommitError::EncryptionKeyAccessDenied(err.msg),
+                        &res,
                     )
                 }
                 "EncryptionKeyDisabledException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         CreateUnreferencedMergeCommitError::EncryptionKeyDisabled(err.msg),
+                        &res,
                     )
                 }
                 "EncryptionKeyNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         CreateUnreferencedMergeCommitError::EncryptionKeyNotFound(err.msg),
+                        &res,
                     )
                 }
                 "EncryptionKeyUnavailableException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         CreateUnreferencedMergeCommitError::EncryptionKeyUnavailable(err.msg),
+                        &res,
                     )
                 }
                 "FileContentSizeLimitExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         CreateUnreferencedMergeCommitError::FileContentSizeLimitExceeded(err.msg),
+                        &res,
                     )
                 }
                 "FileModeRequiredException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         CreateUnreferencedMergeCommitError::FileModeRequired(err.msg),
+                        &res,
                     )
                 }
                 "FolderContentSizeLimitExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         CreateUnreferencedMergeCommitError::FolderContentSizeLimitExceeded(err.msg),
+                        &res,
                     )
                 }
                 "InvalidCommitException" => {
-                    return RusotoError::Service(CreateUnreferencedMergeCommitError::InvalidCommit(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateUnreferencedMergeCommitError::InvalidCommit(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidConflictDetailLevelException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         CreateUnreferencedMergeCommitError::InvalidConflictDetailLevel(err.msg),
+                        &res,
                     )
                 }
                 "InvalidConflictResolutionException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         CreateUnreferencedMergeCommitError::InvalidConflictResolution(err.msg),
+                        &res,
                     )
                 }
                 "InvalidConflictResolutionStrategyException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         CreateUnreferencedMergeCommitError::InvalidConflictResolutionStrategy(
                             err.msg,
                         ),
+                        &res,
                     )
                 }
                 "InvalidEmailException" => {
-                    return RusotoError::Service(CreateUnreferencedMergeCommitError::InvalidEmail(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateUnreferencedMergeCommitError::InvalidEmail(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidFileModeException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         CreateUnreferencedMergeCommitError::InvalidFileMode(err.msg),
+                        &res,
                     )
                 }
                 "InvalidMergeOptionException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         CreateUnreferencedMergeCommitError::InvalidMergeOption(err.msg),
+                        &res,
                     )
                 }
                 "InvalidPathException" => {
-                    return RusotoError::Service(CreateUnreferencedMergeCommitError::InvalidPath(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateUnreferencedMergeCommitError::InvalidPath(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidReplacementContentException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         CreateUnreferencedMergeCommitError::InvalidReplacementContent(err.msg),
+                        &res,
                     )
                 }
                 "InvalidReplacementTypeException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         CreateUnreferencedMergeCommitError::InvalidReplacementType(err.msg),
+                        &res,
                     )
                 }
                 "InvalidRepositoryNameException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         CreateUnreferencedMergeCommitError::InvalidRepositoryName(err.msg),
+                        &res,
                     )
                 }
                 "ManualMergeRequiredException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         CreateUnreferencedMergeCommitError::ManualMergeRequired(err.msg),
+                        &res,
                     )
                 }
-                "MaximumConflictResolutionEntriesExceededException" => return RusotoError::Service(
+                "MaximumConflictResolutionEntriesExceededException" => return RusotoError::service(
                     CreateUnreferencedMergeCommitError::MaximumConflictResolutionEntriesExceeded(
                         err.msg,
                     ),
+                    &res,
                 ),
                 "MaximumFileContentToLoadExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         CreateUnreferencedMergeCommitError::MaximumFileContentToLoadExceeded(
                             err.msg,
                         ),
+                        &res,
                     )
                 }
                 "MaximumItemsToCompareExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         CreateUnreferencedMergeCommitError::MaximumItemsToCompareExceeded(err.msg),
+                        &res,
                     )
                 }
                 "MergeOptionRequiredException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         CreateUnreferencedMergeCommitError::MergeOptionRequired(err.msg),
+                        &res,
                     )
                 }
                 "MultipleConflictResolutionEntriesException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         CreateUnreferencedMergeCommitError::MultipleConflictResolutionEntries(
                             err.msg,
                         ),
+                        &res,
                     )
                 }
                 "NameLengthExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         CreateUnreferencedMergeCommitError::NameLengthExceeded(err.msg),
+                        &res,
                     )
                 }
                 "PathRequiredException" => {
-                    return RusotoError::Service(CreateUnreferencedMergeCommitError::PathRequired(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateUnreferencedMergeCommitError::PathRequired(err.msg),
+                        &res,
+                    )
                 }
                 "ReplacementContentRequiredException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         CreateUnreferencedMergeCommitError::ReplacementContentRequired(err.msg),
+                        &res,
                     )
                 }
                 "ReplacementTypeRequiredException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         CreateUnreferencedMergeCommitError::ReplacementTypeRequired(err.msg),
+                        &res,
                     )
                 }
                 "RepositoryDoesNotExistException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         CreateUnreferencedMergeCommitError::RepositoryDoesNotExist(err.msg),
+                        &res,
                     )
                 }
                 "RepositoryNameRequiredException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         CreateUnreferencedMergeCommitError::RepositoryNameRequired(err.msg),
+                        &res,
                     )
                 }
                 "TipsDivergenceExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         CreateUnreferencedMergeCommitError::TipsDivergenceExceeded(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        CreateUnreferencedMergeCommitError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -5501,12 +5808,16 @@ impl fmt::Display for CreateUnreferencedMergeCommitError {
             CreateUnreferencedMergeCommitError::TipsDivergenceExceeded(ref cause) => {
                 write!(f, "{}", cause)
             }
+            CreateUnreferencedMergeCommitError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for CreateUnreferencedMergeCommitError {}
 /// Errors returned by DeleteApprovalRuleTemplate
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteApprovalRuleTemplateError {
     /// <p>The approval rule template is associated with one or more repositories. You cannot delete a template that is associated with a repository. Remove all associations, and then try again.</p>
     ApprovalRuleTemplateInUse(String),
@@ -5514,6 +5825,8 @@ pub enum DeleteApprovalRuleTemplateError {
     ApprovalRuleTemplateNameRequired(String),
     /// <p>The name of the approval rule template is not valid. Template names must be between 1 and 100 valid characters in length. For more information about limits in AWS CodeCommit, see <a href="https://docs.aws.amazon.com/codecommit/latest/userguide/limits.html">AWS CodeCommit User Guide</a>.</p>
     InvalidApprovalRuleTemplateName(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteApprovalRuleTemplateError {
@@ -5523,22 +5836,27 @@ impl DeleteApprovalRuleTemplateError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ApprovalRuleTemplateInUseException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DeleteApprovalRuleTemplateError::ApprovalRuleTemplateInUse(err.msg),
+                        &res,
                     )
                 }
                 "ApprovalRuleTemplateNameRequiredException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DeleteApprovalRuleTemplateError::ApprovalRuleTemplateNameRequired(err.msg),
+                        &res,
                     )
                 }
                 "InvalidApprovalRuleTemplateNameException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DeleteApprovalRuleTemplateError::InvalidApprovalRuleTemplateName(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(DeleteApprovalRuleTemplateError::Unhandled, &res)
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -5557,12 +5875,14 @@ impl fmt::Display for DeleteApprovalRuleTemplateError {
             DeleteApprovalRuleTemplateError::InvalidApprovalRuleTemplateName(ref cause) => {
                 write!(f, "{}", cause)
             }
+            DeleteApprovalRuleTemplateError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteApprovalRuleTemplateError {}
 /// Errors returned by DeleteBranch
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteBranchError {
     /// <p>A branch name is required, but was not specified.</p>
     BranchNameRequired(String),
@@ -5586,6 +5906,8 @@ pub enum DeleteBranchError {
     RepositoryDoesNotExist(String),
     /// <p>A repository name is required, but was not specified.</p>
     RepositoryNameRequired(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteBranchError {
@@ -5593,48 +5915,73 @@ impl DeleteBranchError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "BranchNameRequiredException" => {
-                    return RusotoError::Service(DeleteBranchError::BranchNameRequired(err.msg))
+                    return RusotoError::service(
+                        DeleteBranchError::BranchNameRequired(err.msg),
+                        &res,
+                    )
                 }
                 "DefaultBranchCannotBeDeletedException" => {
-                    return RusotoError::Service(DeleteBranchError::DefaultBranchCannotBeDeleted(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteBranchError::DefaultBranchCannotBeDeleted(err.msg),
+                        &res,
+                    )
                 }
                 "EncryptionIntegrityChecksFailedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DeleteBranchError::EncryptionIntegrityChecksFailed(err.msg),
+                        &res,
                     )
                 }
                 "EncryptionKeyAccessDeniedException" => {
-                    return RusotoError::Service(DeleteBranchError::EncryptionKeyAccessDenied(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteBranchError::EncryptionKeyAccessDenied(err.msg),
+                        &res,
+                    )
                 }
                 "EncryptionKeyDisabledException" => {
-                    return RusotoError::Service(DeleteBranchError::EncryptionKeyDisabled(err.msg))
+                    return RusotoError::service(
+                        DeleteBranchError::EncryptionKeyDisabled(err.msg),
+                        &res,
+                    )
                 }
                 "EncryptionKeyNotFoundException" => {
-                    return RusotoError::Service(DeleteBranchError::EncryptionKeyNotFound(err.msg))
+                    return RusotoError::service(
+                        DeleteBranchError::EncryptionKeyNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "EncryptionKeyUnavailableException" => {
-                    return RusotoError::Service(DeleteBranchError::EncryptionKeyUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteBranchError::EncryptionKeyUnavailable(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidBranchNameException" => {
-                    return RusotoError::Service(DeleteBranchError::InvalidBranchName(err.msg))
+                    return RusotoError::service(
+                        DeleteBranchError::InvalidBranchName(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidRepositoryNameException" => {
-                    return RusotoError::Service(DeleteBranchError::InvalidRepositoryName(err.msg))
+                    return RusotoError::service(
+                        DeleteBranchError::InvalidRepositoryName(err.msg),
+                        &res,
+                    )
                 }
                 "RepositoryDoesNotExistException" => {
-                    return RusotoError::Service(DeleteBranchError::RepositoryDoesNotExist(err.msg))
+                    return RusotoError::service(
+                        DeleteBranchError::RepositoryDoesNotExist(err.msg),
+                        &res,
+                    )
                 }
                 "RepositoryNameRequiredException" => {
-                    return RusotoError::Service(DeleteBranchError::RepositoryNameRequired(err.msg))
+                    return RusotoError::service(
+                        DeleteBranchError::RepositoryNameRequired(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteBranchError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -5655,12 +6002,14 @@ impl fmt::Display for DeleteBranchError {
             DeleteBranchError::InvalidRepositoryName(ref cause) => write!(f, "{}", cause),
             DeleteBranchError::RepositoryDoesNotExist(ref cause) => write!(f, "{}", cause),
             DeleteBranchError::RepositoryNameRequired(ref cause) => write!(f, "{}", cause),
+            DeleteBranchError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteBranchError {}
 /// Errors returned by DeleteCommentContent
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteCommentContentError {
     /// <p>This comment has already been deleted. You cannot edit or delete a deleted comment.</p>
     CommentDeleted(String),
@@ -5670,6 +6019,8 @@ pub enum DeleteCommentContentError {
     CommentIdRequired(String),
     /// <p>The comment ID is not in a valid format. Make sure that you have provided the full comment ID.</p>
     InvalidCommentId(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteCommentContentError {
@@ -5677,25 +6028,31 @@ impl DeleteCommentContentError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "CommentDeletedException" => {
-                    return RusotoError::Service(DeleteCommentContentError::CommentDeleted(err.msg))
+                    return RusotoError::service(
+                        DeleteCommentContentError::CommentDeleted(err.msg),
+                        &res,
+                    )
                 }
                 "CommentDoesNotExistException" => {
-                    return RusotoError::Service(DeleteCommentContentError::CommentDoesNotExist(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteCommentContentError::CommentDoesNotExist(err.msg),
+                        &res,
+                    )
                 }
                 "CommentIdRequiredException" => {
-                    return RusotoError::Service(DeleteCommentContentError::CommentIdRequired(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteCommentContentError::CommentIdRequired(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidCommentIdException" => {
-                    return RusotoError::Service(DeleteCommentContentError::InvalidCommentId(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteCommentContentError::InvalidCommentId(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteCommentContentError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -5709,12 +6066,14 @@ impl fmt::Display for DeleteCommentContentError {
             DeleteCommentContentError::CommentDoesNotExist(ref cause) => write!(f, "{}", cause),
             DeleteCommentContentError::CommentIdRequired(ref cause) => write!(f, "{}", cause),
             DeleteCommentContentError::InvalidCommentId(ref cause) => write!(f, "{}", cause),
+            DeleteCommentContentError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteCommentContentError {}
 /// Errors returned by DeleteFile
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteFileError {
     /// <p>The specified branch does not exist.</p>
     BranchDoesNotExist(String),
@@ -5760,6 +6119,8 @@ pub enum DeleteFileError {
     RepositoryDoesNotExist(String),
     /// <p>A repository name is required, but was not specified.</p>
     RepositoryNameRequired(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteFileError {
@@ -5767,79 +6128,115 @@ impl DeleteFileError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "BranchDoesNotExistException" => {
-                    return RusotoError::Service(DeleteFileError::BranchDoesNotExist(err.msg))
+                    return RusotoError::service(DeleteFileError::BranchDoesNotExist(err.msg), &res)
                 }
                 "BranchNameIsTagNameException" => {
-                    return RusotoError::Service(DeleteFileError::BranchNameIsTagName(err.msg))
+                    return RusotoError::service(
+                        DeleteFileError::BranchNameIsTagName(err.msg),
+                        &res,
+                    )
                 }
                 "BranchNameRequiredException" => {
-                    return RusotoError::Service(DeleteFileError::BranchNameRequired(err.msg))
+                    return RusotoError::service(DeleteFileError::BranchNameRequired(err.msg), &res)
                 }
                 "CommitMessageLengthExceededException" => {
-                    return RusotoError::Service(DeleteFileError::CommitMessageLengthExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteFileError::CommitMessageLengthExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "EncryptionIntegrityChecksFailedException" => {
-                    return RusotoError::Service(DeleteFileError::EncryptionIntegrityChecksFailed(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteFileError::EncryptionIntegrityChecksFailed(err.msg),
+                        &res,
+                    )
                 }
                 "EncryptionKeyAccessDeniedException" => {
-                    return RusotoError::Service(DeleteFileError::EncryptionKeyAccessDenied(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteFileError::EncryptionKeyAccessDenied(err.msg),
+                        &res,
+                    )
                 }
                 "EncryptionKeyDisabledException" => {
-                    return RusotoError::Service(DeleteFileError::EncryptionKeyDisabled(err.msg))
+                    return RusotoError::service(
+                        DeleteFileError::EncryptionKeyDisabled(err.msg),
+                        &res,
+                    )
                 }
                 "EncryptionKeyNotFoundException" => {
-                    return RusotoError::Service(DeleteFileError::EncryptionKeyNotFound(err.msg))
+                    return RusotoError::service(
+                        DeleteFileError::EncryptionKeyNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "EncryptionKeyUnavailableException" => {
-                    return RusotoError::Service(DeleteFileError::EncryptionKeyUnavailable(err.msg))
+                    return RusotoError::service(
+                        DeleteFileError::EncryptionKeyUnavailable(err.msg),
+                        &res,
+                    )
                 }
                 "FileDoesNotExistException" => {
-                    return RusotoError::Service(DeleteFileError::FileDoesNotExist(err.msg))
+                    return RusotoError::service(DeleteFileError::FileDoesNotExist(err.msg), &res)
                 }
                 "InvalidBranchNameException" => {
-                    return RusotoError::Service(DeleteFileError::InvalidBranchName(err.msg))
+                    return RusotoError::service(DeleteFileError::InvalidBranchName(err.msg), &res)
                 }
                 "InvalidEmailException" => {
-                    return RusotoError::Service(DeleteFileError::InvalidEmail(err.msg))
+                    return RusotoError::service(DeleteFileError::InvalidEmail(err.msg), &res)
                 }
                 "InvalidParentCommitIdException" => {
-                    return RusotoError::Service(DeleteFileError::InvalidParentCommitId(err.msg))
+                    return RusotoError::service(
+                        DeleteFileError::InvalidParentCommitId(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidPathException" => {
-                    return RusotoError::Service(DeleteFileError::InvalidPath(err.msg))
+                    return RusotoError::service(DeleteFileError::InvalidPath(err.msg), &res)
                 }
                 "InvalidRepositoryNameException" => {
-                    return RusotoError::Service(DeleteFileError::InvalidRepositoryName(err.msg))
+                    return RusotoError::service(
+                        DeleteFileError::InvalidRepositoryName(err.msg),
+                        &res,
+                    )
                 }
                 "NameLengthExceededException" => {
-                    return RusotoError::Service(DeleteFileError::NameLengthExceeded(err.msg))
+                    return RusotoError::service(DeleteFileError::NameLengthExceeded(err.msg), &res)
                 }
                 "ParentCommitDoesNotExistException" => {
-                    return RusotoError::Service(DeleteFileError::ParentCommitDoesNotExist(err.msg))
+                    return RusotoError::service(
+                        DeleteFileError::ParentCommitDoesNotExist(err.msg),
+                        &res,
+                    )
                 }
                 "ParentCommitIdOutdatedException" => {
-                    return RusotoError::Service(DeleteFileError::ParentCommitIdOutdated(err.msg))
+                    return RusotoError::service(
+                        DeleteFileError::ParentCommitIdOutdated(err.msg),
+                        &res,
+                    )
                 }
                 "ParentCommitIdRequiredException" => {
-                    return RusotoError::Service(DeleteFileError::ParentCommitIdRequired(err.msg))
+                    return RusotoError::service(
+                        DeleteFileError::ParentCommitIdRequired(err.msg),
+                        &res,
+                    )
                 }
                 "PathRequiredException" => {
-                    return RusotoError::Service(DeleteFileError::PathRequired(err.msg))
+                    return RusotoError::service(DeleteFileError::PathRequired(err.msg), &res)
                 }
                 "RepositoryDoesNotExistException" => {
-                    return RusotoError::Service(DeleteFileError::RepositoryDoesNotExist(err.msg))
+                    return RusotoError::service(
+                        DeleteFileError::RepositoryDoesNotExist(err.msg),
+                        &res,
+                    )
                 }
                 "RepositoryNameRequiredException" => {
-                    return RusotoError::Service(DeleteFileError::RepositoryNameRequired(err.msg))
+                    return RusotoError::service(
+                        DeleteFileError::RepositoryNameRequired(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteFileError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -5871,12 +6268,14 @@ impl fmt::Display for DeleteFileError {
             DeleteFileError::PathRequired(ref cause) => write!(f, "{}", cause),
             DeleteFileError::RepositoryDoesNotExist(ref cause) => write!(f, "{}", cause),
             DeleteFileError::RepositoryNameRequired(ref cause) => write!(f, "{}", cause),
+            DeleteFileError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteFileError {}
 /// Errors returned by DeletePullRequestApprovalRule
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeletePullRequestApprovalRuleError {
     /// <p>An approval rule name is required, but was not specified.</p>
     ApprovalRuleNameRequired(String),
@@ -5902,6 +6301,8 @@ pub enum DeletePullRequestApprovalRuleError {
     PullRequestDoesNotExist(String),
     /// <p>A pull request ID is required, but none was provided.</p>
     PullRequestIdRequired(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeletePullRequestApprovalRuleError {
@@ -5911,71 +6312,88 @@ impl DeletePullRequestApprovalRuleError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ApprovalRuleNameRequiredException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DeletePullRequestApprovalRuleError::ApprovalRuleNameRequired(err.msg),
+                        &res,
                     )
                 }
                 "CannotDeleteApprovalRuleFromTemplateException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DeletePullRequestApprovalRuleError::CannotDeleteApprovalRuleFromTemplate(
                             err.msg,
                         ),
+                        &res,
                     )
                 }
                 "EncryptionIntegrityChecksFailedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DeletePullRequestApprovalRuleError::EncryptionIntegrityChecksFailed(
                             err.msg,
                         ),
+                        &res,
                     )
                 }
                 "EncryptionKeyAccessDeniedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DeletePullRequestApprovalRuleError::EncryptionKeyAccessDenied(err.msg),
+                        &res,
                     )
                 }
                 "EncryptionKeyDisabledException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DeletePullRequestApprovalRuleError::EncryptionKeyDisabled(err.msg),
+                        &res,
                     )
                 }
                 "EncryptionKeyNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DeletePullRequestApprovalRuleError::EncryptionKeyNotFound(err.msg),
+                        &res,
                     )
                 }
                 "EncryptionKeyUnavailableException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DeletePullRequestApprovalRuleError::EncryptionKeyUnavailable(err.msg),
+                        &res,
                     )
                 }
                 "InvalidApprovalRuleNameException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DeletePullRequestApprovalRuleError::InvalidApprovalRuleName(err.msg),
+                        &res,
                     )
                 }
                 "InvalidPullRequestIdException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DeletePullRequestApprovalRuleError::InvalidPullRequestId(err.msg),
+                        &res,
                     )
                 }
                 "PullRequestAlreadyClosedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DeletePullRequestApprovalRuleError::PullRequestAlreadyClosed(err.msg),
+                        &res,
                     )
                 }
                 "PullRequestDoesNotExistException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DeletePullRequestApprovalRuleError::PullRequestDoesNotExist(err.msg),
+                        &res,
                     )
                 }
                 "PullRequestIdRequiredException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DeletePullRequestApprovalRuleError::PullRequestIdRequired(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        DeletePullRequestApprovalRuleError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -6021,12 +6439,16 @@ impl fmt::Display for DeletePullRequestApprovalRuleError {
             DeletePullRequestApprovalRuleError::PullRequestIdRequired(ref cause) => {
                 write!(f, "{}", cause)
             }
+            DeletePullRequestApprovalRuleError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for DeletePullRequestApprovalRuleError {}
 /// Errors returned by DeleteRepository
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteRepositoryError {
     /// <p>An encryption integrity check failed.</p>
     EncryptionIntegrityChecksFailed(String),
@@ -6042,6 +6464,8 @@ pub enum DeleteRepositoryError {
     InvalidRepositoryName(String),
     /// <p>A repository name is required, but was not specified.</p>
     RepositoryNameRequired(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteRepositoryError {
@@ -6049,42 +6473,49 @@ impl DeleteRepositoryError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "EncryptionIntegrityChecksFailedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DeleteRepositoryError::EncryptionIntegrityChecksFailed(err.msg),
+                        &res,
                     )
                 }
                 "EncryptionKeyAccessDeniedException" => {
-                    return RusotoError::Service(DeleteRepositoryError::EncryptionKeyAccessDenied(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteRepositoryError::EncryptionKeyAccessDenied(err.msg),
+                        &res,
+                    )
                 }
                 "EncryptionKeyDisabledException" => {
-                    return RusotoError::Service(DeleteRepositoryError::EncryptionKeyDisabled(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteRepositoryError::EncryptionKeyDisabled(err.msg),
+                        &res,
+                    )
                 }
                 "EncryptionKeyNotFoundException" => {
-                    return RusotoError::Service(DeleteRepositoryError::EncryptionKeyNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteRepositoryError::EncryptionKeyNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "EncryptionKeyUnavailableException" => {
-                    return RusotoError::Service(DeleteRepositoryError::EncryptionKeyUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteRepositoryError::EncryptionKeyUnavailable(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidRepositoryNameException" => {
-                    return RusotoError::Service(DeleteRepositoryError::InvalidRepositoryName(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteRepositoryError::InvalidRepositoryName(err.msg),
+                        &res,
+                    )
                 }
                 "RepositoryNameRequiredException" => {
-                    return RusotoError::Service(DeleteRepositoryError::RepositoryNameRequired(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteRepositoryError::RepositoryNameRequired(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteRepositoryError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -6103,12 +6534,14 @@ impl fmt::Display for DeleteRepositoryError {
             DeleteRepositoryError::EncryptionKeyUnavailable(ref cause) => write!(f, "{}", cause),
             DeleteRepositoryError::InvalidRepositoryName(ref cause) => write!(f, "{}", cause),
             DeleteRepositoryError::RepositoryNameRequired(ref cause) => write!(f, "{}", cause),
+            DeleteRepositoryError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteRepositoryError {}
 /// Errors returned by DescribeMergeConflicts
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeMergeConflictsError {
     /// <p>The specified commit does not exist or no commit was specified, and the specified repository has no default branch.</p>
     CommitDoesNotExist(String),
@@ -6156,6 +6589,8 @@ pub enum DescribeMergeConflictsError {
     RepositoryNameRequired(String),
     /// <p>The divergence between the tips of the provided commit specifiers is too great to determine whether there might be any merge conflicts. Locally compare the specifiers using <code>git diff</code> or a diff tool.</p>
     TipsDivergenceExceeded(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeMergeConflictsError {
@@ -6163,118 +6598,145 @@ impl DescribeMergeConflictsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "CommitDoesNotExistException" => {
-                    return RusotoError::Service(DescribeMergeConflictsError::CommitDoesNotExist(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeMergeConflictsError::CommitDoesNotExist(err.msg),
+                        &res,
+                    )
                 }
                 "CommitRequiredException" => {
-                    return RusotoError::Service(DescribeMergeConflictsError::CommitRequired(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeMergeConflictsError::CommitRequired(err.msg),
+                        &res,
+                    )
                 }
                 "EncryptionIntegrityChecksFailedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DescribeMergeConflictsError::EncryptionIntegrityChecksFailed(err.msg),
+                        &res,
                     )
                 }
                 "EncryptionKeyAccessDeniedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DescribeMergeConflictsError::EncryptionKeyAccessDenied(err.msg),
+                        &res,
                     )
                 }
                 "EncryptionKeyDisabledException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DescribeMergeConflictsError::EncryptionKeyDisabled(err.msg),
+                        &res,
                     )
                 }
                 "EncryptionKeyNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DescribeMergeConflictsError::EncryptionKeyNotFound(err.msg),
+                        &res,
                     )
                 }
                 "EncryptionKeyUnavailableException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DescribeMergeConflictsError::EncryptionKeyUnavailable(err.msg),
+                        &res,
                     )
                 }
                 "FileDoesNotExistException" => {
-                    return RusotoError::Service(DescribeMergeConflictsError::FileDoesNotExist(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeMergeConflictsError::FileDoesNotExist(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidCommitException" => {
-                    return RusotoError::Service(DescribeMergeConflictsError::InvalidCommit(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeMergeConflictsError::InvalidCommit(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidConflictDetailLevelException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DescribeMergeConflictsError::InvalidConflictDetailLevel(err.msg),
+                        &res,
                     )
                 }
                 "InvalidConflictResolutionStrategyException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DescribeMergeConflictsError::InvalidConflictResolutionStrategy(err.msg),
+                        &res,
                     )
                 }
                 "InvalidContinuationTokenException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DescribeMergeConflictsError::InvalidContinuationToken(err.msg),
+                        &res,
                     )
                 }
                 "InvalidMaxMergeHunksException" => {
-                    return RusotoError::Service(DescribeMergeConflictsError::InvalidMaxMergeHunks(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeMergeConflictsError::InvalidMaxMergeHunks(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidMergeOptionException" => {
-                    return RusotoError::Service(DescribeMergeConflictsError::InvalidMergeOption(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeMergeConflictsError::InvalidMergeOption(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidPathException" => {
-                    return RusotoError::Service(DescribeMergeConflictsError::InvalidPath(err.msg))
+                    return RusotoError::service(
+                        DescribeMergeConflictsError::InvalidPath(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidRepositoryNameException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DescribeMergeConflictsError::InvalidRepositoryName(err.msg),
+                        &res,
                     )
                 }
                 "MaximumFileContentToLoadExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DescribeMergeConflictsError::MaximumFileContentToLoadExceeded(err.msg),
+                        &res,
                     )
                 }
                 "MaximumItemsToCompareExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DescribeMergeConflictsError::MaximumItemsToCompareExceeded(err.msg),
+                        &res,
                     )
                 }
                 "MergeOptionRequiredException" => {
-                    return RusotoError::Service(DescribeMergeConflictsError::MergeOptionRequired(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeMergeConflictsError::MergeOptionRequired(err.msg),
+                        &res,
+                    )
                 }
                 "PathRequiredException" => {
-                    return RusotoError::Service(DescribeMergeConflictsError::PathRequired(err.msg))
+                    return RusotoError::service(
+                        DescribeMergeConflictsError::PathRequired(err.msg),
+                        &res,
+                    )
                 }
                 "RepositoryDoesNotExistException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DescribeMergeConflictsError::RepositoryDoesNotExist(err.msg),
+                        &res,
                     )
                 }
                 "RepositoryNameRequiredException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DescribeMergeConflictsError::RepositoryNameRequired(err.msg),
+                        &res,
                     )
                 }
                 "TipsDivergenceExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DescribeMergeConflictsError::TipsDivergenceExceeded(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DescribeMergeConflictsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -6329,12 +6791,14 @@ impl fmt::Display for DescribeMergeConflictsError {
             DescribeMergeConflictsError::TipsDivergenceExceeded(ref cause) => {
                 write!(f, "{}", cause)
             }
+            DescribeMergeConflictsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeMergeConflictsError {}
 /// Errors returned by DescribePullRequestEvents
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribePullRequestEventsError {
     /// <p>The specified Amazon Resource Name (ARN) does not exist in the AWS account.</p>
     ActorDoesNotExist(String),
@@ -6362,6 +6826,8 @@ pub enum DescribePullRequestEventsError {
     PullRequestDoesNotExist(String),
     /// <p>A pull request ID is required, but none was provided.</p>
     PullRequestIdRequired(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribePullRequestEventsError {
@@ -6369,72 +6835,87 @@ impl DescribePullRequestEventsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ActorDoesNotExistException" => {
-                    return RusotoError::Service(DescribePullRequestEventsError::ActorDoesNotExist(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribePullRequestEventsError::ActorDoesNotExist(err.msg),
+                        &res,
+                    )
                 }
                 "EncryptionIntegrityChecksFailedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DescribePullRequestEventsError::EncryptionIntegrityChecksFailed(err.msg),
+                        &res,
                     )
                 }
                 "EncryptionKeyAccessDeniedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DescribePullRequestEventsError::EncryptionKeyAccessDenied(err.msg),
+                        &res,
                     )
                 }
                 "EncryptionKeyDisabledException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DescribePullRequestEventsError::EncryptionKeyDisabled(err.msg),
+                        &res,
                     )
                 }
                 "EncryptionKeyNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DescribePullRequestEventsError::EncryptionKeyNotFound(err.msg),
+                        &res,
                     )
                 }
                 "EncryptionKeyUnavailableException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DescribePullRequestEventsError::EncryptionKeyUnavailable(err.msg),
+                        &res,
                     )
                 }
                 "InvalidActorArnException" => {
-                    return RusotoError::Service(DescribePullRequestEventsError::InvalidActorArn(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribePullRequestEventsError::InvalidActorArn(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidContinuationTokenException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DescribePullRequestEventsError::InvalidContinuationToken(err.msg),
+                        &res,
                     )
                 }
                 "InvalidMaxResultsException" => {
-                    return RusotoError::Service(DescribePullRequestEventsError::InvalidMaxResults(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribePullRequestEventsError::InvalidMaxResults(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidPullRequestEventTypeException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DescribePullRequestEventsError::InvalidPullRequestEventType(err.msg),
+                        &res,
                     )
                 }
                 "InvalidPullRequestIdException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DescribePullRequestEventsError::InvalidPullRequestId(err.msg),
+                        &res,
                     )
                 }
                 "PullRequestDoesNotExistException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DescribePullRequestEventsError::PullRequestDoesNotExist(err.msg),
+                        &res,
                     )
                 }
                 "PullRequestIdRequiredException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DescribePullRequestEventsError::PullRequestIdRequired(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(DescribePullRequestEventsError::Unhandled, &res)
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -6477,12 +6958,14 @@ impl fmt::Display for DescribePullRequestEventsError {
             DescribePullRequestEventsError::PullRequestIdRequired(ref cause) => {
                 write!(f, "{}", cause)
             }
+            DescribePullRequestEventsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribePullRequestEventsError {}
 /// Errors returned by DisassociateApprovalRuleTemplateFromRepository
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DisassociateApprovalRuleTemplateFromRepositoryError {
     /// <p>The specified approval rule template does not exist. Verify that the name is correct and that you are signed in to the AWS Region where the template was created, and then try again.</p>
     ApprovalRuleTemplateDoesNotExist(String),
@@ -6506,6 +6989,8 @@ pub enum DisassociateApprovalRuleTemplateFromRepositoryError {
     RepositoryDoesNotExist(String),
     /// <p>A repository name is required, but was not specified.</p>
     RepositoryNameRequired(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DisassociateApprovalRuleTemplateFromRepositoryError {
@@ -6514,19 +6999,19 @@ impl DisassociateApprovalRuleTemplateFromRepositoryError {
     ) -> RusotoError<DisassociateApprovalRuleTemplateFromRepositoryError> {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
-                                "ApprovalRuleTemplateDoesNotExistException" => return RusotoError::Service(DisassociateApprovalRuleTemplateFromRepositoryError::ApprovalRuleTemplateDoesNotExist(err.msg)),
-"ApprovalRuleTemplateNameRequiredException" => return RusotoError::Service(DisassociateApprovalRuleTemplateFromRepositoryError::ApprovalRuleTemplateNameRequired(err.msg)),
-"EncryptionIntegrityChecksFailedException" => return RusotoError::Service(DisassociateApprovalRuleTemplateFromRepositoryError::EncryptionIntegrityChecksFailed(err.msg)),
-"EncryptionKeyAccessDeniedException" => return RusotoError::Service(DisassociateApprovalRuleTemplateFromRepositoryError::EncryptionKeyAccessDenied(err.msg)),
-"EncryptionKeyDisabledException" => return RusotoError::Service(DisassociateApprovalRuleTemplateFromRepositoryError::EncryptionKeyDisabled(err.msg)),
-"EncryptionKeyNotFoundException" => return RusotoError::Service(DisassociateApprovalRuleTemplateFromRepositoryError::EncryptionKeyNotFound(err.msg)),
-"EncryptionKeyUnavailableException" => return RusotoError::Service(DisassociateApprovalRuleTemplateFromRepositoryError::EncryptionKeyUnavailable(err.msg)),
-"InvalidApprovalRuleTemplateNameException" => return RusotoError::Service(DisassociateApprovalRuleTemplateFromRepositoryError::InvalidApprovalRuleTemplateName(err.msg)),
-"InvalidRepositoryNameException" => return RusotoError::Service(DisassociateApprovalRuleTemplateFromRepositoryError::InvalidRepositoryName(err.msg)),
-"RepositoryDoesNotExistException" => return RusotoError::Service(DisassociateApprovalRuleTemplateFromRepositoryError::RepositoryDoesNotExist(err.msg)),
-"RepositoryNameRequiredException" => return RusotoError::Service(DisassociateApprovalRuleTemplateFromRepositoryError::RepositoryNameRequired(err.msg)),
+                                "ApprovalRuleTemplateDoesNotExistException" => return RusotoError::service(DisassociateApprovalRuleTemplateFromRepositoryError::ApprovalRuleTemplateDoesNotExist(err.msg), &res),
+"ApprovalRuleTemplateNameRequiredException" => return RusotoError::service(DisassociateApprovalRuleTemplateFromRepositoryError::ApprovalRuleTemplateNameRequired(err.msg), &res),
+"EncryptionIntegrityChecksFailedException" => return RusotoError::service(DisassociateApprovalRuleTemplateFromRepositoryError::EncryptionIntegrityChecksFailed(err.msg), &res),
+"EncryptionKeyAccessDeniedException" => return RusotoError::service(DisassociateApprovalRuleTemplateFromRepositoryError::EncryptionKeyAccessDenied(err.msg), &res),
+"EncryptionKeyDisabledException" => return RusotoError::service(DisassociateApprovalRuleTemplateFromRepositoryError::EncryptionKeyDisabled(err.msg), &res),
+"EncryptionKeyNotFoundException" => return RusotoError::service(DisassociateApprovalRuleTemplateFromRepositoryError::EncryptionKeyNotFound(err.msg), &res),
+"EncryptionKeyUnavailableException" => return RusotoError::service(DisassociateApprovalRuleTemplateFromRepositoryError::EncryptionKeyUnavailable(err.msg), &res),
+"InvalidApprovalRuleTemplateNameException" => return RusotoError::service(DisassociateApprovalRuleTemplateFromRepositoryError::InvalidApprovalRuleTemplateName(err.msg), &res),
+"InvalidRepositoryNameException" => return RusotoError::service(DisassociateApprovalRuleTemplateFromRepositoryError::InvalidRepositoryName(err.msg), &res),
+"RepositoryDoesNotExistException" => return RusotoError::service(DisassociateApprovalRuleTemplateFromRepositoryError::RepositoryDoesNotExist(err.msg), &res),
+"RepositoryNameRequiredException" => return RusotoError::service(DisassociateApprovalRuleTemplateFromRepositoryError::RepositoryNameRequired(err.msg), &res),
 "ValidationException" => return RusotoError::Validation(err.msg),
-_ => {}
+_ => return RusotoError::unhandled(DisassociateApprovalRuleTemplateFromRepositoryError::Unhandled, &res),
                             }
         }
         RusotoError::Unknown(res)
@@ -6546,13 +7031,15 @@ DisassociateApprovalRuleTemplateFromRepositoryError::EncryptionKeyUnavailable(re
 DisassociateApprovalRuleTemplateFromRepositoryError::InvalidApprovalRuleTemplateName(ref cause) => write!(f, "{}", cause),
 DisassociateApprovalRuleTemplateFromRepositoryError::InvalidRepositoryName(ref cause) => write!(f, "{}", cause),
 DisassociateApprovalRuleTemplateFromRepositoryError::RepositoryDoesNotExist(ref cause) => write!(f, "{}", cause),
-DisassociateApprovalRuleTemplateFromRepositoryError::RepositoryNameRequired(ref cause) => write!(f, "{}", cause)
-                        }
+DisassociateApprovalRuleTemplateFromRepositoryError::RepositoryNameRequired(ref cause) => write!(f, "{}", cause),
+            DisassociateApprovalRuleTemplateFromRepositoryError::Unhandled(ref metadata) => write!(f, "{}", metadata),
+        }
     }
 }
 impl Error for DisassociateApprovalRuleTemplateFromRepositoryError {}
 /// Errors returned by EvaluatePullRequestApprovalRules
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum EvaluatePullRequestApprovalRulesError {
     /// <p>An encryption integrity check failed.</p>
     EncryptionIntegrityChecksFailed(String),
@@ -6576,6 +7063,8 @@ pub enum EvaluatePullRequestApprovalRulesError {
     RevisionIdRequired(String),
     /// <p>The revision ID provided in the request does not match the current revision ID. Use GetPullRequest to retrieve the current revision ID.</p>
     RevisionNotCurrent(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl EvaluatePullRequestApprovalRulesError {
@@ -6585,64 +7074,80 @@ impl EvaluatePullRequestApprovalRulesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "EncryptionIntegrityChecksFailedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         EvaluatePullRequestApprovalRulesError::EncryptionIntegrityChecksFailed(
                             err.msg,
                         ),
+                        &res,
                     )
                 }
                 "EncryptionKeyAccessDeniedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         EvaluatePullRequestApprovalRulesError::EncryptionKeyAccessDenied(err.msg),
+                        &res,
                     )
                 }
                 "EncryptionKeyDisabledException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         EvaluatePullRequestApprovalRulesError::EncryptionKeyDisabled(err.msg),
+                        &res,
                     )
                 }
                 "EncryptionKeyNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         EvaluatePullRequestApprovalRulesError::EncryptionKeyNotFound(err.msg),
+                        &res,
                     )
                 }
                 "EncryptionKeyUnavailableException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         EvaluatePullRequestApprovalRulesError::EncryptionKeyUnavailable(err.msg),
+                        &res,
                     )
                 }
                 "InvalidPullRequestIdException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         EvaluatePullRequestApprovalRulesError::InvalidPullRequestId(err.msg),
+                        &res,
                     )
                 }
                 "InvalidRevisionIdException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         EvaluatePullRequestApprovalRulesError::InvalidRevisionId(err.msg),
+                        &res,
                     )
                 }
                 "PullRequestDoesNotExistException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         EvaluatePullRequestApprovalRulesError::PullRequestDoesNotExist(err.msg),
+                        &res,
                     )
                 }
                 "PullRequestIdRequiredException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         EvaluatePullRequestApprovalRulesError::PullRequestIdRequired(err.msg),
+                        &res,
                     )
                 }
                 "RevisionIdRequiredException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         EvaluatePullRequestApprovalRulesError::RevisionIdRequired(err.msg),
+                        &res,
                     )
                 }
                 "RevisionNotCurrentException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         EvaluatePullRequestApprovalRulesError::RevisionNotCurrent(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        EvaluatePullRequestApprovalRulesError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -6685,12 +7190,16 @@ impl fmt::Display for EvaluatePullRequestApprovalRulesError {
             EvaluatePullRequestApprovalRulesError::RevisionNotCurrent(ref cause) => {
                 write!(f, "{}", cause)
             }
+            EvaluatePullRequestApprovalRulesError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for EvaluatePullRequestApprovalRulesError {}
 /// Errors returned by GetApprovalRuleTemplate
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetApprovalRuleTemplateError {
     /// <p>The specified approval rule template does not exist. Verify that the name is correct and that you are signed in to the AWS Region where the template was created, and then try again.</p>
     ApprovalRuleTemplateDoesNotExist(String),
@@ -6698,6 +7207,8 @@ pub enum GetApprovalRuleTemplateError {
     ApprovalRuleTemplateNameRequired(String),
     /// <p>The name of the approval rule template is not valid. Template names must be between 1 and 100 valid characters in length. For more information about limits in AWS CodeCommit, see <a href="https://docs.aws.amazon.com/codecommit/latest/userguide/limits.html">AWS CodeCommit User Guide</a>.</p>
     InvalidApprovalRuleTemplateName(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetApprovalRuleTemplateError {
@@ -6705,22 +7216,25 @@ impl GetApprovalRuleTemplateError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ApprovalRuleTemplateDoesNotExistException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         GetApprovalRuleTemplateError::ApprovalRuleTemplateDoesNotExist(err.msg),
+                        &res,
                     )
                 }
                 "ApprovalRuleTemplateNameRequiredException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         GetApprovalRuleTemplateError::ApprovalRuleTemplateNameRequired(err.msg),
+                        &res,
                     )
                 }
                 "InvalidApprovalRuleTemplateNameException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         GetApprovalRuleTemplateError::InvalidApprovalRuleTemplateName(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetApprovalRuleTemplateError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -6739,12 +7253,14 @@ impl fmt::Display for GetApprovalRuleTemplateError {
             GetApprovalRuleTemplateError::InvalidApprovalRuleTemplateName(ref cause) => {
                 write!(f, "{}", cause)
             }
+            GetApprovalRuleTemplateError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetApprovalRuleTemplateError {}
 /// Errors returned by GetBlob
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetBlobError {
     /// <p>The specified blob does not exist.</p>
     BlobIdDoesNotExist(String),
@@ -6770,6 +7286,8 @@ pub enum GetBlobError {
     RepositoryDoesNotExist(String),
     /// <p>A repository name is required, but was not specified.</p>
     RepositoryNameRequired(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetBlobError {
@@ -6777,45 +7295,58 @@ impl GetBlobError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "BlobIdDoesNotExistException" => {
-                    return RusotoError::Service(GetBlobError::BlobIdDoesNotExist(err.msg))
+                    return RusotoError::service(GetBlobError::BlobIdDoesNotExist(err.msg), &res)
                 }
                 "BlobIdRequiredException" => {
-                    return RusotoError::Service(GetBlobError::BlobIdRequired(err.msg))
+                    return RusotoError::service(GetBlobError::BlobIdRequired(err.msg), &res)
                 }
                 "EncryptionIntegrityChecksFailedException" => {
-                    return RusotoError::Service(GetBlobError::EncryptionIntegrityChecksFailed(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetBlobError::EncryptionIntegrityChecksFailed(err.msg),
+                        &res,
+                    )
                 }
                 "EncryptionKeyAccessDeniedException" => {
-                    return RusotoError::Service(GetBlobError::EncryptionKeyAccessDenied(err.msg))
+                    return RusotoError::service(
+                        GetBlobError::EncryptionKeyAccessDenied(err.msg),
+                        &res,
+                    )
                 }
                 "EncryptionKeyDisabledException" => {
-                    return RusotoError::Service(GetBlobError::EncryptionKeyDisabled(err.msg))
+                    return RusotoError::service(GetBlobError::EncryptionKeyDisabled(err.msg), &res)
                 }
                 "EncryptionKeyNotFoundException" => {
-                    return RusotoError::Service(GetBlobError::EncryptionKeyNotFound(err.msg))
+                    return RusotoError::service(GetBlobError::EncryptionKeyNotFound(err.msg), &res)
                 }
                 "EncryptionKeyUnavailableException" => {
-                    return RusotoError::Service(GetBlobError::EncryptionKeyUnavailable(err.msg))
+                    return RusotoError::service(
+                        GetBlobError::EncryptionKeyUnavailable(err.msg),
+                        &res,
+                    )
                 }
                 "FileTooLargeException" => {
-                    return RusotoError::Service(GetBlobError::FileTooLarge(err.msg))
+                    return RusotoError::service(GetBlobError::FileTooLarge(err.msg), &res)
                 }
                 "InvalidBlobIdException" => {
-                    return RusotoError::Service(GetBlobError::InvalidBlobId(err.msg))
+                    return RusotoError::service(GetBlobError::InvalidBlobId(err.msg), &res)
                 }
                 "InvalidRepositoryNameException" => {
-                    return RusotoError::Service(GetBlobError::InvalidRepositoryName(err.msg))
+                    return RusotoError::service(GetBlobError::InvalidRepositoryName(err.msg), &res)
                 }
                 "RepositoryDoesNotExistException" => {
-                    return RusotoError::Service(GetBlobError::RepositoryDoesNotExist(err.msg))
+                    return RusotoError::service(
+                        GetBlobError::RepositoryDoesNotExist(err.msg),
+                        &res,
+                    )
                 }
                 "RepositoryNameRequiredException" => {
-                    return RusotoError::Service(GetBlobError::RepositoryNameRequired(err.msg))
+                    return RusotoError::service(
+                        GetBlobError::RepositoryNameRequired(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetBlobError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -6837,12 +7368,14 @@ impl fmt::Display for GetBlobError {
             GetBlobError::InvalidRepositoryName(ref cause) => write!(f, "{}", cause),
             GetBlobError::RepositoryDoesNotExist(ref cause) => write!(f, "{}", cause),
             GetBlobError::RepositoryNameRequired(ref cause) => write!(f, "{}", cause),
+            GetBlobError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetBlobError {}
 /// Errors returned by GetBranch
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetBranchError {
     /// <p>The specified branch does not exist.</p>
     BranchDoesNotExist(String),
@@ -6866,6 +7399,8 @@ pub enum GetBranchError {
     RepositoryDoesNotExist(String),
     /// <p>A repository name is required, but was not specified.</p>
     RepositoryNameRequired(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetBranchError {
@@ -6873,42 +7408,64 @@ impl GetBranchError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "BranchDoesNotExistException" => {
-                    return RusotoError::Service(GetBranchError::BranchDoesNotExist(err.msg))
+                    return RusotoError::service(GetBranchError::BranchDoesNotExist(err.msg), &res)
                 }
                 "BranchNameRequiredException" => {
-                    return RusotoError::Service(GetBranchError::BranchNameRequired(err.msg))
+                    return RusotoError::service(GetBranchError::BranchNameRequired(err.msg), &res)
                 }
                 "EncryptionIntegrityChecksFailedException" => {
-                    return RusotoError::Service(GetBranchError::EncryptionIntegrityChecksFailed(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetBranchError::EncryptionIntegrityChecksFailed(err.msg),
+                        &res,
+                    )
                 }
                 "EncryptionKeyAccessDeniedException" => {
-                    return RusotoError::Service(GetBranchError::EncryptionKeyAccessDenied(err.msg))
+                    return RusotoError::service(
+                        GetBranchError::EncryptionKeyAccessDenied(err.msg),
+                        &res,
+                    )
                 }
                 "EncryptionKeyDisabledException" => {
-                    return RusotoError::Service(GetBranchError::EncryptionKeyDisabled(err.msg))
+                    return RusotoError::service(
+                        GetBranchError::EncryptionKeyDisabled(err.msg),
+                        &res,
+                    )
                 }
                 "EncryptionKeyNotFoundException" => {
-                    return RusotoError::Service(GetBranchError::EncryptionKeyNotFound(err.msg))
+                    return RusotoError::service(
+                        GetBranchError::EncryptionKeyNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "EncryptionKeyUnavailableException" => {
-                    return RusotoError::Service(GetBranchError::EncryptionKeyUnavailable(err.msg))
+                    return RusotoError::service(
+                        GetBranchError::EncryptionKeyUnavailable(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidBranchNameException" => {
-                    return RusotoError::Service(GetBranchError::InvalidBranchName(err.msg))
+                    return RusotoError::service(GetBranchError::InvalidBranchName(err.msg), &res)
                 }
                 "InvalidRepositoryNameException" => {
-                    return RusotoError::Service(GetBranchError::InvalidRepositoryName(err.msg))
+                    return RusotoError::service(
+                        GetBranchError::InvalidRepositoryName(err.msg),
+                        &res,
+                    )
                 }
                 "RepositoryDoesNotExistException" => {
-                    return RusotoError::Service(GetBranchError::RepositoryDoesNotExist(err.msg))
+                    return RusotoError::service(
+                        GetBranchError::RepositoryDoesNotExist(err.msg),
+                        &res,
+                    )
                 }
                 "RepositoryNameRequiredException" => {
-                    return RusotoError::Service(GetBranchError::RepositoryNameRequired(err.msg))
+                    return RusotoError::service(
+                        GetBranchError::RepositoryNameRequired(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetBranchError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -6929,12 +7486,14 @@ impl fmt::Display for GetBranchError {
             GetBranchError::InvalidRepositoryName(ref cause) => write!(f, "{}", cause),
             GetBranchError::RepositoryDoesNotExist(ref cause) => write!(f, "{}", cause),
             GetBranchError::RepositoryNameRequired(ref cause) => write!(f, "{}", cause),
+            GetBranchError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetBranchError {}
 /// Errors returned by GetComment
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetCommentError {
     /// <p>This comment has already been deleted. You cannot edit or delete a deleted comment.</p>
     CommentDeleted(String),
@@ -6944,6 +7503,8 @@ pub enum GetCommentError {
     CommentIdRequired(String),
     /// <p>The comment ID is not in a valid format. Make sure that you have provided the full comment ID.</p>
     InvalidCommentId(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetCommentError {
@@ -6951,19 +7512,22 @@ impl GetCommentError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "CommentDeletedException" => {
-                    return RusotoError::Service(GetCommentError::CommentDeleted(err.msg))
+                    return RusotoError::service(GetCommentError::CommentDeleted(err.msg), &res)
                 }
                 "CommentDoesNotExistException" => {
-                    return RusotoError::Service(GetCommentError::CommentDoesNotExist(err.msg))
+                    return RusotoError::service(
+                        GetCommentError::CommentDoesNotExist(err.msg),
+                        &res,
+                    )
                 }
                 "CommentIdRequiredException" => {
-                    return RusotoError::Service(GetCommentError::CommentIdRequired(err.msg))
+                    return RusotoError::service(GetCommentError::CommentIdRequired(err.msg), &res)
                 }
                 "InvalidCommentIdException" => {
-                    return RusotoError::Service(GetCommentError::InvalidCommentId(err.msg))
+                    return RusotoError::service(GetCommentError::InvalidCommentId(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetCommentError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -6977,12 +7541,14 @@ impl fmt::Display for GetCommentError {
             GetCommentError::CommentDoesNotExist(ref cause) => write!(f, "{}", cause),
             GetCommentError::CommentIdRequired(ref cause) => write!(f, "{}", cause),
             GetCommentError::InvalidCommentId(ref cause) => write!(f, "{}", cause),
+            GetCommentError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetCommentError {}
 /// Errors returned by GetCommentsForComparedCommit
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetCommentsForComparedCommitError {
     /// <p>The specified commit does not exist or no commit was specified, and the specified repository has no default branch.</p>
     CommitDoesNotExist(String),
@@ -7010,6 +7576,8 @@ pub enum GetCommentsForComparedCommitError {
     RepositoryDoesNotExist(String),
     /// <p>A repository name is required, but was not specified.</p>
     RepositoryNameRequired(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetCommentsForComparedCommitError {
@@ -7019,72 +7587,90 @@ impl GetCommentsForComparedCommitError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "CommitDoesNotExistException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         GetCommentsForComparedCommitError::CommitDoesNotExist(err.msg),
+                        &res,
                     )
                 }
                 "CommitIdRequiredException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         GetCommentsForComparedCommitError::CommitIdRequired(err.msg),
+                        &res,
                     )
                 }
                 "EncryptionIntegrityChecksFailedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         GetCommentsForComparedCommitError::EncryptionIntegrityChecksFailed(err.msg),
+                        &res,
                     )
                 }
                 "EncryptionKeyAccessDeniedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         GetCommentsForComparedCommitError::EncryptionKeyAccessDenied(err.msg),
+                        &res,
                     )
                 }
                 "EncryptionKeyDisabledException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         GetCommentsForComparedCommitError::EncryptionKeyDisabled(err.msg),
+                        &res,
                     )
                 }
                 "EncryptionKeyNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         GetCommentsForComparedCommitError::EncryptionKeyNotFound(err.msg),
+                        &res,
                     )
                 }
                 "EncryptionKeyUnavailableException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         GetCommentsForComparedCommitError::EncryptionKeyUnavailable(err.msg),
+                        &res,
                     )
                 }
                 "InvalidCommitIdException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         GetCommentsForComparedCommitError::InvalidCommitId(err.msg),
+                        &res,
                     )
                 }
                 "InvalidContinuationTokenException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         GetCommentsForComparedCommitError::InvalidContinuationToken(err.msg),
+                        &res,
                     )
                 }
                 "InvalidMaxResultsException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         GetCommentsForComparedCommitError::InvalidMaxResults(err.msg),
+                        &res,
                     )
                 }
                 "InvalidRepositoryNameException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         GetCommentsForComparedCommitError::InvalidRepositoryName(err.msg),
+                        &res,
                     )
                 }
                 "RepositoryDoesNotExistException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         GetCommentsForComparedCommitError::RepositoryDoesNotExist(err.msg),
+                        &res,
                     )
                 }
                 "RepositoryNameRequiredException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         GetCommentsForComparedCommitError::RepositoryNameRequired(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        GetCommentsForComparedCommitError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -7131,12 +7717,14 @@ impl fmt::Display for GetCommentsForComparedCommitError {
             GetCommentsForComparedCommitError::RepositoryNameRequired(ref cause) => {
                 write!(f, "{}", cause)
             }
+            GetCommentsForComparedCommitError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetCommentsForComparedCommitError {}
 /// Errors returned by GetCommentsForPullRequest
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetCommentsForPullRequestError {
     /// <p>The specified commit does not exist or no commit was specified, and the specified repository has no default branch.</p>
     CommitDoesNotExist(String),
@@ -7172,6 +7760,8 @@ pub enum GetCommentsForPullRequestError {
     RepositoryNameRequired(String),
     /// <p>The repository does not contain any pull requests with that pull request ID. Use GetPullRequest to verify the correct repository name for the pull request ID.</p>
     RepositoryNotAssociatedWithPullRequest(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetCommentsForPullRequestError {
@@ -7179,94 +7769,113 @@ impl GetCommentsForPullRequestError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "CommitDoesNotExistException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         GetCommentsForPullRequestError::CommitDoesNotExist(err.msg),
+                        &res,
                     )
                 }
                 "CommitIdRequiredException" => {
-                    return RusotoError::Service(GetCommentsForPullRequestError::CommitIdRequired(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetCommentsForPullRequestError::CommitIdRequired(err.msg),
+                        &res,
+                    )
                 }
                 "EncryptionIntegrityChecksFailedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         GetCommentsForPullRequestError::EncryptionIntegrityChecksFailed(err.msg),
+                        &res,
                     )
                 }
                 "EncryptionKeyAccessDeniedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         GetCommentsForPullRequestError::EncryptionKeyAccessDenied(err.msg),
+                        &res,
                     )
                 }
                 "EncryptionKeyDisabledException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         GetCommentsForPullRequestError::EncryptionKeyDisabled(err.msg),
+                        &res,
                     )
                 }
                 "EncryptionKeyNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         GetCommentsForPullRequestError::EncryptionKeyNotFound(err.msg),
+                        &res,
                     )
                 }
                 "EncryptionKeyUnavailableException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         GetCommentsForPullRequestError::EncryptionKeyUnavailable(err.msg),
+                        &res,
                     )
                 }
                 "InvalidCommitIdException" => {
-                    return RusotoError::Service(GetCommentsForPullRequestError::InvalidCommitId(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetCommentsForPullRequestError::InvalidCommitId(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidContinuationTokenException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         GetCommentsForPullRequestError::InvalidContinuationToken(err.msg),
+                        &res,
                     )
                 }
                 "InvalidMaxResultsException" => {
-                    return RusotoError::Service(GetCommentsForPullRequestError::InvalidMaxResults(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetCommentsForPullRequestError::InvalidMaxResults(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidPullRequestIdException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         GetCommentsForPullRequestError::InvalidPullRequestId(err.msg),
+                        &res,
                     )
                 }
                 "InvalidRepositoryNameException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         GetCommentsForPullRequestError::InvalidRepositoryName(err.msg),
+                        &res,
                     )
                 }
                 "PullRequestDoesNotExistException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         GetCommentsForPullRequestError::PullRequestDoesNotExist(err.msg),
+                        &res,
                     )
                 }
                 "PullRequestIdRequiredException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         GetCommentsForPullRequestError::PullRequestIdRequired(err.msg),
+                        &res,
                     )
                 }
                 "RepositoryDoesNotExistException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         GetCommentsForPullRequestError::RepositoryDoesNotExist(err.msg),
+                        &res,
                     )
                 }
                 "RepositoryNameRequiredException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         GetCommentsForPullRequestError::RepositoryNameRequired(err.msg),
+                        &res,
                     )
                 }
                 "RepositoryNotAssociatedWithPullRequestException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         GetCommentsForPullRequestError::RepositoryNotAssociatedWithPullRequest(
                             err.msg,
                         ),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(GetCommentsForPullRequestError::Unhandled, &res)
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -7319,12 +7928,14 @@ impl fmt::Display for GetCommentsForPullRequestError {
             GetCommentsForPullRequestError::RepositoryNotAssociatedWithPullRequest(ref cause) => {
                 write!(f, "{}", cause)
             }
+            GetCommentsForPullRequestError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetCommentsForPullRequestError {}
 /// Errors returned by GetCommit
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetCommitError {
     /// <p>The specified commit ID does not exist.</p>
     CommitIdDoesNotExist(String),
@@ -7348,6 +7959,8 @@ pub enum GetCommitError {
     RepositoryDoesNotExist(String),
     /// <p>A repository name is required, but was not specified.</p>
     RepositoryNameRequired(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetCommitError {
@@ -7355,42 +7968,67 @@ impl GetCommitError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "CommitIdDoesNotExistException" => {
-                    return RusotoError::Service(GetCommitError::CommitIdDoesNotExist(err.msg))
+                    return RusotoError::service(
+                        GetCommitError::CommitIdDoesNotExist(err.msg),
+                        &res,
+                    )
                 }
                 "CommitIdRequiredException" => {
-                    return RusotoError::Service(GetCommitError::CommitIdRequired(err.msg))
+                    return RusotoError::service(GetCommitError::CommitIdRequired(err.msg), &res)
                 }
                 "EncryptionIntegrityChecksFailedException" => {
-                    return RusotoError::Service(GetCommitError::EncryptionIntegrityChecksFailed(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetCommitError::EncryptionIntegrityChecksFailed(err.msg),
+                        &res,
+                    )
                 }
                 "EncryptionKeyAccessDeniedException" => {
-                    return RusotoError::Service(GetCommitError::EncryptionKeyAccessDenied(err.msg))
+                    return RusotoError::service(
+                        GetCommitError::EncryptionKeyAccessDenied(err.msg),
+                        &res,
+                    )
                 }
                 "EncryptionKeyDisabledException" => {
-                    return RusotoError::Service(GetCommitError::EncryptionKeyDisabled(err.msg))
+                    return RusotoError::service(
+                        GetCommitError::EncryptionKeyDisabled(err.msg),
+                        &res,
+                    )
                 }
                 "EncryptionKeyNotFoundException" => {
-                    return RusotoError::Service(GetCommitError::EncryptionKeyNotFound(err.msg))
+                    return RusotoError::service(
+                        GetCommitError::EncryptionKeyNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "EncryptionKeyUnavailableException" => {
-                    return RusotoError::Service(GetCommitError::EncryptionKeyUnavailable(err.msg))
+                    return RusotoError::service(
+                        GetCommitError::EncryptionKeyUnavailable(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidCommitIdException" => {
-                    return RusotoError::Service(GetCommitError::InvalidCommitId(err.msg))
+                    return RusotoError::service(GetCommitError::InvalidCommitId(err.msg), &res)
                 }
                 "InvalidRepositoryNameException" => {
-                    return RusotoError::Service(GetCommitError::InvalidRepositoryName(err.msg))
+                    return RusotoError::service(
+                        GetCommitError::InvalidRepositoryName(err.msg),
+                        &res,
+                    )
                 }
                 "RepositoryDoesNotExistException" => {
-                    return RusotoError::Service(GetCommitError::RepositoryDoesNotExist(err.msg))
+                    return RusotoError::service(
+                        GetCommitError::RepositoryDoesNotExist(err.msg),
+                        &res,
+                    )
                 }
                 "RepositoryNameRequiredException" => {
-                    return RusotoError::Service(GetCommitError::RepositoryNameRequired(err.msg))
+                    return RusotoError::service(
+                        GetCommitError::RepositoryNameRequired(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetCommitError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -7411,12 +8049,14 @@ impl fmt::Display for GetCommitError {
             GetCommitError::InvalidRepositoryName(ref cause) => write!(f, "{}", cause),
             GetCommitError::RepositoryDoesNotExist(ref cause) => write!(f, "{}", cause),
             GetCommitError::RepositoryNameRequired(ref cause) => write!(f, "{}", cause),
+            GetCommitError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetCommitError {}
 /// Errors returned by GetDifferences
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetDifferencesError {
     /// <p>The specified commit does not exist or no commit was specified, and the specified repository has no default branch.</p>
     CommitDoesNotExist(String),
@@ -7450,6 +8090,8 @@ pub enum GetDifferencesError {
     RepositoryDoesNotExist(String),
     /// <p>A repository name is required, but was not specified.</p>
     RepositoryNameRequired(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetDifferencesError {
@@ -7457,73 +8099,94 @@ impl GetDifferencesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "CommitDoesNotExistException" => {
-                    return RusotoError::Service(GetDifferencesError::CommitDoesNotExist(err.msg))
+                    return RusotoError::service(
+                        GetDifferencesError::CommitDoesNotExist(err.msg),
+                        &res,
+                    )
                 }
                 "CommitRequiredException" => {
-                    return RusotoError::Service(GetDifferencesError::CommitRequired(err.msg))
+                    return RusotoError::service(GetDifferencesError::CommitRequired(err.msg), &res)
                 }
                 "EncryptionIntegrityChecksFailedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         GetDifferencesError::EncryptionIntegrityChecksFailed(err.msg),
+                        &res,
                     )
                 }
                 "EncryptionKeyAccessDeniedException" => {
-                    return RusotoError::Service(GetDifferencesError::EncryptionKeyAccessDenied(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetDifferencesError::EncryptionKeyAccessDenied(err.msg),
+                        &res,
+                    )
                 }
                 "EncryptionKeyDisabledException" => {
-                    return RusotoError::Service(GetDifferencesError::EncryptionKeyDisabled(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetDifferencesError::EncryptionKeyDisabled(err.msg),
+                        &res,
+                    )
                 }
                 "EncryptionKeyNotFoundException" => {
-                    return RusotoError::Service(GetDifferencesError::EncryptionKeyNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetDifferencesError::EncryptionKeyNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "EncryptionKeyUnavailableException" => {
-                    return RusotoError::Service(GetDifferencesError::EncryptionKeyUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetDifferencesError::EncryptionKeyUnavailable(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidCommitException" => {
-                    return RusotoError::Service(GetDifferencesError::InvalidCommit(err.msg))
+                    return RusotoError::service(GetDifferencesError::InvalidCommit(err.msg), &res)
                 }
                 "InvalidCommitIdException" => {
-                    return RusotoError::Service(GetDifferencesError::InvalidCommitId(err.msg))
+                    return RusotoError::service(
+                        GetDifferencesError::InvalidCommitId(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidContinuationTokenException" => {
-                    return RusotoError::Service(GetDifferencesError::InvalidContinuationToken(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetDifferencesError::InvalidContinuationToken(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidMaxResultsException" => {
-                    return RusotoError::Service(GetDifferencesError::InvalidMaxResults(err.msg))
+                    return RusotoError::service(
+                        GetDifferencesError::InvalidMaxResults(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidPathException" => {
-                    return RusotoError::Service(GetDifferencesError::InvalidPath(err.msg))
+                    return RusotoError::service(GetDifferencesError::InvalidPath(err.msg), &res)
                 }
                 "InvalidRepositoryNameException" => {
-                    return RusotoError::Service(GetDifferencesError::InvalidRepositoryName(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetDifferencesError::InvalidRepositoryName(err.msg),
+                        &res,
+                    )
                 }
                 "PathDoesNotExistException" => {
-                    return RusotoError::Service(GetDifferencesError::PathDoesNotExist(err.msg))
+                    return RusotoError::service(
+                        GetDifferencesError::PathDoesNotExist(err.msg),
+                        &res,
+                    )
                 }
                 "RepositoryDoesNotExistException" => {
-                    return RusotoError::Service(GetDifferencesError::RepositoryDoesNotExist(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetDifferencesError::RepositoryDoesNotExist(err.msg),
+                        &res,
+                    )
                 }
                 "RepositoryNameRequiredException" => {
-                    return RusotoError::Service(GetDifferencesError::RepositoryNameRequired(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetDifferencesError::RepositoryNameRequired(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetDifferencesError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -7551,12 +8214,14 @@ impl fmt::Display for GetDifferencesError {
             GetDifferencesError::PathDoesNotExist(ref cause) => write!(f, "{}", cause),
             GetDifferencesError::RepositoryDoesNotExist(ref cause) => write!(f, "{}", cause),
             GetDifferencesError::RepositoryNameRequired(ref cause) => write!(f, "{}", cause),
+            GetDifferencesError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetDifferencesError {}
 /// Errors returned by GetFile
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetFileError {
     /// <p>The specified commit does not exist or no commit was specified, and the specified repository has no default branch.</p>
     CommitDoesNotExist(String),
@@ -7586,6 +8251,8 @@ pub enum GetFileError {
     RepositoryDoesNotExist(String),
     /// <p>A repository name is required, but was not specified.</p>
     RepositoryNameRequired(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetFileError {
@@ -7593,51 +8260,64 @@ impl GetFileError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "CommitDoesNotExistException" => {
-                    return RusotoError::Service(GetFileError::CommitDoesNotExist(err.msg))
+                    return RusotoError::service(GetFileError::CommitDoesNotExist(err.msg), &res)
                 }
                 "EncryptionIntegrityChecksFailedException" => {
-                    return RusotoError::Service(GetFileError::EncryptionIntegrityChecksFailed(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetFileError::EncryptionIntegrityChecksFailed(err.msg),
+                        &res,
+                    )
                 }
                 "EncryptionKeyAccessDeniedException" => {
-                    return RusotoError::Service(GetFileError::EncryptionKeyAccessDenied(err.msg))
+                    return RusotoError::service(
+                        GetFileError::EncryptionKeyAccessDenied(err.msg),
+                        &res,
+                    )
                 }
                 "EncryptionKeyDisabledException" => {
-                    return RusotoError::Service(GetFileError::EncryptionKeyDisabled(err.msg))
+                    return RusotoError::service(GetFileError::EncryptionKeyDisabled(err.msg), &res)
                 }
                 "EncryptionKeyNotFoundException" => {
-                    return RusotoError::Service(GetFileError::EncryptionKeyNotFound(err.msg))
+                    return RusotoError::service(GetFileError::EncryptionKeyNotFound(err.msg), &res)
                 }
                 "EncryptionKeyUnavailableException" => {
-                    return RusotoError::Service(GetFileError::EncryptionKeyUnavailable(err.msg))
+                    return RusotoError::service(
+                        GetFileError::EncryptionKeyUnavailable(err.msg),
+                        &res,
+                    )
                 }
                 "FileDoesNotExistException" => {
-                    return RusotoError::Service(GetFileError::FileDoesNotExist(err.msg))
+                    return RusotoError::service(GetFileError::FileDoesNotExist(err.msg), &res)
                 }
                 "FileTooLargeException" => {
-                    return RusotoError::Service(GetFileError::FileTooLarge(err.msg))
+                    return RusotoError::service(GetFileError::FileTooLarge(err.msg), &res)
                 }
                 "InvalidCommitException" => {
-                    return RusotoError::Service(GetFileError::InvalidCommit(err.msg))
+                    return RusotoError::service(GetFileError::InvalidCommit(err.msg), &res)
                 }
                 "InvalidPathException" => {
-                    return RusotoError::Service(GetFileError::InvalidPath(err.msg))
+                    return RusotoError::service(GetFileError::InvalidPath(err.msg), &res)
                 }
                 "InvalidRepositoryNameException" => {
-                    return RusotoError::Service(GetFileError::InvalidRepositoryName(err.msg))
+                    return RusotoError::service(GetFileError::InvalidRepositoryName(err.msg), &res)
                 }
                 "PathRequiredException" => {
-                    return RusotoError::Service(GetFileError::PathRequired(err.msg))
+                    return RusotoError::service(GetFileError::PathRequired(err.msg), &res)
                 }
                 "RepositoryDoesNotExistException" => {
-                    return RusotoError::Service(GetFileError::RepositoryDoesNotExist(err.msg))
+                    return RusotoError::service(
+                        GetFileError::RepositoryDoesNotExist(err.msg),
+                        &res,
+                    )
                 }
                 "RepositoryNameRequiredException" => {
-                    return RusotoError::Service(GetFileError::RepositoryNameRequired(err.msg))
+                    return RusotoError::service(
+                        GetFileError::RepositoryNameRequired(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetFileError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -7661,12 +8341,14 @@ impl fmt::Display for GetFileError {
             GetFileError::PathRequired(ref cause) => write!(f, "{}", cause),
             GetFileError::RepositoryDoesNotExist(ref cause) => write!(f, "{}", cause),
             GetFileError::RepositoryNameRequired(ref cause) => write!(f, "{}", cause),
+            GetFileError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetFileError {}
 /// Errors returned by GetFolder
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetFolderError {
     /// <p>The specified commit does not exist or no commit was specified, and the specified repository has no default branch.</p>
     CommitDoesNotExist(String),
@@ -7694,6 +8376,8 @@ pub enum GetFolderError {
     RepositoryDoesNotExist(String),
     /// <p>A repository name is required, but was not specified.</p>
     RepositoryNameRequired(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetFolderError {
@@ -7701,48 +8385,70 @@ impl GetFolderError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "CommitDoesNotExistException" => {
-                    return RusotoError::Service(GetFolderError::CommitDoesNotExist(err.msg))
+                    return RusotoError::service(GetFolderError::CommitDoesNotExist(err.msg), &res)
                 }
                 "EncryptionIntegrityChecksFailedException" => {
-                    return RusotoError::Service(GetFolderError::EncryptionIntegrityChecksFailed(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetFolderError::EncryptionIntegrityChecksFailed(err.msg),
+                        &res,
+                    )
                 }
                 "EncryptionKeyAccessDeniedException" => {
-                    return RusotoError::Service(GetFolderError::EncryptionKeyAccessDenied(err.msg))
+                    return RusotoError::service(
+                        GetFolderError::EncryptionKeyAccessDenied(err.msg),
+                        &res,
+                    )
                 }
                 "EncryptionKeyDisabledException" => {
-                    return RusotoError::Service(GetFolderError::EncryptionKeyDisabled(err.msg))
+                    return RusotoError::service(
+                        GetFolderError::EncryptionKeyDisabled(err.msg),
+                        &res,
+                    )
                 }
                 "EncryptionKeyNotFoundException" => {
-                    return RusotoError::Service(GetFolderError::EncryptionKeyNotFound(err.msg))
+                    return RusotoError::service(
+                        GetFolderError::EncryptionKeyNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "EncryptionKeyUnavailableException" => {
-                    return RusotoError::Service(GetFolderError::EncryptionKeyUnavailable(err.msg))
+                    return RusotoError::service(
+                        GetFolderError::EncryptionKeyUnavailable(err.msg),
+                        &res,
+                    )
                 }
                 "FolderDoesNotExistException" => {
-                    return RusotoError::Service(GetFolderError::FolderDoesNotExist(err.msg))
+                    return RusotoError::service(GetFolderError::FolderDoesNotExist(err.msg), &res)
                 }
                 "InvalidCommitException" => {
-                    return RusotoError::Service(GetFolderError::InvalidCommit(err.msg))
+                    return RusotoError::service(GetFolderError::InvalidCommit(err.msg), &res)
                 }
                 "InvalidPathException" => {
-                    return RusotoError::Service(GetFolderError::InvalidPath(err.msg))
+                    return RusotoError::service(GetFolderError::InvalidPath(err.msg), &res)
                 }
                 "InvalidRepositoryNameException" => {
-                    return RusotoError::Service(GetFolderError::InvalidRepositoryName(err.msg))
+                    return RusotoError::service(
+                        GetFolderError::InvalidRepositoryName(err.msg),
+                        &res,
+                    )
                 }
                 "PathRequiredException" => {
-                    return RusotoError::Service(GetFolderError::PathRequired(err.msg))
+                    return RusotoError::service(GetFolderError::PathRequired(err.msg), &res)
                 }
                 "RepositoryDoesNotExistException" => {
-                    return RusotoError::Service(GetFolderError::RepositoryDoesNotExist(err.msg))
+                    return RusotoError::service(
+                        GetFolderError::RepositoryDoesNotExist(err.msg),
+                        &res,
+                    )
                 }
                 "RepositoryNameRequiredException" => {
-                    return RusotoError::Service(GetFolderError::RepositoryNameRequired(err.msg))
+                    return RusotoError::service(
+                        GetFolderError::RepositoryNameRequired(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetFolderError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -7765,12 +8471,14 @@ impl fmt::Display for GetFolderError {
             GetFolderError::PathRequired(ref cause) => write!(f, "{}", cause),
             GetFolderError::RepositoryDoesNotExist(ref cause) => write!(f, "{}", cause),
             GetFolderError::RepositoryNameRequired(ref cause) => write!(f, "{}", cause),
+            GetFolderError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetFolderError {}
 /// Errors returned by GetMergeCommit
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetMergeCommitError {
     /// <p>The specified commit does not exist or no commit was specified, and the specified repository has no default branch.</p>
     CommitDoesNotExist(String),
@@ -7798,6 +8506,8 @@ pub enum GetMergeCommitError {
     RepositoryDoesNotExist(String),
     /// <p>A repository name is required, but was not specified.</p>
     RepositoryNameRequired(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetMergeCommitError {
@@ -7805,66 +8515,79 @@ impl GetMergeCommitError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "CommitDoesNotExistException" => {
-                    return RusotoError::Service(GetMergeCommitError::CommitDoesNotExist(err.msg))
+                    return RusotoError::service(
+                        GetMergeCommitError::CommitDoesNotExist(err.msg),
+                        &res,
+                    )
                 }
                 "CommitRequiredException" => {
-                    return RusotoError::Service(GetMergeCommitError::CommitRequired(err.msg))
+                    return RusotoError::service(GetMergeCommitError::CommitRequired(err.msg), &res)
                 }
                 "EncryptionIntegrityChecksFailedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         GetMergeCommitError::EncryptionIntegrityChecksFailed(err.msg),
+                        &res,
                     )
                 }
                 "EncryptionKeyAccessDeniedException" => {
-                    return RusotoError::Service(GetMergeCommitError::EncryptionKeyAccessDenied(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetMergeCommitError::EncryptionKeyAccessDenied(err.msg),
+                        &res,
+                    )
                 }
                 "EncryptionKeyDisabledException" => {
-                    return RusotoError::Service(GetMergeCommitError::EncryptionKeyDisabled(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetMergeCommitError::EncryptionKeyDisabled(err.msg),
+                        &res,
+                    )
                 }
                 "EncryptionKeyNotFoundException" => {
-                    return RusotoError::Service(GetMergeCommitError::EncryptionKeyNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetMergeCommitError::EncryptionKeyNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "EncryptionKeyUnavailableException" => {
-                    return RusotoError::Service(GetMergeCommitError::EncryptionKeyUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetMergeCommitError::EncryptionKeyUnavailable(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidCommitException" => {
-                    return RusotoError::Service(GetMergeCommitError::InvalidCommit(err.msg))
+                    return RusotoError::service(GetMergeCommitError::InvalidCommit(err.msg), &res)
                 }
                 "InvalidConflictDetailLevelException" => {
-                    return RusotoError::Service(GetMergeCommitError::InvalidConflictDetailLevel(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetMergeCommitError::InvalidConflictDetailLevel(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidConflictResolutionStrategyException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         GetMergeCommitError::InvalidConflictResolutionStrategy(err.msg),
+                        &res,
                     )
                 }
                 "InvalidRepositoryNameException" => {
-                    return RusotoError::Service(GetMergeCommitError::InvalidRepositoryName(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetMergeCommitError::InvalidRepositoryName(err.msg),
+                        &res,
+                    )
                 }
                 "RepositoryDoesNotExistException" => {
-                    return RusotoError::Service(GetMergeCommitError::RepositoryDoesNotExist(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetMergeCommitError::RepositoryDoesNotExist(err.msg),
+                        &res,
+                    )
                 }
                 "RepositoryNameRequiredException" => {
-                    return RusotoError::Service(GetMergeCommitError::RepositoryNameRequired(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetMergeCommitError::RepositoryNameRequired(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetMergeCommitError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -7891,12 +8614,14 @@ impl fmt::Display for GetMergeCommitError {
             GetMergeCommitError::InvalidRepositoryName(ref cause) => write!(f, "{}", cause),
             GetMergeCommitError::RepositoryDoesNotExist(ref cause) => write!(f, "{}", cause),
             GetMergeCommitError::RepositoryNameRequired(ref cause) => write!(f, "{}", cause),
+            GetMergeCommitError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetMergeCommitError {}
 /// Errors returned by GetMergeConflicts
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetMergeConflictsError {
     /// <p>The specified commit does not exist or no commit was specified, and the specified repository has no default branch.</p>
     CommitDoesNotExist(String),
@@ -7942,6 +8667,8 @@ pub enum GetMergeConflictsError {
     RepositoryNameRequired(String),
     /// <p>The divergence between the tips of the provided commit specifiers is too great to determine whether there might be any merge conflicts. Locally compare the specifiers using <code>git diff</code> or a diff tool.</p>
     TipsDivergenceExceeded(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetMergeConflictsError {
@@ -7949,113 +8676,139 @@ impl GetMergeConflictsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "CommitDoesNotExistException" => {
-                    return RusotoError::Service(GetMergeConflictsError::CommitDoesNotExist(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetMergeConflictsError::CommitDoesNotExist(err.msg),
+                        &res,
+                    )
                 }
                 "CommitRequiredException" => {
-                    return RusotoError::Service(GetMergeConflictsError::CommitRequired(err.msg))
+                    return RusotoError::service(
+                        GetMergeConflictsError::CommitRequired(err.msg),
+                        &res,
+                    )
                 }
                 "EncryptionIntegrityChecksFailedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         GetMergeConflictsError::EncryptionIntegrityChecksFailed(err.msg),
+                        &res,
                     )
                 }
                 "EncryptionKeyAccessDeniedException" => {
-                    return RusotoError::Service(GetMergeConflictsError::EncryptionKeyAccessDenied(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetMergeConflictsError::EncryptionKeyAccessDenied(err.msg),
+                        &res,
+                    )
                 }
                 "EncryptionKeyDisabledException" => {
-                    return RusotoError::Service(GetMergeConflictsError::EncryptionKeyDisabled(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetMergeConflictsError::EncryptionKeyDisabled(err.msg),
+                        &res,
+                    )
                 }
                 "EncryptionKeyNotFoundException" => {
-                    return RusotoError::Service(GetMergeConflictsError::EncryptionKeyNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetMergeConflictsError::EncryptionKeyNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "EncryptionKeyUnavailableException" => {
-                    return RusotoError::Service(GetMergeConflictsError::EncryptionKeyUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetMergeConflictsError::EncryptionKeyUnavailable(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidCommitException" => {
-                    return RusotoError::Service(GetMergeConflictsError::InvalidCommit(err.msg))
+                    return RusotoError::service(
+                        GetMergeConflictsError::InvalidCommit(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidConflictDetailLevelException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         GetMergeConflictsError::InvalidConflictDetailLevel(err.msg),
+                        &res,
                     )
                 }
                 "InvalidConflictResolutionStrategyException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         GetMergeConflictsError::InvalidConflictResolutionStrategy(err.msg),
+                        &res,
                     )
                 }
                 "InvalidContinuationTokenException" => {
-                    return RusotoError::Service(GetMergeConflictsError::InvalidContinuationToken(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetMergeConflictsError::InvalidContinuationToken(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidDestinationCommitSpecifierException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         GetMergeConflictsError::InvalidDestinationCommitSpecifier(err.msg),
+                        &res,
                     )
                 }
                 "InvalidMaxConflictFilesException" => {
-                    return RusotoError::Service(GetMergeConflictsError::InvalidMaxConflictFiles(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetMergeConflictsError::InvalidMaxConflictFiles(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidMergeOptionException" => {
-                    return RusotoError::Service(GetMergeConflictsError::InvalidMergeOption(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetMergeConflictsError::InvalidMergeOption(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidRepositoryNameException" => {
-                    return RusotoError::Service(GetMergeConflictsError::InvalidRepositoryName(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetMergeConflictsError::InvalidRepositoryName(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidSourceCommitSpecifierException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         GetMergeConflictsError::InvalidSourceCommitSpecifier(err.msg),
+                        &res,
                     )
                 }
                 "MaximumFileContentToLoadExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         GetMergeConflictsError::MaximumFileContentToLoadExceeded(err.msg),
+                        &res,
                     )
                 }
                 "MaximumItemsToCompareExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         GetMergeConflictsError::MaximumItemsToCompareExceeded(err.msg),
+                        &res,
                     )
                 }
                 "MergeOptionRequiredException" => {
-                    return RusotoError::Service(GetMergeConflictsError::MergeOptionRequired(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetMergeConflictsError::MergeOptionRequired(err.msg),
+                        &res,
+                    )
                 }
                 "RepositoryDoesNotExistException" => {
-                    return RusotoError::Service(GetMergeConflictsError::RepositoryDoesNotExist(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetMergeConflictsError::RepositoryDoesNotExist(err.msg),
+                        &res,
+                    )
                 }
                 "RepositoryNameRequiredException" => {
-                    return RusotoError::Service(GetMergeConflictsError::RepositoryNameRequired(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetMergeConflictsError::RepositoryNameRequired(err.msg),
+                        &res,
+                    )
                 }
                 "TipsDivergenceExceededException" => {
-                    return RusotoError::Service(GetMergeConflictsError::TipsDivergenceExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetMergeConflictsError::TipsDivergenceExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetMergeConflictsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -8099,12 +8852,14 @@ impl fmt::Display for GetMergeConflictsError {
             GetMergeConflictsError::RepositoryDoesNotExist(ref cause) => write!(f, "{}", cause),
             GetMergeConflictsError::RepositoryNameRequired(ref cause) => write!(f, "{}", cause),
             GetMergeConflictsError::TipsDivergenceExceeded(ref cause) => write!(f, "{}", cause),
+            GetMergeConflictsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetMergeConflictsError {}
 /// Errors returned by GetMergeOptions
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetMergeOptionsError {
     /// <p>The specified commit does not exist or no commit was specified, and the specified repository has no default branch.</p>
     CommitDoesNotExist(String),
@@ -8138,6 +8893,8 @@ pub enum GetMergeOptionsError {
     RepositoryNameRequired(String),
     /// <p>The divergence between the tips of the provided commit specifiers is too great to determine whether there might be any merge conflicts. Locally compare the specifiers using <code>git diff</code> or a diff tool.</p>
     TipsDivergenceExceeded(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetMergeOptionsError {
@@ -8145,81 +8902,100 @@ impl GetMergeOptionsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "CommitDoesNotExistException" => {
-                    return RusotoError::Service(GetMergeOptionsError::CommitDoesNotExist(err.msg))
+                    return RusotoError::service(
+                        GetMergeOptionsError::CommitDoesNotExist(err.msg),
+                        &res,
+                    )
                 }
                 "CommitRequiredException" => {
-                    return RusotoError::Service(GetMergeOptionsError::CommitRequired(err.msg))
+                    return RusotoError::service(
+                        GetMergeOptionsError::CommitRequired(err.msg),
+                        &res,
+                    )
                 }
                 "EncryptionIntegrityChecksFailedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         GetMergeOptionsError::EncryptionIntegrityChecksFailed(err.msg),
+                        &res,
                     )
                 }
                 "EncryptionKeyAccessDeniedException" => {
-                    return RusotoError::Service(GetMergeOptionsError::EncryptionKeyAccessDenied(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetMergeOptionsError::EncryptionKeyAccessDenied(err.msg),
+                        &res,
+                    )
                 }
                 "EncryptionKeyDisabledException" => {
-                    return RusotoError::Service(GetMergeOptionsError::EncryptionKeyDisabled(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetMergeOptionsError::EncryptionKeyDisabled(err.msg),
+                        &res,
+                    )
                 }
                 "EncryptionKeyNotFoundException" => {
-                    return RusotoError::Service(GetMergeOptionsError::EncryptionKeyNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetMergeOptionsError::EncryptionKeyNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "EncryptionKeyUnavailableException" => {
-                    return RusotoError::Service(GetMergeOptionsError::EncryptionKeyUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetMergeOptionsError::EncryptionKeyUnavailable(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidCommitException" => {
-                    return RusotoError::Service(GetMergeOptionsError::InvalidCommit(err.msg))
+                    return RusotoError::service(GetMergeOptionsError::InvalidCommit(err.msg), &res)
                 }
                 "InvalidConflictDetailLevelException" => {
-                    return RusotoError::Service(GetMergeOptionsError::InvalidConflictDetailLevel(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetMergeOptionsError::InvalidConflictDetailLevel(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidConflictResolutionStrategyException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         GetMergeOptionsError::InvalidConflictResolutionStrategy(err.msg),
+                        &res,
                     )
                 }
                 "InvalidRepositoryNameException" => {
-                    return RusotoError::Service(GetMergeOptionsError::InvalidRepositoryName(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetMergeOptionsError::InvalidRepositoryName(err.msg),
+                        &res,
+                    )
                 }
                 "MaximumFileContentToLoadExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         GetMergeOptionsError::MaximumFileContentToLoadExceeded(err.msg),
+                        &res,
                     )
                 }
                 "MaximumItemsToCompareExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         GetMergeOptionsError::MaximumItemsToCompareExceeded(err.msg),
+                        &res,
                     )
                 }
                 "RepositoryDoesNotExistException" => {
-                    return RusotoError::Service(GetMergeOptionsError::RepositoryDoesNotExist(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetMergeOptionsError::RepositoryDoesNotExist(err.msg),
+                        &res,
+                    )
                 }
                 "RepositoryNameRequiredException" => {
-                    return RusotoError::Service(GetMergeOptionsError::RepositoryNameRequired(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetMergeOptionsError::RepositoryNameRequired(err.msg),
+                        &res,
+                    )
                 }
                 "TipsDivergenceExceededException" => {
-                    return RusotoError::Service(GetMergeOptionsError::TipsDivergenceExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetMergeOptionsError::TipsDivergenceExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetMergeOptionsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -8253,12 +9029,14 @@ impl fmt::Display for GetMergeOptionsError {
             GetMergeOptionsError::RepositoryDoesNotExist(ref cause) => write!(f, "{}", cause),
             GetMergeOptionsError::RepositoryNameRequired(ref cause) => write!(f, "{}", cause),
             GetMergeOptionsError::TipsDivergenceExceeded(ref cause) => write!(f, "{}", cause),
+            GetMergeOptionsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetMergeOptionsError {}
 /// Errors returned by GetPullRequest
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetPullRequestError {
     /// <p>An encryption integrity check failed.</p>
     EncryptionIntegrityChecksFailed(String),
@@ -8276,6 +9054,8 @@ pub enum GetPullRequestError {
     PullRequestDoesNotExist(String),
     /// <p>A pull request ID is required, but none was provided.</p>
     PullRequestIdRequired(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetPullRequestError {
@@ -8283,45 +9063,55 @@ impl GetPullRequestError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "EncryptionIntegrityChecksFailedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         GetPullRequestError::EncryptionIntegrityChecksFailed(err.msg),
+                        &res,
                     )
                 }
                 "EncryptionKeyAccessDeniedException" => {
-                    return RusotoError::Service(GetPullRequestError::EncryptionKeyAccessDenied(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetPullRequestError::EncryptionKeyAccessDenied(err.msg),
+                        &res,
+                    )
                 }
                 "EncryptionKeyDisabledException" => {
-                    return RusotoError::Service(GetPullRequestError::EncryptionKeyDisabled(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetPullRequestError::EncryptionKeyDisabled(err.msg),
+                        &res,
+                    )
                 }
                 "EncryptionKeyNotFoundException" => {
-                    return RusotoError::Service(GetPullRequestError::EncryptionKeyNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetPullRequestError::EncryptionKeyNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "EncryptionKeyUnavailableException" => {
-                    return RusotoError::Service(GetPullRequestError::EncryptionKeyUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetPullRequestError::EncryptionKeyUnavailable(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidPullRequestIdException" => {
-                    return RusotoError::Service(GetPullRequestError::InvalidPullRequestId(err.msg))
+                    return RusotoError::service(
+                        GetPullRequestError::InvalidPullRequestId(err.msg),
+                        &res,
+                    )
                 }
                 "PullRequestDoesNotExistException" => {
-                    return RusotoError::Service(GetPullRequestError::PullRequestDoesNotExist(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetPullRequestError::PullRequestDoesNotExist(err.msg),
+                        &res,
+                    )
                 }
                 "PullRequestIdRequiredException" => {
-                    return RusotoError::Service(GetPullRequestError::PullRequestIdRequired(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetPullRequestError::PullRequestIdRequired(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetPullRequestError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -8341,12 +9131,14 @@ impl fmt::Display for GetPullRequestError {
             GetPullRequestError::InvalidPullRequestId(ref cause) => write!(f, "{}", cause),
             GetPullRequestError::PullRequestDoesNotExist(ref cause) => write!(f, "{}", cause),
             GetPullRequestError::PullRequestIdRequired(ref cause) => write!(f, "{}", cause),
+            GetPullRequestError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetPullRequestError {}
 /// Errors returned by GetPullRequestApprovalStates
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetPullRequestApprovalStatesError {
     /// <p>An encryption integrity check failed.</p>
     EncryptionIntegrityChecksFailed(String),
@@ -8368,6 +9160,8 @@ pub enum GetPullRequestApprovalStatesError {
     PullRequestIdRequired(String),
     /// <p>A revision ID is required, but was not provided.</p>
     RevisionIdRequired(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetPullRequestApprovalStatesError {
@@ -8377,57 +9171,72 @@ impl GetPullRequestApprovalStatesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "EncryptionIntegrityChecksFailedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         GetPullRequestApprovalStatesError::EncryptionIntegrityChecksFailed(err.msg),
+                        &res,
                     )
                 }
                 "EncryptionKeyAccessDeniedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         GetPullRequestApprovalStatesError::EncryptionKeyAccessDenied(err.msg),
+                        &res,
                     )
                 }
                 "EncryptionKeyDisabledException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         GetPullRequestApprovalStatesError::EncryptionKeyDisabled(err.msg),
+                        &res,
                     )
                 }
                 "EncryptionKeyNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         GetPullRequestApprovalStatesError::EncryptionKeyNotFound(err.msg),
+                        &res,
                     )
                 }
                 "EncryptionKeyUnavailableException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         GetPullRequestApprovalStatesError::EncryptionKeyUnavailable(err.msg),
+                        &res,
                     )
                 }
                 "InvalidPullRequestIdException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         GetPullRequestApprovalStatesError::InvalidPullRequestId(err.msg),
+                        &res,
                     )
                 }
                 "InvalidRevisionIdException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         GetPullRequestApprovalStatesError::InvalidRevisionId(err.msg),
+                        &res,
                     )
                 }
                 "PullRequestDoesNotExistException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         GetPullRequestApprovalStatesError::PullRequestDoesNotExist(err.msg),
+                        &res,
                     )
                 }
                 "PullRequestIdRequiredException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         GetPullRequestApprovalStatesError::PullRequestIdRequired(err.msg),
+                        &res,
                     )
                 }
                 "RevisionIdRequiredException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         GetPullRequestApprovalStatesError::RevisionIdRequired(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        GetPullRequestApprovalStatesError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -8467,12 +9276,14 @@ impl fmt::Display for GetPullRequestApprovalStatesError {
             GetPullRequestApprovalStatesError::RevisionIdRequired(ref cause) => {
                 write!(f, "{}", cause)
             }
+            GetPullRequestApprovalStatesError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetPullRequestApprovalStatesError {}
 /// Errors returned by GetPullRequestOverrideState
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetPullRequestOverrideStateError {
     /// <p>An encryption integrity check failed.</p>
     EncryptionIntegrityChecksFailed(String),
@@ -8494,6 +9305,8 @@ pub enum GetPullRequestOverrideStateError {
     PullRequestIdRequired(String),
     /// <p>A revision ID is required, but was not provided.</p>
     RevisionIdRequired(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetPullRequestOverrideStateError {
@@ -8503,57 +9316,72 @@ impl GetPullRequestOverrideStateError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "EncryptionIntegrityChecksFailedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         GetPullRequestOverrideStateError::EncryptionIntegrityChecksFailed(err.msg),
+                        &res,
                     )
                 }
                 "EncryptionKeyAccessDeniedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         GetPullRequestOverrideStateError::EncryptionKeyAccessDenied(err.msg),
+                        &res,
                     )
                 }
                 "EncryptionKeyDisabledException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         GetPullRequestOverrideStateError::EncryptionKeyDisabled(err.msg),
+                        &res,
                     )
                 }
                 "EncryptionKeyNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         GetPullRequestOverrideStateError::EncryptionKeyNotFound(err.msg),
+                        &res,
                     )
                 }
                 "EncryptionKeyUnavailableException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         GetPullRequestOverrideStateError::EncryptionKeyUnavailable(err.msg),
+                        &res,
                     )
                 }
                 "InvalidPullRequestIdException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         GetPullRequestOverrideStateError::InvalidPullRequestId(err.msg),
+                        &res,
                     )
                 }
                 "InvalidRevisionIdException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         GetPullRequestOverrideStateError::InvalidRevisionId(err.msg),
+                        &res,
                     )
                 }
                 "PullRequestDoesNotExistException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         GetPullRequestOverrideStateError::PullRequestDoesNotExist(err.msg),
+                        &res,
                     )
                 }
                 "PullRequestIdRequiredException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         GetPullRequestOverrideStateError::PullRequestIdRequired(err.msg),
+                        &res,
                     )
                 }
                 "RevisionIdRequiredException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         GetPullRequestOverrideStateError::RevisionIdRequired(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        GetPullRequestOverrideStateError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -8593,12 +9421,14 @@ impl fmt::Display for GetPullRequestOverrideStateError {
             GetPullRequestOverrideStateError::RevisionIdRequired(ref cause) => {
                 write!(f, "{}", cause)
             }
+            GetPullRequestOverrideStateError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetPullRequestOverrideStateError {}
 /// Errors returned by GetRepository
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetRepositoryError {
     /// <p>An encryption integrity check failed.</p>
     EncryptionIntegrityChecksFailed(String),
@@ -8616,6 +9446,8 @@ pub enum GetRepositoryError {
     RepositoryDoesNotExist(String),
     /// <p>A repository name is required, but was not specified.</p>
     RepositoryNameRequired(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetRepositoryError {
@@ -8623,41 +9455,55 @@ impl GetRepositoryError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "EncryptionIntegrityChecksFailedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         GetRepositoryError::EncryptionIntegrityChecksFailed(err.msg),
+                        &res,
                     )
                 }
                 "EncryptionKeyAccessDeniedException" => {
-                    return RusotoError::Service(GetRepositoryError::EncryptionKeyAccessDenied(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetRepositoryError::EncryptionKeyAccessDenied(err.msg),
+                        &res,
+                    )
                 }
                 "EncryptionKeyDisabledException" => {
-                    return RusotoError::Service(GetRepositoryError::EncryptionKeyDisabled(err.msg))
+                    return RusotoError::service(
+                        GetRepositoryError::EncryptionKeyDisabled(err.msg),
+                        &res,
+                    )
                 }
                 "EncryptionKeyNotFoundException" => {
-                    return RusotoError::Service(GetRepositoryError::EncryptionKeyNotFound(err.msg))
+                    return RusotoError::service(
+                        GetRepositoryError::EncryptionKeyNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "EncryptionKeyUnavailableException" => {
-                    return RusotoError::Service(GetRepositoryError::EncryptionKeyUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetRepositoryError::EncryptionKeyUnavailable(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidRepositoryNameException" => {
-                    return RusotoError::Service(GetRepositoryError::InvalidRepositoryName(err.msg))
+                    return RusotoError::service(
+                        GetRepositoryError::InvalidRepositoryName(err.msg),
+                        &res,
+                    )
                 }
                 "RepositoryDoesNotExistException" => {
-                    return RusotoError::Service(GetRepositoryError::RepositoryDoesNotExist(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetRepositoryError::RepositoryDoesNotExist(err.msg),
+                        &res,
+                    )
                 }
                 "RepositoryNameRequiredException" => {
-                    return RusotoError::Service(GetRepositoryError::RepositoryNameRequired(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetRepositoryError::RepositoryNameRequired(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetRepositoryError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -8677,12 +9523,14 @@ impl fmt::Display for GetRepositoryError {
             GetRepositoryError::InvalidRepositoryName(ref cause) => write!(f, "{}", cause),
             GetRepositoryError::RepositoryDoesNotExist(ref cause) => write!(f, "{}", cause),
             GetRepositoryError::RepositoryNameRequired(ref cause) => write!(f, "{}", cause),
+            GetRepositoryError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetRepositoryError {}
 /// Errors returned by GetRepositoryTriggers
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetRepositoryTriggersError {
     /// <p>An encryption integrity check failed.</p>
     EncryptionIntegrityChecksFailed(String),
@@ -8700,6 +9548,8 @@ pub enum GetRepositoryTriggersError {
     RepositoryDoesNotExist(String),
     /// <p>A repository name is required, but was not specified.</p>
     RepositoryNameRequired(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetRepositoryTriggersError {
@@ -8707,47 +9557,55 @@ impl GetRepositoryTriggersError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "EncryptionIntegrityChecksFailedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         GetRepositoryTriggersError::EncryptionIntegrityChecksFailed(err.msg),
+                        &res,
                     )
                 }
                 "EncryptionKeyAccessDeniedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         GetRepositoryTriggersError::EncryptionKeyAccessDenied(err.msg),
+                        &res,
                     )
                 }
                 "EncryptionKeyDisabledException" => {
-                    return RusotoError::Service(GetRepositoryTriggersError::EncryptionKeyDisabled(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetRepositoryTriggersError::EncryptionKeyDisabled(err.msg),
+                        &res,
+                    )
                 }
                 "EncryptionKeyNotFoundException" => {
-                    return RusotoError::Service(GetRepositoryTriggersError::EncryptionKeyNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetRepositoryTriggersError::EncryptionKeyNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "EncryptionKeyUnavailableException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         GetRepositoryTriggersError::EncryptionKeyUnavailable(err.msg),
+                        &res,
                     )
                 }
                 "InvalidRepositoryNameException" => {
-                    return RusotoError::Service(GetRepositoryTriggersError::InvalidRepositoryName(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetRepositoryTriggersError::InvalidRepositoryName(err.msg),
+                        &res,
+                    )
                 }
                 "RepositoryDoesNotExistException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         GetRepositoryTriggersError::RepositoryDoesNotExist(err.msg),
+                        &res,
                     )
                 }
                 "RepositoryNameRequiredException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         GetRepositoryTriggersError::RepositoryNameRequired(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetRepositoryTriggersError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -8771,17 +9629,21 @@ impl fmt::Display for GetRepositoryTriggersError {
             GetRepositoryTriggersError::InvalidRepositoryName(ref cause) => write!(f, "{}", cause),
             GetRepositoryTriggersError::RepositoryDoesNotExist(ref cause) => write!(f, "{}", cause),
             GetRepositoryTriggersError::RepositoryNameRequired(ref cause) => write!(f, "{}", cause),
+            GetRepositoryTriggersError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetRepositoryTriggersError {}
 /// Errors returned by ListApprovalRuleTemplates
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListApprovalRuleTemplatesError {
     /// <p>The specified continuation token is not valid.</p>
     InvalidContinuationToken(String),
     /// <p>The specified number of maximum results is not valid.</p>
     InvalidMaxResults(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListApprovalRuleTemplatesError {
@@ -8789,17 +9651,21 @@ impl ListApprovalRuleTemplatesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidContinuationTokenException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         ListApprovalRuleTemplatesError::InvalidContinuationToken(err.msg),
+                        &res,
                     )
                 }
                 "InvalidMaxResultsException" => {
-                    return RusotoError::Service(ListApprovalRuleTemplatesError::InvalidMaxResults(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListApprovalRuleTemplatesError::InvalidMaxResults(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(ListApprovalRuleTemplatesError::Unhandled, &res)
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -8813,12 +9679,14 @@ impl fmt::Display for ListApprovalRuleTemplatesError {
                 write!(f, "{}", cause)
             }
             ListApprovalRuleTemplatesError::InvalidMaxResults(ref cause) => write!(f, "{}", cause),
+            ListApprovalRuleTemplatesError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListApprovalRuleTemplatesError {}
 /// Errors returned by ListAssociatedApprovalRuleTemplatesForRepository
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListAssociatedApprovalRuleTemplatesForRepositoryError {
     /// <p>An encryption integrity check failed.</p>
     EncryptionIntegrityChecksFailed(String),
@@ -8840,6 +9708,8 @@ pub enum ListAssociatedApprovalRuleTemplatesForRepositoryError {
     RepositoryDoesNotExist(String),
     /// <p>A repository name is required, but was not specified.</p>
     RepositoryNameRequired(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListAssociatedApprovalRuleTemplatesForRepositoryError {
@@ -8848,18 +9718,18 @@ impl ListAssociatedApprovalRuleTemplatesForRepositoryError {
     ) -> RusotoError<ListAssociatedApprovalRuleTemplatesForRepositoryError> {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
-                                "EncryptionIntegrityChecksFailedException" => return RusotoError::Service(ListAssociatedApprovalRuleTemplatesForRepositoryError::EncryptionIntegrityChecksFailed(err.msg)),
-"EncryptionKeyAccessDeniedException" => return RusotoError::Service(ListAssociatedApprovalRuleTemplatesForRepositoryError::EncryptionKeyAccessDenied(err.msg)),
-"EncryptionKeyDisabledException" => return RusotoError::Service(ListAssociatedApprovalRuleTemplatesForRepositoryError::EncryptionKeyDisabled(err.msg)),
-"EncryptionKeyNotFoundException" => return RusotoError::Service(ListAssociatedApprovalRuleTemplatesForRepositoryError::EncryptionKeyNotFound(err.msg)),
-"EncryptionKeyUnavailableException" => return RusotoError::Service(ListAssociatedApprovalRuleTemplatesForRepositoryError::EncryptionKeyUnavailable(err.msg)),
-"InvalidContinuationTokenException" => return RusotoError::Service(ListAssociatedApprovalRuleTemplatesForRepositoryError::InvalidContinuationToken(err.msg)),
-"InvalidMaxResultsException" => return RusotoError::Service(ListAssociatedApprovalRuleTemplatesForRepositoryError::InvalidMaxResults(err.msg)),
-"InvalidRepositoryNameException" => return RusotoError::Service(ListAssociatedApprovalRuleTemplatesForRepositoryError::InvalidRepositoryName(err.msg)),
-"RepositoryDoesNotExistException" => return RusotoError::Service(ListAssociatedApprovalRuleTemplatesForRepositoryError::RepositoryDoesNotExist(err.msg)),
-"RepositoryNameRequiredException" => return RusotoError::Service(ListAssociatedApprovalRuleTemplatesForRepositoryError::RepositoryNameRequired(err.msg)),
+                                "EncryptionIntegrityChecksFailedException" => return RusotoError::service(ListAssociatedApprovalRuleTemplatesForRepositoryError::EncryptionIntegrityChecksFailed(err.msg), &res),
+"EncryptionKeyAccessDeniedException" => return RusotoError::service(ListAssociatedApprovalRuleTemplatesForRepositoryError::EncryptionKeyAccessDenied(err.msg), &res),
+"EncryptionKeyDisabledException" => return RusotoError::service(ListAssociatedApprovalRuleTemplatesForRepositoryError::EncryptionKeyDisabled(err.msg), &res),
+"EncryptionKeyNotFoundException" => return RusotoError::service(ListAssociatedApprovalRuleTemplatesForRepositoryError::EncryptionKeyNotFound(err.msg), &res),
+"EncryptionKeyUnavailableException" => return RusotoError::service(ListAssociatedApprovalRuleTemplatesForRepositoryError::EncryptionKeyUnavailable(err.msg), &res),
+"InvalidContinuationTokenException" => return RusotoError::service(ListAssociatedApprovalRuleTemplatesForRepositoryError::InvalidContinuationToken(err.msg), &res),
+"InvalidMaxResultsException" => return RusotoError::service(ListAssociatedApprovalRuleTemplatesForRepositoryError::InvalidMaxResults(err.msg), &res),
+"InvalidRepositoryNameException" => return RusotoError::service(ListAssociatedApprovalRuleTemplatesForRepositoryError::InvalidRepositoryName(err.msg), &res),
+"RepositoryDoesNotExistException" => return RusotoError::service(ListAssociatedApprovalRuleTemplatesForRepositoryError::RepositoryDoesNotExist(err.msg), &res),
+"RepositoryNameRequiredException" => return RusotoError::service(ListAssociatedApprovalRuleTemplatesForRepositoryError::RepositoryNameRequired(err.msg), &res),
 "ValidationException" => return RusotoError::Validation(err.msg),
-_ => {}
+_ => return RusotoError::unhandled(ListAssociatedApprovalRuleTemplatesForRepositoryError::Unhandled, &res),
                             }
         }
         RusotoError::Unknown(res)
@@ -8878,13 +9748,15 @@ ListAssociatedApprovalRuleTemplatesForRepositoryError::InvalidContinuationToken(
 ListAssociatedApprovalRuleTemplatesForRepositoryError::InvalidMaxResults(ref cause) => write!(f, "{}", cause),
 ListAssociatedApprovalRuleTemplatesForRepositoryError::InvalidRepositoryName(ref cause) => write!(f, "{}", cause),
 ListAssociatedApprovalRuleTemplatesForRepositoryError::RepositoryDoesNotExist(ref cause) => write!(f, "{}", cause),
-ListAssociatedApprovalRuleTemplatesForRepositoryError::RepositoryNameRequired(ref cause) => write!(f, "{}", cause)
-                        }
+ListAssociatedApprovalRuleTemplatesForRepositoryError::RepositoryNameRequired(ref cause) => write!(f, "{}", cause),
+            ListAssociatedApprovalRuleTemplatesForRepositoryError::Unhandled(ref metadata) => write!(f, "{}", metadata),
+        }
     }
 }
 impl Error for ListAssociatedApprovalRuleTemplatesForRepositoryError {}
 /// Errors returned by ListBranches
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListBranchesError {
     /// <p>An encryption integrity check failed.</p>
     EncryptionIntegrityChecksFailed(String),
@@ -8904,6 +9776,8 @@ pub enum ListBranchesError {
     RepositoryDoesNotExist(String),
     /// <p>A repository name is required, but was not specified.</p>
     RepositoryNameRequired(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListBranchesError {
@@ -8911,42 +9785,61 @@ impl ListBranchesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "EncryptionIntegrityChecksFailedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         ListBranchesError::EncryptionIntegrityChecksFailed(err.msg),
+                        &res,
                     )
                 }
                 "EncryptionKeyAccessDeniedException" => {
-                    return RusotoError::Service(ListBranchesError::EncryptionKeyAccessDenied(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListBranchesError::EncryptionKeyAccessDenied(err.msg),
+                        &res,
+                    )
                 }
                 "EncryptionKeyDisabledException" => {
-                    return RusotoError::Service(ListBranchesError::EncryptionKeyDisabled(err.msg))
+                    return RusotoError::service(
+                        ListBranchesError::EncryptionKeyDisabled(err.msg),
+                        &res,
+                    )
                 }
                 "EncryptionKeyNotFoundException" => {
-                    return RusotoError::Service(ListBranchesError::EncryptionKeyNotFound(err.msg))
+                    return RusotoError::service(
+                        ListBranchesError::EncryptionKeyNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "EncryptionKeyUnavailableException" => {
-                    return RusotoError::Service(ListBranchesError::EncryptionKeyUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListBranchesError::EncryptionKeyUnavailable(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidContinuationTokenException" => {
-                    return RusotoError::Service(ListBranchesError::InvalidContinuationToken(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListBranchesError::InvalidContinuationToken(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidRepositoryNameException" => {
-                    return RusotoError::Service(ListBranchesError::InvalidRepositoryName(err.msg))
+                    return RusotoError::service(
+                        ListBranchesError::InvalidRepositoryName(err.msg),
+                        &res,
+                    )
                 }
                 "RepositoryDoesNotExistException" => {
-                    return RusotoError::Service(ListBranchesError::RepositoryDoesNotExist(err.msg))
+                    return RusotoError::service(
+                        ListBranchesError::RepositoryDoesNotExist(err.msg),
+                        &res,
+                    )
                 }
                 "RepositoryNameRequiredException" => {
-                    return RusotoError::Service(ListBranchesError::RepositoryNameRequired(err.msg))
+                    return RusotoError::service(
+                        ListBranchesError::RepositoryNameRequired(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListBranchesError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -8965,12 +9858,14 @@ impl fmt::Display for ListBranchesError {
             ListBranchesError::InvalidRepositoryName(ref cause) => write!(f, "{}", cause),
             ListBranchesError::RepositoryDoesNotExist(ref cause) => write!(f, "{}", cause),
             ListBranchesError::RepositoryNameRequired(ref cause) => write!(f, "{}", cause),
+            ListBranchesError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListBranchesError {}
 /// Errors returned by ListPullRequests
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListPullRequestsError {
     /// <p>The specified Amazon Resource Name (ARN) does not exist in the AWS account.</p>
     AuthorDoesNotExist(String),
@@ -8998,6 +9893,8 @@ pub enum ListPullRequestsError {
     RepositoryDoesNotExist(String),
     /// <p>A repository name is required, but was not specified.</p>
     RepositoryNameRequired(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListPullRequestsError {
@@ -9005,66 +9902,85 @@ impl ListPullRequestsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AuthorDoesNotExistException" => {
-                    return RusotoError::Service(ListPullRequestsError::AuthorDoesNotExist(err.msg))
+                    return RusotoError::service(
+                        ListPullRequestsError::AuthorDoesNotExist(err.msg),
+                        &res,
+                    )
                 }
                 "EncryptionIntegrityChecksFailedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         ListPullRequestsError::EncryptionIntegrityChecksFailed(err.msg),
+                        &res,
                     )
                 }
                 "EncryptionKeyAccessDeniedException" => {
-                    return RusotoError::Service(ListPullRequestsError::EncryptionKeyAccessDenied(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListPullRequestsError::EncryptionKeyAccessDenied(err.msg),
+                        &res,
+                    )
                 }
                 "EncryptionKeyDisabledException" => {
-                    return RusotoError::Service(ListPullRequestsError::EncryptionKeyDisabled(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListPullRequestsError::EncryptionKeyDisabled(err.msg),
+                        &res,
+                    )
                 }
                 "EncryptionKeyNotFoundException" => {
-                    return RusotoError::Service(ListPullRequestsError::EncryptionKeyNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListPullRequestsError::EncryptionKeyNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "EncryptionKeyUnavailableException" => {
-                    return RusotoError::Service(ListPullRequestsError::EncryptionKeyUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListPullRequestsError::EncryptionKeyUnavailable(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidAuthorArnException" => {
-                    return RusotoError::Service(ListPullRequestsError::InvalidAuthorArn(err.msg))
+                    return RusotoError::service(
+                        ListPullRequestsError::InvalidAuthorArn(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidContinuationTokenException" => {
-                    return RusotoError::Service(ListPullRequestsError::InvalidContinuationToken(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListPullRequestsError::InvalidContinuationToken(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidMaxResultsException" => {
-                    return RusotoError::Service(ListPullRequestsError::InvalidMaxResults(err.msg))
+                    return RusotoError::service(
+                        ListPullRequestsError::InvalidMaxResults(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidPullRequestStatusException" => {
-                    return RusotoError::Service(ListPullRequestsError::InvalidPullRequestStatus(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListPullRequestsError::InvalidPullRequestStatus(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidRepositoryNameException" => {
-                    return RusotoError::Service(ListPullRequestsError::InvalidRepositoryName(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListPullRequestsError::InvalidRepositoryName(err.msg),
+                        &res,
+                    )
                 }
                 "RepositoryDoesNotExistException" => {
-                    return RusotoError::Service(ListPullRequestsError::RepositoryDoesNotExist(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListPullRequestsError::RepositoryDoesNotExist(err.msg),
+                        &res,
+                    )
                 }
                 "RepositoryNameRequiredException" => {
-                    return RusotoError::Service(ListPullRequestsError::RepositoryNameRequired(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListPullRequestsError::RepositoryNameRequired(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListPullRequestsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -9089,12 +10005,14 @@ impl fmt::Display for ListPullRequestsError {
             ListPullRequestsError::InvalidRepositoryName(ref cause) => write!(f, "{}", cause),
             ListPullRequestsError::RepositoryDoesNotExist(ref cause) => write!(f, "{}", cause),
             ListPullRequestsError::RepositoryNameRequired(ref cause) => write!(f, "{}", cause),
+            ListPullRequestsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListPullRequestsError {}
 /// Errors returned by ListRepositories
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListRepositoriesError {
     /// <p>The specified continuation token is not valid.</p>
     InvalidContinuationToken(String),
@@ -9102,6 +10020,8 @@ pub enum ListRepositoriesError {
     InvalidOrder(String),
     /// <p>The specified sort by value is not valid.</p>
     InvalidSortBy(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListRepositoriesError {
@@ -9109,18 +10029,22 @@ impl ListRepositoriesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidContinuationTokenException" => {
-                    return RusotoError::Service(ListRepositoriesError::InvalidContinuationToken(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListRepositoriesError::InvalidContinuationToken(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidOrderException" => {
-                    return RusotoError::Service(ListRepositoriesError::InvalidOrder(err.msg))
+                    return RusotoError::service(ListRepositoriesError::InvalidOrder(err.msg), &res)
                 }
                 "InvalidSortByException" => {
-                    return RusotoError::Service(ListRepositoriesError::InvalidSortBy(err.msg))
+                    return RusotoError::service(
+                        ListRepositoriesError::InvalidSortBy(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListRepositoriesError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -9133,12 +10057,14 @@ impl fmt::Display for ListRepositoriesError {
             ListRepositoriesError::InvalidContinuationToken(ref cause) => write!(f, "{}", cause),
             ListRepositoriesError::InvalidOrder(ref cause) => write!(f, "{}", cause),
             ListRepositoriesError::InvalidSortBy(ref cause) => write!(f, "{}", cause),
+            ListRepositoriesError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListRepositoriesError {}
 /// Errors returned by ListRepositoriesForApprovalRuleTemplate
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListRepositoriesForApprovalRuleTemplateError {
     /// <p>The specified approval rule template does not exist. Verify that the name is correct and that you are signed in to the AWS Region where the template was created, and then try again.</p>
     ApprovalRuleTemplateDoesNotExist(String),
@@ -9160,6 +10086,8 @@ pub enum ListRepositoriesForApprovalRuleTemplateError {
     InvalidContinuationToken(String),
     /// <p>The specified number of maximum results is not valid.</p>
     InvalidMaxResults(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListRepositoriesForApprovalRuleTemplateError {
@@ -9168,68 +10096,83 @@ impl ListRepositoriesForApprovalRuleTemplateError {
     ) -> RusotoError<ListRepositoriesForApprovalRuleTemplateError> {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
-                "ApprovalRuleTemplateDoesNotExistException" => return RusotoError::Service(
+                "ApprovalRuleTemplateDoesNotExistException" => return RusotoError::service(
                     ListRepositoriesForApprovalRuleTemplateError::ApprovalRuleTemplateDoesNotExist(
                         err.msg,
                     ),
+                    &res,
                 ),
-                "ApprovalRuleTemplateNameRequiredException" => return RusotoError::Service(
+                "ApprovalRuleTemplateNameRequiredException" => return RusotoError::service(
                     ListRepositoriesForApprovalRuleTemplateError::ApprovalRuleTemplateNameRequired(
                         err.msg,
                     ),
+                    &res,
                 ),
-                "EncryptionIntegrityChecksFailedException" => return RusotoError::Service(
+                "EncryptionIntegrityChecksFailedException" => return RusotoError::service(
                     ListRepositoriesForApprovalRuleTemplateError::EncryptionIntegrityChecksFailed(
                         err.msg,
                     ),
+                    &res,
                 ),
                 "EncryptionKeyAccessDeniedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         ListRepositoriesForApprovalRuleTemplateError::EncryptionKeyAccessDenied(
                             err.msg,
                         ),
+                        &res,
                     )
                 }
                 "EncryptionKeyDisabledException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         ListRepositoriesForApprovalRuleTemplateError::EncryptionKeyDisabled(
                             err.msg,
                         ),
+                        &res,
                     )
                 }
                 "EncryptionKeyNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         ListRepositoriesForApprovalRuleTemplateError::EncryptionKeyNotFound(
                             err.msg,
                         ),
+                        &res,
                     )
                 }
                 "EncryptionKeyUnavailableException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         ListRepositoriesForApprovalRuleTemplateError::EncryptionKeyUnavailable(
                             err.msg,
                         ),
+                        &res,
                     )
                 }
-                "InvalidApprovalRuleTemplateNameException" => return RusotoError::Service(
+                "InvalidApprovalRuleTemplateNameException" => return RusotoError::service(
                     ListRepositoriesForApprovalRuleTemplateError::InvalidApprovalRuleTemplateName(
                         err.msg,
                     ),
+                    &res,
                 ),
                 "InvalidContinuationTokenException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         ListRepositoriesForApprovalRuleTemplateError::InvalidContinuationToken(
                             err.msg,
                         ),
+                        &res,
                     )
                 }
                 "InvalidMaxResultsException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         ListRepositoriesForApprovalRuleTemplateError::InvalidMaxResults(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        ListRepositoriesForApprovalRuleTemplateError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -9269,12 +10212,16 @@ impl fmt::Display for ListRepositoriesForApprovalRuleTemplateError {
             ListRepositoriesForApprovalRuleTemplateError::InvalidMaxResults(ref cause) => {
                 write!(f, "{}", cause)
             }
+            ListRepositoriesForApprovalRuleTemplateError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for ListRepositoriesForApprovalRuleTemplateError {}
 /// Errors returned by ListTagsForResource
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListTagsForResourceError {
     /// <p><p>A specified repository name is not valid.</p> <note> <p>This exception occurs only when a specified repository name is not valid. Other exceptions occur when a required repository parameter is missing, or when a specified repository does not exist.</p> </note></p>
     InvalidRepositoryName(String),
@@ -9284,6 +10231,8 @@ pub enum ListTagsForResourceError {
     RepositoryDoesNotExist(String),
     /// <p>A valid Amazon Resource Name (ARN) for an AWS CodeCommit resource is required. For a list of valid resources in AWS CodeCommit, see <a href="https://docs.aws.amazon.com/codecommit/latest/userguide/auth-and-access-control-iam-access-control-identity-based.html#arn-formats">CodeCommit Resources and Operations</a> in the AWS CodeCommit User Guide.</p>
     ResourceArnRequired(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListTagsForResourceError {
@@ -9291,27 +10240,31 @@ impl ListTagsForResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidRepositoryNameException" => {
-                    return RusotoError::Service(ListTagsForResourceError::InvalidRepositoryName(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListTagsForResourceError::InvalidRepositoryName(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidResourceArnException" => {
-                    return RusotoError::Service(ListTagsForResourceError::InvalidResourceArn(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListTagsForResourceError::InvalidResourceArn(err.msg),
+                        &res,
+                    )
                 }
                 "RepositoryDoesNotExistException" => {
-                    return RusotoError::Service(ListTagsForResourceError::RepositoryDoesNotExist(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListTagsForResourceError::RepositoryDoesNotExist(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceArnRequiredException" => {
-                    return RusotoError::Service(ListTagsForResourceError::ResourceArnRequired(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListTagsForResourceError::ResourceArnRequired(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListTagsForResourceError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -9325,12 +10278,14 @@ impl fmt::Display for ListTagsForResourceError {
             ListTagsForResourceError::InvalidResourceArn(ref cause) => write!(f, "{}", cause),
             ListTagsForResourceError::RepositoryDoesNotExist(ref cause) => write!(f, "{}", cause),
             ListTagsForResourceError::ResourceArnRequired(ref cause) => write!(f, "{}", cause),
+            ListTagsForResourceError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListTagsForResourceError {}
 /// Errors returned by MergeBranchesByFastForward
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum MergeBranchesByFastForwardError {
     /// <p>The specified branch does not exist.</p>
     BranchDoesNotExist(String),
@@ -9370,6 +10325,8 @@ pub enum MergeBranchesByFastForwardError {
     RepositoryNameRequired(String),
     /// <p>The divergence between the tips of the provided commit specifiers is too great to determine whether there might be any merge conflicts. Locally compare the specifiers using <code>git diff</code> or a diff tool.</p>
     TipsDivergenceExceeded(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl MergeBranchesByFastForwardError {
@@ -9379,102 +10336,123 @@ impl MergeBranchesByFastForwardError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "BranchDoesNotExistException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         MergeBranchesByFastForwardError::BranchDoesNotExist(err.msg),
+                        &res,
                     )
                 }
                 "BranchNameIsTagNameException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         MergeBranchesByFastForwardError::BranchNameIsTagName(err.msg),
+                        &res,
                     )
                 }
                 "BranchNameRequiredException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         MergeBranchesByFastForwardError::BranchNameRequired(err.msg),
+                        &res,
                     )
                 }
                 "CommitDoesNotExistException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         MergeBranchesByFastForwardError::CommitDoesNotExist(err.msg),
+                        &res,
                     )
                 }
                 "CommitRequiredException" => {
-                    return RusotoError::Service(MergeBranchesByFastForwardError::CommitRequired(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        MergeBranchesByFastForwardError::CommitRequired(err.msg),
+                        &res,
+                    )
                 }
                 "ConcurrentReferenceUpdateException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         MergeBranchesByFastForwardError::ConcurrentReferenceUpdate(err.msg),
+                        &res,
                     )
                 }
                 "EncryptionIntegrityChecksFailedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         MergeBranchesByFastForwardError::EncryptionIntegrityChecksFailed(err.msg),
+                        &res,
                     )
                 }
                 "EncryptionKeyAccessDeniedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         MergeBranchesByFastForwardError::EncryptionKeyAccessDenied(err.msg),
+                        &res,
                     )
                 }
                 "EncryptionKeyDisabledException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         MergeBranchesByFastForwardError::EncryptionKeyDisabled(err.msg),
+                        &res,
                     )
                 }
                 "EncryptionKeyNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         MergeBranchesByFastForwardError::EncryptionKeyNotFound(err.msg),
+                        &res,
                     )
                 }
                 "EncryptionKeyUnavailableException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         MergeBranchesByFastForwardError::EncryptionKeyUnavailable(err.msg),
+                        &res,
                     )
                 }
                 "InvalidBranchNameException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         MergeBranchesByFastForwardError::InvalidBranchName(err.msg),
+                        &res,
                     )
                 }
                 "InvalidCommitException" => {
-                    return RusotoError::Service(MergeBranchesByFastForwardError::InvalidCommit(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        MergeBranchesByFastForwardError::InvalidCommit(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidRepositoryNameException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         MergeBranchesByFastForwardError::InvalidRepositoryName(err.msg),
+                        &res,
                     )
                 }
                 "InvalidTargetBranchException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         MergeBranchesByFastForwardError::InvalidTargetBranch(err.msg),
+                        &res,
                     )
                 }
                 "ManualMergeRequiredException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         MergeBranchesByFastForwardError::ManualMergeRequired(err.msg),
+                        &res,
                     )
                 }
                 "RepositoryDoesNotExistException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         MergeBranchesByFastForwardError::RepositoryDoesNotExist(err.msg),
+                        &res,
                     )
                 }
                 "RepositoryNameRequiredException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         MergeBranchesByFastForwardError::RepositoryNameRequired(err.msg),
+                        &res,
                     )
                 }
                 "TipsDivergenceExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         MergeBranchesByFastForwardError::TipsDivergenceExceeded(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(MergeBranchesByFastForwardError::Unhandled, &res)
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -9535,12 +10513,14 @@ impl fmt::Display for MergeBranchesByFastForwardError {
             MergeBranchesByFastForwardError::TipsDivergenceExceeded(ref cause) => {
                 write!(f, "{}", cause)
             }
+            MergeBranchesByFastForwardError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for MergeBranchesByFastForwardError {}
 /// Errors returned by MergeBranchesBySquash
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum MergeBranchesBySquashError {
     /// <p>The specified branch does not exist.</p>
     BranchDoesNotExist(String),
@@ -9620,6 +10600,8 @@ pub enum MergeBranchesBySquashError {
     RepositoryNameRequired(String),
     /// <p>The divergence between the tips of the provided commit specifiers is too great to determine whether there might be any merge conflicts. Locally compare the specifiers using <code>git diff</code> or a diff tool.</p>
     TipsDivergenceExceeded(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl MergeBranchesBySquashError {
@@ -9627,196 +10609,243 @@ impl MergeBranchesBySquashError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "BranchDoesNotExistException" => {
-                    return RusotoError::Service(MergeBranchesBySquashError::BranchDoesNotExist(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        MergeBranchesBySquashError::BranchDoesNotExist(err.msg),
+                        &res,
+                    )
                 }
                 "BranchNameIsTagNameException" => {
-                    return RusotoError::Service(MergeBranchesBySquashError::BranchNameIsTagName(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        MergeBranchesBySquashError::BranchNameIsTagName(err.msg),
+                        &res,
+                    )
                 }
                 "BranchNameRequiredException" => {
-                    return RusotoError::Service(MergeBranchesBySquashError::BranchNameRequired(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        MergeBranchesBySquashError::BranchNameRequired(err.msg),
+                        &res,
+                    )
                 }
                 "CommitDoesNotExistException" => {
-                    return RusotoError::Service(MergeBranchesBySquashError::CommitDoesNotExist(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        MergeBranchesBySquashError::CommitDoesNotExist(err.msg),
+                        &res,
+                    )
                 }
                 "CommitMessageLengthExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         MergeBranchesBySquashError::CommitMessageLengthExceeded(err.msg),
+                        &res,
                     )
                 }
                 "CommitRequiredException" => {
-                    return RusotoError::Service(MergeBranchesBySquashError::CommitRequired(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        MergeBranchesBySquashError::CommitRequired(err.msg),
+                        &res,
+                    )
                 }
                 "ConcurrentReferenceUpdateException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         MergeBranchesBySquashError::ConcurrentReferenceUpdate(err.msg),
+                        &res,
                     )
                 }
                 "EncryptionIntegrityChecksFailedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         MergeBranchesBySquashError::EncryptionIntegrityChecksFailed(err.msg),
+                        &res,
                     )
                 }
                 "EncryptionKeyAccessDeniedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         MergeBranchesBySquashError::EncryptionKeyAccessDenied(err.msg),
+                        &res,
                     )
                 }
                 "EncryptionKeyDisabledException" => {
-                    return RusotoError::Service(MergeBranchesBySquashError::EncryptionKeyDisabled(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        MergeBranchesBySquashError::EncryptionKeyDisabled(err.msg),
+                        &res,
+                    )
                 }
                 "EncryptionKeyNotFoundException" => {
-                    return RusotoError::Service(MergeBranchesBySquashError::EncryptionKeyNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        MergeBranchesBySquashError::EncryptionKeyNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "EncryptionKeyUnavailableException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         MergeBranchesBySquashError::EncryptionKeyUnavailable(err.msg),
+                        &res,
                     )
                 }
                 "FileContentSizeLimitExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         MergeBranchesBySquashError::FileContentSizeLimitExceeded(err.msg),
+                        &res,
                     )
                 }
                 "FileModeRequiredException" => {
-                    return RusotoError::Service(MergeBranchesBySquashError::FileModeRequired(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        MergeBranchesBySquashError::FileModeRequired(err.msg),
+                        &res,
+                    )
                 }
                 "FolderContentSizeLimitExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         MergeBranchesBySquashError::FolderContentSizeLimitExceeded(err.msg),
+                        &res,
                     )
                 }
                 "InvalidBranchNameException" => {
-                    return RusotoError::Service(MergeBranchesBySquashError::InvalidBranchName(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        MergeBranchesBySquashError::InvalidBranchName(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidCommitException" => {
-                    return RusotoError::Service(MergeBranchesBySquashError::InvalidCommit(err.msg))
+                    return RusotoError::service(
+                        MergeBranchesBySquashError::InvalidCommit(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidConflictDetailLevelException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         MergeBranchesBySquashError::InvalidConflictDetailLevel(err.msg),
+                        &res,
                     )
                 }
                 "InvalidConflictResolutionException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         MergeBranchesBySquashError::InvalidConflictResolution(err.msg),
+                        &res,
                     )
                 }
                 "InvalidConflictResolutionStrategyException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         MergeBranchesBySquashError::InvalidConflictResolutionStrategy(err.msg),
+                        &res,
                     )
                 }
                 "InvalidEmailException" => {
-                    return RusotoError::Service(MergeBranchesBySquashError::InvalidEmail(err.msg))
+                    return RusotoError::service(
+                        MergeBranchesBySquashError::InvalidEmail(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidFileModeException" => {
-                    return RusotoError::Service(MergeBranchesBySquashError::InvalidFileMode(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        MergeBranchesBySquashError::InvalidFileMode(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidPathException" => {
-                    return RusotoError::Service(MergeBranchesBySquashError::InvalidPath(err.msg))
+                    return RusotoError::service(
+                        MergeBranchesBySquashError::InvalidPath(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidReplacementContentException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         MergeBranchesBySquashError::InvalidReplacementContent(err.msg),
+                        &res,
                     )
                 }
                 "InvalidReplacementTypeException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         MergeBranchesBySquashError::InvalidReplacementType(err.msg),
+                        &res,
                     )
                 }
                 "InvalidRepositoryNameException" => {
-                    return RusotoError::Service(MergeBranchesBySquashError::InvalidRepositoryName(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        MergeBranchesBySquashError::InvalidRepositoryName(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidTargetBranchException" => {
-                    return RusotoError::Service(MergeBranchesBySquashError::InvalidTargetBranch(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        MergeBranchesBySquashError::InvalidTargetBranch(err.msg),
+                        &res,
+                    )
                 }
                 "ManualMergeRequiredException" => {
-                    return RusotoError::Service(MergeBranchesBySquashError::ManualMergeRequired(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        MergeBranchesBySquashError::ManualMergeRequired(err.msg),
+                        &res,
+                    )
                 }
                 "MaximumConflictResolutionEntriesExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         MergeBranchesBySquashError::MaximumConflictResolutionEntriesExceeded(
                             err.msg,
                         ),
+                        &res,
                     )
                 }
                 "MaximumFileContentToLoadExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         MergeBranchesBySquashError::MaximumFileContentToLoadExceeded(err.msg),
+                        &res,
                     )
                 }
                 "MaximumItemsToCompareExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         MergeBranchesBySquashError::MaximumItemsToCompareExceeded(err.msg),
+                        &res,
                     )
                 }
                 "MultipleConflictResolutionEntriesException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         MergeBranchesBySquashError::MultipleConflictResolutionEntries(err.msg),
+                        &res,
                     )
                 }
                 "NameLengthExceededException" => {
-                    return RusotoError::Service(MergeBranchesBySquashError::NameLengthExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        MergeBranchesBySquashError::NameLengthExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "PathRequiredException" => {
-                    return RusotoError::Service(MergeBranchesBySquashError::PathRequired(err.msg))
+                    return RusotoError::service(
+                        MergeBranchesBySquashError::PathRequired(err.msg),
+                        &res,
+                    )
                 }
                 "ReplacementContentRequiredException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         MergeBranchesBySquashError::ReplacementContentRequired(err.msg),
+                        &res,
                     )
                 }
                 "ReplacementTypeRequiredException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         MergeBranchesBySquashError::ReplacementTypeRequired(err.msg),
+                        &res,
                     )
                 }
                 "RepositoryDoesNotExistException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         MergeBranchesBySquashError::RepositoryDoesNotExist(err.msg),
+                        &res,
                     )
                 }
                 "RepositoryNameRequiredException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         MergeBranchesBySquashError::RepositoryNameRequired(err.msg),
+                        &res,
                     )
                 }
                 "TipsDivergenceExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         MergeBranchesBySquashError::TipsDivergenceExceeded(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(MergeBranchesBySquashError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -9899,12 +10928,14 @@ impl fmt::Display for MergeBranchesBySquashError {
             MergeBranchesBySquashError::RepositoryDoesNotExist(ref cause) => write!(f, "{}", cause),
             MergeBranchesBySquashError::RepositoryNameRequired(ref cause) => write!(f, "{}", cause),
             MergeBranchesBySquashError::TipsDivergenceExceeded(ref cause) => write!(f, "{}", cause),
+            MergeBranchesBySquashError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for MergeBranchesBySquashError {}
 /// Errors returned by MergeBranchesByThreeWay
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum MergeBranchesByThreeWayError {
     /// <p>The specified branch does not exist.</p>
     BranchDoesNotExist(String),
@@ -9984,6 +11015,8 @@ pub enum MergeBranchesByThreeWayError {
     RepositoryNameRequired(String),
     /// <p>The divergence between the tips of the provided commit specifiers is too great to determine whether there might be any merge conflicts. Locally compare the specifiers using <code>git diff</code> or a diff tool.</p>
     TipsDivergenceExceeded(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl MergeBranchesByThreeWayError {
@@ -9991,202 +11024,243 @@ impl MergeBranchesByThreeWayError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "BranchDoesNotExistException" => {
-                    return RusotoError::Service(MergeBranchesByThreeWayError::BranchDoesNotExist(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        MergeBranchesByThreeWayError::BranchDoesNotExist(err.msg),
+                        &res,
+                    )
                 }
                 "BranchNameIsTagNameException" => {
-                    return RusotoError::Service(MergeBranchesByThreeWayError::BranchNameIsTagName(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        MergeBranchesByThreeWayError::BranchNameIsTagName(err.msg),
+                        &res,
+                    )
                 }
                 "BranchNameRequiredException" => {
-                    return RusotoError::Service(MergeBranchesByThreeWayError::BranchNameRequired(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        MergeBranchesByThreeWayError::BranchNameRequired(err.msg),
+                        &res,
+                    )
                 }
                 "CommitDoesNotExistException" => {
-                    return RusotoError::Service(MergeBranchesByThreeWayError::CommitDoesNotExist(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        MergeBranchesByThreeWayError::CommitDoesNotExist(err.msg),
+                        &res,
+                    )
                 }
                 "CommitMessageLengthExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         MergeBranchesByThreeWayError::CommitMessageLengthExceeded(err.msg),
+                        &res,
                     )
                 }
                 "CommitRequiredException" => {
-                    return RusotoError::Service(MergeBranchesByThreeWayError::CommitRequired(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        MergeBranchesByThreeWayError::CommitRequired(err.msg),
+                        &res,
+                    )
                 }
                 "ConcurrentReferenceUpdateException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         MergeBranchesByThreeWayError::ConcurrentReferenceUpdate(err.msg),
+                        &res,
                     )
                 }
                 "EncryptionIntegrityChecksFailedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         MergeBranchesByThreeWayError::EncryptionIntegrityChecksFailed(err.msg),
+                        &res,
                     )
                 }
                 "EncryptionKeyAccessDeniedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         MergeBranchesByThreeWayError::EncryptionKeyAccessDenied(err.msg),
+                        &res,
                     )
                 }
                 "EncryptionKeyDisabledException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         MergeBranchesByThreeWayError::EncryptionKeyDisabled(err.msg),
+                        &res,
                     )
                 }
                 "EncryptionKeyNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         MergeBranchesByThreeWayError::EncryptionKeyNotFound(err.msg),
+                        &res,
                     )
                 }
                 "EncryptionKeyUnavailableException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         MergeBranchesByThreeWayError::EncryptionKeyUnavailable(err.msg),
+                        &res,
                     )
                 }
                 "FileContentSizeLimitExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         MergeBranchesByThreeWayError::FileContentSizeLimitExceeded(err.msg),
+                        &res,
                     )
                 }
                 "FileModeRequiredException" => {
-                    return RusotoError::Service(MergeBranchesByThreeWayError::FileModeRequired(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        MergeBranchesByThreeWayError::FileModeRequired(err.msg),
+                        &res,
+                    )
                 }
                 "FolderContentSizeLimitExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         MergeBranchesByThreeWayError::FolderContentSizeLimitExceeded(err.msg),
+                        &res,
                     )
                 }
                 "InvalidBranchNameException" => {
-                    return RusotoError::Service(MergeBranchesByThreeWayError::InvalidBranchName(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        MergeBranchesByThreeWayError::InvalidBranchName(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidCommitException" => {
-                    return RusotoError::Service(MergeBranchesByThreeWayError::InvalidCommit(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        MergeBranchesByThreeWayError::InvalidCommit(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidConflictDetailLevelException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         MergeBranchesByThreeWayError::InvalidConflictDetailLevel(err.msg),
+                        &res,
                     )
                 }
                 "InvalidConflictResolutionException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         MergeBranchesByThreeWayError::InvalidConflictResolution(err.msg),
+                        &res,
                     )
                 }
                 "InvalidConflictResolutionStrategyException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         MergeBranchesByThreeWayError::InvalidConflictResolutionStrategy(err.msg),
+                        &res,
                     )
                 }
                 "InvalidEmailException" => {
-                    return RusotoError::Service(MergeBranchesByThreeWayError::InvalidEmail(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        MergeBranchesByThreeWayError::InvalidEmail(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidFileModeException" => {
-                    return RusotoError::Service(MergeBranchesByThreeWayError::InvalidFileMode(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        MergeBranchesByThreeWayError::InvalidFileMode(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidPathException" => {
-                    return RusotoError::Service(MergeBranchesByThreeWayError::InvalidPath(err.msg))
+                    return RusotoError::service(
+                        MergeBranchesByThreeWayError::InvalidPath(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidReplacementContentException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         MergeBranchesByThreeWayError::InvalidReplacementContent(err.msg),
+                        &res,
                     )
                 }
                 "InvalidReplacementTypeException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         MergeBranchesByThreeWayError::InvalidReplacementType(err.msg),
+                        &res,
                     )
                 }
                 "InvalidRepositoryNameException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         MergeBranchesByThreeWayError::InvalidRepositoryName(err.msg),
+                        &res,
                     )
                 }
                 "InvalidTargetBranchException" => {
-                    return RusotoError::Service(MergeBranchesByThreeWayError::InvalidTargetBranch(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        MergeBranchesByThreeWayError::InvalidTargetBranch(err.msg),
+                        &res,
+                    )
                 }
                 "ManualMergeRequiredException" => {
-                    return RusotoError::Service(MergeBranchesByThreeWayError::ManualMergeRequired(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        MergeBranchesByThreeWayError::ManualMergeRequired(err.msg),
+                        &res,
+                    )
                 }
                 "MaximumConflictResolutionEntriesExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         MergeBranchesByThreeWayError::MaximumConflictResolutionEntriesExceeded(
                             err.msg,
                         ),
+                        &res,
                     )
                 }
                 "MaximumFileContentToLoadExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         MergeBranchesByThreeWayError::MaximumFileContentToLoadExceeded(err.msg),
+                        &res,
                     )
                 }
                 "MaximumItemsToCompareExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         MergeBranchesByThreeWayError::MaximumItemsToCompareExceeded(err.msg),
+                        &res,
                     )
                 }
                 "MultipleConflictResolutionEntriesException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         MergeBranchesByThreeWayError::MultipleConflictResolutionEntries(err.msg),
+                        &res,
                     )
                 }
                 "NameLengthExceededException" => {
-                    return RusotoError::Service(MergeBranchesByThreeWayError::NameLengthExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        MergeBranchesByThreeWayError::NameLengthExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "PathRequiredException" => {
-                    return RusotoError::Service(MergeBranchesByThreeWayError::PathRequired(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        MergeBranchesByThreeWayError::PathRequired(err.msg),
+                        &res,
+                    )
                 }
                 "ReplacementContentRequiredException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         MergeBranchesByThreeWayError::ReplacementContentRequired(err.msg),
+                        &res,
                     )
                 }
                 "ReplacementTypeRequiredException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         MergeBranchesByThreeWayError::ReplacementTypeRequired(err.msg),
+                        &res,
                     )
                 }
                 "RepositoryDoesNotExistException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         MergeBranchesByThreeWayError::RepositoryDoesNotExist(err.msg),
+                        &res,
                     )
                 }
                 "RepositoryNameRequiredException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         MergeBranchesByThreeWayError::RepositoryNameRequired(err.msg),
+                        &res,
                     )
                 }
                 "TipsDivergenceExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         MergeBranchesByThreeWayError::TipsDivergenceExceeded(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(MergeBranchesByThreeWayError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -10283,12 +11357,14 @@ impl fmt::Display for MergeBranchesByThreeWayError {
             MergeBranchesByThreeWayError::TipsDivergenceExceeded(ref cause) => {
                 write!(f, "{}", cause)
             }
+            MergeBranchesByThreeWayError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for MergeBranchesByThreeWayError {}
 /// Errors returned by MergePullRequestByFastForward
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum MergePullRequestByFastForwardError {
     /// <p>The merge cannot be completed because the target branch has been modified. Another user might have modified the target branch while the merge was in progress. Wait a few minutes, and then try again.</p>
     ConcurrentReferenceUpdate(String),
@@ -10328,6 +11404,8 @@ pub enum MergePullRequestByFastForwardError {
     RepositoryNotAssociatedWithPullRequest(String),
     /// <p>The tip of the source branch in the destination repository does not match the tip of the source branch specified in your request. The pull request might have been updated. Make sure that you have the latest changes.</p>
     TipOfSourceReferenceIsDifferent(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl MergePullRequestByFastForwardError {
@@ -10337,110 +11415,134 @@ impl MergePullRequestByFastForwardError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentReferenceUpdateException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         MergePullRequestByFastForwardError::ConcurrentReferenceUpdate(err.msg),
+                        &res,
                     )
                 }
                 "EncryptionIntegrityChecksFailedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         MergePullRequestByFastForwardError::EncryptionIntegrityChecksFailed(
                             err.msg,
                         ),
+                        &res,
                     )
                 }
                 "EncryptionKeyAccessDeniedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         MergePullRequestByFastForwardError::EncryptionKeyAccessDenied(err.msg),
+                        &res,
                     )
                 }
                 "EncryptionKeyDisabledException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         MergePullRequestByFastForwardError::EncryptionKeyDisabled(err.msg),
+                        &res,
                     )
                 }
                 "EncryptionKeyNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         MergePullRequestByFastForwardError::EncryptionKeyNotFound(err.msg),
+                        &res,
                     )
                 }
                 "EncryptionKeyUnavailableException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         MergePullRequestByFastForwardError::EncryptionKeyUnavailable(err.msg),
+                        &res,
                     )
                 }
                 "InvalidCommitIdException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         MergePullRequestByFastForwardError::InvalidCommitId(err.msg),
+                        &res,
                     )
                 }
                 "InvalidPullRequestIdException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         MergePullRequestByFastForwardError::InvalidPullRequestId(err.msg),
+                        &res,
                     )
                 }
                 "InvalidRepositoryNameException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         MergePullRequestByFastForwardError::InvalidRepositoryName(err.msg),
+                        &res,
                     )
                 }
                 "ManualMergeRequiredException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         MergePullRequestByFastForwardError::ManualMergeRequired(err.msg),
+                        &res,
                     )
                 }
                 "PullRequestAlreadyClosedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         MergePullRequestByFastForwardError::PullRequestAlreadyClosed(err.msg),
+                        &res,
                     )
                 }
                 "PullRequestApprovalRulesNotSatisfiedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         MergePullRequestByFastForwardError::PullRequestApprovalRulesNotSatisfied(
                             err.msg,
                         ),
+                        &res,
                     )
                 }
                 "PullRequestDoesNotExistException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         MergePullRequestByFastForwardError::PullRequestDoesNotExist(err.msg),
+                        &res,
                     )
                 }
                 "PullRequestIdRequiredException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         MergePullRequestByFastForwardError::PullRequestIdRequired(err.msg),
+                        &res,
                     )
                 }
                 "ReferenceDoesNotExistException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         MergePullRequestByFastForwardError::ReferenceDoesNotExist(err.msg),
+                        &res,
                     )
                 }
                 "RepositoryDoesNotExistException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         MergePullRequestByFastForwardError::RepositoryDoesNotExist(err.msg),
+                        &res,
                     )
                 }
                 "RepositoryNameRequiredException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         MergePullRequestByFastForwardError::RepositoryNameRequired(err.msg),
+                        &res,
                     )
                 }
                 "RepositoryNotAssociatedWithPullRequestException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         MergePullRequestByFastForwardError::RepositoryNotAssociatedWithPullRequest(
                             err.msg,
                         ),
+                        &res,
                     )
                 }
                 "TipOfSourceReferenceIsDifferentException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         MergePullRequestByFastForwardError::TipOfSourceReferenceIsDifferent(
                             err.msg,
                         ),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        MergePullRequestByFastForwardError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -10507,12 +11609,16 @@ impl fmt::Display for MergePullRequestByFastForwardError {
             MergePullRequestByFastForwardError::TipOfSourceReferenceIsDifferent(ref cause) => {
                 write!(f, "{}", cause)
             }
+            MergePullRequestByFastForwardError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for MergePullRequestByFastForwardError {}
 /// Errors returned by MergePullRequestBySquash
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum MergePullRequestBySquashError {
     /// <p>The commit message is too long. Provide a shorter string. </p>
     CommitMessageLengthExceeded(String),
@@ -10590,6 +11696,8 @@ pub enum MergePullRequestBySquashError {
     TipOfSourceReferenceIsDifferent(String),
     /// <p>The divergence between the tips of the provided commit specifiers is too great to determine whether there might be any merge conflicts. Locally compare the specifiers using <code>git diff</code> or a diff tool.</p>
     TipsDivergenceExceeded(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl MergePullRequestBySquashError {
@@ -10597,203 +11705,241 @@ impl MergePullRequestBySquashError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "CommitMessageLengthExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         MergePullRequestBySquashError::CommitMessageLengthExceeded(err.msg),
+                        &res,
                     )
                 }
                 "ConcurrentReferenceUpdateException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         MergePullRequestBySquashError::ConcurrentReferenceUpdate(err.msg),
+                        &res,
                     )
                 }
                 "EncryptionIntegrityChecksFailedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         MergePullRequestBySquashError::EncryptionIntegrityChecksFailed(err.msg),
+                        &res,
                     )
                 }
                 "EncryptionKeyAccessDeniedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         MergePullRequestBySquashError::EncryptionKeyAccessDenied(err.msg),
+                        &res,
                     )
                 }
                 "EncryptionKeyDisabledException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         MergePullRequestBySquashError::EncryptionKeyDisabled(err.msg),
+                        &res,
                     )
                 }
                 "EncryptionKeyNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         MergePullRequestBySquashError::EncryptionKeyNotFound(err.msg),
+                        &res,
                     )
                 }
                 "EncryptionKeyUnavailableException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         MergePullRequestBySquashError::EncryptionKeyUnavailable(err.msg),
+                        &res,
                     )
                 }
                 "FileContentSizeLimitExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         MergePullRequestBySquashError::FileContentSizeLimitExceeded(err.msg),
+                        &res,
                     )
                 }
                 "FolderContentSizeLimitExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         MergePullRequestBySquashError::FolderContentSizeLimitExceeded(err.msg),
+                        &res,
                     )
                 }
                 "InvalidCommitIdException" => {
-                    return RusotoError::Service(MergePullRequestBySquashError::InvalidCommitId(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        MergePullRequestBySquashError::InvalidCommitId(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidConflictDetailLevelException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         MergePullRequestBySquashError::InvalidConflictDetailLevel(err.msg),
+                        &res,
                     )
                 }
                 "InvalidConflictResolutionException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         MergePullRequestBySquashError::InvalidConflictResolution(err.msg),
+                        &res,
                     )
                 }
                 "InvalidConflictResolutionStrategyException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         MergePullRequestBySquashError::InvalidConflictResolutionStrategy(err.msg),
+                        &res,
                     )
                 }
                 "InvalidEmailException" => {
-                    return RusotoError::Service(MergePullRequestBySquashError::InvalidEmail(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        MergePullRequestBySquashError::InvalidEmail(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidFileModeException" => {
-                    return RusotoError::Service(MergePullRequestBySquashError::InvalidFileMode(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        MergePullRequestBySquashError::InvalidFileMode(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidPathException" => {
-                    return RusotoError::Service(MergePullRequestBySquashError::InvalidPath(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        MergePullRequestBySquashError::InvalidPath(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidPullRequestIdException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         MergePullRequestBySquashError::InvalidPullRequestId(err.msg),
+                        &res,
                     )
                 }
                 "InvalidReplacementContentException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         MergePullRequestBySquashError::InvalidReplacementContent(err.msg),
+                        &res,
                     )
                 }
                 "InvalidReplacementTypeException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         MergePullRequestBySquashError::InvalidReplacementType(err.msg),
+                        &res,
                     )
                 }
                 "InvalidRepositoryNameException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         MergePullRequestBySquashError::InvalidRepositoryName(err.msg),
+                        &res,
                     )
                 }
                 "ManualMergeRequiredException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         MergePullRequestBySquashError::ManualMergeRequired(err.msg),
+                        &res,
                     )
                 }
                 "MaximumConflictResolutionEntriesExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         MergePullRequestBySquashError::MaximumConflictResolutionEntriesExceeded(
                             err.msg,
                         ),
+                        &res,
                     )
                 }
                 "MaximumFileContentToLoadExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         MergePullRequestBySquashError::MaximumFileContentToLoadExceeded(err.msg),
+                        &res,
                     )
                 }
                 "MaximumItemsToCompareExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         MergePullRequestBySquashError::MaximumItemsToCompareExceeded(err.msg),
+                        &res,
                     )
                 }
                 "MultipleConflictResolutionEntriesException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         MergePullRequestBySquashError::MultipleConflictResolutionEntries(err.msg),
+                        &res,
                     )
                 }
                 "NameLengthExceededException" => {
-                    return RusotoError::Service(MergePullRequestBySquashError::NameLengthExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        MergePullRequestBySquashError::NameLengthExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "PathRequiredException" => {
-                    return RusotoError::Service(MergePullRequestBySquashError::PathRequired(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        MergePullRequestBySquashError::PathRequired(err.msg),
+                        &res,
+                    )
                 }
                 "PullRequestAlreadyClosedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         MergePullRequestBySquashError::PullRequestAlreadyClosed(err.msg),
+                        &res,
                     )
                 }
                 "PullRequestApprovalRulesNotSatisfiedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         MergePullRequestBySquashError::PullRequestApprovalRulesNotSatisfied(
                             err.msg,
                         ),
+                        &res,
                     )
                 }
                 "PullRequestDoesNotExistException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         MergePullRequestBySquashError::PullRequestDoesNotExist(err.msg),
+                        &res,
                     )
                 }
                 "PullRequestIdRequiredException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         MergePullRequestBySquashError::PullRequestIdRequired(err.msg),
+                        &res,
                     )
                 }
                 "ReplacementContentRequiredException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         MergePullRequestBySquashError::ReplacementContentRequired(err.msg),
+                        &res,
                     )
                 }
                 "ReplacementTypeRequiredException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         MergePullRequestBySquashError::ReplacementTypeRequired(err.msg),
+                        &res,
                     )
                 }
                 "RepositoryDoesNotExistException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         MergePullRequestBySquashError::RepositoryDoesNotExist(err.msg),
+                        &res,
                     )
                 }
                 "RepositoryNameRequiredException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         MergePullRequestBySquashError::RepositoryNameRequired(err.msg),
+                        &res,
                     )
                 }
                 "RepositoryNotAssociatedWithPullRequestException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         MergePullRequestBySquashError::RepositoryNotAssociatedWithPullRequest(
                             err.msg,
                         ),
+                        &res,
                     )
                 }
                 "TipOfSourceReferenceIsDifferentException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         MergePullRequestBySquashError::TipOfSourceReferenceIsDifferent(err.msg),
+                        &res,
                     )
                 }
                 "TipsDivergenceExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         MergePullRequestBySquashError::TipsDivergenceExceeded(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(MergePullRequestBySquashError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -10903,12 +12049,14 @@ impl fmt::Display for MergePullRequestBySquashError {
             MergePullRequestBySquashError::TipsDivergenceExceeded(ref cause) => {
                 write!(f, "{}", cause)
             }
+            MergePullRequestBySquashError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for MergePullRequestBySquashError {}
 /// Errors returned by MergePullRequestByThreeWay
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum MergePullRequestByThreeWayError {
     /// <p>The commit message is too long. Provide a shorter string. </p>
     CommitMessageLengthExceeded(String),
@@ -10986,6 +12134,8 @@ pub enum MergePullRequestByThreeWayError {
     TipOfSourceReferenceIsDifferent(String),
     /// <p>The divergence between the tips of the provided commit specifiers is too great to determine whether there might be any merge conflicts. Locally compare the specifiers using <code>git diff</code> or a diff tool.</p>
     TipsDivergenceExceeded(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl MergePullRequestByThreeWayError {
@@ -10995,203 +12145,243 @@ impl MergePullRequestByThreeWayError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "CommitMessageLengthExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         MergePullRequestByThreeWayError::CommitMessageLengthExceeded(err.msg),
+                        &res,
                     )
                 }
                 "ConcurrentReferenceUpdateException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         MergePullRequestByThreeWayError::ConcurrentReferenceUpdate(err.msg),
+                        &res,
                     )
                 }
                 "EncryptionIntegrityChecksFailedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         MergePullRequestByThreeWayError::EncryptionIntegrityChecksFailed(err.msg),
+                        &res,
                     )
                 }
                 "EncryptionKeyAccessDeniedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         MergePullRequestByThreeWayError::EncryptionKeyAccessDenied(err.msg),
+                        &res,
                     )
                 }
                 "EncryptionKeyDisabledException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         MergePullRequestByThreeWayError::EncryptionKeyDisabled(err.msg),
+                        &res,
                     )
                 }
                 "EncryptionKeyNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         MergePullRequestByThreeWayError::EncryptionKeyNotFound(err.msg),
+                        &res,
                     )
                 }
                 "EncryptionKeyUnavailableException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         MergePullRequestByThreeWayError::EncryptionKeyUnavailable(err.msg),
+                        &res,
                     )
                 }
                 "FileContentSizeLimitExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         MergePullRequestByThreeWayError::FileContentSizeLimitExceeded(err.msg),
+                        &res,
                     )
                 }
                 "FolderContentSizeLimitExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         MergePullRequestByThreeWayError::FolderContentSizeLimitExceeded(err.msg),
+                        &res,
                     )
                 }
                 "InvalidCommitIdException" => {
-                    return RusotoError::Service(MergePullRequestByThreeWayError::InvalidCommitId(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        MergePullRequestByThreeWayError::InvalidCommitId(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidConflictDetailLevelException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         MergePullRequestByThreeWayError::InvalidConflictDetailLevel(err.msg),
+                        &res,
                     )
                 }
                 "InvalidConflictResolutionException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         MergePullRequestByThreeWayError::InvalidConflictResolution(err.msg),
+                        &res,
                     )
                 }
                 "InvalidConflictResolutionStrategyException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         MergePullRequestByThreeWayError::InvalidConflictResolutionStrategy(err.msg),
+                        &res,
                     )
                 }
                 "InvalidEmailException" => {
-                    return RusotoError::Service(MergePullRequestByThreeWayError::InvalidEmail(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        MergePullRequestByThreeWayError::InvalidEmail(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidFileModeException" => {
-                    return RusotoError::Service(MergePullRequestByThreeWayError::InvalidFileMode(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        MergePullRequestByThreeWayError::InvalidFileMode(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidPathException" => {
-                    return RusotoError::Service(MergePullRequestByThreeWayError::InvalidPath(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        MergePullRequestByThreeWayError::InvalidPath(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidPullRequestIdException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         MergePullRequestByThreeWayError::InvalidPullRequestId(err.msg),
+                        &res,
                     )
                 }
                 "InvalidReplacementContentException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         MergePullRequestByThreeWayError::InvalidReplacementContent(err.msg),
+                        &res,
                     )
                 }
                 "InvalidReplacementTypeException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         MergePullRequestByThreeWayError::InvalidReplacementType(err.msg),
+                        &res,
                     )
                 }
                 "InvalidRepositoryNameException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         MergePullRequestByThreeWayError::InvalidRepositoryName(err.msg),
+                        &res,
                     )
                 }
                 "ManualMergeRequiredException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         MergePullRequestByThreeWayError::ManualMergeRequired(err.msg),
+                        &res,
                     )
                 }
                 "MaximumConflictResolutionEntriesExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         MergePullRequestByThreeWayError::MaximumConflictResolutionEntriesExceeded(
                             err.msg,
                         ),
+                        &res,
                     )
                 }
                 "MaximumFileContentToLoadExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         MergePullRequestByThreeWayError::MaximumFileContentToLoadExceeded(err.msg),
+                        &res,
                     )
                 }
                 "MaximumItemsToCompareExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         MergePullRequestByThreeWayError::MaximumItemsToCompareExceeded(err.msg),
+                        &res,
                     )
                 }
                 "MultipleConflictResolutionEntriesException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         MergePullRequestByThreeWayError::MultipleConflictResolutionEntries(err.msg),
+                        &res,
                     )
                 }
                 "NameLengthExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         MergePullRequestByThreeWayError::NameLengthExceeded(err.msg),
+                        &res,
                     )
                 }
                 "PathRequiredException" => {
-                    return RusotoError::Service(MergePullRequestByThreeWayError::PathRequired(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        MergePullRequestByThreeWayError::PathRequired(err.msg),
+                        &res,
+                    )
                 }
                 "PullRequestAlreadyClosedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         MergePullRequestByThreeWayError::PullRequestAlreadyClosed(err.msg),
+                        &res,
                     )
                 }
                 "PullRequestApprovalRulesNotSatisfiedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         MergePullRequestByThreeWayError::PullRequestApprovalRulesNotSatisfied(
                             err.msg,
                         ),
+                        &res,
                     )
                 }
                 "PullRequestDoesNotExistException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         MergePullRequestByThreeWayError::PullRequestDoesNotExist(err.msg),
+                        &res,
                     )
                 }
                 "PullRequestIdRequiredException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         MergePullRequestByThreeWayError::PullRequestIdRequired(err.msg),
+                        &res,
                     )
                 }
                 "ReplacementContentRequiredException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         MergePullRequestByThreeWayError::ReplacementContentRequired(err.msg),
+                        &res,
                     )
                 }
                 "ReplacementTypeRequiredException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         MergePullRequestByThreeWayError::ReplacementTypeRequired(err.msg),
+                        &res,
                     )
                 }
                 "RepositoryDoesNotExistException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         MergePullRequestByThreeWayError::RepositoryDoesNotExist(err.msg),
+                        &res,
                     )
                 }
                 "RepositoryNameRequiredException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         MergePullRequestByThreeWayError::RepositoryNameRequired(err.msg),
+                        &res,
                     )
                 }
                 "RepositoryNotAssociatedWithPullRequestException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         MergePullRequestByThreeWayError::RepositoryNotAssociatedWithPullRequest(
                             err.msg,
                         ),
+                        &res,
                     )
                 }
                 "TipOfSourceReferenceIsDifferentException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         MergePullRequestByThreeWayError::TipOfSourceReferenceIsDifferent(err.msg),
+                        &res,
                     )
                 }
                 "TipsDivergenceExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         MergePullRequestByThreeWayError::TipsDivergenceExceeded(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(MergePullRequestByThreeWayError::Unhandled, &res)
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -11305,12 +12495,14 @@ impl fmt::Display for MergePullRequestByThreeWayError {
             MergePullRequestByThreeWayError::TipsDivergenceExceeded(ref cause) => {
                 write!(f, "{}", cause)
             }
+            MergePullRequestByThreeWayError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for MergePullRequestByThreeWayError {}
 /// Errors returned by OverridePullRequestApprovalRules
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum OverridePullRequestApprovalRulesError {
     /// <p>An encryption integrity check failed.</p>
     EncryptionIntegrityChecksFailed(String),
@@ -11342,6 +12534,8 @@ pub enum OverridePullRequestApprovalRulesError {
     RevisionIdRequired(String),
     /// <p>The revision ID provided in the request does not match the current revision ID. Use GetPullRequest to retrieve the current revision ID.</p>
     RevisionNotCurrent(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl OverridePullRequestApprovalRulesError {
@@ -11351,84 +12545,104 @@ impl OverridePullRequestApprovalRulesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "EncryptionIntegrityChecksFailedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         OverridePullRequestApprovalRulesError::EncryptionIntegrityChecksFailed(
                             err.msg,
                         ),
+                        &res,
                     )
                 }
                 "EncryptionKeyAccessDeniedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         OverridePullRequestApprovalRulesError::EncryptionKeyAccessDenied(err.msg),
+                        &res,
                     )
                 }
                 "EncryptionKeyDisabledException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         OverridePullRequestApprovalRulesError::EncryptionKeyDisabled(err.msg),
+                        &res,
                     )
                 }
                 "EncryptionKeyNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         OverridePullRequestApprovalRulesError::EncryptionKeyNotFound(err.msg),
+                        &res,
                     )
                 }
                 "EncryptionKeyUnavailableException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         OverridePullRequestApprovalRulesError::EncryptionKeyUnavailable(err.msg),
+                        &res,
                     )
                 }
                 "InvalidOverrideStatusException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         OverridePullRequestApprovalRulesError::InvalidOverrideStatus(err.msg),
+                        &res,
                     )
                 }
                 "InvalidPullRequestIdException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         OverridePullRequestApprovalRulesError::InvalidPullRequestId(err.msg),
+                        &res,
                     )
                 }
                 "InvalidRevisionIdException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         OverridePullRequestApprovalRulesError::InvalidRevisionId(err.msg),
+                        &res,
                     )
                 }
                 "OverrideAlreadySetException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         OverridePullRequestApprovalRulesError::OverrideAlreadySet(err.msg),
+                        &res,
                     )
                 }
                 "OverrideStatusRequiredException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         OverridePullRequestApprovalRulesError::OverrideStatusRequired(err.msg),
+                        &res,
                     )
                 }
                 "PullRequestAlreadyClosedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         OverridePullRequestApprovalRulesError::PullRequestAlreadyClosed(err.msg),
+                        &res,
                     )
                 }
                 "PullRequestDoesNotExistException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         OverridePullRequestApprovalRulesError::PullRequestDoesNotExist(err.msg),
+                        &res,
                     )
                 }
                 "PullRequestIdRequiredException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         OverridePullRequestApprovalRulesError::PullRequestIdRequired(err.msg),
+                        &res,
                     )
                 }
                 "RevisionIdRequiredException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         OverridePullRequestApprovalRulesError::RevisionIdRequired(err.msg),
+                        &res,
                     )
                 }
                 "RevisionNotCurrentException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         OverridePullRequestApprovalRulesError::RevisionNotCurrent(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        OverridePullRequestApprovalRulesError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -11483,12 +12697,16 @@ impl fmt::Display for OverridePullRequestApprovalRulesError {
             OverridePullRequestApprovalRulesError::RevisionNotCurrent(ref cause) => {
                 write!(f, "{}", cause)
             }
+            OverridePullRequestApprovalRulesError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for OverridePullRequestApprovalRulesError {}
 /// Errors returned by PostCommentForComparedCommit
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum PostCommentForComparedCommitError {
     /// <p>The before commit ID and the after commit ID are the same, which is not valid. The before commit ID and the after commit ID must be different commit IDs.</p>
     BeforeCommitIdAndAfterCommitIdAreSame(String),
@@ -11536,6 +12754,8 @@ pub enum PostCommentForComparedCommitError {
     RepositoryDoesNotExist(String),
     /// <p>A repository name is required, but was not specified.</p>
     RepositoryNameRequired(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl PostCommentForComparedCommitError {
@@ -11545,124 +12765,152 @@ impl PostCommentForComparedCommitError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "BeforeCommitIdAndAfterCommitIdAreSameException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         PostCommentForComparedCommitError::BeforeCommitIdAndAfterCommitIdAreSame(
                             err.msg,
                         ),
+                        &res,
                     )
                 }
                 "ClientRequestTokenRequiredException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         PostCommentForComparedCommitError::ClientRequestTokenRequired(err.msg),
+                        &res,
                     )
                 }
                 "CommentContentRequiredException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         PostCommentForComparedCommitError::CommentContentRequired(err.msg),
+                        &res,
                     )
                 }
                 "CommentContentSizeLimitExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         PostCommentForComparedCommitError::CommentContentSizeLimitExceeded(err.msg),
+                        &res,
                     )
                 }
                 "CommitDoesNotExistException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         PostCommentForComparedCommitError::CommitDoesNotExist(err.msg),
+                        &res,
                     )
                 }
                 "CommitIdRequiredException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         PostCommentForComparedCommitError::CommitIdRequired(err.msg),
+                        &res,
                     )
                 }
                 "EncryptionIntegrityChecksFailedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         PostCommentForComparedCommitError::EncryptionIntegrityChecksFailed(err.msg),
+                        &res,
                     )
                 }
                 "EncryptionKeyAccessDeniedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         PostCommentForComparedCommitError::EncryptionKeyAccessDenied(err.msg),
+                        &res,
                     )
                 }
                 "EncryptionKeyDisabledException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         PostCommentForComparedCommitError::EncryptionKeyDisabled(err.msg),
+                        &res,
                     )
                 }
                 "EncryptionKeyNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         PostCommentForComparedCommitError::EncryptionKeyNotFound(err.msg),
+                        &res,
                     )
                 }
                 "EncryptionKeyUnavailableException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         PostCommentForComparedCommitError::EncryptionKeyUnavailable(err.msg),
+                        &res,
                     )
                 }
                 "IdempotencyParameterMismatchException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         PostCommentForComparedCommitError::IdempotencyParameterMismatch(err.msg),
+                        &res,
                     )
                 }
                 "InvalidClientRequestTokenException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         PostCommentForComparedCommitError::InvalidClientRequestToken(err.msg),
+                        &res,
                     )
                 }
                 "InvalidCommitIdException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         PostCommentForComparedCommitError::InvalidCommitId(err.msg),
+                        &res,
                     )
                 }
                 "InvalidFileLocationException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         PostCommentForComparedCommitError::InvalidFileLocation(err.msg),
+                        &res,
                     )
                 }
                 "InvalidFilePositionException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         PostCommentForComparedCommitError::InvalidFilePosition(err.msg),
+                        &res,
                     )
                 }
                 "InvalidPathException" => {
-                    return RusotoError::Service(PostCommentForComparedCommitError::InvalidPath(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        PostCommentForComparedCommitError::InvalidPath(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidRelativeFileVersionEnumException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         PostCommentForComparedCommitError::InvalidRelativeFileVersionEnum(err.msg),
+                        &res,
                     )
                 }
                 "InvalidRepositoryNameException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         PostCommentForComparedCommitError::InvalidRepositoryName(err.msg),
+                        &res,
                     )
                 }
                 "PathDoesNotExistException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         PostCommentForComparedCommitError::PathDoesNotExist(err.msg),
+                        &res,
                     )
                 }
                 "PathRequiredException" => {
-                    return RusotoError::Service(PostCommentForComparedCommitError::PathRequired(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        PostCommentForComparedCommitError::PathRequired(err.msg),
+                        &res,
+                    )
                 }
                 "RepositoryDoesNotExistException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         PostCommentForComparedCommitError::RepositoryDoesNotExist(err.msg),
+                        &res,
                     )
                 }
                 "RepositoryNameRequiredException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         PostCommentForComparedCommitError::RepositoryNameRequired(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        PostCommentForComparedCommitError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -11735,12 +12983,14 @@ impl fmt::Display for PostCommentForComparedCommitError {
             PostCommentForComparedCommitError::RepositoryNameRequired(ref cause) => {
                 write!(f, "{}", cause)
             }
+            PostCommentForComparedCommitError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for PostCommentForComparedCommitError {}
 /// Errors returned by PostCommentForPullRequest
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum PostCommentForPullRequestError {
     /// <p>The before commit ID and the after commit ID are the same, which is not valid. The before commit ID and the after commit ID must be different commit IDs.</p>
     BeforeCommitIdAndAfterCommitIdAreSame(String),
@@ -11796,6 +13046,8 @@ pub enum PostCommentForPullRequestError {
     RepositoryNameRequired(String),
     /// <p>The repository does not contain any pull requests with that pull request ID. Use GetPullRequest to verify the correct repository name for the pull request ID.</p>
     RepositoryNotAssociatedWithPullRequest(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl PostCommentForPullRequestError {
@@ -11803,146 +13055,175 @@ impl PostCommentForPullRequestError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "BeforeCommitIdAndAfterCommitIdAreSameException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         PostCommentForPullRequestError::BeforeCommitIdAndAfterCommitIdAreSame(
                             err.msg,
                         ),
+                        &res,
                     )
                 }
                 "ClientRequestTokenRequiredException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         PostCommentForPullRequestError::ClientRequestTokenRequired(err.msg),
+                        &res,
                     )
                 }
                 "CommentContentRequiredException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         PostCommentForPullRequestError::CommentContentRequired(err.msg),
+                        &res,
                     )
                 }
                 "CommentContentSizeLimitExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         PostCommentForPullRequestError::CommentContentSizeLimitExceeded(err.msg),
+                        &res,
                     )
                 }
                 "CommitDoesNotExistException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         PostCommentForPullRequestError::CommitDoesNotExist(err.msg),
+                        &res,
                     )
                 }
                 "CommitIdRequiredException" => {
-                    return RusotoError::Service(PostCommentForPullRequestError::CommitIdRequired(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        PostCommentForPullRequestError::CommitIdRequired(err.msg),
+                        &res,
+                    )
                 }
                 "EncryptionIntegrityChecksFailedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         PostCommentForPullRequestError::EncryptionIntegrityChecksFailed(err.msg),
+                        &res,
                     )
                 }
                 "EncryptionKeyAccessDeniedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         PostCommentForPullRequestError::EncryptionKeyAccessDenied(err.msg),
+                        &res,
                     )
                 }
                 "EncryptionKeyDisabledException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         PostCommentForPullRequestError::EncryptionKeyDisabled(err.msg),
+                        &res,
                     )
                 }
                 "EncryptionKeyNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         PostCommentForPullRequestError::EncryptionKeyNotFound(err.msg),
+                        &res,
                     )
                 }
                 "EncryptionKeyUnavailableException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         PostCommentForPullRequestError::EncryptionKeyUnavailable(err.msg),
+                        &res,
                     )
                 }
                 "IdempotencyParameterMismatchException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         PostCommentForPullRequestError::IdempotencyParameterMismatch(err.msg),
+                        &res,
                     )
                 }
                 "InvalidClientRequestTokenException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         PostCommentForPullRequestError::InvalidClientRequestToken(err.msg),
+                        &res,
                     )
                 }
                 "InvalidCommitIdException" => {
-                    return RusotoError::Service(PostCommentForPullRequestError::InvalidCommitId(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        PostCommentForPullRequestError::InvalidCommitId(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidFileLocationException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         PostCommentForPullRequestError::InvalidFileLocation(err.msg),
+                        &res,
                     )
                 }
                 "InvalidFilePositionException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         PostCommentForPullRequestError::InvalidFilePosition(err.msg),
+                        &res,
                     )
                 }
                 "InvalidPathException" => {
-                    return RusotoError::Service(PostCommentForPullRequestError::InvalidPath(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        PostCommentForPullRequestError::InvalidPath(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidPullRequestIdException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         PostCommentForPullRequestError::InvalidPullRequestId(err.msg),
+                        &res,
                     )
                 }
                 "InvalidRelativeFileVersionEnumException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         PostCommentForPullRequestError::InvalidRelativeFileVersionEnum(err.msg),
+                        &res,
                     )
                 }
                 "InvalidRepositoryNameException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         PostCommentForPullRequestError::InvalidRepositoryName(err.msg),
+                        &res,
                     )
                 }
                 "PathDoesNotExistException" => {
-                    return RusotoError::Service(PostCommentForPullRequestError::PathDoesNotExist(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        PostCommentForPullRequestError::PathDoesNotExist(err.msg),
+                        &res,
+                    )
                 }
                 "PathRequiredException" => {
-                    return RusotoError::Service(PostCommentForPullRequestError::PathRequired(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        PostCommentForPullRequestError::PathRequired(err.msg),
+                        &res,
+                    )
                 }
                 "PullRequestDoesNotExistException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         PostCommentForPullRequestError::PullRequestDoesNotExist(err.msg),
+                        &res,
                     )
                 }
                 "PullRequestIdRequiredException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         PostCommentForPullRequestError::PullRequestIdRequired(err.msg),
+                        &res,
                     )
                 }
                 "RepositoryDoesNotExistException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         PostCommentForPullRequestError::RepositoryDoesNotExist(err.msg),
+                        &res,
                     )
                 }
                 "RepositoryNameRequiredException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         PostCommentForPullRequestError::RepositoryNameRequired(err.msg),
+                        &res,
                     )
                 }
                 "RepositoryNotAssociatedWithPullRequestException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         PostCommentForPullRequestError::RepositoryNotAssociatedWithPullRequest(
                             err.msg,
                         ),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(PostCommentForPullRequestError::Unhandled, &res)
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -12021,12 +13302,14 @@ impl fmt::Display for PostCommentForPullRequestError {
             PostCommentForPullRequestError::RepositoryNotAssociatedWithPullRequest(ref cause) => {
                 write!(f, "{}", cause)
             }
+            PostCommentForPullRequestError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for PostCommentForPullRequestError {}
 /// Errors returned by PostCommentReply
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum PostCommentReplyError {
     /// <p>A client request token is required. A client request token is an unique, client-generated idempotency token that, when provided in a request, ensures the request cannot be repeated with a changed parameter. If a request is received with the same parameters and a token is included, the request returns information about the initial request that used that token.</p>
     ClientRequestTokenRequired(String),
@@ -12044,6 +13327,8 @@ pub enum PostCommentReplyError {
     InvalidClientRequestToken(String),
     /// <p>The comment ID is not in a valid format. Make sure that you have provided the full comment ID.</p>
     InvalidCommentId(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl PostCommentReplyError {
@@ -12051,43 +13336,55 @@ impl PostCommentReplyError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ClientRequestTokenRequiredException" => {
-                    return RusotoError::Service(PostCommentReplyError::ClientRequestTokenRequired(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        PostCommentReplyError::ClientRequestTokenRequired(err.msg),
+                        &res,
+                    )
                 }
                 "CommentContentRequiredException" => {
-                    return RusotoError::Service(PostCommentReplyError::CommentContentRequired(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        PostCommentReplyError::CommentContentRequired(err.msg),
+                        &res,
+                    )
                 }
                 "CommentContentSizeLimitExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         PostCommentReplyError::CommentContentSizeLimitExceeded(err.msg),
+                        &res,
                     )
                 }
                 "CommentDoesNotExistException" => {
-                    return RusotoError::Service(PostCommentReplyError::CommentDoesNotExist(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        PostCommentReplyError::CommentDoesNotExist(err.msg),
+                        &res,
+                    )
                 }
                 "CommentIdRequiredException" => {
-                    return RusotoError::Service(PostCommentReplyError::CommentIdRequired(err.msg))
+                    return RusotoError::service(
+                        PostCommentReplyError::CommentIdRequired(err.msg),
+                        &res,
+                    )
                 }
                 "IdempotencyParameterMismatchException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         PostCommentReplyError::IdempotencyParameterMismatch(err.msg),
+                        &res,
                     )
                 }
                 "InvalidClientRequestTokenException" => {
-                    return RusotoError::Service(PostCommentReplyError::InvalidClientRequestToken(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        PostCommentReplyError::InvalidClientRequestToken(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidCommentIdException" => {
-                    return RusotoError::Service(PostCommentReplyError::InvalidCommentId(err.msg))
+                    return RusotoError::service(
+                        PostCommentReplyError::InvalidCommentId(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(PostCommentReplyError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -12109,12 +13406,14 @@ impl fmt::Display for PostCommentReplyError {
             }
             PostCommentReplyError::InvalidClientRequestToken(ref cause) => write!(f, "{}", cause),
             PostCommentReplyError::InvalidCommentId(ref cause) => write!(f, "{}", cause),
+            PostCommentReplyError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for PostCommentReplyError {}
 /// Errors returned by PutFile
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum PutFileError {
     /// <p>The specified branch does not exist.</p>
     BranchDoesNotExist(String),
@@ -12176,6 +13475,8 @@ pub enum PutFileError {
     RepositoryNameRequired(String),
     /// <p>The file was not added or updated because the content of the file is exactly the same as the content of that file in the repository and branch that you specified.</p>
     SameFileContent(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl PutFileError {
@@ -12183,109 +13484,142 @@ impl PutFileError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "BranchDoesNotExistException" => {
-                    return RusotoError::Service(PutFileError::BranchDoesNotExist(err.msg))
+                    return RusotoError::service(PutFileError::BranchDoesNotExist(err.msg), &res)
                 }
                 "BranchNameIsTagNameException" => {
-                    return RusotoError::Service(PutFileError::BranchNameIsTagName(err.msg))
+                    return RusotoError::service(PutFileError::BranchNameIsTagName(err.msg), &res)
                 }
                 "BranchNameRequiredException" => {
-                    return RusotoError::Service(PutFileError::BranchNameRequired(err.msg))
+                    return RusotoError::service(PutFileError::BranchNameRequired(err.msg), &res)
                 }
                 "CommitMessageLengthExceededException" => {
-                    return RusotoError::Service(PutFileError::CommitMessageLengthExceeded(err.msg))
+                    return RusotoError::service(
+                        PutFileError::CommitMessageLengthExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "DirectoryNameConflictsWithFileNameException" => {
-                    return RusotoError::Service(PutFileError::DirectoryNameConflictsWithFileName(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        PutFileError::DirectoryNameConflictsWithFileName(err.msg),
+                        &res,
+                    )
                 }
                 "EncryptionIntegrityChecksFailedException" => {
-                    return RusotoError::Service(PutFileError::EncryptionIntegrityChecksFailed(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        PutFileError::EncryptionIntegrityChecksFailed(err.msg),
+                        &res,
+                    )
                 }
                 "EncryptionKeyAccessDeniedException" => {
-                    return RusotoError::Service(PutFileError::EncryptionKeyAccessDenied(err.msg))
+                    return RusotoError::service(
+                        PutFileError::EncryptionKeyAccessDenied(err.msg),
+                        &res,
+                    )
                 }
                 "EncryptionKeyDisabledException" => {
-                    return RusotoError::Service(PutFileError::EncryptionKeyDisabled(err.msg))
+                    return RusotoError::service(PutFileError::EncryptionKeyDisabled(err.msg), &res)
                 }
                 "EncryptionKeyNotFoundException" => {
-                    return RusotoError::Service(PutFileError::EncryptionKeyNotFound(err.msg))
+                    return RusotoError::service(PutFileError::EncryptionKeyNotFound(err.msg), &res)
                 }
                 "EncryptionKeyUnavailableException" => {
-                    return RusotoError::Service(PutFileError::EncryptionKeyUnavailable(err.msg))
+                    return RusotoError::service(
+                        PutFileError::EncryptionKeyUnavailable(err.msg),
+                        &res,
+                    )
                 }
                 "FileContentRequiredException" => {
-                    return RusotoError::Service(PutFileError::FileContentRequired(err.msg))
+                    return RusotoError::service(PutFileError::FileContentRequired(err.msg), &res)
                 }
                 "FileContentSizeLimitExceededException" => {
-                    return RusotoError::Service(PutFileError::FileContentSizeLimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        PutFileError::FileContentSizeLimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "FileNameConflictsWithDirectoryNameException" => {
-                    return RusotoError::Service(PutFileError::FileNameConflictsWithDirectoryName(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        PutFileError::FileNameConflictsWithDirectoryName(err.msg),
+                        &res,
+                    )
                 }
                 "FilePathConflictsWithSubmodulePathException" => {
-                    return RusotoError::Service(PutFileError::FilePathConflictsWithSubmodulePath(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        PutFileError::FilePathConflictsWithSubmodulePath(err.msg),
+                        &res,
+                    )
                 }
                 "FolderContentSizeLimitExceededException" => {
-                    return RusotoError::Service(PutFileError::FolderContentSizeLimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        PutFileError::FolderContentSizeLimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidBranchNameException" => {
-                    return RusotoError::Service(PutFileError::InvalidBranchName(err.msg))
+                    return RusotoError::service(PutFileError::InvalidBranchName(err.msg), &res)
                 }
                 "InvalidDeletionParameterException" => {
-                    return RusotoError::Service(PutFileError::InvalidDeletionParameter(err.msg))
+                    return RusotoError::service(
+                        PutFileError::InvalidDeletionParameter(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidEmailException" => {
-                    return RusotoError::Service(PutFileError::InvalidEmail(err.msg))
+                    return RusotoError::service(PutFileError::InvalidEmail(err.msg), &res)
                 }
                 "InvalidFileModeException" => {
-                    return RusotoError::Service(PutFileError::InvalidFileMode(err.msg))
+                    return RusotoError::service(PutFileError::InvalidFileMode(err.msg), &res)
                 }
                 "InvalidParentCommitIdException" => {
-                    return RusotoError::Service(PutFileError::InvalidParentCommitId(err.msg))
+                    return RusotoError::service(PutFileError::InvalidParentCommitId(err.msg), &res)
                 }
                 "InvalidPathException" => {
-                    return RusotoError::Service(PutFileError::InvalidPath(err.msg))
+                    return RusotoError::service(PutFileError::InvalidPath(err.msg), &res)
                 }
                 "InvalidRepositoryNameException" => {
-                    return RusotoError::Service(PutFileError::InvalidRepositoryName(err.msg))
+                    return RusotoError::service(PutFileError::InvalidRepositoryName(err.msg), &res)
                 }
                 "NameLengthExceededException" => {
-                    return RusotoError::Service(PutFileError::NameLengthExceeded(err.msg))
+                    return RusotoError::service(PutFileError::NameLengthExceeded(err.msg), &res)
                 }
                 "ParentCommitDoesNotExistException" => {
-                    return RusotoError::Service(PutFileError::ParentCommitDoesNotExist(err.msg))
+                    return RusotoError::service(
+                        PutFileError::ParentCommitDoesNotExist(err.msg),
+                        &res,
+                    )
                 }
                 "ParentCommitIdOutdatedException" => {
-                    return RusotoError::Service(PutFileError::ParentCommitIdOutdated(err.msg))
+                    return RusotoError::service(
+                        PutFileError::ParentCommitIdOutdated(err.msg),
+                        &res,
+                    )
                 }
                 "ParentCommitIdRequiredException" => {
-                    return RusotoError::Service(PutFileError::ParentCommitIdRequired(err.msg))
+                    return RusotoError::service(
+                        PutFileError::ParentCommitIdRequired(err.msg),
+                        &res,
+                    )
                 }
                 "PathRequiredException" => {
-                    return RusotoError::Service(PutFileError::PathRequired(err.msg))
+                    return RusotoError::service(PutFileError::PathRequired(err.msg), &res)
                 }
                 "RepositoryDoesNotExistException" => {
-                    return RusotoError::Service(PutFileError::RepositoryDoesNotExist(err.msg))
+                    return RusotoError::service(
+                        PutFileError::RepositoryDoesNotExist(err.msg),
+                        &res,
+                    )
                 }
                 "RepositoryNameRequiredException" => {
-                    return RusotoError::Service(PutFileError::RepositoryNameRequired(err.msg))
+                    return RusotoError::service(
+                        PutFileError::RepositoryNameRequired(err.msg),
+                        &res,
+                    )
                 }
                 "SameFileContentException" => {
-                    return RusotoError::Service(PutFileError::SameFileContent(err.msg))
+                    return RusotoError::service(PutFileError::SameFileContent(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(PutFileError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -12325,12 +13659,14 @@ impl fmt::Display for PutFileError {
             PutFileError::RepositoryDoesNotExist(ref cause) => write!(f, "{}", cause),
             PutFileError::RepositoryNameRequired(ref cause) => write!(f, "{}", cause),
             PutFileError::SameFileContent(ref cause) => write!(f, "{}", cause),
+            PutFileError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for PutFileError {}
 /// Errors returned by PutRepositoryTriggers
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum PutRepositoryTriggersError {
     /// <p>An encryption integrity check failed.</p>
     EncryptionIntegrityChecksFailed(String),
@@ -12374,6 +13710,8 @@ pub enum PutRepositoryTriggersError {
     RepositoryTriggerNameRequired(String),
     /// <p>The list of triggers for the repository is required, but was not specified.</p>
     RepositoryTriggersListRequired(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl PutRepositoryTriggersError {
@@ -12381,116 +13719,137 @@ impl PutRepositoryTriggersError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "EncryptionIntegrityChecksFailedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         PutRepositoryTriggersError::EncryptionIntegrityChecksFailed(err.msg),
+                        &res,
                     )
                 }
                 "EncryptionKeyAccessDeniedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         PutRepositoryTriggersError::EncryptionKeyAccessDenied(err.msg),
+                        &res,
                     )
                 }
                 "EncryptionKeyDisabledException" => {
-                    return RusotoError::Service(PutRepositoryTriggersError::EncryptionKeyDisabled(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        PutRepositoryTriggersError::EncryptionKeyDisabled(err.msg),
+                        &res,
+                    )
                 }
                 "EncryptionKeyNotFoundException" => {
-                    return RusotoError::Service(PutRepositoryTriggersError::EncryptionKeyNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        PutRepositoryTriggersError::EncryptionKeyNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "EncryptionKeyUnavailableException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         PutRepositoryTriggersError::EncryptionKeyUnavailable(err.msg),
+                        &res,
                     )
                 }
                 "InvalidRepositoryNameException" => {
-                    return RusotoError::Service(PutRepositoryTriggersError::InvalidRepositoryName(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        PutRepositoryTriggersError::InvalidRepositoryName(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidRepositoryTriggerBranchNameException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         PutRepositoryTriggersError::InvalidRepositoryTriggerBranchName(err.msg),
+                        &res,
                     )
                 }
                 "InvalidRepositoryTriggerCustomDataException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         PutRepositoryTriggersError::InvalidRepositoryTriggerCustomData(err.msg),
+                        &res,
                     )
                 }
                 "InvalidRepositoryTriggerDestinationArnException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         PutRepositoryTriggersError::InvalidRepositoryTriggerDestinationArn(err.msg),
+                        &res,
                     )
                 }
                 "InvalidRepositoryTriggerEventsException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         PutRepositoryTriggersError::InvalidRepositoryTriggerEvents(err.msg),
+                        &res,
                     )
                 }
                 "InvalidRepositoryTriggerNameException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         PutRepositoryTriggersError::InvalidRepositoryTriggerName(err.msg),
+                        &res,
                     )
                 }
                 "InvalidRepositoryTriggerRegionException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         PutRepositoryTriggersError::InvalidRepositoryTriggerRegion(err.msg),
+                        &res,
                     )
                 }
                 "MaximumBranchesExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         PutRepositoryTriggersError::MaximumBranchesExceeded(err.msg),
+                        &res,
                     )
                 }
                 "MaximumRepositoryTriggersExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         PutRepositoryTriggersError::MaximumRepositoryTriggersExceeded(err.msg),
+                        &res,
                     )
                 }
                 "RepositoryDoesNotExistException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         PutRepositoryTriggersError::RepositoryDoesNotExist(err.msg),
+                        &res,
                     )
                 }
                 "RepositoryNameRequiredException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         PutRepositoryTriggersError::RepositoryNameRequired(err.msg),
+                        &res,
                     )
                 }
                 "RepositoryTriggerBranchNameListRequiredException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         PutRepositoryTriggersError::RepositoryTriggerBranchNameListRequired(
                             err.msg,
                         ),
+                        &res,
                     )
                 }
                 "RepositoryTriggerDestinationArnRequiredException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         PutRepositoryTriggersError::RepositoryTriggerDestinationArnRequired(
                             err.msg,
                         ),
+                        &res,
                     )
                 }
                 "RepositoryTriggerEventsListRequiredException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         PutRepositoryTriggersError::RepositoryTriggerEventsListRequired(err.msg),
+                        &res,
                     )
                 }
                 "RepositoryTriggerNameRequiredException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         PutRepositoryTriggersError::RepositoryTriggerNameRequired(err.msg),
+                        &res,
                     )
                 }
                 "RepositoryTriggersListRequiredException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         PutRepositoryTriggersError::RepositoryTriggersListRequired(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(PutRepositoryTriggersError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -12553,12 +13912,14 @@ impl fmt::Display for PutRepositoryTriggersError {
             PutRepositoryTriggersError::RepositoryTriggersListRequired(ref cause) => {
                 write!(f, "{}", cause)
             }
+            PutRepositoryTriggersError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for PutRepositoryTriggersError {}
 /// Errors returned by TagResource
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum TagResourceError {
     /// <p><p>A specified repository name is not valid.</p> <note> <p>This exception occurs only when a specified repository name is not valid. Other exceptions occur when a required repository parameter is missing, or when a specified repository does not exist.</p> </note></p>
     InvalidRepositoryName(String),
@@ -12578,6 +13939,8 @@ pub enum TagResourceError {
     TagsMapRequired(String),
     /// <p>The maximum number of tags for an AWS CodeCommit resource has been exceeded.</p>
     TooManyTags(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl TagResourceError {
@@ -12585,34 +13948,49 @@ impl TagResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidRepositoryNameException" => {
-                    return RusotoError::Service(TagResourceError::InvalidRepositoryName(err.msg))
+                    return RusotoError::service(
+                        TagResourceError::InvalidRepositoryName(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidResourceArnException" => {
-                    return RusotoError::Service(TagResourceError::InvalidResourceArn(err.msg))
+                    return RusotoError::service(
+                        TagResourceError::InvalidResourceArn(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidSystemTagUsageException" => {
-                    return RusotoError::Service(TagResourceError::InvalidSystemTagUsage(err.msg))
+                    return RusotoError::service(
+                        TagResourceError::InvalidSystemTagUsage(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidTagsMapException" => {
-                    return RusotoError::Service(TagResourceError::InvalidTagsMap(err.msg))
+                    return RusotoError::service(TagResourceError::InvalidTagsMap(err.msg), &res)
                 }
                 "RepositoryDoesNotExistException" => {
-                    return RusotoError::Service(TagResourceError::RepositoryDoesNotExist(err.msg))
+                    return RusotoError::service(
+                        TagResourceError::RepositoryDoesNotExist(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceArnRequiredException" => {
-                    return RusotoError::Service(TagResourceError::ResourceArnRequired(err.msg))
+                    return RusotoError::service(
+                        TagResourceError::ResourceArnRequired(err.msg),
+                        &res,
+                    )
                 }
                 "TagPolicyException" => {
-                    return RusotoError::Service(TagResourceError::TagPolicy(err.msg))
+                    return RusotoError::service(TagResourceError::TagPolicy(err.msg), &res)
                 }
                 "TagsMapRequiredException" => {
-                    return RusotoError::Service(TagResourceError::TagsMapRequired(err.msg))
+                    return RusotoError::service(TagResourceError::TagsMapRequired(err.msg), &res)
                 }
                 "TooManyTagsException" => {
-                    return RusotoError::Service(TagResourceError::TooManyTags(err.msg))
+                    return RusotoError::service(TagResourceError::TooManyTags(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(TagResourceError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -12631,12 +14009,14 @@ impl fmt::Display for TagResourceError {
             TagResourceError::TagPolicy(ref cause) => write!(f, "{}", cause),
             TagResourceError::TagsMapRequired(ref cause) => write!(f, "{}", cause),
             TagResourceError::TooManyTags(ref cause) => write!(f, "{}", cause),
+            TagResourceError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for TagResourceError {}
 /// Errors returned by TestRepositoryTriggers
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum TestRepositoryTriggersError {
     /// <p>An encryption integrity check failed.</p>
     EncryptionIntegrityChecksFailed(String),
@@ -12680,6 +14060,8 @@ pub enum TestRepositoryTriggersError {
     RepositoryTriggerNameRequired(String),
     /// <p>The list of triggers for the repository is required, but was not specified.</p>
     RepositoryTriggersListRequired(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl TestRepositoryTriggersError {
@@ -12687,118 +14069,139 @@ impl TestRepositoryTriggersError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "EncryptionIntegrityChecksFailedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         TestRepositoryTriggersError::EncryptionIntegrityChecksFailed(err.msg),
+                        &res,
                     )
                 }
                 "EncryptionKeyAccessDeniedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         TestRepositoryTriggersError::EncryptionKeyAccessDenied(err.msg),
+                        &res,
                     )
                 }
                 "EncryptionKeyDisabledException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         TestRepositoryTriggersError::EncryptionKeyDisabled(err.msg),
+                        &res,
                     )
                 }
                 "EncryptionKeyNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         TestRepositoryTriggersError::EncryptionKeyNotFound(err.msg),
+                        &res,
                     )
                 }
                 "EncryptionKeyUnavailableException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         TestRepositoryTriggersError::EncryptionKeyUnavailable(err.msg),
+                        &res,
                     )
                 }
                 "InvalidRepositoryNameException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         TestRepositoryTriggersError::InvalidRepositoryName(err.msg),
+                        &res,
                     )
                 }
                 "InvalidRepositoryTriggerBranchNameException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         TestRepositoryTriggersError::InvalidRepositoryTriggerBranchName(err.msg),
+                        &res,
                     )
                 }
                 "InvalidRepositoryTriggerCustomDataException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         TestRepositoryTriggersError::InvalidRepositoryTriggerCustomData(err.msg),
+                        &res,
                     )
                 }
                 "InvalidRepositoryTriggerDestinationArnException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         TestRepositoryTriggersError::InvalidRepositoryTriggerDestinationArn(
                             err.msg,
                         ),
+                        &res,
                     )
                 }
                 "InvalidRepositoryTriggerEventsException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         TestRepositoryTriggersError::InvalidRepositoryTriggerEvents(err.msg),
+                        &res,
                     )
                 }
                 "InvalidRepositoryTriggerNameException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         TestRepositoryTriggersError::InvalidRepositoryTriggerName(err.msg),
+                        &res,
                     )
                 }
                 "InvalidRepositoryTriggerRegionException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         TestRepositoryTriggersError::InvalidRepositoryTriggerRegion(err.msg),
+                        &res,
                     )
                 }
                 "MaximumBranchesExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         TestRepositoryTriggersError::MaximumBranchesExceeded(err.msg),
+                        &res,
                     )
                 }
                 "MaximumRepositoryTriggersExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         TestRepositoryTriggersError::MaximumRepositoryTriggersExceeded(err.msg),
+                        &res,
                     )
                 }
                 "RepositoryDoesNotExistException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         TestRepositoryTriggersError::RepositoryDoesNotExist(err.msg),
+                        &res,
                     )
                 }
                 "RepositoryNameRequiredException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         TestRepositoryTriggersError::RepositoryNameRequired(err.msg),
+                        &res,
                     )
                 }
                 "RepositoryTriggerBranchNameListRequiredException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         TestRepositoryTriggersError::RepositoryTriggerBranchNameListRequired(
                             err.msg,
                         ),
+                        &res,
                     )
                 }
                 "RepositoryTriggerDestinationArnRequiredException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         TestRepositoryTriggersError::RepositoryTriggerDestinationArnRequired(
                             err.msg,
                         ),
+                        &res,
                     )
                 }
                 "RepositoryTriggerEventsListRequiredException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         TestRepositoryTriggersError::RepositoryTriggerEventsListRequired(err.msg),
+                        &res,
                     )
                 }
                 "RepositoryTriggerNameRequiredException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         TestRepositoryTriggersError::RepositoryTriggerNameRequired(err.msg),
+                        &res,
                     )
                 }
                 "RepositoryTriggersListRequiredException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         TestRepositoryTriggersError::RepositoryTriggersListRequired(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(TestRepositoryTriggersError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -12865,12 +14268,14 @@ impl fmt::Display for TestRepositoryTriggersError {
             TestRepositoryTriggersError::RepositoryTriggersListRequired(ref cause) => {
                 write!(f, "{}", cause)
             }
+            TestRepositoryTriggersError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for TestRepositoryTriggersError {}
 /// Errors returned by UntagResource
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UntagResourceError {
     /// <p><p>A specified repository name is not valid.</p> <note> <p>This exception occurs only when a specified repository name is not valid. Other exceptions occur when a required repository parameter is missing, or when a specified repository does not exist.</p> </note></p>
     InvalidRepositoryName(String),
@@ -12890,6 +14295,8 @@ pub enum UntagResourceError {
     TagPolicy(String),
     /// <p>The maximum number of tags for an AWS CodeCommit resource has been exceeded.</p>
     TooManyTags(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UntagResourceError {
@@ -12897,36 +14304,55 @@ impl UntagResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidRepositoryNameException" => {
-                    return RusotoError::Service(UntagResourceError::InvalidRepositoryName(err.msg))
+                    return RusotoError::service(
+                        UntagResourceError::InvalidRepositoryName(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidResourceArnException" => {
-                    return RusotoError::Service(UntagResourceError::InvalidResourceArn(err.msg))
+                    return RusotoError::service(
+                        UntagResourceError::InvalidResourceArn(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidSystemTagUsageException" => {
-                    return RusotoError::Service(UntagResourceError::InvalidSystemTagUsage(err.msg))
+                    return RusotoError::service(
+                        UntagResourceError::InvalidSystemTagUsage(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidTagKeysListException" => {
-                    return RusotoError::Service(UntagResourceError::InvalidTagKeysList(err.msg))
+                    return RusotoError::service(
+                        UntagResourceError::InvalidTagKeysList(err.msg),
+                        &res,
+                    )
                 }
                 "RepositoryDoesNotExistException" => {
-                    return RusotoError::Service(UntagResourceError::RepositoryDoesNotExist(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UntagResourceError::RepositoryDoesNotExist(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceArnRequiredException" => {
-                    return RusotoError::Service(UntagResourceError::ResourceArnRequired(err.msg))
+                    return RusotoError::service(
+                        UntagResourceError::ResourceArnRequired(err.msg),
+                        &res,
+                    )
                 }
                 "TagKeysListRequiredException" => {
-                    return RusotoError::Service(UntagResourceError::TagKeysListRequired(err.msg))
+                    return RusotoError::service(
+                        UntagResourceError::TagKeysListRequired(err.msg),
+                        &res,
+                    )
                 }
                 "TagPolicyException" => {
-                    return RusotoError::Service(UntagResourceError::TagPolicy(err.msg))
+                    return RusotoError::service(UntagResourceError::TagPolicy(err.msg), &res)
                 }
                 "TooManyTagsException" => {
-                    return RusotoError::Service(UntagResourceError::TooManyTags(err.msg))
+                    return RusotoError::service(UntagResourceError::TooManyTags(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UntagResourceError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -12945,12 +14371,14 @@ impl fmt::Display for UntagResourceError {
             UntagResourceError::TagKeysListRequired(ref cause) => write!(f, "{}", cause),
             UntagResourceError::TagPolicy(ref cause) => write!(f, "{}", cause),
             UntagResourceError::TooManyTags(ref cause) => write!(f, "{}", cause),
+            UntagResourceError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for UntagResourceError {}
 /// Errors returned by UpdateApprovalRuleTemplateContent
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdateApprovalRuleTemplateContentError {
     /// <p>The content for the approval rule template is empty. You must provide some content for an approval rule template. The content cannot be null.</p>
     ApprovalRuleTemplateContentRequired(String),
@@ -12964,6 +14392,8 @@ pub enum UpdateApprovalRuleTemplateContentError {
     InvalidApprovalRuleTemplateName(String),
     /// <p>The SHA-256 hash signature for the rule content is not valid.</p>
     InvalidRuleContentSha256(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdateApprovalRuleTemplateContentError {
@@ -12973,47 +14403,58 @@ impl UpdateApprovalRuleTemplateContentError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ApprovalRuleTemplateContentRequiredException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         UpdateApprovalRuleTemplateContentError::ApprovalRuleTemplateContentRequired(
                             err.msg,
                         ),
+                        &res,
                     )
                 }
                 "ApprovalRuleTemplateDoesNotExistException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         UpdateApprovalRuleTemplateContentError::ApprovalRuleTemplateDoesNotExist(
                             err.msg,
                         ),
+                        &res,
                     )
                 }
                 "ApprovalRuleTemplateNameRequiredException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         UpdateApprovalRuleTemplateContentError::ApprovalRuleTemplateNameRequired(
                             err.msg,
                         ),
+                        &res,
                     )
                 }
                 "InvalidApprovalRuleTemplateContentException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         UpdateApprovalRuleTemplateContentError::InvalidApprovalRuleTemplateContent(
                             err.msg,
                         ),
+                        &res,
                     )
                 }
                 "InvalidApprovalRuleTemplateNameException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         UpdateApprovalRuleTemplateContentError::InvalidApprovalRuleTemplateName(
                             err.msg,
                         ),
+                        &res,
                     )
                 }
                 "InvalidRuleContentSha256Exception" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         UpdateApprovalRuleTemplateContentError::InvalidRuleContentSha256(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        UpdateApprovalRuleTemplateContentError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -13041,12 +14482,16 @@ impl fmt::Display for UpdateApprovalRuleTemplateContentError {
             UpdateApprovalRuleTemplateContentError::InvalidRuleContentSha256(ref cause) => {
                 write!(f, "{}", cause)
             }
+            UpdateApprovalRuleTemplateContentError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for UpdateApprovalRuleTemplateContentError {}
 /// Errors returned by UpdateApprovalRuleTemplateDescription
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdateApprovalRuleTemplateDescriptionError {
     /// <p>The specified approval rule template does not exist. Verify that the name is correct and that you are signed in to the AWS Region where the template was created, and then try again.</p>
     ApprovalRuleTemplateDoesNotExist(String),
@@ -13056,6 +14501,8 @@ pub enum UpdateApprovalRuleTemplateDescriptionError {
     InvalidApprovalRuleTemplateDescription(String),
     /// <p>The name of the approval rule template is not valid. Template names must be between 1 and 100 valid characters in length. For more information about limits in AWS CodeCommit, see <a href="https://docs.aws.amazon.com/codecommit/latest/userguide/limits.html">AWS CodeCommit User Guide</a>.</p>
     InvalidApprovalRuleTemplateName(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdateApprovalRuleTemplateDescriptionError {
@@ -13064,12 +14511,12 @@ impl UpdateApprovalRuleTemplateDescriptionError {
     ) -> RusotoError<UpdateApprovalRuleTemplateDescriptionError> {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
-                                "ApprovalRuleTemplateDoesNotExistException" => return RusotoError::Service(UpdateApprovalRuleTemplateDescriptionError::ApprovalRuleTemplateDoesNotExist(err.msg)),
-"ApprovalRuleTemplateNameRequiredException" => return RusotoError::Service(UpdateApprovalRuleTemplateDescriptionError::ApprovalRuleTemplateNameRequired(err.msg)),
-"InvalidApprovalRuleTemplateDescriptionException" => return RusotoError::Service(UpdateApprovalRuleTemplateDescriptionError::InvalidApprovalRuleTemplateDescription(err.msg)),
-"InvalidApprovalRuleTemplateNameException" => return RusotoError::Service(UpdateApprovalRuleTemplateDescriptionError::InvalidApprovalRuleTemplateName(err.msg)),
+                                "ApprovalRuleTemplateDoesNotExistException" => return RusotoError::service(UpdateApprovalRuleTemplateDescriptionError::ApprovalRuleTemplateDoesNotExist(err.msg), &res),
+"ApprovalRuleTemplateNameRequiredException" => return RusotoError::service(UpdateApprovalRuleTemplateDescriptionError::ApprovalRuleTemplateNameRequired(err.msg), &res),
+"InvalidApprovalRuleTemplateDescriptionException" => return RusotoError::service(UpdateApprovalRuleTemplateDescriptionError::InvalidApprovalRuleTemplateDescription(err.msg), &res),
+"InvalidApprovalRuleTemplateNameException" => return RusotoError::service(UpdateApprovalRuleTemplateDescriptionError::InvalidApprovalRuleTemplateName(err.msg), &res),
 "ValidationException" => return RusotoError::Validation(err.msg),
-_ => {}
+_ => return RusotoError::unhandled(UpdateApprovalRuleTemplateDescriptionError::Unhandled, &res),
                             }
         }
         RusotoError::Unknown(res)
@@ -13091,12 +14538,16 @@ impl fmt::Display for UpdateApprovalRuleTemplateDescriptionError {
             UpdateApprovalRuleTemplateDescriptionError::InvalidApprovalRuleTemplateName(
                 ref cause,
             ) => write!(f, "{}", cause),
+            UpdateApprovalRuleTemplateDescriptionError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for UpdateApprovalRuleTemplateDescriptionError {}
 /// Errors returned by UpdateApprovalRuleTemplateName
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdateApprovalRuleTemplateNameError {
     /// <p>The specified approval rule template does not exist. Verify that the name is correct and that you are signed in to the AWS Region where the template was created, and then try again.</p>
     ApprovalRuleTemplateDoesNotExist(String),
@@ -13106,6 +14557,8 @@ pub enum UpdateApprovalRuleTemplateNameError {
     ApprovalRuleTemplateNameRequired(String),
     /// <p>The name of the approval rule template is not valid. Template names must be between 1 and 100 valid characters in length. For more information about limits in AWS CodeCommit, see <a href="https://docs.aws.amazon.com/codecommit/latest/userguide/limits.html">AWS CodeCommit User Guide</a>.</p>
     InvalidApprovalRuleTemplateName(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdateApprovalRuleTemplateNameError {
@@ -13115,35 +14568,44 @@ impl UpdateApprovalRuleTemplateNameError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ApprovalRuleTemplateDoesNotExistException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         UpdateApprovalRuleTemplateNameError::ApprovalRuleTemplateDoesNotExist(
                             err.msg,
                         ),
+                        &res,
                     )
                 }
                 "ApprovalRuleTemplateNameAlreadyExistsException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         UpdateApprovalRuleTemplateNameError::ApprovalRuleTemplateNameAlreadyExists(
                             err.msg,
                         ),
+                        &res,
                     )
                 }
                 "ApprovalRuleTemplateNameRequiredException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         UpdateApprovalRuleTemplateNameError::ApprovalRuleTemplateNameRequired(
                             err.msg,
                         ),
+                        &res,
                     )
                 }
                 "InvalidApprovalRuleTemplateNameException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         UpdateApprovalRuleTemplateNameError::InvalidApprovalRuleTemplateName(
                             err.msg,
                         ),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        UpdateApprovalRuleTemplateNameError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -13165,12 +14627,16 @@ impl fmt::Display for UpdateApprovalRuleTemplateNameError {
             UpdateApprovalRuleTemplateNameError::InvalidApprovalRuleTemplateName(ref cause) => {
                 write!(f, "{}", cause)
             }
+            UpdateApprovalRuleTemplateNameError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for UpdateApprovalRuleTemplateNameError {}
 /// Errors returned by UpdateComment
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdateCommentError {
     /// <p>The comment is empty. You must provide some content for a comment. The content cannot be null.</p>
     CommentContentRequired(String),
@@ -13186,6 +14652,8 @@ pub enum UpdateCommentError {
     CommentNotCreatedByCaller(String),
     /// <p>The comment ID is not in a valid format. Make sure that you have provided the full comment ID.</p>
     InvalidCommentId(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdateCommentError {
@@ -13193,34 +14661,46 @@ impl UpdateCommentError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "CommentContentRequiredException" => {
-                    return RusotoError::Service(UpdateCommentError::CommentContentRequired(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateCommentError::CommentContentRequired(err.msg),
+                        &res,
+                    )
                 }
                 "CommentContentSizeLimitExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         UpdateCommentError::CommentContentSizeLimitExceeded(err.msg),
+                        &res,
                     )
                 }
                 "CommentDeletedException" => {
-                    return RusotoError::Service(UpdateCommentError::CommentDeleted(err.msg))
+                    return RusotoError::service(UpdateCommentError::CommentDeleted(err.msg), &res)
                 }
                 "CommentDoesNotExistException" => {
-                    return RusotoError::Service(UpdateCommentError::CommentDoesNotExist(err.msg))
+                    return RusotoError::service(
+                        UpdateCommentError::CommentDoesNotExist(err.msg),
+                        &res,
+                    )
                 }
                 "CommentIdRequiredException" => {
-                    return RusotoError::Service(UpdateCommentError::CommentIdRequired(err.msg))
+                    return RusotoError::service(
+                        UpdateCommentError::CommentIdRequired(err.msg),
+                        &res,
+                    )
                 }
                 "CommentNotCreatedByCallerException" => {
-                    return RusotoError::Service(UpdateCommentError::CommentNotCreatedByCaller(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateCommentError::CommentNotCreatedByCaller(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidCommentIdException" => {
-                    return RusotoError::Service(UpdateCommentError::InvalidCommentId(err.msg))
+                    return RusotoError::service(
+                        UpdateCommentError::InvalidCommentId(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UpdateCommentError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -13239,12 +14719,14 @@ impl fmt::Display for UpdateCommentError {
             UpdateCommentError::CommentIdRequired(ref cause) => write!(f, "{}", cause),
             UpdateCommentError::CommentNotCreatedByCaller(ref cause) => write!(f, "{}", cause),
             UpdateCommentError::InvalidCommentId(ref cause) => write!(f, "{}", cause),
+            UpdateCommentError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for UpdateCommentError {}
 /// Errors returned by UpdateDefaultBranch
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdateDefaultBranchError {
     /// <p>The specified branch does not exist.</p>
     BranchDoesNotExist(String),
@@ -13268,6 +14750,8 @@ pub enum UpdateDefaultBranchError {
     RepositoryDoesNotExist(String),
     /// <p>A repository name is required, but was not specified.</p>
     RepositoryNameRequired(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdateDefaultBranchError {
@@ -13275,62 +14759,73 @@ impl UpdateDefaultBranchError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "BranchDoesNotExistException" => {
-                    return RusotoError::Service(UpdateDefaultBranchError::BranchDoesNotExist(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateDefaultBranchError::BranchDoesNotExist(err.msg),
+                        &res,
+                    )
                 }
                 "BranchNameRequiredException" => {
-                    return RusotoError::Service(UpdateDefaultBranchError::BranchNameRequired(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateDefaultBranchError::BranchNameRequired(err.msg),
+                        &res,
+                    )
                 }
                 "EncryptionIntegrityChecksFailedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         UpdateDefaultBranchError::EncryptionIntegrityChecksFailed(err.msg),
+                        &res,
                     )
                 }
                 "EncryptionKeyAccessDeniedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         UpdateDefaultBranchError::EncryptionKeyAccessDenied(err.msg),
+                        &res,
                     )
                 }
                 "EncryptionKeyDisabledException" => {
-                    return RusotoError::Service(UpdateDefaultBranchError::EncryptionKeyDisabled(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateDefaultBranchError::EncryptionKeyDisabled(err.msg),
+                        &res,
+                    )
                 }
                 "EncryptionKeyNotFoundException" => {
-                    return RusotoError::Service(UpdateDefaultBranchError::EncryptionKeyNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateDefaultBranchError::EncryptionKeyNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "EncryptionKeyUnavailableException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         UpdateDefaultBranchError::EncryptionKeyUnavailable(err.msg),
+                        &res,
                     )
                 }
                 "InvalidBranchNameException" => {
-                    return RusotoError::Service(UpdateDefaultBranchError::InvalidBranchName(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateDefaultBranchError::InvalidBranchName(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidRepositoryNameException" => {
-                    return RusotoError::Service(UpdateDefaultBranchError::InvalidRepositoryName(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateDefaultBranchError::InvalidRepositoryName(err.msg),
+                        &res,
+                    )
                 }
                 "RepositoryDoesNotExistException" => {
-                    return RusotoError::Service(UpdateDefaultBranchError::RepositoryDoesNotExist(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateDefaultBranchError::RepositoryDoesNotExist(err.msg),
+                        &res,
+                    )
                 }
                 "RepositoryNameRequiredException" => {
-                    return RusotoError::Service(UpdateDefaultBranchError::RepositoryNameRequired(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateDefaultBranchError::RepositoryNameRequired(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UpdateDefaultBranchError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -13355,12 +14850,14 @@ impl fmt::Display for UpdateDefaultBranchError {
             UpdateDefaultBranchError::InvalidRepositoryName(ref cause) => write!(f, "{}", cause),
             UpdateDefaultBranchError::RepositoryDoesNotExist(ref cause) => write!(f, "{}", cause),
             UpdateDefaultBranchError::RepositoryNameRequired(ref cause) => write!(f, "{}", cause),
+            UpdateDefaultBranchError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for UpdateDefaultBranchError {}
 /// Errors returned by UpdatePullRequestApprovalRuleContent
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdatePullRequestApprovalRuleContentError {
     /// <p>The content for the approval rule is empty. You must provide some content for an approval rule. The content cannot be null.</p>
     ApprovalRuleContentRequired(String),
@@ -13394,6 +14891,8 @@ pub enum UpdatePullRequestApprovalRuleContentError {
     PullRequestDoesNotExist(String),
     /// <p>A pull request ID is required, but none was provided.</p>
     PullRequestIdRequired(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdatePullRequestApprovalRuleContentError {
@@ -13403,105 +14902,126 @@ impl UpdatePullRequestApprovalRuleContentError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ApprovalRuleContentRequiredException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         UpdatePullRequestApprovalRuleContentError::ApprovalRuleContentRequired(
                             err.msg,
                         ),
+                        &res,
                     )
                 }
                 "ApprovalRuleDoesNotExistException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         UpdatePullRequestApprovalRuleContentError::ApprovalRuleDoesNotExist(
                             err.msg,
                         ),
+                        &res,
                     )
                 }
                 "ApprovalRuleNameRequiredException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         UpdatePullRequestApprovalRuleContentError::ApprovalRuleNameRequired(
                             err.msg,
                         ),
+                        &res,
                     )
                 }
-                "CannotModifyApprovalRuleFromTemplateException" => return RusotoError::Service(
+                "CannotModifyApprovalRuleFromTemplateException" => return RusotoError::service(
                     UpdatePullRequestApprovalRuleContentError::CannotModifyApprovalRuleFromTemplate(
                         err.msg,
                     ),
+                    &res,
                 ),
                 "EncryptionIntegrityChecksFailedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         UpdatePullRequestApprovalRuleContentError::EncryptionIntegrityChecksFailed(
                             err.msg,
                         ),
+                        &res,
                     )
                 }
                 "EncryptionKeyAccessDeniedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         UpdatePullRequestApprovalRuleContentError::EncryptionKeyAccessDenied(
                             err.msg,
                         ),
+                        &res,
                     )
                 }
                 "EncryptionKeyDisabledException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         UpdatePullRequestApprovalRuleContentError::EncryptionKeyDisabled(err.msg),
+                        &res,
                     )
                 }
                 "EncryptionKeyNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         UpdatePullRequestApprovalRuleContentError::EncryptionKeyNotFound(err.msg),
+                        &res,
                     )
                 }
                 "EncryptionKeyUnavailableException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         UpdatePullRequestApprovalRuleContentError::EncryptionKeyUnavailable(
                             err.msg,
                         ),
+                        &res,
                     )
                 }
                 "InvalidApprovalRuleContentException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         UpdatePullRequestApprovalRuleContentError::InvalidApprovalRuleContent(
                             err.msg,
                         ),
+                        &res,
                     )
                 }
                 "InvalidApprovalRuleNameException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         UpdatePullRequestApprovalRuleContentError::InvalidApprovalRuleName(err.msg),
+                        &res,
                     )
                 }
                 "InvalidPullRequestIdException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         UpdatePullRequestApprovalRuleContentError::InvalidPullRequestId(err.msg),
+                        &res,
                     )
                 }
                 "InvalidRuleContentSha256Exception" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         UpdatePullRequestApprovalRuleContentError::InvalidRuleContentSha256(
                             err.msg,
                         ),
+                        &res,
                     )
                 }
                 "PullRequestAlreadyClosedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         UpdatePullRequestApprovalRuleContentError::PullRequestAlreadyClosed(
                             err.msg,
                         ),
+                        &res,
                     )
                 }
                 "PullRequestDoesNotExistException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         UpdatePullRequestApprovalRuleContentError::PullRequestDoesNotExist(err.msg),
+                        &res,
                     )
                 }
                 "PullRequestIdRequiredException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         UpdatePullRequestApprovalRuleContentError::PullRequestIdRequired(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        UpdatePullRequestApprovalRuleContentError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -13559,12 +15079,16 @@ impl fmt::Display for UpdatePullRequestApprovalRuleContentError {
             UpdatePullRequestApprovalRuleContentError::PullRequestIdRequired(ref cause) => {
                 write!(f, "{}", cause)
             }
+            UpdatePullRequestApprovalRuleContentError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for UpdatePullRequestApprovalRuleContentError {}
 /// Errors returned by UpdatePullRequestApprovalState
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdatePullRequestApprovalStateError {
     /// <p>An approval state is required, but was not specified.</p>
     ApprovalStateRequired(String),
@@ -13598,6 +15122,8 @@ pub enum UpdatePullRequestApprovalStateError {
     RevisionIdRequired(String),
     /// <p>The revision ID provided in the request does not match the current revision ID. Use GetPullRequest to retrieve the current revision ID.</p>
     RevisionNotCurrent(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdatePullRequestApprovalStateError {
@@ -13607,93 +15133,114 @@ impl UpdatePullRequestApprovalStateError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ApprovalStateRequiredException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         UpdatePullRequestApprovalStateError::ApprovalStateRequired(err.msg),
+                        &res,
                     )
                 }
                 "EncryptionIntegrityChecksFailedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         UpdatePullRequestApprovalStateError::EncryptionIntegrityChecksFailed(
                             err.msg,
                         ),
+                        &res,
                     )
                 }
                 "EncryptionKeyAccessDeniedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         UpdatePullRequestApprovalStateError::EncryptionKeyAccessDenied(err.msg),
+                        &res,
                     )
                 }
                 "EncryptionKeyDisabledException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         UpdatePullRequestApprovalStateError::EncryptionKeyDisabled(err.msg),
+                        &res,
                     )
                 }
                 "EncryptionKeyNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         UpdatePullRequestApprovalStateError::EncryptionKeyNotFound(err.msg),
+                        &res,
                     )
                 }
                 "EncryptionKeyUnavailableException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         UpdatePullRequestApprovalStateError::EncryptionKeyUnavailable(err.msg),
+                        &res,
                     )
                 }
                 "InvalidApprovalStateException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         UpdatePullRequestApprovalStateError::InvalidApprovalState(err.msg),
+                        &res,
                     )
                 }
                 "InvalidPullRequestIdException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         UpdatePullRequestApprovalStateError::InvalidPullRequestId(err.msg),
+                        &res,
                     )
                 }
                 "InvalidRevisionIdException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         UpdatePullRequestApprovalStateError::InvalidRevisionId(err.msg),
+                        &res,
                     )
                 }
                 "MaximumNumberOfApprovalsExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         UpdatePullRequestApprovalStateError::MaximumNumberOfApprovalsExceeded(
                             err.msg,
                         ),
+                        &res,
                     )
                 }
                 "PullRequestAlreadyClosedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         UpdatePullRequestApprovalStateError::PullRequestAlreadyClosed(err.msg),
+                        &res,
                     )
                 }
                 "PullRequestCannotBeApprovedByAuthorException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         UpdatePullRequestApprovalStateError::PullRequestCannotBeApprovedByAuthor(
                             err.msg,
                         ),
+                        &res,
                     )
                 }
                 "PullRequestDoesNotExistException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         UpdatePullRequestApprovalStateError::PullRequestDoesNotExist(err.msg),
+                        &res,
                     )
                 }
                 "PullRequestIdRequiredException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         UpdatePullRequestApprovalStateError::PullRequestIdRequired(err.msg),
+                        &res,
                     )
                 }
                 "RevisionIdRequiredException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         UpdatePullRequestApprovalStateError::RevisionIdRequired(err.msg),
+                        &res,
                     )
                 }
                 "RevisionNotCurrentException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         UpdatePullRequestApprovalStateError::RevisionNotCurrent(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        UpdatePullRequestApprovalStateError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -13751,12 +15298,16 @@ impl fmt::Display for UpdatePullRequestApprovalStateError {
             UpdatePullRequestApprovalStateError::RevisionNotCurrent(ref cause) => {
                 write!(f, "{}", cause)
             }
+            UpdatePullRequestApprovalStateError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for UpdatePullRequestApprovalStateError {}
 /// Errors returned by UpdatePullRequestDescription
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdatePullRequestDescriptionError {
     /// <p>The pull request description is not valid. Descriptions cannot be more than 1,000 characters.</p>
     InvalidDescription(String),
@@ -13768,6 +15319,8 @@ pub enum UpdatePullRequestDescriptionError {
     PullRequestDoesNotExist(String),
     /// <p>A pull request ID is required, but none was provided.</p>
     PullRequestIdRequired(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdatePullRequestDescriptionError {
@@ -13777,32 +15330,42 @@ impl UpdatePullRequestDescriptionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidDescriptionException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         UpdatePullRequestDescriptionError::InvalidDescription(err.msg),
+                        &res,
                     )
                 }
                 "InvalidPullRequestIdException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         UpdatePullRequestDescriptionError::InvalidPullRequestId(err.msg),
+                        &res,
                     )
                 }
                 "PullRequestAlreadyClosedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         UpdatePullRequestDescriptionError::PullRequestAlreadyClosed(err.msg),
+                        &res,
                     )
                 }
                 "PullRequestDoesNotExistException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         UpdatePullRequestDescriptionError::PullRequestDoesNotExist(err.msg),
+                        &res,
                     )
                 }
                 "PullRequestIdRequiredException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         UpdatePullRequestDescriptionError::PullRequestIdRequired(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        UpdatePullRequestDescriptionError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -13827,12 +15390,14 @@ impl fmt::Display for UpdatePullRequestDescriptionError {
             UpdatePullRequestDescriptionError::PullRequestIdRequired(ref cause) => {
                 write!(f, "{}", cause)
             }
+            UpdatePullRequestDescriptionError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for UpdatePullRequestDescriptionError {}
 /// Errors returned by UpdatePullRequestStatus
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdatePullRequestStatusError {
     /// <p>An encryption integrity check failed.</p>
     EncryptionIntegrityChecksFailed(String),
@@ -13856,6 +15421,8 @@ pub enum UpdatePullRequestStatusError {
     PullRequestIdRequired(String),
     /// <p>A pull request status is required, but none was provided.</p>
     PullRequestStatusRequired(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdatePullRequestStatusError {
@@ -13863,62 +15430,73 @@ impl UpdatePullRequestStatusError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "EncryptionIntegrityChecksFailedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         UpdatePullRequestStatusError::EncryptionIntegrityChecksFailed(err.msg),
+                        &res,
                     )
                 }
                 "EncryptionKeyAccessDeniedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         UpdatePullRequestStatusError::EncryptionKeyAccessDenied(err.msg),
+                        &res,
                     )
                 }
                 "EncryptionKeyDisabledException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         UpdatePullRequestStatusError::EncryptionKeyDisabled(err.msg),
+                        &res,
                     )
                 }
                 "EncryptionKeyNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         UpdatePullRequestStatusError::EncryptionKeyNotFound(err.msg),
+                        &res,
                     )
                 }
                 "EncryptionKeyUnavailableException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         UpdatePullRequestStatusError::EncryptionKeyUnavailable(err.msg),
+                        &res,
                     )
                 }
                 "InvalidPullRequestIdException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         UpdatePullRequestStatusError::InvalidPullRequestId(err.msg),
+                        &res,
                     )
                 }
                 "InvalidPullRequestStatusException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         UpdatePullRequestStatusError::InvalidPullRequestStatus(err.msg),
+                        &res,
                     )
                 }
                 "InvalidPullRequestStatusUpdateException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         UpdatePullRequestStatusError::InvalidPullRequestStatusUpdate(err.msg),
+                        &res,
                     )
                 }
                 "PullRequestDoesNotExistException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         UpdatePullRequestStatusError::PullRequestDoesNotExist(err.msg),
+                        &res,
                     )
                 }
                 "PullRequestIdRequiredException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         UpdatePullRequestStatusError::PullRequestIdRequired(err.msg),
+                        &res,
                     )
                 }
                 "PullRequestStatusRequiredException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         UpdatePullRequestStatusError::PullRequestStatusRequired(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UpdatePullRequestStatusError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -13959,12 +15537,14 @@ impl fmt::Display for UpdatePullRequestStatusError {
             UpdatePullRequestStatusError::PullRequestStatusRequired(ref cause) => {
                 write!(f, "{}", cause)
             }
+            UpdatePullRequestStatusError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for UpdatePullRequestStatusError {}
 /// Errors returned by UpdatePullRequestTitle
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdatePullRequestTitleError {
     /// <p>The pull request ID is not valid. Make sure that you have provided the full ID and that the pull request is in the specified repository, and then try again.</p>
     InvalidPullRequestId(String),
@@ -13978,6 +15558,8 @@ pub enum UpdatePullRequestTitleError {
     PullRequestIdRequired(String),
     /// <p>A pull request title is required. It cannot be empty or null.</p>
     TitleRequired(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdatePullRequestTitleError {
@@ -13985,35 +15567,43 @@ impl UpdatePullRequestTitleError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidPullRequestIdException" => {
-                    return RusotoError::Service(UpdatePullRequestTitleError::InvalidPullRequestId(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdatePullRequestTitleError::InvalidPullRequestId(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidTitleException" => {
-                    return RusotoError::Service(UpdatePullRequestTitleError::InvalidTitle(err.msg))
+                    return RusotoError::service(
+                        UpdatePullRequestTitleError::InvalidTitle(err.msg),
+                        &res,
+                    )
                 }
                 "PullRequestAlreadyClosedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         UpdatePullRequestTitleError::PullRequestAlreadyClosed(err.msg),
+                        &res,
                     )
                 }
                 "PullRequestDoesNotExistException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         UpdatePullRequestTitleError::PullRequestDoesNotExist(err.msg),
+                        &res,
                     )
                 }
                 "PullRequestIdRequiredException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         UpdatePullRequestTitleError::PullRequestIdRequired(err.msg),
+                        &res,
                     )
                 }
                 "TitleRequiredException" => {
-                    return RusotoError::Service(UpdatePullRequestTitleError::TitleRequired(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdatePullRequestTitleError::TitleRequired(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UpdatePullRequestTitleError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -14033,12 +15623,14 @@ impl fmt::Display for UpdatePullRequestTitleError {
             }
             UpdatePullRequestTitleError::PullRequestIdRequired(ref cause) => write!(f, "{}", cause),
             UpdatePullRequestTitleError::TitleRequired(ref cause) => write!(f, "{}", cause),
+            UpdatePullRequestTitleError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for UpdatePullRequestTitleError {}
 /// Errors returned by UpdateRepositoryDescription
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdateRepositoryDescriptionError {
     /// <p>An encryption integrity check failed.</p>
     EncryptionIntegrityChecksFailed(String),
@@ -14058,6 +15650,8 @@ pub enum UpdateRepositoryDescriptionError {
     RepositoryDoesNotExist(String),
     /// <p>A repository name is required, but was not specified.</p>
     RepositoryNameRequired(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdateRepositoryDescriptionError {
@@ -14067,52 +15661,66 @@ impl UpdateRepositoryDescriptionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "EncryptionIntegrityChecksFailedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         UpdateRepositoryDescriptionError::EncryptionIntegrityChecksFailed(err.msg),
+                        &res,
                     )
                 }
                 "EncryptionKeyAccessDeniedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         UpdateRepositoryDescriptionError::EncryptionKeyAccessDenied(err.msg),
+                        &res,
                     )
                 }
                 "EncryptionKeyDisabledException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         UpdateRepositoryDescriptionError::EncryptionKeyDisabled(err.msg),
+                        &res,
                     )
                 }
                 "EncryptionKeyNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         UpdateRepositoryDescriptionError::EncryptionKeyNotFound(err.msg),
+                        &res,
                     )
                 }
                 "EncryptionKeyUnavailableException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         UpdateRepositoryDescriptionError::EncryptionKeyUnavailable(err.msg),
+                        &res,
                     )
                 }
                 "InvalidRepositoryDescriptionException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         UpdateRepositoryDescriptionError::InvalidRepositoryDescription(err.msg),
+                        &res,
                     )
                 }
                 "InvalidRepositoryNameException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         UpdateRepositoryDescriptionError::InvalidRepositoryName(err.msg),
+                        &res,
                     )
                 }
                 "RepositoryDoesNotExistException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         UpdateRepositoryDescriptionError::RepositoryDoesNotExist(err.msg),
+                        &res,
                     )
                 }
                 "RepositoryNameRequiredException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         UpdateRepositoryDescriptionError::RepositoryNameRequired(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        UpdateRepositoryDescriptionError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -14149,12 +15757,14 @@ impl fmt::Display for UpdateRepositoryDescriptionError {
             UpdateRepositoryDescriptionError::RepositoryNameRequired(ref cause) => {
                 write!(f, "{}", cause)
             }
+            UpdateRepositoryDescriptionError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for UpdateRepositoryDescriptionError {}
 /// Errors returned by UpdateRepositoryName
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdateRepositoryNameError {
     /// <p><p>A specified repository name is not valid.</p> <note> <p>This exception occurs only when a specified repository name is not valid. Other exceptions occur when a required repository parameter is missing, or when a specified repository does not exist.</p> </note></p>
     InvalidRepositoryName(String),
@@ -14164,6 +15774,8 @@ pub enum UpdateRepositoryNameError {
     RepositoryNameExists(String),
     /// <p>A repository name is required, but was not specified.</p>
     RepositoryNameRequired(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdateRepositoryNameError {
@@ -14171,27 +15783,31 @@ impl UpdateRepositoryNameError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidRepositoryNameException" => {
-                    return RusotoError::Service(UpdateRepositoryNameError::InvalidRepositoryName(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateRepositoryNameError::InvalidRepositoryName(err.msg),
+                        &res,
+                    )
                 }
                 "RepositoryDoesNotExistException" => {
-                    return RusotoError::Service(UpdateRepositoryNameError::RepositoryDoesNotExist(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateRepositoryNameError::RepositoryDoesNotExist(err.msg),
+                        &res,
+                    )
                 }
                 "RepositoryNameExistsException" => {
-                    return RusotoError::Service(UpdateRepositoryNameError::RepositoryNameExists(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateRepositoryNameError::RepositoryNameExists(err.msg),
+                        &res,
+                    )
                 }
                 "RepositoryNameRequiredException" => {
-                    return RusotoError::Service(UpdateRepositoryNameError::RepositoryNameRequired(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateRepositoryNameError::RepositoryNameRequired(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UpdateRepositoryNameError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -14205,6 +15821,7 @@ impl fmt::Display for UpdateRepositoryNameError {
             UpdateRepositoryNameError::RepositoryDoesNotExist(ref cause) => write!(f, "{}", cause),
             UpdateRepositoryNameError::RepositoryNameExists(ref cause) => write!(f, "{}", cause),
             UpdateRepositoryNameError::RepositoryNameRequired(ref cause) => write!(f, "{}", cause),
+            UpdateRepositoryNameError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
@@ -14700,6 +16317,7 @@ impl CodeCommitClient {
     /// Creates a client backed by the default tokio event loop.
     ///
     /// The client will use the default credentials provider and tls client.
+    #[cfg(not(target_arch = "wasm32"))]
     pub fn new(region: region::Region) -> CodeCommitClient {
         CodeCommitClient {
             client: Client::shared(),
@@ -14725,16 +16343,66 @@ impl CodeCommitClient {
     pub fn new_with_client(client: Client, region: region::Region) -> CodeCommitClient {
         CodeCommitClient { client, region }
     }
+
+    /// Returns a builder of a client, setting any of its region, credentials provider,
+    /// dispatcher, retry policy, endpoint and timeouts.
+    pub fn builder() -> rusoto_core::ClientBuilder<CodeCommitClient> {
+        rusoto_core::ClientBuilder::new(CodeCommitClient::new_with_client)
+    }
+
+    /// Returns a clone of this client with the overrides of `config` applied to its
+    /// operations, sharing its connections.
+    pub fn customize(&self, config: &rusoto_core::OperationConfig) -> CodeCommitClient {
+        CodeCommitClient {
+            client: self.client.customize(config),
+            region: config.resolve_region(&self.region),
+        }
+    }
 }
 
-#[async_trait]
-impl CodeCommit for CodeCommitClient {
-    /// <p>Creates an association between an approval rule template and a specified repository. Then, the next time a pull request is created in the repository where the destination reference (if specified) matches the destination reference (branch) for the pull request, an approval rule that matches the template conditions is automatically created for that pull request. If no destination references are specified in the template, an approval rule that matches the template contents is created for all pull requests in that repository.</p>
-    async fn associate_approval_rule_template_with_repository(
+impl CodeCommitClient {
+    /// Calls `associate_approval_rule_template_with_repository`, also returning the metadata of the response.
+    pub async fn associate_approval_rule_template_with_repository_with_metadata(
         &self,
         input: AssociateApprovalRuleTemplateWithRepositoryInput,
-    ) -> Result<(), RusotoError<AssociateApprovalRuleTemplateWithRepositoryError>> {
+    ) -> Result<((), ResponseMetadata), RusotoError<AssociateApprovalRuleTemplateWithRepositoryError>>
+    {
+        let request = self.build_associate_approval_rule_template_with_repository_request(input);
+
+        let mut response = self
+            .client
+            .sign_and_dispatch(request)
+            .await
+            .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
+        if response.status.is_success() {
+            std::mem::drop(response);
+            Ok(((), metadata))
+        } else {
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(AssociateApprovalRuleTemplateWithRepositoryError::from_response(response))
+        }
+    }
+
+    /// Presigns the request `associate_approval_rule_template_with_repository` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_associate_approval_rule_template_with_repository(
+        &self,
+        presigner: &rusoto_core::Presigner,
+        input: AssociateApprovalRuleTemplateWithRepositoryInput,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_associate_approval_rule_template_with_repository_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_associate_approval_rule_template_with_repository_request(
+        &self,
+        input: AssociateApprovalRuleTemplateWithRepositoryInput,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "codecommit", &self.region, "/");
+        request.set_operation("AssociateApprovalRuleTemplateWithRepository");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -14744,30 +16412,64 @@ impl CodeCommit for CodeCommitClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `batch_associate_approval_rule_template_with_repositories`, also returning the metadata of the response.
+    pub async fn batch_associate_approval_rule_template_with_repositories_with_metadata(
+        &self,
+        input: BatchAssociateApprovalRuleTemplateWithRepositoriesInput,
+    ) -> Result<
+        (
+            BatchAssociateApprovalRuleTemplateWithRepositoriesOutput,
+            ResponseMetadata,
+        ),
+        RusotoError<BatchAssociateApprovalRuleTemplateWithRepositoriesError>,
+    > {
+        let request =
+            self.build_batch_associate_approval_rule_template_with_repositories_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            std::mem::drop(response);
-            Ok(())
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<BatchAssociateApprovalRuleTemplateWithRepositoriesOutput, _>(
+            )?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(AssociateApprovalRuleTemplateWithRepositoryError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(BatchAssociateApprovalRuleTemplateWithRepositoriesError::from_response(response))
         }
     }
 
-    /// <p>Creates an association between an approval rule template and one or more specified repositories. </p>
-    async fn batch_associate_approval_rule_template_with_repositories(
+    /// Presigns the request `batch_associate_approval_rule_template_with_repositories` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_batch_associate_approval_rule_template_with_repositories(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: BatchAssociateApprovalRuleTemplateWithRepositoriesInput,
-    ) -> Result<
-        BatchAssociateApprovalRuleTemplateWithRepositoriesOutput,
-        RusotoError<BatchAssociateApprovalRuleTemplateWithRepositoriesError>,
-    > {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request =
+            self.build_batch_associate_approval_rule_template_with_repositories_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_batch_associate_approval_rule_template_with_repositories_request(
+        &self,
+        input: BatchAssociateApprovalRuleTemplateWithRepositoriesInput,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "codecommit", &self.region, "/");
+        request.set_operation("BatchAssociateApprovalRuleTemplateWithRepositories");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -14777,29 +16479,58 @@ impl CodeCommit for CodeCommitClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `batch_describe_merge_conflicts`, also returning the metadata of the response.
+    pub async fn batch_describe_merge_conflicts_with_metadata(
+        &self,
+        input: BatchDescribeMergeConflictsInput,
+    ) -> Result<
+        (BatchDescribeMergeConflictsOutput, ResponseMetadata),
+        RusotoError<BatchDescribeMergeConflictsError>,
+    > {
+        let request = self.build_batch_describe_merge_conflicts_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<BatchAssociateApprovalRuleTemplateWithRepositoriesOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<BatchDescribeMergeConflictsOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(BatchAssociateApprovalRuleTemplateWithRepositoriesError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(BatchDescribeMergeConflictsError::from_response(response))
         }
     }
 
-    /// <p>Returns information about one or more merge conflicts in the attempted merge of two commit specifiers using the squash or three-way merge strategy.</p>
-    async fn batch_describe_merge_conflicts(
+    /// Presigns the request `batch_describe_merge_conflicts` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_batch_describe_merge_conflicts(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: BatchDescribeMergeConflictsInput,
-    ) -> Result<BatchDescribeMergeConflictsOutput, RusotoError<BatchDescribeMergeConflictsError>>
-    {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_batch_describe_merge_conflicts_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_batch_describe_merge_conflicts_request(
+        &self,
+        input: BatchDescribeMergeConflictsInput,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "codecommit", &self.region, "/");
+        request.set_operation("BatchDescribeMergeConflicts");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -14809,31 +16540,64 @@ impl CodeCommit for CodeCommitClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `batch_disassociate_approval_rule_template_from_repositories`, also returning the metadata of the response.
+    pub async fn batch_disassociate_approval_rule_template_from_repositories_with_metadata(
+        &self,
+        input: BatchDisassociateApprovalRuleTemplateFromRepositoriesInput,
+    ) -> Result<
+        (
+            BatchDisassociateApprovalRuleTemplateFromRepositoriesOutput,
+            ResponseMetadata,
+        ),
+        RusotoError<BatchDisassociateApprovalRuleTemplateFromRepositoriesError>,
+    > {
+        let request =
+            self.build_batch_disassociate_approval_rule_template_from_repositories_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<BatchDescribeMergeConflictsOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<BatchDisassociateApprovalRuleTemplateFromRepositoriesOutput, _>(
+            )?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(BatchDescribeMergeConflictsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(BatchDisassociateApprovalRuleTemplateFromRepositoriesError::from_response(response))
         }
     }
 
-    /// <p>Removes the association between an approval rule template and one or more specified repositories. </p>
-    async fn batch_disassociate_approval_rule_template_from_repositories(
+    /// Presigns the request `batch_disassociate_approval_rule_template_from_repositories` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_batch_disassociate_approval_rule_template_from_repositories(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: BatchDisassociateApprovalRuleTemplateFromRepositoriesInput,
-    ) -> Result<
-        BatchDisassociateApprovalRuleTemplateFromRepositoriesOutput,
-        RusotoError<BatchDisassociateApprovalRuleTemplateFromRepositoriesError>,
-    > {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request =
+            self.build_batch_disassociate_approval_rule_template_from_repositories_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_batch_disassociate_approval_rule_template_from_repositories_request(
+        &self,
+        input: BatchDisassociateApprovalRuleTemplateFromRepositoriesInput,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "codecommit", &self.region, "/");
+        request.set_operation("BatchDisassociateApprovalRuleTemplateFromRepositories");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -14843,84 +16607,168 @@ impl CodeCommit for CodeCommitClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `batch_get_commits`, also returning the metadata of the response.
+    pub async fn batch_get_commits_with_metadata(
+        &self,
+        input: BatchGetCommitsInput,
+    ) -> Result<(BatchGetCommitsOutput, ResponseMetadata), RusotoError<BatchGetCommitsError>> {
+        let request = self.build_batch_get_commits_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<BatchDisassociateApprovalRuleTemplateFromRepositoriesOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<BatchGetCommitsOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(BatchDisassociateApprovalRuleTemplateFromRepositoriesError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(BatchGetCommitsError::from_response(response))
         }
     }
 
-    /// <p>Returns information about the contents of one or more commits in a repository.</p>
-    async fn batch_get_commits(
+    /// Presigns the request `batch_get_commits` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_batch_get_commits(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: BatchGetCommitsInput,
-    ) -> Result<BatchGetCommitsOutput, RusotoError<BatchGetCommitsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_batch_get_commits_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_batch_get_commits_request(&self, input: BatchGetCommitsInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "codecommit", &self.region, "/");
+        request.set_operation("BatchGetCommits");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "CodeCommit_20150413.BatchGetCommits");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `batch_get_repositories`, also returning the metadata of the response.
+    pub async fn batch_get_repositories_with_metadata(
+        &self,
+        input: BatchGetRepositoriesInput,
+    ) -> Result<
+        (BatchGetRepositoriesOutput, ResponseMetadata),
+        RusotoError<BatchGetRepositoriesError>,
+    > {
+        let request = self.build_batch_get_repositories_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<BatchGetCommitsOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<BatchGetRepositoriesOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(BatchGetCommitsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(BatchGetRepositoriesError::from_response(response))
         }
     }
 
-    /// <p><p>Returns information about one or more repositories.</p> <note> <p>The description field for a repository accepts all HTML characters and all valid Unicode characters. Applications that do not HTML-encode the description and display it in a webpage can expose users to potentially malicious code. Make sure that you HTML-encode the description field in any application that uses this API to display the repository description on a webpage.</p> </note></p>
-    async fn batch_get_repositories(
+    /// Presigns the request `batch_get_repositories` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_batch_get_repositories(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: BatchGetRepositoriesInput,
-    ) -> Result<BatchGetRepositoriesOutput, RusotoError<BatchGetRepositoriesError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_batch_get_repositories_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_batch_get_repositories_request(
+        &self,
+        input: BatchGetRepositoriesInput,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "codecommit", &self.region, "/");
+        request.set_operation("BatchGetRepositories");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "CodeCommit_20150413.BatchGetRepositories");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `create_approval_rule_template`, also returning the metadata of the response.
+    pub async fn create_approval_rule_template_with_metadata(
+        &self,
+        input: CreateApprovalRuleTemplateInput,
+    ) -> Result<
+        (CreateApprovalRuleTemplateOutput, ResponseMetadata),
+        RusotoError<CreateApprovalRuleTemplateError>,
+    > {
+        let request = self.build_create_approval_rule_template_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<BatchGetRepositoriesOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<CreateApprovalRuleTemplateOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(BatchGetRepositoriesError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(CreateApprovalRuleTemplateError::from_response(response))
         }
     }
 
-    /// <p>Creates a template for approval rules that can then be associated with one or more repositories in your AWS account. When you associate a template with a repository, AWS CodeCommit creates an approval rule that matches the conditions of the template for all pull requests that meet the conditions of the template. For more information, see <a>AssociateApprovalRuleTemplateWithRepository</a>.</p>
-    async fn create_approval_rule_template(
+    /// Presigns the request `create_approval_rule_template` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_approval_rule_template(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: CreateApprovalRuleTemplateInput,
-    ) -> Result<CreateApprovalRuleTemplateOutput, RusotoError<CreateApprovalRuleTemplateError>>
-    {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_approval_rule_template_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_approval_rule_template_request(
+        &self,
+        input: CreateApprovalRuleTemplateInput,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "codecommit", &self.region, "/");
+        request.set_operation("CreateApprovalRuleTemplate");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -14930,110 +16778,210 @@ impl CodeCommit for CodeCommitClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `create_branch`, also returning the metadata of the response.
+    pub async fn create_branch_with_metadata(
+        &self,
+        input: CreateBranchInput,
+    ) -> Result<((), ResponseMetadata), RusotoError<CreateBranchError>> {
+        let request = self.build_create_branch_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<CreateApprovalRuleTemplateOutput, _>()
+            std::mem::drop(response);
+            Ok(((), metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(CreateApprovalRuleTemplateError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(CreateBranchError::from_response(response))
         }
     }
 
-    /// <p><p>Creates a branch in a repository and points the branch to a commit.</p> <note> <p>Calling the create branch operation does not set a repository&#39;s default branch. To do this, call the update default branch operation.</p> </note></p>
-    async fn create_branch(
+    /// Presigns the request `create_branch` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_branch(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: CreateBranchInput,
-    ) -> Result<(), RusotoError<CreateBranchError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_branch_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_branch_request(&self, input: CreateBranchInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "codecommit", &self.region, "/");
+        request.set_operation("CreateBranch");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "CodeCommit_20150413.CreateBranch");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `create_commit`, also returning the metadata of the response.
+    pub async fn create_commit_with_metadata(
+        &self,
+        input: CreateCommitInput,
+    ) -> Result<(CreateCommitOutput, ResponseMetadata), RusotoError<CreateCommitError>> {
+        let request = self.build_create_commit_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            std::mem::drop(response);
-            Ok(())
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<CreateCommitOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(CreateBranchError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(CreateCommitError::from_response(response))
         }
     }
 
-    /// <p>Creates a commit for a repository on the tip of a specified branch.</p>
-    async fn create_commit(
+    /// Presigns the request `create_commit` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_commit(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: CreateCommitInput,
-    ) -> Result<CreateCommitOutput, RusotoError<CreateCommitError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_commit_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_commit_request(&self, input: CreateCommitInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "codecommit", &self.region, "/");
+        request.set_operation("CreateCommit");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "CodeCommit_20150413.CreateCommit");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `create_pull_request`, also returning the metadata of the response.
+    pub async fn create_pull_request_with_metadata(
+        &self,
+        input: CreatePullRequestInput,
+    ) -> Result<(CreatePullRequestOutput, ResponseMetadata), RusotoError<CreatePullRequestError>>
+    {
+        let request = self.build_create_pull_request_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<CreateCommitOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<CreatePullRequestOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(CreateCommitError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(CreatePullRequestError::from_response(response))
         }
     }
 
-    /// <p>Creates a pull request in the specified repository.</p>
-    async fn create_pull_request(
+    /// Presigns the request `create_pull_request` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_pull_request(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: CreatePullRequestInput,
-    ) -> Result<CreatePullRequestOutput, RusotoError<CreatePullRequestError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_pull_request_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_pull_request_request(&self, input: CreatePullRequestInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "codecommit", &self.region, "/");
+        request.set_operation("CreatePullRequest");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "CodeCommit_20150413.CreatePullRequest");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `create_pull_request_approval_rule`, also returning the metadata of the response.
+    pub async fn create_pull_request_approval_rule_with_metadata(
+        &self,
+        input: CreatePullRequestApprovalRuleInput,
+    ) -> Result<
+        (CreatePullRequestApprovalRuleOutput, ResponseMetadata),
+        RusotoError<CreatePullRequestApprovalRuleError>,
+    > {
+        let request = self.build_create_pull_request_approval_rule_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<CreatePullRequestOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<CreatePullRequestApprovalRuleOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(CreatePullRequestError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(CreatePullRequestApprovalRuleError::from_response(response))
         }
     }
 
-    /// <p>Creates an approval rule for a pull request.</p>
-    async fn create_pull_request_approval_rule(
+    /// Presigns the request `create_pull_request_approval_rule` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_pull_request_approval_rule(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: CreatePullRequestApprovalRuleInput,
-    ) -> Result<CreatePullRequestApprovalRuleOutput, RusotoError<CreatePullRequestApprovalRuleError>>
-    {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_pull_request_approval_rule_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_pull_request_approval_rule_request(
+        &self,
+        input: CreatePullRequestApprovalRuleInput,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "codecommit", &self.region, "/");
+        request.set_operation("CreatePullRequestApprovalRule");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -15043,56 +16991,111 @@ impl CodeCommit for CodeCommitClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `create_repository`, also returning the metadata of the response.
+    pub async fn create_repository_with_metadata(
+        &self,
+        input: CreateRepositoryInput,
+    ) -> Result<(CreateRepositoryOutput, ResponseMetadata), RusotoError<CreateRepositoryError>>
+    {
+        let request = self.build_create_repository_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<CreatePullRequestApprovalRuleOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<CreateRepositoryOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(CreatePullRequestApprovalRuleError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(CreateRepositoryError::from_response(response))
         }
     }
 
-    /// <p>Creates a new, empty repository.</p>
-    async fn create_repository(
+    /// Presigns the request `create_repository` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_repository(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: CreateRepositoryInput,
-    ) -> Result<CreateRepositoryOutput, RusotoError<CreateRepositoryError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_repository_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_repository_request(&self, input: CreateRepositoryInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "codecommit", &self.region, "/");
+        request.set_operation("CreateRepository");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "CodeCommit_20150413.CreateRepository");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `create_unreferenced_merge_commit`, also returning the metadata of the response.
+    pub async fn create_unreferenced_merge_commit_with_metadata(
+        &self,
+        input: CreateUnreferencedMergeCommitInput,
+    ) -> Result<
+        (CreateUnreferencedMergeCommitOutput, ResponseMetadata),
+        RusotoError<CreateUnreferencedMergeCommitError>,
+    > {
+        let request = self.build_create_unreferenced_merge_commit_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<CreateRepositoryOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<CreateUnreferencedMergeCommitOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(CreateRepositoryError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(CreateUnreferencedMergeCommitError::from_response(response))
         }
     }
 
-    /// <p><p>Creates an unreferenced commit that represents the result of merging two branches using a specified merge strategy. This can help you determine the outcome of a potential merge. This API cannot be used with the fast-forward merge strategy because that strategy does not create a merge commit.</p> <note> <p>This unreferenced merge commit can only be accessed using the GetCommit API or through git commands such as git fetch. To retrieve this commit, you must specify its commit ID or otherwise reference it.</p> </note></p>
-    async fn create_unreferenced_merge_commit(
+    /// Presigns the request `create_unreferenced_merge_commit` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_unreferenced_merge_commit(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: CreateUnreferencedMergeCommitInput,
-    ) -> Result<CreateUnreferencedMergeCommitOutput, RusotoError<CreateUnreferencedMergeCommitError>>
-    {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_unreferenced_merge_commit_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_unreferenced_merge_commit_request(
+        &self,
+        input: CreateUnreferencedMergeCommitInput,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "codecommit", &self.region, "/");
+        request.set_operation("CreateUnreferencedMergeCommit");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -15102,29 +17105,58 @@ impl CodeCommit for CodeCommitClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `delete_approval_rule_template`, also returning the metadata of the response.
+    pub async fn delete_approval_rule_template_with_metadata(
+        &self,
+        input: DeleteApprovalRuleTemplateInput,
+    ) -> Result<
+        (DeleteApprovalRuleTemplateOutput, ResponseMetadata),
+        RusotoError<DeleteApprovalRuleTemplateError>,
+    > {
+        let request = self.build_delete_approval_rule_template_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<CreateUnreferencedMergeCommitOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DeleteApprovalRuleTemplateOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(CreateUnreferencedMergeCommitError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DeleteApprovalRuleTemplateError::from_response(response))
         }
     }
 
-    /// <p>Deletes a specified approval rule template. Deleting a template does not remove approval rules on pull requests already created with the template.</p>
-    async fn delete_approval_rule_template(
+    /// Presigns the request `delete_approval_rule_template` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_approval_rule_template(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteApprovalRuleTemplateInput,
-    ) -> Result<DeleteApprovalRuleTemplateOutput, RusotoError<DeleteApprovalRuleTemplateError>>
-    {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_approval_rule_template_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_approval_rule_template_request(
+        &self,
+        input: DeleteApprovalRuleTemplateInput,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "codecommit", &self.region, "/");
+        request.set_operation("DeleteApprovalRuleTemplate");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -15134,111 +17166,220 @@ impl CodeCommit for CodeCommitClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `delete_branch`, also returning the metadata of the response.
+    pub async fn delete_branch_with_metadata(
+        &self,
+        input: DeleteBranchInput,
+    ) -> Result<(DeleteBranchOutput, ResponseMetadata), RusotoError<DeleteBranchError>> {
+        let request = self.build_delete_branch_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DeleteApprovalRuleTemplateOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DeleteBranchOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteApprovalRuleTemplateError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DeleteBranchError::from_response(response))
         }
     }
 
-    /// <p>Deletes a branch from a repository, unless that branch is the default branch for the repository. </p>
-    async fn delete_branch(
+    /// Presigns the request `delete_branch` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_branch(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteBranchInput,
-    ) -> Result<DeleteBranchOutput, RusotoError<DeleteBranchError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_branch_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_branch_request(&self, input: DeleteBranchInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "codecommit", &self.region, "/");
+        request.set_operation("DeleteBranch");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "CodeCommit_20150413.DeleteBranch");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `delete_comment_content`, also returning the metadata of the response.
+    pub async fn delete_comment_content_with_metadata(
+        &self,
+        input: DeleteCommentContentInput,
+    ) -> Result<
+        (DeleteCommentContentOutput, ResponseMetadata),
+        RusotoError<DeleteCommentContentError>,
+    > {
+        let request = self.build_delete_comment_content_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<DeleteBranchOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DeleteCommentContentOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteBranchError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DeleteCommentContentError::from_response(response))
         }
     }
 
-    /// <p>Deletes the content of a comment made on a change, file, or commit in a repository.</p>
-    async fn delete_comment_content(
+    /// Presigns the request `delete_comment_content` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_comment_content(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteCommentContentInput,
-    ) -> Result<DeleteCommentContentOutput, RusotoError<DeleteCommentContentError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_comment_content_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_comment_content_request(
+        &self,
+        input: DeleteCommentContentInput,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "codecommit", &self.region, "/");
+        request.set_operation("DeleteCommentContent");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "CodeCommit_20150413.DeleteCommentContent");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `delete_file`, also returning the metadata of the response.
+    pub async fn delete_file_with_metadata(
+        &self,
+        input: DeleteFileInput,
+    ) -> Result<(DeleteFileOutput, ResponseMetadata), RusotoError<DeleteFileError>> {
+        let request = self.build_delete_file_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DeleteCommentContentOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DeleteFileOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteCommentContentError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DeleteFileError::from_response(response))
         }
     }
 
-    /// <p>Deletes a specified file from a specified branch. A commit is created on the branch that contains the revision. The file still exists in the commits earlier to the commit that contains the deletion.</p>
-    async fn delete_file(
+    /// Presigns the request `delete_file` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_file(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteFileInput,
-    ) -> Result<DeleteFileOutput, RusotoError<DeleteFileError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_file_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_file_request(&self, input: DeleteFileInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "codecommit", &self.region, "/");
+        request.set_operation("DeleteFile");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "CodeCommit_20150413.DeleteFile");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `delete_pull_request_approval_rule`, also returning the metadata of the response.
+    pub async fn delete_pull_request_approval_rule_with_metadata(
+        &self,
+        input: DeletePullRequestApprovalRuleInput,
+    ) -> Result<
+        (DeletePullRequestApprovalRuleOutput, ResponseMetadata),
+        RusotoError<DeletePullRequestApprovalRuleError>,
+    > {
+        let request = self.build_delete_pull_request_approval_rule_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<DeleteFileOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DeletePullRequestApprovalRuleOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteFileError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DeletePullRequestApprovalRuleError::from_response(response))
         }
     }
 
-    /// <p>Deletes an approval rule from a specified pull request. Approval rules can be deleted from a pull request only if the pull request is open, and if the approval rule was created specifically for a pull request and not generated from an approval rule template associated with the repository where the pull request was created. You cannot delete an approval rule from a merged or closed pull request.</p>
-    async fn delete_pull_request_approval_rule(
+    /// Presigns the request `delete_pull_request_approval_rule` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_pull_request_approval_rule(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeletePullRequestApprovalRuleInput,
-    ) -> Result<DeletePullRequestApprovalRuleOutput, RusotoError<DeletePullRequestApprovalRuleError>>
-    {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_pull_request_approval_rule_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_pull_request_approval_rule_request(
+        &self,
+        input: DeletePullRequestApprovalRuleInput,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "codecommit", &self.region, "/");
+        request.set_operation("DeletePullRequestApprovalRule");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -15248,83 +17389,169 @@ impl CodeCommit for CodeCommitClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `delete_repository`, also returning the metadata of the response.
+    pub async fn delete_repository_with_metadata(
+        &self,
+        input: DeleteRepositoryInput,
+    ) -> Result<(DeleteRepositoryOutput, ResponseMetadata), RusotoError<DeleteRepositoryError>>
+    {
+        let request = self.build_delete_repository_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DeletePullRequestApprovalRuleOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DeleteRepositoryOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DeletePullRequestApprovalRuleError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DeleteRepositoryError::from_response(response))
         }
     }
 
-    /// <p><p>Deletes a repository. If a specified repository was already deleted, a null repository ID is returned.</p> <important> <p>Deleting a repository also deletes all associated objects and metadata. After a repository is deleted, all future push calls to the deleted repository fail.</p> </important></p>
-    async fn delete_repository(
+    /// Presigns the request `delete_repository` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_repository(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteRepositoryInput,
-    ) -> Result<DeleteRepositoryOutput, RusotoError<DeleteRepositoryError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_repository_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_repository_request(&self, input: DeleteRepositoryInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "codecommit", &self.region, "/");
+        request.set_operation("DeleteRepository");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "CodeCommit_20150413.DeleteRepository");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `describe_merge_conflicts`, also returning the metadata of the response.
+    pub async fn describe_merge_conflicts_with_metadata(
+        &self,
+        input: DescribeMergeConflictsInput,
+    ) -> Result<
+        (DescribeMergeConflictsOutput, ResponseMetadata),
+        RusotoError<DescribeMergeConflictsError>,
+    > {
+        let request = self.build_describe_merge_conflicts_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<DeleteRepositoryOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DescribeMergeConflictsOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteRepositoryError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DescribeMergeConflictsError::from_response(response))
         }
     }
 
-    /// <p>Returns information about one or more merge conflicts in the attempted merge of two commit specifiers using the squash or three-way merge strategy. If the merge option for the attempted merge is specified as FAST_FORWARD_MERGE, an exception is thrown.</p>
-    async fn describe_merge_conflicts(
+    /// Presigns the request `describe_merge_conflicts` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_merge_conflicts(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeMergeConflictsInput,
-    ) -> Result<DescribeMergeConflictsOutput, RusotoError<DescribeMergeConflictsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_merge_conflicts_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_merge_conflicts_request(
+        &self,
+        input: DescribeMergeConflictsInput,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "codecommit", &self.region, "/");
+        request.set_operation("DescribeMergeConflicts");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "CodeCommit_20150413.DescribeMergeConflicts");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `describe_pull_request_events`, also returning the metadata of the response.
+    pub async fn describe_pull_request_events_with_metadata(
+        &self,
+        input: DescribePullRequestEventsInput,
+    ) -> Result<
+        (DescribePullRequestEventsOutput, ResponseMetadata),
+        RusotoError<DescribePullRequestEventsError>,
+    > {
+        let request = self.build_describe_pull_request_events_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DescribeMergeConflictsOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DescribePullRequestEventsOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeMergeConflictsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DescribePullRequestEventsError::from_response(response))
         }
     }
 
-    /// <p>Returns information about one or more pull request events.</p>
-    async fn describe_pull_request_events(
+    /// Presigns the request `describe_pull_request_events` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_pull_request_events(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribePullRequestEventsInput,
-    ) -> Result<DescribePullRequestEventsOutput, RusotoError<DescribePullRequestEventsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_pull_request_events_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_pull_request_events_request(
+        &self,
+        input: DescribePullRequestEventsInput,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "codecommit", &self.region, "/");
+        request.set_operation("DescribePullRequestEvents");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -15334,28 +17561,53 @@ impl CodeCommit for CodeCommitClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `disassociate_approval_rule_template_from_repository`, also returning the metadata of the response.
+    pub async fn disassociate_approval_rule_template_from_repository_with_metadata(
+        &self,
+        input: DisassociateApprovalRuleTemplateFromRepositoryInput,
+    ) -> Result<
+        ((), ResponseMetadata),
+        RusotoError<DisassociateApprovalRuleTemplateFromRepositoryError>,
+    > {
+        let request = self.build_disassociate_approval_rule_template_from_repository_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DescribePullRequestEventsOutput, _>()
+            std::mem::drop(response);
+            Ok(((), metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribePullRequestEventsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DisassociateApprovalRuleTemplateFromRepositoryError::from_response(response))
         }
     }
 
-    /// <p>Removes the association between a template and a repository so that approval rules based on the template are not automatically created when pull requests are created in the specified repository. This does not delete any approval rules previously created for pull requests through the template association.</p>
-    async fn disassociate_approval_rule_template_from_repository(
+    /// Presigns the request `disassociate_approval_rule_template_from_repository` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_disassociate_approval_rule_template_from_repository(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DisassociateApprovalRuleTemplateFromRepositoryInput,
-    ) -> Result<(), RusotoError<DisassociateApprovalRuleTemplateFromRepositoryError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_disassociate_approval_rule_template_from_repository_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_disassociate_approval_rule_template_from_repository_request(
+        &self,
+        input: DisassociateApprovalRuleTemplateFromRepositoryInput,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "codecommit", &self.region, "/");
+        request.set_operation("DisassociateApprovalRuleTemplateFromRepository");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -15365,30 +17617,60 @@ impl CodeCommit for CodeCommitClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `evaluate_pull_request_approval_rules`, also returning the metadata of the response.
+    pub async fn evaluate_pull_request_approval_rules_with_metadata(
+        &self,
+        input: EvaluatePullRequestApprovalRulesInput,
+    ) -> Result<
+        (EvaluatePullRequestApprovalRulesOutput, ResponseMetadata),
+        RusotoError<EvaluatePullRequestApprovalRulesError>,
+    > {
+        let request = self.build_evaluate_pull_request_approval_rules_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            std::mem::drop(response);
-            Ok(())
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<EvaluatePullRequestApprovalRulesOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DisassociateApprovalRuleTemplateFromRepositoryError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(EvaluatePullRequestApprovalRulesError::from_response(
+                response,
+            ))
         }
     }
 
-    /// <p>Evaluates whether a pull request has met all the conditions specified in its associated approval rules.</p>
-    async fn evaluate_pull_request_approval_rules(
+    /// Presigns the request `evaluate_pull_request_approval_rules` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_evaluate_pull_request_approval_rules(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: EvaluatePullRequestApprovalRulesInput,
-    ) -> Result<
-        EvaluatePullRequestApprovalRulesOutput,
-        RusotoError<EvaluatePullRequestApprovalRulesError>,
-    > {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_evaluate_pull_request_approval_rules_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_evaluate_pull_request_approval_rules_request(
+        &self,
+        input: EvaluatePullRequestApprovalRulesInput,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "codecommit", &self.region, "/");
+        request.set_operation("EvaluatePullRequestApprovalRules");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -15398,30 +17680,58 @@ impl CodeCommit for CodeCommitClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `get_approval_rule_template`, also returning the metadata of the response.
+    pub async fn get_approval_rule_template_with_metadata(
+        &self,
+        input: GetApprovalRuleTemplateInput,
+    ) -> Result<
+        (GetApprovalRuleTemplateOutput, ResponseMetadata),
+        RusotoError<GetApprovalRuleTemplateError>,
+    > {
+        let request = self.build_get_approval_rule_template_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<EvaluatePullRequestApprovalRulesOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<GetApprovalRuleTemplateOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(EvaluatePullRequestApprovalRulesError::from_response(
-                response,
-            ))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(GetApprovalRuleTemplateError::from_response(response))
         }
     }
 
-    /// <p>Returns information about a specified approval rule template.</p>
-    async fn get_approval_rule_template(
+    /// Presigns the request `get_approval_rule_template` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_approval_rule_template(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetApprovalRuleTemplateInput,
-    ) -> Result<GetApprovalRuleTemplateOutput, RusotoError<GetApprovalRuleTemplateError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_approval_rule_template_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_approval_rule_template_request(
+        &self,
+        input: GetApprovalRuleTemplateInput,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "codecommit", &self.region, "/");
+        request.set_operation("GetApprovalRuleTemplate");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -15431,110 +17741,214 @@ impl CodeCommit for CodeCommitClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `get_blob`, also returning the metadata of the response.
+    pub async fn get_blob_with_metadata(
+        &self,
+        input: GetBlobInput,
+    ) -> Result<(GetBlobOutput, ResponseMetadata), RusotoError<GetBlobError>> {
+        let request = self.build_get_blob_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<GetApprovalRuleTemplateOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result =
+                proto::json::ResponsePayload::new(&response).deserialize::<GetBlobOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(GetApprovalRuleTemplateError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(GetBlobError::from_response(response))
         }
     }
 
-    /// <p>Returns the base-64 encoded content of an individual blob in a repository.</p>
-    async fn get_blob(
+    /// Presigns the request `get_blob` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_blob(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetBlobInput,
-    ) -> Result<GetBlobOutput, RusotoError<GetBlobError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_blob_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_blob_request(&self, input: GetBlobInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "codecommit", &self.region, "/");
+        request.set_operation("GetBlob");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "CodeCommit_20150413.GetBlob");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `get_branch`, also returning the metadata of the response.
+    pub async fn get_branch_with_metadata(
+        &self,
+        input: GetBranchInput,
+    ) -> Result<(GetBranchOutput, ResponseMetadata), RusotoError<GetBranchError>> {
+        let request = self.build_get_branch_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<GetBlobOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result =
+                proto::json::ResponsePayload::new(&response).deserialize::<GetBranchOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(GetBlobError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(GetBranchError::from_response(response))
         }
     }
 
-    /// <p>Returns information about a repository branch, including its name and the last commit ID.</p>
-    async fn get_branch(
+    /// Presigns the request `get_branch` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_branch(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetBranchInput,
-    ) -> Result<GetBranchOutput, RusotoError<GetBranchError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_branch_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_branch_request(&self, input: GetBranchInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "codecommit", &self.region, "/");
+        request.set_operation("GetBranch");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "CodeCommit_20150413.GetBranch");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `get_comment`, also returning the metadata of the response.
+    pub async fn get_comment_with_metadata(
+        &self,
+        input: GetCommentInput,
+    ) -> Result<(GetCommentOutput, ResponseMetadata), RusotoError<GetCommentError>> {
+        let request = self.build_get_comment_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<GetBranchOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<GetCommentOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(GetBranchError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(GetCommentError::from_response(response))
         }
     }
 
-    /// <p>Returns the content of a comment made on a change, file, or commit in a repository.</p>
-    async fn get_comment(
+    /// Presigns the request `get_comment` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_comment(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetCommentInput,
-    ) -> Result<GetCommentOutput, RusotoError<GetCommentError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_comment_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_comment_request(&self, input: GetCommentInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "codecommit", &self.region, "/");
+        request.set_operation("GetComment");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "CodeCommit_20150413.GetComment");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `get_comments_for_compared_commit`, also returning the metadata of the response.
+    pub async fn get_comments_for_compared_commit_with_metadata(
+        &self,
+        input: GetCommentsForComparedCommitInput,
+    ) -> Result<
+        (GetCommentsForComparedCommitOutput, ResponseMetadata),
+        RusotoError<GetCommentsForComparedCommitError>,
+    > {
+        let request = self.build_get_comments_for_compared_commit_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<GetCommentOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<GetCommentsForComparedCommitOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(GetCommentError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(GetCommentsForComparedCommitError::from_response(response))
         }
     }
 
-    /// <p>Returns information about comments made on the comparison between two commits.</p>
-    async fn get_comments_for_compared_commit(
+    /// Presigns the request `get_comments_for_compared_commit` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_comments_for_compared_commit(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetCommentsForComparedCommitInput,
-    ) -> Result<GetCommentsForComparedCommitOutput, RusotoError<GetCommentsForComparedCommitError>>
-    {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_comments_for_compared_commit_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_comments_for_compared_commit_request(
+        &self,
+        input: GetCommentsForComparedCommitInput,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "codecommit", &self.region, "/");
+        request.set_operation("GetCommentsForComparedCommit");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -15544,28 +17958,58 @@ impl CodeCommit for CodeCommitClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `get_comments_for_pull_request`, also returning the metadata of the response.
+    pub async fn get_comments_for_pull_request_with_metadata(
+        &self,
+        input: GetCommentsForPullRequestInput,
+    ) -> Result<
+        (GetCommentsForPullRequestOutput, ResponseMetadata),
+        RusotoError<GetCommentsForPullRequestError>,
+    > {
+        let request = self.build_get_comments_for_pull_request_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<GetCommentsForComparedCommitOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<GetCommentsForPullRequestOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(GetCommentsForComparedCommitError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(GetCommentsForPullRequestError::from_response(response))
         }
     }
 
-    /// <p>Returns comments made on a pull request.</p>
-    async fn get_comments_for_pull_request(
+    /// Presigns the request `get_comments_for_pull_request` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_comments_for_pull_request(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetCommentsForPullRequestInput,
-    ) -> Result<GetCommentsForPullRequestOutput, RusotoError<GetCommentsForPullRequestError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_comments_for_pull_request_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_comments_for_pull_request_request(
+        &self,
+        input: GetCommentsForPullRequestInput,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "codecommit", &self.region, "/");
+        request.set_operation("GetCommentsForPullRequest");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -15575,245 +18019,475 @@ impl CodeCommit for CodeCommitClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `get_commit`, also returning the metadata of the response.
+    pub async fn get_commit_with_metadata(
+        &self,
+        input: GetCommitInput,
+    ) -> Result<(GetCommitOutput, ResponseMetadata), RusotoError<GetCommitError>> {
+        let request = self.build_get_commit_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<GetCommentsForPullRequestOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result =
+                proto::json::ResponsePayload::new(&response).deserialize::<GetCommitOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(GetCommentsForPullRequestError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(GetCommitError::from_response(response))
         }
     }
 
-    /// <p>Returns information about a commit, including commit message and committer information.</p>
-    async fn get_commit(
+    /// Presigns the request `get_commit` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_commit(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetCommitInput,
-    ) -> Result<GetCommitOutput, RusotoError<GetCommitError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_commit_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_commit_request(&self, input: GetCommitInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "codecommit", &self.region, "/");
+        request.set_operation("GetCommit");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "CodeCommit_20150413.GetCommit");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `get_differences`, also returning the metadata of the response.
+    pub async fn get_differences_with_metadata(
+        &self,
+        input: GetDifferencesInput,
+    ) -> Result<(GetDifferencesOutput, ResponseMetadata), RusotoError<GetDifferencesError>> {
+        let request = self.build_get_differences_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<GetCommitOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<GetDifferencesOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(GetCommitError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(GetDifferencesError::from_response(response))
         }
     }
 
-    /// <p>Returns information about the differences in a valid commit specifier (such as a branch, tag, HEAD, commit ID, or other fully qualified reference). Results can be limited to a specified path.</p>
-    async fn get_differences(
+    /// Presigns the request `get_differences` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_differences(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetDifferencesInput,
-    ) -> Result<GetDifferencesOutput, RusotoError<GetDifferencesError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_differences_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_differences_request(&self, input: GetDifferencesInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "codecommit", &self.region, "/");
+        request.set_operation("GetDifferences");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "CodeCommit_20150413.GetDifferences");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `get_file`, also returning the metadata of the response.
+    pub async fn get_file_with_metadata(
+        &self,
+        input: GetFileInput,
+    ) -> Result<(GetFileOutput, ResponseMetadata), RusotoError<GetFileError>> {
+        let request = self.build_get_file_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<GetDifferencesOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result =
+                proto::json::ResponsePayload::new(&response).deserialize::<GetFileOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(GetDifferencesError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(GetFileError::from_response(response))
         }
     }
 
-    /// <p>Returns the base-64 encoded contents of a specified file and its metadata.</p>
-    async fn get_file(
+    /// Presigns the request `get_file` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_file(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetFileInput,
-    ) -> Result<GetFileOutput, RusotoError<GetFileError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_file_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_file_request(&self, input: GetFileInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "codecommit", &self.region, "/");
+        request.set_operation("GetFile");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "CodeCommit_20150413.GetFile");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `get_folder`, also returning the metadata of the response.
+    pub async fn get_folder_with_metadata(
+        &self,
+        input: GetFolderInput,
+    ) -> Result<(GetFolderOutput, ResponseMetadata), RusotoError<GetFolderError>> {
+        let request = self.build_get_folder_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<GetFileOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result =
+                proto::json::ResponsePayload::new(&response).deserialize::<GetFolderOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(GetFileError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(GetFolderError::from_response(response))
         }
     }
 
-    /// <p>Returns the contents of a specified folder in a repository.</p>
-    async fn get_folder(
+    /// Presigns the request `get_folder` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_folder(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetFolderInput,
-    ) -> Result<GetFolderOutput, RusotoError<GetFolderError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_folder_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_folder_request(&self, input: GetFolderInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "codecommit", &self.region, "/");
+        request.set_operation("GetFolder");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "CodeCommit_20150413.GetFolder");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `get_merge_commit`, also returning the metadata of the response.
+    pub async fn get_merge_commit_with_metadata(
+        &self,
+        input: GetMergeCommitInput,
+    ) -> Result<(GetMergeCommitOutput, ResponseMetadata), RusotoError<GetMergeCommitError>> {
+        let request = self.build_get_merge_commit_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<GetFolderOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<GetMergeCommitOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(GetFolderError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(GetMergeCommitError::from_response(response))
         }
     }
 
-    /// <p>Returns information about a specified merge commit.</p>
-    async fn get_merge_commit(
+    /// Presigns the request `get_merge_commit` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_merge_commit(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetMergeCommitInput,
-    ) -> Result<GetMergeCommitOutput, RusotoError<GetMergeCommitError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_merge_commit_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_merge_commit_request(&self, input: GetMergeCommitInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "codecommit", &self.region, "/");
+        request.set_operation("GetMergeCommit");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "CodeCommit_20150413.GetMergeCommit");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `get_merge_conflicts`, also returning the metadata of the response.
+    pub async fn get_merge_conflicts_with_metadata(
+        &self,
+        input: GetMergeConflictsInput,
+    ) -> Result<(GetMergeConflictsOutput, ResponseMetadata), RusotoError<GetMergeConflictsError>>
+    {
+        let request = self.build_get_merge_conflicts_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<GetMergeCommitOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<GetMergeConflictsOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(GetMergeCommitError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(GetMergeConflictsError::from_response(response))
         }
     }
 
-    /// <p>Returns information about merge conflicts between the before and after commit IDs for a pull request in a repository.</p>
-    async fn get_merge_conflicts(
+    /// Presigns the request `get_merge_conflicts` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_merge_conflicts(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetMergeConflictsInput,
-    ) -> Result<GetMergeConflictsOutput, RusotoError<GetMergeConflictsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_merge_conflicts_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_merge_conflicts_request(&self, input: GetMergeConflictsInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "codecommit", &self.region, "/");
+        request.set_operation("GetMergeConflicts");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "CodeCommit_20150413.GetMergeConflicts");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `get_merge_options`, also returning the metadata of the response.
+    pub async fn get_merge_options_with_metadata(
+        &self,
+        input: GetMergeOptionsInput,
+    ) -> Result<(GetMergeOptionsOutput, ResponseMetadata), RusotoError<GetMergeOptionsError>> {
+        let request = self.build_get_merge_options_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<GetMergeConflictsOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<GetMergeOptionsOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(GetMergeConflictsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(GetMergeOptionsError::from_response(response))
         }
     }
 
-    /// <p>Returns information about the merge options available for merging two specified branches. For details about why a merge option is not available, use GetMergeConflicts or DescribeMergeConflicts.</p>
-    async fn get_merge_options(
+    /// Presigns the request `get_merge_options` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_merge_options(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetMergeOptionsInput,
-    ) -> Result<GetMergeOptionsOutput, RusotoError<GetMergeOptionsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_merge_options_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_merge_options_request(&self, input: GetMergeOptionsInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "codecommit", &self.region, "/");
+        request.set_operation("GetMergeOptions");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "CodeCommit_20150413.GetMergeOptions");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `get_pull_request`, also returning the metadata of the response.
+    pub async fn get_pull_request_with_metadata(
+        &self,
+        input: GetPullRequestInput,
+    ) -> Result<(GetPullRequestOutput, ResponseMetadata), RusotoError<GetPullRequestError>> {
+        let request = self.build_get_pull_request_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<GetMergeOptionsOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<GetPullRequestOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(GetMergeOptionsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(GetPullRequestError::from_response(response))
         }
     }
 
-    /// <p>Gets information about a pull request in a specified repository.</p>
-    async fn get_pull_request(
+    /// Presigns the request `get_pull_request` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_pull_request(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetPullRequestInput,
-    ) -> Result<GetPullRequestOutput, RusotoError<GetPullRequestError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_pull_request_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_pull_request_request(&self, input: GetPullRequestInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "codecommit", &self.region, "/");
+        request.set_operation("GetPullRequest");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "CodeCommit_20150413.GetPullRequest");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `get_pull_request_approval_states`, also returning the metadata of the response.
+    pub async fn get_pull_request_approval_states_with_metadata(
+        &self,
+        input: GetPullRequestApprovalStatesInput,
+    ) -> Result<
+        (GetPullRequestApprovalStatesOutput, ResponseMetadata),
+        RusotoError<GetPullRequestApprovalStatesError>,
+    > {
+        let request = self.build_get_pull_request_approval_states_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<GetPullRequestOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<GetPullRequestApprovalStatesOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(GetPullRequestError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(GetPullRequestApprovalStatesError::from_response(response))
         }
     }
 
-    /// <p>Gets information about the approval states for a specified pull request. Approval states only apply to pull requests that have one or more approval rules applied to them.</p>
-    async fn get_pull_request_approval_states(
+    /// Presigns the request `get_pull_request_approval_states` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_pull_request_approval_states(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetPullRequestApprovalStatesInput,
-    ) -> Result<GetPullRequestApprovalStatesOutput, RusotoError<GetPullRequestApprovalStatesError>>
-    {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_pull_request_approval_states_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_pull_request_approval_states_request(
+        &self,
+        input: GetPullRequestApprovalStatesInput,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "codecommit", &self.region, "/");
+        request.set_operation("GetPullRequestApprovalStates");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -15823,29 +18497,58 @@ impl CodeCommit for CodeCommitClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `get_pull_request_override_state`, also returning the metadata of the response.
+    pub async fn get_pull_request_override_state_with_metadata(
+        &self,
+        input: GetPullRequestOverrideStateInput,
+    ) -> Result<
+        (GetPullRequestOverrideStateOutput, ResponseMetadata),
+        RusotoError<GetPullRequestOverrideStateError>,
+    > {
+        let request = self.build_get_pull_request_override_state_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<GetPullRequestApprovalStatesOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<GetPullRequestOverrideStateOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(GetPullRequestApprovalStatesError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(GetPullRequestOverrideStateError::from_response(response))
         }
     }
 
-    /// <p>Returns information about whether approval rules have been set aside (overridden) for a pull request, and if so, the Amazon Resource Name (ARN) of the user or identity that overrode the rules and their requirements for the pull request.</p>
-    async fn get_pull_request_override_state(
+    /// Presigns the request `get_pull_request_override_state` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_pull_request_override_state(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetPullRequestOverrideStateInput,
-    ) -> Result<GetPullRequestOverrideStateOutput, RusotoError<GetPullRequestOverrideStateError>>
-    {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_pull_request_override_state_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_pull_request_override_state_request(
+        &self,
+        input: GetPullRequestOverrideStateInput,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "codecommit", &self.region, "/");
+        request.set_operation("GetPullRequestOverrideState");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -15855,83 +18558,168 @@ impl CodeCommit for CodeCommitClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `get_repository`, also returning the metadata of the response.
+    pub async fn get_repository_with_metadata(
+        &self,
+        input: GetRepositoryInput,
+    ) -> Result<(GetRepositoryOutput, ResponseMetadata), RusotoError<GetRepositoryError>> {
+        let request = self.build_get_repository_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<GetPullRequestOverrideStateOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<GetRepositoryOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(GetPullRequestOverrideStateError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(GetRepositoryError::from_response(response))
         }
     }
 
-    /// <p><p>Returns information about a repository.</p> <note> <p>The description field for a repository accepts all HTML characters and all valid Unicode characters. Applications that do not HTML-encode the description and display it in a webpage can expose users to potentially malicious code. Make sure that you HTML-encode the description field in any application that uses this API to display the repository description on a webpage.</p> </note></p>
-    async fn get_repository(
+    /// Presigns the request `get_repository` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_repository(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetRepositoryInput,
-    ) -> Result<GetRepositoryOutput, RusotoError<GetRepositoryError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_repository_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_repository_request(&self, input: GetRepositoryInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "codecommit", &self.region, "/");
+        request.set_operation("GetRepository");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "CodeCommit_20150413.GetRepository");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `get_repository_triggers`, also returning the metadata of the response.
+    pub async fn get_repository_triggers_with_metadata(
+        &self,
+        input: GetRepositoryTriggersInput,
+    ) -> Result<
+        (GetRepositoryTriggersOutput, ResponseMetadata),
+        RusotoError<GetRepositoryTriggersError>,
+    > {
+        let request = self.build_get_repository_triggers_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<GetRepositoryOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<GetRepositoryTriggersOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(GetRepositoryError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(GetRepositoryTriggersError::from_response(response))
         }
     }
 
-    /// <p>Gets information about triggers configured for a repository.</p>
-    async fn get_repository_triggers(
+    /// Presigns the request `get_repository_triggers` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_repository_triggers(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetRepositoryTriggersInput,
-    ) -> Result<GetRepositoryTriggersOutput, RusotoError<GetRepositoryTriggersError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_repository_triggers_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_repository_triggers_request(
+        &self,
+        input: GetRepositoryTriggersInput,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "codecommit", &self.region, "/");
+        request.set_operation("GetRepositoryTriggers");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "CodeCommit_20150413.GetRepositoryTriggers");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `list_approval_rule_templates`, also returning the metadata of the response.
+    pub async fn list_approval_rule_templates_with_metadata(
+        &self,
+        input: ListApprovalRuleTemplatesInput,
+    ) -> Result<
+        (ListApprovalRuleTemplatesOutput, ResponseMetadata),
+        RusotoError<ListApprovalRuleTemplatesError>,
+    > {
+        let request = self.build_list_approval_rule_templates_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<GetRepositoryTriggersOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ListApprovalRuleTemplatesOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(GetRepositoryTriggersError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ListApprovalRuleTemplatesError::from_response(response))
         }
     }
 
-    /// <p>Lists all approval rule templates in the specified AWS Region in your AWS account. If an AWS Region is not specified, the AWS Region where you are signed in is used.</p>
-    async fn list_approval_rule_templates(
+    /// Presigns the request `list_approval_rule_templates` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_approval_rule_templates(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListApprovalRuleTemplatesInput,
-    ) -> Result<ListApprovalRuleTemplatesOutput, RusotoError<ListApprovalRuleTemplatesError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_approval_rule_templates_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_approval_rule_templates_request(
+        &self,
+        input: ListApprovalRuleTemplatesInput,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "codecommit", &self.region, "/");
+        request.set_operation("ListApprovalRuleTemplates");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -15941,31 +18729,64 @@ impl CodeCommit for CodeCommitClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `list_associated_approval_rule_templates_for_repository`, also returning the metadata of the response.
+    pub async fn list_associated_approval_rule_templates_for_repository_with_metadata(
+        &self,
+        input: ListAssociatedApprovalRuleTemplatesForRepositoryInput,
+    ) -> Result<
+        (
+            ListAssociatedApprovalRuleTemplatesForRepositoryOutput,
+            ResponseMetadata,
+        ),
+        RusotoError<ListAssociatedApprovalRuleTemplatesForRepositoryError>,
+    > {
+        let request =
+            self.build_list_associated_approval_rule_templates_for_repository_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListApprovalRuleTemplatesOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ListAssociatedApprovalRuleTemplatesForRepositoryOutput, _>(
+            )?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ListApprovalRuleTemplatesError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ListAssociatedApprovalRuleTemplatesForRepositoryError::from_response(response))
         }
     }
 
-    /// <p>Lists all approval rule templates that are associated with a specified repository.</p>
-    async fn list_associated_approval_rule_templates_for_repository(
+    /// Presigns the request `list_associated_approval_rule_templates_for_repository` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_associated_approval_rule_templates_for_repository(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListAssociatedApprovalRuleTemplatesForRepositoryInput,
-    ) -> Result<
-        ListAssociatedApprovalRuleTemplatesForRepositoryOutput,
-        RusotoError<ListAssociatedApprovalRuleTemplatesForRepositoryError>,
-    > {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request =
+            self.build_list_associated_approval_rule_templates_for_repository_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_associated_approval_rule_templates_for_repository_request(
+        &self,
+        input: ListAssociatedApprovalRuleTemplatesForRepositoryInput,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "codecommit", &self.region, "/");
+        request.set_operation("ListAssociatedApprovalRuleTemplatesForRepository");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -15975,112 +18796,221 @@ impl CodeCommit for CodeCommitClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `list_branches`, also returning the metadata of the response.
+    pub async fn list_branches_with_metadata(
+        &self,
+        input: ListBranchesInput,
+    ) -> Result<(ListBranchesOutput, ResponseMetadata), RusotoError<ListBranchesError>> {
+        let request = self.build_list_branches_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListAssociatedApprovalRuleTemplatesForRepositoryOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ListBranchesOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ListAssociatedApprovalRuleTemplatesForRepositoryError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ListBranchesError::from_response(response))
         }
     }
 
-    /// <p>Gets information about one or more branches in a repository.</p>
-    async fn list_branches(
+    /// Presigns the request `list_branches` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_branches(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListBranchesInput,
-    ) -> Result<ListBranchesOutput, RusotoError<ListBranchesError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_branches_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_branches_request(&self, input: ListBranchesInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "codecommit", &self.region, "/");
+        request.set_operation("ListBranches");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "CodeCommit_20150413.ListBranches");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `list_pull_requests`, also returning the metadata of the response.
+    pub async fn list_pull_requests_with_metadata(
+        &self,
+        input: ListPullRequestsInput,
+    ) -> Result<(ListPullRequestsOutput, ResponseMetadata), RusotoError<ListPullRequestsError>>
+    {
+        let request = self.build_list_pull_requests_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<ListBranchesOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ListPullRequestsOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ListBranchesError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ListPullRequestsError::from_response(response))
         }
     }
 
-    /// <p>Returns a list of pull requests for a specified repository. The return list can be refined by pull request status or pull request author ARN.</p>
-    async fn list_pull_requests(
+    /// Presigns the request `list_pull_requests` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_pull_requests(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListPullRequestsInput,
-    ) -> Result<ListPullRequestsOutput, RusotoError<ListPullRequestsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_pull_requests_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_pull_requests_request(&self, input: ListPullRequestsInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "codecommit", &self.region, "/");
+        request.set_operation("ListPullRequests");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "CodeCommit_20150413.ListPullRequests");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `list_repositories`, also returning the metadata of the response.
+    pub async fn list_repositories_with_metadata(
+        &self,
+        input: ListRepositoriesInput,
+    ) -> Result<(ListRepositoriesOutput, ResponseMetadata), RusotoError<ListRepositoriesError>>
+    {
+        let request = self.build_list_repositories_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<ListPullRequestsOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ListRepositoriesOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ListPullRequestsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ListRepositoriesError::from_response(response))
         }
     }
 
-    /// <p>Gets information about one or more repositories.</p>
-    async fn list_repositories(
+    /// Presigns the request `list_repositories` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_repositories(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListRepositoriesInput,
-    ) -> Result<ListRepositoriesOutput, RusotoError<ListRepositoriesError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_repositories_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_repositories_request(&self, input: ListRepositoriesInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "codecommit", &self.region, "/");
+        request.set_operation("ListRepositories");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "CodeCommit_20150413.ListRepositories");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `list_repositories_for_approval_rule_template`, also returning the metadata of the response.
+    pub async fn list_repositories_for_approval_rule_template_with_metadata(
+        &self,
+        input: ListRepositoriesForApprovalRuleTemplateInput,
+    ) -> Result<
+        (
+            ListRepositoriesForApprovalRuleTemplateOutput,
+            ResponseMetadata,
+        ),
+        RusotoError<ListRepositoriesForApprovalRuleTemplateError>,
+    > {
+        let request = self.build_list_repositories_for_approval_rule_template_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<ListRepositoriesOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ListRepositoriesForApprovalRuleTemplateOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ListRepositoriesError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ListRepositoriesForApprovalRuleTemplateError::from_response(
+                response,
+            ))
         }
     }
 
-    /// <p>Lists all repositories associated with the specified approval rule template.</p>
-    async fn list_repositories_for_approval_rule_template(
+    /// Presigns the request `list_repositories_for_approval_rule_template` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_repositories_for_approval_rule_template(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListRepositoriesForApprovalRuleTemplateInput,
-    ) -> Result<
-        ListRepositoriesForApprovalRuleTemplateOutput,
-        RusotoError<ListRepositoriesForApprovalRuleTemplateError>,
-    > {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_repositories_for_approval_rule_template_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_repositories_for_approval_rule_template_request(
+        &self,
+        input: ListRepositoriesForApprovalRuleTemplateInput,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "codecommit", &self.region, "/");
+        request.set_operation("ListRepositoriesForApprovalRuleTemplate");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -16090,59 +19020,114 @@ impl CodeCommit for CodeCommitClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `list_tags_for_resource`, also returning the metadata of the response.
+    pub async fn list_tags_for_resource_with_metadata(
+        &self,
+        input: ListTagsForResourceInput,
+    ) -> Result<(ListTagsForResourceOutput, ResponseMetadata), RusotoError<ListTagsForResourceError>>
+    {
+        let request = self.build_list_tags_for_resource_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListRepositoriesForApprovalRuleTemplateOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ListTagsForResourceOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ListRepositoriesForApprovalRuleTemplateError::from_response(
-                response,
-            ))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ListTagsForResourceError::from_response(response))
         }
     }
 
-    /// <p>Gets information about AWS tags for a specified Amazon Resource Name (ARN) in AWS CodeCommit. For a list of valid resources in AWS CodeCommit, see <a href="https://docs.aws.amazon.com/codecommit/latest/userguide/auth-and-access-control-iam-access-control-identity-based.html#arn-formats">CodeCommit Resources and Operations</a> in the<i> AWS CodeCommit User Guide</i>.</p>
-    async fn list_tags_for_resource(
+    /// Presigns the request `list_tags_for_resource` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_tags_for_resource(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListTagsForResourceInput,
-    ) -> Result<ListTagsForResourceOutput, RusotoError<ListTagsForResourceError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_tags_for_resource_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_tags_for_resource_request(
+        &self,
+        input: ListTagsForResourceInput,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "codecommit", &self.region, "/");
+        request.set_operation("ListTagsForResource");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "CodeCommit_20150413.ListTagsForResource");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `merge_branches_by_fast_forward`, also returning the metadata of the response.
+    pub async fn merge_branches_by_fast_forward_with_metadata(
+        &self,
+        input: MergeBranchesByFastForwardInput,
+    ) -> Result<
+        (MergeBranchesByFastForwardOutput, ResponseMetadata),
+        RusotoError<MergeBranchesByFastForwardError>,
+    > {
+        let request = self.build_merge_branches_by_fast_forward_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListTagsForResourceOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<MergeBranchesByFastForwardOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ListTagsForResourceError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(MergeBranchesByFastForwardError::from_response(response))
         }
     }
 
-    /// <p>Merges two branches using the fast-forward merge strategy.</p>
-    async fn merge_branches_by_fast_forward(
+    /// Presigns the request `merge_branches_by_fast_forward` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_merge_branches_by_fast_forward(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: MergeBranchesByFastForwardInput,
-    ) -> Result<MergeBranchesByFastForwardOutput, RusotoError<MergeBranchesByFastForwardError>>
-    {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_merge_branches_by_fast_forward_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_merge_branches_by_fast_forward_request(
+        &self,
+        input: MergeBranchesByFastForwardInput,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "codecommit", &self.region, "/");
+        request.set_operation("MergeBranchesByFastForward");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -16152,56 +19137,116 @@ impl CodeCommit for CodeCommitClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `merge_branches_by_squash`, also returning the metadata of the response.
+    pub async fn merge_branches_by_squash_with_metadata(
+        &self,
+        input: MergeBranchesBySquashInput,
+    ) -> Result<
+        (MergeBranchesBySquashOutput, ResponseMetadata),
+        RusotoError<MergeBranchesBySquashError>,
+    > {
+        let request = self.build_merge_branches_by_squash_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<MergeBranchesByFastForwardOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<MergeBranchesBySquashOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(MergeBranchesByFastForwardError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(MergeBranchesBySquashError::from_response(response))
         }
     }
 
-    /// <p>Merges two branches using the squash merge strategy.</p>
-    async fn merge_branches_by_squash(
+    /// Presigns the request `merge_branches_by_squash` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_merge_branches_by_squash(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: MergeBranchesBySquashInput,
-    ) -> Result<MergeBranchesBySquashOutput, RusotoError<MergeBranchesBySquashError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_merge_branches_by_squash_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_merge_branches_by_squash_request(
+        &self,
+        input: MergeBranchesBySquashInput,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "codecommit", &self.region, "/");
+        request.set_operation("MergeBranchesBySquash");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "CodeCommit_20150413.MergeBranchesBySquash");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `merge_branches_by_three_way`, also returning the metadata of the response.
+    pub async fn merge_branches_by_three_way_with_metadata(
+        &self,
+        input: MergeBranchesByThreeWayInput,
+    ) -> Result<
+        (MergeBranchesByThreeWayOutput, ResponseMetadata),
+        RusotoError<MergeBranchesByThreeWayError>,
+    > {
+        let request = self.build_merge_branches_by_three_way_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<MergeBranchesBySquashOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<MergeBranchesByThreeWayOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(MergeBranchesBySquashError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(MergeBranchesByThreeWayError::from_response(response))
         }
     }
 
-    /// <p>Merges two specified branches using the three-way merge strategy.</p>
-    async fn merge_branches_by_three_way(
+    /// Presigns the request `merge_branches_by_three_way` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_merge_branches_by_three_way(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: MergeBranchesByThreeWayInput,
-    ) -> Result<MergeBranchesByThreeWayOutput, RusotoError<MergeBranchesByThreeWayError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_merge_branches_by_three_way_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_merge_branches_by_three_way_request(
+        &self,
+        input: MergeBranchesByThreeWayInput,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "codecommit", &self.region, "/");
+        request.set_operation("MergeBranchesByThreeWay");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -16211,29 +19256,58 @@ impl CodeCommit for CodeCommitClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `merge_pull_request_by_fast_forward`, also returning the metadata of the response.
+    pub async fn merge_pull_request_by_fast_forward_with_metadata(
+        &self,
+        input: MergePullRequestByFastForwardInput,
+    ) -> Result<
+        (MergePullRequestByFastForwardOutput, ResponseMetadata),
+        RusotoError<MergePullRequestByFastForwardError>,
+    > {
+        let request = self.build_merge_pull_request_by_fast_forward_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<MergeBranchesByThreeWayOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<MergePullRequestByFastForwardOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(MergeBranchesByThreeWayError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(MergePullRequestByFastForwardError::from_response(response))
         }
     }
 
-    /// <p>Attempts to merge the source commit of a pull request into the specified destination branch for that pull request at the specified commit using the fast-forward merge strategy. If the merge is successful, it closes the pull request.</p>
-    async fn merge_pull_request_by_fast_forward(
+    /// Presigns the request `merge_pull_request_by_fast_forward` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_merge_pull_request_by_fast_forward(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: MergePullRequestByFastForwardInput,
-    ) -> Result<MergePullRequestByFastForwardOutput, RusotoError<MergePullRequestByFastForwardError>>
-    {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_merge_pull_request_by_fast_forward_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_merge_pull_request_by_fast_forward_request(
+        &self,
+        input: MergePullRequestByFastForwardInput,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "codecommit", &self.region, "/");
+        request.set_operation("MergePullRequestByFastForward");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -16243,28 +19317,58 @@ impl CodeCommit for CodeCommitClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `merge_pull_request_by_squash`, also returning the metadata of the response.
+    pub async fn merge_pull_request_by_squash_with_metadata(
+        &self,
+        input: MergePullRequestBySquashInput,
+    ) -> Result<
+        (MergePullRequestBySquashOutput, ResponseMetadata),
+        RusotoError<MergePullRequestBySquashError>,
+    > {
+        let request = self.build_merge_pull_request_by_squash_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<MergePullRequestByFastForwardOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<MergePullRequestBySquashOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(MergePullRequestByFastForwardError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(MergePullRequestBySquashError::from_response(response))
         }
     }
 
-    /// <p>Attempts to merge the source commit of a pull request into the specified destination branch for that pull request at the specified commit using the squash merge strategy. If the merge is successful, it closes the pull request.</p>
-    async fn merge_pull_request_by_squash(
+    /// Presigns the request `merge_pull_request_by_squash` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_merge_pull_request_by_squash(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: MergePullRequestBySquashInput,
-    ) -> Result<MergePullRequestBySquashOutput, RusotoError<MergePullRequestBySquashError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_merge_pull_request_by_squash_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_merge_pull_request_by_squash_request(
+        &self,
+        input: MergePullRequestBySquashInput,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "codecommit", &self.region, "/");
+        request.set_operation("MergePullRequestBySquash");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -16274,29 +19378,58 @@ impl CodeCommit for CodeCommitClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `merge_pull_request_by_three_way`, also returning the metadata of the response.
+    pub async fn merge_pull_request_by_three_way_with_metadata(
+        &self,
+        input: MergePullRequestByThreeWayInput,
+    ) -> Result<
+        (MergePullRequestByThreeWayOutput, ResponseMetadata),
+        RusotoError<MergePullRequestByThreeWayError>,
+    > {
+        let request = self.build_merge_pull_request_by_three_way_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<MergePullRequestBySquashOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<MergePullRequestByThreeWayOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(MergePullRequestBySquashError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(MergePullRequestByThreeWayError::from_response(response))
         }
     }
 
-    /// <p>Attempts to merge the source commit of a pull request into the specified destination branch for that pull request at the specified commit using the three-way merge strategy. If the merge is successful, it closes the pull request.</p>
-    async fn merge_pull_request_by_three_way(
+    /// Presigns the request `merge_pull_request_by_three_way` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_merge_pull_request_by_three_way(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: MergePullRequestByThreeWayInput,
-    ) -> Result<MergePullRequestByThreeWayOutput, RusotoError<MergePullRequestByThreeWayError>>
-    {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_merge_pull_request_by_three_way_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_merge_pull_request_by_three_way_request(
+        &self,
+        input: MergePullRequestByThreeWayInput,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "codecommit", &self.region, "/");
+        request.set_operation("MergePullRequestByThreeWay");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -16306,28 +19439,52 @@ impl CodeCommit for CodeCommitClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `override_pull_request_approval_rules`, also returning the metadata of the response.
+    pub async fn override_pull_request_approval_rules_with_metadata(
+        &self,
+        input: OverridePullRequestApprovalRulesInput,
+    ) -> Result<((), ResponseMetadata), RusotoError<OverridePullRequestApprovalRulesError>> {
+        let request = self.build_override_pull_request_approval_rules_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<MergePullRequestByThreeWayOutput, _>()
+            std::mem::drop(response);
+            Ok(((), metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(MergePullRequestByThreeWayError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(OverridePullRequestApprovalRulesError::from_response(
+                response,
+            ))
         }
     }
 
-    /// <p>Sets aside (overrides) all approval rule requirements for a specified pull request.</p>
-    async fn override_pull_request_approval_rules(
+    /// Presigns the request `override_pull_request_approval_rules` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_override_pull_request_approval_rules(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: OverridePullRequestApprovalRulesInput,
-    ) -> Result<(), RusotoError<OverridePullRequestApprovalRulesError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_override_pull_request_approval_rules_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_override_pull_request_approval_rules_request(
+        &self,
+        input: OverridePullRequestApprovalRulesInput,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "codecommit", &self.region, "/");
+        request.set_operation("OverridePullRequestApprovalRules");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -16337,30 +19494,58 @@ impl CodeCommit for CodeCommitClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `post_comment_for_compared_commit`, also returning the metadata of the response.
+    pub async fn post_comment_for_compared_commit_with_metadata(
+        &self,
+        input: PostCommentForComparedCommitInput,
+    ) -> Result<
+        (PostCommentForComparedCommitOutput, ResponseMetadata),
+        RusotoError<PostCommentForComparedCommitError>,
+    > {
+        let request = self.build_post_comment_for_compared_commit_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            std::mem::drop(response);
-            Ok(())
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<PostCommentForComparedCommitOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(OverridePullRequestApprovalRulesError::from_response(
-                response,
-            ))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(PostCommentForComparedCommitError::from_response(response))
         }
     }
 
-    /// <p>Posts a comment on the comparison between two commits.</p>
-    async fn post_comment_for_compared_commit(
+    /// Presigns the request `post_comment_for_compared_commit` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_post_comment_for_compared_commit(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: PostCommentForComparedCommitInput,
-    ) -> Result<PostCommentForComparedCommitOutput, RusotoError<PostCommentForComparedCommitError>>
-    {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_post_comment_for_compared_commit_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_post_comment_for_compared_commit_request(
+        &self,
+        input: PostCommentForComparedCommitInput,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "codecommit", &self.region, "/");
+        request.set_operation("PostCommentForComparedCommit");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -16370,28 +19555,58 @@ impl CodeCommit for CodeCommitClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
-        let mut response = self
-            .client
+        request
+    }
+
+    /// Calls `post_comment_for_pull_request`, also returning the metadata of the response.
+    pub async fn post_comment_for_pull_request_with_metadata(
+        &self,
+        input: PostCommentForPullRequestInput,
+    ) -> Result<
+        (PostCommentForPullRequestOutput, ResponseMetadata),
+        RusotoError<PostCommentForPullRequestError>,
+    > {
+        let request = self.build_post_comment_for_pull_request_request(input);
+
+        let mut response = self
+            .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<PostCommentForComparedCommitOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<PostCommentForPullRequestOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(PostCommentForComparedCommitError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(PostCommentForPullRequestError::from_response(response))
         }
     }
 
-    /// <p>Posts a comment on a pull request.</p>
-    async fn post_comment_for_pull_request(
+    /// Presigns the request `post_comment_for_pull_request` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_post_comment_for_pull_request(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: PostCommentForPullRequestInput,
-    ) -> Result<PostCommentForPullRequestOutput, RusotoError<PostCommentForPullRequestError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_post_comment_for_pull_request_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_post_comment_for_pull_request_request(
+        &self,
+        input: PostCommentForPullRequestInput,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "codecommit", &self.region, "/");
+        request.set_operation("PostCommentForPullRequest");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -16401,195 +19616,375 @@ impl CodeCommit for CodeCommitClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `post_comment_reply`, also returning the metadata of the response.
+    pub async fn post_comment_reply_with_metadata(
+        &self,
+        input: PostCommentReplyInput,
+    ) -> Result<(PostCommentReplyOutput, ResponseMetadata), RusotoError<PostCommentReplyError>>
+    {
+        let request = self.build_post_comment_reply_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<PostCommentForPullRequestOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<PostCommentReplyOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(PostCommentForPullRequestError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(PostCommentReplyError::from_response(response))
         }
     }
 
-    /// <p>Posts a comment in reply to an existing comment on a comparison between commits or a pull request.</p>
-    async fn post_comment_reply(
+    /// Presigns the request `post_comment_reply` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_post_comment_reply(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: PostCommentReplyInput,
-    ) -> Result<PostCommentReplyOutput, RusotoError<PostCommentReplyError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_post_comment_reply_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_post_comment_reply_request(&self, input: PostCommentReplyInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "codecommit", &self.region, "/");
+        request.set_operation("PostCommentReply");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "CodeCommit_20150413.PostCommentReply");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `put_file`, also returning the metadata of the response.
+    pub async fn put_file_with_metadata(
+        &self,
+        input: PutFileInput,
+    ) -> Result<(PutFileOutput, ResponseMetadata), RusotoError<PutFileError>> {
+        let request = self.build_put_file_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<PostCommentReplyOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result =
+                proto::json::ResponsePayload::new(&response).deserialize::<PutFileOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(PostCommentReplyError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(PutFileError::from_response(response))
         }
     }
 
-    /// <p>Adds or updates a file in a branch in an AWS CodeCommit repository, and generates a commit for the addition in the specified branch.</p>
-    async fn put_file(
+    /// Presigns the request `put_file` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_put_file(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: PutFileInput,
-    ) -> Result<PutFileOutput, RusotoError<PutFileError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_put_file_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_put_file_request(&self, input: PutFileInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "codecommit", &self.region, "/");
+        request.set_operation("PutFile");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "CodeCommit_20150413.PutFile");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `put_repository_triggers`, also returning the metadata of the response.
+    pub async fn put_repository_triggers_with_metadata(
+        &self,
+        input: PutRepositoryTriggersInput,
+    ) -> Result<
+        (PutRepositoryTriggersOutput, ResponseMetadata),
+        RusotoError<PutRepositoryTriggersError>,
+    > {
+        let request = self.build_put_repository_triggers_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<PutFileOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<PutRepositoryTriggersOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(PutFileError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(PutRepositoryTriggersError::from_response(response))
         }
     }
 
-    /// <p>Replaces all triggers for a repository. Used to create or delete triggers.</p>
-    async fn put_repository_triggers(
+    /// Presigns the request `put_repository_triggers` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_put_repository_triggers(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: PutRepositoryTriggersInput,
-    ) -> Result<PutRepositoryTriggersOutput, RusotoError<PutRepositoryTriggersError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_put_repository_triggers_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_put_repository_triggers_request(
+        &self,
+        input: PutRepositoryTriggersInput,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "codecommit", &self.region, "/");
+        request.set_operation("PutRepositoryTriggers");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "CodeCommit_20150413.PutRepositoryTriggers");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `tag_resource`, also returning the metadata of the response.
+    pub async fn tag_resource_with_metadata(
+        &self,
+        input: TagResourceInput,
+    ) -> Result<((), ResponseMetadata), RusotoError<TagResourceError>> {
+        let request = self.build_tag_resource_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<PutRepositoryTriggersOutput, _>()
+            std::mem::drop(response);
+            Ok(((), metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(PutRepositoryTriggersError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(TagResourceError::from_response(response))
         }
     }
 
-    /// <p>Adds or updates tags for a resource in AWS CodeCommit. For a list of valid resources in AWS CodeCommit, see <a href="https://docs.aws.amazon.com/codecommit/latest/userguide/auth-and-access-control-iam-access-control-identity-based.html#arn-formats">CodeCommit Resources and Operations</a> in the <i>AWS CodeCommit User Guide</i>.</p>
-    async fn tag_resource(
+    /// Presigns the request `tag_resource` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_tag_resource(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: TagResourceInput,
-    ) -> Result<(), RusotoError<TagResourceError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_tag_resource_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_tag_resource_request(&self, input: TagResourceInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "codecommit", &self.region, "/");
+        request.set_operation("TagResource");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "CodeCommit_20150413.TagResource");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `test_repository_triggers`, also returning the metadata of the response.
+    pub async fn test_repository_triggers_with_metadata(
+        &self,
+        input: TestRepositoryTriggersInput,
+    ) -> Result<
+        (TestRepositoryTriggersOutput, ResponseMetadata),
+        RusotoError<TestRepositoryTriggersError>,
+    > {
+        let request = self.build_test_repository_triggers_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            std::mem::drop(response);
-            Ok(())
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<TestRepositoryTriggersOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(TagResourceError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(TestRepositoryTriggersError::from_response(response))
         }
     }
 
-    /// <p>Tests the functionality of repository triggers by sending information to the trigger target. If real data is available in the repository, the test sends data from the last commit. If no data is available, sample data is generated.</p>
-    async fn test_repository_triggers(
+    /// Presigns the request `test_repository_triggers` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_test_repository_triggers(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: TestRepositoryTriggersInput,
-    ) -> Result<TestRepositoryTriggersOutput, RusotoError<TestRepositoryTriggersError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_test_repository_triggers_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_test_repository_triggers_request(
+        &self,
+        input: TestRepositoryTriggersInput,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "codecommit", &self.region, "/");
+        request.set_operation("TestRepositoryTriggers");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "CodeCommit_20150413.TestRepositoryTriggers");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `untag_resource`, also returning the metadata of the response.
+    pub async fn untag_resource_with_metadata(
+        &self,
+        input: UntagResourceInput,
+    ) -> Result<((), ResponseMetadata), RusotoError<UntagResourceError>> {
+        let request = self.build_untag_resource_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<TestRepositoryTriggersOutput, _>()
+            std::mem::drop(response);
+            Ok(((), metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(TestRepositoryTriggersError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(UntagResourceError::from_response(response))
         }
     }
 
-    /// <p>Removes tags for a resource in AWS CodeCommit. For a list of valid resources in AWS CodeCommit, see <a href="https://docs.aws.amazon.com/codecommit/latest/userguide/auth-and-access-control-iam-access-control-identity-based.html#arn-formats">CodeCommit Resources and Operations</a> in the <i>AWS CodeCommit User Guide</i>.</p>
-    async fn untag_resource(
+    /// Presigns the request `untag_resource` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_untag_resource(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UntagResourceInput,
-    ) -> Result<(), RusotoError<UntagResourceError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_untag_resource_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_untag_resource_request(&self, input: UntagResourceInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "codecommit", &self.region, "/");
+        request.set_operation("UntagResource");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "CodeCommit_20150413.UntagResource");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `update_approval_rule_template_content`, also returning the metadata of the response.
+    pub async fn update_approval_rule_template_content_with_metadata(
+        &self,
+        input: UpdateApprovalRuleTemplateContentInput,
+    ) -> Result<
+        (UpdateApprovalRuleTemplateContentOutput, ResponseMetadata),
+        RusotoError<UpdateApprovalRuleTemplateContentError>,
+    > {
+        let request = self.build_update_approval_rule_template_content_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            std::mem::drop(response);
-            Ok(())
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<UpdateApprovalRuleTemplateContentOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(UntagResourceError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(UpdateApprovalRuleTemplateContentError::from_response(
+                response,
+            ))
         }
     }
 
-    /// <p>Updates the content of an approval rule template. You can change the number of required approvals, the membership of the approval rule, and whether an approval pool is defined.</p>
-    async fn update_approval_rule_template_content(
+    /// Presigns the request `update_approval_rule_template_content` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_approval_rule_template_content(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UpdateApprovalRuleTemplateContentInput,
-    ) -> Result<
-        UpdateApprovalRuleTemplateContentOutput,
-        RusotoError<UpdateApprovalRuleTemplateContentError>,
-    > {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_approval_rule_template_content_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_approval_rule_template_content_request(
+        &self,
+        input: UpdateApprovalRuleTemplateContentInput,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "codecommit", &self.region, "/");
+        request.set_operation("UpdateApprovalRuleTemplateContent");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -16599,33 +19994,63 @@ impl CodeCommit for CodeCommitClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `update_approval_rule_template_description`, also returning the metadata of the response.
+    pub async fn update_approval_rule_template_description_with_metadata(
+        &self,
+        input: UpdateApprovalRuleTemplateDescriptionInput,
+    ) -> Result<
+        (
+            UpdateApprovalRuleTemplateDescriptionOutput,
+            ResponseMetadata,
+        ),
+        RusotoError<UpdateApprovalRuleTemplateDescriptionError>,
+    > {
+        let request = self.build_update_approval_rule_template_description_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<UpdateApprovalRuleTemplateContentOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<UpdateApprovalRuleTemplateDescriptionOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(UpdateApprovalRuleTemplateContentError::from_response(
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(UpdateApprovalRuleTemplateDescriptionError::from_response(
                 response,
             ))
         }
     }
 
-    /// <p>Updates the description for a specified approval rule template.</p>
-    async fn update_approval_rule_template_description(
+    /// Presigns the request `update_approval_rule_template_description` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_approval_rule_template_description(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UpdateApprovalRuleTemplateDescriptionInput,
-    ) -> Result<
-        UpdateApprovalRuleTemplateDescriptionOutput,
-        RusotoError<UpdateApprovalRuleTemplateDescriptionError>,
-    > {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_approval_rule_template_description_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_approval_rule_template_description_request(
+        &self,
+        input: UpdateApprovalRuleTemplateDescriptionInput,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "codecommit", &self.region, "/");
+        request.set_operation("UpdateApprovalRuleTemplateDescription");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -16635,33 +20060,58 @@ impl CodeCommit for CodeCommitClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `update_approval_rule_template_name`, also returning the metadata of the response.
+    pub async fn update_approval_rule_template_name_with_metadata(
+        &self,
+        input: UpdateApprovalRuleTemplateNameInput,
+    ) -> Result<
+        (UpdateApprovalRuleTemplateNameOutput, ResponseMetadata),
+        RusotoError<UpdateApprovalRuleTemplateNameError>,
+    > {
+        let request = self.build_update_approval_rule_template_name_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<UpdateApprovalRuleTemplateDescriptionOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<UpdateApprovalRuleTemplateNameOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(UpdateApprovalRuleTemplateDescriptionError::from_response(
-                response,
-            ))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(UpdateApprovalRuleTemplateNameError::from_response(response))
         }
     }
 
-    /// <p>Updates the name of a specified approval rule template.</p>
-    async fn update_approval_rule_template_name(
+    /// Presigns the request `update_approval_rule_template_name` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_approval_rule_template_name(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UpdateApprovalRuleTemplateNameInput,
-    ) -> Result<
-        UpdateApprovalRuleTemplateNameOutput,
-        RusotoError<UpdateApprovalRuleTemplateNameError>,
-    > {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_approval_rule_template_name_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_approval_rule_template_name_request(
+        &self,
+        input: UpdateApprovalRuleTemplateNameInput,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "codecommit", &self.region, "/");
+        request.set_operation("UpdateApprovalRuleTemplateName");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -16671,85 +20121,162 @@ impl CodeCommit for CodeCommitClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `update_comment`, also returning the metadata of the response.
+    pub async fn update_comment_with_metadata(
+        &self,
+        input: UpdateCommentInput,
+    ) -> Result<(UpdateCommentOutput, ResponseMetadata), RusotoError<UpdateCommentError>> {
+        let request = self.build_update_comment_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<UpdateApprovalRuleTemplateNameOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<UpdateCommentOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(UpdateApprovalRuleTemplateNameError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(UpdateCommentError::from_response(response))
         }
     }
 
-    /// <p>Replaces the contents of a comment.</p>
-    async fn update_comment(
+    /// Presigns the request `update_comment` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_comment(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UpdateCommentInput,
-    ) -> Result<UpdateCommentOutput, RusotoError<UpdateCommentError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_comment_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_comment_request(&self, input: UpdateCommentInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "codecommit", &self.region, "/");
+        request.set_operation("UpdateComment");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "CodeCommit_20150413.UpdateComment");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `update_default_branch`, also returning the metadata of the response.
+    pub async fn update_default_branch_with_metadata(
+        &self,
+        input: UpdateDefaultBranchInput,
+    ) -> Result<((), ResponseMetadata), RusotoError<UpdateDefaultBranchError>> {
+        let request = self.build_update_default_branch_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<UpdateCommentOutput, _>()
+            std::mem::drop(response);
+            Ok(((), metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(UpdateCommentError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(UpdateDefaultBranchError::from_response(response))
         }
     }
 
-    /// <p><p>Sets or changes the default branch name for the specified repository.</p> <note> <p>If you use this operation to change the default branch name to the current default branch name, a success message is returned even though the default branch did not change.</p> </note></p>
-    async fn update_default_branch(
+    /// Presigns the request `update_default_branch` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_default_branch(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UpdateDefaultBranchInput,
-    ) -> Result<(), RusotoError<UpdateDefaultBranchError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_default_branch_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_default_branch_request(
+        &self,
+        input: UpdateDefaultBranchInput,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "codecommit", &self.region, "/");
+        request.set_operation("UpdateDefaultBranch");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "CodeCommit_20150413.UpdateDefaultBranch");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `update_pull_request_approval_rule_content`, also returning the metadata of the response.
+    pub async fn update_pull_request_approval_rule_content_with_metadata(
+        &self,
+        input: UpdatePullRequestApprovalRuleContentInput,
+    ) -> Result<
+        (UpdatePullRequestApprovalRuleContentOutput, ResponseMetadata),
+        RusotoError<UpdatePullRequestApprovalRuleContentError>,
+    > {
+        let request = self.build_update_pull_request_approval_rule_content_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            std::mem::drop(response);
-            Ok(())
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<UpdatePullRequestApprovalRuleContentOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(UpdateDefaultBranchError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(UpdatePullRequestApprovalRuleContentError::from_response(
+                response,
+            ))
         }
     }
 
-    /// <p>Updates the structure of an approval rule created specifically for a pull request. For example, you can change the number of required approvers and the approval pool for approvers. </p>
-    async fn update_pull_request_approval_rule_content(
+    /// Presigns the request `update_pull_request_approval_rule_content` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_pull_request_approval_rule_content(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UpdatePullRequestApprovalRuleContentInput,
-    ) -> Result<
-        UpdatePullRequestApprovalRuleContentOutput,
-        RusotoError<UpdatePullRequestApprovalRuleContentError>,
-    > {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_pull_request_approval_rule_content_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_pull_request_approval_rule_content_request(
+        &self,
+        input: UpdatePullRequestApprovalRuleContentInput,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "codecommit", &self.region, "/");
+        request.set_operation("UpdatePullRequestApprovalRuleContent");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -16759,30 +20286,50 @@ impl CodeCommit for CodeCommitClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `update_pull_request_approval_state`, also returning the metadata of the response.
+    pub async fn update_pull_request_approval_state_with_metadata(
+        &self,
+        input: UpdatePullRequestApprovalStateInput,
+    ) -> Result<((), ResponseMetadata), RusotoError<UpdatePullRequestApprovalStateError>> {
+        let request = self.build_update_pull_request_approval_state_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<UpdatePullRequestApprovalRuleContentOutput, _>()
+            std::mem::drop(response);
+            Ok(((), metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(UpdatePullRequestApprovalRuleContentError::from_response(
-                response,
-            ))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(UpdatePullRequestApprovalStateError::from_response(response))
         }
     }
 
-    /// <p>Updates the state of a user's approval on a pull request. The user is derived from the signed-in account when the request is made.</p>
-    async fn update_pull_request_approval_state(
+    /// Presigns the request `update_pull_request_approval_state` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_pull_request_approval_state(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UpdatePullRequestApprovalStateInput,
-    ) -> Result<(), RusotoError<UpdatePullRequestApprovalStateError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_pull_request_approval_state_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_pull_request_approval_state_request(
+        &self,
+        input: UpdatePullRequestApprovalStateInput,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "codecommit", &self.region, "/");
+        request.set_operation("UpdatePullRequestApprovalState");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -16792,28 +20339,58 @@ impl CodeCommit for CodeCommitClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `update_pull_request_description`, also returning the metadata of the response.
+    pub async fn update_pull_request_description_with_metadata(
+        &self,
+        input: UpdatePullRequestDescriptionInput,
+    ) -> Result<
+        (UpdatePullRequestDescriptionOutput, ResponseMetadata),
+        RusotoError<UpdatePullRequestDescriptionError>,
+    > {
+        let request = self.build_update_pull_request_description_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            std::mem::drop(response);
-            Ok(())
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<UpdatePullRequestDescriptionOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(UpdatePullRequestApprovalStateError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(UpdatePullRequestDescriptionError::from_response(response))
         }
     }
 
-    /// <p>Replaces the contents of the description of a pull request.</p>
-    async fn update_pull_request_description(
+    /// Presigns the request `update_pull_request_description` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_pull_request_description(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UpdatePullRequestDescriptionInput,
-    ) -> Result<UpdatePullRequestDescriptionOutput, RusotoError<UpdatePullRequestDescriptionError>>
-    {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_pull_request_description_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_pull_request_description_request(
+        &self,
+        input: UpdatePullRequestDescriptionInput,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "codecommit", &self.region, "/");
+        request.set_operation("UpdatePullRequestDescription");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -16823,28 +20400,58 @@ impl CodeCommit for CodeCommitClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
-        let mut response = self
-            .client
-            .sign_and_dispatch(request)
-            .await
+        request
+    }
+
+    /// Calls `update_pull_request_status`, also returning the metadata of the response.
+    pub async fn update_pull_request_status_with_metadata(
+        &self,
+        input: UpdatePullRequestStatusInput,
+    ) -> Result<
+        (UpdatePullRequestStatusOutput, ResponseMetadata),
+        RusotoError<UpdatePullRequestStatusError>,
+    > {
+        let request = self.build_update_pull_request_status_request(input);
+
+        let mut response = self
+            .client
+            .sign_and_dispatch(request)
+            .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<UpdatePullRequestDescriptionOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<UpdatePullRequestStatusOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(UpdatePullRequestDescriptionError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(UpdatePullRequestStatusError::from_response(response))
         }
     }
 
-    /// <p>Updates the status of a pull request. </p>
-    async fn update_pull_request_status(
+    /// Presigns the request `update_pull_request_status` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_pull_request_status(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UpdatePullRequestStatusInput,
-    ) -> Result<UpdatePullRequestStatusOutput, RusotoError<UpdatePullRequestStatusError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_pull_request_status_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_pull_request_status_request(
+        &self,
+        input: UpdatePullRequestStatusInput,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "codecommit", &self.region, "/");
+        request.set_operation("UpdatePullRequestStatus");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -16854,56 +20461,108 @@ impl CodeCommit for CodeCommitClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `update_pull_request_title`, also returning the metadata of the response.
+    pub async fn update_pull_request_title_with_metadata(
+        &self,
+        input: UpdatePullRequestTitleInput,
+    ) -> Result<
+        (UpdatePullRequestTitleOutput, ResponseMetadata),
+        RusotoError<UpdatePullRequestTitleError>,
+    > {
+        let request = self.build_update_pull_request_title_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<UpdatePullRequestStatusOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<UpdatePullRequestTitleOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(UpdatePullRequestStatusError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(UpdatePullRequestTitleError::from_response(response))
         }
     }
 
-    /// <p>Replaces the title of a pull request.</p>
-    async fn update_pull_request_title(
+    /// Presigns the request `update_pull_request_title` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_pull_request_title(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UpdatePullRequestTitleInput,
-    ) -> Result<UpdatePullRequestTitleOutput, RusotoError<UpdatePullRequestTitleError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_pull_request_title_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_pull_request_title_request(
+        &self,
+        input: UpdatePullRequestTitleInput,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "codecommit", &self.region, "/");
+        request.set_operation("UpdatePullRequestTitle");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "CodeCommit_20150413.UpdatePullRequestTitle");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `update_repository_description`, also returning the metadata of the response.
+    pub async fn update_repository_description_with_metadata(
+        &self,
+        input: UpdateRepositoryDescriptionInput,
+    ) -> Result<((), ResponseMetadata), RusotoError<UpdateRepositoryDescriptionError>> {
+        let request = self.build_update_repository_description_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<UpdatePullRequestTitleOutput, _>()
+            std::mem::drop(response);
+            Ok(((), metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(UpdatePullRequestTitleError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(UpdateRepositoryDescriptionError::from_response(response))
         }
     }
 
-    /// <p><p>Sets or changes the comment or description for a repository.</p> <note> <p>The description field for a repository accepts all HTML characters and all valid Unicode characters. Applications that do not HTML-encode the description and display it in a webpage can expose users to potentially malicious code. Make sure that you HTML-encode the description field in any application that uses this API to display the repository description on a webpage.</p> </note></p>
-    async fn update_repository_description(
+    /// Presigns the request `update_repository_description` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_repository_description(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UpdateRepositoryDescriptionInput,
-    ) -> Result<(), RusotoError<UpdateRepositoryDescriptionError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_repository_description_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_repository_description_request(
+        &self,
+        input: UpdateRepositoryDescriptionInput,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "codecommit", &self.region, "/");
+        request.set_operation("UpdateRepositoryDescription");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -16913,45 +20572,1117 @@ impl CodeCommit for CodeCommitClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `update_repository_name`, also returning the metadata of the response.
+    pub async fn update_repository_name_with_metadata(
+        &self,
+        input: UpdateRepositoryNameInput,
+    ) -> Result<((), ResponseMetadata), RusotoError<UpdateRepositoryNameError>> {
+        let request = self.build_update_repository_name_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
             std::mem::drop(response);
-            Ok(())
+            Ok(((), metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(UpdateRepositoryDescriptionError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(UpdateRepositoryNameError::from_response(response))
         }
     }
 
-    /// <p>Renames a repository. The repository name must be unique across the calling AWS account. Repository names are limited to 100 alphanumeric, dash, and underscore characters, and cannot include certain characters. The suffix .git is prohibited. For more information about the limits on repository names, see <a href="https://docs.aws.amazon.com/codecommit/latest/userguide/limits.html">Limits</a> in the AWS CodeCommit User Guide.</p>
-    async fn update_repository_name(
+    /// Presigns the request `update_repository_name` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_repository_name(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UpdateRepositoryNameInput,
-    ) -> Result<(), RusotoError<UpdateRepositoryNameError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_repository_name_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_repository_name_request(
+        &self,
+        input: UpdateRepositoryNameInput,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "codecommit", &self.region, "/");
+        request.set_operation("UpdateRepositoryName");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "CodeCommit_20150413.UpdateRepositoryName");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
-        let mut response = self
-            .client
-            .sign_and_dispatch(request)
+        request
+    }
+
+    /// The pages of `DescribePullRequestEvents` results for `input`, requesting each next page with the `nextToken` of the last one as its `nextToken`.
+    /// The page size is the `maxResults` of `input`.
+    pub fn describe_pull_request_events_pages(
+        &self,
+        input: DescribePullRequestEventsInput,
+    ) -> impl futures::Stream<
+        Item = Result<DescribePullRequestEventsOutput, RusotoError<DescribePullRequestEventsError>>,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: CodeCommitClient, input| async move {
+                client.describe_pull_request_events(input).await
+            },
+            |input: &DescribePullRequestEventsInput, output: &DescribePullRequestEventsOutput| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(DescribePullRequestEventsInput {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `pullRequestEvents` of every page of `DescribePullRequestEvents` results for `input`, as `describe_pull_request_events_pages` requests them.
+    pub fn describe_pull_request_events_stream(
+        &self,
+        input: DescribePullRequestEventsInput,
+    ) -> impl futures::Stream<
+        Item = Result<PullRequestEvent, RusotoError<DescribePullRequestEventsError>>,
+    > + Send {
+        rusoto_core::pagination::items(self.describe_pull_request_events_pages(input), |page| {
+            page.pull_request_events
+        })
+    }
+
+    /// The pages of `GetCommentsForComparedCommit` results for `input`, requesting each next page with the `nextToken` of the last one as its `nextToken`.
+    /// The page size is the `maxResults` of `input`.
+    pub fn get_comments_for_compared_commit_pages(
+        &self,
+        input: GetCommentsForComparedCommitInput,
+    ) -> impl futures::Stream<
+        Item = Result<
+            GetCommentsForComparedCommitOutput,
+            RusotoError<GetCommentsForComparedCommitError>,
+        >,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: CodeCommitClient, input| async move {
+                client.get_comments_for_compared_commit(input).await
+            },
+            |input: &GetCommentsForComparedCommitInput,
+             output: &GetCommentsForComparedCommitOutput| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(GetCommentsForComparedCommitInput {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `commentsForComparedCommitData` of every page of `GetCommentsForComparedCommit` results for `input`, as `get_comments_for_compared_commit_pages` requests them.
+    pub fn get_comments_for_compared_commit_stream(
+        &self,
+        input: GetCommentsForComparedCommitInput,
+    ) -> impl futures::Stream<
+        Item = Result<CommentsForComparedCommit, RusotoError<GetCommentsForComparedCommitError>>,
+    > + Send {
+        rusoto_core::pagination::items(self.get_comments_for_compared_commit_pages(input), |page| {
+            page.comments_for_compared_commit_data.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `GetCommentsForPullRequest` results for `input`, requesting each next page with the `nextToken` of the last one as its `nextToken`.
+    /// The page size is the `maxResults` of `input`.
+    pub fn get_comments_for_pull_request_pages(
+        &self,
+        input: GetCommentsForPullRequestInput,
+    ) -> impl futures::Stream<
+        Item = Result<GetCommentsForPullRequestOutput, RusotoError<GetCommentsForPullRequestError>>,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: CodeCommitClient, input| async move {
+                client.get_comments_for_pull_request(input).await
+            },
+            |input: &GetCommentsForPullRequestInput, output: &GetCommentsForPullRequestOutput| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(GetCommentsForPullRequestInput {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `commentsForPullRequestData` of every page of `GetCommentsForPullRequest` results for `input`, as `get_comments_for_pull_request_pages` requests them.
+    pub fn get_comments_for_pull_request_stream(
+        &self,
+        input: GetCommentsForPullRequestInput,
+    ) -> impl futures::Stream<
+        Item = Result<CommentsForPullRequest, RusotoError<GetCommentsForPullRequestError>>,
+    > + Send {
+        rusoto_core::pagination::items(self.get_comments_for_pull_request_pages(input), |page| {
+            page.comments_for_pull_request_data.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `GetDifferences` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `MaxResults` of `input`.
+    pub fn get_differences_pages(
+        &self,
+        input: GetDifferencesInput,
+    ) -> impl futures::Stream<Item = Result<GetDifferencesOutput, RusotoError<GetDifferencesError>>> + Send
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: CodeCommitClient, input| async move { client.get_differences(input).await },
+            |input: &GetDifferencesInput, output: &GetDifferencesOutput| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(GetDifferencesInput {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `differences` of every page of `GetDifferences` results for `input`, as `get_differences_pages` requests them.
+    pub fn get_differences_stream(
+        &self,
+        input: GetDifferencesInput,
+    ) -> impl futures::Stream<Item = Result<Difference, RusotoError<GetDifferencesError>>> + Send
+    {
+        rusoto_core::pagination::items(self.get_differences_pages(input), |page| {
+            page.differences.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `ListBranches` results for `input`, requesting each next page with the `nextToken` of the last one as its `nextToken`.
+    pub fn list_branches_pages(
+        &self,
+        input: ListBranchesInput,
+    ) -> impl futures::Stream<Item = Result<ListBranchesOutput, RusotoError<ListBranchesError>>> + Send
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: CodeCommitClient, input| async move { client.list_branches(input).await },
+            |input: &ListBranchesInput, output: &ListBranchesOutput| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListBranchesInput {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `branches` of every page of `ListBranches` results for `input`, as `list_branches_pages` requests them.
+    pub fn list_branches_stream(
+        &self,
+        input: ListBranchesInput,
+    ) -> impl futures::Stream<Item = Result<String, RusotoError<ListBranchesError>>> + Send {
+        rusoto_core::pagination::items(self.list_branches_pages(input), |page| {
+            page.branches.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `ListPullRequests` results for `input`, requesting each next page with the `nextToken` of the last one as its `nextToken`.
+    /// The page size is the `maxResults` of `input`.
+    pub fn list_pull_requests_pages(
+        &self,
+        input: ListPullRequestsInput,
+    ) -> impl futures::Stream<
+        Item = Result<ListPullRequestsOutput, RusotoError<ListPullRequestsError>>,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: CodeCommitClient, input| async move { client.list_pull_requests(input).await },
+            |input: &ListPullRequestsInput, output: &ListPullRequestsOutput| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListPullRequestsInput {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `pullRequestIds` of every page of `ListPullRequests` results for `input`, as `list_pull_requests_pages` requests them.
+    pub fn list_pull_requests_stream(
+        &self,
+        input: ListPullRequestsInput,
+    ) -> impl futures::Stream<Item = Result<String, RusotoError<ListPullRequestsError>>> + Send
+    {
+        rusoto_core::pagination::items(self.list_pull_requests_pages(input), |page| {
+            page.pull_request_ids
+        })
+    }
+
+    /// The pages of `ListRepositories` results for `input`, requesting each next page with the `nextToken` of the last one as its `nextToken`.
+    pub fn list_repositories_pages(
+        &self,
+        input: ListRepositoriesInput,
+    ) -> impl futures::Stream<
+        Item = Result<ListRepositoriesOutput, RusotoError<ListRepositoriesError>>,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: CodeCommitClient, input| async move { client.list_repositories(input).await },
+            |input: &ListRepositoriesInput, output: &ListRepositoriesOutput| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListRepositoriesInput {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `repositories` of every page of `ListRepositories` results for `input`, as `list_repositories_pages` requests them.
+    pub fn list_repositories_stream(
+        &self,
+        input: ListRepositoriesInput,
+    ) -> impl futures::Stream<Item = Result<RepositoryNameIdPair, RusotoError<ListRepositoriesError>>>
+           + Send {
+        rusoto_core::pagination::items(self.list_repositories_pages(input), |page| {
+            page.repositories.unwrap_or_default()
+        })
+    }
+}
+
+#[async_trait]
+impl CodeCommit for CodeCommitClient {
+    /// <p>Creates an association between an approval rule template and a specified repository. Then, the next time a pull request is created in the repository where the destination reference (if specified) matches the destination reference (branch) for the pull request, an approval rule that matches the template conditions is automatically created for that pull request. If no destination references are specified in the template, an approval rule that matches the template contents is created for all pull requests in that repository.</p>
+    async fn associate_approval_rule_template_with_repository(
+        &self,
+        input: AssociateApprovalRuleTemplateWithRepositoryInput,
+    ) -> Result<(), RusotoError<AssociateApprovalRuleTemplateWithRepositoryError>> {
+        self.associate_approval_rule_template_with_repository_with_metadata(input)
             .await
-            .map_err(RusotoError::from)?;
-        if response.status.is_success() {
-            std::mem::drop(response);
-            Ok(())
-        } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(UpdateRepositoryNameError::from_response(response))
-        }
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Creates an association between an approval rule template and one or more specified repositories. </p>
+    async fn batch_associate_approval_rule_template_with_repositories(
+        &self,
+        input: BatchAssociateApprovalRuleTemplateWithRepositoriesInput,
+    ) -> Result<
+        BatchAssociateApprovalRuleTemplateWithRepositoriesOutput,
+        RusotoError<BatchAssociateApprovalRuleTemplateWithRepositoriesError>,
+    > {
+        self.batch_associate_approval_rule_template_with_repositories_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns information about one or more merge conflicts in the attempted merge of two commit specifiers using the squash or three-way merge strategy.</p>
+    async fn batch_describe_merge_conflicts(
+        &self,
+        input: BatchDescribeMergeConflictsInput,
+    ) -> Result<BatchDescribeMergeConflictsOutput, RusotoError<BatchDescribeMergeConflictsError>>
+    {
+        self.batch_describe_merge_conflicts_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Removes the association between an approval rule template and one or more specified repositories. </p>
+    async fn batch_disassociate_approval_rule_template_from_repositories(
+        &self,
+        input: BatchDisassociateApprovalRuleTemplateFromRepositoriesInput,
+    ) -> Result<
+        BatchDisassociateApprovalRuleTemplateFromRepositoriesOutput,
+        RusotoError<BatchDisassociateApprovalRuleTemplateFromRepositoriesError>,
+    > {
+        self.batch_disassociate_approval_rule_template_from_repositories_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns information about the contents of one or more commits in a repository.</p>
+    async fn batch_get_commits(
+        &self,
+        input: BatchGetCommitsInput,
+    ) -> Result<BatchGetCommitsOutput, RusotoError<BatchGetCommitsError>> {
+        self.batch_get_commits_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Returns information about one or more repositories.</p> <note> <p>The description field for a repository accepts all HTML characters and all valid Unicode characters. Applications that do not HTML-encode the description and display it in a webpage can expose users to potentially malicious code. Make sure that you HTML-encode the description field in any application that uses this API to display the repository description on a webpage.</p> </note></p>
+    async fn batch_get_repositories(
+        &self,
+        input: BatchGetRepositoriesInput,
+    ) -> Result<BatchGetRepositoriesOutput, RusotoError<BatchGetRepositoriesError>> {
+        self.batch_get_repositories_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Creates a template for approval rules that can then be associated with one or more repositories in your AWS account. When you associate a template with a repository, AWS CodeCommit creates an approval rule that matches the conditions of the template for all pull requests that meet the conditions of the template. For more information, see <a>AssociateApprovalRuleTemplateWithRepository</a>.</p>
+    async fn create_approval_rule_template(
+        &self,
+        input: CreateApprovalRuleTemplateInput,
+    ) -> Result<CreateApprovalRuleTemplateOutput, RusotoError<CreateApprovalRuleTemplateError>>
+    {
+        self.create_approval_rule_template_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Creates a branch in a repository and points the branch to a commit.</p> <note> <p>Calling the create branch operation does not set a repository&#39;s default branch. To do this, call the update default branch operation.</p> </note></p>
+    async fn create_branch(
+        &self,
+        input: CreateBranchInput,
+    ) -> Result<(), RusotoError<CreateBranchError>> {
+        self.create_branch_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Creates a commit for a repository on the tip of a specified branch.</p>
+    async fn create_commit(
+        &self,
+        input: CreateCommitInput,
+    ) -> Result<CreateCommitOutput, RusotoError<CreateCommitError>> {
+        self.create_commit_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Creates a pull request in the specified repository.</p>
+    async fn create_pull_request(
+        &self,
+        input: CreatePullRequestInput,
+    ) -> Result<CreatePullRequestOutput, RusotoError<CreatePullRequestError>> {
+        self.create_pull_request_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Creates an approval rule for a pull request.</p>
+    async fn create_pull_request_approval_rule(
+        &self,
+        input: CreatePullRequestApprovalRuleInput,
+    ) -> Result<CreatePullRequestApprovalRuleOutput, RusotoError<CreatePullRequestApprovalRuleError>>
+    {
+        self.create_pull_request_approval_rule_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Creates a new, empty repository.</p>
+    async fn create_repository(
+        &self,
+        input: CreateRepositoryInput,
+    ) -> Result<CreateRepositoryOutput, RusotoError<CreateRepositoryError>> {
+        self.create_repository_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Creates an unreferenced commit that represents the result of merging two branches using a specified merge strategy. This can help you determine the outcome of a potential merge. This API cannot be used with the fast-forward merge strategy because that strategy does not create a merge commit.</p> <note> <p>This unreferenced merge commit can only be accessed using the GetCommit API or through git commands such as git fetch. To retrieve this commit, you must specify its commit ID or otherwise reference it.</p> </note></p>
+    async fn create_unreferenced_merge_commit(
+        &self,
+        input: CreateUnreferencedMergeCommitInput,
+    ) -> Result<CreateUnreferencedMergeCommitOutput, RusotoError<CreateUnreferencedMergeCommitError>>
+    {
+        self.create_unreferenced_merge_commit_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes a specified approval rule template. Deleting a template does not remove approval rules on pull requests already created with the template.</p>
+    async fn delete_approval_rule_template(
+        &self,
+        input: DeleteApprovalRuleTemplateInput,
+    ) -> Result<DeleteApprovalRuleTemplateOutput, RusotoError<DeleteApprovalRuleTemplateError>>
+    {
+        self.delete_approval_rule_template_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes a branch from a repository, unless that branch is the default branch for the repository. </p>
+    async fn delete_branch(
+        &self,
+        input: DeleteBranchInput,
+    ) -> Result<DeleteBranchOutput, RusotoError<DeleteBranchError>> {
+        self.delete_branch_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes the content of a comment made on a change, file, or commit in a repository.</p>
+    async fn delete_comment_content(
+        &self,
+        input: DeleteCommentContentInput,
+    ) -> Result<DeleteCommentContentOutput, RusotoError<DeleteCommentContentError>> {
+        self.delete_comment_content_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes a specified file from a specified branch. A commit is created on the branch that contains the revision. The file still exists in the commits earlier to the commit that contains the deletion.</p>
+    async fn delete_file(
+        &self,
+        input: DeleteFileInput,
+    ) -> Result<DeleteFileOutput, RusotoError<DeleteFileError>> {
+        self.delete_file_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes an approval rule from a specified pull request. Approval rules can be deleted from a pull request only if the pull request is open, and if the approval rule was created specifically for a pull request and not generated from an approval rule template associated with the repository where the pull request was created. You cannot delete an approval rule from a merged or closed pull request.</p>
+    async fn delete_pull_request_approval_rule(
+        &self,
+        input: DeletePullRequestApprovalRuleInput,
+    ) -> Result<DeletePullRequestApprovalRuleOutput, RusotoError<DeletePullRequestApprovalRuleError>>
+    {
+        self.delete_pull_request_approval_rule_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Deletes a repository. If a specified repository was already deleted, a null repository ID is returned.</p> <important> <p>Deleting a repository also deletes all associated objects and metadata. After a repository is deleted, all future push calls to the deleted repository fail.</p> </important></p>
+    async fn delete_repository(
+        &self,
+        input: DeleteRepositoryInput,
+    ) -> Result<DeleteRepositoryOutput, RusotoError<DeleteRepositoryError>> {
+        self.delete_repository_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns information about one or more merge conflicts in the attempted merge of two commit specifiers using the squash or three-way merge strategy. If the merge option for the attempted merge is specified as FAST_FORWARD_MERGE, an exception is thrown.</p>
+    async fn describe_merge_conflicts(
+        &self,
+        input: DescribeMergeConflictsInput,
+    ) -> Result<DescribeMergeConflictsOutput, RusotoError<DescribeMergeConflictsError>> {
+        self.describe_merge_conflicts_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns information about one or more pull request events.</p>
+    async fn describe_pull_request_events(
+        &self,
+        input: DescribePullRequestEventsInput,
+    ) -> Result<DescribePullRequestEventsOutput, RusotoError<DescribePullRequestEventsError>> {
+        self.describe_pull_request_events_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Removes the association between a template and a repository so that approval rules based on the template are not automatically created when pull requests are created in the specified repository. This does not delete any approval rules previously created for pull requests through the template association.</p>
+    async fn disassociate_approval_rule_template_from_repository(
+        &self,
+        input: DisassociateApprovalRuleTemplateFromRepositoryInput,
+    ) -> Result<(), RusotoError<DisassociateApprovalRuleTemplateFromRepositoryError>> {
+        self.disassociate_approval_rule_template_from_repository_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Evaluates whether a pull request has met all the conditions specified in its associated approval rules.</p>
+    async fn evaluate_pull_request_approval_rules(
+        &self,
+        input: EvaluatePullRequestApprovalRulesInput,
+    ) -> Result<
+        EvaluatePullRequestApprovalRulesOutput,
+        RusotoError<EvaluatePullRequestApprovalRulesError>,
+    > {
+        self.evaluate_pull_request_approval_rules_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns information about a specified approval rule template.</p>
+    async fn get_approval_rule_template(
+        &self,
+        input: GetApprovalRuleTemplateInput,
+    ) -> Result<GetApprovalRuleTemplateOutput, RusotoError<GetApprovalRuleTemplateError>> {
+        self.get_approval_rule_template_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns the base-64 encoded content of an individual blob in a repository.</p>
+    async fn get_blob(
+        &self,
+        input: GetBlobInput,
+    ) -> Result<GetBlobOutput, RusotoError<GetBlobError>> {
+        self.get_blob_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns information about a repository branch, including its name and the last commit ID.</p>
+    async fn get_branch(
+        &self,
+        input: GetBranchInput,
+    ) -> Result<GetBranchOutput, RusotoError<GetBranchError>> {
+        self.get_branch_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns the content of a comment made on a change, file, or commit in a repository.</p>
+    async fn get_comment(
+        &self,
+        input: GetCommentInput,
+    ) -> Result<GetCommentOutput, RusotoError<GetCommentError>> {
+        self.get_comment_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns information about comments made on the comparison between two commits.</p>
+    async fn get_comments_for_compared_commit(
+        &self,
+        input: GetCommentsForComparedCommitInput,
+    ) -> Result<GetCommentsForComparedCommitOutput, RusotoError<GetCommentsForComparedCommitError>>
+    {
+        self.get_comments_for_compared_commit_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns comments made on a pull request.</p>
+    async fn get_comments_for_pull_request(
+        &self,
+        input: GetCommentsForPullRequestInput,
+    ) -> Result<GetCommentsForPullRequestOutput, RusotoError<GetCommentsForPullRequestError>> {
+        self.get_comments_for_pull_request_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns information about a commit, including commit message and committer information.</p>
+    async fn get_commit(
+        &self,
+        input: GetCommitInput,
+    ) -> Result<GetCommitOutput, RusotoError<GetCommitError>> {
+        self.get_commit_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns information about the differences in a valid commit specifier (such as a branch, tag, HEAD, commit ID, or other fully qualified reference). Results can be limited to a specified path.</p>
+    async fn get_differences(
+        &self,
+        input: GetDifferencesInput,
+    ) -> Result<GetDifferencesOutput, RusotoError<GetDifferencesError>> {
+        self.get_differences_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns the base-64 encoded contents of a specified file and its metadata.</p>
+    async fn get_file(
+        &self,
+        input: GetFileInput,
+    ) -> Result<GetFileOutput, RusotoError<GetFileError>> {
+        self.get_file_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns the contents of a specified folder in a repository.</p>
+    async fn get_folder(
+        &self,
+        input: GetFolderInput,
+    ) -> Result<GetFolderOutput, RusotoError<GetFolderError>> {
+        self.get_folder_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns information about a specified merge commit.</p>
+    async fn get_merge_commit(
+        &self,
+        input: GetMergeCommitInput,
+    ) -> Result<GetMergeCommitOutput, RusotoError<GetMergeCommitError>> {
+        self.get_merge_commit_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns information about merge conflicts between the before and after commit IDs for a pull request in a repository.</p>
+    async fn get_merge_conflicts(
+        &self,
+        input: GetMergeConflictsInput,
+    ) -> Result<GetMergeConflictsOutput, RusotoError<GetMergeConflictsError>> {
+        self.get_merge_conflicts_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns information about the merge options available for merging two specified branches. For details about why a merge option is not available, use GetMergeConflicts or DescribeMergeConflicts.</p>
+    async fn get_merge_options(
+        &self,
+        input: GetMergeOptionsInput,
+    ) -> Result<GetMergeOptionsOutput, RusotoError<GetMergeOptionsError>> {
+        self.get_merge_options_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Gets information about a pull request in a specified repository.</p>
+    async fn get_pull_request(
+        &self,
+        input: GetPullRequestInput,
+    ) -> Result<GetPullRequestOutput, RusotoError<GetPullRequestError>> {
+        self.get_pull_request_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Gets information about the approval states for a specified pull request. Approval states only apply to pull requests that have one or more approval rules applied to them.</p>
+    async fn get_pull_request_approval_states(
+        &self,
+        input: GetPullRequestApprovalStatesInput,
+    ) -> Result<GetPullRequestApprovalStatesOutput, RusotoError<GetPullRequestApprovalStatesError>>
+    {
+        self.get_pull_request_approval_states_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns information about whether approval rules have been set aside (overridden) for a pull request, and if so, the Amazon Resource Name (ARN) of the user or identity that overrode the rules and their requirements for the pull request.</p>
+    async fn get_pull_request_override_state(
+        &self,
+        input: GetPullRequestOverrideStateInput,
+    ) -> Result<GetPullRequestOverrideStateOutput, RusotoError<GetPullRequestOverrideStateError>>
+    {
+        self.get_pull_request_override_state_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Returns information about a repository.</p> <note> <p>The description field for a repository accepts all HTML characters and all valid Unicode characters. Applications that do not HTML-encode the description and display it in a webpage can expose users to potentially malicious code. Make sure that you HTML-encode the description field in any application that uses this API to display the repository description on a webpage.</p> </note></p>
+    async fn get_repository(
+        &self,
+        input: GetRepositoryInput,
+    ) -> Result<GetRepositoryOutput, RusotoError<GetRepositoryError>> {
+        self.get_repository_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Gets information about triggers configured for a repository.</p>
+    async fn get_repository_triggers(
+        &self,
+        input: GetRepositoryTriggersInput,
+    ) -> Result<GetRepositoryTriggersOutput, RusotoError<GetRepositoryTriggersError>> {
+        self.get_repository_triggers_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Lists all approval rule templates in the specified AWS Region in your AWS account. If an AWS Region is not specified, the AWS Region where you are signed in is used.</p>
+    async fn list_approval_rule_templates(
+        &self,
+        input: ListApprovalRuleTemplatesInput,
+    ) -> Result<ListApprovalRuleTemplatesOutput, RusotoError<ListApprovalRuleTemplatesError>> {
+        self.list_approval_rule_templates_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Lists all approval rule templates that are associated with a specified repository.</p>
+    async fn list_associated_approval_rule_templates_for_repository(
+        &self,
+        input: ListAssociatedApprovalRuleTemplatesForRepositoryInput,
+    ) -> Result<
+        ListAssociatedApprovalRuleTemplatesForRepositoryOutput,
+        RusotoError<ListAssociatedApprovalRuleTemplatesForRepositoryError>,
+    > {
+        self.list_associated_approval_rule_templates_for_repository_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Gets information about one or more branches in a repository.</p>
+    async fn list_branches(
+        &self,
+        input: ListBranchesInput,
+    ) -> Result<ListBranchesOutput, RusotoError<ListBranchesError>> {
+        self.list_branches_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns a list of pull requests for a specified repository. The return list can be refined by pull request status or pull request author ARN.</p>
+    async fn list_pull_requests(
+        &self,
+        input: ListPullRequestsInput,
+    ) -> Result<ListPullRequestsOutput, RusotoError<ListPullRequestsError>> {
+        self.list_pull_requests_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Gets information about one or more repositories.</p>
+    async fn list_repositories(
+        &self,
+        input: ListRepositoriesInput,
+    ) -> Result<ListRepositoriesOutput, RusotoError<ListRepositoriesError>> {
+        self.list_repositories_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Lists all repositories associated with the specified approval rule template.</p>
+    async fn list_repositories_for_approval_rule_template(
+        &self,
+        input: ListRepositoriesForApprovalRuleTemplateInput,
+    ) -> Result<
+        ListRepositoriesForApprovalRuleTemplateOutput,
+        RusotoError<ListRepositoriesForApprovalRuleTemplateError>,
+    > {
+        self.list_repositories_for_approval_rule_template_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Gets information about AWS tags for a specified Amazon Resource Name (ARN) in AWS CodeCommit. For a list of valid resources in AWS CodeCommit, see <a href="https://docs.aws.amazon.com/codecommit/latest/userguide/auth-and-access-control-iam-access-control-identity-based.html#arn-formats">CodeCommit Resources and Operations</a> in the<i> AWS CodeCommit User Guide</i>.</p>
+    async fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceInput,
+    ) -> Result<ListTagsForResourceOutput, RusotoError<ListTagsForResourceError>> {
+        self.list_tags_for_resource_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Merges two branches using the fast-forward merge strategy.</p>
+    async fn merge_branches_by_fast_forward(
+        &self,
+        input: MergeBranchesByFastForwardInput,
+    ) -> Result<MergeBranchesByFastForwardOutput, RusotoError<MergeBranchesByFastForwardError>>
+    {
+        self.merge_branches_by_fast_forward_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Merges two branches using the squash merge strategy.</p>
+    async fn merge_branches_by_squash(
+        &self,
+        input: MergeBranchesBySquashInput,
+    ) -> Result<MergeBranchesBySquashOutput, RusotoError<MergeBranchesBySquashError>> {
+        self.merge_branches_by_squash_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Merges two specified branches using the three-way merge strategy.</p>
+    async fn merge_branches_by_three_way(
+        &self,
+        input: MergeBranchesByThreeWayInput,
+    ) -> Result<MergeBranchesByThreeWayOutput, RusotoError<MergeBranchesByThreeWayError>> {
+        self.merge_branches_by_three_way_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Attempts to merge the source commit of a pull request into the specified destination branch for that pull request at the specified commit using the fast-forward merge strategy. If the merge is successful, it closes the pull request.</p>
+    async fn merge_pull_request_by_fast_forward(
+        &self,
+        input: MergePullRequestByFastForwardInput,
+    ) -> Result<MergePullRequestByFastForwardOutput, RusotoError<MergePullRequestByFastForwardError>>
+    {
+        self.merge_pull_request_by_fast_forward_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Attempts to merge the source commit of a pull request into the specified destination branch for that pull request at the specified commit using the squash merge strategy. If the merge is successful, it closes the pull request.</p>
+    async fn merge_pull_request_by_squash(
+        &self,
+        input: MergePullRequestBySquashInput,
+    ) -> Result<MergePullRequestBySquashOutput, RusotoError<MergePullRequestBySquashError>> {
+        self.merge_pull_request_by_squash_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Attempts to merge the source commit of a pull request into the specified destination branch for that pull request at the specified commit using the three-way merge strategy. If the merge is successful, it closes the pull request.</p>
+    async fn merge_pull_request_by_three_way(
+        &self,
+        input: MergePullRequestByThreeWayInput,
+    ) -> Result<MergePullRequestByThreeWayOutput, RusotoError<MergePullRequestByThreeWayError>>
+    {
+        self.merge_pull_request_by_three_way_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Sets aside (overrides) all approval rule requirements for a specified pull request.</p>
+    async fn override_pull_request_approval_rules(
+        &self,
+        input: OverridePullRequestApprovalRulesInput,
+    ) -> Result<(), RusotoError<OverridePullRequestApprovalRulesError>> {
+        self.override_pull_request_approval_rules_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Posts a comment on the comparison between two commits.</p>
+    async fn post_comment_for_compared_commit(
+        &self,
+        input: PostCommentForComparedCommitInput,
+    ) -> Result<PostCommentForComparedCommitOutput, RusotoError<PostCommentForComparedCommitError>>
+    {
+        self.post_comment_for_compared_commit_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Posts a comment on a pull request.</p>
+    async fn post_comment_for_pull_request(
+        &self,
+        input: PostCommentForPullRequestInput,
+    ) -> Result<PostCommentForPullRequestOutput, RusotoError<PostCommentForPullRequestError>> {
+        self.post_comment_for_pull_request_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Posts a comment in reply to an existing comment on a comparison between commits or a pull request.</p>
+    async fn post_comment_reply(
+        &self,
+        input: PostCommentReplyInput,
+    ) -> Result<PostCommentReplyOutput, RusotoError<PostCommentReplyError>> {
+        self.post_comment_reply_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Adds or updates a file in a branch in an AWS CodeCommit repository, and generates a commit for the addition in the specified branch.</p>
+    async fn put_file(
+        &self,
+        input: PutFileInput,
+    ) -> Result<PutFileOutput, RusotoError<PutFileError>> {
+        self.put_file_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Replaces all triggers for a repository. Used to create or delete triggers.</p>
+    async fn put_repository_triggers(
+        &self,
+        input: PutRepositoryTriggersInput,
+    ) -> Result<PutRepositoryTriggersOutput, RusotoError<PutRepositoryTriggersError>> {
+        self.put_repository_triggers_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Adds or updates tags for a resource in AWS CodeCommit. For a list of valid resources in AWS CodeCommit, see <a href="https://docs.aws.amazon.com/codecommit/latest/userguide/auth-and-access-control-iam-access-control-identity-based.html#arn-formats">CodeCommit Resources and Operations</a> in the <i>AWS CodeCommit User Guide</i>.</p>
+    async fn tag_resource(
+        &self,
+        input: TagResourceInput,
+    ) -> Result<(), RusotoError<TagResourceError>> {
+        self.tag_resource_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Tests the functionality of repository triggers by sending information to the trigger target. If real data is available in the repository, the test sends data from the last commit. If no data is available, sample data is generated.</p>
+    async fn test_repository_triggers(
+        &self,
+        input: TestRepositoryTriggersInput,
+    ) -> Result<TestRepositoryTriggersOutput, RusotoError<TestRepositoryTriggersError>> {
+        self.test_repository_triggers_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Removes tags for a resource in AWS CodeCommit. For a list of valid resources in AWS CodeCommit, see <a href="https://docs.aws.amazon.com/codecommit/latest/userguide/auth-and-access-control-iam-access-control-identity-based.html#arn-formats">CodeCommit Resources and Operations</a> in the <i>AWS CodeCommit User Guide</i>.</p>
+    async fn untag_resource(
+        &self,
+        input: UntagResourceInput,
+    ) -> Result<(), RusotoError<UntagResourceError>> {
+        self.untag_resource_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Updates the content of an approval rule template. You can change the number of required approvals, the membership of the approval rule, and whether an approval pool is defined.</p>
+    async fn update_approval_rule_template_content(
+        &self,
+        input: UpdateApprovalRuleTemplateContentInput,
+    ) -> Result<
+        UpdateApprovalRuleTemplateContentOutput,
+        RusotoError<UpdateApprovalRuleTemplateContentError>,
+    > {
+        self.update_approval_rule_template_content_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Updates the description for a specified approval rule template.</p>
+    async fn update_approval_rule_template_description(
+        &self,
+        input: UpdateApprovalRuleTemplateDescriptionInput,
+    ) -> Result<
+        UpdateApprovalRuleTemplateDescriptionOutput,
+        RusotoError<UpdateApprovalRuleTemplateDescriptionError>,
+    > {
+        self.update_approval_rule_template_description_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Updates the name of a specified approval rule template.</p>
+    async fn update_approval_rule_template_name(
+        &self,
+        input: UpdateApprovalRuleTemplateNameInput,
+    ) -> Result<
+        UpdateApprovalRuleTemplateNameOutput,
+        RusotoError<UpdateApprovalRuleTemplateNameError>,
+    > {
+        self.update_approval_rule_template_name_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Replaces the contents of a comment.</p>
+    async fn update_comment(
+        &self,
+        input: UpdateCommentInput,
+    ) -> Result<UpdateCommentOutput, RusotoError<UpdateCommentError>> {
+        self.update_comment_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Sets or changes the default branch name for the specified repository.</p> <note> <p>If you use this operation to change the default branch name to the current default branch name, a success message is returned even though the default branch did not change.</p> </note></p>
+    async fn update_default_branch(
+        &self,
+        input: UpdateDefaultBranchInput,
+    ) -> Result<(), RusotoError<UpdateDefaultBranchError>> {
+        self.update_default_branch_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Updates the structure of an approval rule created specifically for a pull request. For example, you can change the number of required approvers and the approval pool for approvers. </p>
+    async fn update_pull_request_approval_rule_content(
+        &self,
+        input: UpdatePullRequestApprovalRuleContentInput,
+    ) -> Result<
+        UpdatePullRequestApprovalRuleContentOutput,
+        RusotoError<UpdatePullRequestApprovalRuleContentError>,
+    > {
+        self.update_pull_request_approval_rule_content_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Updates the state of a user's approval on a pull request. The user is derived from the signed-in account when the request is made.</p>
+    async fn update_pull_request_approval_state(
+        &self,
+        input: UpdatePullRequestApprovalStateInput,
+    ) -> Result<(), RusotoError<UpdatePullRequestApprovalStateError>> {
+        self.update_pull_request_approval_state_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Replaces the contents of the description of a pull request.</p>
+    async fn update_pull_request_description(
+        &self,
+        input: UpdatePullRequestDescriptionInput,
+    ) -> Result<UpdatePullRequestDescriptionOutput, RusotoError<UpdatePullRequestDescriptionError>>
+    {
+        self.update_pull_request_description_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Updates the status of a pull request. </p>
+    async fn update_pull_request_status(
+        &self,
+        input: UpdatePullRequestStatusInput,
+    ) -> Result<UpdatePullRequestStatusOutput, RusotoError<UpdatePullRequestStatusError>> {
+        self.update_pull_request_status_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Replaces the title of a pull request.</p>
+    async fn update_pull_request_title(
+        &self,
+        input: UpdatePullRequestTitleInput,
+    ) -> Result<UpdatePullRequestTitleOutput, RusotoError<UpdatePullRequestTitleError>> {
+        self.update_pull_request_title_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Sets or changes the comment or description for a repository.</p> <note> <p>The description field for a repository accepts all HTML characters and all valid Unicode characters. Applications that do not HTML-encode the description and display it in a webpage can expose users to potentially malicious code. Make sure that you HTML-encode the description field in any application that uses this API to display the repository description on a webpage.</p> </note></p>
+    async fn update_repository_description(
+        &self,
+        input: UpdateRepositoryDescriptionInput,
+    ) -> Result<(), RusotoError<UpdateRepositoryDescriptionError>> {
+        self.update_repository_description_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Renames a repository. The repository name must be unique across the calling AWS account. Repository names are limited to 100 alphanumeric, dash, and underscore characters, and cannot include certain characters. The suffix .git is prohibited. For more information about the limits on repository names, see <a href="https://docs.aws.amazon.com/codecommit/latest/userguide/limits.html">Limits</a> in the AWS CodeCommit User Guide.</p>
+    async fn update_repository_name(
+        &self,
+        input: UpdateRepositoryNameInput,
+    ) -> Result<(), RusotoError<UpdateRepositoryNameError>> {
+        self.update_repository_name_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
     }
 }
@@ -16,8 +16,8 @@ use std::fmt;
 use async_trait::async_trait;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
-use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest, ResponseMetadata};
+use rusoto_core::{Client, ErrorMetadata, RusotoError};
 
 use rusoto_core::proto;
 use rusoto_core::signature::SignedRequest;
@@ -2330,6 +2330,7 @@ pub struct UpdateDeploymentGroupOutput {
 
 /// Errors returned by AddTagsToOnPremisesInstances
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum AddTagsToOnPremisesInstancesError {
     /// <p>The maximum number of allowed on-premises instances in a single call was exceeded.</p>
     InstanceLimitExceeded(String),
@@ -2345,6 +2346,8 @@ pub enum AddTagsToOnPremisesInstancesError {
     TagLimitExceeded(String),
     /// <p>A tag was not specified.</p>
     TagRequired(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl AddTagsToOnPremisesInstancesError {
@@ -2354,42 +2357,54 @@ impl AddTagsToOnPremisesInstancesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InstanceLimitExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         AddTagsToOnPremisesInstancesError::InstanceLimitExceeded(err.msg),
+                        &res,
                     )
                 }
                 "InstanceNameRequiredException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         AddTagsToOnPremisesInstancesError::InstanceNameRequired(err.msg),
+                        &res,
                     )
                 }
                 "InstanceNotRegisteredException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         AddTagsToOnPremisesInstancesError::InstanceNotRegistered(err.msg),
+                        &res,
                     )
                 }
                 "InvalidInstanceNameException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         AddTagsToOnPremisesInstancesError::InvalidInstanceName(err.msg),
+                        &res,
                     )
                 }
                 "InvalidTagException" => {
-                    return RusotoError::Service(AddTagsToOnPremisesInstancesError::InvalidTag(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        AddTagsToOnPremisesInstancesError::InvalidTag(err.msg),
+                        &res,
+                    )
                 }
                 "TagLimitExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         AddTagsToOnPremisesInstancesError::TagLimitExceeded(err.msg),
+                        &res,
                     )
                 }
                 "TagRequiredException" => {
-                    return RusotoError::Service(AddTagsToOnPremisesInstancesError::TagRequired(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        AddTagsToOnPremisesInstancesError::TagRequired(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        AddTagsToOnPremisesInstancesError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -2416,12 +2431,14 @@ impl fmt::Display for AddTagsToOnPremisesInstancesError {
                 write!(f, "{}", cause)
             }
             AddTagsToOnPremisesInstancesError::TagRequired(ref cause) => write!(f, "{}", cause),
+            AddTagsToOnPremisesInstancesError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for AddTagsToOnPremisesInstancesError {}
 /// Errors returned by BatchGetApplicationRevisions
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum BatchGetApplicationRevisionsError {
     /// <p>The application does not exist with the IAM user or AWS account.</p>
     ApplicationDoesNotExist(String),
@@ -2435,6 +2452,8 @@ pub enum BatchGetApplicationRevisionsError {
     InvalidRevision(String),
     /// <p>The revision ID was not specified.</p>
     RevisionRequired(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl BatchGetApplicationRevisionsError {
@@ -2444,37 +2463,48 @@ impl BatchGetApplicationRevisionsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ApplicationDoesNotExistException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         BatchGetApplicationRevisionsError::ApplicationDoesNotExist(err.msg),
+                        &res,
                     )
                 }
                 "ApplicationNameRequiredException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         BatchGetApplicationRevisionsError::ApplicationNameRequired(err.msg),
+                        &res,
                     )
                 }
                 "BatchLimitExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         BatchGetApplicationRevisionsError::BatchLimitExceeded(err.msg),
+                        &res,
                     )
                 }
                 "InvalidApplicationNameException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         BatchGetApplicationRevisionsError::InvalidApplicationName(err.msg),
+                        &res,
                     )
                 }
                 "InvalidRevisionException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         BatchGetApplicationRevisionsError::InvalidRevision(err.msg),
+                        &res,
                     )
                 }
                 "RevisionRequiredException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         BatchGetApplicationRevisionsError::RevisionRequired(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        BatchGetApplicationRevisionsError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -2500,12 +2530,14 @@ impl fmt::Display for BatchGetApplicationRevisionsError {
             BatchGetApplicationRevisionsError::RevisionRequired(ref cause) => {
                 write!(f, "{}", cause)
             }
+            BatchGetApplicationRevisionsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for BatchGetApplicationRevisionsError {}
 /// Errors returned by BatchGetApplications
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum BatchGetApplicationsError {
     /// <p>The application does not exist with the IAM user or AWS account.</p>
     ApplicationDoesNotExist(String),
@@ -2515,6 +2547,8 @@ pub enum BatchGetApplicationsError {
     BatchLimitExceeded(String),
     /// <p>The application name was specified in an invalid format.</p>
     InvalidApplicationName(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl BatchGetApplicationsError {
@@ -2522,27 +2556,31 @@ impl BatchGetApplicationsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ApplicationDoesNotExistException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         BatchGetApplicationsError::ApplicationDoesNotExist(err.msg),
+                        &res,
                     )
                 }
                 "ApplicationNameRequiredException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         BatchGetApplicationsError::ApplicationNameRequired(err.msg),
+                        &res,
                     )
                 }
                 "BatchLimitExceededException" => {
-                    return RusotoError::Service(BatchGetApplicationsError::BatchLimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        BatchGetApplicationsError::BatchLimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidApplicationNameException" => {
-                    return RusotoError::Service(BatchGetApplicationsError::InvalidApplicationName(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        BatchGetApplicationsError::InvalidApplicationName(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(BatchGetApplicationsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2556,12 +2594,14 @@ impl fmt::Display for BatchGetApplicationsError {
             BatchGetApplicationsError::ApplicationNameRequired(ref cause) => write!(f, "{}", cause),
             BatchGetApplicationsError::BatchLimitExceeded(ref cause) => write!(f, "{}", cause),
             BatchGetApplicationsError::InvalidApplicationName(ref cause) => write!(f, "{}", cause),
+            BatchGetApplicationsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for BatchGetApplicationsError {}
 /// Errors returned by BatchGetDeploymentGroups
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum BatchGetDeploymentGroupsError {
     /// <p>The application does not exist with the IAM user or AWS account.</p>
     ApplicationDoesNotExist(String),
@@ -2577,6 +2617,8 @@ pub enum BatchGetDeploymentGroupsError {
     InvalidApplicationName(String),
     /// <p>The deployment group name was specified in an invalid format.</p>
     InvalidDeploymentGroupName(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl BatchGetDeploymentGroupsError {
@@ -2584,42 +2626,49 @@ impl BatchGetDeploymentGroupsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ApplicationDoesNotExistException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         BatchGetDeploymentGroupsError::ApplicationDoesNotExist(err.msg),
+                        &res,
                     )
                 }
                 "ApplicationNameRequiredException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         BatchGetDeploymentGroupsError::ApplicationNameRequired(err.msg),
+                        &res,
                     )
                 }
                 "BatchLimitExceededException" => {
-                    return RusotoError::Service(BatchGetDeploymentGroupsError::BatchLimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        BatchGetDeploymentGroupsError::BatchLimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "DeploymentConfigDoesNotExistException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         BatchGetDeploymentGroupsError::DeploymentConfigDoesNotExist(err.msg),
+                        &res,
                     )
                 }
                 "DeploymentGroupNameRequiredException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         BatchGetDeploymentGroupsError::DeploymentGroupNameRequired(err.msg),
+                        &res,
                     )
                 }
                 "InvalidApplicationNameException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         BatchGetDeploymentGroupsError::InvalidApplicationName(err.msg),
+                        &res,
                     )
                 }
                 "InvalidDeploymentGroupNameException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         BatchGetDeploymentGroupsError::InvalidDeploymentGroupName(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(BatchGetDeploymentGroupsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2648,12 +2697,14 @@ impl fmt::Display for BatchGetDeploymentGroupsError {
             BatchGetDeploymentGroupsError::InvalidDeploymentGroupName(ref cause) => {
                 write!(f, "{}", cause)
             }
+            BatchGetDeploymentGroupsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for BatchGetDeploymentGroupsError {}
 /// Errors returned by BatchGetDeploymentInstances
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum BatchGetDeploymentInstancesError {
     /// <p>The maximum number of names or IDs allowed for this request (100) was exceeded.</p>
     BatchLimitExceeded(String),
@@ -2669,6 +2720,8 @@ pub enum BatchGetDeploymentInstancesError {
     InvalidDeploymentId(String),
     /// <p>The on-premises instance name was specified in an invalid format.</p>
     InvalidInstanceName(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl BatchGetDeploymentInstancesError {
@@ -2678,42 +2731,54 @@ impl BatchGetDeploymentInstancesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "BatchLimitExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         BatchGetDeploymentInstancesError::BatchLimitExceeded(err.msg),
+                        &res,
                     )
                 }
                 "DeploymentDoesNotExistException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         BatchGetDeploymentInstancesError::DeploymentDoesNotExist(err.msg),
+                        &res,
                     )
                 }
                 "DeploymentIdRequiredException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         BatchGetDeploymentInstancesError::DeploymentIdRequired(err.msg),
+                        &res,
                     )
                 }
                 "InstanceIdRequiredException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         BatchGetDeploymentInstancesError::InstanceIdRequired(err.msg),
+                        &res,
                     )
                 }
                 "InvalidComputePlatformException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         BatchGetDeploymentInstancesError::InvalidComputePlatform(err.msg),
+                        &res,
                     )
                 }
                 "InvalidDeploymentIdException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         BatchGetDeploymentInstancesError::InvalidDeploymentId(err.msg),
+                        &res,
                     )
                 }
                 "InvalidInstanceNameException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         BatchGetDeploymentInstancesError::InvalidInstanceName(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        BatchGetDeploymentInstancesError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -2744,12 +2809,14 @@ impl fmt::Display for BatchGetDeploymentInstancesError {
             BatchGetDeploymentInstancesError::InvalidInstanceName(ref cause) => {
                 write!(f, "{}", cause)
             }
+            BatchGetDeploymentInstancesError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for BatchGetDeploymentInstancesError {}
 /// Errors returned by BatchGetDeploymentTargets
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum BatchGetDeploymentTargetsError {
     /// <p>The deployment with the IAM user or AWS account does not exist.</p>
     DeploymentDoesNotExist(String),
@@ -2767,6 +2834,8 @@ pub enum BatchGetDeploymentTargetsError {
     InvalidDeploymentId(String),
     /// <p> The target ID provided was not valid. </p>
     InvalidDeploymentTargetId(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl BatchGetDeploymentTargetsError {
@@ -2774,47 +2843,57 @@ impl BatchGetDeploymentTargetsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DeploymentDoesNotExistException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         BatchGetDeploymentTargetsError::DeploymentDoesNotExist(err.msg),
+                        &res,
                     )
                 }
                 "DeploymentIdRequiredException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         BatchGetDeploymentTargetsError::DeploymentIdRequired(err.msg),
+                        &res,
                     )
                 }
                 "DeploymentNotStartedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         BatchGetDeploymentTargetsError::DeploymentNotStarted(err.msg),
+                        &res,
                     )
                 }
                 "DeploymentTargetDoesNotExistException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         BatchGetDeploymentTargetsError::DeploymentTargetDoesNotExist(err.msg),
+                        &res,
                     )
                 }
                 "DeploymentTargetIdRequiredException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         BatchGetDeploymentTargetsError::DeploymentTargetIdRequired(err.msg),
+                        &res,
                     )
                 }
                 "DeploymentTargetListSizeExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         BatchGetDeploymentTargetsError::DeploymentTargetListSizeExceeded(err.msg),
+                        &res,
                     )
                 }
                 "InvalidDeploymentIdException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         BatchGetDeploymentTargetsError::InvalidDeploymentId(err.msg),
+                        &res,
                     )
                 }
                 "InvalidDeploymentTargetIdException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         BatchGetDeploymentTargetsError::InvalidDeploymentTargetId(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(BatchGetDeploymentTargetsError::Unhandled, &res)
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -2848,12 +2927,14 @@ impl fmt::Display for BatchGetDeploymentTargetsError {
             BatchGetDeploymentTargetsError::InvalidDeploymentTargetId(ref cause) => {
                 write!(f, "{}", cause)
             }
+            BatchGetDeploymentTargetsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for BatchGetDeploymentTargetsError {}
 /// Errors returned by BatchGetDeployments
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum BatchGetDeploymentsError {
     /// <p>The maximum number of names or IDs allowed for this request (100) was exceeded.</p>
     BatchLimitExceeded(String),
@@ -2861,6 +2942,8 @@ pub enum BatchGetDeploymentsError {
     DeploymentIdRequired(String),
     /// <p>At least one of the deployment IDs was specified in an invalid format.</p>
     InvalidDeploymentId(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl BatchGetDeploymentsError {
@@ -2868,22 +2951,25 @@ impl BatchGetDeploymentsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "BatchLimitExceededException" => {
-                    return RusotoError::Service(BatchGetDeploymentsError::BatchLimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        BatchGetDeploymentsError::BatchLimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "DeploymentIdRequiredException" => {
-                    return RusotoError::Service(BatchGetDeploymentsError::DeploymentIdRequired(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        BatchGetDeploymentsError::DeploymentIdRequired(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidDeploymentIdException" => {
-                    return RusotoError::Service(BatchGetDeploymentsError::InvalidDeploymentId(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        BatchGetDeploymentsError::InvalidDeploymentId(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(BatchGetDeploymentsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2896,12 +2982,14 @@ impl fmt::Display for BatchGetDeploymentsError {
             BatchGetDeploymentsError::BatchLimitExceeded(ref cause) => write!(f, "{}", cause),
             BatchGetDeploymentsError::DeploymentIdRequired(ref cause) => write!(f, "{}", cause),
             BatchGetDeploymentsError::InvalidDeploymentId(ref cause) => write!(f, "{}", cause),
+            BatchGetDeploymentsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for BatchGetDeploymentsError {}
 /// Errors returned by BatchGetOnPremisesInstances
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum BatchGetOnPremisesInstancesError {
     /// <p>The maximum number of names or IDs allowed for this request (100) was exceeded.</p>
     BatchLimitExceeded(String),
@@ -2909,6 +2997,8 @@ pub enum BatchGetOnPremisesInstancesError {
     InstanceNameRequired(String),
     /// <p>The on-premises instance name was specified in an invalid format.</p>
     InvalidInstanceName(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl BatchGetOnPremisesInstancesError {
@@ -2918,22 +3008,30 @@ impl BatchGetOnPremisesInstancesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "BatchLimitExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         BatchGetOnPremisesInstancesError::BatchLimitExceeded(err.msg),
+                        &res,
                     )
                 }
                 "InstanceNameRequiredException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         BatchGetOnPremisesInstancesError::InstanceNameRequired(err.msg),
+                        &res,
                     )
                 }
                 "InvalidInstanceNameException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         BatchGetOnPremisesInstancesError::InvalidInstanceName(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        BatchGetOnPremisesInstancesError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -2952,12 +3050,14 @@ impl fmt::Display for BatchGetOnPremisesInstancesError {
             BatchGetOnPremisesInstancesError::InvalidInstanceName(ref cause) => {
                 write!(f, "{}", cause)
             }
+            BatchGetOnPremisesInstancesError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for BatchGetOnPremisesInstancesError {}
 /// Errors returned by ContinueDeployment
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ContinueDeploymentError {
     /// <p>The deployment is already complete.</p>
     DeploymentAlreadyCompleted(String),
@@ -2975,6 +3075,8 @@ pub enum ContinueDeploymentError {
     InvalidDeploymentWaitType(String),
     /// <p>A call was submitted that is not supported for the specified deployment type.</p>
     UnsupportedActionForDeploymentType(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ContinueDeploymentError {
@@ -2982,47 +3084,55 @@ impl ContinueDeploymentError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DeploymentAlreadyCompletedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         ContinueDeploymentError::DeploymentAlreadyCompleted(err.msg),
+                        &res,
                     )
                 }
                 "DeploymentDoesNotExistException" => {
-                    return RusotoError::Service(ContinueDeploymentError::DeploymentDoesNotExist(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ContinueDeploymentError::DeploymentDoesNotExist(err.msg),
+                        &res,
+                    )
                 }
                 "DeploymentIdRequiredException" => {
-                    return RusotoError::Service(ContinueDeploymentError::DeploymentIdRequired(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ContinueDeploymentError::DeploymentIdRequired(err.msg),
+                        &res,
+                    )
                 }
                 "DeploymentIsNotInReadyStateException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         ContinueDeploymentError::DeploymentIsNotInReadyState(err.msg),
+                        &res,
                     )
                 }
                 "InvalidDeploymentIdException" => {
-                    return RusotoError::Service(ContinueDeploymentError::InvalidDeploymentId(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ContinueDeploymentError::InvalidDeploymentId(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidDeploymentStatusException" => {
-                    return RusotoError::Service(ContinueDeploymentError::InvalidDeploymentStatus(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ContinueDeploymentError::InvalidDeploymentStatus(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidDeploymentWaitTypeException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         ContinueDeploymentError::InvalidDeploymentWaitType(err.msg),
+                        &res,
                     )
                 }
                 "UnsupportedActionForDeploymentTypeException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         ContinueDeploymentError::UnsupportedActionForDeploymentType(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ContinueDeploymentError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3046,12 +3156,14 @@ impl fmt::Display for ContinueDeploymentError {
             ContinueDeploymentError::UnsupportedActionForDeploymentType(ref cause) => {
                 write!(f, "{}", cause)
             }
+            ContinueDeploymentError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ContinueDeploymentError {}
 /// Errors returned by CreateApplication
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateApplicationError {
     /// <p>An application with the specified name with the IAM user or AWS account already exists.</p>
     ApplicationAlreadyExists(String),
@@ -3065,6 +3177,8 @@ pub enum CreateApplicationError {
     InvalidComputePlatform(String),
     /// <p> The specified tags are not valid. </p>
     InvalidTagsToAdd(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateApplicationError {
@@ -3072,35 +3186,43 @@ impl CreateApplicationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ApplicationAlreadyExistsException" => {
-                    return RusotoError::Service(CreateApplicationError::ApplicationAlreadyExists(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateApplicationError::ApplicationAlreadyExists(err.msg),
+                        &res,
+                    )
                 }
                 "ApplicationLimitExceededException" => {
-                    return RusotoError::Service(CreateApplicationError::ApplicationLimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateApplicationError::ApplicationLimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "ApplicationNameRequiredException" => {
-                    return RusotoError::Service(CreateApplicationError::ApplicationNameRequired(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateApplicationError::ApplicationNameRequired(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidApplicationNameException" => {
-                    return RusotoError::Service(CreateApplicationError::InvalidApplicationName(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateApplicationError::InvalidApplicationName(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidComputePlatformException" => {
-                    return RusotoError::Service(CreateApplicationError::InvalidComputePlatform(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateApplicationError::InvalidComputePlatform(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidTagsToAddException" => {
-                    return RusotoError::Service(CreateApplicationError::InvalidTagsToAdd(err.msg))
+                    return RusotoError::service(
+                        CreateApplicationError::InvalidTagsToAdd(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreateApplicationError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3116,12 +3238,14 @@ impl fmt::Display for CreateApplicationError {
             CreateApplicationError::InvalidApplicationName(ref cause) => write!(f, "{}", cause),
             CreateApplicationError::InvalidComputePlatform(ref cause) => write!(f, "{}", cause),
             CreateApplicationError::InvalidTagsToAdd(ref cause) => write!(f, "{}", cause),
+            CreateApplicationError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateApplicationError {}
 /// Errors returned by CreateDeployment
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateDeploymentError {
     /// <p>The application does not exist with the IAM user or AWS account.</p>
     ApplicationDoesNotExist(String),
@@ -3169,6 +3293,8 @@ pub enum CreateDeploymentError {
     RevisionRequired(String),
     /// <p>An API function was called too frequently.</p>
     Throttling(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateDeploymentError {
@@ -3176,112 +3302,139 @@ impl CreateDeploymentError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ApplicationDoesNotExistException" => {
-                    return RusotoError::Service(CreateDeploymentError::ApplicationDoesNotExist(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateDeploymentError::ApplicationDoesNotExist(err.msg),
+                        &res,
+                    )
                 }
                 "ApplicationNameRequiredException" => {
-                    return RusotoError::Service(CreateDeploymentError::ApplicationNameRequired(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateDeploymentError::ApplicationNameRequired(err.msg),
+                        &res,
+                    )
                 }
                 "DeploymentConfigDoesNotExistException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         CreateDeploymentError::DeploymentConfigDoesNotExist(err.msg),
+                        &res,
                     )
                 }
                 "DeploymentGroupDoesNotExistException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         CreateDeploymentError::DeploymentGroupDoesNotExist(err.msg),
+                        &res,
                     )
                 }
                 "DeploymentGroupNameRequiredException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         CreateDeploymentError::DeploymentGroupNameRequired(err.msg),
+                        &res,
                     )
                 }
                 "DeploymentLimitExceededException" => {
-                    return RusotoError::Service(CreateDeploymentError::DeploymentLimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateDeploymentError::DeploymentLimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "DescriptionTooLongException" => {
-                    return RusotoError::Service(CreateDeploymentError::DescriptionTooLong(err.msg))
+                    return RusotoError::service(
+                        CreateDeploymentError::DescriptionTooLong(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidApplicationNameException" => {
-                    return RusotoError::Service(CreateDeploymentError::InvalidApplicationName(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateDeploymentError::InvalidApplicationName(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidAutoRollbackConfigException" => {
-                    return RusotoError::Service(CreateDeploymentError::InvalidAutoRollbackConfig(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateDeploymentError::InvalidAutoRollbackConfig(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidAutoScalingGroupException" => {
-                    return RusotoError::Service(CreateDeploymentError::InvalidAutoScalingGroup(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateDeploymentError::InvalidAutoScalingGroup(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidDeploymentConfigNameException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         CreateDeploymentError::InvalidDeploymentConfigName(err.msg),
+                        &res,
                     )
                 }
                 "InvalidDeploymentGroupNameException" => {
-                    return RusotoError::Service(CreateDeploymentError::InvalidDeploymentGroupName(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateDeploymentError::InvalidDeploymentGroupName(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidFileExistsBehaviorException" => {
-                    return RusotoError::Service(CreateDeploymentError::InvalidFileExistsBehavior(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateDeploymentError::InvalidFileExistsBehavior(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidGitHubAccountTokenException" => {
-                    return RusotoError::Service(CreateDeploymentError::InvalidGitHubAccountToken(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateDeploymentError::InvalidGitHubAccountToken(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidIgnoreApplicationStopFailuresValueException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         CreateDeploymentError::InvalidIgnoreApplicationStopFailuresValue(err.msg),
+                        &res,
                     )
                 }
                 "InvalidLoadBalancerInfoException" => {
-                    return RusotoError::Service(CreateDeploymentError::InvalidLoadBalancerInfo(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateDeploymentError::InvalidLoadBalancerInfo(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidRevisionException" => {
-                    return RusotoError::Service(CreateDeploymentError::InvalidRevision(err.msg))
+                    return RusotoError::service(
+                        CreateDeploymentError::InvalidRevision(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidRoleException" => {
-                    return RusotoError::Service(CreateDeploymentError::InvalidRole(err.msg))
+                    return RusotoError::service(CreateDeploymentError::InvalidRole(err.msg), &res)
                 }
                 "InvalidTargetInstancesException" => {
-                    return RusotoError::Service(CreateDeploymentError::InvalidTargetInstances(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateDeploymentError::InvalidTargetInstances(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidUpdateOutdatedInstancesOnlyValueException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         CreateDeploymentError::InvalidUpdateOutdatedInstancesOnlyValue(err.msg),
+                        &res,
                     )
                 }
                 "RevisionDoesNotExistException" => {
-                    return RusotoError::Service(CreateDeploymentError::RevisionDoesNotExist(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateDeploymentError::RevisionDoesNotExist(err.msg),
+                        &res,
+                    )
                 }
                 "RevisionRequiredException" => {
-                    return RusotoError::Service(CreateDeploymentError::RevisionRequired(err.msg))
+                    return RusotoError::service(
+                        CreateDeploymentError::RevisionRequired(err.msg),
+                        &res,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(CreateDeploymentError::Throttling(err.msg))
+                    return RusotoError::service(CreateDeploymentError::Throttling(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreateDeploymentError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3320,12 +3473,14 @@ impl fmt::Display for CreateDeploymentError {
             CreateDeploymentError::RevisionDoesNotExist(ref cause) => write!(f, "{}", cause),
             CreateDeploymentError::RevisionRequired(ref cause) => write!(f, "{}", cause),
             CreateDeploymentError::Throttling(ref cause) => write!(f, "{}", cause),
+            CreateDeploymentError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateDeploymentError {}
 /// Errors returned by CreateDeploymentConfig
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateDeploymentConfigError {
     /// <p>A deployment configuration with the specified name with the IAM user or AWS account already exists .</p>
     DeploymentConfigAlreadyExists(String),
@@ -3341,6 +3496,8 @@ pub enum CreateDeploymentConfigError {
     InvalidMinimumHealthyHostValue(String),
     /// <p> The configuration that specifies how traffic is routed during a deployment is invalid.</p>
     InvalidTrafficRoutingConfiguration(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateDeploymentConfigError {
@@ -3348,42 +3505,49 @@ impl CreateDeploymentConfigError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DeploymentConfigAlreadyExistsException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         CreateDeploymentConfigError::DeploymentConfigAlreadyExists(err.msg),
+                        &res,
                     )
                 }
                 "DeploymentConfigLimitExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         CreateDeploymentConfigError::DeploymentConfigLimitExceeded(err.msg),
+                        &res,
                     )
                 }
                 "DeploymentConfigNameRequiredException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         CreateDeploymentConfigError::DeploymentConfigNameRequired(err.msg),
+                        &res,
                     )
                 }
                 "InvalidComputePlatformException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         CreateDeploymentConfigError::InvalidComputePlatform(err.msg),
+                        &res,
                     )
                 }
                 "InvalidDeploymentConfigNameException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         CreateDeploymentConfigError::InvalidDeploymentConfigName(err.msg),
+                        &res,
                     )
                 }
                 "InvalidMinimumHealthyHostValueException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         CreateDeploymentConfigError::InvalidMinimumHealthyHostValue(err.msg),
+                        &res,
                     )
                 }
                 "InvalidTrafficRoutingConfigurationException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         CreateDeploymentConfigError::InvalidTrafficRoutingConfiguration(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreateDeploymentConfigError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3414,12 +3578,14 @@ impl fmt::Display for CreateDeploymentConfigError {
             CreateDeploymentConfigError::InvalidTrafficRoutingConfiguration(ref cause) => {
                 write!(f, "{}", cause)
             }
+            CreateDeploymentConfigError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateDeploymentConfigError {}
 /// Errors returned by CreateDeploymentGroup
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateDeploymentGroupError {
     /// <p>The maximum number of alarms for a deployment group (10) was exceeded.</p>
     AlarmsLimitExceeded(String),
@@ -3485,6 +3651,8 @@ pub enum CreateDeploymentGroupError {
     Throttling(String),
     /// <p>The maximum allowed number of triggers was exceeded.</p>
     TriggerTargetsLimitExceeded(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateDeploymentGroupError {
@@ -3492,157 +3660,201 @@ impl CreateDeploymentGroupError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AlarmsLimitExceededException" => {
-                    return RusotoError::Service(CreateDeploymentGroupError::AlarmsLimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateDeploymentGroupError::AlarmsLimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "ApplicationDoesNotExistException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         CreateDeploymentGroupError::ApplicationDoesNotExist(err.msg),
+                        &res,
                     )
                 }
                 "ApplicationNameRequiredException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         CreateDeploymentGroupError::ApplicationNameRequired(err.msg),
+                        &res,
                     )
                 }
                 "DeploymentConfigDoesNotExistException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         CreateDeploymentGroupError::DeploymentConfigDoesNotExist(err.msg),
+                        &res,
                     )
                 }
                 "DeploymentGroupAlreadyExistsException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         CreateDeploymentGroupError::DeploymentGroupAlreadyExists(err.msg),
+                        &res,
                     )
                 }
                 "DeploymentGroupLimitExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         CreateDeploymentGroupError::DeploymentGroupLimitExceeded(err.msg),
+                        &res,
                     )
                 }
                 "DeploymentGroupNameRequiredException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         CreateDeploymentGroupError::DeploymentGroupNameRequired(err.msg),
+                        &res,
                     )
                 }
                 "ECSServiceMappingLimitExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         CreateDeploymentGroupError::ECSServiceMappingLimitExceeded(err.msg),
+                        &res,
                     )
                 }
                 "InvalidAlarmConfigException" => {
-                    return RusotoError::Service(CreateDeploymentGroupError::InvalidAlarmConfig(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateDeploymentGroupError::InvalidAlarmConfig(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidApplicationNameException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         CreateDeploymentGroupError::InvalidApplicationName(err.msg),
+                        &res,
                     )
                 }
                 "InvalidAutoRollbackConfigException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         CreateDeploymentGroupError::InvalidAutoRollbackConfig(err.msg),
+                        &res,
                     )
                 }
                 "InvalidAutoScalingGroupException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         CreateDeploymentGroupError::InvalidAutoScalingGroup(err.msg),
+                        &res,
                     )
                 }
                 "InvalidBlueGreenDeploymentConfigurationException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         CreateDeploymentGroupError::InvalidBlueGreenDeploymentConfiguration(
                             err.msg,
                         ),
+                        &res,
                     )
                 }
                 "InvalidDeploymentConfigNameException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         CreateDeploymentGroupError::InvalidDeploymentConfigName(err.msg),
+                        &res,
                     )
                 }
                 "InvalidDeploymentGroupNameException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         CreateDeploymentGroupError::InvalidDeploymentGroupName(err.msg),
+                        &res,
                     )
                 }
                 "InvalidDeploymentStyleException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         CreateDeploymentGroupError::InvalidDeploymentStyle(err.msg),
+                        &res,
                     )
                 }
                 "InvalidEC2TagCombinationException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         CreateDeploymentGroupError::InvalidEC2TagCombination(err.msg),
+                        &res,
                     )
                 }
                 "InvalidEC2TagException" => {
-                    return RusotoError::Service(CreateDeploymentGroupError::InvalidEC2Tag(err.msg))
+                    return RusotoError::service(
+                        CreateDeploymentGroupError::InvalidEC2Tag(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidECSServiceException" => {
-                    return RusotoError::Service(CreateDeploymentGroupError::InvalidECSService(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateDeploymentGroupError::InvalidECSService(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(CreateDeploymentGroupError::InvalidInput(err.msg))
+                    return RusotoError::service(
+                        CreateDeploymentGroupError::InvalidInput(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidLoadBalancerInfoException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         CreateDeploymentGroupError::InvalidLoadBalancerInfo(err.msg),
+                        &res,
                     )
                 }
                 "InvalidOnPremisesTagCombinationException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         CreateDeploymentGroupError::InvalidOnPremisesTagCombination(err.msg),
+                        &res,
                     )
                 }
                 "InvalidRoleException" => {
-                    return RusotoError::Service(CreateDeploymentGroupError::InvalidRole(err.msg))
+                    return RusotoError::service(
+                        CreateDeploymentGroupError::InvalidRole(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidTagException" => {
-                    return RusotoError::Service(CreateDeploymentGroupError::InvalidTag(err.msg))
+                    return RusotoError::service(
+                        CreateDeploymentGroupError::InvalidTag(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidTagsToAddException" => {
-                    return RusotoError::Service(CreateDeploymentGroupError::InvalidTagsToAdd(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateDeploymentGroupError::InvalidTagsToAdd(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidTargetGroupPairException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         CreateDeploymentGroupError::InvalidTargetGroupPair(err.msg),
+                        &res,
                     )
                 }
                 "InvalidTriggerConfigException" => {
-                    return RusotoError::Service(CreateDeploymentGroupError::InvalidTriggerConfig(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateDeploymentGroupError::InvalidTriggerConfig(err.msg),
+                        &res,
+                    )
                 }
                 "LifecycleHookLimitExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         CreateDeploymentGroupError::LifecycleHookLimitExceeded(err.msg),
+                        &res,
                     )
                 }
                 "RoleRequiredException" => {
-                    return RusotoError::Service(CreateDeploymentGroupError::RoleRequired(err.msg))
+                    return RusotoError::service(
+                        CreateDeploymentGroupError::RoleRequired(err.msg),
+                        &res,
+                    )
                 }
                 "TagSetListLimitExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         CreateDeploymentGroupError::TagSetListLimitExceeded(err.msg),
+                        &res,
                     )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(CreateDeploymentGroupError::Throttling(err.msg))
+                    return RusotoError::service(
+                        CreateDeploymentGroupError::Throttling(err.msg),
+                        &res,
+                    )
                 }
                 "TriggerTargetsLimitExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         CreateDeploymentGroupError::TriggerTargetsLimitExceeded(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreateDeploymentGroupError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3720,12 +3932,14 @@ impl fmt::Display for CreateDeploymentGroupError {
             CreateDeploymentGroupError::TriggerTargetsLimitExceeded(ref cause) => {
                 write!(f, "{}", cause)
             }
+            CreateDeploymentGroupError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateDeploymentGroupError {}
 /// Errors returned by DeleteApplication
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteApplicationError {
     /// <p>The minimum number of required application names was not specified.</p>
     ApplicationNameRequired(String),
@@ -3733,6 +3947,8 @@ pub enum DeleteApplicationError {
     InvalidApplicationName(String),
     /// <p>The service role ARN was specified in an invalid format. Or, if an Auto Scaling group was specified, the specified service role does not grant the appropriate permissions to Amazon EC2 Auto Scaling.</p>
     InvalidRole(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteApplicationError {
@@ -3740,20 +3956,22 @@ impl DeleteApplicationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ApplicationNameRequiredException" => {
-                    return RusotoError::Service(DeleteApplicationError::ApplicationNameRequired(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteApplicationError::ApplicationNameRequired(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidApplicationNameException" => {
-                    return RusotoError::Service(DeleteApplicationError::InvalidApplicationName(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteApplicationError::InvalidApplicationName(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidRoleException" => {
-                    return RusotoError::Service(DeleteApplicationError::InvalidRole(err.msg))
+                    return RusotoError::service(DeleteApplicationError::InvalidRole(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteApplicationError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3766,12 +3984,14 @@ impl fmt::Display for DeleteApplicationError {
             DeleteApplicationError::ApplicationNameRequired(ref cause) => write!(f, "{}", cause),
             DeleteApplicationError::InvalidApplicationName(ref cause) => write!(f, "{}", cause),
             DeleteApplicationError::InvalidRole(ref cause) => write!(f, "{}", cause),
+            DeleteApplicationError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteApplicationError {}
 /// Errors returned by DeleteDeploymentConfig
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteDeploymentConfigError {
     /// <p>The deployment configuration is still in use.</p>
     DeploymentConfigInUse(String),
@@ -3781,6 +4001,8 @@ pub enum DeleteDeploymentConfigError {
     InvalidDeploymentConfigName(String),
     /// <p>An invalid operation was detected.</p>
     InvalidOperation(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteDeploymentConfigError {
@@ -3788,27 +4010,31 @@ impl DeleteDeploymentConfigError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DeploymentConfigInUseException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DeleteDeploymentConfigError::DeploymentConfigInUse(err.msg),
+                        &res,
                     )
                 }
                 "DeploymentConfigNameRequiredException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DeleteDeploymentConfigError::DeploymentConfigNameRequired(err.msg),
+                        &res,
                     )
                 }
                 "InvalidDeploymentConfigNameException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DeleteDeploymentConfigError::InvalidDeploymentConfigName(err.msg),
+                        &res,
                     )
                 }
                 "InvalidOperationException" => {
-                    return RusotoError::Service(DeleteDeploymentConfigError::InvalidOperation(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteDeploymentConfigError::InvalidOperation(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteDeploymentConfigError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3826,12 +4052,14 @@ impl fmt::Display for DeleteDeploymentConfigError {
                 write!(f, "{}", cause)
             }
             DeleteDeploymentConfigError::InvalidOperation(ref cause) => write!(f, "{}", cause),
+            DeleteDeploymentConfigError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteDeploymentConfigError {}
 /// Errors returned by DeleteDeploymentGroup
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteDeploymentGroupError {
     /// <p>The minimum number of required application names was not specified.</p>
     ApplicationNameRequired(String),
@@ -3843,6 +4071,8 @@ pub enum DeleteDeploymentGroupError {
     InvalidDeploymentGroupName(String),
     /// <p>The service role ARN was specified in an invalid format. Or, if an Auto Scaling group was specified, the specified service role does not grant the appropriate permissions to Amazon EC2 Auto Scaling.</p>
     InvalidRole(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteDeploymentGroupError {
@@ -3850,30 +4080,37 @@ impl DeleteDeploymentGroupError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ApplicationNameRequiredException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DeleteDeploymentGroupError::ApplicationNameRequired(err.msg),
+                        &res,
                     )
                 }
                 "DeploymentGroupNameRequiredException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DeleteDeploymentGroupError::DeploymentGroupNameRequired(err.msg),
+                        &res,
                     )
                 }
                 "InvalidApplicationNameException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DeleteDeploymentGroupError::InvalidApplicationName(err.msg),
+                        &res,
                     )
                 }
                 "InvalidDeploymentGroupNameException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DeleteDeploymentGroupError::InvalidDeploymentGroupName(err.msg),
+                        &res,
                     )
                 }
                 "InvalidRoleException" => {
-                    return RusotoError::Service(DeleteDeploymentGroupError::InvalidRole(err.msg))
+                    return RusotoError::service(
+                        DeleteDeploymentGroupError::InvalidRole(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteDeploymentGroupError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3894,12 +4131,14 @@ impl fmt::Display for DeleteDeploymentGroupError {
                 write!(f, "{}", cause)
             }
             DeleteDeploymentGroupError::InvalidRole(ref cause) => write!(f, "{}", cause),
+            DeleteDeploymentGroupError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteDeploymentGroupError {}
 /// Errors returned by DeleteGitHubAccountToken
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteGitHubAccountTokenError {
     /// <p>No GitHub account connection exists with the named specified in the call.</p>
     GitHubAccountTokenDoesNotExist(String),
@@ -3911,6 +4150,8 @@ pub enum DeleteGitHubAccountTokenError {
     OperationNotSupported(String),
     /// <p>The specified resource could not be validated.</p>
     ResourceValidation(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteGitHubAccountTokenError {
@@ -3918,32 +4159,37 @@ impl DeleteGitHubAccountTokenError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "GitHubAccountTokenDoesNotExistException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DeleteGitHubAccountTokenError::GitHubAccountTokenDoesNotExist(err.msg),
+                        &res,
                     )
                 }
                 "GitHubAccountTokenNameRequiredException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DeleteGitHubAccountTokenError::GitHubAccountTokenNameRequired(err.msg),
+                        &res,
                     )
                 }
                 "InvalidGitHubAccountTokenNameException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DeleteGitHubAccountTokenError::InvalidGitHubAccountTokenName(err.msg),
+                        &res,
                     )
                 }
                 "OperationNotSupportedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DeleteGitHubAccountTokenError::OperationNotSupported(err.msg),
+                        &res,
                     )
                 }
                 "ResourceValidationException" => {
-                    return RusotoError::Service(DeleteGitHubAccountTokenError::ResourceValidation(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteGitHubAccountTokenError::ResourceValidation(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteGitHubAccountTokenError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3966,17 +4212,21 @@ impl fmt::Display for DeleteGitHubAccountTokenError {
                 write!(f, "{}", cause)
             }
             DeleteGitHubAccountTokenError::ResourceValidation(ref cause) => write!(f, "{}", cause),
+            DeleteGitHubAccountTokenError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteGitHubAccountTokenError {}
 /// Errors returned by DeregisterOnPremisesInstance
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeregisterOnPremisesInstanceError {
     /// <p>An on-premises instance name was not specified.</p>
     InstanceNameRequired(String),
     /// <p>The on-premises instance name was specified in an invalid format.</p>
     InvalidInstanceName(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeregisterOnPremisesInstanceError {
@@ -3986,17 +4236,24 @@ impl DeregisterOnPremisesInstanceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InstanceNameRequiredException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DeregisterOnPremisesInstanceError::InstanceNameRequired(err.msg),
+                        &res,
                     )
                 }
                 "InvalidInstanceNameException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DeregisterOnPremisesInstanceError::InvalidInstanceName(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        DeregisterOnPremisesInstanceError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -4012,12 +4269,14 @@ impl fmt::Display for DeregisterOnPremisesInstanceError {
             DeregisterOnPremisesInstanceError::InvalidInstanceName(ref cause) => {
                 write!(f, "{}", cause)
             }
+            DeregisterOnPremisesInstanceError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeregisterOnPremisesInstanceError {}
 /// Errors returned by GetApplication
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetApplicationError {
     /// <p>The application does not exist with the IAM user or AWS account.</p>
     ApplicationDoesNotExist(String),
@@ -4025,6 +4284,8 @@ pub enum GetApplicationError {
     ApplicationNameRequired(String),
     /// <p>The application name was specified in an invalid format.</p>
     InvalidApplicationName(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetApplicationError {
@@ -4032,22 +4293,25 @@ impl GetApplicationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ApplicationDoesNotExistException" => {
-                    return RusotoError::Service(GetApplicationError::ApplicationDoesNotExist(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetApplicationError::ApplicationDoesNotExist(err.msg),
+                        &res,
+                    )
                 }
                 "ApplicationNameRequiredException" => {
-                    return RusotoError::Service(GetApplicationError::ApplicationNameRequired(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetApplicationError::ApplicationNameRequired(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidApplicationNameException" => {
-                    return RusotoError::Service(GetApplicationError::InvalidApplicationName(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetApplicationError::InvalidApplicationName(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetApplicationError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -4060,12 +4324,14 @@ impl fmt::Display for GetApplicationError {
             GetApplicationError::ApplicationDoesNotExist(ref cause) => write!(f, "{}", cause),
             GetApplicationError::ApplicationNameRequired(ref cause) => write!(f, "{}", cause),
             GetApplicationError::InvalidApplicationName(ref cause) => write!(f, "{}", cause),
+            GetApplicationError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetApplicationError {}
 /// Errors returned by GetApplicationRevision
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetApplicationRevisionError {
     /// <p>The application does not exist with the IAM user or AWS account.</p>
     ApplicationDoesNotExist(String),
@@ -4079,6 +4345,8 @@ pub enum GetApplicationRevisionError {
     RevisionDoesNotExist(String),
     /// <p>The revision ID was not specified.</p>
     RevisionRequired(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetApplicationRevisionError {
@@ -4086,37 +4354,43 @@ impl GetApplicationRevisionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ApplicationDoesNotExistException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         GetApplicationRevisionError::ApplicationDoesNotExist(err.msg),
+                        &res,
                     )
                 }
                 "ApplicationNameRequiredException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         GetApplicationRevisionError::ApplicationNameRequired(err.msg),
+                        &res,
                     )
                 }
                 "InvalidApplicationNameException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         GetApplicationRevisionError::InvalidApplicationName(err.msg),
+                        &res,
                     )
                 }
                 "InvalidRevisionException" => {
-                    return RusotoError::Service(GetApplicationRevisionError::InvalidRevision(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetApplicationRevisionError::InvalidRevision(err.msg),
+                        &res,
+                    )
                 }
                 "RevisionDoesNotExistException" => {
-                    return RusotoError::Service(GetApplicationRevisionError::RevisionDoesNotExist(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetApplicationRevisionError::RevisionDoesNotExist(err.msg),
+                        &res,
+                    )
                 }
                 "RevisionRequiredException" => {
-                    return RusotoError::Service(GetApplicationRevisionError::RevisionRequired(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetApplicationRevisionError::RevisionRequired(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetApplicationRevisionError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -4138,12 +4412,14 @@ impl fmt::Display for GetApplicationRevisionError {
             GetApplicationRevisionError::InvalidRevision(ref cause) => write!(f, "{}", cause),
             GetApplicationRevisionError::RevisionDoesNotExist(ref cause) => write!(f, "{}", cause),
             GetApplicationRevisionError::RevisionRequired(ref cause) => write!(f, "{}", cause),
+            GetApplicationRevisionError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetApplicationRevisionError {}
 /// Errors returned by GetDeployment
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetDeploymentError {
     /// <p>The deployment with the IAM user or AWS account does not exist.</p>
     DeploymentDoesNotExist(String),
@@ -4151,6 +4427,8 @@ pub enum GetDeploymentError {
     DeploymentIdRequired(String),
     /// <p>At least one of the deployment IDs was specified in an invalid format.</p>
     InvalidDeploymentId(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetDeploymentError {
@@ -4158,18 +4436,25 @@ impl GetDeploymentError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DeploymentDoesNotExistException" => {
-                    return RusotoError::Service(GetDeploymentError::DeploymentDoesNotExist(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetDeploymentError::DeploymentDoesNotExist(err.msg),
+                        &res,
+                    )
                 }
                 "DeploymentIdRequiredException" => {
-                    return RusotoError::Service(GetDeploymentError::DeploymentIdRequired(err.msg))
+                    return RusotoError::service(
+                        GetDeploymentError::DeploymentIdRequired(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidDeploymentIdException" => {
-                    return RusotoError::Service(GetDeploymentError::InvalidDeploymentId(err.msg))
+                    return RusotoError::service(
+                        GetDeploymentError::InvalidDeploymentId(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetDeploymentError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -4182,12 +4467,14 @@ impl fmt::Display for GetDeploymentError {
             GetDeploymentError::DeploymentDoesNotExist(ref cause) => write!(f, "{}", cause),
             GetDeploymentError::DeploymentIdRequired(ref cause) => write!(f, "{}", cause),
             GetDeploymentError::InvalidDeploymentId(ref cause) => write!(f, "{}", cause),
+            GetDeploymentError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetDeploymentError {}
 /// Errors returned by GetDeploymentConfig
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetDeploymentConfigError {
     /// <p>The deployment configuration does not exist with the IAM user or AWS account.</p>
     DeploymentConfigDoesNotExist(String),
@@ -4197,6 +4484,8 @@ pub enum GetDeploymentConfigError {
     InvalidComputePlatform(String),
     /// <p>The deployment configuration name was specified in an invalid format.</p>
     InvalidDeploymentConfigName(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetDeploymentConfigError {
@@ -4204,27 +4493,31 @@ impl GetDeploymentConfigError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DeploymentConfigDoesNotExistException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         GetDeploymentConfigError::DeploymentConfigDoesNotExist(err.msg),
+                        &res,
                     )
                 }
                 "DeploymentConfigNameRequiredException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         GetDeploymentConfigError::DeploymentConfigNameRequired(err.msg),
+                        &res,
                     )
                 }
                 "InvalidComputePlatformException" => {
-                    return RusotoError::Service(GetDeploymentConfigError::InvalidComputePlatform(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetDeploymentConfigError::InvalidComputePlatform(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidDeploymentConfigNameException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         GetDeploymentConfigError::InvalidDeploymentConfigName(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetDeploymentConfigError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -4244,12 +4537,14 @@ impl fmt::Display for GetDeploymentConfigError {
             GetDeploymentConfigError::InvalidDeploymentConfigName(ref cause) => {
                 write!(f, "{}", cause)
             }
+            GetDeploymentConfigError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetDeploymentConfigError {}
 /// Errors returned by GetDeploymentGroup
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetDeploymentGroupError {
     /// <p>The application does not exist with the IAM user or AWS account.</p>
     ApplicationDoesNotExist(String),
@@ -4265,6 +4560,8 @@ pub enum GetDeploymentGroupError {
     InvalidApplicationName(String),
     /// <p>The deployment group name was specified in an invalid format.</p>
     InvalidDeploymentGroupName(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetDeploymentGroupError {
@@ -4272,42 +4569,49 @@ impl GetDeploymentGroupError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ApplicationDoesNotExistException" => {
-                    return RusotoError::Service(GetDeploymentGroupError::ApplicationDoesNotExist(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetDeploymentGroupError::ApplicationDoesNotExist(err.msg),
+                        &res,
+                    )
                 }
                 "ApplicationNameRequiredException" => {
-                    return RusotoError::Service(GetDeploymentGroupError::ApplicationNameRequired(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetDeploymentGroupError::ApplicationNameRequired(err.msg),
+                        &res,
+                    )
                 }
                 "DeploymentConfigDoesNotExistException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         GetDeploymentGroupError::DeploymentConfigDoesNotExist(err.msg),
+                        &res,
                     )
                 }
                 "DeploymentGroupDoesNotExistException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         GetDeploymentGroupError::DeploymentGroupDoesNotExist(err.msg),
+                        &res,
                     )
                 }
                 "DeploymentGroupNameRequiredException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         GetDeploymentGroupError::DeploymentGroupNameRequired(err.msg),
+                        &res,
                     )
                 }
                 "InvalidApplicationNameException" => {
-                    return RusotoError::Service(GetDeploymentGroupError::InvalidApplicationName(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetDeploymentGroupError::InvalidApplicationName(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidDeploymentGroupNameException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         GetDeploymentGroupError::InvalidDeploymentGroupName(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetDeploymentGroupError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -4332,12 +4636,14 @@ impl fmt::Display for GetDeploymentGroupError {
             GetDeploymentGroupError::InvalidDeploymentGroupName(ref cause) => {
                 write!(f, "{}", cause)
             }
+            GetDeploymentGroupError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetDeploymentGroupError {}
 /// Errors returned by GetDeploymentInstance
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetDeploymentInstanceError {
     /// <p>The deployment with the IAM user or AWS account does not exist.</p>
     DeploymentDoesNotExist(String),
@@ -4353,6 +4659,8 @@ pub enum GetDeploymentInstanceError {
     InvalidDeploymentId(String),
     /// <p>The on-premises instance name was specified in an invalid format.</p>
     InvalidInstanceName(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetDeploymentInstanceError {
@@ -4360,42 +4668,49 @@ impl GetDeploymentInstanceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DeploymentDoesNotExistException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         GetDeploymentInstanceError::DeploymentDoesNotExist(err.msg),
+                        &res,
                     )
                 }
                 "DeploymentIdRequiredException" => {
-                    return RusotoError::Service(GetDeploymentInstanceError::DeploymentIdRequired(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetDeploymentInstanceError::DeploymentIdRequired(err.msg),
+                        &res,
+                    )
                 }
                 "InstanceDoesNotExistException" => {
-                    return RusotoError::Service(GetDeploymentInstanceError::InstanceDoesNotExist(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetDeploymentInstanceError::InstanceDoesNotExist(err.msg),
+                        &res,
+                    )
                 }
                 "InstanceIdRequiredException" => {
-                    return RusotoError::Service(GetDeploymentInstanceError::InstanceIdRequired(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetDeploymentInstanceError::InstanceIdRequired(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidComputePlatformException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         GetDeploymentInstanceError::InvalidComputePlatform(err.msg),
+                        &res,
                     )
                 }
                 "InvalidDeploymentIdException" => {
-                    return RusotoError::Service(GetDeploymentInstanceError::InvalidDeploymentId(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetDeploymentInstanceError::InvalidDeploymentId(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidInstanceNameException" => {
-                    return RusotoError::Service(GetDeploymentInstanceError::InvalidInstanceName(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetDeploymentInstanceError::InvalidInstanceName(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetDeploymentInstanceError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -4412,12 +4727,14 @@ impl fmt::Display for GetDeploymentInstanceError {
             GetDeploymentInstanceError::InvalidComputePlatform(ref cause) => write!(f, "{}", cause),
             GetDeploymentInstanceError::InvalidDeploymentId(ref cause) => write!(f, "{}", cause),
             GetDeploymentInstanceError::InvalidInstanceName(ref cause) => write!(f, "{}", cause),
+            GetDeploymentInstanceError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetDeploymentInstanceError {}
 /// Errors returned by GetDeploymentTarget
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetDeploymentTargetError {
     /// <p>The deployment with the IAM user or AWS account does not exist.</p>
     DeploymentDoesNotExist(String),
@@ -4435,6 +4752,8 @@ pub enum GetDeploymentTargetError {
     InvalidDeploymentTargetId(String),
     /// <p>The on-premises instance name was specified in an invalid format.</p>
     InvalidInstanceName(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetDeploymentTargetError {
@@ -4442,47 +4761,55 @@ impl GetDeploymentTargetError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DeploymentDoesNotExistException" => {
-                    return RusotoError::Service(GetDeploymentTargetError::DeploymentDoesNotExist(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetDeploymentTargetError::DeploymentDoesNotExist(err.msg),
+                        &res,
+                    )
                 }
                 "DeploymentIdRequiredException" => {
-                    return RusotoError::Service(GetDeploymentTargetError::DeploymentIdRequired(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetDeploymentTargetError::DeploymentIdRequired(err.msg),
+                        &res,
+                    )
                 }
                 "DeploymentNotStartedException" => {
-                    return RusotoError::Service(GetDeploymentTargetError::DeploymentNotStarted(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetDeploymentTargetError::DeploymentNotStarted(err.msg),
+                        &res,
+                    )
                 }
                 "DeploymentTargetDoesNotExistException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         GetDeploymentTargetError::DeploymentTargetDoesNotExist(err.msg),
+                        &res,
                     )
                 }
                 "DeploymentTargetIdRequiredException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         GetDeploymentTargetError::DeploymentTargetIdRequired(err.msg),
+                        &res,
                     )
                 }
                 "InvalidDeploymentIdException" => {
-                    return RusotoError::Service(GetDeploymentTargetError::InvalidDeploymentId(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetDeploymentTargetError::InvalidDeploymentId(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidDeploymentTargetIdException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         GetDeploymentTargetError::InvalidDeploymentTargetId(err.msg),
+                        &res,
                     )
                 }
                 "InvalidInstanceNameException" => {
-                    return RusotoError::Service(GetDeploymentTargetError::InvalidInstanceName(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetDeploymentTargetError::InvalidInstanceName(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetDeploymentTargetError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -4506,12 +4833,14 @@ impl fmt::Display for GetDeploymentTargetError {
                 write!(f, "{}", cause)
             }
             GetDeploymentTargetError::InvalidInstanceName(ref cause) => write!(f, "{}", cause),
+            GetDeploymentTargetError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetDeploymentTargetError {}
 /// Errors returned by GetOnPremisesInstance
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetOnPremisesInstanceError {
     /// <p>An on-premises instance name was not specified.</p>
     InstanceNameRequired(String),
@@ -4519,6 +4848,8 @@ pub enum GetOnPremisesInstanceError {
     InstanceNotRegistered(String),
     /// <p>The on-premises instance name was specified in an invalid format.</p>
     InvalidInstanceName(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetOnPremisesInstanceError {
@@ -4526,22 +4857,25 @@ impl GetOnPremisesInstanceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InstanceNameRequiredException" => {
-                    return RusotoError::Service(GetOnPremisesInstanceError::InstanceNameRequired(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetOnPremisesInstanceError::InstanceNameRequired(err.msg),
+                        &res,
+                    )
                 }
                 "InstanceNotRegisteredException" => {
-                    return RusotoError::Service(GetOnPremisesInstanceError::InstanceNotRegistered(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetOnPremisesInstanceError::InstanceNotRegistered(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidInstanceNameException" => {
-                    return RusotoError::Service(GetOnPremisesInstanceError::InvalidInstanceName(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetOnPremisesInstanceError::InvalidInstanceName(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetOnPremisesInstanceError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -4554,12 +4888,14 @@ impl fmt::Display for GetOnPremisesInstanceError {
             GetOnPremisesInstanceError::InstanceNameRequired(ref cause) => write!(f, "{}", cause),
             GetOnPremisesInstanceError::InstanceNotRegistered(ref cause) => write!(f, "{}", cause),
             GetOnPremisesInstanceError::InvalidInstanceName(ref cause) => write!(f, "{}", cause),
+            GetOnPremisesInstanceError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetOnPremisesInstanceError {}
 /// Errors returned by ListApplicationRevisions
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListApplicationRevisionsError {
     /// <p>The application does not exist with the IAM user or AWS account.</p>
     ApplicationDoesNotExist(String),
@@ -4581,6 +4917,8 @@ pub enum ListApplicationRevisionsError {
     InvalidSortBy(String),
     /// <p>The sort order was specified in an invalid format.</p>
     InvalidSortOrder(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListApplicationRevisionsError {
@@ -4588,57 +4926,67 @@ impl ListApplicationRevisionsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ApplicationDoesNotExistException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         ListApplicationRevisionsError::ApplicationDoesNotExist(err.msg),
+                        &res,
                     )
                 }
                 "ApplicationNameRequiredException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         ListApplicationRevisionsError::ApplicationNameRequired(err.msg),
+                        &res,
                     )
                 }
                 "BucketNameFilterRequiredException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         ListApplicationRevisionsError::BucketNameFilterRequired(err.msg),
+                        &res,
                     )
                 }
                 "InvalidApplicationNameException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         ListApplicationRevisionsError::InvalidApplicationName(err.msg),
+                        &res,
                     )
                 }
                 "InvalidBucketNameFilterException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         ListApplicationRevisionsError::InvalidBucketNameFilter(err.msg),
+                        &res,
                     )
                 }
                 "InvalidDeployedStateFilterException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         ListApplicationRevisionsError::InvalidDeployedStateFilter(err.msg),
+                        &res,
                     )
                 }
                 "InvalidKeyPrefixFilterException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         ListApplicationRevisionsError::InvalidKeyPrefixFilter(err.msg),
+                        &res,
                     )
                 }
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(ListApplicationRevisionsError::InvalidNextToken(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListApplicationRevisionsError::InvalidNextToken(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidSortByException" => {
-                    return RusotoError::Service(ListApplicationRevisionsError::InvalidSortBy(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListApplicationRevisionsError::InvalidSortBy(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidSortOrderException" => {
-                    return RusotoError::Service(ListApplicationRevisionsError::InvalidSortOrder(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListApplicationRevisionsError::InvalidSortOrder(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListApplicationRevisionsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -4672,15 +5020,19 @@ impl fmt::Display for ListApplicationRevisionsError {
             ListApplicationRevisionsError::InvalidNextToken(ref cause) => write!(f, "{}", cause),
             ListApplicationRevisionsError::InvalidSortBy(ref cause) => write!(f, "{}", cause),
             ListApplicationRevisionsError::InvalidSortOrder(ref cause) => write!(f, "{}", cause),
+            ListApplicationRevisionsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListApplicationRevisionsError {}
 /// Errors returned by ListApplications
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListApplicationsError {
     /// <p>The next token was specified in an invalid format.</p>
     InvalidNextToken(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListApplicationsError {
@@ -4688,10 +5040,13 @@ impl ListApplicationsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(ListApplicationsError::InvalidNextToken(err.msg))
+                    return RusotoError::service(
+                        ListApplicationsError::InvalidNextToken(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListApplicationsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -4702,15 +5057,19 @@ impl fmt::Display for ListApplicationsError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             ListApplicationsError::InvalidNextToken(ref cause) => write!(f, "{}", cause),
+            ListApplicationsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListApplicationsError {}
 /// Errors returned by ListDeploymentConfigs
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListDeploymentConfigsError {
     /// <p>The next token was specified in an invalid format.</p>
     InvalidNextToken(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListDeploymentConfigsError {
@@ -4718,12 +5077,13 @@ impl ListDeploymentConfigsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(ListDeploymentConfigsError::InvalidNextToken(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListDeploymentConfigsError::InvalidNextToken(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListDeploymentConfigsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -4734,12 +5094,14 @@ impl fmt::Display for ListDeploymentConfigsError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             ListDeploymentConfigsError::InvalidNextToken(ref cause) => write!(f, "{}", cause),
+            ListDeploymentConfigsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListDeploymentConfigsError {}
 /// Errors returned by ListDeploymentGroups
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListDeploymentGroupsError {
     /// <p>The application does not exist with the IAM user or AWS account.</p>
     ApplicationDoesNotExist(String),
@@ -4749,6 +5111,8 @@ pub enum ListDeploymentGroupsError {
     InvalidApplicationName(String),
     /// <p>The next token was specified in an invalid format.</p>
     InvalidNextToken(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListDeploymentGroupsError {
@@ -4756,27 +5120,31 @@ impl ListDeploymentGroupsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ApplicationDoesNotExistException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         ListDeploymentGroupsError::ApplicationDoesNotExist(err.msg),
+                        &res,
                     )
                 }
                 "ApplicationNameRequiredException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         ListDeploymentGroupsError::ApplicationNameRequired(err.msg),
+                        &res,
                     )
                 }
                 "InvalidApplicationNameException" => {
-                    return RusotoError::Service(ListDeploymentGroupsError::InvalidApplicationName(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListDeploymentGroupsError::InvalidApplicationName(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(ListDeploymentGroupsError::InvalidNextToken(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListDeploymentGroupsError::InvalidNextToken(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListDeploymentGroupsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -4790,12 +5158,14 @@ impl fmt::Display for ListDeploymentGroupsError {
             ListDeploymentGroupsError::ApplicationNameRequired(ref cause) => write!(f, "{}", cause),
             ListDeploymentGroupsError::InvalidApplicationName(ref cause) => write!(f, "{}", cause),
             ListDeploymentGroupsError::InvalidNextToken(ref cause) => write!(f, "{}", cause),
+            ListDeploymentGroupsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListDeploymentGroupsError {}
 /// Errors returned by ListDeploymentInstances
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListDeploymentInstancesError {
     /// <p>The deployment with the IAM user or AWS account does not exist.</p>
     DeploymentDoesNotExist(String),
@@ -4817,6 +5187,8 @@ pub enum ListDeploymentInstancesError {
     InvalidNextToken(String),
     /// <p> The target filter name is invalid. </p>
     InvalidTargetFilterName(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListDeploymentInstancesError {
@@ -4824,57 +5196,67 @@ impl ListDeploymentInstancesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DeploymentDoesNotExistException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         ListDeploymentInstancesError::DeploymentDoesNotExist(err.msg),
+                        &res,
                     )
                 }
                 "DeploymentIdRequiredException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         ListDeploymentInstancesError::DeploymentIdRequired(err.msg),
+                        &res,
                     )
                 }
                 "DeploymentNotStartedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         ListDeploymentInstancesError::DeploymentNotStarted(err.msg),
+                        &res,
                     )
                 }
                 "InvalidComputePlatformException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         ListDeploymentInstancesError::InvalidComputePlatform(err.msg),
+                        &res,
                     )
                 }
                 "InvalidDeploymentIdException" => {
-                    return RusotoError::Service(ListDeploymentInstancesError::InvalidDeploymentId(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListDeploymentInstancesError::InvalidDeploymentId(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidDeploymentInstanceTypeException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         ListDeploymentInstancesError::InvalidDeploymentInstanceType(err.msg),
+                        &res,
                     )
                 }
                 "InvalidInstanceStatusException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         ListDeploymentInstancesError::InvalidInstanceStatus(err.msg),
+                        &res,
                     )
                 }
                 "InvalidInstanceTypeException" => {
-                    return RusotoError::Service(ListDeploymentInstancesError::InvalidInstanceType(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListDeploymentInstancesError::InvalidInstanceType(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(ListDeploymentInstancesError::InvalidNextToken(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListDeploymentInstancesError::InvalidNextToken(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidTargetFilterNameException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         ListDeploymentInstancesError::InvalidTargetFilterName(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListDeploymentInstancesError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -4904,12 +5286,14 @@ impl fmt::Display for ListDeploymentInstancesError {
             ListDeploymentInstancesError::InvalidTargetFilterName(ref cause) => {
                 write!(f, "{}", cause)
             }
+            ListDeploymentInstancesError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListDeploymentInstancesError {}
 /// Errors returned by ListDeploymentTargets
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListDeploymentTargetsError {
     /// <p>The deployment with the IAM user or AWS account does not exist.</p>
     DeploymentDoesNotExist(String),
@@ -4927,6 +5311,8 @@ pub enum ListDeploymentTargetsError {
     InvalidInstanceType(String),
     /// <p>The next token was specified in an invalid format.</p>
     InvalidNextToken(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListDeploymentTargetsError {
@@ -4934,47 +5320,55 @@ impl ListDeploymentTargetsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DeploymentDoesNotExistException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         ListDeploymentTargetsError::DeploymentDoesNotExist(err.msg),
+                        &res,
                     )
                 }
                 "DeploymentIdRequiredException" => {
-                    return RusotoError::Service(ListDeploymentTargetsError::DeploymentIdRequired(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListDeploymentTargetsError::DeploymentIdRequired(err.msg),
+                        &res,
+                    )
                 }
                 "DeploymentNotStartedException" => {
-                    return RusotoError::Service(ListDeploymentTargetsError::DeploymentNotStarted(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListDeploymentTargetsError::DeploymentNotStarted(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidDeploymentIdException" => {
-                    return RusotoError::Service(ListDeploymentTargetsError::InvalidDeploymentId(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListDeploymentTargetsError::InvalidDeploymentId(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidDeploymentInstanceTypeException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         ListDeploymentTargetsError::InvalidDeploymentInstanceType(err.msg),
+                        &res,
                     )
                 }
                 "InvalidInstanceStatusException" => {
-                    return RusotoError::Service(ListDeploymentTargetsError::InvalidInstanceStatus(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListDeploymentTargetsError::InvalidInstanceStatus(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidInstanceTypeException" => {
-                    return RusotoError::Service(ListDeploymentTargetsError::InvalidInstanceType(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListDeploymentTargetsError::InvalidInstanceType(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(ListDeploymentTargetsError::InvalidNextToken(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListDeploymentTargetsError::InvalidNextToken(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListDeploymentTargetsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -4994,12 +5388,14 @@ impl fmt::Display for ListDeploymentTargetsError {
             ListDeploymentTargetsError::InvalidInstanceStatus(ref cause) => write!(f, "{}", cause),
             ListDeploymentTargetsError::InvalidInstanceType(ref cause) => write!(f, "{}", cause),
             ListDeploymentTargetsError::InvalidNextToken(ref cause) => write!(f, "{}", cause),
+            ListDeploymentTargetsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListDeploymentTargetsError {}
 /// Errors returned by ListDeployments
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListDeploymentsError {
     /// <p>The application does not exist with the IAM user or AWS account.</p>
     ApplicationDoesNotExist(String),
@@ -5019,6 +5415,8 @@ pub enum ListDeploymentsError {
     InvalidNextToken(String),
     /// <p>The specified time range was specified in an invalid format.</p>
     InvalidTimeRange(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListDeploymentsError {
@@ -5026,48 +5424,61 @@ impl ListDeploymentsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ApplicationDoesNotExistException" => {
-                    return RusotoError::Service(ListDeploymentsError::ApplicationDoesNotExist(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListDeploymentsError::ApplicationDoesNotExist(err.msg),
+                        &res,
+                    )
                 }
                 "ApplicationNameRequiredException" => {
-                    return RusotoError::Service(ListDeploymentsError::ApplicationNameRequired(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListDeploymentsError::ApplicationNameRequired(err.msg),
+                        &res,
+                    )
                 }
                 "DeploymentGroupDoesNotExistException" => {
-                    return RusotoError::Service(ListDeploymentsError::DeploymentGroupDoesNotExist(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListDeploymentsError::DeploymentGroupDoesNotExist(err.msg),
+                        &res,
+                    )
                 }
                 "DeploymentGroupNameRequiredException" => {
-                    return RusotoError::Service(ListDeploymentsError::DeploymentGroupNameRequired(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListDeploymentsError::DeploymentGroupNameRequired(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidApplicationNameException" => {
-                    return RusotoError::Service(ListDeploymentsError::InvalidApplicationName(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListDeploymentsError::InvalidApplicationName(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidDeploymentGroupNameException" => {
-                    return RusotoError::Service(ListDeploymentsError::InvalidDeploymentGroupName(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListDeploymentsError::InvalidDeploymentGroupName(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidDeploymentStatusException" => {
-                    return RusotoError::Service(ListDeploymentsError::InvalidDeploymentStatus(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListDeploymentsError::InvalidDeploymentStatus(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(ListDeploymentsError::InvalidNextToken(err.msg))
+                    return RusotoError::service(
+                        ListDeploymentsError::InvalidNextToken(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidTimeRangeException" => {
-                    return RusotoError::Service(ListDeploymentsError::InvalidTimeRange(err.msg))
+                    return RusotoError::service(
+                        ListDeploymentsError::InvalidTimeRange(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListDeploymentsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -5086,12 +5497,14 @@ impl fmt::Display for ListDeploymentsError {
             ListDeploymentsError::InvalidDeploymentStatus(ref cause) => write!(f, "{}", cause),
             ListDeploymentsError::InvalidNextToken(ref cause) => write!(f, "{}", cause),
             ListDeploymentsError::InvalidTimeRange(ref cause) => write!(f, "{}", cause),
+            ListDeploymentsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListDeploymentsError {}
 /// Errors returned by ListGitHubAccountTokenNames
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListGitHubAccountTokenNamesError {
     /// <p>The next token was specified in an invalid format.</p>
     InvalidNextToken(String),
@@ -5099,6 +5512,8 @@ pub enum ListGitHubAccountTokenNamesError {
     OperationNotSupported(String),
     /// <p>The specified resource could not be validated.</p>
     ResourceValidation(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListGitHubAccountTokenNamesError {
@@ -5108,22 +5523,30 @@ impl ListGitHubAccountTokenNamesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         ListGitHubAccountTokenNamesError::InvalidNextToken(err.msg),
+                        &res,
                     )
                 }
                 "OperationNotSupportedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         ListGitHubAccountTokenNamesError::OperationNotSupported(err.msg),
+                        &res,
                     )
                 }
                 "ResourceValidationException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         ListGitHubAccountTokenNamesError::ResourceValidation(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        ListGitHubAccountTokenNamesError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -5140,12 +5563,14 @@ impl fmt::Display for ListGitHubAccountTokenNamesError {
             ListGitHubAccountTokenNamesError::ResourceValidation(ref cause) => {
                 write!(f, "{}", cause)
             }
+            ListGitHubAccountTokenNamesError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListGitHubAccountTokenNamesError {}
 /// Errors returned by ListOnPremisesInstances
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListOnPremisesInstancesError {
     /// <p>The next token was specified in an invalid format.</p>
     InvalidNextToken(String),
@@ -5153,6 +5578,8 @@ pub enum ListOnPremisesInstancesError {
     InvalidRegistrationStatus(String),
     /// <p>The tag filter was specified in an invalid format.</p>
     InvalidTagFilter(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListOnPremisesInstancesError {
@@ -5160,22 +5587,25 @@ impl ListOnPremisesInstancesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(ListOnPremisesInstancesError::InvalidNextToken(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListOnPremisesInstancesError::InvalidNextToken(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidRegistrationStatusException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         ListOnPremisesInstancesError::InvalidRegistrationStatus(err.msg),
+                        &res,
                     )
                 }
                 "InvalidTagFilterException" => {
-                    return RusotoError::Service(ListOnPremisesInstancesError::InvalidTagFilter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListOnPremisesInstancesError::InvalidTagFilter(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListOnPremisesInstancesError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -5190,12 +5620,14 @@ impl fmt::Display for ListOnPremisesInstancesError {
                 write!(f, "{}", cause)
             }
             ListOnPremisesInstancesError::InvalidTagFilter(ref cause) => write!(f, "{}", cause),
+            ListOnPremisesInstancesError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListOnPremisesInstancesError {}
 /// Errors returned by ListTagsForResource
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListTagsForResourceError {
     /// <p> The specified ARN is not supported. For example, it might be an ARN for a resource that is not expected. </p>
     ArnNotSupported(String),
@@ -5203,6 +5635,8 @@ pub enum ListTagsForResourceError {
     InvalidArn(String),
     /// <p> The ARN of a resource is required, but was not found. </p>
     ResourceArnRequired(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListTagsForResourceError {
@@ -5210,18 +5644,25 @@ impl ListTagsForResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ArnNotSupportedException" => {
-                    return RusotoError::Service(ListTagsForResourceError::ArnNotSupported(err.msg))
+                    return RusotoError::service(
+                        ListTagsForResourceError::ArnNotSupported(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidArnException" => {
-                    return RusotoError::Service(ListTagsForResourceError::InvalidArn(err.msg))
+                    return RusotoError::service(
+                        ListTagsForResourceError::InvalidArn(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceArnRequiredException" => {
-                    return RusotoError::Service(ListTagsForResourceError::ResourceArnRequired(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListTagsForResourceError::ResourceArnRequired(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListTagsForResourceError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -5234,12 +5675,14 @@ impl fmt::Display for ListTagsForResourceError {
             ListTagsForResourceError::ArnNotSupported(ref cause) => write!(f, "{}", cause),
             ListTagsForResourceError::InvalidArn(ref cause) => write!(f, "{}", cause),
             ListTagsForResourceError::ResourceArnRequired(ref cause) => write!(f, "{}", cause),
+            ListTagsForResourceError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListTagsForResourceError {}
 /// Errors returned by PutLifecycleEventHookExecutionStatus
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum PutLifecycleEventHookExecutionStatusError {
     /// <p>The deployment with the IAM user or AWS account does not exist.</p>
     DeploymentDoesNotExist(String),
@@ -5255,6 +5698,8 @@ pub enum PutLifecycleEventHookExecutionStatusError {
     LifecycleEventAlreadyCompleted(String),
     /// <p>A call was submitted that is not supported for the specified deployment type.</p>
     UnsupportedActionForDeploymentType(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl PutLifecycleEventHookExecutionStatusError {
@@ -5263,15 +5708,15 @@ impl PutLifecycleEventHookExecutionStatusError {
     ) -> RusotoError<PutLifecycleEventHookExecutionStatusError> {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
-                                "DeploymentDoesNotExistException" => return RusotoError::Service(PutLifecycleEventHookExecutionStatusError::DeploymentDoesNotExist(err.msg)),
-"DeploymentIdRequiredException" => return RusotoError::Service(PutLifecycleEventHookExecutionStatusError::DeploymentIdRequired(err.msg)),
-"InvalidDeploymentIdException" => return RusotoError::Service(PutLifecycleEventHookExecutionStatusError::InvalidDeploymentId(err.msg)),
-"InvalidLifecycleEventHookExecutionIdException" => return RusotoError::Service(PutLifecycleEventHookExecutionStatusError::InvalidLifecycleEventHookExecutionId(err.msg)),
-"InvalidLifecycleEventHookExecutionStatusException" => return RusotoError::Service(PutLifecycleEventHookExecutionStatusError::InvalidLifecycleEventHookExecutionStatus(err.msg)),
-"LifecycleEventAlreadyCompletedException" => return RusotoError::Service(PutLifecycleEventHookExecutionStatusError::LifecycleEventAlreadyCompleted(err.msg)),
-"UnsupportedActionForDeploymentTypeException" => return RusotoError::Service(PutLifecycleEventHookExecutionStatusError::UnsupportedActionForDeploymentType(err.msg)),
+                                "DeploymentDoesNotExistException" => return RusotoError::service(PutLifecycleEventHookExecutionStatusError::DeploymentDoesNotExist(err.msg), &res),
+"DeploymentIdRequiredException" => return RusotoError::service(PutLifecycleEventHookExecutionStatusError::DeploymentIdRequired(err.msg), &res),
+"InvalidDeploymentIdException" => return RusotoError::service(PutLifecycleEventHookExecutionStatusError::InvalidDeploymentId(err.msg), &res),
+"InvalidLifecycleEventHookExecutionIdException" => return RusotoError::service(PutLifecycleEventHookExecutionStatusError::InvalidLifecycleEventHookExecutionId(err.msg), &res),
+"InvalidLifecycleEventHookExecutionStatusException" => return RusotoError::service(PutLifecycleEventHookExecutionStatusError::InvalidLifecycleEventHookExecutionStatus(err.msg), &res),
+"LifecycleEventAlreadyCompletedException" => return RusotoError::service(PutLifecycleEventHookExecutionStatusError::LifecycleEventAlreadyCompleted(err.msg), &res),
+"UnsupportedActionForDeploymentTypeException" => return RusotoError::service(PutLifecycleEventHookExecutionStatusError::UnsupportedActionForDeploymentType(err.msg), &res),
 "ValidationException" => return RusotoError::Validation(err.msg),
-_ => {}
+_ => return RusotoError::unhandled(PutLifecycleEventHookExecutionStatusError::Unhandled, &res),
                             }
         }
         RusotoError::Unknown(res)
@@ -5302,12 +5747,16 @@ impl fmt::Display for PutLifecycleEventHookExecutionStatusError {
             PutLifecycleEventHookExecutionStatusError::UnsupportedActionForDeploymentType(
                 ref cause,
             ) => write!(f, "{}", cause),
+            PutLifecycleEventHookExecutionStatusError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for PutLifecycleEventHookExecutionStatusError {}
 /// Errors returned by RegisterApplicationRevision
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum RegisterApplicationRevisionError {
     /// <p>The application does not exist with the IAM user or AWS account.</p>
     ApplicationDoesNotExist(String),
@@ -5321,6 +5770,8 @@ pub enum RegisterApplicationRevisionError {
     InvalidRevision(String),
     /// <p>The revision ID was not specified.</p>
     RevisionRequired(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl RegisterApplicationRevisionError {
@@ -5330,37 +5781,48 @@ impl RegisterApplicationRevisionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ApplicationDoesNotExistException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         RegisterApplicationRevisionError::ApplicationDoesNotExist(err.msg),
+                        &res,
                     )
                 }
                 "ApplicationNameRequiredException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         RegisterApplicationRevisionError::ApplicationNameRequired(err.msg),
+                        &res,
                     )
                 }
                 "DescriptionTooLongException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         RegisterApplicationRevisionError::DescriptionTooLong(err.msg),
+                        &res,
                     )
                 }
                 "InvalidApplicationNameException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         RegisterApplicationRevisionError::InvalidApplicationName(err.msg),
+                        &res,
                     )
                 }
                 "InvalidRevisionException" => {
-                    return RusotoError::Service(RegisterApplicationRevisionError::InvalidRevision(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        RegisterApplicationRevisionError::InvalidRevision(err.msg),
+                        &res,
+                    )
                 }
                 "RevisionRequiredException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         RegisterApplicationRevisionError::RevisionRequired(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        RegisterApplicationRevisionError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -5384,12 +5846,14 @@ impl fmt::Display for RegisterApplicationRevisionError {
             }
             RegisterApplicationRevisionError::InvalidRevision(ref cause) => write!(f, "{}", cause),
             RegisterApplicationRevisionError::RevisionRequired(ref cause) => write!(f, "{}", cause),
+            RegisterApplicationRevisionError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for RegisterApplicationRevisionError {}
 /// Errors returned by RegisterOnPremisesInstance
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum RegisterOnPremisesInstanceError {
     /// <p>No IAM ARN was included in the request. You must use an IAM session ARN or IAM user ARN in the request.</p>
     IamArnRequired(String),
@@ -5411,6 +5875,8 @@ pub enum RegisterOnPremisesInstanceError {
     InvalidInstanceName(String),
     /// <p>Both an IAM user ARN and an IAM session ARN were included in the request. Use only one ARN type.</p>
     MultipleIamArnsProvided(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl RegisterOnPremisesInstanceError {
@@ -5420,57 +5886,69 @@ impl RegisterOnPremisesInstanceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "IamArnRequiredException" => {
-                    return RusotoError::Service(RegisterOnPremisesInstanceError::IamArnRequired(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        RegisterOnPremisesInstanceError::IamArnRequired(err.msg),
+                        &res,
+                    )
                 }
                 "IamSessionArnAlreadyRegisteredException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         RegisterOnPremisesInstanceError::IamSessionArnAlreadyRegistered(err.msg),
+                        &res,
                     )
                 }
                 "IamUserArnAlreadyRegisteredException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         RegisterOnPremisesInstanceError::IamUserArnAlreadyRegistered(err.msg),
+                        &res,
                     )
                 }
                 "IamUserArnRequiredException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         RegisterOnPremisesInstanceError::IamUserArnRequired(err.msg),
+                        &res,
                     )
                 }
                 "InstanceNameAlreadyRegisteredException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         RegisterOnPremisesInstanceError::InstanceNameAlreadyRegistered(err.msg),
+                        &res,
                     )
                 }
                 "InstanceNameRequiredException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         RegisterOnPremisesInstanceError::InstanceNameRequired(err.msg),
+                        &res,
                     )
                 }
                 "InvalidIamSessionArnException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         RegisterOnPremisesInstanceError::InvalidIamSessionArn(err.msg),
+                        &res,
                     )
                 }
                 "InvalidIamUserArnException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         RegisterOnPremisesInstanceError::InvalidIamUserArn(err.msg),
+                        &res,
                     )
                 }
                 "InvalidInstanceNameException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         RegisterOnPremisesInstanceError::InvalidInstanceName(err.msg),
+                        &res,
                     )
                 }
                 "MultipleIamArnsProvidedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         RegisterOnPremisesInstanceError::MultipleIamArnsProvided(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(RegisterOnPremisesInstanceError::Unhandled, &res)
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -5506,12 +5984,14 @@ impl fmt::Display for RegisterOnPremisesInstanceError {
             RegisterOnPremisesInstanceError::MultipleIamArnsProvided(ref cause) => {
                 write!(f, "{}", cause)
             }
+            RegisterOnPremisesInstanceError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for RegisterOnPremisesInstanceError {}
 /// Errors returned by RemoveTagsFromOnPremisesInstances
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum RemoveTagsFromOnPremisesInstancesError {
     /// <p>The maximum number of allowed on-premises instances in a single call was exceeded.</p>
     InstanceLimitExceeded(String),
@@ -5527,6 +6007,8 @@ pub enum RemoveTagsFromOnPremisesInstancesError {
     TagLimitExceeded(String),
     /// <p>A tag was not specified.</p>
     TagRequired(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl RemoveTagsFromOnPremisesInstancesError {
@@ -5536,42 +6018,54 @@ impl RemoveTagsFromOnPremisesInstancesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InstanceLimitExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         RemoveTagsFromOnPremisesInstancesError::InstanceLimitExceeded(err.msg),
+                        &res,
                     )
                 }
                 "InstanceNameRequiredException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         RemoveTagsFromOnPremisesInstancesError::InstanceNameRequired(err.msg),
+                        &res,
                     )
                 }
                 "InstanceNotRegisteredException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         RemoveTagsFromOnPremisesInstancesError::InstanceNotRegistered(err.msg),
+                        &res,
                     )
                 }
                 "InvalidInstanceNameException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         RemoveTagsFromOnPremisesInstancesError::InvalidInstanceName(err.msg),
+                        &res,
                     )
                 }
                 "InvalidTagException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         RemoveTagsFromOnPremisesInstancesError::InvalidTag(err.msg),
+                        &res,
                     )
                 }
                 "TagLimitExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         RemoveTagsFromOnPremisesInstancesError::TagLimitExceeded(err.msg),
+                        &res,
                     )
                 }
                 "TagRequiredException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         RemoveTagsFromOnPremisesInstancesError::TagRequired(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        RemoveTagsFromOnPremisesInstancesError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -5600,12 +6094,16 @@ impl fmt::Display for RemoveTagsFromOnPremisesInstancesError {
             RemoveTagsFromOnPremisesInstancesError::TagRequired(ref cause) => {
                 write!(f, "{}", cause)
             }
+            RemoveTagsFromOnPremisesInstancesError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for RemoveTagsFromOnPremisesInstancesError {}
 /// Errors returned by SkipWaitTimeForInstanceTermination
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum SkipWaitTimeForInstanceTerminationError {
     /// <p>The deployment is already complete.</p>
     DeploymentAlreadyCompleted(String),
@@ -5619,6 +6117,8 @@ pub enum SkipWaitTimeForInstanceTerminationError {
     InvalidDeploymentId(String),
     /// <p>A call was submitted that is not supported for the specified deployment type.</p>
     UnsupportedActionForDeploymentType(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl SkipWaitTimeForInstanceTerminationError {
@@ -5628,41 +6128,52 @@ impl SkipWaitTimeForInstanceTerminationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DeploymentAlreadyCompletedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         SkipWaitTimeForInstanceTerminationError::DeploymentAlreadyCompleted(
                             err.msg,
                         ),
+                        &res,
                     )
                 }
                 "DeploymentDoesNotExistException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         SkipWaitTimeForInstanceTerminationError::DeploymentDoesNotExist(err.msg),
+                        &res,
                     )
                 }
                 "DeploymentIdRequiredException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         SkipWaitTimeForInstanceTerminationError::DeploymentIdRequired(err.msg),
+                        &res,
                     )
                 }
                 "DeploymentNotStartedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         SkipWaitTimeForInstanceTerminationError::DeploymentNotStarted(err.msg),
+                        &res,
                     )
                 }
                 "InvalidDeploymentIdException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         SkipWaitTimeForInstanceTerminationError::InvalidDeploymentId(err.msg),
+                        &res,
                     )
                 }
                 "UnsupportedActionForDeploymentTypeException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         SkipWaitTimeForInstanceTerminationError::UnsupportedActionForDeploymentType(
                             err.msg,
                         ),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        SkipWaitTimeForInstanceTerminationError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -5690,12 +6201,16 @@ impl fmt::Display for SkipWaitTimeForInstanceTerminationError {
             SkipWaitTimeForInstanceTerminationError::UnsupportedActionForDeploymentType(
                 ref cause,
             ) => write!(f, "{}", cause),
+            SkipWaitTimeForInstanceTerminationError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for SkipWaitTimeForInstanceTerminationError {}
 /// Errors returned by StopDeployment
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum StopDeploymentError {
     /// <p>The deployment is already complete.</p>
     DeploymentAlreadyCompleted(String),
@@ -5707,6 +6222,8 @@ pub enum StopDeploymentError {
     DeploymentIdRequired(String),
     /// <p>At least one of the deployment IDs was specified in an invalid format.</p>
     InvalidDeploymentId(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl StopDeploymentError {
@@ -5714,28 +6231,37 @@ impl StopDeploymentError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DeploymentAlreadyCompletedException" => {
-                    return RusotoError::Service(StopDeploymentError::DeploymentAlreadyCompleted(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        StopDeploymentError::DeploymentAlreadyCompleted(err.msg),
+                        &res,
+                    )
                 }
                 "DeploymentDoesNotExistException" => {
-                    return RusotoError::Service(StopDeploymentError::DeploymentDoesNotExist(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        StopDeploymentError::DeploymentDoesNotExist(err.msg),
+                        &res,
+                    )
                 }
                 "DeploymentGroupDoesNotExistException" => {
-                    return RusotoError::Service(StopDeploymentError::DeploymentGroupDoesNotExist(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        StopDeploymentError::DeploymentGroupDoesNotExist(err.msg),
+                        &res,
+                    )
                 }
                 "DeploymentIdRequiredException" => {
-                    return RusotoError::Service(StopDeploymentError::DeploymentIdRequired(err.msg))
+                    return RusotoError::service(
+                        StopDeploymentError::DeploymentIdRequired(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidDeploymentIdException" => {
-                    return RusotoError::Service(StopDeploymentError::InvalidDeploymentId(err.msg))
+                    return RusotoError::service(
+                        StopDeploymentError::InvalidDeploymentId(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(StopDeploymentError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -5750,12 +6276,14 @@ impl fmt::Display for StopDeploymentError {
             StopDeploymentError::DeploymentGroupDoesNotExist(ref cause) => write!(f, "{}", cause),
             StopDeploymentError::DeploymentIdRequired(ref cause) => write!(f, "{}", cause),
             StopDeploymentError::InvalidDeploymentId(ref cause) => write!(f, "{}", cause),
+            StopDeploymentError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for StopDeploymentError {}
 /// Errors returned by TagResource
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum TagResourceError {
     /// <p>The application does not exist with the IAM user or AWS account.</p>
     ApplicationDoesNotExist(String),
@@ -5773,6 +6301,8 @@ pub enum TagResourceError {
     ResourceArnRequired(String),
     /// <p>A tag was not specified.</p>
     TagRequired(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl TagResourceError {
@@ -5780,35 +6310,43 @@ impl TagResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ApplicationDoesNotExistException" => {
-                    return RusotoError::Service(TagResourceError::ApplicationDoesNotExist(err.msg))
+                    return RusotoError::service(
+                        TagResourceError::ApplicationDoesNotExist(err.msg),
+                        &res,
+                    )
                 }
                 "ArnNotSupportedException" => {
-                    return RusotoError::Service(TagResourceError::ArnNotSupported(err.msg))
+                    return RusotoError::service(TagResourceError::ArnNotSupported(err.msg), &res)
                 }
                 "DeploymentConfigDoesNotExistException" => {
-                    return RusotoError::Service(TagResourceError::DeploymentConfigDoesNotExist(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        TagResourceError::DeploymentConfigDoesNotExist(err.msg),
+                        &res,
+                    )
                 }
                 "DeploymentGroupDoesNotExistException" => {
-                    return RusotoError::Service(TagResourceError::DeploymentGroupDoesNotExist(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        TagResourceError::DeploymentGroupDoesNotExist(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidArnException" => {
-                    return RusotoError::Service(TagResourceError::InvalidArn(err.msg))
+                    return RusotoError::service(TagResourceError::InvalidArn(err.msg), &res)
                 }
                 "InvalidTagsToAddException" => {
-                    return RusotoError::Service(TagResourceError::InvalidTagsToAdd(err.msg))
+                    return RusotoError::service(TagResourceError::InvalidTagsToAdd(err.msg), &res)
                 }
                 "ResourceArnRequiredException" => {
-                    return RusotoError::Service(TagResourceError::ResourceArnRequired(err.msg))
+                    return RusotoError::service(
+                        TagResourceError::ResourceArnRequired(err.msg),
+                        &res,
+                    )
                 }
                 "TagRequiredException" => {
-                    return RusotoError::Service(TagResourceError::TagRequired(err.msg))
+                    return RusotoError::service(TagResourceError::TagRequired(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(TagResourceError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -5826,12 +6364,14 @@ impl fmt::Display for TagResourceError {
             TagResourceError::InvalidTagsToAdd(ref cause) => write!(f, "{}", cause),
             TagResourceError::ResourceArnRequired(ref cause) => write!(f, "{}", cause),
             TagResourceError::TagRequired(ref cause) => write!(f, "{}", cause),
+            TagResourceError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for TagResourceError {}
 /// Errors returned by UntagResource
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UntagResourceError {
     /// <p>The application does not exist with the IAM user or AWS account.</p>
     ApplicationDoesNotExist(String),
@@ -5849,6 +6389,8 @@ pub enum UntagResourceError {
     ResourceArnRequired(String),
     /// <p>A tag was not specified.</p>
     TagRequired(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UntagResourceError {
@@ -5856,37 +6398,46 @@ impl UntagResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ApplicationDoesNotExistException" => {
-                    return RusotoError::Service(UntagResourceError::ApplicationDoesNotExist(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UntagResourceError::ApplicationDoesNotExist(err.msg),
+                        &res,
+                    )
                 }
                 "ArnNotSupportedException" => {
-                    return RusotoError::Service(UntagResourceError::ArnNotSupported(err.msg))
+                    return RusotoError::service(UntagResourceError::ArnNotSupported(err.msg), &res)
                 }
                 "DeploymentConfigDoesNotExistException" => {
-                    return RusotoError::Service(UntagResourceError::DeploymentConfigDoesNotExist(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UntagResourceError::DeploymentConfigDoesNotExist(err.msg),
+                        &res,
+                    )
                 }
                 "DeploymentGroupDoesNotExistException" => {
-                    return RusotoError::Service(UntagResourceError::DeploymentGroupDoesNotExist(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UntagResourceError::DeploymentGroupDoesNotExist(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidArnException" => {
-                    return RusotoError::Service(UntagResourceError::InvalidArn(err.msg))
+                    return RusotoError::service(UntagResourceError::InvalidArn(err.msg), &res)
                 }
                 "InvalidTagsToAddException" => {
-                    return RusotoError::Service(UntagResourceError::InvalidTagsToAdd(err.msg))
+                    return RusotoError::service(
+                        UntagResourceError::InvalidTagsToAdd(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceArnRequiredException" => {
-                    return RusotoError::Service(UntagResourceError::ResourceArnRequired(err.msg))
+                    return RusotoError::service(
+                        UntagResourceError::ResourceArnRequired(err.msg),
+                        &res,
+                    )
                 }
                 "TagRequiredException" => {
-                    return RusotoError::Service(UntagResourceError::TagRequired(err.msg))
+                    return RusotoError::service(UntagResourceError::TagRequired(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UntagResourceError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -5904,12 +6455,14 @@ impl fmt::Display for UntagResourceError {
             UntagResourceError::InvalidTagsToAdd(ref cause) => write!(f, "{}", cause),
             UntagResourceError::ResourceArnRequired(ref cause) => write!(f, "{}", cause),
             UntagResourceError::TagRequired(ref cause) => write!(f, "{}", cause),
+            UntagResourceError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for UntagResourceError {}
 /// Errors returned by UpdateApplication
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdateApplicationError {
     /// <p>An application with the specified name with the IAM user or AWS account already exists.</p>
     ApplicationAlreadyExists(String),
@@ -5919,6 +6472,8 @@ pub enum UpdateApplicationError {
     ApplicationNameRequired(String),
     /// <p>The application name was specified in an invalid format.</p>
     InvalidApplicationName(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdateApplicationError {
@@ -5926,27 +6481,31 @@ impl UpdateApplicationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ApplicationAlreadyExistsException" => {
-                    return RusotoError::Service(UpdateApplicationError::ApplicationAlreadyExists(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateApplicationError::ApplicationAlreadyExists(err.msg),
+                        &res,
+                    )
                 }
                 "ApplicationDoesNotExistException" => {
-                    return RusotoError::Service(UpdateApplicationError::ApplicationDoesNotExist(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateApplicationError::ApplicationDoesNotExist(err.msg),
+                        &res,
+                    )
                 }
                 "ApplicationNameRequiredException" => {
-                    return RusotoError::Service(UpdateApplicationError::ApplicationNameRequired(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateApplicationError::ApplicationNameRequired(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidApplicationNameException" => {
-                    return RusotoError::Service(UpdateApplicationError::InvalidApplicationName(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateApplicationError::InvalidApplicationName(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UpdateApplicationError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -5960,12 +6519,14 @@ impl fmt::Display for UpdateApplicationError {
             UpdateApplicationError::ApplicationDoesNotExist(ref cause) => write!(f, "{}", cause),
             UpdateApplicationError::ApplicationNameRequired(ref cause) => write!(f, "{}", cause),
             UpdateApplicationError::InvalidApplicationName(ref cause) => write!(f, "{}", cause),
+            UpdateApplicationError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for UpdateApplicationError {}
 /// Errors returned by UpdateDeploymentGroup
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdateDeploymentGroupError {
     /// <p>The maximum number of alarms for a deployment group (10) was exceeded.</p>
     AlarmsLimitExceeded(String),
@@ -6027,6 +6588,8 @@ pub enum UpdateDeploymentGroupError {
     Throttling(String),
     /// <p>The maximum allowed number of triggers was exceeded.</p>
     TriggerTargetsLimitExceeded(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdateDeploymentGroupError {
@@ -6034,149 +6597,189 @@ impl UpdateDeploymentGroupError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AlarmsLimitExceededException" => {
-                    return RusotoError::Service(UpdateDeploymentGroupError::AlarmsLimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateDeploymentGroupError::AlarmsLimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "ApplicationDoesNotExistException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         UpdateDeploymentGroupError::ApplicationDoesNotExist(err.msg),
+                        &res,
                     )
                 }
                 "ApplicationNameRequiredException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         UpdateDeploymentGroupError::ApplicationNameRequired(err.msg),
+                        &res,
                     )
                 }
                 "DeploymentConfigDoesNotExistException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         UpdateDeploymentGroupError::DeploymentConfigDoesNotExist(err.msg),
+                        &res,
                     )
                 }
                 "DeploymentGroupAlreadyExistsException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         UpdateDeploymentGroupError::DeploymentGroupAlreadyExists(err.msg),
+                        &res,
                     )
                 }
                 "DeploymentGroupDoesNotExistException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         UpdateDeploymentGroupError::DeploymentGroupDoesNotExist(err.msg),
+                        &res,
                     )
                 }
                 "DeploymentGroupNameRequiredException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         UpdateDeploymentGroupError::DeploymentGroupNameRequired(err.msg),
+                        &res,
                     )
                 }
                 "ECSServiceMappingLimitExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         UpdateDeploymentGroupError::ECSServiceMappingLimitExceeded(err.msg),
+                        &res,
                     )
                 }
                 "InvalidAlarmConfigException" => {
-                    return RusotoError::Service(UpdateDeploymentGroupError::InvalidAlarmConfig(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateDeploymentGroupError::InvalidAlarmConfig(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidApplicationNameException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         UpdateDeploymentGroupError::InvalidApplicationName(err.msg),
+                        &res,
                     )
                 }
                 "InvalidAutoRollbackConfigException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         UpdateDeploymentGroupError::InvalidAutoRollbackConfig(err.msg),
+                        &res,
                     )
                 }
                 "InvalidAutoScalingGroupException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         UpdateDeploymentGroupError::InvalidAutoScalingGroup(err.msg),
+                        &res,
                     )
                 }
                 "InvalidBlueGreenDeploymentConfigurationException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         UpdateDeploymentGroupError::InvalidBlueGreenDeploymentConfiguration(
                             err.msg,
                         ),
+                        &res,
                     )
                 }
                 "InvalidDeploymentConfigNameException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         UpdateDeploymentGroupError::InvalidDeploymentConfigName(err.msg),
+                        &res,
                     )
                 }
                 "InvalidDeploymentGroupNameException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         UpdateDeploymentGroupError::InvalidDeploymentGroupName(err.msg),
+                        &res,
                     )
                 }
                 "InvalidDeploymentStyleException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         UpdateDeploymentGroupError::InvalidDeploymentStyle(err.msg),
+                        &res,
                     )
                 }
                 "InvalidEC2TagCombinationException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         UpdateDeploymentGroupError::InvalidEC2TagCombination(err.msg),
+                        &res,
                     )
                 }
                 "InvalidEC2TagException" => {
-                    return RusotoError::Service(UpdateDeploymentGroupError::InvalidEC2Tag(err.msg))
+                    return RusotoError::service(
+                        UpdateDeploymentGroupError::InvalidEC2Tag(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidECSServiceException" => {
-                    return RusotoError::Service(UpdateDeploymentGroupError::InvalidECSService(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateDeploymentGroupError::InvalidECSService(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(UpdateDeploymentGroupError::InvalidInput(err.msg))
+                    return RusotoError::service(
+                        UpdateDeploymentGroupError::InvalidInput(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidLoadBalancerInfoException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         UpdateDeploymentGroupError::InvalidLoadBalancerInfo(err.msg),
+                        &res,
                     )
                 }
                 "InvalidOnPremisesTagCombinationException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         UpdateDeploymentGroupError::InvalidOnPremisesTagCombination(err.msg),
+                        &res,
                     )
                 }
                 "InvalidRoleException" => {
-                    return RusotoError::Service(UpdateDeploymentGroupError::InvalidRole(err.msg))
+                    return RusotoError::service(
+                        UpdateDeploymentGroupError::InvalidRole(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidTagException" => {
-                    return RusotoError::Service(UpdateDeploymentGroupError::InvalidTag(err.msg))
+                    return RusotoError::service(
+                        UpdateDeploymentGroupError::InvalidTag(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidTargetGroupPairException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         UpdateDeploymentGroupError::InvalidTargetGroupPair(err.msg),
+                        &res,
                     )
                 }
                 "InvalidTriggerConfigException" => {
-                    return RusotoError::Service(UpdateDeploymentGroupError::InvalidTriggerConfig(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateDeploymentGroupError::InvalidTriggerConfig(err.msg),
+                        &res,
+                    )
                 }
                 "LifecycleHookLimitExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         UpdateDeploymentGroupError::LifecycleHookLimitExceeded(err.msg),
+                        &res,
                     )
                 }
                 "TagSetListLimitExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         UpdateDeploymentGroupError::TagSetListLimitExceeded(err.msg),
+                        &res,
                     )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(UpdateDeploymentGroupError::Throttling(err.msg))
+                    return RusotoError::service(
+                        UpdateDeploymentGroupError::Throttling(err.msg),
+                        &res,
+                    )
                 }
                 "TriggerTargetsLimitExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         UpdateDeploymentGroupError::TriggerTargetsLimitExceeded(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UpdateDeploymentGroupError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -6252,6 +6855,7 @@ impl fmt::Display for UpdateDeploymentGroupError {
             UpdateDeploymentGroupError::TriggerTargetsLimitExceeded(ref cause) => {
                 write!(f, "{}", cause)
             }
+            UpdateDeploymentGroupError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
@@ -6549,6 +7153,7 @@ impl CodeDeployClient {
     /// Creates a client backed by the default tokio event loop.
     ///
     /// The client will use the default credentials provider and tls client.
+    #[cfg(not(target_arch = "wasm32"))]
     pub fn new(region: region::Region) -> CodeDeployClient {
         CodeDeployClient {
             client: Client::shared(),
@@ -6574,16 +7179,65 @@ impl CodeDeployClient {
     pub fn new_with_client(client: Client, region: region::Region) -> CodeDeployClient {
         CodeDeployClient { client, region }
     }
+
+    /// Returns a builder of a client, setting any of its region, credentials provider,
+    /// dispatcher, retry policy, endpoint and timeouts.
+    pub fn builder() -> rusoto_core::ClientBuilder<CodeDeployClient> {
+        rusoto_core::ClientBuilder::new(CodeDeployClient::new_with_client)
+    }
+
+    /// Returns a clone of this client with the overrides of `config` applied to its
+    /// operations, sharing its connections.
+    pub fn customize(&self, config: &rusoto_core::OperationConfig) -> CodeDeployClient {
+        CodeDeployClient {
+            client: self.client.customize(config),
+            region: config.resolve_region(&self.region),
+        }
+    }
 }
 
-#[async_trait]
-impl CodeDeploy for CodeDeployClient {
-    /// <p>Adds tags to on-premises instances.</p>
-    async fn add_tags_to_on_premises_instances(
+impl CodeDeployClient {
+    /// Calls `add_tags_to_on_premises_instances`, also returning the metadata of the response.
+    pub async fn add_tags_to_on_premises_instances_with_metadata(
         &self,
         input: AddTagsToOnPremisesInstancesInput,
-    ) -> Result<(), RusotoError<AddTagsToOnPremisesInstancesError>> {
+    ) -> Result<((), ResponseMetadata), RusotoError<AddTagsToOnPremisesInstancesError>> {
+        let request = self.build_add_tags_to_on_premises_instances_request(input);
+
+        let mut response = self
+            .client
+            .sign_and_dispatch(request)
+            .await
+            .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
+        if response.status.is_success() {
+            std::mem::drop(response);
+            Ok(((), metadata))
+        } else {
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(AddTagsToOnPremisesInstancesError::from_response(response))
+        }
+    }
+
+    /// Presigns the request `add_tags_to_on_premises_instances` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_add_tags_to_on_premises_instances(
+        &self,
+        presigner: &rusoto_core::Presigner,
+        input: AddTagsToOnPremisesInstancesInput,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_add_tags_to_on_premises_instances_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_add_tags_to_on_premises_instances_request(
+        &self,
+        input: AddTagsToOnPremisesInstancesInput,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "codedeploy", &self.region, "/");
+        request.set_operation("AddTagsToOnPremisesInstances");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -6593,28 +7247,58 @@ impl CodeDeploy for CodeDeployClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `batch_get_application_revisions`, also returning the metadata of the response.
+    pub async fn batch_get_application_revisions_with_metadata(
+        &self,
+        input: BatchGetApplicationRevisionsInput,
+    ) -> Result<
+        (BatchGetApplicationRevisionsOutput, ResponseMetadata),
+        RusotoError<BatchGetApplicationRevisionsError>,
+    > {
+        let request = self.build_batch_get_application_revisions_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            std::mem::drop(response);
-            Ok(())
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<BatchGetApplicationRevisionsOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(AddTagsToOnPremisesInstancesError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(BatchGetApplicationRevisionsError::from_response(response))
         }
     }
 
-    /// <p>Gets information about one or more application revisions. The maximum number of application revisions that can be returned is 25.</p>
-    async fn batch_get_application_revisions(
+    /// Presigns the request `batch_get_application_revisions` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_batch_get_application_revisions(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: BatchGetApplicationRevisionsInput,
-    ) -> Result<BatchGetApplicationRevisionsOutput, RusotoError<BatchGetApplicationRevisionsError>>
-    {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_batch_get_application_revisions_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_batch_get_application_revisions_request(
+        &self,
+        input: BatchGetApplicationRevisionsInput,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "codedeploy", &self.region, "/");
+        request.set_operation("BatchGetApplicationRevisions");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -6624,56 +7308,116 @@ impl CodeDeploy for CodeDeployClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `batch_get_applications`, also returning the metadata of the response.
+    pub async fn batch_get_applications_with_metadata(
+        &self,
+        input: BatchGetApplicationsInput,
+    ) -> Result<
+        (BatchGetApplicationsOutput, ResponseMetadata),
+        RusotoError<BatchGetApplicationsError>,
+    > {
+        let request = self.build_batch_get_applications_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<BatchGetApplicationRevisionsOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<BatchGetApplicationsOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(BatchGetApplicationRevisionsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(BatchGetApplicationsError::from_response(response))
         }
     }
 
-    /// <p>Gets information about one or more applications. The maximum number of applications that can be returned is 25.</p>
-    async fn batch_get_applications(
+    /// Presigns the request `batch_get_applications` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_batch_get_applications(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: BatchGetApplicationsInput,
-    ) -> Result<BatchGetApplicationsOutput, RusotoError<BatchGetApplicationsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_batch_get_applications_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_batch_get_applications_request(
+        &self,
+        input: BatchGetApplicationsInput,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "codedeploy", &self.region, "/");
+        request.set_operation("BatchGetApplications");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "CodeDeploy_20141006.BatchGetApplications");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `batch_get_deployment_groups`, also returning the metadata of the response.
+    pub async fn batch_get_deployment_groups_with_metadata(
+        &self,
+        input: BatchGetDeploymentGroupsInput,
+    ) -> Result<
+        (BatchGetDeploymentGroupsOutput, ResponseMetadata),
+        RusotoError<BatchGetDeploymentGroupsError>,
+    > {
+        let request = self.build_batch_get_deployment_groups_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<BatchGetApplicationsOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<BatchGetDeploymentGroupsOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(BatchGetApplicationsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(BatchGetDeploymentGroupsError::from_response(response))
         }
     }
 
-    /// <p>Gets information about one or more deployment groups.</p>
-    async fn batch_get_deployment_groups(
+    /// Presigns the request `batch_get_deployment_groups` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_batch_get_deployment_groups(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: BatchGetDeploymentGroupsInput,
-    ) -> Result<BatchGetDeploymentGroupsOutput, RusotoError<BatchGetDeploymentGroupsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_batch_get_deployment_groups_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_batch_get_deployment_groups_request(
+        &self,
+        input: BatchGetDeploymentGroupsInput,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "codedeploy", &self.region, "/");
+        request.set_operation("BatchGetDeploymentGroups");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -6683,29 +7427,58 @@ impl CodeDeploy for CodeDeployClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `batch_get_deployment_instances`, also returning the metadata of the response.
+    pub async fn batch_get_deployment_instances_with_metadata(
+        &self,
+        input: BatchGetDeploymentInstancesInput,
+    ) -> Result<
+        (BatchGetDeploymentInstancesOutput, ResponseMetadata),
+        RusotoError<BatchGetDeploymentInstancesError>,
+    > {
+        let request = self.build_batch_get_deployment_instances_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<BatchGetDeploymentGroupsOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<BatchGetDeploymentInstancesOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(BatchGetDeploymentGroupsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(BatchGetDeploymentInstancesError::from_response(response))
         }
     }
 
-    /// <p><note> <p> This method works, but is deprecated. Use <code>BatchGetDeploymentTargets</code> instead. </p> </note> <p> Returns an array of one or more instances associated with a deployment. This method works with EC2/On-premises and AWS Lambda compute platforms. The newer <code>BatchGetDeploymentTargets</code> works with all compute platforms. The maximum number of instances that can be returned is 25.</p></p>
-    async fn batch_get_deployment_instances(
+    /// Presigns the request `batch_get_deployment_instances` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_batch_get_deployment_instances(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: BatchGetDeploymentInstancesInput,
-    ) -> Result<BatchGetDeploymentInstancesOutput, RusotoError<BatchGetDeploymentInstancesError>>
-    {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_batch_get_deployment_instances_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_batch_get_deployment_instances_request(
+        &self,
+        input: BatchGetDeploymentInstancesInput,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "codedeploy", &self.region, "/");
+        request.set_operation("BatchGetDeploymentInstances");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -6715,28 +7488,58 @@ impl CodeDeploy for CodeDeployClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
-        let mut response = self
+        request
+    }
+
+    /// Calls `batch_get_deployment_targets`, also returning the metadata of the response.
+    pub async fn batch_get_deployment_targets_with_metadata(
+        &self,
+        input: BatchGetDeploymentTargetsInput,
+    ) -> Result<
+        (BatchGetDeploymentTargetsOutput, ResponseMetadata),
+        RusotoError<BatchGetDeploymentTargetsError>,
+    > {
+        let request = self.build_batch_get_deployment_targets_request(input);
+
+        let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<BatchGetDeploymentInstancesOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<BatchGetDeploymentTargetsOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(BatchGetDeploymentInstancesError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(BatchGetDeploymentTargetsError::from_response(response))
         }
     }
 
-    /// <p><p> Returns an array of one or more targets associated with a deployment. This method works with all compute types and should be used instead of the deprecated <code>BatchGetDeploymentInstances</code>. The maximum number of targets that can be returned is 25.</p> <p> The type of targets returned depends on the deployment&#39;s compute platform: </p> <ul> <li> <p> <b>EC2/On-premises</b>: Information about EC2 instance targets. </p> </li> <li> <p> <b>AWS Lambda</b>: Information about Lambda functions targets. </p> </li> <li> <p> <b>Amazon ECS</b>: Information about Amazon ECS service targets. </p> </li> </ul></p>
-    async fn batch_get_deployment_targets(
+    /// Presigns the request `batch_get_deployment_targets` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_batch_get_deployment_targets(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: BatchGetDeploymentTargetsInput,
-    ) -> Result<BatchGetDeploymentTargetsOutput, RusotoError<BatchGetDeploymentTargetsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_batch_get_deployment_targets_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_batch_get_deployment_targets_request(
+        &self,
+        input: BatchGetDeploymentTargetsInput,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "codedeploy", &self.region, "/");
+        request.set_operation("BatchGetDeploymentTargets");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -6746,57 +7549,114 @@ impl CodeDeploy for CodeDeployClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `batch_get_deployments`, also returning the metadata of the response.
+    pub async fn batch_get_deployments_with_metadata(
+        &self,
+        input: BatchGetDeploymentsInput,
+    ) -> Result<(BatchGetDeploymentsOutput, ResponseMetadata), RusotoError<BatchGetDeploymentsError>>
+    {
+        let request = self.build_batch_get_deployments_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<BatchGetDeploymentTargetsOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<BatchGetDeploymentsOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(BatchGetDeploymentTargetsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(BatchGetDeploymentsError::from_response(response))
         }
     }
 
-    /// <p>Gets information about one or more deployments. The maximum number of deployments that can be returned is 25.</p>
-    async fn batch_get_deployments(
+    /// Presigns the request `batch_get_deployments` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_batch_get_deployments(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: BatchGetDeploymentsInput,
-    ) -> Result<BatchGetDeploymentsOutput, RusotoError<BatchGetDeploymentsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_batch_get_deployments_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_batch_get_deployments_request(
+        &self,
+        input: BatchGetDeploymentsInput,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "codedeploy", &self.region, "/");
+        request.set_operation("BatchGetDeployments");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "CodeDeploy_20141006.BatchGetDeployments");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `batch_get_on_premises_instances`, also returning the metadata of the response.
+    pub async fn batch_get_on_premises_instances_with_metadata(
+        &self,
+        input: BatchGetOnPremisesInstancesInput,
+    ) -> Result<
+        (BatchGetOnPremisesInstancesOutput, ResponseMetadata),
+        RusotoError<BatchGetOnPremisesInstancesError>,
+    > {
+        let request = self.build_batch_get_on_premises_instances_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<BatchGetDeploymentsOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<BatchGetOnPremisesInstancesOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(BatchGetDeploymentsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(BatchGetOnPremisesInstancesError::from_response(response))
         }
     }
 
-    /// <p>Gets information about one or more on-premises instances. The maximum number of on-premises instances that can be returned is 25.</p>
-    async fn batch_get_on_premises_instances(
+    /// Presigns the request `batch_get_on_premises_instances` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_batch_get_on_premises_instances(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: BatchGetOnPremisesInstancesInput,
-    ) -> Result<BatchGetOnPremisesInstancesOutput, RusotoError<BatchGetOnPremisesInstancesError>>
-    {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_batch_get_on_premises_instances_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_batch_get_on_premises_instances_request(
+        &self,
+        input: BatchGetOnPremisesInstancesInput,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "codedeploy", &self.region, "/");
+        request.set_operation("BatchGetOnPremisesInstances");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -6806,247 +7666,482 @@ impl CodeDeploy for CodeDeployClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `continue_deployment`, also returning the metadata of the response.
+    pub async fn continue_deployment_with_metadata(
+        &self,
+        input: ContinueDeploymentInput,
+    ) -> Result<((), ResponseMetadata), RusotoError<ContinueDeploymentError>> {
+        let request = self.build_continue_deployment_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<BatchGetOnPremisesInstancesOutput, _>()
+            std::mem::drop(response);
+            Ok(((), metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(BatchGetOnPremisesInstancesError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ContinueDeploymentError::from_response(response))
         }
     }
 
-    /// <p>For a blue/green deployment, starts the process of rerouting traffic from instances in the original environment to instances in the replacement environment without waiting for a specified wait time to elapse. (Traffic rerouting, which is achieved by registering instances in the replacement environment with the load balancer, can start as soon as all instances have a status of Ready.) </p>
-    async fn continue_deployment(
+    /// Presigns the request `continue_deployment` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_continue_deployment(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ContinueDeploymentInput,
-    ) -> Result<(), RusotoError<ContinueDeploymentError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_continue_deployment_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_continue_deployment_request(&self, input: ContinueDeploymentInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "codedeploy", &self.region, "/");
+        request.set_operation("ContinueDeployment");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "CodeDeploy_20141006.ContinueDeployment");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `create_application`, also returning the metadata of the response.
+    pub async fn create_application_with_metadata(
+        &self,
+        input: CreateApplicationInput,
+    ) -> Result<(CreateApplicationOutput, ResponseMetadata), RusotoError<CreateApplicationError>>
+    {
+        let request = self.build_create_application_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            std::mem::drop(response);
-            Ok(())
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<CreateApplicationOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ContinueDeploymentError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(CreateApplicationError::from_response(response))
         }
     }
 
-    /// <p>Creates an application.</p>
-    async fn create_application(
+    /// Presigns the request `create_application` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_application(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: CreateApplicationInput,
-    ) -> Result<CreateApplicationOutput, RusotoError<CreateApplicationError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_application_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_application_request(&self, input: CreateApplicationInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "codedeploy", &self.region, "/");
+        request.set_operation("CreateApplication");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "CodeDeploy_20141006.CreateApplication");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `create_deployment`, also returning the metadata of the response.
+    pub async fn create_deployment_with_metadata(
+        &self,
+        input: CreateDeploymentInput,
+    ) -> Result<(CreateDeploymentOutput, ResponseMetadata), RusotoError<CreateDeploymentError>>
+    {
+        let request = self.build_create_deployment_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<CreateApplicationOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<CreateDeploymentOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(CreateApplicationError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(CreateDeploymentError::from_response(response))
         }
     }
 
-    /// <p>Deploys an application revision through the specified deployment group.</p>
-    async fn create_deployment(
+    /// Presigns the request `create_deployment` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_deployment(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: CreateDeploymentInput,
-    ) -> Result<CreateDeploymentOutput, RusotoError<CreateDeploymentError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_deployment_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_deployment_request(&self, input: CreateDeploymentInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "codedeploy", &self.region, "/");
+        request.set_operation("CreateDeployment");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "CodeDeploy_20141006.CreateDeployment");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `create_deployment_config`, also returning the metadata of the response.
+    pub async fn create_deployment_config_with_metadata(
+        &self,
+        input: CreateDeploymentConfigInput,
+    ) -> Result<
+        (CreateDeploymentConfigOutput, ResponseMetadata),
+        RusotoError<CreateDeploymentConfigError>,
+    > {
+        let request = self.build_create_deployment_config_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<CreateDeploymentOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<CreateDeploymentConfigOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(CreateDeploymentError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(CreateDeploymentConfigError::from_response(response))
         }
     }
 
-    /// <p> Creates a deployment configuration. </p>
-    async fn create_deployment_config(
+    /// Presigns the request `create_deployment_config` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_deployment_config(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: CreateDeploymentConfigInput,
-    ) -> Result<CreateDeploymentConfigOutput, RusotoError<CreateDeploymentConfigError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_deployment_config_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_deployment_config_request(
+        &self,
+        input: CreateDeploymentConfigInput,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "codedeploy", &self.region, "/");
+        request.set_operation("CreateDeploymentConfig");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "CodeDeploy_20141006.CreateDeploymentConfig");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `create_deployment_group`, also returning the metadata of the response.
+    pub async fn create_deployment_group_with_metadata(
+        &self,
+        input: CreateDeploymentGroupInput,
+    ) -> Result<
+        (CreateDeploymentGroupOutput, ResponseMetadata),
+        RusotoError<CreateDeploymentGroupError>,
+    > {
+        let request = self.build_create_deployment_group_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<CreateDeploymentConfigOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<CreateDeploymentGroupOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(CreateDeploymentConfigError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(CreateDeploymentGroupError::from_response(response))
         }
     }
 
-    /// <p>Creates a deployment group to which application revisions are deployed.</p>
-    async fn create_deployment_group(
+    /// Presigns the request `create_deployment_group` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_deployment_group(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: CreateDeploymentGroupInput,
-    ) -> Result<CreateDeploymentGroupOutput, RusotoError<CreateDeploymentGroupError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_deployment_group_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_deployment_group_request(
+        &self,
+        input: CreateDeploymentGroupInput,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "codedeploy", &self.region, "/");
+        request.set_operation("CreateDeploymentGroup");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "CodeDeploy_20141006.CreateDeploymentGroup");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `delete_application`, also returning the metadata of the response.
+    pub async fn delete_application_with_metadata(
+        &self,
+        input: DeleteApplicationInput,
+    ) -> Result<((), ResponseMetadata), RusotoError<DeleteApplicationError>> {
+        let request = self.build_delete_application_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<CreateDeploymentGroupOutput, _>()
+            std::mem::drop(response);
+            Ok(((), metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(CreateDeploymentGroupError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DeleteApplicationError::from_response(response))
         }
     }
 
-    /// <p>Deletes an application.</p>
-    async fn delete_application(
+    /// Presigns the request `delete_application` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_application(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteApplicationInput,
-    ) -> Result<(), RusotoError<DeleteApplicationError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_application_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_application_request(&self, input: DeleteApplicationInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "codedeploy", &self.region, "/");
+        request.set_operation("DeleteApplication");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "CodeDeploy_20141006.DeleteApplication");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `delete_deployment_config`, also returning the metadata of the response.
+    pub async fn delete_deployment_config_with_metadata(
+        &self,
+        input: DeleteDeploymentConfigInput,
+    ) -> Result<((), ResponseMetadata), RusotoError<DeleteDeploymentConfigError>> {
+        let request = self.build_delete_deployment_config_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
             std::mem::drop(response);
-            Ok(())
+            Ok(((), metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteApplicationError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DeleteDeploymentConfigError::from_response(response))
         }
     }
 
-    /// <p><p>Deletes a deployment configuration.</p> <note> <p>A deployment configuration cannot be deleted if it is currently in use. Predefined configurations cannot be deleted.</p> </note></p>
-    async fn delete_deployment_config(
+    /// Presigns the request `delete_deployment_config` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_deployment_config(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteDeploymentConfigInput,
-    ) -> Result<(), RusotoError<DeleteDeploymentConfigError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_deployment_config_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_deployment_config_request(
+        &self,
+        input: DeleteDeploymentConfigInput,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "codedeploy", &self.region, "/");
+        request.set_operation("DeleteDeploymentConfig");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "CodeDeploy_20141006.DeleteDeploymentConfig");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `delete_deployment_group`, also returning the metadata of the response.
+    pub async fn delete_deployment_group_with_metadata(
+        &self,
+        input: DeleteDeploymentGroupInput,
+    ) -> Result<
+        (DeleteDeploymentGroupOutput, ResponseMetadata),
+        RusotoError<DeleteDeploymentGroupError>,
+    > {
+        let request = self.build_delete_deployment_group_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            std::mem::drop(response);
-            Ok(())
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DeleteDeploymentGroupOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteDeploymentConfigError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DeleteDeploymentGroupError::from_response(response))
         }
     }
 
-    /// <p>Deletes a deployment group.</p>
-    async fn delete_deployment_group(
+    /// Presigns the request `delete_deployment_group` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_deployment_group(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteDeploymentGroupInput,
-    ) -> Result<DeleteDeploymentGroupOutput, RusotoError<DeleteDeploymentGroupError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_deployment_group_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_deployment_group_request(
+        &self,
+        input: DeleteDeploymentGroupInput,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "codedeploy", &self.region, "/");
+        request.set_operation("DeleteDeploymentGroup");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "CodeDeploy_20141006.DeleteDeploymentGroup");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `delete_git_hub_account_token`, also returning the metadata of the response.
+    pub async fn delete_git_hub_account_token_with_metadata(
+        &self,
+        input: DeleteGitHubAccountTokenInput,
+    ) -> Result<
+        (DeleteGitHubAccountTokenOutput, ResponseMetadata),
+        RusotoError<DeleteGitHubAccountTokenError>,
+    > {
+        let request = self.build_delete_git_hub_account_token_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DeleteDeploymentGroupOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DeleteGitHubAccountTokenOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteDeploymentGroupError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DeleteGitHubAccountTokenError::from_response(response))
         }
     }
 
-    /// <p>Deletes a GitHub account connection.</p>
-    async fn delete_git_hub_account_token(
+    /// Presigns the request `delete_git_hub_account_token` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_git_hub_account_token(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteGitHubAccountTokenInput,
-    ) -> Result<DeleteGitHubAccountTokenOutput, RusotoError<DeleteGitHubAccountTokenError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_git_hub_account_token_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_git_hub_account_token_request(
+        &self,
+        input: DeleteGitHubAccountTokenInput,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "codedeploy", &self.region, "/");
+        request.set_operation("DeleteGitHubAccountToken");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -7056,28 +8151,50 @@ impl CodeDeploy for CodeDeployClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `deregister_on_premises_instance`, also returning the metadata of the response.
+    pub async fn deregister_on_premises_instance_with_metadata(
+        &self,
+        input: DeregisterOnPremisesInstanceInput,
+    ) -> Result<((), ResponseMetadata), RusotoError<DeregisterOnPremisesInstanceError>> {
+        let request = self.build_deregister_on_premises_instance_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DeleteGitHubAccountTokenOutput, _>()
+            std::mem::drop(response);
+            Ok(((), metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteGitHubAccountTokenError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DeregisterOnPremisesInstanceError::from_response(response))
         }
     }
 
-    /// <p>Deregisters an on-premises instance.</p>
-    async fn deregister_on_premises_instance(
+    /// Presigns the request `deregister_on_premises_instance` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_deregister_on_premises_instance(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeregisterOnPremisesInstanceInput,
-    ) -> Result<(), RusotoError<DeregisterOnPremisesInstanceError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_deregister_on_premises_instance_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_deregister_on_premises_instance_request(
+        &self,
+        input: DeregisterOnPremisesInstanceInput,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "codedeploy", &self.region, "/");
+        request.set_operation("DeregisterOnPremisesInstance");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -7087,249 +8204,501 @@ impl CodeDeploy for CodeDeployClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `get_application`, also returning the metadata of the response.
+    pub async fn get_application_with_metadata(
+        &self,
+        input: GetApplicationInput,
+    ) -> Result<(GetApplicationOutput, ResponseMetadata), RusotoError<GetApplicationError>> {
+        let request = self.build_get_application_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            std::mem::drop(response);
-            Ok(())
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<GetApplicationOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DeregisterOnPremisesInstanceError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(GetApplicationError::from_response(response))
         }
     }
 
-    /// <p>Gets information about an application.</p>
-    async fn get_application(
+    /// Presigns the request `get_application` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_application(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetApplicationInput,
-    ) -> Result<GetApplicationOutput, RusotoError<GetApplicationError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_application_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_application_request(&self, input: GetApplicationInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "codedeploy", &self.region, "/");
+        request.set_operation("GetApplication");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "CodeDeploy_20141006.GetApplication");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `get_application_revision`, also returning the metadata of the response.
+    pub async fn get_application_revision_with_metadata(
+        &self,
+        input: GetApplicationRevisionInput,
+    ) -> Result<
+        (GetApplicationRevisionOutput, ResponseMetadata),
+        RusotoError<GetApplicationRevisionError>,
+    > {
+        let request = self.build_get_application_revision_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<GetApplicationOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<GetApplicationRevisionOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(GetApplicationError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(GetApplicationRevisionError::from_response(response))
         }
     }
 
-    /// <p>Gets information about an application revision.</p>
-    async fn get_application_revision(
+    /// Presigns the request `get_application_revision` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_application_revision(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetApplicationRevisionInput,
-    ) -> Result<GetApplicationRevisionOutput, RusotoError<GetApplicationRevisionError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_application_revision_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_application_revision_request(
+        &self,
+        input: GetApplicationRevisionInput,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "codedeploy", &self.region, "/");
+        request.set_operation("GetApplicationRevision");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "CodeDeploy_20141006.GetApplicationRevision");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `get_deployment`, also returning the metadata of the response.
+    pub async fn get_deployment_with_metadata(
+        &self,
+        input: GetDeploymentInput,
+    ) -> Result<(GetDeploymentOutput, ResponseMetadata), RusotoError<GetDeploymentError>> {
+        let request = self.build_get_deployment_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<GetApplicationRevisionOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<GetDeploymentOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(GetApplicationRevisionError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(GetDeploymentError::from_response(response))
         }
     }
 
-    /// <p><p>Gets information about a deployment.</p> <note> <p> The <code>content</code> property of the <code>appSpecContent</code> object in the returned revision is always null. Use <code>GetApplicationRevision</code> and the <code>sha256</code> property of the returned <code>appSpecContent</code> object to get the content of the deployment’s AppSpec file. </p> </note></p>
-    async fn get_deployment(
+    /// Presigns the request `get_deployment` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_deployment(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetDeploymentInput,
-    ) -> Result<GetDeploymentOutput, RusotoError<GetDeploymentError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_deployment_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_deployment_request(&self, input: GetDeploymentInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "codedeploy", &self.region, "/");
+        request.set_operation("GetDeployment");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "CodeDeploy_20141006.GetDeployment");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `get_deployment_config`, also returning the metadata of the response.
+    pub async fn get_deployment_config_with_metadata(
+        &self,
+        input: GetDeploymentConfigInput,
+    ) -> Result<(GetDeploymentConfigOutput, ResponseMetadata), RusotoError<GetDeploymentConfigError>>
+    {
+        let request = self.build_get_deployment_config_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<GetDeploymentOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<GetDeploymentConfigOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(GetDeploymentError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(GetDeploymentConfigError::from_response(response))
         }
     }
 
-    /// <p>Gets information about a deployment configuration.</p>
-    async fn get_deployment_config(
+    /// Presigns the request `get_deployment_config` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_deployment_config(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetDeploymentConfigInput,
-    ) -> Result<GetDeploymentConfigOutput, RusotoError<GetDeploymentConfigError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_deployment_config_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_deployment_config_request(
+        &self,
+        input: GetDeploymentConfigInput,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "codedeploy", &self.region, "/");
+        request.set_operation("GetDeploymentConfig");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "CodeDeploy_20141006.GetDeploymentConfig");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
-        let mut response = self
-            .client
-            .sign_and_dispatch(request)
-            .await
+        request
+    }
+
+    /// Calls `get_deployment_group`, also returning the metadata of the response.
+    pub async fn get_deployment_group_with_metadata(
+        &self,
+        input: GetDeploymentGroupInput,
+    ) -> Result<(GetDeploymentGroupOutput, ResponseMetadata), RusotoError<GetDeploymentGroupError>>
+    {
+        let request = self.build_get_deployment_group_request(input);
+
+        let mut response = self
+            .client
+            .sign_and_dispatch(request)
+            .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<GetDeploymentConfigOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<GetDeploymentGroupOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(GetDeploymentConfigError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(GetDeploymentGroupError::from_response(response))
         }
     }
 
-    /// <p>Gets information about a deployment group.</p>
-    async fn get_deployment_group(
+    /// Presigns the request `get_deployment_group` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_deployment_group(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetDeploymentGroupInput,
-    ) -> Result<GetDeploymentGroupOutput, RusotoError<GetDeploymentGroupError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_deployment_group_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_deployment_group_request(&self, input: GetDeploymentGroupInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "codedeploy", &self.region, "/");
+        request.set_operation("GetDeploymentGroup");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "CodeDeploy_20141006.GetDeploymentGroup");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `get_deployment_instance`, also returning the metadata of the response.
+    pub async fn get_deployment_instance_with_metadata(
+        &self,
+        input: GetDeploymentInstanceInput,
+    ) -> Result<
+        (GetDeploymentInstanceOutput, ResponseMetadata),
+        RusotoError<GetDeploymentInstanceError>,
+    > {
+        let request = self.build_get_deployment_instance_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<GetDeploymentGroupOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<GetDeploymentInstanceOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(GetDeploymentGroupError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(GetDeploymentInstanceError::from_response(response))
         }
     }
 
-    /// <p>Gets information about an instance as part of a deployment.</p>
-    async fn get_deployment_instance(
+    /// Presigns the request `get_deployment_instance` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_deployment_instance(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetDeploymentInstanceInput,
-    ) -> Result<GetDeploymentInstanceOutput, RusotoError<GetDeploymentInstanceError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_deployment_instance_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_deployment_instance_request(
+        &self,
+        input: GetDeploymentInstanceInput,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "codedeploy", &self.region, "/");
+        request.set_operation("GetDeploymentInstance");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "CodeDeploy_20141006.GetDeploymentInstance");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `get_deployment_target`, also returning the metadata of the response.
+    pub async fn get_deployment_target_with_metadata(
+        &self,
+        input: GetDeploymentTargetInput,
+    ) -> Result<(GetDeploymentTargetOutput, ResponseMetadata), RusotoError<GetDeploymentTargetError>>
+    {
+        let request = self.build_get_deployment_target_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<GetDeploymentInstanceOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<GetDeploymentTargetOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(GetDeploymentInstanceError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(GetDeploymentTargetError::from_response(response))
         }
     }
 
-    /// <p> Returns information about a deployment target. </p>
-    async fn get_deployment_target(
+    /// Presigns the request `get_deployment_target` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_deployment_target(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetDeploymentTargetInput,
-    ) -> Result<GetDeploymentTargetOutput, RusotoError<GetDeploymentTargetError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_deployment_target_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_deployment_target_request(
+        &self,
+        input: GetDeploymentTargetInput,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "codedeploy", &self.region, "/");
+        request.set_operation("GetDeploymentTarget");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "CodeDeploy_20141006.GetDeploymentTarget");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `get_on_premises_instance`, also returning the metadata of the response.
+    pub async fn get_on_premises_instance_with_metadata(
+        &self,
+        input: GetOnPremisesInstanceInput,
+    ) -> Result<
+        (GetOnPremisesInstanceOutput, ResponseMetadata),
+        RusotoError<GetOnPremisesInstanceError>,
+    > {
+        let request = self.build_get_on_premises_instance_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<GetDeploymentTargetOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<GetOnPremisesInstanceOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(GetDeploymentTargetError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(GetOnPremisesInstanceError::from_response(response))
         }
     }
 
-    /// <p> Gets information about an on-premises instance. </p>
-    async fn get_on_premises_instance(
+    /// Presigns the request `get_on_premises_instance` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_on_premises_instance(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetOnPremisesInstanceInput,
-    ) -> Result<GetOnPremisesInstanceOutput, RusotoError<GetOnPremisesInstanceError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_on_premises_instance_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_on_premises_instance_request(
+        &self,
+        input: GetOnPremisesInstanceInput,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "codedeploy", &self.region, "/");
+        request.set_operation("GetOnPremisesInstance");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "CodeDeploy_20141006.GetOnPremisesInstance");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `list_application_revisions`, also returning the metadata of the response.
+    pub async fn list_application_revisions_with_metadata(
+        &self,
+        input: ListApplicationRevisionsInput,
+    ) -> Result<
+        (ListApplicationRevisionsOutput, ResponseMetadata),
+        RusotoError<ListApplicationRevisionsError>,
+    > {
+        let request = self.build_list_application_revisions_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<GetOnPremisesInstanceOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ListApplicationRevisionsOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(GetOnPremisesInstanceError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ListApplicationRevisionsError::from_response(response))
         }
     }
 
-    /// <p>Lists information about revisions for an application.</p>
-    async fn list_application_revisions(
+    /// Presigns the request `list_application_revisions` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_application_revisions(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListApplicationRevisionsInput,
-    ) -> Result<ListApplicationRevisionsOutput, RusotoError<ListApplicationRevisionsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_application_revisions_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_application_revisions_request(
+        &self,
+        input: ListApplicationRevisionsInput,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "codedeploy", &self.region, "/");
+        request.set_operation("ListApplicationRevisions");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -7339,111 +8708,227 @@ impl CodeDeploy for CodeDeployClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `list_applications`, also returning the metadata of the response.
+    pub async fn list_applications_with_metadata(
+        &self,
+        input: ListApplicationsInput,
+    ) -> Result<(ListApplicationsOutput, ResponseMetadata), RusotoError<ListApplicationsError>>
+    {
+        let request = self.build_list_applications_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListApplicationRevisionsOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ListApplicationsOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ListApplicationRevisionsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ListApplicationsError::from_response(response))
         }
     }
 
-    /// <p>Lists the applications registered with the IAM user or AWS account.</p>
-    async fn list_applications(
+    /// Presigns the request `list_applications` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_applications(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListApplicationsInput,
-    ) -> Result<ListApplicationsOutput, RusotoError<ListApplicationsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_applications_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_applications_request(&self, input: ListApplicationsInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "codedeploy", &self.region, "/");
+        request.set_operation("ListApplications");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "CodeDeploy_20141006.ListApplications");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `list_deployment_configs`, also returning the metadata of the response.
+    pub async fn list_deployment_configs_with_metadata(
+        &self,
+        input: ListDeploymentConfigsInput,
+    ) -> Result<
+        (ListDeploymentConfigsOutput, ResponseMetadata),
+        RusotoError<ListDeploymentConfigsError>,
+    > {
+        let request = self.build_list_deployment_configs_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<ListApplicationsOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ListDeploymentConfigsOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ListApplicationsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ListDeploymentConfigsError::from_response(response))
         }
     }
 
-    /// <p>Lists the deployment configurations with the IAM user or AWS account.</p>
-    async fn list_deployment_configs(
+    /// Presigns the request `list_deployment_configs` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_deployment_configs(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListDeploymentConfigsInput,
-    ) -> Result<ListDeploymentConfigsOutput, RusotoError<ListDeploymentConfigsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_deployment_configs_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_deployment_configs_request(
+        &self,
+        input: ListDeploymentConfigsInput,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "codedeploy", &self.region, "/");
+        request.set_operation("ListDeploymentConfigs");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "CodeDeploy_20141006.ListDeploymentConfigs");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `list_deployment_groups`, also returning the metadata of the response.
+    pub async fn list_deployment_groups_with_metadata(
+        &self,
+        input: ListDeploymentGroupsInput,
+    ) -> Result<
+        (ListDeploymentGroupsOutput, ResponseMetadata),
+        RusotoError<ListDeploymentGroupsError>,
+    > {
+        let request = self.build_list_deployment_groups_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListDeploymentConfigsOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ListDeploymentGroupsOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ListDeploymentConfigsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ListDeploymentGroupsError::from_response(response))
         }
     }
 
-    /// <p>Lists the deployment groups for an application registered with the IAM user or AWS account.</p>
-    async fn list_deployment_groups(
+    /// Presigns the request `list_deployment_groups` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_deployment_groups(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListDeploymentGroupsInput,
-    ) -> Result<ListDeploymentGroupsOutput, RusotoError<ListDeploymentGroupsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_deployment_groups_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_deployment_groups_request(
+        &self,
+        input: ListDeploymentGroupsInput,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "codedeploy", &self.region, "/");
+        request.set_operation("ListDeploymentGroups");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "CodeDeploy_20141006.ListDeploymentGroups");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `list_deployment_instances`, also returning the metadata of the response.
+    pub async fn list_deployment_instances_with_metadata(
+        &self,
+        input: ListDeploymentInstancesInput,
+    ) -> Result<
+        (ListDeploymentInstancesOutput, ResponseMetadata),
+        RusotoError<ListDeploymentInstancesError>,
+    > {
+        let request = self.build_list_deployment_instances_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListDeploymentGroupsOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ListDeploymentInstancesOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ListDeploymentGroupsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ListDeploymentInstancesError::from_response(response))
         }
     }
 
-    /// <p><note> <p> The newer BatchGetDeploymentTargets should be used instead because it works with all compute types. <code>ListDeploymentInstances</code> throws an exception if it is used with a compute platform other than EC2/On-premises or AWS Lambda. </p> </note> <p> Lists the instance for a deployment associated with the IAM user or AWS account. </p></p>
-    async fn list_deployment_instances(
+    /// Presigns the request `list_deployment_instances` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_deployment_instances(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListDeploymentInstancesInput,
-    ) -> Result<ListDeploymentInstancesOutput, RusotoError<ListDeploymentInstancesError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_deployment_instances_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_deployment_instances_request(
+        &self,
+        input: ListDeploymentInstancesInput,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "codedeploy", &self.region, "/");
+        request.set_operation("ListDeploymentInstances");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -7453,84 +8938,168 @@ impl CodeDeploy for CodeDeployClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `list_deployment_targets`, also returning the metadata of the response.
+    pub async fn list_deployment_targets_with_metadata(
+        &self,
+        input: ListDeploymentTargetsInput,
+    ) -> Result<
+        (ListDeploymentTargetsOutput, ResponseMetadata),
+        RusotoError<ListDeploymentTargetsError>,
+    > {
+        let request = self.build_list_deployment_targets_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListDeploymentInstancesOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ListDeploymentTargetsOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ListDeploymentInstancesError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ListDeploymentTargetsError::from_response(response))
         }
     }
 
-    /// <p> Returns an array of target IDs that are associated a deployment. </p>
-    async fn list_deployment_targets(
+    /// Presigns the request `list_deployment_targets` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_deployment_targets(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListDeploymentTargetsInput,
-    ) -> Result<ListDeploymentTargetsOutput, RusotoError<ListDeploymentTargetsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_deployment_targets_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_deployment_targets_request(
+        &self,
+        input: ListDeploymentTargetsInput,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "codedeploy", &self.region, "/");
+        request.set_operation("ListDeploymentTargets");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "CodeDeploy_20141006.ListDeploymentTargets");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `list_deployments`, also returning the metadata of the response.
+    pub async fn list_deployments_with_metadata(
+        &self,
+        input: ListDeploymentsInput,
+    ) -> Result<(ListDeploymentsOutput, ResponseMetadata), RusotoError<ListDeploymentsError>> {
+        let request = self.build_list_deployments_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListDeploymentTargetsOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ListDeploymentsOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ListDeploymentTargetsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ListDeploymentsError::from_response(response))
         }
     }
 
-    /// <p>Lists the deployments in a deployment group for an application registered with the IAM user or AWS account.</p>
-    async fn list_deployments(
+    /// Presigns the request `list_deployments` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_deployments(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListDeploymentsInput,
-    ) -> Result<ListDeploymentsOutput, RusotoError<ListDeploymentsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_deployments_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_deployments_request(&self, input: ListDeploymentsInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "codedeploy", &self.region, "/");
+        request.set_operation("ListDeployments");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "CodeDeploy_20141006.ListDeployments");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `list_git_hub_account_token_names`, also returning the metadata of the response.
+    pub async fn list_git_hub_account_token_names_with_metadata(
+        &self,
+        input: ListGitHubAccountTokenNamesInput,
+    ) -> Result<
+        (ListGitHubAccountTokenNamesOutput, ResponseMetadata),
+        RusotoError<ListGitHubAccountTokenNamesError>,
+    > {
+        let request = self.build_list_git_hub_account_token_names_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<ListDeploymentsOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ListGitHubAccountTokenNamesOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ListDeploymentsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ListGitHubAccountTokenNamesError::from_response(response))
         }
     }
 
-    /// <p>Lists the names of stored connections to GitHub accounts.</p>
-    async fn list_git_hub_account_token_names(
+    /// Presigns the request `list_git_hub_account_token_names` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_git_hub_account_token_names(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListGitHubAccountTokenNamesInput,
-    ) -> Result<ListGitHubAccountTokenNamesOutput, RusotoError<ListGitHubAccountTokenNamesError>>
-    {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_git_hub_account_token_names_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_git_hub_account_token_names_request(
+        &self,
+        input: ListGitHubAccountTokenNamesInput,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "codedeploy", &self.region, "/");
+        request.set_operation("ListGitHubAccountTokenNames");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -7540,28 +9109,58 @@ impl CodeDeploy for CodeDeployClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `list_on_premises_instances`, also returning the metadata of the response.
+    pub async fn list_on_premises_instances_with_metadata(
+        &self,
+        input: ListOnPremisesInstancesInput,
+    ) -> Result<
+        (ListOnPremisesInstancesOutput, ResponseMetadata),
+        RusotoError<ListOnPremisesInstancesError>,
+    > {
+        let request = self.build_list_on_premises_instances_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListGitHubAccountTokenNamesOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ListOnPremisesInstancesOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ListGitHubAccountTokenNamesError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ListOnPremisesInstancesError::from_response(response))
         }
     }
 
-    /// <p>Gets a list of names for one or more on-premises instances.</p> <p>Unless otherwise specified, both registered and deregistered on-premises instance names are listed. To list only registered or deregistered on-premises instance names, use the registration status parameter.</p>
-    async fn list_on_premises_instances(
+    /// Presigns the request `list_on_premises_instances` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_on_premises_instances(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListOnPremisesInstancesInput,
-    ) -> Result<ListOnPremisesInstancesOutput, RusotoError<ListOnPremisesInstancesError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_on_premises_instances_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_on_premises_instances_request(
+        &self,
+        input: ListOnPremisesInstancesInput,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "codedeploy", &self.region, "/");
+        request.set_operation("ListOnPremisesInstances");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -7571,343 +9170,1460 @@ impl CodeDeploy for CodeDeployClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `list_tags_for_resource`, also returning the metadata of the response.
+    pub async fn list_tags_for_resource_with_metadata(
+        &self,
+        input: ListTagsForResourceInput,
+    ) -> Result<(ListTagsForResourceOutput, ResponseMetadata), RusotoError<ListTagsForResourceError>>
+    {
+        let request = self.build_list_tags_for_resource_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListOnPremisesInstancesOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ListTagsForResourceOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ListOnPremisesInstancesError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ListTagsForResourceError::from_response(response))
         }
     }
 
-    /// <p> Returns a list of tags for the resource identified by a specified ARN. Tags are used to organize and categorize your CodeDeploy resources. </p>
-    async fn list_tags_for_resource(
+    /// Presigns the request `list_tags_for_resource` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_tags_for_resource(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListTagsForResourceInput,
-    ) -> Result<ListTagsForResourceOutput, RusotoError<ListTagsForResourceError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_tags_for_resource_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_tags_for_resource_request(
+        &self,
+        input: ListTagsForResourceInput,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "codedeploy", &self.region, "/");
+        request.set_operation("ListTagsForResource");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "CodeDeploy_20141006.ListTagsForResource");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
-        let mut response = self
-            .client
-            .sign_and_dispatch(request)
-            .await
-            .map_err(RusotoError::from)?;
-        if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListTagsForResourceOutput, _>()
-        } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ListTagsForResourceError::from_response(response))
-        }
+        request
     }
 
-    /// <p> Sets the result of a Lambda validation function. The function validates one or both lifecycle events (<code>BeforeAllowTraffic</code> and <code>AfterAllowTraffic</code>) and returns <code>Succeeded</code> or <code>Failed</code>. </p>
-    async fn put_lifecycle_event_hook_execution_status(
+    /// Calls `put_lifecycle_event_hook_execution_status`, also returning the metadata of the response.
+    pub async fn put_lifecycle_event_hook_execution_status_with_metadata(
         &self,
         input: PutLifecycleEventHookExecutionStatusInput,
     ) -> Result<
-        PutLifecycleEventHookExecutionStatusOutput,
+        (PutLifecycleEventHookExecutionStatusOutput, ResponseMetadata),
         RusotoError<PutLifecycleEventHookExecutionStatusError>,
     > {
-        let mut request = SignedRequest::new("POST", "codedeploy", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "CodeDeploy_20141006.PutLifecycleEventHookExecutionStatus",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
+        let request = self.build_put_lifecycle_event_hook_execution_status_request(input);
 
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<PutLifecycleEventHookExecutionStatusOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<PutLifecycleEventHookExecutionStatusOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
             Err(PutLifecycleEventHookExecutionStatusError::from_response(
                 response,
             ))
         }
     }
 
-    /// <p>Registers with AWS CodeDeploy a revision for the specified application.</p>
-    async fn register_application_revision(
+    /// Presigns the request `put_lifecycle_event_hook_execution_status` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_put_lifecycle_event_hook_execution_status(
         &self,
-        input: RegisterApplicationRevisionInput,
-    ) -> Result<(), RusotoError<RegisterApplicationRevisionError>> {
+        presigner: &rusoto_core::Presigner,
+        input: PutLifecycleEventHookExecutionStatusInput,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_put_lifecycle_event_hook_execution_status_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_put_lifecycle_event_hook_execution_status_request(
+        &self,
+        input: PutLifecycleEventHookExecutionStatusInput,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "codedeploy", &self.region, "/");
+        request.set_operation("PutLifecycleEventHookExecutionStatus");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
             "x-amz-target",
-            "CodeDeploy_20141006.RegisterApplicationRevision",
+            "CodeDeploy_20141006.PutLifecycleEventHookExecutionStatus",
         );
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `register_application_revision`, also returning the metadata of the response.
+    pub async fn register_application_revision_with_metadata(
+        &self,
+        input: RegisterApplicationRevisionInput,
+    ) -> Result<((), ResponseMetadata), RusotoError<RegisterApplicationRevisionError>> {
+        let request = self.build_register_application_revision_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
             std::mem::drop(response);
-            Ok(())
+            Ok(((), metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
             Err(RegisterApplicationRevisionError::from_response(response))
         }
     }
 
-    /// <p><p>Registers an on-premises instance.</p> <note> <p>Only one IAM ARN (an IAM session ARN or IAM user ARN) is supported in the request. You cannot use both.</p> </note></p>
-    async fn register_on_premises_instance(
+    /// Presigns the request `register_application_revision` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_register_application_revision(
         &self,
-        input: RegisterOnPremisesInstanceInput,
-    ) -> Result<(), RusotoError<RegisterOnPremisesInstanceError>> {
+        presigner: &rusoto_core::Presigner,
+        input: RegisterApplicationRevisionInput,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_register_application_revision_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_register_application_revision_request(
+        &self,
+        input: RegisterApplicationRevisionInput,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "codedeploy", &self.region, "/");
+        request.set_operation("RegisterApplicationRevision");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
             "x-amz-target",
-            "CodeDeploy_20141006.RegisterOnPremisesInstance",
+            "CodeDeploy_20141006.RegisterApplicationRevision",
         );
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `register_on_premises_instance`, also returning the metadata of the response.
+    pub async fn register_on_premises_instance_with_metadata(
+        &self,
+        input: RegisterOnPremisesInstanceInput,
+    ) -> Result<((), ResponseMetadata), RusotoError<RegisterOnPremisesInstanceError>> {
+        let request = self.build_register_on_premises_instance_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
             std::mem::drop(response);
-            Ok(())
+            Ok(((), metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
             Err(RegisterOnPremisesInstanceError::from_response(response))
         }
     }
 
-    /// <p>Removes one or more tags from one or more on-premises instances.</p>
-    async fn remove_tags_from_on_premises_instances(
+    /// Presigns the request `register_on_premises_instance` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_register_on_premises_instance(
         &self,
-        input: RemoveTagsFromOnPremisesInstancesInput,
-    ) -> Result<(), RusotoError<RemoveTagsFromOnPremisesInstancesError>> {
+        presigner: &rusoto_core::Presigner,
+        input: RegisterOnPremisesInstanceInput,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_register_on_premises_instance_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_register_on_premises_instance_request(
+        &self,
+        input: RegisterOnPremisesInstanceInput,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "codedeploy", &self.region, "/");
+        request.set_operation("RegisterOnPremisesInstance");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
             "x-amz-target",
-            "CodeDeploy_20141006.RemoveTagsFromOnPremisesInstances",
+            "CodeDeploy_20141006.RegisterOnPremisesInstance",
         );
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `remove_tags_from_on_premises_instances`, also returning the metadata of the response.
+    pub async fn remove_tags_from_on_premises_instances_with_metadata(
+        &self,
+        input: RemoveTagsFromOnPremisesInstancesInput,
+    ) -> Result<((), ResponseMetadata), RusotoError<RemoveTagsFromOnPremisesInstancesError>> {
+        let request = self.build_remove_tags_from_on_premises_instances_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
             std::mem::drop(response);
-            Ok(())
+            Ok(((), metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
             Err(RemoveTagsFromOnPremisesInstancesError::from_response(
                 response,
             ))
         }
     }
 
-    /// <p>In a blue/green deployment, overrides any specified wait time and starts terminating instances immediately after the traffic routing is complete.</p>
-    async fn skip_wait_time_for_instance_termination(
+    /// Presigns the request `remove_tags_from_on_premises_instances` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_remove_tags_from_on_premises_instances(
         &self,
-        input: SkipWaitTimeForInstanceTerminationInput,
-    ) -> Result<(), RusotoError<SkipWaitTimeForInstanceTerminationError>> {
+        presigner: &rusoto_core::Presigner,
+        input: RemoveTagsFromOnPremisesInstancesInput,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_remove_tags_from_on_premises_instances_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_remove_tags_from_on_premises_instances_request(
+        &self,
+        input: RemoveTagsFromOnPremisesInstancesInput,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "codedeploy", &self.region, "/");
+        request.set_operation("RemoveTagsFromOnPremisesInstances");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
             "x-amz-target",
-            "CodeDeploy_20141006.SkipWaitTimeForInstanceTermination",
+            "CodeDeploy_20141006.RemoveTagsFromOnPremisesInstances",
         );
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `skip_wait_time_for_instance_termination`, also returning the metadata of the response.
+    pub async fn skip_wait_time_for_instance_termination_with_metadata(
+        &self,
+        input: SkipWaitTimeForInstanceTerminationInput,
+    ) -> Result<((), ResponseMetadata), RusotoError<SkipWaitTimeForInstanceTerminationError>> {
+        let request = self.build_skip_wait_time_for_instance_termination_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
             std::mem::drop(response);
-            Ok(())
+            Ok(((), metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
             Err(SkipWaitTimeForInstanceTerminationError::from_response(
                 response,
             ))
         }
     }
 
-    /// <p>Attempts to stop an ongoing deployment.</p>
-    async fn stop_deployment(
+    /// Presigns the request `skip_wait_time_for_instance_termination` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_skip_wait_time_for_instance_termination(
         &self,
-        input: StopDeploymentInput,
-    ) -> Result<StopDeploymentOutput, RusotoError<StopDeploymentError>> {
+        presigner: &rusoto_core::Presigner,
+        input: SkipWaitTimeForInstanceTerminationInput,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_skip_wait_time_for_instance_termination_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_skip_wait_time_for_instance_termination_request(
+        &self,
+        input: SkipWaitTimeForInstanceTerminationInput,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "codedeploy", &self.region, "/");
+        request.set_operation("SkipWaitTimeForInstanceTermination");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "CodeDeploy_20141006.StopDeployment");
+        request.add_header(
+            "x-amz-target",
+            "CodeDeploy_20141006.SkipWaitTimeForInstanceTermination",
+        );
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `stop_deployment`, also returning the metadata of the response.
+    pub async fn stop_deployment_with_metadata(
+        &self,
+        input: StopDeploymentInput,
+    ) -> Result<(StopDeploymentOutput, ResponseMetadata), RusotoError<StopDeploymentError>> {
+        let request = self.build_stop_deployment_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<StopDeploymentOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<StopDeploymentOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
             Err(StopDeploymentError::from_response(response))
         }
     }
 
-    /// <p> Associates the list of tags in the input <code>Tags</code> parameter with the resource identified by the <code>ResourceArn</code> input parameter. </p>
-    async fn tag_resource(
+    /// Presigns the request `stop_deployment` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_stop_deployment(
         &self,
-        input: TagResourceInput,
-    ) -> Result<TagResourceOutput, RusotoError<TagResourceError>> {
+        presigner: &rusoto_core::Presigner,
+        input: StopDeploymentInput,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_stop_deployment_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_stop_deployment_request(&self, input: StopDeploymentInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "codedeploy", &self.region, "/");
+        request.set_operation("StopDeployment");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "CodeDeploy_20141006.TagResource");
+        request.add_header("x-amz-target", "CodeDeploy_20141006.StopDeployment");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `tag_resource`, also returning the metadata of the response.
+    pub async fn tag_resource_with_metadata(
+        &self,
+        input: TagResourceInput,
+    ) -> Result<(TagResourceOutput, ResponseMetadata), RusotoError<TagResourceError>> {
+        let request = self.build_tag_resource_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<TagResourceOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<TagResourceOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
             Err(TagResourceError::from_response(response))
         }
     }
 
-    /// <p> Disassociates a resource from a list of tags. The resource is identified by the <code>ResourceArn</code> input parameter. The tags are identfied by the list of keys in the <code>TagKeys</code> input parameter. </p>
-    async fn untag_resource(
+    /// Presigns the request `tag_resource` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_tag_resource(
         &self,
-        input: UntagResourceInput,
-    ) -> Result<UntagResourceOutput, RusotoError<UntagResourceError>> {
+        presigner: &rusoto_core::Presigner,
+        input: TagResourceInput,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_tag_resource_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_tag_resource_request(&self, input: TagResourceInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "codedeploy", &self.region, "/");
+        request.set_operation("TagResource");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "CodeDeploy_20141006.UntagResource");
+        request.add_header("x-amz-target", "CodeDeploy_20141006.TagResource");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `untag_resource`, also returning the metadata of the response.
+    pub async fn untag_resource_with_metadata(
+        &self,
+        input: UntagResourceInput,
+    ) -> Result<(UntagResourceOutput, ResponseMetadata), RusotoError<UntagResourceError>> {
+        let request = self.build_untag_resource_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<UntagResourceOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<UntagResourceOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
             Err(UntagResourceError::from_response(response))
         }
     }
 
-    /// <p>Changes the name of an application.</p>
-    async fn update_application(
+    /// Presigns the request `untag_resource` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_untag_resource(
         &self,
-        input: UpdateApplicationInput,
-    ) -> Result<(), RusotoError<UpdateApplicationError>> {
+        presigner: &rusoto_core::Presigner,
+        input: UntagResourceInput,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_untag_resource_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_untag_resource_request(&self, input: UntagResourceInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "codedeploy", &self.region, "/");
+        request.set_operation("UntagResource");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "CodeDeploy_20141006.UpdateApplication");
+        request.add_header("x-amz-target", "CodeDeploy_20141006.UntagResource");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `update_application`, also returning the metadata of the response.
+    pub async fn update_application_with_metadata(
+        &self,
+        input: UpdateApplicationInput,
+    ) -> Result<((), ResponseMetadata), RusotoError<UpdateApplicationError>> {
+        let request = self.build_update_application_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
             std::mem::drop(response);
-            Ok(())
+            Ok(((), metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
             Err(UpdateApplicationError::from_response(response))
         }
     }
 
-    /// <p>Changes information about a deployment group.</p>
-    async fn update_deployment_group(
+    /// Presigns the request `update_application` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_application(
         &self,
-        input: UpdateDeploymentGroupInput,
-    ) -> Result<UpdateDeploymentGroupOutput, RusotoError<UpdateDeploymentGroupError>> {
+        presigner: &rusoto_core::Presigner,
+        input: UpdateApplicationInput,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_application_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_application_request(&self, input: UpdateApplicationInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "codedeploy", &self.region, "/");
+        request.set_operation("UpdateApplication");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "CodeDeploy_20141006.UpdateDeploymentGroup");
+        request.add_header("x-amz-target", "CodeDeploy_20141006.UpdateApplication");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `update_deployment_group`, also returning the metadata of the response.
+    pub async fn update_deployment_group_with_metadata(
+        &self,
+        input: UpdateDeploymentGroupInput,
+    ) -> Result<
+        (UpdateDeploymentGroupOutput, ResponseMetadata),
+        RusotoError<UpdateDeploymentGroupError>,
+    > {
+        let request = self.build_update_deployment_group_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<UpdateDeploymentGroupOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<UpdateDeploymentGroupOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
             Err(UpdateDeploymentGroupError::from_response(response))
         }
     }
+
+    /// Presigns the request `update_deployment_group` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_deployment_group(
+        &self,
+        presigner: &rusoto_core::Presigner,
+        input: UpdateDeploymentGroupInput,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_deployment_group_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_deployment_group_request(
+        &self,
+        input: UpdateDeploymentGroupInput,
+    ) -> SignedRequest {
+        let mut request = SignedRequest::new("POST", "codedeploy", &self.region, "/");
+        request.set_operation("UpdateDeploymentGroup");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "CodeDeploy_20141006.UpdateDeploymentGroup");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        request
+    }
+
+    /// The pages of `ListApplicationRevisions` results for `input`, requesting each next page with the `nextToken` of the last one as its `nextToken`.
+    pub fn list_application_revisions_pages(
+        &self,
+        input: ListApplicationRevisionsInput,
+    ) -> impl futures::Stream<
+        Item = Result<ListApplicationRevisionsOutput, RusotoError<ListApplicationRevisionsError>>,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: CodeDeployClient, input| async move {
+                client.list_application_revisions(input).await
+            },
+            |input: &ListApplicationRevisionsInput, output: &ListApplicationRevisionsOutput| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListApplicationRevisionsInput {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `revisions` of every page of `ListApplicationRevisions` results for `input`, as `list_application_revisions_pages` requests them.
+    pub fn list_application_revisions_stream(
+        &self,
+        input: ListApplicationRevisionsInput,
+    ) -> impl futures::Stream<
+        Item = Result<RevisionLocation, RusotoError<ListApplicationRevisionsError>>,
+    > + Send {
+        rusoto_core::pagination::items(self.list_application_revisions_pages(input), |page| {
+            page.revisions.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `ListApplications` results for `input`, requesting each next page with the `nextToken` of the last one as its `nextToken`.
+    pub fn list_applications_pages(
+        &self,
+        input: ListApplicationsInput,
+    ) -> impl futures::Stream<
+        Item = Result<ListApplicationsOutput, RusotoError<ListApplicationsError>>,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: CodeDeployClient, input| async move { client.list_applications(input).await },
+            |input: &ListApplicationsInput, output: &ListApplicationsOutput| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListApplicationsInput {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `applications` of every page of `ListApplications` results for `input`, as `list_applications_pages` requests them.
+    pub fn list_applications_stream(
+        &self,
+        input: ListApplicationsInput,
+    ) -> impl futures::Stream<Item = Result<String, RusotoError<ListApplicationsError>>> + Send
+    {
+        rusoto_core::pagination::items(self.list_applications_pages(input), |page| {
+            page.applications.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `ListDeploymentConfigs` results for `input`, requesting each next page with the `nextToken` of the last one as its `nextToken`.
+    pub fn list_deployment_configs_pages(
+        &self,
+        input: ListDeploymentConfigsInput,
+    ) -> impl futures::Stream<
+        Item = Result<ListDeploymentConfigsOutput, RusotoError<ListDeploymentConfigsError>>,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: CodeDeployClient, input| async move { client.list_deployment_configs(input).await },
+            |input: &ListDeploymentConfigsInput, output: &ListDeploymentConfigsOutput| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListDeploymentConfigsInput {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `deploymentConfigsList` of every page of `ListDeploymentConfigs` results for `input`, as `list_deployment_configs_pages` requests them.
+    pub fn list_deployment_configs_stream(
+        &self,
+        input: ListDeploymentConfigsInput,
+    ) -> impl futures::Stream<Item = Result<String, RusotoError<ListDeploymentConfigsError>>> + Send
+    {
+        rusoto_core::pagination::items(self.list_deployment_configs_pages(input), |page| {
+            page.deployment_configs_list.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `ListDeploymentGroups` results for `input`, requesting each next page with the `nextToken` of the last one as its `nextToken`.
+    pub fn list_deployment_groups_pages(
+        &self,
+        input: ListDeploymentGroupsInput,
+    ) -> impl futures::Stream<
+        Item = Result<ListDeploymentGroupsOutput, RusotoError<ListDeploymentGroupsError>>,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: CodeDeployClient, input| async move { client.list_deployment_groups(input).await },
+            |input: &ListDeploymentGroupsInput, output: &ListDeploymentGroupsOutput| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListDeploymentGroupsInput {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `deploymentGroups` of every page of `ListDeploymentGroups` results for `input`, as `list_deployment_groups_pages` requests them.
+    pub fn list_deployment_groups_stream(
+        &self,
+        input: ListDeploymentGroupsInput,
+    ) -> impl futures::Stream<Item = Result<String, RusotoError<ListDeploymentGroupsError>>> + Send
+    {
+        rusoto_core::pagination::items(self.list_deployment_groups_pages(input), |page| {
+            page.deployment_groups.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `ListDeploymentInstances` results for `input`, requesting each next page with the `nextToken` of the last one as its `nextToken`.
+    pub fn list_deployment_instances_pages(
+        &self,
+        input: ListDeploymentInstancesInput,
+    ) -> impl futures::Stream<
+        Item = Result<ListDeploymentInstancesOutput, RusotoError<ListDeploymentInstancesError>>,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: CodeDeployClient, input| async move {
+                client.list_deployment_instances(input).await
+            },
+            |input: &ListDeploymentInstancesInput, output: &ListDeploymentInstancesOutput| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListDeploymentInstancesInput {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `instancesList` of every page of `ListDeploymentInstances` results for `input`, as `list_deployment_instances_pages` requests them.
+    pub fn list_deployment_instances_stream(
+        &self,
+        input: ListDeploymentInstancesInput,
+    ) -> impl futures::Stream<Item = Result<String, RusotoError<ListDeploymentInstancesError>>> + Send
+    {
+        rusoto_core::pagination::items(self.list_deployment_instances_pages(input), |page| {
+            page.instances_list.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `ListDeploymentTargets` results for `input`, requesting each next page with the `nextToken` of the last one as its `nextToken`.
+    pub fn list_deployment_targets_pages(
+        &self,
+        input: ListDeploymentTargetsInput,
+    ) -> impl futures::Stream<
+        Item = Result<ListDeploymentTargetsOutput, RusotoError<ListDeploymentTargetsError>>,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: CodeDeployClient, input| async move { client.list_deployment_targets(input).await },
+            |input: &ListDeploymentTargetsInput, output: &ListDeploymentTargetsOutput| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListDeploymentTargetsInput {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `targetIds` of every page of `ListDeploymentTargets` results for `input`, as `list_deployment_targets_pages` requests them.
+    pub fn list_deployment_targets_stream(
+        &self,
+        input: ListDeploymentTargetsInput,
+    ) -> impl futures::Stream<Item = Result<String, RusotoError<ListDeploymentTargetsError>>> + Send
+    {
+        rusoto_core::pagination::items(self.list_deployment_targets_pages(input), |page| {
+            page.target_ids.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `ListDeployments` results for `input`, requesting each next page with the `nextToken` of the last one as its `nextToken`.
+    pub fn list_deployments_pages(
+        &self,
+        input: ListDeploymentsInput,
+    ) -> impl futures::Stream<Item = Result<ListDeploymentsOutput, RusotoError<ListDeploymentsError>>>
+           + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: CodeDeployClient, input| async move { client.list_deployments(input).await },
+            |input: &ListDeploymentsInput, output: &ListDeploymentsOutput| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListDeploymentsInput {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `deployments` of every page of `ListDeployments` results for `input`, as `list_deployments_pages` requests them.
+    pub fn list_deployments_stream(
+        &self,
+        input: ListDeploymentsInput,
+    ) -> impl futures::Stream<Item = Result<String, RusotoError<ListDeploymentsError>>> + Send {
+        rusoto_core::pagination::items(self.list_deployments_pages(input), |page| {
+            page.deployments.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `ListGitHubAccountTokenNames` results for `input`, requesting each next page with the `nextToken` of the last one as its `nextToken`.
+    pub fn list_git_hub_account_token_names_pages(
+        &self,
+        input: ListGitHubAccountTokenNamesInput,
+    ) -> impl futures::Stream<
+        Item = Result<
+            ListGitHubAccountTokenNamesOutput,
+            RusotoError<ListGitHubAccountTokenNamesError>,
+        >,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: CodeDeployClient, input| async move {
+                client.list_git_hub_account_token_names(input).await
+            },
+            |input: &ListGitHubAccountTokenNamesInput,
+             output: &ListGitHubAccountTokenNamesOutput| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListGitHubAccountTokenNamesInput {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `tokenNameList` of every page of `ListGitHubAccountTokenNames` results for `input`, as `list_git_hub_account_token_names_pages` requests them.
+    pub fn list_git_hub_account_token_names_stream(
+        &self,
+        input: ListGitHubAccountTokenNamesInput,
+    ) -> impl futures::Stream<Item = Result<String, RusotoError<ListGitHubAccountTokenNamesError>>> + Send
+    {
+        rusoto_core::pagination::items(self.list_git_hub_account_token_names_pages(input), |page| {
+            page.token_name_list.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `ListOnPremisesInstances` results for `input`, requesting each next page with the `nextToken` of the last one as its `nextToken`.
+    pub fn list_on_premises_instances_pages(
+        &self,
+        input: ListOnPremisesInstancesInput,
+    ) -> impl futures::Stream<
+        Item = Result<ListOnPremisesInstancesOutput, RusotoError<ListOnPremisesInstancesError>>,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: CodeDeployClient, input| async move {
+                client.list_on_premises_instances(input).await
+            },
+            |input: &ListOnPremisesInstancesInput, output: &ListOnPremisesInstancesOutput| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListOnPremisesInstancesInput {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `instanceNames` of every page of `ListOnPremisesInstances` results for `input`, as `list_on_premises_instances_pages` requests them.
+    pub fn list_on_premises_instances_stream(
+        &self,
+        input: ListOnPremisesInstancesInput,
+    ) -> impl futures::Stream<Item = Result<String, RusotoError<ListOnPremisesInstancesError>>> + Send
+    {
+        rusoto_core::pagination::items(self.list_on_premises_instances_pages(input), |page| {
+            page.instance_names.unwrap_or_default()
+        })
+    }
+
+    /// Waits for `DeploymentSuccessful`, polling `GetDeployment` with `input` every 15 seconds, up to 120 times.
+    pub async fn wait_until_deployment_successful(
+        &self,
+        input: GetDeploymentInput,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<GetDeploymentError>> {
+        let waiter = rusoto_core::waiter::Waiter::new(std::time::Duration::from_secs(15), 120);
+        self.wait_until_deployment_successful_with(input, &waiter)
+            .await
+    }
+
+    /// Waits for `DeploymentSuccessful` like `wait_until_deployment_successful`, with the delay, attempts and runtime of `waiter`.
+    pub async fn wait_until_deployment_successful_with(
+        &self,
+        input: GetDeploymentInput,
+        waiter: &rusoto_core::waiter::Waiter,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<GetDeploymentError>> {
+        rusoto_core::waiter::wait(
+            waiter,
+            || self.get_deployment(input.clone()),
+            |result| {
+                if match result {
+                    Ok(output) => std::iter::once(output)
+                        .filter_map(|value| value.deployment_info.as_ref())
+                        .filter_map(|value| value.status.as_ref())
+                        .any(|value| value == "Succeeded"),
+                    Err(_) => false,
+                } {
+                    return Some(rusoto_core::waiter::WaiterState::Success);
+                }
+                if match result {
+                    Ok(output) => std::iter::once(output)
+                        .filter_map(|value| value.deployment_info.as_ref())
+                        .filter_map(|value| value.status.as_ref())
+                        .any(|value| value == "Failed"),
+                    Err(_) => false,
+                } {
+                    return Some(rusoto_core::waiter::WaiterState::Failure);
+                }
+                if match result {
+                    Ok(output) => std::iter::once(output)
+                        .filter_map(|value| value.deployment_info.as_ref())
+                        .filter_map(|value| value.status.as_ref())
+                        .any(|value| value == "Stopped"),
+                    Err(_) => false,
+                } {
+                    return Some(rusoto_core::waiter::WaiterState::Failure);
+                }
+                None
+            },
+        )
+        .await
+    }
+}
+
+#[async_trait]
+impl CodeDeploy for CodeDeployClient {
+    /// <p>Adds tags to on-premises instances.</p>
+    async fn add_tags_to_on_premises_instances(
+        &self,
+        input: AddTagsToOnPremisesInstancesInput,
+    ) -> Result<(), RusotoError<AddTagsToOnPremisesInstancesError>> {
+        self.add_tags_to_on_premises_instances_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Gets information about one or more application revisions. The maximum number of application revisions that can be returned is 25.</p>
+    async fn batch_get_application_revisions(
+        &self,
+        input: BatchGetApplicationRevisionsInput,
+    ) -> Result<BatchGetApplicationRevisionsOutput, RusotoError<BatchGetApplicationRevisionsError>>
+    {
+        self.batch_get_application_revisions_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Gets information about one or more applications. The maximum number of applications that can be returned is 25.</p>
+    async fn batch_get_applications(
+        &self,
+        input: BatchGetApplicationsInput,
+    ) -> Result<BatchGetApplicationsOutput, RusotoError<BatchGetApplicationsError>> {
+        self.batch_get_applications_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Gets information about one or more deployment groups.</p>
+    async fn batch_get_deployment_groups(
+        &self,
+        input: BatchGetDeploymentGroupsInput,
+    ) -> Result<BatchGetDeploymentGroupsOutput, RusotoError<BatchGetDeploymentGroupsError>> {
+        self.batch_get_deployment_groups_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><note> <p> This method works, but is deprecated. Use <code>BatchGetDeploymentTargets</code> instead. </p> </note> <p> Returns an array of one or more instances associated with a deployment. This method works with EC2/On-premises and AWS Lambda compute platforms. The newer <code>BatchGetDeploymentTargets</code> works with all compute platforms. The maximum number of instances that can be returned is 25.</p></p>
+    async fn batch_get_deployment_instances(
+        &self,
+        input: BatchGetDeploymentInstancesInput,
+    ) -> Result<BatchGetDeploymentInstancesOutput, RusotoError<BatchGetDeploymentInstancesError>>
+    {
+        self.batch_get_deployment_instances_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p> Returns an array of one or more targets associated with a deployment. This method works with all compute types and should be used instead of the deprecated <code>BatchGetDeploymentInstances</code>. The maximum number of targets that can be returned is 25.</p> <p> The type of targets returned depends on the deployment&#39;s compute platform: </p> <ul> <li> <p> <b>EC2/On-premises</b>: Information about EC2 instance targets. </p> </li> <li> <p> <b>AWS Lambda</b>: Information about Lambda functions targets. </p> </li> <li> <p> <b>Amazon ECS</b>: Information about Amazon ECS service targets. </p> </li> </ul></p>
+    async fn batch_get_deployment_targets(
+        &self,
+        input: BatchGetDeploymentTargetsInput,
+    ) -> Result<BatchGetDeploymentTargetsOutput, RusotoError<BatchGetDeploymentTargetsError>> {
+        self.batch_get_deployment_targets_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Gets information about one or more deployments. The maximum number of deployments that can be returned is 25.</p>
+    async fn batch_get_deployments(
+        &self,
+        input: BatchGetDeploymentsInput,
+    ) -> Result<BatchGetDeploymentsOutput, RusotoError<BatchGetDeploymentsError>> {
+        self.batch_get_deployments_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Gets information about one or more on-premises instances. The maximum number of on-premises instances that can be returned is 25.</p>
+    async fn batch_get_on_premises_instances(
+        &self,
+        input: BatchGetOnPremisesInstancesInput,
+    ) -> Result<BatchGetOnPremisesInstancesOutput, RusotoError<BatchGetOnPremisesInstancesError>>
+    {
+        self.batch_get_on_premises_instances_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>For a blue/green deployment, starts the process of rerouting traffic from instances in the original environment to instances in the replacement environment without waiting for a specified wait time to elapse. (Traffic rerouting, which is achieved by registering instances in the replacement environment with the load balancer, can start as soon as all instances have a status of Ready.) </p>
+    async fn continue_deployment(
+        &self,
+        input: ContinueDeploymentInput,
+    ) -> Result<(), RusotoError<ContinueDeploymentError>> {
+        self.continue_deployment_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Creates an application.</p>
+    async fn create_application(
+        &self,
+        input: CreateApplicationInput,
+    ) -> Result<CreateApplicationOutput, RusotoError<CreateApplicationError>> {
+        self.create_application_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deploys an application revision through the specified deployment group.</p>
+    async fn create_deployment(
+        &self,
+        input: CreateDeploymentInput,
+    ) -> Result<CreateDeploymentOutput, RusotoError<CreateDeploymentError>> {
+        self.create_deployment_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p> Creates a deployment configuration. </p>
+    async fn create_deployment_config(
+        &self,
+        input: CreateDeploymentConfigInput,
+    ) -> Result<CreateDeploymentConfigOutput, RusotoError<CreateDeploymentConfigError>> {
+        self.create_deployment_config_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Creates a deployment group to which application revisions are deployed.</p>
+    async fn create_deployment_group(
+        &self,
+        input: CreateDeploymentGroupInput,
+    ) -> Result<CreateDeploymentGroupOutput, RusotoError<CreateDeploymentGroupError>> {
+        self.create_deployment_group_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes an application.</p>
+    async fn delete_application(
+        &self,
+        input: DeleteApplicationInput,
+    ) -> Result<(), RusotoError<DeleteApplicationError>> {
+        self.delete_application_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Deletes a deployment configuration.</p> <note> <p>A deployment configuration cannot be deleted if it is currently in use. Predefined configurations cannot be deleted.</p> </note></p>
+    async fn delete_deployment_config(
+        &self,
+        input: DeleteDeploymentConfigInput,
+    ) -> Result<(), RusotoError<DeleteDeploymentConfigError>> {
+        self.delete_deployment_config_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes a deployment group.</p>
+    async fn delete_deployment_group(
+        &self,
+        input: DeleteDeploymentGroupInput,
+    ) -> Result<DeleteDeploymentGroupOutput, RusotoError<DeleteDeploymentGroupError>> {
+        self.delete_deployment_group_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes a GitHub account connection.</p>
+    async fn delete_git_hub_account_token(
+        &self,
+        input: DeleteGitHubAccountTokenInput,
+    ) -> Result<DeleteGitHubAccountTokenOutput, RusotoError<DeleteGitHubAccountTokenError>> {
+        self.delete_git_hub_account_token_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deregisters an on-premises instance.</p>
+    async fn deregister_on_premises_instance(
+        &self,
+        input: DeregisterOnPremisesInstanceInput,
+    ) -> Result<(), RusotoError<DeregisterOnPremisesInstanceError>> {
+        self.deregister_on_premises_instance_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Gets information about an application.</p>
+    async fn get_application(
+        &self,
+        input: GetApplicationInput,
+    ) -> Result<GetApplicationOutput, RusotoError<GetApplicationError>> {
+        self.get_application_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Gets information about an application revision.</p>
+    async fn get_application_revision(
+        &self,
+        input: GetApplicationRevisionInput,
+    ) -> Result<GetApplicationRevisionOutput, RusotoError<GetApplicationRevisionError>> {
+        self.get_application_revision_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Gets information about a deployment.</p> <note> <p> The <code>content</code> property of the <code>appSpecContent</code> object in the returned revision is always null. Use <code>GetApplicationRevision</code> and the <code>sha256</code> property of the returned <code>appSpecContent</code> object to get the content of the deployment’s AppSpec file. </p> </note></p>
+    async fn get_deployment(
+        &self,
+        input: GetDeploymentInput,
+    ) -> Result<GetDeploymentOutput, RusotoError<GetDeploymentError>> {
+        self.get_deployment_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Gets information about a deployment configuration.</p>
+    async fn get_deployment_config(
+        &self,
+        input: GetDeploymentConfigInput,
+    ) -> Result<GetDeploymentConfigOutput, RusotoError<GetDeploymentConfigError>> {
+        self.get_deployment_config_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Gets information about a deployment group.</p>
+    async fn get_deployment_group(
+        &self,
+        input: GetDeploymentGroupInput,
+    ) -> Result<GetDeploymentGroupOutput, RusotoError<GetDeploymentGroupError>> {
+        self.get_deployment_group_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Gets information about an instance as part of a deployment.</p>
+    async fn get_deployment_instance(
+        &self,
+        input: GetDeploymentInstanceInput,
+    ) -> Result<GetDeploymentInstanceOutput, RusotoError<GetDeploymentInstanceError>> {
+        self.get_deployment_instance_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p> Returns information about a deployment target. </p>
+    async fn get_deployment_target(
+        &self,
+        input: GetDeploymentTargetInput,
+    ) -> Result<GetDeploymentTargetOutput, RusotoError<GetDeploymentTargetError>> {
+        self.get_deployment_target_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p> Gets information about an on-premises instance. </p>
+    async fn get_on_premises_instance(
+        &self,
+        input: GetOnPremisesInstanceInput,
+    ) -> Result<GetOnPremisesInstanceOutput, RusotoError<GetOnPremisesInstanceError>> {
+        self.get_on_premises_instance_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Lists information about revisions for an application.</p>
+    async fn list_application_revisions(
+        &self,
+        input: ListApplicationRevisionsInput,
+    ) -> Result<ListApplicationRevisionsOutput, RusotoError<ListApplicationRevisionsError>> {
+        self.list_application_revisions_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Lists the applications registered with the IAM user or AWS account.</p>
+    async fn list_applications(
+        &self,
+        input: ListApplicationsInput,
+    ) -> Result<ListApplicationsOutput, RusotoError<ListApplicationsError>> {
+        self.list_applications_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Lists the deployment configurations with the IAM user or AWS account.</p>
+    async fn list_deployment_configs(
+        &self,
+        input: ListDeploymentConfigsInput,
+    ) -> Result<ListDeploymentConfigsOutput, RusotoError<ListDeploymentConfigsError>> {
+        self.list_deployment_configs_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Lists the deployment groups for an application registered with the IAM user or AWS account.</p>
+    async fn list_deployment_groups(
+        &self,
+        input: ListDeploymentGroupsInput,
+    ) -> Result<ListDeploymentGroupsOutput, RusotoError<ListDeploymentGroupsError>> {
+        self.list_deployment_groups_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><note> <p> The newer BatchGetDeploymentTargets should be used instead because it works with all compute types. <code>ListDeploymentInstances</code> throws an exception if it is used with a compute platform other than EC2/On-premises or AWS Lambda. </p> </note> <p> Lists the instance for a deployment associated with the IAM user or AWS account. </p></p>
+    async fn list_deployment_instances(
+        &self,
+        input: ListDeploymentInstancesInput,
+    ) -> Result<ListDeploymentInstancesOutput, RusotoError<ListDeploymentInstancesError>> {
+        self.list_deployment_instances_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p> Returns an array of target IDs that are associated a deployment. </p>
+    async fn list_deployment_targets(
+        &self,
+        input: ListDeploymentTargetsInput,
+    ) -> Result<ListDeploymentTargetsOutput, RusotoError<ListDeploymentTargetsError>> {
+        self.list_deployment_targets_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Lists the deployments in a deployment group for an application registered with the IAM user or AWS account.</p>
+    async fn list_deployments(
+        &self,
+        input: ListDeploymentsInput,
+    ) -> Result<ListDeploymentsOutput, RusotoError<ListDeploymentsError>> {
+        self.list_deployments_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Lists the names of stored connections to GitHub accounts.</p>
+    async fn list_git_hub_account_token_names(
+        &self,
+        input: ListGitHubAccountTokenNamesInput,
+    ) -> Result<ListGitHubAccountTokenNamesOutput, RusotoError<ListGitHubAccountTokenNamesError>>
+    {
+        self.list_git_hub_account_token_names_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Gets a list of names for one or more on-premises instances.</p> <p>Unless otherwise specified, both registered and deregistered on-premises instance names are listed. To list only registered or deregistered on-premises instance names, use the registration status parameter.</p>
+    async fn list_on_premises_instances(
+        &self,
+        input: ListOnPremisesInstancesInput,
+    ) -> Result<ListOnPremisesInstancesOutput, RusotoError<ListOnPremisesInstancesError>> {
+        self.list_on_premises_instances_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p> Returns a list of tags for the resource identified by a specified ARN. Tags are used to organize and categorize your CodeDeploy resources. </p>
+    async fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceInput,
+    ) -> Result<ListTagsForResourceOutput, RusotoError<ListTagsForResourceError>> {
+        self.list_tags_for_resource_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p> Sets the result of a Lambda validation function. The function validates one or both lifecycle events (<code>BeforeAllowTraffic</code> and <code>AfterAllowTraffic</code>) and returns <code>Succeeded</code> or <code>Failed</code>. </p>
+    async fn put_lifecycle_event_hook_execution_status(
+        &self,
+        input: PutLifecycleEventHookExecutionStatusInput,
+    ) -> Result<
+        PutLifecycleEventHookExecutionStatusOutput,
+        RusotoError<PutLifecycleEventHookExecutionStatusError>,
+    > {
+        self.put_lifecycle_event_hook_execution_status_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Registers with AWS CodeDeploy a revision for the specified application.</p>
+    async fn register_application_revision(
+        &self,
+        input: RegisterApplicationRevisionInput,
+    ) -> Result<(), RusotoError<RegisterApplicationRevisionError>> {
+        self.register_application_revision_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Registers an on-premises instance.</p> <note> <p>Only one IAM ARN (an IAM session ARN or IAM user ARN) is supported in the request. You cannot use both.</p> </note></p>
+    async fn register_on_premises_instance(
+        &self,
+        input: RegisterOnPremisesInstanceInput,
+    ) -> Result<(), RusotoError<RegisterOnPremisesInstanceError>> {
+        self.register_on_premises_instance_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Removes one or more tags from one or more on-premises instances.</p>
+    async fn remove_tags_from_on_premises_instances(
+        &self,
+        input: RemoveTagsFromOnPremisesInstancesInput,
+    ) -> Result<(), RusotoError<RemoveTagsFromOnPremisesInstancesError>> {
+        self.remove_tags_from_on_premises_instances_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>In a blue/green deployment, overrides any specified wait time and starts terminating instances immediately after the traffic routing is complete.</p>
+    async fn skip_wait_time_for_instance_termination(
+        &self,
+        input: SkipWaitTimeForInstanceTerminationInput,
+    ) -> Result<(), RusotoError<SkipWaitTimeForInstanceTerminationError>> {
+        self.skip_wait_time_for_instance_termination_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Attempts to stop an ongoing deployment.</p>
+    async fn stop_deployment(
+        &self,
+        input: StopDeploymentInput,
+    ) -> Result<StopDeploymentOutput, RusotoError<StopDeploymentError>> {
+        self.stop_deployment_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p> Associates the list of tags in the input <code>Tags</code> parameter with the resource identified by the <code>ResourceArn</code> input parameter. </p>
+    async fn tag_resource(
+        &self,
+        input: TagResourceInput,
+    ) -> Result<TagResourceOutput, RusotoError<TagResourceError>> {
+        self.tag_resource_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p> Disassociates a resource from a list of tags. The resource is identified by the <code>ResourceArn</code> input parameter. The tags are identfied by the list of keys in the <code>TagKeys</code> input parameter. </p>
+    async fn untag_resource(
+        &self,
+        input: UntagResourceInput,
+    ) -> Result<UntagResourceOutput, RusotoError<UntagResourceError>> {
+        self.untag_resource_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Changes the name of an application.</p>
+    async fn update_application(
+        &self,
+        input: UpdateApplicationInput,
+    ) -> Result<(), RusotoError<UpdateApplicationError>> {
+        self.update_application_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Changes information about a deployment group.</p>
+    async fn update_deployment_group(
+        &self,
+        input: UpdateDeploymentGroupInput,
+    ) -> Result<UpdateDeploymentGroupOutput, RusotoError<UpdateDeploymentGroupError>> {
+        self.update_deployment_group_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
 }
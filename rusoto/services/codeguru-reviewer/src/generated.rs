@@ -16,8 +16,8 @@ use std::fmt;
 use async_trait::async_trait;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
-use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest, ResponseMetadata};
+use rusoto_core::{Client, ErrorMetadata, RusotoError};
 
 use rusoto_core::param::{Params, ServiceParams};
 use rusoto_core::proto;
@@ -219,6 +219,7 @@ pub struct RepositoryAssociationSummary {
 
 /// Errors returned by AssociateRepository
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum AssociateRepositoryError {
     /// <p>You do not have sufficient access to perform this action.</p>
     AccessDenied(String),
@@ -228,6 +229,8 @@ pub enum AssociateRepositoryError {
     InternalServer(String),
     /// <p>The request was denied due to request throttling.</p>
     Throttling(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl AssociateRepositoryError {
@@ -235,19 +238,28 @@ impl AssociateRepositoryError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(AssociateRepositoryError::AccessDenied(err.msg))
+                    return RusotoError::service(
+                        AssociateRepositoryError::AccessDenied(err.msg),
+                        &res,
+                    )
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(AssociateRepositoryError::Conflict(err.msg))
+                    return RusotoError::service(AssociateRepositoryError::Conflict(err.msg), &res)
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(AssociateRepositoryError::InternalServer(err.msg))
+                    return RusotoError::service(
+                        AssociateRepositoryError::InternalServer(err.msg),
+                        &res,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(AssociateRepositoryError::Throttling(err.msg))
+                    return RusotoError::service(
+                        AssociateRepositoryError::Throttling(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(AssociateRepositoryError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -261,12 +273,14 @@ impl fmt::Display for AssociateRepositoryError {
             AssociateRepositoryError::Conflict(ref cause) => write!(f, "{}", cause),
             AssociateRepositoryError::InternalServer(ref cause) => write!(f, "{}", cause),
             AssociateRepositoryError::Throttling(ref cause) => write!(f, "{}", cause),
+            AssociateRepositoryError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for AssociateRepositoryError {}
 /// Errors returned by DescribeRepositoryAssociation
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeRepositoryAssociationError {
     /// <p>You do not have sufficient access to perform this action.</p>
     AccessDenied(String),
@@ -276,6 +290,8 @@ pub enum DescribeRepositoryAssociationError {
     NotFound(String),
     /// <p>The request was denied due to request throttling.</p>
     Throttling(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeRepositoryAssociationError {
@@ -285,27 +301,36 @@ impl DescribeRepositoryAssociationError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(DescribeRepositoryAssociationError::AccessDenied(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeRepositoryAssociationError::AccessDenied(err.msg),
+                        &res,
+                    )
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DescribeRepositoryAssociationError::InternalServer(err.msg),
+                        &res,
                     )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DescribeRepositoryAssociationError::NotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeRepositoryAssociationError::NotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DescribeRepositoryAssociationError::Throttling(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeRepositoryAssociationError::Throttling(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        DescribeRepositoryAssociationError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -319,12 +344,16 @@ impl fmt::Display for DescribeRepositoryAssociationError {
             DescribeRepositoryAssociationError::InternalServer(ref cause) => write!(f, "{}", cause),
             DescribeRepositoryAssociationError::NotFound(ref cause) => write!(f, "{}", cause),
             DescribeRepositoryAssociationError::Throttling(ref cause) => write!(f, "{}", cause),
+            DescribeRepositoryAssociationError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for DescribeRepositoryAssociationError {}
 /// Errors returned by DisassociateRepository
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DisassociateRepositoryError {
     /// <p>You do not have sufficient access to perform this action.</p>
     AccessDenied(String),
@@ -336,6 +365,8 @@ pub enum DisassociateRepositoryError {
     NotFound(String),
     /// <p>The request was denied due to request throttling.</p>
     Throttling(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DisassociateRepositoryError {
@@ -343,24 +374,37 @@ impl DisassociateRepositoryError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(DisassociateRepositoryError::AccessDenied(err.msg))
+                    return RusotoError::service(
+                        DisassociateRepositoryError::AccessDenied(err.msg),
+                        &res,
+                    )
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(DisassociateRepositoryError::Conflict(err.msg))
+                    return RusotoError::service(
+                        DisassociateRepositoryError::Conflict(err.msg),
+                        &res,
+                    )
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(DisassociateRepositoryError::InternalServer(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DisassociateRepositoryError::InternalServer(err.msg),
+                        &res,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DisassociateRepositoryError::NotFound(err.msg))
+                    return RusotoError::service(
+                        DisassociateRepositoryError::NotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DisassociateRepositoryError::Throttling(err.msg))
+                    return RusotoError::service(
+                        DisassociateRepositoryError::Throttling(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DisassociateRepositoryError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -375,17 +419,21 @@ impl fmt::Display for DisassociateRepositoryError {
             DisassociateRepositoryError::InternalServer(ref cause) => write!(f, "{}", cause),
             DisassociateRepositoryError::NotFound(ref cause) => write!(f, "{}", cause),
             DisassociateRepositoryError::Throttling(ref cause) => write!(f, "{}", cause),
+            DisassociateRepositoryError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DisassociateRepositoryError {}
 /// Errors returned by ListRepositoryAssociations
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListRepositoryAssociationsError {
     /// <p>The server encountered an internal error and is unable to complete the request.</p>
     InternalServer(String),
     /// <p>The request was denied due to request throttling.</p>
     Throttling(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListRepositoryAssociationsError {
@@ -395,17 +443,21 @@ impl ListRepositoryAssociationsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(ListRepositoryAssociationsError::InternalServer(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListRepositoryAssociationsError::InternalServer(err.msg),
+                        &res,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(ListRepositoryAssociationsError::Throttling(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListRepositoryAssociationsError::Throttling(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(ListRepositoryAssociationsError::Unhandled, &res)
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -417,6 +469,7 @@ impl fmt::Display for ListRepositoryAssociationsError {
         match *self {
             ListRepositoryAssociationsError::InternalServer(ref cause) => write!(f, "{}", cause),
             ListRepositoryAssociationsError::Throttling(ref cause) => write!(f, "{}", cause),
+            ListRepositoryAssociationsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
@@ -462,6 +515,7 @@ impl CodeGuruReviewerClient {
     /// Creates a client backed by the default tokio event loop.
     ///
     /// The client will use the default credentials provider and tls client.
+    #[cfg(not(target_arch = "wasm32"))]
     pub fn new(region: region::Region) -> CodeGuruReviewerClient {
         CodeGuruReviewerClient {
             client: Client::shared(),
@@ -487,49 +541,133 @@ impl CodeGuruReviewerClient {
     pub fn new_with_client(client: Client, region: region::Region) -> CodeGuruReviewerClient {
         CodeGuruReviewerClient { client, region }
     }
+
+    /// Returns a builder of a client, setting any of its region, credentials provider,
+    /// dispatcher, retry policy, endpoint and timeouts.
+    pub fn builder() -> rusoto_core::ClientBuilder<CodeGuruReviewerClient> {
+        rusoto_core::ClientBuilder::new(CodeGuruReviewerClient::new_with_client)
+    }
+
+    /// Returns a clone of this client with the overrides of `config` applied to its
+    /// operations, sharing its connections.
+    pub fn customize(&self, config: &rusoto_core::OperationConfig) -> CodeGuruReviewerClient {
+        CodeGuruReviewerClient {
+            client: self.client.customize(config),
+            region: config.resolve_region(&self.region),
+        }
+    }
 }
 
-#[async_trait]
-impl CodeGuruReviewer for CodeGuruReviewerClient {
-    /// <p>Associates an AWS CodeCommit repository with Amazon CodeGuru Reviewer. When you associate an AWS CodeCommit repository with Amazon CodeGuru Reviewer, Amazon CodeGuru Reviewer will provide recommendations for each pull request. You can view recommendations in the AWS CodeCommit repository.</p> <p>You can associate a GitHub repository using the Amazon CodeGuru Reviewer console.</p>
-    async fn associate_repository(
+impl CodeGuruReviewerClient {
+    /// Calls `associate_repository`, also returning the metadata of the response.
+    pub async fn associate_repository_with_metadata(
         &self,
         input: AssociateRepositoryRequest,
-    ) -> Result<AssociateRepositoryResponse, RusotoError<AssociateRepositoryError>> {
+    ) -> Result<
+        (AssociateRepositoryResponse, ResponseMetadata),
+        RusotoError<AssociateRepositoryError>,
+    > {
+        let request = self.build_associate_repository_request(input);
+
+        let mut response = self
+            .client
+            .sign_and_dispatch(request)
+            .await
+            .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
+        if response.status.is_success() {
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<AssociateRepositoryResponse, _>()?;
+
+            Ok((result, metadata))
+        } else {
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(AssociateRepositoryError::from_response(response))
+        }
+    }
+
+    /// Presigns the request `associate_repository` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_associate_repository(
+        &self,
+        presigner: &rusoto_core::Presigner,
+        input: AssociateRepositoryRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_associate_repository_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_associate_repository_request(
+        &self,
+        input: AssociateRepositoryRequest,
+    ) -> SignedRequest {
         let request_uri = "/associations";
 
         let mut request =
             SignedRequest::new("POST", "codeguru-reviewer", &self.region, &request_uri);
+        request.set_operation("AssociateRepository");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `describe_repository_association`, also returning the metadata of the response.
+    pub async fn describe_repository_association_with_metadata(
+        &self,
+        input: DescribeRepositoryAssociationRequest,
+    ) -> Result<
+        (DescribeRepositoryAssociationResponse, ResponseMetadata),
+        RusotoError<DescribeRepositoryAssociationError>,
+    > {
+        let request = self.build_describe_repository_association_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<AssociateRepositoryResponse, _>()?;
+                .deserialize::<DescribeRepositoryAssociationResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(AssociateRepositoryError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(DescribeRepositoryAssociationError::from_response(response))
         }
     }
 
-    /// <p>Describes a repository association.</p>
-    async fn describe_repository_association(
+    /// Presigns the request `describe_repository_association` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_repository_association(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeRepositoryAssociationRequest,
-    ) -> Result<
-        DescribeRepositoryAssociationResponse,
-        RusotoError<DescribeRepositoryAssociationError>,
-    > {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_repository_association_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_repository_association_request(
+        &self,
+        input: DescribeRepositoryAssociationRequest,
+    ) -> SignedRequest {
         let request_uri = format!(
             "/associations/{association_arn}",
             association_arn = input.association_arn
@@ -537,30 +675,60 @@ impl CodeGuruReviewer for CodeGuruReviewerClient {
 
         let mut request =
             SignedRequest::new("GET", "codeguru-reviewer", &self.region, &request_uri);
+        request.set_operation("DescribeRepositoryAssociation");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
+        request
+    }
+
+    /// Calls `disassociate_repository`, also returning the metadata of the response.
+    pub async fn disassociate_repository_with_metadata(
+        &self,
+        input: DisassociateRepositoryRequest,
+    ) -> Result<
+        (DisassociateRepositoryResponse, ResponseMetadata),
+        RusotoError<DisassociateRepositoryError>,
+    > {
+        let request = self.build_disassociate_repository_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<DescribeRepositoryAssociationResponse, _>()?;
+                .deserialize::<DisassociateRepositoryResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeRepositoryAssociationError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(DisassociateRepositoryError::from_response(response))
         }
     }
 
-    /// <p>Removes the association between Amazon CodeGuru Reviewer and a repository.</p>
-    async fn disassociate_repository(
+    /// Presigns the request `disassociate_repository` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_disassociate_repository(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DisassociateRepositoryRequest,
-    ) -> Result<DisassociateRepositoryResponse, RusotoError<DisassociateRepositoryError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_disassociate_repository_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_disassociate_repository_request(
+        &self,
+        input: DisassociateRepositoryRequest,
+    ) -> SignedRequest {
         let request_uri = format!(
             "/associations/{association_arn}",
             association_arn = input.association_arn
@@ -568,35 +736,65 @@ impl CodeGuruReviewer for CodeGuruReviewerClient {
 
         let mut request =
             SignedRequest::new("DELETE", "codeguru-reviewer", &self.region, &request_uri);
+        request.set_operation("DisassociateRepository");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
+        request
+    }
+
+    /// Calls `list_repository_associations`, also returning the metadata of the response.
+    pub async fn list_repository_associations_with_metadata(
+        &self,
+        input: ListRepositoryAssociationsRequest,
+    ) -> Result<
+        (ListRepositoryAssociationsResponse, ResponseMetadata),
+        RusotoError<ListRepositoryAssociationsError>,
+    > {
+        let request = self.build_list_repository_associations_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<DisassociateRepositoryResponse, _>()?;
+                .deserialize::<ListRepositoryAssociationsResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(DisassociateRepositoryError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(ListRepositoryAssociationsError::from_response(response))
         }
     }
 
-    /// <p>Lists repository associations. You can optionally filter on one or more of the following recommendation properties: provider types, states, names, and owners.</p>
-    async fn list_repository_associations(
+    /// Presigns the request `list_repository_associations` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_repository_associations(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListRepositoryAssociationsRequest,
-    ) -> Result<ListRepositoryAssociationsResponse, RusotoError<ListRepositoryAssociationsError>>
-    {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_repository_associations_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_repository_associations_request(
+        &self,
+        input: ListRepositoryAssociationsRequest,
+    ) -> SignedRequest {
         let request_uri = "/associations";
 
         let mut request =
             SignedRequest::new("GET", "codeguru-reviewer", &self.region, &request_uri);
+        request.set_operation("ListRepositoryAssociations");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -628,20 +826,150 @@ impl CodeGuruReviewer for CodeGuruReviewerClient {
         }
         request.set_params(params);
 
-        let mut response = self
-            .client
-            .sign_and_dispatch(request)
+        request
+    }
+
+    /// The pages of `ListRepositoryAssociations` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `MaxResults` of `input`.
+    pub fn list_repository_associations_pages(
+        &self,
+        input: ListRepositoryAssociationsRequest,
+    ) -> impl futures::Stream<
+        Item = Result<
+            ListRepositoryAssociationsResponse,
+            RusotoError<ListRepositoryAssociationsError>,
+        >,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: CodeGuruReviewerClient, input| async move {
+                client.list_repository_associations(input).await
+            },
+            |input: &ListRepositoryAssociationsRequest,
+             output: &ListRepositoryAssociationsResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListRepositoryAssociationsRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `RepositoryAssociationSummaries` of every page of `ListRepositoryAssociations` results for `input`, as `list_repository_associations_pages` requests them.
+    pub fn list_repository_associations_stream(
+        &self,
+        input: ListRepositoryAssociationsRequest,
+    ) -> impl futures::Stream<
+        Item = Result<RepositoryAssociationSummary, RusotoError<ListRepositoryAssociationsError>>,
+    > + Send {
+        rusoto_core::pagination::items(self.list_repository_associations_pages(input), |page| {
+            page.repository_association_summaries.unwrap_or_default()
+        })
+    }
+
+    /// Waits for `RepositoryAssociationSucceeded`, polling `DescribeRepositoryAssociation` with `input` every 10 seconds, up to 30 times.
+    pub async fn wait_until_repository_association_succeeded(
+        &self,
+        input: DescribeRepositoryAssociationRequest,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<DescribeRepositoryAssociationError>> {
+        let waiter = rusoto_core::waiter::Waiter::new(std::time::Duration::from_secs(10), 30);
+        self.wait_until_repository_association_succeeded_with(input, &waiter)
             .await
-            .map_err(RusotoError::from)?;
-        if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListRepositoryAssociationsResponse, _>()?;
+    }
 
-            Ok(result)
-        } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(ListRepositoryAssociationsError::from_response(response))
-        }
+    /// Waits for `RepositoryAssociationSucceeded` like `wait_until_repository_association_succeeded`, with the delay, attempts and runtime of `waiter`.
+    pub async fn wait_until_repository_association_succeeded_with(
+        &self,
+        input: DescribeRepositoryAssociationRequest,
+        waiter: &rusoto_core::waiter::Waiter,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<DescribeRepositoryAssociationError>> {
+        rusoto_core::waiter::wait(
+            waiter,
+            || self.describe_repository_association(input.clone()),
+            |result| {
+                if match result {
+                    Ok(output) => std::iter::once(output)
+                        .filter_map(|value| value.repository_association.as_ref())
+                        .filter_map(|value| value.state.as_ref())
+                        .any(|value| value == "Associated"),
+                    Err(_) => false,
+                } {
+                    return Some(rusoto_core::waiter::WaiterState::Success);
+                }
+                if match result {
+                    Ok(output) => std::iter::once(output)
+                        .filter_map(|value| value.repository_association.as_ref())
+                        .filter_map(|value| value.state.as_ref())
+                        .any(|value| value == "Failed"),
+                    Err(_) => false,
+                } {
+                    return Some(rusoto_core::waiter::WaiterState::Failure);
+                }
+                if match result {
+                    Ok(output) => std::iter::once(output)
+                        .filter_map(|value| value.repository_association.as_ref())
+                        .filter_map(|value| value.state.as_ref())
+                        .any(|value| value == "Associating"),
+                    Err(_) => false,
+                } {
+                    return Some(rusoto_core::waiter::WaiterState::Retry);
+                }
+                None
+            },
+        )
+        .await
+    }
+}
+
+#[async_trait]
+impl CodeGuruReviewer for CodeGuruReviewerClient {
+    /// <p>Associates an AWS CodeCommit repository with Amazon CodeGuru Reviewer. When you associate an AWS CodeCommit repository with Amazon CodeGuru Reviewer, Amazon CodeGuru Reviewer will provide recommendations for each pull request. You can view recommendations in the AWS CodeCommit repository.</p> <p>You can associate a GitHub repository using the Amazon CodeGuru Reviewer console.</p>
+    async fn associate_repository(
+        &self,
+        input: AssociateRepositoryRequest,
+    ) -> Result<AssociateRepositoryResponse, RusotoError<AssociateRepositoryError>> {
+        self.associate_repository_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Describes a repository association.</p>
+    async fn describe_repository_association(
+        &self,
+        input: DescribeRepositoryAssociationRequest,
+    ) -> Result<
+        DescribeRepositoryAssociationResponse,
+        RusotoError<DescribeRepositoryAssociationError>,
+    > {
+        self.describe_repository_association_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Removes the association between Amazon CodeGuru Reviewer and a repository.</p>
+    async fn disassociate_repository(
+        &self,
+        input: DisassociateRepositoryRequest,
+    ) -> Result<DisassociateRepositoryResponse, RusotoError<DisassociateRepositoryError>> {
+        self.disassociate_repository_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Lists repository associations. You can optionally filter on one or more of the following recommendation properties: provider types, states, names, and owners.</p>
+    async fn list_repository_associations(
+        &self,
+        input: ListRepositoryAssociationsRequest,
+    ) -> Result<ListRepositoryAssociationsResponse, RusotoError<ListRepositoryAssociationsError>>
+    {
+        self.list_repository_associations_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
     }
 }
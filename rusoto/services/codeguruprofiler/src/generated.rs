@@ -16,8 +16,8 @@ use std::fmt;
 use async_trait::async_trait;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
-use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest, ResponseMetadata};
+use rusoto_core::{Client, ErrorMetadata, RusotoError};
 
 use rusoto_core::param::{Params, ServiceParams};
 use rusoto_core::proto;
@@ -337,6 +337,7 @@ pub struct UpdateProfilingGroupResponse {
 
 /// Errors returned by ConfigureAgent
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ConfigureAgentError {
     /// <p>Unexpected error during processing of request.</p>
     InternalServer(String),
@@ -344,6 +345,8 @@ pub enum ConfigureAgentError {
     ResourceNotFound(String),
     /// <p>Request was denied due to request throttling.</p>
     Throttling(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ConfigureAgentError {
@@ -351,16 +354,19 @@ impl ConfigureAgentError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(ConfigureAgentError::InternalServer(err.msg))
+                    return RusotoError::service(ConfigureAgentError::InternalServer(err.msg), &res)
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ConfigureAgentError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        ConfigureAgentError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(ConfigureAgentError::Throttling(err.msg))
+                    return RusotoError::service(ConfigureAgentError::Throttling(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ConfigureAgentError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -373,12 +379,14 @@ impl fmt::Display for ConfigureAgentError {
             ConfigureAgentError::InternalServer(ref cause) => write!(f, "{}", cause),
             ConfigureAgentError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             ConfigureAgentError::Throttling(ref cause) => write!(f, "{}", cause),
+            ConfigureAgentError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ConfigureAgentError {}
 /// Errors returned by CreateProfilingGroup
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateProfilingGroupError {
     /// <p>Request can can cause an inconsistent state for the resource.</p>
     Conflict(String),
@@ -388,6 +396,8 @@ pub enum CreateProfilingGroupError {
     ServiceQuotaExceeded(String),
     /// <p>Request was denied due to request throttling.</p>
     Throttling(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateProfilingGroupError {
@@ -395,21 +405,28 @@ impl CreateProfilingGroupError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ConflictException" => {
-                    return RusotoError::Service(CreateProfilingGroupError::Conflict(err.msg))
+                    return RusotoError::service(CreateProfilingGroupError::Conflict(err.msg), &res)
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(CreateProfilingGroupError::InternalServer(err.msg))
+                    return RusotoError::service(
+                        CreateProfilingGroupError::InternalServer(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceQuotaExceededException" => {
-                    return RusotoError::Service(CreateProfilingGroupError::ServiceQuotaExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateProfilingGroupError::ServiceQuotaExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(CreateProfilingGroupError::Throttling(err.msg))
+                    return RusotoError::service(
+                        CreateProfilingGroupError::Throttling(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreateProfilingGroupError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -423,12 +440,14 @@ impl fmt::Display for CreateProfilingGroupError {
             CreateProfilingGroupError::InternalServer(ref cause) => write!(f, "{}", cause),
             CreateProfilingGroupError::ServiceQuotaExceeded(ref cause) => write!(f, "{}", cause),
             CreateProfilingGroupError::Throttling(ref cause) => write!(f, "{}", cause),
+            CreateProfilingGroupError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateProfilingGroupError {}
 /// Errors returned by DeleteProfilingGroup
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteProfilingGroupError {
     /// <p>Unexpected error during processing of request.</p>
     InternalServer(String),
@@ -436,6 +455,8 @@ pub enum DeleteProfilingGroupError {
     ResourceNotFound(String),
     /// <p>Request was denied due to request throttling.</p>
     Throttling(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteProfilingGroupError {
@@ -443,18 +464,25 @@ impl DeleteProfilingGroupError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(DeleteProfilingGroupError::InternalServer(err.msg))
+                    return RusotoError::service(
+                        DeleteProfilingGroupError::InternalServer(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteProfilingGroupError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteProfilingGroupError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DeleteProfilingGroupError::Throttling(err.msg))
+                    return RusotoError::service(
+                        DeleteProfilingGroupError::Throttling(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteProfilingGroupError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -467,12 +495,14 @@ impl fmt::Display for DeleteProfilingGroupError {
             DeleteProfilingGroupError::InternalServer(ref cause) => write!(f, "{}", cause),
             DeleteProfilingGroupError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             DeleteProfilingGroupError::Throttling(ref cause) => write!(f, "{}", cause),
+            DeleteProfilingGroupError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteProfilingGroupError {}
 /// Errors returned by DescribeProfilingGroup
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeProfilingGroupError {
     /// <p>Unexpected error during processing of request.</p>
     InternalServer(String),
@@ -480,6 +510,8 @@ pub enum DescribeProfilingGroupError {
     ResourceNotFound(String),
     /// <p>Request was denied due to request throttling.</p>
     Throttling(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeProfilingGroupError {
@@ -487,20 +519,25 @@ impl DescribeProfilingGroupError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(DescribeProfilingGroupError::InternalServer(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeProfilingGroupError::InternalServer(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeProfilingGroupError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeProfilingGroupError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DescribeProfilingGroupError::Throttling(err.msg))
+                    return RusotoError::service(
+                        DescribeProfilingGroupError::Throttling(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DescribeProfilingGroupError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -513,12 +550,14 @@ impl fmt::Display for DescribeProfilingGroupError {
             DescribeProfilingGroupError::InternalServer(ref cause) => write!(f, "{}", cause),
             DescribeProfilingGroupError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             DescribeProfilingGroupError::Throttling(ref cause) => write!(f, "{}", cause),
+            DescribeProfilingGroupError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeProfilingGroupError {}
 /// Errors returned by GetProfile
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetProfileError {
     /// <p>Unexpected error during processing of request.</p>
     InternalServer(String),
@@ -526,6 +565,8 @@ pub enum GetProfileError {
     ResourceNotFound(String),
     /// <p>Request was denied due to request throttling.</p>
     Throttling(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetProfileError {
@@ -533,16 +574,16 @@ impl GetProfileError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(GetProfileError::InternalServer(err.msg))
+                    return RusotoError::service(GetProfileError::InternalServer(err.msg), &res)
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetProfileError::ResourceNotFound(err.msg))
+                    return RusotoError::service(GetProfileError::ResourceNotFound(err.msg), &res)
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(GetProfileError::Throttling(err.msg))
+                    return RusotoError::service(GetProfileError::Throttling(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetProfileError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -555,12 +596,14 @@ impl fmt::Display for GetProfileError {
             GetProfileError::InternalServer(ref cause) => write!(f, "{}", cause),
             GetProfileError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             GetProfileError::Throttling(ref cause) => write!(f, "{}", cause),
+            GetProfileError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetProfileError {}
 /// Errors returned by ListProfileTimes
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListProfileTimesError {
     /// <p>Unexpected error during processing of request.</p>
     InternalServer(String),
@@ -568,6 +611,8 @@ pub enum ListProfileTimesError {
     ResourceNotFound(String),
     /// <p>Request was denied due to request throttling.</p>
     Throttling(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListProfileTimesError {
@@ -575,16 +620,22 @@ impl ListProfileTimesError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(ListProfileTimesError::InternalServer(err.msg))
+                    return RusotoError::service(
+                        ListProfileTimesError::InternalServer(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListProfileTimesError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        ListProfileTimesError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(ListProfileTimesError::Throttling(err.msg))
+                    return RusotoError::service(ListProfileTimesError::Throttling(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListProfileTimesError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -597,17 +648,21 @@ impl fmt::Display for ListProfileTimesError {
             ListProfileTimesError::InternalServer(ref cause) => write!(f, "{}", cause),
             ListProfileTimesError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             ListProfileTimesError::Throttling(ref cause) => write!(f, "{}", cause),
+            ListProfileTimesError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListProfileTimesError {}
 /// Errors returned by ListProfilingGroups
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListProfilingGroupsError {
     /// <p>Unexpected error during processing of request.</p>
     InternalServer(String),
     /// <p>Request was denied due to request throttling.</p>
     Throttling(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListProfilingGroupsError {
@@ -615,13 +670,19 @@ impl ListProfilingGroupsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(ListProfilingGroupsError::InternalServer(err.msg))
+                    return RusotoError::service(
+                        ListProfilingGroupsError::InternalServer(err.msg),
+                        &res,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(ListProfilingGroupsError::Throttling(err.msg))
+                    return RusotoError::service(
+                        ListProfilingGroupsError::Throttling(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListProfilingGroupsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -633,12 +694,14 @@ impl fmt::Display for ListProfilingGroupsError {
         match *self {
             ListProfilingGroupsError::InternalServer(ref cause) => write!(f, "{}", cause),
             ListProfilingGroupsError::Throttling(ref cause) => write!(f, "{}", cause),
+            ListProfilingGroupsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListProfilingGroupsError {}
 /// Errors returned by PostAgentProfile
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum PostAgentProfileError {
     /// <p>Unexpected error during processing of request.</p>
     InternalServer(String),
@@ -646,6 +709,8 @@ pub enum PostAgentProfileError {
     ResourceNotFound(String),
     /// <p>Request was denied due to request throttling.</p>
     Throttling(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl PostAgentProfileError {
@@ -653,16 +718,22 @@ impl PostAgentProfileError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(PostAgentProfileError::InternalServer(err.msg))
+                    return RusotoError::service(
+                        PostAgentProfileError::InternalServer(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(PostAgentProfileError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        PostAgentProfileError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(PostAgentProfileError::Throttling(err.msg))
+                    return RusotoError::service(PostAgentProfileError::Throttling(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(PostAgentProfileError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -675,12 +746,14 @@ impl fmt::Display for PostAgentProfileError {
             PostAgentProfileError::InternalServer(ref cause) => write!(f, "{}", cause),
             PostAgentProfileError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             PostAgentProfileError::Throttling(ref cause) => write!(f, "{}", cause),
+            PostAgentProfileError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for PostAgentProfileError {}
 /// Errors returned by UpdateProfilingGroup
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdateProfilingGroupError {
     /// <p>Request can can cause an inconsistent state for the resource.</p>
     Conflict(String),
@@ -690,6 +763,8 @@ pub enum UpdateProfilingGroupError {
     ResourceNotFound(String),
     /// <p>Request was denied due to request throttling.</p>
     Throttling(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdateProfilingGroupError {
@@ -697,21 +772,28 @@ impl UpdateProfilingGroupError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ConflictException" => {
-                    return RusotoError::Service(UpdateProfilingGroupError::Conflict(err.msg))
+                    return RusotoError::service(UpdateProfilingGroupError::Conflict(err.msg), &res)
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(UpdateProfilingGroupError::InternalServer(err.msg))
+                    return RusotoError::service(
+                        UpdateProfilingGroupError::InternalServer(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateProfilingGroupError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateProfilingGroupError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(UpdateProfilingGroupError::Throttling(err.msg))
+                    return RusotoError::service(
+                        UpdateProfilingGroupError::Throttling(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UpdateProfilingGroupError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -725,6 +807,7 @@ impl fmt::Display for UpdateProfilingGroupError {
             UpdateProfilingGroupError::InternalServer(ref cause) => write!(f, "{}", cause),
             UpdateProfilingGroupError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             UpdateProfilingGroupError::Throttling(ref cause) => write!(f, "{}", cause),
+            UpdateProfilingGroupError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
@@ -797,6 +880,7 @@ impl CodeGuruProfilerClient {
     /// Creates a client backed by the default tokio event loop.
     ///
     /// The client will use the default credentials provider and tls client.
+    #[cfg(not(target_arch = "wasm32"))]
     pub fn new(region: region::Region) -> CodeGuruProfilerClient {
         CodeGuruProfilerClient {
             client: Client::shared(),
@@ -822,15 +906,66 @@ impl CodeGuruProfilerClient {
     pub fn new_with_client(client: Client, region: region::Region) -> CodeGuruProfilerClient {
         CodeGuruProfilerClient { client, region }
     }
+
+    /// Returns a builder of a client, setting any of its region, credentials provider,
+    /// dispatcher, retry policy, endpoint and timeouts.
+    pub fn builder() -> rusoto_core::ClientBuilder<CodeGuruProfilerClient> {
+        rusoto_core::ClientBuilder::new(CodeGuruProfilerClient::new_with_client)
+    }
+
+    /// Returns a clone of this client with the overrides of `config` applied to its
+    /// operations, sharing its connections.
+    pub fn customize(&self, config: &rusoto_core::OperationConfig) -> CodeGuruProfilerClient {
+        CodeGuruProfilerClient {
+            client: self.client.customize(config),
+            region: config.resolve_region(&self.region),
+        }
+    }
 }
 
-#[async_trait]
-impl CodeGuruProfiler for CodeGuruProfilerClient {
-    /// <p>Provides the configuration to use for an agent of the profiling group.</p>
-    async fn configure_agent(
+impl CodeGuruProfilerClient {
+    /// Calls `configure_agent`, also returning the metadata of the response.
+    pub async fn configure_agent_with_metadata(
         &self,
         input: ConfigureAgentRequest,
-    ) -> Result<ConfigureAgentResponse, RusotoError<ConfigureAgentError>> {
+    ) -> Result<(ConfigureAgentResponse, ResponseMetadata), RusotoError<ConfigureAgentError>> {
+        let request = self.build_configure_agent_request(input);
+
+        let mut response = self
+            .client
+            .sign_and_dispatch(request)
+            .await
+            .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
+        if response.status.as_u16() == 200 {
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ConfigureAgentResponse, _>()?;
+
+            Ok((result, metadata))
+        } else {
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(ConfigureAgentError::from_response(response))
+        }
+    }
+
+    /// Presigns the request `configure_agent` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_configure_agent(
+        &self,
+        presigner: &rusoto_core::Presigner,
+        input: ConfigureAgentRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_configure_agent_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_configure_agent_request(&self, input: ConfigureAgentRequest) -> SignedRequest {
         let request_uri = format!(
             "/profilingGroups/{profiling_group_name}/configureAgent",
             profiling_group_name = input.profiling_group_name
@@ -838,37 +973,68 @@ impl CodeGuruProfiler for CodeGuruProfilerClient {
 
         let mut request =
             SignedRequest::new("POST", "codeguru-profiler", &self.region, &request_uri);
+        request.set_operation("ConfigureAgent");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `create_profiling_group`, also returning the metadata of the response.
+    pub async fn create_profiling_group_with_metadata(
+        &self,
+        input: CreateProfilingGroupRequest,
+    ) -> Result<
+        (CreateProfilingGroupResponse, ResponseMetadata),
+        RusotoError<CreateProfilingGroupError>,
+    > {
+        let request = self.build_create_profiling_group_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
-        if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let metadata = ResponseMetadata::from_response(&response);
+        if response.status.as_u16() == 201 {
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<ConfigureAgentResponse, _>()?;
+                .deserialize::<CreateProfilingGroupResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(ConfigureAgentError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(CreateProfilingGroupError::from_response(response))
         }
     }
 
-    /// <p>Create a profiling group.</p>
-    async fn create_profiling_group(
+    /// Presigns the request `create_profiling_group` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_profiling_group(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: CreateProfilingGroupRequest,
-    ) -> Result<CreateProfilingGroupResponse, RusotoError<CreateProfilingGroupError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_profiling_group_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_profiling_group_request(
+        &self,
+        input: CreateProfilingGroupRequest,
+    ) -> SignedRequest {
         let request_uri = "/profilingGroups";
 
         let mut request =
             SignedRequest::new("POST", "codeguru-profiler", &self.region, &request_uri);
+        request.set_operation("CreateProfilingGroup");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -878,28 +1044,57 @@ impl CodeGuruProfiler for CodeGuruProfilerClient {
         params.put("clientToken", &input.client_token);
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `delete_profiling_group`, also returning the metadata of the response.
+    pub async fn delete_profiling_group_with_metadata(
+        &self,
+        input: DeleteProfilingGroupRequest,
+    ) -> Result<
+        (DeleteProfilingGroupResponse, ResponseMetadata),
+        RusotoError<DeleteProfilingGroupError>,
+    > {
+        let request = self.build_delete_profiling_group_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
-        if response.status.as_u16() == 201 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let metadata = ResponseMetadata::from_response(&response);
+        if response.status.as_u16() == 204 {
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<CreateProfilingGroupResponse, _>()?;
+                .deserialize::<DeleteProfilingGroupResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(CreateProfilingGroupError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(DeleteProfilingGroupError::from_response(response))
         }
     }
 
-    /// <p>Delete a profiling group.</p>
-    async fn delete_profiling_group(
+    /// Presigns the request `delete_profiling_group` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_profiling_group(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteProfilingGroupRequest,
-    ) -> Result<DeleteProfilingGroupResponse, RusotoError<DeleteProfilingGroupError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_profiling_group_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_profiling_group_request(
+        &self,
+        input: DeleteProfilingGroupRequest,
+    ) -> SignedRequest {
         let request_uri = format!(
             "/profilingGroups/{profiling_group_name}",
             profiling_group_name = input.profiling_group_name
@@ -907,30 +1102,60 @@ impl CodeGuruProfiler for CodeGuruProfilerClient {
 
         let mut request =
             SignedRequest::new("DELETE", "codeguru-profiler", &self.region, &request_uri);
+        request.set_operation("DeleteProfilingGroup");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
+        request
+    }
+
+    /// Calls `describe_profiling_group`, also returning the metadata of the response.
+    pub async fn describe_profiling_group_with_metadata(
+        &self,
+        input: DescribeProfilingGroupRequest,
+    ) -> Result<
+        (DescribeProfilingGroupResponse, ResponseMetadata),
+        RusotoError<DescribeProfilingGroupError>,
+    > {
+        let request = self.build_describe_profiling_group_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
-        if response.status.as_u16() == 204 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let metadata = ResponseMetadata::from_response(&response);
+        if response.status.as_u16() == 200 {
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<DeleteProfilingGroupResponse, _>()?;
+                .deserialize::<DescribeProfilingGroupResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteProfilingGroupError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(DescribeProfilingGroupError::from_response(response))
         }
     }
 
-    /// <p>Describe a profiling group.</p>
-    async fn describe_profiling_group(
+    /// Presigns the request `describe_profiling_group` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_profiling_group(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeProfilingGroupRequest,
-    ) -> Result<DescribeProfilingGroupResponse, RusotoError<DescribeProfilingGroupError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_profiling_group_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_profiling_group_request(
+        &self,
+        input: DescribeProfilingGroupRequest,
+    ) -> SignedRequest {
         let request_uri = format!(
             "/profilingGroups/{profiling_group_name}",
             profiling_group_name = input.profiling_group_name
@@ -938,30 +1163,62 @@ impl CodeGuruProfiler for CodeGuruProfilerClient {
 
         let mut request =
             SignedRequest::new("GET", "codeguru-profiler", &self.region, &request_uri);
+        request.set_operation("DescribeProfilingGroup");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
+        request
+    }
+
+    /// Calls `get_profile`, also returning the metadata of the response.
+    pub async fn get_profile_with_metadata(
+        &self,
+        input: GetProfileRequest,
+    ) -> Result<(GetProfileResponse, ResponseMetadata), RusotoError<GetProfileError>> {
+        let request = self.build_get_profile_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<DescribeProfilingGroupResponse, _>()?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
 
-            Ok(result)
+            let mut result = GetProfileResponse::default();
+            result.profile = response.body;
+
+            if let Some(content_encoding) = response.headers.get("Content-Encoding") {
+                let value = content_encoding.to_owned();
+                result.content_encoding = Some(value)
+            };
+            let value = response.headers.get("Content-Type").unwrap().to_owned();
+            result.content_type = value;
+
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeProfilingGroupError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(GetProfileError::from_response(response))
         }
     }
 
-    /// <p>Get the aggregated profile of a profiling group for the specified time range. If the requested time range does not align with the available aggregated profiles, it will be expanded to attain alignment. If aggregated profiles are available only for part of the period requested, the profile is returned from the earliest available to the latest within the requested time range. For instance, if the requested time range is from 00:00 to 00:20 and the available profiles are from 00:15 to 00:25, then the returned profile will be from 00:15 to 00:20.</p>
-    async fn get_profile(
+    /// Presigns the request `get_profile` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_profile(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetProfileRequest,
-    ) -> Result<GetProfileResponse, RusotoError<GetProfileError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_profile_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_profile_request(&self, input: GetProfileRequest) -> SignedRequest {
         let request_uri = format!(
             "/profilingGroups/{profiling_group_name}/profile",
             profiling_group_name = input.profiling_group_name
@@ -969,6 +1226,7 @@ impl CodeGuruProfiler for CodeGuruProfilerClient {
 
         let mut request =
             SignedRequest::new("GET", "codeguru-profiler", &self.region, &request_uri);
+        request.set_operation("GetProfile");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         if let Some(ref accept) = input.accept {
@@ -989,36 +1247,52 @@ impl CodeGuruProfiler for CodeGuruProfilerClient {
         }
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `list_profile_times`, also returning the metadata of the response.
+    pub async fn list_profile_times_with_metadata(
+        &self,
+        input: ListProfileTimesRequest,
+    ) -> Result<(ListProfileTimesResponse, ResponseMetadata), RusotoError<ListProfileTimesError>>
+    {
+        let request = self.build_list_profile_times_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-
-            let mut result = GetProfileResponse::default();
-            result.profile = response.body;
-
-            if let Some(content_encoding) = response.headers.get("Content-Encoding") {
-                let value = content_encoding.to_owned();
-                result.content_encoding = Some(value)
-            };
-            let value = response.headers.get("Content-Type").unwrap().to_owned();
-            result.content_type = value;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ListProfileTimesResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(GetProfileError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(ListProfileTimesError::from_response(response))
         }
     }
 
-    /// <p>List the start times of the available aggregated profiles of a profiling group for an aggregation period within the specified time range.</p>
-    async fn list_profile_times(
+    /// Presigns the request `list_profile_times` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_profile_times(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListProfileTimesRequest,
-    ) -> Result<ListProfileTimesResponse, RusotoError<ListProfileTimesError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_profile_times_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_profile_times_request(&self, input: ListProfileTimesRequest) -> SignedRequest {
         let request_uri = format!(
             "/profilingGroups/{profiling_group_name}/profileTimes",
             profiling_group_name = input.profiling_group_name
@@ -1026,6 +1300,7 @@ impl CodeGuruProfiler for CodeGuruProfilerClient {
 
         let mut request =
             SignedRequest::new("GET", "codeguru-profiler", &self.region, &request_uri);
+        request.set_operation("ListProfileTimes");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -1043,32 +1318,62 @@ impl CodeGuruProfiler for CodeGuruProfilerClient {
         params.put("startTime", &input.start_time);
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `list_profiling_groups`, also returning the metadata of the response.
+    pub async fn list_profiling_groups_with_metadata(
+        &self,
+        input: ListProfilingGroupsRequest,
+    ) -> Result<
+        (ListProfilingGroupsResponse, ResponseMetadata),
+        RusotoError<ListProfilingGroupsError>,
+    > {
+        let request = self.build_list_profiling_groups_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListProfileTimesResponse, _>()?;
+                .deserialize::<ListProfilingGroupsResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(ListProfileTimesError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(ListProfilingGroupsError::from_response(response))
         }
     }
 
-    /// <p>List profiling groups in the account.</p>
-    async fn list_profiling_groups(
+    /// Presigns the request `list_profiling_groups` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_profiling_groups(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListProfilingGroupsRequest,
-    ) -> Result<ListProfilingGroupsResponse, RusotoError<ListProfilingGroupsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_profiling_groups_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_profiling_groups_request(
+        &self,
+        input: ListProfilingGroupsRequest,
+    ) -> SignedRequest {
         let request_uri = "/profilingGroups";
 
         let mut request =
             SignedRequest::new("GET", "codeguru-profiler", &self.region, &request_uri);
+        request.set_operation("ListProfilingGroups");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -1083,28 +1388,52 @@ impl CodeGuruProfiler for CodeGuruProfilerClient {
         }
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `post_agent_profile`, also returning the metadata of the response.
+    pub async fn post_agent_profile_with_metadata(
+        &self,
+        input: PostAgentProfileRequest,
+    ) -> Result<(PostAgentProfileResponse, ResponseMetadata), RusotoError<PostAgentProfileError>>
+    {
+        let request = self.build_post_agent_profile_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
-        if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let metadata = ResponseMetadata::from_response(&response);
+        if response.status.as_u16() == 204 {
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListProfilingGroupsResponse, _>()?;
+                .deserialize::<PostAgentProfileResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(ListProfilingGroupsError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(PostAgentProfileError::from_response(response))
         }
     }
 
-    /// <p>Submit profile collected by an agent belonging to a profiling group for aggregation.</p>
-    async fn post_agent_profile(
+    /// Presigns the request `post_agent_profile` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_post_agent_profile(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: PostAgentProfileRequest,
-    ) -> Result<PostAgentProfileResponse, RusotoError<PostAgentProfileError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_post_agent_profile_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_post_agent_profile_request(&self, input: PostAgentProfileRequest) -> SignedRequest {
         let request_uri = format!(
             "/profilingGroups/{profiling_group_name}/agentProfile",
             profiling_group_name = input.profiling_group_name
@@ -1112,6 +1441,7 @@ impl CodeGuruProfiler for CodeGuruProfilerClient {
 
         let mut request =
             SignedRequest::new("POST", "codeguru-profiler", &self.region, &request_uri);
+        request.set_operation("PostAgentProfile");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(input.agent_profile.to_owned());
@@ -1123,28 +1453,57 @@ impl CodeGuruProfiler for CodeGuruProfilerClient {
         }
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `update_profiling_group`, also returning the metadata of the response.
+    pub async fn update_profiling_group_with_metadata(
+        &self,
+        input: UpdateProfilingGroupRequest,
+    ) -> Result<
+        (UpdateProfilingGroupResponse, ResponseMetadata),
+        RusotoError<UpdateProfilingGroupError>,
+    > {
+        let request = self.build_update_profiling_group_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
-        if response.status.as_u16() == 204 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let metadata = ResponseMetadata::from_response(&response);
+        if response.status.as_u16() == 200 {
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<PostAgentProfileResponse, _>()?;
+                .deserialize::<UpdateProfilingGroupResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(PostAgentProfileError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(UpdateProfilingGroupError::from_response(response))
         }
     }
 
-    /// <p>Update a profiling group.</p>
-    async fn update_profiling_group(
+    /// Presigns the request `update_profiling_group` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_profiling_group(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UpdateProfilingGroupRequest,
-    ) -> Result<UpdateProfilingGroupResponse, RusotoError<UpdateProfilingGroupError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_profiling_group_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_profiling_group_request(
+        &self,
+        input: UpdateProfilingGroupRequest,
+    ) -> SignedRequest {
         let request_uri = format!(
             "/profilingGroups/{profiling_group_name}",
             profiling_group_name = input.profiling_group_name
@@ -1152,25 +1511,144 @@ impl CodeGuruProfiler for CodeGuruProfilerClient {
 
         let mut request =
             SignedRequest::new("PUT", "codeguru-profiler", &self.region, &request_uri);
+        request.set_operation("UpdateProfilingGroup");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
-        let mut response = self
-            .client
-            .sign_and_dispatch(request)
+        request
+    }
+
+    /// The pages of `ListProfileTimes` results for `input`, requesting each next page with the `nextToken` of the last one as its `nextToken`.
+    /// The page size is the `maxResults` of `input`.
+    pub fn list_profile_times_pages(
+        &self,
+        input: ListProfileTimesRequest,
+    ) -> impl futures::Stream<
+        Item = Result<ListProfileTimesResponse, RusotoError<ListProfileTimesError>>,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: CodeGuruProfilerClient, input| async move {
+                client.list_profile_times(input).await
+            },
+            |input: &ListProfileTimesRequest, output: &ListProfileTimesResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListProfileTimesRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `profileTimes` of every page of `ListProfileTimes` results for `input`, as `list_profile_times_pages` requests them.
+    pub fn list_profile_times_stream(
+        &self,
+        input: ListProfileTimesRequest,
+    ) -> impl futures::Stream<Item = Result<ProfileTime, RusotoError<ListProfileTimesError>>> + Send
+    {
+        rusoto_core::pagination::items(self.list_profile_times_pages(input), |page| {
+            page.profile_times
+        })
+    }
+}
+
+#[async_trait]
+impl CodeGuruProfiler for CodeGuruProfilerClient {
+    /// <p>Provides the configuration to use for an agent of the profiling group.</p>
+    async fn configure_agent(
+        &self,
+        input: ConfigureAgentRequest,
+    ) -> Result<ConfigureAgentResponse, RusotoError<ConfigureAgentError>> {
+        self.configure_agent_with_metadata(input)
             .await
-            .map_err(RusotoError::from)?;
-        if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<UpdateProfilingGroupResponse, _>()?;
+            .map(|(output, _)| output)
+    }
 
-            Ok(result)
-        } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(UpdateProfilingGroupError::from_response(response))
-        }
+    /// <p>Create a profiling group.</p>
+    async fn create_profiling_group(
+        &self,
+        input: CreateProfilingGroupRequest,
+    ) -> Result<CreateProfilingGroupResponse, RusotoError<CreateProfilingGroupError>> {
+        self.create_profiling_group_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Delete a profiling group.</p>
+    async fn delete_profiling_group(
+        &self,
+        input: DeleteProfilingGroupRequest,
+    ) -> Result<DeleteProfilingGroupResponse, RusotoError<DeleteProfilingGroupError>> {
+        self.delete_profiling_group_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Describe a profiling group.</p>
+    async fn describe_profiling_group(
+        &self,
+        input: DescribeProfilingGroupRequest,
+    ) -> Result<DescribeProfilingGroupResponse, RusotoError<DescribeProfilingGroupError>> {
+        self.describe_profiling_group_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Get the aggregated profile of a profiling group for the specified time range. If the requested time range does not align with the available aggregated profiles, it will be expanded to attain alignment. If aggregated profiles are available only for part of the period requested, the profile is returned from the earliest available to the latest within the requested time range. For instance, if the requested time range is from 00:00 to 00:20 and the available profiles are from 00:15 to 00:25, then the returned profile will be from 00:15 to 00:20.</p>
+    async fn get_profile(
+        &self,
+        input: GetProfileRequest,
+    ) -> Result<GetProfileResponse, RusotoError<GetProfileError>> {
+        self.get_profile_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>List the start times of the available aggregated profiles of a profiling group for an aggregation period within the specified time range.</p>
+    async fn list_profile_times(
+        &self,
+        input: ListProfileTimesRequest,
+    ) -> Result<ListProfileTimesResponse, RusotoError<ListProfileTimesError>> {
+        self.list_profile_times_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>List profiling groups in the account.</p>
+    async fn list_profiling_groups(
+        &self,
+        input: ListProfilingGroupsRequest,
+    ) -> Result<ListProfilingGroupsResponse, RusotoError<ListProfilingGroupsError>> {
+        self.list_profiling_groups_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Submit profile collected by an agent belonging to a profiling group for aggregation.</p>
+    async fn post_agent_profile(
+        &self,
+        input: PostAgentProfileRequest,
+    ) -> Result<PostAgentProfileResponse, RusotoError<PostAgentProfileError>> {
+        self.post_agent_profile_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Update a profiling group.</p>
+    async fn update_profiling_group(
+        &self,
+        input: UpdateProfilingGroupRequest,
+    ) -> Result<UpdateProfilingGroupResponse, RusotoError<UpdateProfilingGroupError>> {
+        self.update_profiling_group_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
     }
 }
@@ -16,8 +16,8 @@ use std::fmt;
 use async_trait::async_trait;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
-use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest, ResponseMetadata};
+use rusoto_core::{Client, ErrorMetadata, RusotoError};
 
 use rusoto_core::proto;
 use rusoto_core::signature::SignedRequest;
@@ -2012,11 +2012,14 @@ pub struct WebhookFilterRule {
 
 /// Errors returned by AcknowledgeJob
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum AcknowledgeJobError {
     /// <p>The nonce was specified in an invalid format.</p>
     InvalidNonce(String),
     /// <p>The job was specified in an invalid format or cannot be found.</p>
     JobNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl AcknowledgeJobError {
@@ -2024,13 +2027,13 @@ impl AcknowledgeJobError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidNonceException" => {
-                    return RusotoError::Service(AcknowledgeJobError::InvalidNonce(err.msg))
+                    return RusotoError::service(AcknowledgeJobError::InvalidNonce(err.msg), &res)
                 }
                 "JobNotFoundException" => {
-                    return RusotoError::Service(AcknowledgeJobError::JobNotFound(err.msg))
+                    return RusotoError::service(AcknowledgeJobError::JobNotFound(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(AcknowledgeJobError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2042,12 +2045,14 @@ impl fmt::Display for AcknowledgeJobError {
         match *self {
             AcknowledgeJobError::InvalidNonce(ref cause) => write!(f, "{}", cause),
             AcknowledgeJobError::JobNotFound(ref cause) => write!(f, "{}", cause),
+            AcknowledgeJobError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for AcknowledgeJobError {}
 /// Errors returned by AcknowledgeThirdPartyJob
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum AcknowledgeThirdPartyJobError {
     /// <p>The client token was specified in an invalid format</p>
     InvalidClientToken(String),
@@ -2055,6 +2060,8 @@ pub enum AcknowledgeThirdPartyJobError {
     InvalidNonce(String),
     /// <p>The job was specified in an invalid format or cannot be found.</p>
     JobNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl AcknowledgeThirdPartyJobError {
@@ -2062,22 +2069,25 @@ impl AcknowledgeThirdPartyJobError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidClientTokenException" => {
-                    return RusotoError::Service(AcknowledgeThirdPartyJobError::InvalidClientToken(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        AcknowledgeThirdPartyJobError::InvalidClientToken(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidNonceException" => {
-                    return RusotoError::Service(AcknowledgeThirdPartyJobError::InvalidNonce(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        AcknowledgeThirdPartyJobError::InvalidNonce(err.msg),
+                        &res,
+                    )
                 }
                 "JobNotFoundException" => {
-                    return RusotoError::Service(AcknowledgeThirdPartyJobError::JobNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        AcknowledgeThirdPartyJobError::JobNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(AcknowledgeThirdPartyJobError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2090,12 +2100,14 @@ impl fmt::Display for AcknowledgeThirdPartyJobError {
             AcknowledgeThirdPartyJobError::InvalidClientToken(ref cause) => write!(f, "{}", cause),
             AcknowledgeThirdPartyJobError::InvalidNonce(ref cause) => write!(f, "{}", cause),
             AcknowledgeThirdPartyJobError::JobNotFound(ref cause) => write!(f, "{}", cause),
+            AcknowledgeThirdPartyJobError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for AcknowledgeThirdPartyJobError {}
 /// Errors returned by CreateCustomActionType
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateCustomActionTypeError {
     /// <p>Unable to modify the tag due to a simultaneous update request.</p>
     ConcurrentModification(String),
@@ -2105,6 +2117,8 @@ pub enum CreateCustomActionTypeError {
     LimitExceeded(String),
     /// <p>The tags limit for a resource has been exceeded.</p>
     TooManyTags(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateCustomActionTypeError {
@@ -2112,23 +2126,31 @@ impl CreateCustomActionTypeError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         CreateCustomActionTypeError::ConcurrentModification(err.msg),
+                        &res,
                     )
                 }
                 "InvalidTagsException" => {
-                    return RusotoError::Service(CreateCustomActionTypeError::InvalidTags(err.msg))
+                    return RusotoError::service(
+                        CreateCustomActionTypeError::InvalidTags(err.msg),
+                        &res,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateCustomActionTypeError::LimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateCustomActionTypeError::LimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "TooManyTagsException" => {
-                    return RusotoError::Service(CreateCustomActionTypeError::TooManyTags(err.msg))
+                    return RusotoError::service(
+                        CreateCustomActionTypeError::TooManyTags(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreateCustomActionTypeError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2144,12 +2166,14 @@ impl fmt::Display for CreateCustomActionTypeError {
             CreateCustomActionTypeError::InvalidTags(ref cause) => write!(f, "{}", cause),
             CreateCustomActionTypeError::LimitExceeded(ref cause) => write!(f, "{}", cause),
             CreateCustomActionTypeError::TooManyTags(ref cause) => write!(f, "{}", cause),
+            CreateCustomActionTypeError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateCustomActionTypeError {}
 /// Errors returned by CreatePipeline
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreatePipelineError {
     /// <p>Unable to modify the tag due to a simultaneous update request.</p>
     ConcurrentModification(String),
@@ -2169,6 +2193,8 @@ pub enum CreatePipelineError {
     PipelineNameInUse(String),
     /// <p>The tags limit for a resource has been exceeded.</p>
     TooManyTags(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreatePipelineError {
@@ -2176,42 +2202,52 @@ impl CreatePipelineError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(CreatePipelineError::ConcurrentModification(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreatePipelineError::ConcurrentModification(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidActionDeclarationException" => {
-                    return RusotoError::Service(CreatePipelineError::InvalidActionDeclaration(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreatePipelineError::InvalidActionDeclaration(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidBlockerDeclarationException" => {
-                    return RusotoError::Service(CreatePipelineError::InvalidBlockerDeclaration(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreatePipelineError::InvalidBlockerDeclaration(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidStageDeclarationException" => {
-                    return RusotoError::Service(CreatePipelineError::InvalidStageDeclaration(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreatePipelineError::InvalidStageDeclaration(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidStructureException" => {
-                    return RusotoError::Service(CreatePipelineError::InvalidStructure(err.msg))
+                    return RusotoError::service(
+                        CreatePipelineError::InvalidStructure(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidTagsException" => {
-                    return RusotoError::Service(CreatePipelineError::InvalidTags(err.msg))
+                    return RusotoError::service(CreatePipelineError::InvalidTags(err.msg), &res)
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreatePipelineError::LimitExceeded(err.msg))
+                    return RusotoError::service(CreatePipelineError::LimitExceeded(err.msg), &res)
                 }
                 "PipelineNameInUseException" => {
-                    return RusotoError::Service(CreatePipelineError::PipelineNameInUse(err.msg))
+                    return RusotoError::service(
+                        CreatePipelineError::PipelineNameInUse(err.msg),
+                        &res,
+                    )
                 }
                 "TooManyTagsException" => {
-                    return RusotoError::Service(CreatePipelineError::TooManyTags(err.msg))
+                    return RusotoError::service(CreatePipelineError::TooManyTags(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreatePipelineError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2230,15 +2266,19 @@ impl fmt::Display for CreatePipelineError {
             CreatePipelineError::LimitExceeded(ref cause) => write!(f, "{}", cause),
             CreatePipelineError::PipelineNameInUse(ref cause) => write!(f, "{}", cause),
             CreatePipelineError::TooManyTags(ref cause) => write!(f, "{}", cause),
+            CreatePipelineError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreatePipelineError {}
 /// Errors returned by DeleteCustomActionType
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteCustomActionTypeError {
     /// <p>Unable to modify the tag due to a simultaneous update request.</p>
     ConcurrentModification(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteCustomActionTypeError {
@@ -2246,12 +2286,13 @@ impl DeleteCustomActionTypeError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DeleteCustomActionTypeError::ConcurrentModification(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteCustomActionTypeError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2264,15 +2305,19 @@ impl fmt::Display for DeleteCustomActionTypeError {
             DeleteCustomActionTypeError::ConcurrentModification(ref cause) => {
                 write!(f, "{}", cause)
             }
+            DeleteCustomActionTypeError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteCustomActionTypeError {}
 /// Errors returned by DeletePipeline
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeletePipelineError {
     /// <p>Unable to modify the tag due to a simultaneous update request.</p>
     ConcurrentModification(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeletePipelineError {
@@ -2280,12 +2325,13 @@ impl DeletePipelineError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(DeletePipelineError::ConcurrentModification(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeletePipelineError::ConcurrentModification(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeletePipelineError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2296,15 +2342,19 @@ impl fmt::Display for DeletePipelineError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             DeletePipelineError::ConcurrentModification(ref cause) => write!(f, "{}", cause),
+            DeletePipelineError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeletePipelineError {}
 /// Errors returned by DeleteWebhook
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteWebhookError {
     /// <p>Unable to modify the tag due to a simultaneous update request.</p>
     ConcurrentModification(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteWebhookError {
@@ -2312,12 +2362,13 @@ impl DeleteWebhookError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(DeleteWebhookError::ConcurrentModification(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteWebhookError::ConcurrentModification(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteWebhookError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2328,15 +2379,19 @@ impl fmt::Display for DeleteWebhookError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             DeleteWebhookError::ConcurrentModification(ref cause) => write!(f, "{}", cause),
+            DeleteWebhookError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteWebhookError {}
 /// Errors returned by DeregisterWebhookWithThirdParty
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeregisterWebhookWithThirdPartyError {
     /// <p>The specified webhook was entered in an invalid format or cannot be found.</p>
     WebhookNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeregisterWebhookWithThirdPartyError {
@@ -2346,12 +2401,18 @@ impl DeregisterWebhookWithThirdPartyError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "WebhookNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DeregisterWebhookWithThirdPartyError::WebhookNotFound(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        DeregisterWebhookWithThirdPartyError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -2364,17 +2425,23 @@ impl fmt::Display for DeregisterWebhookWithThirdPartyError {
             DeregisterWebhookWithThirdPartyError::WebhookNotFound(ref cause) => {
                 write!(f, "{}", cause)
             }
+            DeregisterWebhookWithThirdPartyError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for DeregisterWebhookWithThirdPartyError {}
 /// Errors returned by DisableStageTransition
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DisableStageTransitionError {
     /// <p>The pipeline was specified in an invalid format or cannot be found.</p>
     PipelineNotFound(String),
     /// <p>The stage was specified in an invalid format or cannot be found.</p>
     StageNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DisableStageTransitionError {
@@ -2382,17 +2449,19 @@ impl DisableStageTransitionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "PipelineNotFoundException" => {
-                    return RusotoError::Service(DisableStageTransitionError::PipelineNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DisableStageTransitionError::PipelineNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "StageNotFoundException" => {
-                    return RusotoError::Service(DisableStageTransitionError::StageNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DisableStageTransitionError::StageNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DisableStageTransitionError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2404,17 +2473,21 @@ impl fmt::Display for DisableStageTransitionError {
         match *self {
             DisableStageTransitionError::PipelineNotFound(ref cause) => write!(f, "{}", cause),
             DisableStageTransitionError::StageNotFound(ref cause) => write!(f, "{}", cause),
+            DisableStageTransitionError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DisableStageTransitionError {}
 /// Errors returned by EnableStageTransition
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum EnableStageTransitionError {
     /// <p>The pipeline was specified in an invalid format or cannot be found.</p>
     PipelineNotFound(String),
     /// <p>The stage was specified in an invalid format or cannot be found.</p>
     StageNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl EnableStageTransitionError {
@@ -2422,15 +2495,19 @@ impl EnableStageTransitionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "PipelineNotFoundException" => {
-                    return RusotoError::Service(EnableStageTransitionError::PipelineNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        EnableStageTransitionError::PipelineNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "StageNotFoundException" => {
-                    return RusotoError::Service(EnableStageTransitionError::StageNotFound(err.msg))
+                    return RusotoError::service(
+                        EnableStageTransitionError::StageNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(EnableStageTransitionError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2442,15 +2519,19 @@ impl fmt::Display for EnableStageTransitionError {
         match *self {
             EnableStageTransitionError::PipelineNotFound(ref cause) => write!(f, "{}", cause),
             EnableStageTransitionError::StageNotFound(ref cause) => write!(f, "{}", cause),
+            EnableStageTransitionError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for EnableStageTransitionError {}
 /// Errors returned by GetJobDetails
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetJobDetailsError {
     /// <p>The job was specified in an invalid format or cannot be found.</p>
     JobNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetJobDetailsError {
@@ -2458,10 +2539,10 @@ impl GetJobDetailsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "JobNotFoundException" => {
-                    return RusotoError::Service(GetJobDetailsError::JobNotFound(err.msg))
+                    return RusotoError::service(GetJobDetailsError::JobNotFound(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetJobDetailsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2472,17 +2553,21 @@ impl fmt::Display for GetJobDetailsError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             GetJobDetailsError::JobNotFound(ref cause) => write!(f, "{}", cause),
+            GetJobDetailsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetJobDetailsError {}
 /// Errors returned by GetPipeline
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetPipelineError {
     /// <p>The pipeline was specified in an invalid format or cannot be found.</p>
     PipelineNotFound(String),
     /// <p>The pipeline version was specified in an invalid format or cannot be found.</p>
     PipelineVersionNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetPipelineError {
@@ -2490,13 +2575,16 @@ impl GetPipelineError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "PipelineNotFoundException" => {
-                    return RusotoError::Service(GetPipelineError::PipelineNotFound(err.msg))
+                    return RusotoError::service(GetPipelineError::PipelineNotFound(err.msg), &res)
                 }
                 "PipelineVersionNotFoundException" => {
-                    return RusotoError::Service(GetPipelineError::PipelineVersionNotFound(err.msg))
+                    return RusotoError::service(
+                        GetPipelineError::PipelineVersionNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetPipelineError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2508,17 +2596,21 @@ impl fmt::Display for GetPipelineError {
         match *self {
             GetPipelineError::PipelineNotFound(ref cause) => write!(f, "{}", cause),
             GetPipelineError::PipelineVersionNotFound(ref cause) => write!(f, "{}", cause),
+            GetPipelineError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetPipelineError {}
 /// Errors returned by GetPipelineExecution
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetPipelineExecutionError {
     /// <p>The pipeline execution was specified in an invalid format or cannot be found, or an execution ID does not belong to the specified pipeline. </p>
     PipelineExecutionNotFound(String),
     /// <p>The pipeline was specified in an invalid format or cannot be found.</p>
     PipelineNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetPipelineExecutionError {
@@ -2526,17 +2618,19 @@ impl GetPipelineExecutionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "PipelineExecutionNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         GetPipelineExecutionError::PipelineExecutionNotFound(err.msg),
+                        &res,
                     )
                 }
                 "PipelineNotFoundException" => {
-                    return RusotoError::Service(GetPipelineExecutionError::PipelineNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetPipelineExecutionError::PipelineNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetPipelineExecutionError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2550,15 +2644,19 @@ impl fmt::Display for GetPipelineExecutionError {
                 write!(f, "{}", cause)
             }
             GetPipelineExecutionError::PipelineNotFound(ref cause) => write!(f, "{}", cause),
+            GetPipelineExecutionError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetPipelineExecutionError {}
 /// Errors returned by GetPipelineState
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetPipelineStateError {
     /// <p>The pipeline was specified in an invalid format or cannot be found.</p>
     PipelineNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetPipelineStateError {
@@ -2566,10 +2664,13 @@ impl GetPipelineStateError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "PipelineNotFoundException" => {
-                    return RusotoError::Service(GetPipelineStateError::PipelineNotFound(err.msg))
+                    return RusotoError::service(
+                        GetPipelineStateError::PipelineNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetPipelineStateError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2580,12 +2681,14 @@ impl fmt::Display for GetPipelineStateError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             GetPipelineStateError::PipelineNotFound(ref cause) => write!(f, "{}", cause),
+            GetPipelineStateError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetPipelineStateError {}
 /// Errors returned by GetThirdPartyJobDetails
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetThirdPartyJobDetailsError {
     /// <p>The client token was specified in an invalid format</p>
     InvalidClientToken(String),
@@ -2593,6 +2696,8 @@ pub enum GetThirdPartyJobDetailsError {
     InvalidJob(String),
     /// <p>The job was specified in an invalid format or cannot be found.</p>
     JobNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetThirdPartyJobDetailsError {
@@ -2600,18 +2705,25 @@ impl GetThirdPartyJobDetailsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidClientTokenException" => {
-                    return RusotoError::Service(GetThirdPartyJobDetailsError::InvalidClientToken(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetThirdPartyJobDetailsError::InvalidClientToken(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidJobException" => {
-                    return RusotoError::Service(GetThirdPartyJobDetailsError::InvalidJob(err.msg))
+                    return RusotoError::service(
+                        GetThirdPartyJobDetailsError::InvalidJob(err.msg),
+                        &res,
+                    )
                 }
                 "JobNotFoundException" => {
-                    return RusotoError::Service(GetThirdPartyJobDetailsError::JobNotFound(err.msg))
+                    return RusotoError::service(
+                        GetThirdPartyJobDetailsError::JobNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetThirdPartyJobDetailsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2624,12 +2736,14 @@ impl fmt::Display for GetThirdPartyJobDetailsError {
             GetThirdPartyJobDetailsError::InvalidClientToken(ref cause) => write!(f, "{}", cause),
             GetThirdPartyJobDetailsError::InvalidJob(ref cause) => write!(f, "{}", cause),
             GetThirdPartyJobDetailsError::JobNotFound(ref cause) => write!(f, "{}", cause),
+            GetThirdPartyJobDetailsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetThirdPartyJobDetailsError {}
 /// Errors returned by ListActionExecutions
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListActionExecutionsError {
     /// <p>The next token was specified in an invalid format. Make sure that the next token you provide is the token returned by a previous call.</p>
     InvalidNextToken(String),
@@ -2637,6 +2751,8 @@ pub enum ListActionExecutionsError {
     PipelineExecutionNotFound(String),
     /// <p>The pipeline was specified in an invalid format or cannot be found.</p>
     PipelineNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListActionExecutionsError {
@@ -2644,22 +2760,25 @@ impl ListActionExecutionsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(ListActionExecutionsError::InvalidNextToken(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListActionExecutionsError::InvalidNextToken(err.msg),
+                        &res,
+                    )
                 }
                 "PipelineExecutionNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         ListActionExecutionsError::PipelineExecutionNotFound(err.msg),
+                        &res,
                     )
                 }
                 "PipelineNotFoundException" => {
-                    return RusotoError::Service(ListActionExecutionsError::PipelineNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListActionExecutionsError::PipelineNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListActionExecutionsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2674,15 +2793,19 @@ impl fmt::Display for ListActionExecutionsError {
                 write!(f, "{}", cause)
             }
             ListActionExecutionsError::PipelineNotFound(ref cause) => write!(f, "{}", cause),
+            ListActionExecutionsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListActionExecutionsError {}
 /// Errors returned by ListActionTypes
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListActionTypesError {
     /// <p>The next token was specified in an invalid format. Make sure that the next token you provide is the token returned by a previous call.</p>
     InvalidNextToken(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListActionTypesError {
@@ -2690,10 +2813,13 @@ impl ListActionTypesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(ListActionTypesError::InvalidNextToken(err.msg))
+                    return RusotoError::service(
+                        ListActionTypesError::InvalidNextToken(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListActionTypesError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2704,17 +2830,21 @@ impl fmt::Display for ListActionTypesError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             ListActionTypesError::InvalidNextToken(ref cause) => write!(f, "{}", cause),
+            ListActionTypesError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListActionTypesError {}
 /// Errors returned by ListPipelineExecutions
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListPipelineExecutionsError {
     /// <p>The next token was specified in an invalid format. Make sure that the next token you provide is the token returned by a previous call.</p>
     InvalidNextToken(String),
     /// <p>The pipeline was specified in an invalid format or cannot be found.</p>
     PipelineNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListPipelineExecutionsError {
@@ -2722,17 +2852,19 @@ impl ListPipelineExecutionsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(ListPipelineExecutionsError::InvalidNextToken(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListPipelineExecutionsError::InvalidNextToken(err.msg),
+                        &res,
+                    )
                 }
                 "PipelineNotFoundException" => {
-                    return RusotoError::Service(ListPipelineExecutionsError::PipelineNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListPipelineExecutionsError::PipelineNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListPipelineExecutionsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2744,15 +2876,19 @@ impl fmt::Display for ListPipelineExecutionsError {
         match *self {
             ListPipelineExecutionsError::InvalidNextToken(ref cause) => write!(f, "{}", cause),
             ListPipelineExecutionsError::PipelineNotFound(ref cause) => write!(f, "{}", cause),
+            ListPipelineExecutionsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListPipelineExecutionsError {}
 /// Errors returned by ListPipelines
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListPipelinesError {
     /// <p>The next token was specified in an invalid format. Make sure that the next token you provide is the token returned by a previous call.</p>
     InvalidNextToken(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListPipelinesError {
@@ -2760,10 +2896,13 @@ impl ListPipelinesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(ListPipelinesError::InvalidNextToken(err.msg))
+                    return RusotoError::service(
+                        ListPipelinesError::InvalidNextToken(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListPipelinesError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2774,12 +2913,14 @@ impl fmt::Display for ListPipelinesError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             ListPipelinesError::InvalidNextToken(ref cause) => write!(f, "{}", cause),
+            ListPipelinesError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListPipelinesError {}
 /// Errors returned by ListTagsForResource
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListTagsForResourceError {
     /// <p>The specified resource ARN is invalid.</p>
     InvalidArn(String),
@@ -2787,6 +2928,8 @@ pub enum ListTagsForResourceError {
     InvalidNextToken(String),
     /// <p>The resource was specified in an invalid format.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListTagsForResourceError {
@@ -2794,20 +2937,25 @@ impl ListTagsForResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidArnException" => {
-                    return RusotoError::Service(ListTagsForResourceError::InvalidArn(err.msg))
+                    return RusotoError::service(
+                        ListTagsForResourceError::InvalidArn(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(ListTagsForResourceError::InvalidNextToken(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListTagsForResourceError::InvalidNextToken(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListTagsForResourceError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListTagsForResourceError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListTagsForResourceError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2820,15 +2968,19 @@ impl fmt::Display for ListTagsForResourceError {
             ListTagsForResourceError::InvalidArn(ref cause) => write!(f, "{}", cause),
             ListTagsForResourceError::InvalidNextToken(ref cause) => write!(f, "{}", cause),
             ListTagsForResourceError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            ListTagsForResourceError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListTagsForResourceError {}
 /// Errors returned by ListWebhooks
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListWebhooksError {
     /// <p>The next token was specified in an invalid format. Make sure that the next token you provide is the token returned by a previous call.</p>
     InvalidNextToken(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListWebhooksError {
@@ -2836,10 +2988,10 @@ impl ListWebhooksError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(ListWebhooksError::InvalidNextToken(err.msg))
+                    return RusotoError::service(ListWebhooksError::InvalidNextToken(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListWebhooksError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2850,15 +3002,19 @@ impl fmt::Display for ListWebhooksError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             ListWebhooksError::InvalidNextToken(ref cause) => write!(f, "{}", cause),
+            ListWebhooksError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListWebhooksError {}
 /// Errors returned by PollForJobs
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum PollForJobsError {
     /// <p>The specified action type cannot be found.</p>
     ActionTypeNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl PollForJobsError {
@@ -2866,10 +3022,13 @@ impl PollForJobsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ActionTypeNotFoundException" => {
-                    return RusotoError::Service(PollForJobsError::ActionTypeNotFound(err.msg))
+                    return RusotoError::service(
+                        PollForJobsError::ActionTypeNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(PollForJobsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2880,15 +3039,19 @@ impl fmt::Display for PollForJobsError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             PollForJobsError::ActionTypeNotFound(ref cause) => write!(f, "{}", cause),
+            PollForJobsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for PollForJobsError {}
 /// Errors returned by PollForThirdPartyJobs
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum PollForThirdPartyJobsError {
     /// <p>The specified action type cannot be found.</p>
     ActionTypeNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl PollForThirdPartyJobsError {
@@ -2896,12 +3059,13 @@ impl PollForThirdPartyJobsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ActionTypeNotFoundException" => {
-                    return RusotoError::Service(PollForThirdPartyJobsError::ActionTypeNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        PollForThirdPartyJobsError::ActionTypeNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(PollForThirdPartyJobsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2912,12 +3076,14 @@ impl fmt::Display for PollForThirdPartyJobsError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             PollForThirdPartyJobsError::ActionTypeNotFound(ref cause) => write!(f, "{}", cause),
+            PollForThirdPartyJobsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for PollForThirdPartyJobsError {}
 /// Errors returned by PutActionRevision
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum PutActionRevisionError {
     /// <p>The specified action cannot be found.</p>
     ActionNotFound(String),
@@ -2925,6 +3091,8 @@ pub enum PutActionRevisionError {
     PipelineNotFound(String),
     /// <p>The stage was specified in an invalid format or cannot be found.</p>
     StageNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl PutActionRevisionError {
@@ -2932,16 +3100,25 @@ impl PutActionRevisionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ActionNotFoundException" => {
-                    return RusotoError::Service(PutActionRevisionError::ActionNotFound(err.msg))
+                    return RusotoError::service(
+                        PutActionRevisionError::ActionNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "PipelineNotFoundException" => {
-                    return RusotoError::Service(PutActionRevisionError::PipelineNotFound(err.msg))
+                    return RusotoError::service(
+                        PutActionRevisionError::PipelineNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "StageNotFoundException" => {
-                    return RusotoError::Service(PutActionRevisionError::StageNotFound(err.msg))
+                    return RusotoError::service(
+                        PutActionRevisionError::StageNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(PutActionRevisionError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2954,12 +3131,14 @@ impl fmt::Display for PutActionRevisionError {
             PutActionRevisionError::ActionNotFound(ref cause) => write!(f, "{}", cause),
             PutActionRevisionError::PipelineNotFound(ref cause) => write!(f, "{}", cause),
             PutActionRevisionError::StageNotFound(ref cause) => write!(f, "{}", cause),
+            PutActionRevisionError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for PutActionRevisionError {}
 /// Errors returned by PutApprovalResult
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum PutApprovalResultError {
     /// <p>The specified action cannot be found.</p>
     ActionNotFound(String),
@@ -2971,6 +3150,8 @@ pub enum PutApprovalResultError {
     PipelineNotFound(String),
     /// <p>The stage was specified in an invalid format or cannot be found.</p>
     StageNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl PutApprovalResultError {
@@ -2978,26 +3159,37 @@ impl PutApprovalResultError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ActionNotFoundException" => {
-                    return RusotoError::Service(PutApprovalResultError::ActionNotFound(err.msg))
+                    return RusotoError::service(
+                        PutApprovalResultError::ActionNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ApprovalAlreadyCompletedException" => {
-                    return RusotoError::Service(PutApprovalResultError::ApprovalAlreadyCompleted(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        PutApprovalResultError::ApprovalAlreadyCompleted(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidApprovalTokenException" => {
-                    return RusotoError::Service(PutApprovalResultError::InvalidApprovalToken(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        PutApprovalResultError::InvalidApprovalToken(err.msg),
+                        &res,
+                    )
                 }
                 "PipelineNotFoundException" => {
-                    return RusotoError::Service(PutApprovalResultError::PipelineNotFound(err.msg))
+                    return RusotoError::service(
+                        PutApprovalResultError::PipelineNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "StageNotFoundException" => {
-                    return RusotoError::Service(PutApprovalResultError::StageNotFound(err.msg))
+                    return RusotoError::service(
+                        PutApprovalResultError::StageNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(PutApprovalResultError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3012,17 +3204,21 @@ impl fmt::Display for PutApprovalResultError {
             PutApprovalResultError::InvalidApprovalToken(ref cause) => write!(f, "{}", cause),
             PutApprovalResultError::PipelineNotFound(ref cause) => write!(f, "{}", cause),
             PutApprovalResultError::StageNotFound(ref cause) => write!(f, "{}", cause),
+            PutApprovalResultError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for PutApprovalResultError {}
 /// Errors returned by PutJobFailureResult
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum PutJobFailureResultError {
     /// <p>The job state was specified in an invalid format.</p>
     InvalidJobState(String),
     /// <p>The job was specified in an invalid format or cannot be found.</p>
     JobNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl PutJobFailureResultError {
@@ -3030,13 +3226,19 @@ impl PutJobFailureResultError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidJobStateException" => {
-                    return RusotoError::Service(PutJobFailureResultError::InvalidJobState(err.msg))
+                    return RusotoError::service(
+                        PutJobFailureResultError::InvalidJobState(err.msg),
+                        &res,
+                    )
                 }
                 "JobNotFoundException" => {
-                    return RusotoError::Service(PutJobFailureResultError::JobNotFound(err.msg))
+                    return RusotoError::service(
+                        PutJobFailureResultError::JobNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(PutJobFailureResultError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3048,12 +3250,14 @@ impl fmt::Display for PutJobFailureResultError {
         match *self {
             PutJobFailureResultError::InvalidJobState(ref cause) => write!(f, "{}", cause),
             PutJobFailureResultError::JobNotFound(ref cause) => write!(f, "{}", cause),
+            PutJobFailureResultError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for PutJobFailureResultError {}
 /// Errors returned by PutJobSuccessResult
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum PutJobSuccessResultError {
     /// <p>The job state was specified in an invalid format.</p>
     InvalidJobState(String),
@@ -3061,6 +3265,8 @@ pub enum PutJobSuccessResultError {
     JobNotFound(String),
     /// <p>Exceeded the total size limit for all variables in the pipeline.</p>
     OutputVariablesSizeExceeded(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl PutJobSuccessResultError {
@@ -3068,18 +3274,25 @@ impl PutJobSuccessResultError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidJobStateException" => {
-                    return RusotoError::Service(PutJobSuccessResultError::InvalidJobState(err.msg))
+                    return RusotoError::service(
+                        PutJobSuccessResultError::InvalidJobState(err.msg),
+                        &res,
+                    )
                 }
                 "JobNotFoundException" => {
-                    return RusotoError::Service(PutJobSuccessResultError::JobNotFound(err.msg))
+                    return RusotoError::service(
+                        PutJobSuccessResultError::JobNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "OutputVariablesSizeExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         PutJobSuccessResultError::OutputVariablesSizeExceeded(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(PutJobSuccessResultError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3094,12 +3307,14 @@ impl fmt::Display for PutJobSuccessResultError {
             PutJobSuccessResultError::OutputVariablesSizeExceeded(ref cause) => {
                 write!(f, "{}", cause)
             }
+            PutJobSuccessResultError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for PutJobSuccessResultError {}
 /// Errors returned by PutThirdPartyJobFailureResult
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum PutThirdPartyJobFailureResultError {
     /// <p>The client token was specified in an invalid format</p>
     InvalidClientToken(String),
@@ -3107,6 +3322,8 @@ pub enum PutThirdPartyJobFailureResultError {
     InvalidJobState(String),
     /// <p>The job was specified in an invalid format or cannot be found.</p>
     JobNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl PutThirdPartyJobFailureResultError {
@@ -3116,22 +3333,30 @@ impl PutThirdPartyJobFailureResultError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidClientTokenException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         PutThirdPartyJobFailureResultError::InvalidClientToken(err.msg),
+                        &res,
                     )
                 }
                 "InvalidJobStateException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         PutThirdPartyJobFailureResultError::InvalidJobState(err.msg),
+                        &res,
                     )
                 }
                 "JobNotFoundException" => {
-                    return RusotoError::Service(PutThirdPartyJobFailureResultError::JobNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        PutThirdPartyJobFailureResultError::JobNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        PutThirdPartyJobFailureResultError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -3148,12 +3373,16 @@ impl fmt::Display for PutThirdPartyJobFailureResultError {
                 write!(f, "{}", cause)
             }
             PutThirdPartyJobFailureResultError::JobNotFound(ref cause) => write!(f, "{}", cause),
+            PutThirdPartyJobFailureResultError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for PutThirdPartyJobFailureResultError {}
 /// Errors returned by PutThirdPartyJobSuccessResult
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum PutThirdPartyJobSuccessResultError {
     /// <p>The client token was specified in an invalid format</p>
     InvalidClientToken(String),
@@ -3161,6 +3390,8 @@ pub enum PutThirdPartyJobSuccessResultError {
     InvalidJobState(String),
     /// <p>The job was specified in an invalid format or cannot be found.</p>
     JobNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl PutThirdPartyJobSuccessResultError {
@@ -3170,22 +3401,30 @@ impl PutThirdPartyJobSuccessResultError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidClientTokenException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         PutThirdPartyJobSuccessResultError::InvalidClientToken(err.msg),
+                        &res,
                     )
                 }
                 "InvalidJobStateException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         PutThirdPartyJobSuccessResultError::InvalidJobState(err.msg),
+                        &res,
                     )
                 }
                 "JobNotFoundException" => {
-                    return RusotoError::Service(PutThirdPartyJobSuccessResultError::JobNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        PutThirdPartyJobSuccessResultError::JobNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        PutThirdPartyJobSuccessResultError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -3202,12 +3441,16 @@ impl fmt::Display for PutThirdPartyJobSuccessResultError {
                 write!(f, "{}", cause)
             }
             PutThirdPartyJobSuccessResultError::JobNotFound(ref cause) => write!(f, "{}", cause),
+            PutThirdPartyJobSuccessResultError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for PutThirdPartyJobSuccessResultError {}
 /// Errors returned by PutWebhook
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum PutWebhookError {
     /// <p>Unable to modify the tag due to a simultaneous update request.</p>
     ConcurrentModification(String),
@@ -3223,6 +3466,8 @@ pub enum PutWebhookError {
     PipelineNotFound(String),
     /// <p>The tags limit for a resource has been exceeded.</p>
     TooManyTags(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl PutWebhookError {
@@ -3230,32 +3475,37 @@ impl PutWebhookError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(PutWebhookError::ConcurrentModification(err.msg))
+                    return RusotoError::service(
+                        PutWebhookError::ConcurrentModification(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidTagsException" => {
-                    return RusotoError::Service(PutWebhookError::InvalidTags(err.msg))
+                    return RusotoError::service(PutWebhookError::InvalidTags(err.msg), &res)
                 }
                 "InvalidWebhookAuthenticationParametersException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         PutWebhookError::InvalidWebhookAuthenticationParameters(err.msg),
+                        &res,
                     )
                 }
                 "InvalidWebhookFilterPatternException" => {
-                    return RusotoError::Service(PutWebhookError::InvalidWebhookFilterPattern(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        PutWebhookError::InvalidWebhookFilterPattern(err.msg),
+                        &res,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(PutWebhookError::LimitExceeded(err.msg))
+                    return RusotoError::service(PutWebhookError::LimitExceeded(err.msg), &res)
                 }
                 "PipelineNotFoundException" => {
-                    return RusotoError::Service(PutWebhookError::PipelineNotFound(err.msg))
+                    return RusotoError::service(PutWebhookError::PipelineNotFound(err.msg), &res)
                 }
                 "TooManyTagsException" => {
-                    return RusotoError::Service(PutWebhookError::TooManyTags(err.msg))
+                    return RusotoError::service(PutWebhookError::TooManyTags(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(PutWebhookError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3274,15 +3524,19 @@ impl fmt::Display for PutWebhookError {
             PutWebhookError::LimitExceeded(ref cause) => write!(f, "{}", cause),
             PutWebhookError::PipelineNotFound(ref cause) => write!(f, "{}", cause),
             PutWebhookError::TooManyTags(ref cause) => write!(f, "{}", cause),
+            PutWebhookError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for PutWebhookError {}
 /// Errors returned by RegisterWebhookWithThirdParty
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum RegisterWebhookWithThirdPartyError {
     /// <p>The specified webhook was entered in an invalid format or cannot be found.</p>
     WebhookNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl RegisterWebhookWithThirdPartyError {
@@ -3292,12 +3546,18 @@ impl RegisterWebhookWithThirdPartyError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "WebhookNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         RegisterWebhookWithThirdPartyError::WebhookNotFound(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        RegisterWebhookWithThirdPartyError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -3310,12 +3570,16 @@ impl fmt::Display for RegisterWebhookWithThirdPartyError {
             RegisterWebhookWithThirdPartyError::WebhookNotFound(ref cause) => {
                 write!(f, "{}", cause)
             }
+            RegisterWebhookWithThirdPartyError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for RegisterWebhookWithThirdPartyError {}
 /// Errors returned by RetryStageExecution
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum RetryStageExecutionError {
     /// <p>The stage has failed in a later run of the pipeline and the pipelineExecutionId associated with the request is out of date.</p>
     NotLatestPipelineExecution(String),
@@ -3325,6 +3589,8 @@ pub enum RetryStageExecutionError {
     StageNotFound(String),
     /// <p>Unable to retry. The pipeline structure or stage state might have changed while actions awaited retry, or the stage contains no failed actions.</p>
     StageNotRetryable(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl RetryStageExecutionError {
@@ -3332,25 +3598,31 @@ impl RetryStageExecutionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "NotLatestPipelineExecutionException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         RetryStageExecutionError::NotLatestPipelineExecution(err.msg),
+                        &res,
                     )
                 }
                 "PipelineNotFoundException" => {
-                    return RusotoError::Service(RetryStageExecutionError::PipelineNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        RetryStageExecutionError::PipelineNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "StageNotFoundException" => {
-                    return RusotoError::Service(RetryStageExecutionError::StageNotFound(err.msg))
+                    return RusotoError::service(
+                        RetryStageExecutionError::StageNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "StageNotRetryableException" => {
-                    return RusotoError::Service(RetryStageExecutionError::StageNotRetryable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        RetryStageExecutionError::StageNotRetryable(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(RetryStageExecutionError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3366,15 +3638,19 @@ impl fmt::Display for RetryStageExecutionError {
             RetryStageExecutionError::PipelineNotFound(ref cause) => write!(f, "{}", cause),
             RetryStageExecutionError::StageNotFound(ref cause) => write!(f, "{}", cause),
             RetryStageExecutionError::StageNotRetryable(ref cause) => write!(f, "{}", cause),
+            RetryStageExecutionError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for RetryStageExecutionError {}
 /// Errors returned by StartPipelineExecution
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum StartPipelineExecutionError {
     /// <p>The pipeline was specified in an invalid format or cannot be found.</p>
     PipelineNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl StartPipelineExecutionError {
@@ -3382,12 +3658,13 @@ impl StartPipelineExecutionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "PipelineNotFoundException" => {
-                    return RusotoError::Service(StartPipelineExecutionError::PipelineNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        StartPipelineExecutionError::PipelineNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(StartPipelineExecutionError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3398,12 +3675,14 @@ impl fmt::Display for StartPipelineExecutionError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             StartPipelineExecutionError::PipelineNotFound(ref cause) => write!(f, "{}", cause),
+            StartPipelineExecutionError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for StartPipelineExecutionError {}
 /// Errors returned by StopPipelineExecution
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum StopPipelineExecutionError {
     /// <p>The pipeline execution is already in a <code>Stopping</code> state. If you already chose to stop and wait, you cannot make that request again. You can choose to stop and abandon now, but be aware that this option can lead to failed tasks or out of sequence tasks. If you already chose to stop and abandon, you cannot make that request again.</p>
     DuplicatedStopRequest(String),
@@ -3411,6 +3690,8 @@ pub enum StopPipelineExecutionError {
     PipelineExecutionNotStoppable(String),
     /// <p>The pipeline was specified in an invalid format or cannot be found.</p>
     PipelineNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl StopPipelineExecutionError {
@@ -3418,22 +3699,25 @@ impl StopPipelineExecutionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DuplicatedStopRequestException" => {
-                    return RusotoError::Service(StopPipelineExecutionError::DuplicatedStopRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        StopPipelineExecutionError::DuplicatedStopRequest(err.msg),
+                        &res,
+                    )
                 }
                 "PipelineExecutionNotStoppableException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         StopPipelineExecutionError::PipelineExecutionNotStoppable(err.msg),
+                        &res,
                     )
                 }
                 "PipelineNotFoundException" => {
-                    return RusotoError::Service(StopPipelineExecutionError::PipelineNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        StopPipelineExecutionError::PipelineNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(StopPipelineExecutionError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3448,12 +3732,14 @@ impl fmt::Display for StopPipelineExecutionError {
                 write!(f, "{}", cause)
             }
             StopPipelineExecutionError::PipelineNotFound(ref cause) => write!(f, "{}", cause),
+            StopPipelineExecutionError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for StopPipelineExecutionError {}
 /// Errors returned by TagResource
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum TagResourceError {
     /// <p>Unable to modify the tag due to a simultaneous update request.</p>
     ConcurrentModification(String),
@@ -3465,6 +3751,8 @@ pub enum TagResourceError {
     ResourceNotFound(String),
     /// <p>The tags limit for a resource has been exceeded.</p>
     TooManyTags(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl TagResourceError {
@@ -3472,22 +3760,25 @@ impl TagResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(TagResourceError::ConcurrentModification(err.msg))
+                    return RusotoError::service(
+                        TagResourceError::ConcurrentModification(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidArnException" => {
-                    return RusotoError::Service(TagResourceError::InvalidArn(err.msg))
+                    return RusotoError::service(TagResourceError::InvalidArn(err.msg), &res)
                 }
                 "InvalidTagsException" => {
-                    return RusotoError::Service(TagResourceError::InvalidTags(err.msg))
+                    return RusotoError::service(TagResourceError::InvalidTags(err.msg), &res)
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(TagResourceError::ResourceNotFound(err.msg))
+                    return RusotoError::service(TagResourceError::ResourceNotFound(err.msg), &res)
                 }
                 "TooManyTagsException" => {
-                    return RusotoError::Service(TagResourceError::TooManyTags(err.msg))
+                    return RusotoError::service(TagResourceError::TooManyTags(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(TagResourceError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3502,12 +3793,14 @@ impl fmt::Display for TagResourceError {
             TagResourceError::InvalidTags(ref cause) => write!(f, "{}", cause),
             TagResourceError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             TagResourceError::TooManyTags(ref cause) => write!(f, "{}", cause),
+            TagResourceError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for TagResourceError {}
 /// Errors returned by UntagResource
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UntagResourceError {
     /// <p>Unable to modify the tag due to a simultaneous update request.</p>
     ConcurrentModification(String),
@@ -3517,6 +3810,8 @@ pub enum UntagResourceError {
     InvalidTags(String),
     /// <p>The resource was specified in an invalid format.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UntagResourceError {
@@ -3524,21 +3819,25 @@ impl UntagResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(UntagResourceError::ConcurrentModification(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UntagResourceError::ConcurrentModification(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidArnException" => {
-                    return RusotoError::Service(UntagResourceError::InvalidArn(err.msg))
+                    return RusotoError::service(UntagResourceError::InvalidArn(err.msg), &res)
                 }
                 "InvalidTagsException" => {
-                    return RusotoError::Service(UntagResourceError::InvalidTags(err.msg))
+                    return RusotoError::service(UntagResourceError::InvalidTags(err.msg), &res)
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UntagResourceError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        UntagResourceError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UntagResourceError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3552,12 +3851,14 @@ impl fmt::Display for UntagResourceError {
             UntagResourceError::InvalidArn(ref cause) => write!(f, "{}", cause),
             UntagResourceError::InvalidTags(ref cause) => write!(f, "{}", cause),
             UntagResourceError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            UntagResourceError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for UntagResourceError {}
 /// Errors returned by UpdatePipeline
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdatePipelineError {
     /// <p>The action declaration was specified in an invalid format.</p>
     InvalidActionDeclaration(String),
@@ -3569,6 +3870,8 @@ pub enum UpdatePipelineError {
     InvalidStructure(String),
     /// <p>The number of pipelines associated with the AWS account has exceeded the limit allowed for the account.</p>
     LimitExceeded(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdatePipelineError {
@@ -3576,28 +3879,34 @@ impl UpdatePipelineError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidActionDeclarationException" => {
-                    return RusotoError::Service(UpdatePipelineError::InvalidActionDeclaration(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdatePipelineError::InvalidActionDeclaration(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidBlockerDeclarationException" => {
-                    return RusotoError::Service(UpdatePipelineError::InvalidBlockerDeclaration(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdatePipelineError::InvalidBlockerDeclaration(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidStageDeclarationException" => {
-                    return RusotoError::Service(UpdatePipelineError::InvalidStageDeclaration(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdatePipelineError::InvalidStageDeclaration(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidStructureException" => {
-                    return RusotoError::Service(UpdatePipelineError::InvalidStructure(err.msg))
+                    return RusotoError::service(
+                        UpdatePipelineError::InvalidStructure(err.msg),
+                        &res,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(UpdatePipelineError::LimitExceeded(err.msg))
+                    return RusotoError::service(UpdatePipelineError::LimitExceeded(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UpdatePipelineError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3612,6 +3921,7 @@ impl fmt::Display for UpdatePipelineError {
             UpdatePipelineError::InvalidStageDeclaration(ref cause) => write!(f, "{}", cause),
             UpdatePipelineError::InvalidStructure(ref cause) => write!(f, "{}", cause),
             UpdatePipelineError::LimitExceeded(ref cause) => write!(f, "{}", cause),
+            UpdatePipelineError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
@@ -3855,6 +4165,7 @@ impl CodePipelineClient {
     /// Creates a client backed by the default tokio event loop.
     ///
     /// The client will use the default credentials provider and tls client.
+    #[cfg(not(target_arch = "wasm32"))]
     pub fn new(region: region::Region) -> CodePipelineClient {
         CodePipelineClient {
             client: Client::shared(),
@@ -3880,43 +4191,125 @@ impl CodePipelineClient {
     pub fn new_with_client(client: Client, region: region::Region) -> CodePipelineClient {
         CodePipelineClient { client, region }
     }
+
+    /// Returns a builder of a client, setting any of its region, credentials provider,
+    /// dispatcher, retry policy, endpoint and timeouts.
+    pub fn builder() -> rusoto_core::ClientBuilder<CodePipelineClient> {
+        rusoto_core::ClientBuilder::new(CodePipelineClient::new_with_client)
+    }
+
+    /// Returns a clone of this client with the overrides of `config` applied to its
+    /// operations, sharing its connections.
+    pub fn customize(&self, config: &rusoto_core::OperationConfig) -> CodePipelineClient {
+        CodePipelineClient {
+            client: self.client.customize(config),
+            region: config.resolve_region(&self.region),
+        }
+    }
 }
 
-#[async_trait]
-impl CodePipeline for CodePipelineClient {
-    /// <p>Returns information about a specified job and whether that job has been received by the job worker. Used for custom actions only.</p>
-    async fn acknowledge_job(
+impl CodePipelineClient {
+    /// Calls `acknowledge_job`, also returning the metadata of the response.
+    pub async fn acknowledge_job_with_metadata(
         &self,
         input: AcknowledgeJobInput,
-    ) -> Result<AcknowledgeJobOutput, RusotoError<AcknowledgeJobError>> {
+    ) -> Result<(AcknowledgeJobOutput, ResponseMetadata), RusotoError<AcknowledgeJobError>> {
+        let request = self.build_acknowledge_job_request(input);
+
+        let mut response = self
+            .client
+            .sign_and_dispatch(request)
+            .await
+            .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
+        if response.status.is_success() {
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<AcknowledgeJobOutput, _>()?;
+            Ok((result, metadata))
+        } else {
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(AcknowledgeJobError::from_response(response))
+        }
+    }
+
+    /// Presigns the request `acknowledge_job` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_acknowledge_job(
+        &self,
+        presigner: &rusoto_core::Presigner,
+        input: AcknowledgeJobInput,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_acknowledge_job_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_acknowledge_job_request(&self, input: AcknowledgeJobInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "codepipeline", &self.region, "/");
+        request.set_operation("AcknowledgeJob");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "CodePipeline_20150709.AcknowledgeJob");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `acknowledge_third_party_job`, also returning the metadata of the response.
+    pub async fn acknowledge_third_party_job_with_metadata(
+        &self,
+        input: AcknowledgeThirdPartyJobInput,
+    ) -> Result<
+        (AcknowledgeThirdPartyJobOutput, ResponseMetadata),
+        RusotoError<AcknowledgeThirdPartyJobError>,
+    > {
+        let request = self.build_acknowledge_third_party_job_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<AcknowledgeJobOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<AcknowledgeThirdPartyJobOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(AcknowledgeJobError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(AcknowledgeThirdPartyJobError::from_response(response))
         }
     }
 
-    /// <p>Confirms a job worker has received the specified job. Used for partner actions only.</p>
-    async fn acknowledge_third_party_job(
+    /// Presigns the request `acknowledge_third_party_job` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_acknowledge_third_party_job(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: AcknowledgeThirdPartyJobInput,
-    ) -> Result<AcknowledgeThirdPartyJobOutput, RusotoError<AcknowledgeThirdPartyJobError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_acknowledge_third_party_job_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_acknowledge_third_party_job_request(
+        &self,
+        input: AcknowledgeThirdPartyJobInput,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "codepipeline", &self.region, "/");
+        request.set_operation("AcknowledgeThirdPartyJob");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -3926,28 +4319,58 @@ impl CodePipeline for CodePipelineClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `create_custom_action_type`, also returning the metadata of the response.
+    pub async fn create_custom_action_type_with_metadata(
+        &self,
+        input: CreateCustomActionTypeInput,
+    ) -> Result<
+        (CreateCustomActionTypeOutput, ResponseMetadata),
+        RusotoError<CreateCustomActionTypeError>,
+    > {
+        let request = self.build_create_custom_action_type_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<AcknowledgeThirdPartyJobOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<CreateCustomActionTypeOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(AcknowledgeThirdPartyJobError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(CreateCustomActionTypeError::from_response(response))
         }
     }
 
-    /// <p>Creates a new custom action that can be used in all pipelines associated with the AWS account. Only used for custom actions.</p>
-    async fn create_custom_action_type(
+    /// Presigns the request `create_custom_action_type` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_custom_action_type(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: CreateCustomActionTypeInput,
-    ) -> Result<CreateCustomActionTypeOutput, RusotoError<CreateCustomActionTypeError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_custom_action_type_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_custom_action_type_request(
+        &self,
+        input: CreateCustomActionTypeInput,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "codepipeline", &self.region, "/");
+        request.set_operation("CreateCustomActionType");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -3957,55 +4380,102 @@ impl CodePipeline for CodePipelineClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `create_pipeline`, also returning the metadata of the response.
+    pub async fn create_pipeline_with_metadata(
+        &self,
+        input: CreatePipelineInput,
+    ) -> Result<(CreatePipelineOutput, ResponseMetadata), RusotoError<CreatePipelineError>> {
+        let request = self.build_create_pipeline_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<CreateCustomActionTypeOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<CreatePipelineOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(CreateCustomActionTypeError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(CreatePipelineError::from_response(response))
         }
     }
 
-    /// <p><p>Creates a pipeline.</p> <note> <p>In the pipeline structure, you must include either <code>artifactStore</code> or <code>artifactStores</code> in your pipeline, but you cannot use both. If you create a cross-region action in your pipeline, you must use <code>artifactStores</code>.</p> </note></p>
-    async fn create_pipeline(
+    /// Presigns the request `create_pipeline` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_pipeline(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: CreatePipelineInput,
-    ) -> Result<CreatePipelineOutput, RusotoError<CreatePipelineError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_pipeline_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_pipeline_request(&self, input: CreatePipelineInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "codepipeline", &self.region, "/");
+        request.set_operation("CreatePipeline");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "CodePipeline_20150709.CreatePipeline");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `delete_custom_action_type`, also returning the metadata of the response.
+    pub async fn delete_custom_action_type_with_metadata(
+        &self,
+        input: DeleteCustomActionTypeInput,
+    ) -> Result<((), ResponseMetadata), RusotoError<DeleteCustomActionTypeError>> {
+        let request = self.build_delete_custom_action_type_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<CreatePipelineOutput, _>()
+            std::mem::drop(response);
+            Ok(((), metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(CreatePipelineError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DeleteCustomActionTypeError::from_response(response))
         }
     }
 
-    /// <p><p>Marks a custom action as deleted. <code>PollForJobs</code> for the custom action fails after the action is marked for deletion. Used for custom actions only.</p> <important> <p>To re-create a custom action after it has been deleted you must use a string in the version field that has never been used before. This string can be an incremented version number, for example. To restore a deleted custom action, use a JSON file that is identical to the deleted action, including the original string in the version field.</p> </important></p>
-    async fn delete_custom_action_type(
+    /// Presigns the request `delete_custom_action_type` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_custom_action_type(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteCustomActionTypeInput,
-    ) -> Result<(), RusotoError<DeleteCustomActionTypeError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_custom_action_type_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_custom_action_type_request(
+        &self,
+        input: DeleteCustomActionTypeInput,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "codepipeline", &self.region, "/");
+        request.set_operation("DeleteCustomActionType");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -4015,84 +4485,159 @@ impl CodePipeline for CodePipelineClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `delete_pipeline`, also returning the metadata of the response.
+    pub async fn delete_pipeline_with_metadata(
+        &self,
+        input: DeletePipelineInput,
+    ) -> Result<((), ResponseMetadata), RusotoError<DeletePipelineError>> {
+        let request = self.build_delete_pipeline_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
             std::mem::drop(response);
-            Ok(())
+            Ok(((), metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteCustomActionTypeError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DeletePipelineError::from_response(response))
         }
     }
 
-    /// <p>Deletes the specified pipeline.</p>
-    async fn delete_pipeline(
+    /// Presigns the request `delete_pipeline` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_pipeline(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeletePipelineInput,
-    ) -> Result<(), RusotoError<DeletePipelineError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_pipeline_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_pipeline_request(&self, input: DeletePipelineInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "codepipeline", &self.region, "/");
+        request.set_operation("DeletePipeline");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "CodePipeline_20150709.DeletePipeline");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `delete_webhook`, also returning the metadata of the response.
+    pub async fn delete_webhook_with_metadata(
+        &self,
+        input: DeleteWebhookInput,
+    ) -> Result<(DeleteWebhookOutput, ResponseMetadata), RusotoError<DeleteWebhookError>> {
+        let request = self.build_delete_webhook_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            std::mem::drop(response);
-            Ok(())
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DeleteWebhookOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DeletePipelineError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DeleteWebhookError::from_response(response))
         }
     }
 
-    /// <p>Deletes a previously created webhook by name. Deleting the webhook stops AWS CodePipeline from starting a pipeline every time an external event occurs. The API returns successfully when trying to delete a webhook that is already deleted. If a deleted webhook is re-created by calling PutWebhook with the same name, it will have a different URL.</p>
-    async fn delete_webhook(
+    /// Presigns the request `delete_webhook` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_webhook(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteWebhookInput,
-    ) -> Result<DeleteWebhookOutput, RusotoError<DeleteWebhookError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_webhook_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_webhook_request(&self, input: DeleteWebhookInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "codepipeline", &self.region, "/");
+        request.set_operation("DeleteWebhook");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "CodePipeline_20150709.DeleteWebhook");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `deregister_webhook_with_third_party`, also returning the metadata of the response.
+    pub async fn deregister_webhook_with_third_party_with_metadata(
+        &self,
+        input: DeregisterWebhookWithThirdPartyInput,
+    ) -> Result<
+        (DeregisterWebhookWithThirdPartyOutput, ResponseMetadata),
+        RusotoError<DeregisterWebhookWithThirdPartyError>,
+    > {
+        let request = self.build_deregister_webhook_with_third_party_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<DeleteWebhookOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DeregisterWebhookWithThirdPartyOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteWebhookError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DeregisterWebhookWithThirdPartyError::from_response(
+                response,
+            ))
         }
     }
 
-    /// <p>Removes the connection between the webhook that was created by CodePipeline and the external tool with events to be detected. Currently supported only for webhooks that target an action type of GitHub.</p>
-    async fn deregister_webhook_with_third_party(
+    /// Presigns the request `deregister_webhook_with_third_party` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_deregister_webhook_with_third_party(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeregisterWebhookWithThirdPartyInput,
-    ) -> Result<
-        DeregisterWebhookWithThirdPartyOutput,
-        RusotoError<DeregisterWebhookWithThirdPartyError>,
-    > {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_deregister_webhook_with_third_party_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_deregister_webhook_with_third_party_request(
+        &self,
+        input: DeregisterWebhookWithThirdPartyInput,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "codepipeline", &self.region, "/");
+        request.set_operation("DeregisterWebhookWithThirdParty");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -4102,30 +4647,50 @@ impl CodePipeline for CodePipelineClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `disable_stage_transition`, also returning the metadata of the response.
+    pub async fn disable_stage_transition_with_metadata(
+        &self,
+        input: DisableStageTransitionInput,
+    ) -> Result<((), ResponseMetadata), RusotoError<DisableStageTransitionError>> {
+        let request = self.build_disable_stage_transition_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DeregisterWebhookWithThirdPartyOutput, _>()
+            std::mem::drop(response);
+            Ok(((), metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DeregisterWebhookWithThirdPartyError::from_response(
-                response,
-            ))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DisableStageTransitionError::from_response(response))
         }
     }
 
-    /// <p>Prevents artifacts in a pipeline from transitioning to the next stage in the pipeline.</p>
-    async fn disable_stage_transition(
+    /// Presigns the request `disable_stage_transition` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_disable_stage_transition(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DisableStageTransitionInput,
-    ) -> Result<(), RusotoError<DisableStageTransitionError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_disable_stage_transition_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_disable_stage_transition_request(
+        &self,
+        input: DisableStageTransitionInput,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "codepipeline", &self.region, "/");
+        request.set_operation("DisableStageTransition");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -4135,27 +4700,50 @@ impl CodePipeline for CodePipelineClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `enable_stage_transition`, also returning the metadata of the response.
+    pub async fn enable_stage_transition_with_metadata(
+        &self,
+        input: EnableStageTransitionInput,
+    ) -> Result<((), ResponseMetadata), RusotoError<EnableStageTransitionError>> {
+        let request = self.build_enable_stage_transition_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
             std::mem::drop(response);
-            Ok(())
+            Ok(((), metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DisableStageTransitionError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(EnableStageTransitionError::from_response(response))
         }
     }
 
-    /// <p>Enables artifacts in a pipeline to transition to a stage in a pipeline.</p>
-    async fn enable_stage_transition(
+    /// Presigns the request `enable_stage_transition` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_enable_stage_transition(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: EnableStageTransitionInput,
-    ) -> Result<(), RusotoError<EnableStageTransitionError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_enable_stage_transition_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_enable_stage_transition_request(
+        &self,
+        input: EnableStageTransitionInput,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "codepipeline", &self.region, "/");
+        request.set_operation("EnableStageTransition");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -4165,136 +4753,273 @@ impl CodePipeline for CodePipelineClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `get_job_details`, also returning the metadata of the response.
+    pub async fn get_job_details_with_metadata(
+        &self,
+        input: GetJobDetailsInput,
+    ) -> Result<(GetJobDetailsOutput, ResponseMetadata), RusotoError<GetJobDetailsError>> {
+        let request = self.build_get_job_details_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            std::mem::drop(response);
-            Ok(())
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<GetJobDetailsOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(EnableStageTransitionError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(GetJobDetailsError::from_response(response))
         }
     }
 
-    /// <p><p>Returns information about a job. Used for custom actions only.</p> <important> <p>When this API is called, AWS CodePipeline returns temporary credentials for the S3 bucket used to store artifacts for the pipeline, if the action requires access to that S3 bucket for input or output artifacts. This API also returns any secret values defined for the action.</p> </important></p>
-    async fn get_job_details(
+    /// Presigns the request `get_job_details` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_job_details(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetJobDetailsInput,
-    ) -> Result<GetJobDetailsOutput, RusotoError<GetJobDetailsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_job_details_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_job_details_request(&self, input: GetJobDetailsInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "codepipeline", &self.region, "/");
+        request.set_operation("GetJobDetails");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "CodePipeline_20150709.GetJobDetails");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
-        let mut response = self
-            .client
+        request
+    }
+
+    /// Calls `get_pipeline`, also returning the metadata of the response.
+    pub async fn get_pipeline_with_metadata(
+        &self,
+        input: GetPipelineInput,
+    ) -> Result<(GetPipelineOutput, ResponseMetadata), RusotoError<GetPipelineError>> {
+        let request = self.build_get_pipeline_request(input);
+
+        let mut response = self
+            .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<GetJobDetailsOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<GetPipelineOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(GetJobDetailsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(GetPipelineError::from_response(response))
         }
     }
 
-    /// <p>Returns the metadata, structure, stages, and actions of a pipeline. Can be used to return the entire structure of a pipeline in JSON format, which can then be modified and used to update the pipeline structure with <a>UpdatePipeline</a>.</p>
-    async fn get_pipeline(
+    /// Presigns the request `get_pipeline` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_pipeline(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetPipelineInput,
-    ) -> Result<GetPipelineOutput, RusotoError<GetPipelineError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_pipeline_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_pipeline_request(&self, input: GetPipelineInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "codepipeline", &self.region, "/");
+        request.set_operation("GetPipeline");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "CodePipeline_20150709.GetPipeline");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `get_pipeline_execution`, also returning the metadata of the response.
+    pub async fn get_pipeline_execution_with_metadata(
+        &self,
+        input: GetPipelineExecutionInput,
+    ) -> Result<
+        (GetPipelineExecutionOutput, ResponseMetadata),
+        RusotoError<GetPipelineExecutionError>,
+    > {
+        let request = self.build_get_pipeline_execution_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<GetPipelineOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<GetPipelineExecutionOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(GetPipelineError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(GetPipelineExecutionError::from_response(response))
         }
     }
 
-    /// <p>Returns information about an execution of a pipeline, including details about artifacts, the pipeline execution ID, and the name, version, and status of the pipeline.</p>
-    async fn get_pipeline_execution(
+    /// Presigns the request `get_pipeline_execution` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_pipeline_execution(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetPipelineExecutionInput,
-    ) -> Result<GetPipelineExecutionOutput, RusotoError<GetPipelineExecutionError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_pipeline_execution_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_pipeline_execution_request(
+        &self,
+        input: GetPipelineExecutionInput,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "codepipeline", &self.region, "/");
+        request.set_operation("GetPipelineExecution");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "CodePipeline_20150709.GetPipelineExecution");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `get_pipeline_state`, also returning the metadata of the response.
+    pub async fn get_pipeline_state_with_metadata(
+        &self,
+        input: GetPipelineStateInput,
+    ) -> Result<(GetPipelineStateOutput, ResponseMetadata), RusotoError<GetPipelineStateError>>
+    {
+        let request = self.build_get_pipeline_state_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<GetPipelineExecutionOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<GetPipelineStateOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(GetPipelineExecutionError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(GetPipelineStateError::from_response(response))
         }
     }
 
-    /// <p><p>Returns information about the state of a pipeline, including the stages and actions.</p> <note> <p>Values returned in the <code>revisionId</code> and <code>revisionUrl</code> fields indicate the source revision information, such as the commit ID, for the current state.</p> </note></p>
-    async fn get_pipeline_state(
+    /// Presigns the request `get_pipeline_state` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_pipeline_state(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetPipelineStateInput,
-    ) -> Result<GetPipelineStateOutput, RusotoError<GetPipelineStateError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_pipeline_state_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_pipeline_state_request(&self, input: GetPipelineStateInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "codepipeline", &self.region, "/");
+        request.set_operation("GetPipelineState");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "CodePipeline_20150709.GetPipelineState");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `get_third_party_job_details`, also returning the metadata of the response.
+    pub async fn get_third_party_job_details_with_metadata(
+        &self,
+        input: GetThirdPartyJobDetailsInput,
+    ) -> Result<
+        (GetThirdPartyJobDetailsOutput, ResponseMetadata),
+        RusotoError<GetThirdPartyJobDetailsError>,
+    > {
+        let request = self.build_get_third_party_job_details_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<GetPipelineStateOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<GetThirdPartyJobDetailsOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(GetPipelineStateError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(GetThirdPartyJobDetailsError::from_response(response))
         }
     }
 
-    /// <p><p>Requests the details of a job for a third party action. Used for partner actions only.</p> <important> <p>When this API is called, AWS CodePipeline returns temporary credentials for the S3 bucket used to store artifacts for the pipeline, if the action requires access to that S3 bucket for input or output artifacts. This API also returns any secret values defined for the action.</p> </important></p>
-    async fn get_third_party_job_details(
+    /// Presigns the request `get_third_party_job_details` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_third_party_job_details(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetThirdPartyJobDetailsInput,
-    ) -> Result<GetThirdPartyJobDetailsOutput, RusotoError<GetThirdPartyJobDetailsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_third_party_job_details_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_third_party_job_details_request(
+        &self,
+        input: GetThirdPartyJobDetailsInput,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "codepipeline", &self.region, "/");
+        request.set_operation("GetThirdPartyJobDetails");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -4304,83 +5029,168 @@ impl CodePipeline for CodePipelineClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `list_action_executions`, also returning the metadata of the response.
+    pub async fn list_action_executions_with_metadata(
+        &self,
+        input: ListActionExecutionsInput,
+    ) -> Result<
+        (ListActionExecutionsOutput, ResponseMetadata),
+        RusotoError<ListActionExecutionsError>,
+    > {
+        let request = self.build_list_action_executions_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<GetThirdPartyJobDetailsOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ListActionExecutionsOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(GetThirdPartyJobDetailsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ListActionExecutionsError::from_response(response))
         }
     }
 
-    /// <p>Lists the action executions that have occurred in a pipeline.</p>
-    async fn list_action_executions(
+    /// Presigns the request `list_action_executions` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_action_executions(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListActionExecutionsInput,
-    ) -> Result<ListActionExecutionsOutput, RusotoError<ListActionExecutionsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_action_executions_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_action_executions_request(
+        &self,
+        input: ListActionExecutionsInput,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "codepipeline", &self.region, "/");
+        request.set_operation("ListActionExecutions");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "CodePipeline_20150709.ListActionExecutions");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `list_action_types`, also returning the metadata of the response.
+    pub async fn list_action_types_with_metadata(
+        &self,
+        input: ListActionTypesInput,
+    ) -> Result<(ListActionTypesOutput, ResponseMetadata), RusotoError<ListActionTypesError>> {
+        let request = self.build_list_action_types_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListActionExecutionsOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ListActionTypesOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ListActionExecutionsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ListActionTypesError::from_response(response))
         }
     }
 
-    /// <p>Gets a summary of all AWS CodePipeline action types associated with your account.</p>
-    async fn list_action_types(
+    /// Presigns the request `list_action_types` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_action_types(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListActionTypesInput,
-    ) -> Result<ListActionTypesOutput, RusotoError<ListActionTypesError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_action_types_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_action_types_request(&self, input: ListActionTypesInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "codepipeline", &self.region, "/");
+        request.set_operation("ListActionTypes");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "CodePipeline_20150709.ListActionTypes");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `list_pipeline_executions`, also returning the metadata of the response.
+    pub async fn list_pipeline_executions_with_metadata(
+        &self,
+        input: ListPipelineExecutionsInput,
+    ) -> Result<
+        (ListPipelineExecutionsOutput, ResponseMetadata),
+        RusotoError<ListPipelineExecutionsError>,
+    > {
+        let request = self.build_list_pipeline_executions_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<ListActionTypesOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ListPipelineExecutionsOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ListActionTypesError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ListPipelineExecutionsError::from_response(response))
         }
     }
 
-    /// <p>Gets a summary of the most recent executions for a pipeline.</p>
-    async fn list_pipeline_executions(
+    /// Presigns the request `list_pipeline_executions` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_pipeline_executions(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListPipelineExecutionsInput,
-    ) -> Result<ListPipelineExecutionsOutput, RusotoError<ListPipelineExecutionsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_pipeline_executions_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_pipeline_executions_request(
+        &self,
+        input: ListPipelineExecutionsInput,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "codepipeline", &self.region, "/");
+        request.set_operation("ListPipelineExecutions");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -4390,137 +5200,270 @@ impl CodePipeline for CodePipelineClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `list_pipelines`, also returning the metadata of the response.
+    pub async fn list_pipelines_with_metadata(
+        &self,
+        input: ListPipelinesInput,
+    ) -> Result<(ListPipelinesOutput, ResponseMetadata), RusotoError<ListPipelinesError>> {
+        let request = self.build_list_pipelines_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListPipelineExecutionsOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ListPipelinesOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ListPipelineExecutionsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ListPipelinesError::from_response(response))
         }
     }
 
-    /// <p>Gets a summary of all of the pipelines associated with your account.</p>
-    async fn list_pipelines(
+    /// Presigns the request `list_pipelines` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_pipelines(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListPipelinesInput,
-    ) -> Result<ListPipelinesOutput, RusotoError<ListPipelinesError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_pipelines_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_pipelines_request(&self, input: ListPipelinesInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "codepipeline", &self.region, "/");
+        request.set_operation("ListPipelines");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "CodePipeline_20150709.ListPipelines");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `list_tags_for_resource`, also returning the metadata of the response.
+    pub async fn list_tags_for_resource_with_metadata(
+        &self,
+        input: ListTagsForResourceInput,
+    ) -> Result<(ListTagsForResourceOutput, ResponseMetadata), RusotoError<ListTagsForResourceError>>
+    {
+        let request = self.build_list_tags_for_resource_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<ListPipelinesOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ListTagsForResourceOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ListPipelinesError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ListTagsForResourceError::from_response(response))
         }
     }
 
-    /// <p>Gets the set of key-value pairs (metadata) that are used to manage the resource.</p>
-    async fn list_tags_for_resource(
+    /// Presigns the request `list_tags_for_resource` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_tags_for_resource(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListTagsForResourceInput,
-    ) -> Result<ListTagsForResourceOutput, RusotoError<ListTagsForResourceError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_tags_for_resource_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_tags_for_resource_request(
+        &self,
+        input: ListTagsForResourceInput,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "codepipeline", &self.region, "/");
+        request.set_operation("ListTagsForResource");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "CodePipeline_20150709.ListTagsForResource");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `list_webhooks`, also returning the metadata of the response.
+    pub async fn list_webhooks_with_metadata(
+        &self,
+        input: ListWebhooksInput,
+    ) -> Result<(ListWebhooksOutput, ResponseMetadata), RusotoError<ListWebhooksError>> {
+        let request = self.build_list_webhooks_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListTagsForResourceOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ListWebhooksOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ListTagsForResourceError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ListWebhooksError::from_response(response))
         }
     }
 
-    /// <p>Gets a listing of all the webhooks in this AWS Region for this account. The output lists all webhooks and includes the webhook URL and ARN and the configuration for each webhook.</p>
-    async fn list_webhooks(
+    /// Presigns the request `list_webhooks` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_webhooks(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListWebhooksInput,
-    ) -> Result<ListWebhooksOutput, RusotoError<ListWebhooksError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_webhooks_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_webhooks_request(&self, input: ListWebhooksInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "codepipeline", &self.region, "/");
+        request.set_operation("ListWebhooks");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "CodePipeline_20150709.ListWebhooks");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `poll_for_jobs`, also returning the metadata of the response.
+    pub async fn poll_for_jobs_with_metadata(
+        &self,
+        input: PollForJobsInput,
+    ) -> Result<(PollForJobsOutput, ResponseMetadata), RusotoError<PollForJobsError>> {
+        let request = self.build_poll_for_jobs_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<ListWebhooksOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<PollForJobsOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ListWebhooksError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(PollForJobsError::from_response(response))
         }
     }
 
-    /// <p><p>Returns information about any jobs for AWS CodePipeline to act on. <code>PollForJobs</code> is valid only for action types with &quot;Custom&quot; in the owner field. If the action type contains &quot;AWS&quot; or &quot;ThirdParty&quot; in the owner field, the <code>PollForJobs</code> action returns an error.</p> <important> <p>When this API is called, AWS CodePipeline returns temporary credentials for the S3 bucket used to store artifacts for the pipeline, if the action requires access to that S3 bucket for input or output artifacts. This API also returns any secret values defined for the action.</p> </important></p>
-    async fn poll_for_jobs(
+    /// Presigns the request `poll_for_jobs` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_poll_for_jobs(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: PollForJobsInput,
-    ) -> Result<PollForJobsOutput, RusotoError<PollForJobsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_poll_for_jobs_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_poll_for_jobs_request(&self, input: PollForJobsInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "codepipeline", &self.region, "/");
+        request.set_operation("PollForJobs");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "CodePipeline_20150709.PollForJobs");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `poll_for_third_party_jobs`, also returning the metadata of the response.
+    pub async fn poll_for_third_party_jobs_with_metadata(
+        &self,
+        input: PollForThirdPartyJobsInput,
+    ) -> Result<
+        (PollForThirdPartyJobsOutput, ResponseMetadata),
+        RusotoError<PollForThirdPartyJobsError>,
+    > {
+        let request = self.build_poll_for_third_party_jobs_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<PollForJobsOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<PollForThirdPartyJobsOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(PollForJobsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(PollForThirdPartyJobsError::from_response(response))
         }
     }
 
-    /// <p><p>Determines whether there are any third party jobs for a job worker to act on. Used for partner actions only.</p> <important> <p>When this API is called, AWS CodePipeline returns temporary credentials for the S3 bucket used to store artifacts for the pipeline, if the action requires access to that S3 bucket for input or output artifacts.</p> </important></p>
-    async fn poll_for_third_party_jobs(
+    /// Presigns the request `poll_for_third_party_jobs` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_poll_for_third_party_jobs(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: PollForThirdPartyJobsInput,
-    ) -> Result<PollForThirdPartyJobsOutput, RusotoError<PollForThirdPartyJobsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_poll_for_third_party_jobs_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_poll_for_third_party_jobs_request(
+        &self,
+        input: PollForThirdPartyJobsInput,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "codepipeline", &self.region, "/");
+        request.set_operation("PollForThirdPartyJobs");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -4530,136 +5473,256 @@ impl CodePipeline for CodePipelineClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `put_action_revision`, also returning the metadata of the response.
+    pub async fn put_action_revision_with_metadata(
+        &self,
+        input: PutActionRevisionInput,
+    ) -> Result<(PutActionRevisionOutput, ResponseMetadata), RusotoError<PutActionRevisionError>>
+    {
+        let request = self.build_put_action_revision_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<PollForThirdPartyJobsOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<PutActionRevisionOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(PollForThirdPartyJobsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(PutActionRevisionError::from_response(response))
         }
     }
 
-    /// <p>Provides information to AWS CodePipeline about new revisions to a source.</p>
-    async fn put_action_revision(
+    /// Presigns the request `put_action_revision` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_put_action_revision(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: PutActionRevisionInput,
-    ) -> Result<PutActionRevisionOutput, RusotoError<PutActionRevisionError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_put_action_revision_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_put_action_revision_request(&self, input: PutActionRevisionInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "codepipeline", &self.region, "/");
+        request.set_operation("PutActionRevision");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "CodePipeline_20150709.PutActionRevision");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `put_approval_result`, also returning the metadata of the response.
+    pub async fn put_approval_result_with_metadata(
+        &self,
+        input: PutApprovalResultInput,
+    ) -> Result<(PutApprovalResultOutput, ResponseMetadata), RusotoError<PutApprovalResultError>>
+    {
+        let request = self.build_put_approval_result_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<PutActionRevisionOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<PutApprovalResultOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(PutActionRevisionError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(PutApprovalResultError::from_response(response))
         }
     }
 
-    /// <p>Provides the response to a manual approval request to AWS CodePipeline. Valid responses include Approved and Rejected.</p>
-    async fn put_approval_result(
+    /// Presigns the request `put_approval_result` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_put_approval_result(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: PutApprovalResultInput,
-    ) -> Result<PutApprovalResultOutput, RusotoError<PutApprovalResultError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_put_approval_result_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_put_approval_result_request(&self, input: PutApprovalResultInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "codepipeline", &self.region, "/");
+        request.set_operation("PutApprovalResult");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "CodePipeline_20150709.PutApprovalResult");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `put_job_failure_result`, also returning the metadata of the response.
+    pub async fn put_job_failure_result_with_metadata(
+        &self,
+        input: PutJobFailureResultInput,
+    ) -> Result<((), ResponseMetadata), RusotoError<PutJobFailureResultError>> {
+        let request = self.build_put_job_failure_result_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<PutApprovalResultOutput, _>()
+            std::mem::drop(response);
+            Ok(((), metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(PutApprovalResultError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(PutJobFailureResultError::from_response(response))
         }
     }
 
-    /// <p>Represents the failure of a job as returned to the pipeline by a job worker. Used for custom actions only.</p>
-    async fn put_job_failure_result(
+    /// Presigns the request `put_job_failure_result` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_put_job_failure_result(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: PutJobFailureResultInput,
-    ) -> Result<(), RusotoError<PutJobFailureResultError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_put_job_failure_result_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_put_job_failure_result_request(
+        &self,
+        input: PutJobFailureResultInput,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "codepipeline", &self.region, "/");
+        request.set_operation("PutJobFailureResult");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "CodePipeline_20150709.PutJobFailureResult");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `put_job_success_result`, also returning the metadata of the response.
+    pub async fn put_job_success_result_with_metadata(
+        &self,
+        input: PutJobSuccessResultInput,
+    ) -> Result<((), ResponseMetadata), RusotoError<PutJobSuccessResultError>> {
+        let request = self.build_put_job_success_result_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
             std::mem::drop(response);
-            Ok(())
+            Ok(((), metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(PutJobFailureResultError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(PutJobSuccessResultError::from_response(response))
         }
     }
 
-    /// <p>Represents the success of a job as returned to the pipeline by a job worker. Used for custom actions only.</p>
-    async fn put_job_success_result(
+    /// Presigns the request `put_job_success_result` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_put_job_success_result(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: PutJobSuccessResultInput,
-    ) -> Result<(), RusotoError<PutJobSuccessResultError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_put_job_success_result_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_put_job_success_result_request(
+        &self,
+        input: PutJobSuccessResultInput,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "codepipeline", &self.region, "/");
+        request.set_operation("PutJobSuccessResult");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "CodePipeline_20150709.PutJobSuccessResult");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `put_third_party_job_failure_result`, also returning the metadata of the response.
+    pub async fn put_third_party_job_failure_result_with_metadata(
+        &self,
+        input: PutThirdPartyJobFailureResultInput,
+    ) -> Result<((), ResponseMetadata), RusotoError<PutThirdPartyJobFailureResultError>> {
+        let request = self.build_put_third_party_job_failure_result_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
             std::mem::drop(response);
-            Ok(())
+            Ok(((), metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(PutJobSuccessResultError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(PutThirdPartyJobFailureResultError::from_response(response))
         }
     }
 
-    /// <p>Represents the failure of a third party job as returned to the pipeline by a job worker. Used for partner actions only.</p>
-    async fn put_third_party_job_failure_result(
+    /// Presigns the request `put_third_party_job_failure_result` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_put_third_party_job_failure_result(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: PutThirdPartyJobFailureResultInput,
-    ) -> Result<(), RusotoError<PutThirdPartyJobFailureResultError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_put_third_party_job_failure_result_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_put_third_party_job_failure_result_request(
+        &self,
+        input: PutThirdPartyJobFailureResultInput,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "codepipeline", &self.region, "/");
+        request.set_operation("PutThirdPartyJobFailureResult");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -4669,27 +5732,50 @@ impl CodePipeline for CodePipelineClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `put_third_party_job_success_result`, also returning the metadata of the response.
+    pub async fn put_third_party_job_success_result_with_metadata(
+        &self,
+        input: PutThirdPartyJobSuccessResultInput,
+    ) -> Result<((), ResponseMetadata), RusotoError<PutThirdPartyJobSuccessResultError>> {
+        let request = self.build_put_third_party_job_success_result_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
             std::mem::drop(response);
-            Ok(())
+            Ok(((), metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(PutThirdPartyJobFailureResultError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(PutThirdPartyJobSuccessResultError::from_response(response))
         }
     }
 
-    /// <p>Represents the success of a third party job as returned to the pipeline by a job worker. Used for partner actions only.</p>
-    async fn put_third_party_job_success_result(
+    /// Presigns the request `put_third_party_job_success_result` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_put_third_party_job_success_result(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: PutThirdPartyJobSuccessResultInput,
-    ) -> Result<(), RusotoError<PutThirdPartyJobSuccessResultError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_put_third_party_job_success_result_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_put_third_party_job_success_result_request(
+        &self,
+        input: PutThirdPartyJobSuccessResultInput,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "codepipeline", &self.region, "/");
+        request.set_operation("PutThirdPartyJobSuccessResult");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -4699,55 +5785,110 @@ impl CodePipeline for CodePipelineClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
-        let mut response = self
-            .client
-            .sign_and_dispatch(request)
+        request
+    }
+
+    /// Calls `put_webhook`, also returning the metadata of the response.
+    pub async fn put_webhook_with_metadata(
+        &self,
+        input: PutWebhookInput,
+    ) -> Result<(PutWebhookOutput, ResponseMetadata), RusotoError<PutWebhookError>> {
+        let request = self.build_put_webhook_request(input);
+
+        let mut response = self
+            .client
+            .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            std::mem::drop(response);
-            Ok(())
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<PutWebhookOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(PutThirdPartyJobSuccessResultError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(PutWebhookError::from_response(response))
         }
     }
 
-    /// <p>Defines a webhook and returns a unique webhook URL generated by CodePipeline. This URL can be supplied to third party source hosting providers to call every time there's a code change. When CodePipeline receives a POST request on this URL, the pipeline defined in the webhook is started as long as the POST request satisfied the authentication and filtering requirements supplied when defining the webhook. RegisterWebhookWithThirdParty and DeregisterWebhookWithThirdParty APIs can be used to automatically configure supported third parties to call the generated webhook URL.</p>
-    async fn put_webhook(
+    /// Presigns the request `put_webhook` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_put_webhook(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: PutWebhookInput,
-    ) -> Result<PutWebhookOutput, RusotoError<PutWebhookError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_put_webhook_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_put_webhook_request(&self, input: PutWebhookInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "codepipeline", &self.region, "/");
+        request.set_operation("PutWebhook");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "CodePipeline_20150709.PutWebhook");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `register_webhook_with_third_party`, also returning the metadata of the response.
+    pub async fn register_webhook_with_third_party_with_metadata(
+        &self,
+        input: RegisterWebhookWithThirdPartyInput,
+    ) -> Result<
+        (RegisterWebhookWithThirdPartyOutput, ResponseMetadata),
+        RusotoError<RegisterWebhookWithThirdPartyError>,
+    > {
+        let request = self.build_register_webhook_with_third_party_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<PutWebhookOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<RegisterWebhookWithThirdPartyOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(PutWebhookError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(RegisterWebhookWithThirdPartyError::from_response(response))
         }
     }
 
-    /// <p>Configures a connection between the webhook that was created and the external tool with events to be detected.</p>
-    async fn register_webhook_with_third_party(
+    /// Presigns the request `register_webhook_with_third_party` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_register_webhook_with_third_party(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: RegisterWebhookWithThirdPartyInput,
-    ) -> Result<RegisterWebhookWithThirdPartyOutput, RusotoError<RegisterWebhookWithThirdPartyError>>
-    {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_register_webhook_with_third_party_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_register_webhook_with_third_party_request(
+        &self,
+        input: RegisterWebhookWithThirdPartyInput,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "codepipeline", &self.region, "/");
+        request.set_operation("RegisterWebhookWithThirdParty");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -4757,56 +5898,114 @@ impl CodePipeline for CodePipelineClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `retry_stage_execution`, also returning the metadata of the response.
+    pub async fn retry_stage_execution_with_metadata(
+        &self,
+        input: RetryStageExecutionInput,
+    ) -> Result<(RetryStageExecutionOutput, ResponseMetadata), RusotoError<RetryStageExecutionError>>
+    {
+        let request = self.build_retry_stage_execution_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<RegisterWebhookWithThirdPartyOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<RetryStageExecutionOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(RegisterWebhookWithThirdPartyError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(RetryStageExecutionError::from_response(response))
         }
     }
 
-    /// <p>Resumes the pipeline execution by retrying the last failed actions in a stage. You can retry a stage immediately if any of the actions in the stage fail. When you retry, all actions that are still in progress continue working, and failed actions are triggered again.</p>
-    async fn retry_stage_execution(
+    /// Presigns the request `retry_stage_execution` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_retry_stage_execution(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: RetryStageExecutionInput,
-    ) -> Result<RetryStageExecutionOutput, RusotoError<RetryStageExecutionError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_retry_stage_execution_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_retry_stage_execution_request(
+        &self,
+        input: RetryStageExecutionInput,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "codepipeline", &self.region, "/");
+        request.set_operation("RetryStageExecution");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "CodePipeline_20150709.RetryStageExecution");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `start_pipeline_execution`, also returning the metadata of the response.
+    pub async fn start_pipeline_execution_with_metadata(
+        &self,
+        input: StartPipelineExecutionInput,
+    ) -> Result<
+        (StartPipelineExecutionOutput, ResponseMetadata),
+        RusotoError<StartPipelineExecutionError>,
+    > {
+        let request = self.build_start_pipeline_execution_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<RetryStageExecutionOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<StartPipelineExecutionOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(RetryStageExecutionError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(StartPipelineExecutionError::from_response(response))
         }
     }
 
-    /// <p>Starts the specified pipeline. Specifically, it begins processing the latest commit to the source location specified as part of the pipeline.</p>
-    async fn start_pipeline_execution(
+    /// Presigns the request `start_pipeline_execution` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_start_pipeline_execution(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: StartPipelineExecutionInput,
-    ) -> Result<StartPipelineExecutionOutput, RusotoError<StartPipelineExecutionError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_start_pipeline_execution_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_start_pipeline_execution_request(
+        &self,
+        input: StartPipelineExecutionInput,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "codepipeline", &self.region, "/");
+        request.set_operation("StartPipelineExecution");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -4816,28 +6015,58 @@ impl CodePipeline for CodePipelineClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `stop_pipeline_execution`, also returning the metadata of the response.
+    pub async fn stop_pipeline_execution_with_metadata(
+        &self,
+        input: StopPipelineExecutionInput,
+    ) -> Result<
+        (StopPipelineExecutionOutput, ResponseMetadata),
+        RusotoError<StopPipelineExecutionError>,
+    > {
+        let request = self.build_stop_pipeline_execution_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<StartPipelineExecutionOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<StopPipelineExecutionOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(StartPipelineExecutionError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(StopPipelineExecutionError::from_response(response))
         }
     }
 
-    /// <p>Stops the specified pipeline execution. You choose to either stop the pipeline execution by completing in-progress actions without starting subsequent actions, or by abandoning in-progress actions. While completing or abandoning in-progress actions, the pipeline execution is in a <code>Stopping</code> state. After all in-progress actions are completed or abandoned, the pipeline execution is in a <code>Stopped</code> state.</p>
-    async fn stop_pipeline_execution(
+    /// Presigns the request `stop_pipeline_execution` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_stop_pipeline_execution(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: StopPipelineExecutionInput,
-    ) -> Result<StopPipelineExecutionOutput, RusotoError<StopPipelineExecutionError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_stop_pipeline_execution_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_stop_pipeline_execution_request(
+        &self,
+        input: StopPipelineExecutionInput,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "codepipeline", &self.region, "/");
+        request.set_operation("StopPipelineExecution");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -4847,100 +6076,765 @@ impl CodePipeline for CodePipelineClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `tag_resource`, also returning the metadata of the response.
+    pub async fn tag_resource_with_metadata(
+        &self,
+        input: TagResourceInput,
+    ) -> Result<(TagResourceOutput, ResponseMetadata), RusotoError<TagResourceError>> {
+        let request = self.build_tag_resource_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<StopPipelineExecutionOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<TagResourceOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(StopPipelineExecutionError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(TagResourceError::from_response(response))
         }
     }
 
-    /// <p>Adds to or modifies the tags of the given resource. Tags are metadata that can be used to manage a resource. </p>
-    async fn tag_resource(
+    /// Presigns the request `tag_resource` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_tag_resource(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: TagResourceInput,
-    ) -> Result<TagResourceOutput, RusotoError<TagResourceError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_tag_resource_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_tag_resource_request(&self, input: TagResourceInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "codepipeline", &self.region, "/");
+        request.set_operation("TagResource");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "CodePipeline_20150709.TagResource");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `untag_resource`, also returning the metadata of the response.
+    pub async fn untag_resource_with_metadata(
+        &self,
+        input: UntagResourceInput,
+    ) -> Result<(UntagResourceOutput, ResponseMetadata), RusotoError<UntagResourceError>> {
+        let request = self.build_untag_resource_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<TagResourceOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<UntagResourceOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(TagResourceError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(UntagResourceError::from_response(response))
         }
     }
 
-    /// <p>Removes tags from an AWS resource.</p>
-    async fn untag_resource(
+    /// Presigns the request `untag_resource` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_untag_resource(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UntagResourceInput,
-    ) -> Result<UntagResourceOutput, RusotoError<UntagResourceError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_untag_resource_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_untag_resource_request(&self, input: UntagResourceInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "codepipeline", &self.region, "/");
+        request.set_operation("UntagResource");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "CodePipeline_20150709.UntagResource");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `update_pipeline`, also returning the metadata of the response.
+    pub async fn update_pipeline_with_metadata(
+        &self,
+        input: UpdatePipelineInput,
+    ) -> Result<(UpdatePipelineOutput, ResponseMetadata), RusotoError<UpdatePipelineError>> {
+        let request = self.build_update_pipeline_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<UntagResourceOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<UpdatePipelineOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(UntagResourceError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(UpdatePipelineError::from_response(response))
         }
     }
 
-    /// <p>Updates a specified pipeline with edits or changes to its structure. Use a JSON file with the pipeline structure and <code>UpdatePipeline</code> to provide the full structure of the pipeline. Updating the pipeline increases the version number of the pipeline by 1.</p>
-    async fn update_pipeline(
+    /// Presigns the request `update_pipeline` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_pipeline(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UpdatePipelineInput,
-    ) -> Result<UpdatePipelineOutput, RusotoError<UpdatePipelineError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_pipeline_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_pipeline_request(&self, input: UpdatePipelineInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "codepipeline", &self.region, "/");
+        request.set_operation("UpdatePipeline");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "CodePipeline_20150709.UpdatePipeline");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
-        let mut response = self
-            .client
-            .sign_and_dispatch(request)
+        request
+    }
+
+    /// The pages of `ListActionExecutions` results for `input`, requesting each next page with the `nextToken` of the last one as its `nextToken`.
+    /// The page size is the `maxResults` of `input`.
+    pub fn list_action_executions_pages(
+        &self,
+        input: ListActionExecutionsInput,
+    ) -> impl futures::Stream<
+        Item = Result<ListActionExecutionsOutput, RusotoError<ListActionExecutionsError>>,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: CodePipelineClient, input| async move {
+                client.list_action_executions(input).await
+            },
+            |input: &ListActionExecutionsInput, output: &ListActionExecutionsOutput| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListActionExecutionsInput {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `actionExecutionDetails` of every page of `ListActionExecutions` results for `input`, as `list_action_executions_pages` requests them.
+    pub fn list_action_executions_stream(
+        &self,
+        input: ListActionExecutionsInput,
+    ) -> impl futures::Stream<
+        Item = Result<ActionExecutionDetail, RusotoError<ListActionExecutionsError>>,
+    > + Send {
+        rusoto_core::pagination::items(self.list_action_executions_pages(input), |page| {
+            page.action_execution_details.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `ListActionTypes` results for `input`, requesting each next page with the `nextToken` of the last one as its `nextToken`.
+    pub fn list_action_types_pages(
+        &self,
+        input: ListActionTypesInput,
+    ) -> impl futures::Stream<Item = Result<ListActionTypesOutput, RusotoError<ListActionTypesError>>>
+           + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: CodePipelineClient, input| async move { client.list_action_types(input).await },
+            |input: &ListActionTypesInput, output: &ListActionTypesOutput| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListActionTypesInput {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `actionTypes` of every page of `ListActionTypes` results for `input`, as `list_action_types_pages` requests them.
+    pub fn list_action_types_stream(
+        &self,
+        input: ListActionTypesInput,
+    ) -> impl futures::Stream<Item = Result<ActionType, RusotoError<ListActionTypesError>>> + Send
+    {
+        rusoto_core::pagination::items(self.list_action_types_pages(input), |page| {
+            page.action_types
+        })
+    }
+
+    /// The pages of `ListPipelineExecutions` results for `input`, requesting each next page with the `nextToken` of the last one as its `nextToken`.
+    /// The page size is the `maxResults` of `input`.
+    pub fn list_pipeline_executions_pages(
+        &self,
+        input: ListPipelineExecutionsInput,
+    ) -> impl futures::Stream<
+        Item = Result<ListPipelineExecutionsOutput, RusotoError<ListPipelineExecutionsError>>,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: CodePipelineClient, input| async move {
+                client.list_pipeline_executions(input).await
+            },
+            |input: &ListPipelineExecutionsInput, output: &ListPipelineExecutionsOutput| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListPipelineExecutionsInput {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `pipelineExecutionSummaries` of every page of `ListPipelineExecutions` results for `input`, as `list_pipeline_executions_pages` requests them.
+    pub fn list_pipeline_executions_stream(
+        &self,
+        input: ListPipelineExecutionsInput,
+    ) -> impl futures::Stream<
+        Item = Result<PipelineExecutionSummary, RusotoError<ListPipelineExecutionsError>>,
+    > + Send {
+        rusoto_core::pagination::items(self.list_pipeline_executions_pages(input), |page| {
+            page.pipeline_execution_summaries.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `ListPipelines` results for `input`, requesting each next page with the `nextToken` of the last one as its `nextToken`.
+    /// The page size is the `maxResults` of `input`.
+    pub fn list_pipelines_pages(
+        &self,
+        input: ListPipelinesInput,
+    ) -> impl futures::Stream<Item = Result<ListPipelinesOutput, RusotoError<ListPipelinesError>>> + Send
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: CodePipelineClient, input| async move { client.list_pipelines(input).await },
+            |input: &ListPipelinesInput, output: &ListPipelinesOutput| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListPipelinesInput {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `pipelines` of every page of `ListPipelines` results for `input`, as `list_pipelines_pages` requests them.
+    pub fn list_pipelines_stream(
+        &self,
+        input: ListPipelinesInput,
+    ) -> impl futures::Stream<Item = Result<PipelineSummary, RusotoError<ListPipelinesError>>> + Send
+    {
+        rusoto_core::pagination::items(self.list_pipelines_pages(input), |page| {
+            page.pipelines.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `ListTagsForResource` results for `input`, requesting each next page with the `nextToken` of the last one as its `nextToken`.
+    /// The page size is the `maxResults` of `input`.
+    pub fn list_tags_for_resource_pages(
+        &self,
+        input: ListTagsForResourceInput,
+    ) -> impl futures::Stream<
+        Item = Result<ListTagsForResourceOutput, RusotoError<ListTagsForResourceError>>,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: CodePipelineClient, input| async move {
+                client.list_tags_for_resource(input).await
+            },
+            |input: &ListTagsForResourceInput, output: &ListTagsForResourceOutput| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListTagsForResourceInput {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `tags` of every page of `ListTagsForResource` results for `input`, as `list_tags_for_resource_pages` requests them.
+    pub fn list_tags_for_resource_stream(
+        &self,
+        input: ListTagsForResourceInput,
+    ) -> impl futures::Stream<Item = Result<Tag, RusotoError<ListTagsForResourceError>>> + Send
+    {
+        rusoto_core::pagination::items(self.list_tags_for_resource_pages(input), |page| {
+            page.tags.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `ListWebhooks` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `MaxResults` of `input`.
+    pub fn list_webhooks_pages(
+        &self,
+        input: ListWebhooksInput,
+    ) -> impl futures::Stream<Item = Result<ListWebhooksOutput, RusotoError<ListWebhooksError>>> + Send
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: CodePipelineClient, input| async move { client.list_webhooks(input).await },
+            |input: &ListWebhooksInput, output: &ListWebhooksOutput| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListWebhooksInput {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `webhooks` of every page of `ListWebhooks` results for `input`, as `list_webhooks_pages` requests them.
+    pub fn list_webhooks_stream(
+        &self,
+        input: ListWebhooksInput,
+    ) -> impl futures::Stream<Item = Result<ListWebhookItem, RusotoError<ListWebhooksError>>> + Send
+    {
+        rusoto_core::pagination::items(self.list_webhooks_pages(input), |page| {
+            page.webhooks.unwrap_or_default()
+        })
+    }
+}
+
+#[async_trait]
+impl CodePipeline for CodePipelineClient {
+    /// <p>Returns information about a specified job and whether that job has been received by the job worker. Used for custom actions only.</p>
+    async fn acknowledge_job(
+        &self,
+        input: AcknowledgeJobInput,
+    ) -> Result<AcknowledgeJobOutput, RusotoError<AcknowledgeJobError>> {
+        self.acknowledge_job_with_metadata(input)
             .await
-            .map_err(RusotoError::from)?;
-        if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<UpdatePipelineOutput, _>()
-        } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(UpdatePipelineError::from_response(response))
-        }
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Confirms a job worker has received the specified job. Used for partner actions only.</p>
+    async fn acknowledge_third_party_job(
+        &self,
+        input: AcknowledgeThirdPartyJobInput,
+    ) -> Result<AcknowledgeThirdPartyJobOutput, RusotoError<AcknowledgeThirdPartyJobError>> {
+        self.acknowledge_third_party_job_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Creates a new custom action that can be used in all pipelines associated with the AWS account. Only used for custom actions.</p>
+    async fn create_custom_action_type(
+        &self,
+        input: CreateCustomActionTypeInput,
+    ) -> Result<CreateCustomActionTypeOutput, RusotoError<CreateCustomActionTypeError>> {
+        self.create_custom_action_type_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Creates a pipeline.</p> <note> <p>In the pipeline structure, you must include either <code>artifactStore</code> or <code>artifactStores</code> in your pipeline, but you cannot use both. If you create a cross-region action in your pipeline, you must use <code>artifactStores</code>.</p> </note></p>
+    async fn create_pipeline(
+        &self,
+        input: CreatePipelineInput,
+    ) -> Result<CreatePipelineOutput, RusotoError<CreatePipelineError>> {
+        self.create_pipeline_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Marks a custom action as deleted. <code>PollForJobs</code> for the custom action fails after the action is marked for deletion. Used for custom actions only.</p> <important> <p>To re-create a custom action after it has been deleted you must use a string in the version field that has never been used before. This string can be an incremented version number, for example. To restore a deleted custom action, use a JSON file that is identical to the deleted action, including the original string in the version field.</p> </important></p>
+    async fn delete_custom_action_type(
+        &self,
+        input: DeleteCustomActionTypeInput,
+    ) -> Result<(), RusotoError<DeleteCustomActionTypeError>> {
+        self.delete_custom_action_type_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes the specified pipeline.</p>
+    async fn delete_pipeline(
+        &self,
+        input: DeletePipelineInput,
+    ) -> Result<(), RusotoError<DeletePipelineError>> {
+        self.delete_pipeline_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes a previously created webhook by name. Deleting the webhook stops AWS CodePipeline from starting a pipeline every time an external event occurs. The API returns successfully when trying to delete a webhook that is already deleted. If a deleted webhook is re-created by calling PutWebhook with the same name, it will have a different URL.</p>
+    async fn delete_webhook(
+        &self,
+        input: DeleteWebhookInput,
+    ) -> Result<DeleteWebhookOutput, RusotoError<DeleteWebhookError>> {
+        self.delete_webhook_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Removes the connection between the webhook that was created by CodePipeline and the external tool with events to be detected. Currently supported only for webhooks that target an action type of GitHub.</p>
+    async fn deregister_webhook_with_third_party(
+        &self,
+        input: DeregisterWebhookWithThirdPartyInput,
+    ) -> Result<
+        DeregisterWebhookWithThirdPartyOutput,
+        RusotoError<DeregisterWebhookWithThirdPartyError>,
+    > {
+        self.deregister_webhook_with_third_party_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Prevents artifacts in a pipeline from transitioning to the next stage in the pipeline.</p>
+    async fn disable_stage_transition(
+        &self,
+        input: DisableStageTransitionInput,
+    ) -> Result<(), RusotoError<DisableStageTransitionError>> {
+        self.disable_stage_transition_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Enables artifacts in a pipeline to transition to a stage in a pipeline.</p>
+    async fn enable_stage_transition(
+        &self,
+        input: EnableStageTransitionInput,
+    ) -> Result<(), RusotoError<EnableStageTransitionError>> {
+        self.enable_stage_transition_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Returns information about a job. Used for custom actions only.</p> <important> <p>When this API is called, AWS CodePipeline returns temporary credentials for the S3 bucket used to store artifacts for the pipeline, if the action requires access to that S3 bucket for input or output artifacts. This API also returns any secret values defined for the action.</p> </important></p>
+    async fn get_job_details(
+        &self,
+        input: GetJobDetailsInput,
+    ) -> Result<GetJobDetailsOutput, RusotoError<GetJobDetailsError>> {
+        self.get_job_details_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns the metadata, structure, stages, and actions of a pipeline. Can be used to return the entire structure of a pipeline in JSON format, which can then be modified and used to update the pipeline structure with <a>UpdatePipeline</a>.</p>
+    async fn get_pipeline(
+        &self,
+        input: GetPipelineInput,
+    ) -> Result<GetPipelineOutput, RusotoError<GetPipelineError>> {
+        self.get_pipeline_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns information about an execution of a pipeline, including details about artifacts, the pipeline execution ID, and the name, version, and status of the pipeline.</p>
+    async fn get_pipeline_execution(
+        &self,
+        input: GetPipelineExecutionInput,
+    ) -> Result<GetPipelineExecutionOutput, RusotoError<GetPipelineExecutionError>> {
+        self.get_pipeline_execution_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Returns information about the state of a pipeline, including the stages and actions.</p> <note> <p>Values returned in the <code>revisionId</code> and <code>revisionUrl</code> fields indicate the source revision information, such as the commit ID, for the current state.</p> </note></p>
+    async fn get_pipeline_state(
+        &self,
+        input: GetPipelineStateInput,
+    ) -> Result<GetPipelineStateOutput, RusotoError<GetPipelineStateError>> {
+        self.get_pipeline_state_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Requests the details of a job for a third party action. Used for partner actions only.</p> <important> <p>When this API is called, AWS CodePipeline returns temporary credentials for the S3 bucket used to store artifacts for the pipeline, if the action requires access to that S3 bucket for input or output artifacts. This API also returns any secret values defined for the action.</p> </important></p>
+    async fn get_third_party_job_details(
+        &self,
+        input: GetThirdPartyJobDetailsInput,
+    ) -> Result<GetThirdPartyJobDetailsOutput, RusotoError<GetThirdPartyJobDetailsError>> {
+        self.get_third_party_job_details_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Lists the action executions that have occurred in a pipeline.</p>
+    async fn list_action_executions(
+        &self,
+        input: ListActionExecutionsInput,
+    ) -> Result<ListActionExecutionsOutput, RusotoError<ListActionExecutionsError>> {
+        self.list_action_executions_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Gets a summary of all AWS CodePipeline action types associated with your account.</p>
+    async fn list_action_types(
+        &self,
+        input: ListActionTypesInput,
+    ) -> Result<ListActionTypesOutput, RusotoError<ListActionTypesError>> {
+        self.list_action_types_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Gets a summary of the most recent executions for a pipeline.</p>
+    async fn list_pipeline_executions(
+        &self,
+        input: ListPipelineExecutionsInput,
+    ) -> Result<ListPipelineExecutionsOutput, RusotoError<ListPipelineExecutionsError>> {
+        self.list_pipeline_executions_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Gets a summary of all of the pipelines associated with your account.</p>
+    async fn list_pipelines(
+        &self,
+        input: ListPipelinesInput,
+    ) -> Result<ListPipelinesOutput, RusotoError<ListPipelinesError>> {
+        self.list_pipelines_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Gets the set of key-value pairs (metadata) that are used to manage the resource.</p>
+    async fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceInput,
+    ) -> Result<ListTagsForResourceOutput, RusotoError<ListTagsForResourceError>> {
+        self.list_tags_for_resource_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Gets a listing of all the webhooks in this AWS Region for this account. The output lists all webhooks and includes the webhook URL and ARN and the configuration for each webhook.</p>
+    async fn list_webhooks(
+        &self,
+        input: ListWebhooksInput,
+    ) -> Result<ListWebhooksOutput, RusotoError<ListWebhooksError>> {
+        self.list_webhooks_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Returns information about any jobs for AWS CodePipeline to act on. <code>PollForJobs</code> is valid only for action types with &quot;Custom&quot; in the owner field. If the action type contains &quot;AWS&quot; or &quot;ThirdParty&quot; in the owner field, the <code>PollForJobs</code> action returns an error.</p> <important> <p>When this API is called, AWS CodePipeline returns temporary credentials for the S3 bucket used to store artifacts for the pipeline, if the action requires access to that S3 bucket for input or output artifacts. This API also returns any secret values defined for the action.</p> </important></p>
+    async fn poll_for_jobs(
+        &self,
+        input: PollForJobsInput,
+    ) -> Result<PollForJobsOutput, RusotoError<PollForJobsError>> {
+        self.poll_for_jobs_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Determines whether there are any third party jobs for a job worker to act on. Used for partner actions only.</p> <important> <p>When this API is called, AWS CodePipeline returns temporary credentials for the S3 bucket used to store artifacts for the pipeline, if the action requires access to that S3 bucket for input or output artifacts.</p> </important></p>
+    async fn poll_for_third_party_jobs(
+        &self,
+        input: PollForThirdPartyJobsInput,
+    ) -> Result<PollForThirdPartyJobsOutput, RusotoError<PollForThirdPartyJobsError>> {
+        self.poll_for_third_party_jobs_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Provides information to AWS CodePipeline about new revisions to a source.</p>
+    async fn put_action_revision(
+        &self,
+        input: PutActionRevisionInput,
+    ) -> Result<PutActionRevisionOutput, RusotoError<PutActionRevisionError>> {
+        self.put_action_revision_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Provides the response to a manual approval request to AWS CodePipeline. Valid responses include Approved and Rejected.</p>
+    async fn put_approval_result(
+        &self,
+        input: PutApprovalResultInput,
+    ) -> Result<PutApprovalResultOutput, RusotoError<PutApprovalResultError>> {
+        self.put_approval_result_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Represents the failure of a job as returned to the pipeline by a job worker. Used for custom actions only.</p>
+    async fn put_job_failure_result(
+        &self,
+        input: PutJobFailureResultInput,
+    ) -> Result<(), RusotoError<PutJobFailureResultError>> {
+        self.put_job_failure_result_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Represents the success of a job as returned to the pipeline by a job worker. Used for custom actions only.</p>
+    async fn put_job_success_result(
+        &self,
+        input: PutJobSuccessResultInput,
+    ) -> Result<(), RusotoError<PutJobSuccessResultError>> {
+        self.put_job_success_result_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Represents the failure of a third party job as returned to the pipeline by a job worker. Used for partner actions only.</p>
+    async fn put_third_party_job_failure_result(
+        &self,
+        input: PutThirdPartyJobFailureResultInput,
+    ) -> Result<(), RusotoError<PutThirdPartyJobFailureResultError>> {
+        self.put_third_party_job_failure_result_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Represents the success of a third party job as returned to the pipeline by a job worker. Used for partner actions only.</p>
+    async fn put_third_party_job_success_result(
+        &self,
+        input: PutThirdPartyJobSuccessResultInput,
+    ) -> Result<(), RusotoError<PutThirdPartyJobSuccessResultError>> {
+        self.put_third_party_job_success_result_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Defines a webhook and returns a unique webhook URL generated by CodePipeline. This URL can be supplied to third party source hosting providers to call every time there's a code change. When CodePipeline receives a POST request on this URL, the pipeline defined in the webhook is started as long as the POST request satisfied the authentication and filtering requirements supplied when defining the webhook. RegisterWebhookWithThirdParty and DeregisterWebhookWithThirdParty APIs can be used to automatically configure supported third parties to call the generated webhook URL.</p>
+    async fn put_webhook(
+        &self,
+        input: PutWebhookInput,
+    ) -> Result<PutWebhookOutput, RusotoError<PutWebhookError>> {
+        self.put_webhook_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Configures a connection between the webhook that was created and the external tool with events to be detected.</p>
+    async fn register_webhook_with_third_party(
+        &self,
+        input: RegisterWebhookWithThirdPartyInput,
+    ) -> Result<RegisterWebhookWithThirdPartyOutput, RusotoError<RegisterWebhookWithThirdPartyError>>
+    {
+        self.register_webhook_with_third_party_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Resumes the pipeline execution by retrying the last failed actions in a stage. You can retry a stage immediately if any of the actions in the stage fail. When you retry, all actions that are still in progress continue working, and failed actions are triggered again.</p>
+    async fn retry_stage_execution(
+        &self,
+        input: RetryStageExecutionInput,
+    ) -> Result<RetryStageExecutionOutput, RusotoError<RetryStageExecutionError>> {
+        self.retry_stage_execution_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Starts the specified pipeline. Specifically, it begins processing the latest commit to the source location specified as part of the pipeline.</p>
+    async fn start_pipeline_execution(
+        &self,
+        input: StartPipelineExecutionInput,
+    ) -> Result<StartPipelineExecutionOutput, RusotoError<StartPipelineExecutionError>> {
+        self.start_pipeline_execution_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Stops the specified pipeline execution. You choose to either stop the pipeline execution by completing in-progress actions without starting subsequent actions, or by abandoning in-progress actions. While completing or abandoning in-progress actions, the pipeline execution is in a <code>Stopping</code> state. After all in-progress actions are completed or abandoned, the pipeline execution is in a <code>Stopped</code> state.</p>
+    async fn stop_pipeline_execution(
+        &self,
+        input: StopPipelineExecutionInput,
+    ) -> Result<StopPipelineExecutionOutput, RusotoError<StopPipelineExecutionError>> {
+        self.stop_pipeline_execution_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Adds to or modifies the tags of the given resource. Tags are metadata that can be used to manage a resource. </p>
+    async fn tag_resource(
+        &self,
+        input: TagResourceInput,
+    ) -> Result<TagResourceOutput, RusotoError<TagResourceError>> {
+        self.tag_resource_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Removes tags from an AWS resource.</p>
+    async fn untag_resource(
+        &self,
+        input: UntagResourceInput,
+    ) -> Result<UntagResourceOutput, RusotoError<UntagResourceError>> {
+        self.untag_resource_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Updates a specified pipeline with edits or changes to its structure. Use a JSON file with the pipeline structure and <code>UpdatePipeline</code> to provide the full structure of the pipeline. Updating the pipeline increases the version number of the pipeline by 1.</p>
+    async fn update_pipeline(
+        &self,
+        input: UpdatePipelineInput,
+    ) -> Result<UpdatePipelineOutput, RusotoError<UpdatePipelineError>> {
+        self.update_pipeline_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
     }
 }
@@ -16,8 +16,8 @@ use std::fmt;
 use async_trait::async_trait;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
-use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest, ResponseMetadata};
+use rusoto_core::{Client, ErrorMetadata, RusotoError};
 
 use rusoto_core::proto;
 use rusoto_core::signature::SignedRequest;
@@ -130,9 +130,12 @@ pub struct ListConnectionsOutput {
 
 /// Errors returned by CreateConnection
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateConnectionError {
     /// <p>Exceeded the maximum limit for connections.</p>
     LimitExceeded(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateConnectionError {
@@ -140,10 +143,13 @@ impl CreateConnectionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateConnectionError::LimitExceeded(err.msg))
+                    return RusotoError::service(
+                        CreateConnectionError::LimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreateConnectionError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -154,15 +160,19 @@ impl fmt::Display for CreateConnectionError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             CreateConnectionError::LimitExceeded(ref cause) => write!(f, "{}", cause),
+            CreateConnectionError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateConnectionError {}
 /// Errors returned by DeleteConnection
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteConnectionError {
     /// <p>Resource not found. Verify the connection resource ARN and try again.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteConnectionError {
@@ -170,10 +180,13 @@ impl DeleteConnectionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteConnectionError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        DeleteConnectionError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteConnectionError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -184,15 +197,19 @@ impl fmt::Display for DeleteConnectionError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             DeleteConnectionError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            DeleteConnectionError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteConnectionError {}
 /// Errors returned by GetConnection
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetConnectionError {
     /// <p>Resource not found. Verify the connection resource ARN and try again.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetConnectionError {
@@ -200,10 +217,13 @@ impl GetConnectionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetConnectionError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        GetConnectionError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetConnectionError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -214,20 +234,25 @@ impl fmt::Display for GetConnectionError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             GetConnectionError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            GetConnectionError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetConnectionError {}
 /// Errors returned by ListConnections
 #[derive(Debug, PartialEq)]
-pub enum ListConnectionsError {}
+#[non_exhaustive]
+pub enum ListConnectionsError {
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
+}
 
 impl ListConnectionsError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<ListConnectionsError> {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListConnectionsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -236,7 +261,9 @@ impl ListConnectionsError {
 impl fmt::Display for ListConnectionsError {
     #[allow(unused_variables)]
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        match *self {}
+        match *self {
+            ListConnectionsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
+        }
     }
 }
 impl Error for ListConnectionsError {}
@@ -278,6 +305,7 @@ impl CodeStarConnectionsClient {
     /// Creates a client backed by the default tokio event loop.
     ///
     /// The client will use the default credentials provider and tls client.
+    #[cfg(not(target_arch = "wasm32"))]
     pub fn new(region: region::Region) -> CodeStarConnectionsClient {
         CodeStarConnectionsClient {
             client: Client::shared(),
@@ -303,16 +331,68 @@ impl CodeStarConnectionsClient {
     pub fn new_with_client(client: Client, region: region::Region) -> CodeStarConnectionsClient {
         CodeStarConnectionsClient { client, region }
     }
+
+    /// Returns a builder of a client, setting any of its region, credentials provider,
+    /// dispatcher, retry policy, endpoint and timeouts.
+    pub fn builder() -> rusoto_core::ClientBuilder<CodeStarConnectionsClient> {
+        rusoto_core::ClientBuilder::new(CodeStarConnectionsClient::new_with_client)
+    }
+
+    /// Returns a clone of this client with the overrides of `config` applied to its
+    /// operations, sharing its connections.
+    pub fn customize(&self, config: &rusoto_core::OperationConfig) -> CodeStarConnectionsClient {
+        CodeStarConnectionsClient {
+            client: self.client.customize(config),
+            region: config.resolve_region(&self.region),
+        }
+    }
 }
 
-#[async_trait]
-impl CodeStarConnections for CodeStarConnectionsClient {
-    /// <p>Creates a connection that can then be given to other AWS services like CodePipeline so that it can access third-party code repositories. The connection is in pending status until the third-party connection handshake is completed from the console.</p>
-    async fn create_connection(
+impl CodeStarConnectionsClient {
+    /// Calls `create_connection`, also returning the metadata of the response.
+    pub async fn create_connection_with_metadata(
         &self,
         input: CreateConnectionInput,
-    ) -> Result<CreateConnectionOutput, RusotoError<CreateConnectionError>> {
+    ) -> Result<(CreateConnectionOutput, ResponseMetadata), RusotoError<CreateConnectionError>>
+    {
+        let request = self.build_create_connection_request(input);
+
+        let mut response = self
+            .client
+            .sign_and_dispatch(request)
+            .await
+            .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
+        if response.status.is_success() {
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<CreateConnectionOutput, _>()?;
+            Ok((result, metadata))
+        } else {
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(CreateConnectionError::from_response(response))
+        }
+    }
+
+    /// Presigns the request `create_connection` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_connection(
+        &self,
+        presigner: &rusoto_core::Presigner,
+        input: CreateConnectionInput,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_connection_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_connection_request(&self, input: CreateConnectionInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "codestar-connections", &self.region, "/");
+        request.set_operation("CreateConnection");
 
         request.set_content_type("application/x-amz-json-1.0".to_owned());
         request.add_header(
@@ -322,27 +402,53 @@ impl CodeStarConnections for CodeStarConnectionsClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `delete_connection`, also returning the metadata of the response.
+    pub async fn delete_connection_with_metadata(
+        &self,
+        input: DeleteConnectionInput,
+    ) -> Result<(DeleteConnectionOutput, ResponseMetadata), RusotoError<DeleteConnectionError>>
+    {
+        let request = self.build_delete_connection_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<CreateConnectionOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DeleteConnectionOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(CreateConnectionError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DeleteConnectionError::from_response(response))
         }
     }
 
-    /// <p>The connection to be deleted.</p>
-    async fn delete_connection(
+    /// Presigns the request `delete_connection` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_connection(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteConnectionInput,
-    ) -> Result<DeleteConnectionOutput, RusotoError<DeleteConnectionError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_connection_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_connection_request(&self, input: DeleteConnectionInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "codestar-connections", &self.region, "/");
+        request.set_operation("DeleteConnection");
 
         request.set_content_type("application/x-amz-json-1.0".to_owned());
         request.add_header(
@@ -352,27 +458,52 @@ impl CodeStarConnections for CodeStarConnectionsClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `get_connection`, also returning the metadata of the response.
+    pub async fn get_connection_with_metadata(
+        &self,
+        input: GetConnectionInput,
+    ) -> Result<(GetConnectionOutput, ResponseMetadata), RusotoError<GetConnectionError>> {
+        let request = self.build_get_connection_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<DeleteConnectionOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<GetConnectionOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteConnectionError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(GetConnectionError::from_response(response))
         }
     }
 
-    /// <p>Returns the connection ARN and details such as status, owner, and provider type.</p>
-    async fn get_connection(
+    /// Presigns the request `get_connection` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_connection(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetConnectionInput,
-    ) -> Result<GetConnectionOutput, RusotoError<GetConnectionError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_connection_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_connection_request(&self, input: GetConnectionInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "codestar-connections", &self.region, "/");
+        request.set_operation("GetConnection");
 
         request.set_content_type("application/x-amz-json-1.0".to_owned());
         request.add_header(
@@ -382,27 +513,52 @@ impl CodeStarConnections for CodeStarConnectionsClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `list_connections`, also returning the metadata of the response.
+    pub async fn list_connections_with_metadata(
+        &self,
+        input: ListConnectionsInput,
+    ) -> Result<(ListConnectionsOutput, ResponseMetadata), RusotoError<ListConnectionsError>> {
+        let request = self.build_list_connections_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<GetConnectionOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ListConnectionsOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(GetConnectionError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ListConnectionsError::from_response(response))
         }
     }
 
-    /// <p>Lists the connections associated with your account.</p>
-    async fn list_connections(
+    /// Presigns the request `list_connections` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_connections(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListConnectionsInput,
-    ) -> Result<ListConnectionsOutput, RusotoError<ListConnectionsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_connections_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_connections_request(&self, input: ListConnectionsInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "codestar-connections", &self.region, "/");
+        request.set_operation("ListConnections");
 
         request.set_content_type("application/x-amz-json-1.0".to_owned());
         request.add_header(
@@ -412,18 +568,49 @@ impl CodeStarConnections for CodeStarConnectionsClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
-        let mut response = self
-            .client
-            .sign_and_dispatch(request)
+        request
+    }
+}
+
+#[async_trait]
+impl CodeStarConnections for CodeStarConnectionsClient {
+    /// <p>Creates a connection that can then be given to other AWS services like CodePipeline so that it can access third-party code repositories. The connection is in pending status until the third-party connection handshake is completed from the console.</p>
+    async fn create_connection(
+        &self,
+        input: CreateConnectionInput,
+    ) -> Result<CreateConnectionOutput, RusotoError<CreateConnectionError>> {
+        self.create_connection_with_metadata(input)
             .await
-            .map_err(RusotoError::from)?;
-        if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<ListConnectionsOutput, _>()
-        } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ListConnectionsError::from_response(response))
-        }
+            .map(|(output, _)| output)
+    }
+
+    /// <p>The connection to be deleted.</p>
+    async fn delete_connection(
+        &self,
+        input: DeleteConnectionInput,
+    ) -> Result<DeleteConnectionOutput, RusotoError<DeleteConnectionError>> {
+        self.delete_connection_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns the connection ARN and details such as status, owner, and provider type.</p>
+    async fn get_connection(
+        &self,
+        input: GetConnectionInput,
+    ) -> Result<GetConnectionOutput, RusotoError<GetConnectionError>> {
+        self.get_connection_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Lists the connections associated with your account.</p>
+    async fn list_connections(
+        &self,
+        input: ListConnectionsInput,
+    ) -> Result<ListConnectionsOutput, RusotoError<ListConnectionsError>> {
+        self.list_connections_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
     }
 }
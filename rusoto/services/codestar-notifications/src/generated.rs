@@ -16,8 +16,8 @@ use std::fmt;
 use async_trait::async_trait;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
-use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest, ResponseMetadata};
+use rusoto_core::{Client, ErrorMetadata, RusotoError};
 
 use rusoto_core::proto;
 use rusoto_core::signature::SignedRequest;
@@ -476,6 +476,7 @@ pub struct UpdateNotificationRuleResult {}
 
 /// Errors returned by CreateNotificationRule
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateNotificationRuleError {
     /// <p>AWS CodeStar Notifications can't create the notification rule because you do not have sufficient permissions.</p>
     AccessDenied(String),
@@ -487,6 +488,8 @@ pub enum CreateNotificationRuleError {
     LimitExceeded(String),
     /// <p>A resource with the same name or ID already exists. Notification rule names must be unique in your AWS account.</p>
     ResourceAlreadyExists(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateNotificationRuleError {
@@ -494,30 +497,37 @@ impl CreateNotificationRuleError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(CreateNotificationRuleError::AccessDenied(err.msg))
+                    return RusotoError::service(
+                        CreateNotificationRuleError::AccessDenied(err.msg),
+                        &res,
+                    )
                 }
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         CreateNotificationRuleError::ConcurrentModification(err.msg),
+                        &res,
                     )
                 }
                 "ConfigurationException" => {
-                    return RusotoError::Service(CreateNotificationRuleError::Configuration(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateNotificationRuleError::Configuration(err.msg),
+                        &res,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateNotificationRuleError::LimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateNotificationRuleError::LimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceAlreadyExistsException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         CreateNotificationRuleError::ResourceAlreadyExists(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreateNotificationRuleError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -534,17 +544,21 @@ impl fmt::Display for CreateNotificationRuleError {
             CreateNotificationRuleError::Configuration(ref cause) => write!(f, "{}", cause),
             CreateNotificationRuleError::LimitExceeded(ref cause) => write!(f, "{}", cause),
             CreateNotificationRuleError::ResourceAlreadyExists(ref cause) => write!(f, "{}", cause),
+            CreateNotificationRuleError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateNotificationRuleError {}
 /// Errors returned by DeleteNotificationRule
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteNotificationRuleError {
     /// <p>AWS CodeStar Notifications can't complete the request because the resource is being modified by another process. Wait a few minutes and try again.</p>
     ConcurrentModification(String),
     /// <p>One of the AWS CodeStar Notifications limits has been exceeded. Limits apply to accounts, notification rules, notifications, resources, and targets. For more information, see Limits.</p>
     LimitExceeded(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteNotificationRuleError {
@@ -552,17 +566,19 @@ impl DeleteNotificationRuleError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DeleteNotificationRuleError::ConcurrentModification(err.msg),
+                        &res,
                     )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(DeleteNotificationRuleError::LimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteNotificationRuleError::LimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteNotificationRuleError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -576,20 +592,25 @@ impl fmt::Display for DeleteNotificationRuleError {
                 write!(f, "{}", cause)
             }
             DeleteNotificationRuleError::LimitExceeded(ref cause) => write!(f, "{}", cause),
+            DeleteNotificationRuleError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteNotificationRuleError {}
 /// Errors returned by DeleteTarget
 #[derive(Debug, PartialEq)]
-pub enum DeleteTargetError {}
+#[non_exhaustive]
+pub enum DeleteTargetError {
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
+}
 
 impl DeleteTargetError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<DeleteTargetError> {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteTargetError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -598,15 +619,20 @@ impl DeleteTargetError {
 impl fmt::Display for DeleteTargetError {
     #[allow(unused_variables)]
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        match *self {}
+        match *self {
+            DeleteTargetError::Unhandled(ref metadata) => write!(f, "{}", metadata),
+        }
     }
 }
 impl Error for DeleteTargetError {}
 /// Errors returned by DescribeNotificationRule
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeNotificationRuleError {
     /// <p>AWS CodeStar Notifications can't find a resource that matches the provided ARN. </p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeNotificationRuleError {
@@ -614,12 +640,13 @@ impl DescribeNotificationRuleError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeNotificationRuleError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeNotificationRuleError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DescribeNotificationRuleError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -630,15 +657,19 @@ impl fmt::Display for DescribeNotificationRuleError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             DescribeNotificationRuleError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            DescribeNotificationRuleError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeNotificationRuleError {}
 /// Errors returned by ListEventTypes
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListEventTypesError {
     /// <p>The value for the enumeration token used in the request to return the next batch of the results is not valid. </p>
     InvalidNextToken(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListEventTypesError {
@@ -646,10 +677,13 @@ impl ListEventTypesError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(ListEventTypesError::InvalidNextToken(err.msg))
+                    return RusotoError::service(
+                        ListEventTypesError::InvalidNextToken(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListEventTypesError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -660,15 +694,19 @@ impl fmt::Display for ListEventTypesError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             ListEventTypesError::InvalidNextToken(ref cause) => write!(f, "{}", cause),
+            ListEventTypesError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListEventTypesError {}
 /// Errors returned by ListNotificationRules
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListNotificationRulesError {
     /// <p>The value for the enumeration token used in the request to return the next batch of the results is not valid. </p>
     InvalidNextToken(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListNotificationRulesError {
@@ -676,12 +714,13 @@ impl ListNotificationRulesError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(ListNotificationRulesError::InvalidNextToken(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListNotificationRulesError::InvalidNextToken(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListNotificationRulesError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -692,15 +731,19 @@ impl fmt::Display for ListNotificationRulesError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             ListNotificationRulesError::InvalidNextToken(ref cause) => write!(f, "{}", cause),
+            ListNotificationRulesError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListNotificationRulesError {}
 /// Errors returned by ListTagsForResource
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListTagsForResourceError {
     /// <p>AWS CodeStar Notifications can't find a resource that matches the provided ARN. </p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListTagsForResourceError {
@@ -708,12 +751,13 @@ impl ListTagsForResourceError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListTagsForResourceError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListTagsForResourceError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListTagsForResourceError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -724,15 +768,19 @@ impl fmt::Display for ListTagsForResourceError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             ListTagsForResourceError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            ListTagsForResourceError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListTagsForResourceError {}
 /// Errors returned by ListTargets
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListTargetsError {
     /// <p>The value for the enumeration token used in the request to return the next batch of the results is not valid. </p>
     InvalidNextToken(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListTargetsError {
@@ -740,10 +788,10 @@ impl ListTargetsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(ListTargetsError::InvalidNextToken(err.msg))
+                    return RusotoError::service(ListTargetsError::InvalidNextToken(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListTargetsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -754,15 +802,19 @@ impl fmt::Display for ListTargetsError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             ListTargetsError::InvalidNextToken(ref cause) => write!(f, "{}", cause),
+            ListTargetsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListTargetsError {}
 /// Errors returned by Subscribe
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum SubscribeError {
     /// <p>AWS CodeStar Notifications can't find a resource that matches the provided ARN. </p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl SubscribeError {
@@ -770,10 +822,10 @@ impl SubscribeError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(SubscribeError::ResourceNotFound(err.msg))
+                    return RusotoError::service(SubscribeError::ResourceNotFound(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(SubscribeError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -784,17 +836,21 @@ impl fmt::Display for SubscribeError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             SubscribeError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            SubscribeError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for SubscribeError {}
 /// Errors returned by TagResource
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum TagResourceError {
     /// <p>AWS CodeStar Notifications can't complete the request because the resource is being modified by another process. Wait a few minutes and try again.</p>
     ConcurrentModification(String),
     /// <p>AWS CodeStar Notifications can't find a resource that matches the provided ARN. </p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl TagResourceError {
@@ -802,13 +858,16 @@ impl TagResourceError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(TagResourceError::ConcurrentModification(err.msg))
+                    return RusotoError::service(
+                        TagResourceError::ConcurrentModification(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(TagResourceError::ResourceNotFound(err.msg))
+                    return RusotoError::service(TagResourceError::ResourceNotFound(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(TagResourceError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -820,20 +879,25 @@ impl fmt::Display for TagResourceError {
         match *self {
             TagResourceError::ConcurrentModification(ref cause) => write!(f, "{}", cause),
             TagResourceError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            TagResourceError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for TagResourceError {}
 /// Errors returned by Unsubscribe
 #[derive(Debug, PartialEq)]
-pub enum UnsubscribeError {}
+#[non_exhaustive]
+pub enum UnsubscribeError {
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
+}
 
 impl UnsubscribeError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<UnsubscribeError> {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UnsubscribeError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -842,17 +906,22 @@ impl UnsubscribeError {
 impl fmt::Display for UnsubscribeError {
     #[allow(unused_variables)]
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        match *self {}
+        match *self {
+            UnsubscribeError::Unhandled(ref metadata) => write!(f, "{}", metadata),
+        }
     }
 }
 impl Error for UnsubscribeError {}
 /// Errors returned by UntagResource
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UntagResourceError {
     /// <p>AWS CodeStar Notifications can't complete the request because the resource is being modified by another process. Wait a few minutes and try again.</p>
     ConcurrentModification(String),
     /// <p>AWS CodeStar Notifications can't find a resource that matches the provided ARN. </p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UntagResourceError {
@@ -860,15 +929,19 @@ impl UntagResourceError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(UntagResourceError::ConcurrentModification(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UntagResourceError::ConcurrentModification(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UntagResourceError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        UntagResourceError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UntagResourceError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -880,15 +953,19 @@ impl fmt::Display for UntagResourceError {
         match *self {
             UntagResourceError::ConcurrentModification(ref cause) => write!(f, "{}", cause),
             UntagResourceError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            UntagResourceError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for UntagResourceError {}
 /// Errors returned by UpdateNotificationRule
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdateNotificationRuleError {
     /// <p>AWS CodeStar Notifications can't find a resource that matches the provided ARN. </p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdateNotificationRuleError {
@@ -896,12 +973,13 @@ impl UpdateNotificationRuleError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateNotificationRuleError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateNotificationRuleError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UpdateNotificationRuleError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -912,6 +990,7 @@ impl fmt::Display for UpdateNotificationRuleError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             UpdateNotificationRuleError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            UpdateNotificationRuleError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
@@ -1008,6 +1087,7 @@ impl CodeStarNotificationsClient {
     /// Creates a client backed by the default tokio event loop.
     ///
     /// The client will use the default credentials provider and tls client.
+    #[cfg(not(target_arch = "wasm32"))]
     pub fn new(region: region::Region) -> CodeStarNotificationsClient {
         CodeStarNotificationsClient {
             client: Client::shared(),
@@ -1033,410 +1113,1017 @@ impl CodeStarNotificationsClient {
     pub fn new_with_client(client: Client, region: region::Region) -> CodeStarNotificationsClient {
         CodeStarNotificationsClient { client, region }
     }
+
+    /// Returns a builder of a client, setting any of its region, credentials provider,
+    /// dispatcher, retry policy, endpoint and timeouts.
+    pub fn builder() -> rusoto_core::ClientBuilder<CodeStarNotificationsClient> {
+        rusoto_core::ClientBuilder::new(CodeStarNotificationsClient::new_with_client)
+    }
+
+    /// Returns a clone of this client with the overrides of `config` applied to its
+    /// operations, sharing its connections.
+    pub fn customize(&self, config: &rusoto_core::OperationConfig) -> CodeStarNotificationsClient {
+        CodeStarNotificationsClient {
+            client: self.client.customize(config),
+            region: config.resolve_region(&self.region),
+        }
+    }
 }
 
-#[async_trait]
-impl CodeStarNotifications for CodeStarNotificationsClient {
-    /// <p>Creates a notification rule for a resource. The rule specifies the events you want notifications about and the targets (such as SNS topics) where you want to receive them.</p>
-    async fn create_notification_rule(
+impl CodeStarNotificationsClient {
+    /// Calls `create_notification_rule`, also returning the metadata of the response.
+    pub async fn create_notification_rule_with_metadata(
         &self,
         input: CreateNotificationRuleRequest,
-    ) -> Result<CreateNotificationRuleResult, RusotoError<CreateNotificationRuleError>> {
-        let request_uri = "/createNotificationRule";
-
-        let mut request =
-            SignedRequest::new("POST", "codestar-notifications", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let encoded = Some(serde_json::to_vec(&input).unwrap());
-        request.set_payload(encoded);
+    ) -> Result<
+        (CreateNotificationRuleResult, ResponseMetadata),
+        RusotoError<CreateNotificationRuleError>,
+    > {
+        let request = self.build_create_notification_rule_request(input);
 
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<CreateNotificationRuleResult, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             Err(CreateNotificationRuleError::from_response(response))
         }
     }
 
-    /// <p>Deletes a notification rule for a resource.</p>
-    async fn delete_notification_rule(
+    /// Presigns the request `create_notification_rule` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_notification_rule(
         &self,
-        input: DeleteNotificationRuleRequest,
-    ) -> Result<DeleteNotificationRuleResult, RusotoError<DeleteNotificationRuleError>> {
-        let request_uri = "/deleteNotificationRule";
+        presigner: &rusoto_core::Presigner,
+        input: CreateNotificationRuleRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_notification_rule_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_notification_rule_request(
+        &self,
+        input: CreateNotificationRuleRequest,
+    ) -> SignedRequest {
+        let request_uri = "/createNotificationRule";
 
         let mut request =
             SignedRequest::new("POST", "codestar-notifications", &self.region, &request_uri);
+        request.set_operation("CreateNotificationRule");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `delete_notification_rule`, also returning the metadata of the response.
+    pub async fn delete_notification_rule_with_metadata(
+        &self,
+        input: DeleteNotificationRuleRequest,
+    ) -> Result<
+        (DeleteNotificationRuleResult, ResponseMetadata),
+        RusotoError<DeleteNotificationRuleError>,
+    > {
+        let request = self.build_delete_notification_rule_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<DeleteNotificationRuleResult, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             Err(DeleteNotificationRuleError::from_response(response))
         }
     }
 
-    /// <p>Deletes a specified target for notifications.</p>
-    async fn delete_target(
+    /// Presigns the request `delete_notification_rule` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_notification_rule(
         &self,
-        input: DeleteTargetRequest,
-    ) -> Result<DeleteTargetResult, RusotoError<DeleteTargetError>> {
-        let request_uri = "/deleteTarget";
+        presigner: &rusoto_core::Presigner,
+        input: DeleteNotificationRuleRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_notification_rule_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_notification_rule_request(
+        &self,
+        input: DeleteNotificationRuleRequest,
+    ) -> SignedRequest {
+        let request_uri = "/deleteNotificationRule";
 
         let mut request =
             SignedRequest::new("POST", "codestar-notifications", &self.region, &request_uri);
+        request.set_operation("DeleteNotificationRule");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `delete_target`, also returning the metadata of the response.
+    pub async fn delete_target_with_metadata(
+        &self,
+        input: DeleteTargetRequest,
+    ) -> Result<(DeleteTargetResult, ResponseMetadata), RusotoError<DeleteTargetError>> {
+        let request = self.build_delete_target_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<DeleteTargetResult, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             Err(DeleteTargetError::from_response(response))
         }
     }
 
-    /// <p>Returns information about a specified notification rule.</p>
-    async fn describe_notification_rule(
+    /// Presigns the request `delete_target` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_target(
         &self,
-        input: DescribeNotificationRuleRequest,
-    ) -> Result<DescribeNotificationRuleResult, RusotoError<DescribeNotificationRuleError>> {
-        let request_uri = "/describeNotificationRule";
+        presigner: &rusoto_core::Presigner,
+        input: DeleteTargetRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_target_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_target_request(&self, input: DeleteTargetRequest) -> SignedRequest {
+        let request_uri = "/deleteTarget";
 
         let mut request =
             SignedRequest::new("POST", "codestar-notifications", &self.region, &request_uri);
+        request.set_operation("DeleteTarget");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `describe_notification_rule`, also returning the metadata of the response.
+    pub async fn describe_notification_rule_with_metadata(
+        &self,
+        input: DescribeNotificationRuleRequest,
+    ) -> Result<
+        (DescribeNotificationRuleResult, ResponseMetadata),
+        RusotoError<DescribeNotificationRuleError>,
+    > {
+        let request = self.build_describe_notification_rule_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<DescribeNotificationRuleResult, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             Err(DescribeNotificationRuleError::from_response(response))
         }
     }
 
-    /// <p>Returns information about the event types available for configuring notifications.</p>
-    async fn list_event_types(
+    /// Presigns the request `describe_notification_rule` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_notification_rule(
         &self,
-        input: ListEventTypesRequest,
-    ) -> Result<ListEventTypesResult, RusotoError<ListEventTypesError>> {
-        let request_uri = "/listEventTypes";
+        presigner: &rusoto_core::Presigner,
+        input: DescribeNotificationRuleRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_notification_rule_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_notification_rule_request(
+        &self,
+        input: DescribeNotificationRuleRequest,
+    ) -> SignedRequest {
+        let request_uri = "/describeNotificationRule";
 
         let mut request =
             SignedRequest::new("POST", "codestar-notifications", &self.region, &request_uri);
+        request.set_operation("DescribeNotificationRule");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `list_event_types`, also returning the metadata of the response.
+    pub async fn list_event_types_with_metadata(
+        &self,
+        input: ListEventTypesRequest,
+    ) -> Result<(ListEventTypesResult, ResponseMetadata), RusotoError<ListEventTypesError>> {
+        let request = self.build_list_event_types_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<ListEventTypesResult, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             Err(ListEventTypesError::from_response(response))
         }
     }
 
-    /// <p>Returns a list of the notification rules for an AWS account.</p>
-    async fn list_notification_rules(
+    /// Presigns the request `list_event_types` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_event_types(
         &self,
-        input: ListNotificationRulesRequest,
-    ) -> Result<ListNotificationRulesResult, RusotoError<ListNotificationRulesError>> {
-        let request_uri = "/listNotificationRules";
+        presigner: &rusoto_core::Presigner,
+        input: ListEventTypesRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_event_types_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_event_types_request(&self, input: ListEventTypesRequest) -> SignedRequest {
+        let request_uri = "/listEventTypes";
 
         let mut request =
             SignedRequest::new("POST", "codestar-notifications", &self.region, &request_uri);
+        request.set_operation("ListEventTypes");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `list_notification_rules`, also returning the metadata of the response.
+    pub async fn list_notification_rules_with_metadata(
+        &self,
+        input: ListNotificationRulesRequest,
+    ) -> Result<
+        (ListNotificationRulesResult, ResponseMetadata),
+        RusotoError<ListNotificationRulesError>,
+    > {
+        let request = self.build_list_notification_rules_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<ListNotificationRulesResult, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             Err(ListNotificationRulesError::from_response(response))
         }
     }
 
-    /// <p>Returns a list of the tags associated with a notification rule.</p>
-    async fn list_tags_for_resource(
+    /// Presigns the request `list_notification_rules` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_notification_rules(
         &self,
-        input: ListTagsForResourceRequest,
-    ) -> Result<ListTagsForResourceResult, RusotoError<ListTagsForResourceError>> {
-        let request_uri = "/listTagsForResource";
+        presigner: &rusoto_core::Presigner,
+        input: ListNotificationRulesRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_notification_rules_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_notification_rules_request(
+        &self,
+        input: ListNotificationRulesRequest,
+    ) -> SignedRequest {
+        let request_uri = "/listNotificationRules";
 
         let mut request =
             SignedRequest::new("POST", "codestar-notifications", &self.region, &request_uri);
+        request.set_operation("ListNotificationRules");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `list_tags_for_resource`, also returning the metadata of the response.
+    pub async fn list_tags_for_resource_with_metadata(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> Result<(ListTagsForResourceResult, ResponseMetadata), RusotoError<ListTagsForResourceError>>
+    {
+        let request = self.build_list_tags_for_resource_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<ListTagsForResourceResult, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             Err(ListTagsForResourceError::from_response(response))
         }
     }
 
-    /// <p>Returns a list of the notification rule targets for an AWS account.</p>
-    async fn list_targets(
+    /// Presigns the request `list_tags_for_resource` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_tags_for_resource(
         &self,
-        input: ListTargetsRequest,
-    ) -> Result<ListTargetsResult, RusotoError<ListTargetsError>> {
-        let request_uri = "/listTargets";
+        presigner: &rusoto_core::Presigner,
+        input: ListTagsForResourceRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_tags_for_resource_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_tags_for_resource_request(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> SignedRequest {
+        let request_uri = "/listTagsForResource";
 
         let mut request =
             SignedRequest::new("POST", "codestar-notifications", &self.region, &request_uri);
+        request.set_operation("ListTagsForResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `list_targets`, also returning the metadata of the response.
+    pub async fn list_targets_with_metadata(
+        &self,
+        input: ListTargetsRequest,
+    ) -> Result<(ListTargetsResult, ResponseMetadata), RusotoError<ListTargetsError>> {
+        let request = self.build_list_targets_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<ListTargetsResult, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             Err(ListTargetsError::from_response(response))
         }
     }
 
-    /// <p>Creates an association between a notification rule and an SNS topic so that the associated target can receive notifications when the events described in the rule are triggered.</p>
-    async fn subscribe(
+    /// Presigns the request `list_targets` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_targets(
         &self,
-        input: SubscribeRequest,
-    ) -> Result<SubscribeResult, RusotoError<SubscribeError>> {
-        let request_uri = "/subscribe";
+        presigner: &rusoto_core::Presigner,
+        input: ListTargetsRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_targets_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_targets_request(&self, input: ListTargetsRequest) -> SignedRequest {
+        let request_uri = "/listTargets";
 
         let mut request =
             SignedRequest::new("POST", "codestar-notifications", &self.region, &request_uri);
+        request.set_operation("ListTargets");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `subscribe`, also returning the metadata of the response.
+    pub async fn subscribe_with_metadata(
+        &self,
+        input: SubscribeRequest,
+    ) -> Result<(SubscribeResult, ResponseMetadata), RusotoError<SubscribeError>> {
+        let request = self.build_subscribe_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result =
                 proto::json::ResponsePayload::new(&response).deserialize::<SubscribeResult, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             Err(SubscribeError::from_response(response))
         }
     }
 
-    /// <p>Associates a set of provided tags with a notification rule.</p>
-    async fn tag_resource(
+    /// Presigns the request `subscribe` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_subscribe(
         &self,
-        input: TagResourceRequest,
-    ) -> Result<TagResourceResult, RusotoError<TagResourceError>> {
-        let request_uri = "/tagResource";
+        presigner: &rusoto_core::Presigner,
+        input: SubscribeRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_subscribe_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_subscribe_request(&self, input: SubscribeRequest) -> SignedRequest {
+        let request_uri = "/subscribe";
 
         let mut request =
             SignedRequest::new("POST", "codestar-notifications", &self.region, &request_uri);
+        request.set_operation("Subscribe");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `tag_resource`, also returning the metadata of the response.
+    pub async fn tag_resource_with_metadata(
+        &self,
+        input: TagResourceRequest,
+    ) -> Result<(TagResourceResult, ResponseMetadata), RusotoError<TagResourceError>> {
+        let request = self.build_tag_resource_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<TagResourceResult, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             Err(TagResourceError::from_response(response))
         }
     }
 
-    /// <p>Removes an association between a notification rule and an Amazon SNS topic so that subscribers to that topic stop receiving notifications when the events described in the rule are triggered.</p>
-    async fn unsubscribe(
+    /// Presigns the request `tag_resource` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_tag_resource(
         &self,
-        input: UnsubscribeRequest,
-    ) -> Result<UnsubscribeResult, RusotoError<UnsubscribeError>> {
-        let request_uri = "/unsubscribe";
+        presigner: &rusoto_core::Presigner,
+        input: TagResourceRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_tag_resource_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_tag_resource_request(&self, input: TagResourceRequest) -> SignedRequest {
+        let request_uri = "/tagResource";
 
         let mut request =
             SignedRequest::new("POST", "codestar-notifications", &self.region, &request_uri);
+        request.set_operation("TagResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `unsubscribe`, also returning the metadata of the response.
+    pub async fn unsubscribe_with_metadata(
+        &self,
+        input: UnsubscribeRequest,
+    ) -> Result<(UnsubscribeResult, ResponseMetadata), RusotoError<UnsubscribeError>> {
+        let request = self.build_unsubscribe_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<UnsubscribeResult, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             Err(UnsubscribeError::from_response(response))
         }
     }
 
-    /// <p>Removes the association between one or more provided tags and a notification rule.</p>
-    async fn untag_resource(
+    /// Presigns the request `unsubscribe` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_unsubscribe(
         &self,
-        input: UntagResourceRequest,
-    ) -> Result<UntagResourceResult, RusotoError<UntagResourceError>> {
-        let request_uri = "/untagResource";
+        presigner: &rusoto_core::Presigner,
+        input: UnsubscribeRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_unsubscribe_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_unsubscribe_request(&self, input: UnsubscribeRequest) -> SignedRequest {
+        let request_uri = "/unsubscribe";
 
         let mut request =
             SignedRequest::new("POST", "codestar-notifications", &self.region, &request_uri);
+        request.set_operation("Unsubscribe");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `untag_resource`, also returning the metadata of the response.
+    pub async fn untag_resource_with_metadata(
+        &self,
+        input: UntagResourceRequest,
+    ) -> Result<(UntagResourceResult, ResponseMetadata), RusotoError<UntagResourceError>> {
+        let request = self.build_untag_resource_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<UntagResourceResult, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             Err(UntagResourceError::from_response(response))
         }
     }
 
-    /// <p><p>Updates a notification rule for a resource. You can change the events that trigger the notification rule, the status of the rule, and the targets that receive the notifications.</p> <note> <p>To add or remove tags for a notification rule, you must use <a>TagResource</a> and <a>UntagResource</a>.</p> </note></p>
-    async fn update_notification_rule(
+    /// Presigns the request `untag_resource` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_untag_resource(
         &self,
-        input: UpdateNotificationRuleRequest,
-    ) -> Result<UpdateNotificationRuleResult, RusotoError<UpdateNotificationRuleError>> {
-        let request_uri = "/updateNotificationRule";
+        presigner: &rusoto_core::Presigner,
+        input: UntagResourceRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_untag_resource_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_untag_resource_request(&self, input: UntagResourceRequest) -> SignedRequest {
+        let request_uri = "/untagResource";
 
         let mut request =
             SignedRequest::new("POST", "codestar-notifications", &self.region, &request_uri);
+        request.set_operation("UntagResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `update_notification_rule`, also returning the metadata of the response.
+    pub async fn update_notification_rule_with_metadata(
+        &self,
+        input: UpdateNotificationRuleRequest,
+    ) -> Result<
+        (UpdateNotificationRuleResult, ResponseMetadata),
+        RusotoError<UpdateNotificationRuleError>,
+    > {
+        let request = self.build_update_notification_rule_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<UpdateNotificationRuleResult, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             Err(UpdateNotificationRuleError::from_response(response))
         }
     }
+
+    /// Presigns the request `update_notification_rule` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_notification_rule(
+        &self,
+        presigner: &rusoto_core::Presigner,
+        input: UpdateNotificationRuleRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_notification_rule_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_notification_rule_request(
+        &self,
+        input: UpdateNotificationRuleRequest,
+    ) -> SignedRequest {
+        let request_uri = "/updateNotificationRule";
+
+        let mut request =
+            SignedRequest::new("POST", "codestar-notifications", &self.region, &request_uri);
+        request.set_operation("UpdateNotificationRule");
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let encoded = Some(serde_json::to_vec(&input).unwrap());
+        request.set_payload(encoded);
+
+        request
+    }
+
+    /// The pages of `ListEventTypes` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `MaxResults` of `input`.
+    pub fn list_event_types_pages(
+        &self,
+        input: ListEventTypesRequest,
+    ) -> impl futures::Stream<Item = Result<ListEventTypesResult, RusotoError<ListEventTypesError>>> + Send
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: CodeStarNotificationsClient, input| async move {
+                client.list_event_types(input).await
+            },
+            |input: &ListEventTypesRequest, output: &ListEventTypesResult| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListEventTypesRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `EventTypes` of every page of `ListEventTypes` results for `input`, as `list_event_types_pages` requests them.
+    pub fn list_event_types_stream(
+        &self,
+        input: ListEventTypesRequest,
+    ) -> impl futures::Stream<Item = Result<EventTypeSummary, RusotoError<ListEventTypesError>>> + Send
+    {
+        rusoto_core::pagination::items(self.list_event_types_pages(input), |page| {
+            page.event_types.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `ListNotificationRules` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `MaxResults` of `input`.
+    pub fn list_notification_rules_pages(
+        &self,
+        input: ListNotificationRulesRequest,
+    ) -> impl futures::Stream<
+        Item = Result<ListNotificationRulesResult, RusotoError<ListNotificationRulesError>>,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: CodeStarNotificationsClient, input| async move {
+                client.list_notification_rules(input).await
+            },
+            |input: &ListNotificationRulesRequest, output: &ListNotificationRulesResult| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListNotificationRulesRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `NotificationRules` of every page of `ListNotificationRules` results for `input`, as `list_notification_rules_pages` requests them.
+    pub fn list_notification_rules_stream(
+        &self,
+        input: ListNotificationRulesRequest,
+    ) -> impl futures::Stream<
+        Item = Result<NotificationRuleSummary, RusotoError<ListNotificationRulesError>>,
+    > + Send {
+        rusoto_core::pagination::items(self.list_notification_rules_pages(input), |page| {
+            page.notification_rules.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `ListTargets` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `MaxResults` of `input`.
+    pub fn list_targets_pages(
+        &self,
+        input: ListTargetsRequest,
+    ) -> impl futures::Stream<Item = Result<ListTargetsResult, RusotoError<ListTargetsError>>> + Send
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: CodeStarNotificationsClient, input| async move { client.list_targets(input).await },
+            |input: &ListTargetsRequest, output: &ListTargetsResult| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListTargetsRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `Targets` of every page of `ListTargets` results for `input`, as `list_targets_pages` requests them.
+    pub fn list_targets_stream(
+        &self,
+        input: ListTargetsRequest,
+    ) -> impl futures::Stream<Item = Result<TargetSummary, RusotoError<ListTargetsError>>> + Send
+    {
+        rusoto_core::pagination::items(self.list_targets_pages(input), |page| {
+            page.targets.unwrap_or_default()
+        })
+    }
+}
+
+#[async_trait]
+impl CodeStarNotifications for CodeStarNotificationsClient {
+    /// <p>Creates a notification rule for a resource. The rule specifies the events you want notifications about and the targets (such as SNS topics) where you want to receive them.</p>
+    async fn create_notification_rule(
+        &self,
+        input: CreateNotificationRuleRequest,
+    ) -> Result<CreateNotificationRuleResult, RusotoError<CreateNotificationRuleError>> {
+        self.create_notification_rule_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes a notification rule for a resource.</p>
+    async fn delete_notification_rule(
+        &self,
+        input: DeleteNotificationRuleRequest,
+    ) -> Result<DeleteNotificationRuleResult, RusotoError<DeleteNotificationRuleError>> {
+        self.delete_notification_rule_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes a specified target for notifications.</p>
+    async fn delete_target(
+        &self,
+        input: DeleteTargetRequest,
+    ) -> Result<DeleteTargetResult, RusotoError<DeleteTargetError>> {
+        self.delete_target_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns information about a specified notification rule.</p>
+    async fn describe_notification_rule(
+        &self,
+        input: DescribeNotificationRuleRequest,
+    ) -> Result<DescribeNotificationRuleResult, RusotoError<DescribeNotificationRuleError>> {
+        self.describe_notification_rule_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns information about the event types available for configuring notifications.</p>
+    async fn list_event_types(
+        &self,
+        input: ListEventTypesRequest,
+    ) -> Result<ListEventTypesResult, RusotoError<ListEventTypesError>> {
+        self.list_event_types_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns a list of the notification rules for an AWS account.</p>
+    async fn list_notification_rules(
+        &self,
+        input: ListNotificationRulesRequest,
+    ) -> Result<ListNotificationRulesResult, RusotoError<ListNotificationRulesError>> {
+        self.list_notification_rules_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns a list of the tags associated with a notification rule.</p>
+    async fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> Result<ListTagsForResourceResult, RusotoError<ListTagsForResourceError>> {
+        self.list_tags_for_resource_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns a list of the notification rule targets for an AWS account.</p>
+    async fn list_targets(
+        &self,
+        input: ListTargetsRequest,
+    ) -> Result<ListTargetsResult, RusotoError<ListTargetsError>> {
+        self.list_targets_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Creates an association between a notification rule and an SNS topic so that the associated target can receive notifications when the events described in the rule are triggered.</p>
+    async fn subscribe(
+        &self,
+        input: SubscribeRequest,
+    ) -> Result<SubscribeResult, RusotoError<SubscribeError>> {
+        self.subscribe_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Associates a set of provided tags with a notification rule.</p>
+    async fn tag_resource(
+        &self,
+        input: TagResourceRequest,
+    ) -> Result<TagResourceResult, RusotoError<TagResourceError>> {
+        self.tag_resource_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Removes an association between a notification rule and an Amazon SNS topic so that subscribers to that topic stop receiving notifications when the events described in the rule are triggered.</p>
+    async fn unsubscribe(
+        &self,
+        input: UnsubscribeRequest,
+    ) -> Result<UnsubscribeResult, RusotoError<UnsubscribeError>> {
+        self.unsubscribe_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Removes the association between one or more provided tags and a notification rule.</p>
+    async fn untag_resource(
+        &self,
+        input: UntagResourceRequest,
+    ) -> Result<UntagResourceResult, RusotoError<UntagResourceError>> {
+        self.untag_resource_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Updates a notification rule for a resource. You can change the events that trigger the notification rule, the status of the rule, and the targets that receive the notifications.</p> <note> <p>To add or remove tags for a notification rule, you must use <a>TagResource</a> and <a>UntagResource</a>.</p> </note></p>
+    async fn update_notification_rule(
+        &self,
+        input: UpdateNotificationRuleRequest,
+    ) -> Result<UpdateNotificationRuleResult, RusotoError<UpdateNotificationRuleError>> {
+        self.update_notification_rule_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
 }
@@ -16,8 +16,8 @@ use std::fmt;
 use async_trait::async_trait;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
-use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest, ResponseMetadata};
+use rusoto_core::{Client, ErrorMetadata, RusotoError};
 
 use rusoto_core::proto;
 use rusoto_core::signature::SignedRequest;
@@ -757,6 +757,7 @@ pub struct UserProfileSummary {
 
 /// Errors returned by AssociateTeamMember
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum AssociateTeamMemberError {
     /// <p>Another modification is being made. That modification must complete before you can make your change.</p>
     ConcurrentModification(String),
@@ -770,6 +771,8 @@ pub enum AssociateTeamMemberError {
     ProjectNotFound(String),
     /// <p>The team member is already associated with a role in this project.</p>
     TeamMemberAlreadyAssociated(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl AssociateTeamMemberError {
@@ -777,33 +780,43 @@ impl AssociateTeamMemberError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(AssociateTeamMemberError::ConcurrentModification(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        AssociateTeamMemberError::ConcurrentModification(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidServiceRoleException" => {
-                    return RusotoError::Service(AssociateTeamMemberError::InvalidServiceRole(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        AssociateTeamMemberError::InvalidServiceRole(err.msg),
+                        &res,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(AssociateTeamMemberError::LimitExceeded(err.msg))
+                    return RusotoError::service(
+                        AssociateTeamMemberError::LimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "ProjectConfigurationException" => {
-                    return RusotoError::Service(AssociateTeamMemberError::ProjectConfiguration(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        AssociateTeamMemberError::ProjectConfiguration(err.msg),
+                        &res,
+                    )
                 }
                 "ProjectNotFoundException" => {
-                    return RusotoError::Service(AssociateTeamMemberError::ProjectNotFound(err.msg))
+                    return RusotoError::service(
+                        AssociateTeamMemberError::ProjectNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "TeamMemberAlreadyAssociatedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         AssociateTeamMemberError::TeamMemberAlreadyAssociated(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(AssociateTeamMemberError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -821,12 +834,14 @@ impl fmt::Display for AssociateTeamMemberError {
             AssociateTeamMemberError::TeamMemberAlreadyAssociated(ref cause) => {
                 write!(f, "{}", cause)
             }
+            AssociateTeamMemberError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for AssociateTeamMemberError {}
 /// Errors returned by CreateProject
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateProjectError {
     /// <p>Another modification is being made. That modification must complete before you can make your change.</p>
     ConcurrentModification(String),
@@ -840,6 +855,8 @@ pub enum CreateProjectError {
     ProjectConfiguration(String),
     /// <p>The project creation request was valid, but a nonspecific exception or error occurred during project creation. The project could not be created in AWS CodeStar.</p>
     ProjectCreationFailed(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateProjectError {
@@ -847,27 +864,40 @@ impl CreateProjectError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(CreateProjectError::ConcurrentModification(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateProjectError::ConcurrentModification(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidServiceRoleException" => {
-                    return RusotoError::Service(CreateProjectError::InvalidServiceRole(err.msg))
+                    return RusotoError::service(
+                        CreateProjectError::InvalidServiceRole(err.msg),
+                        &res,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateProjectError::LimitExceeded(err.msg))
+                    return RusotoError::service(CreateProjectError::LimitExceeded(err.msg), &res)
                 }
                 "ProjectAlreadyExistsException" => {
-                    return RusotoError::Service(CreateProjectError::ProjectAlreadyExists(err.msg))
+                    return RusotoError::service(
+                        CreateProjectError::ProjectAlreadyExists(err.msg),
+                        &res,
+                    )
                 }
                 "ProjectConfigurationException" => {
-                    return RusotoError::Service(CreateProjectError::ProjectConfiguration(err.msg))
+                    return RusotoError::service(
+                        CreateProjectError::ProjectConfiguration(err.msg),
+                        &res,
+                    )
                 }
                 "ProjectCreationFailedException" => {
-                    return RusotoError::Service(CreateProjectError::ProjectCreationFailed(err.msg))
+                    return RusotoError::service(
+                        CreateProjectError::ProjectCreationFailed(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreateProjectError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -883,15 +913,19 @@ impl fmt::Display for CreateProjectError {
             CreateProjectError::ProjectAlreadyExists(ref cause) => write!(f, "{}", cause),
             CreateProjectError::ProjectConfiguration(ref cause) => write!(f, "{}", cause),
             CreateProjectError::ProjectCreationFailed(ref cause) => write!(f, "{}", cause),
+            CreateProjectError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateProjectError {}
 /// Errors returned by CreateUserProfile
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateUserProfileError {
     /// <p>A user profile with that name already exists in this region for the AWS account. AWS CodeStar user profile names must be unique within a region for the AWS account. </p>
     UserProfileAlreadyExists(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateUserProfileError {
@@ -899,12 +933,13 @@ impl CreateUserProfileError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "UserProfileAlreadyExistsException" => {
-                    return RusotoError::Service(CreateUserProfileError::UserProfileAlreadyExists(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateUserProfileError::UserProfileAlreadyExists(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreateUserProfileError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -915,17 +950,21 @@ impl fmt::Display for CreateUserProfileError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             CreateUserProfileError::UserProfileAlreadyExists(ref cause) => write!(f, "{}", cause),
+            CreateUserProfileError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateUserProfileError {}
 /// Errors returned by DeleteProject
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteProjectError {
     /// <p>Another modification is being made. That modification must complete before you can make your change.</p>
     ConcurrentModification(String),
     /// <p>The service role is not valid.</p>
     InvalidServiceRole(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteProjectError {
@@ -933,15 +972,19 @@ impl DeleteProjectError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(DeleteProjectError::ConcurrentModification(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteProjectError::ConcurrentModification(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidServiceRoleException" => {
-                    return RusotoError::Service(DeleteProjectError::InvalidServiceRole(err.msg))
+                    return RusotoError::service(
+                        DeleteProjectError::InvalidServiceRole(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteProjectError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -953,20 +996,25 @@ impl fmt::Display for DeleteProjectError {
         match *self {
             DeleteProjectError::ConcurrentModification(ref cause) => write!(f, "{}", cause),
             DeleteProjectError::InvalidServiceRole(ref cause) => write!(f, "{}", cause),
+            DeleteProjectError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteProjectError {}
 /// Errors returned by DeleteUserProfile
 #[derive(Debug, PartialEq)]
-pub enum DeleteUserProfileError {}
+#[non_exhaustive]
+pub enum DeleteUserProfileError {
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
+}
 
 impl DeleteUserProfileError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<DeleteUserProfileError> {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteUserProfileError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -975,12 +1023,15 @@ impl DeleteUserProfileError {
 impl fmt::Display for DeleteUserProfileError {
     #[allow(unused_variables)]
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        match *self {}
+        match *self {
+            DeleteUserProfileError::Unhandled(ref metadata) => write!(f, "{}", metadata),
+        }
     }
 }
 impl Error for DeleteUserProfileError {}
 /// Errors returned by DescribeProject
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeProjectError {
     /// <p>Another modification is being made. That modification must complete before you can make your change.</p>
     ConcurrentModification(String),
@@ -990,6 +1041,8 @@ pub enum DescribeProjectError {
     ProjectConfiguration(String),
     /// <p>The specified AWS CodeStar project was not found.</p>
     ProjectNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeProjectError {
@@ -997,23 +1050,31 @@ impl DescribeProjectError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(DescribeProjectError::ConcurrentModification(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeProjectError::ConcurrentModification(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidServiceRoleException" => {
-                    return RusotoError::Service(DescribeProjectError::InvalidServiceRole(err.msg))
+                    return RusotoError::service(
+                        DescribeProjectError::InvalidServiceRole(err.msg),
+                        &res,
+                    )
                 }
                 "ProjectConfigurationException" => {
-                    return RusotoError::Service(DescribeProjectError::ProjectConfiguration(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeProjectError::ProjectConfiguration(err.msg),
+                        &res,
+                    )
                 }
                 "ProjectNotFoundException" => {
-                    return RusotoError::Service(DescribeProjectError::ProjectNotFound(err.msg))
+                    return RusotoError::service(
+                        DescribeProjectError::ProjectNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DescribeProjectError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1027,15 +1088,19 @@ impl fmt::Display for DescribeProjectError {
             DescribeProjectError::InvalidServiceRole(ref cause) => write!(f, "{}", cause),
             DescribeProjectError::ProjectConfiguration(ref cause) => write!(f, "{}", cause),
             DescribeProjectError::ProjectNotFound(ref cause) => write!(f, "{}", cause),
+            DescribeProjectError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeProjectError {}
 /// Errors returned by DescribeUserProfile
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeUserProfileError {
     /// <p>The user profile was not found.</p>
     UserProfileNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeUserProfileError {
@@ -1043,12 +1108,13 @@ impl DescribeUserProfileError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "UserProfileNotFoundException" => {
-                    return RusotoError::Service(DescribeUserProfileError::UserProfileNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeUserProfileError::UserProfileNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DescribeUserProfileError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1059,12 +1125,14 @@ impl fmt::Display for DescribeUserProfileError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             DescribeUserProfileError::UserProfileNotFound(ref cause) => write!(f, "{}", cause),
+            DescribeUserProfileError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeUserProfileError {}
 /// Errors returned by DisassociateTeamMember
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DisassociateTeamMemberError {
     /// <p>Another modification is being made. That modification must complete before you can make your change.</p>
     ConcurrentModification(String),
@@ -1072,6 +1140,8 @@ pub enum DisassociateTeamMemberError {
     InvalidServiceRole(String),
     /// <p>The specified AWS CodeStar project was not found.</p>
     ProjectNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DisassociateTeamMemberError {
@@ -1079,22 +1149,25 @@ impl DisassociateTeamMemberError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DisassociateTeamMemberError::ConcurrentModification(err.msg),
+                        &res,
                     )
                 }
                 "InvalidServiceRoleException" => {
-                    return RusotoError::Service(DisassociateTeamMemberError::InvalidServiceRole(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DisassociateTeamMemberError::InvalidServiceRole(err.msg),
+                        &res,
+                    )
                 }
                 "ProjectNotFoundException" => {
-                    return RusotoError::Service(DisassociateTeamMemberError::ProjectNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DisassociateTeamMemberError::ProjectNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DisassociateTeamMemberError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1109,15 +1182,19 @@ impl fmt::Display for DisassociateTeamMemberError {
             }
             DisassociateTeamMemberError::InvalidServiceRole(ref cause) => write!(f, "{}", cause),
             DisassociateTeamMemberError::ProjectNotFound(ref cause) => write!(f, "{}", cause),
+            DisassociateTeamMemberError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DisassociateTeamMemberError {}
 /// Errors returned by ListProjects
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListProjectsError {
     /// <p>The next token is not valid.</p>
     InvalidNextToken(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListProjectsError {
@@ -1125,10 +1202,10 @@ impl ListProjectsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(ListProjectsError::InvalidNextToken(err.msg))
+                    return RusotoError::service(ListProjectsError::InvalidNextToken(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListProjectsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1139,17 +1216,21 @@ impl fmt::Display for ListProjectsError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             ListProjectsError::InvalidNextToken(ref cause) => write!(f, "{}", cause),
+            ListProjectsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListProjectsError {}
 /// Errors returned by ListResources
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListResourcesError {
     /// <p>The next token is not valid.</p>
     InvalidNextToken(String),
     /// <p>The specified AWS CodeStar project was not found.</p>
     ProjectNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListResourcesError {
@@ -1157,13 +1238,16 @@ impl ListResourcesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(ListResourcesError::InvalidNextToken(err.msg))
+                    return RusotoError::service(
+                        ListResourcesError::InvalidNextToken(err.msg),
+                        &res,
+                    )
                 }
                 "ProjectNotFoundException" => {
-                    return RusotoError::Service(ListResourcesError::ProjectNotFound(err.msg))
+                    return RusotoError::service(ListResourcesError::ProjectNotFound(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListResourcesError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1175,17 +1259,21 @@ impl fmt::Display for ListResourcesError {
         match *self {
             ListResourcesError::InvalidNextToken(ref cause) => write!(f, "{}", cause),
             ListResourcesError::ProjectNotFound(ref cause) => write!(f, "{}", cause),
+            ListResourcesError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListResourcesError {}
 /// Errors returned by ListTagsForProject
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListTagsForProjectError {
     /// <p>The next token is not valid.</p>
     InvalidNextToken(String),
     /// <p>The specified AWS CodeStar project was not found.</p>
     ProjectNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListTagsForProjectError {
@@ -1193,13 +1281,19 @@ impl ListTagsForProjectError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(ListTagsForProjectError::InvalidNextToken(err.msg))
+                    return RusotoError::service(
+                        ListTagsForProjectError::InvalidNextToken(err.msg),
+                        &res,
+                    )
                 }
                 "ProjectNotFoundException" => {
-                    return RusotoError::Service(ListTagsForProjectError::ProjectNotFound(err.msg))
+                    return RusotoError::service(
+                        ListTagsForProjectError::ProjectNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListTagsForProjectError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1211,17 +1305,21 @@ impl fmt::Display for ListTagsForProjectError {
         match *self {
             ListTagsForProjectError::InvalidNextToken(ref cause) => write!(f, "{}", cause),
             ListTagsForProjectError::ProjectNotFound(ref cause) => write!(f, "{}", cause),
+            ListTagsForProjectError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListTagsForProjectError {}
 /// Errors returned by ListTeamMembers
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListTeamMembersError {
     /// <p>The next token is not valid.</p>
     InvalidNextToken(String),
     /// <p>The specified AWS CodeStar project was not found.</p>
     ProjectNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListTeamMembersError {
@@ -1229,13 +1327,19 @@ impl ListTeamMembersError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(ListTeamMembersError::InvalidNextToken(err.msg))
+                    return RusotoError::service(
+                        ListTeamMembersError::InvalidNextToken(err.msg),
+                        &res,
+                    )
                 }
                 "ProjectNotFoundException" => {
-                    return RusotoError::Service(ListTeamMembersError::ProjectNotFound(err.msg))
+                    return RusotoError::service(
+                        ListTeamMembersError::ProjectNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListTeamMembersError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1247,15 +1351,19 @@ impl fmt::Display for ListTeamMembersError {
         match *self {
             ListTeamMembersError::InvalidNextToken(ref cause) => write!(f, "{}", cause),
             ListTeamMembersError::ProjectNotFound(ref cause) => write!(f, "{}", cause),
+            ListTeamMembersError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListTeamMembersError {}
 /// Errors returned by ListUserProfiles
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListUserProfilesError {
     /// <p>The next token is not valid.</p>
     InvalidNextToken(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListUserProfilesError {
@@ -1263,10 +1371,13 @@ impl ListUserProfilesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(ListUserProfilesError::InvalidNextToken(err.msg))
+                    return RusotoError::service(
+                        ListUserProfilesError::InvalidNextToken(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListUserProfilesError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1277,12 +1388,14 @@ impl fmt::Display for ListUserProfilesError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             ListUserProfilesError::InvalidNextToken(ref cause) => write!(f, "{}", cause),
+            ListUserProfilesError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListUserProfilesError {}
 /// Errors returned by TagProject
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum TagProjectError {
     /// <p>Another modification is being made. That modification must complete before you can make your change.</p>
     ConcurrentModification(String),
@@ -1290,6 +1403,8 @@ pub enum TagProjectError {
     LimitExceeded(String),
     /// <p>The specified AWS CodeStar project was not found.</p>
     ProjectNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl TagProjectError {
@@ -1297,16 +1412,19 @@ impl TagProjectError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(TagProjectError::ConcurrentModification(err.msg))
+                    return RusotoError::service(
+                        TagProjectError::ConcurrentModification(err.msg),
+                        &res,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(TagProjectError::LimitExceeded(err.msg))
+                    return RusotoError::service(TagProjectError::LimitExceeded(err.msg), &res)
                 }
                 "ProjectNotFoundException" => {
-                    return RusotoError::Service(TagProjectError::ProjectNotFound(err.msg))
+                    return RusotoError::service(TagProjectError::ProjectNotFound(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(TagProjectError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1319,12 +1437,14 @@ impl fmt::Display for TagProjectError {
             TagProjectError::ConcurrentModification(ref cause) => write!(f, "{}", cause),
             TagProjectError::LimitExceeded(ref cause) => write!(f, "{}", cause),
             TagProjectError::ProjectNotFound(ref cause) => write!(f, "{}", cause),
+            TagProjectError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for TagProjectError {}
 /// Errors returned by UntagProject
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UntagProjectError {
     /// <p>Another modification is being made. That modification must complete before you can make your change.</p>
     ConcurrentModification(String),
@@ -1332,6 +1452,8 @@ pub enum UntagProjectError {
     LimitExceeded(String),
     /// <p>The specified AWS CodeStar project was not found.</p>
     ProjectNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UntagProjectError {
@@ -1339,16 +1461,19 @@ impl UntagProjectError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(UntagProjectError::ConcurrentModification(err.msg))
+                    return RusotoError::service(
+                        UntagProjectError::ConcurrentModification(err.msg),
+                        &res,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(UntagProjectError::LimitExceeded(err.msg))
+                    return RusotoError::service(UntagProjectError::LimitExceeded(err.msg), &res)
                 }
                 "ProjectNotFoundException" => {
-                    return RusotoError::Service(UntagProjectError::ProjectNotFound(err.msg))
+                    return RusotoError::service(UntagProjectError::ProjectNotFound(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UntagProjectError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1361,15 +1486,19 @@ impl fmt::Display for UntagProjectError {
             UntagProjectError::ConcurrentModification(ref cause) => write!(f, "{}", cause),
             UntagProjectError::LimitExceeded(ref cause) => write!(f, "{}", cause),
             UntagProjectError::ProjectNotFound(ref cause) => write!(f, "{}", cause),
+            UntagProjectError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for UntagProjectError {}
 /// Errors returned by UpdateProject
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdateProjectError {
     /// <p>The specified AWS CodeStar project was not found.</p>
     ProjectNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdateProjectError {
@@ -1377,10 +1506,10 @@ impl UpdateProjectError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ProjectNotFoundException" => {
-                    return RusotoError::Service(UpdateProjectError::ProjectNotFound(err.msg))
+                    return RusotoError::service(UpdateProjectError::ProjectNotFound(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UpdateProjectError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1391,12 +1520,14 @@ impl fmt::Display for UpdateProjectError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             UpdateProjectError::ProjectNotFound(ref cause) => write!(f, "{}", cause),
+            UpdateProjectError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for UpdateProjectError {}
 /// Errors returned by UpdateTeamMember
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdateTeamMemberError {
     /// <p>Another modification is being made. That modification must complete before you can make your change.</p>
     ConcurrentModification(String),
@@ -1410,6 +1541,8 @@ pub enum UpdateTeamMemberError {
     ProjectNotFound(String),
     /// <p>The specified team member was not found.</p>
     TeamMemberNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdateTeamMemberError {
@@ -1417,29 +1550,43 @@ impl UpdateTeamMemberError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(UpdateTeamMemberError::ConcurrentModification(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateTeamMemberError::ConcurrentModification(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidServiceRoleException" => {
-                    return RusotoError::Service(UpdateTeamMemberError::InvalidServiceRole(err.msg))
+                    return RusotoError::service(
+                        UpdateTeamMemberError::InvalidServiceRole(err.msg),
+                        &res,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(UpdateTeamMemberError::LimitExceeded(err.msg))
+                    return RusotoError::service(
+                        UpdateTeamMemberError::LimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "ProjectConfigurationException" => {
-                    return RusotoError::Service(UpdateTeamMemberError::ProjectConfiguration(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateTeamMemberError::ProjectConfiguration(err.msg),
+                        &res,
+                    )
                 }
                 "ProjectNotFoundException" => {
-                    return RusotoError::Service(UpdateTeamMemberError::ProjectNotFound(err.msg))
+                    return RusotoError::service(
+                        UpdateTeamMemberError::ProjectNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "TeamMemberNotFoundException" => {
-                    return RusotoError::Service(UpdateTeamMemberError::TeamMemberNotFound(err.msg))
+                    return RusotoError::service(
+                        UpdateTeamMemberError::TeamMemberNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UpdateTeamMemberError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1455,15 +1602,19 @@ impl fmt::Display for UpdateTeamMemberError {
             UpdateTeamMemberError::ProjectConfiguration(ref cause) => write!(f, "{}", cause),
             UpdateTeamMemberError::ProjectNotFound(ref cause) => write!(f, "{}", cause),
             UpdateTeamMemberError::TeamMemberNotFound(ref cause) => write!(f, "{}", cause),
+            UpdateTeamMemberError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for UpdateTeamMemberError {}
 /// Errors returned by UpdateUserProfile
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdateUserProfileError {
     /// <p>The user profile was not found.</p>
     UserProfileNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdateUserProfileError {
@@ -1471,12 +1622,13 @@ impl UpdateUserProfileError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "UserProfileNotFoundException" => {
-                    return RusotoError::Service(UpdateUserProfileError::UserProfileNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateUserProfileError::UserProfileNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UpdateUserProfileError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1487,6 +1639,7 @@ impl fmt::Display for UpdateUserProfileError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             UpdateUserProfileError::UserProfileNotFound(ref cause) => write!(f, "{}", cause),
+            UpdateUserProfileError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
@@ -1613,6 +1766,7 @@ impl CodeStarClient {
     /// Creates a client backed by the default tokio event loop.
     ///
     /// The client will use the default credentials provider and tls client.
+    #[cfg(not(target_arch = "wasm32"))]
     pub fn new(region: region::Region) -> CodeStarClient {
         CodeStarClient {
             client: Client::shared(),
@@ -1638,497 +1792,1163 @@ impl CodeStarClient {
     pub fn new_with_client(client: Client, region: region::Region) -> CodeStarClient {
         CodeStarClient { client, region }
     }
+
+    /// Returns a builder of a client, setting any of its region, credentials provider,
+    /// dispatcher, retry policy, endpoint and timeouts.
+    pub fn builder() -> rusoto_core::ClientBuilder<CodeStarClient> {
+        rusoto_core::ClientBuilder::new(CodeStarClient::new_with_client)
+    }
+
+    /// Returns a clone of this client with the overrides of `config` applied to its
+    /// operations, sharing its connections.
+    pub fn customize(&self, config: &rusoto_core::OperationConfig) -> CodeStarClient {
+        CodeStarClient {
+            client: self.client.customize(config),
+            region: config.resolve_region(&self.region),
+        }
+    }
 }
 
-#[async_trait]
-impl CodeStar for CodeStarClient {
-    /// <p>Adds an IAM user to the team for an AWS CodeStar project.</p>
-    async fn associate_team_member(
+impl CodeStarClient {
+    /// Calls `associate_team_member`, also returning the metadata of the response.
+    pub async fn associate_team_member_with_metadata(
         &self,
         input: AssociateTeamMemberRequest,
-    ) -> Result<AssociateTeamMemberResult, RusotoError<AssociateTeamMemberError>> {
+    ) -> Result<(AssociateTeamMemberResult, ResponseMetadata), RusotoError<AssociateTeamMemberError>>
+    {
+        let request = self.build_associate_team_member_request(input);
+
+        let mut response = self
+            .client
+            .sign_and_dispatch(request)
+            .await
+            .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
+        if response.status.is_success() {
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<AssociateTeamMemberResult, _>()?;
+            Ok((result, metadata))
+        } else {
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(AssociateTeamMemberError::from_response(response))
+        }
+    }
+
+    /// Presigns the request `associate_team_member` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_associate_team_member(
+        &self,
+        presigner: &rusoto_core::Presigner,
+        input: AssociateTeamMemberRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_associate_team_member_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_associate_team_member_request(
+        &self,
+        input: AssociateTeamMemberRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "codestar", &self.region, "/");
+        request.set_operation("AssociateTeamMember");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "CodeStar_20170419.AssociateTeamMember");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `create_project`, also returning the metadata of the response.
+    pub async fn create_project_with_metadata(
+        &self,
+        input: CreateProjectRequest,
+    ) -> Result<(CreateProjectResult, ResponseMetadata), RusotoError<CreateProjectError>> {
+        let request = self.build_create_project_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<AssociateTeamMemberResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<CreateProjectResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(AssociateTeamMemberError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(CreateProjectError::from_response(response))
         }
     }
 
-    /// <p>Creates a project, including project resources. This action creates a project based on a submitted project request. A set of source code files and a toolchain template file can be included with the project request. If these are not provided, an empty project is created.</p>
-    async fn create_project(
+    /// Presigns the request `create_project` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_project(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: CreateProjectRequest,
-    ) -> Result<CreateProjectResult, RusotoError<CreateProjectError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_project_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_project_request(&self, input: CreateProjectRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "codestar", &self.region, "/");
+        request.set_operation("CreateProject");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "CodeStar_20170419.CreateProject");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `create_user_profile`, also returning the metadata of the response.
+    pub async fn create_user_profile_with_metadata(
+        &self,
+        input: CreateUserProfileRequest,
+    ) -> Result<(CreateUserProfileResult, ResponseMetadata), RusotoError<CreateUserProfileError>>
+    {
+        let request = self.build_create_user_profile_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<CreateProjectResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<CreateUserProfileResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(CreateProjectError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(CreateUserProfileError::from_response(response))
         }
     }
 
-    /// <p>Creates a profile for a user that includes user preferences, such as the display name and email address assocciated with the user, in AWS CodeStar. The user profile is not project-specific. Information in the user profile is displayed wherever the user's information appears to other users in AWS CodeStar.</p>
-    async fn create_user_profile(
+    /// Presigns the request `create_user_profile` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_user_profile(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: CreateUserProfileRequest,
-    ) -> Result<CreateUserProfileResult, RusotoError<CreateUserProfileError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_user_profile_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_user_profile_request(&self, input: CreateUserProfileRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "codestar", &self.region, "/");
+        request.set_operation("CreateUserProfile");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "CodeStar_20170419.CreateUserProfile");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `delete_project`, also returning the metadata of the response.
+    pub async fn delete_project_with_metadata(
+        &self,
+        input: DeleteProjectRequest,
+    ) -> Result<(DeleteProjectResult, ResponseMetadata), RusotoError<DeleteProjectError>> {
+        let request = self.build_delete_project_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<CreateUserProfileResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DeleteProjectResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(CreateUserProfileError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DeleteProjectError::from_response(response))
         }
     }
 
-    /// <p>Deletes a project, including project resources. Does not delete users associated with the project, but does delete the IAM roles that allowed access to the project.</p>
-    async fn delete_project(
+    /// Presigns the request `delete_project` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_project(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteProjectRequest,
-    ) -> Result<DeleteProjectResult, RusotoError<DeleteProjectError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_project_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_project_request(&self, input: DeleteProjectRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "codestar", &self.region, "/");
+        request.set_operation("DeleteProject");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "CodeStar_20170419.DeleteProject");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `delete_user_profile`, also returning the metadata of the response.
+    pub async fn delete_user_profile_with_metadata(
+        &self,
+        input: DeleteUserProfileRequest,
+    ) -> Result<(DeleteUserProfileResult, ResponseMetadata), RusotoError<DeleteUserProfileError>>
+    {
+        let request = self.build_delete_user_profile_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<DeleteProjectResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DeleteUserProfileResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteProjectError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DeleteUserProfileError::from_response(response))
         }
     }
 
-    /// <p>Deletes a user profile in AWS CodeStar, including all personal preference data associated with that profile, such as display name and email address. It does not delete the history of that user, for example the history of commits made by that user.</p>
-    async fn delete_user_profile(
+    /// Presigns the request `delete_user_profile` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_user_profile(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteUserProfileRequest,
-    ) -> Result<DeleteUserProfileResult, RusotoError<DeleteUserProfileError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_user_profile_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_user_profile_request(&self, input: DeleteUserProfileRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "codestar", &self.region, "/");
+        request.set_operation("DeleteUserProfile");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "CodeStar_20170419.DeleteUserProfile");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `describe_project`, also returning the metadata of the response.
+    pub async fn describe_project_with_metadata(
+        &self,
+        input: DescribeProjectRequest,
+    ) -> Result<(DescribeProjectResult, ResponseMetadata), RusotoError<DescribeProjectError>> {
+        let request = self.build_describe_project_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<DeleteUserProfileResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DescribeProjectResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteUserProfileError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DescribeProjectError::from_response(response))
         }
     }
 
-    /// <p>Describes a project and its resources.</p>
-    async fn describe_project(
+    /// Presigns the request `describe_project` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_project(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeProjectRequest,
-    ) -> Result<DescribeProjectResult, RusotoError<DescribeProjectError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_project_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_project_request(&self, input: DescribeProjectRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "codestar", &self.region, "/");
+        request.set_operation("DescribeProject");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "CodeStar_20170419.DescribeProject");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `describe_user_profile`, also returning the metadata of the response.
+    pub async fn describe_user_profile_with_metadata(
+        &self,
+        input: DescribeUserProfileRequest,
+    ) -> Result<(DescribeUserProfileResult, ResponseMetadata), RusotoError<DescribeUserProfileError>>
+    {
+        let request = self.build_describe_user_profile_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<DescribeProjectResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DescribeUserProfileResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeProjectError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DescribeUserProfileError::from_response(response))
         }
     }
 
-    /// <p>Describes a user in AWS CodeStar and the user attributes across all projects.</p>
-    async fn describe_user_profile(
+    /// Presigns the request `describe_user_profile` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_user_profile(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeUserProfileRequest,
-    ) -> Result<DescribeUserProfileResult, RusotoError<DescribeUserProfileError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_user_profile_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_user_profile_request(
+        &self,
+        input: DescribeUserProfileRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "codestar", &self.region, "/");
+        request.set_operation("DescribeUserProfile");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "CodeStar_20170419.DescribeUserProfile");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `disassociate_team_member`, also returning the metadata of the response.
+    pub async fn disassociate_team_member_with_metadata(
+        &self,
+        input: DisassociateTeamMemberRequest,
+    ) -> Result<
+        (DisassociateTeamMemberResult, ResponseMetadata),
+        RusotoError<DisassociateTeamMemberError>,
+    > {
+        let request = self.build_disassociate_team_member_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DescribeUserProfileResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DisassociateTeamMemberResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeUserProfileError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DisassociateTeamMemberError::from_response(response))
         }
     }
 
-    /// <p>Removes a user from a project. Removing a user from a project also removes the IAM policies from that user that allowed access to the project and its resources. Disassociating a team member does not remove that user's profile from AWS CodeStar. It does not remove the user from IAM.</p>
-    async fn disassociate_team_member(
+    /// Presigns the request `disassociate_team_member` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_disassociate_team_member(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DisassociateTeamMemberRequest,
-    ) -> Result<DisassociateTeamMemberResult, RusotoError<DisassociateTeamMemberError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_disassociate_team_member_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_disassociate_team_member_request(
+        &self,
+        input: DisassociateTeamMemberRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "codestar", &self.region, "/");
+        request.set_operation("DisassociateTeamMember");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "CodeStar_20170419.DisassociateTeamMember");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `list_projects`, also returning the metadata of the response.
+    pub async fn list_projects_with_metadata(
+        &self,
+        input: ListProjectsRequest,
+    ) -> Result<(ListProjectsResult, ResponseMetadata), RusotoError<ListProjectsError>> {
+        let request = self.build_list_projects_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DisassociateTeamMemberResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ListProjectsResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DisassociateTeamMemberError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ListProjectsError::from_response(response))
         }
     }
 
-    /// <p>Lists all projects in AWS CodeStar associated with your AWS account.</p>
-    async fn list_projects(
+    /// Presigns the request `list_projects` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_projects(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListProjectsRequest,
-    ) -> Result<ListProjectsResult, RusotoError<ListProjectsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_projects_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_projects_request(&self, input: ListProjectsRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "codestar", &self.region, "/");
+        request.set_operation("ListProjects");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "CodeStar_20170419.ListProjects");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `list_resources`, also returning the metadata of the response.
+    pub async fn list_resources_with_metadata(
+        &self,
+        input: ListResourcesRequest,
+    ) -> Result<(ListResourcesResult, ResponseMetadata), RusotoError<ListResourcesError>> {
+        let request = self.build_list_resources_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<ListProjectsResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ListResourcesResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ListProjectsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ListResourcesError::from_response(response))
         }
     }
 
-    /// <p>Lists resources associated with a project in AWS CodeStar.</p>
-    async fn list_resources(
+    /// Presigns the request `list_resources` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_resources(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListResourcesRequest,
-    ) -> Result<ListResourcesResult, RusotoError<ListResourcesError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_resources_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_resources_request(&self, input: ListResourcesRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "codestar", &self.region, "/");
+        request.set_operation("ListResources");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "CodeStar_20170419.ListResources");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `list_tags_for_project`, also returning the metadata of the response.
+    pub async fn list_tags_for_project_with_metadata(
+        &self,
+        input: ListTagsForProjectRequest,
+    ) -> Result<(ListTagsForProjectResult, ResponseMetadata), RusotoError<ListTagsForProjectError>>
+    {
+        let request = self.build_list_tags_for_project_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<ListResourcesResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ListTagsForProjectResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ListResourcesError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ListTagsForProjectError::from_response(response))
         }
     }
 
-    /// <p>Gets the tags for a project.</p>
-    async fn list_tags_for_project(
+    /// Presigns the request `list_tags_for_project` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_tags_for_project(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListTagsForProjectRequest,
-    ) -> Result<ListTagsForProjectResult, RusotoError<ListTagsForProjectError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_tags_for_project_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_tags_for_project_request(
+        &self,
+        input: ListTagsForProjectRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "codestar", &self.region, "/");
+        request.set_operation("ListTagsForProject");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "CodeStar_20170419.ListTagsForProject");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `list_team_members`, also returning the metadata of the response.
+    pub async fn list_team_members_with_metadata(
+        &self,
+        input: ListTeamMembersRequest,
+    ) -> Result<(ListTeamMembersResult, ResponseMetadata), RusotoError<ListTeamMembersError>> {
+        let request = self.build_list_team_members_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListTagsForProjectResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ListTeamMembersResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ListTagsForProjectError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ListTeamMembersError::from_response(response))
         }
     }
 
-    /// <p>Lists all team members associated with a project.</p>
-    async fn list_team_members(
+    /// Presigns the request `list_team_members` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_team_members(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListTeamMembersRequest,
-    ) -> Result<ListTeamMembersResult, RusotoError<ListTeamMembersError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_team_members_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_team_members_request(&self, input: ListTeamMembersRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "codestar", &self.region, "/");
+        request.set_operation("ListTeamMembers");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "CodeStar_20170419.ListTeamMembers");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `list_user_profiles`, also returning the metadata of the response.
+    pub async fn list_user_profiles_with_metadata(
+        &self,
+        input: ListUserProfilesRequest,
+    ) -> Result<(ListUserProfilesResult, ResponseMetadata), RusotoError<ListUserProfilesError>>
+    {
+        let request = self.build_list_user_profiles_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<ListTeamMembersResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ListUserProfilesResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ListTeamMembersError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ListUserProfilesError::from_response(response))
         }
     }
 
-    /// <p>Lists all the user profiles configured for your AWS account in AWS CodeStar.</p>
-    async fn list_user_profiles(
+    /// Presigns the request `list_user_profiles` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_user_profiles(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListUserProfilesRequest,
-    ) -> Result<ListUserProfilesResult, RusotoError<ListUserProfilesError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_user_profiles_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_user_profiles_request(&self, input: ListUserProfilesRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "codestar", &self.region, "/");
+        request.set_operation("ListUserProfiles");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "CodeStar_20170419.ListUserProfiles");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `tag_project`, also returning the metadata of the response.
+    pub async fn tag_project_with_metadata(
+        &self,
+        input: TagProjectRequest,
+    ) -> Result<(TagProjectResult, ResponseMetadata), RusotoError<TagProjectError>> {
+        let request = self.build_tag_project_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<ListUserProfilesResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<TagProjectResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ListUserProfilesError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(TagProjectError::from_response(response))
         }
     }
 
-    /// <p>Adds tags to a project.</p>
-    async fn tag_project(
+    /// Presigns the request `tag_project` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_tag_project(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: TagProjectRequest,
-    ) -> Result<TagProjectResult, RusotoError<TagProjectError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_tag_project_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_tag_project_request(&self, input: TagProjectRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "codestar", &self.region, "/");
+        request.set_operation("TagProject");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "CodeStar_20170419.TagProject");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `untag_project`, also returning the metadata of the response.
+    pub async fn untag_project_with_metadata(
+        &self,
+        input: UntagProjectRequest,
+    ) -> Result<(UntagProjectResult, ResponseMetadata), RusotoError<UntagProjectError>> {
+        let request = self.build_untag_project_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<TagProjectResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<UntagProjectResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(TagProjectError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(UntagProjectError::from_response(response))
         }
     }
 
-    /// <p>Removes tags from a project.</p>
-    async fn untag_project(
+    /// Presigns the request `untag_project` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_untag_project(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UntagProjectRequest,
-    ) -> Result<UntagProjectResult, RusotoError<UntagProjectError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_untag_project_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_untag_project_request(&self, input: UntagProjectRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "codestar", &self.region, "/");
+        request.set_operation("UntagProject");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "CodeStar_20170419.UntagProject");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `update_project`, also returning the metadata of the response.
+    pub async fn update_project_with_metadata(
+        &self,
+        input: UpdateProjectRequest,
+    ) -> Result<(UpdateProjectResult, ResponseMetadata), RusotoError<UpdateProjectError>> {
+        let request = self.build_update_project_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<UntagProjectResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<UpdateProjectResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(UntagProjectError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(UpdateProjectError::from_response(response))
         }
     }
 
-    /// <p>Updates a project in AWS CodeStar.</p>
-    async fn update_project(
+    /// Presigns the request `update_project` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_project(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UpdateProjectRequest,
-    ) -> Result<UpdateProjectResult, RusotoError<UpdateProjectError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_project_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_project_request(&self, input: UpdateProjectRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "codestar", &self.region, "/");
+        request.set_operation("UpdateProject");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "CodeStar_20170419.UpdateProject");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `update_team_member`, also returning the metadata of the response.
+    pub async fn update_team_member_with_metadata(
+        &self,
+        input: UpdateTeamMemberRequest,
+    ) -> Result<(UpdateTeamMemberResult, ResponseMetadata), RusotoError<UpdateTeamMemberError>>
+    {
+        let request = self.build_update_team_member_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<UpdateProjectResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<UpdateTeamMemberResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(UpdateProjectError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(UpdateTeamMemberError::from_response(response))
         }
     }
 
-    /// <p>Updates a team member's attributes in an AWS CodeStar project. For example, you can change a team member's role in the project, or change whether they have remote access to project resources.</p>
-    async fn update_team_member(
+    /// Presigns the request `update_team_member` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_team_member(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UpdateTeamMemberRequest,
-    ) -> Result<UpdateTeamMemberResult, RusotoError<UpdateTeamMemberError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_team_member_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_team_member_request(&self, input: UpdateTeamMemberRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "codestar", &self.region, "/");
+        request.set_operation("UpdateTeamMember");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "CodeStar_20170419.UpdateTeamMember");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `update_user_profile`, also returning the metadata of the response.
+    pub async fn update_user_profile_with_metadata(
+        &self,
+        input: UpdateUserProfileRequest,
+    ) -> Result<(UpdateUserProfileResult, ResponseMetadata), RusotoError<UpdateUserProfileError>>
+    {
+        let request = self.build_update_user_profile_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<UpdateTeamMemberResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<UpdateUserProfileResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(UpdateTeamMemberError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(UpdateUserProfileError::from_response(response))
         }
     }
 
-    /// <p>Updates a user's profile in AWS CodeStar. The user profile is not project-specific. Information in the user profile is displayed wherever the user's information appears to other users in AWS CodeStar. </p>
-    async fn update_user_profile(
+    /// Presigns the request `update_user_profile` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_user_profile(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UpdateUserProfileRequest,
-    ) -> Result<UpdateUserProfileResult, RusotoError<UpdateUserProfileError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_user_profile_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_user_profile_request(&self, input: UpdateUserProfileRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "codestar", &self.region, "/");
+        request.set_operation("UpdateUserProfile");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "CodeStar_20170419.UpdateUserProfile");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
-        let mut response = self
-            .client
-            .sign_and_dispatch(request)
+        request
+    }
+}
+
+#[async_trait]
+impl CodeStar for CodeStarClient {
+    /// <p>Adds an IAM user to the team for an AWS CodeStar project.</p>
+    async fn associate_team_member(
+        &self,
+        input: AssociateTeamMemberRequest,
+    ) -> Result<AssociateTeamMemberResult, RusotoError<AssociateTeamMemberError>> {
+        self.associate_team_member_with_metadata(input)
             .await
-            .map_err(RusotoError::from)?;
-        if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<UpdateUserProfileResult, _>()
-        } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(UpdateUserProfileError::from_response(response))
-        }
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Creates a project, including project resources. This action creates a project based on a submitted project request. A set of source code files and a toolchain template file can be included with the project request. If these are not provided, an empty project is created.</p>
+    async fn create_project(
+        &self,
+        input: CreateProjectRequest,
+    ) -> Result<CreateProjectResult, RusotoError<CreateProjectError>> {
+        self.create_project_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Creates a profile for a user that includes user preferences, such as the display name and email address assocciated with the user, in AWS CodeStar. The user profile is not project-specific. Information in the user profile is displayed wherever the user's information appears to other users in AWS CodeStar.</p>
+    async fn create_user_profile(
+        &self,
+        input: CreateUserProfileRequest,
+    ) -> Result<CreateUserProfileResult, RusotoError<CreateUserProfileError>> {
+        self.create_user_profile_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes a project, including project resources. Does not delete users associated with the project, but does delete the IAM roles that allowed access to the project.</p>
+    async fn delete_project(
+        &self,
+        input: DeleteProjectRequest,
+    ) -> Result<DeleteProjectResult, RusotoError<DeleteProjectError>> {
+        self.delete_project_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes a user profile in AWS CodeStar, including all personal preference data associated with that profile, such as display name and email address. It does not delete the history of that user, for example the history of commits made by that user.</p>
+    async fn delete_user_profile(
+        &self,
+        input: DeleteUserProfileRequest,
+    ) -> Result<DeleteUserProfileResult, RusotoError<DeleteUserProfileError>> {
+        self.delete_user_profile_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Describes a project and its resources.</p>
+    async fn describe_project(
+        &self,
+        input: DescribeProjectRequest,
+    ) -> Result<DescribeProjectResult, RusotoError<DescribeProjectError>> {
+        self.describe_project_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Describes a user in AWS CodeStar and the user attributes across all projects.</p>
+    async fn describe_user_profile(
+        &self,
+        input: DescribeUserProfileRequest,
+    ) -> Result<DescribeUserProfileResult, RusotoError<DescribeUserProfileError>> {
+        self.describe_user_profile_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Removes a user from a project. Removing a user from a project also removes the IAM policies from that user that allowed access to the project and its resources. Disassociating a team member does not remove that user's profile from AWS CodeStar. It does not remove the user from IAM.</p>
+    async fn disassociate_team_member(
+        &self,
+        input: DisassociateTeamMemberRequest,
+    ) -> Result<DisassociateTeamMemberResult, RusotoError<DisassociateTeamMemberError>> {
+        self.disassociate_team_member_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Lists all projects in AWS CodeStar associated with your AWS account.</p>
+    async fn list_projects(
+        &self,
+        input: ListProjectsRequest,
+    ) -> Result<ListProjectsResult, RusotoError<ListProjectsError>> {
+        self.list_projects_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Lists resources associated with a project in AWS CodeStar.</p>
+    async fn list_resources(
+        &self,
+        input: ListResourcesRequest,
+    ) -> Result<ListResourcesResult, RusotoError<ListResourcesError>> {
+        self.list_resources_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Gets the tags for a project.</p>
+    async fn list_tags_for_project(
+        &self,
+        input: ListTagsForProjectRequest,
+    ) -> Result<ListTagsForProjectResult, RusotoError<ListTagsForProjectError>> {
+        self.list_tags_for_project_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Lists all team members associated with a project.</p>
+    async fn list_team_members(
+        &self,
+        input: ListTeamMembersRequest,
+    ) -> Result<ListTeamMembersResult, RusotoError<ListTeamMembersError>> {
+        self.list_team_members_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Lists all the user profiles configured for your AWS account in AWS CodeStar.</p>
+    async fn list_user_profiles(
+        &self,
+        input: ListUserProfilesRequest,
+    ) -> Result<ListUserProfilesResult, RusotoError<ListUserProfilesError>> {
+        self.list_user_profiles_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Adds tags to a project.</p>
+    async fn tag_project(
+        &self,
+        input: TagProjectRequest,
+    ) -> Result<TagProjectResult, RusotoError<TagProjectError>> {
+        self.tag_project_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Removes tags from a project.</p>
+    async fn untag_project(
+        &self,
+        input: UntagProjectRequest,
+    ) -> Result<UntagProjectResult, RusotoError<UntagProjectError>> {
+        self.untag_project_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Updates a project in AWS CodeStar.</p>
+    async fn update_project(
+        &self,
+        input: UpdateProjectRequest,
+    ) -> Result<UpdateProjectResult, RusotoError<UpdateProjectError>> {
+        self.update_project_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Updates a team member's attributes in an AWS CodeStar project. For example, you can change a team member's role in the project, or change whether they have remote access to project resources.</p>
+    async fn update_team_member(
+        &self,
+        input: UpdateTeamMemberRequest,
+    ) -> Result<UpdateTeamMemberResult, RusotoError<UpdateTeamMemberError>> {
+        self.update_team_member_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Updates a user's profile in AWS CodeStar. The user profile is not project-specific. Information in the user profile is displayed wherever the user's information appears to other users in AWS CodeStar. </p>
+    async fn update_user_profile(
+        &self,
+        input: UpdateUserProfileRequest,
+    ) -> Result<UpdateUserProfileResult, RusotoError<UpdateUserProfileError>> {
+        self.update_user_profile_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
     }
 }
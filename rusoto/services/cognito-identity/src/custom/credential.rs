@@ -0,0 +1,273 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use chrono::{Duration, TimeZone, Utc};
+use futures::lock::Mutex;
+
+use rusoto_core::credential::{AwsCredentials, CredentialsError, ProvideAwsCredentials};
+
+use crate::{
+    CognitoIdentity, CognitoIdentityClient, Credentials, GetCredentialsForIdentityInput, GetIdInput,
+};
+
+/// Provides the credentials of an identity in a Cognito Identity pool, from
+/// `cognito-identity:GetCredentialsForIdentity`.
+///
+/// The identity is looked up once with `cognito-identity:GetId`, unless it is set with
+/// `set_identity_id`, and identifies a user with the tokens of the identity providers in its
+/// logins, or is unauthenticated without any. The credentials are cached and fetched again five
+/// minutes before they expire.
+///
+/// Both operations are public, the `CognitoIdentityClient` can be created with
+/// `Client::new_not_signing`.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use rusoto_cognito_identity::{CognitoIdentityClient, CognitoProvider};
+/// use rusoto_core::{Client, HttpClient, Region};
+///
+/// let client = CognitoIdentityClient::new_with_client(
+///     Client::new_not_signing(HttpClient::new().unwrap()),
+///     Region::UsEast1,
+/// );
+/// let mut provider = CognitoProvider::new(
+///     client,
+///     "us-east-1:8f9a2ef6-7b54-4d0a-8b1e-2f6a9c7c4e41".to_owned(),
+/// );
+/// provider.add_login("accounts.google.com".to_owned(), "<id token>".to_owned());
+/// ```
+#[derive(Clone)]
+pub struct CognitoProvider {
+    client: CognitoIdentityClient,
+    identity_pool_id: String,
+    account_id: Option<String>,
+    logins: HashMap<String, String>,
+    custom_role_arn: Option<String>,
+    identity_id: Arc<Mutex<Option<String>>>,
+    credentials: Arc<Mutex<Option<AwsCredentials>>>,
+}
+
+impl CognitoProvider {
+    /// Creates a new `CognitoProvider` for an identity in the pool `identity_pool_id`.
+    pub fn new(client: CognitoIdentityClient, identity_pool_id: String) -> CognitoProvider {
+        CognitoProvider {
+            client,
+            identity_pool_id,
+            account_id: None,
+            logins: HashMap::new(),
+            custom_role_arn: None,
+            identity_id: Default::default(),
+            credentials: Default::default(),
+        }
+    }
+
+    /// Adds the `token` of the identity provider `provider`, e.g. `accounts.google.com` or
+    /// `cognito-idp.us-east-1.amazonaws.com/<user pool id>`.
+    ///
+    /// Cached credentials are discarded, the next request is authenticated with the new login.
+    pub fn add_login(&mut self, provider: String, token: String) {
+        self.logins.insert(provider, token);
+        self.invalidate();
+    }
+
+    /// Sets the tokens of the identity providers, keyed by provider name.
+    pub fn set_logins(&mut self, logins: HashMap<String, String>) {
+        self.logins = logins;
+        self.invalidate();
+    }
+
+    /// Sets the identity, skipping `cognito-identity:GetId`.
+    pub fn set_identity_id(&mut self, identity_id: String) {
+        self.identity_id = Arc::new(Mutex::new(Some(identity_id)));
+        self.invalidate();
+    }
+
+    /// Sets the ARN of the role to assume, of the roles the identity provider allows.
+    pub fn set_custom_role_arn(&mut self, custom_role_arn: Option<String>) {
+        self.custom_role_arn = custom_role_arn;
+        self.invalidate();
+    }
+
+    /// Sets the ID of the account owning the identity pool.
+    pub fn set_account_id(&mut self, account_id: Option<String>) {
+        self.account_id = account_id;
+    }
+
+    /// The identity the credentials are provided for, looked up with `cognito-identity:GetId`
+    /// the first time.
+    pub async fn identity_id(&self) -> Result<String, CredentialsError> {
+        let mut identity_id = self.identity_id.lock().await;
+        if let Some(ref identity_id) = *identity_id {
+            return Ok(identity_id.clone());
+        }
+        let response = self
+            .client
+            .get_id(GetIdInput {
+                account_id: self.account_id.clone(),
+                identity_pool_id: self.identity_pool_id.clone(),
+                logins: self.logins(),
+            })
+            .await
+            .map_err(|err| CredentialsError::new(format!("Cognito GetId error: {}", err)))?;
+        let id = response
+            .identity_id
+            .ok_or_else(|| CredentialsError::new("No identity ID in Cognito GetId response"))?;
+        *identity_id = Some(id.clone());
+        Ok(id)
+    }
+
+    fn logins(&self) -> Option<HashMap<String, String>> {
+        if self.logins.is_empty() {
+            None
+        } else {
+            Some(self.logins.clone())
+        }
+    }
+
+    /// Replaces the cache, leaving clones made before sharing the old credentials.
+    fn invalidate(&mut self) {
+        self.credentials = Default::default();
+    }
+}
+
+#[async_trait]
+impl ProvideAwsCredentials for CognitoProvider {
+    async fn credentials(&self) -> Result<AwsCredentials, CredentialsError> {
+        let mut cached = self.credentials.lock().await;
+        if let Some(ref credentials) = *cached {
+            if !is_expiring(credentials) {
+                return Ok(credentials.clone());
+            }
+        }
+        let identity_id = self.identity_id().await?;
+        let response = self
+            .client
+            .get_credentials_for_identity(GetCredentialsForIdentityInput {
+                custom_role_arn: self.custom_role_arn.clone(),
+                identity_id,
+                logins: self.logins(),
+            })
+            .await
+            .map_err(|err| {
+                CredentialsError::new(format!("Cognito GetCredentialsForIdentity error: {}", err))
+            })?;
+        let credentials = aws_credentials(response.credentials.ok_or_else(|| {
+            CredentialsError::new("No credentials in Cognito GetCredentialsForIdentity response")
+        })?)?;
+        *cached = Some(credentials.clone());
+        Ok(credentials)
+    }
+}
+
+impl std::fmt::Debug for CognitoProvider {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.debug_struct("CognitoProvider")
+            .field("identity_pool_id", &self.identity_pool_id)
+            .field("account_id", &self.account_id)
+            .field("logins", &self.logins.keys().collect::<Vec<_>>())
+            .field("custom_role_arn", &self.custom_role_arn)
+            .finish()
+    }
+}
+
+/// Credentials are fetched again this long before they expire.
+fn is_expiring(credentials: &AwsCredentials) -> bool {
+    match credentials.expires_at() {
+        Some(expires_at) => *expires_at < Utc::now() + Duration::minutes(5),
+        None => false,
+    }
+}
+
+fn aws_credentials(credentials: Credentials) -> Result<AwsCredentials, CredentialsError> {
+    let missing =
+        |field| CredentialsError::new(format!("No {} in Cognito Identity credentials", field));
+    let expires_at = match credentials.expiration {
+        Some(seconds) => Some(
+            Utc.timestamp_opt(seconds as i64, 0)
+                .single()
+                .ok_or_else(|| missing("valid expiration"))?,
+        ),
+        None => None,
+    };
+    Ok(AwsCredentials::new(
+        credentials
+            .access_key_id
+            .ok_or_else(|| missing("access key ID"))?,
+        credentials
+            .secret_key
+            .ok_or_else(|| missing("secret key"))?,
+        credentials.session_token,
+        expires_at,
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rusoto_core::signature::SignedRequest;
+    use rusoto_core::Region;
+    use rusoto_mock::{MockCredentialsProvider, MockRequestDispatcher};
+    use std::sync::Mutex as StdMutex;
+
+    // Answers both `cognito-identity:GetId` and `cognito-identity:GetCredentialsForIdentity`.
+    const RESPONSE: &str = r#"{"IdentityId": "us-east-1:abc", "Credentials": {"AccessKeyId": "key",
+        "SecretKey": "secret", "SessionToken": "token", "Expiration": 4102444800}}"#;
+
+    /// A provider for the pool `us-east-1:pool`, and the operations it calls.
+    fn provider() -> (CognitoProvider, Arc<StdMutex<Vec<String>>>) {
+        let targets = Arc::new(StdMutex::new(Vec::new()));
+        let recorded = targets.clone();
+        let dispatcher = MockRequestDispatcher::default()
+            .with_body(RESPONSE)
+            .with_request_checker(move |request: &SignedRequest| {
+                let target = &request.headers()["x-amz-target"][0];
+                recorded
+                    .lock()
+                    .unwrap()
+                    .push(String::from_utf8(target.clone()).unwrap());
+            });
+        let client =
+            CognitoIdentityClient::new_with(dispatcher, MockCredentialsProvider, Region::UsEast1);
+        (
+            CognitoProvider::new(client, "us-east-1:pool".to_owned()),
+            targets,
+        )
+    }
+
+    #[tokio::test]
+    async fn looks_up_identity_and_caches_credentials() {
+        let (provider, targets) = provider();
+        let credentials = provider.credentials().await.unwrap();
+        assert_eq!(credentials.aws_access_key_id(), "key");
+        assert_eq!(credentials.aws_secret_access_key(), "secret");
+        assert_eq!(credentials.token().as_deref(), Some("token"));
+        assert_eq!(credentials.expires_at().unwrap().timestamp(), 4_102_444_800);
+        provider.credentials().await.unwrap();
+        assert_eq!(provider.identity_id().await.unwrap(), "us-east-1:abc");
+        assert_eq!(
+            *targets.lock().unwrap(),
+            vec![
+                "AWSCognitoIdentityService.GetId",
+                "AWSCognitoIdentityService.GetCredentialsForIdentity",
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn refetches_credentials_after_new_logins() {
+        let (mut provider, targets) = provider();
+        provider.set_identity_id("us-east-1:known".to_owned());
+        provider.credentials().await.unwrap();
+        provider.add_login("accounts.google.com".to_owned(), "id-token".to_owned());
+        provider.credentials().await.unwrap();
+        assert_eq!(
+            *targets.lock().unwrap(),
+            vec![
+                "AWSCognitoIdentityService.GetCredentialsForIdentity",
+                "AWSCognitoIdentityService.GetCredentialsForIdentity",
+            ]
+        );
+    }
+}
@@ -1 +1,3 @@
+mod credential;
 
+pub use self::credential::CognitoProvider;
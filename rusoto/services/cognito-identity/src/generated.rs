@@ -16,8 +16,8 @@ use std::fmt;
 use async_trait::async_trait;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
-use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest, ResponseMetadata};
+use rusoto_core::{Client, ErrorMetadata, RusotoError};
 
 use rusoto_core::proto;
 use rusoto_core::signature::SignedRequest;
@@ -662,6 +662,7 @@ pub struct UntagResourceResponse {}
 
 /// Errors returned by CreateIdentityPool
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateIdentityPoolError {
     /// <p>Thrown when the service encounters an error during processing the request.</p>
     InternalError(String),
@@ -675,6 +676,8 @@ pub enum CreateIdentityPoolError {
     ResourceConflict(String),
     /// <p>Thrown when a request is throttled.</p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateIdentityPoolError {
@@ -682,25 +685,43 @@ impl CreateIdentityPoolError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalErrorException" => {
-                    return RusotoError::Service(CreateIdentityPoolError::InternalError(err.msg))
+                    return RusotoError::service(
+                        CreateIdentityPoolError::InternalError(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(CreateIdentityPoolError::InvalidParameter(err.msg))
+                    return RusotoError::service(
+                        CreateIdentityPoolError::InvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateIdentityPoolError::LimitExceeded(err.msg))
+                    return RusotoError::service(
+                        CreateIdentityPoolError::LimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "NotAuthorizedException" => {
-                    return RusotoError::Service(CreateIdentityPoolError::NotAuthorized(err.msg))
+                    return RusotoError::service(
+                        CreateIdentityPoolError::NotAuthorized(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceConflictException" => {
-                    return RusotoError::Service(CreateIdentityPoolError::ResourceConflict(err.msg))
+                    return RusotoError::service(
+                        CreateIdentityPoolError::ResourceConflict(err.msg),
+                        &res,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(CreateIdentityPoolError::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        CreateIdentityPoolError::TooManyRequests(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreateIdentityPoolError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -716,12 +737,14 @@ impl fmt::Display for CreateIdentityPoolError {
             CreateIdentityPoolError::NotAuthorized(ref cause) => write!(f, "{}", cause),
             CreateIdentityPoolError::ResourceConflict(ref cause) => write!(f, "{}", cause),
             CreateIdentityPoolError::TooManyRequests(ref cause) => write!(f, "{}", cause),
+            CreateIdentityPoolError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateIdentityPoolError {}
 /// Errors returned by DeleteIdentities
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteIdentitiesError {
     /// <p>Thrown when the service encounters an error during processing the request.</p>
     InternalError(String),
@@ -729,6 +752,8 @@ pub enum DeleteIdentitiesError {
     InvalidParameter(String),
     /// <p>Thrown when a request is throttled.</p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteIdentitiesError {
@@ -736,16 +761,25 @@ impl DeleteIdentitiesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalErrorException" => {
-                    return RusotoError::Service(DeleteIdentitiesError::InternalError(err.msg))
+                    return RusotoError::service(
+                        DeleteIdentitiesError::InternalError(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DeleteIdentitiesError::InvalidParameter(err.msg))
+                    return RusotoError::service(
+                        DeleteIdentitiesError::InvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(DeleteIdentitiesError::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        DeleteIdentitiesError::TooManyRequests(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteIdentitiesError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -758,12 +792,14 @@ impl fmt::Display for DeleteIdentitiesError {
             DeleteIdentitiesError::InternalError(ref cause) => write!(f, "{}", cause),
             DeleteIdentitiesError::InvalidParameter(ref cause) => write!(f, "{}", cause),
             DeleteIdentitiesError::TooManyRequests(ref cause) => write!(f, "{}", cause),
+            DeleteIdentitiesError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteIdentitiesError {}
 /// Errors returned by DeleteIdentityPool
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteIdentityPoolError {
     /// <p>Thrown when the service encounters an error during processing the request.</p>
     InternalError(String),
@@ -775,6 +811,8 @@ pub enum DeleteIdentityPoolError {
     ResourceNotFound(String),
     /// <p>Thrown when a request is throttled.</p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteIdentityPoolError {
@@ -782,22 +820,37 @@ impl DeleteIdentityPoolError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalErrorException" => {
-                    return RusotoError::Service(DeleteIdentityPoolError::InternalError(err.msg))
+                    return RusotoError::service(
+                        DeleteIdentityPoolError::InternalError(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DeleteIdentityPoolError::InvalidParameter(err.msg))
+                    return RusotoError::service(
+                        DeleteIdentityPoolError::InvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "NotAuthorizedException" => {
-                    return RusotoError::Service(DeleteIdentityPoolError::NotAuthorized(err.msg))
+                    return RusotoError::service(
+                        DeleteIdentityPoolError::NotAuthorized(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteIdentityPoolError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        DeleteIdentityPoolError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(DeleteIdentityPoolError::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        DeleteIdentityPoolError::TooManyRequests(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteIdentityPoolError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -812,12 +865,14 @@ impl fmt::Display for DeleteIdentityPoolError {
             DeleteIdentityPoolError::NotAuthorized(ref cause) => write!(f, "{}", cause),
             DeleteIdentityPoolError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             DeleteIdentityPoolError::TooManyRequests(ref cause) => write!(f, "{}", cause),
+            DeleteIdentityPoolError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteIdentityPoolError {}
 /// Errors returned by DescribeIdentity
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeIdentityError {
     /// <p>Thrown when the service encounters an error during processing the request.</p>
     InternalError(String),
@@ -829,6 +884,8 @@ pub enum DescribeIdentityError {
     ResourceNotFound(String),
     /// <p>Thrown when a request is throttled.</p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeIdentityError {
@@ -836,22 +893,37 @@ impl DescribeIdentityError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalErrorException" => {
-                    return RusotoError::Service(DescribeIdentityError::InternalError(err.msg))
+                    return RusotoError::service(
+                        DescribeIdentityError::InternalError(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DescribeIdentityError::InvalidParameter(err.msg))
+                    return RusotoError::service(
+                        DescribeIdentityError::InvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "NotAuthorizedException" => {
-                    return RusotoError::Service(DescribeIdentityError::NotAuthorized(err.msg))
+                    return RusotoError::service(
+                        DescribeIdentityError::NotAuthorized(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeIdentityError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        DescribeIdentityError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(DescribeIdentityError::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        DescribeIdentityError::TooManyRequests(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DescribeIdentityError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -866,12 +938,14 @@ impl fmt::Display for DescribeIdentityError {
             DescribeIdentityError::NotAuthorized(ref cause) => write!(f, "{}", cause),
             DescribeIdentityError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             DescribeIdentityError::TooManyRequests(ref cause) => write!(f, "{}", cause),
+            DescribeIdentityError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeIdentityError {}
 /// Errors returned by DescribeIdentityPool
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeIdentityPoolError {
     /// <p>Thrown when the service encounters an error during processing the request.</p>
     InternalError(String),
@@ -883,6 +957,8 @@ pub enum DescribeIdentityPoolError {
     ResourceNotFound(String),
     /// <p>Thrown when a request is throttled.</p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeIdentityPoolError {
@@ -890,28 +966,37 @@ impl DescribeIdentityPoolError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalErrorException" => {
-                    return RusotoError::Service(DescribeIdentityPoolError::InternalError(err.msg))
+                    return RusotoError::service(
+                        DescribeIdentityPoolError::InternalError(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DescribeIdentityPoolError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeIdentityPoolError::InvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "NotAuthorizedException" => {
-                    return RusotoError::Service(DescribeIdentityPoolError::NotAuthorized(err.msg))
+                    return RusotoError::service(
+                        DescribeIdentityPoolError::NotAuthorized(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeIdentityPoolError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeIdentityPoolError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(DescribeIdentityPoolError::TooManyRequests(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeIdentityPoolError::TooManyRequests(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DescribeIdentityPoolError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -926,12 +1011,14 @@ impl fmt::Display for DescribeIdentityPoolError {
             DescribeIdentityPoolError::NotAuthorized(ref cause) => write!(f, "{}", cause),
             DescribeIdentityPoolError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             DescribeIdentityPoolError::TooManyRequests(ref cause) => write!(f, "{}", cause),
+            DescribeIdentityPoolError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeIdentityPoolError {}
 /// Errors returned by GetCredentialsForIdentity
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetCredentialsForIdentityError {
     /// <p>An exception thrown when a dependent service such as Facebook or Twitter is not responding</p>
     ExternalService(String),
@@ -949,6 +1036,8 @@ pub enum GetCredentialsForIdentityError {
     ResourceNotFound(String),
     /// <p>Thrown when a request is throttled.</p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetCredentialsForIdentityError {
@@ -956,47 +1045,57 @@ impl GetCredentialsForIdentityError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ExternalServiceException" => {
-                    return RusotoError::Service(GetCredentialsForIdentityError::ExternalService(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetCredentialsForIdentityError::ExternalService(err.msg),
+                        &res,
+                    )
                 }
                 "InternalErrorException" => {
-                    return RusotoError::Service(GetCredentialsForIdentityError::InternalError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetCredentialsForIdentityError::InternalError(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidIdentityPoolConfigurationException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         GetCredentialsForIdentityError::InvalidIdentityPoolConfiguration(err.msg),
+                        &res,
                     )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(GetCredentialsForIdentityError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetCredentialsForIdentityError::InvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "NotAuthorizedException" => {
-                    return RusotoError::Service(GetCredentialsForIdentityError::NotAuthorized(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetCredentialsForIdentityError::NotAuthorized(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceConflictException" => {
-                    return RusotoError::Service(GetCredentialsForIdentityError::ResourceConflict(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetCredentialsForIdentityError::ResourceConflict(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetCredentialsForIdentityError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetCredentialsForIdentityError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(GetCredentialsForIdentityError::TooManyRequests(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetCredentialsForIdentityError::TooManyRequests(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(GetCredentialsForIdentityError::Unhandled, &res)
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -1016,12 +1115,14 @@ impl fmt::Display for GetCredentialsForIdentityError {
             GetCredentialsForIdentityError::ResourceConflict(ref cause) => write!(f, "{}", cause),
             GetCredentialsForIdentityError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             GetCredentialsForIdentityError::TooManyRequests(ref cause) => write!(f, "{}", cause),
+            GetCredentialsForIdentityError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetCredentialsForIdentityError {}
 /// Errors returned by GetId
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetIdError {
     /// <p>An exception thrown when a dependent service such as Facebook or Twitter is not responding</p>
     ExternalService(String),
@@ -1039,6 +1140,8 @@ pub enum GetIdError {
     ResourceNotFound(String),
     /// <p>Thrown when a request is throttled.</p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetIdError {
@@ -1046,31 +1149,31 @@ impl GetIdError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ExternalServiceException" => {
-                    return RusotoError::Service(GetIdError::ExternalService(err.msg))
+                    return RusotoError::service(GetIdError::ExternalService(err.msg), &res)
                 }
                 "InternalErrorException" => {
-                    return RusotoError::Service(GetIdError::InternalError(err.msg))
+                    return RusotoError::service(GetIdError::InternalError(err.msg), &res)
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(GetIdError::InvalidParameter(err.msg))
+                    return RusotoError::service(GetIdError::InvalidParameter(err.msg), &res)
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(GetIdError::LimitExceeded(err.msg))
+                    return RusotoError::service(GetIdError::LimitExceeded(err.msg), &res)
                 }
                 "NotAuthorizedException" => {
-                    return RusotoError::Service(GetIdError::NotAuthorized(err.msg))
+                    return RusotoError::service(GetIdError::NotAuthorized(err.msg), &res)
                 }
                 "ResourceConflictException" => {
-                    return RusotoError::Service(GetIdError::ResourceConflict(err.msg))
+                    return RusotoError::service(GetIdError::ResourceConflict(err.msg), &res)
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetIdError::ResourceNotFound(err.msg))
+                    return RusotoError::service(GetIdError::ResourceNotFound(err.msg), &res)
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(GetIdError::TooManyRequests(err.msg))
+                    return RusotoError::service(GetIdError::TooManyRequests(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetIdError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1088,12 +1191,14 @@ impl fmt::Display for GetIdError {
             GetIdError::ResourceConflict(ref cause) => write!(f, "{}", cause),
             GetIdError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             GetIdError::TooManyRequests(ref cause) => write!(f, "{}", cause),
+            GetIdError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetIdError {}
 /// Errors returned by GetIdentityPoolRoles
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetIdentityPoolRolesError {
     /// <p>Thrown when the service encounters an error during processing the request.</p>
     InternalError(String),
@@ -1107,6 +1212,8 @@ pub enum GetIdentityPoolRolesError {
     ResourceNotFound(String),
     /// <p>Thrown when a request is throttled.</p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetIdentityPoolRolesError {
@@ -1114,33 +1221,43 @@ impl GetIdentityPoolRolesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalErrorException" => {
-                    return RusotoError::Service(GetIdentityPoolRolesError::InternalError(err.msg))
+                    return RusotoError::service(
+                        GetIdentityPoolRolesError::InternalError(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(GetIdentityPoolRolesError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetIdentityPoolRolesError::InvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "NotAuthorizedException" => {
-                    return RusotoError::Service(GetIdentityPoolRolesError::NotAuthorized(err.msg))
+                    return RusotoError::service(
+                        GetIdentityPoolRolesError::NotAuthorized(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceConflictException" => {
-                    return RusotoError::Service(GetIdentityPoolRolesError::ResourceConflict(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetIdentityPoolRolesError::ResourceConflict(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetIdentityPoolRolesError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetIdentityPoolRolesError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(GetIdentityPoolRolesError::TooManyRequests(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetIdentityPoolRolesError::TooManyRequests(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetIdentityPoolRolesError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1156,12 +1273,14 @@ impl fmt::Display for GetIdentityPoolRolesError {
             GetIdentityPoolRolesError::ResourceConflict(ref cause) => write!(f, "{}", cause),
             GetIdentityPoolRolesError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             GetIdentityPoolRolesError::TooManyRequests(ref cause) => write!(f, "{}", cause),
+            GetIdentityPoolRolesError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetIdentityPoolRolesError {}
 /// Errors returned by GetOpenIdToken
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetOpenIdTokenError {
     /// <p>An exception thrown when a dependent service such as Facebook or Twitter is not responding</p>
     ExternalService(String),
@@ -1177,6 +1296,8 @@ pub enum GetOpenIdTokenError {
     ResourceNotFound(String),
     /// <p>Thrown when a request is throttled.</p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetOpenIdTokenError {
@@ -1184,28 +1305,43 @@ impl GetOpenIdTokenError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ExternalServiceException" => {
-                    return RusotoError::Service(GetOpenIdTokenError::ExternalService(err.msg))
+                    return RusotoError::service(
+                        GetOpenIdTokenError::ExternalService(err.msg),
+                        &res,
+                    )
                 }
                 "InternalErrorException" => {
-                    return RusotoError::Service(GetOpenIdTokenError::InternalError(err.msg))
+                    return RusotoError::service(GetOpenIdTokenError::InternalError(err.msg), &res)
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(GetOpenIdTokenError::InvalidParameter(err.msg))
+                    return RusotoError::service(
+                        GetOpenIdTokenError::InvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "NotAuthorizedException" => {
-                    return RusotoError::Service(GetOpenIdTokenError::NotAuthorized(err.msg))
+                    return RusotoError::service(GetOpenIdTokenError::NotAuthorized(err.msg), &res)
                 }
                 "ResourceConflictException" => {
-                    return RusotoError::Service(GetOpenIdTokenError::ResourceConflict(err.msg))
+                    return RusotoError::service(
+                        GetOpenIdTokenError::ResourceConflict(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetOpenIdTokenError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        GetOpenIdTokenError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(GetOpenIdTokenError::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        GetOpenIdTokenError::TooManyRequests(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetOpenIdTokenError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1222,12 +1358,14 @@ impl fmt::Display for GetOpenIdTokenError {
             GetOpenIdTokenError::ResourceConflict(ref cause) => write!(f, "{}", cause),
             GetOpenIdTokenError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             GetOpenIdTokenError::TooManyRequests(ref cause) => write!(f, "{}", cause),
+            GetOpenIdTokenError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetOpenIdTokenError {}
 /// Errors returned by GetOpenIdTokenForDeveloperIdentity
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetOpenIdTokenForDeveloperIdentityError {
     /// <p>The provided developer user identifier is already registered with Cognito under a different identity ID.</p>
     DeveloperUserAlreadyRegistered(String),
@@ -1243,6 +1381,8 @@ pub enum GetOpenIdTokenForDeveloperIdentityError {
     ResourceNotFound(String),
     /// <p>Thrown when a request is throttled.</p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetOpenIdTokenForDeveloperIdentityError {
@@ -1252,44 +1392,56 @@ impl GetOpenIdTokenForDeveloperIdentityError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DeveloperUserAlreadyRegisteredException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         GetOpenIdTokenForDeveloperIdentityError::DeveloperUserAlreadyRegistered(
                             err.msg,
                         ),
+                        &res,
                     )
                 }
                 "InternalErrorException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         GetOpenIdTokenForDeveloperIdentityError::InternalError(err.msg),
+                        &res,
                     )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         GetOpenIdTokenForDeveloperIdentityError::InvalidParameter(err.msg),
+                        &res,
                     )
                 }
                 "NotAuthorizedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         GetOpenIdTokenForDeveloperIdentityError::NotAuthorized(err.msg),
+                        &res,
                     )
                 }
                 "ResourceConflictException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         GetOpenIdTokenForDeveloperIdentityError::ResourceConflict(err.msg),
+                        &res,
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         GetOpenIdTokenForDeveloperIdentityError::ResourceNotFound(err.msg),
+                        &res,
                     )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         GetOpenIdTokenForDeveloperIdentityError::TooManyRequests(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        GetOpenIdTokenForDeveloperIdentityError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -1320,12 +1472,16 @@ impl fmt::Display for GetOpenIdTokenForDeveloperIdentityError {
             GetOpenIdTokenForDeveloperIdentityError::TooManyRequests(ref cause) => {
                 write!(f, "{}", cause)
             }
+            GetOpenIdTokenForDeveloperIdentityError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for GetOpenIdTokenForDeveloperIdentityError {}
 /// Errors returned by ListIdentities
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListIdentitiesError {
     /// <p>Thrown when the service encounters an error during processing the request.</p>
     InternalError(String),
@@ -1337,6 +1493,8 @@ pub enum ListIdentitiesError {
     ResourceNotFound(String),
     /// <p>Thrown when a request is throttled.</p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListIdentitiesError {
@@ -1344,22 +1502,31 @@ impl ListIdentitiesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalErrorException" => {
-                    return RusotoError::Service(ListIdentitiesError::InternalError(err.msg))
+                    return RusotoError::service(ListIdentitiesError::InternalError(err.msg), &res)
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(ListIdentitiesError::InvalidParameter(err.msg))
+                    return RusotoError::service(
+                        ListIdentitiesError::InvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "NotAuthorizedException" => {
-                    return RusotoError::Service(ListIdentitiesError::NotAuthorized(err.msg))
+                    return RusotoError::service(ListIdentitiesError::NotAuthorized(err.msg), &res)
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListIdentitiesError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        ListIdentitiesError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(ListIdentitiesError::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        ListIdentitiesError::TooManyRequests(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListIdentitiesError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1374,12 +1541,14 @@ impl fmt::Display for ListIdentitiesError {
             ListIdentitiesError::NotAuthorized(ref cause) => write!(f, "{}", cause),
             ListIdentitiesError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             ListIdentitiesError::TooManyRequests(ref cause) => write!(f, "{}", cause),
+            ListIdentitiesError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListIdentitiesError {}
 /// Errors returned by ListIdentityPools
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListIdentityPoolsError {
     /// <p>Thrown when the service encounters an error during processing the request.</p>
     InternalError(String),
@@ -1391,6 +1560,8 @@ pub enum ListIdentityPoolsError {
     ResourceNotFound(String),
     /// <p>Thrown when a request is throttled.</p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListIdentityPoolsError {
@@ -1398,22 +1569,37 @@ impl ListIdentityPoolsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalErrorException" => {
-                    return RusotoError::Service(ListIdentityPoolsError::InternalError(err.msg))
+                    return RusotoError::service(
+                        ListIdentityPoolsError::InternalError(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(ListIdentityPoolsError::InvalidParameter(err.msg))
+                    return RusotoError::service(
+                        ListIdentityPoolsError::InvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "NotAuthorizedException" => {
-                    return RusotoError::Service(ListIdentityPoolsError::NotAuthorized(err.msg))
+                    return RusotoError::service(
+                        ListIdentityPoolsError::NotAuthorized(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListIdentityPoolsError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        ListIdentityPoolsError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(ListIdentityPoolsError::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        ListIdentityPoolsError::TooManyRequests(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListIdentityPoolsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1428,12 +1614,14 @@ impl fmt::Display for ListIdentityPoolsError {
             ListIdentityPoolsError::NotAuthorized(ref cause) => write!(f, "{}", cause),
             ListIdentityPoolsError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             ListIdentityPoolsError::TooManyRequests(ref cause) => write!(f, "{}", cause),
+            ListIdentityPoolsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListIdentityPoolsError {}
 /// Errors returned by ListTagsForResource
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListTagsForResourceError {
     /// <p>Thrown when the service encounters an error during processing the request.</p>
     InternalError(String),
@@ -1445,6 +1633,8 @@ pub enum ListTagsForResourceError {
     ResourceNotFound(String),
     /// <p>Thrown when a request is throttled.</p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListTagsForResourceError {
@@ -1452,26 +1642,37 @@ impl ListTagsForResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalErrorException" => {
-                    return RusotoError::Service(ListTagsForResourceError::InternalError(err.msg))
+                    return RusotoError::service(
+                        ListTagsForResourceError::InternalError(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(ListTagsForResourceError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListTagsForResourceError::InvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "NotAuthorizedException" => {
-                    return RusotoError::Service(ListTagsForResourceError::NotAuthorized(err.msg))
+                    return RusotoError::service(
+                        ListTagsForResourceError::NotAuthorized(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListTagsForResourceError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListTagsForResourceError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(ListTagsForResourceError::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        ListTagsForResourceError::TooManyRequests(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListTagsForResourceError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1486,12 +1687,14 @@ impl fmt::Display for ListTagsForResourceError {
             ListTagsForResourceError::NotAuthorized(ref cause) => write!(f, "{}", cause),
             ListTagsForResourceError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             ListTagsForResourceError::TooManyRequests(ref cause) => write!(f, "{}", cause),
+            ListTagsForResourceError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListTagsForResourceError {}
 /// Errors returned by LookupDeveloperIdentity
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum LookupDeveloperIdentityError {
     /// <p>Thrown when the service encounters an error during processing the request.</p>
     InternalError(String),
@@ -1505,6 +1708,8 @@ pub enum LookupDeveloperIdentityError {
     ResourceNotFound(String),
     /// <p>Thrown when a request is throttled.</p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl LookupDeveloperIdentityError {
@@ -1512,37 +1717,43 @@ impl LookupDeveloperIdentityError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalErrorException" => {
-                    return RusotoError::Service(LookupDeveloperIdentityError::InternalError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        LookupDeveloperIdentityError::InternalError(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(LookupDeveloperIdentityError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        LookupDeveloperIdentityError::InvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "NotAuthorizedException" => {
-                    return RusotoError::Service(LookupDeveloperIdentityError::NotAuthorized(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        LookupDeveloperIdentityError::NotAuthorized(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceConflictException" => {
-                    return RusotoError::Service(LookupDeveloperIdentityError::ResourceConflict(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        LookupDeveloperIdentityError::ResourceConflict(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(LookupDeveloperIdentityError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        LookupDeveloperIdentityError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(LookupDeveloperIdentityError::TooManyRequests(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        LookupDeveloperIdentityError::TooManyRequests(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(LookupDeveloperIdentityError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1558,12 +1769,14 @@ impl fmt::Display for LookupDeveloperIdentityError {
             LookupDeveloperIdentityError::ResourceConflict(ref cause) => write!(f, "{}", cause),
             LookupDeveloperIdentityError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             LookupDeveloperIdentityError::TooManyRequests(ref cause) => write!(f, "{}", cause),
+            LookupDeveloperIdentityError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for LookupDeveloperIdentityError {}
 /// Errors returned by MergeDeveloperIdentities
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum MergeDeveloperIdentitiesError {
     /// <p>Thrown when the service encounters an error during processing the request.</p>
     InternalError(String),
@@ -1577,6 +1790,8 @@ pub enum MergeDeveloperIdentitiesError {
     ResourceNotFound(String),
     /// <p>Thrown when a request is throttled.</p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl MergeDeveloperIdentitiesError {
@@ -1584,37 +1799,43 @@ impl MergeDeveloperIdentitiesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalErrorException" => {
-                    return RusotoError::Service(MergeDeveloperIdentitiesError::InternalError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        MergeDeveloperIdentitiesError::InternalError(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(MergeDeveloperIdentitiesError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        MergeDeveloperIdentitiesError::InvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "NotAuthorizedException" => {
-                    return RusotoError::Service(MergeDeveloperIdentitiesError::NotAuthorized(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        MergeDeveloperIdentitiesError::NotAuthorized(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceConflictException" => {
-                    return RusotoError::Service(MergeDeveloperIdentitiesError::ResourceConflict(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        MergeDeveloperIdentitiesError::ResourceConflict(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(MergeDeveloperIdentitiesError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        MergeDeveloperIdentitiesError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(MergeDeveloperIdentitiesError::TooManyRequests(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        MergeDeveloperIdentitiesError::TooManyRequests(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(MergeDeveloperIdentitiesError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1630,12 +1851,14 @@ impl fmt::Display for MergeDeveloperIdentitiesError {
             MergeDeveloperIdentitiesError::ResourceConflict(ref cause) => write!(f, "{}", cause),
             MergeDeveloperIdentitiesError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             MergeDeveloperIdentitiesError::TooManyRequests(ref cause) => write!(f, "{}", cause),
+            MergeDeveloperIdentitiesError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for MergeDeveloperIdentitiesError {}
 /// Errors returned by SetIdentityPoolRoles
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum SetIdentityPoolRolesError {
     /// <p>Thrown if there are parallel requests to modify a resource.</p>
     ConcurrentModification(String),
@@ -1651,6 +1874,8 @@ pub enum SetIdentityPoolRolesError {
     ResourceNotFound(String),
     /// <p>Thrown when a request is throttled.</p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl SetIdentityPoolRolesError {
@@ -1658,38 +1883,49 @@ impl SetIdentityPoolRolesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(SetIdentityPoolRolesError::ConcurrentModification(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        SetIdentityPoolRolesError::ConcurrentModification(err.msg),
+                        &res,
+                    )
                 }
                 "InternalErrorException" => {
-                    return RusotoError::Service(SetIdentityPoolRolesError::InternalError(err.msg))
+                    return RusotoError::service(
+                        SetIdentityPoolRolesError::InternalError(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(SetIdentityPoolRolesError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        SetIdentityPoolRolesError::InvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "NotAuthorizedException" => {
-                    return RusotoError::Service(SetIdentityPoolRolesError::NotAuthorized(err.msg))
+                    return RusotoError::service(
+                        SetIdentityPoolRolesError::NotAuthorized(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceConflictException" => {
-                    return RusotoError::Service(SetIdentityPoolRolesError::ResourceConflict(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        SetIdentityPoolRolesError::ResourceConflict(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(SetIdentityPoolRolesError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        SetIdentityPoolRolesError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(SetIdentityPoolRolesError::TooManyRequests(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        SetIdentityPoolRolesError::TooManyRequests(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(SetIdentityPoolRolesError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1706,12 +1942,14 @@ impl fmt::Display for SetIdentityPoolRolesError {
             SetIdentityPoolRolesError::ResourceConflict(ref cause) => write!(f, "{}", cause),
             SetIdentityPoolRolesError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             SetIdentityPoolRolesError::TooManyRequests(ref cause) => write!(f, "{}", cause),
+            SetIdentityPoolRolesError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for SetIdentityPoolRolesError {}
 /// Errors returned by TagResource
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum TagResourceError {
     /// <p>Thrown when the service encounters an error during processing the request.</p>
     InternalError(String),
@@ -1723,6 +1961,8 @@ pub enum TagResourceError {
     ResourceNotFound(String),
     /// <p>Thrown when a request is throttled.</p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl TagResourceError {
@@ -1730,22 +1970,22 @@ impl TagResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalErrorException" => {
-                    return RusotoError::Service(TagResourceError::InternalError(err.msg))
+                    return RusotoError::service(TagResourceError::InternalError(err.msg), &res)
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(TagResourceError::InvalidParameter(err.msg))
+                    return RusotoError::service(TagResourceError::InvalidParameter(err.msg), &res)
                 }
                 "NotAuthorizedException" => {
-                    return RusotoError::Service(TagResourceError::NotAuthorized(err.msg))
+                    return RusotoError::service(TagResourceError::NotAuthorized(err.msg), &res)
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(TagResourceError::ResourceNotFound(err.msg))
+                    return RusotoError::service(TagResourceError::ResourceNotFound(err.msg), &res)
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(TagResourceError::TooManyRequests(err.msg))
+                    return RusotoError::service(TagResourceError::TooManyRequests(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(TagResourceError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1760,12 +2000,14 @@ impl fmt::Display for TagResourceError {
             TagResourceError::NotAuthorized(ref cause) => write!(f, "{}", cause),
             TagResourceError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             TagResourceError::TooManyRequests(ref cause) => write!(f, "{}", cause),
+            TagResourceError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for TagResourceError {}
 /// Errors returned by UnlinkDeveloperIdentity
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UnlinkDeveloperIdentityError {
     /// <p>Thrown when the service encounters an error during processing the request.</p>
     InternalError(String),
@@ -1779,6 +2021,8 @@ pub enum UnlinkDeveloperIdentityError {
     ResourceNotFound(String),
     /// <p>Thrown when a request is throttled.</p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UnlinkDeveloperIdentityError {
@@ -1786,37 +2030,43 @@ impl UnlinkDeveloperIdentityError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalErrorException" => {
-                    return RusotoError::Service(UnlinkDeveloperIdentityError::InternalError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UnlinkDeveloperIdentityError::InternalError(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(UnlinkDeveloperIdentityError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UnlinkDeveloperIdentityError::InvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "NotAuthorizedException" => {
-                    return RusotoError::Service(UnlinkDeveloperIdentityError::NotAuthorized(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UnlinkDeveloperIdentityError::NotAuthorized(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceConflictException" => {
-                    return RusotoError::Service(UnlinkDeveloperIdentityError::ResourceConflict(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UnlinkDeveloperIdentityError::ResourceConflict(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UnlinkDeveloperIdentityError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UnlinkDeveloperIdentityError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(UnlinkDeveloperIdentityError::TooManyRequests(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UnlinkDeveloperIdentityError::TooManyRequests(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UnlinkDeveloperIdentityError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1832,12 +2082,14 @@ impl fmt::Display for UnlinkDeveloperIdentityError {
             UnlinkDeveloperIdentityError::ResourceConflict(ref cause) => write!(f, "{}", cause),
             UnlinkDeveloperIdentityError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             UnlinkDeveloperIdentityError::TooManyRequests(ref cause) => write!(f, "{}", cause),
+            UnlinkDeveloperIdentityError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for UnlinkDeveloperIdentityError {}
 /// Errors returned by UnlinkIdentity
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UnlinkIdentityError {
     /// <p>An exception thrown when a dependent service such as Facebook or Twitter is not responding</p>
     ExternalService(String),
@@ -1853,6 +2105,8 @@ pub enum UnlinkIdentityError {
     ResourceNotFound(String),
     /// <p>Thrown when a request is throttled.</p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UnlinkIdentityError {
@@ -1860,28 +2114,43 @@ impl UnlinkIdentityError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ExternalServiceException" => {
-                    return RusotoError::Service(UnlinkIdentityError::ExternalService(err.msg))
+                    return RusotoError::service(
+                        UnlinkIdentityError::ExternalService(err.msg),
+                        &res,
+                    )
                 }
                 "InternalErrorException" => {
-                    return RusotoError::Service(UnlinkIdentityError::InternalError(err.msg))
+                    return RusotoError::service(UnlinkIdentityError::InternalError(err.msg), &res)
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(UnlinkIdentityError::InvalidParameter(err.msg))
+                    return RusotoError::service(
+                        UnlinkIdentityError::InvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "NotAuthorizedException" => {
-                    return RusotoError::Service(UnlinkIdentityError::NotAuthorized(err.msg))
+                    return RusotoError::service(UnlinkIdentityError::NotAuthorized(err.msg), &res)
                 }
                 "ResourceConflictException" => {
-                    return RusotoError::Service(UnlinkIdentityError::ResourceConflict(err.msg))
+                    return RusotoError::service(
+                        UnlinkIdentityError::ResourceConflict(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UnlinkIdentityError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        UnlinkIdentityError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(UnlinkIdentityError::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        UnlinkIdentityError::TooManyRequests(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UnlinkIdentityError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1898,12 +2167,14 @@ impl fmt::Display for UnlinkIdentityError {
             UnlinkIdentityError::ResourceConflict(ref cause) => write!(f, "{}", cause),
             UnlinkIdentityError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             UnlinkIdentityError::TooManyRequests(ref cause) => write!(f, "{}", cause),
+            UnlinkIdentityError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for UnlinkIdentityError {}
 /// Errors returned by UntagResource
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UntagResourceError {
     /// <p>Thrown when the service encounters an error during processing the request.</p>
     InternalError(String),
@@ -1915,6 +2186,8 @@ pub enum UntagResourceError {
     ResourceNotFound(String),
     /// <p>Thrown when a request is throttled.</p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UntagResourceError {
@@ -1922,22 +2195,28 @@ impl UntagResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalErrorException" => {
-                    return RusotoError::Service(UntagResourceError::InternalError(err.msg))
+                    return RusotoError::service(UntagResourceError::InternalError(err.msg), &res)
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(UntagResourceError::InvalidParameter(err.msg))
+                    return RusotoError::service(
+                        UntagResourceError::InvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "NotAuthorizedException" => {
-                    return RusotoError::Service(UntagResourceError::NotAuthorized(err.msg))
+                    return RusotoError::service(UntagResourceError::NotAuthorized(err.msg), &res)
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UntagResourceError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        UntagResourceError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(UntagResourceError::TooManyRequests(err.msg))
+                    return RusotoError::service(UntagResourceError::TooManyRequests(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UntagResourceError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1952,12 +2231,14 @@ impl fmt::Display for UntagResourceError {
             UntagResourceError::NotAuthorized(ref cause) => write!(f, "{}", cause),
             UntagResourceError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             UntagResourceError::TooManyRequests(ref cause) => write!(f, "{}", cause),
+            UntagResourceError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for UntagResourceError {}
 /// Errors returned by UpdateIdentityPool
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdateIdentityPoolError {
     /// <p>Thrown if there are parallel requests to modify a resource.</p>
     ConcurrentModification(String),
@@ -1975,6 +2256,8 @@ pub enum UpdateIdentityPoolError {
     ResourceNotFound(String),
     /// <p>Thrown when a request is throttled.</p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdateIdentityPoolError {
@@ -1982,33 +2265,55 @@ impl UpdateIdentityPoolError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(UpdateIdentityPoolError::ConcurrentModification(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateIdentityPoolError::ConcurrentModification(err.msg),
+                        &res,
+                    )
                 }
                 "InternalErrorException" => {
-                    return RusotoError::Service(UpdateIdentityPoolError::InternalError(err.msg))
+                    return RusotoError::service(
+                        UpdateIdentityPoolError::InternalError(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(UpdateIdentityPoolError::InvalidParameter(err.msg))
+                    return RusotoError::service(
+                        UpdateIdentityPoolError::InvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(UpdateIdentityPoolError::LimitExceeded(err.msg))
+                    return RusotoError::service(
+                        UpdateIdentityPoolError::LimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "NotAuthorizedException" => {
-                    return RusotoError::Service(UpdateIdentityPoolError::NotAuthorized(err.msg))
+                    return RusotoError::service(
+                        UpdateIdentityPoolError::NotAuthorized(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceConflictException" => {
-                    return RusotoError::Service(UpdateIdentityPoolError::ResourceConflict(err.msg))
+                    return RusotoError::service(
+                        UpdateIdentityPoolError::ResourceConflict(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateIdentityPoolError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        UpdateIdentityPoolError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(UpdateIdentityPoolError::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        UpdateIdentityPoolError::TooManyRequests(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UpdateIdentityPoolError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2026,6 +2331,7 @@ impl fmt::Display for UpdateIdentityPoolError {
             UpdateIdentityPoolError::ResourceConflict(ref cause) => write!(f, "{}", cause),
             UpdateIdentityPoolError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             UpdateIdentityPoolError::TooManyRequests(ref cause) => write!(f, "{}", cause),
+            UpdateIdentityPoolError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
@@ -2170,6 +2476,7 @@ impl CognitoIdentityClient {
     /// Creates a client backed by the default tokio event loop.
     ///
     /// The client will use the default credentials provider and tls client.
+    #[cfg(not(target_arch = "wasm32"))]
     pub fn new(region: region::Region) -> CognitoIdentityClient {
         CognitoIdentityClient {
             client: Client::shared(),
@@ -2195,16 +2502,67 @@ impl CognitoIdentityClient {
     pub fn new_with_client(client: Client, region: region::Region) -> CognitoIdentityClient {
         CognitoIdentityClient { client, region }
     }
+
+    /// Returns a builder of a client, setting any of its region, credentials provider,
+    /// dispatcher, retry policy, endpoint and timeouts.
+    pub fn builder() -> rusoto_core::ClientBuilder<CognitoIdentityClient> {
+        rusoto_core::ClientBuilder::new(CognitoIdentityClient::new_with_client)
+    }
+
+    /// Returns a clone of this client with the overrides of `config` applied to its
+    /// operations, sharing its connections.
+    pub fn customize(&self, config: &rusoto_core::OperationConfig) -> CognitoIdentityClient {
+        CognitoIdentityClient {
+            client: self.client.customize(config),
+            region: config.resolve_region(&self.region),
+        }
+    }
 }
 
-#[async_trait]
-impl CognitoIdentity for CognitoIdentityClient {
-    /// <p>Creates a new identity pool. The identity pool is a store of user identity information that is specific to your AWS account. The keys for <code>SupportedLoginProviders</code> are as follows:</p> <ul> <li> <p>Facebook: <code>graph.facebook.com</code> </p> </li> <li> <p>Google: <code>accounts.google.com</code> </p> </li> <li> <p>Amazon: <code>www.amazon.com</code> </p> </li> <li> <p>Twitter: <code>api.twitter.com</code> </p> </li> <li> <p>Digits: <code>www.digits.com</code> </p> </li> </ul> <p>You must use AWS Developer credentials to call this API.</p>
-    async fn create_identity_pool(
+impl CognitoIdentityClient {
+    /// Calls `create_identity_pool`, also returning the metadata of the response.
+    pub async fn create_identity_pool_with_metadata(
         &self,
         input: CreateIdentityPoolInput,
-    ) -> Result<IdentityPool, RusotoError<CreateIdentityPoolError>> {
+    ) -> Result<(IdentityPool, ResponseMetadata), RusotoError<CreateIdentityPoolError>> {
+        let request = self.build_create_identity_pool_request(input);
+
+        let mut response = self
+            .client
+            .sign_and_dispatch(request)
+            .await
+            .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
+        if response.status.is_success() {
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result =
+                proto::json::ResponsePayload::new(&response).deserialize::<IdentityPool, _>()?;
+            Ok((result, metadata))
+        } else {
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(CreateIdentityPoolError::from_response(response))
+        }
+    }
+
+    /// Presigns the request `create_identity_pool` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_identity_pool(
+        &self,
+        presigner: &rusoto_core::Presigner,
+        input: CreateIdentityPoolInput,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_identity_pool_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_identity_pool_request(&self, input: CreateIdentityPoolInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "cognito-identity", &self.region, "/");
+        request.set_operation("CreateIdentityPool");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -2214,55 +2572,100 @@ impl CognitoIdentity for CognitoIdentityClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `delete_identities`, also returning the metadata of the response.
+    pub async fn delete_identities_with_metadata(
+        &self,
+        input: DeleteIdentitiesInput,
+    ) -> Result<(DeleteIdentitiesResponse, ResponseMetadata), RusotoError<DeleteIdentitiesError>>
+    {
+        let request = self.build_delete_identities_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<IdentityPool, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DeleteIdentitiesResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(CreateIdentityPoolError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DeleteIdentitiesError::from_response(response))
         }
     }
 
-    /// <p>Deletes identities from an identity pool. You can specify a list of 1-60 identities that you want to delete.</p> <p>You must use AWS Developer credentials to call this API.</p>
-    async fn delete_identities(
+    /// Presigns the request `delete_identities` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_identities(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteIdentitiesInput,
-    ) -> Result<DeleteIdentitiesResponse, RusotoError<DeleteIdentitiesError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_identities_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_identities_request(&self, input: DeleteIdentitiesInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "cognito-identity", &self.region, "/");
+        request.set_operation("DeleteIdentities");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSCognitoIdentityService.DeleteIdentities");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `delete_identity_pool`, also returning the metadata of the response.
+    pub async fn delete_identity_pool_with_metadata(
+        &self,
+        input: DeleteIdentityPoolInput,
+    ) -> Result<((), ResponseMetadata), RusotoError<DeleteIdentityPoolError>> {
+        let request = self.build_delete_identity_pool_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DeleteIdentitiesResponse, _>()
+            std::mem::drop(response);
+            Ok(((), metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteIdentitiesError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DeleteIdentityPoolError::from_response(response))
         }
     }
 
-    /// <p>Deletes an identity pool. Once a pool is deleted, users will not be able to authenticate with the pool.</p> <p>You must use AWS Developer credentials to call this API.</p>
-    async fn delete_identity_pool(
+    /// Presigns the request `delete_identity_pool` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_identity_pool(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteIdentityPoolInput,
-    ) -> Result<(), RusotoError<DeleteIdentityPoolError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_identity_pool_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_identity_pool_request(&self, input: DeleteIdentityPoolInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "cognito-identity", &self.region, "/");
+        request.set_operation("DeleteIdentityPool");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -2272,54 +2675,107 @@ impl CognitoIdentity for CognitoIdentityClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `describe_identity`, also returning the metadata of the response.
+    pub async fn describe_identity_with_metadata(
+        &self,
+        input: DescribeIdentityInput,
+    ) -> Result<(IdentityDescription, ResponseMetadata), RusotoError<DescribeIdentityError>> {
+        let request = self.build_describe_identity_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            std::mem::drop(response);
-            Ok(())
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<IdentityDescription, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteIdentityPoolError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DescribeIdentityError::from_response(response))
         }
     }
 
-    /// <p>Returns metadata related to the given identity, including when the identity was created and any associated linked logins.</p> <p>You must use AWS Developer credentials to call this API.</p>
-    async fn describe_identity(
+    /// Presigns the request `describe_identity` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_identity(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeIdentityInput,
-    ) -> Result<IdentityDescription, RusotoError<DescribeIdentityError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_identity_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_identity_request(&self, input: DescribeIdentityInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "cognito-identity", &self.region, "/");
+        request.set_operation("DescribeIdentity");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSCognitoIdentityService.DescribeIdentity");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `describe_identity_pool`, also returning the metadata of the response.
+    pub async fn describe_identity_pool_with_metadata(
+        &self,
+        input: DescribeIdentityPoolInput,
+    ) -> Result<(IdentityPool, ResponseMetadata), RusotoError<DescribeIdentityPoolError>> {
+        let request = self.build_describe_identity_pool_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<IdentityDescription, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result =
+                proto::json::ResponsePayload::new(&response).deserialize::<IdentityPool, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeIdentityError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DescribeIdentityPoolError::from_response(response))
         }
     }
 
-    /// <p>Gets details about a particular identity pool, including the pool name, ID description, creation date, and current number of users.</p> <p>You must use AWS Developer credentials to call this API.</p>
-    async fn describe_identity_pool(
+    /// Presigns the request `describe_identity_pool` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_identity_pool(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeIdentityPoolInput,
-    ) -> Result<IdentityPool, RusotoError<DescribeIdentityPoolError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_identity_pool_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_identity_pool_request(
+        &self,
+        input: DescribeIdentityPoolInput,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "cognito-identity", &self.region, "/");
+        request.set_operation("DescribeIdentityPool");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -2329,28 +2785,58 @@ impl CognitoIdentity for CognitoIdentityClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `get_credentials_for_identity`, also returning the metadata of the response.
+    pub async fn get_credentials_for_identity_with_metadata(
+        &self,
+        input: GetCredentialsForIdentityInput,
+    ) -> Result<
+        (GetCredentialsForIdentityResponse, ResponseMetadata),
+        RusotoError<GetCredentialsForIdentityError>,
+    > {
+        let request = self.build_get_credentials_for_identity_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<IdentityPool, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<GetCredentialsForIdentityResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeIdentityPoolError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(GetCredentialsForIdentityError::from_response(response))
         }
     }
 
-    /// <p>Returns credentials for the provided identity ID. Any provided logins will be validated against supported login providers. If the token is for cognito-identity.amazonaws.com, it will be passed through to AWS Security Token Service with the appropriate role for the token.</p> <p>This is a public API. You do not need any credentials to call this API.</p>
-    async fn get_credentials_for_identity(
+    /// Presigns the request `get_credentials_for_identity` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_credentials_for_identity(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetCredentialsForIdentityInput,
-    ) -> Result<GetCredentialsForIdentityResponse, RusotoError<GetCredentialsForIdentityError>>
-    {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_credentials_for_identity_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_credentials_for_identity_request(
+        &self,
+        input: GetCredentialsForIdentityInput,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "cognito-identity", &self.region, "/");
+        request.set_operation("GetCredentialsForIdentity");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -2360,52 +2846,110 @@ impl CognitoIdentity for CognitoIdentityClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `get_id`, also returning the metadata of the response.
+    pub async fn get_id_with_metadata(
+        &self,
+        input: GetIdInput,
+    ) -> Result<(GetIdResponse, ResponseMetadata), RusotoError<GetIdError>> {
+        let request = self.build_get_id_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<GetCredentialsForIdentityResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result =
+                proto::json::ResponsePayload::new(&response).deserialize::<GetIdResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(GetCredentialsForIdentityError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(GetIdError::from_response(response))
         }
     }
 
-    /// <p>Generates (or retrieves) a Cognito ID. Supplying multiple logins will create an implicit linked account.</p> <p>This is a public API. You do not need any credentials to call this API.</p>
-    async fn get_id(&self, input: GetIdInput) -> Result<GetIdResponse, RusotoError<GetIdError>> {
+    /// Presigns the request `get_id` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_id(
+        &self,
+        presigner: &rusoto_core::Presigner,
+        input: GetIdInput,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_id_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_id_request(&self, input: GetIdInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "cognito-identity", &self.region, "/");
+        request.set_operation("GetId");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSCognitoIdentityService.GetId");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `get_identity_pool_roles`, also returning the metadata of the response.
+    pub async fn get_identity_pool_roles_with_metadata(
+        &self,
+        input: GetIdentityPoolRolesInput,
+    ) -> Result<
+        (GetIdentityPoolRolesResponse, ResponseMetadata),
+        RusotoError<GetIdentityPoolRolesError>,
+    > {
+        let request = self.build_get_identity_pool_roles_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<GetIdResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<GetIdentityPoolRolesResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(GetIdError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(GetIdentityPoolRolesError::from_response(response))
         }
     }
 
-    /// <p>Gets the roles for an identity pool.</p> <p>You must use AWS Developer credentials to call this API.</p>
-    async fn get_identity_pool_roles(
+    /// Presigns the request `get_identity_pool_roles` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_identity_pool_roles(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetIdentityPoolRolesInput,
-    ) -> Result<GetIdentityPoolRolesResponse, RusotoError<GetIdentityPoolRolesError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_identity_pool_roles_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_identity_pool_roles_request(
+        &self,
+        input: GetIdentityPoolRolesInput,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "cognito-identity", &self.region, "/");
+        request.set_operation("GetIdentityPoolRoles");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -2415,58 +2959,112 @@ impl CognitoIdentity for CognitoIdentityClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `get_open_id_token`, also returning the metadata of the response.
+    pub async fn get_open_id_token_with_metadata(
+        &self,
+        input: GetOpenIdTokenInput,
+    ) -> Result<(GetOpenIdTokenResponse, ResponseMetadata), RusotoError<GetOpenIdTokenError>> {
+        let request = self.build_get_open_id_token_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<GetIdentityPoolRolesResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<GetOpenIdTokenResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(GetIdentityPoolRolesError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(GetOpenIdTokenError::from_response(response))
         }
     }
 
-    /// <p>Gets an OpenID token, using a known Cognito ID. This known Cognito ID is returned by <a>GetId</a>. You can optionally add additional logins for the identity. Supplying multiple logins creates an implicit link.</p> <p>The OpenId token is valid for 10 minutes.</p> <p>This is a public API. You do not need any credentials to call this API.</p>
-    async fn get_open_id_token(
+    /// Presigns the request `get_open_id_token` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_open_id_token(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetOpenIdTokenInput,
-    ) -> Result<GetOpenIdTokenResponse, RusotoError<GetOpenIdTokenError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_open_id_token_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_open_id_token_request(&self, input: GetOpenIdTokenInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "cognito-identity", &self.region, "/");
+        request.set_operation("GetOpenIdToken");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSCognitoIdentityService.GetOpenIdToken");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `get_open_id_token_for_developer_identity`, also returning the metadata of the response.
+    pub async fn get_open_id_token_for_developer_identity_with_metadata(
+        &self,
+        input: GetOpenIdTokenForDeveloperIdentityInput,
+    ) -> Result<
+        (GetOpenIdTokenForDeveloperIdentityResponse, ResponseMetadata),
+        RusotoError<GetOpenIdTokenForDeveloperIdentityError>,
+    > {
+        let request = self.build_get_open_id_token_for_developer_identity_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<GetOpenIdTokenResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<GetOpenIdTokenForDeveloperIdentityResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(GetOpenIdTokenError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(GetOpenIdTokenForDeveloperIdentityError::from_response(
+                response,
+            ))
         }
     }
 
-    /// <p>Registers (or retrieves) a Cognito <code>IdentityId</code> and an OpenID Connect token for a user authenticated by your backend authentication process. Supplying multiple logins will create an implicit linked account. You can only specify one developer provider as part of the <code>Logins</code> map, which is linked to the identity pool. The developer provider is the "domain" by which Cognito will refer to your users.</p> <p>You can use <code>GetOpenIdTokenForDeveloperIdentity</code> to create a new identity and to link new logins (that is, user credentials issued by a public provider or developer provider) to an existing identity. When you want to create a new identity, the <code>IdentityId</code> should be null. When you want to associate a new login with an existing authenticated/unauthenticated identity, you can do so by providing the existing <code>IdentityId</code>. This API will create the identity in the specified <code>IdentityPoolId</code>.</p> <p>You must use AWS Developer credentials to call this API.</p>
-    async fn get_open_id_token_for_developer_identity(
+    /// Presigns the request `get_open_id_token_for_developer_identity` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_open_id_token_for_developer_identity(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetOpenIdTokenForDeveloperIdentityInput,
-    ) -> Result<
-        GetOpenIdTokenForDeveloperIdentityResponse,
-        RusotoError<GetOpenIdTokenForDeveloperIdentityError>,
-    > {
-        let mut request = SignedRequest::new("POST", "cognito-identity", &self.region, "/");
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_open_id_token_for_developer_identity_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_open_id_token_for_developer_identity_request(
+        &self,
+        input: GetOpenIdTokenForDeveloperIdentityInput,
+    ) -> SignedRequest {
+        let mut request = SignedRequest::new("POST", "cognito-identity", &self.region, "/");
+        request.set_operation("GetOpenIdTokenForDeveloperIdentity");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -2476,57 +3074,105 @@ impl CognitoIdentity for CognitoIdentityClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `list_identities`, also returning the metadata of the response.
+    pub async fn list_identities_with_metadata(
+        &self,
+        input: ListIdentitiesInput,
+    ) -> Result<(ListIdentitiesResponse, ResponseMetadata), RusotoError<ListIdentitiesError>> {
+        let request = self.build_list_identities_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<GetOpenIdTokenForDeveloperIdentityResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ListIdentitiesResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(GetOpenIdTokenForDeveloperIdentityError::from_response(
-                response,
-            ))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ListIdentitiesError::from_response(response))
         }
     }
 
-    /// <p>Lists the identities in an identity pool.</p> <p>You must use AWS Developer credentials to call this API.</p>
-    async fn list_identities(
+    /// Presigns the request `list_identities` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_identities(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListIdentitiesInput,
-    ) -> Result<ListIdentitiesResponse, RusotoError<ListIdentitiesError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_identities_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_identities_request(&self, input: ListIdentitiesInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "cognito-identity", &self.region, "/");
+        request.set_operation("ListIdentities");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSCognitoIdentityService.ListIdentities");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `list_identity_pools`, also returning the metadata of the response.
+    pub async fn list_identity_pools_with_metadata(
+        &self,
+        input: ListIdentityPoolsInput,
+    ) -> Result<(ListIdentityPoolsResponse, ResponseMetadata), RusotoError<ListIdentityPoolsError>>
+    {
+        let request = self.build_list_identity_pools_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<ListIdentitiesResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ListIdentityPoolsResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ListIdentitiesError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ListIdentityPoolsError::from_response(response))
         }
     }
 
-    /// <p>Lists all of the Cognito identity pools registered for your account.</p> <p>You must use AWS Developer credentials to call this API.</p>
-    async fn list_identity_pools(
+    /// Presigns the request `list_identity_pools` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_identity_pools(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListIdentityPoolsInput,
-    ) -> Result<ListIdentityPoolsResponse, RusotoError<ListIdentityPoolsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_identity_pools_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_identity_pools_request(&self, input: ListIdentityPoolsInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "cognito-identity", &self.region, "/");
+        request.set_operation("ListIdentityPools");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -2536,28 +3182,58 @@ impl CognitoIdentity for CognitoIdentityClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `list_tags_for_resource`, also returning the metadata of the response.
+    pub async fn list_tags_for_resource_with_metadata(
+        &self,
+        input: ListTagsForResourceInput,
+    ) -> Result<
+        (ListTagsForResourceResponse, ResponseMetadata),
+        RusotoError<ListTagsForResourceError>,
+    > {
+        let request = self.build_list_tags_for_resource_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListIdentityPoolsResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ListTagsForResourceResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ListIdentityPoolsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ListTagsForResourceError::from_response(response))
         }
     }
 
-    /// <p>Lists the tags that are assigned to an Amazon Cognito identity pool.</p> <p>A tag is a label that you can apply to identity pools to categorize and manage them in different ways, such as by purpose, owner, environment, or other criteria.</p> <p>You can use this action up to 10 times per second, per account.</p>
-    async fn list_tags_for_resource(
+    /// Presigns the request `list_tags_for_resource` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_tags_for_resource(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListTagsForResourceInput,
-    ) -> Result<ListTagsForResourceResponse, RusotoError<ListTagsForResourceError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_tags_for_resource_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_tags_for_resource_request(
+        &self,
+        input: ListTagsForResourceInput,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "cognito-identity", &self.region, "/");
+        request.set_operation("ListTagsForResource");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -2567,28 +3243,58 @@ impl CognitoIdentity for CognitoIdentityClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `lookup_developer_identity`, also returning the metadata of the response.
+    pub async fn lookup_developer_identity_with_metadata(
+        &self,
+        input: LookupDeveloperIdentityInput,
+    ) -> Result<
+        (LookupDeveloperIdentityResponse, ResponseMetadata),
+        RusotoError<LookupDeveloperIdentityError>,
+    > {
+        let request = self.build_lookup_developer_identity_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListTagsForResourceResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<LookupDeveloperIdentityResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ListTagsForResourceError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(LookupDeveloperIdentityError::from_response(response))
         }
     }
 
-    /// <p>Retrieves the <code>IdentityID</code> associated with a <code>DeveloperUserIdentifier</code> or the list of <code>DeveloperUserIdentifier</code> values associated with an <code>IdentityId</code> for an existing identity. Either <code>IdentityID</code> or <code>DeveloperUserIdentifier</code> must not be null. If you supply only one of these values, the other value will be searched in the database and returned as a part of the response. If you supply both, <code>DeveloperUserIdentifier</code> will be matched against <code>IdentityID</code>. If the values are verified against the database, the response returns both values and is the same as the request. Otherwise a <code>ResourceConflictException</code> is thrown.</p> <p> <code>LookupDeveloperIdentity</code> is intended for low-throughput control plane operations: for example, to enable customer service to locate an identity ID by username. If you are using it for higher-volume operations such as user authentication, your requests are likely to be throttled. <a>GetOpenIdTokenForDeveloperIdentity</a> is a better option for higher-volume operations for user authentication.</p> <p>You must use AWS Developer credentials to call this API.</p>
-    async fn lookup_developer_identity(
+    /// Presigns the request `lookup_developer_identity` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_lookup_developer_identity(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: LookupDeveloperIdentityInput,
-    ) -> Result<LookupDeveloperIdentityResponse, RusotoError<LookupDeveloperIdentityError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_lookup_developer_identity_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_lookup_developer_identity_request(
+        &self,
+        input: LookupDeveloperIdentityInput,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "cognito-identity", &self.region, "/");
+        request.set_operation("LookupDeveloperIdentity");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -2598,28 +3304,58 @@ impl CognitoIdentity for CognitoIdentityClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `merge_developer_identities`, also returning the metadata of the response.
+    pub async fn merge_developer_identities_with_metadata(
+        &self,
+        input: MergeDeveloperIdentitiesInput,
+    ) -> Result<
+        (MergeDeveloperIdentitiesResponse, ResponseMetadata),
+        RusotoError<MergeDeveloperIdentitiesError>,
+    > {
+        let request = self.build_merge_developer_identities_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<LookupDeveloperIdentityResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<MergeDeveloperIdentitiesResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(LookupDeveloperIdentityError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(MergeDeveloperIdentitiesError::from_response(response))
         }
     }
 
-    /// <p>Merges two users having different <code>IdentityId</code>s, existing in the same identity pool, and identified by the same developer provider. You can use this action to request that discrete users be merged and identified as a single user in the Cognito environment. Cognito associates the given source user (<code>SourceUserIdentifier</code>) with the <code>IdentityId</code> of the <code>DestinationUserIdentifier</code>. Only developer-authenticated users can be merged. If the users to be merged are associated with the same public provider, but as two different users, an exception will be thrown.</p> <p>The number of linked logins is limited to 20. So, the number of linked logins for the source user, <code>SourceUserIdentifier</code>, and the destination user, <code>DestinationUserIdentifier</code>, together should not be larger than 20. Otherwise, an exception will be thrown.</p> <p>You must use AWS Developer credentials to call this API.</p>
-    async fn merge_developer_identities(
+    /// Presigns the request `merge_developer_identities` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_merge_developer_identities(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: MergeDeveloperIdentitiesInput,
-    ) -> Result<MergeDeveloperIdentitiesResponse, RusotoError<MergeDeveloperIdentitiesError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_merge_developer_identities_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_merge_developer_identities_request(
+        &self,
+        input: MergeDeveloperIdentitiesInput,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "cognito-identity", &self.region, "/");
+        request.set_operation("MergeDeveloperIdentities");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -2629,28 +3365,50 @@ impl CognitoIdentity for CognitoIdentityClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `set_identity_pool_roles`, also returning the metadata of the response.
+    pub async fn set_identity_pool_roles_with_metadata(
+        &self,
+        input: SetIdentityPoolRolesInput,
+    ) -> Result<((), ResponseMetadata), RusotoError<SetIdentityPoolRolesError>> {
+        let request = self.build_set_identity_pool_roles_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<MergeDeveloperIdentitiesResponse, _>()
+            std::mem::drop(response);
+            Ok(((), metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(MergeDeveloperIdentitiesError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(SetIdentityPoolRolesError::from_response(response))
         }
     }
 
-    /// <p>Sets the roles for an identity pool. These roles are used when making calls to <a>GetCredentialsForIdentity</a> action.</p> <p>You must use AWS Developer credentials to call this API.</p>
-    async fn set_identity_pool_roles(
+    /// Presigns the request `set_identity_pool_roles` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_set_identity_pool_roles(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: SetIdentityPoolRolesInput,
-    ) -> Result<(), RusotoError<SetIdentityPoolRolesError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_set_identity_pool_roles_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_set_identity_pool_roles_request(
+        &self,
+        input: SetIdentityPoolRolesInput,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "cognito-identity", &self.region, "/");
+        request.set_operation("SetIdentityPoolRoles");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -2660,54 +3418,102 @@ impl CognitoIdentity for CognitoIdentityClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `tag_resource`, also returning the metadata of the response.
+    pub async fn tag_resource_with_metadata(
+        &self,
+        input: TagResourceInput,
+    ) -> Result<(TagResourceResponse, ResponseMetadata), RusotoError<TagResourceError>> {
+        let request = self.build_tag_resource_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            std::mem::drop(response);
-            Ok(())
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<TagResourceResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(SetIdentityPoolRolesError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(TagResourceError::from_response(response))
         }
     }
 
-    /// <p>Assigns a set of tags to an Amazon Cognito identity pool. A tag is a label that you can use to categorize and manage identity pools in different ways, such as by purpose, owner, environment, or other criteria.</p> <p>Each tag consists of a key and value, both of which you define. A key is a general category for more specific values. For example, if you have two versions of an identity pool, one for testing and another for production, you might assign an <code>Environment</code> tag key to both identity pools. The value of this key might be <code>Test</code> for one identity pool and <code>Production</code> for the other.</p> <p>Tags are useful for cost tracking and access control. You can activate your tags so that they appear on the Billing and Cost Management console, where you can track the costs associated with your identity pools. In an IAM policy, you can constrain permissions for identity pools based on specific tags or tag values.</p> <p>You can use this action up to 5 times per second, per account. An identity pool can have as many as 50 tags.</p>
-    async fn tag_resource(
+    /// Presigns the request `tag_resource` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_tag_resource(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: TagResourceInput,
-    ) -> Result<TagResourceResponse, RusotoError<TagResourceError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_tag_resource_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_tag_resource_request(&self, input: TagResourceInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "cognito-identity", &self.region, "/");
+        request.set_operation("TagResource");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSCognitoIdentityService.TagResource");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `unlink_developer_identity`, also returning the metadata of the response.
+    pub async fn unlink_developer_identity_with_metadata(
+        &self,
+        input: UnlinkDeveloperIdentityInput,
+    ) -> Result<((), ResponseMetadata), RusotoError<UnlinkDeveloperIdentityError>> {
+        let request = self.build_unlink_developer_identity_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<TagResourceResponse, _>()
+            std::mem::drop(response);
+            Ok(((), metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(TagResourceError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(UnlinkDeveloperIdentityError::from_response(response))
         }
     }
 
-    /// <p>Unlinks a <code>DeveloperUserIdentifier</code> from an existing identity. Unlinked developer users will be considered new identities next time they are seen. If, for a given Cognito identity, you remove all federated identities as well as the developer user identifier, the Cognito identity becomes inaccessible.</p> <p>You must use AWS Developer credentials to call this API.</p>
-    async fn unlink_developer_identity(
+    /// Presigns the request `unlink_developer_identity` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_unlink_developer_identity(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UnlinkDeveloperIdentityInput,
-    ) -> Result<(), RusotoError<UnlinkDeveloperIdentityError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_unlink_developer_identity_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_unlink_developer_identity_request(
+        &self,
+        input: UnlinkDeveloperIdentityInput,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "cognito-identity", &self.region, "/");
+        request.set_operation("UnlinkDeveloperIdentity");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -2717,81 +3523,151 @@ impl CognitoIdentity for CognitoIdentityClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `unlink_identity`, also returning the metadata of the response.
+    pub async fn unlink_identity_with_metadata(
+        &self,
+        input: UnlinkIdentityInput,
+    ) -> Result<((), ResponseMetadata), RusotoError<UnlinkIdentityError>> {
+        let request = self.build_unlink_identity_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
             std::mem::drop(response);
-            Ok(())
+            Ok(((), metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(UnlinkDeveloperIdentityError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(UnlinkIdentityError::from_response(response))
         }
     }
 
-    /// <p>Unlinks a federated identity from an existing account. Unlinked logins will be considered new identities next time they are seen. Removing the last linked login will make this identity inaccessible.</p> <p>This is a public API. You do not need any credentials to call this API.</p>
-    async fn unlink_identity(
+    /// Presigns the request `unlink_identity` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_unlink_identity(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UnlinkIdentityInput,
-    ) -> Result<(), RusotoError<UnlinkIdentityError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_unlink_identity_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_unlink_identity_request(&self, input: UnlinkIdentityInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "cognito-identity", &self.region, "/");
+        request.set_operation("UnlinkIdentity");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSCognitoIdentityService.UnlinkIdentity");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `untag_resource`, also returning the metadata of the response.
+    pub async fn untag_resource_with_metadata(
+        &self,
+        input: UntagResourceInput,
+    ) -> Result<(UntagResourceResponse, ResponseMetadata), RusotoError<UntagResourceError>> {
+        let request = self.build_untag_resource_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            std::mem::drop(response);
-            Ok(())
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<UntagResourceResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(UnlinkIdentityError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(UntagResourceError::from_response(response))
         }
     }
 
-    /// <p>Removes the specified tags from an Amazon Cognito identity pool. You can use this action up to 5 times per second, per account</p>
-    async fn untag_resource(
+    /// Presigns the request `untag_resource` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_untag_resource(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UntagResourceInput,
-    ) -> Result<UntagResourceResponse, RusotoError<UntagResourceError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_untag_resource_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_untag_resource_request(&self, input: UntagResourceInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "cognito-identity", &self.region, "/");
+        request.set_operation("UntagResource");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSCognitoIdentityService.UntagResource");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `update_identity_pool`, also returning the metadata of the response.
+    pub async fn update_identity_pool_with_metadata(
+        &self,
+        input: IdentityPool,
+    ) -> Result<(IdentityPool, ResponseMetadata), RusotoError<UpdateIdentityPoolError>> {
+        let request = self.build_update_identity_pool_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<UntagResourceResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result =
+                proto::json::ResponsePayload::new(&response).deserialize::<IdentityPool, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(UntagResourceError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(UpdateIdentityPoolError::from_response(response))
         }
     }
 
-    /// <p>Updates an identity pool.</p> <p>You must use AWS Developer credentials to call this API.</p>
-    async fn update_identity_pool(
+    /// Presigns the request `update_identity_pool` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_identity_pool(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: IdentityPool,
-    ) -> Result<IdentityPool, RusotoError<UpdateIdentityPoolError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_identity_pool_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_identity_pool_request(&self, input: IdentityPool) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "cognito-identity", &self.region, "/");
+        request.set_operation("UpdateIdentityPool");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -2801,18 +3677,260 @@ impl CognitoIdentity for CognitoIdentityClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
-        let mut response = self
-            .client
-            .sign_and_dispatch(request)
+        request
+    }
+
+    /// The pages of `ListIdentityPools` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `MaxResults` of `input`.
+    pub fn list_identity_pools_pages(
+        &self,
+        input: ListIdentityPoolsInput,
+    ) -> impl futures::Stream<
+        Item = Result<ListIdentityPoolsResponse, RusotoError<ListIdentityPoolsError>>,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: CognitoIdentityClient, input| async move {
+                client.list_identity_pools(input).await
+            },
+            |input: &ListIdentityPoolsInput, output: &ListIdentityPoolsResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListIdentityPoolsInput {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `IdentityPools` of every page of `ListIdentityPools` results for `input`, as `list_identity_pools_pages` requests them.
+    pub fn list_identity_pools_stream(
+        &self,
+        input: ListIdentityPoolsInput,
+    ) -> impl futures::Stream<
+        Item = Result<IdentityPoolShortDescription, RusotoError<ListIdentityPoolsError>>,
+    > + Send {
+        rusoto_core::pagination::items(self.list_identity_pools_pages(input), |page| {
+            page.identity_pools.unwrap_or_default()
+        })
+    }
+}
+
+#[async_trait]
+impl CognitoIdentity for CognitoIdentityClient {
+    /// <p>Creates a new identity pool. The identity pool is a store of user identity information that is specific to your AWS account. The keys for <code>SupportedLoginProviders</code> are as follows:</p> <ul> <li> <p>Facebook: <code>graph.facebook.com</code> </p> </li> <li> <p>Google: <code>accounts.google.com</code> </p> </li> <li> <p>Amazon: <code>www.amazon.com</code> </p> </li> <li> <p>Twitter: <code>api.twitter.com</code> </p> </li> <li> <p>Digits: <code>www.digits.com</code> </p> </li> </ul> <p>You must use AWS Developer credentials to call this API.</p>
+    async fn create_identity_pool(
+        &self,
+        input: CreateIdentityPoolInput,
+    ) -> Result<IdentityPool, RusotoError<CreateIdentityPoolError>> {
+        self.create_identity_pool_with_metadata(input)
             .await
-            .map_err(RusotoError::from)?;
-        if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<IdentityPool, _>()
-        } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(UpdateIdentityPoolError::from_response(response))
-        }
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes identities from an identity pool. You can specify a list of 1-60 identities that you want to delete.</p> <p>You must use AWS Developer credentials to call this API.</p>
+    async fn delete_identities(
+        &self,
+        input: DeleteIdentitiesInput,
+    ) -> Result<DeleteIdentitiesResponse, RusotoError<DeleteIdentitiesError>> {
+        self.delete_identities_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes an identity pool. Once a pool is deleted, users will not be able to authenticate with the pool.</p> <p>You must use AWS Developer credentials to call this API.</p>
+    async fn delete_identity_pool(
+        &self,
+        input: DeleteIdentityPoolInput,
+    ) -> Result<(), RusotoError<DeleteIdentityPoolError>> {
+        self.delete_identity_pool_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns metadata related to the given identity, including when the identity was created and any associated linked logins.</p> <p>You must use AWS Developer credentials to call this API.</p>
+    async fn describe_identity(
+        &self,
+        input: DescribeIdentityInput,
+    ) -> Result<IdentityDescription, RusotoError<DescribeIdentityError>> {
+        self.describe_identity_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Gets details about a particular identity pool, including the pool name, ID description, creation date, and current number of users.</p> <p>You must use AWS Developer credentials to call this API.</p>
+    async fn describe_identity_pool(
+        &self,
+        input: DescribeIdentityPoolInput,
+    ) -> Result<IdentityPool, RusotoError<DescribeIdentityPoolError>> {
+        self.describe_identity_pool_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns credentials for the provided identity ID. Any provided logins will be validated against supported login providers. If the token is for cognito-identity.amazonaws.com, it will be passed through to AWS Security Token Service with the appropriate role for the token.</p> <p>This is a public API. You do not need any credentials to call this API.</p>
+    async fn get_credentials_for_identity(
+        &self,
+        input: GetCredentialsForIdentityInput,
+    ) -> Result<GetCredentialsForIdentityResponse, RusotoError<GetCredentialsForIdentityError>>
+    {
+        self.get_credentials_for_identity_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Generates (or retrieves) a Cognito ID. Supplying multiple logins will create an implicit linked account.</p> <p>This is a public API. You do not need any credentials to call this API.</p>
+    async fn get_id(&self, input: GetIdInput) -> Result<GetIdResponse, RusotoError<GetIdError>> {
+        self.get_id_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Gets the roles for an identity pool.</p> <p>You must use AWS Developer credentials to call this API.</p>
+    async fn get_identity_pool_roles(
+        &self,
+        input: GetIdentityPoolRolesInput,
+    ) -> Result<GetIdentityPoolRolesResponse, RusotoError<GetIdentityPoolRolesError>> {
+        self.get_identity_pool_roles_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Gets an OpenID token, using a known Cognito ID. This known Cognito ID is returned by <a>GetId</a>. You can optionally add additional logins for the identity. Supplying multiple logins creates an implicit link.</p> <p>The OpenId token is valid for 10 minutes.</p> <p>This is a public API. You do not need any credentials to call this API.</p>
+    async fn get_open_id_token(
+        &self,
+        input: GetOpenIdTokenInput,
+    ) -> Result<GetOpenIdTokenResponse, RusotoError<GetOpenIdTokenError>> {
+        self.get_open_id_token_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Registers (or retrieves) a Cognito <code>IdentityId</code> and an OpenID Connect token for a user authenticated by your backend authentication process. Supplying multiple logins will create an implicit linked account. You can only specify one developer provider as part of the <code>Logins</code> map, which is linked to the identity pool. The developer provider is the "domain" by which Cognito will refer to your users.</p> <p>You can use <code>GetOpenIdTokenForDeveloperIdentity</code> to create a new identity and to link new logins (that is, user credentials issued by a public provider or developer provider) to an existing identity. When you want to create a new identity, the <code>IdentityId</code> should be null. When you want to associate a new login with an existing authenticated/unauthenticated identity, you can do so by providing the existing <code>IdentityId</code>. This API will create the identity in the specified <code>IdentityPoolId</code>.</p> <p>You must use AWS Developer credentials to call this API.</p>
+    async fn get_open_id_token_for_developer_identity(
+        &self,
+        input: GetOpenIdTokenForDeveloperIdentityInput,
+    ) -> Result<
+        GetOpenIdTokenForDeveloperIdentityResponse,
+        RusotoError<GetOpenIdTokenForDeveloperIdentityError>,
+    > {
+        self.get_open_id_token_for_developer_identity_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Lists the identities in an identity pool.</p> <p>You must use AWS Developer credentials to call this API.</p>
+    async fn list_identities(
+        &self,
+        input: ListIdentitiesInput,
+    ) -> Result<ListIdentitiesResponse, RusotoError<ListIdentitiesError>> {
+        self.list_identities_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Lists all of the Cognito identity pools registered for your account.</p> <p>You must use AWS Developer credentials to call this API.</p>
+    async fn list_identity_pools(
+        &self,
+        input: ListIdentityPoolsInput,
+    ) -> Result<ListIdentityPoolsResponse, RusotoError<ListIdentityPoolsError>> {
+        self.list_identity_pools_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Lists the tags that are assigned to an Amazon Cognito identity pool.</p> <p>A tag is a label that you can apply to identity pools to categorize and manage them in different ways, such as by purpose, owner, environment, or other criteria.</p> <p>You can use this action up to 10 times per second, per account.</p>
+    async fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceInput,
+    ) -> Result<ListTagsForResourceResponse, RusotoError<ListTagsForResourceError>> {
+        self.list_tags_for_resource_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Retrieves the <code>IdentityID</code> associated with a <code>DeveloperUserIdentifier</code> or the list of <code>DeveloperUserIdentifier</code> values associated with an <code>IdentityId</code> for an existing identity. Either <code>IdentityID</code> or <code>DeveloperUserIdentifier</code> must not be null. If you supply only one of these values, the other value will be searched in the database and returned as a part of the response. If you supply both, <code>DeveloperUserIdentifier</code> will be matched against <code>IdentityID</code>. If the values are verified against the database, the response returns both values and is the same as the request. Otherwise a <code>ResourceConflictException</code> is thrown.</p> <p> <code>LookupDeveloperIdentity</code> is intended for low-throughput control plane operations: for example, to enable customer service to locate an identity ID by username. If you are using it for higher-volume operations such as user authentication, your requests are likely to be throttled. <a>GetOpenIdTokenForDeveloperIdentity</a> is a better option for higher-volume operations for user authentication.</p> <p>You must use AWS Developer credentials to call this API.</p>
+    async fn lookup_developer_identity(
+        &self,
+        input: LookupDeveloperIdentityInput,
+    ) -> Result<LookupDeveloperIdentityResponse, RusotoError<LookupDeveloperIdentityError>> {
+        self.lookup_developer_identity_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Merges two users having different <code>IdentityId</code>s, existing in the same identity pool, and identified by the same developer provider. You can use this action to request that discrete users be merged and identified as a single user in the Cognito environment. Cognito associates the given source user (<code>SourceUserIdentifier</code>) with the <code>IdentityId</code> of the <code>DestinationUserIdentifier</code>. Only developer-authenticated users can be merged. If the users to be merged are associated with the same public provider, but as two different users, an exception will be thrown.</p> <p>The number of linked logins is limited to 20. So, the number of linked logins for the source user, <code>SourceUserIdentifier</code>, and the destination user, <code>DestinationUserIdentifier</code>, together should not be larger than 20. Otherwise, an exception will be thrown.</p> <p>You must use AWS Developer credentials to call this API.</p>
+    async fn merge_developer_identities(
+        &self,
+        input: MergeDeveloperIdentitiesInput,
+    ) -> Result<MergeDeveloperIdentitiesResponse, RusotoError<MergeDeveloperIdentitiesError>> {
+        self.merge_developer_identities_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Sets the roles for an identity pool. These roles are used when making calls to <a>GetCredentialsForIdentity</a> action.</p> <p>You must use AWS Developer credentials to call this API.</p>
+    async fn set_identity_pool_roles(
+        &self,
+        input: SetIdentityPoolRolesInput,
+    ) -> Result<(), RusotoError<SetIdentityPoolRolesError>> {
+        self.set_identity_pool_roles_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Assigns a set of tags to an Amazon Cognito identity pool. A tag is a label that you can use to categorize and manage identity pools in different ways, such as by purpose, owner, environment, or other criteria.</p> <p>Each tag consists of a key and value, both of which you define. A key is a general category for more specific values. For example, if you have two versions of an identity pool, one for testing and another for production, you might assign an <code>Environment</code> tag key to both identity pools. The value of this key might be <code>Test</code> for one identity pool and <code>Production</code> for the other.</p> <p>Tags are useful for cost tracking and access control. You can activate your tags so that they appear on the Billing and Cost Management console, where you can track the costs associated with your identity pools. In an IAM policy, you can constrain permissions for identity pools based on specific tags or tag values.</p> <p>You can use this action up to 5 times per second, per account. An identity pool can have as many as 50 tags.</p>
+    async fn tag_resource(
+        &self,
+        input: TagResourceInput,
+    ) -> Result<TagResourceResponse, RusotoError<TagResourceError>> {
+        self.tag_resource_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Unlinks a <code>DeveloperUserIdentifier</code> from an existing identity. Unlinked developer users will be considered new identities next time they are seen. If, for a given Cognito identity, you remove all federated identities as well as the developer user identifier, the Cognito identity becomes inaccessible.</p> <p>You must use AWS Developer credentials to call this API.</p>
+    async fn unlink_developer_identity(
+        &self,
+        input: UnlinkDeveloperIdentityInput,
+    ) -> Result<(), RusotoError<UnlinkDeveloperIdentityError>> {
+        self.unlink_developer_identity_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Unlinks a federated identity from an existing account. Unlinked logins will be considered new identities next time they are seen. Removing the last linked login will make this identity inaccessible.</p> <p>This is a public API. You do not need any credentials to call this API.</p>
+    async fn unlink_identity(
+        &self,
+        input: UnlinkIdentityInput,
+    ) -> Result<(), RusotoError<UnlinkIdentityError>> {
+        self.unlink_identity_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Removes the specified tags from an Amazon Cognito identity pool. You can use this action up to 5 times per second, per account</p>
+    async fn untag_resource(
+        &self,
+        input: UntagResourceInput,
+    ) -> Result<UntagResourceResponse, RusotoError<UntagResourceError>> {
+        self.untag_resource_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Updates an identity pool.</p> <p>You must use AWS Developer credentials to call this API.</p>
+    async fn update_identity_pool(
+        &self,
+        input: IdentityPool,
+    ) -> Result<IdentityPool, RusotoError<UpdateIdentityPoolError>> {
+        self.update_identity_pool_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
     }
 }
@@ -1 +1,6 @@
+mod srp;
+mod user_pool;
 
+pub use self::user_pool::{
+    DeviceCredentials, UserPoolAuthError, UserPoolAuthenticator, UserPoolTokens,
+};
@@ -0,0 +1,268 @@
+//! The Secure Remote Password protocol as Cognito User Pools implement it for the
+//! `USER_SRP_AUTH` flow and device authentication.
+
+use chrono::{DateTime, Utc};
+use hmac::{Hmac, Mac};
+use num_bigint::BigUint;
+use rand::RngCore;
+use sha2::{Digest, Sha256};
+
+/// The 3072-bit group of RFC 5054, with the generator 2.
+const N_HEX: &str = "\
+    FFFFFFFFFFFFFFFFC90FDAA22168C234C4C6628B80DC1CD129024E088A67CC74020BBEA63B139B22514A08798E34\
+    04DDEF9519B3CD3A431B302B0A6DF25F14374FE1356D6D51C245E485B576625E7EC6F44C42E9A637ED6B0BFF5CB6\
+    F406B7EDEE386BFB5A899FA5AE9F24117C4B1FE649286651ECE45B3DC2007CB8A163BF0598DA48361C55D39A6916\
+    3FA8FD24CF5F83655D23DCA3AD961C62F356208552BB9ED529077096966D670C354E4ABC9804F1746C08CA18217C\
+    32905E462E36CE3BE39E772C180E86039B2783A2EC07A28FB5C55DF06F4C52C9DE2BCBF6955817183995497CEA95\
+    6AE515D2261898FA051015728E5A8AAAC42DAD33170D04507A33A85521ABDF1CBA64ECFB850458DBEF0A8AEA7157\
+    5D060C7DB3970F85A6E1E4C7ABF5AE8CDB0933D71E8C94E04A25619DCEE3D2261AD2EE6BF12FFA06D98A0864D876\
+    02733EC86A64521F2B18177B200CBBE117577A615D6C770988C0BAD946E208E24FA074E5AB3143DB5BFCE0FD108E\
+    4B82D120A93AD2CAFFFFFFFFFFFFFFFF";
+
+const DERIVED_KEY_INFO: &[u8] = b"Caldera Derived Key";
+
+fn n() -> BigUint {
+    BigUint::parse_bytes(N_HEX.as_bytes(), 16).expect("invalid SRP group")
+}
+
+fn g() -> BigUint {
+    BigUint::from(2u32)
+}
+
+/// The multiplier `k = H(N | g)`.
+fn k() -> BigUint {
+    hash_to_int(&[&pad(&n()), &pad(&g())])
+}
+
+/// The big-endian bytes of `value`, with a leading zero byte if the high bit is set, as Cognito
+/// hashes numbers.
+pub(crate) fn pad(value: &BigUint) -> Vec<u8> {
+    let bytes = value.to_bytes_be();
+    if bytes[0] & 0x80 != 0 {
+        let mut padded = vec![0];
+        padded.extend_from_slice(&bytes);
+        padded
+    } else {
+        bytes
+    }
+}
+
+fn hash(parts: &[&[u8]]) -> Vec<u8> {
+    let mut hasher = Sha256::new();
+    for part in parts {
+        hasher.input(part);
+    }
+    hasher.result().to_vec()
+}
+
+fn hash_to_int(parts: &[&[u8]]) -> BigUint {
+    BigUint::from_bytes_be(&hash(parts))
+}
+
+fn hmac(key: &[u8], parts: &[&[u8]]) -> Vec<u8> {
+    let mut mac = Hmac::<Sha256>::new_varkey(key).expect("HMAC accepts keys of any length");
+    for part in parts {
+        mac.input(part);
+    }
+    mac.result().code().to_vec()
+}
+
+fn parse_hex(name: &str, hex: &str) -> Result<BigUint, String> {
+    BigUint::parse_bytes(hex.as_bytes(), 16).ok_or_else(|| format!("{} is not hexadecimal", name))
+}
+
+/// The private value `x = H(salt | H(pool_name | username | ":" | password))`.
+fn private_key(pool_name: &str, username: &str, password: &str, salt: &BigUint) -> BigUint {
+    let credentials = hash(&[
+        pool_name.as_bytes(),
+        username.as_bytes(),
+        b":",
+        password.as_bytes(),
+    ]);
+    hash_to_int(&[&pad(salt), &credentials])
+}
+
+/// The 16 byte key derived with HKDF from the premaster secret `s` and the scrambler `u`.
+fn derive_key(s: &BigUint, u: &BigUint) -> Vec<u8> {
+    let prk = hmac(&pad(u), &[&pad(s)]);
+    let mut key = hmac(&prk, &[DERIVED_KEY_INFO, &[1]]);
+    key.truncate(16);
+    key
+}
+
+/// The client side of one SRP handshake, with its ephemeral key pair.
+pub(crate) struct SrpClient {
+    a: BigUint,
+    public_a: BigUint,
+}
+
+impl SrpClient {
+    /// A client with a random private ephemeral key.
+    pub(crate) fn new() -> SrpClient {
+        loop {
+            let mut bytes = [0; 128];
+            rand::thread_rng().fill_bytes(&mut bytes);
+            if let Some(client) = SrpClient::with_private_key(BigUint::from_bytes_be(&bytes)) {
+                return client;
+            }
+        }
+    }
+
+    /// A client with the private ephemeral key `a`, or `None` if `A = g^a` is unusable.
+    pub(crate) fn with_private_key(a: BigUint) -> Option<SrpClient> {
+        let public_a = g().modpow(&a, &n());
+        if public_a == BigUint::from(0u32) {
+            return None;
+        }
+        Some(SrpClient { a, public_a })
+    }
+
+    /// The public ephemeral key `A`, sent as the `SRP_A` parameter.
+    pub(crate) fn public_key(&self) -> String {
+        self.public_a.to_str_radix(16)
+    }
+
+    /// The key shared with Cognito after it answered with its public key `SRP_B` and the `SALT`
+    /// of the user's verifier.
+    pub(crate) fn authentication_key(
+        &self,
+        pool_name: &str,
+        username: &str,
+        password: &str,
+        public_b: &str,
+        salt: &str,
+    ) -> Result<Vec<u8>, String> {
+        let n = n();
+        let public_b = parse_hex("SRP_B", public_b)?;
+        if &public_b % &n == BigUint::from(0u32) {
+            return Err("SRP_B is zero modulo N".to_owned());
+        }
+        let u = hash_to_int(&[&pad(&self.public_a), &pad(&public_b)]);
+        if u == BigUint::from(0u32) {
+            return Err("the SRP scrambler is zero".to_owned());
+        }
+        let x = private_key(pool_name, username, password, &parse_hex("SALT", salt)?);
+        // S = (B - k * g^x) ^ (a + u * x), with the subtraction kept positive modulo N.
+        let kgx = (k() * g().modpow(&x, &n)) % &n;
+        let base = (&public_b % &n + &n - kgx) % &n;
+        let s = base.modpow(&(&self.a + &u * &x), &n);
+        Ok(derive_key(&s, &u))
+    }
+}
+
+/// The `PASSWORD_CLAIM_SIGNATURE` proving the knowledge of `key` for the `SECRET_BLOCK`.
+pub(crate) fn claim_signature(
+    key: &[u8],
+    pool_name: &str,
+    username: &str,
+    secret_block: &str,
+    timestamp: &str,
+) -> Result<String, String> {
+    let secret_block =
+        base64::decode(secret_block).map_err(|err| format!("invalid SECRET_BLOCK: {}", err))?;
+    let signature = hmac(
+        key,
+        &[
+            pool_name.as_bytes(),
+            username.as_bytes(),
+            &secret_block,
+            timestamp.as_bytes(),
+        ],
+    );
+    Ok(base64::encode(&signature))
+}
+
+/// The `TIMESTAMP` of a claim, e.g. `Tue Jan 2 15:04:05 UTC 2006`.
+pub(crate) fn timestamp(now: DateTime<Utc>) -> String {
+    now.format("%a %b %-d %H:%M:%S UTC %Y").to_string()
+}
+
+/// The `SECRET_HASH` required by app clients with a client secret.
+pub(crate) fn secret_hash(client_secret: &str, username: &str, client_id: &str) -> String {
+    base64::encode(&hmac(
+        client_secret.as_bytes(),
+        &[username.as_bytes(), client_id.as_bytes()],
+    ))
+}
+
+/// A random device password, and the base64 encoded verifier and salt Cognito stores for it.
+pub(crate) fn device_verifier(
+    device_group_key: &str,
+    device_key: &str,
+) -> (String, String, String) {
+    let mut bytes = [0; 40];
+    rand::thread_rng().fill_bytes(&mut bytes);
+    let password = base64::encode(&bytes[..]);
+    let mut salt = [0; 16];
+    rand::thread_rng().fill_bytes(&mut salt);
+    let salt = BigUint::from_bytes_be(&salt);
+    let x = private_key(device_group_key, device_key, &password, &salt);
+    let verifier = g().modpow(&x, &n());
+    (
+        password,
+        base64::encode(&pad(&verifier)),
+        base64::encode(&pad(&salt)),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    #[test]
+    fn agrees_on_the_key_with_the_server() {
+        let (pool_name, username, password) = ("abcdef", "user-id", "correct horse");
+        let salt = BigUint::from(0x1234_5678_9abc_u64);
+        let n = n();
+        // The verifier stored at sign up, and the server's ephemeral key pair.
+        let v = g().modpow(&private_key(pool_name, username, password, &salt), &n);
+        let b = BigUint::from(0xdead_beef_u64);
+        let public_b = (k() * &v + g().modpow(&b, &n)) % &n;
+
+        let client = SrpClient::with_private_key(BigUint::from(0xc0ffee_u64)).unwrap();
+        let key = client
+            .authentication_key(
+                pool_name,
+                username,
+                password,
+                &public_b.to_str_radix(16),
+                &salt.to_str_radix(16),
+            )
+            .unwrap();
+
+        let u = hash_to_int(&[&pad(&client.public_a), &pad(&public_b)]);
+        // S = (A * v^u) ^ b on the server.
+        let s = ((&client.public_a * v.modpow(&u, &n)) % &n).modpow(&b, &n);
+        assert_eq!(key, derive_key(&s, &u));
+        assert_eq!(key.len(), 16);
+
+        let wrong = client
+            .authentication_key(
+                pool_name,
+                username,
+                "wrong",
+                &public_b.to_str_radix(16),
+                &salt.to_str_radix(16),
+            )
+            .unwrap();
+        assert_ne!(wrong, key);
+        assert!(client
+            .authentication_key(pool_name, username, password, &n.to_str_radix(16), "12")
+            .is_err());
+    }
+
+    #[test]
+    fn formats_claims() {
+        assert_eq!(
+            timestamp(Utc.with_ymd_and_hms(2006, 1, 2, 15, 4, 5).unwrap()),
+            "Mon Jan 2 15:04:05 UTC 2006"
+        );
+        assert_eq!(
+            secret_hash("secret", "user", "client"),
+            "wvW87lzZoI+qQCVGmWVBJLlucdJ65huAVP1z+0MgA6E="
+        );
+        assert_eq!(pad(&BigUint::from(0x80u32)), vec![0, 0x80]);
+        assert_eq!(pad(&BigUint::from(0x7fu32)), vec![0x7f]);
+    }
+}
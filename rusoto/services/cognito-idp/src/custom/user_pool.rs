@@ -0,0 +1,638 @@
+// The errors wrap `RusotoError`s, as large as those of the generated operations.
+#![allow(clippy::result_large_err)]
+
+use std::collections::HashMap;
+use std::error::Error;
+use std::fmt;
+
+use chrono::Utc;
+
+use rusoto_core::RusotoError;
+
+use super::srp::{self, SrpClient};
+use crate::{
+    AuthenticationResultType, CognitoIdentityProvider, CognitoIdentityProviderClient,
+    ConfirmDeviceError, ConfirmDeviceRequest, DeviceSecretVerifierConfigType, InitiateAuthError,
+    InitiateAuthRequest, InitiateAuthResponse, NewDeviceMetadataType, RespondToAuthChallengeError,
+    RespondToAuthChallengeRequest, RespondToAuthChallengeResponse,
+};
+
+/// Signs users of a Cognito user pool in with their password, using the `USER_SRP_AUTH` flow.
+///
+/// The password never leaves the client: Cognito answers `InitiateAuth` with a
+/// `PASSWORD_VERIFIER` challenge, which is answered with a proof of the password computed with
+/// the Secure Remote Password protocol. Remembered devices, set with `set_device`, are
+/// authenticated the same way with their device password.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use rusoto_cognito_idp::{CognitoIdentityProviderClient, UserPoolAuthenticator};
+/// use rusoto_core::{Client, HttpClient, Region};
+///
+/// # async fn sign_in() -> Result<(), Box<dyn std::error::Error>> {
+/// let client = CognitoIdentityProviderClient::new_with_client(
+///     Client::new_not_signing(HttpClient::new()?),
+///     Region::UsEast1,
+/// );
+/// let authenticator = UserPoolAuthenticator::new(
+///     client,
+///     "us-east-1_AbCdEfGhI".to_owned(),
+///     "1example23456789".to_owned(),
+/// );
+/// let tokens = authenticator.authenticate("jane", "correct horse").await?;
+/// let tokens = authenticator
+///     .refresh("jane", tokens.refresh_token.as_ref().unwrap())
+///     .await?;
+/// println!("{}", tokens.access_token);
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone)]
+pub struct UserPoolAuthenticator {
+    client: CognitoIdentityProviderClient,
+    user_pool_id: String,
+    client_id: String,
+    client_secret: Option<String>,
+    device: Option<DeviceCredentials>,
+}
+
+/// The tokens of a signed in user.
+#[derive(Clone, Debug, PartialEq)]
+pub struct UserPoolTokens {
+    /// The ID token, with the claims of the user's identity.
+    pub id_token: String,
+    /// The access token, authorizing the user's requests to the user pool.
+    pub access_token: String,
+    /// The refresh token, to get new ID and access tokens with
+    /// `UserPoolAuthenticator::refresh`.
+    pub refresh_token: Option<String>,
+    /// The seconds the ID and access tokens are valid for.
+    pub expires_in: Option<i64>,
+    /// A device Cognito started tracking, to be confirmed with
+    /// `UserPoolAuthenticator::confirm_device` to remember it.
+    pub new_device: Option<NewDeviceMetadataType>,
+}
+
+/// The key and password of a device remembered by a user pool.
+#[derive(Clone, PartialEq)]
+pub struct DeviceCredentials {
+    /// The key of the device.
+    pub device_key: String,
+    /// The key of the group the device belongs to.
+    pub device_group_key: String,
+    /// The password generated when the device was confirmed.
+    pub device_password: String,
+}
+
+impl fmt::Debug for DeviceCredentials {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("DeviceCredentials")
+            .field("device_key", &self.device_key)
+            .field("device_group_key", &self.device_group_key)
+            .field("device_password", &"**********")
+            .finish()
+    }
+}
+
+/// Errors returned by `UserPoolAuthenticator`.
+#[derive(Debug, PartialEq)]
+pub enum UserPoolAuthError {
+    /// `InitiateAuth` failed.
+    InitiateAuth(RusotoError<InitiateAuthError>),
+    /// `RespondToAuthChallenge` failed.
+    RespondToAuthChallenge(RusotoError<RespondToAuthChallengeError>),
+    /// `ConfirmDevice` failed.
+    ConfirmDevice(RusotoError<ConfirmDeviceError>),
+    /// Cognito asked for a challenge the authenticator doesn't answer, e.g. `SMS_MFA` or
+    /// `NEW_PASSWORD_REQUIRED`. It can be answered with `RespondToAuthChallenge` in `session`.
+    ChallengeRequired {
+        /// The name of the challenge.
+        name: String,
+        /// The parameters of the challenge.
+        parameters: HashMap<String, String>,
+        /// The session to answer the challenge in.
+        session: Option<String>,
+    },
+    /// A response of Cognito was missing parameters or had invalid ones.
+    InvalidResponse(String),
+}
+
+impl fmt::Display for UserPoolAuthError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            UserPoolAuthError::InitiateAuth(ref err) => write!(f, "InitiateAuth failed: {}", err),
+            UserPoolAuthError::RespondToAuthChallenge(ref err) => {
+                write!(f, "RespondToAuthChallenge failed: {}", err)
+            }
+            UserPoolAuthError::ConfirmDevice(ref err) => {
+                write!(f, "ConfirmDevice failed: {}", err)
+            }
+            UserPoolAuthError::ChallengeRequired { ref name, .. } => {
+                write!(f, "Unsupported challenge {}", name)
+            }
+            UserPoolAuthError::InvalidResponse(ref cause) => {
+                write!(f, "Invalid Cognito response: {}", cause)
+            }
+        }
+    }
+}
+
+impl Error for UserPoolAuthError {}
+
+/// An answer of `InitiateAuth` or `RespondToAuthChallenge`.
+struct Step {
+    challenge_name: Option<String>,
+    parameters: HashMap<String, String>,
+    session: Option<String>,
+    result: Option<AuthenticationResultType>,
+}
+
+impl From<InitiateAuthResponse> for Step {
+    fn from(response: InitiateAuthResponse) -> Step {
+        Step {
+            challenge_name: response.challenge_name,
+            parameters: response.challenge_parameters.unwrap_or_default(),
+            session: response.session,
+            result: response.authentication_result,
+        }
+    }
+}
+
+impl From<RespondToAuthChallengeResponse> for Step {
+    fn from(response: RespondToAuthChallengeResponse) -> Step {
+        Step {
+            challenge_name: response.challenge_name,
+            parameters: response.challenge_parameters.unwrap_or_default(),
+            session: response.session,
+            result: response.authentication_result,
+        }
+    }
+}
+
+impl Step {
+    fn parameter(&self, name: &str) -> Result<&str, UserPoolAuthError> {
+        self.parameters
+            .get(name)
+            .map(String::as_str)
+            .ok_or_else(|| {
+                UserPoolAuthError::InvalidResponse(format!("no {} challenge parameter", name))
+            })
+    }
+}
+
+impl UserPoolAuthenticator {
+    /// Creates a new `UserPoolAuthenticator` for the app client `client_id` of the user pool
+    /// `user_pool_id`.
+    ///
+    /// The operations it calls are public, the `CognitoIdentityProviderClient` can be created
+    /// with `Client::new_not_signing`.
+    pub fn new(
+        client: CognitoIdentityProviderClient,
+        user_pool_id: String,
+        client_id: String,
+    ) -> UserPoolAuthenticator {
+        UserPoolAuthenticator {
+            client,
+            user_pool_id,
+            client_id,
+            client_secret: None,
+            device: None,
+        }
+    }
+
+    /// Sets the secret of the app client, sent hashed as the `SECRET_HASH` parameter.
+    pub fn set_client_secret(&mut self, client_secret: Option<String>) {
+        self.client_secret = client_secret;
+    }
+
+    /// Sets the device to authenticate, letting remembered devices skip MFA.
+    pub fn set_device(&mut self, device: Option<DeviceCredentials>) {
+        self.device = device;
+    }
+
+    /// Signs `username` in with `password`.
+    pub async fn authenticate(
+        &self,
+        username: &str,
+        password: &str,
+    ) -> Result<UserPoolTokens, UserPoolAuthError> {
+        let srp = SrpClient::new();
+        let mut parameters = self.parameters(username);
+        parameters.insert("SRP_A".to_owned(), srp.public_key());
+        let response = self
+            .client
+            .initiate_auth(InitiateAuthRequest {
+                auth_flow: "USER_SRP_AUTH".to_owned(),
+                auth_parameters: Some(parameters),
+                client_id: self.client_id.clone(),
+                ..Default::default()
+            })
+            .await
+            .map_err(UserPoolAuthError::InitiateAuth)?;
+        let mut step = Step::from(response);
+        let mut device_srp = None;
+        loop {
+            let challenge_name = match step.challenge_name {
+                Some(ref name) => name.clone(),
+                None => return tokens(step.result, None),
+            };
+            let username = step
+                .parameters
+                .get("USERNAME")
+                .map(String::as_str)
+                .unwrap_or(username)
+                .to_owned();
+            let responses = match (challenge_name.as_str(), &self.device) {
+                ("PASSWORD_VERIFIER", _) => {
+                    let user_id = step.parameter("USER_ID_FOR_SRP")?.to_owned();
+                    let mut responses = self.parameters(&user_id);
+                    self.claim(
+                        &mut responses,
+                        &srp,
+                        self.pool_name()?,
+                        &user_id,
+                        password,
+                        &step,
+                    )?;
+                    responses
+                }
+                ("DEVICE_SRP_AUTH", Some(_)) => {
+                    let srp = SrpClient::new();
+                    let mut responses = self.parameters(&username);
+                    responses.insert("SRP_A".to_owned(), srp.public_key());
+                    device_srp = Some(srp);
+                    responses
+                }
+                ("DEVICE_PASSWORD_VERIFIER", Some(device)) => {
+                    let srp = device_srp.as_ref().ok_or_else(|| {
+                        UserPoolAuthError::InvalidResponse(
+                            "DEVICE_PASSWORD_VERIFIER challenge before DEVICE_SRP_AUTH".to_owned(),
+                        )
+                    })?;
+                    let mut responses = self.parameters(&username);
+                    self.claim(
+                        &mut responses,
+                        srp,
+                        &device.device_group_key,
+                        &device.device_key,
+                        &device.device_password,
+                        &step,
+                    )?;
+                    responses
+                }
+                _ => {
+                    return Err(UserPoolAuthError::ChallengeRequired {
+                        name: challenge_name,
+                        parameters: step.parameters,
+                        session: step.session,
+                    })
+                }
+            };
+            let response = self
+                .client
+                .respond_to_auth_challenge(RespondToAuthChallengeRequest {
+                    challenge_name,
+                    challenge_responses: Some(responses),
+                    client_id: self.client_id.clone(),
+                    session: step.session.take(),
+                    ..Default::default()
+                })
+                .await
+                .map_err(UserPoolAuthError::RespondToAuthChallenge)?;
+            step = Step::from(response);
+        }
+    }
+
+    /// Gets new ID and access tokens with the `refresh_token` of `username`, which is only used
+    /// for the `SECRET_HASH` of app clients with a secret.
+    pub async fn refresh(
+        &self,
+        username: &str,
+        refresh_token: &str,
+    ) -> Result<UserPoolTokens, UserPoolAuthError> {
+        let mut parameters = self.parameters(username);
+        parameters.remove("USERNAME");
+        parameters.insert("REFRESH_TOKEN".to_owned(), refresh_token.to_owned());
+        let response = self
+            .client
+            .initiate_auth(InitiateAuthRequest {
+                auth_flow: "REFRESH_TOKEN_AUTH".to_owned(),
+                auth_parameters: Some(parameters),
+                client_id: self.client_id.clone(),
+                ..Default::default()
+            })
+            .await
+            .map_err(UserPoolAuthError::InitiateAuth)?;
+        let step = Step::from(response);
+        if let Some(name) = step.challenge_name {
+            return Err(UserPoolAuthError::ChallengeRequired {
+                name,
+                parameters: step.parameters,
+                session: step.session,
+            });
+        }
+        tokens(step.result, Some(refresh_token))
+    }
+
+    /// Confirms the `new_device` of signed in tokens, generating its device password.
+    ///
+    /// The returned credentials should be stored, and set with `set_device` to authenticate the
+    /// device. If the user pool remembers devices only when users opt in, the device has to be
+    /// marked as remembered with `UpdateDeviceStatus` too.
+    pub async fn confirm_device(
+        &self,
+        access_token: &str,
+        new_device: &NewDeviceMetadataType,
+        device_name: Option<String>,
+    ) -> Result<DeviceCredentials, UserPoolAuthError> {
+        let missing = |field| UserPoolAuthError::InvalidResponse(format!("no {} of device", field));
+        let device_key = new_device
+            .device_key
+            .clone()
+            .ok_or_else(|| missing("key"))?;
+        let device_group_key = new_device
+            .device_group_key
+            .clone()
+            .ok_or_else(|| missing("group key"))?;
+        let (device_password, password_verifier, salt) =
+            srp::device_verifier(&device_group_key, &device_key);
+        self.client
+            .confirm_device(ConfirmDeviceRequest {
+                access_token: access_token.to_owned(),
+                device_key: device_key.clone(),
+                device_name,
+                device_secret_verifier_config: Some(DeviceSecretVerifierConfigType {
+                    password_verifier: Some(password_verifier),
+                    salt: Some(salt),
+                }),
+            })
+            .await
+            .map_err(UserPoolAuthError::ConfirmDevice)?;
+        Ok(DeviceCredentials {
+            device_key,
+            device_group_key,
+            device_password,
+        })
+    }
+
+    /// The `USERNAME`, `SECRET_HASH` and `DEVICE_KEY` parameters.
+    fn parameters(&self, username: &str) -> HashMap<String, String> {
+        let mut parameters = HashMap::new();
+        parameters.insert("USERNAME".to_owned(), username.to_owned());
+        if let Some(ref client_secret) = self.client_secret {
+            parameters.insert(
+                "SECRET_HASH".to_owned(),
+                srp::secret_hash(client_secret, username, &self.client_id),
+            );
+        }
+        if let Some(ref device) = self.device {
+            parameters.insert("DEVICE_KEY".to_owned(), device.device_key.clone());
+        }
+        parameters
+    }
+
+    /// The name of the user pool SRP uses, the part of its ID after the region.
+    fn pool_name(&self) -> Result<&str, UserPoolAuthError> {
+        self.user_pool_id
+            .split_once('_')
+            .map(|(_, name)| name)
+            .ok_or_else(|| {
+                UserPoolAuthError::InvalidResponse(format!(
+                    "invalid user pool ID {}",
+                    self.user_pool_id
+                ))
+            })
+    }
+
+    /// Adds the proof of `password` for the `SRP_B`, `SALT` and `SECRET_BLOCK` of `step`.
+    fn claim(
+        &self,
+        responses: &mut HashMap<String, String>,
+        srp: &SrpClient,
+        pool_name: &str,
+        username: &str,
+        password: &str,
+        step: &Step,
+    ) -> Result<(), UserPoolAuthError> {
+        let secret_block = step.parameter("SECRET_BLOCK")?;
+        let key = srp
+            .authentication_key(
+                pool_name,
+                username,
+                password,
+                step.parameter("SRP_B")?,
+                step.parameter("SALT")?,
+            )
+            .map_err(UserPoolAuthError::InvalidResponse)?;
+        let timestamp = srp::timestamp(Utc::now());
+        let signature = srp::claim_signature(&key, pool_name, username, secret_block, &timestamp)
+            .map_err(UserPoolAuthError::InvalidResponse)?;
+        responses.insert(
+            "PASSWORD_CLAIM_SECRET_BLOCK".to_owned(),
+            secret_block.to_owned(),
+        );
+        responses.insert("PASSWORD_CLAIM_SIGNATURE".to_owned(), signature);
+        responses.insert("TIMESTAMP".to_owned(), timestamp);
+        Ok(())
+    }
+}
+
+impl fmt::Debug for UserPoolAuthenticator {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("UserPoolAuthenticator")
+            .field("user_pool_id", &self.user_pool_id)
+            .field("client_id", &self.client_id)
+            .field(
+                "client_secret",
+                &self.client_secret.as_ref().map(|_| "**********"),
+            )
+            .field("device", &self.device)
+            .finish()
+    }
+}
+
+fn tokens(
+    result: Option<AuthenticationResultType>,
+    refresh_token: Option<&str>,
+) -> Result<UserPoolTokens, UserPoolAuthError> {
+    let result = result.ok_or_else(|| {
+        UserPoolAuthError::InvalidResponse("no challenge or authentication result".to_owned())
+    })?;
+    let missing = |field| UserPoolAuthError::InvalidResponse(format!("no {}", field));
+    Ok(UserPoolTokens {
+        id_token: result.id_token.ok_or_else(|| missing("ID token"))?,
+        access_token: result.access_token.ok_or_else(|| missing("access token"))?,
+        refresh_token: result
+            .refresh_token
+            .or_else(|| refresh_token.map(ToOwned::to_owned)),
+        expires_in: result.expires_in,
+        new_device: result.new_device_metadata,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::VecDeque;
+    use std::sync::{Arc, Mutex};
+    use std::time::Duration;
+
+    use futures::FutureExt;
+    use http::{Request, Response};
+    use rusoto_core::request::DispatchSignedRequestFuture;
+    use rusoto_core::signature::{SignedRequest, SignedRequestPayload};
+    use rusoto_core::{ByteStream, DispatchSignedRequest, Region};
+    use rusoto_mock::MockCredentialsProvider;
+    use serde_json::Value;
+
+    type Requests = Arc<Mutex<Vec<(String, Value)>>>;
+
+    /// Answers requests with `responses` in order, recording their targets and bodies.
+    struct ScriptedDispatcher {
+        responses: Mutex<VecDeque<&'static str>>,
+        requests: Requests,
+    }
+
+    impl DispatchSignedRequest for ScriptedDispatcher {
+        fn dispatch(
+            &self,
+            request: Request<ByteStream>,
+            _timeout: Option<Duration>,
+        ) -> DispatchSignedRequestFuture {
+            let request = request.extensions().get::<SignedRequest>().unwrap();
+            let target = String::from_utf8(request.headers()["x-amz-target"][0].clone()).unwrap();
+            let body = match request.payload {
+                Some(SignedRequestPayload::Buffer(ref bytes)) => {
+                    serde_json::from_slice(bytes).unwrap()
+                }
+                _ => Value::Null,
+            };
+            self.requests.lock().unwrap().push((target, body));
+            let response = self.responses.lock().unwrap().pop_front().unwrap();
+            let response = Response::new(ByteStream::from(response.as_bytes().to_vec()));
+            futures::future::ready(Ok(response)).boxed()
+        }
+    }
+
+    fn authenticator(responses: &[&'static str]) -> (UserPoolAuthenticator, Requests) {
+        let requests = Requests::default();
+        let dispatcher = ScriptedDispatcher {
+            responses: Mutex::new(responses.iter().cloned().collect()),
+            requests: requests.clone(),
+        };
+        let client = CognitoIdentityProviderClient::new_with(
+            dispatcher,
+            MockCredentialsProvider,
+            Region::UsEast1,
+        );
+        let authenticator =
+            UserPoolAuthenticator::new(client, "us-east-1_pool".to_owned(), "client".to_owned());
+        (authenticator, requests)
+    }
+
+    const PASSWORD_VERIFIER: &str = r#"{"ChallengeName": "PASSWORD_VERIFIER",
+        "ChallengeParameters": {"USERNAME": "user-sub", "USER_ID_FOR_SRP": "user-sub",
+        "SALT": "a1b2c3", "SRP_B": "6f2b9a4c", "SECRET_BLOCK": "c2VjcmV0"}}"#;
+    const AUTHENTICATED: &str = r#"{"ChallengeParameters": {}, "AuthenticationResult": {
+        "AccessToken": "access", "IdToken": "id", "RefreshToken": "refresh",
+        "ExpiresIn": 3600, "TokenType": "Bearer"}}"#;
+
+    #[tokio::test]
+    async fn authenticates_users_and_devices() {
+        let (mut authenticator, requests) = authenticator(&[
+            PASSWORD_VERIFIER,
+            r#"{"ChallengeName": "DEVICE_SRP_AUTH", "Session": "session",
+                "ChallengeParameters": {"USERNAME": "user-sub"}}"#,
+            r#"{"ChallengeName": "DEVICE_PASSWORD_VERIFIER", "ChallengeParameters": {
+                "USERNAME": "user-sub", "SALT": "d4e5f6", "SRP_B": "51c3e7",
+                "SECRET_BLOCK": "ZGV2aWNl"}}"#,
+            AUTHENTICATED,
+        ]);
+        authenticator.set_client_secret(Some("secret".to_owned()));
+        authenticator.set_device(Some(DeviceCredentials {
+            device_key: "us-east-1_device".to_owned(),
+            device_group_key: "group".to_owned(),
+            device_password: "device-password".to_owned(),
+        }));
+        let tokens = authenticator
+            .authenticate("jane", "password")
+            .await
+            .unwrap();
+        assert_eq!(tokens.id_token, "id");
+        assert_eq!(tokens.access_token, "access");
+        assert_eq!(tokens.refresh_token.as_deref(), Some("refresh"));
+        assert_eq!(tokens.expires_in, Some(3600));
+
+        let requests = requests.lock().unwrap();
+        let targets: Vec<&str> = requests.iter().map(|(target, _)| target.as_str()).collect();
+        assert_eq!(
+            targets,
+            vec![
+                "AWSCognitoIdentityProviderService.InitiateAuth",
+                "AWSCognitoIdentityProviderService.RespondToAuthChallenge",
+                "AWSCognitoIdentityProviderService.RespondToAuthChallenge",
+                "AWSCognitoIdentityProviderService.RespondToAuthChallenge",
+            ]
+        );
+        let auth = &requests[0].1["AuthParameters"];
+        assert_eq!(requests[0].1["AuthFlow"], "USER_SRP_AUTH");
+        assert_eq!(auth["USERNAME"], "jane");
+        assert_eq!(auth["DEVICE_KEY"], "us-east-1_device");
+        assert_eq!(
+            auth["SECRET_HASH"],
+            srp::secret_hash("secret", "jane", "client")
+        );
+
+        let claim = &requests[1].1["ChallengeResponses"];
+        assert_eq!(requests[1].1["ChallengeName"], "PASSWORD_VERIFIER");
+        assert_eq!(claim["USERNAME"], "user-sub");
+        assert_eq!(claim["PASSWORD_CLAIM_SECRET_BLOCK"], "c2VjcmV0");
+        let signature = base64::decode(claim["PASSWORD_CLAIM_SIGNATURE"].as_str().unwrap());
+        assert_eq!(signature.unwrap().len(), 32);
+        assert_eq!(
+            claim["SECRET_HASH"],
+            srp::secret_hash("secret", "user-sub", "client")
+        );
+
+        let device = &requests[2].1["ChallengeResponses"];
+        assert_eq!(requests[2].1["Session"], "session");
+        assert_eq!(device["DEVICE_KEY"], "us-east-1_device");
+        assert_ne!(device["SRP_A"], auth["SRP_A"]);
+        let device_claim = &requests[3].1["ChallengeResponses"];
+        assert_eq!(device_claim["PASSWORD_CLAIM_SECRET_BLOCK"], "ZGV2aWNl");
+        assert!(device_claim["TIMESTAMP"]
+            .as_str()
+            .unwrap()
+            .contains(" UTC "));
+    }
+
+    #[tokio::test]
+    async fn refreshes_tokens() {
+        let (authenticator, requests) = authenticator(&[
+            r#"{"AuthenticationResult": {"AccessToken": "new-access", "IdToken": "new-id"}}"#,
+        ]);
+        let tokens = authenticator.refresh("jane", "refresh").await.unwrap();
+        assert_eq!(tokens.access_token, "new-access");
+        assert_eq!(tokens.refresh_token.as_deref(), Some("refresh"));
+        let request = &requests.lock().unwrap()[0].1;
+        assert_eq!(request["AuthFlow"], "REFRESH_TOKEN_AUTH");
+        assert_eq!(request["AuthParameters"]["REFRESH_TOKEN"], "refresh");
+    }
+
+    #[tokio::test]
+    async fn returns_unsupported_challenges() {
+        let (authenticator, _) = authenticator(&[
+            PASSWORD_VERIFIER,
+            r#"{"ChallengeName": "SMS_MFA", "Session": "session",
+                "ChallengeParameters": {"CODE_DELIVERY_DESTINATION": "+*******1234"}}"#,
+        ]);
+        match authenticator.authenticate("jane", "password").await {
+            Err(UserPoolAuthError::ChallengeRequired { name, session, .. }) => {
+                assert_eq!(name, "SMS_MFA");
+                assert_eq!(session.as_deref(), Some("session"));
+            }
+            other => panic!("unexpected result {:?}", other),
+        }
+    }
+}
@@ -16,8 +16,8 @@ use std::fmt;
 use async_trait::async_trait;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
-use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest, ResponseMetadata};
+use rusoto_core::{Client, ErrorMetadata, RusotoError};
 
 use rusoto_core::proto;
 use rusoto_core::signature::SignedRequest;
@@ -4146,6 +4146,7 @@ pub struct VerifyUserAttributeResponse {}
 
 /// Errors returned by AddCustomAttributes
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum AddCustomAttributesError {
     /// <p>This exception is thrown when Amazon Cognito encounters an internal error.</p>
     InternalError(String),
@@ -4159,6 +4160,8 @@ pub enum AddCustomAttributesError {
     TooManyRequests(String),
     /// <p>This exception is thrown when you are trying to modify a user pool while a user import job is in progress for that pool.</p>
     UserImportInProgress(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl AddCustomAttributesError {
@@ -4166,31 +4169,43 @@ impl AddCustomAttributesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalErrorException" => {
-                    return RusotoError::Service(AddCustomAttributesError::InternalError(err.msg))
+                    return RusotoError::service(
+                        AddCustomAttributesError::InternalError(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(AddCustomAttributesError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        AddCustomAttributesError::InvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "NotAuthorizedException" => {
-                    return RusotoError::Service(AddCustomAttributesError::NotAuthorized(err.msg))
+                    return RusotoError::service(
+                        AddCustomAttributesError::NotAuthorized(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(AddCustomAttributesError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        AddCustomAttributesError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(AddCustomAttributesError::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        AddCustomAttributesError::TooManyRequests(err.msg),
+                        &res,
+                    )
                 }
                 "UserImportInProgressException" => {
-                    return RusotoError::Service(AddCustomAttributesError::UserImportInProgress(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        AddCustomAttributesError::UserImportInProgress(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(AddCustomAttributesError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -4206,12 +4221,14 @@ impl fmt::Display for AddCustomAttributesError {
             AddCustomAttributesError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             AddCustomAttributesError::TooManyRequests(ref cause) => write!(f, "{}", cause),
             AddCustomAttributesError::UserImportInProgress(ref cause) => write!(f, "{}", cause),
+            AddCustomAttributesError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for AddCustomAttributesError {}
 /// Errors returned by AdminAddUserToGroup
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum AdminAddUserToGroupError {
     /// <p>This exception is thrown when Amazon Cognito encounters an internal error.</p>
     InternalError(String),
@@ -4225,6 +4242,8 @@ pub enum AdminAddUserToGroupError {
     TooManyRequests(String),
     /// <p>This exception is thrown when a user is not found.</p>
     UserNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl AdminAddUserToGroupError {
@@ -4232,29 +4251,43 @@ impl AdminAddUserToGroupError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalErrorException" => {
-                    return RusotoError::Service(AdminAddUserToGroupError::InternalError(err.msg))
+                    return RusotoError::service(
+                        AdminAddUserToGroupError::InternalError(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(AdminAddUserToGroupError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        AdminAddUserToGroupError::InvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "NotAuthorizedException" => {
-                    return RusotoError::Service(AdminAddUserToGroupError::NotAuthorized(err.msg))
+                    return RusotoError::service(
+                        AdminAddUserToGroupError::NotAuthorized(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(AdminAddUserToGroupError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        AdminAddUserToGroupError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(AdminAddUserToGroupError::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        AdminAddUserToGroupError::TooManyRequests(err.msg),
+                        &res,
+                    )
                 }
                 "UserNotFoundException" => {
-                    return RusotoError::Service(AdminAddUserToGroupError::UserNotFound(err.msg))
+                    return RusotoError::service(
+                        AdminAddUserToGroupError::UserNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(AdminAddUserToGroupError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -4270,12 +4303,14 @@ impl fmt::Display for AdminAddUserToGroupError {
             AdminAddUserToGroupError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             AdminAddUserToGroupError::TooManyRequests(ref cause) => write!(f, "{}", cause),
             AdminAddUserToGroupError::UserNotFound(ref cause) => write!(f, "{}", cause),
+            AdminAddUserToGroupError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for AdminAddUserToGroupError {}
 /// Errors returned by AdminConfirmSignUp
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum AdminConfirmSignUpError {
     /// <p>This exception is thrown when Amazon Cognito encounters an internal error.</p>
     InternalError(String),
@@ -4299,6 +4334,8 @@ pub enum AdminConfirmSignUpError {
     UserLambdaValidation(String),
     /// <p>This exception is thrown when a user is not found.</p>
     UserNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl AdminConfirmSignUpError {
@@ -4306,46 +4343,73 @@ impl AdminConfirmSignUpError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalErrorException" => {
-                    return RusotoError::Service(AdminConfirmSignUpError::InternalError(err.msg))
+                    return RusotoError::service(
+                        AdminConfirmSignUpError::InternalError(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidLambdaResponseException" => {
-                    return RusotoError::Service(AdminConfirmSignUpError::InvalidLambdaResponse(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        AdminConfirmSignUpError::InvalidLambdaResponse(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(AdminConfirmSignUpError::InvalidParameter(err.msg))
+                    return RusotoError::service(
+                        AdminConfirmSignUpError::InvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(AdminConfirmSignUpError::LimitExceeded(err.msg))
+                    return RusotoError::service(
+                        AdminConfirmSignUpError::LimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "NotAuthorizedException" => {
-                    return RusotoError::Service(AdminConfirmSignUpError::NotAuthorized(err.msg))
+                    return RusotoError::service(
+                        AdminConfirmSignUpError::NotAuthorized(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(AdminConfirmSignUpError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        AdminConfirmSignUpError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "TooManyFailedAttemptsException" => {
-                    return RusotoError::Service(AdminConfirmSignUpError::TooManyFailedAttempts(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        AdminConfirmSignUpError::TooManyFailedAttempts(err.msg),
+                        &res,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(AdminConfirmSignUpError::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        AdminConfirmSignUpError::TooManyRequests(err.msg),
+                        &res,
+                    )
                 }
                 "UnexpectedLambdaException" => {
-                    return RusotoError::Service(AdminConfirmSignUpError::UnexpectedLambda(err.msg))
+                    return RusotoError::service(
+                        AdminConfirmSignUpError::UnexpectedLambda(err.msg),
+                        &res,
+                    )
                 }
                 "UserLambdaValidationException" => {
-                    return RusotoError::Service(AdminConfirmSignUpError::UserLambdaValidation(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        AdminConfirmSignUpError::UserLambdaValidation(err.msg),
+                        &res,
+                    )
                 }
                 "UserNotFoundException" => {
-                    return RusotoError::Service(AdminConfirmSignUpError::UserNotFound(err.msg))
+                    return RusotoError::service(
+                        AdminConfirmSignUpError::UserNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(AdminConfirmSignUpError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -4366,12 +4430,14 @@ impl fmt::Display for AdminConfirmSignUpError {
             AdminConfirmSignUpError::UnexpectedLambda(ref cause) => write!(f, "{}", cause),
             AdminConfirmSignUpError::UserLambdaValidation(ref cause) => write!(f, "{}", cause),
             AdminConfirmSignUpError::UserNotFound(ref cause) => write!(f, "{}", cause),
+            AdminConfirmSignUpError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for AdminConfirmSignUpError {}
 /// Errors returned by AdminCreateUser
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum AdminCreateUserError {
     /// <p>This exception is thrown when a verification code fails to deliver successfully.</p>
     CodeDeliveryFailure(String),
@@ -4405,6 +4471,8 @@ pub enum AdminCreateUserError {
     UserNotFound(String),
     /// <p>This exception is thrown when Amazon Cognito encounters a user name that already exists in the user pool.</p>
     UsernameExists(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl AdminCreateUserError {
@@ -4412,65 +4480,94 @@ impl AdminCreateUserError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "CodeDeliveryFailureException" => {
-                    return RusotoError::Service(AdminCreateUserError::CodeDeliveryFailure(err.msg))
+                    return RusotoError::service(
+                        AdminCreateUserError::CodeDeliveryFailure(err.msg),
+                        &res,
+                    )
                 }
                 "InternalErrorException" => {
-                    return RusotoError::Service(AdminCreateUserError::InternalError(err.msg))
+                    return RusotoError::service(AdminCreateUserError::InternalError(err.msg), &res)
                 }
                 "InvalidLambdaResponseException" => {
-                    return RusotoError::Service(AdminCreateUserError::InvalidLambdaResponse(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        AdminCreateUserError::InvalidLambdaResponse(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(AdminCreateUserError::InvalidParameter(err.msg))
+                    return RusotoError::service(
+                        AdminCreateUserError::InvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidPasswordException" => {
-                    return RusotoError::Service(AdminCreateUserError::InvalidPassword(err.msg))
+                    return RusotoError::service(
+                        AdminCreateUserError::InvalidPassword(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidSmsRoleAccessPolicyException" => {
-                    return RusotoError::Service(AdminCreateUserError::InvalidSmsRoleAccessPolicy(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        AdminCreateUserError::InvalidSmsRoleAccessPolicy(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidSmsRoleTrustRelationshipException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         AdminCreateUserError::InvalidSmsRoleTrustRelationship(err.msg),
+                        &res,
                     )
                 }
                 "NotAuthorizedException" => {
-                    return RusotoError::Service(AdminCreateUserError::NotAuthorized(err.msg))
+                    return RusotoError::service(AdminCreateUserError::NotAuthorized(err.msg), &res)
                 }
                 "PreconditionNotMetException" => {
-                    return RusotoError::Service(AdminCreateUserError::PreconditionNotMet(err.msg))
+                    return RusotoError::service(
+                        AdminCreateUserError::PreconditionNotMet(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(AdminCreateUserError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        AdminCreateUserError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(AdminCreateUserError::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        AdminCreateUserError::TooManyRequests(err.msg),
+                        &res,
+                    )
                 }
                 "UnexpectedLambdaException" => {
-                    return RusotoError::Service(AdminCreateUserError::UnexpectedLambda(err.msg))
+                    return RusotoError::service(
+                        AdminCreateUserError::UnexpectedLambda(err.msg),
+                        &res,
+                    )
                 }
                 "UnsupportedUserStateException" => {
-                    return RusotoError::Service(AdminCreateUserError::UnsupportedUserState(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        AdminCreateUserError::UnsupportedUserState(err.msg),
+                        &res,
+                    )
                 }
                 "UserLambdaValidationException" => {
-                    return RusotoError::Service(AdminCreateUserError::UserLambdaValidation(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        AdminCreateUserError::UserLambdaValidation(err.msg),
+                        &res,
+                    )
                 }
                 "UserNotFoundException" => {
-                    return RusotoError::Service(AdminCreateUserError::UserNotFound(err.msg))
+                    return RusotoError::service(AdminCreateUserError::UserNotFound(err.msg), &res)
                 }
                 "UsernameExistsException" => {
-                    return RusotoError::Service(AdminCreateUserError::UsernameExists(err.msg))
+                    return RusotoError::service(
+                        AdminCreateUserError::UsernameExists(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(AdminCreateUserError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -4498,12 +4595,14 @@ impl fmt::Display for AdminCreateUserError {
             AdminCreateUserError::UserLambdaValidation(ref cause) => write!(f, "{}", cause),
             AdminCreateUserError::UserNotFound(ref cause) => write!(f, "{}", cause),
             AdminCreateUserError::UsernameExists(ref cause) => write!(f, "{}", cause),
+            AdminCreateUserError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for AdminCreateUserError {}
 /// Errors returned by AdminDeleteUser
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum AdminDeleteUserError {
     /// <p>This exception is thrown when Amazon Cognito encounters an internal error.</p>
     InternalError(String),
@@ -4517,6 +4616,8 @@ pub enum AdminDeleteUserError {
     TooManyRequests(String),
     /// <p>This exception is thrown when a user is not found.</p>
     UserNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl AdminDeleteUserError {
@@ -4524,25 +4625,34 @@ impl AdminDeleteUserError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalErrorException" => {
-                    return RusotoError::Service(AdminDeleteUserError::InternalError(err.msg))
+                    return RusotoError::service(AdminDeleteUserError::InternalError(err.msg), &res)
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(AdminDeleteUserError::InvalidParameter(err.msg))
+                    return RusotoError::service(
+                        AdminDeleteUserError::InvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "NotAuthorizedException" => {
-                    return RusotoError::Service(AdminDeleteUserError::NotAuthorized(err.msg))
+                    return RusotoError::service(AdminDeleteUserError::NotAuthorized(err.msg), &res)
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(AdminDeleteUserError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        AdminDeleteUserError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(AdminDeleteUserError::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        AdminDeleteUserError::TooManyRequests(err.msg),
+                        &res,
+                    )
                 }
                 "UserNotFoundException" => {
-                    return RusotoError::Service(AdminDeleteUserError::UserNotFound(err.msg))
+                    return RusotoError::service(AdminDeleteUserError::UserNotFound(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(AdminDeleteUserError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -4558,12 +4668,14 @@ impl fmt::Display for AdminDeleteUserError {
             AdminDeleteUserError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             AdminDeleteUserError::TooManyRequests(ref cause) => write!(f, "{}", cause),
             AdminDeleteUserError::UserNotFound(ref cause) => write!(f, "{}", cause),
+            AdminDeleteUserError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for AdminDeleteUserError {}
 /// Errors returned by AdminDeleteUserAttributes
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum AdminDeleteUserAttributesError {
     /// <p>This exception is thrown when Amazon Cognito encounters an internal error.</p>
     InternalError(String),
@@ -4577,6 +4689,8 @@ pub enum AdminDeleteUserAttributesError {
     TooManyRequests(String),
     /// <p>This exception is thrown when a user is not found.</p>
     UserNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl AdminDeleteUserAttributesError {
@@ -4584,37 +4698,45 @@ impl AdminDeleteUserAttributesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalErrorException" => {
-                    return RusotoError::Service(AdminDeleteUserAttributesError::InternalError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        AdminDeleteUserAttributesError::InternalError(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(AdminDeleteUserAttributesError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        AdminDeleteUserAttributesError::InvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "NotAuthorizedException" => {
-                    return RusotoError::Service(AdminDeleteUserAttributesError::NotAuthorized(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        AdminDeleteUserAttributesError::NotAuthorized(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(AdminDeleteUserAttributesError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        AdminDeleteUserAttributesError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(AdminDeleteUserAttributesError::TooManyRequests(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        AdminDeleteUserAttributesError::TooManyRequests(err.msg),
+                        &res,
+                    )
                 }
                 "UserNotFoundException" => {
-                    return RusotoError::Service(AdminDeleteUserAttributesError::UserNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        AdminDeleteUserAttributesError::UserNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(AdminDeleteUserAttributesError::Unhandled, &res)
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -4630,12 +4752,14 @@ impl fmt::Display for AdminDeleteUserAttributesError {
             AdminDeleteUserAttributesError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             AdminDeleteUserAttributesError::TooManyRequests(ref cause) => write!(f, "{}", cause),
             AdminDeleteUserAttributesError::UserNotFound(ref cause) => write!(f, "{}", cause),
+            AdminDeleteUserAttributesError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for AdminDeleteUserAttributesError {}
 /// Errors returned by AdminDisableProviderForUser
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum AdminDisableProviderForUserError {
     /// <p>This exception is thrown when a user tries to confirm the account with an email or phone number that has already been supplied as an alias from a different account. This exception tells user that an account with this email or phone already exists.</p>
     AliasExists(String),
@@ -4651,6 +4775,8 @@ pub enum AdminDisableProviderForUserError {
     TooManyRequests(String),
     /// <p>This exception is thrown when a user is not found.</p>
     UserNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl AdminDisableProviderForUserError {
@@ -4660,42 +4786,54 @@ impl AdminDisableProviderForUserError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AliasExistsException" => {
-                    return RusotoError::Service(AdminDisableProviderForUserError::AliasExists(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        AdminDisableProviderForUserError::AliasExists(err.msg),
+                        &res,
+                    )
                 }
                 "InternalErrorException" => {
-                    return RusotoError::Service(AdminDisableProviderForUserError::InternalError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        AdminDisableProviderForUserError::InternalError(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         AdminDisableProviderForUserError::InvalidParameter(err.msg),
+                        &res,
                     )
                 }
                 "NotAuthorizedException" => {
-                    return RusotoError::Service(AdminDisableProviderForUserError::NotAuthorized(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        AdminDisableProviderForUserError::NotAuthorized(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         AdminDisableProviderForUserError::ResourceNotFound(err.msg),
+                        &res,
                     )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(AdminDisableProviderForUserError::TooManyRequests(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        AdminDisableProviderForUserError::TooManyRequests(err.msg),
+                        &res,
+                    )
                 }
                 "UserNotFoundException" => {
-                    return RusotoError::Service(AdminDisableProviderForUserError::UserNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        AdminDisableProviderForUserError::UserNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        AdminDisableProviderForUserError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -4712,12 +4850,14 @@ impl fmt::Display for AdminDisableProviderForUserError {
             AdminDisableProviderForUserError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             AdminDisableProviderForUserError::TooManyRequests(ref cause) => write!(f, "{}", cause),
             AdminDisableProviderForUserError::UserNotFound(ref cause) => write!(f, "{}", cause),
+            AdminDisableProviderForUserError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for AdminDisableProviderForUserError {}
 /// Errors returned by AdminDisableUser
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum AdminDisableUserError {
     /// <p>This exception is thrown when Amazon Cognito encounters an internal error.</p>
     InternalError(String),
@@ -4731,6 +4871,8 @@ pub enum AdminDisableUserError {
     TooManyRequests(String),
     /// <p>This exception is thrown when a user is not found.</p>
     UserNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl AdminDisableUserError {
@@ -4738,25 +4880,40 @@ impl AdminDisableUserError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalErrorException" => {
-                    return RusotoError::Service(AdminDisableUserError::InternalError(err.msg))
+                    return RusotoError::service(
+                        AdminDisableUserError::InternalError(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(AdminDisableUserError::InvalidParameter(err.msg))
+                    return RusotoError::service(
+                        AdminDisableUserError::InvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "NotAuthorizedException" => {
-                    return RusotoError::Service(AdminDisableUserError::NotAuthorized(err.msg))
+                    return RusotoError::service(
+                        AdminDisableUserError::NotAuthorized(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(AdminDisableUserError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        AdminDisableUserError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(AdminDisableUserError::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        AdminDisableUserError::TooManyRequests(err.msg),
+                        &res,
+                    )
                 }
                 "UserNotFoundException" => {
-                    return RusotoError::Service(AdminDisableUserError::UserNotFound(err.msg))
+                    return RusotoError::service(AdminDisableUserError::UserNotFound(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(AdminDisableUserError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -4772,12 +4929,14 @@ impl fmt::Display for AdminDisableUserError {
             AdminDisableUserError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             AdminDisableUserError::TooManyRequests(ref cause) => write!(f, "{}", cause),
             AdminDisableUserError::UserNotFound(ref cause) => write!(f, "{}", cause),
+            AdminDisableUserError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for AdminDisableUserError {}
 /// Errors returned by AdminEnableUser
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum AdminEnableUserError {
     /// <p>This exception is thrown when Amazon Cognito encounters an internal error.</p>
     InternalError(String),
@@ -4791,6 +4950,8 @@ pub enum AdminEnableUserError {
     TooManyRequests(String),
     /// <p>This exception is thrown when a user is not found.</p>
     UserNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl AdminEnableUserError {
@@ -4798,25 +4959,34 @@ impl AdminEnableUserError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalErrorException" => {
-                    return RusotoError::Service(AdminEnableUserError::InternalError(err.msg))
+                    return RusotoError::service(AdminEnableUserError::InternalError(err.msg), &res)
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(AdminEnableUserError::InvalidParameter(err.msg))
+                    return RusotoError::service(
+                        AdminEnableUserError::InvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "NotAuthorizedException" => {
-                    return RusotoError::Service(AdminEnableUserError::NotAuthorized(err.msg))
+                    return RusotoError::service(AdminEnableUserError::NotAuthorized(err.msg), &res)
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(AdminEnableUserError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        AdminEnableUserError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(AdminEnableUserError::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        AdminEnableUserError::TooManyRequests(err.msg),
+                        &res,
+                    )
                 }
                 "UserNotFoundException" => {
-                    return RusotoError::Service(AdminEnableUserError::UserNotFound(err.msg))
+                    return RusotoError::service(AdminEnableUserError::UserNotFound(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(AdminEnableUserError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -4832,12 +5002,14 @@ impl fmt::Display for AdminEnableUserError {
             AdminEnableUserError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             AdminEnableUserError::TooManyRequests(ref cause) => write!(f, "{}", cause),
             AdminEnableUserError::UserNotFound(ref cause) => write!(f, "{}", cause),
+            AdminEnableUserError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for AdminEnableUserError {}
 /// Errors returned by AdminForgetDevice
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum AdminForgetDeviceError {
     /// <p>This exception is thrown when Amazon Cognito encounters an internal error.</p>
     InternalError(String),
@@ -4853,6 +5025,8 @@ pub enum AdminForgetDeviceError {
     TooManyRequests(String),
     /// <p>This exception is thrown when a user is not found.</p>
     UserNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl AdminForgetDeviceError {
@@ -4860,30 +5034,49 @@ impl AdminForgetDeviceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalErrorException" => {
-                    return RusotoError::Service(AdminForgetDeviceError::InternalError(err.msg))
+                    return RusotoError::service(
+                        AdminForgetDeviceError::InternalError(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(AdminForgetDeviceError::InvalidParameter(err.msg))
+                    return RusotoError::service(
+                        AdminForgetDeviceError::InvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidUserPoolConfigurationException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         AdminForgetDeviceError::InvalidUserPoolConfiguration(err.msg),
+                        &res,
                     )
                 }
                 "NotAuthorizedException" => {
-                    return RusotoError::Service(AdminForgetDeviceError::NotAuthorized(err.msg))
+                    return RusotoError::service(
+                        AdminForgetDeviceError::NotAuthorized(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(AdminForgetDeviceError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        AdminForgetDeviceError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(AdminForgetDeviceError::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        AdminForgetDeviceError::TooManyRequests(err.msg),
+                        &res,
+                    )
                 }
                 "UserNotFoundException" => {
-                    return RusotoError::Service(AdminForgetDeviceError::UserNotFound(err.msg))
+                    return RusotoError::service(
+                        AdminForgetDeviceError::UserNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(AdminForgetDeviceError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -4902,12 +5095,14 @@ impl fmt::Display for AdminForgetDeviceError {
             AdminForgetDeviceError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             AdminForgetDeviceError::TooManyRequests(ref cause) => write!(f, "{}", cause),
             AdminForgetDeviceError::UserNotFound(ref cause) => write!(f, "{}", cause),
+            AdminForgetDeviceError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for AdminForgetDeviceError {}
 /// Errors returned by AdminGetDevice
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum AdminGetDeviceError {
     /// <p>This exception is thrown when Amazon Cognito encounters an internal error.</p>
     InternalError(String),
@@ -4921,6 +5116,8 @@ pub enum AdminGetDeviceError {
     ResourceNotFound(String),
     /// <p>This exception is thrown when the user has made too many requests for a given operation.</p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl AdminGetDeviceError {
@@ -4928,27 +5125,37 @@ impl AdminGetDeviceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalErrorException" => {
-                    return RusotoError::Service(AdminGetDeviceError::InternalError(err.msg))
+                    return RusotoError::service(AdminGetDeviceError::InternalError(err.msg), &res)
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(AdminGetDeviceError::InvalidParameter(err.msg))
+                    return RusotoError::service(
+                        AdminGetDeviceError::InvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidUserPoolConfigurationException" => {
-                    return RusotoError::Service(AdminGetDeviceError::InvalidUserPoolConfiguration(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        AdminGetDeviceError::InvalidUserPoolConfiguration(err.msg),
+                        &res,
+                    )
                 }
                 "NotAuthorizedException" => {
-                    return RusotoError::Service(AdminGetDeviceError::NotAuthorized(err.msg))
+                    return RusotoError::service(AdminGetDeviceError::NotAuthorized(err.msg), &res)
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(AdminGetDeviceError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        AdminGetDeviceError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(AdminGetDeviceError::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        AdminGetDeviceError::TooManyRequests(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(AdminGetDeviceError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -4964,12 +5171,14 @@ impl fmt::Display for AdminGetDeviceError {
             AdminGetDeviceError::NotAuthorized(ref cause) => write!(f, "{}", cause),
             AdminGetDeviceError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             AdminGetDeviceError::TooManyRequests(ref cause) => write!(f, "{}", cause),
+            AdminGetDeviceError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for AdminGetDeviceError {}
 /// Errors returned by AdminGetUser
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum AdminGetUserError {
     /// <p>This exception is thrown when Amazon Cognito encounters an internal error.</p>
     InternalError(String),
@@ -4983,6 +5192,8 @@ pub enum AdminGetUserError {
     TooManyRequests(String),
     /// <p>This exception is thrown when a user is not found.</p>
     UserNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl AdminGetUserError {
@@ -4990,25 +5201,25 @@ impl AdminGetUserError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalErrorException" => {
-                    return RusotoError::Service(AdminGetUserError::InternalError(err.msg))
+                    return RusotoError::service(AdminGetUserError::InternalError(err.msg), &res)
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(AdminGetUserError::InvalidParameter(err.msg))
+                    return RusotoError::service(AdminGetUserError::InvalidParameter(err.msg), &res)
                 }
                 "NotAuthorizedException" => {
-                    return RusotoError::Service(AdminGetUserError::NotAuthorized(err.msg))
+                    return RusotoError::service(AdminGetUserError::NotAuthorized(err.msg), &res)
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(AdminGetUserError::ResourceNotFound(err.msg))
+                    return RusotoError::service(AdminGetUserError::ResourceNotFound(err.msg), &res)
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(AdminGetUserError::TooManyRequests(err.msg))
+                    return RusotoError::service(AdminGetUserError::TooManyRequests(err.msg), &res)
                 }
                 "UserNotFoundException" => {
-                    return RusotoError::Service(AdminGetUserError::UserNotFound(err.msg))
+                    return RusotoError::service(AdminGetUserError::UserNotFound(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(AdminGetUserError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -5024,12 +5235,14 @@ impl fmt::Display for AdminGetUserError {
             AdminGetUserError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             AdminGetUserError::TooManyRequests(ref cause) => write!(f, "{}", cause),
             AdminGetUserError::UserNotFound(ref cause) => write!(f, "{}", cause),
+            AdminGetUserError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for AdminGetUserError {}
 /// Errors returned by AdminInitiateAuth
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum AdminInitiateAuthError {
     /// <p>This exception is thrown when Amazon Cognito encounters an internal error.</p>
     InternalError(String),
@@ -5061,6 +5274,8 @@ pub enum AdminInitiateAuthError {
     UserNotConfirmed(String),
     /// <p>This exception is thrown when a user is not found.</p>
     UserNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl AdminInitiateAuthError {
@@ -5068,64 +5283,97 @@ impl AdminInitiateAuthError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalErrorException" => {
-                    return RusotoError::Service(AdminInitiateAuthError::InternalError(err.msg))
+                    return RusotoError::service(
+                        AdminInitiateAuthError::InternalError(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidLambdaResponseException" => {
-                    return RusotoError::Service(AdminInitiateAuthError::InvalidLambdaResponse(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        AdminInitiateAuthError::InvalidLambdaResponse(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(AdminInitiateAuthError::InvalidParameter(err.msg))
+                    return RusotoError::service(
+                        AdminInitiateAuthError::InvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidSmsRoleAccessPolicyException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         AdminInitiateAuthError::InvalidSmsRoleAccessPolicy(err.msg),
+                        &res,
                     )
                 }
                 "InvalidSmsRoleTrustRelationshipException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         AdminInitiateAuthError::InvalidSmsRoleTrustRelationship(err.msg),
+                        &res,
                     )
                 }
                 "InvalidUserPoolConfigurationException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         AdminInitiateAuthError::InvalidUserPoolConfiguration(err.msg),
+                        &res,
                     )
                 }
                 "MFAMethodNotFoundException" => {
-                    return RusotoError::Service(AdminInitiateAuthError::MFAMethodNotFound(err.msg))
+                    return RusotoError::service(
+                        AdminInitiateAuthError::MFAMethodNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "NotAuthorizedException" => {
-                    return RusotoError::Service(AdminInitiateAuthError::NotAuthorized(err.msg))
+                    return RusotoError::service(
+                        AdminInitiateAuthError::NotAuthorized(err.msg),
+                        &res,
+                    )
                 }
                 "PasswordResetRequiredException" => {
-                    return RusotoError::Service(AdminInitiateAuthError::PasswordResetRequired(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        AdminInitiateAuthError::PasswordResetRequired(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(AdminInitiateAuthError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        AdminInitiateAuthError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(AdminInitiateAuthError::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        AdminInitiateAuthError::TooManyRequests(err.msg),
+                        &res,
+                    )
                 }
                 "UnexpectedLambdaException" => {
-                    return RusotoError::Service(AdminInitiateAuthError::UnexpectedLambda(err.msg))
+                    return RusotoError::service(
+                        AdminInitiateAuthError::UnexpectedLambda(err.msg),
+                        &res,
+                    )
                 }
                 "UserLambdaValidationException" => {
-                    return RusotoError::Service(AdminInitiateAuthError::UserLambdaValidation(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        AdminInitiateAuthError::UserLambdaValidation(err.msg),
+                        &res,
+                    )
                 }
                 "UserNotConfirmedException" => {
-                    return RusotoError::Service(AdminInitiateAuthError::UserNotConfirmed(err.msg))
+                    return RusotoError::service(
+                        AdminInitiateAuthError::UserNotConfirmed(err.msg),
+                        &res,
+                    )
                 }
                 "UserNotFoundException" => {
-                    return RusotoError::Service(AdminInitiateAuthError::UserNotFound(err.msg))
+                    return RusotoError::service(
+                        AdminInitiateAuthError::UserNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(AdminInitiateAuthError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -5154,12 +5402,14 @@ impl fmt::Display for AdminInitiateAuthError {
             AdminInitiateAuthError::UserLambdaValidation(ref cause) => write!(f, "{}", cause),
             AdminInitiateAuthError::UserNotConfirmed(ref cause) => write!(f, "{}", cause),
             AdminInitiateAuthError::UserNotFound(ref cause) => write!(f, "{}", cause),
+            AdminInitiateAuthError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for AdminInitiateAuthError {}
 /// Errors returned by AdminLinkProviderForUser
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum AdminLinkProviderForUserError {
     /// <p>This exception is thrown when a user tries to confirm the account with an email or phone number that has already been supplied as an alias from a different account. This exception tells user that an account with this email or phone already exists.</p>
     AliasExists(String),
@@ -5175,6 +5425,8 @@ pub enum AdminLinkProviderForUserError {
     TooManyRequests(String),
     /// <p>This exception is thrown when a user is not found.</p>
     UserNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl AdminLinkProviderForUserError {
@@ -5182,42 +5434,49 @@ impl AdminLinkProviderForUserError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AliasExistsException" => {
-                    return RusotoError::Service(AdminLinkProviderForUserError::AliasExists(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        AdminLinkProviderForUserError::AliasExists(err.msg),
+                        &res,
+                    )
                 }
                 "InternalErrorException" => {
-                    return RusotoError::Service(AdminLinkProviderForUserError::InternalError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        AdminLinkProviderForUserError::InternalError(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(AdminLinkProviderForUserError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        AdminLinkProviderForUserError::InvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "NotAuthorizedException" => {
-                    return RusotoError::Service(AdminLinkProviderForUserError::NotAuthorized(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        AdminLinkProviderForUserError::NotAuthorized(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(AdminLinkProviderForUserError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        AdminLinkProviderForUserError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(AdminLinkProviderForUserError::TooManyRequests(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        AdminLinkProviderForUserError::TooManyRequests(err.msg),
+                        &res,
+                    )
                 }
                 "UserNotFoundException" => {
-                    return RusotoError::Service(AdminLinkProviderForUserError::UserNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        AdminLinkProviderForUserError::UserNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(AdminLinkProviderForUserError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -5234,12 +5493,14 @@ impl fmt::Display for AdminLinkProviderForUserError {
             AdminLinkProviderForUserError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             AdminLinkProviderForUserError::TooManyRequests(ref cause) => write!(f, "{}", cause),
             AdminLinkProviderForUserError::UserNotFound(ref cause) => write!(f, "{}", cause),
+            AdminLinkProviderForUserError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for AdminLinkProviderForUserError {}
 /// Errors returned by AdminListDevices
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum AdminListDevicesError {
     /// <p>This exception is thrown when Amazon Cognito encounters an internal error.</p>
     InternalError(String),
@@ -5253,6 +5514,8 @@ pub enum AdminListDevicesError {
     ResourceNotFound(String),
     /// <p>This exception is thrown when the user has made too many requests for a given operation.</p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl AdminListDevicesError {
@@ -5260,27 +5523,43 @@ impl AdminListDevicesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalErrorException" => {
-                    return RusotoError::Service(AdminListDevicesError::InternalError(err.msg))
+                    return RusotoError::service(
+                        AdminListDevicesError::InternalError(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(AdminListDevicesError::InvalidParameter(err.msg))
+                    return RusotoError::service(
+                        AdminListDevicesError::InvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidUserPoolConfigurationException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         AdminListDevicesError::InvalidUserPoolConfiguration(err.msg),
+                        &res,
                     )
                 }
                 "NotAuthorizedException" => {
-                    return RusotoError::Service(AdminListDevicesError::NotAuthorized(err.msg))
+                    return RusotoError::service(
+                        AdminListDevicesError::NotAuthorized(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(AdminListDevicesError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        AdminListDevicesError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(AdminListDevicesError::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        AdminListDevicesError::TooManyRequests(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(AdminListDevicesError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -5298,12 +5577,14 @@ impl fmt::Display for AdminListDevicesError {
             AdminListDevicesError::NotAuthorized(ref cause) => write!(f, "{}", cause),
             AdminListDevicesError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             AdminListDevicesError::TooManyRequests(ref cause) => write!(f, "{}", cause),
+            AdminListDevicesError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for AdminListDevicesError {}
 /// Errors returned by AdminListGroupsForUser
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum AdminListGroupsForUserError {
     /// <p>This exception is thrown when Amazon Cognito encounters an internal error.</p>
     InternalError(String),
@@ -5317,6 +5598,8 @@ pub enum AdminListGroupsForUserError {
     TooManyRequests(String),
     /// <p>This exception is thrown when a user is not found.</p>
     UserNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl AdminListGroupsForUserError {
@@ -5324,35 +5607,43 @@ impl AdminListGroupsForUserError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalErrorException" => {
-                    return RusotoError::Service(AdminListGroupsForUserError::InternalError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        AdminListGroupsForUserError::InternalError(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(AdminListGroupsForUserError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        AdminListGroupsForUserError::InvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "NotAuthorizedException" => {
-                    return RusotoError::Service(AdminListGroupsForUserError::NotAuthorized(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        AdminListGroupsForUserError::NotAuthorized(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(AdminListGroupsForUserError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        AdminListGroupsForUserError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(AdminListGroupsForUserError::TooManyRequests(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        AdminListGroupsForUserError::TooManyRequests(err.msg),
+                        &res,
+                    )
                 }
                 "UserNotFoundException" => {
-                    return RusotoError::Service(AdminListGroupsForUserError::UserNotFound(err.msg))
+                    return RusotoError::service(
+                        AdminListGroupsForUserError::UserNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(AdminListGroupsForUserError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -5368,12 +5659,14 @@ impl fmt::Display for AdminListGroupsForUserError {
             AdminListGroupsForUserError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             AdminListGroupsForUserError::TooManyRequests(ref cause) => write!(f, "{}", cause),
             AdminListGroupsForUserError::UserNotFound(ref cause) => write!(f, "{}", cause),
+            AdminListGroupsForUserError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for AdminListGroupsForUserError {}
 /// Errors returned by AdminListUserAuthEvents
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum AdminListUserAuthEventsError {
     /// <p>This exception is thrown when Amazon Cognito encounters an internal error.</p>
     InternalError(String),
@@ -5389,6 +5682,8 @@ pub enum AdminListUserAuthEventsError {
     UserNotFound(String),
     /// <p>This exception is thrown when user pool add-ons are not enabled.</p>
     UserPoolAddOnNotEnabled(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl AdminListUserAuthEventsError {
@@ -5396,42 +5691,49 @@ impl AdminListUserAuthEventsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalErrorException" => {
-                    return RusotoError::Service(AdminListUserAuthEventsError::InternalError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        AdminListUserAuthEventsError::InternalError(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(AdminListUserAuthEventsError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        AdminListUserAuthEventsError::InvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "NotAuthorizedException" => {
-                    return RusotoError::Service(AdminListUserAuthEventsError::NotAuthorized(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        AdminListUserAuthEventsError::NotAuthorized(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(AdminListUserAuthEventsError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        AdminListUserAuthEventsError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(AdminListUserAuthEventsError::TooManyRequests(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        AdminListUserAuthEventsError::TooManyRequests(err.msg),
+                        &res,
+                    )
                 }
                 "UserNotFoundException" => {
-                    return RusotoError::Service(AdminListUserAuthEventsError::UserNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        AdminListUserAuthEventsError::UserNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "UserPoolAddOnNotEnabledException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         AdminListUserAuthEventsError::UserPoolAddOnNotEnabled(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(AdminListUserAuthEventsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -5450,12 +5752,14 @@ impl fmt::Display for AdminListUserAuthEventsError {
             AdminListUserAuthEventsError::UserPoolAddOnNotEnabled(ref cause) => {
                 write!(f, "{}", cause)
             }
+            AdminListUserAuthEventsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for AdminListUserAuthEventsError {}
 /// Errors returned by AdminRemoveUserFromGroup
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum AdminRemoveUserFromGroupError {
     /// <p>This exception is thrown when Amazon Cognito encounters an internal error.</p>
     InternalError(String),
@@ -5469,6 +5773,8 @@ pub enum AdminRemoveUserFromGroupError {
     TooManyRequests(String),
     /// <p>This exception is thrown when a user is not found.</p>
     UserNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl AdminRemoveUserFromGroupError {
@@ -5476,37 +5782,43 @@ impl AdminRemoveUserFromGroupError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalErrorException" => {
-                    return RusotoError::Service(AdminRemoveUserFromGroupError::InternalError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        AdminRemoveUserFromGroupError::InternalError(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(AdminRemoveUserFromGroupError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        AdminRemoveUserFromGroupError::InvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "NotAuthorizedException" => {
-                    return RusotoError::Service(AdminRemoveUserFromGroupError::NotAuthorized(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        AdminRemoveUserFromGroupError::NotAuthorized(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(AdminRemoveUserFromGroupError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        AdminRemoveUserFromGroupError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(AdminRemoveUserFromGroupError::TooManyRequests(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        AdminRemoveUserFromGroupError::TooManyRequests(err.msg),
+                        &res,
+                    )
                 }
                 "UserNotFoundException" => {
-                    return RusotoError::Service(AdminRemoveUserFromGroupError::UserNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        AdminRemoveUserFromGroupError::UserNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(AdminRemoveUserFromGroupError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -5522,12 +5834,14 @@ impl fmt::Display for AdminRemoveUserFromGroupError {
             AdminRemoveUserFromGroupError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             AdminRemoveUserFromGroupError::TooManyRequests(ref cause) => write!(f, "{}", cause),
             AdminRemoveUserFromGroupError::UserNotFound(ref cause) => write!(f, "{}", cause),
+            AdminRemoveUserFromGroupError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for AdminRemoveUserFromGroupError {}
 /// Errors returned by AdminResetUserPassword
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum AdminResetUserPasswordError {
     /// <p>This exception is thrown when Amazon Cognito encounters an internal error.</p>
     InternalError(String),
@@ -5555,6 +5869,8 @@ pub enum AdminResetUserPasswordError {
     UserLambdaValidation(String),
     /// <p>This exception is thrown when a user is not found.</p>
     UserNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl AdminResetUserPasswordError {
@@ -5562,70 +5878,85 @@ impl AdminResetUserPasswordError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalErrorException" => {
-                    return RusotoError::Service(AdminResetUserPasswordError::InternalError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        AdminResetUserPasswordError::InternalError(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidEmailRoleAccessPolicyException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         AdminResetUserPasswordError::InvalidEmailRoleAccessPolicy(err.msg),
+                        &res,
                     )
                 }
                 "InvalidLambdaResponseException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         AdminResetUserPasswordError::InvalidLambdaResponse(err.msg),
+                        &res,
                     )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(AdminResetUserPasswordError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        AdminResetUserPasswordError::InvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidSmsRoleAccessPolicyException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         AdminResetUserPasswordError::InvalidSmsRoleAccessPolicy(err.msg),
+                        &res,
                     )
                 }
                 "InvalidSmsRoleTrustRelationshipException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         AdminResetUserPasswordError::InvalidSmsRoleTrustRelationship(err.msg),
+                        &res,
                     )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(AdminResetUserPasswordError::LimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        AdminResetUserPasswordError::LimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "NotAuthorizedException" => {
-                    return RusotoError::Service(AdminResetUserPasswordError::NotAuthorized(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        AdminResetUserPasswordError::NotAuthorized(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(AdminResetUserPasswordError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        AdminResetUserPasswordError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(AdminResetUserPasswordError::TooManyRequests(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        AdminResetUserPasswordError::TooManyRequests(err.msg),
+                        &res,
+                    )
                 }
                 "UnexpectedLambdaException" => {
-                    return RusotoError::Service(AdminResetUserPasswordError::UnexpectedLambda(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        AdminResetUserPasswordError::UnexpectedLambda(err.msg),
+                        &res,
+                    )
                 }
                 "UserLambdaValidationException" => {
-                    return RusotoError::Service(AdminResetUserPasswordError::UserLambdaValidation(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        AdminResetUserPasswordError::UserLambdaValidation(err.msg),
+                        &res,
+                    )
                 }
                 "UserNotFoundException" => {
-                    return RusotoError::Service(AdminResetUserPasswordError::UserNotFound(err.msg))
+                    return RusotoError::service(
+                        AdminResetUserPasswordError::UserNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(AdminResetUserPasswordError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -5654,12 +5985,14 @@ impl fmt::Display for AdminResetUserPasswordError {
             AdminResetUserPasswordError::UnexpectedLambda(ref cause) => write!(f, "{}", cause),
             AdminResetUserPasswordError::UserLambdaValidation(ref cause) => write!(f, "{}", cause),
             AdminResetUserPasswordError::UserNotFound(ref cause) => write!(f, "{}", cause),
+            AdminResetUserPasswordError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for AdminResetUserPasswordError {}
 /// Errors returned by AdminRespondToAuthChallenge
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum AdminRespondToAuthChallengeError {
     /// <p>This exception is thrown when a user tries to confirm the account with an email or phone number that has already been supplied as an alias from a different account. This exception tells user that an account with this email or phone already exists.</p>
     AliasExists(String),
@@ -5701,6 +6034,8 @@ pub enum AdminRespondToAuthChallengeError {
     UserNotConfirmed(String),
     /// <p>This exception is thrown when a user is not found.</p>
     UserNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl AdminRespondToAuthChallengeError {
@@ -5710,107 +6045,132 @@ impl AdminRespondToAuthChallengeError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AliasExistsException" => {
-                    return RusotoError::Service(AdminRespondToAuthChallengeError::AliasExists(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        AdminRespondToAuthChallengeError::AliasExists(err.msg),
+                        &res,
+                    )
                 }
                 "CodeMismatchException" => {
-                    return RusotoError::Service(AdminRespondToAuthChallengeError::CodeMismatch(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        AdminRespondToAuthChallengeError::CodeMismatch(err.msg),
+                        &res,
+                    )
                 }
                 "ExpiredCodeException" => {
-                    return RusotoError::Service(AdminRespondToAuthChallengeError::ExpiredCode(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        AdminRespondToAuthChallengeError::ExpiredCode(err.msg),
+                        &res,
+                    )
                 }
                 "InternalErrorException" => {
-                    return RusotoError::Service(AdminRespondToAuthChallengeError::InternalError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        AdminRespondToAuthChallengeError::InternalError(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidLambdaResponseException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         AdminRespondToAuthChallengeError::InvalidLambdaResponse(err.msg),
+                        &res,
                     )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         AdminRespondToAuthChallengeError::InvalidParameter(err.msg),
+                        &res,
                     )
                 }
                 "InvalidPasswordException" => {
-                    return RusotoError::Service(AdminRespondToAuthChallengeError::InvalidPassword(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        AdminRespondToAuthChallengeError::InvalidPassword(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidSmsRoleAccessPolicyException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         AdminRespondToAuthChallengeError::InvalidSmsRoleAccessPolicy(err.msg),
+                        &res,
                     )
                 }
                 "InvalidSmsRoleTrustRelationshipException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         AdminRespondToAuthChallengeError::InvalidSmsRoleTrustRelationship(err.msg),
+                        &res,
                     )
                 }
                 "InvalidUserPoolConfigurationException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         AdminRespondToAuthChallengeError::InvalidUserPoolConfiguration(err.msg),
+                        &res,
                     )
                 }
                 "MFAMethodNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         AdminRespondToAuthChallengeError::MFAMethodNotFound(err.msg),
+                        &res,
                     )
                 }
                 "NotAuthorizedException" => {
-                    return RusotoError::Service(AdminRespondToAuthChallengeError::NotAuthorized(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        AdminRespondToAuthChallengeError::NotAuthorized(err.msg),
+                        &res,
+                    )
                 }
                 "PasswordResetRequiredException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         AdminRespondToAuthChallengeError::PasswordResetRequired(err.msg),
+                        &res,
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         AdminRespondToAuthChallengeError::ResourceNotFound(err.msg),
+                        &res,
                     )
                 }
                 "SoftwareTokenMFANotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         AdminRespondToAuthChallengeError::SoftwareTokenMFANotFound(err.msg),
+                        &res,
                     )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(AdminRespondToAuthChallengeError::TooManyRequests(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        AdminRespondToAuthChallengeError::TooManyRequests(err.msg),
+                        &res,
+                    )
                 }
                 "UnexpectedLambdaException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         AdminRespondToAuthChallengeError::UnexpectedLambda(err.msg),
+                        &res,
                     )
                 }
                 "UserLambdaValidationException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         AdminRespondToAuthChallengeError::UserLambdaValidation(err.msg),
+                        &res,
                     )
                 }
                 "UserNotConfirmedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         AdminRespondToAuthChallengeError::UserNotConfirmed(err.msg),
+                        &res,
                     )
                 }
                 "UserNotFoundException" => {
-                    return RusotoError::Service(AdminRespondToAuthChallengeError::UserNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        AdminRespondToAuthChallengeError::UserNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        AdminRespondToAuthChallengeError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -5856,12 +6216,14 @@ impl fmt::Display for AdminRespondToAuthChallengeError {
             }
             AdminRespondToAuthChallengeError::UserNotConfirmed(ref cause) => write!(f, "{}", cause),
             AdminRespondToAuthChallengeError::UserNotFound(ref cause) => write!(f, "{}", cause),
+            AdminRespondToAuthChallengeError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for AdminRespondToAuthChallengeError {}
 /// Errors returned by AdminSetUserMFAPreference
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum AdminSetUserMFAPreferenceError {
     /// <p>This exception is thrown when Amazon Cognito encounters an internal error.</p>
     InternalError(String),
@@ -5877,6 +6239,8 @@ pub enum AdminSetUserMFAPreferenceError {
     UserNotConfirmed(String),
     /// <p>This exception is thrown when a user is not found.</p>
     UserNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl AdminSetUserMFAPreferenceError {
@@ -5884,42 +6248,51 @@ impl AdminSetUserMFAPreferenceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalErrorException" => {
-                    return RusotoError::Service(AdminSetUserMFAPreferenceError::InternalError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        AdminSetUserMFAPreferenceError::InternalError(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(AdminSetUserMFAPreferenceError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        AdminSetUserMFAPreferenceError::InvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "NotAuthorizedException" => {
-                    return RusotoError::Service(AdminSetUserMFAPreferenceError::NotAuthorized(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        AdminSetUserMFAPreferenceError::NotAuthorized(err.msg),
+                        &res,
+                    )
                 }
                 "PasswordResetRequiredException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         AdminSetUserMFAPreferenceError::PasswordResetRequired(err.msg),
+                        &res,
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(AdminSetUserMFAPreferenceError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        AdminSetUserMFAPreferenceError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "UserNotConfirmedException" => {
-                    return RusotoError::Service(AdminSetUserMFAPreferenceError::UserNotConfirmed(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        AdminSetUserMFAPreferenceError::UserNotConfirmed(err.msg),
+                        &res,
+                    )
                 }
                 "UserNotFoundException" => {
-                    return RusotoError::Service(AdminSetUserMFAPreferenceError::UserNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        AdminSetUserMFAPreferenceError::UserNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(AdminSetUserMFAPreferenceError::Unhandled, &res)
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -5938,12 +6311,14 @@ impl fmt::Display for AdminSetUserMFAPreferenceError {
             AdminSetUserMFAPreferenceError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             AdminSetUserMFAPreferenceError::UserNotConfirmed(ref cause) => write!(f, "{}", cause),
             AdminSetUserMFAPreferenceError::UserNotFound(ref cause) => write!(f, "{}", cause),
+            AdminSetUserMFAPreferenceError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for AdminSetUserMFAPreferenceError {}
 /// Errors returned by AdminSetUserPassword
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum AdminSetUserPasswordError {
     /// <p>This exception is thrown when Amazon Cognito encounters an internal error.</p>
     InternalError(String),
@@ -5959,6 +6334,8 @@ pub enum AdminSetUserPasswordError {
     TooManyRequests(String),
     /// <p>This exception is thrown when a user is not found.</p>
     UserNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl AdminSetUserPasswordError {
@@ -5966,36 +6343,49 @@ impl AdminSetUserPasswordError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalErrorException" => {
-                    return RusotoError::Service(AdminSetUserPasswordError::InternalError(err.msg))
+                    return RusotoError::service(
+                        AdminSetUserPasswordError::InternalError(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(AdminSetUserPasswordError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        AdminSetUserPasswordError::InvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidPasswordException" => {
-                    return RusotoError::Service(AdminSetUserPasswordError::InvalidPassword(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        AdminSetUserPasswordError::InvalidPassword(err.msg),
+                        &res,
+                    )
                 }
                 "NotAuthorizedException" => {
-                    return RusotoError::Service(AdminSetUserPasswordError::NotAuthorized(err.msg))
+                    return RusotoError::service(
+                        AdminSetUserPasswordError::NotAuthorized(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(AdminSetUserPasswordError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        AdminSetUserPasswordError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(AdminSetUserPasswordError::TooManyRequests(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        AdminSetUserPasswordError::TooManyRequests(err.msg),
+                        &res,
+                    )
                 }
                 "UserNotFoundException" => {
-                    return RusotoError::Service(AdminSetUserPasswordError::UserNotFound(err.msg))
+                    return RusotoError::service(
+                        AdminSetUserPasswordError::UserNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(AdminSetUserPasswordError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -6012,12 +6402,14 @@ impl fmt::Display for AdminSetUserPasswordError {
             AdminSetUserPasswordError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             AdminSetUserPasswordError::TooManyRequests(ref cause) => write!(f, "{}", cause),
             AdminSetUserPasswordError::UserNotFound(ref cause) => write!(f, "{}", cause),
+            AdminSetUserPasswordError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for AdminSetUserPasswordError {}
 /// Errors returned by AdminSetUserSettings
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum AdminSetUserSettingsError {
     /// <p>This exception is thrown when Amazon Cognito encounters an internal error.</p>
     InternalError(String),
@@ -6029,6 +6421,8 @@ pub enum AdminSetUserSettingsError {
     ResourceNotFound(String),
     /// <p>This exception is thrown when a user is not found.</p>
     UserNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl AdminSetUserSettingsError {
@@ -6036,26 +6430,37 @@ impl AdminSetUserSettingsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalErrorException" => {
-                    return RusotoError::Service(AdminSetUserSettingsError::InternalError(err.msg))
+                    return RusotoError::service(
+                        AdminSetUserSettingsError::InternalError(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(AdminSetUserSettingsError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        AdminSetUserSettingsError::InvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "NotAuthorizedException" => {
-                    return RusotoError::Service(AdminSetUserSettingsError::NotAuthorized(err.msg))
+                    return RusotoError::service(
+                        AdminSetUserSettingsError::NotAuthorized(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(AdminSetUserSettingsError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        AdminSetUserSettingsError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "UserNotFoundException" => {
-                    return RusotoError::Service(AdminSetUserSettingsError::UserNotFound(err.msg))
+                    return RusotoError::service(
+                        AdminSetUserSettingsError::UserNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(AdminSetUserSettingsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -6070,12 +6475,14 @@ impl fmt::Display for AdminSetUserSettingsError {
             AdminSetUserSettingsError::NotAuthorized(ref cause) => write!(f, "{}", cause),
             AdminSetUserSettingsError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             AdminSetUserSettingsError::UserNotFound(ref cause) => write!(f, "{}", cause),
+            AdminSetUserSettingsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for AdminSetUserSettingsError {}
 /// Errors returned by AdminUpdateAuthEventFeedback
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum AdminUpdateAuthEventFeedbackError {
     /// <p>This exception is thrown when Amazon Cognito encounters an internal error.</p>
     InternalError(String),
@@ -6091,6 +6498,8 @@ pub enum AdminUpdateAuthEventFeedbackError {
     UserNotFound(String),
     /// <p>This exception is thrown when user pool add-ons are not enabled.</p>
     UserPoolAddOnNotEnabled(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl AdminUpdateAuthEventFeedbackError {
@@ -6100,42 +6509,54 @@ impl AdminUpdateAuthEventFeedbackError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalErrorException" => {
-                    return RusotoError::Service(AdminUpdateAuthEventFeedbackError::InternalError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        AdminUpdateAuthEventFeedbackError::InternalError(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         AdminUpdateAuthEventFeedbackError::InvalidParameter(err.msg),
+                        &res,
                     )
                 }
                 "NotAuthorizedException" => {
-                    return RusotoError::Service(AdminUpdateAuthEventFeedbackError::NotAuthorized(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        AdminUpdateAuthEventFeedbackError::NotAuthorized(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         AdminUpdateAuthEventFeedbackError::ResourceNotFound(err.msg),
+                        &res,
                     )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         AdminUpdateAuthEventFeedbackError::TooManyRequests(err.msg),
+                        &res,
                     )
                 }
                 "UserNotFoundException" => {
-                    return RusotoError::Service(AdminUpdateAuthEventFeedbackError::UserNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        AdminUpdateAuthEventFeedbackError::UserNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "UserPoolAddOnNotEnabledException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         AdminUpdateAuthEventFeedbackError::UserPoolAddOnNotEnabled(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        AdminUpdateAuthEventFeedbackError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -6158,12 +6579,14 @@ impl fmt::Display for AdminUpdateAuthEventFeedbackError {
             AdminUpdateAuthEventFeedbackError::UserPoolAddOnNotEnabled(ref cause) => {
                 write!(f, "{}", cause)
             }
+            AdminUpdateAuthEventFeedbackError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for AdminUpdateAuthEventFeedbackError {}
 /// Errors returned by AdminUpdateDeviceStatus
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum AdminUpdateDeviceStatusError {
     /// <p>This exception is thrown when Amazon Cognito encounters an internal error.</p>
     InternalError(String),
@@ -6179,6 +6602,8 @@ pub enum AdminUpdateDeviceStatusError {
     TooManyRequests(String),
     /// <p>This exception is thrown when a user is not found.</p>
     UserNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl AdminUpdateDeviceStatusError {
@@ -6186,42 +6611,49 @@ impl AdminUpdateDeviceStatusError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalErrorException" => {
-                    return RusotoError::Service(AdminUpdateDeviceStatusError::InternalError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        AdminUpdateDeviceStatusError::InternalError(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(AdminUpdateDeviceStatusError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        AdminUpdateDeviceStatusError::InvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidUserPoolConfigurationException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         AdminUpdateDeviceStatusError::InvalidUserPoolConfiguration(err.msg),
+                        &res,
                     )
                 }
                 "NotAuthorizedException" => {
-                    return RusotoError::Service(AdminUpdateDeviceStatusError::NotAuthorized(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        AdminUpdateDeviceStatusError::NotAuthorized(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(AdminUpdateDeviceStatusError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        AdminUpdateDeviceStatusError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(AdminUpdateDeviceStatusError::TooManyRequests(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        AdminUpdateDeviceStatusError::TooManyRequests(err.msg),
+                        &res,
+                    )
                 }
                 "UserNotFoundException" => {
-                    return RusotoError::Service(AdminUpdateDeviceStatusError::UserNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        AdminUpdateDeviceStatusError::UserNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(AdminUpdateDeviceStatusError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -6240,12 +6672,14 @@ impl fmt::Display for AdminUpdateDeviceStatusError {
             AdminUpdateDeviceStatusError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             AdminUpdateDeviceStatusError::TooManyRequests(ref cause) => write!(f, "{}", cause),
             AdminUpdateDeviceStatusError::UserNotFound(ref cause) => write!(f, "{}", cause),
+            AdminUpdateDeviceStatusError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for AdminUpdateDeviceStatusError {}
 /// Errors returned by AdminUpdateUserAttributes
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum AdminUpdateUserAttributesError {
     /// <p>This exception is thrown when a user tries to confirm the account with an email or phone number that has already been supplied as an alias from a different account. This exception tells user that an account with this email or phone already exists.</p>
     AliasExists(String),
@@ -6273,6 +6707,8 @@ pub enum AdminUpdateUserAttributesError {
     UserLambdaValidation(String),
     /// <p>This exception is thrown when a user is not found.</p>
     UserNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl AdminUpdateUserAttributesError {
@@ -6280,72 +6716,87 @@ impl AdminUpdateUserAttributesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AliasExistsException" => {
-                    return RusotoError::Service(AdminUpdateUserAttributesError::AliasExists(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        AdminUpdateUserAttributesError::AliasExists(err.msg),
+                        &res,
+                    )
                 }
                 "InternalErrorException" => {
-                    return RusotoError::Service(AdminUpdateUserAttributesError::InternalError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        AdminUpdateUserAttributesError::InternalError(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidEmailRoleAccessPolicyException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         AdminUpdateUserAttributesError::InvalidEmailRoleAccessPolicy(err.msg),
+                        &res,
                     )
                 }
                 "InvalidLambdaResponseException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         AdminUpdateUserAttributesError::InvalidLambdaResponse(err.msg),
+                        &res,
                     )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(AdminUpdateUserAttributesError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        AdminUpdateUserAttributesError::InvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidSmsRoleAccessPolicyException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         AdminUpdateUserAttributesError::InvalidSmsRoleAccessPolicy(err.msg),
+                        &res,
                     )
                 }
                 "InvalidSmsRoleTrustRelationshipException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         AdminUpdateUserAttributesError::InvalidSmsRoleTrustRelationship(err.msg),
+                        &res,
                     )
                 }
                 "NotAuthorizedException" => {
-                    return RusotoError::Service(AdminUpdateUserAttributesError::NotAuthorized(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        AdminUpdateUserAttributesError::NotAuthorized(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(AdminUpdateUserAttributesError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        AdminUpdateUserAttributesError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(AdminUpdateUserAttributesError::TooManyRequests(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        AdminUpdateUserAttributesError::TooManyRequests(err.msg),
+                        &res,
+                    )
                 }
                 "UnexpectedLambdaException" => {
-                    return RusotoError::Service(AdminUpdateUserAttributesError::UnexpectedLambda(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        AdminUpdateUserAttributesError::UnexpectedLambda(err.msg),
+                        &res,
+                    )
                 }
                 "UserLambdaValidationException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         AdminUpdateUserAttributesError::UserLambdaValidation(err.msg),
+                        &res,
                     )
                 }
                 "UserNotFoundException" => {
-                    return RusotoError::Service(AdminUpdateUserAttributesError::UserNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        AdminUpdateUserAttributesError::UserNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(AdminUpdateUserAttributesError::Unhandled, &res)
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -6378,12 +6829,14 @@ impl fmt::Display for AdminUpdateUserAttributesError {
                 write!(f, "{}", cause)
             }
             AdminUpdateUserAttributesError::UserNotFound(ref cause) => write!(f, "{}", cause),
+            AdminUpdateUserAttributesError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for AdminUpdateUserAttributesError {}
 /// Errors returned by AdminUserGlobalSignOut
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum AdminUserGlobalSignOutError {
     /// <p>This exception is thrown when Amazon Cognito encounters an internal error.</p>
     InternalError(String),
@@ -6397,6 +6850,8 @@ pub enum AdminUserGlobalSignOutError {
     TooManyRequests(String),
     /// <p>This exception is thrown when a user is not found.</p>
     UserNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl AdminUserGlobalSignOutError {
@@ -6404,35 +6859,43 @@ impl AdminUserGlobalSignOutError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalErrorException" => {
-                    return RusotoError::Service(AdminUserGlobalSignOutError::InternalError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        AdminUserGlobalSignOutError::InternalError(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(AdminUserGlobalSignOutError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        AdminUserGlobalSignOutError::InvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "NotAuthorizedException" => {
-                    return RusotoError::Service(AdminUserGlobalSignOutError::NotAuthorized(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        AdminUserGlobalSignOutError::NotAuthorized(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(AdminUserGlobalSignOutError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        AdminUserGlobalSignOutError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(AdminUserGlobalSignOutError::TooManyRequests(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        AdminUserGlobalSignOutError::TooManyRequests(err.msg),
+                        &res,
+                    )
                 }
                 "UserNotFoundException" => {
-                    return RusotoError::Service(AdminUserGlobalSignOutError::UserNotFound(err.msg))
+                    return RusotoError::service(
+                        AdminUserGlobalSignOutError::UserNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(AdminUserGlobalSignOutError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -6448,12 +6911,14 @@ impl fmt::Display for AdminUserGlobalSignOutError {
             AdminUserGlobalSignOutError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             AdminUserGlobalSignOutError::TooManyRequests(ref cause) => write!(f, "{}", cause),
             AdminUserGlobalSignOutError::UserNotFound(ref cause) => write!(f, "{}", cause),
+            AdminUserGlobalSignOutError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for AdminUserGlobalSignOutError {}
 /// Errors returned by AssociateSoftwareToken
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum AssociateSoftwareTokenError {
     /// <p>This exception is thrown when Amazon Cognito encounters an internal error.</p>
     InternalError(String),
@@ -6465,6 +6930,8 @@ pub enum AssociateSoftwareTokenError {
     ResourceNotFound(String),
     /// <p>This exception is thrown when the software token TOTP multi-factor authentication (MFA) is not enabled for the user pool.</p>
     SoftwareTokenMFANotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl AssociateSoftwareTokenError {
@@ -6472,32 +6939,37 @@ impl AssociateSoftwareTokenError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalErrorException" => {
-                    return RusotoError::Service(AssociateSoftwareTokenError::InternalError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        AssociateSoftwareTokenError::InternalError(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(AssociateSoftwareTokenError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        AssociateSoftwareTokenError::InvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "NotAuthorizedException" => {
-                    return RusotoError::Service(AssociateSoftwareTokenError::NotAuthorized(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        AssociateSoftwareTokenError::NotAuthorized(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(AssociateSoftwareTokenError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        AssociateSoftwareTokenError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "SoftwareTokenMFANotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         AssociateSoftwareTokenError::SoftwareTokenMFANotFound(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(AssociateSoftwareTokenError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -6514,12 +6986,14 @@ impl fmt::Display for AssociateSoftwareTokenError {
             AssociateSoftwareTokenError::SoftwareTokenMFANotFound(ref cause) => {
                 write!(f, "{}", cause)
             }
+            AssociateSoftwareTokenError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for AssociateSoftwareTokenError {}
 /// Errors returned by ChangePassword
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ChangePasswordError {
     /// <p>This exception is thrown when Amazon Cognito encounters an internal error.</p>
     InternalError(String),
@@ -6541,6 +7015,8 @@ pub enum ChangePasswordError {
     UserNotConfirmed(String),
     /// <p>This exception is thrown when a user is not found.</p>
     UserNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ChangePasswordError {
@@ -6548,39 +7024,55 @@ impl ChangePasswordError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalErrorException" => {
-                    return RusotoError::Service(ChangePasswordError::InternalError(err.msg))
+                    return RusotoError::service(ChangePasswordError::InternalError(err.msg), &res)
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(ChangePasswordError::InvalidParameter(err.msg))
+                    return RusotoError::service(
+                        ChangePasswordError::InvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidPasswordException" => {
-                    return RusotoError::Service(ChangePasswordError::InvalidPassword(err.msg))
+                    return RusotoError::service(
+                        ChangePasswordError::InvalidPassword(err.msg),
+                        &res,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(ChangePasswordError::LimitExceeded(err.msg))
+                    return RusotoError::service(ChangePasswordError::LimitExceeded(err.msg), &res)
                 }
                 "NotAuthorizedException" => {
-                    return RusotoError::Service(ChangePasswordError::NotAuthorized(err.msg))
+                    return RusotoError::service(ChangePasswordError::NotAuthorized(err.msg), &res)
                 }
                 "PasswordResetRequiredException" => {
-                    return RusotoError::Service(ChangePasswordError::PasswordResetRequired(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ChangePasswordError::PasswordResetRequired(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ChangePasswordError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        ChangePasswordError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(ChangePasswordError::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        ChangePasswordError::TooManyRequests(err.msg),
+                        &res,
+                    )
                 }
                 "UserNotConfirmedException" => {
-                    return RusotoError::Service(ChangePasswordError::UserNotConfirmed(err.msg))
+                    return RusotoError::service(
+                        ChangePasswordError::UserNotConfirmed(err.msg),
+                        &res,
+                    )
                 }
                 "UserNotFoundException" => {
-                    return RusotoError::Service(ChangePasswordError::UserNotFound(err.msg))
+                    return RusotoError::service(ChangePasswordError::UserNotFound(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ChangePasswordError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -6600,12 +7092,14 @@ impl fmt::Display for ChangePasswordError {
             ChangePasswordError::TooManyRequests(ref cause) => write!(f, "{}", cause),
             ChangePasswordError::UserNotConfirmed(ref cause) => write!(f, "{}", cause),
             ChangePasswordError::UserNotFound(ref cause) => write!(f, "{}", cause),
+            ChangePasswordError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ChangePasswordError {}
 /// Errors returned by ConfirmDevice
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ConfirmDeviceError {
     /// <p>This exception is thrown when Amazon Cognito encounters an internal error.</p>
     InternalError(String),
@@ -6631,6 +7125,8 @@ pub enum ConfirmDeviceError {
     UserNotFound(String),
     /// <p>This exception is thrown when Amazon Cognito encounters a user name that already exists in the user pool.</p>
     UsernameExists(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ConfirmDeviceError {
@@ -6638,45 +7134,61 @@ impl ConfirmDeviceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalErrorException" => {
-                    return RusotoError::Service(ConfirmDeviceError::InternalError(err.msg))
+                    return RusotoError::service(ConfirmDeviceError::InternalError(err.msg), &res)
                 }
                 "InvalidLambdaResponseException" => {
-                    return RusotoError::Service(ConfirmDeviceError::InvalidLambdaResponse(err.msg))
+                    return RusotoError::service(
+                        ConfirmDeviceError::InvalidLambdaResponse(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(ConfirmDeviceError::InvalidParameter(err.msg))
+                    return RusotoError::service(
+                        ConfirmDeviceError::InvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidPasswordException" => {
-                    return RusotoError::Service(ConfirmDeviceError::InvalidPassword(err.msg))
+                    return RusotoError::service(ConfirmDeviceError::InvalidPassword(err.msg), &res)
                 }
                 "InvalidUserPoolConfigurationException" => {
-                    return RusotoError::Service(ConfirmDeviceError::InvalidUserPoolConfiguration(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ConfirmDeviceError::InvalidUserPoolConfiguration(err.msg),
+                        &res,
+                    )
                 }
                 "NotAuthorizedException" => {
-                    return RusotoError::Service(ConfirmDeviceError::NotAuthorized(err.msg))
+                    return RusotoError::service(ConfirmDeviceError::NotAuthorized(err.msg), &res)
                 }
                 "PasswordResetRequiredException" => {
-                    return RusotoError::Service(ConfirmDeviceError::PasswordResetRequired(err.msg))
+                    return RusotoError::service(
+                        ConfirmDeviceError::PasswordResetRequired(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ConfirmDeviceError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        ConfirmDeviceError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(ConfirmDeviceError::TooManyRequests(err.msg))
+                    return RusotoError::service(ConfirmDeviceError::TooManyRequests(err.msg), &res)
                 }
                 "UserNotConfirmedException" => {
-                    return RusotoError::Service(ConfirmDeviceError::UserNotConfirmed(err.msg))
+                    return RusotoError::service(
+                        ConfirmDeviceError::UserNotConfirmed(err.msg),
+                        &res,
+                    )
                 }
                 "UserNotFoundException" => {
-                    return RusotoError::Service(ConfirmDeviceError::UserNotFound(err.msg))
+                    return RusotoError::service(ConfirmDeviceError::UserNotFound(err.msg), &res)
                 }
                 "UsernameExistsException" => {
-                    return RusotoError::Service(ConfirmDeviceError::UsernameExists(err.msg))
+                    return RusotoError::service(ConfirmDeviceError::UsernameExists(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ConfirmDeviceError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -6698,12 +7210,14 @@ impl fmt::Display for ConfirmDeviceError {
             ConfirmDeviceError::UserNotConfirmed(ref cause) => write!(f, "{}", cause),
             ConfirmDeviceError::UserNotFound(ref cause) => write!(f, "{}", cause),
             ConfirmDeviceError::UsernameExists(ref cause) => write!(f, "{}", cause),
+            ConfirmDeviceError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ConfirmDeviceError {}
 /// Errors returned by ConfirmForgotPassword
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ConfirmForgotPasswordError {
     /// <p>This exception is thrown if the provided code does not match what the server was expecting.</p>
     CodeMismatch(String),
@@ -6735,6 +7249,8 @@ pub enum ConfirmForgotPasswordError {
     UserNotConfirmed(String),
     /// <p>This exception is thrown when a user is not found.</p>
     UserNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ConfirmForgotPasswordError {
@@ -6742,70 +7258,97 @@ impl ConfirmForgotPasswordError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "CodeMismatchException" => {
-                    return RusotoError::Service(ConfirmForgotPasswordError::CodeMismatch(err.msg))
+                    return RusotoError::service(
+                        ConfirmForgotPasswordError::CodeMismatch(err.msg),
+                        &res,
+                    )
                 }
                 "ExpiredCodeException" => {
-                    return RusotoError::Service(ConfirmForgotPasswordError::ExpiredCode(err.msg))
+                    return RusotoError::service(
+                        ConfirmForgotPasswordError::ExpiredCode(err.msg),
+                        &res,
+                    )
                 }
                 "InternalErrorException" => {
-                    return RusotoError::Service(ConfirmForgotPasswordError::InternalError(err.msg))
+                    return RusotoError::service(
+                        ConfirmForgotPasswordError::InternalError(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidLambdaResponseException" => {
-                    return RusotoError::Service(ConfirmForgotPasswordError::InvalidLambdaResponse(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ConfirmForgotPasswordError::InvalidLambdaResponse(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(ConfirmForgotPasswordError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ConfirmForgotPasswordError::InvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidPasswordException" => {
-                    return RusotoError::Service(ConfirmForgotPasswordError::InvalidPassword(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ConfirmForgotPasswordError::InvalidPassword(err.msg),
+                        &res,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(ConfirmForgotPasswordError::LimitExceeded(err.msg))
+                    return RusotoError::service(
+                        ConfirmForgotPasswordError::LimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "NotAuthorizedException" => {
-                    return RusotoError::Service(ConfirmForgotPasswordError::NotAuthorized(err.msg))
+                    return RusotoError::service(
+                        ConfirmForgotPasswordError::NotAuthorized(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ConfirmForgotPasswordError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ConfirmForgotPasswordError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "TooManyFailedAttemptsException" => {
-                    return RusotoError::Service(ConfirmForgotPasswordError::TooManyFailedAttempts(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ConfirmForgotPasswordError::TooManyFailedAttempts(err.msg),
+                        &res,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(ConfirmForgotPasswordError::TooManyRequests(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ConfirmForgotPasswordError::TooManyRequests(err.msg),
+                        &res,
+                    )
                 }
                 "UnexpectedLambdaException" => {
-                    return RusotoError::Service(ConfirmForgotPasswordError::UnexpectedLambda(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ConfirmForgotPasswordError::UnexpectedLambda(err.msg),
+                        &res,
+                    )
                 }
                 "UserLambdaValidationException" => {
-                    return RusotoError::Service(ConfirmForgotPasswordError::UserLambdaValidation(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ConfirmForgotPasswordError::UserLambdaValidation(err.msg),
+                        &res,
+                    )
                 }
                 "UserNotConfirmedException" => {
-                    return RusotoError::Service(ConfirmForgotPasswordError::UserNotConfirmed(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ConfirmForgotPasswordError::UserNotConfirmed(err.msg),
+                        &res,
+                    )
                 }
                 "UserNotFoundException" => {
-                    return RusotoError::Service(ConfirmForgotPasswordError::UserNotFound(err.msg))
+                    return RusotoError::service(
+                        ConfirmForgotPasswordError::UserNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ConfirmForgotPasswordError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -6830,12 +7373,14 @@ impl fmt::Display for ConfirmForgotPasswordError {
             ConfirmForgotPasswordError::UserLambdaValidation(ref cause) => write!(f, "{}", cause),
             ConfirmForgotPasswordError::UserNotConfirmed(ref cause) => write!(f, "{}", cause),
             ConfirmForgotPasswordError::UserNotFound(ref cause) => write!(f, "{}", cause),
+            ConfirmForgotPasswordError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ConfirmForgotPasswordError {}
 /// Errors returned by ConfirmSignUp
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ConfirmSignUpError {
     /// <p>This exception is thrown when a user tries to confirm the account with an email or phone number that has already been supplied as an alias from a different account. This exception tells user that an account with this email or phone already exists.</p>
     AliasExists(String),
@@ -6865,6 +7410,8 @@ pub enum ConfirmSignUpError {
     UserLambdaValidation(String),
     /// <p>This exception is thrown when a user is not found.</p>
     UserNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ConfirmSignUpError {
@@ -6872,49 +7419,67 @@ impl ConfirmSignUpError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AliasExistsException" => {
-                    return RusotoError::Service(ConfirmSignUpError::AliasExists(err.msg))
+                    return RusotoError::service(ConfirmSignUpError::AliasExists(err.msg), &res)
                 }
                 "CodeMismatchException" => {
-                    return RusotoError::Service(ConfirmSignUpError::CodeMismatch(err.msg))
+                    return RusotoError::service(ConfirmSignUpError::CodeMismatch(err.msg), &res)
                 }
                 "ExpiredCodeException" => {
-                    return RusotoError::Service(ConfirmSignUpError::ExpiredCode(err.msg))
+                    return RusotoError::service(ConfirmSignUpError::ExpiredCode(err.msg), &res)
                 }
                 "InternalErrorException" => {
-                    return RusotoError::Service(ConfirmSignUpError::InternalError(err.msg))
+                    return RusotoError::service(ConfirmSignUpError::InternalError(err.msg), &res)
                 }
                 "InvalidLambdaResponseException" => {
-                    return RusotoError::Service(ConfirmSignUpError::InvalidLambdaResponse(err.msg))
+                    return RusotoError::service(
+                        ConfirmSignUpError::InvalidLambdaResponse(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(ConfirmSignUpError::InvalidParameter(err.msg))
+                    return RusotoError::service(
+                        ConfirmSignUpError::InvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(ConfirmSignUpError::LimitExceeded(err.msg))
+                    return RusotoError::service(ConfirmSignUpError::LimitExceeded(err.msg), &res)
                 }
                 "NotAuthorizedException" => {
-                    return RusotoError::Service(ConfirmSignUpError::NotAuthorized(err.msg))
+                    return RusotoError::service(ConfirmSignUpError::NotAuthorized(err.msg), &res)
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ConfirmSignUpError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        ConfirmSignUpError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "TooManyFailedAttemptsException" => {
-                    return RusotoError::Service(ConfirmSignUpError::TooManyFailedAttempts(err.msg))
+                    return RusotoError::service(
+                        ConfirmSignUpError::TooManyFailedAttempts(err.msg),
+                        &res,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(ConfirmSignUpError::TooManyRequests(err.msg))
+                    return RusotoError::service(ConfirmSignUpError::TooManyRequests(err.msg), &res)
                 }
                 "UnexpectedLambdaException" => {
-                    return RusotoError::Service(ConfirmSignUpError::UnexpectedLambda(err.msg))
+                    return RusotoError::service(
+                        ConfirmSignUpError::UnexpectedLambda(err.msg),
+                        &res,
+                    )
                 }
                 "UserLambdaValidationException" => {
-                    return RusotoError::Service(ConfirmSignUpError::UserLambdaValidation(err.msg))
+                    return RusotoError::service(
+                        ConfirmSignUpError::UserLambdaValidation(err.msg),
+                        &res,
+                    )
                 }
                 "UserNotFoundException" => {
-                    return RusotoError::Service(ConfirmSignUpError::UserNotFound(err.msg))
+                    return RusotoError::service(ConfirmSignUpError::UserNotFound(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ConfirmSignUpError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -6938,12 +7503,14 @@ impl fmt::Display for ConfirmSignUpError {
             ConfirmSignUpError::UnexpectedLambda(ref cause) => write!(f, "{}", cause),
             ConfirmSignUpError::UserLambdaValidation(ref cause) => write!(f, "{}", cause),
             ConfirmSignUpError::UserNotFound(ref cause) => write!(f, "{}", cause),
+            ConfirmSignUpError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ConfirmSignUpError {}
 /// Errors returned by CreateGroup
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateGroupError {
     /// <p>This exception is thrown when Amazon Cognito encounters a group that already exists in the user pool.</p>
     GroupExists(String),
@@ -6959,6 +7526,8 @@ pub enum CreateGroupError {
     ResourceNotFound(String),
     /// <p>This exception is thrown when the user has made too many requests for a given operation.</p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateGroupError {
@@ -6966,28 +7535,28 @@ impl CreateGroupError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "GroupExistsException" => {
-                    return RusotoError::Service(CreateGroupError::GroupExists(err.msg))
+                    return RusotoError::service(CreateGroupError::GroupExists(err.msg), &res)
                 }
                 "InternalErrorException" => {
-                    return RusotoError::Service(CreateGroupError::InternalError(err.msg))
+                    return RusotoError::service(CreateGroupError::InternalError(err.msg), &res)
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(CreateGroupError::InvalidParameter(err.msg))
+                    return RusotoError::service(CreateGroupError::InvalidParameter(err.msg), &res)
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateGroupError::LimitExceeded(err.msg))
+                    return RusotoError::service(CreateGroupError::LimitExceeded(err.msg), &res)
                 }
                 "NotAuthorizedException" => {
-                    return RusotoError::Service(CreateGroupError::NotAuthorized(err.msg))
+                    return RusotoError::service(CreateGroupError::NotAuthorized(err.msg), &res)
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(CreateGroupError::ResourceNotFound(err.msg))
+                    return RusotoError::service(CreateGroupError::ResourceNotFound(err.msg), &res)
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(CreateGroupError::TooManyRequests(err.msg))
+                    return RusotoError::service(CreateGroupError::TooManyRequests(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreateGroupError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -7004,12 +7573,14 @@ impl fmt::Display for CreateGroupError {
             CreateGroupError::NotAuthorized(ref cause) => write!(f, "{}", cause),
             CreateGroupError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             CreateGroupError::TooManyRequests(ref cause) => write!(f, "{}", cause),
+            CreateGroupError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateGroupError {}
 /// Errors returned by CreateIdentityProvider
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateIdentityProviderError {
     /// <p>This exception is thrown when the provider is already supported by the user pool.</p>
     DuplicateProvider(String),
@@ -7025,6 +7596,8 @@ pub enum CreateIdentityProviderError {
     ResourceNotFound(String),
     /// <p>This exception is thrown when the user has made too many requests for a given operation.</p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateIdentityProviderError {
@@ -7032,42 +7605,49 @@ impl CreateIdentityProviderError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DuplicateProviderException" => {
-                    return RusotoError::Service(CreateIdentityProviderError::DuplicateProvider(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateIdentityProviderError::DuplicateProvider(err.msg),
+                        &res,
+                    )
                 }
                 "InternalErrorException" => {
-                    return RusotoError::Service(CreateIdentityProviderError::InternalError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateIdentityProviderError::InternalError(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(CreateIdentityProviderError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateIdentityProviderError::InvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateIdentityProviderError::LimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateIdentityProviderError::LimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "NotAuthorizedException" => {
-                    return RusotoError::Service(CreateIdentityProviderError::NotAuthorized(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateIdentityProviderError::NotAuthorized(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(CreateIdentityProviderError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateIdentityProviderError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(CreateIdentityProviderError::TooManyRequests(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateIdentityProviderError::TooManyRequests(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreateIdentityProviderError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -7084,12 +7664,14 @@ impl fmt::Display for CreateIdentityProviderError {
             CreateIdentityProviderError::NotAuthorized(ref cause) => write!(f, "{}", cause),
             CreateIdentityProviderError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             CreateIdentityProviderError::TooManyRequests(ref cause) => write!(f, "{}", cause),
+            CreateIdentityProviderError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateIdentityProviderError {}
 /// Errors returned by CreateResourceServer
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateResourceServerError {
     /// <p>This exception is thrown when Amazon Cognito encounters an internal error.</p>
     InternalError(String),
@@ -7103,6 +7685,8 @@ pub enum CreateResourceServerError {
     ResourceNotFound(String),
     /// <p>This exception is thrown when the user has made too many requests for a given operation.</p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateResourceServerError {
@@ -7110,31 +7694,43 @@ impl CreateResourceServerError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalErrorException" => {
-                    return RusotoError::Service(CreateResourceServerError::InternalError(err.msg))
+                    return RusotoError::service(
+                        CreateResourceServerError::InternalError(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(CreateResourceServerError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateResourceServerError::InvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateResourceServerError::LimitExceeded(err.msg))
+                    return RusotoError::service(
+                        CreateResourceServerError::LimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "NotAuthorizedException" => {
-                    return RusotoError::Service(CreateResourceServerError::NotAuthorized(err.msg))
+                    return RusotoError::service(
+                        CreateResourceServerError::NotAuthorized(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(CreateResourceServerError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateResourceServerError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(CreateResourceServerError::TooManyRequests(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateResourceServerError::TooManyRequests(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreateResourceServerError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -7150,12 +7746,14 @@ impl fmt::Display for CreateResourceServerError {
             CreateResourceServerError::NotAuthorized(ref cause) => write!(f, "{}", cause),
             CreateResourceServerError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             CreateResourceServerError::TooManyRequests(ref cause) => write!(f, "{}", cause),
+            CreateResourceServerError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateResourceServerError {}
 /// Errors returned by CreateUserImportJob
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateUserImportJobError {
     /// <p>This exception is thrown when Amazon Cognito encounters an internal error.</p>
     InternalError(String),
@@ -7171,6 +7769,8 @@ pub enum CreateUserImportJobError {
     ResourceNotFound(String),
     /// <p>This exception is thrown when the user has made too many requests for a given operation.</p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateUserImportJobError {
@@ -7178,34 +7778,49 @@ impl CreateUserImportJobError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalErrorException" => {
-                    return RusotoError::Service(CreateUserImportJobError::InternalError(err.msg))
+                    return RusotoError::service(
+                        CreateUserImportJobError::InternalError(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(CreateUserImportJobError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateUserImportJobError::InvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateUserImportJobError::LimitExceeded(err.msg))
+                    return RusotoError::service(
+                        CreateUserImportJobError::LimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "NotAuthorizedException" => {
-                    return RusotoError::Service(CreateUserImportJobError::NotAuthorized(err.msg))
+                    return RusotoError::service(
+                        CreateUserImportJobError::NotAuthorized(err.msg),
+                        &res,
+                    )
                 }
                 "PreconditionNotMetException" => {
-                    return RusotoError::Service(CreateUserImportJobError::PreconditionNotMet(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateUserImportJobError::PreconditionNotMet(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(CreateUserImportJobError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateUserImportJobError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(CreateUserImportJobError::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        CreateUserImportJobError::TooManyRequests(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreateUserImportJobError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -7222,12 +7837,14 @@ impl fmt::Display for CreateUserImportJobError {
             CreateUserImportJobError::PreconditionNotMet(ref cause) => write!(f, "{}", cause),
             CreateUserImportJobError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             CreateUserImportJobError::TooManyRequests(ref cause) => write!(f, "{}", cause),
+            CreateUserImportJobError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateUserImportJobError {}
 /// Errors returned by CreateUserPool
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateUserPoolError {
     /// <p>This exception is thrown when Amazon Cognito encounters an internal error.</p>
     InternalError(String),
@@ -7247,6 +7864,8 @@ pub enum CreateUserPoolError {
     TooManyRequests(String),
     /// <p>This exception is thrown when a user pool tag cannot be set or updated.</p>
     UserPoolTagging(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateUserPoolError {
@@ -7254,40 +7873,52 @@ impl CreateUserPoolError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalErrorException" => {
-                    return RusotoError::Service(CreateUserPoolError::InternalError(err.msg))
+                    return RusotoError::service(CreateUserPoolError::InternalError(err.msg), &res)
                 }
                 "InvalidEmailRoleAccessPolicyException" => {
-                    return RusotoError::Service(CreateUserPoolError::InvalidEmailRoleAccessPolicy(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateUserPoolError::InvalidEmailRoleAccessPolicy(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(CreateUserPoolError::InvalidParameter(err.msg))
+                    return RusotoError::service(
+                        CreateUserPoolError::InvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidSmsRoleAccessPolicyException" => {
-                    return RusotoError::Service(CreateUserPoolError::InvalidSmsRoleAccessPolicy(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateUserPoolError::InvalidSmsRoleAccessPolicy(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidSmsRoleTrustRelationshipException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         CreateUserPoolError::InvalidSmsRoleTrustRelationship(err.msg),
+                        &res,
                     )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateUserPoolError::LimitExceeded(err.msg))
+                    return RusotoError::service(CreateUserPoolError::LimitExceeded(err.msg), &res)
                 }
                 "NotAuthorizedException" => {
-                    return RusotoError::Service(CreateUserPoolError::NotAuthorized(err.msg))
+                    return RusotoError::service(CreateUserPoolError::NotAuthorized(err.msg), &res)
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(CreateUserPoolError::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        CreateUserPoolError::TooManyRequests(err.msg),
+                        &res,
+                    )
                 }
                 "UserPoolTaggingException" => {
-                    return RusotoError::Service(CreateUserPoolError::UserPoolTagging(err.msg))
+                    return RusotoError::service(
+                        CreateUserPoolError::UserPoolTagging(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreateUserPoolError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -7308,12 +7939,14 @@ impl fmt::Display for CreateUserPoolError {
             CreateUserPoolError::NotAuthorized(ref cause) => write!(f, "{}", cause),
             CreateUserPoolError::TooManyRequests(ref cause) => write!(f, "{}", cause),
             CreateUserPoolError::UserPoolTagging(ref cause) => write!(f, "{}", cause),
+            CreateUserPoolError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateUserPoolError {}
 /// Errors returned by CreateUserPoolClient
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateUserPoolClientError {
     /// <p>This exception is thrown when Amazon Cognito encounters an internal error.</p>
     InternalError(String),
@@ -7331,6 +7964,8 @@ pub enum CreateUserPoolClientError {
     ScopeDoesNotExist(String),
     /// <p>This exception is thrown when the user has made too many requests for a given operation.</p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateUserPoolClientError {
@@ -7338,41 +7973,55 @@ impl CreateUserPoolClientError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalErrorException" => {
-                    return RusotoError::Service(CreateUserPoolClientError::InternalError(err.msg))
+                    return RusotoError::service(
+                        CreateUserPoolClientError::InternalError(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidOAuthFlowException" => {
-                    return RusotoError::Service(CreateUserPoolClientError::InvalidOAuthFlow(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateUserPoolClientError::InvalidOAuthFlow(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(CreateUserPoolClientError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateUserPoolClientError::InvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateUserPoolClientError::LimitExceeded(err.msg))
+                    return RusotoError::service(
+                        CreateUserPoolClientError::LimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "NotAuthorizedException" => {
-                    return RusotoError::Service(CreateUserPoolClientError::NotAuthorized(err.msg))
+                    return RusotoError::service(
+                        CreateUserPoolClientError::NotAuthorized(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(CreateUserPoolClientError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateUserPoolClientError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ScopeDoesNotExistException" => {
-                    return RusotoError::Service(CreateUserPoolClientError::ScopeDoesNotExist(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateUserPoolClientError::ScopeDoesNotExist(err.msg),
+                        &res,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(CreateUserPoolClientError::TooManyRequests(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateUserPoolClientError::TooManyRequests(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreateUserPoolClientError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -7390,12 +8039,14 @@ impl fmt::Display for CreateUserPoolClientError {
             CreateUserPoolClientError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             CreateUserPoolClientError::ScopeDoesNotExist(ref cause) => write!(f, "{}", cause),
             CreateUserPoolClientError::TooManyRequests(ref cause) => write!(f, "{}", cause),
+            CreateUserPoolClientError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateUserPoolClientError {}
 /// Errors returned by CreateUserPoolDomain
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateUserPoolDomainError {
     /// <p>This exception is thrown when Amazon Cognito encounters an internal error.</p>
     InternalError(String),
@@ -7407,6 +8058,8 @@ pub enum CreateUserPoolDomainError {
     NotAuthorized(String),
     /// <p>This exception is thrown when the Amazon Cognito service cannot find the requested resource.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateUserPoolDomainError {
@@ -7414,26 +8067,37 @@ impl CreateUserPoolDomainError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalErrorException" => {
-                    return RusotoError::Service(CreateUserPoolDomainError::InternalError(err.msg))
+                    return RusotoError::service(
+                        CreateUserPoolDomainError::InternalError(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(CreateUserPoolDomainError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateUserPoolDomainError::InvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateUserPoolDomainError::LimitExceeded(err.msg))
+                    return RusotoError::service(
+                        CreateUserPoolDomainError::LimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "NotAuthorizedException" => {
-                    return RusotoError::Service(CreateUserPoolDomainError::NotAuthorized(err.msg))
+                    return RusotoError::service(
+                        CreateUserPoolDomainError::NotAuthorized(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(CreateUserPoolDomainError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateUserPoolDomainError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreateUserPoolDomainError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -7448,12 +8112,14 @@ impl fmt::Display for CreateUserPoolDomainError {
             CreateUserPoolDomainError::LimitExceeded(ref cause) => write!(f, "{}", cause),
             CreateUserPoolDomainError::NotAuthorized(ref cause) => write!(f, "{}", cause),
             CreateUserPoolDomainError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            CreateUserPoolDomainError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateUserPoolDomainError {}
 /// Errors returned by DeleteGroup
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteGroupError {
     /// <p>This exception is thrown when Amazon Cognito encounters an internal error.</p>
     InternalError(String),
@@ -7465,6 +8131,8 @@ pub enum DeleteGroupError {
     ResourceNotFound(String),
     /// <p>This exception is thrown when the user has made too many requests for a given operation.</p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteGroupError {
@@ -7472,22 +8140,22 @@ impl DeleteGroupError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalErrorException" => {
-                    return RusotoError::Service(DeleteGroupError::InternalError(err.msg))
+                    return RusotoError::service(DeleteGroupError::InternalError(err.msg), &res)
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DeleteGroupError::InvalidParameter(err.msg))
+                    return RusotoError::service(DeleteGroupError::InvalidParameter(err.msg), &res)
                 }
                 "NotAuthorizedException" => {
-                    return RusotoError::Service(DeleteGroupError::NotAuthorized(err.msg))
+                    return RusotoError::service(DeleteGroupError::NotAuthorized(err.msg), &res)
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteGroupError::ResourceNotFound(err.msg))
+                    return RusotoError::service(DeleteGroupError::ResourceNotFound(err.msg), &res)
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(DeleteGroupError::TooManyRequests(err.msg))
+                    return RusotoError::service(DeleteGroupError::TooManyRequests(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteGroupError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -7502,12 +8170,14 @@ impl fmt::Display for DeleteGroupError {
             DeleteGroupError::NotAuthorized(ref cause) => write!(f, "{}", cause),
             DeleteGroupError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             DeleteGroupError::TooManyRequests(ref cause) => write!(f, "{}", cause),
+            DeleteGroupError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteGroupError {}
 /// Errors returned by DeleteIdentityProvider
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteIdentityProviderError {
     /// <p>This exception is thrown when Amazon Cognito encounters an internal error.</p>
     InternalError(String),
@@ -7521,6 +8191,8 @@ pub enum DeleteIdentityProviderError {
     TooManyRequests(String),
     /// <p>This exception is thrown when the specified identifier is not supported.</p>
     UnsupportedIdentityProvider(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteIdentityProviderError {
@@ -7528,37 +8200,43 @@ impl DeleteIdentityProviderError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalErrorException" => {
-                    return RusotoError::Service(DeleteIdentityProviderError::InternalError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteIdentityProviderError::InternalError(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DeleteIdentityProviderError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteIdentityProviderError::InvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "NotAuthorizedException" => {
-                    return RusotoError::Service(DeleteIdentityProviderError::NotAuthorized(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteIdentityProviderError::NotAuthorized(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteIdentityProviderError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteIdentityProviderError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(DeleteIdentityProviderError::TooManyRequests(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteIdentityProviderError::TooManyRequests(err.msg),
+                        &res,
+                    )
                 }
                 "UnsupportedIdentityProviderException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DeleteIdentityProviderError::UnsupportedIdentityProvider(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteIdentityProviderError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -7576,12 +8254,14 @@ impl fmt::Display for DeleteIdentityProviderError {
             DeleteIdentityProviderError::UnsupportedIdentityProvider(ref cause) => {
                 write!(f, "{}", cause)
             }
+            DeleteIdentityProviderError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteIdentityProviderError {}
 /// Errors returned by DeleteResourceServer
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteResourceServerError {
     /// <p>This exception is thrown when Amazon Cognito encounters an internal error.</p>
     InternalError(String),
@@ -7593,6 +8273,8 @@ pub enum DeleteResourceServerError {
     ResourceNotFound(String),
     /// <p>This exception is thrown when the user has made too many requests for a given operation.</p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteResourceServerError {
@@ -7600,28 +8282,37 @@ impl DeleteResourceServerError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalErrorException" => {
-                    return RusotoError::Service(DeleteResourceServerError::InternalError(err.msg))
+                    return RusotoError::service(
+                        DeleteResourceServerError::InternalError(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DeleteResourceServerError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteResourceServerError::InvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "NotAuthorizedException" => {
-                    return RusotoError::Service(DeleteResourceServerError::NotAuthorized(err.msg))
+                    return RusotoError::service(
+                        DeleteResourceServerError::NotAuthorized(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteResourceServerError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteResourceServerError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(DeleteResourceServerError::TooManyRequests(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteResourceServerError::TooManyRequests(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteResourceServerError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -7636,12 +8327,14 @@ impl fmt::Display for DeleteResourceServerError {
             DeleteResourceServerError::NotAuthorized(ref cause) => write!(f, "{}", cause),
             DeleteResourceServerError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             DeleteResourceServerError::TooManyRequests(ref cause) => write!(f, "{}", cause),
+            DeleteResourceServerError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteResourceServerError {}
 /// Errors returned by DeleteUser
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteUserError {
     /// <p>This exception is thrown when Amazon Cognito encounters an internal error.</p>
     InternalError(String),
@@ -7659,6 +8352,8 @@ pub enum DeleteUserError {
     UserNotConfirmed(String),
     /// <p>This exception is thrown when a user is not found.</p>
     UserNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteUserError {
@@ -7666,31 +8361,34 @@ impl DeleteUserError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalErrorException" => {
-                    return RusotoError::Service(DeleteUserError::InternalError(err.msg))
+                    return RusotoError::service(DeleteUserError::InternalError(err.msg), &res)
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DeleteUserError::InvalidParameter(err.msg))
+                    return RusotoError::service(DeleteUserError::InvalidParameter(err.msg), &res)
                 }
                 "NotAuthorizedException" => {
-                    return RusotoError::Service(DeleteUserError::NotAuthorized(err.msg))
+                    return RusotoError::service(DeleteUserError::NotAuthorized(err.msg), &res)
                 }
                 "PasswordResetRequiredException" => {
-                    return RusotoError::Service(DeleteUserError::PasswordResetRequired(err.msg))
+                    return RusotoError::service(
+                        DeleteUserError::PasswordResetRequired(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteUserError::ResourceNotFound(err.msg))
+                    return RusotoError::service(DeleteUserError::ResourceNotFound(err.msg), &res)
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(DeleteUserError::TooManyRequests(err.msg))
+                    return RusotoError::service(DeleteUserError::TooManyRequests(err.msg), &res)
                 }
                 "UserNotConfirmedException" => {
-                    return RusotoError::Service(DeleteUserError::UserNotConfirmed(err.msg))
+                    return RusotoError::service(DeleteUserError::UserNotConfirmed(err.msg), &res)
                 }
                 "UserNotFoundException" => {
-                    return RusotoError::Service(DeleteUserError::UserNotFound(err.msg))
+                    return RusotoError::service(DeleteUserError::UserNotFound(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteUserError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -7708,12 +8406,14 @@ impl fmt::Display for DeleteUserError {
             DeleteUserError::TooManyRequests(ref cause) => write!(f, "{}", cause),
             DeleteUserError::UserNotConfirmed(ref cause) => write!(f, "{}", cause),
             DeleteUserError::UserNotFound(ref cause) => write!(f, "{}", cause),
+            DeleteUserError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteUserError {}
 /// Errors returned by DeleteUserAttributes
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteUserAttributesError {
     /// <p>This exception is thrown when Amazon Cognito encounters an internal error.</p>
     InternalError(String),
@@ -7731,6 +8431,8 @@ pub enum DeleteUserAttributesError {
     UserNotConfirmed(String),
     /// <p>This exception is thrown when a user is not found.</p>
     UserNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteUserAttributesError {
@@ -7738,41 +8440,55 @@ impl DeleteUserAttributesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalErrorException" => {
-                    return RusotoError::Service(DeleteUserAttributesError::InternalError(err.msg))
+                    return RusotoError::service(
+                        DeleteUserAttributesError::InternalError(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DeleteUserAttributesError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteUserAttributesError::InvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "NotAuthorizedException" => {
-                    return RusotoError::Service(DeleteUserAttributesError::NotAuthorized(err.msg))
+                    return RusotoError::service(
+                        DeleteUserAttributesError::NotAuthorized(err.msg),
+                        &res,
+                    )
                 }
                 "PasswordResetRequiredException" => {
-                    return RusotoError::Service(DeleteUserAttributesError::PasswordResetRequired(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteUserAttributesError::PasswordResetRequired(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteUserAttributesError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteUserAttributesError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(DeleteUserAttributesError::TooManyRequests(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteUserAttributesError::TooManyRequests(err.msg),
+                        &res,
+                    )
                 }
                 "UserNotConfirmedException" => {
-                    return RusotoError::Service(DeleteUserAttributesError::UserNotConfirmed(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteUserAttributesError::UserNotConfirmed(err.msg),
+                        &res,
+                    )
                 }
                 "UserNotFoundException" => {
-                    return RusotoError::Service(DeleteUserAttributesError::UserNotFound(err.msg))
+                    return RusotoError::service(
+                        DeleteUserAttributesError::UserNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteUserAttributesError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -7790,12 +8506,14 @@ impl fmt::Display for DeleteUserAttributesError {
             DeleteUserAttributesError::TooManyRequests(ref cause) => write!(f, "{}", cause),
             DeleteUserAttributesError::UserNotConfirmed(ref cause) => write!(f, "{}", cause),
             DeleteUserAttributesError::UserNotFound(ref cause) => write!(f, "{}", cause),
+            DeleteUserAttributesError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteUserAttributesError {}
 /// Errors returned by DeleteUserPool
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteUserPoolError {
     /// <p>This exception is thrown when Amazon Cognito encounters an internal error.</p>
     InternalError(String),
@@ -7809,6 +8527,8 @@ pub enum DeleteUserPoolError {
     TooManyRequests(String),
     /// <p>This exception is thrown when you are trying to modify a user pool while a user import job is in progress for that pool.</p>
     UserImportInProgress(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteUserPoolError {
@@ -7816,25 +8536,37 @@ impl DeleteUserPoolError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalErrorException" => {
-                    return RusotoError::Service(DeleteUserPoolError::InternalError(err.msg))
+                    return RusotoError::service(DeleteUserPoolError::InternalError(err.msg), &res)
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DeleteUserPoolError::InvalidParameter(err.msg))
+                    return RusotoError::service(
+                        DeleteUserPoolError::InvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "NotAuthorizedException" => {
-                    return RusotoError::Service(DeleteUserPoolError::NotAuthorized(err.msg))
+                    return RusotoError::service(DeleteUserPoolError::NotAuthorized(err.msg), &res)
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteUserPoolError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        DeleteUserPoolError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(DeleteUserPoolError::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        DeleteUserPoolError::TooManyRequests(err.msg),
+                        &res,
+                    )
                 }
                 "UserImportInProgressException" => {
-                    return RusotoError::Service(DeleteUserPoolError::UserImportInProgress(err.msg))
+                    return RusotoError::service(
+                        DeleteUserPoolError::UserImportInProgress(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteUserPoolError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -7850,12 +8582,14 @@ impl fmt::Display for DeleteUserPoolError {
             DeleteUserPoolError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             DeleteUserPoolError::TooManyRequests(ref cause) => write!(f, "{}", cause),
             DeleteUserPoolError::UserImportInProgress(ref cause) => write!(f, "{}", cause),
+            DeleteUserPoolError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteUserPoolError {}
 /// Errors returned by DeleteUserPoolClient
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteUserPoolClientError {
     /// <p>This exception is thrown when Amazon Cognito encounters an internal error.</p>
     InternalError(String),
@@ -7867,6 +8601,8 @@ pub enum DeleteUserPoolClientError {
     ResourceNotFound(String),
     /// <p>This exception is thrown when the user has made too many requests for a given operation.</p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteUserPoolClientError {
@@ -7874,28 +8610,37 @@ impl DeleteUserPoolClientError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalErrorException" => {
-                    return RusotoError::Service(DeleteUserPoolClientError::InternalError(err.msg))
+                    return RusotoError::service(
+                        DeleteUserPoolClientError::InternalError(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DeleteUserPoolClientError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteUserPoolClientError::InvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "NotAuthorizedException" => {
-                    return RusotoError::Service(DeleteUserPoolClientError::NotAuthorized(err.msg))
+                    return RusotoError::service(
+                        DeleteUserPoolClientError::NotAuthorized(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteUserPoolClientError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteUserPoolClientError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(DeleteUserPoolClientError::TooManyRequests(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteUserPoolClientError::TooManyRequests(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteUserPoolClientError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -7910,12 +8655,14 @@ impl fmt::Display for DeleteUserPoolClientError {
             DeleteUserPoolClientError::NotAuthorized(ref cause) => write!(f, "{}", cause),
             DeleteUserPoolClientError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             DeleteUserPoolClientError::TooManyRequests(ref cause) => write!(f, "{}", cause),
+            DeleteUserPoolClientError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteUserPoolClientError {}
 /// Errors returned by DeleteUserPoolDomain
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteUserPoolDomainError {
     /// <p>This exception is thrown when Amazon Cognito encounters an internal error.</p>
     InternalError(String),
@@ -7925,6 +8672,8 @@ pub enum DeleteUserPoolDomainError {
     NotAuthorized(String),
     /// <p>This exception is thrown when the Amazon Cognito service cannot find the requested resource.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteUserPoolDomainError {
@@ -7932,23 +8681,31 @@ impl DeleteUserPoolDomainError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalErrorException" => {
-                    return RusotoError::Service(DeleteUserPoolDomainError::InternalError(err.msg))
+                    return RusotoError::service(
+                        DeleteUserPoolDomainError::InternalError(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DeleteUserPoolDomainError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteUserPoolDomainError::InvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "NotAuthorizedException" => {
-                    return RusotoError::Service(DeleteUserPoolDomainError::NotAuthorized(err.msg))
+                    return RusotoError::service(
+                        DeleteUserPoolDomainError::NotAuthorized(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteUserPoolDomainError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteUserPoolDomainError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteUserPoolDomainError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -7962,12 +8719,14 @@ impl fmt::Display for DeleteUserPoolDomainError {
             DeleteUserPoolDomainError::InvalidParameter(ref cause) => write!(f, "{}", cause),
             DeleteUserPoolDomainError::NotAuthorized(ref cause) => write!(f, "{}", cause),
             DeleteUserPoolDomainError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            DeleteUserPoolDomainError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteUserPoolDomainError {}
 /// Errors returned by DescribeIdentityProvider
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeIdentityProviderError {
     /// <p>This exception is thrown when Amazon Cognito encounters an internal error.</p>
     InternalError(String),
@@ -7979,6 +8738,8 @@ pub enum DescribeIdentityProviderError {
     ResourceNotFound(String),
     /// <p>This exception is thrown when the user has made too many requests for a given operation.</p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeIdentityProviderError {
@@ -7986,32 +8747,37 @@ impl DescribeIdentityProviderError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalErrorException" => {
-                    return RusotoError::Service(DescribeIdentityProviderError::InternalError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeIdentityProviderError::InternalError(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DescribeIdentityProviderError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeIdentityProviderError::InvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "NotAuthorizedException" => {
-                    return RusotoError::Service(DescribeIdentityProviderError::NotAuthorized(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeIdentityProviderError::NotAuthorized(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeIdentityProviderError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeIdentityProviderError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(DescribeIdentityProviderError::TooManyRequests(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeIdentityProviderError::TooManyRequests(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DescribeIdentityProviderError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -8026,12 +8792,14 @@ impl fmt::Display for DescribeIdentityProviderError {
             DescribeIdentityProviderError::NotAuthorized(ref cause) => write!(f, "{}", cause),
             DescribeIdentityProviderError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             DescribeIdentityProviderError::TooManyRequests(ref cause) => write!(f, "{}", cause),
+            DescribeIdentityProviderError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeIdentityProviderError {}
 /// Errors returned by DescribeResourceServer
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeResourceServerError {
     /// <p>This exception is thrown when Amazon Cognito encounters an internal error.</p>
     InternalError(String),
@@ -8043,6 +8811,8 @@ pub enum DescribeResourceServerError {
     ResourceNotFound(String),
     /// <p>This exception is thrown when the user has made too many requests for a given operation.</p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeResourceServerError {
@@ -8050,32 +8820,37 @@ impl DescribeResourceServerError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalErrorException" => {
-                    return RusotoError::Service(DescribeResourceServerError::InternalError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeResourceServerError::InternalError(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DescribeResourceServerError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeResourceServerError::InvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "NotAuthorizedException" => {
-                    return RusotoError::Service(DescribeResourceServerError::NotAuthorized(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeResourceServerError::NotAuthorized(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeResourceServerError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeResourceServerError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(DescribeResourceServerError::TooManyRequests(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeResourceServerError::TooManyRequests(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DescribeResourceServerError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -8090,12 +8865,14 @@ impl fmt::Display for DescribeResourceServerError {
             DescribeResourceServerError::NotAuthorized(ref cause) => write!(f, "{}", cause),
             DescribeResourceServerError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             DescribeResourceServerError::TooManyRequests(ref cause) => write!(f, "{}", cause),
+            DescribeResourceServerError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeResourceServerError {}
 /// Errors returned by DescribeRiskConfiguration
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeRiskConfigurationError {
     /// <p>This exception is thrown when Amazon Cognito encounters an internal error.</p>
     InternalError(String),
@@ -8109,6 +8886,8 @@ pub enum DescribeRiskConfigurationError {
     TooManyRequests(String),
     /// <p>This exception is thrown when user pool add-ons are not enabled.</p>
     UserPoolAddOnNotEnabled(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeRiskConfigurationError {
@@ -8116,37 +8895,45 @@ impl DescribeRiskConfigurationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalErrorException" => {
-                    return RusotoError::Service(DescribeRiskConfigurationError::InternalError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeRiskConfigurationError::InternalError(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DescribeRiskConfigurationError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeRiskConfigurationError::InvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "NotAuthorizedException" => {
-                    return RusotoError::Service(DescribeRiskConfigurationError::NotAuthorized(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeRiskConfigurationError::NotAuthorized(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeRiskConfigurationError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeRiskConfigurationError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(DescribeRiskConfigurationError::TooManyRequests(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeRiskConfigurationError::TooManyRequests(err.msg),
+                        &res,
+                    )
                 }
                 "UserPoolAddOnNotEnabledException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DescribeRiskConfigurationError::UserPoolAddOnNotEnabled(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(DescribeRiskConfigurationError::Unhandled, &res)
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -8164,12 +8951,14 @@ impl fmt::Display for DescribeRiskConfigurationError {
             DescribeRiskConfigurationError::UserPoolAddOnNotEnabled(ref cause) => {
                 write!(f, "{}", cause)
             }
+            DescribeRiskConfigurationError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeRiskConfigurationError {}
 /// Errors returned by DescribeUserImportJob
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeUserImportJobError {
     /// <p>This exception is thrown when Amazon Cognito encounters an internal error.</p>
     InternalError(String),
@@ -8181,6 +8970,8 @@ pub enum DescribeUserImportJobError {
     ResourceNotFound(String),
     /// <p>This exception is thrown when the user has made too many requests for a given operation.</p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeUserImportJobError {
@@ -8188,28 +8979,37 @@ impl DescribeUserImportJobError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalErrorException" => {
-                    return RusotoError::Service(DescribeUserImportJobError::InternalError(err.msg))
+                    return RusotoError::service(
+                        DescribeUserImportJobError::InternalError(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DescribeUserImportJobError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeUserImportJobError::InvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "NotAuthorizedException" => {
-                    return RusotoError::Service(DescribeUserImportJobError::NotAuthorized(err.msg))
+                    return RusotoError::service(
+                        DescribeUserImportJobError::NotAuthorized(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeUserImportJobError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeUserImportJobError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(DescribeUserImportJobError::TooManyRequests(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeUserImportJobError::TooManyRequests(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DescribeUserImportJobError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -8224,12 +9024,14 @@ impl fmt::Display for DescribeUserImportJobError {
             DescribeUserImportJobError::NotAuthorized(ref cause) => write!(f, "{}", cause),
             DescribeUserImportJobError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             DescribeUserImportJobError::TooManyRequests(ref cause) => write!(f, "{}", cause),
+            DescribeUserImportJobError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeUserImportJobError {}
 /// Errors returned by DescribeUserPool
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeUserPoolError {
     /// <p>This exception is thrown when Amazon Cognito encounters an internal error.</p>
     InternalError(String),
@@ -8243,6 +9045,8 @@ pub enum DescribeUserPoolError {
     TooManyRequests(String),
     /// <p>This exception is thrown when a user pool tag cannot be set or updated.</p>
     UserPoolTagging(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeUserPoolError {
@@ -8250,25 +9054,43 @@ impl DescribeUserPoolError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalErrorException" => {
-                    return RusotoError::Service(DescribeUserPoolError::InternalError(err.msg))
+                    return RusotoError::service(
+                        DescribeUserPoolError::InternalError(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DescribeUserPoolError::InvalidParameter(err.msg))
+                    return RusotoError::service(
+                        DescribeUserPoolError::InvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "NotAuthorizedException" => {
-                    return RusotoError::Service(DescribeUserPoolError::NotAuthorized(err.msg))
+                    return RusotoError::service(
+                        DescribeUserPoolError::NotAuthorized(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeUserPoolError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        DescribeUserPoolError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(DescribeUserPoolError::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        DescribeUserPoolError::TooManyRequests(err.msg),
+                        &res,
+                    )
                 }
                 "UserPoolTaggingException" => {
-                    return RusotoError::Service(DescribeUserPoolError::UserPoolTagging(err.msg))
+                    return RusotoError::service(
+                        DescribeUserPoolError::UserPoolTagging(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DescribeUserPoolError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -8284,12 +9106,14 @@ impl fmt::Display for DescribeUserPoolError {
             DescribeUserPoolError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             DescribeUserPoolError::TooManyRequests(ref cause) => write!(f, "{}", cause),
             DescribeUserPoolError::UserPoolTagging(ref cause) => write!(f, "{}", cause),
+            DescribeUserPoolError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeUserPoolError {}
 /// Errors returned by DescribeUserPoolClient
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeUserPoolClientError {
     /// <p>This exception is thrown when Amazon Cognito encounters an internal error.</p>
     InternalError(String),
@@ -8301,6 +9125,8 @@ pub enum DescribeUserPoolClientError {
     ResourceNotFound(String),
     /// <p>This exception is thrown when the user has made too many requests for a given operation.</p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeUserPoolClientError {
@@ -8308,32 +9134,37 @@ impl DescribeUserPoolClientError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalErrorException" => {
-                    return RusotoError::Service(DescribeUserPoolClientError::InternalError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeUserPoolClientError::InternalError(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DescribeUserPoolClientError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeUserPoolClientError::InvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "NotAuthorizedException" => {
-                    return RusotoError::Service(DescribeUserPoolClientError::NotAuthorized(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeUserPoolClientError::NotAuthorized(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeUserPoolClientError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeUserPoolClientError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(DescribeUserPoolClientError::TooManyRequests(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeUserPoolClientError::TooManyRequests(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DescribeUserPoolClientError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -8348,12 +9179,14 @@ impl fmt::Display for DescribeUserPoolClientError {
             DescribeUserPoolClientError::NotAuthorized(ref cause) => write!(f, "{}", cause),
             DescribeUserPoolClientError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             DescribeUserPoolClientError::TooManyRequests(ref cause) => write!(f, "{}", cause),
+            DescribeUserPoolClientError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeUserPoolClientError {}
 /// Errors returned by DescribeUserPoolDomain
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeUserPoolDomainError {
     /// <p>This exception is thrown when Amazon Cognito encounters an internal error.</p>
     InternalError(String),
@@ -8363,6 +9196,8 @@ pub enum DescribeUserPoolDomainError {
     NotAuthorized(String),
     /// <p>This exception is thrown when the Amazon Cognito service cannot find the requested resource.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeUserPoolDomainError {
@@ -8370,27 +9205,31 @@ impl DescribeUserPoolDomainError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalErrorException" => {
-                    return RusotoError::Service(DescribeUserPoolDomainError::InternalError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeUserPoolDomainError::InternalError(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DescribeUserPoolDomainError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeUserPoolDomainError::InvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "NotAuthorizedException" => {
-                    return RusotoError::Service(DescribeUserPoolDomainError::NotAuthorized(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeUserPoolDomainError::NotAuthorized(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeUserPoolDomainError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeUserPoolDomainError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DescribeUserPoolDomainError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -8404,12 +9243,14 @@ impl fmt::Display for DescribeUserPoolDomainError {
             DescribeUserPoolDomainError::InvalidParameter(ref cause) => write!(f, "{}", cause),
             DescribeUserPoolDomainError::NotAuthorized(ref cause) => write!(f, "{}", cause),
             DescribeUserPoolDomainError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            DescribeUserPoolDomainError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeUserPoolDomainError {}
 /// Errors returned by ForgetDevice
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ForgetDeviceError {
     /// <p>This exception is thrown when Amazon Cognito encounters an internal error.</p>
     InternalError(String),
@@ -8429,6 +9270,8 @@ pub enum ForgetDeviceError {
     UserNotConfirmed(String),
     /// <p>This exception is thrown when a user is not found.</p>
     UserNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ForgetDeviceError {
@@ -8436,36 +9279,40 @@ impl ForgetDeviceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalErrorException" => {
-                    return RusotoError::Service(ForgetDeviceError::InternalError(err.msg))
+                    return RusotoError::service(ForgetDeviceError::InternalError(err.msg), &res)
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(ForgetDeviceError::InvalidParameter(err.msg))
+                    return RusotoError::service(ForgetDeviceError::InvalidParameter(err.msg), &res)
                 }
                 "InvalidUserPoolConfigurationException" => {
-                    return RusotoError::Service(ForgetDeviceError::InvalidUserPoolConfiguration(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ForgetDeviceError::InvalidUserPoolConfiguration(err.msg),
+                        &res,
+                    )
                 }
                 "NotAuthorizedException" => {
-                    return RusotoError::Service(ForgetDeviceError::NotAuthorized(err.msg))
+                    return RusotoError::service(ForgetDeviceError::NotAuthorized(err.msg), &res)
                 }
                 "PasswordResetRequiredException" => {
-                    return RusotoError::Service(ForgetDeviceError::PasswordResetRequired(err.msg))
+                    return RusotoError::service(
+                        ForgetDeviceError::PasswordResetRequired(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ForgetDeviceError::ResourceNotFound(err.msg))
+                    return RusotoError::service(ForgetDeviceError::ResourceNotFound(err.msg), &res)
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(ForgetDeviceError::TooManyRequests(err.msg))
+                    return RusotoError::service(ForgetDeviceError::TooManyRequests(err.msg), &res)
                 }
                 "UserNotConfirmedException" => {
-                    return RusotoError::Service(ForgetDeviceError::UserNotConfirmed(err.msg))
+                    return RusotoError::service(ForgetDeviceError::UserNotConfirmed(err.msg), &res)
                 }
                 "UserNotFoundException" => {
-                    return RusotoError::Service(ForgetDeviceError::UserNotFound(err.msg))
+                    return RusotoError::service(ForgetDeviceError::UserNotFound(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ForgetDeviceError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -8484,12 +9331,14 @@ impl fmt::Display for ForgetDeviceError {
             ForgetDeviceError::TooManyRequests(ref cause) => write!(f, "{}", cause),
             ForgetDeviceError::UserNotConfirmed(ref cause) => write!(f, "{}", cause),
             ForgetDeviceError::UserNotFound(ref cause) => write!(f, "{}", cause),
+            ForgetDeviceError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ForgetDeviceError {}
 /// Errors returned by ForgotPassword
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ForgotPasswordError {
     /// <p>This exception is thrown when a verification code fails to deliver successfully.</p>
     CodeDeliveryFailure(String),
@@ -8521,6 +9370,8 @@ pub enum ForgotPasswordError {
     UserNotConfirmed(String),
     /// <p>This exception is thrown when a user is not found.</p>
     UserNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ForgotPasswordError {
@@ -8528,60 +9379,85 @@ impl ForgotPasswordError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "CodeDeliveryFailureException" => {
-                    return RusotoError::Service(ForgotPasswordError::CodeDeliveryFailure(err.msg))
+                    return RusotoError::service(
+                        ForgotPasswordError::CodeDeliveryFailure(err.msg),
+                        &res,
+                    )
                 }
                 "InternalErrorException" => {
-                    return RusotoError::Service(ForgotPasswordError::InternalError(err.msg))
+                    return RusotoError::service(ForgotPasswordError::InternalError(err.msg), &res)
                 }
                 "InvalidEmailRoleAccessPolicyException" => {
-                    return RusotoError::Service(ForgotPasswordError::InvalidEmailRoleAccessPolicy(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ForgotPasswordError::InvalidEmailRoleAccessPolicy(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidLambdaResponseException" => {
-                    return RusotoError::Service(ForgotPasswordError::InvalidLambdaResponse(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ForgotPasswordError::InvalidLambdaResponse(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(ForgotPasswordError::InvalidParameter(err.msg))
+                    return RusotoError::service(
+                        ForgotPasswordError::InvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidSmsRoleAccessPolicyException" => {
-                    return RusotoError::Service(ForgotPasswordError::InvalidSmsRoleAccessPolicy(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ForgotPasswordError::InvalidSmsRoleAccessPolicy(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidSmsRoleTrustRelationshipException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         ForgotPasswordError::InvalidSmsRoleTrustRelationship(err.msg),
+                        &res,
                     )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(ForgotPasswordError::LimitExceeded(err.msg))
+                    return RusotoError::service(ForgotPasswordError::LimitExceeded(err.msg), &res)
                 }
                 "NotAuthorizedException" => {
-                    return RusotoError::Service(ForgotPasswordError::NotAuthorized(err.msg))
+                    return RusotoError::service(ForgotPasswordError::NotAuthorized(err.msg), &res)
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ForgotPasswordError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        ForgotPasswordError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(ForgotPasswordError::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        ForgotPasswordError::TooManyRequests(err.msg),
+                        &res,
+                    )
                 }
                 "UnexpectedLambdaException" => {
-                    return RusotoError::Service(ForgotPasswordError::UnexpectedLambda(err.msg))
+                    return RusotoError::service(
+                        ForgotPasswordError::UnexpectedLambda(err.msg),
+                        &res,
+                    )
                 }
                 "UserLambdaValidationException" => {
-                    return RusotoError::Service(ForgotPasswordError::UserLambdaValidation(err.msg))
+                    return RusotoError::service(
+                        ForgotPasswordError::UserLambdaValidation(err.msg),
+                        &res,
+                    )
                 }
                 "UserNotConfirmedException" => {
-                    return RusotoError::Service(ForgotPasswordError::UserNotConfirmed(err.msg))
+                    return RusotoError::service(
+                        ForgotPasswordError::UserNotConfirmed(err.msg),
+                        &res,
+                    )
                 }
                 "UserNotFoundException" => {
-                    return RusotoError::Service(ForgotPasswordError::UserNotFound(err.msg))
+                    return RusotoError::service(ForgotPasswordError::UserNotFound(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ForgotPasswordError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -8608,12 +9484,14 @@ impl fmt::Display for ForgotPasswordError {
             ForgotPasswordError::UserLambdaValidation(ref cause) => write!(f, "{}", cause),
             ForgotPasswordError::UserNotConfirmed(ref cause) => write!(f, "{}", cause),
             ForgotPasswordError::UserNotFound(ref cause) => write!(f, "{}", cause),
+            ForgotPasswordError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ForgotPasswordError {}
 /// Errors returned by GetCSVHeader
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetCSVHeaderError {
     /// <p>This exception is thrown when Amazon Cognito encounters an internal error.</p>
     InternalError(String),
@@ -8625,6 +9503,8 @@ pub enum GetCSVHeaderError {
     ResourceNotFound(String),
     /// <p>This exception is thrown when the user has made too many requests for a given operation.</p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetCSVHeaderError {
@@ -8632,22 +9512,22 @@ impl GetCSVHeaderError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalErrorException" => {
-                    return RusotoError::Service(GetCSVHeaderError::InternalError(err.msg))
+                    return RusotoError::service(GetCSVHeaderError::InternalError(err.msg), &res)
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(GetCSVHeaderError::InvalidParameter(err.msg))
+                    return RusotoError::service(GetCSVHeaderError::InvalidParameter(err.msg), &res)
                 }
                 "NotAuthorizedException" => {
-                    return RusotoError::Service(GetCSVHeaderError::NotAuthorized(err.msg))
+                    return RusotoError::service(GetCSVHeaderError::NotAuthorized(err.msg), &res)
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetCSVHeaderError::ResourceNotFound(err.msg))
+                    return RusotoError::service(GetCSVHeaderError::ResourceNotFound(err.msg), &res)
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(GetCSVHeaderError::TooManyRequests(err.msg))
+                    return RusotoError::service(GetCSVHeaderError::TooManyRequests(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetCSVHeaderError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -8662,12 +9542,14 @@ impl fmt::Display for GetCSVHeaderError {
             GetCSVHeaderError::NotAuthorized(ref cause) => write!(f, "{}", cause),
             GetCSVHeaderError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             GetCSVHeaderError::TooManyRequests(ref cause) => write!(f, "{}", cause),
+            GetCSVHeaderError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetCSVHeaderError {}
 /// Errors returned by GetDevice
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetDeviceError {
     /// <p>This exception is thrown when Amazon Cognito encounters an internal error.</p>
     InternalError(String),
@@ -8687,6 +9569,8 @@ pub enum GetDeviceError {
     UserNotConfirmed(String),
     /// <p>This exception is thrown when a user is not found.</p>
     UserNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetDeviceError {
@@ -8694,36 +9578,40 @@ impl GetDeviceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalErrorException" => {
-                    return RusotoError::Service(GetDeviceError::InternalError(err.msg))
+                    return RusotoError::service(GetDeviceError::InternalError(err.msg), &res)
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(GetDeviceError::InvalidParameter(err.msg))
+                    return RusotoError::service(GetDeviceError::InvalidParameter(err.msg), &res)
                 }
                 "InvalidUserPoolConfigurationException" => {
-                    return RusotoError::Service(GetDeviceError::InvalidUserPoolConfiguration(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetDeviceError::InvalidUserPoolConfiguration(err.msg),
+                        &res,
+                    )
                 }
                 "NotAuthorizedException" => {
-                    return RusotoError::Service(GetDeviceError::NotAuthorized(err.msg))
+                    return RusotoError::service(GetDeviceError::NotAuthorized(err.msg), &res)
                 }
                 "PasswordResetRequiredException" => {
-                    return RusotoError::Service(GetDeviceError::PasswordResetRequired(err.msg))
+                    return RusotoError::service(
+                        GetDeviceError::PasswordResetRequired(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetDeviceError::ResourceNotFound(err.msg))
+                    return RusotoError::service(GetDeviceError::ResourceNotFound(err.msg), &res)
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(GetDeviceError::TooManyRequests(err.msg))
+                    return RusotoError::service(GetDeviceError::TooManyRequests(err.msg), &res)
                 }
                 "UserNotConfirmedException" => {
-                    return RusotoError::Service(GetDeviceError::UserNotConfirmed(err.msg))
+                    return RusotoError::service(GetDeviceError::UserNotConfirmed(err.msg), &res)
                 }
                 "UserNotFoundException" => {
-                    return RusotoError::Service(GetDeviceError::UserNotFound(err.msg))
+                    return RusotoError::service(GetDeviceError::UserNotFound(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetDeviceError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -8742,12 +9630,14 @@ impl fmt::Display for GetDeviceError {
             GetDeviceError::TooManyRequests(ref cause) => write!(f, "{}", cause),
             GetDeviceError::UserNotConfirmed(ref cause) => write!(f, "{}", cause),
             GetDeviceError::UserNotFound(ref cause) => write!(f, "{}", cause),
+            GetDeviceError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetDeviceError {}
 /// Errors returned by GetGroup
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetGroupError {
     /// <p>This exception is thrown when Amazon Cognito encounters an internal error.</p>
     InternalError(String),
@@ -8759,6 +9649,8 @@ pub enum GetGroupError {
     ResourceNotFound(String),
     /// <p>This exception is thrown when the user has made too many requests for a given operation.</p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetGroupError {
@@ -8766,22 +9658,22 @@ impl GetGroupError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalErrorException" => {
-                    return RusotoError::Service(GetGroupError::InternalError(err.msg))
+                    return RusotoError::service(GetGroupError::InternalError(err.msg), &res)
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(GetGroupError::InvalidParameter(err.msg))
+                    return RusotoError::service(GetGroupError::InvalidParameter(err.msg), &res)
                 }
                 "NotAuthorizedException" => {
-                    return RusotoError::Service(GetGroupError::NotAuthorized(err.msg))
+                    return RusotoError::service(GetGroupError::NotAuthorized(err.msg), &res)
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetGroupError::ResourceNotFound(err.msg))
+                    return RusotoError::service(GetGroupError::ResourceNotFound(err.msg), &res)
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(GetGroupError::TooManyRequests(err.msg))
+                    return RusotoError::service(GetGroupError::TooManyRequests(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetGroupError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -8796,12 +9688,14 @@ impl fmt::Display for GetGroupError {
             GetGroupError::NotAuthorized(ref cause) => write!(f, "{}", cause),
             GetGroupError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             GetGroupError::TooManyRequests(ref cause) => write!(f, "{}", cause),
+            GetGroupError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetGroupError {}
 /// Errors returned by GetIdentityProviderByIdentifier
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetIdentityProviderByIdentifierError {
     /// <p>This exception is thrown when Amazon Cognito encounters an internal error.</p>
     InternalError(String),
@@ -8813,6 +9707,8 @@ pub enum GetIdentityProviderByIdentifierError {
     ResourceNotFound(String),
     /// <p>This exception is thrown when the user has made too many requests for a given operation.</p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetIdentityProviderByIdentifierError {
@@ -8822,32 +9718,42 @@ impl GetIdentityProviderByIdentifierError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalErrorException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         GetIdentityProviderByIdentifierError::InternalError(err.msg),
+                        &res,
                     )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         GetIdentityProviderByIdentifierError::InvalidParameter(err.msg),
+                        &res,
                     )
                 }
                 "NotAuthorizedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         GetIdentityProviderByIdentifierError::NotAuthorized(err.msg),
+                        &res,
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         GetIdentityProviderByIdentifierError::ResourceNotFound(err.msg),
+                        &res,
                     )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         GetIdentityProviderByIdentifierError::TooManyRequests(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        GetIdentityProviderByIdentifierError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -8872,12 +9778,16 @@ impl fmt::Display for GetIdentityProviderByIdentifierError {
             GetIdentityProviderByIdentifierError::TooManyRequests(ref cause) => {
                 write!(f, "{}", cause)
             }
+            GetIdentityProviderByIdentifierError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for GetIdentityProviderByIdentifierError {}
 /// Errors returned by GetSigningCertificate
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetSigningCertificateError {
     /// <p>This exception is thrown when Amazon Cognito encounters an internal error.</p>
     InternalError(String),
@@ -8885,6 +9795,8 @@ pub enum GetSigningCertificateError {
     InvalidParameter(String),
     /// <p>This exception is thrown when the Amazon Cognito service cannot find the requested resource.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetSigningCertificateError {
@@ -8892,20 +9804,25 @@ impl GetSigningCertificateError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalErrorException" => {
-                    return RusotoError::Service(GetSigningCertificateError::InternalError(err.msg))
+                    return RusotoError::service(
+                        GetSigningCertificateError::InternalError(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(GetSigningCertificateError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetSigningCertificateError::InvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetSigningCertificateError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetSigningCertificateError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetSigningCertificateError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -8918,12 +9835,14 @@ impl fmt::Display for GetSigningCertificateError {
             GetSigningCertificateError::InternalError(ref cause) => write!(f, "{}", cause),
             GetSigningCertificateError::InvalidParameter(ref cause) => write!(f, "{}", cause),
             GetSigningCertificateError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            GetSigningCertificateError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetSigningCertificateError {}
 /// Errors returned by GetUICustomization
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetUICustomizationError {
     /// <p>This exception is thrown when Amazon Cognito encounters an internal error.</p>
     InternalError(String),
@@ -8935,6 +9854,8 @@ pub enum GetUICustomizationError {
     ResourceNotFound(String),
     /// <p>This exception is thrown when the user has made too many requests for a given operation.</p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetUICustomizationError {
@@ -8942,22 +9863,37 @@ impl GetUICustomizationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalErrorException" => {
-                    return RusotoError::Service(GetUICustomizationError::InternalError(err.msg))
+                    return RusotoError::service(
+                        GetUICustomizationError::InternalError(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(GetUICustomizationError::InvalidParameter(err.msg))
+                    return RusotoError::service(
+                        GetUICustomizationError::InvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "NotAuthorizedException" => {
-                    return RusotoError::Service(GetUICustomizationError::NotAuthorized(err.msg))
+                    return RusotoError::service(
+                        GetUICustomizationError::NotAuthorized(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetUICustomizationError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        GetUICustomizationError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(GetUICustomizationError::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        GetUICustomizationError::TooManyRequests(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetUICustomizationError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -8972,12 +9908,14 @@ impl fmt::Display for GetUICustomizationError {
             GetUICustomizationError::NotAuthorized(ref cause) => write!(f, "{}", cause),
             GetUICustomizationError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             GetUICustomizationError::TooManyRequests(ref cause) => write!(f, "{}", cause),
+            GetUICustomizationError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetUICustomizationError {}
 /// Errors returned by GetUser
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetUserError {
     /// <p>This exception is thrown when Amazon Cognito encounters an internal error.</p>
     InternalError(String),
@@ -8995,6 +9933,8 @@ pub enum GetUserError {
     UserNotConfirmed(String),
     /// <p>This exception is thrown when a user is not found.</p>
     UserNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetUserError {
@@ -9002,31 +9942,31 @@ impl GetUserError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalErrorException" => {
-                    return RusotoError::Service(GetUserError::InternalError(err.msg))
+                    return RusotoError::service(GetUserError::InternalError(err.msg), &res)
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(GetUserError::InvalidParameter(err.msg))
+                    return RusotoError::service(GetUserError::InvalidParameter(err.msg), &res)
                 }
                 "NotAuthorizedException" => {
-                    return RusotoError::Service(GetUserError::NotAuthorized(err.msg))
+                    return RusotoError::service(GetUserError::NotAuthorized(err.msg), &res)
                 }
                 "PasswordResetRequiredException" => {
-                    return RusotoError::Service(GetUserError::PasswordResetRequired(err.msg))
+                    return RusotoError::service(GetUserError::PasswordResetRequired(err.msg), &res)
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetUserError::ResourceNotFound(err.msg))
+                    return RusotoError::service(GetUserError::ResourceNotFound(err.msg), &res)
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(GetUserError::TooManyRequests(err.msg))
+                    return RusotoError::service(GetUserError::TooManyRequests(err.msg), &res)
                 }
                 "UserNotConfirmedException" => {
-                    return RusotoError::Service(GetUserError::UserNotConfirmed(err.msg))
+                    return RusotoError::service(GetUserError::UserNotConfirmed(err.msg), &res)
                 }
                 "UserNotFoundException" => {
-                    return RusotoError::Service(GetUserError::UserNotFound(err.msg))
+                    return RusotoError::service(GetUserError::UserNotFound(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetUserError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -9044,12 +9984,14 @@ impl fmt::Display for GetUserError {
             GetUserError::TooManyRequests(ref cause) => write!(f, "{}", cause),
             GetUserError::UserNotConfirmed(ref cause) => write!(f, "{}", cause),
             GetUserError::UserNotFound(ref cause) => write!(f, "{}", cause),
+            GetUserError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetUserError {}
 /// Errors returned by GetUserAttributeVerificationCode
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetUserAttributeVerificationCodeError {
     /// <p>This exception is thrown when a verification code fails to deliver successfully.</p>
     CodeDeliveryFailure(String),
@@ -9083,6 +10025,8 @@ pub enum GetUserAttributeVerificationCodeError {
     UserNotConfirmed(String),
     /// <p>This exception is thrown when a user is not found.</p>
     UserNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetUserAttributeVerificationCodeError {
@@ -9092,91 +10036,112 @@ impl GetUserAttributeVerificationCodeError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "CodeDeliveryFailureException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         GetUserAttributeVerificationCodeError::CodeDeliveryFailure(err.msg),
+                        &res,
                     )
                 }
                 "InternalErrorException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         GetUserAttributeVerificationCodeError::InternalError(err.msg),
+                        &res,
                     )
                 }
                 "InvalidEmailRoleAccessPolicyException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         GetUserAttributeVerificationCodeError::InvalidEmailRoleAccessPolicy(
                             err.msg,
                         ),
+                        &res,
                     )
                 }
                 "InvalidLambdaResponseException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         GetUserAttributeVerificationCodeError::InvalidLambdaResponse(err.msg),
+                        &res,
                     )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         GetUserAttributeVerificationCodeError::InvalidParameter(err.msg),
+                        &res,
                     )
                 }
                 "InvalidSmsRoleAccessPolicyException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         GetUserAttributeVerificationCodeError::InvalidSmsRoleAccessPolicy(err.msg),
+                        &res,
                     )
                 }
                 "InvalidSmsRoleTrustRelationshipException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         GetUserAttributeVerificationCodeError::InvalidSmsRoleTrustRelationship(
                             err.msg,
                         ),
+                        &res,
                     )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         GetUserAttributeVerificationCodeError::LimitExceeded(err.msg),
+                        &res,
                     )
                 }
                 "NotAuthorizedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         GetUserAttributeVerificationCodeError::NotAuthorized(err.msg),
+                        &res,
                     )
                 }
                 "PasswordResetRequiredException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         GetUserAttributeVerificationCodeError::PasswordResetRequired(err.msg),
+                        &res,
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         GetUserAttributeVerificationCodeError::ResourceNotFound(err.msg),
+                        &res,
                     )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         GetUserAttributeVerificationCodeError::TooManyRequests(err.msg),
+                        &res,
                     )
                 }
                 "UnexpectedLambdaException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         GetUserAttributeVerificationCodeError::UnexpectedLambda(err.msg),
+                        &res,
                     )
                 }
                 "UserLambdaValidationException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         GetUserAttributeVerificationCodeError::UserLambdaValidation(err.msg),
+                        &res,
                     )
                 }
                 "UserNotConfirmedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         GetUserAttributeVerificationCodeError::UserNotConfirmed(err.msg),
+                        &res,
                     )
                 }
                 "UserNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         GetUserAttributeVerificationCodeError::UserNotFound(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        GetUserAttributeVerificationCodeError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -9234,12 +10199,16 @@ impl fmt::Display for GetUserAttributeVerificationCodeError {
             GetUserAttributeVerificationCodeError::UserNotFound(ref cause) => {
                 write!(f, "{}", cause)
             }
+            GetUserAttributeVerificationCodeError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for GetUserAttributeVerificationCodeError {}
 /// Errors returned by GetUserPoolMfaConfig
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetUserPoolMfaConfigError {
     /// <p>This exception is thrown when Amazon Cognito encounters an internal error.</p>
     InternalError(String),
@@ -9251,6 +10220,8 @@ pub enum GetUserPoolMfaConfigError {
     ResourceNotFound(String),
     /// <p>This exception is thrown when the user has made too many requests for a given operation.</p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetUserPoolMfaConfigError {
@@ -9258,28 +10229,37 @@ impl GetUserPoolMfaConfigError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalErrorException" => {
-                    return RusotoError::Service(GetUserPoolMfaConfigError::InternalError(err.msg))
+                    return RusotoError::service(
+                        GetUserPoolMfaConfigError::InternalError(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(GetUserPoolMfaConfigError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetUserPoolMfaConfigError::InvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "NotAuthorizedException" => {
-                    return RusotoError::Service(GetUserPoolMfaConfigError::NotAuthorized(err.msg))
+                    return RusotoError::service(
+                        GetUserPoolMfaConfigError::NotAuthorized(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetUserPoolMfaConfigError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetUserPoolMfaConfigError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(GetUserPoolMfaConfigError::TooManyRequests(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetUserPoolMfaConfigError::TooManyRequests(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetUserPoolMfaConfigError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -9294,12 +10274,14 @@ impl fmt::Display for GetUserPoolMfaConfigError {
             GetUserPoolMfaConfigError::NotAuthorized(ref cause) => write!(f, "{}", cause),
             GetUserPoolMfaConfigError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             GetUserPoolMfaConfigError::TooManyRequests(ref cause) => write!(f, "{}", cause),
+            GetUserPoolMfaConfigError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetUserPoolMfaConfigError {}
 /// Errors returned by GlobalSignOut
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GlobalSignOutError {
     /// <p>This exception is thrown when Amazon Cognito encounters an internal error.</p>
     InternalError(String),
@@ -9315,6 +10297,8 @@ pub enum GlobalSignOutError {
     TooManyRequests(String),
     /// <p>This exception is thrown when a user is not confirmed successfully.</p>
     UserNotConfirmed(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GlobalSignOutError {
@@ -9322,28 +10306,40 @@ impl GlobalSignOutError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalErrorException" => {
-                    return RusotoError::Service(GlobalSignOutError::InternalError(err.msg))
+                    return RusotoError::service(GlobalSignOutError::InternalError(err.msg), &res)
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(GlobalSignOutError::InvalidParameter(err.msg))
+                    return RusotoError::service(
+                        GlobalSignOutError::InvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "NotAuthorizedException" => {
-                    return RusotoError::Service(GlobalSignOutError::NotAuthorized(err.msg))
+                    return RusotoError::service(GlobalSignOutError::NotAuthorized(err.msg), &res)
                 }
                 "PasswordResetRequiredException" => {
-                    return RusotoError::Service(GlobalSignOutError::PasswordResetRequired(err.msg))
+                    return RusotoError::service(
+                        GlobalSignOutError::PasswordResetRequired(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GlobalSignOutError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        GlobalSignOutError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(GlobalSignOutError::TooManyRequests(err.msg))
+                    return RusotoError::service(GlobalSignOutError::TooManyRequests(err.msg), &res)
                 }
                 "UserNotConfirmedException" => {
-                    return RusotoError::Service(GlobalSignOutError::UserNotConfirmed(err.msg))
+                    return RusotoError::service(
+                        GlobalSignOutError::UserNotConfirmed(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GlobalSignOutError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -9360,12 +10356,14 @@ impl fmt::Display for GlobalSignOutError {
             GlobalSignOutError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             GlobalSignOutError::TooManyRequests(ref cause) => write!(f, "{}", cause),
             GlobalSignOutError::UserNotConfirmed(ref cause) => write!(f, "{}", cause),
+            GlobalSignOutError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GlobalSignOutError {}
 /// Errors returned by InitiateAuth
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum InitiateAuthError {
     /// <p>This exception is thrown when Amazon Cognito encounters an internal error.</p>
     InternalError(String),
@@ -9395,6 +10393,8 @@ pub enum InitiateAuthError {
     UserNotConfirmed(String),
     /// <p>This exception is thrown when a user is not found.</p>
     UserNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl InitiateAuthError {
@@ -9402,55 +10402,67 @@ impl InitiateAuthError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalErrorException" => {
-                    return RusotoError::Service(InitiateAuthError::InternalError(err.msg))
+                    return RusotoError::service(InitiateAuthError::InternalError(err.msg), &res)
                 }
                 "InvalidLambdaResponseException" => {
-                    return RusotoError::Service(InitiateAuthError::InvalidLambdaResponse(err.msg))
+                    return RusotoError::service(
+                        InitiateAuthError::InvalidLambdaResponse(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(InitiateAuthError::InvalidParameter(err.msg))
+                    return RusotoError::service(InitiateAuthError::InvalidParameter(err.msg), &res)
                 }
                 "InvalidSmsRoleAccessPolicyException" => {
-                    return RusotoError::Service(InitiateAuthError::InvalidSmsRoleAccessPolicy(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        InitiateAuthError::InvalidSmsRoleAccessPolicy(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidSmsRoleTrustRelationshipException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         InitiateAuthError::InvalidSmsRoleTrustRelationship(err.msg),
+                        &res,
                     )
                 }
                 "InvalidUserPoolConfigurationException" => {
-                    return RusotoError::Service(InitiateAuthError::InvalidUserPoolConfiguration(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        InitiateAuthError::InvalidUserPoolConfiguration(err.msg),
+                        &res,
+                    )
                 }
                 "NotAuthorizedException" => {
-                    return RusotoError::Service(InitiateAuthError::NotAuthorized(err.msg))
+                    return RusotoError::service(InitiateAuthError::NotAuthorized(err.msg), &res)
                 }
                 "PasswordResetRequiredException" => {
-                    return RusotoError::Service(InitiateAuthError::PasswordResetRequired(err.msg))
+                    return RusotoError::service(
+                        InitiateAuthError::PasswordResetRequired(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(InitiateAuthError::ResourceNotFound(err.msg))
+                    return RusotoError::service(InitiateAuthError::ResourceNotFound(err.msg), &res)
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(InitiateAuthError::TooManyRequests(err.msg))
+                    return RusotoError::service(InitiateAuthError::TooManyRequests(err.msg), &res)
                 }
                 "UnexpectedLambdaException" => {
-                    return RusotoError::Service(InitiateAuthError::UnexpectedLambda(err.msg))
+                    return RusotoError::service(InitiateAuthError::UnexpectedLambda(err.msg), &res)
                 }
                 "UserLambdaValidationException" => {
-                    return RusotoError::Service(InitiateAuthError::UserLambdaValidation(err.msg))
+                    return RusotoError::service(
+                        InitiateAuthError::UserLambdaValidation(err.msg),
+                        &res,
+                    )
                 }
                 "UserNotConfirmedException" => {
-                    return RusotoError::Service(InitiateAuthError::UserNotConfirmed(err.msg))
+                    return RusotoError::service(InitiateAuthError::UserNotConfirmed(err.msg), &res)
                 }
                 "UserNotFoundException" => {
-                    return RusotoError::Service(InitiateAuthError::UserNotFound(err.msg))
+                    return RusotoError::service(InitiateAuthError::UserNotFound(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(InitiateAuthError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -9474,12 +10486,14 @@ impl fmt::Display for InitiateAuthError {
             InitiateAuthError::UserLambdaValidation(ref cause) => write!(f, "{}", cause),
             InitiateAuthError::UserNotConfirmed(ref cause) => write!(f, "{}", cause),
             InitiateAuthError::UserNotFound(ref cause) => write!(f, "{}", cause),
+            InitiateAuthError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for InitiateAuthError {}
 /// Errors returned by ListDevices
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListDevicesError {
     /// <p>This exception is thrown when Amazon Cognito encounters an internal error.</p>
     InternalError(String),
@@ -9499,6 +10513,8 @@ pub enum ListDevicesError {
     UserNotConfirmed(String),
     /// <p>This exception is thrown when a user is not found.</p>
     UserNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListDevicesError {
@@ -9506,36 +10522,40 @@ impl ListDevicesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalErrorException" => {
-                    return RusotoError::Service(ListDevicesError::InternalError(err.msg))
+                    return RusotoError::service(ListDevicesError::InternalError(err.msg), &res)
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(ListDevicesError::InvalidParameter(err.msg))
+                    return RusotoError::service(ListDevicesError::InvalidParameter(err.msg), &res)
                 }
                 "InvalidUserPoolConfigurationException" => {
-                    return RusotoError::Service(ListDevicesError::InvalidUserPoolConfiguration(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListDevicesError::InvalidUserPoolConfiguration(err.msg),
+                        &res,
+                    )
                 }
                 "NotAuthorizedException" => {
-                    return RusotoError::Service(ListDevicesError::NotAuthorized(err.msg))
+                    return RusotoError::service(ListDevicesError::NotAuthorized(err.msg), &res)
                 }
                 "PasswordResetRequiredException" => {
-                    return RusotoError::Service(ListDevicesError::PasswordResetRequired(err.msg))
+                    return RusotoError::service(
+                        ListDevicesError::PasswordResetRequired(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListDevicesError::ResourceNotFound(err.msg))
+                    return RusotoError::service(ListDevicesError::ResourceNotFound(err.msg), &res)
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(ListDevicesError::TooManyRequests(err.msg))
+                    return RusotoError::service(ListDevicesError::TooManyRequests(err.msg), &res)
                 }
                 "UserNotConfirmedException" => {
-                    return RusotoError::Service(ListDevicesError::UserNotConfirmed(err.msg))
+                    return RusotoError::service(ListDevicesError::UserNotConfirmed(err.msg), &res)
                 }
                 "UserNotFoundException" => {
-                    return RusotoError::Service(ListDevicesError::UserNotFound(err.msg))
+                    return RusotoError::service(ListDevicesError::UserNotFound(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListDevicesError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -9554,12 +10574,14 @@ impl fmt::Display for ListDevicesError {
             ListDevicesError::TooManyRequests(ref cause) => write!(f, "{}", cause),
             ListDevicesError::UserNotConfirmed(ref cause) => write!(f, "{}", cause),
             ListDevicesError::UserNotFound(ref cause) => write!(f, "{}", cause),
+            ListDevicesError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListDevicesError {}
 /// Errors returned by ListGroups
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListGroupsError {
     /// <p>This exception is thrown when Amazon Cognito encounters an internal error.</p>
     InternalError(String),
@@ -9571,6 +10593,8 @@ pub enum ListGroupsError {
     ResourceNotFound(String),
     /// <p>This exception is thrown when the user has made too many requests for a given operation.</p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListGroupsError {
@@ -9578,22 +10602,22 @@ impl ListGroupsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalErrorException" => {
-                    return RusotoError::Service(ListGroupsError::InternalError(err.msg))
+                    return RusotoError::service(ListGroupsError::InternalError(err.msg), &res)
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(ListGroupsError::InvalidParameter(err.msg))
+                    return RusotoError::service(ListGroupsError::InvalidParameter(err.msg), &res)
                 }
                 "NotAuthorizedException" => {
-                    return RusotoError::Service(ListGroupsError::NotAuthorized(err.msg))
+                    return RusotoError::service(ListGroupsError::NotAuthorized(err.msg), &res)
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListGroupsError::ResourceNotFound(err.msg))
+                    return RusotoError::service(ListGroupsError::ResourceNotFound(err.msg), &res)
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(ListGroupsError::TooManyRequests(err.msg))
+                    return RusotoError::service(ListGroupsError::TooManyRequests(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListGroupsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -9608,12 +10632,14 @@ impl fmt::Display for ListGroupsError {
             ListGroupsError::NotAuthorized(ref cause) => write!(f, "{}", cause),
             ListGroupsError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             ListGroupsError::TooManyRequests(ref cause) => write!(f, "{}", cause),
+            ListGroupsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListGroupsError {}
 /// Errors returned by ListIdentityProviders
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListIdentityProvidersError {
     /// <p>This exception is thrown when Amazon Cognito encounters an internal error.</p>
     InternalError(String),
@@ -9625,6 +10651,8 @@ pub enum ListIdentityProvidersError {
     ResourceNotFound(String),
     /// <p>This exception is thrown when the user has made too many requests for a given operation.</p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListIdentityProvidersError {
@@ -9632,28 +10660,37 @@ impl ListIdentityProvidersError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalErrorException" => {
-                    return RusotoError::Service(ListIdentityProvidersError::InternalError(err.msg))
+                    return RusotoError::service(
+                        ListIdentityProvidersError::InternalError(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(ListIdentityProvidersError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListIdentityProvidersError::InvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "NotAuthorizedException" => {
-                    return RusotoError::Service(ListIdentityProvidersError::NotAuthorized(err.msg))
+                    return RusotoError::service(
+                        ListIdentityProvidersError::NotAuthorized(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListIdentityProvidersError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListIdentityProvidersError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(ListIdentityProvidersError::TooManyRequests(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListIdentityProvidersError::TooManyRequests(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListIdentityProvidersError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -9668,12 +10705,14 @@ impl fmt::Display for ListIdentityProvidersError {
             ListIdentityProvidersError::NotAuthorized(ref cause) => write!(f, "{}", cause),
             ListIdentityProvidersError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             ListIdentityProvidersError::TooManyRequests(ref cause) => write!(f, "{}", cause),
+            ListIdentityProvidersError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListIdentityProvidersError {}
 /// Errors returned by ListResourceServers
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListResourceServersError {
     /// <p>This exception is thrown when Amazon Cognito encounters an internal error.</p>
     InternalError(String),
@@ -9685,6 +10724,8 @@ pub enum ListResourceServersError {
     ResourceNotFound(String),
     /// <p>This exception is thrown when the user has made too many requests for a given operation.</p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListResourceServersError {
@@ -9692,26 +10733,37 @@ impl ListResourceServersError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalErrorException" => {
-                    return RusotoError::Service(ListResourceServersError::InternalError(err.msg))
+                    return RusotoError::service(
+                        ListResourceServersError::InternalError(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(ListResourceServersError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListResourceServersError::InvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "NotAuthorizedException" => {
-                    return RusotoError::Service(ListResourceServersError::NotAuthorized(err.msg))
+                    return RusotoError::service(
+                        ListResourceServersError::NotAuthorized(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListResourceServersError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListResourceServersError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(ListResourceServersError::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        ListResourceServersError::TooManyRequests(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListResourceServersError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -9726,12 +10778,14 @@ impl fmt::Display for ListResourceServersError {
             ListResourceServersError::NotAuthorized(ref cause) => write!(f, "{}", cause),
             ListResourceServersError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             ListResourceServersError::TooManyRequests(ref cause) => write!(f, "{}", cause),
+            ListResourceServersError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListResourceServersError {}
 /// Errors returned by ListTagsForResource
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListTagsForResourceError {
     /// <p>This exception is thrown when Amazon Cognito encounters an internal error.</p>
     InternalError(String),
@@ -9743,6 +10797,8 @@ pub enum ListTagsForResourceError {
     ResourceNotFound(String),
     /// <p>This exception is thrown when the user has made too many requests for a given operation.</p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListTagsForResourceError {
@@ -9750,26 +10806,37 @@ impl ListTagsForResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalErrorException" => {
-                    return RusotoError::Service(ListTagsForResourceError::InternalError(err.msg))
+                    return RusotoError::service(
+                        ListTagsForResourceError::InternalError(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(ListTagsForResourceError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListTagsForResourceError::InvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "NotAuthorizedException" => {
-                    return RusotoError::Service(ListTagsForResourceError::NotAuthorized(err.msg))
+                    return RusotoError::service(
+                        ListTagsForResourceError::NotAuthorized(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListTagsForResourceError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListTagsForResourceError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(ListTagsForResourceError::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        ListTagsForResourceError::TooManyRequests(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListTagsForResourceError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -9784,12 +10851,14 @@ impl fmt::Display for ListTagsForResourceError {
             ListTagsForResourceError::NotAuthorized(ref cause) => write!(f, "{}", cause),
             ListTagsForResourceError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             ListTagsForResourceError::TooManyRequests(ref cause) => write!(f, "{}", cause),
+            ListTagsForResourceError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListTagsForResourceError {}
 /// Errors returned by ListUserImportJobs
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListUserImportJobsError {
     /// <p>This exception is thrown when Amazon Cognito encounters an internal error.</p>
     InternalError(String),
@@ -9801,6 +10870,8 @@ pub enum ListUserImportJobsError {
     ResourceNotFound(String),
     /// <p>This exception is thrown when the user has made too many requests for a given operation.</p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListUserImportJobsError {
@@ -9808,22 +10879,37 @@ impl ListUserImportJobsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalErrorException" => {
-                    return RusotoError::Service(ListUserImportJobsError::InternalError(err.msg))
+                    return RusotoError::service(
+                        ListUserImportJobsError::InternalError(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(ListUserImportJobsError::InvalidParameter(err.msg))
+                    return RusotoError::service(
+                        ListUserImportJobsError::InvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "NotAuthorizedException" => {
-                    return RusotoError::Service(ListUserImportJobsError::NotAuthorized(err.msg))
+                    return RusotoError::service(
+                        ListUserImportJobsError::NotAuthorized(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListUserImportJobsError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        ListUserImportJobsError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(ListUserImportJobsError::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        ListUserImportJobsError::TooManyRequests(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListUserImportJobsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -9838,12 +10924,14 @@ impl fmt::Display for ListUserImportJobsError {
             ListUserImportJobsError::NotAuthorized(ref cause) => write!(f, "{}", cause),
             ListUserImportJobsError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             ListUserImportJobsError::TooManyRequests(ref cause) => write!(f, "{}", cause),
+            ListUserImportJobsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListUserImportJobsError {}
 /// Errors returned by ListUserPoolClients
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListUserPoolClientsError {
     /// <p>This exception is thrown when Amazon Cognito encounters an internal error.</p>
     InternalError(String),
@@ -9855,6 +10943,8 @@ pub enum ListUserPoolClientsError {
     ResourceNotFound(String),
     /// <p>This exception is thrown when the user has made too many requests for a given operation.</p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListUserPoolClientsError {
@@ -9862,26 +10952,37 @@ impl ListUserPoolClientsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalErrorException" => {
-                    return RusotoError::Service(ListUserPoolClientsError::InternalError(err.msg))
+                    return RusotoError::service(
+                        ListUserPoolClientsError::InternalError(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(ListUserPoolClientsError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListUserPoolClientsError::InvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "NotAuthorizedException" => {
-                    return RusotoError::Service(ListUserPoolClientsError::NotAuthorized(err.msg))
+                    return RusotoError::service(
+                        ListUserPoolClientsError::NotAuthorized(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListUserPoolClientsError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListUserPoolClientsError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(ListUserPoolClientsError::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        ListUserPoolClientsError::TooManyRequests(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListUserPoolClientsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -9896,12 +10997,14 @@ impl fmt::Display for ListUserPoolClientsError {
             ListUserPoolClientsError::NotAuthorized(ref cause) => write!(f, "{}", cause),
             ListUserPoolClientsError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             ListUserPoolClientsError::TooManyRequests(ref cause) => write!(f, "{}", cause),
+            ListUserPoolClientsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListUserPoolClientsError {}
 /// Errors returned by ListUserPools
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListUserPoolsError {
     /// <p>This exception is thrown when Amazon Cognito encounters an internal error.</p>
     InternalError(String),
@@ -9911,6 +11014,8 @@ pub enum ListUserPoolsError {
     NotAuthorized(String),
     /// <p>This exception is thrown when the user has made too many requests for a given operation.</p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListUserPoolsError {
@@ -9918,19 +11023,22 @@ impl ListUserPoolsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalErrorException" => {
-                    return RusotoError::Service(ListUserPoolsError::InternalError(err.msg))
+                    return RusotoError::service(ListUserPoolsError::InternalError(err.msg), &res)
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(ListUserPoolsError::InvalidParameter(err.msg))
+                    return RusotoError::service(
+                        ListUserPoolsError::InvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "NotAuthorizedException" => {
-                    return RusotoError::Service(ListUserPoolsError::NotAuthorized(err.msg))
+                    return RusotoError::service(ListUserPoolsError::NotAuthorized(err.msg), &res)
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(ListUserPoolsError::TooManyRequests(err.msg))
+                    return RusotoError::service(ListUserPoolsError::TooManyRequests(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListUserPoolsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -9944,12 +11052,14 @@ impl fmt::Display for ListUserPoolsError {
             ListUserPoolsError::InvalidParameter(ref cause) => write!(f, "{}", cause),
             ListUserPoolsError::NotAuthorized(ref cause) => write!(f, "{}", cause),
             ListUserPoolsError::TooManyRequests(ref cause) => write!(f, "{}", cause),
+            ListUserPoolsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListUserPoolsError {}
 /// Errors returned by ListUsers
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListUsersError {
     /// <p>This exception is thrown when Amazon Cognito encounters an internal error.</p>
     InternalError(String),
@@ -9961,6 +11071,8 @@ pub enum ListUsersError {
     ResourceNotFound(String),
     /// <p>This exception is thrown when the user has made too many requests for a given operation.</p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListUsersError {
@@ -9968,22 +11080,22 @@ impl ListUsersError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalErrorException" => {
-                    return RusotoError::Service(ListUsersError::InternalError(err.msg))
+                    return RusotoError::service(ListUsersError::InternalError(err.msg), &res)
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(ListUsersError::InvalidParameter(err.msg))
+                    return RusotoError::service(ListUsersError::InvalidParameter(err.msg), &res)
                 }
                 "NotAuthorizedException" => {
-                    return RusotoError::Service(ListUsersError::NotAuthorized(err.msg))
+                    return RusotoError::service(ListUsersError::NotAuthorized(err.msg), &res)
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListUsersError::ResourceNotFound(err.msg))
+                    return RusotoError::service(ListUsersError::ResourceNotFound(err.msg), &res)
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(ListUsersError::TooManyRequests(err.msg))
+                    return RusotoError::service(ListUsersError::TooManyRequests(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListUsersError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -9998,12 +11110,14 @@ impl fmt::Display for ListUsersError {
             ListUsersError::NotAuthorized(ref cause) => write!(f, "{}", cause),
             ListUsersError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             ListUsersError::TooManyRequests(ref cause) => write!(f, "{}", cause),
+            ListUsersError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListUsersError {}
 /// Errors returned by ListUsersInGroup
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListUsersInGroupError {
     /// <p>This exception is thrown when Amazon Cognito encounters an internal error.</p>
     InternalError(String),
@@ -10015,6 +11129,8 @@ pub enum ListUsersInGroupError {
     ResourceNotFound(String),
     /// <p>This exception is thrown when the user has made too many requests for a given operation.</p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListUsersInGroupError {
@@ -10022,22 +11138,37 @@ impl ListUsersInGroupError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalErrorException" => {
-                    return RusotoError::Service(ListUsersInGroupError::InternalError(err.msg))
+                    return RusotoError::service(
+                        ListUsersInGroupError::InternalError(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(ListUsersInGroupError::InvalidParameter(err.msg))
+                    return RusotoError::service(
+                        ListUsersInGroupError::InvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "NotAuthorizedException" => {
-                    return RusotoError::Service(ListUsersInGroupError::NotAuthorized(err.msg))
+                    return RusotoError::service(
+                        ListUsersInGroupError::NotAuthorized(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListUsersInGroupError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        ListUsersInGroupError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(ListUsersInGroupError::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        ListUsersInGroupError::TooManyRequests(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListUsersInGroupError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -10052,12 +11183,14 @@ impl fmt::Display for ListUsersInGroupError {
             ListUsersInGroupError::NotAuthorized(ref cause) => write!(f, "{}", cause),
             ListUsersInGroupError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             ListUsersInGroupError::TooManyRequests(ref cause) => write!(f, "{}", cause),
+            ListUsersInGroupError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListUsersInGroupError {}
 /// Errors returned by ResendConfirmationCode
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ResendConfirmationCodeError {
     /// <p>This exception is thrown when a verification code fails to deliver successfully.</p>
     CodeDeliveryFailure(String),
@@ -10087,6 +11220,8 @@ pub enum ResendConfirmationCodeError {
     UserLambdaValidation(String),
     /// <p>This exception is thrown when a user is not found.</p>
     UserNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ResendConfirmationCodeError {
@@ -10094,75 +11229,91 @@ impl ResendConfirmationCodeError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "CodeDeliveryFailureException" => {
-                    return RusotoError::Service(ResendConfirmationCodeError::CodeDeliveryFailure(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ResendConfirmationCodeError::CodeDeliveryFailure(err.msg),
+                        &res,
+                    )
                 }
                 "InternalErrorException" => {
-                    return RusotoError::Service(ResendConfirmationCodeError::InternalError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ResendConfirmationCodeError::InternalError(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidEmailRoleAccessPolicyException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         ResendConfirmationCodeError::InvalidEmailRoleAccessPolicy(err.msg),
+                        &res,
                     )
                 }
                 "InvalidLambdaResponseException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         ResendConfirmationCodeError::InvalidLambdaResponse(err.msg),
+                        &res,
                     )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(ResendConfirmationCodeError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ResendConfirmationCodeError::InvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidSmsRoleAccessPolicyException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         ResendConfirmationCodeError::InvalidSmsRoleAccessPolicy(err.msg),
+                        &res,
                     )
                 }
                 "InvalidSmsRoleTrustRelationshipException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         ResendConfirmationCodeError::InvalidSmsRoleTrustRelationship(err.msg),
+                        &res,
                     )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(ResendConfirmationCodeError::LimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ResendConfirmationCodeError::LimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "NotAuthorizedException" => {
-                    return RusotoError::Service(ResendConfirmationCodeError::NotAuthorized(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ResendConfirmationCodeError::NotAuthorized(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ResendConfirmationCodeError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ResendConfirmationCodeError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(ResendConfirmationCodeError::TooManyRequests(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ResendConfirmationCodeError::TooManyRequests(err.msg),
+                        &res,
+                    )
                 }
                 "UnexpectedLambdaException" => {
-                    return RusotoError::Service(ResendConfirmationCodeError::UnexpectedLambda(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ResendConfirmationCodeError::UnexpectedLambda(err.msg),
+                        &res,
+                    )
                 }
                 "UserLambdaValidationException" => {
-                    return RusotoError::Service(ResendConfirmationCodeError::UserLambdaValidation(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ResendConfirmationCodeError::UserLambdaValidation(err.msg),
+                        &res,
+                    )
                 }
                 "UserNotFoundException" => {
-                    return RusotoError::Service(ResendConfirmationCodeError::UserNotFound(err.msg))
+                    return RusotoError::service(
+                        ResendConfirmationCodeError::UserNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ResendConfirmationCodeError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -10192,12 +11343,14 @@ impl fmt::Display for ResendConfirmationCodeError {
             ResendConfirmationCodeError::UnexpectedLambda(ref cause) => write!(f, "{}", cause),
             ResendConfirmationCodeError::UserLambdaValidation(ref cause) => write!(f, "{}", cause),
             ResendConfirmationCodeError::UserNotFound(ref cause) => write!(f, "{}", cause),
+            ResendConfirmationCodeError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ResendConfirmationCodeError {}
 /// Errors returned by RespondToAuthChallenge
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum RespondToAuthChallengeError {
     /// <p>This exception is thrown when a user tries to confirm the account with an email or phone number that has already been supplied as an alias from a different account. This exception tells user that an account with this email or phone already exists.</p>
     AliasExists(String),
@@ -10239,6 +11392,8 @@ pub enum RespondToAuthChallengeError {
     UserNotConfirmed(String),
     /// <p>This exception is thrown when a user is not found.</p>
     UserNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl RespondToAuthChallengeError {
@@ -10246,99 +11401,127 @@ impl RespondToAuthChallengeError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AliasExistsException" => {
-                    return RusotoError::Service(RespondToAuthChallengeError::AliasExists(err.msg))
+                    return RusotoError::service(
+                        RespondToAuthChallengeError::AliasExists(err.msg),
+                        &res,
+                    )
                 }
                 "CodeMismatchException" => {
-                    return RusotoError::Service(RespondToAuthChallengeError::CodeMismatch(err.msg))
+                    return RusotoError::service(
+                        RespondToAuthChallengeError::CodeMismatch(err.msg),
+                        &res,
+                    )
                 }
                 "ExpiredCodeException" => {
-                    return RusotoError::Service(RespondToAuthChallengeError::ExpiredCode(err.msg))
+                    return RusotoError::service(
+                        RespondToAuthChallengeError::ExpiredCode(err.msg),
+                        &res,
+                    )
                 }
                 "InternalErrorException" => {
-                    return RusotoError::Service(RespondToAuthChallengeError::InternalError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        RespondToAuthChallengeError::InternalError(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidLambdaResponseException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         RespondToAuthChallengeError::InvalidLambdaResponse(err.msg),
+                        &res,
                     )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(RespondToAuthChallengeError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        RespondToAuthChallengeError::InvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidPasswordException" => {
-                    return RusotoError::Service(RespondToAuthChallengeError::InvalidPassword(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        RespondToAuthChallengeError::InvalidPassword(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidSmsRoleAccessPolicyException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         RespondToAuthChallengeError::InvalidSmsRoleAccessPolicy(err.msg),
+                        &res,
                     )
                 }
                 "InvalidSmsRoleTrustRelationshipException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         RespondToAuthChallengeError::InvalidSmsRoleTrustRelationship(err.msg),
+                        &res,
                     )
                 }
                 "InvalidUserPoolConfigurationException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         RespondToAuthChallengeError::InvalidUserPoolConfiguration(err.msg),
+                        &res,
                     )
                 }
                 "MFAMethodNotFoundException" => {
-                    return RusotoError::Service(RespondToAuthChallengeError::MFAMethodNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        RespondToAuthChallengeError::MFAMethodNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "NotAuthorizedException" => {
-                    return RusotoError::Service(RespondToAuthChallengeError::NotAuthorized(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        RespondToAuthChallengeError::NotAuthorized(err.msg),
+                        &res,
+                    )
                 }
                 "PasswordResetRequiredException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         RespondToAuthChallengeError::PasswordResetRequired(err.msg),
+                        &res,
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(RespondToAuthChallengeError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        RespondToAuthChallengeError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "SoftwareTokenMFANotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         RespondToAuthChallengeError::SoftwareTokenMFANotFound(err.msg),
+                        &res,
                     )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(RespondToAuthChallengeError::TooManyRequests(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        RespondToAuthChallengeError::TooManyRequests(err.msg),
+                        &res,
+                    )
                 }
                 "UnexpectedLambdaException" => {
-                    return RusotoError::Service(RespondToAuthChallengeError::UnexpectedLambda(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        RespondToAuthChallengeError::UnexpectedLambda(err.msg),
+                        &res,
+                    )
                 }
                 "UserLambdaValidationException" => {
-                    return RusotoError::Service(RespondToAuthChallengeError::UserLambdaValidation(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        RespondToAuthChallengeError::UserLambdaValidation(err.msg),
+                        &res,
+                    )
                 }
                 "UserNotConfirmedException" => {
-                    return RusotoError::Service(RespondToAuthChallengeError::UserNotConfirmed(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        RespondToAuthChallengeError::UserNotConfirmed(err.msg),
+                        &res,
+                    )
                 }
                 "UserNotFoundException" => {
-                    return RusotoError::Service(RespondToAuthChallengeError::UserNotFound(err.msg))
+                    return RusotoError::service(
+                        RespondToAuthChallengeError::UserNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(RespondToAuthChallengeError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -10376,12 +11559,14 @@ impl fmt::Display for RespondToAuthChallengeError {
             RespondToAuthChallengeError::UserLambdaValidation(ref cause) => write!(f, "{}", cause),
             RespondToAuthChallengeError::UserNotConfirmed(ref cause) => write!(f, "{}", cause),
             RespondToAuthChallengeError::UserNotFound(ref cause) => write!(f, "{}", cause),
+            RespondToAuthChallengeError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for RespondToAuthChallengeError {}
 /// Errors returned by SetRiskConfiguration
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum SetRiskConfigurationError {
     /// <p>This exception is thrown when a verification code fails to deliver successfully.</p>
     CodeDeliveryFailure(String),
@@ -10399,6 +11584,8 @@ pub enum SetRiskConfigurationError {
     TooManyRequests(String),
     /// <p>This exception is thrown when user pool add-ons are not enabled.</p>
     UserPoolAddOnNotEnabled(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl SetRiskConfigurationError {
@@ -10406,43 +11593,55 @@ impl SetRiskConfigurationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "CodeDeliveryFailureException" => {
-                    return RusotoError::Service(SetRiskConfigurationError::CodeDeliveryFailure(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        SetRiskConfigurationError::CodeDeliveryFailure(err.msg),
+                        &res,
+                    )
                 }
                 "InternalErrorException" => {
-                    return RusotoError::Service(SetRiskConfigurationError::InternalError(err.msg))
+                    return RusotoError::service(
+                        SetRiskConfigurationError::InternalError(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidEmailRoleAccessPolicyException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         SetRiskConfigurationError::InvalidEmailRoleAccessPolicy(err.msg),
+                        &res,
                     )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(SetRiskConfigurationError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        SetRiskConfigurationError::InvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "NotAuthorizedException" => {
-                    return RusotoError::Service(SetRiskConfigurationError::NotAuthorized(err.msg))
+                    return RusotoError::service(
+                        SetRiskConfigurationError::NotAuthorized(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(SetRiskConfigurationError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        SetRiskConfigurationError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(SetRiskConfigurationError::TooManyRequests(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        SetRiskConfigurationError::TooManyRequests(err.msg),
+                        &res,
+                    )
                 }
                 "UserPoolAddOnNotEnabledException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         SetRiskConfigurationError::UserPoolAddOnNotEnabled(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(SetRiskConfigurationError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -10462,12 +11661,14 @@ impl fmt::Display for SetRiskConfigurationError {
             SetRiskConfigurationError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             SetRiskConfigurationError::TooManyRequests(ref cause) => write!(f, "{}", cause),
             SetRiskConfigurationError::UserPoolAddOnNotEnabled(ref cause) => write!(f, "{}", cause),
+            SetRiskConfigurationError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for SetRiskConfigurationError {}
 /// Errors returned by SetUICustomization
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum SetUICustomizationError {
     /// <p>This exception is thrown when Amazon Cognito encounters an internal error.</p>
     InternalError(String),
@@ -10479,6 +11680,8 @@ pub enum SetUICustomizationError {
     ResourceNotFound(String),
     /// <p>This exception is thrown when the user has made too many requests for a given operation.</p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl SetUICustomizationError {
@@ -10486,22 +11689,37 @@ impl SetUICustomizationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalErrorException" => {
-                    return RusotoError::Service(SetUICustomizationError::InternalError(err.msg))
+                    return RusotoError::service(
+                        SetUICustomizationError::InternalError(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(SetUICustomizationError::InvalidParameter(err.msg))
+                    return RusotoError::service(
+                        SetUICustomizationError::InvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "NotAuthorizedException" => {
-                    return RusotoError::Service(SetUICustomizationError::NotAuthorized(err.msg))
+                    return RusotoError::service(
+                        SetUICustomizationError::NotAuthorized(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(SetUICustomizationError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        SetUICustomizationError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(SetUICustomizationError::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        SetUICustomizationError::TooManyRequests(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(SetUICustomizationError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -10516,12 +11734,14 @@ impl fmt::Display for SetUICustomizationError {
             SetUICustomizationError::NotAuthorized(ref cause) => write!(f, "{}", cause),
             SetUICustomizationError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             SetUICustomizationError::TooManyRequests(ref cause) => write!(f, "{}", cause),
+            SetUICustomizationError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for SetUICustomizationError {}
 /// Errors returned by SetUserMFAPreference
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum SetUserMFAPreferenceError {
     /// <p>This exception is thrown when Amazon Cognito encounters an internal error.</p>
     InternalError(String),
@@ -10537,6 +11757,8 @@ pub enum SetUserMFAPreferenceError {
     UserNotConfirmed(String),
     /// <p>This exception is thrown when a user is not found.</p>
     UserNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl SetUserMFAPreferenceError {
@@ -10544,36 +11766,49 @@ impl SetUserMFAPreferenceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalErrorException" => {
-                    return RusotoError::Service(SetUserMFAPreferenceError::InternalError(err.msg))
+                    return RusotoError::service(
+                        SetUserMFAPreferenceError::InternalError(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(SetUserMFAPreferenceError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        SetUserMFAPreferenceError::InvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "NotAuthorizedException" => {
-                    return RusotoError::Service(SetUserMFAPreferenceError::NotAuthorized(err.msg))
+                    return RusotoError::service(
+                        SetUserMFAPreferenceError::NotAuthorized(err.msg),
+                        &res,
+                    )
                 }
                 "PasswordResetRequiredException" => {
-                    return RusotoError::Service(SetUserMFAPreferenceError::PasswordResetRequired(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        SetUserMFAPreferenceError::PasswordResetRequired(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(SetUserMFAPreferenceError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        SetUserMFAPreferenceError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "UserNotConfirmedException" => {
-                    return RusotoError::Service(SetUserMFAPreferenceError::UserNotConfirmed(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        SetUserMFAPreferenceError::UserNotConfirmed(err.msg),
+                        &res,
+                    )
                 }
                 "UserNotFoundException" => {
-                    return RusotoError::Service(SetUserMFAPreferenceError::UserNotFound(err.msg))
+                    return RusotoError::service(
+                        SetUserMFAPreferenceError::UserNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(SetUserMFAPreferenceError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -10590,12 +11825,14 @@ impl fmt::Display for SetUserMFAPreferenceError {
             SetUserMFAPreferenceError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             SetUserMFAPreferenceError::UserNotConfirmed(ref cause) => write!(f, "{}", cause),
             SetUserMFAPreferenceError::UserNotFound(ref cause) => write!(f, "{}", cause),
+            SetUserMFAPreferenceError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for SetUserMFAPreferenceError {}
 /// Errors returned by SetUserPoolMfaConfig
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum SetUserPoolMfaConfigError {
     /// <p>This exception is thrown when Amazon Cognito encounters an internal error.</p>
     InternalError(String),
@@ -10611,6 +11848,8 @@ pub enum SetUserPoolMfaConfigError {
     ResourceNotFound(String),
     /// <p>This exception is thrown when the user has made too many requests for a given operation.</p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl SetUserPoolMfaConfigError {
@@ -10618,38 +11857,49 @@ impl SetUserPoolMfaConfigError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalErrorException" => {
-                    return RusotoError::Service(SetUserPoolMfaConfigError::InternalError(err.msg))
+                    return RusotoError::service(
+                        SetUserPoolMfaConfigError::InternalError(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(SetUserPoolMfaConfigError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        SetUserPoolMfaConfigError::InvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidSmsRoleAccessPolicyException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         SetUserPoolMfaConfigError::InvalidSmsRoleAccessPolicy(err.msg),
+                        &res,
                     )
                 }
                 "InvalidSmsRoleTrustRelationshipException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         SetUserPoolMfaConfigError::InvalidSmsRoleTrustRelationship(err.msg),
+                        &res,
                     )
                 }
                 "NotAuthorizedException" => {
-                    return RusotoError::Service(SetUserPoolMfaConfigError::NotAuthorized(err.msg))
+                    return RusotoError::service(
+                        SetUserPoolMfaConfigError::NotAuthorized(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(SetUserPoolMfaConfigError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        SetUserPoolMfaConfigError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(SetUserPoolMfaConfigError::TooManyRequests(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        SetUserPoolMfaConfigError::TooManyRequests(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(SetUserPoolMfaConfigError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -10670,12 +11920,14 @@ impl fmt::Display for SetUserPoolMfaConfigError {
             SetUserPoolMfaConfigError::NotAuthorized(ref cause) => write!(f, "{}", cause),
             SetUserPoolMfaConfigError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             SetUserPoolMfaConfigError::TooManyRequests(ref cause) => write!(f, "{}", cause),
+            SetUserPoolMfaConfigError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for SetUserPoolMfaConfigError {}
 /// Errors returned by SetUserSettings
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum SetUserSettingsError {
     /// <p>This exception is thrown when Amazon Cognito encounters an internal error.</p>
     InternalError(String),
@@ -10691,6 +11943,8 @@ pub enum SetUserSettingsError {
     UserNotConfirmed(String),
     /// <p>This exception is thrown when a user is not found.</p>
     UserNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl SetUserSettingsError {
@@ -10698,30 +11952,40 @@ impl SetUserSettingsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalErrorException" => {
-                    return RusotoError::Service(SetUserSettingsError::InternalError(err.msg))
+                    return RusotoError::service(SetUserSettingsError::InternalError(err.msg), &res)
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(SetUserSettingsError::InvalidParameter(err.msg))
+                    return RusotoError::service(
+                        SetUserSettingsError::InvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "NotAuthorizedException" => {
-                    return RusotoError::Service(SetUserSettingsError::NotAuthorized(err.msg))
+                    return RusotoError::service(SetUserSettingsError::NotAuthorized(err.msg), &res)
                 }
                 "PasswordResetRequiredException" => {
-                    return RusotoError::Service(SetUserSettingsError::PasswordResetRequired(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        SetUserSettingsError::PasswordResetRequired(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(SetUserSettingsError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        SetUserSettingsError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "UserNotConfirmedException" => {
-                    return RusotoError::Service(SetUserSettingsError::UserNotConfirmed(err.msg))
+                    return RusotoError::service(
+                        SetUserSettingsError::UserNotConfirmed(err.msg),
+                        &res,
+                    )
                 }
                 "UserNotFoundException" => {
-                    return RusotoError::Service(SetUserSettingsError::UserNotFound(err.msg))
+                    return RusotoError::service(SetUserSettingsError::UserNotFound(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(SetUserSettingsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -10738,12 +12002,14 @@ impl fmt::Display for SetUserSettingsError {
             SetUserSettingsError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             SetUserSettingsError::UserNotConfirmed(ref cause) => write!(f, "{}", cause),
             SetUserSettingsError::UserNotFound(ref cause) => write!(f, "{}", cause),
+            SetUserSettingsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for SetUserSettingsError {}
 /// Errors returned by SignUp
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum SignUpError {
     /// <p>This exception is thrown when a verification code fails to deliver successfully.</p>
     CodeDeliveryFailure(String),
@@ -10773,6 +12039,8 @@ pub enum SignUpError {
     UserLambdaValidation(String),
     /// <p>This exception is thrown when Amazon Cognito encounters a user name that already exists in the user pool.</p>
     UsernameExists(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl SignUpError {
@@ -10780,51 +12048,58 @@ impl SignUpError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "CodeDeliveryFailureException" => {
-                    return RusotoError::Service(SignUpError::CodeDeliveryFailure(err.msg))
+                    return RusotoError::service(SignUpError::CodeDeliveryFailure(err.msg), &res)
                 }
                 "InternalErrorException" => {
-                    return RusotoError::Service(SignUpError::InternalError(err.msg))
+                    return RusotoError::service(SignUpError::InternalError(err.msg), &res)
                 }
                 "InvalidEmailRoleAccessPolicyException" => {
-                    return RusotoError::Service(SignUpError::InvalidEmailRoleAccessPolicy(err.msg))
+                    return RusotoError::service(
+                        SignUpError::InvalidEmailRoleAccessPolicy(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidLambdaResponseException" => {
-                    return RusotoError::Service(SignUpError::InvalidLambdaResponse(err.msg))
+                    return RusotoError::service(SignUpError::InvalidLambdaResponse(err.msg), &res)
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(SignUpError::InvalidParameter(err.msg))
+                    return RusotoError::service(SignUpError::InvalidParameter(err.msg), &res)
                 }
                 "InvalidPasswordException" => {
-                    return RusotoError::Service(SignUpError::InvalidPassword(err.msg))
+                    return RusotoError::service(SignUpError::InvalidPassword(err.msg), &res)
                 }
                 "InvalidSmsRoleAccessPolicyException" => {
-                    return RusotoError::Service(SignUpError::InvalidSmsRoleAccessPolicy(err.msg))
+                    return RusotoError::service(
+                        SignUpError::InvalidSmsRoleAccessPolicy(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidSmsRoleTrustRelationshipException" => {
-                    return RusotoError::Service(SignUpError::InvalidSmsRoleTrustRelationship(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        SignUpError::InvalidSmsRoleTrustRelationship(err.msg),
+                        &res,
+                    )
                 }
                 "NotAuthorizedException" => {
-                    return RusotoError::Service(SignUpError::NotAuthorized(err.msg))
+                    return RusotoError::service(SignUpError::NotAuthorized(err.msg), &res)
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(SignUpError::ResourceNotFound(err.msg))
+                    return RusotoError::service(SignUpError::ResourceNotFound(err.msg), &res)
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(SignUpError::TooManyRequests(err.msg))
+                    return RusotoError::service(SignUpError::TooManyRequests(err.msg), &res)
                 }
                 "UnexpectedLambdaException" => {
-                    return RusotoError::Service(SignUpError::UnexpectedLambda(err.msg))
+                    return RusotoError::service(SignUpError::UnexpectedLambda(err.msg), &res)
                 }
                 "UserLambdaValidationException" => {
-                    return RusotoError::Service(SignUpError::UserLambdaValidation(err.msg))
+                    return RusotoError::service(SignUpError::UserLambdaValidation(err.msg), &res)
                 }
                 "UsernameExistsException" => {
-                    return RusotoError::Service(SignUpError::UsernameExists(err.msg))
+                    return RusotoError::service(SignUpError::UsernameExists(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(SignUpError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -10848,12 +12123,14 @@ impl fmt::Display for SignUpError {
             SignUpError::UnexpectedLambda(ref cause) => write!(f, "{}", cause),
             SignUpError::UserLambdaValidation(ref cause) => write!(f, "{}", cause),
             SignUpError::UsernameExists(ref cause) => write!(f, "{}", cause),
+            SignUpError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for SignUpError {}
 /// Errors returned by StartUserImportJob
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum StartUserImportJobError {
     /// <p>This exception is thrown when Amazon Cognito encounters an internal error.</p>
     InternalError(String),
@@ -10867,6 +12144,8 @@ pub enum StartUserImportJobError {
     ResourceNotFound(String),
     /// <p>This exception is thrown when the user has made too many requests for a given operation.</p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl StartUserImportJobError {
@@ -10874,27 +12153,43 @@ impl StartUserImportJobError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalErrorException" => {
-                    return RusotoError::Service(StartUserImportJobError::InternalError(err.msg))
+                    return RusotoError::service(
+                        StartUserImportJobError::InternalError(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(StartUserImportJobError::InvalidParameter(err.msg))
+                    return RusotoError::service(
+                        StartUserImportJobError::InvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "NotAuthorizedException" => {
-                    return RusotoError::Service(StartUserImportJobError::NotAuthorized(err.msg))
+                    return RusotoError::service(
+                        StartUserImportJobError::NotAuthorized(err.msg),
+                        &res,
+                    )
                 }
                 "PreconditionNotMetException" => {
-                    return RusotoError::Service(StartUserImportJobError::PreconditionNotMet(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        StartUserImportJobError::PreconditionNotMet(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(StartUserImportJobError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        StartUserImportJobError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(StartUserImportJobError::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        StartUserImportJobError::TooManyRequests(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(StartUserImportJobError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -10910,12 +12205,14 @@ impl fmt::Display for StartUserImportJobError {
             StartUserImportJobError::PreconditionNotMet(ref cause) => write!(f, "{}", cause),
             StartUserImportJobError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             StartUserImportJobError::TooManyRequests(ref cause) => write!(f, "{}", cause),
+            StartUserImportJobError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for StartUserImportJobError {}
 /// Errors returned by StopUserImportJob
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum StopUserImportJobError {
     /// <p>This exception is thrown when Amazon Cognito encounters an internal error.</p>
     InternalError(String),
@@ -10929,6 +12226,8 @@ pub enum StopUserImportJobError {
     ResourceNotFound(String),
     /// <p>This exception is thrown when the user has made too many requests for a given operation.</p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl StopUserImportJobError {
@@ -10936,27 +12235,43 @@ impl StopUserImportJobError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalErrorException" => {
-                    return RusotoError::Service(StopUserImportJobError::InternalError(err.msg))
+                    return RusotoError::service(
+                        StopUserImportJobError::InternalError(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(StopUserImportJobError::InvalidParameter(err.msg))
+                    return RusotoError::service(
+                        StopUserImportJobError::InvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "NotAuthorizedException" => {
-                    return RusotoError::Service(StopUserImportJobError::NotAuthorized(err.msg))
+                    return RusotoError::service(
+                        StopUserImportJobError::NotAuthorized(err.msg),
+                        &res,
+                    )
                 }
                 "PreconditionNotMetException" => {
-                    return RusotoError::Service(StopUserImportJobError::PreconditionNotMet(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        StopUserImportJobError::PreconditionNotMet(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(StopUserImportJobError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        StopUserImportJobError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(StopUserImportJobError::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        StopUserImportJobError::TooManyRequests(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(StopUserImportJobError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -10972,12 +12287,14 @@ impl fmt::Display for StopUserImportJobError {
             StopUserImportJobError::PreconditionNotMet(ref cause) => write!(f, "{}", cause),
             StopUserImportJobError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             StopUserImportJobError::TooManyRequests(ref cause) => write!(f, "{}", cause),
+            StopUserImportJobError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for StopUserImportJobError {}
 /// Errors returned by TagResource
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum TagResourceError {
     /// <p>This exception is thrown when Amazon Cognito encounters an internal error.</p>
     InternalError(String),
@@ -10989,6 +12306,8 @@ pub enum TagResourceError {
     ResourceNotFound(String),
     /// <p>This exception is thrown when the user has made too many requests for a given operation.</p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl TagResourceError {
@@ -10996,22 +12315,22 @@ impl TagResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalErrorException" => {
-                    return RusotoError::Service(TagResourceError::InternalError(err.msg))
+                    return RusotoError::service(TagResourceError::InternalError(err.msg), &res)
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(TagResourceError::InvalidParameter(err.msg))
+                    return RusotoError::service(TagResourceError::InvalidParameter(err.msg), &res)
                 }
                 "NotAuthorizedException" => {
-                    return RusotoError::Service(TagResourceError::NotAuthorized(err.msg))
+                    return RusotoError::service(TagResourceError::NotAuthorized(err.msg), &res)
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(TagResourceError::ResourceNotFound(err.msg))
+                    return RusotoError::service(TagResourceError::ResourceNotFound(err.msg), &res)
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(TagResourceError::TooManyRequests(err.msg))
+                    return RusotoError::service(TagResourceError::TooManyRequests(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(TagResourceError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -11026,12 +12345,14 @@ impl fmt::Display for TagResourceError {
             TagResourceError::NotAuthorized(ref cause) => write!(f, "{}", cause),
             TagResourceError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             TagResourceError::TooManyRequests(ref cause) => write!(f, "{}", cause),
+            TagResourceError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for TagResourceError {}
 /// Errors returned by UntagResource
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UntagResourceError {
     /// <p>This exception is thrown when Amazon Cognito encounters an internal error.</p>
     InternalError(String),
@@ -11043,6 +12364,8 @@ pub enum UntagResourceError {
     ResourceNotFound(String),
     /// <p>This exception is thrown when the user has made too many requests for a given operation.</p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UntagResourceError {
@@ -11050,22 +12373,28 @@ impl UntagResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalErrorException" => {
-                    return RusotoError::Service(UntagResourceError::InternalError(err.msg))
+                    return RusotoError::service(UntagResourceError::InternalError(err.msg), &res)
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(UntagResourceError::InvalidParameter(err.msg))
+                    return RusotoError::service(
+                        UntagResourceError::InvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "NotAuthorizedException" => {
-                    return RusotoError::Service(UntagResourceError::NotAuthorized(err.msg))
+                    return RusotoError::service(UntagResourceError::NotAuthorized(err.msg), &res)
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UntagResourceError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        UntagResourceError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(UntagResourceError::TooManyRequests(err.msg))
+                    return RusotoError::service(UntagResourceError::TooManyRequests(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UntagResourceError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -11080,12 +12409,14 @@ impl fmt::Display for UntagResourceError {
             UntagResourceError::NotAuthorized(ref cause) => write!(f, "{}", cause),
             UntagResourceError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             UntagResourceError::TooManyRequests(ref cause) => write!(f, "{}", cause),
+            UntagResourceError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for UntagResourceError {}
 /// Errors returned by UpdateAuthEventFeedback
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdateAuthEventFeedbackError {
     /// <p>This exception is thrown when Amazon Cognito encounters an internal error.</p>
     InternalError(String),
@@ -11101,6 +12432,8 @@ pub enum UpdateAuthEventFeedbackError {
     UserNotFound(String),
     /// <p>This exception is thrown when user pool add-ons are not enabled.</p>
     UserPoolAddOnNotEnabled(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdateAuthEventFeedbackError {
@@ -11108,42 +12441,49 @@ impl UpdateAuthEventFeedbackError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalErrorException" => {
-                    return RusotoError::Service(UpdateAuthEventFeedbackError::InternalError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateAuthEventFeedbackError::InternalError(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(UpdateAuthEventFeedbackError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateAuthEventFeedbackError::InvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "NotAuthorizedException" => {
-                    return RusotoError::Service(UpdateAuthEventFeedbackError::NotAuthorized(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateAuthEventFeedbackError::NotAuthorized(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateAuthEventFeedbackError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateAuthEventFeedbackError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(UpdateAuthEventFeedbackError::TooManyRequests(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateAuthEventFeedbackError::TooManyRequests(err.msg),
+                        &res,
+                    )
                 }
                 "UserNotFoundException" => {
-                    return RusotoError::Service(UpdateAuthEventFeedbackError::UserNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateAuthEventFeedbackError::UserNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "UserPoolAddOnNotEnabledException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         UpdateAuthEventFeedbackError::UserPoolAddOnNotEnabled(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UpdateAuthEventFeedbackError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -11162,12 +12502,14 @@ impl fmt::Display for UpdateAuthEventFeedbackError {
             UpdateAuthEventFeedbackError::UserPoolAddOnNotEnabled(ref cause) => {
                 write!(f, "{}", cause)
             }
+            UpdateAuthEventFeedbackError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for UpdateAuthEventFeedbackError {}
 /// Errors returned by UpdateDeviceStatus
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdateDeviceStatusError {
     /// <p>This exception is thrown when Amazon Cognito encounters an internal error.</p>
     InternalError(String),
@@ -11187,6 +12529,8 @@ pub enum UpdateDeviceStatusError {
     UserNotConfirmed(String),
     /// <p>This exception is thrown when a user is not found.</p>
     UserNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdateDeviceStatusError {
@@ -11194,38 +12538,61 @@ impl UpdateDeviceStatusError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalErrorException" => {
-                    return RusotoError::Service(UpdateDeviceStatusError::InternalError(err.msg))
+                    return RusotoError::service(
+                        UpdateDeviceStatusError::InternalError(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(UpdateDeviceStatusError::InvalidParameter(err.msg))
+                    return RusotoError::service(
+                        UpdateDeviceStatusError::InvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidUserPoolConfigurationException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         UpdateDeviceStatusError::InvalidUserPoolConfiguration(err.msg),
+                        &res,
                     )
                 }
                 "NotAuthorizedException" => {
-                    return RusotoError::Service(UpdateDeviceStatusError::NotAuthorized(err.msg))
+                    return RusotoError::service(
+                        UpdateDeviceStatusError::NotAuthorized(err.msg),
+                        &res,
+                    )
                 }
                 "PasswordResetRequiredException" => {
-                    return RusotoError::Service(UpdateDeviceStatusError::PasswordResetRequired(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateDeviceStatusError::PasswordResetRequired(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateDeviceStatusError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        UpdateDeviceStatusError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(UpdateDeviceStatusError::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        UpdateDeviceStatusError::TooManyRequests(err.msg),
+                        &res,
+                    )
                 }
                 "UserNotConfirmedException" => {
-                    return RusotoError::Service(UpdateDeviceStatusError::UserNotConfirmed(err.msg))
+                    return RusotoError::service(
+                        UpdateDeviceStatusError::UserNotConfirmed(err.msg),
+                        &res,
+                    )
                 }
                 "UserNotFoundException" => {
-                    return RusotoError::Service(UpdateDeviceStatusError::UserNotFound(err.msg))
+                    return RusotoError::service(
+                        UpdateDeviceStatusError::UserNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UpdateDeviceStatusError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -11246,12 +12613,14 @@ impl fmt::Display for UpdateDeviceStatusError {
             UpdateDeviceStatusError::TooManyRequests(ref cause) => write!(f, "{}", cause),
             UpdateDeviceStatusError::UserNotConfirmed(ref cause) => write!(f, "{}", cause),
             UpdateDeviceStatusError::UserNotFound(ref cause) => write!(f, "{}", cause),
+            UpdateDeviceStatusError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for UpdateDeviceStatusError {}
 /// Errors returned by UpdateGroup
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdateGroupError {
     /// <p>This exception is thrown when Amazon Cognito encounters an internal error.</p>
     InternalError(String),
@@ -11263,6 +12632,8 @@ pub enum UpdateGroupError {
     ResourceNotFound(String),
     /// <p>This exception is thrown when the user has made too many requests for a given operation.</p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdateGroupError {
@@ -11270,22 +12641,22 @@ impl UpdateGroupError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalErrorException" => {
-                    return RusotoError::Service(UpdateGroupError::InternalError(err.msg))
+                    return RusotoError::service(UpdateGroupError::InternalError(err.msg), &res)
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(UpdateGroupError::InvalidParameter(err.msg))
+                    return RusotoError::service(UpdateGroupError::InvalidParameter(err.msg), &res)
                 }
                 "NotAuthorizedException" => {
-                    return RusotoError::Service(UpdateGroupError::NotAuthorized(err.msg))
+                    return RusotoError::service(UpdateGroupError::NotAuthorized(err.msg), &res)
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateGroupError::ResourceNotFound(err.msg))
+                    return RusotoError::service(UpdateGroupError::ResourceNotFound(err.msg), &res)
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(UpdateGroupError::TooManyRequests(err.msg))
+                    return RusotoError::service(UpdateGroupError::TooManyRequests(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UpdateGroupError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -11300,12 +12671,14 @@ impl fmt::Display for UpdateGroupError {
             UpdateGroupError::NotAuthorized(ref cause) => write!(f, "{}", cause),
             UpdateGroupError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             UpdateGroupError::TooManyRequests(ref cause) => write!(f, "{}", cause),
+            UpdateGroupError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for UpdateGroupError {}
 /// Errors returned by UpdateIdentityProvider
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdateIdentityProviderError {
     /// <p>This exception is thrown when Amazon Cognito encounters an internal error.</p>
     InternalError(String),
@@ -11319,6 +12692,8 @@ pub enum UpdateIdentityProviderError {
     TooManyRequests(String),
     /// <p>This exception is thrown when the specified identifier is not supported.</p>
     UnsupportedIdentityProvider(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdateIdentityProviderError {
@@ -11326,37 +12701,43 @@ impl UpdateIdentityProviderError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalErrorException" => {
-                    return RusotoError::Service(UpdateIdentityProviderError::InternalError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateIdentityProviderError::InternalError(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(UpdateIdentityProviderError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateIdentityProviderError::InvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "NotAuthorizedException" => {
-                    return RusotoError::Service(UpdateIdentityProviderError::NotAuthorized(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateIdentityProviderError::NotAuthorized(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateIdentityProviderError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateIdentityProviderError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(UpdateIdentityProviderError::TooManyRequests(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateIdentityProviderError::TooManyRequests(err.msg),
+                        &res,
+                    )
                 }
                 "UnsupportedIdentityProviderException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         UpdateIdentityProviderError::UnsupportedIdentityProvider(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UpdateIdentityProviderError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -11374,12 +12755,14 @@ impl fmt::Display for UpdateIdentityProviderError {
             UpdateIdentityProviderError::UnsupportedIdentityProvider(ref cause) => {
                 write!(f, "{}", cause)
             }
+            UpdateIdentityProviderError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for UpdateIdentityProviderError {}
 /// Errors returned by UpdateResourceServer
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdateResourceServerError {
     /// <p>This exception is thrown when Amazon Cognito encounters an internal error.</p>
     InternalError(String),
@@ -11391,6 +12774,8 @@ pub enum UpdateResourceServerError {
     ResourceNotFound(String),
     /// <p>This exception is thrown when the user has made too many requests for a given operation.</p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdateResourceServerError {
@@ -11398,28 +12783,37 @@ impl UpdateResourceServerError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalErrorException" => {
-                    return RusotoError::Service(UpdateResourceServerError::InternalError(err.msg))
+                    return RusotoError::service(
+                        UpdateResourceServerError::InternalError(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(UpdateResourceServerError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateResourceServerError::InvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "NotAuthorizedException" => {
-                    return RusotoError::Service(UpdateResourceServerError::NotAuthorized(err.msg))
+                    return RusotoError::service(
+                        UpdateResourceServerError::NotAuthorized(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateResourceServerError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateResourceServerError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(UpdateResourceServerError::TooManyRequests(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateResourceServerError::TooManyRequests(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UpdateResourceServerError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -11434,12 +12828,14 @@ impl fmt::Display for UpdateResourceServerError {
             UpdateResourceServerError::NotAuthorized(ref cause) => write!(f, "{}", cause),
             UpdateResourceServerError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             UpdateResourceServerError::TooManyRequests(ref cause) => write!(f, "{}", cause),
+            UpdateResourceServerError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for UpdateResourceServerError {}
 /// Errors returned by UpdateUserAttributes
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdateUserAttributesError {
     /// <p>This exception is thrown when a user tries to confirm the account with an email or phone number that has already been supplied as an alias from a different account. This exception tells user that an account with this email or phone already exists.</p>
     AliasExists(String),
@@ -11477,6 +12873,8 @@ pub enum UpdateUserAttributesError {
     UserNotConfirmed(String),
     /// <p>This exception is thrown when a user is not found.</p>
     UserNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdateUserAttributesError {
@@ -11484,85 +12882,115 @@ impl UpdateUserAttributesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AliasExistsException" => {
-                    return RusotoError::Service(UpdateUserAttributesError::AliasExists(err.msg))
+                    return RusotoError::service(
+                        UpdateUserAttributesError::AliasExists(err.msg),
+                        &res,
+                    )
                 }
                 "CodeDeliveryFailureException" => {
-                    return RusotoError::Service(UpdateUserAttributesError::CodeDeliveryFailure(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateUserAttributesError::CodeDeliveryFailure(err.msg),
+                        &res,
+                    )
                 }
                 "CodeMismatchException" => {
-                    return RusotoError::Service(UpdateUserAttributesError::CodeMismatch(err.msg))
+                    return RusotoError::service(
+                        UpdateUserAttributesError::CodeMismatch(err.msg),
+                        &res,
+                    )
                 }
                 "ExpiredCodeException" => {
-                    return RusotoError::Service(UpdateUserAttributesError::ExpiredCode(err.msg))
+                    return RusotoError::service(
+                        UpdateUserAttributesError::ExpiredCode(err.msg),
+                        &res,
+                    )
                 }
                 "InternalErrorException" => {
-                    return RusotoError::Service(UpdateUserAttributesError::InternalError(err.msg))
+                    return RusotoError::service(
+                        UpdateUserAttributesError::InternalError(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidEmailRoleAccessPolicyException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         UpdateUserAttributesError::InvalidEmailRoleAccessPolicy(err.msg),
+                        &res,
                     )
                 }
                 "InvalidLambdaResponseException" => {
-                    return RusotoError::Service(UpdateUserAttributesError::InvalidLambdaResponse(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateUserAttributesError::InvalidLambdaResponse(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(UpdateUserAttributesError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateUserAttributesError::InvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidSmsRoleAccessPolicyException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         UpdateUserAttributesError::InvalidSmsRoleAccessPolicy(err.msg),
+                        &res,
                     )
                 }
                 "InvalidSmsRoleTrustRelationshipException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         UpdateUserAttributesError::InvalidSmsRoleTrustRelationship(err.msg),
+                        &res,
                     )
                 }
                 "NotAuthorizedException" => {
-                    return RusotoError::Service(UpdateUserAttributesError::NotAuthorized(err.msg))
+                    return RusotoError::service(
+                        UpdateUserAttributesError::NotAuthorized(err.msg),
+                        &res,
+                    )
                 }
                 "PasswordResetRequiredException" => {
-                    return RusotoError::Service(UpdateUserAttributesError::PasswordResetRequired(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateUserAttributesError::PasswordResetRequired(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateUserAttributesError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateUserAttributesError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(UpdateUserAttributesError::TooManyRequests(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateUserAttributesError::TooManyRequests(err.msg),
+                        &res,
+                    )
                 }
                 "UnexpectedLambdaException" => {
-                    return RusotoError::Service(UpdateUserAttributesError::UnexpectedLambda(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateUserAttributesError::UnexpectedLambda(err.msg),
+                        &res,
+                    )
                 }
                 "UserLambdaValidationException" => {
-                    return RusotoError::Service(UpdateUserAttributesError::UserLambdaValidation(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateUserAttributesError::UserLambdaValidation(err.msg),
+                        &res,
+                    )
                 }
                 "UserNotConfirmedException" => {
-                    return RusotoError::Service(UpdateUserAttributesError::UserNotConfirmed(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateUserAttributesError::UserNotConfirmed(err.msg),
+                        &res,
+                    )
                 }
                 "UserNotFoundException" => {
-                    return RusotoError::Service(UpdateUserAttributesError::UserNotFound(err.msg))
+                    return RusotoError::service(
+                        UpdateUserAttributesError::UserNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UpdateUserAttributesError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -11596,12 +13024,14 @@ impl fmt::Display for UpdateUserAttributesError {
             UpdateUserAttributesError::UserLambdaValidation(ref cause) => write!(f, "{}", cause),
             UpdateUserAttributesError::UserNotConfirmed(ref cause) => write!(f, "{}", cause),
             UpdateUserAttributesError::UserNotFound(ref cause) => write!(f, "{}", cause),
+            UpdateUserAttributesError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for UpdateUserAttributesError {}
 /// Errors returned by UpdateUserPool
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdateUserPoolError {
     /// <p>This exception is thrown if two or more modifications are happening concurrently.</p>
     ConcurrentModification(String),
@@ -11625,6 +13055,8 @@ pub enum UpdateUserPoolError {
     UserImportInProgress(String),
     /// <p>This exception is thrown when a user pool tag cannot be set or updated.</p>
     UserPoolTagging(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdateUserPoolError {
@@ -11632,48 +13064,67 @@ impl UpdateUserPoolError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(UpdateUserPoolError::ConcurrentModification(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateUserPoolError::ConcurrentModification(err.msg),
+                        &res,
+                    )
                 }
                 "InternalErrorException" => {
-                    return RusotoError::Service(UpdateUserPoolError::InternalError(err.msg))
+                    return RusotoError::service(UpdateUserPoolError::InternalError(err.msg), &res)
                 }
                 "InvalidEmailRoleAccessPolicyException" => {
-                    return RusotoError::Service(UpdateUserPoolError::InvalidEmailRoleAccessPolicy(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateUserPoolError::InvalidEmailRoleAccessPolicy(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(UpdateUserPoolError::InvalidParameter(err.msg))
+                    return RusotoError::service(
+                        UpdateUserPoolError::InvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidSmsRoleAccessPolicyException" => {
-                    return RusotoError::Service(UpdateUserPoolError::InvalidSmsRoleAccessPolicy(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateUserPoolError::InvalidSmsRoleAccessPolicy(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidSmsRoleTrustRelationshipException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         UpdateUserPoolError::InvalidSmsRoleTrustRelationship(err.msg),
+                        &res,
                     )
                 }
                 "NotAuthorizedException" => {
-                    return RusotoError::Service(UpdateUserPoolError::NotAuthorized(err.msg))
+                    return RusotoError::service(UpdateUserPoolError::NotAuthorized(err.msg), &res)
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateUserPoolError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        UpdateUserPoolError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(UpdateUserPoolError::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        UpdateUserPoolError::TooManyRequests(err.msg),
+                        &res,
+                    )
                 }
                 "UserImportInProgressException" => {
-                    return RusotoError::Service(UpdateUserPoolError::UserImportInProgress(err.msg))
+                    return RusotoError::service(
+                        UpdateUserPoolError::UserImportInProgress(err.msg),
+                        &res,
+                    )
                 }
                 "UserPoolTaggingException" => {
-                    return RusotoError::Service(UpdateUserPoolError::UserPoolTagging(err.msg))
+                    return RusotoError::service(
+                        UpdateUserPoolError::UserPoolTagging(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UpdateUserPoolError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -11696,12 +13147,14 @@ impl fmt::Display for UpdateUserPoolError {
             UpdateUserPoolError::TooManyRequests(ref cause) => write!(f, "{}", cause),
             UpdateUserPoolError::UserImportInProgress(ref cause) => write!(f, "{}", cause),
             UpdateUserPoolError::UserPoolTagging(ref cause) => write!(f, "{}", cause),
+            UpdateUserPoolError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for UpdateUserPoolError {}
 /// Errors returned by UpdateUserPoolClient
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdateUserPoolClientError {
     /// <p>This exception is thrown if two or more modifications are happening concurrently.</p>
     ConcurrentModification(String),
@@ -11719,6 +13172,8 @@ pub enum UpdateUserPoolClientError {
     ScopeDoesNotExist(String),
     /// <p>This exception is thrown when the user has made too many requests for a given operation.</p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdateUserPoolClientError {
@@ -11726,43 +13181,55 @@ impl UpdateUserPoolClientError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(UpdateUserPoolClientError::ConcurrentModification(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateUserPoolClientError::ConcurrentModification(err.msg),
+                        &res,
+                    )
                 }
                 "InternalErrorException" => {
-                    return RusotoError::Service(UpdateUserPoolClientError::InternalError(err.msg))
+                    return RusotoError::service(
+                        UpdateUserPoolClientError::InternalError(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidOAuthFlowException" => {
-                    return RusotoError::Service(UpdateUserPoolClientError::InvalidOAuthFlow(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateUserPoolClientError::InvalidOAuthFlow(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(UpdateUserPoolClientError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateUserPoolClientError::InvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "NotAuthorizedException" => {
-                    return RusotoError::Service(UpdateUserPoolClientError::NotAuthorized(err.msg))
+                    return RusotoError::service(
+                        UpdateUserPoolClientError::NotAuthorized(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateUserPoolClientError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateUserPoolClientError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ScopeDoesNotExistException" => {
-                    return RusotoError::Service(UpdateUserPoolClientError::ScopeDoesNotExist(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateUserPoolClientError::ScopeDoesNotExist(err.msg),
+                        &res,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(UpdateUserPoolClientError::TooManyRequests(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateUserPoolClientError::TooManyRequests(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UpdateUserPoolClientError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -11780,12 +13247,14 @@ impl fmt::Display for UpdateUserPoolClientError {
             UpdateUserPoolClientError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             UpdateUserPoolClientError::ScopeDoesNotExist(ref cause) => write!(f, "{}", cause),
             UpdateUserPoolClientError::TooManyRequests(ref cause) => write!(f, "{}", cause),
+            UpdateUserPoolClientError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for UpdateUserPoolClientError {}
 /// Errors returned by UpdateUserPoolDomain
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdateUserPoolDomainError {
     /// <p>This exception is thrown when Amazon Cognito encounters an internal error.</p>
     InternalError(String),
@@ -11797,6 +13266,8 @@ pub enum UpdateUserPoolDomainError {
     ResourceNotFound(String),
     /// <p>This exception is thrown when the user has made too many requests for a given operation.</p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdateUserPoolDomainError {
@@ -11804,28 +13275,37 @@ impl UpdateUserPoolDomainError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalErrorException" => {
-                    return RusotoError::Service(UpdateUserPoolDomainError::InternalError(err.msg))
+                    return RusotoError::service(
+                        UpdateUserPoolDomainError::InternalError(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(UpdateUserPoolDomainError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateUserPoolDomainError::InvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "NotAuthorizedException" => {
-                    return RusotoError::Service(UpdateUserPoolDomainError::NotAuthorized(err.msg))
+                    return RusotoError::service(
+                        UpdateUserPoolDomainError::NotAuthorized(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateUserPoolDomainError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateUserPoolDomainError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(UpdateUserPoolDomainError::TooManyRequests(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateUserPoolDomainError::TooManyRequests(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UpdateUserPoolDomainError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -11840,12 +13320,14 @@ impl fmt::Display for UpdateUserPoolDomainError {
             UpdateUserPoolDomainError::NotAuthorized(ref cause) => write!(f, "{}", cause),
             UpdateUserPoolDomainError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             UpdateUserPoolDomainError::TooManyRequests(ref cause) => write!(f, "{}", cause),
+            UpdateUserPoolDomainError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for UpdateUserPoolDomainError {}
 /// Errors returned by VerifySoftwareToken
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum VerifySoftwareTokenError {
     /// <p>This exception is thrown if the provided code does not match what the server was expecting.</p>
     CodeMismatch(String),
@@ -11871,6 +13353,8 @@ pub enum VerifySoftwareTokenError {
     UserNotConfirmed(String),
     /// <p>This exception is thrown when a user is not found.</p>
     UserNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl VerifySoftwareTokenError {
@@ -11878,57 +13362,79 @@ impl VerifySoftwareTokenError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "CodeMismatchException" => {
-                    return RusotoError::Service(VerifySoftwareTokenError::CodeMismatch(err.msg))
+                    return RusotoError::service(
+                        VerifySoftwareTokenError::CodeMismatch(err.msg),
+                        &res,
+                    )
                 }
                 "EnableSoftwareTokenMFAException" => {
-                    return RusotoError::Service(VerifySoftwareTokenError::EnableSoftwareTokenMFA(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        VerifySoftwareTokenError::EnableSoftwareTokenMFA(err.msg),
+                        &res,
+                    )
                 }
                 "InternalErrorException" => {
-                    return RusotoError::Service(VerifySoftwareTokenError::InternalError(err.msg))
+                    return RusotoError::service(
+                        VerifySoftwareTokenError::InternalError(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(VerifySoftwareTokenError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        VerifySoftwareTokenError::InvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidUserPoolConfigurationException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         VerifySoftwareTokenError::InvalidUserPoolConfiguration(err.msg),
+                        &res,
                     )
                 }
                 "NotAuthorizedException" => {
-                    return RusotoError::Service(VerifySoftwareTokenError::NotAuthorized(err.msg))
+                    return RusotoError::service(
+                        VerifySoftwareTokenError::NotAuthorized(err.msg),
+                        &res,
+                    )
                 }
                 "PasswordResetRequiredException" => {
-                    return RusotoError::Service(VerifySoftwareTokenError::PasswordResetRequired(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        VerifySoftwareTokenError::PasswordResetRequired(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(VerifySoftwareTokenError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        VerifySoftwareTokenError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "SoftwareTokenMFANotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         VerifySoftwareTokenError::SoftwareTokenMFANotFound(err.msg),
+                        &res,
                     )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(VerifySoftwareTokenError::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        VerifySoftwareTokenError::TooManyRequests(err.msg),
+                        &res,
+                    )
                 }
                 "UserNotConfirmedException" => {
-                    return RusotoError::Service(VerifySoftwareTokenError::UserNotConfirmed(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        VerifySoftwareTokenError::UserNotConfirmed(err.msg),
+                        &res,
+                    )
                 }
                 "UserNotFoundException" => {
-                    return RusotoError::Service(VerifySoftwareTokenError::UserNotFound(err.msg))
+                    return RusotoError::service(
+                        VerifySoftwareTokenError::UserNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(VerifySoftwareTokenError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -11952,12 +13458,14 @@ impl fmt::Display for VerifySoftwareTokenError {
             VerifySoftwareTokenError::TooManyRequests(ref cause) => write!(f, "{}", cause),
             VerifySoftwareTokenError::UserNotConfirmed(ref cause) => write!(f, "{}", cause),
             VerifySoftwareTokenError::UserNotFound(ref cause) => write!(f, "{}", cause),
+            VerifySoftwareTokenError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for VerifySoftwareTokenError {}
 /// Errors returned by VerifyUserAttribute
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum VerifyUserAttributeError {
     /// <p>This exception is thrown if the provided code does not match what the server was expecting.</p>
     CodeMismatch(String),
@@ -11981,6 +13489,8 @@ pub enum VerifyUserAttributeError {
     UserNotConfirmed(String),
     /// <p>This exception is thrown when a user is not found.</p>
     UserNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl VerifyUserAttributeError {
@@ -11988,48 +13498,73 @@ impl VerifyUserAttributeError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "CodeMismatchException" => {
-                    return RusotoError::Service(VerifyUserAttributeError::CodeMismatch(err.msg))
+                    return RusotoError::service(
+                        VerifyUserAttributeError::CodeMismatch(err.msg),
+                        &res,
+                    )
                 }
                 "ExpiredCodeException" => {
-                    return RusotoError::Service(VerifyUserAttributeError::ExpiredCode(err.msg))
+                    return RusotoError::service(
+                        VerifyUserAttributeError::ExpiredCode(err.msg),
+                        &res,
+                    )
                 }
                 "InternalErrorException" => {
-                    return RusotoError::Service(VerifyUserAttributeError::InternalError(err.msg))
+                    return RusotoError::service(
+                        VerifyUserAttributeError::InternalError(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(VerifyUserAttributeError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        VerifyUserAttributeError::InvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(VerifyUserAttributeError::LimitExceeded(err.msg))
+                    return RusotoError::service(
+                        VerifyUserAttributeError::LimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "NotAuthorizedException" => {
-                    return RusotoError::Service(VerifyUserAttributeError::NotAuthorized(err.msg))
+                    return RusotoError::service(
+                        VerifyUserAttributeError::NotAuthorized(err.msg),
+                        &res,
+                    )
                 }
                 "PasswordResetRequiredException" => {
-                    return RusotoError::Service(VerifyUserAttributeError::PasswordResetRequired(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        VerifyUserAttributeError::PasswordResetRequired(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(VerifyUserAttributeError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        VerifyUserAttributeError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(VerifyUserAttributeError::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        VerifyUserAttributeError::TooManyRequests(err.msg),
+                        &res,
+                    )
                 }
                 "UserNotConfirmedException" => {
-                    return RusotoError::Service(VerifyUserAttributeError::UserNotConfirmed(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        VerifyUserAttributeError::UserNotConfirmed(err.msg),
+                        &res,
+                    )
                 }
                 "UserNotFoundException" => {
-                    return RusotoError::Service(VerifyUserAttributeError::UserNotFound(err.msg))
+                    return RusotoError::service(
+                        VerifyUserAttributeError::UserNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(VerifyUserAttributeError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -12050,6 +13585,7 @@ impl fmt::Display for VerifyUserAttributeError {
             VerifyUserAttributeError::TooManyRequests(ref cause) => write!(f, "{}", cause),
             VerifyUserAttributeError::UserNotConfirmed(ref cause) => write!(f, "{}", cause),
             VerifyUserAttributeError::UserNotFound(ref cause) => write!(f, "{}", cause),
+            VerifyUserAttributeError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
@@ -12674,6 +14210,7 @@ impl CognitoIdentityProviderClient {
     /// Creates a client backed by the default tokio event loop.
     ///
     /// The client will use the default credentials provider and tls client.
+    #[cfg(not(target_arch = "wasm32"))]
     pub fn new(region: region::Region) -> CognitoIdentityProviderClient {
         CognitoIdentityProviderClient {
             client: Client::shared(),
@@ -12702,16 +14239,76 @@ impl CognitoIdentityProviderClient {
     ) -> CognitoIdentityProviderClient {
         CognitoIdentityProviderClient { client, region }
     }
+
+    /// Returns a builder of a client, setting any of its region, credentials provider,
+    /// dispatcher, retry policy, endpoint and timeouts.
+    pub fn builder() -> rusoto_core::ClientBuilder<CognitoIdentityProviderClient> {
+        rusoto_core::ClientBuilder::new(CognitoIdentityProviderClient::new_with_client)
+    }
+
+    /// Returns a clone of this client with the overrides of `config` applied to its
+    /// operations, sharing its connections.
+    pub fn customize(
+        &self,
+        config: &rusoto_core::OperationConfig,
+    ) -> CognitoIdentityProviderClient {
+        CognitoIdentityProviderClient {
+            client: self.client.customize(config),
+            region: config.resolve_region(&self.region),
+        }
+    }
 }
 
-#[async_trait]
-impl CognitoIdentityProvider for CognitoIdentityProviderClient {
-    /// <p>Adds additional user attributes to the user pool schema.</p>
-    async fn add_custom_attributes(
+impl CognitoIdentityProviderClient {
+    /// Calls `add_custom_attributes`, also returning the metadata of the response.
+    pub async fn add_custom_attributes_with_metadata(
         &self,
         input: AddCustomAttributesRequest,
-    ) -> Result<AddCustomAttributesResponse, RusotoError<AddCustomAttributesError>> {
+    ) -> Result<
+        (AddCustomAttributesResponse, ResponseMetadata),
+        RusotoError<AddCustomAttributesError>,
+    > {
+        let request = self.build_add_custom_attributes_request(input);
+
+        let mut response = self
+            .client
+            .sign_and_dispatch(request)
+            .await
+            .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
+        if response.status.is_success() {
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<AddCustomAttributesResponse, _>()?;
+            Ok((result, metadata))
+        } else {
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(AddCustomAttributesError::from_response(response))
+        }
+    }
+
+    /// Presigns the request `add_custom_attributes` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_add_custom_attributes(
+        &self,
+        presigner: &rusoto_core::Presigner,
+        input: AddCustomAttributesRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_add_custom_attributes_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_add_custom_attributes_request(
+        &self,
+        input: AddCustomAttributesRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "cognito-idp", &self.region, "/");
+        request.set_operation("AddCustomAttributes");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -12721,28 +14318,50 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `admin_add_user_to_group`, also returning the metadata of the response.
+    pub async fn admin_add_user_to_group_with_metadata(
+        &self,
+        input: AdminAddUserToGroupRequest,
+    ) -> Result<((), ResponseMetadata), RusotoError<AdminAddUserToGroupError>> {
+        let request = self.build_admin_add_user_to_group_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<AddCustomAttributesResponse, _>()
+            std::mem::drop(response);
+            Ok(((), metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(AddCustomAttributesError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(AdminAddUserToGroupError::from_response(response))
         }
     }
 
-    /// <p>Adds the specified user to the specified group.</p> <p>Calling this action requires developer credentials.</p>
-    async fn admin_add_user_to_group(
+    /// Presigns the request `admin_add_user_to_group` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_admin_add_user_to_group(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: AdminAddUserToGroupRequest,
-    ) -> Result<(), RusotoError<AdminAddUserToGroupError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_admin_add_user_to_group_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_admin_add_user_to_group_request(
+        &self,
+        input: AdminAddUserToGroupRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "cognito-idp", &self.region, "/");
+        request.set_operation("AdminAddUserToGroup");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -12752,27 +14371,56 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `admin_confirm_sign_up`, also returning the metadata of the response.
+    pub async fn admin_confirm_sign_up_with_metadata(
+        &self,
+        input: AdminConfirmSignUpRequest,
+    ) -> Result<(AdminConfirmSignUpResponse, ResponseMetadata), RusotoError<AdminConfirmSignUpError>>
+    {
+        let request = self.build_admin_confirm_sign_up_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            std::mem::drop(response);
-            Ok(())
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<AdminConfirmSignUpResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(AdminAddUserToGroupError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(AdminConfirmSignUpError::from_response(response))
         }
     }
 
-    /// <p>Confirms user registration as an admin without using a confirmation code. Works on any user.</p> <p>Calling this action requires developer credentials.</p>
-    async fn admin_confirm_sign_up(
+    /// Presigns the request `admin_confirm_sign_up` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_admin_confirm_sign_up(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: AdminConfirmSignUpRequest,
-    ) -> Result<AdminConfirmSignUpResponse, RusotoError<AdminConfirmSignUpError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_admin_confirm_sign_up_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_admin_confirm_sign_up_request(
+        &self,
+        input: AdminConfirmSignUpRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "cognito-idp", &self.region, "/");
+        request.set_operation("AdminConfirmSignUp");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -12782,28 +14430,53 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `admin_create_user`, also returning the metadata of the response.
+    pub async fn admin_create_user_with_metadata(
+        &self,
+        input: AdminCreateUserRequest,
+    ) -> Result<(AdminCreateUserResponse, ResponseMetadata), RusotoError<AdminCreateUserError>>
+    {
+        let request = self.build_admin_create_user_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<AdminConfirmSignUpResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<AdminCreateUserResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(AdminConfirmSignUpError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(AdminCreateUserError::from_response(response))
         }
     }
 
-    /// <p>Creates a new user in the specified user pool.</p> <p>If <code>MessageAction</code> is not set, the default is to send a welcome message via email or phone (SMS).</p> <note> <p>This message is based on a template that you configured in your call to or . This template includes your custom sign-up instructions and placeholders for user name and temporary password.</p> </note> <p>Alternatively, you can call AdminCreateUser with “SUPPRESS” for the <code>MessageAction</code> parameter, and Amazon Cognito will not send any email. </p> <p>In either case, the user will be in the <code>FORCE_CHANGE_PASSWORD</code> state until they sign in and change their password.</p> <p>AdminCreateUser requires developer credentials.</p>
-    async fn admin_create_user(
+    /// Presigns the request `admin_create_user` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_admin_create_user(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: AdminCreateUserRequest,
-    ) -> Result<AdminCreateUserResponse, RusotoError<AdminCreateUserError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_admin_create_user_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_admin_create_user_request(&self, input: AdminCreateUserRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "cognito-idp", &self.region, "/");
+        request.set_operation("AdminCreateUser");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -12813,27 +14486,47 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `admin_delete_user`, also returning the metadata of the response.
+    pub async fn admin_delete_user_with_metadata(
+        &self,
+        input: AdminDeleteUserRequest,
+    ) -> Result<((), ResponseMetadata), RusotoError<AdminDeleteUserError>> {
+        let request = self.build_admin_delete_user_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<AdminCreateUserResponse, _>()
+            std::mem::drop(response);
+            Ok(((), metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(AdminCreateUserError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(AdminDeleteUserError::from_response(response))
         }
     }
 
-    /// <p>Deletes a user as an administrator. Works on any user.</p> <p>Calling this action requires developer credentials.</p>
-    async fn admin_delete_user(
+    /// Presigns the request `admin_delete_user` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_admin_delete_user(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: AdminDeleteUserRequest,
-    ) -> Result<(), RusotoError<AdminDeleteUserError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_admin_delete_user_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_admin_delete_user_request(&self, input: AdminDeleteUserRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "cognito-idp", &self.region, "/");
+        request.set_operation("AdminDeleteUser");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -12843,28 +14536,58 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `admin_delete_user_attributes`, also returning the metadata of the response.
+    pub async fn admin_delete_user_attributes_with_metadata(
+        &self,
+        input: AdminDeleteUserAttributesRequest,
+    ) -> Result<
+        (AdminDeleteUserAttributesResponse, ResponseMetadata),
+        RusotoError<AdminDeleteUserAttributesError>,
+    > {
+        let request = self.build_admin_delete_user_attributes_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            std::mem::drop(response);
-            Ok(())
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<AdminDeleteUserAttributesResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(AdminDeleteUserError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(AdminDeleteUserAttributesError::from_response(response))
         }
     }
 
-    /// <p>Deletes the user attributes in a user pool as an administrator. Works on any user.</p> <p>Calling this action requires developer credentials.</p>
-    async fn admin_delete_user_attributes(
+    /// Presigns the request `admin_delete_user_attributes` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_admin_delete_user_attributes(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: AdminDeleteUserAttributesRequest,
-    ) -> Result<AdminDeleteUserAttributesResponse, RusotoError<AdminDeleteUserAttributesError>>
-    {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_admin_delete_user_attributes_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_admin_delete_user_attributes_request(
+        &self,
+        input: AdminDeleteUserAttributesRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "cognito-idp", &self.region, "/");
+        request.set_operation("AdminDeleteUserAttributes");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -12874,29 +14597,58 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `admin_disable_provider_for_user`, also returning the metadata of the response.
+    pub async fn admin_disable_provider_for_user_with_metadata(
+        &self,
+        input: AdminDisableProviderForUserRequest,
+    ) -> Result<
+        (AdminDisableProviderForUserResponse, ResponseMetadata),
+        RusotoError<AdminDisableProviderForUserError>,
+    > {
+        let request = self.build_admin_disable_provider_for_user_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<AdminDeleteUserAttributesResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<AdminDisableProviderForUserResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(AdminDeleteUserAttributesError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(AdminDisableProviderForUserError::from_response(response))
         }
     }
 
-    /// <p>Disables the user from signing in with the specified external (SAML or social) identity provider. If the user to disable is a Cognito User Pools native username + password user, they are not permitted to use their password to sign-in. If the user to disable is a linked external IdP user, any link between that user and an existing user is removed. The next time the external user (no longer attached to the previously linked <code>DestinationUser</code>) signs in, they must create a new user account. See .</p> <p>This action is enabled only for admin access and requires developer credentials.</p> <p>The <code>ProviderName</code> must match the value specified when creating an IdP for the pool. </p> <p>To disable a native username + password user, the <code>ProviderName</code> value must be <code>Cognito</code> and the <code>ProviderAttributeName</code> must be <code>Cognito_Subject</code>, with the <code>ProviderAttributeValue</code> being the name that is used in the user pool for the user.</p> <p>The <code>ProviderAttributeName</code> must always be <code>Cognito_Subject</code> for social identity providers. The <code>ProviderAttributeValue</code> must always be the exact subject that was used when the user was originally linked as a source user.</p> <p>For de-linking a SAML identity, there are two scenarios. If the linked identity has not yet been used to sign-in, the <code>ProviderAttributeName</code> and <code>ProviderAttributeValue</code> must be the same values that were used for the <code>SourceUser</code> when the identities were originally linked in the call. (If the linking was done with <code>ProviderAttributeName</code> set to <code>Cognito_Subject</code>, the same applies here). However, if the user has already signed in, the <code>ProviderAttributeName</code> must be <code>Cognito_Subject</code> and <code>ProviderAttributeValue</code> must be the subject of the SAML assertion.</p>
-    async fn admin_disable_provider_for_user(
+    /// Presigns the request `admin_disable_provider_for_user` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_admin_disable_provider_for_user(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: AdminDisableProviderForUserRequest,
-    ) -> Result<AdminDisableProviderForUserResponse, RusotoError<AdminDisableProviderForUserError>>
-    {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_admin_disable_provider_for_user_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_admin_disable_provider_for_user_request(
+        &self,
+        input: AdminDisableProviderForUserRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "cognito-idp", &self.region, "/");
+        request.set_operation("AdminDisableProviderForUser");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -12906,28 +14658,53 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `admin_disable_user`, also returning the metadata of the response.
+    pub async fn admin_disable_user_with_metadata(
+        &self,
+        input: AdminDisableUserRequest,
+    ) -> Result<(AdminDisableUserResponse, ResponseMetadata), RusotoError<AdminDisableUserError>>
+    {
+        let request = self.build_admin_disable_user_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<AdminDisableProviderForUserResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<AdminDisableUserResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(AdminDisableProviderForUserError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(AdminDisableUserError::from_response(response))
         }
     }
 
-    /// <p>Disables the specified user.</p> <p>Calling this action requires developer credentials.</p>
-    async fn admin_disable_user(
+    /// Presigns the request `admin_disable_user` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_admin_disable_user(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: AdminDisableUserRequest,
-    ) -> Result<AdminDisableUserResponse, RusotoError<AdminDisableUserError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_admin_disable_user_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_admin_disable_user_request(&self, input: AdminDisableUserRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "cognito-idp", &self.region, "/");
+        request.set_operation("AdminDisableUser");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -12937,28 +14714,53 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `admin_enable_user`, also returning the metadata of the response.
+    pub async fn admin_enable_user_with_metadata(
+        &self,
+        input: AdminEnableUserRequest,
+    ) -> Result<(AdminEnableUserResponse, ResponseMetadata), RusotoError<AdminEnableUserError>>
+    {
+        let request = self.build_admin_enable_user_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<AdminDisableUserResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<AdminEnableUserResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(AdminDisableUserError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(AdminEnableUserError::from_response(response))
         }
     }
 
-    /// <p>Enables the specified user as an administrator. Works on any user.</p> <p>Calling this action requires developer credentials.</p>
-    async fn admin_enable_user(
+    /// Presigns the request `admin_enable_user` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_admin_enable_user(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: AdminEnableUserRequest,
-    ) -> Result<AdminEnableUserResponse, RusotoError<AdminEnableUserError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_admin_enable_user_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_admin_enable_user_request(&self, input: AdminEnableUserRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "cognito-idp", &self.region, "/");
+        request.set_operation("AdminEnableUser");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -12968,27 +14770,47 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `admin_forget_device`, also returning the metadata of the response.
+    pub async fn admin_forget_device_with_metadata(
+        &self,
+        input: AdminForgetDeviceRequest,
+    ) -> Result<((), ResponseMetadata), RusotoError<AdminForgetDeviceError>> {
+        let request = self.build_admin_forget_device_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<AdminEnableUserResponse, _>()
+            std::mem::drop(response);
+            Ok(((), metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(AdminEnableUserError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(AdminForgetDeviceError::from_response(response))
         }
     }
 
-    /// <p>Forgets the device, as an administrator.</p> <p>Calling this action requires developer credentials.</p>
-    async fn admin_forget_device(
+    /// Presigns the request `admin_forget_device` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_admin_forget_device(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: AdminForgetDeviceRequest,
-    ) -> Result<(), RusotoError<AdminForgetDeviceError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_admin_forget_device_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_admin_forget_device_request(&self, input: AdminForgetDeviceRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "cognito-idp", &self.region, "/");
+        request.set_operation("AdminForgetDevice");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -12998,27 +14820,52 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `admin_get_device`, also returning the metadata of the response.
+    pub async fn admin_get_device_with_metadata(
+        &self,
+        input: AdminGetDeviceRequest,
+    ) -> Result<(AdminGetDeviceResponse, ResponseMetadata), RusotoError<AdminGetDeviceError>> {
+        let request = self.build_admin_get_device_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            std::mem::drop(response);
-            Ok(())
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<AdminGetDeviceResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(AdminForgetDeviceError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(AdminGetDeviceError::from_response(response))
         }
     }
 
-    /// <p>Gets the device, as an administrator.</p> <p>Calling this action requires developer credentials.</p>
-    async fn admin_get_device(
+    /// Presigns the request `admin_get_device` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_admin_get_device(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: AdminGetDeviceRequest,
-    ) -> Result<AdminGetDeviceResponse, RusotoError<AdminGetDeviceError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_admin_get_device_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_admin_get_device_request(&self, input: AdminGetDeviceRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "cognito-idp", &self.region, "/");
+        request.set_operation("AdminGetDevice");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -13028,27 +14875,52 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `admin_get_user`, also returning the metadata of the response.
+    pub async fn admin_get_user_with_metadata(
+        &self,
+        input: AdminGetUserRequest,
+    ) -> Result<(AdminGetUserResponse, ResponseMetadata), RusotoError<AdminGetUserError>> {
+        let request = self.build_admin_get_user_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<AdminGetDeviceResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<AdminGetUserResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(AdminGetDeviceError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(AdminGetUserError::from_response(response))
         }
     }
 
-    /// <p>Gets the specified user by user name in a user pool as an administrator. Works on any user.</p> <p>Calling this action requires developer credentials.</p>
-    async fn admin_get_user(
+    /// Presigns the request `admin_get_user` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_admin_get_user(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: AdminGetUserRequest,
-    ) -> Result<AdminGetUserResponse, RusotoError<AdminGetUserError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_admin_get_user_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_admin_get_user_request(&self, input: AdminGetUserRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "cognito-idp", &self.region, "/");
+        request.set_operation("AdminGetUser");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -13058,27 +14930,53 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `admin_initiate_auth`, also returning the metadata of the response.
+    pub async fn admin_initiate_auth_with_metadata(
+        &self,
+        input: AdminInitiateAuthRequest,
+    ) -> Result<(AdminInitiateAuthResponse, ResponseMetadata), RusotoError<AdminInitiateAuthError>>
+    {
+        let request = self.build_admin_initiate_auth_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<AdminGetUserResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<AdminInitiateAuthResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(AdminGetUserError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(AdminInitiateAuthError::from_response(response))
         }
     }
 
-    /// <p>Initiates the authentication flow, as an administrator.</p> <p>Calling this action requires developer credentials.</p>
-    async fn admin_initiate_auth(
+    /// Presigns the request `admin_initiate_auth` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_admin_initiate_auth(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: AdminInitiateAuthRequest,
-    ) -> Result<AdminInitiateAuthResponse, RusotoError<AdminInitiateAuthError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_admin_initiate_auth_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_admin_initiate_auth_request(&self, input: AdminInitiateAuthRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "cognito-idp", &self.region, "/");
+        request.set_operation("AdminInitiateAuth");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -13088,28 +14986,58 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `admin_link_provider_for_user`, also returning the metadata of the response.
+    pub async fn admin_link_provider_for_user_with_metadata(
+        &self,
+        input: AdminLinkProviderForUserRequest,
+    ) -> Result<
+        (AdminLinkProviderForUserResponse, ResponseMetadata),
+        RusotoError<AdminLinkProviderForUserError>,
+    > {
+        let request = self.build_admin_link_provider_for_user_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<AdminInitiateAuthResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<AdminLinkProviderForUserResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(AdminInitiateAuthError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(AdminLinkProviderForUserError::from_response(response))
         }
     }
 
-    /// <p>Links an existing user account in a user pool (<code>DestinationUser</code>) to an identity from an external identity provider (<code>SourceUser</code>) based on a specified attribute name and value from the external identity provider. This allows you to create a link from the existing user account to an external federated user identity that has not yet been used to sign in, so that the federated user identity can be used to sign in as the existing user account. </p> <p> For example, if there is an existing user with a username and password, this API links that user to a federated user identity, so that when the federated user identity is used, the user signs in as the existing user account. </p> <important> <p>Because this API allows a user with an external federated identity to sign in as an existing user in the user pool, it is critical that it only be used with external identity providers and provider attributes that have been trusted by the application owner.</p> </important> <p>See also .</p> <p>This action is enabled only for admin access and requires developer credentials.</p>
-    async fn admin_link_provider_for_user(
+    /// Presigns the request `admin_link_provider_for_user` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_admin_link_provider_for_user(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: AdminLinkProviderForUserRequest,
-    ) -> Result<AdminLinkProviderForUserResponse, RusotoError<AdminLinkProviderForUserError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_admin_link_provider_for_user_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_admin_link_provider_for_user_request(
+        &self,
+        input: AdminLinkProviderForUserRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "cognito-idp", &self.region, "/");
+        request.set_operation("AdminLinkProviderForUser");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -13119,28 +15047,53 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `admin_list_devices`, also returning the metadata of the response.
+    pub async fn admin_list_devices_with_metadata(
+        &self,
+        input: AdminListDevicesRequest,
+    ) -> Result<(AdminListDevicesResponse, ResponseMetadata), RusotoError<AdminListDevicesError>>
+    {
+        let request = self.build_admin_list_devices_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<AdminLinkProviderForUserResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<AdminListDevicesResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(AdminLinkProviderForUserError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(AdminListDevicesError::from_response(response))
         }
     }
 
-    /// <p>Lists devices, as an administrator.</p> <p>Calling this action requires developer credentials.</p>
-    async fn admin_list_devices(
+    /// Presigns the request `admin_list_devices` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_admin_list_devices(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: AdminListDevicesRequest,
-    ) -> Result<AdminListDevicesResponse, RusotoError<AdminListDevicesError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_admin_list_devices_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_admin_list_devices_request(&self, input: AdminListDevicesRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "cognito-idp", &self.region, "/");
+        request.set_operation("AdminListDevices");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -13150,28 +15103,58 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `admin_list_groups_for_user`, also returning the metadata of the response.
+    pub async fn admin_list_groups_for_user_with_metadata(
+        &self,
+        input: AdminListGroupsForUserRequest,
+    ) -> Result<
+        (AdminListGroupsForUserResponse, ResponseMetadata),
+        RusotoError<AdminListGroupsForUserError>,
+    > {
+        let request = self.build_admin_list_groups_for_user_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<AdminListDevicesResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<AdminListGroupsForUserResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(AdminListDevicesError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(AdminListGroupsForUserError::from_response(response))
         }
     }
 
-    /// <p>Lists the groups that the user belongs to.</p> <p>Calling this action requires developer credentials.</p>
-    async fn admin_list_groups_for_user(
+    /// Presigns the request `admin_list_groups_for_user` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_admin_list_groups_for_user(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: AdminListGroupsForUserRequest,
-    ) -> Result<AdminListGroupsForUserResponse, RusotoError<AdminListGroupsForUserError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_admin_list_groups_for_user_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_admin_list_groups_for_user_request(
+        &self,
+        input: AdminListGroupsForUserRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "cognito-idp", &self.region, "/");
+        request.set_operation("AdminListGroupsForUser");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -13181,28 +15164,58 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `admin_list_user_auth_events`, also returning the metadata of the response.
+    pub async fn admin_list_user_auth_events_with_metadata(
+        &self,
+        input: AdminListUserAuthEventsRequest,
+    ) -> Result<
+        (AdminListUserAuthEventsResponse, ResponseMetadata),
+        RusotoError<AdminListUserAuthEventsError>,
+    > {
+        let request = self.build_admin_list_user_auth_events_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<AdminListGroupsForUserResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<AdminListUserAuthEventsResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(AdminListGroupsForUserError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(AdminListUserAuthEventsError::from_response(response))
         }
     }
 
-    /// <p>Lists a history of user activity and any risks detected as part of Amazon Cognito advanced security.</p>
-    async fn admin_list_user_auth_events(
+    /// Presigns the request `admin_list_user_auth_events` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_admin_list_user_auth_events(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: AdminListUserAuthEventsRequest,
-    ) -> Result<AdminListUserAuthEventsResponse, RusotoError<AdminListUserAuthEventsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_admin_list_user_auth_events_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_admin_list_user_auth_events_request(
+        &self,
+        input: AdminListUserAuthEventsRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "cognito-idp", &self.region, "/");
+        request.set_operation("AdminListUserAuthEvents");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -13212,28 +15225,50 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `admin_remove_user_from_group`, also returning the metadata of the response.
+    pub async fn admin_remove_user_from_group_with_metadata(
+        &self,
+        input: AdminRemoveUserFromGroupRequest,
+    ) -> Result<((), ResponseMetadata), RusotoError<AdminRemoveUserFromGroupError>> {
+        let request = self.build_admin_remove_user_from_group_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<AdminListUserAuthEventsResponse, _>()
+            std::mem::drop(response);
+            Ok(((), metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(AdminListUserAuthEventsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(AdminRemoveUserFromGroupError::from_response(response))
         }
     }
 
-    /// <p>Removes the specified user from the specified group.</p> <p>Calling this action requires developer credentials.</p>
-    async fn admin_remove_user_from_group(
+    /// Presigns the request `admin_remove_user_from_group` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_admin_remove_user_from_group(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: AdminRemoveUserFromGroupRequest,
-    ) -> Result<(), RusotoError<AdminRemoveUserFromGroupError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_admin_remove_user_from_group_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_admin_remove_user_from_group_request(
+        &self,
+        input: AdminRemoveUserFromGroupRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "cognito-idp", &self.region, "/");
+        request.set_operation("AdminRemoveUserFromGroup");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -13243,27 +15278,58 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `admin_reset_user_password`, also returning the metadata of the response.
+    pub async fn admin_reset_user_password_with_metadata(
+        &self,
+        input: AdminResetUserPasswordRequest,
+    ) -> Result<
+        (AdminResetUserPasswordResponse, ResponseMetadata),
+        RusotoError<AdminResetUserPasswordError>,
+    > {
+        let request = self.build_admin_reset_user_password_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            std::mem::drop(response);
-            Ok(())
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<AdminResetUserPasswordResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(AdminRemoveUserFromGroupError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(AdminResetUserPasswordError::from_response(response))
         }
     }
 
-    /// <p>Resets the specified user's password in a user pool as an administrator. Works on any user.</p> <p>When a developer calls this API, the current password is invalidated, so it must be changed. If a user tries to sign in after the API is called, the app will get a PasswordResetRequiredException exception back and should direct the user down the flow to reset the password, which is the same as the forgot password flow. In addition, if the user pool has phone verification selected and a verified phone number exists for the user, or if email verification is selected and a verified email exists for the user, calling this API will also result in sending a message to the end user with the code to change their password.</p> <p>Calling this action requires developer credentials.</p>
-    async fn admin_reset_user_password(
+    /// Presigns the request `admin_reset_user_password` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_admin_reset_user_password(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: AdminResetUserPasswordRequest,
-    ) -> Result<AdminResetUserPasswordResponse, RusotoError<AdminResetUserPasswordError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_admin_reset_user_password_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_admin_reset_user_password_request(
+        &self,
+        input: AdminResetUserPasswordRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "cognito-idp", &self.region, "/");
+        request.set_operation("AdminResetUserPassword");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -13273,29 +15339,58 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `admin_respond_to_auth_challenge`, also returning the metadata of the response.
+    pub async fn admin_respond_to_auth_challenge_with_metadata(
+        &self,
+        input: AdminRespondToAuthChallengeRequest,
+    ) -> Result<
+        (AdminRespondToAuthChallengeResponse, ResponseMetadata),
+        RusotoError<AdminRespondToAuthChallengeError>,
+    > {
+        let request = self.build_admin_respond_to_auth_challenge_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<AdminResetUserPasswordResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<AdminRespondToAuthChallengeResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(AdminResetUserPasswordError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(AdminRespondToAuthChallengeError::from_response(response))
         }
     }
 
-    /// <p>Responds to an authentication challenge, as an administrator.</p> <p>Calling this action requires developer credentials.</p>
-    async fn admin_respond_to_auth_challenge(
+    /// Presigns the request `admin_respond_to_auth_challenge` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_admin_respond_to_auth_challenge(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: AdminRespondToAuthChallengeRequest,
-    ) -> Result<AdminRespondToAuthChallengeResponse, RusotoError<AdminRespondToAuthChallengeError>>
-    {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_admin_respond_to_auth_challenge_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_admin_respond_to_auth_challenge_request(
+        &self,
+        input: AdminRespondToAuthChallengeRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "cognito-idp", &self.region, "/");
+        request.set_operation("AdminRespondToAuthChallenge");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -13305,29 +15400,58 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `admin_set_user_mfa_preference`, also returning the metadata of the response.
+    pub async fn admin_set_user_mfa_preference_with_metadata(
+        &self,
+        input: AdminSetUserMFAPreferenceRequest,
+    ) -> Result<
+        (AdminSetUserMFAPreferenceResponse, ResponseMetadata),
+        RusotoError<AdminSetUserMFAPreferenceError>,
+    > {
+        let request = self.build_admin_set_user_mfa_preference_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<AdminRespondToAuthChallengeResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<AdminSetUserMFAPreferenceResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(AdminRespondToAuthChallengeError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(AdminSetUserMFAPreferenceError::from_response(response))
         }
     }
 
-    /// <p>Sets the user's multi-factor authentication (MFA) preference, including which MFA options are enabled and if any are preferred. Only one factor can be set as preferred. The preferred MFA factor will be used to authenticate a user if multiple factors are enabled. If multiple options are enabled and no preference is set, a challenge to choose an MFA option will be returned during sign in.</p>
-    async fn admin_set_user_mfa_preference(
+    /// Presigns the request `admin_set_user_mfa_preference` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_admin_set_user_mfa_preference(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: AdminSetUserMFAPreferenceRequest,
-    ) -> Result<AdminSetUserMFAPreferenceResponse, RusotoError<AdminSetUserMFAPreferenceError>>
-    {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_admin_set_user_mfa_preference_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_admin_set_user_mfa_preference_request(
+        &self,
+        input: AdminSetUserMFAPreferenceRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "cognito-idp", &self.region, "/");
+        request.set_operation("AdminSetUserMFAPreference");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -13337,28 +15461,58 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `admin_set_user_password`, also returning the metadata of the response.
+    pub async fn admin_set_user_password_with_metadata(
+        &self,
+        input: AdminSetUserPasswordRequest,
+    ) -> Result<
+        (AdminSetUserPasswordResponse, ResponseMetadata),
+        RusotoError<AdminSetUserPasswordError>,
+    > {
+        let request = self.build_admin_set_user_password_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<AdminSetUserMFAPreferenceResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<AdminSetUserPasswordResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(AdminSetUserMFAPreferenceError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(AdminSetUserPasswordError::from_response(response))
         }
     }
 
-    /// <p>Sets the specified user's password in a user pool as an administrator. Works on any user. </p> <p>The password can be temporary or permanent. If it is temporary, the user status will be placed into the <code>FORCE_CHANGE_PASSWORD</code> state. When the user next tries to sign in, the InitiateAuth/AdminInitiateAuth response will contain the <code>NEW_PASSWORD_REQUIRED</code> challenge. If the user does not sign in before it expires, the user will not be able to sign in and their password will need to be reset by an administrator. </p> <p>Once the user has set a new password, or the password is permanent, the user status will be set to <code>Confirmed</code>.</p>
-    async fn admin_set_user_password(
+    /// Presigns the request `admin_set_user_password` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_admin_set_user_password(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: AdminSetUserPasswordRequest,
-    ) -> Result<AdminSetUserPasswordResponse, RusotoError<AdminSetUserPasswordError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_admin_set_user_password_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_admin_set_user_password_request(
+        &self,
+        input: AdminSetUserPasswordRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "cognito-idp", &self.region, "/");
+        request.set_operation("AdminSetUserPassword");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -13368,28 +15522,58 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `admin_set_user_settings`, also returning the metadata of the response.
+    pub async fn admin_set_user_settings_with_metadata(
+        &self,
+        input: AdminSetUserSettingsRequest,
+    ) -> Result<
+        (AdminSetUserSettingsResponse, ResponseMetadata),
+        RusotoError<AdminSetUserSettingsError>,
+    > {
+        let request = self.build_admin_set_user_settings_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<AdminSetUserPasswordResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<AdminSetUserSettingsResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(AdminSetUserPasswordError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(AdminSetUserSettingsError::from_response(response))
         }
     }
 
-    /// <p> <i>This action is no longer supported.</i> You can use it to configure only SMS MFA. You can't use it to configure TOTP software token MFA. To configure either type of MFA, use the <a>AdminSetUserMFAPreference</a> action instead.</p>
-    async fn admin_set_user_settings(
+    /// Presigns the request `admin_set_user_settings` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_admin_set_user_settings(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: AdminSetUserSettingsRequest,
-    ) -> Result<AdminSetUserSettingsResponse, RusotoError<AdminSetUserSettingsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_admin_set_user_settings_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_admin_set_user_settings_request(
+        &self,
+        input: AdminSetUserSettingsRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "cognito-idp", &self.region, "/");
+        request.set_operation("AdminSetUserSettings");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -13399,29 +15583,58 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `admin_update_auth_event_feedback`, also returning the metadata of the response.
+    pub async fn admin_update_auth_event_feedback_with_metadata(
+        &self,
+        input: AdminUpdateAuthEventFeedbackRequest,
+    ) -> Result<
+        (AdminUpdateAuthEventFeedbackResponse, ResponseMetadata),
+        RusotoError<AdminUpdateAuthEventFeedbackError>,
+    > {
+        let request = self.build_admin_update_auth_event_feedback_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<AdminSetUserSettingsResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<AdminUpdateAuthEventFeedbackResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(AdminSetUserSettingsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(AdminUpdateAuthEventFeedbackError::from_response(response))
         }
     }
 
-    /// <p>Provides feedback for an authentication event as to whether it was from a valid user. This feedback is used for improving the risk evaluation decision for the user pool as part of Amazon Cognito advanced security.</p>
-    async fn admin_update_auth_event_feedback(
+    /// Presigns the request `admin_update_auth_event_feedback` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_admin_update_auth_event_feedback(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: AdminUpdateAuthEventFeedbackRequest,
-    ) -> Result<AdminUpdateAuthEventFeedbackResponse, RusotoError<AdminUpdateAuthEventFeedbackError>>
-    {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_admin_update_auth_event_feedback_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_admin_update_auth_event_feedback_request(
+        &self,
+        input: AdminUpdateAuthEventFeedbackRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "cognito-idp", &self.region, "/");
+        request.set_operation("AdminUpdateAuthEventFeedback");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -13431,28 +15644,58 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `admin_update_device_status`, also returning the metadata of the response.
+    pub async fn admin_update_device_status_with_metadata(
+        &self,
+        input: AdminUpdateDeviceStatusRequest,
+    ) -> Result<
+        (AdminUpdateDeviceStatusResponse, ResponseMetadata),
+        RusotoError<AdminUpdateDeviceStatusError>,
+    > {
+        let request = self.build_admin_update_device_status_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<AdminUpdateAuthEventFeedbackResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<AdminUpdateDeviceStatusResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(AdminUpdateAuthEventFeedbackError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(AdminUpdateDeviceStatusError::from_response(response))
         }
     }
 
-    /// <p>Updates the device status as an administrator.</p> <p>Calling this action requires developer credentials.</p>
-    async fn admin_update_device_status(
+    /// Presigns the request `admin_update_device_status` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_admin_update_device_status(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: AdminUpdateDeviceStatusRequest,
-    ) -> Result<AdminUpdateDeviceStatusResponse, RusotoError<AdminUpdateDeviceStatusError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_admin_update_device_status_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_admin_update_device_status_request(
+        &self,
+        input: AdminUpdateDeviceStatusRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "cognito-idp", &self.region, "/");
+        request.set_operation("AdminUpdateDeviceStatus");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -13462,29 +15705,58 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `admin_update_user_attributes`, also returning the metadata of the response.
+    pub async fn admin_update_user_attributes_with_metadata(
+        &self,
+        input: AdminUpdateUserAttributesRequest,
+    ) -> Result<
+        (AdminUpdateUserAttributesResponse, ResponseMetadata),
+        RusotoError<AdminUpdateUserAttributesError>,
+    > {
+        let request = self.build_admin_update_user_attributes_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<AdminUpdateDeviceStatusResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<AdminUpdateUserAttributesResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(AdminUpdateDeviceStatusError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(AdminUpdateUserAttributesError::from_response(response))
         }
     }
 
-    /// <p>Updates the specified user's attributes, including developer attributes, as an administrator. Works on any user.</p> <p>For custom attributes, you must prepend the <code>custom:</code> prefix to the attribute name.</p> <p>In addition to updating user attributes, this API can also be used to mark phone and email as verified.</p> <p>Calling this action requires developer credentials.</p>
-    async fn admin_update_user_attributes(
+    /// Presigns the request `admin_update_user_attributes` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_admin_update_user_attributes(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: AdminUpdateUserAttributesRequest,
-    ) -> Result<AdminUpdateUserAttributesResponse, RusotoError<AdminUpdateUserAttributesError>>
-    {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_admin_update_user_attributes_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_admin_update_user_attributes_request(
+        &self,
+        input: AdminUpdateUserAttributesRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "cognito-idp", &self.region, "/");
+        request.set_operation("AdminUpdateUserAttributes");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -13494,28 +15766,58 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `admin_user_global_sign_out`, also returning the metadata of the response.
+    pub async fn admin_user_global_sign_out_with_metadata(
+        &self,
+        input: AdminUserGlobalSignOutRequest,
+    ) -> Result<
+        (AdminUserGlobalSignOutResponse, ResponseMetadata),
+        RusotoError<AdminUserGlobalSignOutError>,
+    > {
+        let request = self.build_admin_user_global_sign_out_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<AdminUpdateUserAttributesResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<AdminUserGlobalSignOutResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(AdminUpdateUserAttributesError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(AdminUserGlobalSignOutError::from_response(response))
         }
     }
 
-    /// <p>Signs out users from all devices, as an administrator. It also invalidates all refresh tokens issued to a user. The user's current access and Id tokens remain valid until their expiry. Access and Id tokens expire one hour after they are issued.</p> <p>Calling this action requires developer credentials.</p>
-    async fn admin_user_global_sign_out(
+    /// Presigns the request `admin_user_global_sign_out` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_admin_user_global_sign_out(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: AdminUserGlobalSignOutRequest,
-    ) -> Result<AdminUserGlobalSignOutResponse, RusotoError<AdminUserGlobalSignOutError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_admin_user_global_sign_out_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_admin_user_global_sign_out_request(
+        &self,
+        input: AdminUserGlobalSignOutRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "cognito-idp", &self.region, "/");
+        request.set_operation("AdminUserGlobalSignOut");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -13525,28 +15827,58 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `associate_software_token`, also returning the metadata of the response.
+    pub async fn associate_software_token_with_metadata(
+        &self,
+        input: AssociateSoftwareTokenRequest,
+    ) -> Result<
+        (AssociateSoftwareTokenResponse, ResponseMetadata),
+        RusotoError<AssociateSoftwareTokenError>,
+    > {
+        let request = self.build_associate_software_token_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<AdminUserGlobalSignOutResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<AssociateSoftwareTokenResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(AdminUserGlobalSignOutError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(AssociateSoftwareTokenError::from_response(response))
         }
     }
 
-    /// <p>Returns a unique generated shared secret key code for the user account. The request takes an access token or a session string, but not both.</p>
-    async fn associate_software_token(
+    /// Presigns the request `associate_software_token` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_associate_software_token(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: AssociateSoftwareTokenRequest,
-    ) -> Result<AssociateSoftwareTokenResponse, RusotoError<AssociateSoftwareTokenError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_associate_software_token_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_associate_software_token_request(
+        &self,
+        input: AssociateSoftwareTokenRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "cognito-idp", &self.region, "/");
+        request.set_operation("AssociateSoftwareToken");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -13556,28 +15888,52 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `change_password`, also returning the metadata of the response.
+    pub async fn change_password_with_metadata(
+        &self,
+        input: ChangePasswordRequest,
+    ) -> Result<(ChangePasswordResponse, ResponseMetadata), RusotoError<ChangePasswordError>> {
+        let request = self.build_change_password_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<AssociateSoftwareTokenResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ChangePasswordResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(AssociateSoftwareTokenError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ChangePasswordError::from_response(response))
         }
     }
 
-    /// <p>Changes the password for a specified user in a user pool.</p>
-    async fn change_password(
+    /// Presigns the request `change_password` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_change_password(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ChangePasswordRequest,
-    ) -> Result<ChangePasswordResponse, RusotoError<ChangePasswordError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_change_password_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_change_password_request(&self, input: ChangePasswordRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "cognito-idp", &self.region, "/");
+        request.set_operation("ChangePassword");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -13587,27 +15943,52 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `confirm_device`, also returning the metadata of the response.
+    pub async fn confirm_device_with_metadata(
+        &self,
+        input: ConfirmDeviceRequest,
+    ) -> Result<(ConfirmDeviceResponse, ResponseMetadata), RusotoError<ConfirmDeviceError>> {
+        let request = self.build_confirm_device_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<ChangePasswordResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ConfirmDeviceResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ChangePasswordError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ConfirmDeviceError::from_response(response))
         }
     }
 
-    /// <p>Confirms tracking of the device. This API call is the call that begins device tracking.</p>
-    async fn confirm_device(
+    /// Presigns the request `confirm_device` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_confirm_device(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ConfirmDeviceRequest,
-    ) -> Result<ConfirmDeviceResponse, RusotoError<ConfirmDeviceError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_confirm_device_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_confirm_device_request(&self, input: ConfirmDeviceRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "cognito-idp", &self.region, "/");
+        request.set_operation("ConfirmDevice");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -13617,27 +15998,58 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `confirm_forgot_password`, also returning the metadata of the response.
+    pub async fn confirm_forgot_password_with_metadata(
+        &self,
+        input: ConfirmForgotPasswordRequest,
+    ) -> Result<
+        (ConfirmForgotPasswordResponse, ResponseMetadata),
+        RusotoError<ConfirmForgotPasswordError>,
+    > {
+        let request = self.build_confirm_forgot_password_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<ConfirmDeviceResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ConfirmForgotPasswordResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ConfirmDeviceError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ConfirmForgotPasswordError::from_response(response))
         }
     }
 
-    /// <p>Allows a user to enter a confirmation code to reset a forgotten password.</p>
-    async fn confirm_forgot_password(
+    /// Presigns the request `confirm_forgot_password` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_confirm_forgot_password(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ConfirmForgotPasswordRequest,
-    ) -> Result<ConfirmForgotPasswordResponse, RusotoError<ConfirmForgotPasswordError>> {
-        let mut request = SignedRequest::new("POST", "cognito-idp", &self.region, "/");
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_confirm_forgot_password_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_confirm_forgot_password_request(
+        &self,
+        input: ConfirmForgotPasswordRequest,
+    ) -> SignedRequest {
+        let mut request = SignedRequest::new("POST", "cognito-idp", &self.region, "/");
+        request.set_operation("ConfirmForgotPassword");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -13647,28 +16059,52 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `confirm_sign_up`, also returning the metadata of the response.
+    pub async fn confirm_sign_up_with_metadata(
+        &self,
+        input: ConfirmSignUpRequest,
+    ) -> Result<(ConfirmSignUpResponse, ResponseMetadata), RusotoError<ConfirmSignUpError>> {
+        let request = self.build_confirm_sign_up_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<ConfirmForgotPasswordResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ConfirmSignUpResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ConfirmForgotPasswordError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ConfirmSignUpError::from_response(response))
         }
     }
 
-    /// <p>Confirms registration of a user and handles the existing alias from a previous user.</p>
-    async fn confirm_sign_up(
+    /// Presigns the request `confirm_sign_up` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_confirm_sign_up(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ConfirmSignUpRequest,
-    ) -> Result<ConfirmSignUpResponse, RusotoError<ConfirmSignUpError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_confirm_sign_up_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_confirm_sign_up_request(&self, input: ConfirmSignUpRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "cognito-idp", &self.region, "/");
+        request.set_operation("ConfirmSignUp");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -13678,27 +16114,52 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `create_group`, also returning the metadata of the response.
+    pub async fn create_group_with_metadata(
+        &self,
+        input: CreateGroupRequest,
+    ) -> Result<(CreateGroupResponse, ResponseMetadata), RusotoError<CreateGroupError>> {
+        let request = self.build_create_group_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<ConfirmSignUpResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<CreateGroupResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ConfirmSignUpError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(CreateGroupError::from_response(response))
         }
     }
 
-    /// <p>Creates a new group in the specified user pool.</p> <p>Calling this action requires developer credentials.</p>
-    async fn create_group(
+    /// Presigns the request `create_group` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_group(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: CreateGroupRequest,
-    ) -> Result<CreateGroupResponse, RusotoError<CreateGroupError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_group_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_group_request(&self, input: CreateGroupRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "cognito-idp", &self.region, "/");
+        request.set_operation("CreateGroup");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -13708,27 +16169,58 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `create_identity_provider`, also returning the metadata of the response.
+    pub async fn create_identity_provider_with_metadata(
+        &self,
+        input: CreateIdentityProviderRequest,
+    ) -> Result<
+        (CreateIdentityProviderResponse, ResponseMetadata),
+        RusotoError<CreateIdentityProviderError>,
+    > {
+        let request = self.build_create_identity_provider_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<CreateGroupResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<CreateIdentityProviderResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(CreateGroupError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(CreateIdentityProviderError::from_response(response))
         }
     }
 
-    /// <p>Creates an identity provider for a user pool.</p>
-    async fn create_identity_provider(
+    /// Presigns the request `create_identity_provider` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_identity_provider(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: CreateIdentityProviderRequest,
-    ) -> Result<CreateIdentityProviderResponse, RusotoError<CreateIdentityProviderError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_identity_provider_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_identity_provider_request(
+        &self,
+        input: CreateIdentityProviderRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "cognito-idp", &self.region, "/");
+        request.set_operation("CreateIdentityProvider");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -13738,28 +16230,58 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `create_resource_server`, also returning the metadata of the response.
+    pub async fn create_resource_server_with_metadata(
+        &self,
+        input: CreateResourceServerRequest,
+    ) -> Result<
+        (CreateResourceServerResponse, ResponseMetadata),
+        RusotoError<CreateResourceServerError>,
+    > {
+        let request = self.build_create_resource_server_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<CreateIdentityProviderResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<CreateResourceServerResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(CreateIdentityProviderError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(CreateResourceServerError::from_response(response))
         }
     }
 
-    /// <p>Creates a new OAuth2.0 resource server and defines custom scopes in it.</p>
-    async fn create_resource_server(
+    /// Presigns the request `create_resource_server` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_resource_server(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: CreateResourceServerRequest,
-    ) -> Result<CreateResourceServerResponse, RusotoError<CreateResourceServerError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_resource_server_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_resource_server_request(
+        &self,
+        input: CreateResourceServerRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "cognito-idp", &self.region, "/");
+        request.set_operation("CreateResourceServer");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -13769,28 +16291,58 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `create_user_import_job`, also returning the metadata of the response.
+    pub async fn create_user_import_job_with_metadata(
+        &self,
+        input: CreateUserImportJobRequest,
+    ) -> Result<
+        (CreateUserImportJobResponse, ResponseMetadata),
+        RusotoError<CreateUserImportJobError>,
+    > {
+        let request = self.build_create_user_import_job_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<CreateResourceServerResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<CreateUserImportJobResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(CreateResourceServerError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(CreateUserImportJobError::from_response(response))
         }
     }
 
-    /// <p>Creates the user import job.</p>
-    async fn create_user_import_job(
+    /// Presigns the request `create_user_import_job` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_user_import_job(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: CreateUserImportJobRequest,
-    ) -> Result<CreateUserImportJobResponse, RusotoError<CreateUserImportJobError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_user_import_job_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_user_import_job_request(
+        &self,
+        input: CreateUserImportJobRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "cognito-idp", &self.region, "/");
+        request.set_operation("CreateUserImportJob");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -13800,28 +16352,52 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `create_user_pool`, also returning the metadata of the response.
+    pub async fn create_user_pool_with_metadata(
+        &self,
+        input: CreateUserPoolRequest,
+    ) -> Result<(CreateUserPoolResponse, ResponseMetadata), RusotoError<CreateUserPoolError>> {
+        let request = self.build_create_user_pool_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<CreateUserImportJobResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<CreateUserPoolResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(CreateUserImportJobError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(CreateUserPoolError::from_response(response))
         }
     }
 
-    /// <p>Creates a new Amazon Cognito user pool and sets the password policy for the pool.</p>
-    async fn create_user_pool(
+    /// Presigns the request `create_user_pool` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_user_pool(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: CreateUserPoolRequest,
-    ) -> Result<CreateUserPoolResponse, RusotoError<CreateUserPoolError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_user_pool_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_user_pool_request(&self, input: CreateUserPoolRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "cognito-idp", &self.region, "/");
+        request.set_operation("CreateUserPool");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -13831,27 +16407,58 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `create_user_pool_client`, also returning the metadata of the response.
+    pub async fn create_user_pool_client_with_metadata(
+        &self,
+        input: CreateUserPoolClientRequest,
+    ) -> Result<
+        (CreateUserPoolClientResponse, ResponseMetadata),
+        RusotoError<CreateUserPoolClientError>,
+    > {
+        let request = self.build_create_user_pool_client_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<CreateUserPoolResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<CreateUserPoolClientResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(CreateUserPoolError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(CreateUserPoolClientError::from_response(response))
         }
     }
 
-    /// <p>Creates the user pool client.</p>
-    async fn create_user_pool_client(
+    /// Presigns the request `create_user_pool_client` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_user_pool_client(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: CreateUserPoolClientRequest,
-    ) -> Result<CreateUserPoolClientResponse, RusotoError<CreateUserPoolClientError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_user_pool_client_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_user_pool_client_request(
+        &self,
+        input: CreateUserPoolClientRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "cognito-idp", &self.region, "/");
+        request.set_operation("CreateUserPoolClient");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -13861,28 +16468,58 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `create_user_pool_domain`, also returning the metadata of the response.
+    pub async fn create_user_pool_domain_with_metadata(
+        &self,
+        input: CreateUserPoolDomainRequest,
+    ) -> Result<
+        (CreateUserPoolDomainResponse, ResponseMetadata),
+        RusotoError<CreateUserPoolDomainError>,
+    > {
+        let request = self.build_create_user_pool_domain_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<CreateUserPoolClientResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<CreateUserPoolDomainResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(CreateUserPoolClientError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(CreateUserPoolDomainError::from_response(response))
         }
     }
 
-    /// <p>Creates a new domain for a user pool.</p>
-    async fn create_user_pool_domain(
+    /// Presigns the request `create_user_pool_domain` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_user_pool_domain(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: CreateUserPoolDomainRequest,
-    ) -> Result<CreateUserPoolDomainResponse, RusotoError<CreateUserPoolDomainError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_user_pool_domain_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_user_pool_domain_request(
+        &self,
+        input: CreateUserPoolDomainRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "cognito-idp", &self.region, "/");
+        request.set_operation("CreateUserPoolDomain");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -13892,28 +16529,47 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `delete_group`, also returning the metadata of the response.
+    pub async fn delete_group_with_metadata(
+        &self,
+        input: DeleteGroupRequest,
+    ) -> Result<((), ResponseMetadata), RusotoError<DeleteGroupError>> {
+        let request = self.build_delete_group_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<CreateUserPoolDomainResponse, _>()
+            std::mem::drop(response);
+            Ok(((), metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(CreateUserPoolDomainError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DeleteGroupError::from_response(response))
         }
     }
 
-    /// <p>Deletes a group. Currently only groups with no members can be deleted.</p> <p>Calling this action requires developer credentials.</p>
-    async fn delete_group(
+    /// Presigns the request `delete_group` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_group(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteGroupRequest,
-    ) -> Result<(), RusotoError<DeleteGroupError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_group_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_group_request(&self, input: DeleteGroupRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "cognito-idp", &self.region, "/");
+        request.set_operation("DeleteGroup");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -13923,27 +16579,50 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `delete_identity_provider`, also returning the metadata of the response.
+    pub async fn delete_identity_provider_with_metadata(
+        &self,
+        input: DeleteIdentityProviderRequest,
+    ) -> Result<((), ResponseMetadata), RusotoError<DeleteIdentityProviderError>> {
+        let request = self.build_delete_identity_provider_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
             std::mem::drop(response);
-            Ok(())
+            Ok(((), metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteGroupError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DeleteIdentityProviderError::from_response(response))
         }
     }
 
-    /// <p>Deletes an identity provider for a user pool.</p>
-    async fn delete_identity_provider(
+    /// Presigns the request `delete_identity_provider` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_identity_provider(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteIdentityProviderRequest,
-    ) -> Result<(), RusotoError<DeleteIdentityProviderError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_identity_provider_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_identity_provider_request(
+        &self,
+        input: DeleteIdentityProviderRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "cognito-idp", &self.region, "/");
+        request.set_operation("DeleteIdentityProvider");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -13953,27 +16632,50 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `delete_resource_server`, also returning the metadata of the response.
+    pub async fn delete_resource_server_with_metadata(
+        &self,
+        input: DeleteResourceServerRequest,
+    ) -> Result<((), ResponseMetadata), RusotoError<DeleteResourceServerError>> {
+        let request = self.build_delete_resource_server_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
             std::mem::drop(response);
-            Ok(())
+            Ok(((), metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteIdentityProviderError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DeleteResourceServerError::from_response(response))
         }
     }
 
-    /// <p>Deletes a resource server.</p>
-    async fn delete_resource_server(
+    /// Presigns the request `delete_resource_server` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_resource_server(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteResourceServerRequest,
-    ) -> Result<(), RusotoError<DeleteResourceServerError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_resource_server_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_resource_server_request(
+        &self,
+        input: DeleteResourceServerRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "cognito-idp", &self.region, "/");
+        request.set_operation("DeleteResourceServer");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -13983,27 +16685,47 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `delete_user`, also returning the metadata of the response.
+    pub async fn delete_user_with_metadata(
+        &self,
+        input: DeleteUserRequest,
+    ) -> Result<((), ResponseMetadata), RusotoError<DeleteUserError>> {
+        let request = self.build_delete_user_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
             std::mem::drop(response);
-            Ok(())
+            Ok(((), metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteResourceServerError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DeleteUserError::from_response(response))
         }
     }
 
-    /// <p>Allows a user to delete himself or herself.</p>
-    async fn delete_user(
+    /// Presigns the request `delete_user` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_user(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteUserRequest,
-    ) -> Result<(), RusotoError<DeleteUserError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_user_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_user_request(&self, input: DeleteUserRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "cognito-idp", &self.region, "/");
+        request.set_operation("DeleteUser");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -14013,27 +16735,58 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `delete_user_attributes`, also returning the metadata of the response.
+    pub async fn delete_user_attributes_with_metadata(
+        &self,
+        input: DeleteUserAttributesRequest,
+    ) -> Result<
+        (DeleteUserAttributesResponse, ResponseMetadata),
+        RusotoError<DeleteUserAttributesError>,
+    > {
+        let request = self.build_delete_user_attributes_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            std::mem::drop(response);
-            Ok(())
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DeleteUserAttributesResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteUserError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DeleteUserAttributesError::from_response(response))
         }
     }
 
-    /// <p>Deletes the attributes for a user.</p>
-    async fn delete_user_attributes(
+    /// Presigns the request `delete_user_attributes` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_user_attributes(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteUserAttributesRequest,
-    ) -> Result<DeleteUserAttributesResponse, RusotoError<DeleteUserAttributesError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_user_attributes_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_user_attributes_request(
+        &self,
+        input: DeleteUserAttributesRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "cognito-idp", &self.region, "/");
+        request.set_operation("DeleteUserAttributes");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -14043,28 +16796,47 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `delete_user_pool`, also returning the metadata of the response.
+    pub async fn delete_user_pool_with_metadata(
+        &self,
+        input: DeleteUserPoolRequest,
+    ) -> Result<((), ResponseMetadata), RusotoError<DeleteUserPoolError>> {
+        let request = self.build_delete_user_pool_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DeleteUserAttributesResponse, _>()
+            std::mem::drop(response);
+            Ok(((), metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteUserAttributesError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DeleteUserPoolError::from_response(response))
         }
     }
 
-    /// <p>Deletes the specified Amazon Cognito user pool.</p>
-    async fn delete_user_pool(
+    /// Presigns the request `delete_user_pool` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_user_pool(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteUserPoolRequest,
-    ) -> Result<(), RusotoError<DeleteUserPoolError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_user_pool_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_user_pool_request(&self, input: DeleteUserPoolRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "cognito-idp", &self.region, "/");
+        request.set_operation("DeleteUserPool");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -14074,27 +16846,50 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `delete_user_pool_client`, also returning the metadata of the response.
+    pub async fn delete_user_pool_client_with_metadata(
+        &self,
+        input: DeleteUserPoolClientRequest,
+    ) -> Result<((), ResponseMetadata), RusotoError<DeleteUserPoolClientError>> {
+        let request = self.build_delete_user_pool_client_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
             std::mem::drop(response);
-            Ok(())
+            Ok(((), metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteUserPoolError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DeleteUserPoolClientError::from_response(response))
         }
     }
 
-    /// <p>Allows the developer to delete the user pool client.</p>
-    async fn delete_user_pool_client(
+    /// Presigns the request `delete_user_pool_client` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_user_pool_client(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteUserPoolClientRequest,
-    ) -> Result<(), RusotoError<DeleteUserPoolClientError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_user_pool_client_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_user_pool_client_request(
+        &self,
+        input: DeleteUserPoolClientRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "cognito-idp", &self.region, "/");
+        request.set_operation("DeleteUserPoolClient");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -14104,27 +16899,58 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `delete_user_pool_domain`, also returning the metadata of the response.
+    pub async fn delete_user_pool_domain_with_metadata(
+        &self,
+        input: DeleteUserPoolDomainRequest,
+    ) -> Result<
+        (DeleteUserPoolDomainResponse, ResponseMetadata),
+        RusotoError<DeleteUserPoolDomainError>,
+    > {
+        let request = self.build_delete_user_pool_domain_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            std::mem::drop(response);
-            Ok(())
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DeleteUserPoolDomainResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteUserPoolClientError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DeleteUserPoolDomainError::from_response(response))
         }
     }
 
-    /// <p>Deletes a domain for a user pool.</p>
-    async fn delete_user_pool_domain(
+    /// Presigns the request `delete_user_pool_domain` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_user_pool_domain(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteUserPoolDomainRequest,
-    ) -> Result<DeleteUserPoolDomainResponse, RusotoError<DeleteUserPoolDomainError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_user_pool_domain_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_user_pool_domain_request(
+        &self,
+        input: DeleteUserPoolDomainRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "cognito-idp", &self.region, "/");
+        request.set_operation("DeleteUserPoolDomain");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -14134,28 +16960,58 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `describe_identity_provider`, also returning the metadata of the response.
+    pub async fn describe_identity_provider_with_metadata(
+        &self,
+        input: DescribeIdentityProviderRequest,
+    ) -> Result<
+        (DescribeIdentityProviderResponse, ResponseMetadata),
+        RusotoError<DescribeIdentityProviderError>,
+    > {
+        let request = self.build_describe_identity_provider_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DeleteUserPoolDomainResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DescribeIdentityProviderResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteUserPoolDomainError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DescribeIdentityProviderError::from_response(response))
         }
     }
 
-    /// <p>Gets information about a specific identity provider.</p>
-    async fn describe_identity_provider(
+    /// Presigns the request `describe_identity_provider` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_identity_provider(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeIdentityProviderRequest,
-    ) -> Result<DescribeIdentityProviderResponse, RusotoError<DescribeIdentityProviderError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_identity_provider_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_identity_provider_request(
+        &self,
+        input: DescribeIdentityProviderRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "cognito-idp", &self.region, "/");
+        request.set_operation("DescribeIdentityProvider");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -14165,28 +17021,58 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
-        let mut response = self
-            .client
-            .sign_and_dispatch(request)
-            .await
-            .map_err(RusotoError::from)?;
+        request
+    }
+
+    /// Calls `describe_resource_server`, also returning the metadata of the response.
+    pub async fn describe_resource_server_with_metadata(
+        &self,
+        input: DescribeResourceServerRequest,
+    ) -> Result<
+        (DescribeResourceServerResponse, ResponseMetadata),
+        RusotoError<DescribeResourceServerError>,
+    > {
+        let request = self.build_describe_resource_server_request(input);
+
+        let mut response = self
+            .client
+            .sign_and_dispatch(request)
+            .await
+            .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DescribeIdentityProviderResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DescribeResourceServerResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeIdentityProviderError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DescribeResourceServerError::from_response(response))
         }
     }
 
-    /// <p>Describes a resource server.</p>
-    async fn describe_resource_server(
+    /// Presigns the request `describe_resource_server` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_resource_server(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeResourceServerRequest,
-    ) -> Result<DescribeResourceServerResponse, RusotoError<DescribeResourceServerError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_resource_server_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_resource_server_request(
+        &self,
+        input: DescribeResourceServerRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "cognito-idp", &self.region, "/");
+        request.set_operation("DescribeResourceServer");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -14196,29 +17082,58 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `describe_risk_configuration`, also returning the metadata of the response.
+    pub async fn describe_risk_configuration_with_metadata(
+        &self,
+        input: DescribeRiskConfigurationRequest,
+    ) -> Result<
+        (DescribeRiskConfigurationResponse, ResponseMetadata),
+        RusotoError<DescribeRiskConfigurationError>,
+    > {
+        let request = self.build_describe_risk_configuration_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DescribeResourceServerResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DescribeRiskConfigurationResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeResourceServerError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DescribeRiskConfigurationError::from_response(response))
         }
     }
 
-    /// <p>Describes the risk configuration.</p>
-    async fn describe_risk_configuration(
+    /// Presigns the request `describe_risk_configuration` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_risk_configuration(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeRiskConfigurationRequest,
-    ) -> Result<DescribeRiskConfigurationResponse, RusotoError<DescribeRiskConfigurationError>>
-    {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_risk_configuration_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_risk_configuration_request(
+        &self,
+        input: DescribeRiskConfigurationRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "cognito-idp", &self.region, "/");
+        request.set_operation("DescribeRiskConfiguration");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -14228,28 +17143,58 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `describe_user_import_job`, also returning the metadata of the response.
+    pub async fn describe_user_import_job_with_metadata(
+        &self,
+        input: DescribeUserImportJobRequest,
+    ) -> Result<
+        (DescribeUserImportJobResponse, ResponseMetadata),
+        RusotoError<DescribeUserImportJobError>,
+    > {
+        let request = self.build_describe_user_import_job_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DescribeRiskConfigurationResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DescribeUserImportJobResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeRiskConfigurationError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DescribeUserImportJobError::from_response(response))
         }
     }
 
-    /// <p>Describes the user import job.</p>
-    async fn describe_user_import_job(
+    /// Presigns the request `describe_user_import_job` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_user_import_job(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeUserImportJobRequest,
-    ) -> Result<DescribeUserImportJobResponse, RusotoError<DescribeUserImportJobError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_user_import_job_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_user_import_job_request(
+        &self,
+        input: DescribeUserImportJobRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "cognito-idp", &self.region, "/");
+        request.set_operation("DescribeUserImportJob");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -14259,28 +17204,53 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `describe_user_pool`, also returning the metadata of the response.
+    pub async fn describe_user_pool_with_metadata(
+        &self,
+        input: DescribeUserPoolRequest,
+    ) -> Result<(DescribeUserPoolResponse, ResponseMetadata), RusotoError<DescribeUserPoolError>>
+    {
+        let request = self.build_describe_user_pool_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DescribeUserImportJobResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DescribeUserPoolResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeUserImportJobError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DescribeUserPoolError::from_response(response))
         }
     }
 
-    /// <p>Returns the configuration information and metadata of the specified user pool.</p>
-    async fn describe_user_pool(
+    /// Presigns the request `describe_user_pool` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_user_pool(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeUserPoolRequest,
-    ) -> Result<DescribeUserPoolResponse, RusotoError<DescribeUserPoolError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_user_pool_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_user_pool_request(&self, input: DescribeUserPoolRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "cognito-idp", &self.region, "/");
+        request.set_operation("DescribeUserPool");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -14290,28 +17260,58 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `describe_user_pool_client`, also returning the metadata of the response.
+    pub async fn describe_user_pool_client_with_metadata(
+        &self,
+        input: DescribeUserPoolClientRequest,
+    ) -> Result<
+        (DescribeUserPoolClientResponse, ResponseMetadata),
+        RusotoError<DescribeUserPoolClientError>,
+    > {
+        let request = self.build_describe_user_pool_client_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DescribeUserPoolResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DescribeUserPoolClientResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeUserPoolError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DescribeUserPoolClientError::from_response(response))
         }
     }
 
-    /// <p>Client method for returning the configuration information and metadata of the specified user pool app client.</p>
-    async fn describe_user_pool_client(
+    /// Presigns the request `describe_user_pool_client` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_user_pool_client(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeUserPoolClientRequest,
-    ) -> Result<DescribeUserPoolClientResponse, RusotoError<DescribeUserPoolClientError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_user_pool_client_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_user_pool_client_request(
+        &self,
+        input: DescribeUserPoolClientRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "cognito-idp", &self.region, "/");
+        request.set_operation("DescribeUserPoolClient");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -14321,28 +17321,58 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `describe_user_pool_domain`, also returning the metadata of the response.
+    pub async fn describe_user_pool_domain_with_metadata(
+        &self,
+        input: DescribeUserPoolDomainRequest,
+    ) -> Result<
+        (DescribeUserPoolDomainResponse, ResponseMetadata),
+        RusotoError<DescribeUserPoolDomainError>,
+    > {
+        let request = self.build_describe_user_pool_domain_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DescribeUserPoolClientResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DescribeUserPoolDomainResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeUserPoolClientError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DescribeUserPoolDomainError::from_response(response))
         }
     }
 
-    /// <p>Gets information about a domain.</p>
-    async fn describe_user_pool_domain(
+    /// Presigns the request `describe_user_pool_domain` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_user_pool_domain(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeUserPoolDomainRequest,
-    ) -> Result<DescribeUserPoolDomainResponse, RusotoError<DescribeUserPoolDomainError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_user_pool_domain_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_user_pool_domain_request(
+        &self,
+        input: DescribeUserPoolDomainRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "cognito-idp", &self.region, "/");
+        request.set_operation("DescribeUserPoolDomain");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -14352,28 +17382,47 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `forget_device`, also returning the metadata of the response.
+    pub async fn forget_device_with_metadata(
+        &self,
+        input: ForgetDeviceRequest,
+    ) -> Result<((), ResponseMetadata), RusotoError<ForgetDeviceError>> {
+        let request = self.build_forget_device_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DescribeUserPoolDomainResponse, _>()
+            std::mem::drop(response);
+            Ok(((), metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeUserPoolDomainError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ForgetDeviceError::from_response(response))
         }
     }
 
-    /// <p>Forgets the specified device.</p>
-    async fn forget_device(
+    /// Presigns the request `forget_device` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_forget_device(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ForgetDeviceRequest,
-    ) -> Result<(), RusotoError<ForgetDeviceError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_forget_device_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_forget_device_request(&self, input: ForgetDeviceRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "cognito-idp", &self.region, "/");
+        request.set_operation("ForgetDevice");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -14383,27 +17432,52 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `forgot_password`, also returning the metadata of the response.
+    pub async fn forgot_password_with_metadata(
+        &self,
+        input: ForgotPasswordRequest,
+    ) -> Result<(ForgotPasswordResponse, ResponseMetadata), RusotoError<ForgotPasswordError>> {
+        let request = self.build_forgot_password_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            std::mem::drop(response);
-            Ok(())
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ForgotPasswordResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ForgetDeviceError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ForgotPasswordError::from_response(response))
         }
     }
 
-    /// <p>Calling this API causes a message to be sent to the end user with a confirmation code that is required to change the user's password. For the <code>Username</code> parameter, you can use the username or user alias. If a verified phone number exists for the user, the confirmation code is sent to the phone number. Otherwise, if a verified email exists, the confirmation code is sent to the email. If neither a verified phone number nor a verified email exists, <code>InvalidParameterException</code> is thrown. To use the confirmation code for resetting the password, call .</p>
-    async fn forgot_password(
+    /// Presigns the request `forgot_password` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_forgot_password(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ForgotPasswordRequest,
-    ) -> Result<ForgotPasswordResponse, RusotoError<ForgotPasswordError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_forgot_password_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_forgot_password_request(&self, input: ForgotPasswordRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "cognito-idp", &self.region, "/");
+        request.set_operation("ForgotPassword");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -14413,27 +17487,52 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `get_csv_header`, also returning the metadata of the response.
+    pub async fn get_csv_header_with_metadata(
+        &self,
+        input: GetCSVHeaderRequest,
+    ) -> Result<(GetCSVHeaderResponse, ResponseMetadata), RusotoError<GetCSVHeaderError>> {
+        let request = self.build_get_csv_header_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<ForgotPasswordResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<GetCSVHeaderResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ForgotPasswordError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(GetCSVHeaderError::from_response(response))
         }
     }
 
-    /// <p>Gets the header information for the .csv file to be used as input for the user import job.</p>
-    async fn get_csv_header(
+    /// Presigns the request `get_csv_header` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_csv_header(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetCSVHeaderRequest,
-    ) -> Result<GetCSVHeaderResponse, RusotoError<GetCSVHeaderError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_csv_header_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_csv_header_request(&self, input: GetCSVHeaderRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "cognito-idp", &self.region, "/");
+        request.set_operation("GetCSVHeader");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -14443,27 +17542,52 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `get_device`, also returning the metadata of the response.
+    pub async fn get_device_with_metadata(
+        &self,
+        input: GetDeviceRequest,
+    ) -> Result<(GetDeviceResponse, ResponseMetadata), RusotoError<GetDeviceError>> {
+        let request = self.build_get_device_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<GetCSVHeaderResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<GetDeviceResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(GetCSVHeaderError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(GetDeviceError::from_response(response))
         }
     }
 
-    /// <p>Gets the device.</p>
-    async fn get_device(
+    /// Presigns the request `get_device` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_device(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetDeviceRequest,
-    ) -> Result<GetDeviceResponse, RusotoError<GetDeviceError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_device_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_device_request(&self, input: GetDeviceRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "cognito-idp", &self.region, "/");
+        request.set_operation("GetDevice");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -14473,57 +17597,112 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `get_group`, also returning the metadata of the response.
+    pub async fn get_group_with_metadata(
+        &self,
+        input: GetGroupRequest,
+    ) -> Result<(GetGroupResponse, ResponseMetadata), RusotoError<GetGroupError>> {
+        let request = self.build_get_group_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<GetDeviceResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<GetGroupResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(GetDeviceError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(GetGroupError::from_response(response))
         }
     }
 
-    /// <p>Gets a group.</p> <p>Calling this action requires developer credentials.</p>
-    async fn get_group(
+    /// Presigns the request `get_group` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_group(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetGroupRequest,
-    ) -> Result<GetGroupResponse, RusotoError<GetGroupError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_group_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_group_request(&self, input: GetGroupRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "cognito-idp", &self.region, "/");
+        request.set_operation("GetGroup");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSCognitoIdentityProviderService.GetGroup");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `get_identity_provider_by_identifier`, also returning the metadata of the response.
+    pub async fn get_identity_provider_by_identifier_with_metadata(
+        &self,
+        input: GetIdentityProviderByIdentifierRequest,
+    ) -> Result<
+        (GetIdentityProviderByIdentifierResponse, ResponseMetadata),
+        RusotoError<GetIdentityProviderByIdentifierError>,
+    > {
+        let request = self.build_get_identity_provider_by_identifier_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<GetGroupResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<GetIdentityProviderByIdentifierResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(GetGroupError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(GetIdentityProviderByIdentifierError::from_response(
+                response,
+            ))
         }
     }
 
-    /// <p>Gets the specified identity provider.</p>
-    async fn get_identity_provider_by_identifier(
+    /// Presigns the request `get_identity_provider_by_identifier` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_identity_provider_by_identifier(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetIdentityProviderByIdentifierRequest,
-    ) -> Result<
-        GetIdentityProviderByIdentifierResponse,
-        RusotoError<GetIdentityProviderByIdentifierError>,
-    > {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_identity_provider_by_identifier_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_identity_provider_by_identifier_request(
+        &self,
+        input: GetIdentityProviderByIdentifierRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "cognito-idp", &self.region, "/");
+        request.set_operation("GetIdentityProviderByIdentifier");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -14533,30 +17712,58 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `get_signing_certificate`, also returning the metadata of the response.
+    pub async fn get_signing_certificate_with_metadata(
+        &self,
+        input: GetSigningCertificateRequest,
+    ) -> Result<
+        (GetSigningCertificateResponse, ResponseMetadata),
+        RusotoError<GetSigningCertificateError>,
+    > {
+        let request = self.build_get_signing_certificate_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<GetIdentityProviderByIdentifierResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<GetSigningCertificateResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(GetIdentityProviderByIdentifierError::from_response(
-                response,
-            ))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(GetSigningCertificateError::from_response(response))
         }
     }
 
-    /// <p>This method takes a user pool ID, and returns the signing certificate.</p>
-    async fn get_signing_certificate(
+    /// Presigns the request `get_signing_certificate` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_signing_certificate(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetSigningCertificateRequest,
-    ) -> Result<GetSigningCertificateResponse, RusotoError<GetSigningCertificateError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_signing_certificate_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_signing_certificate_request(
+        &self,
+        input: GetSigningCertificateRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "cognito-idp", &self.region, "/");
+        request.set_operation("GetSigningCertificate");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -14566,28 +17773,56 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `get_ui_customization`, also returning the metadata of the response.
+    pub async fn get_ui_customization_with_metadata(
+        &self,
+        input: GetUICustomizationRequest,
+    ) -> Result<(GetUICustomizationResponse, ResponseMetadata), RusotoError<GetUICustomizationError>>
+    {
+        let request = self.build_get_ui_customization_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<GetSigningCertificateResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<GetUICustomizationResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(GetSigningCertificateError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(GetUICustomizationError::from_response(response))
         }
     }
 
-    /// <p>Gets the UI Customization information for a particular app client's app UI, if there is something set. If nothing is set for the particular client, but there is an existing pool level customization (app <code>clientId</code> will be <code>ALL</code>), then that is returned. If nothing is present, then an empty shape is returned.</p>
-    async fn get_ui_customization(
+    /// Presigns the request `get_ui_customization` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_ui_customization(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetUICustomizationRequest,
-    ) -> Result<GetUICustomizationResponse, RusotoError<GetUICustomizationError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_ui_customization_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_ui_customization_request(
+        &self,
+        input: GetUICustomizationRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "cognito-idp", &self.region, "/");
+        request.set_operation("GetUICustomization");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -14597,122 +17832,228 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `get_user`, also returning the metadata of the response.
+    pub async fn get_user_with_metadata(
+        &self,
+        input: GetUserRequest,
+    ) -> Result<(GetUserResponse, ResponseMetadata), RusotoError<GetUserError>> {
+        let request = self.build_get_user_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<GetUICustomizationResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result =
+                proto::json::ResponsePayload::new(&response).deserialize::<GetUserResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(GetUICustomizationError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(GetUserError::from_response(response))
         }
     }
 
-    /// <p>Gets the user attributes and metadata for a user.</p>
-    async fn get_user(
+    /// Presigns the request `get_user` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_user(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetUserRequest,
-    ) -> Result<GetUserResponse, RusotoError<GetUserError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_user_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_user_request(&self, input: GetUserRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "cognito-idp", &self.region, "/");
+        request.set_operation("GetUser");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSCognitoIdentityProviderService.GetUser");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
-        let mut response = self
-            .client
-            .sign_and_dispatch(request)
-            .await
-            .map_err(RusotoError::from)?;
-        if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<GetUserResponse, _>()
-        } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(GetUserError::from_response(response))
-        }
+        request
     }
 
-    /// <p>Gets the user attribute verification code for the specified attribute name.</p>
-    async fn get_user_attribute_verification_code(
+    /// Calls `get_user_attribute_verification_code`, also returning the metadata of the response.
+    pub async fn get_user_attribute_verification_code_with_metadata(
         &self,
         input: GetUserAttributeVerificationCodeRequest,
     ) -> Result<
-        GetUserAttributeVerificationCodeResponse,
+        (GetUserAttributeVerificationCodeResponse, ResponseMetadata),
         RusotoError<GetUserAttributeVerificationCodeError>,
     > {
-        let mut request = SignedRequest::new("POST", "cognito-idp", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "AWSCognitoIdentityProviderService.GetUserAttributeVerificationCode",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
+        let request = self.build_get_user_attribute_verification_code_request(input);
 
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<GetUserAttributeVerificationCodeResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<GetUserAttributeVerificationCodeResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
             Err(GetUserAttributeVerificationCodeError::from_response(
                 response,
             ))
         }
     }
 
-    /// <p>Gets the user pool multi-factor authentication (MFA) configuration.</p>
-    async fn get_user_pool_mfa_config(
+    /// Presigns the request `get_user_attribute_verification_code` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_user_attribute_verification_code(
         &self,
-        input: GetUserPoolMfaConfigRequest,
-    ) -> Result<GetUserPoolMfaConfigResponse, RusotoError<GetUserPoolMfaConfigError>> {
-        let mut request = SignedRequest::new("POST", "cognito-idp", &self.region, "/");
+        presigner: &rusoto_core::Presigner,
+        input: GetUserAttributeVerificationCodeRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_user_attribute_verification_code_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_user_attribute_verification_code_request(
+        &self,
+        input: GetUserAttributeVerificationCodeRequest,
+    ) -> SignedRequest {
+        let mut request = SignedRequest::new("POST", "cognito-idp", &self.region, "/");
+        request.set_operation("GetUserAttributeVerificationCode");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
             "x-amz-target",
-            "AWSCognitoIdentityProviderService.GetUserPoolMfaConfig",
+            "AWSCognitoIdentityProviderService.GetUserAttributeVerificationCode",
         );
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `get_user_pool_mfa_config`, also returning the metadata of the response.
+    pub async fn get_user_pool_mfa_config_with_metadata(
+        &self,
+        input: GetUserPoolMfaConfigRequest,
+    ) -> Result<
+        (GetUserPoolMfaConfigResponse, ResponseMetadata),
+        RusotoError<GetUserPoolMfaConfigError>,
+    > {
+        let request = self.build_get_user_pool_mfa_config_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<GetUserPoolMfaConfigResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<GetUserPoolMfaConfigResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
             Err(GetUserPoolMfaConfigError::from_response(response))
         }
     }
 
-    /// <p>Signs out users from all devices. It also invalidates all refresh tokens issued to a user. The user's current access and Id tokens remain valid until their expiry. Access and Id tokens expire one hour after they are issued.</p>
-    async fn global_sign_out(
+    /// Presigns the request `get_user_pool_mfa_config` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_user_pool_mfa_config(
+        &self,
+        presigner: &rusoto_core::Presigner,
+        input: GetUserPoolMfaConfigRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_user_pool_mfa_config_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_user_pool_mfa_config_request(
+        &self,
+        input: GetUserPoolMfaConfigRequest,
+    ) -> SignedRequest {
+        let mut request = SignedRequest::new("POST", "cognito-idp", &self.region, "/");
+        request.set_operation("GetUserPoolMfaConfig");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "AWSCognitoIdentityProviderService.GetUserPoolMfaConfig",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        request
+    }
+
+    /// Calls `global_sign_out`, also returning the metadata of the response.
+    pub async fn global_sign_out_with_metadata(
         &self,
         input: GlobalSignOutRequest,
-    ) -> Result<GlobalSignOutResponse, RusotoError<GlobalSignOutError>> {
+    ) -> Result<(GlobalSignOutResponse, ResponseMetadata), RusotoError<GlobalSignOutError>> {
+        let request = self.build_global_sign_out_request(input);
+
+        let mut response = self
+            .client
+            .sign_and_dispatch(request)
+            .await
+            .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
+        if response.status.is_success() {
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<GlobalSignOutResponse, _>()?;
+            Ok((result, metadata))
+        } else {
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(GlobalSignOutError::from_response(response))
+        }
+    }
+
+    /// Presigns the request `global_sign_out` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_global_sign_out(
+        &self,
+        presigner: &rusoto_core::Presigner,
+        input: GlobalSignOutRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_global_sign_out_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_global_sign_out_request(&self, input: GlobalSignOutRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "cognito-idp", &self.region, "/");
+        request.set_operation("GlobalSignOut");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -14722,27 +18063,52 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `initiate_auth`, also returning the metadata of the response.
+    pub async fn initiate_auth_with_metadata(
+        &self,
+        input: InitiateAuthRequest,
+    ) -> Result<(InitiateAuthResponse, ResponseMetadata), RusotoError<InitiateAuthError>> {
+        let request = self.build_initiate_auth_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<GlobalSignOutResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<InitiateAuthResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(GlobalSignOutError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(InitiateAuthError::from_response(response))
         }
     }
 
-    /// <p>Initiates the authentication flow.</p>
-    async fn initiate_auth(
+    /// Presigns the request `initiate_auth` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_initiate_auth(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: InitiateAuthRequest,
-    ) -> Result<InitiateAuthResponse, RusotoError<InitiateAuthError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_initiate_auth_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_initiate_auth_request(&self, input: InitiateAuthRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "cognito-idp", &self.region, "/");
+        request.set_operation("InitiateAuth");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -14752,27 +18118,52 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `list_devices`, also returning the metadata of the response.
+    pub async fn list_devices_with_metadata(
+        &self,
+        input: ListDevicesRequest,
+    ) -> Result<(ListDevicesResponse, ResponseMetadata), RusotoError<ListDevicesError>> {
+        let request = self.build_list_devices_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<InitiateAuthResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ListDevicesResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(InitiateAuthError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ListDevicesError::from_response(response))
         }
     }
 
-    /// <p>Lists the devices.</p>
-    async fn list_devices(
+    /// Presigns the request `list_devices` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_devices(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListDevicesRequest,
-    ) -> Result<ListDevicesResponse, RusotoError<ListDevicesError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_devices_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_devices_request(&self, input: ListDevicesRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "cognito-idp", &self.region, "/");
+        request.set_operation("ListDevices");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -14782,27 +18173,52 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `list_groups`, also returning the metadata of the response.
+    pub async fn list_groups_with_metadata(
+        &self,
+        input: ListGroupsRequest,
+    ) -> Result<(ListGroupsResponse, ResponseMetadata), RusotoError<ListGroupsError>> {
+        let request = self.build_list_groups_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<ListDevicesResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ListGroupsResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ListDevicesError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ListGroupsError::from_response(response))
         }
     }
 
-    /// <p>Lists the groups associated with a user pool.</p> <p>Calling this action requires developer credentials.</p>
-    async fn list_groups(
+    /// Presigns the request `list_groups` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_groups(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListGroupsRequest,
-    ) -> Result<ListGroupsResponse, RusotoError<ListGroupsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_groups_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_groups_request(&self, input: ListGroupsRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "cognito-idp", &self.region, "/");
+        request.set_operation("ListGroups");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -14812,27 +18228,58 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `list_identity_providers`, also returning the metadata of the response.
+    pub async fn list_identity_providers_with_metadata(
+        &self,
+        input: ListIdentityProvidersRequest,
+    ) -> Result<
+        (ListIdentityProvidersResponse, ResponseMetadata),
+        RusotoError<ListIdentityProvidersError>,
+    > {
+        let request = self.build_list_identity_providers_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<ListGroupsResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ListIdentityProvidersResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ListGroupsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ListIdentityProvidersError::from_response(response))
         }
     }
 
-    /// <p>Lists information about all identity providers for a user pool.</p>
-    async fn list_identity_providers(
+    /// Presigns the request `list_identity_providers` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_identity_providers(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListIdentityProvidersRequest,
-    ) -> Result<ListIdentityProvidersResponse, RusotoError<ListIdentityProvidersError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_identity_providers_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_identity_providers_request(
+        &self,
+        input: ListIdentityProvidersRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "cognito-idp", &self.region, "/");
+        request.set_operation("ListIdentityProviders");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -14842,28 +18289,58 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `list_resource_servers`, also returning the metadata of the response.
+    pub async fn list_resource_servers_with_metadata(
+        &self,
+        input: ListResourceServersRequest,
+    ) -> Result<
+        (ListResourceServersResponse, ResponseMetadata),
+        RusotoError<ListResourceServersError>,
+    > {
+        let request = self.build_list_resource_servers_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListIdentityProvidersResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ListResourceServersResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ListIdentityProvidersError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ListResourceServersError::from_response(response))
         }
     }
 
-    /// <p>Lists the resource servers for a user pool.</p>
-    async fn list_resource_servers(
+    /// Presigns the request `list_resource_servers` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_resource_servers(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListResourceServersRequest,
-    ) -> Result<ListResourceServersResponse, RusotoError<ListResourceServersError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_resource_servers_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_resource_servers_request(
+        &self,
+        input: ListResourceServersRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "cognito-idp", &self.region, "/");
+        request.set_operation("ListResourceServers");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -14873,28 +18350,58 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `list_tags_for_resource`, also returning the metadata of the response.
+    pub async fn list_tags_for_resource_with_metadata(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> Result<
+        (ListTagsForResourceResponse, ResponseMetadata),
+        RusotoError<ListTagsForResourceError>,
+    > {
+        let request = self.build_list_tags_for_resource_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListResourceServersResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ListTagsForResourceResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ListResourceServersError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ListTagsForResourceError::from_response(response))
         }
     }
 
-    /// <p>Lists the tags that are assigned to an Amazon Cognito user pool.</p> <p>A tag is a label that you can apply to user pools to categorize and manage them in different ways, such as by purpose, owner, environment, or other criteria.</p> <p>You can use this action up to 10 times per second, per account.</p>
-    async fn list_tags_for_resource(
+    /// Presigns the request `list_tags_for_resource` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_tags_for_resource(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListTagsForResourceRequest,
-    ) -> Result<ListTagsForResourceResponse, RusotoError<ListTagsForResourceError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_tags_for_resource_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_tags_for_resource_request(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "cognito-idp", &self.region, "/");
+        request.set_operation("ListTagsForResource");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -14904,28 +18411,56 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `list_user_import_jobs`, also returning the metadata of the response.
+    pub async fn list_user_import_jobs_with_metadata(
+        &self,
+        input: ListUserImportJobsRequest,
+    ) -> Result<(ListUserImportJobsResponse, ResponseMetadata), RusotoError<ListUserImportJobsError>>
+    {
+        let request = self.build_list_user_import_jobs_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListTagsForResourceResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ListUserImportJobsResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ListTagsForResourceError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ListUserImportJobsError::from_response(response))
         }
     }
 
-    /// <p>Lists the user import jobs.</p>
-    async fn list_user_import_jobs(
+    /// Presigns the request `list_user_import_jobs` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_user_import_jobs(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListUserImportJobsRequest,
-    ) -> Result<ListUserImportJobsResponse, RusotoError<ListUserImportJobsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_user_import_jobs_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_user_import_jobs_request(
+        &self,
+        input: ListUserImportJobsRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "cognito-idp", &self.region, "/");
+        request.set_operation("ListUserImportJobs");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -14935,28 +18470,58 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `list_user_pool_clients`, also returning the metadata of the response.
+    pub async fn list_user_pool_clients_with_metadata(
+        &self,
+        input: ListUserPoolClientsRequest,
+    ) -> Result<
+        (ListUserPoolClientsResponse, ResponseMetadata),
+        RusotoError<ListUserPoolClientsError>,
+    > {
+        let request = self.build_list_user_pool_clients_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListUserImportJobsResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ListUserPoolClientsResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ListUserImportJobsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ListUserPoolClientsError::from_response(response))
         }
     }
 
-    /// <p>Lists the clients that have been created for the specified user pool.</p>
-    async fn list_user_pool_clients(
+    /// Presigns the request `list_user_pool_clients` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_user_pool_clients(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListUserPoolClientsRequest,
-    ) -> Result<ListUserPoolClientsResponse, RusotoError<ListUserPoolClientsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_user_pool_clients_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_user_pool_clients_request(
+        &self,
+        input: ListUserPoolClientsRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "cognito-idp", &self.region, "/");
+        request.set_operation("ListUserPoolClients");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -14966,28 +18531,52 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `list_user_pools`, also returning the metadata of the response.
+    pub async fn list_user_pools_with_metadata(
+        &self,
+        input: ListUserPoolsRequest,
+    ) -> Result<(ListUserPoolsResponse, ResponseMetadata), RusotoError<ListUserPoolsError>> {
+        let request = self.build_list_user_pools_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListUserPoolClientsResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ListUserPoolsResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ListUserPoolClientsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ListUserPoolsError::from_response(response))
         }
     }
 
-    /// <p>Lists the user pools associated with an AWS account.</p>
-    async fn list_user_pools(
+    /// Presigns the request `list_user_pools` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_user_pools(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListUserPoolsRequest,
-    ) -> Result<ListUserPoolsResponse, RusotoError<ListUserPoolsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_user_pools_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_user_pools_request(&self, input: ListUserPoolsRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "cognito-idp", &self.region, "/");
+        request.set_operation("ListUserPools");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -14997,27 +18586,52 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `list_users`, also returning the metadata of the response.
+    pub async fn list_users_with_metadata(
+        &self,
+        input: ListUsersRequest,
+    ) -> Result<(ListUsersResponse, ResponseMetadata), RusotoError<ListUsersError>> {
+        let request = self.build_list_users_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<ListUserPoolsResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ListUsersResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ListUserPoolsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ListUsersError::from_response(response))
         }
     }
 
-    /// <p>Lists the users in the Amazon Cognito user pool.</p>
-    async fn list_users(
+    /// Presigns the request `list_users` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_users(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListUsersRequest,
-    ) -> Result<ListUsersResponse, RusotoError<ListUsersError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_users_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_users_request(&self, input: ListUsersRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "cognito-idp", &self.region, "/");
+        request.set_operation("ListUsers");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -15027,27 +18641,53 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `list_users_in_group`, also returning the metadata of the response.
+    pub async fn list_users_in_group_with_metadata(
+        &self,
+        input: ListUsersInGroupRequest,
+    ) -> Result<(ListUsersInGroupResponse, ResponseMetadata), RusotoError<ListUsersInGroupError>>
+    {
+        let request = self.build_list_users_in_group_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<ListUsersResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ListUsersInGroupResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ListUsersError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ListUsersInGroupError::from_response(response))
         }
     }
 
-    /// <p>Lists the users in the specified group.</p> <p>Calling this action requires developer credentials.</p>
-    async fn list_users_in_group(
+    /// Presigns the request `list_users_in_group` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_users_in_group(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListUsersInGroupRequest,
-    ) -> Result<ListUsersInGroupResponse, RusotoError<ListUsersInGroupError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_users_in_group_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_users_in_group_request(&self, input: ListUsersInGroupRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "cognito-idp", &self.region, "/");
+        request.set_operation("ListUsersInGroup");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -15057,28 +18697,58 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `resend_confirmation_code`, also returning the metadata of the response.
+    pub async fn resend_confirmation_code_with_metadata(
+        &self,
+        input: ResendConfirmationCodeRequest,
+    ) -> Result<
+        (ResendConfirmationCodeResponse, ResponseMetadata),
+        RusotoError<ResendConfirmationCodeError>,
+    > {
+        let request = self.build_resend_confirmation_code_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListUsersInGroupResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ResendConfirmationCodeResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ListUsersInGroupError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ResendConfirmationCodeError::from_response(response))
         }
     }
 
-    /// <p>Resends the confirmation (for confirmation of registration) to a specific user in the user pool.</p>
-    async fn resend_confirmation_code(
+    /// Presigns the request `resend_confirmation_code` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_resend_confirmation_code(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ResendConfirmationCodeRequest,
-    ) -> Result<ResendConfirmationCodeResponse, RusotoError<ResendConfirmationCodeError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_resend_confirmation_code_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_resend_confirmation_code_request(
+        &self,
+        input: ResendConfirmationCodeRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "cognito-idp", &self.region, "/");
+        request.set_operation("ResendConfirmationCode");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -15088,28 +18758,58 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `respond_to_auth_challenge`, also returning the metadata of the response.
+    pub async fn respond_to_auth_challenge_with_metadata(
+        &self,
+        input: RespondToAuthChallengeRequest,
+    ) -> Result<
+        (RespondToAuthChallengeResponse, ResponseMetadata),
+        RusotoError<RespondToAuthChallengeError>,
+    > {
+        let request = self.build_respond_to_auth_challenge_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<ResendConfirmationCodeResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<RespondToAuthChallengeResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ResendConfirmationCodeError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(RespondToAuthChallengeError::from_response(response))
         }
     }
 
-    /// <p>Responds to the authentication challenge.</p>
-    async fn respond_to_auth_challenge(
+    /// Presigns the request `respond_to_auth_challenge` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_respond_to_auth_challenge(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: RespondToAuthChallengeRequest,
-    ) -> Result<RespondToAuthChallengeResponse, RusotoError<RespondToAuthChallengeError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_respond_to_auth_challenge_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_respond_to_auth_challenge_request(
+        &self,
+        input: RespondToAuthChallengeRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "cognito-idp", &self.region, "/");
+        request.set_operation("RespondToAuthChallenge");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -15119,28 +18819,58 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `set_risk_configuration`, also returning the metadata of the response.
+    pub async fn set_risk_configuration_with_metadata(
+        &self,
+        input: SetRiskConfigurationRequest,
+    ) -> Result<
+        (SetRiskConfigurationResponse, ResponseMetadata),
+        RusotoError<SetRiskConfigurationError>,
+    > {
+        let request = self.build_set_risk_configuration_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<RespondToAuthChallengeResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<SetRiskConfigurationResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(RespondToAuthChallengeError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(SetRiskConfigurationError::from_response(response))
         }
     }
 
-    /// <p>Configures actions on detected risks. To delete the risk configuration for <code>UserPoolId</code> or <code>ClientId</code>, pass null values for all four configuration types.</p> <p>To enable Amazon Cognito advanced security features, update the user pool to include the <code>UserPoolAddOns</code> key<code>AdvancedSecurityMode</code>.</p> <p>See .</p>
-    async fn set_risk_configuration(
+    /// Presigns the request `set_risk_configuration` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_set_risk_configuration(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: SetRiskConfigurationRequest,
-    ) -> Result<SetRiskConfigurationResponse, RusotoError<SetRiskConfigurationError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_set_risk_configuration_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_set_risk_configuration_request(
+        &self,
+        input: SetRiskConfigurationRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "cognito-idp", &self.region, "/");
+        request.set_operation("SetRiskConfiguration");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -15150,28 +18880,56 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `set_ui_customization`, also returning the metadata of the response.
+    pub async fn set_ui_customization_with_metadata(
+        &self,
+        input: SetUICustomizationRequest,
+    ) -> Result<(SetUICustomizationResponse, ResponseMetadata), RusotoError<SetUICustomizationError>>
+    {
+        let request = self.build_set_ui_customization_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<SetRiskConfigurationResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<SetUICustomizationResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(SetRiskConfigurationError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(SetUICustomizationError::from_response(response))
         }
     }
 
-    /// <p><p>Sets the UI customization information for a user pool&#39;s built-in app UI.</p> <p>You can specify app UI customization settings for a single client (with a specific <code>clientId</code>) or for all clients (by setting the <code>clientId</code> to <code>ALL</code>). If you specify <code>ALL</code>, the default configuration will be used for every client that has no UI customization set previously. If you specify UI customization settings for a particular client, it will no longer fall back to the <code>ALL</code> configuration. </p> <note> <p>To use this API, your user pool must have a domain associated with it. Otherwise, there is no place to host the app&#39;s pages, and the service will throw an error.</p> </note></p>
-    async fn set_ui_customization(
+    /// Presigns the request `set_ui_customization` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_set_ui_customization(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: SetUICustomizationRequest,
-    ) -> Result<SetUICustomizationResponse, RusotoError<SetUICustomizationError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_set_ui_customization_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_set_ui_customization_request(
+        &self,
+        input: SetUICustomizationRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "cognito-idp", &self.region, "/");
+        request.set_operation("SetUICustomization");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -15181,28 +18939,58 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `set_user_mfa_preference`, also returning the metadata of the response.
+    pub async fn set_user_mfa_preference_with_metadata(
+        &self,
+        input: SetUserMFAPreferenceRequest,
+    ) -> Result<
+        (SetUserMFAPreferenceResponse, ResponseMetadata),
+        RusotoError<SetUserMFAPreferenceError>,
+    > {
+        let request = self.build_set_user_mfa_preference_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<SetUICustomizationResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<SetUserMFAPreferenceResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(SetUICustomizationError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(SetUserMFAPreferenceError::from_response(response))
         }
     }
 
-    /// <p>Set the user's multi-factor authentication (MFA) method preference, including which MFA factors are enabled and if any are preferred. Only one factor can be set as preferred. The preferred MFA factor will be used to authenticate a user if multiple factors are enabled. If multiple options are enabled and no preference is set, a challenge to choose an MFA option will be returned during sign in.</p>
-    async fn set_user_mfa_preference(
+    /// Presigns the request `set_user_mfa_preference` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_set_user_mfa_preference(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: SetUserMFAPreferenceRequest,
-    ) -> Result<SetUserMFAPreferenceResponse, RusotoError<SetUserMFAPreferenceError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_set_user_mfa_preference_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_set_user_mfa_preference_request(
+        &self,
+        input: SetUserMFAPreferenceRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "cognito-idp", &self.region, "/");
+        request.set_operation("SetUserMFAPreference");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -15212,28 +19000,58 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `set_user_pool_mfa_config`, also returning the metadata of the response.
+    pub async fn set_user_pool_mfa_config_with_metadata(
+        &self,
+        input: SetUserPoolMfaConfigRequest,
+    ) -> Result<
+        (SetUserPoolMfaConfigResponse, ResponseMetadata),
+        RusotoError<SetUserPoolMfaConfigError>,
+    > {
+        let request = self.build_set_user_pool_mfa_config_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<SetUserMFAPreferenceResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<SetUserPoolMfaConfigResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(SetUserMFAPreferenceError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(SetUserPoolMfaConfigError::from_response(response))
         }
     }
 
-    /// <p>Set the user pool multi-factor authentication (MFA) configuration.</p>
-    async fn set_user_pool_mfa_config(
+    /// Presigns the request `set_user_pool_mfa_config` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_set_user_pool_mfa_config(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: SetUserPoolMfaConfigRequest,
-    ) -> Result<SetUserPoolMfaConfigResponse, RusotoError<SetUserPoolMfaConfigError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_set_user_pool_mfa_config_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_set_user_pool_mfa_config_request(
+        &self,
+        input: SetUserPoolMfaConfigRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "cognito-idp", &self.region, "/");
+        request.set_operation("SetUserPoolMfaConfig");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -15243,28 +19061,53 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `set_user_settings`, also returning the metadata of the response.
+    pub async fn set_user_settings_with_metadata(
+        &self,
+        input: SetUserSettingsRequest,
+    ) -> Result<(SetUserSettingsResponse, ResponseMetadata), RusotoError<SetUserSettingsError>>
+    {
+        let request = self.build_set_user_settings_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<SetUserPoolMfaConfigResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<SetUserSettingsResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(SetUserPoolMfaConfigError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(SetUserSettingsError::from_response(response))
         }
     }
 
-    /// <p> <i>This action is no longer supported.</i> You can use it to configure only SMS MFA. You can't use it to configure TOTP software token MFA. To configure either type of MFA, use the <a>SetUserMFAPreference</a> action instead.</p>
-    async fn set_user_settings(
+    /// Presigns the request `set_user_settings` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_set_user_settings(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: SetUserSettingsRequest,
-    ) -> Result<SetUserSettingsResponse, RusotoError<SetUserSettingsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_set_user_settings_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_set_user_settings_request(&self, input: SetUserSettingsRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "cognito-idp", &self.region, "/");
+        request.set_operation("SetUserSettings");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -15274,54 +19117,108 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `sign_up`, also returning the metadata of the response.
+    pub async fn sign_up_with_metadata(
+        &self,
+        input: SignUpRequest,
+    ) -> Result<(SignUpResponse, ResponseMetadata), RusotoError<SignUpError>> {
+        let request = self.build_sign_up_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<SetUserSettingsResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result =
+                proto::json::ResponsePayload::new(&response).deserialize::<SignUpResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(SetUserSettingsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(SignUpError::from_response(response))
         }
     }
 
-    /// <p>Registers the user in the specified user pool and creates a user name, password, and user attributes.</p>
-    async fn sign_up(
+    /// Presigns the request `sign_up` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_sign_up(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: SignUpRequest,
-    ) -> Result<SignUpResponse, RusotoError<SignUpError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_sign_up_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_sign_up_request(&self, input: SignUpRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "cognito-idp", &self.region, "/");
+        request.set_operation("SignUp");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSCognitoIdentityProviderService.SignUp");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `start_user_import_job`, also returning the metadata of the response.
+    pub async fn start_user_import_job_with_metadata(
+        &self,
+        input: StartUserImportJobRequest,
+    ) -> Result<(StartUserImportJobResponse, ResponseMetadata), RusotoError<StartUserImportJobError>>
+    {
+        let request = self.build_start_user_import_job_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<SignUpResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<StartUserImportJobResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(SignUpError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(StartUserImportJobError::from_response(response))
         }
     }
 
-    /// <p>Starts the user import.</p>
-    async fn start_user_import_job(
+    /// Presigns the request `start_user_import_job` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_start_user_import_job(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: StartUserImportJobRequest,
-    ) -> Result<StartUserImportJobResponse, RusotoError<StartUserImportJobError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_start_user_import_job_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_start_user_import_job_request(
+        &self,
+        input: StartUserImportJobRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "cognito-idp", &self.region, "/");
+        request.set_operation("StartUserImportJob");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -15331,28 +19228,53 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `stop_user_import_job`, also returning the metadata of the response.
+    pub async fn stop_user_import_job_with_metadata(
+        &self,
+        input: StopUserImportJobRequest,
+    ) -> Result<(StopUserImportJobResponse, ResponseMetadata), RusotoError<StopUserImportJobError>>
+    {
+        let request = self.build_stop_user_import_job_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<StartUserImportJobResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<StopUserImportJobResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(StartUserImportJobError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(StopUserImportJobError::from_response(response))
         }
     }
 
-    /// <p>Stops the user import job.</p>
-    async fn stop_user_import_job(
+    /// Presigns the request `stop_user_import_job` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_stop_user_import_job(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: StopUserImportJobRequest,
-    ) -> Result<StopUserImportJobResponse, RusotoError<StopUserImportJobError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_stop_user_import_job_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_stop_user_import_job_request(&self, input: StopUserImportJobRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "cognito-idp", &self.region, "/");
+        request.set_operation("StopUserImportJob");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -15362,28 +19284,52 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `tag_resource`, also returning the metadata of the response.
+    pub async fn tag_resource_with_metadata(
+        &self,
+        input: TagResourceRequest,
+    ) -> Result<(TagResourceResponse, ResponseMetadata), RusotoError<TagResourceError>> {
+        let request = self.build_tag_resource_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<StopUserImportJobResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<TagResourceResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(StopUserImportJobError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(TagResourceError::from_response(response))
         }
     }
 
-    /// <p>Assigns a set of tags to an Amazon Cognito user pool. A tag is a label that you can use to categorize and manage user pools in different ways, such as by purpose, owner, environment, or other criteria.</p> <p>Each tag consists of a key and value, both of which you define. A key is a general category for more specific values. For example, if you have two versions of a user pool, one for testing and another for production, you might assign an <code>Environment</code> tag key to both user pools. The value of this key might be <code>Test</code> for one user pool and <code>Production</code> for the other.</p> <p>Tags are useful for cost tracking and access control. You can activate your tags so that they appear on the Billing and Cost Management console, where you can track the costs associated with your user pools. In an IAM policy, you can constrain permissions for user pools based on specific tags or tag values.</p> <p>You can use this action up to 5 times per second, per account. A user pool can have as many as 50 tags.</p>
-    async fn tag_resource(
+    /// Presigns the request `tag_resource` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_tag_resource(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: TagResourceRequest,
-    ) -> Result<TagResourceResponse, RusotoError<TagResourceError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_tag_resource_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_tag_resource_request(&self, input: TagResourceRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "cognito-idp", &self.region, "/");
+        request.set_operation("TagResource");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -15393,27 +19339,52 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `untag_resource`, also returning the metadata of the response.
+    pub async fn untag_resource_with_metadata(
+        &self,
+        input: UntagResourceRequest,
+    ) -> Result<(UntagResourceResponse, ResponseMetadata), RusotoError<UntagResourceError>> {
+        let request = self.build_untag_resource_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<TagResourceResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<UntagResourceResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(TagResourceError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(UntagResourceError::from_response(response))
         }
     }
 
-    /// <p>Removes the specified tags from an Amazon Cognito user pool. You can use this action up to 5 times per second, per account</p>
-    async fn untag_resource(
+    /// Presigns the request `untag_resource` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_untag_resource(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UntagResourceRequest,
-    ) -> Result<UntagResourceResponse, RusotoError<UntagResourceError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_untag_resource_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_untag_resource_request(&self, input: UntagResourceRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "cognito-idp", &self.region, "/");
+        request.set_operation("UntagResource");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -15423,27 +19394,58 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `update_auth_event_feedback`, also returning the metadata of the response.
+    pub async fn update_auth_event_feedback_with_metadata(
+        &self,
+        input: UpdateAuthEventFeedbackRequest,
+    ) -> Result<
+        (UpdateAuthEventFeedbackResponse, ResponseMetadata),
+        RusotoError<UpdateAuthEventFeedbackError>,
+    > {
+        let request = self.build_update_auth_event_feedback_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<UntagResourceResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<UpdateAuthEventFeedbackResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(UntagResourceError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(UpdateAuthEventFeedbackError::from_response(response))
         }
     }
 
-    /// <p>Provides the feedback for an authentication event whether it was from a valid user or not. This feedback is used for improving the risk evaluation decision for the user pool as part of Amazon Cognito advanced security.</p>
-    async fn update_auth_event_feedback(
+    /// Presigns the request `update_auth_event_feedback` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_auth_event_feedback(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UpdateAuthEventFeedbackRequest,
-    ) -> Result<UpdateAuthEventFeedbackResponse, RusotoError<UpdateAuthEventFeedbackError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_auth_event_feedback_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_auth_event_feedback_request(
+        &self,
+        input: UpdateAuthEventFeedbackRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "cognito-idp", &self.region, "/");
+        request.set_operation("UpdateAuthEventFeedback");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -15453,28 +19455,56 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `update_device_status`, also returning the metadata of the response.
+    pub async fn update_device_status_with_metadata(
+        &self,
+        input: UpdateDeviceStatusRequest,
+    ) -> Result<(UpdateDeviceStatusResponse, ResponseMetadata), RusotoError<UpdateDeviceStatusError>>
+    {
+        let request = self.build_update_device_status_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<UpdateAuthEventFeedbackResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<UpdateDeviceStatusResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(UpdateAuthEventFeedbackError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(UpdateDeviceStatusError::from_response(response))
         }
     }
 
-    /// <p>Updates the device status.</p>
-    async fn update_device_status(
+    /// Presigns the request `update_device_status` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_device_status(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UpdateDeviceStatusRequest,
-    ) -> Result<UpdateDeviceStatusResponse, RusotoError<UpdateDeviceStatusError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_device_status_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_device_status_request(
+        &self,
+        input: UpdateDeviceStatusRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "cognito-idp", &self.region, "/");
+        request.set_operation("UpdateDeviceStatus");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -15484,28 +19514,52 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `update_group`, also returning the metadata of the response.
+    pub async fn update_group_with_metadata(
+        &self,
+        input: UpdateGroupRequest,
+    ) -> Result<(UpdateGroupResponse, ResponseMetadata), RusotoError<UpdateGroupError>> {
+        let request = self.build_update_group_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<UpdateDeviceStatusResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<UpdateGroupResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(UpdateDeviceStatusError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(UpdateGroupError::from_response(response))
         }
     }
 
-    /// <p><p>Updates the specified group with the specified attributes.</p> <p>Calling this action requires developer credentials.</p> <important> <p>If you don&#39;t provide a value for an attribute, it will be set to the default value.</p> </important></p>
-    async fn update_group(
+    /// Presigns the request `update_group` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_group(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UpdateGroupRequest,
-    ) -> Result<UpdateGroupResponse, RusotoError<UpdateGroupError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_group_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_group_request(&self, input: UpdateGroupRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "cognito-idp", &self.region, "/");
+        request.set_operation("UpdateGroup");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -15515,27 +19569,58 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `update_identity_provider`, also returning the metadata of the response.
+    pub async fn update_identity_provider_with_metadata(
+        &self,
+        input: UpdateIdentityProviderRequest,
+    ) -> Result<
+        (UpdateIdentityProviderResponse, ResponseMetadata),
+        RusotoError<UpdateIdentityProviderError>,
+    > {
+        let request = self.build_update_identity_provider_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<UpdateGroupResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<UpdateIdentityProviderResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(UpdateGroupError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(UpdateIdentityProviderError::from_response(response))
         }
     }
 
-    /// <p>Updates identity provider information for a user pool.</p>
-    async fn update_identity_provider(
+    /// Presigns the request `update_identity_provider` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_identity_provider(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UpdateIdentityProviderRequest,
-    ) -> Result<UpdateIdentityProviderResponse, RusotoError<UpdateIdentityProviderError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_identity_provider_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_identity_provider_request(
+        &self,
+        input: UpdateIdentityProviderRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "cognito-idp", &self.region, "/");
+        request.set_operation("UpdateIdentityProvider");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -15545,28 +19630,58 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `update_resource_server`, also returning the metadata of the response.
+    pub async fn update_resource_server_with_metadata(
+        &self,
+        input: UpdateResourceServerRequest,
+    ) -> Result<
+        (UpdateResourceServerResponse, ResponseMetadata),
+        RusotoError<UpdateResourceServerError>,
+    > {
+        let request = self.build_update_resource_server_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<UpdateIdentityProviderResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<UpdateResourceServerResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(UpdateIdentityProviderError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(UpdateResourceServerError::from_response(response))
         }
     }
 
-    /// <p><p>Updates the name and scopes of resource server. All other fields are read-only.</p> <important> <p>If you don&#39;t provide a value for an attribute, it will be set to the default value.</p> </important></p>
-    async fn update_resource_server(
+    /// Presigns the request `update_resource_server` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_resource_server(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UpdateResourceServerRequest,
-    ) -> Result<UpdateResourceServerResponse, RusotoError<UpdateResourceServerError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_resource_server_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_resource_server_request(
+        &self,
+        input: UpdateResourceServerRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "cognito-idp", &self.region, "/");
+        request.set_operation("UpdateResourceServer");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -15576,28 +19691,58 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `update_user_attributes`, also returning the metadata of the response.
+    pub async fn update_user_attributes_with_metadata(
+        &self,
+        input: UpdateUserAttributesRequest,
+    ) -> Result<
+        (UpdateUserAttributesResponse, ResponseMetadata),
+        RusotoError<UpdateUserAttributesError>,
+    > {
+        let request = self.build_update_user_attributes_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<UpdateResourceServerResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<UpdateUserAttributesResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(UpdateResourceServerError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(UpdateUserAttributesError::from_response(response))
         }
     }
 
-    /// <p>Allows a user to update a specific attribute (one at a time).</p>
-    async fn update_user_attributes(
+    /// Presigns the request `update_user_attributes` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_user_attributes(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UpdateUserAttributesRequest,
-    ) -> Result<UpdateUserAttributesResponse, RusotoError<UpdateUserAttributesError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_user_attributes_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_user_attributes_request(
+        &self,
+        input: UpdateUserAttributesRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "cognito-idp", &self.region, "/");
+        request.set_operation("UpdateUserAttributes");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -15607,28 +19752,52 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `update_user_pool`, also returning the metadata of the response.
+    pub async fn update_user_pool_with_metadata(
+        &self,
+        input: UpdateUserPoolRequest,
+    ) -> Result<(UpdateUserPoolResponse, ResponseMetadata), RusotoError<UpdateUserPoolError>> {
+        let request = self.build_update_user_pool_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<UpdateUserAttributesResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<UpdateUserPoolResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(UpdateUserAttributesError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(UpdateUserPoolError::from_response(response))
         }
     }
 
-    /// <p><p>Updates the specified user pool with the specified attributes. You can get a list of the current user pool settings with .</p> <important> <p>If you don&#39;t provide a value for an attribute, it will be set to the default value.</p> </important></p>
-    async fn update_user_pool(
+    /// Presigns the request `update_user_pool` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_user_pool(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UpdateUserPoolRequest,
-    ) -> Result<UpdateUserPoolResponse, RusotoError<UpdateUserPoolError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_user_pool_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_user_pool_request(&self, input: UpdateUserPoolRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "cognito-idp", &self.region, "/");
+        request.set_operation("UpdateUserPool");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -15638,27 +19807,58 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `update_user_pool_client`, also returning the metadata of the response.
+    pub async fn update_user_pool_client_with_metadata(
+        &self,
+        input: UpdateUserPoolClientRequest,
+    ) -> Result<
+        (UpdateUserPoolClientResponse, ResponseMetadata),
+        RusotoError<UpdateUserPoolClientError>,
+    > {
+        let request = self.build_update_user_pool_client_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<UpdateUserPoolResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<UpdateUserPoolClientResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(UpdateUserPoolError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(UpdateUserPoolClientError::from_response(response))
         }
     }
 
-    /// <p><p>Updates the specified user pool app client with the specified attributes. You can get a list of the current user pool app client settings with .</p> <important> <p>If you don&#39;t provide a value for an attribute, it will be set to the default value.</p> </important></p>
-    async fn update_user_pool_client(
+    /// Presigns the request `update_user_pool_client` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_user_pool_client(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UpdateUserPoolClientRequest,
-    ) -> Result<UpdateUserPoolClientResponse, RusotoError<UpdateUserPoolClientError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_user_pool_client_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_user_pool_client_request(
+        &self,
+        input: UpdateUserPoolClientRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "cognito-idp", &self.region, "/");
+        request.set_operation("UpdateUserPoolClient");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -15668,28 +19868,58 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `update_user_pool_domain`, also returning the metadata of the response.
+    pub async fn update_user_pool_domain_with_metadata(
+        &self,
+        input: UpdateUserPoolDomainRequest,
+    ) -> Result<
+        (UpdateUserPoolDomainResponse, ResponseMetadata),
+        RusotoError<UpdateUserPoolDomainError>,
+    > {
+        let request = self.build_update_user_pool_domain_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<UpdateUserPoolClientResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<UpdateUserPoolDomainResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(UpdateUserPoolClientError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(UpdateUserPoolDomainError::from_response(response))
         }
     }
 
-    /// <p>Updates the Secure Sockets Layer (SSL) certificate for the custom domain for your user pool.</p> <p>You can use this operation to provide the Amazon Resource Name (ARN) of a new certificate to Amazon Cognito. You cannot use it to change the domain for a user pool.</p> <p>A custom domain is used to host the Amazon Cognito hosted UI, which provides sign-up and sign-in pages for your application. When you set up a custom domain, you provide a certificate that you manage with AWS Certificate Manager (ACM). When necessary, you can use this operation to change the certificate that you applied to your custom domain.</p> <p>Usually, this is unnecessary following routine certificate renewal with ACM. When you renew your existing certificate in ACM, the ARN for your certificate remains the same, and your custom domain uses the new certificate automatically.</p> <p>However, if you replace your existing certificate with a new one, ACM gives the new certificate a new ARN. To apply the new certificate to your custom domain, you must provide this ARN to Amazon Cognito.</p> <p>When you add your new certificate in ACM, you must choose US East (N. Virginia) as the AWS Region.</p> <p>After you submit your request, Amazon Cognito requires up to 1 hour to distribute your new certificate to your custom domain.</p> <p>For more information about adding a custom domain to your user pool, see <a href="https://docs.aws.amazon.com/cognito/latest/developerguide/cognito-user-pools-add-custom-domain.html">Using Your Own Domain for the Hosted UI</a>.</p>
-    async fn update_user_pool_domain(
+    /// Presigns the request `update_user_pool_domain` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_user_pool_domain(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UpdateUserPoolDomainRequest,
-    ) -> Result<UpdateUserPoolDomainResponse, RusotoError<UpdateUserPoolDomainError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_user_pool_domain_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_user_pool_domain_request(
+        &self,
+        input: UpdateUserPoolDomainRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "cognito-idp", &self.region, "/");
+        request.set_operation("UpdateUserPoolDomain");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -15699,28 +19929,58 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
-        let mut response = self
-            .client
-            .sign_and_dispatch(request)
+        request
+    }
+
+    /// Calls `verify_software_token`, also returning the metadata of the response.
+    pub async fn verify_software_token_with_metadata(
+        &self,
+        input: VerifySoftwareTokenRequest,
+    ) -> Result<
+        (VerifySoftwareTokenResponse, ResponseMetadata),
+        RusotoError<VerifySoftwareTokenError>,
+    > {
+        let request = self.build_verify_software_token_request(input);
+
+        let mut response = self
+            .client
+            .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<UpdateUserPoolDomainResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<VerifySoftwareTokenResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(UpdateUserPoolDomainError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(VerifySoftwareTokenError::from_response(response))
         }
     }
 
-    /// <p>Use this API to register a user's entered TOTP code and mark the user's software token MFA status as "verified" if successful. The request takes an access token or a session string, but not both.</p>
-    async fn verify_software_token(
+    /// Presigns the request `verify_software_token` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_verify_software_token(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: VerifySoftwareTokenRequest,
-    ) -> Result<VerifySoftwareTokenResponse, RusotoError<VerifySoftwareTokenError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_verify_software_token_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_verify_software_token_request(
+        &self,
+        input: VerifySoftwareTokenRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "cognito-idp", &self.region, "/");
+        request.set_operation("VerifySoftwareToken");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -15730,28 +19990,58 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `verify_user_attribute`, also returning the metadata of the response.
+    pub async fn verify_user_attribute_with_metadata(
+        &self,
+        input: VerifyUserAttributeRequest,
+    ) -> Result<
+        (VerifyUserAttributeResponse, ResponseMetadata),
+        RusotoError<VerifyUserAttributeError>,
+    > {
+        let request = self.build_verify_user_attribute_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<VerifySoftwareTokenResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<VerifyUserAttributeResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(VerifySoftwareTokenError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(VerifyUserAttributeError::from_response(response))
         }
     }
 
-    /// <p>Verifies the specified user attributes in the user pool.</p>
-    async fn verify_user_attribute(
+    /// Presigns the request `verify_user_attribute` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_verify_user_attribute(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: VerifyUserAttributeRequest,
-    ) -> Result<VerifyUserAttributeResponse, RusotoError<VerifyUserAttributeError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_verify_user_attribute_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_verify_user_attribute_request(
+        &self,
+        input: VerifyUserAttributeRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "cognito-idp", &self.region, "/");
+        request.set_operation("VerifyUserAttribute");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -15761,19 +20051,1368 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
-        let mut response = self
-            .client
-            .sign_and_dispatch(request)
+        request
+    }
+
+    /// The pages of `AdminListGroupsForUser` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `Limit` of `input`.
+    pub fn admin_list_groups_for_user_pages(
+        &self,
+        input: AdminListGroupsForUserRequest,
+    ) -> impl futures::Stream<
+        Item = Result<AdminListGroupsForUserResponse, RusotoError<AdminListGroupsForUserError>>,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: CognitoIdentityProviderClient, input| async move {
+                client.admin_list_groups_for_user(input).await
+            },
+            |input: &AdminListGroupsForUserRequest, output: &AdminListGroupsForUserResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(AdminListGroupsForUserRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `Groups` of every page of `AdminListGroupsForUser` results for `input`, as `admin_list_groups_for_user_pages` requests them.
+    pub fn admin_list_groups_for_user_stream(
+        &self,
+        input: AdminListGroupsForUserRequest,
+    ) -> impl futures::Stream<Item = Result<GroupType, RusotoError<AdminListGroupsForUserError>>> + Send
+    {
+        rusoto_core::pagination::items(self.admin_list_groups_for_user_pages(input), |page| {
+            page.groups.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `AdminListUserAuthEvents` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `MaxResults` of `input`.
+    pub fn admin_list_user_auth_events_pages(
+        &self,
+        input: AdminListUserAuthEventsRequest,
+    ) -> impl futures::Stream<
+        Item = Result<AdminListUserAuthEventsResponse, RusotoError<AdminListUserAuthEventsError>>,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: CognitoIdentityProviderClient, input| async move {
+                client.admin_list_user_auth_events(input).await
+            },
+            |input: &AdminListUserAuthEventsRequest, output: &AdminListUserAuthEventsResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(AdminListUserAuthEventsRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `AuthEvents` of every page of `AdminListUserAuthEvents` results for `input`, as `admin_list_user_auth_events_pages` requests them.
+    pub fn admin_list_user_auth_events_stream(
+        &self,
+        input: AdminListUserAuthEventsRequest,
+    ) -> impl futures::Stream<Item = Result<AuthEventType, RusotoError<AdminListUserAuthEventsError>>>
+           + Send {
+        rusoto_core::pagination::items(self.admin_list_user_auth_events_pages(input), |page| {
+            page.auth_events.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `ListGroups` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `Limit` of `input`.
+    pub fn list_groups_pages(
+        &self,
+        input: ListGroupsRequest,
+    ) -> impl futures::Stream<Item = Result<ListGroupsResponse, RusotoError<ListGroupsError>>> + Send
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: CognitoIdentityProviderClient, input| async move {
+                client.list_groups(input).await
+            },
+            |input: &ListGroupsRequest, output: &ListGroupsResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListGroupsRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `Groups` of every page of `ListGroups` results for `input`, as `list_groups_pages` requests them.
+    pub fn list_groups_stream(
+        &self,
+        input: ListGroupsRequest,
+    ) -> impl futures::Stream<Item = Result<GroupType, RusotoError<ListGroupsError>>> + Send {
+        rusoto_core::pagination::items(self.list_groups_pages(input), |page| {
+            page.groups.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `ListIdentityProviders` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `MaxResults` of `input`.
+    pub fn list_identity_providers_pages(
+        &self,
+        input: ListIdentityProvidersRequest,
+    ) -> impl futures::Stream<
+        Item = Result<ListIdentityProvidersResponse, RusotoError<ListIdentityProvidersError>>,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: CognitoIdentityProviderClient, input| async move {
+                client.list_identity_providers(input).await
+            },
+            |input: &ListIdentityProvidersRequest, output: &ListIdentityProvidersResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListIdentityProvidersRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `Providers` of every page of `ListIdentityProviders` results for `input`, as `list_identity_providers_pages` requests them.
+    pub fn list_identity_providers_stream(
+        &self,
+        input: ListIdentityProvidersRequest,
+    ) -> impl futures::Stream<
+        Item = Result<ProviderDescription, RusotoError<ListIdentityProvidersError>>,
+    > + Send {
+        rusoto_core::pagination::items(self.list_identity_providers_pages(input), |page| {
+            page.providers
+        })
+    }
+
+    /// The pages of `ListResourceServers` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `MaxResults` of `input`.
+    pub fn list_resource_servers_pages(
+        &self,
+        input: ListResourceServersRequest,
+    ) -> impl futures::Stream<
+        Item = Result<ListResourceServersResponse, RusotoError<ListResourceServersError>>,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: CognitoIdentityProviderClient, input| async move {
+                client.list_resource_servers(input).await
+            },
+            |input: &ListResourceServersRequest, output: &ListResourceServersResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListResourceServersRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `ResourceServers` of every page of `ListResourceServers` results for `input`, as `list_resource_servers_pages` requests them.
+    pub fn list_resource_servers_stream(
+        &self,
+        input: ListResourceServersRequest,
+    ) -> impl futures::Stream<Item = Result<ResourceServerType, RusotoError<ListResourceServersError>>>
+           + Send {
+        rusoto_core::pagination::items(self.list_resource_servers_pages(input), |page| {
+            page.resource_servers
+        })
+    }
+
+    /// The pages of `ListUserPoolClients` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `MaxResults` of `input`.
+    pub fn list_user_pool_clients_pages(
+        &self,
+        input: ListUserPoolClientsRequest,
+    ) -> impl futures::Stream<
+        Item = Result<ListUserPoolClientsResponse, RusotoError<ListUserPoolClientsError>>,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: CognitoIdentityProviderClient, input| async move {
+                client.list_user_pool_clients(input).await
+            },
+            |input: &ListUserPoolClientsRequest, output: &ListUserPoolClientsResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListUserPoolClientsRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `UserPoolClients` of every page of `ListUserPoolClients` results for `input`, as `list_user_pool_clients_pages` requests them.
+    pub fn list_user_pool_clients_stream(
+        &self,
+        input: ListUserPoolClientsRequest,
+    ) -> impl futures::Stream<
+        Item = Result<UserPoolClientDescription, RusotoError<ListUserPoolClientsError>>,
+    > + Send {
+        rusoto_core::pagination::items(self.list_user_pool_clients_pages(input), |page| {
+            page.user_pool_clients.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `ListUserPools` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `MaxResults` of `input`.
+    pub fn list_user_pools_pages(
+        &self,
+        input: ListUserPoolsRequest,
+    ) -> impl futures::Stream<Item = Result<ListUserPoolsResponse, RusotoError<ListUserPoolsError>>> + Send
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: CognitoIdentityProviderClient, input| async move {
+                client.list_user_pools(input).await
+            },
+            |input: &ListUserPoolsRequest, output: &ListUserPoolsResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListUserPoolsRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `UserPools` of every page of `ListUserPools` results for `input`, as `list_user_pools_pages` requests them.
+    pub fn list_user_pools_stream(
+        &self,
+        input: ListUserPoolsRequest,
+    ) -> impl futures::Stream<Item = Result<UserPoolDescriptionType, RusotoError<ListUserPoolsError>>>
+           + Send {
+        rusoto_core::pagination::items(self.list_user_pools_pages(input), |page| {
+            page.user_pools.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `ListUsers` results for `input`, requesting each next page with the `PaginationToken` of the last one as its `PaginationToken`.
+    /// The page size is the `Limit` of `input`.
+    pub fn list_users_pages(
+        &self,
+        input: ListUsersRequest,
+    ) -> impl futures::Stream<Item = Result<ListUsersResponse, RusotoError<ListUsersError>>> + Send
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: CognitoIdentityProviderClient, input| async move { client.list_users(input).await },
+            |input: &ListUsersRequest, output: &ListUsersResponse| {
+                let pagination_token = output.pagination_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if pagination_token.is_none() || pagination_token == input.pagination_token {
+                    return None;
+                }
+                Some(ListUsersRequest {
+                    pagination_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `Users` of every page of `ListUsers` results for `input`, as `list_users_pages` requests them.
+    pub fn list_users_stream(
+        &self,
+        input: ListUsersRequest,
+    ) -> impl futures::Stream<Item = Result<UserType, RusotoError<ListUsersError>>> + Send {
+        rusoto_core::pagination::items(self.list_users_pages(input), |page| {
+            page.users.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `ListUsersInGroup` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `Limit` of `input`.
+    pub fn list_users_in_group_pages(
+        &self,
+        input: ListUsersInGroupRequest,
+    ) -> impl futures::Stream<
+        Item = Result<ListUsersInGroupResponse, RusotoError<ListUsersInGroupError>>,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: CognitoIdentityProviderClient, input| async move {
+                client.list_users_in_group(input).await
+            },
+            |input: &ListUsersInGroupRequest, output: &ListUsersInGroupResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListUsersInGroupRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `Users` of every page of `ListUsersInGroup` results for `input`, as `list_users_in_group_pages` requests them.
+    pub fn list_users_in_group_stream(
+        &self,
+        input: ListUsersInGroupRequest,
+    ) -> impl futures::Stream<Item = Result<UserType, RusotoError<ListUsersInGroupError>>> + Send
+    {
+        rusoto_core::pagination::items(self.list_users_in_group_pages(input), |page| {
+            page.users.unwrap_or_default()
+        })
+    }
+}
+
+#[async_trait]
+impl CognitoIdentityProvider for CognitoIdentityProviderClient {
+    /// <p>Adds additional user attributes to the user pool schema.</p>
+    async fn add_custom_attributes(
+        &self,
+        input: AddCustomAttributesRequest,
+    ) -> Result<AddCustomAttributesResponse, RusotoError<AddCustomAttributesError>> {
+        self.add_custom_attributes_with_metadata(input)
             .await
-            .map_err(RusotoError::from)?;
-        if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<VerifyUserAttributeResponse, _>()
-        } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(VerifyUserAttributeError::from_response(response))
-        }
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Adds the specified user to the specified group.</p> <p>Calling this action requires developer credentials.</p>
+    async fn admin_add_user_to_group(
+        &self,
+        input: AdminAddUserToGroupRequest,
+    ) -> Result<(), RusotoError<AdminAddUserToGroupError>> {
+        self.admin_add_user_to_group_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Confirms user registration as an admin without using a confirmation code. Works on any user.</p> <p>Calling this action requires developer credentials.</p>
+    async fn admin_confirm_sign_up(
+        &self,
+        input: AdminConfirmSignUpRequest,
+    ) -> Result<AdminConfirmSignUpResponse, RusotoError<AdminConfirmSignUpError>> {
+        self.admin_confirm_sign_up_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Creates a new user in the specified user pool.</p> <p>If <code>MessageAction</code> is not set, the default is to send a welcome message via email or phone (SMS).</p> <note> <p>This message is based on a template that you configured in your call to or . This template includes your custom sign-up instructions and placeholders for user name and temporary password.</p> </note> <p>Alternatively, you can call AdminCreateUser with “SUPPRESS” for the <code>MessageAction</code> parameter, and Amazon Cognito will not send any email. </p> <p>In either case, the user will be in the <code>FORCE_CHANGE_PASSWORD</code> state until they sign in and change their password.</p> <p>AdminCreateUser requires developer credentials.</p>
+    async fn admin_create_user(
+        &self,
+        input: AdminCreateUserRequest,
+    ) -> Result<AdminCreateUserResponse, RusotoError<AdminCreateUserError>> {
+        self.admin_create_user_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes a user as an administrator. Works on any user.</p> <p>Calling this action requires developer credentials.</p>
+    async fn admin_delete_user(
+        &self,
+        input: AdminDeleteUserRequest,
+    ) -> Result<(), RusotoError<AdminDeleteUserError>> {
+        self.admin_delete_user_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes the user attributes in a user pool as an administrator. Works on any user.</p> <p>Calling this action requires developer credentials.</p>
+    async fn admin_delete_user_attributes(
+        &self,
+        input: AdminDeleteUserAttributesRequest,
+    ) -> Result<AdminDeleteUserAttributesResponse, RusotoError<AdminDeleteUserAttributesError>>
+    {
+        self.admin_delete_user_attributes_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Disables the user from signing in with the specified external (SAML or social) identity provider. If the user to disable is a Cognito User Pools native username + password user, they are not permitted to use their password to sign-in. If the user to disable is a linked external IdP user, any link between that user and an existing user is removed. The next time the external user (no longer attached to the previously linked <code>DestinationUser</code>) signs in, they must create a new user account. See .</p> <p>This action is enabled only for admin access and requires developer credentials.</p> <p>The <code>ProviderName</code> must match the value specified when creating an IdP for the pool. </p> <p>To disable a native username + password user, the <code>ProviderName</code> value must be <code>Cognito</code> and the <code>ProviderAttributeName</code> must be <code>Cognito_Subject</code>, with the <code>ProviderAttributeValue</code> being the name that is used in the user pool for the user.</p> <p>The <code>ProviderAttributeName</code> must always be <code>Cognito_Subject</code> for social identity providers. The <code>ProviderAttributeValue</code> must always be the exact subject that was used when the user was originally linked as a source user.</p> <p>For de-linking a SAML identity, there are two scenarios. If the linked identity has not yet been used to sign-in, the <code>ProviderAttributeName</code> and <code>ProviderAttributeValue</code> must be the same values that were used for the <code>SourceUser</code> when the identities were originally linked in the call. (If the linking was done with <code>ProviderAttributeName</code> set to <code>Cognito_Subject</code>, the same applies here). However, if the user has already signed in, the <code>ProviderAttributeName</code> must be <code>Cognito_Subject</code> and <code>ProviderAttributeValue</code> must be the subject of the SAML assertion.</p>
+    async fn admin_disable_provider_for_user(
+        &self,
+        input: AdminDisableProviderForUserRequest,
+    ) -> Result<AdminDisableProviderForUserResponse, RusotoError<AdminDisableProviderForUserError>>
+    {
+        self.admin_disable_provider_for_user_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Disables the specified user.</p> <p>Calling this action requires developer credentials.</p>
+    async fn admin_disable_user(
+        &self,
+        input: AdminDisableUserRequest,
+    ) -> Result<AdminDisableUserResponse, RusotoError<AdminDisableUserError>> {
+        self.admin_disable_user_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Enables the specified user as an administrator. Works on any user.</p> <p>Calling this action requires developer credentials.</p>
+    async fn admin_enable_user(
+        &self,
+        input: AdminEnableUserRequest,
+    ) -> Result<AdminEnableUserResponse, RusotoError<AdminEnableUserError>> {
+        self.admin_enable_user_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Forgets the device, as an administrator.</p> <p>Calling this action requires developer credentials.</p>
+    async fn admin_forget_device(
+        &self,
+        input: AdminForgetDeviceRequest,
+    ) -> Result<(), RusotoError<AdminForgetDeviceError>> {
+        self.admin_forget_device_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Gets the device, as an administrator.</p> <p>Calling this action requires developer credentials.</p>
+    async fn admin_get_device(
+        &self,
+        input: AdminGetDeviceRequest,
+    ) -> Result<AdminGetDeviceResponse, RusotoError<AdminGetDeviceError>> {
+        self.admin_get_device_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Gets the specified user by user name in a user pool as an administrator. Works on any user.</p> <p>Calling this action requires developer credentials.</p>
+    async fn admin_get_user(
+        &self,
+        input: AdminGetUserRequest,
+    ) -> Result<AdminGetUserResponse, RusotoError<AdminGetUserError>> {
+        self.admin_get_user_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Initiates the authentication flow, as an administrator.</p> <p>Calling this action requires developer credentials.</p>
+    async fn admin_initiate_auth(
+        &self,
+        input: AdminInitiateAuthRequest,
+    ) -> Result<AdminInitiateAuthResponse, RusotoError<AdminInitiateAuthError>> {
+        self.admin_initiate_auth_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Links an existing user account in a user pool (<code>DestinationUser</code>) to an identity from an external identity provider (<code>SourceUser</code>) based on a specified attribute name and value from the external identity provider. This allows you to create a link from the existing user account to an external federated user identity that has not yet been used to sign in, so that the federated user identity can be used to sign in as the existing user account. </p> <p> For example, if there is an existing user with a username and password, this API links that user to a federated user identity, so that when the federated user identity is used, the user signs in as the existing user account. </p> <important> <p>Because this API allows a user with an external federated identity to sign in as an existing user in the user pool, it is critical that it only be used with external identity providers and provider attributes that have been trusted by the application owner.</p> </important> <p>See also .</p> <p>This action is enabled only for admin access and requires developer credentials.</p>
+    async fn admin_link_provider_for_user(
+        &self,
+        input: AdminLinkProviderForUserRequest,
+    ) -> Result<AdminLinkProviderForUserResponse, RusotoError<AdminLinkProviderForUserError>> {
+        self.admin_link_provider_for_user_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Lists devices, as an administrator.</p> <p>Calling this action requires developer credentials.</p>
+    async fn admin_list_devices(
+        &self,
+        input: AdminListDevicesRequest,
+    ) -> Result<AdminListDevicesResponse, RusotoError<AdminListDevicesError>> {
+        self.admin_list_devices_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Lists the groups that the user belongs to.</p> <p>Calling this action requires developer credentials.</p>
+    async fn admin_list_groups_for_user(
+        &self,
+        input: AdminListGroupsForUserRequest,
+    ) -> Result<AdminListGroupsForUserResponse, RusotoError<AdminListGroupsForUserError>> {
+        self.admin_list_groups_for_user_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Lists a history of user activity and any risks detected as part of Amazon Cognito advanced security.</p>
+    async fn admin_list_user_auth_events(
+        &self,
+        input: AdminListUserAuthEventsRequest,
+    ) -> Result<AdminListUserAuthEventsResponse, RusotoError<AdminListUserAuthEventsError>> {
+        self.admin_list_user_auth_events_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Removes the specified user from the specified group.</p> <p>Calling this action requires developer credentials.</p>
+    async fn admin_remove_user_from_group(
+        &self,
+        input: AdminRemoveUserFromGroupRequest,
+    ) -> Result<(), RusotoError<AdminRemoveUserFromGroupError>> {
+        self.admin_remove_user_from_group_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Resets the specified user's password in a user pool as an administrator. Works on any user.</p> <p>When a developer calls this API, the current password is invalidated, so it must be changed. If a user tries to sign in after the API is called, the app will get a PasswordResetRequiredException exception back and should direct the user down the flow to reset the password, which is the same as the forgot password flow. In addition, if the user pool has phone verification selected and a verified phone number exists for the user, or if email verification is selected and a verified email exists for the user, calling this API will also result in sending a message to the end user with the code to change their password.</p> <p>Calling this action requires developer credentials.</p>
+    async fn admin_reset_user_password(
+        &self,
+        input: AdminResetUserPasswordRequest,
+    ) -> Result<AdminResetUserPasswordResponse, RusotoError<AdminResetUserPasswordError>> {
+        self.admin_reset_user_password_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Responds to an authentication challenge, as an administrator.</p> <p>Calling this action requires developer credentials.</p>
+    async fn admin_respond_to_auth_challenge(
+        &self,
+        input: AdminRespondToAuthChallengeRequest,
+    ) -> Result<AdminRespondToAuthChallengeResponse, RusotoError<AdminRespondToAuthChallengeError>>
+    {
+        self.admin_respond_to_auth_challenge_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Sets the user's multi-factor authentication (MFA) preference, including which MFA options are enabled and if any are preferred. Only one factor can be set as preferred. The preferred MFA factor will be used to authenticate a user if multiple factors are enabled. If multiple options are enabled and no preference is set, a challenge to choose an MFA option will be returned during sign in.</p>
+    async fn admin_set_user_mfa_preference(
+        &self,
+        input: AdminSetUserMFAPreferenceRequest,
+    ) -> Result<AdminSetUserMFAPreferenceResponse, RusotoError<AdminSetUserMFAPreferenceError>>
+    {
+        self.admin_set_user_mfa_preference_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Sets the specified user's password in a user pool as an administrator. Works on any user. </p> <p>The password can be temporary or permanent. If it is temporary, the user status will be placed into the <code>FORCE_CHANGE_PASSWORD</code> state. When the user next tries to sign in, the InitiateAuth/AdminInitiateAuth response will contain the <code>NEW_PASSWORD_REQUIRED</code> challenge. If the user does not sign in before it expires, the user will not be able to sign in and their password will need to be reset by an administrator. </p> <p>Once the user has set a new password, or the password is permanent, the user status will be set to <code>Confirmed</code>.</p>
+    async fn admin_set_user_password(
+        &self,
+        input: AdminSetUserPasswordRequest,
+    ) -> Result<AdminSetUserPasswordResponse, RusotoError<AdminSetUserPasswordError>> {
+        self.admin_set_user_password_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p> <i>This action is no longer supported.</i> You can use it to configure only SMS MFA. You can't use it to configure TOTP software token MFA. To configure either type of MFA, use the <a>AdminSetUserMFAPreference</a> action instead.</p>
+    async fn admin_set_user_settings(
+        &self,
+        input: AdminSetUserSettingsRequest,
+    ) -> Result<AdminSetUserSettingsResponse, RusotoError<AdminSetUserSettingsError>> {
+        self.admin_set_user_settings_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Provides feedback for an authentication event as to whether it was from a valid user. This feedback is used for improving the risk evaluation decision for the user pool as part of Amazon Cognito advanced security.</p>
+    async fn admin_update_auth_event_feedback(
+        &self,
+        input: AdminUpdateAuthEventFeedbackRequest,
+    ) -> Result<AdminUpdateAuthEventFeedbackResponse, RusotoError<AdminUpdateAuthEventFeedbackError>>
+    {
+        self.admin_update_auth_event_feedback_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Updates the device status as an administrator.</p> <p>Calling this action requires developer credentials.</p>
+    async fn admin_update_device_status(
+        &self,
+        input: AdminUpdateDeviceStatusRequest,
+    ) -> Result<AdminUpdateDeviceStatusResponse, RusotoError<AdminUpdateDeviceStatusError>> {
+        self.admin_update_device_status_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Updates the specified user's attributes, including developer attributes, as an administrator. Works on any user.</p> <p>For custom attributes, you must prepend the <code>custom:</code> prefix to the attribute name.</p> <p>In addition to updating user attributes, this API can also be used to mark phone and email as verified.</p> <p>Calling this action requires developer credentials.</p>
+    async fn admin_update_user_attributes(
+        &self,
+        input: AdminUpdateUserAttributesRequest,
+    ) -> Result<AdminUpdateUserAttributesResponse, RusotoError<AdminUpdateUserAttributesError>>
+    {
+        self.admin_update_user_attributes_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Signs out users from all devices, as an administrator. It also invalidates all refresh tokens issued to a user. The user's current access and Id tokens remain valid until their expiry. Access and Id tokens expire one hour after they are issued.</p> <p>Calling this action requires developer credentials.</p>
+    async fn admin_user_global_sign_out(
+        &self,
+        input: AdminUserGlobalSignOutRequest,
+    ) -> Result<AdminUserGlobalSignOutResponse, RusotoError<AdminUserGlobalSignOutError>> {
+        self.admin_user_global_sign_out_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns a unique generated shared secret key code for the user account. The request takes an access token or a session string, but not both.</p>
+    async fn associate_software_token(
+        &self,
+        input: AssociateSoftwareTokenRequest,
+    ) -> Result<AssociateSoftwareTokenResponse, RusotoError<AssociateSoftwareTokenError>> {
+        self.associate_software_token_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Changes the password for a specified user in a user pool.</p>
+    async fn change_password(
+        &self,
+        input: ChangePasswordRequest,
+    ) -> Result<ChangePasswordResponse, RusotoError<ChangePasswordError>> {
+        self.change_password_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Confirms tracking of the device. This API call is the call that begins device tracking.</p>
+    async fn confirm_device(
+        &self,
+        input: ConfirmDeviceRequest,
+    ) -> Result<ConfirmDeviceResponse, RusotoError<ConfirmDeviceError>> {
+        self.confirm_device_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Allows a user to enter a confirmation code to reset a forgotten password.</p>
+    async fn confirm_forgot_password(
+        &self,
+        input: ConfirmForgotPasswordRequest,
+    ) -> Result<ConfirmForgotPasswordResponse, RusotoError<ConfirmForgotPasswordError>> {
+        self.confirm_forgot_password_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Confirms registration of a user and handles the existing alias from a previous user.</p>
+    async fn confirm_sign_up(
+        &self,
+        input: ConfirmSignUpRequest,
+    ) -> Result<ConfirmSignUpResponse, RusotoError<ConfirmSignUpError>> {
+        self.confirm_sign_up_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Creates a new group in the specified user pool.</p> <p>Calling this action requires developer credentials.</p>
+    async fn create_group(
+        &self,
+        input: CreateGroupRequest,
+    ) -> Result<CreateGroupResponse, RusotoError<CreateGroupError>> {
+        self.create_group_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Creates an identity provider for a user pool.</p>
+    async fn create_identity_provider(
+        &self,
+        input: CreateIdentityProviderRequest,
+    ) -> Result<CreateIdentityProviderResponse, RusotoError<CreateIdentityProviderError>> {
+        self.create_identity_provider_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Creates a new OAuth2.0 resource server and defines custom scopes in it.</p>
+    async fn create_resource_server(
+        &self,
+        input: CreateResourceServerRequest,
+    ) -> Result<CreateResourceServerResponse, RusotoError<CreateResourceServerError>> {
+        self.create_resource_server_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Creates the user import job.</p>
+    async fn create_user_import_job(
+        &self,
+        input: CreateUserImportJobRequest,
+    ) -> Result<CreateUserImportJobResponse, RusotoError<CreateUserImportJobError>> {
+        self.create_user_import_job_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Creates a new Amazon Cognito user pool and sets the password policy for the pool.</p>
+    async fn create_user_pool(
+        &self,
+        input: CreateUserPoolRequest,
+    ) -> Result<CreateUserPoolResponse, RusotoError<CreateUserPoolError>> {
+        self.create_user_pool_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Creates the user pool client.</p>
+    async fn create_user_pool_client(
+        &self,
+        input: CreateUserPoolClientRequest,
+    ) -> Result<CreateUserPoolClientResponse, RusotoError<CreateUserPoolClientError>> {
+        self.create_user_pool_client_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Creates a new domain for a user pool.</p>
+    async fn create_user_pool_domain(
+        &self,
+        input: CreateUserPoolDomainRequest,
+    ) -> Result<CreateUserPoolDomainResponse, RusotoError<CreateUserPoolDomainError>> {
+        self.create_user_pool_domain_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes a group. Currently only groups with no members can be deleted.</p> <p>Calling this action requires developer credentials.</p>
+    async fn delete_group(
+        &self,
+        input: DeleteGroupRequest,
+    ) -> Result<(), RusotoError<DeleteGroupError>> {
+        self.delete_group_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes an identity provider for a user pool.</p>
+    async fn delete_identity_provider(
+        &self,
+        input: DeleteIdentityProviderRequest,
+    ) -> Result<(), RusotoError<DeleteIdentityProviderError>> {
+        self.delete_identity_provider_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes a resource server.</p>
+    async fn delete_resource_server(
+        &self,
+        input: DeleteResourceServerRequest,
+    ) -> Result<(), RusotoError<DeleteResourceServerError>> {
+        self.delete_resource_server_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Allows a user to delete himself or herself.</p>
+    async fn delete_user(
+        &self,
+        input: DeleteUserRequest,
+    ) -> Result<(), RusotoError<DeleteUserError>> {
+        self.delete_user_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes the attributes for a user.</p>
+    async fn delete_user_attributes(
+        &self,
+        input: DeleteUserAttributesRequest,
+    ) -> Result<DeleteUserAttributesResponse, RusotoError<DeleteUserAttributesError>> {
+        self.delete_user_attributes_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes the specified Amazon Cognito user pool.</p>
+    async fn delete_user_pool(
+        &self,
+        input: DeleteUserPoolRequest,
+    ) -> Result<(), RusotoError<DeleteUserPoolError>> {
+        self.delete_user_pool_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Allows the developer to delete the user pool client.</p>
+    async fn delete_user_pool_client(
+        &self,
+        input: DeleteUserPoolClientRequest,
+    ) -> Result<(), RusotoError<DeleteUserPoolClientError>> {
+        self.delete_user_pool_client_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes a domain for a user pool.</p>
+    async fn delete_user_pool_domain(
+        &self,
+        input: DeleteUserPoolDomainRequest,
+    ) -> Result<DeleteUserPoolDomainResponse, RusotoError<DeleteUserPoolDomainError>> {
+        self.delete_user_pool_domain_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Gets information about a specific identity provider.</p>
+    async fn describe_identity_provider(
+        &self,
+        input: DescribeIdentityProviderRequest,
+    ) -> Result<DescribeIdentityProviderResponse, RusotoError<DescribeIdentityProviderError>> {
+        self.describe_identity_provider_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Describes a resource server.</p>
+    async fn describe_resource_server(
+        &self,
+        input: DescribeResourceServerRequest,
+    ) -> Result<DescribeResourceServerResponse, RusotoError<DescribeResourceServerError>> {
+        self.describe_resource_server_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Describes the risk configuration.</p>
+    async fn describe_risk_configuration(
+        &self,
+        input: DescribeRiskConfigurationRequest,
+    ) -> Result<DescribeRiskConfigurationResponse, RusotoError<DescribeRiskConfigurationError>>
+    {
+        self.describe_risk_configuration_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Describes the user import job.</p>
+    async fn describe_user_import_job(
+        &self,
+        input: DescribeUserImportJobRequest,
+    ) -> Result<DescribeUserImportJobResponse, RusotoError<DescribeUserImportJobError>> {
+        self.describe_user_import_job_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns the configuration information and metadata of the specified user pool.</p>
+    async fn describe_user_pool(
+        &self,
+        input: DescribeUserPoolRequest,
+    ) -> Result<DescribeUserPoolResponse, RusotoError<DescribeUserPoolError>> {
+        self.describe_user_pool_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Client method for returning the configuration information and metadata of the specified user pool app client.</p>
+    async fn describe_user_pool_client(
+        &self,
+        input: DescribeUserPoolClientRequest,
+    ) -> Result<DescribeUserPoolClientResponse, RusotoError<DescribeUserPoolClientError>> {
+        self.describe_user_pool_client_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Gets information about a domain.</p>
+    async fn describe_user_pool_domain(
+        &self,
+        input: DescribeUserPoolDomainRequest,
+    ) -> Result<DescribeUserPoolDomainResponse, RusotoError<DescribeUserPoolDomainError>> {
+        self.describe_user_pool_domain_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Forgets the specified device.</p>
+    async fn forget_device(
+        &self,
+        input: ForgetDeviceRequest,
+    ) -> Result<(), RusotoError<ForgetDeviceError>> {
+        self.forget_device_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Calling this API causes a message to be sent to the end user with a confirmation code that is required to change the user's password. For the <code>Username</code> parameter, you can use the username or user alias. If a verified phone number exists for the user, the confirmation code is sent to the phone number. Otherwise, if a verified email exists, the confirmation code is sent to the email. If neither a verified phone number nor a verified email exists, <code>InvalidParameterException</code> is thrown. To use the confirmation code for resetting the password, call .</p>
+    async fn forgot_password(
+        &self,
+        input: ForgotPasswordRequest,
+    ) -> Result<ForgotPasswordResponse, RusotoError<ForgotPasswordError>> {
+        self.forgot_password_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Gets the header information for the .csv file to be used as input for the user import job.</p>
+    async fn get_csv_header(
+        &self,
+        input: GetCSVHeaderRequest,
+    ) -> Result<GetCSVHeaderResponse, RusotoError<GetCSVHeaderError>> {
+        self.get_csv_header_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Gets the device.</p>
+    async fn get_device(
+        &self,
+        input: GetDeviceRequest,
+    ) -> Result<GetDeviceResponse, RusotoError<GetDeviceError>> {
+        self.get_device_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Gets a group.</p> <p>Calling this action requires developer credentials.</p>
+    async fn get_group(
+        &self,
+        input: GetGroupRequest,
+    ) -> Result<GetGroupResponse, RusotoError<GetGroupError>> {
+        self.get_group_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Gets the specified identity provider.</p>
+    async fn get_identity_provider_by_identifier(
+        &self,
+        input: GetIdentityProviderByIdentifierRequest,
+    ) -> Result<
+        GetIdentityProviderByIdentifierResponse,
+        RusotoError<GetIdentityProviderByIdentifierError>,
+    > {
+        self.get_identity_provider_by_identifier_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>This method takes a user pool ID, and returns the signing certificate.</p>
+    async fn get_signing_certificate(
+        &self,
+        input: GetSigningCertificateRequest,
+    ) -> Result<GetSigningCertificateResponse, RusotoError<GetSigningCertificateError>> {
+        self.get_signing_certificate_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Gets the UI Customization information for a particular app client's app UI, if there is something set. If nothing is set for the particular client, but there is an existing pool level customization (app <code>clientId</code> will be <code>ALL</code>), then that is returned. If nothing is present, then an empty shape is returned.</p>
+    async fn get_ui_customization(
+        &self,
+        input: GetUICustomizationRequest,
+    ) -> Result<GetUICustomizationResponse, RusotoError<GetUICustomizationError>> {
+        self.get_ui_customization_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Gets the user attributes and metadata for a user.</p>
+    async fn get_user(
+        &self,
+        input: GetUserRequest,
+    ) -> Result<GetUserResponse, RusotoError<GetUserError>> {
+        self.get_user_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Gets the user attribute verification code for the specified attribute name.</p>
+    async fn get_user_attribute_verification_code(
+        &self,
+        input: GetUserAttributeVerificationCodeRequest,
+    ) -> Result<
+        GetUserAttributeVerificationCodeResponse,
+        RusotoError<GetUserAttributeVerificationCodeError>,
+    > {
+        self.get_user_attribute_verification_code_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Gets the user pool multi-factor authentication (MFA) configuration.</p>
+    async fn get_user_pool_mfa_config(
+        &self,
+        input: GetUserPoolMfaConfigRequest,
+    ) -> Result<GetUserPoolMfaConfigResponse, RusotoError<GetUserPoolMfaConfigError>> {
+        self.get_user_pool_mfa_config_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Signs out users from all devices. It also invalidates all refresh tokens issued to a user. The user's current access and Id tokens remain valid until their expiry. Access and Id tokens expire one hour after they are issued.</p>
+    async fn global_sign_out(
+        &self,
+        input: GlobalSignOutRequest,
+    ) -> Result<GlobalSignOutResponse, RusotoError<GlobalSignOutError>> {
+        self.global_sign_out_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Initiates the authentication flow.</p>
+    async fn initiate_auth(
+        &self,
+        input: InitiateAuthRequest,
+    ) -> Result<InitiateAuthResponse, RusotoError<InitiateAuthError>> {
+        self.initiate_auth_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Lists the devices.</p>
+    async fn list_devices(
+        &self,
+        input: ListDevicesRequest,
+    ) -> Result<ListDevicesResponse, RusotoError<ListDevicesError>> {
+        self.list_devices_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Lists the groups associated with a user pool.</p> <p>Calling this action requires developer credentials.</p>
+    async fn list_groups(
+        &self,
+        input: ListGroupsRequest,
+    ) -> Result<ListGroupsResponse, RusotoError<ListGroupsError>> {
+        self.list_groups_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Lists information about all identity providers for a user pool.</p>
+    async fn list_identity_providers(
+        &self,
+        input: ListIdentityProvidersRequest,
+    ) -> Result<ListIdentityProvidersResponse, RusotoError<ListIdentityProvidersError>> {
+        self.list_identity_providers_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Lists the resource servers for a user pool.</p>
+    async fn list_resource_servers(
+        &self,
+        input: ListResourceServersRequest,
+    ) -> Result<ListResourceServersResponse, RusotoError<ListResourceServersError>> {
+        self.list_resource_servers_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Lists the tags that are assigned to an Amazon Cognito user pool.</p> <p>A tag is a label that you can apply to user pools to categorize and manage them in different ways, such as by purpose, owner, environment, or other criteria.</p> <p>You can use this action up to 10 times per second, per account.</p>
+    async fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> Result<ListTagsForResourceResponse, RusotoError<ListTagsForResourceError>> {
+        self.list_tags_for_resource_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Lists the user import jobs.</p>
+    async fn list_user_import_jobs(
+        &self,
+        input: ListUserImportJobsRequest,
+    ) -> Result<ListUserImportJobsResponse, RusotoError<ListUserImportJobsError>> {
+        self.list_user_import_jobs_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Lists the clients that have been created for the specified user pool.</p>
+    async fn list_user_pool_clients(
+        &self,
+        input: ListUserPoolClientsRequest,
+    ) -> Result<ListUserPoolClientsResponse, RusotoError<ListUserPoolClientsError>> {
+        self.list_user_pool_clients_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Lists the user pools associated with an AWS account.</p>
+    async fn list_user_pools(
+        &self,
+        input: ListUserPoolsRequest,
+    ) -> Result<ListUserPoolsResponse, RusotoError<ListUserPoolsError>> {
+        self.list_user_pools_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Lists the users in the Amazon Cognito user pool.</p>
+    async fn list_users(
+        &self,
+        input: ListUsersRequest,
+    ) -> Result<ListUsersResponse, RusotoError<ListUsersError>> {
+        self.list_users_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Lists the users in the specified group.</p> <p>Calling this action requires developer credentials.</p>
+    async fn list_users_in_group(
+        &self,
+        input: ListUsersInGroupRequest,
+    ) -> Result<ListUsersInGroupResponse, RusotoError<ListUsersInGroupError>> {
+        self.list_users_in_group_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Resends the confirmation (for confirmation of registration) to a specific user in the user pool.</p>
+    async fn resend_confirmation_code(
+        &self,
+        input: ResendConfirmationCodeRequest,
+    ) -> Result<ResendConfirmationCodeResponse, RusotoError<ResendConfirmationCodeError>> {
+        self.resend_confirmation_code_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Responds to the authentication challenge.</p>
+    async fn respond_to_auth_challenge(
+        &self,
+        input: RespondToAuthChallengeRequest,
+    ) -> Result<RespondToAuthChallengeResponse, RusotoError<RespondToAuthChallengeError>> {
+        self.respond_to_auth_challenge_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Configures actions on detected risks. To delete the risk configuration for <code>UserPoolId</code> or <code>ClientId</code>, pass null values for all four configuration types.</p> <p>To enable Amazon Cognito advanced security features, update the user pool to include the <code>UserPoolAddOns</code> key<code>AdvancedSecurityMode</code>.</p> <p>See .</p>
+    async fn set_risk_configuration(
+        &self,
+        input: SetRiskConfigurationRequest,
+    ) -> Result<SetRiskConfigurationResponse, RusotoError<SetRiskConfigurationError>> {
+        self.set_risk_configuration_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Sets the UI customization information for a user pool&#39;s built-in app UI.</p> <p>You can specify app UI customization settings for a single client (with a specific <code>clientId</code>) or for all clients (by setting the <code>clientId</code> to <code>ALL</code>). If you specify <code>ALL</code>, the default configuration will be used for every client that has no UI customization set previously. If you specify UI customization settings for a particular client, it will no longer fall back to the <code>ALL</code> configuration. </p> <note> <p>To use this API, your user pool must have a domain associated with it. Otherwise, there is no place to host the app&#39;s pages, and the service will throw an error.</p> </note></p>
+    async fn set_ui_customization(
+        &self,
+        input: SetUICustomizationRequest,
+    ) -> Result<SetUICustomizationResponse, RusotoError<SetUICustomizationError>> {
+        self.set_ui_customization_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Set the user's multi-factor authentication (MFA) method preference, including which MFA factors are enabled and if any are preferred. Only one factor can be set as preferred. The preferred MFA factor will be used to authenticate a user if multiple factors are enabled. If multiple options are enabled and no preference is set, a challenge to choose an MFA option will be returned during sign in.</p>
+    async fn set_user_mfa_preference(
+        &self,
+        input: SetUserMFAPreferenceRequest,
+    ) -> Result<SetUserMFAPreferenceResponse, RusotoError<SetUserMFAPreferenceError>> {
+        self.set_user_mfa_preference_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Set the user pool multi-factor authentication (MFA) configuration.</p>
+    async fn set_user_pool_mfa_config(
+        &self,
+        input: SetUserPoolMfaConfigRequest,
+    ) -> Result<SetUserPoolMfaConfigResponse, RusotoError<SetUserPoolMfaConfigError>> {
+        self.set_user_pool_mfa_config_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p> <i>This action is no longer supported.</i> You can use it to configure only SMS MFA. You can't use it to configure TOTP software token MFA. To configure either type of MFA, use the <a>SetUserMFAPreference</a> action instead.</p>
+    async fn set_user_settings(
+        &self,
+        input: SetUserSettingsRequest,
+    ) -> Result<SetUserSettingsResponse, RusotoError<SetUserSettingsError>> {
+        self.set_user_settings_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Registers the user in the specified user pool and creates a user name, password, and user attributes.</p>
+    async fn sign_up(
+        &self,
+        input: SignUpRequest,
+    ) -> Result<SignUpResponse, RusotoError<SignUpError>> {
+        self.sign_up_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Starts the user import.</p>
+    async fn start_user_import_job(
+        &self,
+        input: StartUserImportJobRequest,
+    ) -> Result<StartUserImportJobResponse, RusotoError<StartUserImportJobError>> {
+        self.start_user_import_job_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Stops the user import job.</p>
+    async fn stop_user_import_job(
+        &self,
+        input: StopUserImportJobRequest,
+    ) -> Result<StopUserImportJobResponse, RusotoError<StopUserImportJobError>> {
+        self.stop_user_import_job_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Assigns a set of tags to an Amazon Cognito user pool. A tag is a label that you can use to categorize and manage user pools in different ways, such as by purpose, owner, environment, or other criteria.</p> <p>Each tag consists of a key and value, both of which you define. A key is a general category for more specific values. For example, if you have two versions of a user pool, one for testing and another for production, you might assign an <code>Environment</code> tag key to both user pools. The value of this key might be <code>Test</code> for one user pool and <code>Production</code> for the other.</p> <p>Tags are useful for cost tracking and access control. You can activate your tags so that they appear on the Billing and Cost Management console, where you can track the costs associated with your user pools. In an IAM policy, you can constrain permissions for user pools based on specific tags or tag values.</p> <p>You can use this action up to 5 times per second, per account. A user pool can have as many as 50 tags.</p>
+    async fn tag_resource(
+        &self,
+        input: TagResourceRequest,
+    ) -> Result<TagResourceResponse, RusotoError<TagResourceError>> {
+        self.tag_resource_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Removes the specified tags from an Amazon Cognito user pool. You can use this action up to 5 times per second, per account</p>
+    async fn untag_resource(
+        &self,
+        input: UntagResourceRequest,
+    ) -> Result<UntagResourceResponse, RusotoError<UntagResourceError>> {
+        self.untag_resource_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Provides the feedback for an authentication event whether it was from a valid user or not. This feedback is used for improving the risk evaluation decision for the user pool as part of Amazon Cognito advanced security.</p>
+    async fn update_auth_event_feedback(
+        &self,
+        input: UpdateAuthEventFeedbackRequest,
+    ) -> Result<UpdateAuthEventFeedbackResponse, RusotoError<UpdateAuthEventFeedbackError>> {
+        self.update_auth_event_feedback_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Updates the device status.</p>
+    async fn update_device_status(
+        &self,
+        input: UpdateDeviceStatusRequest,
+    ) -> Result<UpdateDeviceStatusResponse, RusotoError<UpdateDeviceStatusError>> {
+        self.update_device_status_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Updates the specified group with the specified attributes.</p> <p>Calling this action requires developer credentials.</p> <important> <p>If you don&#39;t provide a value for an attribute, it will be set to the default value.</p> </important></p>
+    async fn update_group(
+        &self,
+        input: UpdateGroupRequest,
+    ) -> Result<UpdateGroupResponse, RusotoError<UpdateGroupError>> {
+        self.update_group_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Updates identity provider information for a user pool.</p>
+    async fn update_identity_provider(
+        &self,
+        input: UpdateIdentityProviderRequest,
+    ) -> Result<UpdateIdentityProviderResponse, RusotoError<UpdateIdentityProviderError>> {
+        self.update_identity_provider_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Updates the name and scopes of resource server. All other fields are read-only.</p> <important> <p>If you don&#39;t provide a value for an attribute, it will be set to the default value.</p> </important></p>
+    async fn update_resource_server(
+        &self,
+        input: UpdateResourceServerRequest,
+    ) -> Result<UpdateResourceServerResponse, RusotoError<UpdateResourceServerError>> {
+        self.update_resource_server_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Allows a user to update a specific attribute (one at a time).</p>
+    async fn update_user_attributes(
+        &self,
+        input: UpdateUserAttributesRequest,
+    ) -> Result<UpdateUserAttributesResponse, RusotoError<UpdateUserAttributesError>> {
+        self.update_user_attributes_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Updates the specified user pool with the specified attributes. You can get a list of the current user pool settings with .</p> <important> <p>If you don&#39;t provide a value for an attribute, it will be set to the default value.</p> </important></p>
+    async fn update_user_pool(
+        &self,
+        input: UpdateUserPoolRequest,
+    ) -> Result<UpdateUserPoolResponse, RusotoError<UpdateUserPoolError>> {
+        self.update_user_pool_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Updates the specified user pool app client with the specified attributes. You can get a list of the current user pool app client settings with .</p> <important> <p>If you don&#39;t provide a value for an attribute, it will be set to the default value.</p> </important></p>
+    async fn update_user_pool_client(
+        &self,
+        input: UpdateUserPoolClientRequest,
+    ) -> Result<UpdateUserPoolClientResponse, RusotoError<UpdateUserPoolClientError>> {
+        self.update_user_pool_client_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Updates the Secure Sockets Layer (SSL) certificate for the custom domain for your user pool.</p> <p>You can use this operation to provide the Amazon Resource Name (ARN) of a new certificate to Amazon Cognito. You cannot use it to change the domain for a user pool.</p> <p>A custom domain is used to host the Amazon Cognito hosted UI, which provides sign-up and sign-in pages for your application. When you set up a custom domain, you provide a certificate that you manage with AWS Certificate Manager (ACM). When necessary, you can use this operation to change the certificate that you applied to your custom domain.</p> <p>Usually, this is unnecessary following routine certificate renewal with ACM. When you renew your existing certificate in ACM, the ARN for your certificate remains the same, and your custom domain uses the new certificate automatically.</p> <p>However, if you replace your existing certificate with a new one, ACM gives the new certificate a new ARN. To apply the new certificate to your custom domain, you must provide this ARN to Amazon Cognito.</p> <p>When you add your new certificate in ACM, you must choose US East (N. Virginia) as the AWS Region.</p> <p>After you submit your request, Amazon Cognito requires up to 1 hour to distribute your new certificate to your custom domain.</p> <p>For more information about adding a custom domain to your user pool, see <a href="https://docs.aws.amazon.com/cognito/latest/developerguide/cognito-user-pools-add-custom-domain.html">Using Your Own Domain for the Hosted UI</a>.</p>
+    async fn update_user_pool_domain(
+        &self,
+        input: UpdateUserPoolDomainRequest,
+    ) -> Result<UpdateUserPoolDomainResponse, RusotoError<UpdateUserPoolDomainError>> {
+        self.update_user_pool_domain_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Use this API to register a user's entered TOTP code and mark the user's software token MFA status as "verified" if successful. The request takes an access token or a session string, but not both.</p>
+    async fn verify_software_token(
+        &self,
+        input: VerifySoftwareTokenRequest,
+    ) -> Result<VerifySoftwareTokenResponse, RusotoError<VerifySoftwareTokenError>> {
+        self.verify_software_token_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Verifies the specified user attributes in the user pool.</p>
+    async fn verify_user_attribute(
+        &self,
+        input: VerifyUserAttributeRequest,
+    ) -> Result<VerifyUserAttributeResponse, RusotoError<VerifyUserAttributeError>> {
+        self.verify_user_attribute_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
     }
 }
@@ -16,8 +16,8 @@ use std::fmt;
 use async_trait::async_trait;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
-use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest, ResponseMetadata};
+use rusoto_core::{Client, ErrorMetadata, RusotoError};
 
 use rusoto_core::param::{Params, ServiceParams};
 use rusoto_core::proto;
@@ -691,6 +691,7 @@ pub struct UpdateRecordsResponse {
 
 /// Errors returned by BulkPublish
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum BulkPublishError {
     /// <p>An exception thrown when a bulk publish operation is requested less than 24 hours after a previous bulk publish operation completed successfully.</p>
     AlreadyStreamed(String),
@@ -704,6 +705,8 @@ pub enum BulkPublishError {
     NotAuthorized(String),
     /// <p>Thrown if the resource doesn&#39;t exist.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl BulkPublishError {
@@ -711,25 +714,25 @@ impl BulkPublishError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AlreadyStreamedException" => {
-                    return RusotoError::Service(BulkPublishError::AlreadyStreamed(err.msg))
+                    return RusotoError::service(BulkPublishError::AlreadyStreamed(err.msg), &res)
                 }
                 "DuplicateRequestException" => {
-                    return RusotoError::Service(BulkPublishError::DuplicateRequest(err.msg))
+                    return RusotoError::service(BulkPublishError::DuplicateRequest(err.msg), &res)
                 }
                 "InternalErrorException" => {
-                    return RusotoError::Service(BulkPublishError::InternalError(err.msg))
+                    return RusotoError::service(BulkPublishError::InternalError(err.msg), &res)
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(BulkPublishError::InvalidParameter(err.msg))
+                    return RusotoError::service(BulkPublishError::InvalidParameter(err.msg), &res)
                 }
                 "NotAuthorizedException" => {
-                    return RusotoError::Service(BulkPublishError::NotAuthorized(err.msg))
+                    return RusotoError::service(BulkPublishError::NotAuthorized(err.msg), &res)
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(BulkPublishError::ResourceNotFound(err.msg))
+                    return RusotoError::service(BulkPublishError::ResourceNotFound(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(BulkPublishError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -745,12 +748,14 @@ impl fmt::Display for BulkPublishError {
             BulkPublishError::InvalidParameter(ref cause) => write!(f, "{}", cause),
             BulkPublishError::NotAuthorized(ref cause) => write!(f, "{}", cause),
             BulkPublishError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            BulkPublishError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for BulkPublishError {}
 /// Errors returned by DeleteDataset
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteDatasetError {
     /// <p>Indicates an internal service error.</p>
     InternalError(String),
@@ -764,6 +769,8 @@ pub enum DeleteDatasetError {
     ResourceNotFound(String),
     /// <p>Thrown if the request is throttled.</p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteDatasetError {
@@ -771,25 +778,34 @@ impl DeleteDatasetError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalErrorException" => {
-                    return RusotoError::Service(DeleteDatasetError::InternalError(err.msg))
+                    return RusotoError::service(DeleteDatasetError::InternalError(err.msg), &res)
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DeleteDatasetError::InvalidParameter(err.msg))
+                    return RusotoError::service(
+                        DeleteDatasetError::InvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "NotAuthorizedException" => {
-                    return RusotoError::Service(DeleteDatasetError::NotAuthorized(err.msg))
+                    return RusotoError::service(DeleteDatasetError::NotAuthorized(err.msg), &res)
                 }
                 "ResourceConflictException" => {
-                    return RusotoError::Service(DeleteDatasetError::ResourceConflict(err.msg))
+                    return RusotoError::service(
+                        DeleteDatasetError::ResourceConflict(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteDatasetError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        DeleteDatasetError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(DeleteDatasetError::TooManyRequests(err.msg))
+                    return RusotoError::service(DeleteDatasetError::TooManyRequests(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteDatasetError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -805,12 +821,14 @@ impl fmt::Display for DeleteDatasetError {
             DeleteDatasetError::ResourceConflict(ref cause) => write!(f, "{}", cause),
             DeleteDatasetError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             DeleteDatasetError::TooManyRequests(ref cause) => write!(f, "{}", cause),
+            DeleteDatasetError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteDatasetError {}
 /// Errors returned by DescribeDataset
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeDatasetError {
     /// <p>Indicates an internal service error.</p>
     InternalError(String),
@@ -822,6 +840,8 @@ pub enum DescribeDatasetError {
     ResourceNotFound(String),
     /// <p>Thrown if the request is throttled.</p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeDatasetError {
@@ -829,22 +849,31 @@ impl DescribeDatasetError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalErrorException" => {
-                    return RusotoError::Service(DescribeDatasetError::InternalError(err.msg))
+                    return RusotoError::service(DescribeDatasetError::InternalError(err.msg), &res)
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DescribeDatasetError::InvalidParameter(err.msg))
+                    return RusotoError::service(
+                        DescribeDatasetError::InvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "NotAuthorizedException" => {
-                    return RusotoError::Service(DescribeDatasetError::NotAuthorized(err.msg))
+                    return RusotoError::service(DescribeDatasetError::NotAuthorized(err.msg), &res)
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeDatasetError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        DescribeDatasetError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(DescribeDatasetError::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        DescribeDatasetError::TooManyRequests(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DescribeDatasetError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -859,12 +888,14 @@ impl fmt::Display for DescribeDatasetError {
             DescribeDatasetError::NotAuthorized(ref cause) => write!(f, "{}", cause),
             DescribeDatasetError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             DescribeDatasetError::TooManyRequests(ref cause) => write!(f, "{}", cause),
+            DescribeDatasetError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeDatasetError {}
 /// Errors returned by DescribeIdentityPoolUsage
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeIdentityPoolUsageError {
     /// <p>Indicates an internal service error.</p>
     InternalError(String),
@@ -876,6 +907,8 @@ pub enum DescribeIdentityPoolUsageError {
     ResourceNotFound(String),
     /// <p>Thrown if the request is throttled.</p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeIdentityPoolUsageError {
@@ -883,32 +916,39 @@ impl DescribeIdentityPoolUsageError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalErrorException" => {
-                    return RusotoError::Service(DescribeIdentityPoolUsageError::InternalError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeIdentityPoolUsageError::InternalError(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DescribeIdentityPoolUsageError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeIdentityPoolUsageError::InvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "NotAuthorizedException" => {
-                    return RusotoError::Service(DescribeIdentityPoolUsageError::NotAuthorized(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeIdentityPoolUsageError::NotAuthorized(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeIdentityPoolUsageError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeIdentityPoolUsageError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(DescribeIdentityPoolUsageError::TooManyRequests(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeIdentityPoolUsageError::TooManyRequests(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(DescribeIdentityPoolUsageError::Unhandled, &res)
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -923,12 +963,14 @@ impl fmt::Display for DescribeIdentityPoolUsageError {
             DescribeIdentityPoolUsageError::NotAuthorized(ref cause) => write!(f, "{}", cause),
             DescribeIdentityPoolUsageError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             DescribeIdentityPoolUsageError::TooManyRequests(ref cause) => write!(f, "{}", cause),
+            DescribeIdentityPoolUsageError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeIdentityPoolUsageError {}
 /// Errors returned by DescribeIdentityUsage
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeIdentityUsageError {
     /// <p>Indicates an internal service error.</p>
     InternalError(String),
@@ -940,6 +982,8 @@ pub enum DescribeIdentityUsageError {
     ResourceNotFound(String),
     /// <p>Thrown if the request is throttled.</p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeIdentityUsageError {
@@ -947,28 +991,37 @@ impl DescribeIdentityUsageError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalErrorException" => {
-                    return RusotoError::Service(DescribeIdentityUsageError::InternalError(err.msg))
+                    return RusotoError::service(
+                        DescribeIdentityUsageError::InternalError(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DescribeIdentityUsageError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeIdentityUsageError::InvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "NotAuthorizedException" => {
-                    return RusotoError::Service(DescribeIdentityUsageError::NotAuthorized(err.msg))
+                    return RusotoError::service(
+                        DescribeIdentityUsageError::NotAuthorized(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeIdentityUsageError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeIdentityUsageError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(DescribeIdentityUsageError::TooManyRequests(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeIdentityUsageError::TooManyRequests(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DescribeIdentityUsageError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -983,12 +1036,14 @@ impl fmt::Display for DescribeIdentityUsageError {
             DescribeIdentityUsageError::NotAuthorized(ref cause) => write!(f, "{}", cause),
             DescribeIdentityUsageError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             DescribeIdentityUsageError::TooManyRequests(ref cause) => write!(f, "{}", cause),
+            DescribeIdentityUsageError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeIdentityUsageError {}
 /// Errors returned by GetBulkPublishDetails
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetBulkPublishDetailsError {
     /// <p>Indicates an internal service error.</p>
     InternalError(String),
@@ -998,6 +1053,8 @@ pub enum GetBulkPublishDetailsError {
     NotAuthorized(String),
     /// <p>Thrown if the resource doesn&#39;t exist.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetBulkPublishDetailsError {
@@ -1005,23 +1062,31 @@ impl GetBulkPublishDetailsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalErrorException" => {
-                    return RusotoError::Service(GetBulkPublishDetailsError::InternalError(err.msg))
+                    return RusotoError::service(
+                        GetBulkPublishDetailsError::InternalError(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(GetBulkPublishDetailsError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetBulkPublishDetailsError::InvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "NotAuthorizedException" => {
-                    return RusotoError::Service(GetBulkPublishDetailsError::NotAuthorized(err.msg))
+                    return RusotoError::service(
+                        GetBulkPublishDetailsError::NotAuthorized(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetBulkPublishDetailsError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetBulkPublishDetailsError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetBulkPublishDetailsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1035,12 +1100,14 @@ impl fmt::Display for GetBulkPublishDetailsError {
             GetBulkPublishDetailsError::InvalidParameter(ref cause) => write!(f, "{}", cause),
             GetBulkPublishDetailsError::NotAuthorized(ref cause) => write!(f, "{}", cause),
             GetBulkPublishDetailsError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            GetBulkPublishDetailsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetBulkPublishDetailsError {}
 /// Errors returned by GetCognitoEvents
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetCognitoEventsError {
     /// <p>Indicates an internal service error.</p>
     InternalError(String),
@@ -1052,6 +1119,8 @@ pub enum GetCognitoEventsError {
     ResourceNotFound(String),
     /// <p>Thrown if the request is throttled.</p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetCognitoEventsError {
@@ -1059,22 +1128,37 @@ impl GetCognitoEventsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalErrorException" => {
-                    return RusotoError::Service(GetCognitoEventsError::InternalError(err.msg))
+                    return RusotoError::service(
+                        GetCognitoEventsError::InternalError(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(GetCognitoEventsError::InvalidParameter(err.msg))
+                    return RusotoError::service(
+                        GetCognitoEventsError::InvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "NotAuthorizedException" => {
-                    return RusotoError::Service(GetCognitoEventsError::NotAuthorized(err.msg))
+                    return RusotoError::service(
+                        GetCognitoEventsError::NotAuthorized(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetCognitoEventsError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        GetCognitoEventsError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(GetCognitoEventsError::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        GetCognitoEventsError::TooManyRequests(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetCognitoEventsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1089,12 +1173,14 @@ impl fmt::Display for GetCognitoEventsError {
             GetCognitoEventsError::NotAuthorized(ref cause) => write!(f, "{}", cause),
             GetCognitoEventsError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             GetCognitoEventsError::TooManyRequests(ref cause) => write!(f, "{}", cause),
+            GetCognitoEventsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetCognitoEventsError {}
 /// Errors returned by GetIdentityPoolConfiguration
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetIdentityPoolConfigurationError {
     /// <p>Indicates an internal service error.</p>
     InternalError(String),
@@ -1106,6 +1192,8 @@ pub enum GetIdentityPoolConfigurationError {
     ResourceNotFound(String),
     /// <p>Thrown if the request is throttled.</p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetIdentityPoolConfigurationError {
@@ -1115,32 +1203,42 @@ impl GetIdentityPoolConfigurationError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalErrorException" => {
-                    return RusotoError::Service(GetIdentityPoolConfigurationError::InternalError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetIdentityPoolConfigurationError::InternalError(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         GetIdentityPoolConfigurationError::InvalidParameter(err.msg),
+                        &res,
                     )
                 }
                 "NotAuthorizedException" => {
-                    return RusotoError::Service(GetIdentityPoolConfigurationError::NotAuthorized(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetIdentityPoolConfigurationError::NotAuthorized(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         GetIdentityPoolConfigurationError::ResourceNotFound(err.msg),
+                        &res,
                     )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         GetIdentityPoolConfigurationError::TooManyRequests(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        GetIdentityPoolConfigurationError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -1159,12 +1257,14 @@ impl fmt::Display for GetIdentityPoolConfigurationError {
                 write!(f, "{}", cause)
             }
             GetIdentityPoolConfigurationError::TooManyRequests(ref cause) => write!(f, "{}", cause),
+            GetIdentityPoolConfigurationError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetIdentityPoolConfigurationError {}
 /// Errors returned by ListDatasets
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListDatasetsError {
     /// <p>Indicates an internal service error.</p>
     InternalError(String),
@@ -1174,6 +1274,8 @@ pub enum ListDatasetsError {
     NotAuthorized(String),
     /// <p>Thrown if the request is throttled.</p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListDatasetsError {
@@ -1181,19 +1283,19 @@ impl ListDatasetsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalErrorException" => {
-                    return RusotoError::Service(ListDatasetsError::InternalError(err.msg))
+                    return RusotoError::service(ListDatasetsError::InternalError(err.msg), &res)
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(ListDatasetsError::InvalidParameter(err.msg))
+                    return RusotoError::service(ListDatasetsError::InvalidParameter(err.msg), &res)
                 }
                 "NotAuthorizedException" => {
-                    return RusotoError::Service(ListDatasetsError::NotAuthorized(err.msg))
+                    return RusotoError::service(ListDatasetsError::NotAuthorized(err.msg), &res)
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(ListDatasetsError::TooManyRequests(err.msg))
+                    return RusotoError::service(ListDatasetsError::TooManyRequests(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListDatasetsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1207,12 +1309,14 @@ impl fmt::Display for ListDatasetsError {
             ListDatasetsError::InvalidParameter(ref cause) => write!(f, "{}", cause),
             ListDatasetsError::NotAuthorized(ref cause) => write!(f, "{}", cause),
             ListDatasetsError::TooManyRequests(ref cause) => write!(f, "{}", cause),
+            ListDatasetsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListDatasetsError {}
 /// Errors returned by ListIdentityPoolUsage
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListIdentityPoolUsageError {
     /// <p>Indicates an internal service error.</p>
     InternalError(String),
@@ -1222,6 +1326,8 @@ pub enum ListIdentityPoolUsageError {
     NotAuthorized(String),
     /// <p>Thrown if the request is throttled.</p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListIdentityPoolUsageError {
@@ -1229,23 +1335,31 @@ impl ListIdentityPoolUsageError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalErrorException" => {
-                    return RusotoError::Service(ListIdentityPoolUsageError::InternalError(err.msg))
+                    return RusotoError::service(
+                        ListIdentityPoolUsageError::InternalError(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(ListIdentityPoolUsageError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListIdentityPoolUsageError::InvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "NotAuthorizedException" => {
-                    return RusotoError::Service(ListIdentityPoolUsageError::NotAuthorized(err.msg))
+                    return RusotoError::service(
+                        ListIdentityPoolUsageError::NotAuthorized(err.msg),
+                        &res,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(ListIdentityPoolUsageError::TooManyRequests(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListIdentityPoolUsageError::TooManyRequests(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListIdentityPoolUsageError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1259,12 +1373,14 @@ impl fmt::Display for ListIdentityPoolUsageError {
             ListIdentityPoolUsageError::InvalidParameter(ref cause) => write!(f, "{}", cause),
             ListIdentityPoolUsageError::NotAuthorized(ref cause) => write!(f, "{}", cause),
             ListIdentityPoolUsageError::TooManyRequests(ref cause) => write!(f, "{}", cause),
+            ListIdentityPoolUsageError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListIdentityPoolUsageError {}
 /// Errors returned by ListRecords
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListRecordsError {
     /// <p>Indicates an internal service error.</p>
     InternalError(String),
@@ -1274,6 +1390,8 @@ pub enum ListRecordsError {
     NotAuthorized(String),
     /// <p>Thrown if the request is throttled.</p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListRecordsError {
@@ -1281,19 +1399,19 @@ impl ListRecordsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalErrorException" => {
-                    return RusotoError::Service(ListRecordsError::InternalError(err.msg))
+                    return RusotoError::service(ListRecordsError::InternalError(err.msg), &res)
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(ListRecordsError::InvalidParameter(err.msg))
+                    return RusotoError::service(ListRecordsError::InvalidParameter(err.msg), &res)
                 }
                 "NotAuthorizedException" => {
-                    return RusotoError::Service(ListRecordsError::NotAuthorized(err.msg))
+                    return RusotoError::service(ListRecordsError::NotAuthorized(err.msg), &res)
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(ListRecordsError::TooManyRequests(err.msg))
+                    return RusotoError::service(ListRecordsError::TooManyRequests(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListRecordsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1307,12 +1425,14 @@ impl fmt::Display for ListRecordsError {
             ListRecordsError::InvalidParameter(ref cause) => write!(f, "{}", cause),
             ListRecordsError::NotAuthorized(ref cause) => write!(f, "{}", cause),
             ListRecordsError::TooManyRequests(ref cause) => write!(f, "{}", cause),
+            ListRecordsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListRecordsError {}
 /// Errors returned by RegisterDevice
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum RegisterDeviceError {
     /// <p>Indicates an internal service error.</p>
     InternalError(String),
@@ -1326,6 +1446,8 @@ pub enum RegisterDeviceError {
     ResourceNotFound(String),
     /// <p>Thrown if the request is throttled.</p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl RegisterDeviceError {
@@ -1333,25 +1455,37 @@ impl RegisterDeviceError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalErrorException" => {
-                    return RusotoError::Service(RegisterDeviceError::InternalError(err.msg))
+                    return RusotoError::service(RegisterDeviceError::InternalError(err.msg), &res)
                 }
                 "InvalidConfigurationException" => {
-                    return RusotoError::Service(RegisterDeviceError::InvalidConfiguration(err.msg))
+                    return RusotoError::service(
+                        RegisterDeviceError::InvalidConfiguration(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(RegisterDeviceError::InvalidParameter(err.msg))
+                    return RusotoError::service(
+                        RegisterDeviceError::InvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "NotAuthorizedException" => {
-                    return RusotoError::Service(RegisterDeviceError::NotAuthorized(err.msg))
+                    return RusotoError::service(RegisterDeviceError::NotAuthorized(err.msg), &res)
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(RegisterDeviceError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        RegisterDeviceError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(RegisterDeviceError::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        RegisterDeviceError::TooManyRequests(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(RegisterDeviceError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1367,12 +1501,14 @@ impl fmt::Display for RegisterDeviceError {
             RegisterDeviceError::NotAuthorized(ref cause) => write!(f, "{}", cause),
             RegisterDeviceError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             RegisterDeviceError::TooManyRequests(ref cause) => write!(f, "{}", cause),
+            RegisterDeviceError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for RegisterDeviceError {}
 /// Errors returned by SetCognitoEvents
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum SetCognitoEventsError {
     /// <p>Indicates an internal service error.</p>
     InternalError(String),
@@ -1384,6 +1520,8 @@ pub enum SetCognitoEventsError {
     ResourceNotFound(String),
     /// <p>Thrown if the request is throttled.</p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl SetCognitoEventsError {
@@ -1391,22 +1529,37 @@ impl SetCognitoEventsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalErrorException" => {
-                    return RusotoError::Service(SetCognitoEventsError::InternalError(err.msg))
+                    return RusotoError::service(
+                        SetCognitoEventsError::InternalError(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(SetCognitoEventsError::InvalidParameter(err.msg))
+                    return RusotoError::service(
+                        SetCognitoEventsError::InvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "NotAuthorizedException" => {
-                    return RusotoError::Service(SetCognitoEventsError::NotAuthorized(err.msg))
+                    return RusotoError::service(
+                        SetCognitoEventsError::NotAuthorized(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(SetCognitoEventsError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        SetCognitoEventsError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(SetCognitoEventsError::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        SetCognitoEventsError::TooManyRequests(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(SetCognitoEventsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1421,12 +1574,14 @@ impl fmt::Display for SetCognitoEventsError {
             SetCognitoEventsError::NotAuthorized(ref cause) => write!(f, "{}", cause),
             SetCognitoEventsError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             SetCognitoEventsError::TooManyRequests(ref cause) => write!(f, "{}", cause),
+            SetCognitoEventsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for SetCognitoEventsError {}
 /// Errors returned by SetIdentityPoolConfiguration
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum SetIdentityPoolConfigurationError {
     /// <p>Thrown if there are parallel requests to modify a resource.</p>
     ConcurrentModification(String),
@@ -1440,6 +1595,8 @@ pub enum SetIdentityPoolConfigurationError {
     ResourceNotFound(String),
     /// <p>Thrown if the request is throttled.</p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl SetIdentityPoolConfigurationError {
@@ -1449,37 +1606,48 @@ impl SetIdentityPoolConfigurationError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         SetIdentityPoolConfigurationError::ConcurrentModification(err.msg),
+                        &res,
                     )
                 }
                 "InternalErrorException" => {
-                    return RusotoError::Service(SetIdentityPoolConfigurationError::InternalError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        SetIdentityPoolConfigurationError::InternalError(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         SetIdentityPoolConfigurationError::InvalidParameter(err.msg),
+                        &res,
                     )
                 }
                 "NotAuthorizedException" => {
-                    return RusotoError::Service(SetIdentityPoolConfigurationError::NotAuthorized(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        SetIdentityPoolConfigurationError::NotAuthorized(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         SetIdentityPoolConfigurationError::ResourceNotFound(err.msg),
+                        &res,
                     )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         SetIdentityPoolConfigurationError::TooManyRequests(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        SetIdentityPoolConfigurationError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -1501,12 +1669,14 @@ impl fmt::Display for SetIdentityPoolConfigurationError {
                 write!(f, "{}", cause)
             }
             SetIdentityPoolConfigurationError::TooManyRequests(ref cause) => write!(f, "{}", cause),
+            SetIdentityPoolConfigurationError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for SetIdentityPoolConfigurationError {}
 /// Errors returned by SubscribeToDataset
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum SubscribeToDatasetError {
     /// <p>Indicates an internal service error.</p>
     InternalError(String),
@@ -1520,6 +1690,8 @@ pub enum SubscribeToDatasetError {
     ResourceNotFound(String),
     /// <p>Thrown if the request is throttled.</p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl SubscribeToDatasetError {
@@ -1527,27 +1699,43 @@ impl SubscribeToDatasetError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalErrorException" => {
-                    return RusotoError::Service(SubscribeToDatasetError::InternalError(err.msg))
+                    return RusotoError::service(
+                        SubscribeToDatasetError::InternalError(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidConfigurationException" => {
-                    return RusotoError::Service(SubscribeToDatasetError::InvalidConfiguration(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        SubscribeToDatasetError::InvalidConfiguration(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(SubscribeToDatasetError::InvalidParameter(err.msg))
+                    return RusotoError::service(
+                        SubscribeToDatasetError::InvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "NotAuthorizedException" => {
-                    return RusotoError::Service(SubscribeToDatasetError::NotAuthorized(err.msg))
+                    return RusotoError::service(
+                        SubscribeToDatasetError::NotAuthorized(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(SubscribeToDatasetError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        SubscribeToDatasetError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(SubscribeToDatasetError::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        SubscribeToDatasetError::TooManyRequests(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(SubscribeToDatasetError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1563,12 +1751,14 @@ impl fmt::Display for SubscribeToDatasetError {
             SubscribeToDatasetError::NotAuthorized(ref cause) => write!(f, "{}", cause),
             SubscribeToDatasetError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             SubscribeToDatasetError::TooManyRequests(ref cause) => write!(f, "{}", cause),
+            SubscribeToDatasetError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for SubscribeToDatasetError {}
 /// Errors returned by UnsubscribeFromDataset
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UnsubscribeFromDatasetError {
     /// <p>Indicates an internal service error.</p>
     InternalError(String),
@@ -1582,6 +1772,8 @@ pub enum UnsubscribeFromDatasetError {
     ResourceNotFound(String),
     /// <p>Thrown if the request is throttled.</p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UnsubscribeFromDatasetError {
@@ -1589,37 +1781,43 @@ impl UnsubscribeFromDatasetError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalErrorException" => {
-                    return RusotoError::Service(UnsubscribeFromDatasetError::InternalError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UnsubscribeFromDatasetError::InternalError(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidConfigurationException" => {
-                    return RusotoError::Service(UnsubscribeFromDatasetError::InvalidConfiguration(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UnsubscribeFromDatasetError::InvalidConfiguration(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(UnsubscribeFromDatasetError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UnsubscribeFromDatasetError::InvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "NotAuthorizedException" => {
-                    return RusotoError::Service(UnsubscribeFromDatasetError::NotAuthorized(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UnsubscribeFromDatasetError::NotAuthorized(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UnsubscribeFromDatasetError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UnsubscribeFromDatasetError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(UnsubscribeFromDatasetError::TooManyRequests(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UnsubscribeFromDatasetError::TooManyRequests(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UnsubscribeFromDatasetError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1635,12 +1833,14 @@ impl fmt::Display for UnsubscribeFromDatasetError {
             UnsubscribeFromDatasetError::NotAuthorized(ref cause) => write!(f, "{}", cause),
             UnsubscribeFromDatasetError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             UnsubscribeFromDatasetError::TooManyRequests(ref cause) => write!(f, "{}", cause),
+            UnsubscribeFromDatasetError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for UnsubscribeFromDatasetError {}
 /// Errors returned by UpdateRecords
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdateRecordsError {
     /// <p>Indicates an internal service error.</p>
     InternalError(String),
@@ -1660,6 +1860,8 @@ pub enum UpdateRecordsError {
     ResourceNotFound(String),
     /// <p>Thrown if the request is throttled.</p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdateRecordsError {
@@ -1667,36 +1869,46 @@ impl UpdateRecordsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalErrorException" => {
-                    return RusotoError::Service(UpdateRecordsError::InternalError(err.msg))
+                    return RusotoError::service(UpdateRecordsError::InternalError(err.msg), &res)
                 }
                 "InvalidLambdaFunctionOutputException" => {
-                    return RusotoError::Service(UpdateRecordsError::InvalidLambdaFunctionOutput(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateRecordsError::InvalidLambdaFunctionOutput(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(UpdateRecordsError::InvalidParameter(err.msg))
+                    return RusotoError::service(
+                        UpdateRecordsError::InvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "LambdaThrottledException" => {
-                    return RusotoError::Service(UpdateRecordsError::LambdaThrottled(err.msg))
+                    return RusotoError::service(UpdateRecordsError::LambdaThrottled(err.msg), &res)
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(UpdateRecordsError::LimitExceeded(err.msg))
+                    return RusotoError::service(UpdateRecordsError::LimitExceeded(err.msg), &res)
                 }
                 "NotAuthorizedException" => {
-                    return RusotoError::Service(UpdateRecordsError::NotAuthorized(err.msg))
+                    return RusotoError::service(UpdateRecordsError::NotAuthorized(err.msg), &res)
                 }
                 "ResourceConflictException" => {
-                    return RusotoError::Service(UpdateRecordsError::ResourceConflict(err.msg))
+                    return RusotoError::service(
+                        UpdateRecordsError::ResourceConflict(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateRecordsError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        UpdateRecordsError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(UpdateRecordsError::TooManyRequests(err.msg))
+                    return RusotoError::service(UpdateRecordsError::TooManyRequests(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UpdateRecordsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1715,6 +1927,7 @@ impl fmt::Display for UpdateRecordsError {
             UpdateRecordsError::ResourceConflict(ref cause) => write!(f, "{}", cause),
             UpdateRecordsError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             UpdateRecordsError::TooManyRequests(ref cause) => write!(f, "{}", cause),
+            UpdateRecordsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
@@ -1835,6 +2048,7 @@ impl CognitoSyncClient {
     /// Creates a client backed by the default tokio event loop.
     ///
     /// The client will use the default credentials provider and tls client.
+    #[cfg(not(target_arch = "wasm32"))]
     pub fn new(region: region::Region) -> CognitoSyncClient {
         CognitoSyncClient {
             client: Client::shared(),
@@ -1860,45 +2074,120 @@ impl CognitoSyncClient {
     pub fn new_with_client(client: Client, region: region::Region) -> CognitoSyncClient {
         CognitoSyncClient { client, region }
     }
+
+    /// Returns a builder of a client, setting any of its region, credentials provider,
+    /// dispatcher, retry policy, endpoint and timeouts.
+    pub fn builder() -> rusoto_core::ClientBuilder<CognitoSyncClient> {
+        rusoto_core::ClientBuilder::new(CognitoSyncClient::new_with_client)
+    }
+
+    /// Returns a clone of this client with the overrides of `config` applied to its
+    /// operations, sharing its connections.
+    pub fn customize(&self, config: &rusoto_core::OperationConfig) -> CognitoSyncClient {
+        CognitoSyncClient {
+            client: self.client.customize(config),
+            region: config.resolve_region(&self.region),
+        }
+    }
 }
 
-#[async_trait]
-impl CognitoSync for CognitoSyncClient {
-    /// <p>Initiates a bulk publish of all existing datasets for an Identity Pool to the configured stream. Customers are limited to one successful bulk publish per 24 hours. Bulk publish is an asynchronous request, customers can see the status of the request via the GetBulkPublishDetails operation.</p> <p>This API can only be called with developer credentials. You cannot call this API with the temporary user credentials provided by Cognito Identity.</p>
-    async fn bulk_publish(
+impl CognitoSyncClient {
+    /// Calls `bulk_publish`, also returning the metadata of the response.
+    pub async fn bulk_publish_with_metadata(
         &self,
         input: BulkPublishRequest,
-    ) -> Result<BulkPublishResponse, RusotoError<BulkPublishError>> {
+    ) -> Result<(BulkPublishResponse, ResponseMetadata), RusotoError<BulkPublishError>> {
+        let request = self.build_bulk_publish_request(input);
+
+        let mut response = self
+            .client
+            .sign_and_dispatch(request)
+            .await
+            .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
+        if response.status.as_u16() == 200 {
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<BulkPublishResponse, _>()?;
+
+            Ok((result, metadata))
+        } else {
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(BulkPublishError::from_response(response))
+        }
+    }
+
+    /// Presigns the request `bulk_publish` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_bulk_publish(
+        &self,
+        presigner: &rusoto_core::Presigner,
+        input: BulkPublishRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_bulk_publish_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_bulk_publish_request(&self, input: BulkPublishRequest) -> SignedRequest {
         let request_uri = format!(
             "/identitypools/{identity_pool_id}/bulkpublish",
             identity_pool_id = input.identity_pool_id
         );
 
         let mut request = SignedRequest::new("POST", "cognito-sync", &self.region, &request_uri);
+        request.set_operation("BulkPublish");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
+        request
+    }
+
+    /// Calls `delete_dataset`, also returning the metadata of the response.
+    pub async fn delete_dataset_with_metadata(
+        &self,
+        input: DeleteDatasetRequest,
+    ) -> Result<(DeleteDatasetResponse, ResponseMetadata), RusotoError<DeleteDatasetError>> {
+        let request = self.build_delete_dataset_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<BulkPublishResponse, _>()?;
+                .deserialize::<DeleteDatasetResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(BulkPublishError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(DeleteDatasetError::from_response(response))
         }
     }
 
-    /// <p>Deletes the specific dataset. The dataset will be deleted permanently, and the action can't be undone. Datasets that this dataset was merged with will no longer report the merge. Any subsequent operation on this dataset will result in a ResourceNotFoundException.</p> <p>This API can be called with temporary user credentials provided by Cognito Identity or with developer credentials.</p>
-    async fn delete_dataset(
+    /// Presigns the request `delete_dataset` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_dataset(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteDatasetRequest,
-    ) -> Result<DeleteDatasetResponse, RusotoError<DeleteDatasetError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_dataset_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_dataset_request(&self, input: DeleteDatasetRequest) -> SignedRequest {
         let request_uri = format!(
             "/identitypools/{identity_pool_id}/identities/{identity_id}/datasets/{dataset_name}",
             dataset_name = input.dataset_name,
@@ -1907,30 +2196,55 @@ impl CognitoSync for CognitoSyncClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "cognito-sync", &self.region, &request_uri);
+        request.set_operation("DeleteDataset");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
+        request
+    }
+
+    /// Calls `describe_dataset`, also returning the metadata of the response.
+    pub async fn describe_dataset_with_metadata(
+        &self,
+        input: DescribeDatasetRequest,
+    ) -> Result<(DescribeDatasetResponse, ResponseMetadata), RusotoError<DescribeDatasetError>>
+    {
+        let request = self.build_describe_dataset_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<DeleteDatasetResponse, _>()?;
+                .deserialize::<DescribeDatasetResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteDatasetError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(DescribeDatasetError::from_response(response))
         }
     }
 
-    /// <p>Gets meta data about a dataset by identity and dataset name. With Amazon Cognito Sync, each identity has access only to its own data. Thus, the credentials used to make this API call need to have access to the identity data.</p> <p>This API can be called with temporary user credentials provided by Cognito Identity or with developer credentials. You should use Cognito Identity credentials to make this API call.</p>
-    async fn describe_dataset(
+    /// Presigns the request `describe_dataset` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_dataset(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeDatasetRequest,
-    ) -> Result<DescribeDatasetResponse, RusotoError<DescribeDatasetError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_dataset_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_dataset_request(&self, input: DescribeDatasetRequest) -> SignedRequest {
         let request_uri = format!(
             "/identitypools/{identity_pool_id}/identities/{identity_id}/datasets/{dataset_name}",
             dataset_name = input.dataset_name,
@@ -1939,61 +2253,120 @@ impl CognitoSync for CognitoSyncClient {
         );
 
         let mut request = SignedRequest::new("GET", "cognito-sync", &self.region, &request_uri);
+        request.set_operation("DescribeDataset");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
+        request
+    }
+
+    /// Calls `describe_identity_pool_usage`, also returning the metadata of the response.
+    pub async fn describe_identity_pool_usage_with_metadata(
+        &self,
+        input: DescribeIdentityPoolUsageRequest,
+    ) -> Result<
+        (DescribeIdentityPoolUsageResponse, ResponseMetadata),
+        RusotoError<DescribeIdentityPoolUsageError>,
+    > {
+        let request = self.build_describe_identity_pool_usage_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<DescribeDatasetResponse, _>()?;
+                .deserialize::<DescribeIdentityPoolUsageResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeDatasetError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(DescribeIdentityPoolUsageError::from_response(response))
         }
     }
 
-    /// <p>Gets usage details (for example, data storage) about a particular identity pool.</p> <p>This API can only be called with developer credentials. You cannot call this API with the temporary user credentials provided by Cognito Identity.</p>
-    async fn describe_identity_pool_usage(
+    /// Presigns the request `describe_identity_pool_usage` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_identity_pool_usage(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeIdentityPoolUsageRequest,
-    ) -> Result<DescribeIdentityPoolUsageResponse, RusotoError<DescribeIdentityPoolUsageError>>
-    {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_identity_pool_usage_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_identity_pool_usage_request(
+        &self,
+        input: DescribeIdentityPoolUsageRequest,
+    ) -> SignedRequest {
         let request_uri = format!(
             "/identitypools/{identity_pool_id}",
             identity_pool_id = input.identity_pool_id
         );
 
         let mut request = SignedRequest::new("GET", "cognito-sync", &self.region, &request_uri);
+        request.set_operation("DescribeIdentityPoolUsage");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
+        request
+    }
+
+    /// Calls `describe_identity_usage`, also returning the metadata of the response.
+    pub async fn describe_identity_usage_with_metadata(
+        &self,
+        input: DescribeIdentityUsageRequest,
+    ) -> Result<
+        (DescribeIdentityUsageResponse, ResponseMetadata),
+        RusotoError<DescribeIdentityUsageError>,
+    > {
+        let request = self.build_describe_identity_usage_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<DescribeIdentityPoolUsageResponse, _>()?;
+                .deserialize::<DescribeIdentityUsageResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeIdentityPoolUsageError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(DescribeIdentityUsageError::from_response(response))
         }
     }
 
-    /// <p>Gets usage information for an identity, including number of datasets and data usage.</p> <p>This API can be called with temporary user credentials provided by Cognito Identity or with developer credentials.</p>
-    async fn describe_identity_usage(
+    /// Presigns the request `describe_identity_usage` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_identity_usage(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeIdentityUsageRequest,
-    ) -> Result<DescribeIdentityUsageResponse, RusotoError<DescribeIdentityUsageError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_identity_usage_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_identity_usage_request(
+        &self,
+        input: DescribeIdentityUsageRequest,
+    ) -> SignedRequest {
         let request_uri = format!(
             "/identitypools/{identity_pool_id}/identities/{identity_id}",
             identity_id = input.identity_id,
@@ -2001,121 +2374,229 @@ impl CognitoSync for CognitoSyncClient {
         );
 
         let mut request = SignedRequest::new("GET", "cognito-sync", &self.region, &request_uri);
+        request.set_operation("DescribeIdentityUsage");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
+        request
+    }
+
+    /// Calls `get_bulk_publish_details`, also returning the metadata of the response.
+    pub async fn get_bulk_publish_details_with_metadata(
+        &self,
+        input: GetBulkPublishDetailsRequest,
+    ) -> Result<
+        (GetBulkPublishDetailsResponse, ResponseMetadata),
+        RusotoError<GetBulkPublishDetailsError>,
+    > {
+        let request = self.build_get_bulk_publish_details_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<DescribeIdentityUsageResponse, _>()?;
+                .deserialize::<GetBulkPublishDetailsResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeIdentityUsageError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(GetBulkPublishDetailsError::from_response(response))
         }
     }
 
-    /// <p>Get the status of the last BulkPublish operation for an identity pool.</p> <p>This API can only be called with developer credentials. You cannot call this API with the temporary user credentials provided by Cognito Identity.</p>
-    async fn get_bulk_publish_details(
+    /// Presigns the request `get_bulk_publish_details` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_bulk_publish_details(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetBulkPublishDetailsRequest,
-    ) -> Result<GetBulkPublishDetailsResponse, RusotoError<GetBulkPublishDetailsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_bulk_publish_details_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_bulk_publish_details_request(
+        &self,
+        input: GetBulkPublishDetailsRequest,
+    ) -> SignedRequest {
         let request_uri = format!(
             "/identitypools/{identity_pool_id}/getBulkPublishDetails",
             identity_pool_id = input.identity_pool_id
         );
 
         let mut request = SignedRequest::new("POST", "cognito-sync", &self.region, &request_uri);
+        request.set_operation("GetBulkPublishDetails");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
+        request
+    }
+
+    /// Calls `get_cognito_events`, also returning the metadata of the response.
+    pub async fn get_cognito_events_with_metadata(
+        &self,
+        input: GetCognitoEventsRequest,
+    ) -> Result<(GetCognitoEventsResponse, ResponseMetadata), RusotoError<GetCognitoEventsError>>
+    {
+        let request = self.build_get_cognito_events_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<GetBulkPublishDetailsResponse, _>()?;
+                .deserialize::<GetCognitoEventsResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(GetBulkPublishDetailsError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(GetCognitoEventsError::from_response(response))
         }
     }
 
-    /// <p>Gets the events and the corresponding Lambda functions associated with an identity pool.</p> <p>This API can only be called with developer credentials. You cannot call this API with the temporary user credentials provided by Cognito Identity.</p>
-    async fn get_cognito_events(
+    /// Presigns the request `get_cognito_events` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_cognito_events(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetCognitoEventsRequest,
-    ) -> Result<GetCognitoEventsResponse, RusotoError<GetCognitoEventsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_cognito_events_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_cognito_events_request(&self, input: GetCognitoEventsRequest) -> SignedRequest {
         let request_uri = format!(
             "/identitypools/{identity_pool_id}/events",
             identity_pool_id = input.identity_pool_id
         );
 
         let mut request = SignedRequest::new("GET", "cognito-sync", &self.region, &request_uri);
+        request.set_operation("GetCognitoEvents");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
+        request
+    }
+
+    /// Calls `get_identity_pool_configuration`, also returning the metadata of the response.
+    pub async fn get_identity_pool_configuration_with_metadata(
+        &self,
+        input: GetIdentityPoolConfigurationRequest,
+    ) -> Result<
+        (GetIdentityPoolConfigurationResponse, ResponseMetadata),
+        RusotoError<GetIdentityPoolConfigurationError>,
+    > {
+        let request = self.build_get_identity_pool_configuration_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<GetCognitoEventsResponse, _>()?;
+                .deserialize::<GetIdentityPoolConfigurationResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(GetCognitoEventsError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(GetIdentityPoolConfigurationError::from_response(response))
         }
     }
 
-    /// <p>Gets the configuration settings of an identity pool.</p> <p>This API can only be called with developer credentials. You cannot call this API with the temporary user credentials provided by Cognito Identity.</p>
-    async fn get_identity_pool_configuration(
+    /// Presigns the request `get_identity_pool_configuration` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_identity_pool_configuration(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetIdentityPoolConfigurationRequest,
-    ) -> Result<GetIdentityPoolConfigurationResponse, RusotoError<GetIdentityPoolConfigurationError>>
-    {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_identity_pool_configuration_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_identity_pool_configuration_request(
+        &self,
+        input: GetIdentityPoolConfigurationRequest,
+    ) -> SignedRequest {
         let request_uri = format!(
             "/identitypools/{identity_pool_id}/configuration",
             identity_pool_id = input.identity_pool_id
         );
 
         let mut request = SignedRequest::new("GET", "cognito-sync", &self.region, &request_uri);
+        request.set_operation("GetIdentityPoolConfiguration");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
+        request
+    }
+
+    /// Calls `list_datasets`, also returning the metadata of the response.
+    pub async fn list_datasets_with_metadata(
+        &self,
+        input: ListDatasetsRequest,
+    ) -> Result<(ListDatasetsResponse, ResponseMetadata), RusotoError<ListDatasetsError>> {
+        let request = self.build_list_datasets_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<GetIdentityPoolConfigurationResponse, _>()?;
+                .deserialize::<ListDatasetsResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(GetIdentityPoolConfigurationError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(ListDatasetsError::from_response(response))
         }
     }
 
-    /// <p>Lists datasets for an identity. With Amazon Cognito Sync, each identity has access only to its own data. Thus, the credentials used to make this API call need to have access to the identity data.</p> <p>ListDatasets can be called with temporary user credentials provided by Cognito Identity or with developer credentials. You should use the Cognito Identity credentials to make this API call.</p>
-    async fn list_datasets(
+    /// Presigns the request `list_datasets` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_datasets(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListDatasetsRequest,
-    ) -> Result<ListDatasetsResponse, RusotoError<ListDatasetsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_datasets_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_datasets_request(&self, input: ListDatasetsRequest) -> SignedRequest {
         let request_uri = format!(
             "/identitypools/{identity_pool_id}/identities/{identity_id}/datasets",
             identity_id = input.identity_id,
@@ -2123,6 +2604,7 @@ impl CognitoSync for CognitoSyncClient {
         );
 
         let mut request = SignedRequest::new("GET", "cognito-sync", &self.region, &request_uri);
+        request.set_operation("ListDatasets");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -2134,31 +2616,61 @@ impl CognitoSync for CognitoSyncClient {
         }
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `list_identity_pool_usage`, also returning the metadata of the response.
+    pub async fn list_identity_pool_usage_with_metadata(
+        &self,
+        input: ListIdentityPoolUsageRequest,
+    ) -> Result<
+        (ListIdentityPoolUsageResponse, ResponseMetadata),
+        RusotoError<ListIdentityPoolUsageError>,
+    > {
+        let request = self.build_list_identity_pool_usage_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListDatasetsResponse, _>()?;
+                .deserialize::<ListIdentityPoolUsageResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(ListDatasetsError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(ListIdentityPoolUsageError::from_response(response))
         }
     }
 
-    /// <p>Gets a list of identity pools registered with Cognito.</p> <p>ListIdentityPoolUsage can only be called with developer credentials. You cannot make this API call with the temporary user credentials provided by Cognito Identity.</p>
-    async fn list_identity_pool_usage(
+    /// Presigns the request `list_identity_pool_usage` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_identity_pool_usage(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListIdentityPoolUsageRequest,
-    ) -> Result<ListIdentityPoolUsageResponse, RusotoError<ListIdentityPoolUsageError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_identity_pool_usage_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_identity_pool_usage_request(
+        &self,
+        input: ListIdentityPoolUsageRequest,
+    ) -> SignedRequest {
         let request_uri = "/identitypools";
 
         let mut request = SignedRequest::new("GET", "cognito-sync", &self.region, &request_uri);
+        request.set_operation("ListIdentityPoolUsage");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -2170,31 +2682,55 @@ impl CognitoSync for CognitoSyncClient {
         }
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `list_records`, also returning the metadata of the response.
+    pub async fn list_records_with_metadata(
+        &self,
+        input: ListRecordsRequest,
+    ) -> Result<(ListRecordsResponse, ResponseMetadata), RusotoError<ListRecordsError>> {
+        let request = self.build_list_records_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListIdentityPoolUsageResponse, _>()?;
+                .deserialize::<ListRecordsResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(ListIdentityPoolUsageError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(ListRecordsError::from_response(response))
         }
     }
 
-    /// <p>Gets paginated records, optionally changed after a particular sync count for a dataset and identity. With Amazon Cognito Sync, each identity has access only to its own data. Thus, the credentials used to make this API call need to have access to the identity data.</p> <p>ListRecords can be called with temporary user credentials provided by Cognito Identity or with developer credentials. You should use Cognito Identity credentials to make this API call.</p>
-    async fn list_records(
+    /// Presigns the request `list_records` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_records(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListRecordsRequest,
-    ) -> Result<ListRecordsResponse, RusotoError<ListRecordsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_records_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_records_request(&self, input: ListRecordsRequest) -> SignedRequest {
         let request_uri = format!("/identitypools/{identity_pool_id}/identities/{identity_id}/datasets/{dataset_name}/records", dataset_name = input.dataset_name, identity_id = input.identity_id, identity_pool_id = input.identity_pool_id);
 
         let mut request = SignedRequest::new("GET", "cognito-sync", &self.region, &request_uri);
+        request.set_operation("ListRecords");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -2212,28 +2748,51 @@ impl CognitoSync for CognitoSyncClient {
         }
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `register_device`, also returning the metadata of the response.
+    pub async fn register_device_with_metadata(
+        &self,
+        input: RegisterDeviceRequest,
+    ) -> Result<(RegisterDeviceResponse, ResponseMetadata), RusotoError<RegisterDeviceError>> {
+        let request = self.build_register_device_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListRecordsResponse, _>()?;
+                .deserialize::<RegisterDeviceResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(ListRecordsError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(RegisterDeviceError::from_response(response))
         }
     }
 
-    /// <p>Registers a device to receive push sync notifications.</p> <p>This API can only be called with temporary credentials provided by Cognito Identity. You cannot call this API with developer credentials.</p>
-    async fn register_device(
+    /// Presigns the request `register_device` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_register_device(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: RegisterDeviceRequest,
-    ) -> Result<RegisterDeviceResponse, RusotoError<RegisterDeviceError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_register_device_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_register_device_request(&self, input: RegisterDeviceRequest) -> SignedRequest {
         let request_uri = format!(
             "/identitypools/{identity_pool_id}/identity/{identity_id}/device",
             identity_id = input.identity_id,
@@ -2241,153 +2800,288 @@ impl CognitoSync for CognitoSyncClient {
         );
 
         let mut request = SignedRequest::new("POST", "cognito-sync", &self.region, &request_uri);
+        request.set_operation("RegisterDevice");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `set_cognito_events`, also returning the metadata of the response.
+    pub async fn set_cognito_events_with_metadata(
+        &self,
+        input: SetCognitoEventsRequest,
+    ) -> Result<((), ResponseMetadata), RusotoError<SetCognitoEventsError>> {
+        let request = self.build_set_cognito_events_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<RegisterDeviceResponse, _>()?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = ::std::mem::drop(response);
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(RegisterDeviceError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(SetCognitoEventsError::from_response(response))
         }
     }
 
-    /// <p>Sets the AWS Lambda function for a given event type for an identity pool. This request only updates the key/value pair specified. Other key/values pairs are not updated. To remove a key value pair, pass a empty value for the particular key.</p> <p>This API can only be called with developer credentials. You cannot call this API with the temporary user credentials provided by Cognito Identity.</p>
-    async fn set_cognito_events(
+    /// Presigns the request `set_cognito_events` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_set_cognito_events(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: SetCognitoEventsRequest,
-    ) -> Result<(), RusotoError<SetCognitoEventsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_set_cognito_events_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_set_cognito_events_request(&self, input: SetCognitoEventsRequest) -> SignedRequest {
         let request_uri = format!(
             "/identitypools/{identity_pool_id}/events",
             identity_pool_id = input.identity_pool_id
         );
 
         let mut request = SignedRequest::new("POST", "cognito-sync", &self.region, &request_uri);
+        request.set_operation("SetCognitoEvents");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `set_identity_pool_configuration`, also returning the metadata of the response.
+    pub async fn set_identity_pool_configuration_with_metadata(
+        &self,
+        input: SetIdentityPoolConfigurationRequest,
+    ) -> Result<
+        (SetIdentityPoolConfigurationResponse, ResponseMetadata),
+        RusotoError<SetIdentityPoolConfigurationError>,
+    > {
+        let request = self.build_set_identity_pool_configuration_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            let result = ::std::mem::drop(response);
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<SetIdentityPoolConfigurationResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(SetCognitoEventsError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(SetIdentityPoolConfigurationError::from_response(response))
         }
     }
 
-    /// <p>Sets the necessary configuration for push sync.</p> <p>This API can only be called with developer credentials. You cannot call this API with the temporary user credentials provided by Cognito Identity.</p>
-    async fn set_identity_pool_configuration(
+    /// Presigns the request `set_identity_pool_configuration` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_set_identity_pool_configuration(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: SetIdentityPoolConfigurationRequest,
-    ) -> Result<SetIdentityPoolConfigurationResponse, RusotoError<SetIdentityPoolConfigurationError>>
-    {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_set_identity_pool_configuration_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_set_identity_pool_configuration_request(
+        &self,
+        input: SetIdentityPoolConfigurationRequest,
+    ) -> SignedRequest {
         let request_uri = format!(
             "/identitypools/{identity_pool_id}/configuration",
             identity_pool_id = input.identity_pool_id
         );
 
         let mut request = SignedRequest::new("POST", "cognito-sync", &self.region, &request_uri);
+        request.set_operation("SetIdentityPoolConfiguration");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `subscribe_to_dataset`, also returning the metadata of the response.
+    pub async fn subscribe_to_dataset_with_metadata(
+        &self,
+        input: SubscribeToDatasetRequest,
+    ) -> Result<(SubscribeToDatasetResponse, ResponseMetadata), RusotoError<SubscribeToDatasetError>>
+    {
+        let request = self.build_subscribe_to_dataset_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<SetIdentityPoolConfigurationResponse, _>()?;
+                .deserialize::<SubscribeToDatasetResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(SetIdentityPoolConfigurationError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(SubscribeToDatasetError::from_response(response))
         }
     }
 
-    /// <p>Subscribes to receive notifications when a dataset is modified by another device.</p> <p>This API can only be called with temporary credentials provided by Cognito Identity. You cannot call this API with developer credentials.</p>
-    async fn subscribe_to_dataset(
+    /// Presigns the request `subscribe_to_dataset` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_subscribe_to_dataset(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: SubscribeToDatasetRequest,
-    ) -> Result<SubscribeToDatasetResponse, RusotoError<SubscribeToDatasetError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_subscribe_to_dataset_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_subscribe_to_dataset_request(
+        &self,
+        input: SubscribeToDatasetRequest,
+    ) -> SignedRequest {
         let request_uri = format!("/identitypools/{identity_pool_id}/identities/{identity_id}/datasets/{dataset_name}/subscriptions/{device_id}", dataset_name = input.dataset_name, device_id = input.device_id, identity_id = input.identity_id, identity_pool_id = input.identity_pool_id);
 
         let mut request = SignedRequest::new("POST", "cognito-sync", &self.region, &request_uri);
+        request.set_operation("SubscribeToDataset");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
+        request
+    }
+
+    /// Calls `unsubscribe_from_dataset`, also returning the metadata of the response.
+    pub async fn unsubscribe_from_dataset_with_metadata(
+        &self,
+        input: UnsubscribeFromDatasetRequest,
+    ) -> Result<
+        (UnsubscribeFromDatasetResponse, ResponseMetadata),
+        RusotoError<UnsubscribeFromDatasetError>,
+    > {
+        let request = self.build_unsubscribe_from_dataset_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<SubscribeToDatasetResponse, _>()?;
+                .deserialize::<UnsubscribeFromDatasetResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(SubscribeToDatasetError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(UnsubscribeFromDatasetError::from_response(response))
         }
     }
 
-    /// <p>Unsubscribes from receiving notifications when a dataset is modified by another device.</p> <p>This API can only be called with temporary credentials provided by Cognito Identity. You cannot call this API with developer credentials.</p>
-    async fn unsubscribe_from_dataset(
+    /// Presigns the request `unsubscribe_from_dataset` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_unsubscribe_from_dataset(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UnsubscribeFromDatasetRequest,
-    ) -> Result<UnsubscribeFromDatasetResponse, RusotoError<UnsubscribeFromDatasetError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_unsubscribe_from_dataset_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_unsubscribe_from_dataset_request(
+        &self,
+        input: UnsubscribeFromDatasetRequest,
+    ) -> SignedRequest {
         let request_uri = format!("/identitypools/{identity_pool_id}/identities/{identity_id}/datasets/{dataset_name}/subscriptions/{device_id}", dataset_name = input.dataset_name, device_id = input.device_id, identity_id = input.identity_id, identity_pool_id = input.identity_pool_id);
 
         let mut request = SignedRequest::new("DELETE", "cognito-sync", &self.region, &request_uri);
+        request.set_operation("UnsubscribeFromDataset");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
+        request
+    }
+
+    /// Calls `update_records`, also returning the metadata of the response.
+    pub async fn update_records_with_metadata(
+        &self,
+        input: UpdateRecordsRequest,
+    ) -> Result<(UpdateRecordsResponse, ResponseMetadata), RusotoError<UpdateRecordsError>> {
+        let request = self.build_update_records_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<UnsubscribeFromDatasetResponse, _>()?;
+                .deserialize::<UpdateRecordsResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(UnsubscribeFromDatasetError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(UpdateRecordsError::from_response(response))
         }
     }
 
-    /// <p>Posts updates to records and adds and deletes records for a dataset and user.</p> <p>The sync count in the record patch is your last known sync count for that record. The server will reject an UpdateRecords request with a ResourceConflictException if you try to patch a record with a new value but a stale sync count.</p> <p>For example, if the sync count on the server is 5 for a key called highScore and you try and submit a new highScore with sync count of 4, the request will be rejected. To obtain the current sync count for a record, call ListRecords. On a successful update of the record, the response returns the new sync count for that record. You should present that sync count the next time you try to update that same record. When the record does not exist, specify the sync count as 0.</p> <p>This API can be called with temporary user credentials provided by Cognito Identity or with developer credentials.</p>
-    async fn update_records(
+    /// Presigns the request `update_records` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_records(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UpdateRecordsRequest,
-    ) -> Result<UpdateRecordsResponse, RusotoError<UpdateRecordsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_records_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_records_request(&self, input: UpdateRecordsRequest) -> SignedRequest {
         let request_uri = format!(
             "/identitypools/{identity_pool_id}/identities/{identity_id}/datasets/{dataset_name}",
             dataset_name = input.dataset_name,
@@ -2396,6 +3090,7 @@ impl CognitoSync for CognitoSyncClient {
         );
 
         let mut request = SignedRequest::new("POST", "cognito-sync", &self.region, &request_uri);
+        request.set_operation("UpdateRecords");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2405,20 +3100,182 @@ impl CognitoSync for CognitoSyncClient {
             request.add_header("x-amz-Client-Context", &client_context.to_string());
         }
 
-        let mut response = self
-            .client
-            .sign_and_dispatch(request)
+        request
+    }
+}
+
+#[async_trait]
+impl CognitoSync for CognitoSyncClient {
+    /// <p>Initiates a bulk publish of all existing datasets for an Identity Pool to the configured stream. Customers are limited to one successful bulk publish per 24 hours. Bulk publish is an asynchronous request, customers can see the status of the request via the GetBulkPublishDetails operation.</p> <p>This API can only be called with developer credentials. You cannot call this API with the temporary user credentials provided by Cognito Identity.</p>
+    async fn bulk_publish(
+        &self,
+        input: BulkPublishRequest,
+    ) -> Result<BulkPublishResponse, RusotoError<BulkPublishError>> {
+        self.bulk_publish_with_metadata(input)
             .await
-            .map_err(RusotoError::from)?;
-        if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<UpdateRecordsResponse, _>()?;
+            .map(|(output, _)| output)
+    }
 
-            Ok(result)
-        } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(UpdateRecordsError::from_response(response))
-        }
+    /// <p>Deletes the specific dataset. The dataset will be deleted permanently, and the action can't be undone. Datasets that this dataset was merged with will no longer report the merge. Any subsequent operation on this dataset will result in a ResourceNotFoundException.</p> <p>This API can be called with temporary user credentials provided by Cognito Identity or with developer credentials.</p>
+    async fn delete_dataset(
+        &self,
+        input: DeleteDatasetRequest,
+    ) -> Result<DeleteDatasetResponse, RusotoError<DeleteDatasetError>> {
+        self.delete_dataset_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Gets meta data about a dataset by identity and dataset name. With Amazon Cognito Sync, each identity has access only to its own data. Thus, the credentials used to make this API call need to have access to the identity data.</p> <p>This API can be called with temporary user credentials provided by Cognito Identity or with developer credentials. You should use Cognito Identity credentials to make this API call.</p>
+    async fn describe_dataset(
+        &self,
+        input: DescribeDatasetRequest,
+    ) -> Result<DescribeDatasetResponse, RusotoError<DescribeDatasetError>> {
+        self.describe_dataset_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Gets usage details (for example, data storage) about a particular identity pool.</p> <p>This API can only be called with developer credentials. You cannot call this API with the temporary user credentials provided by Cognito Identity.</p>
+    async fn describe_identity_pool_usage(
+        &self,
+        input: DescribeIdentityPoolUsageRequest,
+    ) -> Result<DescribeIdentityPoolUsageResponse, RusotoError<DescribeIdentityPoolUsageError>>
+    {
+        self.describe_identity_pool_usage_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Gets usage information for an identity, including number of datasets and data usage.</p> <p>This API can be called with temporary user credentials provided by Cognito Identity or with developer credentials.</p>
+    async fn describe_identity_usage(
+        &self,
+        input: DescribeIdentityUsageRequest,
+    ) -> Result<DescribeIdentityUsageResponse, RusotoError<DescribeIdentityUsageError>> {
+        self.describe_identity_usage_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Get the status of the last BulkPublish operation for an identity pool.</p> <p>This API can only be called with developer credentials. You cannot call this API with the temporary user credentials provided by Cognito Identity.</p>
+    async fn get_bulk_publish_details(
+        &self,
+        input: GetBulkPublishDetailsRequest,
+    ) -> Result<GetBulkPublishDetailsResponse, RusotoError<GetBulkPublishDetailsError>> {
+        self.get_bulk_publish_details_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Gets the events and the corresponding Lambda functions associated with an identity pool.</p> <p>This API can only be called with developer credentials. You cannot call this API with the temporary user credentials provided by Cognito Identity.</p>
+    async fn get_cognito_events(
+        &self,
+        input: GetCognitoEventsRequest,
+    ) -> Result<GetCognitoEventsResponse, RusotoError<GetCognitoEventsError>> {
+        self.get_cognito_events_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Gets the configuration settings of an identity pool.</p> <p>This API can only be called with developer credentials. You cannot call this API with the temporary user credentials provided by Cognito Identity.</p>
+    async fn get_identity_pool_configuration(
+        &self,
+        input: GetIdentityPoolConfigurationRequest,
+    ) -> Result<GetIdentityPoolConfigurationResponse, RusotoError<GetIdentityPoolConfigurationError>>
+    {
+        self.get_identity_pool_configuration_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Lists datasets for an identity. With Amazon Cognito Sync, each identity has access only to its own data. Thus, the credentials used to make this API call need to have access to the identity data.</p> <p>ListDatasets can be called with temporary user credentials provided by Cognito Identity or with developer credentials. You should use the Cognito Identity credentials to make this API call.</p>
+    async fn list_datasets(
+        &self,
+        input: ListDatasetsRequest,
+    ) -> Result<ListDatasetsResponse, RusotoError<ListDatasetsError>> {
+        self.list_datasets_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Gets a list of identity pools registered with Cognito.</p> <p>ListIdentityPoolUsage can only be called with developer credentials. You cannot make this API call with the temporary user credentials provided by Cognito Identity.</p>
+    async fn list_identity_pool_usage(
+        &self,
+        input: ListIdentityPoolUsageRequest,
+    ) -> Result<ListIdentityPoolUsageResponse, RusotoError<ListIdentityPoolUsageError>> {
+        self.list_identity_pool_usage_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Gets paginated records, optionally changed after a particular sync count for a dataset and identity. With Amazon Cognito Sync, each identity has access only to its own data. Thus, the credentials used to make this API call need to have access to the identity data.</p> <p>ListRecords can be called with temporary user credentials provided by Cognito Identity or with developer credentials. You should use Cognito Identity credentials to make this API call.</p>
+    async fn list_records(
+        &self,
+        input: ListRecordsRequest,
+    ) -> Result<ListRecordsResponse, RusotoError<ListRecordsError>> {
+        self.list_records_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Registers a device to receive push sync notifications.</p> <p>This API can only be called with temporary credentials provided by Cognito Identity. You cannot call this API with developer credentials.</p>
+    async fn register_device(
+        &self,
+        input: RegisterDeviceRequest,
+    ) -> Result<RegisterDeviceResponse, RusotoError<RegisterDeviceError>> {
+        self.register_device_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Sets the AWS Lambda function for a given event type for an identity pool. This request only updates the key/value pair specified. Other key/values pairs are not updated. To remove a key value pair, pass a empty value for the particular key.</p> <p>This API can only be called with developer credentials. You cannot call this API with the temporary user credentials provided by Cognito Identity.</p>
+    async fn set_cognito_events(
+        &self,
+        input: SetCognitoEventsRequest,
+    ) -> Result<(), RusotoError<SetCognitoEventsError>> {
+        self.set_cognito_events_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Sets the necessary configuration for push sync.</p> <p>This API can only be called with developer credentials. You cannot call this API with the temporary user credentials provided by Cognito Identity.</p>
+    async fn set_identity_pool_configuration(
+        &self,
+        input: SetIdentityPoolConfigurationRequest,
+    ) -> Result<SetIdentityPoolConfigurationResponse, RusotoError<SetIdentityPoolConfigurationError>>
+    {
+        self.set_identity_pool_configuration_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Subscribes to receive notifications when a dataset is modified by another device.</p> <p>This API can only be called with temporary credentials provided by Cognito Identity. You cannot call this API with developer credentials.</p>
+    async fn subscribe_to_dataset(
+        &self,
+        input: SubscribeToDatasetRequest,
+    ) -> Result<SubscribeToDatasetResponse, RusotoError<SubscribeToDatasetError>> {
+        self.subscribe_to_dataset_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Unsubscribes from receiving notifications when a dataset is modified by another device.</p> <p>This API can only be called with temporary credentials provided by Cognito Identity. You cannot call this API with developer credentials.</p>
+    async fn unsubscribe_from_dataset(
+        &self,
+        input: UnsubscribeFromDatasetRequest,
+    ) -> Result<UnsubscribeFromDatasetResponse, RusotoError<UnsubscribeFromDatasetError>> {
+        self.unsubscribe_from_dataset_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Posts updates to records and adds and deletes records for a dataset and user.</p> <p>The sync count in the record patch is your last known sync count for that record. The server will reject an UpdateRecords request with a ResourceConflictException if you try to patch a record with a new value but a stale sync count.</p> <p>For example, if the sync count on the server is 5 for a key called highScore and you try and submit a new highScore with sync count of 4, the request will be rejected. To obtain the current sync count for a record, call ListRecords. On a successful update of the record, the response returns the new sync count for that record. You should present that sync count the next time you try to update that same record. When the record does not exist, specify the sync count as 0.</p> <p>This API can be called with temporary user credentials provided by Cognito Identity or with developer credentials.</p>
+    async fn update_records(
+        &self,
+        input: UpdateRecordsRequest,
+    ) -> Result<UpdateRecordsResponse, RusotoError<UpdateRecordsError>> {
+        self.update_records_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
     }
 }
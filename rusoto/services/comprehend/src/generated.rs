@@ -16,8 +16,8 @@ use std::fmt;
 use async_trait::async_trait;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
-use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest, ResponseMetadata};
+use rusoto_core::{Client, ErrorMetadata, RusotoError};
 
 use rusoto_core::proto;
 use rusoto_core::signature::SignedRequest;
@@ -2473,6 +2473,7 @@ pub struct VpcConfig {
 
 /// Errors returned by BatchDetectDominantLanguage
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum BatchDetectDominantLanguageError {
     /// <p>The number of documents in the request exceeds the limit of 25. Try your request again with fewer documents.</p>
     BatchSizeLimitExceeded(String),
@@ -2482,6 +2483,8 @@ pub enum BatchDetectDominantLanguageError {
     InvalidRequest(String),
     /// <p>The size of the input text exceeds the limit. Use a smaller document.</p>
     TextSizeLimitExceeded(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl BatchDetectDominantLanguageError {
@@ -2491,27 +2494,36 @@ impl BatchDetectDominantLanguageError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "BatchSizeLimitExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         BatchDetectDominantLanguageError::BatchSizeLimitExceeded(err.msg),
+                        &res,
                     )
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(BatchDetectDominantLanguageError::InternalServer(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        BatchDetectDominantLanguageError::InternalServer(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(BatchDetectDominantLanguageError::InvalidRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        BatchDetectDominantLanguageError::InvalidRequest(err.msg),
+                        &res,
+                    )
                 }
                 "TextSizeLimitExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         BatchDetectDominantLanguageError::TextSizeLimitExceeded(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        BatchDetectDominantLanguageError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -2529,12 +2541,14 @@ impl fmt::Display for BatchDetectDominantLanguageError {
             BatchDetectDominantLanguageError::TextSizeLimitExceeded(ref cause) => {
                 write!(f, "{}", cause)
             }
+            BatchDetectDominantLanguageError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for BatchDetectDominantLanguageError {}
 /// Errors returned by BatchDetectEntities
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum BatchDetectEntitiesError {
     /// <p>The number of documents in the request exceeds the limit of 25. Try your request again with fewer documents.</p>
     BatchSizeLimitExceeded(String),
@@ -2546,6 +2560,8 @@ pub enum BatchDetectEntitiesError {
     TextSizeLimitExceeded(String),
     /// <p>Amazon Comprehend can't process the language of the input text. For all custom entity recognition APIs (such as <code>CreateEntityRecognizer</code>), only English is accepted. For most other APIs, such as those for Custom Classification, Amazon Comprehend accepts text in all supported languages. For a list of supported languages, see <a>supported-languages</a>. </p>
     UnsupportedLanguage(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl BatchDetectEntitiesError {
@@ -2553,28 +2569,37 @@ impl BatchDetectEntitiesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "BatchSizeLimitExceededException" => {
-                    return RusotoError::Service(BatchDetectEntitiesError::BatchSizeLimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        BatchDetectEntitiesError::BatchSizeLimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(BatchDetectEntitiesError::InternalServer(err.msg))
+                    return RusotoError::service(
+                        BatchDetectEntitiesError::InternalServer(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(BatchDetectEntitiesError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        BatchDetectEntitiesError::InvalidRequest(err.msg),
+                        &res,
+                    )
                 }
                 "TextSizeLimitExceededException" => {
-                    return RusotoError::Service(BatchDetectEntitiesError::TextSizeLimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        BatchDetectEntitiesError::TextSizeLimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "UnsupportedLanguageException" => {
-                    return RusotoError::Service(BatchDetectEntitiesError::UnsupportedLanguage(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        BatchDetectEntitiesError::UnsupportedLanguage(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(BatchDetectEntitiesError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2589,12 +2614,14 @@ impl fmt::Display for BatchDetectEntitiesError {
             BatchDetectEntitiesError::InvalidRequest(ref cause) => write!(f, "{}", cause),
             BatchDetectEntitiesError::TextSizeLimitExceeded(ref cause) => write!(f, "{}", cause),
             BatchDetectEntitiesError::UnsupportedLanguage(ref cause) => write!(f, "{}", cause),
+            BatchDetectEntitiesError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for BatchDetectEntitiesError {}
 /// Errors returned by BatchDetectKeyPhrases
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum BatchDetectKeyPhrasesError {
     /// <p>The number of documents in the request exceeds the limit of 25. Try your request again with fewer documents.</p>
     BatchSizeLimitExceeded(String),
@@ -2606,6 +2633,8 @@ pub enum BatchDetectKeyPhrasesError {
     TextSizeLimitExceeded(String),
     /// <p>Amazon Comprehend can't process the language of the input text. For all custom entity recognition APIs (such as <code>CreateEntityRecognizer</code>), only English is accepted. For most other APIs, such as those for Custom Classification, Amazon Comprehend accepts text in all supported languages. For a list of supported languages, see <a>supported-languages</a>. </p>
     UnsupportedLanguage(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl BatchDetectKeyPhrasesError {
@@ -2613,32 +2642,37 @@ impl BatchDetectKeyPhrasesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "BatchSizeLimitExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         BatchDetectKeyPhrasesError::BatchSizeLimitExceeded(err.msg),
+                        &res,
                     )
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(BatchDetectKeyPhrasesError::InternalServer(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        BatchDetectKeyPhrasesError::InternalServer(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(BatchDetectKeyPhrasesError::InvalidRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        BatchDetectKeyPhrasesError::InvalidRequest(err.msg),
+                        &res,
+                    )
                 }
                 "TextSizeLimitExceededException" => {
-                    return RusotoError::Service(BatchDetectKeyPhrasesError::TextSizeLimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        BatchDetectKeyPhrasesError::TextSizeLimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "UnsupportedLanguageException" => {
-                    return RusotoError::Service(BatchDetectKeyPhrasesError::UnsupportedLanguage(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        BatchDetectKeyPhrasesError::UnsupportedLanguage(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(BatchDetectKeyPhrasesError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2653,12 +2687,14 @@ impl fmt::Display for BatchDetectKeyPhrasesError {
             BatchDetectKeyPhrasesError::InvalidRequest(ref cause) => write!(f, "{}", cause),
             BatchDetectKeyPhrasesError::TextSizeLimitExceeded(ref cause) => write!(f, "{}", cause),
             BatchDetectKeyPhrasesError::UnsupportedLanguage(ref cause) => write!(f, "{}", cause),
+            BatchDetectKeyPhrasesError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for BatchDetectKeyPhrasesError {}
 /// Errors returned by BatchDetectSentiment
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum BatchDetectSentimentError {
     /// <p>The number of documents in the request exceeds the limit of 25. Try your request again with fewer documents.</p>
     BatchSizeLimitExceeded(String),
@@ -2670,6 +2706,8 @@ pub enum BatchDetectSentimentError {
     TextSizeLimitExceeded(String),
     /// <p>Amazon Comprehend can't process the language of the input text. For all custom entity recognition APIs (such as <code>CreateEntityRecognizer</code>), only English is accepted. For most other APIs, such as those for Custom Classification, Amazon Comprehend accepts text in all supported languages. For a list of supported languages, see <a>supported-languages</a>. </p>
     UnsupportedLanguage(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl BatchDetectSentimentError {
@@ -2677,28 +2715,37 @@ impl BatchDetectSentimentError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "BatchSizeLimitExceededException" => {
-                    return RusotoError::Service(BatchDetectSentimentError::BatchSizeLimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        BatchDetectSentimentError::BatchSizeLimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(BatchDetectSentimentError::InternalServer(err.msg))
+                    return RusotoError::service(
+                        BatchDetectSentimentError::InternalServer(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(BatchDetectSentimentError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        BatchDetectSentimentError::InvalidRequest(err.msg),
+                        &res,
+                    )
                 }
                 "TextSizeLimitExceededException" => {
-                    return RusotoError::Service(BatchDetectSentimentError::TextSizeLimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        BatchDetectSentimentError::TextSizeLimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "UnsupportedLanguageException" => {
-                    return RusotoError::Service(BatchDetectSentimentError::UnsupportedLanguage(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        BatchDetectSentimentError::UnsupportedLanguage(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(BatchDetectSentimentError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2713,12 +2760,14 @@ impl fmt::Display for BatchDetectSentimentError {
             BatchDetectSentimentError::InvalidRequest(ref cause) => write!(f, "{}", cause),
             BatchDetectSentimentError::TextSizeLimitExceeded(ref cause) => write!(f, "{}", cause),
             BatchDetectSentimentError::UnsupportedLanguage(ref cause) => write!(f, "{}", cause),
+            BatchDetectSentimentError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for BatchDetectSentimentError {}
 /// Errors returned by BatchDetectSyntax
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum BatchDetectSyntaxError {
     /// <p>The number of documents in the request exceeds the limit of 25. Try your request again with fewer documents.</p>
     BatchSizeLimitExceeded(String),
@@ -2730,6 +2779,8 @@ pub enum BatchDetectSyntaxError {
     TextSizeLimitExceeded(String),
     /// <p>Amazon Comprehend can't process the language of the input text. For all custom entity recognition APIs (such as <code>CreateEntityRecognizer</code>), only English is accepted. For most other APIs, such as those for Custom Classification, Amazon Comprehend accepts text in all supported languages. For a list of supported languages, see <a>supported-languages</a>. </p>
     UnsupportedLanguage(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl BatchDetectSyntaxError {
@@ -2737,28 +2788,37 @@ impl BatchDetectSyntaxError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "BatchSizeLimitExceededException" => {
-                    return RusotoError::Service(BatchDetectSyntaxError::BatchSizeLimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        BatchDetectSyntaxError::BatchSizeLimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(BatchDetectSyntaxError::InternalServer(err.msg))
+                    return RusotoError::service(
+                        BatchDetectSyntaxError::InternalServer(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(BatchDetectSyntaxError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        BatchDetectSyntaxError::InvalidRequest(err.msg),
+                        &res,
+                    )
                 }
                 "TextSizeLimitExceededException" => {
-                    return RusotoError::Service(BatchDetectSyntaxError::TextSizeLimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        BatchDetectSyntaxError::TextSizeLimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "UnsupportedLanguageException" => {
-                    return RusotoError::Service(BatchDetectSyntaxError::UnsupportedLanguage(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        BatchDetectSyntaxError::UnsupportedLanguage(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(BatchDetectSyntaxError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2773,12 +2833,14 @@ impl fmt::Display for BatchDetectSyntaxError {
             BatchDetectSyntaxError::InvalidRequest(ref cause) => write!(f, "{}", cause),
             BatchDetectSyntaxError::TextSizeLimitExceeded(ref cause) => write!(f, "{}", cause),
             BatchDetectSyntaxError::UnsupportedLanguage(ref cause) => write!(f, "{}", cause),
+            BatchDetectSyntaxError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for BatchDetectSyntaxError {}
 /// Errors returned by ClassifyDocument
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ClassifyDocumentError {
     /// <p>An internal server error occurred. Retry your request.</p>
     InternalServer(String),
@@ -2788,6 +2850,8 @@ pub enum ClassifyDocumentError {
     ResourceUnavailable(String),
     /// <p>The size of the input text exceeds the limit. Use a smaller document.</p>
     TextSizeLimitExceeded(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ClassifyDocumentError {
@@ -2795,23 +2859,31 @@ impl ClassifyDocumentError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(ClassifyDocumentError::InternalServer(err.msg))
+                    return RusotoError::service(
+                        ClassifyDocumentError::InternalServer(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(ClassifyDocumentError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        ClassifyDocumentError::InvalidRequest(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceUnavailableException" => {
-                    return RusotoError::Service(ClassifyDocumentError::ResourceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ClassifyDocumentError::ResourceUnavailable(err.msg),
+                        &res,
+                    )
                 }
                 "TextSizeLimitExceededException" => {
-                    return RusotoError::Service(ClassifyDocumentError::TextSizeLimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ClassifyDocumentError::TextSizeLimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ClassifyDocumentError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2825,12 +2897,14 @@ impl fmt::Display for ClassifyDocumentError {
             ClassifyDocumentError::InvalidRequest(ref cause) => write!(f, "{}", cause),
             ClassifyDocumentError::ResourceUnavailable(ref cause) => write!(f, "{}", cause),
             ClassifyDocumentError::TextSizeLimitExceeded(ref cause) => write!(f, "{}", cause),
+            ClassifyDocumentError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ClassifyDocumentError {}
 /// Errors returned by CreateDocumentClassifier
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateDocumentClassifierError {
     /// <p>An internal server error occurred. Retry your request.</p>
     InternalServer(String),
@@ -2848,6 +2922,8 @@ pub enum CreateDocumentClassifierError {
     TooManyTags(String),
     /// <p>Amazon Comprehend can't process the language of the input text. For all custom entity recognition APIs (such as <code>CreateEntityRecognizer</code>), only English is accepted. For most other APIs, such as those for Custom Classification, Amazon Comprehend accepts text in all supported languages. For a list of supported languages, see <a>supported-languages</a>. </p>
     UnsupportedLanguage(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateDocumentClassifierError {
@@ -2855,47 +2931,55 @@ impl CreateDocumentClassifierError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(CreateDocumentClassifierError::InternalServer(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateDocumentClassifierError::InternalServer(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(CreateDocumentClassifierError::InvalidRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateDocumentClassifierError::InvalidRequest(err.msg),
+                        &res,
+                    )
                 }
                 "KmsKeyValidationException" => {
-                    return RusotoError::Service(CreateDocumentClassifierError::KmsKeyValidation(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateDocumentClassifierError::KmsKeyValidation(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(CreateDocumentClassifierError::ResourceInUse(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateDocumentClassifierError::ResourceInUse(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceLimitExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         CreateDocumentClassifierError::ResourceLimitExceeded(err.msg),
+                        &res,
                     )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(CreateDocumentClassifierError::TooManyRequests(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateDocumentClassifierError::TooManyRequests(err.msg),
+                        &res,
+                    )
                 }
                 "TooManyTagsException" => {
-                    return RusotoError::Service(CreateDocumentClassifierError::TooManyTags(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateDocumentClassifierError::TooManyTags(err.msg),
+                        &res,
+                    )
                 }
                 "UnsupportedLanguageException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         CreateDocumentClassifierError::UnsupportedLanguage(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreateDocumentClassifierError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2915,12 +2999,14 @@ impl fmt::Display for CreateDocumentClassifierError {
             CreateDocumentClassifierError::TooManyRequests(ref cause) => write!(f, "{}", cause),
             CreateDocumentClassifierError::TooManyTags(ref cause) => write!(f, "{}", cause),
             CreateDocumentClassifierError::UnsupportedLanguage(ref cause) => write!(f, "{}", cause),
+            CreateDocumentClassifierError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateDocumentClassifierError {}
 /// Errors returned by CreateEndpoint
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateEndpointError {
     /// <p>An internal server error occurred. Retry your request.</p>
     InternalServer(String),
@@ -2938,6 +3024,8 @@ pub enum CreateEndpointError {
     TooManyRequests(String),
     /// <p>The request contains more tags than can be associated with a resource (50 tags per resource). The maximum number of tags includes both existing tags and those included in your current request. </p>
     TooManyTags(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateEndpointError {
@@ -2945,33 +3033,43 @@ impl CreateEndpointError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(CreateEndpointError::InternalServer(err.msg))
+                    return RusotoError::service(CreateEndpointError::InternalServer(err.msg), &res)
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(CreateEndpointError::InvalidRequest(err.msg))
+                    return RusotoError::service(CreateEndpointError::InvalidRequest(err.msg), &res)
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(CreateEndpointError::ResourceInUse(err.msg))
+                    return RusotoError::service(CreateEndpointError::ResourceInUse(err.msg), &res)
                 }
                 "ResourceLimitExceededException" => {
-                    return RusotoError::Service(CreateEndpointError::ResourceLimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateEndpointError::ResourceLimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(CreateEndpointError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        CreateEndpointError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceUnavailableException" => {
-                    return RusotoError::Service(CreateEndpointError::ResourceUnavailable(err.msg))
+                    return RusotoError::service(
+                        CreateEndpointError::ResourceUnavailable(err.msg),
+                        &res,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(CreateEndpointError::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        CreateEndpointError::TooManyRequests(err.msg),
+                        &res,
+                    )
                 }
                 "TooManyTagsException" => {
-                    return RusotoError::Service(CreateEndpointError::TooManyTags(err.msg))
+                    return RusotoError::service(CreateEndpointError::TooManyTags(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreateEndpointError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2989,12 +3087,14 @@ impl fmt::Display for CreateEndpointError {
             CreateEndpointError::ResourceUnavailable(ref cause) => write!(f, "{}", cause),
             CreateEndpointError::TooManyRequests(ref cause) => write!(f, "{}", cause),
             CreateEndpointError::TooManyTags(ref cause) => write!(f, "{}", cause),
+            CreateEndpointError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateEndpointError {}
 /// Errors returned by CreateEntityRecognizer
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateEntityRecognizerError {
     /// <p>An internal server error occurred. Retry your request.</p>
     InternalServer(String),
@@ -3012,6 +3112,8 @@ pub enum CreateEntityRecognizerError {
     TooManyTags(String),
     /// <p>Amazon Comprehend can't process the language of the input text. For all custom entity recognition APIs (such as <code>CreateEntityRecognizer</code>), only English is accepted. For most other APIs, such as those for Custom Classification, Amazon Comprehend accepts text in all supported languages. For a list of supported languages, see <a>supported-languages</a>. </p>
     UnsupportedLanguage(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateEntityRecognizerError {
@@ -3019,45 +3121,55 @@ impl CreateEntityRecognizerError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(CreateEntityRecognizerError::InternalServer(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateEntityRecognizerError::InternalServer(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(CreateEntityRecognizerError::InvalidRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateEntityRecognizerError::InvalidRequest(err.msg),
+                        &res,
+                    )
                 }
                 "KmsKeyValidationException" => {
-                    return RusotoError::Service(CreateEntityRecognizerError::KmsKeyValidation(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateEntityRecognizerError::KmsKeyValidation(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(CreateEntityRecognizerError::ResourceInUse(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateEntityRecognizerError::ResourceInUse(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceLimitExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         CreateEntityRecognizerError::ResourceLimitExceeded(err.msg),
+                        &res,
                     )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(CreateEntityRecognizerError::TooManyRequests(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateEntityRecognizerError::TooManyRequests(err.msg),
+                        &res,
+                    )
                 }
                 "TooManyTagsException" => {
-                    return RusotoError::Service(CreateEntityRecognizerError::TooManyTags(err.msg))
+                    return RusotoError::service(
+                        CreateEntityRecognizerError::TooManyTags(err.msg),
+                        &res,
+                    )
                 }
                 "UnsupportedLanguageException" => {
-                    return RusotoError::Service(CreateEntityRecognizerError::UnsupportedLanguage(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateEntityRecognizerError::UnsupportedLanguage(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreateEntityRecognizerError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3075,12 +3187,14 @@ impl fmt::Display for CreateEntityRecognizerError {
             CreateEntityRecognizerError::TooManyRequests(ref cause) => write!(f, "{}", cause),
             CreateEntityRecognizerError::TooManyTags(ref cause) => write!(f, "{}", cause),
             CreateEntityRecognizerError::UnsupportedLanguage(ref cause) => write!(f, "{}", cause),
+            CreateEntityRecognizerError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateEntityRecognizerError {}
 /// Errors returned by DeleteDocumentClassifier
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteDocumentClassifierError {
     /// <p>An internal server error occurred. Retry your request.</p>
     InternalServer(String),
@@ -3094,6 +3208,8 @@ pub enum DeleteDocumentClassifierError {
     ResourceUnavailable(String),
     /// <p>The number of requests exceeds the limit. Resubmit your request later.</p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteDocumentClassifierError {
@@ -3101,37 +3217,43 @@ impl DeleteDocumentClassifierError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(DeleteDocumentClassifierError::InternalServer(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteDocumentClassifierError::InternalServer(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DeleteDocumentClassifierError::InvalidRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteDocumentClassifierError::InvalidRequest(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(DeleteDocumentClassifierError::ResourceInUse(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteDocumentClassifierError::ResourceInUse(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteDocumentClassifierError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteDocumentClassifierError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceUnavailableException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DeleteDocumentClassifierError::ResourceUnavailable(err.msg),
+                        &res,
                     )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(DeleteDocumentClassifierError::TooManyRequests(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteDocumentClassifierError::TooManyRequests(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteDocumentClassifierError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3147,12 +3269,14 @@ impl fmt::Display for DeleteDocumentClassifierError {
             DeleteDocumentClassifierError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             DeleteDocumentClassifierError::ResourceUnavailable(ref cause) => write!(f, "{}", cause),
             DeleteDocumentClassifierError::TooManyRequests(ref cause) => write!(f, "{}", cause),
+            DeleteDocumentClassifierError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteDocumentClassifierError {}
 /// Errors returned by DeleteEndpoint
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteEndpointError {
     /// <p>An internal server error occurred. Retry your request.</p>
     InternalServer(String),
@@ -3164,6 +3288,8 @@ pub enum DeleteEndpointError {
     ResourceNotFound(String),
     /// <p>The number of requests exceeds the limit. Resubmit your request later.</p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteEndpointError {
@@ -3171,22 +3297,28 @@ impl DeleteEndpointError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(DeleteEndpointError::InternalServer(err.msg))
+                    return RusotoError::service(DeleteEndpointError::InternalServer(err.msg), &res)
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DeleteEndpointError::InvalidRequest(err.msg))
+                    return RusotoError::service(DeleteEndpointError::InvalidRequest(err.msg), &res)
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(DeleteEndpointError::ResourceInUse(err.msg))
+                    return RusotoError::service(DeleteEndpointError::ResourceInUse(err.msg), &res)
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteEndpointError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        DeleteEndpointError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(DeleteEndpointError::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        DeleteEndpointError::TooManyRequests(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteEndpointError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3201,12 +3333,14 @@ impl fmt::Display for DeleteEndpointError {
             DeleteEndpointError::ResourceInUse(ref cause) => write!(f, "{}", cause),
             DeleteEndpointError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             DeleteEndpointError::TooManyRequests(ref cause) => write!(f, "{}", cause),
+            DeleteEndpointError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteEndpointError {}
 /// Errors returned by DeleteEntityRecognizer
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteEntityRecognizerError {
     /// <p>An internal server error occurred. Retry your request.</p>
     InternalServer(String),
@@ -3220,6 +3354,8 @@ pub enum DeleteEntityRecognizerError {
     ResourceUnavailable(String),
     /// <p>The number of requests exceeds the limit. Resubmit your request later.</p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteEntityRecognizerError {
@@ -3227,37 +3363,43 @@ impl DeleteEntityRecognizerError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(DeleteEntityRecognizerError::InternalServer(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteEntityRecognizerError::InternalServer(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DeleteEntityRecognizerError::InvalidRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteEntityRecognizerError::InvalidRequest(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(DeleteEntityRecognizerError::ResourceInUse(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteEntityRecognizerError::ResourceInUse(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteEntityRecognizerError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteEntityRecognizerError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceUnavailableException" => {
-                    return RusotoError::Service(DeleteEntityRecognizerError::ResourceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteEntityRecognizerError::ResourceUnavailable(err.msg),
+                        &res,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(DeleteEntityRecognizerError::TooManyRequests(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteEntityRecognizerError::TooManyRequests(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteEntityRecognizerError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3273,12 +3415,14 @@ impl fmt::Display for DeleteEntityRecognizerError {
             DeleteEntityRecognizerError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             DeleteEntityRecognizerError::ResourceUnavailable(ref cause) => write!(f, "{}", cause),
             DeleteEntityRecognizerError::TooManyRequests(ref cause) => write!(f, "{}", cause),
+            DeleteEntityRecognizerError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteEntityRecognizerError {}
 /// Errors returned by DescribeDocumentClassificationJob
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeDocumentClassificationJobError {
     /// <p>An internal server error occurred. Retry your request.</p>
     InternalServer(String),
@@ -3288,6 +3432,8 @@ pub enum DescribeDocumentClassificationJobError {
     JobNotFound(String),
     /// <p>The number of requests exceeds the limit. Resubmit your request later.</p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeDocumentClassificationJobError {
@@ -3297,27 +3443,36 @@ impl DescribeDocumentClassificationJobError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DescribeDocumentClassificationJobError::InternalServer(err.msg),
+                        &res,
                     )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DescribeDocumentClassificationJobError::InvalidRequest(err.msg),
+                        &res,
                     )
                 }
                 "JobNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DescribeDocumentClassificationJobError::JobNotFound(err.msg),
+                        &res,
                     )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DescribeDocumentClassificationJobError::TooManyRequests(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        DescribeDocumentClassificationJobError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -3339,12 +3494,16 @@ impl fmt::Display for DescribeDocumentClassificationJobError {
             DescribeDocumentClassificationJobError::TooManyRequests(ref cause) => {
                 write!(f, "{}", cause)
             }
+            DescribeDocumentClassificationJobError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for DescribeDocumentClassificationJobError {}
 /// Errors returned by DescribeDocumentClassifier
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeDocumentClassifierError {
     /// <p>An internal server error occurred. Retry your request.</p>
     InternalServer(String),
@@ -3354,6 +3513,8 @@ pub enum DescribeDocumentClassifierError {
     ResourceNotFound(String),
     /// <p>The number of requests exceeds the limit. Resubmit your request later.</p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeDocumentClassifierError {
@@ -3363,27 +3524,33 @@ impl DescribeDocumentClassifierError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(DescribeDocumentClassifierError::InternalServer(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeDocumentClassifierError::InternalServer(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DescribeDocumentClassifierError::InvalidRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeDocumentClassifierError::InvalidRequest(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeDocumentClassifierError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeDocumentClassifierError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(DescribeDocumentClassifierError::TooManyRequests(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeDocumentClassifierError::TooManyRequests(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(DescribeDocumentClassifierError::Unhandled, &res)
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -3397,12 +3564,14 @@ impl fmt::Display for DescribeDocumentClassifierError {
             DescribeDocumentClassifierError::InvalidRequest(ref cause) => write!(f, "{}", cause),
             DescribeDocumentClassifierError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             DescribeDocumentClassifierError::TooManyRequests(ref cause) => write!(f, "{}", cause),
+            DescribeDocumentClassifierError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeDocumentClassifierError {}
 /// Errors returned by DescribeDominantLanguageDetectionJob
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeDominantLanguageDetectionJobError {
     /// <p>An internal server error occurred. Retry your request.</p>
     InternalServer(String),
@@ -3412,6 +3581,8 @@ pub enum DescribeDominantLanguageDetectionJobError {
     JobNotFound(String),
     /// <p>The number of requests exceeds the limit. Resubmit your request later.</p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeDominantLanguageDetectionJobError {
@@ -3421,27 +3592,36 @@ impl DescribeDominantLanguageDetectionJobError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DescribeDominantLanguageDetectionJobError::InternalServer(err.msg),
+                        &res,
                     )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DescribeDominantLanguageDetectionJobError::InvalidRequest(err.msg),
+                        &res,
                     )
                 }
                 "JobNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DescribeDominantLanguageDetectionJobError::JobNotFound(err.msg),
+                        &res,
                     )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DescribeDominantLanguageDetectionJobError::TooManyRequests(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        DescribeDominantLanguageDetectionJobError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -3463,12 +3643,16 @@ impl fmt::Display for DescribeDominantLanguageDetectionJobError {
             DescribeDominantLanguageDetectionJobError::TooManyRequests(ref cause) => {
                 write!(f, "{}", cause)
             }
+            DescribeDominantLanguageDetectionJobError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for DescribeDominantLanguageDetectionJobError {}
 /// Errors returned by DescribeEndpoint
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeEndpointError {
     /// <p>An internal server error occurred. Retry your request.</p>
     InternalServer(String),
@@ -3478,6 +3662,8 @@ pub enum DescribeEndpointError {
     ResourceNotFound(String),
     /// <p>The number of requests exceeds the limit. Resubmit your request later.</p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeEndpointError {
@@ -3485,19 +3671,31 @@ impl DescribeEndpointError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(DescribeEndpointError::InternalServer(err.msg))
+                    return RusotoError::service(
+                        DescribeEndpointError::InternalServer(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DescribeEndpointError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        DescribeEndpointError::InvalidRequest(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeEndpointError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        DescribeEndpointError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(DescribeEndpointError::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        DescribeEndpointError::TooManyRequests(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DescribeEndpointError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3511,12 +3709,14 @@ impl fmt::Display for DescribeEndpointError {
             DescribeEndpointError::InvalidRequest(ref cause) => write!(f, "{}", cause),
             DescribeEndpointError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             DescribeEndpointError::TooManyRequests(ref cause) => write!(f, "{}", cause),
+            DescribeEndpointError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeEndpointError {}
 /// Errors returned by DescribeEntitiesDetectionJob
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeEntitiesDetectionJobError {
     /// <p>An internal server error occurred. Retry your request.</p>
     InternalServer(String),
@@ -3526,6 +3726,8 @@ pub enum DescribeEntitiesDetectionJobError {
     JobNotFound(String),
     /// <p>The number of requests exceeds the limit. Resubmit your request later.</p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeEntitiesDetectionJobError {
@@ -3535,27 +3737,36 @@ impl DescribeEntitiesDetectionJobError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(DescribeEntitiesDetectionJobError::InternalServer(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeEntitiesDetectionJobError::InternalServer(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DescribeEntitiesDetectionJobError::InvalidRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeEntitiesDetectionJobError::InvalidRequest(err.msg),
+                        &res,
+                    )
                 }
                 "JobNotFoundException" => {
-                    return RusotoError::Service(DescribeEntitiesDetectionJobError::JobNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeEntitiesDetectionJobError::JobNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DescribeEntitiesDetectionJobError::TooManyRequests(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        DescribeEntitiesDetectionJobError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -3569,12 +3780,14 @@ impl fmt::Display for DescribeEntitiesDetectionJobError {
             DescribeEntitiesDetectionJobError::InvalidRequest(ref cause) => write!(f, "{}", cause),
             DescribeEntitiesDetectionJobError::JobNotFound(ref cause) => write!(f, "{}", cause),
             DescribeEntitiesDetectionJobError::TooManyRequests(ref cause) => write!(f, "{}", cause),
+            DescribeEntitiesDetectionJobError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeEntitiesDetectionJobError {}
 /// Errors returned by DescribeEntityRecognizer
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeEntityRecognizerError {
     /// <p>An internal server error occurred. Retry your request.</p>
     InternalServer(String),
@@ -3584,6 +3797,8 @@ pub enum DescribeEntityRecognizerError {
     ResourceNotFound(String),
     /// <p>The number of requests exceeds the limit. Resubmit your request later.</p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeEntityRecognizerError {
@@ -3591,27 +3806,31 @@ impl DescribeEntityRecognizerError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(DescribeEntityRecognizerError::InternalServer(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeEntityRecognizerError::InternalServer(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DescribeEntityRecognizerError::InvalidRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeEntityRecognizerError::InvalidRequest(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeEntityRecognizerError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeEntityRecognizerError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(DescribeEntityRecognizerError::TooManyRequests(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeEntityRecognizerError::TooManyRequests(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DescribeEntityRecognizerError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3625,12 +3844,14 @@ impl fmt::Display for DescribeEntityRecognizerError {
             DescribeEntityRecognizerError::InvalidRequest(ref cause) => write!(f, "{}", cause),
             DescribeEntityRecognizerError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             DescribeEntityRecognizerError::TooManyRequests(ref cause) => write!(f, "{}", cause),
+            DescribeEntityRecognizerError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeEntityRecognizerError {}
 /// Errors returned by DescribeKeyPhrasesDetectionJob
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeKeyPhrasesDetectionJobError {
     /// <p>An internal server error occurred. Retry your request.</p>
     InternalServer(String),
@@ -3640,6 +3861,8 @@ pub enum DescribeKeyPhrasesDetectionJobError {
     JobNotFound(String),
     /// <p>The number of requests exceeds the limit. Resubmit your request later.</p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeKeyPhrasesDetectionJobError {
@@ -3649,27 +3872,36 @@ impl DescribeKeyPhrasesDetectionJobError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DescribeKeyPhrasesDetectionJobError::InternalServer(err.msg),
+                        &res,
                     )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DescribeKeyPhrasesDetectionJobError::InvalidRequest(err.msg),
+                        &res,
                     )
                 }
                 "JobNotFoundException" => {
-                    return RusotoError::Service(DescribeKeyPhrasesDetectionJobError::JobNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeKeyPhrasesDetectionJobError::JobNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DescribeKeyPhrasesDetectionJobError::TooManyRequests(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        DescribeKeyPhrasesDetectionJobError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -3689,12 +3921,16 @@ impl fmt::Display for DescribeKeyPhrasesDetectionJobError {
             DescribeKeyPhrasesDetectionJobError::TooManyRequests(ref cause) => {
                 write!(f, "{}", cause)
             }
+            DescribeKeyPhrasesDetectionJobError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for DescribeKeyPhrasesDetectionJobError {}
 /// Errors returned by DescribeSentimentDetectionJob
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeSentimentDetectionJobError {
     /// <p>An internal server error occurred. Retry your request.</p>
     InternalServer(String),
@@ -3704,6 +3940,8 @@ pub enum DescribeSentimentDetectionJobError {
     JobNotFound(String),
     /// <p>The number of requests exceeds the limit. Resubmit your request later.</p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeSentimentDetectionJobError {
@@ -3713,27 +3951,36 @@ impl DescribeSentimentDetectionJobError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DescribeSentimentDetectionJobError::InternalServer(err.msg),
+                        &res,
                     )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DescribeSentimentDetectionJobError::InvalidRequest(err.msg),
+                        &res,
                     )
                 }
                 "JobNotFoundException" => {
-                    return RusotoError::Service(DescribeSentimentDetectionJobError::JobNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeSentimentDetectionJobError::JobNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DescribeSentimentDetectionJobError::TooManyRequests(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        DescribeSentimentDetectionJobError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -3749,12 +3996,16 @@ impl fmt::Display for DescribeSentimentDetectionJobError {
             DescribeSentimentDetectionJobError::TooManyRequests(ref cause) => {
                 write!(f, "{}", cause)
             }
+            DescribeSentimentDetectionJobError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for DescribeSentimentDetectionJobError {}
 /// Errors returned by DescribeTopicsDetectionJob
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeTopicsDetectionJobError {
     /// <p>An internal server error occurred. Retry your request.</p>
     InternalServer(String),
@@ -3764,6 +4015,8 @@ pub enum DescribeTopicsDetectionJobError {
     JobNotFound(String),
     /// <p>The number of requests exceeds the limit. Resubmit your request later.</p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeTopicsDetectionJobError {
@@ -3773,27 +4026,33 @@ impl DescribeTopicsDetectionJobError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(DescribeTopicsDetectionJobError::InternalServer(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeTopicsDetectionJobError::InternalServer(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DescribeTopicsDetectionJobError::InvalidRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeTopicsDetectionJobError::InvalidRequest(err.msg),
+                        &res,
+                    )
                 }
                 "JobNotFoundException" => {
-                    return RusotoError::Service(DescribeTopicsDetectionJobError::JobNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeTopicsDetectionJobError::JobNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(DescribeTopicsDetectionJobError::TooManyRequests(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeTopicsDetectionJobError::TooManyRequests(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(DescribeTopicsDetectionJobError::Unhandled, &res)
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -3807,12 +4066,14 @@ impl fmt::Display for DescribeTopicsDetectionJobError {
             DescribeTopicsDetectionJobError::InvalidRequest(ref cause) => write!(f, "{}", cause),
             DescribeTopicsDetectionJobError::JobNotFound(ref cause) => write!(f, "{}", cause),
             DescribeTopicsDetectionJobError::TooManyRequests(ref cause) => write!(f, "{}", cause),
+            DescribeTopicsDetectionJobError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeTopicsDetectionJobError {}
 /// Errors returned by DetectDominantLanguage
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DetectDominantLanguageError {
     /// <p>An internal server error occurred. Retry your request.</p>
     InternalServer(String),
@@ -3820,6 +4081,8 @@ pub enum DetectDominantLanguageError {
     InvalidRequest(String),
     /// <p>The size of the input text exceeds the limit. Use a smaller document.</p>
     TextSizeLimitExceeded(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DetectDominantLanguageError {
@@ -3827,22 +4090,25 @@ impl DetectDominantLanguageError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(DetectDominantLanguageError::InternalServer(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DetectDominantLanguageError::InternalServer(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DetectDominantLanguageError::InvalidRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DetectDominantLanguageError::InvalidRequest(err.msg),
+                        &res,
+                    )
                 }
                 "TextSizeLimitExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DetectDominantLanguageError::TextSizeLimitExceeded(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DetectDominantLanguageError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3855,12 +4121,14 @@ impl fmt::Display for DetectDominantLanguageError {
             DetectDominantLanguageError::InternalServer(ref cause) => write!(f, "{}", cause),
             DetectDominantLanguageError::InvalidRequest(ref cause) => write!(f, "{}", cause),
             DetectDominantLanguageError::TextSizeLimitExceeded(ref cause) => write!(f, "{}", cause),
+            DetectDominantLanguageError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DetectDominantLanguageError {}
 /// Errors returned by DetectEntities
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DetectEntitiesError {
     /// <p>An internal server error occurred. Retry your request.</p>
     InternalServer(String),
@@ -3870,6 +4138,8 @@ pub enum DetectEntitiesError {
     TextSizeLimitExceeded(String),
     /// <p>Amazon Comprehend can't process the language of the input text. For all custom entity recognition APIs (such as <code>CreateEntityRecognizer</code>), only English is accepted. For most other APIs, such as those for Custom Classification, Amazon Comprehend accepts text in all supported languages. For a list of supported languages, see <a>supported-languages</a>. </p>
     UnsupportedLanguage(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DetectEntitiesError {
@@ -3877,21 +4147,25 @@ impl DetectEntitiesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(DetectEntitiesError::InternalServer(err.msg))
+                    return RusotoError::service(DetectEntitiesError::InternalServer(err.msg), &res)
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DetectEntitiesError::InvalidRequest(err.msg))
+                    return RusotoError::service(DetectEntitiesError::InvalidRequest(err.msg), &res)
                 }
                 "TextSizeLimitExceededException" => {
-                    return RusotoError::Service(DetectEntitiesError::TextSizeLimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DetectEntitiesError::TextSizeLimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "UnsupportedLanguageException" => {
-                    return RusotoError::Service(DetectEntitiesError::UnsupportedLanguage(err.msg))
+                    return RusotoError::service(
+                        DetectEntitiesError::UnsupportedLanguage(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DetectEntitiesError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3905,12 +4179,14 @@ impl fmt::Display for DetectEntitiesError {
             DetectEntitiesError::InvalidRequest(ref cause) => write!(f, "{}", cause),
             DetectEntitiesError::TextSizeLimitExceeded(ref cause) => write!(f, "{}", cause),
             DetectEntitiesError::UnsupportedLanguage(ref cause) => write!(f, "{}", cause),
+            DetectEntitiesError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DetectEntitiesError {}
 /// Errors returned by DetectKeyPhrases
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DetectKeyPhrasesError {
     /// <p>An internal server error occurred. Retry your request.</p>
     InternalServer(String),
@@ -3920,6 +4196,8 @@ pub enum DetectKeyPhrasesError {
     TextSizeLimitExceeded(String),
     /// <p>Amazon Comprehend can't process the language of the input text. For all custom entity recognition APIs (such as <code>CreateEntityRecognizer</code>), only English is accepted. For most other APIs, such as those for Custom Classification, Amazon Comprehend accepts text in all supported languages. For a list of supported languages, see <a>supported-languages</a>. </p>
     UnsupportedLanguage(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DetectKeyPhrasesError {
@@ -3927,23 +4205,31 @@ impl DetectKeyPhrasesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(DetectKeyPhrasesError::InternalServer(err.msg))
+                    return RusotoError::service(
+                        DetectKeyPhrasesError::InternalServer(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DetectKeyPhrasesError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        DetectKeyPhrasesError::InvalidRequest(err.msg),
+                        &res,
+                    )
                 }
                 "TextSizeLimitExceededException" => {
-                    return RusotoError::Service(DetectKeyPhrasesError::TextSizeLimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DetectKeyPhrasesError::TextSizeLimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "UnsupportedLanguageException" => {
-                    return RusotoError::Service(DetectKeyPhrasesError::UnsupportedLanguage(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DetectKeyPhrasesError::UnsupportedLanguage(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DetectKeyPhrasesError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3957,12 +4243,14 @@ impl fmt::Display for DetectKeyPhrasesError {
             DetectKeyPhrasesError::InvalidRequest(ref cause) => write!(f, "{}", cause),
             DetectKeyPhrasesError::TextSizeLimitExceeded(ref cause) => write!(f, "{}", cause),
             DetectKeyPhrasesError::UnsupportedLanguage(ref cause) => write!(f, "{}", cause),
+            DetectKeyPhrasesError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DetectKeyPhrasesError {}
 /// Errors returned by DetectSentiment
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DetectSentimentError {
     /// <p>An internal server error occurred. Retry your request.</p>
     InternalServer(String),
@@ -3972,6 +4260,8 @@ pub enum DetectSentimentError {
     TextSizeLimitExceeded(String),
     /// <p>Amazon Comprehend can't process the language of the input text. For all custom entity recognition APIs (such as <code>CreateEntityRecognizer</code>), only English is accepted. For most other APIs, such as those for Custom Classification, Amazon Comprehend accepts text in all supported languages. For a list of supported languages, see <a>supported-languages</a>. </p>
     UnsupportedLanguage(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DetectSentimentError {
@@ -3979,21 +4269,31 @@ impl DetectSentimentError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(DetectSentimentError::InternalServer(err.msg))
+                    return RusotoError::service(
+                        DetectSentimentError::InternalServer(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DetectSentimentError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        DetectSentimentError::InvalidRequest(err.msg),
+                        &res,
+                    )
                 }
                 "TextSizeLimitExceededException" => {
-                    return RusotoError::Service(DetectSentimentError::TextSizeLimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DetectSentimentError::TextSizeLimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "UnsupportedLanguageException" => {
-                    return RusotoError::Service(DetectSentimentError::UnsupportedLanguage(err.msg))
+                    return RusotoError::service(
+                        DetectSentimentError::UnsupportedLanguage(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DetectSentimentError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -4007,12 +4307,14 @@ impl fmt::Display for DetectSentimentError {
             DetectSentimentError::InvalidRequest(ref cause) => write!(f, "{}", cause),
             DetectSentimentError::TextSizeLimitExceeded(ref cause) => write!(f, "{}", cause),
             DetectSentimentError::UnsupportedLanguage(ref cause) => write!(f, "{}", cause),
+            DetectSentimentError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DetectSentimentError {}
 /// Errors returned by DetectSyntax
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DetectSyntaxError {
     /// <p>An internal server error occurred. Retry your request.</p>
     InternalServer(String),
@@ -4022,6 +4324,8 @@ pub enum DetectSyntaxError {
     TextSizeLimitExceeded(String),
     /// <p>Amazon Comprehend can't process the language of the input text. For all custom entity recognition APIs (such as <code>CreateEntityRecognizer</code>), only English is accepted. For most other APIs, such as those for Custom Classification, Amazon Comprehend accepts text in all supported languages. For a list of supported languages, see <a>supported-languages</a>. </p>
     UnsupportedLanguage(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DetectSyntaxError {
@@ -4029,19 +4333,25 @@ impl DetectSyntaxError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(DetectSyntaxError::InternalServer(err.msg))
+                    return RusotoError::service(DetectSyntaxError::InternalServer(err.msg), &res)
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DetectSyntaxError::InvalidRequest(err.msg))
+                    return RusotoError::service(DetectSyntaxError::InvalidRequest(err.msg), &res)
                 }
                 "TextSizeLimitExceededException" => {
-                    return RusotoError::Service(DetectSyntaxError::TextSizeLimitExceeded(err.msg))
+                    return RusotoError::service(
+                        DetectSyntaxError::TextSizeLimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "UnsupportedLanguageException" => {
-                    return RusotoError::Service(DetectSyntaxError::UnsupportedLanguage(err.msg))
+                    return RusotoError::service(
+                        DetectSyntaxError::UnsupportedLanguage(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DetectSyntaxError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -4055,12 +4365,14 @@ impl fmt::Display for DetectSyntaxError {
             DetectSyntaxError::InvalidRequest(ref cause) => write!(f, "{}", cause),
             DetectSyntaxError::TextSizeLimitExceeded(ref cause) => write!(f, "{}", cause),
             DetectSyntaxError::UnsupportedLanguage(ref cause) => write!(f, "{}", cause),
+            DetectSyntaxError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DetectSyntaxError {}
 /// Errors returned by ListDocumentClassificationJobs
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListDocumentClassificationJobsError {
     /// <p>An internal server error occurred. Retry your request.</p>
     InternalServer(String),
@@ -4070,6 +4382,8 @@ pub enum ListDocumentClassificationJobsError {
     InvalidRequest(String),
     /// <p>The number of requests exceeds the limit. Resubmit your request later.</p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListDocumentClassificationJobsError {
@@ -4079,27 +4393,36 @@ impl ListDocumentClassificationJobsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         ListDocumentClassificationJobsError::InternalServer(err.msg),
+                        &res,
                     )
                 }
                 "InvalidFilterException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         ListDocumentClassificationJobsError::InvalidFilter(err.msg),
+                        &res,
                     )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         ListDocumentClassificationJobsError::InvalidRequest(err.msg),
+                        &res,
                     )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         ListDocumentClassificationJobsError::TooManyRequests(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        ListDocumentClassificationJobsError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -4119,12 +4442,16 @@ impl fmt::Display for ListDocumentClassificationJobsError {
             ListDocumentClassificationJobsError::TooManyRequests(ref cause) => {
                 write!(f, "{}", cause)
             }
+            ListDocumentClassificationJobsError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for ListDocumentClassificationJobsError {}
 /// Errors returned by ListDocumentClassifiers
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListDocumentClassifiersError {
     /// <p>An internal server error occurred. Retry your request.</p>
     InternalServer(String),
@@ -4134,6 +4461,8 @@ pub enum ListDocumentClassifiersError {
     InvalidRequest(String),
     /// <p>The number of requests exceeds the limit. Resubmit your request later.</p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListDocumentClassifiersError {
@@ -4141,27 +4470,31 @@ impl ListDocumentClassifiersError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(ListDocumentClassifiersError::InternalServer(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListDocumentClassifiersError::InternalServer(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidFilterException" => {
-                    return RusotoError::Service(ListDocumentClassifiersError::InvalidFilter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListDocumentClassifiersError::InvalidFilter(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(ListDocumentClassifiersError::InvalidRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListDocumentClassifiersError::InvalidRequest(err.msg),
+                        &res,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(ListDocumentClassifiersError::TooManyRequests(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListDocumentClassifiersError::TooManyRequests(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListDocumentClassifiersError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -4175,12 +4508,14 @@ impl fmt::Display for ListDocumentClassifiersError {
             ListDocumentClassifiersError::InvalidFilter(ref cause) => write!(f, "{}", cause),
             ListDocumentClassifiersError::InvalidRequest(ref cause) => write!(f, "{}", cause),
             ListDocumentClassifiersError::TooManyRequests(ref cause) => write!(f, "{}", cause),
+            ListDocumentClassifiersError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListDocumentClassifiersError {}
 /// Errors returned by ListDominantLanguageDetectionJobs
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListDominantLanguageDetectionJobsError {
     /// <p>An internal server error occurred. Retry your request.</p>
     InternalServer(String),
@@ -4190,6 +4525,8 @@ pub enum ListDominantLanguageDetectionJobsError {
     InvalidRequest(String),
     /// <p>The number of requests exceeds the limit. Resubmit your request later.</p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListDominantLanguageDetectionJobsError {
@@ -4199,27 +4536,36 @@ impl ListDominantLanguageDetectionJobsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         ListDominantLanguageDetectionJobsError::InternalServer(err.msg),
+                        &res,
                     )
                 }
                 "InvalidFilterException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         ListDominantLanguageDetectionJobsError::InvalidFilter(err.msg),
+                        &res,
                     )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         ListDominantLanguageDetectionJobsError::InvalidRequest(err.msg),
+                        &res,
                     )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         ListDominantLanguageDetectionJobsError::TooManyRequests(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        ListDominantLanguageDetectionJobsError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -4241,12 +4587,16 @@ impl fmt::Display for ListDominantLanguageDetectionJobsError {
             ListDominantLanguageDetectionJobsError::TooManyRequests(ref cause) => {
                 write!(f, "{}", cause)
             }
+            ListDominantLanguageDetectionJobsError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for ListDominantLanguageDetectionJobsError {}
 /// Errors returned by ListEndpoints
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListEndpointsError {
     /// <p>An internal server error occurred. Retry your request.</p>
     InternalServer(String),
@@ -4254,6 +4604,8 @@ pub enum ListEndpointsError {
     InvalidRequest(String),
     /// <p>The number of requests exceeds the limit. Resubmit your request later.</p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListEndpointsError {
@@ -4261,16 +4613,16 @@ impl ListEndpointsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(ListEndpointsError::InternalServer(err.msg))
+                    return RusotoError::service(ListEndpointsError::InternalServer(err.msg), &res)
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(ListEndpointsError::InvalidRequest(err.msg))
+                    return RusotoError::service(ListEndpointsError::InvalidRequest(err.msg), &res)
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(ListEndpointsError::TooManyRequests(err.msg))
+                    return RusotoError::service(ListEndpointsError::TooManyRequests(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListEndpointsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -4283,12 +4635,14 @@ impl fmt::Display for ListEndpointsError {
             ListEndpointsError::InternalServer(ref cause) => write!(f, "{}", cause),
             ListEndpointsError::InvalidRequest(ref cause) => write!(f, "{}", cause),
             ListEndpointsError::TooManyRequests(ref cause) => write!(f, "{}", cause),
+            ListEndpointsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListEndpointsError {}
 /// Errors returned by ListEntitiesDetectionJobs
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListEntitiesDetectionJobsError {
     /// <p>An internal server error occurred. Retry your request.</p>
     InternalServer(String),
@@ -4298,6 +4652,8 @@ pub enum ListEntitiesDetectionJobsError {
     InvalidRequest(String),
     /// <p>The number of requests exceeds the limit. Resubmit your request later.</p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListEntitiesDetectionJobsError {
@@ -4305,27 +4661,33 @@ impl ListEntitiesDetectionJobsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(ListEntitiesDetectionJobsError::InternalServer(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListEntitiesDetectionJobsError::InternalServer(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidFilterException" => {
-                    return RusotoError::Service(ListEntitiesDetectionJobsError::InvalidFilter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListEntitiesDetectionJobsError::InvalidFilter(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(ListEntitiesDetectionJobsError::InvalidRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListEntitiesDetectionJobsError::InvalidRequest(err.msg),
+                        &res,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(ListEntitiesDetectionJobsError::TooManyRequests(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListEntitiesDetectionJobsError::TooManyRequests(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(ListEntitiesDetectionJobsError::Unhandled, &res)
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -4339,12 +4701,14 @@ impl fmt::Display for ListEntitiesDetectionJobsError {
             ListEntitiesDetectionJobsError::InvalidFilter(ref cause) => write!(f, "{}", cause),
             ListEntitiesDetectionJobsError::InvalidRequest(ref cause) => write!(f, "{}", cause),
             ListEntitiesDetectionJobsError::TooManyRequests(ref cause) => write!(f, "{}", cause),
+            ListEntitiesDetectionJobsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListEntitiesDetectionJobsError {}
 /// Errors returned by ListEntityRecognizers
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListEntityRecognizersError {
     /// <p>An internal server error occurred. Retry your request.</p>
     InternalServer(String),
@@ -4354,6 +4718,8 @@ pub enum ListEntityRecognizersError {
     InvalidRequest(String),
     /// <p>The number of requests exceeds the limit. Resubmit your request later.</p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListEntityRecognizersError {
@@ -4361,25 +4727,31 @@ impl ListEntityRecognizersError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(ListEntityRecognizersError::InternalServer(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListEntityRecognizersError::InternalServer(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidFilterException" => {
-                    return RusotoError::Service(ListEntityRecognizersError::InvalidFilter(err.msg))
+                    return RusotoError::service(
+                        ListEntityRecognizersError::InvalidFilter(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(ListEntityRecognizersError::InvalidRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListEntityRecognizersError::InvalidRequest(err.msg),
+                        &res,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(ListEntityRecognizersError::TooManyRequests(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListEntityRecognizersError::TooManyRequests(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListEntityRecognizersError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -4393,12 +4765,14 @@ impl fmt::Display for ListEntityRecognizersError {
             ListEntityRecognizersError::InvalidFilter(ref cause) => write!(f, "{}", cause),
             ListEntityRecognizersError::InvalidRequest(ref cause) => write!(f, "{}", cause),
             ListEntityRecognizersError::TooManyRequests(ref cause) => write!(f, "{}", cause),
+            ListEntityRecognizersError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListEntityRecognizersError {}
 /// Errors returned by ListKeyPhrasesDetectionJobs
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListKeyPhrasesDetectionJobsError {
     /// <p>An internal server error occurred. Retry your request.</p>
     InternalServer(String),
@@ -4408,6 +4782,8 @@ pub enum ListKeyPhrasesDetectionJobsError {
     InvalidRequest(String),
     /// <p>The number of requests exceeds the limit. Resubmit your request later.</p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListKeyPhrasesDetectionJobsError {
@@ -4417,27 +4793,36 @@ impl ListKeyPhrasesDetectionJobsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(ListKeyPhrasesDetectionJobsError::InternalServer(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListKeyPhrasesDetectionJobsError::InternalServer(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidFilterException" => {
-                    return RusotoError::Service(ListKeyPhrasesDetectionJobsError::InvalidFilter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListKeyPhrasesDetectionJobsError::InvalidFilter(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(ListKeyPhrasesDetectionJobsError::InvalidRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListKeyPhrasesDetectionJobsError::InvalidRequest(err.msg),
+                        &res,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(ListKeyPhrasesDetectionJobsError::TooManyRequests(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListKeyPhrasesDetectionJobsError::TooManyRequests(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        ListKeyPhrasesDetectionJobsError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -4451,12 +4836,14 @@ impl fmt::Display for ListKeyPhrasesDetectionJobsError {
             ListKeyPhrasesDetectionJobsError::InvalidFilter(ref cause) => write!(f, "{}", cause),
             ListKeyPhrasesDetectionJobsError::InvalidRequest(ref cause) => write!(f, "{}", cause),
             ListKeyPhrasesDetectionJobsError::TooManyRequests(ref cause) => write!(f, "{}", cause),
+            ListKeyPhrasesDetectionJobsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListKeyPhrasesDetectionJobsError {}
 /// Errors returned by ListSentimentDetectionJobs
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListSentimentDetectionJobsError {
     /// <p>An internal server error occurred. Retry your request.</p>
     InternalServer(String),
@@ -4466,6 +4853,8 @@ pub enum ListSentimentDetectionJobsError {
     InvalidRequest(String),
     /// <p>The number of requests exceeds the limit. Resubmit your request later.</p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListSentimentDetectionJobsError {
@@ -4475,27 +4864,33 @@ impl ListSentimentDetectionJobsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(ListSentimentDetectionJobsError::InternalServer(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListSentimentDetectionJobsError::InternalServer(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidFilterException" => {
-                    return RusotoError::Service(ListSentimentDetectionJobsError::InvalidFilter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListSentimentDetectionJobsError::InvalidFilter(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(ListSentimentDetectionJobsError::InvalidRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListSentimentDetectionJobsError::InvalidRequest(err.msg),
+                        &res,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(ListSentimentDetectionJobsError::TooManyRequests(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListSentimentDetectionJobsError::TooManyRequests(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(ListSentimentDetectionJobsError::Unhandled, &res)
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -4509,12 +4904,14 @@ impl fmt::Display for ListSentimentDetectionJobsError {
             ListSentimentDetectionJobsError::InvalidFilter(ref cause) => write!(f, "{}", cause),
             ListSentimentDetectionJobsError::InvalidRequest(ref cause) => write!(f, "{}", cause),
             ListSentimentDetectionJobsError::TooManyRequests(ref cause) => write!(f, "{}", cause),
+            ListSentimentDetectionJobsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListSentimentDetectionJobsError {}
 /// Errors returned by ListTagsForResource
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListTagsForResourceError {
     /// <p>An internal server error occurred. Retry your request.</p>
     InternalServer(String),
@@ -4522,6 +4919,8 @@ pub enum ListTagsForResourceError {
     InvalidRequest(String),
     /// <p>The specified resource ARN was not found. Check the ARN and try your request again.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListTagsForResourceError {
@@ -4529,18 +4928,25 @@ impl ListTagsForResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(ListTagsForResourceError::InternalServer(err.msg))
+                    return RusotoError::service(
+                        ListTagsForResourceError::InternalServer(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(ListTagsForResourceError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        ListTagsForResourceError::InvalidRequest(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListTagsForResourceError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListTagsForResourceError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListTagsForResourceError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -4553,12 +4959,14 @@ impl fmt::Display for ListTagsForResourceError {
             ListTagsForResourceError::InternalServer(ref cause) => write!(f, "{}", cause),
             ListTagsForResourceError::InvalidRequest(ref cause) => write!(f, "{}", cause),
             ListTagsForResourceError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            ListTagsForResourceError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListTagsForResourceError {}
 /// Errors returned by ListTopicsDetectionJobs
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListTopicsDetectionJobsError {
     /// <p>An internal server error occurred. Retry your request.</p>
     InternalServer(String),
@@ -4568,6 +4976,8 @@ pub enum ListTopicsDetectionJobsError {
     InvalidRequest(String),
     /// <p>The number of requests exceeds the limit. Resubmit your request later.</p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListTopicsDetectionJobsError {
@@ -4575,27 +4985,31 @@ impl ListTopicsDetectionJobsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(ListTopicsDetectionJobsError::InternalServer(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListTopicsDetectionJobsError::InternalServer(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidFilterException" => {
-                    return RusotoError::Service(ListTopicsDetectionJobsError::InvalidFilter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListTopicsDetectionJobsError::InvalidFilter(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(ListTopicsDetectionJobsError::InvalidRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListTopicsDetectionJobsError::InvalidRequest(err.msg),
+                        &res,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(ListTopicsDetectionJobsError::TooManyRequests(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListTopicsDetectionJobsError::TooManyRequests(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListTopicsDetectionJobsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -4609,12 +5023,14 @@ impl fmt::Display for ListTopicsDetectionJobsError {
             ListTopicsDetectionJobsError::InvalidFilter(ref cause) => write!(f, "{}", cause),
             ListTopicsDetectionJobsError::InvalidRequest(ref cause) => write!(f, "{}", cause),
             ListTopicsDetectionJobsError::TooManyRequests(ref cause) => write!(f, "{}", cause),
+            ListTopicsDetectionJobsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListTopicsDetectionJobsError {}
 /// Errors returned by StartDocumentClassificationJob
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum StartDocumentClassificationJobError {
     /// <p>An internal server error occurred. Retry your request.</p>
     InternalServer(String),
@@ -4628,6 +5044,8 @@ pub enum StartDocumentClassificationJobError {
     ResourceUnavailable(String),
     /// <p>The number of requests exceeds the limit. Resubmit your request later.</p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl StartDocumentClassificationJobError {
@@ -4637,37 +5055,48 @@ impl StartDocumentClassificationJobError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         StartDocumentClassificationJobError::InternalServer(err.msg),
+                        &res,
                     )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         StartDocumentClassificationJobError::InvalidRequest(err.msg),
+                        &res,
                     )
                 }
                 "KmsKeyValidationException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         StartDocumentClassificationJobError::KmsKeyValidation(err.msg),
+                        &res,
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         StartDocumentClassificationJobError::ResourceNotFound(err.msg),
+                        &res,
                     )
                 }
                 "ResourceUnavailableException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         StartDocumentClassificationJobError::ResourceUnavailable(err.msg),
+                        &res,
                     )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         StartDocumentClassificationJobError::TooManyRequests(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        StartDocumentClassificationJobError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -4695,12 +5124,16 @@ impl fmt::Display for StartDocumentClassificationJobError {
             StartDocumentClassificationJobError::TooManyRequests(ref cause) => {
                 write!(f, "{}", cause)
             }
+            StartDocumentClassificationJobError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for StartDocumentClassificationJobError {}
 /// Errors returned by StartDominantLanguageDetectionJob
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum StartDominantLanguageDetectionJobError {
     /// <p>An internal server error occurred. Retry your request.</p>
     InternalServer(String),
@@ -4710,6 +5143,8 @@ pub enum StartDominantLanguageDetectionJobError {
     KmsKeyValidation(String),
     /// <p>The number of requests exceeds the limit. Resubmit your request later.</p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl StartDominantLanguageDetectionJobError {
@@ -4719,27 +5154,36 @@ impl StartDominantLanguageDetectionJobError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         StartDominantLanguageDetectionJobError::InternalServer(err.msg),
+                        &res,
                     )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         StartDominantLanguageDetectionJobError::InvalidRequest(err.msg),
+                        &res,
                     )
                 }
                 "KmsKeyValidationException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         StartDominantLanguageDetectionJobError::KmsKeyValidation(err.msg),
+                        &res,
                     )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         StartDominantLanguageDetectionJobError::TooManyRequests(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        StartDominantLanguageDetectionJobError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -4761,12 +5205,16 @@ impl fmt::Display for StartDominantLanguageDetectionJobError {
             StartDominantLanguageDetectionJobError::TooManyRequests(ref cause) => {
                 write!(f, "{}", cause)
             }
+            StartDominantLanguageDetectionJobError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for StartDominantLanguageDetectionJobError {}
 /// Errors returned by StartEntitiesDetectionJob
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum StartEntitiesDetectionJobError {
     /// <p>An internal server error occurred. Retry your request.</p>
     InternalServer(String),
@@ -4780,6 +5228,8 @@ pub enum StartEntitiesDetectionJobError {
     ResourceUnavailable(String),
     /// <p>The number of requests exceeds the limit. Resubmit your request later.</p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl StartEntitiesDetectionJobError {
@@ -4787,37 +5237,45 @@ impl StartEntitiesDetectionJobError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(StartEntitiesDetectionJobError::InternalServer(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        StartEntitiesDetectionJobError::InternalServer(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(StartEntitiesDetectionJobError::InvalidRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        StartEntitiesDetectionJobError::InvalidRequest(err.msg),
+                        &res,
+                    )
                 }
                 "KmsKeyValidationException" => {
-                    return RusotoError::Service(StartEntitiesDetectionJobError::KmsKeyValidation(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        StartEntitiesDetectionJobError::KmsKeyValidation(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(StartEntitiesDetectionJobError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        StartEntitiesDetectionJobError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceUnavailableException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         StartEntitiesDetectionJobError::ResourceUnavailable(err.msg),
+                        &res,
                     )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(StartEntitiesDetectionJobError::TooManyRequests(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        StartEntitiesDetectionJobError::TooManyRequests(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(StartEntitiesDetectionJobError::Unhandled, &res)
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -4835,12 +5293,14 @@ impl fmt::Display for StartEntitiesDetectionJobError {
                 write!(f, "{}", cause)
             }
             StartEntitiesDetectionJobError::TooManyRequests(ref cause) => write!(f, "{}", cause),
+            StartEntitiesDetectionJobError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for StartEntitiesDetectionJobError {}
 /// Errors returned by StartKeyPhrasesDetectionJob
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum StartKeyPhrasesDetectionJobError {
     /// <p>An internal server error occurred. Retry your request.</p>
     InternalServer(String),
@@ -4850,6 +5310,8 @@ pub enum StartKeyPhrasesDetectionJobError {
     KmsKeyValidation(String),
     /// <p>The number of requests exceeds the limit. Resubmit your request later.</p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl StartKeyPhrasesDetectionJobError {
@@ -4859,27 +5321,36 @@ impl StartKeyPhrasesDetectionJobError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(StartKeyPhrasesDetectionJobError::InternalServer(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        StartKeyPhrasesDetectionJobError::InternalServer(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(StartKeyPhrasesDetectionJobError::InvalidRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        StartKeyPhrasesDetectionJobError::InvalidRequest(err.msg),
+                        &res,
+                    )
                 }
                 "KmsKeyValidationException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         StartKeyPhrasesDetectionJobError::KmsKeyValidation(err.msg),
+                        &res,
                     )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(StartKeyPhrasesDetectionJobError::TooManyRequests(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        StartKeyPhrasesDetectionJobError::TooManyRequests(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        StartKeyPhrasesDetectionJobError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -4893,12 +5364,14 @@ impl fmt::Display for StartKeyPhrasesDetectionJobError {
             StartKeyPhrasesDetectionJobError::InvalidRequest(ref cause) => write!(f, "{}", cause),
             StartKeyPhrasesDetectionJobError::KmsKeyValidation(ref cause) => write!(f, "{}", cause),
             StartKeyPhrasesDetectionJobError::TooManyRequests(ref cause) => write!(f, "{}", cause),
+            StartKeyPhrasesDetectionJobError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for StartKeyPhrasesDetectionJobError {}
 /// Errors returned by StartSentimentDetectionJob
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum StartSentimentDetectionJobError {
     /// <p>An internal server error occurred. Retry your request.</p>
     InternalServer(String),
@@ -4908,6 +5381,8 @@ pub enum StartSentimentDetectionJobError {
     KmsKeyValidation(String),
     /// <p>The number of requests exceeds the limit. Resubmit your request later.</p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl StartSentimentDetectionJobError {
@@ -4917,27 +5392,33 @@ impl StartSentimentDetectionJobError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(StartSentimentDetectionJobError::InternalServer(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        StartSentimentDetectionJobError::InternalServer(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(StartSentimentDetectionJobError::InvalidRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        StartSentimentDetectionJobError::InvalidRequest(err.msg),
+                        &res,
+                    )
                 }
                 "KmsKeyValidationException" => {
-                    return RusotoError::Service(StartSentimentDetectionJobError::KmsKeyValidation(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        StartSentimentDetectionJobError::KmsKeyValidation(err.msg),
+                        &res,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(StartSentimentDetectionJobError::TooManyRequests(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        StartSentimentDetectionJobError::TooManyRequests(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(StartSentimentDetectionJobError::Unhandled, &res)
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -4951,12 +5432,14 @@ impl fmt::Display for StartSentimentDetectionJobError {
             StartSentimentDetectionJobError::InvalidRequest(ref cause) => write!(f, "{}", cause),
             StartSentimentDetectionJobError::KmsKeyValidation(ref cause) => write!(f, "{}", cause),
             StartSentimentDetectionJobError::TooManyRequests(ref cause) => write!(f, "{}", cause),
+            StartSentimentDetectionJobError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for StartSentimentDetectionJobError {}
 /// Errors returned by StartTopicsDetectionJob
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum StartTopicsDetectionJobError {
     /// <p>An internal server error occurred. Retry your request.</p>
     InternalServer(String),
@@ -4966,6 +5449,8 @@ pub enum StartTopicsDetectionJobError {
     KmsKeyValidation(String),
     /// <p>The number of requests exceeds the limit. Resubmit your request later.</p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl StartTopicsDetectionJobError {
@@ -4973,27 +5458,31 @@ impl StartTopicsDetectionJobError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(StartTopicsDetectionJobError::InternalServer(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        StartTopicsDetectionJobError::InternalServer(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(StartTopicsDetectionJobError::InvalidRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        StartTopicsDetectionJobError::InvalidRequest(err.msg),
+                        &res,
+                    )
                 }
                 "KmsKeyValidationException" => {
-                    return RusotoError::Service(StartTopicsDetectionJobError::KmsKeyValidation(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        StartTopicsDetectionJobError::KmsKeyValidation(err.msg),
+                        &res,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(StartTopicsDetectionJobError::TooManyRequests(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        StartTopicsDetectionJobError::TooManyRequests(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(StartTopicsDetectionJobError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -5007,12 +5496,14 @@ impl fmt::Display for StartTopicsDetectionJobError {
             StartTopicsDetectionJobError::InvalidRequest(ref cause) => write!(f, "{}", cause),
             StartTopicsDetectionJobError::KmsKeyValidation(ref cause) => write!(f, "{}", cause),
             StartTopicsDetectionJobError::TooManyRequests(ref cause) => write!(f, "{}", cause),
+            StartTopicsDetectionJobError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for StartTopicsDetectionJobError {}
 /// Errors returned by StopDominantLanguageDetectionJob
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum StopDominantLanguageDetectionJobError {
     /// <p>An internal server error occurred. Retry your request.</p>
     InternalServer(String),
@@ -5020,6 +5511,8 @@ pub enum StopDominantLanguageDetectionJobError {
     InvalidRequest(String),
     /// <p>The specified job was not found. Check the job ID and try again.</p>
     JobNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl StopDominantLanguageDetectionJobError {
@@ -5029,22 +5522,30 @@ impl StopDominantLanguageDetectionJobError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         StopDominantLanguageDetectionJobError::InternalServer(err.msg),
+                        &res,
                     )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         StopDominantLanguageDetectionJobError::InvalidRequest(err.msg),
+                        &res,
                     )
                 }
                 "JobNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         StopDominantLanguageDetectionJobError::JobNotFound(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        StopDominantLanguageDetectionJobError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -5061,12 +5562,16 @@ impl fmt::Display for StopDominantLanguageDetectionJobError {
                 write!(f, "{}", cause)
             }
             StopDominantLanguageDetectionJobError::JobNotFound(ref cause) => write!(f, "{}", cause),
+            StopDominantLanguageDetectionJobError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for StopDominantLanguageDetectionJobError {}
 /// Errors returned by StopEntitiesDetectionJob
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum StopEntitiesDetectionJobError {
     /// <p>An internal server error occurred. Retry your request.</p>
     InternalServer(String),
@@ -5074,6 +5579,8 @@ pub enum StopEntitiesDetectionJobError {
     InvalidRequest(String),
     /// <p>The specified job was not found. Check the job ID and try again.</p>
     JobNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl StopEntitiesDetectionJobError {
@@ -5081,22 +5588,25 @@ impl StopEntitiesDetectionJobError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(StopEntitiesDetectionJobError::InternalServer(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        StopEntitiesDetectionJobError::InternalServer(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(StopEntitiesDetectionJobError::InvalidRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        StopEntitiesDetectionJobError::InvalidRequest(err.msg),
+                        &res,
+                    )
                 }
                 "JobNotFoundException" => {
-                    return RusotoError::Service(StopEntitiesDetectionJobError::JobNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        StopEntitiesDetectionJobError::JobNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(StopEntitiesDetectionJobError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -5109,12 +5619,14 @@ impl fmt::Display for StopEntitiesDetectionJobError {
             StopEntitiesDetectionJobError::InternalServer(ref cause) => write!(f, "{}", cause),
             StopEntitiesDetectionJobError::InvalidRequest(ref cause) => write!(f, "{}", cause),
             StopEntitiesDetectionJobError::JobNotFound(ref cause) => write!(f, "{}", cause),
+            StopEntitiesDetectionJobError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for StopEntitiesDetectionJobError {}
 /// Errors returned by StopKeyPhrasesDetectionJob
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum StopKeyPhrasesDetectionJobError {
     /// <p>An internal server error occurred. Retry your request.</p>
     InternalServer(String),
@@ -5122,6 +5634,8 @@ pub enum StopKeyPhrasesDetectionJobError {
     InvalidRequest(String),
     /// <p>The specified job was not found. Check the job ID and try again.</p>
     JobNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl StopKeyPhrasesDetectionJobError {
@@ -5131,22 +5645,27 @@ impl StopKeyPhrasesDetectionJobError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(StopKeyPhrasesDetectionJobError::InternalServer(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        StopKeyPhrasesDetectionJobError::InternalServer(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(StopKeyPhrasesDetectionJobError::InvalidRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        StopKeyPhrasesDetectionJobError::InvalidRequest(err.msg),
+                        &res,
+                    )
                 }
                 "JobNotFoundException" => {
-                    return RusotoError::Service(StopKeyPhrasesDetectionJobError::JobNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        StopKeyPhrasesDetectionJobError::JobNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(StopKeyPhrasesDetectionJobError::Unhandled, &res)
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -5159,12 +5678,14 @@ impl fmt::Display for StopKeyPhrasesDetectionJobError {
             StopKeyPhrasesDetectionJobError::InternalServer(ref cause) => write!(f, "{}", cause),
             StopKeyPhrasesDetectionJobError::InvalidRequest(ref cause) => write!(f, "{}", cause),
             StopKeyPhrasesDetectionJobError::JobNotFound(ref cause) => write!(f, "{}", cause),
+            StopKeyPhrasesDetectionJobError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for StopKeyPhrasesDetectionJobError {}
 /// Errors returned by StopSentimentDetectionJob
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum StopSentimentDetectionJobError {
     /// <p>An internal server error occurred. Retry your request.</p>
     InternalServer(String),
@@ -5172,6 +5693,8 @@ pub enum StopSentimentDetectionJobError {
     InvalidRequest(String),
     /// <p>The specified job was not found. Check the job ID and try again.</p>
     JobNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl StopSentimentDetectionJobError {
@@ -5179,22 +5702,27 @@ impl StopSentimentDetectionJobError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(StopSentimentDetectionJobError::InternalServer(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        StopSentimentDetectionJobError::InternalServer(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(StopSentimentDetectionJobError::InvalidRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        StopSentimentDetectionJobError::InvalidRequest(err.msg),
+                        &res,
+                    )
                 }
                 "JobNotFoundException" => {
-                    return RusotoError::Service(StopSentimentDetectionJobError::JobNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        StopSentimentDetectionJobError::JobNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(StopSentimentDetectionJobError::Unhandled, &res)
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -5207,12 +5735,14 @@ impl fmt::Display for StopSentimentDetectionJobError {
             StopSentimentDetectionJobError::InternalServer(ref cause) => write!(f, "{}", cause),
             StopSentimentDetectionJobError::InvalidRequest(ref cause) => write!(f, "{}", cause),
             StopSentimentDetectionJobError::JobNotFound(ref cause) => write!(f, "{}", cause),
+            StopSentimentDetectionJobError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for StopSentimentDetectionJobError {}
 /// Errors returned by StopTrainingDocumentClassifier
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum StopTrainingDocumentClassifierError {
     /// <p>An internal server error occurred. Retry your request.</p>
     InternalServer(String),
@@ -5222,6 +5752,8 @@ pub enum StopTrainingDocumentClassifierError {
     ResourceNotFound(String),
     /// <p>The number of requests exceeds the limit. Resubmit your request later.</p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl StopTrainingDocumentClassifierError {
@@ -5231,27 +5763,36 @@ impl StopTrainingDocumentClassifierError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         StopTrainingDocumentClassifierError::InternalServer(err.msg),
+                        &res,
                     )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         StopTrainingDocumentClassifierError::InvalidRequest(err.msg),
+                        &res,
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         StopTrainingDocumentClassifierError::ResourceNotFound(err.msg),
+                        &res,
                     )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         StopTrainingDocumentClassifierError::TooManyRequests(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        StopTrainingDocumentClassifierError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -5273,12 +5814,16 @@ impl fmt::Display for StopTrainingDocumentClassifierError {
             StopTrainingDocumentClassifierError::TooManyRequests(ref cause) => {
                 write!(f, "{}", cause)
             }
+            StopTrainingDocumentClassifierError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for StopTrainingDocumentClassifierError {}
 /// Errors returned by StopTrainingEntityRecognizer
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum StopTrainingEntityRecognizerError {
     /// <p>An internal server error occurred. Retry your request.</p>
     InternalServer(String),
@@ -5288,6 +5833,8 @@ pub enum StopTrainingEntityRecognizerError {
     ResourceNotFound(String),
     /// <p>The number of requests exceeds the limit. Resubmit your request later.</p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl StopTrainingEntityRecognizerError {
@@ -5297,27 +5844,36 @@ impl StopTrainingEntityRecognizerError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(StopTrainingEntityRecognizerError::InternalServer(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        StopTrainingEntityRecognizerError::InternalServer(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(StopTrainingEntityRecognizerError::InvalidRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        StopTrainingEntityRecognizerError::InvalidRequest(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         StopTrainingEntityRecognizerError::ResourceNotFound(err.msg),
+                        &res,
                     )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         StopTrainingEntityRecognizerError::TooManyRequests(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        StopTrainingEntityRecognizerError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -5333,12 +5889,14 @@ impl fmt::Display for StopTrainingEntityRecognizerError {
                 write!(f, "{}", cause)
             }
             StopTrainingEntityRecognizerError::TooManyRequests(ref cause) => write!(f, "{}", cause),
+            StopTrainingEntityRecognizerError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for StopTrainingEntityRecognizerError {}
 /// Errors returned by TagResource
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum TagResourceError {
     /// <p>Concurrent modification of the tags associated with an Amazon Comprehend resource is not supported. </p>
     ConcurrentModification(String),
@@ -5350,6 +5908,8 @@ pub enum TagResourceError {
     ResourceNotFound(String),
     /// <p>The request contains more tags than can be associated with a resource (50 tags per resource). The maximum number of tags includes both existing tags and those included in your current request. </p>
     TooManyTags(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl TagResourceError {
@@ -5357,22 +5917,25 @@ impl TagResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(TagResourceError::ConcurrentModification(err.msg))
+                    return RusotoError::service(
+                        TagResourceError::ConcurrentModification(err.msg),
+                        &res,
+                    )
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(TagResourceError::InternalServer(err.msg))
+                    return RusotoError::service(TagResourceError::InternalServer(err.msg), &res)
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(TagResourceError::InvalidRequest(err.msg))
+                    return RusotoError::service(TagResourceError::InvalidRequest(err.msg), &res)
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(TagResourceError::ResourceNotFound(err.msg))
+                    return RusotoError::service(TagResourceError::ResourceNotFound(err.msg), &res)
                 }
                 "TooManyTagsException" => {
-                    return RusotoError::Service(TagResourceError::TooManyTags(err.msg))
+                    return RusotoError::service(TagResourceError::TooManyTags(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(TagResourceError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -5387,12 +5950,14 @@ impl fmt::Display for TagResourceError {
             TagResourceError::InvalidRequest(ref cause) => write!(f, "{}", cause),
             TagResourceError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             TagResourceError::TooManyTags(ref cause) => write!(f, "{}", cause),
+            TagResourceError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for TagResourceError {}
 /// Errors returned by UntagResource
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UntagResourceError {
     /// <p>Concurrent modification of the tags associated with an Amazon Comprehend resource is not supported. </p>
     ConcurrentModification(String),
@@ -5404,6 +5969,8 @@ pub enum UntagResourceError {
     ResourceNotFound(String),
     /// <p>The request contains more tag keys than can be associated with a resource (50 tag keys per resource).</p>
     TooManyTagKeys(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UntagResourceError {
@@ -5411,24 +5978,28 @@ impl UntagResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(UntagResourceError::ConcurrentModification(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UntagResourceError::ConcurrentModification(err.msg),
+                        &res,
+                    )
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(UntagResourceError::InternalServer(err.msg))
+                    return RusotoError::service(UntagResourceError::InternalServer(err.msg), &res)
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(UntagResourceError::InvalidRequest(err.msg))
+                    return RusotoError::service(UntagResourceError::InvalidRequest(err.msg), &res)
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UntagResourceError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        UntagResourceError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "TooManyTagKeysException" => {
-                    return RusotoError::Service(UntagResourceError::TooManyTagKeys(err.msg))
+                    return RusotoError::service(UntagResourceError::TooManyTagKeys(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UntagResourceError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -5443,12 +6014,14 @@ impl fmt::Display for UntagResourceError {
             UntagResourceError::InvalidRequest(ref cause) => write!(f, "{}", cause),
             UntagResourceError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             UntagResourceError::TooManyTagKeys(ref cause) => write!(f, "{}", cause),
+            UntagResourceError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for UntagResourceError {}
 /// Errors returned by UpdateEndpoint
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdateEndpointError {
     /// <p>An internal server error occurred. Retry your request.</p>
     InternalServer(String),
@@ -5464,6 +6037,8 @@ pub enum UpdateEndpointError {
     ResourceUnavailable(String),
     /// <p>The number of requests exceeds the limit. Resubmit your request later.</p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdateEndpointError {
@@ -5471,30 +6046,40 @@ impl UpdateEndpointError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(UpdateEndpointError::InternalServer(err.msg))
+                    return RusotoError::service(UpdateEndpointError::InternalServer(err.msg), &res)
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(UpdateEndpointError::InvalidRequest(err.msg))
+                    return RusotoError::service(UpdateEndpointError::InvalidRequest(err.msg), &res)
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(UpdateEndpointError::ResourceInUse(err.msg))
+                    return RusotoError::service(UpdateEndpointError::ResourceInUse(err.msg), &res)
                 }
                 "ResourceLimitExceededException" => {
-                    return RusotoError::Service(UpdateEndpointError::ResourceLimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateEndpointError::ResourceLimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateEndpointError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        UpdateEndpointError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceUnavailableException" => {
-                    return RusotoError::Service(UpdateEndpointError::ResourceUnavailable(err.msg))
+                    return RusotoError::service(
+                        UpdateEndpointError::ResourceUnavailable(err.msg),
+                        &res,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(UpdateEndpointError::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        UpdateEndpointError::TooManyRequests(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UpdateEndpointError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -5511,6 +6096,7 @@ impl fmt::Display for UpdateEndpointError {
             UpdateEndpointError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             UpdateEndpointError::ResourceUnavailable(ref cause) => write!(f, "{}", cause),
             UpdateEndpointError::TooManyRequests(ref cause) => write!(f, "{}", cause),
+            UpdateEndpointError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
@@ -5865,6 +6451,7 @@ impl ComprehendClient {
     /// Creates a client backed by the default tokio event loop.
     ///
     /// The client will use the default credentials provider and tls client.
+    #[cfg(not(target_arch = "wasm32"))]
     pub fn new(region: region::Region) -> ComprehendClient {
         ComprehendClient {
             client: Client::shared(),
@@ -5890,188 +6477,414 @@ impl ComprehendClient {
     pub fn new_with_client(client: Client, region: region::Region) -> ComprehendClient {
         ComprehendClient { client, region }
     }
+
+    /// Returns a builder of a client, setting any of its region, credentials provider,
+    /// dispatcher, retry policy, endpoint and timeouts.
+    pub fn builder() -> rusoto_core::ClientBuilder<ComprehendClient> {
+        rusoto_core::ClientBuilder::new(ComprehendClient::new_with_client)
+    }
+
+    /// Returns a clone of this client with the overrides of `config` applied to its
+    /// operations, sharing its connections.
+    pub fn customize(&self, config: &rusoto_core::OperationConfig) -> ComprehendClient {
+        ComprehendClient {
+            client: self.client.customize(config),
+            region: config.resolve_region(&self.region),
+        }
+    }
 }
 
-#[async_trait]
-impl Comprehend for ComprehendClient {
-    /// <p>Determines the dominant language of the input text for a batch of documents. For a list of languages that Amazon Comprehend can detect, see <a href="https://docs.aws.amazon.com/comprehend/latest/dg/how-languages.html">Amazon Comprehend Supported Languages</a>. </p>
-    async fn batch_detect_dominant_language(
+impl ComprehendClient {
+    /// Calls `batch_detect_dominant_language`, also returning the metadata of the response.
+    pub async fn batch_detect_dominant_language_with_metadata(
         &self,
         input: BatchDetectDominantLanguageRequest,
-    ) -> Result<BatchDetectDominantLanguageResponse, RusotoError<BatchDetectDominantLanguageError>>
-    {
-        let mut request = SignedRequest::new("POST", "comprehend", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "Comprehend_20171127.BatchDetectDominantLanguage",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
+    ) -> Result<
+        (BatchDetectDominantLanguageResponse, ResponseMetadata),
+        RusotoError<BatchDetectDominantLanguageError>,
+    > {
+        let request = self.build_batch_detect_dominant_language_request(input);
 
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<BatchDetectDominantLanguageResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<BatchDetectDominantLanguageResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
             Err(BatchDetectDominantLanguageError::from_response(response))
         }
     }
 
-    /// <p>Inspects the text of a batch of documents for named entities and returns information about them. For more information about named entities, see <a>how-entities</a> </p>
-    async fn batch_detect_entities(
+    /// Presigns the request `batch_detect_dominant_language` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_batch_detect_dominant_language(
         &self,
-        input: BatchDetectEntitiesRequest,
-    ) -> Result<BatchDetectEntitiesResponse, RusotoError<BatchDetectEntitiesError>> {
+        presigner: &rusoto_core::Presigner,
+        input: BatchDetectDominantLanguageRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_batch_detect_dominant_language_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_batch_detect_dominant_language_request(
+        &self,
+        input: BatchDetectDominantLanguageRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "comprehend", &self.region, "/");
+        request.set_operation("BatchDetectDominantLanguage");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "Comprehend_20171127.BatchDetectEntities");
+        request.add_header(
+            "x-amz-target",
+            "Comprehend_20171127.BatchDetectDominantLanguage",
+        );
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `batch_detect_entities`, also returning the metadata of the response.
+    pub async fn batch_detect_entities_with_metadata(
+        &self,
+        input: BatchDetectEntitiesRequest,
+    ) -> Result<
+        (BatchDetectEntitiesResponse, ResponseMetadata),
+        RusotoError<BatchDetectEntitiesError>,
+    > {
+        let request = self.build_batch_detect_entities_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<BatchDetectEntitiesResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<BatchDetectEntitiesResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
             Err(BatchDetectEntitiesError::from_response(response))
         }
     }
 
-    /// <p>Detects the key noun phrases found in a batch of documents.</p>
-    async fn batch_detect_key_phrases(
+    /// Presigns the request `batch_detect_entities` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_batch_detect_entities(
         &self,
-        input: BatchDetectKeyPhrasesRequest,
-    ) -> Result<BatchDetectKeyPhrasesResponse, RusotoError<BatchDetectKeyPhrasesError>> {
+        presigner: &rusoto_core::Presigner,
+        input: BatchDetectEntitiesRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_batch_detect_entities_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_batch_detect_entities_request(
+        &self,
+        input: BatchDetectEntitiesRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "comprehend", &self.region, "/");
+        request.set_operation("BatchDetectEntities");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "Comprehend_20171127.BatchDetectKeyPhrases");
+        request.add_header("x-amz-target", "Comprehend_20171127.BatchDetectEntities");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
-        let mut response = self
+        request
+    }
+
+    /// Calls `batch_detect_key_phrases`, also returning the metadata of the response.
+    pub async fn batch_detect_key_phrases_with_metadata(
+        &self,
+        input: BatchDetectKeyPhrasesRequest,
+    ) -> Result<
+        (BatchDetectKeyPhrasesResponse, ResponseMetadata),
+        RusotoError<BatchDetectKeyPhrasesError>,
+    > {
+        let request = self.build_batch_detect_key_phrases_request(input);
+
+        let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<BatchDetectKeyPhrasesResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<BatchDetectKeyPhrasesResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
             Err(BatchDetectKeyPhrasesError::from_response(response))
         }
     }
 
-    /// <p>Inspects a batch of documents and returns an inference of the prevailing sentiment, <code>POSITIVE</code>, <code>NEUTRAL</code>, <code>MIXED</code>, or <code>NEGATIVE</code>, in each one.</p>
-    async fn batch_detect_sentiment(
+    /// Presigns the request `batch_detect_key_phrases` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_batch_detect_key_phrases(
+        &self,
+        presigner: &rusoto_core::Presigner,
+        input: BatchDetectKeyPhrasesRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_batch_detect_key_phrases_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_batch_detect_key_phrases_request(
+        &self,
+        input: BatchDetectKeyPhrasesRequest,
+    ) -> SignedRequest {
+        let mut request = SignedRequest::new("POST", "comprehend", &self.region, "/");
+        request.set_operation("BatchDetectKeyPhrases");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "Comprehend_20171127.BatchDetectKeyPhrases");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        request
+    }
+
+    /// Calls `batch_detect_sentiment`, also returning the metadata of the response.
+    pub async fn batch_detect_sentiment_with_metadata(
         &self,
         input: BatchDetectSentimentRequest,
-    ) -> Result<BatchDetectSentimentResponse, RusotoError<BatchDetectSentimentError>> {
+    ) -> Result<
+        (BatchDetectSentimentResponse, ResponseMetadata),
+        RusotoError<BatchDetectSentimentError>,
+    > {
+        let request = self.build_batch_detect_sentiment_request(input);
+
+        let mut response = self
+            .client
+            .sign_and_dispatch(request)
+            .await
+            .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
+        if response.status.is_success() {
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<BatchDetectSentimentResponse, _>()?;
+            Ok((result, metadata))
+        } else {
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(BatchDetectSentimentError::from_response(response))
+        }
+    }
+
+    /// Presigns the request `batch_detect_sentiment` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_batch_detect_sentiment(
+        &self,
+        presigner: &rusoto_core::Presigner,
+        input: BatchDetectSentimentRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_batch_detect_sentiment_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_batch_detect_sentiment_request(
+        &self,
+        input: BatchDetectSentimentRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "comprehend", &self.region, "/");
+        request.set_operation("BatchDetectSentiment");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Comprehend_20171127.BatchDetectSentiment");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `batch_detect_syntax`, also returning the metadata of the response.
+    pub async fn batch_detect_syntax_with_metadata(
+        &self,
+        input: BatchDetectSyntaxRequest,
+    ) -> Result<(BatchDetectSyntaxResponse, ResponseMetadata), RusotoError<BatchDetectSyntaxError>>
+    {
+        let request = self.build_batch_detect_syntax_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<BatchDetectSentimentResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<BatchDetectSyntaxResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(BatchDetectSentimentError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(BatchDetectSyntaxError::from_response(response))
         }
     }
 
-    /// <p>Inspects the text of a batch of documents for the syntax and part of speech of the words in the document and returns information about them. For more information, see <a>how-syntax</a>.</p>
-    async fn batch_detect_syntax(
+    /// Presigns the request `batch_detect_syntax` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_batch_detect_syntax(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: BatchDetectSyntaxRequest,
-    ) -> Result<BatchDetectSyntaxResponse, RusotoError<BatchDetectSyntaxError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_batch_detect_syntax_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_batch_detect_syntax_request(&self, input: BatchDetectSyntaxRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "comprehend", &self.region, "/");
+        request.set_operation("BatchDetectSyntax");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Comprehend_20171127.BatchDetectSyntax");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `classify_document`, also returning the metadata of the response.
+    pub async fn classify_document_with_metadata(
+        &self,
+        input: ClassifyDocumentRequest,
+    ) -> Result<(ClassifyDocumentResponse, ResponseMetadata), RusotoError<ClassifyDocumentError>>
+    {
+        let request = self.build_classify_document_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<BatchDetectSyntaxResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ClassifyDocumentResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(BatchDetectSyntaxError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ClassifyDocumentError::from_response(response))
         }
     }
 
-    /// <p>Creates a new document classification request to analyze a single document in real-time, using a previously created and trained custom model and an endpoint.</p>
-    async fn classify_document(
+    /// Presigns the request `classify_document` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_classify_document(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ClassifyDocumentRequest,
-    ) -> Result<ClassifyDocumentResponse, RusotoError<ClassifyDocumentError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_classify_document_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_classify_document_request(&self, input: ClassifyDocumentRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "comprehend", &self.region, "/");
+        request.set_operation("ClassifyDocument");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Comprehend_20171127.ClassifyDocument");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `create_document_classifier`, also returning the metadata of the response.
+    pub async fn create_document_classifier_with_metadata(
+        &self,
+        input: CreateDocumentClassifierRequest,
+    ) -> Result<
+        (CreateDocumentClassifierResponse, ResponseMetadata),
+        RusotoError<CreateDocumentClassifierError>,
+    > {
+        let request = self.build_create_document_classifier_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<ClassifyDocumentResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<CreateDocumentClassifierResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ClassifyDocumentError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(CreateDocumentClassifierError::from_response(response))
         }
     }
 
-    /// <p>Creates a new document classifier that you can use to categorize documents. To create a classifier you provide a set of training documents that labeled with the categories that you want to use. After the classifier is trained you can use it to categorize a set of labeled documents into the categories. For more information, see <a>how-document-classification</a>.</p>
-    async fn create_document_classifier(
+    /// Presigns the request `create_document_classifier` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_document_classifier(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: CreateDocumentClassifierRequest,
-    ) -> Result<CreateDocumentClassifierResponse, RusotoError<CreateDocumentClassifierError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_document_classifier_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_document_classifier_request(
+        &self,
+        input: CreateDocumentClassifierRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "comprehend", &self.region, "/");
+        request.set_operation("CreateDocumentClassifier");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -6081,83 +6894,168 @@ impl Comprehend for ComprehendClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `create_endpoint`, also returning the metadata of the response.
+    pub async fn create_endpoint_with_metadata(
+        &self,
+        input: CreateEndpointRequest,
+    ) -> Result<(CreateEndpointResponse, ResponseMetadata), RusotoError<CreateEndpointError>> {
+        let request = self.build_create_endpoint_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<CreateDocumentClassifierResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<CreateEndpointResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(CreateDocumentClassifierError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(CreateEndpointError::from_response(response))
         }
     }
 
-    /// <p>Creates a model-specific endpoint for synchronous inference for a previously trained custom model </p>
-    async fn create_endpoint(
+    /// Presigns the request `create_endpoint` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_endpoint(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: CreateEndpointRequest,
-    ) -> Result<CreateEndpointResponse, RusotoError<CreateEndpointError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_endpoint_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_endpoint_request(&self, input: CreateEndpointRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "comprehend", &self.region, "/");
+        request.set_operation("CreateEndpoint");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Comprehend_20171127.CreateEndpoint");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `create_entity_recognizer`, also returning the metadata of the response.
+    pub async fn create_entity_recognizer_with_metadata(
+        &self,
+        input: CreateEntityRecognizerRequest,
+    ) -> Result<
+        (CreateEntityRecognizerResponse, ResponseMetadata),
+        RusotoError<CreateEntityRecognizerError>,
+    > {
+        let request = self.build_create_entity_recognizer_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<CreateEndpointResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<CreateEntityRecognizerResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(CreateEndpointError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(CreateEntityRecognizerError::from_response(response))
         }
     }
 
-    /// <p>Creates an entity recognizer using submitted files. After your <code>CreateEntityRecognizer</code> request is submitted, you can check job status using the API. </p>
-    async fn create_entity_recognizer(
+    /// Presigns the request `create_entity_recognizer` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_entity_recognizer(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: CreateEntityRecognizerRequest,
-    ) -> Result<CreateEntityRecognizerResponse, RusotoError<CreateEntityRecognizerError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_entity_recognizer_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_entity_recognizer_request(
+        &self,
+        input: CreateEntityRecognizerRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "comprehend", &self.region, "/");
+        request.set_operation("CreateEntityRecognizer");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Comprehend_20171127.CreateEntityRecognizer");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `delete_document_classifier`, also returning the metadata of the response.
+    pub async fn delete_document_classifier_with_metadata(
+        &self,
+        input: DeleteDocumentClassifierRequest,
+    ) -> Result<
+        (DeleteDocumentClassifierResponse, ResponseMetadata),
+        RusotoError<DeleteDocumentClassifierError>,
+    > {
+        let request = self.build_delete_document_classifier_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<CreateEntityRecognizerResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DeleteDocumentClassifierResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(CreateEntityRecognizerError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DeleteDocumentClassifierError::from_response(response))
         }
     }
 
-    /// <p>Deletes a previously created document classifier</p> <p>Only those classifiers that are in terminated states (IN_ERROR, TRAINED) will be deleted. If an active inference job is using the model, a <code>ResourceInUseException</code> will be returned.</p> <p>This is an asynchronous action that puts the classifier into a DELETING state, and it is then removed by a background job. Once removed, the classifier disappears from your account and is no longer available for use. </p>
-    async fn delete_document_classifier(
+    /// Presigns the request `delete_document_classifier` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_document_classifier(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteDocumentClassifierRequest,
-    ) -> Result<DeleteDocumentClassifierResponse, RusotoError<DeleteDocumentClassifierError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_document_classifier_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_document_classifier_request(
+        &self,
+        input: DeleteDocumentClassifierRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "comprehend", &self.region, "/");
+        request.set_operation("DeleteDocumentClassifier");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -6167,86 +7065,170 @@ impl Comprehend for ComprehendClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `delete_endpoint`, also returning the metadata of the response.
+    pub async fn delete_endpoint_with_metadata(
+        &self,
+        input: DeleteEndpointRequest,
+    ) -> Result<(DeleteEndpointResponse, ResponseMetadata), RusotoError<DeleteEndpointError>> {
+        let request = self.build_delete_endpoint_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DeleteDocumentClassifierResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DeleteEndpointResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteDocumentClassifierError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DeleteEndpointError::from_response(response))
         }
     }
 
-    /// <p>Deletes a model-specific endpoint for a previously-trained custom model. All endpoints must be deleted in order for the model to be deleted.</p>
-    async fn delete_endpoint(
+    /// Presigns the request `delete_endpoint` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_endpoint(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteEndpointRequest,
-    ) -> Result<DeleteEndpointResponse, RusotoError<DeleteEndpointError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_endpoint_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_endpoint_request(&self, input: DeleteEndpointRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "comprehend", &self.region, "/");
+        request.set_operation("DeleteEndpoint");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Comprehend_20171127.DeleteEndpoint");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `delete_entity_recognizer`, also returning the metadata of the response.
+    pub async fn delete_entity_recognizer_with_metadata(
+        &self,
+        input: DeleteEntityRecognizerRequest,
+    ) -> Result<
+        (DeleteEntityRecognizerResponse, ResponseMetadata),
+        RusotoError<DeleteEntityRecognizerError>,
+    > {
+        let request = self.build_delete_entity_recognizer_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<DeleteEndpointResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DeleteEntityRecognizerResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteEndpointError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DeleteEntityRecognizerError::from_response(response))
         }
     }
 
-    /// <p>Deletes an entity recognizer.</p> <p>Only those recognizers that are in terminated states (IN_ERROR, TRAINED) will be deleted. If an active inference job is using the model, a <code>ResourceInUseException</code> will be returned.</p> <p>This is an asynchronous action that puts the recognizer into a DELETING state, and it is then removed by a background job. Once removed, the recognizer disappears from your account and is no longer available for use. </p>
-    async fn delete_entity_recognizer(
+    /// Presigns the request `delete_entity_recognizer` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_entity_recognizer(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteEntityRecognizerRequest,
-    ) -> Result<DeleteEntityRecognizerResponse, RusotoError<DeleteEntityRecognizerError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_entity_recognizer_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_entity_recognizer_request(
+        &self,
+        input: DeleteEntityRecognizerRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "comprehend", &self.region, "/");
+        request.set_operation("DeleteEntityRecognizer");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Comprehend_20171127.DeleteEntityRecognizer");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `describe_document_classification_job`, also returning the metadata of the response.
+    pub async fn describe_document_classification_job_with_metadata(
+        &self,
+        input: DescribeDocumentClassificationJobRequest,
+    ) -> Result<
+        (DescribeDocumentClassificationJobResponse, ResponseMetadata),
+        RusotoError<DescribeDocumentClassificationJobError>,
+    > {
+        let request = self.build_describe_document_classification_job_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DeleteEntityRecognizerResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DescribeDocumentClassificationJobResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteEntityRecognizerError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DescribeDocumentClassificationJobError::from_response(
+                response,
+            ))
         }
     }
 
-    /// <p>Gets the properties associated with a document classification job. Use this operation to get the status of a classification job.</p>
-    async fn describe_document_classification_job(
+    /// Presigns the request `describe_document_classification_job` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_document_classification_job(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeDocumentClassificationJobRequest,
-    ) -> Result<
-        DescribeDocumentClassificationJobResponse,
-        RusotoError<DescribeDocumentClassificationJobError>,
-    > {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_document_classification_job_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_document_classification_job_request(
+        &self,
+        input: DescribeDocumentClassificationJobRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "comprehend", &self.region, "/");
+        request.set_operation("DescribeDocumentClassificationJob");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -6256,31 +7238,58 @@ impl Comprehend for ComprehendClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `describe_document_classifier`, also returning the metadata of the response.
+    pub async fn describe_document_classifier_with_metadata(
+        &self,
+        input: DescribeDocumentClassifierRequest,
+    ) -> Result<
+        (DescribeDocumentClassifierResponse, ResponseMetadata),
+        RusotoError<DescribeDocumentClassifierError>,
+    > {
+        let request = self.build_describe_document_classifier_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DescribeDocumentClassificationJobResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DescribeDocumentClassifierResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeDocumentClassificationJobError::from_response(
-                response,
-            ))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DescribeDocumentClassifierError::from_response(response))
         }
     }
 
-    /// <p>Gets the properties associated with a document classifier.</p>
-    async fn describe_document_classifier(
+    /// Presigns the request `describe_document_classifier` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_document_classifier(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeDocumentClassifierRequest,
-    ) -> Result<DescribeDocumentClassifierResponse, RusotoError<DescribeDocumentClassifierError>>
-    {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_document_classifier_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_document_classifier_request(
+        &self,
+        input: DescribeDocumentClassifierRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "comprehend", &self.region, "/");
+        request.set_operation("DescribeDocumentClassifier");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -6290,31 +7299,63 @@ impl Comprehend for ComprehendClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `describe_dominant_language_detection_job`, also returning the metadata of the response.
+    pub async fn describe_dominant_language_detection_job_with_metadata(
+        &self,
+        input: DescribeDominantLanguageDetectionJobRequest,
+    ) -> Result<
+        (
+            DescribeDominantLanguageDetectionJobResponse,
+            ResponseMetadata,
+        ),
+        RusotoError<DescribeDominantLanguageDetectionJobError>,
+    > {
+        let request = self.build_describe_dominant_language_detection_job_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DescribeDocumentClassifierResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DescribeDominantLanguageDetectionJobResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeDocumentClassifierError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DescribeDominantLanguageDetectionJobError::from_response(
+                response,
+            ))
         }
     }
 
-    /// <p>Gets the properties associated with a dominant language detection job. Use this operation to get the status of a detection job.</p>
-    async fn describe_dominant_language_detection_job(
+    /// Presigns the request `describe_dominant_language_detection_job` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_dominant_language_detection_job(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeDominantLanguageDetectionJobRequest,
-    ) -> Result<
-        DescribeDominantLanguageDetectionJobResponse,
-        RusotoError<DescribeDominantLanguageDetectionJobError>,
-    > {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_dominant_language_detection_job_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_dominant_language_detection_job_request(
+        &self,
+        input: DescribeDominantLanguageDetectionJobRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "comprehend", &self.region, "/");
+        request.set_operation("DescribeDominantLanguageDetectionJob");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -6324,59 +7365,111 @@ impl Comprehend for ComprehendClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `describe_endpoint`, also returning the metadata of the response.
+    pub async fn describe_endpoint_with_metadata(
+        &self,
+        input: DescribeEndpointRequest,
+    ) -> Result<(DescribeEndpointResponse, ResponseMetadata), RusotoError<DescribeEndpointError>>
+    {
+        let request = self.build_describe_endpoint_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DescribeDominantLanguageDetectionJobResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DescribeEndpointResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeDominantLanguageDetectionJobError::from_response(
-                response,
-            ))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DescribeEndpointError::from_response(response))
         }
     }
 
-    /// <p>Gets the properties associated with a specific endpoint. Use this operation to get the status of an endpoint.</p>
-    async fn describe_endpoint(
+    /// Presigns the request `describe_endpoint` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_endpoint(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeEndpointRequest,
-    ) -> Result<DescribeEndpointResponse, RusotoError<DescribeEndpointError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_endpoint_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_endpoint_request(&self, input: DescribeEndpointRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "comprehend", &self.region, "/");
+        request.set_operation("DescribeEndpoint");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Comprehend_20171127.DescribeEndpoint");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `describe_entities_detection_job`, also returning the metadata of the response.
+    pub async fn describe_entities_detection_job_with_metadata(
+        &self,
+        input: DescribeEntitiesDetectionJobRequest,
+    ) -> Result<
+        (DescribeEntitiesDetectionJobResponse, ResponseMetadata),
+        RusotoError<DescribeEntitiesDetectionJobError>,
+    > {
+        let request = self.build_describe_entities_detection_job_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DescribeEndpointResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DescribeEntitiesDetectionJobResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeEndpointError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DescribeEntitiesDetectionJobError::from_response(response))
         }
     }
 
-    /// <p>Gets the properties associated with an entities detection job. Use this operation to get the status of a detection job.</p>
-    async fn describe_entities_detection_job(
+    /// Presigns the request `describe_entities_detection_job` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_entities_detection_job(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeEntitiesDetectionJobRequest,
-    ) -> Result<DescribeEntitiesDetectionJobResponse, RusotoError<DescribeEntitiesDetectionJobError>>
-    {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_entities_detection_job_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_entities_detection_job_request(
+        &self,
+        input: DescribeEntitiesDetectionJobRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "comprehend", &self.region, "/");
+        request.set_operation("DescribeEntitiesDetectionJob");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -6386,28 +7479,58 @@ impl Comprehend for ComprehendClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
-        let mut response = self
+        request
+    }
+
+    /// Calls `describe_entity_recognizer`, also returning the metadata of the response.
+    pub async fn describe_entity_recognizer_with_metadata(
+        &self,
+        input: DescribeEntityRecognizerRequest,
+    ) -> Result<
+        (DescribeEntityRecognizerResponse, ResponseMetadata),
+        RusotoError<DescribeEntityRecognizerError>,
+    > {
+        let request = self.build_describe_entity_recognizer_request(input);
+
+        let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DescribeEntitiesDetectionJobResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DescribeEntityRecognizerResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeEntitiesDetectionJobError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DescribeEntityRecognizerError::from_response(response))
         }
     }
 
-    /// <p>Provides details about an entity recognizer including status, S3 buckets containing training data, recognizer metadata, metrics, and so on.</p>
-    async fn describe_entity_recognizer(
+    /// Presigns the request `describe_entity_recognizer` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_entity_recognizer(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeEntityRecognizerRequest,
-    ) -> Result<DescribeEntityRecognizerResponse, RusotoError<DescribeEntityRecognizerError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_entity_recognizer_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_entity_recognizer_request(
+        &self,
+        input: DescribeEntityRecognizerRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "comprehend", &self.region, "/");
+        request.set_operation("DescribeEntityRecognizer");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -6417,31 +7540,58 @@ impl Comprehend for ComprehendClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `describe_key_phrases_detection_job`, also returning the metadata of the response.
+    pub async fn describe_key_phrases_detection_job_with_metadata(
+        &self,
+        input: DescribeKeyPhrasesDetectionJobRequest,
+    ) -> Result<
+        (DescribeKeyPhrasesDetectionJobResponse, ResponseMetadata),
+        RusotoError<DescribeKeyPhrasesDetectionJobError>,
+    > {
+        let request = self.build_describe_key_phrases_detection_job_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DescribeEntityRecognizerResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DescribeKeyPhrasesDetectionJobResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeEntityRecognizerError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DescribeKeyPhrasesDetectionJobError::from_response(response))
         }
     }
 
-    /// <p>Gets the properties associated with a key phrases detection job. Use this operation to get the status of a detection job.</p>
-    async fn describe_key_phrases_detection_job(
+    /// Presigns the request `describe_key_phrases_detection_job` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_key_phrases_detection_job(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeKeyPhrasesDetectionJobRequest,
-    ) -> Result<
-        DescribeKeyPhrasesDetectionJobResponse,
-        RusotoError<DescribeKeyPhrasesDetectionJobError>,
-    > {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_key_phrases_detection_job_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_key_phrases_detection_job_request(
+        &self,
+        input: DescribeKeyPhrasesDetectionJobRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "comprehend", &self.region, "/");
+        request.set_operation("DescribeKeyPhrasesDetectionJob");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -6451,31 +7601,58 @@ impl Comprehend for ComprehendClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `describe_sentiment_detection_job`, also returning the metadata of the response.
+    pub async fn describe_sentiment_detection_job_with_metadata(
+        &self,
+        input: DescribeSentimentDetectionJobRequest,
+    ) -> Result<
+        (DescribeSentimentDetectionJobResponse, ResponseMetadata),
+        RusotoError<DescribeSentimentDetectionJobError>,
+    > {
+        let request = self.build_describe_sentiment_detection_job_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DescribeKeyPhrasesDetectionJobResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DescribeSentimentDetectionJobResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeKeyPhrasesDetectionJobError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DescribeSentimentDetectionJobError::from_response(response))
         }
     }
 
-    /// <p>Gets the properties associated with a sentiment detection job. Use this operation to get the status of a detection job.</p>
-    async fn describe_sentiment_detection_job(
+    /// Presigns the request `describe_sentiment_detection_job` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_sentiment_detection_job(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeSentimentDetectionJobRequest,
-    ) -> Result<
-        DescribeSentimentDetectionJobResponse,
-        RusotoError<DescribeSentimentDetectionJobError>,
-    > {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_sentiment_detection_job_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_sentiment_detection_job_request(
+        &self,
+        input: DescribeSentimentDetectionJobRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "comprehend", &self.region, "/");
+        request.set_operation("DescribeSentimentDetectionJob");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -6485,29 +7662,58 @@ impl Comprehend for ComprehendClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `describe_topics_detection_job`, also returning the metadata of the response.
+    pub async fn describe_topics_detection_job_with_metadata(
+        &self,
+        input: DescribeTopicsDetectionJobRequest,
+    ) -> Result<
+        (DescribeTopicsDetectionJobResponse, ResponseMetadata),
+        RusotoError<DescribeTopicsDetectionJobError>,
+    > {
+        let request = self.build_describe_topics_detection_job_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DescribeSentimentDetectionJobResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DescribeTopicsDetectionJobResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeSentimentDetectionJobError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DescribeTopicsDetectionJobError::from_response(response))
         }
     }
 
-    /// <p>Gets the properties associated with a topic detection job. Use this operation to get the status of a detection job.</p>
-    async fn describe_topics_detection_job(
+    /// Presigns the request `describe_topics_detection_job` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_topics_detection_job(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeTopicsDetectionJobRequest,
-    ) -> Result<DescribeTopicsDetectionJobResponse, RusotoError<DescribeTopicsDetectionJobError>>
-    {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_topics_detection_job_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_topics_detection_job_request(
+        &self,
+        input: DescribeTopicsDetectionJobRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "comprehend", &self.region, "/");
+        request.set_operation("DescribeTopicsDetectionJob");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -6517,168 +7723,326 @@ impl Comprehend for ComprehendClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `detect_dominant_language`, also returning the metadata of the response.
+    pub async fn detect_dominant_language_with_metadata(
+        &self,
+        input: DetectDominantLanguageRequest,
+    ) -> Result<
+        (DetectDominantLanguageResponse, ResponseMetadata),
+        RusotoError<DetectDominantLanguageError>,
+    > {
+        let request = self.build_detect_dominant_language_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DescribeTopicsDetectionJobResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DetectDominantLanguageResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeTopicsDetectionJobError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DetectDominantLanguageError::from_response(response))
         }
     }
 
-    /// <p>Determines the dominant language of the input text. For a list of languages that Amazon Comprehend can detect, see <a href="https://docs.aws.amazon.com/comprehend/latest/dg/how-languages.html">Amazon Comprehend Supported Languages</a>. </p>
-    async fn detect_dominant_language(
+    /// Presigns the request `detect_dominant_language` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_detect_dominant_language(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DetectDominantLanguageRequest,
-    ) -> Result<DetectDominantLanguageResponse, RusotoError<DetectDominantLanguageError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_detect_dominant_language_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_detect_dominant_language_request(
+        &self,
+        input: DetectDominantLanguageRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "comprehend", &self.region, "/");
+        request.set_operation("DetectDominantLanguage");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Comprehend_20171127.DetectDominantLanguage");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `detect_entities`, also returning the metadata of the response.
+    pub async fn detect_entities_with_metadata(
+        &self,
+        input: DetectEntitiesRequest,
+    ) -> Result<(DetectEntitiesResponse, ResponseMetadata), RusotoError<DetectEntitiesError>> {
+        let request = self.build_detect_entities_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DetectDominantLanguageResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DetectEntitiesResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DetectDominantLanguageError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DetectEntitiesError::from_response(response))
         }
     }
 
-    /// <p>Inspects text for named entities, and returns information about them. For more information, about named entities, see <a>how-entities</a>. </p>
-    async fn detect_entities(
+    /// Presigns the request `detect_entities` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_detect_entities(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DetectEntitiesRequest,
-    ) -> Result<DetectEntitiesResponse, RusotoError<DetectEntitiesError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_detect_entities_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_detect_entities_request(&self, input: DetectEntitiesRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "comprehend", &self.region, "/");
+        request.set_operation("DetectEntities");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Comprehend_20171127.DetectEntities");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `detect_key_phrases`, also returning the metadata of the response.
+    pub async fn detect_key_phrases_with_metadata(
+        &self,
+        input: DetectKeyPhrasesRequest,
+    ) -> Result<(DetectKeyPhrasesResponse, ResponseMetadata), RusotoError<DetectKeyPhrasesError>>
+    {
+        let request = self.build_detect_key_phrases_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<DetectEntitiesResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DetectKeyPhrasesResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DetectEntitiesError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DetectKeyPhrasesError::from_response(response))
         }
     }
 
-    /// <p>Detects the key noun phrases found in the text. </p>
-    async fn detect_key_phrases(
+    /// Presigns the request `detect_key_phrases` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_detect_key_phrases(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DetectKeyPhrasesRequest,
-    ) -> Result<DetectKeyPhrasesResponse, RusotoError<DetectKeyPhrasesError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_detect_key_phrases_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_detect_key_phrases_request(&self, input: DetectKeyPhrasesRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "comprehend", &self.region, "/");
+        request.set_operation("DetectKeyPhrases");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Comprehend_20171127.DetectKeyPhrases");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `detect_sentiment`, also returning the metadata of the response.
+    pub async fn detect_sentiment_with_metadata(
+        &self,
+        input: DetectSentimentRequest,
+    ) -> Result<(DetectSentimentResponse, ResponseMetadata), RusotoError<DetectSentimentError>>
+    {
+        let request = self.build_detect_sentiment_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DetectKeyPhrasesResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DetectSentimentResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DetectKeyPhrasesError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DetectSentimentError::from_response(response))
         }
     }
 
-    /// <p>Inspects text and returns an inference of the prevailing sentiment (<code>POSITIVE</code>, <code>NEUTRAL</code>, <code>MIXED</code>, or <code>NEGATIVE</code>). </p>
-    async fn detect_sentiment(
+    /// Presigns the request `detect_sentiment` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_detect_sentiment(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DetectSentimentRequest,
-    ) -> Result<DetectSentimentResponse, RusotoError<DetectSentimentError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_detect_sentiment_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_detect_sentiment_request(&self, input: DetectSentimentRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "comprehend", &self.region, "/");
+        request.set_operation("DetectSentiment");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Comprehend_20171127.DetectSentiment");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `detect_syntax`, also returning the metadata of the response.
+    pub async fn detect_syntax_with_metadata(
+        &self,
+        input: DetectSyntaxRequest,
+    ) -> Result<(DetectSyntaxResponse, ResponseMetadata), RusotoError<DetectSyntaxError>> {
+        let request = self.build_detect_syntax_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<DetectSentimentResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DetectSyntaxResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DetectSentimentError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DetectSyntaxError::from_response(response))
         }
     }
 
-    /// <p>Inspects text for syntax and the part of speech of words in the document. For more information, <a>how-syntax</a>.</p>
-    async fn detect_syntax(
+    /// Presigns the request `detect_syntax` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_detect_syntax(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DetectSyntaxRequest,
-    ) -> Result<DetectSyntaxResponse, RusotoError<DetectSyntaxError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_detect_syntax_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_detect_syntax_request(&self, input: DetectSyntaxRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "comprehend", &self.region, "/");
+        request.set_operation("DetectSyntax");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Comprehend_20171127.DetectSyntax");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `list_document_classification_jobs`, also returning the metadata of the response.
+    pub async fn list_document_classification_jobs_with_metadata(
+        &self,
+        input: ListDocumentClassificationJobsRequest,
+    ) -> Result<
+        (ListDocumentClassificationJobsResponse, ResponseMetadata),
+        RusotoError<ListDocumentClassificationJobsError>,
+    > {
+        let request = self.build_list_document_classification_jobs_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<DetectSyntaxResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ListDocumentClassificationJobsResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DetectSyntaxError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ListDocumentClassificationJobsError::from_response(response))
         }
     }
 
-    /// <p>Gets a list of the documentation classification jobs that you have submitted.</p>
-    async fn list_document_classification_jobs(
+    /// Presigns the request `list_document_classification_jobs` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_document_classification_jobs(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListDocumentClassificationJobsRequest,
-    ) -> Result<
-        ListDocumentClassificationJobsResponse,
-        RusotoError<ListDocumentClassificationJobsError>,
-    > {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_document_classification_jobs_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_document_classification_jobs_request(
+        &self,
+        input: ListDocumentClassificationJobsRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "comprehend", &self.region, "/");
+        request.set_operation("ListDocumentClassificationJobs");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -6688,28 +8052,58 @@ impl Comprehend for ComprehendClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `list_document_classifiers`, also returning the metadata of the response.
+    pub async fn list_document_classifiers_with_metadata(
+        &self,
+        input: ListDocumentClassifiersRequest,
+    ) -> Result<
+        (ListDocumentClassifiersResponse, ResponseMetadata),
+        RusotoError<ListDocumentClassifiersError>,
+    > {
+        let request = self.build_list_document_classifiers_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListDocumentClassificationJobsResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ListDocumentClassifiersResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ListDocumentClassificationJobsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ListDocumentClassifiersError::from_response(response))
         }
     }
 
-    /// <p>Gets a list of the document classifiers that you have created.</p>
-    async fn list_document_classifiers(
+    /// Presigns the request `list_document_classifiers` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_document_classifiers(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListDocumentClassifiersRequest,
-    ) -> Result<ListDocumentClassifiersResponse, RusotoError<ListDocumentClassifiersError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_document_classifiers_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_document_classifiers_request(
+        &self,
+        input: ListDocumentClassifiersRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "comprehend", &self.region, "/");
+        request.set_operation("ListDocumentClassifiers");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -6719,31 +8113,60 @@ impl Comprehend for ComprehendClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `list_dominant_language_detection_jobs`, also returning the metadata of the response.
+    pub async fn list_dominant_language_detection_jobs_with_metadata(
+        &self,
+        input: ListDominantLanguageDetectionJobsRequest,
+    ) -> Result<
+        (ListDominantLanguageDetectionJobsResponse, ResponseMetadata),
+        RusotoError<ListDominantLanguageDetectionJobsError>,
+    > {
+        let request = self.build_list_dominant_language_detection_jobs_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListDocumentClassifiersResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ListDominantLanguageDetectionJobsResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ListDocumentClassifiersError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ListDominantLanguageDetectionJobsError::from_response(
+                response,
+            ))
         }
     }
 
-    /// <p>Gets a list of the dominant language detection jobs that you have submitted.</p>
-    async fn list_dominant_language_detection_jobs(
+    /// Presigns the request `list_dominant_language_detection_jobs` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_dominant_language_detection_jobs(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListDominantLanguageDetectionJobsRequest,
-    ) -> Result<
-        ListDominantLanguageDetectionJobsResponse,
-        RusotoError<ListDominantLanguageDetectionJobsError>,
-    > {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_dominant_language_detection_jobs_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_dominant_language_detection_jobs_request(
+        &self,
+        input: ListDominantLanguageDetectionJobsRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "comprehend", &self.region, "/");
+        request.set_operation("ListDominantLanguageDetectionJobs");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -6753,58 +8176,110 @@ impl Comprehend for ComprehendClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `list_endpoints`, also returning the metadata of the response.
+    pub async fn list_endpoints_with_metadata(
+        &self,
+        input: ListEndpointsRequest,
+    ) -> Result<(ListEndpointsResponse, ResponseMetadata), RusotoError<ListEndpointsError>> {
+        let request = self.build_list_endpoints_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListDominantLanguageDetectionJobsResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ListEndpointsResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ListDominantLanguageDetectionJobsError::from_response(
-                response,
-            ))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ListEndpointsError::from_response(response))
         }
     }
 
-    /// <p>Gets a list of all existing endpoints that you've created.</p>
-    async fn list_endpoints(
+    /// Presigns the request `list_endpoints` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_endpoints(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListEndpointsRequest,
-    ) -> Result<ListEndpointsResponse, RusotoError<ListEndpointsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_endpoints_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_endpoints_request(&self, input: ListEndpointsRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "comprehend", &self.region, "/");
+        request.set_operation("ListEndpoints");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Comprehend_20171127.ListEndpoints");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `list_entities_detection_jobs`, also returning the metadata of the response.
+    pub async fn list_entities_detection_jobs_with_metadata(
+        &self,
+        input: ListEntitiesDetectionJobsRequest,
+    ) -> Result<
+        (ListEntitiesDetectionJobsResponse, ResponseMetadata),
+        RusotoError<ListEntitiesDetectionJobsError>,
+    > {
+        let request = self.build_list_entities_detection_jobs_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<ListEndpointsResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ListEntitiesDetectionJobsResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ListEndpointsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ListEntitiesDetectionJobsError::from_response(response))
         }
     }
 
-    /// <p>Gets a list of the entity detection jobs that you have submitted.</p>
-    async fn list_entities_detection_jobs(
+    /// Presigns the request `list_entities_detection_jobs` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_entities_detection_jobs(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListEntitiesDetectionJobsRequest,
-    ) -> Result<ListEntitiesDetectionJobsResponse, RusotoError<ListEntitiesDetectionJobsError>>
-    {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_entities_detection_jobs_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_entities_detection_jobs_request(
+        &self,
+        input: ListEntitiesDetectionJobsRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "comprehend", &self.region, "/");
+        request.set_operation("ListEntitiesDetectionJobs");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -6814,57 +8289,116 @@ impl Comprehend for ComprehendClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `list_entity_recognizers`, also returning the metadata of the response.
+    pub async fn list_entity_recognizers_with_metadata(
+        &self,
+        input: ListEntityRecognizersRequest,
+    ) -> Result<
+        (ListEntityRecognizersResponse, ResponseMetadata),
+        RusotoError<ListEntityRecognizersError>,
+    > {
+        let request = self.build_list_entity_recognizers_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListEntitiesDetectionJobsResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ListEntityRecognizersResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ListEntitiesDetectionJobsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ListEntityRecognizersError::from_response(response))
         }
     }
 
-    /// <p>Gets a list of the properties of all entity recognizers that you created, including recognizers currently in training. Allows you to filter the list of recognizers based on criteria such as status and submission time. This call returns up to 500 entity recognizers in the list, with a default number of 100 recognizers in the list.</p> <p>The results of this list are not in any particular order. Please get the list and sort locally if needed.</p>
-    async fn list_entity_recognizers(
+    /// Presigns the request `list_entity_recognizers` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_entity_recognizers(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListEntityRecognizersRequest,
-    ) -> Result<ListEntityRecognizersResponse, RusotoError<ListEntityRecognizersError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_entity_recognizers_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_entity_recognizers_request(
+        &self,
+        input: ListEntityRecognizersRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "comprehend", &self.region, "/");
+        request.set_operation("ListEntityRecognizers");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Comprehend_20171127.ListEntityRecognizers");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `list_key_phrases_detection_jobs`, also returning the metadata of the response.
+    pub async fn list_key_phrases_detection_jobs_with_metadata(
+        &self,
+        input: ListKeyPhrasesDetectionJobsRequest,
+    ) -> Result<
+        (ListKeyPhrasesDetectionJobsResponse, ResponseMetadata),
+        RusotoError<ListKeyPhrasesDetectionJobsError>,
+    > {
+        let request = self.build_list_key_phrases_detection_jobs_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListEntityRecognizersResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ListKeyPhrasesDetectionJobsResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ListEntityRecognizersError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ListKeyPhrasesDetectionJobsError::from_response(response))
         }
     }
 
-    /// <p>Get a list of key phrase detection jobs that you have submitted.</p>
-    async fn list_key_phrases_detection_jobs(
+    /// Presigns the request `list_key_phrases_detection_jobs` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_key_phrases_detection_jobs(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListKeyPhrasesDetectionJobsRequest,
-    ) -> Result<ListKeyPhrasesDetectionJobsResponse, RusotoError<ListKeyPhrasesDetectionJobsError>>
-    {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_key_phrases_detection_jobs_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_key_phrases_detection_jobs_request(
+        &self,
+        input: ListKeyPhrasesDetectionJobsRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "comprehend", &self.region, "/");
+        request.set_operation("ListKeyPhrasesDetectionJobs");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -6874,29 +8408,58 @@ impl Comprehend for ComprehendClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `list_sentiment_detection_jobs`, also returning the metadata of the response.
+    pub async fn list_sentiment_detection_jobs_with_metadata(
+        &self,
+        input: ListSentimentDetectionJobsRequest,
+    ) -> Result<
+        (ListSentimentDetectionJobsResponse, ResponseMetadata),
+        RusotoError<ListSentimentDetectionJobsError>,
+    > {
+        let request = self.build_list_sentiment_detection_jobs_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListKeyPhrasesDetectionJobsResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ListSentimentDetectionJobsResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ListKeyPhrasesDetectionJobsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ListSentimentDetectionJobsError::from_response(response))
         }
     }
 
-    /// <p>Gets a list of sentiment detection jobs that you have submitted.</p>
-    async fn list_sentiment_detection_jobs(
+    /// Presigns the request `list_sentiment_detection_jobs` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_sentiment_detection_jobs(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListSentimentDetectionJobsRequest,
-    ) -> Result<ListSentimentDetectionJobsResponse, RusotoError<ListSentimentDetectionJobsError>>
-    {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_sentiment_detection_jobs_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_sentiment_detection_jobs_request(
+        &self,
+        input: ListSentimentDetectionJobsRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "comprehend", &self.region, "/");
+        request.set_operation("ListSentimentDetectionJobs");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -6906,56 +8469,116 @@ impl Comprehend for ComprehendClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `list_tags_for_resource`, also returning the metadata of the response.
+    pub async fn list_tags_for_resource_with_metadata(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> Result<
+        (ListTagsForResourceResponse, ResponseMetadata),
+        RusotoError<ListTagsForResourceError>,
+    > {
+        let request = self.build_list_tags_for_resource_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListSentimentDetectionJobsResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ListTagsForResourceResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ListSentimentDetectionJobsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ListTagsForResourceError::from_response(response))
         }
     }
 
-    /// <p>Lists all tags associated with a given Amazon Comprehend resource. </p>
-    async fn list_tags_for_resource(
+    /// Presigns the request `list_tags_for_resource` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_tags_for_resource(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListTagsForResourceRequest,
-    ) -> Result<ListTagsForResourceResponse, RusotoError<ListTagsForResourceError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_tags_for_resource_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_tags_for_resource_request(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "comprehend", &self.region, "/");
+        request.set_operation("ListTagsForResource");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Comprehend_20171127.ListTagsForResource");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `list_topics_detection_jobs`, also returning the metadata of the response.
+    pub async fn list_topics_detection_jobs_with_metadata(
+        &self,
+        input: ListTopicsDetectionJobsRequest,
+    ) -> Result<
+        (ListTopicsDetectionJobsResponse, ResponseMetadata),
+        RusotoError<ListTopicsDetectionJobsError>,
+    > {
+        let request = self.build_list_topics_detection_jobs_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListTagsForResourceResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ListTopicsDetectionJobsResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ListTagsForResourceError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ListTopicsDetectionJobsError::from_response(response))
         }
     }
 
-    /// <p>Gets a list of the topic detection jobs that you have submitted.</p>
-    async fn list_topics_detection_jobs(
+    /// Presigns the request `list_topics_detection_jobs` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_topics_detection_jobs(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListTopicsDetectionJobsRequest,
-    ) -> Result<ListTopicsDetectionJobsResponse, RusotoError<ListTopicsDetectionJobsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_topics_detection_jobs_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_topics_detection_jobs_request(
+        &self,
+        input: ListTopicsDetectionJobsRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "comprehend", &self.region, "/");
+        request.set_operation("ListTopicsDetectionJobs");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -6965,31 +8588,58 @@ impl Comprehend for ComprehendClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `start_document_classification_job`, also returning the metadata of the response.
+    pub async fn start_document_classification_job_with_metadata(
+        &self,
+        input: StartDocumentClassificationJobRequest,
+    ) -> Result<
+        (StartDocumentClassificationJobResponse, ResponseMetadata),
+        RusotoError<StartDocumentClassificationJobError>,
+    > {
+        let request = self.build_start_document_classification_job_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListTopicsDetectionJobsResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<StartDocumentClassificationJobResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ListTopicsDetectionJobsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(StartDocumentClassificationJobError::from_response(response))
         }
     }
 
-    /// <p>Starts an asynchronous document classification job. Use the operation to track the progress of the job.</p>
-    async fn start_document_classification_job(
+    /// Presigns the request `start_document_classification_job` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_start_document_classification_job(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: StartDocumentClassificationJobRequest,
-    ) -> Result<
-        StartDocumentClassificationJobResponse,
-        RusotoError<StartDocumentClassificationJobError>,
-    > {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_start_document_classification_job_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_start_document_classification_job_request(
+        &self,
+        input: StartDocumentClassificationJobRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "comprehend", &self.region, "/");
+        request.set_operation("StartDocumentClassificationJob");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -6999,31 +8649,60 @@ impl Comprehend for ComprehendClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `start_dominant_language_detection_job`, also returning the metadata of the response.
+    pub async fn start_dominant_language_detection_job_with_metadata(
+        &self,
+        input: StartDominantLanguageDetectionJobRequest,
+    ) -> Result<
+        (StartDominantLanguageDetectionJobResponse, ResponseMetadata),
+        RusotoError<StartDominantLanguageDetectionJobError>,
+    > {
+        let request = self.build_start_dominant_language_detection_job_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<StartDocumentClassificationJobResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<StartDominantLanguageDetectionJobResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(StartDocumentClassificationJobError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(StartDominantLanguageDetectionJobError::from_response(
+                response,
+            ))
         }
     }
 
-    /// <p>Starts an asynchronous dominant language detection job for a collection of documents. Use the operation to track the status of a job.</p>
-    async fn start_dominant_language_detection_job(
+    /// Presigns the request `start_dominant_language_detection_job` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_start_dominant_language_detection_job(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: StartDominantLanguageDetectionJobRequest,
-    ) -> Result<
-        StartDominantLanguageDetectionJobResponse,
-        RusotoError<StartDominantLanguageDetectionJobError>,
-    > {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_start_dominant_language_detection_job_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_start_dominant_language_detection_job_request(
+        &self,
+        input: StartDominantLanguageDetectionJobRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "comprehend", &self.region, "/");
+        request.set_operation("StartDominantLanguageDetectionJob");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -7033,31 +8712,58 @@ impl Comprehend for ComprehendClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `start_entities_detection_job`, also returning the metadata of the response.
+    pub async fn start_entities_detection_job_with_metadata(
+        &self,
+        input: StartEntitiesDetectionJobRequest,
+    ) -> Result<
+        (StartEntitiesDetectionJobResponse, ResponseMetadata),
+        RusotoError<StartEntitiesDetectionJobError>,
+    > {
+        let request = self.build_start_entities_detection_job_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<StartDominantLanguageDetectionJobResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<StartEntitiesDetectionJobResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(StartDominantLanguageDetectionJobError::from_response(
-                response,
-            ))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(StartEntitiesDetectionJobError::from_response(response))
         }
     }
 
-    /// <p>Starts an asynchronous entity detection job for a collection of documents. Use the operation to track the status of a job.</p> <p>This API can be used for either standard entity detection or custom entity recognition. In order to be used for custom entity recognition, the optional <code>EntityRecognizerArn</code> must be used in order to provide access to the recognizer being used to detect the custom entity.</p>
-    async fn start_entities_detection_job(
+    /// Presigns the request `start_entities_detection_job` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_start_entities_detection_job(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: StartEntitiesDetectionJobRequest,
-    ) -> Result<StartEntitiesDetectionJobResponse, RusotoError<StartEntitiesDetectionJobError>>
-    {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_start_entities_detection_job_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_start_entities_detection_job_request(
+        &self,
+        input: StartEntitiesDetectionJobRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "comprehend", &self.region, "/");
+        request.set_operation("StartEntitiesDetectionJob");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -7067,29 +8773,58 @@ impl Comprehend for ComprehendClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `start_key_phrases_detection_job`, also returning the metadata of the response.
+    pub async fn start_key_phrases_detection_job_with_metadata(
+        &self,
+        input: StartKeyPhrasesDetectionJobRequest,
+    ) -> Result<
+        (StartKeyPhrasesDetectionJobResponse, ResponseMetadata),
+        RusotoError<StartKeyPhrasesDetectionJobError>,
+    > {
+        let request = self.build_start_key_phrases_detection_job_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<StartEntitiesDetectionJobResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<StartKeyPhrasesDetectionJobResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(StartEntitiesDetectionJobError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(StartKeyPhrasesDetectionJobError::from_response(response))
         }
     }
 
-    /// <p>Starts an asynchronous key phrase detection job for a collection of documents. Use the operation to track the status of a job.</p>
-    async fn start_key_phrases_detection_job(
+    /// Presigns the request `start_key_phrases_detection_job` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_start_key_phrases_detection_job(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: StartKeyPhrasesDetectionJobRequest,
-    ) -> Result<StartKeyPhrasesDetectionJobResponse, RusotoError<StartKeyPhrasesDetectionJobError>>
-    {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_start_key_phrases_detection_job_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_start_key_phrases_detection_job_request(
+        &self,
+        input: StartKeyPhrasesDetectionJobRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "comprehend", &self.region, "/");
+        request.set_operation("StartKeyPhrasesDetectionJob");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -7099,29 +8834,58 @@ impl Comprehend for ComprehendClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `start_sentiment_detection_job`, also returning the metadata of the response.
+    pub async fn start_sentiment_detection_job_with_metadata(
+        &self,
+        input: StartSentimentDetectionJobRequest,
+    ) -> Result<
+        (StartSentimentDetectionJobResponse, ResponseMetadata),
+        RusotoError<StartSentimentDetectionJobError>,
+    > {
+        let request = self.build_start_sentiment_detection_job_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<StartKeyPhrasesDetectionJobResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<StartSentimentDetectionJobResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(StartKeyPhrasesDetectionJobError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(StartSentimentDetectionJobError::from_response(response))
         }
     }
 
-    /// <p>Starts an asynchronous sentiment detection job for a collection of documents. use the operation to track the status of a job.</p>
-    async fn start_sentiment_detection_job(
+    /// Presigns the request `start_sentiment_detection_job` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_start_sentiment_detection_job(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: StartSentimentDetectionJobRequest,
-    ) -> Result<StartSentimentDetectionJobResponse, RusotoError<StartSentimentDetectionJobError>>
-    {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_start_sentiment_detection_job_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_start_sentiment_detection_job_request(
+        &self,
+        input: StartSentimentDetectionJobRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "comprehend", &self.region, "/");
+        request.set_operation("StartSentimentDetectionJob");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -7131,28 +8895,58 @@ impl Comprehend for ComprehendClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `start_topics_detection_job`, also returning the metadata of the response.
+    pub async fn start_topics_detection_job_with_metadata(
+        &self,
+        input: StartTopicsDetectionJobRequest,
+    ) -> Result<
+        (StartTopicsDetectionJobResponse, ResponseMetadata),
+        RusotoError<StartTopicsDetectionJobError>,
+    > {
+        let request = self.build_start_topics_detection_job_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<StartSentimentDetectionJobResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<StartTopicsDetectionJobResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(StartSentimentDetectionJobError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(StartTopicsDetectionJobError::from_response(response))
         }
     }
 
-    /// <p>Starts an asynchronous topic detection job. Use the <code>DescribeTopicDetectionJob</code> operation to track the status of a job.</p>
-    async fn start_topics_detection_job(
+    /// Presigns the request `start_topics_detection_job` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_start_topics_detection_job(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: StartTopicsDetectionJobRequest,
-    ) -> Result<StartTopicsDetectionJobResponse, RusotoError<StartTopicsDetectionJobError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_start_topics_detection_job_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_start_topics_detection_job_request(
+        &self,
+        input: StartTopicsDetectionJobRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "comprehend", &self.region, "/");
+        request.set_operation("StartTopicsDetectionJob");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -7162,31 +8956,60 @@ impl Comprehend for ComprehendClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `stop_dominant_language_detection_job`, also returning the metadata of the response.
+    pub async fn stop_dominant_language_detection_job_with_metadata(
+        &self,
+        input: StopDominantLanguageDetectionJobRequest,
+    ) -> Result<
+        (StopDominantLanguageDetectionJobResponse, ResponseMetadata),
+        RusotoError<StopDominantLanguageDetectionJobError>,
+    > {
+        let request = self.build_stop_dominant_language_detection_job_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<StartTopicsDetectionJobResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<StopDominantLanguageDetectionJobResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(StartTopicsDetectionJobError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(StopDominantLanguageDetectionJobError::from_response(
+                response,
+            ))
         }
     }
 
-    /// <p>Stops a dominant language detection job in progress.</p> <p>If the job state is <code>IN_PROGRESS</code> the job is marked for termination and put into the <code>STOP_REQUESTED</code> state. If the job completes before it can be stopped, it is put into the <code>COMPLETED</code> state; otherwise the job is stopped and put into the <code>STOPPED</code> state.</p> <p>If the job is in the <code>COMPLETED</code> or <code>FAILED</code> state when you call the <code>StopDominantLanguageDetectionJob</code> operation, the operation returns a 400 Internal Request Exception. </p> <p>When a job is stopped, any documents already processed are written to the output location.</p>
-    async fn stop_dominant_language_detection_job(
+    /// Presigns the request `stop_dominant_language_detection_job` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_stop_dominant_language_detection_job(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: StopDominantLanguageDetectionJobRequest,
-    ) -> Result<
-        StopDominantLanguageDetectionJobResponse,
-        RusotoError<StopDominantLanguageDetectionJobError>,
-    > {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_stop_dominant_language_detection_job_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_stop_dominant_language_detection_job_request(
+        &self,
+        input: StopDominantLanguageDetectionJobRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "comprehend", &self.region, "/");
+        request.set_operation("StopDominantLanguageDetectionJob");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -7196,30 +9019,58 @@ impl Comprehend for ComprehendClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `stop_entities_detection_job`, also returning the metadata of the response.
+    pub async fn stop_entities_detection_job_with_metadata(
+        &self,
+        input: StopEntitiesDetectionJobRequest,
+    ) -> Result<
+        (StopEntitiesDetectionJobResponse, ResponseMetadata),
+        RusotoError<StopEntitiesDetectionJobError>,
+    > {
+        let request = self.build_stop_entities_detection_job_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<StopDominantLanguageDetectionJobResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<StopEntitiesDetectionJobResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(StopDominantLanguageDetectionJobError::from_response(
-                response,
-            ))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(StopEntitiesDetectionJobError::from_response(response))
         }
     }
 
-    /// <p>Stops an entities detection job in progress.</p> <p>If the job state is <code>IN_PROGRESS</code> the job is marked for termination and put into the <code>STOP_REQUESTED</code> state. If the job completes before it can be stopped, it is put into the <code>COMPLETED</code> state; otherwise the job is stopped and put into the <code>STOPPED</code> state.</p> <p>If the job is in the <code>COMPLETED</code> or <code>FAILED</code> state when you call the <code>StopDominantLanguageDetectionJob</code> operation, the operation returns a 400 Internal Request Exception. </p> <p>When a job is stopped, any documents already processed are written to the output location.</p>
-    async fn stop_entities_detection_job(
+    /// Presigns the request `stop_entities_detection_job` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_stop_entities_detection_job(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: StopEntitiesDetectionJobRequest,
-    ) -> Result<StopEntitiesDetectionJobResponse, RusotoError<StopEntitiesDetectionJobError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_stop_entities_detection_job_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_stop_entities_detection_job_request(
+        &self,
+        input: StopEntitiesDetectionJobRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "comprehend", &self.region, "/");
+        request.set_operation("StopEntitiesDetectionJob");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -7229,29 +9080,58 @@ impl Comprehend for ComprehendClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `stop_key_phrases_detection_job`, also returning the metadata of the response.
+    pub async fn stop_key_phrases_detection_job_with_metadata(
+        &self,
+        input: StopKeyPhrasesDetectionJobRequest,
+    ) -> Result<
+        (StopKeyPhrasesDetectionJobResponse, ResponseMetadata),
+        RusotoError<StopKeyPhrasesDetectionJobError>,
+    > {
+        let request = self.build_stop_key_phrases_detection_job_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<StopEntitiesDetectionJobResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<StopKeyPhrasesDetectionJobResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(StopEntitiesDetectionJobError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(StopKeyPhrasesDetectionJobError::from_response(response))
         }
     }
 
-    /// <p>Stops a key phrases detection job in progress.</p> <p>If the job state is <code>IN_PROGRESS</code> the job is marked for termination and put into the <code>STOP_REQUESTED</code> state. If the job completes before it can be stopped, it is put into the <code>COMPLETED</code> state; otherwise the job is stopped and put into the <code>STOPPED</code> state.</p> <p>If the job is in the <code>COMPLETED</code> or <code>FAILED</code> state when you call the <code>StopDominantLanguageDetectionJob</code> operation, the operation returns a 400 Internal Request Exception. </p> <p>When a job is stopped, any documents already processed are written to the output location.</p>
-    async fn stop_key_phrases_detection_job(
+    /// Presigns the request `stop_key_phrases_detection_job` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_stop_key_phrases_detection_job(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: StopKeyPhrasesDetectionJobRequest,
-    ) -> Result<StopKeyPhrasesDetectionJobResponse, RusotoError<StopKeyPhrasesDetectionJobError>>
-    {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_stop_key_phrases_detection_job_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_stop_key_phrases_detection_job_request(
+        &self,
+        input: StopKeyPhrasesDetectionJobRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "comprehend", &self.region, "/");
+        request.set_operation("StopKeyPhrasesDetectionJob");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -7261,29 +9141,58 @@ impl Comprehend for ComprehendClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `stop_sentiment_detection_job`, also returning the metadata of the response.
+    pub async fn stop_sentiment_detection_job_with_metadata(
+        &self,
+        input: StopSentimentDetectionJobRequest,
+    ) -> Result<
+        (StopSentimentDetectionJobResponse, ResponseMetadata),
+        RusotoError<StopSentimentDetectionJobError>,
+    > {
+        let request = self.build_stop_sentiment_detection_job_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<StopKeyPhrasesDetectionJobResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<StopSentimentDetectionJobResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(StopKeyPhrasesDetectionJobError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(StopSentimentDetectionJobError::from_response(response))
         }
     }
 
-    /// <p>Stops a sentiment detection job in progress.</p> <p>If the job state is <code>IN_PROGRESS</code> the job is marked for termination and put into the <code>STOP_REQUESTED</code> state. If the job completes before it can be stopped, it is put into the <code>COMPLETED</code> state; otherwise the job is be stopped and put into the <code>STOPPED</code> state.</p> <p>If the job is in the <code>COMPLETED</code> or <code>FAILED</code> state when you call the <code>StopDominantLanguageDetectionJob</code> operation, the operation returns a 400 Internal Request Exception. </p> <p>When a job is stopped, any documents already processed are written to the output location.</p>
-    async fn stop_sentiment_detection_job(
+    /// Presigns the request `stop_sentiment_detection_job` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_stop_sentiment_detection_job(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: StopSentimentDetectionJobRequest,
-    ) -> Result<StopSentimentDetectionJobResponse, RusotoError<StopSentimentDetectionJobError>>
-    {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_stop_sentiment_detection_job_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_stop_sentiment_detection_job_request(
+        &self,
+        input: StopSentimentDetectionJobRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "comprehend", &self.region, "/");
+        request.set_operation("StopSentimentDetectionJob");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -7293,31 +9202,58 @@ impl Comprehend for ComprehendClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `stop_training_document_classifier`, also returning the metadata of the response.
+    pub async fn stop_training_document_classifier_with_metadata(
+        &self,
+        input: StopTrainingDocumentClassifierRequest,
+    ) -> Result<
+        (StopTrainingDocumentClassifierResponse, ResponseMetadata),
+        RusotoError<StopTrainingDocumentClassifierError>,
+    > {
+        let request = self.build_stop_training_document_classifier_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<StopSentimentDetectionJobResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<StopTrainingDocumentClassifierResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(StopSentimentDetectionJobError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(StopTrainingDocumentClassifierError::from_response(response))
         }
     }
 
-    /// <p>Stops a document classifier training job while in progress.</p> <p>If the training job state is <code>TRAINING</code>, the job is marked for termination and put into the <code>STOP_REQUESTED</code> state. If the training job completes before it can be stopped, it is put into the <code>TRAINED</code>; otherwise the training job is stopped and put into the <code>STOPPED</code> state and the service sends back an HTTP 200 response with an empty HTTP body. </p>
-    async fn stop_training_document_classifier(
+    /// Presigns the request `stop_training_document_classifier` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_stop_training_document_classifier(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: StopTrainingDocumentClassifierRequest,
-    ) -> Result<
-        StopTrainingDocumentClassifierResponse,
-        RusotoError<StopTrainingDocumentClassifierError>,
-    > {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_stop_training_document_classifier_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_stop_training_document_classifier_request(
+        &self,
+        input: StopTrainingDocumentClassifierRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "comprehend", &self.region, "/");
+        request.set_operation("StopTrainingDocumentClassifier");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -7327,29 +9263,58 @@ impl Comprehend for ComprehendClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `stop_training_entity_recognizer`, also returning the metadata of the response.
+    pub async fn stop_training_entity_recognizer_with_metadata(
+        &self,
+        input: StopTrainingEntityRecognizerRequest,
+    ) -> Result<
+        (StopTrainingEntityRecognizerResponse, ResponseMetadata),
+        RusotoError<StopTrainingEntityRecognizerError>,
+    > {
+        let request = self.build_stop_training_entity_recognizer_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<StopTrainingDocumentClassifierResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<StopTrainingEntityRecognizerResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(StopTrainingDocumentClassifierError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(StopTrainingEntityRecognizerError::from_response(response))
         }
     }
 
-    /// <p>Stops an entity recognizer training job while in progress.</p> <p>If the training job state is <code>TRAINING</code>, the job is marked for termination and put into the <code>STOP_REQUESTED</code> state. If the training job completes before it can be stopped, it is put into the <code>TRAINED</code>; otherwise the training job is stopped and putted into the <code>STOPPED</code> state and the service sends back an HTTP 200 response with an empty HTTP body.</p>
-    async fn stop_training_entity_recognizer(
+    /// Presigns the request `stop_training_entity_recognizer` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_stop_training_entity_recognizer(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: StopTrainingEntityRecognizerRequest,
-    ) -> Result<StopTrainingEntityRecognizerResponse, RusotoError<StopTrainingEntityRecognizerError>>
-    {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_stop_training_entity_recognizer_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_stop_training_entity_recognizer_request(
+        &self,
+        input: StopTrainingEntityRecognizerRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "comprehend", &self.region, "/");
+        request.set_operation("StopTrainingEntityRecognizer");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -7359,100 +9324,1116 @@ impl Comprehend for ComprehendClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `tag_resource`, also returning the metadata of the response.
+    pub async fn tag_resource_with_metadata(
+        &self,
+        input: TagResourceRequest,
+    ) -> Result<(TagResourceResponse, ResponseMetadata), RusotoError<TagResourceError>> {
+        let request = self.build_tag_resource_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<StopTrainingEntityRecognizerResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<TagResourceResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(StopTrainingEntityRecognizerError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(TagResourceError::from_response(response))
         }
     }
 
-    /// <p>Associates a specific tag with an Amazon Comprehend resource. A tag is a key-value pair that adds as a metadata to a resource used by Amazon Comprehend. For example, a tag with "Sales" as the key might be added to a resource to indicate its use by the sales department. </p>
-    async fn tag_resource(
+    /// Presigns the request `tag_resource` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_tag_resource(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: TagResourceRequest,
-    ) -> Result<TagResourceResponse, RusotoError<TagResourceError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_tag_resource_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_tag_resource_request(&self, input: TagResourceRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "comprehend", &self.region, "/");
+        request.set_operation("TagResource");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Comprehend_20171127.TagResource");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `untag_resource`, also returning the metadata of the response.
+    pub async fn untag_resource_with_metadata(
+        &self,
+        input: UntagResourceRequest,
+    ) -> Result<(UntagResourceResponse, ResponseMetadata), RusotoError<UntagResourceError>> {
+        let request = self.build_untag_resource_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<TagResourceResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<UntagResourceResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(TagResourceError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(UntagResourceError::from_response(response))
         }
     }
 
-    /// <p>Removes a specific tag associated with an Amazon Comprehend resource. </p>
-    async fn untag_resource(
+    /// Presigns the request `untag_resource` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_untag_resource(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UntagResourceRequest,
-    ) -> Result<UntagResourceResponse, RusotoError<UntagResourceError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_untag_resource_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_untag_resource_request(&self, input: UntagResourceRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "comprehend", &self.region, "/");
+        request.set_operation("UntagResource");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Comprehend_20171127.UntagResource");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `update_endpoint`, also returning the metadata of the response.
+    pub async fn update_endpoint_with_metadata(
+        &self,
+        input: UpdateEndpointRequest,
+    ) -> Result<(UpdateEndpointResponse, ResponseMetadata), RusotoError<UpdateEndpointError>> {
+        let request = self.build_update_endpoint_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<UntagResourceResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<UpdateEndpointResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(UntagResourceError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(UpdateEndpointError::from_response(response))
         }
     }
 
-    /// <p>Updates information about the specified endpoint.</p>
-    async fn update_endpoint(
+    /// Presigns the request `update_endpoint` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_endpoint(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UpdateEndpointRequest,
-    ) -> Result<UpdateEndpointResponse, RusotoError<UpdateEndpointError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_endpoint_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_endpoint_request(&self, input: UpdateEndpointRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "comprehend", &self.region, "/");
+        request.set_operation("UpdateEndpoint");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Comprehend_20171127.UpdateEndpoint");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
-        let mut response = self
-            .client
-            .sign_and_dispatch(request)
+        request
+    }
+
+    /// The pages of `ListDocumentClassificationJobs` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `MaxResults` of `input`.
+    pub fn list_document_classification_jobs_pages(
+        &self,
+        input: ListDocumentClassificationJobsRequest,
+    ) -> impl futures::Stream<
+        Item = Result<
+            ListDocumentClassificationJobsResponse,
+            RusotoError<ListDocumentClassificationJobsError>,
+        >,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: ComprehendClient, input| async move {
+                client.list_document_classification_jobs(input).await
+            },
+            |input: &ListDocumentClassificationJobsRequest,
+             output: &ListDocumentClassificationJobsResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListDocumentClassificationJobsRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `DocumentClassificationJobPropertiesList` of every page of `ListDocumentClassificationJobs` results for `input`, as `list_document_classification_jobs_pages` requests them.
+    pub fn list_document_classification_jobs_stream(
+        &self,
+        input: ListDocumentClassificationJobsRequest,
+    ) -> impl futures::Stream<
+        Item = Result<
+            DocumentClassificationJobProperties,
+            RusotoError<ListDocumentClassificationJobsError>,
+        >,
+    > + Send {
+        rusoto_core::pagination::items(
+            self.list_document_classification_jobs_pages(input),
+            |page| {
+                page.document_classification_job_properties_list
+                    .unwrap_or_default()
+            },
+        )
+    }
+
+    /// The pages of `ListDocumentClassifiers` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `MaxResults` of `input`.
+    pub fn list_document_classifiers_pages(
+        &self,
+        input: ListDocumentClassifiersRequest,
+    ) -> impl futures::Stream<
+        Item = Result<ListDocumentClassifiersResponse, RusotoError<ListDocumentClassifiersError>>,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: ComprehendClient, input| async move {
+                client.list_document_classifiers(input).await
+            },
+            |input: &ListDocumentClassifiersRequest, output: &ListDocumentClassifiersResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListDocumentClassifiersRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `DocumentClassifierPropertiesList` of every page of `ListDocumentClassifiers` results for `input`, as `list_document_classifiers_pages` requests them.
+    pub fn list_document_classifiers_stream(
+        &self,
+        input: ListDocumentClassifiersRequest,
+    ) -> impl futures::Stream<
+        Item = Result<DocumentClassifierProperties, RusotoError<ListDocumentClassifiersError>>,
+    > + Send {
+        rusoto_core::pagination::items(self.list_document_classifiers_pages(input), |page| {
+            page.document_classifier_properties_list.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `ListDominantLanguageDetectionJobs` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `MaxResults` of `input`.
+    pub fn list_dominant_language_detection_jobs_pages(
+        &self,
+        input: ListDominantLanguageDetectionJobsRequest,
+    ) -> impl futures::Stream<
+        Item = Result<
+            ListDominantLanguageDetectionJobsResponse,
+            RusotoError<ListDominantLanguageDetectionJobsError>,
+        >,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: ComprehendClient, input| async move {
+                client.list_dominant_language_detection_jobs(input).await
+            },
+            |input: &ListDominantLanguageDetectionJobsRequest,
+             output: &ListDominantLanguageDetectionJobsResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListDominantLanguageDetectionJobsRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `DominantLanguageDetectionJobPropertiesList` of every page of `ListDominantLanguageDetectionJobs` results for `input`, as `list_dominant_language_detection_jobs_pages` requests them.
+    pub fn list_dominant_language_detection_jobs_stream(
+        &self,
+        input: ListDominantLanguageDetectionJobsRequest,
+    ) -> impl futures::Stream<
+        Item = Result<
+            DominantLanguageDetectionJobProperties,
+            RusotoError<ListDominantLanguageDetectionJobsError>,
+        >,
+    > + Send {
+        rusoto_core::pagination::items(
+            self.list_dominant_language_detection_jobs_pages(input),
+            |page| {
+                page.dominant_language_detection_job_properties_list
+                    .unwrap_or_default()
+            },
+        )
+    }
+
+    /// The pages of `ListEndpoints` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `MaxResults` of `input`.
+    pub fn list_endpoints_pages(
+        &self,
+        input: ListEndpointsRequest,
+    ) -> impl futures::Stream<Item = Result<ListEndpointsResponse, RusotoError<ListEndpointsError>>> + Send
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: ComprehendClient, input| async move { client.list_endpoints(input).await },
+            |input: &ListEndpointsRequest, output: &ListEndpointsResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListEndpointsRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `EndpointPropertiesList` of every page of `ListEndpoints` results for `input`, as `list_endpoints_pages` requests them.
+    pub fn list_endpoints_stream(
+        &self,
+        input: ListEndpointsRequest,
+    ) -> impl futures::Stream<Item = Result<EndpointProperties, RusotoError<ListEndpointsError>>> + Send
+    {
+        rusoto_core::pagination::items(self.list_endpoints_pages(input), |page| {
+            page.endpoint_properties_list.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `ListEntitiesDetectionJobs` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `MaxResults` of `input`.
+    pub fn list_entities_detection_jobs_pages(
+        &self,
+        input: ListEntitiesDetectionJobsRequest,
+    ) -> impl futures::Stream<
+        Item = Result<
+            ListEntitiesDetectionJobsResponse,
+            RusotoError<ListEntitiesDetectionJobsError>,
+        >,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: ComprehendClient, input| async move {
+                client.list_entities_detection_jobs(input).await
+            },
+            |input: &ListEntitiesDetectionJobsRequest,
+             output: &ListEntitiesDetectionJobsResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListEntitiesDetectionJobsRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `EntitiesDetectionJobPropertiesList` of every page of `ListEntitiesDetectionJobs` results for `input`, as `list_entities_detection_jobs_pages` requests them.
+    pub fn list_entities_detection_jobs_stream(
+        &self,
+        input: ListEntitiesDetectionJobsRequest,
+    ) -> impl futures::Stream<
+        Item = Result<EntitiesDetectionJobProperties, RusotoError<ListEntitiesDetectionJobsError>>,
+    > + Send {
+        rusoto_core::pagination::items(self.list_entities_detection_jobs_pages(input), |page| {
+            page.entities_detection_job_properties_list
+                .unwrap_or_default()
+        })
+    }
+
+    /// The pages of `ListEntityRecognizers` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `MaxResults` of `input`.
+    pub fn list_entity_recognizers_pages(
+        &self,
+        input: ListEntityRecognizersRequest,
+    ) -> impl futures::Stream<
+        Item = Result<ListEntityRecognizersResponse, RusotoError<ListEntityRecognizersError>>,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: ComprehendClient, input| async move { client.list_entity_recognizers(input).await },
+            |input: &ListEntityRecognizersRequest, output: &ListEntityRecognizersResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListEntityRecognizersRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `EntityRecognizerPropertiesList` of every page of `ListEntityRecognizers` results for `input`, as `list_entity_recognizers_pages` requests them.
+    pub fn list_entity_recognizers_stream(
+        &self,
+        input: ListEntityRecognizersRequest,
+    ) -> impl futures::Stream<
+        Item = Result<EntityRecognizerProperties, RusotoError<ListEntityRecognizersError>>,
+    > + Send {
+        rusoto_core::pagination::items(self.list_entity_recognizers_pages(input), |page| {
+            page.entity_recognizer_properties_list.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `ListKeyPhrasesDetectionJobs` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `MaxResults` of `input`.
+    pub fn list_key_phrases_detection_jobs_pages(
+        &self,
+        input: ListKeyPhrasesDetectionJobsRequest,
+    ) -> impl futures::Stream<
+        Item = Result<
+            ListKeyPhrasesDetectionJobsResponse,
+            RusotoError<ListKeyPhrasesDetectionJobsError>,
+        >,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: ComprehendClient, input| async move {
+                client.list_key_phrases_detection_jobs(input).await
+            },
+            |input: &ListKeyPhrasesDetectionJobsRequest,
+             output: &ListKeyPhrasesDetectionJobsResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListKeyPhrasesDetectionJobsRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `KeyPhrasesDetectionJobPropertiesList` of every page of `ListKeyPhrasesDetectionJobs` results for `input`, as `list_key_phrases_detection_jobs_pages` requests them.
+    pub fn list_key_phrases_detection_jobs_stream(
+        &self,
+        input: ListKeyPhrasesDetectionJobsRequest,
+    ) -> impl futures::Stream<
+        Item = Result<
+            KeyPhrasesDetectionJobProperties,
+            RusotoError<ListKeyPhrasesDetectionJobsError>,
+        >,
+    > + Send {
+        rusoto_core::pagination::items(self.list_key_phrases_detection_jobs_pages(input), |page| {
+            page.key_phrases_detection_job_properties_list
+                .unwrap_or_default()
+        })
+    }
+
+    /// The pages of `ListSentimentDetectionJobs` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `MaxResults` of `input`.
+    pub fn list_sentiment_detection_jobs_pages(
+        &self,
+        input: ListSentimentDetectionJobsRequest,
+    ) -> impl futures::Stream<
+        Item = Result<
+            ListSentimentDetectionJobsResponse,
+            RusotoError<ListSentimentDetectionJobsError>,
+        >,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: ComprehendClient, input| async move {
+                client.list_sentiment_detection_jobs(input).await
+            },
+            |input: &ListSentimentDetectionJobsRequest,
+             output: &ListSentimentDetectionJobsResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListSentimentDetectionJobsRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `SentimentDetectionJobPropertiesList` of every page of `ListSentimentDetectionJobs` results for `input`, as `list_sentiment_detection_jobs_pages` requests them.
+    pub fn list_sentiment_detection_jobs_stream(
+        &self,
+        input: ListSentimentDetectionJobsRequest,
+    ) -> impl futures::Stream<
+        Item = Result<
+            SentimentDetectionJobProperties,
+            RusotoError<ListSentimentDetectionJobsError>,
+        >,
+    > + Send {
+        rusoto_core::pagination::items(self.list_sentiment_detection_jobs_pages(input), |page| {
+            page.sentiment_detection_job_properties_list
+                .unwrap_or_default()
+        })
+    }
+
+    /// The pages of `ListTopicsDetectionJobs` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `MaxResults` of `input`.
+    pub fn list_topics_detection_jobs_pages(
+        &self,
+        input: ListTopicsDetectionJobsRequest,
+    ) -> impl futures::Stream<
+        Item = Result<ListTopicsDetectionJobsResponse, RusotoError<ListTopicsDetectionJobsError>>,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: ComprehendClient, input| async move {
+                client.list_topics_detection_jobs(input).await
+            },
+            |input: &ListTopicsDetectionJobsRequest, output: &ListTopicsDetectionJobsResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListTopicsDetectionJobsRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `TopicsDetectionJobPropertiesList` of every page of `ListTopicsDetectionJobs` results for `input`, as `list_topics_detection_jobs_pages` requests them.
+    pub fn list_topics_detection_jobs_stream(
+        &self,
+        input: ListTopicsDetectionJobsRequest,
+    ) -> impl futures::Stream<
+        Item = Result<TopicsDetectionJobProperties, RusotoError<ListTopicsDetectionJobsError>>,
+    > + Send {
+        rusoto_core::pagination::items(self.list_topics_detection_jobs_pages(input), |page| {
+            page.topics_detection_job_properties_list
+                .unwrap_or_default()
+        })
+    }
+}
+
+#[async_trait]
+impl Comprehend for ComprehendClient {
+    /// <p>Determines the dominant language of the input text for a batch of documents. For a list of languages that Amazon Comprehend can detect, see <a href="https://docs.aws.amazon.com/comprehend/latest/dg/how-languages.html">Amazon Comprehend Supported Languages</a>. </p>
+    async fn batch_detect_dominant_language(
+        &self,
+        input: BatchDetectDominantLanguageRequest,
+    ) -> Result<BatchDetectDominantLanguageResponse, RusotoError<BatchDetectDominantLanguageError>>
+    {
+        self.batch_detect_dominant_language_with_metadata(input)
             .await
-            .map_err(RusotoError::from)?;
-        if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<UpdateEndpointResponse, _>()
-        } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(UpdateEndpointError::from_response(response))
-        }
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Inspects the text of a batch of documents for named entities and returns information about them. For more information about named entities, see <a>how-entities</a> </p>
+    async fn batch_detect_entities(
+        &self,
+        input: BatchDetectEntitiesRequest,
+    ) -> Result<BatchDetectEntitiesResponse, RusotoError<BatchDetectEntitiesError>> {
+        self.batch_detect_entities_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Detects the key noun phrases found in a batch of documents.</p>
+    async fn batch_detect_key_phrases(
+        &self,
+        input: BatchDetectKeyPhrasesRequest,
+    ) -> Result<BatchDetectKeyPhrasesResponse, RusotoError<BatchDetectKeyPhrasesError>> {
+        self.batch_detect_key_phrases_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Inspects a batch of documents and returns an inference of the prevailing sentiment, <code>POSITIVE</code>, <code>NEUTRAL</code>, <code>MIXED</code>, or <code>NEGATIVE</code>, in each one.</p>
+    async fn batch_detect_sentiment(
+        &self,
+        input: BatchDetectSentimentRequest,
+    ) -> Result<BatchDetectSentimentResponse, RusotoError<BatchDetectSentimentError>> {
+        self.batch_detect_sentiment_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Inspects the text of a batch of documents for the syntax and part of speech of the words in the document and returns information about them. For more information, see <a>how-syntax</a>.</p>
+    async fn batch_detect_syntax(
+        &self,
+        input: BatchDetectSyntaxRequest,
+    ) -> Result<BatchDetectSyntaxResponse, RusotoError<BatchDetectSyntaxError>> {
+        self.batch_detect_syntax_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Creates a new document classification request to analyze a single document in real-time, using a previously created and trained custom model and an endpoint.</p>
+    async fn classify_document(
+        &self,
+        input: ClassifyDocumentRequest,
+    ) -> Result<ClassifyDocumentResponse, RusotoError<ClassifyDocumentError>> {
+        self.classify_document_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Creates a new document classifier that you can use to categorize documents. To create a classifier you provide a set of training documents that labeled with the categories that you want to use. After the classifier is trained you can use it to categorize a set of labeled documents into the categories. For more information, see <a>how-document-classification</a>.</p>
+    async fn create_document_classifier(
+        &self,
+        input: CreateDocumentClassifierRequest,
+    ) -> Result<CreateDocumentClassifierResponse, RusotoError<CreateDocumentClassifierError>> {
+        self.create_document_classifier_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Creates a model-specific endpoint for synchronous inference for a previously trained custom model </p>
+    async fn create_endpoint(
+        &self,
+        input: CreateEndpointRequest,
+    ) -> Result<CreateEndpointResponse, RusotoError<CreateEndpointError>> {
+        self.create_endpoint_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Creates an entity recognizer using submitted files. After your <code>CreateEntityRecognizer</code> request is submitted, you can check job status using the API. </p>
+    async fn create_entity_recognizer(
+        &self,
+        input: CreateEntityRecognizerRequest,
+    ) -> Result<CreateEntityRecognizerResponse, RusotoError<CreateEntityRecognizerError>> {
+        self.create_entity_recognizer_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes a previously created document classifier</p> <p>Only those classifiers that are in terminated states (IN_ERROR, TRAINED) will be deleted. If an active inference job is using the model, a <code>ResourceInUseException</code> will be returned.</p> <p>This is an asynchronous action that puts the classifier into a DELETING state, and it is then removed by a background job. Once removed, the classifier disappears from your account and is no longer available for use. </p>
+    async fn delete_document_classifier(
+        &self,
+        input: DeleteDocumentClassifierRequest,
+    ) -> Result<DeleteDocumentClassifierResponse, RusotoError<DeleteDocumentClassifierError>> {
+        self.delete_document_classifier_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes a model-specific endpoint for a previously-trained custom model. All endpoints must be deleted in order for the model to be deleted.</p>
+    async fn delete_endpoint(
+        &self,
+        input: DeleteEndpointRequest,
+    ) -> Result<DeleteEndpointResponse, RusotoError<DeleteEndpointError>> {
+        self.delete_endpoint_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes an entity recognizer.</p> <p>Only those recognizers that are in terminated states (IN_ERROR, TRAINED) will be deleted. If an active inference job is using the model, a <code>ResourceInUseException</code> will be returned.</p> <p>This is an asynchronous action that puts the recognizer into a DELETING state, and it is then removed by a background job. Once removed, the recognizer disappears from your account and is no longer available for use. </p>
+    async fn delete_entity_recognizer(
+        &self,
+        input: DeleteEntityRecognizerRequest,
+    ) -> Result<DeleteEntityRecognizerResponse, RusotoError<DeleteEntityRecognizerError>> {
+        self.delete_entity_recognizer_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Gets the properties associated with a document classification job. Use this operation to get the status of a classification job.</p>
+    async fn describe_document_classification_job(
+        &self,
+        input: DescribeDocumentClassificationJobRequest,
+    ) -> Result<
+        DescribeDocumentClassificationJobResponse,
+        RusotoError<DescribeDocumentClassificationJobError>,
+    > {
+        self.describe_document_classification_job_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Gets the properties associated with a document classifier.</p>
+    async fn describe_document_classifier(
+        &self,
+        input: DescribeDocumentClassifierRequest,
+    ) -> Result<DescribeDocumentClassifierResponse, RusotoError<DescribeDocumentClassifierError>>
+    {
+        self.describe_document_classifier_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Gets the properties associated with a dominant language detection job. Use this operation to get the status of a detection job.</p>
+    async fn describe_dominant_language_detection_job(
+        &self,
+        input: DescribeDominantLanguageDetectionJobRequest,
+    ) -> Result<
+        DescribeDominantLanguageDetectionJobResponse,
+        RusotoError<DescribeDominantLanguageDetectionJobError>,
+    > {
+        self.describe_dominant_language_detection_job_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Gets the properties associated with a specific endpoint. Use this operation to get the status of an endpoint.</p>
+    async fn describe_endpoint(
+        &self,
+        input: DescribeEndpointRequest,
+    ) -> Result<DescribeEndpointResponse, RusotoError<DescribeEndpointError>> {
+        self.describe_endpoint_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Gets the properties associated with an entities detection job. Use this operation to get the status of a detection job.</p>
+    async fn describe_entities_detection_job(
+        &self,
+        input: DescribeEntitiesDetectionJobRequest,
+    ) -> Result<DescribeEntitiesDetectionJobResponse, RusotoError<DescribeEntitiesDetectionJobError>>
+    {
+        self.describe_entities_detection_job_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Provides details about an entity recognizer including status, S3 buckets containing training data, recognizer metadata, metrics, and so on.</p>
+    async fn describe_entity_recognizer(
+        &self,
+        input: DescribeEntityRecognizerRequest,
+    ) -> Result<DescribeEntityRecognizerResponse, RusotoError<DescribeEntityRecognizerError>> {
+        self.describe_entity_recognizer_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Gets the properties associated with a key phrases detection job. Use this operation to get the status of a detection job.</p>
+    async fn describe_key_phrases_detection_job(
+        &self,
+        input: DescribeKeyPhrasesDetectionJobRequest,
+    ) -> Result<
+        DescribeKeyPhrasesDetectionJobResponse,
+        RusotoError<DescribeKeyPhrasesDetectionJobError>,
+    > {
+        self.describe_key_phrases_detection_job_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Gets the properties associated with a sentiment detection job. Use this operation to get the status of a detection job.</p>
+    async fn describe_sentiment_detection_job(
+        &self,
+        input: DescribeSentimentDetectionJobRequest,
+    ) -> Result<
+        DescribeSentimentDetectionJobResponse,
+        RusotoError<DescribeSentimentDetectionJobError>,
+    > {
+        self.describe_sentiment_detection_job_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Gets the properties associated with a topic detection job. Use this operation to get the status of a detection job.</p>
+    async fn describe_topics_detection_job(
+        &self,
+        input: DescribeTopicsDetectionJobRequest,
+    ) -> Result<DescribeTopicsDetectionJobResponse, RusotoError<DescribeTopicsDetectionJobError>>
+    {
+        self.describe_topics_detection_job_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Determines the dominant language of the input text. For a list of languages that Amazon Comprehend can detect, see <a href="https://docs.aws.amazon.com/comprehend/latest/dg/how-languages.html">Amazon Comprehend Supported Languages</a>. </p>
+    async fn detect_dominant_language(
+        &self,
+        input: DetectDominantLanguageRequest,
+    ) -> Result<DetectDominantLanguageResponse, RusotoError<DetectDominantLanguageError>> {
+        self.detect_dominant_language_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Inspects text for named entities, and returns information about them. For more information, about named entities, see <a>how-entities</a>. </p>
+    async fn detect_entities(
+        &self,
+        input: DetectEntitiesRequest,
+    ) -> Result<DetectEntitiesResponse, RusotoError<DetectEntitiesError>> {
+        self.detect_entities_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Detects the key noun phrases found in the text. </p>
+    async fn detect_key_phrases(
+        &self,
+        input: DetectKeyPhrasesRequest,
+    ) -> Result<DetectKeyPhrasesResponse, RusotoError<DetectKeyPhrasesError>> {
+        self.detect_key_phrases_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Inspects text and returns an inference of the prevailing sentiment (<code>POSITIVE</code>, <code>NEUTRAL</code>, <code>MIXED</code>, or <code>NEGATIVE</code>). </p>
+    async fn detect_sentiment(
+        &self,
+        input: DetectSentimentRequest,
+    ) -> Result<DetectSentimentResponse, RusotoError<DetectSentimentError>> {
+        self.detect_sentiment_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Inspects text for syntax and the part of speech of words in the document. For more information, <a>how-syntax</a>.</p>
+    async fn detect_syntax(
+        &self,
+        input: DetectSyntaxRequest,
+    ) -> Result<DetectSyntaxResponse, RusotoError<DetectSyntaxError>> {
+        self.detect_syntax_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Gets a list of the documentation classification jobs that you have submitted.</p>
+    async fn list_document_classification_jobs(
+        &self,
+        input: ListDocumentClassificationJobsRequest,
+    ) -> Result<
+        ListDocumentClassificationJobsResponse,
+        RusotoError<ListDocumentClassificationJobsError>,
+    > {
+        self.list_document_classification_jobs_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Gets a list of the document classifiers that you have created.</p>
+    async fn list_document_classifiers(
+        &self,
+        input: ListDocumentClassifiersRequest,
+    ) -> Result<ListDocumentClassifiersResponse, RusotoError<ListDocumentClassifiersError>> {
+        self.list_document_classifiers_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Gets a list of the dominant language detection jobs that you have submitted.</p>
+    async fn list_dominant_language_detection_jobs(
+        &self,
+        input: ListDominantLanguageDetectionJobsRequest,
+    ) -> Result<
+        ListDominantLanguageDetectionJobsResponse,
+        RusotoError<ListDominantLanguageDetectionJobsError>,
+    > {
+        self.list_dominant_language_detection_jobs_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Gets a list of all existing endpoints that you've created.</p>
+    async fn list_endpoints(
+        &self,
+        input: ListEndpointsRequest,
+    ) -> Result<ListEndpointsResponse, RusotoError<ListEndpointsError>> {
+        self.list_endpoints_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Gets a list of the entity detection jobs that you have submitted.</p>
+    async fn list_entities_detection_jobs(
+        &self,
+        input: ListEntitiesDetectionJobsRequest,
+    ) -> Result<ListEntitiesDetectionJobsResponse, RusotoError<ListEntitiesDetectionJobsError>>
+    {
+        self.list_entities_detection_jobs_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Gets a list of the properties of all entity recognizers that you created, including recognizers currently in training. Allows you to filter the list of recognizers based on criteria such as status and submission time. This call returns up to 500 entity recognizers in the list, with a default number of 100 recognizers in the list.</p> <p>The results of this list are not in any particular order. Please get the list and sort locally if needed.</p>
+    async fn list_entity_recognizers(
+        &self,
+        input: ListEntityRecognizersRequest,
+    ) -> Result<ListEntityRecognizersResponse, RusotoError<ListEntityRecognizersError>> {
+        self.list_entity_recognizers_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Get a list of key phrase detection jobs that you have submitted.</p>
+    async fn list_key_phrases_detection_jobs(
+        &self,
+        input: ListKeyPhrasesDetectionJobsRequest,
+    ) -> Result<ListKeyPhrasesDetectionJobsResponse, RusotoError<ListKeyPhrasesDetectionJobsError>>
+    {
+        self.list_key_phrases_detection_jobs_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Gets a list of sentiment detection jobs that you have submitted.</p>
+    async fn list_sentiment_detection_jobs(
+        &self,
+        input: ListSentimentDetectionJobsRequest,
+    ) -> Result<ListSentimentDetectionJobsResponse, RusotoError<ListSentimentDetectionJobsError>>
+    {
+        self.list_sentiment_detection_jobs_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Lists all tags associated with a given Amazon Comprehend resource. </p>
+    async fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> Result<ListTagsForResourceResponse, RusotoError<ListTagsForResourceError>> {
+        self.list_tags_for_resource_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Gets a list of the topic detection jobs that you have submitted.</p>
+    async fn list_topics_detection_jobs(
+        &self,
+        input: ListTopicsDetectionJobsRequest,
+    ) -> Result<ListTopicsDetectionJobsResponse, RusotoError<ListTopicsDetectionJobsError>> {
+        self.list_topics_detection_jobs_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Starts an asynchronous document classification job. Use the operation to track the progress of the job.</p>
+    async fn start_document_classification_job(
+        &self,
+        input: StartDocumentClassificationJobRequest,
+    ) -> Result<
+        StartDocumentClassificationJobResponse,
+        RusotoError<StartDocumentClassificationJobError>,
+    > {
+        self.start_document_classification_job_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Starts an asynchronous dominant language detection job for a collection of documents. Use the operation to track the status of a job.</p>
+    async fn start_dominant_language_detection_job(
+        &self,
+        input: StartDominantLanguageDetectionJobRequest,
+    ) -> Result<
+        StartDominantLanguageDetectionJobResponse,
+        RusotoError<StartDominantLanguageDetectionJobError>,
+    > {
+        self.start_dominant_language_detection_job_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Starts an asynchronous entity detection job for a collection of documents. Use the operation to track the status of a job.</p> <p>This API can be used for either standard entity detection or custom entity recognition. In order to be used for custom entity recognition, the optional <code>EntityRecognizerArn</code> must be used in order to provide access to the recognizer being used to detect the custom entity.</p>
+    async fn start_entities_detection_job(
+        &self,
+        input: StartEntitiesDetectionJobRequest,
+    ) -> Result<StartEntitiesDetectionJobResponse, RusotoError<StartEntitiesDetectionJobError>>
+    {
+        self.start_entities_detection_job_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Starts an asynchronous key phrase detection job for a collection of documents. Use the operation to track the status of a job.</p>
+    async fn start_key_phrases_detection_job(
+        &self,
+        input: StartKeyPhrasesDetectionJobRequest,
+    ) -> Result<StartKeyPhrasesDetectionJobResponse, RusotoError<StartKeyPhrasesDetectionJobError>>
+    {
+        self.start_key_phrases_detection_job_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Starts an asynchronous sentiment detection job for a collection of documents. use the operation to track the status of a job.</p>
+    async fn start_sentiment_detection_job(
+        &self,
+        input: StartSentimentDetectionJobRequest,
+    ) -> Result<StartSentimentDetectionJobResponse, RusotoError<StartSentimentDetectionJobError>>
+    {
+        self.start_sentiment_detection_job_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Starts an asynchronous topic detection job. Use the <code>DescribeTopicDetectionJob</code> operation to track the status of a job.</p>
+    async fn start_topics_detection_job(
+        &self,
+        input: StartTopicsDetectionJobRequest,
+    ) -> Result<StartTopicsDetectionJobResponse, RusotoError<StartTopicsDetectionJobError>> {
+        self.start_topics_detection_job_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Stops a dominant language detection job in progress.</p> <p>If the job state is <code>IN_PROGRESS</code> the job is marked for termination and put into the <code>STOP_REQUESTED</code> state. If the job completes before it can be stopped, it is put into the <code>COMPLETED</code> state; otherwise the job is stopped and put into the <code>STOPPED</code> state.</p> <p>If the job is in the <code>COMPLETED</code> or <code>FAILED</code> state when you call the <code>StopDominantLanguageDetectionJob</code> operation, the operation returns a 400 Internal Request Exception. </p> <p>When a job is stopped, any documents already processed are written to the output location.</p>
+    async fn stop_dominant_language_detection_job(
+        &self,
+        input: StopDominantLanguageDetectionJobRequest,
+    ) -> Result<
+        StopDominantLanguageDetectionJobResponse,
+        RusotoError<StopDominantLanguageDetectionJobError>,
+    > {
+        self.stop_dominant_language_detection_job_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Stops an entities detection job in progress.</p> <p>If the job state is <code>IN_PROGRESS</code> the job is marked for termination and put into the <code>STOP_REQUESTED</code> state. If the job completes before it can be stopped, it is put into the <code>COMPLETED</code> state; otherwise the job is stopped and put into the <code>STOPPED</code> state.</p> <p>If the job is in the <code>COMPLETED</code> or <code>FAILED</code> state when you call the <code>StopDominantLanguageDetectionJob</code> operation, the operation returns a 400 Internal Request Exception. </p> <p>When a job is stopped, any documents already processed are written to the output location.</p>
+    async fn stop_entities_detection_job(
+        &self,
+        input: StopEntitiesDetectionJobRequest,
+    ) -> Result<StopEntitiesDetectionJobResponse, RusotoError<StopEntitiesDetectionJobError>> {
+        self.stop_entities_detection_job_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Stops a key phrases detection job in progress.</p> <p>If the job state is <code>IN_PROGRESS</code> the job is marked for termination and put into the <code>STOP_REQUESTED</code> state. If the job completes before it can be stopped, it is put into the <code>COMPLETED</code> state; otherwise the job is stopped and put into the <code>STOPPED</code> state.</p> <p>If the job is in the <code>COMPLETED</code> or <code>FAILED</code> state when you call the <code>StopDominantLanguageDetectionJob</code> operation, the operation returns a 400 Internal Request Exception. </p> <p>When a job is stopped, any documents already processed are written to the output location.</p>
+    async fn stop_key_phrases_detection_job(
+        &self,
+        input: StopKeyPhrasesDetectionJobRequest,
+    ) -> Result<StopKeyPhrasesDetectionJobResponse, RusotoError<StopKeyPhrasesDetectionJobError>>
+    {
+        self.stop_key_phrases_detection_job_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Stops a sentiment detection job in progress.</p> <p>If the job state is <code>IN_PROGRESS</code> the job is marked for termination and put into the <code>STOP_REQUESTED</code> state. If the job completes before it can be stopped, it is put into the <code>COMPLETED</code> state; otherwise the job is be stopped and put into the <code>STOPPED</code> state.</p> <p>If the job is in the <code>COMPLETED</code> or <code>FAILED</code> state when you call the <code>StopDominantLanguageDetectionJob</code> operation, the operation returns a 400 Internal Request Exception. </p> <p>When a job is stopped, any documents already processed are written to the output location.</p>
+    async fn stop_sentiment_detection_job(
+        &self,
+        input: StopSentimentDetectionJobRequest,
+    ) -> Result<StopSentimentDetectionJobResponse, RusotoError<StopSentimentDetectionJobError>>
+    {
+        self.stop_sentiment_detection_job_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Stops a document classifier training job while in progress.</p> <p>If the training job state is <code>TRAINING</code>, the job is marked for termination and put into the <code>STOP_REQUESTED</code> state. If the training job completes before it can be stopped, it is put into the <code>TRAINED</code>; otherwise the training job is stopped and put into the <code>STOPPED</code> state and the service sends back an HTTP 200 response with an empty HTTP body. </p>
+    async fn stop_training_document_classifier(
+        &self,
+        input: StopTrainingDocumentClassifierRequest,
+    ) -> Result<
+        StopTrainingDocumentClassifierResponse,
+        RusotoError<StopTrainingDocumentClassifierError>,
+    > {
+        self.stop_training_document_classifier_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Stops an entity recognizer training job while in progress.</p> <p>If the training job state is <code>TRAINING</code>, the job is marked for termination and put into the <code>STOP_REQUESTED</code> state. If the training job completes before it can be stopped, it is put into the <code>TRAINED</code>; otherwise the training job is stopped and putted into the <code>STOPPED</code> state and the service sends back an HTTP 200 response with an empty HTTP body.</p>
+    async fn stop_training_entity_recognizer(
+        &self,
+        input: StopTrainingEntityRecognizerRequest,
+    ) -> Result<StopTrainingEntityRecognizerResponse, RusotoError<StopTrainingEntityRecognizerError>>
+    {
+        self.stop_training_entity_recognizer_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Associates a specific tag with an Amazon Comprehend resource. A tag is a key-value pair that adds as a metadata to a resource used by Amazon Comprehend. For example, a tag with "Sales" as the key might be added to a resource to indicate its use by the sales department. </p>
+    async fn tag_resource(
+        &self,
+        input: TagResourceRequest,
+    ) -> Result<TagResourceResponse, RusotoError<TagResourceError>> {
+        self.tag_resource_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Removes a specific tag associated with an Amazon Comprehend resource. </p>
+    async fn untag_resource(
+        &self,
+        input: UntagResourceRequest,
+    ) -> Result<UntagResourceResponse, RusotoError<UntagResourceError>> {
+        self.untag_resource_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Updates information about the specified endpoint.</p>
+    async fn update_endpoint(
+        &self,
+        input: UpdateEndpointRequest,
+    ) -> Result<UpdateEndpointResponse, RusotoError<UpdateEndpointError>> {
+        self.update_endpoint_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
     }
 }
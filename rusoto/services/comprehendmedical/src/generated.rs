@@ -16,8 +16,8 @@ use std::fmt;
 use async_trait::async_trait;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
-use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest, ResponseMetadata};
+use rusoto_core::{Client, ErrorMetadata, RusotoError};
 
 use rusoto_core::proto;
 use rusoto_core::signature::SignedRequest;
@@ -805,6 +805,7 @@ pub struct UnmappedAttribute {
 
 /// Errors returned by DescribeEntitiesDetectionV2Job
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeEntitiesDetectionV2JobError {
     /// <p> An internal server error occurred. Retry your request. </p>
     InternalServer(String),
@@ -814,6 +815,8 @@ pub enum DescribeEntitiesDetectionV2JobError {
     ResourceNotFound(String),
     /// <p> You have made too many requests within a short period of time. Wait for a short time and then try your request again. Contact customer support for more information about a service limit increase. </p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeEntitiesDetectionV2JobError {
@@ -823,27 +826,36 @@ impl DescribeEntitiesDetectionV2JobError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DescribeEntitiesDetectionV2JobError::InternalServer(err.msg),
+                        &res,
                     )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DescribeEntitiesDetectionV2JobError::InvalidRequest(err.msg),
+                        &res,
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DescribeEntitiesDetectionV2JobError::ResourceNotFound(err.msg),
+                        &res,
                     )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DescribeEntitiesDetectionV2JobError::TooManyRequests(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        DescribeEntitiesDetectionV2JobError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -865,12 +877,16 @@ impl fmt::Display for DescribeEntitiesDetectionV2JobError {
             DescribeEntitiesDetectionV2JobError::TooManyRequests(ref cause) => {
                 write!(f, "{}", cause)
             }
+            DescribeEntitiesDetectionV2JobError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for DescribeEntitiesDetectionV2JobError {}
 /// Errors returned by DescribePHIDetectionJob
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribePHIDetectionJobError {
     /// <p> An internal server error occurred. Retry your request. </p>
     InternalServer(String),
@@ -880,6 +896,8 @@ pub enum DescribePHIDetectionJobError {
     ResourceNotFound(String),
     /// <p> You have made too many requests within a short period of time. Wait for a short time and then try your request again. Contact customer support for more information about a service limit increase. </p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribePHIDetectionJobError {
@@ -887,27 +905,31 @@ impl DescribePHIDetectionJobError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(DescribePHIDetectionJobError::InternalServer(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribePHIDetectionJobError::InternalServer(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DescribePHIDetectionJobError::InvalidRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribePHIDetectionJobError::InvalidRequest(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribePHIDetectionJobError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribePHIDetectionJobError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(DescribePHIDetectionJobError::TooManyRequests(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribePHIDetectionJobError::TooManyRequests(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DescribePHIDetectionJobError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -921,12 +943,14 @@ impl fmt::Display for DescribePHIDetectionJobError {
             DescribePHIDetectionJobError::InvalidRequest(ref cause) => write!(f, "{}", cause),
             DescribePHIDetectionJobError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             DescribePHIDetectionJobError::TooManyRequests(ref cause) => write!(f, "{}", cause),
+            DescribePHIDetectionJobError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribePHIDetectionJobError {}
 /// Errors returned by DetectEntities
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DetectEntitiesError {
     /// <p> An internal server error occurred. Retry your request. </p>
     InternalServer(String),
@@ -940,6 +964,8 @@ pub enum DetectEntitiesError {
     TextSizeLimitExceeded(String),
     /// <p> You have made too many requests within a short period of time. Wait for a short time and then try your request again. Contact customer support for more information about a service limit increase. </p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DetectEntitiesError {
@@ -947,27 +973,37 @@ impl DetectEntitiesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(DetectEntitiesError::InternalServer(err.msg))
+                    return RusotoError::service(DetectEntitiesError::InternalServer(err.msg), &res)
                 }
                 "InvalidEncodingException" => {
-                    return RusotoError::Service(DetectEntitiesError::InvalidEncoding(err.msg))
+                    return RusotoError::service(
+                        DetectEntitiesError::InvalidEncoding(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DetectEntitiesError::InvalidRequest(err.msg))
+                    return RusotoError::service(DetectEntitiesError::InvalidRequest(err.msg), &res)
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(DetectEntitiesError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        DetectEntitiesError::ServiceUnavailable(err.msg),
+                        &res,
+                    )
                 }
                 "TextSizeLimitExceededException" => {
-                    return RusotoError::Service(DetectEntitiesError::TextSizeLimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DetectEntitiesError::TextSizeLimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(DetectEntitiesError::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        DetectEntitiesError::TooManyRequests(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DetectEntitiesError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -983,12 +1019,14 @@ impl fmt::Display for DetectEntitiesError {
             DetectEntitiesError::ServiceUnavailable(ref cause) => write!(f, "{}", cause),
             DetectEntitiesError::TextSizeLimitExceeded(ref cause) => write!(f, "{}", cause),
             DetectEntitiesError::TooManyRequests(ref cause) => write!(f, "{}", cause),
+            DetectEntitiesError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DetectEntitiesError {}
 /// Errors returned by DetectEntitiesV2
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DetectEntitiesV2Error {
     /// <p> An internal server error occurred. Retry your request. </p>
     InternalServer(String),
@@ -1002,6 +1040,8 @@ pub enum DetectEntitiesV2Error {
     TextSizeLimitExceeded(String),
     /// <p> You have made too many requests within a short period of time. Wait for a short time and then try your request again. Contact customer support for more information about a service limit increase. </p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DetectEntitiesV2Error {
@@ -1009,27 +1049,43 @@ impl DetectEntitiesV2Error {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(DetectEntitiesV2Error::InternalServer(err.msg))
+                    return RusotoError::service(
+                        DetectEntitiesV2Error::InternalServer(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidEncodingException" => {
-                    return RusotoError::Service(DetectEntitiesV2Error::InvalidEncoding(err.msg))
+                    return RusotoError::service(
+                        DetectEntitiesV2Error::InvalidEncoding(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DetectEntitiesV2Error::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        DetectEntitiesV2Error::InvalidRequest(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(DetectEntitiesV2Error::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        DetectEntitiesV2Error::ServiceUnavailable(err.msg),
+                        &res,
+                    )
                 }
                 "TextSizeLimitExceededException" => {
-                    return RusotoError::Service(DetectEntitiesV2Error::TextSizeLimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DetectEntitiesV2Error::TextSizeLimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(DetectEntitiesV2Error::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        DetectEntitiesV2Error::TooManyRequests(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DetectEntitiesV2Error::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1045,12 +1101,14 @@ impl fmt::Display for DetectEntitiesV2Error {
             DetectEntitiesV2Error::ServiceUnavailable(ref cause) => write!(f, "{}", cause),
             DetectEntitiesV2Error::TextSizeLimitExceeded(ref cause) => write!(f, "{}", cause),
             DetectEntitiesV2Error::TooManyRequests(ref cause) => write!(f, "{}", cause),
+            DetectEntitiesV2Error::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DetectEntitiesV2Error {}
 /// Errors returned by DetectPHI
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DetectPHIError {
     /// <p> An internal server error occurred. Retry your request. </p>
     InternalServer(String),
@@ -1064,6 +1122,8 @@ pub enum DetectPHIError {
     TextSizeLimitExceeded(String),
     /// <p> You have made too many requests within a short period of time. Wait for a short time and then try your request again. Contact customer support for more information about a service limit increase. </p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DetectPHIError {
@@ -1071,25 +1131,28 @@ impl DetectPHIError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(DetectPHIError::InternalServer(err.msg))
+                    return RusotoError::service(DetectPHIError::InternalServer(err.msg), &res)
                 }
                 "InvalidEncodingException" => {
-                    return RusotoError::Service(DetectPHIError::InvalidEncoding(err.msg))
+                    return RusotoError::service(DetectPHIError::InvalidEncoding(err.msg), &res)
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DetectPHIError::InvalidRequest(err.msg))
+                    return RusotoError::service(DetectPHIError::InvalidRequest(err.msg), &res)
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(DetectPHIError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(DetectPHIError::ServiceUnavailable(err.msg), &res)
                 }
                 "TextSizeLimitExceededException" => {
-                    return RusotoError::Service(DetectPHIError::TextSizeLimitExceeded(err.msg))
+                    return RusotoError::service(
+                        DetectPHIError::TextSizeLimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(DetectPHIError::TooManyRequests(err.msg))
+                    return RusotoError::service(DetectPHIError::TooManyRequests(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DetectPHIError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1105,12 +1168,14 @@ impl fmt::Display for DetectPHIError {
             DetectPHIError::ServiceUnavailable(ref cause) => write!(f, "{}", cause),
             DetectPHIError::TextSizeLimitExceeded(ref cause) => write!(f, "{}", cause),
             DetectPHIError::TooManyRequests(ref cause) => write!(f, "{}", cause),
+            DetectPHIError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DetectPHIError {}
 /// Errors returned by InferICD10CM
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum InferICD10CMError {
     /// <p> An internal server error occurred. Retry your request. </p>
     InternalServer(String),
@@ -1124,6 +1189,8 @@ pub enum InferICD10CMError {
     TextSizeLimitExceeded(String),
     /// <p> You have made too many requests within a short period of time. Wait for a short time and then try your request again. Contact customer support for more information about a service limit increase. </p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl InferICD10CMError {
@@ -1131,25 +1198,31 @@ impl InferICD10CMError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(InferICD10CMError::InternalServer(err.msg))
+                    return RusotoError::service(InferICD10CMError::InternalServer(err.msg), &res)
                 }
                 "InvalidEncodingException" => {
-                    return RusotoError::Service(InferICD10CMError::InvalidEncoding(err.msg))
+                    return RusotoError::service(InferICD10CMError::InvalidEncoding(err.msg), &res)
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(InferICD10CMError::InvalidRequest(err.msg))
+                    return RusotoError::service(InferICD10CMError::InvalidRequest(err.msg), &res)
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(InferICD10CMError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        InferICD10CMError::ServiceUnavailable(err.msg),
+                        &res,
+                    )
                 }
                 "TextSizeLimitExceededException" => {
-                    return RusotoError::Service(InferICD10CMError::TextSizeLimitExceeded(err.msg))
+                    return RusotoError::service(
+                        InferICD10CMError::TextSizeLimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(InferICD10CMError::TooManyRequests(err.msg))
+                    return RusotoError::service(InferICD10CMError::TooManyRequests(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(InferICD10CMError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1165,12 +1238,14 @@ impl fmt::Display for InferICD10CMError {
             InferICD10CMError::ServiceUnavailable(ref cause) => write!(f, "{}", cause),
             InferICD10CMError::TextSizeLimitExceeded(ref cause) => write!(f, "{}", cause),
             InferICD10CMError::TooManyRequests(ref cause) => write!(f, "{}", cause),
+            InferICD10CMError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for InferICD10CMError {}
 /// Errors returned by InferRxNorm
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum InferRxNormError {
     /// <p> An internal server error occurred. Retry your request. </p>
     InternalServer(String),
@@ -1184,6 +1259,8 @@ pub enum InferRxNormError {
     TextSizeLimitExceeded(String),
     /// <p> You have made too many requests within a short period of time. Wait for a short time and then try your request again. Contact customer support for more information about a service limit increase. </p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl InferRxNormError {
@@ -1191,25 +1268,31 @@ impl InferRxNormError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(InferRxNormError::InternalServer(err.msg))
+                    return RusotoError::service(InferRxNormError::InternalServer(err.msg), &res)
                 }
                 "InvalidEncodingException" => {
-                    return RusotoError::Service(InferRxNormError::InvalidEncoding(err.msg))
+                    return RusotoError::service(InferRxNormError::InvalidEncoding(err.msg), &res)
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(InferRxNormError::InvalidRequest(err.msg))
+                    return RusotoError::service(InferRxNormError::InvalidRequest(err.msg), &res)
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(InferRxNormError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        InferRxNormError::ServiceUnavailable(err.msg),
+                        &res,
+                    )
                 }
                 "TextSizeLimitExceededException" => {
-                    return RusotoError::Service(InferRxNormError::TextSizeLimitExceeded(err.msg))
+                    return RusotoError::service(
+                        InferRxNormError::TextSizeLimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(InferRxNormError::TooManyRequests(err.msg))
+                    return RusotoError::service(InferRxNormError::TooManyRequests(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(InferRxNormError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1225,12 +1308,14 @@ impl fmt::Display for InferRxNormError {
             InferRxNormError::ServiceUnavailable(ref cause) => write!(f, "{}", cause),
             InferRxNormError::TextSizeLimitExceeded(ref cause) => write!(f, "{}", cause),
             InferRxNormError::TooManyRequests(ref cause) => write!(f, "{}", cause),
+            InferRxNormError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for InferRxNormError {}
 /// Errors returned by ListEntitiesDetectionV2Jobs
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListEntitiesDetectionV2JobsError {
     /// <p> An internal server error occurred. Retry your request. </p>
     InternalServer(String),
@@ -1238,6 +1323,8 @@ pub enum ListEntitiesDetectionV2JobsError {
     InvalidRequest(String),
     /// <p> You have made too many requests within a short period of time. Wait for a short time and then try your request again. Contact customer support for more information about a service limit increase. </p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListEntitiesDetectionV2JobsError {
@@ -1247,22 +1334,30 @@ impl ListEntitiesDetectionV2JobsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(ListEntitiesDetectionV2JobsError::InternalServer(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListEntitiesDetectionV2JobsError::InternalServer(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(ListEntitiesDetectionV2JobsError::InvalidRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListEntitiesDetectionV2JobsError::InvalidRequest(err.msg),
+                        &res,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(ListEntitiesDetectionV2JobsError::TooManyRequests(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListEntitiesDetectionV2JobsError::TooManyRequests(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        ListEntitiesDetectionV2JobsError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -1275,12 +1370,14 @@ impl fmt::Display for ListEntitiesDetectionV2JobsError {
             ListEntitiesDetectionV2JobsError::InternalServer(ref cause) => write!(f, "{}", cause),
             ListEntitiesDetectionV2JobsError::InvalidRequest(ref cause) => write!(f, "{}", cause),
             ListEntitiesDetectionV2JobsError::TooManyRequests(ref cause) => write!(f, "{}", cause),
+            ListEntitiesDetectionV2JobsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListEntitiesDetectionV2JobsError {}
 /// Errors returned by ListPHIDetectionJobs
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListPHIDetectionJobsError {
     /// <p> An internal server error occurred. Retry your request. </p>
     InternalServer(String),
@@ -1288,6 +1385,8 @@ pub enum ListPHIDetectionJobsError {
     InvalidRequest(String),
     /// <p> You have made too many requests within a short period of time. Wait for a short time and then try your request again. Contact customer support for more information about a service limit increase. </p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListPHIDetectionJobsError {
@@ -1295,18 +1394,25 @@ impl ListPHIDetectionJobsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(ListPHIDetectionJobsError::InternalServer(err.msg))
+                    return RusotoError::service(
+                        ListPHIDetectionJobsError::InternalServer(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(ListPHIDetectionJobsError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        ListPHIDetectionJobsError::InvalidRequest(err.msg),
+                        &res,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(ListPHIDetectionJobsError::TooManyRequests(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListPHIDetectionJobsError::TooManyRequests(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListPHIDetectionJobsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1319,12 +1425,14 @@ impl fmt::Display for ListPHIDetectionJobsError {
             ListPHIDetectionJobsError::InternalServer(ref cause) => write!(f, "{}", cause),
             ListPHIDetectionJobsError::InvalidRequest(ref cause) => write!(f, "{}", cause),
             ListPHIDetectionJobsError::TooManyRequests(ref cause) => write!(f, "{}", cause),
+            ListPHIDetectionJobsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListPHIDetectionJobsError {}
 /// Errors returned by StartEntitiesDetectionV2Job
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum StartEntitiesDetectionV2JobError {
     /// <p> An internal server error occurred. Retry your request. </p>
     InternalServer(String),
@@ -1334,6 +1442,8 @@ pub enum StartEntitiesDetectionV2JobError {
     ResourceNotFound(String),
     /// <p> You have made too many requests within a short period of time. Wait for a short time and then try your request again. Contact customer support for more information about a service limit increase. </p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl StartEntitiesDetectionV2JobError {
@@ -1343,27 +1453,36 @@ impl StartEntitiesDetectionV2JobError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(StartEntitiesDetectionV2JobError::InternalServer(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        StartEntitiesDetectionV2JobError::InternalServer(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(StartEntitiesDetectionV2JobError::InvalidRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        StartEntitiesDetectionV2JobError::InvalidRequest(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         StartEntitiesDetectionV2JobError::ResourceNotFound(err.msg),
+                        &res,
                     )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(StartEntitiesDetectionV2JobError::TooManyRequests(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        StartEntitiesDetectionV2JobError::TooManyRequests(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        StartEntitiesDetectionV2JobError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -1377,12 +1496,14 @@ impl fmt::Display for StartEntitiesDetectionV2JobError {
             StartEntitiesDetectionV2JobError::InvalidRequest(ref cause) => write!(f, "{}", cause),
             StartEntitiesDetectionV2JobError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             StartEntitiesDetectionV2JobError::TooManyRequests(ref cause) => write!(f, "{}", cause),
+            StartEntitiesDetectionV2JobError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for StartEntitiesDetectionV2JobError {}
 /// Errors returned by StartPHIDetectionJob
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum StartPHIDetectionJobError {
     /// <p> An internal server error occurred. Retry your request. </p>
     InternalServer(String),
@@ -1392,6 +1513,8 @@ pub enum StartPHIDetectionJobError {
     ResourceNotFound(String),
     /// <p> You have made too many requests within a short period of time. Wait for a short time and then try your request again. Contact customer support for more information about a service limit increase. </p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl StartPHIDetectionJobError {
@@ -1399,23 +1522,31 @@ impl StartPHIDetectionJobError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(StartPHIDetectionJobError::InternalServer(err.msg))
+                    return RusotoError::service(
+                        StartPHIDetectionJobError::InternalServer(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(StartPHIDetectionJobError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        StartPHIDetectionJobError::InvalidRequest(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(StartPHIDetectionJobError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        StartPHIDetectionJobError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(StartPHIDetectionJobError::TooManyRequests(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        StartPHIDetectionJobError::TooManyRequests(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(StartPHIDetectionJobError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1429,12 +1560,14 @@ impl fmt::Display for StartPHIDetectionJobError {
             StartPHIDetectionJobError::InvalidRequest(ref cause) => write!(f, "{}", cause),
             StartPHIDetectionJobError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             StartPHIDetectionJobError::TooManyRequests(ref cause) => write!(f, "{}", cause),
+            StartPHIDetectionJobError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for StartPHIDetectionJobError {}
 /// Errors returned by StopEntitiesDetectionV2Job
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum StopEntitiesDetectionV2JobError {
     /// <p> An internal server error occurred. Retry your request. </p>
     InternalServer(String),
@@ -1442,6 +1575,8 @@ pub enum StopEntitiesDetectionV2JobError {
     InvalidRequest(String),
     /// <p>The resource identified by the specified Amazon Resource Name (ARN) was not found. Check the ARN and try your request again.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl StopEntitiesDetectionV2JobError {
@@ -1451,22 +1586,27 @@ impl StopEntitiesDetectionV2JobError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(StopEntitiesDetectionV2JobError::InternalServer(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        StopEntitiesDetectionV2JobError::InternalServer(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(StopEntitiesDetectionV2JobError::InvalidRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        StopEntitiesDetectionV2JobError::InvalidRequest(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(StopEntitiesDetectionV2JobError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        StopEntitiesDetectionV2JobError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(StopEntitiesDetectionV2JobError::Unhandled, &res)
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -1479,12 +1619,14 @@ impl fmt::Display for StopEntitiesDetectionV2JobError {
             StopEntitiesDetectionV2JobError::InternalServer(ref cause) => write!(f, "{}", cause),
             StopEntitiesDetectionV2JobError::InvalidRequest(ref cause) => write!(f, "{}", cause),
             StopEntitiesDetectionV2JobError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            StopEntitiesDetectionV2JobError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for StopEntitiesDetectionV2JobError {}
 /// Errors returned by StopPHIDetectionJob
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum StopPHIDetectionJobError {
     /// <p> An internal server error occurred. Retry your request. </p>
     InternalServer(String),
@@ -1492,6 +1634,8 @@ pub enum StopPHIDetectionJobError {
     InvalidRequest(String),
     /// <p>The resource identified by the specified Amazon Resource Name (ARN) was not found. Check the ARN and try your request again.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl StopPHIDetectionJobError {
@@ -1499,18 +1643,25 @@ impl StopPHIDetectionJobError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(StopPHIDetectionJobError::InternalServer(err.msg))
+                    return RusotoError::service(
+                        StopPHIDetectionJobError::InternalServer(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(StopPHIDetectionJobError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        StopPHIDetectionJobError::InvalidRequest(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(StopPHIDetectionJobError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        StopPHIDetectionJobError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(StopPHIDetectionJobError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1523,6 +1674,7 @@ impl fmt::Display for StopPHIDetectionJobError {
             StopPHIDetectionJobError::InternalServer(ref cause) => write!(f, "{}", cause),
             StopPHIDetectionJobError::InvalidRequest(ref cause) => write!(f, "{}", cause),
             StopPHIDetectionJobError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            StopPHIDetectionJobError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
@@ -1622,6 +1774,7 @@ impl ComprehendMedicalClient {
     /// Creates a client backed by the default tokio event loop.
     ///
     /// The client will use the default credentials provider and tls client.
+    #[cfg(not(target_arch = "wasm32"))]
     pub fn new(region: region::Region) -> ComprehendMedicalClient {
         ComprehendMedicalClient {
             client: Client::shared(),
@@ -1647,19 +1800,73 @@ impl ComprehendMedicalClient {
     pub fn new_with_client(client: Client, region: region::Region) -> ComprehendMedicalClient {
         ComprehendMedicalClient { client, region }
     }
+
+    /// Returns a builder of a client, setting any of its region, credentials provider,
+    /// dispatcher, retry policy, endpoint and timeouts.
+    pub fn builder() -> rusoto_core::ClientBuilder<ComprehendMedicalClient> {
+        rusoto_core::ClientBuilder::new(ComprehendMedicalClient::new_with_client)
+    }
+
+    /// Returns a clone of this client with the overrides of `config` applied to its
+    /// operations, sharing its connections.
+    pub fn customize(&self, config: &rusoto_core::OperationConfig) -> ComprehendMedicalClient {
+        ComprehendMedicalClient {
+            client: self.client.customize(config),
+            region: config.resolve_region(&self.region),
+        }
+    }
 }
 
-#[async_trait]
-impl ComprehendMedical for ComprehendMedicalClient {
-    /// <p>Gets the properties associated with a medical entities detection job. Use this operation to get the status of a detection job.</p>
-    async fn describe_entities_detection_v2_job(
+impl ComprehendMedicalClient {
+    /// Calls `describe_entities_detection_v2_job`, also returning the metadata of the response.
+    pub async fn describe_entities_detection_v2_job_with_metadata(
         &self,
         input: DescribeEntitiesDetectionV2JobRequest,
     ) -> Result<
-        DescribeEntitiesDetectionV2JobResponse,
+        (DescribeEntitiesDetectionV2JobResponse, ResponseMetadata),
         RusotoError<DescribeEntitiesDetectionV2JobError>,
     > {
+        let request = self.build_describe_entities_detection_v2_job_request(input);
+
+        let mut response = self
+            .client
+            .sign_and_dispatch(request)
+            .await
+            .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
+        if response.status.is_success() {
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DescribeEntitiesDetectionV2JobResponse, _>()?;
+            Ok((result, metadata))
+        } else {
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DescribeEntitiesDetectionV2JobError::from_response(response))
+        }
+    }
+
+    /// Presigns the request `describe_entities_detection_v2_job` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_entities_detection_v2_job(
+        &self,
+        presigner: &rusoto_core::Presigner,
+        input: DescribeEntitiesDetectionV2JobRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_entities_detection_v2_job_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_entities_detection_v2_job_request(
+        &self,
+        input: DescribeEntitiesDetectionV2JobRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "comprehendmedical", &self.region, "/");
+        request.set_operation("DescribeEntitiesDetectionV2Job");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -1669,28 +1876,58 @@ impl ComprehendMedical for ComprehendMedicalClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `describe_phi_detection_job`, also returning the metadata of the response.
+    pub async fn describe_phi_detection_job_with_metadata(
+        &self,
+        input: DescribePHIDetectionJobRequest,
+    ) -> Result<
+        (DescribePHIDetectionJobResponse, ResponseMetadata),
+        RusotoError<DescribePHIDetectionJobError>,
+    > {
+        let request = self.build_describe_phi_detection_job_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DescribeEntitiesDetectionV2JobResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DescribePHIDetectionJobResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeEntitiesDetectionV2JobError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DescribePHIDetectionJobError::from_response(response))
         }
     }
 
-    /// <p>Gets the properties associated with a protected health information (PHI) detection job. Use this operation to get the status of a detection job.</p>
-    async fn describe_phi_detection_job(
+    /// Presigns the request `describe_phi_detection_job` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_phi_detection_job(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribePHIDetectionJobRequest,
-    ) -> Result<DescribePHIDetectionJobResponse, RusotoError<DescribePHIDetectionJobError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_phi_detection_job_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_phi_detection_job_request(
+        &self,
+        input: DescribePHIDetectionJobRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "comprehendmedical", &self.region, "/");
+        request.set_operation("DescribePHIDetectionJob");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -1700,55 +1937,105 @@ impl ComprehendMedical for ComprehendMedicalClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `detect_entities`, also returning the metadata of the response.
+    pub async fn detect_entities_with_metadata(
+        &self,
+        input: DetectEntitiesRequest,
+    ) -> Result<(DetectEntitiesResponse, ResponseMetadata), RusotoError<DetectEntitiesError>> {
+        let request = self.build_detect_entities_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DescribePHIDetectionJobResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DetectEntitiesResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribePHIDetectionJobError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DetectEntitiesError::from_response(response))
         }
     }
 
-    /// <p>The <code>DetectEntities</code> operation is deprecated. You should use the <a>DetectEntitiesV2</a> operation instead.</p> <p> Inspects the clinical text for a variety of medical entities and returns specific information about them such as entity category, location, and confidence score on that information .</p>
-    async fn detect_entities(
+    /// Presigns the request `detect_entities` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_detect_entities(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DetectEntitiesRequest,
-    ) -> Result<DetectEntitiesResponse, RusotoError<DetectEntitiesError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_detect_entities_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_detect_entities_request(&self, input: DetectEntitiesRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "comprehendmedical", &self.region, "/");
+        request.set_operation("DetectEntities");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "ComprehendMedical_20181030.DetectEntities");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `detect_entities_v2`, also returning the metadata of the response.
+    pub async fn detect_entities_v2_with_metadata(
+        &self,
+        input: DetectEntitiesV2Request,
+    ) -> Result<(DetectEntitiesV2Response, ResponseMetadata), RusotoError<DetectEntitiesV2Error>>
+    {
+        let request = self.build_detect_entities_v2_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<DetectEntitiesResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DetectEntitiesV2Response, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DetectEntitiesError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DetectEntitiesV2Error::from_response(response))
         }
     }
 
-    /// <p>Inspects the clinical text for a variety of medical entities and returns specific information about them such as entity category, location, and confidence score on that information.</p> <p>The <code>DetectEntitiesV2</code> operation replaces the <a>DetectEntities</a> operation. This new action uses a different model for determining the entities in your medical text and changes the way that some entities are returned in the output. You should use the <code>DetectEntitiesV2</code> operation in all new applications.</p> <p>The <code>DetectEntitiesV2</code> operation returns the <code>Acuity</code> and <code>Direction</code> entities as attributes instead of types. </p>
-    async fn detect_entities_v2(
+    /// Presigns the request `detect_entities_v2` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_detect_entities_v2(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DetectEntitiesV2Request,
-    ) -> Result<DetectEntitiesV2Response, RusotoError<DetectEntitiesV2Error>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_detect_entities_v2_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_detect_entities_v2_request(&self, input: DetectEntitiesV2Request) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "comprehendmedical", &self.region, "/");
+        request.set_operation("DetectEntitiesV2");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -1758,110 +2045,214 @@ impl ComprehendMedical for ComprehendMedicalClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `detect_phi`, also returning the metadata of the response.
+    pub async fn detect_phi_with_metadata(
+        &self,
+        input: DetectPHIRequest,
+    ) -> Result<(DetectPHIResponse, ResponseMetadata), RusotoError<DetectPHIError>> {
+        let request = self.build_detect_phi_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DetectEntitiesV2Response, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DetectPHIResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DetectEntitiesV2Error::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DetectPHIError::from_response(response))
         }
     }
 
-    /// <p> Inspects the clinical text for protected health information (PHI) entities and entity category, location, and confidence score on that information.</p>
-    async fn detect_phi(
+    /// Presigns the request `detect_phi` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_detect_phi(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DetectPHIRequest,
-    ) -> Result<DetectPHIResponse, RusotoError<DetectPHIError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_detect_phi_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_detect_phi_request(&self, input: DetectPHIRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "comprehendmedical", &self.region, "/");
+        request.set_operation("DetectPHI");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "ComprehendMedical_20181030.DetectPHI");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `infer_icd10cm`, also returning the metadata of the response.
+    pub async fn infer_icd10cm_with_metadata(
+        &self,
+        input: InferICD10CMRequest,
+    ) -> Result<(InferICD10CMResponse, ResponseMetadata), RusotoError<InferICD10CMError>> {
+        let request = self.build_infer_icd10cm_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<DetectPHIResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<InferICD10CMResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DetectPHIError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(InferICD10CMError::from_response(response))
         }
     }
 
-    /// <p>InferICD10CM detects medical conditions as entities listed in a patient record and links those entities to normalized concept identifiers in the ICD-10-CM knowledge base from the Centers for Disease Control.</p>
-    async fn infer_icd10cm(
+    /// Presigns the request `infer_icd10cm` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_infer_icd10cm(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: InferICD10CMRequest,
-    ) -> Result<InferICD10CMResponse, RusotoError<InferICD10CMError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_infer_icd10cm_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_infer_icd10cm_request(&self, input: InferICD10CMRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "comprehendmedical", &self.region, "/");
+        request.set_operation("InferICD10CM");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "ComprehendMedical_20181030.InferICD10CM");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `infer_rx_norm`, also returning the metadata of the response.
+    pub async fn infer_rx_norm_with_metadata(
+        &self,
+        input: InferRxNormRequest,
+    ) -> Result<(InferRxNormResponse, ResponseMetadata), RusotoError<InferRxNormError>> {
+        let request = self.build_infer_rx_norm_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<InferICD10CMResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<InferRxNormResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(InferICD10CMError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(InferRxNormError::from_response(response))
         }
     }
 
-    /// <p>InferRxNorm detects medications as entities listed in a patient record and links to the normalized concept identifiers in the RxNorm database from the National Library of Medicine.</p>
-    async fn infer_rx_norm(
+    /// Presigns the request `infer_rx_norm` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_infer_rx_norm(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: InferRxNormRequest,
-    ) -> Result<InferRxNormResponse, RusotoError<InferRxNormError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_infer_rx_norm_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_infer_rx_norm_request(&self, input: InferRxNormRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "comprehendmedical", &self.region, "/");
+        request.set_operation("InferRxNorm");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "ComprehendMedical_20181030.InferRxNorm");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `list_entities_detection_v2_jobs`, also returning the metadata of the response.
+    pub async fn list_entities_detection_v2_jobs_with_metadata(
+        &self,
+        input: ListEntitiesDetectionV2JobsRequest,
+    ) -> Result<
+        (ListEntitiesDetectionV2JobsResponse, ResponseMetadata),
+        RusotoError<ListEntitiesDetectionV2JobsError>,
+    > {
+        let request = self.build_list_entities_detection_v2_jobs_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<InferRxNormResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ListEntitiesDetectionV2JobsResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(InferRxNormError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ListEntitiesDetectionV2JobsError::from_response(response))
         }
     }
 
-    /// <p>Gets a list of medical entity detection jobs that you have submitted.</p>
-    async fn list_entities_detection_v2_jobs(
+    /// Presigns the request `list_entities_detection_v2_jobs` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_entities_detection_v2_jobs(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListEntitiesDetectionV2JobsRequest,
-    ) -> Result<ListEntitiesDetectionV2JobsResponse, RusotoError<ListEntitiesDetectionV2JobsError>>
-    {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_entities_detection_v2_jobs_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_entities_detection_v2_jobs_request(
+        &self,
+        input: ListEntitiesDetectionV2JobsRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "comprehendmedical", &self.region, "/");
+        request.set_operation("ListEntitiesDetectionV2Jobs");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -1871,28 +2262,58 @@ impl ComprehendMedical for ComprehendMedicalClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `list_phi_detection_jobs`, also returning the metadata of the response.
+    pub async fn list_phi_detection_jobs_with_metadata(
+        &self,
+        input: ListPHIDetectionJobsRequest,
+    ) -> Result<
+        (ListPHIDetectionJobsResponse, ResponseMetadata),
+        RusotoError<ListPHIDetectionJobsError>,
+    > {
+        let request = self.build_list_phi_detection_jobs_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListEntitiesDetectionV2JobsResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ListPHIDetectionJobsResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ListEntitiesDetectionV2JobsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ListPHIDetectionJobsError::from_response(response))
         }
     }
 
-    /// <p>Gets a list of protected health information (PHI) detection jobs that you have submitted.</p>
-    async fn list_phi_detection_jobs(
+    /// Presigns the request `list_phi_detection_jobs` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_phi_detection_jobs(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListPHIDetectionJobsRequest,
-    ) -> Result<ListPHIDetectionJobsResponse, RusotoError<ListPHIDetectionJobsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_phi_detection_jobs_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_phi_detection_jobs_request(
+        &self,
+        input: ListPHIDetectionJobsRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "comprehendmedical", &self.region, "/");
+        request.set_operation("ListPHIDetectionJobs");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -1902,29 +2323,58 @@ impl ComprehendMedical for ComprehendMedicalClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `start_entities_detection_v2_job`, also returning the metadata of the response.
+    pub async fn start_entities_detection_v2_job_with_metadata(
+        &self,
+        input: StartEntitiesDetectionV2JobRequest,
+    ) -> Result<
+        (StartEntitiesDetectionV2JobResponse, ResponseMetadata),
+        RusotoError<StartEntitiesDetectionV2JobError>,
+    > {
+        let request = self.build_start_entities_detection_v2_job_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListPHIDetectionJobsResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<StartEntitiesDetectionV2JobResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ListPHIDetectionJobsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(StartEntitiesDetectionV2JobError::from_response(response))
         }
     }
 
-    /// <p>Starts an asynchronous medical entity detection job for a collection of documents. Use the <code>DescribeEntitiesDetectionV2Job</code> operation to track the status of a job.</p>
-    async fn start_entities_detection_v2_job(
+    /// Presigns the request `start_entities_detection_v2_job` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_start_entities_detection_v2_job(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: StartEntitiesDetectionV2JobRequest,
-    ) -> Result<StartEntitiesDetectionV2JobResponse, RusotoError<StartEntitiesDetectionV2JobError>>
-    {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_start_entities_detection_v2_job_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_start_entities_detection_v2_job_request(
+        &self,
+        input: StartEntitiesDetectionV2JobRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "comprehendmedical", &self.region, "/");
+        request.set_operation("StartEntitiesDetectionV2Job");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -1934,28 +2384,58 @@ impl ComprehendMedical for ComprehendMedicalClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `start_phi_detection_job`, also returning the metadata of the response.
+    pub async fn start_phi_detection_job_with_metadata(
+        &self,
+        input: StartPHIDetectionJobRequest,
+    ) -> Result<
+        (StartPHIDetectionJobResponse, ResponseMetadata),
+        RusotoError<StartPHIDetectionJobError>,
+    > {
+        let request = self.build_start_phi_detection_job_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<StartEntitiesDetectionV2JobResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<StartPHIDetectionJobResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(StartEntitiesDetectionV2JobError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(StartPHIDetectionJobError::from_response(response))
         }
     }
 
-    /// <p>Starts an asynchronous job to detect protected health information (PHI). Use the <code>DescribePHIDetectionJob</code> operation to track the status of a job.</p>
-    async fn start_phi_detection_job(
+    /// Presigns the request `start_phi_detection_job` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_start_phi_detection_job(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: StartPHIDetectionJobRequest,
-    ) -> Result<StartPHIDetectionJobResponse, RusotoError<StartPHIDetectionJobError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_start_phi_detection_job_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_start_phi_detection_job_request(
+        &self,
+        input: StartPHIDetectionJobRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "comprehendmedical", &self.region, "/");
+        request.set_operation("StartPHIDetectionJob");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -1965,29 +2445,58 @@ impl ComprehendMedical for ComprehendMedicalClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `stop_entities_detection_v2_job`, also returning the metadata of the response.
+    pub async fn stop_entities_detection_v2_job_with_metadata(
+        &self,
+        input: StopEntitiesDetectionV2JobRequest,
+    ) -> Result<
+        (StopEntitiesDetectionV2JobResponse, ResponseMetadata),
+        RusotoError<StopEntitiesDetectionV2JobError>,
+    > {
+        let request = self.build_stop_entities_detection_v2_job_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<StartPHIDetectionJobResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<StopEntitiesDetectionV2JobResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(StartPHIDetectionJobError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(StopEntitiesDetectionV2JobError::from_response(response))
         }
     }
 
-    /// <p>Stops a medical entities detection job in progress.</p>
-    async fn stop_entities_detection_v2_job(
+    /// Presigns the request `stop_entities_detection_v2_job` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_stop_entities_detection_v2_job(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: StopEntitiesDetectionV2JobRequest,
-    ) -> Result<StopEntitiesDetectionV2JobResponse, RusotoError<StopEntitiesDetectionV2JobError>>
-    {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_stop_entities_detection_v2_job_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_stop_entities_detection_v2_job_request(
+        &self,
+        input: StopEntitiesDetectionV2JobRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "comprehendmedical", &self.region, "/");
+        request.set_operation("StopEntitiesDetectionV2Job");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -1997,28 +2506,58 @@ impl ComprehendMedical for ComprehendMedicalClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `stop_phi_detection_job`, also returning the metadata of the response.
+    pub async fn stop_phi_detection_job_with_metadata(
+        &self,
+        input: StopPHIDetectionJobRequest,
+    ) -> Result<
+        (StopPHIDetectionJobResponse, ResponseMetadata),
+        RusotoError<StopPHIDetectionJobError>,
+    > {
+        let request = self.build_stop_phi_detection_job_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<StopEntitiesDetectionV2JobResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<StopPHIDetectionJobResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(StopEntitiesDetectionV2JobError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(StopPHIDetectionJobError::from_response(response))
         }
     }
 
-    /// <p>Stops a protected health information (PHI) detection job in progress.</p>
-    async fn stop_phi_detection_job(
+    /// Presigns the request `stop_phi_detection_job` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_stop_phi_detection_job(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: StopPHIDetectionJobRequest,
-    ) -> Result<StopPHIDetectionJobResponse, RusotoError<StopPHIDetectionJobError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_stop_phi_detection_job_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_stop_phi_detection_job_request(
+        &self,
+        input: StopPHIDetectionJobRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "comprehendmedical", &self.region, "/");
+        request.set_operation("StopPHIDetectionJob");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -2028,19 +2567,145 @@ impl ComprehendMedical for ComprehendMedicalClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
-        let mut response = self
-            .client
-            .sign_and_dispatch(request)
+        request
+    }
+}
+
+#[async_trait]
+impl ComprehendMedical for ComprehendMedicalClient {
+    /// <p>Gets the properties associated with a medical entities detection job. Use this operation to get the status of a detection job.</p>
+    async fn describe_entities_detection_v2_job(
+        &self,
+        input: DescribeEntitiesDetectionV2JobRequest,
+    ) -> Result<
+        DescribeEntitiesDetectionV2JobResponse,
+        RusotoError<DescribeEntitiesDetectionV2JobError>,
+    > {
+        self.describe_entities_detection_v2_job_with_metadata(input)
             .await
-            .map_err(RusotoError::from)?;
-        if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<StopPHIDetectionJobResponse, _>()
-        } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(StopPHIDetectionJobError::from_response(response))
-        }
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Gets the properties associated with a protected health information (PHI) detection job. Use this operation to get the status of a detection job.</p>
+    async fn describe_phi_detection_job(
+        &self,
+        input: DescribePHIDetectionJobRequest,
+    ) -> Result<DescribePHIDetectionJobResponse, RusotoError<DescribePHIDetectionJobError>> {
+        self.describe_phi_detection_job_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>The <code>DetectEntities</code> operation is deprecated. You should use the <a>DetectEntitiesV2</a> operation instead.</p> <p> Inspects the clinical text for a variety of medical entities and returns specific information about them such as entity category, location, and confidence score on that information .</p>
+    async fn detect_entities(
+        &self,
+        input: DetectEntitiesRequest,
+    ) -> Result<DetectEntitiesResponse, RusotoError<DetectEntitiesError>> {
+        self.detect_entities_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Inspects the clinical text for a variety of medical entities and returns specific information about them such as entity category, location, and confidence score on that information.</p> <p>The <code>DetectEntitiesV2</code> operation replaces the <a>DetectEntities</a> operation. This new action uses a different model for determining the entities in your medical text and changes the way that some entities are returned in the output. You should use the <code>DetectEntitiesV2</code> operation in all new applications.</p> <p>The <code>DetectEntitiesV2</code> operation returns the <code>Acuity</code> and <code>Direction</code> entities as attributes instead of types. </p>
+    async fn detect_entities_v2(
+        &self,
+        input: DetectEntitiesV2Request,
+    ) -> Result<DetectEntitiesV2Response, RusotoError<DetectEntitiesV2Error>> {
+        self.detect_entities_v2_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p> Inspects the clinical text for protected health information (PHI) entities and entity category, location, and confidence score on that information.</p>
+    async fn detect_phi(
+        &self,
+        input: DetectPHIRequest,
+    ) -> Result<DetectPHIResponse, RusotoError<DetectPHIError>> {
+        self.detect_phi_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>InferICD10CM detects medical conditions as entities listed in a patient record and links those entities to normalized concept identifiers in the ICD-10-CM knowledge base from the Centers for Disease Control.</p>
+    async fn infer_icd10cm(
+        &self,
+        input: InferICD10CMRequest,
+    ) -> Result<InferICD10CMResponse, RusotoError<InferICD10CMError>> {
+        self.infer_icd10cm_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>InferRxNorm detects medications as entities listed in a patient record and links to the normalized concept identifiers in the RxNorm database from the National Library of Medicine.</p>
+    async fn infer_rx_norm(
+        &self,
+        input: InferRxNormRequest,
+    ) -> Result<InferRxNormResponse, RusotoError<InferRxNormError>> {
+        self.infer_rx_norm_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Gets a list of medical entity detection jobs that you have submitted.</p>
+    async fn list_entities_detection_v2_jobs(
+        &self,
+        input: ListEntitiesDetectionV2JobsRequest,
+    ) -> Result<ListEntitiesDetectionV2JobsResponse, RusotoError<ListEntitiesDetectionV2JobsError>>
+    {
+        self.list_entities_detection_v2_jobs_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Gets a list of protected health information (PHI) detection jobs that you have submitted.</p>
+    async fn list_phi_detection_jobs(
+        &self,
+        input: ListPHIDetectionJobsRequest,
+    ) -> Result<ListPHIDetectionJobsResponse, RusotoError<ListPHIDetectionJobsError>> {
+        self.list_phi_detection_jobs_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Starts an asynchronous medical entity detection job for a collection of documents. Use the <code>DescribeEntitiesDetectionV2Job</code> operation to track the status of a job.</p>
+    async fn start_entities_detection_v2_job(
+        &self,
+        input: StartEntitiesDetectionV2JobRequest,
+    ) -> Result<StartEntitiesDetectionV2JobResponse, RusotoError<StartEntitiesDetectionV2JobError>>
+    {
+        self.start_entities_detection_v2_job_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Starts an asynchronous job to detect protected health information (PHI). Use the <code>DescribePHIDetectionJob</code> operation to track the status of a job.</p>
+    async fn start_phi_detection_job(
+        &self,
+        input: StartPHIDetectionJobRequest,
+    ) -> Result<StartPHIDetectionJobResponse, RusotoError<StartPHIDetectionJobError>> {
+        self.start_phi_detection_job_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Stops a medical entities detection job in progress.</p>
+    async fn stop_entities_detection_v2_job(
+        &self,
+        input: StopEntitiesDetectionV2JobRequest,
+    ) -> Result<StopEntitiesDetectionV2JobResponse, RusotoError<StopEntitiesDetectionV2JobError>>
+    {
+        self.stop_entities_detection_v2_job_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Stops a protected health information (PHI) detection job in progress.</p>
+    async fn stop_phi_detection_job(
+        &self,
+        input: StopPHIDetectionJobRequest,
+    ) -> Result<StopPHIDetectionJobResponse, RusotoError<StopPHIDetectionJobError>> {
+        self.stop_phi_detection_job_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
     }
 }
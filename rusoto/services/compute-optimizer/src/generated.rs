@@ -16,8 +16,8 @@ use std::fmt;
 use async_trait::async_trait;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
-use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest, ResponseMetadata};
+use rusoto_core::{Client, ErrorMetadata, RusotoError};
 
 use rusoto_core::proto;
 use rusoto_core::signature::SignedRequest;
@@ -501,6 +501,7 @@ pub struct UtilizationMetric {
 
 /// Errors returned by GetAutoScalingGroupRecommendations
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetAutoScalingGroupRecommendationsError {
     /// <p>You do not have sufficient access to perform this action.</p>
     AccessDenied(String),
@@ -518,6 +519,8 @@ pub enum GetAutoScalingGroupRecommendationsError {
     ServiceUnavailable(String),
     /// <p>The limit on the number of requests per second was exceeded.</p>
     Throttling(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetAutoScalingGroupRecommendationsError {
@@ -527,49 +530,62 @@ impl GetAutoScalingGroupRecommendationsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         GetAutoScalingGroupRecommendationsError::AccessDenied(err.msg),
+                        &res,
                     )
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         GetAutoScalingGroupRecommendationsError::InternalServer(err.msg),
+                        &res,
                     )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         GetAutoScalingGroupRecommendationsError::InvalidParameterValue(err.msg),
+                        &res,
                     )
                 }
                 "MissingAuthenticationToken" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         GetAutoScalingGroupRecommendationsError::MissingAuthenticationToken(
                             err.msg,
                         ),
+                        &res,
                     )
                 }
                 "OptInRequiredException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         GetAutoScalingGroupRecommendationsError::OptInRequired(err.msg),
+                        &res,
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         GetAutoScalingGroupRecommendationsError::ResourceNotFound(err.msg),
+                        &res,
                     )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         GetAutoScalingGroupRecommendationsError::ServiceUnavailable(err.msg),
+                        &res,
                     )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         GetAutoScalingGroupRecommendationsError::Throttling(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        GetAutoScalingGroupRecommendationsError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -603,12 +619,16 @@ impl fmt::Display for GetAutoScalingGroupRecommendationsError {
             GetAutoScalingGroupRecommendationsError::Throttling(ref cause) => {
                 write!(f, "{}", cause)
             }
+            GetAutoScalingGroupRecommendationsError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for GetAutoScalingGroupRecommendationsError {}
 /// Errors returned by GetEC2InstanceRecommendations
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetEC2InstanceRecommendationsError {
     /// <p>You do not have sufficient access to perform this action.</p>
     AccessDenied(String),
@@ -626,6 +646,8 @@ pub enum GetEC2InstanceRecommendationsError {
     ServiceUnavailable(String),
     /// <p>The limit on the number of requests per second was exceeded.</p>
     Throttling(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetEC2InstanceRecommendationsError {
@@ -635,47 +657,60 @@ impl GetEC2InstanceRecommendationsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(GetEC2InstanceRecommendationsError::AccessDenied(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetEC2InstanceRecommendationsError::AccessDenied(err.msg),
+                        &res,
+                    )
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         GetEC2InstanceRecommendationsError::InternalServer(err.msg),
+                        &res,
                     )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         GetEC2InstanceRecommendationsError::InvalidParameterValue(err.msg),
+                        &res,
                     )
                 }
                 "MissingAuthenticationToken" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         GetEC2InstanceRecommendationsError::MissingAuthenticationToken(err.msg),
+                        &res,
                     )
                 }
                 "OptInRequiredException" => {
-                    return RusotoError::Service(GetEC2InstanceRecommendationsError::OptInRequired(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetEC2InstanceRecommendationsError::OptInRequired(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         GetEC2InstanceRecommendationsError::ResourceNotFound(err.msg),
+                        &res,
                     )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         GetEC2InstanceRecommendationsError::ServiceUnavailable(err.msg),
+                        &res,
                     )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(GetEC2InstanceRecommendationsError::Throttling(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetEC2InstanceRecommendationsError::Throttling(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        GetEC2InstanceRecommendationsError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -701,12 +736,16 @@ impl fmt::Display for GetEC2InstanceRecommendationsError {
                 write!(f, "{}", cause)
             }
             GetEC2InstanceRecommendationsError::Throttling(ref cause) => write!(f, "{}", cause),
+            GetEC2InstanceRecommendationsError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for GetEC2InstanceRecommendationsError {}
 /// Errors returned by GetEC2RecommendationProjectedMetrics
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetEC2RecommendationProjectedMetricsError {
     /// <p>You do not have sufficient access to perform this action.</p>
     AccessDenied(String),
@@ -724,6 +763,8 @@ pub enum GetEC2RecommendationProjectedMetricsError {
     ServiceUnavailable(String),
     /// <p>The limit on the number of requests per second was exceeded.</p>
     Throttling(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetEC2RecommendationProjectedMetricsError {
@@ -733,49 +774,62 @@ impl GetEC2RecommendationProjectedMetricsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         GetEC2RecommendationProjectedMetricsError::AccessDenied(err.msg),
+                        &res,
                     )
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         GetEC2RecommendationProjectedMetricsError::InternalServer(err.msg),
+                        &res,
                     )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         GetEC2RecommendationProjectedMetricsError::InvalidParameterValue(err.msg),
+                        &res,
                     )
                 }
                 "MissingAuthenticationToken" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         GetEC2RecommendationProjectedMetricsError::MissingAuthenticationToken(
                             err.msg,
                         ),
+                        &res,
                     )
                 }
                 "OptInRequiredException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         GetEC2RecommendationProjectedMetricsError::OptInRequired(err.msg),
+                        &res,
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         GetEC2RecommendationProjectedMetricsError::ResourceNotFound(err.msg),
+                        &res,
                     )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         GetEC2RecommendationProjectedMetricsError::ServiceUnavailable(err.msg),
+                        &res,
                     )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         GetEC2RecommendationProjectedMetricsError::Throttling(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        GetEC2RecommendationProjectedMetricsError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -809,12 +863,16 @@ impl fmt::Display for GetEC2RecommendationProjectedMetricsError {
             GetEC2RecommendationProjectedMetricsError::Throttling(ref cause) => {
                 write!(f, "{}", cause)
             }
+            GetEC2RecommendationProjectedMetricsError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for GetEC2RecommendationProjectedMetricsError {}
 /// Errors returned by GetEnrollmentStatus
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetEnrollmentStatusError {
     /// <p>You do not have sufficient access to perform this action.</p>
     AccessDenied(String),
@@ -828,6 +886,8 @@ pub enum GetEnrollmentStatusError {
     ServiceUnavailable(String),
     /// <p>The limit on the number of requests per second was exceeded.</p>
     Throttling(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetEnrollmentStatusError {
@@ -835,31 +895,43 @@ impl GetEnrollmentStatusError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(GetEnrollmentStatusError::AccessDenied(err.msg))
+                    return RusotoError::service(
+                        GetEnrollmentStatusError::AccessDenied(err.msg),
+                        &res,
+                    )
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(GetEnrollmentStatusError::InternalServer(err.msg))
+                    return RusotoError::service(
+                        GetEnrollmentStatusError::InternalServer(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(GetEnrollmentStatusError::InvalidParameterValue(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetEnrollmentStatusError::InvalidParameterValue(err.msg),
+                        &res,
+                    )
                 }
                 "MissingAuthenticationToken" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         GetEnrollmentStatusError::MissingAuthenticationToken(err.msg),
+                        &res,
                     )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(GetEnrollmentStatusError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetEnrollmentStatusError::ServiceUnavailable(err.msg),
+                        &res,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(GetEnrollmentStatusError::Throttling(err.msg))
+                    return RusotoError::service(
+                        GetEnrollmentStatusError::Throttling(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetEnrollmentStatusError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -877,12 +949,14 @@ impl fmt::Display for GetEnrollmentStatusError {
             }
             GetEnrollmentStatusError::ServiceUnavailable(ref cause) => write!(f, "{}", cause),
             GetEnrollmentStatusError::Throttling(ref cause) => write!(f, "{}", cause),
+            GetEnrollmentStatusError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetEnrollmentStatusError {}
 /// Errors returned by GetRecommendationSummaries
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetRecommendationSummariesError {
     /// <p>You do not have sufficient access to perform this action.</p>
     AccessDenied(String),
@@ -898,6 +972,8 @@ pub enum GetRecommendationSummariesError {
     ServiceUnavailable(String),
     /// <p>The limit on the number of requests per second was exceeded.</p>
     Throttling(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetRecommendationSummariesError {
@@ -907,42 +983,51 @@ impl GetRecommendationSummariesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(GetRecommendationSummariesError::AccessDenied(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetRecommendationSummariesError::AccessDenied(err.msg),
+                        &res,
+                    )
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(GetRecommendationSummariesError::InternalServer(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetRecommendationSummariesError::InternalServer(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         GetRecommendationSummariesError::InvalidParameterValue(err.msg),
+                        &res,
                     )
                 }
                 "MissingAuthenticationToken" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         GetRecommendationSummariesError::MissingAuthenticationToken(err.msg),
+                        &res,
                     )
                 }
                 "OptInRequiredException" => {
-                    return RusotoError::Service(GetRecommendationSummariesError::OptInRequired(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetRecommendationSummariesError::OptInRequired(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         GetRecommendationSummariesError::ServiceUnavailable(err.msg),
+                        &res,
                     )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(GetRecommendationSummariesError::Throttling(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetRecommendationSummariesError::Throttling(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(GetRecommendationSummariesError::Unhandled, &res)
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -965,12 +1050,14 @@ impl fmt::Display for GetRecommendationSummariesError {
                 write!(f, "{}", cause)
             }
             GetRecommendationSummariesError::Throttling(ref cause) => write!(f, "{}", cause),
+            GetRecommendationSummariesError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetRecommendationSummariesError {}
 /// Errors returned by UpdateEnrollmentStatus
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdateEnrollmentStatusError {
     /// <p>You do not have sufficient access to perform this action.</p>
     AccessDenied(String),
@@ -984,6 +1071,8 @@ pub enum UpdateEnrollmentStatusError {
     ServiceUnavailable(String),
     /// <p>The limit on the number of requests per second was exceeded.</p>
     Throttling(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdateEnrollmentStatusError {
@@ -991,33 +1080,43 @@ impl UpdateEnrollmentStatusError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(UpdateEnrollmentStatusError::AccessDenied(err.msg))
+                    return RusotoError::service(
+                        UpdateEnrollmentStatusError::AccessDenied(err.msg),
+                        &res,
+                    )
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(UpdateEnrollmentStatusError::InternalServer(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateEnrollmentStatusError::InternalServer(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         UpdateEnrollmentStatusError::InvalidParameterValue(err.msg),
+                        &res,
                     )
                 }
                 "MissingAuthenticationToken" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         UpdateEnrollmentStatusError::MissingAuthenticationToken(err.msg),
+                        &res,
                     )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(UpdateEnrollmentStatusError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateEnrollmentStatusError::ServiceUnavailable(err.msg),
+                        &res,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(UpdateEnrollmentStatusError::Throttling(err.msg))
+                    return RusotoError::service(
+                        UpdateEnrollmentStatusError::Throttling(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UpdateEnrollmentStatusError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1035,6 +1134,7 @@ impl fmt::Display for UpdateEnrollmentStatusError {
             }
             UpdateEnrollmentStatusError::ServiceUnavailable(ref cause) => write!(f, "{}", cause),
             UpdateEnrollmentStatusError::Throttling(ref cause) => write!(f, "{}", cause),
+            UpdateEnrollmentStatusError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
@@ -1097,6 +1197,7 @@ impl ComputeOptimizerClient {
     /// Creates a client backed by the default tokio event loop.
     ///
     /// The client will use the default credentials provider and tls client.
+    #[cfg(not(target_arch = "wasm32"))]
     pub fn new(region: region::Region) -> ComputeOptimizerClient {
         ComputeOptimizerClient {
             client: Client::shared(),
@@ -1122,19 +1223,75 @@ impl ComputeOptimizerClient {
     pub fn new_with_client(client: Client, region: region::Region) -> ComputeOptimizerClient {
         ComputeOptimizerClient { client, region }
     }
+
+    /// Returns a builder of a client, setting any of its region, credentials provider,
+    /// dispatcher, retry policy, endpoint and timeouts.
+    pub fn builder() -> rusoto_core::ClientBuilder<ComputeOptimizerClient> {
+        rusoto_core::ClientBuilder::new(ComputeOptimizerClient::new_with_client)
+    }
+
+    /// Returns a clone of this client with the overrides of `config` applied to its
+    /// operations, sharing its connections.
+    pub fn customize(&self, config: &rusoto_core::OperationConfig) -> ComputeOptimizerClient {
+        ComputeOptimizerClient {
+            client: self.client.customize(config),
+            region: config.resolve_region(&self.region),
+        }
+    }
 }
 
-#[async_trait]
-impl ComputeOptimizer for ComputeOptimizerClient {
-    /// <p>Returns Auto Scaling group recommendations.</p> <p>AWS Compute Optimizer currently generates recommendations for Auto Scaling groups that are configured to run instances of the M, C, R, T, and X instance families. The service does not generate recommendations for Auto Scaling groups that have a scaling policy attached to them, or that do not have the same values for desired, minimum, and maximum capacity. In order for Compute Optimizer to analyze your Auto Scaling groups, they must be of a fixed size. For more information, see the <a href="https://docs.aws.amazon.com/compute-optimizer/latest/ug/what-is.html">AWS Compute Optimizer User Guide</a>.</p>
-    async fn get_auto_scaling_group_recommendations(
+impl ComputeOptimizerClient {
+    /// Calls `get_auto_scaling_group_recommendations`, also returning the metadata of the response.
+    pub async fn get_auto_scaling_group_recommendations_with_metadata(
         &self,
         input: GetAutoScalingGroupRecommendationsRequest,
     ) -> Result<
-        GetAutoScalingGroupRecommendationsResponse,
+        (GetAutoScalingGroupRecommendationsResponse, ResponseMetadata),
         RusotoError<GetAutoScalingGroupRecommendationsError>,
     > {
+        let request = self.build_get_auto_scaling_group_recommendations_request(input);
+
+        let mut response = self
+            .client
+            .sign_and_dispatch(request)
+            .await
+            .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
+        if response.status.is_success() {
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<GetAutoScalingGroupRecommendationsResponse, _>()?;
+            Ok((result, metadata))
+        } else {
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(GetAutoScalingGroupRecommendationsError::from_response(
+                response,
+            ))
+        }
+    }
+
+    /// Presigns the request `get_auto_scaling_group_recommendations` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_auto_scaling_group_recommendations(
+        &self,
+        presigner: &rusoto_core::Presigner,
+        input: GetAutoScalingGroupRecommendationsRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_auto_scaling_group_recommendations_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_auto_scaling_group_recommendations_request(
+        &self,
+        input: GetAutoScalingGroupRecommendationsRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "compute-optimizer", &self.region, "/");
+        request.set_operation("GetAutoScalingGroupRecommendations");
 
         request.set_content_type("application/x-amz-json-1.0".to_owned());
         request.add_header(
@@ -1144,33 +1301,58 @@ impl ComputeOptimizer for ComputeOptimizerClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `get_ec2_instance_recommendations`, also returning the metadata of the response.
+    pub async fn get_ec2_instance_recommendations_with_metadata(
+        &self,
+        input: GetEC2InstanceRecommendationsRequest,
+    ) -> Result<
+        (GetEC2InstanceRecommendationsResponse, ResponseMetadata),
+        RusotoError<GetEC2InstanceRecommendationsError>,
+    > {
+        let request = self.build_get_ec2_instance_recommendations_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<GetAutoScalingGroupRecommendationsResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<GetEC2InstanceRecommendationsResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(GetAutoScalingGroupRecommendationsError::from_response(
-                response,
-            ))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(GetEC2InstanceRecommendationsError::from_response(response))
         }
     }
 
-    /// <p>Returns Amazon EC2 instance recommendations.</p> <p>AWS Compute Optimizer currently generates recommendations for Amazon Elastic Compute Cloud (Amazon EC2) and Amazon EC2 Auto Scaling. It generates recommendations for M, C, R, T, and X instance families. For more information, see the <a href="https://docs.aws.amazon.com/compute-optimizer/latest/ug/what-is.html">AWS Compute Optimizer User Guide</a>.</p>
-    async fn get_ec2_instance_recommendations(
+    /// Presigns the request `get_ec2_instance_recommendations` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_ec2_instance_recommendations(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetEC2InstanceRecommendationsRequest,
-    ) -> Result<
-        GetEC2InstanceRecommendationsResponse,
-        RusotoError<GetEC2InstanceRecommendationsError>,
-    > {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_ec2_instance_recommendations_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_ec2_instance_recommendations_request(
+        &self,
+        input: GetEC2InstanceRecommendationsRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "compute-optimizer", &self.region, "/");
+        request.set_operation("GetEC2InstanceRecommendations");
 
         request.set_content_type("application/x-amz-json-1.0".to_owned());
         request.add_header(
@@ -1180,31 +1362,63 @@ impl ComputeOptimizer for ComputeOptimizerClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `get_ec2_recommendation_projected_metrics`, also returning the metadata of the response.
+    pub async fn get_ec2_recommendation_projected_metrics_with_metadata(
+        &self,
+        input: GetEC2RecommendationProjectedMetricsRequest,
+    ) -> Result<
+        (
+            GetEC2RecommendationProjectedMetricsResponse,
+            ResponseMetadata,
+        ),
+        RusotoError<GetEC2RecommendationProjectedMetricsError>,
+    > {
+        let request = self.build_get_ec2_recommendation_projected_metrics_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<GetEC2InstanceRecommendationsResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<GetEC2RecommendationProjectedMetricsResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(GetEC2InstanceRecommendationsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(GetEC2RecommendationProjectedMetricsError::from_response(
+                response,
+            ))
         }
     }
 
-    /// <p>Returns the projected utilization metrics of Amazon EC2 instance recommendations.</p>
-    async fn get_ec2_recommendation_projected_metrics(
+    /// Presigns the request `get_ec2_recommendation_projected_metrics` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_ec2_recommendation_projected_metrics(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetEC2RecommendationProjectedMetricsRequest,
-    ) -> Result<
-        GetEC2RecommendationProjectedMetricsResponse,
-        RusotoError<GetEC2RecommendationProjectedMetricsError>,
-    > {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_ec2_recommendation_projected_metrics_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_ec2_recommendation_projected_metrics_request(
+        &self,
+        input: GetEC2RecommendationProjectedMetricsRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "compute-optimizer", &self.region, "/");
+        request.set_operation("GetEC2RecommendationProjectedMetrics");
 
         request.set_content_type("application/x-amz-json-1.0".to_owned());
         request.add_header(
@@ -1214,29 +1428,53 @@ impl ComputeOptimizer for ComputeOptimizerClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `get_enrollment_status`, also returning the metadata of the response.
+    pub async fn get_enrollment_status_with_metadata(
+        &self,
+    ) -> Result<
+        (GetEnrollmentStatusResponse, ResponseMetadata),
+        RusotoError<GetEnrollmentStatusError>,
+    > {
+        let request = self.build_get_enrollment_status_request();
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<GetEC2RecommendationProjectedMetricsResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<GetEnrollmentStatusResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(GetEC2RecommendationProjectedMetricsError::from_response(
-                response,
-            ))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(GetEnrollmentStatusError::from_response(response))
         }
     }
 
-    /// <p>Returns the enrollment (opt in) status of an account to the AWS Compute Optimizer service.</p> <p>If the account is a master account of an organization, this operation also confirms the enrollment status of member accounts within the organization.</p>
-    async fn get_enrollment_status(
+    /// Presigns the request `get_enrollment_status` would send with `presigner` instead of sending it.
+    pub async fn presign_get_enrollment_status(
         &self,
-    ) -> Result<GetEnrollmentStatusResponse, RusotoError<GetEnrollmentStatusError>> {
+        presigner: &rusoto_core::Presigner,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_enrollment_status_request();
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_enrollment_status_request(&self) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "compute-optimizer", &self.region, "/");
+        request.set_operation("GetEnrollmentStatus");
 
         request.set_content_type("application/x-amz-json-1.0".to_owned());
         request.add_header(
@@ -1245,29 +1483,58 @@ impl ComputeOptimizer for ComputeOptimizerClient {
         );
         request.set_payload(Some(bytes::Bytes::from_static(b"{}")));
 
+        request
+    }
+
+    /// Calls `get_recommendation_summaries`, also returning the metadata of the response.
+    pub async fn get_recommendation_summaries_with_metadata(
+        &self,
+        input: GetRecommendationSummariesRequest,
+    ) -> Result<
+        (GetRecommendationSummariesResponse, ResponseMetadata),
+        RusotoError<GetRecommendationSummariesError>,
+    > {
+        let request = self.build_get_recommendation_summaries_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<GetEnrollmentStatusResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<GetRecommendationSummariesResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(GetEnrollmentStatusError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(GetRecommendationSummariesError::from_response(response))
         }
     }
 
-    /// <p>Returns the optimization findings for an account.</p> <p>For example, it returns the number of Amazon EC2 instances in an account that are under-provisioned, over-provisioned, or optimized. It also returns the number of Auto Scaling groups in an account that are not optimized, or optimized.</p>
-    async fn get_recommendation_summaries(
+    /// Presigns the request `get_recommendation_summaries` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_recommendation_summaries(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetRecommendationSummariesRequest,
-    ) -> Result<GetRecommendationSummariesResponse, RusotoError<GetRecommendationSummariesError>>
-    {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_recommendation_summaries_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_recommendation_summaries_request(
+        &self,
+        input: GetRecommendationSummariesRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "compute-optimizer", &self.region, "/");
+        request.set_operation("GetRecommendationSummaries");
 
         request.set_content_type("application/x-amz-json-1.0".to_owned());
         request.add_header(
@@ -1277,28 +1544,58 @@ impl ComputeOptimizer for ComputeOptimizerClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `update_enrollment_status`, also returning the metadata of the response.
+    pub async fn update_enrollment_status_with_metadata(
+        &self,
+        input: UpdateEnrollmentStatusRequest,
+    ) -> Result<
+        (UpdateEnrollmentStatusResponse, ResponseMetadata),
+        RusotoError<UpdateEnrollmentStatusError>,
+    > {
+        let request = self.build_update_enrollment_status_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<GetRecommendationSummariesResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<UpdateEnrollmentStatusResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(GetRecommendationSummariesError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(UpdateEnrollmentStatusError::from_response(response))
         }
     }
 
-    /// <p>Updates the enrollment (opt in) status of an account to the AWS Compute Optimizer service.</p> <p>If the account is a master account of an organization, this operation can also enroll member accounts within the organization.</p>
-    async fn update_enrollment_status(
+    /// Presigns the request `update_enrollment_status` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_enrollment_status(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UpdateEnrollmentStatusRequest,
-    ) -> Result<UpdateEnrollmentStatusResponse, RusotoError<UpdateEnrollmentStatusError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_enrollment_status_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_enrollment_status_request(
+        &self,
+        input: UpdateEnrollmentStatusRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "compute-optimizer", &self.region, "/");
+        request.set_operation("UpdateEnrollmentStatus");
 
         request.set_content_type("application/x-amz-json-1.0".to_owned());
         request.add_header(
@@ -1308,19 +1605,122 @@ impl ComputeOptimizer for ComputeOptimizerClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
-        let mut response = self
-            .client
-            .sign_and_dispatch(request)
+        request
+    }
+
+    /// The pages of `GetRecommendationSummaries` results for `input`, requesting each next page with the `nextToken` of the last one as its `nextToken`.
+    /// The page size is the `maxResults` of `input`.
+    pub fn get_recommendation_summaries_pages(
+        &self,
+        input: GetRecommendationSummariesRequest,
+    ) -> impl futures::Stream<
+        Item = Result<
+            GetRecommendationSummariesResponse,
+            RusotoError<GetRecommendationSummariesError>,
+        >,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: ComputeOptimizerClient, input| async move {
+                client.get_recommendation_summaries(input).await
+            },
+            |input: &GetRecommendationSummariesRequest,
+             output: &GetRecommendationSummariesResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(GetRecommendationSummariesRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `recommendationSummaries` of every page of `GetRecommendationSummaries` results for `input`, as `get_recommendation_summaries_pages` requests them.
+    pub fn get_recommendation_summaries_stream(
+        &self,
+        input: GetRecommendationSummariesRequest,
+    ) -> impl futures::Stream<
+        Item = Result<RecommendationSummary, RusotoError<GetRecommendationSummariesError>>,
+    > + Send {
+        rusoto_core::pagination::items(self.get_recommendation_summaries_pages(input), |page| {
+            page.recommendation_summaries.unwrap_or_default()
+        })
+    }
+}
+
+#[async_trait]
+impl ComputeOptimizer for ComputeOptimizerClient {
+    /// <p>Returns Auto Scaling group recommendations.</p> <p>AWS Compute Optimizer currently generates recommendations for Auto Scaling groups that are configured to run instances of the M, C, R, T, and X instance families. The service does not generate recommendations for Auto Scaling groups that have a scaling policy attached to them, or that do not have the same values for desired, minimum, and maximum capacity. In order for Compute Optimizer to analyze your Auto Scaling groups, they must be of a fixed size. For more information, see the <a href="https://docs.aws.amazon.com/compute-optimizer/latest/ug/what-is.html">AWS Compute Optimizer User Guide</a>.</p>
+    async fn get_auto_scaling_group_recommendations(
+        &self,
+        input: GetAutoScalingGroupRecommendationsRequest,
+    ) -> Result<
+        GetAutoScalingGroupRecommendationsResponse,
+        RusotoError<GetAutoScalingGroupRecommendationsError>,
+    > {
+        self.get_auto_scaling_group_recommendations_with_metadata(input)
             .await
-            .map_err(RusotoError::from)?;
-        if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<UpdateEnrollmentStatusResponse, _>()
-        } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(UpdateEnrollmentStatusError::from_response(response))
-        }
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns Amazon EC2 instance recommendations.</p> <p>AWS Compute Optimizer currently generates recommendations for Amazon Elastic Compute Cloud (Amazon EC2) and Amazon EC2 Auto Scaling. It generates recommendations for M, C, R, T, and X instance families. For more information, see the <a href="https://docs.aws.amazon.com/compute-optimizer/latest/ug/what-is.html">AWS Compute Optimizer User Guide</a>.</p>
+    async fn get_ec2_instance_recommendations(
+        &self,
+        input: GetEC2InstanceRecommendationsRequest,
+    ) -> Result<
+        GetEC2InstanceRecommendationsResponse,
+        RusotoError<GetEC2InstanceRecommendationsError>,
+    > {
+        self.get_ec2_instance_recommendations_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns the projected utilization metrics of Amazon EC2 instance recommendations.</p>
+    async fn get_ec2_recommendation_projected_metrics(
+        &self,
+        input: GetEC2RecommendationProjectedMetricsRequest,
+    ) -> Result<
+        GetEC2RecommendationProjectedMetricsResponse,
+        RusotoError<GetEC2RecommendationProjectedMetricsError>,
+    > {
+        self.get_ec2_recommendation_projected_metrics_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns the enrollment (opt in) status of an account to the AWS Compute Optimizer service.</p> <p>If the account is a master account of an organization, this operation also confirms the enrollment status of member accounts within the organization.</p>
+    async fn get_enrollment_status(
+        &self,
+    ) -> Result<GetEnrollmentStatusResponse, RusotoError<GetEnrollmentStatusError>> {
+        self.get_enrollment_status_with_metadata()
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns the optimization findings for an account.</p> <p>For example, it returns the number of Amazon EC2 instances in an account that are under-provisioned, over-provisioned, or optimized. It also returns the number of Auto Scaling groups in an account that are not optimized, or optimized.</p>
+    async fn get_recommendation_summaries(
+        &self,
+        input: GetRecommendationSummariesRequest,
+    ) -> Result<GetRecommendationSummariesResponse, RusotoError<GetRecommendationSummariesError>>
+    {
+        self.get_recommendation_summaries_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Updates the enrollment (opt in) status of an account to the AWS Compute Optimizer service.</p> <p>If the account is a master account of an organization, this operation can also enroll member accounts within the organization.</p>
+    async fn update_enrollment_status(
+        &self,
+        input: UpdateEnrollmentStatusRequest,
+    ) -> Result<UpdateEnrollmentStatusResponse, RusotoError<UpdateEnrollmentStatusError>> {
+        self.update_enrollment_status_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
     }
 }
@@ -16,8 +16,8 @@ use std::fmt;
 use async_trait::async_trait;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
-use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest, ResponseMetadata};
+use rusoto_core::{Client, ErrorMetadata, RusotoError};
 
 use rusoto_core::proto;
 use rusoto_core::signature::SignedRequest;
@@ -3624,9 +3624,12 @@ pub struct UntagResourceRequest {
 
 /// Errors returned by BatchGetAggregateResourceConfig
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum BatchGetAggregateResourceConfigError {
     /// <p>You have specified a configuration aggregator that does not exist.</p>
     NoSuchConfigurationAggregator(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl BatchGetAggregateResourceConfigError {
@@ -3636,14 +3639,20 @@ impl BatchGetAggregateResourceConfigError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "NoSuchConfigurationAggregatorException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         BatchGetAggregateResourceConfigError::NoSuchConfigurationAggregator(
                             err.msg,
                         ),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        BatchGetAggregateResourceConfigError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -3656,15 +3665,21 @@ impl fmt::Display for BatchGetAggregateResourceConfigError {
             BatchGetAggregateResourceConfigError::NoSuchConfigurationAggregator(ref cause) => {
                 write!(f, "{}", cause)
             }
+            BatchGetAggregateResourceConfigError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for BatchGetAggregateResourceConfigError {}
 /// Errors returned by BatchGetResourceConfig
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum BatchGetResourceConfigError {
     /// <p>There are no configuration recorders available to provide the role needed to describe your resources. Create a configuration recorder.</p>
     NoAvailableConfigurationRecorder(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl BatchGetResourceConfigError {
@@ -3672,12 +3687,13 @@ impl BatchGetResourceConfigError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "NoAvailableConfigurationRecorderException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         BatchGetResourceConfigError::NoAvailableConfigurationRecorder(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(BatchGetResourceConfigError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3690,15 +3706,19 @@ impl fmt::Display for BatchGetResourceConfigError {
             BatchGetResourceConfigError::NoAvailableConfigurationRecorder(ref cause) => {
                 write!(f, "{}", cause)
             }
+            BatchGetResourceConfigError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for BatchGetResourceConfigError {}
 /// Errors returned by DeleteAggregationAuthorization
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteAggregationAuthorizationError {
     /// <p>One or more of the specified parameters are invalid. Verify that your parameters are valid and try again.</p>
     InvalidParameterValue(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteAggregationAuthorizationError {
@@ -3708,12 +3728,18 @@ impl DeleteAggregationAuthorizationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DeleteAggregationAuthorizationError::InvalidParameterValue(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        DeleteAggregationAuthorizationError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -3726,17 +3752,23 @@ impl fmt::Display for DeleteAggregationAuthorizationError {
             DeleteAggregationAuthorizationError::InvalidParameterValue(ref cause) => {
                 write!(f, "{}", cause)
             }
+            DeleteAggregationAuthorizationError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for DeleteAggregationAuthorizationError {}
 /// Errors returned by DeleteConfigRule
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteConfigRuleError {
     /// <p>One or more AWS Config rules in the request are invalid. Verify that the rule names are correct and try again.</p>
     NoSuchConfigRule(String),
     /// <p><p>You see this exception in the following cases: </p> <ul> <li> <p>For DeleteConfigRule, AWS Config is deleting this rule. Try your request again later.</p> </li> <li> <p>For DeleteConfigRule, the rule is deleting your evaluation results. Try your request again later.</p> </li> <li> <p>For DeleteConfigRule, a remediation action is associated with the rule and AWS Config cannot delete this rule. Delete the remediation action associated with the rule before deleting the rule and try your request again later.</p> </li> <li> <p>For PutConfigOrganizationRule, organization config rule deletion is in progress. Try your request again later.</p> </li> <li> <p>For DeleteOrganizationConfigRule, organization config rule creation is in progress. Try your request again later.</p> </li> <li> <p>For PutConformancePack and PutOrganizationConformancePack, a conformance pack creation, update, and deletion is in progress. Try your request again later.</p> </li> <li> <p>For DeleteConformancePack, a conformance pack creation, update, and deletion is in progress. Try your request again later.</p> </li> </ul></p>
     ResourceInUse(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteConfigRuleError {
@@ -3744,13 +3776,19 @@ impl DeleteConfigRuleError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "NoSuchConfigRuleException" => {
-                    return RusotoError::Service(DeleteConfigRuleError::NoSuchConfigRule(err.msg))
+                    return RusotoError::service(
+                        DeleteConfigRuleError::NoSuchConfigRule(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(DeleteConfigRuleError::ResourceInUse(err.msg))
+                    return RusotoError::service(
+                        DeleteConfigRuleError::ResourceInUse(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteConfigRuleError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3762,15 +3800,19 @@ impl fmt::Display for DeleteConfigRuleError {
         match *self {
             DeleteConfigRuleError::NoSuchConfigRule(ref cause) => write!(f, "{}", cause),
             DeleteConfigRuleError::ResourceInUse(ref cause) => write!(f, "{}", cause),
+            DeleteConfigRuleError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteConfigRuleError {}
 /// Errors returned by DeleteConfigurationAggregator
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteConfigurationAggregatorError {
     /// <p>You have specified a configuration aggregator that does not exist.</p>
     NoSuchConfigurationAggregator(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteConfigurationAggregatorError {
@@ -3780,12 +3822,18 @@ impl DeleteConfigurationAggregatorError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "NoSuchConfigurationAggregatorException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DeleteConfigurationAggregatorError::NoSuchConfigurationAggregator(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        DeleteConfigurationAggregatorError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -3798,15 +3846,21 @@ impl fmt::Display for DeleteConfigurationAggregatorError {
             DeleteConfigurationAggregatorError::NoSuchConfigurationAggregator(ref cause) => {
                 write!(f, "{}", cause)
             }
+            DeleteConfigurationAggregatorError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for DeleteConfigurationAggregatorError {}
 /// Errors returned by DeleteConfigurationRecorder
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteConfigurationRecorderError {
     /// <p>You have specified a configuration recorder that does not exist.</p>
     NoSuchConfigurationRecorder(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteConfigurationRecorderError {
@@ -3816,12 +3870,18 @@ impl DeleteConfigurationRecorderError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "NoSuchConfigurationRecorderException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DeleteConfigurationRecorderError::NoSuchConfigurationRecorder(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        DeleteConfigurationRecorderError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -3834,17 +3894,21 @@ impl fmt::Display for DeleteConfigurationRecorderError {
             DeleteConfigurationRecorderError::NoSuchConfigurationRecorder(ref cause) => {
                 write!(f, "{}", cause)
             }
+            DeleteConfigurationRecorderError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteConfigurationRecorderError {}
 /// Errors returned by DeleteConformancePack
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteConformancePackError {
     /// <p>You specified one or more conformance packs that do not exist.</p>
     NoSuchConformancePack(String),
     /// <p><p>You see this exception in the following cases: </p> <ul> <li> <p>For DeleteConfigRule, AWS Config is deleting this rule. Try your request again later.</p> </li> <li> <p>For DeleteConfigRule, the rule is deleting your evaluation results. Try your request again later.</p> </li> <li> <p>For DeleteConfigRule, a remediation action is associated with the rule and AWS Config cannot delete this rule. Delete the remediation action associated with the rule before deleting the rule and try your request again later.</p> </li> <li> <p>For PutConfigOrganizationRule, organization config rule deletion is in progress. Try your request again later.</p> </li> <li> <p>For DeleteOrganizationConfigRule, organization config rule creation is in progress. Try your request again later.</p> </li> <li> <p>For PutConformancePack and PutOrganizationConformancePack, a conformance pack creation, update, and deletion is in progress. Try your request again later.</p> </li> <li> <p>For DeleteConformancePack, a conformance pack creation, update, and deletion is in progress. Try your request again later.</p> </li> </ul></p>
     ResourceInUse(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteConformancePackError {
@@ -3852,15 +3916,19 @@ impl DeleteConformancePackError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "NoSuchConformancePackException" => {
-                    return RusotoError::Service(DeleteConformancePackError::NoSuchConformancePack(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteConformancePackError::NoSuchConformancePack(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(DeleteConformancePackError::ResourceInUse(err.msg))
+                    return RusotoError::service(
+                        DeleteConformancePackError::ResourceInUse(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteConformancePackError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3872,17 +3940,21 @@ impl fmt::Display for DeleteConformancePackError {
         match *self {
             DeleteConformancePackError::NoSuchConformancePack(ref cause) => write!(f, "{}", cause),
             DeleteConformancePackError::ResourceInUse(ref cause) => write!(f, "{}", cause),
+            DeleteConformancePackError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteConformancePackError {}
 /// Errors returned by DeleteDeliveryChannel
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteDeliveryChannelError {
     /// <p>You cannot delete the delivery channel you specified because the configuration recorder is running.</p>
     LastDeliveryChannelDeleteFailed(String),
     /// <p>You have specified a delivery channel that does not exist.</p>
     NoSuchDeliveryChannel(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteDeliveryChannelError {
@@ -3890,17 +3962,19 @@ impl DeleteDeliveryChannelError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "LastDeliveryChannelDeleteFailedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DeleteDeliveryChannelError::LastDeliveryChannelDeleteFailed(err.msg),
+                        &res,
                     )
                 }
                 "NoSuchDeliveryChannelException" => {
-                    return RusotoError::Service(DeleteDeliveryChannelError::NoSuchDeliveryChannel(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteDeliveryChannelError::NoSuchDeliveryChannel(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteDeliveryChannelError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3914,17 +3988,21 @@ impl fmt::Display for DeleteDeliveryChannelError {
                 write!(f, "{}", cause)
             }
             DeleteDeliveryChannelError::NoSuchDeliveryChannel(ref cause) => write!(f, "{}", cause),
+            DeleteDeliveryChannelError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteDeliveryChannelError {}
 /// Errors returned by DeleteEvaluationResults
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteEvaluationResultsError {
     /// <p>One or more AWS Config rules in the request are invalid. Verify that the rule names are correct and try again.</p>
     NoSuchConfigRule(String),
     /// <p><p>You see this exception in the following cases: </p> <ul> <li> <p>For DeleteConfigRule, AWS Config is deleting this rule. Try your request again later.</p> </li> <li> <p>For DeleteConfigRule, the rule is deleting your evaluation results. Try your request again later.</p> </li> <li> <p>For DeleteConfigRule, a remediation action is associated with the rule and AWS Config cannot delete this rule. Delete the remediation action associated with the rule before deleting the rule and try your request again later.</p> </li> <li> <p>For PutConfigOrganizationRule, organization config rule deletion is in progress. Try your request again later.</p> </li> <li> <p>For DeleteOrganizationConfigRule, organization config rule creation is in progress. Try your request again later.</p> </li> <li> <p>For PutConformancePack and PutOrganizationConformancePack, a conformance pack creation, update, and deletion is in progress. Try your request again later.</p> </li> <li> <p>For DeleteConformancePack, a conformance pack creation, update, and deletion is in progress. Try your request again later.</p> </li> </ul></p>
     ResourceInUse(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteEvaluationResultsError {
@@ -3932,17 +4010,19 @@ impl DeleteEvaluationResultsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "NoSuchConfigRuleException" => {
-                    return RusotoError::Service(DeleteEvaluationResultsError::NoSuchConfigRule(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteEvaluationResultsError::NoSuchConfigRule(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(DeleteEvaluationResultsError::ResourceInUse(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteEvaluationResultsError::ResourceInUse(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteEvaluationResultsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3954,12 +4034,14 @@ impl fmt::Display for DeleteEvaluationResultsError {
         match *self {
             DeleteEvaluationResultsError::NoSuchConfigRule(ref cause) => write!(f, "{}", cause),
             DeleteEvaluationResultsError::ResourceInUse(ref cause) => write!(f, "{}", cause),
+            DeleteEvaluationResultsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteEvaluationResultsError {}
 /// Errors returned by DeleteOrganizationConfigRule
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteOrganizationConfigRuleError {
     /// <p>You specified one or more organization config rules that do not exist.</p>
     NoSuchOrganizationConfigRule(String),
@@ -3967,6 +4049,8 @@ pub enum DeleteOrganizationConfigRuleError {
     OrganizationAccessDenied(String),
     /// <p><p>You see this exception in the following cases: </p> <ul> <li> <p>For DeleteConfigRule, AWS Config is deleting this rule. Try your request again later.</p> </li> <li> <p>For DeleteConfigRule, the rule is deleting your evaluation results. Try your request again later.</p> </li> <li> <p>For DeleteConfigRule, a remediation action is associated with the rule and AWS Config cannot delete this rule. Delete the remediation action associated with the rule before deleting the rule and try your request again later.</p> </li> <li> <p>For PutConfigOrganizationRule, organization config rule deletion is in progress. Try your request again later.</p> </li> <li> <p>For DeleteOrganizationConfigRule, organization config rule creation is in progress. Try your request again later.</p> </li> <li> <p>For PutConformancePack and PutOrganizationConformancePack, a conformance pack creation, update, and deletion is in progress. Try your request again later.</p> </li> <li> <p>For DeleteConformancePack, a conformance pack creation, update, and deletion is in progress. Try your request again later.</p> </li> </ul></p>
     ResourceInUse(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteOrganizationConfigRuleError {
@@ -3976,22 +4060,30 @@ impl DeleteOrganizationConfigRuleError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "NoSuchOrganizationConfigRuleException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DeleteOrganizationConfigRuleError::NoSuchOrganizationConfigRule(err.msg),
+                        &res,
                     )
                 }
                 "OrganizationAccessDeniedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DeleteOrganizationConfigRuleError::OrganizationAccessDenied(err.msg),
+                        &res,
                     )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(DeleteOrganizationConfigRuleError::ResourceInUse(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteOrganizationConfigRuleError::ResourceInUse(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        DeleteOrganizationConfigRuleError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -4008,12 +4100,14 @@ impl fmt::Display for DeleteOrganizationConfigRuleError {
                 write!(f, "{}", cause)
             }
             DeleteOrganizationConfigRuleError::ResourceInUse(ref cause) => write!(f, "{}", cause),
+            DeleteOrganizationConfigRuleError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteOrganizationConfigRuleError {}
 /// Errors returned by DeleteOrganizationConformancePack
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteOrganizationConformancePackError {
     /// <p>AWS Config organization conformance pack that you passed in the filter does not exist.</p> <p>For DeleteOrganizationConformancePack, you tried to delete an organization conformance pack that does not exist.</p>
     NoSuchOrganizationConformancePack(String),
@@ -4021,6 +4115,8 @@ pub enum DeleteOrganizationConformancePackError {
     OrganizationAccessDenied(String),
     /// <p><p>You see this exception in the following cases: </p> <ul> <li> <p>For DeleteConfigRule, AWS Config is deleting this rule. Try your request again later.</p> </li> <li> <p>For DeleteConfigRule, the rule is deleting your evaluation results. Try your request again later.</p> </li> <li> <p>For DeleteConfigRule, a remediation action is associated with the rule and AWS Config cannot delete this rule. Delete the remediation action associated with the rule before deleting the rule and try your request again later.</p> </li> <li> <p>For PutConfigOrganizationRule, organization config rule deletion is in progress. Try your request again later.</p> </li> <li> <p>For DeleteOrganizationConfigRule, organization config rule creation is in progress. Try your request again later.</p> </li> <li> <p>For PutConformancePack and PutOrganizationConformancePack, a conformance pack creation, update, and deletion is in progress. Try your request again later.</p> </li> <li> <p>For DeleteConformancePack, a conformance pack creation, update, and deletion is in progress. Try your request again later.</p> </li> </ul></p>
     ResourceInUse(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteOrganizationConformancePackError {
@@ -4030,24 +4126,32 @@ impl DeleteOrganizationConformancePackError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "NoSuchOrganizationConformancePackException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DeleteOrganizationConformancePackError::NoSuchOrganizationConformancePack(
                             err.msg,
                         ),
+                        &res,
                     )
                 }
                 "OrganizationAccessDeniedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DeleteOrganizationConformancePackError::OrganizationAccessDenied(err.msg),
+                        &res,
                     )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DeleteOrganizationConformancePackError::ResourceInUse(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        DeleteOrganizationConformancePackError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -4066,15 +4170,21 @@ impl fmt::Display for DeleteOrganizationConformancePackError {
             DeleteOrganizationConformancePackError::ResourceInUse(ref cause) => {
                 write!(f, "{}", cause)
             }
+            DeleteOrganizationConformancePackError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for DeleteOrganizationConformancePackError {}
 /// Errors returned by DeletePendingAggregationRequest
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeletePendingAggregationRequestError {
     /// <p>One or more of the specified parameters are invalid. Verify that your parameters are valid and try again.</p>
     InvalidParameterValue(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeletePendingAggregationRequestError {
@@ -4084,12 +4194,18 @@ impl DeletePendingAggregationRequestError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DeletePendingAggregationRequestError::InvalidParameterValue(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        DeletePendingAggregationRequestError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -4102,17 +4218,23 @@ impl fmt::Display for DeletePendingAggregationRequestError {
             DeletePendingAggregationRequestError::InvalidParameterValue(ref cause) => {
                 write!(f, "{}", cause)
             }
+            DeletePendingAggregationRequestError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for DeletePendingAggregationRequestError {}
 /// Errors returned by DeleteRemediationConfiguration
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteRemediationConfigurationError {
     /// <p>You specified an AWS Config rule without a remediation configuration.</p>
     NoSuchRemediationConfiguration(String),
     /// <p>Remediation action is in progress. You can either cancel execution in AWS Systems Manager or wait and try again later. </p>
     RemediationInProgress(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteRemediationConfigurationError {
@@ -4122,19 +4244,26 @@ impl DeleteRemediationConfigurationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "NoSuchRemediationConfigurationException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DeleteRemediationConfigurationError::NoSuchRemediationConfiguration(
                             err.msg,
                         ),
+                        &res,
                     )
                 }
                 "RemediationInProgressException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DeleteRemediationConfigurationError::RemediationInProgress(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        DeleteRemediationConfigurationError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -4150,15 +4279,21 @@ impl fmt::Display for DeleteRemediationConfigurationError {
             DeleteRemediationConfigurationError::RemediationInProgress(ref cause) => {
                 write!(f, "{}", cause)
             }
+            DeleteRemediationConfigurationError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for DeleteRemediationConfigurationError {}
 /// Errors returned by DeleteRemediationExceptions
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteRemediationExceptionsError {
     /// <p>You tried to delete a remediation exception that does not exist.</p>
     NoSuchRemediation(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteRemediationExceptionsError {
@@ -4168,12 +4303,18 @@ impl DeleteRemediationExceptionsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "NoSuchRemediationExceptionException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DeleteRemediationExceptionsError::NoSuchRemediation(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        DeleteRemediationExceptionsError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -4186,15 +4327,19 @@ impl fmt::Display for DeleteRemediationExceptionsError {
             DeleteRemediationExceptionsError::NoSuchRemediation(ref cause) => {
                 write!(f, "{}", cause)
             }
+            DeleteRemediationExceptionsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteRemediationExceptionsError {}
 /// Errors returned by DeleteResourceConfig
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteResourceConfigError {
     /// <p>There is no configuration recorder running.</p>
     NoRunningConfigurationRecorder(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteResourceConfigError {
@@ -4202,12 +4347,13 @@ impl DeleteResourceConfigError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "NoRunningConfigurationRecorderException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DeleteResourceConfigError::NoRunningConfigurationRecorder(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteResourceConfigError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -4220,17 +4366,21 @@ impl fmt::Display for DeleteResourceConfigError {
             DeleteResourceConfigError::NoRunningConfigurationRecorder(ref cause) => {
                 write!(f, "{}", cause)
             }
+            DeleteResourceConfigError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteResourceConfigError {}
 /// Errors returned by DeleteRetentionConfiguration
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteRetentionConfigurationError {
     /// <p>One or more of the specified parameters are invalid. Verify that your parameters are valid and try again.</p>
     InvalidParameterValue(String),
     /// <p>You have specified a retention configuration that does not exist.</p>
     NoSuchRetentionConfiguration(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteRetentionConfigurationError {
@@ -4240,17 +4390,24 @@ impl DeleteRetentionConfigurationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DeleteRetentionConfigurationError::InvalidParameterValue(err.msg),
+                        &res,
                     )
                 }
                 "NoSuchRetentionConfigurationException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DeleteRetentionConfigurationError::NoSuchRetentionConfiguration(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        DeleteRetentionConfigurationError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -4266,12 +4423,14 @@ impl fmt::Display for DeleteRetentionConfigurationError {
             DeleteRetentionConfigurationError::NoSuchRetentionConfiguration(ref cause) => {
                 write!(f, "{}", cause)
             }
+            DeleteRetentionConfigurationError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteRetentionConfigurationError {}
 /// Errors returned by DeliverConfigSnapshot
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeliverConfigSnapshotError {
     /// <p>There are no configuration recorders available to provide the role needed to describe your resources. Create a configuration recorder.</p>
     NoAvailableConfigurationRecorder(String),
@@ -4279,6 +4438,8 @@ pub enum DeliverConfigSnapshotError {
     NoRunningConfigurationRecorder(String),
     /// <p>You have specified a delivery channel that does not exist.</p>
     NoSuchDeliveryChannel(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeliverConfigSnapshotError {
@@ -4286,22 +4447,25 @@ impl DeliverConfigSnapshotError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "NoAvailableConfigurationRecorderException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DeliverConfigSnapshotError::NoAvailableConfigurationRecorder(err.msg),
+                        &res,
                     )
                 }
                 "NoRunningConfigurationRecorderException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DeliverConfigSnapshotError::NoRunningConfigurationRecorder(err.msg),
+                        &res,
                     )
                 }
                 "NoSuchDeliveryChannelException" => {
-                    return RusotoError::Service(DeliverConfigSnapshotError::NoSuchDeliveryChannel(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeliverConfigSnapshotError::NoSuchDeliveryChannel(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeliverConfigSnapshotError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -4318,12 +4482,14 @@ impl fmt::Display for DeliverConfigSnapshotError {
                 write!(f, "{}", cause)
             }
             DeliverConfigSnapshotError::NoSuchDeliveryChannel(ref cause) => write!(f, "{}", cause),
+            DeliverConfigSnapshotError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeliverConfigSnapshotError {}
 /// Errors returned by DescribeAggregateComplianceByConfigRules
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeAggregateComplianceByConfigRulesError {
     /// <p>The specified limit is outside the allowable range.</p>
     InvalidLimit(String),
@@ -4331,6 +4497,8 @@ pub enum DescribeAggregateComplianceByConfigRulesError {
     InvalidNextToken(String),
     /// <p>You have specified a configuration aggregator that does not exist.</p>
     NoSuchConfigurationAggregator(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeAggregateComplianceByConfigRulesError {
@@ -4340,22 +4508,30 @@ impl DescribeAggregateComplianceByConfigRulesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidLimitException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DescribeAggregateComplianceByConfigRulesError::InvalidLimit(err.msg),
+                        &res,
                     )
                 }
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DescribeAggregateComplianceByConfigRulesError::InvalidNextToken(err.msg),
+                        &res,
                     )
                 }
-                "NoSuchConfigurationAggregatorException" => return RusotoError::Service(
+                "NoSuchConfigurationAggregatorException" => return RusotoError::service(
                     DescribeAggregateComplianceByConfigRulesError::NoSuchConfigurationAggregator(
                         err.msg,
                     ),
+                    &res,
                 ),
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        DescribeAggregateComplianceByConfigRulesError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -4374,12 +4550,16 @@ impl fmt::Display for DescribeAggregateComplianceByConfigRulesError {
             DescribeAggregateComplianceByConfigRulesError::NoSuchConfigurationAggregator(
                 ref cause,
             ) => write!(f, "{}", cause),
+            DescribeAggregateComplianceByConfigRulesError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for DescribeAggregateComplianceByConfigRulesError {}
 /// Errors returned by DescribeAggregationAuthorizations
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeAggregationAuthorizationsError {
     /// <p>The specified limit is outside the allowable range.</p>
     InvalidLimit(String),
@@ -4387,6 +4567,8 @@ pub enum DescribeAggregationAuthorizationsError {
     InvalidNextToken(String),
     /// <p>One or more of the specified parameters are invalid. Verify that your parameters are valid and try again.</p>
     InvalidParameterValue(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeAggregationAuthorizationsError {
@@ -4396,22 +4578,30 @@ impl DescribeAggregationAuthorizationsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidLimitException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DescribeAggregationAuthorizationsError::InvalidLimit(err.msg),
+                        &res,
                     )
                 }
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DescribeAggregationAuthorizationsError::InvalidNextToken(err.msg),
+                        &res,
                     )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DescribeAggregationAuthorizationsError::InvalidParameterValue(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        DescribeAggregationAuthorizationsError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -4430,12 +4620,16 @@ impl fmt::Display for DescribeAggregationAuthorizationsError {
             DescribeAggregationAuthorizationsError::InvalidParameterValue(ref cause) => {
                 write!(f, "{}", cause)
             }
+            DescribeAggregationAuthorizationsError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for DescribeAggregationAuthorizationsError {}
 /// Errors returned by DescribeComplianceByConfigRule
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeComplianceByConfigRuleError {
     /// <p>The specified next token is invalid. Specify the <code>nextToken</code> string that was returned in the previous response to get the next page of results.</p>
     InvalidNextToken(String),
@@ -4443,6 +4637,8 @@ pub enum DescribeComplianceByConfigRuleError {
     InvalidParameterValue(String),
     /// <p>One or more AWS Config rules in the request are invalid. Verify that the rule names are correct and try again.</p>
     NoSuchConfigRule(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeComplianceByConfigRuleError {
@@ -4452,22 +4648,30 @@ impl DescribeComplianceByConfigRuleError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DescribeComplianceByConfigRuleError::InvalidNextToken(err.msg),
+                        &res,
                     )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DescribeComplianceByConfigRuleError::InvalidParameterValue(err.msg),
+                        &res,
                     )
                 }
                 "NoSuchConfigRuleException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DescribeComplianceByConfigRuleError::NoSuchConfigRule(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        DescribeComplianceByConfigRuleError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -4486,17 +4690,23 @@ impl fmt::Display for DescribeComplianceByConfigRuleError {
             DescribeComplianceByConfigRuleError::NoSuchConfigRule(ref cause) => {
                 write!(f, "{}", cause)
             }
+            DescribeComplianceByConfigRuleError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for DescribeComplianceByConfigRuleError {}
 /// Errors returned by DescribeComplianceByResource
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeComplianceByResourceError {
     /// <p>The specified next token is invalid. Specify the <code>nextToken</code> string that was returned in the previous response to get the next page of results.</p>
     InvalidNextToken(String),
     /// <p>One or more of the specified parameters are invalid. Verify that your parameters are valid and try again.</p>
     InvalidParameterValue(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeComplianceByResourceError {
@@ -4506,17 +4716,24 @@ impl DescribeComplianceByResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DescribeComplianceByResourceError::InvalidNextToken(err.msg),
+                        &res,
                     )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DescribeComplianceByResourceError::InvalidParameterValue(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        DescribeComplianceByResourceError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -4532,12 +4749,14 @@ impl fmt::Display for DescribeComplianceByResourceError {
             DescribeComplianceByResourceError::InvalidParameterValue(ref cause) => {
                 write!(f, "{}", cause)
             }
+            DescribeComplianceByResourceError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeComplianceByResourceError {}
 /// Errors returned by DescribeConfigRuleEvaluationStatus
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeConfigRuleEvaluationStatusError {
     /// <p>The specified next token is invalid. Specify the <code>nextToken</code> string that was returned in the previous response to get the next page of results.</p>
     InvalidNextToken(String),
@@ -4545,6 +4764,8 @@ pub enum DescribeConfigRuleEvaluationStatusError {
     InvalidParameterValue(String),
     /// <p>One or more AWS Config rules in the request are invalid. Verify that the rule names are correct and try again.</p>
     NoSuchConfigRule(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeConfigRuleEvaluationStatusError {
@@ -4554,22 +4775,30 @@ impl DescribeConfigRuleEvaluationStatusError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DescribeConfigRuleEvaluationStatusError::InvalidNextToken(err.msg),
+                        &res,
                     )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DescribeConfigRuleEvaluationStatusError::InvalidParameterValue(err.msg),
+                        &res,
                     )
                 }
                 "NoSuchConfigRuleException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DescribeConfigRuleEvaluationStatusError::NoSuchConfigRule(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        DescribeConfigRuleEvaluationStatusError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -4588,17 +4817,23 @@ impl fmt::Display for DescribeConfigRuleEvaluationStatusError {
             DescribeConfigRuleEvaluationStatusError::NoSuchConfigRule(ref cause) => {
                 write!(f, "{}", cause)
             }
+            DescribeConfigRuleEvaluationStatusError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for DescribeConfigRuleEvaluationStatusError {}
 /// Errors returned by DescribeConfigRules
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeConfigRulesError {
     /// <p>The specified next token is invalid. Specify the <code>nextToken</code> string that was returned in the previous response to get the next page of results.</p>
     InvalidNextToken(String),
     /// <p>One or more AWS Config rules in the request are invalid. Verify that the rule names are correct and try again.</p>
     NoSuchConfigRule(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeConfigRulesError {
@@ -4606,17 +4841,19 @@ impl DescribeConfigRulesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(DescribeConfigRulesError::InvalidNextToken(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeConfigRulesError::InvalidNextToken(err.msg),
+                        &res,
+                    )
                 }
                 "NoSuchConfigRuleException" => {
-                    return RusotoError::Service(DescribeConfigRulesError::NoSuchConfigRule(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeConfigRulesError::NoSuchConfigRule(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DescribeConfigRulesError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -4628,12 +4865,14 @@ impl fmt::Display for DescribeConfigRulesError {
         match *self {
             DescribeConfigRulesError::InvalidNextToken(ref cause) => write!(f, "{}", cause),
             DescribeConfigRulesError::NoSuchConfigRule(ref cause) => write!(f, "{}", cause),
+            DescribeConfigRulesError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeConfigRulesError {}
 /// Errors returned by DescribeConfigurationAggregatorSourcesStatus
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeConfigurationAggregatorSourcesStatusError {
     /// <p>The specified limit is outside the allowable range.</p>
     InvalidLimit(String),
@@ -4643,6 +4882,8 @@ pub enum DescribeConfigurationAggregatorSourcesStatusError {
     InvalidParameterValue(String),
     /// <p>You have specified a configuration aggregator that does not exist.</p>
     NoSuchConfigurationAggregator(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeConfigurationAggregatorSourcesStatusError {
@@ -4651,12 +4892,12 @@ impl DescribeConfigurationAggregatorSourcesStatusError {
     ) -> RusotoError<DescribeConfigurationAggregatorSourcesStatusError> {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
-                                "InvalidLimitException" => return RusotoError::Service(DescribeConfigurationAggregatorSourcesStatusError::InvalidLimit(err.msg)),
-"InvalidNextTokenException" => return RusotoError::Service(DescribeConfigurationAggregatorSourcesStatusError::InvalidNextToken(err.msg)),
-"InvalidParameterValueException" => return RusotoError::Service(DescribeConfigurationAggregatorSourcesStatusError::InvalidParameterValue(err.msg)),
-"NoSuchConfigurationAggregatorException" => return RusotoError::Service(DescribeConfigurationAggregatorSourcesStatusError::NoSuchConfigurationAggregator(err.msg)),
+                                "InvalidLimitException" => return RusotoError::service(DescribeConfigurationAggregatorSourcesStatusError::InvalidLimit(err.msg), &res),
+"InvalidNextTokenException" => return RusotoError::service(DescribeConfigurationAggregatorSourcesStatusError::InvalidNextToken(err.msg), &res),
+"InvalidParameterValueException" => return RusotoError::service(DescribeConfigurationAggregatorSourcesStatusError::InvalidParameterValue(err.msg), &res),
+"NoSuchConfigurationAggregatorException" => return RusotoError::service(DescribeConfigurationAggregatorSourcesStatusError::NoSuchConfigurationAggregator(err.msg), &res),
 "ValidationException" => return RusotoError::Validation(err.msg),
-_ => {}
+_ => return RusotoError::unhandled(DescribeConfigurationAggregatorSourcesStatusError::Unhandled, &res),
                             }
         }
         RusotoError::Unknown(res)
@@ -4678,12 +4919,16 @@ impl fmt::Display for DescribeConfigurationAggregatorSourcesStatusError {
             DescribeConfigurationAggregatorSourcesStatusError::NoSuchConfigurationAggregator(
                 ref cause,
             ) => write!(f, "{}", cause),
+            DescribeConfigurationAggregatorSourcesStatusError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for DescribeConfigurationAggregatorSourcesStatusError {}
 /// Errors returned by DescribeConfigurationAggregators
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeConfigurationAggregatorsError {
     /// <p>The specified limit is outside the allowable range.</p>
     InvalidLimit(String),
@@ -4693,6 +4938,8 @@ pub enum DescribeConfigurationAggregatorsError {
     InvalidParameterValue(String),
     /// <p>You have specified a configuration aggregator that does not exist.</p>
     NoSuchConfigurationAggregator(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeConfigurationAggregatorsError {
@@ -4702,29 +4949,38 @@ impl DescribeConfigurationAggregatorsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidLimitException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DescribeConfigurationAggregatorsError::InvalidLimit(err.msg),
+                        &res,
                     )
                 }
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DescribeConfigurationAggregatorsError::InvalidNextToken(err.msg),
+                        &res,
                     )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DescribeConfigurationAggregatorsError::InvalidParameterValue(err.msg),
+                        &res,
                     )
                 }
                 "NoSuchConfigurationAggregatorException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DescribeConfigurationAggregatorsError::NoSuchConfigurationAggregator(
                             err.msg,
                         ),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        DescribeConfigurationAggregatorsError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -4746,15 +5002,21 @@ impl fmt::Display for DescribeConfigurationAggregatorsError {
             DescribeConfigurationAggregatorsError::NoSuchConfigurationAggregator(ref cause) => {
                 write!(f, "{}", cause)
             }
+            DescribeConfigurationAggregatorsError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for DescribeConfigurationAggregatorsError {}
 /// Errors returned by DescribeConfigurationRecorderStatus
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeConfigurationRecorderStatusError {
     /// <p>You have specified a configuration recorder that does not exist.</p>
     NoSuchConfigurationRecorder(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeConfigurationRecorderStatusError {
@@ -4764,14 +5026,20 @@ impl DescribeConfigurationRecorderStatusError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "NoSuchConfigurationRecorderException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DescribeConfigurationRecorderStatusError::NoSuchConfigurationRecorder(
                             err.msg,
                         ),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        DescribeConfigurationRecorderStatusError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -4784,15 +5052,21 @@ impl fmt::Display for DescribeConfigurationRecorderStatusError {
             DescribeConfigurationRecorderStatusError::NoSuchConfigurationRecorder(ref cause) => {
                 write!(f, "{}", cause)
             }
+            DescribeConfigurationRecorderStatusError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for DescribeConfigurationRecorderStatusError {}
 /// Errors returned by DescribeConfigurationRecorders
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeConfigurationRecordersError {
     /// <p>You have specified a configuration recorder that does not exist.</p>
     NoSuchConfigurationRecorder(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeConfigurationRecordersError {
@@ -4802,12 +5076,18 @@ impl DescribeConfigurationRecordersError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "NoSuchConfigurationRecorderException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DescribeConfigurationRecordersError::NoSuchConfigurationRecorder(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        DescribeConfigurationRecordersError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -4820,12 +5100,16 @@ impl fmt::Display for DescribeConfigurationRecordersError {
             DescribeConfigurationRecordersError::NoSuchConfigurationRecorder(ref cause) => {
                 write!(f, "{}", cause)
             }
+            DescribeConfigurationRecordersError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for DescribeConfigurationRecordersError {}
 /// Errors returned by DescribeConformancePackCompliance
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeConformancePackComplianceError {
     /// <p>The specified limit is outside the allowable range.</p>
     InvalidLimit(String),
@@ -4837,6 +5121,8 @@ pub enum DescribeConformancePackComplianceError {
     NoSuchConfigRuleInConformancePack(String),
     /// <p>You specified one or more conformance packs that do not exist.</p>
     NoSuchConformancePack(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeConformancePackComplianceError {
@@ -4846,34 +5132,44 @@ impl DescribeConformancePackComplianceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidLimitException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DescribeConformancePackComplianceError::InvalidLimit(err.msg),
+                        &res,
                     )
                 }
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DescribeConformancePackComplianceError::InvalidNextToken(err.msg),
+                        &res,
                     )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DescribeConformancePackComplianceError::InvalidParameterValue(err.msg),
+                        &res,
                     )
                 }
                 "NoSuchConfigRuleInConformancePackException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DescribeConformancePackComplianceError::NoSuchConfigRuleInConformancePack(
                             err.msg,
                         ),
+                        &res,
                     )
                 }
                 "NoSuchConformancePackException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DescribeConformancePackComplianceError::NoSuchConformancePack(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        DescribeConformancePackComplianceError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -4898,17 +5194,23 @@ impl fmt::Display for DescribeConformancePackComplianceError {
             DescribeConformancePackComplianceError::NoSuchConformancePack(ref cause) => {
                 write!(f, "{}", cause)
             }
+            DescribeConformancePackComplianceError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for DescribeConformancePackComplianceError {}
 /// Errors returned by DescribeConformancePackStatus
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeConformancePackStatusError {
     /// <p>The specified limit is outside the allowable range.</p>
     InvalidLimit(String),
     /// <p>The specified next token is invalid. Specify the <code>nextToken</code> string that was returned in the previous response to get the next page of results.</p>
     InvalidNextToken(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeConformancePackStatusError {
@@ -4918,17 +5220,24 @@ impl DescribeConformancePackStatusError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidLimitException" => {
-                    return RusotoError::Service(DescribeConformancePackStatusError::InvalidLimit(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeConformancePackStatusError::InvalidLimit(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DescribeConformancePackStatusError::InvalidNextToken(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        DescribeConformancePackStatusError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -4942,12 +5251,16 @@ impl fmt::Display for DescribeConformancePackStatusError {
             DescribeConformancePackStatusError::InvalidNextToken(ref cause) => {
                 write!(f, "{}", cause)
             }
+            DescribeConformancePackStatusError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for DescribeConformancePackStatusError {}
 /// Errors returned by DescribeConformancePacks
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeConformancePacksError {
     /// <p>The specified limit is outside the allowable range.</p>
     InvalidLimit(String),
@@ -4955,6 +5268,8 @@ pub enum DescribeConformancePacksError {
     InvalidNextToken(String),
     /// <p>You specified one or more conformance packs that do not exist.</p>
     NoSuchConformancePack(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeConformancePacksError {
@@ -4962,22 +5277,25 @@ impl DescribeConformancePacksError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidLimitException" => {
-                    return RusotoError::Service(DescribeConformancePacksError::InvalidLimit(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeConformancePacksError::InvalidLimit(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(DescribeConformancePacksError::InvalidNextToken(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeConformancePacksError::InvalidNextToken(err.msg),
+                        &res,
+                    )
                 }
                 "NoSuchConformancePackException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DescribeConformancePacksError::NoSuchConformancePack(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DescribeConformancePacksError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -4992,15 +5310,19 @@ impl fmt::Display for DescribeConformancePacksError {
             DescribeConformancePacksError::NoSuchConformancePack(ref cause) => {
                 write!(f, "{}", cause)
             }
+            DescribeConformancePacksError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeConformancePacksError {}
 /// Errors returned by DescribeDeliveryChannelStatus
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeDeliveryChannelStatusError {
     /// <p>You have specified a delivery channel that does not exist.</p>
     NoSuchDeliveryChannel(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeDeliveryChannelStatusError {
@@ -5010,12 +5332,18 @@ impl DescribeDeliveryChannelStatusError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "NoSuchDeliveryChannelException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DescribeDeliveryChannelStatusError::NoSuchDeliveryChannel(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        DescribeDeliveryChannelStatusError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -5028,15 +5356,21 @@ impl fmt::Display for DescribeDeliveryChannelStatusError {
             DescribeDeliveryChannelStatusError::NoSuchDeliveryChannel(ref cause) => {
                 write!(f, "{}", cause)
             }
+            DescribeDeliveryChannelStatusError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for DescribeDeliveryChannelStatusError {}
 /// Errors returned by DescribeDeliveryChannels
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeDeliveryChannelsError {
     /// <p>You have specified a delivery channel that does not exist.</p>
     NoSuchDeliveryChannel(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeDeliveryChannelsError {
@@ -5044,12 +5378,13 @@ impl DescribeDeliveryChannelsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "NoSuchDeliveryChannelException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DescribeDeliveryChannelsError::NoSuchDeliveryChannel(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DescribeDeliveryChannelsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -5062,12 +5397,14 @@ impl fmt::Display for DescribeDeliveryChannelsError {
             DescribeDeliveryChannelsError::NoSuchDeliveryChannel(ref cause) => {
                 write!(f, "{}", cause)
             }
+            DescribeDeliveryChannelsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeDeliveryChannelsError {}
 /// Errors returned by DescribeOrganizationConfigRuleStatuses
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeOrganizationConfigRuleStatusesError {
     /// <p>The specified limit is outside the allowable range.</p>
     InvalidLimit(String),
@@ -5077,6 +5414,8 @@ pub enum DescribeOrganizationConfigRuleStatusesError {
     NoSuchOrganizationConfigRule(String),
     /// <p>For PutConfigAggregator API, no permission to call EnableAWSServiceAccess API.</p> <p>For all OrganizationConfigRule and OrganizationConformancePack APIs, AWS Config throws an exception if APIs are called from member accounts. All APIs must be called from organization master account.</p>
     OrganizationAccessDenied(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeOrganizationConfigRuleStatusesError {
@@ -5086,31 +5425,40 @@ impl DescribeOrganizationConfigRuleStatusesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidLimitException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DescribeOrganizationConfigRuleStatusesError::InvalidLimit(err.msg),
+                        &res,
                     )
                 }
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DescribeOrganizationConfigRuleStatusesError::InvalidNextToken(err.msg),
+                        &res,
                     )
                 }
                 "NoSuchOrganizationConfigRuleException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DescribeOrganizationConfigRuleStatusesError::NoSuchOrganizationConfigRule(
                             err.msg,
                         ),
+                        &res,
                     )
                 }
                 "OrganizationAccessDeniedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DescribeOrganizationConfigRuleStatusesError::OrganizationAccessDenied(
                             err.msg,
                         ),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        DescribeOrganizationConfigRuleStatusesError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -5132,12 +5480,16 @@ impl fmt::Display for DescribeOrganizationConfigRuleStatusesError {
             DescribeOrganizationConfigRuleStatusesError::OrganizationAccessDenied(ref cause) => {
                 write!(f, "{}", cause)
             }
+            DescribeOrganizationConfigRuleStatusesError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for DescribeOrganizationConfigRuleStatusesError {}
 /// Errors returned by DescribeOrganizationConfigRules
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeOrganizationConfigRulesError {
     /// <p>The specified limit is outside the allowable range.</p>
     InvalidLimit(String),
@@ -5147,6 +5499,8 @@ pub enum DescribeOrganizationConfigRulesError {
     NoSuchOrganizationConfigRule(String),
     /// <p>For PutConfigAggregator API, no permission to call EnableAWSServiceAccess API.</p> <p>For all OrganizationConfigRule and OrganizationConformancePack APIs, AWS Config throws an exception if APIs are called from member accounts. All APIs must be called from organization master account.</p>
     OrganizationAccessDenied(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeOrganizationConfigRulesError {
@@ -5156,27 +5510,36 @@ impl DescribeOrganizationConfigRulesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidLimitException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DescribeOrganizationConfigRulesError::InvalidLimit(err.msg),
+                        &res,
                     )
                 }
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DescribeOrganizationConfigRulesError::InvalidNextToken(err.msg),
+                        &res,
                     )
                 }
                 "NoSuchOrganizationConfigRuleException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DescribeOrganizationConfigRulesError::NoSuchOrganizationConfigRule(err.msg),
+                        &res,
                     )
                 }
                 "OrganizationAccessDeniedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DescribeOrganizationConfigRulesError::OrganizationAccessDenied(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        DescribeOrganizationConfigRulesError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -5196,12 +5559,16 @@ impl fmt::Display for DescribeOrganizationConfigRulesError {
             DescribeOrganizationConfigRulesError::OrganizationAccessDenied(ref cause) => {
                 write!(f, "{}", cause)
             }
+            DescribeOrganizationConfigRulesError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for DescribeOrganizationConfigRulesError {}
 /// Errors returned by DescribeOrganizationConformancePackStatuses
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeOrganizationConformancePackStatusesError {
     /// <p>The specified limit is outside the allowable range.</p>
     InvalidLimit(String),
@@ -5211,6 +5578,8 @@ pub enum DescribeOrganizationConformancePackStatusesError {
     NoSuchOrganizationConformancePack(String),
     /// <p>For PutConfigAggregator API, no permission to call EnableAWSServiceAccess API.</p> <p>For all OrganizationConfigRule and OrganizationConformancePack APIs, AWS Config throws an exception if APIs are called from member accounts. All APIs must be called from organization master account.</p>
     OrganizationAccessDenied(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeOrganizationConformancePackStatusesError {
@@ -5219,12 +5588,12 @@ impl DescribeOrganizationConformancePackStatusesError {
     ) -> RusotoError<DescribeOrganizationConformancePackStatusesError> {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
-                                "InvalidLimitException" => return RusotoError::Service(DescribeOrganizationConformancePackStatusesError::InvalidLimit(err.msg)),
-"InvalidNextTokenException" => return RusotoError::Service(DescribeOrganizationConformancePackStatusesError::InvalidNextToken(err.msg)),
-"NoSuchOrganizationConformancePackException" => return RusotoError::Service(DescribeOrganizationConformancePackStatusesError::NoSuchOrganizationConformancePack(err.msg)),
-"OrganizationAccessDeniedException" => return RusotoError::Service(DescribeOrganizationConformancePackStatusesError::OrganizationAccessDenied(err.msg)),
+                                "InvalidLimitException" => return RusotoError::service(DescribeOrganizationConformancePackStatusesError::InvalidLimit(err.msg), &res),
+"InvalidNextTokenException" => return RusotoError::service(DescribeOrganizationConformancePackStatusesError::InvalidNextToken(err.msg), &res),
+"NoSuchOrganizationConformancePackException" => return RusotoError::service(DescribeOrganizationConformancePackStatusesError::NoSuchOrganizationConformancePack(err.msg), &res),
+"OrganizationAccessDeniedException" => return RusotoError::service(DescribeOrganizationConformancePackStatusesError::OrganizationAccessDenied(err.msg), &res),
 "ValidationException" => return RusotoError::Validation(err.msg),
-_ => {}
+_ => return RusotoError::unhandled(DescribeOrganizationConformancePackStatusesError::Unhandled, &res),
                             }
         }
         RusotoError::Unknown(res)
@@ -5246,12 +5615,16 @@ impl fmt::Display for DescribeOrganizationConformancePackStatusesError {
             DescribeOrganizationConformancePackStatusesError::OrganizationAccessDenied(
                 ref cause,
             ) => write!(f, "{}", cause),
+            DescribeOrganizationConformancePackStatusesError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for DescribeOrganizationConformancePackStatusesError {}
 /// Errors returned by DescribeOrganizationConformancePacks
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeOrganizationConformancePacksError {
     /// <p>The specified limit is outside the allowable range.</p>
     InvalidLimit(String),
@@ -5261,6 +5634,8 @@ pub enum DescribeOrganizationConformancePacksError {
     NoSuchOrganizationConformancePack(String),
     /// <p>For PutConfigAggregator API, no permission to call EnableAWSServiceAccess API.</p> <p>For all OrganizationConfigRule and OrganizationConformancePack APIs, AWS Config throws an exception if APIs are called from member accounts. All APIs must be called from organization master account.</p>
     OrganizationAccessDenied(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeOrganizationConformancePacksError {
@@ -5270,29 +5645,38 @@ impl DescribeOrganizationConformancePacksError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidLimitException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DescribeOrganizationConformancePacksError::InvalidLimit(err.msg),
+                        &res,
                     )
                 }
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DescribeOrganizationConformancePacksError::InvalidNextToken(err.msg),
+                        &res,
                     )
                 }
-                "NoSuchOrganizationConformancePackException" => return RusotoError::Service(
+                "NoSuchOrganizationConformancePackException" => return RusotoError::service(
                     DescribeOrganizationConformancePacksError::NoSuchOrganizationConformancePack(
                         err.msg,
                     ),
+                    &res,
                 ),
                 "OrganizationAccessDeniedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DescribeOrganizationConformancePacksError::OrganizationAccessDenied(
                             err.msg,
                         ),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        DescribeOrganizationConformancePacksError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -5314,12 +5698,16 @@ impl fmt::Display for DescribeOrganizationConformancePacksError {
             DescribeOrganizationConformancePacksError::OrganizationAccessDenied(ref cause) => {
                 write!(f, "{}", cause)
             }
+            DescribeOrganizationConformancePacksError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for DescribeOrganizationConformancePacksError {}
 /// Errors returned by DescribePendingAggregationRequests
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribePendingAggregationRequestsError {
     /// <p>The specified limit is outside the allowable range.</p>
     InvalidLimit(String),
@@ -5327,6 +5715,8 @@ pub enum DescribePendingAggregationRequestsError {
     InvalidNextToken(String),
     /// <p>One or more of the specified parameters are invalid. Verify that your parameters are valid and try again.</p>
     InvalidParameterValue(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribePendingAggregationRequestsError {
@@ -5336,22 +5726,30 @@ impl DescribePendingAggregationRequestsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidLimitException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DescribePendingAggregationRequestsError::InvalidLimit(err.msg),
+                        &res,
                     )
                 }
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DescribePendingAggregationRequestsError::InvalidNextToken(err.msg),
+                        &res,
                     )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DescribePendingAggregationRequestsError::InvalidParameterValue(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        DescribePendingAggregationRequestsError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -5370,13 +5768,20 @@ impl fmt::Display for DescribePendingAggregationRequestsError {
             DescribePendingAggregationRequestsError::InvalidParameterValue(ref cause) => {
                 write!(f, "{}", cause)
             }
+            DescribePendingAggregationRequestsError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for DescribePendingAggregationRequestsError {}
 /// Errors returned by DescribeRemediationConfigurations
 #[derive(Debug, PartialEq)]
-pub enum DescribeRemediationConfigurationsError {}
+#[non_exhaustive]
+pub enum DescribeRemediationConfigurationsError {
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
+}
 
 impl DescribeRemediationConfigurationsError {
     pub fn from_response(
@@ -5385,7 +5790,12 @@ impl DescribeRemediationConfigurationsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        DescribeRemediationConfigurationsError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -5394,17 +5804,24 @@ impl DescribeRemediationConfigurationsError {
 impl fmt::Display for DescribeRemediationConfigurationsError {
     #[allow(unused_variables)]
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        match *self {}
+        match *self {
+            DescribeRemediationConfigurationsError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
+        }
     }
 }
 impl Error for DescribeRemediationConfigurationsError {}
 /// Errors returned by DescribeRemediationExceptions
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeRemediationExceptionsError {
     /// <p>The specified next token is invalid. Specify the <code>nextToken</code> string that was returned in the previous response to get the next page of results.</p>
     InvalidNextToken(String),
     /// <p>One or more of the specified parameters are invalid. Verify that your parameters are valid and try again.</p>
     InvalidParameterValue(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeRemediationExceptionsError {
@@ -5414,17 +5831,24 @@ impl DescribeRemediationExceptionsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DescribeRemediationExceptionsError::InvalidNextToken(err.msg),
+                        &res,
                     )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DescribeRemediationExceptionsError::InvalidParameterValue(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        DescribeRemediationExceptionsError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -5440,17 +5864,23 @@ impl fmt::Display for DescribeRemediationExceptionsError {
             DescribeRemediationExceptionsError::InvalidParameterValue(ref cause) => {
                 write!(f, "{}", cause)
             }
+            DescribeRemediationExceptionsError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for DescribeRemediationExceptionsError {}
 /// Errors returned by DescribeRemediationExecutionStatus
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeRemediationExecutionStatusError {
     /// <p>The specified next token is invalid. Specify the <code>nextToken</code> string that was returned in the previous response to get the next page of results.</p>
     InvalidNextToken(String),
     /// <p>You specified an AWS Config rule without a remediation configuration.</p>
     NoSuchRemediationConfiguration(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeRemediationExecutionStatusError {
@@ -5460,19 +5890,26 @@ impl DescribeRemediationExecutionStatusError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DescribeRemediationExecutionStatusError::InvalidNextToken(err.msg),
+                        &res,
                     )
                 }
                 "NoSuchRemediationConfigurationException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DescribeRemediationExecutionStatusError::NoSuchRemediationConfiguration(
                             err.msg,
                         ),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        DescribeRemediationExecutionStatusError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -5488,12 +5925,16 @@ impl fmt::Display for DescribeRemediationExecutionStatusError {
             DescribeRemediationExecutionStatusError::NoSuchRemediationConfiguration(ref cause) => {
                 write!(f, "{}", cause)
             }
+            DescribeRemediationExecutionStatusError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for DescribeRemediationExecutionStatusError {}
 /// Errors returned by DescribeRetentionConfigurations
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeRetentionConfigurationsError {
     /// <p>The specified next token is invalid. Specify the <code>nextToken</code> string that was returned in the previous response to get the next page of results.</p>
     InvalidNextToken(String),
@@ -5501,6 +5942,8 @@ pub enum DescribeRetentionConfigurationsError {
     InvalidParameterValue(String),
     /// <p>You have specified a retention configuration that does not exist.</p>
     NoSuchRetentionConfiguration(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeRetentionConfigurationsError {
@@ -5510,22 +5953,30 @@ impl DescribeRetentionConfigurationsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DescribeRetentionConfigurationsError::InvalidNextToken(err.msg),
+                        &res,
                     )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DescribeRetentionConfigurationsError::InvalidParameterValue(err.msg),
+                        &res,
                     )
                 }
                 "NoSuchRetentionConfigurationException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DescribeRetentionConfigurationsError::NoSuchRetentionConfiguration(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        DescribeRetentionConfigurationsError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -5544,12 +5995,16 @@ impl fmt::Display for DescribeRetentionConfigurationsError {
             DescribeRetentionConfigurationsError::NoSuchRetentionConfiguration(ref cause) => {
                 write!(f, "{}", cause)
             }
+            DescribeRetentionConfigurationsError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for DescribeRetentionConfigurationsError {}
 /// Errors returned by GetAggregateComplianceDetailsByConfigRule
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetAggregateComplianceDetailsByConfigRuleError {
     /// <p>The specified limit is outside the allowable range.</p>
     InvalidLimit(String),
@@ -5557,6 +6012,8 @@ pub enum GetAggregateComplianceDetailsByConfigRuleError {
     InvalidNextToken(String),
     /// <p>You have specified a configuration aggregator that does not exist.</p>
     NoSuchConfigurationAggregator(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetAggregateComplianceDetailsByConfigRuleError {
@@ -5566,22 +6023,30 @@ impl GetAggregateComplianceDetailsByConfigRuleError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidLimitException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         GetAggregateComplianceDetailsByConfigRuleError::InvalidLimit(err.msg),
+                        &res,
                     )
                 }
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         GetAggregateComplianceDetailsByConfigRuleError::InvalidNextToken(err.msg),
+                        &res,
                     )
                 }
-                "NoSuchConfigurationAggregatorException" => return RusotoError::Service(
+                "NoSuchConfigurationAggregatorException" => return RusotoError::service(
                     GetAggregateComplianceDetailsByConfigRuleError::NoSuchConfigurationAggregator(
                         err.msg,
                     ),
+                    &res,
                 ),
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        GetAggregateComplianceDetailsByConfigRuleError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -5600,12 +6065,16 @@ impl fmt::Display for GetAggregateComplianceDetailsByConfigRuleError {
             GetAggregateComplianceDetailsByConfigRuleError::NoSuchConfigurationAggregator(
                 ref cause,
             ) => write!(f, "{}", cause),
+            GetAggregateComplianceDetailsByConfigRuleError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for GetAggregateComplianceDetailsByConfigRuleError {}
 /// Errors returned by GetAggregateConfigRuleComplianceSummary
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetAggregateConfigRuleComplianceSummaryError {
     /// <p>The specified limit is outside the allowable range.</p>
     InvalidLimit(String),
@@ -5613,6 +6082,8 @@ pub enum GetAggregateConfigRuleComplianceSummaryError {
     InvalidNextToken(String),
     /// <p>You have specified a configuration aggregator that does not exist.</p>
     NoSuchConfigurationAggregator(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetAggregateConfigRuleComplianceSummaryError {
@@ -5622,24 +6093,32 @@ impl GetAggregateConfigRuleComplianceSummaryError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidLimitException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         GetAggregateConfigRuleComplianceSummaryError::InvalidLimit(err.msg),
+                        &res,
                     )
                 }
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         GetAggregateConfigRuleComplianceSummaryError::InvalidNextToken(err.msg),
+                        &res,
                     )
                 }
                 "NoSuchConfigurationAggregatorException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         GetAggregateConfigRuleComplianceSummaryError::NoSuchConfigurationAggregator(
                             err.msg,
                         ),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        GetAggregateConfigRuleComplianceSummaryError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -5658,12 +6137,16 @@ impl fmt::Display for GetAggregateConfigRuleComplianceSummaryError {
             GetAggregateConfigRuleComplianceSummaryError::NoSuchConfigurationAggregator(
                 ref cause,
             ) => write!(f, "{}", cause),
+            GetAggregateConfigRuleComplianceSummaryError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for GetAggregateConfigRuleComplianceSummaryError {}
 /// Errors returned by GetAggregateDiscoveredResourceCounts
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetAggregateDiscoveredResourceCountsError {
     /// <p>The specified limit is outside the allowable range.</p>
     InvalidLimit(String),
@@ -5671,6 +6154,8 @@ pub enum GetAggregateDiscoveredResourceCountsError {
     InvalidNextToken(String),
     /// <p>You have specified a configuration aggregator that does not exist.</p>
     NoSuchConfigurationAggregator(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetAggregateDiscoveredResourceCountsError {
@@ -5680,24 +6165,32 @@ impl GetAggregateDiscoveredResourceCountsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidLimitException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         GetAggregateDiscoveredResourceCountsError::InvalidLimit(err.msg),
+                        &res,
                     )
                 }
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         GetAggregateDiscoveredResourceCountsError::InvalidNextToken(err.msg),
+                        &res,
                     )
                 }
                 "NoSuchConfigurationAggregatorException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         GetAggregateDiscoveredResourceCountsError::NoSuchConfigurationAggregator(
                             err.msg,
                         ),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        GetAggregateDiscoveredResourceCountsError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -5716,12 +6209,16 @@ impl fmt::Display for GetAggregateDiscoveredResourceCountsError {
             GetAggregateDiscoveredResourceCountsError::NoSuchConfigurationAggregator(ref cause) => {
                 write!(f, "{}", cause)
             }
+            GetAggregateDiscoveredResourceCountsError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for GetAggregateDiscoveredResourceCountsError {}
 /// Errors returned by GetAggregateResourceConfig
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetAggregateResourceConfigError {
     /// <p>You have specified a configuration aggregator that does not exist.</p>
     NoSuchConfigurationAggregator(String),
@@ -5729,6 +6226,8 @@ pub enum GetAggregateResourceConfigError {
     OversizedConfigurationItem(String),
     /// <p>You have specified a resource that is either unknown or has not been discovered.</p>
     ResourceNotDiscovered(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetAggregateResourceConfigError {
@@ -5738,22 +6237,27 @@ impl GetAggregateResourceConfigError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "NoSuchConfigurationAggregatorException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         GetAggregateResourceConfigError::NoSuchConfigurationAggregator(err.msg),
+                        &res,
                     )
                 }
                 "OversizedConfigurationItemException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         GetAggregateResourceConfigError::OversizedConfigurationItem(err.msg),
+                        &res,
                     )
                 }
                 "ResourceNotDiscoveredException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         GetAggregateResourceConfigError::ResourceNotDiscovered(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(GetAggregateResourceConfigError::Unhandled, &res)
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -5772,12 +6276,14 @@ impl fmt::Display for GetAggregateResourceConfigError {
             GetAggregateResourceConfigError::ResourceNotDiscovered(ref cause) => {
                 write!(f, "{}", cause)
             }
+            GetAggregateResourceConfigError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetAggregateResourceConfigError {}
 /// Errors returned by GetComplianceDetailsByConfigRule
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetComplianceDetailsByConfigRuleError {
     /// <p>The specified next token is invalid. Specify the <code>nextToken</code> string that was returned in the previous response to get the next page of results.</p>
     InvalidNextToken(String),
@@ -5785,6 +6291,8 @@ pub enum GetComplianceDetailsByConfigRuleError {
     InvalidParameterValue(String),
     /// <p>One or more AWS Config rules in the request are invalid. Verify that the rule names are correct and try again.</p>
     NoSuchConfigRule(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetComplianceDetailsByConfigRuleError {
@@ -5794,22 +6302,30 @@ impl GetComplianceDetailsByConfigRuleError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         GetComplianceDetailsByConfigRuleError::InvalidNextToken(err.msg),
+                        &res,
                     )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         GetComplianceDetailsByConfigRuleError::InvalidParameterValue(err.msg),
+                        &res,
                     )
                 }
                 "NoSuchConfigRuleException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         GetComplianceDetailsByConfigRuleError::NoSuchConfigRule(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        GetComplianceDetailsByConfigRuleError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -5828,15 +6344,21 @@ impl fmt::Display for GetComplianceDetailsByConfigRuleError {
             GetComplianceDetailsByConfigRuleError::NoSuchConfigRule(ref cause) => {
                 write!(f, "{}", cause)
             }
+            GetComplianceDetailsByConfigRuleError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for GetComplianceDetailsByConfigRuleError {}
 /// Errors returned by GetComplianceDetailsByResource
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetComplianceDetailsByResourceError {
     /// <p>One or more of the specified parameters are invalid. Verify that your parameters are valid and try again.</p>
     InvalidParameterValue(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetComplianceDetailsByResourceError {
@@ -5846,12 +6368,18 @@ impl GetComplianceDetailsByResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         GetComplianceDetailsByResourceError::InvalidParameterValue(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        GetComplianceDetailsByResourceError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -5864,13 +6392,20 @@ impl fmt::Display for GetComplianceDetailsByResourceError {
             GetComplianceDetailsByResourceError::InvalidParameterValue(ref cause) => {
                 write!(f, "{}", cause)
             }
+            GetComplianceDetailsByResourceError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for GetComplianceDetailsByResourceError {}
 /// Errors returned by GetComplianceSummaryByConfigRule
 #[derive(Debug, PartialEq)]
-pub enum GetComplianceSummaryByConfigRuleError {}
+#[non_exhaustive]
+pub enum GetComplianceSummaryByConfigRuleError {
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
+}
 
 impl GetComplianceSummaryByConfigRuleError {
     pub fn from_response(
@@ -5879,7 +6414,12 @@ impl GetComplianceSummaryByConfigRuleError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        GetComplianceSummaryByConfigRuleError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -5888,15 +6428,22 @@ impl GetComplianceSummaryByConfigRuleError {
 impl fmt::Display for GetComplianceSummaryByConfigRuleError {
     #[allow(unused_variables)]
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        match *self {}
+        match *self {
+            GetComplianceSummaryByConfigRuleError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
+        }
     }
 }
 impl Error for GetComplianceSummaryByConfigRuleError {}
 /// Errors returned by GetComplianceSummaryByResourceType
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetComplianceSummaryByResourceTypeError {
     /// <p>One or more of the specified parameters are invalid. Verify that your parameters are valid and try again.</p>
     InvalidParameterValue(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetComplianceSummaryByResourceTypeError {
@@ -5906,12 +6453,18 @@ impl GetComplianceSummaryByResourceTypeError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         GetComplianceSummaryByResourceTypeError::InvalidParameterValue(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        GetComplianceSummaryByResourceTypeError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -5924,12 +6477,16 @@ impl fmt::Display for GetComplianceSummaryByResourceTypeError {
             GetComplianceSummaryByResourceTypeError::InvalidParameterValue(ref cause) => {
                 write!(f, "{}", cause)
             }
+            GetComplianceSummaryByResourceTypeError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for GetComplianceSummaryByResourceTypeError {}
 /// Errors returned by GetConformancePackComplianceDetails
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetConformancePackComplianceDetailsError {
     /// <p>The specified limit is outside the allowable range.</p>
     InvalidLimit(String),
@@ -5941,6 +6498,8 @@ pub enum GetConformancePackComplianceDetailsError {
     NoSuchConfigRuleInConformancePack(String),
     /// <p>You specified one or more conformance packs that do not exist.</p>
     NoSuchConformancePack(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetConformancePackComplianceDetailsError {
@@ -5950,34 +6509,44 @@ impl GetConformancePackComplianceDetailsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidLimitException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         GetConformancePackComplianceDetailsError::InvalidLimit(err.msg),
+                        &res,
                     )
                 }
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         GetConformancePackComplianceDetailsError::InvalidNextToken(err.msg),
+                        &res,
                     )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         GetConformancePackComplianceDetailsError::InvalidParameterValue(err.msg),
+                        &res,
                     )
                 }
                 "NoSuchConfigRuleInConformancePackException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         GetConformancePackComplianceDetailsError::NoSuchConfigRuleInConformancePack(
                             err.msg,
                         ),
+                        &res,
                     )
                 }
                 "NoSuchConformancePackException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         GetConformancePackComplianceDetailsError::NoSuchConformancePack(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        GetConformancePackComplianceDetailsError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -6002,12 +6571,16 @@ impl fmt::Display for GetConformancePackComplianceDetailsError {
             GetConformancePackComplianceDetailsError::NoSuchConformancePack(ref cause) => {
                 write!(f, "{}", cause)
             }
+            GetConformancePackComplianceDetailsError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for GetConformancePackComplianceDetailsError {}
 /// Errors returned by GetConformancePackComplianceSummary
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetConformancePackComplianceSummaryError {
     /// <p>The specified limit is outside the allowable range.</p>
     InvalidLimit(String),
@@ -6015,6 +6588,8 @@ pub enum GetConformancePackComplianceSummaryError {
     InvalidNextToken(String),
     /// <p>You specified one or more conformance packs that do not exist.</p>
     NoSuchConformancePack(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetConformancePackComplianceSummaryError {
@@ -6024,22 +6599,30 @@ impl GetConformancePackComplianceSummaryError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidLimitException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         GetConformancePackComplianceSummaryError::InvalidLimit(err.msg),
+                        &res,
                     )
                 }
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         GetConformancePackComplianceSummaryError::InvalidNextToken(err.msg),
+                        &res,
                     )
                 }
                 "NoSuchConformancePackException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         GetConformancePackComplianceSummaryError::NoSuchConformancePack(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        GetConformancePackComplianceSummaryError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -6058,17 +6641,23 @@ impl fmt::Display for GetConformancePackComplianceSummaryError {
             GetConformancePackComplianceSummaryError::NoSuchConformancePack(ref cause) => {
                 write!(f, "{}", cause)
             }
+            GetConformancePackComplianceSummaryError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for GetConformancePackComplianceSummaryError {}
 /// Errors returned by GetDiscoveredResourceCounts
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetDiscoveredResourceCountsError {
     /// <p>The specified limit is outside the allowable range.</p>
     InvalidLimit(String),
     /// <p>The specified next token is invalid. Specify the <code>nextToken</code> string that was returned in the previous response to get the next page of results.</p>
     InvalidNextToken(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetDiscoveredResourceCountsError {
@@ -6078,17 +6667,24 @@ impl GetDiscoveredResourceCountsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidLimitException" => {
-                    return RusotoError::Service(GetDiscoveredResourceCountsError::InvalidLimit(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetDiscoveredResourceCountsError::InvalidLimit(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         GetDiscoveredResourceCountsError::InvalidNextToken(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        GetDiscoveredResourceCountsError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -6100,12 +6696,14 @@ impl fmt::Display for GetDiscoveredResourceCountsError {
         match *self {
             GetDiscoveredResourceCountsError::InvalidLimit(ref cause) => write!(f, "{}", cause),
             GetDiscoveredResourceCountsError::InvalidNextToken(ref cause) => write!(f, "{}", cause),
+            GetDiscoveredResourceCountsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetDiscoveredResourceCountsError {}
 /// Errors returned by GetOrganizationConfigRuleDetailedStatus
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetOrganizationConfigRuleDetailedStatusError {
     /// <p>The specified limit is outside the allowable range.</p>
     InvalidLimit(String),
@@ -6115,6 +6713,8 @@ pub enum GetOrganizationConfigRuleDetailedStatusError {
     NoSuchOrganizationConfigRule(String),
     /// <p>For PutConfigAggregator API, no permission to call EnableAWSServiceAccess API.</p> <p>For all OrganizationConfigRule and OrganizationConformancePack APIs, AWS Config throws an exception if APIs are called from member accounts. All APIs must be called from organization master account.</p>
     OrganizationAccessDenied(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetOrganizationConfigRuleDetailedStatusError {
@@ -6124,31 +6724,40 @@ impl GetOrganizationConfigRuleDetailedStatusError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidLimitException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         GetOrganizationConfigRuleDetailedStatusError::InvalidLimit(err.msg),
+                        &res,
                     )
                 }
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         GetOrganizationConfigRuleDetailedStatusError::InvalidNextToken(err.msg),
+                        &res,
                     )
                 }
                 "NoSuchOrganizationConfigRuleException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         GetOrganizationConfigRuleDetailedStatusError::NoSuchOrganizationConfigRule(
                             err.msg,
                         ),
+                        &res,
                     )
                 }
                 "OrganizationAccessDeniedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         GetOrganizationConfigRuleDetailedStatusError::OrganizationAccessDenied(
                             err.msg,
                         ),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        GetOrganizationConfigRuleDetailedStatusError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -6170,12 +6779,16 @@ impl fmt::Display for GetOrganizationConfigRuleDetailedStatusError {
             GetOrganizationConfigRuleDetailedStatusError::OrganizationAccessDenied(ref cause) => {
                 write!(f, "{}", cause)
             }
+            GetOrganizationConfigRuleDetailedStatusError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for GetOrganizationConfigRuleDetailedStatusError {}
 /// Errors returned by GetOrganizationConformancePackDetailedStatus
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetOrganizationConformancePackDetailedStatusError {
     /// <p>The specified limit is outside the allowable range.</p>
     InvalidLimit(String),
@@ -6185,6 +6798,8 @@ pub enum GetOrganizationConformancePackDetailedStatusError {
     NoSuchOrganizationConformancePack(String),
     /// <p>For PutConfigAggregator API, no permission to call EnableAWSServiceAccess API.</p> <p>For all OrganizationConfigRule and OrganizationConformancePack APIs, AWS Config throws an exception if APIs are called from member accounts. All APIs must be called from organization master account.</p>
     OrganizationAccessDenied(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetOrganizationConformancePackDetailedStatusError {
@@ -6193,12 +6808,12 @@ impl GetOrganizationConformancePackDetailedStatusError {
     ) -> RusotoError<GetOrganizationConformancePackDetailedStatusError> {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
-                                "InvalidLimitException" => return RusotoError::Service(GetOrganizationConformancePackDetailedStatusError::InvalidLimit(err.msg)),
-"InvalidNextTokenException" => return RusotoError::Service(GetOrganizationConformancePackDetailedStatusError::InvalidNextToken(err.msg)),
-"NoSuchOrganizationConformancePackException" => return RusotoError::Service(GetOrganizationConformancePackDetailedStatusError::NoSuchOrganizationConformancePack(err.msg)),
-"OrganizationAccessDeniedException" => return RusotoError::Service(GetOrganizationConformancePackDetailedStatusError::OrganizationAccessDenied(err.msg)),
+                                "InvalidLimitException" => return RusotoError::service(GetOrganizationConformancePackDetailedStatusError::InvalidLimit(err.msg), &res),
+"InvalidNextTokenException" => return RusotoError::service(GetOrganizationConformancePackDetailedStatusError::InvalidNextToken(err.msg), &res),
+"NoSuchOrganizationConformancePackException" => return RusotoError::service(GetOrganizationConformancePackDetailedStatusError::NoSuchOrganizationConformancePack(err.msg), &res),
+"OrganizationAccessDeniedException" => return RusotoError::service(GetOrganizationConformancePackDetailedStatusError::OrganizationAccessDenied(err.msg), &res),
 "ValidationException" => return RusotoError::Validation(err.msg),
-_ => {}
+_ => return RusotoError::unhandled(GetOrganizationConformancePackDetailedStatusError::Unhandled, &res),
                             }
         }
         RusotoError::Unknown(res)
@@ -6211,13 +6826,15 @@ impl fmt::Display for GetOrganizationConformancePackDetailedStatusError {
                             GetOrganizationConformancePackDetailedStatusError::InvalidLimit(ref cause) => write!(f, "{}", cause),
 GetOrganizationConformancePackDetailedStatusError::InvalidNextToken(ref cause) => write!(f, "{}", cause),
 GetOrganizationConformancePackDetailedStatusError::NoSuchOrganizationConformancePack(ref cause) => write!(f, "{}", cause),
-GetOrganizationConformancePackDetailedStatusError::OrganizationAccessDenied(ref cause) => write!(f, "{}", cause)
-                        }
+GetOrganizationConformancePackDetailedStatusError::OrganizationAccessDenied(ref cause) => write!(f, "{}", cause),
+            GetOrganizationConformancePackDetailedStatusError::Unhandled(ref metadata) => write!(f, "{}", metadata),
+        }
     }
 }
 impl Error for GetOrganizationConformancePackDetailedStatusError {}
 /// Errors returned by GetResourceConfigHistory
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetResourceConfigHistoryError {
     /// <p>The specified limit is outside the allowable range.</p>
     InvalidLimit(String),
@@ -6229,6 +6846,8 @@ pub enum GetResourceConfigHistoryError {
     NoAvailableConfigurationRecorder(String),
     /// <p>You have specified a resource that is either unknown or has not been discovered.</p>
     ResourceNotDiscovered(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetResourceConfigHistoryError {
@@ -6236,32 +6855,37 @@ impl GetResourceConfigHistoryError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidLimitException" => {
-                    return RusotoError::Service(GetResourceConfigHistoryError::InvalidLimit(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetResourceConfigHistoryError::InvalidLimit(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(GetResourceConfigHistoryError::InvalidNextToken(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetResourceConfigHistoryError::InvalidNextToken(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidTimeRangeException" => {
-                    return RusotoError::Service(GetResourceConfigHistoryError::InvalidTimeRange(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetResourceConfigHistoryError::InvalidTimeRange(err.msg),
+                        &res,
+                    )
                 }
                 "NoAvailableConfigurationRecorderException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         GetResourceConfigHistoryError::NoAvailableConfigurationRecorder(err.msg),
+                        &res,
                     )
                 }
                 "ResourceNotDiscoveredException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         GetResourceConfigHistoryError::ResourceNotDiscovered(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetResourceConfigHistoryError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -6280,12 +6904,14 @@ impl fmt::Display for GetResourceConfigHistoryError {
             GetResourceConfigHistoryError::ResourceNotDiscovered(ref cause) => {
                 write!(f, "{}", cause)
             }
+            GetResourceConfigHistoryError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetResourceConfigHistoryError {}
 /// Errors returned by ListAggregateDiscoveredResources
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListAggregateDiscoveredResourcesError {
     /// <p>The specified limit is outside the allowable range.</p>
     InvalidLimit(String),
@@ -6293,6 +6919,8 @@ pub enum ListAggregateDiscoveredResourcesError {
     InvalidNextToken(String),
     /// <p>You have specified a configuration aggregator that does not exist.</p>
     NoSuchConfigurationAggregator(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListAggregateDiscoveredResourcesError {
@@ -6302,24 +6930,32 @@ impl ListAggregateDiscoveredResourcesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidLimitException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         ListAggregateDiscoveredResourcesError::InvalidLimit(err.msg),
+                        &res,
                     )
                 }
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         ListAggregateDiscoveredResourcesError::InvalidNextToken(err.msg),
+                        &res,
                     )
                 }
                 "NoSuchConfigurationAggregatorException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         ListAggregateDiscoveredResourcesError::NoSuchConfigurationAggregator(
                             err.msg,
                         ),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        ListAggregateDiscoveredResourcesError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -6338,12 +6974,16 @@ impl fmt::Display for ListAggregateDiscoveredResourcesError {
             ListAggregateDiscoveredResourcesError::NoSuchConfigurationAggregator(ref cause) => {
                 write!(f, "{}", cause)
             }
+            ListAggregateDiscoveredResourcesError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for ListAggregateDiscoveredResourcesError {}
 /// Errors returned by ListDiscoveredResources
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListDiscoveredResourcesError {
     /// <p>The specified limit is outside the allowable range.</p>
     InvalidLimit(String),
@@ -6351,6 +6991,8 @@ pub enum ListDiscoveredResourcesError {
     InvalidNextToken(String),
     /// <p>There are no configuration recorders available to provide the role needed to describe your resources. Create a configuration recorder.</p>
     NoAvailableConfigurationRecorder(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListDiscoveredResourcesError {
@@ -6358,22 +7000,25 @@ impl ListDiscoveredResourcesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidLimitException" => {
-                    return RusotoError::Service(ListDiscoveredResourcesError::InvalidLimit(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListDiscoveredResourcesError::InvalidLimit(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(ListDiscoveredResourcesError::InvalidNextToken(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListDiscoveredResourcesError::InvalidNextToken(err.msg),
+                        &res,
+                    )
                 }
                 "NoAvailableConfigurationRecorderException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         ListDiscoveredResourcesError::NoAvailableConfigurationRecorder(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListDiscoveredResourcesError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -6388,12 +7033,14 @@ impl fmt::Display for ListDiscoveredResourcesError {
             ListDiscoveredResourcesError::NoAvailableConfigurationRecorder(ref cause) => {
                 write!(f, "{}", cause)
             }
+            ListDiscoveredResourcesError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListDiscoveredResourcesError {}
 /// Errors returned by ListTagsForResource
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListTagsForResourceError {
     /// <p>The specified limit is outside the allowable range.</p>
     InvalidLimit(String),
@@ -6401,6 +7048,8 @@ pub enum ListTagsForResourceError {
     InvalidNextToken(String),
     /// <p>You have specified a resource that does not exist.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListTagsForResourceError {
@@ -6408,20 +7057,25 @@ impl ListTagsForResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidLimitException" => {
-                    return RusotoError::Service(ListTagsForResourceError::InvalidLimit(err.msg))
+                    return RusotoError::service(
+                        ListTagsForResourceError::InvalidLimit(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(ListTagsForResourceError::InvalidNextToken(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListTagsForResourceError::InvalidNextToken(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListTagsForResourceError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListTagsForResourceError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListTagsForResourceError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -6434,15 +7088,19 @@ impl fmt::Display for ListTagsForResourceError {
             ListTagsForResourceError::InvalidLimit(ref cause) => write!(f, "{}", cause),
             ListTagsForResourceError::InvalidNextToken(ref cause) => write!(f, "{}", cause),
             ListTagsForResourceError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            ListTagsForResourceError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListTagsForResourceError {}
 /// Errors returned by PutAggregationAuthorization
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum PutAggregationAuthorizationError {
     /// <p>One or more of the specified parameters are invalid. Verify that your parameters are valid and try again.</p>
     InvalidParameterValue(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl PutAggregationAuthorizationError {
@@ -6452,12 +7110,18 @@ impl PutAggregationAuthorizationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         PutAggregationAuthorizationError::InvalidParameterValue(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        PutAggregationAuthorizationError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -6470,12 +7134,14 @@ impl fmt::Display for PutAggregationAuthorizationError {
             PutAggregationAuthorizationError::InvalidParameterValue(ref cause) => {
                 write!(f, "{}", cause)
             }
+            PutAggregationAuthorizationError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for PutAggregationAuthorizationError {}
 /// Errors returned by PutConfigRule
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum PutConfigRuleError {
     /// <p><p>Indicates one of the following errors:</p> <ul> <li> <p>For PutConfigRule, the rule cannot be created because the IAM role assigned to AWS Config lacks permissions to perform the config:Put* action.</p> </li> <li> <p>For PutConfigRule, the AWS Lambda function cannot be invoked. Check the function ARN, and check the function&#39;s permissions.</p> </li> <li> <p>For PutOrganizationConfigRule, organization config rule cannot be created because you do not have permissions to call IAM <code>GetRole</code> action or create a service linked role.</p> </li> <li> <p>For PutConformancePack and PutOrganizationConformancePack, a conformance pack cannot be created because you do not have permissions: </p> <ul> <li> <p>To call IAM <code>GetRole</code> action or create a service linked role.</p> </li> <li> <p>To read Amazon S3 bucket.</p> </li> </ul> </li> </ul></p>
     InsufficientPermissions(String),
@@ -6487,6 +7153,8 @@ pub enum PutConfigRuleError {
     NoAvailableConfigurationRecorder(String),
     /// <p><p>You see this exception in the following cases: </p> <ul> <li> <p>For DeleteConfigRule, AWS Config is deleting this rule. Try your request again later.</p> </li> <li> <p>For DeleteConfigRule, the rule is deleting your evaluation results. Try your request again later.</p> </li> <li> <p>For DeleteConfigRule, a remediation action is associated with the rule and AWS Config cannot delete this rule. Delete the remediation action associated with the rule before deleting the rule and try your request again later.</p> </li> <li> <p>For PutConfigOrganizationRule, organization config rule deletion is in progress. Try your request again later.</p> </li> <li> <p>For DeleteOrganizationConfigRule, organization config rule creation is in progress. Try your request again later.</p> </li> <li> <p>For PutConformancePack and PutOrganizationConformancePack, a conformance pack creation, update, and deletion is in progress. Try your request again later.</p> </li> <li> <p>For DeleteConformancePack, a conformance pack creation, update, and deletion is in progress. Try your request again later.</p> </li> </ul></p>
     ResourceInUse(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl PutConfigRuleError {
@@ -6494,28 +7162,34 @@ impl PutConfigRuleError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InsufficientPermissionsException" => {
-                    return RusotoError::Service(PutConfigRuleError::InsufficientPermissions(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        PutConfigRuleError::InsufficientPermissions(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(PutConfigRuleError::InvalidParameterValue(err.msg))
+                    return RusotoError::service(
+                        PutConfigRuleError::InvalidParameterValue(err.msg),
+                        &res,
+                    )
                 }
                 "MaxNumberOfConfigRulesExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         PutConfigRuleError::MaxNumberOfConfigRulesExceeded(err.msg),
+                        &res,
                     )
                 }
                 "NoAvailableConfigurationRecorderException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         PutConfigRuleError::NoAvailableConfigurationRecorder(err.msg),
+                        &res,
                     )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(PutConfigRuleError::ResourceInUse(err.msg))
+                    return RusotoError::service(PutConfigRuleError::ResourceInUse(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(PutConfigRuleError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -6532,12 +7206,14 @@ impl fmt::Display for PutConfigRuleError {
                 write!(f, "{}", cause)
             }
             PutConfigRuleError::ResourceInUse(ref cause) => write!(f, "{}", cause),
+            PutConfigRuleError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for PutConfigRuleError {}
 /// Errors returned by PutConfigurationAggregator
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum PutConfigurationAggregatorError {
     /// <p>One or more of the specified parameters are invalid. Verify that your parameters are valid and try again.</p>
     InvalidParameterValue(String),
@@ -6551,6 +7227,8 @@ pub enum PutConfigurationAggregatorError {
     OrganizationAccessDenied(String),
     /// <p>AWS Config resource cannot be created because your organization does not have all features enabled.</p>
     OrganizationAllFeaturesNotEnabled(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl PutConfigurationAggregatorError {
@@ -6560,37 +7238,45 @@ impl PutConfigurationAggregatorError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         PutConfigurationAggregatorError::InvalidParameterValue(err.msg),
+                        &res,
                     )
                 }
                 "InvalidRoleException" => {
-                    return RusotoError::Service(PutConfigurationAggregatorError::InvalidRole(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        PutConfigurationAggregatorError::InvalidRole(err.msg),
+                        &res,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(PutConfigurationAggregatorError::LimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        PutConfigurationAggregatorError::LimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "NoAvailableOrganizationException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         PutConfigurationAggregatorError::NoAvailableOrganization(err.msg),
+                        &res,
                     )
                 }
                 "OrganizationAccessDeniedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         PutConfigurationAggregatorError::OrganizationAccessDenied(err.msg),
+                        &res,
                     )
                 }
                 "OrganizationAllFeaturesNotEnabledException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         PutConfigurationAggregatorError::OrganizationAllFeaturesNotEnabled(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(PutConfigurationAggregatorError::Unhandled, &res)
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -6614,12 +7300,14 @@ impl fmt::Display for PutConfigurationAggregatorError {
             PutConfigurationAggregatorError::OrganizationAllFeaturesNotEnabled(ref cause) => {
                 write!(f, "{}", cause)
             }
+            PutConfigurationAggregatorError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for PutConfigurationAggregatorError {}
 /// Errors returned by PutConfigurationRecorder
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum PutConfigurationRecorderError {
     /// <p>You have provided a configuration recorder name that is not valid.</p>
     InvalidConfigurationRecorderName(String),
@@ -6629,6 +7317,8 @@ pub enum PutConfigurationRecorderError {
     InvalidRole(String),
     /// <p>You have reached the limit of the number of recorders you can create.</p>
     MaxNumberOfConfigurationRecordersExceeded(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl PutConfigurationRecorderError {
@@ -6636,29 +7326,33 @@ impl PutConfigurationRecorderError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidConfigurationRecorderNameException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         PutConfigurationRecorderError::InvalidConfigurationRecorderName(err.msg),
+                        &res,
                     )
                 }
                 "InvalidRecordingGroupException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         PutConfigurationRecorderError::InvalidRecordingGroup(err.msg),
+                        &res,
                     )
                 }
                 "InvalidRoleException" => {
-                    return RusotoError::Service(PutConfigurationRecorderError::InvalidRole(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        PutConfigurationRecorderError::InvalidRole(err.msg),
+                        &res,
+                    )
                 }
                 "MaxNumberOfConfigurationRecordersExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         PutConfigurationRecorderError::MaxNumberOfConfigurationRecordersExceeded(
                             err.msg,
                         ),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(PutConfigurationRecorderError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -6678,12 +7372,14 @@ impl fmt::Display for PutConfigurationRecorderError {
             PutConfigurationRecorderError::MaxNumberOfConfigurationRecordersExceeded(ref cause) => {
                 write!(f, "{}", cause)
             }
+            PutConfigurationRecorderError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for PutConfigurationRecorderError {}
 /// Errors returned by PutConformancePack
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum PutConformancePackError {
     /// <p>You have specified a template that is not valid or supported.</p>
     ConformancePackTemplateValidation(String),
@@ -6695,6 +7391,8 @@ pub enum PutConformancePackError {
     MaxNumberOfConformancePacksExceeded(String),
     /// <p><p>You see this exception in the following cases: </p> <ul> <li> <p>For DeleteConfigRule, AWS Config is deleting this rule. Try your request again later.</p> </li> <li> <p>For DeleteConfigRule, the rule is deleting your evaluation results. Try your request again later.</p> </li> <li> <p>For DeleteConfigRule, a remediation action is associated with the rule and AWS Config cannot delete this rule. Delete the remediation action associated with the rule before deleting the rule and try your request again later.</p> </li> <li> <p>For PutConfigOrganizationRule, organization config rule deletion is in progress. Try your request again later.</p> </li> <li> <p>For DeleteOrganizationConfigRule, organization config rule creation is in progress. Try your request again later.</p> </li> <li> <p>For PutConformancePack and PutOrganizationConformancePack, a conformance pack creation, update, and deletion is in progress. Try your request again later.</p> </li> <li> <p>For DeleteConformancePack, a conformance pack creation, update, and deletion is in progress. Try your request again later.</p> </li> </ul></p>
     ResourceInUse(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl PutConformancePackError {
@@ -6702,30 +7400,37 @@ impl PutConformancePackError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConformancePackTemplateValidationException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         PutConformancePackError::ConformancePackTemplateValidation(err.msg),
+                        &res,
                     )
                 }
                 "InsufficientPermissionsException" => {
-                    return RusotoError::Service(PutConformancePackError::InsufficientPermissions(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        PutConformancePackError::InsufficientPermissions(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(PutConformancePackError::InvalidParameterValue(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        PutConformancePackError::InvalidParameterValue(err.msg),
+                        &res,
+                    )
                 }
                 "MaxNumberOfConformancePacksExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         PutConformancePackError::MaxNumberOfConformancePacksExceeded(err.msg),
+                        &res,
                     )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(PutConformancePackError::ResourceInUse(err.msg))
+                    return RusotoError::service(
+                        PutConformancePackError::ResourceInUse(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(PutConformancePackError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -6744,12 +7449,14 @@ impl fmt::Display for PutConformancePackError {
                 write!(f, "{}", cause)
             }
             PutConformancePackError::ResourceInUse(ref cause) => write!(f, "{}", cause),
+            PutConformancePackError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for PutConformancePackError {}
 /// Errors returned by PutDeliveryChannel
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum PutDeliveryChannelError {
     /// <p>Your Amazon S3 bucket policy does not permit AWS Config to write to it.</p>
     InsufficientDeliveryPolicy(String),
@@ -6765,6 +7472,8 @@ pub enum PutDeliveryChannelError {
     NoAvailableConfigurationRecorder(String),
     /// <p>The specified Amazon S3 bucket does not exist.</p>
     NoSuchBucket(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl PutDeliveryChannelError {
@@ -6772,40 +7481,49 @@ impl PutDeliveryChannelError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InsufficientDeliveryPolicyException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         PutDeliveryChannelError::InsufficientDeliveryPolicy(err.msg),
+                        &res,
                     )
                 }
                 "InvalidDeliveryChannelNameException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         PutDeliveryChannelError::InvalidDeliveryChannelName(err.msg),
+                        &res,
                     )
                 }
                 "InvalidS3KeyPrefixException" => {
-                    return RusotoError::Service(PutDeliveryChannelError::InvalidS3KeyPrefix(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        PutDeliveryChannelError::InvalidS3KeyPrefix(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidSNSTopicARNException" => {
-                    return RusotoError::Service(PutDeliveryChannelError::InvalidSNSTopicARN(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        PutDeliveryChannelError::InvalidSNSTopicARN(err.msg),
+                        &res,
+                    )
                 }
                 "MaxNumberOfDeliveryChannelsExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         PutDeliveryChannelError::MaxNumberOfDeliveryChannelsExceeded(err.msg),
+                        &res,
                     )
                 }
                 "NoAvailableConfigurationRecorderException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         PutDeliveryChannelError::NoAvailableConfigurationRecorder(err.msg),
+                        &res,
                     )
                 }
                 "NoSuchBucketException" => {
-                    return RusotoError::Service(PutDeliveryChannelError::NoSuchBucket(err.msg))
+                    return RusotoError::service(
+                        PutDeliveryChannelError::NoSuchBucket(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(PutDeliveryChannelError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -6830,12 +7548,14 @@ impl fmt::Display for PutDeliveryChannelError {
                 write!(f, "{}", cause)
             }
             PutDeliveryChannelError::NoSuchBucket(ref cause) => write!(f, "{}", cause),
+            PutDeliveryChannelError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for PutDeliveryChannelError {}
 /// Errors returned by PutEvaluations
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum PutEvaluationsError {
     /// <p>One or more of the specified parameters are invalid. Verify that your parameters are valid and try again.</p>
     InvalidParameterValue(String),
@@ -6843,6 +7563,8 @@ pub enum PutEvaluationsError {
     InvalidResultToken(String),
     /// <p>One or more AWS Config rules in the request are invalid. Verify that the rule names are correct and try again.</p>
     NoSuchConfigRule(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl PutEvaluationsError {
@@ -6850,18 +7572,25 @@ impl PutEvaluationsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(PutEvaluationsError::InvalidParameterValue(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        PutEvaluationsError::InvalidParameterValue(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidResultTokenException" => {
-                    return RusotoError::Service(PutEvaluationsError::InvalidResultToken(err.msg))
+                    return RusotoError::service(
+                        PutEvaluationsError::InvalidResultToken(err.msg),
+                        &res,
+                    )
                 }
                 "NoSuchConfigRuleException" => {
-                    return RusotoError::Service(PutEvaluationsError::NoSuchConfigRule(err.msg))
+                    return RusotoError::service(
+                        PutEvaluationsError::NoSuchConfigRule(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(PutEvaluationsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -6874,12 +7603,14 @@ impl fmt::Display for PutEvaluationsError {
             PutEvaluationsError::InvalidParameterValue(ref cause) => write!(f, "{}", cause),
             PutEvaluationsError::InvalidResultToken(ref cause) => write!(f, "{}", cause),
             PutEvaluationsError::NoSuchConfigRule(ref cause) => write!(f, "{}", cause),
+            PutEvaluationsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for PutEvaluationsError {}
 /// Errors returned by PutOrganizationConfigRule
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum PutOrganizationConfigRuleError {
     /// <p><p>Indicates one of the following errors:</p> <ul> <li> <p>For PutConfigRule, the rule cannot be created because the IAM role assigned to AWS Config lacks permissions to perform the config:Put* action.</p> </li> <li> <p>For PutConfigRule, the AWS Lambda function cannot be invoked. Check the function ARN, and check the function&#39;s permissions.</p> </li> <li> <p>For PutOrganizationConfigRule, organization config rule cannot be created because you do not have permissions to call IAM <code>GetRole</code> action or create a service linked role.</p> </li> <li> <p>For PutConformancePack and PutOrganizationConformancePack, a conformance pack cannot be created because you do not have permissions: </p> <ul> <li> <p>To call IAM <code>GetRole</code> action or create a service linked role.</p> </li> <li> <p>To read Amazon S3 bucket.</p> </li> </ul> </li> </ul></p>
     InsufficientPermissions(String),
@@ -6895,6 +7626,8 @@ pub enum PutOrganizationConfigRuleError {
     OrganizationAllFeaturesNotEnabled(String),
     /// <p><p>You see this exception in the following cases: </p> <ul> <li> <p>For DeleteConfigRule, AWS Config is deleting this rule. Try your request again later.</p> </li> <li> <p>For DeleteConfigRule, the rule is deleting your evaluation results. Try your request again later.</p> </li> <li> <p>For DeleteConfigRule, a remediation action is associated with the rule and AWS Config cannot delete this rule. Delete the remediation action associated with the rule before deleting the rule and try your request again later.</p> </li> <li> <p>For PutConfigOrganizationRule, organization config rule deletion is in progress. Try your request again later.</p> </li> <li> <p>For DeleteOrganizationConfigRule, organization config rule creation is in progress. Try your request again later.</p> </li> <li> <p>For PutConformancePack and PutOrganizationConformancePack, a conformance pack creation, update, and deletion is in progress. Try your request again later.</p> </li> <li> <p>For DeleteConformancePack, a conformance pack creation, update, and deletion is in progress. Try your request again later.</p> </li> </ul></p>
     ResourceInUse(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl PutOrganizationConfigRuleError {
@@ -6902,44 +7635,53 @@ impl PutOrganizationConfigRuleError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InsufficientPermissionsException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         PutOrganizationConfigRuleError::InsufficientPermissions(err.msg),
+                        &res,
                     )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         PutOrganizationConfigRuleError::InvalidParameterValue(err.msg),
+                        &res,
                     )
                 }
                 "MaxNumberOfOrganizationConfigRulesExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         PutOrganizationConfigRuleError::MaxNumberOfOrganizationConfigRulesExceeded(
                             err.msg,
                         ),
+                        &res,
                     )
                 }
                 "NoAvailableOrganizationException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         PutOrganizationConfigRuleError::NoAvailableOrganization(err.msg),
+                        &res,
                     )
                 }
                 "OrganizationAccessDeniedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         PutOrganizationConfigRuleError::OrganizationAccessDenied(err.msg),
+                        &res,
                     )
                 }
                 "OrganizationAllFeaturesNotEnabledException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         PutOrganizationConfigRuleError::OrganizationAllFeaturesNotEnabled(err.msg),
+                        &res,
                     )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(PutOrganizationConfigRuleError::ResourceInUse(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        PutOrganizationConfigRuleError::ResourceInUse(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(PutOrganizationConfigRuleError::Unhandled, &res)
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -6968,12 +7710,14 @@ impl fmt::Display for PutOrganizationConfigRuleError {
                 write!(f, "{}", cause)
             }
             PutOrganizationConfigRuleError::ResourceInUse(ref cause) => write!(f, "{}", cause),
+            PutOrganizationConfigRuleError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for PutOrganizationConfigRuleError {}
 /// Errors returned by PutOrganizationConformancePack
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum PutOrganizationConformancePackError {
     /// <p><p>Indicates one of the following errors:</p> <ul> <li> <p>For PutConfigRule, the rule cannot be created because the IAM role assigned to AWS Config lacks permissions to perform the config:Put* action.</p> </li> <li> <p>For PutConfigRule, the AWS Lambda function cannot be invoked. Check the function ARN, and check the function&#39;s permissions.</p> </li> <li> <p>For PutOrganizationConfigRule, organization config rule cannot be created because you do not have permissions to call IAM <code>GetRole</code> action or create a service linked role.</p> </li> <li> <p>For PutConformancePack and PutOrganizationConformancePack, a conformance pack cannot be created because you do not have permissions: </p> <ul> <li> <p>To call IAM <code>GetRole</code> action or create a service linked role.</p> </li> <li> <p>To read Amazon S3 bucket.</p> </li> </ul> </li> </ul></p>
     InsufficientPermissions(String),
@@ -6989,6 +7733,8 @@ pub enum PutOrganizationConformancePackError {
     OrganizationConformancePackTemplateValidation(String),
     /// <p><p>You see this exception in the following cases: </p> <ul> <li> <p>For DeleteConfigRule, AWS Config is deleting this rule. Try your request again later.</p> </li> <li> <p>For DeleteConfigRule, the rule is deleting your evaluation results. Try your request again later.</p> </li> <li> <p>For DeleteConfigRule, a remediation action is associated with the rule and AWS Config cannot delete this rule. Delete the remediation action associated with the rule before deleting the rule and try your request again later.</p> </li> <li> <p>For PutConfigOrganizationRule, organization config rule deletion is in progress. Try your request again later.</p> </li> <li> <p>For DeleteOrganizationConfigRule, organization config rule creation is in progress. Try your request again later.</p> </li> <li> <p>For PutConformancePack and PutOrganizationConformancePack, a conformance pack creation, update, and deletion is in progress. Try your request again later.</p> </li> <li> <p>For DeleteConformancePack, a conformance pack creation, update, and deletion is in progress. Try your request again later.</p> </li> </ul></p>
     ResourceInUse(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl PutOrganizationConformancePackError {
@@ -6997,15 +7743,15 @@ impl PutOrganizationConformancePackError {
     ) -> RusotoError<PutOrganizationConformancePackError> {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
-                                "InsufficientPermissionsException" => return RusotoError::Service(PutOrganizationConformancePackError::InsufficientPermissions(err.msg)),
-"MaxNumberOfOrganizationConformancePacksExceededException" => return RusotoError::Service(PutOrganizationConformancePackError::MaxNumberOfOrganizationConformancePacksExceeded(err.msg)),
-"NoAvailableOrganizationException" => return RusotoError::Service(PutOrganizationConformancePackError::NoAvailableOrganization(err.msg)),
-"OrganizationAccessDeniedException" => return RusotoError::Service(PutOrganizationConformancePackError::OrganizationAccessDenied(err.msg)),
-"OrganizationAllFeaturesNotEnabledException" => return RusotoError::Service(PutOrganizationConformancePackError::OrganizationAllFeaturesNotEnabled(err.msg)),
-"OrganizationConformancePackTemplateValidationException" => return RusotoError::Service(PutOrganizationConformancePackError::OrganizationConformancePackTemplateValidation(err.msg)),
-"ResourceInUseException" => return RusotoError::Service(PutOrganizationConformancePackError::ResourceInUse(err.msg)),
+                                "InsufficientPermissionsException" => return RusotoError::service(PutOrganizationConformancePackError::InsufficientPermissions(err.msg), &res),
+"MaxNumberOfOrganizationConformancePacksExceededException" => return RusotoError::service(PutOrganizationConformancePackError::MaxNumberOfOrganizationConformancePacksExceeded(err.msg), &res),
+"NoAvailableOrganizationException" => return RusotoError::service(PutOrganizationConformancePackError::NoAvailableOrganization(err.msg), &res),
+"OrganizationAccessDeniedException" => return RusotoError::service(PutOrganizationConformancePackError::OrganizationAccessDenied(err.msg), &res),
+"OrganizationAllFeaturesNotEnabledException" => return RusotoError::service(PutOrganizationConformancePackError::OrganizationAllFeaturesNotEnabled(err.msg), &res),
+"OrganizationConformancePackTemplateValidationException" => return RusotoError::service(PutOrganizationConformancePackError::OrganizationConformancePackTemplateValidation(err.msg), &res),
+"ResourceInUseException" => return RusotoError::service(PutOrganizationConformancePackError::ResourceInUse(err.msg), &res),
 "ValidationException" => return RusotoError::Validation(err.msg),
-_ => {}
+_ => return RusotoError::unhandled(PutOrganizationConformancePackError::Unhandled, &res),
                             }
         }
         RusotoError::Unknown(res)
@@ -7021,18 +7767,22 @@ PutOrganizationConformancePackError::NoAvailableOrganization(ref cause) => write
 PutOrganizationConformancePackError::OrganizationAccessDenied(ref cause) => write!(f, "{}", cause),
 PutOrganizationConformancePackError::OrganizationAllFeaturesNotEnabled(ref cause) => write!(f, "{}", cause),
 PutOrganizationConformancePackError::OrganizationConformancePackTemplateValidation(ref cause) => write!(f, "{}", cause),
-PutOrganizationConformancePackError::ResourceInUse(ref cause) => write!(f, "{}", cause)
-                        }
+PutOrganizationConformancePackError::ResourceInUse(ref cause) => write!(f, "{}", cause),
+            PutOrganizationConformancePackError::Unhandled(ref metadata) => write!(f, "{}", metadata),
+        }
     }
 }
 impl Error for PutOrganizationConformancePackError {}
 /// Errors returned by PutRemediationConfigurations
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum PutRemediationConfigurationsError {
     /// <p><p>Indicates one of the following errors:</p> <ul> <li> <p>For PutConfigRule, the rule cannot be created because the IAM role assigned to AWS Config lacks permissions to perform the config:Put* action.</p> </li> <li> <p>For PutConfigRule, the AWS Lambda function cannot be invoked. Check the function ARN, and check the function&#39;s permissions.</p> </li> <li> <p>For PutOrganizationConfigRule, organization config rule cannot be created because you do not have permissions to call IAM <code>GetRole</code> action or create a service linked role.</p> </li> <li> <p>For PutConformancePack and PutOrganizationConformancePack, a conformance pack cannot be created because you do not have permissions: </p> <ul> <li> <p>To call IAM <code>GetRole</code> action or create a service linked role.</p> </li> <li> <p>To read Amazon S3 bucket.</p> </li> </ul> </li> </ul></p>
     InsufficientPermissions(String),
     /// <p>One or more of the specified parameters are invalid. Verify that your parameters are valid and try again.</p>
     InvalidParameterValue(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl PutRemediationConfigurationsError {
@@ -7042,17 +7792,24 @@ impl PutRemediationConfigurationsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InsufficientPermissionsException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         PutRemediationConfigurationsError::InsufficientPermissions(err.msg),
+                        &res,
                     )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         PutRemediationConfigurationsError::InvalidParameterValue(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        PutRemediationConfigurationsError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -7068,15 +7825,19 @@ impl fmt::Display for PutRemediationConfigurationsError {
             PutRemediationConfigurationsError::InvalidParameterValue(ref cause) => {
                 write!(f, "{}", cause)
             }
+            PutRemediationConfigurationsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for PutRemediationConfigurationsError {}
 /// Errors returned by PutRemediationExceptions
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum PutRemediationExceptionsError {
     /// <p>One or more of the specified parameters are invalid. Verify that your parameters are valid and try again.</p>
     InvalidParameterValue(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl PutRemediationExceptionsError {
@@ -7084,12 +7845,13 @@ impl PutRemediationExceptionsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         PutRemediationExceptionsError::InvalidParameterValue(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(PutRemediationExceptionsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -7102,12 +7864,14 @@ impl fmt::Display for PutRemediationExceptionsError {
             PutRemediationExceptionsError::InvalidParameterValue(ref cause) => {
                 write!(f, "{}", cause)
             }
+            PutRemediationExceptionsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for PutRemediationExceptionsError {}
 /// Errors returned by PutResourceConfig
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum PutResourceConfigError {
     /// <p><p>Indicates one of the following errors:</p> <ul> <li> <p>For PutConfigRule, the rule cannot be created because the IAM role assigned to AWS Config lacks permissions to perform the config:Put* action.</p> </li> <li> <p>For PutConfigRule, the AWS Lambda function cannot be invoked. Check the function ARN, and check the function&#39;s permissions.</p> </li> <li> <p>For PutOrganizationConfigRule, organization config rule cannot be created because you do not have permissions to call IAM <code>GetRole</code> action or create a service linked role.</p> </li> <li> <p>For PutConformancePack and PutOrganizationConformancePack, a conformance pack cannot be created because you do not have permissions: </p> <ul> <li> <p>To call IAM <code>GetRole</code> action or create a service linked role.</p> </li> <li> <p>To read Amazon S3 bucket.</p> </li> </ul> </li> </ul></p>
     InsufficientPermissions(String),
@@ -7115,6 +7879,8 @@ pub enum PutResourceConfigError {
     MaxActiveResourcesExceeded(String),
     /// <p>There is no configuration recorder running.</p>
     NoRunningConfigurationRecorder(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl PutResourceConfigError {
@@ -7122,22 +7888,25 @@ impl PutResourceConfigError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InsufficientPermissionsException" => {
-                    return RusotoError::Service(PutResourceConfigError::InsufficientPermissions(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        PutResourceConfigError::InsufficientPermissions(err.msg),
+                        &res,
+                    )
                 }
                 "MaxActiveResourcesExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         PutResourceConfigError::MaxActiveResourcesExceeded(err.msg),
+                        &res,
                     )
                 }
                 "NoRunningConfigurationRecorderException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         PutResourceConfigError::NoRunningConfigurationRecorder(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(PutResourceConfigError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -7152,17 +7921,21 @@ impl fmt::Display for PutResourceConfigError {
             PutResourceConfigError::NoRunningConfigurationRecorder(ref cause) => {
                 write!(f, "{}", cause)
             }
+            PutResourceConfigError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for PutResourceConfigError {}
 /// Errors returned by PutRetentionConfiguration
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum PutRetentionConfigurationError {
     /// <p>One or more of the specified parameters are invalid. Verify that your parameters are valid and try again.</p>
     InvalidParameterValue(String),
     /// <p>Failed to add the retention configuration because a retention configuration with that name already exists.</p>
     MaxNumberOfRetentionConfigurationsExceeded(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl PutRetentionConfigurationError {
@@ -7170,19 +7943,23 @@ impl PutRetentionConfigurationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         PutRetentionConfigurationError::InvalidParameterValue(err.msg),
+                        &res,
                     )
                 }
                 "MaxNumberOfRetentionConfigurationsExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         PutRetentionConfigurationError::MaxNumberOfRetentionConfigurationsExceeded(
                             err.msg,
                         ),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(PutRetentionConfigurationError::Unhandled, &res)
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -7198,12 +7975,14 @@ impl fmt::Display for PutRetentionConfigurationError {
             PutRetentionConfigurationError::MaxNumberOfRetentionConfigurationsExceeded(
                 ref cause,
             ) => write!(f, "{}", cause),
+            PutRetentionConfigurationError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for PutRetentionConfigurationError {}
 /// Errors returned by SelectResourceConfig
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum SelectResourceConfigError {
     /// <p>The syntax of the query is incorrect.</p>
     InvalidExpression(String),
@@ -7211,6 +7990,8 @@ pub enum SelectResourceConfigError {
     InvalidLimit(String),
     /// <p>The specified next token is invalid. Specify the <code>nextToken</code> string that was returned in the previous response to get the next page of results.</p>
     InvalidNextToken(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl SelectResourceConfigError {
@@ -7218,20 +7999,25 @@ impl SelectResourceConfigError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidExpressionException" => {
-                    return RusotoError::Service(SelectResourceConfigError::InvalidExpression(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        SelectResourceConfigError::InvalidExpression(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidLimitException" => {
-                    return RusotoError::Service(SelectResourceConfigError::InvalidLimit(err.msg))
+                    return RusotoError::service(
+                        SelectResourceConfigError::InvalidLimit(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(SelectResourceConfigError::InvalidNextToken(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        SelectResourceConfigError::InvalidNextToken(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(SelectResourceConfigError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -7244,12 +8030,14 @@ impl fmt::Display for SelectResourceConfigError {
             SelectResourceConfigError::InvalidExpression(ref cause) => write!(f, "{}", cause),
             SelectResourceConfigError::InvalidLimit(ref cause) => write!(f, "{}", cause),
             SelectResourceConfigError::InvalidNextToken(ref cause) => write!(f, "{}", cause),
+            SelectResourceConfigError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for SelectResourceConfigError {}
 /// Errors returned by StartConfigRulesEvaluation
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum StartConfigRulesEvaluationError {
     /// <p>One or more of the specified parameters are invalid. Verify that your parameters are valid and try again.</p>
     InvalidParameterValue(String),
@@ -7259,6 +8047,8 @@ pub enum StartConfigRulesEvaluationError {
     NoSuchConfigRule(String),
     /// <p><p>You see this exception in the following cases: </p> <ul> <li> <p>For DeleteConfigRule, AWS Config is deleting this rule. Try your request again later.</p> </li> <li> <p>For DeleteConfigRule, the rule is deleting your evaluation results. Try your request again later.</p> </li> <li> <p>For DeleteConfigRule, a remediation action is associated with the rule and AWS Config cannot delete this rule. Delete the remediation action associated with the rule before deleting the rule and try your request again later.</p> </li> <li> <p>For PutConfigOrganizationRule, organization config rule deletion is in progress. Try your request again later.</p> </li> <li> <p>For DeleteOrganizationConfigRule, organization config rule creation is in progress. Try your request again later.</p> </li> <li> <p>For PutConformancePack and PutOrganizationConformancePack, a conformance pack creation, update, and deletion is in progress. Try your request again later.</p> </li> <li> <p>For DeleteConformancePack, a conformance pack creation, update, and deletion is in progress. Try your request again later.</p> </li> </ul></p>
     ResourceInUse(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl StartConfigRulesEvaluationError {
@@ -7268,27 +8058,33 @@ impl StartConfigRulesEvaluationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         StartConfigRulesEvaluationError::InvalidParameterValue(err.msg),
+                        &res,
                     )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(StartConfigRulesEvaluationError::LimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        StartConfigRulesEvaluationError::LimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "NoSuchConfigRuleException" => {
-                    return RusotoError::Service(StartConfigRulesEvaluationError::NoSuchConfigRule(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        StartConfigRulesEvaluationError::NoSuchConfigRule(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(StartConfigRulesEvaluationError::ResourceInUse(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        StartConfigRulesEvaluationError::ResourceInUse(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(StartConfigRulesEvaluationError::Unhandled, &res)
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -7304,17 +8100,21 @@ impl fmt::Display for StartConfigRulesEvaluationError {
             StartConfigRulesEvaluationError::LimitExceeded(ref cause) => write!(f, "{}", cause),
             StartConfigRulesEvaluationError::NoSuchConfigRule(ref cause) => write!(f, "{}", cause),
             StartConfigRulesEvaluationError::ResourceInUse(ref cause) => write!(f, "{}", cause),
+            StartConfigRulesEvaluationError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for StartConfigRulesEvaluationError {}
 /// Errors returned by StartConfigurationRecorder
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum StartConfigurationRecorderError {
     /// <p>There is no delivery channel available to record configurations.</p>
     NoAvailableDeliveryChannel(String),
     /// <p>You have specified a configuration recorder that does not exist.</p>
     NoSuchConfigurationRecorder(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl StartConfigurationRecorderError {
@@ -7324,17 +8124,21 @@ impl StartConfigurationRecorderError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "NoAvailableDeliveryChannelException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         StartConfigurationRecorderError::NoAvailableDeliveryChannel(err.msg),
+                        &res,
                     )
                 }
                 "NoSuchConfigurationRecorderException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         StartConfigurationRecorderError::NoSuchConfigurationRecorder(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(StartConfigurationRecorderError::Unhandled, &res)
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -7350,12 +8154,14 @@ impl fmt::Display for StartConfigurationRecorderError {
             StartConfigurationRecorderError::NoSuchConfigurationRecorder(ref cause) => {
                 write!(f, "{}", cause)
             }
+            StartConfigurationRecorderError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for StartConfigurationRecorderError {}
 /// Errors returned by StartRemediationExecution
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum StartRemediationExecutionError {
     /// <p><p>Indicates one of the following errors:</p> <ul> <li> <p>For PutConfigRule, the rule cannot be created because the IAM role assigned to AWS Config lacks permissions to perform the config:Put* action.</p> </li> <li> <p>For PutConfigRule, the AWS Lambda function cannot be invoked. Check the function ARN, and check the function&#39;s permissions.</p> </li> <li> <p>For PutOrganizationConfigRule, organization config rule cannot be created because you do not have permissions to call IAM <code>GetRole</code> action or create a service linked role.</p> </li> <li> <p>For PutConformancePack and PutOrganizationConformancePack, a conformance pack cannot be created because you do not have permissions: </p> <ul> <li> <p>To call IAM <code>GetRole</code> action or create a service linked role.</p> </li> <li> <p>To read Amazon S3 bucket.</p> </li> </ul> </li> </ul></p>
     InsufficientPermissions(String),
@@ -7363,6 +8169,8 @@ pub enum StartRemediationExecutionError {
     InvalidParameterValue(String),
     /// <p>You specified an AWS Config rule without a remediation configuration.</p>
     NoSuchRemediationConfiguration(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl StartRemediationExecutionError {
@@ -7370,22 +8178,27 @@ impl StartRemediationExecutionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InsufficientPermissionsException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         StartRemediationExecutionError::InsufficientPermissions(err.msg),
+                        &res,
                     )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         StartRemediationExecutionError::InvalidParameterValue(err.msg),
+                        &res,
                     )
                 }
                 "NoSuchRemediationConfigurationException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         StartRemediationExecutionError::NoSuchRemediationConfiguration(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(StartRemediationExecutionError::Unhandled, &res)
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -7404,15 +8217,19 @@ impl fmt::Display for StartRemediationExecutionError {
             StartRemediationExecutionError::NoSuchRemediationConfiguration(ref cause) => {
                 write!(f, "{}", cause)
             }
+            StartRemediationExecutionError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for StartRemediationExecutionError {}
 /// Errors returned by StopConfigurationRecorder
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum StopConfigurationRecorderError {
     /// <p>You have specified a configuration recorder that does not exist.</p>
     NoSuchConfigurationRecorder(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl StopConfigurationRecorderError {
@@ -7420,12 +8237,15 @@ impl StopConfigurationRecorderError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "NoSuchConfigurationRecorderException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         StopConfigurationRecorderError::NoSuchConfigurationRecorder(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(StopConfigurationRecorderError::Unhandled, &res)
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -7438,17 +8258,21 @@ impl fmt::Display for StopConfigurationRecorderError {
             StopConfigurationRecorderError::NoSuchConfigurationRecorder(ref cause) => {
                 write!(f, "{}", cause)
             }
+            StopConfigurationRecorderError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for StopConfigurationRecorderError {}
 /// Errors returned by TagResource
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum TagResourceError {
     /// <p>You have specified a resource that does not exist.</p>
     ResourceNotFound(String),
     /// <p>You have reached the limit of the number of tags you can use. You have more than 50 tags.</p>
     TooManyTags(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl TagResourceError {
@@ -7456,13 +8280,13 @@ impl TagResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(TagResourceError::ResourceNotFound(err.msg))
+                    return RusotoError::service(TagResourceError::ResourceNotFound(err.msg), &res)
                 }
                 "TooManyTagsException" => {
-                    return RusotoError::Service(TagResourceError::TooManyTags(err.msg))
+                    return RusotoError::service(TagResourceError::TooManyTags(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(TagResourceError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -7474,15 +8298,19 @@ impl fmt::Display for TagResourceError {
         match *self {
             TagResourceError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             TagResourceError::TooManyTags(ref cause) => write!(f, "{}", cause),
+            TagResourceError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for TagResourceError {}
 /// Errors returned by UntagResource
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UntagResourceError {
     /// <p>You have specified a resource that does not exist.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UntagResourceError {
@@ -7490,10 +8318,13 @@ impl UntagResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UntagResourceError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        UntagResourceError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UntagResourceError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -7504,6 +8335,7 @@ impl fmt::Display for UntagResourceError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             UntagResourceError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            UntagResourceError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
@@ -8094,6 +8926,7 @@ impl ConfigServiceClient {
     /// Creates a client backed by the default tokio event loop.
     ///
     /// The client will use the default credentials provider and tls client.
+    #[cfg(not(target_arch = "wasm32"))]
     pub fn new(region: region::Region) -> ConfigServiceClient {
         ConfigServiceClient {
             client: Client::shared(),
@@ -8119,80 +8952,186 @@ impl ConfigServiceClient {
     pub fn new_with_client(client: Client, region: region::Region) -> ConfigServiceClient {
         ConfigServiceClient { client, region }
     }
+
+    /// Returns a builder of a client, setting any of its region, credentials provider,
+    /// dispatcher, retry policy, endpoint and timeouts.
+    pub fn builder() -> rusoto_core::ClientBuilder<ConfigServiceClient> {
+        rusoto_core::ClientBuilder::new(ConfigServiceClient::new_with_client)
+    }
+
+    /// Returns a clone of this client with the overrides of `config` applied to its
+    /// operations, sharing its connections.
+    pub fn customize(&self, config: &rusoto_core::OperationConfig) -> ConfigServiceClient {
+        ConfigServiceClient {
+            client: self.client.customize(config),
+            region: config.resolve_region(&self.region),
+        }
+    }
 }
 
-#[async_trait]
-impl ConfigService for ConfigServiceClient {
-    /// <p><p>Returns the current configuration items for resources that are present in your AWS Config aggregator. The operation also returns a list of resources that are not processed in the current request. If there are no unprocessed resources, the operation returns an empty <code>unprocessedResourceIdentifiers</code> list. </p> <note> <ul> <li> <p>The API does not return results for deleted resources.</p> </li> <li> <p> The API does not return tags and relationships.</p> </li> </ul> </note></p>
-    async fn batch_get_aggregate_resource_config(
+impl ConfigServiceClient {
+    /// Calls `batch_get_aggregate_resource_config`, also returning the metadata of the response.
+    pub async fn batch_get_aggregate_resource_config_with_metadata(
         &self,
         input: BatchGetAggregateResourceConfigRequest,
     ) -> Result<
-        BatchGetAggregateResourceConfigResponse,
+        (BatchGetAggregateResourceConfigResponse, ResponseMetadata),
         RusotoError<BatchGetAggregateResourceConfigError>,
     > {
-        let mut request = SignedRequest::new("POST", "config", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "StarlingDoveService.BatchGetAggregateResourceConfig",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
+        let request = self.build_batch_get_aggregate_resource_config_request(input);
 
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<BatchGetAggregateResourceConfigResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<BatchGetAggregateResourceConfigResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
             Err(BatchGetAggregateResourceConfigError::from_response(
                 response,
             ))
         }
     }
 
-    /// <p><p>Returns the current configuration for one or more requested resources. The operation also returns a list of resources that are not processed in the current request. If there are no unprocessed resources, the operation returns an empty unprocessedResourceKeys list. </p> <note> <ul> <li> <p>The API does not return results for deleted resources.</p> </li> <li> <p> The API does not return any tags for the requested resources. This information is filtered out of the supplementaryConfiguration section of the API response.</p> </li> </ul> </note></p>
-    async fn batch_get_resource_config(
+    /// Presigns the request `batch_get_aggregate_resource_config` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_batch_get_aggregate_resource_config(
         &self,
-        input: BatchGetResourceConfigRequest,
-    ) -> Result<BatchGetResourceConfigResponse, RusotoError<BatchGetResourceConfigError>> {
+        presigner: &rusoto_core::Presigner,
+        input: BatchGetAggregateResourceConfigRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_batch_get_aggregate_resource_config_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_batch_get_aggregate_resource_config_request(
+        &self,
+        input: BatchGetAggregateResourceConfigRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "config", &self.region, "/");
+        request.set_operation("BatchGetAggregateResourceConfig");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "StarlingDoveService.BatchGetResourceConfig");
+        request.add_header(
+            "x-amz-target",
+            "StarlingDoveService.BatchGetAggregateResourceConfig",
+        );
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
-        let mut response = self
+        request
+    }
+
+    /// Calls `batch_get_resource_config`, also returning the metadata of the response.
+    pub async fn batch_get_resource_config_with_metadata(
+        &self,
+        input: BatchGetResourceConfigRequest,
+    ) -> Result<
+        (BatchGetResourceConfigResponse, ResponseMetadata),
+        RusotoError<BatchGetResourceConfigError>,
+    > {
+        let request = self.build_batch_get_resource_config_request(input);
+
+        let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<BatchGetResourceConfigResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<BatchGetResourceConfigResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
             Err(BatchGetResourceConfigError::from_response(response))
         }
     }
 
-    /// <p>Deletes the authorization granted to the specified configuration aggregator account in a specified region.</p>
-    async fn delete_aggregation_authorization(
+    /// Presigns the request `batch_get_resource_config` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_batch_get_resource_config(
+        &self,
+        presigner: &rusoto_core::Presigner,
+        input: BatchGetResourceConfigRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_batch_get_resource_config_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_batch_get_resource_config_request(
+        &self,
+        input: BatchGetResourceConfigRequest,
+    ) -> SignedRequest {
+        let mut request = SignedRequest::new("POST", "config", &self.region, "/");
+        request.set_operation("BatchGetResourceConfig");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "StarlingDoveService.BatchGetResourceConfig");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        request
+    }
+
+    /// Calls `delete_aggregation_authorization`, also returning the metadata of the response.
+    pub async fn delete_aggregation_authorization_with_metadata(
         &self,
         input: DeleteAggregationAuthorizationRequest,
-    ) -> Result<(), RusotoError<DeleteAggregationAuthorizationError>> {
+    ) -> Result<((), ResponseMetadata), RusotoError<DeleteAggregationAuthorizationError>> {
+        let request = self.build_delete_aggregation_authorization_request(input);
+
+        let mut response = self
+            .client
+            .sign_and_dispatch(request)
+            .await
+            .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
+        if response.status.is_success() {
+            std::mem::drop(response);
+            Ok(((), metadata))
+        } else {
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DeleteAggregationAuthorizationError::from_response(response))
+        }
+    }
+
+    /// Presigns the request `delete_aggregation_authorization` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_aggregation_authorization(
+        &self,
+        presigner: &rusoto_core::Presigner,
+        input: DeleteAggregationAuthorizationRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_aggregation_authorization_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_aggregation_authorization_request(
+        &self,
+        input: DeleteAggregationAuthorizationRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "config", &self.region, "/");
+        request.set_operation("DeleteAggregationAuthorization");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -8202,54 +9141,97 @@ impl ConfigService for ConfigServiceClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `delete_config_rule`, also returning the metadata of the response.
+    pub async fn delete_config_rule_with_metadata(
+        &self,
+        input: DeleteConfigRuleRequest,
+    ) -> Result<((), ResponseMetadata), RusotoError<DeleteConfigRuleError>> {
+        let request = self.build_delete_config_rule_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
             std::mem::drop(response);
-            Ok(())
+            Ok(((), metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteAggregationAuthorizationError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DeleteConfigRuleError::from_response(response))
         }
     }
 
-    /// <p>Deletes the specified AWS Config rule and all of its evaluation results.</p> <p>AWS Config sets the state of a rule to <code>DELETING</code> until the deletion is complete. You cannot update a rule while it is in this state. If you make a <code>PutConfigRule</code> or <code>DeleteConfigRule</code> request for the rule, you will receive a <code>ResourceInUseException</code>.</p> <p>You can check the state of a rule by using the <code>DescribeConfigRules</code> request.</p>
-    async fn delete_config_rule(
+    /// Presigns the request `delete_config_rule` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_config_rule(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteConfigRuleRequest,
-    ) -> Result<(), RusotoError<DeleteConfigRuleError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_config_rule_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_config_rule_request(&self, input: DeleteConfigRuleRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "config", &self.region, "/");
+        request.set_operation("DeleteConfigRule");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "StarlingDoveService.DeleteConfigRule");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `delete_configuration_aggregator`, also returning the metadata of the response.
+    pub async fn delete_configuration_aggregator_with_metadata(
+        &self,
+        input: DeleteConfigurationAggregatorRequest,
+    ) -> Result<((), ResponseMetadata), RusotoError<DeleteConfigurationAggregatorError>> {
+        let request = self.build_delete_configuration_aggregator_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
             std::mem::drop(response);
-            Ok(())
+            Ok(((), metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteConfigRuleError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DeleteConfigurationAggregatorError::from_response(response))
         }
     }
 
-    /// <p>Deletes the specified configuration aggregator and the aggregated data associated with the aggregator.</p>
-    async fn delete_configuration_aggregator(
+    /// Presigns the request `delete_configuration_aggregator` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_configuration_aggregator(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteConfigurationAggregatorRequest,
-    ) -> Result<(), RusotoError<DeleteConfigurationAggregatorError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_configuration_aggregator_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_configuration_aggregator_request(
+        &self,
+        input: DeleteConfigurationAggregatorRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "config", &self.region, "/");
+        request.set_operation("DeleteConfigurationAggregator");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -8259,27 +9241,50 @@ impl ConfigService for ConfigServiceClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `delete_configuration_recorder`, also returning the metadata of the response.
+    pub async fn delete_configuration_recorder_with_metadata(
+        &self,
+        input: DeleteConfigurationRecorderRequest,
+    ) -> Result<((), ResponseMetadata), RusotoError<DeleteConfigurationRecorderError>> {
+        let request = self.build_delete_configuration_recorder_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
             std::mem::drop(response);
-            Ok(())
+            Ok(((), metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteConfigurationAggregatorError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DeleteConfigurationRecorderError::from_response(response))
         }
     }
 
-    /// <p>Deletes the configuration recorder.</p> <p>After the configuration recorder is deleted, AWS Config will not record resource configuration changes until you create a new configuration recorder.</p> <p>This action does not delete the configuration information that was previously recorded. You will be able to access the previously recorded information by using the <code>GetResourceConfigHistory</code> action, but you will not be able to access this information in the AWS Config console until you create a new configuration recorder.</p>
-    async fn delete_configuration_recorder(
+    /// Presigns the request `delete_configuration_recorder` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_configuration_recorder(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteConfigurationRecorderRequest,
-    ) -> Result<(), RusotoError<DeleteConfigurationRecorderError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_configuration_recorder_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_configuration_recorder_request(
+        &self,
+        input: DeleteConfigurationRecorderRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "config", &self.region, "/");
+        request.set_operation("DeleteConfigurationRecorder");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -8289,81 +9294,158 @@ impl ConfigService for ConfigServiceClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `delete_conformance_pack`, also returning the metadata of the response.
+    pub async fn delete_conformance_pack_with_metadata(
+        &self,
+        input: DeleteConformancePackRequest,
+    ) -> Result<((), ResponseMetadata), RusotoError<DeleteConformancePackError>> {
+        let request = self.build_delete_conformance_pack_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
             std::mem::drop(response);
-            Ok(())
+            Ok(((), metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteConfigurationRecorderError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DeleteConformancePackError::from_response(response))
         }
     }
 
-    /// <p>Deletes the specified conformance pack and all the AWS Config rules, remediation actions, and all evaluation results within that conformance pack.</p> <p>AWS Config sets the conformance pack to <code>DELETE_IN_PROGRESS</code> until the deletion is complete. You cannot update a conformance pack while it is in this state.</p>
-    async fn delete_conformance_pack(
+    /// Presigns the request `delete_conformance_pack` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_conformance_pack(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteConformancePackRequest,
-    ) -> Result<(), RusotoError<DeleteConformancePackError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_conformance_pack_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_conformance_pack_request(
+        &self,
+        input: DeleteConformancePackRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "config", &self.region, "/");
+        request.set_operation("DeleteConformancePack");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "StarlingDoveService.DeleteConformancePack");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `delete_delivery_channel`, also returning the metadata of the response.
+    pub async fn delete_delivery_channel_with_metadata(
+        &self,
+        input: DeleteDeliveryChannelRequest,
+    ) -> Result<((), ResponseMetadata), RusotoError<DeleteDeliveryChannelError>> {
+        let request = self.build_delete_delivery_channel_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
             std::mem::drop(response);
-            Ok(())
+            Ok(((), metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteConformancePackError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DeleteDeliveryChannelError::from_response(response))
         }
     }
 
-    /// <p>Deletes the delivery channel.</p> <p>Before you can delete the delivery channel, you must stop the configuration recorder by using the <a>StopConfigurationRecorder</a> action.</p>
-    async fn delete_delivery_channel(
+    /// Presigns the request `delete_delivery_channel` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_delivery_channel(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteDeliveryChannelRequest,
-    ) -> Result<(), RusotoError<DeleteDeliveryChannelError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_delivery_channel_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_delivery_channel_request(
+        &self,
+        input: DeleteDeliveryChannelRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "config", &self.region, "/");
+        request.set_operation("DeleteDeliveryChannel");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "StarlingDoveService.DeleteDeliveryChannel");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `delete_evaluation_results`, also returning the metadata of the response.
+    pub async fn delete_evaluation_results_with_metadata(
+        &self,
+        input: DeleteEvaluationResultsRequest,
+    ) -> Result<
+        (DeleteEvaluationResultsResponse, ResponseMetadata),
+        RusotoError<DeleteEvaluationResultsError>,
+    > {
+        let request = self.build_delete_evaluation_results_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            std::mem::drop(response);
-            Ok(())
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DeleteEvaluationResultsResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteDeliveryChannelError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DeleteEvaluationResultsError::from_response(response))
         }
     }
 
-    /// <p>Deletes the evaluation results for the specified AWS Config rule. You can specify one AWS Config rule per request. After you delete the evaluation results, you can call the <a>StartConfigRulesEvaluation</a> API to start evaluating your AWS resources against the rule.</p>
-    async fn delete_evaluation_results(
+    /// Presigns the request `delete_evaluation_results` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_evaluation_results(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteEvaluationResultsRequest,
-    ) -> Result<DeleteEvaluationResultsResponse, RusotoError<DeleteEvaluationResultsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_evaluation_results_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_evaluation_results_request(
+        &self,
+        input: DeleteEvaluationResultsRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "config", &self.region, "/");
+        request.set_operation("DeleteEvaluationResults");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -8373,28 +9455,50 @@ impl ConfigService for ConfigServiceClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `delete_organization_config_rule`, also returning the metadata of the response.
+    pub async fn delete_organization_config_rule_with_metadata(
+        &self,
+        input: DeleteOrganizationConfigRuleRequest,
+    ) -> Result<((), ResponseMetadata), RusotoError<DeleteOrganizationConfigRuleError>> {
+        let request = self.build_delete_organization_config_rule_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DeleteEvaluationResultsResponse, _>()
+            std::mem::drop(response);
+            Ok(((), metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteEvaluationResultsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DeleteOrganizationConfigRuleError::from_response(response))
         }
     }
 
-    /// <p>Deletes the specified organization config rule and all of its evaluation results from all member accounts in that organization. Only a master account can delete an organization config rule.</p> <p>AWS Config sets the state of a rule to DELETE_IN_PROGRESS until the deletion is complete. You cannot update a rule while it is in this state.</p>
-    async fn delete_organization_config_rule(
+    /// Presigns the request `delete_organization_config_rule` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_organization_config_rule(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteOrganizationConfigRuleRequest,
-    ) -> Result<(), RusotoError<DeleteOrganizationConfigRuleError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_organization_config_rule_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_organization_config_rule_request(
+        &self,
+        input: DeleteOrganizationConfigRuleRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "config", &self.region, "/");
+        request.set_operation("DeleteOrganizationConfigRule");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -8404,27 +9508,52 @@ impl ConfigService for ConfigServiceClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `delete_organization_conformance_pack`, also returning the metadata of the response.
+    pub async fn delete_organization_conformance_pack_with_metadata(
+        &self,
+        input: DeleteOrganizationConformancePackRequest,
+    ) -> Result<((), ResponseMetadata), RusotoError<DeleteOrganizationConformancePackError>> {
+        let request = self.build_delete_organization_conformance_pack_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
             std::mem::drop(response);
-            Ok(())
+            Ok(((), metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteOrganizationConfigRuleError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DeleteOrganizationConformancePackError::from_response(
+                response,
+            ))
         }
     }
 
-    /// <p>Deletes the specified organization conformance pack and all of the config rules and remediation actions from all member accounts in that organization. Only a master account can delete an organization conformance pack.</p> <p>AWS Config sets the state of a conformance pack to DELETE_IN_PROGRESS until the deletion is complete. You cannot update a conformance pack while it is in this state. </p>
-    async fn delete_organization_conformance_pack(
+    /// Presigns the request `delete_organization_conformance_pack` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_organization_conformance_pack(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteOrganizationConformancePackRequest,
-    ) -> Result<(), RusotoError<DeleteOrganizationConformancePackError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_organization_conformance_pack_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_organization_conformance_pack_request(
+        &self,
+        input: DeleteOrganizationConformancePackRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "config", &self.region, "/");
+        request.set_operation("DeleteOrganizationConformancePack");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -8434,29 +9563,52 @@ impl ConfigService for ConfigServiceClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `delete_pending_aggregation_request`, also returning the metadata of the response.
+    pub async fn delete_pending_aggregation_request_with_metadata(
+        &self,
+        input: DeletePendingAggregationRequestRequest,
+    ) -> Result<((), ResponseMetadata), RusotoError<DeletePendingAggregationRequestError>> {
+        let request = self.build_delete_pending_aggregation_request_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
             std::mem::drop(response);
-            Ok(())
+            Ok(((), metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteOrganizationConformancePackError::from_response(
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DeletePendingAggregationRequestError::from_response(
                 response,
             ))
         }
     }
 
-    /// <p>Deletes pending authorization requests for a specified aggregator account in a specified region.</p>
-    async fn delete_pending_aggregation_request(
+    /// Presigns the request `delete_pending_aggregation_request` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_pending_aggregation_request(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeletePendingAggregationRequestRequest,
-    ) -> Result<(), RusotoError<DeletePendingAggregationRequestError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_pending_aggregation_request_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_pending_aggregation_request_request(
+        &self,
+        input: DeletePendingAggregationRequestRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "config", &self.region, "/");
+        request.set_operation("DeletePendingAggregationRequest");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -8466,32 +9618,58 @@ impl ConfigService for ConfigServiceClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `delete_remediation_configuration`, also returning the metadata of the response.
+    pub async fn delete_remediation_configuration_with_metadata(
+        &self,
+        input: DeleteRemediationConfigurationRequest,
+    ) -> Result<
+        (DeleteRemediationConfigurationResponse, ResponseMetadata),
+        RusotoError<DeleteRemediationConfigurationError>,
+    > {
+        let request = self.build_delete_remediation_configuration_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            std::mem::drop(response);
-            Ok(())
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DeleteRemediationConfigurationResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DeletePendingAggregationRequestError::from_response(
-                response,
-            ))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DeleteRemediationConfigurationError::from_response(response))
         }
     }
 
-    /// <p>Deletes the remediation configuration.</p>
-    async fn delete_remediation_configuration(
+    /// Presigns the request `delete_remediation_configuration` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_remediation_configuration(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteRemediationConfigurationRequest,
-    ) -> Result<
-        DeleteRemediationConfigurationResponse,
-        RusotoError<DeleteRemediationConfigurationError>,
-    > {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_remediation_configuration_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_remediation_configuration_request(
+        &self,
+        input: DeleteRemediationConfigurationRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "config", &self.region, "/");
+        request.set_operation("DeleteRemediationConfiguration");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -8501,29 +9679,58 @@ impl ConfigService for ConfigServiceClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `delete_remediation_exceptions`, also returning the metadata of the response.
+    pub async fn delete_remediation_exceptions_with_metadata(
+        &self,
+        input: DeleteRemediationExceptionsRequest,
+    ) -> Result<
+        (DeleteRemediationExceptionsResponse, ResponseMetadata),
+        RusotoError<DeleteRemediationExceptionsError>,
+    > {
+        let request = self.build_delete_remediation_exceptions_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DeleteRemediationConfigurationResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DeleteRemediationExceptionsResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteRemediationConfigurationError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DeleteRemediationExceptionsError::from_response(response))
         }
     }
 
-    /// <p>Deletes one or more remediation exceptions mentioned in the resource keys.</p>
-    async fn delete_remediation_exceptions(
+    /// Presigns the request `delete_remediation_exceptions` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_remediation_exceptions(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteRemediationExceptionsRequest,
-    ) -> Result<DeleteRemediationExceptionsResponse, RusotoError<DeleteRemediationExceptionsError>>
-    {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_remediation_exceptions_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_remediation_exceptions_request(
+        &self,
+        input: DeleteRemediationExceptionsRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "config", &self.region, "/");
+        request.set_operation("DeleteRemediationExceptions");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -8533,55 +9740,100 @@ impl ConfigService for ConfigServiceClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `delete_resource_config`, also returning the metadata of the response.
+    pub async fn delete_resource_config_with_metadata(
+        &self,
+        input: DeleteResourceConfigRequest,
+    ) -> Result<((), ResponseMetadata), RusotoError<DeleteResourceConfigError>> {
+        let request = self.build_delete_resource_config_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DeleteRemediationExceptionsResponse, _>()
+            std::mem::drop(response);
+            Ok(((), metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteRemediationExceptionsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DeleteResourceConfigError::from_response(response))
         }
     }
 
-    /// <p>Records the configuration state for a custom resource that has been deleted. This API records a new ConfigurationItem with a ResourceDeleted status. You can retrieve the ConfigurationItems recorded for this resource in your AWS Config History. </p>
-    async fn delete_resource_config(
+    /// Presigns the request `delete_resource_config` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_resource_config(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteResourceConfigRequest,
-    ) -> Result<(), RusotoError<DeleteResourceConfigError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_resource_config_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_resource_config_request(
+        &self,
+        input: DeleteResourceConfigRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "config", &self.region, "/");
+        request.set_operation("DeleteResourceConfig");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "StarlingDoveService.DeleteResourceConfig");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `delete_retention_configuration`, also returning the metadata of the response.
+    pub async fn delete_retention_configuration_with_metadata(
+        &self,
+        input: DeleteRetentionConfigurationRequest,
+    ) -> Result<((), ResponseMetadata), RusotoError<DeleteRetentionConfigurationError>> {
+        let request = self.build_delete_retention_configuration_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
             std::mem::drop(response);
-            Ok(())
+            Ok(((), metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteResourceConfigError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DeleteRetentionConfigurationError::from_response(response))
         }
     }
 
-    /// <p>Deletes the retention configuration.</p>
-    async fn delete_retention_configuration(
+    /// Presigns the request `delete_retention_configuration` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_retention_configuration(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteRetentionConfigurationRequest,
-    ) -> Result<(), RusotoError<DeleteRetentionConfigurationError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_retention_configuration_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_retention_configuration_request(
+        &self,
+        input: DeleteRetentionConfigurationRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "config", &self.region, "/");
+        request.set_operation("DeleteRetentionConfiguration");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -8591,58 +9843,119 @@ impl ConfigService for ConfigServiceClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `deliver_config_snapshot`, also returning the metadata of the response.
+    pub async fn deliver_config_snapshot_with_metadata(
+        &self,
+        input: DeliverConfigSnapshotRequest,
+    ) -> Result<
+        (DeliverConfigSnapshotResponse, ResponseMetadata),
+        RusotoError<DeliverConfigSnapshotError>,
+    > {
+        let request = self.build_deliver_config_snapshot_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            std::mem::drop(response);
-            Ok(())
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DeliverConfigSnapshotResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteRetentionConfigurationError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DeliverConfigSnapshotError::from_response(response))
         }
     }
 
-    /// <p><p>Schedules delivery of a configuration snapshot to the Amazon S3 bucket in the specified delivery channel. After the delivery has started, AWS Config sends the following notifications using an Amazon SNS topic that you have specified.</p> <ul> <li> <p>Notification of the start of the delivery.</p> </li> <li> <p>Notification of the completion of the delivery, if the delivery was successfully completed.</p> </li> <li> <p>Notification of delivery failure, if the delivery failed.</p> </li> </ul></p>
-    async fn deliver_config_snapshot(
+    /// Presigns the request `deliver_config_snapshot` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_deliver_config_snapshot(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeliverConfigSnapshotRequest,
-    ) -> Result<DeliverConfigSnapshotResponse, RusotoError<DeliverConfigSnapshotError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_deliver_config_snapshot_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_deliver_config_snapshot_request(
+        &self,
+        input: DeliverConfigSnapshotRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "config", &self.region, "/");
+        request.set_operation("DeliverConfigSnapshot");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "StarlingDoveService.DeliverConfigSnapshot");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `describe_aggregate_compliance_by_config_rules`, also returning the metadata of the response.
+    pub async fn describe_aggregate_compliance_by_config_rules_with_metadata(
+        &self,
+        input: DescribeAggregateComplianceByConfigRulesRequest,
+    ) -> Result<
+        (
+            DescribeAggregateComplianceByConfigRulesResponse,
+            ResponseMetadata,
+        ),
+        RusotoError<DescribeAggregateComplianceByConfigRulesError>,
+    > {
+        let request = self.build_describe_aggregate_compliance_by_config_rules_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DeliverConfigSnapshotResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DescribeAggregateComplianceByConfigRulesResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DeliverConfigSnapshotError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DescribeAggregateComplianceByConfigRulesError::from_response(response))
         }
     }
 
-    /// <p><p>Returns a list of compliant and noncompliant rules with the number of resources for compliant and noncompliant rules. </p> <note> <p>The results can return an empty result page, but if you have a <code>nextToken</code>, the results are displayed on the next page.</p> </note></p>
-    async fn describe_aggregate_compliance_by_config_rules(
+    /// Presigns the request `describe_aggregate_compliance_by_config_rules` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_aggregate_compliance_by_config_rules(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeAggregateComplianceByConfigRulesRequest,
-    ) -> Result<
-        DescribeAggregateComplianceByConfigRulesResponse,
-        RusotoError<DescribeAggregateComplianceByConfigRulesError>,
-    > {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_aggregate_compliance_by_config_rules_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_aggregate_compliance_by_config_rules_request(
+        &self,
+        input: DescribeAggregateComplianceByConfigRulesRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "config", &self.region, "/");
+        request.set_operation("DescribeAggregateComplianceByConfigRules");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -8652,31 +9965,60 @@ impl ConfigService for ConfigServiceClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `describe_aggregation_authorizations`, also returning the metadata of the response.
+    pub async fn describe_aggregation_authorizations_with_metadata(
+        &self,
+        input: DescribeAggregationAuthorizationsRequest,
+    ) -> Result<
+        (DescribeAggregationAuthorizationsResponse, ResponseMetadata),
+        RusotoError<DescribeAggregationAuthorizationsError>,
+    > {
+        let request = self.build_describe_aggregation_authorizations_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DescribeAggregateComplianceByConfigRulesResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DescribeAggregationAuthorizationsResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeAggregateComplianceByConfigRulesError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DescribeAggregationAuthorizationsError::from_response(
+                response,
+            ))
         }
     }
 
-    /// <p>Returns a list of authorizations granted to various aggregator accounts and regions.</p>
-    async fn describe_aggregation_authorizations(
+    /// Presigns the request `describe_aggregation_authorizations` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_aggregation_authorizations(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeAggregationAuthorizationsRequest,
-    ) -> Result<
-        DescribeAggregationAuthorizationsResponse,
-        RusotoError<DescribeAggregationAuthorizationsError>,
-    > {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_aggregation_authorizations_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_aggregation_authorizations_request(
+        &self,
+        input: DescribeAggregationAuthorizationsRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "config", &self.region, "/");
+        request.set_operation("DescribeAggregationAuthorizations");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -8686,33 +10028,58 @@ impl ConfigService for ConfigServiceClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `describe_compliance_by_config_rule`, also returning the metadata of the response.
+    pub async fn describe_compliance_by_config_rule_with_metadata(
+        &self,
+        input: DescribeComplianceByConfigRuleRequest,
+    ) -> Result<
+        (DescribeComplianceByConfigRuleResponse, ResponseMetadata),
+        RusotoError<DescribeComplianceByConfigRuleError>,
+    > {
+        let request = self.build_describe_compliance_by_config_rule_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DescribeAggregationAuthorizationsResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DescribeComplianceByConfigRuleResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeAggregationAuthorizationsError::from_response(
-                response,
-            ))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DescribeComplianceByConfigRuleError::from_response(response))
         }
     }
 
-    /// <p><p>Indicates whether the specified AWS Config rules are compliant. If a rule is noncompliant, this action returns the number of AWS resources that do not comply with the rule.</p> <p>A rule is compliant if all of the evaluated resources comply with it. It is noncompliant if any of these resources do not comply.</p> <p>If AWS Config has no current evaluation results for the rule, it returns <code>INSUFFICIENT<em>DATA</code>. This result might indicate one of the following conditions:</p> <ul> <li> <p>AWS Config has never invoked an evaluation for the rule. To check whether it has, use the <code>DescribeConfigRuleEvaluationStatus</code> action to get the <code>LastSuccessfulInvocationTime</code> and <code>LastFailedInvocationTime</code>.</p> </li> <li> <p>The rule&#39;s AWS Lambda function is failing to send evaluation results to AWS Config. Verify that the role you assigned to your configuration recorder includes the <code>config:PutEvaluations</code> permission. If the rule is a custom rule, verify that the AWS Lambda execution role includes the <code>config:PutEvaluations</code> permission.</p> </li> <li> <p>The rule&#39;s AWS Lambda function has returned <code>NOT</em>APPLICABLE</code> for all evaluation results. This can occur if the resources were deleted or removed from the rule&#39;s scope.</p> </li> </ul></p>
-    async fn describe_compliance_by_config_rule(
+    /// Presigns the request `describe_compliance_by_config_rule` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_compliance_by_config_rule(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeComplianceByConfigRuleRequest,
-    ) -> Result<
-        DescribeComplianceByConfigRuleResponse,
-        RusotoError<DescribeComplianceByConfigRuleError>,
-    > {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_compliance_by_config_rule_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_compliance_by_config_rule_request(
+        &self,
+        input: DescribeComplianceByConfigRuleRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "config", &self.region, "/");
+        request.set_operation("DescribeComplianceByConfigRule");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -8722,29 +10089,58 @@ impl ConfigService for ConfigServiceClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `describe_compliance_by_resource`, also returning the metadata of the response.
+    pub async fn describe_compliance_by_resource_with_metadata(
+        &self,
+        input: DescribeComplianceByResourceRequest,
+    ) -> Result<
+        (DescribeComplianceByResourceResponse, ResponseMetadata),
+        RusotoError<DescribeComplianceByResourceError>,
+    > {
+        let request = self.build_describe_compliance_by_resource_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DescribeComplianceByConfigRuleResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DescribeComplianceByResourceResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeComplianceByConfigRuleError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DescribeComplianceByResourceError::from_response(response))
         }
     }
 
-    /// <p><p>Indicates whether the specified AWS resources are compliant. If a resource is noncompliant, this action returns the number of AWS Config rules that the resource does not comply with.</p> <p>A resource is compliant if it complies with all the AWS Config rules that evaluate it. It is noncompliant if it does not comply with one or more of these rules.</p> <p>If AWS Config has no current evaluation results for the resource, it returns <code>INSUFFICIENT<em>DATA</code>. This result might indicate one of the following conditions about the rules that evaluate the resource:</p> <ul> <li> <p>AWS Config has never invoked an evaluation for the rule. To check whether it has, use the <code>DescribeConfigRuleEvaluationStatus</code> action to get the <code>LastSuccessfulInvocationTime</code> and <code>LastFailedInvocationTime</code>.</p> </li> <li> <p>The rule&#39;s AWS Lambda function is failing to send evaluation results to AWS Config. Verify that the role that you assigned to your configuration recorder includes the <code>config:PutEvaluations</code> permission. If the rule is a custom rule, verify that the AWS Lambda execution role includes the <code>config:PutEvaluations</code> permission.</p> </li> <li> <p>The rule&#39;s AWS Lambda function has returned <code>NOT</em>APPLICABLE</code> for all evaluation results. This can occur if the resources were deleted or removed from the rule&#39;s scope.</p> </li> </ul></p>
-    async fn describe_compliance_by_resource(
+    /// Presigns the request `describe_compliance_by_resource` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_compliance_by_resource(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeComplianceByResourceRequest,
-    ) -> Result<DescribeComplianceByResourceResponse, RusotoError<DescribeComplianceByResourceError>>
-    {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_compliance_by_resource_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_compliance_by_resource_request(
+        &self,
+        input: DescribeComplianceByResourceRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "config", &self.region, "/");
+        request.set_operation("DescribeComplianceByResource");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -8754,31 +10150,60 @@ impl ConfigService for ConfigServiceClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `describe_config_rule_evaluation_status`, also returning the metadata of the response.
+    pub async fn describe_config_rule_evaluation_status_with_metadata(
+        &self,
+        input: DescribeConfigRuleEvaluationStatusRequest,
+    ) -> Result<
+        (DescribeConfigRuleEvaluationStatusResponse, ResponseMetadata),
+        RusotoError<DescribeConfigRuleEvaluationStatusError>,
+    > {
+        let request = self.build_describe_config_rule_evaluation_status_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DescribeComplianceByResourceResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DescribeConfigRuleEvaluationStatusResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeComplianceByResourceError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DescribeConfigRuleEvaluationStatusError::from_response(
+                response,
+            ))
         }
     }
 
-    /// <p>Returns status information for each of your AWS managed Config rules. The status includes information such as the last time AWS Config invoked the rule, the last time AWS Config failed to invoke the rule, and the related error for the last failure.</p>
-    async fn describe_config_rule_evaluation_status(
+    /// Presigns the request `describe_config_rule_evaluation_status` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_config_rule_evaluation_status(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeConfigRuleEvaluationStatusRequest,
-    ) -> Result<
-        DescribeConfigRuleEvaluationStatusResponse,
-        RusotoError<DescribeConfigRuleEvaluationStatusError>,
-    > {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_config_rule_evaluation_status_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_config_rule_evaluation_status_request(
+        &self,
+        input: DescribeConfigRuleEvaluationStatusRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "config", &self.region, "/");
+        request.set_operation("DescribeConfigRuleEvaluationStatus");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -8788,61 +10213,120 @@ impl ConfigService for ConfigServiceClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `describe_config_rules`, also returning the metadata of the response.
+    pub async fn describe_config_rules_with_metadata(
+        &self,
+        input: DescribeConfigRulesRequest,
+    ) -> Result<
+        (DescribeConfigRulesResponse, ResponseMetadata),
+        RusotoError<DescribeConfigRulesError>,
+    > {
+        let request = self.build_describe_config_rules_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DescribeConfigRuleEvaluationStatusResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DescribeConfigRulesResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeConfigRuleEvaluationStatusError::from_response(
-                response,
-            ))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DescribeConfigRulesError::from_response(response))
         }
     }
 
-    /// <p>Returns details about your AWS Config rules.</p>
-    async fn describe_config_rules(
+    /// Presigns the request `describe_config_rules` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_config_rules(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeConfigRulesRequest,
-    ) -> Result<DescribeConfigRulesResponse, RusotoError<DescribeConfigRulesError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_config_rules_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_config_rules_request(
+        &self,
+        input: DescribeConfigRulesRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "config", &self.region, "/");
+        request.set_operation("DescribeConfigRules");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "StarlingDoveService.DescribeConfigRules");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `describe_configuration_aggregator_sources_status`, also returning the metadata of the response.
+    pub async fn describe_configuration_aggregator_sources_status_with_metadata(
+        &self,
+        input: DescribeConfigurationAggregatorSourcesStatusRequest,
+    ) -> Result<
+        (
+            DescribeConfigurationAggregatorSourcesStatusResponse,
+            ResponseMetadata,
+        ),
+        RusotoError<DescribeConfigurationAggregatorSourcesStatusError>,
+    > {
+        let request = self.build_describe_configuration_aggregator_sources_status_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DescribeConfigRulesResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DescribeConfigurationAggregatorSourcesStatusResponse, _>(
+            )?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeConfigRulesError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DescribeConfigurationAggregatorSourcesStatusError::from_response(response))
         }
     }
 
-    /// <p>Returns status information for sources within an aggregator. The status includes information about the last time AWS Config verified authorization between the source account and an aggregator account. In case of a failure, the status contains the related error code or message. </p>
-    async fn describe_configuration_aggregator_sources_status(
+    /// Presigns the request `describe_configuration_aggregator_sources_status` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_configuration_aggregator_sources_status(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeConfigurationAggregatorSourcesStatusRequest,
-    ) -> Result<
-        DescribeConfigurationAggregatorSourcesStatusResponse,
-        RusotoError<DescribeConfigurationAggregatorSourcesStatusError>,
-    > {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_configuration_aggregator_sources_status_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_configuration_aggregator_sources_status_request(
+        &self,
+        input: DescribeConfigurationAggregatorSourcesStatusRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "config", &self.region, "/");
+        request.set_operation("DescribeConfigurationAggregatorSourcesStatus");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -8852,31 +10336,60 @@ impl ConfigService for ConfigServiceClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `describe_configuration_aggregators`, also returning the metadata of the response.
+    pub async fn describe_configuration_aggregators_with_metadata(
+        &self,
+        input: DescribeConfigurationAggregatorsRequest,
+    ) -> Result<
+        (DescribeConfigurationAggregatorsResponse, ResponseMetadata),
+        RusotoError<DescribeConfigurationAggregatorsError>,
+    > {
+        let request = self.build_describe_configuration_aggregators_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DescribeConfigurationAggregatorSourcesStatusResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DescribeConfigurationAggregatorsResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeConfigurationAggregatorSourcesStatusError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DescribeConfigurationAggregatorsError::from_response(
+                response,
+            ))
         }
     }
 
-    /// <p>Returns the details of one or more configuration aggregators. If the configuration aggregator is not specified, this action returns the details for all the configuration aggregators associated with the account. </p>
-    async fn describe_configuration_aggregators(
+    /// Presigns the request `describe_configuration_aggregators` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_configuration_aggregators(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeConfigurationAggregatorsRequest,
-    ) -> Result<
-        DescribeConfigurationAggregatorsResponse,
-        RusotoError<DescribeConfigurationAggregatorsError>,
-    > {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_configuration_aggregators_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_configuration_aggregators_request(
+        &self,
+        input: DescribeConfigurationAggregatorsRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "config", &self.region, "/");
+        request.set_operation("DescribeConfigurationAggregators");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -8886,33 +10399,63 @@ impl ConfigService for ConfigServiceClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `describe_configuration_recorder_status`, also returning the metadata of the response.
+    pub async fn describe_configuration_recorder_status_with_metadata(
+        &self,
+        input: DescribeConfigurationRecorderStatusRequest,
+    ) -> Result<
+        (
+            DescribeConfigurationRecorderStatusResponse,
+            ResponseMetadata,
+        ),
+        RusotoError<DescribeConfigurationRecorderStatusError>,
+    > {
+        let request = self.build_describe_configuration_recorder_status_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DescribeConfigurationAggregatorsResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DescribeConfigurationRecorderStatusResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeConfigurationAggregatorsError::from_response(
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DescribeConfigurationRecorderStatusError::from_response(
                 response,
             ))
         }
     }
 
-    /// <p><p>Returns the current status of the specified configuration recorder. If a configuration recorder is not specified, this action returns the status of all configuration recorders associated with the account.</p> <note> <p>Currently, you can specify only one configuration recorder per region in your account.</p> </note></p>
-    async fn describe_configuration_recorder_status(
+    /// Presigns the request `describe_configuration_recorder_status` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_configuration_recorder_status(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeConfigurationRecorderStatusRequest,
-    ) -> Result<
-        DescribeConfigurationRecorderStatusResponse,
-        RusotoError<DescribeConfigurationRecorderStatusError>,
-    > {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_configuration_recorder_status_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_configuration_recorder_status_request(
+        &self,
+        input: DescribeConfigurationRecorderStatusRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "config", &self.region, "/");
+        request.set_operation("DescribeConfigurationRecorderStatus");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -8922,33 +10465,58 @@ impl ConfigService for ConfigServiceClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `describe_configuration_recorders`, also returning the metadata of the response.
+    pub async fn describe_configuration_recorders_with_metadata(
+        &self,
+        input: DescribeConfigurationRecordersRequest,
+    ) -> Result<
+        (DescribeConfigurationRecordersResponse, ResponseMetadata),
+        RusotoError<DescribeConfigurationRecordersError>,
+    > {
+        let request = self.build_describe_configuration_recorders_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DescribeConfigurationRecorderStatusResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DescribeConfigurationRecordersResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeConfigurationRecorderStatusError::from_response(
-                response,
-            ))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DescribeConfigurationRecordersError::from_response(response))
         }
     }
 
-    /// <p><p>Returns the details for the specified configuration recorders. If the configuration recorder is not specified, this action returns the details for all configuration recorders associated with the account.</p> <note> <p>Currently, you can specify only one configuration recorder per region in your account.</p> </note></p>
-    async fn describe_configuration_recorders(
+    /// Presigns the request `describe_configuration_recorders` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_configuration_recorders(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeConfigurationRecordersRequest,
-    ) -> Result<
-        DescribeConfigurationRecordersResponse,
-        RusotoError<DescribeConfigurationRecordersError>,
-    > {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_configuration_recorders_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_configuration_recorders_request(
+        &self,
+        input: DescribeConfigurationRecordersRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "config", &self.region, "/");
+        request.set_operation("DescribeConfigurationRecorders");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -8958,31 +10526,60 @@ impl ConfigService for ConfigServiceClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `describe_conformance_pack_compliance`, also returning the metadata of the response.
+    pub async fn describe_conformance_pack_compliance_with_metadata(
+        &self,
+        input: DescribeConformancePackComplianceRequest,
+    ) -> Result<
+        (DescribeConformancePackComplianceResponse, ResponseMetadata),
+        RusotoError<DescribeConformancePackComplianceError>,
+    > {
+        let request = self.build_describe_conformance_pack_compliance_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DescribeConfigurationRecordersResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DescribeConformancePackComplianceResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeConfigurationRecordersError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DescribeConformancePackComplianceError::from_response(
+                response,
+            ))
         }
     }
 
-    /// <p><p>Returns compliance details for each rule in that conformance pack.</p> <note> <p>You must provide exact rule names.</p> </note></p>
-    async fn describe_conformance_pack_compliance(
+    /// Presigns the request `describe_conformance_pack_compliance` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_conformance_pack_compliance(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeConformancePackComplianceRequest,
-    ) -> Result<
-        DescribeConformancePackComplianceResponse,
-        RusotoError<DescribeConformancePackComplianceError>,
-    > {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_conformance_pack_compliance_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_conformance_pack_compliance_request(
+        &self,
+        input: DescribeConformancePackComplianceRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "config", &self.region, "/");
+        request.set_operation("DescribeConformancePackCompliance");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -8992,33 +10589,58 @@ impl ConfigService for ConfigServiceClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `describe_conformance_pack_status`, also returning the metadata of the response.
+    pub async fn describe_conformance_pack_status_with_metadata(
+        &self,
+        input: DescribeConformancePackStatusRequest,
+    ) -> Result<
+        (DescribeConformancePackStatusResponse, ResponseMetadata),
+        RusotoError<DescribeConformancePackStatusError>,
+    > {
+        let request = self.build_describe_conformance_pack_status_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DescribeConformancePackComplianceResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DescribeConformancePackStatusResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeConformancePackComplianceError::from_response(
-                response,
-            ))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DescribeConformancePackStatusError::from_response(response))
         }
     }
 
-    /// <p><p>Provides one or more conformance packs deployment status.</p> <note> <p>If there are no conformance packs then you will see an empty result.</p> </note></p>
-    async fn describe_conformance_pack_status(
+    /// Presigns the request `describe_conformance_pack_status` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_conformance_pack_status(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeConformancePackStatusRequest,
-    ) -> Result<
-        DescribeConformancePackStatusResponse,
-        RusotoError<DescribeConformancePackStatusError>,
-    > {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_conformance_pack_status_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_conformance_pack_status_request(
+        &self,
+        input: DescribeConformancePackStatusRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "config", &self.region, "/");
+        request.set_operation("DescribeConformancePackStatus");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -9028,28 +10650,58 @@ impl ConfigService for ConfigServiceClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
-        let mut response = self
-            .client
+        request
+    }
+
+    /// Calls `describe_conformance_packs`, also returning the metadata of the response.
+    pub async fn describe_conformance_packs_with_metadata(
+        &self,
+        input: DescribeConformancePacksRequest,
+    ) -> Result<
+        (DescribeConformancePacksResponse, ResponseMetadata),
+        RusotoError<DescribeConformancePacksError>,
+    > {
+        let request = self.build_describe_conformance_packs_request(input);
+
+        let mut response = self
+            .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DescribeConformancePackStatusResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DescribeConformancePacksResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeConformancePackStatusError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DescribeConformancePacksError::from_response(response))
         }
     }
 
-    /// <p>Returns a list of one or more conformance packs.</p>
-    async fn describe_conformance_packs(
+    /// Presigns the request `describe_conformance_packs` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_conformance_packs(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeConformancePacksRequest,
-    ) -> Result<DescribeConformancePacksResponse, RusotoError<DescribeConformancePacksError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_conformance_packs_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_conformance_packs_request(
+        &self,
+        input: DescribeConformancePacksRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "config", &self.region, "/");
+        request.set_operation("DescribeConformancePacks");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -9059,31 +10711,58 @@ impl ConfigService for ConfigServiceClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `describe_delivery_channel_status`, also returning the metadata of the response.
+    pub async fn describe_delivery_channel_status_with_metadata(
+        &self,
+        input: DescribeDeliveryChannelStatusRequest,
+    ) -> Result<
+        (DescribeDeliveryChannelStatusResponse, ResponseMetadata),
+        RusotoError<DescribeDeliveryChannelStatusError>,
+    > {
+        let request = self.build_describe_delivery_channel_status_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DescribeConformancePacksResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DescribeDeliveryChannelStatusResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeConformancePacksError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DescribeDeliveryChannelStatusError::from_response(response))
         }
     }
 
-    /// <p><p>Returns the current status of the specified delivery channel. If a delivery channel is not specified, this action returns the current status of all delivery channels associated with the account.</p> <note> <p>Currently, you can specify only one delivery channel per region in your account.</p> </note></p>
-    async fn describe_delivery_channel_status(
+    /// Presigns the request `describe_delivery_channel_status` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_delivery_channel_status(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeDeliveryChannelStatusRequest,
-    ) -> Result<
-        DescribeDeliveryChannelStatusResponse,
-        RusotoError<DescribeDeliveryChannelStatusError>,
-    > {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_delivery_channel_status_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_delivery_channel_status_request(
+        &self,
+        input: DescribeDeliveryChannelStatusRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "config", &self.region, "/");
+        request.set_operation("DescribeDeliveryChannelStatus");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -9093,28 +10772,58 @@ impl ConfigService for ConfigServiceClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `describe_delivery_channels`, also returning the metadata of the response.
+    pub async fn describe_delivery_channels_with_metadata(
+        &self,
+        input: DescribeDeliveryChannelsRequest,
+    ) -> Result<
+        (DescribeDeliveryChannelsResponse, ResponseMetadata),
+        RusotoError<DescribeDeliveryChannelsError>,
+    > {
+        let request = self.build_describe_delivery_channels_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DescribeDeliveryChannelStatusResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DescribeDeliveryChannelsResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeDeliveryChannelStatusError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DescribeDeliveryChannelsError::from_response(response))
         }
     }
 
-    /// <p><p>Returns details about the specified delivery channel. If a delivery channel is not specified, this action returns the details of all delivery channels associated with the account.</p> <note> <p>Currently, you can specify only one delivery channel per region in your account.</p> </note></p>
-    async fn describe_delivery_channels(
+    /// Presigns the request `describe_delivery_channels` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_delivery_channels(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeDeliveryChannelsRequest,
-    ) -> Result<DescribeDeliveryChannelsResponse, RusotoError<DescribeDeliveryChannelsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_delivery_channels_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_delivery_channels_request(
+        &self,
+        input: DescribeDeliveryChannelsRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "config", &self.region, "/");
+        request.set_operation("DescribeDeliveryChannels");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -9124,31 +10833,63 @@ impl ConfigService for ConfigServiceClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `describe_organization_config_rule_statuses`, also returning the metadata of the response.
+    pub async fn describe_organization_config_rule_statuses_with_metadata(
+        &self,
+        input: DescribeOrganizationConfigRuleStatusesRequest,
+    ) -> Result<
+        (
+            DescribeOrganizationConfigRuleStatusesResponse,
+            ResponseMetadata,
+        ),
+        RusotoError<DescribeOrganizationConfigRuleStatusesError>,
+    > {
+        let request = self.build_describe_organization_config_rule_statuses_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DescribeDeliveryChannelsResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DescribeOrganizationConfigRuleStatusesResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeDeliveryChannelsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DescribeOrganizationConfigRuleStatusesError::from_response(
+                response,
+            ))
         }
     }
 
-    /// <p><p>Provides organization config rule deployment status for an organization.</p> <note> <p>The status is not considered successful until organization config rule is successfully deployed in all the member accounts with an exception of excluded accounts.</p> <p>When you specify the limit and the next token, you receive a paginated response. Limit and next token are not applicable if you specify organization config rule names. It is only applicable, when you request all the organization config rules.</p> <p>Only a master account can call this API.</p> </note></p>
-    async fn describe_organization_config_rule_statuses(
+    /// Presigns the request `describe_organization_config_rule_statuses` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_organization_config_rule_statuses(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeOrganizationConfigRuleStatusesRequest,
-    ) -> Result<
-        DescribeOrganizationConfigRuleStatusesResponse,
-        RusotoError<DescribeOrganizationConfigRuleStatusesError>,
-    > {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_organization_config_rule_statuses_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_organization_config_rule_statuses_request(
+        &self,
+        input: DescribeOrganizationConfigRuleStatusesRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "config", &self.region, "/");
+        request.set_operation("DescribeOrganizationConfigRuleStatuses");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -9158,33 +10899,60 @@ impl ConfigService for ConfigServiceClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `describe_organization_config_rules`, also returning the metadata of the response.
+    pub async fn describe_organization_config_rules_with_metadata(
+        &self,
+        input: DescribeOrganizationConfigRulesRequest,
+    ) -> Result<
+        (DescribeOrganizationConfigRulesResponse, ResponseMetadata),
+        RusotoError<DescribeOrganizationConfigRulesError>,
+    > {
+        let request = self.build_describe_organization_config_rules_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DescribeOrganizationConfigRuleStatusesResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DescribeOrganizationConfigRulesResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeOrganizationConfigRuleStatusesError::from_response(
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DescribeOrganizationConfigRulesError::from_response(
                 response,
             ))
         }
     }
 
-    /// <p><p>Returns a list of organization config rules.</p> <note> <p>When you specify the limit and the next token, you receive a paginated response. Limit and next token are not applicable if you specify organization config rule names. It is only applicable, when you request all the organization config rules.</p> <p>Only a master account can call this API.</p> </note></p>
-    async fn describe_organization_config_rules(
+    /// Presigns the request `describe_organization_config_rules` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_organization_config_rules(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeOrganizationConfigRulesRequest,
-    ) -> Result<
-        DescribeOrganizationConfigRulesResponse,
-        RusotoError<DescribeOrganizationConfigRulesError>,
-    > {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_organization_config_rules_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_organization_config_rules_request(
+        &self,
+        input: DescribeOrganizationConfigRulesRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "config", &self.region, "/");
+        request.set_operation("DescribeOrganizationConfigRules");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -9194,33 +10962,61 @@ impl ConfigService for ConfigServiceClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `describe_organization_conformance_pack_statuses`, also returning the metadata of the response.
+    pub async fn describe_organization_conformance_pack_statuses_with_metadata(
+        &self,
+        input: DescribeOrganizationConformancePackStatusesRequest,
+    ) -> Result<
+        (
+            DescribeOrganizationConformancePackStatusesResponse,
+            ResponseMetadata,
+        ),
+        RusotoError<DescribeOrganizationConformancePackStatusesError>,
+    > {
+        let request = self.build_describe_organization_conformance_pack_statuses_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DescribeOrganizationConfigRulesResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DescribeOrganizationConformancePackStatusesResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeOrganizationConfigRulesError::from_response(
-                response,
-            ))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DescribeOrganizationConformancePackStatusesError::from_response(response))
         }
     }
 
-    /// <p><p>Provides organization conformance pack deployment status for an organization.</p> <note> <p>The status is not considered successful until organization conformance pack is successfully deployed in all the member accounts with an exception of excluded accounts.</p> <p>When you specify the limit and the next token, you receive a paginated response. Limit and next token are not applicable if you specify organization conformance pack names. They are only applicable, when you request all the organization conformance packs.</p> <p>Only a master account can call this API.</p> </note></p>
-    async fn describe_organization_conformance_pack_statuses(
+    /// Presigns the request `describe_organization_conformance_pack_statuses` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_organization_conformance_pack_statuses(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeOrganizationConformancePackStatusesRequest,
-    ) -> Result<
-        DescribeOrganizationConformancePackStatusesResponse,
-        RusotoError<DescribeOrganizationConformancePackStatusesError>,
-    > {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_organization_conformance_pack_statuses_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_organization_conformance_pack_statuses_request(
+        &self,
+        input: DescribeOrganizationConformancePackStatusesRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "config", &self.region, "/");
+        request.set_operation("DescribeOrganizationConformancePackStatuses");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -9230,31 +11026,63 @@ impl ConfigService for ConfigServiceClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `describe_organization_conformance_packs`, also returning the metadata of the response.
+    pub async fn describe_organization_conformance_packs_with_metadata(
+        &self,
+        input: DescribeOrganizationConformancePacksRequest,
+    ) -> Result<
+        (
+            DescribeOrganizationConformancePacksResponse,
+            ResponseMetadata,
+        ),
+        RusotoError<DescribeOrganizationConformancePacksError>,
+    > {
+        let request = self.build_describe_organization_conformance_packs_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DescribeOrganizationConformancePackStatusesResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DescribeOrganizationConformancePacksResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeOrganizationConformancePackStatusesError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DescribeOrganizationConformancePacksError::from_response(
+                response,
+            ))
         }
     }
 
-    /// <p><p>Returns a list of organization conformance packs.</p> <note> <p>When you specify the limit and the next token, you receive a paginated response. </p> <p>Limit and next token are not applicable if you specify organization conformance packs names. They are only applicable, when you request all the organization conformance packs. </p> <p>Only a master account can call this API.</p> </note></p>
-    async fn describe_organization_conformance_packs(
+    /// Presigns the request `describe_organization_conformance_packs` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_organization_conformance_packs(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeOrganizationConformancePacksRequest,
-    ) -> Result<
-        DescribeOrganizationConformancePacksResponse,
-        RusotoError<DescribeOrganizationConformancePacksError>,
-    > {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_organization_conformance_packs_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_organization_conformance_packs_request(
+        &self,
+        input: DescribeOrganizationConformancePacksRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "config", &self.region, "/");
+        request.set_operation("DescribeOrganizationConformancePacks");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -9264,33 +11092,60 @@ impl ConfigService for ConfigServiceClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `describe_pending_aggregation_requests`, also returning the metadata of the response.
+    pub async fn describe_pending_aggregation_requests_with_metadata(
+        &self,
+        input: DescribePendingAggregationRequestsRequest,
+    ) -> Result<
+        (DescribePendingAggregationRequestsResponse, ResponseMetadata),
+        RusotoError<DescribePendingAggregationRequestsError>,
+    > {
+        let request = self.build_describe_pending_aggregation_requests_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DescribeOrganizationConformancePacksResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DescribePendingAggregationRequestsResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeOrganizationConformancePacksError::from_response(
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DescribePendingAggregationRequestsError::from_response(
                 response,
             ))
         }
     }
 
-    /// <p>Returns a list of all pending aggregation requests.</p>
-    async fn describe_pending_aggregation_requests(
+    /// Presigns the request `describe_pending_aggregation_requests` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_pending_aggregation_requests(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribePendingAggregationRequestsRequest,
-    ) -> Result<
-        DescribePendingAggregationRequestsResponse,
-        RusotoError<DescribePendingAggregationRequestsError>,
-    > {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_pending_aggregation_requests_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_pending_aggregation_requests_request(
+        &self,
+        input: DescribePendingAggregationRequestsRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "config", &self.region, "/");
+        request.set_operation("DescribePendingAggregationRequests");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -9300,33 +11155,60 @@ impl ConfigService for ConfigServiceClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `describe_remediation_configurations`, also returning the metadata of the response.
+    pub async fn describe_remediation_configurations_with_metadata(
+        &self,
+        input: DescribeRemediationConfigurationsRequest,
+    ) -> Result<
+        (DescribeRemediationConfigurationsResponse, ResponseMetadata),
+        RusotoError<DescribeRemediationConfigurationsError>,
+    > {
+        let request = self.build_describe_remediation_configurations_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DescribePendingAggregationRequestsResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DescribeRemediationConfigurationsResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribePendingAggregationRequestsError::from_response(
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DescribeRemediationConfigurationsError::from_response(
                 response,
             ))
         }
     }
 
-    /// <p>Returns the details of one or more remediation configurations.</p>
-    async fn describe_remediation_configurations(
+    /// Presigns the request `describe_remediation_configurations` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_remediation_configurations(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeRemediationConfigurationsRequest,
-    ) -> Result<
-        DescribeRemediationConfigurationsResponse,
-        RusotoError<DescribeRemediationConfigurationsError>,
-    > {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_remediation_configurations_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_remediation_configurations_request(
+        &self,
+        input: DescribeRemediationConfigurationsRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "config", &self.region, "/");
+        request.set_operation("DescribeRemediationConfigurations");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -9336,33 +11218,58 @@ impl ConfigService for ConfigServiceClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `describe_remediation_exceptions`, also returning the metadata of the response.
+    pub async fn describe_remediation_exceptions_with_metadata(
+        &self,
+        input: DescribeRemediationExceptionsRequest,
+    ) -> Result<
+        (DescribeRemediationExceptionsResponse, ResponseMetadata),
+        RusotoError<DescribeRemediationExceptionsError>,
+    > {
+        let request = self.build_describe_remediation_exceptions_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DescribeRemediationConfigurationsResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DescribeRemediationExceptionsResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeRemediationConfigurationsError::from_response(
-                response,
-            ))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DescribeRemediationExceptionsError::from_response(response))
         }
     }
 
-    /// <p><p>Returns the details of one or more remediation exceptions. A detailed view of a remediation exception for a set of resources that includes an explanation of an exception and the time when the exception will be deleted. When you specify the limit and the next token, you receive a paginated response. </p> <note> <p>When you specify the limit and the next token, you receive a paginated response. </p> <p>Limit and next token are not applicable if you request resources in batch. It is only applicable, when you request all resources.</p> </note></p>
-    async fn describe_remediation_exceptions(
+    /// Presigns the request `describe_remediation_exceptions` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_remediation_exceptions(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeRemediationExceptionsRequest,
-    ) -> Result<
-        DescribeRemediationExceptionsResponse,
-        RusotoError<DescribeRemediationExceptionsError>,
-    > {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_remediation_exceptions_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_remediation_exceptions_request(
+        &self,
+        input: DescribeRemediationExceptionsRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "config", &self.region, "/");
+        request.set_operation("DescribeRemediationExceptions");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -9372,31 +11279,60 @@ impl ConfigService for ConfigServiceClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `describe_remediation_execution_status`, also returning the metadata of the response.
+    pub async fn describe_remediation_execution_status_with_metadata(
+        &self,
+        input: DescribeRemediationExecutionStatusRequest,
+    ) -> Result<
+        (DescribeRemediationExecutionStatusResponse, ResponseMetadata),
+        RusotoError<DescribeRemediationExecutionStatusError>,
+    > {
+        let request = self.build_describe_remediation_execution_status_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DescribeRemediationExceptionsResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DescribeRemediationExecutionStatusResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeRemediationExceptionsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DescribeRemediationExecutionStatusError::from_response(
+                response,
+            ))
         }
     }
 
-    /// <p>Provides a detailed view of a Remediation Execution for a set of resources including state, timestamps for when steps for the remediation execution occur, and any error messages for steps that have failed. When you specify the limit and the next token, you receive a paginated response.</p>
-    async fn describe_remediation_execution_status(
+    /// Presigns the request `describe_remediation_execution_status` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_remediation_execution_status(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeRemediationExecutionStatusRequest,
-    ) -> Result<
-        DescribeRemediationExecutionStatusResponse,
-        RusotoError<DescribeRemediationExecutionStatusError>,
-    > {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_remediation_execution_status_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_remediation_execution_status_request(
+        &self,
+        input: DescribeRemediationExecutionStatusRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "config", &self.region, "/");
+        request.set_operation("DescribeRemediationExecutionStatus");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -9406,33 +11342,60 @@ impl ConfigService for ConfigServiceClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `describe_retention_configurations`, also returning the metadata of the response.
+    pub async fn describe_retention_configurations_with_metadata(
+        &self,
+        input: DescribeRetentionConfigurationsRequest,
+    ) -> Result<
+        (DescribeRetentionConfigurationsResponse, ResponseMetadata),
+        RusotoError<DescribeRetentionConfigurationsError>,
+    > {
+        let request = self.build_describe_retention_configurations_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DescribeRemediationExecutionStatusResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DescribeRetentionConfigurationsResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeRemediationExecutionStatusError::from_response(
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DescribeRetentionConfigurationsError::from_response(
                 response,
             ))
         }
     }
 
-    /// <p><p>Returns the details of one or more retention configurations. If the retention configuration name is not specified, this action returns the details for all the retention configurations for that account.</p> <note> <p>Currently, AWS Config supports only one retention configuration per region in your account.</p> </note></p>
-    async fn describe_retention_configurations(
+    /// Presigns the request `describe_retention_configurations` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_retention_configurations(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeRetentionConfigurationsRequest,
-    ) -> Result<
-        DescribeRetentionConfigurationsResponse,
-        RusotoError<DescribeRetentionConfigurationsError>,
-    > {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_retention_configurations_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_retention_configurations_request(
+        &self,
+        input: DescribeRetentionConfigurationsRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "config", &self.region, "/");
+        request.set_operation("DescribeRetentionConfigurations");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -9442,33 +11405,61 @@ impl ConfigService for ConfigServiceClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `get_aggregate_compliance_details_by_config_rule`, also returning the metadata of the response.
+    pub async fn get_aggregate_compliance_details_by_config_rule_with_metadata(
+        &self,
+        input: GetAggregateComplianceDetailsByConfigRuleRequest,
+    ) -> Result<
+        (
+            GetAggregateComplianceDetailsByConfigRuleResponse,
+            ResponseMetadata,
+        ),
+        RusotoError<GetAggregateComplianceDetailsByConfigRuleError>,
+    > {
+        let request = self.build_get_aggregate_compliance_details_by_config_rule_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DescribeRetentionConfigurationsResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<GetAggregateComplianceDetailsByConfigRuleResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeRetentionConfigurationsError::from_response(
-                response,
-            ))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(GetAggregateComplianceDetailsByConfigRuleError::from_response(response))
         }
     }
 
-    /// <p><p>Returns the evaluation results for the specified AWS Config rule for a specific resource in a rule. The results indicate which AWS resources were evaluated by the rule, when each resource was last evaluated, and whether each resource complies with the rule. </p> <note> <p>The results can return an empty result page. But if you have a <code>nextToken</code>, the results are displayed on the next page.</p> </note></p>
-    async fn get_aggregate_compliance_details_by_config_rule(
+    /// Presigns the request `get_aggregate_compliance_details_by_config_rule` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_aggregate_compliance_details_by_config_rule(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetAggregateComplianceDetailsByConfigRuleRequest,
-    ) -> Result<
-        GetAggregateComplianceDetailsByConfigRuleResponse,
-        RusotoError<GetAggregateComplianceDetailsByConfigRuleError>,
-    > {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_aggregate_compliance_details_by_config_rule_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_aggregate_compliance_details_by_config_rule_request(
+        &self,
+        input: GetAggregateComplianceDetailsByConfigRuleRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "config", &self.region, "/");
+        request.set_operation("GetAggregateComplianceDetailsByConfigRule");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -9478,31 +11469,63 @@ impl ConfigService for ConfigServiceClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `get_aggregate_config_rule_compliance_summary`, also returning the metadata of the response.
+    pub async fn get_aggregate_config_rule_compliance_summary_with_metadata(
+        &self,
+        input: GetAggregateConfigRuleComplianceSummaryRequest,
+    ) -> Result<
+        (
+            GetAggregateConfigRuleComplianceSummaryResponse,
+            ResponseMetadata,
+        ),
+        RusotoError<GetAggregateConfigRuleComplianceSummaryError>,
+    > {
+        let request = self.build_get_aggregate_config_rule_compliance_summary_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<GetAggregateComplianceDetailsByConfigRuleResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<GetAggregateConfigRuleComplianceSummaryResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(GetAggregateComplianceDetailsByConfigRuleError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(GetAggregateConfigRuleComplianceSummaryError::from_response(
+                response,
+            ))
         }
     }
 
-    /// <p><p>Returns the number of compliant and noncompliant rules for one or more accounts and regions in an aggregator.</p> <note> <p>The results can return an empty result page, but if you have a nextToken, the results are displayed on the next page.</p> </note></p>
-    async fn get_aggregate_config_rule_compliance_summary(
+    /// Presigns the request `get_aggregate_config_rule_compliance_summary` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_aggregate_config_rule_compliance_summary(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetAggregateConfigRuleComplianceSummaryRequest,
-    ) -> Result<
-        GetAggregateConfigRuleComplianceSummaryResponse,
-        RusotoError<GetAggregateConfigRuleComplianceSummaryError>,
-    > {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_aggregate_config_rule_compliance_summary_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_aggregate_config_rule_compliance_summary_request(
+        &self,
+        input: GetAggregateConfigRuleComplianceSummaryRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "config", &self.region, "/");
+        request.set_operation("GetAggregateConfigRuleComplianceSummary");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -9512,33 +11535,63 @@ impl ConfigService for ConfigServiceClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `get_aggregate_discovered_resource_counts`, also returning the metadata of the response.
+    pub async fn get_aggregate_discovered_resource_counts_with_metadata(
+        &self,
+        input: GetAggregateDiscoveredResourceCountsRequest,
+    ) -> Result<
+        (
+            GetAggregateDiscoveredResourceCountsResponse,
+            ResponseMetadata,
+        ),
+        RusotoError<GetAggregateDiscoveredResourceCountsError>,
+    > {
+        let request = self.build_get_aggregate_discovered_resource_counts_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<GetAggregateConfigRuleComplianceSummaryResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<GetAggregateDiscoveredResourceCountsResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(GetAggregateConfigRuleComplianceSummaryError::from_response(
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(GetAggregateDiscoveredResourceCountsError::from_response(
                 response,
             ))
         }
     }
 
-    /// <p>Returns the resource counts across accounts and regions that are present in your AWS Config aggregator. You can request the resource counts by providing filters and GroupByKey.</p> <p>For example, if the input contains accountID 12345678910 and region us-east-1 in filters, the API returns the count of resources in account ID 12345678910 and region us-east-1. If the input contains ACCOUNT_ID as a GroupByKey, the API returns resource counts for all source accounts that are present in your aggregator.</p>
-    async fn get_aggregate_discovered_resource_counts(
+    /// Presigns the request `get_aggregate_discovered_resource_counts` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_aggregate_discovered_resource_counts(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetAggregateDiscoveredResourceCountsRequest,
-    ) -> Result<
-        GetAggregateDiscoveredResourceCountsResponse,
-        RusotoError<GetAggregateDiscoveredResourceCountsError>,
-    > {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_aggregate_discovered_resource_counts_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_aggregate_discovered_resource_counts_request(
+        &self,
+        input: GetAggregateDiscoveredResourceCountsRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "config", &self.region, "/");
+        request.set_operation("GetAggregateDiscoveredResourceCounts");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -9548,31 +11601,58 @@ impl ConfigService for ConfigServiceClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `get_aggregate_resource_config`, also returning the metadata of the response.
+    pub async fn get_aggregate_resource_config_with_metadata(
+        &self,
+        input: GetAggregateResourceConfigRequest,
+    ) -> Result<
+        (GetAggregateResourceConfigResponse, ResponseMetadata),
+        RusotoError<GetAggregateResourceConfigError>,
+    > {
+        let request = self.build_get_aggregate_resource_config_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<GetAggregateDiscoveredResourceCountsResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<GetAggregateResourceConfigResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(GetAggregateDiscoveredResourceCountsError::from_response(
-                response,
-            ))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(GetAggregateResourceConfigError::from_response(response))
         }
     }
 
-    /// <p>Returns configuration item that is aggregated for your specific resource in a specific source account and region.</p>
-    async fn get_aggregate_resource_config(
+    /// Presigns the request `get_aggregate_resource_config` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_aggregate_resource_config(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetAggregateResourceConfigRequest,
-    ) -> Result<GetAggregateResourceConfigResponse, RusotoError<GetAggregateResourceConfigError>>
-    {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_aggregate_resource_config_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_aggregate_resource_config_request(
+        &self,
+        input: GetAggregateResourceConfigRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "config", &self.region, "/");
+        request.set_operation("GetAggregateResourceConfig");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -9582,31 +11662,60 @@ impl ConfigService for ConfigServiceClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `get_compliance_details_by_config_rule`, also returning the metadata of the response.
+    pub async fn get_compliance_details_by_config_rule_with_metadata(
+        &self,
+        input: GetComplianceDetailsByConfigRuleRequest,
+    ) -> Result<
+        (GetComplianceDetailsByConfigRuleResponse, ResponseMetadata),
+        RusotoError<GetComplianceDetailsByConfigRuleError>,
+    > {
+        let request = self.build_get_compliance_details_by_config_rule_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<GetAggregateResourceConfigResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<GetComplianceDetailsByConfigRuleResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(GetAggregateResourceConfigError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(GetComplianceDetailsByConfigRuleError::from_response(
+                response,
+            ))
         }
     }
 
-    /// <p>Returns the evaluation results for the specified AWS Config rule. The results indicate which AWS resources were evaluated by the rule, when each resource was last evaluated, and whether each resource complies with the rule.</p>
-    async fn get_compliance_details_by_config_rule(
+    /// Presigns the request `get_compliance_details_by_config_rule` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_compliance_details_by_config_rule(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetComplianceDetailsByConfigRuleRequest,
-    ) -> Result<
-        GetComplianceDetailsByConfigRuleResponse,
-        RusotoError<GetComplianceDetailsByConfigRuleError>,
-    > {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_compliance_details_by_config_rule_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_compliance_details_by_config_rule_request(
+        &self,
+        input: GetComplianceDetailsByConfigRuleRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "config", &self.region, "/");
+        request.set_operation("GetComplianceDetailsByConfigRule");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -9616,33 +11725,58 @@ impl ConfigService for ConfigServiceClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `get_compliance_details_by_resource`, also returning the metadata of the response.
+    pub async fn get_compliance_details_by_resource_with_metadata(
+        &self,
+        input: GetComplianceDetailsByResourceRequest,
+    ) -> Result<
+        (GetComplianceDetailsByResourceResponse, ResponseMetadata),
+        RusotoError<GetComplianceDetailsByResourceError>,
+    > {
+        let request = self.build_get_compliance_details_by_resource_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<GetComplianceDetailsByConfigRuleResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<GetComplianceDetailsByResourceResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(GetComplianceDetailsByConfigRuleError::from_response(
-                response,
-            ))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(GetComplianceDetailsByResourceError::from_response(response))
         }
     }
 
-    /// <p>Returns the evaluation results for the specified AWS resource. The results indicate which AWS Config rules were used to evaluate the resource, when each rule was last used, and whether the resource complies with each rule.</p>
-    async fn get_compliance_details_by_resource(
+    /// Presigns the request `get_compliance_details_by_resource` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_compliance_details_by_resource(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetComplianceDetailsByResourceRequest,
-    ) -> Result<
-        GetComplianceDetailsByResourceResponse,
-        RusotoError<GetComplianceDetailsByResourceError>,
-    > {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_compliance_details_by_resource_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_compliance_details_by_resource_request(
+        &self,
+        input: GetComplianceDetailsByResourceRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "config", &self.region, "/");
+        request.set_operation("GetComplianceDetailsByResource");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -9652,30 +11786,55 @@ impl ConfigService for ConfigServiceClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `get_compliance_summary_by_config_rule`, also returning the metadata of the response.
+    pub async fn get_compliance_summary_by_config_rule_with_metadata(
+        &self,
+    ) -> Result<
+        (GetComplianceSummaryByConfigRuleResponse, ResponseMetadata),
+        RusotoError<GetComplianceSummaryByConfigRuleError>,
+    > {
+        let request = self.build_get_compliance_summary_by_config_rule_request();
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<GetComplianceDetailsByResourceResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<GetComplianceSummaryByConfigRuleResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(GetComplianceDetailsByResourceError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(GetComplianceSummaryByConfigRuleError::from_response(
+                response,
+            ))
         }
     }
 
-    /// <p>Returns the number of AWS Config rules that are compliant and noncompliant, up to a maximum of 25 for each.</p>
-    async fn get_compliance_summary_by_config_rule(
+    /// Presigns the request `get_compliance_summary_by_config_rule` would send with `presigner` instead of sending it.
+    pub async fn presign_get_compliance_summary_by_config_rule(
         &self,
-    ) -> Result<
-        GetComplianceSummaryByConfigRuleResponse,
-        RusotoError<GetComplianceSummaryByConfigRuleError>,
-    > {
+        presigner: &rusoto_core::Presigner,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_compliance_summary_by_config_rule_request();
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_compliance_summary_by_config_rule_request(&self) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "config", &self.region, "/");
+        request.set_operation("GetComplianceSummaryByConfigRule");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -9684,33 +11843,60 @@ impl ConfigService for ConfigServiceClient {
         );
         request.set_payload(Some(bytes::Bytes::from_static(b"{}")));
 
+        request
+    }
+
+    /// Calls `get_compliance_summary_by_resource_type`, also returning the metadata of the response.
+    pub async fn get_compliance_summary_by_resource_type_with_metadata(
+        &self,
+        input: GetComplianceSummaryByResourceTypeRequest,
+    ) -> Result<
+        (GetComplianceSummaryByResourceTypeResponse, ResponseMetadata),
+        RusotoError<GetComplianceSummaryByResourceTypeError>,
+    > {
+        let request = self.build_get_compliance_summary_by_resource_type_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<GetComplianceSummaryByConfigRuleResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<GetComplianceSummaryByResourceTypeResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(GetComplianceSummaryByConfigRuleError::from_response(
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(GetComplianceSummaryByResourceTypeError::from_response(
                 response,
             ))
         }
     }
 
-    /// <p>Returns the number of resources that are compliant and the number that are noncompliant. You can specify one or more resource types to get these numbers for each resource type. The maximum number returned is 100.</p>
-    async fn get_compliance_summary_by_resource_type(
+    /// Presigns the request `get_compliance_summary_by_resource_type` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_compliance_summary_by_resource_type(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetComplianceSummaryByResourceTypeRequest,
-    ) -> Result<
-        GetComplianceSummaryByResourceTypeResponse,
-        RusotoError<GetComplianceSummaryByResourceTypeError>,
-    > {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_compliance_summary_by_resource_type_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_compliance_summary_by_resource_type_request(
+        &self,
+        input: GetComplianceSummaryByResourceTypeRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "config", &self.region, "/");
+        request.set_operation("GetComplianceSummaryByResourceType");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -9720,33 +11906,63 @@ impl ConfigService for ConfigServiceClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `get_conformance_pack_compliance_details`, also returning the metadata of the response.
+    pub async fn get_conformance_pack_compliance_details_with_metadata(
+        &self,
+        input: GetConformancePackComplianceDetailsRequest,
+    ) -> Result<
+        (
+            GetConformancePackComplianceDetailsResponse,
+            ResponseMetadata,
+        ),
+        RusotoError<GetConformancePackComplianceDetailsError>,
+    > {
+        let request = self.build_get_conformance_pack_compliance_details_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<GetComplianceSummaryByResourceTypeResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<GetConformancePackComplianceDetailsResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(GetComplianceSummaryByResourceTypeError::from_response(
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(GetConformancePackComplianceDetailsError::from_response(
                 response,
             ))
         }
     }
 
-    /// <p>Returns compliance details of a conformance pack for all AWS resources that are monitered by conformance pack.</p>
-    async fn get_conformance_pack_compliance_details(
+    /// Presigns the request `get_conformance_pack_compliance_details` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_conformance_pack_compliance_details(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetConformancePackComplianceDetailsRequest,
-    ) -> Result<
-        GetConformancePackComplianceDetailsResponse,
-        RusotoError<GetConformancePackComplianceDetailsError>,
-    > {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_conformance_pack_compliance_details_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_conformance_pack_compliance_details_request(
+        &self,
+        input: GetConformancePackComplianceDetailsRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "config", &self.region, "/");
+        request.set_operation("GetConformancePackComplianceDetails");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -9756,33 +11972,63 @@ impl ConfigService for ConfigServiceClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `get_conformance_pack_compliance_summary`, also returning the metadata of the response.
+    pub async fn get_conformance_pack_compliance_summary_with_metadata(
+        &self,
+        input: GetConformancePackComplianceSummaryRequest,
+    ) -> Result<
+        (
+            GetConformancePackComplianceSummaryResponse,
+            ResponseMetadata,
+        ),
+        RusotoError<GetConformancePackComplianceSummaryError>,
+    > {
+        let request = self.build_get_conformance_pack_compliance_summary_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<GetConformancePackComplianceDetailsResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<GetConformancePackComplianceSummaryResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(GetConformancePackComplianceDetailsError::from_response(
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(GetConformancePackComplianceSummaryError::from_response(
                 response,
             ))
         }
     }
 
-    /// <p>Returns compliance details for the conformance pack based on the cumulative compliance results of all the rules in that conformance pack.</p>
-    async fn get_conformance_pack_compliance_summary(
+    /// Presigns the request `get_conformance_pack_compliance_summary` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_conformance_pack_compliance_summary(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetConformancePackComplianceSummaryRequest,
-    ) -> Result<
-        GetConformancePackComplianceSummaryResponse,
-        RusotoError<GetConformancePackComplianceSummaryError>,
-    > {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_conformance_pack_compliance_summary_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_conformance_pack_compliance_summary_request(
+        &self,
+        input: GetConformancePackComplianceSummaryRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "config", &self.region, "/");
+        request.set_operation("GetConformancePackComplianceSummary");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -9792,31 +12038,58 @@ impl ConfigService for ConfigServiceClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `get_discovered_resource_counts`, also returning the metadata of the response.
+    pub async fn get_discovered_resource_counts_with_metadata(
+        &self,
+        input: GetDiscoveredResourceCountsRequest,
+    ) -> Result<
+        (GetDiscoveredResourceCountsResponse, ResponseMetadata),
+        RusotoError<GetDiscoveredResourceCountsError>,
+    > {
+        let request = self.build_get_discovered_resource_counts_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<GetConformancePackComplianceSummaryResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<GetDiscoveredResourceCountsResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(GetConformancePackComplianceSummaryError::from_response(
-                response,
-            ))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(GetDiscoveredResourceCountsError::from_response(response))
         }
     }
 
-    /// <p><p>Returns the resource types, the number of each resource type, and the total number of resources that AWS Config is recording in this region for your AWS account. </p> <p class="title"> <b>Example</b> </p> <ol> <li> <p>AWS Config is recording three resource types in the US East (Ohio) Region for your account: 25 EC2 instances, 20 IAM users, and 15 S3 buckets.</p> </li> <li> <p>You make a call to the <code>GetDiscoveredResourceCounts</code> action and specify that you want all resource types. </p> </li> <li> <p>AWS Config returns the following:</p> <ul> <li> <p>The resource types (EC2 instances, IAM users, and S3 buckets).</p> </li> <li> <p>The number of each resource type (25, 20, and 15).</p> </li> <li> <p>The total number of all resources (60).</p> </li> </ul> </li> </ol> <p>The response is paginated. By default, AWS Config lists 100 <a>ResourceCount</a> objects on each page. You can customize this number with the <code>limit</code> parameter. The response includes a <code>nextToken</code> string. To get the next page of results, run the request again and specify the string for the <code>nextToken</code> parameter.</p> <note> <p>If you make a call to the <a>GetDiscoveredResourceCounts</a> action, you might not immediately receive resource counts in the following situations:</p> <ul> <li> <p>You are a new AWS Config customer.</p> </li> <li> <p>You just enabled resource recording.</p> </li> </ul> <p>It might take a few minutes for AWS Config to record and count your resources. Wait a few minutes and then retry the <a>GetDiscoveredResourceCounts</a> action. </p> </note></p>
-    async fn get_discovered_resource_counts(
+    /// Presigns the request `get_discovered_resource_counts` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_discovered_resource_counts(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetDiscoveredResourceCountsRequest,
-    ) -> Result<GetDiscoveredResourceCountsResponse, RusotoError<GetDiscoveredResourceCountsError>>
-    {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_discovered_resource_counts_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_discovered_resource_counts_request(
+        &self,
+        input: GetDiscoveredResourceCountsRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "config", &self.region, "/");
+        request.set_operation("GetDiscoveredResourceCounts");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -9826,132 +12099,252 @@ impl ConfigService for ConfigServiceClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
-        let mut response = self
-            .client
-            .sign_and_dispatch(request)
-            .await
-            .map_err(RusotoError::from)?;
-        if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<GetDiscoveredResourceCountsResponse, _>()
-        } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(GetDiscoveredResourceCountsError::from_response(response))
-        }
+        request
     }
 
-    /// <p><p>Returns detailed status for each member account within an organization for a given organization config rule.</p> <note> <p>Only a master account can call this API.</p> </note></p>
-    async fn get_organization_config_rule_detailed_status(
+    /// Calls `get_organization_config_rule_detailed_status`, also returning the metadata of the response.
+    pub async fn get_organization_config_rule_detailed_status_with_metadata(
         &self,
         input: GetOrganizationConfigRuleDetailedStatusRequest,
     ) -> Result<
-        GetOrganizationConfigRuleDetailedStatusResponse,
+        (
+            GetOrganizationConfigRuleDetailedStatusResponse,
+            ResponseMetadata,
+        ),
         RusotoError<GetOrganizationConfigRuleDetailedStatusError>,
     > {
-        let mut request = SignedRequest::new("POST", "config", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header(
-            "x-amz-target",
-            "StarlingDoveService.GetOrganizationConfigRuleDetailedStatus",
-        );
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
+        let request = self.build_get_organization_config_rule_detailed_status_request(input);
 
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<GetOrganizationConfigRuleDetailedStatusResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<GetOrganizationConfigRuleDetailedStatusResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
             Err(GetOrganizationConfigRuleDetailedStatusError::from_response(
                 response,
             ))
         }
     }
 
-    /// <p>Returns detailed status for each member account within an organization for a given organization conformance pack.</p> <p>Only a master account can call this API.</p>
-    async fn get_organization_conformance_pack_detailed_status(
+    /// Presigns the request `get_organization_config_rule_detailed_status` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_organization_config_rule_detailed_status(
         &self,
-        input: GetOrganizationConformancePackDetailedStatusRequest,
-    ) -> Result<
-        GetOrganizationConformancePackDetailedStatusResponse,
-        RusotoError<GetOrganizationConformancePackDetailedStatusError>,
-    > {
+        presigner: &rusoto_core::Presigner,
+        input: GetOrganizationConfigRuleDetailedStatusRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_organization_config_rule_detailed_status_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_organization_config_rule_detailed_status_request(
+        &self,
+        input: GetOrganizationConfigRuleDetailedStatusRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "config", &self.region, "/");
+        request.set_operation("GetOrganizationConfigRuleDetailedStatus");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
             "x-amz-target",
-            "StarlingDoveService.GetOrganizationConformancePackDetailedStatus",
+            "StarlingDoveService.GetOrganizationConfigRuleDetailedStatus",
         );
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `get_organization_conformance_pack_detailed_status`, also returning the metadata of the response.
+    pub async fn get_organization_conformance_pack_detailed_status_with_metadata(
+        &self,
+        input: GetOrganizationConformancePackDetailedStatusRequest,
+    ) -> Result<
+        (
+            GetOrganizationConformancePackDetailedStatusResponse,
+            ResponseMetadata,
+        ),
+        RusotoError<GetOrganizationConformancePackDetailedStatusError>,
+    > {
+        let request = self.build_get_organization_conformance_pack_detailed_status_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<GetOrganizationConformancePackDetailedStatusResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<GetOrganizationConformancePackDetailedStatusResponse, _>(
+            )?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
             Err(GetOrganizationConformancePackDetailedStatusError::from_response(response))
         }
     }
 
-    /// <p><p>Returns a list of configuration items for the specified resource. The list contains details about each state of the resource during the specified time interval. If you specified a retention period to retain your <code>ConfigurationItems</code> between a minimum of 30 days and a maximum of 7 years (2557 days), AWS Config returns the <code>ConfigurationItems</code> for the specified retention period. </p> <p>The response is paginated. By default, AWS Config returns a limit of 10 configuration items per page. You can customize this number with the <code>limit</code> parameter. The response includes a <code>nextToken</code> string. To get the next page of results, run the request again and specify the string for the <code>nextToken</code> parameter.</p> <note> <p>Each call to the API is limited to span a duration of seven days. It is likely that the number of records returned is smaller than the specified <code>limit</code>. In such cases, you can make another call, using the <code>nextToken</code>.</p> </note></p>
-    async fn get_resource_config_history(
+    /// Presigns the request `get_organization_conformance_pack_detailed_status` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_organization_conformance_pack_detailed_status(
         &self,
-        input: GetResourceConfigHistoryRequest,
-    ) -> Result<GetResourceConfigHistoryResponse, RusotoError<GetResourceConfigHistoryError>> {
+        presigner: &rusoto_core::Presigner,
+        input: GetOrganizationConformancePackDetailedStatusRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_organization_conformance_pack_detailed_status_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_organization_conformance_pack_detailed_status_request(
+        &self,
+        input: GetOrganizationConformancePackDetailedStatusRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "config", &self.region, "/");
+        request.set_operation("GetOrganizationConformancePackDetailedStatus");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
             "x-amz-target",
-            "StarlingDoveService.GetResourceConfigHistory",
+            "StarlingDoveService.GetOrganizationConformancePackDetailedStatus",
         );
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `get_resource_config_history`, also returning the metadata of the response.
+    pub async fn get_resource_config_history_with_metadata(
+        &self,
+        input: GetResourceConfigHistoryRequest,
+    ) -> Result<
+        (GetResourceConfigHistoryResponse, ResponseMetadata),
+        RusotoError<GetResourceConfigHistoryError>,
+    > {
+        let request = self.build_get_resource_config_history_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<GetResourceConfigHistoryResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<GetResourceConfigHistoryResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
             Err(GetResourceConfigHistoryError::from_response(response))
         }
     }
 
-    /// <p>Accepts a resource type and returns a list of resource identifiers that are aggregated for a specific resource type across accounts and regions. A resource identifier includes the resource type, ID, (if available) the custom resource name, source account, and source region. You can narrow the results to include only resources that have specific resource IDs, or a resource name, or source account ID, or source region.</p> <p>For example, if the input consists of accountID 12345678910 and the region is us-east-1 for resource type <code>AWS::EC2::Instance</code> then the API returns all the EC2 instance identifiers of accountID 12345678910 and region us-east-1.</p>
-    async fn list_aggregate_discovered_resources(
+    /// Presigns the request `get_resource_config_history` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_resource_config_history(
+        &self,
+        presigner: &rusoto_core::Presigner,
+        input: GetResourceConfigHistoryRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_resource_config_history_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_resource_config_history_request(
+        &self,
+        input: GetResourceConfigHistoryRequest,
+    ) -> SignedRequest {
+        let mut request = SignedRequest::new("POST", "config", &self.region, "/");
+        request.set_operation("GetResourceConfigHistory");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header(
+            "x-amz-target",
+            "StarlingDoveService.GetResourceConfigHistory",
+        );
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        request
+    }
+
+    /// Calls `list_aggregate_discovered_resources`, also returning the metadata of the response.
+    pub async fn list_aggregate_discovered_resources_with_metadata(
         &self,
         input: ListAggregateDiscoveredResourcesRequest,
     ) -> Result<
-        ListAggregateDiscoveredResourcesResponse,
+        (ListAggregateDiscoveredResourcesResponse, ResponseMetadata),
         RusotoError<ListAggregateDiscoveredResourcesError>,
     > {
+        let request = self.build_list_aggregate_discovered_resources_request(input);
+
+        let mut response = self
+            .client
+            .sign_and_dispatch(request)
+            .await
+            .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
+        if response.status.is_success() {
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ListAggregateDiscoveredResourcesResponse, _>()?;
+            Ok((result, metadata))
+        } else {
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ListAggregateDiscoveredResourcesError::from_response(
+                response,
+            ))
+        }
+    }
+
+    /// Presigns the request `list_aggregate_discovered_resources` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_aggregate_discovered_resources(
+        &self,
+        presigner: &rusoto_core::Presigner,
+        input: ListAggregateDiscoveredResourcesRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_aggregate_discovered_resources_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_aggregate_discovered_resources_request(
+        &self,
+        input: ListAggregateDiscoveredResourcesRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "config", &self.region, "/");
+        request.set_operation("ListAggregateDiscoveredResources");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -9961,30 +12354,58 @@ impl ConfigService for ConfigServiceClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `list_discovered_resources`, also returning the metadata of the response.
+    pub async fn list_discovered_resources_with_metadata(
+        &self,
+        input: ListDiscoveredResourcesRequest,
+    ) -> Result<
+        (ListDiscoveredResourcesResponse, ResponseMetadata),
+        RusotoError<ListDiscoveredResourcesError>,
+    > {
+        let request = self.build_list_discovered_resources_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListAggregateDiscoveredResourcesResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ListDiscoveredResourcesResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ListAggregateDiscoveredResourcesError::from_response(
-                response,
-            ))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ListDiscoveredResourcesError::from_response(response))
         }
     }
 
-    /// <p>Accepts a resource type and returns a list of resource identifiers for the resources of that type. A resource identifier includes the resource type, ID, and (if available) the custom resource name. The results consist of resources that AWS Config has discovered, including those that AWS Config is not currently recording. You can narrow the results to include only resources that have specific resource IDs or a resource name.</p> <note> <p>You can specify either resource IDs or a resource name, but not both, in the same request.</p> </note> <p>The response is paginated. By default, AWS Config lists 100 resource identifiers on each page. You can customize this number with the <code>limit</code> parameter. The response includes a <code>nextToken</code> string. To get the next page of results, run the request again and specify the string for the <code>nextToken</code> parameter.</p>
-    async fn list_discovered_resources(
+    /// Presigns the request `list_discovered_resources` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_discovered_resources(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListDiscoveredResourcesRequest,
-    ) -> Result<ListDiscoveredResourcesResponse, RusotoError<ListDiscoveredResourcesError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_discovered_resources_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_discovered_resources_request(
+        &self,
+        input: ListDiscoveredResourcesRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "config", &self.region, "/");
+        request.set_operation("ListDiscoveredResources");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -9994,57 +12415,116 @@ impl ConfigService for ConfigServiceClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `list_tags_for_resource`, also returning the metadata of the response.
+    pub async fn list_tags_for_resource_with_metadata(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> Result<
+        (ListTagsForResourceResponse, ResponseMetadata),
+        RusotoError<ListTagsForResourceError>,
+    > {
+        let request = self.build_list_tags_for_resource_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListDiscoveredResourcesResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ListTagsForResourceResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ListDiscoveredResourcesError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ListTagsForResourceError::from_response(response))
         }
     }
 
-    /// <p>List the tags for AWS Config resource.</p>
-    async fn list_tags_for_resource(
+    /// Presigns the request `list_tags_for_resource` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_tags_for_resource(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListTagsForResourceRequest,
-    ) -> Result<ListTagsForResourceResponse, RusotoError<ListTagsForResourceError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_tags_for_resource_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_tags_for_resource_request(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "config", &self.region, "/");
+        request.set_operation("ListTagsForResource");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "StarlingDoveService.ListTagsForResource");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `put_aggregation_authorization`, also returning the metadata of the response.
+    pub async fn put_aggregation_authorization_with_metadata(
+        &self,
+        input: PutAggregationAuthorizationRequest,
+    ) -> Result<
+        (PutAggregationAuthorizationResponse, ResponseMetadata),
+        RusotoError<PutAggregationAuthorizationError>,
+    > {
+        let request = self.build_put_aggregation_authorization_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListTagsForResourceResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<PutAggregationAuthorizationResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ListTagsForResourceError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(PutAggregationAuthorizationError::from_response(response))
         }
     }
 
-    /// <p>Authorizes the aggregator account and region to collect data from the source account and region. </p>
-    async fn put_aggregation_authorization(
+    /// Presigns the request `put_aggregation_authorization` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_put_aggregation_authorization(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: PutAggregationAuthorizationRequest,
-    ) -> Result<PutAggregationAuthorizationResponse, RusotoError<PutAggregationAuthorizationError>>
-    {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_put_aggregation_authorization_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_put_aggregation_authorization_request(
+        &self,
+        input: PutAggregationAuthorizationRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "config", &self.region, "/");
+        request.set_operation("PutAggregationAuthorization");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -10054,56 +12534,105 @@ impl ConfigService for ConfigServiceClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `put_config_rule`, also returning the metadata of the response.
+    pub async fn put_config_rule_with_metadata(
+        &self,
+        input: PutConfigRuleRequest,
+    ) -> Result<((), ResponseMetadata), RusotoError<PutConfigRuleError>> {
+        let request = self.build_put_config_rule_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<PutAggregationAuthorizationResponse, _>()
+            std::mem::drop(response);
+            Ok(((), metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(PutAggregationAuthorizationError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(PutConfigRuleError::from_response(response))
         }
     }
 
-    /// <p>Adds or updates an AWS Config rule for evaluating whether your AWS resources comply with your desired configurations.</p> <p>You can use this action for custom AWS Config rules and AWS managed Config rules. A custom AWS Config rule is a rule that you develop and maintain. An AWS managed Config rule is a customizable, predefined rule that AWS Config provides.</p> <p>If you are adding a new custom AWS Config rule, you must first create the AWS Lambda function that the rule invokes to evaluate your resources. When you use the <code>PutConfigRule</code> action to add the rule to AWS Config, you must specify the Amazon Resource Name (ARN) that AWS Lambda assigns to the function. Specify the ARN for the <code>SourceIdentifier</code> key. This key is part of the <code>Source</code> object, which is part of the <code>ConfigRule</code> object. </p> <p>If you are adding an AWS managed Config rule, specify the rule's identifier for the <code>SourceIdentifier</code> key. To reference AWS managed Config rule identifiers, see <a href="https://docs.aws.amazon.com/config/latest/developerguide/evaluate-config_use-managed-rules.html">About AWS Managed Config Rules</a>.</p> <p>For any new rule that you add, specify the <code>ConfigRuleName</code> in the <code>ConfigRule</code> object. Do not specify the <code>ConfigRuleArn</code> or the <code>ConfigRuleId</code>. These values are generated by AWS Config for new rules.</p> <p>If you are updating a rule that you added previously, you can specify the rule by <code>ConfigRuleName</code>, <code>ConfigRuleId</code>, or <code>ConfigRuleArn</code> in the <code>ConfigRule</code> data type that you use in this request.</p> <p>The maximum number of rules that AWS Config supports is 150.</p> <p>For information about requesting a rule limit increase, see <a href="http://docs.aws.amazon.com/general/latest/gr/aws_service_limits.html#limits_config">AWS Config Limits</a> in the <i>AWS General Reference Guide</i>.</p> <p>For more information about developing and using AWS Config rules, see <a href="https://docs.aws.amazon.com/config/latest/developerguide/evaluate-config.html">Evaluating AWS Resource Configurations with AWS Config</a> in the <i>AWS Config Developer Guide</i>.</p>
-    async fn put_config_rule(
+    /// Presigns the request `put_config_rule` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_put_config_rule(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: PutConfigRuleRequest,
-    ) -> Result<(), RusotoError<PutConfigRuleError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_put_config_rule_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_put_config_rule_request(&self, input: PutConfigRuleRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "config", &self.region, "/");
+        request.set_operation("PutConfigRule");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "StarlingDoveService.PutConfigRule");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `put_configuration_aggregator`, also returning the metadata of the response.
+    pub async fn put_configuration_aggregator_with_metadata(
+        &self,
+        input: PutConfigurationAggregatorRequest,
+    ) -> Result<
+        (PutConfigurationAggregatorResponse, ResponseMetadata),
+        RusotoError<PutConfigurationAggregatorError>,
+    > {
+        let request = self.build_put_configuration_aggregator_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            std::mem::drop(response);
-            Ok(())
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<PutConfigurationAggregatorResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(PutConfigRuleError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(PutConfigurationAggregatorError::from_response(response))
         }
     }
 
-    /// <p><p>Creates and updates the configuration aggregator with the selected source accounts and regions. The source account can be individual account(s) or an organization.</p> <note> <p>AWS Config should be enabled in source accounts and regions you want to aggregate.</p> <p>If your source type is an organization, you must be signed in to the master account and all features must be enabled in your organization. AWS Config calls <code>EnableAwsServiceAccess</code> API to enable integration between AWS Config and AWS Organizations. </p> </note></p>
-    async fn put_configuration_aggregator(
+    /// Presigns the request `put_configuration_aggregator` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_put_configuration_aggregator(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: PutConfigurationAggregatorRequest,
-    ) -> Result<PutConfigurationAggregatorResponse, RusotoError<PutConfigurationAggregatorError>>
-    {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_put_configuration_aggregator_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_put_configuration_aggregator_request(
+        &self,
+        input: PutConfigurationAggregatorRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "config", &self.region, "/");
+        request.set_operation("PutConfigurationAggregator");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -10113,28 +12642,50 @@ impl ConfigService for ConfigServiceClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `put_configuration_recorder`, also returning the metadata of the response.
+    pub async fn put_configuration_recorder_with_metadata(
+        &self,
+        input: PutConfigurationRecorderRequest,
+    ) -> Result<((), ResponseMetadata), RusotoError<PutConfigurationRecorderError>> {
+        let request = self.build_put_configuration_recorder_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<PutConfigurationAggregatorResponse, _>()
+            std::mem::drop(response);
+            Ok(((), metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(PutConfigurationAggregatorError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(PutConfigurationRecorderError::from_response(response))
         }
     }
 
-    /// <p><p>Creates a new configuration recorder to record the selected resource configurations.</p> <p>You can use this action to change the role <code>roleARN</code> or the <code>recordingGroup</code> of an existing recorder. To change the role, call the action on the existing configuration recorder and specify a role.</p> <note> <p>Currently, you can specify only one configuration recorder per region in your account.</p> <p>If <code>ConfigurationRecorder</code> does not have the <b>recordingGroup</b> parameter specified, the default is to record all supported resource types.</p> </note></p>
-    async fn put_configuration_recorder(
+    /// Presigns the request `put_configuration_recorder` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_put_configuration_recorder(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: PutConfigurationRecorderRequest,
-    ) -> Result<(), RusotoError<PutConfigurationRecorderError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_put_configuration_recorder_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_put_configuration_recorder_request(
+        &self,
+        input: PutConfigurationRecorderRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "config", &self.region, "/");
+        request.set_operation("PutConfigurationRecorder");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -10144,110 +12695,216 @@ impl ConfigService for ConfigServiceClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `put_conformance_pack`, also returning the metadata of the response.
+    pub async fn put_conformance_pack_with_metadata(
+        &self,
+        input: PutConformancePackRequest,
+    ) -> Result<(PutConformancePackResponse, ResponseMetadata), RusotoError<PutConformancePackError>>
+    {
+        let request = self.build_put_conformance_pack_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            std::mem::drop(response);
-            Ok(())
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<PutConformancePackResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(PutConfigurationRecorderError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(PutConformancePackError::from_response(response))
         }
     }
 
-    /// <p><p>Creates or updates a conformance pack. A conformance pack is a collection of AWS Config rules that can be easily deployed in an account and a region and across AWS Organization.</p> <p>This API creates a service linked role <code>AWSServiceRoleForConfigConforms</code> in your account. The service linked role is created only when the role does not exist in your account. AWS Config verifies the existence of role with <code>GetRole</code> action.</p> <note> <p>You must specify either the <code>TemplateS3Uri</code> or the <code>TemplateBody</code> parameter, but not both. If you provide both AWS Config uses the <code>TemplateS3Uri</code> parameter and ignores the <code>TemplateBody</code> parameter.</p> </note></p>
-    async fn put_conformance_pack(
+    /// Presigns the request `put_conformance_pack` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_put_conformance_pack(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: PutConformancePackRequest,
-    ) -> Result<PutConformancePackResponse, RusotoError<PutConformancePackError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_put_conformance_pack_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_put_conformance_pack_request(
+        &self,
+        input: PutConformancePackRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "config", &self.region, "/");
+        request.set_operation("PutConformancePack");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "StarlingDoveService.PutConformancePack");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `put_delivery_channel`, also returning the metadata of the response.
+    pub async fn put_delivery_channel_with_metadata(
+        &self,
+        input: PutDeliveryChannelRequest,
+    ) -> Result<((), ResponseMetadata), RusotoError<PutDeliveryChannelError>> {
+        let request = self.build_put_delivery_channel_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<PutConformancePackResponse, _>()
+            std::mem::drop(response);
+            Ok(((), metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(PutConformancePackError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(PutDeliveryChannelError::from_response(response))
         }
     }
 
-    /// <p><p>Creates a delivery channel object to deliver configuration information to an Amazon S3 bucket and Amazon SNS topic.</p> <p>Before you can create a delivery channel, you must create a configuration recorder.</p> <p>You can use this action to change the Amazon S3 bucket or an Amazon SNS topic of the existing delivery channel. To change the Amazon S3 bucket or an Amazon SNS topic, call this action and specify the changed values for the S3 bucket and the SNS topic. If you specify a different value for either the S3 bucket or the SNS topic, this action will keep the existing value for the parameter that is not changed.</p> <note> <p>You can have only one delivery channel per region in your account.</p> </note></p>
-    async fn put_delivery_channel(
+    /// Presigns the request `put_delivery_channel` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_put_delivery_channel(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: PutDeliveryChannelRequest,
-    ) -> Result<(), RusotoError<PutDeliveryChannelError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_put_delivery_channel_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_put_delivery_channel_request(
+        &self,
+        input: PutDeliveryChannelRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "config", &self.region, "/");
+        request.set_operation("PutDeliveryChannel");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "StarlingDoveService.PutDeliveryChannel");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `put_evaluations`, also returning the metadata of the response.
+    pub async fn put_evaluations_with_metadata(
+        &self,
+        input: PutEvaluationsRequest,
+    ) -> Result<(PutEvaluationsResponse, ResponseMetadata), RusotoError<PutEvaluationsError>> {
+        let request = self.build_put_evaluations_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            std::mem::drop(response);
-            Ok(())
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<PutEvaluationsResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(PutDeliveryChannelError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(PutEvaluationsError::from_response(response))
         }
     }
 
-    /// <p>Used by an AWS Lambda function to deliver evaluation results to AWS Config. This action is required in every AWS Lambda function that is invoked by an AWS Config rule.</p>
-    async fn put_evaluations(
+    /// Presigns the request `put_evaluations` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_put_evaluations(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: PutEvaluationsRequest,
-    ) -> Result<PutEvaluationsResponse, RusotoError<PutEvaluationsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_put_evaluations_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_put_evaluations_request(&self, input: PutEvaluationsRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "config", &self.region, "/");
+        request.set_operation("PutEvaluations");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "StarlingDoveService.PutEvaluations");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `put_organization_config_rule`, also returning the metadata of the response.
+    pub async fn put_organization_config_rule_with_metadata(
+        &self,
+        input: PutOrganizationConfigRuleRequest,
+    ) -> Result<
+        (PutOrganizationConfigRuleResponse, ResponseMetadata),
+        RusotoError<PutOrganizationConfigRuleError>,
+    > {
+        let request = self.build_put_organization_config_rule_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<PutEvaluationsResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<PutOrganizationConfigRuleResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(PutEvaluationsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(PutOrganizationConfigRuleError::from_response(response))
         }
     }
 
-    /// <p><p>Adds or updates organization config rule for your entire organization evaluating whether your AWS resources comply with your desired configurations. Only a master account can create or update an organization config rule.</p> <p>This API enables organization service access through the <code>EnableAWSServiceAccess</code> action and creates a service linked role <code>AWSServiceRoleForConfigMultiAccountSetup</code> in the master account of your organization. The service linked role is created only when the role does not exist in the master account. AWS Config verifies the existence of role with <code>GetRole</code> action.</p> <p>You can use this action to create both custom AWS Config rules and AWS managed Config rules. If you are adding a new custom AWS Config rule, you must first create AWS Lambda function in the master account that the rule invokes to evaluate your resources. When you use the <code>PutOrganizationConfigRule</code> action to add the rule to AWS Config, you must specify the Amazon Resource Name (ARN) that AWS Lambda assigns to the function. If you are adding an AWS managed Config rule, specify the rule&#39;s identifier for the <code>RuleIdentifier</code> key.</p> <p>The maximum number of organization config rules that AWS Config supports is 150.</p> <note> <p>Specify either <code>OrganizationCustomRuleMetadata</code> or <code>OrganizationManagedRuleMetadata</code>.</p> </note></p>
-    async fn put_organization_config_rule(
+    /// Presigns the request `put_organization_config_rule` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_put_organization_config_rule(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: PutOrganizationConfigRuleRequest,
-    ) -> Result<PutOrganizationConfigRuleResponse, RusotoError<PutOrganizationConfigRuleError>>
-    {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_put_organization_config_rule_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_put_organization_config_rule_request(
+        &self,
+        input: PutOrganizationConfigRuleRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "config", &self.region, "/");
+        request.set_operation("PutOrganizationConfigRule");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -10257,31 +12914,58 @@ impl ConfigService for ConfigServiceClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `put_organization_conformance_pack`, also returning the metadata of the response.
+    pub async fn put_organization_conformance_pack_with_metadata(
+        &self,
+        input: PutOrganizationConformancePackRequest,
+    ) -> Result<
+        (PutOrganizationConformancePackResponse, ResponseMetadata),
+        RusotoError<PutOrganizationConformancePackError>,
+    > {
+        let request = self.build_put_organization_conformance_pack_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<PutOrganizationConfigRuleResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<PutOrganizationConformancePackResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(PutOrganizationConfigRuleError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(PutOrganizationConformancePackError::from_response(response))
         }
     }
 
-    /// <p><p>Deploys conformance packs across member accounts in an AWS Organization.</p> <p>This API enables organization service access for <code>config-multiaccountsetup.amazonaws.com</code> through the <code>EnableAWSServiceAccess</code> action and creates a service linked role <code>AWSServiceRoleForConfigMultiAccountSetup</code> in the master account of your organization. The service linked role is created only when the role does not exist in the master account. AWS Config verifies the existence of role with GetRole action.</p> <note> <p>You must specify either the <code>TemplateS3Uri</code> or the <code>TemplateBody</code> parameter, but not both. If you provide both AWS Config uses the <code>TemplateS3Uri</code> parameter and ignores the <code>TemplateBody</code> parameter.</p> <p>AWS Config sets the state of a conformance pack to CREATE<em>IN</em>PROGRESS and UPDATE<em>IN</em>PROGRESS until the confomance pack is created or updated. You cannot update a conformance pack while it is in this state.</p> <p>You can create 6 conformance packs with 25 AWS Config rules in each pack.</p> </note></p>
-    async fn put_organization_conformance_pack(
+    /// Presigns the request `put_organization_conformance_pack` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_put_organization_conformance_pack(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: PutOrganizationConformancePackRequest,
-    ) -> Result<
-        PutOrganizationConformancePackResponse,
-        RusotoError<PutOrganizationConformancePackError>,
-    > {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_put_organization_conformance_pack_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_put_organization_conformance_pack_request(
+        &self,
+        input: PutOrganizationConformancePackRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "config", &self.region, "/");
+        request.set_operation("PutOrganizationConformancePack");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -10291,29 +12975,58 @@ impl ConfigService for ConfigServiceClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `put_remediation_configurations`, also returning the metadata of the response.
+    pub async fn put_remediation_configurations_with_metadata(
+        &self,
+        input: PutRemediationConfigurationsRequest,
+    ) -> Result<
+        (PutRemediationConfigurationsResponse, ResponseMetadata),
+        RusotoError<PutRemediationConfigurationsError>,
+    > {
+        let request = self.build_put_remediation_configurations_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<PutOrganizationConformancePackResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<PutRemediationConfigurationsResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(PutOrganizationConformancePackError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(PutRemediationConfigurationsError::from_response(response))
         }
     }
 
-    /// <p>Adds or updates the remediation configuration with a specific AWS Config rule with the selected target or action. The API creates the <code>RemediationConfiguration</code> object for the AWS Config rule. The AWS Config rule must already exist for you to add a remediation configuration. The target (SSM document) must exist and have permissions to use the target. </p>
-    async fn put_remediation_configurations(
+    /// Presigns the request `put_remediation_configurations` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_put_remediation_configurations(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: PutRemediationConfigurationsRequest,
-    ) -> Result<PutRemediationConfigurationsResponse, RusotoError<PutRemediationConfigurationsError>>
-    {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_put_remediation_configurations_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_put_remediation_configurations_request(
+        &self,
+        input: PutRemediationConfigurationsRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "config", &self.region, "/");
+        request.set_operation("PutRemediationConfigurations");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -10323,28 +13036,58 @@ impl ConfigService for ConfigServiceClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
-        let mut response = self
-            .client
+        request
+    }
+
+    /// Calls `put_remediation_exceptions`, also returning the metadata of the response.
+    pub async fn put_remediation_exceptions_with_metadata(
+        &self,
+        input: PutRemediationExceptionsRequest,
+    ) -> Result<
+        (PutRemediationExceptionsResponse, ResponseMetadata),
+        RusotoError<PutRemediationExceptionsError>,
+    > {
+        let request = self.build_put_remediation_exceptions_request(input);
+
+        let mut response = self
+            .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<PutRemediationConfigurationsResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<PutRemediationExceptionsResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(PutRemediationConfigurationsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(PutRemediationExceptionsError::from_response(response))
         }
     }
 
-    /// <p>A remediation exception is when a specific resource is no longer considered for auto-remediation. This API adds a new exception or updates an exisiting exception for a specific resource with a specific AWS Config rule. </p>
-    async fn put_remediation_exceptions(
+    /// Presigns the request `put_remediation_exceptions` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_put_remediation_exceptions(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: PutRemediationExceptionsRequest,
-    ) -> Result<PutRemediationExceptionsResponse, RusotoError<PutRemediationExceptionsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_put_remediation_exceptions_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_put_remediation_exceptions_request(
+        &self,
+        input: PutRemediationExceptionsRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "config", &self.region, "/");
+        request.set_operation("PutRemediationExceptions");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -10354,56 +13097,105 @@ impl ConfigService for ConfigServiceClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `put_resource_config`, also returning the metadata of the response.
+    pub async fn put_resource_config_with_metadata(
+        &self,
+        input: PutResourceConfigRequest,
+    ) -> Result<((), ResponseMetadata), RusotoError<PutResourceConfigError>> {
+        let request = self.build_put_resource_config_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<PutRemediationExceptionsResponse, _>()
+            std::mem::drop(response);
+            Ok(((), metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(PutRemediationExceptionsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(PutResourceConfigError::from_response(response))
         }
     }
 
-    /// <p><p>Records the configuration state for the resource provided in the request. The configuration state of a resource is represented in AWS Config as Configuration Items. Once this API records the configuration item, you can retrieve the list of configuration items for the custom resource type using existing AWS Config APIs. </p> <note> <p>The custom resource type must be registered with AWS CloudFormation. This API accepts the configuration item registered with AWS CloudFormation.</p> <p>When you call this API, AWS Config only stores configuration state of the resource provided in the request. This API does not change or remediate the configuration of the resource. </p> </note></p>
-    async fn put_resource_config(
+    /// Presigns the request `put_resource_config` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_put_resource_config(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: PutResourceConfigRequest,
-    ) -> Result<(), RusotoError<PutResourceConfigError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_put_resource_config_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_put_resource_config_request(&self, input: PutResourceConfigRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "config", &self.region, "/");
+        request.set_operation("PutResourceConfig");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "StarlingDoveService.PutResourceConfig");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `put_retention_configuration`, also returning the metadata of the response.
+    pub async fn put_retention_configuration_with_metadata(
+        &self,
+        input: PutRetentionConfigurationRequest,
+    ) -> Result<
+        (PutRetentionConfigurationResponse, ResponseMetadata),
+        RusotoError<PutRetentionConfigurationError>,
+    > {
+        let request = self.build_put_retention_configuration_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            std::mem::drop(response);
-            Ok(())
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<PutRetentionConfigurationResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(PutResourceConfigError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(PutRetentionConfigurationError::from_response(response))
         }
     }
 
-    /// <p><p>Creates and updates the retention configuration with details about retention period (number of days) that AWS Config stores your historical information. The API creates the <code>RetentionConfiguration</code> object and names the object as <b>default</b>. When you have a <code>RetentionConfiguration</code> object named <b>default</b>, calling the API modifies the default object. </p> <note> <p>Currently, AWS Config supports only one retention configuration per region in your account.</p> </note></p>
-    async fn put_retention_configuration(
+    /// Presigns the request `put_retention_configuration` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_put_retention_configuration(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: PutRetentionConfigurationRequest,
-    ) -> Result<PutRetentionConfigurationResponse, RusotoError<PutRetentionConfigurationError>>
-    {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_put_retention_configuration_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_put_retention_configuration_request(
+        &self,
+        input: PutRetentionConfigurationRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "config", &self.region, "/");
+        request.set_operation("PutRetentionConfiguration");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -10413,57 +13205,116 @@ impl ConfigService for ConfigServiceClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `select_resource_config`, also returning the metadata of the response.
+    pub async fn select_resource_config_with_metadata(
+        &self,
+        input: SelectResourceConfigRequest,
+    ) -> Result<
+        (SelectResourceConfigResponse, ResponseMetadata),
+        RusotoError<SelectResourceConfigError>,
+    > {
+        let request = self.build_select_resource_config_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<PutRetentionConfigurationResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<SelectResourceConfigResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(PutRetentionConfigurationError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(SelectResourceConfigError::from_response(response))
         }
     }
 
-    /// <p>Accepts a structured query language (SQL) <code>SELECT</code> command, performs the corresponding search, and returns resource configurations matching the properties.</p> <p>For more information about query components, see the <a href="https://docs.aws.amazon.com/config/latest/developerguide/query-components.html"> <b>Query Components</b> </a> section in the AWS Config Developer Guide.</p>
-    async fn select_resource_config(
+    /// Presigns the request `select_resource_config` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_select_resource_config(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: SelectResourceConfigRequest,
-    ) -> Result<SelectResourceConfigResponse, RusotoError<SelectResourceConfigError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_select_resource_config_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_select_resource_config_request(
+        &self,
+        input: SelectResourceConfigRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "config", &self.region, "/");
+        request.set_operation("SelectResourceConfig");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "StarlingDoveService.SelectResourceConfig");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `start_config_rules_evaluation`, also returning the metadata of the response.
+    pub async fn start_config_rules_evaluation_with_metadata(
+        &self,
+        input: StartConfigRulesEvaluationRequest,
+    ) -> Result<
+        (StartConfigRulesEvaluationResponse, ResponseMetadata),
+        RusotoError<StartConfigRulesEvaluationError>,
+    > {
+        let request = self.build_start_config_rules_evaluation_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<SelectResourceConfigResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<StartConfigRulesEvaluationResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(SelectResourceConfigError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(StartConfigRulesEvaluationError::from_response(response))
         }
     }
 
-    /// <p><p>Runs an on-demand evaluation for the specified AWS Config rules against the last known configuration state of the resources. Use <code>StartConfigRulesEvaluation</code> when you want to test that a rule you updated is working as expected. <code>StartConfigRulesEvaluation</code> does not re-record the latest configuration state for your resources. It re-runs an evaluation against the last known state of your resources. </p> <p>You can specify up to 25 AWS Config rules per request. </p> <p>An existing <code>StartConfigRulesEvaluation</code> call for the specified rules must complete before you can call the API again. If you chose to have AWS Config stream to an Amazon SNS topic, you will receive a <code>ConfigRuleEvaluationStarted</code> notification when the evaluation starts.</p> <note> <p>You don&#39;t need to call the <code>StartConfigRulesEvaluation</code> API to run an evaluation for a new rule. When you create a rule, AWS Config evaluates your resources against the rule automatically. </p> </note> <p>The <code>StartConfigRulesEvaluation</code> API is useful if you want to run on-demand evaluations, such as the following example:</p> <ol> <li> <p>You have a custom rule that evaluates your IAM resources every 24 hours.</p> </li> <li> <p>You update your Lambda function to add additional conditions to your rule.</p> </li> <li> <p>Instead of waiting for the next periodic evaluation, you call the <code>StartConfigRulesEvaluation</code> API.</p> </li> <li> <p>AWS Config invokes your Lambda function and evaluates your IAM resources.</p> </li> <li> <p>Your custom rule will still run periodic evaluations every 24 hours.</p> </li> </ol></p>
-    async fn start_config_rules_evaluation(
+    /// Presigns the request `start_config_rules_evaluation` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_start_config_rules_evaluation(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: StartConfigRulesEvaluationRequest,
-    ) -> Result<StartConfigRulesEvaluationResponse, RusotoError<StartConfigRulesEvaluationError>>
-    {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_start_config_rules_evaluation_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_start_config_rules_evaluation_request(
+        &self,
+        input: StartConfigRulesEvaluationRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "config", &self.region, "/");
+        request.set_operation("StartConfigRulesEvaluation");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -10473,28 +13324,50 @@ impl ConfigService for ConfigServiceClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `start_configuration_recorder`, also returning the metadata of the response.
+    pub async fn start_configuration_recorder_with_metadata(
+        &self,
+        input: StartConfigurationRecorderRequest,
+    ) -> Result<((), ResponseMetadata), RusotoError<StartConfigurationRecorderError>> {
+        let request = self.build_start_configuration_recorder_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<StartConfigRulesEvaluationResponse, _>()
+            std::mem::drop(response);
+            Ok(((), metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(StartConfigRulesEvaluationError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(StartConfigurationRecorderError::from_response(response))
         }
     }
 
-    /// <p>Starts recording configurations of the AWS resources you have selected to record in your AWS account.</p> <p>You must have created at least one delivery channel to successfully start the configuration recorder.</p>
-    async fn start_configuration_recorder(
+    /// Presigns the request `start_configuration_recorder` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_start_configuration_recorder(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: StartConfigurationRecorderRequest,
-    ) -> Result<(), RusotoError<StartConfigurationRecorderError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_start_configuration_recorder_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_start_configuration_recorder_request(
+        &self,
+        input: StartConfigurationRecorderRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "config", &self.region, "/");
+        request.set_operation("StartConfigurationRecorder");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -10504,28 +13377,58 @@ impl ConfigService for ConfigServiceClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `start_remediation_execution`, also returning the metadata of the response.
+    pub async fn start_remediation_execution_with_metadata(
+        &self,
+        input: StartRemediationExecutionRequest,
+    ) -> Result<
+        (StartRemediationExecutionResponse, ResponseMetadata),
+        RusotoError<StartRemediationExecutionError>,
+    > {
+        let request = self.build_start_remediation_execution_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            std::mem::drop(response);
-            Ok(())
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<StartRemediationExecutionResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(StartConfigurationRecorderError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(StartRemediationExecutionError::from_response(response))
         }
     }
 
-    /// <p>Runs an on-demand remediation for the specified AWS Config rules against the last known remediation configuration. It runs an execution against the current state of your resources. Remediation execution is asynchronous.</p> <p>You can specify up to 100 resource keys per request. An existing StartRemediationExecution call for the specified resource keys must complete before you can call the API again.</p>
-    async fn start_remediation_execution(
+    /// Presigns the request `start_remediation_execution` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_start_remediation_execution(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: StartRemediationExecutionRequest,
-    ) -> Result<StartRemediationExecutionResponse, RusotoError<StartRemediationExecutionError>>
-    {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_start_remediation_execution_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_start_remediation_execution_request(
+        &self,
+        input: StartRemediationExecutionRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "config", &self.region, "/");
+        request.set_operation("StartRemediationExecution");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -10535,28 +13438,50 @@ impl ConfigService for ConfigServiceClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `stop_configuration_recorder`, also returning the metadata of the response.
+    pub async fn stop_configuration_recorder_with_metadata(
+        &self,
+        input: StopConfigurationRecorderRequest,
+    ) -> Result<((), ResponseMetadata), RusotoError<StopConfigurationRecorderError>> {
+        let request = self.build_stop_configuration_recorder_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<StartRemediationExecutionResponse, _>()
+            std::mem::drop(response);
+            Ok(((), metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(StartRemediationExecutionError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(StopConfigurationRecorderError::from_response(response))
         }
     }
 
-    /// <p>Stops recording configurations of the AWS resources you have selected to record in your AWS account.</p>
-    async fn stop_configuration_recorder(
+    /// Presigns the request `stop_configuration_recorder` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_stop_configuration_recorder(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: StopConfigurationRecorderRequest,
-    ) -> Result<(), RusotoError<StopConfigurationRecorderError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_stop_configuration_recorder_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_stop_configuration_recorder_request(
+        &self,
+        input: StopConfigurationRecorderRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "config", &self.region, "/");
+        request.set_operation("StopConfigurationRecorder");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -10566,72 +13491,2338 @@ impl ConfigService for ConfigServiceClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `tag_resource`, also returning the metadata of the response.
+    pub async fn tag_resource_with_metadata(
+        &self,
+        input: TagResourceRequest,
+    ) -> Result<((), ResponseMetadata), RusotoError<TagResourceError>> {
+        let request = self.build_tag_resource_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
             std::mem::drop(response);
-            Ok(())
+            Ok(((), metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(StopConfigurationRecorderError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(TagResourceError::from_response(response))
         }
     }
 
-    /// <p>Associates the specified tags to a resource with the specified resourceArn. If existing tags on a resource are not specified in the request parameters, they are not changed. When a resource is deleted, the tags associated with that resource are deleted as well.</p>
-    async fn tag_resource(
+    /// Presigns the request `tag_resource` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_tag_resource(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: TagResourceRequest,
-    ) -> Result<(), RusotoError<TagResourceError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_tag_resource_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_tag_resource_request(&self, input: TagResourceRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "config", &self.region, "/");
+        request.set_operation("TagResource");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "StarlingDoveService.TagResource");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `untag_resource`, also returning the metadata of the response.
+    pub async fn untag_resource_with_metadata(
+        &self,
+        input: UntagResourceRequest,
+    ) -> Result<((), ResponseMetadata), RusotoError<UntagResourceError>> {
+        let request = self.build_untag_resource_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
             std::mem::drop(response);
-            Ok(())
+            Ok(((), metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(TagResourceError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(UntagResourceError::from_response(response))
         }
     }
 
-    /// <p>Deletes specified tags from a resource.</p>
-    async fn untag_resource(
+    /// Presigns the request `untag_resource` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_untag_resource(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UntagResourceRequest,
-    ) -> Result<(), RusotoError<UntagResourceError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_untag_resource_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_untag_resource_request(&self, input: UntagResourceRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "config", &self.region, "/");
+        request.set_operation("UntagResource");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "StarlingDoveService.UntagResource");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
-        let mut response = self
-            .client
-            .sign_and_dispatch(request)
-            .await
-            .map_err(RusotoError::from)?;
-        if response.status.is_success() {
-            std::mem::drop(response);
-            Ok(())
-        } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(UntagResourceError::from_response(response))
-        }
+        request
+    }
+
+    /// The pages of `DescribeAggregateComplianceByConfigRules` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `Limit` of `input`.
+    pub fn describe_aggregate_compliance_by_config_rules_pages(
+        &self,
+        input: DescribeAggregateComplianceByConfigRulesRequest,
+    ) -> impl futures::Stream<
+        Item = Result<
+            DescribeAggregateComplianceByConfigRulesResponse,
+            RusotoError<DescribeAggregateComplianceByConfigRulesError>,
+        >,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: ConfigServiceClient, input| async move {
+                client
+                    .describe_aggregate_compliance_by_config_rules(input)
+                    .await
+            },
+            |input: &DescribeAggregateComplianceByConfigRulesRequest,
+             output: &DescribeAggregateComplianceByConfigRulesResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(DescribeAggregateComplianceByConfigRulesRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `AggregateComplianceByConfigRules` of every page of `DescribeAggregateComplianceByConfigRules` results for `input`, as `describe_aggregate_compliance_by_config_rules_pages` requests them.
+    pub fn describe_aggregate_compliance_by_config_rules_stream(
+        &self,
+        input: DescribeAggregateComplianceByConfigRulesRequest,
+    ) -> impl futures::Stream<
+        Item = Result<
+            AggregateComplianceByConfigRule,
+            RusotoError<DescribeAggregateComplianceByConfigRulesError>,
+        >,
+    > + Send {
+        rusoto_core::pagination::items(
+            self.describe_aggregate_compliance_by_config_rules_pages(input),
+            |page| {
+                page.aggregate_compliance_by_config_rules
+                    .unwrap_or_default()
+            },
+        )
+    }
+
+    /// The pages of `DescribeAggregationAuthorizations` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `Limit` of `input`.
+    pub fn describe_aggregation_authorizations_pages(
+        &self,
+        input: DescribeAggregationAuthorizationsRequest,
+    ) -> impl futures::Stream<
+        Item = Result<
+            DescribeAggregationAuthorizationsResponse,
+            RusotoError<DescribeAggregationAuthorizationsError>,
+        >,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: ConfigServiceClient, input| async move {
+                client.describe_aggregation_authorizations(input).await
+            },
+            |input: &DescribeAggregationAuthorizationsRequest,
+             output: &DescribeAggregationAuthorizationsResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(DescribeAggregationAuthorizationsRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `AggregationAuthorizations` of every page of `DescribeAggregationAuthorizations` results for `input`, as `describe_aggregation_authorizations_pages` requests them.
+    pub fn describe_aggregation_authorizations_stream(
+        &self,
+        input: DescribeAggregationAuthorizationsRequest,
+    ) -> impl futures::Stream<
+        Item = Result<
+            AggregationAuthorization,
+            RusotoError<DescribeAggregationAuthorizationsError>,
+        >,
+    > + Send {
+        rusoto_core::pagination::items(
+            self.describe_aggregation_authorizations_pages(input),
+            |page| page.aggregation_authorizations.unwrap_or_default(),
+        )
+    }
+
+    /// The pages of `DescribeComplianceByConfigRule` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    pub fn describe_compliance_by_config_rule_pages(
+        &self,
+        input: DescribeComplianceByConfigRuleRequest,
+    ) -> impl futures::Stream<
+        Item = Result<
+            DescribeComplianceByConfigRuleResponse,
+            RusotoError<DescribeComplianceByConfigRuleError>,
+        >,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: ConfigServiceClient, input| async move {
+                client.describe_compliance_by_config_rule(input).await
+            },
+            |input: &DescribeComplianceByConfigRuleRequest,
+             output: &DescribeComplianceByConfigRuleResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(DescribeComplianceByConfigRuleRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `ComplianceByConfigRules` of every page of `DescribeComplianceByConfigRule` results for `input`, as `describe_compliance_by_config_rule_pages` requests them.
+    pub fn describe_compliance_by_config_rule_stream(
+        &self,
+        input: DescribeComplianceByConfigRuleRequest,
+    ) -> impl futures::Stream<
+        Item = Result<ComplianceByConfigRule, RusotoError<DescribeComplianceByConfigRuleError>>,
+    > + Send {
+        rusoto_core::pagination::items(
+            self.describe_compliance_by_config_rule_pages(input),
+            |page| page.compliance_by_config_rules.unwrap_or_default(),
+        )
+    }
+
+    /// The pages of `DescribeComplianceByResource` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `Limit` of `input`.
+    pub fn describe_compliance_by_resource_pages(
+        &self,
+        input: DescribeComplianceByResourceRequest,
+    ) -> impl futures::Stream<
+        Item = Result<
+            DescribeComplianceByResourceResponse,
+            RusotoError<DescribeComplianceByResourceError>,
+        >,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: ConfigServiceClient, input| async move {
+                client.describe_compliance_by_resource(input).await
+            },
+            |input: &DescribeComplianceByResourceRequest,
+             output: &DescribeComplianceByResourceResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(DescribeComplianceByResourceRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `ComplianceByResources` of every page of `DescribeComplianceByResource` results for `input`, as `describe_compliance_by_resource_pages` requests them.
+    pub fn describe_compliance_by_resource_stream(
+        &self,
+        input: DescribeComplianceByResourceRequest,
+    ) -> impl futures::Stream<
+        Item = Result<ComplianceByResource, RusotoError<DescribeComplianceByResourceError>>,
+    > + Send {
+        rusoto_core::pagination::items(self.describe_compliance_by_resource_pages(input), |page| {
+            page.compliance_by_resources.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `DescribeConfigRuleEvaluationStatus` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `Limit` of `input`.
+    pub fn describe_config_rule_evaluation_status_pages(
+        &self,
+        input: DescribeConfigRuleEvaluationStatusRequest,
+    ) -> impl futures::Stream<
+        Item = Result<
+            DescribeConfigRuleEvaluationStatusResponse,
+            RusotoError<DescribeConfigRuleEvaluationStatusError>,
+        >,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: ConfigServiceClient, input| async move {
+                client.describe_config_rule_evaluation_status(input).await
+            },
+            |input: &DescribeConfigRuleEvaluationStatusRequest,
+             output: &DescribeConfigRuleEvaluationStatusResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(DescribeConfigRuleEvaluationStatusRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `ConfigRulesEvaluationStatus` of every page of `DescribeConfigRuleEvaluationStatus` results for `input`, as `describe_config_rule_evaluation_status_pages` requests them.
+    pub fn describe_config_rule_evaluation_status_stream(
+        &self,
+        input: DescribeConfigRuleEvaluationStatusRequest,
+    ) -> impl futures::Stream<
+        Item = Result<
+            ConfigRuleEvaluationStatus,
+            RusotoError<DescribeConfigRuleEvaluationStatusError>,
+        >,
+    > + Send {
+        rusoto_core::pagination::items(
+            self.describe_config_rule_evaluation_status_pages(input),
+            |page| page.config_rules_evaluation_status.unwrap_or_default(),
+        )
+    }
+
+    /// The pages of `DescribeConfigRules` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    pub fn describe_config_rules_pages(
+        &self,
+        input: DescribeConfigRulesRequest,
+    ) -> impl futures::Stream<
+        Item = Result<DescribeConfigRulesResponse, RusotoError<DescribeConfigRulesError>>,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: ConfigServiceClient, input| async move {
+                client.describe_config_rules(input).await
+            },
+            |input: &DescribeConfigRulesRequest, output: &DescribeConfigRulesResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(DescribeConfigRulesRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `ConfigRules` of every page of `DescribeConfigRules` results for `input`, as `describe_config_rules_pages` requests them.
+    pub fn describe_config_rules_stream(
+        &self,
+        input: DescribeConfigRulesRequest,
+    ) -> impl futures::Stream<Item = Result<ConfigRule, RusotoError<DescribeConfigRulesError>>> + Send
+    {
+        rusoto_core::pagination::items(self.describe_config_rules_pages(input), |page| {
+            page.config_rules.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `DescribeConfigurationAggregatorSourcesStatus` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `Limit` of `input`.
+    pub fn describe_configuration_aggregator_sources_status_pages(
+        &self,
+        input: DescribeConfigurationAggregatorSourcesStatusRequest,
+    ) -> impl futures::Stream<
+        Item = Result<
+            DescribeConfigurationAggregatorSourcesStatusResponse,
+            RusotoError<DescribeConfigurationAggregatorSourcesStatusError>,
+        >,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: ConfigServiceClient, input| async move {
+                client
+                    .describe_configuration_aggregator_sources_status(input)
+                    .await
+            },
+            |input: &DescribeConfigurationAggregatorSourcesStatusRequest,
+             output: &DescribeConfigurationAggregatorSourcesStatusResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(DescribeConfigurationAggregatorSourcesStatusRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `AggregatedSourceStatusList` of every page of `DescribeConfigurationAggregatorSourcesStatus` results for `input`, as `describe_configuration_aggregator_sources_status_pages` requests them.
+    pub fn describe_configuration_aggregator_sources_status_stream(
+        &self,
+        input: DescribeConfigurationAggregatorSourcesStatusRequest,
+    ) -> impl futures::Stream<
+        Item = Result<
+            AggregatedSourceStatus,
+            RusotoError<DescribeConfigurationAggregatorSourcesStatusError>,
+        >,
+    > + Send {
+        rusoto_core::pagination::items(
+            self.describe_configuration_aggregator_sources_status_pages(input),
+            |page| page.aggregated_source_status_list.unwrap_or_default(),
+        )
+    }
+
+    /// The pages of `DescribeConfigurationAggregators` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `Limit` of `input`.
+    pub fn describe_configuration_aggregators_pages(
+        &self,
+        input: DescribeConfigurationAggregatorsRequest,
+    ) -> impl futures::Stream<
+        Item = Result<
+            DescribeConfigurationAggregatorsResponse,
+            RusotoError<DescribeConfigurationAggregatorsError>,
+        >,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: ConfigServiceClient, input| async move {
+                client.describe_configuration_aggregators(input).await
+            },
+            |input: &DescribeConfigurationAggregatorsRequest,
+             output: &DescribeConfigurationAggregatorsResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(DescribeConfigurationAggregatorsRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `ConfigurationAggregators` of every page of `DescribeConfigurationAggregators` results for `input`, as `describe_configuration_aggregators_pages` requests them.
+    pub fn describe_configuration_aggregators_stream(
+        &self,
+        input: DescribeConfigurationAggregatorsRequest,
+    ) -> impl futures::Stream<
+        Item = Result<ConfigurationAggregator, RusotoError<DescribeConfigurationAggregatorsError>>,
+    > + Send {
+        rusoto_core::pagination::items(
+            self.describe_configuration_aggregators_pages(input),
+            |page| page.configuration_aggregators.unwrap_or_default(),
+        )
+    }
+
+    /// The pages of `DescribeConformancePackCompliance` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `Limit` of `input`.
+    pub fn describe_conformance_pack_compliance_pages(
+        &self,
+        input: DescribeConformancePackComplianceRequest,
+    ) -> impl futures::Stream<
+        Item = Result<
+            DescribeConformancePackComplianceResponse,
+            RusotoError<DescribeConformancePackComplianceError>,
+        >,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: ConfigServiceClient, input| async move {
+                client.describe_conformance_pack_compliance(input).await
+            },
+            |input: &DescribeConformancePackComplianceRequest,
+             output: &DescribeConformancePackComplianceResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(DescribeConformancePackComplianceRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `ConformancePackRuleComplianceList` of every page of `DescribeConformancePackCompliance` results for `input`, as `describe_conformance_pack_compliance_pages` requests them.
+    pub fn describe_conformance_pack_compliance_stream(
+        &self,
+        input: DescribeConformancePackComplianceRequest,
+    ) -> impl futures::Stream<
+        Item = Result<
+            ConformancePackRuleCompliance,
+            RusotoError<DescribeConformancePackComplianceError>,
+        >,
+    > + Send {
+        rusoto_core::pagination::items(
+            self.describe_conformance_pack_compliance_pages(input),
+            |page| page.conformance_pack_rule_compliance_list,
+        )
+    }
+
+    /// The pages of `DescribeConformancePackStatus` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `Limit` of `input`.
+    pub fn describe_conformance_pack_status_pages(
+        &self,
+        input: DescribeConformancePackStatusRequest,
+    ) -> impl futures::Stream<
+        Item = Result<
+            DescribeConformancePackStatusResponse,
+            RusotoError<DescribeConformancePackStatusError>,
+        >,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: ConfigServiceClient, input| async move {
+                client.describe_conformance_pack_status(input).await
+            },
+            |input: &DescribeConformancePackStatusRequest,
+             output: &DescribeConformancePackStatusResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(DescribeConformancePackStatusRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `ConformancePackStatusDetails` of every page of `DescribeConformancePackStatus` results for `input`, as `describe_conformance_pack_status_pages` requests them.
+    pub fn describe_conformance_pack_status_stream(
+        &self,
+        input: DescribeConformancePackStatusRequest,
+    ) -> impl futures::Stream<
+        Item = Result<ConformancePackStatusDetail, RusotoError<DescribeConformancePackStatusError>>,
+    > + Send {
+        rusoto_core::pagination::items(self.describe_conformance_pack_status_pages(input), |page| {
+            page.conformance_pack_status_details.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `DescribeConformancePacks` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `Limit` of `input`.
+    pub fn describe_conformance_packs_pages(
+        &self,
+        input: DescribeConformancePacksRequest,
+    ) -> impl futures::Stream<
+        Item = Result<DescribeConformancePacksResponse, RusotoError<DescribeConformancePacksError>>,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: ConfigServiceClient, input| async move {
+                client.describe_conformance_packs(input).await
+            },
+            |input: &DescribeConformancePacksRequest, output: &DescribeConformancePacksResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(DescribeConformancePacksRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `ConformancePackDetails` of every page of `DescribeConformancePacks` results for `input`, as `describe_conformance_packs_pages` requests them.
+    pub fn describe_conformance_packs_stream(
+        &self,
+        input: DescribeConformancePacksRequest,
+    ) -> impl futures::Stream<
+        Item = Result<ConformancePackDetail, RusotoError<DescribeConformancePacksError>>,
+    > + Send {
+        rusoto_core::pagination::items(self.describe_conformance_packs_pages(input), |page| {
+            page.conformance_pack_details.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `DescribeOrganizationConfigRuleStatuses` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `Limit` of `input`.
+    pub fn describe_organization_config_rule_statuses_pages(
+        &self,
+        input: DescribeOrganizationConfigRuleStatusesRequest,
+    ) -> impl futures::Stream<
+        Item = Result<
+            DescribeOrganizationConfigRuleStatusesResponse,
+            RusotoError<DescribeOrganizationConfigRuleStatusesError>,
+        >,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: ConfigServiceClient, input| async move {
+                client
+                    .describe_organization_config_rule_statuses(input)
+                    .await
+            },
+            |input: &DescribeOrganizationConfigRuleStatusesRequest,
+             output: &DescribeOrganizationConfigRuleStatusesResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(DescribeOrganizationConfigRuleStatusesRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `OrganizationConfigRuleStatuses` of every page of `DescribeOrganizationConfigRuleStatuses` results for `input`, as `describe_organization_config_rule_statuses_pages` requests them.
+    pub fn describe_organization_config_rule_statuses_stream(
+        &self,
+        input: DescribeOrganizationConfigRuleStatusesRequest,
+    ) -> impl futures::Stream<
+        Item = Result<
+            OrganizationConfigRuleStatus,
+            RusotoError<DescribeOrganizationConfigRuleStatusesError>,
+        >,
+    > + Send {
+        rusoto_core::pagination::items(
+            self.describe_organization_config_rule_statuses_pages(input),
+            |page| page.organization_config_rule_statuses.unwrap_or_default(),
+        )
+    }
+
+    /// The pages of `DescribeOrganizationConfigRules` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `Limit` of `input`.
+    pub fn describe_organization_config_rules_pages(
+        &self,
+        input: DescribeOrganizationConfigRulesRequest,
+    ) -> impl futures::Stream<
+        Item = Result<
+            DescribeOrganizationConfigRulesResponse,
+            RusotoError<DescribeOrganizationConfigRulesError>,
+        >,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: ConfigServiceClient, input| async move {
+                client.describe_organization_config_rules(input).await
+            },
+            |input: &DescribeOrganizationConfigRulesRequest,
+             output: &DescribeOrganizationConfigRulesResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(DescribeOrganizationConfigRulesRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `OrganizationConfigRules` of every page of `DescribeOrganizationConfigRules` results for `input`, as `describe_organization_config_rules_pages` requests them.
+    pub fn describe_organization_config_rules_stream(
+        &self,
+        input: DescribeOrganizationConfigRulesRequest,
+    ) -> impl futures::Stream<
+        Item = Result<OrganizationConfigRule, RusotoError<DescribeOrganizationConfigRulesError>>,
+    > + Send {
+        rusoto_core::pagination::items(
+            self.describe_organization_config_rules_pages(input),
+            |page| page.organization_config_rules.unwrap_or_default(),
+        )
+    }
+
+    /// The pages of `DescribeOrganizationConformancePackStatuses` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `Limit` of `input`.
+    pub fn describe_organization_conformance_pack_statuses_pages(
+        &self,
+        input: DescribeOrganizationConformancePackStatusesRequest,
+    ) -> impl futures::Stream<
+        Item = Result<
+            DescribeOrganizationConformancePackStatusesResponse,
+            RusotoError<DescribeOrganizationConformancePackStatusesError>,
+        >,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: ConfigServiceClient, input| async move {
+                client
+                    .describe_organization_conformance_pack_statuses(input)
+                    .await
+            },
+            |input: &DescribeOrganizationConformancePackStatusesRequest,
+             output: &DescribeOrganizationConformancePackStatusesResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(DescribeOrganizationConformancePackStatusesRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `OrganizationConformancePackStatuses` of every page of `DescribeOrganizationConformancePackStatuses` results for `input`, as `describe_organization_conformance_pack_statuses_pages` requests them.
+    pub fn describe_organization_conformance_pack_statuses_stream(
+        &self,
+        input: DescribeOrganizationConformancePackStatusesRequest,
+    ) -> impl futures::Stream<
+        Item = Result<
+            OrganizationConformancePackStatus,
+            RusotoError<DescribeOrganizationConformancePackStatusesError>,
+        >,
+    > + Send {
+        rusoto_core::pagination::items(
+            self.describe_organization_conformance_pack_statuses_pages(input),
+            |page| {
+                page.organization_conformance_pack_statuses
+                    .unwrap_or_default()
+            },
+        )
+    }
+
+    /// The pages of `DescribeOrganizationConformancePacks` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `Limit` of `input`.
+    pub fn describe_organization_conformance_packs_pages(
+        &self,
+        input: DescribeOrganizationConformancePacksRequest,
+    ) -> impl futures::Stream<
+        Item = Result<
+            DescribeOrganizationConformancePacksResponse,
+            RusotoError<DescribeOrganizationConformancePacksError>,
+        >,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: ConfigServiceClient, input| async move {
+                client.describe_organization_conformance_packs(input).await
+            },
+            |input: &DescribeOrganizationConformancePacksRequest,
+             output: &DescribeOrganizationConformancePacksResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(DescribeOrganizationConformancePacksRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `OrganizationConformancePacks` of every page of `DescribeOrganizationConformancePacks` results for `input`, as `describe_organization_conformance_packs_pages` requests them.
+    pub fn describe_organization_conformance_packs_stream(
+        &self,
+        input: DescribeOrganizationConformancePacksRequest,
+    ) -> impl futures::Stream<
+        Item = Result<
+            OrganizationConformancePack,
+            RusotoError<DescribeOrganizationConformancePacksError>,
+        >,
+    > + Send {
+        rusoto_core::pagination::items(
+            self.describe_organization_conformance_packs_pages(input),
+            |page| page.organization_conformance_packs.unwrap_or_default(),
+        )
+    }
+
+    /// The pages of `DescribePendingAggregationRequests` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `Limit` of `input`.
+    pub fn describe_pending_aggregation_requests_pages(
+        &self,
+        input: DescribePendingAggregationRequestsRequest,
+    ) -> impl futures::Stream<
+        Item = Result<
+            DescribePendingAggregationRequestsResponse,
+            RusotoError<DescribePendingAggregationRequestsError>,
+        >,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: ConfigServiceClient, input| async move {
+                client.describe_pending_aggregation_requests(input).await
+            },
+            |input: &DescribePendingAggregationRequestsRequest,
+             output: &DescribePendingAggregationRequestsResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(DescribePendingAggregationRequestsRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `PendingAggregationRequests` of every page of `DescribePendingAggregationRequests` results for `input`, as `describe_pending_aggregation_requests_pages` requests them.
+    pub fn describe_pending_aggregation_requests_stream(
+        &self,
+        input: DescribePendingAggregationRequestsRequest,
+    ) -> impl futures::Stream<
+        Item = Result<
+            PendingAggregationRequest,
+            RusotoError<DescribePendingAggregationRequestsError>,
+        >,
+    > + Send {
+        rusoto_core::pagination::items(
+            self.describe_pending_aggregation_requests_pages(input),
+            |page| page.pending_aggregation_requests.unwrap_or_default(),
+        )
+    }
+
+    /// The pages of `DescribeRemediationExecutionStatus` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `Limit` of `input`.
+    pub fn describe_remediation_execution_status_pages(
+        &self,
+        input: DescribeRemediationExecutionStatusRequest,
+    ) -> impl futures::Stream<
+        Item = Result<
+            DescribeRemediationExecutionStatusResponse,
+            RusotoError<DescribeRemediationExecutionStatusError>,
+        >,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: ConfigServiceClient, input| async move {
+                client.describe_remediation_execution_status(input).await
+            },
+            |input: &DescribeRemediationExecutionStatusRequest,
+             output: &DescribeRemediationExecutionStatusResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(DescribeRemediationExecutionStatusRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `RemediationExecutionStatuses` of every page of `DescribeRemediationExecutionStatus` results for `input`, as `describe_remediation_execution_status_pages` requests them.
+    pub fn describe_remediation_execution_status_stream(
+        &self,
+        input: DescribeRemediationExecutionStatusRequest,
+    ) -> impl futures::Stream<
+        Item = Result<
+            RemediationExecutionStatus,
+            RusotoError<DescribeRemediationExecutionStatusError>,
+        >,
+    > + Send {
+        rusoto_core::pagination::items(
+            self.describe_remediation_execution_status_pages(input),
+            |page| page.remediation_execution_statuses.unwrap_or_default(),
+        )
+    }
+
+    /// The pages of `DescribeRetentionConfigurations` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    pub fn describe_retention_configurations_pages(
+        &self,
+        input: DescribeRetentionConfigurationsRequest,
+    ) -> impl futures::Stream<
+        Item = Result<
+            DescribeRetentionConfigurationsResponse,
+            RusotoError<DescribeRetentionConfigurationsError>,
+        >,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: ConfigServiceClient, input| async move {
+                client.describe_retention_configurations(input).await
+            },
+            |input: &DescribeRetentionConfigurationsRequest,
+             output: &DescribeRetentionConfigurationsResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(DescribeRetentionConfigurationsRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `RetentionConfigurations` of every page of `DescribeRetentionConfigurations` results for `input`, as `describe_retention_configurations_pages` requests them.
+    pub fn describe_retention_configurations_stream(
+        &self,
+        input: DescribeRetentionConfigurationsRequest,
+    ) -> impl futures::Stream<
+        Item = Result<RetentionConfiguration, RusotoError<DescribeRetentionConfigurationsError>>,
+    > + Send {
+        rusoto_core::pagination::items(
+            self.describe_retention_configurations_pages(input),
+            |page| page.retention_configurations.unwrap_or_default(),
+        )
+    }
+
+    /// The pages of `GetAggregateComplianceDetailsByConfigRule` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `Limit` of `input`.
+    pub fn get_aggregate_compliance_details_by_config_rule_pages(
+        &self,
+        input: GetAggregateComplianceDetailsByConfigRuleRequest,
+    ) -> impl futures::Stream<
+        Item = Result<
+            GetAggregateComplianceDetailsByConfigRuleResponse,
+            RusotoError<GetAggregateComplianceDetailsByConfigRuleError>,
+        >,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: ConfigServiceClient, input| async move {
+                client
+                    .get_aggregate_compliance_details_by_config_rule(input)
+                    .await
+            },
+            |input: &GetAggregateComplianceDetailsByConfigRuleRequest,
+             output: &GetAggregateComplianceDetailsByConfigRuleResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(GetAggregateComplianceDetailsByConfigRuleRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `AggregateEvaluationResults` of every page of `GetAggregateComplianceDetailsByConfigRule` results for `input`, as `get_aggregate_compliance_details_by_config_rule_pages` requests them.
+    pub fn get_aggregate_compliance_details_by_config_rule_stream(
+        &self,
+        input: GetAggregateComplianceDetailsByConfigRuleRequest,
+    ) -> impl futures::Stream<
+        Item = Result<
+            AggregateEvaluationResult,
+            RusotoError<GetAggregateComplianceDetailsByConfigRuleError>,
+        >,
+    > + Send {
+        rusoto_core::pagination::items(
+            self.get_aggregate_compliance_details_by_config_rule_pages(input),
+            |page| page.aggregate_evaluation_results.unwrap_or_default(),
+        )
+    }
+
+    /// The pages of `GetComplianceDetailsByConfigRule` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `Limit` of `input`.
+    pub fn get_compliance_details_by_config_rule_pages(
+        &self,
+        input: GetComplianceDetailsByConfigRuleRequest,
+    ) -> impl futures::Stream<
+        Item = Result<
+            GetComplianceDetailsByConfigRuleResponse,
+            RusotoError<GetComplianceDetailsByConfigRuleError>,
+        >,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: ConfigServiceClient, input| async move {
+                client.get_compliance_details_by_config_rule(input).await
+            },
+            |input: &GetComplianceDetailsByConfigRuleRequest,
+             output: &GetComplianceDetailsByConfigRuleResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(GetComplianceDetailsByConfigRuleRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `EvaluationResults` of every page of `GetComplianceDetailsByConfigRule` results for `input`, as `get_compliance_details_by_config_rule_pages` requests them.
+    pub fn get_compliance_details_by_config_rule_stream(
+        &self,
+        input: GetComplianceDetailsByConfigRuleRequest,
+    ) -> impl futures::Stream<
+        Item = Result<EvaluationResult, RusotoError<GetComplianceDetailsByConfigRuleError>>,
+    > + Send {
+        rusoto_core::pagination::items(
+            self.get_compliance_details_by_config_rule_pages(input),
+            |page| page.evaluation_results.unwrap_or_default(),
+        )
+    }
+
+    /// The pages of `GetComplianceDetailsByResource` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    pub fn get_compliance_details_by_resource_pages(
+        &self,
+        input: GetComplianceDetailsByResourceRequest,
+    ) -> impl futures::Stream<
+        Item = Result<
+            GetComplianceDetailsByResourceResponse,
+            RusotoError<GetComplianceDetailsByResourceError>,
+        >,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: ConfigServiceClient, input| async move {
+                client.get_compliance_details_by_resource(input).await
+            },
+            |input: &GetComplianceDetailsByResourceRequest,
+             output: &GetComplianceDetailsByResourceResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(GetComplianceDetailsByResourceRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `EvaluationResults` of every page of `GetComplianceDetailsByResource` results for `input`, as `get_compliance_details_by_resource_pages` requests them.
+    pub fn get_compliance_details_by_resource_stream(
+        &self,
+        input: GetComplianceDetailsByResourceRequest,
+    ) -> impl futures::Stream<
+        Item = Result<EvaluationResult, RusotoError<GetComplianceDetailsByResourceError>>,
+    > + Send {
+        rusoto_core::pagination::items(
+            self.get_compliance_details_by_resource_pages(input),
+            |page| page.evaluation_results.unwrap_or_default(),
+        )
+    }
+
+    /// The pages of `GetConformancePackComplianceSummary` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `Limit` of `input`.
+    pub fn get_conformance_pack_compliance_summary_pages(
+        &self,
+        input: GetConformancePackComplianceSummaryRequest,
+    ) -> impl futures::Stream<
+        Item = Result<
+            GetConformancePackComplianceSummaryResponse,
+            RusotoError<GetConformancePackComplianceSummaryError>,
+        >,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: ConfigServiceClient, input| async move {
+                client.get_conformance_pack_compliance_summary(input).await
+            },
+            |input: &GetConformancePackComplianceSummaryRequest,
+             output: &GetConformancePackComplianceSummaryResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(GetConformancePackComplianceSummaryRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `ConformancePackComplianceSummaryList` of every page of `GetConformancePackComplianceSummary` results for `input`, as `get_conformance_pack_compliance_summary_pages` requests them.
+    pub fn get_conformance_pack_compliance_summary_stream(
+        &self,
+        input: GetConformancePackComplianceSummaryRequest,
+    ) -> impl futures::Stream<
+        Item = Result<
+            ConformancePackComplianceSummary,
+            RusotoError<GetConformancePackComplianceSummaryError>,
+        >,
+    > + Send {
+        rusoto_core::pagination::items(
+            self.get_conformance_pack_compliance_summary_pages(input),
+            |page| {
+                page.conformance_pack_compliance_summary_list
+                    .unwrap_or_default()
+            },
+        )
+    }
+
+    /// The pages of `GetOrganizationConfigRuleDetailedStatus` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `Limit` of `input`.
+    pub fn get_organization_config_rule_detailed_status_pages(
+        &self,
+        input: GetOrganizationConfigRuleDetailedStatusRequest,
+    ) -> impl futures::Stream<
+        Item = Result<
+            GetOrganizationConfigRuleDetailedStatusResponse,
+            RusotoError<GetOrganizationConfigRuleDetailedStatusError>,
+        >,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: ConfigServiceClient, input| async move {
+                client
+                    .get_organization_config_rule_detailed_status(input)
+                    .await
+            },
+            |input: &GetOrganizationConfigRuleDetailedStatusRequest,
+             output: &GetOrganizationConfigRuleDetailedStatusResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(GetOrganizationConfigRuleDetailedStatusRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `OrganizationConfigRuleDetailedStatus` of every page of `GetOrganizationConfigRuleDetailedStatus` results for `input`, as `get_organization_config_rule_detailed_status_pages` requests them.
+    pub fn get_organization_config_rule_detailed_status_stream(
+        &self,
+        input: GetOrganizationConfigRuleDetailedStatusRequest,
+    ) -> impl futures::Stream<
+        Item = Result<
+            MemberAccountStatus,
+            RusotoError<GetOrganizationConfigRuleDetailedStatusError>,
+        >,
+    > + Send {
+        rusoto_core::pagination::items(
+            self.get_organization_config_rule_detailed_status_pages(input),
+            |page| {
+                page.organization_config_rule_detailed_status
+                    .unwrap_or_default()
+            },
+        )
+    }
+
+    /// The pages of `GetOrganizationConformancePackDetailedStatus` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `Limit` of `input`.
+    pub fn get_organization_conformance_pack_detailed_status_pages(
+        &self,
+        input: GetOrganizationConformancePackDetailedStatusRequest,
+    ) -> impl futures::Stream<
+        Item = Result<
+            GetOrganizationConformancePackDetailedStatusResponse,
+            RusotoError<GetOrganizationConformancePackDetailedStatusError>,
+        >,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: ConfigServiceClient, input| async move {
+                client
+                    .get_organization_conformance_pack_detailed_status(input)
+                    .await
+            },
+            |input: &GetOrganizationConformancePackDetailedStatusRequest,
+             output: &GetOrganizationConformancePackDetailedStatusResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(GetOrganizationConformancePackDetailedStatusRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `OrganizationConformancePackDetailedStatuses` of every page of `GetOrganizationConformancePackDetailedStatus` results for `input`, as `get_organization_conformance_pack_detailed_status_pages` requests them.
+    pub fn get_organization_conformance_pack_detailed_status_stream(
+        &self,
+        input: GetOrganizationConformancePackDetailedStatusRequest,
+    ) -> impl futures::Stream<
+        Item = Result<
+            OrganizationConformancePackDetailedStatus,
+            RusotoError<GetOrganizationConformancePackDetailedStatusError>,
+        >,
+    > + Send {
+        rusoto_core::pagination::items(
+            self.get_organization_conformance_pack_detailed_status_pages(input),
+            |page| {
+                page.organization_conformance_pack_detailed_statuses
+                    .unwrap_or_default()
+            },
+        )
+    }
+
+    /// The pages of `GetResourceConfigHistory` results for `input`, requesting each next page with the `nextToken` of the last one as its `nextToken`.
+    /// The page size is the `limit` of `input`.
+    pub fn get_resource_config_history_pages(
+        &self,
+        input: GetResourceConfigHistoryRequest,
+    ) -> impl futures::Stream<
+        Item = Result<GetResourceConfigHistoryResponse, RusotoError<GetResourceConfigHistoryError>>,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: ConfigServiceClient, input| async move {
+                client.get_resource_config_history(input).await
+            },
+            |input: &GetResourceConfigHistoryRequest, output: &GetResourceConfigHistoryResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(GetResourceConfigHistoryRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `configurationItems` of every page of `GetResourceConfigHistory` results for `input`, as `get_resource_config_history_pages` requests them.
+    pub fn get_resource_config_history_stream(
+        &self,
+        input: GetResourceConfigHistoryRequest,
+    ) -> impl futures::Stream<
+        Item = Result<ConfigurationItem, RusotoError<GetResourceConfigHistoryError>>,
+    > + Send {
+        rusoto_core::pagination::items(self.get_resource_config_history_pages(input), |page| {
+            page.configuration_items.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `ListAggregateDiscoveredResources` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `Limit` of `input`.
+    pub fn list_aggregate_discovered_resources_pages(
+        &self,
+        input: ListAggregateDiscoveredResourcesRequest,
+    ) -> impl futures::Stream<
+        Item = Result<
+            ListAggregateDiscoveredResourcesResponse,
+            RusotoError<ListAggregateDiscoveredResourcesError>,
+        >,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: ConfigServiceClient, input| async move {
+                client.list_aggregate_discovered_resources(input).await
+            },
+            |input: &ListAggregateDiscoveredResourcesRequest,
+             output: &ListAggregateDiscoveredResourcesResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListAggregateDiscoveredResourcesRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `ResourceIdentifiers` of every page of `ListAggregateDiscoveredResources` results for `input`, as `list_aggregate_discovered_resources_pages` requests them.
+    pub fn list_aggregate_discovered_resources_stream(
+        &self,
+        input: ListAggregateDiscoveredResourcesRequest,
+    ) -> impl futures::Stream<
+        Item = Result<
+            AggregateResourceIdentifier,
+            RusotoError<ListAggregateDiscoveredResourcesError>,
+        >,
+    > + Send {
+        rusoto_core::pagination::items(
+            self.list_aggregate_discovered_resources_pages(input),
+            |page| page.resource_identifiers.unwrap_or_default(),
+        )
+    }
+
+    /// The pages of `ListDiscoveredResources` results for `input`, requesting each next page with the `nextToken` of the last one as its `nextToken`.
+    /// The page size is the `limit` of `input`.
+    pub fn list_discovered_resources_pages(
+        &self,
+        input: ListDiscoveredResourcesRequest,
+    ) -> impl futures::Stream<
+        Item = Result<ListDiscoveredResourcesResponse, RusotoError<ListDiscoveredResourcesError>>,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: ConfigServiceClient, input| async move {
+                client.list_discovered_resources(input).await
+            },
+            |input: &ListDiscoveredResourcesRequest, output: &ListDiscoveredResourcesResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListDiscoveredResourcesRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `resourceIdentifiers` of every page of `ListDiscoveredResources` results for `input`, as `list_discovered_resources_pages` requests them.
+    pub fn list_discovered_resources_stream(
+        &self,
+        input: ListDiscoveredResourcesRequest,
+    ) -> impl futures::Stream<
+        Item = Result<ResourceIdentifier, RusotoError<ListDiscoveredResourcesError>>,
+    > + Send {
+        rusoto_core::pagination::items(self.list_discovered_resources_pages(input), |page| {
+            page.resource_identifiers.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `ListTagsForResource` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `Limit` of `input`.
+    pub fn list_tags_for_resource_pages(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> impl futures::Stream<
+        Item = Result<ListTagsForResourceResponse, RusotoError<ListTagsForResourceError>>,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: ConfigServiceClient, input| async move {
+                client.list_tags_for_resource(input).await
+            },
+            |input: &ListTagsForResourceRequest, output: &ListTagsForResourceResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListTagsForResourceRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `Tags` of every page of `ListTagsForResource` results for `input`, as `list_tags_for_resource_pages` requests them.
+    pub fn list_tags_for_resource_stream(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> impl futures::Stream<Item = Result<Tag, RusotoError<ListTagsForResourceError>>> + Send
+    {
+        rusoto_core::pagination::items(self.list_tags_for_resource_pages(input), |page| {
+            page.tags.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `SelectResourceConfig` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `Limit` of `input`.
+    pub fn select_resource_config_pages(
+        &self,
+        input: SelectResourceConfigRequest,
+    ) -> impl futures::Stream<
+        Item = Result<SelectResourceConfigResponse, RusotoError<SelectResourceConfigError>>,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: ConfigServiceClient, input| async move {
+                client.select_resource_config(input).await
+            },
+            |input: &SelectResourceConfigRequest, output: &SelectResourceConfigResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(SelectResourceConfigRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `Results` of every page of `SelectResourceConfig` results for `input`, as `select_resource_config_pages` requests them.
+    pub fn select_resource_config_stream(
+        &self,
+        input: SelectResourceConfigRequest,
+    ) -> impl futures::Stream<Item = Result<String, RusotoError<SelectResourceConfigError>>> + Send
+    {
+        rusoto_core::pagination::items(self.select_resource_config_pages(input), |page| {
+            page.results.unwrap_or_default()
+        })
+    }
+}
+
+#[async_trait]
+impl ConfigService for ConfigServiceClient {
+    /// <p><p>Returns the current configuration items for resources that are present in your AWS Config aggregator. The operation also returns a list of resources that are not processed in the current request. If there are no unprocessed resources, the operation returns an empty <code>unprocessedResourceIdentifiers</code> list. </p> <note> <ul> <li> <p>The API does not return results for deleted resources.</p> </li> <li> <p> The API does not return tags and relationships.</p> </li> </ul> </note></p>
+    async fn batch_get_aggregate_resource_config(
+        &self,
+        input: BatchGetAggregateResourceConfigRequest,
+    ) -> Result<
+        BatchGetAggregateResourceConfigResponse,
+        RusotoError<BatchGetAggregateResourceConfigError>,
+    > {
+        self.batch_get_aggregate_resource_config_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Returns the current configuration for one or more requested resources. The operation also returns a list of resources that are not processed in the current request. If there are no unprocessed resources, the operation returns an empty unprocessedResourceKeys list. </p> <note> <ul> <li> <p>The API does not return results for deleted resources.</p> </li> <li> <p> The API does not return any tags for the requested resources. This information is filtered out of the supplementaryConfiguration section of the API response.</p> </li> </ul> </note></p>
+    async fn batch_get_resource_config(
+        &self,
+        input: BatchGetResourceConfigRequest,
+    ) -> Result<BatchGetResourceConfigResponse, RusotoError<BatchGetResourceConfigError>> {
+        self.batch_get_resource_config_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes the authorization granted to the specified configuration aggregator account in a specified region.</p>
+    async fn delete_aggregation_authorization(
+        &self,
+        input: DeleteAggregationAuthorizationRequest,
+    ) -> Result<(), RusotoError<DeleteAggregationAuthorizationError>> {
+        self.delete_aggregation_authorization_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes the specified AWS Config rule and all of its evaluation results.</p> <p>AWS Config sets the state of a rule to <code>DELETING</code> until the deletion is complete. You cannot update a rule while it is in this state. If you make a <code>PutConfigRule</code> or <code>DeleteConfigRule</code> request for the rule, you will receive a <code>ResourceInUseException</code>.</p> <p>You can check the state of a rule by using the <code>DescribeConfigRules</code> request.</p>
+    async fn delete_config_rule(
+        &self,
+        input: DeleteConfigRuleRequest,
+    ) -> Result<(), RusotoError<DeleteConfigRuleError>> {
+        self.delete_config_rule_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes the specified configuration aggregator and the aggregated data associated with the aggregator.</p>
+    async fn delete_configuration_aggregator(
+        &self,
+        input: DeleteConfigurationAggregatorRequest,
+    ) -> Result<(), RusotoError<DeleteConfigurationAggregatorError>> {
+        self.delete_configuration_aggregator_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes the configuration recorder.</p> <p>After the configuration recorder is deleted, AWS Config will not record resource configuration changes until you create a new configuration recorder.</p> <p>This action does not delete the configuration information that was previously recorded. You will be able to access the previously recorded information by using the <code>GetResourceConfigHistory</code> action, but you will not be able to access this information in the AWS Config console until you create a new configuration recorder.</p>
+    async fn delete_configuration_recorder(
+        &self,
+        input: DeleteConfigurationRecorderRequest,
+    ) -> Result<(), RusotoError<DeleteConfigurationRecorderError>> {
+        self.delete_configuration_recorder_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes the specified conformance pack and all the AWS Config rules, remediation actions, and all evaluation results within that conformance pack.</p> <p>AWS Config sets the conformance pack to <code>DELETE_IN_PROGRESS</code> until the deletion is complete. You cannot update a conformance pack while it is in this state.</p>
+    async fn delete_conformance_pack(
+        &self,
+        input: DeleteConformancePackRequest,
+    ) -> Result<(), RusotoError<DeleteConformancePackError>> {
+        self.delete_conformance_pack_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes the delivery channel.</p> <p>Before you can delete the delivery channel, you must stop the configuration recorder by using the <a>StopConfigurationRecorder</a> action.</p>
+    async fn delete_delivery_channel(
+        &self,
+        input: DeleteDeliveryChannelRequest,
+    ) -> Result<(), RusotoError<DeleteDeliveryChannelError>> {
+        self.delete_delivery_channel_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes the evaluation results for the specified AWS Config rule. You can specify one AWS Config rule per request. After you delete the evaluation results, you can call the <a>StartConfigRulesEvaluation</a> API to start evaluating your AWS resources against the rule.</p>
+    async fn delete_evaluation_results(
+        &self,
+        input: DeleteEvaluationResultsRequest,
+    ) -> Result<DeleteEvaluationResultsResponse, RusotoError<DeleteEvaluationResultsError>> {
+        self.delete_evaluation_results_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes the specified organization config rule and all of its evaluation results from all member accounts in that organization. Only a master account can delete an organization config rule.</p> <p>AWS Config sets the state of a rule to DELETE_IN_PROGRESS until the deletion is complete. You cannot update a rule while it is in this state.</p>
+    async fn delete_organization_config_rule(
+        &self,
+        input: DeleteOrganizationConfigRuleRequest,
+    ) -> Result<(), RusotoError<DeleteOrganizationConfigRuleError>> {
+        self.delete_organization_config_rule_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes the specified organization conformance pack and all of the config rules and remediation actions from all member accounts in that organization. Only a master account can delete an organization conformance pack.</p> <p>AWS Config sets the state of a conformance pack to DELETE_IN_PROGRESS until the deletion is complete. You cannot update a conformance pack while it is in this state. </p>
+    async fn delete_organization_conformance_pack(
+        &self,
+        input: DeleteOrganizationConformancePackRequest,
+    ) -> Result<(), RusotoError<DeleteOrganizationConformancePackError>> {
+        self.delete_organization_conformance_pack_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes pending authorization requests for a specified aggregator account in a specified region.</p>
+    async fn delete_pending_aggregation_request(
+        &self,
+        input: DeletePendingAggregationRequestRequest,
+    ) -> Result<(), RusotoError<DeletePendingAggregationRequestError>> {
+        self.delete_pending_aggregation_request_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes the remediation configuration.</p>
+    async fn delete_remediation_configuration(
+        &self,
+        input: DeleteRemediationConfigurationRequest,
+    ) -> Result<
+        DeleteRemediationConfigurationResponse,
+        RusotoError<DeleteRemediationConfigurationError>,
+    > {
+        self.delete_remediation_configuration_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes one or more remediation exceptions mentioned in the resource keys.</p>
+    async fn delete_remediation_exceptions(
+        &self,
+        input: DeleteRemediationExceptionsRequest,
+    ) -> Result<DeleteRemediationExceptionsResponse, RusotoError<DeleteRemediationExceptionsError>>
+    {
+        self.delete_remediation_exceptions_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Records the configuration state for a custom resource that has been deleted. This API records a new ConfigurationItem with a ResourceDeleted status. You can retrieve the ConfigurationItems recorded for this resource in your AWS Config History. </p>
+    async fn delete_resource_config(
+        &self,
+        input: DeleteResourceConfigRequest,
+    ) -> Result<(), RusotoError<DeleteResourceConfigError>> {
+        self.delete_resource_config_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes the retention configuration.</p>
+    async fn delete_retention_configuration(
+        &self,
+        input: DeleteRetentionConfigurationRequest,
+    ) -> Result<(), RusotoError<DeleteRetentionConfigurationError>> {
+        self.delete_retention_configuration_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Schedules delivery of a configuration snapshot to the Amazon S3 bucket in the specified delivery channel. After the delivery has started, AWS Config sends the following notifications using an Amazon SNS topic that you have specified.</p> <ul> <li> <p>Notification of the start of the delivery.</p> </li> <li> <p>Notification of the completion of the delivery, if the delivery was successfully completed.</p> </li> <li> <p>Notification of delivery failure, if the delivery failed.</p> </li> </ul></p>
+    async fn deliver_config_snapshot(
+        &self,
+        input: DeliverConfigSnapshotRequest,
+    ) -> Result<DeliverConfigSnapshotResponse, RusotoError<DeliverConfigSnapshotError>> {
+        self.deliver_config_snapshot_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Returns a list of compliant and noncompliant rules with the number of resources for compliant and noncompliant rules. </p> <note> <p>The results can return an empty result page, but if you have a <code>nextToken</code>, the results are displayed on the next page.</p> </note></p>
+    async fn describe_aggregate_compliance_by_config_rules(
+        &self,
+        input: DescribeAggregateComplianceByConfigRulesRequest,
+    ) -> Result<
+        DescribeAggregateComplianceByConfigRulesResponse,
+        RusotoError<DescribeAggregateComplianceByConfigRulesError>,
+    > {
+        self.describe_aggregate_compliance_by_config_rules_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns a list of authorizations granted to various aggregator accounts and regions.</p>
+    async fn describe_aggregation_authorizations(
+        &self,
+        input: DescribeAggregationAuthorizationsRequest,
+    ) -> Result<
+        DescribeAggregationAuthorizationsResponse,
+        RusotoError<DescribeAggregationAuthorizationsError>,
+    > {
+        self.describe_aggregation_authorizations_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Indicates whether the specified AWS Config rules are compliant. If a rule is noncompliant, this action returns the number of AWS resources that do not comply with the rule.</p> <p>A rule is compliant if all of the evaluated resources comply with it. It is noncompliant if any of these resources do not comply.</p> <p>If AWS Config has no current evaluation results for the rule, it returns <code>INSUFFICIENT<em>DATA</code>. This result might indicate one of the following conditions:</p> <ul> <li> <p>AWS Config has never invoked an evaluation for the rule. To check whether it has, use the <code>DescribeConfigRuleEvaluationStatus</code> action to get the <code>LastSuccessfulInvocationTime</code> and <code>LastFailedInvocationTime</code>.</p> </li> <li> <p>The rule&#39;s AWS Lambda function is failing to send evaluation results to AWS Config. Verify that the role you assigned to your configuration recorder includes the <code>config:PutEvaluations</code> permission. If the rule is a custom rule, verify that the AWS Lambda execution role includes the <code>config:PutEvaluations</code> permission.</p> </li> <li> <p>The rule&#39;s AWS Lambda function has returned <code>NOT</em>APPLICABLE</code> for all evaluation results. This can occur if the resources were deleted or removed from the rule&#39;s scope.</p> </li> </ul></p>
+    async fn describe_compliance_by_config_rule(
+        &self,
+        input: DescribeComplianceByConfigRuleRequest,
+    ) -> Result<
+        DescribeComplianceByConfigRuleResponse,
+        RusotoError<DescribeComplianceByConfigRuleError>,
+    > {
+        self.describe_compliance_by_config_rule_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Indicates whether the specified AWS resources are compliant. If a resource is noncompliant, this action returns the number of AWS Config rules that the resource does not comply with.</p> <p>A resource is compliant if it complies with all the AWS Config rules that evaluate it. It is noncompliant if it does not comply with one or more of these rules.</p> <p>If AWS Config has no current evaluation results for the resource, it returns <code>INSUFFICIENT<em>DATA</code>. This result might indicate one of the following conditions about the rules that evaluate the resource:</p> <ul> <li> <p>AWS Config has never invoked an evaluation for the rule. To check whether it has, use the <code>DescribeConfigRuleEvaluationStatus</code> action to get the <code>LastSuccessfulInvocationTime</code> and <code>LastFailedInvocationTime</code>.</p> </li> <li> <p>The rule&#39;s AWS Lambda function is failing to send evaluation results to AWS Config. Verify that the role that you assigned to your configuration recorder includes the <code>config:PutEvaluations</code> permission. If the rule is a custom rule, verify that the AWS Lambda execution role includes the <code>config:PutEvaluations</code> permission.</p> </li> <li> <p>The rule&#39;s AWS Lambda function has returned <code>NOT</em>APPLICABLE</code> for all evaluation results. This can occur if the resources were deleted or removed from the rule&#39;s scope.</p> </li> </ul></p>
+    async fn describe_compliance_by_resource(
+        &self,
+        input: DescribeComplianceByResourceRequest,
+    ) -> Result<DescribeComplianceByResourceResponse, RusotoError<DescribeComplianceByResourceError>>
+    {
+        self.describe_compliance_by_resource_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns status information for each of your AWS managed Config rules. The status includes information such as the last time AWS Config invoked the rule, the last time AWS Config failed to invoke the rule, and the related error for the last failure.</p>
+    async fn describe_config_rule_evaluation_status(
+        &self,
+        input: DescribeConfigRuleEvaluationStatusRequest,
+    ) -> Result<
+        DescribeConfigRuleEvaluationStatusResponse,
+        RusotoError<DescribeConfigRuleEvaluationStatusError>,
+    > {
+        self.describe_config_rule_evaluation_status_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns details about your AWS Config rules.</p>
+    async fn describe_config_rules(
+        &self,
+        input: DescribeConfigRulesRequest,
+    ) -> Result<DescribeConfigRulesResponse, RusotoError<DescribeConfigRulesError>> {
+        self.describe_config_rules_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns status information for sources within an aggregator. The status includes information about the last time AWS Config verified authorization between the source account and an aggregator account. In case of a failure, the status contains the related error code or message. </p>
+    async fn describe_configuration_aggregator_sources_status(
+        &self,
+        input: DescribeConfigurationAggregatorSourcesStatusRequest,
+    ) -> Result<
+        DescribeConfigurationAggregatorSourcesStatusResponse,
+        RusotoError<DescribeConfigurationAggregatorSourcesStatusError>,
+    > {
+        self.describe_configuration_aggregator_sources_status_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns the details of one or more configuration aggregators. If the configuration aggregator is not specified, this action returns the details for all the configuration aggregators associated with the account. </p>
+    async fn describe_configuration_aggregators(
+        &self,
+        input: DescribeConfigurationAggregatorsRequest,
+    ) -> Result<
+        DescribeConfigurationAggregatorsResponse,
+        RusotoError<DescribeConfigurationAggregatorsError>,
+    > {
+        self.describe_configuration_aggregators_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Returns the current status of the specified configuration recorder. If a configuration recorder is not specified, this action returns the status of all configuration recorders associated with the account.</p> <note> <p>Currently, you can specify only one configuration recorder per region in your account.</p> </note></p>
+    async fn describe_configuration_recorder_status(
+        &self,
+        input: DescribeConfigurationRecorderStatusRequest,
+    ) -> Result<
+        DescribeConfigurationRecorderStatusResponse,
+        RusotoError<DescribeConfigurationRecorderStatusError>,
+    > {
+        self.describe_configuration_recorder_status_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Returns the details for the specified configuration recorders. If the configuration recorder is not specified, this action returns the details for all configuration recorders associated with the account.</p> <note> <p>Currently, you can specify only one configuration recorder per region in your account.</p> </note></p>
+    async fn describe_configuration_recorders(
+        &self,
+        input: DescribeConfigurationRecordersRequest,
+    ) -> Result<
+        DescribeConfigurationRecordersResponse,
+        RusotoError<DescribeConfigurationRecordersError>,
+    > {
+        self.describe_configuration_recorders_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Returns compliance details for each rule in that conformance pack.</p> <note> <p>You must provide exact rule names.</p> </note></p>
+    async fn describe_conformance_pack_compliance(
+        &self,
+        input: DescribeConformancePackComplianceRequest,
+    ) -> Result<
+        DescribeConformancePackComplianceResponse,
+        RusotoError<DescribeConformancePackComplianceError>,
+    > {
+        self.describe_conformance_pack_compliance_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Provides one or more conformance packs deployment status.</p> <note> <p>If there are no conformance packs then you will see an empty result.</p> </note></p>
+    async fn describe_conformance_pack_status(
+        &self,
+        input: DescribeConformancePackStatusRequest,
+    ) -> Result<
+        DescribeConformancePackStatusResponse,
+        RusotoError<DescribeConformancePackStatusError>,
+    > {
+        self.describe_conformance_pack_status_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns a list of one or more conformance packs.</p>
+    async fn describe_conformance_packs(
+        &self,
+        input: DescribeConformancePacksRequest,
+    ) -> Result<DescribeConformancePacksResponse, RusotoError<DescribeConformancePacksError>> {
+        self.describe_conformance_packs_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Returns the current status of the specified delivery channel. If a delivery channel is not specified, this action returns the current status of all delivery channels associated with the account.</p> <note> <p>Currently, you can specify only one delivery channel per region in your account.</p> </note></p>
+    async fn describe_delivery_channel_status(
+        &self,
+        input: DescribeDeliveryChannelStatusRequest,
+    ) -> Result<
+        DescribeDeliveryChannelStatusResponse,
+        RusotoError<DescribeDeliveryChannelStatusError>,
+    > {
+        self.describe_delivery_channel_status_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Returns details about the specified delivery channel. If a delivery channel is not specified, this action returns the details of all delivery channels associated with the account.</p> <note> <p>Currently, you can specify only one delivery channel per region in your account.</p> </note></p>
+    async fn describe_delivery_channels(
+        &self,
+        input: DescribeDeliveryChannelsRequest,
+    ) -> Result<DescribeDeliveryChannelsResponse, RusotoError<DescribeDeliveryChannelsError>> {
+        self.describe_delivery_channels_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Provides organization config rule deployment status for an organization.</p> <note> <p>The status is not considered successful until organization config rule is successfully deployed in all the member accounts with an exception of excluded accounts.</p> <p>When you specify the limit and the next token, you receive a paginated response. Limit and next token are not applicable if you specify organization config rule names. It is only applicable, when you request all the organization config rules.</p> <p>Only a master account can call this API.</p> </note></p>
+    async fn describe_organization_config_rule_statuses(
+        &self,
+        input: DescribeOrganizationConfigRuleStatusesRequest,
+    ) -> Result<
+        DescribeOrganizationConfigRuleStatusesResponse,
+        RusotoError<DescribeOrganizationConfigRuleStatusesError>,
+    > {
+        self.describe_organization_config_rule_statuses_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Returns a list of organization config rules.</p> <note> <p>When you specify the limit and the next token, you receive a paginated response. Limit and next token are not applicable if you specify organization config rule names. It is only applicable, when you request all the organization config rules.</p> <p>Only a master account can call this API.</p> </note></p>
+    async fn describe_organization_config_rules(
+        &self,
+        input: DescribeOrganizationConfigRulesRequest,
+    ) -> Result<
+        DescribeOrganizationConfigRulesResponse,
+        RusotoError<DescribeOrganizationConfigRulesError>,
+    > {
+        self.describe_organization_config_rules_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Provides organization conformance pack deployment status for an organization.</p> <note> <p>The status is not considered successful until organization conformance pack is successfully deployed in all the member accounts with an exception of excluded accounts.</p> <p>When you specify the limit and the next token, you receive a paginated response. Limit and next token are not applicable if you specify organization conformance pack names. They are only applicable, when you request all the organization conformance packs.</p> <p>Only a master account can call this API.</p> </note></p>
+    async fn describe_organization_conformance_pack_statuses(
+        &self,
+        input: DescribeOrganizationConformancePackStatusesRequest,
+    ) -> Result<
+        DescribeOrganizationConformancePackStatusesResponse,
+        RusotoError<DescribeOrganizationConformancePackStatusesError>,
+    > {
+        self.describe_organization_conformance_pack_statuses_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Returns a list of organization conformance packs.</p> <note> <p>When you specify the limit and the next token, you receive a paginated response. </p> <p>Limit and next token are not applicable if you specify organization conformance packs names. They are only applicable, when you request all the organization conformance packs. </p> <p>Only a master account can call this API.</p> </note></p>
+    async fn describe_organization_conformance_packs(
+        &self,
+        input: DescribeOrganizationConformancePacksRequest,
+    ) -> Result<
+        DescribeOrganizationConformancePacksResponse,
+        RusotoError<DescribeOrganizationConformancePacksError>,
+    > {
+        self.describe_organization_conformance_packs_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns a list of all pending aggregation requests.</p>
+    async fn describe_pending_aggregation_requests(
+        &self,
+        input: DescribePendingAggregationRequestsRequest,
+    ) -> Result<
+        DescribePendingAggregationRequestsResponse,
+        RusotoError<DescribePendingAggregationRequestsError>,
+    > {
+        self.describe_pending_aggregation_requests_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns the details of one or more remediation configurations.</p>
+    async fn describe_remediation_configurations(
+        &self,
+        input: DescribeRemediationConfigurationsRequest,
+    ) -> Result<
+        DescribeRemediationConfigurationsResponse,
+        RusotoError<DescribeRemediationConfigurationsError>,
+    > {
+        self.describe_remediation_configurations_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Returns the details of one or more remediation exceptions. A detailed view of a remediation exception for a set of resources that includes an explanation of an exception and the time when the exception will be deleted. When you specify the limit and the next token, you receive a paginated response. </p> <note> <p>When you specify the limit and the next token, you receive a paginated response. </p> <p>Limit and next token are not applicable if you request resources in batch. It is only applicable, when you request all resources.</p> </note></p>
+    async fn describe_remediation_exceptions(
+        &self,
+        input: DescribeRemediationExceptionsRequest,
+    ) -> Result<
+        DescribeRemediationExceptionsResponse,
+        RusotoError<DescribeRemediationExceptionsError>,
+    > {
+        self.describe_remediation_exceptions_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Provides a detailed view of a Remediation Execution for a set of resources including state, timestamps for when steps for the remediation execution occur, and any error messages for steps that have failed. When you specify the limit and the next token, you receive a paginated response.</p>
+    async fn describe_remediation_execution_status(
+        &self,
+        input: DescribeRemediationExecutionStatusRequest,
+    ) -> Result<
+        DescribeRemediationExecutionStatusResponse,
+        RusotoError<DescribeRemediationExecutionStatusError>,
+    > {
+        self.describe_remediation_execution_status_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Returns the details of one or more retention configurations. If the retention configuration name is not specified, this action returns the details for all the retention configurations for that account.</p> <note> <p>Currently, AWS Config supports only one retention configuration per region in your account.</p> </note></p>
+    async fn describe_retention_configurations(
+        &self,
+        input: DescribeRetentionConfigurationsRequest,
+    ) -> Result<
+        DescribeRetentionConfigurationsResponse,
+        RusotoError<DescribeRetentionConfigurationsError>,
+    > {
+        self.describe_retention_configurations_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Returns the evaluation results for the specified AWS Config rule for a specific resource in a rule. The results indicate which AWS resources were evaluated by the rule, when each resource was last evaluated, and whether each resource complies with the rule. </p> <note> <p>The results can return an empty result page. But if you have a <code>nextToken</code>, the results are displayed on the next page.</p> </note></p>
+    async fn get_aggregate_compliance_details_by_config_rule(
+        &self,
+        input: GetAggregateComplianceDetailsByConfigRuleRequest,
+    ) -> Result<
+        GetAggregateComplianceDetailsByConfigRuleResponse,
+        RusotoError<GetAggregateComplianceDetailsByConfigRuleError>,
+    > {
+        self.get_aggregate_compliance_details_by_config_rule_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Returns the number of compliant and noncompliant rules for one or more accounts and regions in an aggregator.</p> <note> <p>The results can return an empty result page, but if you have a nextToken, the results are displayed on the next page.</p> </note></p>
+    async fn get_aggregate_config_rule_compliance_summary(
+        &self,
+        input: GetAggregateConfigRuleComplianceSummaryRequest,
+    ) -> Result<
+        GetAggregateConfigRuleComplianceSummaryResponse,
+        RusotoError<GetAggregateConfigRuleComplianceSummaryError>,
+    > {
+        self.get_aggregate_config_rule_compliance_summary_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns the resource counts across accounts and regions that are present in your AWS Config aggregator. You can request the resource counts by providing filters and GroupByKey.</p> <p>For example, if the input contains accountID 12345678910 and region us-east-1 in filters, the API returns the count of resources in account ID 12345678910 and region us-east-1. If the input contains ACCOUNT_ID as a GroupByKey, the API returns resource counts for all source accounts that are present in your aggregator.</p>
+    async fn get_aggregate_discovered_resource_counts(
+        &self,
+        input: GetAggregateDiscoveredResourceCountsRequest,
+    ) -> Result<
+        GetAggregateDiscoveredResourceCountsResponse,
+        RusotoError<GetAggregateDiscoveredResourceCountsError>,
+    > {
+        self.get_aggregate_discovered_resource_counts_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns configuration item that is aggregated for your specific resource in a specific source account and region.</p>
+    async fn get_aggregate_resource_config(
+        &self,
+        input: GetAggregateResourceConfigRequest,
+    ) -> Result<GetAggregateResourceConfigResponse, RusotoError<GetAggregateResourceConfigError>>
+    {
+        self.get_aggregate_resource_config_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns the evaluation results for the specified AWS Config rule. The results indicate which AWS resources were evaluated by the rule, when each resource was last evaluated, and whether each resource complies with the rule.</p>
+    async fn get_compliance_details_by_config_rule(
+        &self,
+        input: GetComplianceDetailsByConfigRuleRequest,
+    ) -> Result<
+        GetComplianceDetailsByConfigRuleResponse,
+        RusotoError<GetComplianceDetailsByConfigRuleError>,
+    > {
+        self.get_compliance_details_by_config_rule_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns the evaluation results for the specified AWS resource. The results indicate which AWS Config rules were used to evaluate the resource, when each rule was last used, and whether the resource complies with each rule.</p>
+    async fn get_compliance_details_by_resource(
+        &self,
+        input: GetComplianceDetailsByResourceRequest,
+    ) -> Result<
+        GetComplianceDetailsByResourceResponse,
+        RusotoError<GetComplianceDetailsByResourceError>,
+    > {
+        self.get_compliance_details_by_resource_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns the number of AWS Config rules that are compliant and noncompliant, up to a maximum of 25 for each.</p>
+    async fn get_compliance_summary_by_config_rule(
+        &self,
+    ) -> Result<
+        GetComplianceSummaryByConfigRuleResponse,
+        RusotoError<GetComplianceSummaryByConfigRuleError>,
+    > {
+        self.get_compliance_summary_by_config_rule_with_metadata()
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns the number of resources that are compliant and the number that are noncompliant. You can specify one or more resource types to get these numbers for each resource type. The maximum number returned is 100.</p>
+    async fn get_compliance_summary_by_resource_type(
+        &self,
+        input: GetComplianceSummaryByResourceTypeRequest,
+    ) -> Result<
+        GetComplianceSummaryByResourceTypeResponse,
+        RusotoError<GetComplianceSummaryByResourceTypeError>,
+    > {
+        self.get_compliance_summary_by_resource_type_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns compliance details of a conformance pack for all AWS resources that are monitered by conformance pack.</p>
+    async fn get_conformance_pack_compliance_details(
+        &self,
+        input: GetConformancePackComplianceDetailsRequest,
+    ) -> Result<
+        GetConformancePackComplianceDetailsResponse,
+        RusotoError<GetConformancePackComplianceDetailsError>,
+    > {
+        self.get_conformance_pack_compliance_details_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns compliance details for the conformance pack based on the cumulative compliance results of all the rules in that conformance pack.</p>
+    async fn get_conformance_pack_compliance_summary(
+        &self,
+        input: GetConformancePackComplianceSummaryRequest,
+    ) -> Result<
+        GetConformancePackComplianceSummaryResponse,
+        RusotoError<GetConformancePackComplianceSummaryError>,
+    > {
+        self.get_conformance_pack_compliance_summary_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Returns the resource types, the number of each resource type, and the total number of resources that AWS Config is recording in this region for your AWS account. </p> <p class="title"> <b>Example</b> </p> <ol> <li> <p>AWS Config is recording three resource types in the US East (Ohio) Region for your account: 25 EC2 instances, 20 IAM users, and 15 S3 buckets.</p> </li> <li> <p>You make a call to the <code>GetDiscoveredResourceCounts</code> action and specify that you want all resource types. </p> </li> <li> <p>AWS Config returns the following:</p> <ul> <li> <p>The resource types (EC2 instances, IAM users, and S3 buckets).</p> </li> <li> <p>The number of each resource type (25, 20, and 15).</p> </li> <li> <p>The total number of all resources (60).</p> </li> </ul> </li> </ol> <p>The response is paginated. By default, AWS Config lists 100 <a>ResourceCount</a> objects on each page. You can customize this number with the <code>limit</code> parameter. The response includes a <code>nextToken</code> string. To get the next page of results, run the request again and specify the string for the <code>nextToken</code> parameter.</p> <note> <p>If you make a call to the <a>GetDiscoveredResourceCounts</a> action, you might not immediately receive resource counts in the following situations:</p> <ul> <li> <p>You are a new AWS Config customer.</p> </li> <li> <p>You just enabled resource recording.</p> </li> </ul> <p>It might take a few minutes for AWS Config to record and count your resources. Wait a few minutes and then retry the <a>GetDiscoveredResourceCounts</a> action. </p> </note></p>
+    async fn get_discovered_resource_counts(
+        &self,
+        input: GetDiscoveredResourceCountsRequest,
+    ) -> Result<GetDiscoveredResourceCountsResponse, RusotoError<GetDiscoveredResourceCountsError>>
+    {
+        self.get_discovered_resource_counts_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Returns detailed status for each member account within an organization for a given organization config rule.</p> <note> <p>Only a master account can call this API.</p> </note></p>
+    async fn get_organization_config_rule_detailed_status(
+        &self,
+        input: GetOrganizationConfigRuleDetailedStatusRequest,
+    ) -> Result<
+        GetOrganizationConfigRuleDetailedStatusResponse,
+        RusotoError<GetOrganizationConfigRuleDetailedStatusError>,
+    > {
+        self.get_organization_config_rule_detailed_status_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns detailed status for each member account within an organization for a given organization conformance pack.</p> <p>Only a master account can call this API.</p>
+    async fn get_organization_conformance_pack_detailed_status(
+        &self,
+        input: GetOrganizationConformancePackDetailedStatusRequest,
+    ) -> Result<
+        GetOrganizationConformancePackDetailedStatusResponse,
+        RusotoError<GetOrganizationConformancePackDetailedStatusError>,
+    > {
+        self.get_organization_conformance_pack_detailed_status_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Returns a list of configuration items for the specified resource. The list contains details about each state of the resource during the specified time interval. If you specified a retention period to retain your <code>ConfigurationItems</code> between a minimum of 30 days and a maximum of 7 years (2557 days), AWS Config returns the <code>ConfigurationItems</code> for the specified retention period. </p> <p>The response is paginated. By default, AWS Config returns a limit of 10 configuration items per page. You can customize this number with the <code>limit</code> parameter. The response includes a <code>nextToken</code> string. To get the next page of results, run the request again and specify the string for the <code>nextToken</code> parameter.</p> <note> <p>Each call to the API is limited to span a duration of seven days. It is likely that the number of records returned is smaller than the specified <code>limit</code>. In such cases, you can make another call, using the <code>nextToken</code>.</p> </note></p>
+    async fn get_resource_config_history(
+        &self,
+        input: GetResourceConfigHistoryRequest,
+    ) -> Result<GetResourceConfigHistoryResponse, RusotoError<GetResourceConfigHistoryError>> {
+        self.get_resource_config_history_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Accepts a resource type and returns a list of resource identifiers that are aggregated for a specific resource type across accounts and regions. A resource identifier includes the resource type, ID, (if available) the custom resource name, source account, and source region. You can narrow the results to include only resources that have specific resource IDs, or a resource name, or source account ID, or source region.</p> <p>For example, if the input consists of accountID 12345678910 and the region is us-east-1 for resource type <code>AWS::EC2::Instance</code> then the API returns all the EC2 instance identifiers of accountID 12345678910 and region us-east-1.</p>
+    async fn list_aggregate_discovered_resources(
+        &self,
+        input: ListAggregateDiscoveredResourcesRequest,
+    ) -> Result<
+        ListAggregateDiscoveredResourcesResponse,
+        RusotoError<ListAggregateDiscoveredResourcesError>,
+    > {
+        self.list_aggregate_discovered_resources_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Accepts a resource type and returns a list of resource identifiers for the resources of that type. A resource identifier includes the resource type, ID, and (if available) the custom resource name. The results consist of resources that AWS Config has discovered, including those that AWS Config is not currently recording. You can narrow the results to include only resources that have specific resource IDs or a resource name.</p> <note> <p>You can specify either resource IDs or a resource name, but not both, in the same request.</p> </note> <p>The response is paginated. By default, AWS Config lists 100 resource identifiers on each page. You can customize this number with the <code>limit</code> parameter. The response includes a <code>nextToken</code> string. To get the next page of results, run the request again and specify the string for the <code>nextToken</code> parameter.</p>
+    async fn list_discovered_resources(
+        &self,
+        input: ListDiscoveredResourcesRequest,
+    ) -> Result<ListDiscoveredResourcesResponse, RusotoError<ListDiscoveredResourcesError>> {
+        self.list_discovered_resources_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>List the tags for AWS Config resource.</p>
+    async fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> Result<ListTagsForResourceResponse, RusotoError<ListTagsForResourceError>> {
+        self.list_tags_for_resource_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Authorizes the aggregator account and region to collect data from the source account and region. </p>
+    async fn put_aggregation_authorization(
+        &self,
+        input: PutAggregationAuthorizationRequest,
+    ) -> Result<PutAggregationAuthorizationResponse, RusotoError<PutAggregationAuthorizationError>>
+    {
+        self.put_aggregation_authorization_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Adds or updates an AWS Config rule for evaluating whether your AWS resources comply with your desired configurations.</p> <p>You can use this action for custom AWS Config rules and AWS managed Config rules. A custom AWS Config rule is a rule that you develop and maintain. An AWS managed Config rule is a customizable, predefined rule that AWS Config provides.</p> <p>If you are adding a new custom AWS Config rule, you must first create the AWS Lambda function that the rule invokes to evaluate your resources. When you use the <code>PutConfigRule</code> action to add the rule to AWS Config, you must specify the Amazon Resource Name (ARN) that AWS Lambda assigns to the function. Specify the ARN for the <code>SourceIdentifier</code> key. This key is part of the <code>Source</code> object, which is part of the <code>ConfigRule</code> object. </p> <p>If you are adding an AWS managed Config rule, specify the rule's identifier for the <code>SourceIdentifier</code> key. To reference AWS managed Config rule identifiers, see <a href="https://docs.aws.amazon.com/config/latest/developerguide/evaluate-config_use-managed-rules.html">About AWS Managed Config Rules</a>.</p> <p>For any new rule that you add, specify the <code>ConfigRuleName</code> in the <code>ConfigRule</code> object. Do not specify the <code>ConfigRuleArn</code> or the <code>ConfigRuleId</code>. These values are generated by AWS Config for new rules.</p> <p>If you are updating a rule that you added previously, you can specify the rule by <code>ConfigRuleName</code>, <code>ConfigRuleId</code>, or <code>ConfigRuleArn</code> in the <code>ConfigRule</code> data type that you use in this request.</p> <p>The maximum number of rules that AWS Config supports is 150.</p> <p>For information about requesting a rule limit increase, see <a href="http://docs.aws.amazon.com/general/latest/gr/aws_service_limits.html#limits_config">AWS Config Limits</a> in the <i>AWS General Reference Guide</i>.</p> <p>For more information about developing and using AWS Config rules, see <a href="https://docs.aws.amazon.com/config/latest/developerguide/evaluate-config.html">Evaluating AWS Resource Configurations with AWS Config</a> in the <i>AWS Config Developer Guide</i>.</p>
+    async fn put_config_rule(
+        &self,
+        input: PutConfigRuleRequest,
+    ) -> Result<(), RusotoError<PutConfigRuleError>> {
+        self.put_config_rule_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Creates and updates the configuration aggregator with the selected source accounts and regions. The source account can be individual account(s) or an organization.</p> <note> <p>AWS Config should be enabled in source accounts and regions you want to aggregate.</p> <p>If your source type is an organization, you must be signed in to the master account and all features must be enabled in your organization. AWS Config calls <code>EnableAwsServiceAccess</code> API to enable integration between AWS Config and AWS Organizations. </p> </note></p>
+    async fn put_configuration_aggregator(
+        &self,
+        input: PutConfigurationAggregatorRequest,
+    ) -> Result<PutConfigurationAggregatorResponse, RusotoError<PutConfigurationAggregatorError>>
+    {
+        self.put_configuration_aggregator_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Creates a new configuration recorder to record the selected resource configurations.</p> <p>You can use this action to change the role <code>roleARN</code> or the <code>recordingGroup</code> of an existing recorder. To change the role, call the action on the existing configuration recorder and specify a role.</p> <note> <p>Currently, you can specify only one configuration recorder per region in your account.</p> <p>If <code>ConfigurationRecorder</code> does not have the <b>recordingGroup</b> parameter specified, the default is to record all supported resource types.</p> </note></p>
+    async fn put_configuration_recorder(
+        &self,
+        input: PutConfigurationRecorderRequest,
+    ) -> Result<(), RusotoError<PutConfigurationRecorderError>> {
+        self.put_configuration_recorder_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Creates or updates a conformance pack. A conformance pack is a collection of AWS Config rules that can be easily deployed in an account and a region and across AWS Organization.</p> <p>This API creates a service linked role <code>AWSServiceRoleForConfigConforms</code> in your account. The service linked role is created only when the role does not exist in your account. AWS Config verifies the existence of role with <code>GetRole</code> action.</p> <note> <p>You must specify either the <code>TemplateS3Uri</code> or the <code>TemplateBody</code> parameter, but not both. If you provide both AWS Config uses the <code>TemplateS3Uri</code> parameter and ignores the <code>TemplateBody</code> parameter.</p> </note></p>
+    async fn put_conformance_pack(
+        &self,
+        input: PutConformancePackRequest,
+    ) -> Result<PutConformancePackResponse, RusotoError<PutConformancePackError>> {
+        self.put_conformance_pack_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Creates a delivery channel object to deliver configuration information to an Amazon S3 bucket and Amazon SNS topic.</p> <p>Before you can create a delivery channel, you must create a configuration recorder.</p> <p>You can use this action to change the Amazon S3 bucket or an Amazon SNS topic of the existing delivery channel. To change the Amazon S3 bucket or an Amazon SNS topic, call this action and specify the changed values for the S3 bucket and the SNS topic. If you specify a different value for either the S3 bucket or the SNS topic, this action will keep the existing value for the parameter that is not changed.</p> <note> <p>You can have only one delivery channel per region in your account.</p> </note></p>
+    async fn put_delivery_channel(
+        &self,
+        input: PutDeliveryChannelRequest,
+    ) -> Result<(), RusotoError<PutDeliveryChannelError>> {
+        self.put_delivery_channel_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Used by an AWS Lambda function to deliver evaluation results to AWS Config. This action is required in every AWS Lambda function that is invoked by an AWS Config rule.</p>
+    async fn put_evaluations(
+        &self,
+        input: PutEvaluationsRequest,
+    ) -> Result<PutEvaluationsResponse, RusotoError<PutEvaluationsError>> {
+        self.put_evaluations_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Adds or updates organization config rule for your entire organization evaluating whether your AWS resources comply with your desired configurations. Only a master account can create or update an organization config rule.</p> <p>This API enables organization service access through the <code>EnableAWSServiceAccess</code> action and creates a service linked role <code>AWSServiceRoleForConfigMultiAccountSetup</code> in the master account of your organization. The service linked role is created only when the role does not exist in the master account. AWS Config verifies the existence of role with <code>GetRole</code> action.</p> <p>You can use this action to create both custom AWS Config rules and AWS managed Config rules. If you are adding a new custom AWS Config rule, you must first create AWS Lambda function in the master account that the rule invokes to evaluate your resources. When you use the <code>PutOrganizationConfigRule</code> action to add the rule to AWS Config, you must specify the Amazon Resource Name (ARN) that AWS Lambda assigns to the function. If you are adding an AWS managed Config rule, specify the rule&#39;s identifier for the <code>RuleIdentifier</code> key.</p> <p>The maximum number of organization config rules that AWS Config supports is 150.</p> <note> <p>Specify either <code>OrganizationCustomRuleMetadata</code> or <code>OrganizationManagedRuleMetadata</code>.</p> </note></p>
+    async fn put_organization_config_rule(
+        &self,
+        input: PutOrganizationConfigRuleRequest,
+    ) -> Result<PutOrganizationConfigRuleResponse, RusotoError<PutOrganizationConfigRuleError>>
+    {
+        self.put_organization_config_rule_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Deploys conformance packs across member accounts in an AWS Organization.</p> <p>This API enables organization service access for <code>config-multiaccountsetup.amazonaws.com</code> through the <code>EnableAWSServiceAccess</code> action and creates a service linked role <code>AWSServiceRoleForConfigMultiAccountSetup</code> in the master account of your organization. The service linked role is created only when the role does not exist in the master account. AWS Config verifies the existence of role with GetRole action.</p> <note> <p>You must specify either the <code>TemplateS3Uri</code> or the <code>TemplateBody</code> parameter, but not both. If you provide both AWS Config uses the <code>TemplateS3Uri</code> parameter and ignores the <code>TemplateBody</code> parameter.</p> <p>AWS Config sets the state of a conformance pack to CREATE<em>IN</em>PROGRESS and UPDATE<em>IN</em>PROGRESS until the confomance pack is created or updated. You cannot update a conformance pack while it is in this state.</p> <p>You can create 6 conformance packs with 25 AWS Config rules in each pack.</p> </note></p>
+    async fn put_organization_conformance_pack(
+        &self,
+        input: PutOrganizationConformancePackRequest,
+    ) -> Result<
+        PutOrganizationConformancePackResponse,
+        RusotoError<PutOrganizationConformancePackError>,
+    > {
+        self.put_organization_conformance_pack_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Adds or updates the remediation configuration with a specific AWS Config rule with the selected target or action. The API creates the <code>RemediationConfiguration</code> object for the AWS Config rule. The AWS Config rule must already exist for you to add a remediation configuration. The target (SSM document) must exist and have permissions to use the target. </p>
+    async fn put_remediation_configurations(
+        &self,
+        input: PutRemediationConfigurationsRequest,
+    ) -> Result<PutRemediationConfigurationsResponse, RusotoError<PutRemediationConfigurationsError>>
+    {
+        self.put_remediation_configurations_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>A remediation exception is when a specific resource is no longer considered for auto-remediation. This API adds a new exception or updates an exisiting exception for a specific resource with a specific AWS Config rule. </p>
+    async fn put_remediation_exceptions(
+        &self,
+        input: PutRemediationExceptionsRequest,
+    ) -> Result<PutRemediationExceptionsResponse, RusotoError<PutRemediationExceptionsError>> {
+        self.put_remediation_exceptions_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Records the configuration state for the resource provided in the request. The configuration state of a resource is represented in AWS Config as Configuration Items. Once this API records the configuration item, you can retrieve the list of configuration items for the custom resource type using existing AWS Config APIs. </p> <note> <p>The custom resource type must be registered with AWS CloudFormation. This API accepts the configuration item registered with AWS CloudFormation.</p> <p>When you call this API, AWS Config only stores configuration state of the resource provided in the request. This API does not change or remediate the configuration of the resource. </p> </note></p>
+    async fn put_resource_config(
+        &self,
+        input: PutResourceConfigRequest,
+    ) -> Result<(), RusotoError<PutResourceConfigError>> {
+        self.put_resource_config_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Creates and updates the retention configuration with details about retention period (number of days) that AWS Config stores your historical information. The API creates the <code>RetentionConfiguration</code> object and names the object as <b>default</b>. When you have a <code>RetentionConfiguration</code> object named <b>default</b>, calling the API modifies the default object. </p> <note> <p>Currently, AWS Config supports only one retention configuration per region in your account.</p> </note></p>
+    async fn put_retention_configuration(
+        &self,
+        input: PutRetentionConfigurationRequest,
+    ) -> Result<PutRetentionConfigurationResponse, RusotoError<PutRetentionConfigurationError>>
+    {
+        self.put_retention_configuration_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Accepts a structured query language (SQL) <code>SELECT</code> command, performs the corresponding search, and returns resource configurations matching the properties.</p> <p>For more information about query components, see the <a href="https://docs.aws.amazon.com/config/latest/developerguide/query-components.html"> <b>Query Components</b> </a> section in the AWS Config Developer Guide.</p>
+    async fn select_resource_config(
+        &self,
+        input: SelectResourceConfigRequest,
+    ) -> Result<SelectResourceConfigResponse, RusotoError<SelectResourceConfigError>> {
+        self.select_resource_config_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Runs an on-demand evaluation for the specified AWS Config rules against the last known configuration state of the resources. Use <code>StartConfigRulesEvaluation</code> when you want to test that a rule you updated is working as expected. <code>StartConfigRulesEvaluation</code> does not re-record the latest configuration state for your resources. It re-runs an evaluation against the last known state of your resources. </p> <p>You can specify up to 25 AWS Config rules per request. </p> <p>An existing <code>StartConfigRulesEvaluation</code> call for the specified rules must complete before you can call the API again. If you chose to have AWS Config stream to an Amazon SNS topic, you will receive a <code>ConfigRuleEvaluationStarted</code> notification when the evaluation starts.</p> <note> <p>You don&#39;t need to call the <code>StartConfigRulesEvaluation</code> API to run an evaluation for a new rule. When you create a rule, AWS Config evaluates your resources against the rule automatically. </p> </note> <p>The <code>StartConfigRulesEvaluation</code> API is useful if you want to run on-demand evaluations, such as the following example:</p> <ol> <li> <p>You have a custom rule that evaluates your IAM resources every 24 hours.</p> </li> <li> <p>You update your Lambda function to add additional conditions to your rule.</p> </li> <li> <p>Instead of waiting for the next periodic evaluation, you call the <code>StartConfigRulesEvaluation</code> API.</p> </li> <li> <p>AWS Config invokes your Lambda function and evaluates your IAM resources.</p> </li> <li> <p>Your custom rule will still run periodic evaluations every 24 hours.</p> </li> </ol></p>
+    async fn start_config_rules_evaluation(
+        &self,
+        input: StartConfigRulesEvaluationRequest,
+    ) -> Result<StartConfigRulesEvaluationResponse, RusotoError<StartConfigRulesEvaluationError>>
+    {
+        self.start_config_rules_evaluation_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Starts recording configurations of the AWS resources you have selected to record in your AWS account.</p> <p>You must have created at least one delivery channel to successfully start the configuration recorder.</p>
+    async fn start_configuration_recorder(
+        &self,
+        input: StartConfigurationRecorderRequest,
+    ) -> Result<(), RusotoError<StartConfigurationRecorderError>> {
+        self.start_configuration_recorder_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Runs an on-demand remediation for the specified AWS Config rules against the last known remediation configuration. It runs an execution against the current state of your resources. Remediation execution is asynchronous.</p> <p>You can specify up to 100 resource keys per request. An existing StartRemediationExecution call for the specified resource keys must complete before you can call the API again.</p>
+    async fn start_remediation_execution(
+        &self,
+        input: StartRemediationExecutionRequest,
+    ) -> Result<StartRemediationExecutionResponse, RusotoError<StartRemediationExecutionError>>
+    {
+        self.start_remediation_execution_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Stops recording configurations of the AWS resources you have selected to record in your AWS account.</p>
+    async fn stop_configuration_recorder(
+        &self,
+        input: StopConfigurationRecorderRequest,
+    ) -> Result<(), RusotoError<StopConfigurationRecorderError>> {
+        self.stop_configuration_recorder_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Associates the specified tags to a resource with the specified resourceArn. If existing tags on a resource are not specified in the request parameters, they are not changed. When a resource is deleted, the tags associated with that resource are deleted as well.</p>
+    async fn tag_resource(
+        &self,
+        input: TagResourceRequest,
+    ) -> Result<(), RusotoError<TagResourceError>> {
+        self.tag_resource_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes specified tags from a resource.</p>
+    async fn untag_resource(
+        &self,
+        input: UntagResourceRequest,
+    ) -> Result<(), RusotoError<UntagResourceError>> {
+        self.untag_resource_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
     }
 }
@@ -16,8 +16,8 @@ use std::fmt;
 use async_trait::async_trait;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
-use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest, ResponseMetadata};
+use rusoto_core::{Client, ErrorMetadata, RusotoError};
 
 use rusoto_core::param::{Params, ServiceParams};
 use rusoto_core::proto;
@@ -1273,6 +1273,7 @@ pub struct UserSummary {
 
 /// Errors returned by CreateUser
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateUserError {
     /// <p>A resource with the specified name already exists.</p>
     DuplicateResource(String),
@@ -1288,6 +1289,8 @@ pub enum CreateUserError {
     ResourceNotFound(String),
     /// <p>The throttling limit has been exceeded.</p>
     Throttling(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateUserError {
@@ -1295,28 +1298,28 @@ impl CreateUserError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "DuplicateResourceException" => {
-                    return RusotoError::Service(CreateUserError::DuplicateResource(err.msg))
+                    return RusotoError::service(CreateUserError::DuplicateResource(err.msg), &res)
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(CreateUserError::InternalService(err.msg))
+                    return RusotoError::service(CreateUserError::InternalService(err.msg), &res)
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(CreateUserError::InvalidParameter(err.msg))
+                    return RusotoError::service(CreateUserError::InvalidParameter(err.msg), &res)
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(CreateUserError::InvalidRequest(err.msg))
+                    return RusotoError::service(CreateUserError::InvalidRequest(err.msg), &res)
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateUserError::LimitExceeded(err.msg))
+                    return RusotoError::service(CreateUserError::LimitExceeded(err.msg), &res)
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(CreateUserError::ResourceNotFound(err.msg))
+                    return RusotoError::service(CreateUserError::ResourceNotFound(err.msg), &res)
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(CreateUserError::Throttling(err.msg))
+                    return RusotoError::service(CreateUserError::Throttling(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreateUserError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1333,12 +1336,14 @@ impl fmt::Display for CreateUserError {
             CreateUserError::LimitExceeded(ref cause) => write!(f, "{}", cause),
             CreateUserError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             CreateUserError::Throttling(ref cause) => write!(f, "{}", cause),
+            CreateUserError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateUserError {}
 /// Errors returned by DeleteUser
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteUserError {
     /// <p>Request processing failed due to an error or failure with the service.</p>
     InternalService(String),
@@ -1350,6 +1355,8 @@ pub enum DeleteUserError {
     ResourceNotFound(String),
     /// <p>The throttling limit has been exceeded.</p>
     Throttling(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteUserError {
@@ -1357,22 +1364,22 @@ impl DeleteUserError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalServiceException" => {
-                    return RusotoError::Service(DeleteUserError::InternalService(err.msg))
+                    return RusotoError::service(DeleteUserError::InternalService(err.msg), &res)
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DeleteUserError::InvalidParameter(err.msg))
+                    return RusotoError::service(DeleteUserError::InvalidParameter(err.msg), &res)
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DeleteUserError::InvalidRequest(err.msg))
+                    return RusotoError::service(DeleteUserError::InvalidRequest(err.msg), &res)
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteUserError::ResourceNotFound(err.msg))
+                    return RusotoError::service(DeleteUserError::ResourceNotFound(err.msg), &res)
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DeleteUserError::Throttling(err.msg))
+                    return RusotoError::service(DeleteUserError::Throttling(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteUserError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1387,12 +1394,14 @@ impl fmt::Display for DeleteUserError {
             DeleteUserError::InvalidRequest(ref cause) => write!(f, "{}", cause),
             DeleteUserError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             DeleteUserError::Throttling(ref cause) => write!(f, "{}", cause),
+            DeleteUserError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteUserError {}
 /// Errors returned by DescribeUser
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeUserError {
     /// <p>Request processing failed due to an error or failure with the service.</p>
     InternalService(String),
@@ -1404,6 +1413,8 @@ pub enum DescribeUserError {
     ResourceNotFound(String),
     /// <p>The throttling limit has been exceeded.</p>
     Throttling(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeUserError {
@@ -1411,22 +1422,22 @@ impl DescribeUserError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalServiceException" => {
-                    return RusotoError::Service(DescribeUserError::InternalService(err.msg))
+                    return RusotoError::service(DescribeUserError::InternalService(err.msg), &res)
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DescribeUserError::InvalidParameter(err.msg))
+                    return RusotoError::service(DescribeUserError::InvalidParameter(err.msg), &res)
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DescribeUserError::InvalidRequest(err.msg))
+                    return RusotoError::service(DescribeUserError::InvalidRequest(err.msg), &res)
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeUserError::ResourceNotFound(err.msg))
+                    return RusotoError::service(DescribeUserError::ResourceNotFound(err.msg), &res)
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DescribeUserError::Throttling(err.msg))
+                    return RusotoError::service(DescribeUserError::Throttling(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DescribeUserError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1441,12 +1452,14 @@ impl fmt::Display for DescribeUserError {
             DescribeUserError::InvalidRequest(ref cause) => write!(f, "{}", cause),
             DescribeUserError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             DescribeUserError::Throttling(ref cause) => write!(f, "{}", cause),
+            DescribeUserError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeUserError {}
 /// Errors returned by DescribeUserHierarchyGroup
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeUserHierarchyGroupError {
     /// <p>Request processing failed due to an error or failure with the service.</p>
     InternalService(String),
@@ -1458,6 +1471,8 @@ pub enum DescribeUserHierarchyGroupError {
     ResourceNotFound(String),
     /// <p>The throttling limit has been exceeded.</p>
     Throttling(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeUserHierarchyGroupError {
@@ -1467,32 +1482,39 @@ impl DescribeUserHierarchyGroupError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalServiceException" => {
-                    return RusotoError::Service(DescribeUserHierarchyGroupError::InternalService(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeUserHierarchyGroupError::InternalService(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DescribeUserHierarchyGroupError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeUserHierarchyGroupError::InvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DescribeUserHierarchyGroupError::InvalidRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeUserHierarchyGroupError::InvalidRequest(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeUserHierarchyGroupError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeUserHierarchyGroupError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DescribeUserHierarchyGroupError::Throttling(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeUserHierarchyGroupError::Throttling(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(DescribeUserHierarchyGroupError::Unhandled, &res)
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -1507,12 +1529,14 @@ impl fmt::Display for DescribeUserHierarchyGroupError {
             DescribeUserHierarchyGroupError::InvalidRequest(ref cause) => write!(f, "{}", cause),
             DescribeUserHierarchyGroupError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             DescribeUserHierarchyGroupError::Throttling(ref cause) => write!(f, "{}", cause),
+            DescribeUserHierarchyGroupError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeUserHierarchyGroupError {}
 /// Errors returned by DescribeUserHierarchyStructure
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeUserHierarchyStructureError {
     /// <p>Request processing failed due to an error or failure with the service.</p>
     InternalService(String),
@@ -1524,6 +1548,8 @@ pub enum DescribeUserHierarchyStructureError {
     ResourceNotFound(String),
     /// <p>The throttling limit has been exceeded.</p>
     Throttling(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeUserHierarchyStructureError {
@@ -1533,32 +1559,42 @@ impl DescribeUserHierarchyStructureError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalServiceException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DescribeUserHierarchyStructureError::InternalService(err.msg),
+                        &res,
                     )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DescribeUserHierarchyStructureError::InvalidParameter(err.msg),
+                        &res,
                     )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DescribeUserHierarchyStructureError::InvalidRequest(err.msg),
+                        &res,
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DescribeUserHierarchyStructureError::ResourceNotFound(err.msg),
+                        &res,
                     )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DescribeUserHierarchyStructureError::Throttling(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeUserHierarchyStructureError::Throttling(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        DescribeUserHierarchyStructureError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -1581,12 +1617,16 @@ impl fmt::Display for DescribeUserHierarchyStructureError {
                 write!(f, "{}", cause)
             }
             DescribeUserHierarchyStructureError::Throttling(ref cause) => write!(f, "{}", cause),
+            DescribeUserHierarchyStructureError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for DescribeUserHierarchyStructureError {}
 /// Errors returned by GetContactAttributes
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetContactAttributesError {
     /// <p>Request processing failed due to an error or failure with the service.</p>
     InternalService(String),
@@ -1594,6 +1634,8 @@ pub enum GetContactAttributesError {
     InvalidRequest(String),
     /// <p>The specified resource was not found.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetContactAttributesError {
@@ -1601,20 +1643,25 @@ impl GetContactAttributesError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalServiceException" => {
-                    return RusotoError::Service(GetContactAttributesError::InternalService(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetContactAttributesError::InternalService(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(GetContactAttributesError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        GetContactAttributesError::InvalidRequest(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetContactAttributesError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetContactAttributesError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetContactAttributesError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1627,12 +1674,14 @@ impl fmt::Display for GetContactAttributesError {
             GetContactAttributesError::InternalService(ref cause) => write!(f, "{}", cause),
             GetContactAttributesError::InvalidRequest(ref cause) => write!(f, "{}", cause),
             GetContactAttributesError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            GetContactAttributesError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetContactAttributesError {}
 /// Errors returned by GetCurrentMetricData
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetCurrentMetricDataError {
     /// <p>Request processing failed due to an error or failure with the service.</p>
     InternalService(String),
@@ -1644,6 +1693,8 @@ pub enum GetCurrentMetricDataError {
     ResourceNotFound(String),
     /// <p>The throttling limit has been exceeded.</p>
     Throttling(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetCurrentMetricDataError {
@@ -1651,28 +1702,37 @@ impl GetCurrentMetricDataError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalServiceException" => {
-                    return RusotoError::Service(GetCurrentMetricDataError::InternalService(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetCurrentMetricDataError::InternalService(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(GetCurrentMetricDataError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetCurrentMetricDataError::InvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(GetCurrentMetricDataError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        GetCurrentMetricDataError::InvalidRequest(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetCurrentMetricDataError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetCurrentMetricDataError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(GetCurrentMetricDataError::Throttling(err.msg))
+                    return RusotoError::service(
+                        GetCurrentMetricDataError::Throttling(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetCurrentMetricDataError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1687,12 +1747,14 @@ impl fmt::Display for GetCurrentMetricDataError {
             GetCurrentMetricDataError::InvalidRequest(ref cause) => write!(f, "{}", cause),
             GetCurrentMetricDataError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             GetCurrentMetricDataError::Throttling(ref cause) => write!(f, "{}", cause),
+            GetCurrentMetricDataError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetCurrentMetricDataError {}
 /// Errors returned by GetFederationToken
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetFederationTokenError {
     /// <p>A resource with the specified name already exists.</p>
     DuplicateResource(String),
@@ -1706,6 +1768,8 @@ pub enum GetFederationTokenError {
     ResourceNotFound(String),
     /// <p>No user with the specified credentials was found in the Amazon Connect instance.</p>
     UserNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetFederationTokenError {
@@ -1713,27 +1777,43 @@ impl GetFederationTokenError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "DuplicateResourceException" => {
-                    return RusotoError::Service(GetFederationTokenError::DuplicateResource(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetFederationTokenError::DuplicateResource(err.msg),
+                        &res,
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(GetFederationTokenError::InternalService(err.msg))
+                    return RusotoError::service(
+                        GetFederationTokenError::InternalService(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(GetFederationTokenError::InvalidParameter(err.msg))
+                    return RusotoError::service(
+                        GetFederationTokenError::InvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(GetFederationTokenError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        GetFederationTokenError::InvalidRequest(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetFederationTokenError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        GetFederationTokenError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "UserNotFoundException" => {
-                    return RusotoError::Service(GetFederationTokenError::UserNotFound(err.msg))
+                    return RusotoError::service(
+                        GetFederationTokenError::UserNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetFederationTokenError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1749,12 +1829,14 @@ impl fmt::Display for GetFederationTokenError {
             GetFederationTokenError::InvalidRequest(ref cause) => write!(f, "{}", cause),
             GetFederationTokenError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             GetFederationTokenError::UserNotFound(ref cause) => write!(f, "{}", cause),
+            GetFederationTokenError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetFederationTokenError {}
 /// Errors returned by GetMetricData
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetMetricDataError {
     /// <p>Request processing failed due to an error or failure with the service.</p>
     InternalService(String),
@@ -1766,6 +1848,8 @@ pub enum GetMetricDataError {
     ResourceNotFound(String),
     /// <p>The throttling limit has been exceeded.</p>
     Throttling(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetMetricDataError {
@@ -1773,22 +1857,28 @@ impl GetMetricDataError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalServiceException" => {
-                    return RusotoError::Service(GetMetricDataError::InternalService(err.msg))
+                    return RusotoError::service(GetMetricDataError::InternalService(err.msg), &res)
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(GetMetricDataError::InvalidParameter(err.msg))
+                    return RusotoError::service(
+                        GetMetricDataError::InvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(GetMetricDataError::InvalidRequest(err.msg))
+                    return RusotoError::service(GetMetricDataError::InvalidRequest(err.msg), &res)
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetMetricDataError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        GetMetricDataError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(GetMetricDataError::Throttling(err.msg))
+                    return RusotoError::service(GetMetricDataError::Throttling(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetMetricDataError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1803,12 +1893,14 @@ impl fmt::Display for GetMetricDataError {
             GetMetricDataError::InvalidRequest(ref cause) => write!(f, "{}", cause),
             GetMetricDataError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             GetMetricDataError::Throttling(ref cause) => write!(f, "{}", cause),
+            GetMetricDataError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetMetricDataError {}
 /// Errors returned by ListContactFlows
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListContactFlowsError {
     /// <p>Request processing failed due to an error or failure with the service.</p>
     InternalService(String),
@@ -1820,6 +1912,8 @@ pub enum ListContactFlowsError {
     ResourceNotFound(String),
     /// <p>The throttling limit has been exceeded.</p>
     Throttling(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListContactFlowsError {
@@ -1827,22 +1921,34 @@ impl ListContactFlowsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalServiceException" => {
-                    return RusotoError::Service(ListContactFlowsError::InternalService(err.msg))
+                    return RusotoError::service(
+                        ListContactFlowsError::InternalService(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(ListContactFlowsError::InvalidParameter(err.msg))
+                    return RusotoError::service(
+                        ListContactFlowsError::InvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(ListContactFlowsError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        ListContactFlowsError::InvalidRequest(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListContactFlowsError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        ListContactFlowsError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(ListContactFlowsError::Throttling(err.msg))
+                    return RusotoError::service(ListContactFlowsError::Throttling(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListContactFlowsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1857,12 +1963,14 @@ impl fmt::Display for ListContactFlowsError {
             ListContactFlowsError::InvalidRequest(ref cause) => write!(f, "{}", cause),
             ListContactFlowsError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             ListContactFlowsError::Throttling(ref cause) => write!(f, "{}", cause),
+            ListContactFlowsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListContactFlowsError {}
 /// Errors returned by ListHoursOfOperations
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListHoursOfOperationsError {
     /// <p>Request processing failed due to an error or failure with the service.</p>
     InternalService(String),
@@ -1874,6 +1982,8 @@ pub enum ListHoursOfOperationsError {
     ResourceNotFound(String),
     /// <p>The throttling limit has been exceeded.</p>
     Throttling(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListHoursOfOperationsError {
@@ -1881,30 +1991,37 @@ impl ListHoursOfOperationsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalServiceException" => {
-                    return RusotoError::Service(ListHoursOfOperationsError::InternalService(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListHoursOfOperationsError::InternalService(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(ListHoursOfOperationsError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListHoursOfOperationsError::InvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(ListHoursOfOperationsError::InvalidRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListHoursOfOperationsError::InvalidRequest(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListHoursOfOperationsError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListHoursOfOperationsError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(ListHoursOfOperationsError::Throttling(err.msg))
+                    return RusotoError::service(
+                        ListHoursOfOperationsError::Throttling(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListHoursOfOperationsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1919,12 +2036,14 @@ impl fmt::Display for ListHoursOfOperationsError {
             ListHoursOfOperationsError::InvalidRequest(ref cause) => write!(f, "{}", cause),
             ListHoursOfOperationsError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             ListHoursOfOperationsError::Throttling(ref cause) => write!(f, "{}", cause),
+            ListHoursOfOperationsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListHoursOfOperationsError {}
 /// Errors returned by ListPhoneNumbers
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListPhoneNumbersError {
     /// <p>Request processing failed due to an error or failure with the service.</p>
     InternalService(String),
@@ -1936,6 +2055,8 @@ pub enum ListPhoneNumbersError {
     ResourceNotFound(String),
     /// <p>The throttling limit has been exceeded.</p>
     Throttling(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListPhoneNumbersError {
@@ -1943,22 +2064,34 @@ impl ListPhoneNumbersError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalServiceException" => {
-                    return RusotoError::Service(ListPhoneNumbersError::InternalService(err.msg))
+                    return RusotoError::service(
+                        ListPhoneNumbersError::InternalService(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(ListPhoneNumbersError::InvalidParameter(err.msg))
+                    return RusotoError::service(
+                        ListPhoneNumbersError::InvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(ListPhoneNumbersError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        ListPhoneNumbersError::InvalidRequest(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListPhoneNumbersError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        ListPhoneNumbersError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(ListPhoneNumbersError::Throttling(err.msg))
+                    return RusotoError::service(ListPhoneNumbersError::Throttling(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListPhoneNumbersError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1973,12 +2106,14 @@ impl fmt::Display for ListPhoneNumbersError {
             ListPhoneNumbersError::InvalidRequest(ref cause) => write!(f, "{}", cause),
             ListPhoneNumbersError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             ListPhoneNumbersError::Throttling(ref cause) => write!(f, "{}", cause),
+            ListPhoneNumbersError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListPhoneNumbersError {}
 /// Errors returned by ListQueues
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListQueuesError {
     /// <p>Request processing failed due to an error or failure with the service.</p>
     InternalService(String),
@@ -1990,6 +2125,8 @@ pub enum ListQueuesError {
     ResourceNotFound(String),
     /// <p>The throttling limit has been exceeded.</p>
     Throttling(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListQueuesError {
@@ -1997,22 +2134,22 @@ impl ListQueuesError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalServiceException" => {
-                    return RusotoError::Service(ListQueuesError::InternalService(err.msg))
+                    return RusotoError::service(ListQueuesError::InternalService(err.msg), &res)
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(ListQueuesError::InvalidParameter(err.msg))
+                    return RusotoError::service(ListQueuesError::InvalidParameter(err.msg), &res)
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(ListQueuesError::InvalidRequest(err.msg))
+                    return RusotoError::service(ListQueuesError::InvalidRequest(err.msg), &res)
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListQueuesError::ResourceNotFound(err.msg))
+                    return RusotoError::service(ListQueuesError::ResourceNotFound(err.msg), &res)
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(ListQueuesError::Throttling(err.msg))
+                    return RusotoError::service(ListQueuesError::Throttling(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListQueuesError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2027,12 +2164,14 @@ impl fmt::Display for ListQueuesError {
             ListQueuesError::InvalidRequest(ref cause) => write!(f, "{}", cause),
             ListQueuesError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             ListQueuesError::Throttling(ref cause) => write!(f, "{}", cause),
+            ListQueuesError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListQueuesError {}
 /// Errors returned by ListRoutingProfiles
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListRoutingProfilesError {
     /// <p>Request processing failed due to an error or failure with the service.</p>
     InternalService(String),
@@ -2044,6 +2183,8 @@ pub enum ListRoutingProfilesError {
     ResourceNotFound(String),
     /// <p>The throttling limit has been exceeded.</p>
     Throttling(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListRoutingProfilesError {
@@ -2051,26 +2192,37 @@ impl ListRoutingProfilesError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalServiceException" => {
-                    return RusotoError::Service(ListRoutingProfilesError::InternalService(err.msg))
+                    return RusotoError::service(
+                        ListRoutingProfilesError::InternalService(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(ListRoutingProfilesError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListRoutingProfilesError::InvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(ListRoutingProfilesError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        ListRoutingProfilesError::InvalidRequest(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListRoutingProfilesError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListRoutingProfilesError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(ListRoutingProfilesError::Throttling(err.msg))
+                    return RusotoError::service(
+                        ListRoutingProfilesError::Throttling(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListRoutingProfilesError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2085,12 +2237,14 @@ impl fmt::Display for ListRoutingProfilesError {
             ListRoutingProfilesError::InvalidRequest(ref cause) => write!(f, "{}", cause),
             ListRoutingProfilesError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             ListRoutingProfilesError::Throttling(ref cause) => write!(f, "{}", cause),
+            ListRoutingProfilesError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListRoutingProfilesError {}
 /// Errors returned by ListSecurityProfiles
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListSecurityProfilesError {
     /// <p>Request processing failed due to an error or failure with the service.</p>
     InternalService(String),
@@ -2102,6 +2256,8 @@ pub enum ListSecurityProfilesError {
     ResourceNotFound(String),
     /// <p>The throttling limit has been exceeded.</p>
     Throttling(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListSecurityProfilesError {
@@ -2109,28 +2265,37 @@ impl ListSecurityProfilesError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalServiceException" => {
-                    return RusotoError::Service(ListSecurityProfilesError::InternalService(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListSecurityProfilesError::InternalService(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(ListSecurityProfilesError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListSecurityProfilesError::InvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(ListSecurityProfilesError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        ListSecurityProfilesError::InvalidRequest(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListSecurityProfilesError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListSecurityProfilesError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(ListSecurityProfilesError::Throttling(err.msg))
+                    return RusotoError::service(
+                        ListSecurityProfilesError::Throttling(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListSecurityProfilesError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2145,12 +2310,14 @@ impl fmt::Display for ListSecurityProfilesError {
             ListSecurityProfilesError::InvalidRequest(ref cause) => write!(f, "{}", cause),
             ListSecurityProfilesError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             ListSecurityProfilesError::Throttling(ref cause) => write!(f, "{}", cause),
+            ListSecurityProfilesError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListSecurityProfilesError {}
 /// Errors returned by ListTagsForResource
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListTagsForResourceError {
     /// <p>Request processing failed due to an error or failure with the service.</p>
     InternalService(String),
@@ -2162,6 +2329,8 @@ pub enum ListTagsForResourceError {
     ResourceNotFound(String),
     /// <p>The throttling limit has been exceeded.</p>
     Throttling(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListTagsForResourceError {
@@ -2169,26 +2338,37 @@ impl ListTagsForResourceError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalServiceException" => {
-                    return RusotoError::Service(ListTagsForResourceError::InternalService(err.msg))
+                    return RusotoError::service(
+                        ListTagsForResourceError::InternalService(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(ListTagsForResourceError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListTagsForResourceError::InvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(ListTagsForResourceError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        ListTagsForResourceError::InvalidRequest(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListTagsForResourceError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListTagsForResourceError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(ListTagsForResourceError::Throttling(err.msg))
+                    return RusotoError::service(
+                        ListTagsForResourceError::Throttling(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListTagsForResourceError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2203,12 +2383,14 @@ impl fmt::Display for ListTagsForResourceError {
             ListTagsForResourceError::InvalidRequest(ref cause) => write!(f, "{}", cause),
             ListTagsForResourceError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             ListTagsForResourceError::Throttling(ref cause) => write!(f, "{}", cause),
+            ListTagsForResourceError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListTagsForResourceError {}
 /// Errors returned by ListUserHierarchyGroups
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListUserHierarchyGroupsError {
     /// <p>Request processing failed due to an error or failure with the service.</p>
     InternalService(String),
@@ -2220,6 +2402,8 @@ pub enum ListUserHierarchyGroupsError {
     ResourceNotFound(String),
     /// <p>The throttling limit has been exceeded.</p>
     Throttling(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListUserHierarchyGroupsError {
@@ -2227,30 +2411,37 @@ impl ListUserHierarchyGroupsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalServiceException" => {
-                    return RusotoError::Service(ListUserHierarchyGroupsError::InternalService(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListUserHierarchyGroupsError::InternalService(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(ListUserHierarchyGroupsError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListUserHierarchyGroupsError::InvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(ListUserHierarchyGroupsError::InvalidRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListUserHierarchyGroupsError::InvalidRequest(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListUserHierarchyGroupsError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListUserHierarchyGroupsError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(ListUserHierarchyGroupsError::Throttling(err.msg))
+                    return RusotoError::service(
+                        ListUserHierarchyGroupsError::Throttling(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListUserHierarchyGroupsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2265,12 +2456,14 @@ impl fmt::Display for ListUserHierarchyGroupsError {
             ListUserHierarchyGroupsError::InvalidRequest(ref cause) => write!(f, "{}", cause),
             ListUserHierarchyGroupsError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             ListUserHierarchyGroupsError::Throttling(ref cause) => write!(f, "{}", cause),
+            ListUserHierarchyGroupsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListUserHierarchyGroupsError {}
 /// Errors returned by ListUsers
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListUsersError {
     /// <p>Request processing failed due to an error or failure with the service.</p>
     InternalService(String),
@@ -2282,6 +2475,8 @@ pub enum ListUsersError {
     ResourceNotFound(String),
     /// <p>The throttling limit has been exceeded.</p>
     Throttling(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListUsersError {
@@ -2289,22 +2484,22 @@ impl ListUsersError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalServiceException" => {
-                    return RusotoError::Service(ListUsersError::InternalService(err.msg))
+                    return RusotoError::service(ListUsersError::InternalService(err.msg), &res)
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(ListUsersError::InvalidParameter(err.msg))
+                    return RusotoError::service(ListUsersError::InvalidParameter(err.msg), &res)
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(ListUsersError::InvalidRequest(err.msg))
+                    return RusotoError::service(ListUsersError::InvalidRequest(err.msg), &res)
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListUsersError::ResourceNotFound(err.msg))
+                    return RusotoError::service(ListUsersError::ResourceNotFound(err.msg), &res)
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(ListUsersError::Throttling(err.msg))
+                    return RusotoError::service(ListUsersError::Throttling(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListUsersError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2319,12 +2514,14 @@ impl fmt::Display for ListUsersError {
             ListUsersError::InvalidRequest(ref cause) => write!(f, "{}", cause),
             ListUsersError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             ListUsersError::Throttling(ref cause) => write!(f, "{}", cause),
+            ListUsersError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListUsersError {}
 /// Errors returned by StartChatContact
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum StartChatContactError {
     /// <p>Request processing failed due to an error or failure with the service.</p>
     InternalService(String),
@@ -2336,6 +2533,8 @@ pub enum StartChatContactError {
     LimitExceeded(String),
     /// <p>The specified resource was not found.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl StartChatContactError {
@@ -2343,22 +2542,37 @@ impl StartChatContactError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalServiceException" => {
-                    return RusotoError::Service(StartChatContactError::InternalService(err.msg))
+                    return RusotoError::service(
+                        StartChatContactError::InternalService(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(StartChatContactError::InvalidParameter(err.msg))
+                    return RusotoError::service(
+                        StartChatContactError::InvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(StartChatContactError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        StartChatContactError::InvalidRequest(err.msg),
+                        &res,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(StartChatContactError::LimitExceeded(err.msg))
+                    return RusotoError::service(
+                        StartChatContactError::LimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(StartChatContactError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        StartChatContactError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(StartChatContactError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2373,12 +2587,14 @@ impl fmt::Display for StartChatContactError {
             StartChatContactError::InvalidRequest(ref cause) => write!(f, "{}", cause),
             StartChatContactError::LimitExceeded(ref cause) => write!(f, "{}", cause),
             StartChatContactError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            StartChatContactError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for StartChatContactError {}
 /// Errors returned by StartOutboundVoiceContact
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum StartOutboundVoiceContactError {
     /// <p>Outbound calls to the destination number are not allowed.</p>
     DestinationNotAllowed(String),
@@ -2394,6 +2610,8 @@ pub enum StartOutboundVoiceContactError {
     OutboundContactNotPermitted(String),
     /// <p>The specified resource was not found.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl StartOutboundVoiceContactError {
@@ -2401,42 +2619,51 @@ impl StartOutboundVoiceContactError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "DestinationNotAllowedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         StartOutboundVoiceContactError::DestinationNotAllowed(err.msg),
+                        &res,
                     )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(StartOutboundVoiceContactError::InternalService(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        StartOutboundVoiceContactError::InternalService(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(StartOutboundVoiceContactError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        StartOutboundVoiceContactError::InvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(StartOutboundVoiceContactError::InvalidRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        StartOutboundVoiceContactError::InvalidRequest(err.msg),
+                        &res,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(StartOutboundVoiceContactError::LimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        StartOutboundVoiceContactError::LimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "OutboundContactNotPermittedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         StartOutboundVoiceContactError::OutboundContactNotPermitted(err.msg),
+                        &res,
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(StartOutboundVoiceContactError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        StartOutboundVoiceContactError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(StartOutboundVoiceContactError::Unhandled, &res)
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -2457,12 +2684,14 @@ impl fmt::Display for StartOutboundVoiceContactError {
                 write!(f, "{}", cause)
             }
             StartOutboundVoiceContactError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            StartOutboundVoiceContactError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for StartOutboundVoiceContactError {}
 /// Errors returned by StopContact
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum StopContactError {
     /// <p>The contact with the specified ID is not active or does not exist.</p>
     ContactNotFound(String),
@@ -2474,6 +2703,8 @@ pub enum StopContactError {
     InvalidRequest(String),
     /// <p>The specified resource was not found.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl StopContactError {
@@ -2481,22 +2712,22 @@ impl StopContactError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ContactNotFoundException" => {
-                    return RusotoError::Service(StopContactError::ContactNotFound(err.msg))
+                    return RusotoError::service(StopContactError::ContactNotFound(err.msg), &res)
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(StopContactError::InternalService(err.msg))
+                    return RusotoError::service(StopContactError::InternalService(err.msg), &res)
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(StopContactError::InvalidParameter(err.msg))
+                    return RusotoError::service(StopContactError::InvalidParameter(err.msg), &res)
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(StopContactError::InvalidRequest(err.msg))
+                    return RusotoError::service(StopContactError::InvalidRequest(err.msg), &res)
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(StopContactError::ResourceNotFound(err.msg))
+                    return RusotoError::service(StopContactError::ResourceNotFound(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(StopContactError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2511,12 +2742,14 @@ impl fmt::Display for StopContactError {
             StopContactError::InvalidParameter(ref cause) => write!(f, "{}", cause),
             StopContactError::InvalidRequest(ref cause) => write!(f, "{}", cause),
             StopContactError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            StopContactError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for StopContactError {}
 /// Errors returned by TagResource
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum TagResourceError {
     /// <p>Request processing failed due to an error or failure with the service.</p>
     InternalService(String),
@@ -2528,6 +2761,8 @@ pub enum TagResourceError {
     ResourceNotFound(String),
     /// <p>The throttling limit has been exceeded.</p>
     Throttling(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl TagResourceError {
@@ -2535,22 +2770,22 @@ impl TagResourceError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalServiceException" => {
-                    return RusotoError::Service(TagResourceError::InternalService(err.msg))
+                    return RusotoError::service(TagResourceError::InternalService(err.msg), &res)
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(TagResourceError::InvalidParameter(err.msg))
+                    return RusotoError::service(TagResourceError::InvalidParameter(err.msg), &res)
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(TagResourceError::InvalidRequest(err.msg))
+                    return RusotoError::service(TagResourceError::InvalidRequest(err.msg), &res)
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(TagResourceError::ResourceNotFound(err.msg))
+                    return RusotoError::service(TagResourceError::ResourceNotFound(err.msg), &res)
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(TagResourceError::Throttling(err.msg))
+                    return RusotoError::service(TagResourceError::Throttling(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(TagResourceError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2565,12 +2800,14 @@ impl fmt::Display for TagResourceError {
             TagResourceError::InvalidRequest(ref cause) => write!(f, "{}", cause),
             TagResourceError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             TagResourceError::Throttling(ref cause) => write!(f, "{}", cause),
+            TagResourceError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for TagResourceError {}
 /// Errors returned by UntagResource
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UntagResourceError {
     /// <p>Request processing failed due to an error or failure with the service.</p>
     InternalService(String),
@@ -2582,6 +2819,8 @@ pub enum UntagResourceError {
     ResourceNotFound(String),
     /// <p>The throttling limit has been exceeded.</p>
     Throttling(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UntagResourceError {
@@ -2589,22 +2828,28 @@ impl UntagResourceError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalServiceException" => {
-                    return RusotoError::Service(UntagResourceError::InternalService(err.msg))
+                    return RusotoError::service(UntagResourceError::InternalService(err.msg), &res)
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(UntagResourceError::InvalidParameter(err.msg))
+                    return RusotoError::service(
+                        UntagResourceError::InvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(UntagResourceError::InvalidRequest(err.msg))
+                    return RusotoError::service(UntagResourceError::InvalidRequest(err.msg), &res)
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UntagResourceError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        UntagResourceError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(UntagResourceError::Throttling(err.msg))
+                    return RusotoError::service(UntagResourceError::Throttling(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UntagResourceError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2619,12 +2864,14 @@ impl fmt::Display for UntagResourceError {
             UntagResourceError::InvalidRequest(ref cause) => write!(f, "{}", cause),
             UntagResourceError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             UntagResourceError::Throttling(ref cause) => write!(f, "{}", cause),
+            UntagResourceError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for UntagResourceError {}
 /// Errors returned by UpdateContactAttributes
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdateContactAttributesError {
     /// <p>Request processing failed due to an error or failure with the service.</p>
     InternalService(String),
@@ -2634,6 +2881,8 @@ pub enum UpdateContactAttributesError {
     InvalidRequest(String),
     /// <p>The specified resource was not found.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdateContactAttributesError {
@@ -2641,27 +2890,31 @@ impl UpdateContactAttributesError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalServiceException" => {
-                    return RusotoError::Service(UpdateContactAttributesError::InternalService(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateContactAttributesError::InternalService(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(UpdateContactAttributesError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateContactAttributesError::InvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(UpdateContactAttributesError::InvalidRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateContactAttributesError::InvalidRequest(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateContactAttributesError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateContactAttributesError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UpdateContactAttributesError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2675,12 +2928,14 @@ impl fmt::Display for UpdateContactAttributesError {
             UpdateContactAttributesError::InvalidParameter(ref cause) => write!(f, "{}", cause),
             UpdateContactAttributesError::InvalidRequest(ref cause) => write!(f, "{}", cause),
             UpdateContactAttributesError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            UpdateContactAttributesError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for UpdateContactAttributesError {}
 /// Errors returned by UpdateUserHierarchy
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdateUserHierarchyError {
     /// <p>Request processing failed due to an error or failure with the service.</p>
     InternalService(String),
@@ -2692,6 +2947,8 @@ pub enum UpdateUserHierarchyError {
     ResourceNotFound(String),
     /// <p>The throttling limit has been exceeded.</p>
     Throttling(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdateUserHierarchyError {
@@ -2699,26 +2956,37 @@ impl UpdateUserHierarchyError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalServiceException" => {
-                    return RusotoError::Service(UpdateUserHierarchyError::InternalService(err.msg))
+                    return RusotoError::service(
+                        UpdateUserHierarchyError::InternalService(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(UpdateUserHierarchyError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateUserHierarchyError::InvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(UpdateUserHierarchyError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        UpdateUserHierarchyError::InvalidRequest(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateUserHierarchyError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateUserHierarchyError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(UpdateUserHierarchyError::Throttling(err.msg))
+                    return RusotoError::service(
+                        UpdateUserHierarchyError::Throttling(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UpdateUserHierarchyError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2733,12 +3001,14 @@ impl fmt::Display for UpdateUserHierarchyError {
             UpdateUserHierarchyError::InvalidRequest(ref cause) => write!(f, "{}", cause),
             UpdateUserHierarchyError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             UpdateUserHierarchyError::Throttling(ref cause) => write!(f, "{}", cause),
+            UpdateUserHierarchyError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for UpdateUserHierarchyError {}
 /// Errors returned by UpdateUserIdentityInfo
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdateUserIdentityInfoError {
     /// <p>Request processing failed due to an error or failure with the service.</p>
     InternalService(String),
@@ -2750,6 +3020,8 @@ pub enum UpdateUserIdentityInfoError {
     ResourceNotFound(String),
     /// <p>The throttling limit has been exceeded.</p>
     Throttling(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdateUserIdentityInfoError {
@@ -2757,30 +3029,37 @@ impl UpdateUserIdentityInfoError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalServiceException" => {
-                    return RusotoError::Service(UpdateUserIdentityInfoError::InternalService(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateUserIdentityInfoError::InternalService(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(UpdateUserIdentityInfoError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateUserIdentityInfoError::InvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(UpdateUserIdentityInfoError::InvalidRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateUserIdentityInfoError::InvalidRequest(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateUserIdentityInfoError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateUserIdentityInfoError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(UpdateUserIdentityInfoError::Throttling(err.msg))
+                    return RusotoError::service(
+                        UpdateUserIdentityInfoError::Throttling(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UpdateUserIdentityInfoError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2795,12 +3074,14 @@ impl fmt::Display for UpdateUserIdentityInfoError {
             UpdateUserIdentityInfoError::InvalidRequest(ref cause) => write!(f, "{}", cause),
             UpdateUserIdentityInfoError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             UpdateUserIdentityInfoError::Throttling(ref cause) => write!(f, "{}", cause),
+            UpdateUserIdentityInfoError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for UpdateUserIdentityInfoError {}
 /// Errors returned by UpdateUserPhoneConfig
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdateUserPhoneConfigError {
     /// <p>Request processing failed due to an error or failure with the service.</p>
     InternalService(String),
@@ -2812,6 +3093,8 @@ pub enum UpdateUserPhoneConfigError {
     ResourceNotFound(String),
     /// <p>The throttling limit has been exceeded.</p>
     Throttling(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdateUserPhoneConfigError {
@@ -2819,30 +3102,37 @@ impl UpdateUserPhoneConfigError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalServiceException" => {
-                    return RusotoError::Service(UpdateUserPhoneConfigError::InternalService(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateUserPhoneConfigError::InternalService(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(UpdateUserPhoneConfigError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateUserPhoneConfigError::InvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(UpdateUserPhoneConfigError::InvalidRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateUserPhoneConfigError::InvalidRequest(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateUserPhoneConfigError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateUserPhoneConfigError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(UpdateUserPhoneConfigError::Throttling(err.msg))
+                    return RusotoError::service(
+                        UpdateUserPhoneConfigError::Throttling(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UpdateUserPhoneConfigError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2857,12 +3147,14 @@ impl fmt::Display for UpdateUserPhoneConfigError {
             UpdateUserPhoneConfigError::InvalidRequest(ref cause) => write!(f, "{}", cause),
             UpdateUserPhoneConfigError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             UpdateUserPhoneConfigError::Throttling(ref cause) => write!(f, "{}", cause),
+            UpdateUserPhoneConfigError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for UpdateUserPhoneConfigError {}
 /// Errors returned by UpdateUserRoutingProfile
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdateUserRoutingProfileError {
     /// <p>Request processing failed due to an error or failure with the service.</p>
     InternalService(String),
@@ -2874,6 +3166,8 @@ pub enum UpdateUserRoutingProfileError {
     ResourceNotFound(String),
     /// <p>The throttling limit has been exceeded.</p>
     Throttling(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdateUserRoutingProfileError {
@@ -2881,30 +3175,37 @@ impl UpdateUserRoutingProfileError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalServiceException" => {
-                    return RusotoError::Service(UpdateUserRoutingProfileError::InternalService(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateUserRoutingProfileError::InternalService(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(UpdateUserRoutingProfileError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateUserRoutingProfileError::InvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(UpdateUserRoutingProfileError::InvalidRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateUserRoutingProfileError::InvalidRequest(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateUserRoutingProfileError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateUserRoutingProfileError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(UpdateUserRoutingProfileError::Throttling(err.msg))
+                    return RusotoError::service(
+                        UpdateUserRoutingProfileError::Throttling(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UpdateUserRoutingProfileError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2919,12 +3220,14 @@ impl fmt::Display for UpdateUserRoutingProfileError {
             UpdateUserRoutingProfileError::InvalidRequest(ref cause) => write!(f, "{}", cause),
             UpdateUserRoutingProfileError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             UpdateUserRoutingProfileError::Throttling(ref cause) => write!(f, "{}", cause),
+            UpdateUserRoutingProfileError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for UpdateUserRoutingProfileError {}
 /// Errors returned by UpdateUserSecurityProfiles
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdateUserSecurityProfilesError {
     /// <p>Request processing failed due to an error or failure with the service.</p>
     InternalService(String),
@@ -2936,6 +3239,8 @@ pub enum UpdateUserSecurityProfilesError {
     ResourceNotFound(String),
     /// <p>The throttling limit has been exceeded.</p>
     Throttling(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdateUserSecurityProfilesError {
@@ -2945,32 +3250,39 @@ impl UpdateUserSecurityProfilesError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalServiceException" => {
-                    return RusotoError::Service(UpdateUserSecurityProfilesError::InternalService(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateUserSecurityProfilesError::InternalService(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(UpdateUserSecurityProfilesError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateUserSecurityProfilesError::InvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(UpdateUserSecurityProfilesError::InvalidRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateUserSecurityProfilesError::InvalidRequest(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateUserSecurityProfilesError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateUserSecurityProfilesError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(UpdateUserSecurityProfilesError::Throttling(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateUserSecurityProfilesError::Throttling(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(UpdateUserSecurityProfilesError::Unhandled, &res)
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -2985,6 +3297,7 @@ impl fmt::Display for UpdateUserSecurityProfilesError {
             UpdateUserSecurityProfilesError::InvalidRequest(ref cause) => write!(f, "{}", cause),
             UpdateUserSecurityProfilesError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             UpdateUserSecurityProfilesError::Throttling(ref cause) => write!(f, "{}", cause),
+            UpdateUserSecurityProfilesError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
@@ -3180,6 +3493,7 @@ impl ConnectClient {
     /// Creates a client backed by the default tokio event loop.
     ///
     /// The client will use the default credentials provider and tls client.
+    #[cfg(not(target_arch = "wasm32"))]
     pub fn new(region: region::Region) -> ConnectClient {
         ConnectClient {
             client: Client::shared(),
@@ -3205,45 +3519,119 @@ impl ConnectClient {
     pub fn new_with_client(client: Client, region: region::Region) -> ConnectClient {
         ConnectClient { client, region }
     }
+
+    /// Returns a builder of a client, setting any of its region, credentials provider,
+    /// dispatcher, retry policy, endpoint and timeouts.
+    pub fn builder() -> rusoto_core::ClientBuilder<ConnectClient> {
+        rusoto_core::ClientBuilder::new(ConnectClient::new_with_client)
+    }
+
+    /// Returns a clone of this client with the overrides of `config` applied to its
+    /// operations, sharing its connections.
+    pub fn customize(&self, config: &rusoto_core::OperationConfig) -> ConnectClient {
+        ConnectClient {
+            client: self.client.customize(config),
+            region: config.resolve_region(&self.region),
+        }
+    }
 }
 
-#[async_trait]
-impl Connect for ConnectClient {
-    /// <p>Creates a user account for the specified Amazon Connect instance.</p>
-    async fn create_user(
+impl ConnectClient {
+    /// Calls `create_user`, also returning the metadata of the response.
+    pub async fn create_user_with_metadata(
         &self,
         input: CreateUserRequest,
-    ) -> Result<CreateUserResponse, RusotoError<CreateUserError>> {
+    ) -> Result<(CreateUserResponse, ResponseMetadata), RusotoError<CreateUserError>> {
+        let request = self.build_create_user_request(input);
+
+        let mut response = self
+            .client
+            .sign_and_dispatch(request)
+            .await
+            .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
+        if response.status.is_success() {
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<CreateUserResponse, _>()?;
+
+            Ok((result, metadata))
+        } else {
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(CreateUserError::from_response(response))
+        }
+    }
+
+    /// Presigns the request `create_user` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_user(
+        &self,
+        presigner: &rusoto_core::Presigner,
+        input: CreateUserRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_user_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_user_request(&self, input: CreateUserRequest) -> SignedRequest {
         let request_uri = format!("/users/{instance_id}", instance_id = input.instance_id);
 
         let mut request = SignedRequest::new("PUT", "connect", &self.region, &request_uri);
+        request.set_operation("CreateUser");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `delete_user`, also returning the metadata of the response.
+    pub async fn delete_user_with_metadata(
+        &self,
+        input: DeleteUserRequest,
+    ) -> Result<((), ResponseMetadata), RusotoError<DeleteUserError>> {
+        let request = self.build_delete_user_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<CreateUserResponse, _>()?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = ::std::mem::drop(response);
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(CreateUserError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(DeleteUserError::from_response(response))
         }
     }
 
-    /// <p>Deletes a user account from the specified Amazon Connect instance.</p>
-    async fn delete_user(
+    /// Presigns the request `delete_user` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_user(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteUserRequest,
-    ) -> Result<(), RusotoError<DeleteUserError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_user_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_user_request(&self, input: DeleteUserRequest) -> SignedRequest {
         let request_uri = format!(
             "/users/{instance_id}/{user_id}",
             instance_id = input.instance_id,
@@ -3251,29 +3639,54 @@ impl Connect for ConnectClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "connect", &self.region, &request_uri);
+        request.set_operation("DeleteUser");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
+        request
+    }
+
+    /// Calls `describe_user`, also returning the metadata of the response.
+    pub async fn describe_user_with_metadata(
+        &self,
+        input: DescribeUserRequest,
+    ) -> Result<(DescribeUserResponse, ResponseMetadata), RusotoError<DescribeUserError>> {
+        let request = self.build_describe_user_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            let result = ::std::mem::drop(response);
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DescribeUserResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteUserError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(DescribeUserError::from_response(response))
         }
     }
 
-    /// <p>Describes the specified user account. You can find the instance ID in the console (it’s the final part of the ARN). The console does not display the user IDs. Instead, list the users and note the IDs provided in the output.</p>
-    async fn describe_user(
+    /// Presigns the request `describe_user` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_user(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeUserRequest,
-    ) -> Result<DescribeUserResponse, RusotoError<DescribeUserError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_user_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_user_request(&self, input: DescribeUserRequest) -> SignedRequest {
         let request_uri = format!(
             "/users/{instance_id}/{user_id}",
             instance_id = input.instance_id,
@@ -3281,31 +3694,60 @@ impl Connect for ConnectClient {
         );
 
         let mut request = SignedRequest::new("GET", "connect", &self.region, &request_uri);
+        request.set_operation("DescribeUser");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
+        request
+    }
+
+    /// Calls `describe_user_hierarchy_group`, also returning the metadata of the response.
+    pub async fn describe_user_hierarchy_group_with_metadata(
+        &self,
+        input: DescribeUserHierarchyGroupRequest,
+    ) -> Result<
+        (DescribeUserHierarchyGroupResponse, ResponseMetadata),
+        RusotoError<DescribeUserHierarchyGroupError>,
+    > {
+        let request = self.build_describe_user_hierarchy_group_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<DescribeUserResponse, _>()?;
+                .deserialize::<DescribeUserHierarchyGroupResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeUserError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(DescribeUserHierarchyGroupError::from_response(response))
         }
     }
 
-    /// <p>Describes the specified hierarchy group.</p>
-    async fn describe_user_hierarchy_group(
+    /// Presigns the request `describe_user_hierarchy_group` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_user_hierarchy_group(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeUserHierarchyGroupRequest,
-    ) -> Result<DescribeUserHierarchyGroupResponse, RusotoError<DescribeUserHierarchyGroupError>>
-    {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_user_hierarchy_group_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_user_hierarchy_group_request(
+        &self,
+        input: DescribeUserHierarchyGroupRequest,
+    ) -> SignedRequest {
         let request_uri = format!(
             "/user-hierarchy-groups/{instance_id}/{hierarchy_group_id}",
             hierarchy_group_id = input.hierarchy_group_id,
@@ -3313,63 +3755,120 @@ impl Connect for ConnectClient {
         );
 
         let mut request = SignedRequest::new("GET", "connect", &self.region, &request_uri);
+        request.set_operation("DescribeUserHierarchyGroup");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
+        request
+    }
+
+    /// Calls `describe_user_hierarchy_structure`, also returning the metadata of the response.
+    pub async fn describe_user_hierarchy_structure_with_metadata(
+        &self,
+        input: DescribeUserHierarchyStructureRequest,
+    ) -> Result<
+        (DescribeUserHierarchyStructureResponse, ResponseMetadata),
+        RusotoError<DescribeUserHierarchyStructureError>,
+    > {
+        let request = self.build_describe_user_hierarchy_structure_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<DescribeUserHierarchyGroupResponse, _>()?;
+                .deserialize::<DescribeUserHierarchyStructureResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeUserHierarchyGroupError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(DescribeUserHierarchyStructureError::from_response(response))
         }
     }
 
-    /// <p>Describes the hierarchy structure of the specified Amazon Connect instance.</p>
-    async fn describe_user_hierarchy_structure(
+    /// Presigns the request `describe_user_hierarchy_structure` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_user_hierarchy_structure(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeUserHierarchyStructureRequest,
-    ) -> Result<
-        DescribeUserHierarchyStructureResponse,
-        RusotoError<DescribeUserHierarchyStructureError>,
-    > {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_user_hierarchy_structure_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_user_hierarchy_structure_request(
+        &self,
+        input: DescribeUserHierarchyStructureRequest,
+    ) -> SignedRequest {
         let request_uri = format!(
             "/user-hierarchy-structure/{instance_id}",
             instance_id = input.instance_id
         );
 
         let mut request = SignedRequest::new("GET", "connect", &self.region, &request_uri);
+        request.set_operation("DescribeUserHierarchyStructure");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
+        request
+    }
+
+    /// Calls `get_contact_attributes`, also returning the metadata of the response.
+    pub async fn get_contact_attributes_with_metadata(
+        &self,
+        input: GetContactAttributesRequest,
+    ) -> Result<
+        (GetContactAttributesResponse, ResponseMetadata),
+        RusotoError<GetContactAttributesError>,
+    > {
+        let request = self.build_get_contact_attributes_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<DescribeUserHierarchyStructureResponse, _>()?;
+                .deserialize::<GetContactAttributesResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeUserHierarchyStructureError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(GetContactAttributesError::from_response(response))
         }
     }
 
-    /// <p>Retrieves the contact attributes for the specified contact.</p>
-    async fn get_contact_attributes(
+    /// Presigns the request `get_contact_attributes` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_contact_attributes(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetContactAttributesRequest,
-    ) -> Result<GetContactAttributesResponse, RusotoError<GetContactAttributesError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_contact_attributes_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_contact_attributes_request(
+        &self,
+        input: GetContactAttributesRequest,
+    ) -> SignedRequest {
         let request_uri = format!(
             "/contact/attributes/{instance_id}/{initial_contact_id}",
             initial_contact_id = input.initial_contact_id,
@@ -3377,132 +3876,240 @@ impl Connect for ConnectClient {
         );
 
         let mut request = SignedRequest::new("GET", "connect", &self.region, &request_uri);
+        request.set_operation("GetContactAttributes");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
+        request
+    }
+
+    /// Calls `get_current_metric_data`, also returning the metadata of the response.
+    pub async fn get_current_metric_data_with_metadata(
+        &self,
+        input: GetCurrentMetricDataRequest,
+    ) -> Result<
+        (GetCurrentMetricDataResponse, ResponseMetadata),
+        RusotoError<GetCurrentMetricDataError>,
+    > {
+        let request = self.build_get_current_metric_data_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<GetContactAttributesResponse, _>()?;
+                .deserialize::<GetCurrentMetricDataResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(GetContactAttributesError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(GetCurrentMetricDataError::from_response(response))
         }
     }
 
-    /// <p>Gets the real-time metric data from the specified Amazon Connect instance.</p> <p>For more information, see <a href="https://docs.aws.amazon.com/connect/latest/adminguide/real-time-metrics-reports.html">Real-time Metrics Reports</a> in the <i>Amazon Connect Administrator Guide</i>.</p>
-    async fn get_current_metric_data(
+    /// Presigns the request `get_current_metric_data` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_current_metric_data(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetCurrentMetricDataRequest,
-    ) -> Result<GetCurrentMetricDataResponse, RusotoError<GetCurrentMetricDataError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_current_metric_data_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_current_metric_data_request(
+        &self,
+        input: GetCurrentMetricDataRequest,
+    ) -> SignedRequest {
         let request_uri = format!(
             "/metrics/current/{instance_id}",
             instance_id = input.instance_id
         );
 
         let mut request = SignedRequest::new("POST", "connect", &self.region, &request_uri);
+        request.set_operation("GetCurrentMetricData");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `get_federation_token`, also returning the metadata of the response.
+    pub async fn get_federation_token_with_metadata(
+        &self,
+        input: GetFederationTokenRequest,
+    ) -> Result<(GetFederationTokenResponse, ResponseMetadata), RusotoError<GetFederationTokenError>>
+    {
+        let request = self.build_get_federation_token_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<GetCurrentMetricDataResponse, _>()?;
+                .deserialize::<GetFederationTokenResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(GetCurrentMetricDataError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(GetFederationTokenError::from_response(response))
         }
     }
 
-    /// <p>Retrieves a token for federation.</p>
-    async fn get_federation_token(
+    /// Presigns the request `get_federation_token` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_federation_token(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetFederationTokenRequest,
-    ) -> Result<GetFederationTokenResponse, RusotoError<GetFederationTokenError>> {
-        let request_uri = format!(
-            "/user/federate/{instance_id}",
-            instance_id = input.instance_id
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_federation_token_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_federation_token_request(
+        &self,
+        input: GetFederationTokenRequest,
+    ) -> SignedRequest {
+        let request_uri = format!(
+            "/user/federate/{instance_id}",
+            instance_id = input.instance_id
         );
 
         let mut request = SignedRequest::new("GET", "connect", &self.region, &request_uri);
+        request.set_operation("GetFederationToken");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
+        request
+    }
+
+    /// Calls `get_metric_data`, also returning the metadata of the response.
+    pub async fn get_metric_data_with_metadata(
+        &self,
+        input: GetMetricDataRequest,
+    ) -> Result<(GetMetricDataResponse, ResponseMetadata), RusotoError<GetMetricDataError>> {
+        let request = self.build_get_metric_data_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<GetFederationTokenResponse, _>()?;
+                .deserialize::<GetMetricDataResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(GetFederationTokenError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(GetMetricDataError::from_response(response))
         }
     }
 
-    /// <p>Gets historical metric data from the specified Amazon Connect instance.</p> <p>For more information, see <a href="https://docs.aws.amazon.com/connect/latest/adminguide/historical-metrics.html">Historical Metrics Reports</a> in the <i>Amazon Connect Administrator Guide</i>.</p>
-    async fn get_metric_data(
+    /// Presigns the request `get_metric_data` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_metric_data(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetMetricDataRequest,
-    ) -> Result<GetMetricDataResponse, RusotoError<GetMetricDataError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_metric_data_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_metric_data_request(&self, input: GetMetricDataRequest) -> SignedRequest {
         let request_uri = format!(
             "/metrics/historical/{instance_id}",
             instance_id = input.instance_id
         );
 
         let mut request = SignedRequest::new("POST", "connect", &self.region, &request_uri);
+        request.set_operation("GetMetricData");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `list_contact_flows`, also returning the metadata of the response.
+    pub async fn list_contact_flows_with_metadata(
+        &self,
+        input: ListContactFlowsRequest,
+    ) -> Result<(ListContactFlowsResponse, ResponseMetadata), RusotoError<ListContactFlowsError>>
+    {
+        let request = self.build_list_contact_flows_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<GetMetricDataResponse, _>()?;
+                .deserialize::<ListContactFlowsResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(GetMetricDataError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(ListContactFlowsError::from_response(response))
         }
     }
 
-    /// <p>Provides information about the contact flows for the specified Amazon Connect instance.</p>
-    async fn list_contact_flows(
+    /// Presigns the request `list_contact_flows` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_contact_flows(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListContactFlowsRequest,
-    ) -> Result<ListContactFlowsResponse, RusotoError<ListContactFlowsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_contact_flows_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_contact_flows_request(&self, input: ListContactFlowsRequest) -> SignedRequest {
         let request_uri = format!(
             "/contact-flows-summary/{instance_id}",
             instance_id = input.instance_id
         );
 
         let mut request = SignedRequest::new("GET", "connect", &self.region, &request_uri);
+        request.set_operation("ListContactFlows");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -3519,34 +4126,64 @@ impl Connect for ConnectClient {
         }
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `list_hours_of_operations`, also returning the metadata of the response.
+    pub async fn list_hours_of_operations_with_metadata(
+        &self,
+        input: ListHoursOfOperationsRequest,
+    ) -> Result<
+        (ListHoursOfOperationsResponse, ResponseMetadata),
+        RusotoError<ListHoursOfOperationsError>,
+    > {
+        let request = self.build_list_hours_of_operations_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListContactFlowsResponse, _>()?;
+                .deserialize::<ListHoursOfOperationsResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(ListContactFlowsError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(ListHoursOfOperationsError::from_response(response))
         }
     }
 
-    /// <p>Provides information about the hours of operation for the specified Amazon Connect instance.</p>
-    async fn list_hours_of_operations(
+    /// Presigns the request `list_hours_of_operations` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_hours_of_operations(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListHoursOfOperationsRequest,
-    ) -> Result<ListHoursOfOperationsResponse, RusotoError<ListHoursOfOperationsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_hours_of_operations_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_hours_of_operations_request(
+        &self,
+        input: ListHoursOfOperationsRequest,
+    ) -> SignedRequest {
         let request_uri = format!(
             "/hours-of-operations-summary/{instance_id}",
             instance_id = input.instance_id
         );
 
         let mut request = SignedRequest::new("GET", "connect", &self.region, &request_uri);
+        request.set_operation("ListHoursOfOperations");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -3558,34 +4195,59 @@ impl Connect for ConnectClient {
         }
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `list_phone_numbers`, also returning the metadata of the response.
+    pub async fn list_phone_numbers_with_metadata(
+        &self,
+        input: ListPhoneNumbersRequest,
+    ) -> Result<(ListPhoneNumbersResponse, ResponseMetadata), RusotoError<ListPhoneNumbersError>>
+    {
+        let request = self.build_list_phone_numbers_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListHoursOfOperationsResponse, _>()?;
+                .deserialize::<ListPhoneNumbersResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(ListHoursOfOperationsError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(ListPhoneNumbersError::from_response(response))
         }
     }
 
-    /// <p>Provides information about the phone numbers for the specified Amazon Connect instance.</p>
-    async fn list_phone_numbers(
+    /// Presigns the request `list_phone_numbers` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_phone_numbers(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListPhoneNumbersRequest,
-    ) -> Result<ListPhoneNumbersResponse, RusotoError<ListPhoneNumbersError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_phone_numbers_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_phone_numbers_request(&self, input: ListPhoneNumbersRequest) -> SignedRequest {
         let request_uri = format!(
             "/phone-numbers-summary/{instance_id}",
             instance_id = input.instance_id
         );
 
         let mut request = SignedRequest::new("GET", "connect", &self.region, &request_uri);
+        request.set_operation("ListPhoneNumbers");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -3607,34 +4269,58 @@ impl Connect for ConnectClient {
         }
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `list_queues`, also returning the metadata of the response.
+    pub async fn list_queues_with_metadata(
+        &self,
+        input: ListQueuesRequest,
+    ) -> Result<(ListQueuesResponse, ResponseMetadata), RusotoError<ListQueuesError>> {
+        let request = self.build_list_queues_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListPhoneNumbersResponse, _>()?;
+                .deserialize::<ListQueuesResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(ListPhoneNumbersError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(ListQueuesError::from_response(response))
         }
     }
 
-    /// <p>Provides information about the queues for the specified Amazon Connect instance.</p>
-    async fn list_queues(
+    /// Presigns the request `list_queues` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_queues(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListQueuesRequest,
-    ) -> Result<ListQueuesResponse, RusotoError<ListQueuesError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_queues_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_queues_request(&self, input: ListQueuesRequest) -> SignedRequest {
         let request_uri = format!(
             "/queues-summary/{instance_id}",
             instance_id = input.instance_id
         );
 
         let mut request = SignedRequest::new("GET", "connect", &self.region, &request_uri);
+        request.set_operation("ListQueues");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -3651,34 +4337,64 @@ impl Connect for ConnectClient {
         }
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `list_routing_profiles`, also returning the metadata of the response.
+    pub async fn list_routing_profiles_with_metadata(
+        &self,
+        input: ListRoutingProfilesRequest,
+    ) -> Result<
+        (ListRoutingProfilesResponse, ResponseMetadata),
+        RusotoError<ListRoutingProfilesError>,
+    > {
+        let request = self.build_list_routing_profiles_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListQueuesResponse, _>()?;
+                .deserialize::<ListRoutingProfilesResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(ListQueuesError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(ListRoutingProfilesError::from_response(response))
         }
     }
 
-    /// <p>Provides summary information about the routing profiles for the specified Amazon Connect instance.</p>
-    async fn list_routing_profiles(
+    /// Presigns the request `list_routing_profiles` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_routing_profiles(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListRoutingProfilesRequest,
-    ) -> Result<ListRoutingProfilesResponse, RusotoError<ListRoutingProfilesError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_routing_profiles_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_routing_profiles_request(
+        &self,
+        input: ListRoutingProfilesRequest,
+    ) -> SignedRequest {
         let request_uri = format!(
             "/routing-profiles-summary/{instance_id}",
             instance_id = input.instance_id
         );
 
         let mut request = SignedRequest::new("GET", "connect", &self.region, &request_uri);
+        request.set_operation("ListRoutingProfiles");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -3690,34 +4406,64 @@ impl Connect for ConnectClient {
         }
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `list_security_profiles`, also returning the metadata of the response.
+    pub async fn list_security_profiles_with_metadata(
+        &self,
+        input: ListSecurityProfilesRequest,
+    ) -> Result<
+        (ListSecurityProfilesResponse, ResponseMetadata),
+        RusotoError<ListSecurityProfilesError>,
+    > {
+        let request = self.build_list_security_profiles_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListRoutingProfilesResponse, _>()?;
+                .deserialize::<ListSecurityProfilesResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(ListRoutingProfilesError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(ListSecurityProfilesError::from_response(response))
         }
     }
 
-    /// <p>Provides summary information about the security profiles for the specified Amazon Connect instance.</p>
-    async fn list_security_profiles(
+    /// Presigns the request `list_security_profiles` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_security_profiles(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListSecurityProfilesRequest,
-    ) -> Result<ListSecurityProfilesResponse, RusotoError<ListSecurityProfilesError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_security_profiles_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_security_profiles_request(
+        &self,
+        input: ListSecurityProfilesRequest,
+    ) -> SignedRequest {
         let request_uri = format!(
             "/security-profiles-summary/{instance_id}",
             instance_id = input.instance_id
         );
 
         let mut request = SignedRequest::new("GET", "connect", &self.region, &request_uri);
+        request.set_operation("ListSecurityProfiles");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -3729,61 +4475,121 @@ impl Connect for ConnectClient {
         }
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `list_tags_for_resource`, also returning the metadata of the response.
+    pub async fn list_tags_for_resource_with_metadata(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> Result<
+        (ListTagsForResourceResponse, ResponseMetadata),
+        RusotoError<ListTagsForResourceError>,
+    > {
+        let request = self.build_list_tags_for_resource_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListSecurityProfilesResponse, _>()?;
+                .deserialize::<ListTagsForResourceResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(ListSecurityProfilesError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(ListTagsForResourceError::from_response(response))
         }
     }
 
-    /// <p>Lists the tags for the specified resource.</p>
-    async fn list_tags_for_resource(
+    /// Presigns the request `list_tags_for_resource` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_tags_for_resource(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListTagsForResourceRequest,
-    ) -> Result<ListTagsForResourceResponse, RusotoError<ListTagsForResourceError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_tags_for_resource_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_tags_for_resource_request(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> SignedRequest {
         let request_uri = format!("/tags/{resource_arn}", resource_arn = input.resource_arn);
 
         let mut request = SignedRequest::new("GET", "connect", &self.region, &request_uri);
+        request.set_operation("ListTagsForResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
+        request
+    }
+
+    /// Calls `list_user_hierarchy_groups`, also returning the metadata of the response.
+    pub async fn list_user_hierarchy_groups_with_metadata(
+        &self,
+        input: ListUserHierarchyGroupsRequest,
+    ) -> Result<
+        (ListUserHierarchyGroupsResponse, ResponseMetadata),
+        RusotoError<ListUserHierarchyGroupsError>,
+    > {
+        let request = self.build_list_user_hierarchy_groups_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListTagsForResourceResponse, _>()?;
+                .deserialize::<ListUserHierarchyGroupsResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(ListTagsForResourceError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(ListUserHierarchyGroupsError::from_response(response))
         }
     }
 
-    /// <p>Provides summary information about the hierarchy groups for the specified Amazon Connect instance.</p>
-    async fn list_user_hierarchy_groups(
+    /// Presigns the request `list_user_hierarchy_groups` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_user_hierarchy_groups(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListUserHierarchyGroupsRequest,
-    ) -> Result<ListUserHierarchyGroupsResponse, RusotoError<ListUserHierarchyGroupsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_user_hierarchy_groups_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_user_hierarchy_groups_request(
+        &self,
+        input: ListUserHierarchyGroupsRequest,
+    ) -> SignedRequest {
         let request_uri = format!(
             "/user-hierarchy-groups-summary/{instance_id}",
             instance_id = input.instance_id
         );
 
         let mut request = SignedRequest::new("GET", "connect", &self.region, &request_uri);
+        request.set_operation("ListUserHierarchyGroups");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -3795,34 +4601,58 @@ impl Connect for ConnectClient {
         }
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `list_users`, also returning the metadata of the response.
+    pub async fn list_users_with_metadata(
+        &self,
+        input: ListUsersRequest,
+    ) -> Result<(ListUsersResponse, ResponseMetadata), RusotoError<ListUsersError>> {
+        let request = self.build_list_users_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListUserHierarchyGroupsResponse, _>()?;
+                .deserialize::<ListUsersResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(ListUserHierarchyGroupsError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(ListUsersError::from_response(response))
         }
     }
 
-    /// <p>Provides summary information about the users for the specified Amazon Connect instance.</p>
-    async fn list_users(
+    /// Presigns the request `list_users` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_users(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListUsersRequest,
-    ) -> Result<ListUsersResponse, RusotoError<ListUsersError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_users_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_users_request(&self, input: ListUsersRequest) -> SignedRequest {
         let request_uri = format!(
             "/users-summary/{instance_id}",
             instance_id = input.instance_id
         );
 
         let mut request = SignedRequest::new("GET", "connect", &self.region, &request_uri);
+        request.set_operation("ListUsers");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -3834,151 +4664,276 @@ impl Connect for ConnectClient {
         }
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `start_chat_contact`, also returning the metadata of the response.
+    pub async fn start_chat_contact_with_metadata(
+        &self,
+        input: StartChatContactRequest,
+    ) -> Result<(StartChatContactResponse, ResponseMetadata), RusotoError<StartChatContactError>>
+    {
+        let request = self.build_start_chat_contact_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListUsersResponse, _>()?;
+                .deserialize::<StartChatContactResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(ListUsersError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(StartChatContactError::from_response(response))
         }
     }
 
-    /// <p>Initiates a contact flow to start a new chat for the customer. Response of this API provides a token required to obtain credentials from the <a href="https://docs.aws.amazon.com/connect-participant/latest/APIReference/API_CreateParticipantConnection.html">CreateParticipantConnection</a> API in the Amazon Connect Participant Service.</p> <p>When a new chat contact is successfully created, clients need to subscribe to the participant’s connection for the created chat within 5 minutes. This is achieved by invoking <a href="https://docs.aws.amazon.com/connect-participant/latest/APIReference/API_CreateParticipantConnection.html">CreateParticipantConnection</a> with WEBSOCKET and CONNECTION_CREDENTIALS. </p>
-    async fn start_chat_contact(
+    /// Presigns the request `start_chat_contact` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_start_chat_contact(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: StartChatContactRequest,
-    ) -> Result<StartChatContactResponse, RusotoError<StartChatContactError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_start_chat_contact_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_start_chat_contact_request(&self, input: StartChatContactRequest) -> SignedRequest {
         let request_uri = "/contact/chat";
 
         let mut request = SignedRequest::new("PUT", "connect", &self.region, &request_uri);
+        request.set_operation("StartChatContact");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `start_outbound_voice_contact`, also returning the metadata of the response.
+    pub async fn start_outbound_voice_contact_with_metadata(
+        &self,
+        input: StartOutboundVoiceContactRequest,
+    ) -> Result<
+        (StartOutboundVoiceContactResponse, ResponseMetadata),
+        RusotoError<StartOutboundVoiceContactError>,
+    > {
+        let request = self.build_start_outbound_voice_contact_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<StartChatContactResponse, _>()?;
+                .deserialize::<StartOutboundVoiceContactResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(StartChatContactError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(StartOutboundVoiceContactError::from_response(response))
         }
     }
 
-    /// <p>Initiates a contact flow to place an outbound call to a customer.</p> <p>There is a 60 second dialing timeout for this operation. If the call is not connected after 60 seconds, it fails.</p>
-    async fn start_outbound_voice_contact(
+    /// Presigns the request `start_outbound_voice_contact` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_start_outbound_voice_contact(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: StartOutboundVoiceContactRequest,
-    ) -> Result<StartOutboundVoiceContactResponse, RusotoError<StartOutboundVoiceContactError>>
-    {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_start_outbound_voice_contact_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_start_outbound_voice_contact_request(
+        &self,
+        input: StartOutboundVoiceContactRequest,
+    ) -> SignedRequest {
         let request_uri = "/contact/outbound-voice";
 
         let mut request = SignedRequest::new("PUT", "connect", &self.region, &request_uri);
+        request.set_operation("StartOutboundVoiceContact");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `stop_contact`, also returning the metadata of the response.
+    pub async fn stop_contact_with_metadata(
+        &self,
+        input: StopContactRequest,
+    ) -> Result<(StopContactResponse, ResponseMetadata), RusotoError<StopContactError>> {
+        let request = self.build_stop_contact_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<StartOutboundVoiceContactResponse, _>()?;
+                .deserialize::<StopContactResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(StartOutboundVoiceContactError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(StopContactError::from_response(response))
         }
     }
 
-    /// <p>Ends the specified contact.</p>
-    async fn stop_contact(
+    /// Presigns the request `stop_contact` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_stop_contact(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: StopContactRequest,
-    ) -> Result<StopContactResponse, RusotoError<StopContactError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_stop_contact_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_stop_contact_request(&self, input: StopContactRequest) -> SignedRequest {
         let request_uri = "/contact/stop";
 
         let mut request = SignedRequest::new("POST", "connect", &self.region, &request_uri);
+        request.set_operation("StopContact");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `tag_resource`, also returning the metadata of the response.
+    pub async fn tag_resource_with_metadata(
+        &self,
+        input: TagResourceRequest,
+    ) -> Result<((), ResponseMetadata), RusotoError<TagResourceError>> {
+        let request = self.build_tag_resource_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<StopContactResponse, _>()?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = ::std::mem::drop(response);
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(StopContactError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(TagResourceError::from_response(response))
         }
     }
 
-    /// <p>Adds the specified tags to the specified resource.</p> <p>The supported resource type is users.</p>
-    async fn tag_resource(
+    /// Presigns the request `tag_resource` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_tag_resource(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: TagResourceRequest,
-    ) -> Result<(), RusotoError<TagResourceError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_tag_resource_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_tag_resource_request(&self, input: TagResourceRequest) -> SignedRequest {
         let request_uri = format!("/tags/{resource_arn}", resource_arn = input.resource_arn);
 
         let mut request = SignedRequest::new("POST", "connect", &self.region, &request_uri);
+        request.set_operation("TagResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `untag_resource`, also returning the metadata of the response.
+    pub async fn untag_resource_with_metadata(
+        &self,
+        input: UntagResourceRequest,
+    ) -> Result<((), ResponseMetadata), RusotoError<UntagResourceError>> {
+        let request = self.build_untag_resource_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = ::std::mem::drop(response);
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(TagResourceError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(UntagResourceError::from_response(response))
         }
     }
 
-    /// <p>Removes the specified tags from the specified resource.</p>
-    async fn untag_resource(
+    /// Presigns the request `untag_resource` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_untag_resource(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UntagResourceRequest,
-    ) -> Result<(), RusotoError<UntagResourceError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_untag_resource_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_untag_resource_request(&self, input: UntagResourceRequest) -> SignedRequest {
         let request_uri = format!("/tags/{resource_arn}", resource_arn = input.resource_arn);
 
         let mut request = SignedRequest::new("DELETE", "connect", &self.region, &request_uri);
+        request.set_operation("UntagResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -3987,57 +4942,113 @@ impl Connect for ConnectClient {
         }
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `update_contact_attributes`, also returning the metadata of the response.
+    pub async fn update_contact_attributes_with_metadata(
+        &self,
+        input: UpdateContactAttributesRequest,
+    ) -> Result<
+        (UpdateContactAttributesResponse, ResponseMetadata),
+        RusotoError<UpdateContactAttributesError>,
+    > {
+        let request = self.build_update_contact_attributes_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            let result = ::std::mem::drop(response);
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<UpdateContactAttributesResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(UntagResourceError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(UpdateContactAttributesError::from_response(response))
         }
     }
 
-    /// <p>Creates or updates the contact attributes associated with the specified contact.</p> <p>You can add or update attributes for both ongoing and completed contacts. For example, you can update the customer's name or the reason the customer called while the call is active, or add notes about steps that the agent took during the call that are displayed to the next agent that takes the call. You can also update attributes for a contact using data from your CRM application and save the data with the contact in Amazon Connect. You could also flag calls for additional analysis, such as legal review or identifying abusive callers.</p> <p>Contact attributes are available in Amazon Connect for 24 months, and are then deleted.</p> <p> <b>Important:</b> You cannot use the operation to update attributes for contacts that occurred prior to the release of the API, September 12, 2018. You can update attributes only for contacts that started after the release of the API. If you attempt to update attributes for a contact that occurred prior to the release of the API, a 400 error is returned. This applies also to queued callbacks that were initiated prior to the release of the API but are still active in your instance.</p>
-    async fn update_contact_attributes(
+    /// Presigns the request `update_contact_attributes` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_contact_attributes(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UpdateContactAttributesRequest,
-    ) -> Result<UpdateContactAttributesResponse, RusotoError<UpdateContactAttributesError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_contact_attributes_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_contact_attributes_request(
+        &self,
+        input: UpdateContactAttributesRequest,
+    ) -> SignedRequest {
         let request_uri = "/contact/attributes";
 
         let mut request = SignedRequest::new("POST", "connect", &self.region, &request_uri);
+        request.set_operation("UpdateContactAttributes");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `update_user_hierarchy`, also returning the metadata of the response.
+    pub async fn update_user_hierarchy_with_metadata(
+        &self,
+        input: UpdateUserHierarchyRequest,
+    ) -> Result<((), ResponseMetadata), RusotoError<UpdateUserHierarchyError>> {
+        let request = self.build_update_user_hierarchy_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<UpdateContactAttributesResponse, _>()?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = ::std::mem::drop(response);
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(UpdateContactAttributesError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(UpdateUserHierarchyError::from_response(response))
         }
     }
 
-    /// <p>Assigns the specified hierarchy group to the specified user.</p>
-    async fn update_user_hierarchy(
+    /// Presigns the request `update_user_hierarchy` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_user_hierarchy(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UpdateUserHierarchyRequest,
-    ) -> Result<(), RusotoError<UpdateUserHierarchyError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_user_hierarchy_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_user_hierarchy_request(
+        &self,
+        input: UpdateUserHierarchyRequest,
+    ) -> SignedRequest {
         let request_uri = format!(
             "/users/{instance_id}/{user_id}/hierarchy",
             instance_id = input.instance_id,
@@ -4045,32 +5056,59 @@ impl Connect for ConnectClient {
         );
 
         let mut request = SignedRequest::new("POST", "connect", &self.region, &request_uri);
+        request.set_operation("UpdateUserHierarchy");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `update_user_identity_info`, also returning the metadata of the response.
+    pub async fn update_user_identity_info_with_metadata(
+        &self,
+        input: UpdateUserIdentityInfoRequest,
+    ) -> Result<((), ResponseMetadata), RusotoError<UpdateUserIdentityInfoError>> {
+        let request = self.build_update_user_identity_info_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = ::std::mem::drop(response);
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(UpdateUserHierarchyError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(UpdateUserIdentityInfoError::from_response(response))
         }
     }
 
-    /// <p>Updates the identity information for the specified user.</p>
-    async fn update_user_identity_info(
+    /// Presigns the request `update_user_identity_info` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_user_identity_info(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UpdateUserIdentityInfoRequest,
-    ) -> Result<(), RusotoError<UpdateUserIdentityInfoError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_user_identity_info_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_user_identity_info_request(
+        &self,
+        input: UpdateUserIdentityInfoRequest,
+    ) -> SignedRequest {
         let request_uri = format!(
             "/users/{instance_id}/{user_id}/identity-info",
             instance_id = input.instance_id,
@@ -4078,32 +5116,59 @@ impl Connect for ConnectClient {
         );
 
         let mut request = SignedRequest::new("POST", "connect", &self.region, &request_uri);
+        request.set_operation("UpdateUserIdentityInfo");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `update_user_phone_config`, also returning the metadata of the response.
+    pub async fn update_user_phone_config_with_metadata(
+        &self,
+        input: UpdateUserPhoneConfigRequest,
+    ) -> Result<((), ResponseMetadata), RusotoError<UpdateUserPhoneConfigError>> {
+        let request = self.build_update_user_phone_config_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = ::std::mem::drop(response);
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(UpdateUserIdentityInfoError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(UpdateUserPhoneConfigError::from_response(response))
         }
     }
 
-    /// <p>Updates the phone configuration settings for the specified user.</p>
-    async fn update_user_phone_config(
+    /// Presigns the request `update_user_phone_config` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_user_phone_config(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UpdateUserPhoneConfigRequest,
-    ) -> Result<(), RusotoError<UpdateUserPhoneConfigError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_user_phone_config_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_user_phone_config_request(
+        &self,
+        input: UpdateUserPhoneConfigRequest,
+    ) -> SignedRequest {
         let request_uri = format!(
             "/users/{instance_id}/{user_id}/phone-config",
             instance_id = input.instance_id,
@@ -4111,32 +5176,59 @@ impl Connect for ConnectClient {
         );
 
         let mut request = SignedRequest::new("POST", "connect", &self.region, &request_uri);
+        request.set_operation("UpdateUserPhoneConfig");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `update_user_routing_profile`, also returning the metadata of the response.
+    pub async fn update_user_routing_profile_with_metadata(
+        &self,
+        input: UpdateUserRoutingProfileRequest,
+    ) -> Result<((), ResponseMetadata), RusotoError<UpdateUserRoutingProfileError>> {
+        let request = self.build_update_user_routing_profile_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = ::std::mem::drop(response);
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(UpdateUserPhoneConfigError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(UpdateUserRoutingProfileError::from_response(response))
         }
     }
 
-    /// <p>Assigns the specified routing profile to the specified user.</p>
-    async fn update_user_routing_profile(
+    /// Presigns the request `update_user_routing_profile` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_user_routing_profile(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UpdateUserRoutingProfileRequest,
-    ) -> Result<(), RusotoError<UpdateUserRoutingProfileError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_user_routing_profile_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_user_routing_profile_request(
+        &self,
+        input: UpdateUserRoutingProfileRequest,
+    ) -> SignedRequest {
         let request_uri = format!(
             "/users/{instance_id}/{user_id}/routing-profile",
             instance_id = input.instance_id,
@@ -4144,32 +5236,59 @@ impl Connect for ConnectClient {
         );
 
         let mut request = SignedRequest::new("POST", "connect", &self.region, &request_uri);
+        request.set_operation("UpdateUserRoutingProfile");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `update_user_security_profiles`, also returning the metadata of the response.
+    pub async fn update_user_security_profiles_with_metadata(
+        &self,
+        input: UpdateUserSecurityProfilesRequest,
+    ) -> Result<((), ResponseMetadata), RusotoError<UpdateUserSecurityProfilesError>> {
+        let request = self.build_update_user_security_profiles_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = ::std::mem::drop(response);
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(UpdateUserRoutingProfileError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(UpdateUserSecurityProfilesError::from_response(response))
         }
     }
 
-    /// <p>Assigns the specified security profiles to the specified user.</p>
-    async fn update_user_security_profiles(
+    /// Presigns the request `update_user_security_profiles` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_user_security_profiles(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UpdateUserSecurityProfilesRequest,
-    ) -> Result<(), RusotoError<UpdateUserSecurityProfilesError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_user_security_profiles_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_user_security_profiles_request(
+        &self,
+        input: UpdateUserSecurityProfilesRequest,
+    ) -> SignedRequest {
         let request_uri = format!(
             "/users/{instance_id}/{user_id}/security-profiles",
             instance_id = input.instance_id,
@@ -4177,24 +5296,643 @@ impl Connect for ConnectClient {
         );
 
         let mut request = SignedRequest::new("POST", "connect", &self.region, &request_uri);
+        request.set_operation("UpdateUserSecurityProfiles");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
-        let mut response = self
-            .client
-            .sign_and_dispatch(request)
+        request
+    }
+
+    /// The pages of `GetMetricData` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `MaxResults` of `input`.
+    pub fn get_metric_data_pages(
+        &self,
+        input: GetMetricDataRequest,
+    ) -> impl futures::Stream<Item = Result<GetMetricDataResponse, RusotoError<GetMetricDataError>>> + Send
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: ConnectClient, input| async move { client.get_metric_data(input).await },
+            |input: &GetMetricDataRequest, output: &GetMetricDataResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(GetMetricDataRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `MetricResults` of every page of `GetMetricData` results for `input`, as `get_metric_data_pages` requests them.
+    pub fn get_metric_data_stream(
+        &self,
+        input: GetMetricDataRequest,
+    ) -> impl futures::Stream<Item = Result<HistoricalMetricResult, RusotoError<GetMetricDataError>>>
+           + Send {
+        rusoto_core::pagination::items(self.get_metric_data_pages(input), |page| {
+            page.metric_results.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `ListContactFlows` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `MaxResults` of `input`.
+    pub fn list_contact_flows_pages(
+        &self,
+        input: ListContactFlowsRequest,
+    ) -> impl futures::Stream<
+        Item = Result<ListContactFlowsResponse, RusotoError<ListContactFlowsError>>,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: ConnectClient, input| async move { client.list_contact_flows(input).await },
+            |input: &ListContactFlowsRequest, output: &ListContactFlowsResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListContactFlowsRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `ContactFlowSummaryList` of every page of `ListContactFlows` results for `input`, as `list_contact_flows_pages` requests them.
+    pub fn list_contact_flows_stream(
+        &self,
+        input: ListContactFlowsRequest,
+    ) -> impl futures::Stream<Item = Result<ContactFlowSummary, RusotoError<ListContactFlowsError>>> + Send
+    {
+        rusoto_core::pagination::items(self.list_contact_flows_pages(input), |page| {
+            page.contact_flow_summary_list.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `ListHoursOfOperations` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `MaxResults` of `input`.
+    pub fn list_hours_of_operations_pages(
+        &self,
+        input: ListHoursOfOperationsRequest,
+    ) -> impl futures::Stream<
+        Item = Result<ListHoursOfOperationsResponse, RusotoError<ListHoursOfOperationsError>>,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: ConnectClient, input| async move { client.list_hours_of_operations(input).await },
+            |input: &ListHoursOfOperationsRequest, output: &ListHoursOfOperationsResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListHoursOfOperationsRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `HoursOfOperationSummaryList` of every page of `ListHoursOfOperations` results for `input`, as `list_hours_of_operations_pages` requests them.
+    pub fn list_hours_of_operations_stream(
+        &self,
+        input: ListHoursOfOperationsRequest,
+    ) -> impl futures::Stream<
+        Item = Result<HoursOfOperationSummary, RusotoError<ListHoursOfOperationsError>>,
+    > + Send {
+        rusoto_core::pagination::items(self.list_hours_of_operations_pages(input), |page| {
+            page.hours_of_operation_summary_list.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `ListPhoneNumbers` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `MaxResults` of `input`.
+    pub fn list_phone_numbers_pages(
+        &self,
+        input: ListPhoneNumbersRequest,
+    ) -> impl futures::Stream<
+        Item = Result<ListPhoneNumbersResponse, RusotoError<ListPhoneNumbersError>>,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: ConnectClient, input| async move { client.list_phone_numbers(input).await },
+            |input: &ListPhoneNumbersRequest, output: &ListPhoneNumbersResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListPhoneNumbersRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `PhoneNumberSummaryList` of every page of `ListPhoneNumbers` results for `input`, as `list_phone_numbers_pages` requests them.
+    pub fn list_phone_numbers_stream(
+        &self,
+        input: ListPhoneNumbersRequest,
+    ) -> impl futures::Stream<Item = Result<PhoneNumberSummary, RusotoError<ListPhoneNumbersError>>> + Send
+    {
+        rusoto_core::pagination::items(self.list_phone_numbers_pages(input), |page| {
+            page.phone_number_summary_list.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `ListQueues` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `MaxResults` of `input`.
+    pub fn list_queues_pages(
+        &self,
+        input: ListQueuesRequest,
+    ) -> impl futures::Stream<Item = Result<ListQueuesResponse, RusotoError<ListQueuesError>>> + Send
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: ConnectClient, input| async move { client.list_queues(input).await },
+            |input: &ListQueuesRequest, output: &ListQueuesResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListQueuesRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `QueueSummaryList` of every page of `ListQueues` results for `input`, as `list_queues_pages` requests them.
+    pub fn list_queues_stream(
+        &self,
+        input: ListQueuesRequest,
+    ) -> impl futures::Stream<Item = Result<QueueSummary, RusotoError<ListQueuesError>>> + Send
+    {
+        rusoto_core::pagination::items(self.list_queues_pages(input), |page| {
+            page.queue_summary_list.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `ListRoutingProfiles` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `MaxResults` of `input`.
+    pub fn list_routing_profiles_pages(
+        &self,
+        input: ListRoutingProfilesRequest,
+    ) -> impl futures::Stream<
+        Item = Result<ListRoutingProfilesResponse, RusotoError<ListRoutingProfilesError>>,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: ConnectClient, input| async move { client.list_routing_profiles(input).await },
+            |input: &ListRoutingProfilesRequest, output: &ListRoutingProfilesResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListRoutingProfilesRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `RoutingProfileSummaryList` of every page of `ListRoutingProfiles` results for `input`, as `list_routing_profiles_pages` requests them.
+    pub fn list_routing_profiles_stream(
+        &self,
+        input: ListRoutingProfilesRequest,
+    ) -> impl futures::Stream<
+        Item = Result<RoutingProfileSummary, RusotoError<ListRoutingProfilesError>>,
+    > + Send {
+        rusoto_core::pagination::items(self.list_routing_profiles_pages(input), |page| {
+            page.routing_profile_summary_list.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `ListSecurityProfiles` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `MaxResults` of `input`.
+    pub fn list_security_profiles_pages(
+        &self,
+        input: ListSecurityProfilesRequest,
+    ) -> impl futures::Stream<
+        Item = Result<ListSecurityProfilesResponse, RusotoError<ListSecurityProfilesError>>,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: ConnectClient, input| async move { client.list_security_profiles(input).await },
+            |input: &ListSecurityProfilesRequest, output: &ListSecurityProfilesResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListSecurityProfilesRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `SecurityProfileSummaryList` of every page of `ListSecurityProfiles` results for `input`, as `list_security_profiles_pages` requests them.
+    pub fn list_security_profiles_stream(
+        &self,
+        input: ListSecurityProfilesRequest,
+    ) -> impl futures::Stream<
+        Item = Result<SecurityProfileSummary, RusotoError<ListSecurityProfilesError>>,
+    > + Send {
+        rusoto_core::pagination::items(self.list_security_profiles_pages(input), |page| {
+            page.security_profile_summary_list.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `ListUserHierarchyGroups` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `MaxResults` of `input`.
+    pub fn list_user_hierarchy_groups_pages(
+        &self,
+        input: ListUserHierarchyGroupsRequest,
+    ) -> impl futures::Stream<
+        Item = Result<ListUserHierarchyGroupsResponse, RusotoError<ListUserHierarchyGroupsError>>,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: ConnectClient, input| async move { client.list_user_hierarchy_groups(input).await },
+            |input: &ListUserHierarchyGroupsRequest, output: &ListUserHierarchyGroupsResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListUserHierarchyGroupsRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `UserHierarchyGroupSummaryList` of every page of `ListUserHierarchyGroups` results for `input`, as `list_user_hierarchy_groups_pages` requests them.
+    pub fn list_user_hierarchy_groups_stream(
+        &self,
+        input: ListUserHierarchyGroupsRequest,
+    ) -> impl futures::Stream<
+        Item = Result<HierarchyGroupSummary, RusotoError<ListUserHierarchyGroupsError>>,
+    > + Send {
+        rusoto_core::pagination::items(self.list_user_hierarchy_groups_pages(input), |page| {
+            page.user_hierarchy_group_summary_list.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `ListUsers` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `MaxResults` of `input`.
+    pub fn list_users_pages(
+        &self,
+        input: ListUsersRequest,
+    ) -> impl futures::Stream<Item = Result<ListUsersResponse, RusotoError<ListUsersError>>> + Send
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: ConnectClient, input| async move { client.list_users(input).await },
+            |input: &ListUsersRequest, output: &ListUsersResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListUsersRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `UserSummaryList` of every page of `ListUsers` results for `input`, as `list_users_pages` requests them.
+    pub fn list_users_stream(
+        &self,
+        input: ListUsersRequest,
+    ) -> impl futures::Stream<Item = Result<UserSummary, RusotoError<ListUsersError>>> + Send {
+        rusoto_core::pagination::items(self.list_users_pages(input), |page| {
+            page.user_summary_list.unwrap_or_default()
+        })
+    }
+}
+
+#[async_trait]
+impl Connect for ConnectClient {
+    /// <p>Creates a user account for the specified Amazon Connect instance.</p>
+    async fn create_user(
+        &self,
+        input: CreateUserRequest,
+    ) -> Result<CreateUserResponse, RusotoError<CreateUserError>> {
+        self.create_user_with_metadata(input)
             .await
-            .map_err(RusotoError::from)?;
-        if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            let result = ::std::mem::drop(response);
+            .map(|(output, _)| output)
+    }
 
-            Ok(result)
-        } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(UpdateUserSecurityProfilesError::from_response(response))
-        }
+    /// <p>Deletes a user account from the specified Amazon Connect instance.</p>
+    async fn delete_user(
+        &self,
+        input: DeleteUserRequest,
+    ) -> Result<(), RusotoError<DeleteUserError>> {
+        self.delete_user_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Describes the specified user account. You can find the instance ID in the console (it’s the final part of the ARN). The console does not display the user IDs. Instead, list the users and note the IDs provided in the output.</p>
+    async fn describe_user(
+        &self,
+        input: DescribeUserRequest,
+    ) -> Result<DescribeUserResponse, RusotoError<DescribeUserError>> {
+        self.describe_user_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Describes the specified hierarchy group.</p>
+    async fn describe_user_hierarchy_group(
+        &self,
+        input: DescribeUserHierarchyGroupRequest,
+    ) -> Result<DescribeUserHierarchyGroupResponse, RusotoError<DescribeUserHierarchyGroupError>>
+    {
+        self.describe_user_hierarchy_group_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Describes the hierarchy structure of the specified Amazon Connect instance.</p>
+    async fn describe_user_hierarchy_structure(
+        &self,
+        input: DescribeUserHierarchyStructureRequest,
+    ) -> Result<
+        DescribeUserHierarchyStructureResponse,
+        RusotoError<DescribeUserHierarchyStructureError>,
+    > {
+        self.describe_user_hierarchy_structure_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Retrieves the contact attributes for the specified contact.</p>
+    async fn get_contact_attributes(
+        &self,
+        input: GetContactAttributesRequest,
+    ) -> Result<GetContactAttributesResponse, RusotoError<GetContactAttributesError>> {
+        self.get_contact_attributes_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Gets the real-time metric data from the specified Amazon Connect instance.</p> <p>For more information, see <a href="https://docs.aws.amazon.com/connect/latest/adminguide/real-time-metrics-reports.html">Real-time Metrics Reports</a> in the <i>Amazon Connect Administrator Guide</i>.</p>
+    async fn get_current_metric_data(
+        &self,
+        input: GetCurrentMetricDataRequest,
+    ) -> Result<GetCurrentMetricDataResponse, RusotoError<GetCurrentMetricDataError>> {
+        self.get_current_metric_data_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Retrieves a token for federation.</p>
+    async fn get_federation_token(
+        &self,
+        input: GetFederationTokenRequest,
+    ) -> Result<GetFederationTokenResponse, RusotoError<GetFederationTokenError>> {
+        self.get_federation_token_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Gets historical metric data from the specified Amazon Connect instance.</p> <p>For more information, see <a href="https://docs.aws.amazon.com/connect/latest/adminguide/historical-metrics.html">Historical Metrics Reports</a> in the <i>Amazon Connect Administrator Guide</i>.</p>
+    async fn get_metric_data(
+        &self,
+        input: GetMetricDataRequest,
+    ) -> Result<GetMetricDataResponse, RusotoError<GetMetricDataError>> {
+        self.get_metric_data_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Provides information about the contact flows for the specified Amazon Connect instance.</p>
+    async fn list_contact_flows(
+        &self,
+        input: ListContactFlowsRequest,
+    ) -> Result<ListContactFlowsResponse, RusotoError<ListContactFlowsError>> {
+        self.list_contact_flows_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Provides information about the hours of operation for the specified Amazon Connect instance.</p>
+    async fn list_hours_of_operations(
+        &self,
+        input: ListHoursOfOperationsRequest,
+    ) -> Result<ListHoursOfOperationsResponse, RusotoError<ListHoursOfOperationsError>> {
+        self.list_hours_of_operations_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Provides information about the phone numbers for the specified Amazon Connect instance.</p>
+    async fn list_phone_numbers(
+        &self,
+        input: ListPhoneNumbersRequest,
+    ) -> Result<ListPhoneNumbersResponse, RusotoError<ListPhoneNumbersError>> {
+        self.list_phone_numbers_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Provides information about the queues for the specified Amazon Connect instance.</p>
+    async fn list_queues(
+        &self,
+        input: ListQueuesRequest,
+    ) -> Result<ListQueuesResponse, RusotoError<ListQueuesError>> {
+        self.list_queues_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Provides summary information about the routing profiles for the specified Amazon Connect instance.</p>
+    async fn list_routing_profiles(
+        &self,
+        input: ListRoutingProfilesRequest,
+    ) -> Result<ListRoutingProfilesResponse, RusotoError<ListRoutingProfilesError>> {
+        self.list_routing_profiles_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Provides summary information about the security profiles for the specified Amazon Connect instance.</p>
+    async fn list_security_profiles(
+        &self,
+        input: ListSecurityProfilesRequest,
+    ) -> Result<ListSecurityProfilesResponse, RusotoError<ListSecurityProfilesError>> {
+        self.list_security_profiles_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Lists the tags for the specified resource.</p>
+    async fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> Result<ListTagsForResourceResponse, RusotoError<ListTagsForResourceError>> {
+        self.list_tags_for_resource_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Provides summary information about the hierarchy groups for the specified Amazon Connect instance.</p>
+    async fn list_user_hierarchy_groups(
+        &self,
+        input: ListUserHierarchyGroupsRequest,
+    ) -> Result<ListUserHierarchyGroupsResponse, RusotoError<ListUserHierarchyGroupsError>> {
+        self.list_user_hierarchy_groups_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Provides summary information about the users for the specified Amazon Connect instance.</p>
+    async fn list_users(
+        &self,
+        input: ListUsersRequest,
+    ) -> Result<ListUsersResponse, RusotoError<ListUsersError>> {
+        self.list_users_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Initiates a contact flow to start a new chat for the customer. Response of this API provides a token required to obtain credentials from the <a href="https://docs.aws.amazon.com/connect-participant/latest/APIReference/API_CreateParticipantConnection.html">CreateParticipantConnection</a> API in the Amazon Connect Participant Service.</p> <p>When a new chat contact is successfully created, clients need to subscribe to the participant’s connection for the created chat within 5 minutes. This is achieved by invoking <a href="https://docs.aws.amazon.com/connect-participant/latest/APIReference/API_CreateParticipantConnection.html">CreateParticipantConnection</a> with WEBSOCKET and CONNECTION_CREDENTIALS. </p>
+    async fn start_chat_contact(
+        &self,
+        input: StartChatContactRequest,
+    ) -> Result<StartChatContactResponse, RusotoError<StartChatContactError>> {
+        self.start_chat_contact_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Initiates a contact flow to place an outbound call to a customer.</p> <p>There is a 60 second dialing timeout for this operation. If the call is not connected after 60 seconds, it fails.</p>
+    async fn start_outbound_voice_contact(
+        &self,
+        input: StartOutboundVoiceContactRequest,
+    ) -> Result<StartOutboundVoiceContactResponse, RusotoError<StartOutboundVoiceContactError>>
+    {
+        self.start_outbound_voice_contact_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Ends the specified contact.</p>
+    async fn stop_contact(
+        &self,
+        input: StopContactRequest,
+    ) -> Result<StopContactResponse, RusotoError<StopContactError>> {
+        self.stop_contact_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Adds the specified tags to the specified resource.</p> <p>The supported resource type is users.</p>
+    async fn tag_resource(
+        &self,
+        input: TagResourceRequest,
+    ) -> Result<(), RusotoError<TagResourceError>> {
+        self.tag_resource_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Removes the specified tags from the specified resource.</p>
+    async fn untag_resource(
+        &self,
+        input: UntagResourceRequest,
+    ) -> Result<(), RusotoError<UntagResourceError>> {
+        self.untag_resource_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Creates or updates the contact attributes associated with the specified contact.</p> <p>You can add or update attributes for both ongoing and completed contacts. For example, you can update the customer's name or the reason the customer called while the call is active, or add notes about steps that the agent took during the call that are displayed to the next agent that takes the call. You can also update attributes for a contact using data from your CRM application and save the data with the contact in Amazon Connect. You could also flag calls for additional analysis, such as legal review or identifying abusive callers.</p> <p>Contact attributes are available in Amazon Connect for 24 months, and are then deleted.</p> <p> <b>Important:</b> You cannot use the operation to update attributes for contacts that occurred prior to the release of the API, September 12, 2018. You can update attributes only for contacts that started after the release of the API. If you attempt to update attributes for a contact that occurred prior to the release of the API, a 400 error is returned. This applies also to queued callbacks that were initiated prior to the release of the API but are still active in your instance.</p>
+    async fn update_contact_attributes(
+        &self,
+        input: UpdateContactAttributesRequest,
+    ) -> Result<UpdateContactAttributesResponse, RusotoError<UpdateContactAttributesError>> {
+        self.update_contact_attributes_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Assigns the specified hierarchy group to the specified user.</p>
+    async fn update_user_hierarchy(
+        &self,
+        input: UpdateUserHierarchyRequest,
+    ) -> Result<(), RusotoError<UpdateUserHierarchyError>> {
+        self.update_user_hierarchy_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Updates the identity information for the specified user.</p>
+    async fn update_user_identity_info(
+        &self,
+        input: UpdateUserIdentityInfoRequest,
+    ) -> Result<(), RusotoError<UpdateUserIdentityInfoError>> {
+        self.update_user_identity_info_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Updates the phone configuration settings for the specified user.</p>
+    async fn update_user_phone_config(
+        &self,
+        input: UpdateUserPhoneConfigRequest,
+    ) -> Result<(), RusotoError<UpdateUserPhoneConfigError>> {
+        self.update_user_phone_config_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Assigns the specified routing profile to the specified user.</p>
+    async fn update_user_routing_profile(
+        &self,
+        input: UpdateUserRoutingProfileRequest,
+    ) -> Result<(), RusotoError<UpdateUserRoutingProfileError>> {
+        self.update_user_routing_profile_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Assigns the specified security profiles to the specified user.</p>
+    async fn update_user_security_profiles(
+        &self,
+        input: UpdateUserSecurityProfilesRequest,
+    ) -> Result<(), RusotoError<UpdateUserSecurityProfilesError>> {
+        self.update_user_security_profiles_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
     }
 }
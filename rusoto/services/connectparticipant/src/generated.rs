@@ -16,8 +16,8 @@ use std::fmt;
 use async_trait::async_trait;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
-use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest, ResponseMetadata};
+use rusoto_core::{Client, ErrorMetadata, RusotoError};
 
 use rusoto_core::proto;
 use rusoto_core::signature::SignedRequest;
@@ -262,6 +262,7 @@ pub struct Websocket {
 
 /// Errors returned by CreateParticipantConnection
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateParticipantConnectionError {
     /// <p>You do not have sufficient access to perform this action.</p>
     AccessDenied(String),
@@ -269,6 +270,8 @@ pub enum CreateParticipantConnectionError {
     InternalServer(String),
     /// <p>The request was denied due to request throttling.</p>
     Throttling(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateParticipantConnectionError {
@@ -278,22 +281,30 @@ impl CreateParticipantConnectionError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(CreateParticipantConnectionError::AccessDenied(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateParticipantConnectionError::AccessDenied(err.msg),
+                        &res,
+                    )
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(CreateParticipantConnectionError::InternalServer(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateParticipantConnectionError::InternalServer(err.msg),
+                        &res,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(CreateParticipantConnectionError::Throttling(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateParticipantConnectionError::Throttling(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        CreateParticipantConnectionError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -306,12 +317,14 @@ impl fmt::Display for CreateParticipantConnectionError {
             CreateParticipantConnectionError::AccessDenied(ref cause) => write!(f, "{}", cause),
             CreateParticipantConnectionError::InternalServer(ref cause) => write!(f, "{}", cause),
             CreateParticipantConnectionError::Throttling(ref cause) => write!(f, "{}", cause),
+            CreateParticipantConnectionError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateParticipantConnectionError {}
 /// Errors returned by DisconnectParticipant
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DisconnectParticipantError {
     /// <p>You do not have sufficient access to perform this action.</p>
     AccessDenied(String),
@@ -319,6 +332,8 @@ pub enum DisconnectParticipantError {
     InternalServer(String),
     /// <p>The request was denied due to request throttling.</p>
     Throttling(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DisconnectParticipantError {
@@ -326,18 +341,25 @@ impl DisconnectParticipantError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(DisconnectParticipantError::AccessDenied(err.msg))
+                    return RusotoError::service(
+                        DisconnectParticipantError::AccessDenied(err.msg),
+                        &res,
+                    )
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(DisconnectParticipantError::InternalServer(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DisconnectParticipantError::InternalServer(err.msg),
+                        &res,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DisconnectParticipantError::Throttling(err.msg))
+                    return RusotoError::service(
+                        DisconnectParticipantError::Throttling(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DisconnectParticipantError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -350,12 +372,14 @@ impl fmt::Display for DisconnectParticipantError {
             DisconnectParticipantError::AccessDenied(ref cause) => write!(f, "{}", cause),
             DisconnectParticipantError::InternalServer(ref cause) => write!(f, "{}", cause),
             DisconnectParticipantError::Throttling(ref cause) => write!(f, "{}", cause),
+            DisconnectParticipantError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DisconnectParticipantError {}
 /// Errors returned by GetTranscript
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetTranscriptError {
     /// <p>You do not have sufficient access to perform this action.</p>
     AccessDenied(String),
@@ -363,6 +387,8 @@ pub enum GetTranscriptError {
     InternalServer(String),
     /// <p>The request was denied due to request throttling.</p>
     Throttling(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetTranscriptError {
@@ -370,16 +396,16 @@ impl GetTranscriptError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(GetTranscriptError::AccessDenied(err.msg))
+                    return RusotoError::service(GetTranscriptError::AccessDenied(err.msg), &res)
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(GetTranscriptError::InternalServer(err.msg))
+                    return RusotoError::service(GetTranscriptError::InternalServer(err.msg), &res)
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(GetTranscriptError::Throttling(err.msg))
+                    return RusotoError::service(GetTranscriptError::Throttling(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetTranscriptError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -392,12 +418,14 @@ impl fmt::Display for GetTranscriptError {
             GetTranscriptError::AccessDenied(ref cause) => write!(f, "{}", cause),
             GetTranscriptError::InternalServer(ref cause) => write!(f, "{}", cause),
             GetTranscriptError::Throttling(ref cause) => write!(f, "{}", cause),
+            GetTranscriptError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetTranscriptError {}
 /// Errors returned by SendEvent
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum SendEventError {
     /// <p>You do not have sufficient access to perform this action.</p>
     AccessDenied(String),
@@ -405,6 +433,8 @@ pub enum SendEventError {
     InternalServer(String),
     /// <p>The request was denied due to request throttling.</p>
     Throttling(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl SendEventError {
@@ -412,16 +442,16 @@ impl SendEventError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(SendEventError::AccessDenied(err.msg))
+                    return RusotoError::service(SendEventError::AccessDenied(err.msg), &res)
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(SendEventError::InternalServer(err.msg))
+                    return RusotoError::service(SendEventError::InternalServer(err.msg), &res)
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(SendEventError::Throttling(err.msg))
+                    return RusotoError::service(SendEventError::Throttling(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(SendEventError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -434,12 +464,14 @@ impl fmt::Display for SendEventError {
             SendEventError::AccessDenied(ref cause) => write!(f, "{}", cause),
             SendEventError::InternalServer(ref cause) => write!(f, "{}", cause),
             SendEventError::Throttling(ref cause) => write!(f, "{}", cause),
+            SendEventError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for SendEventError {}
 /// Errors returned by SendMessage
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum SendMessageError {
     /// <p>You do not have sufficient access to perform this action.</p>
     AccessDenied(String),
@@ -447,6 +479,8 @@ pub enum SendMessageError {
     InternalServer(String),
     /// <p>The request was denied due to request throttling.</p>
     Throttling(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl SendMessageError {
@@ -454,16 +488,16 @@ impl SendMessageError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(SendMessageError::AccessDenied(err.msg))
+                    return RusotoError::service(SendMessageError::AccessDenied(err.msg), &res)
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(SendMessageError::InternalServer(err.msg))
+                    return RusotoError::service(SendMessageError::InternalServer(err.msg), &res)
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(SendMessageError::Throttling(err.msg))
+                    return RusotoError::service(SendMessageError::Throttling(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(SendMessageError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -476,6 +510,7 @@ impl fmt::Display for SendMessageError {
             SendMessageError::AccessDenied(ref cause) => write!(f, "{}", cause),
             SendMessageError::InternalServer(ref cause) => write!(f, "{}", cause),
             SendMessageError::Throttling(ref cause) => write!(f, "{}", cause),
+            SendMessageError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
@@ -524,6 +559,7 @@ impl ConnectParticipantClient {
     /// Creates a client backed by the default tokio event loop.
     ///
     /// The client will use the default credentials provider and tls client.
+    #[cfg(not(target_arch = "wasm32"))]
     pub fn new(region: region::Region) -> ConnectParticipantClient {
         ConnectParticipantClient {
             client: Client::shared(),
@@ -549,83 +585,138 @@ impl ConnectParticipantClient {
     pub fn new_with_client(client: Client, region: region::Region) -> ConnectParticipantClient {
         ConnectParticipantClient { client, region }
     }
+
+    /// Returns a builder of a client, setting any of its region, credentials provider,
+    /// dispatcher, retry policy, endpoint and timeouts.
+    pub fn builder() -> rusoto_core::ClientBuilder<ConnectParticipantClient> {
+        rusoto_core::ClientBuilder::new(ConnectParticipantClient::new_with_client)
+    }
+
+    /// Returns a clone of this client with the overrides of `config` applied to its
+    /// operations, sharing its connections.
+    pub fn customize(&self, config: &rusoto_core::OperationConfig) -> ConnectParticipantClient {
+        ConnectParticipantClient {
+            client: self.client.customize(config),
+            region: config.resolve_region(&self.region),
+        }
+    }
 }
 
-#[async_trait]
-impl ConnectParticipant for ConnectParticipantClient {
-    /// <p>Creates the participant's connection. Note that ParticipantToken is used for invoking this API instead of ConnectionToken.</p> <p>The participant token is valid for the lifetime of the participant – until the they are part of a contact.</p> <p>The response URL for <code>WEBSOCKET</code> Type has a connect expiry timeout of 100s. Clients must manually connect to the returned websocket URL and subscribe to the desired topic. </p> <p>For chat, you need to publish the following on the established websocket connection:</p> <p> <code>{"topic":"aws/subscribe","content":{"topics":["aws/chat"]}}</code> </p> <p>Upon websocket URL expiry, as specified in the response ConnectionExpiry parameter, clients need to call this API again to obtain a new websocket URL and perform the same steps as before.</p>
-    async fn create_participant_connection(
+impl ConnectParticipantClient {
+    /// Calls `create_participant_connection`, also returning the metadata of the response.
+    pub async fn create_participant_connection_with_metadata(
         &self,
         input: CreateParticipantConnectionRequest,
-    ) -> Result<CreateParticipantConnectionResponse, RusotoError<CreateParticipantConnectionError>>
-    {
-        let request_uri = "/participant/connection";
-
-        let mut request = SignedRequest::new("POST", "execute-api", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        request.set_endpoint_prefix("participant.connect".to_string());
-        let encoded = Some(serde_json::to_vec(&input).unwrap());
-        request.set_payload(encoded);
-        request.add_header("X-Amz-Bearer", &input.participant_token);
+    ) -> Result<
+        (CreateParticipantConnectionResponse, ResponseMetadata),
+        RusotoError<CreateParticipantConnectionError>,
+    > {
+        let request = self.build_create_participant_connection_request(input);
 
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<CreateParticipantConnectionResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             Err(CreateParticipantConnectionError::from_response(response))
         }
     }
 
-    /// <p>Disconnects a participant. Note that ConnectionToken is used for invoking this API instead of ParticipantToken.</p>
-    async fn disconnect_participant(
+    /// Presigns the request `create_participant_connection` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_participant_connection(
         &self,
-        input: DisconnectParticipantRequest,
-    ) -> Result<DisconnectParticipantResponse, RusotoError<DisconnectParticipantError>> {
-        let request_uri = "/participant/disconnect";
+        presigner: &rusoto_core::Presigner,
+        input: CreateParticipantConnectionRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_participant_connection_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_participant_connection_request(
+        &self,
+        input: CreateParticipantConnectionRequest,
+    ) -> SignedRequest {
+        let request_uri = "/participant/connection";
 
         let mut request = SignedRequest::new("POST", "execute-api", &self.region, &request_uri);
+        request.set_operation("CreateParticipantConnection");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("participant.connect".to_string());
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
-        request.add_header("X-Amz-Bearer", &input.connection_token);
+        request.add_header("X-Amz-Bearer", &input.participant_token);
+
+        request
+    }
+
+    /// Calls `disconnect_participant`, also returning the metadata of the response.
+    pub async fn disconnect_participant_with_metadata(
+        &self,
+        input: DisconnectParticipantRequest,
+    ) -> Result<
+        (DisconnectParticipantResponse, ResponseMetadata),
+        RusotoError<DisconnectParticipantError>,
+    > {
+        let request = self.build_disconnect_participant_request(input);
 
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<DisconnectParticipantResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             Err(DisconnectParticipantError::from_response(response))
         }
     }
 
-    /// <p>Retrieves a transcript of the session. Note that ConnectionToken is used for invoking this API instead of ParticipantToken.</p>
-    async fn get_transcript(
+    /// Presigns the request `disconnect_participant` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_disconnect_participant(
         &self,
-        input: GetTranscriptRequest,
-    ) -> Result<GetTranscriptResponse, RusotoError<GetTranscriptError>> {
-        let request_uri = "/participant/transcript";
+        presigner: &rusoto_core::Presigner,
+        input: DisconnectParticipantRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_disconnect_participant_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_disconnect_participant_request(
+        &self,
+        input: DisconnectParticipantRequest,
+    ) -> SignedRequest {
+        let request_uri = "/participant/disconnect";
 
         let mut request = SignedRequest::new("POST", "execute-api", &self.region, &request_uri);
+        request.set_operation("DisconnectParticipant");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("participant.connect".to_string());
@@ -633,31 +724,55 @@ impl ConnectParticipant for ConnectParticipantClient {
         request.set_payload(encoded);
         request.add_header("X-Amz-Bearer", &input.connection_token);
 
+        request
+    }
+
+    /// Calls `get_transcript`, also returning the metadata of the response.
+    pub async fn get_transcript_with_metadata(
+        &self,
+        input: GetTranscriptRequest,
+    ) -> Result<(GetTranscriptResponse, ResponseMetadata), RusotoError<GetTranscriptError>> {
+        let request = self.build_get_transcript_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<GetTranscriptResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             Err(GetTranscriptError::from_response(response))
         }
     }
 
-    /// <p>Sends an event. Note that ConnectionToken is used for invoking this API instead of ParticipantToken.</p>
-    async fn send_event(
+    /// Presigns the request `get_transcript` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_transcript(
         &self,
-        input: SendEventRequest,
-    ) -> Result<SendEventResponse, RusotoError<SendEventError>> {
-        let request_uri = "/participant/event";
+        presigner: &rusoto_core::Presigner,
+        input: GetTranscriptRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_transcript_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_transcript_request(&self, input: GetTranscriptRequest) -> SignedRequest {
+        let request_uri = "/participant/transcript";
 
         let mut request = SignedRequest::new("POST", "execute-api", &self.region, &request_uri);
+        request.set_operation("GetTranscript");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("participant.connect".to_string());
@@ -665,31 +780,55 @@ impl ConnectParticipant for ConnectParticipantClient {
         request.set_payload(encoded);
         request.add_header("X-Amz-Bearer", &input.connection_token);
 
+        request
+    }
+
+    /// Calls `send_event`, also returning the metadata of the response.
+    pub async fn send_event_with_metadata(
+        &self,
+        input: SendEventRequest,
+    ) -> Result<(SendEventResponse, ResponseMetadata), RusotoError<SendEventError>> {
+        let request = self.build_send_event_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<SendEventResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             Err(SendEventError::from_response(response))
         }
     }
 
-    /// <p>Sends a message. Note that ConnectionToken is used for invoking this API instead of ParticipantToken.</p>
-    async fn send_message(
+    /// Presigns the request `send_event` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_send_event(
         &self,
-        input: SendMessageRequest,
-    ) -> Result<SendMessageResponse, RusotoError<SendMessageError>> {
-        let request_uri = "/participant/message";
+        presigner: &rusoto_core::Presigner,
+        input: SendEventRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_send_event_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_send_event_request(&self, input: SendEventRequest) -> SignedRequest {
+        let request_uri = "/participant/event";
 
         let mut request = SignedRequest::new("POST", "execute-api", &self.region, &request_uri);
+        request.set_operation("SendEvent");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("participant.connect".to_string());
@@ -697,20 +836,116 @@ impl ConnectParticipant for ConnectParticipantClient {
         request.set_payload(encoded);
         request.add_header("X-Amz-Bearer", &input.connection_token);
 
+        request
+    }
+
+    /// Calls `send_message`, also returning the metadata of the response.
+    pub async fn send_message_with_metadata(
+        &self,
+        input: SendMessageRequest,
+    ) -> Result<(SendMessageResponse, ResponseMetadata), RusotoError<SendMessageError>> {
+        let request = self.build_send_message_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<SendMessageResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             Err(SendMessageError::from_response(response))
         }
     }
+
+    /// Presigns the request `send_message` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_send_message(
+        &self,
+        presigner: &rusoto_core::Presigner,
+        input: SendMessageRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_send_message_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_send_message_request(&self, input: SendMessageRequest) -> SignedRequest {
+        let request_uri = "/participant/message";
+
+        let mut request = SignedRequest::new("POST", "execute-api", &self.region, &request_uri);
+        request.set_operation("SendMessage");
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        request.set_endpoint_prefix("participant.connect".to_string());
+        let encoded = Some(serde_json::to_vec(&input).unwrap());
+        request.set_payload(encoded);
+        request.add_header("X-Amz-Bearer", &input.connection_token);
+
+        request
+    }
+}
+
+#[async_trait]
+impl ConnectParticipant for ConnectParticipantClient {
+    /// <p>Creates the participant's connection. Note that ParticipantToken is used for invoking this API instead of ConnectionToken.</p> <p>The participant token is valid for the lifetime of the participant – until the they are part of a contact.</p> <p>The response URL for <code>WEBSOCKET</code> Type has a connect expiry timeout of 100s. Clients must manually connect to the returned websocket URL and subscribe to the desired topic. </p> <p>For chat, you need to publish the following on the established websocket connection:</p> <p> <code>{"topic":"aws/subscribe","content":{"topics":["aws/chat"]}}</code> </p> <p>Upon websocket URL expiry, as specified in the response ConnectionExpiry parameter, clients need to call this API again to obtain a new websocket URL and perform the same steps as before.</p>
+    async fn create_participant_connection(
+        &self,
+        input: CreateParticipantConnectionRequest,
+    ) -> Result<CreateParticipantConnectionResponse, RusotoError<CreateParticipantConnectionError>>
+    {
+        self.create_participant_connection_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Disconnects a participant. Note that ConnectionToken is used for invoking this API instead of ParticipantToken.</p>
+    async fn disconnect_participant(
+        &self,
+        input: DisconnectParticipantRequest,
+    ) -> Result<DisconnectParticipantResponse, RusotoError<DisconnectParticipantError>> {
+        self.disconnect_participant_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Retrieves a transcript of the session. Note that ConnectionToken is used for invoking this API instead of ParticipantToken.</p>
+    async fn get_transcript(
+        &self,
+        input: GetTranscriptRequest,
+    ) -> Result<GetTranscriptResponse, RusotoError<GetTranscriptError>> {
+        self.get_transcript_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Sends an event. Note that ConnectionToken is used for invoking this API instead of ParticipantToken.</p>
+    async fn send_event(
+        &self,
+        input: SendEventRequest,
+    ) -> Result<SendEventResponse, RusotoError<SendEventError>> {
+        self.send_event_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Sends a message. Note that ConnectionToken is used for invoking this API instead of ParticipantToken.</p>
+    async fn send_message(
+        &self,
+        input: SendMessageRequest,
+    ) -> Result<SendMessageResponse, RusotoError<SendMessageError>> {
+        self.send_message_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
 }
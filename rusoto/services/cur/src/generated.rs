@@ -16,8 +16,8 @@ use std::fmt;
 use async_trait::async_trait;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
-use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest, ResponseMetadata};
+use rusoto_core::{Client, ErrorMetadata, RusotoError};
 
 use rusoto_core::proto;
 use rusoto_core::signature::SignedRequest;
@@ -130,9 +130,12 @@ pub struct ReportDefinition {
 
 /// Errors returned by DeleteReportDefinition
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteReportDefinitionError {
     /// <p>An error on the server occurred during the processing of your request. Try again later.</p>
     InternalError(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteReportDefinitionError {
@@ -140,12 +143,13 @@ impl DeleteReportDefinitionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalErrorException" => {
-                    return RusotoError::Service(DeleteReportDefinitionError::InternalError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteReportDefinitionError::InternalError(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteReportDefinitionError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -156,15 +160,19 @@ impl fmt::Display for DeleteReportDefinitionError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             DeleteReportDefinitionError::InternalError(ref cause) => write!(f, "{}", cause),
+            DeleteReportDefinitionError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteReportDefinitionError {}
 /// Errors returned by DescribeReportDefinitions
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeReportDefinitionsError {
     /// <p>An error on the server occurred during the processing of your request. Try again later.</p>
     InternalError(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeReportDefinitionsError {
@@ -172,12 +180,15 @@ impl DescribeReportDefinitionsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalErrorException" => {
-                    return RusotoError::Service(DescribeReportDefinitionsError::InternalError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeReportDefinitionsError::InternalError(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(DescribeReportDefinitionsError::Unhandled, &res)
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -188,15 +199,19 @@ impl fmt::Display for DescribeReportDefinitionsError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             DescribeReportDefinitionsError::InternalError(ref cause) => write!(f, "{}", cause),
+            DescribeReportDefinitionsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeReportDefinitionsError {}
 /// Errors returned by ModifyReportDefinition
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ModifyReportDefinitionError {
     /// <p>An error on the server occurred during the processing of your request. Try again later.</p>
     InternalError(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ModifyReportDefinitionError {
@@ -204,12 +219,13 @@ impl ModifyReportDefinitionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalErrorException" => {
-                    return RusotoError::Service(ModifyReportDefinitionError::InternalError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ModifyReportDefinitionError::InternalError(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ModifyReportDefinitionError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -220,12 +236,14 @@ impl fmt::Display for ModifyReportDefinitionError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             ModifyReportDefinitionError::InternalError(ref cause) => write!(f, "{}", cause),
+            ModifyReportDefinitionError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ModifyReportDefinitionError {}
 /// Errors returned by PutReportDefinition
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum PutReportDefinitionError {
     /// <p>A report with the specified name already exists in the account. Specify a different report name.</p>
     DuplicateReportName(String),
@@ -233,6 +251,8 @@ pub enum PutReportDefinitionError {
     InternalError(String),
     /// <p>This account already has five reports defined. To define a new report, you must delete an existing report.</p>
     ReportLimitReached(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl PutReportDefinitionError {
@@ -240,20 +260,25 @@ impl PutReportDefinitionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DuplicateReportNameException" => {
-                    return RusotoError::Service(PutReportDefinitionError::DuplicateReportName(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        PutReportDefinitionError::DuplicateReportName(err.msg),
+                        &res,
+                    )
                 }
                 "InternalErrorException" => {
-                    return RusotoError::Service(PutReportDefinitionError::InternalError(err.msg))
+                    return RusotoError::service(
+                        PutReportDefinitionError::InternalError(err.msg),
+                        &res,
+                    )
                 }
                 "ReportLimitReachedException" => {
-                    return RusotoError::Service(PutReportDefinitionError::ReportLimitReached(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        PutReportDefinitionError::ReportLimitReached(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(PutReportDefinitionError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -266,6 +291,7 @@ impl fmt::Display for PutReportDefinitionError {
             PutReportDefinitionError::DuplicateReportName(ref cause) => write!(f, "{}", cause),
             PutReportDefinitionError::InternalError(ref cause) => write!(f, "{}", cause),
             PutReportDefinitionError::ReportLimitReached(ref cause) => write!(f, "{}", cause),
+            PutReportDefinitionError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
@@ -308,6 +334,7 @@ impl CostAndUsageReportClient {
     /// Creates a client backed by the default tokio event loop.
     ///
     /// The client will use the default credentials provider and tls client.
+    #[cfg(not(target_arch = "wasm32"))]
     pub fn new(region: region::Region) -> CostAndUsageReportClient {
         CostAndUsageReportClient {
             client: Client::shared(),
@@ -333,16 +360,73 @@ impl CostAndUsageReportClient {
     pub fn new_with_client(client: Client, region: region::Region) -> CostAndUsageReportClient {
         CostAndUsageReportClient { client, region }
     }
+
+    /// Returns a builder of a client, setting any of its region, credentials provider,
+    /// dispatcher, retry policy, endpoint and timeouts.
+    pub fn builder() -> rusoto_core::ClientBuilder<CostAndUsageReportClient> {
+        rusoto_core::ClientBuilder::new(CostAndUsageReportClient::new_with_client)
+    }
+
+    /// Returns a clone of this client with the overrides of `config` applied to its
+    /// operations, sharing its connections.
+    pub fn customize(&self, config: &rusoto_core::OperationConfig) -> CostAndUsageReportClient {
+        CostAndUsageReportClient {
+            client: self.client.customize(config),
+            region: config.resolve_region(&self.region),
+        }
+    }
 }
 
-#[async_trait]
-impl CostAndUsageReport for CostAndUsageReportClient {
-    /// <p>Deletes the specified report.</p>
-    async fn delete_report_definition(
+impl CostAndUsageReportClient {
+    /// Calls `delete_report_definition`, also returning the metadata of the response.
+    pub async fn delete_report_definition_with_metadata(
         &self,
         input: DeleteReportDefinitionRequest,
-    ) -> Result<DeleteReportDefinitionResponse, RusotoError<DeleteReportDefinitionError>> {
+    ) -> Result<
+        (DeleteReportDefinitionResponse, ResponseMetadata),
+        RusotoError<DeleteReportDefinitionError>,
+    > {
+        let request = self.build_delete_report_definition_request(input);
+
+        let mut response = self
+            .client
+            .sign_and_dispatch(request)
+            .await
+            .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
+        if response.status.is_success() {
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DeleteReportDefinitionResponse, _>()?;
+            Ok((result, metadata))
+        } else {
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DeleteReportDefinitionError::from_response(response))
+        }
+    }
+
+    /// Presigns the request `delete_report_definition` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_report_definition(
+        &self,
+        presigner: &rusoto_core::Presigner,
+        input: DeleteReportDefinitionRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_report_definition_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_report_definition_request(
+        &self,
+        input: DeleteReportDefinitionRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "cur", &self.region, "/");
+        request.set_operation("DeleteReportDefinition");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -352,29 +436,58 @@ impl CostAndUsageReport for CostAndUsageReportClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `describe_report_definitions`, also returning the metadata of the response.
+    pub async fn describe_report_definitions_with_metadata(
+        &self,
+        input: DescribeReportDefinitionsRequest,
+    ) -> Result<
+        (DescribeReportDefinitionsResponse, ResponseMetadata),
+        RusotoError<DescribeReportDefinitionsError>,
+    > {
+        let request = self.build_describe_report_definitions_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DeleteReportDefinitionResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DescribeReportDefinitionsResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteReportDefinitionError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DescribeReportDefinitionsError::from_response(response))
         }
     }
 
-    /// <p>Lists the AWS Cost and Usage reports available to this account.</p>
-    async fn describe_report_definitions(
+    /// Presigns the request `describe_report_definitions` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_report_definitions(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeReportDefinitionsRequest,
-    ) -> Result<DescribeReportDefinitionsResponse, RusotoError<DescribeReportDefinitionsError>>
-    {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_report_definitions_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_report_definitions_request(
+        &self,
+        input: DescribeReportDefinitionsRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "cur", &self.region, "/");
+        request.set_operation("DescribeReportDefinitions");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -384,28 +497,58 @@ impl CostAndUsageReport for CostAndUsageReportClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `modify_report_definition`, also returning the metadata of the response.
+    pub async fn modify_report_definition_with_metadata(
+        &self,
+        input: ModifyReportDefinitionRequest,
+    ) -> Result<
+        (ModifyReportDefinitionResponse, ResponseMetadata),
+        RusotoError<ModifyReportDefinitionError>,
+    > {
+        let request = self.build_modify_report_definition_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DescribeReportDefinitionsResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ModifyReportDefinitionResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeReportDefinitionsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ModifyReportDefinitionError::from_response(response))
         }
     }
 
-    /// <p>Allows you to programatically update your report preferences.</p>
-    async fn modify_report_definition(
+    /// Presigns the request `modify_report_definition` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_modify_report_definition(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ModifyReportDefinitionRequest,
-    ) -> Result<ModifyReportDefinitionResponse, RusotoError<ModifyReportDefinitionError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_modify_report_definition_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_modify_report_definition_request(
+        &self,
+        input: ModifyReportDefinitionRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "cur", &self.region, "/");
+        request.set_operation("ModifyReportDefinition");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -415,28 +558,58 @@ impl CostAndUsageReport for CostAndUsageReportClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `put_report_definition`, also returning the metadata of the response.
+    pub async fn put_report_definition_with_metadata(
+        &self,
+        input: PutReportDefinitionRequest,
+    ) -> Result<
+        (PutReportDefinitionResponse, ResponseMetadata),
+        RusotoError<PutReportDefinitionError>,
+    > {
+        let request = self.build_put_report_definition_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<ModifyReportDefinitionResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<PutReportDefinitionResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ModifyReportDefinitionError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(PutReportDefinitionError::from_response(response))
         }
     }
 
-    /// <p>Creates a new report using the description that you provide.</p>
-    async fn put_report_definition(
+    /// Presigns the request `put_report_definition` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_put_report_definition(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: PutReportDefinitionRequest,
-    ) -> Result<PutReportDefinitionResponse, RusotoError<PutReportDefinitionError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_put_report_definition_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_put_report_definition_request(
+        &self,
+        input: PutReportDefinitionRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "cur", &self.region, "/");
+        request.set_operation("PutReportDefinition");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -446,19 +619,94 @@ impl CostAndUsageReport for CostAndUsageReportClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
-        let mut response = self
-            .client
-            .sign_and_dispatch(request)
+        request
+    }
+
+    /// The pages of `DescribeReportDefinitions` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `MaxResults` of `input`.
+    pub fn describe_report_definitions_pages(
+        &self,
+        input: DescribeReportDefinitionsRequest,
+    ) -> impl futures::Stream<
+        Item = Result<
+            DescribeReportDefinitionsResponse,
+            RusotoError<DescribeReportDefinitionsError>,
+        >,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: CostAndUsageReportClient, input| async move {
+                client.describe_report_definitions(input).await
+            },
+            |input: &DescribeReportDefinitionsRequest,
+             output: &DescribeReportDefinitionsResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(DescribeReportDefinitionsRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `ReportDefinitions` of every page of `DescribeReportDefinitions` results for `input`, as `describe_report_definitions_pages` requests them.
+    pub fn describe_report_definitions_stream(
+        &self,
+        input: DescribeReportDefinitionsRequest,
+    ) -> impl futures::Stream<
+        Item = Result<ReportDefinition, RusotoError<DescribeReportDefinitionsError>>,
+    > + Send {
+        rusoto_core::pagination::items(self.describe_report_definitions_pages(input), |page| {
+            page.report_definitions.unwrap_or_default()
+        })
+    }
+}
+
+#[async_trait]
+impl CostAndUsageReport for CostAndUsageReportClient {
+    /// <p>Deletes the specified report.</p>
+    async fn delete_report_definition(
+        &self,
+        input: DeleteReportDefinitionRequest,
+    ) -> Result<DeleteReportDefinitionResponse, RusotoError<DeleteReportDefinitionError>> {
+        self.delete_report_definition_with_metadata(input)
             .await
-            .map_err(RusotoError::from)?;
-        if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<PutReportDefinitionResponse, _>()
-        } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(PutReportDefinitionError::from_response(response))
-        }
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Lists the AWS Cost and Usage reports available to this account.</p>
+    async fn describe_report_definitions(
+        &self,
+        input: DescribeReportDefinitionsRequest,
+    ) -> Result<DescribeReportDefinitionsResponse, RusotoError<DescribeReportDefinitionsError>>
+    {
+        self.describe_report_definitions_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Allows you to programatically update your report preferences.</p>
+    async fn modify_report_definition(
+        &self,
+        input: ModifyReportDefinitionRequest,
+    ) -> Result<ModifyReportDefinitionResponse, RusotoError<ModifyReportDefinitionError>> {
+        self.modify_report_definition_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Creates a new report using the description that you provide.</p>
+    async fn put_report_definition(
+        &self,
+        input: PutReportDefinitionRequest,
+    ) -> Result<PutReportDefinitionResponse, RusotoError<PutReportDefinitionError>> {
+        self.put_report_definition_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
     }
 }
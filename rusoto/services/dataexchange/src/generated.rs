@@ -16,8 +16,8 @@ use std::fmt;
 use async_trait::async_trait;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
-use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest, ResponseMetadata};
+use rusoto_core::{Client, ErrorMetadata, RusotoError};
 
 use rusoto_core::param::{Params, ServiceParams};
 use rusoto_core::proto;
@@ -1241,6 +1241,7 @@ pub struct UpdateRevisionResponse {
 
 /// Errors returned by CancelJob
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CancelJobError {
     /// <p>The request couldn't be completed because it conflicted with the current state of the resource.</p>
     Conflict(String),
@@ -1250,6 +1251,8 @@ pub enum CancelJobError {
     ResourceNotFound(String),
     /// <p>The limit on the number of requests per second was exceeded.</p>
     Throttling(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CancelJobError {
@@ -1257,19 +1260,19 @@ impl CancelJobError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ConflictException" => {
-                    return RusotoError::Service(CancelJobError::Conflict(err.msg))
+                    return RusotoError::service(CancelJobError::Conflict(err.msg), &res)
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(CancelJobError::InternalServer(err.msg))
+                    return RusotoError::service(CancelJobError::InternalServer(err.msg), &res)
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(CancelJobError::ResourceNotFound(err.msg))
+                    return RusotoError::service(CancelJobError::ResourceNotFound(err.msg), &res)
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(CancelJobError::Throttling(err.msg))
+                    return RusotoError::service(CancelJobError::Throttling(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CancelJobError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1283,12 +1286,14 @@ impl fmt::Display for CancelJobError {
             CancelJobError::InternalServer(ref cause) => write!(f, "{}", cause),
             CancelJobError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             CancelJobError::Throttling(ref cause) => write!(f, "{}", cause),
+            CancelJobError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CancelJobError {}
 /// Errors returned by CreateDataSet
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateDataSetError {
     /// <p>Access to the resource is denied.</p>
     AccessDenied(String),
@@ -1298,6 +1303,8 @@ pub enum CreateDataSetError {
     ServiceLimitExceeded(String),
     /// <p>The limit on the number of requests per second was exceeded.</p>
     Throttling(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateDataSetError {
@@ -1305,19 +1312,22 @@ impl CreateDataSetError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(CreateDataSetError::AccessDenied(err.msg))
+                    return RusotoError::service(CreateDataSetError::AccessDenied(err.msg), &res)
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(CreateDataSetError::InternalServer(err.msg))
+                    return RusotoError::service(CreateDataSetError::InternalServer(err.msg), &res)
                 }
                 "ServiceLimitExceededException" => {
-                    return RusotoError::Service(CreateDataSetError::ServiceLimitExceeded(err.msg))
+                    return RusotoError::service(
+                        CreateDataSetError::ServiceLimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(CreateDataSetError::Throttling(err.msg))
+                    return RusotoError::service(CreateDataSetError::Throttling(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreateDataSetError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1331,12 +1341,14 @@ impl fmt::Display for CreateDataSetError {
             CreateDataSetError::InternalServer(ref cause) => write!(f, "{}", cause),
             CreateDataSetError::ServiceLimitExceeded(ref cause) => write!(f, "{}", cause),
             CreateDataSetError::Throttling(ref cause) => write!(f, "{}", cause),
+            CreateDataSetError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateDataSetError {}
 /// Errors returned by CreateJob
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateJobError {
     /// <p>Access to the resource is denied.</p>
     AccessDenied(String),
@@ -1346,6 +1358,8 @@ pub enum CreateJobError {
     ResourceNotFound(String),
     /// <p>The limit on the number of requests per second was exceeded.</p>
     Throttling(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateJobError {
@@ -1353,19 +1367,19 @@ impl CreateJobError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(CreateJobError::AccessDenied(err.msg))
+                    return RusotoError::service(CreateJobError::AccessDenied(err.msg), &res)
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(CreateJobError::InternalServer(err.msg))
+                    return RusotoError::service(CreateJobError::InternalServer(err.msg), &res)
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(CreateJobError::ResourceNotFound(err.msg))
+                    return RusotoError::service(CreateJobError::ResourceNotFound(err.msg), &res)
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(CreateJobError::Throttling(err.msg))
+                    return RusotoError::service(CreateJobError::Throttling(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreateJobError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1379,12 +1393,14 @@ impl fmt::Display for CreateJobError {
             CreateJobError::InternalServer(ref cause) => write!(f, "{}", cause),
             CreateJobError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             CreateJobError::Throttling(ref cause) => write!(f, "{}", cause),
+            CreateJobError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateJobError {}
 /// Errors returned by CreateRevision
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateRevisionError {
     /// <p>Access to the resource is denied.</p>
     AccessDenied(String),
@@ -1394,6 +1410,8 @@ pub enum CreateRevisionError {
     ResourceNotFound(String),
     /// <p>The limit on the number of requests per second was exceeded.</p>
     Throttling(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateRevisionError {
@@ -1401,19 +1419,22 @@ impl CreateRevisionError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(CreateRevisionError::AccessDenied(err.msg))
+                    return RusotoError::service(CreateRevisionError::AccessDenied(err.msg), &res)
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(CreateRevisionError::InternalServer(err.msg))
+                    return RusotoError::service(CreateRevisionError::InternalServer(err.msg), &res)
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(CreateRevisionError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        CreateRevisionError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(CreateRevisionError::Throttling(err.msg))
+                    return RusotoError::service(CreateRevisionError::Throttling(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreateRevisionError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1427,12 +1448,14 @@ impl fmt::Display for CreateRevisionError {
             CreateRevisionError::InternalServer(ref cause) => write!(f, "{}", cause),
             CreateRevisionError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             CreateRevisionError::Throttling(ref cause) => write!(f, "{}", cause),
+            CreateRevisionError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateRevisionError {}
 /// Errors returned by DeleteAsset
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteAssetError {
     /// <p>Access to the resource is denied.</p>
     AccessDenied(String),
@@ -1444,6 +1467,8 @@ pub enum DeleteAssetError {
     ResourceNotFound(String),
     /// <p>The limit on the number of requests per second was exceeded.</p>
     Throttling(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteAssetError {
@@ -1451,22 +1476,22 @@ impl DeleteAssetError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(DeleteAssetError::AccessDenied(err.msg))
+                    return RusotoError::service(DeleteAssetError::AccessDenied(err.msg), &res)
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(DeleteAssetError::Conflict(err.msg))
+                    return RusotoError::service(DeleteAssetError::Conflict(err.msg), &res)
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(DeleteAssetError::InternalServer(err.msg))
+                    return RusotoError::service(DeleteAssetError::InternalServer(err.msg), &res)
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteAssetError::ResourceNotFound(err.msg))
+                    return RusotoError::service(DeleteAssetError::ResourceNotFound(err.msg), &res)
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DeleteAssetError::Throttling(err.msg))
+                    return RusotoError::service(DeleteAssetError::Throttling(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteAssetError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1481,12 +1506,14 @@ impl fmt::Display for DeleteAssetError {
             DeleteAssetError::InternalServer(ref cause) => write!(f, "{}", cause),
             DeleteAssetError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             DeleteAssetError::Throttling(ref cause) => write!(f, "{}", cause),
+            DeleteAssetError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteAssetError {}
 /// Errors returned by DeleteDataSet
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteDataSetError {
     /// <p>Access to the resource is denied.</p>
     AccessDenied(String),
@@ -1498,6 +1525,8 @@ pub enum DeleteDataSetError {
     ResourceNotFound(String),
     /// <p>The limit on the number of requests per second was exceeded.</p>
     Throttling(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteDataSetError {
@@ -1505,22 +1534,25 @@ impl DeleteDataSetError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(DeleteDataSetError::AccessDenied(err.msg))
+                    return RusotoError::service(DeleteDataSetError::AccessDenied(err.msg), &res)
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(DeleteDataSetError::Conflict(err.msg))
+                    return RusotoError::service(DeleteDataSetError::Conflict(err.msg), &res)
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(DeleteDataSetError::InternalServer(err.msg))
+                    return RusotoError::service(DeleteDataSetError::InternalServer(err.msg), &res)
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteDataSetError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        DeleteDataSetError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DeleteDataSetError::Throttling(err.msg))
+                    return RusotoError::service(DeleteDataSetError::Throttling(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteDataSetError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1535,12 +1567,14 @@ impl fmt::Display for DeleteDataSetError {
             DeleteDataSetError::InternalServer(ref cause) => write!(f, "{}", cause),
             DeleteDataSetError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             DeleteDataSetError::Throttling(ref cause) => write!(f, "{}", cause),
+            DeleteDataSetError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteDataSetError {}
 /// Errors returned by DeleteRevision
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteRevisionError {
     /// <p>Access to the resource is denied.</p>
     AccessDenied(String),
@@ -1552,6 +1586,8 @@ pub enum DeleteRevisionError {
     ResourceNotFound(String),
     /// <p>The limit on the number of requests per second was exceeded.</p>
     Throttling(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteRevisionError {
@@ -1559,22 +1595,25 @@ impl DeleteRevisionError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(DeleteRevisionError::AccessDenied(err.msg))
+                    return RusotoError::service(DeleteRevisionError::AccessDenied(err.msg), &res)
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(DeleteRevisionError::Conflict(err.msg))
+                    return RusotoError::service(DeleteRevisionError::Conflict(err.msg), &res)
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(DeleteRevisionError::InternalServer(err.msg))
+                    return RusotoError::service(DeleteRevisionError::InternalServer(err.msg), &res)
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteRevisionError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        DeleteRevisionError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DeleteRevisionError::Throttling(err.msg))
+                    return RusotoError::service(DeleteRevisionError::Throttling(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteRevisionError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1589,12 +1628,14 @@ impl fmt::Display for DeleteRevisionError {
             DeleteRevisionError::InternalServer(ref cause) => write!(f, "{}", cause),
             DeleteRevisionError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             DeleteRevisionError::Throttling(ref cause) => write!(f, "{}", cause),
+            DeleteRevisionError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteRevisionError {}
 /// Errors returned by GetAsset
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetAssetError {
     /// <p>An exception occurred with the service.</p>
     InternalServer(String),
@@ -1602,6 +1643,8 @@ pub enum GetAssetError {
     ResourceNotFound(String),
     /// <p>The limit on the number of requests per second was exceeded.</p>
     Throttling(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetAssetError {
@@ -1609,16 +1652,16 @@ impl GetAssetError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(GetAssetError::InternalServer(err.msg))
+                    return RusotoError::service(GetAssetError::InternalServer(err.msg), &res)
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetAssetError::ResourceNotFound(err.msg))
+                    return RusotoError::service(GetAssetError::ResourceNotFound(err.msg), &res)
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(GetAssetError::Throttling(err.msg))
+                    return RusotoError::service(GetAssetError::Throttling(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetAssetError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1631,12 +1674,14 @@ impl fmt::Display for GetAssetError {
             GetAssetError::InternalServer(ref cause) => write!(f, "{}", cause),
             GetAssetError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             GetAssetError::Throttling(ref cause) => write!(f, "{}", cause),
+            GetAssetError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetAssetError {}
 /// Errors returned by GetDataSet
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetDataSetError {
     /// <p>An exception occurred with the service.</p>
     InternalServer(String),
@@ -1644,6 +1689,8 @@ pub enum GetDataSetError {
     ResourceNotFound(String),
     /// <p>The limit on the number of requests per second was exceeded.</p>
     Throttling(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetDataSetError {
@@ -1651,16 +1698,16 @@ impl GetDataSetError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(GetDataSetError::InternalServer(err.msg))
+                    return RusotoError::service(GetDataSetError::InternalServer(err.msg), &res)
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetDataSetError::ResourceNotFound(err.msg))
+                    return RusotoError::service(GetDataSetError::ResourceNotFound(err.msg), &res)
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(GetDataSetError::Throttling(err.msg))
+                    return RusotoError::service(GetDataSetError::Throttling(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetDataSetError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1673,12 +1720,14 @@ impl fmt::Display for GetDataSetError {
             GetDataSetError::InternalServer(ref cause) => write!(f, "{}", cause),
             GetDataSetError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             GetDataSetError::Throttling(ref cause) => write!(f, "{}", cause),
+            GetDataSetError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetDataSetError {}
 /// Errors returned by GetJob
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetJobError {
     /// <p>An exception occurred with the service.</p>
     InternalServer(String),
@@ -1686,6 +1735,8 @@ pub enum GetJobError {
     ResourceNotFound(String),
     /// <p>The limit on the number of requests per second was exceeded.</p>
     Throttling(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetJobError {
@@ -1693,16 +1744,16 @@ impl GetJobError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(GetJobError::InternalServer(err.msg))
+                    return RusotoError::service(GetJobError::InternalServer(err.msg), &res)
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetJobError::ResourceNotFound(err.msg))
+                    return RusotoError::service(GetJobError::ResourceNotFound(err.msg), &res)
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(GetJobError::Throttling(err.msg))
+                    return RusotoError::service(GetJobError::Throttling(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetJobError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1715,12 +1766,14 @@ impl fmt::Display for GetJobError {
             GetJobError::InternalServer(ref cause) => write!(f, "{}", cause),
             GetJobError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             GetJobError::Throttling(ref cause) => write!(f, "{}", cause),
+            GetJobError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetJobError {}
 /// Errors returned by GetRevision
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetRevisionError {
     /// <p>An exception occurred with the service.</p>
     InternalServer(String),
@@ -1728,6 +1781,8 @@ pub enum GetRevisionError {
     ResourceNotFound(String),
     /// <p>The limit on the number of requests per second was exceeded.</p>
     Throttling(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetRevisionError {
@@ -1735,16 +1790,16 @@ impl GetRevisionError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(GetRevisionError::InternalServer(err.msg))
+                    return RusotoError::service(GetRevisionError::InternalServer(err.msg), &res)
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetRevisionError::ResourceNotFound(err.msg))
+                    return RusotoError::service(GetRevisionError::ResourceNotFound(err.msg), &res)
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(GetRevisionError::Throttling(err.msg))
+                    return RusotoError::service(GetRevisionError::Throttling(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetRevisionError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1757,12 +1812,14 @@ impl fmt::Display for GetRevisionError {
             GetRevisionError::InternalServer(ref cause) => write!(f, "{}", cause),
             GetRevisionError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             GetRevisionError::Throttling(ref cause) => write!(f, "{}", cause),
+            GetRevisionError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetRevisionError {}
 /// Errors returned by ListDataSetRevisions
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListDataSetRevisionsError {
     /// <p>An exception occurred with the service.</p>
     InternalServer(String),
@@ -1770,6 +1827,8 @@ pub enum ListDataSetRevisionsError {
     ResourceNotFound(String),
     /// <p>The limit on the number of requests per second was exceeded.</p>
     Throttling(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListDataSetRevisionsError {
@@ -1777,18 +1836,25 @@ impl ListDataSetRevisionsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(ListDataSetRevisionsError::InternalServer(err.msg))
+                    return RusotoError::service(
+                        ListDataSetRevisionsError::InternalServer(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListDataSetRevisionsError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListDataSetRevisionsError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(ListDataSetRevisionsError::Throttling(err.msg))
+                    return RusotoError::service(
+                        ListDataSetRevisionsError::Throttling(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListDataSetRevisionsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1801,12 +1867,14 @@ impl fmt::Display for ListDataSetRevisionsError {
             ListDataSetRevisionsError::InternalServer(ref cause) => write!(f, "{}", cause),
             ListDataSetRevisionsError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             ListDataSetRevisionsError::Throttling(ref cause) => write!(f, "{}", cause),
+            ListDataSetRevisionsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListDataSetRevisionsError {}
 /// Errors returned by ListDataSets
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListDataSetsError {
     /// <p>An exception occurred with the service.</p>
     InternalServer(String),
@@ -1814,6 +1882,8 @@ pub enum ListDataSetsError {
     ResourceNotFound(String),
     /// <p>The limit on the number of requests per second was exceeded.</p>
     Throttling(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListDataSetsError {
@@ -1821,16 +1891,16 @@ impl ListDataSetsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(ListDataSetsError::InternalServer(err.msg))
+                    return RusotoError::service(ListDataSetsError::InternalServer(err.msg), &res)
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListDataSetsError::ResourceNotFound(err.msg))
+                    return RusotoError::service(ListDataSetsError::ResourceNotFound(err.msg), &res)
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(ListDataSetsError::Throttling(err.msg))
+                    return RusotoError::service(ListDataSetsError::Throttling(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListDataSetsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1843,12 +1913,14 @@ impl fmt::Display for ListDataSetsError {
             ListDataSetsError::InternalServer(ref cause) => write!(f, "{}", cause),
             ListDataSetsError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             ListDataSetsError::Throttling(ref cause) => write!(f, "{}", cause),
+            ListDataSetsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListDataSetsError {}
 /// Errors returned by ListJobs
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListJobsError {
     /// <p>An exception occurred with the service.</p>
     InternalServer(String),
@@ -1856,6 +1928,8 @@ pub enum ListJobsError {
     ResourceNotFound(String),
     /// <p>The limit on the number of requests per second was exceeded.</p>
     Throttling(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListJobsError {
@@ -1863,16 +1937,16 @@ impl ListJobsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(ListJobsError::InternalServer(err.msg))
+                    return RusotoError::service(ListJobsError::InternalServer(err.msg), &res)
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListJobsError::ResourceNotFound(err.msg))
+                    return RusotoError::service(ListJobsError::ResourceNotFound(err.msg), &res)
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(ListJobsError::Throttling(err.msg))
+                    return RusotoError::service(ListJobsError::Throttling(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListJobsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1885,12 +1959,14 @@ impl fmt::Display for ListJobsError {
             ListJobsError::InternalServer(ref cause) => write!(f, "{}", cause),
             ListJobsError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             ListJobsError::Throttling(ref cause) => write!(f, "{}", cause),
+            ListJobsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListJobsError {}
 /// Errors returned by ListRevisionAssets
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListRevisionAssetsError {
     /// <p>An exception occurred with the service.</p>
     InternalServer(String),
@@ -1898,6 +1974,8 @@ pub enum ListRevisionAssetsError {
     ResourceNotFound(String),
     /// <p>The limit on the number of requests per second was exceeded.</p>
     Throttling(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListRevisionAssetsError {
@@ -1905,16 +1983,22 @@ impl ListRevisionAssetsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(ListRevisionAssetsError::InternalServer(err.msg))
+                    return RusotoError::service(
+                        ListRevisionAssetsError::InternalServer(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListRevisionAssetsError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        ListRevisionAssetsError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(ListRevisionAssetsError::Throttling(err.msg))
+                    return RusotoError::service(ListRevisionAssetsError::Throttling(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListRevisionAssetsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1927,20 +2011,25 @@ impl fmt::Display for ListRevisionAssetsError {
             ListRevisionAssetsError::InternalServer(ref cause) => write!(f, "{}", cause),
             ListRevisionAssetsError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             ListRevisionAssetsError::Throttling(ref cause) => write!(f, "{}", cause),
+            ListRevisionAssetsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListRevisionAssetsError {}
 /// Errors returned by ListTagsForResource
 #[derive(Debug, PartialEq)]
-pub enum ListTagsForResourceError {}
+#[non_exhaustive]
+pub enum ListTagsForResourceError {
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
+}
 
 impl ListTagsForResourceError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<ListTagsForResourceError> {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListTagsForResourceError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1949,12 +2038,15 @@ impl ListTagsForResourceError {
 impl fmt::Display for ListTagsForResourceError {
     #[allow(unused_variables)]
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        match *self {}
+        match *self {
+            ListTagsForResourceError::Unhandled(ref metadata) => write!(f, "{}", metadata),
+        }
     }
 }
 impl Error for ListTagsForResourceError {}
 /// Errors returned by StartJob
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum StartJobError {
     /// <p>Access to the resource is denied.</p>
     AccessDenied(String),
@@ -1966,6 +2058,8 @@ pub enum StartJobError {
     ResourceNotFound(String),
     /// <p>The limit on the number of requests per second was exceeded.</p>
     Throttling(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl StartJobError {
@@ -1973,22 +2067,22 @@ impl StartJobError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(StartJobError::AccessDenied(err.msg))
+                    return RusotoError::service(StartJobError::AccessDenied(err.msg), &res)
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(StartJobError::Conflict(err.msg))
+                    return RusotoError::service(StartJobError::Conflict(err.msg), &res)
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(StartJobError::InternalServer(err.msg))
+                    return RusotoError::service(StartJobError::InternalServer(err.msg), &res)
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(StartJobError::ResourceNotFound(err.msg))
+                    return RusotoError::service(StartJobError::ResourceNotFound(err.msg), &res)
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(StartJobError::Throttling(err.msg))
+                    return RusotoError::service(StartJobError::Throttling(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(StartJobError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2003,20 +2097,25 @@ impl fmt::Display for StartJobError {
             StartJobError::InternalServer(ref cause) => write!(f, "{}", cause),
             StartJobError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             StartJobError::Throttling(ref cause) => write!(f, "{}", cause),
+            StartJobError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for StartJobError {}
 /// Errors returned by TagResource
 #[derive(Debug, PartialEq)]
-pub enum TagResourceError {}
+#[non_exhaustive]
+pub enum TagResourceError {
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
+}
 
 impl TagResourceError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<TagResourceError> {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(TagResourceError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2025,20 +2124,26 @@ impl TagResourceError {
 impl fmt::Display for TagResourceError {
     #[allow(unused_variables)]
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        match *self {}
+        match *self {
+            TagResourceError::Unhandled(ref metadata) => write!(f, "{}", metadata),
+        }
     }
 }
 impl Error for TagResourceError {}
 /// Errors returned by UntagResource
 #[derive(Debug, PartialEq)]
-pub enum UntagResourceError {}
+#[non_exhaustive]
+pub enum UntagResourceError {
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
+}
 
 impl UntagResourceError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<UntagResourceError> {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UntagResourceError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2047,12 +2152,15 @@ impl UntagResourceError {
 impl fmt::Display for UntagResourceError {
     #[allow(unused_variables)]
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        match *self {}
+        match *self {
+            UntagResourceError::Unhandled(ref metadata) => write!(f, "{}", metadata),
+        }
     }
 }
 impl Error for UntagResourceError {}
 /// Errors returned by UpdateAsset
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdateAssetError {
     /// <p>Access to the resource is denied.</p>
     AccessDenied(String),
@@ -2064,6 +2172,8 @@ pub enum UpdateAssetError {
     ResourceNotFound(String),
     /// <p>The limit on the number of requests per second was exceeded.</p>
     Throttling(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdateAssetError {
@@ -2071,22 +2181,22 @@ impl UpdateAssetError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(UpdateAssetError::AccessDenied(err.msg))
+                    return RusotoError::service(UpdateAssetError::AccessDenied(err.msg), &res)
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(UpdateAssetError::Conflict(err.msg))
+                    return RusotoError::service(UpdateAssetError::Conflict(err.msg), &res)
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(UpdateAssetError::InternalServer(err.msg))
+                    return RusotoError::service(UpdateAssetError::InternalServer(err.msg), &res)
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateAssetError::ResourceNotFound(err.msg))
+                    return RusotoError::service(UpdateAssetError::ResourceNotFound(err.msg), &res)
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(UpdateAssetError::Throttling(err.msg))
+                    return RusotoError::service(UpdateAssetError::Throttling(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UpdateAssetError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2101,12 +2211,14 @@ impl fmt::Display for UpdateAssetError {
             UpdateAssetError::InternalServer(ref cause) => write!(f, "{}", cause),
             UpdateAssetError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             UpdateAssetError::Throttling(ref cause) => write!(f, "{}", cause),
+            UpdateAssetError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for UpdateAssetError {}
 /// Errors returned by UpdateDataSet
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdateDataSetError {
     /// <p>Access to the resource is denied.</p>
     AccessDenied(String),
@@ -2116,6 +2228,8 @@ pub enum UpdateDataSetError {
     ResourceNotFound(String),
     /// <p>The limit on the number of requests per second was exceeded.</p>
     Throttling(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdateDataSetError {
@@ -2123,19 +2237,22 @@ impl UpdateDataSetError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(UpdateDataSetError::AccessDenied(err.msg))
+                    return RusotoError::service(UpdateDataSetError::AccessDenied(err.msg), &res)
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(UpdateDataSetError::InternalServer(err.msg))
+                    return RusotoError::service(UpdateDataSetError::InternalServer(err.msg), &res)
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateDataSetError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        UpdateDataSetError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(UpdateDataSetError::Throttling(err.msg))
+                    return RusotoError::service(UpdateDataSetError::Throttling(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UpdateDataSetError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2149,12 +2266,14 @@ impl fmt::Display for UpdateDataSetError {
             UpdateDataSetError::InternalServer(ref cause) => write!(f, "{}", cause),
             UpdateDataSetError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             UpdateDataSetError::Throttling(ref cause) => write!(f, "{}", cause),
+            UpdateDataSetError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for UpdateDataSetError {}
 /// Errors returned by UpdateRevision
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdateRevisionError {
     /// <p>Access to the resource is denied.</p>
     AccessDenied(String),
@@ -2166,6 +2285,8 @@ pub enum UpdateRevisionError {
     ResourceNotFound(String),
     /// <p>The limit on the number of requests per second was exceeded.</p>
     Throttling(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdateRevisionError {
@@ -2173,22 +2294,25 @@ impl UpdateRevisionError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(UpdateRevisionError::AccessDenied(err.msg))
+                    return RusotoError::service(UpdateRevisionError::AccessDenied(err.msg), &res)
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(UpdateRevisionError::Conflict(err.msg))
+                    return RusotoError::service(UpdateRevisionError::Conflict(err.msg), &res)
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(UpdateRevisionError::InternalServer(err.msg))
+                    return RusotoError::service(UpdateRevisionError::InternalServer(err.msg), &res)
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateRevisionError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        UpdateRevisionError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(UpdateRevisionError::Throttling(err.msg))
+                    return RusotoError::service(UpdateRevisionError::Throttling(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UpdateRevisionError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2203,6 +2327,7 @@ impl fmt::Display for UpdateRevisionError {
             UpdateRevisionError::InternalServer(ref cause) => write!(f, "{}", cause),
             UpdateRevisionError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             UpdateRevisionError::Throttling(ref cause) => write!(f, "{}", cause),
+            UpdateRevisionError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
@@ -2350,6 +2475,7 @@ impl DataExchangeClient {
     /// Creates a client backed by the default tokio event loop.
     ///
     /// The client will use the default credentials provider and tls client.
+    #[cfg(not(target_arch = "wasm32"))]
     pub fn new(region: region::Region) -> DataExchangeClient {
         DataExchangeClient {
             client: Client::shared(),
@@ -2375,131 +2501,280 @@ impl DataExchangeClient {
     pub fn new_with_client(client: Client, region: region::Region) -> DataExchangeClient {
         DataExchangeClient { client, region }
     }
+
+    /// Returns a builder of a client, setting any of its region, credentials provider,
+    /// dispatcher, retry policy, endpoint and timeouts.
+    pub fn builder() -> rusoto_core::ClientBuilder<DataExchangeClient> {
+        rusoto_core::ClientBuilder::new(DataExchangeClient::new_with_client)
+    }
+
+    /// Returns a clone of this client with the overrides of `config` applied to its
+    /// operations, sharing its connections.
+    pub fn customize(&self, config: &rusoto_core::OperationConfig) -> DataExchangeClient {
+        DataExchangeClient {
+            client: self.client.customize(config),
+            region: config.resolve_region(&self.region),
+        }
+    }
 }
 
-#[async_trait]
-impl DataExchange for DataExchangeClient {
-    /// <p>This operation cancels a job. Jobs can be cancelled only when they are in the WAITING state.</p>
-    async fn cancel_job(&self, input: CancelJobRequest) -> Result<(), RusotoError<CancelJobError>> {
+impl DataExchangeClient {
+    /// Calls `cancel_job`, also returning the metadata of the response.
+    pub async fn cancel_job_with_metadata(
+        &self,
+        input: CancelJobRequest,
+    ) -> Result<((), ResponseMetadata), RusotoError<CancelJobError>> {
+        let request = self.build_cancel_job_request(input);
+
+        let mut response = self
+            .client
+            .sign_and_dispatch(request)
+            .await
+            .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
+        if response.status.as_u16() == 204 {
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = ::std::mem::drop(response);
+
+            Ok((result, metadata))
+        } else {
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(CancelJobError::from_response(response))
+        }
+    }
+
+    /// Presigns the request `cancel_job` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_cancel_job(
+        &self,
+        presigner: &rusoto_core::Presigner,
+        input: CancelJobRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_cancel_job_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_cancel_job_request(&self, input: CancelJobRequest) -> SignedRequest {
         let request_uri = format!("/v1/jobs/{job_id}", job_id = input.job_id);
 
         let mut request = SignedRequest::new("DELETE", "dataexchange", &self.region, &request_uri);
+        request.set_operation("CancelJob");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
+        request
+    }
+
+    /// Calls `create_data_set`, also returning the metadata of the response.
+    pub async fn create_data_set_with_metadata(
+        &self,
+        input: CreateDataSetRequest,
+    ) -> Result<(CreateDataSetResponse, ResponseMetadata), RusotoError<CreateDataSetError>> {
+        let request = self.build_create_data_set_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
-        if response.status.as_u16() == 204 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            let result = ::std::mem::drop(response);
+        let metadata = ResponseMetadata::from_response(&response);
+        if response.status.as_u16() == 201 {
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<CreateDataSetResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(CancelJobError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(CreateDataSetError::from_response(response))
         }
     }
 
-    /// <p>This operation creates a data set.</p>
-    async fn create_data_set(
+    /// Presigns the request `create_data_set` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_data_set(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: CreateDataSetRequest,
-    ) -> Result<CreateDataSetResponse, RusotoError<CreateDataSetError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_data_set_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_data_set_request(&self, input: CreateDataSetRequest) -> SignedRequest {
         let request_uri = "/v1/data-sets";
 
         let mut request = SignedRequest::new("POST", "dataexchange", &self.region, &request_uri);
+        request.set_operation("CreateDataSet");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `create_job`, also returning the metadata of the response.
+    pub async fn create_job_with_metadata(
+        &self,
+        input: CreateJobRequest,
+    ) -> Result<(CreateJobResponse, ResponseMetadata), RusotoError<CreateJobError>> {
+        let request = self.build_create_job_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 201 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<CreateDataSetResponse, _>()?;
+                .deserialize::<CreateJobResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(CreateDataSetError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(CreateJobError::from_response(response))
         }
     }
 
-    /// <p>This operation creates a job.</p>
-    async fn create_job(
+    /// Presigns the request `create_job` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_job(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: CreateJobRequest,
-    ) -> Result<CreateJobResponse, RusotoError<CreateJobError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_job_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_job_request(&self, input: CreateJobRequest) -> SignedRequest {
         let request_uri = "/v1/jobs";
 
         let mut request = SignedRequest::new("POST", "dataexchange", &self.region, &request_uri);
+        request.set_operation("CreateJob");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `create_revision`, also returning the metadata of the response.
+    pub async fn create_revision_with_metadata(
+        &self,
+        input: CreateRevisionRequest,
+    ) -> Result<(CreateRevisionResponse, ResponseMetadata), RusotoError<CreateRevisionError>> {
+        let request = self.build_create_revision_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 201 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<CreateJobResponse, _>()?;
+                .deserialize::<CreateRevisionResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(CreateJobError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(CreateRevisionError::from_response(response))
         }
     }
 
-    /// <p>This operation creates a revision for a data set.</p>
-    async fn create_revision(
+    /// Presigns the request `create_revision` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_revision(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: CreateRevisionRequest,
-    ) -> Result<CreateRevisionResponse, RusotoError<CreateRevisionError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_revision_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_revision_request(&self, input: CreateRevisionRequest) -> SignedRequest {
         let request_uri = format!(
             "/v1/data-sets/{data_set_id}/revisions",
             data_set_id = input.data_set_id
         );
 
         let mut request = SignedRequest::new("POST", "dataexchange", &self.region, &request_uri);
+        request.set_operation("CreateRevision");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `delete_asset`, also returning the metadata of the response.
+    pub async fn delete_asset_with_metadata(
+        &self,
+        input: DeleteAssetRequest,
+    ) -> Result<((), ResponseMetadata), RusotoError<DeleteAssetError>> {
+        let request = self.build_delete_asset_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
-        if response.status.as_u16() == 201 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<CreateRevisionResponse, _>()?;
+        let metadata = ResponseMetadata::from_response(&response);
+        if response.status.as_u16() == 204 {
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = ::std::mem::drop(response);
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(CreateRevisionError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(DeleteAssetError::from_response(response))
         }
     }
 
-    /// <p>This operation deletes an asset.</p>
-    async fn delete_asset(
+    /// Presigns the request `delete_asset` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_asset(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteAssetRequest,
-    ) -> Result<(), RusotoError<DeleteAssetError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_asset_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_asset_request(&self, input: DeleteAssetRequest) -> SignedRequest {
         let request_uri = format!(
             "/v1/data-sets/{data_set_id}/revisions/{revision_id}/assets/{asset_id}",
             asset_id = input.asset_id,
@@ -2508,58 +2783,106 @@ impl DataExchange for DataExchangeClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "dataexchange", &self.region, &request_uri);
+        request.set_operation("DeleteAsset");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
+        request
+    }
+
+    /// Calls `delete_data_set`, also returning the metadata of the response.
+    pub async fn delete_data_set_with_metadata(
+        &self,
+        input: DeleteDataSetRequest,
+    ) -> Result<((), ResponseMetadata), RusotoError<DeleteDataSetError>> {
+        let request = self.build_delete_data_set_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 204 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = ::std::mem::drop(response);
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteAssetError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(DeleteDataSetError::from_response(response))
         }
     }
 
-    /// <p>This operation deletes a data set.</p>
-    async fn delete_data_set(
+    /// Presigns the request `delete_data_set` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_data_set(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteDataSetRequest,
-    ) -> Result<(), RusotoError<DeleteDataSetError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_data_set_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_data_set_request(&self, input: DeleteDataSetRequest) -> SignedRequest {
         let request_uri = format!(
             "/v1/data-sets/{data_set_id}",
             data_set_id = input.data_set_id
         );
 
         let mut request = SignedRequest::new("DELETE", "dataexchange", &self.region, &request_uri);
+        request.set_operation("DeleteDataSet");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
+        request
+    }
+
+    /// Calls `delete_revision`, also returning the metadata of the response.
+    pub async fn delete_revision_with_metadata(
+        &self,
+        input: DeleteRevisionRequest,
+    ) -> Result<((), ResponseMetadata), RusotoError<DeleteRevisionError>> {
+        let request = self.build_delete_revision_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 204 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = ::std::mem::drop(response);
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteDataSetError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(DeleteRevisionError::from_response(response))
         }
     }
 
-    /// <p>This operation deletes a revision.</p>
-    async fn delete_revision(
+    /// Presigns the request `delete_revision` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_revision(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteRevisionRequest,
-    ) -> Result<(), RusotoError<DeleteRevisionError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_revision_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_revision_request(&self, input: DeleteRevisionRequest) -> SignedRequest {
         let request_uri = format!(
             "/v1/data-sets/{data_set_id}/revisions/{revision_id}",
             data_set_id = input.data_set_id,
@@ -2567,29 +2890,54 @@ impl DataExchange for DataExchangeClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "dataexchange", &self.region, &request_uri);
+        request.set_operation("DeleteRevision");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
+        request
+    }
+
+    /// Calls `get_asset`, also returning the metadata of the response.
+    pub async fn get_asset_with_metadata(
+        &self,
+        input: GetAssetRequest,
+    ) -> Result<(GetAssetResponse, ResponseMetadata), RusotoError<GetAssetError>> {
+        let request = self.build_get_asset_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
-        if response.status.as_u16() == 204 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            let result = ::std::mem::drop(response);
+        let metadata = ResponseMetadata::from_response(&response);
+        if response.status.as_u16() == 200 {
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<GetAssetResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteRevisionError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(GetAssetError::from_response(response))
         }
     }
 
-    /// <p>This operation returns information about an asset.</p>
-    async fn get_asset(
+    /// Presigns the request `get_asset` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_asset(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetAssetRequest,
-    ) -> Result<GetAssetResponse, RusotoError<GetAssetError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_asset_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_asset_request(&self, input: GetAssetRequest) -> SignedRequest {
         let request_uri = format!(
             "/v1/data-sets/{data_set_id}/revisions/{revision_id}/assets/{asset_id}",
             asset_id = input.asset_id,
@@ -2598,87 +2946,159 @@ impl DataExchange for DataExchangeClient {
         );
 
         let mut request = SignedRequest::new("GET", "dataexchange", &self.region, &request_uri);
+        request.set_operation("GetAsset");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
+        request
+    }
+
+    /// Calls `get_data_set`, also returning the metadata of the response.
+    pub async fn get_data_set_with_metadata(
+        &self,
+        input: GetDataSetRequest,
+    ) -> Result<(GetDataSetResponse, ResponseMetadata), RusotoError<GetDataSetError>> {
+        let request = self.build_get_data_set_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<GetAssetResponse, _>()?;
+                .deserialize::<GetDataSetResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(GetAssetError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(GetDataSetError::from_response(response))
         }
     }
 
-    /// <p>This operation returns information about a data set.</p>
-    async fn get_data_set(
+    /// Presigns the request `get_data_set` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_data_set(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetDataSetRequest,
-    ) -> Result<GetDataSetResponse, RusotoError<GetDataSetError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_data_set_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_data_set_request(&self, input: GetDataSetRequest) -> SignedRequest {
         let request_uri = format!(
             "/v1/data-sets/{data_set_id}",
             data_set_id = input.data_set_id
         );
 
         let mut request = SignedRequest::new("GET", "dataexchange", &self.region, &request_uri);
+        request.set_operation("GetDataSet");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
+        request
+    }
+
+    /// Calls `get_job`, also returning the metadata of the response.
+    pub async fn get_job_with_metadata(
+        &self,
+        input: GetJobRequest,
+    ) -> Result<(GetJobResponse, ResponseMetadata), RusotoError<GetJobError>> {
+        let request = self.build_get_job_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<GetDataSetResponse, _>()?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result =
+                proto::json::ResponsePayload::new(&response).deserialize::<GetJobResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(GetDataSetError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(GetJobError::from_response(response))
         }
     }
 
-    /// <p>This operation returns information about a job.</p>
-    async fn get_job(
+    /// Presigns the request `get_job` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_job(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetJobRequest,
-    ) -> Result<GetJobResponse, RusotoError<GetJobError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_job_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_job_request(&self, input: GetJobRequest) -> SignedRequest {
         let request_uri = format!("/v1/jobs/{job_id}", job_id = input.job_id);
 
         let mut request = SignedRequest::new("GET", "dataexchange", &self.region, &request_uri);
+        request.set_operation("GetJob");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
+        request
+    }
+
+    /// Calls `get_revision`, also returning the metadata of the response.
+    pub async fn get_revision_with_metadata(
+        &self,
+        input: GetRevisionRequest,
+    ) -> Result<(GetRevisionResponse, ResponseMetadata), RusotoError<GetRevisionError>> {
+        let request = self.build_get_revision_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            let result =
-                proto::json::ResponsePayload::new(&response).deserialize::<GetJobResponse, _>()?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<GetRevisionResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(GetJobError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(GetRevisionError::from_response(response))
         }
     }
 
-    /// <p>This operation returns information about a revision.</p>
-    async fn get_revision(
+    /// Presigns the request `get_revision` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_revision(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetRevisionRequest,
-    ) -> Result<GetRevisionResponse, RusotoError<GetRevisionError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_revision_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_revision_request(&self, input: GetRevisionRequest) -> SignedRequest {
         let request_uri = format!(
             "/v1/data-sets/{data_set_id}/revisions/{revision_id}",
             data_set_id = input.data_set_id,
@@ -2686,36 +3106,67 @@ impl DataExchange for DataExchangeClient {
         );
 
         let mut request = SignedRequest::new("GET", "dataexchange", &self.region, &request_uri);
+        request.set_operation("GetRevision");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
+        request
+    }
+
+    /// Calls `list_data_set_revisions`, also returning the metadata of the response.
+    pub async fn list_data_set_revisions_with_metadata(
+        &self,
+        input: ListDataSetRevisionsRequest,
+    ) -> Result<
+        (ListDataSetRevisionsResponse, ResponseMetadata),
+        RusotoError<ListDataSetRevisionsError>,
+    > {
+        let request = self.build_list_data_set_revisions_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<GetRevisionResponse, _>()?;
+                .deserialize::<ListDataSetRevisionsResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(GetRevisionError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(ListDataSetRevisionsError::from_response(response))
         }
     }
 
-    /// <p>This operation lists a data set's revisions sorted by CreatedAt in descending order.</p>
-    async fn list_data_set_revisions(
+    /// Presigns the request `list_data_set_revisions` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_data_set_revisions(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListDataSetRevisionsRequest,
-    ) -> Result<ListDataSetRevisionsResponse, RusotoError<ListDataSetRevisionsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_data_set_revisions_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_data_set_revisions_request(
+        &self,
+        input: ListDataSetRevisionsRequest,
+    ) -> SignedRequest {
         let request_uri = format!(
             "/v1/data-sets/{data_set_id}/revisions",
             data_set_id = input.data_set_id
         );
 
         let mut request = SignedRequest::new("GET", "dataexchange", &self.region, &request_uri);
+        request.set_operation("ListDataSetRevisions");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -2727,31 +3178,55 @@ impl DataExchange for DataExchangeClient {
         }
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `list_data_sets`, also returning the metadata of the response.
+    pub async fn list_data_sets_with_metadata(
+        &self,
+        input: ListDataSetsRequest,
+    ) -> Result<(ListDataSetsResponse, ResponseMetadata), RusotoError<ListDataSetsError>> {
+        let request = self.build_list_data_sets_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListDataSetRevisionsResponse, _>()?;
+                .deserialize::<ListDataSetsResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(ListDataSetRevisionsError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(ListDataSetsError::from_response(response))
         }
     }
 
-    /// <p>This operation lists your data sets. When listing by origin OWNED, results are sorted by CreatedAt in descending order. When listing by origin ENTITLED, there is no order and the maxResults parameter is ignored.</p>
-    async fn list_data_sets(
+    /// Presigns the request `list_data_sets` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_data_sets(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListDataSetsRequest,
-    ) -> Result<ListDataSetsResponse, RusotoError<ListDataSetsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_data_sets_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_data_sets_request(&self, input: ListDataSetsRequest) -> SignedRequest {
         let request_uri = "/v1/data-sets";
 
         let mut request = SignedRequest::new("GET", "dataexchange", &self.region, &request_uri);
+        request.set_operation("ListDataSets");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -2766,31 +3241,55 @@ impl DataExchange for DataExchangeClient {
         }
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `list_jobs`, also returning the metadata of the response.
+    pub async fn list_jobs_with_metadata(
+        &self,
+        input: ListJobsRequest,
+    ) -> Result<(ListJobsResponse, ResponseMetadata), RusotoError<ListJobsError>> {
+        let request = self.build_list_jobs_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListDataSetsResponse, _>()?;
+                .deserialize::<ListJobsResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(ListDataSetsError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(ListJobsError::from_response(response))
         }
     }
 
-    /// <p>This operation lists your jobs sorted by CreatedAt in descending order.</p>
-    async fn list_jobs(
+    /// Presigns the request `list_jobs` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_jobs(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListJobsRequest,
-    ) -> Result<ListJobsResponse, RusotoError<ListJobsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_jobs_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_jobs_request(&self, input: ListJobsRequest) -> SignedRequest {
         let request_uri = "/v1/jobs";
 
         let mut request = SignedRequest::new("GET", "dataexchange", &self.region, &request_uri);
+        request.set_operation("ListJobs");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -2808,28 +3307,55 @@ impl DataExchange for DataExchangeClient {
         }
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `list_revision_assets`, also returning the metadata of the response.
+    pub async fn list_revision_assets_with_metadata(
+        &self,
+        input: ListRevisionAssetsRequest,
+    ) -> Result<(ListRevisionAssetsResponse, ResponseMetadata), RusotoError<ListRevisionAssetsError>>
+    {
+        let request = self.build_list_revision_assets_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListJobsResponse, _>()?;
+                .deserialize::<ListRevisionAssetsResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(ListJobsError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(ListRevisionAssetsError::from_response(response))
         }
     }
 
-    /// <p>This operation lists a revision's assets sorted alphabetically in descending order.</p>
-    async fn list_revision_assets(
+    /// Presigns the request `list_revision_assets` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_revision_assets(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListRevisionAssetsRequest,
-    ) -> Result<ListRevisionAssetsResponse, RusotoError<ListRevisionAssetsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_revision_assets_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_revision_assets_request(
+        &self,
+        input: ListRevisionAssetsRequest,
+    ) -> SignedRequest {
         let request_uri = format!(
             "/v1/data-sets/{data_set_id}/revisions/{revision_id}/assets",
             data_set_id = input.data_set_id,
@@ -2837,6 +3363,7 @@ impl DataExchange for DataExchangeClient {
         );
 
         let mut request = SignedRequest::new("GET", "dataexchange", &self.region, &request_uri);
+        request.set_operation("ListRevisionAssets");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -2848,114 +3375,215 @@ impl DataExchange for DataExchangeClient {
         }
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `list_tags_for_resource`, also returning the metadata of the response.
+    pub async fn list_tags_for_resource_with_metadata(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> Result<
+        (ListTagsForResourceResponse, ResponseMetadata),
+        RusotoError<ListTagsForResourceError>,
+    > {
+        let request = self.build_list_tags_for_resource_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListRevisionAssetsResponse, _>()?;
+                .deserialize::<ListTagsForResourceResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(ListRevisionAssetsError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(ListTagsForResourceError::from_response(response))
         }
     }
 
-    /// <p>This operation lists the tags on the resource.</p>
-    async fn list_tags_for_resource(
+    /// Presigns the request `list_tags_for_resource` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_tags_for_resource(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListTagsForResourceRequest,
-    ) -> Result<ListTagsForResourceResponse, RusotoError<ListTagsForResourceError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_tags_for_resource_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_tags_for_resource_request(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> SignedRequest {
         let request_uri = format!("/tags/{resource_arn}", resource_arn = input.resource_arn);
 
         let mut request = SignedRequest::new("GET", "dataexchange", &self.region, &request_uri);
+        request.set_operation("ListTagsForResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
+        request
+    }
+
+    /// Calls `start_job`, also returning the metadata of the response.
+    pub async fn start_job_with_metadata(
+        &self,
+        input: StartJobRequest,
+    ) -> Result<(StartJobResponse, ResponseMetadata), RusotoError<StartJobError>> {
+        let request = self.build_start_job_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
-        if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let metadata = ResponseMetadata::from_response(&response);
+        if response.status.as_u16() == 202 {
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListTagsForResourceResponse, _>()?;
+                .deserialize::<StartJobResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(ListTagsForResourceError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(StartJobError::from_response(response))
         }
     }
 
-    /// <p>This operation starts a job.</p>
-    async fn start_job(
+    /// Presigns the request `start_job` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_start_job(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: StartJobRequest,
-    ) -> Result<StartJobResponse, RusotoError<StartJobError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_start_job_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_start_job_request(&self, input: StartJobRequest) -> SignedRequest {
         let request_uri = format!("/v1/jobs/{job_id}", job_id = input.job_id);
 
         let mut request = SignedRequest::new("PATCH", "dataexchange", &self.region, &request_uri);
+        request.set_operation("StartJob");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
+        request
+    }
+
+    /// Calls `tag_resource`, also returning the metadata of the response.
+    pub async fn tag_resource_with_metadata(
+        &self,
+        input: TagResourceRequest,
+    ) -> Result<((), ResponseMetadata), RusotoError<TagResourceError>> {
+        let request = self.build_tag_resource_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
-        if response.status.as_u16() == 202 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<StartJobResponse, _>()?;
+        let metadata = ResponseMetadata::from_response(&response);
+        if response.status.as_u16() == 204 {
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = ::std::mem::drop(response);
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(StartJobError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(TagResourceError::from_response(response))
         }
     }
 
-    /// <p>This operation tags a resource.</p>
-    async fn tag_resource(
+    /// Presigns the request `tag_resource` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_tag_resource(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: TagResourceRequest,
-    ) -> Result<(), RusotoError<TagResourceError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_tag_resource_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_tag_resource_request(&self, input: TagResourceRequest) -> SignedRequest {
         let request_uri = format!("/tags/{resource_arn}", resource_arn = input.resource_arn);
 
         let mut request = SignedRequest::new("POST", "dataexchange", &self.region, &request_uri);
+        request.set_operation("TagResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `untag_resource`, also returning the metadata of the response.
+    pub async fn untag_resource_with_metadata(
+        &self,
+        input: UntagResourceRequest,
+    ) -> Result<((), ResponseMetadata), RusotoError<UntagResourceError>> {
+        let request = self.build_untag_resource_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 204 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = ::std::mem::drop(response);
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(TagResourceError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(UntagResourceError::from_response(response))
         }
     }
 
-    /// <p>This operation removes one or more tags from a resource.</p>
-    async fn untag_resource(
+    /// Presigns the request `untag_resource` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_untag_resource(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UntagResourceRequest,
-    ) -> Result<(), RusotoError<UntagResourceError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_untag_resource_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_untag_resource_request(&self, input: UntagResourceRequest) -> SignedRequest {
         let request_uri = format!("/tags/{resource_arn}", resource_arn = input.resource_arn);
 
         let mut request = SignedRequest::new("DELETE", "dataexchange", &self.region, &request_uri);
+        request.set_operation("UntagResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -2964,27 +3592,51 @@ impl DataExchange for DataExchangeClient {
         }
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `update_asset`, also returning the metadata of the response.
+    pub async fn update_asset_with_metadata(
+        &self,
+        input: UpdateAssetRequest,
+    ) -> Result<(UpdateAssetResponse, ResponseMetadata), RusotoError<UpdateAssetError>> {
+        let request = self.build_update_asset_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
-        if response.status.as_u16() == 204 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            let result = ::std::mem::drop(response);
+        let metadata = ResponseMetadata::from_response(&response);
+        if response.status.as_u16() == 200 {
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<UpdateAssetResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(UntagResourceError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(UpdateAssetError::from_response(response))
         }
     }
 
-    /// <p>This operation updates an asset.</p>
-    async fn update_asset(
+    /// Presigns the request `update_asset` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_asset(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UpdateAssetRequest,
-    ) -> Result<UpdateAssetResponse, RusotoError<UpdateAssetError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_asset_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_asset_request(&self, input: UpdateAssetRequest) -> SignedRequest {
         let request_uri = format!(
             "/v1/data-sets/{data_set_id}/revisions/{revision_id}/assets/{asset_id}",
             asset_id = input.asset_id,
@@ -2993,66 +3645,114 @@ impl DataExchange for DataExchangeClient {
         );
 
         let mut request = SignedRequest::new("PATCH", "dataexchange", &self.region, &request_uri);
+        request.set_operation("UpdateAsset");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `update_data_set`, also returning the metadata of the response.
+    pub async fn update_data_set_with_metadata(
+        &self,
+        input: UpdateDataSetRequest,
+    ) -> Result<(UpdateDataSetResponse, ResponseMetadata), RusotoError<UpdateDataSetError>> {
+        let request = self.build_update_data_set_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<UpdateAssetResponse, _>()?;
+                .deserialize::<UpdateDataSetResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(UpdateAssetError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(UpdateDataSetError::from_response(response))
         }
     }
 
-    /// <p>This operation updates a data set.</p>
-    async fn update_data_set(
+    /// Presigns the request `update_data_set` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_data_set(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UpdateDataSetRequest,
-    ) -> Result<UpdateDataSetResponse, RusotoError<UpdateDataSetError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_data_set_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_data_set_request(&self, input: UpdateDataSetRequest) -> SignedRequest {
         let request_uri = format!(
             "/v1/data-sets/{data_set_id}",
             data_set_id = input.data_set_id
         );
 
         let mut request = SignedRequest::new("PATCH", "dataexchange", &self.region, &request_uri);
+        request.set_operation("UpdateDataSet");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `update_revision`, also returning the metadata of the response.
+    pub async fn update_revision_with_metadata(
+        &self,
+        input: UpdateRevisionRequest,
+    ) -> Result<(UpdateRevisionResponse, ResponseMetadata), RusotoError<UpdateRevisionError>> {
+        let request = self.build_update_revision_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<UpdateDataSetResponse, _>()?;
+                .deserialize::<UpdateRevisionResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(UpdateDataSetError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(UpdateRevisionError::from_response(response))
         }
     }
 
-    /// <p>This operation updates a revision.</p>
-    async fn update_revision(
+    /// Presigns the request `update_revision` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_revision(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UpdateRevisionRequest,
-    ) -> Result<UpdateRevisionResponse, RusotoError<UpdateRevisionError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_revision_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_revision_request(&self, input: UpdateRevisionRequest) -> SignedRequest {
         let request_uri = format!(
             "/v1/data-sets/{data_set_id}/revisions/{revision_id}",
             data_set_id = input.data_set_id,
@@ -3060,25 +3760,379 @@ impl DataExchange for DataExchangeClient {
         );
 
         let mut request = SignedRequest::new("PATCH", "dataexchange", &self.region, &request_uri);
+        request.set_operation("UpdateRevision");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
-        let mut response = self
-            .client
-            .sign_and_dispatch(request)
+        request
+    }
+
+    /// The pages of `ListDataSetRevisions` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `MaxResults` of `input`.
+    pub fn list_data_set_revisions_pages(
+        &self,
+        input: ListDataSetRevisionsRequest,
+    ) -> impl futures::Stream<
+        Item = Result<ListDataSetRevisionsResponse, RusotoError<ListDataSetRevisionsError>>,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: DataExchangeClient, input| async move {
+                client.list_data_set_revisions(input).await
+            },
+            |input: &ListDataSetRevisionsRequest, output: &ListDataSetRevisionsResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListDataSetRevisionsRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `Revisions` of every page of `ListDataSetRevisions` results for `input`, as `list_data_set_revisions_pages` requests them.
+    pub fn list_data_set_revisions_stream(
+        &self,
+        input: ListDataSetRevisionsRequest,
+    ) -> impl futures::Stream<Item = Result<RevisionEntry, RusotoError<ListDataSetRevisionsError>>> + Send
+    {
+        rusoto_core::pagination::items(self.list_data_set_revisions_pages(input), |page| {
+            page.revisions.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `ListDataSets` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `MaxResults` of `input`.
+    pub fn list_data_sets_pages(
+        &self,
+        input: ListDataSetsRequest,
+    ) -> impl futures::Stream<Item = Result<ListDataSetsResponse, RusotoError<ListDataSetsError>>> + Send
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: DataExchangeClient, input| async move { client.list_data_sets(input).await },
+            |input: &ListDataSetsRequest, output: &ListDataSetsResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListDataSetsRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `DataSets` of every page of `ListDataSets` results for `input`, as `list_data_sets_pages` requests them.
+    pub fn list_data_sets_stream(
+        &self,
+        input: ListDataSetsRequest,
+    ) -> impl futures::Stream<Item = Result<DataSetEntry, RusotoError<ListDataSetsError>>> + Send
+    {
+        rusoto_core::pagination::items(self.list_data_sets_pages(input), |page| {
+            page.data_sets.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `ListJobs` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `MaxResults` of `input`.
+    pub fn list_jobs_pages(
+        &self,
+        input: ListJobsRequest,
+    ) -> impl futures::Stream<Item = Result<ListJobsResponse, RusotoError<ListJobsError>>> + Send
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: DataExchangeClient, input| async move { client.list_jobs(input).await },
+            |input: &ListJobsRequest, output: &ListJobsResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListJobsRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `Jobs` of every page of `ListJobs` results for `input`, as `list_jobs_pages` requests them.
+    pub fn list_jobs_stream(
+        &self,
+        input: ListJobsRequest,
+    ) -> impl futures::Stream<Item = Result<JobEntry, RusotoError<ListJobsError>>> + Send {
+        rusoto_core::pagination::items(self.list_jobs_pages(input), |page| {
+            page.jobs.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `ListRevisionAssets` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `MaxResults` of `input`.
+    pub fn list_revision_assets_pages(
+        &self,
+        input: ListRevisionAssetsRequest,
+    ) -> impl futures::Stream<
+        Item = Result<ListRevisionAssetsResponse, RusotoError<ListRevisionAssetsError>>,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: DataExchangeClient, input| async move { client.list_revision_assets(input).await },
+            |input: &ListRevisionAssetsRequest, output: &ListRevisionAssetsResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListRevisionAssetsRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `Assets` of every page of `ListRevisionAssets` results for `input`, as `list_revision_assets_pages` requests them.
+    pub fn list_revision_assets_stream(
+        &self,
+        input: ListRevisionAssetsRequest,
+    ) -> impl futures::Stream<Item = Result<AssetEntry, RusotoError<ListRevisionAssetsError>>> + Send
+    {
+        rusoto_core::pagination::items(self.list_revision_assets_pages(input), |page| {
+            page.assets.unwrap_or_default()
+        })
+    }
+}
+
+#[async_trait]
+impl DataExchange for DataExchangeClient {
+    /// <p>This operation cancels a job. Jobs can be cancelled only when they are in the WAITING state.</p>
+    async fn cancel_job(&self, input: CancelJobRequest) -> Result<(), RusotoError<CancelJobError>> {
+        self.cancel_job_with_metadata(input)
             .await
-            .map_err(RusotoError::from)?;
-        if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<UpdateRevisionResponse, _>()?;
+            .map(|(output, _)| output)
+    }
 
-            Ok(result)
-        } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(UpdateRevisionError::from_response(response))
-        }
+    /// <p>This operation creates a data set.</p>
+    async fn create_data_set(
+        &self,
+        input: CreateDataSetRequest,
+    ) -> Result<CreateDataSetResponse, RusotoError<CreateDataSetError>> {
+        self.create_data_set_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>This operation creates a job.</p>
+    async fn create_job(
+        &self,
+        input: CreateJobRequest,
+    ) -> Result<CreateJobResponse, RusotoError<CreateJobError>> {
+        self.create_job_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>This operation creates a revision for a data set.</p>
+    async fn create_revision(
+        &self,
+        input: CreateRevisionRequest,
+    ) -> Result<CreateRevisionResponse, RusotoError<CreateRevisionError>> {
+        self.create_revision_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>This operation deletes an asset.</p>
+    async fn delete_asset(
+        &self,
+        input: DeleteAssetRequest,
+    ) -> Result<(), RusotoError<DeleteAssetError>> {
+        self.delete_asset_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>This operation deletes a data set.</p>
+    async fn delete_data_set(
+        &self,
+        input: DeleteDataSetRequest,
+    ) -> Result<(), RusotoError<DeleteDataSetError>> {
+        self.delete_data_set_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>This operation deletes a revision.</p>
+    async fn delete_revision(
+        &self,
+        input: DeleteRevisionRequest,
+    ) -> Result<(), RusotoError<DeleteRevisionError>> {
+        self.delete_revision_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>This operation returns information about an asset.</p>
+    async fn get_asset(
+        &self,
+        input: GetAssetRequest,
+    ) -> Result<GetAssetResponse, RusotoError<GetAssetError>> {
+        self.get_asset_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>This operation returns information about a data set.</p>
+    async fn get_data_set(
+        &self,
+        input: GetDataSetRequest,
+    ) -> Result<GetDataSetResponse, RusotoError<GetDataSetError>> {
+        self.get_data_set_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>This operation returns information about a job.</p>
+    async fn get_job(
+        &self,
+        input: GetJobRequest,
+    ) -> Result<GetJobResponse, RusotoError<GetJobError>> {
+        self.get_job_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>This operation returns information about a revision.</p>
+    async fn get_revision(
+        &self,
+        input: GetRevisionRequest,
+    ) -> Result<GetRevisionResponse, RusotoError<GetRevisionError>> {
+        self.get_revision_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>This operation lists a data set's revisions sorted by CreatedAt in descending order.</p>
+    async fn list_data_set_revisions(
+        &self,
+        input: ListDataSetRevisionsRequest,
+    ) -> Result<ListDataSetRevisionsResponse, RusotoError<ListDataSetRevisionsError>> {
+        self.list_data_set_revisions_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>This operation lists your data sets. When listing by origin OWNED, results are sorted by CreatedAt in descending order. When listing by origin ENTITLED, there is no order and the maxResults parameter is ignored.</p>
+    async fn list_data_sets(
+        &self,
+        input: ListDataSetsRequest,
+    ) -> Result<ListDataSetsResponse, RusotoError<ListDataSetsError>> {
+        self.list_data_sets_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>This operation lists your jobs sorted by CreatedAt in descending order.</p>
+    async fn list_jobs(
+        &self,
+        input: ListJobsRequest,
+    ) -> Result<ListJobsResponse, RusotoError<ListJobsError>> {
+        self.list_jobs_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>This operation lists a revision's assets sorted alphabetically in descending order.</p>
+    async fn list_revision_assets(
+        &self,
+        input: ListRevisionAssetsRequest,
+    ) -> Result<ListRevisionAssetsResponse, RusotoError<ListRevisionAssetsError>> {
+        self.list_revision_assets_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>This operation lists the tags on the resource.</p>
+    async fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> Result<ListTagsForResourceResponse, RusotoError<ListTagsForResourceError>> {
+        self.list_tags_for_resource_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>This operation starts a job.</p>
+    async fn start_job(
+        &self,
+        input: StartJobRequest,
+    ) -> Result<StartJobResponse, RusotoError<StartJobError>> {
+        self.start_job_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>This operation tags a resource.</p>
+    async fn tag_resource(
+        &self,
+        input: TagResourceRequest,
+    ) -> Result<(), RusotoError<TagResourceError>> {
+        self.tag_resource_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>This operation removes one or more tags from a resource.</p>
+    async fn untag_resource(
+        &self,
+        input: UntagResourceRequest,
+    ) -> Result<(), RusotoError<UntagResourceError>> {
+        self.untag_resource_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>This operation updates an asset.</p>
+    async fn update_asset(
+        &self,
+        input: UpdateAssetRequest,
+    ) -> Result<UpdateAssetResponse, RusotoError<UpdateAssetError>> {
+        self.update_asset_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>This operation updates a data set.</p>
+    async fn update_data_set(
+        &self,
+        input: UpdateDataSetRequest,
+    ) -> Result<UpdateDataSetResponse, RusotoError<UpdateDataSetError>> {
+        self.update_data_set_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>This operation updates a revision.</p>
+    async fn update_revision(
+        &self,
+        input: UpdateRevisionRequest,
+    ) -> Result<UpdateRevisionResponse, RusotoError<UpdateRevisionError>> {
+        self.update_revision_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
     }
 }
@@ -16,8 +16,8 @@ use std::fmt;
 use async_trait::async_trait;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
-use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest, ResponseMetadata};
+use rusoto_core::{Client, ErrorMetadata, RusotoError};
 
 use rusoto_core::proto;
 use rusoto_core::signature::SignedRequest;
@@ -722,6 +722,7 @@ pub struct ValidationWarning {
 
 /// Errors returned by ActivatePipeline
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ActivatePipelineError {
     /// <p>An internal service error occurred.</p>
     InternalServiceError(String),
@@ -731,6 +732,8 @@ pub enum ActivatePipelineError {
     PipelineDeleted(String),
     /// <p>The specified pipeline was not found. Verify that you used the correct user and account identifiers.</p>
     PipelineNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ActivatePipelineError {
@@ -738,21 +741,31 @@ impl ActivatePipelineError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceError" => {
-                    return RusotoError::Service(ActivatePipelineError::InternalServiceError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ActivatePipelineError::InternalServiceError(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(ActivatePipelineError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        ActivatePipelineError::InvalidRequest(err.msg),
+                        &res,
+                    )
                 }
                 "PipelineDeletedException" => {
-                    return RusotoError::Service(ActivatePipelineError::PipelineDeleted(err.msg))
+                    return RusotoError::service(
+                        ActivatePipelineError::PipelineDeleted(err.msg),
+                        &res,
+                    )
                 }
                 "PipelineNotFoundException" => {
-                    return RusotoError::Service(ActivatePipelineError::PipelineNotFound(err.msg))
+                    return RusotoError::service(
+                        ActivatePipelineError::PipelineNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ActivatePipelineError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -766,12 +779,14 @@ impl fmt::Display for ActivatePipelineError {
             ActivatePipelineError::InvalidRequest(ref cause) => write!(f, "{}", cause),
             ActivatePipelineError::PipelineDeleted(ref cause) => write!(f, "{}", cause),
             ActivatePipelineError::PipelineNotFound(ref cause) => write!(f, "{}", cause),
+            ActivatePipelineError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ActivatePipelineError {}
 /// Errors returned by AddTags
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum AddTagsError {
     /// <p>An internal service error occurred.</p>
     InternalServiceError(String),
@@ -781,6 +796,8 @@ pub enum AddTagsError {
     PipelineDeleted(String),
     /// <p>The specified pipeline was not found. Verify that you used the correct user and account identifiers.</p>
     PipelineNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl AddTagsError {
@@ -788,19 +805,19 @@ impl AddTagsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceError" => {
-                    return RusotoError::Service(AddTagsError::InternalServiceError(err.msg))
+                    return RusotoError::service(AddTagsError::InternalServiceError(err.msg), &res)
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(AddTagsError::InvalidRequest(err.msg))
+                    return RusotoError::service(AddTagsError::InvalidRequest(err.msg), &res)
                 }
                 "PipelineDeletedException" => {
-                    return RusotoError::Service(AddTagsError::PipelineDeleted(err.msg))
+                    return RusotoError::service(AddTagsError::PipelineDeleted(err.msg), &res)
                 }
                 "PipelineNotFoundException" => {
-                    return RusotoError::Service(AddTagsError::PipelineNotFound(err.msg))
+                    return RusotoError::service(AddTagsError::PipelineNotFound(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(AddTagsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -814,17 +831,21 @@ impl fmt::Display for AddTagsError {
             AddTagsError::InvalidRequest(ref cause) => write!(f, "{}", cause),
             AddTagsError::PipelineDeleted(ref cause) => write!(f, "{}", cause),
             AddTagsError::PipelineNotFound(ref cause) => write!(f, "{}", cause),
+            AddTagsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for AddTagsError {}
 /// Errors returned by CreatePipeline
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreatePipelineError {
     /// <p>An internal service error occurred.</p>
     InternalServiceError(String),
     /// <p>The request was not valid. Verify that your request was properly formatted, that the signature was generated with the correct credentials, and that you haven't exceeded any of the service limits for your account.</p>
     InvalidRequest(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreatePipelineError {
@@ -832,13 +853,16 @@ impl CreatePipelineError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceError" => {
-                    return RusotoError::Service(CreatePipelineError::InternalServiceError(err.msg))
+                    return RusotoError::service(
+                        CreatePipelineError::InternalServiceError(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(CreatePipelineError::InvalidRequest(err.msg))
+                    return RusotoError::service(CreatePipelineError::InvalidRequest(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreatePipelineError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -850,12 +874,14 @@ impl fmt::Display for CreatePipelineError {
         match *self {
             CreatePipelineError::InternalServiceError(ref cause) => write!(f, "{}", cause),
             CreatePipelineError::InvalidRequest(ref cause) => write!(f, "{}", cause),
+            CreatePipelineError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreatePipelineError {}
 /// Errors returned by DeactivatePipeline
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeactivatePipelineError {
     /// <p>An internal service error occurred.</p>
     InternalServiceError(String),
@@ -865,6 +891,8 @@ pub enum DeactivatePipelineError {
     PipelineDeleted(String),
     /// <p>The specified pipeline was not found. Verify that you used the correct user and account identifiers.</p>
     PipelineNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeactivatePipelineError {
@@ -872,21 +900,31 @@ impl DeactivatePipelineError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceError" => {
-                    return RusotoError::Service(DeactivatePipelineError::InternalServiceError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeactivatePipelineError::InternalServiceError(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DeactivatePipelineError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        DeactivatePipelineError::InvalidRequest(err.msg),
+                        &res,
+                    )
                 }
                 "PipelineDeletedException" => {
-                    return RusotoError::Service(DeactivatePipelineError::PipelineDeleted(err.msg))
+                    return RusotoError::service(
+                        DeactivatePipelineError::PipelineDeleted(err.msg),
+                        &res,
+                    )
                 }
                 "PipelineNotFoundException" => {
-                    return RusotoError::Service(DeactivatePipelineError::PipelineNotFound(err.msg))
+                    return RusotoError::service(
+                        DeactivatePipelineError::PipelineNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeactivatePipelineError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -900,12 +938,14 @@ impl fmt::Display for DeactivatePipelineError {
             DeactivatePipelineError::InvalidRequest(ref cause) => write!(f, "{}", cause),
             DeactivatePipelineError::PipelineDeleted(ref cause) => write!(f, "{}", cause),
             DeactivatePipelineError::PipelineNotFound(ref cause) => write!(f, "{}", cause),
+            DeactivatePipelineError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeactivatePipelineError {}
 /// Errors returned by DeletePipeline
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeletePipelineError {
     /// <p>An internal service error occurred.</p>
     InternalServiceError(String),
@@ -913,6 +953,8 @@ pub enum DeletePipelineError {
     InvalidRequest(String),
     /// <p>The specified pipeline was not found. Verify that you used the correct user and account identifiers.</p>
     PipelineNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeletePipelineError {
@@ -920,16 +962,22 @@ impl DeletePipelineError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceError" => {
-                    return RusotoError::Service(DeletePipelineError::InternalServiceError(err.msg))
+                    return RusotoError::service(
+                        DeletePipelineError::InternalServiceError(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DeletePipelineError::InvalidRequest(err.msg))
+                    return RusotoError::service(DeletePipelineError::InvalidRequest(err.msg), &res)
                 }
                 "PipelineNotFoundException" => {
-                    return RusotoError::Service(DeletePipelineError::PipelineNotFound(err.msg))
+                    return RusotoError::service(
+                        DeletePipelineError::PipelineNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeletePipelineError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -942,12 +990,14 @@ impl fmt::Display for DeletePipelineError {
             DeletePipelineError::InternalServiceError(ref cause) => write!(f, "{}", cause),
             DeletePipelineError::InvalidRequest(ref cause) => write!(f, "{}", cause),
             DeletePipelineError::PipelineNotFound(ref cause) => write!(f, "{}", cause),
+            DeletePipelineError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeletePipelineError {}
 /// Errors returned by DescribeObjects
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeObjectsError {
     /// <p>An internal service error occurred.</p>
     InternalServiceError(String),
@@ -957,6 +1007,8 @@ pub enum DescribeObjectsError {
     PipelineDeleted(String),
     /// <p>The specified pipeline was not found. Verify that you used the correct user and account identifiers.</p>
     PipelineNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeObjectsError {
@@ -964,21 +1016,31 @@ impl DescribeObjectsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceError" => {
-                    return RusotoError::Service(DescribeObjectsError::InternalServiceError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeObjectsError::InternalServiceError(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DescribeObjectsError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        DescribeObjectsError::InvalidRequest(err.msg),
+                        &res,
+                    )
                 }
                 "PipelineDeletedException" => {
-                    return RusotoError::Service(DescribeObjectsError::PipelineDeleted(err.msg))
+                    return RusotoError::service(
+                        DescribeObjectsError::PipelineDeleted(err.msg),
+                        &res,
+                    )
                 }
                 "PipelineNotFoundException" => {
-                    return RusotoError::Service(DescribeObjectsError::PipelineNotFound(err.msg))
+                    return RusotoError::service(
+                        DescribeObjectsError::PipelineNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DescribeObjectsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -992,12 +1054,14 @@ impl fmt::Display for DescribeObjectsError {
             DescribeObjectsError::InvalidRequest(ref cause) => write!(f, "{}", cause),
             DescribeObjectsError::PipelineDeleted(ref cause) => write!(f, "{}", cause),
             DescribeObjectsError::PipelineNotFound(ref cause) => write!(f, "{}", cause),
+            DescribeObjectsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeObjectsError {}
 /// Errors returned by DescribePipelines
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribePipelinesError {
     /// <p>An internal service error occurred.</p>
     InternalServiceError(String),
@@ -1007,6 +1071,8 @@ pub enum DescribePipelinesError {
     PipelineDeleted(String),
     /// <p>The specified pipeline was not found. Verify that you used the correct user and account identifiers.</p>
     PipelineNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribePipelinesError {
@@ -1014,21 +1080,31 @@ impl DescribePipelinesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceError" => {
-                    return RusotoError::Service(DescribePipelinesError::InternalServiceError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribePipelinesError::InternalServiceError(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DescribePipelinesError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        DescribePipelinesError::InvalidRequest(err.msg),
+                        &res,
+                    )
                 }
                 "PipelineDeletedException" => {
-                    return RusotoError::Service(DescribePipelinesError::PipelineDeleted(err.msg))
+                    return RusotoError::service(
+                        DescribePipelinesError::PipelineDeleted(err.msg),
+                        &res,
+                    )
                 }
                 "PipelineNotFoundException" => {
-                    return RusotoError::Service(DescribePipelinesError::PipelineNotFound(err.msg))
+                    return RusotoError::service(
+                        DescribePipelinesError::PipelineNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DescribePipelinesError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1042,12 +1118,14 @@ impl fmt::Display for DescribePipelinesError {
             DescribePipelinesError::InvalidRequest(ref cause) => write!(f, "{}", cause),
             DescribePipelinesError::PipelineDeleted(ref cause) => write!(f, "{}", cause),
             DescribePipelinesError::PipelineNotFound(ref cause) => write!(f, "{}", cause),
+            DescribePipelinesError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribePipelinesError {}
 /// Errors returned by EvaluateExpression
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum EvaluateExpressionError {
     /// <p>An internal service error occurred.</p>
     InternalServiceError(String),
@@ -1059,6 +1137,8 @@ pub enum EvaluateExpressionError {
     PipelineNotFound(String),
     /// <p>The specified task was not found. </p>
     TaskNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl EvaluateExpressionError {
@@ -1066,24 +1146,37 @@ impl EvaluateExpressionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceError" => {
-                    return RusotoError::Service(EvaluateExpressionError::InternalServiceError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        EvaluateExpressionError::InternalServiceError(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(EvaluateExpressionError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        EvaluateExpressionError::InvalidRequest(err.msg),
+                        &res,
+                    )
                 }
                 "PipelineDeletedException" => {
-                    return RusotoError::Service(EvaluateExpressionError::PipelineDeleted(err.msg))
+                    return RusotoError::service(
+                        EvaluateExpressionError::PipelineDeleted(err.msg),
+                        &res,
+                    )
                 }
                 "PipelineNotFoundException" => {
-                    return RusotoError::Service(EvaluateExpressionError::PipelineNotFound(err.msg))
+                    return RusotoError::service(
+                        EvaluateExpressionError::PipelineNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "TaskNotFoundException" => {
-                    return RusotoError::Service(EvaluateExpressionError::TaskNotFound(err.msg))
+                    return RusotoError::service(
+                        EvaluateExpressionError::TaskNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(EvaluateExpressionError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1098,12 +1191,14 @@ impl fmt::Display for EvaluateExpressionError {
             EvaluateExpressionError::PipelineDeleted(ref cause) => write!(f, "{}", cause),
             EvaluateExpressionError::PipelineNotFound(ref cause) => write!(f, "{}", cause),
             EvaluateExpressionError::TaskNotFound(ref cause) => write!(f, "{}", cause),
+            EvaluateExpressionError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for EvaluateExpressionError {}
 /// Errors returned by GetPipelineDefinition
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetPipelineDefinitionError {
     /// <p>An internal service error occurred.</p>
     InternalServiceError(String),
@@ -1113,6 +1208,8 @@ pub enum GetPipelineDefinitionError {
     PipelineDeleted(String),
     /// <p>The specified pipeline was not found. Verify that you used the correct user and account identifiers.</p>
     PipelineNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetPipelineDefinitionError {
@@ -1120,27 +1217,31 @@ impl GetPipelineDefinitionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceError" => {
-                    return RusotoError::Service(GetPipelineDefinitionError::InternalServiceError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetPipelineDefinitionError::InternalServiceError(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(GetPipelineDefinitionError::InvalidRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetPipelineDefinitionError::InvalidRequest(err.msg),
+                        &res,
+                    )
                 }
                 "PipelineDeletedException" => {
-                    return RusotoError::Service(GetPipelineDefinitionError::PipelineDeleted(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetPipelineDefinitionError::PipelineDeleted(err.msg),
+                        &res,
+                    )
                 }
                 "PipelineNotFoundException" => {
-                    return RusotoError::Service(GetPipelineDefinitionError::PipelineNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetPipelineDefinitionError::PipelineNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetPipelineDefinitionError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1154,17 +1255,21 @@ impl fmt::Display for GetPipelineDefinitionError {
             GetPipelineDefinitionError::InvalidRequest(ref cause) => write!(f, "{}", cause),
             GetPipelineDefinitionError::PipelineDeleted(ref cause) => write!(f, "{}", cause),
             GetPipelineDefinitionError::PipelineNotFound(ref cause) => write!(f, "{}", cause),
+            GetPipelineDefinitionError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetPipelineDefinitionError {}
 /// Errors returned by ListPipelines
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListPipelinesError {
     /// <p>An internal service error occurred.</p>
     InternalServiceError(String),
     /// <p>The request was not valid. Verify that your request was properly formatted, that the signature was generated with the correct credentials, and that you haven't exceeded any of the service limits for your account.</p>
     InvalidRequest(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListPipelinesError {
@@ -1172,13 +1277,16 @@ impl ListPipelinesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceError" => {
-                    return RusotoError::Service(ListPipelinesError::InternalServiceError(err.msg))
+                    return RusotoError::service(
+                        ListPipelinesError::InternalServiceError(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(ListPipelinesError::InvalidRequest(err.msg))
+                    return RusotoError::service(ListPipelinesError::InvalidRequest(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListPipelinesError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1190,12 +1298,14 @@ impl fmt::Display for ListPipelinesError {
         match *self {
             ListPipelinesError::InternalServiceError(ref cause) => write!(f, "{}", cause),
             ListPipelinesError::InvalidRequest(ref cause) => write!(f, "{}", cause),
+            ListPipelinesError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListPipelinesError {}
 /// Errors returned by PollForTask
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum PollForTaskError {
     /// <p>An internal service error occurred.</p>
     InternalServiceError(String),
@@ -1203,6 +1313,8 @@ pub enum PollForTaskError {
     InvalidRequest(String),
     /// <p>The specified task was not found. </p>
     TaskNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl PollForTaskError {
@@ -1210,16 +1322,19 @@ impl PollForTaskError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceError" => {
-                    return RusotoError::Service(PollForTaskError::InternalServiceError(err.msg))
+                    return RusotoError::service(
+                        PollForTaskError::InternalServiceError(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(PollForTaskError::InvalidRequest(err.msg))
+                    return RusotoError::service(PollForTaskError::InvalidRequest(err.msg), &res)
                 }
                 "TaskNotFoundException" => {
-                    return RusotoError::Service(PollForTaskError::TaskNotFound(err.msg))
+                    return RusotoError::service(PollForTaskError::TaskNotFound(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(PollForTaskError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1232,12 +1347,14 @@ impl fmt::Display for PollForTaskError {
             PollForTaskError::InternalServiceError(ref cause) => write!(f, "{}", cause),
             PollForTaskError::InvalidRequest(ref cause) => write!(f, "{}", cause),
             PollForTaskError::TaskNotFound(ref cause) => write!(f, "{}", cause),
+            PollForTaskError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for PollForTaskError {}
 /// Errors returned by PutPipelineDefinition
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum PutPipelineDefinitionError {
     /// <p>An internal service error occurred.</p>
     InternalServiceError(String),
@@ -1247,6 +1364,8 @@ pub enum PutPipelineDefinitionError {
     PipelineDeleted(String),
     /// <p>The specified pipeline was not found. Verify that you used the correct user and account identifiers.</p>
     PipelineNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl PutPipelineDefinitionError {
@@ -1254,27 +1373,31 @@ impl PutPipelineDefinitionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceError" => {
-                    return RusotoError::Service(PutPipelineDefinitionError::InternalServiceError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        PutPipelineDefinitionError::InternalServiceError(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(PutPipelineDefinitionError::InvalidRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        PutPipelineDefinitionError::InvalidRequest(err.msg),
+                        &res,
+                    )
                 }
                 "PipelineDeletedException" => {
-                    return RusotoError::Service(PutPipelineDefinitionError::PipelineDeleted(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        PutPipelineDefinitionError::PipelineDeleted(err.msg),
+                        &res,
+                    )
                 }
                 "PipelineNotFoundException" => {
-                    return RusotoError::Service(PutPipelineDefinitionError::PipelineNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        PutPipelineDefinitionError::PipelineNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(PutPipelineDefinitionError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1288,12 +1411,14 @@ impl fmt::Display for PutPipelineDefinitionError {
             PutPipelineDefinitionError::InvalidRequest(ref cause) => write!(f, "{}", cause),
             PutPipelineDefinitionError::PipelineDeleted(ref cause) => write!(f, "{}", cause),
             PutPipelineDefinitionError::PipelineNotFound(ref cause) => write!(f, "{}", cause),
+            PutPipelineDefinitionError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for PutPipelineDefinitionError {}
 /// Errors returned by QueryObjects
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum QueryObjectsError {
     /// <p>An internal service error occurred.</p>
     InternalServiceError(String),
@@ -1303,6 +1428,8 @@ pub enum QueryObjectsError {
     PipelineDeleted(String),
     /// <p>The specified pipeline was not found. Verify that you used the correct user and account identifiers.</p>
     PipelineNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl QueryObjectsError {
@@ -1310,19 +1437,22 @@ impl QueryObjectsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceError" => {
-                    return RusotoError::Service(QueryObjectsError::InternalServiceError(err.msg))
+                    return RusotoError::service(
+                        QueryObjectsError::InternalServiceError(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(QueryObjectsError::InvalidRequest(err.msg))
+                    return RusotoError::service(QueryObjectsError::InvalidRequest(err.msg), &res)
                 }
                 "PipelineDeletedException" => {
-                    return RusotoError::Service(QueryObjectsError::PipelineDeleted(err.msg))
+                    return RusotoError::service(QueryObjectsError::PipelineDeleted(err.msg), &res)
                 }
                 "PipelineNotFoundException" => {
-                    return RusotoError::Service(QueryObjectsError::PipelineNotFound(err.msg))
+                    return RusotoError::service(QueryObjectsError::PipelineNotFound(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(QueryObjectsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1336,12 +1466,14 @@ impl fmt::Display for QueryObjectsError {
             QueryObjectsError::InvalidRequest(ref cause) => write!(f, "{}", cause),
             QueryObjectsError::PipelineDeleted(ref cause) => write!(f, "{}", cause),
             QueryObjectsError::PipelineNotFound(ref cause) => write!(f, "{}", cause),
+            QueryObjectsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for QueryObjectsError {}
 /// Errors returned by RemoveTags
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum RemoveTagsError {
     /// <p>An internal service error occurred.</p>
     InternalServiceError(String),
@@ -1351,6 +1483,8 @@ pub enum RemoveTagsError {
     PipelineDeleted(String),
     /// <p>The specified pipeline was not found. Verify that you used the correct user and account identifiers.</p>
     PipelineNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl RemoveTagsError {
@@ -1358,19 +1492,22 @@ impl RemoveTagsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceError" => {
-                    return RusotoError::Service(RemoveTagsError::InternalServiceError(err.msg))
+                    return RusotoError::service(
+                        RemoveTagsError::InternalServiceError(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(RemoveTagsError::InvalidRequest(err.msg))
+                    return RusotoError::service(RemoveTagsError::InvalidRequest(err.msg), &res)
                 }
                 "PipelineDeletedException" => {
-                    return RusotoError::Service(RemoveTagsError::PipelineDeleted(err.msg))
+                    return RusotoError::service(RemoveTagsError::PipelineDeleted(err.msg), &res)
                 }
                 "PipelineNotFoundException" => {
-                    return RusotoError::Service(RemoveTagsError::PipelineNotFound(err.msg))
+                    return RusotoError::service(RemoveTagsError::PipelineNotFound(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(RemoveTagsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1384,12 +1521,14 @@ impl fmt::Display for RemoveTagsError {
             RemoveTagsError::InvalidRequest(ref cause) => write!(f, "{}", cause),
             RemoveTagsError::PipelineDeleted(ref cause) => write!(f, "{}", cause),
             RemoveTagsError::PipelineNotFound(ref cause) => write!(f, "{}", cause),
+            RemoveTagsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for RemoveTagsError {}
 /// Errors returned by ReportTaskProgress
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ReportTaskProgressError {
     /// <p>An internal service error occurred.</p>
     InternalServiceError(String),
@@ -1401,6 +1540,8 @@ pub enum ReportTaskProgressError {
     PipelineNotFound(String),
     /// <p>The specified task was not found. </p>
     TaskNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ReportTaskProgressError {
@@ -1408,24 +1549,37 @@ impl ReportTaskProgressError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceError" => {
-                    return RusotoError::Service(ReportTaskProgressError::InternalServiceError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ReportTaskProgressError::InternalServiceError(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(ReportTaskProgressError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        ReportTaskProgressError::InvalidRequest(err.msg),
+                        &res,
+                    )
                 }
                 "PipelineDeletedException" => {
-                    return RusotoError::Service(ReportTaskProgressError::PipelineDeleted(err.msg))
+                    return RusotoError::service(
+                        ReportTaskProgressError::PipelineDeleted(err.msg),
+                        &res,
+                    )
                 }
                 "PipelineNotFoundException" => {
-                    return RusotoError::Service(ReportTaskProgressError::PipelineNotFound(err.msg))
+                    return RusotoError::service(
+                        ReportTaskProgressError::PipelineNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "TaskNotFoundException" => {
-                    return RusotoError::Service(ReportTaskProgressError::TaskNotFound(err.msg))
+                    return RusotoError::service(
+                        ReportTaskProgressError::TaskNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ReportTaskProgressError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1440,17 +1594,21 @@ impl fmt::Display for ReportTaskProgressError {
             ReportTaskProgressError::PipelineDeleted(ref cause) => write!(f, "{}", cause),
             ReportTaskProgressError::PipelineNotFound(ref cause) => write!(f, "{}", cause),
             ReportTaskProgressError::TaskNotFound(ref cause) => write!(f, "{}", cause),
+            ReportTaskProgressError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ReportTaskProgressError {}
 /// Errors returned by ReportTaskRunnerHeartbeat
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ReportTaskRunnerHeartbeatError {
     /// <p>An internal service error occurred.</p>
     InternalServiceError(String),
     /// <p>The request was not valid. Verify that your request was properly formatted, that the signature was generated with the correct credentials, and that you haven't exceeded any of the service limits for your account.</p>
     InvalidRequest(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ReportTaskRunnerHeartbeatError {
@@ -1458,17 +1616,21 @@ impl ReportTaskRunnerHeartbeatError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceError" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         ReportTaskRunnerHeartbeatError::InternalServiceError(err.msg),
+                        &res,
                     )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(ReportTaskRunnerHeartbeatError::InvalidRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ReportTaskRunnerHeartbeatError::InvalidRequest(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(ReportTaskRunnerHeartbeatError::Unhandled, &res)
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -1482,12 +1644,14 @@ impl fmt::Display for ReportTaskRunnerHeartbeatError {
                 write!(f, "{}", cause)
             }
             ReportTaskRunnerHeartbeatError::InvalidRequest(ref cause) => write!(f, "{}", cause),
+            ReportTaskRunnerHeartbeatError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ReportTaskRunnerHeartbeatError {}
 /// Errors returned by SetStatus
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum SetStatusError {
     /// <p>An internal service error occurred.</p>
     InternalServiceError(String),
@@ -1497,6 +1661,8 @@ pub enum SetStatusError {
     PipelineDeleted(String),
     /// <p>The specified pipeline was not found. Verify that you used the correct user and account identifiers.</p>
     PipelineNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl SetStatusError {
@@ -1504,19 +1670,22 @@ impl SetStatusError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceError" => {
-                    return RusotoError::Service(SetStatusError::InternalServiceError(err.msg))
+                    return RusotoError::service(
+                        SetStatusError::InternalServiceError(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(SetStatusError::InvalidRequest(err.msg))
+                    return RusotoError::service(SetStatusError::InvalidRequest(err.msg), &res)
                 }
                 "PipelineDeletedException" => {
-                    return RusotoError::Service(SetStatusError::PipelineDeleted(err.msg))
+                    return RusotoError::service(SetStatusError::PipelineDeleted(err.msg), &res)
                 }
                 "PipelineNotFoundException" => {
-                    return RusotoError::Service(SetStatusError::PipelineNotFound(err.msg))
+                    return RusotoError::service(SetStatusError::PipelineNotFound(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(SetStatusError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1530,12 +1699,14 @@ impl fmt::Display for SetStatusError {
             SetStatusError::InvalidRequest(ref cause) => write!(f, "{}", cause),
             SetStatusError::PipelineDeleted(ref cause) => write!(f, "{}", cause),
             SetStatusError::PipelineNotFound(ref cause) => write!(f, "{}", cause),
+            SetStatusError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for SetStatusError {}
 /// Errors returned by SetTaskStatus
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum SetTaskStatusError {
     /// <p>An internal service error occurred.</p>
     InternalServiceError(String),
@@ -1547,6 +1718,8 @@ pub enum SetTaskStatusError {
     PipelineNotFound(String),
     /// <p>The specified task was not found. </p>
     TaskNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl SetTaskStatusError {
@@ -1554,22 +1727,28 @@ impl SetTaskStatusError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceError" => {
-                    return RusotoError::Service(SetTaskStatusError::InternalServiceError(err.msg))
+                    return RusotoError::service(
+                        SetTaskStatusError::InternalServiceError(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(SetTaskStatusError::InvalidRequest(err.msg))
+                    return RusotoError::service(SetTaskStatusError::InvalidRequest(err.msg), &res)
                 }
                 "PipelineDeletedException" => {
-                    return RusotoError::Service(SetTaskStatusError::PipelineDeleted(err.msg))
+                    return RusotoError::service(SetTaskStatusError::PipelineDeleted(err.msg), &res)
                 }
                 "PipelineNotFoundException" => {
-                    return RusotoError::Service(SetTaskStatusError::PipelineNotFound(err.msg))
+                    return RusotoError::service(
+                        SetTaskStatusError::PipelineNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "TaskNotFoundException" => {
-                    return RusotoError::Service(SetTaskStatusError::TaskNotFound(err.msg))
+                    return RusotoError::service(SetTaskStatusError::TaskNotFound(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(SetTaskStatusError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1584,12 +1763,14 @@ impl fmt::Display for SetTaskStatusError {
             SetTaskStatusError::PipelineDeleted(ref cause) => write!(f, "{}", cause),
             SetTaskStatusError::PipelineNotFound(ref cause) => write!(f, "{}", cause),
             SetTaskStatusError::TaskNotFound(ref cause) => write!(f, "{}", cause),
+            SetTaskStatusError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for SetTaskStatusError {}
 /// Errors returned by ValidatePipelineDefinition
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ValidatePipelineDefinitionError {
     /// <p>An internal service error occurred.</p>
     InternalServiceError(String),
@@ -1599,6 +1780,8 @@ pub enum ValidatePipelineDefinitionError {
     PipelineDeleted(String),
     /// <p>The specified pipeline was not found. Verify that you used the correct user and account identifiers.</p>
     PipelineNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ValidatePipelineDefinitionError {
@@ -1608,27 +1791,33 @@ impl ValidatePipelineDefinitionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceError" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         ValidatePipelineDefinitionError::InternalServiceError(err.msg),
+                        &res,
                     )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(ValidatePipelineDefinitionError::InvalidRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ValidatePipelineDefinitionError::InvalidRequest(err.msg),
+                        &res,
+                    )
                 }
                 "PipelineDeletedException" => {
-                    return RusotoError::Service(ValidatePipelineDefinitionError::PipelineDeleted(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ValidatePipelineDefinitionError::PipelineDeleted(err.msg),
+                        &res,
+                    )
                 }
                 "PipelineNotFoundException" => {
-                    return RusotoError::Service(ValidatePipelineDefinitionError::PipelineNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ValidatePipelineDefinitionError::PipelineNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(ValidatePipelineDefinitionError::Unhandled, &res)
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -1644,6 +1833,7 @@ impl fmt::Display for ValidatePipelineDefinitionError {
             ValidatePipelineDefinitionError::InvalidRequest(ref cause) => write!(f, "{}", cause),
             ValidatePipelineDefinitionError::PipelineDeleted(ref cause) => write!(f, "{}", cause),
             ValidatePipelineDefinitionError::PipelineNotFound(ref cause) => write!(f, "{}", cause),
+            ValidatePipelineDefinitionError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
@@ -1773,6 +1963,7 @@ impl DataPipelineClient {
     /// Creates a client backed by the default tokio event loop.
     ///
     /// The client will use the default credentials provider and tls client.
+    #[cfg(not(target_arch = "wasm32"))]
     pub fn new(region: region::Region) -> DataPipelineClient {
         DataPipelineClient {
             client: Client::shared(),
@@ -1798,525 +1989,1333 @@ impl DataPipelineClient {
     pub fn new_with_client(client: Client, region: region::Region) -> DataPipelineClient {
         DataPipelineClient { client, region }
     }
+
+    /// Returns a builder of a client, setting any of its region, credentials provider,
+    /// dispatcher, retry policy, endpoint and timeouts.
+    pub fn builder() -> rusoto_core::ClientBuilder<DataPipelineClient> {
+        rusoto_core::ClientBuilder::new(DataPipelineClient::new_with_client)
+    }
+
+    /// Returns a clone of this client with the overrides of `config` applied to its
+    /// operations, sharing its connections.
+    pub fn customize(&self, config: &rusoto_core::OperationConfig) -> DataPipelineClient {
+        DataPipelineClient {
+            client: self.client.customize(config),
+            region: config.resolve_region(&self.region),
+        }
+    }
 }
 
-#[async_trait]
-impl DataPipeline for DataPipelineClient {
-    /// <p>Validates the specified pipeline and starts processing pipeline tasks. If the pipeline does not pass validation, activation fails.</p> <p>If you need to pause the pipeline to investigate an issue with a component, such as a data source or script, call <a>DeactivatePipeline</a>.</p> <p>To activate a finished pipeline, modify the end date for the pipeline and then activate it.</p>
-    async fn activate_pipeline(
+impl DataPipelineClient {
+    /// Calls `activate_pipeline`, also returning the metadata of the response.
+    pub async fn activate_pipeline_with_metadata(
         &self,
         input: ActivatePipelineInput,
-    ) -> Result<ActivatePipelineOutput, RusotoError<ActivatePipelineError>> {
+    ) -> Result<(ActivatePipelineOutput, ResponseMetadata), RusotoError<ActivatePipelineError>>
+    {
+        let request = self.build_activate_pipeline_request(input);
+
+        let mut response = self
+            .client
+            .sign_and_dispatch(request)
+            .await
+            .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
+        if response.status.is_success() {
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ActivatePipelineOutput, _>()?;
+            Ok((result, metadata))
+        } else {
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ActivatePipelineError::from_response(response))
+        }
+    }
+
+    /// Presigns the request `activate_pipeline` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_activate_pipeline(
+        &self,
+        presigner: &rusoto_core::Presigner,
+        input: ActivatePipelineInput,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_activate_pipeline_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_activate_pipeline_request(&self, input: ActivatePipelineInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "datapipeline", &self.region, "/");
+        request.set_operation("ActivatePipeline");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "DataPipeline.ActivatePipeline");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `add_tags`, also returning the metadata of the response.
+    pub async fn add_tags_with_metadata(
+        &self,
+        input: AddTagsInput,
+    ) -> Result<(AddTagsOutput, ResponseMetadata), RusotoError<AddTagsError>> {
+        let request = self.build_add_tags_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<ActivatePipelineOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result =
+                proto::json::ResponsePayload::new(&response).deserialize::<AddTagsOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ActivatePipelineError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(AddTagsError::from_response(response))
         }
     }
 
-    /// <p>Adds or modifies tags for the specified pipeline.</p>
-    async fn add_tags(
+    /// Presigns the request `add_tags` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_add_tags(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: AddTagsInput,
-    ) -> Result<AddTagsOutput, RusotoError<AddTagsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_add_tags_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_add_tags_request(&self, input: AddTagsInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "datapipeline", &self.region, "/");
+        request.set_operation("AddTags");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "DataPipeline.AddTags");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `create_pipeline`, also returning the metadata of the response.
+    pub async fn create_pipeline_with_metadata(
+        &self,
+        input: CreatePipelineInput,
+    ) -> Result<(CreatePipelineOutput, ResponseMetadata), RusotoError<CreatePipelineError>> {
+        let request = self.build_create_pipeline_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<AddTagsOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<CreatePipelineOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(AddTagsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(CreatePipelineError::from_response(response))
         }
     }
 
-    /// <p>Creates a new, empty pipeline. Use <a>PutPipelineDefinition</a> to populate the pipeline.</p>
-    async fn create_pipeline(
+    /// Presigns the request `create_pipeline` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_pipeline(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: CreatePipelineInput,
-    ) -> Result<CreatePipelineOutput, RusotoError<CreatePipelineError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_pipeline_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_pipeline_request(&self, input: CreatePipelineInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "datapipeline", &self.region, "/");
+        request.set_operation("CreatePipeline");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "DataPipeline.CreatePipeline");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `deactivate_pipeline`, also returning the metadata of the response.
+    pub async fn deactivate_pipeline_with_metadata(
+        &self,
+        input: DeactivatePipelineInput,
+    ) -> Result<(DeactivatePipelineOutput, ResponseMetadata), RusotoError<DeactivatePipelineError>>
+    {
+        let request = self.build_deactivate_pipeline_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<CreatePipelineOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DeactivatePipelineOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(CreatePipelineError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DeactivatePipelineError::from_response(response))
         }
     }
 
-    /// <p>Deactivates the specified running pipeline. The pipeline is set to the <code>DEACTIVATING</code> state until the deactivation process completes.</p> <p>To resume a deactivated pipeline, use <a>ActivatePipeline</a>. By default, the pipeline resumes from the last completed execution. Optionally, you can specify the date and time to resume the pipeline.</p>
-    async fn deactivate_pipeline(
+    /// Presigns the request `deactivate_pipeline` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_deactivate_pipeline(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeactivatePipelineInput,
-    ) -> Result<DeactivatePipelineOutput, RusotoError<DeactivatePipelineError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_deactivate_pipeline_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_deactivate_pipeline_request(&self, input: DeactivatePipelineInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "datapipeline", &self.region, "/");
+        request.set_operation("DeactivatePipeline");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "DataPipeline.DeactivatePipeline");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `delete_pipeline`, also returning the metadata of the response.
+    pub async fn delete_pipeline_with_metadata(
+        &self,
+        input: DeletePipelineInput,
+    ) -> Result<((), ResponseMetadata), RusotoError<DeletePipelineError>> {
+        let request = self.build_delete_pipeline_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DeactivatePipelineOutput, _>()
+            std::mem::drop(response);
+            Ok(((), metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DeactivatePipelineError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DeletePipelineError::from_response(response))
         }
     }
 
-    /// <p>Deletes a pipeline, its pipeline definition, and its run history. AWS Data Pipeline attempts to cancel instances associated with the pipeline that are currently being processed by task runners.</p> <p>Deleting a pipeline cannot be undone. You cannot query or restore a deleted pipeline. To temporarily pause a pipeline instead of deleting it, call <a>SetStatus</a> with the status set to <code>PAUSE</code> on individual components. Components that are paused by <a>SetStatus</a> can be resumed.</p>
-    async fn delete_pipeline(
+    /// Presigns the request `delete_pipeline` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_pipeline(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeletePipelineInput,
-    ) -> Result<(), RusotoError<DeletePipelineError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_pipeline_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_pipeline_request(&self, input: DeletePipelineInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "datapipeline", &self.region, "/");
+        request.set_operation("DeletePipeline");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "DataPipeline.DeletePipeline");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `describe_objects`, also returning the metadata of the response.
+    pub async fn describe_objects_with_metadata(
+        &self,
+        input: DescribeObjectsInput,
+    ) -> Result<(DescribeObjectsOutput, ResponseMetadata), RusotoError<DescribeObjectsError>> {
+        let request = self.build_describe_objects_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            std::mem::drop(response);
-            Ok(())
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DescribeObjectsOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DeletePipelineError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DescribeObjectsError::from_response(response))
         }
     }
 
-    /// <p>Gets the object definitions for a set of objects associated with the pipeline. Object definitions are composed of a set of fields that define the properties of the object.</p>
-    async fn describe_objects(
+    /// Presigns the request `describe_objects` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_objects(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeObjectsInput,
-    ) -> Result<DescribeObjectsOutput, RusotoError<DescribeObjectsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_objects_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_objects_request(&self, input: DescribeObjectsInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "datapipeline", &self.region, "/");
+        request.set_operation("DescribeObjects");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "DataPipeline.DescribeObjects");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `describe_pipelines`, also returning the metadata of the response.
+    pub async fn describe_pipelines_with_metadata(
+        &self,
+        input: DescribePipelinesInput,
+    ) -> Result<(DescribePipelinesOutput, ResponseMetadata), RusotoError<DescribePipelinesError>>
+    {
+        let request = self.build_describe_pipelines_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<DescribeObjectsOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DescribePipelinesOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeObjectsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DescribePipelinesError::from_response(response))
         }
     }
 
-    /// <p>Retrieves metadata about one or more pipelines. The information retrieved includes the name of the pipeline, the pipeline identifier, its current state, and the user account that owns the pipeline. Using account credentials, you can retrieve metadata about pipelines that you or your IAM users have created. If you are using an IAM user account, you can retrieve metadata about only those pipelines for which you have read permissions.</p> <p>To retrieve the full pipeline definition instead of metadata about the pipeline, call <a>GetPipelineDefinition</a>.</p>
-    async fn describe_pipelines(
+    /// Presigns the request `describe_pipelines` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_pipelines(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribePipelinesInput,
-    ) -> Result<DescribePipelinesOutput, RusotoError<DescribePipelinesError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_pipelines_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_pipelines_request(&self, input: DescribePipelinesInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "datapipeline", &self.region, "/");
+        request.set_operation("DescribePipelines");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "DataPipeline.DescribePipelines");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `evaluate_expression`, also returning the metadata of the response.
+    pub async fn evaluate_expression_with_metadata(
+        &self,
+        input: EvaluateExpressionInput,
+    ) -> Result<(EvaluateExpressionOutput, ResponseMetadata), RusotoError<EvaluateExpressionError>>
+    {
+        let request = self.build_evaluate_expression_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<DescribePipelinesOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<EvaluateExpressionOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribePipelinesError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(EvaluateExpressionError::from_response(response))
         }
     }
 
-    /// <p>Task runners call <code>EvaluateExpression</code> to evaluate a string in the context of the specified object. For example, a task runner can evaluate SQL queries stored in Amazon S3.</p>
-    async fn evaluate_expression(
+    /// Presigns the request `evaluate_expression` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_evaluate_expression(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: EvaluateExpressionInput,
-    ) -> Result<EvaluateExpressionOutput, RusotoError<EvaluateExpressionError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_evaluate_expression_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_evaluate_expression_request(&self, input: EvaluateExpressionInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "datapipeline", &self.region, "/");
+        request.set_operation("EvaluateExpression");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "DataPipeline.EvaluateExpression");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `get_pipeline_definition`, also returning the metadata of the response.
+    pub async fn get_pipeline_definition_with_metadata(
+        &self,
+        input: GetPipelineDefinitionInput,
+    ) -> Result<
+        (GetPipelineDefinitionOutput, ResponseMetadata),
+        RusotoError<GetPipelineDefinitionError>,
+    > {
+        let request = self.build_get_pipeline_definition_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<EvaluateExpressionOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<GetPipelineDefinitionOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(EvaluateExpressionError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(GetPipelineDefinitionError::from_response(response))
         }
     }
 
-    /// <p>Gets the definition of the specified pipeline. You can call <code>GetPipelineDefinition</code> to retrieve the pipeline definition that you provided using <a>PutPipelineDefinition</a>.</p>
-    async fn get_pipeline_definition(
+    /// Presigns the request `get_pipeline_definition` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_pipeline_definition(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetPipelineDefinitionInput,
-    ) -> Result<GetPipelineDefinitionOutput, RusotoError<GetPipelineDefinitionError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_pipeline_definition_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_pipeline_definition_request(
+        &self,
+        input: GetPipelineDefinitionInput,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "datapipeline", &self.region, "/");
+        request.set_operation("GetPipelineDefinition");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "DataPipeline.GetPipelineDefinition");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `list_pipelines`, also returning the metadata of the response.
+    pub async fn list_pipelines_with_metadata(
+        &self,
+        input: ListPipelinesInput,
+    ) -> Result<(ListPipelinesOutput, ResponseMetadata), RusotoError<ListPipelinesError>> {
+        let request = self.build_list_pipelines_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<GetPipelineDefinitionOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ListPipelinesOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(GetPipelineDefinitionError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ListPipelinesError::from_response(response))
         }
     }
 
-    /// <p>Lists the pipeline identifiers for all active pipelines that you have permission to access.</p>
-    async fn list_pipelines(
+    /// Presigns the request `list_pipelines` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_pipelines(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListPipelinesInput,
-    ) -> Result<ListPipelinesOutput, RusotoError<ListPipelinesError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_pipelines_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_pipelines_request(&self, input: ListPipelinesInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "datapipeline", &self.region, "/");
+        request.set_operation("ListPipelines");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "DataPipeline.ListPipelines");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `poll_for_task`, also returning the metadata of the response.
+    pub async fn poll_for_task_with_metadata(
+        &self,
+        input: PollForTaskInput,
+    ) -> Result<(PollForTaskOutput, ResponseMetadata), RusotoError<PollForTaskError>> {
+        let request = self.build_poll_for_task_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<ListPipelinesOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<PollForTaskOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ListPipelinesError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(PollForTaskError::from_response(response))
         }
     }
 
-    /// <p>Task runners call <code>PollForTask</code> to receive a task to perform from AWS Data Pipeline. The task runner specifies which tasks it can perform by setting a value for the <code>workerGroup</code> parameter. The task returned can come from any of the pipelines that match the <code>workerGroup</code> value passed in by the task runner and that was launched using the IAM user credentials specified by the task runner.</p> <p>If tasks are ready in the work queue, <code>PollForTask</code> returns a response immediately. If no tasks are available in the queue, <code>PollForTask</code> uses long-polling and holds on to a poll connection for up to a 90 seconds, during which time the first newly scheduled task is handed to the task runner. To accomodate this, set the socket timeout in your task runner to 90 seconds. The task runner should not call <code>PollForTask</code> again on the same <code>workerGroup</code> until it receives a response, and this can take up to 90 seconds. </p>
-    async fn poll_for_task(
+    /// Presigns the request `poll_for_task` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_poll_for_task(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: PollForTaskInput,
-    ) -> Result<PollForTaskOutput, RusotoError<PollForTaskError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_poll_for_task_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_poll_for_task_request(&self, input: PollForTaskInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "datapipeline", &self.region, "/");
+        request.set_operation("PollForTask");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "DataPipeline.PollForTask");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `put_pipeline_definition`, also returning the metadata of the response.
+    pub async fn put_pipeline_definition_with_metadata(
+        &self,
+        input: PutPipelineDefinitionInput,
+    ) -> Result<
+        (PutPipelineDefinitionOutput, ResponseMetadata),
+        RusotoError<PutPipelineDefinitionError>,
+    > {
+        let request = self.build_put_pipeline_definition_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<PollForTaskOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<PutPipelineDefinitionOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(PollForTaskError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(PutPipelineDefinitionError::from_response(response))
         }
     }
 
-    /// <p>Adds tasks, schedules, and preconditions to the specified pipeline. You can use <code>PutPipelineDefinition</code> to populate a new pipeline.</p> <p> <code>PutPipelineDefinition</code> also validates the configuration as it adds it to the pipeline. Changes to the pipeline are saved unless one of the following three validation errors exists in the pipeline. </p> <ol> <li>An object is missing a name or identifier field.</li> <li>A string or reference field is empty.</li> <li>The number of objects in the pipeline exceeds the maximum allowed objects.</li> <li>The pipeline is in a FINISHED state.</li> </ol> <p> Pipeline object definitions are passed to the <code>PutPipelineDefinition</code> action and returned by the <a>GetPipelineDefinition</a> action. </p>
-    async fn put_pipeline_definition(
+    /// Presigns the request `put_pipeline_definition` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_put_pipeline_definition(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: PutPipelineDefinitionInput,
-    ) -> Result<PutPipelineDefinitionOutput, RusotoError<PutPipelineDefinitionError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_put_pipeline_definition_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_put_pipeline_definition_request(
+        &self,
+        input: PutPipelineDefinitionInput,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "datapipeline", &self.region, "/");
+        request.set_operation("PutPipelineDefinition");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "DataPipeline.PutPipelineDefinition");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `query_objects`, also returning the metadata of the response.
+    pub async fn query_objects_with_metadata(
+        &self,
+        input: QueryObjectsInput,
+    ) -> Result<(QueryObjectsOutput, ResponseMetadata), RusotoError<QueryObjectsError>> {
+        let request = self.build_query_objects_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<PutPipelineDefinitionOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<QueryObjectsOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(PutPipelineDefinitionError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(QueryObjectsError::from_response(response))
         }
     }
 
-    /// <p>Queries the specified pipeline for the names of objects that match the specified set of conditions.</p>
-    async fn query_objects(
+    /// Presigns the request `query_objects` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_query_objects(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: QueryObjectsInput,
-    ) -> Result<QueryObjectsOutput, RusotoError<QueryObjectsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_query_objects_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_query_objects_request(&self, input: QueryObjectsInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "datapipeline", &self.region, "/");
+        request.set_operation("QueryObjects");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "DataPipeline.QueryObjects");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `remove_tags`, also returning the metadata of the response.
+    pub async fn remove_tags_with_metadata(
+        &self,
+        input: RemoveTagsInput,
+    ) -> Result<(RemoveTagsOutput, ResponseMetadata), RusotoError<RemoveTagsError>> {
+        let request = self.build_remove_tags_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<QueryObjectsOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<RemoveTagsOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(QueryObjectsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(RemoveTagsError::from_response(response))
         }
     }
 
-    /// <p>Removes existing tags from the specified pipeline.</p>
-    async fn remove_tags(
+    /// Presigns the request `remove_tags` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_remove_tags(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: RemoveTagsInput,
-    ) -> Result<RemoveTagsOutput, RusotoError<RemoveTagsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_remove_tags_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_remove_tags_request(&self, input: RemoveTagsInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "datapipeline", &self.region, "/");
+        request.set_operation("RemoveTags");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "DataPipeline.RemoveTags");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `report_task_progress`, also returning the metadata of the response.
+    pub async fn report_task_progress_with_metadata(
+        &self,
+        input: ReportTaskProgressInput,
+    ) -> Result<(ReportTaskProgressOutput, ResponseMetadata), RusotoError<ReportTaskProgressError>>
+    {
+        let request = self.build_report_task_progress_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<RemoveTagsOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ReportTaskProgressOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(RemoveTagsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ReportTaskProgressError::from_response(response))
         }
     }
 
-    /// <p>Task runners call <code>ReportTaskProgress</code> when assigned a task to acknowledge that it has the task. If the web service does not receive this acknowledgement within 2 minutes, it assigns the task in a subsequent <a>PollForTask</a> call. After this initial acknowledgement, the task runner only needs to report progress every 15 minutes to maintain its ownership of the task. You can change this reporting time from 15 minutes by specifying a <code>reportProgressTimeout</code> field in your pipeline.</p> <p>If a task runner does not report its status after 5 minutes, AWS Data Pipeline assumes that the task runner is unable to process the task and reassigns the task in a subsequent response to <a>PollForTask</a>. Task runners should call <code>ReportTaskProgress</code> every 60 seconds.</p>
-    async fn report_task_progress(
+    /// Presigns the request `report_task_progress` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_report_task_progress(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ReportTaskProgressInput,
-    ) -> Result<ReportTaskProgressOutput, RusotoError<ReportTaskProgressError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_report_task_progress_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_report_task_progress_request(&self, input: ReportTaskProgressInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "datapipeline", &self.region, "/");
+        request.set_operation("ReportTaskProgress");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "DataPipeline.ReportTaskProgress");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `report_task_runner_heartbeat`, also returning the metadata of the response.
+    pub async fn report_task_runner_heartbeat_with_metadata(
+        &self,
+        input: ReportTaskRunnerHeartbeatInput,
+    ) -> Result<
+        (ReportTaskRunnerHeartbeatOutput, ResponseMetadata),
+        RusotoError<ReportTaskRunnerHeartbeatError>,
+    > {
+        let request = self.build_report_task_runner_heartbeat_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<ReportTaskProgressOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ReportTaskRunnerHeartbeatOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ReportTaskProgressError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ReportTaskRunnerHeartbeatError::from_response(response))
         }
     }
 
-    /// <p>Task runners call <code>ReportTaskRunnerHeartbeat</code> every 15 minutes to indicate that they are operational. If the AWS Data Pipeline Task Runner is launched on a resource managed by AWS Data Pipeline, the web service can use this call to detect when the task runner application has failed and restart a new instance.</p>
-    async fn report_task_runner_heartbeat(
+    /// Presigns the request `report_task_runner_heartbeat` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_report_task_runner_heartbeat(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ReportTaskRunnerHeartbeatInput,
-    ) -> Result<ReportTaskRunnerHeartbeatOutput, RusotoError<ReportTaskRunnerHeartbeatError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_report_task_runner_heartbeat_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_report_task_runner_heartbeat_request(
+        &self,
+        input: ReportTaskRunnerHeartbeatInput,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "datapipeline", &self.region, "/");
+        request.set_operation("ReportTaskRunnerHeartbeat");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "DataPipeline.ReportTaskRunnerHeartbeat");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `set_status`, also returning the metadata of the response.
+    pub async fn set_status_with_metadata(
+        &self,
+        input: SetStatusInput,
+    ) -> Result<((), ResponseMetadata), RusotoError<SetStatusError>> {
+        let request = self.build_set_status_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<ReportTaskRunnerHeartbeatOutput, _>()
+            std::mem::drop(response);
+            Ok(((), metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ReportTaskRunnerHeartbeatError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(SetStatusError::from_response(response))
         }
     }
 
-    /// <p>Requests that the status of the specified physical or logical pipeline objects be updated in the specified pipeline. This update might not occur immediately, but is eventually consistent. The status that can be set depends on the type of object (for example, DataNode or Activity). You cannot perform this operation on <code>FINISHED</code> pipelines and attempting to do so returns <code>InvalidRequestException</code>.</p>
-    async fn set_status(&self, input: SetStatusInput) -> Result<(), RusotoError<SetStatusError>> {
+    /// Presigns the request `set_status` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_set_status(
+        &self,
+        presigner: &rusoto_core::Presigner,
+        input: SetStatusInput,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_set_status_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_set_status_request(&self, input: SetStatusInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "datapipeline", &self.region, "/");
+        request.set_operation("SetStatus");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "DataPipeline.SetStatus");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `set_task_status`, also returning the metadata of the response.
+    pub async fn set_task_status_with_metadata(
+        &self,
+        input: SetTaskStatusInput,
+    ) -> Result<(SetTaskStatusOutput, ResponseMetadata), RusotoError<SetTaskStatusError>> {
+        let request = self.build_set_task_status_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            std::mem::drop(response);
-            Ok(())
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<SetTaskStatusOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(SetStatusError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(SetTaskStatusError::from_response(response))
         }
     }
 
-    /// <p>Task runners call <code>SetTaskStatus</code> to notify AWS Data Pipeline that a task is completed and provide information about the final status. A task runner makes this call regardless of whether the task was sucessful. A task runner does not need to call <code>SetTaskStatus</code> for tasks that are canceled by the web service during a call to <a>ReportTaskProgress</a>.</p>
-    async fn set_task_status(
+    /// Presigns the request `set_task_status` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_set_task_status(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: SetTaskStatusInput,
-    ) -> Result<SetTaskStatusOutput, RusotoError<SetTaskStatusError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_set_task_status_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_set_task_status_request(&self, input: SetTaskStatusInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "datapipeline", &self.region, "/");
+        request.set_operation("SetTaskStatus");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "DataPipeline.SetTaskStatus");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `validate_pipeline_definition`, also returning the metadata of the response.
+    pub async fn validate_pipeline_definition_with_metadata(
+        &self,
+        input: ValidatePipelineDefinitionInput,
+    ) -> Result<
+        (ValidatePipelineDefinitionOutput, ResponseMetadata),
+        RusotoError<ValidatePipelineDefinitionError>,
+    > {
+        let request = self.build_validate_pipeline_definition_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<SetTaskStatusOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ValidatePipelineDefinitionOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(SetTaskStatusError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ValidatePipelineDefinitionError::from_response(response))
         }
     }
 
-    /// <p>Validates the specified pipeline definition to ensure that it is well formed and can be run without error.</p>
-    async fn validate_pipeline_definition(
+    /// Presigns the request `validate_pipeline_definition` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_validate_pipeline_definition(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ValidatePipelineDefinitionInput,
-    ) -> Result<ValidatePipelineDefinitionOutput, RusotoError<ValidatePipelineDefinitionError>>
-    {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_validate_pipeline_definition_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_validate_pipeline_definition_request(
+        &self,
+        input: ValidatePipelineDefinitionInput,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "datapipeline", &self.region, "/");
+        request.set_operation("ValidatePipelineDefinition");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "DataPipeline.ValidatePipelineDefinition");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
-        let mut response = self
-            .client
-            .sign_and_dispatch(request)
+        request
+    }
+
+    /// The pages of `DescribeObjects` results for `input`, requesting each next page with the `marker` of the last one as its `marker` while its `hasMoreResults` is true.
+    pub fn describe_objects_pages(
+        &self,
+        input: DescribeObjectsInput,
+    ) -> impl futures::Stream<Item = Result<DescribeObjectsOutput, RusotoError<DescribeObjectsError>>>
+           + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: DataPipelineClient, input| async move { client.describe_objects(input).await },
+            |input: &DescribeObjectsInput, output: &DescribeObjectsOutput| {
+                if output.has_more_results != Some(true) {
+                    return None;
+                }
+                let marker = output.marker.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if marker.is_none() || marker == input.marker {
+                    return None;
+                }
+                Some(DescribeObjectsInput {
+                    marker,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `pipelineObjects` of every page of `DescribeObjects` results for `input`, as `describe_objects_pages` requests them.
+    pub fn describe_objects_stream(
+        &self,
+        input: DescribeObjectsInput,
+    ) -> impl futures::Stream<Item = Result<PipelineObject, RusotoError<DescribeObjectsError>>> + Send
+    {
+        rusoto_core::pagination::items(self.describe_objects_pages(input), |page| {
+            page.pipeline_objects
+        })
+    }
+
+    /// The pages of `ListPipelines` results for `input`, requesting each next page with the `marker` of the last one as its `marker` while its `hasMoreResults` is true.
+    pub fn list_pipelines_pages(
+        &self,
+        input: ListPipelinesInput,
+    ) -> impl futures::Stream<Item = Result<ListPipelinesOutput, RusotoError<ListPipelinesError>>> + Send
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: DataPipelineClient, input| async move { client.list_pipelines(input).await },
+            |input: &ListPipelinesInput, output: &ListPipelinesOutput| {
+                if output.has_more_results != Some(true) {
+                    return None;
+                }
+                let marker = output.marker.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if marker.is_none() || marker == input.marker {
+                    return None;
+                }
+                Some(ListPipelinesInput {
+                    marker,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `pipelineIdList` of every page of `ListPipelines` results for `input`, as `list_pipelines_pages` requests them.
+    pub fn list_pipelines_stream(
+        &self,
+        input: ListPipelinesInput,
+    ) -> impl futures::Stream<Item = Result<PipelineIdName, RusotoError<ListPipelinesError>>> + Send
+    {
+        rusoto_core::pagination::items(self.list_pipelines_pages(input), |page| {
+            page.pipeline_id_list
+        })
+    }
+
+    /// The pages of `QueryObjects` results for `input`, requesting each next page with the `marker` of the last one as its `marker` while its `hasMoreResults` is true.
+    /// The page size is the `limit` of `input`.
+    pub fn query_objects_pages(
+        &self,
+        input: QueryObjectsInput,
+    ) -> impl futures::Stream<Item = Result<QueryObjectsOutput, RusotoError<QueryObjectsError>>> + Send
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: DataPipelineClient, input| async move { client.query_objects(input).await },
+            |input: &QueryObjectsInput, output: &QueryObjectsOutput| {
+                if output.has_more_results != Some(true) {
+                    return None;
+                }
+                let marker = output.marker.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if marker.is_none() || marker == input.marker {
+                    return None;
+                }
+                Some(QueryObjectsInput {
+                    marker,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `ids` of every page of `QueryObjects` results for `input`, as `query_objects_pages` requests them.
+    pub fn query_objects_stream(
+        &self,
+        input: QueryObjectsInput,
+    ) -> impl futures::Stream<Item = Result<String, RusotoError<QueryObjectsError>>> + Send {
+        rusoto_core::pagination::items(self.query_objects_pages(input), |page| {
+            page.ids.unwrap_or_default()
+        })
+    }
+}
+
+#[async_trait]
+impl DataPipeline for DataPipelineClient {
+    /// <p>Validates the specified pipeline and starts processing pipeline tasks. If the pipeline does not pass validation, activation fails.</p> <p>If you need to pause the pipeline to investigate an issue with a component, such as a data source or script, call <a>DeactivatePipeline</a>.</p> <p>To activate a finished pipeline, modify the end date for the pipeline and then activate it.</p>
+    async fn activate_pipeline(
+        &self,
+        input: ActivatePipelineInput,
+    ) -> Result<ActivatePipelineOutput, RusotoError<ActivatePipelineError>> {
+        self.activate_pipeline_with_metadata(input)
             .await
-            .map_err(RusotoError::from)?;
-        if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<ValidatePipelineDefinitionOutput, _>()
-        } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ValidatePipelineDefinitionError::from_response(response))
-        }
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Adds or modifies tags for the specified pipeline.</p>
+    async fn add_tags(
+        &self,
+        input: AddTagsInput,
+    ) -> Result<AddTagsOutput, RusotoError<AddTagsError>> {
+        self.add_tags_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Creates a new, empty pipeline. Use <a>PutPipelineDefinition</a> to populate the pipeline.</p>
+    async fn create_pipeline(
+        &self,
+        input: CreatePipelineInput,
+    ) -> Result<CreatePipelineOutput, RusotoError<CreatePipelineError>> {
+        self.create_pipeline_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deactivates the specified running pipeline. The pipeline is set to the <code>DEACTIVATING</code> state until the deactivation process completes.</p> <p>To resume a deactivated pipeline, use <a>ActivatePipeline</a>. By default, the pipeline resumes from the last completed execution. Optionally, you can specify the date and time to resume the pipeline.</p>
+    async fn deactivate_pipeline(
+        &self,
+        input: DeactivatePipelineInput,
+    ) -> Result<DeactivatePipelineOutput, RusotoError<DeactivatePipelineError>> {
+        self.deactivate_pipeline_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes a pipeline, its pipeline definition, and its run history. AWS Data Pipeline attempts to cancel instances associated with the pipeline that are currently being processed by task runners.</p> <p>Deleting a pipeline cannot be undone. You cannot query or restore a deleted pipeline. To temporarily pause a pipeline instead of deleting it, call <a>SetStatus</a> with the status set to <code>PAUSE</code> on individual components. Components that are paused by <a>SetStatus</a> can be resumed.</p>
+    async fn delete_pipeline(
+        &self,
+        input: DeletePipelineInput,
+    ) -> Result<(), RusotoError<DeletePipelineError>> {
+        self.delete_pipeline_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Gets the object definitions for a set of objects associated with the pipeline. Object definitions are composed of a set of fields that define the properties of the object.</p>
+    async fn describe_objects(
+        &self,
+        input: DescribeObjectsInput,
+    ) -> Result<DescribeObjectsOutput, RusotoError<DescribeObjectsError>> {
+        self.describe_objects_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Retrieves metadata about one or more pipelines. The information retrieved includes the name of the pipeline, the pipeline identifier, its current state, and the user account that owns the pipeline. Using account credentials, you can retrieve metadata about pipelines that you or your IAM users have created. If you are using an IAM user account, you can retrieve metadata about only those pipelines for which you have read permissions.</p> <p>To retrieve the full pipeline definition instead of metadata about the pipeline, call <a>GetPipelineDefinition</a>.</p>
+    async fn describe_pipelines(
+        &self,
+        input: DescribePipelinesInput,
+    ) -> Result<DescribePipelinesOutput, RusotoError<DescribePipelinesError>> {
+        self.describe_pipelines_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Task runners call <code>EvaluateExpression</code> to evaluate a string in the context of the specified object. For example, a task runner can evaluate SQL queries stored in Amazon S3.</p>
+    async fn evaluate_expression(
+        &self,
+        input: EvaluateExpressionInput,
+    ) -> Result<EvaluateExpressionOutput, RusotoError<EvaluateExpressionError>> {
+        self.evaluate_expression_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Gets the definition of the specified pipeline. You can call <code>GetPipelineDefinition</code> to retrieve the pipeline definition that you provided using <a>PutPipelineDefinition</a>.</p>
+    async fn get_pipeline_definition(
+        &self,
+        input: GetPipelineDefinitionInput,
+    ) -> Result<GetPipelineDefinitionOutput, RusotoError<GetPipelineDefinitionError>> {
+        self.get_pipeline_definition_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Lists the pipeline identifiers for all active pipelines that you have permission to access.</p>
+    async fn list_pipelines(
+        &self,
+        input: ListPipelinesInput,
+    ) -> Result<ListPipelinesOutput, RusotoError<ListPipelinesError>> {
+        self.list_pipelines_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Task runners call <code>PollForTask</code> to receive a task to perform from AWS Data Pipeline. The task runner specifies which tasks it can perform by setting a value for the <code>workerGroup</code> parameter. The task returned can come from any of the pipelines that match the <code>workerGroup</code> value passed in by the task runner and that was launched using the IAM user credentials specified by the task runner.</p> <p>If tasks are ready in the work queue, <code>PollForTask</code> returns a response immediately. If no tasks are available in the queue, <code>PollForTask</code> uses long-polling and holds on to a poll connection for up to a 90 seconds, during which time the first newly scheduled task is handed to the task runner. To accomodate this, set the socket timeout in your task runner to 90 seconds. The task runner should not call <code>PollForTask</code> again on the same <code>workerGroup</code> until it receives a response, and this can take up to 90 seconds. </p>
+    async fn poll_for_task(
+        &self,
+        input: PollForTaskInput,
+    ) -> Result<PollForTaskOutput, RusotoError<PollForTaskError>> {
+        self.poll_for_task_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Adds tasks, schedules, and preconditions to the specified pipeline. You can use <code>PutPipelineDefinition</code> to populate a new pipeline.</p> <p> <code>PutPipelineDefinition</code> also validates the configuration as it adds it to the pipeline. Changes to the pipeline are saved unless one of the following three validation errors exists in the pipeline. </p> <ol> <li>An object is missing a name or identifier field.</li> <li>A string or reference field is empty.</li> <li>The number of objects in the pipeline exceeds the maximum allowed objects.</li> <li>The pipeline is in a FINISHED state.</li> </ol> <p> Pipeline object definitions are passed to the <code>PutPipelineDefinition</code> action and returned by the <a>GetPipelineDefinition</a> action. </p>
+    async fn put_pipeline_definition(
+        &self,
+        input: PutPipelineDefinitionInput,
+    ) -> Result<PutPipelineDefinitionOutput, RusotoError<PutPipelineDefinitionError>> {
+        self.put_pipeline_definition_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Queries the specified pipeline for the names of objects that match the specified set of conditions.</p>
+    async fn query_objects(
+        &self,
+        input: QueryObjectsInput,
+    ) -> Result<QueryObjectsOutput, RusotoError<QueryObjectsError>> {
+        self.query_objects_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Removes existing tags from the specified pipeline.</p>
+    async fn remove_tags(
+        &self,
+        input: RemoveTagsInput,
+    ) -> Result<RemoveTagsOutput, RusotoError<RemoveTagsError>> {
+        self.remove_tags_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Task runners call <code>ReportTaskProgress</code> when assigned a task to acknowledge that it has the task. If the web service does not receive this acknowledgement within 2 minutes, it assigns the task in a subsequent <a>PollForTask</a> call. After this initial acknowledgement, the task runner only needs to report progress every 15 minutes to maintain its ownership of the task. You can change this reporting time from 15 minutes by specifying a <code>reportProgressTimeout</code> field in your pipeline.</p> <p>If a task runner does not report its status after 5 minutes, AWS Data Pipeline assumes that the task runner is unable to process the task and reassigns the task in a subsequent response to <a>PollForTask</a>. Task runners should call <code>ReportTaskProgress</code> every 60 seconds.</p>
+    async fn report_task_progress(
+        &self,
+        input: ReportTaskProgressInput,
+    ) -> Result<ReportTaskProgressOutput, RusotoError<ReportTaskProgressError>> {
+        self.report_task_progress_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Task runners call <code>ReportTaskRunnerHeartbeat</code> every 15 minutes to indicate that they are operational. If the AWS Data Pipeline Task Runner is launched on a resource managed by AWS Data Pipeline, the web service can use this call to detect when the task runner application has failed and restart a new instance.</p>
+    async fn report_task_runner_heartbeat(
+        &self,
+        input: ReportTaskRunnerHeartbeatInput,
+    ) -> Result<ReportTaskRunnerHeartbeatOutput, RusotoError<ReportTaskRunnerHeartbeatError>> {
+        self.report_task_runner_heartbeat_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Requests that the status of the specified physical or logical pipeline objects be updated in the specified pipeline. This update might not occur immediately, but is eventually consistent. The status that can be set depends on the type of object (for example, DataNode or Activity). You cannot perform this operation on <code>FINISHED</code> pipelines and attempting to do so returns <code>InvalidRequestException</code>.</p>
+    async fn set_status(&self, input: SetStatusInput) -> Result<(), RusotoError<SetStatusError>> {
+        self.set_status_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Task runners call <code>SetTaskStatus</code> to notify AWS Data Pipeline that a task is completed and provide information about the final status. A task runner makes this call regardless of whether the task was sucessful. A task runner does not need to call <code>SetTaskStatus</code> for tasks that are canceled by the web service during a call to <a>ReportTaskProgress</a>.</p>
+    async fn set_task_status(
+        &self,
+        input: SetTaskStatusInput,
+    ) -> Result<SetTaskStatusOutput, RusotoError<SetTaskStatusError>> {
+        self.set_task_status_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Validates the specified pipeline definition to ensure that it is well formed and can be run without error.</p>
+    async fn validate_pipeline_definition(
+        &self,
+        input: ValidatePipelineDefinitionInput,
+    ) -> Result<ValidatePipelineDefinitionOutput, RusotoError<ValidatePipelineDefinitionError>>
+    {
+        self.validate_pipeline_definition_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
     }
 }
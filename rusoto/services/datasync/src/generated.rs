@@ -16,8 +16,8 @@ use std::fmt;
 use async_trait::async_trait;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
-use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest, ResponseMetadata};
+use rusoto_core::{Client, ErrorMetadata, RusotoError};
 
 use rusoto_core::proto;
 use rusoto_core::signature::SignedRequest;
@@ -1211,11 +1211,14 @@ pub struct UpdateTaskResponse {}
 
 /// Errors returned by CancelTaskExecution
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CancelTaskExecutionError {
     /// <p>This exception is thrown when an error occurs in the AWS DataSync service.</p>
     Internal(String),
     /// <p>This exception is thrown when the client submits a malformed request.</p>
     InvalidRequest(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CancelTaskExecutionError {
@@ -1223,13 +1226,16 @@ impl CancelTaskExecutionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalException" => {
-                    return RusotoError::Service(CancelTaskExecutionError::Internal(err.msg))
+                    return RusotoError::service(CancelTaskExecutionError::Internal(err.msg), &res)
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(CancelTaskExecutionError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        CancelTaskExecutionError::InvalidRequest(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CancelTaskExecutionError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1241,17 +1247,21 @@ impl fmt::Display for CancelTaskExecutionError {
         match *self {
             CancelTaskExecutionError::Internal(ref cause) => write!(f, "{}", cause),
             CancelTaskExecutionError::InvalidRequest(ref cause) => write!(f, "{}", cause),
+            CancelTaskExecutionError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CancelTaskExecutionError {}
 /// Errors returned by CreateAgent
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateAgentError {
     /// <p>This exception is thrown when an error occurs in the AWS DataSync service.</p>
     Internal(String),
     /// <p>This exception is thrown when the client submits a malformed request.</p>
     InvalidRequest(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateAgentError {
@@ -1259,13 +1269,13 @@ impl CreateAgentError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalException" => {
-                    return RusotoError::Service(CreateAgentError::Internal(err.msg))
+                    return RusotoError::service(CreateAgentError::Internal(err.msg), &res)
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(CreateAgentError::InvalidRequest(err.msg))
+                    return RusotoError::service(CreateAgentError::InvalidRequest(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreateAgentError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1277,17 +1287,21 @@ impl fmt::Display for CreateAgentError {
         match *self {
             CreateAgentError::Internal(ref cause) => write!(f, "{}", cause),
             CreateAgentError::InvalidRequest(ref cause) => write!(f, "{}", cause),
+            CreateAgentError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateAgentError {}
 /// Errors returned by CreateLocationEfs
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateLocationEfsError {
     /// <p>This exception is thrown when an error occurs in the AWS DataSync service.</p>
     Internal(String),
     /// <p>This exception is thrown when the client submits a malformed request.</p>
     InvalidRequest(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateLocationEfsError {
@@ -1295,13 +1309,16 @@ impl CreateLocationEfsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalException" => {
-                    return RusotoError::Service(CreateLocationEfsError::Internal(err.msg))
+                    return RusotoError::service(CreateLocationEfsError::Internal(err.msg), &res)
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(CreateLocationEfsError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        CreateLocationEfsError::InvalidRequest(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreateLocationEfsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1313,17 +1330,21 @@ impl fmt::Display for CreateLocationEfsError {
         match *self {
             CreateLocationEfsError::Internal(ref cause) => write!(f, "{}", cause),
             CreateLocationEfsError::InvalidRequest(ref cause) => write!(f, "{}", cause),
+            CreateLocationEfsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateLocationEfsError {}
 /// Errors returned by CreateLocationFsxWindows
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateLocationFsxWindowsError {
     /// <p>This exception is thrown when an error occurs in the AWS DataSync service.</p>
     Internal(String),
     /// <p>This exception is thrown when the client submits a malformed request.</p>
     InvalidRequest(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateLocationFsxWindowsError {
@@ -1331,15 +1352,19 @@ impl CreateLocationFsxWindowsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalException" => {
-                    return RusotoError::Service(CreateLocationFsxWindowsError::Internal(err.msg))
+                    return RusotoError::service(
+                        CreateLocationFsxWindowsError::Internal(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(CreateLocationFsxWindowsError::InvalidRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateLocationFsxWindowsError::InvalidRequest(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreateLocationFsxWindowsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1351,17 +1376,21 @@ impl fmt::Display for CreateLocationFsxWindowsError {
         match *self {
             CreateLocationFsxWindowsError::Internal(ref cause) => write!(f, "{}", cause),
             CreateLocationFsxWindowsError::InvalidRequest(ref cause) => write!(f, "{}", cause),
+            CreateLocationFsxWindowsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateLocationFsxWindowsError {}
 /// Errors returned by CreateLocationNfs
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateLocationNfsError {
     /// <p>This exception is thrown when an error occurs in the AWS DataSync service.</p>
     Internal(String),
     /// <p>This exception is thrown when the client submits a malformed request.</p>
     InvalidRequest(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateLocationNfsError {
@@ -1369,13 +1398,16 @@ impl CreateLocationNfsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalException" => {
-                    return RusotoError::Service(CreateLocationNfsError::Internal(err.msg))
+                    return RusotoError::service(CreateLocationNfsError::Internal(err.msg), &res)
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(CreateLocationNfsError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        CreateLocationNfsError::InvalidRequest(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreateLocationNfsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1387,17 +1419,21 @@ impl fmt::Display for CreateLocationNfsError {
         match *self {
             CreateLocationNfsError::Internal(ref cause) => write!(f, "{}", cause),
             CreateLocationNfsError::InvalidRequest(ref cause) => write!(f, "{}", cause),
+            CreateLocationNfsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateLocationNfsError {}
 /// Errors returned by CreateLocationS3
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateLocationS3Error {
     /// <p>This exception is thrown when an error occurs in the AWS DataSync service.</p>
     Internal(String),
     /// <p>This exception is thrown when the client submits a malformed request.</p>
     InvalidRequest(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateLocationS3Error {
@@ -1405,13 +1441,16 @@ impl CreateLocationS3Error {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalException" => {
-                    return RusotoError::Service(CreateLocationS3Error::Internal(err.msg))
+                    return RusotoError::service(CreateLocationS3Error::Internal(err.msg), &res)
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(CreateLocationS3Error::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        CreateLocationS3Error::InvalidRequest(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreateLocationS3Error::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1423,17 +1462,21 @@ impl fmt::Display for CreateLocationS3Error {
         match *self {
             CreateLocationS3Error::Internal(ref cause) => write!(f, "{}", cause),
             CreateLocationS3Error::InvalidRequest(ref cause) => write!(f, "{}", cause),
+            CreateLocationS3Error::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateLocationS3Error {}
 /// Errors returned by CreateLocationSmb
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateLocationSmbError {
     /// <p>This exception is thrown when an error occurs in the AWS DataSync service.</p>
     Internal(String),
     /// <p>This exception is thrown when the client submits a malformed request.</p>
     InvalidRequest(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateLocationSmbError {
@@ -1441,13 +1484,16 @@ impl CreateLocationSmbError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalException" => {
-                    return RusotoError::Service(CreateLocationSmbError::Internal(err.msg))
+                    return RusotoError::service(CreateLocationSmbError::Internal(err.msg), &res)
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(CreateLocationSmbError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        CreateLocationSmbError::InvalidRequest(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreateLocationSmbError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1459,17 +1505,21 @@ impl fmt::Display for CreateLocationSmbError {
         match *self {
             CreateLocationSmbError::Internal(ref cause) => write!(f, "{}", cause),
             CreateLocationSmbError::InvalidRequest(ref cause) => write!(f, "{}", cause),
+            CreateLocationSmbError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateLocationSmbError {}
 /// Errors returned by CreateTask
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateTaskError {
     /// <p>This exception is thrown when an error occurs in the AWS DataSync service.</p>
     Internal(String),
     /// <p>This exception is thrown when the client submits a malformed request.</p>
     InvalidRequest(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateTaskError {
@@ -1477,13 +1527,13 @@ impl CreateTaskError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalException" => {
-                    return RusotoError::Service(CreateTaskError::Internal(err.msg))
+                    return RusotoError::service(CreateTaskError::Internal(err.msg), &res)
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(CreateTaskError::InvalidRequest(err.msg))
+                    return RusotoError::service(CreateTaskError::InvalidRequest(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreateTaskError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1495,17 +1545,21 @@ impl fmt::Display for CreateTaskError {
         match *self {
             CreateTaskError::Internal(ref cause) => write!(f, "{}", cause),
             CreateTaskError::InvalidRequest(ref cause) => write!(f, "{}", cause),
+            CreateTaskError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateTaskError {}
 /// Errors returned by DeleteAgent
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteAgentError {
     /// <p>This exception is thrown when an error occurs in the AWS DataSync service.</p>
     Internal(String),
     /// <p>This exception is thrown when the client submits a malformed request.</p>
     InvalidRequest(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteAgentError {
@@ -1513,13 +1567,13 @@ impl DeleteAgentError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalException" => {
-                    return RusotoError::Service(DeleteAgentError::Internal(err.msg))
+                    return RusotoError::service(DeleteAgentError::Internal(err.msg), &res)
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DeleteAgentError::InvalidRequest(err.msg))
+                    return RusotoError::service(DeleteAgentError::InvalidRequest(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteAgentError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1531,17 +1585,21 @@ impl fmt::Display for DeleteAgentError {
         match *self {
             DeleteAgentError::Internal(ref cause) => write!(f, "{}", cause),
             DeleteAgentError::InvalidRequest(ref cause) => write!(f, "{}", cause),
+            DeleteAgentError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteAgentError {}
 /// Errors returned by DeleteLocation
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteLocationError {
     /// <p>This exception is thrown when an error occurs in the AWS DataSync service.</p>
     Internal(String),
     /// <p>This exception is thrown when the client submits a malformed request.</p>
     InvalidRequest(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteLocationError {
@@ -1549,13 +1607,13 @@ impl DeleteLocationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalException" => {
-                    return RusotoError::Service(DeleteLocationError::Internal(err.msg))
+                    return RusotoError::service(DeleteLocationError::Internal(err.msg), &res)
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DeleteLocationError::InvalidRequest(err.msg))
+                    return RusotoError::service(DeleteLocationError::InvalidRequest(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteLocationError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1567,17 +1625,21 @@ impl fmt::Display for DeleteLocationError {
         match *self {
             DeleteLocationError::Internal(ref cause) => write!(f, "{}", cause),
             DeleteLocationError::InvalidRequest(ref cause) => write!(f, "{}", cause),
+            DeleteLocationError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteLocationError {}
 /// Errors returned by DeleteTask
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteTaskError {
     /// <p>This exception is thrown when an error occurs in the AWS DataSync service.</p>
     Internal(String),
     /// <p>This exception is thrown when the client submits a malformed request.</p>
     InvalidRequest(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteTaskError {
@@ -1585,13 +1647,13 @@ impl DeleteTaskError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalException" => {
-                    return RusotoError::Service(DeleteTaskError::Internal(err.msg))
+                    return RusotoError::service(DeleteTaskError::Internal(err.msg), &res)
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DeleteTaskError::InvalidRequest(err.msg))
+                    return RusotoError::service(DeleteTaskError::InvalidRequest(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteTaskError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1603,17 +1665,21 @@ impl fmt::Display for DeleteTaskError {
         match *self {
             DeleteTaskError::Internal(ref cause) => write!(f, "{}", cause),
             DeleteTaskError::InvalidRequest(ref cause) => write!(f, "{}", cause),
+            DeleteTaskError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteTaskError {}
 /// Errors returned by DescribeAgent
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeAgentError {
     /// <p>This exception is thrown when an error occurs in the AWS DataSync service.</p>
     Internal(String),
     /// <p>This exception is thrown when the client submits a malformed request.</p>
     InvalidRequest(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeAgentError {
@@ -1621,13 +1687,13 @@ impl DescribeAgentError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalException" => {
-                    return RusotoError::Service(DescribeAgentError::Internal(err.msg))
+                    return RusotoError::service(DescribeAgentError::Internal(err.msg), &res)
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DescribeAgentError::InvalidRequest(err.msg))
+                    return RusotoError::service(DescribeAgentError::InvalidRequest(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DescribeAgentError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1639,17 +1705,21 @@ impl fmt::Display for DescribeAgentError {
         match *self {
             DescribeAgentError::Internal(ref cause) => write!(f, "{}", cause),
             DescribeAgentError::InvalidRequest(ref cause) => write!(f, "{}", cause),
+            DescribeAgentError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeAgentError {}
 /// Errors returned by DescribeLocationEfs
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeLocationEfsError {
     /// <p>This exception is thrown when an error occurs in the AWS DataSync service.</p>
     Internal(String),
     /// <p>This exception is thrown when the client submits a malformed request.</p>
     InvalidRequest(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeLocationEfsError {
@@ -1657,13 +1727,16 @@ impl DescribeLocationEfsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalException" => {
-                    return RusotoError::Service(DescribeLocationEfsError::Internal(err.msg))
+                    return RusotoError::service(DescribeLocationEfsError::Internal(err.msg), &res)
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DescribeLocationEfsError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        DescribeLocationEfsError::InvalidRequest(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DescribeLocationEfsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1675,17 +1748,21 @@ impl fmt::Display for DescribeLocationEfsError {
         match *self {
             DescribeLocationEfsError::Internal(ref cause) => write!(f, "{}", cause),
             DescribeLocationEfsError::InvalidRequest(ref cause) => write!(f, "{}", cause),
+            DescribeLocationEfsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeLocationEfsError {}
 /// Errors returned by DescribeLocationFsxWindows
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeLocationFsxWindowsError {
     /// <p>This exception is thrown when an error occurs in the AWS DataSync service.</p>
     Internal(String),
     /// <p>This exception is thrown when the client submits a malformed request.</p>
     InvalidRequest(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeLocationFsxWindowsError {
@@ -1695,15 +1772,21 @@ impl DescribeLocationFsxWindowsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalException" => {
-                    return RusotoError::Service(DescribeLocationFsxWindowsError::Internal(err.msg))
+                    return RusotoError::service(
+                        DescribeLocationFsxWindowsError::Internal(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DescribeLocationFsxWindowsError::InvalidRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeLocationFsxWindowsError::InvalidRequest(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(DescribeLocationFsxWindowsError::Unhandled, &res)
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -1715,17 +1798,21 @@ impl fmt::Display for DescribeLocationFsxWindowsError {
         match *self {
             DescribeLocationFsxWindowsError::Internal(ref cause) => write!(f, "{}", cause),
             DescribeLocationFsxWindowsError::InvalidRequest(ref cause) => write!(f, "{}", cause),
+            DescribeLocationFsxWindowsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeLocationFsxWindowsError {}
 /// Errors returned by DescribeLocationNfs
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeLocationNfsError {
     /// <p>This exception is thrown when an error occurs in the AWS DataSync service.</p>
     Internal(String),
     /// <p>This exception is thrown when the client submits a malformed request.</p>
     InvalidRequest(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeLocationNfsError {
@@ -1733,13 +1820,16 @@ impl DescribeLocationNfsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalException" => {
-                    return RusotoError::Service(DescribeLocationNfsError::Internal(err.msg))
+                    return RusotoError::service(DescribeLocationNfsError::Internal(err.msg), &res)
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DescribeLocationNfsError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        DescribeLocationNfsError::InvalidRequest(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DescribeLocationNfsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1751,17 +1841,21 @@ impl fmt::Display for DescribeLocationNfsError {
         match *self {
             DescribeLocationNfsError::Internal(ref cause) => write!(f, "{}", cause),
             DescribeLocationNfsError::InvalidRequest(ref cause) => write!(f, "{}", cause),
+            DescribeLocationNfsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeLocationNfsError {}
 /// Errors returned by DescribeLocationS3
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeLocationS3Error {
     /// <p>This exception is thrown when an error occurs in the AWS DataSync service.</p>
     Internal(String),
     /// <p>This exception is thrown when the client submits a malformed request.</p>
     InvalidRequest(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeLocationS3Error {
@@ -1769,13 +1863,16 @@ impl DescribeLocationS3Error {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalException" => {
-                    return RusotoError::Service(DescribeLocationS3Error::Internal(err.msg))
+                    return RusotoError::service(DescribeLocationS3Error::Internal(err.msg), &res)
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DescribeLocationS3Error::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        DescribeLocationS3Error::InvalidRequest(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DescribeLocationS3Error::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1787,17 +1884,21 @@ impl fmt::Display for DescribeLocationS3Error {
         match *self {
             DescribeLocationS3Error::Internal(ref cause) => write!(f, "{}", cause),
             DescribeLocationS3Error::InvalidRequest(ref cause) => write!(f, "{}", cause),
+            DescribeLocationS3Error::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeLocationS3Error {}
 /// Errors returned by DescribeLocationSmb
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeLocationSmbError {
     /// <p>This exception is thrown when an error occurs in the AWS DataSync service.</p>
     Internal(String),
     /// <p>This exception is thrown when the client submits a malformed request.</p>
     InvalidRequest(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeLocationSmbError {
@@ -1805,13 +1906,16 @@ impl DescribeLocationSmbError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalException" => {
-                    return RusotoError::Service(DescribeLocationSmbError::Internal(err.msg))
+                    return RusotoError::service(DescribeLocationSmbError::Internal(err.msg), &res)
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DescribeLocationSmbError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        DescribeLocationSmbError::InvalidRequest(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DescribeLocationSmbError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1823,17 +1927,21 @@ impl fmt::Display for DescribeLocationSmbError {
         match *self {
             DescribeLocationSmbError::Internal(ref cause) => write!(f, "{}", cause),
             DescribeLocationSmbError::InvalidRequest(ref cause) => write!(f, "{}", cause),
+            DescribeLocationSmbError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeLocationSmbError {}
 /// Errors returned by DescribeTask
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeTaskError {
     /// <p>This exception is thrown when an error occurs in the AWS DataSync service.</p>
     Internal(String),
     /// <p>This exception is thrown when the client submits a malformed request.</p>
     InvalidRequest(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeTaskError {
@@ -1841,13 +1949,13 @@ impl DescribeTaskError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalException" => {
-                    return RusotoError::Service(DescribeTaskError::Internal(err.msg))
+                    return RusotoError::service(DescribeTaskError::Internal(err.msg), &res)
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DescribeTaskError::InvalidRequest(err.msg))
+                    return RusotoError::service(DescribeTaskError::InvalidRequest(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DescribeTaskError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1859,17 +1967,21 @@ impl fmt::Display for DescribeTaskError {
         match *self {
             DescribeTaskError::Internal(ref cause) => write!(f, "{}", cause),
             DescribeTaskError::InvalidRequest(ref cause) => write!(f, "{}", cause),
+            DescribeTaskError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeTaskError {}
 /// Errors returned by DescribeTaskExecution
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeTaskExecutionError {
     /// <p>This exception is thrown when an error occurs in the AWS DataSync service.</p>
     Internal(String),
     /// <p>This exception is thrown when the client submits a malformed request.</p>
     InvalidRequest(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeTaskExecutionError {
@@ -1877,15 +1989,19 @@ impl DescribeTaskExecutionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalException" => {
-                    return RusotoError::Service(DescribeTaskExecutionError::Internal(err.msg))
+                    return RusotoError::service(
+                        DescribeTaskExecutionError::Internal(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DescribeTaskExecutionError::InvalidRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeTaskExecutionError::InvalidRequest(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DescribeTaskExecutionError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1897,17 +2013,21 @@ impl fmt::Display for DescribeTaskExecutionError {
         match *self {
             DescribeTaskExecutionError::Internal(ref cause) => write!(f, "{}", cause),
             DescribeTaskExecutionError::InvalidRequest(ref cause) => write!(f, "{}", cause),
+            DescribeTaskExecutionError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeTaskExecutionError {}
 /// Errors returned by ListAgents
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListAgentsError {
     /// <p>This exception is thrown when an error occurs in the AWS DataSync service.</p>
     Internal(String),
     /// <p>This exception is thrown when the client submits a malformed request.</p>
     InvalidRequest(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListAgentsError {
@@ -1915,13 +2035,13 @@ impl ListAgentsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalException" => {
-                    return RusotoError::Service(ListAgentsError::Internal(err.msg))
+                    return RusotoError::service(ListAgentsError::Internal(err.msg), &res)
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(ListAgentsError::InvalidRequest(err.msg))
+                    return RusotoError::service(ListAgentsError::InvalidRequest(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListAgentsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1933,17 +2053,21 @@ impl fmt::Display for ListAgentsError {
         match *self {
             ListAgentsError::Internal(ref cause) => write!(f, "{}", cause),
             ListAgentsError::InvalidRequest(ref cause) => write!(f, "{}", cause),
+            ListAgentsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListAgentsError {}
 /// Errors returned by ListLocations
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListLocationsError {
     /// <p>This exception is thrown when an error occurs in the AWS DataSync service.</p>
     Internal(String),
     /// <p>This exception is thrown when the client submits a malformed request.</p>
     InvalidRequest(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListLocationsError {
@@ -1951,13 +2075,13 @@ impl ListLocationsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalException" => {
-                    return RusotoError::Service(ListLocationsError::Internal(err.msg))
+                    return RusotoError::service(ListLocationsError::Internal(err.msg), &res)
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(ListLocationsError::InvalidRequest(err.msg))
+                    return RusotoError::service(ListLocationsError::InvalidRequest(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListLocationsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1969,17 +2093,21 @@ impl fmt::Display for ListLocationsError {
         match *self {
             ListLocationsError::Internal(ref cause) => write!(f, "{}", cause),
             ListLocationsError::InvalidRequest(ref cause) => write!(f, "{}", cause),
+            ListLocationsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListLocationsError {}
 /// Errors returned by ListTagsForResource
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListTagsForResourceError {
     /// <p>This exception is thrown when an error occurs in the AWS DataSync service.</p>
     Internal(String),
     /// <p>This exception is thrown when the client submits a malformed request.</p>
     InvalidRequest(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListTagsForResourceError {
@@ -1987,13 +2115,16 @@ impl ListTagsForResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalException" => {
-                    return RusotoError::Service(ListTagsForResourceError::Internal(err.msg))
+                    return RusotoError::service(ListTagsForResourceError::Internal(err.msg), &res)
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(ListTagsForResourceError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        ListTagsForResourceError::InvalidRequest(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListTagsForResourceError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2005,17 +2136,21 @@ impl fmt::Display for ListTagsForResourceError {
         match *self {
             ListTagsForResourceError::Internal(ref cause) => write!(f, "{}", cause),
             ListTagsForResourceError::InvalidRequest(ref cause) => write!(f, "{}", cause),
+            ListTagsForResourceError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListTagsForResourceError {}
 /// Errors returned by ListTaskExecutions
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListTaskExecutionsError {
     /// <p>This exception is thrown when an error occurs in the AWS DataSync service.</p>
     Internal(String),
     /// <p>This exception is thrown when the client submits a malformed request.</p>
     InvalidRequest(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListTaskExecutionsError {
@@ -2023,13 +2158,16 @@ impl ListTaskExecutionsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalException" => {
-                    return RusotoError::Service(ListTaskExecutionsError::Internal(err.msg))
+                    return RusotoError::service(ListTaskExecutionsError::Internal(err.msg), &res)
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(ListTaskExecutionsError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        ListTaskExecutionsError::InvalidRequest(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListTaskExecutionsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2041,17 +2179,21 @@ impl fmt::Display for ListTaskExecutionsError {
         match *self {
             ListTaskExecutionsError::Internal(ref cause) => write!(f, "{}", cause),
             ListTaskExecutionsError::InvalidRequest(ref cause) => write!(f, "{}", cause),
+            ListTaskExecutionsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListTaskExecutionsError {}
 /// Errors returned by ListTasks
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListTasksError {
     /// <p>This exception is thrown when an error occurs in the AWS DataSync service.</p>
     Internal(String),
     /// <p>This exception is thrown when the client submits a malformed request.</p>
     InvalidRequest(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListTasksError {
@@ -2059,13 +2201,13 @@ impl ListTasksError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalException" => {
-                    return RusotoError::Service(ListTasksError::Internal(err.msg))
+                    return RusotoError::service(ListTasksError::Internal(err.msg), &res)
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(ListTasksError::InvalidRequest(err.msg))
+                    return RusotoError::service(ListTasksError::InvalidRequest(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListTasksError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2077,17 +2219,21 @@ impl fmt::Display for ListTasksError {
         match *self {
             ListTasksError::Internal(ref cause) => write!(f, "{}", cause),
             ListTasksError::InvalidRequest(ref cause) => write!(f, "{}", cause),
+            ListTasksError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListTasksError {}
 /// Errors returned by StartTaskExecution
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum StartTaskExecutionError {
     /// <p>This exception is thrown when an error occurs in the AWS DataSync service.</p>
     Internal(String),
     /// <p>This exception is thrown when the client submits a malformed request.</p>
     InvalidRequest(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl StartTaskExecutionError {
@@ -2095,13 +2241,16 @@ impl StartTaskExecutionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalException" => {
-                    return RusotoError::Service(StartTaskExecutionError::Internal(err.msg))
+                    return RusotoError::service(StartTaskExecutionError::Internal(err.msg), &res)
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(StartTaskExecutionError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        StartTaskExecutionError::InvalidRequest(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(StartTaskExecutionError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2113,17 +2262,21 @@ impl fmt::Display for StartTaskExecutionError {
         match *self {
             StartTaskExecutionError::Internal(ref cause) => write!(f, "{}", cause),
             StartTaskExecutionError::InvalidRequest(ref cause) => write!(f, "{}", cause),
+            StartTaskExecutionError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for StartTaskExecutionError {}
 /// Errors returned by TagResource
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum TagResourceError {
     /// <p>This exception is thrown when an error occurs in the AWS DataSync service.</p>
     Internal(String),
     /// <p>This exception is thrown when the client submits a malformed request.</p>
     InvalidRequest(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl TagResourceError {
@@ -2131,13 +2284,13 @@ impl TagResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalException" => {
-                    return RusotoError::Service(TagResourceError::Internal(err.msg))
+                    return RusotoError::service(TagResourceError::Internal(err.msg), &res)
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(TagResourceError::InvalidRequest(err.msg))
+                    return RusotoError::service(TagResourceError::InvalidRequest(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(TagResourceError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2149,17 +2302,21 @@ impl fmt::Display for TagResourceError {
         match *self {
             TagResourceError::Internal(ref cause) => write!(f, "{}", cause),
             TagResourceError::InvalidRequest(ref cause) => write!(f, "{}", cause),
+            TagResourceError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for TagResourceError {}
 /// Errors returned by UntagResource
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UntagResourceError {
     /// <p>This exception is thrown when an error occurs in the AWS DataSync service.</p>
     Internal(String),
     /// <p>This exception is thrown when the client submits a malformed request.</p>
     InvalidRequest(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UntagResourceError {
@@ -2167,13 +2324,13 @@ impl UntagResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalException" => {
-                    return RusotoError::Service(UntagResourceError::Internal(err.msg))
+                    return RusotoError::service(UntagResourceError::Internal(err.msg), &res)
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(UntagResourceError::InvalidRequest(err.msg))
+                    return RusotoError::service(UntagResourceError::InvalidRequest(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UntagResourceError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2185,17 +2342,21 @@ impl fmt::Display for UntagResourceError {
         match *self {
             UntagResourceError::Internal(ref cause) => write!(f, "{}", cause),
             UntagResourceError::InvalidRequest(ref cause) => write!(f, "{}", cause),
+            UntagResourceError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for UntagResourceError {}
 /// Errors returned by UpdateAgent
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdateAgentError {
     /// <p>This exception is thrown when an error occurs in the AWS DataSync service.</p>
     Internal(String),
     /// <p>This exception is thrown when the client submits a malformed request.</p>
     InvalidRequest(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdateAgentError {
@@ -2203,13 +2364,13 @@ impl UpdateAgentError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalException" => {
-                    return RusotoError::Service(UpdateAgentError::Internal(err.msg))
+                    return RusotoError::service(UpdateAgentError::Internal(err.msg), &res)
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(UpdateAgentError::InvalidRequest(err.msg))
+                    return RusotoError::service(UpdateAgentError::InvalidRequest(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UpdateAgentError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2221,17 +2382,21 @@ impl fmt::Display for UpdateAgentError {
         match *self {
             UpdateAgentError::Internal(ref cause) => write!(f, "{}", cause),
             UpdateAgentError::InvalidRequest(ref cause) => write!(f, "{}", cause),
+            UpdateAgentError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for UpdateAgentError {}
 /// Errors returned by UpdateTask
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdateTaskError {
     /// <p>This exception is thrown when an error occurs in the AWS DataSync service.</p>
     Internal(String),
     /// <p>This exception is thrown when the client submits a malformed request.</p>
     InvalidRequest(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdateTaskError {
@@ -2239,13 +2404,13 @@ impl UpdateTaskError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalException" => {
-                    return RusotoError::Service(UpdateTaskError::Internal(err.msg))
+                    return RusotoError::service(UpdateTaskError::Internal(err.msg), &res)
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(UpdateTaskError::InvalidRequest(err.msg))
+                    return RusotoError::service(UpdateTaskError::InvalidRequest(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UpdateTaskError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2257,6 +2422,7 @@ impl fmt::Display for UpdateTaskError {
         match *self {
             UpdateTaskError::Internal(ref cause) => write!(f, "{}", cause),
             UpdateTaskError::InvalidRequest(ref cause) => write!(f, "{}", cause),
+            UpdateTaskError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
@@ -2449,6 +2615,7 @@ impl DataSyncClient {
     /// Creates a client backed by the default tokio event loop.
     ///
     /// The client will use the default credentials provider and tls client.
+    #[cfg(not(target_arch = "wasm32"))]
     pub fn new(region: region::Region) -> DataSyncClient {
         DataSyncClient {
             client: Client::shared(),
@@ -2474,806 +2641,2070 @@ impl DataSyncClient {
     pub fn new_with_client(client: Client, region: region::Region) -> DataSyncClient {
         DataSyncClient { client, region }
     }
+
+    /// Returns a builder of a client, setting any of its region, credentials provider,
+    /// dispatcher, retry policy, endpoint and timeouts.
+    pub fn builder() -> rusoto_core::ClientBuilder<DataSyncClient> {
+        rusoto_core::ClientBuilder::new(DataSyncClient::new_with_client)
+    }
+
+    /// Returns a clone of this client with the overrides of `config` applied to its
+    /// operations, sharing its connections.
+    pub fn customize(&self, config: &rusoto_core::OperationConfig) -> DataSyncClient {
+        DataSyncClient {
+            client: self.client.customize(config),
+            region: config.resolve_region(&self.region),
+        }
+    }
 }
 
-#[async_trait]
-impl DataSync for DataSyncClient {
-    /// <p>Cancels execution of a task. </p> <p>When you cancel a task execution, the transfer of some files are abruptly interrupted. The contents of files that are transferred to the destination might be incomplete or inconsistent with the source files. However, if you start a new task execution on the same task and you allow the task execution to complete, file content on the destination is complete and consistent. This applies to other unexpected failures that interrupt a task execution. In all of these cases, AWS DataSync successfully complete the transfer when you start the next task execution.</p>
-    async fn cancel_task_execution(
+impl DataSyncClient {
+    /// Calls `cancel_task_execution`, also returning the metadata of the response.
+    pub async fn cancel_task_execution_with_metadata(
         &self,
         input: CancelTaskExecutionRequest,
-    ) -> Result<CancelTaskExecutionResponse, RusotoError<CancelTaskExecutionError>> {
+    ) -> Result<
+        (CancelTaskExecutionResponse, ResponseMetadata),
+        RusotoError<CancelTaskExecutionError>,
+    > {
+        let request = self.build_cancel_task_execution_request(input);
+
+        let mut response = self
+            .client
+            .sign_and_dispatch(request)
+            .await
+            .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
+        if response.status.is_success() {
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<CancelTaskExecutionResponse, _>()?;
+            Ok((result, metadata))
+        } else {
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(CancelTaskExecutionError::from_response(response))
+        }
+    }
+
+    /// Presigns the request `cancel_task_execution` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_cancel_task_execution(
+        &self,
+        presigner: &rusoto_core::Presigner,
+        input: CancelTaskExecutionRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_cancel_task_execution_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_cancel_task_execution_request(
+        &self,
+        input: CancelTaskExecutionRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "datasync", &self.region, "/");
+        request.set_operation("CancelTaskExecution");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "FmrsService.CancelTaskExecution");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `create_agent`, also returning the metadata of the response.
+    pub async fn create_agent_with_metadata(
+        &self,
+        input: CreateAgentRequest,
+    ) -> Result<(CreateAgentResponse, ResponseMetadata), RusotoError<CreateAgentError>> {
+        let request = self.build_create_agent_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<CancelTaskExecutionResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<CreateAgentResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(CancelTaskExecutionError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(CreateAgentError::from_response(response))
         }
     }
 
-    /// <p><p>Activates an AWS DataSync agent that you have deployed on your host. The activation process associates your agent with your account. In the activation process, you specify information such as the AWS Region that you want to activate the agent in. You activate the agent in the AWS Region where your target locations (in Amazon S3 or Amazon EFS) reside. Your tasks are created in this AWS Region.</p> <p>You can activate the agent in a VPC (Virtual private Cloud) or provide the agent access to a VPC endpoint so you can run tasks without going over the public Internet.</p> <p>You can use an agent for more than one location. If a task uses multiple agents, all of them need to have status AVAILABLE for the task to run. If you use multiple agents for a source location, the status of all the agents must be AVAILABLE for the task to run. </p> <p>Agents are automatically updated by AWS on a regular basis, using a mechanism that ensures minimal interruption to your tasks.</p> <p/></p>
-    async fn create_agent(
+    /// Presigns the request `create_agent` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_agent(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: CreateAgentRequest,
-    ) -> Result<CreateAgentResponse, RusotoError<CreateAgentError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_agent_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_agent_request(&self, input: CreateAgentRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "datasync", &self.region, "/");
+        request.set_operation("CreateAgent");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "FmrsService.CreateAgent");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `create_location_efs`, also returning the metadata of the response.
+    pub async fn create_location_efs_with_metadata(
+        &self,
+        input: CreateLocationEfsRequest,
+    ) -> Result<(CreateLocationEfsResponse, ResponseMetadata), RusotoError<CreateLocationEfsError>>
+    {
+        let request = self.build_create_location_efs_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<CreateAgentResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<CreateLocationEfsResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(CreateAgentError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(CreateLocationEfsError::from_response(response))
         }
     }
 
-    /// <p>Creates an endpoint for an Amazon EFS file system.</p>
-    async fn create_location_efs(
+    /// Presigns the request `create_location_efs` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_location_efs(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: CreateLocationEfsRequest,
-    ) -> Result<CreateLocationEfsResponse, RusotoError<CreateLocationEfsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_location_efs_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_location_efs_request(&self, input: CreateLocationEfsRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "datasync", &self.region, "/");
+        request.set_operation("CreateLocationEfs");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "FmrsService.CreateLocationEfs");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `create_location_fsx_windows`, also returning the metadata of the response.
+    pub async fn create_location_fsx_windows_with_metadata(
+        &self,
+        input: CreateLocationFsxWindowsRequest,
+    ) -> Result<
+        (CreateLocationFsxWindowsResponse, ResponseMetadata),
+        RusotoError<CreateLocationFsxWindowsError>,
+    > {
+        let request = self.build_create_location_fsx_windows_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<CreateLocationEfsResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<CreateLocationFsxWindowsResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(CreateLocationEfsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(CreateLocationFsxWindowsError::from_response(response))
         }
     }
 
-    /// <p>Creates an endpoint for an Amazon FSx for Windows file system.</p>
-    async fn create_location_fsx_windows(
+    /// Presigns the request `create_location_fsx_windows` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_location_fsx_windows(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: CreateLocationFsxWindowsRequest,
-    ) -> Result<CreateLocationFsxWindowsResponse, RusotoError<CreateLocationFsxWindowsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_location_fsx_windows_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_location_fsx_windows_request(
+        &self,
+        input: CreateLocationFsxWindowsRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "datasync", &self.region, "/");
+        request.set_operation("CreateLocationFsxWindows");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "FmrsService.CreateLocationFsxWindows");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `create_location_nfs`, also returning the metadata of the response.
+    pub async fn create_location_nfs_with_metadata(
+        &self,
+        input: CreateLocationNfsRequest,
+    ) -> Result<(CreateLocationNfsResponse, ResponseMetadata), RusotoError<CreateLocationNfsError>>
+    {
+        let request = self.build_create_location_nfs_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<CreateLocationFsxWindowsResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<CreateLocationNfsResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(CreateLocationFsxWindowsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(CreateLocationNfsError::from_response(response))
         }
     }
 
-    /// <p>Defines a file system on a Network File System (NFS) server that can be read from or written to</p>
-    async fn create_location_nfs(
+    /// Presigns the request `create_location_nfs` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_location_nfs(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: CreateLocationNfsRequest,
-    ) -> Result<CreateLocationNfsResponse, RusotoError<CreateLocationNfsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_location_nfs_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_location_nfs_request(&self, input: CreateLocationNfsRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "datasync", &self.region, "/");
+        request.set_operation("CreateLocationNfs");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "FmrsService.CreateLocationNfs");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `create_location_s3`, also returning the metadata of the response.
+    pub async fn create_location_s3_with_metadata(
+        &self,
+        input: CreateLocationS3Request,
+    ) -> Result<(CreateLocationS3Response, ResponseMetadata), RusotoError<CreateLocationS3Error>>
+    {
+        let request = self.build_create_location_s3_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<CreateLocationNfsResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<CreateLocationS3Response, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(CreateLocationNfsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(CreateLocationS3Error::from_response(response))
         }
     }
 
-    /// <p>Creates an endpoint for an Amazon S3 bucket.</p> <p>For AWS DataSync to access a destination S3 bucket, it needs an AWS Identity and Access Management (IAM) role that has the required permissions. You can set up the required permissions by creating an IAM policy that grants the required permissions and attaching the policy to the role. An example of such a policy is shown in the examples section.</p> <p>For more information, see https://docs.aws.amazon.com/datasync/latest/userguide/working-with-locations.html#create-s3-location in the <i>AWS DataSync User Guide.</i> </p>
-    async fn create_location_s3(
+    /// Presigns the request `create_location_s3` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_location_s3(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: CreateLocationS3Request,
-    ) -> Result<CreateLocationS3Response, RusotoError<CreateLocationS3Error>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_location_s3_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_location_s3_request(&self, input: CreateLocationS3Request) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "datasync", &self.region, "/");
+        request.set_operation("CreateLocationS3");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "FmrsService.CreateLocationS3");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `create_location_smb`, also returning the metadata of the response.
+    pub async fn create_location_smb_with_metadata(
+        &self,
+        input: CreateLocationSmbRequest,
+    ) -> Result<(CreateLocationSmbResponse, ResponseMetadata), RusotoError<CreateLocationSmbError>>
+    {
+        let request = self.build_create_location_smb_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<CreateLocationS3Response, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<CreateLocationSmbResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(CreateLocationS3Error::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(CreateLocationSmbError::from_response(response))
         }
     }
 
-    /// <p>Defines a file system on an Server Message Block (SMB) server that can be read from or written to.</p>
-    async fn create_location_smb(
+    /// Presigns the request `create_location_smb` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_location_smb(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: CreateLocationSmbRequest,
-    ) -> Result<CreateLocationSmbResponse, RusotoError<CreateLocationSmbError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_location_smb_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_location_smb_request(&self, input: CreateLocationSmbRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "datasync", &self.region, "/");
+        request.set_operation("CreateLocationSmb");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "FmrsService.CreateLocationSmb");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `create_task`, also returning the metadata of the response.
+    pub async fn create_task_with_metadata(
+        &self,
+        input: CreateTaskRequest,
+    ) -> Result<(CreateTaskResponse, ResponseMetadata), RusotoError<CreateTaskError>> {
+        let request = self.build_create_task_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<CreateLocationSmbResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<CreateTaskResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(CreateLocationSmbError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(CreateTaskError::from_response(response))
         }
     }
 
-    /// <p>Creates a task. A task is a set of two locations (source and destination) and a set of Options that you use to control the behavior of a task. If you don't specify Options when you create a task, AWS DataSync populates them with service defaults.</p> <p>When you create a task, it first enters the CREATING state. During CREATING AWS DataSync attempts to mount the on-premises Network File System (NFS) location. The task transitions to the AVAILABLE state without waiting for the AWS location to become mounted. If required, AWS DataSync mounts the AWS location before each task execution.</p> <p>If an agent that is associated with a source (NFS) location goes offline, the task transitions to the UNAVAILABLE status. If the status of the task remains in the CREATING status for more than a few minutes, it means that your agent might be having trouble mounting the source NFS file system. Check the task's ErrorCode and ErrorDetail. Mount issues are often caused by either a misconfigured firewall or a mistyped NFS server host name.</p>
-    async fn create_task(
+    /// Presigns the request `create_task` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_task(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: CreateTaskRequest,
-    ) -> Result<CreateTaskResponse, RusotoError<CreateTaskError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_task_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_task_request(&self, input: CreateTaskRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "datasync", &self.region, "/");
+        request.set_operation("CreateTask");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "FmrsService.CreateTask");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `delete_agent`, also returning the metadata of the response.
+    pub async fn delete_agent_with_metadata(
+        &self,
+        input: DeleteAgentRequest,
+    ) -> Result<(DeleteAgentResponse, ResponseMetadata), RusotoError<DeleteAgentError>> {
+        let request = self.build_delete_agent_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<CreateTaskResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DeleteAgentResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(CreateTaskError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DeleteAgentError::from_response(response))
         }
     }
 
-    /// <p>Deletes an agent. To specify which agent to delete, use the Amazon Resource Name (ARN) of the agent in your request. The operation disassociates the agent from your AWS account. However, it doesn't delete the agent virtual machine (VM) from your on-premises environment.</p>
-    async fn delete_agent(
+    /// Presigns the request `delete_agent` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_agent(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteAgentRequest,
-    ) -> Result<DeleteAgentResponse, RusotoError<DeleteAgentError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_agent_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_agent_request(&self, input: DeleteAgentRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "datasync", &self.region, "/");
+        request.set_operation("DeleteAgent");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "FmrsService.DeleteAgent");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `delete_location`, also returning the metadata of the response.
+    pub async fn delete_location_with_metadata(
+        &self,
+        input: DeleteLocationRequest,
+    ) -> Result<(DeleteLocationResponse, ResponseMetadata), RusotoError<DeleteLocationError>> {
+        let request = self.build_delete_location_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<DeleteAgentResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DeleteLocationResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteAgentError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DeleteLocationError::from_response(response))
         }
     }
 
-    /// <p>Deletes the configuration of a location used by AWS DataSync. </p>
-    async fn delete_location(
+    /// Presigns the request `delete_location` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_location(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteLocationRequest,
-    ) -> Result<DeleteLocationResponse, RusotoError<DeleteLocationError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_location_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_location_request(&self, input: DeleteLocationRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "datasync", &self.region, "/");
+        request.set_operation("DeleteLocation");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "FmrsService.DeleteLocation");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `delete_task`, also returning the metadata of the response.
+    pub async fn delete_task_with_metadata(
+        &self,
+        input: DeleteTaskRequest,
+    ) -> Result<(DeleteTaskResponse, ResponseMetadata), RusotoError<DeleteTaskError>> {
+        let request = self.build_delete_task_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<DeleteLocationResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DeleteTaskResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteLocationError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DeleteTaskError::from_response(response))
         }
     }
 
-    /// <p>Deletes a task.</p>
-    async fn delete_task(
+    /// Presigns the request `delete_task` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_task(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteTaskRequest,
-    ) -> Result<DeleteTaskResponse, RusotoError<DeleteTaskError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_task_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_task_request(&self, input: DeleteTaskRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "datasync", &self.region, "/");
+        request.set_operation("DeleteTask");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "FmrsService.DeleteTask");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `describe_agent`, also returning the metadata of the response.
+    pub async fn describe_agent_with_metadata(
+        &self,
+        input: DescribeAgentRequest,
+    ) -> Result<(DescribeAgentResponse, ResponseMetadata), RusotoError<DescribeAgentError>> {
+        let request = self.build_describe_agent_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<DeleteTaskResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DescribeAgentResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteTaskError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DescribeAgentError::from_response(response))
         }
     }
 
-    /// <p>Returns metadata such as the name, the network interfaces, and the status (that is, whether the agent is running or not) for an agent. To specify which agent to describe, use the Amazon Resource Name (ARN) of the agent in your request. </p>
-    async fn describe_agent(
+    /// Presigns the request `describe_agent` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_agent(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeAgentRequest,
-    ) -> Result<DescribeAgentResponse, RusotoError<DescribeAgentError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_agent_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_agent_request(&self, input: DescribeAgentRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "datasync", &self.region, "/");
+        request.set_operation("DescribeAgent");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "FmrsService.DescribeAgent");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `describe_location_efs`, also returning the metadata of the response.
+    pub async fn describe_location_efs_with_metadata(
+        &self,
+        input: DescribeLocationEfsRequest,
+    ) -> Result<
+        (DescribeLocationEfsResponse, ResponseMetadata),
+        RusotoError<DescribeLocationEfsError>,
+    > {
+        let request = self.build_describe_location_efs_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<DescribeAgentResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DescribeLocationEfsResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeAgentError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DescribeLocationEfsError::from_response(response))
         }
     }
 
-    /// <p>Returns metadata, such as the path information about an Amazon EFS location.</p>
-    async fn describe_location_efs(
+    /// Presigns the request `describe_location_efs` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_location_efs(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeLocationEfsRequest,
-    ) -> Result<DescribeLocationEfsResponse, RusotoError<DescribeLocationEfsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_location_efs_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_location_efs_request(
+        &self,
+        input: DescribeLocationEfsRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "datasync", &self.region, "/");
+        request.set_operation("DescribeLocationEfs");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "FmrsService.DescribeLocationEfs");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `describe_location_fsx_windows`, also returning the metadata of the response.
+    pub async fn describe_location_fsx_windows_with_metadata(
+        &self,
+        input: DescribeLocationFsxWindowsRequest,
+    ) -> Result<
+        (DescribeLocationFsxWindowsResponse, ResponseMetadata),
+        RusotoError<DescribeLocationFsxWindowsError>,
+    > {
+        let request = self.build_describe_location_fsx_windows_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DescribeLocationEfsResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DescribeLocationFsxWindowsResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeLocationEfsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DescribeLocationFsxWindowsError::from_response(response))
         }
     }
 
-    /// <p>Returns metadata, such as the path information about an Amazon FSx for Windows location.</p>
-    async fn describe_location_fsx_windows(
+    /// Presigns the request `describe_location_fsx_windows` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_location_fsx_windows(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeLocationFsxWindowsRequest,
-    ) -> Result<DescribeLocationFsxWindowsResponse, RusotoError<DescribeLocationFsxWindowsError>>
-    {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_location_fsx_windows_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_location_fsx_windows_request(
+        &self,
+        input: DescribeLocationFsxWindowsRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "datasync", &self.region, "/");
+        request.set_operation("DescribeLocationFsxWindows");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "FmrsService.DescribeLocationFsxWindows");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `describe_location_nfs`, also returning the metadata of the response.
+    pub async fn describe_location_nfs_with_metadata(
+        &self,
+        input: DescribeLocationNfsRequest,
+    ) -> Result<
+        (DescribeLocationNfsResponse, ResponseMetadata),
+        RusotoError<DescribeLocationNfsError>,
+    > {
+        let request = self.build_describe_location_nfs_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DescribeLocationFsxWindowsResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DescribeLocationNfsResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeLocationFsxWindowsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DescribeLocationNfsError::from_response(response))
         }
     }
 
-    /// <p>Returns metadata, such as the path information, about a NFS location.</p>
-    async fn describe_location_nfs(
+    /// Presigns the request `describe_location_nfs` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_location_nfs(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeLocationNfsRequest,
-    ) -> Result<DescribeLocationNfsResponse, RusotoError<DescribeLocationNfsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_location_nfs_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_location_nfs_request(
+        &self,
+        input: DescribeLocationNfsRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "datasync", &self.region, "/");
+        request.set_operation("DescribeLocationNfs");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "FmrsService.DescribeLocationNfs");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `describe_location_s3`, also returning the metadata of the response.
+    pub async fn describe_location_s3_with_metadata(
+        &self,
+        input: DescribeLocationS3Request,
+    ) -> Result<(DescribeLocationS3Response, ResponseMetadata), RusotoError<DescribeLocationS3Error>>
+    {
+        let request = self.build_describe_location_s3_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DescribeLocationNfsResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DescribeLocationS3Response, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeLocationNfsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DescribeLocationS3Error::from_response(response))
         }
     }
 
-    /// <p>Returns metadata, such as bucket name, about an Amazon S3 bucket location.</p>
-    async fn describe_location_s3(
+    /// Presigns the request `describe_location_s3` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_location_s3(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeLocationS3Request,
-    ) -> Result<DescribeLocationS3Response, RusotoError<DescribeLocationS3Error>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_location_s3_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_location_s3_request(
+        &self,
+        input: DescribeLocationS3Request,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "datasync", &self.region, "/");
+        request.set_operation("DescribeLocationS3");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "FmrsService.DescribeLocationS3");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `describe_location_smb`, also returning the metadata of the response.
+    pub async fn describe_location_smb_with_metadata(
+        &self,
+        input: DescribeLocationSmbRequest,
+    ) -> Result<
+        (DescribeLocationSmbResponse, ResponseMetadata),
+        RusotoError<DescribeLocationSmbError>,
+    > {
+        let request = self.build_describe_location_smb_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DescribeLocationS3Response, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DescribeLocationSmbResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeLocationS3Error::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DescribeLocationSmbError::from_response(response))
         }
     }
 
-    /// <p>Returns metadata, such as the path and user information about a SMB location.</p>
-    async fn describe_location_smb(
+    /// Presigns the request `describe_location_smb` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_location_smb(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeLocationSmbRequest,
-    ) -> Result<DescribeLocationSmbResponse, RusotoError<DescribeLocationSmbError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_location_smb_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_location_smb_request(
+        &self,
+        input: DescribeLocationSmbRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "datasync", &self.region, "/");
+        request.set_operation("DescribeLocationSmb");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "FmrsService.DescribeLocationSmb");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `describe_task`, also returning the metadata of the response.
+    pub async fn describe_task_with_metadata(
+        &self,
+        input: DescribeTaskRequest,
+    ) -> Result<(DescribeTaskResponse, ResponseMetadata), RusotoError<DescribeTaskError>> {
+        let request = self.build_describe_task_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DescribeLocationSmbResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DescribeTaskResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeLocationSmbError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DescribeTaskError::from_response(response))
         }
     }
 
-    /// <p>Returns metadata about a task.</p>
-    async fn describe_task(
+    /// Presigns the request `describe_task` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_task(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeTaskRequest,
-    ) -> Result<DescribeTaskResponse, RusotoError<DescribeTaskError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_task_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_task_request(&self, input: DescribeTaskRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "datasync", &self.region, "/");
+        request.set_operation("DescribeTask");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "FmrsService.DescribeTask");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
-        let mut response = self
-            .client
-            .sign_and_dispatch(request)
+        request
+    }
+
+    /// Calls `describe_task_execution`, also returning the metadata of the response.
+    pub async fn describe_task_execution_with_metadata(
+        &self,
+        input: DescribeTaskExecutionRequest,
+    ) -> Result<
+        (DescribeTaskExecutionResponse, ResponseMetadata),
+        RusotoError<DescribeTaskExecutionError>,
+    > {
+        let request = self.build_describe_task_execution_request(input);
+
+        let mut response = self
+            .client
+            .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<DescribeTaskResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DescribeTaskExecutionResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeTaskError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DescribeTaskExecutionError::from_response(response))
         }
     }
 
-    /// <p>Returns detailed metadata about a task that is being executed.</p>
-    async fn describe_task_execution(
+    /// Presigns the request `describe_task_execution` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_task_execution(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeTaskExecutionRequest,
-    ) -> Result<DescribeTaskExecutionResponse, RusotoError<DescribeTaskExecutionError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_task_execution_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_task_execution_request(
+        &self,
+        input: DescribeTaskExecutionRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "datasync", &self.region, "/");
+        request.set_operation("DescribeTaskExecution");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "FmrsService.DescribeTaskExecution");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `list_agents`, also returning the metadata of the response.
+    pub async fn list_agents_with_metadata(
+        &self,
+        input: ListAgentsRequest,
+    ) -> Result<(ListAgentsResponse, ResponseMetadata), RusotoError<ListAgentsError>> {
+        let request = self.build_list_agents_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DescribeTaskExecutionResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ListAgentsResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeTaskExecutionError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ListAgentsError::from_response(response))
         }
     }
 
-    /// <p>Returns a list of agents owned by an AWS account in the AWS Region specified in the request. The returned list is ordered by agent Amazon Resource Name (ARN).</p> <p>By default, this operation returns a maximum of 100 agents. This operation supports pagination that enables you to optionally reduce the number of agents returned in a response.</p> <p>If you have more agents than are returned in a response (that is, the response returns only a truncated list of your agents), the response contains a marker that you can specify in your next request to fetch the next page of agents.</p>
-    async fn list_agents(
+    /// Presigns the request `list_agents` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_agents(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListAgentsRequest,
-    ) -> Result<ListAgentsResponse, RusotoError<ListAgentsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_agents_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_agents_request(&self, input: ListAgentsRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "datasync", &self.region, "/");
+        request.set_operation("ListAgents");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "FmrsService.ListAgents");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `list_locations`, also returning the metadata of the response.
+    pub async fn list_locations_with_metadata(
+        &self,
+        input: ListLocationsRequest,
+    ) -> Result<(ListLocationsResponse, ResponseMetadata), RusotoError<ListLocationsError>> {
+        let request = self.build_list_locations_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<ListAgentsResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ListLocationsResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ListAgentsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ListLocationsError::from_response(response))
         }
     }
 
-    /// <p>Returns a lists of source and destination locations.</p> <p>If you have more locations than are returned in a response (that is, the response returns only a truncated list of your agents), the response contains a token that you can specify in your next request to fetch the next page of locations.</p>
-    async fn list_locations(
+    /// Presigns the request `list_locations` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_locations(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListLocationsRequest,
-    ) -> Result<ListLocationsResponse, RusotoError<ListLocationsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_locations_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_locations_request(&self, input: ListLocationsRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "datasync", &self.region, "/");
+        request.set_operation("ListLocations");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "FmrsService.ListLocations");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `list_tags_for_resource`, also returning the metadata of the response.
+    pub async fn list_tags_for_resource_with_metadata(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> Result<
+        (ListTagsForResourceResponse, ResponseMetadata),
+        RusotoError<ListTagsForResourceError>,
+    > {
+        let request = self.build_list_tags_for_resource_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<ListLocationsResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ListTagsForResourceResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ListLocationsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ListTagsForResourceError::from_response(response))
         }
     }
 
-    /// <p>Returns all the tags associated with a specified resources. </p>
-    async fn list_tags_for_resource(
+    /// Presigns the request `list_tags_for_resource` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_tags_for_resource(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListTagsForResourceRequest,
-    ) -> Result<ListTagsForResourceResponse, RusotoError<ListTagsForResourceError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_tags_for_resource_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_tags_for_resource_request(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "datasync", &self.region, "/");
+        request.set_operation("ListTagsForResource");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "FmrsService.ListTagsForResource");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `list_task_executions`, also returning the metadata of the response.
+    pub async fn list_task_executions_with_metadata(
+        &self,
+        input: ListTaskExecutionsRequest,
+    ) -> Result<(ListTaskExecutionsResponse, ResponseMetadata), RusotoError<ListTaskExecutionsError>>
+    {
+        let request = self.build_list_task_executions_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListTagsForResourceResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ListTaskExecutionsResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ListTagsForResourceError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ListTaskExecutionsError::from_response(response))
         }
     }
 
-    /// <p>Returns a list of executed tasks.</p>
-    async fn list_task_executions(
+    /// Presigns the request `list_task_executions` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_task_executions(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListTaskExecutionsRequest,
-    ) -> Result<ListTaskExecutionsResponse, RusotoError<ListTaskExecutionsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_task_executions_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_task_executions_request(
+        &self,
+        input: ListTaskExecutionsRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "datasync", &self.region, "/");
+        request.set_operation("ListTaskExecutions");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "FmrsService.ListTaskExecutions");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `list_tasks`, also returning the metadata of the response.
+    pub async fn list_tasks_with_metadata(
+        &self,
+        input: ListTasksRequest,
+    ) -> Result<(ListTasksResponse, ResponseMetadata), RusotoError<ListTasksError>> {
+        let request = self.build_list_tasks_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListTaskExecutionsResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ListTasksResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ListTaskExecutionsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ListTasksError::from_response(response))
         }
     }
 
-    /// <p>Returns a list of all the tasks.</p>
-    async fn list_tasks(
+    /// Presigns the request `list_tasks` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_tasks(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListTasksRequest,
-    ) -> Result<ListTasksResponse, RusotoError<ListTasksError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_tasks_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_tasks_request(&self, input: ListTasksRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "datasync", &self.region, "/");
+        request.set_operation("ListTasks");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "FmrsService.ListTasks");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `start_task_execution`, also returning the metadata of the response.
+    pub async fn start_task_execution_with_metadata(
+        &self,
+        input: StartTaskExecutionRequest,
+    ) -> Result<(StartTaskExecutionResponse, ResponseMetadata), RusotoError<StartTaskExecutionError>>
+    {
+        let request = self.build_start_task_execution_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<ListTasksResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<StartTaskExecutionResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ListTasksError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(StartTaskExecutionError::from_response(response))
         }
     }
 
-    /// <p>Starts a specific invocation of a task. A <code>TaskExecution</code> value represents an individual run of a task. Each task can have at most one <code>TaskExecution</code> at a time.</p> <p> <code>TaskExecution</code> has the following transition phases: INITIALIZING | PREPARING | TRANSFERRING | VERIFYING | SUCCESS/FAILURE. </p> <p>For detailed information, see the Task Execution section in the Components and Terminology topic in the <i>AWS DataSync User Guide</i>.</p>
-    async fn start_task_execution(
+    /// Presigns the request `start_task_execution` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_start_task_execution(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: StartTaskExecutionRequest,
-    ) -> Result<StartTaskExecutionResponse, RusotoError<StartTaskExecutionError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_start_task_execution_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_start_task_execution_request(
+        &self,
+        input: StartTaskExecutionRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "datasync", &self.region, "/");
+        request.set_operation("StartTaskExecution");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "FmrsService.StartTaskExecution");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `tag_resource`, also returning the metadata of the response.
+    pub async fn tag_resource_with_metadata(
+        &self,
+        input: TagResourceRequest,
+    ) -> Result<(TagResourceResponse, ResponseMetadata), RusotoError<TagResourceError>> {
+        let request = self.build_tag_resource_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<StartTaskExecutionResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<TagResourceResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(StartTaskExecutionError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(TagResourceError::from_response(response))
         }
     }
 
-    /// <p>Applies a key-value pair to an AWS resource.</p>
-    async fn tag_resource(
+    /// Presigns the request `tag_resource` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_tag_resource(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: TagResourceRequest,
-    ) -> Result<TagResourceResponse, RusotoError<TagResourceError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_tag_resource_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_tag_resource_request(&self, input: TagResourceRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "datasync", &self.region, "/");
+        request.set_operation("TagResource");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "FmrsService.TagResource");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `untag_resource`, also returning the metadata of the response.
+    pub async fn untag_resource_with_metadata(
+        &self,
+        input: UntagResourceRequest,
+    ) -> Result<(UntagResourceResponse, ResponseMetadata), RusotoError<UntagResourceError>> {
+        let request = self.build_untag_resource_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<TagResourceResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<UntagResourceResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(TagResourceError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(UntagResourceError::from_response(response))
         }
     }
 
-    /// <p>Removes a tag from an AWS resource.</p>
-    async fn untag_resource(
+    /// Presigns the request `untag_resource` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_untag_resource(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UntagResourceRequest,
-    ) -> Result<UntagResourceResponse, RusotoError<UntagResourceError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_untag_resource_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_untag_resource_request(&self, input: UntagResourceRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "datasync", &self.region, "/");
+        request.set_operation("UntagResource");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "FmrsService.UntagResource");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `update_agent`, also returning the metadata of the response.
+    pub async fn update_agent_with_metadata(
+        &self,
+        input: UpdateAgentRequest,
+    ) -> Result<(UpdateAgentResponse, ResponseMetadata), RusotoError<UpdateAgentError>> {
+        let request = self.build_update_agent_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<UntagResourceResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<UpdateAgentResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(UntagResourceError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(UpdateAgentError::from_response(response))
         }
     }
 
-    /// <p>Updates the name of an agent.</p>
-    async fn update_agent(
+    /// Presigns the request `update_agent` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_agent(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UpdateAgentRequest,
-    ) -> Result<UpdateAgentResponse, RusotoError<UpdateAgentError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_agent_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_agent_request(&self, input: UpdateAgentRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "datasync", &self.region, "/");
+        request.set_operation("UpdateAgent");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "FmrsService.UpdateAgent");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `update_task`, also returning the metadata of the response.
+    pub async fn update_task_with_metadata(
+        &self,
+        input: UpdateTaskRequest,
+    ) -> Result<(UpdateTaskResponse, ResponseMetadata), RusotoError<UpdateTaskError>> {
+        let request = self.build_update_task_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<UpdateAgentResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<UpdateTaskResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(UpdateAgentError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(UpdateTaskError::from_response(response))
         }
     }
 
-    /// <p>Updates the metadata associated with a task.</p>
-    async fn update_task(
+    /// Presigns the request `update_task` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_task(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UpdateTaskRequest,
-    ) -> Result<UpdateTaskResponse, RusotoError<UpdateTaskError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_task_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_task_request(&self, input: UpdateTaskRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "datasync", &self.region, "/");
+        request.set_operation("UpdateTask");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "FmrsService.UpdateTask");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
-        let mut response = self
-            .client
-            .sign_and_dispatch(request)
+        request
+    }
+
+    /// The pages of `ListAgents` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `MaxResults` of `input`.
+    pub fn list_agents_pages(
+        &self,
+        input: ListAgentsRequest,
+    ) -> impl futures::Stream<Item = Result<ListAgentsResponse, RusotoError<ListAgentsError>>> + Send
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: DataSyncClient, input| async move { client.list_agents(input).await },
+            |input: &ListAgentsRequest, output: &ListAgentsResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListAgentsRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `Agents` of every page of `ListAgents` results for `input`, as `list_agents_pages` requests them.
+    pub fn list_agents_stream(
+        &self,
+        input: ListAgentsRequest,
+    ) -> impl futures::Stream<Item = Result<AgentListEntry, RusotoError<ListAgentsError>>> + Send
+    {
+        rusoto_core::pagination::items(self.list_agents_pages(input), |page| {
+            page.agents.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `ListLocations` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `MaxResults` of `input`.
+    pub fn list_locations_pages(
+        &self,
+        input: ListLocationsRequest,
+    ) -> impl futures::Stream<Item = Result<ListLocationsResponse, RusotoError<ListLocationsError>>> + Send
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: DataSyncClient, input| async move { client.list_locations(input).await },
+            |input: &ListLocationsRequest, output: &ListLocationsResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListLocationsRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `Locations` of every page of `ListLocations` results for `input`, as `list_locations_pages` requests them.
+    pub fn list_locations_stream(
+        &self,
+        input: ListLocationsRequest,
+    ) -> impl futures::Stream<Item = Result<LocationListEntry, RusotoError<ListLocationsError>>> + Send
+    {
+        rusoto_core::pagination::items(self.list_locations_pages(input), |page| {
+            page.locations.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `ListTagsForResource` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `MaxResults` of `input`.
+    pub fn list_tags_for_resource_pages(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> impl futures::Stream<
+        Item = Result<ListTagsForResourceResponse, RusotoError<ListTagsForResourceError>>,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: DataSyncClient, input| async move { client.list_tags_for_resource(input).await },
+            |input: &ListTagsForResourceRequest, output: &ListTagsForResourceResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListTagsForResourceRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `Tags` of every page of `ListTagsForResource` results for `input`, as `list_tags_for_resource_pages` requests them.
+    pub fn list_tags_for_resource_stream(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> impl futures::Stream<Item = Result<TagListEntry, RusotoError<ListTagsForResourceError>>> + Send
+    {
+        rusoto_core::pagination::items(self.list_tags_for_resource_pages(input), |page| {
+            page.tags.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `ListTaskExecutions` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `MaxResults` of `input`.
+    pub fn list_task_executions_pages(
+        &self,
+        input: ListTaskExecutionsRequest,
+    ) -> impl futures::Stream<
+        Item = Result<ListTaskExecutionsResponse, RusotoError<ListTaskExecutionsError>>,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: DataSyncClient, input| async move { client.list_task_executions(input).await },
+            |input: &ListTaskExecutionsRequest, output: &ListTaskExecutionsResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListTaskExecutionsRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `TaskExecutions` of every page of `ListTaskExecutions` results for `input`, as `list_task_executions_pages` requests them.
+    pub fn list_task_executions_stream(
+        &self,
+        input: ListTaskExecutionsRequest,
+    ) -> impl futures::Stream<
+        Item = Result<TaskExecutionListEntry, RusotoError<ListTaskExecutionsError>>,
+    > + Send {
+        rusoto_core::pagination::items(self.list_task_executions_pages(input), |page| {
+            page.task_executions.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `ListTasks` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `MaxResults` of `input`.
+    pub fn list_tasks_pages(
+        &self,
+        input: ListTasksRequest,
+    ) -> impl futures::Stream<Item = Result<ListTasksResponse, RusotoError<ListTasksError>>> + Send
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: DataSyncClient, input| async move { client.list_tasks(input).await },
+            |input: &ListTasksRequest, output: &ListTasksResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListTasksRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `Tasks` of every page of `ListTasks` results for `input`, as `list_tasks_pages` requests them.
+    pub fn list_tasks_stream(
+        &self,
+        input: ListTasksRequest,
+    ) -> impl futures::Stream<Item = Result<TaskListEntry, RusotoError<ListTasksError>>> + Send
+    {
+        rusoto_core::pagination::items(self.list_tasks_pages(input), |page| {
+            page.tasks.unwrap_or_default()
+        })
+    }
+}
+
+#[async_trait]
+impl DataSync for DataSyncClient {
+    /// <p>Cancels execution of a task. </p> <p>When you cancel a task execution, the transfer of some files are abruptly interrupted. The contents of files that are transferred to the destination might be incomplete or inconsistent with the source files. However, if you start a new task execution on the same task and you allow the task execution to complete, file content on the destination is complete and consistent. This applies to other unexpected failures that interrupt a task execution. In all of these cases, AWS DataSync successfully complete the transfer when you start the next task execution.</p>
+    async fn cancel_task_execution(
+        &self,
+        input: CancelTaskExecutionRequest,
+    ) -> Result<CancelTaskExecutionResponse, RusotoError<CancelTaskExecutionError>> {
+        self.cancel_task_execution_with_metadata(input)
             .await
-            .map_err(RusotoError::from)?;
-        if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<UpdateTaskResponse, _>()
-        } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(UpdateTaskError::from_response(response))
-        }
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Activates an AWS DataSync agent that you have deployed on your host. The activation process associates your agent with your account. In the activation process, you specify information such as the AWS Region that you want to activate the agent in. You activate the agent in the AWS Region where your target locations (in Amazon S3 or Amazon EFS) reside. Your tasks are created in this AWS Region.</p> <p>You can activate the agent in a VPC (Virtual private Cloud) or provide the agent access to a VPC endpoint so you can run tasks without going over the public Internet.</p> <p>You can use an agent for more than one location. If a task uses multiple agents, all of them need to have status AVAILABLE for the task to run. If you use multiple agents for a source location, the status of all the agents must be AVAILABLE for the task to run. </p> <p>Agents are automatically updated by AWS on a regular basis, using a mechanism that ensures minimal interruption to your tasks.</p> <p/></p>
+    async fn create_agent(
+        &self,
+        input: CreateAgentRequest,
+    ) -> Result<CreateAgentResponse, RusotoError<CreateAgentError>> {
+        self.create_agent_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Creates an endpoint for an Amazon EFS file system.</p>
+    async fn create_location_efs(
+        &self,
+        input: CreateLocationEfsRequest,
+    ) -> Result<CreateLocationEfsResponse, RusotoError<CreateLocationEfsError>> {
+        self.create_location_efs_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Creates an endpoint for an Amazon FSx for Windows file system.</p>
+    async fn create_location_fsx_windows(
+        &self,
+        input: CreateLocationFsxWindowsRequest,
+    ) -> Result<CreateLocationFsxWindowsResponse, RusotoError<CreateLocationFsxWindowsError>> {
+        self.create_location_fsx_windows_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Defines a file system on a Network File System (NFS) server that can be read from or written to</p>
+    async fn create_location_nfs(
+        &self,
+        input: CreateLocationNfsRequest,
+    ) -> Result<CreateLocationNfsResponse, RusotoError<CreateLocationNfsError>> {
+        self.create_location_nfs_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Creates an endpoint for an Amazon S3 bucket.</p> <p>For AWS DataSync to access a destination S3 bucket, it needs an AWS Identity and Access Management (IAM) role that has the required permissions. You can set up the required permissions by creating an IAM policy that grants the required permissions and attaching the policy to the role. An example of such a policy is shown in the examples section.</p> <p>For more information, see https://docs.aws.amazon.com/datasync/latest/userguide/working-with-locations.html#create-s3-location in the <i>AWS DataSync User Guide.</i> </p>
+    async fn create_location_s3(
+        &self,
+        input: CreateLocationS3Request,
+    ) -> Result<CreateLocationS3Response, RusotoError<CreateLocationS3Error>> {
+        self.create_location_s3_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Defines a file system on an Server Message Block (SMB) server that can be read from or written to.</p>
+    async fn create_location_smb(
+        &self,
+        input: CreateLocationSmbRequest,
+    ) -> Result<CreateLocationSmbResponse, RusotoError<CreateLocationSmbError>> {
+        self.create_location_smb_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Creates a task. A task is a set of two locations (source and destination) and a set of Options that you use to control the behavior of a task. If you don't specify Options when you create a task, AWS DataSync populates them with service defaults.</p> <p>When you create a task, it first enters the CREATING state. During CREATING AWS DataSync attempts to mount the on-premises Network File System (NFS) location. The task transitions to the AVAILABLE state without waiting for the AWS location to become mounted. If required, AWS DataSync mounts the AWS location before each task execution.</p> <p>If an agent that is associated with a source (NFS) location goes offline, the task transitions to the UNAVAILABLE status. If the status of the task remains in the CREATING status for more than a few minutes, it means that your agent might be having trouble mounting the source NFS file system. Check the task's ErrorCode and ErrorDetail. Mount issues are often caused by either a misconfigured firewall or a mistyped NFS server host name.</p>
+    async fn create_task(
+        &self,
+        input: CreateTaskRequest,
+    ) -> Result<CreateTaskResponse, RusotoError<CreateTaskError>> {
+        self.create_task_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes an agent. To specify which agent to delete, use the Amazon Resource Name (ARN) of the agent in your request. The operation disassociates the agent from your AWS account. However, it doesn't delete the agent virtual machine (VM) from your on-premises environment.</p>
+    async fn delete_agent(
+        &self,
+        input: DeleteAgentRequest,
+    ) -> Result<DeleteAgentResponse, RusotoError<DeleteAgentError>> {
+        self.delete_agent_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes the configuration of a location used by AWS DataSync. </p>
+    async fn delete_location(
+        &self,
+        input: DeleteLocationRequest,
+    ) -> Result<DeleteLocationResponse, RusotoError<DeleteLocationError>> {
+        self.delete_location_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes a task.</p>
+    async fn delete_task(
+        &self,
+        input: DeleteTaskRequest,
+    ) -> Result<DeleteTaskResponse, RusotoError<DeleteTaskError>> {
+        self.delete_task_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns metadata such as the name, the network interfaces, and the status (that is, whether the agent is running or not) for an agent. To specify which agent to describe, use the Amazon Resource Name (ARN) of the agent in your request. </p>
+    async fn describe_agent(
+        &self,
+        input: DescribeAgentRequest,
+    ) -> Result<DescribeAgentResponse, RusotoError<DescribeAgentError>> {
+        self.describe_agent_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns metadata, such as the path information about an Amazon EFS location.</p>
+    async fn describe_location_efs(
+        &self,
+        input: DescribeLocationEfsRequest,
+    ) -> Result<DescribeLocationEfsResponse, RusotoError<DescribeLocationEfsError>> {
+        self.describe_location_efs_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns metadata, such as the path information about an Amazon FSx for Windows location.</p>
+    async fn describe_location_fsx_windows(
+        &self,
+        input: DescribeLocationFsxWindowsRequest,
+    ) -> Result<DescribeLocationFsxWindowsResponse, RusotoError<DescribeLocationFsxWindowsError>>
+    {
+        self.describe_location_fsx_windows_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns metadata, such as the path information, about a NFS location.</p>
+    async fn describe_location_nfs(
+        &self,
+        input: DescribeLocationNfsRequest,
+    ) -> Result<DescribeLocationNfsResponse, RusotoError<DescribeLocationNfsError>> {
+        self.describe_location_nfs_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns metadata, such as bucket name, about an Amazon S3 bucket location.</p>
+    async fn describe_location_s3(
+        &self,
+        input: DescribeLocationS3Request,
+    ) -> Result<DescribeLocationS3Response, RusotoError<DescribeLocationS3Error>> {
+        self.describe_location_s3_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns metadata, such as the path and user information about a SMB location.</p>
+    async fn describe_location_smb(
+        &self,
+        input: DescribeLocationSmbRequest,
+    ) -> Result<DescribeLocationSmbResponse, RusotoError<DescribeLocationSmbError>> {
+        self.describe_location_smb_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns metadata about a task.</p>
+    async fn describe_task(
+        &self,
+        input: DescribeTaskRequest,
+    ) -> Result<DescribeTaskResponse, RusotoError<DescribeTaskError>> {
+        self.describe_task_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns detailed metadata about a task that is being executed.</p>
+    async fn describe_task_execution(
+        &self,
+        input: DescribeTaskExecutionRequest,
+    ) -> Result<DescribeTaskExecutionResponse, RusotoError<DescribeTaskExecutionError>> {
+        self.describe_task_execution_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns a list of agents owned by an AWS account in the AWS Region specified in the request. The returned list is ordered by agent Amazon Resource Name (ARN).</p> <p>By default, this operation returns a maximum of 100 agents. This operation supports pagination that enables you to optionally reduce the number of agents returned in a response.</p> <p>If you have more agents than are returned in a response (that is, the response returns only a truncated list of your agents), the response contains a marker that you can specify in your next request to fetch the next page of agents.</p>
+    async fn list_agents(
+        &self,
+        input: ListAgentsRequest,
+    ) -> Result<ListAgentsResponse, RusotoError<ListAgentsError>> {
+        self.list_agents_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns a lists of source and destination locations.</p> <p>If you have more locations than are returned in a response (that is, the response returns only a truncated list of your agents), the response contains a token that you can specify in your next request to fetch the next page of locations.</p>
+    async fn list_locations(
+        &self,
+        input: ListLocationsRequest,
+    ) -> Result<ListLocationsResponse, RusotoError<ListLocationsError>> {
+        self.list_locations_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns all the tags associated with a specified resources. </p>
+    async fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> Result<ListTagsForResourceResponse, RusotoError<ListTagsForResourceError>> {
+        self.list_tags_for_resource_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns a list of executed tasks.</p>
+    async fn list_task_executions(
+        &self,
+        input: ListTaskExecutionsRequest,
+    ) -> Result<ListTaskExecutionsResponse, RusotoError<ListTaskExecutionsError>> {
+        self.list_task_executions_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns a list of all the tasks.</p>
+    async fn list_tasks(
+        &self,
+        input: ListTasksRequest,
+    ) -> Result<ListTasksResponse, RusotoError<ListTasksError>> {
+        self.list_tasks_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Starts a specific invocation of a task. A <code>TaskExecution</code> value represents an individual run of a task. Each task can have at most one <code>TaskExecution</code> at a time.</p> <p> <code>TaskExecution</code> has the following transition phases: INITIALIZING | PREPARING | TRANSFERRING | VERIFYING | SUCCESS/FAILURE. </p> <p>For detailed information, see the Task Execution section in the Components and Terminology topic in the <i>AWS DataSync User Guide</i>.</p>
+    async fn start_task_execution(
+        &self,
+        input: StartTaskExecutionRequest,
+    ) -> Result<StartTaskExecutionResponse, RusotoError<StartTaskExecutionError>> {
+        self.start_task_execution_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Applies a key-value pair to an AWS resource.</p>
+    async fn tag_resource(
+        &self,
+        input: TagResourceRequest,
+    ) -> Result<TagResourceResponse, RusotoError<TagResourceError>> {
+        self.tag_resource_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Removes a tag from an AWS resource.</p>
+    async fn untag_resource(
+        &self,
+        input: UntagResourceRequest,
+    ) -> Result<UntagResourceResponse, RusotoError<UntagResourceError>> {
+        self.untag_resource_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Updates the name of an agent.</p>
+    async fn update_agent(
+        &self,
+        input: UpdateAgentRequest,
+    ) -> Result<UpdateAgentResponse, RusotoError<UpdateAgentError>> {
+        self.update_agent_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Updates the metadata associated with a task.</p>
+    async fn update_task(
+        &self,
+        input: UpdateTaskRequest,
+    ) -> Result<UpdateTaskResponse, RusotoError<UpdateTaskError>> {
+        self.update_task_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
     }
 }
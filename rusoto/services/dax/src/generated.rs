@@ -16,8 +16,8 @@ use std::fmt;
 use async_trait::async_trait;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
-use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest, ResponseMetadata};
+use rusoto_core::{Client, ErrorMetadata, RusotoError};
 
 use rusoto_core::proto;
 use rusoto_core::signature::SignedRequest;
@@ -944,6 +944,7 @@ pub struct UpdateSubnetGroupResponse {
 
 /// Errors returned by CreateCluster
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateClusterError {
     /// <p>You already have a DAX cluster with the given identifier.</p>
     ClusterAlreadyExistsFault(String),
@@ -973,6 +974,8 @@ pub enum CreateClusterError {
     SubnetGroupNotFoundFault(String),
     /// <p>You have exceeded the maximum number of tags for this DAX cluster.</p>
     TagQuotaPerResourceExceeded(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateClusterError {
@@ -980,75 +983,91 @@ impl CreateClusterError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ClusterAlreadyExistsFault" => {
-                    return RusotoError::Service(CreateClusterError::ClusterAlreadyExistsFault(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateClusterError::ClusterAlreadyExistsFault(err.msg),
+                        &res,
+                    )
                 }
                 "ClusterQuotaForCustomerExceededFault" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         CreateClusterError::ClusterQuotaForCustomerExceededFault(err.msg),
+                        &res,
                     )
                 }
                 "InsufficientClusterCapacityFault" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         CreateClusterError::InsufficientClusterCapacityFault(err.msg),
+                        &res,
                     )
                 }
                 "InvalidClusterStateFault" => {
-                    return RusotoError::Service(CreateClusterError::InvalidClusterStateFault(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateClusterError::InvalidClusterStateFault(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidParameterCombinationException" => {
-                    return RusotoError::Service(CreateClusterError::InvalidParameterCombination(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateClusterError::InvalidParameterCombination(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidParameterGroupStateFault" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         CreateClusterError::InvalidParameterGroupStateFault(err.msg),
+                        &res,
                     )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(CreateClusterError::InvalidParameterValue(err.msg))
+                    return RusotoError::service(
+                        CreateClusterError::InvalidParameterValue(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidVPCNetworkStateFault" => {
-                    return RusotoError::Service(CreateClusterError::InvalidVPCNetworkStateFault(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateClusterError::InvalidVPCNetworkStateFault(err.msg),
+                        &res,
+                    )
                 }
                 "NodeQuotaForClusterExceededFault" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         CreateClusterError::NodeQuotaForClusterExceededFault(err.msg),
+                        &res,
                     )
                 }
                 "NodeQuotaForCustomerExceededFault" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         CreateClusterError::NodeQuotaForCustomerExceededFault(err.msg),
+                        &res,
                     )
                 }
                 "ParameterGroupNotFoundFault" => {
-                    return RusotoError::Service(CreateClusterError::ParameterGroupNotFoundFault(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateClusterError::ParameterGroupNotFoundFault(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceLinkedRoleNotFoundFault" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         CreateClusterError::ServiceLinkedRoleNotFoundFault(err.msg),
+                        &res,
                     )
                 }
                 "SubnetGroupNotFoundFault" => {
-                    return RusotoError::Service(CreateClusterError::SubnetGroupNotFoundFault(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateClusterError::SubnetGroupNotFoundFault(err.msg),
+                        &res,
+                    )
                 }
                 "TagQuotaPerResourceExceeded" => {
-                    return RusotoError::Service(CreateClusterError::TagQuotaPerResourceExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateClusterError::TagQuotaPerResourceExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreateClusterError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1082,12 +1101,14 @@ impl fmt::Display for CreateClusterError {
             CreateClusterError::ServiceLinkedRoleNotFoundFault(ref cause) => write!(f, "{}", cause),
             CreateClusterError::SubnetGroupNotFoundFault(ref cause) => write!(f, "{}", cause),
             CreateClusterError::TagQuotaPerResourceExceeded(ref cause) => write!(f, "{}", cause),
+            CreateClusterError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateClusterError {}
 /// Errors returned by CreateParameterGroup
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateParameterGroupError {
     /// <p>Two or more incompatible parameters were specified.</p>
     InvalidParameterCombination(String),
@@ -1101,6 +1122,8 @@ pub enum CreateParameterGroupError {
     ParameterGroupQuotaExceededFault(String),
     /// <p>The specified service linked role (SLR) was not found.</p>
     ServiceLinkedRoleNotFoundFault(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateParameterGroupError {
@@ -1108,37 +1131,43 @@ impl CreateParameterGroupError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParameterCombinationException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         CreateParameterGroupError::InvalidParameterCombination(err.msg),
+                        &res,
                     )
                 }
                 "InvalidParameterGroupStateFault" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         CreateParameterGroupError::InvalidParameterGroupStateFault(err.msg),
+                        &res,
                     )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(CreateParameterGroupError::InvalidParameterValue(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateParameterGroupError::InvalidParameterValue(err.msg),
+                        &res,
+                    )
                 }
                 "ParameterGroupAlreadyExistsFault" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         CreateParameterGroupError::ParameterGroupAlreadyExistsFault(err.msg),
+                        &res,
                     )
                 }
                 "ParameterGroupQuotaExceededFault" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         CreateParameterGroupError::ParameterGroupQuotaExceededFault(err.msg),
+                        &res,
                     )
                 }
                 "ServiceLinkedRoleNotFoundFault" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         CreateParameterGroupError::ServiceLinkedRoleNotFoundFault(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreateParameterGroupError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1164,12 +1193,14 @@ impl fmt::Display for CreateParameterGroupError {
             CreateParameterGroupError::ServiceLinkedRoleNotFoundFault(ref cause) => {
                 write!(f, "{}", cause)
             }
+            CreateParameterGroupError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateParameterGroupError {}
 /// Errors returned by CreateSubnetGroup
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateSubnetGroupError {
     /// <p>An invalid subnet identifier was specified.</p>
     InvalidSubnet(String),
@@ -1181,6 +1212,8 @@ pub enum CreateSubnetGroupError {
     SubnetGroupQuotaExceededFault(String),
     /// <p>The request cannot be processed because it would exceed the allowed number of subnets in a subnet group.</p>
     SubnetQuotaExceededFault(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateSubnetGroupError {
@@ -1188,30 +1221,37 @@ impl CreateSubnetGroupError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidSubnet" => {
-                    return RusotoError::Service(CreateSubnetGroupError::InvalidSubnet(err.msg))
+                    return RusotoError::service(
+                        CreateSubnetGroupError::InvalidSubnet(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceLinkedRoleNotFoundFault" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         CreateSubnetGroupError::ServiceLinkedRoleNotFoundFault(err.msg),
+                        &res,
                     )
                 }
                 "SubnetGroupAlreadyExistsFault" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         CreateSubnetGroupError::SubnetGroupAlreadyExistsFault(err.msg),
+                        &res,
                     )
                 }
                 "SubnetGroupQuotaExceededFault" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         CreateSubnetGroupError::SubnetGroupQuotaExceededFault(err.msg),
+                        &res,
                     )
                 }
                 "SubnetQuotaExceededFault" => {
-                    return RusotoError::Service(CreateSubnetGroupError::SubnetQuotaExceededFault(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateSubnetGroupError::SubnetQuotaExceededFault(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreateSubnetGroupError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1232,12 +1272,14 @@ impl fmt::Display for CreateSubnetGroupError {
                 write!(f, "{}", cause)
             }
             CreateSubnetGroupError::SubnetQuotaExceededFault(ref cause) => write!(f, "{}", cause),
+            CreateSubnetGroupError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateSubnetGroupError {}
 /// Errors returned by DecreaseReplicationFactor
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DecreaseReplicationFactorError {
     /// <p>The requested cluster ID does not refer to an existing DAX cluster.</p>
     ClusterNotFoundFault(String),
@@ -1251,6 +1293,8 @@ pub enum DecreaseReplicationFactorError {
     NodeNotFoundFault(String),
     /// <p>The specified service linked role (SLR) was not found.</p>
     ServiceLinkedRoleNotFoundFault(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DecreaseReplicationFactorError {
@@ -1258,37 +1302,45 @@ impl DecreaseReplicationFactorError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ClusterNotFoundFault" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DecreaseReplicationFactorError::ClusterNotFoundFault(err.msg),
+                        &res,
                     )
                 }
                 "InvalidClusterStateFault" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DecreaseReplicationFactorError::InvalidClusterStateFault(err.msg),
+                        &res,
                     )
                 }
                 "InvalidParameterCombinationException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DecreaseReplicationFactorError::InvalidParameterCombination(err.msg),
+                        &res,
                     )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DecreaseReplicationFactorError::InvalidParameterValue(err.msg),
+                        &res,
                     )
                 }
                 "NodeNotFoundFault" => {
-                    return RusotoError::Service(DecreaseReplicationFactorError::NodeNotFoundFault(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DecreaseReplicationFactorError::NodeNotFoundFault(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceLinkedRoleNotFoundFault" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DecreaseReplicationFactorError::ServiceLinkedRoleNotFoundFault(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(DecreaseReplicationFactorError::Unhandled, &res)
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -1314,12 +1366,14 @@ impl fmt::Display for DecreaseReplicationFactorError {
             DecreaseReplicationFactorError::ServiceLinkedRoleNotFoundFault(ref cause) => {
                 write!(f, "{}", cause)
             }
+            DecreaseReplicationFactorError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DecreaseReplicationFactorError {}
 /// Errors returned by DeleteCluster
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteClusterError {
     /// <p>The requested cluster ID does not refer to an existing DAX cluster.</p>
     ClusterNotFoundFault(String),
@@ -1331,6 +1385,8 @@ pub enum DeleteClusterError {
     InvalidParameterValue(String),
     /// <p>The specified service linked role (SLR) was not found.</p>
     ServiceLinkedRoleNotFoundFault(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteClusterError {
@@ -1338,28 +1394,37 @@ impl DeleteClusterError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ClusterNotFoundFault" => {
-                    return RusotoError::Service(DeleteClusterError::ClusterNotFoundFault(err.msg))
+                    return RusotoError::service(
+                        DeleteClusterError::ClusterNotFoundFault(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidClusterStateFault" => {
-                    return RusotoError::Service(DeleteClusterError::InvalidClusterStateFault(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteClusterError::InvalidClusterStateFault(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidParameterCombinationException" => {
-                    return RusotoError::Service(DeleteClusterError::InvalidParameterCombination(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteClusterError::InvalidParameterCombination(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(DeleteClusterError::InvalidParameterValue(err.msg))
+                    return RusotoError::service(
+                        DeleteClusterError::InvalidParameterValue(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceLinkedRoleNotFoundFault" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DeleteClusterError::ServiceLinkedRoleNotFoundFault(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteClusterError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1374,12 +1439,14 @@ impl fmt::Display for DeleteClusterError {
             DeleteClusterError::InvalidParameterCombination(ref cause) => write!(f, "{}", cause),
             DeleteClusterError::InvalidParameterValue(ref cause) => write!(f, "{}", cause),
             DeleteClusterError::ServiceLinkedRoleNotFoundFault(ref cause) => write!(f, "{}", cause),
+            DeleteClusterError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteClusterError {}
 /// Errors returned by DeleteParameterGroup
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteParameterGroupError {
     /// <p>Two or more incompatible parameters were specified.</p>
     InvalidParameterCombination(String),
@@ -1391,6 +1458,8 @@ pub enum DeleteParameterGroupError {
     ParameterGroupNotFoundFault(String),
     /// <p>The specified service linked role (SLR) was not found.</p>
     ServiceLinkedRoleNotFoundFault(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteParameterGroupError {
@@ -1398,32 +1467,37 @@ impl DeleteParameterGroupError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParameterCombinationException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DeleteParameterGroupError::InvalidParameterCombination(err.msg),
+                        &res,
                     )
                 }
                 "InvalidParameterGroupStateFault" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DeleteParameterGroupError::InvalidParameterGroupStateFault(err.msg),
+                        &res,
                     )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(DeleteParameterGroupError::InvalidParameterValue(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteParameterGroupError::InvalidParameterValue(err.msg),
+                        &res,
+                    )
                 }
                 "ParameterGroupNotFoundFault" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DeleteParameterGroupError::ParameterGroupNotFoundFault(err.msg),
+                        &res,
                     )
                 }
                 "ServiceLinkedRoleNotFoundFault" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DeleteParameterGroupError::ServiceLinkedRoleNotFoundFault(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteParameterGroupError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1446,12 +1520,14 @@ impl fmt::Display for DeleteParameterGroupError {
             DeleteParameterGroupError::ServiceLinkedRoleNotFoundFault(ref cause) => {
                 write!(f, "{}", cause)
             }
+            DeleteParameterGroupError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteParameterGroupError {}
 /// Errors returned by DeleteSubnetGroup
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteSubnetGroupError {
     /// <p>The specified service linked role (SLR) was not found.</p>
     ServiceLinkedRoleNotFoundFault(String),
@@ -1459,6 +1535,8 @@ pub enum DeleteSubnetGroupError {
     SubnetGroupInUseFault(String),
     /// <p>The requested subnet group name does not refer to an existing subnet group.</p>
     SubnetGroupNotFoundFault(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteSubnetGroupError {
@@ -1466,22 +1544,25 @@ impl DeleteSubnetGroupError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ServiceLinkedRoleNotFoundFault" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DeleteSubnetGroupError::ServiceLinkedRoleNotFoundFault(err.msg),
+                        &res,
                     )
                 }
                 "SubnetGroupInUseFault" => {
-                    return RusotoError::Service(DeleteSubnetGroupError::SubnetGroupInUseFault(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteSubnetGroupError::SubnetGroupInUseFault(err.msg),
+                        &res,
+                    )
                 }
                 "SubnetGroupNotFoundFault" => {
-                    return RusotoError::Service(DeleteSubnetGroupError::SubnetGroupNotFoundFault(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteSubnetGroupError::SubnetGroupNotFoundFault(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteSubnetGroupError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1496,12 +1577,14 @@ impl fmt::Display for DeleteSubnetGroupError {
             }
             DeleteSubnetGroupError::SubnetGroupInUseFault(ref cause) => write!(f, "{}", cause),
             DeleteSubnetGroupError::SubnetGroupNotFoundFault(ref cause) => write!(f, "{}", cause),
+            DeleteSubnetGroupError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteSubnetGroupError {}
 /// Errors returned by DescribeClusters
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeClustersError {
     /// <p>The requested cluster ID does not refer to an existing DAX cluster.</p>
     ClusterNotFoundFault(String),
@@ -1511,6 +1594,8 @@ pub enum DescribeClustersError {
     InvalidParameterValue(String),
     /// <p>The specified service linked role (SLR) was not found.</p>
     ServiceLinkedRoleNotFoundFault(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeClustersError {
@@ -1518,27 +1603,31 @@ impl DescribeClustersError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ClusterNotFoundFault" => {
-                    return RusotoError::Service(DescribeClustersError::ClusterNotFoundFault(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeClustersError::ClusterNotFoundFault(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidParameterCombinationException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DescribeClustersError::InvalidParameterCombination(err.msg),
+                        &res,
                     )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(DescribeClustersError::InvalidParameterValue(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeClustersError::InvalidParameterValue(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceLinkedRoleNotFoundFault" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DescribeClustersError::ServiceLinkedRoleNotFoundFault(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DescribeClustersError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1554,12 +1643,14 @@ impl fmt::Display for DescribeClustersError {
             DescribeClustersError::ServiceLinkedRoleNotFoundFault(ref cause) => {
                 write!(f, "{}", cause)
             }
+            DescribeClustersError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeClustersError {}
 /// Errors returned by DescribeDefaultParameters
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeDefaultParametersError {
     /// <p>Two or more incompatible parameters were specified.</p>
     InvalidParameterCombination(String),
@@ -1567,6 +1658,8 @@ pub enum DescribeDefaultParametersError {
     InvalidParameterValue(String),
     /// <p>The specified service linked role (SLR) was not found.</p>
     ServiceLinkedRoleNotFoundFault(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeDefaultParametersError {
@@ -1574,22 +1667,27 @@ impl DescribeDefaultParametersError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParameterCombinationException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DescribeDefaultParametersError::InvalidParameterCombination(err.msg),
+                        &res,
                     )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DescribeDefaultParametersError::InvalidParameterValue(err.msg),
+                        &res,
                     )
                 }
                 "ServiceLinkedRoleNotFoundFault" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DescribeDefaultParametersError::ServiceLinkedRoleNotFoundFault(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(DescribeDefaultParametersError::Unhandled, &res)
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -1608,12 +1706,14 @@ impl fmt::Display for DescribeDefaultParametersError {
             DescribeDefaultParametersError::ServiceLinkedRoleNotFoundFault(ref cause) => {
                 write!(f, "{}", cause)
             }
+            DescribeDefaultParametersError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeDefaultParametersError {}
 /// Errors returned by DescribeEvents
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeEventsError {
     /// <p>Two or more incompatible parameters were specified.</p>
     InvalidParameterCombination(String),
@@ -1621,6 +1721,8 @@ pub enum DescribeEventsError {
     InvalidParameterValue(String),
     /// <p>The specified service linked role (SLR) was not found.</p>
     ServiceLinkedRoleNotFoundFault(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeEventsError {
@@ -1628,22 +1730,25 @@ impl DescribeEventsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParameterCombinationException" => {
-                    return RusotoError::Service(DescribeEventsError::InvalidParameterCombination(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeEventsError::InvalidParameterCombination(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(DescribeEventsError::InvalidParameterValue(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeEventsError::InvalidParameterValue(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceLinkedRoleNotFoundFault" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DescribeEventsError::ServiceLinkedRoleNotFoundFault(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DescribeEventsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1658,12 +1763,14 @@ impl fmt::Display for DescribeEventsError {
             DescribeEventsError::ServiceLinkedRoleNotFoundFault(ref cause) => {
                 write!(f, "{}", cause)
             }
+            DescribeEventsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeEventsError {}
 /// Errors returned by DescribeParameterGroups
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeParameterGroupsError {
     /// <p>Two or more incompatible parameters were specified.</p>
     InvalidParameterCombination(String),
@@ -1673,6 +1780,8 @@ pub enum DescribeParameterGroupsError {
     ParameterGroupNotFoundFault(String),
     /// <p>The specified service linked role (SLR) was not found.</p>
     ServiceLinkedRoleNotFoundFault(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeParameterGroupsError {
@@ -1680,27 +1789,31 @@ impl DescribeParameterGroupsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParameterCombinationException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DescribeParameterGroupsError::InvalidParameterCombination(err.msg),
+                        &res,
                     )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DescribeParameterGroupsError::InvalidParameterValue(err.msg),
+                        &res,
                     )
                 }
                 "ParameterGroupNotFoundFault" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DescribeParameterGroupsError::ParameterGroupNotFoundFault(err.msg),
+                        &res,
                     )
                 }
                 "ServiceLinkedRoleNotFoundFault" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DescribeParameterGroupsError::ServiceLinkedRoleNotFoundFault(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DescribeParameterGroupsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1722,12 +1835,14 @@ impl fmt::Display for DescribeParameterGroupsError {
             DescribeParameterGroupsError::ServiceLinkedRoleNotFoundFault(ref cause) => {
                 write!(f, "{}", cause)
             }
+            DescribeParameterGroupsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeParameterGroupsError {}
 /// Errors returned by DescribeParameters
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeParametersError {
     /// <p>Two or more incompatible parameters were specified.</p>
     InvalidParameterCombination(String),
@@ -1737,6 +1852,8 @@ pub enum DescribeParametersError {
     ParameterGroupNotFoundFault(String),
     /// <p>The specified service linked role (SLR) was not found.</p>
     ServiceLinkedRoleNotFoundFault(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeParametersError {
@@ -1744,27 +1861,31 @@ impl DescribeParametersError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParameterCombinationException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DescribeParametersError::InvalidParameterCombination(err.msg),
+                        &res,
                     )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(DescribeParametersError::InvalidParameterValue(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeParametersError::InvalidParameterValue(err.msg),
+                        &res,
+                    )
                 }
                 "ParameterGroupNotFoundFault" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DescribeParametersError::ParameterGroupNotFoundFault(err.msg),
+                        &res,
                     )
                 }
                 "ServiceLinkedRoleNotFoundFault" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DescribeParametersError::ServiceLinkedRoleNotFoundFault(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DescribeParametersError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1784,17 +1905,21 @@ impl fmt::Display for DescribeParametersError {
             DescribeParametersError::ServiceLinkedRoleNotFoundFault(ref cause) => {
                 write!(f, "{}", cause)
             }
+            DescribeParametersError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeParametersError {}
 /// Errors returned by DescribeSubnetGroups
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeSubnetGroupsError {
     /// <p>The specified service linked role (SLR) was not found.</p>
     ServiceLinkedRoleNotFoundFault(String),
     /// <p>The requested subnet group name does not refer to an existing subnet group.</p>
     SubnetGroupNotFoundFault(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeSubnetGroupsError {
@@ -1802,17 +1927,19 @@ impl DescribeSubnetGroupsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ServiceLinkedRoleNotFoundFault" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DescribeSubnetGroupsError::ServiceLinkedRoleNotFoundFault(err.msg),
+                        &res,
                     )
                 }
                 "SubnetGroupNotFoundFault" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DescribeSubnetGroupsError::SubnetGroupNotFoundFault(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DescribeSubnetGroupsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1828,12 +1955,14 @@ impl fmt::Display for DescribeSubnetGroupsError {
             DescribeSubnetGroupsError::SubnetGroupNotFoundFault(ref cause) => {
                 write!(f, "{}", cause)
             }
+            DescribeSubnetGroupsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeSubnetGroupsError {}
 /// Errors returned by IncreaseReplicationFactor
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum IncreaseReplicationFactorError {
     /// <p>The requested cluster ID does not refer to an existing DAX cluster.</p>
     ClusterNotFoundFault(String),
@@ -1853,6 +1982,8 @@ pub enum IncreaseReplicationFactorError {
     NodeQuotaForCustomerExceededFault(String),
     /// <p>The specified service linked role (SLR) was not found.</p>
     ServiceLinkedRoleNotFoundFault(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl IncreaseReplicationFactorError {
@@ -1860,52 +1991,63 @@ impl IncreaseReplicationFactorError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ClusterNotFoundFault" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         IncreaseReplicationFactorError::ClusterNotFoundFault(err.msg),
+                        &res,
                     )
                 }
                 "InsufficientClusterCapacityFault" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         IncreaseReplicationFactorError::InsufficientClusterCapacityFault(err.msg),
+                        &res,
                     )
                 }
                 "InvalidClusterStateFault" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         IncreaseReplicationFactorError::InvalidClusterStateFault(err.msg),
+                        &res,
                     )
                 }
                 "InvalidParameterCombinationException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         IncreaseReplicationFactorError::InvalidParameterCombination(err.msg),
+                        &res,
                     )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         IncreaseReplicationFactorError::InvalidParameterValue(err.msg),
+                        &res,
                     )
                 }
                 "InvalidVPCNetworkStateFault" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         IncreaseReplicationFactorError::InvalidVPCNetworkStateFault(err.msg),
+                        &res,
                     )
                 }
                 "NodeQuotaForClusterExceededFault" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         IncreaseReplicationFactorError::NodeQuotaForClusterExceededFault(err.msg),
+                        &res,
                     )
                 }
                 "NodeQuotaForCustomerExceededFault" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         IncreaseReplicationFactorError::NodeQuotaForCustomerExceededFault(err.msg),
+                        &res,
                     )
                 }
                 "ServiceLinkedRoleNotFoundFault" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         IncreaseReplicationFactorError::ServiceLinkedRoleNotFoundFault(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(IncreaseReplicationFactorError::Unhandled, &res)
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -1942,12 +2084,14 @@ impl fmt::Display for IncreaseReplicationFactorError {
             IncreaseReplicationFactorError::ServiceLinkedRoleNotFoundFault(ref cause) => {
                 write!(f, "{}", cause)
             }
+            IncreaseReplicationFactorError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for IncreaseReplicationFactorError {}
 /// Errors returned by ListTags
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListTagsError {
     /// <p>The requested cluster ID does not refer to an existing DAX cluster.</p>
     ClusterNotFoundFault(String),
@@ -1961,6 +2105,8 @@ pub enum ListTagsError {
     InvalidParameterValue(String),
     /// <p>The specified service linked role (SLR) was not found.</p>
     ServiceLinkedRoleNotFoundFault(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListTagsError {
@@ -1968,29 +2114,37 @@ impl ListTagsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ClusterNotFoundFault" => {
-                    return RusotoError::Service(ListTagsError::ClusterNotFoundFault(err.msg))
+                    return RusotoError::service(ListTagsError::ClusterNotFoundFault(err.msg), &res)
                 }
                 "InvalidARNFault" => {
-                    return RusotoError::Service(ListTagsError::InvalidARNFault(err.msg))
+                    return RusotoError::service(ListTagsError::InvalidARNFault(err.msg), &res)
                 }
                 "InvalidClusterStateFault" => {
-                    return RusotoError::Service(ListTagsError::InvalidClusterStateFault(err.msg))
+                    return RusotoError::service(
+                        ListTagsError::InvalidClusterStateFault(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidParameterCombinationException" => {
-                    return RusotoError::Service(ListTagsError::InvalidParameterCombination(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListTagsError::InvalidParameterCombination(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(ListTagsError::InvalidParameterValue(err.msg))
+                    return RusotoError::service(
+                        ListTagsError::InvalidParameterValue(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceLinkedRoleNotFoundFault" => {
-                    return RusotoError::Service(ListTagsError::ServiceLinkedRoleNotFoundFault(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListTagsError::ServiceLinkedRoleNotFoundFault(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListTagsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2006,12 +2160,14 @@ impl fmt::Display for ListTagsError {
             ListTagsError::InvalidParameterCombination(ref cause) => write!(f, "{}", cause),
             ListTagsError::InvalidParameterValue(ref cause) => write!(f, "{}", cause),
             ListTagsError::ServiceLinkedRoleNotFoundFault(ref cause) => write!(f, "{}", cause),
+            ListTagsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListTagsError {}
 /// Errors returned by RebootNode
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum RebootNodeError {
     /// <p>The requested cluster ID does not refer to an existing DAX cluster.</p>
     ClusterNotFoundFault(String),
@@ -2025,6 +2181,8 @@ pub enum RebootNodeError {
     NodeNotFoundFault(String),
     /// <p>The specified service linked role (SLR) was not found.</p>
     ServiceLinkedRoleNotFoundFault(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl RebootNodeError {
@@ -2032,29 +2190,40 @@ impl RebootNodeError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ClusterNotFoundFault" => {
-                    return RusotoError::Service(RebootNodeError::ClusterNotFoundFault(err.msg))
+                    return RusotoError::service(
+                        RebootNodeError::ClusterNotFoundFault(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidClusterStateFault" => {
-                    return RusotoError::Service(RebootNodeError::InvalidClusterStateFault(err.msg))
+                    return RusotoError::service(
+                        RebootNodeError::InvalidClusterStateFault(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidParameterCombinationException" => {
-                    return RusotoError::Service(RebootNodeError::InvalidParameterCombination(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        RebootNodeError::InvalidParameterCombination(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(RebootNodeError::InvalidParameterValue(err.msg))
+                    return RusotoError::service(
+                        RebootNodeError::InvalidParameterValue(err.msg),
+                        &res,
+                    )
                 }
                 "NodeNotFoundFault" => {
-                    return RusotoError::Service(RebootNodeError::NodeNotFoundFault(err.msg))
+                    return RusotoError::service(RebootNodeError::NodeNotFoundFault(err.msg), &res)
                 }
                 "ServiceLinkedRoleNotFoundFault" => {
-                    return RusotoError::Service(RebootNodeError::ServiceLinkedRoleNotFoundFault(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        RebootNodeError::ServiceLinkedRoleNotFoundFault(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(RebootNodeError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2070,12 +2239,14 @@ impl fmt::Display for RebootNodeError {
             RebootNodeError::InvalidParameterValue(ref cause) => write!(f, "{}", cause),
             RebootNodeError::NodeNotFoundFault(ref cause) => write!(f, "{}", cause),
             RebootNodeError::ServiceLinkedRoleNotFoundFault(ref cause) => write!(f, "{}", cause),
+            RebootNodeError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for RebootNodeError {}
 /// Errors returned by TagResource
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum TagResourceError {
     /// <p>The requested cluster ID does not refer to an existing DAX cluster.</p>
     ClusterNotFoundFault(String),
@@ -2091,6 +2262,8 @@ pub enum TagResourceError {
     ServiceLinkedRoleNotFoundFault(String),
     /// <p>You have exceeded the maximum number of tags for this DAX cluster.</p>
     TagQuotaPerResourceExceeded(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl TagResourceError {
@@ -2098,36 +2271,46 @@ impl TagResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ClusterNotFoundFault" => {
-                    return RusotoError::Service(TagResourceError::ClusterNotFoundFault(err.msg))
+                    return RusotoError::service(
+                        TagResourceError::ClusterNotFoundFault(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidARNFault" => {
-                    return RusotoError::Service(TagResourceError::InvalidARNFault(err.msg))
+                    return RusotoError::service(TagResourceError::InvalidARNFault(err.msg), &res)
                 }
                 "InvalidClusterStateFault" => {
-                    return RusotoError::Service(TagResourceError::InvalidClusterStateFault(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        TagResourceError::InvalidClusterStateFault(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidParameterCombinationException" => {
-                    return RusotoError::Service(TagResourceError::InvalidParameterCombination(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        TagResourceError::InvalidParameterCombination(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(TagResourceError::InvalidParameterValue(err.msg))
+                    return RusotoError::service(
+                        TagResourceError::InvalidParameterValue(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceLinkedRoleNotFoundFault" => {
-                    return RusotoError::Service(TagResourceError::ServiceLinkedRoleNotFoundFault(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        TagResourceError::ServiceLinkedRoleNotFoundFault(err.msg),
+                        &res,
+                    )
                 }
                 "TagQuotaPerResourceExceeded" => {
-                    return RusotoError::Service(TagResourceError::TagQuotaPerResourceExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        TagResourceError::TagQuotaPerResourceExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(TagResourceError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2144,12 +2327,14 @@ impl fmt::Display for TagResourceError {
             TagResourceError::InvalidParameterValue(ref cause) => write!(f, "{}", cause),
             TagResourceError::ServiceLinkedRoleNotFoundFault(ref cause) => write!(f, "{}", cause),
             TagResourceError::TagQuotaPerResourceExceeded(ref cause) => write!(f, "{}", cause),
+            TagResourceError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for TagResourceError {}
 /// Errors returned by UntagResource
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UntagResourceError {
     /// <p>The requested cluster ID does not refer to an existing DAX cluster.</p>
     ClusterNotFoundFault(String),
@@ -2165,6 +2350,8 @@ pub enum UntagResourceError {
     ServiceLinkedRoleNotFoundFault(String),
     /// <p>The tag does not exist.</p>
     TagNotFoundFault(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UntagResourceError {
@@ -2172,34 +2359,46 @@ impl UntagResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ClusterNotFoundFault" => {
-                    return RusotoError::Service(UntagResourceError::ClusterNotFoundFault(err.msg))
+                    return RusotoError::service(
+                        UntagResourceError::ClusterNotFoundFault(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidARNFault" => {
-                    return RusotoError::Service(UntagResourceError::InvalidARNFault(err.msg))
+                    return RusotoError::service(UntagResourceError::InvalidARNFault(err.msg), &res)
                 }
                 "InvalidClusterStateFault" => {
-                    return RusotoError::Service(UntagResourceError::InvalidClusterStateFault(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UntagResourceError::InvalidClusterStateFault(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidParameterCombinationException" => {
-                    return RusotoError::Service(UntagResourceError::InvalidParameterCombination(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UntagResourceError::InvalidParameterCombination(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(UntagResourceError::InvalidParameterValue(err.msg))
+                    return RusotoError::service(
+                        UntagResourceError::InvalidParameterValue(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceLinkedRoleNotFoundFault" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         UntagResourceError::ServiceLinkedRoleNotFoundFault(err.msg),
+                        &res,
                     )
                 }
                 "TagNotFoundFault" => {
-                    return RusotoError::Service(UntagResourceError::TagNotFoundFault(err.msg))
+                    return RusotoError::service(
+                        UntagResourceError::TagNotFoundFault(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UntagResourceError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2216,12 +2415,14 @@ impl fmt::Display for UntagResourceError {
             UntagResourceError::InvalidParameterValue(ref cause) => write!(f, "{}", cause),
             UntagResourceError::ServiceLinkedRoleNotFoundFault(ref cause) => write!(f, "{}", cause),
             UntagResourceError::TagNotFoundFault(ref cause) => write!(f, "{}", cause),
+            UntagResourceError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for UntagResourceError {}
 /// Errors returned by UpdateCluster
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdateClusterError {
     /// <p>The requested cluster ID does not refer to an existing DAX cluster.</p>
     ClusterNotFoundFault(String),
@@ -2237,6 +2438,8 @@ pub enum UpdateClusterError {
     ParameterGroupNotFoundFault(String),
     /// <p>The specified service linked role (SLR) was not found.</p>
     ServiceLinkedRoleNotFoundFault(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdateClusterError {
@@ -2244,38 +2447,49 @@ impl UpdateClusterError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ClusterNotFoundFault" => {
-                    return RusotoError::Service(UpdateClusterError::ClusterNotFoundFault(err.msg))
+                    return RusotoError::service(
+                        UpdateClusterError::ClusterNotFoundFault(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidClusterStateFault" => {
-                    return RusotoError::Service(UpdateClusterError::InvalidClusterStateFault(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateClusterError::InvalidClusterStateFault(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidParameterCombinationException" => {
-                    return RusotoError::Service(UpdateClusterError::InvalidParameterCombination(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateClusterError::InvalidParameterCombination(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidParameterGroupStateFault" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         UpdateClusterError::InvalidParameterGroupStateFault(err.msg),
+                        &res,
                     )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(UpdateClusterError::InvalidParameterValue(err.msg))
+                    return RusotoError::service(
+                        UpdateClusterError::InvalidParameterValue(err.msg),
+                        &res,
+                    )
                 }
                 "ParameterGroupNotFoundFault" => {
-                    return RusotoError::Service(UpdateClusterError::ParameterGroupNotFoundFault(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateClusterError::ParameterGroupNotFoundFault(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceLinkedRoleNotFoundFault" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         UpdateClusterError::ServiceLinkedRoleNotFoundFault(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UpdateClusterError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2294,12 +2508,14 @@ impl fmt::Display for UpdateClusterError {
             UpdateClusterError::InvalidParameterValue(ref cause) => write!(f, "{}", cause),
             UpdateClusterError::ParameterGroupNotFoundFault(ref cause) => write!(f, "{}", cause),
             UpdateClusterError::ServiceLinkedRoleNotFoundFault(ref cause) => write!(f, "{}", cause),
+            UpdateClusterError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for UpdateClusterError {}
 /// Errors returned by UpdateParameterGroup
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdateParameterGroupError {
     /// <p>Two or more incompatible parameters were specified.</p>
     InvalidParameterCombination(String),
@@ -2311,6 +2527,8 @@ pub enum UpdateParameterGroupError {
     ParameterGroupNotFoundFault(String),
     /// <p>The specified service linked role (SLR) was not found.</p>
     ServiceLinkedRoleNotFoundFault(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdateParameterGroupError {
@@ -2318,32 +2536,37 @@ impl UpdateParameterGroupError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParameterCombinationException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         UpdateParameterGroupError::InvalidParameterCombination(err.msg),
+                        &res,
                     )
                 }
                 "InvalidParameterGroupStateFault" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         UpdateParameterGroupError::InvalidParameterGroupStateFault(err.msg),
+                        &res,
                     )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(UpdateParameterGroupError::InvalidParameterValue(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateParameterGroupError::InvalidParameterValue(err.msg),
+                        &res,
+                    )
                 }
                 "ParameterGroupNotFoundFault" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         UpdateParameterGroupError::ParameterGroupNotFoundFault(err.msg),
+                        &res,
                     )
                 }
                 "ServiceLinkedRoleNotFoundFault" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         UpdateParameterGroupError::ServiceLinkedRoleNotFoundFault(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UpdateParameterGroupError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2366,12 +2589,14 @@ impl fmt::Display for UpdateParameterGroupError {
             UpdateParameterGroupError::ServiceLinkedRoleNotFoundFault(ref cause) => {
                 write!(f, "{}", cause)
             }
+            UpdateParameterGroupError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for UpdateParameterGroupError {}
 /// Errors returned by UpdateSubnetGroup
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdateSubnetGroupError {
     /// <p>An invalid subnet identifier was specified.</p>
     InvalidSubnet(String),
@@ -2383,6 +2608,8 @@ pub enum UpdateSubnetGroupError {
     SubnetInUse(String),
     /// <p>The request cannot be processed because it would exceed the allowed number of subnets in a subnet group.</p>
     SubnetQuotaExceededFault(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdateSubnetGroupError {
@@ -2390,28 +2617,34 @@ impl UpdateSubnetGroupError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidSubnet" => {
-                    return RusotoError::Service(UpdateSubnetGroupError::InvalidSubnet(err.msg))
+                    return RusotoError::service(
+                        UpdateSubnetGroupError::InvalidSubnet(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceLinkedRoleNotFoundFault" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         UpdateSubnetGroupError::ServiceLinkedRoleNotFoundFault(err.msg),
+                        &res,
                     )
                 }
                 "SubnetGroupNotFoundFault" => {
-                    return RusotoError::Service(UpdateSubnetGroupError::SubnetGroupNotFoundFault(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateSubnetGroupError::SubnetGroupNotFoundFault(err.msg),
+                        &res,
+                    )
                 }
                 "SubnetInUse" => {
-                    return RusotoError::Service(UpdateSubnetGroupError::SubnetInUse(err.msg))
+                    return RusotoError::service(UpdateSubnetGroupError::SubnetInUse(err.msg), &res)
                 }
                 "SubnetQuotaExceededFault" => {
-                    return RusotoError::Service(UpdateSubnetGroupError::SubnetQuotaExceededFault(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateSubnetGroupError::SubnetQuotaExceededFault(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UpdateSubnetGroupError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2428,6 +2661,7 @@ impl fmt::Display for UpdateSubnetGroupError {
             UpdateSubnetGroupError::SubnetGroupNotFoundFault(ref cause) => write!(f, "{}", cause),
             UpdateSubnetGroupError::SubnetInUse(ref cause) => write!(f, "{}", cause),
             UpdateSubnetGroupError::SubnetQuotaExceededFault(ref cause) => write!(f, "{}", cause),
+            UpdateSubnetGroupError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
@@ -2572,6 +2806,7 @@ impl DynamodbAcceleratorClient {
     /// Creates a client backed by the default tokio event loop.
     ///
     /// The client will use the default credentials provider and tls client.
+    #[cfg(not(target_arch = "wasm32"))]
     pub fn new(region: region::Region) -> DynamodbAcceleratorClient {
         DynamodbAcceleratorClient {
             client: Client::shared(),
@@ -2597,590 +2832,1652 @@ impl DynamodbAcceleratorClient {
     pub fn new_with_client(client: Client, region: region::Region) -> DynamodbAcceleratorClient {
         DynamodbAcceleratorClient { client, region }
     }
+
+    /// Returns a builder of a client, setting any of its region, credentials provider,
+    /// dispatcher, retry policy, endpoint and timeouts.
+    pub fn builder() -> rusoto_core::ClientBuilder<DynamodbAcceleratorClient> {
+        rusoto_core::ClientBuilder::new(DynamodbAcceleratorClient::new_with_client)
+    }
+
+    /// Returns a clone of this client with the overrides of `config` applied to its
+    /// operations, sharing its connections.
+    pub fn customize(&self, config: &rusoto_core::OperationConfig) -> DynamodbAcceleratorClient {
+        DynamodbAcceleratorClient {
+            client: self.client.customize(config),
+            region: config.resolve_region(&self.region),
+        }
+    }
 }
 
-#[async_trait]
-impl DynamodbAccelerator for DynamodbAcceleratorClient {
-    /// <p>Creates a DAX cluster. All nodes in the cluster run the same DAX caching software.</p>
-    async fn create_cluster(
+impl DynamodbAcceleratorClient {
+    /// Calls `create_cluster`, also returning the metadata of the response.
+    pub async fn create_cluster_with_metadata(
         &self,
         input: CreateClusterRequest,
-    ) -> Result<CreateClusterResponse, RusotoError<CreateClusterError>> {
+    ) -> Result<(CreateClusterResponse, ResponseMetadata), RusotoError<CreateClusterError>> {
+        let request = self.build_create_cluster_request(input);
+
+        let mut response = self
+            .client
+            .sign_and_dispatch(request)
+            .await
+            .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
+        if response.status.is_success() {
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<CreateClusterResponse, _>()?;
+            Ok((result, metadata))
+        } else {
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(CreateClusterError::from_response(response))
+        }
+    }
+
+    /// Presigns the request `create_cluster` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_cluster(
+        &self,
+        presigner: &rusoto_core::Presigner,
+        input: CreateClusterRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_cluster_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_cluster_request(&self, input: CreateClusterRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "dax", &self.region, "/");
+        request.set_operation("CreateCluster");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonDAXV3.CreateCluster");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `create_parameter_group`, also returning the metadata of the response.
+    pub async fn create_parameter_group_with_metadata(
+        &self,
+        input: CreateParameterGroupRequest,
+    ) -> Result<
+        (CreateParameterGroupResponse, ResponseMetadata),
+        RusotoError<CreateParameterGroupError>,
+    > {
+        let request = self.build_create_parameter_group_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<CreateClusterResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<CreateParameterGroupResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(CreateClusterError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(CreateParameterGroupError::from_response(response))
         }
     }
 
-    /// <p>Creates a new parameter group. A parameter group is a collection of parameters that you apply to all of the nodes in a DAX cluster.</p>
-    async fn create_parameter_group(
+    /// Presigns the request `create_parameter_group` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_parameter_group(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: CreateParameterGroupRequest,
-    ) -> Result<CreateParameterGroupResponse, RusotoError<CreateParameterGroupError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_parameter_group_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_parameter_group_request(
+        &self,
+        input: CreateParameterGroupRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "dax", &self.region, "/");
+        request.set_operation("CreateParameterGroup");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonDAXV3.CreateParameterGroup");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `create_subnet_group`, also returning the metadata of the response.
+    pub async fn create_subnet_group_with_metadata(
+        &self,
+        input: CreateSubnetGroupRequest,
+    ) -> Result<(CreateSubnetGroupResponse, ResponseMetadata), RusotoError<CreateSubnetGroupError>>
+    {
+        let request = self.build_create_subnet_group_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<CreateParameterGroupResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<CreateSubnetGroupResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(CreateParameterGroupError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(CreateSubnetGroupError::from_response(response))
         }
     }
 
-    /// <p>Creates a new subnet group.</p>
-    async fn create_subnet_group(
+    /// Presigns the request `create_subnet_group` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_subnet_group(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: CreateSubnetGroupRequest,
-    ) -> Result<CreateSubnetGroupResponse, RusotoError<CreateSubnetGroupError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_subnet_group_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_subnet_group_request(&self, input: CreateSubnetGroupRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "dax", &self.region, "/");
+        request.set_operation("CreateSubnetGroup");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonDAXV3.CreateSubnetGroup");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `decrease_replication_factor`, also returning the metadata of the response.
+    pub async fn decrease_replication_factor_with_metadata(
+        &self,
+        input: DecreaseReplicationFactorRequest,
+    ) -> Result<
+        (DecreaseReplicationFactorResponse, ResponseMetadata),
+        RusotoError<DecreaseReplicationFactorError>,
+    > {
+        let request = self.build_decrease_replication_factor_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<CreateSubnetGroupResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DecreaseReplicationFactorResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(CreateSubnetGroupError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DecreaseReplicationFactorError::from_response(response))
         }
     }
 
-    /// <p><p>Removes one or more nodes from a DAX cluster.</p> <note> <p>You cannot use <code>DecreaseReplicationFactor</code> to remove the last node in a DAX cluster. If you need to do this, use <code>DeleteCluster</code> instead.</p> </note></p>
-    async fn decrease_replication_factor(
+    /// Presigns the request `decrease_replication_factor` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_decrease_replication_factor(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DecreaseReplicationFactorRequest,
-    ) -> Result<DecreaseReplicationFactorResponse, RusotoError<DecreaseReplicationFactorError>>
-    {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_decrease_replication_factor_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_decrease_replication_factor_request(
+        &self,
+        input: DecreaseReplicationFactorRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "dax", &self.region, "/");
+        request.set_operation("DecreaseReplicationFactor");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonDAXV3.DecreaseReplicationFactor");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `delete_cluster`, also returning the metadata of the response.
+    pub async fn delete_cluster_with_metadata(
+        &self,
+        input: DeleteClusterRequest,
+    ) -> Result<(DeleteClusterResponse, ResponseMetadata), RusotoError<DeleteClusterError>> {
+        let request = self.build_delete_cluster_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DecreaseReplicationFactorResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DeleteClusterResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DecreaseReplicationFactorError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DeleteClusterError::from_response(response))
         }
     }
 
-    /// <p>Deletes a previously provisioned DAX cluster. <i>DeleteCluster</i> deletes all associated nodes, node endpoints and the DAX cluster itself. When you receive a successful response from this action, DAX immediately begins deleting the cluster; you cannot cancel or revert this action.</p>
-    async fn delete_cluster(
+    /// Presigns the request `delete_cluster` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_cluster(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteClusterRequest,
-    ) -> Result<DeleteClusterResponse, RusotoError<DeleteClusterError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_cluster_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_cluster_request(&self, input: DeleteClusterRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "dax", &self.region, "/");
+        request.set_operation("DeleteCluster");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonDAXV3.DeleteCluster");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `delete_parameter_group`, also returning the metadata of the response.
+    pub async fn delete_parameter_group_with_metadata(
+        &self,
+        input: DeleteParameterGroupRequest,
+    ) -> Result<
+        (DeleteParameterGroupResponse, ResponseMetadata),
+        RusotoError<DeleteParameterGroupError>,
+    > {
+        let request = self.build_delete_parameter_group_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<DeleteClusterResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DeleteParameterGroupResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteClusterError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DeleteParameterGroupError::from_response(response))
         }
     }
 
-    /// <p>Deletes the specified parameter group. You cannot delete a parameter group if it is associated with any DAX clusters.</p>
-    async fn delete_parameter_group(
+    /// Presigns the request `delete_parameter_group` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_parameter_group(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteParameterGroupRequest,
-    ) -> Result<DeleteParameterGroupResponse, RusotoError<DeleteParameterGroupError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_parameter_group_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_parameter_group_request(
+        &self,
+        input: DeleteParameterGroupRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "dax", &self.region, "/");
+        request.set_operation("DeleteParameterGroup");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonDAXV3.DeleteParameterGroup");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `delete_subnet_group`, also returning the metadata of the response.
+    pub async fn delete_subnet_group_with_metadata(
+        &self,
+        input: DeleteSubnetGroupRequest,
+    ) -> Result<(DeleteSubnetGroupResponse, ResponseMetadata), RusotoError<DeleteSubnetGroupError>>
+    {
+        let request = self.build_delete_subnet_group_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DeleteParameterGroupResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DeleteSubnetGroupResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteParameterGroupError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DeleteSubnetGroupError::from_response(response))
         }
     }
 
-    /// <p><p>Deletes a subnet group.</p> <note> <p>You cannot delete a subnet group if it is associated with any DAX clusters.</p> </note></p>
-    async fn delete_subnet_group(
+    /// Presigns the request `delete_subnet_group` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_subnet_group(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteSubnetGroupRequest,
-    ) -> Result<DeleteSubnetGroupResponse, RusotoError<DeleteSubnetGroupError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_subnet_group_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_subnet_group_request(&self, input: DeleteSubnetGroupRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "dax", &self.region, "/");
+        request.set_operation("DeleteSubnetGroup");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonDAXV3.DeleteSubnetGroup");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `describe_clusters`, also returning the metadata of the response.
+    pub async fn describe_clusters_with_metadata(
+        &self,
+        input: DescribeClustersRequest,
+    ) -> Result<(DescribeClustersResponse, ResponseMetadata), RusotoError<DescribeClustersError>>
+    {
+        let request = self.build_describe_clusters_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DeleteSubnetGroupResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DescribeClustersResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteSubnetGroupError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DescribeClustersError::from_response(response))
         }
     }
 
-    /// <p>Returns information about all provisioned DAX clusters if no cluster identifier is specified, or about a specific DAX cluster if a cluster identifier is supplied.</p> <p>If the cluster is in the CREATING state, only cluster level information will be displayed until all of the nodes are successfully provisioned.</p> <p>If the cluster is in the DELETING state, only cluster level information will be displayed.</p> <p>If nodes are currently being added to the DAX cluster, node endpoint information and creation time for the additional nodes will not be displayed until they are completely provisioned. When the DAX cluster state is <i>available</i>, the cluster is ready for use.</p> <p>If nodes are currently being removed from the DAX cluster, no endpoint information for the removed nodes is displayed.</p>
-    async fn describe_clusters(
+    /// Presigns the request `describe_clusters` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_clusters(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeClustersRequest,
-    ) -> Result<DescribeClustersResponse, RusotoError<DescribeClustersError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_clusters_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_clusters_request(&self, input: DescribeClustersRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "dax", &self.region, "/");
+        request.set_operation("DescribeClusters");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonDAXV3.DescribeClusters");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `describe_default_parameters`, also returning the metadata of the response.
+    pub async fn describe_default_parameters_with_metadata(
+        &self,
+        input: DescribeDefaultParametersRequest,
+    ) -> Result<
+        (DescribeDefaultParametersResponse, ResponseMetadata),
+        RusotoError<DescribeDefaultParametersError>,
+    > {
+        let request = self.build_describe_default_parameters_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DescribeClustersResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DescribeDefaultParametersResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeClustersError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DescribeDefaultParametersError::from_response(response))
         }
     }
 
-    /// <p>Returns the default system parameter information for the DAX caching software.</p>
-    async fn describe_default_parameters(
+    /// Presigns the request `describe_default_parameters` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_default_parameters(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeDefaultParametersRequest,
-    ) -> Result<DescribeDefaultParametersResponse, RusotoError<DescribeDefaultParametersError>>
-    {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_default_parameters_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_default_parameters_request(
+        &self,
+        input: DescribeDefaultParametersRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "dax", &self.region, "/");
+        request.set_operation("DescribeDefaultParameters");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonDAXV3.DescribeDefaultParameters");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `describe_events`, also returning the metadata of the response.
+    pub async fn describe_events_with_metadata(
+        &self,
+        input: DescribeEventsRequest,
+    ) -> Result<(DescribeEventsResponse, ResponseMetadata), RusotoError<DescribeEventsError>> {
+        let request = self.build_describe_events_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DescribeDefaultParametersResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DescribeEventsResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeDefaultParametersError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DescribeEventsError::from_response(response))
         }
     }
 
-    /// <p>Returns events related to DAX clusters and parameter groups. You can obtain events specific to a particular DAX cluster or parameter group by providing the name as a parameter.</p> <p>By default, only the events occurring within the last 24 hours are returned; however, you can retrieve up to 14 days' worth of events if necessary.</p>
-    async fn describe_events(
+    /// Presigns the request `describe_events` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_events(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeEventsRequest,
-    ) -> Result<DescribeEventsResponse, RusotoError<DescribeEventsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_events_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_events_request(&self, input: DescribeEventsRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "dax", &self.region, "/");
+        request.set_operation("DescribeEvents");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonDAXV3.DescribeEvents");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `describe_parameter_groups`, also returning the metadata of the response.
+    pub async fn describe_parameter_groups_with_metadata(
+        &self,
+        input: DescribeParameterGroupsRequest,
+    ) -> Result<
+        (DescribeParameterGroupsResponse, ResponseMetadata),
+        RusotoError<DescribeParameterGroupsError>,
+    > {
+        let request = self.build_describe_parameter_groups_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<DescribeEventsResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DescribeParameterGroupsResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeEventsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DescribeParameterGroupsError::from_response(response))
         }
     }
 
-    /// <p>Returns a list of parameter group descriptions. If a parameter group name is specified, the list will contain only the descriptions for that group.</p>
-    async fn describe_parameter_groups(
+    /// Presigns the request `describe_parameter_groups` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_parameter_groups(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeParameterGroupsRequest,
-    ) -> Result<DescribeParameterGroupsResponse, RusotoError<DescribeParameterGroupsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_parameter_groups_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_parameter_groups_request(
+        &self,
+        input: DescribeParameterGroupsRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "dax", &self.region, "/");
+        request.set_operation("DescribeParameterGroups");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonDAXV3.DescribeParameterGroups");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `describe_parameters`, also returning the metadata of the response.
+    pub async fn describe_parameters_with_metadata(
+        &self,
+        input: DescribeParametersRequest,
+    ) -> Result<(DescribeParametersResponse, ResponseMetadata), RusotoError<DescribeParametersError>>
+    {
+        let request = self.build_describe_parameters_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DescribeParameterGroupsResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DescribeParametersResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeParameterGroupsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DescribeParametersError::from_response(response))
         }
     }
 
-    /// <p>Returns the detailed parameter list for a particular parameter group.</p>
-    async fn describe_parameters(
+    /// Presigns the request `describe_parameters` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_parameters(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeParametersRequest,
-    ) -> Result<DescribeParametersResponse, RusotoError<DescribeParametersError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_parameters_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_parameters_request(&self, input: DescribeParametersRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "dax", &self.region, "/");
+        request.set_operation("DescribeParameters");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonDAXV3.DescribeParameters");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `describe_subnet_groups`, also returning the metadata of the response.
+    pub async fn describe_subnet_groups_with_metadata(
+        &self,
+        input: DescribeSubnetGroupsRequest,
+    ) -> Result<
+        (DescribeSubnetGroupsResponse, ResponseMetadata),
+        RusotoError<DescribeSubnetGroupsError>,
+    > {
+        let request = self.build_describe_subnet_groups_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DescribeParametersResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DescribeSubnetGroupsResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeParametersError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DescribeSubnetGroupsError::from_response(response))
         }
     }
 
-    /// <p>Returns a list of subnet group descriptions. If a subnet group name is specified, the list will contain only the description of that group.</p>
-    async fn describe_subnet_groups(
+    /// Presigns the request `describe_subnet_groups` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_subnet_groups(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeSubnetGroupsRequest,
-    ) -> Result<DescribeSubnetGroupsResponse, RusotoError<DescribeSubnetGroupsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_subnet_groups_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_subnet_groups_request(
+        &self,
+        input: DescribeSubnetGroupsRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "dax", &self.region, "/");
+        request.set_operation("DescribeSubnetGroups");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonDAXV3.DescribeSubnetGroups");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `increase_replication_factor`, also returning the metadata of the response.
+    pub async fn increase_replication_factor_with_metadata(
+        &self,
+        input: IncreaseReplicationFactorRequest,
+    ) -> Result<
+        (IncreaseReplicationFactorResponse, ResponseMetadata),
+        RusotoError<IncreaseReplicationFactorError>,
+    > {
+        let request = self.build_increase_replication_factor_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DescribeSubnetGroupsResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<IncreaseReplicationFactorResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeSubnetGroupsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(IncreaseReplicationFactorError::from_response(response))
         }
     }
 
-    /// <p>Adds one or more nodes to a DAX cluster.</p>
-    async fn increase_replication_factor(
+    /// Presigns the request `increase_replication_factor` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_increase_replication_factor(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: IncreaseReplicationFactorRequest,
-    ) -> Result<IncreaseReplicationFactorResponse, RusotoError<IncreaseReplicationFactorError>>
-    {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_increase_replication_factor_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_increase_replication_factor_request(
+        &self,
+        input: IncreaseReplicationFactorRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "dax", &self.region, "/");
+        request.set_operation("IncreaseReplicationFactor");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonDAXV3.IncreaseReplicationFactor");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `list_tags`, also returning the metadata of the response.
+    pub async fn list_tags_with_metadata(
+        &self,
+        input: ListTagsRequest,
+    ) -> Result<(ListTagsResponse, ResponseMetadata), RusotoError<ListTagsError>> {
+        let request = self.build_list_tags_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<IncreaseReplicationFactorResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ListTagsResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(IncreaseReplicationFactorError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ListTagsError::from_response(response))
         }
     }
 
-    /// <p>List all of the tags for a DAX cluster. You can call <code>ListTags</code> up to 10 times per second, per account.</p>
-    async fn list_tags(
+    /// Presigns the request `list_tags` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_tags(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListTagsRequest,
-    ) -> Result<ListTagsResponse, RusotoError<ListTagsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_tags_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_tags_request(&self, input: ListTagsRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "dax", &self.region, "/");
+        request.set_operation("ListTags");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonDAXV3.ListTags");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `reboot_node`, also returning the metadata of the response.
+    pub async fn reboot_node_with_metadata(
+        &self,
+        input: RebootNodeRequest,
+    ) -> Result<(RebootNodeResponse, ResponseMetadata), RusotoError<RebootNodeError>> {
+        let request = self.build_reboot_node_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<ListTagsResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<RebootNodeResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ListTagsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(RebootNodeError::from_response(response))
         }
     }
 
-    /// <p><p>Reboots a single node of a DAX cluster. The reboot action takes place as soon as possible. During the reboot, the node status is set to REBOOTING.</p> <note> <p> <code>RebootNode</code> restarts the DAX engine process and does not remove the contents of the cache. </p> </note></p>
-    async fn reboot_node(
+    /// Presigns the request `reboot_node` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_reboot_node(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: RebootNodeRequest,
-    ) -> Result<RebootNodeResponse, RusotoError<RebootNodeError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_reboot_node_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_reboot_node_request(&self, input: RebootNodeRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "dax", &self.region, "/");
+        request.set_operation("RebootNode");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonDAXV3.RebootNode");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `tag_resource`, also returning the metadata of the response.
+    pub async fn tag_resource_with_metadata(
+        &self,
+        input: TagResourceRequest,
+    ) -> Result<(TagResourceResponse, ResponseMetadata), RusotoError<TagResourceError>> {
+        let request = self.build_tag_resource_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<RebootNodeResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<TagResourceResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(RebootNodeError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(TagResourceError::from_response(response))
         }
     }
 
-    /// <p>Associates a set of tags with a DAX resource. You can call <code>TagResource</code> up to 5 times per second, per account. </p>
-    async fn tag_resource(
+    /// Presigns the request `tag_resource` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_tag_resource(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: TagResourceRequest,
-    ) -> Result<TagResourceResponse, RusotoError<TagResourceError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_tag_resource_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_tag_resource_request(&self, input: TagResourceRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "dax", &self.region, "/");
+        request.set_operation("TagResource");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonDAXV3.TagResource");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `untag_resource`, also returning the metadata of the response.
+    pub async fn untag_resource_with_metadata(
+        &self,
+        input: UntagResourceRequest,
+    ) -> Result<(UntagResourceResponse, ResponseMetadata), RusotoError<UntagResourceError>> {
+        let request = self.build_untag_resource_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<TagResourceResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<UntagResourceResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(TagResourceError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(UntagResourceError::from_response(response))
         }
     }
 
-    /// <p>Removes the association of tags from a DAX resource. You can call <code>UntagResource</code> up to 5 times per second, per account. </p>
-    async fn untag_resource(
+    /// Presigns the request `untag_resource` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_untag_resource(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UntagResourceRequest,
-    ) -> Result<UntagResourceResponse, RusotoError<UntagResourceError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_untag_resource_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_untag_resource_request(&self, input: UntagResourceRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "dax", &self.region, "/");
+        request.set_operation("UntagResource");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonDAXV3.UntagResource");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `update_cluster`, also returning the metadata of the response.
+    pub async fn update_cluster_with_metadata(
+        &self,
+        input: UpdateClusterRequest,
+    ) -> Result<(UpdateClusterResponse, ResponseMetadata), RusotoError<UpdateClusterError>> {
+        let request = self.build_update_cluster_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<UntagResourceResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<UpdateClusterResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(UntagResourceError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(UpdateClusterError::from_response(response))
         }
     }
 
-    /// <p>Modifies the settings for a DAX cluster. You can use this action to change one or more cluster configuration parameters by specifying the parameters and the new values.</p>
-    async fn update_cluster(
+    /// Presigns the request `update_cluster` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_cluster(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UpdateClusterRequest,
-    ) -> Result<UpdateClusterResponse, RusotoError<UpdateClusterError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_cluster_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_cluster_request(&self, input: UpdateClusterRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "dax", &self.region, "/");
+        request.set_operation("UpdateCluster");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonDAXV3.UpdateCluster");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `update_parameter_group`, also returning the metadata of the response.
+    pub async fn update_parameter_group_with_metadata(
+        &self,
+        input: UpdateParameterGroupRequest,
+    ) -> Result<
+        (UpdateParameterGroupResponse, ResponseMetadata),
+        RusotoError<UpdateParameterGroupError>,
+    > {
+        let request = self.build_update_parameter_group_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<UpdateClusterResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<UpdateParameterGroupResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(UpdateClusterError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(UpdateParameterGroupError::from_response(response))
         }
     }
 
-    /// <p>Modifies the parameters of a parameter group. You can modify up to 20 parameters in a single request by submitting a list parameter name and value pairs.</p>
-    async fn update_parameter_group(
+    /// Presigns the request `update_parameter_group` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_parameter_group(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UpdateParameterGroupRequest,
-    ) -> Result<UpdateParameterGroupResponse, RusotoError<UpdateParameterGroupError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_parameter_group_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_parameter_group_request(
+        &self,
+        input: UpdateParameterGroupRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "dax", &self.region, "/");
+        request.set_operation("UpdateParameterGroup");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonDAXV3.UpdateParameterGroup");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `update_subnet_group`, also returning the metadata of the response.
+    pub async fn update_subnet_group_with_metadata(
+        &self,
+        input: UpdateSubnetGroupRequest,
+    ) -> Result<(UpdateSubnetGroupResponse, ResponseMetadata), RusotoError<UpdateSubnetGroupError>>
+    {
+        let request = self.build_update_subnet_group_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<UpdateParameterGroupResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<UpdateSubnetGroupResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(UpdateParameterGroupError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(UpdateSubnetGroupError::from_response(response))
         }
     }
 
-    /// <p>Modifies an existing subnet group.</p>
-    async fn update_subnet_group(
+    /// Presigns the request `update_subnet_group` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_subnet_group(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UpdateSubnetGroupRequest,
-    ) -> Result<UpdateSubnetGroupResponse, RusotoError<UpdateSubnetGroupError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_subnet_group_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_subnet_group_request(&self, input: UpdateSubnetGroupRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "dax", &self.region, "/");
+        request.set_operation("UpdateSubnetGroup");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonDAXV3.UpdateSubnetGroup");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
-        let mut response = self
-            .client
-            .sign_and_dispatch(request)
+        request
+    }
+
+    /// The pages of `DescribeClusters` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `MaxResults` of `input`.
+    pub fn describe_clusters_pages(
+        &self,
+        input: DescribeClustersRequest,
+    ) -> impl futures::Stream<
+        Item = Result<DescribeClustersResponse, RusotoError<DescribeClustersError>>,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: DynamodbAcceleratorClient, input| async move {
+                client.describe_clusters(input).await
+            },
+            |input: &DescribeClustersRequest, output: &DescribeClustersResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(DescribeClustersRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `Clusters` of every page of `DescribeClusters` results for `input`, as `describe_clusters_pages` requests them.
+    pub fn describe_clusters_stream(
+        &self,
+        input: DescribeClustersRequest,
+    ) -> impl futures::Stream<Item = Result<Cluster, RusotoError<DescribeClustersError>>> + Send
+    {
+        rusoto_core::pagination::items(self.describe_clusters_pages(input), |page| {
+            page.clusters.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `DescribeDefaultParameters` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `MaxResults` of `input`.
+    pub fn describe_default_parameters_pages(
+        &self,
+        input: DescribeDefaultParametersRequest,
+    ) -> impl futures::Stream<
+        Item = Result<
+            DescribeDefaultParametersResponse,
+            RusotoError<DescribeDefaultParametersError>,
+        >,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: DynamodbAcceleratorClient, input| async move {
+                client.describe_default_parameters(input).await
+            },
+            |input: &DescribeDefaultParametersRequest,
+             output: &DescribeDefaultParametersResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(DescribeDefaultParametersRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `Parameters` of every page of `DescribeDefaultParameters` results for `input`, as `describe_default_parameters_pages` requests them.
+    pub fn describe_default_parameters_stream(
+        &self,
+        input: DescribeDefaultParametersRequest,
+    ) -> impl futures::Stream<Item = Result<Parameter, RusotoError<DescribeDefaultParametersError>>> + Send
+    {
+        rusoto_core::pagination::items(self.describe_default_parameters_pages(input), |page| {
+            page.parameters.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `DescribeEvents` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `MaxResults` of `input`.
+    pub fn describe_events_pages(
+        &self,
+        input: DescribeEventsRequest,
+    ) -> impl futures::Stream<Item = Result<DescribeEventsResponse, RusotoError<DescribeEventsError>>>
+           + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: DynamodbAcceleratorClient, input| async move {
+                client.describe_events(input).await
+            },
+            |input: &DescribeEventsRequest, output: &DescribeEventsResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(DescribeEventsRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `Events` of every page of `DescribeEvents` results for `input`, as `describe_events_pages` requests them.
+    pub fn describe_events_stream(
+        &self,
+        input: DescribeEventsRequest,
+    ) -> impl futures::Stream<Item = Result<Event, RusotoError<DescribeEventsError>>> + Send {
+        rusoto_core::pagination::items(self.describe_events_pages(input), |page| {
+            page.events.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `DescribeParameterGroups` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `MaxResults` of `input`.
+    pub fn describe_parameter_groups_pages(
+        &self,
+        input: DescribeParameterGroupsRequest,
+    ) -> impl futures::Stream<
+        Item = Result<DescribeParameterGroupsResponse, RusotoError<DescribeParameterGroupsError>>,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: DynamodbAcceleratorClient, input| async move {
+                client.describe_parameter_groups(input).await
+            },
+            |input: &DescribeParameterGroupsRequest, output: &DescribeParameterGroupsResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(DescribeParameterGroupsRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `ParameterGroups` of every page of `DescribeParameterGroups` results for `input`, as `describe_parameter_groups_pages` requests them.
+    pub fn describe_parameter_groups_stream(
+        &self,
+        input: DescribeParameterGroupsRequest,
+    ) -> impl futures::Stream<Item = Result<ParameterGroup, RusotoError<DescribeParameterGroupsError>>>
+           + Send {
+        rusoto_core::pagination::items(self.describe_parameter_groups_pages(input), |page| {
+            page.parameter_groups.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `DescribeParameters` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `MaxResults` of `input`.
+    pub fn describe_parameters_pages(
+        &self,
+        input: DescribeParametersRequest,
+    ) -> impl futures::Stream<
+        Item = Result<DescribeParametersResponse, RusotoError<DescribeParametersError>>,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: DynamodbAcceleratorClient, input| async move {
+                client.describe_parameters(input).await
+            },
+            |input: &DescribeParametersRequest, output: &DescribeParametersResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(DescribeParametersRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `Parameters` of every page of `DescribeParameters` results for `input`, as `describe_parameters_pages` requests them.
+    pub fn describe_parameters_stream(
+        &self,
+        input: DescribeParametersRequest,
+    ) -> impl futures::Stream<Item = Result<Parameter, RusotoError<DescribeParametersError>>> + Send
+    {
+        rusoto_core::pagination::items(self.describe_parameters_pages(input), |page| {
+            page.parameters.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `DescribeSubnetGroups` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `MaxResults` of `input`.
+    pub fn describe_subnet_groups_pages(
+        &self,
+        input: DescribeSubnetGroupsRequest,
+    ) -> impl futures::Stream<
+        Item = Result<DescribeSubnetGroupsResponse, RusotoError<DescribeSubnetGroupsError>>,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: DynamodbAcceleratorClient, input| async move {
+                client.describe_subnet_groups(input).await
+            },
+            |input: &DescribeSubnetGroupsRequest, output: &DescribeSubnetGroupsResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(DescribeSubnetGroupsRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `SubnetGroups` of every page of `DescribeSubnetGroups` results for `input`, as `describe_subnet_groups_pages` requests them.
+    pub fn describe_subnet_groups_stream(
+        &self,
+        input: DescribeSubnetGroupsRequest,
+    ) -> impl futures::Stream<Item = Result<SubnetGroup, RusotoError<DescribeSubnetGroupsError>>> + Send
+    {
+        rusoto_core::pagination::items(self.describe_subnet_groups_pages(input), |page| {
+            page.subnet_groups.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `ListTags` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    pub fn list_tags_pages(
+        &self,
+        input: ListTagsRequest,
+    ) -> impl futures::Stream<Item = Result<ListTagsResponse, RusotoError<ListTagsError>>> + Send
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: DynamodbAcceleratorClient, input| async move { client.list_tags(input).await },
+            |input: &ListTagsRequest, output: &ListTagsResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListTagsRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `Tags` of every page of `ListTags` results for `input`, as `list_tags_pages` requests them.
+    pub fn list_tags_stream(
+        &self,
+        input: ListTagsRequest,
+    ) -> impl futures::Stream<Item = Result<Tag, RusotoError<ListTagsError>>> + Send {
+        rusoto_core::pagination::items(self.list_tags_pages(input), |page| {
+            page.tags.unwrap_or_default()
+        })
+    }
+}
+
+#[async_trait]
+impl DynamodbAccelerator for DynamodbAcceleratorClient {
+    /// <p>Creates a DAX cluster. All nodes in the cluster run the same DAX caching software.</p>
+    async fn create_cluster(
+        &self,
+        input: CreateClusterRequest,
+    ) -> Result<CreateClusterResponse, RusotoError<CreateClusterError>> {
+        self.create_cluster_with_metadata(input)
             .await
-            .map_err(RusotoError::from)?;
-        if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<UpdateSubnetGroupResponse, _>()
-        } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(UpdateSubnetGroupError::from_response(response))
-        }
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Creates a new parameter group. A parameter group is a collection of parameters that you apply to all of the nodes in a DAX cluster.</p>
+    async fn create_parameter_group(
+        &self,
+        input: CreateParameterGroupRequest,
+    ) -> Result<CreateParameterGroupResponse, RusotoError<CreateParameterGroupError>> {
+        self.create_parameter_group_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Creates a new subnet group.</p>
+    async fn create_subnet_group(
+        &self,
+        input: CreateSubnetGroupRequest,
+    ) -> Result<CreateSubnetGroupResponse, RusotoError<CreateSubnetGroupError>> {
+        self.create_subnet_group_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Removes one or more nodes from a DAX cluster.</p> <note> <p>You cannot use <code>DecreaseReplicationFactor</code> to remove the last node in a DAX cluster. If you need to do this, use <code>DeleteCluster</code> instead.</p> </note></p>
+    async fn decrease_replication_factor(
+        &self,
+        input: DecreaseReplicationFactorRequest,
+    ) -> Result<DecreaseReplicationFactorResponse, RusotoError<DecreaseReplicationFactorError>>
+    {
+        self.decrease_replication_factor_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes a previously provisioned DAX cluster. <i>DeleteCluster</i> deletes all associated nodes, node endpoints and the DAX cluster itself. When you receive a successful response from this action, DAX immediately begins deleting the cluster; you cannot cancel or revert this action.</p>
+    async fn delete_cluster(
+        &self,
+        input: DeleteClusterRequest,
+    ) -> Result<DeleteClusterResponse, RusotoError<DeleteClusterError>> {
+        self.delete_cluster_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes the specified parameter group. You cannot delete a parameter group if it is associated with any DAX clusters.</p>
+    async fn delete_parameter_group(
+        &self,
+        input: DeleteParameterGroupRequest,
+    ) -> Result<DeleteParameterGroupResponse, RusotoError<DeleteParameterGroupError>> {
+        self.delete_parameter_group_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Deletes a subnet group.</p> <note> <p>You cannot delete a subnet group if it is associated with any DAX clusters.</p> </note></p>
+    async fn delete_subnet_group(
+        &self,
+        input: DeleteSubnetGroupRequest,
+    ) -> Result<DeleteSubnetGroupResponse, RusotoError<DeleteSubnetGroupError>> {
+        self.delete_subnet_group_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns information about all provisioned DAX clusters if no cluster identifier is specified, or about a specific DAX cluster if a cluster identifier is supplied.</p> <p>If the cluster is in the CREATING state, only cluster level information will be displayed until all of the nodes are successfully provisioned.</p> <p>If the cluster is in the DELETING state, only cluster level information will be displayed.</p> <p>If nodes are currently being added to the DAX cluster, node endpoint information and creation time for the additional nodes will not be displayed until they are completely provisioned. When the DAX cluster state is <i>available</i>, the cluster is ready for use.</p> <p>If nodes are currently being removed from the DAX cluster, no endpoint information for the removed nodes is displayed.</p>
+    async fn describe_clusters(
+        &self,
+        input: DescribeClustersRequest,
+    ) -> Result<DescribeClustersResponse, RusotoError<DescribeClustersError>> {
+        self.describe_clusters_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns the default system parameter information for the DAX caching software.</p>
+    async fn describe_default_parameters(
+        &self,
+        input: DescribeDefaultParametersRequest,
+    ) -> Result<DescribeDefaultParametersResponse, RusotoError<DescribeDefaultParametersError>>
+    {
+        self.describe_default_parameters_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns events related to DAX clusters and parameter groups. You can obtain events specific to a particular DAX cluster or parameter group by providing the name as a parameter.</p> <p>By default, only the events occurring within the last 24 hours are returned; however, you can retrieve up to 14 days' worth of events if necessary.</p>
+    async fn describe_events(
+        &self,
+        input: DescribeEventsRequest,
+    ) -> Result<DescribeEventsResponse, RusotoError<DescribeEventsError>> {
+        self.describe_events_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns a list of parameter group descriptions. If a parameter group name is specified, the list will contain only the descriptions for that group.</p>
+    async fn describe_parameter_groups(
+        &self,
+        input: DescribeParameterGroupsRequest,
+    ) -> Result<DescribeParameterGroupsResponse, RusotoError<DescribeParameterGroupsError>> {
+        self.describe_parameter_groups_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns the detailed parameter list for a particular parameter group.</p>
+    async fn describe_parameters(
+        &self,
+        input: DescribeParametersRequest,
+    ) -> Result<DescribeParametersResponse, RusotoError<DescribeParametersError>> {
+        self.describe_parameters_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns a list of subnet group descriptions. If a subnet group name is specified, the list will contain only the description of that group.</p>
+    async fn describe_subnet_groups(
+        &self,
+        input: DescribeSubnetGroupsRequest,
+    ) -> Result<DescribeSubnetGroupsResponse, RusotoError<DescribeSubnetGroupsError>> {
+        self.describe_subnet_groups_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Adds one or more nodes to a DAX cluster.</p>
+    async fn increase_replication_factor(
+        &self,
+        input: IncreaseReplicationFactorRequest,
+    ) -> Result<IncreaseReplicationFactorResponse, RusotoError<IncreaseReplicationFactorError>>
+    {
+        self.increase_replication_factor_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>List all of the tags for a DAX cluster. You can call <code>ListTags</code> up to 10 times per second, per account.</p>
+    async fn list_tags(
+        &self,
+        input: ListTagsRequest,
+    ) -> Result<ListTagsResponse, RusotoError<ListTagsError>> {
+        self.list_tags_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Reboots a single node of a DAX cluster. The reboot action takes place as soon as possible. During the reboot, the node status is set to REBOOTING.</p> <note> <p> <code>RebootNode</code> restarts the DAX engine process and does not remove the contents of the cache. </p> </note></p>
+    async fn reboot_node(
+        &self,
+        input: RebootNodeRequest,
+    ) -> Result<RebootNodeResponse, RusotoError<RebootNodeError>> {
+        self.reboot_node_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Associates a set of tags with a DAX resource. You can call <code>TagResource</code> up to 5 times per second, per account. </p>
+    async fn tag_resource(
+        &self,
+        input: TagResourceRequest,
+    ) -> Result<TagResourceResponse, RusotoError<TagResourceError>> {
+        self.tag_resource_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Removes the association of tags from a DAX resource. You can call <code>UntagResource</code> up to 5 times per second, per account. </p>
+    async fn untag_resource(
+        &self,
+        input: UntagResourceRequest,
+    ) -> Result<UntagResourceResponse, RusotoError<UntagResourceError>> {
+        self.untag_resource_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Modifies the settings for a DAX cluster. You can use this action to change one or more cluster configuration parameters by specifying the parameters and the new values.</p>
+    async fn update_cluster(
+        &self,
+        input: UpdateClusterRequest,
+    ) -> Result<UpdateClusterResponse, RusotoError<UpdateClusterError>> {
+        self.update_cluster_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Modifies the parameters of a parameter group. You can modify up to 20 parameters in a single request by submitting a list parameter name and value pairs.</p>
+    async fn update_parameter_group(
+        &self,
+        input: UpdateParameterGroupRequest,
+    ) -> Result<UpdateParameterGroupResponse, RusotoError<UpdateParameterGroupError>> {
+        self.update_parameter_group_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Modifies an existing subnet group.</p>
+    async fn update_subnet_group(
+        &self,
+        input: UpdateSubnetGroupRequest,
+    ) -> Result<UpdateSubnetGroupResponse, RusotoError<UpdateSubnetGroupError>> {
+        self.update_subnet_group_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
     }
 }
@@ -16,8 +16,8 @@ use std::fmt;
 use async_trait::async_trait;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
-use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest, ResponseMetadata};
+use rusoto_core::{Client, ErrorMetadata, RusotoError};
 
 use rusoto_core::proto;
 use rusoto_core::signature::SignedRequest;
@@ -298,6 +298,7 @@ pub struct UnprocessedAccount {
 
 /// Errors returned by AcceptInvitation
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum AcceptInvitationError {
     /// <p>The request attempted an invalid action.</p>
     Conflict(String),
@@ -305,6 +306,8 @@ pub enum AcceptInvitationError {
     InternalServer(String),
     /// <p>The request refers to a nonexistent resource.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl AcceptInvitationError {
@@ -312,16 +315,22 @@ impl AcceptInvitationError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ConflictException" => {
-                    return RusotoError::Service(AcceptInvitationError::Conflict(err.msg))
+                    return RusotoError::service(AcceptInvitationError::Conflict(err.msg), &res)
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(AcceptInvitationError::InternalServer(err.msg))
+                    return RusotoError::service(
+                        AcceptInvitationError::InternalServer(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(AcceptInvitationError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        AcceptInvitationError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(AcceptInvitationError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -334,17 +343,21 @@ impl fmt::Display for AcceptInvitationError {
             AcceptInvitationError::Conflict(ref cause) => write!(f, "{}", cause),
             AcceptInvitationError::InternalServer(ref cause) => write!(f, "{}", cause),
             AcceptInvitationError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            AcceptInvitationError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for AcceptInvitationError {}
 /// Errors returned by CreateGraph
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateGraphError {
     /// <p>The request attempted an invalid action.</p>
     Conflict(String),
     /// <p>The request was valid but failed because of a problem with the service.</p>
     InternalServer(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateGraphError {
@@ -352,13 +365,13 @@ impl CreateGraphError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ConflictException" => {
-                    return RusotoError::Service(CreateGraphError::Conflict(err.msg))
+                    return RusotoError::service(CreateGraphError::Conflict(err.msg), &res)
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(CreateGraphError::InternalServer(err.msg))
+                    return RusotoError::service(CreateGraphError::InternalServer(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreateGraphError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -370,12 +383,14 @@ impl fmt::Display for CreateGraphError {
         match *self {
             CreateGraphError::Conflict(ref cause) => write!(f, "{}", cause),
             CreateGraphError::InternalServer(ref cause) => write!(f, "{}", cause),
+            CreateGraphError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateGraphError {}
 /// Errors returned by CreateMembers
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateMembersError {
     /// <p>The request was valid but failed because of a problem with the service.</p>
     InternalServer(String),
@@ -383,6 +398,8 @@ pub enum CreateMembersError {
     ResourceNotFound(String),
     /// <p>This request would cause the number of member accounts in the behavior graph to exceed the maximum allowed. A behavior graph cannot have more than 1000 member accounts.</p>
     ServiceQuotaExceeded(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateMembersError {
@@ -390,16 +407,22 @@ impl CreateMembersError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(CreateMembersError::InternalServer(err.msg))
+                    return RusotoError::service(CreateMembersError::InternalServer(err.msg), &res)
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(CreateMembersError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        CreateMembersError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceQuotaExceededException" => {
-                    return RusotoError::Service(CreateMembersError::ServiceQuotaExceeded(err.msg))
+                    return RusotoError::service(
+                        CreateMembersError::ServiceQuotaExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreateMembersError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -412,17 +435,21 @@ impl fmt::Display for CreateMembersError {
             CreateMembersError::InternalServer(ref cause) => write!(f, "{}", cause),
             CreateMembersError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             CreateMembersError::ServiceQuotaExceeded(ref cause) => write!(f, "{}", cause),
+            CreateMembersError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateMembersError {}
 /// Errors returned by DeleteGraph
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteGraphError {
     /// <p>The request was valid but failed because of a problem with the service.</p>
     InternalServer(String),
     /// <p>The request refers to a nonexistent resource.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteGraphError {
@@ -430,13 +457,13 @@ impl DeleteGraphError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(DeleteGraphError::InternalServer(err.msg))
+                    return RusotoError::service(DeleteGraphError::InternalServer(err.msg), &res)
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteGraphError::ResourceNotFound(err.msg))
+                    return RusotoError::service(DeleteGraphError::ResourceNotFound(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteGraphError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -448,12 +475,14 @@ impl fmt::Display for DeleteGraphError {
         match *self {
             DeleteGraphError::InternalServer(ref cause) => write!(f, "{}", cause),
             DeleteGraphError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            DeleteGraphError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteGraphError {}
 /// Errors returned by DeleteMembers
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteMembersError {
     /// <p>The request attempted an invalid action.</p>
     Conflict(String),
@@ -461,6 +490,8 @@ pub enum DeleteMembersError {
     InternalServer(String),
     /// <p>The request refers to a nonexistent resource.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteMembersError {
@@ -468,16 +499,19 @@ impl DeleteMembersError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ConflictException" => {
-                    return RusotoError::Service(DeleteMembersError::Conflict(err.msg))
+                    return RusotoError::service(DeleteMembersError::Conflict(err.msg), &res)
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(DeleteMembersError::InternalServer(err.msg))
+                    return RusotoError::service(DeleteMembersError::InternalServer(err.msg), &res)
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteMembersError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        DeleteMembersError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteMembersError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -490,12 +524,14 @@ impl fmt::Display for DeleteMembersError {
             DeleteMembersError::Conflict(ref cause) => write!(f, "{}", cause),
             DeleteMembersError::InternalServer(ref cause) => write!(f, "{}", cause),
             DeleteMembersError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            DeleteMembersError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteMembersError {}
 /// Errors returned by DisassociateMembership
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DisassociateMembershipError {
     /// <p>The request attempted an invalid action.</p>
     Conflict(String),
@@ -503,6 +539,8 @@ pub enum DisassociateMembershipError {
     InternalServer(String),
     /// <p>The request refers to a nonexistent resource.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DisassociateMembershipError {
@@ -510,20 +548,25 @@ impl DisassociateMembershipError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ConflictException" => {
-                    return RusotoError::Service(DisassociateMembershipError::Conflict(err.msg))
+                    return RusotoError::service(
+                        DisassociateMembershipError::Conflict(err.msg),
+                        &res,
+                    )
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(DisassociateMembershipError::InternalServer(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DisassociateMembershipError::InternalServer(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DisassociateMembershipError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DisassociateMembershipError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DisassociateMembershipError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -536,17 +579,21 @@ impl fmt::Display for DisassociateMembershipError {
             DisassociateMembershipError::Conflict(ref cause) => write!(f, "{}", cause),
             DisassociateMembershipError::InternalServer(ref cause) => write!(f, "{}", cause),
             DisassociateMembershipError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            DisassociateMembershipError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DisassociateMembershipError {}
 /// Errors returned by GetMembers
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetMembersError {
     /// <p>The request was valid but failed because of a problem with the service.</p>
     InternalServer(String),
     /// <p>The request refers to a nonexistent resource.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetMembersError {
@@ -554,13 +601,13 @@ impl GetMembersError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(GetMembersError::InternalServer(err.msg))
+                    return RusotoError::service(GetMembersError::InternalServer(err.msg), &res)
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetMembersError::ResourceNotFound(err.msg))
+                    return RusotoError::service(GetMembersError::ResourceNotFound(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetMembersError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -572,15 +619,19 @@ impl fmt::Display for GetMembersError {
         match *self {
             GetMembersError::InternalServer(ref cause) => write!(f, "{}", cause),
             GetMembersError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            GetMembersError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetMembersError {}
 /// Errors returned by ListGraphs
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListGraphsError {
     /// <p>The request was valid but failed because of a problem with the service.</p>
     InternalServer(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListGraphsError {
@@ -588,10 +639,10 @@ impl ListGraphsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(ListGraphsError::InternalServer(err.msg))
+                    return RusotoError::service(ListGraphsError::InternalServer(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListGraphsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -602,15 +653,19 @@ impl fmt::Display for ListGraphsError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             ListGraphsError::InternalServer(ref cause) => write!(f, "{}", cause),
+            ListGraphsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListGraphsError {}
 /// Errors returned by ListInvitations
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListInvitationsError {
     /// <p>The request was valid but failed because of a problem with the service.</p>
     InternalServer(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListInvitationsError {
@@ -618,10 +673,13 @@ impl ListInvitationsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(ListInvitationsError::InternalServer(err.msg))
+                    return RusotoError::service(
+                        ListInvitationsError::InternalServer(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListInvitationsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -632,17 +690,21 @@ impl fmt::Display for ListInvitationsError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             ListInvitationsError::InternalServer(ref cause) => write!(f, "{}", cause),
+            ListInvitationsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListInvitationsError {}
 /// Errors returned by ListMembers
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListMembersError {
     /// <p>The request was valid but failed because of a problem with the service.</p>
     InternalServer(String),
     /// <p>The request refers to a nonexistent resource.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListMembersError {
@@ -650,13 +712,13 @@ impl ListMembersError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(ListMembersError::InternalServer(err.msg))
+                    return RusotoError::service(ListMembersError::InternalServer(err.msg), &res)
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListMembersError::ResourceNotFound(err.msg))
+                    return RusotoError::service(ListMembersError::ResourceNotFound(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListMembersError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -668,12 +730,14 @@ impl fmt::Display for ListMembersError {
         match *self {
             ListMembersError::InternalServer(ref cause) => write!(f, "{}", cause),
             ListMembersError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            ListMembersError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListMembersError {}
 /// Errors returned by RejectInvitation
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum RejectInvitationError {
     /// <p>The request attempted an invalid action.</p>
     Conflict(String),
@@ -681,6 +745,8 @@ pub enum RejectInvitationError {
     InternalServer(String),
     /// <p>The request refers to a nonexistent resource.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl RejectInvitationError {
@@ -688,16 +754,22 @@ impl RejectInvitationError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ConflictException" => {
-                    return RusotoError::Service(RejectInvitationError::Conflict(err.msg))
+                    return RusotoError::service(RejectInvitationError::Conflict(err.msg), &res)
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(RejectInvitationError::InternalServer(err.msg))
+                    return RusotoError::service(
+                        RejectInvitationError::InternalServer(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(RejectInvitationError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        RejectInvitationError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(RejectInvitationError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -710,6 +782,7 @@ impl fmt::Display for RejectInvitationError {
             RejectInvitationError::Conflict(ref cause) => write!(f, "{}", cause),
             RejectInvitationError::InternalServer(ref cause) => write!(f, "{}", cause),
             RejectInvitationError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            RejectInvitationError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
@@ -791,6 +864,7 @@ impl DetectiveClient {
     /// Creates a client backed by the default tokio event loop.
     ///
     /// The client will use the default credentials provider and tls client.
+    #[cfg(not(target_arch = "wasm32"))]
     pub fn new(region: region::Region) -> DetectiveClient {
         DetectiveClient {
             client: Client::shared(),
@@ -816,339 +890,732 @@ impl DetectiveClient {
     pub fn new_with_client(client: Client, region: region::Region) -> DetectiveClient {
         DetectiveClient { client, region }
     }
+
+    /// Returns a builder of a client, setting any of its region, credentials provider,
+    /// dispatcher, retry policy, endpoint and timeouts.
+    pub fn builder() -> rusoto_core::ClientBuilder<DetectiveClient> {
+        rusoto_core::ClientBuilder::new(DetectiveClient::new_with_client)
+    }
+
+    /// Returns a clone of this client with the overrides of `config` applied to its
+    /// operations, sharing its connections.
+    pub fn customize(&self, config: &rusoto_core::OperationConfig) -> DetectiveClient {
+        DetectiveClient {
+            client: self.client.customize(config),
+            region: config.resolve_region(&self.region),
+        }
+    }
 }
 
-#[async_trait]
-impl Detective for DetectiveClient {
-    /// <p>Amazon Detective is currently in preview.</p> <p>Accepts an invitation for the member account to contribute data to a behavior graph. This operation can only be called by an invited member account. </p> <p>The request provides the ARN of behavior graph.</p> <p>The member account status in the graph must be <code>INVITED</code>.</p>
-    async fn accept_invitation(
+impl DetectiveClient {
+    /// Calls `accept_invitation`, also returning the metadata of the response.
+    pub async fn accept_invitation_with_metadata(
         &self,
         input: AcceptInvitationRequest,
-    ) -> Result<(), RusotoError<AcceptInvitationError>> {
-        let request_uri = "/invitation";
-
-        let mut request = SignedRequest::new("PUT", "detective", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        request.set_endpoint_prefix("api.detective".to_string());
-        let encoded = Some(serde_json::to_vec(&input).unwrap());
-        request.set_payload(encoded);
+    ) -> Result<((), ResponseMetadata), RusotoError<AcceptInvitationError>> {
+        let request = self.build_accept_invitation_request(input);
 
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = ::std::mem::drop(response);
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             Err(AcceptInvitationError::from_response(response))
         }
     }
 
-    /// <p>Amazon Detective is currently in preview.</p> <p>Creates a new behavior graph for the calling account, and sets that account as the master account. This operation is called by the account that is enabling Detective.</p> <p>The operation also enables Detective for the calling account in the currently selected Region. It returns the ARN of the new behavior graph.</p> <p> <code>CreateGraph</code> triggers a process to create the corresponding data tables for the new behavior graph.</p> <p>An account can only be the master account for one behavior graph within a Region. If the same account calls <code>CreateGraph</code> with the same master account, it always returns the same behavior graph ARN. It does not create a new behavior graph.</p>
-    async fn create_graph(&self) -> Result<CreateGraphResponse, RusotoError<CreateGraphError>> {
-        let request_uri = "/graph";
+    /// Presigns the request `accept_invitation` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_accept_invitation(
+        &self,
+        presigner: &rusoto_core::Presigner,
+        input: AcceptInvitationRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_accept_invitation_request(input);
+        self.client.presign(presigner, request).await
+    }
 
-        let mut request = SignedRequest::new("POST", "detective", &self.region, &request_uri);
+    fn build_accept_invitation_request(&self, input: AcceptInvitationRequest) -> SignedRequest {
+        let request_uri = "/invitation";
+
+        let mut request = SignedRequest::new("PUT", "detective", &self.region, &request_uri);
+        request.set_operation("AcceptInvitation");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("api.detective".to_string());
+        let encoded = Some(serde_json::to_vec(&input).unwrap());
+        request.set_payload(encoded);
+
+        request
+    }
+
+    /// Calls `create_graph`, also returning the metadata of the response.
+    pub async fn create_graph_with_metadata(
+        &self,
+    ) -> Result<(CreateGraphResponse, ResponseMetadata), RusotoError<CreateGraphError>> {
+        let request = self.build_create_graph_request();
 
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<CreateGraphResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             Err(CreateGraphError::from_response(response))
         }
     }
 
-    /// <p><p>Amazon Detective is currently in preview.</p> <p>Sends a request to invite the specified AWS accounts to be member accounts in the behavior graph. This operation can only be called by the master account for a behavior graph. </p> <p> <code>CreateMembers</code> verifies the accounts and then sends invitations to the verified accounts.</p> <p>The request provides the behavior graph ARN and the list of accounts to invite.</p> <p>The response separates the requested accounts into two lists:</p> <ul> <li> <p>The accounts that <code>CreateMembers</code> was able to start the verification for. This list includes member accounts that are being verified, that have passed verification and are being sent an invitation, and that have failed verification.</p> </li> <li> <p>The accounts that <code>CreateMembers</code> was unable to process. This list includes accounts that were already invited to be member accounts in the behavior graph.</p> </li> </ul></p>
-    async fn create_members(
+    /// Presigns the request `create_graph` would send with `presigner` instead of sending it.
+    pub async fn presign_create_graph(
         &self,
-        input: CreateMembersRequest,
-    ) -> Result<CreateMembersResponse, RusotoError<CreateMembersError>> {
-        let request_uri = "/graph/members";
+        presigner: &rusoto_core::Presigner,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_graph_request();
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_graph_request(&self) -> SignedRequest {
+        let request_uri = "/graph";
 
         let mut request = SignedRequest::new("POST", "detective", &self.region, &request_uri);
+        request.set_operation("CreateGraph");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("api.detective".to_string());
-        let encoded = Some(serde_json::to_vec(&input).unwrap());
-        request.set_payload(encoded);
+
+        request
+    }
+
+    /// Calls `create_members`, also returning the metadata of the response.
+    pub async fn create_members_with_metadata(
+        &self,
+        input: CreateMembersRequest,
+    ) -> Result<(CreateMembersResponse, ResponseMetadata), RusotoError<CreateMembersError>> {
+        let request = self.build_create_members_request(input);
 
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<CreateMembersResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             Err(CreateMembersError::from_response(response))
         }
     }
 
-    /// <p>Amazon Detective is currently in preview.</p> <p>Disables the specified behavior graph and queues it to be deleted. This operation removes the graph from each member account's list of behavior graphs.</p> <p> <code>DeleteGraph</code> can only be called by the master account for a behavior graph.</p>
-    async fn delete_graph(
+    /// Presigns the request `create_members` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_members(
         &self,
-        input: DeleteGraphRequest,
-    ) -> Result<(), RusotoError<DeleteGraphError>> {
-        let request_uri = "/graph/removal";
+        presigner: &rusoto_core::Presigner,
+        input: CreateMembersRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_members_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_members_request(&self, input: CreateMembersRequest) -> SignedRequest {
+        let request_uri = "/graph/members";
 
         let mut request = SignedRequest::new("POST", "detective", &self.region, &request_uri);
+        request.set_operation("CreateMembers");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("api.detective".to_string());
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `delete_graph`, also returning the metadata of the response.
+    pub async fn delete_graph_with_metadata(
+        &self,
+        input: DeleteGraphRequest,
+    ) -> Result<((), ResponseMetadata), RusotoError<DeleteGraphError>> {
+        let request = self.build_delete_graph_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = ::std::mem::drop(response);
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             Err(DeleteGraphError::from_response(response))
         }
     }
 
-    /// <p>Amazon Detective is currently in preview.</p> <p>Deletes one or more member accounts from the master account behavior graph. This operation can only be called by a Detective master account. That account cannot use <code>DeleteMembers</code> to delete their own account from the behavior graph. To disable a behavior graph, the master account uses the <code>DeleteGraph</code> API method.</p>
-    async fn delete_members(
+    /// Presigns the request `delete_graph` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_graph(
         &self,
-        input: DeleteMembersRequest,
-    ) -> Result<DeleteMembersResponse, RusotoError<DeleteMembersError>> {
-        let request_uri = "/graph/members/removal";
+        presigner: &rusoto_core::Presigner,
+        input: DeleteGraphRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_graph_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_graph_request(&self, input: DeleteGraphRequest) -> SignedRequest {
+        let request_uri = "/graph/removal";
 
         let mut request = SignedRequest::new("POST", "detective", &self.region, &request_uri);
+        request.set_operation("DeleteGraph");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("api.detective".to_string());
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `delete_members`, also returning the metadata of the response.
+    pub async fn delete_members_with_metadata(
+        &self,
+        input: DeleteMembersRequest,
+    ) -> Result<(DeleteMembersResponse, ResponseMetadata), RusotoError<DeleteMembersError>> {
+        let request = self.build_delete_members_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<DeleteMembersResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             Err(DeleteMembersError::from_response(response))
         }
     }
 
-    /// <p>Amazon Detective is currently in preview.</p> <p>Removes the member account from the specified behavior graph. This operation can only be called by a member account that has the <code>ENABLED</code> status.</p>
-    async fn disassociate_membership(
+    /// Presigns the request `delete_members` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_members(
         &self,
-        input: DisassociateMembershipRequest,
-    ) -> Result<(), RusotoError<DisassociateMembershipError>> {
-        let request_uri = "/membership/removal";
+        presigner: &rusoto_core::Presigner,
+        input: DeleteMembersRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_members_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_members_request(&self, input: DeleteMembersRequest) -> SignedRequest {
+        let request_uri = "/graph/members/removal";
 
         let mut request = SignedRequest::new("POST", "detective", &self.region, &request_uri);
+        request.set_operation("DeleteMembers");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("api.detective".to_string());
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `disassociate_membership`, also returning the metadata of the response.
+    pub async fn disassociate_membership_with_metadata(
+        &self,
+        input: DisassociateMembershipRequest,
+    ) -> Result<((), ResponseMetadata), RusotoError<DisassociateMembershipError>> {
+        let request = self.build_disassociate_membership_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = ::std::mem::drop(response);
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             Err(DisassociateMembershipError::from_response(response))
         }
     }
 
-    /// <p>Amazon Detective is currently in preview.</p> <p>Returns the membership details for specified member accounts for a behavior graph.</p>
-    async fn get_members(
+    /// Presigns the request `disassociate_membership` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_disassociate_membership(
         &self,
-        input: GetMembersRequest,
-    ) -> Result<GetMembersResponse, RusotoError<GetMembersError>> {
-        let request_uri = "/graph/members/get";
+        presigner: &rusoto_core::Presigner,
+        input: DisassociateMembershipRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_disassociate_membership_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_disassociate_membership_request(
+        &self,
+        input: DisassociateMembershipRequest,
+    ) -> SignedRequest {
+        let request_uri = "/membership/removal";
 
         let mut request = SignedRequest::new("POST", "detective", &self.region, &request_uri);
+        request.set_operation("DisassociateMembership");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("api.detective".to_string());
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `get_members`, also returning the metadata of the response.
+    pub async fn get_members_with_metadata(
+        &self,
+        input: GetMembersRequest,
+    ) -> Result<(GetMembersResponse, ResponseMetadata), RusotoError<GetMembersError>> {
+        let request = self.build_get_members_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<GetMembersResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             Err(GetMembersError::from_response(response))
         }
     }
 
-    /// <p>Amazon Detective is currently in preview.</p> <p>Returns the list of behavior graphs that the calling account is a master of. This operation can only be called by a master account.</p> <p>Because an account can currently only be the master of one behavior graph within a Region, the results always contain a single graph.</p>
-    async fn list_graphs(
+    /// Presigns the request `get_members` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_members(
         &self,
-        input: ListGraphsRequest,
-    ) -> Result<ListGraphsResponse, RusotoError<ListGraphsError>> {
-        let request_uri = "/graphs/list";
+        presigner: &rusoto_core::Presigner,
+        input: GetMembersRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_members_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_members_request(&self, input: GetMembersRequest) -> SignedRequest {
+        let request_uri = "/graph/members/get";
 
         let mut request = SignedRequest::new("POST", "detective", &self.region, &request_uri);
+        request.set_operation("GetMembers");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("api.detective".to_string());
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `list_graphs`, also returning the metadata of the response.
+    pub async fn list_graphs_with_metadata(
+        &self,
+        input: ListGraphsRequest,
+    ) -> Result<(ListGraphsResponse, ResponseMetadata), RusotoError<ListGraphsError>> {
+        let request = self.build_list_graphs_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<ListGraphsResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             Err(ListGraphsError::from_response(response))
         }
     }
 
-    /// <p>Amazon Detective is currently in preview.</p> <p>Retrieves the list of open and accepted behavior graph invitations for the member account. This operation can only be called by a member account.</p> <p>Open invitations are invitations that the member account has not responded to.</p> <p>The results do not include behavior graphs for which the member account declined the invitation. The results also do not include behavior graphs that the member account resigned from or was removed from.</p>
-    async fn list_invitations(
+    /// Presigns the request `list_graphs` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_graphs(
         &self,
-        input: ListInvitationsRequest,
-    ) -> Result<ListInvitationsResponse, RusotoError<ListInvitationsError>> {
-        let request_uri = "/invitations/list";
+        presigner: &rusoto_core::Presigner,
+        input: ListGraphsRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_graphs_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_graphs_request(&self, input: ListGraphsRequest) -> SignedRequest {
+        let request_uri = "/graphs/list";
 
         let mut request = SignedRequest::new("POST", "detective", &self.region, &request_uri);
+        request.set_operation("ListGraphs");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("api.detective".to_string());
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `list_invitations`, also returning the metadata of the response.
+    pub async fn list_invitations_with_metadata(
+        &self,
+        input: ListInvitationsRequest,
+    ) -> Result<(ListInvitationsResponse, ResponseMetadata), RusotoError<ListInvitationsError>>
+    {
+        let request = self.build_list_invitations_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<ListInvitationsResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             Err(ListInvitationsError::from_response(response))
         }
     }
 
-    /// <p>Amazon Detective is currently in preview.</p> <p>Retrieves the list of member accounts for a behavior graph. Does not return member accounts that were removed from the behavior graph.</p>
-    async fn list_members(
+    /// Presigns the request `list_invitations` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_invitations(
         &self,
-        input: ListMembersRequest,
-    ) -> Result<ListMembersResponse, RusotoError<ListMembersError>> {
-        let request_uri = "/graph/members/list";
+        presigner: &rusoto_core::Presigner,
+        input: ListInvitationsRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_invitations_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_invitations_request(&self, input: ListInvitationsRequest) -> SignedRequest {
+        let request_uri = "/invitations/list";
 
         let mut request = SignedRequest::new("POST", "detective", &self.region, &request_uri);
+        request.set_operation("ListInvitations");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("api.detective".to_string());
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `list_members`, also returning the metadata of the response.
+    pub async fn list_members_with_metadata(
+        &self,
+        input: ListMembersRequest,
+    ) -> Result<(ListMembersResponse, ResponseMetadata), RusotoError<ListMembersError>> {
+        let request = self.build_list_members_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<ListMembersResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             Err(ListMembersError::from_response(response))
         }
     }
 
-    /// <p>Amazon Detective is currently in preview.</p> <p>Rejects an invitation to contribute the account data to a behavior graph. This operation must be called by a member account that has the <code>INVITED</code> status.</p>
-    async fn reject_invitation(
+    /// Presigns the request `list_members` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_members(
         &self,
-        input: RejectInvitationRequest,
-    ) -> Result<(), RusotoError<RejectInvitationError>> {
-        let request_uri = "/invitation/removal";
+        presigner: &rusoto_core::Presigner,
+        input: ListMembersRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_members_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_members_request(&self, input: ListMembersRequest) -> SignedRequest {
+        let request_uri = "/graph/members/list";
 
         let mut request = SignedRequest::new("POST", "detective", &self.region, &request_uri);
+        request.set_operation("ListMembers");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("api.detective".to_string());
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `reject_invitation`, also returning the metadata of the response.
+    pub async fn reject_invitation_with_metadata(
+        &self,
+        input: RejectInvitationRequest,
+    ) -> Result<((), ResponseMetadata), RusotoError<RejectInvitationError>> {
+        let request = self.build_reject_invitation_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = ::std::mem::drop(response);
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             Err(RejectInvitationError::from_response(response))
         }
     }
+
+    /// Presigns the request `reject_invitation` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_reject_invitation(
+        &self,
+        presigner: &rusoto_core::Presigner,
+        input: RejectInvitationRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_reject_invitation_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_reject_invitation_request(&self, input: RejectInvitationRequest) -> SignedRequest {
+        let request_uri = "/invitation/removal";
+
+        let mut request = SignedRequest::new("POST", "detective", &self.region, &request_uri);
+        request.set_operation("RejectInvitation");
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        request.set_endpoint_prefix("api.detective".to_string());
+        let encoded = Some(serde_json::to_vec(&input).unwrap());
+        request.set_payload(encoded);
+
+        request
+    }
+}
+
+#[async_trait]
+impl Detective for DetectiveClient {
+    /// <p>Amazon Detective is currently in preview.</p> <p>Accepts an invitation for the member account to contribute data to a behavior graph. This operation can only be called by an invited member account. </p> <p>The request provides the ARN of behavior graph.</p> <p>The member account status in the graph must be <code>INVITED</code>.</p>
+    async fn accept_invitation(
+        &self,
+        input: AcceptInvitationRequest,
+    ) -> Result<(), RusotoError<AcceptInvitationError>> {
+        self.accept_invitation_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Amazon Detective is currently in preview.</p> <p>Creates a new behavior graph for the calling account, and sets that account as the master account. This operation is called by the account that is enabling Detective.</p> <p>The operation also enables Detective for the calling account in the currently selected Region. It returns the ARN of the new behavior graph.</p> <p> <code>CreateGraph</code> triggers a process to create the corresponding data tables for the new behavior graph.</p> <p>An account can only be the master account for one behavior graph within a Region. If the same account calls <code>CreateGraph</code> with the same master account, it always returns the same behavior graph ARN. It does not create a new behavior graph.</p>
+    async fn create_graph(&self) -> Result<CreateGraphResponse, RusotoError<CreateGraphError>> {
+        self.create_graph_with_metadata()
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Amazon Detective is currently in preview.</p> <p>Sends a request to invite the specified AWS accounts to be member accounts in the behavior graph. This operation can only be called by the master account for a behavior graph. </p> <p> <code>CreateMembers</code> verifies the accounts and then sends invitations to the verified accounts.</p> <p>The request provides the behavior graph ARN and the list of accounts to invite.</p> <p>The response separates the requested accounts into two lists:</p> <ul> <li> <p>The accounts that <code>CreateMembers</code> was able to start the verification for. This list includes member accounts that are being verified, that have passed verification and are being sent an invitation, and that have failed verification.</p> </li> <li> <p>The accounts that <code>CreateMembers</code> was unable to process. This list includes accounts that were already invited to be member accounts in the behavior graph.</p> </li> </ul></p>
+    async fn create_members(
+        &self,
+        input: CreateMembersRequest,
+    ) -> Result<CreateMembersResponse, RusotoError<CreateMembersError>> {
+        self.create_members_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Amazon Detective is currently in preview.</p> <p>Disables the specified behavior graph and queues it to be deleted. This operation removes the graph from each member account's list of behavior graphs.</p> <p> <code>DeleteGraph</code> can only be called by the master account for a behavior graph.</p>
+    async fn delete_graph(
+        &self,
+        input: DeleteGraphRequest,
+    ) -> Result<(), RusotoError<DeleteGraphError>> {
+        self.delete_graph_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Amazon Detective is currently in preview.</p> <p>Deletes one or more member accounts from the master account behavior graph. This operation can only be called by a Detective master account. That account cannot use <code>DeleteMembers</code> to delete their own account from the behavior graph. To disable a behavior graph, the master account uses the <code>DeleteGraph</code> API method.</p>
+    async fn delete_members(
+        &self,
+        input: DeleteMembersRequest,
+    ) -> Result<DeleteMembersResponse, RusotoError<DeleteMembersError>> {
+        self.delete_members_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Amazon Detective is currently in preview.</p> <p>Removes the member account from the specified behavior graph. This operation can only be called by a member account that has the <code>ENABLED</code> status.</p>
+    async fn disassociate_membership(
+        &self,
+        input: DisassociateMembershipRequest,
+    ) -> Result<(), RusotoError<DisassociateMembershipError>> {
+        self.disassociate_membership_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Amazon Detective is currently in preview.</p> <p>Returns the membership details for specified member accounts for a behavior graph.</p>
+    async fn get_members(
+        &self,
+        input: GetMembersRequest,
+    ) -> Result<GetMembersResponse, RusotoError<GetMembersError>> {
+        self.get_members_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Amazon Detective is currently in preview.</p> <p>Returns the list of behavior graphs that the calling account is a master of. This operation can only be called by a master account.</p> <p>Because an account can currently only be the master of one behavior graph within a Region, the results always contain a single graph.</p>
+    async fn list_graphs(
+        &self,
+        input: ListGraphsRequest,
+    ) -> Result<ListGraphsResponse, RusotoError<ListGraphsError>> {
+        self.list_graphs_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Amazon Detective is currently in preview.</p> <p>Retrieves the list of open and accepted behavior graph invitations for the member account. This operation can only be called by a member account.</p> <p>Open invitations are invitations that the member account has not responded to.</p> <p>The results do not include behavior graphs for which the member account declined the invitation. The results also do not include behavior graphs that the member account resigned from or was removed from.</p>
+    async fn list_invitations(
+        &self,
+        input: ListInvitationsRequest,
+    ) -> Result<ListInvitationsResponse, RusotoError<ListInvitationsError>> {
+        self.list_invitations_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Amazon Detective is currently in preview.</p> <p>Retrieves the list of member accounts for a behavior graph. Does not return member accounts that were removed from the behavior graph.</p>
+    async fn list_members(
+        &self,
+        input: ListMembersRequest,
+    ) -> Result<ListMembersResponse, RusotoError<ListMembersError>> {
+        self.list_members_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Amazon Detective is currently in preview.</p> <p>Rejects an invitation to contribute the account data to a behavior graph. This operation must be called by a member account that has the <code>INVITED</code> status.</p>
+    async fn reject_invitation(
+        &self,
+        input: RejectInvitationRequest,
+    ) -> Result<(), RusotoError<RejectInvitationError>> {
+        self.reject_invitation_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
 }
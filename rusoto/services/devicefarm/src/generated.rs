@@ -16,8 +16,8 @@ use std::fmt;
 use async_trait::async_trait;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
-use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest, ResponseMetadata};
+use rusoto_core::{Client, ErrorMetadata, RusotoError};
 
 use rusoto_core::proto;
 use rusoto_core::signature::SignedRequest;
@@ -3404,6 +3404,7 @@ pub struct VPCEConfiguration {
 
 /// Errors returned by CreateDevicePool
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateDevicePoolError {
     /// <p>An invalid argument was specified.</p>
     Argument(String),
@@ -3413,6 +3414,8 @@ pub enum CreateDevicePoolError {
     NotFound(String),
     /// <p>There was a problem with the service account.</p>
     ServiceAccount(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateDevicePoolError {
@@ -3420,19 +3423,25 @@ impl CreateDevicePoolError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ArgumentException" => {
-                    return RusotoError::Service(CreateDevicePoolError::Argument(err.msg))
+                    return RusotoError::service(CreateDevicePoolError::Argument(err.msg), &res)
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateDevicePoolError::LimitExceeded(err.msg))
+                    return RusotoError::service(
+                        CreateDevicePoolError::LimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(CreateDevicePoolError::NotFound(err.msg))
+                    return RusotoError::service(CreateDevicePoolError::NotFound(err.msg), &res)
                 }
                 "ServiceAccountException" => {
-                    return RusotoError::Service(CreateDevicePoolError::ServiceAccount(err.msg))
+                    return RusotoError::service(
+                        CreateDevicePoolError::ServiceAccount(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreateDevicePoolError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3446,12 +3455,14 @@ impl fmt::Display for CreateDevicePoolError {
             CreateDevicePoolError::LimitExceeded(ref cause) => write!(f, "{}", cause),
             CreateDevicePoolError::NotFound(ref cause) => write!(f, "{}", cause),
             CreateDevicePoolError::ServiceAccount(ref cause) => write!(f, "{}", cause),
+            CreateDevicePoolError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateDevicePoolError {}
 /// Errors returned by CreateInstanceProfile
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateInstanceProfileError {
     /// <p>An invalid argument was specified.</p>
     Argument(String),
@@ -3461,6 +3472,8 @@ pub enum CreateInstanceProfileError {
     NotFound(String),
     /// <p>There was a problem with the service account.</p>
     ServiceAccount(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateInstanceProfileError {
@@ -3468,21 +3481,31 @@ impl CreateInstanceProfileError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ArgumentException" => {
-                    return RusotoError::Service(CreateInstanceProfileError::Argument(err.msg))
+                    return RusotoError::service(
+                        CreateInstanceProfileError::Argument(err.msg),
+                        &res,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateInstanceProfileError::LimitExceeded(err.msg))
+                    return RusotoError::service(
+                        CreateInstanceProfileError::LimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(CreateInstanceProfileError::NotFound(err.msg))
+                    return RusotoError::service(
+                        CreateInstanceProfileError::NotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceAccountException" => {
-                    return RusotoError::Service(CreateInstanceProfileError::ServiceAccount(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateInstanceProfileError::ServiceAccount(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreateInstanceProfileError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3496,12 +3519,14 @@ impl fmt::Display for CreateInstanceProfileError {
             CreateInstanceProfileError::LimitExceeded(ref cause) => write!(f, "{}", cause),
             CreateInstanceProfileError::NotFound(ref cause) => write!(f, "{}", cause),
             CreateInstanceProfileError::ServiceAccount(ref cause) => write!(f, "{}", cause),
+            CreateInstanceProfileError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateInstanceProfileError {}
 /// Errors returned by CreateNetworkProfile
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateNetworkProfileError {
     /// <p>An invalid argument was specified.</p>
     Argument(String),
@@ -3511,6 +3536,8 @@ pub enum CreateNetworkProfileError {
     NotFound(String),
     /// <p>There was a problem with the service account.</p>
     ServiceAccount(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateNetworkProfileError {
@@ -3518,19 +3545,25 @@ impl CreateNetworkProfileError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ArgumentException" => {
-                    return RusotoError::Service(CreateNetworkProfileError::Argument(err.msg))
+                    return RusotoError::service(CreateNetworkProfileError::Argument(err.msg), &res)
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateNetworkProfileError::LimitExceeded(err.msg))
+                    return RusotoError::service(
+                        CreateNetworkProfileError::LimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(CreateNetworkProfileError::NotFound(err.msg))
+                    return RusotoError::service(CreateNetworkProfileError::NotFound(err.msg), &res)
                 }
                 "ServiceAccountException" => {
-                    return RusotoError::Service(CreateNetworkProfileError::ServiceAccount(err.msg))
+                    return RusotoError::service(
+                        CreateNetworkProfileError::ServiceAccount(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreateNetworkProfileError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3544,12 +3577,14 @@ impl fmt::Display for CreateNetworkProfileError {
             CreateNetworkProfileError::LimitExceeded(ref cause) => write!(f, "{}", cause),
             CreateNetworkProfileError::NotFound(ref cause) => write!(f, "{}", cause),
             CreateNetworkProfileError::ServiceAccount(ref cause) => write!(f, "{}", cause),
+            CreateNetworkProfileError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateNetworkProfileError {}
 /// Errors returned by CreateProject
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateProjectError {
     /// <p>An invalid argument was specified.</p>
     Argument(String),
@@ -3561,6 +3596,8 @@ pub enum CreateProjectError {
     ServiceAccount(String),
     /// <p>The operation was not successful. Try again.</p>
     TagOperation(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateProjectError {
@@ -3568,22 +3605,22 @@ impl CreateProjectError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ArgumentException" => {
-                    return RusotoError::Service(CreateProjectError::Argument(err.msg))
+                    return RusotoError::service(CreateProjectError::Argument(err.msg), &res)
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateProjectError::LimitExceeded(err.msg))
+                    return RusotoError::service(CreateProjectError::LimitExceeded(err.msg), &res)
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(CreateProjectError::NotFound(err.msg))
+                    return RusotoError::service(CreateProjectError::NotFound(err.msg), &res)
                 }
                 "ServiceAccountException" => {
-                    return RusotoError::Service(CreateProjectError::ServiceAccount(err.msg))
+                    return RusotoError::service(CreateProjectError::ServiceAccount(err.msg), &res)
                 }
                 "TagOperationException" => {
-                    return RusotoError::Service(CreateProjectError::TagOperation(err.msg))
+                    return RusotoError::service(CreateProjectError::TagOperation(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreateProjectError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3598,12 +3635,14 @@ impl fmt::Display for CreateProjectError {
             CreateProjectError::NotFound(ref cause) => write!(f, "{}", cause),
             CreateProjectError::ServiceAccount(ref cause) => write!(f, "{}", cause),
             CreateProjectError::TagOperation(ref cause) => write!(f, "{}", cause),
+            CreateProjectError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateProjectError {}
 /// Errors returned by CreateRemoteAccessSession
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateRemoteAccessSessionError {
     /// <p>An invalid argument was specified.</p>
     Argument(String),
@@ -3613,6 +3652,8 @@ pub enum CreateRemoteAccessSessionError {
     NotFound(String),
     /// <p>There was a problem with the service account.</p>
     ServiceAccount(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateRemoteAccessSessionError {
@@ -3620,23 +3661,33 @@ impl CreateRemoteAccessSessionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ArgumentException" => {
-                    return RusotoError::Service(CreateRemoteAccessSessionError::Argument(err.msg))
+                    return RusotoError::service(
+                        CreateRemoteAccessSessionError::Argument(err.msg),
+                        &res,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateRemoteAccessSessionError::LimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateRemoteAccessSessionError::LimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(CreateRemoteAccessSessionError::NotFound(err.msg))
+                    return RusotoError::service(
+                        CreateRemoteAccessSessionError::NotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceAccountException" => {
-                    return RusotoError::Service(CreateRemoteAccessSessionError::ServiceAccount(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateRemoteAccessSessionError::ServiceAccount(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(CreateRemoteAccessSessionError::Unhandled, &res)
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -3650,15 +3701,19 @@ impl fmt::Display for CreateRemoteAccessSessionError {
             CreateRemoteAccessSessionError::LimitExceeded(ref cause) => write!(f, "{}", cause),
             CreateRemoteAccessSessionError::NotFound(ref cause) => write!(f, "{}", cause),
             CreateRemoteAccessSessionError::ServiceAccount(ref cause) => write!(f, "{}", cause),
+            CreateRemoteAccessSessionError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateRemoteAccessSessionError {}
 /// Errors returned by CreateTestGridProject
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateTestGridProjectError {
     /// <p>An internal exception was raised in the service. Contact <a href="mailto:aws-devicefarm-support@amazon.com">aws-devicefarm-support@amazon.com</a> if you see this error. </p>
     InternalService(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateTestGridProjectError {
@@ -3666,12 +3721,13 @@ impl CreateTestGridProjectError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceException" => {
-                    return RusotoError::Service(CreateTestGridProjectError::InternalService(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateTestGridProjectError::InternalService(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreateTestGridProjectError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3682,12 +3738,14 @@ impl fmt::Display for CreateTestGridProjectError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             CreateTestGridProjectError::InternalService(ref cause) => write!(f, "{}", cause),
+            CreateTestGridProjectError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateTestGridProjectError {}
 /// Errors returned by CreateTestGridUrl
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateTestGridUrlError {
     /// <p>An invalid argument was specified.</p>
     Argument(String),
@@ -3695,6 +3753,8 @@ pub enum CreateTestGridUrlError {
     InternalService(String),
     /// <p>The specified entity was not found.</p>
     NotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateTestGridUrlError {
@@ -3702,16 +3762,19 @@ impl CreateTestGridUrlError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ArgumentException" => {
-                    return RusotoError::Service(CreateTestGridUrlError::Argument(err.msg))
+                    return RusotoError::service(CreateTestGridUrlError::Argument(err.msg), &res)
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(CreateTestGridUrlError::InternalService(err.msg))
+                    return RusotoError::service(
+                        CreateTestGridUrlError::InternalService(err.msg),
+                        &res,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(CreateTestGridUrlError::NotFound(err.msg))
+                    return RusotoError::service(CreateTestGridUrlError::NotFound(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreateTestGridUrlError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3724,12 +3787,14 @@ impl fmt::Display for CreateTestGridUrlError {
             CreateTestGridUrlError::Argument(ref cause) => write!(f, "{}", cause),
             CreateTestGridUrlError::InternalService(ref cause) => write!(f, "{}", cause),
             CreateTestGridUrlError::NotFound(ref cause) => write!(f, "{}", cause),
+            CreateTestGridUrlError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateTestGridUrlError {}
 /// Errors returned by CreateUpload
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateUploadError {
     /// <p>An invalid argument was specified.</p>
     Argument(String),
@@ -3739,6 +3804,8 @@ pub enum CreateUploadError {
     NotFound(String),
     /// <p>There was a problem with the service account.</p>
     ServiceAccount(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateUploadError {
@@ -3746,19 +3813,19 @@ impl CreateUploadError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ArgumentException" => {
-                    return RusotoError::Service(CreateUploadError::Argument(err.msg))
+                    return RusotoError::service(CreateUploadError::Argument(err.msg), &res)
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateUploadError::LimitExceeded(err.msg))
+                    return RusotoError::service(CreateUploadError::LimitExceeded(err.msg), &res)
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(CreateUploadError::NotFound(err.msg))
+                    return RusotoError::service(CreateUploadError::NotFound(err.msg), &res)
                 }
                 "ServiceAccountException" => {
-                    return RusotoError::Service(CreateUploadError::ServiceAccount(err.msg))
+                    return RusotoError::service(CreateUploadError::ServiceAccount(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreateUploadError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3772,12 +3839,14 @@ impl fmt::Display for CreateUploadError {
             CreateUploadError::LimitExceeded(ref cause) => write!(f, "{}", cause),
             CreateUploadError::NotFound(ref cause) => write!(f, "{}", cause),
             CreateUploadError::ServiceAccount(ref cause) => write!(f, "{}", cause),
+            CreateUploadError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateUploadError {}
 /// Errors returned by CreateVPCEConfiguration
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateVPCEConfigurationError {
     /// <p>An invalid argument was specified.</p>
     Argument(String),
@@ -3785,6 +3854,8 @@ pub enum CreateVPCEConfigurationError {
     LimitExceeded(String),
     /// <p>There was a problem with the service account.</p>
     ServiceAccount(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateVPCEConfigurationError {
@@ -3792,20 +3863,25 @@ impl CreateVPCEConfigurationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ArgumentException" => {
-                    return RusotoError::Service(CreateVPCEConfigurationError::Argument(err.msg))
+                    return RusotoError::service(
+                        CreateVPCEConfigurationError::Argument(err.msg),
+                        &res,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateVPCEConfigurationError::LimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateVPCEConfigurationError::LimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceAccountException" => {
-                    return RusotoError::Service(CreateVPCEConfigurationError::ServiceAccount(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateVPCEConfigurationError::ServiceAccount(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreateVPCEConfigurationError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3818,12 +3894,14 @@ impl fmt::Display for CreateVPCEConfigurationError {
             CreateVPCEConfigurationError::Argument(ref cause) => write!(f, "{}", cause),
             CreateVPCEConfigurationError::LimitExceeded(ref cause) => write!(f, "{}", cause),
             CreateVPCEConfigurationError::ServiceAccount(ref cause) => write!(f, "{}", cause),
+            CreateVPCEConfigurationError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateVPCEConfigurationError {}
 /// Errors returned by DeleteDevicePool
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteDevicePoolError {
     /// <p>An invalid argument was specified.</p>
     Argument(String),
@@ -3833,6 +3911,8 @@ pub enum DeleteDevicePoolError {
     NotFound(String),
     /// <p>There was a problem with the service account.</p>
     ServiceAccount(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteDevicePoolError {
@@ -3840,19 +3920,25 @@ impl DeleteDevicePoolError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ArgumentException" => {
-                    return RusotoError::Service(DeleteDevicePoolError::Argument(err.msg))
+                    return RusotoError::service(DeleteDevicePoolError::Argument(err.msg), &res)
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(DeleteDevicePoolError::LimitExceeded(err.msg))
+                    return RusotoError::service(
+                        DeleteDevicePoolError::LimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteDevicePoolError::NotFound(err.msg))
+                    return RusotoError::service(DeleteDevicePoolError::NotFound(err.msg), &res)
                 }
                 "ServiceAccountException" => {
-                    return RusotoError::Service(DeleteDevicePoolError::ServiceAccount(err.msg))
+                    return RusotoError::service(
+                        DeleteDevicePoolError::ServiceAccount(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteDevicePoolError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3866,12 +3952,14 @@ impl fmt::Display for DeleteDevicePoolError {
             DeleteDevicePoolError::LimitExceeded(ref cause) => write!(f, "{}", cause),
             DeleteDevicePoolError::NotFound(ref cause) => write!(f, "{}", cause),
             DeleteDevicePoolError::ServiceAccount(ref cause) => write!(f, "{}", cause),
+            DeleteDevicePoolError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteDevicePoolError {}
 /// Errors returned by DeleteInstanceProfile
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteInstanceProfileError {
     /// <p>An invalid argument was specified.</p>
     Argument(String),
@@ -3881,6 +3969,8 @@ pub enum DeleteInstanceProfileError {
     NotFound(String),
     /// <p>There was a problem with the service account.</p>
     ServiceAccount(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteInstanceProfileError {
@@ -3888,21 +3978,31 @@ impl DeleteInstanceProfileError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ArgumentException" => {
-                    return RusotoError::Service(DeleteInstanceProfileError::Argument(err.msg))
+                    return RusotoError::service(
+                        DeleteInstanceProfileError::Argument(err.msg),
+                        &res,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(DeleteInstanceProfileError::LimitExceeded(err.msg))
+                    return RusotoError::service(
+                        DeleteInstanceProfileError::LimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteInstanceProfileError::NotFound(err.msg))
+                    return RusotoError::service(
+                        DeleteInstanceProfileError::NotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceAccountException" => {
-                    return RusotoError::Service(DeleteInstanceProfileError::ServiceAccount(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteInstanceProfileError::ServiceAccount(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteInstanceProfileError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3916,12 +4016,14 @@ impl fmt::Display for DeleteInstanceProfileError {
             DeleteInstanceProfileError::LimitExceeded(ref cause) => write!(f, "{}", cause),
             DeleteInstanceProfileError::NotFound(ref cause) => write!(f, "{}", cause),
             DeleteInstanceProfileError::ServiceAccount(ref cause) => write!(f, "{}", cause),
+            DeleteInstanceProfileError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteInstanceProfileError {}
 /// Errors returned by DeleteNetworkProfile
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteNetworkProfileError {
     /// <p>An invalid argument was specified.</p>
     Argument(String),
@@ -3931,6 +4033,8 @@ pub enum DeleteNetworkProfileError {
     NotFound(String),
     /// <p>There was a problem with the service account.</p>
     ServiceAccount(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteNetworkProfileError {
@@ -3938,19 +4042,25 @@ impl DeleteNetworkProfileError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ArgumentException" => {
-                    return RusotoError::Service(DeleteNetworkProfileError::Argument(err.msg))
+                    return RusotoError::service(DeleteNetworkProfileError::Argument(err.msg), &res)
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(DeleteNetworkProfileError::LimitExceeded(err.msg))
+                    return RusotoError::service(
+                        DeleteNetworkProfileError::LimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteNetworkProfileError::NotFound(err.msg))
+                    return RusotoError::service(DeleteNetworkProfileError::NotFound(err.msg), &res)
                 }
                 "ServiceAccountException" => {
-                    return RusotoError::Service(DeleteNetworkProfileError::ServiceAccount(err.msg))
+                    return RusotoError::service(
+                        DeleteNetworkProfileError::ServiceAccount(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteNetworkProfileError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3964,12 +4074,14 @@ impl fmt::Display for DeleteNetworkProfileError {
             DeleteNetworkProfileError::LimitExceeded(ref cause) => write!(f, "{}", cause),
             DeleteNetworkProfileError::NotFound(ref cause) => write!(f, "{}", cause),
             DeleteNetworkProfileError::ServiceAccount(ref cause) => write!(f, "{}", cause),
+            DeleteNetworkProfileError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteNetworkProfileError {}
 /// Errors returned by DeleteProject
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteProjectError {
     /// <p>An invalid argument was specified.</p>
     Argument(String),
@@ -3979,6 +4091,8 @@ pub enum DeleteProjectError {
     NotFound(String),
     /// <p>There was a problem with the service account.</p>
     ServiceAccount(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteProjectError {
@@ -3986,19 +4100,19 @@ impl DeleteProjectError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ArgumentException" => {
-                    return RusotoError::Service(DeleteProjectError::Argument(err.msg))
+                    return RusotoError::service(DeleteProjectError::Argument(err.msg), &res)
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(DeleteProjectError::LimitExceeded(err.msg))
+                    return RusotoError::service(DeleteProjectError::LimitExceeded(err.msg), &res)
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteProjectError::NotFound(err.msg))
+                    return RusotoError::service(DeleteProjectError::NotFound(err.msg), &res)
                 }
                 "ServiceAccountException" => {
-                    return RusotoError::Service(DeleteProjectError::ServiceAccount(err.msg))
+                    return RusotoError::service(DeleteProjectError::ServiceAccount(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteProjectError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -4012,12 +4126,14 @@ impl fmt::Display for DeleteProjectError {
             DeleteProjectError::LimitExceeded(ref cause) => write!(f, "{}", cause),
             DeleteProjectError::NotFound(ref cause) => write!(f, "{}", cause),
             DeleteProjectError::ServiceAccount(ref cause) => write!(f, "{}", cause),
+            DeleteProjectError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteProjectError {}
 /// Errors returned by DeleteRemoteAccessSession
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteRemoteAccessSessionError {
     /// <p>An invalid argument was specified.</p>
     Argument(String),
@@ -4027,6 +4143,8 @@ pub enum DeleteRemoteAccessSessionError {
     NotFound(String),
     /// <p>There was a problem with the service account.</p>
     ServiceAccount(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteRemoteAccessSessionError {
@@ -4034,23 +4152,33 @@ impl DeleteRemoteAccessSessionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ArgumentException" => {
-                    return RusotoError::Service(DeleteRemoteAccessSessionError::Argument(err.msg))
+                    return RusotoError::service(
+                        DeleteRemoteAccessSessionError::Argument(err.msg),
+                        &res,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(DeleteRemoteAccessSessionError::LimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteRemoteAccessSessionError::LimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteRemoteAccessSessionError::NotFound(err.msg))
+                    return RusotoError::service(
+                        DeleteRemoteAccessSessionError::NotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceAccountException" => {
-                    return RusotoError::Service(DeleteRemoteAccessSessionError::ServiceAccount(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteRemoteAccessSessionError::ServiceAccount(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(DeleteRemoteAccessSessionError::Unhandled, &res)
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -4064,12 +4192,14 @@ impl fmt::Display for DeleteRemoteAccessSessionError {
             DeleteRemoteAccessSessionError::LimitExceeded(ref cause) => write!(f, "{}", cause),
             DeleteRemoteAccessSessionError::NotFound(ref cause) => write!(f, "{}", cause),
             DeleteRemoteAccessSessionError::ServiceAccount(ref cause) => write!(f, "{}", cause),
+            DeleteRemoteAccessSessionError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteRemoteAccessSessionError {}
 /// Errors returned by DeleteRun
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteRunError {
     /// <p>An invalid argument was specified.</p>
     Argument(String),
@@ -4079,6 +4209,8 @@ pub enum DeleteRunError {
     NotFound(String),
     /// <p>There was a problem with the service account.</p>
     ServiceAccount(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteRunError {
@@ -4086,19 +4218,19 @@ impl DeleteRunError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ArgumentException" => {
-                    return RusotoError::Service(DeleteRunError::Argument(err.msg))
+                    return RusotoError::service(DeleteRunError::Argument(err.msg), &res)
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(DeleteRunError::LimitExceeded(err.msg))
+                    return RusotoError::service(DeleteRunError::LimitExceeded(err.msg), &res)
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteRunError::NotFound(err.msg))
+                    return RusotoError::service(DeleteRunError::NotFound(err.msg), &res)
                 }
                 "ServiceAccountException" => {
-                    return RusotoError::Service(DeleteRunError::ServiceAccount(err.msg))
+                    return RusotoError::service(DeleteRunError::ServiceAccount(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteRunError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -4112,12 +4244,14 @@ impl fmt::Display for DeleteRunError {
             DeleteRunError::LimitExceeded(ref cause) => write!(f, "{}", cause),
             DeleteRunError::NotFound(ref cause) => write!(f, "{}", cause),
             DeleteRunError::ServiceAccount(ref cause) => write!(f, "{}", cause),
+            DeleteRunError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteRunError {}
 /// Errors returned by DeleteTestGridProject
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteTestGridProjectError {
     /// <p>An invalid argument was specified.</p>
     Argument(String),
@@ -4127,6 +4261,8 @@ pub enum DeleteTestGridProjectError {
     InternalService(String),
     /// <p>The specified entity was not found.</p>
     NotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteTestGridProjectError {
@@ -4134,21 +4270,31 @@ impl DeleteTestGridProjectError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ArgumentException" => {
-                    return RusotoError::Service(DeleteTestGridProjectError::Argument(err.msg))
+                    return RusotoError::service(
+                        DeleteTestGridProjectError::Argument(err.msg),
+                        &res,
+                    )
                 }
                 "CannotDeleteException" => {
-                    return RusotoError::Service(DeleteTestGridProjectError::CannotDelete(err.msg))
+                    return RusotoError::service(
+                        DeleteTestGridProjectError::CannotDelete(err.msg),
+                        &res,
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(DeleteTestGridProjectError::InternalService(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteTestGridProjectError::InternalService(err.msg),
+                        &res,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteTestGridProjectError::NotFound(err.msg))
+                    return RusotoError::service(
+                        DeleteTestGridProjectError::NotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteTestGridProjectError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -4162,12 +4308,14 @@ impl fmt::Display for DeleteTestGridProjectError {
             DeleteTestGridProjectError::CannotDelete(ref cause) => write!(f, "{}", cause),
             DeleteTestGridProjectError::InternalService(ref cause) => write!(f, "{}", cause),
             DeleteTestGridProjectError::NotFound(ref cause) => write!(f, "{}", cause),
+            DeleteTestGridProjectError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteTestGridProjectError {}
 /// Errors returned by DeleteUpload
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteUploadError {
     /// <p>An invalid argument was specified.</p>
     Argument(String),
@@ -4177,6 +4325,8 @@ pub enum DeleteUploadError {
     NotFound(String),
     /// <p>There was a problem with the service account.</p>
     ServiceAccount(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteUploadError {
@@ -4184,19 +4334,19 @@ impl DeleteUploadError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ArgumentException" => {
-                    return RusotoError::Service(DeleteUploadError::Argument(err.msg))
+                    return RusotoError::service(DeleteUploadError::Argument(err.msg), &res)
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(DeleteUploadError::LimitExceeded(err.msg))
+                    return RusotoError::service(DeleteUploadError::LimitExceeded(err.msg), &res)
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteUploadError::NotFound(err.msg))
+                    return RusotoError::service(DeleteUploadError::NotFound(err.msg), &res)
                 }
                 "ServiceAccountException" => {
-                    return RusotoError::Service(DeleteUploadError::ServiceAccount(err.msg))
+                    return RusotoError::service(DeleteUploadError::ServiceAccount(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteUploadError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -4210,12 +4360,14 @@ impl fmt::Display for DeleteUploadError {
             DeleteUploadError::LimitExceeded(ref cause) => write!(f, "{}", cause),
             DeleteUploadError::NotFound(ref cause) => write!(f, "{}", cause),
             DeleteUploadError::ServiceAccount(ref cause) => write!(f, "{}", cause),
+            DeleteUploadError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteUploadError {}
 /// Errors returned by DeleteVPCEConfiguration
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteVPCEConfigurationError {
     /// <p>An invalid argument was specified.</p>
     Argument(String),
@@ -4225,6 +4377,8 @@ pub enum DeleteVPCEConfigurationError {
     NotFound(String),
     /// <p>There was a problem with the service account.</p>
     ServiceAccount(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteVPCEConfigurationError {
@@ -4232,23 +4386,31 @@ impl DeleteVPCEConfigurationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ArgumentException" => {
-                    return RusotoError::Service(DeleteVPCEConfigurationError::Argument(err.msg))
+                    return RusotoError::service(
+                        DeleteVPCEConfigurationError::Argument(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidOperationException" => {
-                    return RusotoError::Service(DeleteVPCEConfigurationError::InvalidOperation(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteVPCEConfigurationError::InvalidOperation(err.msg),
+                        &res,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteVPCEConfigurationError::NotFound(err.msg))
+                    return RusotoError::service(
+                        DeleteVPCEConfigurationError::NotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceAccountException" => {
-                    return RusotoError::Service(DeleteVPCEConfigurationError::ServiceAccount(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteVPCEConfigurationError::ServiceAccount(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteVPCEConfigurationError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -4262,12 +4424,14 @@ impl fmt::Display for DeleteVPCEConfigurationError {
             DeleteVPCEConfigurationError::InvalidOperation(ref cause) => write!(f, "{}", cause),
             DeleteVPCEConfigurationError::NotFound(ref cause) => write!(f, "{}", cause),
             DeleteVPCEConfigurationError::ServiceAccount(ref cause) => write!(f, "{}", cause),
+            DeleteVPCEConfigurationError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteVPCEConfigurationError {}
 /// Errors returned by GetAccountSettings
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetAccountSettingsError {
     /// <p>An invalid argument was specified.</p>
     Argument(String),
@@ -4277,6 +4441,8 @@ pub enum GetAccountSettingsError {
     NotFound(String),
     /// <p>There was a problem with the service account.</p>
     ServiceAccount(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetAccountSettingsError {
@@ -4284,19 +4450,25 @@ impl GetAccountSettingsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ArgumentException" => {
-                    return RusotoError::Service(GetAccountSettingsError::Argument(err.msg))
+                    return RusotoError::service(GetAccountSettingsError::Argument(err.msg), &res)
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(GetAccountSettingsError::LimitExceeded(err.msg))
+                    return RusotoError::service(
+                        GetAccountSettingsError::LimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetAccountSettingsError::NotFound(err.msg))
+                    return RusotoError::service(GetAccountSettingsError::NotFound(err.msg), &res)
                 }
                 "ServiceAccountException" => {
-                    return RusotoError::Service(GetAccountSettingsError::ServiceAccount(err.msg))
+                    return RusotoError::service(
+                        GetAccountSettingsError::ServiceAccount(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetAccountSettingsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -4310,12 +4482,14 @@ impl fmt::Display for GetAccountSettingsError {
             GetAccountSettingsError::LimitExceeded(ref cause) => write!(f, "{}", cause),
             GetAccountSettingsError::NotFound(ref cause) => write!(f, "{}", cause),
             GetAccountSettingsError::ServiceAccount(ref cause) => write!(f, "{}", cause),
+            GetAccountSettingsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetAccountSettingsError {}
 /// Errors returned by GetDevice
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetDeviceError {
     /// <p>An invalid argument was specified.</p>
     Argument(String),
@@ -4325,6 +4499,8 @@ pub enum GetDeviceError {
     NotFound(String),
     /// <p>There was a problem with the service account.</p>
     ServiceAccount(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetDeviceError {
@@ -4332,19 +4508,19 @@ impl GetDeviceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ArgumentException" => {
-                    return RusotoError::Service(GetDeviceError::Argument(err.msg))
+                    return RusotoError::service(GetDeviceError::Argument(err.msg), &res)
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(GetDeviceError::LimitExceeded(err.msg))
+                    return RusotoError::service(GetDeviceError::LimitExceeded(err.msg), &res)
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetDeviceError::NotFound(err.msg))
+                    return RusotoError::service(GetDeviceError::NotFound(err.msg), &res)
                 }
                 "ServiceAccountException" => {
-                    return RusotoError::Service(GetDeviceError::ServiceAccount(err.msg))
+                    return RusotoError::service(GetDeviceError::ServiceAccount(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetDeviceError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -4358,12 +4534,14 @@ impl fmt::Display for GetDeviceError {
             GetDeviceError::LimitExceeded(ref cause) => write!(f, "{}", cause),
             GetDeviceError::NotFound(ref cause) => write!(f, "{}", cause),
             GetDeviceError::ServiceAccount(ref cause) => write!(f, "{}", cause),
+            GetDeviceError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetDeviceError {}
 /// Errors returned by GetDeviceInstance
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetDeviceInstanceError {
     /// <p>An invalid argument was specified.</p>
     Argument(String),
@@ -4373,6 +4551,8 @@ pub enum GetDeviceInstanceError {
     NotFound(String),
     /// <p>There was a problem with the service account.</p>
     ServiceAccount(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetDeviceInstanceError {
@@ -4380,19 +4560,25 @@ impl GetDeviceInstanceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ArgumentException" => {
-                    return RusotoError::Service(GetDeviceInstanceError::Argument(err.msg))
+                    return RusotoError::service(GetDeviceInstanceError::Argument(err.msg), &res)
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(GetDeviceInstanceError::LimitExceeded(err.msg))
+                    return RusotoError::service(
+                        GetDeviceInstanceError::LimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetDeviceInstanceError::NotFound(err.msg))
+                    return RusotoError::service(GetDeviceInstanceError::NotFound(err.msg), &res)
                 }
                 "ServiceAccountException" => {
-                    return RusotoError::Service(GetDeviceInstanceError::ServiceAccount(err.msg))
+                    return RusotoError::service(
+                        GetDeviceInstanceError::ServiceAccount(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetDeviceInstanceError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -4406,12 +4592,14 @@ impl fmt::Display for GetDeviceInstanceError {
             GetDeviceInstanceError::LimitExceeded(ref cause) => write!(f, "{}", cause),
             GetDeviceInstanceError::NotFound(ref cause) => write!(f, "{}", cause),
             GetDeviceInstanceError::ServiceAccount(ref cause) => write!(f, "{}", cause),
+            GetDeviceInstanceError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetDeviceInstanceError {}
 /// Errors returned by GetDevicePool
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetDevicePoolError {
     /// <p>An invalid argument was specified.</p>
     Argument(String),
@@ -4421,6 +4609,8 @@ pub enum GetDevicePoolError {
     NotFound(String),
     /// <p>There was a problem with the service account.</p>
     ServiceAccount(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetDevicePoolError {
@@ -4428,19 +4618,19 @@ impl GetDevicePoolError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ArgumentException" => {
-                    return RusotoError::Service(GetDevicePoolError::Argument(err.msg))
+                    return RusotoError::service(GetDevicePoolError::Argument(err.msg), &res)
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(GetDevicePoolError::LimitExceeded(err.msg))
+                    return RusotoError::service(GetDevicePoolError::LimitExceeded(err.msg), &res)
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetDevicePoolError::NotFound(err.msg))
+                    return RusotoError::service(GetDevicePoolError::NotFound(err.msg), &res)
                 }
                 "ServiceAccountException" => {
-                    return RusotoError::Service(GetDevicePoolError::ServiceAccount(err.msg))
+                    return RusotoError::service(GetDevicePoolError::ServiceAccount(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetDevicePoolError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -4454,12 +4644,14 @@ impl fmt::Display for GetDevicePoolError {
             GetDevicePoolError::LimitExceeded(ref cause) => write!(f, "{}", cause),
             GetDevicePoolError::NotFound(ref cause) => write!(f, "{}", cause),
             GetDevicePoolError::ServiceAccount(ref cause) => write!(f, "{}", cause),
+            GetDevicePoolError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetDevicePoolError {}
 /// Errors returned by GetDevicePoolCompatibility
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetDevicePoolCompatibilityError {
     /// <p>An invalid argument was specified.</p>
     Argument(String),
@@ -4469,6 +4661,8 @@ pub enum GetDevicePoolCompatibilityError {
     NotFound(String),
     /// <p>There was a problem with the service account.</p>
     ServiceAccount(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetDevicePoolCompatibilityError {
@@ -4478,23 +4672,33 @@ impl GetDevicePoolCompatibilityError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ArgumentException" => {
-                    return RusotoError::Service(GetDevicePoolCompatibilityError::Argument(err.msg))
+                    return RusotoError::service(
+                        GetDevicePoolCompatibilityError::Argument(err.msg),
+                        &res,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(GetDevicePoolCompatibilityError::LimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetDevicePoolCompatibilityError::LimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetDevicePoolCompatibilityError::NotFound(err.msg))
+                    return RusotoError::service(
+                        GetDevicePoolCompatibilityError::NotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceAccountException" => {
-                    return RusotoError::Service(GetDevicePoolCompatibilityError::ServiceAccount(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetDevicePoolCompatibilityError::ServiceAccount(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(GetDevicePoolCompatibilityError::Unhandled, &res)
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -4508,12 +4712,14 @@ impl fmt::Display for GetDevicePoolCompatibilityError {
             GetDevicePoolCompatibilityError::LimitExceeded(ref cause) => write!(f, "{}", cause),
             GetDevicePoolCompatibilityError::NotFound(ref cause) => write!(f, "{}", cause),
             GetDevicePoolCompatibilityError::ServiceAccount(ref cause) => write!(f, "{}", cause),
+            GetDevicePoolCompatibilityError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetDevicePoolCompatibilityError {}
 /// Errors returned by GetInstanceProfile
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetInstanceProfileError {
     /// <p>An invalid argument was specified.</p>
     Argument(String),
@@ -4523,6 +4729,8 @@ pub enum GetInstanceProfileError {
     NotFound(String),
     /// <p>There was a problem with the service account.</p>
     ServiceAccount(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetInstanceProfileError {
@@ -4530,19 +4738,25 @@ impl GetInstanceProfileError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ArgumentException" => {
-                    return RusotoError::Service(GetInstanceProfileError::Argument(err.msg))
+                    return RusotoError::service(GetInstanceProfileError::Argument(err.msg), &res)
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(GetInstanceProfileError::LimitExceeded(err.msg))
+                    return RusotoError::service(
+                        GetInstanceProfileError::LimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetInstanceProfileError::NotFound(err.msg))
+                    return RusotoError::service(GetInstanceProfileError::NotFound(err.msg), &res)
                 }
                 "ServiceAccountException" => {
-                    return RusotoError::Service(GetInstanceProfileError::ServiceAccount(err.msg))
+                    return RusotoError::service(
+                        GetInstanceProfileError::ServiceAccount(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetInstanceProfileError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -4556,12 +4770,14 @@ impl fmt::Display for GetInstanceProfileError {
             GetInstanceProfileError::LimitExceeded(ref cause) => write!(f, "{}", cause),
             GetInstanceProfileError::NotFound(ref cause) => write!(f, "{}", cause),
             GetInstanceProfileError::ServiceAccount(ref cause) => write!(f, "{}", cause),
+            GetInstanceProfileError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetInstanceProfileError {}
 /// Errors returned by GetJob
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetJobError {
     /// <p>An invalid argument was specified.</p>
     Argument(String),
@@ -4571,22 +4787,28 @@ pub enum GetJobError {
     NotFound(String),
     /// <p>There was a problem with the service account.</p>
     ServiceAccount(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetJobError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<GetJobError> {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
-                "ArgumentException" => return RusotoError::Service(GetJobError::Argument(err.msg)),
+                "ArgumentException" => {
+                    return RusotoError::service(GetJobError::Argument(err.msg), &res)
+                }
                 "LimitExceededException" => {
-                    return RusotoError::Service(GetJobError::LimitExceeded(err.msg))
+                    return RusotoError::service(GetJobError::LimitExceeded(err.msg), &res)
+                }
+                "NotFoundException" => {
+                    return RusotoError::service(GetJobError::NotFound(err.msg), &res)
                 }
-                "NotFoundException" => return RusotoError::Service(GetJobError::NotFound(err.msg)),
                 "ServiceAccountException" => {
-                    return RusotoError::Service(GetJobError::ServiceAccount(err.msg))
+                    return RusotoError::service(GetJobError::ServiceAccount(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetJobError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -4600,12 +4822,14 @@ impl fmt::Display for GetJobError {
             GetJobError::LimitExceeded(ref cause) => write!(f, "{}", cause),
             GetJobError::NotFound(ref cause) => write!(f, "{}", cause),
             GetJobError::ServiceAccount(ref cause) => write!(f, "{}", cause),
+            GetJobError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetJobError {}
 /// Errors returned by GetNetworkProfile
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetNetworkProfileError {
     /// <p>An invalid argument was specified.</p>
     Argument(String),
@@ -4615,6 +4839,8 @@ pub enum GetNetworkProfileError {
     NotFound(String),
     /// <p>There was a problem with the service account.</p>
     ServiceAccount(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetNetworkProfileError {
@@ -4622,19 +4848,25 @@ impl GetNetworkProfileError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ArgumentException" => {
-                    return RusotoError::Service(GetNetworkProfileError::Argument(err.msg))
+                    return RusotoError::service(GetNetworkProfileError::Argument(err.msg), &res)
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(GetNetworkProfileError::LimitExceeded(err.msg))
+                    return RusotoError::service(
+                        GetNetworkProfileError::LimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetNetworkProfileError::NotFound(err.msg))
+                    return RusotoError::service(GetNetworkProfileError::NotFound(err.msg), &res)
                 }
                 "ServiceAccountException" => {
-                    return RusotoError::Service(GetNetworkProfileError::ServiceAccount(err.msg))
+                    return RusotoError::service(
+                        GetNetworkProfileError::ServiceAccount(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetNetworkProfileError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -4648,12 +4880,14 @@ impl fmt::Display for GetNetworkProfileError {
             GetNetworkProfileError::LimitExceeded(ref cause) => write!(f, "{}", cause),
             GetNetworkProfileError::NotFound(ref cause) => write!(f, "{}", cause),
             GetNetworkProfileError::ServiceAccount(ref cause) => write!(f, "{}", cause),
+            GetNetworkProfileError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetNetworkProfileError {}
 /// Errors returned by GetOfferingStatus
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetOfferingStatusError {
     /// <p>An invalid argument was specified.</p>
     Argument(String),
@@ -4665,6 +4899,8 @@ pub enum GetOfferingStatusError {
     NotFound(String),
     /// <p>There was a problem with the service account.</p>
     ServiceAccount(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetOfferingStatusError {
@@ -4672,22 +4908,28 @@ impl GetOfferingStatusError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ArgumentException" => {
-                    return RusotoError::Service(GetOfferingStatusError::Argument(err.msg))
+                    return RusotoError::service(GetOfferingStatusError::Argument(err.msg), &res)
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(GetOfferingStatusError::LimitExceeded(err.msg))
+                    return RusotoError::service(
+                        GetOfferingStatusError::LimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "NotEligibleException" => {
-                    return RusotoError::Service(GetOfferingStatusError::NotEligible(err.msg))
+                    return RusotoError::service(GetOfferingStatusError::NotEligible(err.msg), &res)
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetOfferingStatusError::NotFound(err.msg))
+                    return RusotoError::service(GetOfferingStatusError::NotFound(err.msg), &res)
                 }
                 "ServiceAccountException" => {
-                    return RusotoError::Service(GetOfferingStatusError::ServiceAccount(err.msg))
+                    return RusotoError::service(
+                        GetOfferingStatusError::ServiceAccount(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetOfferingStatusError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -4702,12 +4944,14 @@ impl fmt::Display for GetOfferingStatusError {
             GetOfferingStatusError::NotEligible(ref cause) => write!(f, "{}", cause),
             GetOfferingStatusError::NotFound(ref cause) => write!(f, "{}", cause),
             GetOfferingStatusError::ServiceAccount(ref cause) => write!(f, "{}", cause),
+            GetOfferingStatusError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetOfferingStatusError {}
 /// Errors returned by GetProject
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetProjectError {
     /// <p>An invalid argument was specified.</p>
     Argument(String),
@@ -4717,6 +4961,8 @@ pub enum GetProjectError {
     NotFound(String),
     /// <p>There was a problem with the service account.</p>
     ServiceAccount(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetProjectError {
@@ -4724,19 +4970,19 @@ impl GetProjectError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ArgumentException" => {
-                    return RusotoError::Service(GetProjectError::Argument(err.msg))
+                    return RusotoError::service(GetProjectError::Argument(err.msg), &res)
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(GetProjectError::LimitExceeded(err.msg))
+                    return RusotoError::service(GetProjectError::LimitExceeded(err.msg), &res)
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetProjectError::NotFound(err.msg))
+                    return RusotoError::service(GetProjectError::NotFound(err.msg), &res)
                 }
                 "ServiceAccountException" => {
-                    return RusotoError::Service(GetProjectError::ServiceAccount(err.msg))
+                    return RusotoError::service(GetProjectError::ServiceAccount(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetProjectError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -4750,12 +4996,14 @@ impl fmt::Display for GetProjectError {
             GetProjectError::LimitExceeded(ref cause) => write!(f, "{}", cause),
             GetProjectError::NotFound(ref cause) => write!(f, "{}", cause),
             GetProjectError::ServiceAccount(ref cause) => write!(f, "{}", cause),
+            GetProjectError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetProjectError {}
 /// Errors returned by GetRemoteAccessSession
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetRemoteAccessSessionError {
     /// <p>An invalid argument was specified.</p>
     Argument(String),
@@ -4765,6 +5013,8 @@ pub enum GetRemoteAccessSessionError {
     NotFound(String),
     /// <p>There was a problem with the service account.</p>
     ServiceAccount(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetRemoteAccessSessionError {
@@ -4772,23 +5022,31 @@ impl GetRemoteAccessSessionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ArgumentException" => {
-                    return RusotoError::Service(GetRemoteAccessSessionError::Argument(err.msg))
+                    return RusotoError::service(
+                        GetRemoteAccessSessionError::Argument(err.msg),
+                        &res,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(GetRemoteAccessSessionError::LimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetRemoteAccessSessionError::LimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetRemoteAccessSessionError::NotFound(err.msg))
+                    return RusotoError::service(
+                        GetRemoteAccessSessionError::NotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceAccountException" => {
-                    return RusotoError::Service(GetRemoteAccessSessionError::ServiceAccount(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetRemoteAccessSessionError::ServiceAccount(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetRemoteAccessSessionError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -4802,12 +5060,14 @@ impl fmt::Display for GetRemoteAccessSessionError {
             GetRemoteAccessSessionError::LimitExceeded(ref cause) => write!(f, "{}", cause),
             GetRemoteAccessSessionError::NotFound(ref cause) => write!(f, "{}", cause),
             GetRemoteAccessSessionError::ServiceAccount(ref cause) => write!(f, "{}", cause),
+            GetRemoteAccessSessionError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetRemoteAccessSessionError {}
 /// Errors returned by GetRun
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetRunError {
     /// <p>An invalid argument was specified.</p>
     Argument(String),
@@ -4817,22 +5077,28 @@ pub enum GetRunError {
     NotFound(String),
     /// <p>There was a problem with the service account.</p>
     ServiceAccount(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetRunError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<GetRunError> {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
-                "ArgumentException" => return RusotoError::Service(GetRunError::Argument(err.msg)),
+                "ArgumentException" => {
+                    return RusotoError::service(GetRunError::Argument(err.msg), &res)
+                }
                 "LimitExceededException" => {
-                    return RusotoError::Service(GetRunError::LimitExceeded(err.msg))
+                    return RusotoError::service(GetRunError::LimitExceeded(err.msg), &res)
+                }
+                "NotFoundException" => {
+                    return RusotoError::service(GetRunError::NotFound(err.msg), &res)
                 }
-                "NotFoundException" => return RusotoError::Service(GetRunError::NotFound(err.msg)),
                 "ServiceAccountException" => {
-                    return RusotoError::Service(GetRunError::ServiceAccount(err.msg))
+                    return RusotoError::service(GetRunError::ServiceAccount(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetRunError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -4846,12 +5112,14 @@ impl fmt::Display for GetRunError {
             GetRunError::LimitExceeded(ref cause) => write!(f, "{}", cause),
             GetRunError::NotFound(ref cause) => write!(f, "{}", cause),
             GetRunError::ServiceAccount(ref cause) => write!(f, "{}", cause),
+            GetRunError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetRunError {}
 /// Errors returned by GetSuite
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetSuiteError {
     /// <p>An invalid argument was specified.</p>
     Argument(String),
@@ -4861,6 +5129,8 @@ pub enum GetSuiteError {
     NotFound(String),
     /// <p>There was a problem with the service account.</p>
     ServiceAccount(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetSuiteError {
@@ -4868,19 +5138,19 @@ impl GetSuiteError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ArgumentException" => {
-                    return RusotoError::Service(GetSuiteError::Argument(err.msg))
+                    return RusotoError::service(GetSuiteError::Argument(err.msg), &res)
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(GetSuiteError::LimitExceeded(err.msg))
+                    return RusotoError::service(GetSuiteError::LimitExceeded(err.msg), &res)
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetSuiteError::NotFound(err.msg))
+                    return RusotoError::service(GetSuiteError::NotFound(err.msg), &res)
                 }
                 "ServiceAccountException" => {
-                    return RusotoError::Service(GetSuiteError::ServiceAccount(err.msg))
+                    return RusotoError::service(GetSuiteError::ServiceAccount(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetSuiteError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -4894,12 +5164,14 @@ impl fmt::Display for GetSuiteError {
             GetSuiteError::LimitExceeded(ref cause) => write!(f, "{}", cause),
             GetSuiteError::NotFound(ref cause) => write!(f, "{}", cause),
             GetSuiteError::ServiceAccount(ref cause) => write!(f, "{}", cause),
+            GetSuiteError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetSuiteError {}
 /// Errors returned by GetTest
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetTestError {
     /// <p>An invalid argument was specified.</p>
     Argument(String),
@@ -4909,6 +5181,8 @@ pub enum GetTestError {
     NotFound(String),
     /// <p>There was a problem with the service account.</p>
     ServiceAccount(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetTestError {
@@ -4916,19 +5190,19 @@ impl GetTestError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ArgumentException" => {
-                    return RusotoError::Service(GetTestError::Argument(err.msg))
+                    return RusotoError::service(GetTestError::Argument(err.msg), &res)
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(GetTestError::LimitExceeded(err.msg))
+                    return RusotoError::service(GetTestError::LimitExceeded(err.msg), &res)
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetTestError::NotFound(err.msg))
+                    return RusotoError::service(GetTestError::NotFound(err.msg), &res)
                 }
                 "ServiceAccountException" => {
-                    return RusotoError::Service(GetTestError::ServiceAccount(err.msg))
+                    return RusotoError::service(GetTestError::ServiceAccount(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetTestError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -4942,12 +5216,14 @@ impl fmt::Display for GetTestError {
             GetTestError::LimitExceeded(ref cause) => write!(f, "{}", cause),
             GetTestError::NotFound(ref cause) => write!(f, "{}", cause),
             GetTestError::ServiceAccount(ref cause) => write!(f, "{}", cause),
+            GetTestError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetTestError {}
 /// Errors returned by GetTestGridProject
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetTestGridProjectError {
     /// <p>An invalid argument was specified.</p>
     Argument(String),
@@ -4955,6 +5231,8 @@ pub enum GetTestGridProjectError {
     InternalService(String),
     /// <p>The specified entity was not found.</p>
     NotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetTestGridProjectError {
@@ -4962,16 +5240,19 @@ impl GetTestGridProjectError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ArgumentException" => {
-                    return RusotoError::Service(GetTestGridProjectError::Argument(err.msg))
+                    return RusotoError::service(GetTestGridProjectError::Argument(err.msg), &res)
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(GetTestGridProjectError::InternalService(err.msg))
+                    return RusotoError::service(
+                        GetTestGridProjectError::InternalService(err.msg),
+                        &res,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetTestGridProjectError::NotFound(err.msg))
+                    return RusotoError::service(GetTestGridProjectError::NotFound(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetTestGridProjectError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -4984,12 +5265,14 @@ impl fmt::Display for GetTestGridProjectError {
             GetTestGridProjectError::Argument(ref cause) => write!(f, "{}", cause),
             GetTestGridProjectError::InternalService(ref cause) => write!(f, "{}", cause),
             GetTestGridProjectError::NotFound(ref cause) => write!(f, "{}", cause),
+            GetTestGridProjectError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetTestGridProjectError {}
 /// Errors returned by GetTestGridSession
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetTestGridSessionError {
     /// <p>An invalid argument was specified.</p>
     Argument(String),
@@ -4997,6 +5280,8 @@ pub enum GetTestGridSessionError {
     InternalService(String),
     /// <p>The specified entity was not found.</p>
     NotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetTestGridSessionError {
@@ -5004,16 +5289,19 @@ impl GetTestGridSessionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ArgumentException" => {
-                    return RusotoError::Service(GetTestGridSessionError::Argument(err.msg))
+                    return RusotoError::service(GetTestGridSessionError::Argument(err.msg), &res)
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(GetTestGridSessionError::InternalService(err.msg))
+                    return RusotoError::service(
+                        GetTestGridSessionError::InternalService(err.msg),
+                        &res,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetTestGridSessionError::NotFound(err.msg))
+                    return RusotoError::service(GetTestGridSessionError::NotFound(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetTestGridSessionError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -5026,12 +5314,14 @@ impl fmt::Display for GetTestGridSessionError {
             GetTestGridSessionError::Argument(ref cause) => write!(f, "{}", cause),
             GetTestGridSessionError::InternalService(ref cause) => write!(f, "{}", cause),
             GetTestGridSessionError::NotFound(ref cause) => write!(f, "{}", cause),
+            GetTestGridSessionError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetTestGridSessionError {}
 /// Errors returned by GetUpload
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetUploadError {
     /// <p>An invalid argument was specified.</p>
     Argument(String),
@@ -5041,6 +5331,8 @@ pub enum GetUploadError {
     NotFound(String),
     /// <p>There was a problem with the service account.</p>
     ServiceAccount(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetUploadError {
@@ -5048,19 +5340,19 @@ impl GetUploadError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ArgumentException" => {
-                    return RusotoError::Service(GetUploadError::Argument(err.msg))
+                    return RusotoError::service(GetUploadError::Argument(err.msg), &res)
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(GetUploadError::LimitExceeded(err.msg))
+                    return RusotoError::service(GetUploadError::LimitExceeded(err.msg), &res)
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetUploadError::NotFound(err.msg))
+                    return RusotoError::service(GetUploadError::NotFound(err.msg), &res)
                 }
                 "ServiceAccountException" => {
-                    return RusotoError::Service(GetUploadError::ServiceAccount(err.msg))
+                    return RusotoError::service(GetUploadError::ServiceAccount(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetUploadError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -5074,12 +5366,14 @@ impl fmt::Display for GetUploadError {
             GetUploadError::LimitExceeded(ref cause) => write!(f, "{}", cause),
             GetUploadError::NotFound(ref cause) => write!(f, "{}", cause),
             GetUploadError::ServiceAccount(ref cause) => write!(f, "{}", cause),
+            GetUploadError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetUploadError {}
 /// Errors returned by GetVPCEConfiguration
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetVPCEConfigurationError {
     /// <p>An invalid argument was specified.</p>
     Argument(String),
@@ -5087,6 +5381,8 @@ pub enum GetVPCEConfigurationError {
     NotFound(String),
     /// <p>There was a problem with the service account.</p>
     ServiceAccount(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetVPCEConfigurationError {
@@ -5094,16 +5390,19 @@ impl GetVPCEConfigurationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ArgumentException" => {
-                    return RusotoError::Service(GetVPCEConfigurationError::Argument(err.msg))
+                    return RusotoError::service(GetVPCEConfigurationError::Argument(err.msg), &res)
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetVPCEConfigurationError::NotFound(err.msg))
+                    return RusotoError::service(GetVPCEConfigurationError::NotFound(err.msg), &res)
                 }
                 "ServiceAccountException" => {
-                    return RusotoError::Service(GetVPCEConfigurationError::ServiceAccount(err.msg))
+                    return RusotoError::service(
+                        GetVPCEConfigurationError::ServiceAccount(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetVPCEConfigurationError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -5116,12 +5415,14 @@ impl fmt::Display for GetVPCEConfigurationError {
             GetVPCEConfigurationError::Argument(ref cause) => write!(f, "{}", cause),
             GetVPCEConfigurationError::NotFound(ref cause) => write!(f, "{}", cause),
             GetVPCEConfigurationError::ServiceAccount(ref cause) => write!(f, "{}", cause),
+            GetVPCEConfigurationError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetVPCEConfigurationError {}
 /// Errors returned by InstallToRemoteAccessSession
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum InstallToRemoteAccessSessionError {
     /// <p>An invalid argument was specified.</p>
     Argument(String),
@@ -5131,6 +5432,8 @@ pub enum InstallToRemoteAccessSessionError {
     NotFound(String),
     /// <p>There was a problem with the service account.</p>
     ServiceAccount(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl InstallToRemoteAccessSessionError {
@@ -5140,27 +5443,36 @@ impl InstallToRemoteAccessSessionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ArgumentException" => {
-                    return RusotoError::Service(InstallToRemoteAccessSessionError::Argument(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        InstallToRemoteAccessSessionError::Argument(err.msg),
+                        &res,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(InstallToRemoteAccessSessionError::LimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        InstallToRemoteAccessSessionError::LimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(InstallToRemoteAccessSessionError::NotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        InstallToRemoteAccessSessionError::NotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceAccountException" => {
-                    return RusotoError::Service(InstallToRemoteAccessSessionError::ServiceAccount(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        InstallToRemoteAccessSessionError::ServiceAccount(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        InstallToRemoteAccessSessionError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -5174,12 +5486,14 @@ impl fmt::Display for InstallToRemoteAccessSessionError {
             InstallToRemoteAccessSessionError::LimitExceeded(ref cause) => write!(f, "{}", cause),
             InstallToRemoteAccessSessionError::NotFound(ref cause) => write!(f, "{}", cause),
             InstallToRemoteAccessSessionError::ServiceAccount(ref cause) => write!(f, "{}", cause),
+            InstallToRemoteAccessSessionError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for InstallToRemoteAccessSessionError {}
 /// Errors returned by ListArtifacts
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListArtifactsError {
     /// <p>An invalid argument was specified.</p>
     Argument(String),
@@ -5189,6 +5503,8 @@ pub enum ListArtifactsError {
     NotFound(String),
     /// <p>There was a problem with the service account.</p>
     ServiceAccount(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListArtifactsError {
@@ -5196,19 +5512,19 @@ impl ListArtifactsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ArgumentException" => {
-                    return RusotoError::Service(ListArtifactsError::Argument(err.msg))
+                    return RusotoError::service(ListArtifactsError::Argument(err.msg), &res)
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(ListArtifactsError::LimitExceeded(err.msg))
+                    return RusotoError::service(ListArtifactsError::LimitExceeded(err.msg), &res)
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(ListArtifactsError::NotFound(err.msg))
+                    return RusotoError::service(ListArtifactsError::NotFound(err.msg), &res)
                 }
                 "ServiceAccountException" => {
-                    return RusotoError::Service(ListArtifactsError::ServiceAccount(err.msg))
+                    return RusotoError::service(ListArtifactsError::ServiceAccount(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListArtifactsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -5222,12 +5538,14 @@ impl fmt::Display for ListArtifactsError {
             ListArtifactsError::LimitExceeded(ref cause) => write!(f, "{}", cause),
             ListArtifactsError::NotFound(ref cause) => write!(f, "{}", cause),
             ListArtifactsError::ServiceAccount(ref cause) => write!(f, "{}", cause),
+            ListArtifactsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListArtifactsError {}
 /// Errors returned by ListDeviceInstances
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListDeviceInstancesError {
     /// <p>An invalid argument was specified.</p>
     Argument(String),
@@ -5237,6 +5555,8 @@ pub enum ListDeviceInstancesError {
     NotFound(String),
     /// <p>There was a problem with the service account.</p>
     ServiceAccount(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListDeviceInstancesError {
@@ -5244,19 +5564,25 @@ impl ListDeviceInstancesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ArgumentException" => {
-                    return RusotoError::Service(ListDeviceInstancesError::Argument(err.msg))
+                    return RusotoError::service(ListDeviceInstancesError::Argument(err.msg), &res)
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(ListDeviceInstancesError::LimitExceeded(err.msg))
+                    return RusotoError::service(
+                        ListDeviceInstancesError::LimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(ListDeviceInstancesError::NotFound(err.msg))
+                    return RusotoError::service(ListDeviceInstancesError::NotFound(err.msg), &res)
                 }
                 "ServiceAccountException" => {
-                    return RusotoError::Service(ListDeviceInstancesError::ServiceAccount(err.msg))
+                    return RusotoError::service(
+                        ListDeviceInstancesError::ServiceAccount(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListDeviceInstancesError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -5270,12 +5596,14 @@ impl fmt::Display for ListDeviceInstancesError {
             ListDeviceInstancesError::LimitExceeded(ref cause) => write!(f, "{}", cause),
             ListDeviceInstancesError::NotFound(ref cause) => write!(f, "{}", cause),
             ListDeviceInstancesError::ServiceAccount(ref cause) => write!(f, "{}", cause),
+            ListDeviceInstancesError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListDeviceInstancesError {}
 /// Errors returned by ListDevicePools
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListDevicePoolsError {
     /// <p>An invalid argument was specified.</p>
     Argument(String),
@@ -5285,6 +5613,8 @@ pub enum ListDevicePoolsError {
     NotFound(String),
     /// <p>There was a problem with the service account.</p>
     ServiceAccount(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListDevicePoolsError {
@@ -5292,19 +5622,22 @@ impl ListDevicePoolsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ArgumentException" => {
-                    return RusotoError::Service(ListDevicePoolsError::Argument(err.msg))
+                    return RusotoError::service(ListDevicePoolsError::Argument(err.msg), &res)
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(ListDevicePoolsError::LimitExceeded(err.msg))
+                    return RusotoError::service(ListDevicePoolsError::LimitExceeded(err.msg), &res)
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(ListDevicePoolsError::NotFound(err.msg))
+                    return RusotoError::service(ListDevicePoolsError::NotFound(err.msg), &res)
                 }
                 "ServiceAccountException" => {
-                    return RusotoError::Service(ListDevicePoolsError::ServiceAccount(err.msg))
+                    return RusotoError::service(
+                        ListDevicePoolsError::ServiceAccount(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListDevicePoolsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -5318,12 +5651,14 @@ impl fmt::Display for ListDevicePoolsError {
             ListDevicePoolsError::LimitExceeded(ref cause) => write!(f, "{}", cause),
             ListDevicePoolsError::NotFound(ref cause) => write!(f, "{}", cause),
             ListDevicePoolsError::ServiceAccount(ref cause) => write!(f, "{}", cause),
+            ListDevicePoolsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListDevicePoolsError {}
 /// Errors returned by ListDevices
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListDevicesError {
     /// <p>An invalid argument was specified.</p>
     Argument(String),
@@ -5333,6 +5668,8 @@ pub enum ListDevicesError {
     NotFound(String),
     /// <p>There was a problem with the service account.</p>
     ServiceAccount(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListDevicesError {
@@ -5340,19 +5677,19 @@ impl ListDevicesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ArgumentException" => {
-                    return RusotoError::Service(ListDevicesError::Argument(err.msg))
+                    return RusotoError::service(ListDevicesError::Argument(err.msg), &res)
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(ListDevicesError::LimitExceeded(err.msg))
+                    return RusotoError::service(ListDevicesError::LimitExceeded(err.msg), &res)
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(ListDevicesError::NotFound(err.msg))
+                    return RusotoError::service(ListDevicesError::NotFound(err.msg), &res)
                 }
                 "ServiceAccountException" => {
-                    return RusotoError::Service(ListDevicesError::ServiceAccount(err.msg))
+                    return RusotoError::service(ListDevicesError::ServiceAccount(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListDevicesError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -5366,12 +5703,14 @@ impl fmt::Display for ListDevicesError {
             ListDevicesError::LimitExceeded(ref cause) => write!(f, "{}", cause),
             ListDevicesError::NotFound(ref cause) => write!(f, "{}", cause),
             ListDevicesError::ServiceAccount(ref cause) => write!(f, "{}", cause),
+            ListDevicesError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListDevicesError {}
 /// Errors returned by ListInstanceProfiles
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListInstanceProfilesError {
     /// <p>An invalid argument was specified.</p>
     Argument(String),
@@ -5381,6 +5720,8 @@ pub enum ListInstanceProfilesError {
     NotFound(String),
     /// <p>There was a problem with the service account.</p>
     ServiceAccount(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListInstanceProfilesError {
@@ -5388,19 +5729,25 @@ impl ListInstanceProfilesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ArgumentException" => {
-                    return RusotoError::Service(ListInstanceProfilesError::Argument(err.msg))
+                    return RusotoError::service(ListInstanceProfilesError::Argument(err.msg), &res)
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(ListInstanceProfilesError::LimitExceeded(err.msg))
+                    return RusotoError::service(
+                        ListInstanceProfilesError::LimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(ListInstanceProfilesError::NotFound(err.msg))
+                    return RusotoError::service(ListInstanceProfilesError::NotFound(err.msg), &res)
                 }
                 "ServiceAccountException" => {
-                    return RusotoError::Service(ListInstanceProfilesError::ServiceAccount(err.msg))
+                    return RusotoError::service(
+                        ListInstanceProfilesError::ServiceAccount(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListInstanceProfilesError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -5414,12 +5761,14 @@ impl fmt::Display for ListInstanceProfilesError {
             ListInstanceProfilesError::LimitExceeded(ref cause) => write!(f, "{}", cause),
             ListInstanceProfilesError::NotFound(ref cause) => write!(f, "{}", cause),
             ListInstanceProfilesError::ServiceAccount(ref cause) => write!(f, "{}", cause),
+            ListInstanceProfilesError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListInstanceProfilesError {}
 /// Errors returned by ListJobs
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListJobsError {
     /// <p>An invalid argument was specified.</p>
     Argument(String),
@@ -5429,6 +5778,8 @@ pub enum ListJobsError {
     NotFound(String),
     /// <p>There was a problem with the service account.</p>
     ServiceAccount(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListJobsError {
@@ -5436,19 +5787,19 @@ impl ListJobsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ArgumentException" => {
-                    return RusotoError::Service(ListJobsError::Argument(err.msg))
+                    return RusotoError::service(ListJobsError::Argument(err.msg), &res)
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(ListJobsError::LimitExceeded(err.msg))
+                    return RusotoError::service(ListJobsError::LimitExceeded(err.msg), &res)
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(ListJobsError::NotFound(err.msg))
+                    return RusotoError::service(ListJobsError::NotFound(err.msg), &res)
                 }
                 "ServiceAccountException" => {
-                    return RusotoError::Service(ListJobsError::ServiceAccount(err.msg))
+                    return RusotoError::service(ListJobsError::ServiceAccount(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListJobsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -5462,12 +5813,14 @@ impl fmt::Display for ListJobsError {
             ListJobsError::LimitExceeded(ref cause) => write!(f, "{}", cause),
             ListJobsError::NotFound(ref cause) => write!(f, "{}", cause),
             ListJobsError::ServiceAccount(ref cause) => write!(f, "{}", cause),
+            ListJobsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListJobsError {}
 /// Errors returned by ListNetworkProfiles
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListNetworkProfilesError {
     /// <p>An invalid argument was specified.</p>
     Argument(String),
@@ -5477,6 +5830,8 @@ pub enum ListNetworkProfilesError {
     NotFound(String),
     /// <p>There was a problem with the service account.</p>
     ServiceAccount(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListNetworkProfilesError {
@@ -5484,19 +5839,25 @@ impl ListNetworkProfilesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ArgumentException" => {
-                    return RusotoError::Service(ListNetworkProfilesError::Argument(err.msg))
+                    return RusotoError::service(ListNetworkProfilesError::Argument(err.msg), &res)
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(ListNetworkProfilesError::LimitExceeded(err.msg))
+                    return RusotoError::service(
+                        ListNetworkProfilesError::LimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(ListNetworkProfilesError::NotFound(err.msg))
+                    return RusotoError::service(ListNetworkProfilesError::NotFound(err.msg), &res)
                 }
                 "ServiceAccountException" => {
-                    return RusotoError::Service(ListNetworkProfilesError::ServiceAccount(err.msg))
+                    return RusotoError::service(
+                        ListNetworkProfilesError::ServiceAccount(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListNetworkProfilesError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -5510,12 +5871,14 @@ impl fmt::Display for ListNetworkProfilesError {
             ListNetworkProfilesError::LimitExceeded(ref cause) => write!(f, "{}", cause),
             ListNetworkProfilesError::NotFound(ref cause) => write!(f, "{}", cause),
             ListNetworkProfilesError::ServiceAccount(ref cause) => write!(f, "{}", cause),
+            ListNetworkProfilesError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListNetworkProfilesError {}
 /// Errors returned by ListOfferingPromotions
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListOfferingPromotionsError {
     /// <p>An invalid argument was specified.</p>
     Argument(String),
@@ -5527,6 +5890,8 @@ pub enum ListOfferingPromotionsError {
     NotFound(String),
     /// <p>There was a problem with the service account.</p>
     ServiceAccount(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListOfferingPromotionsError {
@@ -5534,26 +5899,37 @@ impl ListOfferingPromotionsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ArgumentException" => {
-                    return RusotoError::Service(ListOfferingPromotionsError::Argument(err.msg))
+                    return RusotoError::service(
+                        ListOfferingPromotionsError::Argument(err.msg),
+                        &res,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(ListOfferingPromotionsError::LimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListOfferingPromotionsError::LimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "NotEligibleException" => {
-                    return RusotoError::Service(ListOfferingPromotionsError::NotEligible(err.msg))
+                    return RusotoError::service(
+                        ListOfferingPromotionsError::NotEligible(err.msg),
+                        &res,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(ListOfferingPromotionsError::NotFound(err.msg))
+                    return RusotoError::service(
+                        ListOfferingPromotionsError::NotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceAccountException" => {
-                    return RusotoError::Service(ListOfferingPromotionsError::ServiceAccount(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListOfferingPromotionsError::ServiceAccount(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListOfferingPromotionsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -5568,12 +5944,14 @@ impl fmt::Display for ListOfferingPromotionsError {
             ListOfferingPromotionsError::NotEligible(ref cause) => write!(f, "{}", cause),
             ListOfferingPromotionsError::NotFound(ref cause) => write!(f, "{}", cause),
             ListOfferingPromotionsError::ServiceAccount(ref cause) => write!(f, "{}", cause),
+            ListOfferingPromotionsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListOfferingPromotionsError {}
 /// Errors returned by ListOfferingTransactions
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListOfferingTransactionsError {
     /// <p>An invalid argument was specified.</p>
     Argument(String),
@@ -5585,6 +5963,8 @@ pub enum ListOfferingTransactionsError {
     NotFound(String),
     /// <p>There was a problem with the service account.</p>
     ServiceAccount(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListOfferingTransactionsError {
@@ -5592,28 +5972,37 @@ impl ListOfferingTransactionsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ArgumentException" => {
-                    return RusotoError::Service(ListOfferingTransactionsError::Argument(err.msg))
+                    return RusotoError::service(
+                        ListOfferingTransactionsError::Argument(err.msg),
+                        &res,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(ListOfferingTransactionsError::LimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListOfferingTransactionsError::LimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "NotEligibleException" => {
-                    return RusotoError::Service(ListOfferingTransactionsError::NotEligible(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListOfferingTransactionsError::NotEligible(err.msg),
+                        &res,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(ListOfferingTransactionsError::NotFound(err.msg))
+                    return RusotoError::service(
+                        ListOfferingTransactionsError::NotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceAccountException" => {
-                    return RusotoError::Service(ListOfferingTransactionsError::ServiceAccount(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListOfferingTransactionsError::ServiceAccount(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListOfferingTransactionsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -5628,12 +6017,14 @@ impl fmt::Display for ListOfferingTransactionsError {
             ListOfferingTransactionsError::NotEligible(ref cause) => write!(f, "{}", cause),
             ListOfferingTransactionsError::NotFound(ref cause) => write!(f, "{}", cause),
             ListOfferingTransactionsError::ServiceAccount(ref cause) => write!(f, "{}", cause),
+            ListOfferingTransactionsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListOfferingTransactionsError {}
 /// Errors returned by ListOfferings
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListOfferingsError {
     /// <p>An invalid argument was specified.</p>
     Argument(String),
@@ -5645,6 +6036,8 @@ pub enum ListOfferingsError {
     NotFound(String),
     /// <p>There was a problem with the service account.</p>
     ServiceAccount(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListOfferingsError {
@@ -5652,22 +6045,22 @@ impl ListOfferingsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ArgumentException" => {
-                    return RusotoError::Service(ListOfferingsError::Argument(err.msg))
+                    return RusotoError::service(ListOfferingsError::Argument(err.msg), &res)
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(ListOfferingsError::LimitExceeded(err.msg))
+                    return RusotoError::service(ListOfferingsError::LimitExceeded(err.msg), &res)
                 }
                 "NotEligibleException" => {
-                    return RusotoError::Service(ListOfferingsError::NotEligible(err.msg))
+                    return RusotoError::service(ListOfferingsError::NotEligible(err.msg), &res)
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(ListOfferingsError::NotFound(err.msg))
+                    return RusotoError::service(ListOfferingsError::NotFound(err.msg), &res)
                 }
                 "ServiceAccountException" => {
-                    return RusotoError::Service(ListOfferingsError::ServiceAccount(err.msg))
+                    return RusotoError::service(ListOfferingsError::ServiceAccount(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListOfferingsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -5682,12 +6075,14 @@ impl fmt::Display for ListOfferingsError {
             ListOfferingsError::NotEligible(ref cause) => write!(f, "{}", cause),
             ListOfferingsError::NotFound(ref cause) => write!(f, "{}", cause),
             ListOfferingsError::ServiceAccount(ref cause) => write!(f, "{}", cause),
+            ListOfferingsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListOfferingsError {}
 /// Errors returned by ListProjects
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListProjectsError {
     /// <p>An invalid argument was specified.</p>
     Argument(String),
@@ -5697,6 +6092,8 @@ pub enum ListProjectsError {
     NotFound(String),
     /// <p>There was a problem with the service account.</p>
     ServiceAccount(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListProjectsError {
@@ -5704,19 +6101,19 @@ impl ListProjectsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ArgumentException" => {
-                    return RusotoError::Service(ListProjectsError::Argument(err.msg))
+                    return RusotoError::service(ListProjectsError::Argument(err.msg), &res)
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(ListProjectsError::LimitExceeded(err.msg))
+                    return RusotoError::service(ListProjectsError::LimitExceeded(err.msg), &res)
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(ListProjectsError::NotFound(err.msg))
+                    return RusotoError::service(ListProjectsError::NotFound(err.msg), &res)
                 }
                 "ServiceAccountException" => {
-                    return RusotoError::Service(ListProjectsError::ServiceAccount(err.msg))
+                    return RusotoError::service(ListProjectsError::ServiceAccount(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListProjectsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -5730,12 +6127,14 @@ impl fmt::Display for ListProjectsError {
             ListProjectsError::LimitExceeded(ref cause) => write!(f, "{}", cause),
             ListProjectsError::NotFound(ref cause) => write!(f, "{}", cause),
             ListProjectsError::ServiceAccount(ref cause) => write!(f, "{}", cause),
+            ListProjectsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListProjectsError {}
 /// Errors returned by ListRemoteAccessSessions
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListRemoteAccessSessionsError {
     /// <p>An invalid argument was specified.</p>
     Argument(String),
@@ -5745,6 +6144,8 @@ pub enum ListRemoteAccessSessionsError {
     NotFound(String),
     /// <p>There was a problem with the service account.</p>
     ServiceAccount(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListRemoteAccessSessionsError {
@@ -5752,23 +6153,31 @@ impl ListRemoteAccessSessionsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ArgumentException" => {
-                    return RusotoError::Service(ListRemoteAccessSessionsError::Argument(err.msg))
+                    return RusotoError::service(
+                        ListRemoteAccessSessionsError::Argument(err.msg),
+                        &res,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(ListRemoteAccessSessionsError::LimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListRemoteAccessSessionsError::LimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(ListRemoteAccessSessionsError::NotFound(err.msg))
+                    return RusotoError::service(
+                        ListRemoteAccessSessionsError::NotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceAccountException" => {
-                    return RusotoError::Service(ListRemoteAccessSessionsError::ServiceAccount(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListRemoteAccessSessionsError::ServiceAccount(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListRemoteAccessSessionsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -5782,12 +6191,14 @@ impl fmt::Display for ListRemoteAccessSessionsError {
             ListRemoteAccessSessionsError::LimitExceeded(ref cause) => write!(f, "{}", cause),
             ListRemoteAccessSessionsError::NotFound(ref cause) => write!(f, "{}", cause),
             ListRemoteAccessSessionsError::ServiceAccount(ref cause) => write!(f, "{}", cause),
+            ListRemoteAccessSessionsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListRemoteAccessSessionsError {}
 /// Errors returned by ListRuns
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListRunsError {
     /// <p>An invalid argument was specified.</p>
     Argument(String),
@@ -5797,6 +6208,8 @@ pub enum ListRunsError {
     NotFound(String),
     /// <p>There was a problem with the service account.</p>
     ServiceAccount(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListRunsError {
@@ -5804,19 +6217,19 @@ impl ListRunsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ArgumentException" => {
-                    return RusotoError::Service(ListRunsError::Argument(err.msg))
+                    return RusotoError::service(ListRunsError::Argument(err.msg), &res)
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(ListRunsError::LimitExceeded(err.msg))
+                    return RusotoError::service(ListRunsError::LimitExceeded(err.msg), &res)
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(ListRunsError::NotFound(err.msg))
+                    return RusotoError::service(ListRunsError::NotFound(err.msg), &res)
                 }
                 "ServiceAccountException" => {
-                    return RusotoError::Service(ListRunsError::ServiceAccount(err.msg))
+                    return RusotoError::service(ListRunsError::ServiceAccount(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListRunsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -5830,12 +6243,14 @@ impl fmt::Display for ListRunsError {
             ListRunsError::LimitExceeded(ref cause) => write!(f, "{}", cause),
             ListRunsError::NotFound(ref cause) => write!(f, "{}", cause),
             ListRunsError::ServiceAccount(ref cause) => write!(f, "{}", cause),
+            ListRunsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListRunsError {}
 /// Errors returned by ListSamples
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListSamplesError {
     /// <p>An invalid argument was specified.</p>
     Argument(String),
@@ -5845,6 +6260,8 @@ pub enum ListSamplesError {
     NotFound(String),
     /// <p>There was a problem with the service account.</p>
     ServiceAccount(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListSamplesError {
@@ -5852,19 +6269,19 @@ impl ListSamplesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ArgumentException" => {
-                    return RusotoError::Service(ListSamplesError::Argument(err.msg))
+                    return RusotoError::service(ListSamplesError::Argument(err.msg), &res)
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(ListSamplesError::LimitExceeded(err.msg))
+                    return RusotoError::service(ListSamplesError::LimitExceeded(err.msg), &res)
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(ListSamplesError::NotFound(err.msg))
+                    return RusotoError::service(ListSamplesError::NotFound(err.msg), &res)
                 }
                 "ServiceAccountException" => {
-                    return RusotoError::Service(ListSamplesError::ServiceAccount(err.msg))
+                    return RusotoError::service(ListSamplesError::ServiceAccount(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListSamplesError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -5878,12 +6295,14 @@ impl fmt::Display for ListSamplesError {
             ListSamplesError::LimitExceeded(ref cause) => write!(f, "{}", cause),
             ListSamplesError::NotFound(ref cause) => write!(f, "{}", cause),
             ListSamplesError::ServiceAccount(ref cause) => write!(f, "{}", cause),
+            ListSamplesError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListSamplesError {}
 /// Errors returned by ListSuites
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListSuitesError {
     /// <p>An invalid argument was specified.</p>
     Argument(String),
@@ -5893,6 +6312,8 @@ pub enum ListSuitesError {
     NotFound(String),
     /// <p>There was a problem with the service account.</p>
     ServiceAccount(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListSuitesError {
@@ -5900,19 +6321,19 @@ impl ListSuitesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ArgumentException" => {
-                    return RusotoError::Service(ListSuitesError::Argument(err.msg))
+                    return RusotoError::service(ListSuitesError::Argument(err.msg), &res)
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(ListSuitesError::LimitExceeded(err.msg))
+                    return RusotoError::service(ListSuitesError::LimitExceeded(err.msg), &res)
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(ListSuitesError::NotFound(err.msg))
+                    return RusotoError::service(ListSuitesError::NotFound(err.msg), &res)
                 }
                 "ServiceAccountException" => {
-                    return RusotoError::Service(ListSuitesError::ServiceAccount(err.msg))
+                    return RusotoError::service(ListSuitesError::ServiceAccount(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListSuitesError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -5926,12 +6347,14 @@ impl fmt::Display for ListSuitesError {
             ListSuitesError::LimitExceeded(ref cause) => write!(f, "{}", cause),
             ListSuitesError::NotFound(ref cause) => write!(f, "{}", cause),
             ListSuitesError::ServiceAccount(ref cause) => write!(f, "{}", cause),
+            ListSuitesError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListSuitesError {}
 /// Errors returned by ListTagsForResource
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListTagsForResourceError {
     /// <p>An invalid argument was specified.</p>
     Argument(String),
@@ -5939,6 +6362,8 @@ pub enum ListTagsForResourceError {
     NotFound(String),
     /// <p>The operation was not successful. Try again.</p>
     TagOperation(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListTagsForResourceError {
@@ -5946,16 +6371,19 @@ impl ListTagsForResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ArgumentException" => {
-                    return RusotoError::Service(ListTagsForResourceError::Argument(err.msg))
+                    return RusotoError::service(ListTagsForResourceError::Argument(err.msg), &res)
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(ListTagsForResourceError::NotFound(err.msg))
+                    return RusotoError::service(ListTagsForResourceError::NotFound(err.msg), &res)
                 }
                 "TagOperationException" => {
-                    return RusotoError::Service(ListTagsForResourceError::TagOperation(err.msg))
+                    return RusotoError::service(
+                        ListTagsForResourceError::TagOperation(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListTagsForResourceError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -5968,17 +6396,21 @@ impl fmt::Display for ListTagsForResourceError {
             ListTagsForResourceError::Argument(ref cause) => write!(f, "{}", cause),
             ListTagsForResourceError::NotFound(ref cause) => write!(f, "{}", cause),
             ListTagsForResourceError::TagOperation(ref cause) => write!(f, "{}", cause),
+            ListTagsForResourceError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListTagsForResourceError {}
 /// Errors returned by ListTestGridProjects
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListTestGridProjectsError {
     /// <p>An invalid argument was specified.</p>
     Argument(String),
     /// <p>An internal exception was raised in the service. Contact <a href="mailto:aws-devicefarm-support@amazon.com">aws-devicefarm-support@amazon.com</a> if you see this error. </p>
     InternalService(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListTestGridProjectsError {
@@ -5986,15 +6418,16 @@ impl ListTestGridProjectsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ArgumentException" => {
-                    return RusotoError::Service(ListTestGridProjectsError::Argument(err.msg))
+                    return RusotoError::service(ListTestGridProjectsError::Argument(err.msg), &res)
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(ListTestGridProjectsError::InternalService(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListTestGridProjectsError::InternalService(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListTestGridProjectsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -6006,12 +6439,14 @@ impl fmt::Display for ListTestGridProjectsError {
         match *self {
             ListTestGridProjectsError::Argument(ref cause) => write!(f, "{}", cause),
             ListTestGridProjectsError::InternalService(ref cause) => write!(f, "{}", cause),
+            ListTestGridProjectsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListTestGridProjectsError {}
 /// Errors returned by ListTestGridSessionActions
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListTestGridSessionActionsError {
     /// <p>An invalid argument was specified.</p>
     Argument(String),
@@ -6019,6 +6454,8 @@ pub enum ListTestGridSessionActionsError {
     InternalService(String),
     /// <p>The specified entity was not found.</p>
     NotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListTestGridSessionActionsError {
@@ -6028,18 +6465,27 @@ impl ListTestGridSessionActionsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ArgumentException" => {
-                    return RusotoError::Service(ListTestGridSessionActionsError::Argument(err.msg))
+                    return RusotoError::service(
+                        ListTestGridSessionActionsError::Argument(err.msg),
+                        &res,
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(ListTestGridSessionActionsError::InternalService(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListTestGridSessionActionsError::InternalService(err.msg),
+                        &res,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(ListTestGridSessionActionsError::NotFound(err.msg))
+                    return RusotoError::service(
+                        ListTestGridSessionActionsError::NotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(ListTestGridSessionActionsError::Unhandled, &res)
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -6052,12 +6498,14 @@ impl fmt::Display for ListTestGridSessionActionsError {
             ListTestGridSessionActionsError::Argument(ref cause) => write!(f, "{}", cause),
             ListTestGridSessionActionsError::InternalService(ref cause) => write!(f, "{}", cause),
             ListTestGridSessionActionsError::NotFound(ref cause) => write!(f, "{}", cause),
+            ListTestGridSessionActionsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListTestGridSessionActionsError {}
 /// Errors returned by ListTestGridSessionArtifacts
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListTestGridSessionArtifactsError {
     /// <p>An invalid argument was specified.</p>
     Argument(String),
@@ -6065,6 +6513,8 @@ pub enum ListTestGridSessionArtifactsError {
     InternalService(String),
     /// <p>The specified entity was not found.</p>
     NotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListTestGridSessionArtifactsError {
@@ -6074,22 +6524,30 @@ impl ListTestGridSessionArtifactsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ArgumentException" => {
-                    return RusotoError::Service(ListTestGridSessionArtifactsError::Argument(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListTestGridSessionArtifactsError::Argument(err.msg),
+                        &res,
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         ListTestGridSessionArtifactsError::InternalService(err.msg),
+                        &res,
                     )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(ListTestGridSessionArtifactsError::NotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListTestGridSessionArtifactsError::NotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        ListTestGridSessionArtifactsError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -6102,12 +6560,14 @@ impl fmt::Display for ListTestGridSessionArtifactsError {
             ListTestGridSessionArtifactsError::Argument(ref cause) => write!(f, "{}", cause),
             ListTestGridSessionArtifactsError::InternalService(ref cause) => write!(f, "{}", cause),
             ListTestGridSessionArtifactsError::NotFound(ref cause) => write!(f, "{}", cause),
+            ListTestGridSessionArtifactsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListTestGridSessionArtifactsError {}
 /// Errors returned by ListTestGridSessions
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListTestGridSessionsError {
     /// <p>An invalid argument was specified.</p>
     Argument(String),
@@ -6115,6 +6575,8 @@ pub enum ListTestGridSessionsError {
     InternalService(String),
     /// <p>The specified entity was not found.</p>
     NotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListTestGridSessionsError {
@@ -6122,18 +6584,19 @@ impl ListTestGridSessionsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ArgumentException" => {
-                    return RusotoError::Service(ListTestGridSessionsError::Argument(err.msg))
+                    return RusotoError::service(ListTestGridSessionsError::Argument(err.msg), &res)
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(ListTestGridSessionsError::InternalService(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListTestGridSessionsError::InternalService(err.msg),
+                        &res,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(ListTestGridSessionsError::NotFound(err.msg))
+                    return RusotoError::service(ListTestGridSessionsError::NotFound(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListTestGridSessionsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -6146,12 +6609,14 @@ impl fmt::Display for ListTestGridSessionsError {
             ListTestGridSessionsError::Argument(ref cause) => write!(f, "{}", cause),
             ListTestGridSessionsError::InternalService(ref cause) => write!(f, "{}", cause),
             ListTestGridSessionsError::NotFound(ref cause) => write!(f, "{}", cause),
+            ListTestGridSessionsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListTestGridSessionsError {}
 /// Errors returned by ListTests
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListTestsError {
     /// <p>An invalid argument was specified.</p>
     Argument(String),
@@ -6161,6 +6626,8 @@ pub enum ListTestsError {
     NotFound(String),
     /// <p>There was a problem with the service account.</p>
     ServiceAccount(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListTestsError {
@@ -6168,19 +6635,19 @@ impl ListTestsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ArgumentException" => {
-                    return RusotoError::Service(ListTestsError::Argument(err.msg))
+                    return RusotoError::service(ListTestsError::Argument(err.msg), &res)
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(ListTestsError::LimitExceeded(err.msg))
+                    return RusotoError::service(ListTestsError::LimitExceeded(err.msg), &res)
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(ListTestsError::NotFound(err.msg))
+                    return RusotoError::service(ListTestsError::NotFound(err.msg), &res)
                 }
                 "ServiceAccountException" => {
-                    return RusotoError::Service(ListTestsError::ServiceAccount(err.msg))
+                    return RusotoError::service(ListTestsError::ServiceAccount(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListTestsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -6194,12 +6661,14 @@ impl fmt::Display for ListTestsError {
             ListTestsError::LimitExceeded(ref cause) => write!(f, "{}", cause),
             ListTestsError::NotFound(ref cause) => write!(f, "{}", cause),
             ListTestsError::ServiceAccount(ref cause) => write!(f, "{}", cause),
+            ListTestsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListTestsError {}
 /// Errors returned by ListUniqueProblems
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListUniqueProblemsError {
     /// <p>An invalid argument was specified.</p>
     Argument(String),
@@ -6209,6 +6678,8 @@ pub enum ListUniqueProblemsError {
     NotFound(String),
     /// <p>There was a problem with the service account.</p>
     ServiceAccount(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListUniqueProblemsError {
@@ -6216,19 +6687,25 @@ impl ListUniqueProblemsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ArgumentException" => {
-                    return RusotoError::Service(ListUniqueProblemsError::Argument(err.msg))
+                    return RusotoError::service(ListUniqueProblemsError::Argument(err.msg), &res)
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(ListUniqueProblemsError::LimitExceeded(err.msg))
+                    return RusotoError::service(
+                        ListUniqueProblemsError::LimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(ListUniqueProblemsError::NotFound(err.msg))
+                    return RusotoError::service(ListUniqueProblemsError::NotFound(err.msg), &res)
                 }
                 "ServiceAccountException" => {
-                    return RusotoError::Service(ListUniqueProblemsError::ServiceAccount(err.msg))
+                    return RusotoError::service(
+                        ListUniqueProblemsError::ServiceAccount(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListUniqueProblemsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -6242,12 +6719,14 @@ impl fmt::Display for ListUniqueProblemsError {
             ListUniqueProblemsError::LimitExceeded(ref cause) => write!(f, "{}", cause),
             ListUniqueProblemsError::NotFound(ref cause) => write!(f, "{}", cause),
             ListUniqueProblemsError::ServiceAccount(ref cause) => write!(f, "{}", cause),
+            ListUniqueProblemsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListUniqueProblemsError {}
 /// Errors returned by ListUploads
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListUploadsError {
     /// <p>An invalid argument was specified.</p>
     Argument(String),
@@ -6257,6 +6736,8 @@ pub enum ListUploadsError {
     NotFound(String),
     /// <p>There was a problem with the service account.</p>
     ServiceAccount(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListUploadsError {
@@ -6264,19 +6745,19 @@ impl ListUploadsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ArgumentException" => {
-                    return RusotoError::Service(ListUploadsError::Argument(err.msg))
+                    return RusotoError::service(ListUploadsError::Argument(err.msg), &res)
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(ListUploadsError::LimitExceeded(err.msg))
+                    return RusotoError::service(ListUploadsError::LimitExceeded(err.msg), &res)
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(ListUploadsError::NotFound(err.msg))
+                    return RusotoError::service(ListUploadsError::NotFound(err.msg), &res)
                 }
                 "ServiceAccountException" => {
-                    return RusotoError::Service(ListUploadsError::ServiceAccount(err.msg))
+                    return RusotoError::service(ListUploadsError::ServiceAccount(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListUploadsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -6290,17 +6771,21 @@ impl fmt::Display for ListUploadsError {
             ListUploadsError::LimitExceeded(ref cause) => write!(f, "{}", cause),
             ListUploadsError::NotFound(ref cause) => write!(f, "{}", cause),
             ListUploadsError::ServiceAccount(ref cause) => write!(f, "{}", cause),
+            ListUploadsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListUploadsError {}
 /// Errors returned by ListVPCEConfigurations
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListVPCEConfigurationsError {
     /// <p>An invalid argument was specified.</p>
     Argument(String),
     /// <p>There was a problem with the service account.</p>
     ServiceAccount(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListVPCEConfigurationsError {
@@ -6308,15 +6793,19 @@ impl ListVPCEConfigurationsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ArgumentException" => {
-                    return RusotoError::Service(ListVPCEConfigurationsError::Argument(err.msg))
+                    return RusotoError::service(
+                        ListVPCEConfigurationsError::Argument(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceAccountException" => {
-                    return RusotoError::Service(ListVPCEConfigurationsError::ServiceAccount(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListVPCEConfigurationsError::ServiceAccount(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListVPCEConfigurationsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -6328,12 +6817,14 @@ impl fmt::Display for ListVPCEConfigurationsError {
         match *self {
             ListVPCEConfigurationsError::Argument(ref cause) => write!(f, "{}", cause),
             ListVPCEConfigurationsError::ServiceAccount(ref cause) => write!(f, "{}", cause),
+            ListVPCEConfigurationsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListVPCEConfigurationsError {}
 /// Errors returned by PurchaseOffering
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum PurchaseOfferingError {
     /// <p>An invalid argument was specified.</p>
     Argument(String),
@@ -6345,6 +6836,8 @@ pub enum PurchaseOfferingError {
     NotFound(String),
     /// <p>There was a problem with the service account.</p>
     ServiceAccount(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl PurchaseOfferingError {
@@ -6352,22 +6845,28 @@ impl PurchaseOfferingError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ArgumentException" => {
-                    return RusotoError::Service(PurchaseOfferingError::Argument(err.msg))
+                    return RusotoError::service(PurchaseOfferingError::Argument(err.msg), &res)
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(PurchaseOfferingError::LimitExceeded(err.msg))
+                    return RusotoError::service(
+                        PurchaseOfferingError::LimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "NotEligibleException" => {
-                    return RusotoError::Service(PurchaseOfferingError::NotEligible(err.msg))
+                    return RusotoError::service(PurchaseOfferingError::NotEligible(err.msg), &res)
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(PurchaseOfferingError::NotFound(err.msg))
+                    return RusotoError::service(PurchaseOfferingError::NotFound(err.msg), &res)
                 }
                 "ServiceAccountException" => {
-                    return RusotoError::Service(PurchaseOfferingError::ServiceAccount(err.msg))
+                    return RusotoError::service(
+                        PurchaseOfferingError::ServiceAccount(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(PurchaseOfferingError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -6382,12 +6881,14 @@ impl fmt::Display for PurchaseOfferingError {
             PurchaseOfferingError::NotEligible(ref cause) => write!(f, "{}", cause),
             PurchaseOfferingError::NotFound(ref cause) => write!(f, "{}", cause),
             PurchaseOfferingError::ServiceAccount(ref cause) => write!(f, "{}", cause),
+            PurchaseOfferingError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for PurchaseOfferingError {}
 /// Errors returned by RenewOffering
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum RenewOfferingError {
     /// <p>An invalid argument was specified.</p>
     Argument(String),
@@ -6399,6 +6900,8 @@ pub enum RenewOfferingError {
     NotFound(String),
     /// <p>There was a problem with the service account.</p>
     ServiceAccount(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl RenewOfferingError {
@@ -6406,22 +6909,22 @@ impl RenewOfferingError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ArgumentException" => {
-                    return RusotoError::Service(RenewOfferingError::Argument(err.msg))
+                    return RusotoError::service(RenewOfferingError::Argument(err.msg), &res)
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(RenewOfferingError::LimitExceeded(err.msg))
+                    return RusotoError::service(RenewOfferingError::LimitExceeded(err.msg), &res)
                 }
                 "NotEligibleException" => {
-                    return RusotoError::Service(RenewOfferingError::NotEligible(err.msg))
+                    return RusotoError::service(RenewOfferingError::NotEligible(err.msg), &res)
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(RenewOfferingError::NotFound(err.msg))
+                    return RusotoError::service(RenewOfferingError::NotFound(err.msg), &res)
                 }
                 "ServiceAccountException" => {
-                    return RusotoError::Service(RenewOfferingError::ServiceAccount(err.msg))
+                    return RusotoError::service(RenewOfferingError::ServiceAccount(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(RenewOfferingError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -6436,12 +6939,14 @@ impl fmt::Display for RenewOfferingError {
             RenewOfferingError::NotEligible(ref cause) => write!(f, "{}", cause),
             RenewOfferingError::NotFound(ref cause) => write!(f, "{}", cause),
             RenewOfferingError::ServiceAccount(ref cause) => write!(f, "{}", cause),
+            RenewOfferingError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for RenewOfferingError {}
 /// Errors returned by ScheduleRun
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ScheduleRunError {
     /// <p>An invalid argument was specified.</p>
     Argument(String),
@@ -6453,6 +6958,8 @@ pub enum ScheduleRunError {
     NotFound(String),
     /// <p>There was a problem with the service account.</p>
     ServiceAccount(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ScheduleRunError {
@@ -6460,22 +6967,22 @@ impl ScheduleRunError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ArgumentException" => {
-                    return RusotoError::Service(ScheduleRunError::Argument(err.msg))
+                    return RusotoError::service(ScheduleRunError::Argument(err.msg), &res)
                 }
                 "IdempotencyException" => {
-                    return RusotoError::Service(ScheduleRunError::Idempotency(err.msg))
+                    return RusotoError::service(ScheduleRunError::Idempotency(err.msg), &res)
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(ScheduleRunError::LimitExceeded(err.msg))
+                    return RusotoError::service(ScheduleRunError::LimitExceeded(err.msg), &res)
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(ScheduleRunError::NotFound(err.msg))
+                    return RusotoError::service(ScheduleRunError::NotFound(err.msg), &res)
                 }
                 "ServiceAccountException" => {
-                    return RusotoError::Service(ScheduleRunError::ServiceAccount(err.msg))
+                    return RusotoError::service(ScheduleRunError::ServiceAccount(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ScheduleRunError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -6490,12 +6997,14 @@ impl fmt::Display for ScheduleRunError {
             ScheduleRunError::LimitExceeded(ref cause) => write!(f, "{}", cause),
             ScheduleRunError::NotFound(ref cause) => write!(f, "{}", cause),
             ScheduleRunError::ServiceAccount(ref cause) => write!(f, "{}", cause),
+            ScheduleRunError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ScheduleRunError {}
 /// Errors returned by StopJob
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum StopJobError {
     /// <p>An invalid argument was specified.</p>
     Argument(String),
@@ -6505,6 +7014,8 @@ pub enum StopJobError {
     NotFound(String),
     /// <p>There was a problem with the service account.</p>
     ServiceAccount(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl StopJobError {
@@ -6512,19 +7023,19 @@ impl StopJobError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ArgumentException" => {
-                    return RusotoError::Service(StopJobError::Argument(err.msg))
+                    return RusotoError::service(StopJobError::Argument(err.msg), &res)
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(StopJobError::LimitExceeded(err.msg))
+                    return RusotoError::service(StopJobError::LimitExceeded(err.msg), &res)
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(StopJobError::NotFound(err.msg))
+                    return RusotoError::service(StopJobError::NotFound(err.msg), &res)
                 }
                 "ServiceAccountException" => {
-                    return RusotoError::Service(StopJobError::ServiceAccount(err.msg))
+                    return RusotoError::service(StopJobError::ServiceAccount(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(StopJobError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -6538,12 +7049,14 @@ impl fmt::Display for StopJobError {
             StopJobError::LimitExceeded(ref cause) => write!(f, "{}", cause),
             StopJobError::NotFound(ref cause) => write!(f, "{}", cause),
             StopJobError::ServiceAccount(ref cause) => write!(f, "{}", cause),
+            StopJobError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for StopJobError {}
 /// Errors returned by StopRemoteAccessSession
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum StopRemoteAccessSessionError {
     /// <p>An invalid argument was specified.</p>
     Argument(String),
@@ -6553,6 +7066,8 @@ pub enum StopRemoteAccessSessionError {
     NotFound(String),
     /// <p>There was a problem with the service account.</p>
     ServiceAccount(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl StopRemoteAccessSessionError {
@@ -6560,23 +7075,31 @@ impl StopRemoteAccessSessionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ArgumentException" => {
-                    return RusotoError::Service(StopRemoteAccessSessionError::Argument(err.msg))
+                    return RusotoError::service(
+                        StopRemoteAccessSessionError::Argument(err.msg),
+                        &res,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(StopRemoteAccessSessionError::LimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        StopRemoteAccessSessionError::LimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(StopRemoteAccessSessionError::NotFound(err.msg))
+                    return RusotoError::service(
+                        StopRemoteAccessSessionError::NotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceAccountException" => {
-                    return RusotoError::Service(StopRemoteAccessSessionError::ServiceAccount(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        StopRemoteAccessSessionError::ServiceAccount(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(StopRemoteAccessSessionError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -6590,12 +7113,14 @@ impl fmt::Display for StopRemoteAccessSessionError {
             StopRemoteAccessSessionError::LimitExceeded(ref cause) => write!(f, "{}", cause),
             StopRemoteAccessSessionError::NotFound(ref cause) => write!(f, "{}", cause),
             StopRemoteAccessSessionError::ServiceAccount(ref cause) => write!(f, "{}", cause),
+            StopRemoteAccessSessionError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for StopRemoteAccessSessionError {}
 /// Errors returned by StopRun
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum StopRunError {
     /// <p>An invalid argument was specified.</p>
     Argument(String),
@@ -6605,6 +7130,8 @@ pub enum StopRunError {
     NotFound(String),
     /// <p>There was a problem with the service account.</p>
     ServiceAccount(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl StopRunError {
@@ -6612,19 +7139,19 @@ impl StopRunError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ArgumentException" => {
-                    return RusotoError::Service(StopRunError::Argument(err.msg))
+                    return RusotoError::service(StopRunError::Argument(err.msg), &res)
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(StopRunError::LimitExceeded(err.msg))
+                    return RusotoError::service(StopRunError::LimitExceeded(err.msg), &res)
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(StopRunError::NotFound(err.msg))
+                    return RusotoError::service(StopRunError::NotFound(err.msg), &res)
                 }
                 "ServiceAccountException" => {
-                    return RusotoError::Service(StopRunError::ServiceAccount(err.msg))
+                    return RusotoError::service(StopRunError::ServiceAccount(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(StopRunError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -6638,12 +7165,14 @@ impl fmt::Display for StopRunError {
             StopRunError::LimitExceeded(ref cause) => write!(f, "{}", cause),
             StopRunError::NotFound(ref cause) => write!(f, "{}", cause),
             StopRunError::ServiceAccount(ref cause) => write!(f, "{}", cause),
+            StopRunError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for StopRunError {}
 /// Errors returned by TagResource
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum TagResourceError {
     /// <p>An invalid argument was specified.</p>
     Argument(String),
@@ -6655,6 +7184,8 @@ pub enum TagResourceError {
     TagPolicy(String),
     /// <p>The list of tags on the repository is over the limit. The maximum number of tags that can be applied to a repository is 50. </p>
     TooManyTags(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl TagResourceError {
@@ -6662,22 +7193,22 @@ impl TagResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ArgumentException" => {
-                    return RusotoError::Service(TagResourceError::Argument(err.msg))
+                    return RusotoError::service(TagResourceError::Argument(err.msg), &res)
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(TagResourceError::NotFound(err.msg))
+                    return RusotoError::service(TagResourceError::NotFound(err.msg), &res)
                 }
                 "TagOperationException" => {
-                    return RusotoError::Service(TagResourceError::TagOperation(err.msg))
+                    return RusotoError::service(TagResourceError::TagOperation(err.msg), &res)
                 }
                 "TagPolicyException" => {
-                    return RusotoError::Service(TagResourceError::TagPolicy(err.msg))
+                    return RusotoError::service(TagResourceError::TagPolicy(err.msg), &res)
                 }
                 "TooManyTagsException" => {
-                    return RusotoError::Service(TagResourceError::TooManyTags(err.msg))
+                    return RusotoError::service(TagResourceError::TooManyTags(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(TagResourceError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -6692,12 +7223,14 @@ impl fmt::Display for TagResourceError {
             TagResourceError::TagOperation(ref cause) => write!(f, "{}", cause),
             TagResourceError::TagPolicy(ref cause) => write!(f, "{}", cause),
             TagResourceError::TooManyTags(ref cause) => write!(f, "{}", cause),
+            TagResourceError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for TagResourceError {}
 /// Errors returned by UntagResource
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UntagResourceError {
     /// <p>An invalid argument was specified.</p>
     Argument(String),
@@ -6705,6 +7238,8 @@ pub enum UntagResourceError {
     NotFound(String),
     /// <p>The operation was not successful. Try again.</p>
     TagOperation(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UntagResourceError {
@@ -6712,16 +7247,16 @@ impl UntagResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ArgumentException" => {
-                    return RusotoError::Service(UntagResourceError::Argument(err.msg))
+                    return RusotoError::service(UntagResourceError::Argument(err.msg), &res)
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(UntagResourceError::NotFound(err.msg))
+                    return RusotoError::service(UntagResourceError::NotFound(err.msg), &res)
                 }
                 "TagOperationException" => {
-                    return RusotoError::Service(UntagResourceError::TagOperation(err.msg))
+                    return RusotoError::service(UntagResourceError::TagOperation(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UntagResourceError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -6734,12 +7269,14 @@ impl fmt::Display for UntagResourceError {
             UntagResourceError::Argument(ref cause) => write!(f, "{}", cause),
             UntagResourceError::NotFound(ref cause) => write!(f, "{}", cause),
             UntagResourceError::TagOperation(ref cause) => write!(f, "{}", cause),
+            UntagResourceError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for UntagResourceError {}
 /// Errors returned by UpdateDeviceInstance
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdateDeviceInstanceError {
     /// <p>An invalid argument was specified.</p>
     Argument(String),
@@ -6749,6 +7286,8 @@ pub enum UpdateDeviceInstanceError {
     NotFound(String),
     /// <p>There was a problem with the service account.</p>
     ServiceAccount(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdateDeviceInstanceError {
@@ -6756,19 +7295,25 @@ impl UpdateDeviceInstanceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ArgumentException" => {
-                    return RusotoError::Service(UpdateDeviceInstanceError::Argument(err.msg))
+                    return RusotoError::service(UpdateDeviceInstanceError::Argument(err.msg), &res)
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(UpdateDeviceInstanceError::LimitExceeded(err.msg))
+                    return RusotoError::service(
+                        UpdateDeviceInstanceError::LimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(UpdateDeviceInstanceError::NotFound(err.msg))
+                    return RusotoError::service(UpdateDeviceInstanceError::NotFound(err.msg), &res)
                 }
                 "ServiceAccountException" => {
-                    return RusotoError::Service(UpdateDeviceInstanceError::ServiceAccount(err.msg))
+                    return RusotoError::service(
+                        UpdateDeviceInstanceError::ServiceAccount(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UpdateDeviceInstanceError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -6782,12 +7327,14 @@ impl fmt::Display for UpdateDeviceInstanceError {
             UpdateDeviceInstanceError::LimitExceeded(ref cause) => write!(f, "{}", cause),
             UpdateDeviceInstanceError::NotFound(ref cause) => write!(f, "{}", cause),
             UpdateDeviceInstanceError::ServiceAccount(ref cause) => write!(f, "{}", cause),
+            UpdateDeviceInstanceError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for UpdateDeviceInstanceError {}
 /// Errors returned by UpdateDevicePool
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdateDevicePoolError {
     /// <p>An invalid argument was specified.</p>
     Argument(String),
@@ -6797,6 +7344,8 @@ pub enum UpdateDevicePoolError {
     NotFound(String),
     /// <p>There was a problem with the service account.</p>
     ServiceAccount(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdateDevicePoolError {
@@ -6804,19 +7353,25 @@ impl UpdateDevicePoolError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ArgumentException" => {
-                    return RusotoError::Service(UpdateDevicePoolError::Argument(err.msg))
+                    return RusotoError::service(UpdateDevicePoolError::Argument(err.msg), &res)
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(UpdateDevicePoolError::LimitExceeded(err.msg))
+                    return RusotoError::service(
+                        UpdateDevicePoolError::LimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(UpdateDevicePoolError::NotFound(err.msg))
+                    return RusotoError::service(UpdateDevicePoolError::NotFound(err.msg), &res)
                 }
                 "ServiceAccountException" => {
-                    return RusotoError::Service(UpdateDevicePoolError::ServiceAccount(err.msg))
+                    return RusotoError::service(
+                        UpdateDevicePoolError::ServiceAccount(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UpdateDevicePoolError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -6830,12 +7385,14 @@ impl fmt::Display for UpdateDevicePoolError {
             UpdateDevicePoolError::LimitExceeded(ref cause) => write!(f, "{}", cause),
             UpdateDevicePoolError::NotFound(ref cause) => write!(f, "{}", cause),
             UpdateDevicePoolError::ServiceAccount(ref cause) => write!(f, "{}", cause),
+            UpdateDevicePoolError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for UpdateDevicePoolError {}
 /// Errors returned by UpdateInstanceProfile
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdateInstanceProfileError {
     /// <p>An invalid argument was specified.</p>
     Argument(String),
@@ -6845,6 +7402,8 @@ pub enum UpdateInstanceProfileError {
     NotFound(String),
     /// <p>There was a problem with the service account.</p>
     ServiceAccount(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdateInstanceProfileError {
@@ -6852,21 +7411,31 @@ impl UpdateInstanceProfileError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ArgumentException" => {
-                    return RusotoError::Service(UpdateInstanceProfileError::Argument(err.msg))
+                    return RusotoError::service(
+                        UpdateInstanceProfileError::Argument(err.msg),
+                        &res,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(UpdateInstanceProfileError::LimitExceeded(err.msg))
+                    return RusotoError::service(
+                        UpdateInstanceProfileError::LimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(UpdateInstanceProfileError::NotFound(err.msg))
+                    return RusotoError::service(
+                        UpdateInstanceProfileError::NotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceAccountException" => {
-                    return RusotoError::Service(UpdateInstanceProfileError::ServiceAccount(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateInstanceProfileError::ServiceAccount(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UpdateInstanceProfileError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -6880,12 +7449,14 @@ impl fmt::Display for UpdateInstanceProfileError {
             UpdateInstanceProfileError::LimitExceeded(ref cause) => write!(f, "{}", cause),
             UpdateInstanceProfileError::NotFound(ref cause) => write!(f, "{}", cause),
             UpdateInstanceProfileError::ServiceAccount(ref cause) => write!(f, "{}", cause),
+            UpdateInstanceProfileError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for UpdateInstanceProfileError {}
 /// Errors returned by UpdateNetworkProfile
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdateNetworkProfileError {
     /// <p>An invalid argument was specified.</p>
     Argument(String),
@@ -6895,6 +7466,8 @@ pub enum UpdateNetworkProfileError {
     NotFound(String),
     /// <p>There was a problem with the service account.</p>
     ServiceAccount(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdateNetworkProfileError {
@@ -6902,19 +7475,25 @@ impl UpdateNetworkProfileError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ArgumentException" => {
-                    return RusotoError::Service(UpdateNetworkProfileError::Argument(err.msg))
+                    return RusotoError::service(UpdateNetworkProfileError::Argument(err.msg), &res)
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(UpdateNetworkProfileError::LimitExceeded(err.msg))
+                    return RusotoError::service(
+                        UpdateNetworkProfileError::LimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(UpdateNetworkProfileError::NotFound(err.msg))
+                    return RusotoError::service(UpdateNetworkProfileError::NotFound(err.msg), &res)
                 }
                 "ServiceAccountException" => {
-                    return RusotoError::Service(UpdateNetworkProfileError::ServiceAccount(err.msg))
+                    return RusotoError::service(
+                        UpdateNetworkProfileError::ServiceAccount(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UpdateNetworkProfileError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -6928,12 +7507,14 @@ impl fmt::Display for UpdateNetworkProfileError {
             UpdateNetworkProfileError::LimitExceeded(ref cause) => write!(f, "{}", cause),
             UpdateNetworkProfileError::NotFound(ref cause) => write!(f, "{}", cause),
             UpdateNetworkProfileError::ServiceAccount(ref cause) => write!(f, "{}", cause),
+            UpdateNetworkProfileError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for UpdateNetworkProfileError {}
 /// Errors returned by UpdateProject
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdateProjectError {
     /// <p>An invalid argument was specified.</p>
     Argument(String),
@@ -6943,6 +7524,8 @@ pub enum UpdateProjectError {
     NotFound(String),
     /// <p>There was a problem with the service account.</p>
     ServiceAccount(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdateProjectError {
@@ -6950,19 +7533,19 @@ impl UpdateProjectError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ArgumentException" => {
-                    return RusotoError::Service(UpdateProjectError::Argument(err.msg))
+                    return RusotoError::service(UpdateProjectError::Argument(err.msg), &res)
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(UpdateProjectError::LimitExceeded(err.msg))
+                    return RusotoError::service(UpdateProjectError::LimitExceeded(err.msg), &res)
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(UpdateProjectError::NotFound(err.msg))
+                    return RusotoError::service(UpdateProjectError::NotFound(err.msg), &res)
                 }
                 "ServiceAccountException" => {
-                    return RusotoError::Service(UpdateProjectError::ServiceAccount(err.msg))
+                    return RusotoError::service(UpdateProjectError::ServiceAccount(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UpdateProjectError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -6976,12 +7559,14 @@ impl fmt::Display for UpdateProjectError {
             UpdateProjectError::LimitExceeded(ref cause) => write!(f, "{}", cause),
             UpdateProjectError::NotFound(ref cause) => write!(f, "{}", cause),
             UpdateProjectError::ServiceAccount(ref cause) => write!(f, "{}", cause),
+            UpdateProjectError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for UpdateProjectError {}
 /// Errors returned by UpdateTestGridProject
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdateTestGridProjectError {
     /// <p>An invalid argument was specified.</p>
     Argument(String),
@@ -6989,6 +7574,8 @@ pub enum UpdateTestGridProjectError {
     InternalService(String),
     /// <p>The specified entity was not found.</p>
     NotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdateTestGridProjectError {
@@ -6996,18 +7583,25 @@ impl UpdateTestGridProjectError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ArgumentException" => {
-                    return RusotoError::Service(UpdateTestGridProjectError::Argument(err.msg))
+                    return RusotoError::service(
+                        UpdateTestGridProjectError::Argument(err.msg),
+                        &res,
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(UpdateTestGridProjectError::InternalService(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateTestGridProjectError::InternalService(err.msg),
+                        &res,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(UpdateTestGridProjectError::NotFound(err.msg))
+                    return RusotoError::service(
+                        UpdateTestGridProjectError::NotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UpdateTestGridProjectError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -7020,12 +7614,14 @@ impl fmt::Display for UpdateTestGridProjectError {
             UpdateTestGridProjectError::Argument(ref cause) => write!(f, "{}", cause),
             UpdateTestGridProjectError::InternalService(ref cause) => write!(f, "{}", cause),
             UpdateTestGridProjectError::NotFound(ref cause) => write!(f, "{}", cause),
+            UpdateTestGridProjectError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for UpdateTestGridProjectError {}
 /// Errors returned by UpdateUpload
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdateUploadError {
     /// <p>An invalid argument was specified.</p>
     Argument(String),
@@ -7035,6 +7631,8 @@ pub enum UpdateUploadError {
     NotFound(String),
     /// <p>There was a problem with the service account.</p>
     ServiceAccount(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdateUploadError {
@@ -7042,19 +7640,19 @@ impl UpdateUploadError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ArgumentException" => {
-                    return RusotoError::Service(UpdateUploadError::Argument(err.msg))
+                    return RusotoError::service(UpdateUploadError::Argument(err.msg), &res)
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(UpdateUploadError::LimitExceeded(err.msg))
+                    return RusotoError::service(UpdateUploadError::LimitExceeded(err.msg), &res)
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(UpdateUploadError::NotFound(err.msg))
+                    return RusotoError::service(UpdateUploadError::NotFound(err.msg), &res)
                 }
                 "ServiceAccountException" => {
-                    return RusotoError::Service(UpdateUploadError::ServiceAccount(err.msg))
+                    return RusotoError::service(UpdateUploadError::ServiceAccount(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UpdateUploadError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -7068,12 +7666,14 @@ impl fmt::Display for UpdateUploadError {
             UpdateUploadError::LimitExceeded(ref cause) => write!(f, "{}", cause),
             UpdateUploadError::NotFound(ref cause) => write!(f, "{}", cause),
             UpdateUploadError::ServiceAccount(ref cause) => write!(f, "{}", cause),
+            UpdateUploadError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for UpdateUploadError {}
 /// Errors returned by UpdateVPCEConfiguration
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdateVPCEConfigurationError {
     /// <p>An invalid argument was specified.</p>
     Argument(String),
@@ -7083,6 +7683,8 @@ pub enum UpdateVPCEConfigurationError {
     NotFound(String),
     /// <p>There was a problem with the service account.</p>
     ServiceAccount(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdateVPCEConfigurationError {
@@ -7090,23 +7692,31 @@ impl UpdateVPCEConfigurationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ArgumentException" => {
-                    return RusotoError::Service(UpdateVPCEConfigurationError::Argument(err.msg))
+                    return RusotoError::service(
+                        UpdateVPCEConfigurationError::Argument(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidOperationException" => {
-                    return RusotoError::Service(UpdateVPCEConfigurationError::InvalidOperation(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateVPCEConfigurationError::InvalidOperation(err.msg),
+                        &res,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(UpdateVPCEConfigurationError::NotFound(err.msg))
+                    return RusotoError::service(
+                        UpdateVPCEConfigurationError::NotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceAccountException" => {
-                    return RusotoError::Service(UpdateVPCEConfigurationError::ServiceAccount(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateVPCEConfigurationError::ServiceAccount(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UpdateVPCEConfigurationError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -7120,6 +7730,7 @@ impl fmt::Display for UpdateVPCEConfigurationError {
             UpdateVPCEConfigurationError::InvalidOperation(ref cause) => write!(f, "{}", cause),
             UpdateVPCEConfigurationError::NotFound(ref cause) => write!(f, "{}", cause),
             UpdateVPCEConfigurationError::ServiceAccount(ref cause) => write!(f, "{}", cause),
+            UpdateVPCEConfigurationError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
@@ -7595,6 +8206,7 @@ impl DeviceFarmClient {
     /// Creates a client backed by the default tokio event loop.
     ///
     /// The client will use the default credentials provider and tls client.
+    #[cfg(not(target_arch = "wasm32"))]
     pub fn new(region: region::Region) -> DeviceFarmClient {
         DeviceFarmClient {
             client: Client::shared(),
@@ -7620,126 +8232,294 @@ impl DeviceFarmClient {
     pub fn new_with_client(client: Client, region: region::Region) -> DeviceFarmClient {
         DeviceFarmClient { client, region }
     }
+
+    /// Returns a builder of a client, setting any of its region, credentials provider,
+    /// dispatcher, retry policy, endpoint and timeouts.
+    pub fn builder() -> rusoto_core::ClientBuilder<DeviceFarmClient> {
+        rusoto_core::ClientBuilder::new(DeviceFarmClient::new_with_client)
+    }
+
+    /// Returns a clone of this client with the overrides of `config` applied to its
+    /// operations, sharing its connections.
+    pub fn customize(&self, config: &rusoto_core::OperationConfig) -> DeviceFarmClient {
+        DeviceFarmClient {
+            client: self.client.customize(config),
+            region: config.resolve_region(&self.region),
+        }
+    }
 }
 
-#[async_trait]
-impl DeviceFarm for DeviceFarmClient {
-    /// <p>Creates a device pool.</p>
-    async fn create_device_pool(
+impl DeviceFarmClient {
+    /// Calls `create_device_pool`, also returning the metadata of the response.
+    pub async fn create_device_pool_with_metadata(
         &self,
         input: CreateDevicePoolRequest,
-    ) -> Result<CreateDevicePoolResult, RusotoError<CreateDevicePoolError>> {
+    ) -> Result<(CreateDevicePoolResult, ResponseMetadata), RusotoError<CreateDevicePoolError>>
+    {
+        let request = self.build_create_device_pool_request(input);
+
+        let mut response = self
+            .client
+            .sign_and_dispatch(request)
+            .await
+            .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
+        if response.status.is_success() {
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<CreateDevicePoolResult, _>()?;
+            Ok((result, metadata))
+        } else {
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(CreateDevicePoolError::from_response(response))
+        }
+    }
+
+    /// Presigns the request `create_device_pool` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_device_pool(
+        &self,
+        presigner: &rusoto_core::Presigner,
+        input: CreateDevicePoolRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_device_pool_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_device_pool_request(&self, input: CreateDevicePoolRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "devicefarm", &self.region, "/");
+        request.set_operation("CreateDevicePool");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "DeviceFarm_20150623.CreateDevicePool");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `create_instance_profile`, also returning the metadata of the response.
+    pub async fn create_instance_profile_with_metadata(
+        &self,
+        input: CreateInstanceProfileRequest,
+    ) -> Result<
+        (CreateInstanceProfileResult, ResponseMetadata),
+        RusotoError<CreateInstanceProfileError>,
+    > {
+        let request = self.build_create_instance_profile_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<CreateDevicePoolResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<CreateInstanceProfileResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(CreateDevicePoolError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(CreateInstanceProfileError::from_response(response))
         }
     }
 
-    /// <p>Creates a profile that can be applied to one or more private fleet device instances.</p>
-    async fn create_instance_profile(
+    /// Presigns the request `create_instance_profile` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_instance_profile(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: CreateInstanceProfileRequest,
-    ) -> Result<CreateInstanceProfileResult, RusotoError<CreateInstanceProfileError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_instance_profile_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_instance_profile_request(
+        &self,
+        input: CreateInstanceProfileRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "devicefarm", &self.region, "/");
+        request.set_operation("CreateInstanceProfile");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "DeviceFarm_20150623.CreateInstanceProfile");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `create_network_profile`, also returning the metadata of the response.
+    pub async fn create_network_profile_with_metadata(
+        &self,
+        input: CreateNetworkProfileRequest,
+    ) -> Result<
+        (CreateNetworkProfileResult, ResponseMetadata),
+        RusotoError<CreateNetworkProfileError>,
+    > {
+        let request = self.build_create_network_profile_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<CreateInstanceProfileResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<CreateNetworkProfileResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(CreateInstanceProfileError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(CreateNetworkProfileError::from_response(response))
         }
     }
 
-    /// <p>Creates a network profile.</p>
-    async fn create_network_profile(
+    /// Presigns the request `create_network_profile` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_network_profile(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: CreateNetworkProfileRequest,
-    ) -> Result<CreateNetworkProfileResult, RusotoError<CreateNetworkProfileError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_network_profile_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_network_profile_request(
+        &self,
+        input: CreateNetworkProfileRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "devicefarm", &self.region, "/");
+        request.set_operation("CreateNetworkProfile");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "DeviceFarm_20150623.CreateNetworkProfile");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `create_project`, also returning the metadata of the response.
+    pub async fn create_project_with_metadata(
+        &self,
+        input: CreateProjectRequest,
+    ) -> Result<(CreateProjectResult, ResponseMetadata), RusotoError<CreateProjectError>> {
+        let request = self.build_create_project_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<CreateNetworkProfileResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<CreateProjectResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(CreateNetworkProfileError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(CreateProjectError::from_response(response))
         }
     }
 
-    /// <p>Creates a project.</p>
-    async fn create_project(
+    /// Presigns the request `create_project` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_project(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: CreateProjectRequest,
-    ) -> Result<CreateProjectResult, RusotoError<CreateProjectError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_project_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_project_request(&self, input: CreateProjectRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "devicefarm", &self.region, "/");
+        request.set_operation("CreateProject");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "DeviceFarm_20150623.CreateProject");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `create_remote_access_session`, also returning the metadata of the response.
+    pub async fn create_remote_access_session_with_metadata(
+        &self,
+        input: CreateRemoteAccessSessionRequest,
+    ) -> Result<
+        (CreateRemoteAccessSessionResult, ResponseMetadata),
+        RusotoError<CreateRemoteAccessSessionError>,
+    > {
+        let request = self.build_create_remote_access_session_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<CreateProjectResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<CreateRemoteAccessSessionResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(CreateProjectError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(CreateRemoteAccessSessionError::from_response(response))
         }
     }
 
-    /// <p>Specifies and starts a remote access session.</p>
-    async fn create_remote_access_session(
+    /// Presigns the request `create_remote_access_session` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_remote_access_session(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: CreateRemoteAccessSessionRequest,
-    ) -> Result<CreateRemoteAccessSessionResult, RusotoError<CreateRemoteAccessSessionError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_remote_access_session_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_remote_access_session_request(
+        &self,
+        input: CreateRemoteAccessSessionRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "devicefarm", &self.region, "/");
+        request.set_operation("CreateRemoteAccessSession");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -7749,110 +8529,221 @@ impl DeviceFarm for DeviceFarmClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `create_test_grid_project`, also returning the metadata of the response.
+    pub async fn create_test_grid_project_with_metadata(
+        &self,
+        input: CreateTestGridProjectRequest,
+    ) -> Result<
+        (CreateTestGridProjectResult, ResponseMetadata),
+        RusotoError<CreateTestGridProjectError>,
+    > {
+        let request = self.build_create_test_grid_project_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<CreateRemoteAccessSessionResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<CreateTestGridProjectResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(CreateRemoteAccessSessionError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(CreateTestGridProjectError::from_response(response))
         }
     }
 
-    /// <p>Creates a Selenium testing project. Projects are used to track <a>TestGridSession</a> instances.</p>
-    async fn create_test_grid_project(
+    /// Presigns the request `create_test_grid_project` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_test_grid_project(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: CreateTestGridProjectRequest,
-    ) -> Result<CreateTestGridProjectResult, RusotoError<CreateTestGridProjectError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_test_grid_project_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_test_grid_project_request(
+        &self,
+        input: CreateTestGridProjectRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "devicefarm", &self.region, "/");
+        request.set_operation("CreateTestGridProject");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "DeviceFarm_20150623.CreateTestGridProject");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `create_test_grid_url`, also returning the metadata of the response.
+    pub async fn create_test_grid_url_with_metadata(
+        &self,
+        input: CreateTestGridUrlRequest,
+    ) -> Result<(CreateTestGridUrlResult, ResponseMetadata), RusotoError<CreateTestGridUrlError>>
+    {
+        let request = self.build_create_test_grid_url_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<CreateTestGridProjectResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<CreateTestGridUrlResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(CreateTestGridProjectError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(CreateTestGridUrlError::from_response(response))
         }
     }
 
-    /// <p>Creates a signed, short-term URL that can be passed to a Selenium <code>RemoteWebDriver</code> constructor.</p>
-    async fn create_test_grid_url(
+    /// Presigns the request `create_test_grid_url` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_test_grid_url(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: CreateTestGridUrlRequest,
-    ) -> Result<CreateTestGridUrlResult, RusotoError<CreateTestGridUrlError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_test_grid_url_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_test_grid_url_request(&self, input: CreateTestGridUrlRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "devicefarm", &self.region, "/");
+        request.set_operation("CreateTestGridUrl");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "DeviceFarm_20150623.CreateTestGridUrl");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `create_upload`, also returning the metadata of the response.
+    pub async fn create_upload_with_metadata(
+        &self,
+        input: CreateUploadRequest,
+    ) -> Result<(CreateUploadResult, ResponseMetadata), RusotoError<CreateUploadError>> {
+        let request = self.build_create_upload_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<CreateTestGridUrlResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<CreateUploadResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(CreateTestGridUrlError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(CreateUploadError::from_response(response))
         }
     }
 
-    /// <p>Uploads an app or test scripts.</p>
-    async fn create_upload(
+    /// Presigns the request `create_upload` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_upload(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: CreateUploadRequest,
-    ) -> Result<CreateUploadResult, RusotoError<CreateUploadError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_upload_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_upload_request(&self, input: CreateUploadRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "devicefarm", &self.region, "/");
+        request.set_operation("CreateUpload");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "DeviceFarm_20150623.CreateUpload");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `create_vpce_configuration`, also returning the metadata of the response.
+    pub async fn create_vpce_configuration_with_metadata(
+        &self,
+        input: CreateVPCEConfigurationRequest,
+    ) -> Result<
+        (CreateVPCEConfigurationResult, ResponseMetadata),
+        RusotoError<CreateVPCEConfigurationError>,
+    > {
+        let request = self.build_create_vpce_configuration_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<CreateUploadResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<CreateVPCEConfigurationResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(CreateUploadError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(CreateVPCEConfigurationError::from_response(response))
         }
     }
 
-    /// <p>Creates a configuration record in Device Farm for your Amazon Virtual Private Cloud (VPC) endpoint.</p>
-    async fn create_vpce_configuration(
+    /// Presigns the request `create_vpce_configuration` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_vpce_configuration(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: CreateVPCEConfigurationRequest,
-    ) -> Result<CreateVPCEConfigurationResult, RusotoError<CreateVPCEConfigurationError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_vpce_configuration_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_vpce_configuration_request(
+        &self,
+        input: CreateVPCEConfigurationRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "devicefarm", &self.region, "/");
+        request.set_operation("CreateVPCEConfiguration");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -7862,138 +8753,279 @@ impl DeviceFarm for DeviceFarmClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `delete_device_pool`, also returning the metadata of the response.
+    pub async fn delete_device_pool_with_metadata(
+        &self,
+        input: DeleteDevicePoolRequest,
+    ) -> Result<(DeleteDevicePoolResult, ResponseMetadata), RusotoError<DeleteDevicePoolError>>
+    {
+        let request = self.build_delete_device_pool_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<CreateVPCEConfigurationResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DeleteDevicePoolResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(CreateVPCEConfigurationError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DeleteDevicePoolError::from_response(response))
         }
     }
 
-    /// <p>Deletes a device pool given the pool ARN. Does not allow deletion of curated pools owned by the system.</p>
-    async fn delete_device_pool(
+    /// Presigns the request `delete_device_pool` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_device_pool(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteDevicePoolRequest,
-    ) -> Result<DeleteDevicePoolResult, RusotoError<DeleteDevicePoolError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_device_pool_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_device_pool_request(&self, input: DeleteDevicePoolRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "devicefarm", &self.region, "/");
+        request.set_operation("DeleteDevicePool");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "DeviceFarm_20150623.DeleteDevicePool");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `delete_instance_profile`, also returning the metadata of the response.
+    pub async fn delete_instance_profile_with_metadata(
+        &self,
+        input: DeleteInstanceProfileRequest,
+    ) -> Result<
+        (DeleteInstanceProfileResult, ResponseMetadata),
+        RusotoError<DeleteInstanceProfileError>,
+    > {
+        let request = self.build_delete_instance_profile_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<DeleteDevicePoolResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DeleteInstanceProfileResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteDevicePoolError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DeleteInstanceProfileError::from_response(response))
         }
     }
 
-    /// <p>Deletes a profile that can be applied to one or more private device instances.</p>
-    async fn delete_instance_profile(
+    /// Presigns the request `delete_instance_profile` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_instance_profile(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteInstanceProfileRequest,
-    ) -> Result<DeleteInstanceProfileResult, RusotoError<DeleteInstanceProfileError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_instance_profile_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_instance_profile_request(
+        &self,
+        input: DeleteInstanceProfileRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "devicefarm", &self.region, "/");
+        request.set_operation("DeleteInstanceProfile");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "DeviceFarm_20150623.DeleteInstanceProfile");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `delete_network_profile`, also returning the metadata of the response.
+    pub async fn delete_network_profile_with_metadata(
+        &self,
+        input: DeleteNetworkProfileRequest,
+    ) -> Result<
+        (DeleteNetworkProfileResult, ResponseMetadata),
+        RusotoError<DeleteNetworkProfileError>,
+    > {
+        let request = self.build_delete_network_profile_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DeleteInstanceProfileResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DeleteNetworkProfileResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteInstanceProfileError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DeleteNetworkProfileError::from_response(response))
         }
     }
 
-    /// <p>Deletes a network profile.</p>
-    async fn delete_network_profile(
+    /// Presigns the request `delete_network_profile` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_network_profile(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteNetworkProfileRequest,
-    ) -> Result<DeleteNetworkProfileResult, RusotoError<DeleteNetworkProfileError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_network_profile_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_network_profile_request(
+        &self,
+        input: DeleteNetworkProfileRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "devicefarm", &self.region, "/");
+        request.set_operation("DeleteNetworkProfile");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "DeviceFarm_20150623.DeleteNetworkProfile");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `delete_project`, also returning the metadata of the response.
+    pub async fn delete_project_with_metadata(
+        &self,
+        input: DeleteProjectRequest,
+    ) -> Result<(DeleteProjectResult, ResponseMetadata), RusotoError<DeleteProjectError>> {
+        let request = self.build_delete_project_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DeleteNetworkProfileResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DeleteProjectResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteNetworkProfileError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DeleteProjectError::from_response(response))
         }
     }
 
-    /// <p>Deletes an AWS Device Farm project, given the project ARN.</p> <p> Deleting this resource does not stop an in-progress run.</p>
-    async fn delete_project(
+    /// Presigns the request `delete_project` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_project(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteProjectRequest,
-    ) -> Result<DeleteProjectResult, RusotoError<DeleteProjectError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_project_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_project_request(&self, input: DeleteProjectRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "devicefarm", &self.region, "/");
+        request.set_operation("DeleteProject");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "DeviceFarm_20150623.DeleteProject");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `delete_remote_access_session`, also returning the metadata of the response.
+    pub async fn delete_remote_access_session_with_metadata(
+        &self,
+        input: DeleteRemoteAccessSessionRequest,
+    ) -> Result<
+        (DeleteRemoteAccessSessionResult, ResponseMetadata),
+        RusotoError<DeleteRemoteAccessSessionError>,
+    > {
+        let request = self.build_delete_remote_access_session_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<DeleteProjectResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DeleteRemoteAccessSessionResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteProjectError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DeleteRemoteAccessSessionError::from_response(response))
         }
     }
 
-    /// <p>Deletes a completed remote access session and its results.</p>
-    async fn delete_remote_access_session(
+    /// Presigns the request `delete_remote_access_session` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_remote_access_session(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteRemoteAccessSessionRequest,
-    ) -> Result<DeleteRemoteAccessSessionResult, RusotoError<DeleteRemoteAccessSessionError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_remote_access_session_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_remote_access_session_request(
+        &self,
+        input: DeleteRemoteAccessSessionRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "devicefarm", &self.region, "/");
+        request.set_operation("DeleteRemoteAccessSession");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -8003,110 +9035,220 @@ impl DeviceFarm for DeviceFarmClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `delete_run`, also returning the metadata of the response.
+    pub async fn delete_run_with_metadata(
+        &self,
+        input: DeleteRunRequest,
+    ) -> Result<(DeleteRunResult, ResponseMetadata), RusotoError<DeleteRunError>> {
+        let request = self.build_delete_run_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DeleteRemoteAccessSessionResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result =
+                proto::json::ResponsePayload::new(&response).deserialize::<DeleteRunResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteRemoteAccessSessionError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DeleteRunError::from_response(response))
         }
     }
 
-    /// <p>Deletes the run, given the run ARN.</p> <p> Deleting this resource does not stop an in-progress run.</p>
-    async fn delete_run(
+    /// Presigns the request `delete_run` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_run(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteRunRequest,
-    ) -> Result<DeleteRunResult, RusotoError<DeleteRunError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_run_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_run_request(&self, input: DeleteRunRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "devicefarm", &self.region, "/");
+        request.set_operation("DeleteRun");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "DeviceFarm_20150623.DeleteRun");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `delete_test_grid_project`, also returning the metadata of the response.
+    pub async fn delete_test_grid_project_with_metadata(
+        &self,
+        input: DeleteTestGridProjectRequest,
+    ) -> Result<
+        (DeleteTestGridProjectResult, ResponseMetadata),
+        RusotoError<DeleteTestGridProjectError>,
+    > {
+        let request = self.build_delete_test_grid_project_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<DeleteRunResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DeleteTestGridProjectResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteRunError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DeleteTestGridProjectError::from_response(response))
         }
     }
 
-    /// <p><p> Deletes a Selenium testing project and all content generated under it. </p> <important> <p>You cannot undo this operation.</p> </important> <note> <p>You cannot delete a project if it has active sessions.</p> </note></p>
-    async fn delete_test_grid_project(
+    /// Presigns the request `delete_test_grid_project` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_test_grid_project(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteTestGridProjectRequest,
-    ) -> Result<DeleteTestGridProjectResult, RusotoError<DeleteTestGridProjectError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_test_grid_project_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_test_grid_project_request(
+        &self,
+        input: DeleteTestGridProjectRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "devicefarm", &self.region, "/");
+        request.set_operation("DeleteTestGridProject");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "DeviceFarm_20150623.DeleteTestGridProject");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `delete_upload`, also returning the metadata of the response.
+    pub async fn delete_upload_with_metadata(
+        &self,
+        input: DeleteUploadRequest,
+    ) -> Result<(DeleteUploadResult, ResponseMetadata), RusotoError<DeleteUploadError>> {
+        let request = self.build_delete_upload_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DeleteTestGridProjectResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DeleteUploadResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteTestGridProjectError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DeleteUploadError::from_response(response))
         }
     }
 
-    /// <p>Deletes an upload given the upload ARN.</p>
-    async fn delete_upload(
+    /// Presigns the request `delete_upload` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_upload(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteUploadRequest,
-    ) -> Result<DeleteUploadResult, RusotoError<DeleteUploadError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_upload_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_upload_request(&self, input: DeleteUploadRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "devicefarm", &self.region, "/");
+        request.set_operation("DeleteUpload");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "DeviceFarm_20150623.DeleteUpload");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `delete_vpce_configuration`, also returning the metadata of the response.
+    pub async fn delete_vpce_configuration_with_metadata(
+        &self,
+        input: DeleteVPCEConfigurationRequest,
+    ) -> Result<
+        (DeleteVPCEConfigurationResult, ResponseMetadata),
+        RusotoError<DeleteVPCEConfigurationError>,
+    > {
+        let request = self.build_delete_vpce_configuration_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<DeleteUploadResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DeleteVPCEConfigurationResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteUploadError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DeleteVPCEConfigurationError::from_response(response))
         }
     }
 
-    /// <p>Deletes a configuration for your Amazon Virtual Private Cloud (VPC) endpoint.</p>
-    async fn delete_vpce_configuration(
+    /// Presigns the request `delete_vpce_configuration` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_vpce_configuration(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteVPCEConfigurationRequest,
-    ) -> Result<DeleteVPCEConfigurationResult, RusotoError<DeleteVPCEConfigurationError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_vpce_configuration_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_vpce_configuration_request(
+        &self,
+        input: DeleteVPCEConfigurationRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "devicefarm", &self.region, "/");
+        request.set_operation("DeleteVPCEConfiguration");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -8116,136 +9258,265 @@ impl DeviceFarm for DeviceFarmClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `get_account_settings`, also returning the metadata of the response.
+    pub async fn get_account_settings_with_metadata(
+        &self,
+    ) -> Result<(GetAccountSettingsResult, ResponseMetadata), RusotoError<GetAccountSettingsError>>
+    {
+        let request = self.build_get_account_settings_request();
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DeleteVPCEConfigurationResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<GetAccountSettingsResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteVPCEConfigurationError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(GetAccountSettingsError::from_response(response))
         }
     }
 
-    /// <p>Returns the number of unmetered iOS or unmetered Android devices that have been purchased by the account.</p>
-    async fn get_account_settings(
+    /// Presigns the request `get_account_settings` would send with `presigner` instead of sending it.
+    pub async fn presign_get_account_settings(
         &self,
-    ) -> Result<GetAccountSettingsResult, RusotoError<GetAccountSettingsError>> {
+        presigner: &rusoto_core::Presigner,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_account_settings_request();
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_account_settings_request(&self) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "devicefarm", &self.region, "/");
+        request.set_operation("GetAccountSettings");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "DeviceFarm_20150623.GetAccountSettings");
         request.set_payload(Some(bytes::Bytes::from_static(b"{}")));
 
+        request
+    }
+
+    /// Calls `get_device`, also returning the metadata of the response.
+    pub async fn get_device_with_metadata(
+        &self,
+        input: GetDeviceRequest,
+    ) -> Result<(GetDeviceResult, ResponseMetadata), RusotoError<GetDeviceError>> {
+        let request = self.build_get_device_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<GetAccountSettingsResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result =
+                proto::json::ResponsePayload::new(&response).deserialize::<GetDeviceResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(GetAccountSettingsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(GetDeviceError::from_response(response))
         }
     }
 
-    /// <p>Gets information about a unique device type.</p>
-    async fn get_device(
+    /// Presigns the request `get_device` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_device(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetDeviceRequest,
-    ) -> Result<GetDeviceResult, RusotoError<GetDeviceError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_device_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_device_request(&self, input: GetDeviceRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "devicefarm", &self.region, "/");
+        request.set_operation("GetDevice");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "DeviceFarm_20150623.GetDevice");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `get_device_instance`, also returning the metadata of the response.
+    pub async fn get_device_instance_with_metadata(
+        &self,
+        input: GetDeviceInstanceRequest,
+    ) -> Result<(GetDeviceInstanceResult, ResponseMetadata), RusotoError<GetDeviceInstanceError>>
+    {
+        let request = self.build_get_device_instance_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<GetDeviceResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<GetDeviceInstanceResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(GetDeviceError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(GetDeviceInstanceError::from_response(response))
         }
     }
 
-    /// <p>Returns information about a device instance that belongs to a private device fleet.</p>
-    async fn get_device_instance(
+    /// Presigns the request `get_device_instance` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_device_instance(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetDeviceInstanceRequest,
-    ) -> Result<GetDeviceInstanceResult, RusotoError<GetDeviceInstanceError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_device_instance_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_device_instance_request(&self, input: GetDeviceInstanceRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "devicefarm", &self.region, "/");
+        request.set_operation("GetDeviceInstance");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "DeviceFarm_20150623.GetDeviceInstance");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `get_device_pool`, also returning the metadata of the response.
+    pub async fn get_device_pool_with_metadata(
+        &self,
+        input: GetDevicePoolRequest,
+    ) -> Result<(GetDevicePoolResult, ResponseMetadata), RusotoError<GetDevicePoolError>> {
+        let request = self.build_get_device_pool_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<GetDeviceInstanceResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<GetDevicePoolResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(GetDeviceInstanceError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(GetDevicePoolError::from_response(response))
         }
     }
 
-    /// <p>Gets information about a device pool.</p>
-    async fn get_device_pool(
+    /// Presigns the request `get_device_pool` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_device_pool(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetDevicePoolRequest,
-    ) -> Result<GetDevicePoolResult, RusotoError<GetDevicePoolError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_device_pool_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_device_pool_request(&self, input: GetDevicePoolRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "devicefarm", &self.region, "/");
+        request.set_operation("GetDevicePool");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "DeviceFarm_20150623.GetDevicePool");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `get_device_pool_compatibility`, also returning the metadata of the response.
+    pub async fn get_device_pool_compatibility_with_metadata(
+        &self,
+        input: GetDevicePoolCompatibilityRequest,
+    ) -> Result<
+        (GetDevicePoolCompatibilityResult, ResponseMetadata),
+        RusotoError<GetDevicePoolCompatibilityError>,
+    > {
+        let request = self.build_get_device_pool_compatibility_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<GetDevicePoolResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<GetDevicePoolCompatibilityResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(GetDevicePoolError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(GetDevicePoolCompatibilityError::from_response(response))
         }
     }
 
-    /// <p>Gets information about compatibility with a device pool.</p>
-    async fn get_device_pool_compatibility(
+    /// Presigns the request `get_device_pool_compatibility` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_device_pool_compatibility(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetDevicePoolCompatibilityRequest,
-    ) -> Result<GetDevicePoolCompatibilityResult, RusotoError<GetDevicePoolCompatibilityError>>
-    {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_device_pool_compatibility_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_device_pool_compatibility_request(
+        &self,
+        input: GetDevicePoolCompatibilityRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "devicefarm", &self.region, "/");
+        request.set_operation("GetDevicePoolCompatibility");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -8255,385 +9526,760 @@ impl DeviceFarm for DeviceFarmClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `get_instance_profile`, also returning the metadata of the response.
+    pub async fn get_instance_profile_with_metadata(
+        &self,
+        input: GetInstanceProfileRequest,
+    ) -> Result<(GetInstanceProfileResult, ResponseMetadata), RusotoError<GetInstanceProfileError>>
+    {
+        let request = self.build_get_instance_profile_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<GetDevicePoolCompatibilityResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<GetInstanceProfileResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(GetDevicePoolCompatibilityError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(GetInstanceProfileError::from_response(response))
         }
     }
 
-    /// <p>Returns information about the specified instance profile.</p>
-    async fn get_instance_profile(
+    /// Presigns the request `get_instance_profile` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_instance_profile(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetInstanceProfileRequest,
-    ) -> Result<GetInstanceProfileResult, RusotoError<GetInstanceProfileError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_instance_profile_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_instance_profile_request(
+        &self,
+        input: GetInstanceProfileRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "devicefarm", &self.region, "/");
+        request.set_operation("GetInstanceProfile");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "DeviceFarm_20150623.GetInstanceProfile");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `get_job`, also returning the metadata of the response.
+    pub async fn get_job_with_metadata(
+        &self,
+        input: GetJobRequest,
+    ) -> Result<(GetJobResult, ResponseMetadata), RusotoError<GetJobError>> {
+        let request = self.build_get_job_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<GetInstanceProfileResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result =
+                proto::json::ResponsePayload::new(&response).deserialize::<GetJobResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(GetInstanceProfileError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(GetJobError::from_response(response))
         }
     }
 
-    /// <p>Gets information about a job.</p>
-    async fn get_job(
+    /// Presigns the request `get_job` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_job(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetJobRequest,
-    ) -> Result<GetJobResult, RusotoError<GetJobError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_job_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_job_request(&self, input: GetJobRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "devicefarm", &self.region, "/");
+        request.set_operation("GetJob");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "DeviceFarm_20150623.GetJob");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `get_network_profile`, also returning the metadata of the response.
+    pub async fn get_network_profile_with_metadata(
+        &self,
+        input: GetNetworkProfileRequest,
+    ) -> Result<(GetNetworkProfileResult, ResponseMetadata), RusotoError<GetNetworkProfileError>>
+    {
+        let request = self.build_get_network_profile_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<GetJobResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<GetNetworkProfileResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(GetJobError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(GetNetworkProfileError::from_response(response))
         }
     }
 
-    /// <p>Returns information about a network profile.</p>
-    async fn get_network_profile(
+    /// Presigns the request `get_network_profile` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_network_profile(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetNetworkProfileRequest,
-    ) -> Result<GetNetworkProfileResult, RusotoError<GetNetworkProfileError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_network_profile_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_network_profile_request(&self, input: GetNetworkProfileRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "devicefarm", &self.region, "/");
+        request.set_operation("GetNetworkProfile");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "DeviceFarm_20150623.GetNetworkProfile");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `get_offering_status`, also returning the metadata of the response.
+    pub async fn get_offering_status_with_metadata(
+        &self,
+        input: GetOfferingStatusRequest,
+    ) -> Result<(GetOfferingStatusResult, ResponseMetadata), RusotoError<GetOfferingStatusError>>
+    {
+        let request = self.build_get_offering_status_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<GetNetworkProfileResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<GetOfferingStatusResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(GetNetworkProfileError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(GetOfferingStatusError::from_response(response))
         }
     }
 
-    /// <p>Gets the current status and future status of all offerings purchased by an AWS account. The response indicates how many offerings are currently available and the offerings that will be available in the next period. The API returns a <code>NotEligible</code> error if the user is not permitted to invoke the operation. If you must be able to invoke this operation, contact <a href="mailto:aws-devicefarm-support@amazon.com">aws-devicefarm-support@amazon.com</a>.</p>
-    async fn get_offering_status(
+    /// Presigns the request `get_offering_status` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_offering_status(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetOfferingStatusRequest,
-    ) -> Result<GetOfferingStatusResult, RusotoError<GetOfferingStatusError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_offering_status_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_offering_status_request(&self, input: GetOfferingStatusRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "devicefarm", &self.region, "/");
+        request.set_operation("GetOfferingStatus");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "DeviceFarm_20150623.GetOfferingStatus");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `get_project`, also returning the metadata of the response.
+    pub async fn get_project_with_metadata(
+        &self,
+        input: GetProjectRequest,
+    ) -> Result<(GetProjectResult, ResponseMetadata), RusotoError<GetProjectError>> {
+        let request = self.build_get_project_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<GetOfferingStatusResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<GetProjectResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(GetOfferingStatusError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(GetProjectError::from_response(response))
         }
     }
 
-    /// <p>Gets information about a project.</p>
-    async fn get_project(
+    /// Presigns the request `get_project` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_project(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetProjectRequest,
-    ) -> Result<GetProjectResult, RusotoError<GetProjectError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_project_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_project_request(&self, input: GetProjectRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "devicefarm", &self.region, "/");
+        request.set_operation("GetProject");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "DeviceFarm_20150623.GetProject");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `get_remote_access_session`, also returning the metadata of the response.
+    pub async fn get_remote_access_session_with_metadata(
+        &self,
+        input: GetRemoteAccessSessionRequest,
+    ) -> Result<
+        (GetRemoteAccessSessionResult, ResponseMetadata),
+        RusotoError<GetRemoteAccessSessionError>,
+    > {
+        let request = self.build_get_remote_access_session_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<GetProjectResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<GetRemoteAccessSessionResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(GetProjectError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(GetRemoteAccessSessionError::from_response(response))
         }
     }
 
-    /// <p>Returns a link to a currently running remote access session.</p>
-    async fn get_remote_access_session(
+    /// Presigns the request `get_remote_access_session` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_remote_access_session(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetRemoteAccessSessionRequest,
-    ) -> Result<GetRemoteAccessSessionResult, RusotoError<GetRemoteAccessSessionError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_remote_access_session_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_remote_access_session_request(
+        &self,
+        input: GetRemoteAccessSessionRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "devicefarm", &self.region, "/");
+        request.set_operation("GetRemoteAccessSession");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "DeviceFarm_20150623.GetRemoteAccessSession");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `get_run`, also returning the metadata of the response.
+    pub async fn get_run_with_metadata(
+        &self,
+        input: GetRunRequest,
+    ) -> Result<(GetRunResult, ResponseMetadata), RusotoError<GetRunError>> {
+        let request = self.build_get_run_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<GetRemoteAccessSessionResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result =
+                proto::json::ResponsePayload::new(&response).deserialize::<GetRunResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(GetRemoteAccessSessionError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(GetRunError::from_response(response))
         }
     }
 
-    /// <p>Gets information about a run.</p>
-    async fn get_run(
+    /// Presigns the request `get_run` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_run(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetRunRequest,
-    ) -> Result<GetRunResult, RusotoError<GetRunError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_run_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_run_request(&self, input: GetRunRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "devicefarm", &self.region, "/");
+        request.set_operation("GetRun");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "DeviceFarm_20150623.GetRun");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `get_suite`, also returning the metadata of the response.
+    pub async fn get_suite_with_metadata(
+        &self,
+        input: GetSuiteRequest,
+    ) -> Result<(GetSuiteResult, ResponseMetadata), RusotoError<GetSuiteError>> {
+        let request = self.build_get_suite_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<GetRunResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result =
+                proto::json::ResponsePayload::new(&response).deserialize::<GetSuiteResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(GetRunError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(GetSuiteError::from_response(response))
         }
     }
 
-    /// <p>Gets information about a suite.</p>
-    async fn get_suite(
+    /// Presigns the request `get_suite` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_suite(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetSuiteRequest,
-    ) -> Result<GetSuiteResult, RusotoError<GetSuiteError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_suite_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_suite_request(&self, input: GetSuiteRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "devicefarm", &self.region, "/");
+        request.set_operation("GetSuite");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "DeviceFarm_20150623.GetSuite");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `get_test`, also returning the metadata of the response.
+    pub async fn get_test_with_metadata(
+        &self,
+        input: GetTestRequest,
+    ) -> Result<(GetTestResult, ResponseMetadata), RusotoError<GetTestError>> {
+        let request = self.build_get_test_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<GetSuiteResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result =
+                proto::json::ResponsePayload::new(&response).deserialize::<GetTestResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(GetSuiteError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(GetTestError::from_response(response))
         }
     }
 
-    /// <p>Gets information about a test.</p>
-    async fn get_test(
+    /// Presigns the request `get_test` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_test(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetTestRequest,
-    ) -> Result<GetTestResult, RusotoError<GetTestError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_test_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_test_request(&self, input: GetTestRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "devicefarm", &self.region, "/");
+        request.set_operation("GetTest");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "DeviceFarm_20150623.GetTest");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `get_test_grid_project`, also returning the metadata of the response.
+    pub async fn get_test_grid_project_with_metadata(
+        &self,
+        input: GetTestGridProjectRequest,
+    ) -> Result<(GetTestGridProjectResult, ResponseMetadata), RusotoError<GetTestGridProjectError>>
+    {
+        let request = self.build_get_test_grid_project_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<GetTestResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<GetTestGridProjectResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(GetTestError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(GetTestGridProjectError::from_response(response))
         }
     }
 
-    /// <p>Retrieves information about a Selenium testing project.</p>
-    async fn get_test_grid_project(
+    /// Presigns the request `get_test_grid_project` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_test_grid_project(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetTestGridProjectRequest,
-    ) -> Result<GetTestGridProjectResult, RusotoError<GetTestGridProjectError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_test_grid_project_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_test_grid_project_request(
+        &self,
+        input: GetTestGridProjectRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "devicefarm", &self.region, "/");
+        request.set_operation("GetTestGridProject");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "DeviceFarm_20150623.GetTestGridProject");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `get_test_grid_session`, also returning the metadata of the response.
+    pub async fn get_test_grid_session_with_metadata(
+        &self,
+        input: GetTestGridSessionRequest,
+    ) -> Result<(GetTestGridSessionResult, ResponseMetadata), RusotoError<GetTestGridSessionError>>
+    {
+        let request = self.build_get_test_grid_session_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<GetTestGridProjectResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<GetTestGridSessionResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(GetTestGridProjectError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(GetTestGridSessionError::from_response(response))
         }
     }
 
-    /// <p><p>A session is an instance of a browser created through a <code>RemoteWebDriver</code> with the URL from <a>CreateTestGridUrlResult$url</a>. You can use the following to look up sessions:</p> <ul> <li> <p>The session ARN (<a>GetTestGridSessionRequest$sessionArn</a>).</p> </li> <li> <p>The project ARN and a session ID (<a>GetTestGridSessionRequest$projectArn</a> and <a>GetTestGridSessionRequest$sessionId</a>).</p> </li> </ul> <p/></p>
-    async fn get_test_grid_session(
+    /// Presigns the request `get_test_grid_session` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_test_grid_session(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetTestGridSessionRequest,
-    ) -> Result<GetTestGridSessionResult, RusotoError<GetTestGridSessionError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_test_grid_session_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_test_grid_session_request(
+        &self,
+        input: GetTestGridSessionRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "devicefarm", &self.region, "/");
+        request.set_operation("GetTestGridSession");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "DeviceFarm_20150623.GetTestGridSession");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `get_upload`, also returning the metadata of the response.
+    pub async fn get_upload_with_metadata(
+        &self,
+        input: GetUploadRequest,
+    ) -> Result<(GetUploadResult, ResponseMetadata), RusotoError<GetUploadError>> {
+        let request = self.build_get_upload_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<GetTestGridSessionResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result =
+                proto::json::ResponsePayload::new(&response).deserialize::<GetUploadResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(GetTestGridSessionError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(GetUploadError::from_response(response))
         }
     }
 
-    /// <p>Gets information about an upload.</p>
-    async fn get_upload(
+    /// Presigns the request `get_upload` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_upload(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetUploadRequest,
-    ) -> Result<GetUploadResult, RusotoError<GetUploadError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_upload_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_upload_request(&self, input: GetUploadRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "devicefarm", &self.region, "/");
+        request.set_operation("GetUpload");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "DeviceFarm_20150623.GetUpload");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `get_vpce_configuration`, also returning the metadata of the response.
+    pub async fn get_vpce_configuration_with_metadata(
+        &self,
+        input: GetVPCEConfigurationRequest,
+    ) -> Result<
+        (GetVPCEConfigurationResult, ResponseMetadata),
+        RusotoError<GetVPCEConfigurationError>,
+    > {
+        let request = self.build_get_vpce_configuration_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<GetUploadResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<GetVPCEConfigurationResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(GetUploadError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(GetVPCEConfigurationError::from_response(response))
         }
     }
 
-    /// <p>Returns information about the configuration settings for your Amazon Virtual Private Cloud (VPC) endpoint.</p>
-    async fn get_vpce_configuration(
+    /// Presigns the request `get_vpce_configuration` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_vpce_configuration(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetVPCEConfigurationRequest,
-    ) -> Result<GetVPCEConfigurationResult, RusotoError<GetVPCEConfigurationError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_vpce_configuration_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_vpce_configuration_request(
+        &self,
+        input: GetVPCEConfigurationRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "devicefarm", &self.region, "/");
+        request.set_operation("GetVPCEConfiguration");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "DeviceFarm_20150623.GetVPCEConfiguration");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `install_to_remote_access_session`, also returning the metadata of the response.
+    pub async fn install_to_remote_access_session_with_metadata(
+        &self,
+        input: InstallToRemoteAccessSessionRequest,
+    ) -> Result<
+        (InstallToRemoteAccessSessionResult, ResponseMetadata),
+        RusotoError<InstallToRemoteAccessSessionError>,
+    > {
+        let request = self.build_install_to_remote_access_session_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<GetVPCEConfigurationResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<InstallToRemoteAccessSessionResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(GetVPCEConfigurationError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(InstallToRemoteAccessSessionError::from_response(response))
         }
     }
 
-    /// <p>Installs an application to the device in a remote access session. For Android applications, the file must be in .apk format. For iOS applications, the file must be in .ipa format.</p>
-    async fn install_to_remote_access_session(
+    /// Presigns the request `install_to_remote_access_session` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_install_to_remote_access_session(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: InstallToRemoteAccessSessionRequest,
-    ) -> Result<InstallToRemoteAccessSessionResult, RusotoError<InstallToRemoteAccessSessionError>>
-    {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_install_to_remote_access_session_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_install_to_remote_access_session_request(
+        &self,
+        input: InstallToRemoteAccessSessionRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "devicefarm", &self.region, "/");
+        request.set_operation("InstallToRemoteAccessSession");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -8643,248 +10289,494 @@ impl DeviceFarm for DeviceFarmClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `list_artifacts`, also returning the metadata of the response.
+    pub async fn list_artifacts_with_metadata(
+        &self,
+        input: ListArtifactsRequest,
+    ) -> Result<(ListArtifactsResult, ResponseMetadata), RusotoError<ListArtifactsError>> {
+        let request = self.build_list_artifacts_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<InstallToRemoteAccessSessionResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ListArtifactsResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(InstallToRemoteAccessSessionError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ListArtifactsError::from_response(response))
         }
     }
 
-    /// <p>Gets information about artifacts.</p>
-    async fn list_artifacts(
+    /// Presigns the request `list_artifacts` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_artifacts(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListArtifactsRequest,
-    ) -> Result<ListArtifactsResult, RusotoError<ListArtifactsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_artifacts_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_artifacts_request(&self, input: ListArtifactsRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "devicefarm", &self.region, "/");
+        request.set_operation("ListArtifacts");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "DeviceFarm_20150623.ListArtifacts");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `list_device_instances`, also returning the metadata of the response.
+    pub async fn list_device_instances_with_metadata(
+        &self,
+        input: ListDeviceInstancesRequest,
+    ) -> Result<(ListDeviceInstancesResult, ResponseMetadata), RusotoError<ListDeviceInstancesError>>
+    {
+        let request = self.build_list_device_instances_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<ListArtifactsResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ListDeviceInstancesResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ListArtifactsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ListDeviceInstancesError::from_response(response))
         }
     }
 
-    /// <p>Returns information about the private device instances associated with one or more AWS accounts.</p>
-    async fn list_device_instances(
+    /// Presigns the request `list_device_instances` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_device_instances(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListDeviceInstancesRequest,
-    ) -> Result<ListDeviceInstancesResult, RusotoError<ListDeviceInstancesError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_device_instances_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_device_instances_request(
+        &self,
+        input: ListDeviceInstancesRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "devicefarm", &self.region, "/");
+        request.set_operation("ListDeviceInstances");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "DeviceFarm_20150623.ListDeviceInstances");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `list_device_pools`, also returning the metadata of the response.
+    pub async fn list_device_pools_with_metadata(
+        &self,
+        input: ListDevicePoolsRequest,
+    ) -> Result<(ListDevicePoolsResult, ResponseMetadata), RusotoError<ListDevicePoolsError>> {
+        let request = self.build_list_device_pools_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListDeviceInstancesResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ListDevicePoolsResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ListDeviceInstancesError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ListDevicePoolsError::from_response(response))
         }
     }
 
-    /// <p>Gets information about device pools.</p>
-    async fn list_device_pools(
+    /// Presigns the request `list_device_pools` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_device_pools(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListDevicePoolsRequest,
-    ) -> Result<ListDevicePoolsResult, RusotoError<ListDevicePoolsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_device_pools_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_device_pools_request(&self, input: ListDevicePoolsRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "devicefarm", &self.region, "/");
+        request.set_operation("ListDevicePools");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "DeviceFarm_20150623.ListDevicePools");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `list_devices`, also returning the metadata of the response.
+    pub async fn list_devices_with_metadata(
+        &self,
+        input: ListDevicesRequest,
+    ) -> Result<(ListDevicesResult, ResponseMetadata), RusotoError<ListDevicesError>> {
+        let request = self.build_list_devices_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<ListDevicePoolsResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ListDevicesResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ListDevicePoolsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ListDevicesError::from_response(response))
         }
     }
 
-    /// <p>Gets information about unique device types.</p>
-    async fn list_devices(
+    /// Presigns the request `list_devices` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_devices(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListDevicesRequest,
-    ) -> Result<ListDevicesResult, RusotoError<ListDevicesError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_devices_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_devices_request(&self, input: ListDevicesRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "devicefarm", &self.region, "/");
+        request.set_operation("ListDevices");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "DeviceFarm_20150623.ListDevices");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `list_instance_profiles`, also returning the metadata of the response.
+    pub async fn list_instance_profiles_with_metadata(
+        &self,
+        input: ListInstanceProfilesRequest,
+    ) -> Result<
+        (ListInstanceProfilesResult, ResponseMetadata),
+        RusotoError<ListInstanceProfilesError>,
+    > {
+        let request = self.build_list_instance_profiles_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<ListDevicesResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ListInstanceProfilesResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ListDevicesError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ListInstanceProfilesError::from_response(response))
         }
     }
 
-    /// <p>Returns information about all the instance profiles in an AWS account.</p>
-    async fn list_instance_profiles(
+    /// Presigns the request `list_instance_profiles` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_instance_profiles(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListInstanceProfilesRequest,
-    ) -> Result<ListInstanceProfilesResult, RusotoError<ListInstanceProfilesError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_instance_profiles_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_instance_profiles_request(
+        &self,
+        input: ListInstanceProfilesRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "devicefarm", &self.region, "/");
+        request.set_operation("ListInstanceProfiles");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "DeviceFarm_20150623.ListInstanceProfiles");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `list_jobs`, also returning the metadata of the response.
+    pub async fn list_jobs_with_metadata(
+        &self,
+        input: ListJobsRequest,
+    ) -> Result<(ListJobsResult, ResponseMetadata), RusotoError<ListJobsError>> {
+        let request = self.build_list_jobs_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListInstanceProfilesResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result =
+                proto::json::ResponsePayload::new(&response).deserialize::<ListJobsResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ListInstanceProfilesError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ListJobsError::from_response(response))
         }
     }
 
-    /// <p>Gets information about jobs for a given test run.</p>
-    async fn list_jobs(
+    /// Presigns the request `list_jobs` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_jobs(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListJobsRequest,
-    ) -> Result<ListJobsResult, RusotoError<ListJobsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_jobs_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_jobs_request(&self, input: ListJobsRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "devicefarm", &self.region, "/");
+        request.set_operation("ListJobs");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "DeviceFarm_20150623.ListJobs");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `list_network_profiles`, also returning the metadata of the response.
+    pub async fn list_network_profiles_with_metadata(
+        &self,
+        input: ListNetworkProfilesRequest,
+    ) -> Result<(ListNetworkProfilesResult, ResponseMetadata), RusotoError<ListNetworkProfilesError>>
+    {
+        let request = self.build_list_network_profiles_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<ListJobsResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ListNetworkProfilesResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ListJobsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ListNetworkProfilesError::from_response(response))
         }
     }
 
-    /// <p>Returns the list of available network profiles.</p>
-    async fn list_network_profiles(
+    /// Presigns the request `list_network_profiles` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_network_profiles(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListNetworkProfilesRequest,
-    ) -> Result<ListNetworkProfilesResult, RusotoError<ListNetworkProfilesError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_network_profiles_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_network_profiles_request(
+        &self,
+        input: ListNetworkProfilesRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "devicefarm", &self.region, "/");
+        request.set_operation("ListNetworkProfiles");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "DeviceFarm_20150623.ListNetworkProfiles");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `list_offering_promotions`, also returning the metadata of the response.
+    pub async fn list_offering_promotions_with_metadata(
+        &self,
+        input: ListOfferingPromotionsRequest,
+    ) -> Result<
+        (ListOfferingPromotionsResult, ResponseMetadata),
+        RusotoError<ListOfferingPromotionsError>,
+    > {
+        let request = self.build_list_offering_promotions_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListNetworkProfilesResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ListOfferingPromotionsResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ListNetworkProfilesError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ListOfferingPromotionsError::from_response(response))
         }
     }
 
-    /// <p>Returns a list of offering promotions. Each offering promotion record contains the ID and description of the promotion. The API returns a <code>NotEligible</code> error if the caller is not permitted to invoke the operation. Contact <a href="mailto:aws-devicefarm-support@amazon.com">aws-devicefarm-support@amazon.com</a> if you must be able to invoke this operation.</p>
-    async fn list_offering_promotions(
+    /// Presigns the request `list_offering_promotions` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_offering_promotions(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListOfferingPromotionsRequest,
-    ) -> Result<ListOfferingPromotionsResult, RusotoError<ListOfferingPromotionsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_offering_promotions_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_offering_promotions_request(
+        &self,
+        input: ListOfferingPromotionsRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "devicefarm", &self.region, "/");
+        request.set_operation("ListOfferingPromotions");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "DeviceFarm_20150623.ListOfferingPromotions");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `list_offering_transactions`, also returning the metadata of the response.
+    pub async fn list_offering_transactions_with_metadata(
+        &self,
+        input: ListOfferingTransactionsRequest,
+    ) -> Result<
+        (ListOfferingTransactionsResult, ResponseMetadata),
+        RusotoError<ListOfferingTransactionsError>,
+    > {
+        let request = self.build_list_offering_transactions_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListOfferingPromotionsResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ListOfferingTransactionsResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ListOfferingPromotionsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ListOfferingTransactionsError::from_response(response))
         }
     }
 
-    /// <p>Returns a list of all historical purchases, renewals, and system renewal transactions for an AWS account. The list is paginated and ordered by a descending timestamp (most recent transactions are first). The API returns a <code>NotEligible</code> error if the user is not permitted to invoke the operation. If you must be able to invoke this operation, contact <a href="mailto:aws-devicefarm-support@amazon.com">aws-devicefarm-support@amazon.com</a>.</p>
-    async fn list_offering_transactions(
+    /// Presigns the request `list_offering_transactions` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_offering_transactions(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListOfferingTransactionsRequest,
-    ) -> Result<ListOfferingTransactionsResult, RusotoError<ListOfferingTransactionsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_offering_transactions_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_offering_transactions_request(
+        &self,
+        input: ListOfferingTransactionsRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "devicefarm", &self.region, "/");
+        request.set_operation("ListOfferingTransactions");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -8894,82 +10786,162 @@ impl DeviceFarm for DeviceFarmClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `list_offerings`, also returning the metadata of the response.
+    pub async fn list_offerings_with_metadata(
+        &self,
+        input: ListOfferingsRequest,
+    ) -> Result<(ListOfferingsResult, ResponseMetadata), RusotoError<ListOfferingsError>> {
+        let request = self.build_list_offerings_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListOfferingTransactionsResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ListOfferingsResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ListOfferingTransactionsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ListOfferingsError::from_response(response))
         }
     }
 
-    /// <p>Returns a list of products or offerings that the user can manage through the API. Each offering record indicates the recurring price per unit and the frequency for that offering. The API returns a <code>NotEligible</code> error if the user is not permitted to invoke the operation. If you must be able to invoke this operation, contact <a href="mailto:aws-devicefarm-support@amazon.com">aws-devicefarm-support@amazon.com</a>.</p>
-    async fn list_offerings(
+    /// Presigns the request `list_offerings` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_offerings(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListOfferingsRequest,
-    ) -> Result<ListOfferingsResult, RusotoError<ListOfferingsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_offerings_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_offerings_request(&self, input: ListOfferingsRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "devicefarm", &self.region, "/");
+        request.set_operation("ListOfferings");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "DeviceFarm_20150623.ListOfferings");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `list_projects`, also returning the metadata of the response.
+    pub async fn list_projects_with_metadata(
+        &self,
+        input: ListProjectsRequest,
+    ) -> Result<(ListProjectsResult, ResponseMetadata), RusotoError<ListProjectsError>> {
+        let request = self.build_list_projects_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<ListOfferingsResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ListProjectsResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ListOfferingsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ListProjectsError::from_response(response))
         }
     }
 
-    /// <p>Gets information about projects.</p>
-    async fn list_projects(
+    /// Presigns the request `list_projects` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_projects(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListProjectsRequest,
-    ) -> Result<ListProjectsResult, RusotoError<ListProjectsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_projects_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_projects_request(&self, input: ListProjectsRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "devicefarm", &self.region, "/");
+        request.set_operation("ListProjects");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "DeviceFarm_20150623.ListProjects");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `list_remote_access_sessions`, also returning the metadata of the response.
+    pub async fn list_remote_access_sessions_with_metadata(
+        &self,
+        input: ListRemoteAccessSessionsRequest,
+    ) -> Result<
+        (ListRemoteAccessSessionsResult, ResponseMetadata),
+        RusotoError<ListRemoteAccessSessionsError>,
+    > {
+        let request = self.build_list_remote_access_sessions_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<ListProjectsResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ListRemoteAccessSessionsResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ListProjectsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ListRemoteAccessSessionsError::from_response(response))
         }
     }
 
-    /// <p>Returns a list of all currently running remote access sessions.</p>
-    async fn list_remote_access_sessions(
+    /// Presigns the request `list_remote_access_sessions` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_remote_access_sessions(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListRemoteAccessSessionsRequest,
-    ) -> Result<ListRemoteAccessSessionsResult, RusotoError<ListRemoteAccessSessionsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_remote_access_sessions_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_remote_access_sessions_request(
+        &self,
+        input: ListRemoteAccessSessionsRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "devicefarm", &self.region, "/");
+        request.set_operation("ListRemoteAccessSessions");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -8979,166 +10951,330 @@ impl DeviceFarm for DeviceFarmClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `list_runs`, also returning the metadata of the response.
+    pub async fn list_runs_with_metadata(
+        &self,
+        input: ListRunsRequest,
+    ) -> Result<(ListRunsResult, ResponseMetadata), RusotoError<ListRunsError>> {
+        let request = self.build_list_runs_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListRemoteAccessSessionsResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result =
+                proto::json::ResponsePayload::new(&response).deserialize::<ListRunsResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ListRemoteAccessSessionsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ListRunsError::from_response(response))
         }
     }
 
-    /// <p>Gets information about runs, given an AWS Device Farm project ARN.</p>
-    async fn list_runs(
+    /// Presigns the request `list_runs` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_runs(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListRunsRequest,
-    ) -> Result<ListRunsResult, RusotoError<ListRunsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_runs_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_runs_request(&self, input: ListRunsRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "devicefarm", &self.region, "/");
+        request.set_operation("ListRuns");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "DeviceFarm_20150623.ListRuns");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `list_samples`, also returning the metadata of the response.
+    pub async fn list_samples_with_metadata(
+        &self,
+        input: ListSamplesRequest,
+    ) -> Result<(ListSamplesResult, ResponseMetadata), RusotoError<ListSamplesError>> {
+        let request = self.build_list_samples_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<ListRunsResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ListSamplesResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ListRunsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ListSamplesError::from_response(response))
         }
     }
 
-    /// <p>Gets information about samples, given an AWS Device Farm job ARN.</p>
-    async fn list_samples(
+    /// Presigns the request `list_samples` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_samples(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListSamplesRequest,
-    ) -> Result<ListSamplesResult, RusotoError<ListSamplesError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_samples_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_samples_request(&self, input: ListSamplesRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "devicefarm", &self.region, "/");
+        request.set_operation("ListSamples");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "DeviceFarm_20150623.ListSamples");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `list_suites`, also returning the metadata of the response.
+    pub async fn list_suites_with_metadata(
+        &self,
+        input: ListSuitesRequest,
+    ) -> Result<(ListSuitesResult, ResponseMetadata), RusotoError<ListSuitesError>> {
+        let request = self.build_list_suites_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<ListSamplesResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ListSuitesResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ListSamplesError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ListSuitesError::from_response(response))
         }
     }
 
-    /// <p>Gets information about test suites for a given job.</p>
-    async fn list_suites(
+    /// Presigns the request `list_suites` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_suites(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListSuitesRequest,
-    ) -> Result<ListSuitesResult, RusotoError<ListSuitesError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_suites_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_suites_request(&self, input: ListSuitesRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "devicefarm", &self.region, "/");
+        request.set_operation("ListSuites");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "DeviceFarm_20150623.ListSuites");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `list_tags_for_resource`, also returning the metadata of the response.
+    pub async fn list_tags_for_resource_with_metadata(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> Result<
+        (ListTagsForResourceResponse, ResponseMetadata),
+        RusotoError<ListTagsForResourceError>,
+    > {
+        let request = self.build_list_tags_for_resource_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<ListSuitesResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ListTagsForResourceResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ListSuitesError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ListTagsForResourceError::from_response(response))
         }
     }
 
-    /// <p>List the tags for an AWS Device Farm resource.</p>
-    async fn list_tags_for_resource(
+    /// Presigns the request `list_tags_for_resource` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_tags_for_resource(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListTagsForResourceRequest,
-    ) -> Result<ListTagsForResourceResponse, RusotoError<ListTagsForResourceError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_tags_for_resource_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_tags_for_resource_request(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "devicefarm", &self.region, "/");
+        request.set_operation("ListTagsForResource");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "DeviceFarm_20150623.ListTagsForResource");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `list_test_grid_projects`, also returning the metadata of the response.
+    pub async fn list_test_grid_projects_with_metadata(
+        &self,
+        input: ListTestGridProjectsRequest,
+    ) -> Result<
+        (ListTestGridProjectsResult, ResponseMetadata),
+        RusotoError<ListTestGridProjectsError>,
+    > {
+        let request = self.build_list_test_grid_projects_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListTagsForResourceResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ListTestGridProjectsResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ListTagsForResourceError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ListTestGridProjectsError::from_response(response))
         }
     }
 
-    /// <p>Gets a list of all Selenium testing projects in your account.</p>
-    async fn list_test_grid_projects(
+    /// Presigns the request `list_test_grid_projects` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_test_grid_projects(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListTestGridProjectsRequest,
-    ) -> Result<ListTestGridProjectsResult, RusotoError<ListTestGridProjectsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_test_grid_projects_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_test_grid_projects_request(
+        &self,
+        input: ListTestGridProjectsRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "devicefarm", &self.region, "/");
+        request.set_operation("ListTestGridProjects");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "DeviceFarm_20150623.ListTestGridProjects");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `list_test_grid_session_actions`, also returning the metadata of the response.
+    pub async fn list_test_grid_session_actions_with_metadata(
+        &self,
+        input: ListTestGridSessionActionsRequest,
+    ) -> Result<
+        (ListTestGridSessionActionsResult, ResponseMetadata),
+        RusotoError<ListTestGridSessionActionsError>,
+    > {
+        let request = self.build_list_test_grid_session_actions_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListTestGridProjectsResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ListTestGridSessionActionsResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ListTestGridProjectsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ListTestGridSessionActionsError::from_response(response))
         }
     }
 
-    /// <p>Returns a list of the actions taken in a <a>TestGridSession</a>.</p>
-    async fn list_test_grid_session_actions(
+    /// Presigns the request `list_test_grid_session_actions` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_test_grid_session_actions(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListTestGridSessionActionsRequest,
-    ) -> Result<ListTestGridSessionActionsResult, RusotoError<ListTestGridSessionActionsError>>
-    {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_test_grid_session_actions_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_test_grid_session_actions_request(
+        &self,
+        input: ListTestGridSessionActionsRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "devicefarm", &self.region, "/");
+        request.set_operation("ListTestGridSessionActions");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -9148,29 +11284,58 @@ impl DeviceFarm for DeviceFarmClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `list_test_grid_session_artifacts`, also returning the metadata of the response.
+    pub async fn list_test_grid_session_artifacts_with_metadata(
+        &self,
+        input: ListTestGridSessionArtifactsRequest,
+    ) -> Result<
+        (ListTestGridSessionArtifactsResult, ResponseMetadata),
+        RusotoError<ListTestGridSessionArtifactsError>,
+    > {
+        let request = self.build_list_test_grid_session_artifacts_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListTestGridSessionActionsResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ListTestGridSessionArtifactsResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ListTestGridSessionActionsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ListTestGridSessionArtifactsError::from_response(response))
         }
     }
 
-    /// <p>Retrieves a list of artifacts created during the session.</p>
-    async fn list_test_grid_session_artifacts(
+    /// Presigns the request `list_test_grid_session_artifacts` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_test_grid_session_artifacts(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListTestGridSessionArtifactsRequest,
-    ) -> Result<ListTestGridSessionArtifactsResult, RusotoError<ListTestGridSessionArtifactsError>>
-    {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_test_grid_session_artifacts_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_test_grid_session_artifacts_request(
+        &self,
+        input: ListTestGridSessionArtifactsRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "devicefarm", &self.region, "/");
+        request.set_operation("ListTestGridSessionArtifacts");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -9180,274 +11345,543 @@ impl DeviceFarm for DeviceFarmClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `list_test_grid_sessions`, also returning the metadata of the response.
+    pub async fn list_test_grid_sessions_with_metadata(
+        &self,
+        input: ListTestGridSessionsRequest,
+    ) -> Result<
+        (ListTestGridSessionsResult, ResponseMetadata),
+        RusotoError<ListTestGridSessionsError>,
+    > {
+        let request = self.build_list_test_grid_sessions_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListTestGridSessionArtifactsResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ListTestGridSessionsResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ListTestGridSessionArtifactsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ListTestGridSessionsError::from_response(response))
         }
     }
 
-    /// <p>Retrieves a list of sessions for a <a>TestGridProject</a>.</p>
-    async fn list_test_grid_sessions(
+    /// Presigns the request `list_test_grid_sessions` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_test_grid_sessions(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListTestGridSessionsRequest,
-    ) -> Result<ListTestGridSessionsResult, RusotoError<ListTestGridSessionsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_test_grid_sessions_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_test_grid_sessions_request(
+        &self,
+        input: ListTestGridSessionsRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "devicefarm", &self.region, "/");
+        request.set_operation("ListTestGridSessions");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "DeviceFarm_20150623.ListTestGridSessions");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `list_tests`, also returning the metadata of the response.
+    pub async fn list_tests_with_metadata(
+        &self,
+        input: ListTestsRequest,
+    ) -> Result<(ListTestsResult, ResponseMetadata), RusotoError<ListTestsError>> {
+        let request = self.build_list_tests_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListTestGridSessionsResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result =
+                proto::json::ResponsePayload::new(&response).deserialize::<ListTestsResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ListTestGridSessionsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ListTestsError::from_response(response))
         }
     }
 
-    /// <p>Gets information about tests in a given test suite.</p>
-    async fn list_tests(
+    /// Presigns the request `list_tests` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_tests(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListTestsRequest,
-    ) -> Result<ListTestsResult, RusotoError<ListTestsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_tests_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_tests_request(&self, input: ListTestsRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "devicefarm", &self.region, "/");
+        request.set_operation("ListTests");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "DeviceFarm_20150623.ListTests");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `list_unique_problems`, also returning the metadata of the response.
+    pub async fn list_unique_problems_with_metadata(
+        &self,
+        input: ListUniqueProblemsRequest,
+    ) -> Result<(ListUniqueProblemsResult, ResponseMetadata), RusotoError<ListUniqueProblemsError>>
+    {
+        let request = self.build_list_unique_problems_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<ListTestsResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ListUniqueProblemsResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ListTestsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ListUniqueProblemsError::from_response(response))
         }
     }
 
-    /// <p>Gets information about unique problems, such as exceptions or crashes.</p> <p>Unique problems are defined as a single instance of an error across a run, job, or suite. For example, if a call in your application consistently raises an exception (<code>OutOfBoundsException in MyActivity.java:386</code>), <code>ListUniqueProblems</code> returns a single entry instead of many individual entries for that exception.</p>
-    async fn list_unique_problems(
+    /// Presigns the request `list_unique_problems` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_unique_problems(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListUniqueProblemsRequest,
-    ) -> Result<ListUniqueProblemsResult, RusotoError<ListUniqueProblemsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_unique_problems_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_unique_problems_request(
+        &self,
+        input: ListUniqueProblemsRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "devicefarm", &self.region, "/");
+        request.set_operation("ListUniqueProblems");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "DeviceFarm_20150623.ListUniqueProblems");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `list_uploads`, also returning the metadata of the response.
+    pub async fn list_uploads_with_metadata(
+        &self,
+        input: ListUploadsRequest,
+    ) -> Result<(ListUploadsResult, ResponseMetadata), RusotoError<ListUploadsError>> {
+        let request = self.build_list_uploads_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListUniqueProblemsResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ListUploadsResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ListUniqueProblemsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ListUploadsError::from_response(response))
         }
     }
 
-    /// <p>Gets information about uploads, given an AWS Device Farm project ARN.</p>
-    async fn list_uploads(
+    /// Presigns the request `list_uploads` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_uploads(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListUploadsRequest,
-    ) -> Result<ListUploadsResult, RusotoError<ListUploadsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_uploads_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_uploads_request(&self, input: ListUploadsRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "devicefarm", &self.region, "/");
+        request.set_operation("ListUploads");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "DeviceFarm_20150623.ListUploads");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `list_vpce_configurations`, also returning the metadata of the response.
+    pub async fn list_vpce_configurations_with_metadata(
+        &self,
+        input: ListVPCEConfigurationsRequest,
+    ) -> Result<
+        (ListVPCEConfigurationsResult, ResponseMetadata),
+        RusotoError<ListVPCEConfigurationsError>,
+    > {
+        let request = self.build_list_vpce_configurations_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<ListUploadsResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ListVPCEConfigurationsResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ListUploadsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ListVPCEConfigurationsError::from_response(response))
         }
     }
 
-    /// <p>Returns information about all Amazon Virtual Private Cloud (VPC) endpoint configurations in the AWS account.</p>
-    async fn list_vpce_configurations(
+    /// Presigns the request `list_vpce_configurations` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_vpce_configurations(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListVPCEConfigurationsRequest,
-    ) -> Result<ListVPCEConfigurationsResult, RusotoError<ListVPCEConfigurationsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_vpce_configurations_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_vpce_configurations_request(
+        &self,
+        input: ListVPCEConfigurationsRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "devicefarm", &self.region, "/");
+        request.set_operation("ListVPCEConfigurations");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "DeviceFarm_20150623.ListVPCEConfigurations");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `purchase_offering`, also returning the metadata of the response.
+    pub async fn purchase_offering_with_metadata(
+        &self,
+        input: PurchaseOfferingRequest,
+    ) -> Result<(PurchaseOfferingResult, ResponseMetadata), RusotoError<PurchaseOfferingError>>
+    {
+        let request = self.build_purchase_offering_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListVPCEConfigurationsResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<PurchaseOfferingResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ListVPCEConfigurationsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(PurchaseOfferingError::from_response(response))
         }
     }
 
-    /// <p>Immediately purchases offerings for an AWS account. Offerings renew with the latest total purchased quantity for an offering, unless the renewal was overridden. The API returns a <code>NotEligible</code> error if the user is not permitted to invoke the operation. If you must be able to invoke this operation, contact <a href="mailto:aws-devicefarm-support@amazon.com">aws-devicefarm-support@amazon.com</a>.</p>
-    async fn purchase_offering(
+    /// Presigns the request `purchase_offering` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_purchase_offering(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: PurchaseOfferingRequest,
-    ) -> Result<PurchaseOfferingResult, RusotoError<PurchaseOfferingError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_purchase_offering_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_purchase_offering_request(&self, input: PurchaseOfferingRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "devicefarm", &self.region, "/");
+        request.set_operation("PurchaseOffering");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "DeviceFarm_20150623.PurchaseOffering");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `renew_offering`, also returning the metadata of the response.
+    pub async fn renew_offering_with_metadata(
+        &self,
+        input: RenewOfferingRequest,
+    ) -> Result<(RenewOfferingResult, ResponseMetadata), RusotoError<RenewOfferingError>> {
+        let request = self.build_renew_offering_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<PurchaseOfferingResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<RenewOfferingResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(PurchaseOfferingError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(RenewOfferingError::from_response(response))
         }
     }
 
-    /// <p>Explicitly sets the quantity of devices to renew for an offering, starting from the <code>effectiveDate</code> of the next period. The API returns a <code>NotEligible</code> error if the user is not permitted to invoke the operation. If you must be able to invoke this operation, contact <a href="mailto:aws-devicefarm-support@amazon.com">aws-devicefarm-support@amazon.com</a>.</p>
-    async fn renew_offering(
+    /// Presigns the request `renew_offering` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_renew_offering(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: RenewOfferingRequest,
-    ) -> Result<RenewOfferingResult, RusotoError<RenewOfferingError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_renew_offering_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_renew_offering_request(&self, input: RenewOfferingRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "devicefarm", &self.region, "/");
+        request.set_operation("RenewOffering");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "DeviceFarm_20150623.RenewOffering");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `schedule_run`, also returning the metadata of the response.
+    pub async fn schedule_run_with_metadata(
+        &self,
+        input: ScheduleRunRequest,
+    ) -> Result<(ScheduleRunResult, ResponseMetadata), RusotoError<ScheduleRunError>> {
+        let request = self.build_schedule_run_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<RenewOfferingResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ScheduleRunResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(RenewOfferingError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ScheduleRunError::from_response(response))
         }
     }
 
-    /// <p>Schedules a run.</p>
-    async fn schedule_run(
+    /// Presigns the request `schedule_run` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_schedule_run(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ScheduleRunRequest,
-    ) -> Result<ScheduleRunResult, RusotoError<ScheduleRunError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_schedule_run_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_schedule_run_request(&self, input: ScheduleRunRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "devicefarm", &self.region, "/");
+        request.set_operation("ScheduleRun");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "DeviceFarm_20150623.ScheduleRun");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `stop_job`, also returning the metadata of the response.
+    pub async fn stop_job_with_metadata(
+        &self,
+        input: StopJobRequest,
+    ) -> Result<(StopJobResult, ResponseMetadata), RusotoError<StopJobError>> {
+        let request = self.build_stop_job_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<ScheduleRunResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result =
+                proto::json::ResponsePayload::new(&response).deserialize::<StopJobResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ScheduleRunError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(StopJobError::from_response(response))
         }
     }
 
-    /// <p>Initiates a stop request for the current job. AWS Device Farm immediately stops the job on the device where tests have not started. You are not billed for this device. On the device where tests have started, setup suite and teardown suite tests run to completion on the device. You are billed for setup, teardown, and any tests that were in progress or already completed.</p>
-    async fn stop_job(
+    /// Presigns the request `stop_job` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_stop_job(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: StopJobRequest,
-    ) -> Result<StopJobResult, RusotoError<StopJobError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_stop_job_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_stop_job_request(&self, input: StopJobRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "devicefarm", &self.region, "/");
+        request.set_operation("StopJob");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "DeviceFarm_20150623.StopJob");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `stop_remote_access_session`, also returning the metadata of the response.
+    pub async fn stop_remote_access_session_with_metadata(
+        &self,
+        input: StopRemoteAccessSessionRequest,
+    ) -> Result<
+        (StopRemoteAccessSessionResult, ResponseMetadata),
+        RusotoError<StopRemoteAccessSessionError>,
+    > {
+        let request = self.build_stop_remote_access_session_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<StopJobResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<StopRemoteAccessSessionResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(StopJobError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(StopRemoteAccessSessionError::from_response(response))
         }
     }
 
-    /// <p>Ends a specified remote access session.</p>
-    async fn stop_remote_access_session(
+    /// Presigns the request `stop_remote_access_session` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_stop_remote_access_session(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: StopRemoteAccessSessionRequest,
-    ) -> Result<StopRemoteAccessSessionResult, RusotoError<StopRemoteAccessSessionError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_stop_remote_access_session_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_stop_remote_access_session_request(
+        &self,
+        input: StopRemoteAccessSessionRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "devicefarm", &self.region, "/");
+        request.set_operation("StopRemoteAccessSession");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -9457,302 +11891,603 @@ impl DeviceFarm for DeviceFarmClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
-        let mut response = self
-            .client
-            .sign_and_dispatch(request)
+        request
+    }
+
+    /// Calls `stop_run`, also returning the metadata of the response.
+    pub async fn stop_run_with_metadata(
+        &self,
+        input: StopRunRequest,
+    ) -> Result<(StopRunResult, ResponseMetadata), RusotoError<StopRunError>> {
+        let request = self.build_stop_run_request(input);
+
+        let mut response = self
+            .client
+            .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<StopRemoteAccessSessionResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result =
+                proto::json::ResponsePayload::new(&response).deserialize::<StopRunResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(StopRemoteAccessSessionError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(StopRunError::from_response(response))
         }
     }
 
-    /// <p>Initiates a stop request for the current test run. AWS Device Farm immediately stops the run on devices where tests have not started. You are not billed for these devices. On devices where tests have started executing, setup suite and teardown suite tests run to completion on those devices. You are billed for setup, teardown, and any tests that were in progress or already completed.</p>
-    async fn stop_run(
+    /// Presigns the request `stop_run` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_stop_run(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: StopRunRequest,
-    ) -> Result<StopRunResult, RusotoError<StopRunError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_stop_run_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_stop_run_request(&self, input: StopRunRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "devicefarm", &self.region, "/");
+        request.set_operation("StopRun");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "DeviceFarm_20150623.StopRun");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `tag_resource`, also returning the metadata of the response.
+    pub async fn tag_resource_with_metadata(
+        &self,
+        input: TagResourceRequest,
+    ) -> Result<(TagResourceResponse, ResponseMetadata), RusotoError<TagResourceError>> {
+        let request = self.build_tag_resource_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<StopRunResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<TagResourceResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(StopRunError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(TagResourceError::from_response(response))
         }
     }
 
-    /// <p>Associates the specified tags to a resource with the specified <code>resourceArn</code>. If existing tags on a resource are not specified in the request parameters, they are not changed. When a resource is deleted, the tags associated with that resource are also deleted.</p>
-    async fn tag_resource(
+    /// Presigns the request `tag_resource` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_tag_resource(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: TagResourceRequest,
-    ) -> Result<TagResourceResponse, RusotoError<TagResourceError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_tag_resource_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_tag_resource_request(&self, input: TagResourceRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "devicefarm", &self.region, "/");
+        request.set_operation("TagResource");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "DeviceFarm_20150623.TagResource");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `untag_resource`, also returning the metadata of the response.
+    pub async fn untag_resource_with_metadata(
+        &self,
+        input: UntagResourceRequest,
+    ) -> Result<(UntagResourceResponse, ResponseMetadata), RusotoError<UntagResourceError>> {
+        let request = self.build_untag_resource_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<TagResourceResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<UntagResourceResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(TagResourceError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(UntagResourceError::from_response(response))
         }
     }
 
-    /// <p>Deletes the specified tags from a resource.</p>
-    async fn untag_resource(
+    /// Presigns the request `untag_resource` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_untag_resource(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UntagResourceRequest,
-    ) -> Result<UntagResourceResponse, RusotoError<UntagResourceError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_untag_resource_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_untag_resource_request(&self, input: UntagResourceRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "devicefarm", &self.region, "/");
+        request.set_operation("UntagResource");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "DeviceFarm_20150623.UntagResource");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `update_device_instance`, also returning the metadata of the response.
+    pub async fn update_device_instance_with_metadata(
+        &self,
+        input: UpdateDeviceInstanceRequest,
+    ) -> Result<
+        (UpdateDeviceInstanceResult, ResponseMetadata),
+        RusotoError<UpdateDeviceInstanceError>,
+    > {
+        let request = self.build_update_device_instance_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<UntagResourceResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<UpdateDeviceInstanceResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(UntagResourceError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(UpdateDeviceInstanceError::from_response(response))
         }
     }
 
-    /// <p>Updates information about a private device instance.</p>
-    async fn update_device_instance(
+    /// Presigns the request `update_device_instance` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_device_instance(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UpdateDeviceInstanceRequest,
-    ) -> Result<UpdateDeviceInstanceResult, RusotoError<UpdateDeviceInstanceError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_device_instance_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_device_instance_request(
+        &self,
+        input: UpdateDeviceInstanceRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "devicefarm", &self.region, "/");
+        request.set_operation("UpdateDeviceInstance");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "DeviceFarm_20150623.UpdateDeviceInstance");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `update_device_pool`, also returning the metadata of the response.
+    pub async fn update_device_pool_with_metadata(
+        &self,
+        input: UpdateDevicePoolRequest,
+    ) -> Result<(UpdateDevicePoolResult, ResponseMetadata), RusotoError<UpdateDevicePoolError>>
+    {
+        let request = self.build_update_device_pool_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<UpdateDeviceInstanceResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<UpdateDevicePoolResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(UpdateDeviceInstanceError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(UpdateDevicePoolError::from_response(response))
         }
     }
 
-    /// <p>Modifies the name, description, and rules in a device pool given the attributes and the pool ARN. Rule updates are all-or-nothing, meaning they can only be updated as a whole (or not at all).</p>
-    async fn update_device_pool(
+    /// Presigns the request `update_device_pool` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_device_pool(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UpdateDevicePoolRequest,
-    ) -> Result<UpdateDevicePoolResult, RusotoError<UpdateDevicePoolError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_device_pool_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_device_pool_request(&self, input: UpdateDevicePoolRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "devicefarm", &self.region, "/");
+        request.set_operation("UpdateDevicePool");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "DeviceFarm_20150623.UpdateDevicePool");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `update_instance_profile`, also returning the metadata of the response.
+    pub async fn update_instance_profile_with_metadata(
+        &self,
+        input: UpdateInstanceProfileRequest,
+    ) -> Result<
+        (UpdateInstanceProfileResult, ResponseMetadata),
+        RusotoError<UpdateInstanceProfileError>,
+    > {
+        let request = self.build_update_instance_profile_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<UpdateDevicePoolResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<UpdateInstanceProfileResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(UpdateDevicePoolError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(UpdateInstanceProfileError::from_response(response))
         }
     }
 
-    /// <p>Updates information about an existing private device instance profile.</p>
-    async fn update_instance_profile(
+    /// Presigns the request `update_instance_profile` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_instance_profile(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UpdateInstanceProfileRequest,
-    ) -> Result<UpdateInstanceProfileResult, RusotoError<UpdateInstanceProfileError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_instance_profile_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_instance_profile_request(
+        &self,
+        input: UpdateInstanceProfileRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "devicefarm", &self.region, "/");
+        request.set_operation("UpdateInstanceProfile");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "DeviceFarm_20150623.UpdateInstanceProfile");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `update_network_profile`, also returning the metadata of the response.
+    pub async fn update_network_profile_with_metadata(
+        &self,
+        input: UpdateNetworkProfileRequest,
+    ) -> Result<
+        (UpdateNetworkProfileResult, ResponseMetadata),
+        RusotoError<UpdateNetworkProfileError>,
+    > {
+        let request = self.build_update_network_profile_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<UpdateInstanceProfileResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<UpdateNetworkProfileResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(UpdateInstanceProfileError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(UpdateNetworkProfileError::from_response(response))
         }
     }
 
-    /// <p>Updates the network profile.</p>
-    async fn update_network_profile(
+    /// Presigns the request `update_network_profile` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_network_profile(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UpdateNetworkProfileRequest,
-    ) -> Result<UpdateNetworkProfileResult, RusotoError<UpdateNetworkProfileError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_network_profile_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_network_profile_request(
+        &self,
+        input: UpdateNetworkProfileRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "devicefarm", &self.region, "/");
+        request.set_operation("UpdateNetworkProfile");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "DeviceFarm_20150623.UpdateNetworkProfile");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `update_project`, also returning the metadata of the response.
+    pub async fn update_project_with_metadata(
+        &self,
+        input: UpdateProjectRequest,
+    ) -> Result<(UpdateProjectResult, ResponseMetadata), RusotoError<UpdateProjectError>> {
+        let request = self.build_update_project_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<UpdateNetworkProfileResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<UpdateProjectResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(UpdateNetworkProfileError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(UpdateProjectError::from_response(response))
         }
     }
 
-    /// <p>Modifies the specified project name, given the project ARN and a new name.</p>
-    async fn update_project(
+    /// Presigns the request `update_project` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_project(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UpdateProjectRequest,
-    ) -> Result<UpdateProjectResult, RusotoError<UpdateProjectError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_project_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_project_request(&self, input: UpdateProjectRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "devicefarm", &self.region, "/");
+        request.set_operation("UpdateProject");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "DeviceFarm_20150623.UpdateProject");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `update_test_grid_project`, also returning the metadata of the response.
+    pub async fn update_test_grid_project_with_metadata(
+        &self,
+        input: UpdateTestGridProjectRequest,
+    ) -> Result<
+        (UpdateTestGridProjectResult, ResponseMetadata),
+        RusotoError<UpdateTestGridProjectError>,
+    > {
+        let request = self.build_update_test_grid_project_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<UpdateProjectResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<UpdateTestGridProjectResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(UpdateProjectError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(UpdateTestGridProjectError::from_response(response))
         }
     }
 
-    /// <p>Change details of a project.</p>
-    async fn update_test_grid_project(
+    /// Presigns the request `update_test_grid_project` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_test_grid_project(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UpdateTestGridProjectRequest,
-    ) -> Result<UpdateTestGridProjectResult, RusotoError<UpdateTestGridProjectError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_test_grid_project_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_test_grid_project_request(
+        &self,
+        input: UpdateTestGridProjectRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "devicefarm", &self.region, "/");
+        request.set_operation("UpdateTestGridProject");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "DeviceFarm_20150623.UpdateTestGridProject");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `update_upload`, also returning the metadata of the response.
+    pub async fn update_upload_with_metadata(
+        &self,
+        input: UpdateUploadRequest,
+    ) -> Result<(UpdateUploadResult, ResponseMetadata), RusotoError<UpdateUploadError>> {
+        let request = self.build_update_upload_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<UpdateTestGridProjectResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<UpdateUploadResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(UpdateTestGridProjectError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(UpdateUploadError::from_response(response))
         }
     }
 
-    /// <p>Updates an uploaded test spec.</p>
-    async fn update_upload(
+    /// Presigns the request `update_upload` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_upload(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UpdateUploadRequest,
-    ) -> Result<UpdateUploadResult, RusotoError<UpdateUploadError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_upload_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_upload_request(&self, input: UpdateUploadRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "devicefarm", &self.region, "/");
+        request.set_operation("UpdateUpload");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "DeviceFarm_20150623.UpdateUpload");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `update_vpce_configuration`, also returning the metadata of the response.
+    pub async fn update_vpce_configuration_with_metadata(
+        &self,
+        input: UpdateVPCEConfigurationRequest,
+    ) -> Result<
+        (UpdateVPCEConfigurationResult, ResponseMetadata),
+        RusotoError<UpdateVPCEConfigurationError>,
+    > {
+        let request = self.build_update_vpce_configuration_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<UpdateUploadResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<UpdateVPCEConfigurationResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(UpdateUploadError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(UpdateVPCEConfigurationError::from_response(response))
         }
     }
 
-    /// <p>Updates information about an Amazon Virtual Private Cloud (VPC) endpoint configuration.</p>
-    async fn update_vpce_configuration(
+    /// Presigns the request `update_vpce_configuration` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_vpce_configuration(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UpdateVPCEConfigurationRequest,
-    ) -> Result<UpdateVPCEConfigurationResult, RusotoError<UpdateVPCEConfigurationError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_vpce_configuration_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_vpce_configuration_request(
+        &self,
+        input: UpdateVPCEConfigurationRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "devicefarm", &self.region, "/");
+        request.set_operation("UpdateVPCEConfiguration");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -9762,19 +12497,1474 @@ impl DeviceFarm for DeviceFarmClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
-        let mut response = self
-            .client
-            .sign_and_dispatch(request)
-            .await
-            .map_err(RusotoError::from)?;
-        if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<UpdateVPCEConfigurationResult, _>()
-        } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(UpdateVPCEConfigurationError::from_response(response))
-        }
+        request
+    }
+
+    /// The pages of `GetOfferingStatus` results for `input`, requesting each next page with the `nextToken` of the last one as its `nextToken`.
+    pub fn get_offering_status_pages(
+        &self,
+        input: GetOfferingStatusRequest,
+    ) -> impl futures::Stream<
+        Item = Result<GetOfferingStatusResult, RusotoError<GetOfferingStatusError>>,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: DeviceFarmClient, input| async move { client.get_offering_status(input).await },
+            |input: &GetOfferingStatusRequest, output: &GetOfferingStatusResult| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(GetOfferingStatusRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The pages of `ListArtifacts` results for `input`, requesting each next page with the `nextToken` of the last one as its `nextToken`.
+    pub fn list_artifacts_pages(
+        &self,
+        input: ListArtifactsRequest,
+    ) -> impl futures::Stream<Item = Result<ListArtifactsResult, RusotoError<ListArtifactsError>>> + Send
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: DeviceFarmClient, input| async move { client.list_artifacts(input).await },
+            |input: &ListArtifactsRequest, output: &ListArtifactsResult| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListArtifactsRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `artifacts` of every page of `ListArtifacts` results for `input`, as `list_artifacts_pages` requests them.
+    pub fn list_artifacts_stream(
+        &self,
+        input: ListArtifactsRequest,
+    ) -> impl futures::Stream<Item = Result<Artifact, RusotoError<ListArtifactsError>>> + Send {
+        rusoto_core::pagination::items(self.list_artifacts_pages(input), |page| {
+            page.artifacts.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `ListDeviceInstances` results for `input`, requesting each next page with the `nextToken` of the last one as its `nextToken`.
+    /// The page size is the `maxResults` of `input`.
+    pub fn list_device_instances_pages(
+        &self,
+        input: ListDeviceInstancesRequest,
+    ) -> impl futures::Stream<
+        Item = Result<ListDeviceInstancesResult, RusotoError<ListDeviceInstancesError>>,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: DeviceFarmClient, input| async move { client.list_device_instances(input).await },
+            |input: &ListDeviceInstancesRequest, output: &ListDeviceInstancesResult| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListDeviceInstancesRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `deviceInstances` of every page of `ListDeviceInstances` results for `input`, as `list_device_instances_pages` requests them.
+    pub fn list_device_instances_stream(
+        &self,
+        input: ListDeviceInstancesRequest,
+    ) -> impl futures::Stream<Item = Result<DeviceInstance, RusotoError<ListDeviceInstancesError>>> + Send
+    {
+        rusoto_core::pagination::items(self.list_device_instances_pages(input), |page| {
+            page.device_instances.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `ListDevicePools` results for `input`, requesting each next page with the `nextToken` of the last one as its `nextToken`.
+    pub fn list_device_pools_pages(
+        &self,
+        input: ListDevicePoolsRequest,
+    ) -> impl futures::Stream<Item = Result<ListDevicePoolsResult, RusotoError<ListDevicePoolsError>>>
+           + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: DeviceFarmClient, input| async move { client.list_device_pools(input).await },
+            |input: &ListDevicePoolsRequest, output: &ListDevicePoolsResult| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListDevicePoolsRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `devicePools` of every page of `ListDevicePools` results for `input`, as `list_device_pools_pages` requests them.
+    pub fn list_device_pools_stream(
+        &self,
+        input: ListDevicePoolsRequest,
+    ) -> impl futures::Stream<Item = Result<DevicePool, RusotoError<ListDevicePoolsError>>> + Send
+    {
+        rusoto_core::pagination::items(self.list_device_pools_pages(input), |page| {
+            page.device_pools.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `ListDevices` results for `input`, requesting each next page with the `nextToken` of the last one as its `nextToken`.
+    pub fn list_devices_pages(
+        &self,
+        input: ListDevicesRequest,
+    ) -> impl futures::Stream<Item = Result<ListDevicesResult, RusotoError<ListDevicesError>>> + Send
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: DeviceFarmClient, input| async move { client.list_devices(input).await },
+            |input: &ListDevicesRequest, output: &ListDevicesResult| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListDevicesRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `devices` of every page of `ListDevices` results for `input`, as `list_devices_pages` requests them.
+    pub fn list_devices_stream(
+        &self,
+        input: ListDevicesRequest,
+    ) -> impl futures::Stream<Item = Result<Device, RusotoError<ListDevicesError>>> + Send {
+        rusoto_core::pagination::items(self.list_devices_pages(input), |page| {
+            page.devices.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `ListInstanceProfiles` results for `input`, requesting each next page with the `nextToken` of the last one as its `nextToken`.
+    /// The page size is the `maxResults` of `input`.
+    pub fn list_instance_profiles_pages(
+        &self,
+        input: ListInstanceProfilesRequest,
+    ) -> impl futures::Stream<
+        Item = Result<ListInstanceProfilesResult, RusotoError<ListInstanceProfilesError>>,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: DeviceFarmClient, input| async move { client.list_instance_profiles(input).await },
+            |input: &ListInstanceProfilesRequest, output: &ListInstanceProfilesResult| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListInstanceProfilesRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `instanceProfiles` of every page of `ListInstanceProfiles` results for `input`, as `list_instance_profiles_pages` requests them.
+    pub fn list_instance_profiles_stream(
+        &self,
+        input: ListInstanceProfilesRequest,
+    ) -> impl futures::Stream<Item = Result<InstanceProfile, RusotoError<ListInstanceProfilesError>>>
+           + Send {
+        rusoto_core::pagination::items(self.list_instance_profiles_pages(input), |page| {
+            page.instance_profiles.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `ListJobs` results for `input`, requesting each next page with the `nextToken` of the last one as its `nextToken`.
+    pub fn list_jobs_pages(
+        &self,
+        input: ListJobsRequest,
+    ) -> impl futures::Stream<Item = Result<ListJobsResult, RusotoError<ListJobsError>>> + Send
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: DeviceFarmClient, input| async move { client.list_jobs(input).await },
+            |input: &ListJobsRequest, output: &ListJobsResult| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListJobsRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `jobs` of every page of `ListJobs` results for `input`, as `list_jobs_pages` requests them.
+    pub fn list_jobs_stream(
+        &self,
+        input: ListJobsRequest,
+    ) -> impl futures::Stream<Item = Result<Job, RusotoError<ListJobsError>>> + Send {
+        rusoto_core::pagination::items(self.list_jobs_pages(input), |page| {
+            page.jobs.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `ListNetworkProfiles` results for `input`, requesting each next page with the `nextToken` of the last one as its `nextToken`.
+    pub fn list_network_profiles_pages(
+        &self,
+        input: ListNetworkProfilesRequest,
+    ) -> impl futures::Stream<
+        Item = Result<ListNetworkProfilesResult, RusotoError<ListNetworkProfilesError>>,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: DeviceFarmClient, input| async move { client.list_network_profiles(input).await },
+            |input: &ListNetworkProfilesRequest, output: &ListNetworkProfilesResult| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListNetworkProfilesRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `networkProfiles` of every page of `ListNetworkProfiles` results for `input`, as `list_network_profiles_pages` requests them.
+    pub fn list_network_profiles_stream(
+        &self,
+        input: ListNetworkProfilesRequest,
+    ) -> impl futures::Stream<Item = Result<NetworkProfile, RusotoError<ListNetworkProfilesError>>> + Send
+    {
+        rusoto_core::pagination::items(self.list_network_profiles_pages(input), |page| {
+            page.network_profiles.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `ListOfferingPromotions` results for `input`, requesting each next page with the `nextToken` of the last one as its `nextToken`.
+    pub fn list_offering_promotions_pages(
+        &self,
+        input: ListOfferingPromotionsRequest,
+    ) -> impl futures::Stream<
+        Item = Result<ListOfferingPromotionsResult, RusotoError<ListOfferingPromotionsError>>,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: DeviceFarmClient, input| async move {
+                client.list_offering_promotions(input).await
+            },
+            |input: &ListOfferingPromotionsRequest, output: &ListOfferingPromotionsResult| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListOfferingPromotionsRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `offeringPromotions` of every page of `ListOfferingPromotions` results for `input`, as `list_offering_promotions_pages` requests them.
+    pub fn list_offering_promotions_stream(
+        &self,
+        input: ListOfferingPromotionsRequest,
+    ) -> impl futures::Stream<
+        Item = Result<OfferingPromotion, RusotoError<ListOfferingPromotionsError>>,
+    > + Send {
+        rusoto_core::pagination::items(self.list_offering_promotions_pages(input), |page| {
+            page.offering_promotions.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `ListOfferingTransactions` results for `input`, requesting each next page with the `nextToken` of the last one as its `nextToken`.
+    pub fn list_offering_transactions_pages(
+        &self,
+        input: ListOfferingTransactionsRequest,
+    ) -> impl futures::Stream<
+        Item = Result<ListOfferingTransactionsResult, RusotoError<ListOfferingTransactionsError>>,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: DeviceFarmClient, input| async move {
+                client.list_offering_transactions(input).await
+            },
+            |input: &ListOfferingTransactionsRequest, output: &ListOfferingTransactionsResult| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListOfferingTransactionsRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `offeringTransactions` of every page of `ListOfferingTransactions` results for `input`, as `list_offering_transactions_pages` requests them.
+    pub fn list_offering_transactions_stream(
+        &self,
+        input: ListOfferingTransactionsRequest,
+    ) -> impl futures::Stream<
+        Item = Result<OfferingTransaction, RusotoError<ListOfferingTransactionsError>>,
+    > + Send {
+        rusoto_core::pagination::items(self.list_offering_transactions_pages(input), |page| {
+            page.offering_transactions.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `ListOfferings` results for `input`, requesting each next page with the `nextToken` of the last one as its `nextToken`.
+    pub fn list_offerings_pages(
+        &self,
+        input: ListOfferingsRequest,
+    ) -> impl futures::Stream<Item = Result<ListOfferingsResult, RusotoError<ListOfferingsError>>> + Send
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: DeviceFarmClient, input| async move { client.list_offerings(input).await },
+            |input: &ListOfferingsRequest, output: &ListOfferingsResult| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListOfferingsRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `offerings` of every page of `ListOfferings` results for `input`, as `list_offerings_pages` requests them.
+    pub fn list_offerings_stream(
+        &self,
+        input: ListOfferingsRequest,
+    ) -> impl futures::Stream<Item = Result<Offering, RusotoError<ListOfferingsError>>> + Send {
+        rusoto_core::pagination::items(self.list_offerings_pages(input), |page| {
+            page.offerings.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `ListProjects` results for `input`, requesting each next page with the `nextToken` of the last one as its `nextToken`.
+    pub fn list_projects_pages(
+        &self,
+        input: ListProjectsRequest,
+    ) -> impl futures::Stream<Item = Result<ListProjectsResult, RusotoError<ListProjectsError>>> + Send
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: DeviceFarmClient, input| async move { client.list_projects(input).await },
+            |input: &ListProjectsRequest, output: &ListProjectsResult| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListProjectsRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `projects` of every page of `ListProjects` results for `input`, as `list_projects_pages` requests them.
+    pub fn list_projects_stream(
+        &self,
+        input: ListProjectsRequest,
+    ) -> impl futures::Stream<Item = Result<Project, RusotoError<ListProjectsError>>> + Send {
+        rusoto_core::pagination::items(self.list_projects_pages(input), |page| {
+            page.projects.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `ListRemoteAccessSessions` results for `input`, requesting each next page with the `nextToken` of the last one as its `nextToken`.
+    pub fn list_remote_access_sessions_pages(
+        &self,
+        input: ListRemoteAccessSessionsRequest,
+    ) -> impl futures::Stream<
+        Item = Result<ListRemoteAccessSessionsResult, RusotoError<ListRemoteAccessSessionsError>>,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: DeviceFarmClient, input| async move {
+                client.list_remote_access_sessions(input).await
+            },
+            |input: &ListRemoteAccessSessionsRequest, output: &ListRemoteAccessSessionsResult| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListRemoteAccessSessionsRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `remoteAccessSessions` of every page of `ListRemoteAccessSessions` results for `input`, as `list_remote_access_sessions_pages` requests them.
+    pub fn list_remote_access_sessions_stream(
+        &self,
+        input: ListRemoteAccessSessionsRequest,
+    ) -> impl futures::Stream<
+        Item = Result<RemoteAccessSession, RusotoError<ListRemoteAccessSessionsError>>,
+    > + Send {
+        rusoto_core::pagination::items(self.list_remote_access_sessions_pages(input), |page| {
+            page.remote_access_sessions.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `ListRuns` results for `input`, requesting each next page with the `nextToken` of the last one as its `nextToken`.
+    pub fn list_runs_pages(
+        &self,
+        input: ListRunsRequest,
+    ) -> impl futures::Stream<Item = Result<ListRunsResult, RusotoError<ListRunsError>>> + Send
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: DeviceFarmClient, input| async move { client.list_runs(input).await },
+            |input: &ListRunsRequest, output: &ListRunsResult| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListRunsRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `runs` of every page of `ListRuns` results for `input`, as `list_runs_pages` requests them.
+    pub fn list_runs_stream(
+        &self,
+        input: ListRunsRequest,
+    ) -> impl futures::Stream<Item = Result<Run, RusotoError<ListRunsError>>> + Send {
+        rusoto_core::pagination::items(self.list_runs_pages(input), |page| {
+            page.runs.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `ListSamples` results for `input`, requesting each next page with the `nextToken` of the last one as its `nextToken`.
+    pub fn list_samples_pages(
+        &self,
+        input: ListSamplesRequest,
+    ) -> impl futures::Stream<Item = Result<ListSamplesResult, RusotoError<ListSamplesError>>> + Send
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: DeviceFarmClient, input| async move { client.list_samples(input).await },
+            |input: &ListSamplesRequest, output: &ListSamplesResult| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListSamplesRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `samples` of every page of `ListSamples` results for `input`, as `list_samples_pages` requests them.
+    pub fn list_samples_stream(
+        &self,
+        input: ListSamplesRequest,
+    ) -> impl futures::Stream<Item = Result<Sample, RusotoError<ListSamplesError>>> + Send {
+        rusoto_core::pagination::items(self.list_samples_pages(input), |page| {
+            page.samples.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `ListSuites` results for `input`, requesting each next page with the `nextToken` of the last one as its `nextToken`.
+    pub fn list_suites_pages(
+        &self,
+        input: ListSuitesRequest,
+    ) -> impl futures::Stream<Item = Result<ListSuitesResult, RusotoError<ListSuitesError>>> + Send
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: DeviceFarmClient, input| async move { client.list_suites(input).await },
+            |input: &ListSuitesRequest, output: &ListSuitesResult| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListSuitesRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `suites` of every page of `ListSuites` results for `input`, as `list_suites_pages` requests them.
+    pub fn list_suites_stream(
+        &self,
+        input: ListSuitesRequest,
+    ) -> impl futures::Stream<Item = Result<Suite, RusotoError<ListSuitesError>>> + Send {
+        rusoto_core::pagination::items(self.list_suites_pages(input), |page| {
+            page.suites.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `ListTests` results for `input`, requesting each next page with the `nextToken` of the last one as its `nextToken`.
+    pub fn list_tests_pages(
+        &self,
+        input: ListTestsRequest,
+    ) -> impl futures::Stream<Item = Result<ListTestsResult, RusotoError<ListTestsError>>> + Send
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: DeviceFarmClient, input| async move { client.list_tests(input).await },
+            |input: &ListTestsRequest, output: &ListTestsResult| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListTestsRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `tests` of every page of `ListTests` results for `input`, as `list_tests_pages` requests them.
+    pub fn list_tests_stream(
+        &self,
+        input: ListTestsRequest,
+    ) -> impl futures::Stream<Item = Result<Test, RusotoError<ListTestsError>>> + Send {
+        rusoto_core::pagination::items(self.list_tests_pages(input), |page| {
+            page.tests.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `ListUniqueProblems` results for `input`, requesting each next page with the `nextToken` of the last one as its `nextToken`.
+    pub fn list_unique_problems_pages(
+        &self,
+        input: ListUniqueProblemsRequest,
+    ) -> impl futures::Stream<
+        Item = Result<ListUniqueProblemsResult, RusotoError<ListUniqueProblemsError>>,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: DeviceFarmClient, input| async move { client.list_unique_problems(input).await },
+            |input: &ListUniqueProblemsRequest, output: &ListUniqueProblemsResult| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListUniqueProblemsRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The pages of `ListUploads` results for `input`, requesting each next page with the `nextToken` of the last one as its `nextToken`.
+    pub fn list_uploads_pages(
+        &self,
+        input: ListUploadsRequest,
+    ) -> impl futures::Stream<Item = Result<ListUploadsResult, RusotoError<ListUploadsError>>> + Send
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: DeviceFarmClient, input| async move { client.list_uploads(input).await },
+            |input: &ListUploadsRequest, output: &ListUploadsResult| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListUploadsRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `uploads` of every page of `ListUploads` results for `input`, as `list_uploads_pages` requests them.
+    pub fn list_uploads_stream(
+        &self,
+        input: ListUploadsRequest,
+    ) -> impl futures::Stream<Item = Result<Upload, RusotoError<ListUploadsError>>> + Send {
+        rusoto_core::pagination::items(self.list_uploads_pages(input), |page| {
+            page.uploads.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `ListVPCEConfigurations` results for `input`, requesting each next page with the `nextToken` of the last one as its `nextToken`.
+    /// The page size is the `maxResults` of `input`.
+    pub fn list_vpce_configurations_pages(
+        &self,
+        input: ListVPCEConfigurationsRequest,
+    ) -> impl futures::Stream<
+        Item = Result<ListVPCEConfigurationsResult, RusotoError<ListVPCEConfigurationsError>>,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: DeviceFarmClient, input| async move {
+                client.list_vpce_configurations(input).await
+            },
+            |input: &ListVPCEConfigurationsRequest, output: &ListVPCEConfigurationsResult| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListVPCEConfigurationsRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `vpceConfigurations` of every page of `ListVPCEConfigurations` results for `input`, as `list_vpce_configurations_pages` requests them.
+    pub fn list_vpce_configurations_stream(
+        &self,
+        input: ListVPCEConfigurationsRequest,
+    ) -> impl futures::Stream<
+        Item = Result<VPCEConfiguration, RusotoError<ListVPCEConfigurationsError>>,
+    > + Send {
+        rusoto_core::pagination::items(self.list_vpce_configurations_pages(input), |page| {
+            page.vpce_configurations.unwrap_or_default()
+        })
+    }
+}
+
+#[async_trait]
+impl DeviceFarm for DeviceFarmClient {
+    /// <p>Creates a device pool.</p>
+    async fn create_device_pool(
+        &self,
+        input: CreateDevicePoolRequest,
+    ) -> Result<CreateDevicePoolResult, RusotoError<CreateDevicePoolError>> {
+        self.create_device_pool_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Creates a profile that can be applied to one or more private fleet device instances.</p>
+    async fn create_instance_profile(
+        &self,
+        input: CreateInstanceProfileRequest,
+    ) -> Result<CreateInstanceProfileResult, RusotoError<CreateInstanceProfileError>> {
+        self.create_instance_profile_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Creates a network profile.</p>
+    async fn create_network_profile(
+        &self,
+        input: CreateNetworkProfileRequest,
+    ) -> Result<CreateNetworkProfileResult, RusotoError<CreateNetworkProfileError>> {
+        self.create_network_profile_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Creates a project.</p>
+    async fn create_project(
+        &self,
+        input: CreateProjectRequest,
+    ) -> Result<CreateProjectResult, RusotoError<CreateProjectError>> {
+        self.create_project_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Specifies and starts a remote access session.</p>
+    async fn create_remote_access_session(
+        &self,
+        input: CreateRemoteAccessSessionRequest,
+    ) -> Result<CreateRemoteAccessSessionResult, RusotoError<CreateRemoteAccessSessionError>> {
+        self.create_remote_access_session_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Creates a Selenium testing project. Projects are used to track <a>TestGridSession</a> instances.</p>
+    async fn create_test_grid_project(
+        &self,
+        input: CreateTestGridProjectRequest,
+    ) -> Result<CreateTestGridProjectResult, RusotoError<CreateTestGridProjectError>> {
+        self.create_test_grid_project_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Creates a signed, short-term URL that can be passed to a Selenium <code>RemoteWebDriver</code> constructor.</p>
+    async fn create_test_grid_url(
+        &self,
+        input: CreateTestGridUrlRequest,
+    ) -> Result<CreateTestGridUrlResult, RusotoError<CreateTestGridUrlError>> {
+        self.create_test_grid_url_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Uploads an app or test scripts.</p>
+    async fn create_upload(
+        &self,
+        input: CreateUploadRequest,
+    ) -> Result<CreateUploadResult, RusotoError<CreateUploadError>> {
+        self.create_upload_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Creates a configuration record in Device Farm for your Amazon Virtual Private Cloud (VPC) endpoint.</p>
+    async fn create_vpce_configuration(
+        &self,
+        input: CreateVPCEConfigurationRequest,
+    ) -> Result<CreateVPCEConfigurationResult, RusotoError<CreateVPCEConfigurationError>> {
+        self.create_vpce_configuration_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes a device pool given the pool ARN. Does not allow deletion of curated pools owned by the system.</p>
+    async fn delete_device_pool(
+        &self,
+        input: DeleteDevicePoolRequest,
+    ) -> Result<DeleteDevicePoolResult, RusotoError<DeleteDevicePoolError>> {
+        self.delete_device_pool_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes a profile that can be applied to one or more private device instances.</p>
+    async fn delete_instance_profile(
+        &self,
+        input: DeleteInstanceProfileRequest,
+    ) -> Result<DeleteInstanceProfileResult, RusotoError<DeleteInstanceProfileError>> {
+        self.delete_instance_profile_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes a network profile.</p>
+    async fn delete_network_profile(
+        &self,
+        input: DeleteNetworkProfileRequest,
+    ) -> Result<DeleteNetworkProfileResult, RusotoError<DeleteNetworkProfileError>> {
+        self.delete_network_profile_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes an AWS Device Farm project, given the project ARN.</p> <p> Deleting this resource does not stop an in-progress run.</p>
+    async fn delete_project(
+        &self,
+        input: DeleteProjectRequest,
+    ) -> Result<DeleteProjectResult, RusotoError<DeleteProjectError>> {
+        self.delete_project_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes a completed remote access session and its results.</p>
+    async fn delete_remote_access_session(
+        &self,
+        input: DeleteRemoteAccessSessionRequest,
+    ) -> Result<DeleteRemoteAccessSessionResult, RusotoError<DeleteRemoteAccessSessionError>> {
+        self.delete_remote_access_session_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes the run, given the run ARN.</p> <p> Deleting this resource does not stop an in-progress run.</p>
+    async fn delete_run(
+        &self,
+        input: DeleteRunRequest,
+    ) -> Result<DeleteRunResult, RusotoError<DeleteRunError>> {
+        self.delete_run_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p> Deletes a Selenium testing project and all content generated under it. </p> <important> <p>You cannot undo this operation.</p> </important> <note> <p>You cannot delete a project if it has active sessions.</p> </note></p>
+    async fn delete_test_grid_project(
+        &self,
+        input: DeleteTestGridProjectRequest,
+    ) -> Result<DeleteTestGridProjectResult, RusotoError<DeleteTestGridProjectError>> {
+        self.delete_test_grid_project_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes an upload given the upload ARN.</p>
+    async fn delete_upload(
+        &self,
+        input: DeleteUploadRequest,
+    ) -> Result<DeleteUploadResult, RusotoError<DeleteUploadError>> {
+        self.delete_upload_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes a configuration for your Amazon Virtual Private Cloud (VPC) endpoint.</p>
+    async fn delete_vpce_configuration(
+        &self,
+        input: DeleteVPCEConfigurationRequest,
+    ) -> Result<DeleteVPCEConfigurationResult, RusotoError<DeleteVPCEConfigurationError>> {
+        self.delete_vpce_configuration_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns the number of unmetered iOS or unmetered Android devices that have been purchased by the account.</p>
+    async fn get_account_settings(
+        &self,
+    ) -> Result<GetAccountSettingsResult, RusotoError<GetAccountSettingsError>> {
+        self.get_account_settings_with_metadata()
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Gets information about a unique device type.</p>
+    async fn get_device(
+        &self,
+        input: GetDeviceRequest,
+    ) -> Result<GetDeviceResult, RusotoError<GetDeviceError>> {
+        self.get_device_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns information about a device instance that belongs to a private device fleet.</p>
+    async fn get_device_instance(
+        &self,
+        input: GetDeviceInstanceRequest,
+    ) -> Result<GetDeviceInstanceResult, RusotoError<GetDeviceInstanceError>> {
+        self.get_device_instance_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Gets information about a device pool.</p>
+    async fn get_device_pool(
+        &self,
+        input: GetDevicePoolRequest,
+    ) -> Result<GetDevicePoolResult, RusotoError<GetDevicePoolError>> {
+        self.get_device_pool_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Gets information about compatibility with a device pool.</p>
+    async fn get_device_pool_compatibility(
+        &self,
+        input: GetDevicePoolCompatibilityRequest,
+    ) -> Result<GetDevicePoolCompatibilityResult, RusotoError<GetDevicePoolCompatibilityError>>
+    {
+        self.get_device_pool_compatibility_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns information about the specified instance profile.</p>
+    async fn get_instance_profile(
+        &self,
+        input: GetInstanceProfileRequest,
+    ) -> Result<GetInstanceProfileResult, RusotoError<GetInstanceProfileError>> {
+        self.get_instance_profile_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Gets information about a job.</p>
+    async fn get_job(
+        &self,
+        input: GetJobRequest,
+    ) -> Result<GetJobResult, RusotoError<GetJobError>> {
+        self.get_job_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns information about a network profile.</p>
+    async fn get_network_profile(
+        &self,
+        input: GetNetworkProfileRequest,
+    ) -> Result<GetNetworkProfileResult, RusotoError<GetNetworkProfileError>> {
+        self.get_network_profile_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Gets the current status and future status of all offerings purchased by an AWS account. The response indicates how many offerings are currently available and the offerings that will be available in the next period. The API returns a <code>NotEligible</code> error if the user is not permitted to invoke the operation. If you must be able to invoke this operation, contact <a href="mailto:aws-devicefarm-support@amazon.com">aws-devicefarm-support@amazon.com</a>.</p>
+    async fn get_offering_status(
+        &self,
+        input: GetOfferingStatusRequest,
+    ) -> Result<GetOfferingStatusResult, RusotoError<GetOfferingStatusError>> {
+        self.get_offering_status_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Gets information about a project.</p>
+    async fn get_project(
+        &self,
+        input: GetProjectRequest,
+    ) -> Result<GetProjectResult, RusotoError<GetProjectError>> {
+        self.get_project_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns a link to a currently running remote access session.</p>
+    async fn get_remote_access_session(
+        &self,
+        input: GetRemoteAccessSessionRequest,
+    ) -> Result<GetRemoteAccessSessionResult, RusotoError<GetRemoteAccessSessionError>> {
+        self.get_remote_access_session_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Gets information about a run.</p>
+    async fn get_run(
+        &self,
+        input: GetRunRequest,
+    ) -> Result<GetRunResult, RusotoError<GetRunError>> {
+        self.get_run_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Gets information about a suite.</p>
+    async fn get_suite(
+        &self,
+        input: GetSuiteRequest,
+    ) -> Result<GetSuiteResult, RusotoError<GetSuiteError>> {
+        self.get_suite_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Gets information about a test.</p>
+    async fn get_test(
+        &self,
+        input: GetTestRequest,
+    ) -> Result<GetTestResult, RusotoError<GetTestError>> {
+        self.get_test_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Retrieves information about a Selenium testing project.</p>
+    async fn get_test_grid_project(
+        &self,
+        input: GetTestGridProjectRequest,
+    ) -> Result<GetTestGridProjectResult, RusotoError<GetTestGridProjectError>> {
+        self.get_test_grid_project_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>A session is an instance of a browser created through a <code>RemoteWebDriver</code> with the URL from <a>CreateTestGridUrlResult$url</a>. You can use the following to look up sessions:</p> <ul> <li> <p>The session ARN (<a>GetTestGridSessionRequest$sessionArn</a>).</p> </li> <li> <p>The project ARN and a session ID (<a>GetTestGridSessionRequest$projectArn</a> and <a>GetTestGridSessionRequest$sessionId</a>).</p> </li> </ul> <p/></p>
+    async fn get_test_grid_session(
+        &self,
+        input: GetTestGridSessionRequest,
+    ) -> Result<GetTestGridSessionResult, RusotoError<GetTestGridSessionError>> {
+        self.get_test_grid_session_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Gets information about an upload.</p>
+    async fn get_upload(
+        &self,
+        input: GetUploadRequest,
+    ) -> Result<GetUploadResult, RusotoError<GetUploadError>> {
+        self.get_upload_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns information about the configuration settings for your Amazon Virtual Private Cloud (VPC) endpoint.</p>
+    async fn get_vpce_configuration(
+        &self,
+        input: GetVPCEConfigurationRequest,
+    ) -> Result<GetVPCEConfigurationResult, RusotoError<GetVPCEConfigurationError>> {
+        self.get_vpce_configuration_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Installs an application to the device in a remote access session. For Android applications, the file must be in .apk format. For iOS applications, the file must be in .ipa format.</p>
+    async fn install_to_remote_access_session(
+        &self,
+        input: InstallToRemoteAccessSessionRequest,
+    ) -> Result<InstallToRemoteAccessSessionResult, RusotoError<InstallToRemoteAccessSessionError>>
+    {
+        self.install_to_remote_access_session_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Gets information about artifacts.</p>
+    async fn list_artifacts(
+        &self,
+        input: ListArtifactsRequest,
+    ) -> Result<ListArtifactsResult, RusotoError<ListArtifactsError>> {
+        self.list_artifacts_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns information about the private device instances associated with one or more AWS accounts.</p>
+    async fn list_device_instances(
+        &self,
+        input: ListDeviceInstancesRequest,
+    ) -> Result<ListDeviceInstancesResult, RusotoError<ListDeviceInstancesError>> {
+        self.list_device_instances_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Gets information about device pools.</p>
+    async fn list_device_pools(
+        &self,
+        input: ListDevicePoolsRequest,
+    ) -> Result<ListDevicePoolsResult, RusotoError<ListDevicePoolsError>> {
+        self.list_device_pools_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Gets information about unique device types.</p>
+    async fn list_devices(
+        &self,
+        input: ListDevicesRequest,
+    ) -> Result<ListDevicesResult, RusotoError<ListDevicesError>> {
+        self.list_devices_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns information about all the instance profiles in an AWS account.</p>
+    async fn list_instance_profiles(
+        &self,
+        input: ListInstanceProfilesRequest,
+    ) -> Result<ListInstanceProfilesResult, RusotoError<ListInstanceProfilesError>> {
+        self.list_instance_profiles_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Gets information about jobs for a given test run.</p>
+    async fn list_jobs(
+        &self,
+        input: ListJobsRequest,
+    ) -> Result<ListJobsResult, RusotoError<ListJobsError>> {
+        self.list_jobs_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns the list of available network profiles.</p>
+    async fn list_network_profiles(
+        &self,
+        input: ListNetworkProfilesRequest,
+    ) -> Result<ListNetworkProfilesResult, RusotoError<ListNetworkProfilesError>> {
+        self.list_network_profiles_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns a list of offering promotions. Each offering promotion record contains the ID and description of the promotion. The API returns a <code>NotEligible</code> error if the caller is not permitted to invoke the operation. Contact <a href="mailto:aws-devicefarm-support@amazon.com">aws-devicefarm-support@amazon.com</a> if you must be able to invoke this operation.</p>
+    async fn list_offering_promotions(
+        &self,
+        input: ListOfferingPromotionsRequest,
+    ) -> Result<ListOfferingPromotionsResult, RusotoError<ListOfferingPromotionsError>> {
+        self.list_offering_promotions_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns a list of all historical purchases, renewals, and system renewal transactions for an AWS account. The list is paginated and ordered by a descending timestamp (most recent transactions are first). The API returns a <code>NotEligible</code> error if the user is not permitted to invoke the operation. If you must be able to invoke this operation, contact <a href="mailto:aws-devicefarm-support@amazon.com">aws-devicefarm-support@amazon.com</a>.</p>
+    async fn list_offering_transactions(
+        &self,
+        input: ListOfferingTransactionsRequest,
+    ) -> Result<ListOfferingTransactionsResult, RusotoError<ListOfferingTransactionsError>> {
+        self.list_offering_transactions_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns a list of products or offerings that the user can manage through the API. Each offering record indicates the recurring price per unit and the frequency for that offering. The API returns a <code>NotEligible</code> error if the user is not permitted to invoke the operation. If you must be able to invoke this operation, contact <a href="mailto:aws-devicefarm-support@amazon.com">aws-devicefarm-support@amazon.com</a>.</p>
+    async fn list_offerings(
+        &self,
+        input: ListOfferingsRequest,
+    ) -> Result<ListOfferingsResult, RusotoError<ListOfferingsError>> {
+        self.list_offerings_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Gets information about projects.</p>
+    async fn list_projects(
+        &self,
+        input: ListProjectsRequest,
+    ) -> Result<ListProjectsResult, RusotoError<ListProjectsError>> {
+        self.list_projects_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns a list of all currently running remote access sessions.</p>
+    async fn list_remote_access_sessions(
+        &self,
+        input: ListRemoteAccessSessionsRequest,
+    ) -> Result<ListRemoteAccessSessionsResult, RusotoError<ListRemoteAccessSessionsError>> {
+        self.list_remote_access_sessions_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Gets information about runs, given an AWS Device Farm project ARN.</p>
+    async fn list_runs(
+        &self,
+        input: ListRunsRequest,
+    ) -> Result<ListRunsResult, RusotoError<ListRunsError>> {
+        self.list_runs_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Gets information about samples, given an AWS Device Farm job ARN.</p>
+    async fn list_samples(
+        &self,
+        input: ListSamplesRequest,
+    ) -> Result<ListSamplesResult, RusotoError<ListSamplesError>> {
+        self.list_samples_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Gets information about test suites for a given job.</p>
+    async fn list_suites(
+        &self,
+        input: ListSuitesRequest,
+    ) -> Result<ListSuitesResult, RusotoError<ListSuitesError>> {
+        self.list_suites_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>List the tags for an AWS Device Farm resource.</p>
+    async fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> Result<ListTagsForResourceResponse, RusotoError<ListTagsForResourceError>> {
+        self.list_tags_for_resource_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Gets a list of all Selenium testing projects in your account.</p>
+    async fn list_test_grid_projects(
+        &self,
+        input: ListTestGridProjectsRequest,
+    ) -> Result<ListTestGridProjectsResult, RusotoError<ListTestGridProjectsError>> {
+        self.list_test_grid_projects_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns a list of the actions taken in a <a>TestGridSession</a>.</p>
+    async fn list_test_grid_session_actions(
+        &self,
+        input: ListTestGridSessionActionsRequest,
+    ) -> Result<ListTestGridSessionActionsResult, RusotoError<ListTestGridSessionActionsError>>
+    {
+        self.list_test_grid_session_actions_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Retrieves a list of artifacts created during the session.</p>
+    async fn list_test_grid_session_artifacts(
+        &self,
+        input: ListTestGridSessionArtifactsRequest,
+    ) -> Result<ListTestGridSessionArtifactsResult, RusotoError<ListTestGridSessionArtifactsError>>
+    {
+        self.list_test_grid_session_artifacts_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Retrieves a list of sessions for a <a>TestGridProject</a>.</p>
+    async fn list_test_grid_sessions(
+        &self,
+        input: ListTestGridSessionsRequest,
+    ) -> Result<ListTestGridSessionsResult, RusotoError<ListTestGridSessionsError>> {
+        self.list_test_grid_sessions_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Gets information about tests in a given test suite.</p>
+    async fn list_tests(
+        &self,
+        input: ListTestsRequest,
+    ) -> Result<ListTestsResult, RusotoError<ListTestsError>> {
+        self.list_tests_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Gets information about unique problems, such as exceptions or crashes.</p> <p>Unique problems are defined as a single instance of an error across a run, job, or suite. For example, if a call in your application consistently raises an exception (<code>OutOfBoundsException in MyActivity.java:386</code>), <code>ListUniqueProblems</code> returns a single entry instead of many individual entries for that exception.</p>
+    async fn list_unique_problems(
+        &self,
+        input: ListUniqueProblemsRequest,
+    ) -> Result<ListUniqueProblemsResult, RusotoError<ListUniqueProblemsError>> {
+        self.list_unique_problems_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Gets information about uploads, given an AWS Device Farm project ARN.</p>
+    async fn list_uploads(
+        &self,
+        input: ListUploadsRequest,
+    ) -> Result<ListUploadsResult, RusotoError<ListUploadsError>> {
+        self.list_uploads_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns information about all Amazon Virtual Private Cloud (VPC) endpoint configurations in the AWS account.</p>
+    async fn list_vpce_configurations(
+        &self,
+        input: ListVPCEConfigurationsRequest,
+    ) -> Result<ListVPCEConfigurationsResult, RusotoError<ListVPCEConfigurationsError>> {
+        self.list_vpce_configurations_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Immediately purchases offerings for an AWS account. Offerings renew with the latest total purchased quantity for an offering, unless the renewal was overridden. The API returns a <code>NotEligible</code> error if the user is not permitted to invoke the operation. If you must be able to invoke this operation, contact <a href="mailto:aws-devicefarm-support@amazon.com">aws-devicefarm-support@amazon.com</a>.</p>
+    async fn purchase_offering(
+        &self,
+        input: PurchaseOfferingRequest,
+    ) -> Result<PurchaseOfferingResult, RusotoError<PurchaseOfferingError>> {
+        self.purchase_offering_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Explicitly sets the quantity of devices to renew for an offering, starting from the <code>effectiveDate</code> of the next period. The API returns a <code>NotEligible</code> error if the user is not permitted to invoke the operation. If you must be able to invoke this operation, contact <a href="mailto:aws-devicefarm-support@amazon.com">aws-devicefarm-support@amazon.com</a>.</p>
+    async fn renew_offering(
+        &self,
+        input: RenewOfferingRequest,
+    ) -> Result<RenewOfferingResult, RusotoError<RenewOfferingError>> {
+        self.renew_offering_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Schedules a run.</p>
+    async fn schedule_run(
+        &self,
+        input: ScheduleRunRequest,
+    ) -> Result<ScheduleRunResult, RusotoError<ScheduleRunError>> {
+        self.schedule_run_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Initiates a stop request for the current job. AWS Device Farm immediately stops the job on the device where tests have not started. You are not billed for this device. On the device where tests have started, setup suite and teardown suite tests run to completion on the device. You are billed for setup, teardown, and any tests that were in progress or already completed.</p>
+    async fn stop_job(
+        &self,
+        input: StopJobRequest,
+    ) -> Result<StopJobResult, RusotoError<StopJobError>> {
+        self.stop_job_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Ends a specified remote access session.</p>
+    async fn stop_remote_access_session(
+        &self,
+        input: StopRemoteAccessSessionRequest,
+    ) -> Result<StopRemoteAccessSessionResult, RusotoError<StopRemoteAccessSessionError>> {
+        self.stop_remote_access_session_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Initiates a stop request for the current test run. AWS Device Farm immediately stops the run on devices where tests have not started. You are not billed for these devices. On devices where tests have started executing, setup suite and teardown suite tests run to completion on those devices. You are billed for setup, teardown, and any tests that were in progress or already completed.</p>
+    async fn stop_run(
+        &self,
+        input: StopRunRequest,
+    ) -> Result<StopRunResult, RusotoError<StopRunError>> {
+        self.stop_run_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Associates the specified tags to a resource with the specified <code>resourceArn</code>. If existing tags on a resource are not specified in the request parameters, they are not changed. When a resource is deleted, the tags associated with that resource are also deleted.</p>
+    async fn tag_resource(
+        &self,
+        input: TagResourceRequest,
+    ) -> Result<TagResourceResponse, RusotoError<TagResourceError>> {
+        self.tag_resource_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes the specified tags from a resource.</p>
+    async fn untag_resource(
+        &self,
+        input: UntagResourceRequest,
+    ) -> Result<UntagResourceResponse, RusotoError<UntagResourceError>> {
+        self.untag_resource_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Updates information about a private device instance.</p>
+    async fn update_device_instance(
+        &self,
+        input: UpdateDeviceInstanceRequest,
+    ) -> Result<UpdateDeviceInstanceResult, RusotoError<UpdateDeviceInstanceError>> {
+        self.update_device_instance_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Modifies the name, description, and rules in a device pool given the attributes and the pool ARN. Rule updates are all-or-nothing, meaning they can only be updated as a whole (or not at all).</p>
+    async fn update_device_pool(
+        &self,
+        input: UpdateDevicePoolRequest,
+    ) -> Result<UpdateDevicePoolResult, RusotoError<UpdateDevicePoolError>> {
+        self.update_device_pool_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Updates information about an existing private device instance profile.</p>
+    async fn update_instance_profile(
+        &self,
+        input: UpdateInstanceProfileRequest,
+    ) -> Result<UpdateInstanceProfileResult, RusotoError<UpdateInstanceProfileError>> {
+        self.update_instance_profile_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Updates the network profile.</p>
+    async fn update_network_profile(
+        &self,
+        input: UpdateNetworkProfileRequest,
+    ) -> Result<UpdateNetworkProfileResult, RusotoError<UpdateNetworkProfileError>> {
+        self.update_network_profile_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Modifies the specified project name, given the project ARN and a new name.</p>
+    async fn update_project(
+        &self,
+        input: UpdateProjectRequest,
+    ) -> Result<UpdateProjectResult, RusotoError<UpdateProjectError>> {
+        self.update_project_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Change details of a project.</p>
+    async fn update_test_grid_project(
+        &self,
+        input: UpdateTestGridProjectRequest,
+    ) -> Result<UpdateTestGridProjectResult, RusotoError<UpdateTestGridProjectError>> {
+        self.update_test_grid_project_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Updates an uploaded test spec.</p>
+    async fn update_upload(
+        &self,
+        input: UpdateUploadRequest,
+    ) -> Result<UpdateUploadResult, RusotoError<UpdateUploadError>> {
+        self.update_upload_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Updates information about an Amazon Virtual Private Cloud (VPC) endpoint configuration.</p>
+    async fn update_vpce_configuration(
+        &self,
+        input: UpdateVPCEConfigurationRequest,
+    ) -> Result<UpdateVPCEConfigurationResult, RusotoError<UpdateVPCEConfigurationError>> {
+        self.update_vpce_configuration_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
     }
 }
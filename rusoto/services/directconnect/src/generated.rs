@@ -16,8 +16,8 @@ use std::fmt;
 use async_trait::async_trait;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
-use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest, ResponseMetadata};
+use rusoto_core::{Client, ErrorMetadata, RusotoError};
 
 use rusoto_core::proto;
 use rusoto_core::signature::SignedRequest;
@@ -1953,11 +1953,14 @@ pub struct VirtualInterfaces {
 
 /// Errors returned by AcceptDirectConnectGatewayAssociationProposal
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum AcceptDirectConnectGatewayAssociationProposalError {
     /// <p>One or more parameters are not valid.</p>
     DirectConnectClient(String),
     /// <p>A server-side error occurred.</p>
     DirectConnectServer(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl AcceptDirectConnectGatewayAssociationProposalError {
@@ -1967,21 +1970,28 @@ impl AcceptDirectConnectGatewayAssociationProposalError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DirectConnectClientException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         AcceptDirectConnectGatewayAssociationProposalError::DirectConnectClient(
                             err.msg,
                         ),
+                        &res,
                     )
                 }
                 "DirectConnectServerException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         AcceptDirectConnectGatewayAssociationProposalError::DirectConnectServer(
                             err.msg,
                         ),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        AcceptDirectConnectGatewayAssociationProposalError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -1997,17 +2007,23 @@ impl fmt::Display for AcceptDirectConnectGatewayAssociationProposalError {
             AcceptDirectConnectGatewayAssociationProposalError::DirectConnectServer(ref cause) => {
                 write!(f, "{}", cause)
             }
+            AcceptDirectConnectGatewayAssociationProposalError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for AcceptDirectConnectGatewayAssociationProposalError {}
 /// Errors returned by AllocateConnectionOnInterconnect
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum AllocateConnectionOnInterconnectError {
     /// <p>One or more parameters are not valid.</p>
     DirectConnectClient(String),
     /// <p>A server-side error occurred.</p>
     DirectConnectServer(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl AllocateConnectionOnInterconnectError {
@@ -2017,17 +2033,24 @@ impl AllocateConnectionOnInterconnectError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DirectConnectClientException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         AllocateConnectionOnInterconnectError::DirectConnectClient(err.msg),
+                        &res,
                     )
                 }
                 "DirectConnectServerException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         AllocateConnectionOnInterconnectError::DirectConnectServer(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        AllocateConnectionOnInterconnectError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -2043,12 +2066,16 @@ impl fmt::Display for AllocateConnectionOnInterconnectError {
             AllocateConnectionOnInterconnectError::DirectConnectServer(ref cause) => {
                 write!(f, "{}", cause)
             }
+            AllocateConnectionOnInterconnectError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for AllocateConnectionOnInterconnectError {}
 /// Errors returned by AllocateHostedConnection
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum AllocateHostedConnectionError {
     /// <p>One or more parameters are not valid.</p>
     DirectConnectClient(String),
@@ -2058,6 +2085,8 @@ pub enum AllocateHostedConnectionError {
     DuplicateTagKeys(String),
     /// <p>You have reached the limit on the number of tags that can be assigned.</p>
     TooManyTags(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl AllocateHostedConnectionError {
@@ -2065,27 +2094,31 @@ impl AllocateHostedConnectionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DirectConnectClientException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         AllocateHostedConnectionError::DirectConnectClient(err.msg),
+                        &res,
                     )
                 }
                 "DirectConnectServerException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         AllocateHostedConnectionError::DirectConnectServer(err.msg),
+                        &res,
                     )
                 }
                 "DuplicateTagKeysException" => {
-                    return RusotoError::Service(AllocateHostedConnectionError::DuplicateTagKeys(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        AllocateHostedConnectionError::DuplicateTagKeys(err.msg),
+                        &res,
+                    )
                 }
                 "TooManyTagsException" => {
-                    return RusotoError::Service(AllocateHostedConnectionError::TooManyTags(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        AllocateHostedConnectionError::TooManyTags(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(AllocateHostedConnectionError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2099,12 +2132,14 @@ impl fmt::Display for AllocateHostedConnectionError {
             AllocateHostedConnectionError::DirectConnectServer(ref cause) => write!(f, "{}", cause),
             AllocateHostedConnectionError::DuplicateTagKeys(ref cause) => write!(f, "{}", cause),
             AllocateHostedConnectionError::TooManyTags(ref cause) => write!(f, "{}", cause),
+            AllocateHostedConnectionError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for AllocateHostedConnectionError {}
 /// Errors returned by AllocatePrivateVirtualInterface
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum AllocatePrivateVirtualInterfaceError {
     /// <p>One or more parameters are not valid.</p>
     DirectConnectClient(String),
@@ -2114,6 +2149,8 @@ pub enum AllocatePrivateVirtualInterfaceError {
     DuplicateTagKeys(String),
     /// <p>You have reached the limit on the number of tags that can be assigned.</p>
     TooManyTags(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl AllocatePrivateVirtualInterfaceError {
@@ -2123,27 +2160,36 @@ impl AllocatePrivateVirtualInterfaceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DirectConnectClientException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         AllocatePrivateVirtualInterfaceError::DirectConnectClient(err.msg),
+                        &res,
                     )
                 }
                 "DirectConnectServerException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         AllocatePrivateVirtualInterfaceError::DirectConnectServer(err.msg),
+                        &res,
                     )
                 }
                 "DuplicateTagKeysException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         AllocatePrivateVirtualInterfaceError::DuplicateTagKeys(err.msg),
+                        &res,
                     )
                 }
                 "TooManyTagsException" => {
-                    return RusotoError::Service(AllocatePrivateVirtualInterfaceError::TooManyTags(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        AllocatePrivateVirtualInterfaceError::TooManyTags(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        AllocatePrivateVirtualInterfaceError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -2163,12 +2209,16 @@ impl fmt::Display for AllocatePrivateVirtualInterfaceError {
                 write!(f, "{}", cause)
             }
             AllocatePrivateVirtualInterfaceError::TooManyTags(ref cause) => write!(f, "{}", cause),
+            AllocatePrivateVirtualInterfaceError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for AllocatePrivateVirtualInterfaceError {}
 /// Errors returned by AllocatePublicVirtualInterface
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum AllocatePublicVirtualInterfaceError {
     /// <p>One or more parameters are not valid.</p>
     DirectConnectClient(String),
@@ -2178,6 +2228,8 @@ pub enum AllocatePublicVirtualInterfaceError {
     DuplicateTagKeys(String),
     /// <p>You have reached the limit on the number of tags that can be assigned.</p>
     TooManyTags(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl AllocatePublicVirtualInterfaceError {
@@ -2187,27 +2239,36 @@ impl AllocatePublicVirtualInterfaceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DirectConnectClientException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         AllocatePublicVirtualInterfaceError::DirectConnectClient(err.msg),
+                        &res,
                     )
                 }
                 "DirectConnectServerException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         AllocatePublicVirtualInterfaceError::DirectConnectServer(err.msg),
+                        &res,
                     )
                 }
                 "DuplicateTagKeysException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         AllocatePublicVirtualInterfaceError::DuplicateTagKeys(err.msg),
+                        &res,
                     )
                 }
                 "TooManyTagsException" => {
-                    return RusotoError::Service(AllocatePublicVirtualInterfaceError::TooManyTags(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        AllocatePublicVirtualInterfaceError::TooManyTags(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        AllocatePublicVirtualInterfaceError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -2227,12 +2288,16 @@ impl fmt::Display for AllocatePublicVirtualInterfaceError {
                 write!(f, "{}", cause)
             }
             AllocatePublicVirtualInterfaceError::TooManyTags(ref cause) => write!(f, "{}", cause),
+            AllocatePublicVirtualInterfaceError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for AllocatePublicVirtualInterfaceError {}
 /// Errors returned by AllocateTransitVirtualInterface
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum AllocateTransitVirtualInterfaceError {
     /// <p>One or more parameters are not valid.</p>
     DirectConnectClient(String),
@@ -2242,6 +2307,8 @@ pub enum AllocateTransitVirtualInterfaceError {
     DuplicateTagKeys(String),
     /// <p>You have reached the limit on the number of tags that can be assigned.</p>
     TooManyTags(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl AllocateTransitVirtualInterfaceError {
@@ -2251,27 +2318,36 @@ impl AllocateTransitVirtualInterfaceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DirectConnectClientException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         AllocateTransitVirtualInterfaceError::DirectConnectClient(err.msg),
+                        &res,
                     )
                 }
                 "DirectConnectServerException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         AllocateTransitVirtualInterfaceError::DirectConnectServer(err.msg),
+                        &res,
                     )
                 }
                 "DuplicateTagKeysException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         AllocateTransitVirtualInterfaceError::DuplicateTagKeys(err.msg),
+                        &res,
                     )
                 }
                 "TooManyTagsException" => {
-                    return RusotoError::Service(AllocateTransitVirtualInterfaceError::TooManyTags(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        AllocateTransitVirtualInterfaceError::TooManyTags(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        AllocateTransitVirtualInterfaceError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -2291,17 +2367,23 @@ impl fmt::Display for AllocateTransitVirtualInterfaceError {
                 write!(f, "{}", cause)
             }
             AllocateTransitVirtualInterfaceError::TooManyTags(ref cause) => write!(f, "{}", cause),
+            AllocateTransitVirtualInterfaceError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for AllocateTransitVirtualInterfaceError {}
 /// Errors returned by AssociateConnectionWithLag
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum AssociateConnectionWithLagError {
     /// <p>One or more parameters are not valid.</p>
     DirectConnectClient(String),
     /// <p>A server-side error occurred.</p>
     DirectConnectServer(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl AssociateConnectionWithLagError {
@@ -2311,17 +2393,21 @@ impl AssociateConnectionWithLagError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DirectConnectClientException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         AssociateConnectionWithLagError::DirectConnectClient(err.msg),
+                        &res,
                     )
                 }
                 "DirectConnectServerException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         AssociateConnectionWithLagError::DirectConnectServer(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(AssociateConnectionWithLagError::Unhandled, &res)
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -2337,17 +2423,21 @@ impl fmt::Display for AssociateConnectionWithLagError {
             AssociateConnectionWithLagError::DirectConnectServer(ref cause) => {
                 write!(f, "{}", cause)
             }
+            AssociateConnectionWithLagError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for AssociateConnectionWithLagError {}
 /// Errors returned by AssociateHostedConnection
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum AssociateHostedConnectionError {
     /// <p>One or more parameters are not valid.</p>
     DirectConnectClient(String),
     /// <p>A server-side error occurred.</p>
     DirectConnectServer(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl AssociateHostedConnectionError {
@@ -2355,17 +2445,21 @@ impl AssociateHostedConnectionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DirectConnectClientException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         AssociateHostedConnectionError::DirectConnectClient(err.msg),
+                        &res,
                     )
                 }
                 "DirectConnectServerException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         AssociateHostedConnectionError::DirectConnectServer(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(AssociateHostedConnectionError::Unhandled, &res)
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -2381,17 +2475,21 @@ impl fmt::Display for AssociateHostedConnectionError {
             AssociateHostedConnectionError::DirectConnectServer(ref cause) => {
                 write!(f, "{}", cause)
             }
+            AssociateHostedConnectionError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for AssociateHostedConnectionError {}
 /// Errors returned by AssociateVirtualInterface
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum AssociateVirtualInterfaceError {
     /// <p>One or more parameters are not valid.</p>
     DirectConnectClient(String),
     /// <p>A server-side error occurred.</p>
     DirectConnectServer(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl AssociateVirtualInterfaceError {
@@ -2399,17 +2497,21 @@ impl AssociateVirtualInterfaceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DirectConnectClientException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         AssociateVirtualInterfaceError::DirectConnectClient(err.msg),
+                        &res,
                     )
                 }
                 "DirectConnectServerException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         AssociateVirtualInterfaceError::DirectConnectServer(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(AssociateVirtualInterfaceError::Unhandled, &res)
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -2425,17 +2527,21 @@ impl fmt::Display for AssociateVirtualInterfaceError {
             AssociateVirtualInterfaceError::DirectConnectServer(ref cause) => {
                 write!(f, "{}", cause)
             }
+            AssociateVirtualInterfaceError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for AssociateVirtualInterfaceError {}
 /// Errors returned by ConfirmConnection
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ConfirmConnectionError {
     /// <p>One or more parameters are not valid.</p>
     DirectConnectClient(String),
     /// <p>A server-side error occurred.</p>
     DirectConnectServer(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ConfirmConnectionError {
@@ -2443,17 +2549,19 @@ impl ConfirmConnectionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DirectConnectClientException" => {
-                    return RusotoError::Service(ConfirmConnectionError::DirectConnectClient(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ConfirmConnectionError::DirectConnectClient(err.msg),
+                        &res,
+                    )
                 }
                 "DirectConnectServerException" => {
-                    return RusotoError::Service(ConfirmConnectionError::DirectConnectServer(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ConfirmConnectionError::DirectConnectServer(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ConfirmConnectionError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2465,17 +2573,21 @@ impl fmt::Display for ConfirmConnectionError {
         match *self {
             ConfirmConnectionError::DirectConnectClient(ref cause) => write!(f, "{}", cause),
             ConfirmConnectionError::DirectConnectServer(ref cause) => write!(f, "{}", cause),
+            ConfirmConnectionError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ConfirmConnectionError {}
 /// Errors returned by ConfirmPrivateVirtualInterface
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ConfirmPrivateVirtualInterfaceError {
     /// <p>One or more parameters are not valid.</p>
     DirectConnectClient(String),
     /// <p>A server-side error occurred.</p>
     DirectConnectServer(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ConfirmPrivateVirtualInterfaceError {
@@ -2485,17 +2597,24 @@ impl ConfirmPrivateVirtualInterfaceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DirectConnectClientException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         ConfirmPrivateVirtualInterfaceError::DirectConnectClient(err.msg),
+                        &res,
                     )
                 }
                 "DirectConnectServerException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         ConfirmPrivateVirtualInterfaceError::DirectConnectServer(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        ConfirmPrivateVirtualInterfaceError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -2511,17 +2630,23 @@ impl fmt::Display for ConfirmPrivateVirtualInterfaceError {
             ConfirmPrivateVirtualInterfaceError::DirectConnectServer(ref cause) => {
                 write!(f, "{}", cause)
             }
+            ConfirmPrivateVirtualInterfaceError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for ConfirmPrivateVirtualInterfaceError {}
 /// Errors returned by ConfirmPublicVirtualInterface
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ConfirmPublicVirtualInterfaceError {
     /// <p>One or more parameters are not valid.</p>
     DirectConnectClient(String),
     /// <p>A server-side error occurred.</p>
     DirectConnectServer(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ConfirmPublicVirtualInterfaceError {
@@ -2531,17 +2656,24 @@ impl ConfirmPublicVirtualInterfaceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DirectConnectClientException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         ConfirmPublicVirtualInterfaceError::DirectConnectClient(err.msg),
+                        &res,
                     )
                 }
                 "DirectConnectServerException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         ConfirmPublicVirtualInterfaceError::DirectConnectServer(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        ConfirmPublicVirtualInterfaceError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -2557,17 +2689,23 @@ impl fmt::Display for ConfirmPublicVirtualInterfaceError {
             ConfirmPublicVirtualInterfaceError::DirectConnectServer(ref cause) => {
                 write!(f, "{}", cause)
             }
+            ConfirmPublicVirtualInterfaceError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for ConfirmPublicVirtualInterfaceError {}
 /// Errors returned by ConfirmTransitVirtualInterface
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ConfirmTransitVirtualInterfaceError {
     /// <p>One or more parameters are not valid.</p>
     DirectConnectClient(String),
     /// <p>A server-side error occurred.</p>
     DirectConnectServer(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ConfirmTransitVirtualInterfaceError {
@@ -2577,17 +2715,24 @@ impl ConfirmTransitVirtualInterfaceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DirectConnectClientException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         ConfirmTransitVirtualInterfaceError::DirectConnectClient(err.msg),
+                        &res,
                     )
                 }
                 "DirectConnectServerException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         ConfirmTransitVirtualInterfaceError::DirectConnectServer(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        ConfirmTransitVirtualInterfaceError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -2603,17 +2748,23 @@ impl fmt::Display for ConfirmTransitVirtualInterfaceError {
             ConfirmTransitVirtualInterfaceError::DirectConnectServer(ref cause) => {
                 write!(f, "{}", cause)
             }
+            ConfirmTransitVirtualInterfaceError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for ConfirmTransitVirtualInterfaceError {}
 /// Errors returned by CreateBGPPeer
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateBGPPeerError {
     /// <p>One or more parameters are not valid.</p>
     DirectConnectClient(String),
     /// <p>A server-side error occurred.</p>
     DirectConnectServer(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateBGPPeerError {
@@ -2621,13 +2772,19 @@ impl CreateBGPPeerError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DirectConnectClientException" => {
-                    return RusotoError::Service(CreateBGPPeerError::DirectConnectClient(err.msg))
+                    return RusotoError::service(
+                        CreateBGPPeerError::DirectConnectClient(err.msg),
+                        &res,
+                    )
                 }
                 "DirectConnectServerException" => {
-                    return RusotoError::Service(CreateBGPPeerError::DirectConnectServer(err.msg))
+                    return RusotoError::service(
+                        CreateBGPPeerError::DirectConnectServer(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreateBGPPeerError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2639,12 +2796,14 @@ impl fmt::Display for CreateBGPPeerError {
         match *self {
             CreateBGPPeerError::DirectConnectClient(ref cause) => write!(f, "{}", cause),
             CreateBGPPeerError::DirectConnectServer(ref cause) => write!(f, "{}", cause),
+            CreateBGPPeerError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateBGPPeerError {}
 /// Errors returned by CreateConnection
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateConnectionError {
     /// <p>One or more parameters are not valid.</p>
     DirectConnectClient(String),
@@ -2654,6 +2813,8 @@ pub enum CreateConnectionError {
     DuplicateTagKeys(String),
     /// <p>You have reached the limit on the number of tags that can be assigned.</p>
     TooManyTags(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateConnectionError {
@@ -2661,23 +2822,28 @@ impl CreateConnectionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DirectConnectClientException" => {
-                    return RusotoError::Service(CreateConnectionError::DirectConnectClient(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateConnectionError::DirectConnectClient(err.msg),
+                        &res,
+                    )
                 }
                 "DirectConnectServerException" => {
-                    return RusotoError::Service(CreateConnectionError::DirectConnectServer(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateConnectionError::DirectConnectServer(err.msg),
+                        &res,
+                    )
                 }
                 "DuplicateTagKeysException" => {
-                    return RusotoError::Service(CreateConnectionError::DuplicateTagKeys(err.msg))
+                    return RusotoError::service(
+                        CreateConnectionError::DuplicateTagKeys(err.msg),
+                        &res,
+                    )
                 }
                 "TooManyTagsException" => {
-                    return RusotoError::Service(CreateConnectionError::TooManyTags(err.msg))
+                    return RusotoError::service(CreateConnectionError::TooManyTags(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreateConnectionError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2691,17 +2857,21 @@ impl fmt::Display for CreateConnectionError {
             CreateConnectionError::DirectConnectServer(ref cause) => write!(f, "{}", cause),
             CreateConnectionError::DuplicateTagKeys(ref cause) => write!(f, "{}", cause),
             CreateConnectionError::TooManyTags(ref cause) => write!(f, "{}", cause),
+            CreateConnectionError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateConnectionError {}
 /// Errors returned by CreateDirectConnectGateway
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateDirectConnectGatewayError {
     /// <p>One or more parameters are not valid.</p>
     DirectConnectClient(String),
     /// <p>A server-side error occurred.</p>
     DirectConnectServer(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateDirectConnectGatewayError {
@@ -2711,17 +2881,21 @@ impl CreateDirectConnectGatewayError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DirectConnectClientException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         CreateDirectConnectGatewayError::DirectConnectClient(err.msg),
+                        &res,
                     )
                 }
                 "DirectConnectServerException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         CreateDirectConnectGatewayError::DirectConnectServer(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(CreateDirectConnectGatewayError::Unhandled, &res)
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -2737,17 +2911,21 @@ impl fmt::Display for CreateDirectConnectGatewayError {
             CreateDirectConnectGatewayError::DirectConnectServer(ref cause) => {
                 write!(f, "{}", cause)
             }
+            CreateDirectConnectGatewayError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateDirectConnectGatewayError {}
 /// Errors returned by CreateDirectConnectGatewayAssociation
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateDirectConnectGatewayAssociationError {
     /// <p>One or more parameters are not valid.</p>
     DirectConnectClient(String),
     /// <p>A server-side error occurred.</p>
     DirectConnectServer(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateDirectConnectGatewayAssociationError {
@@ -2757,17 +2935,24 @@ impl CreateDirectConnectGatewayAssociationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DirectConnectClientException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         CreateDirectConnectGatewayAssociationError::DirectConnectClient(err.msg),
+                        &res,
                     )
                 }
                 "DirectConnectServerException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         CreateDirectConnectGatewayAssociationError::DirectConnectServer(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        CreateDirectConnectGatewayAssociationError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -2783,17 +2968,23 @@ impl fmt::Display for CreateDirectConnectGatewayAssociationError {
             CreateDirectConnectGatewayAssociationError::DirectConnectServer(ref cause) => {
                 write!(f, "{}", cause)
             }
+            CreateDirectConnectGatewayAssociationError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for CreateDirectConnectGatewayAssociationError {}
 /// Errors returned by CreateDirectConnectGatewayAssociationProposal
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateDirectConnectGatewayAssociationProposalError {
     /// <p>One or more parameters are not valid.</p>
     DirectConnectClient(String),
     /// <p>A server-side error occurred.</p>
     DirectConnectServer(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateDirectConnectGatewayAssociationProposalError {
@@ -2803,21 +2994,28 @@ impl CreateDirectConnectGatewayAssociationProposalError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DirectConnectClientException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         CreateDirectConnectGatewayAssociationProposalError::DirectConnectClient(
                             err.msg,
                         ),
+                        &res,
                     )
                 }
                 "DirectConnectServerException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         CreateDirectConnectGatewayAssociationProposalError::DirectConnectServer(
                             err.msg,
                         ),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        CreateDirectConnectGatewayAssociationProposalError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -2833,12 +3031,16 @@ impl fmt::Display for CreateDirectConnectGatewayAssociationProposalError {
             CreateDirectConnectGatewayAssociationProposalError::DirectConnectServer(ref cause) => {
                 write!(f, "{}", cause)
             }
+            CreateDirectConnectGatewayAssociationProposalError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for CreateDirectConnectGatewayAssociationProposalError {}
 /// Errors returned by CreateInterconnect
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateInterconnectError {
     /// <p>One or more parameters are not valid.</p>
     DirectConnectClient(String),
@@ -2848,6 +3050,8 @@ pub enum CreateInterconnectError {
     DuplicateTagKeys(String),
     /// <p>You have reached the limit on the number of tags that can be assigned.</p>
     TooManyTags(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateInterconnectError {
@@ -2855,23 +3059,31 @@ impl CreateInterconnectError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DirectConnectClientException" => {
-                    return RusotoError::Service(CreateInterconnectError::DirectConnectClient(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateInterconnectError::DirectConnectClient(err.msg),
+                        &res,
+                    )
                 }
                 "DirectConnectServerException" => {
-                    return RusotoError::Service(CreateInterconnectError::DirectConnectServer(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateInterconnectError::DirectConnectServer(err.msg),
+                        &res,
+                    )
                 }
                 "DuplicateTagKeysException" => {
-                    return RusotoError::Service(CreateInterconnectError::DuplicateTagKeys(err.msg))
+                    return RusotoError::service(
+                        CreateInterconnectError::DuplicateTagKeys(err.msg),
+                        &res,
+                    )
                 }
                 "TooManyTagsException" => {
-                    return RusotoError::Service(CreateInterconnectError::TooManyTags(err.msg))
+                    return RusotoError::service(
+                        CreateInterconnectError::TooManyTags(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreateInterconnectError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2885,12 +3097,14 @@ impl fmt::Display for CreateInterconnectError {
             CreateInterconnectError::DirectConnectServer(ref cause) => write!(f, "{}", cause),
             CreateInterconnectError::DuplicateTagKeys(ref cause) => write!(f, "{}", cause),
             CreateInterconnectError::TooManyTags(ref cause) => write!(f, "{}", cause),
+            CreateInterconnectError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateInterconnectError {}
 /// Errors returned by CreateLag
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateLagError {
     /// <p>One or more parameters are not valid.</p>
     DirectConnectClient(String),
@@ -2900,6 +3114,8 @@ pub enum CreateLagError {
     DuplicateTagKeys(String),
     /// <p>You have reached the limit on the number of tags that can be assigned.</p>
     TooManyTags(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateLagError {
@@ -2907,19 +3123,19 @@ impl CreateLagError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DirectConnectClientException" => {
-                    return RusotoError::Service(CreateLagError::DirectConnectClient(err.msg))
+                    return RusotoError::service(CreateLagError::DirectConnectClient(err.msg), &res)
                 }
                 "DirectConnectServerException" => {
-                    return RusotoError::Service(CreateLagError::DirectConnectServer(err.msg))
+                    return RusotoError::service(CreateLagError::DirectConnectServer(err.msg), &res)
                 }
                 "DuplicateTagKeysException" => {
-                    return RusotoError::Service(CreateLagError::DuplicateTagKeys(err.msg))
+                    return RusotoError::service(CreateLagError::DuplicateTagKeys(err.msg), &res)
                 }
                 "TooManyTagsException" => {
-                    return RusotoError::Service(CreateLagError::TooManyTags(err.msg))
+                    return RusotoError::service(CreateLagError::TooManyTags(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreateLagError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2933,12 +3149,14 @@ impl fmt::Display for CreateLagError {
             CreateLagError::DirectConnectServer(ref cause) => write!(f, "{}", cause),
             CreateLagError::DuplicateTagKeys(ref cause) => write!(f, "{}", cause),
             CreateLagError::TooManyTags(ref cause) => write!(f, "{}", cause),
+            CreateLagError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateLagError {}
 /// Errors returned by CreatePrivateVirtualInterface
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreatePrivateVirtualInterfaceError {
     /// <p>One or more parameters are not valid.</p>
     DirectConnectClient(String),
@@ -2948,6 +3166,8 @@ pub enum CreatePrivateVirtualInterfaceError {
     DuplicateTagKeys(String),
     /// <p>You have reached the limit on the number of tags that can be assigned.</p>
     TooManyTags(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreatePrivateVirtualInterfaceError {
@@ -2957,27 +3177,36 @@ impl CreatePrivateVirtualInterfaceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DirectConnectClientException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         CreatePrivateVirtualInterfaceError::DirectConnectClient(err.msg),
+                        &res,
                     )
                 }
                 "DirectConnectServerException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         CreatePrivateVirtualInterfaceError::DirectConnectServer(err.msg),
+                        &res,
                     )
                 }
                 "DuplicateTagKeysException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         CreatePrivateVirtualInterfaceError::DuplicateTagKeys(err.msg),
+                        &res,
                     )
                 }
                 "TooManyTagsException" => {
-                    return RusotoError::Service(CreatePrivateVirtualInterfaceError::TooManyTags(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreatePrivateVirtualInterfaceError::TooManyTags(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        CreatePrivateVirtualInterfaceError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -2997,12 +3226,16 @@ impl fmt::Display for CreatePrivateVirtualInterfaceError {
                 write!(f, "{}", cause)
             }
             CreatePrivateVirtualInterfaceError::TooManyTags(ref cause) => write!(f, "{}", cause),
+            CreatePrivateVirtualInterfaceError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for CreatePrivateVirtualInterfaceError {}
 /// Errors returned by CreatePublicVirtualInterface
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreatePublicVirtualInterfaceError {
     /// <p>One or more parameters are not valid.</p>
     DirectConnectClient(String),
@@ -3012,6 +3245,8 @@ pub enum CreatePublicVirtualInterfaceError {
     DuplicateTagKeys(String),
     /// <p>You have reached the limit on the number of tags that can be assigned.</p>
     TooManyTags(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreatePublicVirtualInterfaceError {
@@ -3021,27 +3256,36 @@ impl CreatePublicVirtualInterfaceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DirectConnectClientException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         CreatePublicVirtualInterfaceError::DirectConnectClient(err.msg),
+                        &res,
                     )
                 }
                 "DirectConnectServerException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         CreatePublicVirtualInterfaceError::DirectConnectServer(err.msg),
+                        &res,
                     )
                 }
                 "DuplicateTagKeysException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         CreatePublicVirtualInterfaceError::DuplicateTagKeys(err.msg),
+                        &res,
                     )
                 }
                 "TooManyTagsException" => {
-                    return RusotoError::Service(CreatePublicVirtualInterfaceError::TooManyTags(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreatePublicVirtualInterfaceError::TooManyTags(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        CreatePublicVirtualInterfaceError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -3061,12 +3305,14 @@ impl fmt::Display for CreatePublicVirtualInterfaceError {
                 write!(f, "{}", cause)
             }
             CreatePublicVirtualInterfaceError::TooManyTags(ref cause) => write!(f, "{}", cause),
+            CreatePublicVirtualInterfaceError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreatePublicVirtualInterfaceError {}
 /// Errors returned by CreateTransitVirtualInterface
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateTransitVirtualInterfaceError {
     /// <p>One or more parameters are not valid.</p>
     DirectConnectClient(String),
@@ -3076,6 +3322,8 @@ pub enum CreateTransitVirtualInterfaceError {
     DuplicateTagKeys(String),
     /// <p>You have reached the limit on the number of tags that can be assigned.</p>
     TooManyTags(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateTransitVirtualInterfaceError {
@@ -3085,27 +3333,36 @@ impl CreateTransitVirtualInterfaceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DirectConnectClientException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         CreateTransitVirtualInterfaceError::DirectConnectClient(err.msg),
+                        &res,
                     )
                 }
                 "DirectConnectServerException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         CreateTransitVirtualInterfaceError::DirectConnectServer(err.msg),
+                        &res,
                     )
                 }
                 "DuplicateTagKeysException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         CreateTransitVirtualInterfaceError::DuplicateTagKeys(err.msg),
+                        &res,
                     )
                 }
                 "TooManyTagsException" => {
-                    return RusotoError::Service(CreateTransitVirtualInterfaceError::TooManyTags(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateTransitVirtualInterfaceError::TooManyTags(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        CreateTransitVirtualInterfaceError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -3125,17 +3382,23 @@ impl fmt::Display for CreateTransitVirtualInterfaceError {
                 write!(f, "{}", cause)
             }
             CreateTransitVirtualInterfaceError::TooManyTags(ref cause) => write!(f, "{}", cause),
+            CreateTransitVirtualInterfaceError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for CreateTransitVirtualInterfaceError {}
 /// Errors returned by DeleteBGPPeer
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteBGPPeerError {
     /// <p>One or more parameters are not valid.</p>
     DirectConnectClient(String),
     /// <p>A server-side error occurred.</p>
     DirectConnectServer(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteBGPPeerError {
@@ -3143,13 +3406,19 @@ impl DeleteBGPPeerError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DirectConnectClientException" => {
-                    return RusotoError::Service(DeleteBGPPeerError::DirectConnectClient(err.msg))
+                    return RusotoError::service(
+                        DeleteBGPPeerError::DirectConnectClient(err.msg),
+                        &res,
+                    )
                 }
                 "DirectConnectServerException" => {
-                    return RusotoError::Service(DeleteBGPPeerError::DirectConnectServer(err.msg))
+                    return RusotoError::service(
+                        DeleteBGPPeerError::DirectConnectServer(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteBGPPeerError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3161,17 +3430,21 @@ impl fmt::Display for DeleteBGPPeerError {
         match *self {
             DeleteBGPPeerError::DirectConnectClient(ref cause) => write!(f, "{}", cause),
             DeleteBGPPeerError::DirectConnectServer(ref cause) => write!(f, "{}", cause),
+            DeleteBGPPeerError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteBGPPeerError {}
 /// Errors returned by DeleteConnection
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteConnectionError {
     /// <p>One or more parameters are not valid.</p>
     DirectConnectClient(String),
     /// <p>A server-side error occurred.</p>
     DirectConnectServer(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteConnectionError {
@@ -3179,17 +3452,19 @@ impl DeleteConnectionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DirectConnectClientException" => {
-                    return RusotoError::Service(DeleteConnectionError::DirectConnectClient(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteConnectionError::DirectConnectClient(err.msg),
+                        &res,
+                    )
                 }
                 "DirectConnectServerException" => {
-                    return RusotoError::Service(DeleteConnectionError::DirectConnectServer(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteConnectionError::DirectConnectServer(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteConnectionError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3201,17 +3476,21 @@ impl fmt::Display for DeleteConnectionError {
         match *self {
             DeleteConnectionError::DirectConnectClient(ref cause) => write!(f, "{}", cause),
             DeleteConnectionError::DirectConnectServer(ref cause) => write!(f, "{}", cause),
+            DeleteConnectionError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteConnectionError {}
 /// Errors returned by DeleteDirectConnectGateway
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteDirectConnectGatewayError {
     /// <p>One or more parameters are not valid.</p>
     DirectConnectClient(String),
     /// <p>A server-side error occurred.</p>
     DirectConnectServer(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteDirectConnectGatewayError {
@@ -3221,17 +3500,21 @@ impl DeleteDirectConnectGatewayError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DirectConnectClientException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DeleteDirectConnectGatewayError::DirectConnectClient(err.msg),
+                        &res,
                     )
                 }
                 "DirectConnectServerException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DeleteDirectConnectGatewayError::DirectConnectServer(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(DeleteDirectConnectGatewayError::Unhandled, &res)
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -3247,17 +3530,21 @@ impl fmt::Display for DeleteDirectConnectGatewayError {
             DeleteDirectConnectGatewayError::DirectConnectServer(ref cause) => {
                 write!(f, "{}", cause)
             }
+            DeleteDirectConnectGatewayError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteDirectConnectGatewayError {}
 /// Errors returned by DeleteDirectConnectGatewayAssociation
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteDirectConnectGatewayAssociationError {
     /// <p>One or more parameters are not valid.</p>
     DirectConnectClient(String),
     /// <p>A server-side error occurred.</p>
     DirectConnectServer(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteDirectConnectGatewayAssociationError {
@@ -3267,17 +3554,24 @@ impl DeleteDirectConnectGatewayAssociationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DirectConnectClientException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DeleteDirectConnectGatewayAssociationError::DirectConnectClient(err.msg),
+                        &res,
                     )
                 }
                 "DirectConnectServerException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DeleteDirectConnectGatewayAssociationError::DirectConnectServer(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        DeleteDirectConnectGatewayAssociationError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -3293,17 +3587,23 @@ impl fmt::Display for DeleteDirectConnectGatewayAssociationError {
             DeleteDirectConnectGatewayAssociationError::DirectConnectServer(ref cause) => {
                 write!(f, "{}", cause)
             }
+            DeleteDirectConnectGatewayAssociationError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for DeleteDirectConnectGatewayAssociationError {}
 /// Errors returned by DeleteDirectConnectGatewayAssociationProposal
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteDirectConnectGatewayAssociationProposalError {
     /// <p>One or more parameters are not valid.</p>
     DirectConnectClient(String),
     /// <p>A server-side error occurred.</p>
     DirectConnectServer(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteDirectConnectGatewayAssociationProposalError {
@@ -3313,21 +3613,28 @@ impl DeleteDirectConnectGatewayAssociationProposalError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DirectConnectClientException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DeleteDirectConnectGatewayAssociationProposalError::DirectConnectClient(
                             err.msg,
                         ),
+                        &res,
                     )
                 }
                 "DirectConnectServerException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DeleteDirectConnectGatewayAssociationProposalError::DirectConnectServer(
                             err.msg,
                         ),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        DeleteDirectConnectGatewayAssociationProposalError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -3343,17 +3650,23 @@ impl fmt::Display for DeleteDirectConnectGatewayAssociationProposalError {
             DeleteDirectConnectGatewayAssociationProposalError::DirectConnectServer(ref cause) => {
                 write!(f, "{}", cause)
             }
+            DeleteDirectConnectGatewayAssociationProposalError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for DeleteDirectConnectGatewayAssociationProposalError {}
 /// Errors returned by DeleteInterconnect
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteInterconnectError {
     /// <p>One or more parameters are not valid.</p>
     DirectConnectClient(String),
     /// <p>A server-side error occurred.</p>
     DirectConnectServer(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteInterconnectError {
@@ -3361,17 +3674,19 @@ impl DeleteInterconnectError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DirectConnectClientException" => {
-                    return RusotoError::Service(DeleteInterconnectError::DirectConnectClient(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteInterconnectError::DirectConnectClient(err.msg),
+                        &res,
+                    )
                 }
                 "DirectConnectServerException" => {
-                    return RusotoError::Service(DeleteInterconnectError::DirectConnectServer(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteInterconnectError::DirectConnectServer(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteInterconnectError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3383,17 +3698,21 @@ impl fmt::Display for DeleteInterconnectError {
         match *self {
             DeleteInterconnectError::DirectConnectClient(ref cause) => write!(f, "{}", cause),
             DeleteInterconnectError::DirectConnectServer(ref cause) => write!(f, "{}", cause),
+            DeleteInterconnectError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteInterconnectError {}
 /// Errors returned by DeleteLag
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteLagError {
     /// <p>One or more parameters are not valid.</p>
     DirectConnectClient(String),
     /// <p>A server-side error occurred.</p>
     DirectConnectServer(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteLagError {
@@ -3401,13 +3720,13 @@ impl DeleteLagError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DirectConnectClientException" => {
-                    return RusotoError::Service(DeleteLagError::DirectConnectClient(err.msg))
+                    return RusotoError::service(DeleteLagError::DirectConnectClient(err.msg), &res)
                 }
                 "DirectConnectServerException" => {
-                    return RusotoError::Service(DeleteLagError::DirectConnectServer(err.msg))
+                    return RusotoError::service(DeleteLagError::DirectConnectServer(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteLagError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3419,17 +3738,21 @@ impl fmt::Display for DeleteLagError {
         match *self {
             DeleteLagError::DirectConnectClient(ref cause) => write!(f, "{}", cause),
             DeleteLagError::DirectConnectServer(ref cause) => write!(f, "{}", cause),
+            DeleteLagError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteLagError {}
 /// Errors returned by DeleteVirtualInterface
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteVirtualInterfaceError {
     /// <p>One or more parameters are not valid.</p>
     DirectConnectClient(String),
     /// <p>A server-side error occurred.</p>
     DirectConnectServer(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteVirtualInterfaceError {
@@ -3437,17 +3760,19 @@ impl DeleteVirtualInterfaceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DirectConnectClientException" => {
-                    return RusotoError::Service(DeleteVirtualInterfaceError::DirectConnectClient(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteVirtualInterfaceError::DirectConnectClient(err.msg),
+                        &res,
+                    )
                 }
                 "DirectConnectServerException" => {
-                    return RusotoError::Service(DeleteVirtualInterfaceError::DirectConnectServer(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteVirtualInterfaceError::DirectConnectServer(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteVirtualInterfaceError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3459,17 +3784,21 @@ impl fmt::Display for DeleteVirtualInterfaceError {
         match *self {
             DeleteVirtualInterfaceError::DirectConnectClient(ref cause) => write!(f, "{}", cause),
             DeleteVirtualInterfaceError::DirectConnectServer(ref cause) => write!(f, "{}", cause),
+            DeleteVirtualInterfaceError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteVirtualInterfaceError {}
 /// Errors returned by DescribeConnectionLoa
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeConnectionLoaError {
     /// <p>One or more parameters are not valid.</p>
     DirectConnectClient(String),
     /// <p>A server-side error occurred.</p>
     DirectConnectServer(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeConnectionLoaError {
@@ -3477,17 +3806,19 @@ impl DescribeConnectionLoaError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DirectConnectClientException" => {
-                    return RusotoError::Service(DescribeConnectionLoaError::DirectConnectClient(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeConnectionLoaError::DirectConnectClient(err.msg),
+                        &res,
+                    )
                 }
                 "DirectConnectServerException" => {
-                    return RusotoError::Service(DescribeConnectionLoaError::DirectConnectServer(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeConnectionLoaError::DirectConnectServer(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DescribeConnectionLoaError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3499,17 +3830,21 @@ impl fmt::Display for DescribeConnectionLoaError {
         match *self {
             DescribeConnectionLoaError::DirectConnectClient(ref cause) => write!(f, "{}", cause),
             DescribeConnectionLoaError::DirectConnectServer(ref cause) => write!(f, "{}", cause),
+            DescribeConnectionLoaError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeConnectionLoaError {}
 /// Errors returned by DescribeConnections
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeConnectionsError {
     /// <p>One or more parameters are not valid.</p>
     DirectConnectClient(String),
     /// <p>A server-side error occurred.</p>
     DirectConnectServer(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeConnectionsError {
@@ -3517,17 +3852,19 @@ impl DescribeConnectionsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DirectConnectClientException" => {
-                    return RusotoError::Service(DescribeConnectionsError::DirectConnectClient(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeConnectionsError::DirectConnectClient(err.msg),
+                        &res,
+                    )
                 }
                 "DirectConnectServerException" => {
-                    return RusotoError::Service(DescribeConnectionsError::DirectConnectServer(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeConnectionsError::DirectConnectServer(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DescribeConnectionsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3539,17 +3876,21 @@ impl fmt::Display for DescribeConnectionsError {
         match *self {
             DescribeConnectionsError::DirectConnectClient(ref cause) => write!(f, "{}", cause),
             DescribeConnectionsError::DirectConnectServer(ref cause) => write!(f, "{}", cause),
+            DescribeConnectionsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeConnectionsError {}
 /// Errors returned by DescribeConnectionsOnInterconnect
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeConnectionsOnInterconnectError {
     /// <p>One or more parameters are not valid.</p>
     DirectConnectClient(String),
     /// <p>A server-side error occurred.</p>
     DirectConnectServer(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeConnectionsOnInterconnectError {
@@ -3559,17 +3900,24 @@ impl DescribeConnectionsOnInterconnectError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DirectConnectClientException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DescribeConnectionsOnInterconnectError::DirectConnectClient(err.msg),
+                        &res,
                     )
                 }
                 "DirectConnectServerException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DescribeConnectionsOnInterconnectError::DirectConnectServer(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        DescribeConnectionsOnInterconnectError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -3585,17 +3933,23 @@ impl fmt::Display for DescribeConnectionsOnInterconnectError {
             DescribeConnectionsOnInterconnectError::DirectConnectServer(ref cause) => {
                 write!(f, "{}", cause)
             }
+            DescribeConnectionsOnInterconnectError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for DescribeConnectionsOnInterconnectError {}
 /// Errors returned by DescribeDirectConnectGatewayAssociationProposals
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeDirectConnectGatewayAssociationProposalsError {
     /// <p>One or more parameters are not valid.</p>
     DirectConnectClient(String),
     /// <p>A server-side error occurred.</p>
     DirectConnectServer(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeDirectConnectGatewayAssociationProposalsError {
@@ -3605,21 +3959,28 @@ impl DescribeDirectConnectGatewayAssociationProposalsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DirectConnectClientException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DescribeDirectConnectGatewayAssociationProposalsError::DirectConnectClient(
                             err.msg,
                         ),
+                        &res,
                     )
                 }
                 "DirectConnectServerException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DescribeDirectConnectGatewayAssociationProposalsError::DirectConnectServer(
                             err.msg,
                         ),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        DescribeDirectConnectGatewayAssociationProposalsError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -3635,17 +3996,23 @@ impl fmt::Display for DescribeDirectConnectGatewayAssociationProposalsError {
             DescribeDirectConnectGatewayAssociationProposalsError::DirectConnectServer(
                 ref cause,
             ) => write!(f, "{}", cause),
+            DescribeDirectConnectGatewayAssociationProposalsError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for DescribeDirectConnectGatewayAssociationProposalsError {}
 /// Errors returned by DescribeDirectConnectGatewayAssociations
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeDirectConnectGatewayAssociationsError {
     /// <p>One or more parameters are not valid.</p>
     DirectConnectClient(String),
     /// <p>A server-side error occurred.</p>
     DirectConnectServer(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeDirectConnectGatewayAssociationsError {
@@ -3655,17 +4022,24 @@ impl DescribeDirectConnectGatewayAssociationsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DirectConnectClientException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DescribeDirectConnectGatewayAssociationsError::DirectConnectClient(err.msg),
+                        &res,
                     )
                 }
                 "DirectConnectServerException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DescribeDirectConnectGatewayAssociationsError::DirectConnectServer(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        DescribeDirectConnectGatewayAssociationsError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -3681,17 +4055,23 @@ impl fmt::Display for DescribeDirectConnectGatewayAssociationsError {
             DescribeDirectConnectGatewayAssociationsError::DirectConnectServer(ref cause) => {
                 write!(f, "{}", cause)
             }
+            DescribeDirectConnectGatewayAssociationsError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for DescribeDirectConnectGatewayAssociationsError {}
 /// Errors returned by DescribeDirectConnectGatewayAttachments
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeDirectConnectGatewayAttachmentsError {
     /// <p>One or more parameters are not valid.</p>
     DirectConnectClient(String),
     /// <p>A server-side error occurred.</p>
     DirectConnectServer(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeDirectConnectGatewayAttachmentsError {
@@ -3701,17 +4081,24 @@ impl DescribeDirectConnectGatewayAttachmentsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DirectConnectClientException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DescribeDirectConnectGatewayAttachmentsError::DirectConnectClient(err.msg),
+                        &res,
                     )
                 }
                 "DirectConnectServerException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DescribeDirectConnectGatewayAttachmentsError::DirectConnectServer(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        DescribeDirectConnectGatewayAttachmentsError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -3727,17 +4114,23 @@ impl fmt::Display for DescribeDirectConnectGatewayAttachmentsError {
             DescribeDirectConnectGatewayAttachmentsError::DirectConnectServer(ref cause) => {
                 write!(f, "{}", cause)
             }
+            DescribeDirectConnectGatewayAttachmentsError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for DescribeDirectConnectGatewayAttachmentsError {}
 /// Errors returned by DescribeDirectConnectGateways
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeDirectConnectGatewaysError {
     /// <p>One or more parameters are not valid.</p>
     DirectConnectClient(String),
     /// <p>A server-side error occurred.</p>
     DirectConnectServer(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeDirectConnectGatewaysError {
@@ -3747,17 +4140,24 @@ impl DescribeDirectConnectGatewaysError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DirectConnectClientException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DescribeDirectConnectGatewaysError::DirectConnectClient(err.msg),
+                        &res,
                     )
                 }
                 "DirectConnectServerException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DescribeDirectConnectGatewaysError::DirectConnectServer(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        DescribeDirectConnectGatewaysError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -3773,17 +4173,23 @@ impl fmt::Display for DescribeDirectConnectGatewaysError {
             DescribeDirectConnectGatewaysError::DirectConnectServer(ref cause) => {
                 write!(f, "{}", cause)
             }
+            DescribeDirectConnectGatewaysError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for DescribeDirectConnectGatewaysError {}
 /// Errors returned by DescribeHostedConnections
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeHostedConnectionsError {
     /// <p>One or more parameters are not valid.</p>
     DirectConnectClient(String),
     /// <p>A server-side error occurred.</p>
     DirectConnectServer(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeHostedConnectionsError {
@@ -3791,17 +4197,21 @@ impl DescribeHostedConnectionsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DirectConnectClientException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DescribeHostedConnectionsError::DirectConnectClient(err.msg),
+                        &res,
                     )
                 }
                 "DirectConnectServerException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DescribeHostedConnectionsError::DirectConnectServer(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(DescribeHostedConnectionsError::Unhandled, &res)
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -3817,17 +4227,21 @@ impl fmt::Display for DescribeHostedConnectionsError {
             DescribeHostedConnectionsError::DirectConnectServer(ref cause) => {
                 write!(f, "{}", cause)
             }
+            DescribeHostedConnectionsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeHostedConnectionsError {}
 /// Errors returned by DescribeInterconnectLoa
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeInterconnectLoaError {
     /// <p>One or more parameters are not valid.</p>
     DirectConnectClient(String),
     /// <p>A server-side error occurred.</p>
     DirectConnectServer(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeInterconnectLoaError {
@@ -3835,17 +4249,19 @@ impl DescribeInterconnectLoaError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DirectConnectClientException" => {
-                    return RusotoError::Service(DescribeInterconnectLoaError::DirectConnectClient(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeInterconnectLoaError::DirectConnectClient(err.msg),
+                        &res,
+                    )
                 }
                 "DirectConnectServerException" => {
-                    return RusotoError::Service(DescribeInterconnectLoaError::DirectConnectServer(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeInterconnectLoaError::DirectConnectServer(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DescribeInterconnectLoaError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3857,17 +4273,21 @@ impl fmt::Display for DescribeInterconnectLoaError {
         match *self {
             DescribeInterconnectLoaError::DirectConnectClient(ref cause) => write!(f, "{}", cause),
             DescribeInterconnectLoaError::DirectConnectServer(ref cause) => write!(f, "{}", cause),
+            DescribeInterconnectLoaError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeInterconnectLoaError {}
 /// Errors returned by DescribeInterconnects
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeInterconnectsError {
     /// <p>One or more parameters are not valid.</p>
     DirectConnectClient(String),
     /// <p>A server-side error occurred.</p>
     DirectConnectServer(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeInterconnectsError {
@@ -3875,17 +4295,19 @@ impl DescribeInterconnectsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DirectConnectClientException" => {
-                    return RusotoError::Service(DescribeInterconnectsError::DirectConnectClient(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeInterconnectsError::DirectConnectClient(err.msg),
+                        &res,
+                    )
                 }
                 "DirectConnectServerException" => {
-                    return RusotoError::Service(DescribeInterconnectsError::DirectConnectServer(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeInterconnectsError::DirectConnectServer(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DescribeInterconnectsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3897,17 +4319,21 @@ impl fmt::Display for DescribeInterconnectsError {
         match *self {
             DescribeInterconnectsError::DirectConnectClient(ref cause) => write!(f, "{}", cause),
             DescribeInterconnectsError::DirectConnectServer(ref cause) => write!(f, "{}", cause),
+            DescribeInterconnectsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeInterconnectsError {}
 /// Errors returned by DescribeLags
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeLagsError {
     /// <p>One or more parameters are not valid.</p>
     DirectConnectClient(String),
     /// <p>A server-side error occurred.</p>
     DirectConnectServer(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeLagsError {
@@ -3915,13 +4341,19 @@ impl DescribeLagsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DirectConnectClientException" => {
-                    return RusotoError::Service(DescribeLagsError::DirectConnectClient(err.msg))
+                    return RusotoError::service(
+                        DescribeLagsError::DirectConnectClient(err.msg),
+                        &res,
+                    )
                 }
                 "DirectConnectServerException" => {
-                    return RusotoError::Service(DescribeLagsError::DirectConnectServer(err.msg))
+                    return RusotoError::service(
+                        DescribeLagsError::DirectConnectServer(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DescribeLagsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3933,17 +4365,21 @@ impl fmt::Display for DescribeLagsError {
         match *self {
             DescribeLagsError::DirectConnectClient(ref cause) => write!(f, "{}", cause),
             DescribeLagsError::DirectConnectServer(ref cause) => write!(f, "{}", cause),
+            DescribeLagsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeLagsError {}
 /// Errors returned by DescribeLoa
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeLoaError {
     /// <p>One or more parameters are not valid.</p>
     DirectConnectClient(String),
     /// <p>A server-side error occurred.</p>
     DirectConnectServer(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeLoaError {
@@ -3951,13 +4387,19 @@ impl DescribeLoaError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DirectConnectClientException" => {
-                    return RusotoError::Service(DescribeLoaError::DirectConnectClient(err.msg))
+                    return RusotoError::service(
+                        DescribeLoaError::DirectConnectClient(err.msg),
+                        &res,
+                    )
                 }
                 "DirectConnectServerException" => {
-                    return RusotoError::Service(DescribeLoaError::DirectConnectServer(err.msg))
+                    return RusotoError::service(
+                        DescribeLoaError::DirectConnectServer(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DescribeLoaError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3969,17 +4411,21 @@ impl fmt::Display for DescribeLoaError {
         match *self {
             DescribeLoaError::DirectConnectClient(ref cause) => write!(f, "{}", cause),
             DescribeLoaError::DirectConnectServer(ref cause) => write!(f, "{}", cause),
+            DescribeLoaError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeLoaError {}
 /// Errors returned by DescribeLocations
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeLocationsError {
     /// <p>One or more parameters are not valid.</p>
     DirectConnectClient(String),
     /// <p>A server-side error occurred.</p>
     DirectConnectServer(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeLocationsError {
@@ -3987,17 +4433,19 @@ impl DescribeLocationsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DirectConnectClientException" => {
-                    return RusotoError::Service(DescribeLocationsError::DirectConnectClient(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeLocationsError::DirectConnectClient(err.msg),
+                        &res,
+                    )
                 }
                 "DirectConnectServerException" => {
-                    return RusotoError::Service(DescribeLocationsError::DirectConnectServer(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeLocationsError::DirectConnectServer(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DescribeLocationsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -4009,17 +4457,21 @@ impl fmt::Display for DescribeLocationsError {
         match *self {
             DescribeLocationsError::DirectConnectClient(ref cause) => write!(f, "{}", cause),
             DescribeLocationsError::DirectConnectServer(ref cause) => write!(f, "{}", cause),
+            DescribeLocationsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeLocationsError {}
 /// Errors returned by DescribeTags
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeTagsError {
     /// <p>One or more parameters are not valid.</p>
     DirectConnectClient(String),
     /// <p>A server-side error occurred.</p>
     DirectConnectServer(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeTagsError {
@@ -4027,13 +4479,19 @@ impl DescribeTagsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DirectConnectClientException" => {
-                    return RusotoError::Service(DescribeTagsError::DirectConnectClient(err.msg))
+                    return RusotoError::service(
+                        DescribeTagsError::DirectConnectClient(err.msg),
+                        &res,
+                    )
                 }
                 "DirectConnectServerException" => {
-                    return RusotoError::Service(DescribeTagsError::DirectConnectServer(err.msg))
+                    return RusotoError::service(
+                        DescribeTagsError::DirectConnectServer(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DescribeTagsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -4045,17 +4503,21 @@ impl fmt::Display for DescribeTagsError {
         match *self {
             DescribeTagsError::DirectConnectClient(ref cause) => write!(f, "{}", cause),
             DescribeTagsError::DirectConnectServer(ref cause) => write!(f, "{}", cause),
+            DescribeTagsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeTagsError {}
 /// Errors returned by DescribeVirtualGateways
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeVirtualGatewaysError {
     /// <p>One or more parameters are not valid.</p>
     DirectConnectClient(String),
     /// <p>A server-side error occurred.</p>
     DirectConnectServer(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeVirtualGatewaysError {
@@ -4063,17 +4525,19 @@ impl DescribeVirtualGatewaysError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DirectConnectClientException" => {
-                    return RusotoError::Service(DescribeVirtualGatewaysError::DirectConnectClient(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeVirtualGatewaysError::DirectConnectClient(err.msg),
+                        &res,
+                    )
                 }
                 "DirectConnectServerException" => {
-                    return RusotoError::Service(DescribeVirtualGatewaysError::DirectConnectServer(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeVirtualGatewaysError::DirectConnectServer(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DescribeVirtualGatewaysError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -4085,17 +4549,21 @@ impl fmt::Display for DescribeVirtualGatewaysError {
         match *self {
             DescribeVirtualGatewaysError::DirectConnectClient(ref cause) => write!(f, "{}", cause),
             DescribeVirtualGatewaysError::DirectConnectServer(ref cause) => write!(f, "{}", cause),
+            DescribeVirtualGatewaysError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeVirtualGatewaysError {}
 /// Errors returned by DescribeVirtualInterfaces
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeVirtualInterfacesError {
     /// <p>One or more parameters are not valid.</p>
     DirectConnectClient(String),
     /// <p>A server-side error occurred.</p>
     DirectConnectServer(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeVirtualInterfacesError {
@@ -4103,17 +4571,21 @@ impl DescribeVirtualInterfacesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DirectConnectClientException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DescribeVirtualInterfacesError::DirectConnectClient(err.msg),
+                        &res,
                     )
                 }
                 "DirectConnectServerException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DescribeVirtualInterfacesError::DirectConnectServer(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(DescribeVirtualInterfacesError::Unhandled, &res)
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -4129,17 +4601,21 @@ impl fmt::Display for DescribeVirtualInterfacesError {
             DescribeVirtualInterfacesError::DirectConnectServer(ref cause) => {
                 write!(f, "{}", cause)
             }
+            DescribeVirtualInterfacesError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeVirtualInterfacesError {}
 /// Errors returned by DisassociateConnectionFromLag
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DisassociateConnectionFromLagError {
     /// <p>One or more parameters are not valid.</p>
     DirectConnectClient(String),
     /// <p>A server-side error occurred.</p>
     DirectConnectServer(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DisassociateConnectionFromLagError {
@@ -4149,17 +4625,24 @@ impl DisassociateConnectionFromLagError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DirectConnectClientException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DisassociateConnectionFromLagError::DirectConnectClient(err.msg),
+                        &res,
                     )
                 }
                 "DirectConnectServerException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DisassociateConnectionFromLagError::DirectConnectServer(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        DisassociateConnectionFromLagError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -4175,12 +4658,16 @@ impl fmt::Display for DisassociateConnectionFromLagError {
             DisassociateConnectionFromLagError::DirectConnectServer(ref cause) => {
                 write!(f, "{}", cause)
             }
+            DisassociateConnectionFromLagError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for DisassociateConnectionFromLagError {}
 /// Errors returned by TagResource
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum TagResourceError {
     /// <p>One or more parameters are not valid.</p>
     DirectConnectClient(String),
@@ -4190,6 +4677,8 @@ pub enum TagResourceError {
     DuplicateTagKeys(String),
     /// <p>You have reached the limit on the number of tags that can be assigned.</p>
     TooManyTags(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl TagResourceError {
@@ -4197,19 +4686,25 @@ impl TagResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DirectConnectClientException" => {
-                    return RusotoError::Service(TagResourceError::DirectConnectClient(err.msg))
+                    return RusotoError::service(
+                        TagResourceError::DirectConnectClient(err.msg),
+                        &res,
+                    )
                 }
                 "DirectConnectServerException" => {
-                    return RusotoError::Service(TagResourceError::DirectConnectServer(err.msg))
+                    return RusotoError::service(
+                        TagResourceError::DirectConnectServer(err.msg),
+                        &res,
+                    )
                 }
                 "DuplicateTagKeysException" => {
-                    return RusotoError::Service(TagResourceError::DuplicateTagKeys(err.msg))
+                    return RusotoError::service(TagResourceError::DuplicateTagKeys(err.msg), &res)
                 }
                 "TooManyTagsException" => {
-                    return RusotoError::Service(TagResourceError::TooManyTags(err.msg))
+                    return RusotoError::service(TagResourceError::TooManyTags(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(TagResourceError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -4223,17 +4718,21 @@ impl fmt::Display for TagResourceError {
             TagResourceError::DirectConnectServer(ref cause) => write!(f, "{}", cause),
             TagResourceError::DuplicateTagKeys(ref cause) => write!(f, "{}", cause),
             TagResourceError::TooManyTags(ref cause) => write!(f, "{}", cause),
+            TagResourceError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for TagResourceError {}
 /// Errors returned by UntagResource
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UntagResourceError {
     /// <p>One or more parameters are not valid.</p>
     DirectConnectClient(String),
     /// <p>A server-side error occurred.</p>
     DirectConnectServer(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UntagResourceError {
@@ -4241,13 +4740,19 @@ impl UntagResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DirectConnectClientException" => {
-                    return RusotoError::Service(UntagResourceError::DirectConnectClient(err.msg))
+                    return RusotoError::service(
+                        UntagResourceError::DirectConnectClient(err.msg),
+                        &res,
+                    )
                 }
                 "DirectConnectServerException" => {
-                    return RusotoError::Service(UntagResourceError::DirectConnectServer(err.msg))
+                    return RusotoError::service(
+                        UntagResourceError::DirectConnectServer(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UntagResourceError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -4259,17 +4764,21 @@ impl fmt::Display for UntagResourceError {
         match *self {
             UntagResourceError::DirectConnectClient(ref cause) => write!(f, "{}", cause),
             UntagResourceError::DirectConnectServer(ref cause) => write!(f, "{}", cause),
+            UntagResourceError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for UntagResourceError {}
 /// Errors returned by UpdateDirectConnectGatewayAssociation
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdateDirectConnectGatewayAssociationError {
     /// <p>One or more parameters are not valid.</p>
     DirectConnectClient(String),
     /// <p>A server-side error occurred.</p>
     DirectConnectServer(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdateDirectConnectGatewayAssociationError {
@@ -4279,17 +4788,24 @@ impl UpdateDirectConnectGatewayAssociationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DirectConnectClientException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         UpdateDirectConnectGatewayAssociationError::DirectConnectClient(err.msg),
+                        &res,
                     )
                 }
                 "DirectConnectServerException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         UpdateDirectConnectGatewayAssociationError::DirectConnectServer(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        UpdateDirectConnectGatewayAssociationError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -4305,17 +4821,23 @@ impl fmt::Display for UpdateDirectConnectGatewayAssociationError {
             UpdateDirectConnectGatewayAssociationError::DirectConnectServer(ref cause) => {
                 write!(f, "{}", cause)
             }
+            UpdateDirectConnectGatewayAssociationError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for UpdateDirectConnectGatewayAssociationError {}
 /// Errors returned by UpdateLag
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdateLagError {
     /// <p>One or more parameters are not valid.</p>
     DirectConnectClient(String),
     /// <p>A server-side error occurred.</p>
     DirectConnectServer(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdateLagError {
@@ -4323,13 +4845,13 @@ impl UpdateLagError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DirectConnectClientException" => {
-                    return RusotoError::Service(UpdateLagError::DirectConnectClient(err.msg))
+                    return RusotoError::service(UpdateLagError::DirectConnectClient(err.msg), &res)
                 }
                 "DirectConnectServerException" => {
-                    return RusotoError::Service(UpdateLagError::DirectConnectServer(err.msg))
+                    return RusotoError::service(UpdateLagError::DirectConnectServer(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UpdateLagError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -4341,17 +4863,21 @@ impl fmt::Display for UpdateLagError {
         match *self {
             UpdateLagError::DirectConnectClient(ref cause) => write!(f, "{}", cause),
             UpdateLagError::DirectConnectServer(ref cause) => write!(f, "{}", cause),
+            UpdateLagError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for UpdateLagError {}
 /// Errors returned by UpdateVirtualInterfaceAttributes
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdateVirtualInterfaceAttributesError {
     /// <p>One or more parameters are not valid.</p>
     DirectConnectClient(String),
     /// <p>A server-side error occurred.</p>
     DirectConnectServer(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdateVirtualInterfaceAttributesError {
@@ -4361,17 +4887,24 @@ impl UpdateVirtualInterfaceAttributesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DirectConnectClientException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         UpdateVirtualInterfaceAttributesError::DirectConnectClient(err.msg),
+                        &res,
                     )
                 }
                 "DirectConnectServerException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         UpdateVirtualInterfaceAttributesError::DirectConnectServer(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        UpdateVirtualInterfaceAttributesError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -4387,6 +4920,9 @@ impl fmt::Display for UpdateVirtualInterfaceAttributesError {
             UpdateVirtualInterfaceAttributesError::DirectConnectServer(ref cause) => {
                 write!(f, "{}", cause)
             }
+            UpdateVirtualInterfaceAttributesError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
@@ -4752,6 +5288,7 @@ impl DirectConnectClient {
     /// Creates a client backed by the default tokio event loop.
     ///
     /// The client will use the default credentials provider and tls client.
+    #[cfg(not(target_arch = "wasm32"))]
     pub fn new(region: region::Region) -> DirectConnectClient {
         DirectConnectClient {
             client: Client::shared(),
@@ -4777,19 +5314,76 @@ impl DirectConnectClient {
     pub fn new_with_client(client: Client, region: region::Region) -> DirectConnectClient {
         DirectConnectClient { client, region }
     }
+
+    /// Returns a builder of a client, setting any of its region, credentials provider,
+    /// dispatcher, retry policy, endpoint and timeouts.
+    pub fn builder() -> rusoto_core::ClientBuilder<DirectConnectClient> {
+        rusoto_core::ClientBuilder::new(DirectConnectClient::new_with_client)
+    }
+
+    /// Returns a clone of this client with the overrides of `config` applied to its
+    /// operations, sharing its connections.
+    pub fn customize(&self, config: &rusoto_core::OperationConfig) -> DirectConnectClient {
+        DirectConnectClient {
+            client: self.client.customize(config),
+            region: config.resolve_region(&self.region),
+        }
+    }
 }
 
-#[async_trait]
-impl DirectConnect for DirectConnectClient {
-    /// <p>Accepts a proposal request to attach a virtual private gateway or transit gateway to a Direct Connect gateway.</p>
-    async fn accept_direct_connect_gateway_association_proposal(
+impl DirectConnectClient {
+    /// Calls `accept_direct_connect_gateway_association_proposal`, also returning the metadata of the response.
+    pub async fn accept_direct_connect_gateway_association_proposal_with_metadata(
         &self,
         input: AcceptDirectConnectGatewayAssociationProposalRequest,
     ) -> Result<
-        AcceptDirectConnectGatewayAssociationProposalResult,
+        (
+            AcceptDirectConnectGatewayAssociationProposalResult,
+            ResponseMetadata,
+        ),
         RusotoError<AcceptDirectConnectGatewayAssociationProposalError>,
     > {
+        let request = self.build_accept_direct_connect_gateway_association_proposal_request(input);
+
+        let mut response = self
+            .client
+            .sign_and_dispatch(request)
+            .await
+            .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
+        if response.status.is_success() {
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<AcceptDirectConnectGatewayAssociationProposalResult, _>()?;
+            Ok((result, metadata))
+        } else {
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(AcceptDirectConnectGatewayAssociationProposalError::from_response(response))
+        }
+    }
+
+    /// Presigns the request `accept_direct_connect_gateway_association_proposal` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_accept_direct_connect_gateway_association_proposal(
+        &self,
+        presigner: &rusoto_core::Presigner,
+        input: AcceptDirectConnectGatewayAssociationProposalRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_accept_direct_connect_gateway_association_proposal_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_accept_direct_connect_gateway_association_proposal_request(
+        &self,
+        input: AcceptDirectConnectGatewayAssociationProposalRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "directconnect", &self.region, "/");
+        request.set_operation("AcceptDirectConnectGatewayAssociationProposal");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -4799,28 +5393,58 @@ impl DirectConnect for DirectConnectClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `allocate_connection_on_interconnect`, also returning the metadata of the response.
+    pub async fn allocate_connection_on_interconnect_with_metadata(
+        &self,
+        input: AllocateConnectionOnInterconnectRequest,
+    ) -> Result<(Connection, ResponseMetadata), RusotoError<AllocateConnectionOnInterconnectError>>
+    {
+        let request = self.build_allocate_connection_on_interconnect_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<AcceptDirectConnectGatewayAssociationProposalResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result =
+                proto::json::ResponsePayload::new(&response).deserialize::<Connection, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(AcceptDirectConnectGatewayAssociationProposalError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(AllocateConnectionOnInterconnectError::from_response(
+                response,
+            ))
         }
     }
 
-    /// <p><p>Deprecated. Use <a>AllocateHostedConnection</a> instead.</p> <p>Creates a hosted connection on an interconnect.</p> <p>Allocates a VLAN number and a specified amount of bandwidth for use by a hosted connection on the specified interconnect.</p> <note> <p>Intended for use by AWS Direct Connect Partners only.</p> </note></p>
-    async fn allocate_connection_on_interconnect(
+    /// Presigns the request `allocate_connection_on_interconnect` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_allocate_connection_on_interconnect(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: AllocateConnectionOnInterconnectRequest,
-    ) -> Result<Connection, RusotoError<AllocateConnectionOnInterconnectError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_allocate_connection_on_interconnect_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_allocate_connection_on_interconnect_request(
+        &self,
+        input: AllocateConnectionOnInterconnectRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "directconnect", &self.region, "/");
+        request.set_operation("AllocateConnectionOnInterconnect");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -4830,56 +5454,115 @@ impl DirectConnect for DirectConnectClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `allocate_hosted_connection`, also returning the metadata of the response.
+    pub async fn allocate_hosted_connection_with_metadata(
+        &self,
+        input: AllocateHostedConnectionRequest,
+    ) -> Result<(Connection, ResponseMetadata), RusotoError<AllocateHostedConnectionError>> {
+        let request = self.build_allocate_hosted_connection_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<Connection, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result =
+                proto::json::ResponsePayload::new(&response).deserialize::<Connection, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(AllocateConnectionOnInterconnectError::from_response(
-                response,
-            ))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(AllocateHostedConnectionError::from_response(response))
         }
     }
 
-    /// <p><p>Creates a hosted connection on the specified interconnect or a link aggregation group (LAG) of interconnects.</p> <p>Allocates a VLAN number and a specified amount of capacity (bandwidth) for use by a hosted connection on the specified interconnect or LAG of interconnects. AWS polices the hosted connection for the specified capacity and the AWS Direct Connect Partner must also police the hosted connection for the specified capacity.</p> <note> <p>Intended for use by AWS Direct Connect Partners only.</p> </note></p>
-    async fn allocate_hosted_connection(
+    /// Presigns the request `allocate_hosted_connection` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_allocate_hosted_connection(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: AllocateHostedConnectionRequest,
-    ) -> Result<Connection, RusotoError<AllocateHostedConnectionError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_allocate_hosted_connection_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_allocate_hosted_connection_request(
+        &self,
+        input: AllocateHostedConnectionRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "directconnect", &self.region, "/");
+        request.set_operation("AllocateHostedConnection");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "OvertureService.AllocateHostedConnection");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `allocate_private_virtual_interface`, also returning the metadata of the response.
+    pub async fn allocate_private_virtual_interface_with_metadata(
+        &self,
+        input: AllocatePrivateVirtualInterfaceRequest,
+    ) -> Result<
+        (VirtualInterface, ResponseMetadata),
+        RusotoError<AllocatePrivateVirtualInterfaceError>,
+    > {
+        let request = self.build_allocate_private_virtual_interface_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<Connection, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<VirtualInterface, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(AllocateHostedConnectionError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(AllocatePrivateVirtualInterfaceError::from_response(
+                response,
+            ))
         }
     }
 
-    /// <p>Provisions a private virtual interface to be owned by the specified AWS account.</p> <p>Virtual interfaces created using this action must be confirmed by the owner using <a>ConfirmPrivateVirtualInterface</a>. Until then, the virtual interface is in the <code>Confirming</code> state and is not available to handle traffic.</p>
-    async fn allocate_private_virtual_interface(
+    /// Presigns the request `allocate_private_virtual_interface` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_allocate_private_virtual_interface(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: AllocatePrivateVirtualInterfaceRequest,
-    ) -> Result<VirtualInterface, RusotoError<AllocatePrivateVirtualInterfaceError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_allocate_private_virtual_interface_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_allocate_private_virtual_interface_request(
+        &self,
+        input: AllocatePrivateVirtualInterfaceRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "directconnect", &self.region, "/");
+        request.set_operation("AllocatePrivateVirtualInterface");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -4889,29 +5572,58 @@ impl DirectConnect for DirectConnectClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `allocate_public_virtual_interface`, also returning the metadata of the response.
+    pub async fn allocate_public_virtual_interface_with_metadata(
+        &self,
+        input: AllocatePublicVirtualInterfaceRequest,
+    ) -> Result<
+        (VirtualInterface, ResponseMetadata),
+        RusotoError<AllocatePublicVirtualInterfaceError>,
+    > {
+        let request = self.build_allocate_public_virtual_interface_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<VirtualInterface, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<VirtualInterface, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(AllocatePrivateVirtualInterfaceError::from_response(
-                response,
-            ))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(AllocatePublicVirtualInterfaceError::from_response(response))
         }
     }
 
-    /// <p>Provisions a public virtual interface to be owned by the specified AWS account.</p> <p>The owner of a connection calls this function to provision a public virtual interface to be owned by the specified AWS account.</p> <p>Virtual interfaces created using this function must be confirmed by the owner using <a>ConfirmPublicVirtualInterface</a>. Until this step has been completed, the virtual interface is in the <code>confirming</code> state and is not available to handle traffic.</p> <p>When creating an IPv6 public virtual interface, omit the Amazon address and customer address. IPv6 addresses are automatically assigned from the Amazon pool of IPv6 addresses; you cannot specify custom IPv6 addresses.</p>
-    async fn allocate_public_virtual_interface(
+    /// Presigns the request `allocate_public_virtual_interface` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_allocate_public_virtual_interface(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: AllocatePublicVirtualInterfaceRequest,
-    ) -> Result<VirtualInterface, RusotoError<AllocatePublicVirtualInterfaceError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_allocate_public_virtual_interface_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_allocate_public_virtual_interface_request(
+        &self,
+        input: AllocatePublicVirtualInterfaceRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "directconnect", &self.region, "/");
+        request.set_operation("AllocatePublicVirtualInterface");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -4921,30 +5633,60 @@ impl DirectConnect for DirectConnectClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `allocate_transit_virtual_interface`, also returning the metadata of the response.
+    pub async fn allocate_transit_virtual_interface_with_metadata(
+        &self,
+        input: AllocateTransitVirtualInterfaceRequest,
+    ) -> Result<
+        (AllocateTransitVirtualInterfaceResult, ResponseMetadata),
+        RusotoError<AllocateTransitVirtualInterfaceError>,
+    > {
+        let request = self.build_allocate_transit_virtual_interface_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<VirtualInterface, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<AllocateTransitVirtualInterfaceResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(AllocatePublicVirtualInterfaceError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(AllocateTransitVirtualInterfaceError::from_response(
+                response,
+            ))
         }
     }
 
-    /// <p>Provisions a transit virtual interface to be owned by the specified AWS account. Use this type of interface to connect a transit gateway to your Direct Connect gateway.</p> <p>The owner of a connection provisions a transit virtual interface to be owned by the specified AWS account.</p> <p>After you create a transit virtual interface, it must be confirmed by the owner using <a>ConfirmTransitVirtualInterface</a>. Until this step has been completed, the transit virtual interface is in the <code>requested</code> state and is not available to handle traffic.</p>
-    async fn allocate_transit_virtual_interface(
+    /// Presigns the request `allocate_transit_virtual_interface` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_allocate_transit_virtual_interface(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: AllocateTransitVirtualInterfaceRequest,
-    ) -> Result<
-        AllocateTransitVirtualInterfaceResult,
-        RusotoError<AllocateTransitVirtualInterfaceError>,
-    > {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_allocate_transit_virtual_interface_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_allocate_transit_virtual_interface_request(
+        &self,
+        input: AllocateTransitVirtualInterfaceRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "directconnect", &self.region, "/");
+        request.set_operation("AllocateTransitVirtualInterface");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -4954,142 +5696,277 @@ impl DirectConnect for DirectConnectClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `associate_connection_with_lag`, also returning the metadata of the response.
+    pub async fn associate_connection_with_lag_with_metadata(
+        &self,
+        input: AssociateConnectionWithLagRequest,
+    ) -> Result<(Connection, ResponseMetadata), RusotoError<AssociateConnectionWithLagError>> {
+        let request = self.build_associate_connection_with_lag_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<AllocateTransitVirtualInterfaceResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result =
+                proto::json::ResponsePayload::new(&response).deserialize::<Connection, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(AllocateTransitVirtualInterfaceError::from_response(
-                response,
-            ))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(AssociateConnectionWithLagError::from_response(response))
         }
     }
 
-    /// <p>Associates an existing connection with a link aggregation group (LAG). The connection is interrupted and re-established as a member of the LAG (connectivity to AWS is interrupted). The connection must be hosted on the same AWS Direct Connect endpoint as the LAG, and its bandwidth must match the bandwidth for the LAG. You can re-associate a connection that's currently associated with a different LAG; however, if removing the connection would cause the original LAG to fall below its setting for minimum number of operational connections, the request fails.</p> <p>Any virtual interfaces that are directly associated with the connection are automatically re-associated with the LAG. If the connection was originally associated with a different LAG, the virtual interfaces remain associated with the original LAG.</p> <p>For interconnects, any hosted connections are automatically re-associated with the LAG. If the interconnect was originally associated with a different LAG, the hosted connections remain associated with the original LAG.</p>
-    async fn associate_connection_with_lag(
+    /// Presigns the request `associate_connection_with_lag` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_associate_connection_with_lag(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: AssociateConnectionWithLagRequest,
-    ) -> Result<Connection, RusotoError<AssociateConnectionWithLagError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_associate_connection_with_lag_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_associate_connection_with_lag_request(
+        &self,
+        input: AssociateConnectionWithLagRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "directconnect", &self.region, "/");
+        request.set_operation("AssociateConnectionWithLag");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "OvertureService.AssociateConnectionWithLag");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `associate_hosted_connection`, also returning the metadata of the response.
+    pub async fn associate_hosted_connection_with_metadata(
+        &self,
+        input: AssociateHostedConnectionRequest,
+    ) -> Result<(Connection, ResponseMetadata), RusotoError<AssociateHostedConnectionError>> {
+        let request = self.build_associate_hosted_connection_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<Connection, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result =
+                proto::json::ResponsePayload::new(&response).deserialize::<Connection, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(AssociateConnectionWithLagError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(AssociateHostedConnectionError::from_response(response))
         }
     }
 
-    /// <p><p>Associates a hosted connection and its virtual interfaces with a link aggregation group (LAG) or interconnect. If the target interconnect or LAG has an existing hosted connection with a conflicting VLAN number or IP address, the operation fails. This action temporarily interrupts the hosted connection&#39;s connectivity to AWS as it is being migrated.</p> <note> <p>Intended for use by AWS Direct Connect Partners only.</p> </note></p>
-    async fn associate_hosted_connection(
+    /// Presigns the request `associate_hosted_connection` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_associate_hosted_connection(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: AssociateHostedConnectionRequest,
-    ) -> Result<Connection, RusotoError<AssociateHostedConnectionError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_associate_hosted_connection_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_associate_hosted_connection_request(
+        &self,
+        input: AssociateHostedConnectionRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "directconnect", &self.region, "/");
+        request.set_operation("AssociateHostedConnection");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "OvertureService.AssociateHostedConnection");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `associate_virtual_interface`, also returning the metadata of the response.
+    pub async fn associate_virtual_interface_with_metadata(
+        &self,
+        input: AssociateVirtualInterfaceRequest,
+    ) -> Result<(VirtualInterface, ResponseMetadata), RusotoError<AssociateVirtualInterfaceError>>
+    {
+        let request = self.build_associate_virtual_interface_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<Connection, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<VirtualInterface, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(AssociateHostedConnectionError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(AssociateVirtualInterfaceError::from_response(response))
         }
     }
 
-    /// <p>Associates a virtual interface with a specified link aggregation group (LAG) or connection. Connectivity to AWS is temporarily interrupted as the virtual interface is being migrated. If the target connection or LAG has an associated virtual interface with a conflicting VLAN number or a conflicting IP address, the operation fails.</p> <p>Virtual interfaces associated with a hosted connection cannot be associated with a LAG; hosted connections must be migrated along with their virtual interfaces using <a>AssociateHostedConnection</a>.</p> <p>To reassociate a virtual interface to a new connection or LAG, the requester must own either the virtual interface itself or the connection to which the virtual interface is currently associated. Additionally, the requester must own the connection or LAG for the association.</p>
-    async fn associate_virtual_interface(
+    /// Presigns the request `associate_virtual_interface` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_associate_virtual_interface(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: AssociateVirtualInterfaceRequest,
-    ) -> Result<VirtualInterface, RusotoError<AssociateVirtualInterfaceError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_associate_virtual_interface_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_associate_virtual_interface_request(
+        &self,
+        input: AssociateVirtualInterfaceRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "directconnect", &self.region, "/");
+        request.set_operation("AssociateVirtualInterface");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "OvertureService.AssociateVirtualInterface");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `confirm_connection`, also returning the metadata of the response.
+    pub async fn confirm_connection_with_metadata(
+        &self,
+        input: ConfirmConnectionRequest,
+    ) -> Result<(ConfirmConnectionResponse, ResponseMetadata), RusotoError<ConfirmConnectionError>>
+    {
+        let request = self.build_confirm_connection_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<VirtualInterface, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ConfirmConnectionResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(AssociateVirtualInterfaceError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ConfirmConnectionError::from_response(response))
         }
     }
 
-    /// <p>Confirms the creation of the specified hosted connection on an interconnect.</p> <p>Upon creation, the hosted connection is initially in the <code>Ordering</code> state, and remains in this state until the owner confirms creation of the hosted connection.</p>
-    async fn confirm_connection(
+    /// Presigns the request `confirm_connection` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_confirm_connection(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ConfirmConnectionRequest,
-    ) -> Result<ConfirmConnectionResponse, RusotoError<ConfirmConnectionError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_confirm_connection_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_confirm_connection_request(&self, input: ConfirmConnectionRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "directconnect", &self.region, "/");
+        request.set_operation("ConfirmConnection");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "OvertureService.ConfirmConnection");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `confirm_private_virtual_interface`, also returning the metadata of the response.
+    pub async fn confirm_private_virtual_interface_with_metadata(
+        &self,
+        input: ConfirmPrivateVirtualInterfaceRequest,
+    ) -> Result<
+        (ConfirmPrivateVirtualInterfaceResponse, ResponseMetadata),
+        RusotoError<ConfirmPrivateVirtualInterfaceError>,
+    > {
+        let request = self.build_confirm_private_virtual_interface_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<ConfirmConnectionResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ConfirmPrivateVirtualInterfaceResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ConfirmConnectionError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ConfirmPrivateVirtualInterfaceError::from_response(response))
         }
     }
 
-    /// <p>Accepts ownership of a private virtual interface created by another AWS account.</p> <p>After the virtual interface owner makes this call, the virtual interface is created and attached to the specified virtual private gateway or Direct Connect gateway, and is made available to handle traffic.</p>
-    async fn confirm_private_virtual_interface(
+    /// Presigns the request `confirm_private_virtual_interface` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_confirm_private_virtual_interface(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ConfirmPrivateVirtualInterfaceRequest,
-    ) -> Result<
-        ConfirmPrivateVirtualInterfaceResponse,
-        RusotoError<ConfirmPrivateVirtualInterfaceError>,
-    > {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_confirm_private_virtual_interface_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_confirm_private_virtual_interface_request(
+        &self,
+        input: ConfirmPrivateVirtualInterfaceRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "directconnect", &self.region, "/");
+        request.set_operation("ConfirmPrivateVirtualInterface");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -5099,31 +5976,58 @@ impl DirectConnect for DirectConnectClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `confirm_public_virtual_interface`, also returning the metadata of the response.
+    pub async fn confirm_public_virtual_interface_with_metadata(
+        &self,
+        input: ConfirmPublicVirtualInterfaceRequest,
+    ) -> Result<
+        (ConfirmPublicVirtualInterfaceResponse, ResponseMetadata),
+        RusotoError<ConfirmPublicVirtualInterfaceError>,
+    > {
+        let request = self.build_confirm_public_virtual_interface_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<ConfirmPrivateVirtualInterfaceResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ConfirmPublicVirtualInterfaceResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ConfirmPrivateVirtualInterfaceError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ConfirmPublicVirtualInterfaceError::from_response(response))
         }
     }
 
-    /// <p>Accepts ownership of a public virtual interface created by another AWS account.</p> <p>After the virtual interface owner makes this call, the specified virtual interface is created and made available to handle traffic.</p>
-    async fn confirm_public_virtual_interface(
+    /// Presigns the request `confirm_public_virtual_interface` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_confirm_public_virtual_interface(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ConfirmPublicVirtualInterfaceRequest,
-    ) -> Result<
-        ConfirmPublicVirtualInterfaceResponse,
-        RusotoError<ConfirmPublicVirtualInterfaceError>,
-    > {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_confirm_public_virtual_interface_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_confirm_public_virtual_interface_request(
+        &self,
+        input: ConfirmPublicVirtualInterfaceRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "directconnect", &self.region, "/");
+        request.set_operation("ConfirmPublicVirtualInterface");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -5133,31 +6037,58 @@ impl DirectConnect for DirectConnectClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `confirm_transit_virtual_interface`, also returning the metadata of the response.
+    pub async fn confirm_transit_virtual_interface_with_metadata(
+        &self,
+        input: ConfirmTransitVirtualInterfaceRequest,
+    ) -> Result<
+        (ConfirmTransitVirtualInterfaceResponse, ResponseMetadata),
+        RusotoError<ConfirmTransitVirtualInterfaceError>,
+    > {
+        let request = self.build_confirm_transit_virtual_interface_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<ConfirmPublicVirtualInterfaceResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ConfirmTransitVirtualInterfaceResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ConfirmPublicVirtualInterfaceError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ConfirmTransitVirtualInterfaceError::from_response(response))
         }
     }
 
-    /// <p>Accepts ownership of a transit virtual interface created by another AWS account.</p> <p> After the owner of the transit virtual interface makes this call, the specified transit virtual interface is created and made available to handle traffic.</p>
-    async fn confirm_transit_virtual_interface(
+    /// Presigns the request `confirm_transit_virtual_interface` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_confirm_transit_virtual_interface(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ConfirmTransitVirtualInterfaceRequest,
-    ) -> Result<
-        ConfirmTransitVirtualInterfaceResponse,
-        RusotoError<ConfirmTransitVirtualInterfaceError>,
-    > {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_confirm_transit_virtual_interface_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_confirm_transit_virtual_interface_request(
+        &self,
+        input: ConfirmTransitVirtualInterfaceRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "directconnect", &self.region, "/");
+        request.set_operation("ConfirmTransitVirtualInterface");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -5167,114 +6098,225 @@ impl DirectConnect for DirectConnectClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `create_bgp_peer`, also returning the metadata of the response.
+    pub async fn create_bgp_peer_with_metadata(
+        &self,
+        input: CreateBGPPeerRequest,
+    ) -> Result<(CreateBGPPeerResponse, ResponseMetadata), RusotoError<CreateBGPPeerError>> {
+        let request = self.build_create_bgp_peer_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<ConfirmTransitVirtualInterfaceResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<CreateBGPPeerResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ConfirmTransitVirtualInterfaceError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(CreateBGPPeerError::from_response(response))
         }
     }
 
-    /// <p>Creates a BGP peer on the specified virtual interface.</p> <p>You must create a BGP peer for the corresponding address family (IPv4/IPv6) in order to access AWS resources that also use that address family.</p> <p>If logical redundancy is not supported by the connection, interconnect, or LAG, the BGP peer cannot be in the same address family as an existing BGP peer on the virtual interface.</p> <p>When creating a IPv6 BGP peer, omit the Amazon address and customer address. IPv6 addresses are automatically assigned from the Amazon pool of IPv6 addresses; you cannot specify custom IPv6 addresses.</p> <p>For a public virtual interface, the Autonomous System Number (ASN) must be private or already whitelisted for the virtual interface.</p>
-    async fn create_bgp_peer(
+    /// Presigns the request `create_bgp_peer` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_bgp_peer(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: CreateBGPPeerRequest,
-    ) -> Result<CreateBGPPeerResponse, RusotoError<CreateBGPPeerError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_bgp_peer_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_bgp_peer_request(&self, input: CreateBGPPeerRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "directconnect", &self.region, "/");
+        request.set_operation("CreateBGPPeer");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "OvertureService.CreateBGPPeer");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `create_connection`, also returning the metadata of the response.
+    pub async fn create_connection_with_metadata(
+        &self,
+        input: CreateConnectionRequest,
+    ) -> Result<(Connection, ResponseMetadata), RusotoError<CreateConnectionError>> {
+        let request = self.build_create_connection_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<CreateBGPPeerResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result =
+                proto::json::ResponsePayload::new(&response).deserialize::<Connection, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(CreateBGPPeerError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(CreateConnectionError::from_response(response))
         }
     }
 
-    /// <p>Creates a connection between a customer network and a specific AWS Direct Connect location.</p> <p>A connection links your internal network to an AWS Direct Connect location over a standard Ethernet fiber-optic cable. One end of the cable is connected to your router, the other to an AWS Direct Connect router.</p> <p>To find the locations for your Region, use <a>DescribeLocations</a>.</p> <p>You can automatically add the new connection to a link aggregation group (LAG) by specifying a LAG ID in the request. This ensures that the new connection is allocated on the same AWS Direct Connect endpoint that hosts the specified LAG. If there are no available ports on the endpoint, the request fails and no connection is created.</p>
-    async fn create_connection(
+    /// Presigns the request `create_connection` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_connection(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: CreateConnectionRequest,
-    ) -> Result<Connection, RusotoError<CreateConnectionError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_connection_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_connection_request(&self, input: CreateConnectionRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "directconnect", &self.region, "/");
+        request.set_operation("CreateConnection");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "OvertureService.CreateConnection");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `create_direct_connect_gateway`, also returning the metadata of the response.
+    pub async fn create_direct_connect_gateway_with_metadata(
+        &self,
+        input: CreateDirectConnectGatewayRequest,
+    ) -> Result<
+        (CreateDirectConnectGatewayResult, ResponseMetadata),
+        RusotoError<CreateDirectConnectGatewayError>,
+    > {
+        let request = self.build_create_direct_connect_gateway_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<Connection, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<CreateDirectConnectGatewayResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(CreateConnectionError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(CreateDirectConnectGatewayError::from_response(response))
         }
     }
 
-    /// <p>Creates a Direct Connect gateway, which is an intermediate object that enables you to connect a set of virtual interfaces and virtual private gateways. A Direct Connect gateway is global and visible in any AWS Region after it is created. The virtual interfaces and virtual private gateways that are connected through a Direct Connect gateway can be in different AWS Regions. This enables you to connect to a VPC in any Region, regardless of the Region in which the virtual interfaces are located, and pass traffic between them.</p>
-    async fn create_direct_connect_gateway(
+    /// Presigns the request `create_direct_connect_gateway` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_direct_connect_gateway(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: CreateDirectConnectGatewayRequest,
-    ) -> Result<CreateDirectConnectGatewayResult, RusotoError<CreateDirectConnectGatewayError>>
-    {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_direct_connect_gateway_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_direct_connect_gateway_request(
+        &self,
+        input: CreateDirectConnectGatewayRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "directconnect", &self.region, "/");
+        request.set_operation("CreateDirectConnectGateway");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "OvertureService.CreateDirectConnectGateway");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `create_direct_connect_gateway_association`, also returning the metadata of the response.
+    pub async fn create_direct_connect_gateway_association_with_metadata(
+        &self,
+        input: CreateDirectConnectGatewayAssociationRequest,
+    ) -> Result<
+        (
+            CreateDirectConnectGatewayAssociationResult,
+            ResponseMetadata,
+        ),
+        RusotoError<CreateDirectConnectGatewayAssociationError>,
+    > {
+        let request = self.build_create_direct_connect_gateway_association_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<CreateDirectConnectGatewayResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<CreateDirectConnectGatewayAssociationResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(CreateDirectConnectGatewayError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(CreateDirectConnectGatewayAssociationError::from_response(
+                response,
+            ))
         }
     }
 
-    /// <p>Creates an association between a Direct Connect gateway and a virtual private gateway. The virtual private gateway must be attached to a VPC and must not be associated with another Direct Connect gateway.</p>
-    async fn create_direct_connect_gateway_association(
+    /// Presigns the request `create_direct_connect_gateway_association` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_direct_connect_gateway_association(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: CreateDirectConnectGatewayAssociationRequest,
-    ) -> Result<
-        CreateDirectConnectGatewayAssociationResult,
-        RusotoError<CreateDirectConnectGatewayAssociationError>,
-    > {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_direct_connect_gateway_association_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_direct_connect_gateway_association_request(
+        &self,
+        input: CreateDirectConnectGatewayAssociationRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "directconnect", &self.region, "/");
+        request.set_operation("CreateDirectConnectGatewayAssociation");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -5284,33 +6326,61 @@ impl DirectConnect for DirectConnectClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `create_direct_connect_gateway_association_proposal`, also returning the metadata of the response.
+    pub async fn create_direct_connect_gateway_association_proposal_with_metadata(
+        &self,
+        input: CreateDirectConnectGatewayAssociationProposalRequest,
+    ) -> Result<
+        (
+            CreateDirectConnectGatewayAssociationProposalResult,
+            ResponseMetadata,
+        ),
+        RusotoError<CreateDirectConnectGatewayAssociationProposalError>,
+    > {
+        let request = self.build_create_direct_connect_gateway_association_proposal_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<CreateDirectConnectGatewayAssociationResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<CreateDirectConnectGatewayAssociationProposalResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(CreateDirectConnectGatewayAssociationError::from_response(
-                response,
-            ))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(CreateDirectConnectGatewayAssociationProposalError::from_response(response))
         }
     }
 
-    /// <p>Creates a proposal to associate the specified virtual private gateway or transit gateway with the specified Direct Connect gateway.</p> <p>You can only associate a Direct Connect gateway and virtual private gateway or transit gateway when the account that owns the Direct Connect gateway and the account that owns the virtual private gateway or transit gateway have the same AWS Payer ID.</p>
-    async fn create_direct_connect_gateway_association_proposal(
+    /// Presigns the request `create_direct_connect_gateway_association_proposal` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_direct_connect_gateway_association_proposal(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: CreateDirectConnectGatewayAssociationProposalRequest,
-    ) -> Result<
-        CreateDirectConnectGatewayAssociationProposalResult,
-        RusotoError<CreateDirectConnectGatewayAssociationProposalError>,
-    > {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_direct_connect_gateway_association_proposal_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_direct_connect_gateway_association_proposal_request(
+        &self,
+        input: CreateDirectConnectGatewayAssociationProposalRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "directconnect", &self.region, "/");
+        request.set_operation("CreateDirectConnectGatewayAssociationProposal");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -5320,82 +6390,159 @@ impl DirectConnect for DirectConnectClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `create_interconnect`, also returning the metadata of the response.
+    pub async fn create_interconnect_with_metadata(
+        &self,
+        input: CreateInterconnectRequest,
+    ) -> Result<(Interconnect, ResponseMetadata), RusotoError<CreateInterconnectError>> {
+        let request = self.build_create_interconnect_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<CreateDirectConnectGatewayAssociationProposalResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result =
+                proto::json::ResponsePayload::new(&response).deserialize::<Interconnect, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(CreateDirectConnectGatewayAssociationProposalError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(CreateInterconnectError::from_response(response))
         }
     }
 
-    /// <p><p>Creates an interconnect between an AWS Direct Connect Partner&#39;s network and a specific AWS Direct Connect location.</p> <p>An interconnect is a connection that is capable of hosting other connections. The AWS Direct Connect partner can use an interconnect to provide AWS Direct Connect hosted connections to customers through their own network services. Like a standard connection, an interconnect links the partner&#39;s network to an AWS Direct Connect location over a standard Ethernet fiber-optic cable. One end is connected to the partner&#39;s router, the other to an AWS Direct Connect router.</p> <p>You can automatically add the new interconnect to a link aggregation group (LAG) by specifying a LAG ID in the request. This ensures that the new interconnect is allocated on the same AWS Direct Connect endpoint that hosts the specified LAG. If there are no available ports on the endpoint, the request fails and no interconnect is created.</p> <p>For each end customer, the AWS Direct Connect Partner provisions a connection on their interconnect by calling <a>AllocateHostedConnection</a>. The end customer can then connect to AWS resources by creating a virtual interface on their connection, using the VLAN assigned to them by the AWS Direct Connect Partner.</p> <note> <p>Intended for use by AWS Direct Connect Partners only.</p> </note></p>
-    async fn create_interconnect(
+    /// Presigns the request `create_interconnect` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_interconnect(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: CreateInterconnectRequest,
-    ) -> Result<Interconnect, RusotoError<CreateInterconnectError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_interconnect_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_interconnect_request(&self, input: CreateInterconnectRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "directconnect", &self.region, "/");
+        request.set_operation("CreateInterconnect");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "OvertureService.CreateInterconnect");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `create_lag`, also returning the metadata of the response.
+    pub async fn create_lag_with_metadata(
+        &self,
+        input: CreateLagRequest,
+    ) -> Result<(Lag, ResponseMetadata), RusotoError<CreateLagError>> {
+        let request = self.build_create_lag_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<Interconnect, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response).deserialize::<Lag, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(CreateInterconnectError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(CreateLagError::from_response(response))
         }
     }
 
-    /// <p>Creates a link aggregation group (LAG) with the specified number of bundled physical connections between the customer network and a specific AWS Direct Connect location. A LAG is a logical interface that uses the Link Aggregation Control Protocol (LACP) to aggregate multiple interfaces, enabling you to treat them as a single interface.</p> <p>All connections in a LAG must use the same bandwidth and must terminate at the same AWS Direct Connect endpoint.</p> <p>You can have up to 10 connections per LAG. Regardless of this limit, if you request more connections for the LAG than AWS Direct Connect can allocate on a single endpoint, no LAG is created.</p> <p>You can specify an existing physical connection or interconnect to include in the LAG (which counts towards the total number of connections). Doing so interrupts the current physical connection or hosted connections, and re-establishes them as a member of the LAG. The LAG will be created on the same AWS Direct Connect endpoint to which the connection terminates. Any virtual interfaces associated with the connection are automatically disassociated and re-associated with the LAG. The connection ID does not change.</p> <p>If the AWS account used to create a LAG is a registered AWS Direct Connect Partner, the LAG is automatically enabled to host sub-connections. For a LAG owned by a partner, any associated virtual interfaces cannot be directly configured.</p>
-    async fn create_lag(
+    /// Presigns the request `create_lag` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_lag(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: CreateLagRequest,
-    ) -> Result<Lag, RusotoError<CreateLagError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_lag_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_lag_request(&self, input: CreateLagRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "directconnect", &self.region, "/");
+        request.set_operation("CreateLag");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "OvertureService.CreateLag");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `create_private_virtual_interface`, also returning the metadata of the response.
+    pub async fn create_private_virtual_interface_with_metadata(
+        &self,
+        input: CreatePrivateVirtualInterfaceRequest,
+    ) -> Result<(VirtualInterface, ResponseMetadata), RusotoError<CreatePrivateVirtualInterfaceError>>
+    {
+        let request = self.build_create_private_virtual_interface_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<Lag, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<VirtualInterface, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(CreateLagError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(CreatePrivateVirtualInterfaceError::from_response(response))
         }
     }
 
-    /// <p>Creates a private virtual interface. A virtual interface is the VLAN that transports AWS Direct Connect traffic. A private virtual interface can be connected to either a Direct Connect gateway or a Virtual Private Gateway (VGW). Connecting the private virtual interface to a Direct Connect gateway enables the possibility for connecting to multiple VPCs, including VPCs in different AWS Regions. Connecting the private virtual interface to a VGW only provides access to a single VPC within the same Region.</p>
-    async fn create_private_virtual_interface(
+    /// Presigns the request `create_private_virtual_interface` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_private_virtual_interface(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: CreatePrivateVirtualInterfaceRequest,
-    ) -> Result<VirtualInterface, RusotoError<CreatePrivateVirtualInterfaceError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_private_virtual_interface_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_private_virtual_interface_request(
+        &self,
+        input: CreatePrivateVirtualInterfaceRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "directconnect", &self.region, "/");
+        request.set_operation("CreatePrivateVirtualInterface");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -5405,27 +6552,56 @@ impl DirectConnect for DirectConnectClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `create_public_virtual_interface`, also returning the metadata of the response.
+    pub async fn create_public_virtual_interface_with_metadata(
+        &self,
+        input: CreatePublicVirtualInterfaceRequest,
+    ) -> Result<(VirtualInterface, ResponseMetadata), RusotoError<CreatePublicVirtualInterfaceError>>
+    {
+        let request = self.build_create_public_virtual_interface_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<VirtualInterface, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<VirtualInterface, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(CreatePrivateVirtualInterfaceError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(CreatePublicVirtualInterfaceError::from_response(response))
         }
     }
 
-    /// <p>Creates a public virtual interface. A virtual interface is the VLAN that transports AWS Direct Connect traffic. A public virtual interface supports sending traffic to public services of AWS such as Amazon S3.</p> <p>When creating an IPv6 public virtual interface (<code>addressFamily</code> is <code>ipv6</code>), leave the <code>customer</code> and <code>amazon</code> address fields blank to use auto-assigned IPv6 space. Custom IPv6 addresses are not supported.</p>
-    async fn create_public_virtual_interface(
+    /// Presigns the request `create_public_virtual_interface` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_public_virtual_interface(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: CreatePublicVirtualInterfaceRequest,
-    ) -> Result<VirtualInterface, RusotoError<CreatePublicVirtualInterfaceError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_public_virtual_interface_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_public_virtual_interface_request(
+        &self,
+        input: CreatePublicVirtualInterfaceRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "directconnect", &self.region, "/");
+        request.set_operation("CreatePublicVirtualInterface");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -5435,28 +6611,58 @@ impl DirectConnect for DirectConnectClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `create_transit_virtual_interface`, also returning the metadata of the response.
+    pub async fn create_transit_virtual_interface_with_metadata(
+        &self,
+        input: CreateTransitVirtualInterfaceRequest,
+    ) -> Result<
+        (CreateTransitVirtualInterfaceResult, ResponseMetadata),
+        RusotoError<CreateTransitVirtualInterfaceError>,
+    > {
+        let request = self.build_create_transit_virtual_interface_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<VirtualInterface, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<CreateTransitVirtualInterfaceResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(CreatePublicVirtualInterfaceError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(CreateTransitVirtualInterfaceError::from_response(response))
         }
     }
 
-    /// <p><p>Creates a transit virtual interface. A transit virtual interface should be used to access one or more transit gateways associated with Direct Connect gateways. A transit virtual interface enables the connection of multiple VPCs attached to a transit gateway to a Direct Connect gateway.</p> <important> <p>If you associate your transit gateway with one or more Direct Connect gateways, the Autonomous System Number (ASN) used by the transit gateway and the Direct Connect gateway must be different. For example, if you use the default ASN 64512 for both your the transit gateway and Direct Connect gateway, the association request fails.</p> </important></p>
-    async fn create_transit_virtual_interface(
+    /// Presigns the request `create_transit_virtual_interface` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_transit_virtual_interface(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: CreateTransitVirtualInterfaceRequest,
-    ) -> Result<CreateTransitVirtualInterfaceResult, RusotoError<CreateTransitVirtualInterfaceError>>
-    {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_transit_virtual_interface_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_transit_virtual_interface_request(
+        &self,
+        input: CreateTransitVirtualInterfaceRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "directconnect", &self.region, "/");
+        request.set_operation("CreateTransitVirtualInterface");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -5466,114 +6672,225 @@ impl DirectConnect for DirectConnectClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `delete_bgp_peer`, also returning the metadata of the response.
+    pub async fn delete_bgp_peer_with_metadata(
+        &self,
+        input: DeleteBGPPeerRequest,
+    ) -> Result<(DeleteBGPPeerResponse, ResponseMetadata), RusotoError<DeleteBGPPeerError>> {
+        let request = self.build_delete_bgp_peer_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<CreateTransitVirtualInterfaceResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DeleteBGPPeerResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(CreateTransitVirtualInterfaceError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DeleteBGPPeerError::from_response(response))
         }
     }
 
-    /// <p>Deletes the specified BGP peer on the specified virtual interface with the specified customer address and ASN.</p> <p>You cannot delete the last BGP peer from a virtual interface.</p>
-    async fn delete_bgp_peer(
+    /// Presigns the request `delete_bgp_peer` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_bgp_peer(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteBGPPeerRequest,
-    ) -> Result<DeleteBGPPeerResponse, RusotoError<DeleteBGPPeerError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_bgp_peer_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_bgp_peer_request(&self, input: DeleteBGPPeerRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "directconnect", &self.region, "/");
+        request.set_operation("DeleteBGPPeer");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "OvertureService.DeleteBGPPeer");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `delete_connection`, also returning the metadata of the response.
+    pub async fn delete_connection_with_metadata(
+        &self,
+        input: DeleteConnectionRequest,
+    ) -> Result<(Connection, ResponseMetadata), RusotoError<DeleteConnectionError>> {
+        let request = self.build_delete_connection_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<DeleteBGPPeerResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result =
+                proto::json::ResponsePayload::new(&response).deserialize::<Connection, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteBGPPeerError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DeleteConnectionError::from_response(response))
         }
     }
 
-    /// <p>Deletes the specified connection.</p> <p>Deleting a connection only stops the AWS Direct Connect port hour and data transfer charges. If you are partnering with any third parties to connect with the AWS Direct Connect location, you must cancel your service with them separately.</p>
-    async fn delete_connection(
+    /// Presigns the request `delete_connection` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_connection(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteConnectionRequest,
-    ) -> Result<Connection, RusotoError<DeleteConnectionError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_connection_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_connection_request(&self, input: DeleteConnectionRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "directconnect", &self.region, "/");
+        request.set_operation("DeleteConnection");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "OvertureService.DeleteConnection");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `delete_direct_connect_gateway`, also returning the metadata of the response.
+    pub async fn delete_direct_connect_gateway_with_metadata(
+        &self,
+        input: DeleteDirectConnectGatewayRequest,
+    ) -> Result<
+        (DeleteDirectConnectGatewayResult, ResponseMetadata),
+        RusotoError<DeleteDirectConnectGatewayError>,
+    > {
+        let request = self.build_delete_direct_connect_gateway_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<Connection, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DeleteDirectConnectGatewayResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteConnectionError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DeleteDirectConnectGatewayError::from_response(response))
         }
     }
 
-    /// <p>Deletes the specified Direct Connect gateway. You must first delete all virtual interfaces that are attached to the Direct Connect gateway and disassociate all virtual private gateways associated with the Direct Connect gateway.</p>
-    async fn delete_direct_connect_gateway(
+    /// Presigns the request `delete_direct_connect_gateway` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_direct_connect_gateway(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteDirectConnectGatewayRequest,
-    ) -> Result<DeleteDirectConnectGatewayResult, RusotoError<DeleteDirectConnectGatewayError>>
-    {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_direct_connect_gateway_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_direct_connect_gateway_request(
+        &self,
+        input: DeleteDirectConnectGatewayRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "directconnect", &self.region, "/");
+        request.set_operation("DeleteDirectConnectGateway");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "OvertureService.DeleteDirectConnectGateway");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `delete_direct_connect_gateway_association`, also returning the metadata of the response.
+    pub async fn delete_direct_connect_gateway_association_with_metadata(
+        &self,
+        input: DeleteDirectConnectGatewayAssociationRequest,
+    ) -> Result<
+        (
+            DeleteDirectConnectGatewayAssociationResult,
+            ResponseMetadata,
+        ),
+        RusotoError<DeleteDirectConnectGatewayAssociationError>,
+    > {
+        let request = self.build_delete_direct_connect_gateway_association_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DeleteDirectConnectGatewayResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DeleteDirectConnectGatewayAssociationResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteDirectConnectGatewayError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DeleteDirectConnectGatewayAssociationError::from_response(
+                response,
+            ))
         }
     }
 
-    /// <p>Deletes the association between the specified Direct Connect gateway and virtual private gateway.</p> <p>We recommend that you specify the <code>associationID</code> to delete the association. Alternatively, if you own virtual gateway and a Direct Connect gateway association, you can specify the <code>virtualGatewayId</code> and <code>directConnectGatewayId</code> to delete an association.</p>
-    async fn delete_direct_connect_gateway_association(
+    /// Presigns the request `delete_direct_connect_gateway_association` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_direct_connect_gateway_association(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteDirectConnectGatewayAssociationRequest,
-    ) -> Result<
-        DeleteDirectConnectGatewayAssociationResult,
-        RusotoError<DeleteDirectConnectGatewayAssociationError>,
-    > {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_direct_connect_gateway_association_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_direct_connect_gateway_association_request(
+        &self,
+        input: DeleteDirectConnectGatewayAssociationRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "directconnect", &self.region, "/");
+        request.set_operation("DeleteDirectConnectGatewayAssociation");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -5583,33 +6900,61 @@ impl DirectConnect for DirectConnectClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `delete_direct_connect_gateway_association_proposal`, also returning the metadata of the response.
+    pub async fn delete_direct_connect_gateway_association_proposal_with_metadata(
+        &self,
+        input: DeleteDirectConnectGatewayAssociationProposalRequest,
+    ) -> Result<
+        (
+            DeleteDirectConnectGatewayAssociationProposalResult,
+            ResponseMetadata,
+        ),
+        RusotoError<DeleteDirectConnectGatewayAssociationProposalError>,
+    > {
+        let request = self.build_delete_direct_connect_gateway_association_proposal_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DeleteDirectConnectGatewayAssociationResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DeleteDirectConnectGatewayAssociationProposalResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteDirectConnectGatewayAssociationError::from_response(
-                response,
-            ))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DeleteDirectConnectGatewayAssociationProposalError::from_response(response))
         }
     }
 
-    /// <p>Deletes the association proposal request between the specified Direct Connect gateway and virtual private gateway or transit gateway.</p>
-    async fn delete_direct_connect_gateway_association_proposal(
+    /// Presigns the request `delete_direct_connect_gateway_association_proposal` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_direct_connect_gateway_association_proposal(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteDirectConnectGatewayAssociationProposalRequest,
-    ) -> Result<
-        DeleteDirectConnectGatewayAssociationProposalResult,
-        RusotoError<DeleteDirectConnectGatewayAssociationProposalError>,
-    > {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_direct_connect_gateway_association_proposal_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_direct_connect_gateway_association_proposal_request(
+        &self,
+        input: DeleteDirectConnectGatewayAssociationProposalRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "directconnect", &self.region, "/");
+        request.set_operation("DeleteDirectConnectGatewayAssociationProposal");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -5619,166 +6964,333 @@ impl DirectConnect for DirectConnectClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `delete_interconnect`, also returning the metadata of the response.
+    pub async fn delete_interconnect_with_metadata(
+        &self,
+        input: DeleteInterconnectRequest,
+    ) -> Result<(DeleteInterconnectResponse, ResponseMetadata), RusotoError<DeleteInterconnectError>>
+    {
+        let request = self.build_delete_interconnect_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DeleteDirectConnectGatewayAssociationProposalResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DeleteInterconnectResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteDirectConnectGatewayAssociationProposalError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DeleteInterconnectError::from_response(response))
         }
     }
 
-    /// <p><p>Deletes the specified interconnect.</p> <note> <p>Intended for use by AWS Direct Connect Partners only.</p> </note></p>
-    async fn delete_interconnect(
+    /// Presigns the request `delete_interconnect` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_interconnect(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteInterconnectRequest,
-    ) -> Result<DeleteInterconnectResponse, RusotoError<DeleteInterconnectError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_interconnect_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_interconnect_request(&self, input: DeleteInterconnectRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "directconnect", &self.region, "/");
+        request.set_operation("DeleteInterconnect");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "OvertureService.DeleteInterconnect");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `delete_lag`, also returning the metadata of the response.
+    pub async fn delete_lag_with_metadata(
+        &self,
+        input: DeleteLagRequest,
+    ) -> Result<(Lag, ResponseMetadata), RusotoError<DeleteLagError>> {
+        let request = self.build_delete_lag_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DeleteInterconnectResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response).deserialize::<Lag, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteInterconnectError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DeleteLagError::from_response(response))
         }
     }
 
-    /// <p>Deletes the specified link aggregation group (LAG). You cannot delete a LAG if it has active virtual interfaces or hosted connections.</p>
-    async fn delete_lag(
+    /// Presigns the request `delete_lag` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_lag(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteLagRequest,
-    ) -> Result<Lag, RusotoError<DeleteLagError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_lag_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_lag_request(&self, input: DeleteLagRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "directconnect", &self.region, "/");
+        request.set_operation("DeleteLag");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "OvertureService.DeleteLag");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `delete_virtual_interface`, also returning the metadata of the response.
+    pub async fn delete_virtual_interface_with_metadata(
+        &self,
+        input: DeleteVirtualInterfaceRequest,
+    ) -> Result<
+        (DeleteVirtualInterfaceResponse, ResponseMetadata),
+        RusotoError<DeleteVirtualInterfaceError>,
+    > {
+        let request = self.build_delete_virtual_interface_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<Lag, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DeleteVirtualInterfaceResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteLagError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DeleteVirtualInterfaceError::from_response(response))
         }
     }
 
-    /// <p>Deletes a virtual interface.</p>
-    async fn delete_virtual_interface(
+    /// Presigns the request `delete_virtual_interface` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_virtual_interface(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteVirtualInterfaceRequest,
-    ) -> Result<DeleteVirtualInterfaceResponse, RusotoError<DeleteVirtualInterfaceError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_virtual_interface_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_virtual_interface_request(
+        &self,
+        input: DeleteVirtualInterfaceRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "directconnect", &self.region, "/");
+        request.set_operation("DeleteVirtualInterface");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "OvertureService.DeleteVirtualInterface");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `describe_connection_loa`, also returning the metadata of the response.
+    pub async fn describe_connection_loa_with_metadata(
+        &self,
+        input: DescribeConnectionLoaRequest,
+    ) -> Result<
+        (DescribeConnectionLoaResponse, ResponseMetadata),
+        RusotoError<DescribeConnectionLoaError>,
+    > {
+        let request = self.build_describe_connection_loa_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DeleteVirtualInterfaceResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DescribeConnectionLoaResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteVirtualInterfaceError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DescribeConnectionLoaError::from_response(response))
         }
     }
 
-    /// <p>Deprecated. Use <a>DescribeLoa</a> instead.</p> <p>Gets the LOA-CFA for a connection.</p> <p>The Letter of Authorization - Connecting Facility Assignment (LOA-CFA) is a document that your APN partner or service provider uses when establishing your cross connect to AWS at the colocation facility. For more information, see <a href="https://docs.aws.amazon.com/directconnect/latest/UserGuide/Colocation.html">Requesting Cross Connects at AWS Direct Connect Locations</a> in the <i>AWS Direct Connect User Guide</i>.</p>
-    async fn describe_connection_loa(
+    /// Presigns the request `describe_connection_loa` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_connection_loa(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeConnectionLoaRequest,
-    ) -> Result<DescribeConnectionLoaResponse, RusotoError<DescribeConnectionLoaError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_connection_loa_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_connection_loa_request(
+        &self,
+        input: DescribeConnectionLoaRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "directconnect", &self.region, "/");
+        request.set_operation("DescribeConnectionLoa");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "OvertureService.DescribeConnectionLoa");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `describe_connections`, also returning the metadata of the response.
+    pub async fn describe_connections_with_metadata(
+        &self,
+        input: DescribeConnectionsRequest,
+    ) -> Result<(Connections, ResponseMetadata), RusotoError<DescribeConnectionsError>> {
+        let request = self.build_describe_connections_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DescribeConnectionLoaResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result =
+                proto::json::ResponsePayload::new(&response).deserialize::<Connections, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeConnectionLoaError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DescribeConnectionsError::from_response(response))
         }
     }
 
-    /// <p>Displays the specified connection or all connections in this Region.</p>
-    async fn describe_connections(
+    /// Presigns the request `describe_connections` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_connections(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeConnectionsRequest,
-    ) -> Result<Connections, RusotoError<DescribeConnectionsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_connections_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_connections_request(
+        &self,
+        input: DescribeConnectionsRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "directconnect", &self.region, "/");
+        request.set_operation("DescribeConnections");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "OvertureService.DescribeConnections");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `describe_connections_on_interconnect`, also returning the metadata of the response.
+    pub async fn describe_connections_on_interconnect_with_metadata(
+        &self,
+        input: DescribeConnectionsOnInterconnectRequest,
+    ) -> Result<(Connections, ResponseMetadata), RusotoError<DescribeConnectionsOnInterconnectError>>
+    {
+        let request = self.build_describe_connections_on_interconnect_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<Connections, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result =
+                proto::json::ResponsePayload::new(&response).deserialize::<Connections, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeConnectionsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DescribeConnectionsOnInterconnectError::from_response(
+                response,
+            ))
         }
     }
 
-    /// <p><p>Deprecated. Use <a>DescribeHostedConnections</a> instead.</p> <p>Lists the connections that have been provisioned on the specified interconnect.</p> <note> <p>Intended for use by AWS Direct Connect Partners only.</p> </note></p>
-    async fn describe_connections_on_interconnect(
+    /// Presigns the request `describe_connections_on_interconnect` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_connections_on_interconnect(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeConnectionsOnInterconnectRequest,
-    ) -> Result<Connections, RusotoError<DescribeConnectionsOnInterconnectError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_connections_on_interconnect_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_connections_on_interconnect_request(
+        &self,
+        input: DescribeConnectionsOnInterconnectRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "directconnect", &self.region, "/");
+        request.set_operation("DescribeConnectionsOnInterconnect");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -5788,32 +7300,64 @@ impl DirectConnect for DirectConnectClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `describe_direct_connect_gateway_association_proposals`, also returning the metadata of the response.
+    pub async fn describe_direct_connect_gateway_association_proposals_with_metadata(
+        &self,
+        input: DescribeDirectConnectGatewayAssociationProposalsRequest,
+    ) -> Result<
+        (
+            DescribeDirectConnectGatewayAssociationProposalsResult,
+            ResponseMetadata,
+        ),
+        RusotoError<DescribeDirectConnectGatewayAssociationProposalsError>,
+    > {
+        let request =
+            self.build_describe_direct_connect_gateway_association_proposals_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<Connections, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DescribeDirectConnectGatewayAssociationProposalsResult, _>(
+            )?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeConnectionsOnInterconnectError::from_response(
-                response,
-            ))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DescribeDirectConnectGatewayAssociationProposalsError::from_response(response))
         }
     }
 
-    /// <p>Describes one or more association proposals for connection between a virtual private gateway or transit gateway and a Direct Connect gateway. </p>
-    async fn describe_direct_connect_gateway_association_proposals(
+    /// Presigns the request `describe_direct_connect_gateway_association_proposals` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_direct_connect_gateway_association_proposals(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeDirectConnectGatewayAssociationProposalsRequest,
-    ) -> Result<
-        DescribeDirectConnectGatewayAssociationProposalsResult,
-        RusotoError<DescribeDirectConnectGatewayAssociationProposalsError>,
-    > {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request =
+            self.build_describe_direct_connect_gateway_association_proposals_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_direct_connect_gateway_association_proposals_request(
+        &self,
+        input: DescribeDirectConnectGatewayAssociationProposalsRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "directconnect", &self.region, "/");
+        request.set_operation("DescribeDirectConnectGatewayAssociationProposals");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -5823,31 +7367,61 @@ impl DirectConnect for DirectConnectClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `describe_direct_connect_gateway_associations`, also returning the metadata of the response.
+    pub async fn describe_direct_connect_gateway_associations_with_metadata(
+        &self,
+        input: DescribeDirectConnectGatewayAssociationsRequest,
+    ) -> Result<
+        (
+            DescribeDirectConnectGatewayAssociationsResult,
+            ResponseMetadata,
+        ),
+        RusotoError<DescribeDirectConnectGatewayAssociationsError>,
+    > {
+        let request = self.build_describe_direct_connect_gateway_associations_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DescribeDirectConnectGatewayAssociationProposalsResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DescribeDirectConnectGatewayAssociationsResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeDirectConnectGatewayAssociationProposalsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DescribeDirectConnectGatewayAssociationsError::from_response(response))
         }
     }
 
-    /// <p>Lists the associations between your Direct Connect gateways and virtual private gateways. You must specify a Direct Connect gateway, a virtual private gateway, or both. If you specify a Direct Connect gateway, the response contains all virtual private gateways associated with the Direct Connect gateway. If you specify a virtual private gateway, the response contains all Direct Connect gateways associated with the virtual private gateway. If you specify both, the response contains the association between the Direct Connect gateway and the virtual private gateway.</p>
-    async fn describe_direct_connect_gateway_associations(
+    /// Presigns the request `describe_direct_connect_gateway_associations` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_direct_connect_gateway_associations(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeDirectConnectGatewayAssociationsRequest,
-    ) -> Result<
-        DescribeDirectConnectGatewayAssociationsResult,
-        RusotoError<DescribeDirectConnectGatewayAssociationsError>,
-    > {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_direct_connect_gateway_associations_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_direct_connect_gateway_associations_request(
+        &self,
+        input: DescribeDirectConnectGatewayAssociationsRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "directconnect", &self.region, "/");
+        request.set_operation("DescribeDirectConnectGatewayAssociations");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -5857,31 +7431,63 @@ impl DirectConnect for DirectConnectClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `describe_direct_connect_gateway_attachments`, also returning the metadata of the response.
+    pub async fn describe_direct_connect_gateway_attachments_with_metadata(
+        &self,
+        input: DescribeDirectConnectGatewayAttachmentsRequest,
+    ) -> Result<
+        (
+            DescribeDirectConnectGatewayAttachmentsResult,
+            ResponseMetadata,
+        ),
+        RusotoError<DescribeDirectConnectGatewayAttachmentsError>,
+    > {
+        let request = self.build_describe_direct_connect_gateway_attachments_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DescribeDirectConnectGatewayAssociationsResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DescribeDirectConnectGatewayAttachmentsResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeDirectConnectGatewayAssociationsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DescribeDirectConnectGatewayAttachmentsError::from_response(
+                response,
+            ))
         }
     }
 
-    /// <p>Lists the attachments between your Direct Connect gateways and virtual interfaces. You must specify a Direct Connect gateway, a virtual interface, or both. If you specify a Direct Connect gateway, the response contains all virtual interfaces attached to the Direct Connect gateway. If you specify a virtual interface, the response contains all Direct Connect gateways attached to the virtual interface. If you specify both, the response contains the attachment between the Direct Connect gateway and the virtual interface.</p>
-    async fn describe_direct_connect_gateway_attachments(
+    /// Presigns the request `describe_direct_connect_gateway_attachments` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_direct_connect_gateway_attachments(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeDirectConnectGatewayAttachmentsRequest,
-    ) -> Result<
-        DescribeDirectConnectGatewayAttachmentsResult,
-        RusotoError<DescribeDirectConnectGatewayAttachmentsError>,
-    > {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_direct_connect_gateway_attachments_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_direct_connect_gateway_attachments_request(
+        &self,
+        input: DescribeDirectConnectGatewayAttachmentsRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "directconnect", &self.region, "/");
+        request.set_operation("DescribeDirectConnectGatewayAttachments");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -5891,31 +7497,58 @@ impl DirectConnect for DirectConnectClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `describe_direct_connect_gateways`, also returning the metadata of the response.
+    pub async fn describe_direct_connect_gateways_with_metadata(
+        &self,
+        input: DescribeDirectConnectGatewaysRequest,
+    ) -> Result<
+        (DescribeDirectConnectGatewaysResult, ResponseMetadata),
+        RusotoError<DescribeDirectConnectGatewaysError>,
+    > {
+        let request = self.build_describe_direct_connect_gateways_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DescribeDirectConnectGatewayAttachmentsResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DescribeDirectConnectGatewaysResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeDirectConnectGatewayAttachmentsError::from_response(
-                response,
-            ))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DescribeDirectConnectGatewaysError::from_response(response))
         }
     }
 
-    /// <p>Lists all your Direct Connect gateways or only the specified Direct Connect gateway. Deleted Direct Connect gateways are not returned.</p>
-    async fn describe_direct_connect_gateways(
+    /// Presigns the request `describe_direct_connect_gateways` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_direct_connect_gateways(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeDirectConnectGatewaysRequest,
-    ) -> Result<DescribeDirectConnectGatewaysResult, RusotoError<DescribeDirectConnectGatewaysError>>
-    {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_direct_connect_gateways_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_direct_connect_gateways_request(
+        &self,
+        input: DescribeDirectConnectGatewaysRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "directconnect", &self.region, "/");
+        request.set_operation("DescribeDirectConnectGateways");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -5925,266 +7558,533 @@ impl DirectConnect for DirectConnectClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `describe_hosted_connections`, also returning the metadata of the response.
+    pub async fn describe_hosted_connections_with_metadata(
+        &self,
+        input: DescribeHostedConnectionsRequest,
+    ) -> Result<(Connections, ResponseMetadata), RusotoError<DescribeHostedConnectionsError>> {
+        let request = self.build_describe_hosted_connections_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DescribeDirectConnectGatewaysResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result =
+                proto::json::ResponsePayload::new(&response).deserialize::<Connections, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeDirectConnectGatewaysError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DescribeHostedConnectionsError::from_response(response))
         }
     }
 
-    /// <p><p>Lists the hosted connections that have been provisioned on the specified interconnect or link aggregation group (LAG).</p> <note> <p>Intended for use by AWS Direct Connect Partners only.</p> </note></p>
-    async fn describe_hosted_connections(
+    /// Presigns the request `describe_hosted_connections` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_hosted_connections(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeHostedConnectionsRequest,
-    ) -> Result<Connections, RusotoError<DescribeHostedConnectionsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_hosted_connections_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_hosted_connections_request(
+        &self,
+        input: DescribeHostedConnectionsRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "directconnect", &self.region, "/");
+        request.set_operation("DescribeHostedConnections");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "OvertureService.DescribeHostedConnections");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `describe_interconnect_loa`, also returning the metadata of the response.
+    pub async fn describe_interconnect_loa_with_metadata(
+        &self,
+        input: DescribeInterconnectLoaRequest,
+    ) -> Result<
+        (DescribeInterconnectLoaResponse, ResponseMetadata),
+        RusotoError<DescribeInterconnectLoaError>,
+    > {
+        let request = self.build_describe_interconnect_loa_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<Connections, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DescribeInterconnectLoaResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeHostedConnectionsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DescribeInterconnectLoaError::from_response(response))
         }
     }
 
-    /// <p>Deprecated. Use <a>DescribeLoa</a> instead.</p> <p>Gets the LOA-CFA for the specified interconnect.</p> <p>The Letter of Authorization - Connecting Facility Assignment (LOA-CFA) is a document that is used when establishing your cross connect to AWS at the colocation facility. For more information, see <a href="https://docs.aws.amazon.com/directconnect/latest/UserGuide/Colocation.html">Requesting Cross Connects at AWS Direct Connect Locations</a> in the <i>AWS Direct Connect User Guide</i>.</p>
-    async fn describe_interconnect_loa(
+    /// Presigns the request `describe_interconnect_loa` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_interconnect_loa(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeInterconnectLoaRequest,
-    ) -> Result<DescribeInterconnectLoaResponse, RusotoError<DescribeInterconnectLoaError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_interconnect_loa_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_interconnect_loa_request(
+        &self,
+        input: DescribeInterconnectLoaRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "directconnect", &self.region, "/");
+        request.set_operation("DescribeInterconnectLoa");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "OvertureService.DescribeInterconnectLoa");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `describe_interconnects`, also returning the metadata of the response.
+    pub async fn describe_interconnects_with_metadata(
+        &self,
+        input: DescribeInterconnectsRequest,
+    ) -> Result<(Interconnects, ResponseMetadata), RusotoError<DescribeInterconnectsError>> {
+        let request = self.build_describe_interconnects_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DescribeInterconnectLoaResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result =
+                proto::json::ResponsePayload::new(&response).deserialize::<Interconnects, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeInterconnectLoaError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DescribeInterconnectsError::from_response(response))
         }
     }
 
-    /// <p>Lists the interconnects owned by the AWS account or only the specified interconnect.</p>
-    async fn describe_interconnects(
+    /// Presigns the request `describe_interconnects` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_interconnects(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeInterconnectsRequest,
-    ) -> Result<Interconnects, RusotoError<DescribeInterconnectsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_interconnects_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_interconnects_request(
+        &self,
+        input: DescribeInterconnectsRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "directconnect", &self.region, "/");
+        request.set_operation("DescribeInterconnects");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "OvertureService.DescribeInterconnects");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `describe_lags`, also returning the metadata of the response.
+    pub async fn describe_lags_with_metadata(
+        &self,
+        input: DescribeLagsRequest,
+    ) -> Result<(Lags, ResponseMetadata), RusotoError<DescribeLagsError>> {
+        let request = self.build_describe_lags_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<Interconnects, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response).deserialize::<Lags, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeInterconnectsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DescribeLagsError::from_response(response))
         }
     }
 
-    /// <p>Describes all your link aggregation groups (LAG) or the specified LAG.</p>
-    async fn describe_lags(
+    /// Presigns the request `describe_lags` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_lags(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeLagsRequest,
-    ) -> Result<Lags, RusotoError<DescribeLagsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_lags_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_lags_request(&self, input: DescribeLagsRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "directconnect", &self.region, "/");
+        request.set_operation("DescribeLags");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "OvertureService.DescribeLags");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `describe_loa`, also returning the metadata of the response.
+    pub async fn describe_loa_with_metadata(
+        &self,
+        input: DescribeLoaRequest,
+    ) -> Result<(Loa, ResponseMetadata), RusotoError<DescribeLoaError>> {
+        let request = self.build_describe_loa_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<Lags, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response).deserialize::<Loa, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeLagsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DescribeLoaError::from_response(response))
         }
     }
 
-    /// <p>Gets the LOA-CFA for a connection, interconnect, or link aggregation group (LAG).</p> <p>The Letter of Authorization - Connecting Facility Assignment (LOA-CFA) is a document that is used when establishing your cross connect to AWS at the colocation facility. For more information, see <a href="https://docs.aws.amazon.com/directconnect/latest/UserGuide/Colocation.html">Requesting Cross Connects at AWS Direct Connect Locations</a> in the <i>AWS Direct Connect User Guide</i>.</p>
-    async fn describe_loa(
+    /// Presigns the request `describe_loa` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_loa(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeLoaRequest,
-    ) -> Result<Loa, RusotoError<DescribeLoaError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_loa_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_loa_request(&self, input: DescribeLoaRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "directconnect", &self.region, "/");
+        request.set_operation("DescribeLoa");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "OvertureService.DescribeLoa");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `describe_locations`, also returning the metadata of the response.
+    pub async fn describe_locations_with_metadata(
+        &self,
+    ) -> Result<(Locations, ResponseMetadata), RusotoError<DescribeLocationsError>> {
+        let request = self.build_describe_locations_request();
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<Loa, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result =
+                proto::json::ResponsePayload::new(&response).deserialize::<Locations, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeLoaError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DescribeLocationsError::from_response(response))
         }
     }
 
-    /// <p>Lists the AWS Direct Connect locations in the current AWS Region. These are the locations that can be selected when calling <a>CreateConnection</a> or <a>CreateInterconnect</a>.</p>
-    async fn describe_locations(&self) -> Result<Locations, RusotoError<DescribeLocationsError>> {
+    /// Presigns the request `describe_locations` would send with `presigner` instead of sending it.
+    pub async fn presign_describe_locations(
+        &self,
+        presigner: &rusoto_core::Presigner,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_locations_request();
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_locations_request(&self) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "directconnect", &self.region, "/");
+        request.set_operation("DescribeLocations");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "OvertureService.DescribeLocations");
         request.set_payload(Some(bytes::Bytes::from_static(b"{}")));
 
+        request
+    }
+
+    /// Calls `describe_tags`, also returning the metadata of the response.
+    pub async fn describe_tags_with_metadata(
+        &self,
+        input: DescribeTagsRequest,
+    ) -> Result<(DescribeTagsResponse, ResponseMetadata), RusotoError<DescribeTagsError>> {
+        let request = self.build_describe_tags_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<Locations, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DescribeTagsResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeLocationsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DescribeTagsError::from_response(response))
         }
     }
 
-    /// <p>Describes the tags associated with the specified AWS Direct Connect resources.</p>
-    async fn describe_tags(
+    /// Presigns the request `describe_tags` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_tags(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeTagsRequest,
-    ) -> Result<DescribeTagsResponse, RusotoError<DescribeTagsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_tags_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_tags_request(&self, input: DescribeTagsRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "directconnect", &self.region, "/");
+        request.set_operation("DescribeTags");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "OvertureService.DescribeTags");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `describe_virtual_gateways`, also returning the metadata of the response.
+    pub async fn describe_virtual_gateways_with_metadata(
+        &self,
+    ) -> Result<(VirtualGateways, ResponseMetadata), RusotoError<DescribeVirtualGatewaysError>>
+    {
+        let request = self.build_describe_virtual_gateways_request();
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<DescribeTagsResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result =
+                proto::json::ResponsePayload::new(&response).deserialize::<VirtualGateways, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeTagsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DescribeVirtualGatewaysError::from_response(response))
         }
     }
 
-    /// <p>Lists the virtual private gateways owned by the AWS account.</p> <p>You can create one or more AWS Direct Connect private virtual interfaces linked to a virtual private gateway.</p>
-    async fn describe_virtual_gateways(
+    /// Presigns the request `describe_virtual_gateways` would send with `presigner` instead of sending it.
+    pub async fn presign_describe_virtual_gateways(
         &self,
-    ) -> Result<VirtualGateways, RusotoError<DescribeVirtualGatewaysError>> {
+        presigner: &rusoto_core::Presigner,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_virtual_gateways_request();
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_virtual_gateways_request(&self) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "directconnect", &self.region, "/");
+        request.set_operation("DescribeVirtualGateways");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "OvertureService.DescribeVirtualGateways");
         request.set_payload(Some(bytes::Bytes::from_static(b"{}")));
 
+        request
+    }
+
+    /// Calls `describe_virtual_interfaces`, also returning the metadata of the response.
+    pub async fn describe_virtual_interfaces_with_metadata(
+        &self,
+        input: DescribeVirtualInterfacesRequest,
+    ) -> Result<(VirtualInterfaces, ResponseMetadata), RusotoError<DescribeVirtualInterfacesError>>
+    {
+        let request = self.build_describe_virtual_interfaces_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<VirtualGateways, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<VirtualInterfaces, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeVirtualGatewaysError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DescribeVirtualInterfacesError::from_response(response))
         }
     }
 
-    /// <p>Displays all virtual interfaces for an AWS account. Virtual interfaces deleted fewer than 15 minutes before you make the request are also returned. If you specify a connection ID, only the virtual interfaces associated with the connection are returned. If you specify a virtual interface ID, then only a single virtual interface is returned.</p> <p>A virtual interface (VLAN) transmits the traffic between the AWS Direct Connect location and the customer network.</p>
-    async fn describe_virtual_interfaces(
+    /// Presigns the request `describe_virtual_interfaces` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_virtual_interfaces(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeVirtualInterfacesRequest,
-    ) -> Result<VirtualInterfaces, RusotoError<DescribeVirtualInterfacesError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_virtual_interfaces_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_virtual_interfaces_request(
+        &self,
+        input: DescribeVirtualInterfacesRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "directconnect", &self.region, "/");
+        request.set_operation("DescribeVirtualInterfaces");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "OvertureService.DescribeVirtualInterfaces");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `disassociate_connection_from_lag`, also returning the metadata of the response.
+    pub async fn disassociate_connection_from_lag_with_metadata(
+        &self,
+        input: DisassociateConnectionFromLagRequest,
+    ) -> Result<(Connection, ResponseMetadata), RusotoError<DisassociateConnectionFromLagError>>
+    {
+        let request = self.build_disassociate_connection_from_lag_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<VirtualInterfaces, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result =
+                proto::json::ResponsePayload::new(&response).deserialize::<Connection, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeVirtualInterfacesError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DisassociateConnectionFromLagError::from_response(response))
         }
     }
 
-    /// <p>Disassociates a connection from a link aggregation group (LAG). The connection is interrupted and re-established as a standalone connection (the connection is not deleted; to delete the connection, use the <a>DeleteConnection</a> request). If the LAG has associated virtual interfaces or hosted connections, they remain associated with the LAG. A disassociated connection owned by an AWS Direct Connect Partner is automatically converted to an interconnect.</p> <p>If disassociating the connection would cause the LAG to fall below its setting for minimum number of operational connections, the request fails, except when it's the last member of the LAG. If all connections are disassociated, the LAG continues to exist as an empty LAG with no physical connections. </p>
-    async fn disassociate_connection_from_lag(
+    /// Presigns the request `disassociate_connection_from_lag` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_disassociate_connection_from_lag(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DisassociateConnectionFromLagRequest,
-    ) -> Result<Connection, RusotoError<DisassociateConnectionFromLagError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_disassociate_connection_from_lag_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_disassociate_connection_from_lag_request(
+        &self,
+        input: DisassociateConnectionFromLagRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "directconnect", &self.region, "/");
+        request.set_operation("DisassociateConnectionFromLag");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -6194,84 +8094,167 @@ impl DirectConnect for DirectConnectClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `tag_resource`, also returning the metadata of the response.
+    pub async fn tag_resource_with_metadata(
+        &self,
+        input: TagResourceRequest,
+    ) -> Result<(TagResourceResponse, ResponseMetadata), RusotoError<TagResourceError>> {
+        let request = self.build_tag_resource_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<Connection, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<TagResourceResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DisassociateConnectionFromLagError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(TagResourceError::from_response(response))
         }
     }
 
-    /// <p>Adds the specified tags to the specified AWS Direct Connect resource. Each resource can have a maximum of 50 tags.</p> <p>Each tag consists of a key and an optional value. If a tag with the same key is already associated with the resource, this action updates its value.</p>
-    async fn tag_resource(
+    /// Presigns the request `tag_resource` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_tag_resource(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: TagResourceRequest,
-    ) -> Result<TagResourceResponse, RusotoError<TagResourceError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_tag_resource_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_tag_resource_request(&self, input: TagResourceRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "directconnect", &self.region, "/");
+        request.set_operation("TagResource");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "OvertureService.TagResource");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `untag_resource`, also returning the metadata of the response.
+    pub async fn untag_resource_with_metadata(
+        &self,
+        input: UntagResourceRequest,
+    ) -> Result<(UntagResourceResponse, ResponseMetadata), RusotoError<UntagResourceError>> {
+        let request = self.build_untag_resource_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<TagResourceResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<UntagResourceResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(TagResourceError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(UntagResourceError::from_response(response))
         }
     }
 
-    /// <p>Removes one or more tags from the specified AWS Direct Connect resource.</p>
-    async fn untag_resource(
+    /// Presigns the request `untag_resource` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_untag_resource(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UntagResourceRequest,
-    ) -> Result<UntagResourceResponse, RusotoError<UntagResourceError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_untag_resource_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_untag_resource_request(&self, input: UntagResourceRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "directconnect", &self.region, "/");
+        request.set_operation("UntagResource");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "OvertureService.UntagResource");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `update_direct_connect_gateway_association`, also returning the metadata of the response.
+    pub async fn update_direct_connect_gateway_association_with_metadata(
+        &self,
+        input: UpdateDirectConnectGatewayAssociationRequest,
+    ) -> Result<
+        (
+            UpdateDirectConnectGatewayAssociationResult,
+            ResponseMetadata,
+        ),
+        RusotoError<UpdateDirectConnectGatewayAssociationError>,
+    > {
+        let request = self.build_update_direct_connect_gateway_association_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<UntagResourceResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<UpdateDirectConnectGatewayAssociationResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(UntagResourceError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(UpdateDirectConnectGatewayAssociationError::from_response(
+                response,
+            ))
         }
     }
 
-    /// <p>Updates the specified attributes of the Direct Connect gateway association.</p> <p>Add or remove prefixes from the association.</p>
-    async fn update_direct_connect_gateway_association(
+    /// Presigns the request `update_direct_connect_gateway_association` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_direct_connect_gateway_association(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UpdateDirectConnectGatewayAssociationRequest,
-    ) -> Result<
-        UpdateDirectConnectGatewayAssociationResult,
-        RusotoError<UpdateDirectConnectGatewayAssociationError>,
-    > {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_direct_connect_gateway_association_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_direct_connect_gateway_association_request(
+        &self,
+        input: UpdateDirectConnectGatewayAssociationRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "directconnect", &self.region, "/");
+        request.set_operation("UpdateDirectConnectGatewayAssociation");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -6281,57 +8264,111 @@ impl DirectConnect for DirectConnectClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `update_lag`, also returning the metadata of the response.
+    pub async fn update_lag_with_metadata(
+        &self,
+        input: UpdateLagRequest,
+    ) -> Result<(Lag, ResponseMetadata), RusotoError<UpdateLagError>> {
+        let request = self.build_update_lag_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<UpdateDirectConnectGatewayAssociationResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response).deserialize::<Lag, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(UpdateDirectConnectGatewayAssociationError::from_response(
-                response,
-            ))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(UpdateLagError::from_response(response))
         }
     }
 
-    /// <p>Updates the attributes of the specified link aggregation group (LAG).</p> <p>You can update the following attributes:</p> <ul> <li> <p>The name of the LAG.</p> </li> <li> <p>The value for the minimum number of connections that must be operational for the LAG itself to be operational. </p> </li> </ul> <p>When you create a LAG, the default value for the minimum number of operational connections is zero (0). If you update this value and the number of operational connections falls below the specified value, the LAG automatically goes down to avoid over-utilization of the remaining connections. Adjust this value with care, as it could force the LAG down if it is set higher than the current number of operational connections.</p>
-    async fn update_lag(
+    /// Presigns the request `update_lag` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_lag(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UpdateLagRequest,
-    ) -> Result<Lag, RusotoError<UpdateLagError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_lag_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_lag_request(&self, input: UpdateLagRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "directconnect", &self.region, "/");
+        request.set_operation("UpdateLag");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "OvertureService.UpdateLag");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `update_virtual_interface_attributes`, also returning the metadata of the response.
+    pub async fn update_virtual_interface_attributes_with_metadata(
+        &self,
+        input: UpdateVirtualInterfaceAttributesRequest,
+    ) -> Result<
+        (VirtualInterface, ResponseMetadata),
+        RusotoError<UpdateVirtualInterfaceAttributesError>,
+    > {
+        let request = self.build_update_virtual_interface_attributes_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<Lag, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<VirtualInterface, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(UpdateLagError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(UpdateVirtualInterfaceAttributesError::from_response(
+                response,
+            ))
         }
     }
 
-    /// <p>Updates the specified attributes of the specified virtual private interface.</p> <p>Setting the MTU of a virtual interface to 9001 (jumbo frames) can cause an update to the underlying physical connection if it wasn't updated to support jumbo frames. Updating the connection disrupts network connectivity for all virtual interfaces associated with the connection for up to 30 seconds. To check whether your connection supports jumbo frames, call <a>DescribeConnections</a>. To check whether your virtual interface supports jumbo frames, call <a>DescribeVirtualInterfaces</a>.</p>
-    async fn update_virtual_interface_attributes(
+    /// Presigns the request `update_virtual_interface_attributes` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_virtual_interface_attributes(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UpdateVirtualInterfaceAttributesRequest,
-    ) -> Result<VirtualInterface, RusotoError<UpdateVirtualInterfaceAttributesError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_virtual_interface_attributes_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_virtual_interface_attributes_request(
+        &self,
+        input: UpdateVirtualInterfaceAttributesRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "directconnect", &self.region, "/");
+        request.set_operation("UpdateVirtualInterfaceAttributes");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -6341,20 +8378,722 @@ impl DirectConnect for DirectConnectClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
-        let mut response = self
-            .client
-            .sign_and_dispatch(request)
+        request
+    }
+
+    /// The pages of `DescribeDirectConnectGatewayAssociations` results for `input`, requesting each next page with the `nextToken` of the last one as its `nextToken`.
+    /// The page size is the `maxResults` of `input`.
+    pub fn describe_direct_connect_gateway_associations_pages(
+        &self,
+        input: DescribeDirectConnectGatewayAssociationsRequest,
+    ) -> impl futures::Stream<
+        Item = Result<
+            DescribeDirectConnectGatewayAssociationsResult,
+            RusotoError<DescribeDirectConnectGatewayAssociationsError>,
+        >,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: DirectConnectClient, input| async move {
+                client
+                    .describe_direct_connect_gateway_associations(input)
+                    .await
+            },
+            |input: &DescribeDirectConnectGatewayAssociationsRequest,
+             output: &DescribeDirectConnectGatewayAssociationsResult| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(DescribeDirectConnectGatewayAssociationsRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `directConnectGatewayAssociations` of every page of `DescribeDirectConnectGatewayAssociations` results for `input`, as `describe_direct_connect_gateway_associations_pages` requests them.
+    pub fn describe_direct_connect_gateway_associations_stream(
+        &self,
+        input: DescribeDirectConnectGatewayAssociationsRequest,
+    ) -> impl futures::Stream<
+        Item = Result<
+            DirectConnectGatewayAssociation,
+            RusotoError<DescribeDirectConnectGatewayAssociationsError>,
+        >,
+    > + Send {
+        rusoto_core::pagination::items(
+            self.describe_direct_connect_gateway_associations_pages(input),
+            |page| page.direct_connect_gateway_associations.unwrap_or_default(),
+        )
+    }
+
+    /// The pages of `DescribeDirectConnectGatewayAttachments` results for `input`, requesting each next page with the `nextToken` of the last one as its `nextToken`.
+    /// The page size is the `maxResults` of `input`.
+    pub fn describe_direct_connect_gateway_attachments_pages(
+        &self,
+        input: DescribeDirectConnectGatewayAttachmentsRequest,
+    ) -> impl futures::Stream<
+        Item = Result<
+            DescribeDirectConnectGatewayAttachmentsResult,
+            RusotoError<DescribeDirectConnectGatewayAttachmentsError>,
+        >,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: DirectConnectClient, input| async move {
+                client
+                    .describe_direct_connect_gateway_attachments(input)
+                    .await
+            },
+            |input: &DescribeDirectConnectGatewayAttachmentsRequest,
+             output: &DescribeDirectConnectGatewayAttachmentsResult| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(DescribeDirectConnectGatewayAttachmentsRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `directConnectGatewayAttachments` of every page of `DescribeDirectConnectGatewayAttachments` results for `input`, as `describe_direct_connect_gateway_attachments_pages` requests them.
+    pub fn describe_direct_connect_gateway_attachments_stream(
+        &self,
+        input: DescribeDirectConnectGatewayAttachmentsRequest,
+    ) -> impl futures::Stream<
+        Item = Result<
+            DirectConnectGatewayAttachment,
+            RusotoError<DescribeDirectConnectGatewayAttachmentsError>,
+        >,
+    > + Send {
+        rusoto_core::pagination::items(
+            self.describe_direct_connect_gateway_attachments_pages(input),
+            |page| page.direct_connect_gateway_attachments.unwrap_or_default(),
+        )
+    }
+
+    /// The pages of `DescribeDirectConnectGateways` results for `input`, requesting each next page with the `nextToken` of the last one as its `nextToken`.
+    /// The page size is the `maxResults` of `input`.
+    pub fn describe_direct_connect_gateways_pages(
+        &self,
+        input: DescribeDirectConnectGatewaysRequest,
+    ) -> impl futures::Stream<
+        Item = Result<
+            DescribeDirectConnectGatewaysResult,
+            RusotoError<DescribeDirectConnectGatewaysError>,
+        >,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: DirectConnectClient, input| async move {
+                client.describe_direct_connect_gateways(input).await
+            },
+            |input: &DescribeDirectConnectGatewaysRequest,
+             output: &DescribeDirectConnectGatewaysResult| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(DescribeDirectConnectGatewaysRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `directConnectGateways` of every page of `DescribeDirectConnectGateways` results for `input`, as `describe_direct_connect_gateways_pages` requests them.
+    pub fn describe_direct_connect_gateways_stream(
+        &self,
+        input: DescribeDirectConnectGatewaysRequest,
+    ) -> impl futures::Stream<
+        Item = Result<DirectConnectGateway, RusotoError<DescribeDirectConnectGatewaysError>>,
+    > + Send {
+        rusoto_core::pagination::items(self.describe_direct_connect_gateways_pages(input), |page| {
+            page.direct_connect_gateways.unwrap_or_default()
+        })
+    }
+}
+
+#[async_trait]
+impl DirectConnect for DirectConnectClient {
+    /// <p>Accepts a proposal request to attach a virtual private gateway or transit gateway to a Direct Connect gateway.</p>
+    async fn accept_direct_connect_gateway_association_proposal(
+        &self,
+        input: AcceptDirectConnectGatewayAssociationProposalRequest,
+    ) -> Result<
+        AcceptDirectConnectGatewayAssociationProposalResult,
+        RusotoError<AcceptDirectConnectGatewayAssociationProposalError>,
+    > {
+        self.accept_direct_connect_gateway_association_proposal_with_metadata(input)
             .await
-            .map_err(RusotoError::from)?;
-        if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<VirtualInterface, _>()
-        } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(UpdateVirtualInterfaceAttributesError::from_response(
-                response,
-            ))
-        }
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Deprecated. Use <a>AllocateHostedConnection</a> instead.</p> <p>Creates a hosted connection on an interconnect.</p> <p>Allocates a VLAN number and a specified amount of bandwidth for use by a hosted connection on the specified interconnect.</p> <note> <p>Intended for use by AWS Direct Connect Partners only.</p> </note></p>
+    async fn allocate_connection_on_interconnect(
+        &self,
+        input: AllocateConnectionOnInterconnectRequest,
+    ) -> Result<Connection, RusotoError<AllocateConnectionOnInterconnectError>> {
+        self.allocate_connection_on_interconnect_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Creates a hosted connection on the specified interconnect or a link aggregation group (LAG) of interconnects.</p> <p>Allocates a VLAN number and a specified amount of capacity (bandwidth) for use by a hosted connection on the specified interconnect or LAG of interconnects. AWS polices the hosted connection for the specified capacity and the AWS Direct Connect Partner must also police the hosted connection for the specified capacity.</p> <note> <p>Intended for use by AWS Direct Connect Partners only.</p> </note></p>
+    async fn allocate_hosted_connection(
+        &self,
+        input: AllocateHostedConnectionRequest,
+    ) -> Result<Connection, RusotoError<AllocateHostedConnectionError>> {
+        self.allocate_hosted_connection_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Provisions a private virtual interface to be owned by the specified AWS account.</p> <p>Virtual interfaces created using this action must be confirmed by the owner using <a>ConfirmPrivateVirtualInterface</a>. Until then, the virtual interface is in the <code>Confirming</code> state and is not available to handle traffic.</p>
+    async fn allocate_private_virtual_interface(
+        &self,
+        input: AllocatePrivateVirtualInterfaceRequest,
+    ) -> Result<VirtualInterface, RusotoError<AllocatePrivateVirtualInterfaceError>> {
+        self.allocate_private_virtual_interface_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Provisions a public virtual interface to be owned by the specified AWS account.</p> <p>The owner of a connection calls this function to provision a public virtual interface to be owned by the specified AWS account.</p> <p>Virtual interfaces created using this function must be confirmed by the owner using <a>ConfirmPublicVirtualInterface</a>. Until this step has been completed, the virtual interface is in the <code>confirming</code> state and is not available to handle traffic.</p> <p>When creating an IPv6 public virtual interface, omit the Amazon address and customer address. IPv6 addresses are automatically assigned from the Amazon pool of IPv6 addresses; you cannot specify custom IPv6 addresses.</p>
+    async fn allocate_public_virtual_interface(
+        &self,
+        input: AllocatePublicVirtualInterfaceRequest,
+    ) -> Result<VirtualInterface, RusotoError<AllocatePublicVirtualInterfaceError>> {
+        self.allocate_public_virtual_interface_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Provisions a transit virtual interface to be owned by the specified AWS account. Use this type of interface to connect a transit gateway to your Direct Connect gateway.</p> <p>The owner of a connection provisions a transit virtual interface to be owned by the specified AWS account.</p> <p>After you create a transit virtual interface, it must be confirmed by the owner using <a>ConfirmTransitVirtualInterface</a>. Until this step has been completed, the transit virtual interface is in the <code>requested</code> state and is not available to handle traffic.</p>
+    async fn allocate_transit_virtual_interface(
+        &self,
+        input: AllocateTransitVirtualInterfaceRequest,
+    ) -> Result<
+        AllocateTransitVirtualInterfaceResult,
+        RusotoError<AllocateTransitVirtualInterfaceError>,
+    > {
+        self.allocate_transit_virtual_interface_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Associates an existing connection with a link aggregation group (LAG). The connection is interrupted and re-established as a member of the LAG (connectivity to AWS is interrupted). The connection must be hosted on the same AWS Direct Connect endpoint as the LAG, and its bandwidth must match the bandwidth for the LAG. You can re-associate a connection that's currently associated with a different LAG; however, if removing the connection would cause the original LAG to fall below its setting for minimum number of operational connections, the request fails.</p> <p>Any virtual interfaces that are directly associated with the connection are automatically re-associated with the LAG. If the connection was originally associated with a different LAG, the virtual interfaces remain associated with the original LAG.</p> <p>For interconnects, any hosted connections are automatically re-associated with the LAG. If the interconnect was originally associated with a different LAG, the hosted connections remain associated with the original LAG.</p>
+    async fn associate_connection_with_lag(
+        &self,
+        input: AssociateConnectionWithLagRequest,
+    ) -> Result<Connection, RusotoError<AssociateConnectionWithLagError>> {
+        self.associate_connection_with_lag_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Associates a hosted connection and its virtual interfaces with a link aggregation group (LAG) or interconnect. If the target interconnect or LAG has an existing hosted connection with a conflicting VLAN number or IP address, the operation fails. This action temporarily interrupts the hosted connection&#39;s connectivity to AWS as it is being migrated.</p> <note> <p>Intended for use by AWS Direct Connect Partners only.</p> </note></p>
+    async fn associate_hosted_connection(
+        &self,
+        input: AssociateHostedConnectionRequest,
+    ) -> Result<Connection, RusotoError<AssociateHostedConnectionError>> {
+        self.associate_hosted_connection_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Associates a virtual interface with a specified link aggregation group (LAG) or connection. Connectivity to AWS is temporarily interrupted as the virtual interface is being migrated. If the target connection or LAG has an associated virtual interface with a conflicting VLAN number or a conflicting IP address, the operation fails.</p> <p>Virtual interfaces associated with a hosted connection cannot be associated with a LAG; hosted connections must be migrated along with their virtual interfaces using <a>AssociateHostedConnection</a>.</p> <p>To reassociate a virtual interface to a new connection or LAG, the requester must own either the virtual interface itself or the connection to which the virtual interface is currently associated. Additionally, the requester must own the connection or LAG for the association.</p>
+    async fn associate_virtual_interface(
+        &self,
+        input: AssociateVirtualInterfaceRequest,
+    ) -> Result<VirtualInterface, RusotoError<AssociateVirtualInterfaceError>> {
+        self.associate_virtual_interface_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Confirms the creation of the specified hosted connection on an interconnect.</p> <p>Upon creation, the hosted connection is initially in the <code>Ordering</code> state, and remains in this state until the owner confirms creation of the hosted connection.</p>
+    async fn confirm_connection(
+        &self,
+        input: ConfirmConnectionRequest,
+    ) -> Result<ConfirmConnectionResponse, RusotoError<ConfirmConnectionError>> {
+        self.confirm_connection_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Accepts ownership of a private virtual interface created by another AWS account.</p> <p>After the virtual interface owner makes this call, the virtual interface is created and attached to the specified virtual private gateway or Direct Connect gateway, and is made available to handle traffic.</p>
+    async fn confirm_private_virtual_interface(
+        &self,
+        input: ConfirmPrivateVirtualInterfaceRequest,
+    ) -> Result<
+        ConfirmPrivateVirtualInterfaceResponse,
+        RusotoError<ConfirmPrivateVirtualInterfaceError>,
+    > {
+        self.confirm_private_virtual_interface_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Accepts ownership of a public virtual interface created by another AWS account.</p> <p>After the virtual interface owner makes this call, the specified virtual interface is created and made available to handle traffic.</p>
+    async fn confirm_public_virtual_interface(
+        &self,
+        input: ConfirmPublicVirtualInterfaceRequest,
+    ) -> Result<
+        ConfirmPublicVirtualInterfaceResponse,
+        RusotoError<ConfirmPublicVirtualInterfaceError>,
+    > {
+        self.confirm_public_virtual_interface_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Accepts ownership of a transit virtual interface created by another AWS account.</p> <p> After the owner of the transit virtual interface makes this call, the specified transit virtual interface is created and made available to handle traffic.</p>
+    async fn confirm_transit_virtual_interface(
+        &self,
+        input: ConfirmTransitVirtualInterfaceRequest,
+    ) -> Result<
+        ConfirmTransitVirtualInterfaceResponse,
+        RusotoError<ConfirmTransitVirtualInterfaceError>,
+    > {
+        self.confirm_transit_virtual_interface_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Creates a BGP peer on the specified virtual interface.</p> <p>You must create a BGP peer for the corresponding address family (IPv4/IPv6) in order to access AWS resources that also use that address family.</p> <p>If logical redundancy is not supported by the connection, interconnect, or LAG, the BGP peer cannot be in the same address family as an existing BGP peer on the virtual interface.</p> <p>When creating a IPv6 BGP peer, omit the Amazon address and customer address. IPv6 addresses are automatically assigned from the Amazon pool of IPv6 addresses; you cannot specify custom IPv6 addresses.</p> <p>For a public virtual interface, the Autonomous System Number (ASN) must be private or already whitelisted for the virtual interface.</p>
+    async fn create_bgp_peer(
+        &self,
+        input: CreateBGPPeerRequest,
+    ) -> Result<CreateBGPPeerResponse, RusotoError<CreateBGPPeerError>> {
+        self.create_bgp_peer_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Creates a connection between a customer network and a specific AWS Direct Connect location.</p> <p>A connection links your internal network to an AWS Direct Connect location over a standard Ethernet fiber-optic cable. One end of the cable is connected to your router, the other to an AWS Direct Connect router.</p> <p>To find the locations for your Region, use <a>DescribeLocations</a>.</p> <p>You can automatically add the new connection to a link aggregation group (LAG) by specifying a LAG ID in the request. This ensures that the new connection is allocated on the same AWS Direct Connect endpoint that hosts the specified LAG. If there are no available ports on the endpoint, the request fails and no connection is created.</p>
+    async fn create_connection(
+        &self,
+        input: CreateConnectionRequest,
+    ) -> Result<Connection, RusotoError<CreateConnectionError>> {
+        self.create_connection_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Creates a Direct Connect gateway, which is an intermediate object that enables you to connect a set of virtual interfaces and virtual private gateways. A Direct Connect gateway is global and visible in any AWS Region after it is created. The virtual interfaces and virtual private gateways that are connected through a Direct Connect gateway can be in different AWS Regions. This enables you to connect to a VPC in any Region, regardless of the Region in which the virtual interfaces are located, and pass traffic between them.</p>
+    async fn create_direct_connect_gateway(
+        &self,
+        input: CreateDirectConnectGatewayRequest,
+    ) -> Result<CreateDirectConnectGatewayResult, RusotoError<CreateDirectConnectGatewayError>>
+    {
+        self.create_direct_connect_gateway_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Creates an association between a Direct Connect gateway and a virtual private gateway. The virtual private gateway must be attached to a VPC and must not be associated with another Direct Connect gateway.</p>
+    async fn create_direct_connect_gateway_association(
+        &self,
+        input: CreateDirectConnectGatewayAssociationRequest,
+    ) -> Result<
+        CreateDirectConnectGatewayAssociationResult,
+        RusotoError<CreateDirectConnectGatewayAssociationError>,
+    > {
+        self.create_direct_connect_gateway_association_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Creates a proposal to associate the specified virtual private gateway or transit gateway with the specified Direct Connect gateway.</p> <p>You can only associate a Direct Connect gateway and virtual private gateway or transit gateway when the account that owns the Direct Connect gateway and the account that owns the virtual private gateway or transit gateway have the same AWS Payer ID.</p>
+    async fn create_direct_connect_gateway_association_proposal(
+        &self,
+        input: CreateDirectConnectGatewayAssociationProposalRequest,
+    ) -> Result<
+        CreateDirectConnectGatewayAssociationProposalResult,
+        RusotoError<CreateDirectConnectGatewayAssociationProposalError>,
+    > {
+        self.create_direct_connect_gateway_association_proposal_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Creates an interconnect between an AWS Direct Connect Partner&#39;s network and a specific AWS Direct Connect location.</p> <p>An interconnect is a connection that is capable of hosting other connections. The AWS Direct Connect partner can use an interconnect to provide AWS Direct Connect hosted connections to customers through their own network services. Like a standard connection, an interconnect links the partner&#39;s network to an AWS Direct Connect location over a standard Ethernet fiber-optic cable. One end is connected to the partner&#39;s router, the other to an AWS Direct Connect router.</p> <p>You can automatically add the new interconnect to a link aggregation group (LAG) by specifying a LAG ID in the request. This ensures that the new interconnect is allocated on the same AWS Direct Connect endpoint that hosts the specified LAG. If there are no available ports on the endpoint, the request fails and no interconnect is created.</p> <p>For each end customer, the AWS Direct Connect Partner provisions a connection on their interconnect by calling <a>AllocateHostedConnection</a>. The end customer can then connect to AWS resources by creating a virtual interface on their connection, using the VLAN assigned to them by the AWS Direct Connect Partner.</p> <note> <p>Intended for use by AWS Direct Connect Partners only.</p> </note></p>
+    async fn create_interconnect(
+        &self,
+        input: CreateInterconnectRequest,
+    ) -> Result<Interconnect, RusotoError<CreateInterconnectError>> {
+        self.create_interconnect_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Creates a link aggregation group (LAG) with the specified number of bundled physical connections between the customer network and a specific AWS Direct Connect location. A LAG is a logical interface that uses the Link Aggregation Control Protocol (LACP) to aggregate multiple interfaces, enabling you to treat them as a single interface.</p> <p>All connections in a LAG must use the same bandwidth and must terminate at the same AWS Direct Connect endpoint.</p> <p>You can have up to 10 connections per LAG. Regardless of this limit, if you request more connections for the LAG than AWS Direct Connect can allocate on a single endpoint, no LAG is created.</p> <p>You can specify an existing physical connection or interconnect to include in the LAG (which counts towards the total number of connections). Doing so interrupts the current physical connection or hosted connections, and re-establishes them as a member of the LAG. The LAG will be created on the same AWS Direct Connect endpoint to which the connection terminates. Any virtual interfaces associated with the connection are automatically disassociated and re-associated with the LAG. The connection ID does not change.</p> <p>If the AWS account used to create a LAG is a registered AWS Direct Connect Partner, the LAG is automatically enabled to host sub-connections. For a LAG owned by a partner, any associated virtual interfaces cannot be directly configured.</p>
+    async fn create_lag(
+        &self,
+        input: CreateLagRequest,
+    ) -> Result<Lag, RusotoError<CreateLagError>> {
+        self.create_lag_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Creates a private virtual interface. A virtual interface is the VLAN that transports AWS Direct Connect traffic. A private virtual interface can be connected to either a Direct Connect gateway or a Virtual Private Gateway (VGW). Connecting the private virtual interface to a Direct Connect gateway enables the possibility for connecting to multiple VPCs, including VPCs in different AWS Regions. Connecting the private virtual interface to a VGW only provides access to a single VPC within the same Region.</p>
+    async fn create_private_virtual_interface(
+        &self,
+        input: CreatePrivateVirtualInterfaceRequest,
+    ) -> Result<VirtualInterface, RusotoError<CreatePrivateVirtualInterfaceError>> {
+        self.create_private_virtual_interface_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Creates a public virtual interface. A virtual interface is the VLAN that transports AWS Direct Connect traffic. A public virtual interface supports sending traffic to public services of AWS such as Amazon S3.</p> <p>When creating an IPv6 public virtual interface (<code>addressFamily</code> is <code>ipv6</code>), leave the <code>customer</code> and <code>amazon</code> address fields blank to use auto-assigned IPv6 space. Custom IPv6 addresses are not supported.</p>
+    async fn create_public_virtual_interface(
+        &self,
+        input: CreatePublicVirtualInterfaceRequest,
+    ) -> Result<VirtualInterface, RusotoError<CreatePublicVirtualInterfaceError>> {
+        self.create_public_virtual_interface_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Creates a transit virtual interface. A transit virtual interface should be used to access one or more transit gateways associated with Direct Connect gateways. A transit virtual interface enables the connection of multiple VPCs attached to a transit gateway to a Direct Connect gateway.</p> <important> <p>If you associate your transit gateway with one or more Direct Connect gateways, the Autonomous System Number (ASN) used by the transit gateway and the Direct Connect gateway must be different. For example, if you use the default ASN 64512 for both your the transit gateway and Direct Connect gateway, the association request fails.</p> </important></p>
+    async fn create_transit_virtual_interface(
+        &self,
+        input: CreateTransitVirtualInterfaceRequest,
+    ) -> Result<CreateTransitVirtualInterfaceResult, RusotoError<CreateTransitVirtualInterfaceError>>
+    {
+        self.create_transit_virtual_interface_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes the specified BGP peer on the specified virtual interface with the specified customer address and ASN.</p> <p>You cannot delete the last BGP peer from a virtual interface.</p>
+    async fn delete_bgp_peer(
+        &self,
+        input: DeleteBGPPeerRequest,
+    ) -> Result<DeleteBGPPeerResponse, RusotoError<DeleteBGPPeerError>> {
+        self.delete_bgp_peer_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes the specified connection.</p> <p>Deleting a connection only stops the AWS Direct Connect port hour and data transfer charges. If you are partnering with any third parties to connect with the AWS Direct Connect location, you must cancel your service with them separately.</p>
+    async fn delete_connection(
+        &self,
+        input: DeleteConnectionRequest,
+    ) -> Result<Connection, RusotoError<DeleteConnectionError>> {
+        self.delete_connection_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes the specified Direct Connect gateway. You must first delete all virtual interfaces that are attached to the Direct Connect gateway and disassociate all virtual private gateways associated with the Direct Connect gateway.</p>
+    async fn delete_direct_connect_gateway(
+        &self,
+        input: DeleteDirectConnectGatewayRequest,
+    ) -> Result<DeleteDirectConnectGatewayResult, RusotoError<DeleteDirectConnectGatewayError>>
+    {
+        self.delete_direct_connect_gateway_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes the association between the specified Direct Connect gateway and virtual private gateway.</p> <p>We recommend that you specify the <code>associationID</code> to delete the association. Alternatively, if you own virtual gateway and a Direct Connect gateway association, you can specify the <code>virtualGatewayId</code> and <code>directConnectGatewayId</code> to delete an association.</p>
+    async fn delete_direct_connect_gateway_association(
+        &self,
+        input: DeleteDirectConnectGatewayAssociationRequest,
+    ) -> Result<
+        DeleteDirectConnectGatewayAssociationResult,
+        RusotoError<DeleteDirectConnectGatewayAssociationError>,
+    > {
+        self.delete_direct_connect_gateway_association_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes the association proposal request between the specified Direct Connect gateway and virtual private gateway or transit gateway.</p>
+    async fn delete_direct_connect_gateway_association_proposal(
+        &self,
+        input: DeleteDirectConnectGatewayAssociationProposalRequest,
+    ) -> Result<
+        DeleteDirectConnectGatewayAssociationProposalResult,
+        RusotoError<DeleteDirectConnectGatewayAssociationProposalError>,
+    > {
+        self.delete_direct_connect_gateway_association_proposal_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Deletes the specified interconnect.</p> <note> <p>Intended for use by AWS Direct Connect Partners only.</p> </note></p>
+    async fn delete_interconnect(
+        &self,
+        input: DeleteInterconnectRequest,
+    ) -> Result<DeleteInterconnectResponse, RusotoError<DeleteInterconnectError>> {
+        self.delete_interconnect_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes the specified link aggregation group (LAG). You cannot delete a LAG if it has active virtual interfaces or hosted connections.</p>
+    async fn delete_lag(
+        &self,
+        input: DeleteLagRequest,
+    ) -> Result<Lag, RusotoError<DeleteLagError>> {
+        self.delete_lag_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes a virtual interface.</p>
+    async fn delete_virtual_interface(
+        &self,
+        input: DeleteVirtualInterfaceRequest,
+    ) -> Result<DeleteVirtualInterfaceResponse, RusotoError<DeleteVirtualInterfaceError>> {
+        self.delete_virtual_interface_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deprecated. Use <a>DescribeLoa</a> instead.</p> <p>Gets the LOA-CFA for a connection.</p> <p>The Letter of Authorization - Connecting Facility Assignment (LOA-CFA) is a document that your APN partner or service provider uses when establishing your cross connect to AWS at the colocation facility. For more information, see <a href="https://docs.aws.amazon.com/directconnect/latest/UserGuide/Colocation.html">Requesting Cross Connects at AWS Direct Connect Locations</a> in the <i>AWS Direct Connect User Guide</i>.</p>
+    async fn describe_connection_loa(
+        &self,
+        input: DescribeConnectionLoaRequest,
+    ) -> Result<DescribeConnectionLoaResponse, RusotoError<DescribeConnectionLoaError>> {
+        self.describe_connection_loa_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Displays the specified connection or all connections in this Region.</p>
+    async fn describe_connections(
+        &self,
+        input: DescribeConnectionsRequest,
+    ) -> Result<Connections, RusotoError<DescribeConnectionsError>> {
+        self.describe_connections_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Deprecated. Use <a>DescribeHostedConnections</a> instead.</p> <p>Lists the connections that have been provisioned on the specified interconnect.</p> <note> <p>Intended for use by AWS Direct Connect Partners only.</p> </note></p>
+    async fn describe_connections_on_interconnect(
+        &self,
+        input: DescribeConnectionsOnInterconnectRequest,
+    ) -> Result<Connections, RusotoError<DescribeConnectionsOnInterconnectError>> {
+        self.describe_connections_on_interconnect_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Describes one or more association proposals for connection between a virtual private gateway or transit gateway and a Direct Connect gateway. </p>
+    async fn describe_direct_connect_gateway_association_proposals(
+        &self,
+        input: DescribeDirectConnectGatewayAssociationProposalsRequest,
+    ) -> Result<
+        DescribeDirectConnectGatewayAssociationProposalsResult,
+        RusotoError<DescribeDirectConnectGatewayAssociationProposalsError>,
+    > {
+        self.describe_direct_connect_gateway_association_proposals_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Lists the associations between your Direct Connect gateways and virtual private gateways. You must specify a Direct Connect gateway, a virtual private gateway, or both. If you specify a Direct Connect gateway, the response contains all virtual private gateways associated with the Direct Connect gateway. If you specify a virtual private gateway, the response contains all Direct Connect gateways associated with the virtual private gateway. If you specify both, the response contains the association between the Direct Connect gateway and the virtual private gateway.</p>
+    async fn describe_direct_connect_gateway_associations(
+        &self,
+        input: DescribeDirectConnectGatewayAssociationsRequest,
+    ) -> Result<
+        DescribeDirectConnectGatewayAssociationsResult,
+        RusotoError<DescribeDirectConnectGatewayAssociationsError>,
+    > {
+        self.describe_direct_connect_gateway_associations_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Lists the attachments between your Direct Connect gateways and virtual interfaces. You must specify a Direct Connect gateway, a virtual interface, or both. If you specify a Direct Connect gateway, the response contains all virtual interfaces attached to the Direct Connect gateway. If you specify a virtual interface, the response contains all Direct Connect gateways attached to the virtual interface. If you specify both, the response contains the attachment between the Direct Connect gateway and the virtual interface.</p>
+    async fn describe_direct_connect_gateway_attachments(
+        &self,
+        input: DescribeDirectConnectGatewayAttachmentsRequest,
+    ) -> Result<
+        DescribeDirectConnectGatewayAttachmentsResult,
+        RusotoError<DescribeDirectConnectGatewayAttachmentsError>,
+    > {
+        self.describe_direct_connect_gateway_attachments_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Lists all your Direct Connect gateways or only the specified Direct Connect gateway. Deleted Direct Connect gateways are not returned.</p>
+    async fn describe_direct_connect_gateways(
+        &self,
+        input: DescribeDirectConnectGatewaysRequest,
+    ) -> Result<DescribeDirectConnectGatewaysResult, RusotoError<DescribeDirectConnectGatewaysError>>
+    {
+        self.describe_direct_connect_gateways_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Lists the hosted connections that have been provisioned on the specified interconnect or link aggregation group (LAG).</p> <note> <p>Intended for use by AWS Direct Connect Partners only.</p> </note></p>
+    async fn describe_hosted_connections(
+        &self,
+        input: DescribeHostedConnectionsRequest,
+    ) -> Result<Connections, RusotoError<DescribeHostedConnectionsError>> {
+        self.describe_hosted_connections_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deprecated. Use <a>DescribeLoa</a> instead.</p> <p>Gets the LOA-CFA for the specified interconnect.</p> <p>The Letter of Authorization - Connecting Facility Assignment (LOA-CFA) is a document that is used when establishing your cross connect to AWS at the colocation facility. For more information, see <a href="https://docs.aws.amazon.com/directconnect/latest/UserGuide/Colocation.html">Requesting Cross Connects at AWS Direct Connect Locations</a> in the <i>AWS Direct Connect User Guide</i>.</p>
+    async fn describe_interconnect_loa(
+        &self,
+        input: DescribeInterconnectLoaRequest,
+    ) -> Result<DescribeInterconnectLoaResponse, RusotoError<DescribeInterconnectLoaError>> {
+        self.describe_interconnect_loa_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Lists the interconnects owned by the AWS account or only the specified interconnect.</p>
+    async fn describe_interconnects(
+        &self,
+        input: DescribeInterconnectsRequest,
+    ) -> Result<Interconnects, RusotoError<DescribeInterconnectsError>> {
+        self.describe_interconnects_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Describes all your link aggregation groups (LAG) or the specified LAG.</p>
+    async fn describe_lags(
+        &self,
+        input: DescribeLagsRequest,
+    ) -> Result<Lags, RusotoError<DescribeLagsError>> {
+        self.describe_lags_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Gets the LOA-CFA for a connection, interconnect, or link aggregation group (LAG).</p> <p>The Letter of Authorization - Connecting Facility Assignment (LOA-CFA) is a document that is used when establishing your cross connect to AWS at the colocation facility. For more information, see <a href="https://docs.aws.amazon.com/directconnect/latest/UserGuide/Colocation.html">Requesting Cross Connects at AWS Direct Connect Locations</a> in the <i>AWS Direct Connect User Guide</i>.</p>
+    async fn describe_loa(
+        &self,
+        input: DescribeLoaRequest,
+    ) -> Result<Loa, RusotoError<DescribeLoaError>> {
+        self.describe_loa_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Lists the AWS Direct Connect locations in the current AWS Region. These are the locations that can be selected when calling <a>CreateConnection</a> or <a>CreateInterconnect</a>.</p>
+    async fn describe_locations(&self) -> Result<Locations, RusotoError<DescribeLocationsError>> {
+        self.describe_locations_with_metadata()
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Describes the tags associated with the specified AWS Direct Connect resources.</p>
+    async fn describe_tags(
+        &self,
+        input: DescribeTagsRequest,
+    ) -> Result<DescribeTagsResponse, RusotoError<DescribeTagsError>> {
+        self.describe_tags_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Lists the virtual private gateways owned by the AWS account.</p> <p>You can create one or more AWS Direct Connect private virtual interfaces linked to a virtual private gateway.</p>
+    async fn describe_virtual_gateways(
+        &self,
+    ) -> Result<VirtualGateways, RusotoError<DescribeVirtualGatewaysError>> {
+        self.describe_virtual_gateways_with_metadata()
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Displays all virtual interfaces for an AWS account. Virtual interfaces deleted fewer than 15 minutes before you make the request are also returned. If you specify a connection ID, only the virtual interfaces associated with the connection are returned. If you specify a virtual interface ID, then only a single virtual interface is returned.</p> <p>A virtual interface (VLAN) transmits the traffic between the AWS Direct Connect location and the customer network.</p>
+    async fn describe_virtual_interfaces(
+        &self,
+        input: DescribeVirtualInterfacesRequest,
+    ) -> Result<VirtualInterfaces, RusotoError<DescribeVirtualInterfacesError>> {
+        self.describe_virtual_interfaces_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Disassociates a connection from a link aggregation group (LAG). The connection is interrupted and re-established as a standalone connection (the connection is not deleted; to delete the connection, use the <a>DeleteConnection</a> request). If the LAG has associated virtual interfaces or hosted connections, they remain associated with the LAG. A disassociated connection owned by an AWS Direct Connect Partner is automatically converted to an interconnect.</p> <p>If disassociating the connection would cause the LAG to fall below its setting for minimum number of operational connections, the request fails, except when it's the last member of the LAG. If all connections are disassociated, the LAG continues to exist as an empty LAG with no physical connections. </p>
+    async fn disassociate_connection_from_lag(
+        &self,
+        input: DisassociateConnectionFromLagRequest,
+    ) -> Result<Connection, RusotoError<DisassociateConnectionFromLagError>> {
+        self.disassociate_connection_from_lag_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Adds the specified tags to the specified AWS Direct Connect resource. Each resource can have a maximum of 50 tags.</p> <p>Each tag consists of a key and an optional value. If a tag with the same key is already associated with the resource, this action updates its value.</p>
+    async fn tag_resource(
+        &self,
+        input: TagResourceRequest,
+    ) -> Result<TagResourceResponse, RusotoError<TagResourceError>> {
+        self.tag_resource_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Removes one or more tags from the specified AWS Direct Connect resource.</p>
+    async fn untag_resource(
+        &self,
+        input: UntagResourceRequest,
+    ) -> Result<UntagResourceResponse, RusotoError<UntagResourceError>> {
+        self.untag_resource_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Updates the specified attributes of the Direct Connect gateway association.</p> <p>Add or remove prefixes from the association.</p>
+    async fn update_direct_connect_gateway_association(
+        &self,
+        input: UpdateDirectConnectGatewayAssociationRequest,
+    ) -> Result<
+        UpdateDirectConnectGatewayAssociationResult,
+        RusotoError<UpdateDirectConnectGatewayAssociationError>,
+    > {
+        self.update_direct_connect_gateway_association_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Updates the attributes of the specified link aggregation group (LAG).</p> <p>You can update the following attributes:</p> <ul> <li> <p>The name of the LAG.</p> </li> <li> <p>The value for the minimum number of connections that must be operational for the LAG itself to be operational. </p> </li> </ul> <p>When you create a LAG, the default value for the minimum number of operational connections is zero (0). If you update this value and the number of operational connections falls below the specified value, the LAG automatically goes down to avoid over-utilization of the remaining connections. Adjust this value with care, as it could force the LAG down if it is set higher than the current number of operational connections.</p>
+    async fn update_lag(
+        &self,
+        input: UpdateLagRequest,
+    ) -> Result<Lag, RusotoError<UpdateLagError>> {
+        self.update_lag_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Updates the specified attributes of the specified virtual private interface.</p> <p>Setting the MTU of a virtual interface to 9001 (jumbo frames) can cause an update to the underlying physical connection if it wasn't updated to support jumbo frames. Updating the connection disrupts network connectivity for all virtual interfaces associated with the connection for up to 30 seconds. To check whether your connection supports jumbo frames, call <a>DescribeConnections</a>. To check whether your virtual interface supports jumbo frames, call <a>DescribeVirtualInterfaces</a>.</p>
+    async fn update_virtual_interface_attributes(
+        &self,
+        input: UpdateVirtualInterfaceAttributesRequest,
+    ) -> Result<VirtualInterface, RusotoError<UpdateVirtualInterfaceAttributesError>> {
+        self.update_virtual_interface_attributes_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
     }
 }
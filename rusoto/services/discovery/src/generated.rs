@@ -16,8 +16,8 @@ use std::fmt;
 use async_trait::async_trait;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
-use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest, ResponseMetadata};
+use rusoto_core::{Client, ErrorMetadata, RusotoError};
 
 use rusoto_core::proto;
 use rusoto_core::signature::SignedRequest;
@@ -1029,6 +1029,7 @@ pub struct UpdateApplicationResponse {}
 
 /// Errors returned by AssociateConfigurationItemsToApplication
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum AssociateConfigurationItemsToApplicationError {
     /// <p>The AWS user account does not have permission to perform the action. Check the IAM policy associated with this account.</p>
     AuthorizationError(String),
@@ -1040,6 +1041,8 @@ pub enum AssociateConfigurationItemsToApplicationError {
     InvalidParameterValue(String),
     /// <p>The server experienced an internal error. Try again.</p>
     ServerInternalError(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl AssociateConfigurationItemsToApplicationError {
@@ -1049,34 +1052,44 @@ impl AssociateConfigurationItemsToApplicationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AuthorizationErrorException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         AssociateConfigurationItemsToApplicationError::AuthorizationError(err.msg),
+                        &res,
                     )
                 }
                 "HomeRegionNotSetException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         AssociateConfigurationItemsToApplicationError::HomeRegionNotSet(err.msg),
+                        &res,
                     )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         AssociateConfigurationItemsToApplicationError::InvalidParameter(err.msg),
+                        &res,
                     )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         AssociateConfigurationItemsToApplicationError::InvalidParameterValue(
                             err.msg,
                         ),
+                        &res,
                     )
                 }
                 "ServerInternalErrorException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         AssociateConfigurationItemsToApplicationError::ServerInternalError(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        AssociateConfigurationItemsToApplicationError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -1101,12 +1114,16 @@ impl fmt::Display for AssociateConfigurationItemsToApplicationError {
             AssociateConfigurationItemsToApplicationError::ServerInternalError(ref cause) => {
                 write!(f, "{}", cause)
             }
+            AssociateConfigurationItemsToApplicationError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for AssociateConfigurationItemsToApplicationError {}
 /// Errors returned by BatchDeleteImportData
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum BatchDeleteImportDataError {
     /// <p>The AWS user account does not have permission to perform the action. Check the IAM policy associated with this account.</p>
     AuthorizationError(String),
@@ -1118,6 +1135,8 @@ pub enum BatchDeleteImportDataError {
     InvalidParameterValue(String),
     /// <p>The server experienced an internal error. Try again.</p>
     ServerInternalError(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl BatchDeleteImportDataError {
@@ -1125,32 +1144,37 @@ impl BatchDeleteImportDataError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AuthorizationErrorException" => {
-                    return RusotoError::Service(BatchDeleteImportDataError::AuthorizationError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        BatchDeleteImportDataError::AuthorizationError(err.msg),
+                        &res,
+                    )
                 }
                 "HomeRegionNotSetException" => {
-                    return RusotoError::Service(BatchDeleteImportDataError::HomeRegionNotSet(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        BatchDeleteImportDataError::HomeRegionNotSet(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(BatchDeleteImportDataError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        BatchDeleteImportDataError::InvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(BatchDeleteImportDataError::InvalidParameterValue(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        BatchDeleteImportDataError::InvalidParameterValue(err.msg),
+                        &res,
+                    )
                 }
                 "ServerInternalErrorException" => {
-                    return RusotoError::Service(BatchDeleteImportDataError::ServerInternalError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        BatchDeleteImportDataError::ServerInternalError(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(BatchDeleteImportDataError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1165,12 +1189,14 @@ impl fmt::Display for BatchDeleteImportDataError {
             BatchDeleteImportDataError::InvalidParameter(ref cause) => write!(f, "{}", cause),
             BatchDeleteImportDataError::InvalidParameterValue(ref cause) => write!(f, "{}", cause),
             BatchDeleteImportDataError::ServerInternalError(ref cause) => write!(f, "{}", cause),
+            BatchDeleteImportDataError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for BatchDeleteImportDataError {}
 /// Errors returned by CreateApplication
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateApplicationError {
     /// <p>The AWS user account does not have permission to perform the action. Check the IAM policy associated with this account.</p>
     AuthorizationError(String),
@@ -1182,6 +1208,8 @@ pub enum CreateApplicationError {
     InvalidParameterValue(String),
     /// <p>The server experienced an internal error. Try again.</p>
     ServerInternalError(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateApplicationError {
@@ -1189,28 +1217,37 @@ impl CreateApplicationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AuthorizationErrorException" => {
-                    return RusotoError::Service(CreateApplicationError::AuthorizationError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateApplicationError::AuthorizationError(err.msg),
+                        &res,
+                    )
                 }
                 "HomeRegionNotSetException" => {
-                    return RusotoError::Service(CreateApplicationError::HomeRegionNotSet(err.msg))
+                    return RusotoError::service(
+                        CreateApplicationError::HomeRegionNotSet(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(CreateApplicationError::InvalidParameter(err.msg))
+                    return RusotoError::service(
+                        CreateApplicationError::InvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(CreateApplicationError::InvalidParameterValue(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateApplicationError::InvalidParameterValue(err.msg),
+                        &res,
+                    )
                 }
                 "ServerInternalErrorException" => {
-                    return RusotoError::Service(CreateApplicationError::ServerInternalError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateApplicationError::ServerInternalError(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreateApplicationError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1225,12 +1262,14 @@ impl fmt::Display for CreateApplicationError {
             CreateApplicationError::InvalidParameter(ref cause) => write!(f, "{}", cause),
             CreateApplicationError::InvalidParameterValue(ref cause) => write!(f, "{}", cause),
             CreateApplicationError::ServerInternalError(ref cause) => write!(f, "{}", cause),
+            CreateApplicationError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateApplicationError {}
 /// Errors returned by CreateTags
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateTagsError {
     /// <p>The AWS user account does not have permission to perform the action. Check the IAM policy associated with this account.</p>
     AuthorizationError(String),
@@ -1244,6 +1283,8 @@ pub enum CreateTagsError {
     ResourceNotFound(String),
     /// <p>The server experienced an internal error. Try again.</p>
     ServerInternalError(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateTagsError {
@@ -1251,25 +1292,31 @@ impl CreateTagsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AuthorizationErrorException" => {
-                    return RusotoError::Service(CreateTagsError::AuthorizationError(err.msg))
+                    return RusotoError::service(CreateTagsError::AuthorizationError(err.msg), &res)
                 }
                 "HomeRegionNotSetException" => {
-                    return RusotoError::Service(CreateTagsError::HomeRegionNotSet(err.msg))
+                    return RusotoError::service(CreateTagsError::HomeRegionNotSet(err.msg), &res)
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(CreateTagsError::InvalidParameter(err.msg))
+                    return RusotoError::service(CreateTagsError::InvalidParameter(err.msg), &res)
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(CreateTagsError::InvalidParameterValue(err.msg))
+                    return RusotoError::service(
+                        CreateTagsError::InvalidParameterValue(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(CreateTagsError::ResourceNotFound(err.msg))
+                    return RusotoError::service(CreateTagsError::ResourceNotFound(err.msg), &res)
                 }
                 "ServerInternalErrorException" => {
-                    return RusotoError::Service(CreateTagsError::ServerInternalError(err.msg))
+                    return RusotoError::service(
+                        CreateTagsError::ServerInternalError(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreateTagsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1285,12 +1332,14 @@ impl fmt::Display for CreateTagsError {
             CreateTagsError::InvalidParameterValue(ref cause) => write!(f, "{}", cause),
             CreateTagsError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             CreateTagsError::ServerInternalError(ref cause) => write!(f, "{}", cause),
+            CreateTagsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateTagsError {}
 /// Errors returned by DeleteApplications
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteApplicationsError {
     /// <p>The AWS user account does not have permission to perform the action. Check the IAM policy associated with this account.</p>
     AuthorizationError(String),
@@ -1302,6 +1351,8 @@ pub enum DeleteApplicationsError {
     InvalidParameterValue(String),
     /// <p>The server experienced an internal error. Try again.</p>
     ServerInternalError(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteApplicationsError {
@@ -1309,28 +1360,37 @@ impl DeleteApplicationsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AuthorizationErrorException" => {
-                    return RusotoError::Service(DeleteApplicationsError::AuthorizationError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteApplicationsError::AuthorizationError(err.msg),
+                        &res,
+                    )
                 }
                 "HomeRegionNotSetException" => {
-                    return RusotoError::Service(DeleteApplicationsError::HomeRegionNotSet(err.msg))
+                    return RusotoError::service(
+                        DeleteApplicationsError::HomeRegionNotSet(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DeleteApplicationsError::InvalidParameter(err.msg))
+                    return RusotoError::service(
+                        DeleteApplicationsError::InvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(DeleteApplicationsError::InvalidParameterValue(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteApplicationsError::InvalidParameterValue(err.msg),
+                        &res,
+                    )
                 }
                 "ServerInternalErrorException" => {
-                    return RusotoError::Service(DeleteApplicationsError::ServerInternalError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteApplicationsError::ServerInternalError(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteApplicationsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1345,12 +1405,14 @@ impl fmt::Display for DeleteApplicationsError {
             DeleteApplicationsError::InvalidParameter(ref cause) => write!(f, "{}", cause),
             DeleteApplicationsError::InvalidParameterValue(ref cause) => write!(f, "{}", cause),
             DeleteApplicationsError::ServerInternalError(ref cause) => write!(f, "{}", cause),
+            DeleteApplicationsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteApplicationsError {}
 /// Errors returned by DeleteTags
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteTagsError {
     /// <p>The AWS user account does not have permission to perform the action. Check the IAM policy associated with this account.</p>
     AuthorizationError(String),
@@ -1364,6 +1426,8 @@ pub enum DeleteTagsError {
     ResourceNotFound(String),
     /// <p>The server experienced an internal error. Try again.</p>
     ServerInternalError(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteTagsError {
@@ -1371,25 +1435,31 @@ impl DeleteTagsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AuthorizationErrorException" => {
-                    return RusotoError::Service(DeleteTagsError::AuthorizationError(err.msg))
+                    return RusotoError::service(DeleteTagsError::AuthorizationError(err.msg), &res)
                 }
                 "HomeRegionNotSetException" => {
-                    return RusotoError::Service(DeleteTagsError::HomeRegionNotSet(err.msg))
+                    return RusotoError::service(DeleteTagsError::HomeRegionNotSet(err.msg), &res)
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DeleteTagsError::InvalidParameter(err.msg))
+                    return RusotoError::service(DeleteTagsError::InvalidParameter(err.msg), &res)
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(DeleteTagsError::InvalidParameterValue(err.msg))
+                    return RusotoError::service(
+                        DeleteTagsError::InvalidParameterValue(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteTagsError::ResourceNotFound(err.msg))
+                    return RusotoError::service(DeleteTagsError::ResourceNotFound(err.msg), &res)
                 }
                 "ServerInternalErrorException" => {
-                    return RusotoError::Service(DeleteTagsError::ServerInternalError(err.msg))
+                    return RusotoError::service(
+                        DeleteTagsError::ServerInternalError(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteTagsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1405,12 +1475,14 @@ impl fmt::Display for DeleteTagsError {
             DeleteTagsError::InvalidParameterValue(ref cause) => write!(f, "{}", cause),
             DeleteTagsError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             DeleteTagsError::ServerInternalError(ref cause) => write!(f, "{}", cause),
+            DeleteTagsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteTagsError {}
 /// Errors returned by DescribeAgents
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeAgentsError {
     /// <p>The AWS user account does not have permission to perform the action. Check the IAM policy associated with this account.</p>
     AuthorizationError(String),
@@ -1422,6 +1494,8 @@ pub enum DescribeAgentsError {
     InvalidParameterValue(String),
     /// <p>The server experienced an internal error. Try again.</p>
     ServerInternalError(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeAgentsError {
@@ -1429,24 +1503,37 @@ impl DescribeAgentsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AuthorizationErrorException" => {
-                    return RusotoError::Service(DescribeAgentsError::AuthorizationError(err.msg))
+                    return RusotoError::service(
+                        DescribeAgentsError::AuthorizationError(err.msg),
+                        &res,
+                    )
                 }
                 "HomeRegionNotSetException" => {
-                    return RusotoError::Service(DescribeAgentsError::HomeRegionNotSet(err.msg))
+                    return RusotoError::service(
+                        DescribeAgentsError::HomeRegionNotSet(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DescribeAgentsError::InvalidParameter(err.msg))
+                    return RusotoError::service(
+                        DescribeAgentsError::InvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(DescribeAgentsError::InvalidParameterValue(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeAgentsError::InvalidParameterValue(err.msg),
+                        &res,
+                    )
                 }
                 "ServerInternalErrorException" => {
-                    return RusotoError::Service(DescribeAgentsError::ServerInternalError(err.msg))
+                    return RusotoError::service(
+                        DescribeAgentsError::ServerInternalError(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DescribeAgentsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1461,12 +1548,14 @@ impl fmt::Display for DescribeAgentsError {
             DescribeAgentsError::InvalidParameter(ref cause) => write!(f, "{}", cause),
             DescribeAgentsError::InvalidParameterValue(ref cause) => write!(f, "{}", cause),
             DescribeAgentsError::ServerInternalError(ref cause) => write!(f, "{}", cause),
+            DescribeAgentsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeAgentsError {}
 /// Errors returned by DescribeConfigurations
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeConfigurationsError {
     /// <p>The AWS user account does not have permission to perform the action. Check the IAM policy associated with this account.</p>
     AuthorizationError(String),
@@ -1478,6 +1567,8 @@ pub enum DescribeConfigurationsError {
     InvalidParameterValue(String),
     /// <p>The server experienced an internal error. Try again.</p>
     ServerInternalError(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeConfigurationsError {
@@ -1485,32 +1576,37 @@ impl DescribeConfigurationsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AuthorizationErrorException" => {
-                    return RusotoError::Service(DescribeConfigurationsError::AuthorizationError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeConfigurationsError::AuthorizationError(err.msg),
+                        &res,
+                    )
                 }
                 "HomeRegionNotSetException" => {
-                    return RusotoError::Service(DescribeConfigurationsError::HomeRegionNotSet(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeConfigurationsError::HomeRegionNotSet(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DescribeConfigurationsError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeConfigurationsError::InvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DescribeConfigurationsError::InvalidParameterValue(err.msg),
+                        &res,
                     )
                 }
                 "ServerInternalErrorException" => {
-                    return RusotoError::Service(DescribeConfigurationsError::ServerInternalError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeConfigurationsError::ServerInternalError(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DescribeConfigurationsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1525,12 +1621,14 @@ impl fmt::Display for DescribeConfigurationsError {
             DescribeConfigurationsError::InvalidParameter(ref cause) => write!(f, "{}", cause),
             DescribeConfigurationsError::InvalidParameterValue(ref cause) => write!(f, "{}", cause),
             DescribeConfigurationsError::ServerInternalError(ref cause) => write!(f, "{}", cause),
+            DescribeConfigurationsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeConfigurationsError {}
 /// Errors returned by DescribeContinuousExports
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeContinuousExportsError {
     /// <p>The AWS user account does not have permission to perform the action. Check the IAM policy associated with this account.</p>
     AuthorizationError(String),
@@ -1546,6 +1644,8 @@ pub enum DescribeContinuousExportsError {
     ResourceNotFound(String),
     /// <p>The server experienced an internal error. Try again.</p>
     ServerInternalError(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeContinuousExportsError {
@@ -1553,42 +1653,51 @@ impl DescribeContinuousExportsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AuthorizationErrorException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DescribeContinuousExportsError::AuthorizationError(err.msg),
+                        &res,
                     )
                 }
                 "HomeRegionNotSetException" => {
-                    return RusotoError::Service(DescribeContinuousExportsError::HomeRegionNotSet(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeContinuousExportsError::HomeRegionNotSet(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DescribeContinuousExportsError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeContinuousExportsError::InvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DescribeContinuousExportsError::InvalidParameterValue(err.msg),
+                        &res,
                     )
                 }
                 "OperationNotPermittedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DescribeContinuousExportsError::OperationNotPermitted(err.msg),
+                        &res,
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeContinuousExportsError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeContinuousExportsError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ServerInternalErrorException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DescribeContinuousExportsError::ServerInternalError(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(DescribeContinuousExportsError::Unhandled, &res)
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -1611,12 +1720,14 @@ impl fmt::Display for DescribeContinuousExportsError {
             DescribeContinuousExportsError::ServerInternalError(ref cause) => {
                 write!(f, "{}", cause)
             }
+            DescribeContinuousExportsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeContinuousExportsError {}
 /// Errors returned by DescribeExportConfigurations
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeExportConfigurationsError {
     /// <p>The AWS user account does not have permission to perform the action. Check the IAM policy associated with this account.</p>
     AuthorizationError(String),
@@ -1630,6 +1741,8 @@ pub enum DescribeExportConfigurationsError {
     ResourceNotFound(String),
     /// <p>The server experienced an internal error. Try again.</p>
     ServerInternalError(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeExportConfigurationsError {
@@ -1639,37 +1752,48 @@ impl DescribeExportConfigurationsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AuthorizationErrorException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DescribeExportConfigurationsError::AuthorizationError(err.msg),
+                        &res,
                     )
                 }
                 "HomeRegionNotSetException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DescribeExportConfigurationsError::HomeRegionNotSet(err.msg),
+                        &res,
                     )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DescribeExportConfigurationsError::InvalidParameter(err.msg),
+                        &res,
                     )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DescribeExportConfigurationsError::InvalidParameterValue(err.msg),
+                        &res,
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DescribeExportConfigurationsError::ResourceNotFound(err.msg),
+                        &res,
                     )
                 }
                 "ServerInternalErrorException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DescribeExportConfigurationsError::ServerInternalError(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        DescribeExportConfigurationsError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -1697,12 +1821,14 @@ impl fmt::Display for DescribeExportConfigurationsError {
             DescribeExportConfigurationsError::ServerInternalError(ref cause) => {
                 write!(f, "{}", cause)
             }
+            DescribeExportConfigurationsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeExportConfigurationsError {}
 /// Errors returned by DescribeExportTasks
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeExportTasksError {
     /// <p>The AWS user account does not have permission to perform the action. Check the IAM policy associated with this account.</p>
     AuthorizationError(String),
@@ -1714,6 +1840,8 @@ pub enum DescribeExportTasksError {
     InvalidParameterValue(String),
     /// <p>The server experienced an internal error. Try again.</p>
     ServerInternalError(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeExportTasksError {
@@ -1721,32 +1849,37 @@ impl DescribeExportTasksError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AuthorizationErrorException" => {
-                    return RusotoError::Service(DescribeExportTasksError::AuthorizationError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeExportTasksError::AuthorizationError(err.msg),
+                        &res,
+                    )
                 }
                 "HomeRegionNotSetException" => {
-                    return RusotoError::Service(DescribeExportTasksError::HomeRegionNotSet(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeExportTasksError::HomeRegionNotSet(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DescribeExportTasksError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeExportTasksError::InvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(DescribeExportTasksError::InvalidParameterValue(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeExportTasksError::InvalidParameterValue(err.msg),
+                        &res,
+                    )
                 }
                 "ServerInternalErrorException" => {
-                    return RusotoError::Service(DescribeExportTasksError::ServerInternalError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeExportTasksError::ServerInternalError(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DescribeExportTasksError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1761,12 +1894,14 @@ impl fmt::Display for DescribeExportTasksError {
             DescribeExportTasksError::InvalidParameter(ref cause) => write!(f, "{}", cause),
             DescribeExportTasksError::InvalidParameterValue(ref cause) => write!(f, "{}", cause),
             DescribeExportTasksError::ServerInternalError(ref cause) => write!(f, "{}", cause),
+            DescribeExportTasksError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeExportTasksError {}
 /// Errors returned by DescribeImportTasks
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeImportTasksError {
     /// <p>The AWS user account does not have permission to perform the action. Check the IAM policy associated with this account.</p>
     AuthorizationError(String),
@@ -1778,6 +1913,8 @@ pub enum DescribeImportTasksError {
     InvalidParameterValue(String),
     /// <p>The server experienced an internal error. Try again.</p>
     ServerInternalError(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeImportTasksError {
@@ -1785,32 +1922,37 @@ impl DescribeImportTasksError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AuthorizationErrorException" => {
-                    return RusotoError::Service(DescribeImportTasksError::AuthorizationError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeImportTasksError::AuthorizationError(err.msg),
+                        &res,
+                    )
                 }
                 "HomeRegionNotSetException" => {
-                    return RusotoError::Service(DescribeImportTasksError::HomeRegionNotSet(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeImportTasksError::HomeRegionNotSet(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DescribeImportTasksError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeImportTasksError::InvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(DescribeImportTasksError::InvalidParameterValue(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeImportTasksError::InvalidParameterValue(err.msg),
+                        &res,
+                    )
                 }
                 "ServerInternalErrorException" => {
-                    return RusotoError::Service(DescribeImportTasksError::ServerInternalError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeImportTasksError::ServerInternalError(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DescribeImportTasksError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1825,12 +1967,14 @@ impl fmt::Display for DescribeImportTasksError {
             DescribeImportTasksError::InvalidParameter(ref cause) => write!(f, "{}", cause),
             DescribeImportTasksError::InvalidParameterValue(ref cause) => write!(f, "{}", cause),
             DescribeImportTasksError::ServerInternalError(ref cause) => write!(f, "{}", cause),
+            DescribeImportTasksError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeImportTasksError {}
 /// Errors returned by DescribeTags
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeTagsError {
     /// <p>The AWS user account does not have permission to perform the action. Check the IAM policy associated with this account.</p>
     AuthorizationError(String),
@@ -1844,6 +1988,8 @@ pub enum DescribeTagsError {
     ResourceNotFound(String),
     /// <p>The server experienced an internal error. Try again.</p>
     ServerInternalError(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeTagsError {
@@ -1851,25 +1997,34 @@ impl DescribeTagsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AuthorizationErrorException" => {
-                    return RusotoError::Service(DescribeTagsError::AuthorizationError(err.msg))
+                    return RusotoError::service(
+                        DescribeTagsError::AuthorizationError(err.msg),
+                        &res,
+                    )
                 }
                 "HomeRegionNotSetException" => {
-                    return RusotoError::Service(DescribeTagsError::HomeRegionNotSet(err.msg))
+                    return RusotoError::service(DescribeTagsError::HomeRegionNotSet(err.msg), &res)
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DescribeTagsError::InvalidParameter(err.msg))
+                    return RusotoError::service(DescribeTagsError::InvalidParameter(err.msg), &res)
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(DescribeTagsError::InvalidParameterValue(err.msg))
+                    return RusotoError::service(
+                        DescribeTagsError::InvalidParameterValue(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeTagsError::ResourceNotFound(err.msg))
+                    return RusotoError::service(DescribeTagsError::ResourceNotFound(err.msg), &res)
                 }
                 "ServerInternalErrorException" => {
-                    return RusotoError::Service(DescribeTagsError::ServerInternalError(err.msg))
+                    return RusotoError::service(
+                        DescribeTagsError::ServerInternalError(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DescribeTagsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1885,12 +2040,14 @@ impl fmt::Display for DescribeTagsError {
             DescribeTagsError::InvalidParameterValue(ref cause) => write!(f, "{}", cause),
             DescribeTagsError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             DescribeTagsError::ServerInternalError(ref cause) => write!(f, "{}", cause),
+            DescribeTagsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeTagsError {}
 /// Errors returned by DisassociateConfigurationItemsFromApplication
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DisassociateConfigurationItemsFromApplicationError {
     /// <p>The AWS user account does not have permission to perform the action. Check the IAM policy associated with this account.</p>
     AuthorizationError(String),
@@ -1902,6 +2059,8 @@ pub enum DisassociateConfigurationItemsFromApplicationError {
     InvalidParameterValue(String),
     /// <p>The server experienced an internal error. Try again.</p>
     ServerInternalError(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DisassociateConfigurationItemsFromApplicationError {
@@ -1911,42 +2070,52 @@ impl DisassociateConfigurationItemsFromApplicationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AuthorizationErrorException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DisassociateConfigurationItemsFromApplicationError::AuthorizationError(
                             err.msg,
                         ),
+                        &res,
                     )
                 }
                 "HomeRegionNotSetException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DisassociateConfigurationItemsFromApplicationError::HomeRegionNotSet(
                             err.msg,
                         ),
+                        &res,
                     )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DisassociateConfigurationItemsFromApplicationError::InvalidParameter(
                             err.msg,
                         ),
+                        &res,
                     )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DisassociateConfigurationItemsFromApplicationError::InvalidParameterValue(
                             err.msg,
                         ),
+                        &res,
                     )
                 }
                 "ServerInternalErrorException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DisassociateConfigurationItemsFromApplicationError::ServerInternalError(
                             err.msg,
                         ),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        DisassociateConfigurationItemsFromApplicationError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -1971,12 +2140,16 @@ impl fmt::Display for DisassociateConfigurationItemsFromApplicationError {
             DisassociateConfigurationItemsFromApplicationError::ServerInternalError(ref cause) => {
                 write!(f, "{}", cause)
             }
+            DisassociateConfigurationItemsFromApplicationError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for DisassociateConfigurationItemsFromApplicationError {}
 /// Errors returned by ExportConfigurations
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ExportConfigurationsError {
     /// <p>The AWS user account does not have permission to perform the action. Check the IAM policy associated with this account.</p>
     AuthorizationError(String),
@@ -1990,6 +2163,8 @@ pub enum ExportConfigurationsError {
     OperationNotPermitted(String),
     /// <p>The server experienced an internal error. Try again.</p>
     ServerInternalError(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ExportConfigurationsError {
@@ -1997,37 +2172,43 @@ impl ExportConfigurationsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AuthorizationErrorException" => {
-                    return RusotoError::Service(ExportConfigurationsError::AuthorizationError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ExportConfigurationsError::AuthorizationError(err.msg),
+                        &res,
+                    )
                 }
                 "HomeRegionNotSetException" => {
-                    return RusotoError::Service(ExportConfigurationsError::HomeRegionNotSet(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ExportConfigurationsError::HomeRegionNotSet(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(ExportConfigurationsError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ExportConfigurationsError::InvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(ExportConfigurationsError::InvalidParameterValue(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ExportConfigurationsError::InvalidParameterValue(err.msg),
+                        &res,
+                    )
                 }
                 "OperationNotPermittedException" => {
-                    return RusotoError::Service(ExportConfigurationsError::OperationNotPermitted(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ExportConfigurationsError::OperationNotPermitted(err.msg),
+                        &res,
+                    )
                 }
                 "ServerInternalErrorException" => {
-                    return RusotoError::Service(ExportConfigurationsError::ServerInternalError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ExportConfigurationsError::ServerInternalError(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ExportConfigurationsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2043,12 +2224,14 @@ impl fmt::Display for ExportConfigurationsError {
             ExportConfigurationsError::InvalidParameterValue(ref cause) => write!(f, "{}", cause),
             ExportConfigurationsError::OperationNotPermitted(ref cause) => write!(f, "{}", cause),
             ExportConfigurationsError::ServerInternalError(ref cause) => write!(f, "{}", cause),
+            ExportConfigurationsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ExportConfigurationsError {}
 /// Errors returned by GetDiscoverySummary
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetDiscoverySummaryError {
     /// <p>The AWS user account does not have permission to perform the action. Check the IAM policy associated with this account.</p>
     AuthorizationError(String),
@@ -2060,6 +2243,8 @@ pub enum GetDiscoverySummaryError {
     InvalidParameterValue(String),
     /// <p>The server experienced an internal error. Try again.</p>
     ServerInternalError(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetDiscoverySummaryError {
@@ -2067,32 +2252,37 @@ impl GetDiscoverySummaryError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AuthorizationErrorException" => {
-                    return RusotoError::Service(GetDiscoverySummaryError::AuthorizationError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetDiscoverySummaryError::AuthorizationError(err.msg),
+                        &res,
+                    )
                 }
                 "HomeRegionNotSetException" => {
-                    return RusotoError::Service(GetDiscoverySummaryError::HomeRegionNotSet(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetDiscoverySummaryError::HomeRegionNotSet(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(GetDiscoverySummaryError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetDiscoverySummaryError::InvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(GetDiscoverySummaryError::InvalidParameterValue(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetDiscoverySummaryError::InvalidParameterValue(err.msg),
+                        &res,
+                    )
                 }
                 "ServerInternalErrorException" => {
-                    return RusotoError::Service(GetDiscoverySummaryError::ServerInternalError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetDiscoverySummaryError::ServerInternalError(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetDiscoverySummaryError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2107,12 +2297,14 @@ impl fmt::Display for GetDiscoverySummaryError {
             GetDiscoverySummaryError::InvalidParameter(ref cause) => write!(f, "{}", cause),
             GetDiscoverySummaryError::InvalidParameterValue(ref cause) => write!(f, "{}", cause),
             GetDiscoverySummaryError::ServerInternalError(ref cause) => write!(f, "{}", cause),
+            GetDiscoverySummaryError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetDiscoverySummaryError {}
 /// Errors returned by ListConfigurations
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListConfigurationsError {
     /// <p>The AWS user account does not have permission to perform the action. Check the IAM policy associated with this account.</p>
     AuthorizationError(String),
@@ -2126,6 +2318,8 @@ pub enum ListConfigurationsError {
     ResourceNotFound(String),
     /// <p>The server experienced an internal error. Try again.</p>
     ServerInternalError(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListConfigurationsError {
@@ -2133,31 +2327,43 @@ impl ListConfigurationsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AuthorizationErrorException" => {
-                    return RusotoError::Service(ListConfigurationsError::AuthorizationError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListConfigurationsError::AuthorizationError(err.msg),
+                        &res,
+                    )
                 }
                 "HomeRegionNotSetException" => {
-                    return RusotoError::Service(ListConfigurationsError::HomeRegionNotSet(err.msg))
+                    return RusotoError::service(
+                        ListConfigurationsError::HomeRegionNotSet(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(ListConfigurationsError::InvalidParameter(err.msg))
+                    return RusotoError::service(
+                        ListConfigurationsError::InvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(ListConfigurationsError::InvalidParameterValue(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListConfigurationsError::InvalidParameterValue(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListConfigurationsError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        ListConfigurationsError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ServerInternalErrorException" => {
-                    return RusotoError::Service(ListConfigurationsError::ServerInternalError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListConfigurationsError::ServerInternalError(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListConfigurationsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2173,12 +2379,14 @@ impl fmt::Display for ListConfigurationsError {
             ListConfigurationsError::InvalidParameterValue(ref cause) => write!(f, "{}", cause),
             ListConfigurationsError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             ListConfigurationsError::ServerInternalError(ref cause) => write!(f, "{}", cause),
+            ListConfigurationsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListConfigurationsError {}
 /// Errors returned by ListServerNeighbors
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListServerNeighborsError {
     /// <p>The AWS user account does not have permission to perform the action. Check the IAM policy associated with this account.</p>
     AuthorizationError(String),
@@ -2190,6 +2398,8 @@ pub enum ListServerNeighborsError {
     InvalidParameterValue(String),
     /// <p>The server experienced an internal error. Try again.</p>
     ServerInternalError(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListServerNeighborsError {
@@ -2197,32 +2407,37 @@ impl ListServerNeighborsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AuthorizationErrorException" => {
-                    return RusotoError::Service(ListServerNeighborsError::AuthorizationError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListServerNeighborsError::AuthorizationError(err.msg),
+                        &res,
+                    )
                 }
                 "HomeRegionNotSetException" => {
-                    return RusotoError::Service(ListServerNeighborsError::HomeRegionNotSet(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListServerNeighborsError::HomeRegionNotSet(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(ListServerNeighborsError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListServerNeighborsError::InvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(ListServerNeighborsError::InvalidParameterValue(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListServerNeighborsError::InvalidParameterValue(err.msg),
+                        &res,
+                    )
                 }
                 "ServerInternalErrorException" => {
-                    return RusotoError::Service(ListServerNeighborsError::ServerInternalError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListServerNeighborsError::ServerInternalError(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListServerNeighborsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2237,12 +2452,14 @@ impl fmt::Display for ListServerNeighborsError {
             ListServerNeighborsError::InvalidParameter(ref cause) => write!(f, "{}", cause),
             ListServerNeighborsError::InvalidParameterValue(ref cause) => write!(f, "{}", cause),
             ListServerNeighborsError::ServerInternalError(ref cause) => write!(f, "{}", cause),
+            ListServerNeighborsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListServerNeighborsError {}
 /// Errors returned by StartContinuousExport
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum StartContinuousExportError {
     /// <p>The AWS user account does not have permission to perform the action. Check the IAM policy associated with this account.</p>
     AuthorizationError(String),
@@ -2260,6 +2477,8 @@ pub enum StartContinuousExportError {
     ResourceInUse(String),
     /// <p>The server experienced an internal error. Try again.</p>
     ServerInternalError(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl StartContinuousExportError {
@@ -2267,43 +2486,55 @@ impl StartContinuousExportError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AuthorizationErrorException" => {
-                    return RusotoError::Service(StartContinuousExportError::AuthorizationError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        StartContinuousExportError::AuthorizationError(err.msg),
+                        &res,
+                    )
                 }
                 "ConflictErrorException" => {
-                    return RusotoError::Service(StartContinuousExportError::ConflictError(err.msg))
+                    return RusotoError::service(
+                        StartContinuousExportError::ConflictError(err.msg),
+                        &res,
+                    )
                 }
                 "HomeRegionNotSetException" => {
-                    return RusotoError::Service(StartContinuousExportError::HomeRegionNotSet(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        StartContinuousExportError::HomeRegionNotSet(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(StartContinuousExportError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        StartContinuousExportError::InvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(StartContinuousExportError::InvalidParameterValue(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        StartContinuousExportError::InvalidParameterValue(err.msg),
+                        &res,
+                    )
                 }
                 "OperationNotPermittedException" => {
-                    return RusotoError::Service(StartContinuousExportError::OperationNotPermitted(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        StartContinuousExportError::OperationNotPermitted(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(StartContinuousExportError::ResourceInUse(err.msg))
+                    return RusotoError::service(
+                        StartContinuousExportError::ResourceInUse(err.msg),
+                        &res,
+                    )
                 }
                 "ServerInternalErrorException" => {
-                    return RusotoError::Service(StartContinuousExportError::ServerInternalError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        StartContinuousExportError::ServerInternalError(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(StartContinuousExportError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2321,12 +2552,14 @@ impl fmt::Display for StartContinuousExportError {
             StartContinuousExportError::OperationNotPermitted(ref cause) => write!(f, "{}", cause),
             StartContinuousExportError::ResourceInUse(ref cause) => write!(f, "{}", cause),
             StartContinuousExportError::ServerInternalError(ref cause) => write!(f, "{}", cause),
+            StartContinuousExportError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for StartContinuousExportError {}
 /// Errors returned by StartDataCollectionByAgentIds
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum StartDataCollectionByAgentIdsError {
     /// <p>The AWS user account does not have permission to perform the action. Check the IAM policy associated with this account.</p>
     AuthorizationError(String),
@@ -2338,6 +2571,8 @@ pub enum StartDataCollectionByAgentIdsError {
     InvalidParameterValue(String),
     /// <p>The server experienced an internal error. Try again.</p>
     ServerInternalError(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl StartDataCollectionByAgentIdsError {
@@ -2347,32 +2582,42 @@ impl StartDataCollectionByAgentIdsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AuthorizationErrorException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         StartDataCollectionByAgentIdsError::AuthorizationError(err.msg),
+                        &res,
                     )
                 }
                 "HomeRegionNotSetException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         StartDataCollectionByAgentIdsError::HomeRegionNotSet(err.msg),
+                        &res,
                     )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         StartDataCollectionByAgentIdsError::InvalidParameter(err.msg),
+                        &res,
                     )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         StartDataCollectionByAgentIdsError::InvalidParameterValue(err.msg),
+                        &res,
                     )
                 }
                 "ServerInternalErrorException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         StartDataCollectionByAgentIdsError::ServerInternalError(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        StartDataCollectionByAgentIdsError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -2397,12 +2642,16 @@ impl fmt::Display for StartDataCollectionByAgentIdsError {
             StartDataCollectionByAgentIdsError::ServerInternalError(ref cause) => {
                 write!(f, "{}", cause)
             }
+            StartDataCollectionByAgentIdsError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for StartDataCollectionByAgentIdsError {}
 /// Errors returned by StartExportTask
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum StartExportTaskError {
     /// <p>The AWS user account does not have permission to perform the action. Check the IAM policy associated with this account.</p>
     AuthorizationError(String),
@@ -2416,6 +2665,8 @@ pub enum StartExportTaskError {
     OperationNotPermitted(String),
     /// <p>The server experienced an internal error. Try again.</p>
     ServerInternalError(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl StartExportTaskError {
@@ -2423,29 +2674,43 @@ impl StartExportTaskError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AuthorizationErrorException" => {
-                    return RusotoError::Service(StartExportTaskError::AuthorizationError(err.msg))
+                    return RusotoError::service(
+                        StartExportTaskError::AuthorizationError(err.msg),
+                        &res,
+                    )
                 }
                 "HomeRegionNotSetException" => {
-                    return RusotoError::Service(StartExportTaskError::HomeRegionNotSet(err.msg))
+                    return RusotoError::service(
+                        StartExportTaskError::HomeRegionNotSet(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(StartExportTaskError::InvalidParameter(err.msg))
+                    return RusotoError::service(
+                        StartExportTaskError::InvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(StartExportTaskError::InvalidParameterValue(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        StartExportTaskError::InvalidParameterValue(err.msg),
+                        &res,
+                    )
                 }
                 "OperationNotPermittedException" => {
-                    return RusotoError::Service(StartExportTaskError::OperationNotPermitted(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        StartExportTaskError::OperationNotPermitted(err.msg),
+                        &res,
+                    )
                 }
                 "ServerInternalErrorException" => {
-                    return RusotoError::Service(StartExportTaskError::ServerInternalError(err.msg))
+                    return RusotoError::service(
+                        StartExportTaskError::ServerInternalError(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(StartExportTaskError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2461,12 +2726,14 @@ impl fmt::Display for StartExportTaskError {
             StartExportTaskError::InvalidParameterValue(ref cause) => write!(f, "{}", cause),
             StartExportTaskError::OperationNotPermitted(ref cause) => write!(f, "{}", cause),
             StartExportTaskError::ServerInternalError(ref cause) => write!(f, "{}", cause),
+            StartExportTaskError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for StartExportTaskError {}
 /// Errors returned by StartImportTask
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum StartImportTaskError {
     /// <p>The AWS user account does not have permission to perform the action. Check the IAM policy associated with this account.</p>
     AuthorizationError(String),
@@ -2480,6 +2747,8 @@ pub enum StartImportTaskError {
     ResourceInUse(String),
     /// <p>The server experienced an internal error. Try again.</p>
     ServerInternalError(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl StartImportTaskError {
@@ -2487,27 +2756,40 @@ impl StartImportTaskError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AuthorizationErrorException" => {
-                    return RusotoError::Service(StartImportTaskError::AuthorizationError(err.msg))
+                    return RusotoError::service(
+                        StartImportTaskError::AuthorizationError(err.msg),
+                        &res,
+                    )
                 }
                 "HomeRegionNotSetException" => {
-                    return RusotoError::Service(StartImportTaskError::HomeRegionNotSet(err.msg))
+                    return RusotoError::service(
+                        StartImportTaskError::HomeRegionNotSet(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(StartImportTaskError::InvalidParameter(err.msg))
+                    return RusotoError::service(
+                        StartImportTaskError::InvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(StartImportTaskError::InvalidParameterValue(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        StartImportTaskError::InvalidParameterValue(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(StartImportTaskError::ResourceInUse(err.msg))
+                    return RusotoError::service(StartImportTaskError::ResourceInUse(err.msg), &res)
                 }
                 "ServerInternalErrorException" => {
-                    return RusotoError::Service(StartImportTaskError::ServerInternalError(err.msg))
+                    return RusotoError::service(
+                        StartImportTaskError::ServerInternalError(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(StartImportTaskError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2523,12 +2805,14 @@ impl fmt::Display for StartImportTaskError {
             StartImportTaskError::InvalidParameterValue(ref cause) => write!(f, "{}", cause),
             StartImportTaskError::ResourceInUse(ref cause) => write!(f, "{}", cause),
             StartImportTaskError::ServerInternalError(ref cause) => write!(f, "{}", cause),
+            StartImportTaskError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for StartImportTaskError {}
 /// Errors returned by StopContinuousExport
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum StopContinuousExportError {
     /// <p>The AWS user account does not have permission to perform the action. Check the IAM policy associated with this account.</p>
     AuthorizationError(String),
@@ -2546,6 +2830,8 @@ pub enum StopContinuousExportError {
     ResourceNotFound(String),
     /// <p>The server experienced an internal error. Try again.</p>
     ServerInternalError(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl StopContinuousExportError {
@@ -2553,45 +2839,55 @@ impl StopContinuousExportError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AuthorizationErrorException" => {
-                    return RusotoError::Service(StopContinuousExportError::AuthorizationError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        StopContinuousExportError::AuthorizationError(err.msg),
+                        &res,
+                    )
                 }
                 "HomeRegionNotSetException" => {
-                    return RusotoError::Service(StopContinuousExportError::HomeRegionNotSet(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        StopContinuousExportError::HomeRegionNotSet(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(StopContinuousExportError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        StopContinuousExportError::InvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(StopContinuousExportError::InvalidParameterValue(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        StopContinuousExportError::InvalidParameterValue(err.msg),
+                        &res,
+                    )
                 }
                 "OperationNotPermittedException" => {
-                    return RusotoError::Service(StopContinuousExportError::OperationNotPermitted(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        StopContinuousExportError::OperationNotPermitted(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(StopContinuousExportError::ResourceInUse(err.msg))
+                    return RusotoError::service(
+                        StopContinuousExportError::ResourceInUse(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(StopContinuousExportError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        StopContinuousExportError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ServerInternalErrorException" => {
-                    return RusotoError::Service(StopContinuousExportError::ServerInternalError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        StopContinuousExportError::ServerInternalError(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(StopContinuousExportError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2609,12 +2905,14 @@ impl fmt::Display for StopContinuousExportError {
             StopContinuousExportError::ResourceInUse(ref cause) => write!(f, "{}", cause),
             StopContinuousExportError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             StopContinuousExportError::ServerInternalError(ref cause) => write!(f, "{}", cause),
+            StopContinuousExportError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for StopContinuousExportError {}
 /// Errors returned by StopDataCollectionByAgentIds
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum StopDataCollectionByAgentIdsError {
     /// <p>The AWS user account does not have permission to perform the action. Check the IAM policy associated with this account.</p>
     AuthorizationError(String),
@@ -2626,6 +2924,8 @@ pub enum StopDataCollectionByAgentIdsError {
     InvalidParameterValue(String),
     /// <p>The server experienced an internal error. Try again.</p>
     ServerInternalError(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl StopDataCollectionByAgentIdsError {
@@ -2635,32 +2935,42 @@ impl StopDataCollectionByAgentIdsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AuthorizationErrorException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         StopDataCollectionByAgentIdsError::AuthorizationError(err.msg),
+                        &res,
                     )
                 }
                 "HomeRegionNotSetException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         StopDataCollectionByAgentIdsError::HomeRegionNotSet(err.msg),
+                        &res,
                     )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         StopDataCollectionByAgentIdsError::InvalidParameter(err.msg),
+                        &res,
                     )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         StopDataCollectionByAgentIdsError::InvalidParameterValue(err.msg),
+                        &res,
                     )
                 }
                 "ServerInternalErrorException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         StopDataCollectionByAgentIdsError::ServerInternalError(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        StopDataCollectionByAgentIdsError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -2685,12 +2995,14 @@ impl fmt::Display for StopDataCollectionByAgentIdsError {
             StopDataCollectionByAgentIdsError::ServerInternalError(ref cause) => {
                 write!(f, "{}", cause)
             }
+            StopDataCollectionByAgentIdsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for StopDataCollectionByAgentIdsError {}
 /// Errors returned by UpdateApplication
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdateApplicationError {
     /// <p>The AWS user account does not have permission to perform the action. Check the IAM policy associated with this account.</p>
     AuthorizationError(String),
@@ -2702,6 +3014,8 @@ pub enum UpdateApplicationError {
     InvalidParameterValue(String),
     /// <p>The server experienced an internal error. Try again.</p>
     ServerInternalError(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdateApplicationError {
@@ -2709,28 +3023,37 @@ impl UpdateApplicationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AuthorizationErrorException" => {
-                    return RusotoError::Service(UpdateApplicationError::AuthorizationError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateApplicationError::AuthorizationError(err.msg),
+                        &res,
+                    )
                 }
                 "HomeRegionNotSetException" => {
-                    return RusotoError::Service(UpdateApplicationError::HomeRegionNotSet(err.msg))
+                    return RusotoError::service(
+                        UpdateApplicationError::HomeRegionNotSet(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(UpdateApplicationError::InvalidParameter(err.msg))
+                    return RusotoError::service(
+                        UpdateApplicationError::InvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(UpdateApplicationError::InvalidParameterValue(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateApplicationError::InvalidParameterValue(err.msg),
+                        &res,
+                    )
                 }
                 "ServerInternalErrorException" => {
-                    return RusotoError::Service(UpdateApplicationError::ServerInternalError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateApplicationError::ServerInternalError(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UpdateApplicationError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2745,6 +3068,7 @@ impl fmt::Display for UpdateApplicationError {
             UpdateApplicationError::InvalidParameter(ref cause) => write!(f, "{}", cause),
             UpdateApplicationError::InvalidParameterValue(ref cause) => write!(f, "{}", cause),
             UpdateApplicationError::ServerInternalError(ref cause) => write!(f, "{}", cause),
+            UpdateApplicationError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
@@ -2919,6 +3243,7 @@ impl DiscoveryClient {
     /// Creates a client backed by the default tokio event loop.
     ///
     /// The client will use the default credentials provider and tls client.
+    #[cfg(not(target_arch = "wasm32"))]
     pub fn new(region: region::Region) -> DiscoveryClient {
         DiscoveryClient {
             client: Client::shared(),
@@ -2944,19 +3269,76 @@ impl DiscoveryClient {
     pub fn new_with_client(client: Client, region: region::Region) -> DiscoveryClient {
         DiscoveryClient { client, region }
     }
+
+    /// Returns a builder of a client, setting any of its region, credentials provider,
+    /// dispatcher, retry policy, endpoint and timeouts.
+    pub fn builder() -> rusoto_core::ClientBuilder<DiscoveryClient> {
+        rusoto_core::ClientBuilder::new(DiscoveryClient::new_with_client)
+    }
+
+    /// Returns a clone of this client with the overrides of `config` applied to its
+    /// operations, sharing its connections.
+    pub fn customize(&self, config: &rusoto_core::OperationConfig) -> DiscoveryClient {
+        DiscoveryClient {
+            client: self.client.customize(config),
+            region: config.resolve_region(&self.region),
+        }
+    }
 }
 
-#[async_trait]
-impl Discovery for DiscoveryClient {
-    /// <p>Associates one or more configuration items with an application.</p>
-    async fn associate_configuration_items_to_application(
+impl DiscoveryClient {
+    /// Calls `associate_configuration_items_to_application`, also returning the metadata of the response.
+    pub async fn associate_configuration_items_to_application_with_metadata(
         &self,
         input: AssociateConfigurationItemsToApplicationRequest,
     ) -> Result<
-        AssociateConfigurationItemsToApplicationResponse,
+        (
+            AssociateConfigurationItemsToApplicationResponse,
+            ResponseMetadata,
+        ),
         RusotoError<AssociateConfigurationItemsToApplicationError>,
     > {
+        let request = self.build_associate_configuration_items_to_application_request(input);
+
+        let mut response = self
+            .client
+            .sign_and_dispatch(request)
+            .await
+            .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
+        if response.status.is_success() {
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<AssociateConfigurationItemsToApplicationResponse, _>()?;
+            Ok((result, metadata))
+        } else {
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(AssociateConfigurationItemsToApplicationError::from_response(response))
+        }
+    }
+
+    /// Presigns the request `associate_configuration_items_to_application` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_associate_configuration_items_to_application(
+        &self,
+        presigner: &rusoto_core::Presigner,
+        input: AssociateConfigurationItemsToApplicationRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_associate_configuration_items_to_application_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_associate_configuration_items_to_application_request(
+        &self,
+        input: AssociateConfigurationItemsToApplicationRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "discovery", &self.region, "/");
+        request.set_operation("AssociateConfigurationItemsToApplication");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -2966,28 +3348,58 @@ impl Discovery for DiscoveryClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `batch_delete_import_data`, also returning the metadata of the response.
+    pub async fn batch_delete_import_data_with_metadata(
+        &self,
+        input: BatchDeleteImportDataRequest,
+    ) -> Result<
+        (BatchDeleteImportDataResponse, ResponseMetadata),
+        RusotoError<BatchDeleteImportDataError>,
+    > {
+        let request = self.build_batch_delete_import_data_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<AssociateConfigurationItemsToApplicationResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<BatchDeleteImportDataResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(AssociateConfigurationItemsToApplicationError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(BatchDeleteImportDataError::from_response(response))
         }
     }
 
-    /// <p>Deletes one or more import tasks, each identified by their import ID. Each import task has a number of records that can identify servers or applications. </p> <p>AWS Application Discovery Service has built-in matching logic that will identify when discovered servers match existing entries that you've previously discovered, the information for the already-existing discovered server is updated. When you delete an import task that contains records that were used to match, the information in those matched records that comes from the deleted records will also be deleted.</p>
-    async fn batch_delete_import_data(
+    /// Presigns the request `batch_delete_import_data` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_batch_delete_import_data(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: BatchDeleteImportDataRequest,
-    ) -> Result<BatchDeleteImportDataResponse, RusotoError<BatchDeleteImportDataError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_batch_delete_import_data_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_batch_delete_import_data_request(
+        &self,
+        input: BatchDeleteImportDataRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "discovery", &self.region, "/");
+        request.set_operation("BatchDeleteImportData");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -2997,28 +3409,53 @@ impl Discovery for DiscoveryClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `create_application`, also returning the metadata of the response.
+    pub async fn create_application_with_metadata(
+        &self,
+        input: CreateApplicationRequest,
+    ) -> Result<(CreateApplicationResponse, ResponseMetadata), RusotoError<CreateApplicationError>>
+    {
+        let request = self.build_create_application_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<BatchDeleteImportDataResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<CreateApplicationResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(BatchDeleteImportDataError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(CreateApplicationError::from_response(response))
         }
     }
 
-    /// <p>Creates an application with the given name and description.</p>
-    async fn create_application(
+    /// Presigns the request `create_application` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_application(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: CreateApplicationRequest,
-    ) -> Result<CreateApplicationResponse, RusotoError<CreateApplicationError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_application_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_application_request(&self, input: CreateApplicationRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "discovery", &self.region, "/");
+        request.set_operation("CreateApplication");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -3028,55 +3465,105 @@ impl Discovery for DiscoveryClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `create_tags`, also returning the metadata of the response.
+    pub async fn create_tags_with_metadata(
+        &self,
+        input: CreateTagsRequest,
+    ) -> Result<(CreateTagsResponse, ResponseMetadata), RusotoError<CreateTagsError>> {
+        let request = self.build_create_tags_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<CreateApplicationResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<CreateTagsResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(CreateApplicationError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(CreateTagsError::from_response(response))
         }
     }
 
-    /// <p>Creates one or more tags for configuration items. Tags are metadata that help you categorize IT assets. This API accepts a list of multiple configuration items.</p>
-    async fn create_tags(
+    /// Presigns the request `create_tags` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_tags(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: CreateTagsRequest,
-    ) -> Result<CreateTagsResponse, RusotoError<CreateTagsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_tags_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_tags_request(&self, input: CreateTagsRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "discovery", &self.region, "/");
+        request.set_operation("CreateTags");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSPoseidonService_V2015_11_01.CreateTags");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `delete_applications`, also returning the metadata of the response.
+    pub async fn delete_applications_with_metadata(
+        &self,
+        input: DeleteApplicationsRequest,
+    ) -> Result<(DeleteApplicationsResponse, ResponseMetadata), RusotoError<DeleteApplicationsError>>
+    {
+        let request = self.build_delete_applications_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<CreateTagsResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DeleteApplicationsResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(CreateTagsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DeleteApplicationsError::from_response(response))
         }
     }
 
-    /// <p>Deletes a list of applications and their associations with configuration items.</p>
-    async fn delete_applications(
+    /// Presigns the request `delete_applications` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_applications(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteApplicationsRequest,
-    ) -> Result<DeleteApplicationsResponse, RusotoError<DeleteApplicationsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_applications_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_applications_request(&self, input: DeleteApplicationsRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "discovery", &self.region, "/");
+        request.set_operation("DeleteApplications");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -3086,55 +3573,104 @@ impl Discovery for DiscoveryClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `delete_tags`, also returning the metadata of the response.
+    pub async fn delete_tags_with_metadata(
+        &self,
+        input: DeleteTagsRequest,
+    ) -> Result<(DeleteTagsResponse, ResponseMetadata), RusotoError<DeleteTagsError>> {
+        let request = self.build_delete_tags_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DeleteApplicationsResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DeleteTagsResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteApplicationsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DeleteTagsError::from_response(response))
         }
     }
 
-    /// <p>Deletes the association between configuration items and one or more tags. This API accepts a list of multiple configuration items.</p>
-    async fn delete_tags(
+    /// Presigns the request `delete_tags` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_tags(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteTagsRequest,
-    ) -> Result<DeleteTagsResponse, RusotoError<DeleteTagsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_tags_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_tags_request(&self, input: DeleteTagsRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "discovery", &self.region, "/");
+        request.set_operation("DeleteTags");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSPoseidonService_V2015_11_01.DeleteTags");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `describe_agents`, also returning the metadata of the response.
+    pub async fn describe_agents_with_metadata(
+        &self,
+        input: DescribeAgentsRequest,
+    ) -> Result<(DescribeAgentsResponse, ResponseMetadata), RusotoError<DescribeAgentsError>> {
+        let request = self.build_describe_agents_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<DeleteTagsResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DescribeAgentsResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteTagsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DescribeAgentsError::from_response(response))
         }
     }
 
-    /// <p>Lists agents or connectors as specified by ID or other filters. All agents/connectors associated with your user account can be listed if you call <code>DescribeAgents</code> as is without passing any parameters.</p>
-    async fn describe_agents(
+    /// Presigns the request `describe_agents` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_agents(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeAgentsRequest,
-    ) -> Result<DescribeAgentsResponse, RusotoError<DescribeAgentsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_agents_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_agents_request(&self, input: DescribeAgentsRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "discovery", &self.region, "/");
+        request.set_operation("DescribeAgents");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -3144,27 +3680,58 @@ impl Discovery for DiscoveryClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `describe_configurations`, also returning the metadata of the response.
+    pub async fn describe_configurations_with_metadata(
+        &self,
+        input: DescribeConfigurationsRequest,
+    ) -> Result<
+        (DescribeConfigurationsResponse, ResponseMetadata),
+        RusotoError<DescribeConfigurationsError>,
+    > {
+        let request = self.build_describe_configurations_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<DescribeAgentsResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DescribeConfigurationsResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeAgentsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DescribeConfigurationsError::from_response(response))
         }
     }
 
-    /// <p><p>Retrieves attributes for a list of configuration item IDs.</p> <note> <p>All of the supplied IDs must be for the same asset type from one of the following:</p> <ul> <li> <p>server</p> </li> <li> <p>application</p> </li> <li> <p>process</p> </li> <li> <p>connection</p> </li> </ul> <p>Output fields are specific to the asset type specified. For example, the output for a <i>server</i> configuration item includes a list of attributes about the server, such as host name, operating system, number of network cards, etc.</p> <p>For a complete list of outputs for each asset type, see <a href="https://docs.aws.amazon.com/application-discovery/latest/userguide/discovery-api-queries.html#DescribeConfigurations">Using the DescribeConfigurations Action</a> in the <i>AWS Application Discovery Service User Guide</i>.</p> </note></p>
-    async fn describe_configurations(
+    /// Presigns the request `describe_configurations` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_configurations(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeConfigurationsRequest,
-    ) -> Result<DescribeConfigurationsResponse, RusotoError<DescribeConfigurationsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_configurations_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_configurations_request(
+        &self,
+        input: DescribeConfigurationsRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "discovery", &self.region, "/");
+        request.set_operation("DescribeConfigurations");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -3174,29 +3741,58 @@ impl Discovery for DiscoveryClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `describe_continuous_exports`, also returning the metadata of the response.
+    pub async fn describe_continuous_exports_with_metadata(
+        &self,
+        input: DescribeContinuousExportsRequest,
+    ) -> Result<
+        (DescribeContinuousExportsResponse, ResponseMetadata),
+        RusotoError<DescribeContinuousExportsError>,
+    > {
+        let request = self.build_describe_continuous_exports_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DescribeConfigurationsResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DescribeContinuousExportsResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeConfigurationsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DescribeContinuousExportsError::from_response(response))
         }
     }
 
-    /// <p>Lists exports as specified by ID. All continuous exports associated with your user account can be listed if you call <code>DescribeContinuousExports</code> as is without passing any parameters.</p>
-    async fn describe_continuous_exports(
+    /// Presigns the request `describe_continuous_exports` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_continuous_exports(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeContinuousExportsRequest,
-    ) -> Result<DescribeContinuousExportsResponse, RusotoError<DescribeContinuousExportsError>>
-    {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_continuous_exports_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_continuous_exports_request(
+        &self,
+        input: DescribeContinuousExportsRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "discovery", &self.region, "/");
+        request.set_operation("DescribeContinuousExports");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -3206,29 +3802,58 @@ impl Discovery for DiscoveryClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
-        let mut response = self
-            .client
-            .sign_and_dispatch(request)
+        request
+    }
+
+    /// Calls `describe_export_configurations`, also returning the metadata of the response.
+    pub async fn describe_export_configurations_with_metadata(
+        &self,
+        input: DescribeExportConfigurationsRequest,
+    ) -> Result<
+        (DescribeExportConfigurationsResponse, ResponseMetadata),
+        RusotoError<DescribeExportConfigurationsError>,
+    > {
+        let request = self.build_describe_export_configurations_request(input);
+
+        let mut response = self
+            .client
+            .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DescribeContinuousExportsResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DescribeExportConfigurationsResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeContinuousExportsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DescribeExportConfigurationsError::from_response(response))
         }
     }
 
-    /// <p> <code>DescribeExportConfigurations</code> is deprecated. Use <a href="https://docs.aws.amazon.com/application-discovery/latest/APIReference/API_DescribeExportTasks.html">DescribeImportTasks</a>, instead.</p>
-    async fn describe_export_configurations(
+    /// Presigns the request `describe_export_configurations` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_export_configurations(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeExportConfigurationsRequest,
-    ) -> Result<DescribeExportConfigurationsResponse, RusotoError<DescribeExportConfigurationsError>>
-    {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_export_configurations_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_export_configurations_request(
+        &self,
+        input: DescribeExportConfigurationsRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "discovery", &self.region, "/");
+        request.set_operation("DescribeExportConfigurations");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -3238,28 +3863,58 @@ impl Discovery for DiscoveryClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `describe_export_tasks`, also returning the metadata of the response.
+    pub async fn describe_export_tasks_with_metadata(
+        &self,
+        input: DescribeExportTasksRequest,
+    ) -> Result<
+        (DescribeExportTasksResponse, ResponseMetadata),
+        RusotoError<DescribeExportTasksError>,
+    > {
+        let request = self.build_describe_export_tasks_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DescribeExportConfigurationsResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DescribeExportTasksResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeExportConfigurationsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DescribeExportTasksError::from_response(response))
         }
     }
 
-    /// <p>Retrieve status of one or more export tasks. You can retrieve the status of up to 100 export tasks.</p>
-    async fn describe_export_tasks(
+    /// Presigns the request `describe_export_tasks` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_export_tasks(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeExportTasksRequest,
-    ) -> Result<DescribeExportTasksResponse, RusotoError<DescribeExportTasksError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_export_tasks_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_export_tasks_request(
+        &self,
+        input: DescribeExportTasksRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "discovery", &self.region, "/");
+        request.set_operation("DescribeExportTasks");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -3269,28 +3924,58 @@ impl Discovery for DiscoveryClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `describe_import_tasks`, also returning the metadata of the response.
+    pub async fn describe_import_tasks_with_metadata(
+        &self,
+        input: DescribeImportTasksRequest,
+    ) -> Result<
+        (DescribeImportTasksResponse, ResponseMetadata),
+        RusotoError<DescribeImportTasksError>,
+    > {
+        let request = self.build_describe_import_tasks_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DescribeExportTasksResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DescribeImportTasksResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeExportTasksError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DescribeImportTasksError::from_response(response))
         }
     }
 
-    /// <p>Returns an array of import tasks for your account, including status information, times, IDs, the Amazon S3 Object URL for the import file, and more.</p>
-    async fn describe_import_tasks(
+    /// Presigns the request `describe_import_tasks` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_import_tasks(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeImportTasksRequest,
-    ) -> Result<DescribeImportTasksResponse, RusotoError<DescribeImportTasksError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_import_tasks_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_import_tasks_request(
+        &self,
+        input: DescribeImportTasksRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "discovery", &self.region, "/");
+        request.set_operation("DescribeImportTasks");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -3300,28 +3985,52 @@ impl Discovery for DiscoveryClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `describe_tags`, also returning the metadata of the response.
+    pub async fn describe_tags_with_metadata(
+        &self,
+        input: DescribeTagsRequest,
+    ) -> Result<(DescribeTagsResponse, ResponseMetadata), RusotoError<DescribeTagsError>> {
+        let request = self.build_describe_tags_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DescribeImportTasksResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DescribeTagsResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeImportTasksError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DescribeTagsError::from_response(response))
         }
     }
 
-    /// <p>Retrieves a list of configuration items that have tags as specified by the key-value pairs, name and value, passed to the optional parameter <code>filters</code>.</p> <p>There are three valid tag filter names:</p> <ul> <li> <p>tagKey</p> </li> <li> <p>tagValue</p> </li> <li> <p>configurationId</p> </li> </ul> <p>Also, all configuration items associated with your user account that have tags can be listed if you call <code>DescribeTags</code> as is without passing any parameters.</p>
-    async fn describe_tags(
+    /// Presigns the request `describe_tags` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_tags(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeTagsRequest,
-    ) -> Result<DescribeTagsResponse, RusotoError<DescribeTagsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_tags_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_tags_request(&self, input: DescribeTagsRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "discovery", &self.region, "/");
+        request.set_operation("DescribeTags");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -3331,30 +4040,62 @@ impl Discovery for DiscoveryClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `disassociate_configuration_items_from_application`, also returning the metadata of the response.
+    pub async fn disassociate_configuration_items_from_application_with_metadata(
+        &self,
+        input: DisassociateConfigurationItemsFromApplicationRequest,
+    ) -> Result<
+        (
+            DisassociateConfigurationItemsFromApplicationResponse,
+            ResponseMetadata,
+        ),
+        RusotoError<DisassociateConfigurationItemsFromApplicationError>,
+    > {
+        let request = self.build_disassociate_configuration_items_from_application_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<DescribeTagsResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DisassociateConfigurationItemsFromApplicationResponse, _>(
+            )?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeTagsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DisassociateConfigurationItemsFromApplicationError::from_response(response))
         }
     }
 
-    /// <p>Disassociates one or more configuration items from an application.</p>
-    async fn disassociate_configuration_items_from_application(
+    /// Presigns the request `disassociate_configuration_items_from_application` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_disassociate_configuration_items_from_application(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DisassociateConfigurationItemsFromApplicationRequest,
-    ) -> Result<
-        DisassociateConfigurationItemsFromApplicationResponse,
-        RusotoError<DisassociateConfigurationItemsFromApplicationError>,
-    > {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_disassociate_configuration_items_from_application_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_disassociate_configuration_items_from_application_request(
+        &self,
+        input: DisassociateConfigurationItemsFromApplicationRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "discovery", &self.region, "/");
+        request.set_operation("DisassociateConfigurationItemsFromApplication");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -3364,27 +4105,53 @@ impl Discovery for DiscoveryClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `export_configurations`, also returning the metadata of the response.
+    pub async fn export_configurations_with_metadata(
+        &self,
+    ) -> Result<
+        (ExportConfigurationsResponse, ResponseMetadata),
+        RusotoError<ExportConfigurationsError>,
+    > {
+        let request = self.build_export_configurations_request();
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DisassociateConfigurationItemsFromApplicationResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ExportConfigurationsResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DisassociateConfigurationItemsFromApplicationError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ExportConfigurationsError::from_response(response))
         }
     }
 
-    /// <p>Deprecated. Use <code>StartExportTask</code> instead.</p> <p>Exports all discovered configuration data to an Amazon S3 bucket or an application that enables you to view and evaluate the data. Data includes tags and tag associations, processes, connections, servers, and system performance. This API returns an export ID that you can query using the <i>DescribeExportConfigurations</i> API. The system imposes a limit of two configuration exports in six hours.</p>
-    async fn export_configurations(
+    /// Presigns the request `export_configurations` would send with `presigner` instead of sending it.
+    pub async fn presign_export_configurations(
         &self,
-    ) -> Result<ExportConfigurationsResponse, RusotoError<ExportConfigurationsError>> {
+        presigner: &rusoto_core::Presigner,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_export_configurations_request();
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_export_configurations_request(&self) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "discovery", &self.region, "/");
+        request.set_operation("ExportConfigurations");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -3393,27 +4160,53 @@ impl Discovery for DiscoveryClient {
         );
         request.set_payload(Some(bytes::Bytes::from_static(b"{}")));
 
+        request
+    }
+
+    /// Calls `get_discovery_summary`, also returning the metadata of the response.
+    pub async fn get_discovery_summary_with_metadata(
+        &self,
+    ) -> Result<
+        (GetDiscoverySummaryResponse, ResponseMetadata),
+        RusotoError<GetDiscoverySummaryError>,
+    > {
+        let request = self.build_get_discovery_summary_request();
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<ExportConfigurationsResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<GetDiscoverySummaryResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ExportConfigurationsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(GetDiscoverySummaryError::from_response(response))
         }
     }
 
-    /// <p>Retrieves a short summary of discovered assets.</p> <p>This API operation takes no request parameters and is called as is at the command prompt as shown in the example.</p>
-    async fn get_discovery_summary(
+    /// Presigns the request `get_discovery_summary` would send with `presigner` instead of sending it.
+    pub async fn presign_get_discovery_summary(
         &self,
-    ) -> Result<GetDiscoverySummaryResponse, RusotoError<GetDiscoverySummaryError>> {
+        presigner: &rusoto_core::Presigner,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_discovery_summary_request();
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_discovery_summary_request(&self) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "discovery", &self.region, "/");
+        request.set_operation("GetDiscoverySummary");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -3422,28 +4215,53 @@ impl Discovery for DiscoveryClient {
         );
         request.set_payload(Some(bytes::Bytes::from_static(b"{}")));
 
+        request
+    }
+
+    /// Calls `list_configurations`, also returning the metadata of the response.
+    pub async fn list_configurations_with_metadata(
+        &self,
+        input: ListConfigurationsRequest,
+    ) -> Result<(ListConfigurationsResponse, ResponseMetadata), RusotoError<ListConfigurationsError>>
+    {
+        let request = self.build_list_configurations_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<GetDiscoverySummaryResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ListConfigurationsResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(GetDiscoverySummaryError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ListConfigurationsError::from_response(response))
         }
     }
 
-    /// <p>Retrieves a list of configuration items as specified by the value passed to the required parameter <code>configurationType</code>. Optional filtering may be applied to refine search results.</p>
-    async fn list_configurations(
+    /// Presigns the request `list_configurations` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_configurations(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListConfigurationsRequest,
-    ) -> Result<ListConfigurationsResponse, RusotoError<ListConfigurationsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_configurations_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_configurations_request(&self, input: ListConfigurationsRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "discovery", &self.region, "/");
+        request.set_operation("ListConfigurations");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -3453,28 +4271,58 @@ impl Discovery for DiscoveryClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `list_server_neighbors`, also returning the metadata of the response.
+    pub async fn list_server_neighbors_with_metadata(
+        &self,
+        input: ListServerNeighborsRequest,
+    ) -> Result<
+        (ListServerNeighborsResponse, ResponseMetadata),
+        RusotoError<ListServerNeighborsError>,
+    > {
+        let request = self.build_list_server_neighbors_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListConfigurationsResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ListServerNeighborsResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ListConfigurationsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ListServerNeighborsError::from_response(response))
         }
     }
 
-    /// <p>Retrieves a list of servers that are one network hop away from a specified server.</p>
-    async fn list_server_neighbors(
+    /// Presigns the request `list_server_neighbors` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_server_neighbors(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListServerNeighborsRequest,
-    ) -> Result<ListServerNeighborsResponse, RusotoError<ListServerNeighborsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_server_neighbors_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_server_neighbors_request(
+        &self,
+        input: ListServerNeighborsRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "discovery", &self.region, "/");
+        request.set_operation("ListServerNeighbors");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -3484,27 +4332,53 @@ impl Discovery for DiscoveryClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `start_continuous_export`, also returning the metadata of the response.
+    pub async fn start_continuous_export_with_metadata(
+        &self,
+    ) -> Result<
+        (StartContinuousExportResponse, ResponseMetadata),
+        RusotoError<StartContinuousExportError>,
+    > {
+        let request = self.build_start_continuous_export_request();
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListServerNeighborsResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<StartContinuousExportResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ListServerNeighborsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(StartContinuousExportError::from_response(response))
         }
     }
 
-    /// <p>Start the continuous flow of agent's discovered data into Amazon Athena.</p>
-    async fn start_continuous_export(
+    /// Presigns the request `start_continuous_export` would send with `presigner` instead of sending it.
+    pub async fn presign_start_continuous_export(
         &self,
-    ) -> Result<StartContinuousExportResponse, RusotoError<StartContinuousExportError>> {
+        presigner: &rusoto_core::Presigner,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_start_continuous_export_request();
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_start_continuous_export_request(&self) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "discovery", &self.region, "/");
+        request.set_operation("StartContinuousExport");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -3513,31 +4387,58 @@ impl Discovery for DiscoveryClient {
         );
         request.set_payload(Some(bytes::Bytes::from_static(b"{}")));
 
+        request
+    }
+
+    /// Calls `start_data_collection_by_agent_ids`, also returning the metadata of the response.
+    pub async fn start_data_collection_by_agent_ids_with_metadata(
+        &self,
+        input: StartDataCollectionByAgentIdsRequest,
+    ) -> Result<
+        (StartDataCollectionByAgentIdsResponse, ResponseMetadata),
+        RusotoError<StartDataCollectionByAgentIdsError>,
+    > {
+        let request = self.build_start_data_collection_by_agent_ids_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<StartContinuousExportResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<StartDataCollectionByAgentIdsResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(StartContinuousExportError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(StartDataCollectionByAgentIdsError::from_response(response))
         }
     }
 
-    /// <p>Instructs the specified agents or connectors to start collecting data.</p>
-    async fn start_data_collection_by_agent_ids(
+    /// Presigns the request `start_data_collection_by_agent_ids` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_start_data_collection_by_agent_ids(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: StartDataCollectionByAgentIdsRequest,
-    ) -> Result<
-        StartDataCollectionByAgentIdsResponse,
-        RusotoError<StartDataCollectionByAgentIdsError>,
-    > {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_start_data_collection_by_agent_ids_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_start_data_collection_by_agent_ids_request(
+        &self,
+        input: StartDataCollectionByAgentIdsRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "discovery", &self.region, "/");
+        request.set_operation("StartDataCollectionByAgentIds");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -3547,28 +4448,53 @@ impl Discovery for DiscoveryClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `start_export_task`, also returning the metadata of the response.
+    pub async fn start_export_task_with_metadata(
+        &self,
+        input: StartExportTaskRequest,
+    ) -> Result<(StartExportTaskResponse, ResponseMetadata), RusotoError<StartExportTaskError>>
+    {
+        let request = self.build_start_export_task_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<StartDataCollectionByAgentIdsResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<StartExportTaskResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(StartDataCollectionByAgentIdsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(StartExportTaskError::from_response(response))
         }
     }
 
-    /// <p> Begins the export of discovered data to an S3 bucket.</p> <p> If you specify <code>agentIds</code> in a filter, the task exports up to 72 hours of detailed data collected by the identified Application Discovery Agent, including network, process, and performance details. A time range for exported agent data may be set by using <code>startTime</code> and <code>endTime</code>. Export of detailed agent data is limited to five concurrently running exports. </p> <p> If you do not include an <code>agentIds</code> filter, summary data is exported that includes both AWS Agentless Discovery Connector data and summary data from AWS Discovery Agents. Export of summary data is limited to two exports per day. </p>
-    async fn start_export_task(
+    /// Presigns the request `start_export_task` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_start_export_task(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: StartExportTaskRequest,
-    ) -> Result<StartExportTaskResponse, RusotoError<StartExportTaskError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_start_export_task_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_start_export_task_request(&self, input: StartExportTaskRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "discovery", &self.region, "/");
+        request.set_operation("StartExportTask");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -3578,27 +4504,53 @@ impl Discovery for DiscoveryClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `start_import_task`, also returning the metadata of the response.
+    pub async fn start_import_task_with_metadata(
+        &self,
+        input: StartImportTaskRequest,
+    ) -> Result<(StartImportTaskResponse, ResponseMetadata), RusotoError<StartImportTaskError>>
+    {
+        let request = self.build_start_import_task_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<StartExportTaskResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<StartImportTaskResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(StartExportTaskError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(StartImportTaskError::from_response(response))
         }
     }
 
-    /// <p><p>Starts an import task, which allows you to import details of your on-premises environment directly into AWS Migration Hub without having to use the Application Discovery Service (ADS) tools such as the Discovery Connector or Discovery Agent. This gives you the option to perform migration assessment and planning directly from your imported data, including the ability to group your devices as applications and track their migration status.</p> <p>To start an import request, do this:</p> <ol> <li> <p>Download the specially formatted comma separated value (CSV) import template, which you can find here: <a href="https://s3-us-west-2.amazonaws.com/templates-7cffcf56-bd96-4b1c-b45b-a5b42f282e46/import_template.csv">https://s3-us-west-2.amazonaws.com/templates-7cffcf56-bd96-4b1c-b45b-a5b42f282e46/import<em>template.csv</a>.</p> </li> <li> <p>Fill out the template with your server and application data.</p> </li> <li> <p>Upload your import file to an Amazon S3 bucket, and make a note of it&#39;s Object URL. Your import file must be in the CSV format.</p> </li> <li> <p>Use the console or the <code>StartImportTask</code> command with the AWS CLI or one of the AWS SDKs to import the records from your file.</p> </li> </ol> <p>For more information, including step-by-step procedures, see <a href="https://docs.aws.amazon.com/application-discovery/latest/userguide/discovery-import.html">Migration Hub Import</a> in the <i>AWS Application Discovery Service User Guide</i>.</p> <note> <p>There are limits to the number of import tasks you can create (and delete) in an AWS account. For more information, see &lt;a href=&quot;https://docs.aws.amazon.com/application-discovery/latest/userguide/ads</em>service_limits.html&quot;&gt;AWS Application Discovery Service Limits</a> in the <i>AWS Application Discovery Service User Guide</i>.</p> </note></p>
-    async fn start_import_task(
+    /// Presigns the request `start_import_task` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_start_import_task(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: StartImportTaskRequest,
-    ) -> Result<StartImportTaskResponse, RusotoError<StartImportTaskError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_start_import_task_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_start_import_task_request(&self, input: StartImportTaskRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "discovery", &self.region, "/");
+        request.set_operation("StartImportTask");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -3608,27 +4560,58 @@ impl Discovery for DiscoveryClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `stop_continuous_export`, also returning the metadata of the response.
+    pub async fn stop_continuous_export_with_metadata(
+        &self,
+        input: StopContinuousExportRequest,
+    ) -> Result<
+        (StopContinuousExportResponse, ResponseMetadata),
+        RusotoError<StopContinuousExportError>,
+    > {
+        let request = self.build_stop_continuous_export_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<StartImportTaskResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<StopContinuousExportResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(StartImportTaskError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(StopContinuousExportError::from_response(response))
         }
     }
 
-    /// <p>Stop the continuous flow of agent's discovered data into Amazon Athena.</p>
-    async fn stop_continuous_export(
+    /// Presigns the request `stop_continuous_export` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_stop_continuous_export(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: StopContinuousExportRequest,
-    ) -> Result<StopContinuousExportResponse, RusotoError<StopContinuousExportError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_stop_continuous_export_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_stop_continuous_export_request(
+        &self,
+        input: StopContinuousExportRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "discovery", &self.region, "/");
+        request.set_operation("StopContinuousExport");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -3638,29 +4621,58 @@ impl Discovery for DiscoveryClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `stop_data_collection_by_agent_ids`, also returning the metadata of the response.
+    pub async fn stop_data_collection_by_agent_ids_with_metadata(
+        &self,
+        input: StopDataCollectionByAgentIdsRequest,
+    ) -> Result<
+        (StopDataCollectionByAgentIdsResponse, ResponseMetadata),
+        RusotoError<StopDataCollectionByAgentIdsError>,
+    > {
+        let request = self.build_stop_data_collection_by_agent_ids_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<StopContinuousExportResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<StopDataCollectionByAgentIdsResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(StopContinuousExportError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(StopDataCollectionByAgentIdsError::from_response(response))
         }
     }
 
-    /// <p>Instructs the specified agents or connectors to stop collecting data.</p>
-    async fn stop_data_collection_by_agent_ids(
+    /// Presigns the request `stop_data_collection_by_agent_ids` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_stop_data_collection_by_agent_ids(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: StopDataCollectionByAgentIdsRequest,
-    ) -> Result<StopDataCollectionByAgentIdsResponse, RusotoError<StopDataCollectionByAgentIdsError>>
-    {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_stop_data_collection_by_agent_ids_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_stop_data_collection_by_agent_ids_request(
+        &self,
+        input: StopDataCollectionByAgentIdsRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "discovery", &self.region, "/");
+        request.set_operation("StopDataCollectionByAgentIds");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -3670,28 +4682,53 @@ impl Discovery for DiscoveryClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `update_application`, also returning the metadata of the response.
+    pub async fn update_application_with_metadata(
+        &self,
+        input: UpdateApplicationRequest,
+    ) -> Result<(UpdateApplicationResponse, ResponseMetadata), RusotoError<UpdateApplicationError>>
+    {
+        let request = self.build_update_application_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<StopDataCollectionByAgentIdsResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<UpdateApplicationResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(StopDataCollectionByAgentIdsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(UpdateApplicationError::from_response(response))
         }
     }
 
-    /// <p>Updates metadata about an application.</p>
-    async fn update_application(
+    /// Presigns the request `update_application` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_application(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UpdateApplicationRequest,
-    ) -> Result<UpdateApplicationResponse, RusotoError<UpdateApplicationError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_application_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_application_request(&self, input: UpdateApplicationRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "discovery", &self.region, "/");
+        request.set_operation("UpdateApplication");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -3701,19 +4738,543 @@ impl Discovery for DiscoveryClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
-        let mut response = self
-            .client
-            .sign_and_dispatch(request)
-            .await
-            .map_err(RusotoError::from)?;
-        if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<UpdateApplicationResponse, _>()
-        } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(UpdateApplicationError::from_response(response))
-        }
+        request
+    }
+
+    /// The pages of `DescribeAgents` results for `input`, requesting each next page with the `nextToken` of the last one as its `nextToken`.
+    /// The page size is the `maxResults` of `input`.
+    pub fn describe_agents_pages(
+        &self,
+        input: DescribeAgentsRequest,
+    ) -> impl futures::Stream<Item = Result<DescribeAgentsResponse, RusotoError<DescribeAgentsError>>>
+           + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: DiscoveryClient, input| async move { client.describe_agents(input).await },
+            |input: &DescribeAgentsRequest, output: &DescribeAgentsResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(DescribeAgentsRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `agentsInfo` of every page of `DescribeAgents` results for `input`, as `describe_agents_pages` requests them.
+    pub fn describe_agents_stream(
+        &self,
+        input: DescribeAgentsRequest,
+    ) -> impl futures::Stream<Item = Result<AgentInfo, RusotoError<DescribeAgentsError>>> + Send
+    {
+        rusoto_core::pagination::items(self.describe_agents_pages(input), |page| {
+            page.agents_info.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `DescribeContinuousExports` results for `input`, requesting each next page with the `nextToken` of the last one as its `nextToken`.
+    /// The page size is the `maxResults` of `input`.
+    pub fn describe_continuous_exports_pages(
+        &self,
+        input: DescribeContinuousExportsRequest,
+    ) -> impl futures::Stream<
+        Item = Result<
+            DescribeContinuousExportsResponse,
+            RusotoError<DescribeContinuousExportsError>,
+        >,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: DiscoveryClient, input| async move {
+                client.describe_continuous_exports(input).await
+            },
+            |input: &DescribeContinuousExportsRequest,
+             output: &DescribeContinuousExportsResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(DescribeContinuousExportsRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `descriptions` of every page of `DescribeContinuousExports` results for `input`, as `describe_continuous_exports_pages` requests them.
+    pub fn describe_continuous_exports_stream(
+        &self,
+        input: DescribeContinuousExportsRequest,
+    ) -> impl futures::Stream<
+        Item = Result<ContinuousExportDescription, RusotoError<DescribeContinuousExportsError>>,
+    > + Send {
+        rusoto_core::pagination::items(self.describe_continuous_exports_pages(input), |page| {
+            page.descriptions.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `DescribeExportConfigurations` results for `input`, requesting each next page with the `nextToken` of the last one as its `nextToken`.
+    /// The page size is the `maxResults` of `input`.
+    pub fn describe_export_configurations_pages(
+        &self,
+        input: DescribeExportConfigurationsRequest,
+    ) -> impl futures::Stream<
+        Item = Result<
+            DescribeExportConfigurationsResponse,
+            RusotoError<DescribeExportConfigurationsError>,
+        >,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: DiscoveryClient, input| async move {
+                client.describe_export_configurations(input).await
+            },
+            |input: &DescribeExportConfigurationsRequest,
+             output: &DescribeExportConfigurationsResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(DescribeExportConfigurationsRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `exportsInfo` of every page of `DescribeExportConfigurations` results for `input`, as `describe_export_configurations_pages` requests them.
+    pub fn describe_export_configurations_stream(
+        &self,
+        input: DescribeExportConfigurationsRequest,
+    ) -> impl futures::Stream<
+        Item = Result<ExportInfo, RusotoError<DescribeExportConfigurationsError>>,
+    > + Send {
+        rusoto_core::pagination::items(self.describe_export_configurations_pages(input), |page| {
+            page.exports_info.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `DescribeExportTasks` results for `input`, requesting each next page with the `nextToken` of the last one as its `nextToken`.
+    /// The page size is the `maxResults` of `input`.
+    pub fn describe_export_tasks_pages(
+        &self,
+        input: DescribeExportTasksRequest,
+    ) -> impl futures::Stream<
+        Item = Result<DescribeExportTasksResponse, RusotoError<DescribeExportTasksError>>,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: DiscoveryClient, input| async move { client.describe_export_tasks(input).await },
+            |input: &DescribeExportTasksRequest, output: &DescribeExportTasksResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(DescribeExportTasksRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `exportsInfo` of every page of `DescribeExportTasks` results for `input`, as `describe_export_tasks_pages` requests them.
+    pub fn describe_export_tasks_stream(
+        &self,
+        input: DescribeExportTasksRequest,
+    ) -> impl futures::Stream<Item = Result<ExportInfo, RusotoError<DescribeExportTasksError>>> + Send
+    {
+        rusoto_core::pagination::items(self.describe_export_tasks_pages(input), |page| {
+            page.exports_info.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `DescribeImportTasks` results for `input`, requesting each next page with the `nextToken` of the last one as its `nextToken`.
+    /// The page size is the `maxResults` of `input`.
+    pub fn describe_import_tasks_pages(
+        &self,
+        input: DescribeImportTasksRequest,
+    ) -> impl futures::Stream<
+        Item = Result<DescribeImportTasksResponse, RusotoError<DescribeImportTasksError>>,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: DiscoveryClient, input| async move { client.describe_import_tasks(input).await },
+            |input: &DescribeImportTasksRequest, output: &DescribeImportTasksResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(DescribeImportTasksRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `tasks` of every page of `DescribeImportTasks` results for `input`, as `describe_import_tasks_pages` requests them.
+    pub fn describe_import_tasks_stream(
+        &self,
+        input: DescribeImportTasksRequest,
+    ) -> impl futures::Stream<Item = Result<ImportTask, RusotoError<DescribeImportTasksError>>> + Send
+    {
+        rusoto_core::pagination::items(self.describe_import_tasks_pages(input), |page| {
+            page.tasks.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `DescribeTags` results for `input`, requesting each next page with the `nextToken` of the last one as its `nextToken`.
+    /// The page size is the `maxResults` of `input`.
+    pub fn describe_tags_pages(
+        &self,
+        input: DescribeTagsRequest,
+    ) -> impl futures::Stream<Item = Result<DescribeTagsResponse, RusotoError<DescribeTagsError>>> + Send
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: DiscoveryClient, input| async move { client.describe_tags(input).await },
+            |input: &DescribeTagsRequest, output: &DescribeTagsResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(DescribeTagsRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `tags` of every page of `DescribeTags` results for `input`, as `describe_tags_pages` requests them.
+    pub fn describe_tags_stream(
+        &self,
+        input: DescribeTagsRequest,
+    ) -> impl futures::Stream<Item = Result<ConfigurationTag, RusotoError<DescribeTagsError>>> + Send
+    {
+        rusoto_core::pagination::items(self.describe_tags_pages(input), |page| {
+            page.tags.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `ListConfigurations` results for `input`, requesting each next page with the `nextToken` of the last one as its `nextToken`.
+    /// The page size is the `maxResults` of `input`.
+    pub fn list_configurations_pages(
+        &self,
+        input: ListConfigurationsRequest,
+    ) -> impl futures::Stream<
+        Item = Result<ListConfigurationsResponse, RusotoError<ListConfigurationsError>>,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: DiscoveryClient, input| async move { client.list_configurations(input).await },
+            |input: &ListConfigurationsRequest, output: &ListConfigurationsResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListConfigurationsRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `configurations` of every page of `ListConfigurations` results for `input`, as `list_configurations_pages` requests them.
+    pub fn list_configurations_stream(
+        &self,
+        input: ListConfigurationsRequest,
+    ) -> impl futures::Stream<
+        Item = Result<
+            ::std::collections::HashMap<String, String>,
+            RusotoError<ListConfigurationsError>,
+        >,
+    > + Send {
+        rusoto_core::pagination::items(self.list_configurations_pages(input), |page| {
+            page.configurations.unwrap_or_default()
+        })
+    }
+}
+
+#[async_trait]
+impl Discovery for DiscoveryClient {
+    /// <p>Associates one or more configuration items with an application.</p>
+    async fn associate_configuration_items_to_application(
+        &self,
+        input: AssociateConfigurationItemsToApplicationRequest,
+    ) -> Result<
+        AssociateConfigurationItemsToApplicationResponse,
+        RusotoError<AssociateConfigurationItemsToApplicationError>,
+    > {
+        self.associate_configuration_items_to_application_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes one or more import tasks, each identified by their import ID. Each import task has a number of records that can identify servers or applications. </p> <p>AWS Application Discovery Service has built-in matching logic that will identify when discovered servers match existing entries that you've previously discovered, the information for the already-existing discovered server is updated. When you delete an import task that contains records that were used to match, the information in those matched records that comes from the deleted records will also be deleted.</p>
+    async fn batch_delete_import_data(
+        &self,
+        input: BatchDeleteImportDataRequest,
+    ) -> Result<BatchDeleteImportDataResponse, RusotoError<BatchDeleteImportDataError>> {
+        self.batch_delete_import_data_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Creates an application with the given name and description.</p>
+    async fn create_application(
+        &self,
+        input: CreateApplicationRequest,
+    ) -> Result<CreateApplicationResponse, RusotoError<CreateApplicationError>> {
+        self.create_application_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Creates one or more tags for configuration items. Tags are metadata that help you categorize IT assets. This API accepts a list of multiple configuration items.</p>
+    async fn create_tags(
+        &self,
+        input: CreateTagsRequest,
+    ) -> Result<CreateTagsResponse, RusotoError<CreateTagsError>> {
+        self.create_tags_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes a list of applications and their associations with configuration items.</p>
+    async fn delete_applications(
+        &self,
+        input: DeleteApplicationsRequest,
+    ) -> Result<DeleteApplicationsResponse, RusotoError<DeleteApplicationsError>> {
+        self.delete_applications_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes the association between configuration items and one or more tags. This API accepts a list of multiple configuration items.</p>
+    async fn delete_tags(
+        &self,
+        input: DeleteTagsRequest,
+    ) -> Result<DeleteTagsResponse, RusotoError<DeleteTagsError>> {
+        self.delete_tags_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Lists agents or connectors as specified by ID or other filters. All agents/connectors associated with your user account can be listed if you call <code>DescribeAgents</code> as is without passing any parameters.</p>
+    async fn describe_agents(
+        &self,
+        input: DescribeAgentsRequest,
+    ) -> Result<DescribeAgentsResponse, RusotoError<DescribeAgentsError>> {
+        self.describe_agents_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Retrieves attributes for a list of configuration item IDs.</p> <note> <p>All of the supplied IDs must be for the same asset type from one of the following:</p> <ul> <li> <p>server</p> </li> <li> <p>application</p> </li> <li> <p>process</p> </li> <li> <p>connection</p> </li> </ul> <p>Output fields are specific to the asset type specified. For example, the output for a <i>server</i> configuration item includes a list of attributes about the server, such as host name, operating system, number of network cards, etc.</p> <p>For a complete list of outputs for each asset type, see <a href="https://docs.aws.amazon.com/application-discovery/latest/userguide/discovery-api-queries.html#DescribeConfigurations">Using the DescribeConfigurations Action</a> in the <i>AWS Application Discovery Service User Guide</i>.</p> </note></p>
+    async fn describe_configurations(
+        &self,
+        input: DescribeConfigurationsRequest,
+    ) -> Result<DescribeConfigurationsResponse, RusotoError<DescribeConfigurationsError>> {
+        self.describe_configurations_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Lists exports as specified by ID. All continuous exports associated with your user account can be listed if you call <code>DescribeContinuousExports</code> as is without passing any parameters.</p>
+    async fn describe_continuous_exports(
+        &self,
+        input: DescribeContinuousExportsRequest,
+    ) -> Result<DescribeContinuousExportsResponse, RusotoError<DescribeContinuousExportsError>>
+    {
+        self.describe_continuous_exports_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p> <code>DescribeExportConfigurations</code> is deprecated. Use <a href="https://docs.aws.amazon.com/application-discovery/latest/APIReference/API_DescribeExportTasks.html">DescribeImportTasks</a>, instead.</p>
+    async fn describe_export_configurations(
+        &self,
+        input: DescribeExportConfigurationsRequest,
+    ) -> Result<DescribeExportConfigurationsResponse, RusotoError<DescribeExportConfigurationsError>>
+    {
+        self.describe_export_configurations_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Retrieve status of one or more export tasks. You can retrieve the status of up to 100 export tasks.</p>
+    async fn describe_export_tasks(
+        &self,
+        input: DescribeExportTasksRequest,
+    ) -> Result<DescribeExportTasksResponse, RusotoError<DescribeExportTasksError>> {
+        self.describe_export_tasks_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns an array of import tasks for your account, including status information, times, IDs, the Amazon S3 Object URL for the import file, and more.</p>
+    async fn describe_import_tasks(
+        &self,
+        input: DescribeImportTasksRequest,
+    ) -> Result<DescribeImportTasksResponse, RusotoError<DescribeImportTasksError>> {
+        self.describe_import_tasks_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Retrieves a list of configuration items that have tags as specified by the key-value pairs, name and value, passed to the optional parameter <code>filters</code>.</p> <p>There are three valid tag filter names:</p> <ul> <li> <p>tagKey</p> </li> <li> <p>tagValue</p> </li> <li> <p>configurationId</p> </li> </ul> <p>Also, all configuration items associated with your user account that have tags can be listed if you call <code>DescribeTags</code> as is without passing any parameters.</p>
+    async fn describe_tags(
+        &self,
+        input: DescribeTagsRequest,
+    ) -> Result<DescribeTagsResponse, RusotoError<DescribeTagsError>> {
+        self.describe_tags_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Disassociates one or more configuration items from an application.</p>
+    async fn disassociate_configuration_items_from_application(
+        &self,
+        input: DisassociateConfigurationItemsFromApplicationRequest,
+    ) -> Result<
+        DisassociateConfigurationItemsFromApplicationResponse,
+        RusotoError<DisassociateConfigurationItemsFromApplicationError>,
+    > {
+        self.disassociate_configuration_items_from_application_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deprecated. Use <code>StartExportTask</code> instead.</p> <p>Exports all discovered configuration data to an Amazon S3 bucket or an application that enables you to view and evaluate the data. Data includes tags and tag associations, processes, connections, servers, and system performance. This API returns an export ID that you can query using the <i>DescribeExportConfigurations</i> API. The system imposes a limit of two configuration exports in six hours.</p>
+    async fn export_configurations(
+        &self,
+    ) -> Result<ExportConfigurationsResponse, RusotoError<ExportConfigurationsError>> {
+        self.export_configurations_with_metadata()
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Retrieves a short summary of discovered assets.</p> <p>This API operation takes no request parameters and is called as is at the command prompt as shown in the example.</p>
+    async fn get_discovery_summary(
+        &self,
+    ) -> Result<GetDiscoverySummaryResponse, RusotoError<GetDiscoverySummaryError>> {
+        self.get_discovery_summary_with_metadata()
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Retrieves a list of configuration items as specified by the value passed to the required parameter <code>configurationType</code>. Optional filtering may be applied to refine search results.</p>
+    async fn list_configurations(
+        &self,
+        input: ListConfigurationsRequest,
+    ) -> Result<ListConfigurationsResponse, RusotoError<ListConfigurationsError>> {
+        self.list_configurations_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Retrieves a list of servers that are one network hop away from a specified server.</p>
+    async fn list_server_neighbors(
+        &self,
+        input: ListServerNeighborsRequest,
+    ) -> Result<ListServerNeighborsResponse, RusotoError<ListServerNeighborsError>> {
+        self.list_server_neighbors_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Start the continuous flow of agent's discovered data into Amazon Athena.</p>
+    async fn start_continuous_export(
+        &self,
+    ) -> Result<StartContinuousExportResponse, RusotoError<StartContinuousExportError>> {
+        self.start_continuous_export_with_metadata()
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Instructs the specified agents or connectors to start collecting data.</p>
+    async fn start_data_collection_by_agent_ids(
+        &self,
+        input: StartDataCollectionByAgentIdsRequest,
+    ) -> Result<
+        StartDataCollectionByAgentIdsResponse,
+        RusotoError<StartDataCollectionByAgentIdsError>,
+    > {
+        self.start_data_collection_by_agent_ids_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p> Begins the export of discovered data to an S3 bucket.</p> <p> If you specify <code>agentIds</code> in a filter, the task exports up to 72 hours of detailed data collected by the identified Application Discovery Agent, including network, process, and performance details. A time range for exported agent data may be set by using <code>startTime</code> and <code>endTime</code>. Export of detailed agent data is limited to five concurrently running exports. </p> <p> If you do not include an <code>agentIds</code> filter, summary data is exported that includes both AWS Agentless Discovery Connector data and summary data from AWS Discovery Agents. Export of summary data is limited to two exports per day. </p>
+    async fn start_export_task(
+        &self,
+        input: StartExportTaskRequest,
+    ) -> Result<StartExportTaskResponse, RusotoError<StartExportTaskError>> {
+        self.start_export_task_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Starts an import task, which allows you to import details of your on-premises environment directly into AWS Migration Hub without having to use the Application Discovery Service (ADS) tools such as the Discovery Connector or Discovery Agent. This gives you the option to perform migration assessment and planning directly from your imported data, including the ability to group your devices as applications and track their migration status.</p> <p>To start an import request, do this:</p> <ol> <li> <p>Download the specially formatted comma separated value (CSV) import template, which you can find here: <a href="https://s3-us-west-2.amazonaws.com/templates-7cffcf56-bd96-4b1c-b45b-a5b42f282e46/import_template.csv">https://s3-us-west-2.amazonaws.com/templates-7cffcf56-bd96-4b1c-b45b-a5b42f282e46/import<em>template.csv</a>.</p> </li> <li> <p>Fill out the template with your server and application data.</p> </li> <li> <p>Upload your import file to an Amazon S3 bucket, and make a note of it&#39;s Object URL. Your import file must be in the CSV format.</p> </li> <li> <p>Use the console or the <code>StartImportTask</code> command with the AWS CLI or one of the AWS SDKs to import the records from your file.</p> </li> </ol> <p>For more information, including step-by-step procedures, see <a href="https://docs.aws.amazon.com/application-discovery/latest/userguide/discovery-import.html">Migration Hub Import</a> in the <i>AWS Application Discovery Service User Guide</i>.</p> <note> <p>There are limits to the number of import tasks you can create (and delete) in an AWS account. For more information, see &lt;a href=&quot;https://docs.aws.amazon.com/application-discovery/latest/userguide/ads</em>service_limits.html&quot;&gt;AWS Application Discovery Service Limits</a> in the <i>AWS Application Discovery Service User Guide</i>.</p> </note></p>
+    async fn start_import_task(
+        &self,
+        input: StartImportTaskRequest,
+    ) -> Result<StartImportTaskResponse, RusotoError<StartImportTaskError>> {
+        self.start_import_task_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Stop the continuous flow of agent's discovered data into Amazon Athena.</p>
+    async fn stop_continuous_export(
+        &self,
+        input: StopContinuousExportRequest,
+    ) -> Result<StopContinuousExportResponse, RusotoError<StopContinuousExportError>> {
+        self.stop_continuous_export_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Instructs the specified agents or connectors to stop collecting data.</p>
+    async fn stop_data_collection_by_agent_ids(
+        &self,
+        input: StopDataCollectionByAgentIdsRequest,
+    ) -> Result<StopDataCollectionByAgentIdsResponse, RusotoError<StopDataCollectionByAgentIdsError>>
+    {
+        self.stop_data_collection_by_agent_ids_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Updates metadata about an application.</p>
+    async fn update_application(
+        &self,
+        input: UpdateApplicationRequest,
+    ) -> Result<UpdateApplicationResponse, RusotoError<UpdateApplicationError>> {
+        self.update_application_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
     }
 }
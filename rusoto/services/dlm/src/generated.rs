@@ -16,8 +16,8 @@ use std::fmt;
 use async_trait::async_trait;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
-use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest, ResponseMetadata};
+use rusoto_core::{Client, ErrorMetadata, RusotoError};
 
 use rusoto_core::param::{Params, ServiceParams};
 use rusoto_core::proto;
@@ -433,6 +433,7 @@ pub struct UpdateLifecyclePolicyResponse {}
 
 /// Errors returned by CreateLifecyclePolicy
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateLifecyclePolicyError {
     /// <p>The service failed in an unexpected way.</p>
     InternalServer(String),
@@ -440,6 +441,8 @@ pub enum CreateLifecyclePolicyError {
     InvalidRequest(String),
     /// <p>The request failed because a limit was exceeded.</p>
     LimitExceeded(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateLifecyclePolicyError {
@@ -447,20 +450,25 @@ impl CreateLifecyclePolicyError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(CreateLifecyclePolicyError::InternalServer(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateLifecyclePolicyError::InternalServer(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(CreateLifecyclePolicyError::InvalidRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateLifecyclePolicyError::InvalidRequest(err.msg),
+                        &res,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateLifecyclePolicyError::LimitExceeded(err.msg))
+                    return RusotoError::service(
+                        CreateLifecyclePolicyError::LimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreateLifecyclePolicyError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -473,12 +481,14 @@ impl fmt::Display for CreateLifecyclePolicyError {
             CreateLifecyclePolicyError::InternalServer(ref cause) => write!(f, "{}", cause),
             CreateLifecyclePolicyError::InvalidRequest(ref cause) => write!(f, "{}", cause),
             CreateLifecyclePolicyError::LimitExceeded(ref cause) => write!(f, "{}", cause),
+            CreateLifecyclePolicyError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateLifecyclePolicyError {}
 /// Errors returned by DeleteLifecyclePolicy
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteLifecyclePolicyError {
     /// <p>The service failed in an unexpected way.</p>
     InternalServer(String),
@@ -486,6 +496,8 @@ pub enum DeleteLifecyclePolicyError {
     LimitExceeded(String),
     /// <p>A requested resource was not found.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteLifecyclePolicyError {
@@ -493,20 +505,25 @@ impl DeleteLifecyclePolicyError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(DeleteLifecyclePolicyError::InternalServer(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteLifecyclePolicyError::InternalServer(err.msg),
+                        &res,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(DeleteLifecyclePolicyError::LimitExceeded(err.msg))
+                    return RusotoError::service(
+                        DeleteLifecyclePolicyError::LimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteLifecyclePolicyError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteLifecyclePolicyError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteLifecyclePolicyError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -519,12 +536,14 @@ impl fmt::Display for DeleteLifecyclePolicyError {
             DeleteLifecyclePolicyError::InternalServer(ref cause) => write!(f, "{}", cause),
             DeleteLifecyclePolicyError::LimitExceeded(ref cause) => write!(f, "{}", cause),
             DeleteLifecyclePolicyError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            DeleteLifecyclePolicyError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteLifecyclePolicyError {}
 /// Errors returned by GetLifecyclePolicies
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetLifecyclePoliciesError {
     /// <p>The service failed in an unexpected way.</p>
     InternalServer(String),
@@ -534,6 +553,8 @@ pub enum GetLifecyclePoliciesError {
     LimitExceeded(String),
     /// <p>A requested resource was not found.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetLifecyclePoliciesError {
@@ -541,21 +562,31 @@ impl GetLifecyclePoliciesError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(GetLifecyclePoliciesError::InternalServer(err.msg))
+                    return RusotoError::service(
+                        GetLifecyclePoliciesError::InternalServer(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(GetLifecyclePoliciesError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        GetLifecyclePoliciesError::InvalidRequest(err.msg),
+                        &res,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(GetLifecyclePoliciesError::LimitExceeded(err.msg))
+                    return RusotoError::service(
+                        GetLifecyclePoliciesError::LimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetLifecyclePoliciesError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetLifecyclePoliciesError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetLifecyclePoliciesError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -569,12 +600,14 @@ impl fmt::Display for GetLifecyclePoliciesError {
             GetLifecyclePoliciesError::InvalidRequest(ref cause) => write!(f, "{}", cause),
             GetLifecyclePoliciesError::LimitExceeded(ref cause) => write!(f, "{}", cause),
             GetLifecyclePoliciesError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            GetLifecyclePoliciesError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetLifecyclePoliciesError {}
 /// Errors returned by GetLifecyclePolicy
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetLifecyclePolicyError {
     /// <p>The service failed in an unexpected way.</p>
     InternalServer(String),
@@ -582,6 +615,8 @@ pub enum GetLifecyclePolicyError {
     LimitExceeded(String),
     /// <p>A requested resource was not found.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetLifecyclePolicyError {
@@ -589,16 +624,25 @@ impl GetLifecyclePolicyError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(GetLifecyclePolicyError::InternalServer(err.msg))
+                    return RusotoError::service(
+                        GetLifecyclePolicyError::InternalServer(err.msg),
+                        &res,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(GetLifecyclePolicyError::LimitExceeded(err.msg))
+                    return RusotoError::service(
+                        GetLifecyclePolicyError::LimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetLifecyclePolicyError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        GetLifecyclePolicyError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetLifecyclePolicyError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -611,12 +655,14 @@ impl fmt::Display for GetLifecyclePolicyError {
             GetLifecyclePolicyError::InternalServer(ref cause) => write!(f, "{}", cause),
             GetLifecyclePolicyError::LimitExceeded(ref cause) => write!(f, "{}", cause),
             GetLifecyclePolicyError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            GetLifecyclePolicyError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetLifecyclePolicyError {}
 /// Errors returned by ListTagsForResource
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListTagsForResourceError {
     /// <p>The service failed in an unexpected way.</p>
     InternalServer(String),
@@ -624,6 +670,8 @@ pub enum ListTagsForResourceError {
     InvalidRequest(String),
     /// <p>A requested resource was not found.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListTagsForResourceError {
@@ -631,18 +679,25 @@ impl ListTagsForResourceError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(ListTagsForResourceError::InternalServer(err.msg))
+                    return RusotoError::service(
+                        ListTagsForResourceError::InternalServer(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(ListTagsForResourceError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        ListTagsForResourceError::InvalidRequest(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListTagsForResourceError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListTagsForResourceError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListTagsForResourceError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -655,12 +710,14 @@ impl fmt::Display for ListTagsForResourceError {
             ListTagsForResourceError::InternalServer(ref cause) => write!(f, "{}", cause),
             ListTagsForResourceError::InvalidRequest(ref cause) => write!(f, "{}", cause),
             ListTagsForResourceError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            ListTagsForResourceError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListTagsForResourceError {}
 /// Errors returned by TagResource
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum TagResourceError {
     /// <p>The service failed in an unexpected way.</p>
     InternalServer(String),
@@ -668,6 +725,8 @@ pub enum TagResourceError {
     InvalidRequest(String),
     /// <p>A requested resource was not found.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl TagResourceError {
@@ -675,16 +734,16 @@ impl TagResourceError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(TagResourceError::InternalServer(err.msg))
+                    return RusotoError::service(TagResourceError::InternalServer(err.msg), &res)
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(TagResourceError::InvalidRequest(err.msg))
+                    return RusotoError::service(TagResourceError::InvalidRequest(err.msg), &res)
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(TagResourceError::ResourceNotFound(err.msg))
+                    return RusotoError::service(TagResourceError::ResourceNotFound(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(TagResourceError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -697,12 +756,14 @@ impl fmt::Display for TagResourceError {
             TagResourceError::InternalServer(ref cause) => write!(f, "{}", cause),
             TagResourceError::InvalidRequest(ref cause) => write!(f, "{}", cause),
             TagResourceError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            TagResourceError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for TagResourceError {}
 /// Errors returned by UntagResource
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UntagResourceError {
     /// <p>The service failed in an unexpected way.</p>
     InternalServer(String),
@@ -710,6 +771,8 @@ pub enum UntagResourceError {
     InvalidRequest(String),
     /// <p>A requested resource was not found.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UntagResourceError {
@@ -717,16 +780,19 @@ impl UntagResourceError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(UntagResourceError::InternalServer(err.msg))
+                    return RusotoError::service(UntagResourceError::InternalServer(err.msg), &res)
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(UntagResourceError::InvalidRequest(err.msg))
+                    return RusotoError::service(UntagResourceError::InvalidRequest(err.msg), &res)
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UntagResourceError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        UntagResourceError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UntagResourceError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -739,12 +805,14 @@ impl fmt::Display for UntagResourceError {
             UntagResourceError::InternalServer(ref cause) => write!(f, "{}", cause),
             UntagResourceError::InvalidRequest(ref cause) => write!(f, "{}", cause),
             UntagResourceError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            UntagResourceError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for UntagResourceError {}
 /// Errors returned by UpdateLifecyclePolicy
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdateLifecyclePolicyError {
     /// <p>The service failed in an unexpected way.</p>
     InternalServer(String),
@@ -754,6 +822,8 @@ pub enum UpdateLifecyclePolicyError {
     LimitExceeded(String),
     /// <p>A requested resource was not found.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdateLifecyclePolicyError {
@@ -761,25 +831,31 @@ impl UpdateLifecyclePolicyError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(UpdateLifecyclePolicyError::InternalServer(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateLifecyclePolicyError::InternalServer(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(UpdateLifecyclePolicyError::InvalidRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateLifecyclePolicyError::InvalidRequest(err.msg),
+                        &res,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(UpdateLifecyclePolicyError::LimitExceeded(err.msg))
+                    return RusotoError::service(
+                        UpdateLifecyclePolicyError::LimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateLifecyclePolicyError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateLifecyclePolicyError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UpdateLifecyclePolicyError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -793,6 +869,7 @@ impl fmt::Display for UpdateLifecyclePolicyError {
             UpdateLifecyclePolicyError::InvalidRequest(ref cause) => write!(f, "{}", cause),
             UpdateLifecyclePolicyError::LimitExceeded(ref cause) => write!(f, "{}", cause),
             UpdateLifecyclePolicyError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            UpdateLifecyclePolicyError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
@@ -859,6 +936,7 @@ impl DlmClient {
     /// Creates a client backed by the default tokio event loop.
     ///
     /// The client will use the default credentials provider and tls client.
+    #[cfg(not(target_arch = "wasm32"))]
     pub fn new(region: region::Region) -> DlmClient {
         DlmClient {
             client: Client::shared(),
@@ -884,75 +962,193 @@ impl DlmClient {
     pub fn new_with_client(client: Client, region: region::Region) -> DlmClient {
         DlmClient { client, region }
     }
+
+    /// Returns a builder of a client, setting any of its region, credentials provider,
+    /// dispatcher, retry policy, endpoint and timeouts.
+    pub fn builder() -> rusoto_core::ClientBuilder<DlmClient> {
+        rusoto_core::ClientBuilder::new(DlmClient::new_with_client)
+    }
+
+    /// Returns a clone of this client with the overrides of `config` applied to its
+    /// operations, sharing its connections.
+    pub fn customize(&self, config: &rusoto_core::OperationConfig) -> DlmClient {
+        DlmClient {
+            client: self.client.customize(config),
+            region: config.resolve_region(&self.region),
+        }
+    }
 }
 
-#[async_trait]
-impl Dlm for DlmClient {
-    /// <p>Creates a policy to manage the lifecycle of the specified AWS resources. You can create up to 100 lifecycle policies.</p>
-    async fn create_lifecycle_policy(
+impl DlmClient {
+    /// Calls `create_lifecycle_policy`, also returning the metadata of the response.
+    pub async fn create_lifecycle_policy_with_metadata(
         &self,
         input: CreateLifecyclePolicyRequest,
-    ) -> Result<CreateLifecyclePolicyResponse, RusotoError<CreateLifecyclePolicyError>> {
+    ) -> Result<
+        (CreateLifecyclePolicyResponse, ResponseMetadata),
+        RusotoError<CreateLifecyclePolicyError>,
+    > {
+        let request = self.build_create_lifecycle_policy_request(input);
+
+        let mut response = self
+            .client
+            .sign_and_dispatch(request)
+            .await
+            .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
+        if response.status.is_success() {
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<CreateLifecyclePolicyResponse, _>()?;
+
+            Ok((result, metadata))
+        } else {
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(CreateLifecyclePolicyError::from_response(response))
+        }
+    }
+
+    /// Presigns the request `create_lifecycle_policy` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_lifecycle_policy(
+        &self,
+        presigner: &rusoto_core::Presigner,
+        input: CreateLifecyclePolicyRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_lifecycle_policy_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_lifecycle_policy_request(
+        &self,
+        input: CreateLifecyclePolicyRequest,
+    ) -> SignedRequest {
         let request_uri = "/policies";
 
         let mut request = SignedRequest::new("POST", "dlm", &self.region, &request_uri);
+        request.set_operation("CreateLifecyclePolicy");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `delete_lifecycle_policy`, also returning the metadata of the response.
+    pub async fn delete_lifecycle_policy_with_metadata(
+        &self,
+        input: DeleteLifecyclePolicyRequest,
+    ) -> Result<
+        (DeleteLifecyclePolicyResponse, ResponseMetadata),
+        RusotoError<DeleteLifecyclePolicyError>,
+    > {
+        let request = self.build_delete_lifecycle_policy_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<CreateLifecyclePolicyResponse, _>()?;
+                .deserialize::<DeleteLifecyclePolicyResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(CreateLifecyclePolicyError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(DeleteLifecyclePolicyError::from_response(response))
         }
     }
 
-    /// <p>Deletes the specified lifecycle policy and halts the automated operations that the policy specified.</p>
-    async fn delete_lifecycle_policy(
+    /// Presigns the request `delete_lifecycle_policy` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_lifecycle_policy(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteLifecyclePolicyRequest,
-    ) -> Result<DeleteLifecyclePolicyResponse, RusotoError<DeleteLifecyclePolicyError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_lifecycle_policy_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_lifecycle_policy_request(
+        &self,
+        input: DeleteLifecyclePolicyRequest,
+    ) -> SignedRequest {
         let request_uri = format!("/policies/{policy_id}/", policy_id = input.policy_id);
 
         let mut request = SignedRequest::new("DELETE", "dlm", &self.region, &request_uri);
+        request.set_operation("DeleteLifecyclePolicy");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
+        request
+    }
+
+    /// Calls `get_lifecycle_policies`, also returning the metadata of the response.
+    pub async fn get_lifecycle_policies_with_metadata(
+        &self,
+        input: GetLifecyclePoliciesRequest,
+    ) -> Result<
+        (GetLifecyclePoliciesResponse, ResponseMetadata),
+        RusotoError<GetLifecyclePoliciesError>,
+    > {
+        let request = self.build_get_lifecycle_policies_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<DeleteLifecyclePolicyResponse, _>()?;
+                .deserialize::<GetLifecyclePoliciesResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteLifecyclePolicyError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(GetLifecyclePoliciesError::from_response(response))
         }
     }
 
-    /// <p>Gets summary information about all or the specified data lifecycle policies.</p> <p>To get complete information about a policy, use <a>GetLifecyclePolicy</a>.</p>
-    async fn get_lifecycle_policies(
+    /// Presigns the request `get_lifecycle_policies` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_lifecycle_policies(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetLifecyclePoliciesRequest,
-    ) -> Result<GetLifecyclePoliciesResponse, RusotoError<GetLifecyclePoliciesError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_lifecycle_policies_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_lifecycle_policies_request(
+        &self,
+        input: GetLifecyclePoliciesRequest,
+    ) -> SignedRequest {
         let request_uri = "/policies";
 
         let mut request = SignedRequest::new("GET", "dlm", &self.region, &request_uri);
+        request.set_operation("GetLifecyclePolicies");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -981,115 +1177,221 @@ impl Dlm for DlmClient {
         }
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `get_lifecycle_policy`, also returning the metadata of the response.
+    pub async fn get_lifecycle_policy_with_metadata(
+        &self,
+        input: GetLifecyclePolicyRequest,
+    ) -> Result<(GetLifecyclePolicyResponse, ResponseMetadata), RusotoError<GetLifecyclePolicyError>>
+    {
+        let request = self.build_get_lifecycle_policy_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<GetLifecyclePoliciesResponse, _>()?;
+                .deserialize::<GetLifecyclePolicyResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(GetLifecyclePoliciesError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(GetLifecyclePolicyError::from_response(response))
         }
     }
 
-    /// <p>Gets detailed information about the specified lifecycle policy.</p>
-    async fn get_lifecycle_policy(
+    /// Presigns the request `get_lifecycle_policy` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_lifecycle_policy(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetLifecyclePolicyRequest,
-    ) -> Result<GetLifecyclePolicyResponse, RusotoError<GetLifecyclePolicyError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_lifecycle_policy_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_lifecycle_policy_request(
+        &self,
+        input: GetLifecyclePolicyRequest,
+    ) -> SignedRequest {
         let request_uri = format!("/policies/{policy_id}/", policy_id = input.policy_id);
 
         let mut request = SignedRequest::new("GET", "dlm", &self.region, &request_uri);
+        request.set_operation("GetLifecyclePolicy");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
+        request
+    }
+
+    /// Calls `list_tags_for_resource`, also returning the metadata of the response.
+    pub async fn list_tags_for_resource_with_metadata(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> Result<
+        (ListTagsForResourceResponse, ResponseMetadata),
+        RusotoError<ListTagsForResourceError>,
+    > {
+        let request = self.build_list_tags_for_resource_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<GetLifecyclePolicyResponse, _>()?;
+                .deserialize::<ListTagsForResourceResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(GetLifecyclePolicyError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(ListTagsForResourceError::from_response(response))
         }
     }
 
-    /// <p>Lists the tags for the specified resource.</p>
-    async fn list_tags_for_resource(
+    /// Presigns the request `list_tags_for_resource` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_tags_for_resource(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListTagsForResourceRequest,
-    ) -> Result<ListTagsForResourceResponse, RusotoError<ListTagsForResourceError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_tags_for_resource_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_tags_for_resource_request(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> SignedRequest {
         let request_uri = format!("/tags/{resource_arn}", resource_arn = input.resource_arn);
 
         let mut request = SignedRequest::new("GET", "dlm", &self.region, &request_uri);
+        request.set_operation("ListTagsForResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
+        request
+    }
+
+    /// Calls `tag_resource`, also returning the metadata of the response.
+    pub async fn tag_resource_with_metadata(
+        &self,
+        input: TagResourceRequest,
+    ) -> Result<(TagResourceResponse, ResponseMetadata), RusotoError<TagResourceError>> {
+        let request = self.build_tag_resource_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListTagsForResourceResponse, _>()?;
+                .deserialize::<TagResourceResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(ListTagsForResourceError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(TagResourceError::from_response(response))
         }
     }
 
-    /// <p>Adds the specified tags to the specified resource.</p>
-    async fn tag_resource(
+    /// Presigns the request `tag_resource` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_tag_resource(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: TagResourceRequest,
-    ) -> Result<TagResourceResponse, RusotoError<TagResourceError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_tag_resource_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_tag_resource_request(&self, input: TagResourceRequest) -> SignedRequest {
         let request_uri = format!("/tags/{resource_arn}", resource_arn = input.resource_arn);
 
         let mut request = SignedRequest::new("POST", "dlm", &self.region, &request_uri);
+        request.set_operation("TagResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `untag_resource`, also returning the metadata of the response.
+    pub async fn untag_resource_with_metadata(
+        &self,
+        input: UntagResourceRequest,
+    ) -> Result<(UntagResourceResponse, ResponseMetadata), RusotoError<UntagResourceError>> {
+        let request = self.build_untag_resource_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<TagResourceResponse, _>()?;
+                .deserialize::<UntagResourceResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(TagResourceError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(UntagResourceError::from_response(response))
         }
     }
 
-    /// <p>Removes the specified tags from the specified resource.</p>
-    async fn untag_resource(
+    /// Presigns the request `untag_resource` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_untag_resource(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UntagResourceRequest,
-    ) -> Result<UntagResourceResponse, RusotoError<UntagResourceError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_untag_resource_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_untag_resource_request(&self, input: UntagResourceRequest) -> SignedRequest {
         let request_uri = format!("/tags/{resource_arn}", resource_arn = input.resource_arn);
 
         let mut request = SignedRequest::new("DELETE", "dlm", &self.region, &request_uri);
+        request.set_operation("UntagResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -1098,50 +1400,149 @@ impl Dlm for DlmClient {
         }
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `update_lifecycle_policy`, also returning the metadata of the response.
+    pub async fn update_lifecycle_policy_with_metadata(
+        &self,
+        input: UpdateLifecyclePolicyRequest,
+    ) -> Result<
+        (UpdateLifecyclePolicyResponse, ResponseMetadata),
+        RusotoError<UpdateLifecyclePolicyError>,
+    > {
+        let request = self.build_update_lifecycle_policy_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<UntagResourceResponse, _>()?;
+                .deserialize::<UpdateLifecyclePolicyResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(UntagResourceError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(UpdateLifecyclePolicyError::from_response(response))
         }
     }
 
-    /// <p>Updates the specified lifecycle policy.</p>
-    async fn update_lifecycle_policy(
+    /// Presigns the request `update_lifecycle_policy` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_lifecycle_policy(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UpdateLifecyclePolicyRequest,
-    ) -> Result<UpdateLifecyclePolicyResponse, RusotoError<UpdateLifecyclePolicyError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_lifecycle_policy_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_lifecycle_policy_request(
+        &self,
+        input: UpdateLifecyclePolicyRequest,
+    ) -> SignedRequest {
         let request_uri = format!("/policies/{policy_id}", policy_id = input.policy_id);
 
         let mut request = SignedRequest::new("PATCH", "dlm", &self.region, &request_uri);
+        request.set_operation("UpdateLifecyclePolicy");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
-        let mut response = self
-            .client
-            .sign_and_dispatch(request)
+        request
+    }
+}
+
+#[async_trait]
+impl Dlm for DlmClient {
+    /// <p>Creates a policy to manage the lifecycle of the specified AWS resources. You can create up to 100 lifecycle policies.</p>
+    async fn create_lifecycle_policy(
+        &self,
+        input: CreateLifecyclePolicyRequest,
+    ) -> Result<CreateLifecyclePolicyResponse, RusotoError<CreateLifecyclePolicyError>> {
+        self.create_lifecycle_policy_with_metadata(input)
             .await
-            .map_err(RusotoError::from)?;
-        if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<UpdateLifecyclePolicyResponse, _>()?;
+            .map(|(output, _)| output)
+    }
 
-            Ok(result)
-        } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(UpdateLifecyclePolicyError::from_response(response))
-        }
+    /// <p>Deletes the specified lifecycle policy and halts the automated operations that the policy specified.</p>
+    async fn delete_lifecycle_policy(
+        &self,
+        input: DeleteLifecyclePolicyRequest,
+    ) -> Result<DeleteLifecyclePolicyResponse, RusotoError<DeleteLifecyclePolicyError>> {
+        self.delete_lifecycle_policy_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Gets summary information about all or the specified data lifecycle policies.</p> <p>To get complete information about a policy, use <a>GetLifecyclePolicy</a>.</p>
+    async fn get_lifecycle_policies(
+        &self,
+        input: GetLifecyclePoliciesRequest,
+    ) -> Result<GetLifecyclePoliciesResponse, RusotoError<GetLifecyclePoliciesError>> {
+        self.get_lifecycle_policies_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Gets detailed information about the specified lifecycle policy.</p>
+    async fn get_lifecycle_policy(
+        &self,
+        input: GetLifecyclePolicyRequest,
+    ) -> Result<GetLifecyclePolicyResponse, RusotoError<GetLifecyclePolicyError>> {
+        self.get_lifecycle_policy_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Lists the tags for the specified resource.</p>
+    async fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> Result<ListTagsForResourceResponse, RusotoError<ListTagsForResourceError>> {
+        self.list_tags_for_resource_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Adds the specified tags to the specified resource.</p>
+    async fn tag_resource(
+        &self,
+        input: TagResourceRequest,
+    ) -> Result<TagResourceResponse, RusotoError<TagResourceError>> {
+        self.tag_resource_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Removes the specified tags from the specified resource.</p>
+    async fn untag_resource(
+        &self,
+        input: UntagResourceRequest,
+    ) -> Result<UntagResourceResponse, RusotoError<UntagResourceError>> {
+        self.untag_resource_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Updates the specified lifecycle policy.</p>
+    async fn update_lifecycle_policy(
+        &self,
+        input: UpdateLifecyclePolicyRequest,
+    ) -> Result<UpdateLifecyclePolicyResponse, RusotoError<UpdateLifecyclePolicyError>> {
+        self.update_lifecycle_policy_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
     }
 }
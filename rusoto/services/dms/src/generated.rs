@@ -16,8 +16,8 @@ use std::fmt;
 use async_trait::async_trait;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
-use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest, ResponseMetadata};
+use rusoto_core::{Client, ErrorMetadata, RusotoError};
 
 use rusoto_core::proto;
 use rusoto_core::signature::SignedRequest;
@@ -2753,9 +2753,12 @@ pub struct VpcSecurityGroupMembership {
 
 /// Errors returned by AddTagsToResource
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum AddTagsToResourceError {
     /// <p>The resource could not be found.</p>
     ResourceNotFoundFault(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl AddTagsToResourceError {
@@ -2763,12 +2766,13 @@ impl AddTagsToResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFoundFault" => {
-                    return RusotoError::Service(AddTagsToResourceError::ResourceNotFoundFault(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        AddTagsToResourceError::ResourceNotFoundFault(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(AddTagsToResourceError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2779,15 +2783,19 @@ impl fmt::Display for AddTagsToResourceError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             AddTagsToResourceError::ResourceNotFoundFault(ref cause) => write!(f, "{}", cause),
+            AddTagsToResourceError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for AddTagsToResourceError {}
 /// Errors returned by ApplyPendingMaintenanceAction
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ApplyPendingMaintenanceActionError {
     /// <p>The resource could not be found.</p>
     ResourceNotFoundFault(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ApplyPendingMaintenanceActionError {
@@ -2797,12 +2805,18 @@ impl ApplyPendingMaintenanceActionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFoundFault" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         ApplyPendingMaintenanceActionError::ResourceNotFoundFault(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        ApplyPendingMaintenanceActionError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -2815,12 +2829,16 @@ impl fmt::Display for ApplyPendingMaintenanceActionError {
             ApplyPendingMaintenanceActionError::ResourceNotFoundFault(ref cause) => {
                 write!(f, "{}", cause)
             }
+            ApplyPendingMaintenanceActionError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for ApplyPendingMaintenanceActionError {}
 /// Errors returned by CreateEndpoint
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateEndpointError {
     /// <p>AWS DMS was denied access to the endpoint. Check that the role is correctly configured.</p>
     AccessDeniedFault(String),
@@ -2834,6 +2852,8 @@ pub enum CreateEndpointError {
     ResourceNotFoundFault(String),
     /// <p>The quota for this resource quota has been exceeded.</p>
     ResourceQuotaExceededFault(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateEndpointError {
@@ -2841,35 +2861,43 @@ impl CreateEndpointError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedFault" => {
-                    return RusotoError::Service(CreateEndpointError::AccessDeniedFault(err.msg))
+                    return RusotoError::service(
+                        CreateEndpointError::AccessDeniedFault(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidResourceStateFault" => {
-                    return RusotoError::Service(CreateEndpointError::InvalidResourceStateFault(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateEndpointError::InvalidResourceStateFault(err.msg),
+                        &res,
+                    )
                 }
                 "KMSKeyNotAccessibleFault" => {
-                    return RusotoError::Service(CreateEndpointError::KMSKeyNotAccessibleFault(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateEndpointError::KMSKeyNotAccessibleFault(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceAlreadyExistsFault" => {
-                    return RusotoError::Service(CreateEndpointError::ResourceAlreadyExistsFault(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateEndpointError::ResourceAlreadyExistsFault(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundFault" => {
-                    return RusotoError::Service(CreateEndpointError::ResourceNotFoundFault(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateEndpointError::ResourceNotFoundFault(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceQuotaExceededFault" => {
-                    return RusotoError::Service(CreateEndpointError::ResourceQuotaExceededFault(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateEndpointError::ResourceQuotaExceededFault(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreateEndpointError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2885,12 +2913,14 @@ impl fmt::Display for CreateEndpointError {
             CreateEndpointError::ResourceAlreadyExistsFault(ref cause) => write!(f, "{}", cause),
             CreateEndpointError::ResourceNotFoundFault(ref cause) => write!(f, "{}", cause),
             CreateEndpointError::ResourceQuotaExceededFault(ref cause) => write!(f, "{}", cause),
+            CreateEndpointError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateEndpointError {}
 /// Errors returned by CreateEventSubscription
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateEventSubscriptionError {
     /// <p>The ciphertext references a key that doesn't exist or that the DMS account doesn't have access to.</p>
     KMSAccessDeniedFault(String),
@@ -2912,6 +2942,8 @@ pub enum CreateEventSubscriptionError {
     SNSInvalidTopicFault(String),
     /// <p>You are not authorized for the SNS subscription.</p>
     SNSNoAuthorizationFault(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateEventSubscriptionError {
@@ -2919,57 +2951,67 @@ impl CreateEventSubscriptionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "KMSAccessDeniedFault" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         CreateEventSubscriptionError::KMSAccessDeniedFault(err.msg),
+                        &res,
                     )
                 }
                 "KMSDisabledFault" => {
-                    return RusotoError::Service(CreateEventSubscriptionError::KMSDisabledFault(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateEventSubscriptionError::KMSDisabledFault(err.msg),
+                        &res,
+                    )
                 }
                 "KMSInvalidStateFault" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         CreateEventSubscriptionError::KMSInvalidStateFault(err.msg),
+                        &res,
                     )
                 }
                 "KMSNotFoundFault" => {
-                    return RusotoError::Service(CreateEventSubscriptionError::KMSNotFoundFault(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateEventSubscriptionError::KMSNotFoundFault(err.msg),
+                        &res,
+                    )
                 }
                 "KMSThrottlingFault" => {
-                    return RusotoError::Service(CreateEventSubscriptionError::KMSThrottlingFault(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateEventSubscriptionError::KMSThrottlingFault(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceAlreadyExistsFault" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         CreateEventSubscriptionError::ResourceAlreadyExistsFault(err.msg),
+                        &res,
                     )
                 }
                 "ResourceNotFoundFault" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         CreateEventSubscriptionError::ResourceNotFoundFault(err.msg),
+                        &res,
                     )
                 }
                 "ResourceQuotaExceededFault" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         CreateEventSubscriptionError::ResourceQuotaExceededFault(err.msg),
+                        &res,
                     )
                 }
                 "SNSInvalidTopicFault" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         CreateEventSubscriptionError::SNSInvalidTopicFault(err.msg),
+                        &res,
                     )
                 }
                 "SNSNoAuthorizationFault" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         CreateEventSubscriptionError::SNSNoAuthorizationFault(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreateEventSubscriptionError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2997,12 +3039,14 @@ impl fmt::Display for CreateEventSubscriptionError {
             CreateEventSubscriptionError::SNSNoAuthorizationFault(ref cause) => {
                 write!(f, "{}", cause)
             }
+            CreateEventSubscriptionError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateEventSubscriptionError {}
 /// Errors returned by CreateReplicationInstance
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateReplicationInstanceError {
     /// <p>AWS DMS was denied access to the endpoint. Check that the role is correctly configured.</p>
     AccessDeniedFault(String),
@@ -3024,6 +3068,8 @@ pub enum CreateReplicationInstanceError {
     ResourceQuotaExceededFault(String),
     /// <p>The storage quota has been exceeded.</p>
     StorageQuotaExceededFault(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateReplicationInstanceError {
@@ -3031,59 +3077,71 @@ impl CreateReplicationInstanceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedFault" => {
-                    return RusotoError::Service(CreateReplicationInstanceError::AccessDeniedFault(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateReplicationInstanceError::AccessDeniedFault(err.msg),
+                        &res,
+                    )
                 }
                 "InsufficientResourceCapacityFault" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         CreateReplicationInstanceError::InsufficientResourceCapacityFault(err.msg),
+                        &res,
                     )
                 }
                 "InvalidResourceStateFault" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         CreateReplicationInstanceError::InvalidResourceStateFault(err.msg),
+                        &res,
                     )
                 }
                 "InvalidSubnet" => {
-                    return RusotoError::Service(CreateReplicationInstanceError::InvalidSubnet(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateReplicationInstanceError::InvalidSubnet(err.msg),
+                        &res,
+                    )
                 }
                 "KMSKeyNotAccessibleFault" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         CreateReplicationInstanceError::KMSKeyNotAccessibleFault(err.msg),
+                        &res,
                     )
                 }
                 "ReplicationSubnetGroupDoesNotCoverEnoughAZs" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         CreateReplicationInstanceError::ReplicationSubnetGroupDoesNotCoverEnoughAZs(
                             err.msg,
                         ),
+                        &res,
                     )
                 }
                 "ResourceAlreadyExistsFault" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         CreateReplicationInstanceError::ResourceAlreadyExistsFault(err.msg),
+                        &res,
                     )
                 }
                 "ResourceNotFoundFault" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         CreateReplicationInstanceError::ResourceNotFoundFault(err.msg),
+                        &res,
                     )
                 }
                 "ResourceQuotaExceededFault" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         CreateReplicationInstanceError::ResourceQuotaExceededFault(err.msg),
+                        &res,
                     )
                 }
                 "StorageQuotaExceededFault" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         CreateReplicationInstanceError::StorageQuotaExceededFault(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(CreateReplicationInstanceError::Unhandled, &res)
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -3119,12 +3177,14 @@ impl fmt::Display for CreateReplicationInstanceError {
             CreateReplicationInstanceError::StorageQuotaExceededFault(ref cause) => {
                 write!(f, "{}", cause)
             }
+            CreateReplicationInstanceError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateReplicationInstanceError {}
 /// Errors returned by CreateReplicationSubnetGroup
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateReplicationSubnetGroupError {
     /// <p>AWS DMS was denied access to the endpoint. Check that the role is correctly configured.</p>
     AccessDeniedFault(String),
@@ -3138,6 +3198,8 @@ pub enum CreateReplicationSubnetGroupError {
     ResourceNotFoundFault(String),
     /// <p>The quota for this resource quota has been exceeded.</p>
     ResourceQuotaExceededFault(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateReplicationSubnetGroupError {
@@ -3147,37 +3209,48 @@ impl CreateReplicationSubnetGroupError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedFault" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         CreateReplicationSubnetGroupError::AccessDeniedFault(err.msg),
+                        &res,
                     )
                 }
                 "InvalidSubnet" => {
-                    return RusotoError::Service(CreateReplicationSubnetGroupError::InvalidSubnet(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateReplicationSubnetGroupError::InvalidSubnet(err.msg),
+                        &res,
+                    )
                 }
-                "ReplicationSubnetGroupDoesNotCoverEnoughAZs" => return RusotoError::Service(
+                "ReplicationSubnetGroupDoesNotCoverEnoughAZs" => return RusotoError::service(
                     CreateReplicationSubnetGroupError::ReplicationSubnetGroupDoesNotCoverEnoughAZs(
                         err.msg,
                     ),
+                    &res,
                 ),
                 "ResourceAlreadyExistsFault" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         CreateReplicationSubnetGroupError::ResourceAlreadyExistsFault(err.msg),
+                        &res,
                     )
                 }
                 "ResourceNotFoundFault" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         CreateReplicationSubnetGroupError::ResourceNotFoundFault(err.msg),
+                        &res,
                     )
                 }
                 "ResourceQuotaExceededFault" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         CreateReplicationSubnetGroupError::ResourceQuotaExceededFault(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        CreateReplicationSubnetGroupError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -3203,12 +3276,14 @@ impl fmt::Display for CreateReplicationSubnetGroupError {
             CreateReplicationSubnetGroupError::ResourceQuotaExceededFault(ref cause) => {
                 write!(f, "{}", cause)
             }
+            CreateReplicationSubnetGroupError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateReplicationSubnetGroupError {}
 /// Errors returned by CreateReplicationTask
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateReplicationTaskError {
     /// <p>AWS DMS was denied access to the endpoint. Check that the role is correctly configured.</p>
     AccessDeniedFault(String),
@@ -3222,6 +3297,8 @@ pub enum CreateReplicationTaskError {
     ResourceNotFoundFault(String),
     /// <p>The quota for this resource quota has been exceeded.</p>
     ResourceQuotaExceededFault(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateReplicationTaskError {
@@ -3229,37 +3306,43 @@ impl CreateReplicationTaskError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedFault" => {
-                    return RusotoError::Service(CreateReplicationTaskError::AccessDeniedFault(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateReplicationTaskError::AccessDeniedFault(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidResourceStateFault" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         CreateReplicationTaskError::InvalidResourceStateFault(err.msg),
+                        &res,
                     )
                 }
                 "KMSKeyNotAccessibleFault" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         CreateReplicationTaskError::KMSKeyNotAccessibleFault(err.msg),
+                        &res,
                     )
                 }
                 "ResourceAlreadyExistsFault" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         CreateReplicationTaskError::ResourceAlreadyExistsFault(err.msg),
+                        &res,
                     )
                 }
                 "ResourceNotFoundFault" => {
-                    return RusotoError::Service(CreateReplicationTaskError::ResourceNotFoundFault(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateReplicationTaskError::ResourceNotFoundFault(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceQuotaExceededFault" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         CreateReplicationTaskError::ResourceQuotaExceededFault(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreateReplicationTaskError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3283,17 +3366,21 @@ impl fmt::Display for CreateReplicationTaskError {
             CreateReplicationTaskError::ResourceQuotaExceededFault(ref cause) => {
                 write!(f, "{}", cause)
             }
+            CreateReplicationTaskError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateReplicationTaskError {}
 /// Errors returned by DeleteCertificate
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteCertificateError {
     /// <p>The resource is in a state that prevents it from being used for database migration.</p>
     InvalidResourceStateFault(String),
     /// <p>The resource could not be found.</p>
     ResourceNotFoundFault(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteCertificateError {
@@ -3301,17 +3388,19 @@ impl DeleteCertificateError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidResourceStateFault" => {
-                    return RusotoError::Service(DeleteCertificateError::InvalidResourceStateFault(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteCertificateError::InvalidResourceStateFault(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundFault" => {
-                    return RusotoError::Service(DeleteCertificateError::ResourceNotFoundFault(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteCertificateError::ResourceNotFoundFault(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteCertificateError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3323,12 +3412,14 @@ impl fmt::Display for DeleteCertificateError {
         match *self {
             DeleteCertificateError::InvalidResourceStateFault(ref cause) => write!(f, "{}", cause),
             DeleteCertificateError::ResourceNotFoundFault(ref cause) => write!(f, "{}", cause),
+            DeleteCertificateError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteCertificateError {}
 /// Errors returned by DeleteConnection
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteConnectionError {
     /// <p>AWS DMS was denied access to the endpoint. Check that the role is correctly configured.</p>
     AccessDeniedFault(String),
@@ -3336,6 +3427,8 @@ pub enum DeleteConnectionError {
     InvalidResourceStateFault(String),
     /// <p>The resource could not be found.</p>
     ResourceNotFoundFault(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteConnectionError {
@@ -3343,20 +3436,25 @@ impl DeleteConnectionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedFault" => {
-                    return RusotoError::Service(DeleteConnectionError::AccessDeniedFault(err.msg))
+                    return RusotoError::service(
+                        DeleteConnectionError::AccessDeniedFault(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidResourceStateFault" => {
-                    return RusotoError::Service(DeleteConnectionError::InvalidResourceStateFault(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteConnectionError::InvalidResourceStateFault(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundFault" => {
-                    return RusotoError::Service(DeleteConnectionError::ResourceNotFoundFault(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteConnectionError::ResourceNotFoundFault(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteConnectionError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3369,17 +3467,21 @@ impl fmt::Display for DeleteConnectionError {
             DeleteConnectionError::AccessDeniedFault(ref cause) => write!(f, "{}", cause),
             DeleteConnectionError::InvalidResourceStateFault(ref cause) => write!(f, "{}", cause),
             DeleteConnectionError::ResourceNotFoundFault(ref cause) => write!(f, "{}", cause),
+            DeleteConnectionError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteConnectionError {}
 /// Errors returned by DeleteEndpoint
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteEndpointError {
     /// <p>The resource is in a state that prevents it from being used for database migration.</p>
     InvalidResourceStateFault(String),
     /// <p>The resource could not be found.</p>
     ResourceNotFoundFault(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteEndpointError {
@@ -3387,17 +3489,19 @@ impl DeleteEndpointError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidResourceStateFault" => {
-                    return RusotoError::Service(DeleteEndpointError::InvalidResourceStateFault(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteEndpointError::InvalidResourceStateFault(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundFault" => {
-                    return RusotoError::Service(DeleteEndpointError::ResourceNotFoundFault(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteEndpointError::ResourceNotFoundFault(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteEndpointError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3409,17 +3513,21 @@ impl fmt::Display for DeleteEndpointError {
         match *self {
             DeleteEndpointError::InvalidResourceStateFault(ref cause) => write!(f, "{}", cause),
             DeleteEndpointError::ResourceNotFoundFault(ref cause) => write!(f, "{}", cause),
+            DeleteEndpointError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteEndpointError {}
 /// Errors returned by DeleteEventSubscription
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteEventSubscriptionError {
     /// <p>The resource is in a state that prevents it from being used for database migration.</p>
     InvalidResourceStateFault(String),
     /// <p>The resource could not be found.</p>
     ResourceNotFoundFault(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteEventSubscriptionError {
@@ -3427,17 +3535,19 @@ impl DeleteEventSubscriptionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidResourceStateFault" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DeleteEventSubscriptionError::InvalidResourceStateFault(err.msg),
+                        &res,
                     )
                 }
                 "ResourceNotFoundFault" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DeleteEventSubscriptionError::ResourceNotFoundFault(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteEventSubscriptionError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3453,17 +3563,21 @@ impl fmt::Display for DeleteEventSubscriptionError {
             DeleteEventSubscriptionError::ResourceNotFoundFault(ref cause) => {
                 write!(f, "{}", cause)
             }
+            DeleteEventSubscriptionError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteEventSubscriptionError {}
 /// Errors returned by DeleteReplicationInstance
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteReplicationInstanceError {
     /// <p>The resource is in a state that prevents it from being used for database migration.</p>
     InvalidResourceStateFault(String),
     /// <p>The resource could not be found.</p>
     ResourceNotFoundFault(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteReplicationInstanceError {
@@ -3471,17 +3585,21 @@ impl DeleteReplicationInstanceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidResourceStateFault" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DeleteReplicationInstanceError::InvalidResourceStateFault(err.msg),
+                        &res,
                     )
                 }
                 "ResourceNotFoundFault" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DeleteReplicationInstanceError::ResourceNotFoundFault(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(DeleteReplicationInstanceError::Unhandled, &res)
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -3497,17 +3615,21 @@ impl fmt::Display for DeleteReplicationInstanceError {
             DeleteReplicationInstanceError::ResourceNotFoundFault(ref cause) => {
                 write!(f, "{}", cause)
             }
+            DeleteReplicationInstanceError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteReplicationInstanceError {}
 /// Errors returned by DeleteReplicationSubnetGroup
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteReplicationSubnetGroupError {
     /// <p>The resource is in a state that prevents it from being used for database migration.</p>
     InvalidResourceStateFault(String),
     /// <p>The resource could not be found.</p>
     ResourceNotFoundFault(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteReplicationSubnetGroupError {
@@ -3517,17 +3639,24 @@ impl DeleteReplicationSubnetGroupError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidResourceStateFault" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DeleteReplicationSubnetGroupError::InvalidResourceStateFault(err.msg),
+                        &res,
                     )
                 }
                 "ResourceNotFoundFault" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DeleteReplicationSubnetGroupError::ResourceNotFoundFault(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        DeleteReplicationSubnetGroupError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -3543,17 +3672,21 @@ impl fmt::Display for DeleteReplicationSubnetGroupError {
             DeleteReplicationSubnetGroupError::ResourceNotFoundFault(ref cause) => {
                 write!(f, "{}", cause)
             }
+            DeleteReplicationSubnetGroupError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteReplicationSubnetGroupError {}
 /// Errors returned by DeleteReplicationTask
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteReplicationTaskError {
     /// <p>The resource is in a state that prevents it from being used for database migration.</p>
     InvalidResourceStateFault(String),
     /// <p>The resource could not be found.</p>
     ResourceNotFoundFault(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteReplicationTaskError {
@@ -3561,17 +3694,19 @@ impl DeleteReplicationTaskError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidResourceStateFault" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DeleteReplicationTaskError::InvalidResourceStateFault(err.msg),
+                        &res,
                     )
                 }
                 "ResourceNotFoundFault" => {
-                    return RusotoError::Service(DeleteReplicationTaskError::ResourceNotFoundFault(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteReplicationTaskError::ResourceNotFoundFault(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteReplicationTaskError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3585,20 +3720,27 @@ impl fmt::Display for DeleteReplicationTaskError {
                 write!(f, "{}", cause)
             }
             DeleteReplicationTaskError::ResourceNotFoundFault(ref cause) => write!(f, "{}", cause),
+            DeleteReplicationTaskError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteReplicationTaskError {}
 /// Errors returned by DescribeAccountAttributes
 #[derive(Debug, PartialEq)]
-pub enum DescribeAccountAttributesError {}
+#[non_exhaustive]
+pub enum DescribeAccountAttributesError {
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
+}
 
 impl DescribeAccountAttributesError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<DescribeAccountAttributesError> {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(DescribeAccountAttributesError::Unhandled, &res)
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -3607,15 +3749,20 @@ impl DescribeAccountAttributesError {
 impl fmt::Display for DescribeAccountAttributesError {
     #[allow(unused_variables)]
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        match *self {}
+        match *self {
+            DescribeAccountAttributesError::Unhandled(ref metadata) => write!(f, "{}", metadata),
+        }
     }
 }
 impl Error for DescribeAccountAttributesError {}
 /// Errors returned by DescribeCertificates
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeCertificatesError {
     /// <p>The resource could not be found.</p>
     ResourceNotFoundFault(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeCertificatesError {
@@ -3623,12 +3770,13 @@ impl DescribeCertificatesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFoundFault" => {
-                    return RusotoError::Service(DescribeCertificatesError::ResourceNotFoundFault(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeCertificatesError::ResourceNotFoundFault(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DescribeCertificatesError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3639,15 +3787,19 @@ impl fmt::Display for DescribeCertificatesError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             DescribeCertificatesError::ResourceNotFoundFault(ref cause) => write!(f, "{}", cause),
+            DescribeCertificatesError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeCertificatesError {}
 /// Errors returned by DescribeConnections
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeConnectionsError {
     /// <p>The resource could not be found.</p>
     ResourceNotFoundFault(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeConnectionsError {
@@ -3655,12 +3807,13 @@ impl DescribeConnectionsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFoundFault" => {
-                    return RusotoError::Service(DescribeConnectionsError::ResourceNotFoundFault(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeConnectionsError::ResourceNotFoundFault(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DescribeConnectionsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3671,20 +3824,25 @@ impl fmt::Display for DescribeConnectionsError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             DescribeConnectionsError::ResourceNotFoundFault(ref cause) => write!(f, "{}", cause),
+            DescribeConnectionsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeConnectionsError {}
 /// Errors returned by DescribeEndpointTypes
 #[derive(Debug, PartialEq)]
-pub enum DescribeEndpointTypesError {}
+#[non_exhaustive]
+pub enum DescribeEndpointTypesError {
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
+}
 
 impl DescribeEndpointTypesError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<DescribeEndpointTypesError> {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DescribeEndpointTypesError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3693,15 +3851,20 @@ impl DescribeEndpointTypesError {
 impl fmt::Display for DescribeEndpointTypesError {
     #[allow(unused_variables)]
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        match *self {}
+        match *self {
+            DescribeEndpointTypesError::Unhandled(ref metadata) => write!(f, "{}", metadata),
+        }
     }
 }
 impl Error for DescribeEndpointTypesError {}
 /// Errors returned by DescribeEndpoints
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeEndpointsError {
     /// <p>The resource could not be found.</p>
     ResourceNotFoundFault(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeEndpointsError {
@@ -3709,12 +3872,13 @@ impl DescribeEndpointsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFoundFault" => {
-                    return RusotoError::Service(DescribeEndpointsError::ResourceNotFoundFault(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeEndpointsError::ResourceNotFoundFault(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DescribeEndpointsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3725,20 +3889,25 @@ impl fmt::Display for DescribeEndpointsError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             DescribeEndpointsError::ResourceNotFoundFault(ref cause) => write!(f, "{}", cause),
+            DescribeEndpointsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeEndpointsError {}
 /// Errors returned by DescribeEventCategories
 #[derive(Debug, PartialEq)]
-pub enum DescribeEventCategoriesError {}
+#[non_exhaustive]
+pub enum DescribeEventCategoriesError {
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
+}
 
 impl DescribeEventCategoriesError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<DescribeEventCategoriesError> {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DescribeEventCategoriesError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3747,15 +3916,20 @@ impl DescribeEventCategoriesError {
 impl fmt::Display for DescribeEventCategoriesError {
     #[allow(unused_variables)]
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        match *self {}
+        match *self {
+            DescribeEventCategoriesError::Unhandled(ref metadata) => write!(f, "{}", metadata),
+        }
     }
 }
 impl Error for DescribeEventCategoriesError {}
 /// Errors returned by DescribeEventSubscriptions
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeEventSubscriptionsError {
     /// <p>The resource could not be found.</p>
     ResourceNotFoundFault(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeEventSubscriptionsError {
@@ -3765,12 +3939,15 @@ impl DescribeEventSubscriptionsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFoundFault" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DescribeEventSubscriptionsError::ResourceNotFoundFault(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(DescribeEventSubscriptionsError::Unhandled, &res)
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -3783,20 +3960,25 @@ impl fmt::Display for DescribeEventSubscriptionsError {
             DescribeEventSubscriptionsError::ResourceNotFoundFault(ref cause) => {
                 write!(f, "{}", cause)
             }
+            DescribeEventSubscriptionsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeEventSubscriptionsError {}
 /// Errors returned by DescribeEvents
 #[derive(Debug, PartialEq)]
-pub enum DescribeEventsError {}
+#[non_exhaustive]
+pub enum DescribeEventsError {
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
+}
 
 impl DescribeEventsError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<DescribeEventsError> {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DescribeEventsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3805,13 +3987,19 @@ impl DescribeEventsError {
 impl fmt::Display for DescribeEventsError {
     #[allow(unused_variables)]
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        match *self {}
+        match *self {
+            DescribeEventsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
+        }
     }
 }
 impl Error for DescribeEventsError {}
 /// Errors returned by DescribeOrderableReplicationInstances
 #[derive(Debug, PartialEq)]
-pub enum DescribeOrderableReplicationInstancesError {}
+#[non_exhaustive]
+pub enum DescribeOrderableReplicationInstancesError {
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
+}
 
 impl DescribeOrderableReplicationInstancesError {
     pub fn from_response(
@@ -3820,7 +4008,12 @@ impl DescribeOrderableReplicationInstancesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        DescribeOrderableReplicationInstancesError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -3829,15 +4022,22 @@ impl DescribeOrderableReplicationInstancesError {
 impl fmt::Display for DescribeOrderableReplicationInstancesError {
     #[allow(unused_variables)]
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        match *self {}
+        match *self {
+            DescribeOrderableReplicationInstancesError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
+        }
     }
 }
 impl Error for DescribeOrderableReplicationInstancesError {}
 /// Errors returned by DescribePendingMaintenanceActions
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribePendingMaintenanceActionsError {
     /// <p>The resource could not be found.</p>
     ResourceNotFoundFault(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribePendingMaintenanceActionsError {
@@ -3847,12 +4047,18 @@ impl DescribePendingMaintenanceActionsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFoundFault" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DescribePendingMaintenanceActionsError::ResourceNotFoundFault(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        DescribePendingMaintenanceActionsError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -3865,17 +4071,23 @@ impl fmt::Display for DescribePendingMaintenanceActionsError {
             DescribePendingMaintenanceActionsError::ResourceNotFoundFault(ref cause) => {
                 write!(f, "{}", cause)
             }
+            DescribePendingMaintenanceActionsError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for DescribePendingMaintenanceActionsError {}
 /// Errors returned by DescribeRefreshSchemasStatus
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeRefreshSchemasStatusError {
     /// <p>The resource is in a state that prevents it from being used for database migration.</p>
     InvalidResourceStateFault(String),
     /// <p>The resource could not be found.</p>
     ResourceNotFoundFault(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeRefreshSchemasStatusError {
@@ -3885,17 +4097,24 @@ impl DescribeRefreshSchemasStatusError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidResourceStateFault" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DescribeRefreshSchemasStatusError::InvalidResourceStateFault(err.msg),
+                        &res,
                     )
                 }
                 "ResourceNotFoundFault" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DescribeRefreshSchemasStatusError::ResourceNotFoundFault(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        DescribeRefreshSchemasStatusError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -3911,17 +4130,21 @@ impl fmt::Display for DescribeRefreshSchemasStatusError {
             DescribeRefreshSchemasStatusError::ResourceNotFoundFault(ref cause) => {
                 write!(f, "{}", cause)
             }
+            DescribeRefreshSchemasStatusError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeRefreshSchemasStatusError {}
 /// Errors returned by DescribeReplicationInstanceTaskLogs
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeReplicationInstanceTaskLogsError {
     /// <p>The resource is in a state that prevents it from being used for database migration.</p>
     InvalidResourceStateFault(String),
     /// <p>The resource could not be found.</p>
     ResourceNotFoundFault(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeReplicationInstanceTaskLogsError {
@@ -3931,19 +4154,26 @@ impl DescribeReplicationInstanceTaskLogsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidResourceStateFault" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DescribeReplicationInstanceTaskLogsError::InvalidResourceStateFault(
                             err.msg,
                         ),
+                        &res,
                     )
                 }
                 "ResourceNotFoundFault" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DescribeReplicationInstanceTaskLogsError::ResourceNotFoundFault(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        DescribeReplicationInstanceTaskLogsError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -3959,15 +4189,21 @@ impl fmt::Display for DescribeReplicationInstanceTaskLogsError {
             DescribeReplicationInstanceTaskLogsError::ResourceNotFoundFault(ref cause) => {
                 write!(f, "{}", cause)
             }
+            DescribeReplicationInstanceTaskLogsError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for DescribeReplicationInstanceTaskLogsError {}
 /// Errors returned by DescribeReplicationInstances
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeReplicationInstancesError {
     /// <p>The resource could not be found.</p>
     ResourceNotFoundFault(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeReplicationInstancesError {
@@ -3977,12 +4213,18 @@ impl DescribeReplicationInstancesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFoundFault" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DescribeReplicationInstancesError::ResourceNotFoundFault(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        DescribeReplicationInstancesError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -3995,15 +4237,19 @@ impl fmt::Display for DescribeReplicationInstancesError {
             DescribeReplicationInstancesError::ResourceNotFoundFault(ref cause) => {
                 write!(f, "{}", cause)
             }
+            DescribeReplicationInstancesError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeReplicationInstancesError {}
 /// Errors returned by DescribeReplicationSubnetGroups
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeReplicationSubnetGroupsError {
     /// <p>The resource could not be found.</p>
     ResourceNotFoundFault(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeReplicationSubnetGroupsError {
@@ -4013,12 +4259,18 @@ impl DescribeReplicationSubnetGroupsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFoundFault" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DescribeReplicationSubnetGroupsError::ResourceNotFoundFault(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        DescribeReplicationSubnetGroupsError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -4031,15 +4283,21 @@ impl fmt::Display for DescribeReplicationSubnetGroupsError {
             DescribeReplicationSubnetGroupsError::ResourceNotFoundFault(ref cause) => {
                 write!(f, "{}", cause)
             }
+            DescribeReplicationSubnetGroupsError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for DescribeReplicationSubnetGroupsError {}
 /// Errors returned by DescribeReplicationTaskAssessmentResults
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeReplicationTaskAssessmentResultsError {
     /// <p>The resource could not be found.</p>
     ResourceNotFoundFault(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeReplicationTaskAssessmentResultsError {
@@ -4049,14 +4307,20 @@ impl DescribeReplicationTaskAssessmentResultsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFoundFault" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DescribeReplicationTaskAssessmentResultsError::ResourceNotFoundFault(
                             err.msg,
                         ),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        DescribeReplicationTaskAssessmentResultsError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -4069,15 +4333,21 @@ impl fmt::Display for DescribeReplicationTaskAssessmentResultsError {
             DescribeReplicationTaskAssessmentResultsError::ResourceNotFoundFault(ref cause) => {
                 write!(f, "{}", cause)
             }
+            DescribeReplicationTaskAssessmentResultsError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for DescribeReplicationTaskAssessmentResultsError {}
 /// Errors returned by DescribeReplicationTasks
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeReplicationTasksError {
     /// <p>The resource could not be found.</p>
     ResourceNotFoundFault(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeReplicationTasksError {
@@ -4085,12 +4355,13 @@ impl DescribeReplicationTasksError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFoundFault" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DescribeReplicationTasksError::ResourceNotFoundFault(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DescribeReplicationTasksError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -4103,17 +4374,21 @@ impl fmt::Display for DescribeReplicationTasksError {
             DescribeReplicationTasksError::ResourceNotFoundFault(ref cause) => {
                 write!(f, "{}", cause)
             }
+            DescribeReplicationTasksError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeReplicationTasksError {}
 /// Errors returned by DescribeSchemas
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeSchemasError {
     /// <p>The resource is in a state that prevents it from being used for database migration.</p>
     InvalidResourceStateFault(String),
     /// <p>The resource could not be found.</p>
     ResourceNotFoundFault(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeSchemasError {
@@ -4121,17 +4396,19 @@ impl DescribeSchemasError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidResourceStateFault" => {
-                    return RusotoError::Service(DescribeSchemasError::InvalidResourceStateFault(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeSchemasError::InvalidResourceStateFault(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundFault" => {
-                    return RusotoError::Service(DescribeSchemasError::ResourceNotFoundFault(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeSchemasError::ResourceNotFoundFault(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DescribeSchemasError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -4143,17 +4420,21 @@ impl fmt::Display for DescribeSchemasError {
         match *self {
             DescribeSchemasError::InvalidResourceStateFault(ref cause) => write!(f, "{}", cause),
             DescribeSchemasError::ResourceNotFoundFault(ref cause) => write!(f, "{}", cause),
+            DescribeSchemasError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeSchemasError {}
 /// Errors returned by DescribeTableStatistics
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeTableStatisticsError {
     /// <p>The resource is in a state that prevents it from being used for database migration.</p>
     InvalidResourceStateFault(String),
     /// <p>The resource could not be found.</p>
     ResourceNotFoundFault(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeTableStatisticsError {
@@ -4161,17 +4442,19 @@ impl DescribeTableStatisticsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidResourceStateFault" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DescribeTableStatisticsError::InvalidResourceStateFault(err.msg),
+                        &res,
                     )
                 }
                 "ResourceNotFoundFault" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DescribeTableStatisticsError::ResourceNotFoundFault(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DescribeTableStatisticsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -4187,12 +4470,14 @@ impl fmt::Display for DescribeTableStatisticsError {
             DescribeTableStatisticsError::ResourceNotFoundFault(ref cause) => {
                 write!(f, "{}", cause)
             }
+            DescribeTableStatisticsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeTableStatisticsError {}
 /// Errors returned by ImportCertificate
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ImportCertificateError {
     /// <p>The certificate was not valid.</p>
     InvalidCertificateFault(String),
@@ -4200,6 +4485,8 @@ pub enum ImportCertificateError {
     ResourceAlreadyExistsFault(String),
     /// <p>The quota for this resource quota has been exceeded.</p>
     ResourceQuotaExceededFault(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ImportCertificateError {
@@ -4207,22 +4494,25 @@ impl ImportCertificateError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidCertificateFault" => {
-                    return RusotoError::Service(ImportCertificateError::InvalidCertificateFault(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ImportCertificateError::InvalidCertificateFault(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceAlreadyExistsFault" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         ImportCertificateError::ResourceAlreadyExistsFault(err.msg),
+                        &res,
                     )
                 }
                 "ResourceQuotaExceededFault" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         ImportCertificateError::ResourceQuotaExceededFault(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ImportCertificateError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -4235,15 +4525,19 @@ impl fmt::Display for ImportCertificateError {
             ImportCertificateError::InvalidCertificateFault(ref cause) => write!(f, "{}", cause),
             ImportCertificateError::ResourceAlreadyExistsFault(ref cause) => write!(f, "{}", cause),
             ImportCertificateError::ResourceQuotaExceededFault(ref cause) => write!(f, "{}", cause),
+            ImportCertificateError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ImportCertificateError {}
 /// Errors returned by ListTagsForResource
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListTagsForResourceError {
     /// <p>The resource could not be found.</p>
     ResourceNotFoundFault(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListTagsForResourceError {
@@ -4251,12 +4545,13 @@ impl ListTagsForResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFoundFault" => {
-                    return RusotoError::Service(ListTagsForResourceError::ResourceNotFoundFault(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListTagsForResourceError::ResourceNotFoundFault(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListTagsForResourceError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -4267,12 +4562,14 @@ impl fmt::Display for ListTagsForResourceError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             ListTagsForResourceError::ResourceNotFoundFault(ref cause) => write!(f, "{}", cause),
+            ListTagsForResourceError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListTagsForResourceError {}
 /// Errors returned by ModifyEndpoint
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ModifyEndpointError {
     /// <p>AWS DMS was denied access to the endpoint. Check that the role is correctly configured.</p>
     AccessDeniedFault(String),
@@ -4284,6 +4581,8 @@ pub enum ModifyEndpointError {
     ResourceAlreadyExistsFault(String),
     /// <p>The resource could not be found.</p>
     ResourceNotFoundFault(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ModifyEndpointError {
@@ -4291,30 +4590,37 @@ impl ModifyEndpointError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedFault" => {
-                    return RusotoError::Service(ModifyEndpointError::AccessDeniedFault(err.msg))
+                    return RusotoError::service(
+                        ModifyEndpointError::AccessDeniedFault(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidResourceStateFault" => {
-                    return RusotoError::Service(ModifyEndpointError::InvalidResourceStateFault(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ModifyEndpointError::InvalidResourceStateFault(err.msg),
+                        &res,
+                    )
                 }
                 "KMSKeyNotAccessibleFault" => {
-                    return RusotoError::Service(ModifyEndpointError::KMSKeyNotAccessibleFault(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ModifyEndpointError::KMSKeyNotAccessibleFault(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceAlreadyExistsFault" => {
-                    return RusotoError::Service(ModifyEndpointError::ResourceAlreadyExistsFault(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ModifyEndpointError::ResourceAlreadyExistsFault(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundFault" => {
-                    return RusotoError::Service(ModifyEndpointError::ResourceNotFoundFault(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ModifyEndpointError::ResourceNotFoundFault(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ModifyEndpointError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -4329,12 +4635,14 @@ impl fmt::Display for ModifyEndpointError {
             ModifyEndpointError::KMSKeyNotAccessibleFault(ref cause) => write!(f, "{}", cause),
             ModifyEndpointError::ResourceAlreadyExistsFault(ref cause) => write!(f, "{}", cause),
             ModifyEndpointError::ResourceNotFoundFault(ref cause) => write!(f, "{}", cause),
+            ModifyEndpointError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ModifyEndpointError {}
 /// Errors returned by ModifyEventSubscription
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ModifyEventSubscriptionError {
     /// <p>The ciphertext references a key that doesn't exist or that the DMS account doesn't have access to.</p>
     KMSAccessDeniedFault(String),
@@ -4354,6 +4662,8 @@ pub enum ModifyEventSubscriptionError {
     SNSInvalidTopicFault(String),
     /// <p>You are not authorized for the SNS subscription.</p>
     SNSNoAuthorizationFault(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ModifyEventSubscriptionError {
@@ -4361,52 +4671,61 @@ impl ModifyEventSubscriptionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "KMSAccessDeniedFault" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         ModifyEventSubscriptionError::KMSAccessDeniedFault(err.msg),
+                        &res,
                     )
                 }
                 "KMSDisabledFault" => {
-                    return RusotoError::Service(ModifyEventSubscriptionError::KMSDisabledFault(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ModifyEventSubscriptionError::KMSDisabledFault(err.msg),
+                        &res,
+                    )
                 }
                 "KMSInvalidStateFault" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         ModifyEventSubscriptionError::KMSInvalidStateFault(err.msg),
+                        &res,
                     )
                 }
                 "KMSNotFoundFault" => {
-                    return RusotoError::Service(ModifyEventSubscriptionError::KMSNotFoundFault(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ModifyEventSubscriptionError::KMSNotFoundFault(err.msg),
+                        &res,
+                    )
                 }
                 "KMSThrottlingFault" => {
-                    return RusotoError::Service(ModifyEventSubscriptionError::KMSThrottlingFault(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ModifyEventSubscriptionError::KMSThrottlingFault(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundFault" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         ModifyEventSubscriptionError::ResourceNotFoundFault(err.msg),
+                        &res,
                     )
                 }
                 "ResourceQuotaExceededFault" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         ModifyEventSubscriptionError::ResourceQuotaExceededFault(err.msg),
+                        &res,
                     )
                 }
                 "SNSInvalidTopicFault" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         ModifyEventSubscriptionError::SNSInvalidTopicFault(err.msg),
+                        &res,
                     )
                 }
                 "SNSNoAuthorizationFault" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         ModifyEventSubscriptionError::SNSNoAuthorizationFault(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ModifyEventSubscriptionError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -4431,12 +4750,14 @@ impl fmt::Display for ModifyEventSubscriptionError {
             ModifyEventSubscriptionError::SNSNoAuthorizationFault(ref cause) => {
                 write!(f, "{}", cause)
             }
+            ModifyEventSubscriptionError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ModifyEventSubscriptionError {}
 /// Errors returned by ModifyReplicationInstance
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ModifyReplicationInstanceError {
     /// <p>AWS DMS was denied access to the endpoint. Check that the role is correctly configured.</p>
     AccessDeniedFault(String),
@@ -4452,6 +4773,8 @@ pub enum ModifyReplicationInstanceError {
     StorageQuotaExceededFault(String),
     /// <p>An upgrade dependency is preventing the database migration.</p>
     UpgradeDependencyFailureFault(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ModifyReplicationInstanceError {
@@ -4459,42 +4782,51 @@ impl ModifyReplicationInstanceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedFault" => {
-                    return RusotoError::Service(ModifyReplicationInstanceError::AccessDeniedFault(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ModifyReplicationInstanceError::AccessDeniedFault(err.msg),
+                        &res,
+                    )
                 }
                 "InsufficientResourceCapacityFault" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         ModifyReplicationInstanceError::InsufficientResourceCapacityFault(err.msg),
+                        &res,
                     )
                 }
                 "InvalidResourceStateFault" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         ModifyReplicationInstanceError::InvalidResourceStateFault(err.msg),
+                        &res,
                     )
                 }
                 "ResourceAlreadyExistsFault" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         ModifyReplicationInstanceError::ResourceAlreadyExistsFault(err.msg),
+                        &res,
                     )
                 }
                 "ResourceNotFoundFault" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         ModifyReplicationInstanceError::ResourceNotFoundFault(err.msg),
+                        &res,
                     )
                 }
                 "StorageQuotaExceededFault" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         ModifyReplicationInstanceError::StorageQuotaExceededFault(err.msg),
+                        &res,
                     )
                 }
                 "UpgradeDependencyFailureFault" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         ModifyReplicationInstanceError::UpgradeDependencyFailureFault(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(ModifyReplicationInstanceError::Unhandled, &res)
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -4523,12 +4855,14 @@ impl fmt::Display for ModifyReplicationInstanceError {
             ModifyReplicationInstanceError::UpgradeDependencyFailureFault(ref cause) => {
                 write!(f, "{}", cause)
             }
+            ModifyReplicationInstanceError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ModifyReplicationInstanceError {}
 /// Errors returned by ModifyReplicationSubnetGroup
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ModifyReplicationSubnetGroupError {
     /// <p>AWS DMS was denied access to the endpoint. Check that the role is correctly configured.</p>
     AccessDeniedFault(String),
@@ -4542,6 +4876,8 @@ pub enum ModifyReplicationSubnetGroupError {
     ResourceQuotaExceededFault(String),
     /// <p>The specified subnet is already in use.</p>
     SubnetAlreadyInUse(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ModifyReplicationSubnetGroupError {
@@ -4551,37 +4887,48 @@ impl ModifyReplicationSubnetGroupError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedFault" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         ModifyReplicationSubnetGroupError::AccessDeniedFault(err.msg),
+                        &res,
                     )
                 }
                 "InvalidSubnet" => {
-                    return RusotoError::Service(ModifyReplicationSubnetGroupError::InvalidSubnet(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ModifyReplicationSubnetGroupError::InvalidSubnet(err.msg),
+                        &res,
+                    )
                 }
-                "ReplicationSubnetGroupDoesNotCoverEnoughAZs" => return RusotoError::Service(
+                "ReplicationSubnetGroupDoesNotCoverEnoughAZs" => return RusotoError::service(
                     ModifyReplicationSubnetGroupError::ReplicationSubnetGroupDoesNotCoverEnoughAZs(
                         err.msg,
                     ),
+                    &res,
                 ),
                 "ResourceNotFoundFault" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         ModifyReplicationSubnetGroupError::ResourceNotFoundFault(err.msg),
+                        &res,
                     )
                 }
                 "ResourceQuotaExceededFault" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         ModifyReplicationSubnetGroupError::ResourceQuotaExceededFault(err.msg),
+                        &res,
                     )
                 }
                 "SubnetAlreadyInUse" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         ModifyReplicationSubnetGroupError::SubnetAlreadyInUse(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        ModifyReplicationSubnetGroupError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -4607,12 +4954,14 @@ impl fmt::Display for ModifyReplicationSubnetGroupError {
             ModifyReplicationSubnetGroupError::SubnetAlreadyInUse(ref cause) => {
                 write!(f, "{}", cause)
             }
+            ModifyReplicationSubnetGroupError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ModifyReplicationSubnetGroupError {}
 /// Errors returned by ModifyReplicationTask
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ModifyReplicationTaskError {
     /// <p>The resource is in a state that prevents it from being used for database migration.</p>
     InvalidResourceStateFault(String),
@@ -4622,6 +4971,8 @@ pub enum ModifyReplicationTaskError {
     ResourceAlreadyExistsFault(String),
     /// <p>The resource could not be found.</p>
     ResourceNotFoundFault(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ModifyReplicationTaskError {
@@ -4629,27 +4980,31 @@ impl ModifyReplicationTaskError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidResourceStateFault" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         ModifyReplicationTaskError::InvalidResourceStateFault(err.msg),
+                        &res,
                     )
                 }
                 "KMSKeyNotAccessibleFault" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         ModifyReplicationTaskError::KMSKeyNotAccessibleFault(err.msg),
+                        &res,
                     )
                 }
                 "ResourceAlreadyExistsFault" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         ModifyReplicationTaskError::ResourceAlreadyExistsFault(err.msg),
+                        &res,
                     )
                 }
                 "ResourceNotFoundFault" => {
-                    return RusotoError::Service(ModifyReplicationTaskError::ResourceNotFoundFault(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ModifyReplicationTaskError::ResourceNotFoundFault(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ModifyReplicationTaskError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -4669,17 +5024,21 @@ impl fmt::Display for ModifyReplicationTaskError {
                 write!(f, "{}", cause)
             }
             ModifyReplicationTaskError::ResourceNotFoundFault(ref cause) => write!(f, "{}", cause),
+            ModifyReplicationTaskError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ModifyReplicationTaskError {}
 /// Errors returned by RebootReplicationInstance
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum RebootReplicationInstanceError {
     /// <p>The resource is in a state that prevents it from being used for database migration.</p>
     InvalidResourceStateFault(String),
     /// <p>The resource could not be found.</p>
     ResourceNotFoundFault(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl RebootReplicationInstanceError {
@@ -4687,17 +5046,21 @@ impl RebootReplicationInstanceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidResourceStateFault" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         RebootReplicationInstanceError::InvalidResourceStateFault(err.msg),
+                        &res,
                     )
                 }
                 "ResourceNotFoundFault" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         RebootReplicationInstanceError::ResourceNotFoundFault(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(RebootReplicationInstanceError::Unhandled, &res)
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -4713,12 +5076,14 @@ impl fmt::Display for RebootReplicationInstanceError {
             RebootReplicationInstanceError::ResourceNotFoundFault(ref cause) => {
                 write!(f, "{}", cause)
             }
+            RebootReplicationInstanceError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for RebootReplicationInstanceError {}
 /// Errors returned by RefreshSchemas
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum RefreshSchemasError {
     /// <p>The resource is in a state that prevents it from being used for database migration.</p>
     InvalidResourceStateFault(String),
@@ -4728,6 +5093,8 @@ pub enum RefreshSchemasError {
     ResourceNotFoundFault(String),
     /// <p>The quota for this resource quota has been exceeded.</p>
     ResourceQuotaExceededFault(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl RefreshSchemasError {
@@ -4735,27 +5102,31 @@ impl RefreshSchemasError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidResourceStateFault" => {
-                    return RusotoError::Service(RefreshSchemasError::InvalidResourceStateFault(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        RefreshSchemasError::InvalidResourceStateFault(err.msg),
+                        &res,
+                    )
                 }
                 "KMSKeyNotAccessibleFault" => {
-                    return RusotoError::Service(RefreshSchemasError::KMSKeyNotAccessibleFault(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        RefreshSchemasError::KMSKeyNotAccessibleFault(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundFault" => {
-                    return RusotoError::Service(RefreshSchemasError::ResourceNotFoundFault(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        RefreshSchemasError::ResourceNotFoundFault(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceQuotaExceededFault" => {
-                    return RusotoError::Service(RefreshSchemasError::ResourceQuotaExceededFault(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        RefreshSchemasError::ResourceQuotaExceededFault(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(RefreshSchemasError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -4769,17 +5140,21 @@ impl fmt::Display for RefreshSchemasError {
             RefreshSchemasError::KMSKeyNotAccessibleFault(ref cause) => write!(f, "{}", cause),
             RefreshSchemasError::ResourceNotFoundFault(ref cause) => write!(f, "{}", cause),
             RefreshSchemasError::ResourceQuotaExceededFault(ref cause) => write!(f, "{}", cause),
+            RefreshSchemasError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for RefreshSchemasError {}
 /// Errors returned by ReloadTables
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ReloadTablesError {
     /// <p>The resource is in a state that prevents it from being used for database migration.</p>
     InvalidResourceStateFault(String),
     /// <p>The resource could not be found.</p>
     ResourceNotFoundFault(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ReloadTablesError {
@@ -4787,15 +5162,19 @@ impl ReloadTablesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidResourceStateFault" => {
-                    return RusotoError::Service(ReloadTablesError::InvalidResourceStateFault(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ReloadTablesError::InvalidResourceStateFault(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundFault" => {
-                    return RusotoError::Service(ReloadTablesError::ResourceNotFoundFault(err.msg))
+                    return RusotoError::service(
+                        ReloadTablesError::ResourceNotFoundFault(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ReloadTablesError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -4807,15 +5186,19 @@ impl fmt::Display for ReloadTablesError {
         match *self {
             ReloadTablesError::InvalidResourceStateFault(ref cause) => write!(f, "{}", cause),
             ReloadTablesError::ResourceNotFoundFault(ref cause) => write!(f, "{}", cause),
+            ReloadTablesError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ReloadTablesError {}
 /// Errors returned by RemoveTagsFromResource
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum RemoveTagsFromResourceError {
     /// <p>The resource could not be found.</p>
     ResourceNotFoundFault(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl RemoveTagsFromResourceError {
@@ -4823,12 +5206,13 @@ impl RemoveTagsFromResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFoundFault" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         RemoveTagsFromResourceError::ResourceNotFoundFault(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(RemoveTagsFromResourceError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -4839,12 +5223,14 @@ impl fmt::Display for RemoveTagsFromResourceError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             RemoveTagsFromResourceError::ResourceNotFoundFault(ref cause) => write!(f, "{}", cause),
+            RemoveTagsFromResourceError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for RemoveTagsFromResourceError {}
 /// Errors returned by StartReplicationTask
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum StartReplicationTaskError {
     /// <p>AWS DMS was denied access to the endpoint. Check that the role is correctly configured.</p>
     AccessDeniedFault(String),
@@ -4852,6 +5238,8 @@ pub enum StartReplicationTaskError {
     InvalidResourceStateFault(String),
     /// <p>The resource could not be found.</p>
     ResourceNotFoundFault(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl StartReplicationTaskError {
@@ -4859,22 +5247,25 @@ impl StartReplicationTaskError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedFault" => {
-                    return RusotoError::Service(StartReplicationTaskError::AccessDeniedFault(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        StartReplicationTaskError::AccessDeniedFault(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidResourceStateFault" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         StartReplicationTaskError::InvalidResourceStateFault(err.msg),
+                        &res,
                     )
                 }
                 "ResourceNotFoundFault" => {
-                    return RusotoError::Service(StartReplicationTaskError::ResourceNotFoundFault(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        StartReplicationTaskError::ResourceNotFoundFault(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(StartReplicationTaskError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -4889,17 +5280,21 @@ impl fmt::Display for StartReplicationTaskError {
                 write!(f, "{}", cause)
             }
             StartReplicationTaskError::ResourceNotFoundFault(ref cause) => write!(f, "{}", cause),
+            StartReplicationTaskError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for StartReplicationTaskError {}
 /// Errors returned by StartReplicationTaskAssessment
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum StartReplicationTaskAssessmentError {
     /// <p>The resource is in a state that prevents it from being used for database migration.</p>
     InvalidResourceStateFault(String),
     /// <p>The resource could not be found.</p>
     ResourceNotFoundFault(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl StartReplicationTaskAssessmentError {
@@ -4909,17 +5304,24 @@ impl StartReplicationTaskAssessmentError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidResourceStateFault" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         StartReplicationTaskAssessmentError::InvalidResourceStateFault(err.msg),
+                        &res,
                     )
                 }
                 "ResourceNotFoundFault" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         StartReplicationTaskAssessmentError::ResourceNotFoundFault(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        StartReplicationTaskAssessmentError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -4935,17 +5337,23 @@ impl fmt::Display for StartReplicationTaskAssessmentError {
             StartReplicationTaskAssessmentError::ResourceNotFoundFault(ref cause) => {
                 write!(f, "{}", cause)
             }
+            StartReplicationTaskAssessmentError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for StartReplicationTaskAssessmentError {}
 /// Errors returned by StopReplicationTask
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum StopReplicationTaskError {
     /// <p>The resource is in a state that prevents it from being used for database migration.</p>
     InvalidResourceStateFault(String),
     /// <p>The resource could not be found.</p>
     ResourceNotFoundFault(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl StopReplicationTaskError {
@@ -4953,17 +5361,19 @@ impl StopReplicationTaskError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidResourceStateFault" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         StopReplicationTaskError::InvalidResourceStateFault(err.msg),
+                        &res,
                     )
                 }
                 "ResourceNotFoundFault" => {
-                    return RusotoError::Service(StopReplicationTaskError::ResourceNotFoundFault(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        StopReplicationTaskError::ResourceNotFoundFault(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(StopReplicationTaskError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -4977,12 +5387,14 @@ impl fmt::Display for StopReplicationTaskError {
                 write!(f, "{}", cause)
             }
             StopReplicationTaskError::ResourceNotFoundFault(ref cause) => write!(f, "{}", cause),
+            StopReplicationTaskError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for StopReplicationTaskError {}
 /// Errors returned by TestConnection
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum TestConnectionError {
     /// <p>The resource is in a state that prevents it from being used for database migration.</p>
     InvalidResourceStateFault(String),
@@ -4992,6 +5404,8 @@ pub enum TestConnectionError {
     ResourceNotFoundFault(String),
     /// <p>The quota for this resource quota has been exceeded.</p>
     ResourceQuotaExceededFault(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl TestConnectionError {
@@ -4999,27 +5413,31 @@ impl TestConnectionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidResourceStateFault" => {
-                    return RusotoError::Service(TestConnectionError::InvalidResourceStateFault(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        TestConnectionError::InvalidResourceStateFault(err.msg),
+                        &res,
+                    )
                 }
                 "KMSKeyNotAccessibleFault" => {
-                    return RusotoError::Service(TestConnectionError::KMSKeyNotAccessibleFault(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        TestConnectionError::KMSKeyNotAccessibleFault(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundFault" => {
-                    return RusotoError::Service(TestConnectionError::ResourceNotFoundFault(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        TestConnectionError::ResourceNotFoundFault(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceQuotaExceededFault" => {
-                    return RusotoError::Service(TestConnectionError::ResourceQuotaExceededFault(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        TestConnectionError::ResourceQuotaExceededFault(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(TestConnectionError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -5033,6 +5451,7 @@ impl fmt::Display for TestConnectionError {
             TestConnectionError::KMSKeyNotAccessibleFault(ref cause) => write!(f, "{}", cause),
             TestConnectionError::ResourceNotFoundFault(ref cause) => write!(f, "{}", cause),
             TestConnectionError::ResourceQuotaExceededFault(ref cause) => write!(f, "{}", cause),
+            TestConnectionError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
@@ -5353,6 +5772,7 @@ impl DatabaseMigrationServiceClient {
     /// Creates a client backed by the default tokio event loop.
     ///
     /// The client will use the default credentials provider and tls client.
+    #[cfg(not(target_arch = "wasm32"))]
     pub fn new(region: region::Region) -> DatabaseMigrationServiceClient {
         DatabaseMigrationServiceClient {
             client: Client::shared(),
@@ -5381,47 +5801,129 @@ impl DatabaseMigrationServiceClient {
     ) -> DatabaseMigrationServiceClient {
         DatabaseMigrationServiceClient { client, region }
     }
+
+    /// Returns a builder of a client, setting any of its region, credentials provider,
+    /// dispatcher, retry policy, endpoint and timeouts.
+    pub fn builder() -> rusoto_core::ClientBuilder<DatabaseMigrationServiceClient> {
+        rusoto_core::ClientBuilder::new(DatabaseMigrationServiceClient::new_with_client)
+    }
+
+    /// Returns a clone of this client with the overrides of `config` applied to its
+    /// operations, sharing its connections.
+    pub fn customize(
+        &self,
+        config: &rusoto_core::OperationConfig,
+    ) -> DatabaseMigrationServiceClient {
+        DatabaseMigrationServiceClient {
+            client: self.client.customize(config),
+            region: config.resolve_region(&self.region),
+        }
+    }
 }
 
-#[async_trait]
-impl DatabaseMigrationService for DatabaseMigrationServiceClient {
-    /// <p>Adds metadata tags to an AWS DMS resource, including replication instance, endpoint, security group, and migration task. These tags can also be used with cost allocation reporting to track cost associated with DMS resources, or used in a Condition statement in an IAM policy for DMS.</p>
-    async fn add_tags_to_resource(
+impl DatabaseMigrationServiceClient {
+    /// Calls `add_tags_to_resource`, also returning the metadata of the response.
+    pub async fn add_tags_to_resource_with_metadata(
         &self,
         input: AddTagsToResourceMessage,
-    ) -> Result<AddTagsToResourceResponse, RusotoError<AddTagsToResourceError>> {
+    ) -> Result<(AddTagsToResourceResponse, ResponseMetadata), RusotoError<AddTagsToResourceError>>
+    {
+        let request = self.build_add_tags_to_resource_request(input);
+
+        let mut response = self
+            .client
+            .sign_and_dispatch(request)
+            .await
+            .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
+        if response.status.is_success() {
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<AddTagsToResourceResponse, _>()?;
+            Ok((result, metadata))
+        } else {
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(AddTagsToResourceError::from_response(response))
+        }
+    }
+
+    /// Presigns the request `add_tags_to_resource` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_add_tags_to_resource(
+        &self,
+        presigner: &rusoto_core::Presigner,
+        input: AddTagsToResourceMessage,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_add_tags_to_resource_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_add_tags_to_resource_request(&self, input: AddTagsToResourceMessage) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "dms", &self.region, "/");
+        request.set_operation("AddTagsToResource");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonDMSv20160101.AddTagsToResource");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `apply_pending_maintenance_action`, also returning the metadata of the response.
+    pub async fn apply_pending_maintenance_action_with_metadata(
+        &self,
+        input: ApplyPendingMaintenanceActionMessage,
+    ) -> Result<
+        (ApplyPendingMaintenanceActionResponse, ResponseMetadata),
+        RusotoError<ApplyPendingMaintenanceActionError>,
+    > {
+        let request = self.build_apply_pending_maintenance_action_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<AddTagsToResourceResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ApplyPendingMaintenanceActionResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(AddTagsToResourceError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ApplyPendingMaintenanceActionError::from_response(response))
         }
     }
 
-    /// <p>Applies a pending maintenance action to a resource (for example, to a replication instance).</p>
-    async fn apply_pending_maintenance_action(
+    /// Presigns the request `apply_pending_maintenance_action` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_apply_pending_maintenance_action(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ApplyPendingMaintenanceActionMessage,
-    ) -> Result<
-        ApplyPendingMaintenanceActionResponse,
-        RusotoError<ApplyPendingMaintenanceActionError>,
-    > {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_apply_pending_maintenance_action_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_apply_pending_maintenance_action_request(
+        &self,
+        input: ApplyPendingMaintenanceActionMessage,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "dms", &self.region, "/");
+        request.set_operation("ApplyPendingMaintenanceAction");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -5431,84 +5933,168 @@ impl DatabaseMigrationService for DatabaseMigrationServiceClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `create_endpoint`, also returning the metadata of the response.
+    pub async fn create_endpoint_with_metadata(
+        &self,
+        input: CreateEndpointMessage,
+    ) -> Result<(CreateEndpointResponse, ResponseMetadata), RusotoError<CreateEndpointError>> {
+        let request = self.build_create_endpoint_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<ApplyPendingMaintenanceActionResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<CreateEndpointResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ApplyPendingMaintenanceActionError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(CreateEndpointError::from_response(response))
         }
     }
 
-    /// <p>Creates an endpoint using the provided settings.</p>
-    async fn create_endpoint(
+    /// Presigns the request `create_endpoint` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_endpoint(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: CreateEndpointMessage,
-    ) -> Result<CreateEndpointResponse, RusotoError<CreateEndpointError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_endpoint_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_endpoint_request(&self, input: CreateEndpointMessage) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "dms", &self.region, "/");
+        request.set_operation("CreateEndpoint");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonDMSv20160101.CreateEndpoint");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `create_event_subscription`, also returning the metadata of the response.
+    pub async fn create_event_subscription_with_metadata(
+        &self,
+        input: CreateEventSubscriptionMessage,
+    ) -> Result<
+        (CreateEventSubscriptionResponse, ResponseMetadata),
+        RusotoError<CreateEventSubscriptionError>,
+    > {
+        let request = self.build_create_event_subscription_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<CreateEndpointResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<CreateEventSubscriptionResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(CreateEndpointError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(CreateEventSubscriptionError::from_response(response))
         }
     }
 
-    /// <p> Creates an AWS DMS event notification subscription. </p> <p>You can specify the type of source (<code>SourceType</code>) you want to be notified of, provide a list of AWS DMS source IDs (<code>SourceIds</code>) that triggers the events, and provide a list of event categories (<code>EventCategories</code>) for events you want to be notified of. If you specify both the <code>SourceType</code> and <code>SourceIds</code>, such as <code>SourceType = replication-instance</code> and <code>SourceIdentifier = my-replinstance</code>, you will be notified of all the replication instance events for the specified source. If you specify a <code>SourceType</code> but don't specify a <code>SourceIdentifier</code>, you receive notice of the events for that source type for all your AWS DMS sources. If you don't specify either <code>SourceType</code> nor <code>SourceIdentifier</code>, you will be notified of events generated from all AWS DMS sources belonging to your customer account.</p> <p>For more information about AWS DMS events, see <a href="https://docs.aws.amazon.com/dms/latest/userguide/CHAP_Events.html">Working with Events and Notifications</a> in the <i>AWS Database Migration Service User Guide.</i> </p>
-    async fn create_event_subscription(
+    /// Presigns the request `create_event_subscription` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_event_subscription(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: CreateEventSubscriptionMessage,
-    ) -> Result<CreateEventSubscriptionResponse, RusotoError<CreateEventSubscriptionError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_event_subscription_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_event_subscription_request(
+        &self,
+        input: CreateEventSubscriptionMessage,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "dms", &self.region, "/");
+        request.set_operation("CreateEventSubscription");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonDMSv20160101.CreateEventSubscription");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `create_replication_instance`, also returning the metadata of the response.
+    pub async fn create_replication_instance_with_metadata(
+        &self,
+        input: CreateReplicationInstanceMessage,
+    ) -> Result<
+        (CreateReplicationInstanceResponse, ResponseMetadata),
+        RusotoError<CreateReplicationInstanceError>,
+    > {
+        let request = self.build_create_replication_instance_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<CreateEventSubscriptionResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<CreateReplicationInstanceResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(CreateEventSubscriptionError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(CreateReplicationInstanceError::from_response(response))
         }
     }
 
-    /// <p>Creates the replication instance using the specified parameters.</p> <p>AWS DMS requires that your account have certain roles with appropriate permissions before you can create a replication instance. For information on the required roles, see <a href="https://docs.aws.amazon.com/dms/latest/userguide/CHAP_Security.APIRole.html">Creating the IAM Roles to Use With the AWS CLI and AWS DMS API</a>. For information on the required permissions, see <a href="https://docs.aws.amazon.com/dms/latest/userguide/CHAP_Security.IAMPermissions.html">IAM Permissions Needed to Use AWS DMS</a>.</p>
-    async fn create_replication_instance(
+    /// Presigns the request `create_replication_instance` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_replication_instance(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: CreateReplicationInstanceMessage,
-    ) -> Result<CreateReplicationInstanceResponse, RusotoError<CreateReplicationInstanceError>>
-    {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_replication_instance_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_replication_instance_request(
+        &self,
+        input: CreateReplicationInstanceMessage,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "dms", &self.region, "/");
+        request.set_operation("CreateReplicationInstance");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -5518,29 +6104,58 @@ impl DatabaseMigrationService for DatabaseMigrationServiceClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
-        let mut response = self
-            .client
-            .sign_and_dispatch(request)
-            .await
+        request
+    }
+
+    /// Calls `create_replication_subnet_group`, also returning the metadata of the response.
+    pub async fn create_replication_subnet_group_with_metadata(
+        &self,
+        input: CreateReplicationSubnetGroupMessage,
+    ) -> Result<
+        (CreateReplicationSubnetGroupResponse, ResponseMetadata),
+        RusotoError<CreateReplicationSubnetGroupError>,
+    > {
+        let request = self.build_create_replication_subnet_group_request(input);
+
+        let mut response = self
+            .client
+            .sign_and_dispatch(request)
+            .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<CreateReplicationInstanceResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<CreateReplicationSubnetGroupResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(CreateReplicationInstanceError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(CreateReplicationSubnetGroupError::from_response(response))
         }
     }
 
-    /// <p>Creates a replication subnet group given a list of the subnet IDs in a VPC.</p>
-    async fn create_replication_subnet_group(
+    /// Presigns the request `create_replication_subnet_group` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_replication_subnet_group(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: CreateReplicationSubnetGroupMessage,
-    ) -> Result<CreateReplicationSubnetGroupResponse, RusotoError<CreateReplicationSubnetGroupError>>
-    {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_replication_subnet_group_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_replication_subnet_group_request(
+        &self,
+        input: CreateReplicationSubnetGroupMessage,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "dms", &self.region, "/");
+        request.set_operation("CreateReplicationSubnetGroup");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -5550,168 +6165,332 @@ impl DatabaseMigrationService for DatabaseMigrationServiceClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `create_replication_task`, also returning the metadata of the response.
+    pub async fn create_replication_task_with_metadata(
+        &self,
+        input: CreateReplicationTaskMessage,
+    ) -> Result<
+        (CreateReplicationTaskResponse, ResponseMetadata),
+        RusotoError<CreateReplicationTaskError>,
+    > {
+        let request = self.build_create_replication_task_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<CreateReplicationSubnetGroupResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<CreateReplicationTaskResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(CreateReplicationSubnetGroupError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(CreateReplicationTaskError::from_response(response))
         }
     }
 
-    /// <p>Creates a replication task using the specified parameters.</p>
-    async fn create_replication_task(
+    /// Presigns the request `create_replication_task` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_replication_task(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: CreateReplicationTaskMessage,
-    ) -> Result<CreateReplicationTaskResponse, RusotoError<CreateReplicationTaskError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_replication_task_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_replication_task_request(
+        &self,
+        input: CreateReplicationTaskMessage,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "dms", &self.region, "/");
+        request.set_operation("CreateReplicationTask");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonDMSv20160101.CreateReplicationTask");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `delete_certificate`, also returning the metadata of the response.
+    pub async fn delete_certificate_with_metadata(
+        &self,
+        input: DeleteCertificateMessage,
+    ) -> Result<(DeleteCertificateResponse, ResponseMetadata), RusotoError<DeleteCertificateError>>
+    {
+        let request = self.build_delete_certificate_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<CreateReplicationTaskResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DeleteCertificateResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(CreateReplicationTaskError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DeleteCertificateError::from_response(response))
         }
     }
 
-    /// <p>Deletes the specified certificate. </p>
-    async fn delete_certificate(
+    /// Presigns the request `delete_certificate` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_certificate(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteCertificateMessage,
-    ) -> Result<DeleteCertificateResponse, RusotoError<DeleteCertificateError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_certificate_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_certificate_request(&self, input: DeleteCertificateMessage) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "dms", &self.region, "/");
+        request.set_operation("DeleteCertificate");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonDMSv20160101.DeleteCertificate");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `delete_connection`, also returning the metadata of the response.
+    pub async fn delete_connection_with_metadata(
+        &self,
+        input: DeleteConnectionMessage,
+    ) -> Result<(DeleteConnectionResponse, ResponseMetadata), RusotoError<DeleteConnectionError>>
+    {
+        let request = self.build_delete_connection_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DeleteCertificateResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DeleteConnectionResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteCertificateError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DeleteConnectionError::from_response(response))
         }
     }
 
-    /// <p>Deletes the connection between a replication instance and an endpoint.</p>
-    async fn delete_connection(
+    /// Presigns the request `delete_connection` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_connection(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteConnectionMessage,
-    ) -> Result<DeleteConnectionResponse, RusotoError<DeleteConnectionError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_connection_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_connection_request(&self, input: DeleteConnectionMessage) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "dms", &self.region, "/");
+        request.set_operation("DeleteConnection");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonDMSv20160101.DeleteConnection");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `delete_endpoint`, also returning the metadata of the response.
+    pub async fn delete_endpoint_with_metadata(
+        &self,
+        input: DeleteEndpointMessage,
+    ) -> Result<(DeleteEndpointResponse, ResponseMetadata), RusotoError<DeleteEndpointError>> {
+        let request = self.build_delete_endpoint_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DeleteConnectionResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DeleteEndpointResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteConnectionError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DeleteEndpointError::from_response(response))
         }
     }
 
-    /// <p><p>Deletes the specified endpoint.</p> <note> <p>All tasks associated with the endpoint must be deleted before you can delete the endpoint.</p> </note> <p/></p>
-    async fn delete_endpoint(
+    /// Presigns the request `delete_endpoint` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_endpoint(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteEndpointMessage,
-    ) -> Result<DeleteEndpointResponse, RusotoError<DeleteEndpointError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_endpoint_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_endpoint_request(&self, input: DeleteEndpointMessage) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "dms", &self.region, "/");
+        request.set_operation("DeleteEndpoint");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonDMSv20160101.DeleteEndpoint");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `delete_event_subscription`, also returning the metadata of the response.
+    pub async fn delete_event_subscription_with_metadata(
+        &self,
+        input: DeleteEventSubscriptionMessage,
+    ) -> Result<
+        (DeleteEventSubscriptionResponse, ResponseMetadata),
+        RusotoError<DeleteEventSubscriptionError>,
+    > {
+        let request = self.build_delete_event_subscription_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<DeleteEndpointResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DeleteEventSubscriptionResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteEndpointError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DeleteEventSubscriptionError::from_response(response))
         }
     }
 
-    /// <p> Deletes an AWS DMS event subscription. </p>
-    async fn delete_event_subscription(
+    /// Presigns the request `delete_event_subscription` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_event_subscription(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteEventSubscriptionMessage,
-    ) -> Result<DeleteEventSubscriptionResponse, RusotoError<DeleteEventSubscriptionError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_event_subscription_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_event_subscription_request(
+        &self,
+        input: DeleteEventSubscriptionMessage,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "dms", &self.region, "/");
+        request.set_operation("DeleteEventSubscription");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonDMSv20160101.DeleteEventSubscription");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `delete_replication_instance`, also returning the metadata of the response.
+    pub async fn delete_replication_instance_with_metadata(
+        &self,
+        input: DeleteReplicationInstanceMessage,
+    ) -> Result<
+        (DeleteReplicationInstanceResponse, ResponseMetadata),
+        RusotoError<DeleteReplicationInstanceError>,
+    > {
+        let request = self.build_delete_replication_instance_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DeleteEventSubscriptionResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DeleteReplicationInstanceResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteEventSubscriptionError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DeleteReplicationInstanceError::from_response(response))
         }
     }
 
-    /// <p><p>Deletes the specified replication instance.</p> <note> <p>You must delete any migration tasks that are associated with the replication instance before you can delete it.</p> </note> <p/></p>
-    async fn delete_replication_instance(
+    /// Presigns the request `delete_replication_instance` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_replication_instance(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteReplicationInstanceMessage,
-    ) -> Result<DeleteReplicationInstanceResponse, RusotoError<DeleteReplicationInstanceError>>
-    {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_replication_instance_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_replication_instance_request(
+        &self,
+        input: DeleteReplicationInstanceMessage,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "dms", &self.region, "/");
+        request.set_operation("DeleteReplicationInstance");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -5721,29 +6500,58 @@ impl DatabaseMigrationService for DatabaseMigrationServiceClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `delete_replication_subnet_group`, also returning the metadata of the response.
+    pub async fn delete_replication_subnet_group_with_metadata(
+        &self,
+        input: DeleteReplicationSubnetGroupMessage,
+    ) -> Result<
+        (DeleteReplicationSubnetGroupResponse, ResponseMetadata),
+        RusotoError<DeleteReplicationSubnetGroupError>,
+    > {
+        let request = self.build_delete_replication_subnet_group_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DeleteReplicationInstanceResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DeleteReplicationSubnetGroupResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteReplicationInstanceError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DeleteReplicationSubnetGroupError::from_response(response))
         }
     }
 
-    /// <p>Deletes a subnet group.</p>
-    async fn delete_replication_subnet_group(
+    /// Presigns the request `delete_replication_subnet_group` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_replication_subnet_group(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteReplicationSubnetGroupMessage,
-    ) -> Result<DeleteReplicationSubnetGroupResponse, RusotoError<DeleteReplicationSubnetGroupError>>
-    {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_replication_subnet_group_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_replication_subnet_group_request(
+        &self,
+        input: DeleteReplicationSubnetGroupMessage,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "dms", &self.region, "/");
+        request.set_operation("DeleteReplicationSubnetGroup");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -5753,56 +6561,111 @@ impl DatabaseMigrationService for DatabaseMigrationServiceClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `delete_replication_task`, also returning the metadata of the response.
+    pub async fn delete_replication_task_with_metadata(
+        &self,
+        input: DeleteReplicationTaskMessage,
+    ) -> Result<
+        (DeleteReplicationTaskResponse, ResponseMetadata),
+        RusotoError<DeleteReplicationTaskError>,
+    > {
+        let request = self.build_delete_replication_task_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DeleteReplicationSubnetGroupResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DeleteReplicationTaskResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteReplicationSubnetGroupError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DeleteReplicationTaskError::from_response(response))
         }
     }
 
-    /// <p>Deletes the specified replication task.</p>
-    async fn delete_replication_task(
+    /// Presigns the request `delete_replication_task` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_replication_task(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteReplicationTaskMessage,
-    ) -> Result<DeleteReplicationTaskResponse, RusotoError<DeleteReplicationTaskError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_replication_task_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_replication_task_request(
+        &self,
+        input: DeleteReplicationTaskMessage,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "dms", &self.region, "/");
+        request.set_operation("DeleteReplicationTask");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonDMSv20160101.DeleteReplicationTask");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `describe_account_attributes`, also returning the metadata of the response.
+    pub async fn describe_account_attributes_with_metadata(
+        &self,
+    ) -> Result<
+        (DescribeAccountAttributesResponse, ResponseMetadata),
+        RusotoError<DescribeAccountAttributesError>,
+    > {
+        let request = self.build_describe_account_attributes_request();
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DeleteReplicationTaskResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DescribeAccountAttributesResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteReplicationTaskError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DescribeAccountAttributesError::from_response(response))
         }
     }
 
-    /// <p>Lists all of the AWS DMS attributes for a customer account. These attributes include AWS DMS quotas for the account and a unique account identifier in a particular DMS region. DMS quotas include a list of resource quotas supported by the account, such as the number of replication instances allowed. The description for each resource quota, includes the quota name, current usage toward that quota, and the quota's maximum value. DMS uses the unique account identifier to name each artifact used by DMS in the given region.</p> <p>This command does not take any parameters.</p>
-    async fn describe_account_attributes(
+    /// Presigns the request `describe_account_attributes` would send with `presigner` instead of sending it.
+    pub async fn presign_describe_account_attributes(
         &self,
-    ) -> Result<DescribeAccountAttributesResponse, RusotoError<DescribeAccountAttributesError>>
-    {
+        presigner: &rusoto_core::Presigner,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_account_attributes_request();
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_account_attributes_request(&self) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "dms", &self.region, "/");
+        request.set_operation("DescribeAccountAttributes");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -5811,169 +6674,343 @@ impl DatabaseMigrationService for DatabaseMigrationServiceClient {
         );
         request.set_payload(Some(bytes::Bytes::from_static(b"{}")));
 
+        request
+    }
+
+    /// Calls `describe_certificates`, also returning the metadata of the response.
+    pub async fn describe_certificates_with_metadata(
+        &self,
+        input: DescribeCertificatesMessage,
+    ) -> Result<
+        (DescribeCertificatesResponse, ResponseMetadata),
+        RusotoError<DescribeCertificatesError>,
+    > {
+        let request = self.build_describe_certificates_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DescribeAccountAttributesResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DescribeCertificatesResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeAccountAttributesError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DescribeCertificatesError::from_response(response))
         }
     }
 
-    /// <p>Provides a description of the certificate.</p>
-    async fn describe_certificates(
+    /// Presigns the request `describe_certificates` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_certificates(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeCertificatesMessage,
-    ) -> Result<DescribeCertificatesResponse, RusotoError<DescribeCertificatesError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_certificates_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_certificates_request(
+        &self,
+        input: DescribeCertificatesMessage,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "dms", &self.region, "/");
+        request.set_operation("DescribeCertificates");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonDMSv20160101.DescribeCertificates");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `describe_connections`, also returning the metadata of the response.
+    pub async fn describe_connections_with_metadata(
+        &self,
+        input: DescribeConnectionsMessage,
+    ) -> Result<
+        (DescribeConnectionsResponse, ResponseMetadata),
+        RusotoError<DescribeConnectionsError>,
+    > {
+        let request = self.build_describe_connections_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DescribeCertificatesResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DescribeConnectionsResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeCertificatesError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DescribeConnectionsError::from_response(response))
         }
     }
 
-    /// <p>Describes the status of the connections that have been made between the replication instance and an endpoint. Connections are created when you test an endpoint.</p>
-    async fn describe_connections(
+    /// Presigns the request `describe_connections` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_connections(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeConnectionsMessage,
-    ) -> Result<DescribeConnectionsResponse, RusotoError<DescribeConnectionsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_connections_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_connections_request(
+        &self,
+        input: DescribeConnectionsMessage,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "dms", &self.region, "/");
+        request.set_operation("DescribeConnections");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonDMSv20160101.DescribeConnections");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `describe_endpoint_types`, also returning the metadata of the response.
+    pub async fn describe_endpoint_types_with_metadata(
+        &self,
+        input: DescribeEndpointTypesMessage,
+    ) -> Result<
+        (DescribeEndpointTypesResponse, ResponseMetadata),
+        RusotoError<DescribeEndpointTypesError>,
+    > {
+        let request = self.build_describe_endpoint_types_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DescribeConnectionsResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DescribeEndpointTypesResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeConnectionsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DescribeEndpointTypesError::from_response(response))
         }
     }
 
-    /// <p>Returns information about the type of endpoints available.</p>
-    async fn describe_endpoint_types(
+    /// Presigns the request `describe_endpoint_types` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_endpoint_types(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeEndpointTypesMessage,
-    ) -> Result<DescribeEndpointTypesResponse, RusotoError<DescribeEndpointTypesError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_endpoint_types_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_endpoint_types_request(
+        &self,
+        input: DescribeEndpointTypesMessage,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "dms", &self.region, "/");
+        request.set_operation("DescribeEndpointTypes");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonDMSv20160101.DescribeEndpointTypes");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `describe_endpoints`, also returning the metadata of the response.
+    pub async fn describe_endpoints_with_metadata(
+        &self,
+        input: DescribeEndpointsMessage,
+    ) -> Result<(DescribeEndpointsResponse, ResponseMetadata), RusotoError<DescribeEndpointsError>>
+    {
+        let request = self.build_describe_endpoints_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DescribeEndpointTypesResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DescribeEndpointsResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeEndpointTypesError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DescribeEndpointsError::from_response(response))
         }
     }
 
-    /// <p>Returns information about the endpoints for your account in the current region.</p>
-    async fn describe_endpoints(
+    /// Presigns the request `describe_endpoints` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_endpoints(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeEndpointsMessage,
-    ) -> Result<DescribeEndpointsResponse, RusotoError<DescribeEndpointsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_endpoints_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_endpoints_request(&self, input: DescribeEndpointsMessage) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "dms", &self.region, "/");
+        request.set_operation("DescribeEndpoints");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonDMSv20160101.DescribeEndpoints");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `describe_event_categories`, also returning the metadata of the response.
+    pub async fn describe_event_categories_with_metadata(
+        &self,
+        input: DescribeEventCategoriesMessage,
+    ) -> Result<
+        (DescribeEventCategoriesResponse, ResponseMetadata),
+        RusotoError<DescribeEventCategoriesError>,
+    > {
+        let request = self.build_describe_event_categories_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DescribeEndpointsResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DescribeEventCategoriesResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeEndpointsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DescribeEventCategoriesError::from_response(response))
         }
     }
 
-    /// <p>Lists categories for all event source types, or, if specified, for a specified source type. You can see a list of the event categories and source types in <a href="https://docs.aws.amazon.com/dms/latest/userguide/CHAP_Events.html">Working with Events and Notifications</a> in the <i>AWS Database Migration Service User Guide.</i> </p>
-    async fn describe_event_categories(
+    /// Presigns the request `describe_event_categories` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_event_categories(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeEventCategoriesMessage,
-    ) -> Result<DescribeEventCategoriesResponse, RusotoError<DescribeEventCategoriesError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_event_categories_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_event_categories_request(
+        &self,
+        input: DescribeEventCategoriesMessage,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "dms", &self.region, "/");
+        request.set_operation("DescribeEventCategories");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonDMSv20160101.DescribeEventCategories");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `describe_event_subscriptions`, also returning the metadata of the response.
+    pub async fn describe_event_subscriptions_with_metadata(
+        &self,
+        input: DescribeEventSubscriptionsMessage,
+    ) -> Result<
+        (DescribeEventSubscriptionsResponse, ResponseMetadata),
+        RusotoError<DescribeEventSubscriptionsError>,
+    > {
+        let request = self.build_describe_event_subscriptions_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DescribeEventCategoriesResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DescribeEventSubscriptionsResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeEventCategoriesError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DescribeEventSubscriptionsError::from_response(response))
         }
     }
 
-    /// <p>Lists all the event subscriptions for a customer account. The description of a subscription includes <code>SubscriptionName</code>, <code>SNSTopicARN</code>, <code>CustomerID</code>, <code>SourceType</code>, <code>SourceID</code>, <code>CreationTime</code>, and <code>Status</code>. </p> <p>If you specify <code>SubscriptionName</code>, this action lists the description for that subscription.</p>
-    async fn describe_event_subscriptions(
+    /// Presigns the request `describe_event_subscriptions` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_event_subscriptions(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeEventSubscriptionsMessage,
-    ) -> Result<DescribeEventSubscriptionsResponse, RusotoError<DescribeEventSubscriptionsError>>
-    {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_event_subscriptions_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_event_subscriptions_request(
+        &self,
+        input: DescribeEventSubscriptionsMessage,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "dms", &self.region, "/");
+        request.set_operation("DescribeEventSubscriptions");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -5983,58 +7020,115 @@ impl DatabaseMigrationService for DatabaseMigrationServiceClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `describe_events`, also returning the metadata of the response.
+    pub async fn describe_events_with_metadata(
+        &self,
+        input: DescribeEventsMessage,
+    ) -> Result<(DescribeEventsResponse, ResponseMetadata), RusotoError<DescribeEventsError>> {
+        let request = self.build_describe_events_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DescribeEventSubscriptionsResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DescribeEventsResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeEventSubscriptionsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DescribeEventsError::from_response(response))
         }
     }
 
-    /// <p> Lists events for a given source identifier and source type. You can also specify a start and end time. For more information on AWS DMS events, see <a href="https://docs.aws.amazon.com/dms/latest/userguide/CHAP_Events.html">Working with Events and Notifications</a> in the <i>AWS Database Migration User Guide.</i> </p>
-    async fn describe_events(
+    /// Presigns the request `describe_events` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_events(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeEventsMessage,
-    ) -> Result<DescribeEventsResponse, RusotoError<DescribeEventsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_events_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_events_request(&self, input: DescribeEventsMessage) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "dms", &self.region, "/");
+        request.set_operation("DescribeEvents");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonDMSv20160101.DescribeEvents");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `describe_orderable_replication_instances`, also returning the metadata of the response.
+    pub async fn describe_orderable_replication_instances_with_metadata(
+        &self,
+        input: DescribeOrderableReplicationInstancesMessage,
+    ) -> Result<
+        (
+            DescribeOrderableReplicationInstancesResponse,
+            ResponseMetadata,
+        ),
+        RusotoError<DescribeOrderableReplicationInstancesError>,
+    > {
+        let request = self.build_describe_orderable_replication_instances_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<DescribeEventsResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DescribeOrderableReplicationInstancesResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeEventsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DescribeOrderableReplicationInstancesError::from_response(
+                response,
+            ))
         }
     }
 
-    /// <p>Returns information about the replication instance types that can be created in the specified region.</p>
-    async fn describe_orderable_replication_instances(
+    /// Presigns the request `describe_orderable_replication_instances` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_orderable_replication_instances(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeOrderableReplicationInstancesMessage,
-    ) -> Result<
-        DescribeOrderableReplicationInstancesResponse,
-        RusotoError<DescribeOrderableReplicationInstancesError>,
-    > {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_orderable_replication_instances_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_orderable_replication_instances_request(
+        &self,
+        input: DescribeOrderableReplicationInstancesMessage,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "dms", &self.region, "/");
+        request.set_operation("DescribeOrderableReplicationInstances");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -6044,33 +7138,60 @@ impl DatabaseMigrationService for DatabaseMigrationServiceClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `describe_pending_maintenance_actions`, also returning the metadata of the response.
+    pub async fn describe_pending_maintenance_actions_with_metadata(
+        &self,
+        input: DescribePendingMaintenanceActionsMessage,
+    ) -> Result<
+        (DescribePendingMaintenanceActionsResponse, ResponseMetadata),
+        RusotoError<DescribePendingMaintenanceActionsError>,
+    > {
+        let request = self.build_describe_pending_maintenance_actions_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DescribeOrderableReplicationInstancesResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DescribePendingMaintenanceActionsResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeOrderableReplicationInstancesError::from_response(
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DescribePendingMaintenanceActionsError::from_response(
                 response,
             ))
         }
     }
 
-    /// <p>For internal use only</p>
-    async fn describe_pending_maintenance_actions(
+    /// Presigns the request `describe_pending_maintenance_actions` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_pending_maintenance_actions(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribePendingMaintenanceActionsMessage,
-    ) -> Result<
-        DescribePendingMaintenanceActionsResponse,
-        RusotoError<DescribePendingMaintenanceActionsError>,
-    > {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_pending_maintenance_actions_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_pending_maintenance_actions_request(
+        &self,
+        input: DescribePendingMaintenanceActionsMessage,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "dms", &self.region, "/");
+        request.set_operation("DescribePendingMaintenanceActions");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -6080,31 +7201,58 @@ impl DatabaseMigrationService for DatabaseMigrationServiceClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `describe_refresh_schemas_status`, also returning the metadata of the response.
+    pub async fn describe_refresh_schemas_status_with_metadata(
+        &self,
+        input: DescribeRefreshSchemasStatusMessage,
+    ) -> Result<
+        (DescribeRefreshSchemasStatusResponse, ResponseMetadata),
+        RusotoError<DescribeRefreshSchemasStatusError>,
+    > {
+        let request = self.build_describe_refresh_schemas_status_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DescribePendingMaintenanceActionsResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DescribeRefreshSchemasStatusResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribePendingMaintenanceActionsError::from_response(
-                response,
-            ))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DescribeRefreshSchemasStatusError::from_response(response))
         }
     }
 
-    /// <p>Returns the status of the RefreshSchemas operation.</p>
-    async fn describe_refresh_schemas_status(
+    /// Presigns the request `describe_refresh_schemas_status` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_refresh_schemas_status(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeRefreshSchemasStatusMessage,
-    ) -> Result<DescribeRefreshSchemasStatusResponse, RusotoError<DescribeRefreshSchemasStatusError>>
-    {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_refresh_schemas_status_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_refresh_schemas_status_request(
+        &self,
+        input: DescribeRefreshSchemasStatusMessage,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "dms", &self.region, "/");
+        request.set_operation("DescribeRefreshSchemasStatus");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -6114,31 +7262,63 @@ impl DatabaseMigrationService for DatabaseMigrationServiceClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `describe_replication_instance_task_logs`, also returning the metadata of the response.
+    pub async fn describe_replication_instance_task_logs_with_metadata(
+        &self,
+        input: DescribeReplicationInstanceTaskLogsMessage,
+    ) -> Result<
+        (
+            DescribeReplicationInstanceTaskLogsResponse,
+            ResponseMetadata,
+        ),
+        RusotoError<DescribeReplicationInstanceTaskLogsError>,
+    > {
+        let request = self.build_describe_replication_instance_task_logs_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DescribeRefreshSchemasStatusResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DescribeReplicationInstanceTaskLogsResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeRefreshSchemasStatusError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DescribeReplicationInstanceTaskLogsError::from_response(
+                response,
+            ))
         }
     }
 
-    /// <p>Returns information about the task logs for the specified task.</p>
-    async fn describe_replication_instance_task_logs(
+    /// Presigns the request `describe_replication_instance_task_logs` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_replication_instance_task_logs(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeReplicationInstanceTaskLogsMessage,
-    ) -> Result<
-        DescribeReplicationInstanceTaskLogsResponse,
-        RusotoError<DescribeReplicationInstanceTaskLogsError>,
-    > {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_replication_instance_task_logs_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_replication_instance_task_logs_request(
+        &self,
+        input: DescribeReplicationInstanceTaskLogsMessage,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "dms", &self.region, "/");
+        request.set_operation("DescribeReplicationInstanceTaskLogs");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -6148,31 +7328,58 @@ impl DatabaseMigrationService for DatabaseMigrationServiceClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `describe_replication_instances`, also returning the metadata of the response.
+    pub async fn describe_replication_instances_with_metadata(
+        &self,
+        input: DescribeReplicationInstancesMessage,
+    ) -> Result<
+        (DescribeReplicationInstancesResponse, ResponseMetadata),
+        RusotoError<DescribeReplicationInstancesError>,
+    > {
+        let request = self.build_describe_replication_instances_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DescribeReplicationInstanceTaskLogsResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DescribeReplicationInstancesResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeReplicationInstanceTaskLogsError::from_response(
-                response,
-            ))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DescribeReplicationInstancesError::from_response(response))
         }
     }
 
-    /// <p>Returns information about replication instances for your account in the current region.</p>
-    async fn describe_replication_instances(
+    /// Presigns the request `describe_replication_instances` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_replication_instances(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeReplicationInstancesMessage,
-    ) -> Result<DescribeReplicationInstancesResponse, RusotoError<DescribeReplicationInstancesError>>
-    {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_replication_instances_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_replication_instances_request(
+        &self,
+        input: DescribeReplicationInstancesMessage,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "dms", &self.region, "/");
+        request.set_operation("DescribeReplicationInstances");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -6182,31 +7389,60 @@ impl DatabaseMigrationService for DatabaseMigrationServiceClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `describe_replication_subnet_groups`, also returning the metadata of the response.
+    pub async fn describe_replication_subnet_groups_with_metadata(
+        &self,
+        input: DescribeReplicationSubnetGroupsMessage,
+    ) -> Result<
+        (DescribeReplicationSubnetGroupsResponse, ResponseMetadata),
+        RusotoError<DescribeReplicationSubnetGroupsError>,
+    > {
+        let request = self.build_describe_replication_subnet_groups_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DescribeReplicationInstancesResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DescribeReplicationSubnetGroupsResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeReplicationInstancesError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DescribeReplicationSubnetGroupsError::from_response(
+                response,
+            ))
         }
     }
 
-    /// <p>Returns information about the replication subnet groups.</p>
-    async fn describe_replication_subnet_groups(
+    /// Presigns the request `describe_replication_subnet_groups` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_replication_subnet_groups(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeReplicationSubnetGroupsMessage,
-    ) -> Result<
-        DescribeReplicationSubnetGroupsResponse,
-        RusotoError<DescribeReplicationSubnetGroupsError>,
-    > {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_replication_subnet_groups_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_replication_subnet_groups_request(
+        &self,
+        input: DescribeReplicationSubnetGroupsMessage,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "dms", &self.region, "/");
+        request.set_operation("DescribeReplicationSubnetGroups");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -6216,33 +7452,61 @@ impl DatabaseMigrationService for DatabaseMigrationServiceClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `describe_replication_task_assessment_results`, also returning the metadata of the response.
+    pub async fn describe_replication_task_assessment_results_with_metadata(
+        &self,
+        input: DescribeReplicationTaskAssessmentResultsMessage,
+    ) -> Result<
+        (
+            DescribeReplicationTaskAssessmentResultsResponse,
+            ResponseMetadata,
+        ),
+        RusotoError<DescribeReplicationTaskAssessmentResultsError>,
+    > {
+        let request = self.build_describe_replication_task_assessment_results_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DescribeReplicationSubnetGroupsResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DescribeReplicationTaskAssessmentResultsResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeReplicationSubnetGroupsError::from_response(
-                response,
-            ))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DescribeReplicationTaskAssessmentResultsError::from_response(response))
         }
     }
 
-    /// <p>Returns the task assessment results from Amazon S3. This action always returns the latest results.</p>
-    async fn describe_replication_task_assessment_results(
+    /// Presigns the request `describe_replication_task_assessment_results` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_replication_task_assessment_results(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeReplicationTaskAssessmentResultsMessage,
-    ) -> Result<
-        DescribeReplicationTaskAssessmentResultsResponse,
-        RusotoError<DescribeReplicationTaskAssessmentResultsError>,
-    > {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_replication_task_assessment_results_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_replication_task_assessment_results_request(
+        &self,
+        input: DescribeReplicationTaskAssessmentResultsMessage,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "dms", &self.region, "/");
+        request.set_operation("DescribeReplicationTaskAssessmentResults");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -6252,28 +7516,58 @@ impl DatabaseMigrationService for DatabaseMigrationServiceClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `describe_replication_tasks`, also returning the metadata of the response.
+    pub async fn describe_replication_tasks_with_metadata(
+        &self,
+        input: DescribeReplicationTasksMessage,
+    ) -> Result<
+        (DescribeReplicationTasksResponse, ResponseMetadata),
+        RusotoError<DescribeReplicationTasksError>,
+    > {
+        let request = self.build_describe_replication_tasks_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DescribeReplicationTaskAssessmentResultsResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DescribeReplicationTasksResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeReplicationTaskAssessmentResultsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DescribeReplicationTasksError::from_response(response))
         }
     }
 
-    /// <p>Returns information about replication tasks for your account in the current region.</p>
-    async fn describe_replication_tasks(
+    /// Presigns the request `describe_replication_tasks` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_replication_tasks(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeReplicationTasksMessage,
-    ) -> Result<DescribeReplicationTasksResponse, RusotoError<DescribeReplicationTasksError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_replication_tasks_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_replication_tasks_request(
+        &self,
+        input: DescribeReplicationTasksMessage,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "dms", &self.region, "/");
+        request.set_operation("DescribeReplicationTasks");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -6283,195 +7577,390 @@ impl DatabaseMigrationService for DatabaseMigrationServiceClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `describe_schemas`, also returning the metadata of the response.
+    pub async fn describe_schemas_with_metadata(
+        &self,
+        input: DescribeSchemasMessage,
+    ) -> Result<(DescribeSchemasResponse, ResponseMetadata), RusotoError<DescribeSchemasError>>
+    {
+        let request = self.build_describe_schemas_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DescribeReplicationTasksResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DescribeSchemasResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeReplicationTasksError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DescribeSchemasError::from_response(response))
         }
     }
 
-    /// <p><p>Returns information about the schema for the specified endpoint.</p> <p/></p>
-    async fn describe_schemas(
+    /// Presigns the request `describe_schemas` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_schemas(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeSchemasMessage,
-    ) -> Result<DescribeSchemasResponse, RusotoError<DescribeSchemasError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_schemas_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_schemas_request(&self, input: DescribeSchemasMessage) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "dms", &self.region, "/");
+        request.set_operation("DescribeSchemas");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonDMSv20160101.DescribeSchemas");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `describe_table_statistics`, also returning the metadata of the response.
+    pub async fn describe_table_statistics_with_metadata(
+        &self,
+        input: DescribeTableStatisticsMessage,
+    ) -> Result<
+        (DescribeTableStatisticsResponse, ResponseMetadata),
+        RusotoError<DescribeTableStatisticsError>,
+    > {
+        let request = self.build_describe_table_statistics_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<DescribeSchemasResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DescribeTableStatisticsResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeSchemasError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DescribeTableStatisticsError::from_response(response))
         }
     }
 
-    /// <p>Returns table statistics on the database migration task, including table name, rows inserted, rows updated, and rows deleted.</p> <p>Note that the "last updated" column the DMS console only indicates the time that AWS DMS last updated the table statistics record for a table. It does not indicate the time of the last update to the table.</p>
-    async fn describe_table_statistics(
+    /// Presigns the request `describe_table_statistics` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_table_statistics(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeTableStatisticsMessage,
-    ) -> Result<DescribeTableStatisticsResponse, RusotoError<DescribeTableStatisticsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_table_statistics_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_table_statistics_request(
+        &self,
+        input: DescribeTableStatisticsMessage,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "dms", &self.region, "/");
+        request.set_operation("DescribeTableStatistics");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonDMSv20160101.DescribeTableStatistics");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `import_certificate`, also returning the metadata of the response.
+    pub async fn import_certificate_with_metadata(
+        &self,
+        input: ImportCertificateMessage,
+    ) -> Result<(ImportCertificateResponse, ResponseMetadata), RusotoError<ImportCertificateError>>
+    {
+        let request = self.build_import_certificate_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DescribeTableStatisticsResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ImportCertificateResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeTableStatisticsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ImportCertificateError::from_response(response))
         }
     }
 
-    /// <p>Uploads the specified certificate.</p>
-    async fn import_certificate(
+    /// Presigns the request `import_certificate` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_import_certificate(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ImportCertificateMessage,
-    ) -> Result<ImportCertificateResponse, RusotoError<ImportCertificateError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_import_certificate_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_import_certificate_request(&self, input: ImportCertificateMessage) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "dms", &self.region, "/");
+        request.set_operation("ImportCertificate");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonDMSv20160101.ImportCertificate");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `list_tags_for_resource`, also returning the metadata of the response.
+    pub async fn list_tags_for_resource_with_metadata(
+        &self,
+        input: ListTagsForResourceMessage,
+    ) -> Result<
+        (ListTagsForResourceResponse, ResponseMetadata),
+        RusotoError<ListTagsForResourceError>,
+    > {
+        let request = self.build_list_tags_for_resource_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<ImportCertificateResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ListTagsForResourceResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ImportCertificateError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ListTagsForResourceError::from_response(response))
         }
     }
 
-    /// <p>Lists all tags for an AWS DMS resource.</p>
-    async fn list_tags_for_resource(
+    /// Presigns the request `list_tags_for_resource` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_tags_for_resource(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListTagsForResourceMessage,
-    ) -> Result<ListTagsForResourceResponse, RusotoError<ListTagsForResourceError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_tags_for_resource_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_tags_for_resource_request(
+        &self,
+        input: ListTagsForResourceMessage,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "dms", &self.region, "/");
+        request.set_operation("ListTagsForResource");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonDMSv20160101.ListTagsForResource");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `modify_endpoint`, also returning the metadata of the response.
+    pub async fn modify_endpoint_with_metadata(
+        &self,
+        input: ModifyEndpointMessage,
+    ) -> Result<(ModifyEndpointResponse, ResponseMetadata), RusotoError<ModifyEndpointError>> {
+        let request = self.build_modify_endpoint_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListTagsForResourceResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ModifyEndpointResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ListTagsForResourceError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ModifyEndpointError::from_response(response))
         }
     }
 
-    /// <p>Modifies the specified endpoint.</p>
-    async fn modify_endpoint(
+    /// Presigns the request `modify_endpoint` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_modify_endpoint(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ModifyEndpointMessage,
-    ) -> Result<ModifyEndpointResponse, RusotoError<ModifyEndpointError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_modify_endpoint_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_modify_endpoint_request(&self, input: ModifyEndpointMessage) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "dms", &self.region, "/");
+        request.set_operation("ModifyEndpoint");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonDMSv20160101.ModifyEndpoint");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `modify_event_subscription`, also returning the metadata of the response.
+    pub async fn modify_event_subscription_with_metadata(
+        &self,
+        input: ModifyEventSubscriptionMessage,
+    ) -> Result<
+        (ModifyEventSubscriptionResponse, ResponseMetadata),
+        RusotoError<ModifyEventSubscriptionError>,
+    > {
+        let request = self.build_modify_event_subscription_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<ModifyEndpointResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ModifyEventSubscriptionResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ModifyEndpointError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ModifyEventSubscriptionError::from_response(response))
         }
     }
 
-    /// <p>Modifies an existing AWS DMS event notification subscription. </p>
-    async fn modify_event_subscription(
+    /// Presigns the request `modify_event_subscription` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_modify_event_subscription(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ModifyEventSubscriptionMessage,
-    ) -> Result<ModifyEventSubscriptionResponse, RusotoError<ModifyEventSubscriptionError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_modify_event_subscription_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_modify_event_subscription_request(
+        &self,
+        input: ModifyEventSubscriptionMessage,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "dms", &self.region, "/");
+        request.set_operation("ModifyEventSubscription");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonDMSv20160101.ModifyEventSubscription");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
-        let mut response = self
-            .client
-            .sign_and_dispatch(request)
+        request
+    }
+
+    /// Calls `modify_replication_instance`, also returning the metadata of the response.
+    pub async fn modify_replication_instance_with_metadata(
+        &self,
+        input: ModifyReplicationInstanceMessage,
+    ) -> Result<
+        (ModifyReplicationInstanceResponse, ResponseMetadata),
+        RusotoError<ModifyReplicationInstanceError>,
+    > {
+        let request = self.build_modify_replication_instance_request(input);
+
+        let mut response = self
+            .client
+            .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<ModifyEventSubscriptionResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ModifyReplicationInstanceResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ModifyEventSubscriptionError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ModifyReplicationInstanceError::from_response(response))
         }
     }
 
-    /// <p><p>Modifies the replication instance to apply new settings. You can change one or more parameters by specifying these parameters and the new values in the request.</p> <p>Some settings are applied during the maintenance window.</p> <p/></p>
-    async fn modify_replication_instance(
+    /// Presigns the request `modify_replication_instance` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_modify_replication_instance(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ModifyReplicationInstanceMessage,
-    ) -> Result<ModifyReplicationInstanceResponse, RusotoError<ModifyReplicationInstanceError>>
-    {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_modify_replication_instance_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_modify_replication_instance_request(
+        &self,
+        input: ModifyReplicationInstanceMessage,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "dms", &self.region, "/");
+        request.set_operation("ModifyReplicationInstance");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -6481,29 +7970,58 @@ impl DatabaseMigrationService for DatabaseMigrationServiceClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `modify_replication_subnet_group`, also returning the metadata of the response.
+    pub async fn modify_replication_subnet_group_with_metadata(
+        &self,
+        input: ModifyReplicationSubnetGroupMessage,
+    ) -> Result<
+        (ModifyReplicationSubnetGroupResponse, ResponseMetadata),
+        RusotoError<ModifyReplicationSubnetGroupError>,
+    > {
+        let request = self.build_modify_replication_subnet_group_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<ModifyReplicationInstanceResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ModifyReplicationSubnetGroupResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ModifyReplicationInstanceError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ModifyReplicationSubnetGroupError::from_response(response))
         }
     }
 
-    /// <p>Modifies the settings for the specified replication subnet group.</p>
-    async fn modify_replication_subnet_group(
+    /// Presigns the request `modify_replication_subnet_group` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_modify_replication_subnet_group(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ModifyReplicationSubnetGroupMessage,
-    ) -> Result<ModifyReplicationSubnetGroupResponse, RusotoError<ModifyReplicationSubnetGroupError>>
-    {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_modify_replication_subnet_group_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_modify_replication_subnet_group_request(
+        &self,
+        input: ModifyReplicationSubnetGroupMessage,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "dms", &self.region, "/");
+        request.set_operation("ModifyReplicationSubnetGroup");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -6513,57 +8031,116 @@ impl DatabaseMigrationService for DatabaseMigrationServiceClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `modify_replication_task`, also returning the metadata of the response.
+    pub async fn modify_replication_task_with_metadata(
+        &self,
+        input: ModifyReplicationTaskMessage,
+    ) -> Result<
+        (ModifyReplicationTaskResponse, ResponseMetadata),
+        RusotoError<ModifyReplicationTaskError>,
+    > {
+        let request = self.build_modify_replication_task_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<ModifyReplicationSubnetGroupResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ModifyReplicationTaskResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ModifyReplicationSubnetGroupError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ModifyReplicationTaskError::from_response(response))
         }
     }
 
-    /// <p>Modifies the specified replication task.</p> <p>You can't modify the task endpoints. The task must be stopped before you can modify it. </p> <p>For more information about AWS DMS tasks, see <a href="https://docs.aws.amazon.com/dms/latest/userguide/CHAP_Tasks.html">Working with Migration Tasks</a> in the <i>AWS Database Migration Service User Guide</i>.</p>
-    async fn modify_replication_task(
+    /// Presigns the request `modify_replication_task` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_modify_replication_task(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ModifyReplicationTaskMessage,
-    ) -> Result<ModifyReplicationTaskResponse, RusotoError<ModifyReplicationTaskError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_modify_replication_task_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_modify_replication_task_request(
+        &self,
+        input: ModifyReplicationTaskMessage,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "dms", &self.region, "/");
+        request.set_operation("ModifyReplicationTask");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonDMSv20160101.ModifyReplicationTask");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `reboot_replication_instance`, also returning the metadata of the response.
+    pub async fn reboot_replication_instance_with_metadata(
+        &self,
+        input: RebootReplicationInstanceMessage,
+    ) -> Result<
+        (RebootReplicationInstanceResponse, ResponseMetadata),
+        RusotoError<RebootReplicationInstanceError>,
+    > {
+        let request = self.build_reboot_replication_instance_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<ModifyReplicationTaskResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<RebootReplicationInstanceResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ModifyReplicationTaskError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(RebootReplicationInstanceError::from_response(response))
         }
     }
 
-    /// <p>Reboots a replication instance. Rebooting results in a momentary outage, until the replication instance becomes available again.</p>
-    async fn reboot_replication_instance(
+    /// Presigns the request `reboot_replication_instance` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_reboot_replication_instance(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: RebootReplicationInstanceMessage,
-    ) -> Result<RebootReplicationInstanceResponse, RusotoError<RebootReplicationInstanceError>>
-    {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_reboot_replication_instance_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_reboot_replication_instance_request(
+        &self,
+        input: RebootReplicationInstanceMessage,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "dms", &self.region, "/");
+        request.set_operation("RebootReplicationInstance");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -6573,141 +8150,278 @@ impl DatabaseMigrationService for DatabaseMigrationServiceClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `refresh_schemas`, also returning the metadata of the response.
+    pub async fn refresh_schemas_with_metadata(
+        &self,
+        input: RefreshSchemasMessage,
+    ) -> Result<(RefreshSchemasResponse, ResponseMetadata), RusotoError<RefreshSchemasError>> {
+        let request = self.build_refresh_schemas_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<RebootReplicationInstanceResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<RefreshSchemasResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(RebootReplicationInstanceError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(RefreshSchemasError::from_response(response))
         }
     }
 
-    /// <p>Populates the schema for the specified endpoint. This is an asynchronous operation and can take several minutes. You can check the status of this operation by calling the DescribeRefreshSchemasStatus operation.</p>
-    async fn refresh_schemas(
+    /// Presigns the request `refresh_schemas` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_refresh_schemas(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: RefreshSchemasMessage,
-    ) -> Result<RefreshSchemasResponse, RusotoError<RefreshSchemasError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_refresh_schemas_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_refresh_schemas_request(&self, input: RefreshSchemasMessage) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "dms", &self.region, "/");
+        request.set_operation("RefreshSchemas");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonDMSv20160101.RefreshSchemas");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `reload_tables`, also returning the metadata of the response.
+    pub async fn reload_tables_with_metadata(
+        &self,
+        input: ReloadTablesMessage,
+    ) -> Result<(ReloadTablesResponse, ResponseMetadata), RusotoError<ReloadTablesError>> {
+        let request = self.build_reload_tables_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<RefreshSchemasResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ReloadTablesResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(RefreshSchemasError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ReloadTablesError::from_response(response))
         }
     }
 
-    /// <p>Reloads the target database table with the source data. </p>
-    async fn reload_tables(
+    /// Presigns the request `reload_tables` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_reload_tables(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ReloadTablesMessage,
-    ) -> Result<ReloadTablesResponse, RusotoError<ReloadTablesError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_reload_tables_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_reload_tables_request(&self, input: ReloadTablesMessage) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "dms", &self.region, "/");
+        request.set_operation("ReloadTables");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonDMSv20160101.ReloadTables");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `remove_tags_from_resource`, also returning the metadata of the response.
+    pub async fn remove_tags_from_resource_with_metadata(
+        &self,
+        input: RemoveTagsFromResourceMessage,
+    ) -> Result<
+        (RemoveTagsFromResourceResponse, ResponseMetadata),
+        RusotoError<RemoveTagsFromResourceError>,
+    > {
+        let request = self.build_remove_tags_from_resource_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<ReloadTablesResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<RemoveTagsFromResourceResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ReloadTablesError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(RemoveTagsFromResourceError::from_response(response))
         }
     }
 
-    /// <p>Removes metadata tags from a DMS resource.</p>
-    async fn remove_tags_from_resource(
+    /// Presigns the request `remove_tags_from_resource` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_remove_tags_from_resource(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: RemoveTagsFromResourceMessage,
-    ) -> Result<RemoveTagsFromResourceResponse, RusotoError<RemoveTagsFromResourceError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_remove_tags_from_resource_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_remove_tags_from_resource_request(
+        &self,
+        input: RemoveTagsFromResourceMessage,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "dms", &self.region, "/");
+        request.set_operation("RemoveTagsFromResource");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonDMSv20160101.RemoveTagsFromResource");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `start_replication_task`, also returning the metadata of the response.
+    pub async fn start_replication_task_with_metadata(
+        &self,
+        input: StartReplicationTaskMessage,
+    ) -> Result<
+        (StartReplicationTaskResponse, ResponseMetadata),
+        RusotoError<StartReplicationTaskError>,
+    > {
+        let request = self.build_start_replication_task_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<RemoveTagsFromResourceResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<StartReplicationTaskResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(RemoveTagsFromResourceError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(StartReplicationTaskError::from_response(response))
         }
     }
 
-    /// <p>Starts the replication task.</p> <p>For more information about AWS DMS tasks, see <a href="https://docs.aws.amazon.com/dms/latest/userguide/CHAP_Tasks.html">Working with Migration Tasks </a> in the <i>AWS Database Migration Service User Guide.</i> </p>
-    async fn start_replication_task(
+    /// Presigns the request `start_replication_task` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_start_replication_task(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: StartReplicationTaskMessage,
-    ) -> Result<StartReplicationTaskResponse, RusotoError<StartReplicationTaskError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_start_replication_task_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_start_replication_task_request(
+        &self,
+        input: StartReplicationTaskMessage,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "dms", &self.region, "/");
+        request.set_operation("StartReplicationTask");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonDMSv20160101.StartReplicationTask");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `start_replication_task_assessment`, also returning the metadata of the response.
+    pub async fn start_replication_task_assessment_with_metadata(
+        &self,
+        input: StartReplicationTaskAssessmentMessage,
+    ) -> Result<
+        (StartReplicationTaskAssessmentResponse, ResponseMetadata),
+        RusotoError<StartReplicationTaskAssessmentError>,
+    > {
+        let request = self.build_start_replication_task_assessment_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<StartReplicationTaskResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<StartReplicationTaskAssessmentResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(StartReplicationTaskError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(StartReplicationTaskAssessmentError::from_response(response))
         }
     }
 
-    /// <p> Starts the replication task assessment for unsupported data types in the source database. </p>
-    async fn start_replication_task_assessment(
+    /// Presigns the request `start_replication_task_assessment` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_start_replication_task_assessment(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: StartReplicationTaskAssessmentMessage,
-    ) -> Result<
-        StartReplicationTaskAssessmentResponse,
-        RusotoError<StartReplicationTaskAssessmentError>,
-    > {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_start_replication_task_assessment_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_start_replication_task_assessment_request(
+        &self,
+        input: StartReplicationTaskAssessmentMessage,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "dms", &self.region, "/");
+        request.set_operation("StartReplicationTaskAssessment");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -6717,74 +8431,1822 @@ impl DatabaseMigrationService for DatabaseMigrationServiceClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `stop_replication_task`, also returning the metadata of the response.
+    pub async fn stop_replication_task_with_metadata(
+        &self,
+        input: StopReplicationTaskMessage,
+    ) -> Result<
+        (StopReplicationTaskResponse, ResponseMetadata),
+        RusotoError<StopReplicationTaskError>,
+    > {
+        let request = self.build_stop_replication_task_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<StartReplicationTaskAssessmentResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<StopReplicationTaskResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(StartReplicationTaskAssessmentError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(StopReplicationTaskError::from_response(response))
         }
     }
 
-    /// <p><p>Stops the replication task.</p> <p/></p>
-    async fn stop_replication_task(
+    /// Presigns the request `stop_replication_task` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_stop_replication_task(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: StopReplicationTaskMessage,
-    ) -> Result<StopReplicationTaskResponse, RusotoError<StopReplicationTaskError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_stop_replication_task_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_stop_replication_task_request(
+        &self,
+        input: StopReplicationTaskMessage,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "dms", &self.region, "/");
+        request.set_operation("StopReplicationTask");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonDMSv20160101.StopReplicationTask");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `test_connection`, also returning the metadata of the response.
+    pub async fn test_connection_with_metadata(
+        &self,
+        input: TestConnectionMessage,
+    ) -> Result<(TestConnectionResponse, ResponseMetadata), RusotoError<TestConnectionError>> {
+        let request = self.build_test_connection_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<StopReplicationTaskResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<TestConnectionResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(StopReplicationTaskError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(TestConnectionError::from_response(response))
         }
     }
 
-    /// <p>Tests the connection between the replication instance and the endpoint.</p>
-    async fn test_connection(
+    /// Presigns the request `test_connection` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_test_connection(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: TestConnectionMessage,
-    ) -> Result<TestConnectionResponse, RusotoError<TestConnectionError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_test_connection_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_test_connection_request(&self, input: TestConnectionMessage) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "dms", &self.region, "/");
+        request.set_operation("TestConnection");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonDMSv20160101.TestConnection");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
-        let mut response = self
-            .client
-            .sign_and_dispatch(request)
-            .await
-            .map_err(RusotoError::from)?;
-        if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<TestConnectionResponse, _>()
-        } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(TestConnectionError::from_response(response))
-        }
+        request
+    }
+
+    /// The pages of `DescribeCertificates` results for `input`, requesting each next page with the `Marker` of the last one as its `Marker`.
+    /// The page size is the `MaxRecords` of `input`.
+    pub fn describe_certificates_pages(
+        &self,
+        input: DescribeCertificatesMessage,
+    ) -> impl futures::Stream<
+        Item = Result<DescribeCertificatesResponse, RusotoError<DescribeCertificatesError>>,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: DatabaseMigrationServiceClient, input| async move {
+                client.describe_certificates(input).await
+            },
+            |input: &DescribeCertificatesMessage, output: &DescribeCertificatesResponse| {
+                let marker = output.marker.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if marker.is_none() || marker == input.marker {
+                    return None;
+                }
+                Some(DescribeCertificatesMessage {
+                    marker,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `Certificates` of every page of `DescribeCertificates` results for `input`, as `describe_certificates_pages` requests them.
+    pub fn describe_certificates_stream(
+        &self,
+        input: DescribeCertificatesMessage,
+    ) -> impl futures::Stream<Item = Result<Certificate, RusotoError<DescribeCertificatesError>>> + Send
+    {
+        rusoto_core::pagination::items(self.describe_certificates_pages(input), |page| {
+            page.certificates.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `DescribeConnections` results for `input`, requesting each next page with the `Marker` of the last one as its `Marker`.
+    /// The page size is the `MaxRecords` of `input`.
+    pub fn describe_connections_pages(
+        &self,
+        input: DescribeConnectionsMessage,
+    ) -> impl futures::Stream<
+        Item = Result<DescribeConnectionsResponse, RusotoError<DescribeConnectionsError>>,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: DatabaseMigrationServiceClient, input| async move {
+                client.describe_connections(input).await
+            },
+            |input: &DescribeConnectionsMessage, output: &DescribeConnectionsResponse| {
+                let marker = output.marker.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if marker.is_none() || marker == input.marker {
+                    return None;
+                }
+                Some(DescribeConnectionsMessage {
+                    marker,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `Connections` of every page of `DescribeConnections` results for `input`, as `describe_connections_pages` requests them.
+    pub fn describe_connections_stream(
+        &self,
+        input: DescribeConnectionsMessage,
+    ) -> impl futures::Stream<Item = Result<Connection, RusotoError<DescribeConnectionsError>>> + Send
+    {
+        rusoto_core::pagination::items(self.describe_connections_pages(input), |page| {
+            page.connections.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `DescribeEndpointTypes` results for `input`, requesting each next page with the `Marker` of the last one as its `Marker`.
+    /// The page size is the `MaxRecords` of `input`.
+    pub fn describe_endpoint_types_pages(
+        &self,
+        input: DescribeEndpointTypesMessage,
+    ) -> impl futures::Stream<
+        Item = Result<DescribeEndpointTypesResponse, RusotoError<DescribeEndpointTypesError>>,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: DatabaseMigrationServiceClient, input| async move {
+                client.describe_endpoint_types(input).await
+            },
+            |input: &DescribeEndpointTypesMessage, output: &DescribeEndpointTypesResponse| {
+                let marker = output.marker.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if marker.is_none() || marker == input.marker {
+                    return None;
+                }
+                Some(DescribeEndpointTypesMessage {
+                    marker,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `SupportedEndpointTypes` of every page of `DescribeEndpointTypes` results for `input`, as `describe_endpoint_types_pages` requests them.
+    pub fn describe_endpoint_types_stream(
+        &self,
+        input: DescribeEndpointTypesMessage,
+    ) -> impl futures::Stream<
+        Item = Result<SupportedEndpointType, RusotoError<DescribeEndpointTypesError>>,
+    > + Send {
+        rusoto_core::pagination::items(self.describe_endpoint_types_pages(input), |page| {
+            page.supported_endpoint_types.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `DescribeEndpoints` results for `input`, requesting each next page with the `Marker` of the last one as its `Marker`.
+    /// The page size is the `MaxRecords` of `input`.
+    pub fn describe_endpoints_pages(
+        &self,
+        input: DescribeEndpointsMessage,
+    ) -> impl futures::Stream<
+        Item = Result<DescribeEndpointsResponse, RusotoError<DescribeEndpointsError>>,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: DatabaseMigrationServiceClient, input| async move {
+                client.describe_endpoints(input).await
+            },
+            |input: &DescribeEndpointsMessage, output: &DescribeEndpointsResponse| {
+                let marker = output.marker.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if marker.is_none() || marker == input.marker {
+                    return None;
+                }
+                Some(DescribeEndpointsMessage {
+                    marker,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `Endpoints` of every page of `DescribeEndpoints` results for `input`, as `describe_endpoints_pages` requests them.
+    pub fn describe_endpoints_stream(
+        &self,
+        input: DescribeEndpointsMessage,
+    ) -> impl futures::Stream<Item = Result<Endpoint, RusotoError<DescribeEndpointsError>>> + Send
+    {
+        rusoto_core::pagination::items(self.describe_endpoints_pages(input), |page| {
+            page.endpoints.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `DescribeEventSubscriptions` results for `input`, requesting each next page with the `Marker` of the last one as its `Marker`.
+    /// The page size is the `MaxRecords` of `input`.
+    pub fn describe_event_subscriptions_pages(
+        &self,
+        input: DescribeEventSubscriptionsMessage,
+    ) -> impl futures::Stream<
+        Item = Result<
+            DescribeEventSubscriptionsResponse,
+            RusotoError<DescribeEventSubscriptionsError>,
+        >,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: DatabaseMigrationServiceClient, input| async move {
+                client.describe_event_subscriptions(input).await
+            },
+            |input: &DescribeEventSubscriptionsMessage,
+             output: &DescribeEventSubscriptionsResponse| {
+                let marker = output.marker.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if marker.is_none() || marker == input.marker {
+                    return None;
+                }
+                Some(DescribeEventSubscriptionsMessage {
+                    marker,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `EventSubscriptionsList` of every page of `DescribeEventSubscriptions` results for `input`, as `describe_event_subscriptions_pages` requests them.
+    pub fn describe_event_subscriptions_stream(
+        &self,
+        input: DescribeEventSubscriptionsMessage,
+    ) -> impl futures::Stream<
+        Item = Result<EventSubscription, RusotoError<DescribeEventSubscriptionsError>>,
+    > + Send {
+        rusoto_core::pagination::items(self.describe_event_subscriptions_pages(input), |page| {
+            page.event_subscriptions_list.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `DescribeEvents` results for `input`, requesting each next page with the `Marker` of the last one as its `Marker`.
+    /// The page size is the `MaxRecords` of `input`.
+    pub fn describe_events_pages(
+        &self,
+        input: DescribeEventsMessage,
+    ) -> impl futures::Stream<Item = Result<DescribeEventsResponse, RusotoError<DescribeEventsError>>>
+           + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: DatabaseMigrationServiceClient, input| async move {
+                client.describe_events(input).await
+            },
+            |input: &DescribeEventsMessage, output: &DescribeEventsResponse| {
+                let marker = output.marker.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if marker.is_none() || marker == input.marker {
+                    return None;
+                }
+                Some(DescribeEventsMessage {
+                    marker,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `Events` of every page of `DescribeEvents` results for `input`, as `describe_events_pages` requests them.
+    pub fn describe_events_stream(
+        &self,
+        input: DescribeEventsMessage,
+    ) -> impl futures::Stream<Item = Result<Event, RusotoError<DescribeEventsError>>> + Send {
+        rusoto_core::pagination::items(self.describe_events_pages(input), |page| {
+            page.events.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `DescribeOrderableReplicationInstances` results for `input`, requesting each next page with the `Marker` of the last one as its `Marker`.
+    /// The page size is the `MaxRecords` of `input`.
+    pub fn describe_orderable_replication_instances_pages(
+        &self,
+        input: DescribeOrderableReplicationInstancesMessage,
+    ) -> impl futures::Stream<
+        Item = Result<
+            DescribeOrderableReplicationInstancesResponse,
+            RusotoError<DescribeOrderableReplicationInstancesError>,
+        >,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: DatabaseMigrationServiceClient, input| async move {
+                client.describe_orderable_replication_instances(input).await
+            },
+            |input: &DescribeOrderableReplicationInstancesMessage,
+             output: &DescribeOrderableReplicationInstancesResponse| {
+                let marker = output.marker.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if marker.is_none() || marker == input.marker {
+                    return None;
+                }
+                Some(DescribeOrderableReplicationInstancesMessage {
+                    marker,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `OrderableReplicationInstances` of every page of `DescribeOrderableReplicationInstances` results for `input`, as `describe_orderable_replication_instances_pages` requests them.
+    pub fn describe_orderable_replication_instances_stream(
+        &self,
+        input: DescribeOrderableReplicationInstancesMessage,
+    ) -> impl futures::Stream<
+        Item = Result<
+            OrderableReplicationInstance,
+            RusotoError<DescribeOrderableReplicationInstancesError>,
+        >,
+    > + Send {
+        rusoto_core::pagination::items(
+            self.describe_orderable_replication_instances_pages(input),
+            |page| page.orderable_replication_instances.unwrap_or_default(),
+        )
+    }
+
+    /// The pages of `DescribeReplicationInstances` results for `input`, requesting each next page with the `Marker` of the last one as its `Marker`.
+    /// The page size is the `MaxRecords` of `input`.
+    pub fn describe_replication_instances_pages(
+        &self,
+        input: DescribeReplicationInstancesMessage,
+    ) -> impl futures::Stream<
+        Item = Result<
+            DescribeReplicationInstancesResponse,
+            RusotoError<DescribeReplicationInstancesError>,
+        >,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: DatabaseMigrationServiceClient, input| async move {
+                client.describe_replication_instances(input).await
+            },
+            |input: &DescribeReplicationInstancesMessage,
+             output: &DescribeReplicationInstancesResponse| {
+                let marker = output.marker.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if marker.is_none() || marker == input.marker {
+                    return None;
+                }
+                Some(DescribeReplicationInstancesMessage {
+                    marker,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `ReplicationInstances` of every page of `DescribeReplicationInstances` results for `input`, as `describe_replication_instances_pages` requests them.
+    pub fn describe_replication_instances_stream(
+        &self,
+        input: DescribeReplicationInstancesMessage,
+    ) -> impl futures::Stream<
+        Item = Result<ReplicationInstance, RusotoError<DescribeReplicationInstancesError>>,
+    > + Send {
+        rusoto_core::pagination::items(self.describe_replication_instances_pages(input), |page| {
+            page.replication_instances.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `DescribeReplicationSubnetGroups` results for `input`, requesting each next page with the `Marker` of the last one as its `Marker`.
+    /// The page size is the `MaxRecords` of `input`.
+    pub fn describe_replication_subnet_groups_pages(
+        &self,
+        input: DescribeReplicationSubnetGroupsMessage,
+    ) -> impl futures::Stream<
+        Item = Result<
+            DescribeReplicationSubnetGroupsResponse,
+            RusotoError<DescribeReplicationSubnetGroupsError>,
+        >,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: DatabaseMigrationServiceClient, input| async move {
+                client.describe_replication_subnet_groups(input).await
+            },
+            |input: &DescribeReplicationSubnetGroupsMessage,
+             output: &DescribeReplicationSubnetGroupsResponse| {
+                let marker = output.marker.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if marker.is_none() || marker == input.marker {
+                    return None;
+                }
+                Some(DescribeReplicationSubnetGroupsMessage {
+                    marker,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `ReplicationSubnetGroups` of every page of `DescribeReplicationSubnetGroups` results for `input`, as `describe_replication_subnet_groups_pages` requests them.
+    pub fn describe_replication_subnet_groups_stream(
+        &self,
+        input: DescribeReplicationSubnetGroupsMessage,
+    ) -> impl futures::Stream<
+        Item = Result<ReplicationSubnetGroup, RusotoError<DescribeReplicationSubnetGroupsError>>,
+    > + Send {
+        rusoto_core::pagination::items(
+            self.describe_replication_subnet_groups_pages(input),
+            |page| page.replication_subnet_groups.unwrap_or_default(),
+        )
+    }
+
+    /// The pages of `DescribeReplicationTaskAssessmentResults` results for `input`, requesting each next page with the `Marker` of the last one as its `Marker`.
+    /// The page size is the `MaxRecords` of `input`.
+    pub fn describe_replication_task_assessment_results_pages(
+        &self,
+        input: DescribeReplicationTaskAssessmentResultsMessage,
+    ) -> impl futures::Stream<
+        Item = Result<
+            DescribeReplicationTaskAssessmentResultsResponse,
+            RusotoError<DescribeReplicationTaskAssessmentResultsError>,
+        >,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: DatabaseMigrationServiceClient, input| async move {
+                client
+                    .describe_replication_task_assessment_results(input)
+                    .await
+            },
+            |input: &DescribeReplicationTaskAssessmentResultsMessage,
+             output: &DescribeReplicationTaskAssessmentResultsResponse| {
+                let marker = output.marker.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if marker.is_none() || marker == input.marker {
+                    return None;
+                }
+                Some(DescribeReplicationTaskAssessmentResultsMessage {
+                    marker,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `ReplicationTaskAssessmentResults` of every page of `DescribeReplicationTaskAssessmentResults` results for `input`, as `describe_replication_task_assessment_results_pages` requests them.
+    pub fn describe_replication_task_assessment_results_stream(
+        &self,
+        input: DescribeReplicationTaskAssessmentResultsMessage,
+    ) -> impl futures::Stream<
+        Item = Result<
+            ReplicationTaskAssessmentResult,
+            RusotoError<DescribeReplicationTaskAssessmentResultsError>,
+        >,
+    > + Send {
+        rusoto_core::pagination::items(
+            self.describe_replication_task_assessment_results_pages(input),
+            |page| page.replication_task_assessment_results.unwrap_or_default(),
+        )
+    }
+
+    /// The pages of `DescribeReplicationTasks` results for `input`, requesting each next page with the `Marker` of the last one as its `Marker`.
+    /// The page size is the `MaxRecords` of `input`.
+    pub fn describe_replication_tasks_pages(
+        &self,
+        input: DescribeReplicationTasksMessage,
+    ) -> impl futures::Stream<
+        Item = Result<DescribeReplicationTasksResponse, RusotoError<DescribeReplicationTasksError>>,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: DatabaseMigrationServiceClient, input| async move {
+                client.describe_replication_tasks(input).await
+            },
+            |input: &DescribeReplicationTasksMessage, output: &DescribeReplicationTasksResponse| {
+                let marker = output.marker.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if marker.is_none() || marker == input.marker {
+                    return None;
+                }
+                Some(DescribeReplicationTasksMessage {
+                    marker,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `ReplicationTasks` of every page of `DescribeReplicationTasks` results for `input`, as `describe_replication_tasks_pages` requests them.
+    pub fn describe_replication_tasks_stream(
+        &self,
+        input: DescribeReplicationTasksMessage,
+    ) -> impl futures::Stream<
+        Item = Result<ReplicationTask, RusotoError<DescribeReplicationTasksError>>,
+    > + Send {
+        rusoto_core::pagination::items(self.describe_replication_tasks_pages(input), |page| {
+            page.replication_tasks.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `DescribeSchemas` results for `input`, requesting each next page with the `Marker` of the last one as its `Marker`.
+    /// The page size is the `MaxRecords` of `input`.
+    pub fn describe_schemas_pages(
+        &self,
+        input: DescribeSchemasMessage,
+    ) -> impl futures::Stream<
+        Item = Result<DescribeSchemasResponse, RusotoError<DescribeSchemasError>>,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: DatabaseMigrationServiceClient, input| async move {
+                client.describe_schemas(input).await
+            },
+            |input: &DescribeSchemasMessage, output: &DescribeSchemasResponse| {
+                let marker = output.marker.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if marker.is_none() || marker == input.marker {
+                    return None;
+                }
+                Some(DescribeSchemasMessage {
+                    marker,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `Schemas` of every page of `DescribeSchemas` results for `input`, as `describe_schemas_pages` requests them.
+    pub fn describe_schemas_stream(
+        &self,
+        input: DescribeSchemasMessage,
+    ) -> impl futures::Stream<Item = Result<String, RusotoError<DescribeSchemasError>>> + Send {
+        rusoto_core::pagination::items(self.describe_schemas_pages(input), |page| {
+            page.schemas.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `DescribeTableStatistics` results for `input`, requesting each next page with the `Marker` of the last one as its `Marker`.
+    /// The page size is the `MaxRecords` of `input`.
+    pub fn describe_table_statistics_pages(
+        &self,
+        input: DescribeTableStatisticsMessage,
+    ) -> impl futures::Stream<
+        Item = Result<DescribeTableStatisticsResponse, RusotoError<DescribeTableStatisticsError>>,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: DatabaseMigrationServiceClient, input| async move {
+                client.describe_table_statistics(input).await
+            },
+            |input: &DescribeTableStatisticsMessage, output: &DescribeTableStatisticsResponse| {
+                let marker = output.marker.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if marker.is_none() || marker == input.marker {
+                    return None;
+                }
+                Some(DescribeTableStatisticsMessage {
+                    marker,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `TableStatistics` of every page of `DescribeTableStatistics` results for `input`, as `describe_table_statistics_pages` requests them.
+    pub fn describe_table_statistics_stream(
+        &self,
+        input: DescribeTableStatisticsMessage,
+    ) -> impl futures::Stream<
+        Item = Result<TableStatistics, RusotoError<DescribeTableStatisticsError>>,
+    > + Send {
+        rusoto_core::pagination::items(self.describe_table_statistics_pages(input), |page| {
+            page.table_statistics.unwrap_or_default()
+        })
+    }
+
+    /// Waits for `EndpointDeleted`, polling `DescribeEndpoints` with `input` every 5 seconds, up to 60 times.
+    pub async fn wait_until_endpoint_deleted(
+        &self,
+        input: DescribeEndpointsMessage,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<DescribeEndpointsError>> {
+        let waiter = rusoto_core::waiter::Waiter::new(std::time::Duration::from_secs(5), 60);
+        self.wait_until_endpoint_deleted_with(input, &waiter).await
+    }
+
+    /// Waits for `EndpointDeleted` like `wait_until_endpoint_deleted`, with the delay, attempts and runtime of `waiter`.
+    pub async fn wait_until_endpoint_deleted_with(
+        &self,
+        input: DescribeEndpointsMessage,
+        waiter: &rusoto_core::waiter::Waiter,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<DescribeEndpointsError>> {
+        rusoto_core::waiter::wait(
+            waiter,
+            || self.describe_endpoints(input.clone()),
+            |result| {
+                if result
+                    .as_ref()
+                    .err()
+                    .and_then(|error| error.error_code())
+                    .as_deref()
+                    == Some("ResourceNotFoundFault")
+                {
+                    return Some(rusoto_core::waiter::WaiterState::Success);
+                }
+                if match result {
+                    Ok(output) => std::iter::once(output)
+                        .filter_map(|value| value.endpoints.as_ref())
+                        .flat_map(|value| value.iter())
+                        .filter_map(|value| value.status.as_ref())
+                        .any(|value| value == "active"),
+                    Err(_) => false,
+                } {
+                    return Some(rusoto_core::waiter::WaiterState::Failure);
+                }
+                if match result {
+                    Ok(output) => std::iter::once(output)
+                        .filter_map(|value| value.endpoints.as_ref())
+                        .flat_map(|value| value.iter())
+                        .filter_map(|value| value.status.as_ref())
+                        .any(|value| value == "creating"),
+                    Err(_) => false,
+                } {
+                    return Some(rusoto_core::waiter::WaiterState::Failure);
+                }
+                None
+            },
+        )
+        .await
+    }
+
+    /// Waits for `ReplicationInstanceAvailable`, polling `DescribeReplicationInstances` with `input` every 60 seconds, up to 60 times.
+    pub async fn wait_until_replication_instance_available(
+        &self,
+        input: DescribeReplicationInstancesMessage,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<DescribeReplicationInstancesError>> {
+        let waiter = rusoto_core::waiter::Waiter::new(std::time::Duration::from_secs(60), 60);
+        self.wait_until_replication_instance_available_with(input, &waiter)
+            .await
+    }
+
+    /// Waits for `ReplicationInstanceAvailable` like `wait_until_replication_instance_available`, with the delay, attempts and runtime of `waiter`.
+    pub async fn wait_until_replication_instance_available_with(
+        &self,
+        input: DescribeReplicationInstancesMessage,
+        waiter: &rusoto_core::waiter::Waiter,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<DescribeReplicationInstancesError>> {
+        rusoto_core::waiter::wait(
+            waiter,
+            || self.describe_replication_instances(input.clone()),
+            |result| {
+                if match result {
+                    Ok(output) => {
+                        let mut values = std::iter::once(output)
+                            .filter_map(|value| value.replication_instances.as_ref())
+                            .flat_map(|value| value.iter())
+                            .filter_map(|value| value.replication_instance_status.as_ref())
+                            .peekable();
+                        values.peek().is_some() && values.all(|value| value == "available")
+                    }
+                    Err(_) => false,
+                } {
+                    return Some(rusoto_core::waiter::WaiterState::Success);
+                }
+                if match result {
+                    Ok(output) => std::iter::once(output)
+                        .filter_map(|value| value.replication_instances.as_ref())
+                        .flat_map(|value| value.iter())
+                        .filter_map(|value| value.replication_instance_status.as_ref())
+                        .any(|value| value == "deleting"),
+                    Err(_) => false,
+                } {
+                    return Some(rusoto_core::waiter::WaiterState::Failure);
+                }
+                if match result {
+                    Ok(output) => std::iter::once(output)
+                        .filter_map(|value| value.replication_instances.as_ref())
+                        .flat_map(|value| value.iter())
+                        .filter_map(|value| value.replication_instance_status.as_ref())
+                        .any(|value| value == "incompatible-credentials"),
+                    Err(_) => false,
+                } {
+                    return Some(rusoto_core::waiter::WaiterState::Failure);
+                }
+                if match result {
+                    Ok(output) => std::iter::once(output)
+                        .filter_map(|value| value.replication_instances.as_ref())
+                        .flat_map(|value| value.iter())
+                        .filter_map(|value| value.replication_instance_status.as_ref())
+                        .any(|value| value == "incompatible-network"),
+                    Err(_) => false,
+                } {
+                    return Some(rusoto_core::waiter::WaiterState::Failure);
+                }
+                if match result {
+                    Ok(output) => std::iter::once(output)
+                        .filter_map(|value| value.replication_instances.as_ref())
+                        .flat_map(|value| value.iter())
+                        .filter_map(|value| value.replication_instance_status.as_ref())
+                        .any(|value| value == "inaccessible-encryption-credentials"),
+                    Err(_) => false,
+                } {
+                    return Some(rusoto_core::waiter::WaiterState::Failure);
+                }
+                None
+            },
+        )
+        .await
+    }
+
+    /// Waits for `ReplicationInstanceDeleted`, polling `DescribeReplicationInstances` with `input` every 15 seconds, up to 60 times.
+    pub async fn wait_until_replication_instance_deleted(
+        &self,
+        input: DescribeReplicationInstancesMessage,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<DescribeReplicationInstancesError>> {
+        let waiter = rusoto_core::waiter::Waiter::new(std::time::Duration::from_secs(15), 60);
+        self.wait_until_replication_instance_deleted_with(input, &waiter)
+            .await
+    }
+
+    /// Waits for `ReplicationInstanceDeleted` like `wait_until_replication_instance_deleted`, with the delay, attempts and runtime of `waiter`.
+    pub async fn wait_until_replication_instance_deleted_with(
+        &self,
+        input: DescribeReplicationInstancesMessage,
+        waiter: &rusoto_core::waiter::Waiter,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<DescribeReplicationInstancesError>> {
+        rusoto_core::waiter::wait(
+            waiter,
+            || self.describe_replication_instances(input.clone()),
+            |result| {
+                if match result {
+                    Ok(output) => std::iter::once(output)
+                        .filter_map(|value| value.replication_instances.as_ref())
+                        .flat_map(|value| value.iter())
+                        .filter_map(|value| value.replication_instance_status.as_ref())
+                        .any(|value| value == "available"),
+                    Err(_) => false,
+                } {
+                    return Some(rusoto_core::waiter::WaiterState::Failure);
+                }
+                if result
+                    .as_ref()
+                    .err()
+                    .and_then(|error| error.error_code())
+                    .as_deref()
+                    == Some("ResourceNotFoundFault")
+                {
+                    return Some(rusoto_core::waiter::WaiterState::Success);
+                }
+                None
+            },
+        )
+        .await
+    }
+
+    /// Waits for `ReplicationTaskDeleted`, polling `DescribeReplicationTasks` with `input` every 15 seconds, up to 60 times.
+    pub async fn wait_until_replication_task_deleted(
+        &self,
+        input: DescribeReplicationTasksMessage,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<DescribeReplicationTasksError>> {
+        let waiter = rusoto_core::waiter::Waiter::new(std::time::Duration::from_secs(15), 60);
+        self.wait_until_replication_task_deleted_with(input, &waiter)
+            .await
+    }
+
+    /// Waits for `ReplicationTaskDeleted` like `wait_until_replication_task_deleted`, with the delay, attempts and runtime of `waiter`.
+    pub async fn wait_until_replication_task_deleted_with(
+        &self,
+        input: DescribeReplicationTasksMessage,
+        waiter: &rusoto_core::waiter::Waiter,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<DescribeReplicationTasksError>> {
+        rusoto_core::waiter::wait(
+            waiter,
+            || self.describe_replication_tasks(input.clone()),
+            |result| {
+                if match result {
+                    Ok(output) => std::iter::once(output)
+                        .filter_map(|value| value.replication_tasks.as_ref())
+                        .flat_map(|value| value.iter())
+                        .filter_map(|value| value.status.as_ref())
+                        .any(|value| value == "ready"),
+                    Err(_) => false,
+                } {
+                    return Some(rusoto_core::waiter::WaiterState::Failure);
+                }
+                if match result {
+                    Ok(output) => std::iter::once(output)
+                        .filter_map(|value| value.replication_tasks.as_ref())
+                        .flat_map(|value| value.iter())
+                        .filter_map(|value| value.status.as_ref())
+                        .any(|value| value == "creating"),
+                    Err(_) => false,
+                } {
+                    return Some(rusoto_core::waiter::WaiterState::Failure);
+                }
+                if match result {
+                    Ok(output) => std::iter::once(output)
+                        .filter_map(|value| value.replication_tasks.as_ref())
+                        .flat_map(|value| value.iter())
+                        .filter_map(|value| value.status.as_ref())
+                        .any(|value| value == "stopped"),
+                    Err(_) => false,
+                } {
+                    return Some(rusoto_core::waiter::WaiterState::Failure);
+                }
+                if match result {
+                    Ok(output) => std::iter::once(output)
+                        .filter_map(|value| value.replication_tasks.as_ref())
+                        .flat_map(|value| value.iter())
+                        .filter_map(|value| value.status.as_ref())
+                        .any(|value| value == "running"),
+                    Err(_) => false,
+                } {
+                    return Some(rusoto_core::waiter::WaiterState::Failure);
+                }
+                if match result {
+                    Ok(output) => std::iter::once(output)
+                        .filter_map(|value| value.replication_tasks.as_ref())
+                        .flat_map(|value| value.iter())
+                        .filter_map(|value| value.status.as_ref())
+                        .any(|value| value == "failed"),
+                    Err(_) => false,
+                } {
+                    return Some(rusoto_core::waiter::WaiterState::Failure);
+                }
+                if result
+                    .as_ref()
+                    .err()
+                    .and_then(|error| error.error_code())
+                    .as_deref()
+                    == Some("ResourceNotFoundFault")
+                {
+                    return Some(rusoto_core::waiter::WaiterState::Success);
+                }
+                None
+            },
+        )
+        .await
+    }
+
+    /// Waits for `ReplicationTaskReady`, polling `DescribeReplicationTasks` with `input` every 15 seconds, up to 60 times.
+    pub async fn wait_until_replication_task_ready(
+        &self,
+        input: DescribeReplicationTasksMessage,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<DescribeReplicationTasksError>> {
+        let waiter = rusoto_core::waiter::Waiter::new(std::time::Duration::from_secs(15), 60);
+        self.wait_until_replication_task_ready_with(input, &waiter)
+            .await
+    }
+
+    /// Waits for `ReplicationTaskReady` like `wait_until_replication_task_ready`, with the delay, attempts and runtime of `waiter`.
+    pub async fn wait_until_replication_task_ready_with(
+        &self,
+        input: DescribeReplicationTasksMessage,
+        waiter: &rusoto_core::waiter::Waiter,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<DescribeReplicationTasksError>> {
+        rusoto_core::waiter::wait(
+            waiter,
+            || self.describe_replication_tasks(input.clone()),
+            |result| {
+                if match result {
+                    Ok(output) => {
+                        let mut values = std::iter::once(output)
+                            .filter_map(|value| value.replication_tasks.as_ref())
+                            .flat_map(|value| value.iter())
+                            .filter_map(|value| value.status.as_ref())
+                            .peekable();
+                        values.peek().is_some() && values.all(|value| value == "ready")
+                    }
+                    Err(_) => false,
+                } {
+                    return Some(rusoto_core::waiter::WaiterState::Success);
+                }
+                if match result {
+                    Ok(output) => std::iter::once(output)
+                        .filter_map(|value| value.replication_tasks.as_ref())
+                        .flat_map(|value| value.iter())
+                        .filter_map(|value| value.status.as_ref())
+                        .any(|value| value == "starting"),
+                    Err(_) => false,
+                } {
+                    return Some(rusoto_core::waiter::WaiterState::Failure);
+                }
+                if match result {
+                    Ok(output) => std::iter::once(output)
+                        .filter_map(|value| value.replication_tasks.as_ref())
+                        .flat_map(|value| value.iter())
+                        .filter_map(|value| value.status.as_ref())
+                        .any(|value| value == "running"),
+                    Err(_) => false,
+                } {
+                    return Some(rusoto_core::waiter::WaiterState::Failure);
+                }
+                if match result {
+                    Ok(output) => std::iter::once(output)
+                        .filter_map(|value| value.replication_tasks.as_ref())
+                        .flat_map(|value| value.iter())
+                        .filter_map(|value| value.status.as_ref())
+                        .any(|value| value == "stopping"),
+                    Err(_) => false,
+                } {
+                    return Some(rusoto_core::waiter::WaiterState::Failure);
+                }
+                if match result {
+                    Ok(output) => std::iter::once(output)
+                        .filter_map(|value| value.replication_tasks.as_ref())
+                        .flat_map(|value| value.iter())
+                        .filter_map(|value| value.status.as_ref())
+                        .any(|value| value == "stopped"),
+                    Err(_) => false,
+                } {
+                    return Some(rusoto_core::waiter::WaiterState::Failure);
+                }
+                if match result {
+                    Ok(output) => std::iter::once(output)
+                        .filter_map(|value| value.replication_tasks.as_ref())
+                        .flat_map(|value| value.iter())
+                        .filter_map(|value| value.status.as_ref())
+                        .any(|value| value == "failed"),
+                    Err(_) => false,
+                } {
+                    return Some(rusoto_core::waiter::WaiterState::Failure);
+                }
+                if match result {
+                    Ok(output) => std::iter::once(output)
+                        .filter_map(|value| value.replication_tasks.as_ref())
+                        .flat_map(|value| value.iter())
+                        .filter_map(|value| value.status.as_ref())
+                        .any(|value| value == "modifying"),
+                    Err(_) => false,
+                } {
+                    return Some(rusoto_core::waiter::WaiterState::Failure);
+                }
+                if match result {
+                    Ok(output) => std::iter::once(output)
+                        .filter_map(|value| value.replication_tasks.as_ref())
+                        .flat_map(|value| value.iter())
+                        .filter_map(|value| value.status.as_ref())
+                        .any(|value| value == "testing"),
+                    Err(_) => false,
+                } {
+                    return Some(rusoto_core::waiter::WaiterState::Failure);
+                }
+                if match result {
+                    Ok(output) => std::iter::once(output)
+                        .filter_map(|value| value.replication_tasks.as_ref())
+                        .flat_map(|value| value.iter())
+                        .filter_map(|value| value.status.as_ref())
+                        .any(|value| value == "deleting"),
+                    Err(_) => false,
+                } {
+                    return Some(rusoto_core::waiter::WaiterState::Failure);
+                }
+                None
+            },
+        )
+        .await
+    }
+
+    /// Waits for `ReplicationTaskRunning`, polling `DescribeReplicationTasks` with `input` every 15 seconds, up to 60 times.
+    pub async fn wait_until_replication_task_running(
+        &self,
+        input: DescribeReplicationTasksMessage,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<DescribeReplicationTasksError>> {
+        let waiter = rusoto_core::waiter::Waiter::new(std::time::Duration::from_secs(15), 60);
+        self.wait_until_replication_task_running_with(input, &waiter)
+            .await
+    }
+
+    /// Waits for `ReplicationTaskRunning` like `wait_until_replication_task_running`, with the delay, attempts and runtime of `waiter`.
+    pub async fn wait_until_replication_task_running_with(
+        &self,
+        input: DescribeReplicationTasksMessage,
+        waiter: &rusoto_core::waiter::Waiter,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<DescribeReplicationTasksError>> {
+        rusoto_core::waiter::wait(
+            waiter,
+            || self.describe_replication_tasks(input.clone()),
+            |result| {
+                if match result {
+                    Ok(output) => {
+                        let mut values = std::iter::once(output)
+                            .filter_map(|value| value.replication_tasks.as_ref())
+                            .flat_map(|value| value.iter())
+                            .filter_map(|value| value.status.as_ref())
+                            .peekable();
+                        values.peek().is_some() && values.all(|value| value == "running")
+                    }
+                    Err(_) => false,
+                } {
+                    return Some(rusoto_core::waiter::WaiterState::Success);
+                }
+                if match result {
+                    Ok(output) => std::iter::once(output)
+                        .filter_map(|value| value.replication_tasks.as_ref())
+                        .flat_map(|value| value.iter())
+                        .filter_map(|value| value.status.as_ref())
+                        .any(|value| value == "ready"),
+                    Err(_) => false,
+                } {
+                    return Some(rusoto_core::waiter::WaiterState::Failure);
+                }
+                if match result {
+                    Ok(output) => std::iter::once(output)
+                        .filter_map(|value| value.replication_tasks.as_ref())
+                        .flat_map(|value| value.iter())
+                        .filter_map(|value| value.status.as_ref())
+                        .any(|value| value == "creating"),
+                    Err(_) => false,
+                } {
+                    return Some(rusoto_core::waiter::WaiterState::Failure);
+                }
+                if match result {
+                    Ok(output) => std::iter::once(output)
+                        .filter_map(|value| value.replication_tasks.as_ref())
+                        .flat_map(|value| value.iter())
+                        .filter_map(|value| value.status.as_ref())
+                        .any(|value| value == "stopping"),
+                    Err(_) => false,
+                } {
+                    return Some(rusoto_core::waiter::WaiterState::Failure);
+                }
+                if match result {
+                    Ok(output) => std::iter::once(output)
+                        .filter_map(|value| value.replication_tasks.as_ref())
+                        .flat_map(|value| value.iter())
+                        .filter_map(|value| value.status.as_ref())
+                        .any(|value| value == "stopped"),
+                    Err(_) => false,
+                } {
+                    return Some(rusoto_core::waiter::WaiterState::Failure);
+                }
+                if match result {
+                    Ok(output) => std::iter::once(output)
+                        .filter_map(|value| value.replication_tasks.as_ref())
+                        .flat_map(|value| value.iter())
+                        .filter_map(|value| value.status.as_ref())
+                        .any(|value| value == "failed"),
+                    Err(_) => false,
+                } {
+                    return Some(rusoto_core::waiter::WaiterState::Failure);
+                }
+                if match result {
+                    Ok(output) => std::iter::once(output)
+                        .filter_map(|value| value.replication_tasks.as_ref())
+                        .flat_map(|value| value.iter())
+                        .filter_map(|value| value.status.as_ref())
+                        .any(|value| value == "modifying"),
+                    Err(_) => false,
+                } {
+                    return Some(rusoto_core::waiter::WaiterState::Failure);
+                }
+                if match result {
+                    Ok(output) => std::iter::once(output)
+                        .filter_map(|value| value.replication_tasks.as_ref())
+                        .flat_map(|value| value.iter())
+                        .filter_map(|value| value.status.as_ref())
+                        .any(|value| value == "testing"),
+                    Err(_) => false,
+                } {
+                    return Some(rusoto_core::waiter::WaiterState::Failure);
+                }
+                if match result {
+                    Ok(output) => std::iter::once(output)
+                        .filter_map(|value| value.replication_tasks.as_ref())
+                        .flat_map(|value| value.iter())
+                        .filter_map(|value| value.status.as_ref())
+                        .any(|value| value == "deleting"),
+                    Err(_) => false,
+                } {
+                    return Some(rusoto_core::waiter::WaiterState::Failure);
+                }
+                None
+            },
+        )
+        .await
+    }
+
+    /// Waits for `ReplicationTaskStopped`, polling `DescribeReplicationTasks` with `input` every 15 seconds, up to 60 times.
+    pub async fn wait_until_replication_task_stopped(
+        &self,
+        input: DescribeReplicationTasksMessage,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<DescribeReplicationTasksError>> {
+        let waiter = rusoto_core::waiter::Waiter::new(std::time::Duration::from_secs(15), 60);
+        self.wait_until_replication_task_stopped_with(input, &waiter)
+            .await
+    }
+
+    /// Waits for `ReplicationTaskStopped` like `wait_until_replication_task_stopped`, with the delay, attempts and runtime of `waiter`.
+    pub async fn wait_until_replication_task_stopped_with(
+        &self,
+        input: DescribeReplicationTasksMessage,
+        waiter: &rusoto_core::waiter::Waiter,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<DescribeReplicationTasksError>> {
+        rusoto_core::waiter::wait(
+            waiter,
+            || self.describe_replication_tasks(input.clone()),
+            |result| {
+                if match result {
+                    Ok(output) => {
+                        let mut values = std::iter::once(output)
+                            .filter_map(|value| value.replication_tasks.as_ref())
+                            .flat_map(|value| value.iter())
+                            .filter_map(|value| value.status.as_ref())
+                            .peekable();
+                        values.peek().is_some() && values.all(|value| value == "stopped")
+                    }
+                    Err(_) => false,
+                } {
+                    return Some(rusoto_core::waiter::WaiterState::Success);
+                }
+                if match result {
+                    Ok(output) => std::iter::once(output)
+                        .filter_map(|value| value.replication_tasks.as_ref())
+                        .flat_map(|value| value.iter())
+                        .filter_map(|value| value.status.as_ref())
+                        .any(|value| value == "ready"),
+                    Err(_) => false,
+                } {
+                    return Some(rusoto_core::waiter::WaiterState::Failure);
+                }
+                if match result {
+                    Ok(output) => std::iter::once(output)
+                        .filter_map(|value| value.replication_tasks.as_ref())
+                        .flat_map(|value| value.iter())
+                        .filter_map(|value| value.status.as_ref())
+                        .any(|value| value == "creating"),
+                    Err(_) => false,
+                } {
+                    return Some(rusoto_core::waiter::WaiterState::Failure);
+                }
+                if match result {
+                    Ok(output) => std::iter::once(output)
+                        .filter_map(|value| value.replication_tasks.as_ref())
+                        .flat_map(|value| value.iter())
+                        .filter_map(|value| value.status.as_ref())
+                        .any(|value| value == "starting"),
+                    Err(_) => false,
+                } {
+                    return Some(rusoto_core::waiter::WaiterState::Failure);
+                }
+                if match result {
+                    Ok(output) => std::iter::once(output)
+                        .filter_map(|value| value.replication_tasks.as_ref())
+                        .flat_map(|value| value.iter())
+                        .filter_map(|value| value.status.as_ref())
+                        .any(|value| value == "failed"),
+                    Err(_) => false,
+                } {
+                    return Some(rusoto_core::waiter::WaiterState::Failure);
+                }
+                if match result {
+                    Ok(output) => std::iter::once(output)
+                        .filter_map(|value| value.replication_tasks.as_ref())
+                        .flat_map(|value| value.iter())
+                        .filter_map(|value| value.status.as_ref())
+                        .any(|value| value == "modifying"),
+                    Err(_) => false,
+                } {
+                    return Some(rusoto_core::waiter::WaiterState::Failure);
+                }
+                if match result {
+                    Ok(output) => std::iter::once(output)
+                        .filter_map(|value| value.replication_tasks.as_ref())
+                        .flat_map(|value| value.iter())
+                        .filter_map(|value| value.status.as_ref())
+                        .any(|value| value == "testing"),
+                    Err(_) => false,
+                } {
+                    return Some(rusoto_core::waiter::WaiterState::Failure);
+                }
+                if match result {
+                    Ok(output) => std::iter::once(output)
+                        .filter_map(|value| value.replication_tasks.as_ref())
+                        .flat_map(|value| value.iter())
+                        .filter_map(|value| value.status.as_ref())
+                        .any(|value| value == "deleting"),
+                    Err(_) => false,
+                } {
+                    return Some(rusoto_core::waiter::WaiterState::Failure);
+                }
+                None
+            },
+        )
+        .await
+    }
+
+    /// Waits for `TestConnectionSucceeds`, polling `DescribeConnections` with `input` every 5 seconds, up to 60 times.
+    pub async fn wait_until_test_connection_succeeds(
+        &self,
+        input: DescribeConnectionsMessage,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<DescribeConnectionsError>> {
+        let waiter = rusoto_core::waiter::Waiter::new(std::time::Duration::from_secs(5), 60);
+        self.wait_until_test_connection_succeeds_with(input, &waiter)
+            .await
+    }
+
+    /// Waits for `TestConnectionSucceeds` like `wait_until_test_connection_succeeds`, with the delay, attempts and runtime of `waiter`.
+    pub async fn wait_until_test_connection_succeeds_with(
+        &self,
+        input: DescribeConnectionsMessage,
+        waiter: &rusoto_core::waiter::Waiter,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<DescribeConnectionsError>> {
+        rusoto_core::waiter::wait(
+            waiter,
+            || self.describe_connections(input.clone()),
+            |result| {
+                if match result {
+                    Ok(output) => {
+                        let mut values = std::iter::once(output)
+                            .filter_map(|value| value.connections.as_ref())
+                            .flat_map(|value| value.iter())
+                            .filter_map(|value| value.status.as_ref())
+                            .peekable();
+                        values.peek().is_some() && values.all(|value| value == "successful")
+                    }
+                    Err(_) => false,
+                } {
+                    return Some(rusoto_core::waiter::WaiterState::Success);
+                }
+                if match result {
+                    Ok(output) => std::iter::once(output)
+                        .filter_map(|value| value.connections.as_ref())
+                        .flat_map(|value| value.iter())
+                        .filter_map(|value| value.status.as_ref())
+                        .any(|value| value == "failed"),
+                    Err(_) => false,
+                } {
+                    return Some(rusoto_core::waiter::WaiterState::Failure);
+                }
+                None
+            },
+        )
+        .await
+    }
+}
+
+#[async_trait]
+impl DatabaseMigrationService for DatabaseMigrationServiceClient {
+    /// <p>Adds metadata tags to an AWS DMS resource, including replication instance, endpoint, security group, and migration task. These tags can also be used with cost allocation reporting to track cost associated with DMS resources, or used in a Condition statement in an IAM policy for DMS.</p>
+    async fn add_tags_to_resource(
+        &self,
+        input: AddTagsToResourceMessage,
+    ) -> Result<AddTagsToResourceResponse, RusotoError<AddTagsToResourceError>> {
+        self.add_tags_to_resource_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Applies a pending maintenance action to a resource (for example, to a replication instance).</p>
+    async fn apply_pending_maintenance_action(
+        &self,
+        input: ApplyPendingMaintenanceActionMessage,
+    ) -> Result<
+        ApplyPendingMaintenanceActionResponse,
+        RusotoError<ApplyPendingMaintenanceActionError>,
+    > {
+        self.apply_pending_maintenance_action_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Creates an endpoint using the provided settings.</p>
+    async fn create_endpoint(
+        &self,
+        input: CreateEndpointMessage,
+    ) -> Result<CreateEndpointResponse, RusotoError<CreateEndpointError>> {
+        self.create_endpoint_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p> Creates an AWS DMS event notification subscription. </p> <p>You can specify the type of source (<code>SourceType</code>) you want to be notified of, provide a list of AWS DMS source IDs (<code>SourceIds</code>) that triggers the events, and provide a list of event categories (<code>EventCategories</code>) for events you want to be notified of. If you specify both the <code>SourceType</code> and <code>SourceIds</code>, such as <code>SourceType = replication-instance</code> and <code>SourceIdentifier = my-replinstance</code>, you will be notified of all the replication instance events for the specified source. If you specify a <code>SourceType</code> but don't specify a <code>SourceIdentifier</code>, you receive notice of the events for that source type for all your AWS DMS sources. If you don't specify either <code>SourceType</code> nor <code>SourceIdentifier</code>, you will be notified of events generated from all AWS DMS sources belonging to your customer account.</p> <p>For more information about AWS DMS events, see <a href="https://docs.aws.amazon.com/dms/latest/userguide/CHAP_Events.html">Working with Events and Notifications</a> in the <i>AWS Database Migration Service User Guide.</i> </p>
+    async fn create_event_subscription(
+        &self,
+        input: CreateEventSubscriptionMessage,
+    ) -> Result<CreateEventSubscriptionResponse, RusotoError<CreateEventSubscriptionError>> {
+        self.create_event_subscription_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Creates the replication instance using the specified parameters.</p> <p>AWS DMS requires that your account have certain roles with appropriate permissions before you can create a replication instance. For information on the required roles, see <a href="https://docs.aws.amazon.com/dms/latest/userguide/CHAP_Security.APIRole.html">Creating the IAM Roles to Use With the AWS CLI and AWS DMS API</a>. For information on the required permissions, see <a href="https://docs.aws.amazon.com/dms/latest/userguide/CHAP_Security.IAMPermissions.html">IAM Permissions Needed to Use AWS DMS</a>.</p>
+    async fn create_replication_instance(
+        &self,
+        input: CreateReplicationInstanceMessage,
+    ) -> Result<CreateReplicationInstanceResponse, RusotoError<CreateReplicationInstanceError>>
+    {
+        self.create_replication_instance_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Creates a replication subnet group given a list of the subnet IDs in a VPC.</p>
+    async fn create_replication_subnet_group(
+        &self,
+        input: CreateReplicationSubnetGroupMessage,
+    ) -> Result<CreateReplicationSubnetGroupResponse, RusotoError<CreateReplicationSubnetGroupError>>
+    {
+        self.create_replication_subnet_group_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Creates a replication task using the specified parameters.</p>
+    async fn create_replication_task(
+        &self,
+        input: CreateReplicationTaskMessage,
+    ) -> Result<CreateReplicationTaskResponse, RusotoError<CreateReplicationTaskError>> {
+        self.create_replication_task_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes the specified certificate. </p>
+    async fn delete_certificate(
+        &self,
+        input: DeleteCertificateMessage,
+    ) -> Result<DeleteCertificateResponse, RusotoError<DeleteCertificateError>> {
+        self.delete_certificate_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes the connection between a replication instance and an endpoint.</p>
+    async fn delete_connection(
+        &self,
+        input: DeleteConnectionMessage,
+    ) -> Result<DeleteConnectionResponse, RusotoError<DeleteConnectionError>> {
+        self.delete_connection_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Deletes the specified endpoint.</p> <note> <p>All tasks associated with the endpoint must be deleted before you can delete the endpoint.</p> </note> <p/></p>
+    async fn delete_endpoint(
+        &self,
+        input: DeleteEndpointMessage,
+    ) -> Result<DeleteEndpointResponse, RusotoError<DeleteEndpointError>> {
+        self.delete_endpoint_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p> Deletes an AWS DMS event subscription. </p>
+    async fn delete_event_subscription(
+        &self,
+        input: DeleteEventSubscriptionMessage,
+    ) -> Result<DeleteEventSubscriptionResponse, RusotoError<DeleteEventSubscriptionError>> {
+        self.delete_event_subscription_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Deletes the specified replication instance.</p> <note> <p>You must delete any migration tasks that are associated with the replication instance before you can delete it.</p> </note> <p/></p>
+    async fn delete_replication_instance(
+        &self,
+        input: DeleteReplicationInstanceMessage,
+    ) -> Result<DeleteReplicationInstanceResponse, RusotoError<DeleteReplicationInstanceError>>
+    {
+        self.delete_replication_instance_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes a subnet group.</p>
+    async fn delete_replication_subnet_group(
+        &self,
+        input: DeleteReplicationSubnetGroupMessage,
+    ) -> Result<DeleteReplicationSubnetGroupResponse, RusotoError<DeleteReplicationSubnetGroupError>>
+    {
+        self.delete_replication_subnet_group_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes the specified replication task.</p>
+    async fn delete_replication_task(
+        &self,
+        input: DeleteReplicationTaskMessage,
+    ) -> Result<DeleteReplicationTaskResponse, RusotoError<DeleteReplicationTaskError>> {
+        self.delete_replication_task_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Lists all of the AWS DMS attributes for a customer account. These attributes include AWS DMS quotas for the account and a unique account identifier in a particular DMS region. DMS quotas include a list of resource quotas supported by the account, such as the number of replication instances allowed. The description for each resource quota, includes the quota name, current usage toward that quota, and the quota's maximum value. DMS uses the unique account identifier to name each artifact used by DMS in the given region.</p> <p>This command does not take any parameters.</p>
+    async fn describe_account_attributes(
+        &self,
+    ) -> Result<DescribeAccountAttributesResponse, RusotoError<DescribeAccountAttributesError>>
+    {
+        self.describe_account_attributes_with_metadata()
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Provides a description of the certificate.</p>
+    async fn describe_certificates(
+        &self,
+        input: DescribeCertificatesMessage,
+    ) -> Result<DescribeCertificatesResponse, RusotoError<DescribeCertificatesError>> {
+        self.describe_certificates_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Describes the status of the connections that have been made between the replication instance and an endpoint. Connections are created when you test an endpoint.</p>
+    async fn describe_connections(
+        &self,
+        input: DescribeConnectionsMessage,
+    ) -> Result<DescribeConnectionsResponse, RusotoError<DescribeConnectionsError>> {
+        self.describe_connections_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns information about the type of endpoints available.</p>
+    async fn describe_endpoint_types(
+        &self,
+        input: DescribeEndpointTypesMessage,
+    ) -> Result<DescribeEndpointTypesResponse, RusotoError<DescribeEndpointTypesError>> {
+        self.describe_endpoint_types_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns information about the endpoints for your account in the current region.</p>
+    async fn describe_endpoints(
+        &self,
+        input: DescribeEndpointsMessage,
+    ) -> Result<DescribeEndpointsResponse, RusotoError<DescribeEndpointsError>> {
+        self.describe_endpoints_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Lists categories for all event source types, or, if specified, for a specified source type. You can see a list of the event categories and source types in <a href="https://docs.aws.amazon.com/dms/latest/userguide/CHAP_Events.html">Working with Events and Notifications</a> in the <i>AWS Database Migration Service User Guide.</i> </p>
+    async fn describe_event_categories(
+        &self,
+        input: DescribeEventCategoriesMessage,
+    ) -> Result<DescribeEventCategoriesResponse, RusotoError<DescribeEventCategoriesError>> {
+        self.describe_event_categories_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Lists all the event subscriptions for a customer account. The description of a subscription includes <code>SubscriptionName</code>, <code>SNSTopicARN</code>, <code>CustomerID</code>, <code>SourceType</code>, <code>SourceID</code>, <code>CreationTime</code>, and <code>Status</code>. </p> <p>If you specify <code>SubscriptionName</code>, this action lists the description for that subscription.</p>
+    async fn describe_event_subscriptions(
+        &self,
+        input: DescribeEventSubscriptionsMessage,
+    ) -> Result<DescribeEventSubscriptionsResponse, RusotoError<DescribeEventSubscriptionsError>>
+    {
+        self.describe_event_subscriptions_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p> Lists events for a given source identifier and source type. You can also specify a start and end time. For more information on AWS DMS events, see <a href="https://docs.aws.amazon.com/dms/latest/userguide/CHAP_Events.html">Working with Events and Notifications</a> in the <i>AWS Database Migration User Guide.</i> </p>
+    async fn describe_events(
+        &self,
+        input: DescribeEventsMessage,
+    ) -> Result<DescribeEventsResponse, RusotoError<DescribeEventsError>> {
+        self.describe_events_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns information about the replication instance types that can be created in the specified region.</p>
+    async fn describe_orderable_replication_instances(
+        &self,
+        input: DescribeOrderableReplicationInstancesMessage,
+    ) -> Result<
+        DescribeOrderableReplicationInstancesResponse,
+        RusotoError<DescribeOrderableReplicationInstancesError>,
+    > {
+        self.describe_orderable_replication_instances_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>For internal use only</p>
+    async fn describe_pending_maintenance_actions(
+        &self,
+        input: DescribePendingMaintenanceActionsMessage,
+    ) -> Result<
+        DescribePendingMaintenanceActionsResponse,
+        RusotoError<DescribePendingMaintenanceActionsError>,
+    > {
+        self.describe_pending_maintenance_actions_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns the status of the RefreshSchemas operation.</p>
+    async fn describe_refresh_schemas_status(
+        &self,
+        input: DescribeRefreshSchemasStatusMessage,
+    ) -> Result<DescribeRefreshSchemasStatusResponse, RusotoError<DescribeRefreshSchemasStatusError>>
+    {
+        self.describe_refresh_schemas_status_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns information about the task logs for the specified task.</p>
+    async fn describe_replication_instance_task_logs(
+        &self,
+        input: DescribeReplicationInstanceTaskLogsMessage,
+    ) -> Result<
+        DescribeReplicationInstanceTaskLogsResponse,
+        RusotoError<DescribeReplicationInstanceTaskLogsError>,
+    > {
+        self.describe_replication_instance_task_logs_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns information about replication instances for your account in the current region.</p>
+    async fn describe_replication_instances(
+        &self,
+        input: DescribeReplicationInstancesMessage,
+    ) -> Result<DescribeReplicationInstancesResponse, RusotoError<DescribeReplicationInstancesError>>
+    {
+        self.describe_replication_instances_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns information about the replication subnet groups.</p>
+    async fn describe_replication_subnet_groups(
+        &self,
+        input: DescribeReplicationSubnetGroupsMessage,
+    ) -> Result<
+        DescribeReplicationSubnetGroupsResponse,
+        RusotoError<DescribeReplicationSubnetGroupsError>,
+    > {
+        self.describe_replication_subnet_groups_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns the task assessment results from Amazon S3. This action always returns the latest results.</p>
+    async fn describe_replication_task_assessment_results(
+        &self,
+        input: DescribeReplicationTaskAssessmentResultsMessage,
+    ) -> Result<
+        DescribeReplicationTaskAssessmentResultsResponse,
+        RusotoError<DescribeReplicationTaskAssessmentResultsError>,
+    > {
+        self.describe_replication_task_assessment_results_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns information about replication tasks for your account in the current region.</p>
+    async fn describe_replication_tasks(
+        &self,
+        input: DescribeReplicationTasksMessage,
+    ) -> Result<DescribeReplicationTasksResponse, RusotoError<DescribeReplicationTasksError>> {
+        self.describe_replication_tasks_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Returns information about the schema for the specified endpoint.</p> <p/></p>
+    async fn describe_schemas(
+        &self,
+        input: DescribeSchemasMessage,
+    ) -> Result<DescribeSchemasResponse, RusotoError<DescribeSchemasError>> {
+        self.describe_schemas_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns table statistics on the database migration task, including table name, rows inserted, rows updated, and rows deleted.</p> <p>Note that the "last updated" column the DMS console only indicates the time that AWS DMS last updated the table statistics record for a table. It does not indicate the time of the last update to the table.</p>
+    async fn describe_table_statistics(
+        &self,
+        input: DescribeTableStatisticsMessage,
+    ) -> Result<DescribeTableStatisticsResponse, RusotoError<DescribeTableStatisticsError>> {
+        self.describe_table_statistics_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Uploads the specified certificate.</p>
+    async fn import_certificate(
+        &self,
+        input: ImportCertificateMessage,
+    ) -> Result<ImportCertificateResponse, RusotoError<ImportCertificateError>> {
+        self.import_certificate_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Lists all tags for an AWS DMS resource.</p>
+    async fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceMessage,
+    ) -> Result<ListTagsForResourceResponse, RusotoError<ListTagsForResourceError>> {
+        self.list_tags_for_resource_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Modifies the specified endpoint.</p>
+    async fn modify_endpoint(
+        &self,
+        input: ModifyEndpointMessage,
+    ) -> Result<ModifyEndpointResponse, RusotoError<ModifyEndpointError>> {
+        self.modify_endpoint_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Modifies an existing AWS DMS event notification subscription. </p>
+    async fn modify_event_subscription(
+        &self,
+        input: ModifyEventSubscriptionMessage,
+    ) -> Result<ModifyEventSubscriptionResponse, RusotoError<ModifyEventSubscriptionError>> {
+        self.modify_event_subscription_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Modifies the replication instance to apply new settings. You can change one or more parameters by specifying these parameters and the new values in the request.</p> <p>Some settings are applied during the maintenance window.</p> <p/></p>
+    async fn modify_replication_instance(
+        &self,
+        input: ModifyReplicationInstanceMessage,
+    ) -> Result<ModifyReplicationInstanceResponse, RusotoError<ModifyReplicationInstanceError>>
+    {
+        self.modify_replication_instance_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Modifies the settings for the specified replication subnet group.</p>
+    async fn modify_replication_subnet_group(
+        &self,
+        input: ModifyReplicationSubnetGroupMessage,
+    ) -> Result<ModifyReplicationSubnetGroupResponse, RusotoError<ModifyReplicationSubnetGroupError>>
+    {
+        self.modify_replication_subnet_group_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Modifies the specified replication task.</p> <p>You can't modify the task endpoints. The task must be stopped before you can modify it. </p> <p>For more information about AWS DMS tasks, see <a href="https://docs.aws.amazon.com/dms/latest/userguide/CHAP_Tasks.html">Working with Migration Tasks</a> in the <i>AWS Database Migration Service User Guide</i>.</p>
+    async fn modify_replication_task(
+        &self,
+        input: ModifyReplicationTaskMessage,
+    ) -> Result<ModifyReplicationTaskResponse, RusotoError<ModifyReplicationTaskError>> {
+        self.modify_replication_task_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Reboots a replication instance. Rebooting results in a momentary outage, until the replication instance becomes available again.</p>
+    async fn reboot_replication_instance(
+        &self,
+        input: RebootReplicationInstanceMessage,
+    ) -> Result<RebootReplicationInstanceResponse, RusotoError<RebootReplicationInstanceError>>
+    {
+        self.reboot_replication_instance_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Populates the schema for the specified endpoint. This is an asynchronous operation and can take several minutes. You can check the status of this operation by calling the DescribeRefreshSchemasStatus operation.</p>
+    async fn refresh_schemas(
+        &self,
+        input: RefreshSchemasMessage,
+    ) -> Result<RefreshSchemasResponse, RusotoError<RefreshSchemasError>> {
+        self.refresh_schemas_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Reloads the target database table with the source data. </p>
+    async fn reload_tables(
+        &self,
+        input: ReloadTablesMessage,
+    ) -> Result<ReloadTablesResponse, RusotoError<ReloadTablesError>> {
+        self.reload_tables_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Removes metadata tags from a DMS resource.</p>
+    async fn remove_tags_from_resource(
+        &self,
+        input: RemoveTagsFromResourceMessage,
+    ) -> Result<RemoveTagsFromResourceResponse, RusotoError<RemoveTagsFromResourceError>> {
+        self.remove_tags_from_resource_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Starts the replication task.</p> <p>For more information about AWS DMS tasks, see <a href="https://docs.aws.amazon.com/dms/latest/userguide/CHAP_Tasks.html">Working with Migration Tasks </a> in the <i>AWS Database Migration Service User Guide.</i> </p>
+    async fn start_replication_task(
+        &self,
+        input: StartReplicationTaskMessage,
+    ) -> Result<StartReplicationTaskResponse, RusotoError<StartReplicationTaskError>> {
+        self.start_replication_task_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p> Starts the replication task assessment for unsupported data types in the source database. </p>
+    async fn start_replication_task_assessment(
+        &self,
+        input: StartReplicationTaskAssessmentMessage,
+    ) -> Result<
+        StartReplicationTaskAssessmentResponse,
+        RusotoError<StartReplicationTaskAssessmentError>,
+    > {
+        self.start_replication_task_assessment_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Stops the replication task.</p> <p/></p>
+    async fn stop_replication_task(
+        &self,
+        input: StopReplicationTaskMessage,
+    ) -> Result<StopReplicationTaskResponse, RusotoError<StopReplicationTaskError>> {
+        self.stop_replication_task_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Tests the connection between the replication instance and the endpoint.</p>
+    async fn test_connection(
+        &self,
+        input: TestConnectionMessage,
+    ) -> Result<TestConnectionResponse, RusotoError<TestConnectionError>> {
+        self.test_connection_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
     }
 }
@@ -16,8 +16,8 @@ use std::fmt;
 use async_trait::async_trait;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
-use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest, ResponseMetadata};
+use rusoto_core::{Client, ErrorMetadata, RusotoError};
 
 use rusoto_core::param::{Params, ServiceParams};
 use rusoto_core::proto::xml::error::*;
@@ -5727,6 +5727,7 @@ impl VpcSecurityGroupMembershipListDeserializer {
 }
 /// Errors returned by AddTagsToResource
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum AddTagsToResourceError {
     /// <p> <code>DBClusterIdentifier</code> doesn't refer to an existing DB cluster. </p>
     DBClusterNotFoundFault(String),
@@ -5734,6 +5735,8 @@ pub enum AddTagsToResourceError {
     DBInstanceNotFoundFault(String),
     /// <p> <code>DBSnapshotIdentifier</code> doesn't refer to an existing DB snapshot. </p>
     DBSnapshotNotFoundFault(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl AddTagsToResourceError {
@@ -5745,21 +5748,24 @@ impl AddTagsToResourceError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "DBClusterNotFoundFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             AddTagsToResourceError::DBClusterNotFoundFault(parsed_error.message),
+                            &res,
                         )
                     }
                     "DBInstanceNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             AddTagsToResourceError::DBInstanceNotFoundFault(parsed_error.message),
+                            &res,
                         )
                     }
                     "DBSnapshotNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             AddTagsToResourceError::DBSnapshotNotFoundFault(parsed_error.message),
+                            &res,
                         )
                     }
-                    _ => {}
+                    _ => return RusotoError::unhandled(AddTagsToResourceError::Unhandled, &res),
                 }
             }
         }
@@ -5781,12 +5787,14 @@ impl fmt::Display for AddTagsToResourceError {
             AddTagsToResourceError::DBClusterNotFoundFault(ref cause) => write!(f, "{}", cause),
             AddTagsToResourceError::DBInstanceNotFoundFault(ref cause) => write!(f, "{}", cause),
             AddTagsToResourceError::DBSnapshotNotFoundFault(ref cause) => write!(f, "{}", cause),
+            AddTagsToResourceError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for AddTagsToResourceError {}
 /// Errors returned by ApplyPendingMaintenanceAction
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ApplyPendingMaintenanceActionError {
     /// <p>The DB cluster isn't in a valid state.</p>
     InvalidDBClusterStateFault(String),
@@ -5794,6 +5802,8 @@ pub enum ApplyPendingMaintenanceActionError {
     InvalidDBInstanceStateFault(String),
     /// <p>The specified resource ID was not found.</p>
     ResourceNotFoundFault(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ApplyPendingMaintenanceActionError {
@@ -5807,27 +5817,35 @@ impl ApplyPendingMaintenanceActionError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InvalidDBClusterStateFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             ApplyPendingMaintenanceActionError::InvalidDBClusterStateFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "InvalidDBInstanceState" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             ApplyPendingMaintenanceActionError::InvalidDBInstanceStateFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "ResourceNotFoundFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             ApplyPendingMaintenanceActionError::ResourceNotFoundFault(
                                 parsed_error.message,
                             ),
+                            &res,
+                        )
+                    }
+                    _ => {
+                        return RusotoError::unhandled(
+                            ApplyPendingMaintenanceActionError::Unhandled,
+                            &res,
                         )
                     }
-                    _ => {}
                 }
             }
         }
@@ -5855,12 +5873,16 @@ impl fmt::Display for ApplyPendingMaintenanceActionError {
             ApplyPendingMaintenanceActionError::ResourceNotFoundFault(ref cause) => {
                 write!(f, "{}", cause)
             }
+            ApplyPendingMaintenanceActionError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for ApplyPendingMaintenanceActionError {}
 /// Errors returned by CopyDBClusterParameterGroup
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CopyDBClusterParameterGroupError {
     /// <p>A DB parameter group with the same name already exists.</p>
     DBParameterGroupAlreadyExistsFault(String),
@@ -5868,6 +5890,8 @@ pub enum CopyDBClusterParameterGroupError {
     DBParameterGroupNotFoundFault(String),
     /// <p>This request would cause you to exceed the allowed number of DB parameter groups.</p>
     DBParameterGroupQuotaExceededFault(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CopyDBClusterParameterGroupError {
@@ -5881,27 +5905,35 @@ impl CopyDBClusterParameterGroupError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "DBParameterGroupAlreadyExists" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             CopyDBClusterParameterGroupError::DBParameterGroupAlreadyExistsFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "DBParameterGroupNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             CopyDBClusterParameterGroupError::DBParameterGroupNotFoundFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "DBParameterGroupQuotaExceeded" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             CopyDBClusterParameterGroupError::DBParameterGroupQuotaExceededFault(
                                 parsed_error.message,
                             ),
+                            &res,
+                        )
+                    }
+                    _ => {
+                        return RusotoError::unhandled(
+                            CopyDBClusterParameterGroupError::Unhandled,
+                            &res,
                         )
                     }
-                    _ => {}
                 }
             }
         }
@@ -5929,12 +5961,14 @@ impl fmt::Display for CopyDBClusterParameterGroupError {
             CopyDBClusterParameterGroupError::DBParameterGroupQuotaExceededFault(ref cause) => {
                 write!(f, "{}", cause)
             }
+            CopyDBClusterParameterGroupError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CopyDBClusterParameterGroupError {}
 /// Errors returned by CopyDBClusterSnapshot
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CopyDBClusterSnapshotError {
     /// <p>You already have a DB cluster snapshot with the given identifier.</p>
     DBClusterSnapshotAlreadyExistsFault(String),
@@ -5948,6 +5982,8 @@ pub enum CopyDBClusterSnapshotError {
     KMSKeyNotAccessibleFault(String),
     /// <p>The request would cause you to exceed the allowed number of DB snapshots.</p>
     SnapshotQuotaExceededFault(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CopyDBClusterSnapshotError {
@@ -5959,48 +5995,56 @@ impl CopyDBClusterSnapshotError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "DBClusterSnapshotAlreadyExistsFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             CopyDBClusterSnapshotError::DBClusterSnapshotAlreadyExistsFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "DBClusterSnapshotNotFoundFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             CopyDBClusterSnapshotError::DBClusterSnapshotNotFoundFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "InvalidDBClusterSnapshotStateFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             CopyDBClusterSnapshotError::InvalidDBClusterSnapshotStateFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "InvalidDBClusterStateFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             CopyDBClusterSnapshotError::InvalidDBClusterStateFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "KMSKeyNotAccessibleFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             CopyDBClusterSnapshotError::KMSKeyNotAccessibleFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "SnapshotQuotaExceeded" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             CopyDBClusterSnapshotError::SnapshotQuotaExceededFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
-                    _ => {}
+                    _ => {
+                        return RusotoError::unhandled(CopyDBClusterSnapshotError::Unhandled, &res)
+                    }
                 }
             }
         }
@@ -6037,12 +6081,14 @@ impl fmt::Display for CopyDBClusterSnapshotError {
             CopyDBClusterSnapshotError::SnapshotQuotaExceededFault(ref cause) => {
                 write!(f, "{}", cause)
             }
+            CopyDBClusterSnapshotError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CopyDBClusterSnapshotError {}
 /// Errors returned by CreateDBCluster
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateDBClusterError {
     /// <p>You already have a DB cluster with the given identifier.</p>
     DBClusterAlreadyExistsFault(String),
@@ -6074,6 +6120,8 @@ pub enum CreateDBClusterError {
     KMSKeyNotAccessibleFault(String),
     /// <p>The request would cause you to exceed the allowed amount of storage available across all DB instances.</p>
     StorageQuotaExceededFault(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateDBClusterError {
@@ -6085,89 +6133,104 @@ impl CreateDBClusterError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "DBClusterAlreadyExistsFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             CreateDBClusterError::DBClusterAlreadyExistsFault(parsed_error.message),
+                            &res,
                         )
                     }
                     "DBClusterNotFoundFault" => {
-                        return RusotoError::Service(CreateDBClusterError::DBClusterNotFoundFault(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            CreateDBClusterError::DBClusterNotFoundFault(parsed_error.message),
+                            &res,
+                        )
                     }
                     "DBClusterParameterGroupNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             CreateDBClusterError::DBClusterParameterGroupNotFoundFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "DBClusterQuotaExceededFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             CreateDBClusterError::DBClusterQuotaExceededFault(parsed_error.message),
+                            &res,
                         )
                     }
                     "DBInstanceNotFound" => {
-                        return RusotoError::Service(CreateDBClusterError::DBInstanceNotFoundFault(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            CreateDBClusterError::DBInstanceNotFoundFault(parsed_error.message),
+                            &res,
+                        )
                     }
                     "DBSubnetGroupDoesNotCoverEnoughAZs" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             CreateDBClusterError::DBSubnetGroupDoesNotCoverEnoughAZs(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "DBSubnetGroupNotFoundFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             CreateDBClusterError::DBSubnetGroupNotFoundFault(parsed_error.message),
+                            &res,
                         )
                     }
                     "InsufficientStorageClusterCapacity" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             CreateDBClusterError::InsufficientStorageClusterCapacityFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "InvalidDBClusterStateFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             CreateDBClusterError::InvalidDBClusterStateFault(parsed_error.message),
+                            &res,
                         )
                     }
                     "InvalidDBInstanceState" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             CreateDBClusterError::InvalidDBInstanceStateFault(parsed_error.message),
+                            &res,
                         )
                     }
                     "InvalidDBSubnetGroupStateFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             CreateDBClusterError::InvalidDBSubnetGroupStateFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "InvalidSubnet" => {
-                        return RusotoError::Service(CreateDBClusterError::InvalidSubnet(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            CreateDBClusterError::InvalidSubnet(parsed_error.message),
+                            &res,
+                        )
                     }
                     "InvalidVPCNetworkStateFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             CreateDBClusterError::InvalidVPCNetworkStateFault(parsed_error.message),
+                            &res,
                         )
                     }
                     "KMSKeyNotAccessibleFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             CreateDBClusterError::KMSKeyNotAccessibleFault(parsed_error.message),
+                            &res,
                         )
                     }
                     "StorageQuotaExceeded" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             CreateDBClusterError::StorageQuotaExceededFault(parsed_error.message),
+                            &res,
                         )
                     }
-                    _ => {}
+                    _ => return RusotoError::unhandled(CreateDBClusterError::Unhandled, &res),
                 }
             }
         }
@@ -6209,17 +6272,21 @@ impl fmt::Display for CreateDBClusterError {
             CreateDBClusterError::InvalidVPCNetworkStateFault(ref cause) => write!(f, "{}", cause),
             CreateDBClusterError::KMSKeyNotAccessibleFault(ref cause) => write!(f, "{}", cause),
             CreateDBClusterError::StorageQuotaExceededFault(ref cause) => write!(f, "{}", cause),
+            CreateDBClusterError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateDBClusterError {}
 /// Errors returned by CreateDBClusterParameterGroup
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateDBClusterParameterGroupError {
     /// <p>A DB parameter group with the same name already exists.</p>
     DBParameterGroupAlreadyExistsFault(String),
     /// <p>This request would cause you to exceed the allowed number of DB parameter groups.</p>
     DBParameterGroupQuotaExceededFault(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateDBClusterParameterGroupError {
@@ -6233,20 +6300,27 @@ impl CreateDBClusterParameterGroupError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "DBParameterGroupAlreadyExists" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             CreateDBClusterParameterGroupError::DBParameterGroupAlreadyExistsFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "DBParameterGroupQuotaExceeded" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             CreateDBClusterParameterGroupError::DBParameterGroupQuotaExceededFault(
                                 parsed_error.message,
                             ),
+                            &res,
+                        )
+                    }
+                    _ => {
+                        return RusotoError::unhandled(
+                            CreateDBClusterParameterGroupError::Unhandled,
+                            &res,
                         )
                     }
-                    _ => {}
                 }
             }
         }
@@ -6271,12 +6345,16 @@ impl fmt::Display for CreateDBClusterParameterGroupError {
             CreateDBClusterParameterGroupError::DBParameterGroupQuotaExceededFault(ref cause) => {
                 write!(f, "{}", cause)
             }
+            CreateDBClusterParameterGroupError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for CreateDBClusterParameterGroupError {}
 /// Errors returned by CreateDBClusterSnapshot
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateDBClusterSnapshotError {
     /// <p> <code>DBClusterIdentifier</code> doesn't refer to an existing DB cluster. </p>
     DBClusterNotFoundFault(String),
@@ -6288,6 +6366,8 @@ pub enum CreateDBClusterSnapshotError {
     InvalidDBClusterStateFault(String),
     /// <p>The request would cause you to exceed the allowed number of DB snapshots.</p>
     SnapshotQuotaExceededFault(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateDBClusterSnapshotError {
@@ -6299,41 +6379,51 @@ impl CreateDBClusterSnapshotError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "DBClusterNotFoundFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             CreateDBClusterSnapshotError::DBClusterNotFoundFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "DBClusterSnapshotAlreadyExistsFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             CreateDBClusterSnapshotError::DBClusterSnapshotAlreadyExistsFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "InvalidDBClusterSnapshotStateFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             CreateDBClusterSnapshotError::InvalidDBClusterSnapshotStateFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "InvalidDBClusterStateFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             CreateDBClusterSnapshotError::InvalidDBClusterStateFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "SnapshotQuotaExceeded" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             CreateDBClusterSnapshotError::SnapshotQuotaExceededFault(
                                 parsed_error.message,
                             ),
+                            &res,
+                        )
+                    }
+                    _ => {
+                        return RusotoError::unhandled(
+                            CreateDBClusterSnapshotError::Unhandled,
+                            &res,
                         )
                     }
-                    _ => {}
                 }
             }
         }
@@ -6367,12 +6457,14 @@ impl fmt::Display for CreateDBClusterSnapshotError {
             CreateDBClusterSnapshotError::SnapshotQuotaExceededFault(ref cause) => {
                 write!(f, "{}", cause)
             }
+            CreateDBClusterSnapshotError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateDBClusterSnapshotError {}
 /// Errors returned by CreateDBInstance
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateDBInstanceError {
     /// <p>The specified CIDR IP or Amazon EC2 security group isn't authorized for the specified DB security group.</p> <p>Amazon DocumentDB also might not be authorized to perform necessary actions on your behalf using IAM.</p>
     AuthorizationNotFoundFault(String),
@@ -6404,6 +6496,8 @@ pub enum CreateDBInstanceError {
     StorageQuotaExceededFault(String),
     /// <p>Storage of the specified <code>StorageType</code> can't be associated with the DB instance. </p>
     StorageTypeNotSupportedFault(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateDBInstanceError {
@@ -6415,95 +6509,110 @@ impl CreateDBInstanceError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "AuthorizationNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             CreateDBInstanceError::AuthorizationNotFoundFault(parsed_error.message),
+                            &res,
                         )
                     }
                     "DBClusterNotFoundFault" => {
-                        return RusotoError::Service(CreateDBInstanceError::DBClusterNotFoundFault(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            CreateDBInstanceError::DBClusterNotFoundFault(parsed_error.message),
+                            &res,
+                        )
                     }
                     "DBInstanceAlreadyExists" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             CreateDBInstanceError::DBInstanceAlreadyExistsFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "DBParameterGroupNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             CreateDBInstanceError::DBParameterGroupNotFoundFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "DBSecurityGroupNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             CreateDBInstanceError::DBSecurityGroupNotFoundFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "DBSubnetGroupDoesNotCoverEnoughAZs" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             CreateDBInstanceError::DBSubnetGroupDoesNotCoverEnoughAZs(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "DBSubnetGroupNotFoundFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             CreateDBInstanceError::DBSubnetGroupNotFoundFault(parsed_error.message),
+                            &res,
                         )
                     }
                     "InstanceQuotaExceeded" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             CreateDBInstanceError::InstanceQuotaExceededFault(parsed_error.message),
+                            &res,
                         )
                     }
                     "InsufficientDBInstanceCapacity" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             CreateDBInstanceError::InsufficientDBInstanceCapacityFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "InvalidDBClusterStateFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             CreateDBInstanceError::InvalidDBClusterStateFault(parsed_error.message),
+                            &res,
                         )
                     }
                     "InvalidSubnet" => {
-                        return RusotoError::Service(CreateDBInstanceError::InvalidSubnet(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            CreateDBInstanceError::InvalidSubnet(parsed_error.message),
+                            &res,
+                        )
                     }
                     "InvalidVPCNetworkStateFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             CreateDBInstanceError::InvalidVPCNetworkStateFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "KMSKeyNotAccessibleFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             CreateDBInstanceError::KMSKeyNotAccessibleFault(parsed_error.message),
+                            &res,
                         )
                     }
                     "StorageQuotaExceeded" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             CreateDBInstanceError::StorageQuotaExceededFault(parsed_error.message),
+                            &res,
                         )
                     }
                     "StorageTypeNotSupported" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             CreateDBInstanceError::StorageTypeNotSupportedFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
-                    _ => {}
+                    _ => return RusotoError::unhandled(CreateDBInstanceError::Unhandled, &res),
                 }
             }
         }
@@ -6549,12 +6658,14 @@ impl fmt::Display for CreateDBInstanceError {
             CreateDBInstanceError::StorageTypeNotSupportedFault(ref cause) => {
                 write!(f, "{}", cause)
             }
+            CreateDBInstanceError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateDBInstanceError {}
 /// Errors returned by CreateDBSubnetGroup
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateDBSubnetGroupError {
     /// <p> <code>DBSubnetGroupName</code> is already being used by an existing DB subnet group. </p>
     DBSubnetGroupAlreadyExistsFault(String),
@@ -6566,6 +6677,8 @@ pub enum CreateDBSubnetGroupError {
     DBSubnetQuotaExceededFault(String),
     /// <p>The requested subnet is not valid, or multiple subnets were requested that are not all in a common virtual private cloud (VPC).</p>
     InvalidSubnet(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateDBSubnetGroupError {
@@ -6577,39 +6690,44 @@ impl CreateDBSubnetGroupError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "DBSubnetGroupAlreadyExists" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             CreateDBSubnetGroupError::DBSubnetGroupAlreadyExistsFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "DBSubnetGroupDoesNotCoverEnoughAZs" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             CreateDBSubnetGroupError::DBSubnetGroupDoesNotCoverEnoughAZs(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "DBSubnetGroupQuotaExceeded" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             CreateDBSubnetGroupError::DBSubnetGroupQuotaExceededFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "DBSubnetQuotaExceededFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             CreateDBSubnetGroupError::DBSubnetQuotaExceededFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "InvalidSubnet" => {
-                        return RusotoError::Service(CreateDBSubnetGroupError::InvalidSubnet(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            CreateDBSubnetGroupError::InvalidSubnet(parsed_error.message),
+                            &res,
+                        )
                     }
-                    _ => {}
+                    _ => return RusotoError::unhandled(CreateDBSubnetGroupError::Unhandled, &res),
                 }
             }
         }
@@ -6641,12 +6759,14 @@ impl fmt::Display for CreateDBSubnetGroupError {
                 write!(f, "{}", cause)
             }
             CreateDBSubnetGroupError::InvalidSubnet(ref cause) => write!(f, "{}", cause),
+            CreateDBSubnetGroupError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateDBSubnetGroupError {}
 /// Errors returned by DeleteDBCluster
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteDBClusterError {
     /// <p> <code>DBClusterIdentifier</code> doesn't refer to an existing DB cluster. </p>
     DBClusterNotFoundFault(String),
@@ -6658,6 +6778,8 @@ pub enum DeleteDBClusterError {
     InvalidDBClusterStateFault(String),
     /// <p>The request would cause you to exceed the allowed number of DB snapshots.</p>
     SnapshotQuotaExceededFault(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteDBClusterError {
@@ -6669,35 +6791,40 @@ impl DeleteDBClusterError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "DBClusterNotFoundFault" => {
-                        return RusotoError::Service(DeleteDBClusterError::DBClusterNotFoundFault(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            DeleteDBClusterError::DBClusterNotFoundFault(parsed_error.message),
+                            &res,
+                        )
                     }
                     "DBClusterSnapshotAlreadyExistsFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             DeleteDBClusterError::DBClusterSnapshotAlreadyExistsFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "InvalidDBClusterSnapshotStateFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             DeleteDBClusterError::InvalidDBClusterSnapshotStateFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "InvalidDBClusterStateFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             DeleteDBClusterError::InvalidDBClusterStateFault(parsed_error.message),
+                            &res,
                         )
                     }
                     "SnapshotQuotaExceeded" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             DeleteDBClusterError::SnapshotQuotaExceededFault(parsed_error.message),
+                            &res,
                         )
                     }
-                    _ => {}
+                    _ => return RusotoError::unhandled(DeleteDBClusterError::Unhandled, &res),
                 }
             }
         }
@@ -6725,17 +6852,21 @@ impl fmt::Display for DeleteDBClusterError {
             }
             DeleteDBClusterError::InvalidDBClusterStateFault(ref cause) => write!(f, "{}", cause),
             DeleteDBClusterError::SnapshotQuotaExceededFault(ref cause) => write!(f, "{}", cause),
+            DeleteDBClusterError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteDBClusterError {}
 /// Errors returned by DeleteDBClusterParameterGroup
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteDBClusterParameterGroupError {
     /// <p> <code>DBParameterGroupName</code> doesn't refer to an existing DB parameter group. </p>
     DBParameterGroupNotFoundFault(String),
     /// <p>The DB parameter group is in use, or it is in a state that is not valid. If you are trying to delete the parameter group, you can't delete it when the parameter group is in this state.</p>
     InvalidDBParameterGroupStateFault(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteDBClusterParameterGroupError {
@@ -6749,20 +6880,27 @@ impl DeleteDBClusterParameterGroupError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "DBParameterGroupNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             DeleteDBClusterParameterGroupError::DBParameterGroupNotFoundFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "InvalidDBParameterGroupState" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             DeleteDBClusterParameterGroupError::InvalidDBParameterGroupStateFault(
                                 parsed_error.message,
                             ),
+                            &res,
+                        )
+                    }
+                    _ => {
+                        return RusotoError::unhandled(
+                            DeleteDBClusterParameterGroupError::Unhandled,
+                            &res,
                         )
                     }
-                    _ => {}
                 }
             }
         }
@@ -6787,17 +6925,23 @@ impl fmt::Display for DeleteDBClusterParameterGroupError {
             DeleteDBClusterParameterGroupError::InvalidDBParameterGroupStateFault(ref cause) => {
                 write!(f, "{}", cause)
             }
+            DeleteDBClusterParameterGroupError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for DeleteDBClusterParameterGroupError {}
 /// Errors returned by DeleteDBClusterSnapshot
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteDBClusterSnapshotError {
     /// <p> <code>DBClusterSnapshotIdentifier</code> doesn't refer to an existing DB cluster snapshot. </p>
     DBClusterSnapshotNotFoundFault(String),
     /// <p>The provided value isn't a valid DB cluster snapshot state.</p>
     InvalidDBClusterSnapshotStateFault(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteDBClusterSnapshotError {
@@ -6809,20 +6953,27 @@ impl DeleteDBClusterSnapshotError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "DBClusterSnapshotNotFoundFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             DeleteDBClusterSnapshotError::DBClusterSnapshotNotFoundFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "InvalidDBClusterSnapshotStateFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             DeleteDBClusterSnapshotError::InvalidDBClusterSnapshotStateFault(
                                 parsed_error.message,
                             ),
+                            &res,
+                        )
+                    }
+                    _ => {
+                        return RusotoError::unhandled(
+                            DeleteDBClusterSnapshotError::Unhandled,
+                            &res,
                         )
                     }
-                    _ => {}
                 }
             }
         }
@@ -6847,12 +6998,14 @@ impl fmt::Display for DeleteDBClusterSnapshotError {
             DeleteDBClusterSnapshotError::InvalidDBClusterSnapshotStateFault(ref cause) => {
                 write!(f, "{}", cause)
             }
+            DeleteDBClusterSnapshotError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteDBClusterSnapshotError {}
 /// Errors returned by DeleteDBInstance
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteDBInstanceError {
     /// <p> <code>DBInstanceIdentifier</code> doesn't refer to an existing DB instance. </p>
     DBInstanceNotFoundFault(String),
@@ -6864,6 +7017,8 @@ pub enum DeleteDBInstanceError {
     InvalidDBInstanceStateFault(String),
     /// <p>The request would cause you to exceed the allowed number of DB snapshots.</p>
     SnapshotQuotaExceededFault(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteDBInstanceError {
@@ -6875,35 +7030,40 @@ impl DeleteDBInstanceError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "DBInstanceNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             DeleteDBInstanceError::DBInstanceNotFoundFault(parsed_error.message),
+                            &res,
                         )
                     }
                     "DBSnapshotAlreadyExists" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             DeleteDBInstanceError::DBSnapshotAlreadyExistsFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "InvalidDBClusterStateFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             DeleteDBInstanceError::InvalidDBClusterStateFault(parsed_error.message),
+                            &res,
                         )
                     }
                     "InvalidDBInstanceState" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             DeleteDBInstanceError::InvalidDBInstanceStateFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "SnapshotQuotaExceeded" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             DeleteDBInstanceError::SnapshotQuotaExceededFault(parsed_error.message),
+                            &res,
                         )
                     }
-                    _ => {}
+                    _ => return RusotoError::unhandled(DeleteDBInstanceError::Unhandled, &res),
                 }
             }
         }
@@ -6929,12 +7089,14 @@ impl fmt::Display for DeleteDBInstanceError {
             DeleteDBInstanceError::InvalidDBClusterStateFault(ref cause) => write!(f, "{}", cause),
             DeleteDBInstanceError::InvalidDBInstanceStateFault(ref cause) => write!(f, "{}", cause),
             DeleteDBInstanceError::SnapshotQuotaExceededFault(ref cause) => write!(f, "{}", cause),
+            DeleteDBInstanceError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteDBInstanceError {}
 /// Errors returned by DeleteDBSubnetGroup
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteDBSubnetGroupError {
     /// <p> <code>DBSubnetGroupName</code> doesn't refer to an existing DB subnet group. </p>
     DBSubnetGroupNotFoundFault(String),
@@ -6942,6 +7104,8 @@ pub enum DeleteDBSubnetGroupError {
     InvalidDBSubnetGroupStateFault(String),
     /// <p> The DB subnet isn't in the <i>available</i> state. </p>
     InvalidDBSubnetStateFault(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteDBSubnetGroupError {
@@ -6953,27 +7117,30 @@ impl DeleteDBSubnetGroupError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "DBSubnetGroupNotFoundFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             DeleteDBSubnetGroupError::DBSubnetGroupNotFoundFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "InvalidDBSubnetGroupStateFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             DeleteDBSubnetGroupError::InvalidDBSubnetGroupStateFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "InvalidDBSubnetStateFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             DeleteDBSubnetGroupError::InvalidDBSubnetStateFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
-                    _ => {}
+                    _ => return RusotoError::unhandled(DeleteDBSubnetGroupError::Unhandled, &res),
                 }
             }
         }
@@ -7001,15 +7168,19 @@ impl fmt::Display for DeleteDBSubnetGroupError {
             DeleteDBSubnetGroupError::InvalidDBSubnetStateFault(ref cause) => {
                 write!(f, "{}", cause)
             }
+            DeleteDBSubnetGroupError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteDBSubnetGroupError {}
 /// Errors returned by DescribeCertificates
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeCertificatesError {
     /// <p> <code>CertificateIdentifier</code> doesn't refer to an existing certificate. </p>
     CertificateNotFoundFault(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeCertificatesError {
@@ -7021,13 +7192,14 @@ impl DescribeCertificatesError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "CertificateNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             DescribeCertificatesError::CertificateNotFoundFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
-                    _ => {}
+                    _ => return RusotoError::unhandled(DescribeCertificatesError::Unhandled, &res),
                 }
             }
         }
@@ -7049,15 +7221,19 @@ impl fmt::Display for DescribeCertificatesError {
             DescribeCertificatesError::CertificateNotFoundFault(ref cause) => {
                 write!(f, "{}", cause)
             }
+            DescribeCertificatesError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeCertificatesError {}
 /// Errors returned by DescribeDBClusterParameterGroups
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeDBClusterParameterGroupsError {
     /// <p> <code>DBParameterGroupName</code> doesn't refer to an existing DB parameter group. </p>
     DBParameterGroupNotFoundFault(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeDBClusterParameterGroupsError {
@@ -7071,13 +7247,19 @@ impl DescribeDBClusterParameterGroupsError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "DBParameterGroupNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             DescribeDBClusterParameterGroupsError::DBParameterGroupNotFoundFault(
                                 parsed_error.message,
                             ),
+                            &res,
+                        )
+                    }
+                    _ => {
+                        return RusotoError::unhandled(
+                            DescribeDBClusterParameterGroupsError::Unhandled,
+                            &res,
                         )
                     }
-                    _ => {}
                 }
             }
         }
@@ -7099,15 +7281,21 @@ impl fmt::Display for DescribeDBClusterParameterGroupsError {
             DescribeDBClusterParameterGroupsError::DBParameterGroupNotFoundFault(ref cause) => {
                 write!(f, "{}", cause)
             }
+            DescribeDBClusterParameterGroupsError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for DescribeDBClusterParameterGroupsError {}
 /// Errors returned by DescribeDBClusterParameters
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeDBClusterParametersError {
     /// <p> <code>DBParameterGroupName</code> doesn't refer to an existing DB parameter group. </p>
     DBParameterGroupNotFoundFault(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeDBClusterParametersError {
@@ -7121,13 +7309,19 @@ impl DescribeDBClusterParametersError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "DBParameterGroupNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             DescribeDBClusterParametersError::DBParameterGroupNotFoundFault(
                                 parsed_error.message,
                             ),
+                            &res,
+                        )
+                    }
+                    _ => {
+                        return RusotoError::unhandled(
+                            DescribeDBClusterParametersError::Unhandled,
+                            &res,
                         )
                     }
-                    _ => {}
                 }
             }
         }
@@ -7149,15 +7343,19 @@ impl fmt::Display for DescribeDBClusterParametersError {
             DescribeDBClusterParametersError::DBParameterGroupNotFoundFault(ref cause) => {
                 write!(f, "{}", cause)
             }
+            DescribeDBClusterParametersError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeDBClusterParametersError {}
 /// Errors returned by DescribeDBClusterSnapshotAttributes
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeDBClusterSnapshotAttributesError {
     /// <p> <code>DBClusterSnapshotIdentifier</code> doesn't refer to an existing DB cluster snapshot. </p>
     DBClusterSnapshotNotFoundFault(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeDBClusterSnapshotAttributesError {
@@ -7170,12 +7368,18 @@ impl DescribeDBClusterSnapshotAttributesError {
             find_start_element(&mut stack);
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
-                    "DBClusterSnapshotNotFoundFault" => return RusotoError::Service(
+                    "DBClusterSnapshotNotFoundFault" => return RusotoError::service(
                         DescribeDBClusterSnapshotAttributesError::DBClusterSnapshotNotFoundFault(
                             parsed_error.message,
                         ),
+                        &res,
                     ),
-                    _ => {}
+                    _ => {
+                        return RusotoError::unhandled(
+                            DescribeDBClusterSnapshotAttributesError::Unhandled,
+                            &res,
+                        )
+                    }
                 }
             }
         }
@@ -7197,15 +7401,21 @@ impl fmt::Display for DescribeDBClusterSnapshotAttributesError {
             DescribeDBClusterSnapshotAttributesError::DBClusterSnapshotNotFoundFault(ref cause) => {
                 write!(f, "{}", cause)
             }
+            DescribeDBClusterSnapshotAttributesError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for DescribeDBClusterSnapshotAttributesError {}
 /// Errors returned by DescribeDBClusterSnapshots
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeDBClusterSnapshotsError {
     /// <p> <code>DBClusterSnapshotIdentifier</code> doesn't refer to an existing DB cluster snapshot. </p>
     DBClusterSnapshotNotFoundFault(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeDBClusterSnapshotsError {
@@ -7219,13 +7429,19 @@ impl DescribeDBClusterSnapshotsError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "DBClusterSnapshotNotFoundFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             DescribeDBClusterSnapshotsError::DBClusterSnapshotNotFoundFault(
                                 parsed_error.message,
                             ),
+                            &res,
+                        )
+                    }
+                    _ => {
+                        return RusotoError::unhandled(
+                            DescribeDBClusterSnapshotsError::Unhandled,
+                            &res,
                         )
                     }
-                    _ => {}
                 }
             }
         }
@@ -7247,15 +7463,19 @@ impl fmt::Display for DescribeDBClusterSnapshotsError {
             DescribeDBClusterSnapshotsError::DBClusterSnapshotNotFoundFault(ref cause) => {
                 write!(f, "{}", cause)
             }
+            DescribeDBClusterSnapshotsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeDBClusterSnapshotsError {}
 /// Errors returned by DescribeDBClusters
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeDBClustersError {
     /// <p> <code>DBClusterIdentifier</code> doesn't refer to an existing DB cluster. </p>
     DBClusterNotFoundFault(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeDBClustersError {
@@ -7267,11 +7487,12 @@ impl DescribeDBClustersError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "DBClusterNotFoundFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             DescribeDBClustersError::DBClusterNotFoundFault(parsed_error.message),
+                            &res,
                         )
                     }
-                    _ => {}
+                    _ => return RusotoError::unhandled(DescribeDBClustersError::Unhandled, &res),
                 }
             }
         }
@@ -7291,13 +7512,18 @@ impl fmt::Display for DescribeDBClustersError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             DescribeDBClustersError::DBClusterNotFoundFault(ref cause) => write!(f, "{}", cause),
+            DescribeDBClustersError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeDBClustersError {}
 /// Errors returned by DescribeDBEngineVersions
 #[derive(Debug, PartialEq)]
-pub enum DescribeDBEngineVersionsError {}
+#[non_exhaustive]
+pub enum DescribeDBEngineVersionsError {
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
+}
 
 impl DescribeDBEngineVersionsError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<DescribeDBEngineVersionsError> {
@@ -7305,10 +7531,8 @@ impl DescribeDBEngineVersionsError {
             let reader = EventReader::new(res.body.as_ref());
             let mut stack = XmlResponse::new(reader.into_iter().peekable());
             find_start_element(&mut stack);
-            if let Ok(parsed_error) = Self::deserialize(&mut stack) {
-                match &parsed_error.code[..] {
-                    _ => {}
-                }
+            if Self::deserialize(&mut stack).is_ok() {
+                return RusotoError::unhandled(DescribeDBEngineVersionsError::Unhandled, &res);
             }
         }
         RusotoError::Unknown(res)
@@ -7325,15 +7549,20 @@ impl DescribeDBEngineVersionsError {
 impl fmt::Display for DescribeDBEngineVersionsError {
     #[allow(unused_variables)]
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        match *self {}
+        match *self {
+            DescribeDBEngineVersionsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
+        }
     }
 }
 impl Error for DescribeDBEngineVersionsError {}
 /// Errors returned by DescribeDBInstances
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeDBInstancesError {
     /// <p> <code>DBInstanceIdentifier</code> doesn't refer to an existing DB instance. </p>
     DBInstanceNotFoundFault(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeDBInstancesError {
@@ -7345,11 +7574,12 @@ impl DescribeDBInstancesError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "DBInstanceNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             DescribeDBInstancesError::DBInstanceNotFoundFault(parsed_error.message),
+                            &res,
                         )
                     }
-                    _ => {}
+                    _ => return RusotoError::unhandled(DescribeDBInstancesError::Unhandled, &res),
                 }
             }
         }
@@ -7369,15 +7599,19 @@ impl fmt::Display for DescribeDBInstancesError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             DescribeDBInstancesError::DBInstanceNotFoundFault(ref cause) => write!(f, "{}", cause),
+            DescribeDBInstancesError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeDBInstancesError {}
 /// Errors returned by DescribeDBSubnetGroups
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeDBSubnetGroupsError {
     /// <p> <code>DBSubnetGroupName</code> doesn't refer to an existing DB subnet group. </p>
     DBSubnetGroupNotFoundFault(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeDBSubnetGroupsError {
@@ -7389,13 +7623,16 @@ impl DescribeDBSubnetGroupsError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "DBSubnetGroupNotFoundFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             DescribeDBSubnetGroupsError::DBSubnetGroupNotFoundFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
-                    _ => {}
+                    _ => {
+                        return RusotoError::unhandled(DescribeDBSubnetGroupsError::Unhandled, &res)
+                    }
                 }
             }
         }
@@ -7417,13 +7654,18 @@ impl fmt::Display for DescribeDBSubnetGroupsError {
             DescribeDBSubnetGroupsError::DBSubnetGroupNotFoundFault(ref cause) => {
                 write!(f, "{}", cause)
             }
+            DescribeDBSubnetGroupsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeDBSubnetGroupsError {}
 /// Errors returned by DescribeEngineDefaultClusterParameters
 #[derive(Debug, PartialEq)]
-pub enum DescribeEngineDefaultClusterParametersError {}
+#[non_exhaustive]
+pub enum DescribeEngineDefaultClusterParametersError {
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
+}
 
 impl DescribeEngineDefaultClusterParametersError {
     pub fn from_response(
@@ -7433,10 +7675,11 @@ impl DescribeEngineDefaultClusterParametersError {
             let reader = EventReader::new(res.body.as_ref());
             let mut stack = XmlResponse::new(reader.into_iter().peekable());
             find_start_element(&mut stack);
-            if let Ok(parsed_error) = Self::deserialize(&mut stack) {
-                match &parsed_error.code[..] {
-                    _ => {}
-                }
+            if Self::deserialize(&mut stack).is_ok() {
+                return RusotoError::unhandled(
+                    DescribeEngineDefaultClusterParametersError::Unhandled,
+                    &res,
+                );
             }
         }
         RusotoError::Unknown(res)
@@ -7453,13 +7696,21 @@ impl DescribeEngineDefaultClusterParametersError {
 impl fmt::Display for DescribeEngineDefaultClusterParametersError {
     #[allow(unused_variables)]
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        match *self {}
+        match *self {
+            DescribeEngineDefaultClusterParametersError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
+        }
     }
 }
 impl Error for DescribeEngineDefaultClusterParametersError {}
 /// Errors returned by DescribeEventCategories
 #[derive(Debug, PartialEq)]
-pub enum DescribeEventCategoriesError {}
+#[non_exhaustive]
+pub enum DescribeEventCategoriesError {
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
+}
 
 impl DescribeEventCategoriesError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<DescribeEventCategoriesError> {
@@ -7467,10 +7718,8 @@ impl DescribeEventCategoriesError {
             let reader = EventReader::new(res.body.as_ref());
             let mut stack = XmlResponse::new(reader.into_iter().peekable());
             find_start_element(&mut stack);
-            if let Ok(parsed_error) = Self::deserialize(&mut stack) {
-                match &parsed_error.code[..] {
-                    _ => {}
-                }
+            if Self::deserialize(&mut stack).is_ok() {
+                return RusotoError::unhandled(DescribeEventCategoriesError::Unhandled, &res);
             }
         }
         RusotoError::Unknown(res)
@@ -7487,13 +7736,19 @@ impl DescribeEventCategoriesError {
 impl fmt::Display for DescribeEventCategoriesError {
     #[allow(unused_variables)]
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        match *self {}
+        match *self {
+            DescribeEventCategoriesError::Unhandled(ref metadata) => write!(f, "{}", metadata),
+        }
     }
 }
 impl Error for DescribeEventCategoriesError {}
 /// Errors returned by DescribeEvents
 #[derive(Debug, PartialEq)]
-pub enum DescribeEventsError {}
+#[non_exhaustive]
+pub enum DescribeEventsError {
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
+}
 
 impl DescribeEventsError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<DescribeEventsError> {
@@ -7501,10 +7756,8 @@ impl DescribeEventsError {
             let reader = EventReader::new(res.body.as_ref());
             let mut stack = XmlResponse::new(reader.into_iter().peekable());
             find_start_element(&mut stack);
-            if let Ok(parsed_error) = Self::deserialize(&mut stack) {
-                match &parsed_error.code[..] {
-                    _ => {}
-                }
+            if Self::deserialize(&mut stack).is_ok() {
+                return RusotoError::unhandled(DescribeEventsError::Unhandled, &res);
             }
         }
         RusotoError::Unknown(res)
@@ -7521,13 +7774,19 @@ impl DescribeEventsError {
 impl fmt::Display for DescribeEventsError {
     #[allow(unused_variables)]
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        match *self {}
+        match *self {
+            DescribeEventsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
+        }
     }
 }
 impl Error for DescribeEventsError {}
 /// Errors returned by DescribeOrderableDBInstanceOptions
 #[derive(Debug, PartialEq)]
-pub enum DescribeOrderableDBInstanceOptionsError {}
+#[non_exhaustive]
+pub enum DescribeOrderableDBInstanceOptionsError {
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
+}
 
 impl DescribeOrderableDBInstanceOptionsError {
     pub fn from_response(
@@ -7537,10 +7796,11 @@ impl DescribeOrderableDBInstanceOptionsError {
             let reader = EventReader::new(res.body.as_ref());
             let mut stack = XmlResponse::new(reader.into_iter().peekable());
             find_start_element(&mut stack);
-            if let Ok(parsed_error) = Self::deserialize(&mut stack) {
-                match &parsed_error.code[..] {
-                    _ => {}
-                }
+            if Self::deserialize(&mut stack).is_ok() {
+                return RusotoError::unhandled(
+                    DescribeOrderableDBInstanceOptionsError::Unhandled,
+                    &res,
+                );
             }
         }
         RusotoError::Unknown(res)
@@ -7557,15 +7817,22 @@ impl DescribeOrderableDBInstanceOptionsError {
 impl fmt::Display for DescribeOrderableDBInstanceOptionsError {
     #[allow(unused_variables)]
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        match *self {}
+        match *self {
+            DescribeOrderableDBInstanceOptionsError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
+        }
     }
 }
 impl Error for DescribeOrderableDBInstanceOptionsError {}
 /// Errors returned by DescribePendingMaintenanceActions
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribePendingMaintenanceActionsError {
     /// <p>The specified resource ID was not found.</p>
     ResourceNotFoundFault(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribePendingMaintenanceActionsError {
@@ -7579,13 +7846,19 @@ impl DescribePendingMaintenanceActionsError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "ResourceNotFoundFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             DescribePendingMaintenanceActionsError::ResourceNotFoundFault(
                                 parsed_error.message,
                             ),
+                            &res,
+                        )
+                    }
+                    _ => {
+                        return RusotoError::unhandled(
+                            DescribePendingMaintenanceActionsError::Unhandled,
+                            &res,
                         )
                     }
-                    _ => {}
                 }
             }
         }
@@ -7607,12 +7880,16 @@ impl fmt::Display for DescribePendingMaintenanceActionsError {
             DescribePendingMaintenanceActionsError::ResourceNotFoundFault(ref cause) => {
                 write!(f, "{}", cause)
             }
+            DescribePendingMaintenanceActionsError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for DescribePendingMaintenanceActionsError {}
 /// Errors returned by FailoverDBCluster
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum FailoverDBClusterError {
     /// <p> <code>DBClusterIdentifier</code> doesn't refer to an existing DB cluster. </p>
     DBClusterNotFoundFault(String),
@@ -7620,6 +7897,8 @@ pub enum FailoverDBClusterError {
     InvalidDBClusterStateFault(String),
     /// <p> The specified DB instance isn't in the <i>available</i> state. </p>
     InvalidDBInstanceStateFault(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl FailoverDBClusterError {
@@ -7631,25 +7910,28 @@ impl FailoverDBClusterError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "DBClusterNotFoundFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             FailoverDBClusterError::DBClusterNotFoundFault(parsed_error.message),
+                            &res,
                         )
                     }
                     "InvalidDBClusterStateFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             FailoverDBClusterError::InvalidDBClusterStateFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "InvalidDBInstanceState" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             FailoverDBClusterError::InvalidDBInstanceStateFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
-                    _ => {}
+                    _ => return RusotoError::unhandled(FailoverDBClusterError::Unhandled, &res),
                 }
             }
         }
@@ -7673,12 +7955,14 @@ impl fmt::Display for FailoverDBClusterError {
             FailoverDBClusterError::InvalidDBInstanceStateFault(ref cause) => {
                 write!(f, "{}", cause)
             }
+            FailoverDBClusterError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for FailoverDBClusterError {}
 /// Errors returned by ListTagsForResource
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListTagsForResourceError {
     /// <p> <code>DBClusterIdentifier</code> doesn't refer to an existing DB cluster. </p>
     DBClusterNotFoundFault(String),
@@ -7686,6 +7970,8 @@ pub enum ListTagsForResourceError {
     DBInstanceNotFoundFault(String),
     /// <p> <code>DBSnapshotIdentifier</code> doesn't refer to an existing DB snapshot. </p>
     DBSnapshotNotFoundFault(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListTagsForResourceError {
@@ -7697,21 +7983,24 @@ impl ListTagsForResourceError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "DBClusterNotFoundFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             ListTagsForResourceError::DBClusterNotFoundFault(parsed_error.message),
+                            &res,
                         )
                     }
                     "DBInstanceNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             ListTagsForResourceError::DBInstanceNotFoundFault(parsed_error.message),
+                            &res,
                         )
                     }
                     "DBSnapshotNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             ListTagsForResourceError::DBSnapshotNotFoundFault(parsed_error.message),
+                            &res,
                         )
                     }
-                    _ => {}
+                    _ => return RusotoError::unhandled(ListTagsForResourceError::Unhandled, &res),
                 }
             }
         }
@@ -7733,12 +8022,14 @@ impl fmt::Display for ListTagsForResourceError {
             ListTagsForResourceError::DBClusterNotFoundFault(ref cause) => write!(f, "{}", cause),
             ListTagsForResourceError::DBInstanceNotFoundFault(ref cause) => write!(f, "{}", cause),
             ListTagsForResourceError::DBSnapshotNotFoundFault(ref cause) => write!(f, "{}", cause),
+            ListTagsForResourceError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListTagsForResourceError {}
 /// Errors returned by ModifyDBCluster
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ModifyDBClusterError {
     /// <p>You already have a DB cluster with the given identifier.</p>
     DBClusterAlreadyExistsFault(String),
@@ -7762,6 +8053,8 @@ pub enum ModifyDBClusterError {
     InvalidVPCNetworkStateFault(String),
     /// <p>The request would cause you to exceed the allowed amount of storage available across all DB instances.</p>
     StorageQuotaExceededFault(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ModifyDBClusterError {
@@ -7773,67 +8066,78 @@ impl ModifyDBClusterError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "DBClusterAlreadyExistsFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             ModifyDBClusterError::DBClusterAlreadyExistsFault(parsed_error.message),
+                            &res,
                         )
                     }
                     "DBClusterNotFoundFault" => {
-                        return RusotoError::Service(ModifyDBClusterError::DBClusterNotFoundFault(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            ModifyDBClusterError::DBClusterNotFoundFault(parsed_error.message),
+                            &res,
+                        )
                     }
                     "DBClusterParameterGroupNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             ModifyDBClusterError::DBClusterParameterGroupNotFoundFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "DBSubnetGroupNotFoundFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             ModifyDBClusterError::DBSubnetGroupNotFoundFault(parsed_error.message),
+                            &res,
                         )
                     }
                     "InvalidDBClusterStateFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             ModifyDBClusterError::InvalidDBClusterStateFault(parsed_error.message),
+                            &res,
                         )
                     }
                     "InvalidDBInstanceState" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             ModifyDBClusterError::InvalidDBInstanceStateFault(parsed_error.message),
+                            &res,
                         )
                     }
                     "InvalidDBSecurityGroupState" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             ModifyDBClusterError::InvalidDBSecurityGroupStateFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "InvalidDBSubnetGroupStateFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             ModifyDBClusterError::InvalidDBSubnetGroupStateFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "InvalidSubnet" => {
-                        return RusotoError::Service(ModifyDBClusterError::InvalidSubnet(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            ModifyDBClusterError::InvalidSubnet(parsed_error.message),
+                            &res,
+                        )
                     }
                     "InvalidVPCNetworkStateFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             ModifyDBClusterError::InvalidVPCNetworkStateFault(parsed_error.message),
+                            &res,
                         )
                     }
                     "StorageQuotaExceeded" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             ModifyDBClusterError::StorageQuotaExceededFault(parsed_error.message),
+                            &res,
                         )
                     }
-                    _ => {}
+                    _ => return RusotoError::unhandled(ModifyDBClusterError::Unhandled, &res),
                 }
             }
         }
@@ -7869,17 +8173,21 @@ impl fmt::Display for ModifyDBClusterError {
             ModifyDBClusterError::InvalidSubnet(ref cause) => write!(f, "{}", cause),
             ModifyDBClusterError::InvalidVPCNetworkStateFault(ref cause) => write!(f, "{}", cause),
             ModifyDBClusterError::StorageQuotaExceededFault(ref cause) => write!(f, "{}", cause),
+            ModifyDBClusterError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ModifyDBClusterError {}
 /// Errors returned by ModifyDBClusterParameterGroup
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ModifyDBClusterParameterGroupError {
     /// <p> <code>DBParameterGroupName</code> doesn't refer to an existing DB parameter group. </p>
     DBParameterGroupNotFoundFault(String),
     /// <p>The DB parameter group is in use, or it is in a state that is not valid. If you are trying to delete the parameter group, you can't delete it when the parameter group is in this state.</p>
     InvalidDBParameterGroupStateFault(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ModifyDBClusterParameterGroupError {
@@ -7893,20 +8201,27 @@ impl ModifyDBClusterParameterGroupError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "DBParameterGroupNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             ModifyDBClusterParameterGroupError::DBParameterGroupNotFoundFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "InvalidDBParameterGroupState" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             ModifyDBClusterParameterGroupError::InvalidDBParameterGroupStateFault(
                                 parsed_error.message,
                             ),
+                            &res,
+                        )
+                    }
+                    _ => {
+                        return RusotoError::unhandled(
+                            ModifyDBClusterParameterGroupError::Unhandled,
+                            &res,
                         )
                     }
-                    _ => {}
                 }
             }
         }
@@ -7931,12 +8246,16 @@ impl fmt::Display for ModifyDBClusterParameterGroupError {
             ModifyDBClusterParameterGroupError::InvalidDBParameterGroupStateFault(ref cause) => {
                 write!(f, "{}", cause)
             }
+            ModifyDBClusterParameterGroupError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for ModifyDBClusterParameterGroupError {}
 /// Errors returned by ModifyDBClusterSnapshotAttribute
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ModifyDBClusterSnapshotAttributeError {
     /// <p> <code>DBClusterSnapshotIdentifier</code> doesn't refer to an existing DB cluster snapshot. </p>
     DBClusterSnapshotNotFoundFault(String),
@@ -7944,6 +8263,8 @@ pub enum ModifyDBClusterSnapshotAttributeError {
     InvalidDBClusterSnapshotStateFault(String),
     /// <p>You have exceeded the maximum number of accounts that you can share a manual DB snapshot with. </p>
     SharedSnapshotQuotaExceededFault(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ModifyDBClusterSnapshotAttributeError {
@@ -7957,25 +8278,33 @@ impl ModifyDBClusterSnapshotAttributeError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "DBClusterSnapshotNotFoundFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             ModifyDBClusterSnapshotAttributeError::DBClusterSnapshotNotFoundFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
-                    "InvalidDBClusterSnapshotStateFault" => return RusotoError::Service(
+                    "InvalidDBClusterSnapshotStateFault" => return RusotoError::service(
                         ModifyDBClusterSnapshotAttributeError::InvalidDBClusterSnapshotStateFault(
                             parsed_error.message,
                         ),
+                        &res,
                     ),
                     "SharedSnapshotQuotaExceeded" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             ModifyDBClusterSnapshotAttributeError::SharedSnapshotQuotaExceededFault(
                                 parsed_error.message,
                             ),
+                            &res,
+                        )
+                    }
+                    _ => {
+                        return RusotoError::unhandled(
+                            ModifyDBClusterSnapshotAttributeError::Unhandled,
+                            &res,
                         )
                     }
-                    _ => {}
                 }
             }
         }
@@ -8003,12 +8332,16 @@ impl fmt::Display for ModifyDBClusterSnapshotAttributeError {
             ModifyDBClusterSnapshotAttributeError::SharedSnapshotQuotaExceededFault(ref cause) => {
                 write!(f, "{}", cause)
             }
+            ModifyDBClusterSnapshotAttributeError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for ModifyDBClusterSnapshotAttributeError {}
 /// Errors returned by ModifyDBInstance
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ModifyDBInstanceError {
     /// <p>The specified CIDR IP or Amazon EC2 security group isn't authorized for the specified DB security group.</p> <p>Amazon DocumentDB also might not be authorized to perform necessary actions on your behalf using IAM.</p>
     AuthorizationNotFoundFault(String),
@@ -8036,6 +8369,8 @@ pub enum ModifyDBInstanceError {
     StorageQuotaExceededFault(String),
     /// <p>Storage of the specified <code>StorageType</code> can't be associated with the DB instance. </p>
     StorageTypeNotSupportedFault(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ModifyDBInstanceError {
@@ -8047,89 +8382,102 @@ impl ModifyDBInstanceError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "AuthorizationNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             ModifyDBInstanceError::AuthorizationNotFoundFault(parsed_error.message),
+                            &res,
                         )
                     }
                     "CertificateNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             ModifyDBInstanceError::CertificateNotFoundFault(parsed_error.message),
+                            &res,
                         )
                     }
                     "DBInstanceAlreadyExists" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             ModifyDBInstanceError::DBInstanceAlreadyExistsFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "DBInstanceNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             ModifyDBInstanceError::DBInstanceNotFoundFault(parsed_error.message),
+                            &res,
                         )
                     }
                     "DBParameterGroupNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             ModifyDBInstanceError::DBParameterGroupNotFoundFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "DBSecurityGroupNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             ModifyDBInstanceError::DBSecurityGroupNotFoundFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "DBUpgradeDependencyFailure" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             ModifyDBInstanceError::DBUpgradeDependencyFailureFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "InsufficientDBInstanceCapacity" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             ModifyDBInstanceError::InsufficientDBInstanceCapacityFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "InvalidDBInstanceState" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             ModifyDBInstanceError::InvalidDBInstanceStateFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "InvalidDBSecurityGroupState" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             ModifyDBInstanceError::InvalidDBSecurityGroupStateFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "InvalidVPCNetworkStateFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             ModifyDBInstanceError::InvalidVPCNetworkStateFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "StorageQuotaExceeded" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             ModifyDBInstanceError::StorageQuotaExceededFault(parsed_error.message),
+                            &res,
                         )
                     }
                     "StorageTypeNotSupported" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             ModifyDBInstanceError::StorageTypeNotSupportedFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
-                    _ => {}
+                    _ => return RusotoError::unhandled(ModifyDBInstanceError::Unhandled, &res),
                 }
             }
         }
@@ -8175,12 +8523,14 @@ impl fmt::Display for ModifyDBInstanceError {
             ModifyDBInstanceError::StorageTypeNotSupportedFault(ref cause) => {
                 write!(f, "{}", cause)
             }
+            ModifyDBInstanceError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ModifyDBInstanceError {}
 /// Errors returned by ModifyDBSubnetGroup
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ModifyDBSubnetGroupError {
     /// <p>Subnets in the DB subnet group should cover at least two Availability Zones unless there is only one Availability Zone.</p>
     DBSubnetGroupDoesNotCoverEnoughAZs(String),
@@ -8192,6 +8542,8 @@ pub enum ModifyDBSubnetGroupError {
     InvalidSubnet(String),
     /// <p>The DB subnet is already in use in the Availability Zone.</p>
     SubnetAlreadyInUse(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ModifyDBSubnetGroupError {
@@ -8203,37 +8555,42 @@ impl ModifyDBSubnetGroupError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "DBSubnetGroupDoesNotCoverEnoughAZs" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             ModifyDBSubnetGroupError::DBSubnetGroupDoesNotCoverEnoughAZs(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "DBSubnetGroupNotFoundFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             ModifyDBSubnetGroupError::DBSubnetGroupNotFoundFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "DBSubnetQuotaExceededFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             ModifyDBSubnetGroupError::DBSubnetQuotaExceededFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "InvalidSubnet" => {
-                        return RusotoError::Service(ModifyDBSubnetGroupError::InvalidSubnet(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            ModifyDBSubnetGroupError::InvalidSubnet(parsed_error.message),
+                            &res,
+                        )
                     }
                     "SubnetAlreadyInUse" => {
-                        return RusotoError::Service(ModifyDBSubnetGroupError::SubnetAlreadyInUse(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            ModifyDBSubnetGroupError::SubnetAlreadyInUse(parsed_error.message),
+                            &res,
+                        )
                     }
-                    _ => {}
+                    _ => return RusotoError::unhandled(ModifyDBSubnetGroupError::Unhandled, &res),
                 }
             }
         }
@@ -8263,17 +8620,21 @@ impl fmt::Display for ModifyDBSubnetGroupError {
             }
             ModifyDBSubnetGroupError::InvalidSubnet(ref cause) => write!(f, "{}", cause),
             ModifyDBSubnetGroupError::SubnetAlreadyInUse(ref cause) => write!(f, "{}", cause),
+            ModifyDBSubnetGroupError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ModifyDBSubnetGroupError {}
 /// Errors returned by RebootDBInstance
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum RebootDBInstanceError {
     /// <p> <code>DBInstanceIdentifier</code> doesn't refer to an existing DB instance. </p>
     DBInstanceNotFoundFault(String),
     /// <p> The specified DB instance isn't in the <i>available</i> state. </p>
     InvalidDBInstanceStateFault(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl RebootDBInstanceError {
@@ -8285,18 +8646,20 @@ impl RebootDBInstanceError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "DBInstanceNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             RebootDBInstanceError::DBInstanceNotFoundFault(parsed_error.message),
+                            &res,
                         )
                     }
                     "InvalidDBInstanceState" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             RebootDBInstanceError::InvalidDBInstanceStateFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
-                    _ => {}
+                    _ => return RusotoError::unhandled(RebootDBInstanceError::Unhandled, &res),
                 }
             }
         }
@@ -8317,12 +8680,14 @@ impl fmt::Display for RebootDBInstanceError {
         match *self {
             RebootDBInstanceError::DBInstanceNotFoundFault(ref cause) => write!(f, "{}", cause),
             RebootDBInstanceError::InvalidDBInstanceStateFault(ref cause) => write!(f, "{}", cause),
+            RebootDBInstanceError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for RebootDBInstanceError {}
 /// Errors returned by RemoveTagsFromResource
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum RemoveTagsFromResourceError {
     /// <p> <code>DBClusterIdentifier</code> doesn't refer to an existing DB cluster. </p>
     DBClusterNotFoundFault(String),
@@ -8330,6 +8695,8 @@ pub enum RemoveTagsFromResourceError {
     DBInstanceNotFoundFault(String),
     /// <p> <code>DBSnapshotIdentifier</code> doesn't refer to an existing DB snapshot. </p>
     DBSnapshotNotFoundFault(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl RemoveTagsFromResourceError {
@@ -8341,27 +8708,32 @@ impl RemoveTagsFromResourceError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "DBClusterNotFoundFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             RemoveTagsFromResourceError::DBClusterNotFoundFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "DBInstanceNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             RemoveTagsFromResourceError::DBInstanceNotFoundFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "DBSnapshotNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             RemoveTagsFromResourceError::DBSnapshotNotFoundFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
-                    _ => {}
+                    _ => {
+                        return RusotoError::unhandled(RemoveTagsFromResourceError::Unhandled, &res)
+                    }
                 }
             }
         }
@@ -8389,17 +8761,21 @@ impl fmt::Display for RemoveTagsFromResourceError {
             RemoveTagsFromResourceError::DBSnapshotNotFoundFault(ref cause) => {
                 write!(f, "{}", cause)
             }
+            RemoveTagsFromResourceError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for RemoveTagsFromResourceError {}
 /// Errors returned by ResetDBClusterParameterGroup
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ResetDBClusterParameterGroupError {
     /// <p> <code>DBParameterGroupName</code> doesn't refer to an existing DB parameter group. </p>
     DBParameterGroupNotFoundFault(String),
     /// <p>The DB parameter group is in use, or it is in a state that is not valid. If you are trying to delete the parameter group, you can't delete it when the parameter group is in this state.</p>
     InvalidDBParameterGroupStateFault(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ResetDBClusterParameterGroupError {
@@ -8413,20 +8789,27 @@ impl ResetDBClusterParameterGroupError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "DBParameterGroupNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             ResetDBClusterParameterGroupError::DBParameterGroupNotFoundFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "InvalidDBParameterGroupState" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             ResetDBClusterParameterGroupError::InvalidDBParameterGroupStateFault(
                                 parsed_error.message,
                             ),
+                            &res,
+                        )
+                    }
+                    _ => {
+                        return RusotoError::unhandled(
+                            ResetDBClusterParameterGroupError::Unhandled,
+                            &res,
                         )
                     }
-                    _ => {}
                 }
             }
         }
@@ -8451,12 +8834,14 @@ impl fmt::Display for ResetDBClusterParameterGroupError {
             ResetDBClusterParameterGroupError::InvalidDBParameterGroupStateFault(ref cause) => {
                 write!(f, "{}", cause)
             }
+            ResetDBClusterParameterGroupError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ResetDBClusterParameterGroupError {}
 /// Errors returned by RestoreDBClusterFromSnapshot
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum RestoreDBClusterFromSnapshotError {
     /// <p>You already have a DB cluster with the given identifier.</p>
     DBClusterAlreadyExistsFault(String),
@@ -8486,6 +8871,8 @@ pub enum RestoreDBClusterFromSnapshotError {
     KMSKeyNotAccessibleFault(String),
     /// <p>The request would cause you to exceed the allowed amount of storage available across all DB instances.</p>
     StorageQuotaExceededFault(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl RestoreDBClusterFromSnapshotError {
@@ -8499,100 +8886,119 @@ impl RestoreDBClusterFromSnapshotError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "DBClusterAlreadyExistsFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             RestoreDBClusterFromSnapshotError::DBClusterAlreadyExistsFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "DBClusterQuotaExceededFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             RestoreDBClusterFromSnapshotError::DBClusterQuotaExceededFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "DBClusterSnapshotNotFoundFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             RestoreDBClusterFromSnapshotError::DBClusterSnapshotNotFoundFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "DBSnapshotNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             RestoreDBClusterFromSnapshotError::DBSnapshotNotFoundFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "DBSubnetGroupNotFoundFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             RestoreDBClusterFromSnapshotError::DBSubnetGroupNotFoundFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "InsufficientDBClusterCapacityFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             RestoreDBClusterFromSnapshotError::InsufficientDBClusterCapacityFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
-                    "InsufficientStorageClusterCapacity" => return RusotoError::Service(
+                    "InsufficientStorageClusterCapacity" => return RusotoError::service(
                         RestoreDBClusterFromSnapshotError::InsufficientStorageClusterCapacityFault(
                             parsed_error.message,
                         ),
+                        &res,
                     ),
                     "InvalidDBClusterSnapshotStateFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             RestoreDBClusterFromSnapshotError::InvalidDBClusterSnapshotStateFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "InvalidDBSnapshotState" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             RestoreDBClusterFromSnapshotError::InvalidDBSnapshotStateFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "InvalidRestoreFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             RestoreDBClusterFromSnapshotError::InvalidRestoreFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "InvalidSubnet" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             RestoreDBClusterFromSnapshotError::InvalidSubnet(parsed_error.message),
+                            &res,
                         )
                     }
                     "InvalidVPCNetworkStateFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             RestoreDBClusterFromSnapshotError::InvalidVPCNetworkStateFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "KMSKeyNotAccessibleFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             RestoreDBClusterFromSnapshotError::KMSKeyNotAccessibleFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "StorageQuotaExceeded" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             RestoreDBClusterFromSnapshotError::StorageQuotaExceededFault(
                                 parsed_error.message,
                             ),
+                            &res,
+                        )
+                    }
+                    _ => {
+                        return RusotoError::unhandled(
+                            RestoreDBClusterFromSnapshotError::Unhandled,
+                            &res,
                         )
                     }
-                    _ => {}
                 }
             }
         }
@@ -8651,12 +9057,14 @@ impl fmt::Display for RestoreDBClusterFromSnapshotError {
             RestoreDBClusterFromSnapshotError::StorageQuotaExceededFault(ref cause) => {
                 write!(f, "{}", cause)
             }
+            RestoreDBClusterFromSnapshotError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for RestoreDBClusterFromSnapshotError {}
 /// Errors returned by RestoreDBClusterToPointInTime
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum RestoreDBClusterToPointInTimeError {
     /// <p>You already have a DB cluster with the given identifier.</p>
     DBClusterAlreadyExistsFault(String),
@@ -8688,6 +9096,8 @@ pub enum RestoreDBClusterToPointInTimeError {
     KMSKeyNotAccessibleFault(String),
     /// <p>The request would cause you to exceed the allowed amount of storage available across all DB instances.</p>
     StorageQuotaExceededFault(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl RestoreDBClusterToPointInTimeError {
@@ -8701,107 +9111,127 @@ impl RestoreDBClusterToPointInTimeError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "DBClusterAlreadyExistsFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             RestoreDBClusterToPointInTimeError::DBClusterAlreadyExistsFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "DBClusterNotFoundFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             RestoreDBClusterToPointInTimeError::DBClusterNotFoundFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "DBClusterQuotaExceededFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             RestoreDBClusterToPointInTimeError::DBClusterQuotaExceededFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "DBClusterSnapshotNotFoundFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             RestoreDBClusterToPointInTimeError::DBClusterSnapshotNotFoundFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "DBSubnetGroupNotFoundFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             RestoreDBClusterToPointInTimeError::DBSubnetGroupNotFoundFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "InsufficientDBClusterCapacityFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             RestoreDBClusterToPointInTimeError::InsufficientDBClusterCapacityFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
-                    "InsufficientStorageClusterCapacity" => return RusotoError::Service(
+                    "InsufficientStorageClusterCapacity" => return RusotoError::service(
                         RestoreDBClusterToPointInTimeError::InsufficientStorageClusterCapacityFault(
                             parsed_error.message,
                         ),
+                        &res,
                     ),
                     "InvalidDBClusterSnapshotStateFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             RestoreDBClusterToPointInTimeError::InvalidDBClusterSnapshotStateFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "InvalidDBClusterStateFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             RestoreDBClusterToPointInTimeError::InvalidDBClusterStateFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "InvalidDBSnapshotState" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             RestoreDBClusterToPointInTimeError::InvalidDBSnapshotStateFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "InvalidRestoreFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             RestoreDBClusterToPointInTimeError::InvalidRestoreFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "InvalidSubnet" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             RestoreDBClusterToPointInTimeError::InvalidSubnet(parsed_error.message),
+                            &res,
                         )
                     }
                     "InvalidVPCNetworkStateFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             RestoreDBClusterToPointInTimeError::InvalidVPCNetworkStateFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "KMSKeyNotAccessibleFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             RestoreDBClusterToPointInTimeError::KMSKeyNotAccessibleFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "StorageQuotaExceeded" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             RestoreDBClusterToPointInTimeError::StorageQuotaExceededFault(
                                 parsed_error.message,
                             ),
+                            &res,
+                        )
+                    }
+                    _ => {
+                        return RusotoError::unhandled(
+                            RestoreDBClusterToPointInTimeError::Unhandled,
+                            &res,
                         )
                     }
-                    _ => {}
                 }
             }
         }
@@ -8863,12 +9293,16 @@ impl fmt::Display for RestoreDBClusterToPointInTimeError {
             RestoreDBClusterToPointInTimeError::StorageQuotaExceededFault(ref cause) => {
                 write!(f, "{}", cause)
             }
+            RestoreDBClusterToPointInTimeError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for RestoreDBClusterToPointInTimeError {}
 /// Errors returned by StartDBCluster
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum StartDBClusterError {
     /// <p> <code>DBClusterIdentifier</code> doesn't refer to an existing DB cluster. </p>
     DBClusterNotFoundFault(String),
@@ -8876,6 +9310,8 @@ pub enum StartDBClusterError {
     InvalidDBClusterStateFault(String),
     /// <p> The specified DB instance isn't in the <i>available</i> state. </p>
     InvalidDBInstanceStateFault(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl StartDBClusterError {
@@ -8887,21 +9323,24 @@ impl StartDBClusterError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "DBClusterNotFoundFault" => {
-                        return RusotoError::Service(StartDBClusterError::DBClusterNotFoundFault(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            StartDBClusterError::DBClusterNotFoundFault(parsed_error.message),
+                            &res,
+                        )
                     }
                     "InvalidDBClusterStateFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             StartDBClusterError::InvalidDBClusterStateFault(parsed_error.message),
+                            &res,
                         )
                     }
                     "InvalidDBInstanceState" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             StartDBClusterError::InvalidDBInstanceStateFault(parsed_error.message),
+                            &res,
                         )
                     }
-                    _ => {}
+                    _ => return RusotoError::unhandled(StartDBClusterError::Unhandled, &res),
                 }
             }
         }
@@ -8923,12 +9362,14 @@ impl fmt::Display for StartDBClusterError {
             StartDBClusterError::DBClusterNotFoundFault(ref cause) => write!(f, "{}", cause),
             StartDBClusterError::InvalidDBClusterStateFault(ref cause) => write!(f, "{}", cause),
             StartDBClusterError::InvalidDBInstanceStateFault(ref cause) => write!(f, "{}", cause),
+            StartDBClusterError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for StartDBClusterError {}
 /// Errors returned by StopDBCluster
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum StopDBClusterError {
     /// <p> <code>DBClusterIdentifier</code> doesn't refer to an existing DB cluster. </p>
     DBClusterNotFoundFault(String),
@@ -8936,6 +9377,8 @@ pub enum StopDBClusterError {
     InvalidDBClusterStateFault(String),
     /// <p> The specified DB instance isn't in the <i>available</i> state. </p>
     InvalidDBInstanceStateFault(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl StopDBClusterError {
@@ -8947,21 +9390,24 @@ impl StopDBClusterError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "DBClusterNotFoundFault" => {
-                        return RusotoError::Service(StopDBClusterError::DBClusterNotFoundFault(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            StopDBClusterError::DBClusterNotFoundFault(parsed_error.message),
+                            &res,
+                        )
                     }
                     "InvalidDBClusterStateFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             StopDBClusterError::InvalidDBClusterStateFault(parsed_error.message),
+                            &res,
                         )
                     }
                     "InvalidDBInstanceState" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             StopDBClusterError::InvalidDBInstanceStateFault(parsed_error.message),
+                            &res,
                         )
                     }
-                    _ => {}
+                    _ => return RusotoError::unhandled(StopDBClusterError::Unhandled, &res),
                 }
             }
         }
@@ -8983,6 +9429,7 @@ impl fmt::Display for StopDBClusterError {
             StopDBClusterError::DBClusterNotFoundFault(ref cause) => write!(f, "{}", cause),
             StopDBClusterError::InvalidDBClusterStateFault(ref cause) => write!(f, "{}", cause),
             StopDBClusterError::InvalidDBInstanceStateFault(ref cause) => write!(f, "{}", cause),
+            StopDBClusterError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
@@ -9265,6 +9712,7 @@ impl DocdbClient {
     /// Creates a client backed by the default tokio event loop.
     ///
     /// The client will use the default credentials provider and tls client.
+    #[cfg(not(target_arch = "wasm32"))]
     pub fn new(region: region::Region) -> DocdbClient {
         DocdbClient {
             client: Client::shared(),
@@ -9290,64 +9738,101 @@ impl DocdbClient {
     pub fn new_with_client(client: Client, region: region::Region) -> DocdbClient {
         DocdbClient { client, region }
     }
+
+    /// Returns a builder of a client, setting any of its region, credentials provider,
+    /// dispatcher, retry policy, endpoint and timeouts.
+    pub fn builder() -> rusoto_core::ClientBuilder<DocdbClient> {
+        rusoto_core::ClientBuilder::new(DocdbClient::new_with_client)
+    }
+
+    /// Returns a clone of this client with the overrides of `config` applied to its
+    /// operations, sharing its connections.
+    pub fn customize(&self, config: &rusoto_core::OperationConfig) -> DocdbClient {
+        DocdbClient {
+            client: self.client.customize(config),
+            region: config.resolve_region(&self.region),
+        }
+    }
 }
 
-#[async_trait]
-impl Docdb for DocdbClient {
-    /// <p>Adds metadata tags to an Amazon DocumentDB resource. You can use these tags with cost allocation reporting to track costs that are associated with Amazon DocumentDB resources. or in a <code>Condition</code> statement in an AWS Identity and Access Management (IAM) policy for Amazon DocumentDB.</p>
-    async fn add_tags_to_resource(
+impl DocdbClient {
+    /// Calls `add_tags_to_resource`, also returning the metadata of the response.
+    pub async fn add_tags_to_resource_with_metadata(
         &self,
         input: AddTagsToResourceMessage,
-    ) -> Result<(), RusotoError<AddTagsToResourceError>> {
-        let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "AddTagsToResource");
-        params.put("Version", "2014-10-31");
-        AddTagsToResourceMessageSerializer::serialize(&mut params, "", &input);
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+    ) -> Result<((), ResponseMetadata), RusotoError<AddTagsToResourceError>> {
+        let request = self.build_add_tags_to_resource_request(input);
 
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(AddTagsToResourceError::from_response(response));
         }
 
         std::mem::drop(response);
-        Ok(())
+        Ok(((), metadata))
     }
 
-    /// <p>Applies a pending maintenance action to a resource (for example, to a DB instance).</p>
-    async fn apply_pending_maintenance_action(
+    /// Presigns the request `add_tags_to_resource` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_add_tags_to_resource(
         &self,
-        input: ApplyPendingMaintenanceActionMessage,
-    ) -> Result<ApplyPendingMaintenanceActionResult, RusotoError<ApplyPendingMaintenanceActionError>>
-    {
+        presigner: &rusoto_core::Presigner,
+        input: AddTagsToResourceMessage,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_add_tags_to_resource_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_add_tags_to_resource_request(&self, input: AddTagsToResourceMessage) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("AddTagsToResource");
         let mut params = Params::new();
 
-        params.put("Action", "ApplyPendingMaintenanceAction");
+        params.put("Action", "AddTagsToResource");
         params.put("Version", "2014-10-31");
-        ApplyPendingMaintenanceActionMessageSerializer::serialize(&mut params, "", &input);
+        AddTagsToResourceMessageSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `apply_pending_maintenance_action`, also returning the metadata of the response.
+    pub async fn apply_pending_maintenance_action_with_metadata(
+        &self,
+        input: ApplyPendingMaintenanceActionMessage,
+    ) -> Result<
+        (ApplyPendingMaintenanceActionResult, ResponseMetadata),
+        RusotoError<ApplyPendingMaintenanceActionError>,
+    > {
+        let request = self.build_apply_pending_maintenance_action_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(ApplyPendingMaintenanceActionError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -9369,35 +9854,64 @@ impl Docdb for DocdbClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Copies the specified DB cluster parameter group.</p>
-    async fn copy_db_cluster_parameter_group(
+    /// Presigns the request `apply_pending_maintenance_action` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_apply_pending_maintenance_action(
         &self,
-        input: CopyDBClusterParameterGroupMessage,
-    ) -> Result<CopyDBClusterParameterGroupResult, RusotoError<CopyDBClusterParameterGroupError>>
-    {
+        presigner: &rusoto_core::Presigner,
+        input: ApplyPendingMaintenanceActionMessage,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_apply_pending_maintenance_action_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_apply_pending_maintenance_action_request(
+        &self,
+        input: ApplyPendingMaintenanceActionMessage,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("ApplyPendingMaintenanceAction");
         let mut params = Params::new();
 
-        params.put("Action", "CopyDBClusterParameterGroup");
+        params.put("Action", "ApplyPendingMaintenanceAction");
         params.put("Version", "2014-10-31");
-        CopyDBClusterParameterGroupMessageSerializer::serialize(&mut params, "", &input);
+        ApplyPendingMaintenanceActionMessageSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `copy_db_cluster_parameter_group`, also returning the metadata of the response.
+    pub async fn copy_db_cluster_parameter_group_with_metadata(
+        &self,
+        input: CopyDBClusterParameterGroupMessage,
+    ) -> Result<
+        (CopyDBClusterParameterGroupResult, ResponseMetadata),
+        RusotoError<CopyDBClusterParameterGroupError>,
+    > {
+        let request = self.build_copy_db_cluster_parameter_group_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(CopyDBClusterParameterGroupError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -9419,34 +9933,64 @@ impl Docdb for DocdbClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Copies a snapshot of a DB cluster.</p> <p>To copy a DB cluster snapshot from a shared manual DB cluster snapshot, <code>SourceDBClusterSnapshotIdentifier</code> must be the Amazon Resource Name (ARN) of the shared DB cluster snapshot.</p> <p>To cancel the copy operation after it is in progress, delete the target DB cluster snapshot identified by <code>TargetDBClusterSnapshotIdentifier</code> while that DB cluster snapshot is in the <i>copying</i> status.</p>
-    async fn copy_db_cluster_snapshot(
+    /// Presigns the request `copy_db_cluster_parameter_group` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_copy_db_cluster_parameter_group(
         &self,
-        input: CopyDBClusterSnapshotMessage,
-    ) -> Result<CopyDBClusterSnapshotResult, RusotoError<CopyDBClusterSnapshotError>> {
+        presigner: &rusoto_core::Presigner,
+        input: CopyDBClusterParameterGroupMessage,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_copy_db_cluster_parameter_group_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_copy_db_cluster_parameter_group_request(
+        &self,
+        input: CopyDBClusterParameterGroupMessage,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("CopyDBClusterParameterGroup");
         let mut params = Params::new();
 
-        params.put("Action", "CopyDBClusterSnapshot");
+        params.put("Action", "CopyDBClusterParameterGroup");
         params.put("Version", "2014-10-31");
-        CopyDBClusterSnapshotMessageSerializer::serialize(&mut params, "", &input);
+        CopyDBClusterParameterGroupMessageSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `copy_db_cluster_snapshot`, also returning the metadata of the response.
+    pub async fn copy_db_cluster_snapshot_with_metadata(
+        &self,
+        input: CopyDBClusterSnapshotMessage,
+    ) -> Result<
+        (CopyDBClusterSnapshotResult, ResponseMetadata),
+        RusotoError<CopyDBClusterSnapshotError>,
+    > {
+        let request = self.build_copy_db_cluster_snapshot_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(CopyDBClusterSnapshotError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -9468,34 +10012,61 @@ impl Docdb for DocdbClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Creates a new Amazon DocumentDB DB cluster.</p>
-    async fn create_db_cluster(
+    /// Presigns the request `copy_db_cluster_snapshot` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_copy_db_cluster_snapshot(
         &self,
-        input: CreateDBClusterMessage,
-    ) -> Result<CreateDBClusterResult, RusotoError<CreateDBClusterError>> {
+        presigner: &rusoto_core::Presigner,
+        input: CopyDBClusterSnapshotMessage,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_copy_db_cluster_snapshot_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_copy_db_cluster_snapshot_request(
+        &self,
+        input: CopyDBClusterSnapshotMessage,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("CopyDBClusterSnapshot");
         let mut params = Params::new();
 
-        params.put("Action", "CreateDBCluster");
+        params.put("Action", "CopyDBClusterSnapshot");
         params.put("Version", "2014-10-31");
-        CreateDBClusterMessageSerializer::serialize(&mut params, "", &input);
+        CopyDBClusterSnapshotMessageSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `create_db_cluster`, also returning the metadata of the response.
+    pub async fn create_db_cluster_with_metadata(
+        &self,
+        input: CreateDBClusterMessage,
+    ) -> Result<(CreateDBClusterResult, ResponseMetadata), RusotoError<CreateDBClusterError>> {
+        let request = self.build_create_db_cluster_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(CreateDBClusterError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -9517,35 +10088,61 @@ impl Docdb for DocdbClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p><p>Creates a new DB cluster parameter group.</p> <p>Parameters in a DB cluster parameter group apply to all of the instances in a DB cluster.</p> <p>A DB cluster parameter group is initially created with the default parameters for the database engine used by instances in the DB cluster. To provide custom values for any of the parameters, you must modify the group after you create it. After you create a DB cluster parameter group, you must associate it with your DB cluster. For the new DB cluster parameter group and associated settings to take effect, you must then reboot the DB instances in the DB cluster without failover.</p> <important> <p>After you create a DB cluster parameter group, you should wait at least 5 minutes before creating your first DB cluster that uses that DB cluster parameter group as the default parameter group. This allows Amazon DocumentDB to fully complete the create action before the DB cluster parameter group is used as the default for a new DB cluster. This step is especially important for parameters that are critical when creating the default database for a DB cluster, such as the character set for the default database defined by the <code>character<em>set</em>database</code> parameter.</p> </important></p>
-    async fn create_db_cluster_parameter_group(
+    /// Presigns the request `create_db_cluster` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_db_cluster(
         &self,
-        input: CreateDBClusterParameterGroupMessage,
-    ) -> Result<CreateDBClusterParameterGroupResult, RusotoError<CreateDBClusterParameterGroupError>>
-    {
+        presigner: &rusoto_core::Presigner,
+        input: CreateDBClusterMessage,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_db_cluster_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_db_cluster_request(&self, input: CreateDBClusterMessage) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("CreateDBCluster");
         let mut params = Params::new();
 
-        params.put("Action", "CreateDBClusterParameterGroup");
+        params.put("Action", "CreateDBCluster");
         params.put("Version", "2014-10-31");
-        CreateDBClusterParameterGroupMessageSerializer::serialize(&mut params, "", &input);
+        CreateDBClusterMessageSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `create_db_cluster_parameter_group`, also returning the metadata of the response.
+    pub async fn create_db_cluster_parameter_group_with_metadata(
+        &self,
+        input: CreateDBClusterParameterGroupMessage,
+    ) -> Result<
+        (CreateDBClusterParameterGroupResult, ResponseMetadata),
+        RusotoError<CreateDBClusterParameterGroupError>,
+    > {
+        let request = self.build_create_db_cluster_parameter_group_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(CreateDBClusterParameterGroupError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -9567,34 +10164,64 @@ impl Docdb for DocdbClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Creates a snapshot of a DB cluster. </p>
-    async fn create_db_cluster_snapshot(
+    /// Presigns the request `create_db_cluster_parameter_group` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_db_cluster_parameter_group(
         &self,
-        input: CreateDBClusterSnapshotMessage,
-    ) -> Result<CreateDBClusterSnapshotResult, RusotoError<CreateDBClusterSnapshotError>> {
+        presigner: &rusoto_core::Presigner,
+        input: CreateDBClusterParameterGroupMessage,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_db_cluster_parameter_group_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_db_cluster_parameter_group_request(
+        &self,
+        input: CreateDBClusterParameterGroupMessage,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("CreateDBClusterParameterGroup");
         let mut params = Params::new();
 
-        params.put("Action", "CreateDBClusterSnapshot");
+        params.put("Action", "CreateDBClusterParameterGroup");
         params.put("Version", "2014-10-31");
-        CreateDBClusterSnapshotMessageSerializer::serialize(&mut params, "", &input);
+        CreateDBClusterParameterGroupMessageSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `create_db_cluster_snapshot`, also returning the metadata of the response.
+    pub async fn create_db_cluster_snapshot_with_metadata(
+        &self,
+        input: CreateDBClusterSnapshotMessage,
+    ) -> Result<
+        (CreateDBClusterSnapshotResult, ResponseMetadata),
+        RusotoError<CreateDBClusterSnapshotError>,
+    > {
+        let request = self.build_create_db_cluster_snapshot_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(CreateDBClusterSnapshotError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -9616,34 +10243,62 @@ impl Docdb for DocdbClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Creates a new DB instance.</p>
-    async fn create_db_instance(
+    /// Presigns the request `create_db_cluster_snapshot` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_db_cluster_snapshot(
         &self,
-        input: CreateDBInstanceMessage,
-    ) -> Result<CreateDBInstanceResult, RusotoError<CreateDBInstanceError>> {
-        let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        presigner: &rusoto_core::Presigner,
+        input: CreateDBClusterSnapshotMessage,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_db_cluster_snapshot_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_db_cluster_snapshot_request(
+        &self,
+        input: CreateDBClusterSnapshotMessage,
+    ) -> SignedRequest {
+        let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("CreateDBClusterSnapshot");
         let mut params = Params::new();
 
-        params.put("Action", "CreateDBInstance");
+        params.put("Action", "CreateDBClusterSnapshot");
         params.put("Version", "2014-10-31");
-        CreateDBInstanceMessageSerializer::serialize(&mut params, "", &input);
+        CreateDBClusterSnapshotMessageSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `create_db_instance`, also returning the metadata of the response.
+    pub async fn create_db_instance_with_metadata(
+        &self,
+        input: CreateDBInstanceMessage,
+    ) -> Result<(CreateDBInstanceResult, ResponseMetadata), RusotoError<CreateDBInstanceError>>
+    {
+        let request = self.build_create_db_instance_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(CreateDBInstanceError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -9665,34 +10320,59 @@ impl Docdb for DocdbClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Creates a new DB subnet group. DB subnet groups must contain at least one subnet in at least two Availability Zones in the AWS Region.</p>
-    async fn create_db_subnet_group(
+    /// Presigns the request `create_db_instance` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_db_instance(
         &self,
-        input: CreateDBSubnetGroupMessage,
-    ) -> Result<CreateDBSubnetGroupResult, RusotoError<CreateDBSubnetGroupError>> {
+        presigner: &rusoto_core::Presigner,
+        input: CreateDBInstanceMessage,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_db_instance_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_db_instance_request(&self, input: CreateDBInstanceMessage) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("CreateDBInstance");
         let mut params = Params::new();
 
-        params.put("Action", "CreateDBSubnetGroup");
+        params.put("Action", "CreateDBInstance");
         params.put("Version", "2014-10-31");
-        CreateDBSubnetGroupMessageSerializer::serialize(&mut params, "", &input);
+        CreateDBInstanceMessageSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `create_db_subnet_group`, also returning the metadata of the response.
+    pub async fn create_db_subnet_group_with_metadata(
+        &self,
+        input: CreateDBSubnetGroupMessage,
+    ) -> Result<(CreateDBSubnetGroupResult, ResponseMetadata), RusotoError<CreateDBSubnetGroupError>>
+    {
+        let request = self.build_create_db_subnet_group_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(CreateDBSubnetGroupError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -9714,34 +10394,61 @@ impl Docdb for DocdbClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p><p>Deletes a previously provisioned DB cluster. When you delete a DB cluster, all automated backups for that DB cluster are deleted and can&#39;t be recovered. Manual DB cluster snapshots of the specified DB cluster are not deleted.</p> <p/></p>
-    async fn delete_db_cluster(
+    /// Presigns the request `create_db_subnet_group` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_db_subnet_group(
         &self,
-        input: DeleteDBClusterMessage,
-    ) -> Result<DeleteDBClusterResult, RusotoError<DeleteDBClusterError>> {
+        presigner: &rusoto_core::Presigner,
+        input: CreateDBSubnetGroupMessage,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_db_subnet_group_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_db_subnet_group_request(
+        &self,
+        input: CreateDBSubnetGroupMessage,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("CreateDBSubnetGroup");
         let mut params = Params::new();
 
-        params.put("Action", "DeleteDBCluster");
+        params.put("Action", "CreateDBSubnetGroup");
         params.put("Version", "2014-10-31");
-        DeleteDBClusterMessageSerializer::serialize(&mut params, "", &input);
+        CreateDBSubnetGroupMessageSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `delete_db_cluster`, also returning the metadata of the response.
+    pub async fn delete_db_cluster_with_metadata(
+        &self,
+        input: DeleteDBClusterMessage,
+    ) -> Result<(DeleteDBClusterResult, ResponseMetadata), RusotoError<DeleteDBClusterError>> {
+        let request = self.build_delete_db_cluster_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(DeleteDBClusterError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -9763,62 +10470,113 @@ impl Docdb for DocdbClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Deletes a specified DB cluster parameter group. The DB cluster parameter group to be deleted can't be associated with any DB clusters.</p>
-    async fn delete_db_cluster_parameter_group(
+    /// Presigns the request `delete_db_cluster` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_db_cluster(
         &self,
-        input: DeleteDBClusterParameterGroupMessage,
-    ) -> Result<(), RusotoError<DeleteDBClusterParameterGroupError>> {
+        presigner: &rusoto_core::Presigner,
+        input: DeleteDBClusterMessage,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_db_cluster_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_db_cluster_request(&self, input: DeleteDBClusterMessage) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("DeleteDBCluster");
         let mut params = Params::new();
 
-        params.put("Action", "DeleteDBClusterParameterGroup");
+        params.put("Action", "DeleteDBCluster");
         params.put("Version", "2014-10-31");
-        DeleteDBClusterParameterGroupMessageSerializer::serialize(&mut params, "", &input);
+        DeleteDBClusterMessageSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `delete_db_cluster_parameter_group`, also returning the metadata of the response.
+    pub async fn delete_db_cluster_parameter_group_with_metadata(
+        &self,
+        input: DeleteDBClusterParameterGroupMessage,
+    ) -> Result<((), ResponseMetadata), RusotoError<DeleteDBClusterParameterGroupError>> {
+        let request = self.build_delete_db_cluster_parameter_group_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(DeleteDBClusterParameterGroupError::from_response(response));
         }
 
         std::mem::drop(response);
-        Ok(())
+        Ok(((), metadata))
     }
 
-    /// <p><p>Deletes a DB cluster snapshot. If the snapshot is being copied, the copy operation is terminated.</p> <note> <p>The DB cluster snapshot must be in the <code>available</code> state to be deleted.</p> </note></p>
-    async fn delete_db_cluster_snapshot(
+    /// Presigns the request `delete_db_cluster_parameter_group` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_db_cluster_parameter_group(
         &self,
-        input: DeleteDBClusterSnapshotMessage,
-    ) -> Result<DeleteDBClusterSnapshotResult, RusotoError<DeleteDBClusterSnapshotError>> {
+        presigner: &rusoto_core::Presigner,
+        input: DeleteDBClusterParameterGroupMessage,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_db_cluster_parameter_group_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_db_cluster_parameter_group_request(
+        &self,
+        input: DeleteDBClusterParameterGroupMessage,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("DeleteDBClusterParameterGroup");
         let mut params = Params::new();
 
-        params.put("Action", "DeleteDBClusterSnapshot");
+        params.put("Action", "DeleteDBClusterParameterGroup");
         params.put("Version", "2014-10-31");
-        DeleteDBClusterSnapshotMessageSerializer::serialize(&mut params, "", &input);
+        DeleteDBClusterParameterGroupMessageSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `delete_db_cluster_snapshot`, also returning the metadata of the response.
+    pub async fn delete_db_cluster_snapshot_with_metadata(
+        &self,
+        input: DeleteDBClusterSnapshotMessage,
+    ) -> Result<
+        (DeleteDBClusterSnapshotResult, ResponseMetadata),
+        RusotoError<DeleteDBClusterSnapshotError>,
+    > {
+        let request = self.build_delete_db_cluster_snapshot_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(DeleteDBClusterSnapshotError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -9840,34 +10598,62 @@ impl Docdb for DocdbClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Deletes a previously provisioned DB instance. </p>
-    async fn delete_db_instance(
+    /// Presigns the request `delete_db_cluster_snapshot` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_db_cluster_snapshot(
         &self,
-        input: DeleteDBInstanceMessage,
-    ) -> Result<DeleteDBInstanceResult, RusotoError<DeleteDBInstanceError>> {
+        presigner: &rusoto_core::Presigner,
+        input: DeleteDBClusterSnapshotMessage,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_db_cluster_snapshot_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_db_cluster_snapshot_request(
+        &self,
+        input: DeleteDBClusterSnapshotMessage,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("DeleteDBClusterSnapshot");
         let mut params = Params::new();
 
-        params.put("Action", "DeleteDBInstance");
+        params.put("Action", "DeleteDBClusterSnapshot");
         params.put("Version", "2014-10-31");
-        DeleteDBInstanceMessageSerializer::serialize(&mut params, "", &input);
+        DeleteDBClusterSnapshotMessageSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `delete_db_instance`, also returning the metadata of the response.
+    pub async fn delete_db_instance_with_metadata(
+        &self,
+        input: DeleteDBInstanceMessage,
+    ) -> Result<(DeleteDBInstanceResult, ResponseMetadata), RusotoError<DeleteDBInstanceError>>
+    {
+        let request = self.build_delete_db_instance_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(DeleteDBInstanceError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -9889,62 +10675,111 @@ impl Docdb for DocdbClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p><p>Deletes a DB subnet group.</p> <note> <p>The specified database subnet group must not be associated with any DB instances.</p> </note></p>
-    async fn delete_db_subnet_group(
+    /// Presigns the request `delete_db_instance` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_db_instance(
         &self,
-        input: DeleteDBSubnetGroupMessage,
-    ) -> Result<(), RusotoError<DeleteDBSubnetGroupError>> {
+        presigner: &rusoto_core::Presigner,
+        input: DeleteDBInstanceMessage,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_db_instance_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_db_instance_request(&self, input: DeleteDBInstanceMessage) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("DeleteDBInstance");
         let mut params = Params::new();
 
-        params.put("Action", "DeleteDBSubnetGroup");
+        params.put("Action", "DeleteDBInstance");
         params.put("Version", "2014-10-31");
-        DeleteDBSubnetGroupMessageSerializer::serialize(&mut params, "", &input);
+        DeleteDBInstanceMessageSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `delete_db_subnet_group`, also returning the metadata of the response.
+    pub async fn delete_db_subnet_group_with_metadata(
+        &self,
+        input: DeleteDBSubnetGroupMessage,
+    ) -> Result<((), ResponseMetadata), RusotoError<DeleteDBSubnetGroupError>> {
+        let request = self.build_delete_db_subnet_group_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(DeleteDBSubnetGroupError::from_response(response));
         }
 
         std::mem::drop(response);
-        Ok(())
+        Ok(((), metadata))
     }
 
-    /// <p>Returns a list of certificate authority (CA) certificates provided by Amazon RDS for this AWS account.</p>
-    async fn describe_certificates(
+    /// Presigns the request `delete_db_subnet_group` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_db_subnet_group(
         &self,
-        input: DescribeCertificatesMessage,
-    ) -> Result<CertificateMessage, RusotoError<DescribeCertificatesError>> {
+        presigner: &rusoto_core::Presigner,
+        input: DeleteDBSubnetGroupMessage,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_db_subnet_group_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_db_subnet_group_request(
+        &self,
+        input: DeleteDBSubnetGroupMessage,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("DeleteDBSubnetGroup");
         let mut params = Params::new();
 
-        params.put("Action", "DescribeCertificates");
+        params.put("Action", "DeleteDBSubnetGroup");
         params.put("Version", "2014-10-31");
-        DescribeCertificatesMessageSerializer::serialize(&mut params, "", &input);
+        DeleteDBSubnetGroupMessageSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `describe_certificates`, also returning the metadata of the response.
+    pub async fn describe_certificates_with_metadata(
+        &self,
+        input: DescribeCertificatesMessage,
+    ) -> Result<(CertificateMessage, ResponseMetadata), RusotoError<DescribeCertificatesError>>
+    {
+        let request = self.build_describe_certificates_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(DescribeCertificatesError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -9966,37 +10801,66 @@ impl Docdb for DocdbClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Returns a list of <code>DBClusterParameterGroup</code> descriptions. If a <code>DBClusterParameterGroupName</code> parameter is specified, the list contains only the description of the specified DB cluster parameter group. </p>
-    async fn describe_db_cluster_parameter_groups(
+    /// Presigns the request `describe_certificates` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_certificates(
         &self,
-        input: DescribeDBClusterParameterGroupsMessage,
-    ) -> Result<DBClusterParameterGroupsMessage, RusotoError<DescribeDBClusterParameterGroupsError>>
-    {
+        presigner: &rusoto_core::Presigner,
+        input: DescribeCertificatesMessage,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_certificates_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_certificates_request(
+        &self,
+        input: DescribeCertificatesMessage,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("DescribeCertificates");
         let mut params = Params::new();
 
-        params.put("Action", "DescribeDBClusterParameterGroups");
+        params.put("Action", "DescribeCertificates");
         params.put("Version", "2014-10-31");
-        DescribeDBClusterParameterGroupsMessageSerializer::serialize(&mut params, "", &input);
+        DescribeCertificatesMessageSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `describe_db_cluster_parameter_groups`, also returning the metadata of the response.
+    pub async fn describe_db_cluster_parameter_groups_with_metadata(
+        &self,
+        input: DescribeDBClusterParameterGroupsMessage,
+    ) -> Result<
+        (DBClusterParameterGroupsMessage, ResponseMetadata),
+        RusotoError<DescribeDBClusterParameterGroupsError>,
+    > {
+        let request = self.build_describe_db_cluster_parameter_groups_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(DescribeDBClusterParameterGroupsError::from_response(
                 response,
             ));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -10018,34 +10882,64 @@ impl Docdb for DocdbClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Returns the detailed parameter list for a particular DB cluster parameter group.</p>
-    async fn describe_db_cluster_parameters(
+    /// Presigns the request `describe_db_cluster_parameter_groups` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_db_cluster_parameter_groups(
         &self,
-        input: DescribeDBClusterParametersMessage,
-    ) -> Result<DBClusterParameterGroupDetails, RusotoError<DescribeDBClusterParametersError>> {
+        presigner: &rusoto_core::Presigner,
+        input: DescribeDBClusterParameterGroupsMessage,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_db_cluster_parameter_groups_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_db_cluster_parameter_groups_request(
+        &self,
+        input: DescribeDBClusterParameterGroupsMessage,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("DescribeDBClusterParameterGroups");
         let mut params = Params::new();
 
-        params.put("Action", "DescribeDBClusterParameters");
+        params.put("Action", "DescribeDBClusterParameterGroups");
         params.put("Version", "2014-10-31");
-        DescribeDBClusterParametersMessageSerializer::serialize(&mut params, "", &input);
+        DescribeDBClusterParameterGroupsMessageSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `describe_db_cluster_parameters`, also returning the metadata of the response.
+    pub async fn describe_db_cluster_parameters_with_metadata(
+        &self,
+        input: DescribeDBClusterParametersMessage,
+    ) -> Result<
+        (DBClusterParameterGroupDetails, ResponseMetadata),
+        RusotoError<DescribeDBClusterParametersError>,
+    > {
+        let request = self.build_describe_db_cluster_parameters_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(DescribeDBClusterParametersError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -10067,39 +10961,66 @@ impl Docdb for DocdbClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Returns a list of DB cluster snapshot attribute names and values for a manual DB cluster snapshot.</p> <p>When you share snapshots with other AWS accounts, <code>DescribeDBClusterSnapshotAttributes</code> returns the <code>restore</code> attribute and a list of IDs for the AWS accounts that are authorized to copy or restore the manual DB cluster snapshot. If <code>all</code> is included in the list of values for the <code>restore</code> attribute, then the manual DB cluster snapshot is public and can be copied or restored by all AWS accounts.</p>
-    async fn describe_db_cluster_snapshot_attributes(
+    /// Presigns the request `describe_db_cluster_parameters` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_db_cluster_parameters(
         &self,
-        input: DescribeDBClusterSnapshotAttributesMessage,
-    ) -> Result<
-        DescribeDBClusterSnapshotAttributesResult,
-        RusotoError<DescribeDBClusterSnapshotAttributesError>,
-    > {
+        presigner: &rusoto_core::Presigner,
+        input: DescribeDBClusterParametersMessage,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_db_cluster_parameters_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_db_cluster_parameters_request(
+        &self,
+        input: DescribeDBClusterParametersMessage,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("DescribeDBClusterParameters");
         let mut params = Params::new();
 
-        params.put("Action", "DescribeDBClusterSnapshotAttributes");
+        params.put("Action", "DescribeDBClusterParameters");
         params.put("Version", "2014-10-31");
-        DescribeDBClusterSnapshotAttributesMessageSerializer::serialize(&mut params, "", &input);
+        DescribeDBClusterParametersMessageSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `describe_db_cluster_snapshot_attributes`, also returning the metadata of the response.
+    pub async fn describe_db_cluster_snapshot_attributes_with_metadata(
+        &self,
+        input: DescribeDBClusterSnapshotAttributesMessage,
+    ) -> Result<
+        (DescribeDBClusterSnapshotAttributesResult, ResponseMetadata),
+        RusotoError<DescribeDBClusterSnapshotAttributesError>,
+    > {
+        let request = self.build_describe_db_cluster_snapshot_attributes_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(DescribeDBClusterSnapshotAttributesError::from_response(
                 response,
             ));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -10121,34 +11042,64 @@ impl Docdb for DocdbClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Returns information about DB cluster snapshots. This API operation supports pagination.</p>
-    async fn describe_db_cluster_snapshots(
+    /// Presigns the request `describe_db_cluster_snapshot_attributes` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_db_cluster_snapshot_attributes(
         &self,
-        input: DescribeDBClusterSnapshotsMessage,
-    ) -> Result<DBClusterSnapshotMessage, RusotoError<DescribeDBClusterSnapshotsError>> {
+        presigner: &rusoto_core::Presigner,
+        input: DescribeDBClusterSnapshotAttributesMessage,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_db_cluster_snapshot_attributes_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_db_cluster_snapshot_attributes_request(
+        &self,
+        input: DescribeDBClusterSnapshotAttributesMessage,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("DescribeDBClusterSnapshotAttributes");
         let mut params = Params::new();
 
-        params.put("Action", "DescribeDBClusterSnapshots");
+        params.put("Action", "DescribeDBClusterSnapshotAttributes");
         params.put("Version", "2014-10-31");
-        DescribeDBClusterSnapshotsMessageSerializer::serialize(&mut params, "", &input);
+        DescribeDBClusterSnapshotAttributesMessageSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `describe_db_cluster_snapshots`, also returning the metadata of the response.
+    pub async fn describe_db_cluster_snapshots_with_metadata(
+        &self,
+        input: DescribeDBClusterSnapshotsMessage,
+    ) -> Result<
+        (DBClusterSnapshotMessage, ResponseMetadata),
+        RusotoError<DescribeDBClusterSnapshotsError>,
+    > {
+        let request = self.build_describe_db_cluster_snapshots_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(DescribeDBClusterSnapshotsError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -10170,34 +11121,61 @@ impl Docdb for DocdbClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Returns information about provisioned Amazon DocumentDB DB clusters. This API operation supports pagination.</p>
-    async fn describe_db_clusters(
+    /// Presigns the request `describe_db_cluster_snapshots` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_db_cluster_snapshots(
         &self,
-        input: DescribeDBClustersMessage,
-    ) -> Result<DBClusterMessage, RusotoError<DescribeDBClustersError>> {
+        presigner: &rusoto_core::Presigner,
+        input: DescribeDBClusterSnapshotsMessage,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_db_cluster_snapshots_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_db_cluster_snapshots_request(
+        &self,
+        input: DescribeDBClusterSnapshotsMessage,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("DescribeDBClusterSnapshots");
         let mut params = Params::new();
 
-        params.put("Action", "DescribeDBClusters");
+        params.put("Action", "DescribeDBClusterSnapshots");
         params.put("Version", "2014-10-31");
-        DescribeDBClustersMessageSerializer::serialize(&mut params, "", &input);
+        DescribeDBClusterSnapshotsMessageSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `describe_db_clusters`, also returning the metadata of the response.
+    pub async fn describe_db_clusters_with_metadata(
+        &self,
+        input: DescribeDBClustersMessage,
+    ) -> Result<(DBClusterMessage, ResponseMetadata), RusotoError<DescribeDBClustersError>> {
+        let request = self.build_describe_db_clusters_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(DescribeDBClustersError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -10217,34 +11195,64 @@ impl Docdb for DocdbClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Returns a list of the available DB engines.</p>
-    async fn describe_db_engine_versions(
+    /// Presigns the request `describe_db_clusters` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_db_clusters(
         &self,
-        input: DescribeDBEngineVersionsMessage,
-    ) -> Result<DBEngineVersionMessage, RusotoError<DescribeDBEngineVersionsError>> {
+        presigner: &rusoto_core::Presigner,
+        input: DescribeDBClustersMessage,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_db_clusters_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_db_clusters_request(
+        &self,
+        input: DescribeDBClustersMessage,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("DescribeDBClusters");
         let mut params = Params::new();
 
-        params.put("Action", "DescribeDBEngineVersions");
+        params.put("Action", "DescribeDBClusters");
         params.put("Version", "2014-10-31");
-        DescribeDBEngineVersionsMessageSerializer::serialize(&mut params, "", &input);
+        DescribeDBClustersMessageSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `describe_db_engine_versions`, also returning the metadata of the response.
+    pub async fn describe_db_engine_versions_with_metadata(
+        &self,
+        input: DescribeDBEngineVersionsMessage,
+    ) -> Result<
+        (DBEngineVersionMessage, ResponseMetadata),
+        RusotoError<DescribeDBEngineVersionsError>,
+    > {
+        let request = self.build_describe_db_engine_versions_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(DescribeDBEngineVersionsError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -10266,34 +11274,61 @@ impl Docdb for DocdbClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Returns information about provisioned Amazon DocumentDB instances. This API supports pagination.</p>
-    async fn describe_db_instances(
+    /// Presigns the request `describe_db_engine_versions` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_db_engine_versions(
         &self,
-        input: DescribeDBInstancesMessage,
-    ) -> Result<DBInstanceMessage, RusotoError<DescribeDBInstancesError>> {
+        presigner: &rusoto_core::Presigner,
+        input: DescribeDBEngineVersionsMessage,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_db_engine_versions_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_db_engine_versions_request(
+        &self,
+        input: DescribeDBEngineVersionsMessage,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("DescribeDBEngineVersions");
         let mut params = Params::new();
 
-        params.put("Action", "DescribeDBInstances");
+        params.put("Action", "DescribeDBEngineVersions");
         params.put("Version", "2014-10-31");
-        DescribeDBInstancesMessageSerializer::serialize(&mut params, "", &input);
+        DescribeDBEngineVersionsMessageSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `describe_db_instances`, also returning the metadata of the response.
+    pub async fn describe_db_instances_with_metadata(
+        &self,
+        input: DescribeDBInstancesMessage,
+    ) -> Result<(DBInstanceMessage, ResponseMetadata), RusotoError<DescribeDBInstancesError>> {
+        let request = self.build_describe_db_instances_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(DescribeDBInstancesError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -10315,34 +11350,62 @@ impl Docdb for DocdbClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Returns a list of <code>DBSubnetGroup</code> descriptions. If a <code>DBSubnetGroupName</code> is specified, the list will contain only the descriptions of the specified <code>DBSubnetGroup</code>.</p>
-    async fn describe_db_subnet_groups(
+    /// Presigns the request `describe_db_instances` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_db_instances(
         &self,
-        input: DescribeDBSubnetGroupsMessage,
-    ) -> Result<DBSubnetGroupMessage, RusotoError<DescribeDBSubnetGroupsError>> {
+        presigner: &rusoto_core::Presigner,
+        input: DescribeDBInstancesMessage,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_db_instances_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_db_instances_request(
+        &self,
+        input: DescribeDBInstancesMessage,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("DescribeDBInstances");
         let mut params = Params::new();
 
-        params.put("Action", "DescribeDBSubnetGroups");
+        params.put("Action", "DescribeDBInstances");
         params.put("Version", "2014-10-31");
-        DescribeDBSubnetGroupsMessageSerializer::serialize(&mut params, "", &input);
+        DescribeDBInstancesMessageSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `describe_db_subnet_groups`, also returning the metadata of the response.
+    pub async fn describe_db_subnet_groups_with_metadata(
+        &self,
+        input: DescribeDBSubnetGroupsMessage,
+    ) -> Result<(DBSubnetGroupMessage, ResponseMetadata), RusotoError<DescribeDBSubnetGroupsError>>
+    {
+        let request = self.build_describe_db_subnet_groups_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(DescribeDBSubnetGroupsError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -10364,39 +11427,69 @@ impl Docdb for DocdbClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Returns the default engine and system parameter information for the cluster database engine.</p>
-    async fn describe_engine_default_cluster_parameters(
+    /// Presigns the request `describe_db_subnet_groups` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_db_subnet_groups(
         &self,
-        input: DescribeEngineDefaultClusterParametersMessage,
-    ) -> Result<
-        DescribeEngineDefaultClusterParametersResult,
-        RusotoError<DescribeEngineDefaultClusterParametersError>,
-    > {
+        presigner: &rusoto_core::Presigner,
+        input: DescribeDBSubnetGroupsMessage,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_db_subnet_groups_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_db_subnet_groups_request(
+        &self,
+        input: DescribeDBSubnetGroupsMessage,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("DescribeDBSubnetGroups");
         let mut params = Params::new();
 
-        params.put("Action", "DescribeEngineDefaultClusterParameters");
+        params.put("Action", "DescribeDBSubnetGroups");
         params.put("Version", "2014-10-31");
-        DescribeEngineDefaultClusterParametersMessageSerializer::serialize(&mut params, "", &input);
+        DescribeDBSubnetGroupsMessageSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `describe_engine_default_cluster_parameters`, also returning the metadata of the response.
+    pub async fn describe_engine_default_cluster_parameters_with_metadata(
+        &self,
+        input: DescribeEngineDefaultClusterParametersMessage,
+    ) -> Result<
+        (
+            DescribeEngineDefaultClusterParametersResult,
+            ResponseMetadata,
+        ),
+        RusotoError<DescribeEngineDefaultClusterParametersError>,
+    > {
+        let request = self.build_describe_engine_default_cluster_parameters_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(DescribeEngineDefaultClusterParametersError::from_response(
                 response,
             ));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -10418,34 +11511,62 @@ impl Docdb for DocdbClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Displays a list of categories for all event source types, or, if specified, for a specified source type. </p>
-    async fn describe_event_categories(
+    /// Presigns the request `describe_engine_default_cluster_parameters` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_engine_default_cluster_parameters(
         &self,
-        input: DescribeEventCategoriesMessage,
-    ) -> Result<EventCategoriesMessage, RusotoError<DescribeEventCategoriesError>> {
+        presigner: &rusoto_core::Presigner,
+        input: DescribeEngineDefaultClusterParametersMessage,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_engine_default_cluster_parameters_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_engine_default_cluster_parameters_request(
+        &self,
+        input: DescribeEngineDefaultClusterParametersMessage,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("DescribeEngineDefaultClusterParameters");
         let mut params = Params::new();
 
-        params.put("Action", "DescribeEventCategories");
+        params.put("Action", "DescribeEngineDefaultClusterParameters");
         params.put("Version", "2014-10-31");
-        DescribeEventCategoriesMessageSerializer::serialize(&mut params, "", &input);
+        DescribeEngineDefaultClusterParametersMessageSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `describe_event_categories`, also returning the metadata of the response.
+    pub async fn describe_event_categories_with_metadata(
+        &self,
+        input: DescribeEventCategoriesMessage,
+    ) -> Result<(EventCategoriesMessage, ResponseMetadata), RusotoError<DescribeEventCategoriesError>>
+    {
+        let request = self.build_describe_event_categories_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(DescribeEventCategoriesError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -10467,34 +11588,61 @@ impl Docdb for DocdbClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Returns events related to DB instances, DB security groups, DB snapshots, and DB parameter groups for the past 14 days. You can obtain events specific to a particular DB instance, DB security group, DB snapshot, or DB parameter group by providing the name as a parameter. By default, the events of the past hour are returned.</p>
-    async fn describe_events(
+    /// Presigns the request `describe_event_categories` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_event_categories(
         &self,
-        input: DescribeEventsMessage,
-    ) -> Result<EventsMessage, RusotoError<DescribeEventsError>> {
+        presigner: &rusoto_core::Presigner,
+        input: DescribeEventCategoriesMessage,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_event_categories_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_event_categories_request(
+        &self,
+        input: DescribeEventCategoriesMessage,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("DescribeEventCategories");
         let mut params = Params::new();
 
-        params.put("Action", "DescribeEvents");
+        params.put("Action", "DescribeEventCategories");
         params.put("Version", "2014-10-31");
-        DescribeEventsMessageSerializer::serialize(&mut params, "", &input);
+        DescribeEventCategoriesMessageSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `describe_events`, also returning the metadata of the response.
+    pub async fn describe_events_with_metadata(
+        &self,
+        input: DescribeEventsMessage,
+    ) -> Result<(EventsMessage, ResponseMetadata), RusotoError<DescribeEventsError>> {
+        let request = self.build_describe_events_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(DescribeEventsError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -10513,39 +11661,63 @@ impl Docdb for DocdbClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Returns a list of orderable DB instance options for the specified engine.</p>
-    async fn describe_orderable_db_instance_options(
+    /// Presigns the request `describe_events` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_events(
         &self,
-        input: DescribeOrderableDBInstanceOptionsMessage,
-    ) -> Result<
-        OrderableDBInstanceOptionsMessage,
-        RusotoError<DescribeOrderableDBInstanceOptionsError>,
-    > {
+        presigner: &rusoto_core::Presigner,
+        input: DescribeEventsMessage,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_events_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_events_request(&self, input: DescribeEventsMessage) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("DescribeEvents");
         let mut params = Params::new();
 
-        params.put("Action", "DescribeOrderableDBInstanceOptions");
+        params.put("Action", "DescribeEvents");
         params.put("Version", "2014-10-31");
-        DescribeOrderableDBInstanceOptionsMessageSerializer::serialize(&mut params, "", &input);
+        DescribeEventsMessageSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `describe_orderable_db_instance_options`, also returning the metadata of the response.
+    pub async fn describe_orderable_db_instance_options_with_metadata(
+        &self,
+        input: DescribeOrderableDBInstanceOptionsMessage,
+    ) -> Result<
+        (OrderableDBInstanceOptionsMessage, ResponseMetadata),
+        RusotoError<DescribeOrderableDBInstanceOptionsError>,
+    > {
+        let request = self.build_describe_orderable_db_instance_options_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(DescribeOrderableDBInstanceOptionsError::from_response(
                 response,
             ));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -10567,37 +11739,66 @@ impl Docdb for DocdbClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Returns a list of resources (for example, DB instances) that have at least one pending maintenance action.</p>
-    async fn describe_pending_maintenance_actions(
+    /// Presigns the request `describe_orderable_db_instance_options` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_orderable_db_instance_options(
         &self,
-        input: DescribePendingMaintenanceActionsMessage,
-    ) -> Result<PendingMaintenanceActionsMessage, RusotoError<DescribePendingMaintenanceActionsError>>
-    {
+        presigner: &rusoto_core::Presigner,
+        input: DescribeOrderableDBInstanceOptionsMessage,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_orderable_db_instance_options_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_orderable_db_instance_options_request(
+        &self,
+        input: DescribeOrderableDBInstanceOptionsMessage,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("DescribeOrderableDBInstanceOptions");
         let mut params = Params::new();
 
-        params.put("Action", "DescribePendingMaintenanceActions");
+        params.put("Action", "DescribeOrderableDBInstanceOptions");
         params.put("Version", "2014-10-31");
-        DescribePendingMaintenanceActionsMessageSerializer::serialize(&mut params, "", &input);
+        DescribeOrderableDBInstanceOptionsMessageSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `describe_pending_maintenance_actions`, also returning the metadata of the response.
+    pub async fn describe_pending_maintenance_actions_with_metadata(
+        &self,
+        input: DescribePendingMaintenanceActionsMessage,
+    ) -> Result<
+        (PendingMaintenanceActionsMessage, ResponseMetadata),
+        RusotoError<DescribePendingMaintenanceActionsError>,
+    > {
+        let request = self.build_describe_pending_maintenance_actions_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(DescribePendingMaintenanceActionsError::from_response(
                 response,
             ));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -10619,34 +11820,62 @@ impl Docdb for DocdbClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Forces a failover for a DB cluster.</p> <p>A failover for a DB cluster promotes one of the Amazon DocumentDB replicas (read-only instances) in the DB cluster to be the primary instance (the cluster writer).</p> <p>If the primary instance fails, Amazon DocumentDB automatically fails over to an Amazon DocumentDB replica, if one exists. You can force a failover when you want to simulate a failure of a primary instance for testing.</p>
-    async fn failover_db_cluster(
+    /// Presigns the request `describe_pending_maintenance_actions` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_pending_maintenance_actions(
         &self,
-        input: FailoverDBClusterMessage,
-    ) -> Result<FailoverDBClusterResult, RusotoError<FailoverDBClusterError>> {
+        presigner: &rusoto_core::Presigner,
+        input: DescribePendingMaintenanceActionsMessage,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_pending_maintenance_actions_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_pending_maintenance_actions_request(
+        &self,
+        input: DescribePendingMaintenanceActionsMessage,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("DescribePendingMaintenanceActions");
         let mut params = Params::new();
 
-        params.put("Action", "FailoverDBCluster");
+        params.put("Action", "DescribePendingMaintenanceActions");
         params.put("Version", "2014-10-31");
-        FailoverDBClusterMessageSerializer::serialize(&mut params, "", &input);
+        DescribePendingMaintenanceActionsMessageSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `failover_db_cluster`, also returning the metadata of the response.
+    pub async fn failover_db_cluster_with_metadata(
+        &self,
+        input: FailoverDBClusterMessage,
+    ) -> Result<(FailoverDBClusterResult, ResponseMetadata), RusotoError<FailoverDBClusterError>>
+    {
+        let request = self.build_failover_db_cluster_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(FailoverDBClusterError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -10668,34 +11897,58 @@ impl Docdb for DocdbClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Lists all tags on an Amazon DocumentDB resource.</p>
-    async fn list_tags_for_resource(
+    /// Presigns the request `failover_db_cluster` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_failover_db_cluster(
         &self,
-        input: ListTagsForResourceMessage,
-    ) -> Result<TagListMessage, RusotoError<ListTagsForResourceError>> {
+        presigner: &rusoto_core::Presigner,
+        input: FailoverDBClusterMessage,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_failover_db_cluster_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_failover_db_cluster_request(&self, input: FailoverDBClusterMessage) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("FailoverDBCluster");
         let mut params = Params::new();
 
-        params.put("Action", "ListTagsForResource");
+        params.put("Action", "FailoverDBCluster");
         params.put("Version", "2014-10-31");
-        ListTagsForResourceMessageSerializer::serialize(&mut params, "", &input);
+        FailoverDBClusterMessageSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `list_tags_for_resource`, also returning the metadata of the response.
+    pub async fn list_tags_for_resource_with_metadata(
+        &self,
+        input: ListTagsForResourceMessage,
+    ) -> Result<(TagListMessage, ResponseMetadata), RusotoError<ListTagsForResourceError>> {
+        let request = self.build_list_tags_for_resource_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(ListTagsForResourceError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -10715,34 +11968,61 @@ impl Docdb for DocdbClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Modifies a setting for an Amazon DocumentDB DB cluster. You can change one or more database configuration parameters by specifying these parameters and the new values in the request. </p>
-    async fn modify_db_cluster(
+    /// Presigns the request `list_tags_for_resource` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_tags_for_resource(
         &self,
-        input: ModifyDBClusterMessage,
-    ) -> Result<ModifyDBClusterResult, RusotoError<ModifyDBClusterError>> {
+        presigner: &rusoto_core::Presigner,
+        input: ListTagsForResourceMessage,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_tags_for_resource_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_tags_for_resource_request(
+        &self,
+        input: ListTagsForResourceMessage,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("ListTagsForResource");
         let mut params = Params::new();
 
-        params.put("Action", "ModifyDBCluster");
+        params.put("Action", "ListTagsForResource");
         params.put("Version", "2014-10-31");
-        ModifyDBClusterMessageSerializer::serialize(&mut params, "", &input);
+        ListTagsForResourceMessageSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `modify_db_cluster`, also returning the metadata of the response.
+    pub async fn modify_db_cluster_with_metadata(
+        &self,
+        input: ModifyDBClusterMessage,
+    ) -> Result<(ModifyDBClusterResult, ResponseMetadata), RusotoError<ModifyDBClusterError>> {
+        let request = self.build_modify_db_cluster_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(ModifyDBClusterError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -10764,35 +12044,61 @@ impl Docdb for DocdbClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p><p> Modifies the parameters of a DB cluster parameter group. To modify more than one parameter, submit a list of the following: <code>ParameterName</code>, <code>ParameterValue</code>, and <code>ApplyMethod</code>. A maximum of 20 parameters can be modified in a single request. </p> <note> <p>Changes to dynamic parameters are applied immediately. Changes to static parameters require a reboot or maintenance window before the change can take effect.</p> </note> <important> <p>After you create a DB cluster parameter group, you should wait at least 5 minutes before creating your first DB cluster that uses that DB cluster parameter group as the default parameter group. This allows Amazon DocumentDB to fully complete the create action before the parameter group is used as the default for a new DB cluster. This step is especially important for parameters that are critical when creating the default database for a DB cluster, such as the character set for the default database defined by the <code>character<em>set</em>database</code> parameter.</p> </important></p>
-    async fn modify_db_cluster_parameter_group(
+    /// Presigns the request `modify_db_cluster` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_modify_db_cluster(
         &self,
-        input: ModifyDBClusterParameterGroupMessage,
-    ) -> Result<DBClusterParameterGroupNameMessage, RusotoError<ModifyDBClusterParameterGroupError>>
-    {
+        presigner: &rusoto_core::Presigner,
+        input: ModifyDBClusterMessage,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_modify_db_cluster_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_modify_db_cluster_request(&self, input: ModifyDBClusterMessage) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("ModifyDBCluster");
         let mut params = Params::new();
 
-        params.put("Action", "ModifyDBClusterParameterGroup");
+        params.put("Action", "ModifyDBCluster");
         params.put("Version", "2014-10-31");
-        ModifyDBClusterParameterGroupMessageSerializer::serialize(&mut params, "", &input);
+        ModifyDBClusterMessageSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `modify_db_cluster_parameter_group`, also returning the metadata of the response.
+    pub async fn modify_db_cluster_parameter_group_with_metadata(
+        &self,
+        input: ModifyDBClusterParameterGroupMessage,
+    ) -> Result<
+        (DBClusterParameterGroupNameMessage, ResponseMetadata),
+        RusotoError<ModifyDBClusterParameterGroupError>,
+    > {
+        let request = self.build_modify_db_cluster_parameter_group_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(ModifyDBClusterParameterGroupError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -10814,39 +12120,66 @@ impl Docdb for DocdbClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Adds an attribute and values to, or removes an attribute and values from, a manual DB cluster snapshot.</p> <p>To share a manual DB cluster snapshot with other AWS accounts, specify <code>restore</code> as the <code>AttributeName</code>, and use the <code>ValuesToAdd</code> parameter to add a list of IDs of the AWS accounts that are authorized to restore the manual DB cluster snapshot. Use the value <code>all</code> to make the manual DB cluster snapshot public, which means that it can be copied or restored by all AWS accounts. Do not add the <code>all</code> value for any manual DB cluster snapshots that contain private information that you don't want available to all AWS accounts. If a manual DB cluster snapshot is encrypted, it can be shared, but only by specifying a list of authorized AWS account IDs for the <code>ValuesToAdd</code> parameter. You can't use <code>all</code> as a value for that parameter in this case.</p>
-    async fn modify_db_cluster_snapshot_attribute(
+    /// Presigns the request `modify_db_cluster_parameter_group` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_modify_db_cluster_parameter_group(
         &self,
-        input: ModifyDBClusterSnapshotAttributeMessage,
-    ) -> Result<
-        ModifyDBClusterSnapshotAttributeResult,
-        RusotoError<ModifyDBClusterSnapshotAttributeError>,
-    > {
+        presigner: &rusoto_core::Presigner,
+        input: ModifyDBClusterParameterGroupMessage,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_modify_db_cluster_parameter_group_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_modify_db_cluster_parameter_group_request(
+        &self,
+        input: ModifyDBClusterParameterGroupMessage,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("ModifyDBClusterParameterGroup");
         let mut params = Params::new();
 
-        params.put("Action", "ModifyDBClusterSnapshotAttribute");
+        params.put("Action", "ModifyDBClusterParameterGroup");
         params.put("Version", "2014-10-31");
-        ModifyDBClusterSnapshotAttributeMessageSerializer::serialize(&mut params, "", &input);
+        ModifyDBClusterParameterGroupMessageSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `modify_db_cluster_snapshot_attribute`, also returning the metadata of the response.
+    pub async fn modify_db_cluster_snapshot_attribute_with_metadata(
+        &self,
+        input: ModifyDBClusterSnapshotAttributeMessage,
+    ) -> Result<
+        (ModifyDBClusterSnapshotAttributeResult, ResponseMetadata),
+        RusotoError<ModifyDBClusterSnapshotAttributeError>,
+    > {
+        let request = self.build_modify_db_cluster_snapshot_attribute_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(ModifyDBClusterSnapshotAttributeError::from_response(
                 response,
             ));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -10868,34 +12201,62 @@ impl Docdb for DocdbClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Modifies settings for a DB instance. You can change one or more database configuration parameters by specifying these parameters and the new values in the request.</p>
-    async fn modify_db_instance(
+    /// Presigns the request `modify_db_cluster_snapshot_attribute` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_modify_db_cluster_snapshot_attribute(
         &self,
-        input: ModifyDBInstanceMessage,
-    ) -> Result<ModifyDBInstanceResult, RusotoError<ModifyDBInstanceError>> {
+        presigner: &rusoto_core::Presigner,
+        input: ModifyDBClusterSnapshotAttributeMessage,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_modify_db_cluster_snapshot_attribute_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_modify_db_cluster_snapshot_attribute_request(
+        &self,
+        input: ModifyDBClusterSnapshotAttributeMessage,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("ModifyDBClusterSnapshotAttribute");
         let mut params = Params::new();
 
-        params.put("Action", "ModifyDBInstance");
+        params.put("Action", "ModifyDBClusterSnapshotAttribute");
         params.put("Version", "2014-10-31");
-        ModifyDBInstanceMessageSerializer::serialize(&mut params, "", &input);
+        ModifyDBClusterSnapshotAttributeMessageSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `modify_db_instance`, also returning the metadata of the response.
+    pub async fn modify_db_instance_with_metadata(
+        &self,
+        input: ModifyDBInstanceMessage,
+    ) -> Result<(ModifyDBInstanceResult, ResponseMetadata), RusotoError<ModifyDBInstanceError>>
+    {
+        let request = self.build_modify_db_instance_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(ModifyDBInstanceError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -10917,34 +12278,59 @@ impl Docdb for DocdbClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Modifies an existing DB subnet group. DB subnet groups must contain at least one subnet in at least two Availability Zones in the AWS Region.</p>
-    async fn modify_db_subnet_group(
+    /// Presigns the request `modify_db_instance` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_modify_db_instance(
         &self,
-        input: ModifyDBSubnetGroupMessage,
-    ) -> Result<ModifyDBSubnetGroupResult, RusotoError<ModifyDBSubnetGroupError>> {
+        presigner: &rusoto_core::Presigner,
+        input: ModifyDBInstanceMessage,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_modify_db_instance_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_modify_db_instance_request(&self, input: ModifyDBInstanceMessage) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("ModifyDBInstance");
         let mut params = Params::new();
 
-        params.put("Action", "ModifyDBSubnetGroup");
+        params.put("Action", "ModifyDBInstance");
         params.put("Version", "2014-10-31");
-        ModifyDBSubnetGroupMessageSerializer::serialize(&mut params, "", &input);
+        ModifyDBInstanceMessageSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `modify_db_subnet_group`, also returning the metadata of the response.
+    pub async fn modify_db_subnet_group_with_metadata(
+        &self,
+        input: ModifyDBSubnetGroupMessage,
+    ) -> Result<(ModifyDBSubnetGroupResult, ResponseMetadata), RusotoError<ModifyDBSubnetGroupError>>
+    {
+        let request = self.build_modify_db_subnet_group_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(ModifyDBSubnetGroupError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -10966,34 +12352,62 @@ impl Docdb for DocdbClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>You might need to reboot your DB instance, usually for maintenance reasons. For example, if you make certain changes, or if you change the DB cluster parameter group that is associated with the DB instance, you must reboot the instance for the changes to take effect. </p> <p>Rebooting a DB instance restarts the database engine service. Rebooting a DB instance results in a momentary outage, during which the DB instance status is set to <i>rebooting</i>. </p>
-    async fn reboot_db_instance(
+    /// Presigns the request `modify_db_subnet_group` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_modify_db_subnet_group(
         &self,
-        input: RebootDBInstanceMessage,
-    ) -> Result<RebootDBInstanceResult, RusotoError<RebootDBInstanceError>> {
+        presigner: &rusoto_core::Presigner,
+        input: ModifyDBSubnetGroupMessage,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_modify_db_subnet_group_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_modify_db_subnet_group_request(
+        &self,
+        input: ModifyDBSubnetGroupMessage,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("ModifyDBSubnetGroup");
         let mut params = Params::new();
 
-        params.put("Action", "RebootDBInstance");
+        params.put("Action", "ModifyDBSubnetGroup");
         params.put("Version", "2014-10-31");
-        RebootDBInstanceMessageSerializer::serialize(&mut params, "", &input);
+        ModifyDBSubnetGroupMessageSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `reboot_db_instance`, also returning the metadata of the response.
+    pub async fn reboot_db_instance_with_metadata(
+        &self,
+        input: RebootDBInstanceMessage,
+    ) -> Result<(RebootDBInstanceResult, ResponseMetadata), RusotoError<RebootDBInstanceError>>
+    {
+        let request = self.build_reboot_db_instance_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(RebootDBInstanceError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -11015,63 +12429,113 @@ impl Docdb for DocdbClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Removes metadata tags from an Amazon DocumentDB resource.</p>
-    async fn remove_tags_from_resource(
+    /// Presigns the request `reboot_db_instance` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_reboot_db_instance(
         &self,
-        input: RemoveTagsFromResourceMessage,
-    ) -> Result<(), RusotoError<RemoveTagsFromResourceError>> {
+        presigner: &rusoto_core::Presigner,
+        input: RebootDBInstanceMessage,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_reboot_db_instance_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_reboot_db_instance_request(&self, input: RebootDBInstanceMessage) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("RebootDBInstance");
         let mut params = Params::new();
 
-        params.put("Action", "RemoveTagsFromResource");
+        params.put("Action", "RebootDBInstance");
         params.put("Version", "2014-10-31");
-        RemoveTagsFromResourceMessageSerializer::serialize(&mut params, "", &input);
+        RebootDBInstanceMessageSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `remove_tags_from_resource`, also returning the metadata of the response.
+    pub async fn remove_tags_from_resource_with_metadata(
+        &self,
+        input: RemoveTagsFromResourceMessage,
+    ) -> Result<((), ResponseMetadata), RusotoError<RemoveTagsFromResourceError>> {
+        let request = self.build_remove_tags_from_resource_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(RemoveTagsFromResourceError::from_response(response));
         }
 
         std::mem::drop(response);
-        Ok(())
+        Ok(((), metadata))
     }
 
-    /// <p> Modifies the parameters of a DB cluster parameter group to the default value. To reset specific parameters, submit a list of the following: <code>ParameterName</code> and <code>ApplyMethod</code>. To reset the entire DB cluster parameter group, specify the <code>DBClusterParameterGroupName</code> and <code>ResetAllParameters</code> parameters. </p> <p> When you reset the entire group, dynamic parameters are updated immediately and static parameters are set to <code>pending-reboot</code> to take effect on the next DB instance reboot.</p>
-    async fn reset_db_cluster_parameter_group(
+    /// Presigns the request `remove_tags_from_resource` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_remove_tags_from_resource(
         &self,
-        input: ResetDBClusterParameterGroupMessage,
-    ) -> Result<DBClusterParameterGroupNameMessage, RusotoError<ResetDBClusterParameterGroupError>>
-    {
+        presigner: &rusoto_core::Presigner,
+        input: RemoveTagsFromResourceMessage,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_remove_tags_from_resource_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_remove_tags_from_resource_request(
+        &self,
+        input: RemoveTagsFromResourceMessage,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("RemoveTagsFromResource");
         let mut params = Params::new();
 
-        params.put("Action", "ResetDBClusterParameterGroup");
+        params.put("Action", "RemoveTagsFromResource");
         params.put("Version", "2014-10-31");
-        ResetDBClusterParameterGroupMessageSerializer::serialize(&mut params, "", &input);
+        RemoveTagsFromResourceMessageSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `reset_db_cluster_parameter_group`, also returning the metadata of the response.
+    pub async fn reset_db_cluster_parameter_group_with_metadata(
+        &self,
+        input: ResetDBClusterParameterGroupMessage,
+    ) -> Result<
+        (DBClusterParameterGroupNameMessage, ResponseMetadata),
+        RusotoError<ResetDBClusterParameterGroupError>,
+    > {
+        let request = self.build_reset_db_cluster_parameter_group_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(ResetDBClusterParameterGroupError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -11093,35 +12557,64 @@ impl Docdb for DocdbClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Creates a new DB cluster from a DB snapshot or DB cluster snapshot.</p> <p>If a DB snapshot is specified, the target DB cluster is created from the source DB snapshot with a default configuration and default security group.</p> <p>If a DB cluster snapshot is specified, the target DB cluster is created from the source DB cluster restore point with the same configuration as the original source DB cluster, except that the new DB cluster is created with the default security group.</p>
-    async fn restore_db_cluster_from_snapshot(
+    /// Presigns the request `reset_db_cluster_parameter_group` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_reset_db_cluster_parameter_group(
         &self,
-        input: RestoreDBClusterFromSnapshotMessage,
-    ) -> Result<RestoreDBClusterFromSnapshotResult, RusotoError<RestoreDBClusterFromSnapshotError>>
-    {
+        presigner: &rusoto_core::Presigner,
+        input: ResetDBClusterParameterGroupMessage,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_reset_db_cluster_parameter_group_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_reset_db_cluster_parameter_group_request(
+        &self,
+        input: ResetDBClusterParameterGroupMessage,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("ResetDBClusterParameterGroup");
         let mut params = Params::new();
 
-        params.put("Action", "RestoreDBClusterFromSnapshot");
+        params.put("Action", "ResetDBClusterParameterGroup");
         params.put("Version", "2014-10-31");
-        RestoreDBClusterFromSnapshotMessageSerializer::serialize(&mut params, "", &input);
+        ResetDBClusterParameterGroupMessageSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `restore_db_cluster_from_snapshot`, also returning the metadata of the response.
+    pub async fn restore_db_cluster_from_snapshot_with_metadata(
+        &self,
+        input: RestoreDBClusterFromSnapshotMessage,
+    ) -> Result<
+        (RestoreDBClusterFromSnapshotResult, ResponseMetadata),
+        RusotoError<RestoreDBClusterFromSnapshotError>,
+    > {
+        let request = self.build_restore_db_cluster_from_snapshot_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(RestoreDBClusterFromSnapshotError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -11143,35 +12636,64 @@ impl Docdb for DocdbClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Restores a DB cluster to an arbitrary point in time. Users can restore to any point in time before <code>LatestRestorableTime</code> for up to <code>BackupRetentionPeriod</code> days. The target DB cluster is created from the source DB cluster with the same configuration as the original DB cluster, except that the new DB cluster is created with the default DB security group. </p>
-    async fn restore_db_cluster_to_point_in_time(
+    /// Presigns the request `restore_db_cluster_from_snapshot` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_restore_db_cluster_from_snapshot(
         &self,
-        input: RestoreDBClusterToPointInTimeMessage,
-    ) -> Result<RestoreDBClusterToPointInTimeResult, RusotoError<RestoreDBClusterToPointInTimeError>>
-    {
+        presigner: &rusoto_core::Presigner,
+        input: RestoreDBClusterFromSnapshotMessage,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_restore_db_cluster_from_snapshot_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_restore_db_cluster_from_snapshot_request(
+        &self,
+        input: RestoreDBClusterFromSnapshotMessage,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("RestoreDBClusterFromSnapshot");
         let mut params = Params::new();
 
-        params.put("Action", "RestoreDBClusterToPointInTime");
+        params.put("Action", "RestoreDBClusterFromSnapshot");
         params.put("Version", "2014-10-31");
-        RestoreDBClusterToPointInTimeMessageSerializer::serialize(&mut params, "", &input);
+        RestoreDBClusterFromSnapshotMessageSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `restore_db_cluster_to_point_in_time`, also returning the metadata of the response.
+    pub async fn restore_db_cluster_to_point_in_time_with_metadata(
+        &self,
+        input: RestoreDBClusterToPointInTimeMessage,
+    ) -> Result<
+        (RestoreDBClusterToPointInTimeResult, ResponseMetadata),
+        RusotoError<RestoreDBClusterToPointInTimeError>,
+    > {
+        let request = self.build_restore_db_cluster_to_point_in_time_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(RestoreDBClusterToPointInTimeError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -11193,34 +12715,61 @@ impl Docdb for DocdbClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Restarts the stopped cluster that is specified by <code>DBClusterIdentifier</code>. For more information, see <a href="https://docs.aws.amazon.com/documentdb/latest/developerguide/db-cluster-stop-start.html">Stopping and Starting an Amazon DocumentDB Cluster</a>.</p>
-    async fn start_db_cluster(
+    /// Presigns the request `restore_db_cluster_to_point_in_time` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_restore_db_cluster_to_point_in_time(
         &self,
-        input: StartDBClusterMessage,
-    ) -> Result<StartDBClusterResult, RusotoError<StartDBClusterError>> {
+        presigner: &rusoto_core::Presigner,
+        input: RestoreDBClusterToPointInTimeMessage,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_restore_db_cluster_to_point_in_time_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_restore_db_cluster_to_point_in_time_request(
+        &self,
+        input: RestoreDBClusterToPointInTimeMessage,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("RestoreDBClusterToPointInTime");
         let mut params = Params::new();
 
-        params.put("Action", "StartDBCluster");
+        params.put("Action", "RestoreDBClusterToPointInTime");
         params.put("Version", "2014-10-31");
-        StartDBClusterMessageSerializer::serialize(&mut params, "", &input);
+        RestoreDBClusterToPointInTimeMessageSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `start_db_cluster`, also returning the metadata of the response.
+    pub async fn start_db_cluster_with_metadata(
+        &self,
+        input: StartDBClusterMessage,
+    ) -> Result<(StartDBClusterResult, ResponseMetadata), RusotoError<StartDBClusterError>> {
+        let request = self.build_start_db_cluster_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(StartDBClusterError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -11240,34 +12789,58 @@ impl Docdb for DocdbClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Stops the running cluster that is specified by <code>DBClusterIdentifier</code>. The cluster must be in the <i>available</i> state. For more information, see <a href="https://docs.aws.amazon.com/documentdb/latest/developerguide/db-cluster-stop-start.html">Stopping and Starting an Amazon DocumentDB Cluster</a>.</p>
-    async fn stop_db_cluster(
+    /// Presigns the request `start_db_cluster` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_start_db_cluster(
         &self,
-        input: StopDBClusterMessage,
-    ) -> Result<StopDBClusterResult, RusotoError<StopDBClusterError>> {
+        presigner: &rusoto_core::Presigner,
+        input: StartDBClusterMessage,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_start_db_cluster_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_start_db_cluster_request(&self, input: StartDBClusterMessage) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("StartDBCluster");
         let mut params = Params::new();
 
-        params.put("Action", "StopDBCluster");
+        params.put("Action", "StartDBCluster");
         params.put("Version", "2014-10-31");
-        StopDBClusterMessageSerializer::serialize(&mut params, "", &input);
+        StartDBClusterMessageSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `stop_db_cluster`, also returning the metadata of the response.
+    pub async fn stop_db_cluster_with_metadata(
+        &self,
+        input: StopDBClusterMessage,
+    ) -> Result<(StopDBClusterResult, ResponseMetadata), RusotoError<StopDBClusterError>> {
+        let request = self.build_stop_db_cluster_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(StopDBClusterError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -11287,6 +12860,1094 @@ impl Docdb for DocdbClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
+    }
+
+    /// Presigns the request `stop_db_cluster` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_stop_db_cluster(
+        &self,
+        presigner: &rusoto_core::Presigner,
+        input: StopDBClusterMessage,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_stop_db_cluster_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_stop_db_cluster_request(&self, input: StopDBClusterMessage) -> SignedRequest {
+        let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("StopDBCluster");
+        let mut params = Params::new();
+
+        params.put("Action", "StopDBCluster");
+        params.put("Version", "2014-10-31");
+        StopDBClusterMessageSerializer::serialize(&mut params, "", &input);
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        request
+    }
+
+    /// The pages of `DescribeCertificates` results for `input`, requesting each next page with the `Marker` of the last one as its `Marker`.
+    /// The page size is the `MaxRecords` of `input`.
+    pub fn describe_certificates_pages(
+        &self,
+        input: DescribeCertificatesMessage,
+    ) -> impl futures::Stream<
+        Item = Result<CertificateMessage, RusotoError<DescribeCertificatesError>>,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: DocdbClient, input| async move { client.describe_certificates(input).await },
+            |input: &DescribeCertificatesMessage, output: &CertificateMessage| {
+                let marker = output.marker.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if marker.is_none() || marker == input.marker {
+                    return None;
+                }
+                Some(DescribeCertificatesMessage {
+                    marker,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `Certificates` of every page of `DescribeCertificates` results for `input`, as `describe_certificates_pages` requests them.
+    pub fn describe_certificates_stream(
+        &self,
+        input: DescribeCertificatesMessage,
+    ) -> impl futures::Stream<Item = Result<Certificate, RusotoError<DescribeCertificatesError>>> + Send
+    {
+        rusoto_core::pagination::items(self.describe_certificates_pages(input), |page| {
+            page.certificates.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `DescribeDBClusterParameterGroups` results for `input`, requesting each next page with the `Marker` of the last one as its `Marker`.
+    /// The page size is the `MaxRecords` of `input`.
+    pub fn describe_db_cluster_parameter_groups_pages(
+        &self,
+        input: DescribeDBClusterParameterGroupsMessage,
+    ) -> impl futures::Stream<
+        Item = Result<
+            DBClusterParameterGroupsMessage,
+            RusotoError<DescribeDBClusterParameterGroupsError>,
+        >,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: DocdbClient, input| async move {
+                client.describe_db_cluster_parameter_groups(input).await
+            },
+            |input: &DescribeDBClusterParameterGroupsMessage,
+             output: &DBClusterParameterGroupsMessage| {
+                let marker = output.marker.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if marker.is_none() || marker == input.marker {
+                    return None;
+                }
+                Some(DescribeDBClusterParameterGroupsMessage {
+                    marker,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `DBClusterParameterGroups` of every page of `DescribeDBClusterParameterGroups` results for `input`, as `describe_db_cluster_parameter_groups_pages` requests them.
+    pub fn describe_db_cluster_parameter_groups_stream(
+        &self,
+        input: DescribeDBClusterParameterGroupsMessage,
+    ) -> impl futures::Stream<
+        Item = Result<DBClusterParameterGroup, RusotoError<DescribeDBClusterParameterGroupsError>>,
+    > + Send {
+        rusoto_core::pagination::items(
+            self.describe_db_cluster_parameter_groups_pages(input),
+            |page| page.db_cluster_parameter_groups.unwrap_or_default(),
+        )
+    }
+
+    /// The pages of `DescribeDBClusterParameters` results for `input`, requesting each next page with the `Marker` of the last one as its `Marker`.
+    /// The page size is the `MaxRecords` of `input`.
+    pub fn describe_db_cluster_parameters_pages(
+        &self,
+        input: DescribeDBClusterParametersMessage,
+    ) -> impl futures::Stream<
+        Item = Result<
+            DBClusterParameterGroupDetails,
+            RusotoError<DescribeDBClusterParametersError>,
+        >,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: DocdbClient, input| async move {
+                client.describe_db_cluster_parameters(input).await
+            },
+            |input: &DescribeDBClusterParametersMessage,
+             output: &DBClusterParameterGroupDetails| {
+                let marker = output.marker.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if marker.is_none() || marker == input.marker {
+                    return None;
+                }
+                Some(DescribeDBClusterParametersMessage {
+                    marker,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `Parameters` of every page of `DescribeDBClusterParameters` results for `input`, as `describe_db_cluster_parameters_pages` requests them.
+    pub fn describe_db_cluster_parameters_stream(
+        &self,
+        input: DescribeDBClusterParametersMessage,
+    ) -> impl futures::Stream<Item = Result<Parameter, RusotoError<DescribeDBClusterParametersError>>>
+           + Send {
+        rusoto_core::pagination::items(self.describe_db_cluster_parameters_pages(input), |page| {
+            page.parameters.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `DescribeDBClusterSnapshots` results for `input`, requesting each next page with the `Marker` of the last one as its `Marker`.
+    /// The page size is the `MaxRecords` of `input`.
+    pub fn describe_db_cluster_snapshots_pages(
+        &self,
+        input: DescribeDBClusterSnapshotsMessage,
+    ) -> impl futures::Stream<
+        Item = Result<DBClusterSnapshotMessage, RusotoError<DescribeDBClusterSnapshotsError>>,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: DocdbClient, input| async move {
+                client.describe_db_cluster_snapshots(input).await
+            },
+            |input: &DescribeDBClusterSnapshotsMessage, output: &DBClusterSnapshotMessage| {
+                let marker = output.marker.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if marker.is_none() || marker == input.marker {
+                    return None;
+                }
+                Some(DescribeDBClusterSnapshotsMessage {
+                    marker,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `DBClusterSnapshots` of every page of `DescribeDBClusterSnapshots` results for `input`, as `describe_db_cluster_snapshots_pages` requests them.
+    pub fn describe_db_cluster_snapshots_stream(
+        &self,
+        input: DescribeDBClusterSnapshotsMessage,
+    ) -> impl futures::Stream<
+        Item = Result<DBClusterSnapshot, RusotoError<DescribeDBClusterSnapshotsError>>,
+    > + Send {
+        rusoto_core::pagination::items(self.describe_db_cluster_snapshots_pages(input), |page| {
+            page.db_cluster_snapshots.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `DescribeDBClusters` results for `input`, requesting each next page with the `Marker` of the last one as its `Marker`.
+    /// The page size is the `MaxRecords` of `input`.
+    pub fn describe_db_clusters_pages(
+        &self,
+        input: DescribeDBClustersMessage,
+    ) -> impl futures::Stream<Item = Result<DBClusterMessage, RusotoError<DescribeDBClustersError>>> + Send
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: DocdbClient, input| async move { client.describe_db_clusters(input).await },
+            |input: &DescribeDBClustersMessage, output: &DBClusterMessage| {
+                let marker = output.marker.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if marker.is_none() || marker == input.marker {
+                    return None;
+                }
+                Some(DescribeDBClustersMessage {
+                    marker,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `DBClusters` of every page of `DescribeDBClusters` results for `input`, as `describe_db_clusters_pages` requests them.
+    pub fn describe_db_clusters_stream(
+        &self,
+        input: DescribeDBClustersMessage,
+    ) -> impl futures::Stream<Item = Result<DBCluster, RusotoError<DescribeDBClustersError>>> + Send
+    {
+        rusoto_core::pagination::items(self.describe_db_clusters_pages(input), |page| {
+            page.db_clusters.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `DescribeDBEngineVersions` results for `input`, requesting each next page with the `Marker` of the last one as its `Marker`.
+    /// The page size is the `MaxRecords` of `input`.
+    pub fn describe_db_engine_versions_pages(
+        &self,
+        input: DescribeDBEngineVersionsMessage,
+    ) -> impl futures::Stream<
+        Item = Result<DBEngineVersionMessage, RusotoError<DescribeDBEngineVersionsError>>,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: DocdbClient, input| async move { client.describe_db_engine_versions(input).await },
+            |input: &DescribeDBEngineVersionsMessage, output: &DBEngineVersionMessage| {
+                let marker = output.marker.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if marker.is_none() || marker == input.marker {
+                    return None;
+                }
+                Some(DescribeDBEngineVersionsMessage {
+                    marker,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `DBEngineVersions` of every page of `DescribeDBEngineVersions` results for `input`, as `describe_db_engine_versions_pages` requests them.
+    pub fn describe_db_engine_versions_stream(
+        &self,
+        input: DescribeDBEngineVersionsMessage,
+    ) -> impl futures::Stream<
+        Item = Result<DBEngineVersion, RusotoError<DescribeDBEngineVersionsError>>,
+    > + Send {
+        rusoto_core::pagination::items(self.describe_db_engine_versions_pages(input), |page| {
+            page.db_engine_versions.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `DescribeDBInstances` results for `input`, requesting each next page with the `Marker` of the last one as its `Marker`.
+    /// The page size is the `MaxRecords` of `input`.
+    pub fn describe_db_instances_pages(
+        &self,
+        input: DescribeDBInstancesMessage,
+    ) -> impl futures::Stream<Item = Result<DBInstanceMessage, RusotoError<DescribeDBInstancesError>>>
+           + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: DocdbClient, input| async move { client.describe_db_instances(input).await },
+            |input: &DescribeDBInstancesMessage, output: &DBInstanceMessage| {
+                let marker = output.marker.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if marker.is_none() || marker == input.marker {
+                    return None;
+                }
+                Some(DescribeDBInstancesMessage {
+                    marker,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `DBInstances` of every page of `DescribeDBInstances` results for `input`, as `describe_db_instances_pages` requests them.
+    pub fn describe_db_instances_stream(
+        &self,
+        input: DescribeDBInstancesMessage,
+    ) -> impl futures::Stream<Item = Result<DBInstance, RusotoError<DescribeDBInstancesError>>> + Send
+    {
+        rusoto_core::pagination::items(self.describe_db_instances_pages(input), |page| {
+            page.db_instances.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `DescribeDBSubnetGroups` results for `input`, requesting each next page with the `Marker` of the last one as its `Marker`.
+    /// The page size is the `MaxRecords` of `input`.
+    pub fn describe_db_subnet_groups_pages(
+        &self,
+        input: DescribeDBSubnetGroupsMessage,
+    ) -> impl futures::Stream<
+        Item = Result<DBSubnetGroupMessage, RusotoError<DescribeDBSubnetGroupsError>>,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: DocdbClient, input| async move { client.describe_db_subnet_groups(input).await },
+            |input: &DescribeDBSubnetGroupsMessage, output: &DBSubnetGroupMessage| {
+                let marker = output.marker.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if marker.is_none() || marker == input.marker {
+                    return None;
+                }
+                Some(DescribeDBSubnetGroupsMessage {
+                    marker,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `DBSubnetGroups` of every page of `DescribeDBSubnetGroups` results for `input`, as `describe_db_subnet_groups_pages` requests them.
+    pub fn describe_db_subnet_groups_stream(
+        &self,
+        input: DescribeDBSubnetGroupsMessage,
+    ) -> impl futures::Stream<Item = Result<DBSubnetGroup, RusotoError<DescribeDBSubnetGroupsError>>>
+           + Send {
+        rusoto_core::pagination::items(self.describe_db_subnet_groups_pages(input), |page| {
+            page.db_subnet_groups.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `DescribeEvents` results for `input`, requesting each next page with the `Marker` of the last one as its `Marker`.
+    /// The page size is the `MaxRecords` of `input`.
+    pub fn describe_events_pages(
+        &self,
+        input: DescribeEventsMessage,
+    ) -> impl futures::Stream<Item = Result<EventsMessage, RusotoError<DescribeEventsError>>> + Send
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: DocdbClient, input| async move { client.describe_events(input).await },
+            |input: &DescribeEventsMessage, output: &EventsMessage| {
+                let marker = output.marker.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if marker.is_none() || marker == input.marker {
+                    return None;
+                }
+                Some(DescribeEventsMessage {
+                    marker,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `Events` of every page of `DescribeEvents` results for `input`, as `describe_events_pages` requests them.
+    pub fn describe_events_stream(
+        &self,
+        input: DescribeEventsMessage,
+    ) -> impl futures::Stream<Item = Result<Event, RusotoError<DescribeEventsError>>> + Send {
+        rusoto_core::pagination::items(self.describe_events_pages(input), |page| {
+            page.events.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `DescribeOrderableDBInstanceOptions` results for `input`, requesting each next page with the `Marker` of the last one as its `Marker`.
+    /// The page size is the `MaxRecords` of `input`.
+    pub fn describe_orderable_db_instance_options_pages(
+        &self,
+        input: DescribeOrderableDBInstanceOptionsMessage,
+    ) -> impl futures::Stream<
+        Item = Result<
+            OrderableDBInstanceOptionsMessage,
+            RusotoError<DescribeOrderableDBInstanceOptionsError>,
+        >,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: DocdbClient, input| async move {
+                client.describe_orderable_db_instance_options(input).await
+            },
+            |input: &DescribeOrderableDBInstanceOptionsMessage,
+             output: &OrderableDBInstanceOptionsMessage| {
+                let marker = output.marker.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if marker.is_none() || marker == input.marker {
+                    return None;
+                }
+                Some(DescribeOrderableDBInstanceOptionsMessage {
+                    marker,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `OrderableDBInstanceOptions` of every page of `DescribeOrderableDBInstanceOptions` results for `input`, as `describe_orderable_db_instance_options_pages` requests them.
+    pub fn describe_orderable_db_instance_options_stream(
+        &self,
+        input: DescribeOrderableDBInstanceOptionsMessage,
+    ) -> impl futures::Stream<
+        Item = Result<
+            OrderableDBInstanceOption,
+            RusotoError<DescribeOrderableDBInstanceOptionsError>,
+        >,
+    > + Send {
+        rusoto_core::pagination::items(
+            self.describe_orderable_db_instance_options_pages(input),
+            |page| page.orderable_db_instance_options.unwrap_or_default(),
+        )
+    }
+
+    /// The pages of `DescribePendingMaintenanceActions` results for `input`, requesting each next page with the `Marker` of the last one as its `Marker`.
+    /// The page size is the `MaxRecords` of `input`.
+    pub fn describe_pending_maintenance_actions_pages(
+        &self,
+        input: DescribePendingMaintenanceActionsMessage,
+    ) -> impl futures::Stream<
+        Item = Result<
+            PendingMaintenanceActionsMessage,
+            RusotoError<DescribePendingMaintenanceActionsError>,
+        >,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: DocdbClient, input| async move {
+                client.describe_pending_maintenance_actions(input).await
+            },
+            |input: &DescribePendingMaintenanceActionsMessage,
+             output: &PendingMaintenanceActionsMessage| {
+                let marker = output.marker.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if marker.is_none() || marker == input.marker {
+                    return None;
+                }
+                Some(DescribePendingMaintenanceActionsMessage {
+                    marker,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `PendingMaintenanceActions` of every page of `DescribePendingMaintenanceActions` results for `input`, as `describe_pending_maintenance_actions_pages` requests them.
+    pub fn describe_pending_maintenance_actions_stream(
+        &self,
+        input: DescribePendingMaintenanceActionsMessage,
+    ) -> impl futures::Stream<
+        Item = Result<
+            ResourcePendingMaintenanceActions,
+            RusotoError<DescribePendingMaintenanceActionsError>,
+        >,
+    > + Send {
+        rusoto_core::pagination::items(
+            self.describe_pending_maintenance_actions_pages(input),
+            |page| page.pending_maintenance_actions.unwrap_or_default(),
+        )
+    }
+
+    /// Waits for `DBInstanceAvailable`, polling `DescribeDBInstances` with `input` every 30 seconds, up to 60 times.
+    pub async fn wait_until_db_instance_available(
+        &self,
+        input: DescribeDBInstancesMessage,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<DescribeDBInstancesError>> {
+        let waiter = rusoto_core::waiter::Waiter::new(std::time::Duration::from_secs(30), 60);
+        self.wait_until_db_instance_available_with(input, &waiter)
+            .await
+    }
+
+    /// Waits for `DBInstanceAvailable` like `wait_until_db_instance_available`, with the delay, attempts and runtime of `waiter`.
+    pub async fn wait_until_db_instance_available_with(
+        &self,
+        input: DescribeDBInstancesMessage,
+        waiter: &rusoto_core::waiter::Waiter,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<DescribeDBInstancesError>> {
+        rusoto_core::waiter::wait(
+            waiter,
+            || self.describe_db_instances(input.clone()),
+            |result| {
+                if match result {
+                    Ok(output) => {
+                        let mut values = std::iter::once(output)
+                            .filter_map(|value| value.db_instances.as_ref())
+                            .flat_map(|value| value.iter())
+                            .filter_map(|value| value.db_instance_status.as_ref())
+                            .peekable();
+                        values.peek().is_some() && values.all(|value| value == "available")
+                    }
+                    Err(_) => false,
+                } {
+                    return Some(rusoto_core::waiter::WaiterState::Success);
+                }
+                if match result {
+                    Ok(output) => std::iter::once(output)
+                        .filter_map(|value| value.db_instances.as_ref())
+                        .flat_map(|value| value.iter())
+                        .filter_map(|value| value.db_instance_status.as_ref())
+                        .any(|value| value == "deleted"),
+                    Err(_) => false,
+                } {
+                    return Some(rusoto_core::waiter::WaiterState::Failure);
+                }
+                if match result {
+                    Ok(output) => std::iter::once(output)
+                        .filter_map(|value| value.db_instances.as_ref())
+                        .flat_map(|value| value.iter())
+                        .filter_map(|value| value.db_instance_status.as_ref())
+                        .any(|value| value == "deleting"),
+                    Err(_) => false,
+                } {
+                    return Some(rusoto_core::waiter::WaiterState::Failure);
+                }
+                if match result {
+                    Ok(output) => std::iter::once(output)
+                        .filter_map(|value| value.db_instances.as_ref())
+                        .flat_map(|value| value.iter())
+                        .filter_map(|value| value.db_instance_status.as_ref())
+                        .any(|value| value == "failed"),
+                    Err(_) => false,
+                } {
+                    return Some(rusoto_core::waiter::WaiterState::Failure);
+                }
+                if match result {
+                    Ok(output) => std::iter::once(output)
+                        .filter_map(|value| value.db_instances.as_ref())
+                        .flat_map(|value| value.iter())
+                        .filter_map(|value| value.db_instance_status.as_ref())
+                        .any(|value| value == "incompatible-restore"),
+                    Err(_) => false,
+                } {
+                    return Some(rusoto_core::waiter::WaiterState::Failure);
+                }
+                if match result {
+                    Ok(output) => std::iter::once(output)
+                        .filter_map(|value| value.db_instances.as_ref())
+                        .flat_map(|value| value.iter())
+                        .filter_map(|value| value.db_instance_status.as_ref())
+                        .any(|value| value == "incompatible-parameters"),
+                    Err(_) => false,
+                } {
+                    return Some(rusoto_core::waiter::WaiterState::Failure);
+                }
+                None
+            },
+        )
+        .await
+    }
+
+    /// Waits for `DBInstanceDeleted`, polling `DescribeDBInstances` with `input` every 30 seconds, up to 60 times.
+    pub async fn wait_until_db_instance_deleted(
+        &self,
+        input: DescribeDBInstancesMessage,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<DescribeDBInstancesError>> {
+        let waiter = rusoto_core::waiter::Waiter::new(std::time::Duration::from_secs(30), 60);
+        self.wait_until_db_instance_deleted_with(input, &waiter)
+            .await
+    }
+
+    /// Waits for `DBInstanceDeleted` like `wait_until_db_instance_deleted`, with the delay, attempts and runtime of `waiter`.
+    pub async fn wait_until_db_instance_deleted_with(
+        &self,
+        input: DescribeDBInstancesMessage,
+        waiter: &rusoto_core::waiter::Waiter,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<DescribeDBInstancesError>> {
+        rusoto_core::waiter::wait(
+            waiter,
+            || self.describe_db_instances(input.clone()),
+            |result| {
+                if match result {
+                    Ok(output) => {
+                        let mut values = std::iter::once(output)
+                            .filter_map(|value| value.db_instances.as_ref())
+                            .flat_map(|value| value.iter())
+                            .filter_map(|value| value.db_instance_status.as_ref())
+                            .peekable();
+                        values.peek().is_some() && values.all(|value| value == "deleted")
+                    }
+                    Err(_) => false,
+                } {
+                    return Some(rusoto_core::waiter::WaiterState::Success);
+                }
+                if result
+                    .as_ref()
+                    .err()
+                    .and_then(|error| error.error_code())
+                    .as_deref()
+                    == Some("DBInstanceNotFound")
+                {
+                    return Some(rusoto_core::waiter::WaiterState::Success);
+                }
+                if match result {
+                    Ok(output) => std::iter::once(output)
+                        .filter_map(|value| value.db_instances.as_ref())
+                        .flat_map(|value| value.iter())
+                        .filter_map(|value| value.db_instance_status.as_ref())
+                        .any(|value| value == "creating"),
+                    Err(_) => false,
+                } {
+                    return Some(rusoto_core::waiter::WaiterState::Failure);
+                }
+                if match result {
+                    Ok(output) => std::iter::once(output)
+                        .filter_map(|value| value.db_instances.as_ref())
+                        .flat_map(|value| value.iter())
+                        .filter_map(|value| value.db_instance_status.as_ref())
+                        .any(|value| value == "modifying"),
+                    Err(_) => false,
+                } {
+                    return Some(rusoto_core::waiter::WaiterState::Failure);
+                }
+                if match result {
+                    Ok(output) => std::iter::once(output)
+                        .filter_map(|value| value.db_instances.as_ref())
+                        .flat_map(|value| value.iter())
+                        .filter_map(|value| value.db_instance_status.as_ref())
+                        .any(|value| value == "rebooting"),
+                    Err(_) => false,
+                } {
+                    return Some(rusoto_core::waiter::WaiterState::Failure);
+                }
+                if match result {
+                    Ok(output) => std::iter::once(output)
+                        .filter_map(|value| value.db_instances.as_ref())
+                        .flat_map(|value| value.iter())
+                        .filter_map(|value| value.db_instance_status.as_ref())
+                        .any(|value| value == "resetting-master-credentials"),
+                    Err(_) => false,
+                } {
+                    return Some(rusoto_core::waiter::WaiterState::Failure);
+                }
+                None
+            },
+        )
+        .await
+    }
+}
+
+#[async_trait]
+impl Docdb for DocdbClient {
+    /// <p>Adds metadata tags to an Amazon DocumentDB resource. You can use these tags with cost allocation reporting to track costs that are associated with Amazon DocumentDB resources. or in a <code>Condition</code> statement in an AWS Identity and Access Management (IAM) policy for Amazon DocumentDB.</p>
+    async fn add_tags_to_resource(
+        &self,
+        input: AddTagsToResourceMessage,
+    ) -> Result<(), RusotoError<AddTagsToResourceError>> {
+        self.add_tags_to_resource_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Applies a pending maintenance action to a resource (for example, to a DB instance).</p>
+    async fn apply_pending_maintenance_action(
+        &self,
+        input: ApplyPendingMaintenanceActionMessage,
+    ) -> Result<ApplyPendingMaintenanceActionResult, RusotoError<ApplyPendingMaintenanceActionError>>
+    {
+        self.apply_pending_maintenance_action_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Copies the specified DB cluster parameter group.</p>
+    async fn copy_db_cluster_parameter_group(
+        &self,
+        input: CopyDBClusterParameterGroupMessage,
+    ) -> Result<CopyDBClusterParameterGroupResult, RusotoError<CopyDBClusterParameterGroupError>>
+    {
+        self.copy_db_cluster_parameter_group_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Copies a snapshot of a DB cluster.</p> <p>To copy a DB cluster snapshot from a shared manual DB cluster snapshot, <code>SourceDBClusterSnapshotIdentifier</code> must be the Amazon Resource Name (ARN) of the shared DB cluster snapshot.</p> <p>To cancel the copy operation after it is in progress, delete the target DB cluster snapshot identified by <code>TargetDBClusterSnapshotIdentifier</code> while that DB cluster snapshot is in the <i>copying</i> status.</p>
+    async fn copy_db_cluster_snapshot(
+        &self,
+        input: CopyDBClusterSnapshotMessage,
+    ) -> Result<CopyDBClusterSnapshotResult, RusotoError<CopyDBClusterSnapshotError>> {
+        self.copy_db_cluster_snapshot_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Creates a new Amazon DocumentDB DB cluster.</p>
+    async fn create_db_cluster(
+        &self,
+        input: CreateDBClusterMessage,
+    ) -> Result<CreateDBClusterResult, RusotoError<CreateDBClusterError>> {
+        self.create_db_cluster_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Creates a new DB cluster parameter group.</p> <p>Parameters in a DB cluster parameter group apply to all of the instances in a DB cluster.</p> <p>A DB cluster parameter group is initially created with the default parameters for the database engine used by instances in the DB cluster. To provide custom values for any of the parameters, you must modify the group after you create it. After you create a DB cluster parameter group, you must associate it with your DB cluster. For the new DB cluster parameter group and associated settings to take effect, you must then reboot the DB instances in the DB cluster without failover.</p> <important> <p>After you create a DB cluster parameter group, you should wait at least 5 minutes before creating your first DB cluster that uses that DB cluster parameter group as the default parameter group. This allows Amazon DocumentDB to fully complete the create action before the DB cluster parameter group is used as the default for a new DB cluster. This step is especially important for parameters that are critical when creating the default database for a DB cluster, such as the character set for the default database defined by the <code>character<em>set</em>database</code> parameter.</p> </important></p>
+    async fn create_db_cluster_parameter_group(
+        &self,
+        input: CreateDBClusterParameterGroupMessage,
+    ) -> Result<CreateDBClusterParameterGroupResult, RusotoError<CreateDBClusterParameterGroupError>>
+    {
+        self.create_db_cluster_parameter_group_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Creates a snapshot of a DB cluster. </p>
+    async fn create_db_cluster_snapshot(
+        &self,
+        input: CreateDBClusterSnapshotMessage,
+    ) -> Result<CreateDBClusterSnapshotResult, RusotoError<CreateDBClusterSnapshotError>> {
+        self.create_db_cluster_snapshot_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Creates a new DB instance.</p>
+    async fn create_db_instance(
+        &self,
+        input: CreateDBInstanceMessage,
+    ) -> Result<CreateDBInstanceResult, RusotoError<CreateDBInstanceError>> {
+        self.create_db_instance_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Creates a new DB subnet group. DB subnet groups must contain at least one subnet in at least two Availability Zones in the AWS Region.</p>
+    async fn create_db_subnet_group(
+        &self,
+        input: CreateDBSubnetGroupMessage,
+    ) -> Result<CreateDBSubnetGroupResult, RusotoError<CreateDBSubnetGroupError>> {
+        self.create_db_subnet_group_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Deletes a previously provisioned DB cluster. When you delete a DB cluster, all automated backups for that DB cluster are deleted and can&#39;t be recovered. Manual DB cluster snapshots of the specified DB cluster are not deleted.</p> <p/></p>
+    async fn delete_db_cluster(
+        &self,
+        input: DeleteDBClusterMessage,
+    ) -> Result<DeleteDBClusterResult, RusotoError<DeleteDBClusterError>> {
+        self.delete_db_cluster_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes a specified DB cluster parameter group. The DB cluster parameter group to be deleted can't be associated with any DB clusters.</p>
+    async fn delete_db_cluster_parameter_group(
+        &self,
+        input: DeleteDBClusterParameterGroupMessage,
+    ) -> Result<(), RusotoError<DeleteDBClusterParameterGroupError>> {
+        self.delete_db_cluster_parameter_group_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Deletes a DB cluster snapshot. If the snapshot is being copied, the copy operation is terminated.</p> <note> <p>The DB cluster snapshot must be in the <code>available</code> state to be deleted.</p> </note></p>
+    async fn delete_db_cluster_snapshot(
+        &self,
+        input: DeleteDBClusterSnapshotMessage,
+    ) -> Result<DeleteDBClusterSnapshotResult, RusotoError<DeleteDBClusterSnapshotError>> {
+        self.delete_db_cluster_snapshot_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes a previously provisioned DB instance. </p>
+    async fn delete_db_instance(
+        &self,
+        input: DeleteDBInstanceMessage,
+    ) -> Result<DeleteDBInstanceResult, RusotoError<DeleteDBInstanceError>> {
+        self.delete_db_instance_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Deletes a DB subnet group.</p> <note> <p>The specified database subnet group must not be associated with any DB instances.</p> </note></p>
+    async fn delete_db_subnet_group(
+        &self,
+        input: DeleteDBSubnetGroupMessage,
+    ) -> Result<(), RusotoError<DeleteDBSubnetGroupError>> {
+        self.delete_db_subnet_group_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns a list of certificate authority (CA) certificates provided by Amazon RDS for this AWS account.</p>
+    async fn describe_certificates(
+        &self,
+        input: DescribeCertificatesMessage,
+    ) -> Result<CertificateMessage, RusotoError<DescribeCertificatesError>> {
+        self.describe_certificates_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns a list of <code>DBClusterParameterGroup</code> descriptions. If a <code>DBClusterParameterGroupName</code> parameter is specified, the list contains only the description of the specified DB cluster parameter group. </p>
+    async fn describe_db_cluster_parameter_groups(
+        &self,
+        input: DescribeDBClusterParameterGroupsMessage,
+    ) -> Result<DBClusterParameterGroupsMessage, RusotoError<DescribeDBClusterParameterGroupsError>>
+    {
+        self.describe_db_cluster_parameter_groups_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns the detailed parameter list for a particular DB cluster parameter group.</p>
+    async fn describe_db_cluster_parameters(
+        &self,
+        input: DescribeDBClusterParametersMessage,
+    ) -> Result<DBClusterParameterGroupDetails, RusotoError<DescribeDBClusterParametersError>> {
+        self.describe_db_cluster_parameters_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns a list of DB cluster snapshot attribute names and values for a manual DB cluster snapshot.</p> <p>When you share snapshots with other AWS accounts, <code>DescribeDBClusterSnapshotAttributes</code> returns the <code>restore</code> attribute and a list of IDs for the AWS accounts that are authorized to copy or restore the manual DB cluster snapshot. If <code>all</code> is included in the list of values for the <code>restore</code> attribute, then the manual DB cluster snapshot is public and can be copied or restored by all AWS accounts.</p>
+    async fn describe_db_cluster_snapshot_attributes(
+        &self,
+        input: DescribeDBClusterSnapshotAttributesMessage,
+    ) -> Result<
+        DescribeDBClusterSnapshotAttributesResult,
+        RusotoError<DescribeDBClusterSnapshotAttributesError>,
+    > {
+        self.describe_db_cluster_snapshot_attributes_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns information about DB cluster snapshots. This API operation supports pagination.</p>
+    async fn describe_db_cluster_snapshots(
+        &self,
+        input: DescribeDBClusterSnapshotsMessage,
+    ) -> Result<DBClusterSnapshotMessage, RusotoError<DescribeDBClusterSnapshotsError>> {
+        self.describe_db_cluster_snapshots_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns information about provisioned Amazon DocumentDB DB clusters. This API operation supports pagination.</p>
+    async fn describe_db_clusters(
+        &self,
+        input: DescribeDBClustersMessage,
+    ) -> Result<DBClusterMessage, RusotoError<DescribeDBClustersError>> {
+        self.describe_db_clusters_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns a list of the available DB engines.</p>
+    async fn describe_db_engine_versions(
+        &self,
+        input: DescribeDBEngineVersionsMessage,
+    ) -> Result<DBEngineVersionMessage, RusotoError<DescribeDBEngineVersionsError>> {
+        self.describe_db_engine_versions_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns information about provisioned Amazon DocumentDB instances. This API supports pagination.</p>
+    async fn describe_db_instances(
+        &self,
+        input: DescribeDBInstancesMessage,
+    ) -> Result<DBInstanceMessage, RusotoError<DescribeDBInstancesError>> {
+        self.describe_db_instances_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns a list of <code>DBSubnetGroup</code> descriptions. If a <code>DBSubnetGroupName</code> is specified, the list will contain only the descriptions of the specified <code>DBSubnetGroup</code>.</p>
+    async fn describe_db_subnet_groups(
+        &self,
+        input: DescribeDBSubnetGroupsMessage,
+    ) -> Result<DBSubnetGroupMessage, RusotoError<DescribeDBSubnetGroupsError>> {
+        self.describe_db_subnet_groups_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns the default engine and system parameter information for the cluster database engine.</p>
+    async fn describe_engine_default_cluster_parameters(
+        &self,
+        input: DescribeEngineDefaultClusterParametersMessage,
+    ) -> Result<
+        DescribeEngineDefaultClusterParametersResult,
+        RusotoError<DescribeEngineDefaultClusterParametersError>,
+    > {
+        self.describe_engine_default_cluster_parameters_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Displays a list of categories for all event source types, or, if specified, for a specified source type. </p>
+    async fn describe_event_categories(
+        &self,
+        input: DescribeEventCategoriesMessage,
+    ) -> Result<EventCategoriesMessage, RusotoError<DescribeEventCategoriesError>> {
+        self.describe_event_categories_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns events related to DB instances, DB security groups, DB snapshots, and DB parameter groups for the past 14 days. You can obtain events specific to a particular DB instance, DB security group, DB snapshot, or DB parameter group by providing the name as a parameter. By default, the events of the past hour are returned.</p>
+    async fn describe_events(
+        &self,
+        input: DescribeEventsMessage,
+    ) -> Result<EventsMessage, RusotoError<DescribeEventsError>> {
+        self.describe_events_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns a list of orderable DB instance options for the specified engine.</p>
+    async fn describe_orderable_db_instance_options(
+        &self,
+        input: DescribeOrderableDBInstanceOptionsMessage,
+    ) -> Result<
+        OrderableDBInstanceOptionsMessage,
+        RusotoError<DescribeOrderableDBInstanceOptionsError>,
+    > {
+        self.describe_orderable_db_instance_options_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns a list of resources (for example, DB instances) that have at least one pending maintenance action.</p>
+    async fn describe_pending_maintenance_actions(
+        &self,
+        input: DescribePendingMaintenanceActionsMessage,
+    ) -> Result<PendingMaintenanceActionsMessage, RusotoError<DescribePendingMaintenanceActionsError>>
+    {
+        self.describe_pending_maintenance_actions_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Forces a failover for a DB cluster.</p> <p>A failover for a DB cluster promotes one of the Amazon DocumentDB replicas (read-only instances) in the DB cluster to be the primary instance (the cluster writer).</p> <p>If the primary instance fails, Amazon DocumentDB automatically fails over to an Amazon DocumentDB replica, if one exists. You can force a failover when you want to simulate a failure of a primary instance for testing.</p>
+    async fn failover_db_cluster(
+        &self,
+        input: FailoverDBClusterMessage,
+    ) -> Result<FailoverDBClusterResult, RusotoError<FailoverDBClusterError>> {
+        self.failover_db_cluster_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Lists all tags on an Amazon DocumentDB resource.</p>
+    async fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceMessage,
+    ) -> Result<TagListMessage, RusotoError<ListTagsForResourceError>> {
+        self.list_tags_for_resource_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Modifies a setting for an Amazon DocumentDB DB cluster. You can change one or more database configuration parameters by specifying these parameters and the new values in the request. </p>
+    async fn modify_db_cluster(
+        &self,
+        input: ModifyDBClusterMessage,
+    ) -> Result<ModifyDBClusterResult, RusotoError<ModifyDBClusterError>> {
+        self.modify_db_cluster_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p> Modifies the parameters of a DB cluster parameter group. To modify more than one parameter, submit a list of the following: <code>ParameterName</code>, <code>ParameterValue</code>, and <code>ApplyMethod</code>. A maximum of 20 parameters can be modified in a single request. </p> <note> <p>Changes to dynamic parameters are applied immediately. Changes to static parameters require a reboot or maintenance window before the change can take effect.</p> </note> <important> <p>After you create a DB cluster parameter group, you should wait at least 5 minutes before creating your first DB cluster that uses that DB cluster parameter group as the default parameter group. This allows Amazon DocumentDB to fully complete the create action before the parameter group is used as the default for a new DB cluster. This step is especially important for parameters that are critical when creating the default database for a DB cluster, such as the character set for the default database defined by the <code>character<em>set</em>database</code> parameter.</p> </important></p>
+    async fn modify_db_cluster_parameter_group(
+        &self,
+        input: ModifyDBClusterParameterGroupMessage,
+    ) -> Result<DBClusterParameterGroupNameMessage, RusotoError<ModifyDBClusterParameterGroupError>>
+    {
+        self.modify_db_cluster_parameter_group_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Adds an attribute and values to, or removes an attribute and values from, a manual DB cluster snapshot.</p> <p>To share a manual DB cluster snapshot with other AWS accounts, specify <code>restore</code> as the <code>AttributeName</code>, and use the <code>ValuesToAdd</code> parameter to add a list of IDs of the AWS accounts that are authorized to restore the manual DB cluster snapshot. Use the value <code>all</code> to make the manual DB cluster snapshot public, which means that it can be copied or restored by all AWS accounts. Do not add the <code>all</code> value for any manual DB cluster snapshots that contain private information that you don't want available to all AWS accounts. If a manual DB cluster snapshot is encrypted, it can be shared, but only by specifying a list of authorized AWS account IDs for the <code>ValuesToAdd</code> parameter. You can't use <code>all</code> as a value for that parameter in this case.</p>
+    async fn modify_db_cluster_snapshot_attribute(
+        &self,
+        input: ModifyDBClusterSnapshotAttributeMessage,
+    ) -> Result<
+        ModifyDBClusterSnapshotAttributeResult,
+        RusotoError<ModifyDBClusterSnapshotAttributeError>,
+    > {
+        self.modify_db_cluster_snapshot_attribute_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Modifies settings for a DB instance. You can change one or more database configuration parameters by specifying these parameters and the new values in the request.</p>
+    async fn modify_db_instance(
+        &self,
+        input: ModifyDBInstanceMessage,
+    ) -> Result<ModifyDBInstanceResult, RusotoError<ModifyDBInstanceError>> {
+        self.modify_db_instance_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Modifies an existing DB subnet group. DB subnet groups must contain at least one subnet in at least two Availability Zones in the AWS Region.</p>
+    async fn modify_db_subnet_group(
+        &self,
+        input: ModifyDBSubnetGroupMessage,
+    ) -> Result<ModifyDBSubnetGroupResult, RusotoError<ModifyDBSubnetGroupError>> {
+        self.modify_db_subnet_group_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>You might need to reboot your DB instance, usually for maintenance reasons. For example, if you make certain changes, or if you change the DB cluster parameter group that is associated with the DB instance, you must reboot the instance for the changes to take effect. </p> <p>Rebooting a DB instance restarts the database engine service. Rebooting a DB instance results in a momentary outage, during which the DB instance status is set to <i>rebooting</i>. </p>
+    async fn reboot_db_instance(
+        &self,
+        input: RebootDBInstanceMessage,
+    ) -> Result<RebootDBInstanceResult, RusotoError<RebootDBInstanceError>> {
+        self.reboot_db_instance_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Removes metadata tags from an Amazon DocumentDB resource.</p>
+    async fn remove_tags_from_resource(
+        &self,
+        input: RemoveTagsFromResourceMessage,
+    ) -> Result<(), RusotoError<RemoveTagsFromResourceError>> {
+        self.remove_tags_from_resource_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p> Modifies the parameters of a DB cluster parameter group to the default value. To reset specific parameters, submit a list of the following: <code>ParameterName</code> and <code>ApplyMethod</code>. To reset the entire DB cluster parameter group, specify the <code>DBClusterParameterGroupName</code> and <code>ResetAllParameters</code> parameters. </p> <p> When you reset the entire group, dynamic parameters are updated immediately and static parameters are set to <code>pending-reboot</code> to take effect on the next DB instance reboot.</p>
+    async fn reset_db_cluster_parameter_group(
+        &self,
+        input: ResetDBClusterParameterGroupMessage,
+    ) -> Result<DBClusterParameterGroupNameMessage, RusotoError<ResetDBClusterParameterGroupError>>
+    {
+        self.reset_db_cluster_parameter_group_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Creates a new DB cluster from a DB snapshot or DB cluster snapshot.</p> <p>If a DB snapshot is specified, the target DB cluster is created from the source DB snapshot with a default configuration and default security group.</p> <p>If a DB cluster snapshot is specified, the target DB cluster is created from the source DB cluster restore point with the same configuration as the original source DB cluster, except that the new DB cluster is created with the default security group.</p>
+    async fn restore_db_cluster_from_snapshot(
+        &self,
+        input: RestoreDBClusterFromSnapshotMessage,
+    ) -> Result<RestoreDBClusterFromSnapshotResult, RusotoError<RestoreDBClusterFromSnapshotError>>
+    {
+        self.restore_db_cluster_from_snapshot_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Restores a DB cluster to an arbitrary point in time. Users can restore to any point in time before <code>LatestRestorableTime</code> for up to <code>BackupRetentionPeriod</code> days. The target DB cluster is created from the source DB cluster with the same configuration as the original DB cluster, except that the new DB cluster is created with the default DB security group. </p>
+    async fn restore_db_cluster_to_point_in_time(
+        &self,
+        input: RestoreDBClusterToPointInTimeMessage,
+    ) -> Result<RestoreDBClusterToPointInTimeResult, RusotoError<RestoreDBClusterToPointInTimeError>>
+    {
+        self.restore_db_cluster_to_point_in_time_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Restarts the stopped cluster that is specified by <code>DBClusterIdentifier</code>. For more information, see <a href="https://docs.aws.amazon.com/documentdb/latest/developerguide/db-cluster-stop-start.html">Stopping and Starting an Amazon DocumentDB Cluster</a>.</p>
+    async fn start_db_cluster(
+        &self,
+        input: StartDBClusterMessage,
+    ) -> Result<StartDBClusterResult, RusotoError<StartDBClusterError>> {
+        self.start_db_cluster_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Stops the running cluster that is specified by <code>DBClusterIdentifier</code>. The cluster must be in the <i>available</i> state. For more information, see <a href="https://docs.aws.amazon.com/documentdb/latest/developerguide/db-cluster-stop-start.html">Stopping and Starting an Amazon DocumentDB Cluster</a>.</p>
+    async fn stop_db_cluster(
+        &self,
+        input: StopDBClusterMessage,
+    ) -> Result<StopDBClusterResult, RusotoError<StopDBClusterError>> {
+        self.stop_db_cluster_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
     }
 }
@@ -16,8 +16,8 @@ use std::fmt;
 use async_trait::async_trait;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
-use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest, ResponseMetadata};
+use rusoto_core::{Client, ErrorMetadata, RusotoError};
 
 use rusoto_core::proto;
 use rusoto_core::signature::SignedRequest;
@@ -2083,6 +2083,7 @@ pub struct VerifyTrustResult {
 
 /// Errors returned by AcceptSharedDirectory
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum AcceptSharedDirectoryError {
     /// <p>A client exception has occurred.</p>
     Client(String),
@@ -2094,6 +2095,8 @@ pub enum AcceptSharedDirectoryError {
     InvalidParameter(String),
     /// <p>An exception has occurred in AWS Directory Service.</p>
     Service(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl AcceptSharedDirectoryError {
@@ -2101,28 +2104,31 @@ impl AcceptSharedDirectoryError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ClientException" => {
-                    return RusotoError::Service(AcceptSharedDirectoryError::Client(err.msg))
+                    return RusotoError::service(AcceptSharedDirectoryError::Client(err.msg), &res)
                 }
                 "DirectoryAlreadySharedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         AcceptSharedDirectoryError::DirectoryAlreadyShared(err.msg),
+                        &res,
                     )
                 }
                 "EntityDoesNotExistException" => {
-                    return RusotoError::Service(AcceptSharedDirectoryError::EntityDoesNotExist(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        AcceptSharedDirectoryError::EntityDoesNotExist(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(AcceptSharedDirectoryError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        AcceptSharedDirectoryError::InvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(AcceptSharedDirectoryError::Service(err.msg))
+                    return RusotoError::service(AcceptSharedDirectoryError::Service(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(AcceptSharedDirectoryError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2137,12 +2143,14 @@ impl fmt::Display for AcceptSharedDirectoryError {
             AcceptSharedDirectoryError::EntityDoesNotExist(ref cause) => write!(f, "{}", cause),
             AcceptSharedDirectoryError::InvalidParameter(ref cause) => write!(f, "{}", cause),
             AcceptSharedDirectoryError::Service(ref cause) => write!(f, "{}", cause),
+            AcceptSharedDirectoryError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for AcceptSharedDirectoryError {}
 /// Errors returned by AddIpRoutes
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum AddIpRoutesError {
     /// <p>A client exception has occurred.</p>
     Client(String),
@@ -2158,6 +2166,8 @@ pub enum AddIpRoutesError {
     IpRouteLimitExceeded(String),
     /// <p>An exception has occurred in AWS Directory Service.</p>
     Service(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl AddIpRoutesError {
@@ -2165,28 +2175,40 @@ impl AddIpRoutesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ClientException" => {
-                    return RusotoError::Service(AddIpRoutesError::Client(err.msg))
+                    return RusotoError::service(AddIpRoutesError::Client(err.msg), &res)
                 }
                 "DirectoryUnavailableException" => {
-                    return RusotoError::Service(AddIpRoutesError::DirectoryUnavailable(err.msg))
+                    return RusotoError::service(
+                        AddIpRoutesError::DirectoryUnavailable(err.msg),
+                        &res,
+                    )
                 }
                 "EntityAlreadyExistsException" => {
-                    return RusotoError::Service(AddIpRoutesError::EntityAlreadyExists(err.msg))
+                    return RusotoError::service(
+                        AddIpRoutesError::EntityAlreadyExists(err.msg),
+                        &res,
+                    )
                 }
                 "EntityDoesNotExistException" => {
-                    return RusotoError::Service(AddIpRoutesError::EntityDoesNotExist(err.msg))
+                    return RusotoError::service(
+                        AddIpRoutesError::EntityDoesNotExist(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(AddIpRoutesError::InvalidParameter(err.msg))
+                    return RusotoError::service(AddIpRoutesError::InvalidParameter(err.msg), &res)
                 }
                 "IpRouteLimitExceededException" => {
-                    return RusotoError::Service(AddIpRoutesError::IpRouteLimitExceeded(err.msg))
+                    return RusotoError::service(
+                        AddIpRoutesError::IpRouteLimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(AddIpRoutesError::Service(err.msg))
+                    return RusotoError::service(AddIpRoutesError::Service(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(AddIpRoutesError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2203,12 +2225,14 @@ impl fmt::Display for AddIpRoutesError {
             AddIpRoutesError::InvalidParameter(ref cause) => write!(f, "{}", cause),
             AddIpRoutesError::IpRouteLimitExceeded(ref cause) => write!(f, "{}", cause),
             AddIpRoutesError::Service(ref cause) => write!(f, "{}", cause),
+            AddIpRoutesError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for AddIpRoutesError {}
 /// Errors returned by AddTagsToResource
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum AddTagsToResourceError {
     /// <p>A client exception has occurred.</p>
     Client(String),
@@ -2220,6 +2244,8 @@ pub enum AddTagsToResourceError {
     Service(String),
     /// <p>The maximum allowed number of tags was exceeded.</p>
     TagLimitExceeded(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl AddTagsToResourceError {
@@ -2227,24 +2253,31 @@ impl AddTagsToResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ClientException" => {
-                    return RusotoError::Service(AddTagsToResourceError::Client(err.msg))
+                    return RusotoError::service(AddTagsToResourceError::Client(err.msg), &res)
                 }
                 "EntityDoesNotExistException" => {
-                    return RusotoError::Service(AddTagsToResourceError::EntityDoesNotExist(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        AddTagsToResourceError::EntityDoesNotExist(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(AddTagsToResourceError::InvalidParameter(err.msg))
+                    return RusotoError::service(
+                        AddTagsToResourceError::InvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(AddTagsToResourceError::Service(err.msg))
+                    return RusotoError::service(AddTagsToResourceError::Service(err.msg), &res)
                 }
                 "TagLimitExceededException" => {
-                    return RusotoError::Service(AddTagsToResourceError::TagLimitExceeded(err.msg))
+                    return RusotoError::service(
+                        AddTagsToResourceError::TagLimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(AddTagsToResourceError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2259,12 +2292,14 @@ impl fmt::Display for AddTagsToResourceError {
             AddTagsToResourceError::InvalidParameter(ref cause) => write!(f, "{}", cause),
             AddTagsToResourceError::Service(ref cause) => write!(f, "{}", cause),
             AddTagsToResourceError::TagLimitExceeded(ref cause) => write!(f, "{}", cause),
+            AddTagsToResourceError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for AddTagsToResourceError {}
 /// Errors returned by CancelSchemaExtension
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CancelSchemaExtensionError {
     /// <p>A client exception has occurred.</p>
     Client(String),
@@ -2272,6 +2307,8 @@ pub enum CancelSchemaExtensionError {
     EntityDoesNotExist(String),
     /// <p>An exception has occurred in AWS Directory Service.</p>
     Service(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CancelSchemaExtensionError {
@@ -2279,18 +2316,19 @@ impl CancelSchemaExtensionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ClientException" => {
-                    return RusotoError::Service(CancelSchemaExtensionError::Client(err.msg))
+                    return RusotoError::service(CancelSchemaExtensionError::Client(err.msg), &res)
                 }
                 "EntityDoesNotExistException" => {
-                    return RusotoError::Service(CancelSchemaExtensionError::EntityDoesNotExist(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CancelSchemaExtensionError::EntityDoesNotExist(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(CancelSchemaExtensionError::Service(err.msg))
+                    return RusotoError::service(CancelSchemaExtensionError::Service(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CancelSchemaExtensionError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2303,12 +2341,14 @@ impl fmt::Display for CancelSchemaExtensionError {
             CancelSchemaExtensionError::Client(ref cause) => write!(f, "{}", cause),
             CancelSchemaExtensionError::EntityDoesNotExist(ref cause) => write!(f, "{}", cause),
             CancelSchemaExtensionError::Service(ref cause) => write!(f, "{}", cause),
+            CancelSchemaExtensionError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CancelSchemaExtensionError {}
 /// Errors returned by ConnectDirectory
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ConnectDirectoryError {
     /// <p>A client exception has occurred.</p>
     Client(String),
@@ -2318,6 +2358,8 @@ pub enum ConnectDirectoryError {
     InvalidParameter(String),
     /// <p>An exception has occurred in AWS Directory Service.</p>
     Service(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ConnectDirectoryError {
@@ -2325,21 +2367,25 @@ impl ConnectDirectoryError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ClientException" => {
-                    return RusotoError::Service(ConnectDirectoryError::Client(err.msg))
+                    return RusotoError::service(ConnectDirectoryError::Client(err.msg), &res)
                 }
                 "DirectoryLimitExceededException" => {
-                    return RusotoError::Service(ConnectDirectoryError::DirectoryLimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ConnectDirectoryError::DirectoryLimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(ConnectDirectoryError::InvalidParameter(err.msg))
+                    return RusotoError::service(
+                        ConnectDirectoryError::InvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(ConnectDirectoryError::Service(err.msg))
+                    return RusotoError::service(ConnectDirectoryError::Service(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ConnectDirectoryError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2353,12 +2399,14 @@ impl fmt::Display for ConnectDirectoryError {
             ConnectDirectoryError::DirectoryLimitExceeded(ref cause) => write!(f, "{}", cause),
             ConnectDirectoryError::InvalidParameter(ref cause) => write!(f, "{}", cause),
             ConnectDirectoryError::Service(ref cause) => write!(f, "{}", cause),
+            ConnectDirectoryError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ConnectDirectoryError {}
 /// Errors returned by CreateAlias
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateAliasError {
     /// <p>A client exception has occurred.</p>
     Client(String),
@@ -2370,6 +2418,8 @@ pub enum CreateAliasError {
     InvalidParameter(String),
     /// <p>An exception has occurred in AWS Directory Service.</p>
     Service(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateAliasError {
@@ -2377,22 +2427,28 @@ impl CreateAliasError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ClientException" => {
-                    return RusotoError::Service(CreateAliasError::Client(err.msg))
+                    return RusotoError::service(CreateAliasError::Client(err.msg), &res)
                 }
                 "EntityAlreadyExistsException" => {
-                    return RusotoError::Service(CreateAliasError::EntityAlreadyExists(err.msg))
+                    return RusotoError::service(
+                        CreateAliasError::EntityAlreadyExists(err.msg),
+                        &res,
+                    )
                 }
                 "EntityDoesNotExistException" => {
-                    return RusotoError::Service(CreateAliasError::EntityDoesNotExist(err.msg))
+                    return RusotoError::service(
+                        CreateAliasError::EntityDoesNotExist(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(CreateAliasError::InvalidParameter(err.msg))
+                    return RusotoError::service(CreateAliasError::InvalidParameter(err.msg), &res)
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(CreateAliasError::Service(err.msg))
+                    return RusotoError::service(CreateAliasError::Service(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreateAliasError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2407,12 +2463,14 @@ impl fmt::Display for CreateAliasError {
             CreateAliasError::EntityDoesNotExist(ref cause) => write!(f, "{}", cause),
             CreateAliasError::InvalidParameter(ref cause) => write!(f, "{}", cause),
             CreateAliasError::Service(ref cause) => write!(f, "{}", cause),
+            CreateAliasError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateAliasError {}
 /// Errors returned by CreateComputer
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateComputerError {
     /// <p>An authentication error occurred.</p>
     AuthenticationFailed(String),
@@ -2430,6 +2488,8 @@ pub enum CreateComputerError {
     Service(String),
     /// <p>The operation is not supported.</p>
     UnsupportedOperation(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateComputerError {
@@ -2437,31 +2497,49 @@ impl CreateComputerError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AuthenticationFailedException" => {
-                    return RusotoError::Service(CreateComputerError::AuthenticationFailed(err.msg))
+                    return RusotoError::service(
+                        CreateComputerError::AuthenticationFailed(err.msg),
+                        &res,
+                    )
                 }
                 "ClientException" => {
-                    return RusotoError::Service(CreateComputerError::Client(err.msg))
+                    return RusotoError::service(CreateComputerError::Client(err.msg), &res)
                 }
                 "DirectoryUnavailableException" => {
-                    return RusotoError::Service(CreateComputerError::DirectoryUnavailable(err.msg))
+                    return RusotoError::service(
+                        CreateComputerError::DirectoryUnavailable(err.msg),
+                        &res,
+                    )
                 }
                 "EntityAlreadyExistsException" => {
-                    return RusotoError::Service(CreateComputerError::EntityAlreadyExists(err.msg))
+                    return RusotoError::service(
+                        CreateComputerError::EntityAlreadyExists(err.msg),
+                        &res,
+                    )
                 }
                 "EntityDoesNotExistException" => {
-                    return RusotoError::Service(CreateComputerError::EntityDoesNotExist(err.msg))
+                    return RusotoError::service(
+                        CreateComputerError::EntityDoesNotExist(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(CreateComputerError::InvalidParameter(err.msg))
+                    return RusotoError::service(
+                        CreateComputerError::InvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(CreateComputerError::Service(err.msg))
+                    return RusotoError::service(CreateComputerError::Service(err.msg), &res)
                 }
                 "UnsupportedOperationException" => {
-                    return RusotoError::Service(CreateComputerError::UnsupportedOperation(err.msg))
+                    return RusotoError::service(
+                        CreateComputerError::UnsupportedOperation(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreateComputerError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2479,12 +2557,14 @@ impl fmt::Display for CreateComputerError {
             CreateComputerError::InvalidParameter(ref cause) => write!(f, "{}", cause),
             CreateComputerError::Service(ref cause) => write!(f, "{}", cause),
             CreateComputerError::UnsupportedOperation(ref cause) => write!(f, "{}", cause),
+            CreateComputerError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateComputerError {}
 /// Errors returned by CreateConditionalForwarder
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateConditionalForwarderError {
     /// <p>A client exception has occurred.</p>
     Client(String),
@@ -2500,6 +2580,8 @@ pub enum CreateConditionalForwarderError {
     Service(String),
     /// <p>The operation is not supported.</p>
     UnsupportedOperation(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateConditionalForwarderError {
@@ -2509,38 +2591,51 @@ impl CreateConditionalForwarderError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ClientException" => {
-                    return RusotoError::Service(CreateConditionalForwarderError::Client(err.msg))
+                    return RusotoError::service(
+                        CreateConditionalForwarderError::Client(err.msg),
+                        &res,
+                    )
                 }
                 "DirectoryUnavailableException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         CreateConditionalForwarderError::DirectoryUnavailable(err.msg),
+                        &res,
                     )
                 }
                 "EntityAlreadyExistsException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         CreateConditionalForwarderError::EntityAlreadyExists(err.msg),
+                        &res,
                     )
                 }
                 "EntityDoesNotExistException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         CreateConditionalForwarderError::EntityDoesNotExist(err.msg),
+                        &res,
                     )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(CreateConditionalForwarderError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateConditionalForwarderError::InvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(CreateConditionalForwarderError::Service(err.msg))
+                    return RusotoError::service(
+                        CreateConditionalForwarderError::Service(err.msg),
+                        &res,
+                    )
                 }
                 "UnsupportedOperationException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         CreateConditionalForwarderError::UnsupportedOperation(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(CreateConditionalForwarderError::Unhandled, &res)
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -2565,12 +2660,14 @@ impl fmt::Display for CreateConditionalForwarderError {
             CreateConditionalForwarderError::UnsupportedOperation(ref cause) => {
                 write!(f, "{}", cause)
             }
+            CreateConditionalForwarderError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateConditionalForwarderError {}
 /// Errors returned by CreateDirectory
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateDirectoryError {
     /// <p>A client exception has occurred.</p>
     Client(String),
@@ -2580,6 +2677,8 @@ pub enum CreateDirectoryError {
     InvalidParameter(String),
     /// <p>An exception has occurred in AWS Directory Service.</p>
     Service(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateDirectoryError {
@@ -2587,21 +2686,25 @@ impl CreateDirectoryError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ClientException" => {
-                    return RusotoError::Service(CreateDirectoryError::Client(err.msg))
+                    return RusotoError::service(CreateDirectoryError::Client(err.msg), &res)
                 }
                 "DirectoryLimitExceededException" => {
-                    return RusotoError::Service(CreateDirectoryError::DirectoryLimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateDirectoryError::DirectoryLimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(CreateDirectoryError::InvalidParameter(err.msg))
+                    return RusotoError::service(
+                        CreateDirectoryError::InvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(CreateDirectoryError::Service(err.msg))
+                    return RusotoError::service(CreateDirectoryError::Service(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreateDirectoryError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2615,12 +2718,14 @@ impl fmt::Display for CreateDirectoryError {
             CreateDirectoryError::DirectoryLimitExceeded(ref cause) => write!(f, "{}", cause),
             CreateDirectoryError::InvalidParameter(ref cause) => write!(f, "{}", cause),
             CreateDirectoryError::Service(ref cause) => write!(f, "{}", cause),
+            CreateDirectoryError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateDirectoryError {}
 /// Errors returned by CreateLogSubscription
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateLogSubscriptionError {
     /// <p>A client exception has occurred.</p>
     Client(String),
@@ -2634,6 +2739,8 @@ pub enum CreateLogSubscriptionError {
     Service(String),
     /// <p>The operation is not supported.</p>
     UnsupportedOperation(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateLogSubscriptionError {
@@ -2641,33 +2748,37 @@ impl CreateLogSubscriptionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ClientException" => {
-                    return RusotoError::Service(CreateLogSubscriptionError::Client(err.msg))
+                    return RusotoError::service(CreateLogSubscriptionError::Client(err.msg), &res)
                 }
                 "EntityAlreadyExistsException" => {
-                    return RusotoError::Service(CreateLogSubscriptionError::EntityAlreadyExists(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateLogSubscriptionError::EntityAlreadyExists(err.msg),
+                        &res,
+                    )
                 }
                 "EntityDoesNotExistException" => {
-                    return RusotoError::Service(CreateLogSubscriptionError::EntityDoesNotExist(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateLogSubscriptionError::EntityDoesNotExist(err.msg),
+                        &res,
+                    )
                 }
                 "InsufficientPermissionsException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         CreateLogSubscriptionError::InsufficientPermissions(err.msg),
+                        &res,
                     )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(CreateLogSubscriptionError::Service(err.msg))
+                    return RusotoError::service(CreateLogSubscriptionError::Service(err.msg), &res)
                 }
                 "UnsupportedOperationException" => {
-                    return RusotoError::Service(CreateLogSubscriptionError::UnsupportedOperation(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateLogSubscriptionError::UnsupportedOperation(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreateLogSubscriptionError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2685,12 +2796,14 @@ impl fmt::Display for CreateLogSubscriptionError {
             }
             CreateLogSubscriptionError::Service(ref cause) => write!(f, "{}", cause),
             CreateLogSubscriptionError::UnsupportedOperation(ref cause) => write!(f, "{}", cause),
+            CreateLogSubscriptionError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateLogSubscriptionError {}
 /// Errors returned by CreateMicrosoftAD
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateMicrosoftADError {
     /// <p>A client exception has occurred.</p>
     Client(String),
@@ -2702,6 +2815,8 @@ pub enum CreateMicrosoftADError {
     Service(String),
     /// <p>The operation is not supported.</p>
     UnsupportedOperation(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateMicrosoftADError {
@@ -2709,26 +2824,31 @@ impl CreateMicrosoftADError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ClientException" => {
-                    return RusotoError::Service(CreateMicrosoftADError::Client(err.msg))
+                    return RusotoError::service(CreateMicrosoftADError::Client(err.msg), &res)
                 }
                 "DirectoryLimitExceededException" => {
-                    return RusotoError::Service(CreateMicrosoftADError::DirectoryLimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateMicrosoftADError::DirectoryLimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(CreateMicrosoftADError::InvalidParameter(err.msg))
+                    return RusotoError::service(
+                        CreateMicrosoftADError::InvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(CreateMicrosoftADError::Service(err.msg))
+                    return RusotoError::service(CreateMicrosoftADError::Service(err.msg), &res)
                 }
                 "UnsupportedOperationException" => {
-                    return RusotoError::Service(CreateMicrosoftADError::UnsupportedOperation(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateMicrosoftADError::UnsupportedOperation(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreateMicrosoftADError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2743,12 +2863,14 @@ impl fmt::Display for CreateMicrosoftADError {
             CreateMicrosoftADError::InvalidParameter(ref cause) => write!(f, "{}", cause),
             CreateMicrosoftADError::Service(ref cause) => write!(f, "{}", cause),
             CreateMicrosoftADError::UnsupportedOperation(ref cause) => write!(f, "{}", cause),
+            CreateMicrosoftADError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateMicrosoftADError {}
 /// Errors returned by CreateSnapshot
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateSnapshotError {
     /// <p>A client exception has occurred.</p>
     Client(String),
@@ -2760,6 +2882,8 @@ pub enum CreateSnapshotError {
     Service(String),
     /// <p>The maximum number of manual snapshots for the directory has been reached. You can use the <a>GetSnapshotLimits</a> operation to determine the snapshot limits for a directory.</p>
     SnapshotLimitExceeded(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateSnapshotError {
@@ -2767,24 +2891,31 @@ impl CreateSnapshotError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ClientException" => {
-                    return RusotoError::Service(CreateSnapshotError::Client(err.msg))
+                    return RusotoError::service(CreateSnapshotError::Client(err.msg), &res)
                 }
                 "EntityDoesNotExistException" => {
-                    return RusotoError::Service(CreateSnapshotError::EntityDoesNotExist(err.msg))
+                    return RusotoError::service(
+                        CreateSnapshotError::EntityDoesNotExist(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(CreateSnapshotError::InvalidParameter(err.msg))
+                    return RusotoError::service(
+                        CreateSnapshotError::InvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(CreateSnapshotError::Service(err.msg))
+                    return RusotoError::service(CreateSnapshotError::Service(err.msg), &res)
                 }
                 "SnapshotLimitExceededException" => {
-                    return RusotoError::Service(CreateSnapshotError::SnapshotLimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateSnapshotError::SnapshotLimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreateSnapshotError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2799,12 +2930,14 @@ impl fmt::Display for CreateSnapshotError {
             CreateSnapshotError::InvalidParameter(ref cause) => write!(f, "{}", cause),
             CreateSnapshotError::Service(ref cause) => write!(f, "{}", cause),
             CreateSnapshotError::SnapshotLimitExceeded(ref cause) => write!(f, "{}", cause),
+            CreateSnapshotError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateSnapshotError {}
 /// Errors returned by CreateTrust
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateTrustError {
     /// <p>A client exception has occurred.</p>
     Client(String),
@@ -2818,6 +2951,8 @@ pub enum CreateTrustError {
     Service(String),
     /// <p>The operation is not supported.</p>
     UnsupportedOperation(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateTrustError {
@@ -2825,25 +2960,34 @@ impl CreateTrustError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ClientException" => {
-                    return RusotoError::Service(CreateTrustError::Client(err.msg))
+                    return RusotoError::service(CreateTrustError::Client(err.msg), &res)
                 }
                 "EntityAlreadyExistsException" => {
-                    return RusotoError::Service(CreateTrustError::EntityAlreadyExists(err.msg))
+                    return RusotoError::service(
+                        CreateTrustError::EntityAlreadyExists(err.msg),
+                        &res,
+                    )
                 }
                 "EntityDoesNotExistException" => {
-                    return RusotoError::Service(CreateTrustError::EntityDoesNotExist(err.msg))
+                    return RusotoError::service(
+                        CreateTrustError::EntityDoesNotExist(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(CreateTrustError::InvalidParameter(err.msg))
+                    return RusotoError::service(CreateTrustError::InvalidParameter(err.msg), &res)
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(CreateTrustError::Service(err.msg))
+                    return RusotoError::service(CreateTrustError::Service(err.msg), &res)
                 }
                 "UnsupportedOperationException" => {
-                    return RusotoError::Service(CreateTrustError::UnsupportedOperation(err.msg))
+                    return RusotoError::service(
+                        CreateTrustError::UnsupportedOperation(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreateTrustError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2859,12 +3003,14 @@ impl fmt::Display for CreateTrustError {
             CreateTrustError::InvalidParameter(ref cause) => write!(f, "{}", cause),
             CreateTrustError::Service(ref cause) => write!(f, "{}", cause),
             CreateTrustError::UnsupportedOperation(ref cause) => write!(f, "{}", cause),
+            CreateTrustError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateTrustError {}
 /// Errors returned by DeleteConditionalForwarder
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteConditionalForwarderError {
     /// <p>A client exception has occurred.</p>
     Client(String),
@@ -2878,6 +3024,8 @@ pub enum DeleteConditionalForwarderError {
     Service(String),
     /// <p>The operation is not supported.</p>
     UnsupportedOperation(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteConditionalForwarderError {
@@ -2887,33 +3035,45 @@ impl DeleteConditionalForwarderError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ClientException" => {
-                    return RusotoError::Service(DeleteConditionalForwarderError::Client(err.msg))
+                    return RusotoError::service(
+                        DeleteConditionalForwarderError::Client(err.msg),
+                        &res,
+                    )
                 }
                 "DirectoryUnavailableException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DeleteConditionalForwarderError::DirectoryUnavailable(err.msg),
+                        &res,
                     )
                 }
                 "EntityDoesNotExistException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DeleteConditionalForwarderError::EntityDoesNotExist(err.msg),
+                        &res,
                     )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DeleteConditionalForwarderError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteConditionalForwarderError::InvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(DeleteConditionalForwarderError::Service(err.msg))
+                    return RusotoError::service(
+                        DeleteConditionalForwarderError::Service(err.msg),
+                        &res,
+                    )
                 }
                 "UnsupportedOperationException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DeleteConditionalForwarderError::UnsupportedOperation(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(DeleteConditionalForwarderError::Unhandled, &res)
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -2935,12 +3095,14 @@ impl fmt::Display for DeleteConditionalForwarderError {
             DeleteConditionalForwarderError::UnsupportedOperation(ref cause) => {
                 write!(f, "{}", cause)
             }
+            DeleteConditionalForwarderError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteConditionalForwarderError {}
 /// Errors returned by DeleteDirectory
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteDirectoryError {
     /// <p>A client exception has occurred.</p>
     Client(String),
@@ -2948,6 +3110,8 @@ pub enum DeleteDirectoryError {
     EntityDoesNotExist(String),
     /// <p>An exception has occurred in AWS Directory Service.</p>
     Service(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteDirectoryError {
@@ -2955,16 +3119,19 @@ impl DeleteDirectoryError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ClientException" => {
-                    return RusotoError::Service(DeleteDirectoryError::Client(err.msg))
+                    return RusotoError::service(DeleteDirectoryError::Client(err.msg), &res)
                 }
                 "EntityDoesNotExistException" => {
-                    return RusotoError::Service(DeleteDirectoryError::EntityDoesNotExist(err.msg))
+                    return RusotoError::service(
+                        DeleteDirectoryError::EntityDoesNotExist(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(DeleteDirectoryError::Service(err.msg))
+                    return RusotoError::service(DeleteDirectoryError::Service(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteDirectoryError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2977,12 +3144,14 @@ impl fmt::Display for DeleteDirectoryError {
             DeleteDirectoryError::Client(ref cause) => write!(f, "{}", cause),
             DeleteDirectoryError::EntityDoesNotExist(ref cause) => write!(f, "{}", cause),
             DeleteDirectoryError::Service(ref cause) => write!(f, "{}", cause),
+            DeleteDirectoryError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteDirectoryError {}
 /// Errors returned by DeleteLogSubscription
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteLogSubscriptionError {
     /// <p>A client exception has occurred.</p>
     Client(String),
@@ -2992,6 +3161,8 @@ pub enum DeleteLogSubscriptionError {
     Service(String),
     /// <p>The operation is not supported.</p>
     UnsupportedOperation(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteLogSubscriptionError {
@@ -2999,23 +3170,25 @@ impl DeleteLogSubscriptionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ClientException" => {
-                    return RusotoError::Service(DeleteLogSubscriptionError::Client(err.msg))
+                    return RusotoError::service(DeleteLogSubscriptionError::Client(err.msg), &res)
                 }
                 "EntityDoesNotExistException" => {
-                    return RusotoError::Service(DeleteLogSubscriptionError::EntityDoesNotExist(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteLogSubscriptionError::EntityDoesNotExist(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(DeleteLogSubscriptionError::Service(err.msg))
+                    return RusotoError::service(DeleteLogSubscriptionError::Service(err.msg), &res)
                 }
                 "UnsupportedOperationException" => {
-                    return RusotoError::Service(DeleteLogSubscriptionError::UnsupportedOperation(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteLogSubscriptionError::UnsupportedOperation(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteLogSubscriptionError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3029,12 +3202,14 @@ impl fmt::Display for DeleteLogSubscriptionError {
             DeleteLogSubscriptionError::EntityDoesNotExist(ref cause) => write!(f, "{}", cause),
             DeleteLogSubscriptionError::Service(ref cause) => write!(f, "{}", cause),
             DeleteLogSubscriptionError::UnsupportedOperation(ref cause) => write!(f, "{}", cause),
+            DeleteLogSubscriptionError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteLogSubscriptionError {}
 /// Errors returned by DeleteSnapshot
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteSnapshotError {
     /// <p>A client exception has occurred.</p>
     Client(String),
@@ -3044,6 +3219,8 @@ pub enum DeleteSnapshotError {
     InvalidParameter(String),
     /// <p>An exception has occurred in AWS Directory Service.</p>
     Service(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteSnapshotError {
@@ -3051,19 +3228,25 @@ impl DeleteSnapshotError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ClientException" => {
-                    return RusotoError::Service(DeleteSnapshotError::Client(err.msg))
+                    return RusotoError::service(DeleteSnapshotError::Client(err.msg), &res)
                 }
                 "EntityDoesNotExistException" => {
-                    return RusotoError::Service(DeleteSnapshotError::EntityDoesNotExist(err.msg))
+                    return RusotoError::service(
+                        DeleteSnapshotError::EntityDoesNotExist(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DeleteSnapshotError::InvalidParameter(err.msg))
+                    return RusotoError::service(
+                        DeleteSnapshotError::InvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(DeleteSnapshotError::Service(err.msg))
+                    return RusotoError::service(DeleteSnapshotError::Service(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteSnapshotError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3077,12 +3260,14 @@ impl fmt::Display for DeleteSnapshotError {
             DeleteSnapshotError::EntityDoesNotExist(ref cause) => write!(f, "{}", cause),
             DeleteSnapshotError::InvalidParameter(ref cause) => write!(f, "{}", cause),
             DeleteSnapshotError::Service(ref cause) => write!(f, "{}", cause),
+            DeleteSnapshotError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteSnapshotError {}
 /// Errors returned by DeleteTrust
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteTrustError {
     /// <p>A client exception has occurred.</p>
     Client(String),
@@ -3094,6 +3279,8 @@ pub enum DeleteTrustError {
     Service(String),
     /// <p>The operation is not supported.</p>
     UnsupportedOperation(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteTrustError {
@@ -3101,22 +3288,28 @@ impl DeleteTrustError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ClientException" => {
-                    return RusotoError::Service(DeleteTrustError::Client(err.msg))
+                    return RusotoError::service(DeleteTrustError::Client(err.msg), &res)
                 }
                 "EntityDoesNotExistException" => {
-                    return RusotoError::Service(DeleteTrustError::EntityDoesNotExist(err.msg))
+                    return RusotoError::service(
+                        DeleteTrustError::EntityDoesNotExist(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DeleteTrustError::InvalidParameter(err.msg))
+                    return RusotoError::service(DeleteTrustError::InvalidParameter(err.msg), &res)
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(DeleteTrustError::Service(err.msg))
+                    return RusotoError::service(DeleteTrustError::Service(err.msg), &res)
                 }
                 "UnsupportedOperationException" => {
-                    return RusotoError::Service(DeleteTrustError::UnsupportedOperation(err.msg))
+                    return RusotoError::service(
+                        DeleteTrustError::UnsupportedOperation(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteTrustError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3131,12 +3324,14 @@ impl fmt::Display for DeleteTrustError {
             DeleteTrustError::InvalidParameter(ref cause) => write!(f, "{}", cause),
             DeleteTrustError::Service(ref cause) => write!(f, "{}", cause),
             DeleteTrustError::UnsupportedOperation(ref cause) => write!(f, "{}", cause),
+            DeleteTrustError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteTrustError {}
 /// Errors returned by DeregisterCertificate
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeregisterCertificateError {
     /// <p>The certificate is not present in the system for describe or deregister activities.</p>
     CertificateDoesNotExist(String),
@@ -3154,6 +3349,8 @@ pub enum DeregisterCertificateError {
     Service(String),
     /// <p>The operation is not supported.</p>
     UnsupportedOperation(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeregisterCertificateError {
@@ -3161,43 +3358,49 @@ impl DeregisterCertificateError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "CertificateDoesNotExistException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DeregisterCertificateError::CertificateDoesNotExist(err.msg),
+                        &res,
                     )
                 }
                 "CertificateInUseException" => {
-                    return RusotoError::Service(DeregisterCertificateError::CertificateInUse(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeregisterCertificateError::CertificateInUse(err.msg),
+                        &res,
+                    )
                 }
                 "ClientException" => {
-                    return RusotoError::Service(DeregisterCertificateError::Client(err.msg))
+                    return RusotoError::service(DeregisterCertificateError::Client(err.msg), &res)
                 }
                 "DirectoryDoesNotExistException" => {
-                    return RusotoError::Service(DeregisterCertificateError::DirectoryDoesNotExist(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeregisterCertificateError::DirectoryDoesNotExist(err.msg),
+                        &res,
+                    )
                 }
                 "DirectoryUnavailableException" => {
-                    return RusotoError::Service(DeregisterCertificateError::DirectoryUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeregisterCertificateError::DirectoryUnavailable(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DeregisterCertificateError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeregisterCertificateError::InvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(DeregisterCertificateError::Service(err.msg))
+                    return RusotoError::service(DeregisterCertificateError::Service(err.msg), &res)
                 }
                 "UnsupportedOperationException" => {
-                    return RusotoError::Service(DeregisterCertificateError::UnsupportedOperation(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeregisterCertificateError::UnsupportedOperation(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeregisterCertificateError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3217,12 +3420,14 @@ impl fmt::Display for DeregisterCertificateError {
             DeregisterCertificateError::InvalidParameter(ref cause) => write!(f, "{}", cause),
             DeregisterCertificateError::Service(ref cause) => write!(f, "{}", cause),
             DeregisterCertificateError::UnsupportedOperation(ref cause) => write!(f, "{}", cause),
+            DeregisterCertificateError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeregisterCertificateError {}
 /// Errors returned by DeregisterEventTopic
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeregisterEventTopicError {
     /// <p>A client exception has occurred.</p>
     Client(String),
@@ -3232,6 +3437,8 @@ pub enum DeregisterEventTopicError {
     InvalidParameter(String),
     /// <p>An exception has occurred in AWS Directory Service.</p>
     Service(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeregisterEventTopicError {
@@ -3239,23 +3446,25 @@ impl DeregisterEventTopicError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ClientException" => {
-                    return RusotoError::Service(DeregisterEventTopicError::Client(err.msg))
+                    return RusotoError::service(DeregisterEventTopicError::Client(err.msg), &res)
                 }
                 "EntityDoesNotExistException" => {
-                    return RusotoError::Service(DeregisterEventTopicError::EntityDoesNotExist(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeregisterEventTopicError::EntityDoesNotExist(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DeregisterEventTopicError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeregisterEventTopicError::InvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(DeregisterEventTopicError::Service(err.msg))
+                    return RusotoError::service(DeregisterEventTopicError::Service(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeregisterEventTopicError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3269,12 +3478,14 @@ impl fmt::Display for DeregisterEventTopicError {
             DeregisterEventTopicError::EntityDoesNotExist(ref cause) => write!(f, "{}", cause),
             DeregisterEventTopicError::InvalidParameter(ref cause) => write!(f, "{}", cause),
             DeregisterEventTopicError::Service(ref cause) => write!(f, "{}", cause),
+            DeregisterEventTopicError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeregisterEventTopicError {}
 /// Errors returned by DescribeCertificate
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeCertificateError {
     /// <p>The certificate is not present in the system for describe or deregister activities.</p>
     CertificateDoesNotExist(String),
@@ -3288,6 +3499,8 @@ pub enum DescribeCertificateError {
     Service(String),
     /// <p>The operation is not supported.</p>
     UnsupportedOperation(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeCertificateError {
@@ -3295,33 +3508,37 @@ impl DescribeCertificateError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "CertificateDoesNotExistException" => {
-                    return RusotoError::Service(DescribeCertificateError::CertificateDoesNotExist(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeCertificateError::CertificateDoesNotExist(err.msg),
+                        &res,
+                    )
                 }
                 "ClientException" => {
-                    return RusotoError::Service(DescribeCertificateError::Client(err.msg))
+                    return RusotoError::service(DescribeCertificateError::Client(err.msg), &res)
                 }
                 "DirectoryDoesNotExistException" => {
-                    return RusotoError::Service(DescribeCertificateError::DirectoryDoesNotExist(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeCertificateError::DirectoryDoesNotExist(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DescribeCertificateError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeCertificateError::InvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(DescribeCertificateError::Service(err.msg))
+                    return RusotoError::service(DescribeCertificateError::Service(err.msg), &res)
                 }
                 "UnsupportedOperationException" => {
-                    return RusotoError::Service(DescribeCertificateError::UnsupportedOperation(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeCertificateError::UnsupportedOperation(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DescribeCertificateError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3337,12 +3554,14 @@ impl fmt::Display for DescribeCertificateError {
             DescribeCertificateError::InvalidParameter(ref cause) => write!(f, "{}", cause),
             DescribeCertificateError::Service(ref cause) => write!(f, "{}", cause),
             DescribeCertificateError::UnsupportedOperation(ref cause) => write!(f, "{}", cause),
+            DescribeCertificateError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeCertificateError {}
 /// Errors returned by DescribeConditionalForwarders
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeConditionalForwardersError {
     /// <p>A client exception has occurred.</p>
     Client(String),
@@ -3356,6 +3575,8 @@ pub enum DescribeConditionalForwardersError {
     Service(String),
     /// <p>The operation is not supported.</p>
     UnsupportedOperation(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeConditionalForwardersError {
@@ -3365,37 +3586,48 @@ impl DescribeConditionalForwardersError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ClientException" => {
-                    return RusotoError::Service(DescribeConditionalForwardersError::Client(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeConditionalForwardersError::Client(err.msg),
+                        &res,
+                    )
                 }
                 "DirectoryUnavailableException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DescribeConditionalForwardersError::DirectoryUnavailable(err.msg),
+                        &res,
                     )
                 }
                 "EntityDoesNotExistException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DescribeConditionalForwardersError::EntityDoesNotExist(err.msg),
+                        &res,
                     )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DescribeConditionalForwardersError::InvalidParameter(err.msg),
+                        &res,
                     )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(DescribeConditionalForwardersError::Service(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeConditionalForwardersError::Service(err.msg),
+                        &res,
+                    )
                 }
                 "UnsupportedOperationException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DescribeConditionalForwardersError::UnsupportedOperation(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        DescribeConditionalForwardersError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -3419,12 +3651,16 @@ impl fmt::Display for DescribeConditionalForwardersError {
             DescribeConditionalForwardersError::UnsupportedOperation(ref cause) => {
                 write!(f, "{}", cause)
             }
+            DescribeConditionalForwardersError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for DescribeConditionalForwardersError {}
 /// Errors returned by DescribeDirectories
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeDirectoriesError {
     /// <p>A client exception has occurred.</p>
     Client(String),
@@ -3436,6 +3672,8 @@ pub enum DescribeDirectoriesError {
     InvalidParameter(String),
     /// <p>An exception has occurred in AWS Directory Service.</p>
     Service(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeDirectoriesError {
@@ -3443,28 +3681,31 @@ impl DescribeDirectoriesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ClientException" => {
-                    return RusotoError::Service(DescribeDirectoriesError::Client(err.msg))
+                    return RusotoError::service(DescribeDirectoriesError::Client(err.msg), &res)
                 }
                 "EntityDoesNotExistException" => {
-                    return RusotoError::Service(DescribeDirectoriesError::EntityDoesNotExist(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeDirectoriesError::EntityDoesNotExist(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(DescribeDirectoriesError::InvalidNextToken(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeDirectoriesError::InvalidNextToken(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DescribeDirectoriesError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeDirectoriesError::InvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(DescribeDirectoriesError::Service(err.msg))
+                    return RusotoError::service(DescribeDirectoriesError::Service(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DescribeDirectoriesError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3479,12 +3720,14 @@ impl fmt::Display for DescribeDirectoriesError {
             DescribeDirectoriesError::InvalidNextToken(ref cause) => write!(f, "{}", cause),
             DescribeDirectoriesError::InvalidParameter(ref cause) => write!(f, "{}", cause),
             DescribeDirectoriesError::Service(ref cause) => write!(f, "{}", cause),
+            DescribeDirectoriesError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeDirectoriesError {}
 /// Errors returned by DescribeDomainControllers
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeDomainControllersError {
     /// <p>A client exception has occurred.</p>
     Client(String),
@@ -3498,6 +3741,8 @@ pub enum DescribeDomainControllersError {
     Service(String),
     /// <p>The operation is not supported.</p>
     UnsupportedOperation(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeDomainControllersError {
@@ -3505,33 +3750,45 @@ impl DescribeDomainControllersError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ClientException" => {
-                    return RusotoError::Service(DescribeDomainControllersError::Client(err.msg))
+                    return RusotoError::service(
+                        DescribeDomainControllersError::Client(err.msg),
+                        &res,
+                    )
                 }
                 "EntityDoesNotExistException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DescribeDomainControllersError::EntityDoesNotExist(err.msg),
+                        &res,
                     )
                 }
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(DescribeDomainControllersError::InvalidNextToken(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeDomainControllersError::InvalidNextToken(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DescribeDomainControllersError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeDomainControllersError::InvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(DescribeDomainControllersError::Service(err.msg))
+                    return RusotoError::service(
+                        DescribeDomainControllersError::Service(err.msg),
+                        &res,
+                    )
                 }
                 "UnsupportedOperationException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DescribeDomainControllersError::UnsupportedOperation(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(DescribeDomainControllersError::Unhandled, &res)
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -3549,12 +3806,14 @@ impl fmt::Display for DescribeDomainControllersError {
             DescribeDomainControllersError::UnsupportedOperation(ref cause) => {
                 write!(f, "{}", cause)
             }
+            DescribeDomainControllersError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeDomainControllersError {}
 /// Errors returned by DescribeEventTopics
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeEventTopicsError {
     /// <p>A client exception has occurred.</p>
     Client(String),
@@ -3564,6 +3823,8 @@ pub enum DescribeEventTopicsError {
     InvalidParameter(String),
     /// <p>An exception has occurred in AWS Directory Service.</p>
     Service(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeEventTopicsError {
@@ -3571,23 +3832,25 @@ impl DescribeEventTopicsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ClientException" => {
-                    return RusotoError::Service(DescribeEventTopicsError::Client(err.msg))
+                    return RusotoError::service(DescribeEventTopicsError::Client(err.msg), &res)
                 }
                 "EntityDoesNotExistException" => {
-                    return RusotoError::Service(DescribeEventTopicsError::EntityDoesNotExist(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeEventTopicsError::EntityDoesNotExist(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DescribeEventTopicsError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeEventTopicsError::InvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(DescribeEventTopicsError::Service(err.msg))
+                    return RusotoError::service(DescribeEventTopicsError::Service(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DescribeEventTopicsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3601,12 +3864,14 @@ impl fmt::Display for DescribeEventTopicsError {
             DescribeEventTopicsError::EntityDoesNotExist(ref cause) => write!(f, "{}", cause),
             DescribeEventTopicsError::InvalidParameter(ref cause) => write!(f, "{}", cause),
             DescribeEventTopicsError::Service(ref cause) => write!(f, "{}", cause),
+            DescribeEventTopicsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeEventTopicsError {}
 /// Errors returned by DescribeLDAPSSettings
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeLDAPSSettingsError {
     /// <p>A client exception has occurred.</p>
     Client(String),
@@ -3620,6 +3885,8 @@ pub enum DescribeLDAPSSettingsError {
     Service(String),
     /// <p>The operation is not supported.</p>
     UnsupportedOperation(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeLDAPSSettingsError {
@@ -3627,33 +3894,37 @@ impl DescribeLDAPSSettingsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ClientException" => {
-                    return RusotoError::Service(DescribeLDAPSSettingsError::Client(err.msg))
+                    return RusotoError::service(DescribeLDAPSSettingsError::Client(err.msg), &res)
                 }
                 "DirectoryDoesNotExistException" => {
-                    return RusotoError::Service(DescribeLDAPSSettingsError::DirectoryDoesNotExist(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeLDAPSSettingsError::DirectoryDoesNotExist(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(DescribeLDAPSSettingsError::InvalidNextToken(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeLDAPSSettingsError::InvalidNextToken(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DescribeLDAPSSettingsError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeLDAPSSettingsError::InvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(DescribeLDAPSSettingsError::Service(err.msg))
+                    return RusotoError::service(DescribeLDAPSSettingsError::Service(err.msg), &res)
                 }
                 "UnsupportedOperationException" => {
-                    return RusotoError::Service(DescribeLDAPSSettingsError::UnsupportedOperation(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeLDAPSSettingsError::UnsupportedOperation(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DescribeLDAPSSettingsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3669,12 +3940,14 @@ impl fmt::Display for DescribeLDAPSSettingsError {
             DescribeLDAPSSettingsError::InvalidParameter(ref cause) => write!(f, "{}", cause),
             DescribeLDAPSSettingsError::Service(ref cause) => write!(f, "{}", cause),
             DescribeLDAPSSettingsError::UnsupportedOperation(ref cause) => write!(f, "{}", cause),
+            DescribeLDAPSSettingsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeLDAPSSettingsError {}
 /// Errors returned by DescribeSharedDirectories
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeSharedDirectoriesError {
     /// <p>A client exception has occurred.</p>
     Client(String),
@@ -3688,6 +3961,8 @@ pub enum DescribeSharedDirectoriesError {
     Service(String),
     /// <p>The operation is not supported.</p>
     UnsupportedOperation(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeSharedDirectoriesError {
@@ -3695,33 +3970,45 @@ impl DescribeSharedDirectoriesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ClientException" => {
-                    return RusotoError::Service(DescribeSharedDirectoriesError::Client(err.msg))
+                    return RusotoError::service(
+                        DescribeSharedDirectoriesError::Client(err.msg),
+                        &res,
+                    )
                 }
                 "EntityDoesNotExistException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DescribeSharedDirectoriesError::EntityDoesNotExist(err.msg),
+                        &res,
                     )
                 }
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(DescribeSharedDirectoriesError::InvalidNextToken(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeSharedDirectoriesError::InvalidNextToken(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DescribeSharedDirectoriesError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeSharedDirectoriesError::InvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(DescribeSharedDirectoriesError::Service(err.msg))
+                    return RusotoError::service(
+                        DescribeSharedDirectoriesError::Service(err.msg),
+                        &res,
+                    )
                 }
                 "UnsupportedOperationException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DescribeSharedDirectoriesError::UnsupportedOperation(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(DescribeSharedDirectoriesError::Unhandled, &res)
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -3739,12 +4026,14 @@ impl fmt::Display for DescribeSharedDirectoriesError {
             DescribeSharedDirectoriesError::UnsupportedOperation(ref cause) => {
                 write!(f, "{}", cause)
             }
+            DescribeSharedDirectoriesError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeSharedDirectoriesError {}
 /// Errors returned by DescribeSnapshots
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeSnapshotsError {
     /// <p>A client exception has occurred.</p>
     Client(String),
@@ -3756,6 +4045,8 @@ pub enum DescribeSnapshotsError {
     InvalidParameter(String),
     /// <p>An exception has occurred in AWS Directory Service.</p>
     Service(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeSnapshotsError {
@@ -3763,24 +4054,31 @@ impl DescribeSnapshotsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ClientException" => {
-                    return RusotoError::Service(DescribeSnapshotsError::Client(err.msg))
+                    return RusotoError::service(DescribeSnapshotsError::Client(err.msg), &res)
                 }
                 "EntityDoesNotExistException" => {
-                    return RusotoError::Service(DescribeSnapshotsError::EntityDoesNotExist(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeSnapshotsError::EntityDoesNotExist(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(DescribeSnapshotsError::InvalidNextToken(err.msg))
+                    return RusotoError::service(
+                        DescribeSnapshotsError::InvalidNextToken(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DescribeSnapshotsError::InvalidParameter(err.msg))
+                    return RusotoError::service(
+                        DescribeSnapshotsError::InvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(DescribeSnapshotsError::Service(err.msg))
+                    return RusotoError::service(DescribeSnapshotsError::Service(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DescribeSnapshotsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3795,12 +4093,14 @@ impl fmt::Display for DescribeSnapshotsError {
             DescribeSnapshotsError::InvalidNextToken(ref cause) => write!(f, "{}", cause),
             DescribeSnapshotsError::InvalidParameter(ref cause) => write!(f, "{}", cause),
             DescribeSnapshotsError::Service(ref cause) => write!(f, "{}", cause),
+            DescribeSnapshotsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeSnapshotsError {}
 /// Errors returned by DescribeTrusts
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeTrustsError {
     /// <p>A client exception has occurred.</p>
     Client(String),
@@ -3814,6 +4114,8 @@ pub enum DescribeTrustsError {
     Service(String),
     /// <p>The operation is not supported.</p>
     UnsupportedOperation(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeTrustsError {
@@ -3821,25 +4123,37 @@ impl DescribeTrustsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ClientException" => {
-                    return RusotoError::Service(DescribeTrustsError::Client(err.msg))
+                    return RusotoError::service(DescribeTrustsError::Client(err.msg), &res)
                 }
                 "EntityDoesNotExistException" => {
-                    return RusotoError::Service(DescribeTrustsError::EntityDoesNotExist(err.msg))
+                    return RusotoError::service(
+                        DescribeTrustsError::EntityDoesNotExist(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(DescribeTrustsError::InvalidNextToken(err.msg))
+                    return RusotoError::service(
+                        DescribeTrustsError::InvalidNextToken(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DescribeTrustsError::InvalidParameter(err.msg))
+                    return RusotoError::service(
+                        DescribeTrustsError::InvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(DescribeTrustsError::Service(err.msg))
+                    return RusotoError::service(DescribeTrustsError::Service(err.msg), &res)
                 }
                 "UnsupportedOperationException" => {
-                    return RusotoError::Service(DescribeTrustsError::UnsupportedOperation(err.msg))
+                    return RusotoError::service(
+                        DescribeTrustsError::UnsupportedOperation(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DescribeTrustsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3855,12 +4169,14 @@ impl fmt::Display for DescribeTrustsError {
             DescribeTrustsError::InvalidParameter(ref cause) => write!(f, "{}", cause),
             DescribeTrustsError::Service(ref cause) => write!(f, "{}", cause),
             DescribeTrustsError::UnsupportedOperation(ref cause) => write!(f, "{}", cause),
+            DescribeTrustsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeTrustsError {}
 /// Errors returned by DisableLDAPS
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DisableLDAPSError {
     /// <p>A client exception has occurred.</p>
     Client(String),
@@ -3876,6 +4192,8 @@ pub enum DisableLDAPSError {
     Service(String),
     /// <p>The operation is not supported.</p>
     UnsupportedOperation(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DisableLDAPSError {
@@ -3883,28 +4201,40 @@ impl DisableLDAPSError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ClientException" => {
-                    return RusotoError::Service(DisableLDAPSError::Client(err.msg))
+                    return RusotoError::service(DisableLDAPSError::Client(err.msg), &res)
                 }
                 "DirectoryDoesNotExistException" => {
-                    return RusotoError::Service(DisableLDAPSError::DirectoryDoesNotExist(err.msg))
+                    return RusotoError::service(
+                        DisableLDAPSError::DirectoryDoesNotExist(err.msg),
+                        &res,
+                    )
                 }
                 "DirectoryUnavailableException" => {
-                    return RusotoError::Service(DisableLDAPSError::DirectoryUnavailable(err.msg))
+                    return RusotoError::service(
+                        DisableLDAPSError::DirectoryUnavailable(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidLDAPSStatusException" => {
-                    return RusotoError::Service(DisableLDAPSError::InvalidLDAPSStatus(err.msg))
+                    return RusotoError::service(
+                        DisableLDAPSError::InvalidLDAPSStatus(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DisableLDAPSError::InvalidParameter(err.msg))
+                    return RusotoError::service(DisableLDAPSError::InvalidParameter(err.msg), &res)
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(DisableLDAPSError::Service(err.msg))
+                    return RusotoError::service(DisableLDAPSError::Service(err.msg), &res)
                 }
                 "UnsupportedOperationException" => {
-                    return RusotoError::Service(DisableLDAPSError::UnsupportedOperation(err.msg))
+                    return RusotoError::service(
+                        DisableLDAPSError::UnsupportedOperation(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DisableLDAPSError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3921,12 +4251,14 @@ impl fmt::Display for DisableLDAPSError {
             DisableLDAPSError::InvalidParameter(ref cause) => write!(f, "{}", cause),
             DisableLDAPSError::Service(ref cause) => write!(f, "{}", cause),
             DisableLDAPSError::UnsupportedOperation(ref cause) => write!(f, "{}", cause),
+            DisableLDAPSError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DisableLDAPSError {}
 /// Errors returned by DisableRadius
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DisableRadiusError {
     /// <p>A client exception has occurred.</p>
     Client(String),
@@ -3934,6 +4266,8 @@ pub enum DisableRadiusError {
     EntityDoesNotExist(String),
     /// <p>An exception has occurred in AWS Directory Service.</p>
     Service(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DisableRadiusError {
@@ -3941,16 +4275,19 @@ impl DisableRadiusError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ClientException" => {
-                    return RusotoError::Service(DisableRadiusError::Client(err.msg))
+                    return RusotoError::service(DisableRadiusError::Client(err.msg), &res)
                 }
                 "EntityDoesNotExistException" => {
-                    return RusotoError::Service(DisableRadiusError::EntityDoesNotExist(err.msg))
+                    return RusotoError::service(
+                        DisableRadiusError::EntityDoesNotExist(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(DisableRadiusError::Service(err.msg))
+                    return RusotoError::service(DisableRadiusError::Service(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DisableRadiusError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3963,12 +4300,14 @@ impl fmt::Display for DisableRadiusError {
             DisableRadiusError::Client(ref cause) => write!(f, "{}", cause),
             DisableRadiusError::EntityDoesNotExist(ref cause) => write!(f, "{}", cause),
             DisableRadiusError::Service(ref cause) => write!(f, "{}", cause),
+            DisableRadiusError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DisableRadiusError {}
 /// Errors returned by DisableSso
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DisableSsoError {
     /// <p>An authentication error occurred.</p>
     AuthenticationFailed(String),
@@ -3980,6 +4319,8 @@ pub enum DisableSsoError {
     InsufficientPermissions(String),
     /// <p>An exception has occurred in AWS Directory Service.</p>
     Service(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DisableSsoError {
@@ -3987,20 +4328,28 @@ impl DisableSsoError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AuthenticationFailedException" => {
-                    return RusotoError::Service(DisableSsoError::AuthenticationFailed(err.msg))
+                    return RusotoError::service(
+                        DisableSsoError::AuthenticationFailed(err.msg),
+                        &res,
+                    )
+                }
+                "ClientException" => {
+                    return RusotoError::service(DisableSsoError::Client(err.msg), &res)
                 }
-                "ClientException" => return RusotoError::Service(DisableSsoError::Client(err.msg)),
                 "EntityDoesNotExistException" => {
-                    return RusotoError::Service(DisableSsoError::EntityDoesNotExist(err.msg))
+                    return RusotoError::service(DisableSsoError::EntityDoesNotExist(err.msg), &res)
                 }
                 "InsufficientPermissionsException" => {
-                    return RusotoError::Service(DisableSsoError::InsufficientPermissions(err.msg))
+                    return RusotoError::service(
+                        DisableSsoError::InsufficientPermissions(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(DisableSsoError::Service(err.msg))
+                    return RusotoError::service(DisableSsoError::Service(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DisableSsoError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -4015,12 +4364,14 @@ impl fmt::Display for DisableSsoError {
             DisableSsoError::EntityDoesNotExist(ref cause) => write!(f, "{}", cause),
             DisableSsoError::InsufficientPermissions(ref cause) => write!(f, "{}", cause),
             DisableSsoError::Service(ref cause) => write!(f, "{}", cause),
+            DisableSsoError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DisableSsoError {}
 /// Errors returned by EnableLDAPS
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum EnableLDAPSError {
     /// <p>A client exception has occurred.</p>
     Client(String),
@@ -4038,6 +4389,8 @@ pub enum EnableLDAPSError {
     Service(String),
     /// <p>The operation is not supported.</p>
     UnsupportedOperation(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl EnableLDAPSError {
@@ -4045,31 +4398,46 @@ impl EnableLDAPSError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ClientException" => {
-                    return RusotoError::Service(EnableLDAPSError::Client(err.msg))
+                    return RusotoError::service(EnableLDAPSError::Client(err.msg), &res)
                 }
                 "DirectoryDoesNotExistException" => {
-                    return RusotoError::Service(EnableLDAPSError::DirectoryDoesNotExist(err.msg))
+                    return RusotoError::service(
+                        EnableLDAPSError::DirectoryDoesNotExist(err.msg),
+                        &res,
+                    )
                 }
                 "DirectoryUnavailableException" => {
-                    return RusotoError::Service(EnableLDAPSError::DirectoryUnavailable(err.msg))
+                    return RusotoError::service(
+                        EnableLDAPSError::DirectoryUnavailable(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidLDAPSStatusException" => {
-                    return RusotoError::Service(EnableLDAPSError::InvalidLDAPSStatus(err.msg))
+                    return RusotoError::service(
+                        EnableLDAPSError::InvalidLDAPSStatus(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(EnableLDAPSError::InvalidParameter(err.msg))
+                    return RusotoError::service(EnableLDAPSError::InvalidParameter(err.msg), &res)
                 }
                 "NoAvailableCertificateException" => {
-                    return RusotoError::Service(EnableLDAPSError::NoAvailableCertificate(err.msg))
+                    return RusotoError::service(
+                        EnableLDAPSError::NoAvailableCertificate(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(EnableLDAPSError::Service(err.msg))
+                    return RusotoError::service(EnableLDAPSError::Service(err.msg), &res)
                 }
                 "UnsupportedOperationException" => {
-                    return RusotoError::Service(EnableLDAPSError::UnsupportedOperation(err.msg))
+                    return RusotoError::service(
+                        EnableLDAPSError::UnsupportedOperation(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(EnableLDAPSError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -4087,12 +4455,14 @@ impl fmt::Display for EnableLDAPSError {
             EnableLDAPSError::NoAvailableCertificate(ref cause) => write!(f, "{}", cause),
             EnableLDAPSError::Service(ref cause) => write!(f, "{}", cause),
             EnableLDAPSError::UnsupportedOperation(ref cause) => write!(f, "{}", cause),
+            EnableLDAPSError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for EnableLDAPSError {}
 /// Errors returned by EnableRadius
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum EnableRadiusError {
     /// <p>A client exception has occurred.</p>
     Client(String),
@@ -4104,6 +4474,8 @@ pub enum EnableRadiusError {
     InvalidParameter(String),
     /// <p>An exception has occurred in AWS Directory Service.</p>
     Service(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl EnableRadiusError {
@@ -4111,22 +4483,28 @@ impl EnableRadiusError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ClientException" => {
-                    return RusotoError::Service(EnableRadiusError::Client(err.msg))
+                    return RusotoError::service(EnableRadiusError::Client(err.msg), &res)
                 }
                 "EntityAlreadyExistsException" => {
-                    return RusotoError::Service(EnableRadiusError::EntityAlreadyExists(err.msg))
+                    return RusotoError::service(
+                        EnableRadiusError::EntityAlreadyExists(err.msg),
+                        &res,
+                    )
                 }
                 "EntityDoesNotExistException" => {
-                    return RusotoError::Service(EnableRadiusError::EntityDoesNotExist(err.msg))
+                    return RusotoError::service(
+                        EnableRadiusError::EntityDoesNotExist(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(EnableRadiusError::InvalidParameter(err.msg))
+                    return RusotoError::service(EnableRadiusError::InvalidParameter(err.msg), &res)
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(EnableRadiusError::Service(err.msg))
+                    return RusotoError::service(EnableRadiusError::Service(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(EnableRadiusError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -4141,12 +4519,14 @@ impl fmt::Display for EnableRadiusError {
             EnableRadiusError::EntityDoesNotExist(ref cause) => write!(f, "{}", cause),
             EnableRadiusError::InvalidParameter(ref cause) => write!(f, "{}", cause),
             EnableRadiusError::Service(ref cause) => write!(f, "{}", cause),
+            EnableRadiusError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for EnableRadiusError {}
 /// Errors returned by EnableSso
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum EnableSsoError {
     /// <p>An authentication error occurred.</p>
     AuthenticationFailed(String),
@@ -4158,6 +4538,8 @@ pub enum EnableSsoError {
     InsufficientPermissions(String),
     /// <p>An exception has occurred in AWS Directory Service.</p>
     Service(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl EnableSsoError {
@@ -4165,20 +4547,28 @@ impl EnableSsoError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AuthenticationFailedException" => {
-                    return RusotoError::Service(EnableSsoError::AuthenticationFailed(err.msg))
+                    return RusotoError::service(
+                        EnableSsoError::AuthenticationFailed(err.msg),
+                        &res,
+                    )
+                }
+                "ClientException" => {
+                    return RusotoError::service(EnableSsoError::Client(err.msg), &res)
                 }
-                "ClientException" => return RusotoError::Service(EnableSsoError::Client(err.msg)),
                 "EntityDoesNotExistException" => {
-                    return RusotoError::Service(EnableSsoError::EntityDoesNotExist(err.msg))
+                    return RusotoError::service(EnableSsoError::EntityDoesNotExist(err.msg), &res)
                 }
                 "InsufficientPermissionsException" => {
-                    return RusotoError::Service(EnableSsoError::InsufficientPermissions(err.msg))
+                    return RusotoError::service(
+                        EnableSsoError::InsufficientPermissions(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(EnableSsoError::Service(err.msg))
+                    return RusotoError::service(EnableSsoError::Service(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(EnableSsoError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -4193,12 +4583,14 @@ impl fmt::Display for EnableSsoError {
             EnableSsoError::EntityDoesNotExist(ref cause) => write!(f, "{}", cause),
             EnableSsoError::InsufficientPermissions(ref cause) => write!(f, "{}", cause),
             EnableSsoError::Service(ref cause) => write!(f, "{}", cause),
+            EnableSsoError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for EnableSsoError {}
 /// Errors returned by GetDirectoryLimits
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetDirectoryLimitsError {
     /// <p>A client exception has occurred.</p>
     Client(String),
@@ -4206,6 +4598,8 @@ pub enum GetDirectoryLimitsError {
     EntityDoesNotExist(String),
     /// <p>An exception has occurred in AWS Directory Service.</p>
     Service(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetDirectoryLimitsError {
@@ -4213,18 +4607,19 @@ impl GetDirectoryLimitsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ClientException" => {
-                    return RusotoError::Service(GetDirectoryLimitsError::Client(err.msg))
+                    return RusotoError::service(GetDirectoryLimitsError::Client(err.msg), &res)
                 }
                 "EntityDoesNotExistException" => {
-                    return RusotoError::Service(GetDirectoryLimitsError::EntityDoesNotExist(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetDirectoryLimitsError::EntityDoesNotExist(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(GetDirectoryLimitsError::Service(err.msg))
+                    return RusotoError::service(GetDirectoryLimitsError::Service(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetDirectoryLimitsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -4237,12 +4632,14 @@ impl fmt::Display for GetDirectoryLimitsError {
             GetDirectoryLimitsError::Client(ref cause) => write!(f, "{}", cause),
             GetDirectoryLimitsError::EntityDoesNotExist(ref cause) => write!(f, "{}", cause),
             GetDirectoryLimitsError::Service(ref cause) => write!(f, "{}", cause),
+            GetDirectoryLimitsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetDirectoryLimitsError {}
 /// Errors returned by GetSnapshotLimits
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetSnapshotLimitsError {
     /// <p>A client exception has occurred.</p>
     Client(String),
@@ -4250,6 +4647,8 @@ pub enum GetSnapshotLimitsError {
     EntityDoesNotExist(String),
     /// <p>An exception has occurred in AWS Directory Service.</p>
     Service(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetSnapshotLimitsError {
@@ -4257,18 +4656,19 @@ impl GetSnapshotLimitsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ClientException" => {
-                    return RusotoError::Service(GetSnapshotLimitsError::Client(err.msg))
+                    return RusotoError::service(GetSnapshotLimitsError::Client(err.msg), &res)
                 }
                 "EntityDoesNotExistException" => {
-                    return RusotoError::Service(GetSnapshotLimitsError::EntityDoesNotExist(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetSnapshotLimitsError::EntityDoesNotExist(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(GetSnapshotLimitsError::Service(err.msg))
+                    return RusotoError::service(GetSnapshotLimitsError::Service(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetSnapshotLimitsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -4281,12 +4681,14 @@ impl fmt::Display for GetSnapshotLimitsError {
             GetSnapshotLimitsError::Client(ref cause) => write!(f, "{}", cause),
             GetSnapshotLimitsError::EntityDoesNotExist(ref cause) => write!(f, "{}", cause),
             GetSnapshotLimitsError::Service(ref cause) => write!(f, "{}", cause),
+            GetSnapshotLimitsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetSnapshotLimitsError {}
 /// Errors returned by ListCertificates
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListCertificatesError {
     /// <p>A client exception has occurred.</p>
     Client(String),
@@ -4300,6 +4702,8 @@ pub enum ListCertificatesError {
     Service(String),
     /// <p>The operation is not supported.</p>
     UnsupportedOperation(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListCertificatesError {
@@ -4307,29 +4711,37 @@ impl ListCertificatesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ClientException" => {
-                    return RusotoError::Service(ListCertificatesError::Client(err.msg))
+                    return RusotoError::service(ListCertificatesError::Client(err.msg), &res)
                 }
                 "DirectoryDoesNotExistException" => {
-                    return RusotoError::Service(ListCertificatesError::DirectoryDoesNotExist(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListCertificatesError::DirectoryDoesNotExist(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(ListCertificatesError::InvalidNextToken(err.msg))
+                    return RusotoError::service(
+                        ListCertificatesError::InvalidNextToken(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(ListCertificatesError::InvalidParameter(err.msg))
+                    return RusotoError::service(
+                        ListCertificatesError::InvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(ListCertificatesError::Service(err.msg))
+                    return RusotoError::service(ListCertificatesError::Service(err.msg), &res)
                 }
                 "UnsupportedOperationException" => {
-                    return RusotoError::Service(ListCertificatesError::UnsupportedOperation(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListCertificatesError::UnsupportedOperation(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListCertificatesError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -4345,12 +4757,14 @@ impl fmt::Display for ListCertificatesError {
             ListCertificatesError::InvalidParameter(ref cause) => write!(f, "{}", cause),
             ListCertificatesError::Service(ref cause) => write!(f, "{}", cause),
             ListCertificatesError::UnsupportedOperation(ref cause) => write!(f, "{}", cause),
+            ListCertificatesError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListCertificatesError {}
 /// Errors returned by ListIpRoutes
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListIpRoutesError {
     /// <p>A client exception has occurred.</p>
     Client(String),
@@ -4362,6 +4776,8 @@ pub enum ListIpRoutesError {
     InvalidParameter(String),
     /// <p>An exception has occurred in AWS Directory Service.</p>
     Service(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListIpRoutesError {
@@ -4369,22 +4785,25 @@ impl ListIpRoutesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ClientException" => {
-                    return RusotoError::Service(ListIpRoutesError::Client(err.msg))
+                    return RusotoError::service(ListIpRoutesError::Client(err.msg), &res)
                 }
                 "EntityDoesNotExistException" => {
-                    return RusotoError::Service(ListIpRoutesError::EntityDoesNotExist(err.msg))
+                    return RusotoError::service(
+                        ListIpRoutesError::EntityDoesNotExist(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(ListIpRoutesError::InvalidNextToken(err.msg))
+                    return RusotoError::service(ListIpRoutesError::InvalidNextToken(err.msg), &res)
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(ListIpRoutesError::InvalidParameter(err.msg))
+                    return RusotoError::service(ListIpRoutesError::InvalidParameter(err.msg), &res)
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(ListIpRoutesError::Service(err.msg))
+                    return RusotoError::service(ListIpRoutesError::Service(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListIpRoutesError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -4399,12 +4818,14 @@ impl fmt::Display for ListIpRoutesError {
             ListIpRoutesError::InvalidNextToken(ref cause) => write!(f, "{}", cause),
             ListIpRoutesError::InvalidParameter(ref cause) => write!(f, "{}", cause),
             ListIpRoutesError::Service(ref cause) => write!(f, "{}", cause),
+            ListIpRoutesError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListIpRoutesError {}
 /// Errors returned by ListLogSubscriptions
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListLogSubscriptionsError {
     /// <p>A client exception has occurred.</p>
     Client(String),
@@ -4414,6 +4835,8 @@ pub enum ListLogSubscriptionsError {
     InvalidNextToken(String),
     /// <p>An exception has occurred in AWS Directory Service.</p>
     Service(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListLogSubscriptionsError {
@@ -4421,23 +4844,25 @@ impl ListLogSubscriptionsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ClientException" => {
-                    return RusotoError::Service(ListLogSubscriptionsError::Client(err.msg))
+                    return RusotoError::service(ListLogSubscriptionsError::Client(err.msg), &res)
                 }
                 "EntityDoesNotExistException" => {
-                    return RusotoError::Service(ListLogSubscriptionsError::EntityDoesNotExist(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListLogSubscriptionsError::EntityDoesNotExist(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(ListLogSubscriptionsError::InvalidNextToken(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListLogSubscriptionsError::InvalidNextToken(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(ListLogSubscriptionsError::Service(err.msg))
+                    return RusotoError::service(ListLogSubscriptionsError::Service(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListLogSubscriptionsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -4451,12 +4876,14 @@ impl fmt::Display for ListLogSubscriptionsError {
             ListLogSubscriptionsError::EntityDoesNotExist(ref cause) => write!(f, "{}", cause),
             ListLogSubscriptionsError::InvalidNextToken(ref cause) => write!(f, "{}", cause),
             ListLogSubscriptionsError::Service(ref cause) => write!(f, "{}", cause),
+            ListLogSubscriptionsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListLogSubscriptionsError {}
 /// Errors returned by ListSchemaExtensions
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListSchemaExtensionsError {
     /// <p>A client exception has occurred.</p>
     Client(String),
@@ -4466,6 +4893,8 @@ pub enum ListSchemaExtensionsError {
     InvalidNextToken(String),
     /// <p>An exception has occurred in AWS Directory Service.</p>
     Service(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListSchemaExtensionsError {
@@ -4473,23 +4902,25 @@ impl ListSchemaExtensionsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ClientException" => {
-                    return RusotoError::Service(ListSchemaExtensionsError::Client(err.msg))
+                    return RusotoError::service(ListSchemaExtensionsError::Client(err.msg), &res)
                 }
                 "EntityDoesNotExistException" => {
-                    return RusotoError::Service(ListSchemaExtensionsError::EntityDoesNotExist(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListSchemaExtensionsError::EntityDoesNotExist(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(ListSchemaExtensionsError::InvalidNextToken(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListSchemaExtensionsError::InvalidNextToken(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(ListSchemaExtensionsError::Service(err.msg))
+                    return RusotoError::service(ListSchemaExtensionsError::Service(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListSchemaExtensionsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -4503,12 +4934,14 @@ impl fmt::Display for ListSchemaExtensionsError {
             ListSchemaExtensionsError::EntityDoesNotExist(ref cause) => write!(f, "{}", cause),
             ListSchemaExtensionsError::InvalidNextToken(ref cause) => write!(f, "{}", cause),
             ListSchemaExtensionsError::Service(ref cause) => write!(f, "{}", cause),
+            ListSchemaExtensionsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListSchemaExtensionsError {}
 /// Errors returned by ListTagsForResource
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListTagsForResourceError {
     /// <p>A client exception has occurred.</p>
     Client(String),
@@ -4520,6 +4953,8 @@ pub enum ListTagsForResourceError {
     InvalidParameter(String),
     /// <p>An exception has occurred in AWS Directory Service.</p>
     Service(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListTagsForResourceError {
@@ -4527,28 +4962,31 @@ impl ListTagsForResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ClientException" => {
-                    return RusotoError::Service(ListTagsForResourceError::Client(err.msg))
+                    return RusotoError::service(ListTagsForResourceError::Client(err.msg), &res)
                 }
                 "EntityDoesNotExistException" => {
-                    return RusotoError::Service(ListTagsForResourceError::EntityDoesNotExist(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListTagsForResourceError::EntityDoesNotExist(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(ListTagsForResourceError::InvalidNextToken(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListTagsForResourceError::InvalidNextToken(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(ListTagsForResourceError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListTagsForResourceError::InvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(ListTagsForResourceError::Service(err.msg))
+                    return RusotoError::service(ListTagsForResourceError::Service(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListTagsForResourceError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -4563,12 +5001,14 @@ impl fmt::Display for ListTagsForResourceError {
             ListTagsForResourceError::InvalidNextToken(ref cause) => write!(f, "{}", cause),
             ListTagsForResourceError::InvalidParameter(ref cause) => write!(f, "{}", cause),
             ListTagsForResourceError::Service(ref cause) => write!(f, "{}", cause),
+            ListTagsForResourceError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListTagsForResourceError {}
 /// Errors returned by RegisterCertificate
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum RegisterCertificateError {
     /// <p>The certificate has already been registered into the system.</p>
     CertificateAlreadyExists(String),
@@ -4588,6 +5028,8 @@ pub enum RegisterCertificateError {
     Service(String),
     /// <p>The operation is not supported.</p>
     UnsupportedOperation(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl RegisterCertificateError {
@@ -4595,48 +5037,55 @@ impl RegisterCertificateError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "CertificateAlreadyExistsException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         RegisterCertificateError::CertificateAlreadyExists(err.msg),
+                        &res,
                     )
                 }
                 "CertificateLimitExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         RegisterCertificateError::CertificateLimitExceeded(err.msg),
+                        &res,
                     )
                 }
                 "ClientException" => {
-                    return RusotoError::Service(RegisterCertificateError::Client(err.msg))
+                    return RusotoError::service(RegisterCertificateError::Client(err.msg), &res)
                 }
                 "DirectoryDoesNotExistException" => {
-                    return RusotoError::Service(RegisterCertificateError::DirectoryDoesNotExist(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        RegisterCertificateError::DirectoryDoesNotExist(err.msg),
+                        &res,
+                    )
                 }
                 "DirectoryUnavailableException" => {
-                    return RusotoError::Service(RegisterCertificateError::DirectoryUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        RegisterCertificateError::DirectoryUnavailable(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidCertificateException" => {
-                    return RusotoError::Service(RegisterCertificateError::InvalidCertificate(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        RegisterCertificateError::InvalidCertificate(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(RegisterCertificateError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        RegisterCertificateError::InvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(RegisterCertificateError::Service(err.msg))
+                    return RusotoError::service(RegisterCertificateError::Service(err.msg), &res)
                 }
                 "UnsupportedOperationException" => {
-                    return RusotoError::Service(RegisterCertificateError::UnsupportedOperation(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        RegisterCertificateError::UnsupportedOperation(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(RegisterCertificateError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -4655,12 +5104,14 @@ impl fmt::Display for RegisterCertificateError {
             RegisterCertificateError::InvalidParameter(ref cause) => write!(f, "{}", cause),
             RegisterCertificateError::Service(ref cause) => write!(f, "{}", cause),
             RegisterCertificateError::UnsupportedOperation(ref cause) => write!(f, "{}", cause),
+            RegisterCertificateError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for RegisterCertificateError {}
 /// Errors returned by RegisterEventTopic
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum RegisterEventTopicError {
     /// <p>A client exception has occurred.</p>
     Client(String),
@@ -4670,6 +5121,8 @@ pub enum RegisterEventTopicError {
     InvalidParameter(String),
     /// <p>An exception has occurred in AWS Directory Service.</p>
     Service(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl RegisterEventTopicError {
@@ -4677,21 +5130,25 @@ impl RegisterEventTopicError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ClientException" => {
-                    return RusotoError::Service(RegisterEventTopicError::Client(err.msg))
+                    return RusotoError::service(RegisterEventTopicError::Client(err.msg), &res)
                 }
                 "EntityDoesNotExistException" => {
-                    return RusotoError::Service(RegisterEventTopicError::EntityDoesNotExist(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        RegisterEventTopicError::EntityDoesNotExist(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(RegisterEventTopicError::InvalidParameter(err.msg))
+                    return RusotoError::service(
+                        RegisterEventTopicError::InvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(RegisterEventTopicError::Service(err.msg))
+                    return RusotoError::service(RegisterEventTopicError::Service(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(RegisterEventTopicError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -4705,12 +5162,14 @@ impl fmt::Display for RegisterEventTopicError {
             RegisterEventTopicError::EntityDoesNotExist(ref cause) => write!(f, "{}", cause),
             RegisterEventTopicError::InvalidParameter(ref cause) => write!(f, "{}", cause),
             RegisterEventTopicError::Service(ref cause) => write!(f, "{}", cause),
+            RegisterEventTopicError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for RegisterEventTopicError {}
 /// Errors returned by RejectSharedDirectory
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum RejectSharedDirectoryError {
     /// <p>A client exception has occurred.</p>
     Client(String),
@@ -4722,6 +5181,8 @@ pub enum RejectSharedDirectoryError {
     InvalidParameter(String),
     /// <p>An exception has occurred in AWS Directory Service.</p>
     Service(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl RejectSharedDirectoryError {
@@ -4729,28 +5190,31 @@ impl RejectSharedDirectoryError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ClientException" => {
-                    return RusotoError::Service(RejectSharedDirectoryError::Client(err.msg))
+                    return RusotoError::service(RejectSharedDirectoryError::Client(err.msg), &res)
                 }
                 "DirectoryAlreadySharedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         RejectSharedDirectoryError::DirectoryAlreadyShared(err.msg),
+                        &res,
                     )
                 }
                 "EntityDoesNotExistException" => {
-                    return RusotoError::Service(RejectSharedDirectoryError::EntityDoesNotExist(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        RejectSharedDirectoryError::EntityDoesNotExist(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(RejectSharedDirectoryError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        RejectSharedDirectoryError::InvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(RejectSharedDirectoryError::Service(err.msg))
+                    return RusotoError::service(RejectSharedDirectoryError::Service(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(RejectSharedDirectoryError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -4765,12 +5229,14 @@ impl fmt::Display for RejectSharedDirectoryError {
             RejectSharedDirectoryError::EntityDoesNotExist(ref cause) => write!(f, "{}", cause),
             RejectSharedDirectoryError::InvalidParameter(ref cause) => write!(f, "{}", cause),
             RejectSharedDirectoryError::Service(ref cause) => write!(f, "{}", cause),
+            RejectSharedDirectoryError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for RejectSharedDirectoryError {}
 /// Errors returned by RemoveIpRoutes
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum RemoveIpRoutesError {
     /// <p>A client exception has occurred.</p>
     Client(String),
@@ -4782,6 +5248,8 @@ pub enum RemoveIpRoutesError {
     InvalidParameter(String),
     /// <p>An exception has occurred in AWS Directory Service.</p>
     Service(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl RemoveIpRoutesError {
@@ -4789,22 +5257,31 @@ impl RemoveIpRoutesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ClientException" => {
-                    return RusotoError::Service(RemoveIpRoutesError::Client(err.msg))
+                    return RusotoError::service(RemoveIpRoutesError::Client(err.msg), &res)
                 }
                 "DirectoryUnavailableException" => {
-                    return RusotoError::Service(RemoveIpRoutesError::DirectoryUnavailable(err.msg))
+                    return RusotoError::service(
+                        RemoveIpRoutesError::DirectoryUnavailable(err.msg),
+                        &res,
+                    )
                 }
                 "EntityDoesNotExistException" => {
-                    return RusotoError::Service(RemoveIpRoutesError::EntityDoesNotExist(err.msg))
+                    return RusotoError::service(
+                        RemoveIpRoutesError::EntityDoesNotExist(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(RemoveIpRoutesError::InvalidParameter(err.msg))
+                    return RusotoError::service(
+                        RemoveIpRoutesError::InvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(RemoveIpRoutesError::Service(err.msg))
+                    return RusotoError::service(RemoveIpRoutesError::Service(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(RemoveIpRoutesError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -4819,12 +5296,14 @@ impl fmt::Display for RemoveIpRoutesError {
             RemoveIpRoutesError::EntityDoesNotExist(ref cause) => write!(f, "{}", cause),
             RemoveIpRoutesError::InvalidParameter(ref cause) => write!(f, "{}", cause),
             RemoveIpRoutesError::Service(ref cause) => write!(f, "{}", cause),
+            RemoveIpRoutesError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for RemoveIpRoutesError {}
 /// Errors returned by RemoveTagsFromResource
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum RemoveTagsFromResourceError {
     /// <p>A client exception has occurred.</p>
     Client(String),
@@ -4834,6 +5313,8 @@ pub enum RemoveTagsFromResourceError {
     InvalidParameter(String),
     /// <p>An exception has occurred in AWS Directory Service.</p>
     Service(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl RemoveTagsFromResourceError {
@@ -4841,23 +5322,28 @@ impl RemoveTagsFromResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ClientException" => {
-                    return RusotoError::Service(RemoveTagsFromResourceError::Client(err.msg))
+                    return RusotoError::service(RemoveTagsFromResourceError::Client(err.msg), &res)
                 }
                 "EntityDoesNotExistException" => {
-                    return RusotoError::Service(RemoveTagsFromResourceError::EntityDoesNotExist(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        RemoveTagsFromResourceError::EntityDoesNotExist(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(RemoveTagsFromResourceError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        RemoveTagsFromResourceError::InvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(RemoveTagsFromResourceError::Service(err.msg))
+                    return RusotoError::service(
+                        RemoveTagsFromResourceError::Service(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(RemoveTagsFromResourceError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -4871,12 +5357,14 @@ impl fmt::Display for RemoveTagsFromResourceError {
             RemoveTagsFromResourceError::EntityDoesNotExist(ref cause) => write!(f, "{}", cause),
             RemoveTagsFromResourceError::InvalidParameter(ref cause) => write!(f, "{}", cause),
             RemoveTagsFromResourceError::Service(ref cause) => write!(f, "{}", cause),
+            RemoveTagsFromResourceError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for RemoveTagsFromResourceError {}
 /// Errors returned by ResetUserPassword
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ResetUserPasswordError {
     /// <p>A client exception has occurred.</p>
     Client(String),
@@ -4892,6 +5380,8 @@ pub enum ResetUserPasswordError {
     UnsupportedOperation(String),
     /// <p>The user provided a username that does not exist in your directory.</p>
     UserDoesNotExist(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ResetUserPasswordError {
@@ -4899,34 +5389,43 @@ impl ResetUserPasswordError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ClientException" => {
-                    return RusotoError::Service(ResetUserPasswordError::Client(err.msg))
+                    return RusotoError::service(ResetUserPasswordError::Client(err.msg), &res)
                 }
                 "DirectoryUnavailableException" => {
-                    return RusotoError::Service(ResetUserPasswordError::DirectoryUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ResetUserPasswordError::DirectoryUnavailable(err.msg),
+                        &res,
+                    )
                 }
                 "EntityDoesNotExistException" => {
-                    return RusotoError::Service(ResetUserPasswordError::EntityDoesNotExist(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ResetUserPasswordError::EntityDoesNotExist(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidPasswordException" => {
-                    return RusotoError::Service(ResetUserPasswordError::InvalidPassword(err.msg))
+                    return RusotoError::service(
+                        ResetUserPasswordError::InvalidPassword(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(ResetUserPasswordError::Service(err.msg))
+                    return RusotoError::service(ResetUserPasswordError::Service(err.msg), &res)
                 }
                 "UnsupportedOperationException" => {
-                    return RusotoError::Service(ResetUserPasswordError::UnsupportedOperation(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ResetUserPasswordError::UnsupportedOperation(err.msg),
+                        &res,
+                    )
                 }
                 "UserDoesNotExistException" => {
-                    return RusotoError::Service(ResetUserPasswordError::UserDoesNotExist(err.msg))
+                    return RusotoError::service(
+                        ResetUserPasswordError::UserDoesNotExist(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ResetUserPasswordError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -4943,12 +5442,14 @@ impl fmt::Display for ResetUserPasswordError {
             ResetUserPasswordError::Service(ref cause) => write!(f, "{}", cause),
             ResetUserPasswordError::UnsupportedOperation(ref cause) => write!(f, "{}", cause),
             ResetUserPasswordError::UserDoesNotExist(ref cause) => write!(f, "{}", cause),
+            ResetUserPasswordError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ResetUserPasswordError {}
 /// Errors returned by RestoreFromSnapshot
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum RestoreFromSnapshotError {
     /// <p>A client exception has occurred.</p>
     Client(String),
@@ -4958,6 +5459,8 @@ pub enum RestoreFromSnapshotError {
     InvalidParameter(String),
     /// <p>An exception has occurred in AWS Directory Service.</p>
     Service(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl RestoreFromSnapshotError {
@@ -4965,23 +5468,25 @@ impl RestoreFromSnapshotError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ClientException" => {
-                    return RusotoError::Service(RestoreFromSnapshotError::Client(err.msg))
+                    return RusotoError::service(RestoreFromSnapshotError::Client(err.msg), &res)
                 }
                 "EntityDoesNotExistException" => {
-                    return RusotoError::Service(RestoreFromSnapshotError::EntityDoesNotExist(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        RestoreFromSnapshotError::EntityDoesNotExist(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(RestoreFromSnapshotError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        RestoreFromSnapshotError::InvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(RestoreFromSnapshotError::Service(err.msg))
+                    return RusotoError::service(RestoreFromSnapshotError::Service(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(RestoreFromSnapshotError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -4995,12 +5500,14 @@ impl fmt::Display for RestoreFromSnapshotError {
             RestoreFromSnapshotError::EntityDoesNotExist(ref cause) => write!(f, "{}", cause),
             RestoreFromSnapshotError::InvalidParameter(ref cause) => write!(f, "{}", cause),
             RestoreFromSnapshotError::Service(ref cause) => write!(f, "{}", cause),
+            RestoreFromSnapshotError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for RestoreFromSnapshotError {}
 /// Errors returned by ShareDirectory
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ShareDirectoryError {
     /// <p>You do not have sufficient access to perform this action.</p>
     AccessDenied(String),
@@ -5022,6 +5529,8 @@ pub enum ShareDirectoryError {
     ShareLimitExceeded(String),
     /// <p>The operation is not supported.</p>
     UnsupportedOperation(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ShareDirectoryError {
@@ -5029,39 +5538,52 @@ impl ShareDirectoryError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(ShareDirectoryError::AccessDenied(err.msg))
+                    return RusotoError::service(ShareDirectoryError::AccessDenied(err.msg), &res)
                 }
                 "ClientException" => {
-                    return RusotoError::Service(ShareDirectoryError::Client(err.msg))
+                    return RusotoError::service(ShareDirectoryError::Client(err.msg), &res)
                 }
                 "DirectoryAlreadySharedException" => {
-                    return RusotoError::Service(ShareDirectoryError::DirectoryAlreadyShared(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ShareDirectoryError::DirectoryAlreadyShared(err.msg),
+                        &res,
+                    )
                 }
                 "EntityDoesNotExistException" => {
-                    return RusotoError::Service(ShareDirectoryError::EntityDoesNotExist(err.msg))
+                    return RusotoError::service(
+                        ShareDirectoryError::EntityDoesNotExist(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(ShareDirectoryError::InvalidParameter(err.msg))
+                    return RusotoError::service(
+                        ShareDirectoryError::InvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidTargetException" => {
-                    return RusotoError::Service(ShareDirectoryError::InvalidTarget(err.msg))
+                    return RusotoError::service(ShareDirectoryError::InvalidTarget(err.msg), &res)
                 }
                 "OrganizationsException" => {
-                    return RusotoError::Service(ShareDirectoryError::Organizations(err.msg))
+                    return RusotoError::service(ShareDirectoryError::Organizations(err.msg), &res)
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(ShareDirectoryError::Service(err.msg))
+                    return RusotoError::service(ShareDirectoryError::Service(err.msg), &res)
                 }
                 "ShareLimitExceededException" => {
-                    return RusotoError::Service(ShareDirectoryError::ShareLimitExceeded(err.msg))
+                    return RusotoError::service(
+                        ShareDirectoryError::ShareLimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "UnsupportedOperationException" => {
-                    return RusotoError::Service(ShareDirectoryError::UnsupportedOperation(err.msg))
+                    return RusotoError::service(
+                        ShareDirectoryError::UnsupportedOperation(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ShareDirectoryError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -5081,12 +5603,14 @@ impl fmt::Display for ShareDirectoryError {
             ShareDirectoryError::Service(ref cause) => write!(f, "{}", cause),
             ShareDirectoryError::ShareLimitExceeded(ref cause) => write!(f, "{}", cause),
             ShareDirectoryError::UnsupportedOperation(ref cause) => write!(f, "{}", cause),
+            ShareDirectoryError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ShareDirectoryError {}
 /// Errors returned by StartSchemaExtension
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum StartSchemaExtensionError {
     /// <p>A client exception has occurred.</p>
     Client(String),
@@ -5100,6 +5624,8 @@ pub enum StartSchemaExtensionError {
     Service(String),
     /// <p>The maximum number of manual snapshots for the directory has been reached. You can use the <a>GetSnapshotLimits</a> operation to determine the snapshot limits for a directory.</p>
     SnapshotLimitExceeded(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl StartSchemaExtensionError {
@@ -5107,33 +5633,37 @@ impl StartSchemaExtensionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ClientException" => {
-                    return RusotoError::Service(StartSchemaExtensionError::Client(err.msg))
+                    return RusotoError::service(StartSchemaExtensionError::Client(err.msg), &res)
                 }
                 "DirectoryUnavailableException" => {
-                    return RusotoError::Service(StartSchemaExtensionError::DirectoryUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        StartSchemaExtensionError::DirectoryUnavailable(err.msg),
+                        &res,
+                    )
                 }
                 "EntityDoesNotExistException" => {
-                    return RusotoError::Service(StartSchemaExtensionError::EntityDoesNotExist(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        StartSchemaExtensionError::EntityDoesNotExist(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(StartSchemaExtensionError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        StartSchemaExtensionError::InvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(StartSchemaExtensionError::Service(err.msg))
+                    return RusotoError::service(StartSchemaExtensionError::Service(err.msg), &res)
                 }
                 "SnapshotLimitExceededException" => {
-                    return RusotoError::Service(StartSchemaExtensionError::SnapshotLimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        StartSchemaExtensionError::SnapshotLimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(StartSchemaExtensionError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -5149,12 +5679,14 @@ impl fmt::Display for StartSchemaExtensionError {
             StartSchemaExtensionError::InvalidParameter(ref cause) => write!(f, "{}", cause),
             StartSchemaExtensionError::Service(ref cause) => write!(f, "{}", cause),
             StartSchemaExtensionError::SnapshotLimitExceeded(ref cause) => write!(f, "{}", cause),
+            StartSchemaExtensionError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for StartSchemaExtensionError {}
 /// Errors returned by UnshareDirectory
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UnshareDirectoryError {
     /// <p>A client exception has occurred.</p>
     Client(String),
@@ -5166,6 +5698,8 @@ pub enum UnshareDirectoryError {
     InvalidTarget(String),
     /// <p>An exception has occurred in AWS Directory Service.</p>
     Service(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UnshareDirectoryError {
@@ -5173,22 +5707,31 @@ impl UnshareDirectoryError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ClientException" => {
-                    return RusotoError::Service(UnshareDirectoryError::Client(err.msg))
+                    return RusotoError::service(UnshareDirectoryError::Client(err.msg), &res)
                 }
                 "DirectoryNotSharedException" => {
-                    return RusotoError::Service(UnshareDirectoryError::DirectoryNotShared(err.msg))
+                    return RusotoError::service(
+                        UnshareDirectoryError::DirectoryNotShared(err.msg),
+                        &res,
+                    )
                 }
                 "EntityDoesNotExistException" => {
-                    return RusotoError::Service(UnshareDirectoryError::EntityDoesNotExist(err.msg))
+                    return RusotoError::service(
+                        UnshareDirectoryError::EntityDoesNotExist(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidTargetException" => {
-                    return RusotoError::Service(UnshareDirectoryError::InvalidTarget(err.msg))
+                    return RusotoError::service(
+                        UnshareDirectoryError::InvalidTarget(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(UnshareDirectoryError::Service(err.msg))
+                    return RusotoError::service(UnshareDirectoryError::Service(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UnshareDirectoryError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -5203,12 +5746,14 @@ impl fmt::Display for UnshareDirectoryError {
             UnshareDirectoryError::EntityDoesNotExist(ref cause) => write!(f, "{}", cause),
             UnshareDirectoryError::InvalidTarget(ref cause) => write!(f, "{}", cause),
             UnshareDirectoryError::Service(ref cause) => write!(f, "{}", cause),
+            UnshareDirectoryError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for UnshareDirectoryError {}
 /// Errors returned by UpdateConditionalForwarder
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdateConditionalForwarderError {
     /// <p>A client exception has occurred.</p>
     Client(String),
@@ -5222,6 +5767,8 @@ pub enum UpdateConditionalForwarderError {
     Service(String),
     /// <p>The operation is not supported.</p>
     UnsupportedOperation(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdateConditionalForwarderError {
@@ -5231,33 +5778,45 @@ impl UpdateConditionalForwarderError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ClientException" => {
-                    return RusotoError::Service(UpdateConditionalForwarderError::Client(err.msg))
+                    return RusotoError::service(
+                        UpdateConditionalForwarderError::Client(err.msg),
+                        &res,
+                    )
                 }
                 "DirectoryUnavailableException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         UpdateConditionalForwarderError::DirectoryUnavailable(err.msg),
+                        &res,
                     )
                 }
                 "EntityDoesNotExistException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         UpdateConditionalForwarderError::EntityDoesNotExist(err.msg),
+                        &res,
                     )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(UpdateConditionalForwarderError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateConditionalForwarderError::InvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(UpdateConditionalForwarderError::Service(err.msg))
+                    return RusotoError::service(
+                        UpdateConditionalForwarderError::Service(err.msg),
+                        &res,
+                    )
                 }
                 "UnsupportedOperationException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         UpdateConditionalForwarderError::UnsupportedOperation(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(UpdateConditionalForwarderError::Unhandled, &res)
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -5279,12 +5838,14 @@ impl fmt::Display for UpdateConditionalForwarderError {
             UpdateConditionalForwarderError::UnsupportedOperation(ref cause) => {
                 write!(f, "{}", cause)
             }
+            UpdateConditionalForwarderError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for UpdateConditionalForwarderError {}
 /// Errors returned by UpdateNumberOfDomainControllers
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdateNumberOfDomainControllersError {
     /// <p>A client exception has occurred.</p>
     Client(String),
@@ -5300,6 +5861,8 @@ pub enum UpdateNumberOfDomainControllersError {
     Service(String),
     /// <p>The operation is not supported.</p>
     UnsupportedOperation(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdateNumberOfDomainControllersError {
@@ -5309,44 +5872,56 @@ impl UpdateNumberOfDomainControllersError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ClientException" => {
-                    return RusotoError::Service(UpdateNumberOfDomainControllersError::Client(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateNumberOfDomainControllersError::Client(err.msg),
+                        &res,
+                    )
                 }
                 "DirectoryUnavailableException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         UpdateNumberOfDomainControllersError::DirectoryUnavailable(err.msg),
+                        &res,
                     )
                 }
                 "DomainControllerLimitExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         UpdateNumberOfDomainControllersError::DomainControllerLimitExceeded(
                             err.msg,
                         ),
+                        &res,
                     )
                 }
                 "EntityDoesNotExistException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         UpdateNumberOfDomainControllersError::EntityDoesNotExist(err.msg),
+                        &res,
                     )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         UpdateNumberOfDomainControllersError::InvalidParameter(err.msg),
+                        &res,
                     )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(UpdateNumberOfDomainControllersError::Service(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateNumberOfDomainControllersError::Service(err.msg),
+                        &res,
+                    )
                 }
                 "UnsupportedOperationException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         UpdateNumberOfDomainControllersError::UnsupportedOperation(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        UpdateNumberOfDomainControllersError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -5373,12 +5948,16 @@ impl fmt::Display for UpdateNumberOfDomainControllersError {
             UpdateNumberOfDomainControllersError::UnsupportedOperation(ref cause) => {
                 write!(f, "{}", cause)
             }
+            UpdateNumberOfDomainControllersError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for UpdateNumberOfDomainControllersError {}
 /// Errors returned by UpdateRadius
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdateRadiusError {
     /// <p>A client exception has occurred.</p>
     Client(String),
@@ -5388,6 +5967,8 @@ pub enum UpdateRadiusError {
     InvalidParameter(String),
     /// <p>An exception has occurred in AWS Directory Service.</p>
     Service(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdateRadiusError {
@@ -5395,19 +5976,22 @@ impl UpdateRadiusError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ClientException" => {
-                    return RusotoError::Service(UpdateRadiusError::Client(err.msg))
+                    return RusotoError::service(UpdateRadiusError::Client(err.msg), &res)
                 }
                 "EntityDoesNotExistException" => {
-                    return RusotoError::Service(UpdateRadiusError::EntityDoesNotExist(err.msg))
+                    return RusotoError::service(
+                        UpdateRadiusError::EntityDoesNotExist(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(UpdateRadiusError::InvalidParameter(err.msg))
+                    return RusotoError::service(UpdateRadiusError::InvalidParameter(err.msg), &res)
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(UpdateRadiusError::Service(err.msg))
+                    return RusotoError::service(UpdateRadiusError::Service(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UpdateRadiusError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -5421,12 +6005,14 @@ impl fmt::Display for UpdateRadiusError {
             UpdateRadiusError::EntityDoesNotExist(ref cause) => write!(f, "{}", cause),
             UpdateRadiusError::InvalidParameter(ref cause) => write!(f, "{}", cause),
             UpdateRadiusError::Service(ref cause) => write!(f, "{}", cause),
+            UpdateRadiusError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for UpdateRadiusError {}
 /// Errors returned by UpdateTrust
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdateTrustError {
     /// <p>A client exception has occurred.</p>
     Client(String),
@@ -5436,6 +6022,8 @@ pub enum UpdateTrustError {
     InvalidParameter(String),
     /// <p>An exception has occurred in AWS Directory Service.</p>
     Service(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdateTrustError {
@@ -5443,19 +6031,22 @@ impl UpdateTrustError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ClientException" => {
-                    return RusotoError::Service(UpdateTrustError::Client(err.msg))
+                    return RusotoError::service(UpdateTrustError::Client(err.msg), &res)
                 }
                 "EntityDoesNotExistException" => {
-                    return RusotoError::Service(UpdateTrustError::EntityDoesNotExist(err.msg))
+                    return RusotoError::service(
+                        UpdateTrustError::EntityDoesNotExist(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(UpdateTrustError::InvalidParameter(err.msg))
+                    return RusotoError::service(UpdateTrustError::InvalidParameter(err.msg), &res)
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(UpdateTrustError::Service(err.msg))
+                    return RusotoError::service(UpdateTrustError::Service(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UpdateTrustError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -5469,12 +6060,14 @@ impl fmt::Display for UpdateTrustError {
             UpdateTrustError::EntityDoesNotExist(ref cause) => write!(f, "{}", cause),
             UpdateTrustError::InvalidParameter(ref cause) => write!(f, "{}", cause),
             UpdateTrustError::Service(ref cause) => write!(f, "{}", cause),
+            UpdateTrustError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for UpdateTrustError {}
 /// Errors returned by VerifyTrust
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum VerifyTrustError {
     /// <p>A client exception has occurred.</p>
     Client(String),
@@ -5486,6 +6079,8 @@ pub enum VerifyTrustError {
     Service(String),
     /// <p>The operation is not supported.</p>
     UnsupportedOperation(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl VerifyTrustError {
@@ -5493,22 +6088,28 @@ impl VerifyTrustError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ClientException" => {
-                    return RusotoError::Service(VerifyTrustError::Client(err.msg))
+                    return RusotoError::service(VerifyTrustError::Client(err.msg), &res)
                 }
                 "EntityDoesNotExistException" => {
-                    return RusotoError::Service(VerifyTrustError::EntityDoesNotExist(err.msg))
+                    return RusotoError::service(
+                        VerifyTrustError::EntityDoesNotExist(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(VerifyTrustError::InvalidParameter(err.msg))
+                    return RusotoError::service(VerifyTrustError::InvalidParameter(err.msg), &res)
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(VerifyTrustError::Service(err.msg))
+                    return RusotoError::service(VerifyTrustError::Service(err.msg), &res)
                 }
                 "UnsupportedOperationException" => {
-                    return RusotoError::Service(VerifyTrustError::UnsupportedOperation(err.msg))
+                    return RusotoError::service(
+                        VerifyTrustError::UnsupportedOperation(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(VerifyTrustError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -5523,6 +6124,7 @@ impl fmt::Display for VerifyTrustError {
             VerifyTrustError::InvalidParameter(ref cause) => write!(f, "{}", cause),
             VerifyTrustError::Service(ref cause) => write!(f, "{}", cause),
             VerifyTrustError::UnsupportedOperation(ref cause) => write!(f, "{}", cause),
+            VerifyTrustError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
@@ -5885,6 +6487,7 @@ impl DirectoryServiceClient {
     /// Creates a client backed by the default tokio event loop.
     ///
     /// The client will use the default credentials provider and tls client.
+    #[cfg(not(target_arch = "wasm32"))]
     pub fn new(region: region::Region) -> DirectoryServiceClient {
         DirectoryServiceClient {
             client: Client::shared(),
@@ -5910,16 +6513,73 @@ impl DirectoryServiceClient {
     pub fn new_with_client(client: Client, region: region::Region) -> DirectoryServiceClient {
         DirectoryServiceClient { client, region }
     }
+
+    /// Returns a builder of a client, setting any of its region, credentials provider,
+    /// dispatcher, retry policy, endpoint and timeouts.
+    pub fn builder() -> rusoto_core::ClientBuilder<DirectoryServiceClient> {
+        rusoto_core::ClientBuilder::new(DirectoryServiceClient::new_with_client)
+    }
+
+    /// Returns a clone of this client with the overrides of `config` applied to its
+    /// operations, sharing its connections.
+    pub fn customize(&self, config: &rusoto_core::OperationConfig) -> DirectoryServiceClient {
+        DirectoryServiceClient {
+            client: self.client.customize(config),
+            region: config.resolve_region(&self.region),
+        }
+    }
 }
 
-#[async_trait]
-impl DirectoryService for DirectoryServiceClient {
-    /// <p>Accepts a directory sharing request that was sent from the directory owner account.</p>
-    async fn accept_shared_directory(
+impl DirectoryServiceClient {
+    /// Calls `accept_shared_directory`, also returning the metadata of the response.
+    pub async fn accept_shared_directory_with_metadata(
         &self,
         input: AcceptSharedDirectoryRequest,
-    ) -> Result<AcceptSharedDirectoryResult, RusotoError<AcceptSharedDirectoryError>> {
+    ) -> Result<
+        (AcceptSharedDirectoryResult, ResponseMetadata),
+        RusotoError<AcceptSharedDirectoryError>,
+    > {
+        let request = self.build_accept_shared_directory_request(input);
+
+        let mut response = self
+            .client
+            .sign_and_dispatch(request)
+            .await
+            .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
+        if response.status.is_success() {
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<AcceptSharedDirectoryResult, _>()?;
+            Ok((result, metadata))
+        } else {
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(AcceptSharedDirectoryError::from_response(response))
+        }
+    }
+
+    /// Presigns the request `accept_shared_directory` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_accept_shared_directory(
+        &self,
+        presigner: &rusoto_core::Presigner,
+        input: AcceptSharedDirectoryRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_accept_shared_directory_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_accept_shared_directory_request(
+        &self,
+        input: AcceptSharedDirectoryRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "ds", &self.region, "/");
+        request.set_operation("AcceptSharedDirectory");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -5929,55 +6589,105 @@ impl DirectoryService for DirectoryServiceClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `add_ip_routes`, also returning the metadata of the response.
+    pub async fn add_ip_routes_with_metadata(
+        &self,
+        input: AddIpRoutesRequest,
+    ) -> Result<(AddIpRoutesResult, ResponseMetadata), RusotoError<AddIpRoutesError>> {
+        let request = self.build_add_ip_routes_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<AcceptSharedDirectoryResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<AddIpRoutesResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(AcceptSharedDirectoryError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(AddIpRoutesError::from_response(response))
         }
     }
 
-    /// <p>If the DNS server for your on-premises domain uses a publicly addressable IP address, you must add a CIDR address block to correctly route traffic to and from your Microsoft AD on Amazon Web Services. <i>AddIpRoutes</i> adds this address block. You can also use <i>AddIpRoutes</i> to facilitate routing traffic that uses public IP ranges from your Microsoft AD on AWS to a peer VPC. </p> <p>Before you call <i>AddIpRoutes</i>, ensure that all of the required permissions have been explicitly granted through a policy. For details about what permissions are required to run the <i>AddIpRoutes</i> operation, see <a href="http://docs.aws.amazon.com/directoryservice/latest/admin-guide/UsingWithDS_IAM_ResourcePermissions.html">AWS Directory Service API Permissions: Actions, Resources, and Conditions Reference</a>.</p>
-    async fn add_ip_routes(
+    /// Presigns the request `add_ip_routes` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_add_ip_routes(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: AddIpRoutesRequest,
-    ) -> Result<AddIpRoutesResult, RusotoError<AddIpRoutesError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_add_ip_routes_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_add_ip_routes_request(&self, input: AddIpRoutesRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "ds", &self.region, "/");
+        request.set_operation("AddIpRoutes");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "DirectoryService_20150416.AddIpRoutes");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `add_tags_to_resource`, also returning the metadata of the response.
+    pub async fn add_tags_to_resource_with_metadata(
+        &self,
+        input: AddTagsToResourceRequest,
+    ) -> Result<(AddTagsToResourceResult, ResponseMetadata), RusotoError<AddTagsToResourceError>>
+    {
+        let request = self.build_add_tags_to_resource_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<AddIpRoutesResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<AddTagsToResourceResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(AddIpRoutesError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(AddTagsToResourceError::from_response(response))
         }
     }
 
-    /// <p>Adds or overwrites one or more tags for the specified directory. Each directory can have a maximum of 50 tags. Each tag consists of a key and optional value. Tag keys must be unique to each resource.</p>
-    async fn add_tags_to_resource(
+    /// Presigns the request `add_tags_to_resource` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_add_tags_to_resource(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: AddTagsToResourceRequest,
-    ) -> Result<AddTagsToResourceResult, RusotoError<AddTagsToResourceError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_add_tags_to_resource_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_add_tags_to_resource_request(&self, input: AddTagsToResourceRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "ds", &self.region, "/");
+        request.set_operation("AddTagsToResource");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -5987,27 +6697,58 @@ impl DirectoryService for DirectoryServiceClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `cancel_schema_extension`, also returning the metadata of the response.
+    pub async fn cancel_schema_extension_with_metadata(
+        &self,
+        input: CancelSchemaExtensionRequest,
+    ) -> Result<
+        (CancelSchemaExtensionResult, ResponseMetadata),
+        RusotoError<CancelSchemaExtensionError>,
+    > {
+        let request = self.build_cancel_schema_extension_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<AddTagsToResourceResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<CancelSchemaExtensionResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(AddTagsToResourceError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(CancelSchemaExtensionError::from_response(response))
         }
     }
 
-    /// <p>Cancels an in-progress schema extension to a Microsoft AD directory. Once a schema extension has started replicating to all domain controllers, the task can no longer be canceled. A schema extension can be canceled during any of the following states; <code>Initializing</code>, <code>CreatingSnapshot</code>, and <code>UpdatingSchema</code>.</p>
-    async fn cancel_schema_extension(
+    /// Presigns the request `cancel_schema_extension` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_cancel_schema_extension(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: CancelSchemaExtensionRequest,
-    ) -> Result<CancelSchemaExtensionResult, RusotoError<CancelSchemaExtensionError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_cancel_schema_extension_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_cancel_schema_extension_request(
+        &self,
+        input: CancelSchemaExtensionRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "ds", &self.region, "/");
+        request.set_operation("CancelSchemaExtension");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -6017,110 +6758,215 @@ impl DirectoryService for DirectoryServiceClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `connect_directory`, also returning the metadata of the response.
+    pub async fn connect_directory_with_metadata(
+        &self,
+        input: ConnectDirectoryRequest,
+    ) -> Result<(ConnectDirectoryResult, ResponseMetadata), RusotoError<ConnectDirectoryError>>
+    {
+        let request = self.build_connect_directory_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<CancelSchemaExtensionResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ConnectDirectoryResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(CancelSchemaExtensionError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ConnectDirectoryError::from_response(response))
         }
     }
 
-    /// <p>Creates an AD Connector to connect to an on-premises directory.</p> <p>Before you call <code>ConnectDirectory</code>, ensure that all of the required permissions have been explicitly granted through a policy. For details about what permissions are required to run the <code>ConnectDirectory</code> operation, see <a href="http://docs.aws.amazon.com/directoryservice/latest/admin-guide/UsingWithDS_IAM_ResourcePermissions.html">AWS Directory Service API Permissions: Actions, Resources, and Conditions Reference</a>.</p>
-    async fn connect_directory(
+    /// Presigns the request `connect_directory` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_connect_directory(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ConnectDirectoryRequest,
-    ) -> Result<ConnectDirectoryResult, RusotoError<ConnectDirectoryError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_connect_directory_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_connect_directory_request(&self, input: ConnectDirectoryRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "ds", &self.region, "/");
+        request.set_operation("ConnectDirectory");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "DirectoryService_20150416.ConnectDirectory");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `create_alias`, also returning the metadata of the response.
+    pub async fn create_alias_with_metadata(
+        &self,
+        input: CreateAliasRequest,
+    ) -> Result<(CreateAliasResult, ResponseMetadata), RusotoError<CreateAliasError>> {
+        let request = self.build_create_alias_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<ConnectDirectoryResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<CreateAliasResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ConnectDirectoryError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(CreateAliasError::from_response(response))
         }
     }
 
-    /// <p><p>Creates an alias for a directory and assigns the alias to the directory. The alias is used to construct the access URL for the directory, such as <code>http://&lt;alias&gt;.awsapps.com</code>.</p> <important> <p>After an alias has been created, it cannot be deleted or reused, so this operation should only be used when absolutely necessary.</p> </important></p>
-    async fn create_alias(
+    /// Presigns the request `create_alias` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_alias(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: CreateAliasRequest,
-    ) -> Result<CreateAliasResult, RusotoError<CreateAliasError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_alias_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_alias_request(&self, input: CreateAliasRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "ds", &self.region, "/");
+        request.set_operation("CreateAlias");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "DirectoryService_20150416.CreateAlias");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `create_computer`, also returning the metadata of the response.
+    pub async fn create_computer_with_metadata(
+        &self,
+        input: CreateComputerRequest,
+    ) -> Result<(CreateComputerResult, ResponseMetadata), RusotoError<CreateComputerError>> {
+        let request = self.build_create_computer_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<CreateAliasResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<CreateComputerResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(CreateAliasError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(CreateComputerError::from_response(response))
         }
     }
 
-    /// <p>Creates a computer account in the specified directory, and joins the computer to the directory.</p>
-    async fn create_computer(
+    /// Presigns the request `create_computer` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_computer(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: CreateComputerRequest,
-    ) -> Result<CreateComputerResult, RusotoError<CreateComputerError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_computer_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_computer_request(&self, input: CreateComputerRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "ds", &self.region, "/");
+        request.set_operation("CreateComputer");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "DirectoryService_20150416.CreateComputer");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `create_conditional_forwarder`, also returning the metadata of the response.
+    pub async fn create_conditional_forwarder_with_metadata(
+        &self,
+        input: CreateConditionalForwarderRequest,
+    ) -> Result<
+        (CreateConditionalForwarderResult, ResponseMetadata),
+        RusotoError<CreateConditionalForwarderError>,
+    > {
+        let request = self.build_create_conditional_forwarder_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<CreateComputerResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<CreateConditionalForwarderResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(CreateComputerError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(CreateConditionalForwarderError::from_response(response))
         }
     }
 
-    /// <p>Creates a conditional forwarder associated with your AWS directory. Conditional forwarders are required in order to set up a trust relationship with another domain. The conditional forwarder points to the trusted domain.</p>
-    async fn create_conditional_forwarder(
+    /// Presigns the request `create_conditional_forwarder` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_conditional_forwarder(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: CreateConditionalForwarderRequest,
-    ) -> Result<CreateConditionalForwarderResult, RusotoError<CreateConditionalForwarderError>>
-    {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_conditional_forwarder_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_conditional_forwarder_request(
+        &self,
+        input: CreateConditionalForwarderRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "ds", &self.region, "/");
+        request.set_operation("CreateConditionalForwarder");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -6130,55 +6976,110 @@ impl DirectoryService for DirectoryServiceClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `create_directory`, also returning the metadata of the response.
+    pub async fn create_directory_with_metadata(
+        &self,
+        input: CreateDirectoryRequest,
+    ) -> Result<(CreateDirectoryResult, ResponseMetadata), RusotoError<CreateDirectoryError>> {
+        let request = self.build_create_directory_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<CreateConditionalForwarderResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<CreateDirectoryResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(CreateConditionalForwarderError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(CreateDirectoryError::from_response(response))
         }
     }
 
-    /// <p>Creates a Simple AD directory. For more information, see <a href="https://docs.aws.amazon.com/directoryservice/latest/admin-guide/directory_simple_ad.html">Simple Active Directory</a> in the <i>AWS Directory Service Admin Guide</i>.</p> <p>Before you call <code>CreateDirectory</code>, ensure that all of the required permissions have been explicitly granted through a policy. For details about what permissions are required to run the <code>CreateDirectory</code> operation, see <a href="http://docs.aws.amazon.com/directoryservice/latest/admin-guide/UsingWithDS_IAM_ResourcePermissions.html">AWS Directory Service API Permissions: Actions, Resources, and Conditions Reference</a>.</p>
-    async fn create_directory(
+    /// Presigns the request `create_directory` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_directory(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: CreateDirectoryRequest,
-    ) -> Result<CreateDirectoryResult, RusotoError<CreateDirectoryError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_directory_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_directory_request(&self, input: CreateDirectoryRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "ds", &self.region, "/");
+        request.set_operation("CreateDirectory");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "DirectoryService_20150416.CreateDirectory");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `create_log_subscription`, also returning the metadata of the response.
+    pub async fn create_log_subscription_with_metadata(
+        &self,
+        input: CreateLogSubscriptionRequest,
+    ) -> Result<
+        (CreateLogSubscriptionResult, ResponseMetadata),
+        RusotoError<CreateLogSubscriptionError>,
+    > {
+        let request = self.build_create_log_subscription_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<CreateDirectoryResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<CreateLogSubscriptionResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(CreateDirectoryError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(CreateLogSubscriptionError::from_response(response))
         }
     }
 
-    /// <p>Creates a subscription to forward real-time Directory Service domain controller security logs to the specified Amazon CloudWatch log group in your AWS account.</p>
-    async fn create_log_subscription(
+    /// Presigns the request `create_log_subscription` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_log_subscription(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: CreateLogSubscriptionRequest,
-    ) -> Result<CreateLogSubscriptionResult, RusotoError<CreateLogSubscriptionError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_log_subscription_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_log_subscription_request(
+        &self,
+        input: CreateLogSubscriptionRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "ds", &self.region, "/");
+        request.set_operation("CreateLogSubscription");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -6188,28 +7089,53 @@ impl DirectoryService for DirectoryServiceClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `create_microsoft_ad`, also returning the metadata of the response.
+    pub async fn create_microsoft_ad_with_metadata(
+        &self,
+        input: CreateMicrosoftADRequest,
+    ) -> Result<(CreateMicrosoftADResult, ResponseMetadata), RusotoError<CreateMicrosoftADError>>
+    {
+        let request = self.build_create_microsoft_ad_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<CreateLogSubscriptionResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<CreateMicrosoftADResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(CreateLogSubscriptionError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(CreateMicrosoftADError::from_response(response))
         }
     }
 
-    /// <p>Creates a Microsoft AD directory in the AWS Cloud. For more information, see <a href="https://docs.aws.amazon.com/directoryservice/latest/admin-guide/directory_microsoft_ad.html">AWS Managed Microsoft AD</a> in the <i>AWS Directory Service Admin Guide</i>.</p> <p>Before you call <i>CreateMicrosoftAD</i>, ensure that all of the required permissions have been explicitly granted through a policy. For details about what permissions are required to run the <i>CreateMicrosoftAD</i> operation, see <a href="http://docs.aws.amazon.com/directoryservice/latest/admin-guide/UsingWithDS_IAM_ResourcePermissions.html">AWS Directory Service API Permissions: Actions, Resources, and Conditions Reference</a>.</p>
-    async fn create_microsoft_ad(
+    /// Presigns the request `create_microsoft_ad` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_microsoft_ad(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: CreateMicrosoftADRequest,
-    ) -> Result<CreateMicrosoftADResult, RusotoError<CreateMicrosoftADError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_microsoft_ad_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_microsoft_ad_request(&self, input: CreateMicrosoftADRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "ds", &self.region, "/");
+        request.set_operation("CreateMicrosoftAD");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -6219,82 +7145,162 @@ impl DirectoryService for DirectoryServiceClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `create_snapshot`, also returning the metadata of the response.
+    pub async fn create_snapshot_with_metadata(
+        &self,
+        input: CreateSnapshotRequest,
+    ) -> Result<(CreateSnapshotResult, ResponseMetadata), RusotoError<CreateSnapshotError>> {
+        let request = self.build_create_snapshot_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<CreateMicrosoftADResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<CreateSnapshotResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(CreateMicrosoftADError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(CreateSnapshotError::from_response(response))
         }
     }
 
-    /// <p><p>Creates a snapshot of a Simple AD or Microsoft AD directory in the AWS cloud.</p> <note> <p>You cannot take snapshots of AD Connector directories.</p> </note></p>
-    async fn create_snapshot(
+    /// Presigns the request `create_snapshot` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_snapshot(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: CreateSnapshotRequest,
-    ) -> Result<CreateSnapshotResult, RusotoError<CreateSnapshotError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_snapshot_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_snapshot_request(&self, input: CreateSnapshotRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "ds", &self.region, "/");
+        request.set_operation("CreateSnapshot");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "DirectoryService_20150416.CreateSnapshot");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `create_trust`, also returning the metadata of the response.
+    pub async fn create_trust_with_metadata(
+        &self,
+        input: CreateTrustRequest,
+    ) -> Result<(CreateTrustResult, ResponseMetadata), RusotoError<CreateTrustError>> {
+        let request = self.build_create_trust_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<CreateSnapshotResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<CreateTrustResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(CreateSnapshotError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(CreateTrustError::from_response(response))
         }
     }
 
-    /// <p>AWS Directory Service for Microsoft Active Directory allows you to configure trust relationships. For example, you can establish a trust between your AWS Managed Microsoft AD directory, and your existing on-premises Microsoft Active Directory. This would allow you to provide users and groups access to resources in either domain, with a single set of credentials.</p> <p>This action initiates the creation of the AWS side of a trust relationship between an AWS Managed Microsoft AD directory and an external domain. You can create either a forest trust or an external trust.</p>
-    async fn create_trust(
+    /// Presigns the request `create_trust` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_trust(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: CreateTrustRequest,
-    ) -> Result<CreateTrustResult, RusotoError<CreateTrustError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_trust_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_trust_request(&self, input: CreateTrustRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "ds", &self.region, "/");
+        request.set_operation("CreateTrust");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "DirectoryService_20150416.CreateTrust");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `delete_conditional_forwarder`, also returning the metadata of the response.
+    pub async fn delete_conditional_forwarder_with_metadata(
+        &self,
+        input: DeleteConditionalForwarderRequest,
+    ) -> Result<
+        (DeleteConditionalForwarderResult, ResponseMetadata),
+        RusotoError<DeleteConditionalForwarderError>,
+    > {
+        let request = self.build_delete_conditional_forwarder_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<CreateTrustResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DeleteConditionalForwarderResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(CreateTrustError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DeleteConditionalForwarderError::from_response(response))
         }
     }
 
-    /// <p>Deletes a conditional forwarder that has been set up for your AWS directory.</p>
-    async fn delete_conditional_forwarder(
+    /// Presigns the request `delete_conditional_forwarder` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_conditional_forwarder(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteConditionalForwarderRequest,
-    ) -> Result<DeleteConditionalForwarderResult, RusotoError<DeleteConditionalForwarderError>>
-    {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_conditional_forwarder_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_conditional_forwarder_request(
+        &self,
+        input: DeleteConditionalForwarderRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "ds", &self.region, "/");
+        request.set_operation("DeleteConditionalForwarder");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -6304,55 +7310,110 @@ impl DirectoryService for DirectoryServiceClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `delete_directory`, also returning the metadata of the response.
+    pub async fn delete_directory_with_metadata(
+        &self,
+        input: DeleteDirectoryRequest,
+    ) -> Result<(DeleteDirectoryResult, ResponseMetadata), RusotoError<DeleteDirectoryError>> {
+        let request = self.build_delete_directory_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DeleteConditionalForwarderResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DeleteDirectoryResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteConditionalForwarderError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DeleteDirectoryError::from_response(response))
         }
     }
 
-    /// <p>Deletes an AWS Directory Service directory.</p> <p>Before you call <code>DeleteDirectory</code>, ensure that all of the required permissions have been explicitly granted through a policy. For details about what permissions are required to run the <code>DeleteDirectory</code> operation, see <a href="http://docs.aws.amazon.com/directoryservice/latest/admin-guide/UsingWithDS_IAM_ResourcePermissions.html">AWS Directory Service API Permissions: Actions, Resources, and Conditions Reference</a>.</p>
-    async fn delete_directory(
+    /// Presigns the request `delete_directory` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_directory(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteDirectoryRequest,
-    ) -> Result<DeleteDirectoryResult, RusotoError<DeleteDirectoryError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_directory_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_directory_request(&self, input: DeleteDirectoryRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "ds", &self.region, "/");
+        request.set_operation("DeleteDirectory");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "DirectoryService_20150416.DeleteDirectory");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `delete_log_subscription`, also returning the metadata of the response.
+    pub async fn delete_log_subscription_with_metadata(
+        &self,
+        input: DeleteLogSubscriptionRequest,
+    ) -> Result<
+        (DeleteLogSubscriptionResult, ResponseMetadata),
+        RusotoError<DeleteLogSubscriptionError>,
+    > {
+        let request = self.build_delete_log_subscription_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<DeleteDirectoryResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DeleteLogSubscriptionResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteDirectoryError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DeleteLogSubscriptionError::from_response(response))
         }
     }
 
-    /// <p>Deletes the specified log subscription.</p>
-    async fn delete_log_subscription(
+    /// Presigns the request `delete_log_subscription` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_log_subscription(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteLogSubscriptionRequest,
-    ) -> Result<DeleteLogSubscriptionResult, RusotoError<DeleteLogSubscriptionError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_log_subscription_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_log_subscription_request(
+        &self,
+        input: DeleteLogSubscriptionRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "ds", &self.region, "/");
+        request.set_operation("DeleteLogSubscription");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -6362,82 +7423,162 @@ impl DirectoryService for DirectoryServiceClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `delete_snapshot`, also returning the metadata of the response.
+    pub async fn delete_snapshot_with_metadata(
+        &self,
+        input: DeleteSnapshotRequest,
+    ) -> Result<(DeleteSnapshotResult, ResponseMetadata), RusotoError<DeleteSnapshotError>> {
+        let request = self.build_delete_snapshot_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DeleteLogSubscriptionResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DeleteSnapshotResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteLogSubscriptionError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DeleteSnapshotError::from_response(response))
         }
     }
 
-    /// <p>Deletes a directory snapshot.</p>
-    async fn delete_snapshot(
+    /// Presigns the request `delete_snapshot` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_snapshot(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteSnapshotRequest,
-    ) -> Result<DeleteSnapshotResult, RusotoError<DeleteSnapshotError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_snapshot_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_snapshot_request(&self, input: DeleteSnapshotRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "ds", &self.region, "/");
+        request.set_operation("DeleteSnapshot");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "DirectoryService_20150416.DeleteSnapshot");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `delete_trust`, also returning the metadata of the response.
+    pub async fn delete_trust_with_metadata(
+        &self,
+        input: DeleteTrustRequest,
+    ) -> Result<(DeleteTrustResult, ResponseMetadata), RusotoError<DeleteTrustError>> {
+        let request = self.build_delete_trust_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<DeleteSnapshotResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DeleteTrustResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteSnapshotError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DeleteTrustError::from_response(response))
         }
     }
 
-    /// <p>Deletes an existing trust relationship between your AWS Managed Microsoft AD directory and an external domain.</p>
-    async fn delete_trust(
+    /// Presigns the request `delete_trust` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_trust(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteTrustRequest,
-    ) -> Result<DeleteTrustResult, RusotoError<DeleteTrustError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_trust_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_trust_request(&self, input: DeleteTrustRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "ds", &self.region, "/");
+        request.set_operation("DeleteTrust");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "DirectoryService_20150416.DeleteTrust");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `deregister_certificate`, also returning the metadata of the response.
+    pub async fn deregister_certificate_with_metadata(
+        &self,
+        input: DeregisterCertificateRequest,
+    ) -> Result<
+        (DeregisterCertificateResult, ResponseMetadata),
+        RusotoError<DeregisterCertificateError>,
+    > {
+        let request = self.build_deregister_certificate_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<DeleteTrustResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DeregisterCertificateResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteTrustError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DeregisterCertificateError::from_response(response))
         }
     }
 
-    /// <p>Deletes from the system the certificate that was registered for a secured LDAP connection.</p>
-    async fn deregister_certificate(
+    /// Presigns the request `deregister_certificate` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_deregister_certificate(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeregisterCertificateRequest,
-    ) -> Result<DeregisterCertificateResult, RusotoError<DeregisterCertificateError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_deregister_certificate_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_deregister_certificate_request(
+        &self,
+        input: DeregisterCertificateRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "ds", &self.region, "/");
+        request.set_operation("DeregisterCertificate");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -6447,28 +7588,58 @@ impl DirectoryService for DirectoryServiceClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `deregister_event_topic`, also returning the metadata of the response.
+    pub async fn deregister_event_topic_with_metadata(
+        &self,
+        input: DeregisterEventTopicRequest,
+    ) -> Result<
+        (DeregisterEventTopicResult, ResponseMetadata),
+        RusotoError<DeregisterEventTopicError>,
+    > {
+        let request = self.build_deregister_event_topic_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DeregisterCertificateResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DeregisterEventTopicResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DeregisterCertificateError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DeregisterEventTopicError::from_response(response))
         }
     }
 
-    /// <p>Removes the specified directory as a publisher to the specified SNS topic.</p>
-    async fn deregister_event_topic(
+    /// Presigns the request `deregister_event_topic` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_deregister_event_topic(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeregisterEventTopicRequest,
-    ) -> Result<DeregisterEventTopicResult, RusotoError<DeregisterEventTopicError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_deregister_event_topic_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_deregister_event_topic_request(
+        &self,
+        input: DeregisterEventTopicRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "ds", &self.region, "/");
+        request.set_operation("DeregisterEventTopic");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -6478,28 +7649,56 @@ impl DirectoryService for DirectoryServiceClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `describe_certificate`, also returning the metadata of the response.
+    pub async fn describe_certificate_with_metadata(
+        &self,
+        input: DescribeCertificateRequest,
+    ) -> Result<(DescribeCertificateResult, ResponseMetadata), RusotoError<DescribeCertificateError>>
+    {
+        let request = self.build_describe_certificate_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DeregisterEventTopicResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DescribeCertificateResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DeregisterEventTopicError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DescribeCertificateError::from_response(response))
         }
     }
 
-    /// <p>Displays information about the certificate registered for a secured LDAP connection.</p>
-    async fn describe_certificate(
+    /// Presigns the request `describe_certificate` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_certificate(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeCertificateRequest,
-    ) -> Result<DescribeCertificateResult, RusotoError<DescribeCertificateError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_certificate_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_certificate_request(
+        &self,
+        input: DescribeCertificateRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "ds", &self.region, "/");
+        request.set_operation("DescribeCertificate");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -6509,29 +7708,58 @@ impl DirectoryService for DirectoryServiceClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `describe_conditional_forwarders`, also returning the metadata of the response.
+    pub async fn describe_conditional_forwarders_with_metadata(
+        &self,
+        input: DescribeConditionalForwardersRequest,
+    ) -> Result<
+        (DescribeConditionalForwardersResult, ResponseMetadata),
+        RusotoError<DescribeConditionalForwardersError>,
+    > {
+        let request = self.build_describe_conditional_forwarders_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DescribeCertificateResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DescribeConditionalForwardersResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeCertificateError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DescribeConditionalForwardersError::from_response(response))
         }
     }
 
-    /// <p>Obtains information about the conditional forwarders for this account.</p> <p>If no input parameters are provided for RemoteDomainNames, this request describes all conditional forwarders for the specified directory ID.</p>
-    async fn describe_conditional_forwarders(
+    /// Presigns the request `describe_conditional_forwarders` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_conditional_forwarders(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeConditionalForwardersRequest,
-    ) -> Result<DescribeConditionalForwardersResult, RusotoError<DescribeConditionalForwardersError>>
-    {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_conditional_forwarders_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_conditional_forwarders_request(
+        &self,
+        input: DescribeConditionalForwardersRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "ds", &self.region, "/");
+        request.set_operation("DescribeConditionalForwarders");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -6541,28 +7769,56 @@ impl DirectoryService for DirectoryServiceClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `describe_directories`, also returning the metadata of the response.
+    pub async fn describe_directories_with_metadata(
+        &self,
+        input: DescribeDirectoriesRequest,
+    ) -> Result<(DescribeDirectoriesResult, ResponseMetadata), RusotoError<DescribeDirectoriesError>>
+    {
+        let request = self.build_describe_directories_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DescribeConditionalForwardersResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DescribeDirectoriesResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeConditionalForwardersError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DescribeDirectoriesError::from_response(response))
         }
     }
 
-    /// <p>Obtains information about the directories that belong to this account.</p> <p>You can retrieve information about specific directories by passing the directory identifiers in the <code>DirectoryIds</code> parameter. Otherwise, all directories that belong to the current account are returned.</p> <p>This operation supports pagination with the use of the <code>NextToken</code> request and response parameters. If more results are available, the <code>DescribeDirectoriesResult.NextToken</code> member contains a token that you pass in the next call to <a>DescribeDirectories</a> to retrieve the next set of items.</p> <p>You can also specify a maximum number of return results with the <code>Limit</code> parameter.</p>
-    async fn describe_directories(
+    /// Presigns the request `describe_directories` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_directories(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeDirectoriesRequest,
-    ) -> Result<DescribeDirectoriesResult, RusotoError<DescribeDirectoriesError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_directories_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_directories_request(
+        &self,
+        input: DescribeDirectoriesRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "ds", &self.region, "/");
+        request.set_operation("DescribeDirectories");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -6572,28 +7828,58 @@ impl DirectoryService for DirectoryServiceClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `describe_domain_controllers`, also returning the metadata of the response.
+    pub async fn describe_domain_controllers_with_metadata(
+        &self,
+        input: DescribeDomainControllersRequest,
+    ) -> Result<
+        (DescribeDomainControllersResult, ResponseMetadata),
+        RusotoError<DescribeDomainControllersError>,
+    > {
+        let request = self.build_describe_domain_controllers_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DescribeDirectoriesResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DescribeDomainControllersResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeDirectoriesError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DescribeDomainControllersError::from_response(response))
         }
     }
 
-    /// <p>Provides information about any domain controllers in your directory.</p>
-    async fn describe_domain_controllers(
+    /// Presigns the request `describe_domain_controllers` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_domain_controllers(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeDomainControllersRequest,
-    ) -> Result<DescribeDomainControllersResult, RusotoError<DescribeDomainControllersError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_domain_controllers_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_domain_controllers_request(
+        &self,
+        input: DescribeDomainControllersRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "ds", &self.region, "/");
+        request.set_operation("DescribeDomainControllers");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -6603,28 +7889,56 @@ impl DirectoryService for DirectoryServiceClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `describe_event_topics`, also returning the metadata of the response.
+    pub async fn describe_event_topics_with_metadata(
+        &self,
+        input: DescribeEventTopicsRequest,
+    ) -> Result<(DescribeEventTopicsResult, ResponseMetadata), RusotoError<DescribeEventTopicsError>>
+    {
+        let request = self.build_describe_event_topics_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DescribeDomainControllersResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DescribeEventTopicsResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeDomainControllersError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DescribeEventTopicsError::from_response(response))
         }
     }
 
-    /// <p>Obtains information about which SNS topics receive status messages from the specified directory.</p> <p>If no input parameters are provided, such as DirectoryId or TopicName, this request describes all of the associations in the account.</p>
-    async fn describe_event_topics(
+    /// Presigns the request `describe_event_topics` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_event_topics(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeEventTopicsRequest,
-    ) -> Result<DescribeEventTopicsResult, RusotoError<DescribeEventTopicsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_event_topics_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_event_topics_request(
+        &self,
+        input: DescribeEventTopicsRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "ds", &self.region, "/");
+        request.set_operation("DescribeEventTopics");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -6634,28 +7948,58 @@ impl DirectoryService for DirectoryServiceClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `describe_ldaps_settings`, also returning the metadata of the response.
+    pub async fn describe_ldaps_settings_with_metadata(
+        &self,
+        input: DescribeLDAPSSettingsRequest,
+    ) -> Result<
+        (DescribeLDAPSSettingsResult, ResponseMetadata),
+        RusotoError<DescribeLDAPSSettingsError>,
+    > {
+        let request = self.build_describe_ldaps_settings_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DescribeEventTopicsResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DescribeLDAPSSettingsResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeEventTopicsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DescribeLDAPSSettingsError::from_response(response))
         }
     }
 
-    /// <p>Describes the status of LDAP security for the specified directory.</p>
-    async fn describe_ldaps_settings(
+    /// Presigns the request `describe_ldaps_settings` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_ldaps_settings(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeLDAPSSettingsRequest,
-    ) -> Result<DescribeLDAPSSettingsResult, RusotoError<DescribeLDAPSSettingsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_ldaps_settings_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_ldaps_settings_request(
+        &self,
+        input: DescribeLDAPSSettingsRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "ds", &self.region, "/");
+        request.set_operation("DescribeLDAPSSettings");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -6665,28 +8009,58 @@ impl DirectoryService for DirectoryServiceClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `describe_shared_directories`, also returning the metadata of the response.
+    pub async fn describe_shared_directories_with_metadata(
+        &self,
+        input: DescribeSharedDirectoriesRequest,
+    ) -> Result<
+        (DescribeSharedDirectoriesResult, ResponseMetadata),
+        RusotoError<DescribeSharedDirectoriesError>,
+    > {
+        let request = self.build_describe_shared_directories_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DescribeLDAPSSettingsResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DescribeSharedDirectoriesResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeLDAPSSettingsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DescribeSharedDirectoriesError::from_response(response))
         }
     }
 
-    /// <p>Returns the shared directories in your account. </p>
-    async fn describe_shared_directories(
+    /// Presigns the request `describe_shared_directories` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_shared_directories(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeSharedDirectoriesRequest,
-    ) -> Result<DescribeSharedDirectoriesResult, RusotoError<DescribeSharedDirectoriesError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_shared_directories_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_shared_directories_request(
+        &self,
+        input: DescribeSharedDirectoriesRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "ds", &self.region, "/");
+        request.set_operation("DescribeSharedDirectories");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -6696,28 +8070,53 @@ impl DirectoryService for DirectoryServiceClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `describe_snapshots`, also returning the metadata of the response.
+    pub async fn describe_snapshots_with_metadata(
+        &self,
+        input: DescribeSnapshotsRequest,
+    ) -> Result<(DescribeSnapshotsResult, ResponseMetadata), RusotoError<DescribeSnapshotsError>>
+    {
+        let request = self.build_describe_snapshots_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DescribeSharedDirectoriesResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DescribeSnapshotsResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeSharedDirectoriesError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DescribeSnapshotsError::from_response(response))
         }
     }
 
-    /// <p>Obtains information about the directory snapshots that belong to this account.</p> <p>This operation supports pagination with the use of the <i>NextToken</i> request and response parameters. If more results are available, the <i>DescribeSnapshots.NextToken</i> member contains a token that you pass in the next call to <a>DescribeSnapshots</a> to retrieve the next set of items.</p> <p>You can also specify a maximum number of return results with the <i>Limit</i> parameter.</p>
-    async fn describe_snapshots(
+    /// Presigns the request `describe_snapshots` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_snapshots(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeSnapshotsRequest,
-    ) -> Result<DescribeSnapshotsResult, RusotoError<DescribeSnapshotsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_snapshots_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_snapshots_request(&self, input: DescribeSnapshotsRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "ds", &self.region, "/");
+        request.set_operation("DescribeSnapshots");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -6727,215 +8126,415 @@ impl DirectoryService for DirectoryServiceClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `describe_trusts`, also returning the metadata of the response.
+    pub async fn describe_trusts_with_metadata(
+        &self,
+        input: DescribeTrustsRequest,
+    ) -> Result<(DescribeTrustsResult, ResponseMetadata), RusotoError<DescribeTrustsError>> {
+        let request = self.build_describe_trusts_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<DescribeSnapshotsResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DescribeTrustsResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeSnapshotsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DescribeTrustsError::from_response(response))
         }
     }
 
-    /// <p>Obtains information about the trust relationships for this account.</p> <p>If no input parameters are provided, such as DirectoryId or TrustIds, this request describes all the trust relationships belonging to the account.</p>
-    async fn describe_trusts(
+    /// Presigns the request `describe_trusts` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_trusts(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeTrustsRequest,
-    ) -> Result<DescribeTrustsResult, RusotoError<DescribeTrustsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_trusts_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_trusts_request(&self, input: DescribeTrustsRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "ds", &self.region, "/");
+        request.set_operation("DescribeTrusts");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "DirectoryService_20150416.DescribeTrusts");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `disable_ldaps`, also returning the metadata of the response.
+    pub async fn disable_ldaps_with_metadata(
+        &self,
+        input: DisableLDAPSRequest,
+    ) -> Result<(DisableLDAPSResult, ResponseMetadata), RusotoError<DisableLDAPSError>> {
+        let request = self.build_disable_ldaps_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<DescribeTrustsResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DisableLDAPSResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeTrustsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DisableLDAPSError::from_response(response))
         }
     }
 
-    /// <p>Deactivates LDAP secure calls for the specified directory.</p>
-    async fn disable_ldaps(
+    /// Presigns the request `disable_ldaps` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_disable_ldaps(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DisableLDAPSRequest,
-    ) -> Result<DisableLDAPSResult, RusotoError<DisableLDAPSError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_disable_ldaps_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_disable_ldaps_request(&self, input: DisableLDAPSRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "ds", &self.region, "/");
+        request.set_operation("DisableLDAPS");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "DirectoryService_20150416.DisableLDAPS");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `disable_radius`, also returning the metadata of the response.
+    pub async fn disable_radius_with_metadata(
+        &self,
+        input: DisableRadiusRequest,
+    ) -> Result<(DisableRadiusResult, ResponseMetadata), RusotoError<DisableRadiusError>> {
+        let request = self.build_disable_radius_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<DisableLDAPSResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DisableRadiusResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DisableLDAPSError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DisableRadiusError::from_response(response))
         }
     }
 
-    /// <p>Disables multi-factor authentication (MFA) with the Remote Authentication Dial In User Service (RADIUS) server for an AD Connector or Microsoft AD directory.</p>
-    async fn disable_radius(
+    /// Presigns the request `disable_radius` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_disable_radius(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DisableRadiusRequest,
-    ) -> Result<DisableRadiusResult, RusotoError<DisableRadiusError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_disable_radius_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_disable_radius_request(&self, input: DisableRadiusRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "ds", &self.region, "/");
+        request.set_operation("DisableRadius");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "DirectoryService_20150416.DisableRadius");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `disable_sso`, also returning the metadata of the response.
+    pub async fn disable_sso_with_metadata(
+        &self,
+        input: DisableSsoRequest,
+    ) -> Result<(DisableSsoResult, ResponseMetadata), RusotoError<DisableSsoError>> {
+        let request = self.build_disable_sso_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<DisableRadiusResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DisableSsoResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DisableRadiusError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DisableSsoError::from_response(response))
         }
     }
 
-    /// <p>Disables single-sign on for a directory.</p>
-    async fn disable_sso(
+    /// Presigns the request `disable_sso` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_disable_sso(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DisableSsoRequest,
-    ) -> Result<DisableSsoResult, RusotoError<DisableSsoError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_disable_sso_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_disable_sso_request(&self, input: DisableSsoRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "ds", &self.region, "/");
+        request.set_operation("DisableSso");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "DirectoryService_20150416.DisableSso");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `enable_ldaps`, also returning the metadata of the response.
+    pub async fn enable_ldaps_with_metadata(
+        &self,
+        input: EnableLDAPSRequest,
+    ) -> Result<(EnableLDAPSResult, ResponseMetadata), RusotoError<EnableLDAPSError>> {
+        let request = self.build_enable_ldaps_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<DisableSsoResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<EnableLDAPSResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DisableSsoError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(EnableLDAPSError::from_response(response))
         }
     }
 
-    /// <p>Activates the switch for the specific directory to always use LDAP secure calls.</p>
-    async fn enable_ldaps(
+    /// Presigns the request `enable_ldaps` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_enable_ldaps(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: EnableLDAPSRequest,
-    ) -> Result<EnableLDAPSResult, RusotoError<EnableLDAPSError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_enable_ldaps_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_enable_ldaps_request(&self, input: EnableLDAPSRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "ds", &self.region, "/");
+        request.set_operation("EnableLDAPS");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "DirectoryService_20150416.EnableLDAPS");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `enable_radius`, also returning the metadata of the response.
+    pub async fn enable_radius_with_metadata(
+        &self,
+        input: EnableRadiusRequest,
+    ) -> Result<(EnableRadiusResult, ResponseMetadata), RusotoError<EnableRadiusError>> {
+        let request = self.build_enable_radius_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<EnableLDAPSResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<EnableRadiusResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(EnableLDAPSError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(EnableRadiusError::from_response(response))
         }
     }
 
-    /// <p>Enables multi-factor authentication (MFA) with the Remote Authentication Dial In User Service (RADIUS) server for an AD Connector or Microsoft AD directory.</p>
-    async fn enable_radius(
+    /// Presigns the request `enable_radius` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_enable_radius(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: EnableRadiusRequest,
-    ) -> Result<EnableRadiusResult, RusotoError<EnableRadiusError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_enable_radius_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_enable_radius_request(&self, input: EnableRadiusRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "ds", &self.region, "/");
+        request.set_operation("EnableRadius");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "DirectoryService_20150416.EnableRadius");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `enable_sso`, also returning the metadata of the response.
+    pub async fn enable_sso_with_metadata(
+        &self,
+        input: EnableSsoRequest,
+    ) -> Result<(EnableSsoResult, ResponseMetadata), RusotoError<EnableSsoError>> {
+        let request = self.build_enable_sso_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<EnableRadiusResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result =
+                proto::json::ResponsePayload::new(&response).deserialize::<EnableSsoResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(EnableRadiusError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(EnableSsoError::from_response(response))
         }
     }
 
-    /// <p>Enables single sign-on for a directory. Single sign-on allows users in your directory to access certain AWS services from a computer joined to the directory without having to enter their credentials separately.</p>
-    async fn enable_sso(
+    /// Presigns the request `enable_sso` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_enable_sso(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: EnableSsoRequest,
-    ) -> Result<EnableSsoResult, RusotoError<EnableSsoError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_enable_sso_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_enable_sso_request(&self, input: EnableSsoRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "ds", &self.region, "/");
+        request.set_operation("EnableSso");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "DirectoryService_20150416.EnableSso");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `get_directory_limits`, also returning the metadata of the response.
+    pub async fn get_directory_limits_with_metadata(
+        &self,
+    ) -> Result<(GetDirectoryLimitsResult, ResponseMetadata), RusotoError<GetDirectoryLimitsError>>
+    {
+        let request = self.build_get_directory_limits_request();
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<EnableSsoResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<GetDirectoryLimitsResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(EnableSsoError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(GetDirectoryLimitsError::from_response(response))
         }
     }
 
-    /// <p>Obtains directory limit information for the current Region.</p>
-    async fn get_directory_limits(
+    /// Presigns the request `get_directory_limits` would send with `presigner` instead of sending it.
+    pub async fn presign_get_directory_limits(
         &self,
-    ) -> Result<GetDirectoryLimitsResult, RusotoError<GetDirectoryLimitsError>> {
+        presigner: &rusoto_core::Presigner,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_directory_limits_request();
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_directory_limits_request(&self) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "ds", &self.region, "/");
+        request.set_operation("GetDirectoryLimits");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -6944,28 +8543,53 @@ impl DirectoryService for DirectoryServiceClient {
         );
         request.set_payload(Some(bytes::Bytes::from_static(b"{}")));
 
+        request
+    }
+
+    /// Calls `get_snapshot_limits`, also returning the metadata of the response.
+    pub async fn get_snapshot_limits_with_metadata(
+        &self,
+        input: GetSnapshotLimitsRequest,
+    ) -> Result<(GetSnapshotLimitsResult, ResponseMetadata), RusotoError<GetSnapshotLimitsError>>
+    {
+        let request = self.build_get_snapshot_limits_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<GetDirectoryLimitsResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<GetSnapshotLimitsResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(GetDirectoryLimitsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(GetSnapshotLimitsError::from_response(response))
         }
     }
 
-    /// <p>Obtains the manual snapshot limits for a directory.</p>
-    async fn get_snapshot_limits(
+    /// Presigns the request `get_snapshot_limits` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_snapshot_limits(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetSnapshotLimitsRequest,
-    ) -> Result<GetSnapshotLimitsResult, RusotoError<GetSnapshotLimitsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_snapshot_limits_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_snapshot_limits_request(&self, input: GetSnapshotLimitsRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "ds", &self.region, "/");
+        request.set_operation("GetSnapshotLimits");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -6975,81 +8599,163 @@ impl DirectoryService for DirectoryServiceClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `list_certificates`, also returning the metadata of the response.
+    pub async fn list_certificates_with_metadata(
+        &self,
+        input: ListCertificatesRequest,
+    ) -> Result<(ListCertificatesResult, ResponseMetadata), RusotoError<ListCertificatesError>>
+    {
+        let request = self.build_list_certificates_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<GetSnapshotLimitsResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ListCertificatesResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(GetSnapshotLimitsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ListCertificatesError::from_response(response))
         }
     }
 
-    /// <p>For the specified directory, lists all the certificates registered for a secured LDAP connection.</p>
-    async fn list_certificates(
+    /// Presigns the request `list_certificates` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_certificates(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListCertificatesRequest,
-    ) -> Result<ListCertificatesResult, RusotoError<ListCertificatesError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_certificates_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_certificates_request(&self, input: ListCertificatesRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "ds", &self.region, "/");
+        request.set_operation("ListCertificates");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "DirectoryService_20150416.ListCertificates");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `list_ip_routes`, also returning the metadata of the response.
+    pub async fn list_ip_routes_with_metadata(
+        &self,
+        input: ListIpRoutesRequest,
+    ) -> Result<(ListIpRoutesResult, ResponseMetadata), RusotoError<ListIpRoutesError>> {
+        let request = self.build_list_ip_routes_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<ListCertificatesResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ListIpRoutesResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ListCertificatesError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ListIpRoutesError::from_response(response))
         }
     }
 
-    /// <p>Lists the address blocks that you have added to a directory.</p>
-    async fn list_ip_routes(
+    /// Presigns the request `list_ip_routes` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_ip_routes(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListIpRoutesRequest,
-    ) -> Result<ListIpRoutesResult, RusotoError<ListIpRoutesError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_ip_routes_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_ip_routes_request(&self, input: ListIpRoutesRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "ds", &self.region, "/");
+        request.set_operation("ListIpRoutes");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "DirectoryService_20150416.ListIpRoutes");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `list_log_subscriptions`, also returning the metadata of the response.
+    pub async fn list_log_subscriptions_with_metadata(
+        &self,
+        input: ListLogSubscriptionsRequest,
+    ) -> Result<
+        (ListLogSubscriptionsResult, ResponseMetadata),
+        RusotoError<ListLogSubscriptionsError>,
+    > {
+        let request = self.build_list_log_subscriptions_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<ListIpRoutesResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ListLogSubscriptionsResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ListIpRoutesError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ListLogSubscriptionsError::from_response(response))
         }
     }
 
-    /// <p>Lists the active log subscriptions for the AWS account.</p>
-    async fn list_log_subscriptions(
+    /// Presigns the request `list_log_subscriptions` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_log_subscriptions(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListLogSubscriptionsRequest,
-    ) -> Result<ListLogSubscriptionsResult, RusotoError<ListLogSubscriptionsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_log_subscriptions_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_log_subscriptions_request(
+        &self,
+        input: ListLogSubscriptionsRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "ds", &self.region, "/");
+        request.set_operation("ListLogSubscriptions");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -7059,28 +8765,58 @@ impl DirectoryService for DirectoryServiceClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `list_schema_extensions`, also returning the metadata of the response.
+    pub async fn list_schema_extensions_with_metadata(
+        &self,
+        input: ListSchemaExtensionsRequest,
+    ) -> Result<
+        (ListSchemaExtensionsResult, ResponseMetadata),
+        RusotoError<ListSchemaExtensionsError>,
+    > {
+        let request = self.build_list_schema_extensions_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListLogSubscriptionsResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ListSchemaExtensionsResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ListLogSubscriptionsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ListSchemaExtensionsError::from_response(response))
         }
     }
 
-    /// <p>Lists all schema extensions applied to a Microsoft AD Directory.</p>
-    async fn list_schema_extensions(
+    /// Presigns the request `list_schema_extensions` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_schema_extensions(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListSchemaExtensionsRequest,
-    ) -> Result<ListSchemaExtensionsResult, RusotoError<ListSchemaExtensionsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_schema_extensions_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_schema_extensions_request(
+        &self,
+        input: ListSchemaExtensionsRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "ds", &self.region, "/");
+        request.set_operation("ListSchemaExtensions");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -7090,30 +8826,58 @@ impl DirectoryService for DirectoryServiceClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `list_tags_for_resource`, also returning the metadata of the response.
+    pub async fn list_tags_for_resource_with_metadata(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> Result<(ListTagsForResourceResult, ResponseMetadata), RusotoError<ListTagsForResourceError>>
+    {
+        let request = self.build_list_tags_for_resource_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListSchemaExtensionsResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ListTagsForResourceResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ListSchemaExtensionsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ListTagsForResourceError::from_response(response))
         }
     }
 
-    /// <p>Lists all tags on a directory.</p>
-    async fn list_tags_for_resource(
+    /// Presigns the request `list_tags_for_resource` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_tags_for_resource(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListTagsForResourceRequest,
-    ) -> Result<ListTagsForResourceResult, RusotoError<ListTagsForResourceError>> {
-        let mut request = SignedRequest::new("POST", "ds", &self.region, "/");
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_tags_for_resource_request(input);
+        self.client.presign(presigner, request).await
+    }
 
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
+    fn build_list_tags_for_resource_request(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> SignedRequest {
+        let mut request = SignedRequest::new("POST", "ds", &self.region, "/");
+        request.set_operation("ListTagsForResource");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
             "x-amz-target",
             "DirectoryService_20150416.ListTagsForResource",
@@ -7121,28 +8885,56 @@ impl DirectoryService for DirectoryServiceClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `register_certificate`, also returning the metadata of the response.
+    pub async fn register_certificate_with_metadata(
+        &self,
+        input: RegisterCertificateRequest,
+    ) -> Result<(RegisterCertificateResult, ResponseMetadata), RusotoError<RegisterCertificateError>>
+    {
+        let request = self.build_register_certificate_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListTagsForResourceResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<RegisterCertificateResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ListTagsForResourceError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(RegisterCertificateError::from_response(response))
         }
     }
 
-    /// <p>Registers a certificate for secured LDAP connection.</p>
-    async fn register_certificate(
+    /// Presigns the request `register_certificate` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_register_certificate(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: RegisterCertificateRequest,
-    ) -> Result<RegisterCertificateResult, RusotoError<RegisterCertificateError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_register_certificate_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_register_certificate_request(
+        &self,
+        input: RegisterCertificateRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "ds", &self.region, "/");
+        request.set_operation("RegisterCertificate");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -7152,28 +8944,56 @@ impl DirectoryService for DirectoryServiceClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `register_event_topic`, also returning the metadata of the response.
+    pub async fn register_event_topic_with_metadata(
+        &self,
+        input: RegisterEventTopicRequest,
+    ) -> Result<(RegisterEventTopicResult, ResponseMetadata), RusotoError<RegisterEventTopicError>>
+    {
+        let request = self.build_register_event_topic_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<RegisterCertificateResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<RegisterEventTopicResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(RegisterCertificateError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(RegisterEventTopicError::from_response(response))
         }
     }
 
-    /// <p>Associates a directory with an SNS topic. This establishes the directory as a publisher to the specified SNS topic. You can then receive email or text (SMS) messages when the status of your directory changes. You get notified if your directory goes from an Active status to an Impaired or Inoperable status. You also receive a notification when the directory returns to an Active status.</p>
-    async fn register_event_topic(
+    /// Presigns the request `register_event_topic` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_register_event_topic(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: RegisterEventTopicRequest,
-    ) -> Result<RegisterEventTopicResult, RusotoError<RegisterEventTopicError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_register_event_topic_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_register_event_topic_request(
+        &self,
+        input: RegisterEventTopicRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "ds", &self.region, "/");
+        request.set_operation("RegisterEventTopic");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -7183,28 +9003,58 @@ impl DirectoryService for DirectoryServiceClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `reject_shared_directory`, also returning the metadata of the response.
+    pub async fn reject_shared_directory_with_metadata(
+        &self,
+        input: RejectSharedDirectoryRequest,
+    ) -> Result<
+        (RejectSharedDirectoryResult, ResponseMetadata),
+        RusotoError<RejectSharedDirectoryError>,
+    > {
+        let request = self.build_reject_shared_directory_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<RegisterEventTopicResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<RejectSharedDirectoryResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(RegisterEventTopicError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(RejectSharedDirectoryError::from_response(response))
         }
     }
 
-    /// <p>Rejects a directory sharing request that was sent from the directory owner account.</p>
-    async fn reject_shared_directory(
+    /// Presigns the request `reject_shared_directory` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_reject_shared_directory(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: RejectSharedDirectoryRequest,
-    ) -> Result<RejectSharedDirectoryResult, RusotoError<RejectSharedDirectoryError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_reject_shared_directory_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_reject_shared_directory_request(
+        &self,
+        input: RejectSharedDirectoryRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "ds", &self.region, "/");
+        request.set_operation("RejectSharedDirectory");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -7214,55 +9064,110 @@ impl DirectoryService for DirectoryServiceClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `remove_ip_routes`, also returning the metadata of the response.
+    pub async fn remove_ip_routes_with_metadata(
+        &self,
+        input: RemoveIpRoutesRequest,
+    ) -> Result<(RemoveIpRoutesResult, ResponseMetadata), RusotoError<RemoveIpRoutesError>> {
+        let request = self.build_remove_ip_routes_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<RejectSharedDirectoryResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<RemoveIpRoutesResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(RejectSharedDirectoryError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(RemoveIpRoutesError::from_response(response))
         }
     }
 
-    /// <p>Removes IP address blocks from a directory.</p>
-    async fn remove_ip_routes(
+    /// Presigns the request `remove_ip_routes` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_remove_ip_routes(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: RemoveIpRoutesRequest,
-    ) -> Result<RemoveIpRoutesResult, RusotoError<RemoveIpRoutesError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_remove_ip_routes_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_remove_ip_routes_request(&self, input: RemoveIpRoutesRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "ds", &self.region, "/");
+        request.set_operation("RemoveIpRoutes");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "DirectoryService_20150416.RemoveIpRoutes");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `remove_tags_from_resource`, also returning the metadata of the response.
+    pub async fn remove_tags_from_resource_with_metadata(
+        &self,
+        input: RemoveTagsFromResourceRequest,
+    ) -> Result<
+        (RemoveTagsFromResourceResult, ResponseMetadata),
+        RusotoError<RemoveTagsFromResourceError>,
+    > {
+        let request = self.build_remove_tags_from_resource_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<RemoveIpRoutesResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<RemoveTagsFromResourceResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(RemoveIpRoutesError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(RemoveTagsFromResourceError::from_response(response))
         }
     }
 
-    /// <p>Removes tags from a directory.</p>
-    async fn remove_tags_from_resource(
+    /// Presigns the request `remove_tags_from_resource` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_remove_tags_from_resource(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: RemoveTagsFromResourceRequest,
-    ) -> Result<RemoveTagsFromResourceResult, RusotoError<RemoveTagsFromResourceError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_remove_tags_from_resource_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_remove_tags_from_resource_request(
+        &self,
+        input: RemoveTagsFromResourceRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "ds", &self.region, "/");
+        request.set_operation("RemoveTagsFromResource");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -7272,28 +9177,53 @@ impl DirectoryService for DirectoryServiceClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `reset_user_password`, also returning the metadata of the response.
+    pub async fn reset_user_password_with_metadata(
+        &self,
+        input: ResetUserPasswordRequest,
+    ) -> Result<(ResetUserPasswordResult, ResponseMetadata), RusotoError<ResetUserPasswordError>>
+    {
+        let request = self.build_reset_user_password_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<RemoveTagsFromResourceResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ResetUserPasswordResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(RemoveTagsFromResourceError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ResetUserPasswordError::from_response(response))
         }
     }
 
-    /// <p><p>Resets the password for any user in your AWS Managed Microsoft AD or Simple AD directory.</p> <p>You can reset the password for any user in your directory with the following exceptions:</p> <ul> <li> <p>For Simple AD, you cannot reset the password for any user that is a member of either the <b>Domain Admins</b> or <b>Enterprise Admins</b> group except for the administrator user.</p> </li> <li> <p>For AWS Managed Microsoft AD, you can only reset the password for a user that is in an OU based off of the NetBIOS name that you typed when you created your directory. For example, you cannot reset the password for a user in the <b>AWS Reserved</b> OU. For more information about the OU structure for an AWS Managed Microsoft AD directory, see <a href="https://docs.aws.amazon.com/directoryservice/latest/admin-guide/ms_ad_getting_started_what_gets_created.html">What Gets Created</a> in the <i>AWS Directory Service Administration Guide</i>.</p> </li> </ul></p>
-    async fn reset_user_password(
+    /// Presigns the request `reset_user_password` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_reset_user_password(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ResetUserPasswordRequest,
-    ) -> Result<ResetUserPasswordResult, RusotoError<ResetUserPasswordError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_reset_user_password_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_reset_user_password_request(&self, input: ResetUserPasswordRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "ds", &self.region, "/");
+        request.set_operation("ResetUserPassword");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -7303,27 +9233,56 @@ impl DirectoryService for DirectoryServiceClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `restore_from_snapshot`, also returning the metadata of the response.
+    pub async fn restore_from_snapshot_with_metadata(
+        &self,
+        input: RestoreFromSnapshotRequest,
+    ) -> Result<(RestoreFromSnapshotResult, ResponseMetadata), RusotoError<RestoreFromSnapshotError>>
+    {
+        let request = self.build_restore_from_snapshot_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<ResetUserPasswordResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<RestoreFromSnapshotResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ResetUserPasswordError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(RestoreFromSnapshotError::from_response(response))
         }
     }
 
-    /// <p>Restores a directory using an existing directory snapshot.</p> <p>When you restore a directory from a snapshot, any changes made to the directory after the snapshot date are overwritten.</p> <p>This action returns as soon as the restore operation is initiated. You can monitor the progress of the restore operation by calling the <a>DescribeDirectories</a> operation with the directory identifier. When the <b>DirectoryDescription.Stage</b> value changes to <code>Active</code>, the restore operation is complete.</p>
-    async fn restore_from_snapshot(
+    /// Presigns the request `restore_from_snapshot` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_restore_from_snapshot(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: RestoreFromSnapshotRequest,
-    ) -> Result<RestoreFromSnapshotResult, RusotoError<RestoreFromSnapshotError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_restore_from_snapshot_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_restore_from_snapshot_request(
+        &self,
+        input: RestoreFromSnapshotRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "ds", &self.region, "/");
+        request.set_operation("RestoreFromSnapshot");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -7333,55 +9292,110 @@ impl DirectoryService for DirectoryServiceClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `share_directory`, also returning the metadata of the response.
+    pub async fn share_directory_with_metadata(
+        &self,
+        input: ShareDirectoryRequest,
+    ) -> Result<(ShareDirectoryResult, ResponseMetadata), RusotoError<ShareDirectoryError>> {
+        let request = self.build_share_directory_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<RestoreFromSnapshotResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ShareDirectoryResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(RestoreFromSnapshotError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ShareDirectoryError::from_response(response))
         }
     }
 
-    /// <p>Shares a specified directory (<code>DirectoryId</code>) in your AWS account (directory owner) with another AWS account (directory consumer). With this operation you can use your directory from any AWS account and from any Amazon VPC within an AWS Region.</p> <p>When you share your AWS Managed Microsoft AD directory, AWS Directory Service creates a shared directory in the directory consumer account. This shared directory contains the metadata to provide access to the directory within the directory owner account. The shared directory is visible in all VPCs in the directory consumer account.</p> <p>The <code>ShareMethod</code> parameter determines whether the specified directory can be shared between AWS accounts inside the same AWS organization (<code>ORGANIZATIONS</code>). It also determines whether you can share the directory with any other AWS account either inside or outside of the organization (<code>HANDSHAKE</code>).</p> <p>The <code>ShareNotes</code> parameter is only used when <code>HANDSHAKE</code> is called, which sends a directory sharing request to the directory consumer. </p>
-    async fn share_directory(
+    /// Presigns the request `share_directory` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_share_directory(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ShareDirectoryRequest,
-    ) -> Result<ShareDirectoryResult, RusotoError<ShareDirectoryError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_share_directory_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_share_directory_request(&self, input: ShareDirectoryRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "ds", &self.region, "/");
+        request.set_operation("ShareDirectory");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "DirectoryService_20150416.ShareDirectory");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `start_schema_extension`, also returning the metadata of the response.
+    pub async fn start_schema_extension_with_metadata(
+        &self,
+        input: StartSchemaExtensionRequest,
+    ) -> Result<
+        (StartSchemaExtensionResult, ResponseMetadata),
+        RusotoError<StartSchemaExtensionError>,
+    > {
+        let request = self.build_start_schema_extension_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<ShareDirectoryResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<StartSchemaExtensionResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ShareDirectoryError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(StartSchemaExtensionError::from_response(response))
         }
     }
 
-    /// <p>Applies a schema extension to a Microsoft AD directory.</p>
-    async fn start_schema_extension(
+    /// Presigns the request `start_schema_extension` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_start_schema_extension(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: StartSchemaExtensionRequest,
-    ) -> Result<StartSchemaExtensionResult, RusotoError<StartSchemaExtensionError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_start_schema_extension_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_start_schema_extension_request(
+        &self,
+        input: StartSchemaExtensionRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "ds", &self.region, "/");
+        request.set_operation("StartSchemaExtension");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -7391,56 +9405,111 @@ impl DirectoryService for DirectoryServiceClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `unshare_directory`, also returning the metadata of the response.
+    pub async fn unshare_directory_with_metadata(
+        &self,
+        input: UnshareDirectoryRequest,
+    ) -> Result<(UnshareDirectoryResult, ResponseMetadata), RusotoError<UnshareDirectoryError>>
+    {
+        let request = self.build_unshare_directory_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<StartSchemaExtensionResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<UnshareDirectoryResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(StartSchemaExtensionError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(UnshareDirectoryError::from_response(response))
         }
     }
 
-    /// <p>Stops the directory sharing between the directory owner and consumer accounts. </p>
-    async fn unshare_directory(
+    /// Presigns the request `unshare_directory` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_unshare_directory(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UnshareDirectoryRequest,
-    ) -> Result<UnshareDirectoryResult, RusotoError<UnshareDirectoryError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_unshare_directory_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_unshare_directory_request(&self, input: UnshareDirectoryRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "ds", &self.region, "/");
+        request.set_operation("UnshareDirectory");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "DirectoryService_20150416.UnshareDirectory");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `update_conditional_forwarder`, also returning the metadata of the response.
+    pub async fn update_conditional_forwarder_with_metadata(
+        &self,
+        input: UpdateConditionalForwarderRequest,
+    ) -> Result<
+        (UpdateConditionalForwarderResult, ResponseMetadata),
+        RusotoError<UpdateConditionalForwarderError>,
+    > {
+        let request = self.build_update_conditional_forwarder_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<UnshareDirectoryResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<UpdateConditionalForwarderResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(UnshareDirectoryError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(UpdateConditionalForwarderError::from_response(response))
         }
     }
 
-    /// <p>Updates a conditional forwarder that has been set up for your AWS directory.</p>
-    async fn update_conditional_forwarder(
+    /// Presigns the request `update_conditional_forwarder` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_conditional_forwarder(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UpdateConditionalForwarderRequest,
-    ) -> Result<UpdateConditionalForwarderResult, RusotoError<UpdateConditionalForwarderError>>
-    {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_conditional_forwarder_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_conditional_forwarder_request(
+        &self,
+        input: UpdateConditionalForwarderRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "ds", &self.region, "/");
+        request.set_operation("UpdateConditionalForwarder");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -7450,31 +9519,60 @@ impl DirectoryService for DirectoryServiceClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `update_number_of_domain_controllers`, also returning the metadata of the response.
+    pub async fn update_number_of_domain_controllers_with_metadata(
+        &self,
+        input: UpdateNumberOfDomainControllersRequest,
+    ) -> Result<
+        (UpdateNumberOfDomainControllersResult, ResponseMetadata),
+        RusotoError<UpdateNumberOfDomainControllersError>,
+    > {
+        let request = self.build_update_number_of_domain_controllers_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<UpdateConditionalForwarderResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<UpdateNumberOfDomainControllersResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(UpdateConditionalForwarderError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(UpdateNumberOfDomainControllersError::from_response(
+                response,
+            ))
         }
     }
 
-    /// <p>Adds or removes domain controllers to or from the directory. Based on the difference between current value and new value (provided through this API call), domain controllers will be added or removed. It may take up to 45 minutes for any new domain controllers to become fully active once the requested number of domain controllers is updated. During this time, you cannot make another update request.</p>
-    async fn update_number_of_domain_controllers(
+    /// Presigns the request `update_number_of_domain_controllers` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_number_of_domain_controllers(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UpdateNumberOfDomainControllersRequest,
-    ) -> Result<
-        UpdateNumberOfDomainControllersResult,
-        RusotoError<UpdateNumberOfDomainControllersError>,
-    > {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_number_of_domain_controllers_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_number_of_domain_controllers_request(
+        &self,
+        input: UpdateNumberOfDomainControllersRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "ds", &self.region, "/");
+        request.set_operation("UpdateNumberOfDomainControllers");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -7484,102 +9582,1165 @@ impl DirectoryService for DirectoryServiceClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `update_radius`, also returning the metadata of the response.
+    pub async fn update_radius_with_metadata(
+        &self,
+        input: UpdateRadiusRequest,
+    ) -> Result<(UpdateRadiusResult, ResponseMetadata), RusotoError<UpdateRadiusError>> {
+        let request = self.build_update_radius_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<UpdateNumberOfDomainControllersResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<UpdateRadiusResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(UpdateNumberOfDomainControllersError::from_response(
-                response,
-            ))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(UpdateRadiusError::from_response(response))
         }
     }
 
-    /// <p>Updates the Remote Authentication Dial In User Service (RADIUS) server information for an AD Connector or Microsoft AD directory.</p>
-    async fn update_radius(
+    /// Presigns the request `update_radius` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_radius(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UpdateRadiusRequest,
-    ) -> Result<UpdateRadiusResult, RusotoError<UpdateRadiusError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_radius_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_radius_request(&self, input: UpdateRadiusRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "ds", &self.region, "/");
+        request.set_operation("UpdateRadius");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "DirectoryService_20150416.UpdateRadius");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `update_trust`, also returning the metadata of the response.
+    pub async fn update_trust_with_metadata(
+        &self,
+        input: UpdateTrustRequest,
+    ) -> Result<(UpdateTrustResult, ResponseMetadata), RusotoError<UpdateTrustError>> {
+        let request = self.build_update_trust_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<UpdateRadiusResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<UpdateTrustResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(UpdateRadiusError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(UpdateTrustError::from_response(response))
         }
     }
 
-    /// <p>Updates the trust that has been set up between your AWS Managed Microsoft AD directory and an on-premises Active Directory.</p>
-    async fn update_trust(
+    /// Presigns the request `update_trust` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_trust(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UpdateTrustRequest,
-    ) -> Result<UpdateTrustResult, RusotoError<UpdateTrustError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_trust_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_trust_request(&self, input: UpdateTrustRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "ds", &self.region, "/");
+        request.set_operation("UpdateTrust");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "DirectoryService_20150416.UpdateTrust");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `verify_trust`, also returning the metadata of the response.
+    pub async fn verify_trust_with_metadata(
+        &self,
+        input: VerifyTrustRequest,
+    ) -> Result<(VerifyTrustResult, ResponseMetadata), RusotoError<VerifyTrustError>> {
+        let request = self.build_verify_trust_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<UpdateTrustResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<VerifyTrustResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(UpdateTrustError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(VerifyTrustError::from_response(response))
         }
     }
 
-    /// <p>AWS Directory Service for Microsoft Active Directory allows you to configure and verify trust relationships.</p> <p>This action verifies a trust relationship between your AWS Managed Microsoft AD directory and an external domain.</p>
-    async fn verify_trust(
+    /// Presigns the request `verify_trust` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_verify_trust(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: VerifyTrustRequest,
-    ) -> Result<VerifyTrustResult, RusotoError<VerifyTrustError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_verify_trust_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_verify_trust_request(&self, input: VerifyTrustRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "ds", &self.region, "/");
+        request.set_operation("VerifyTrust");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "DirectoryService_20150416.VerifyTrust");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
-        let mut response = self
-            .client
-            .sign_and_dispatch(request)
-            .await
-            .map_err(RusotoError::from)?;
-        if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<VerifyTrustResult, _>()
-        } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(VerifyTrustError::from_response(response))
-        }
+        request
+    }
+
+    /// The pages of `DescribeDirectories` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `Limit` of `input`.
+    pub fn describe_directories_pages(
+        &self,
+        input: DescribeDirectoriesRequest,
+    ) -> impl futures::Stream<
+        Item = Result<DescribeDirectoriesResult, RusotoError<DescribeDirectoriesError>>,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: DirectoryServiceClient, input| async move {
+                client.describe_directories(input).await
+            },
+            |input: &DescribeDirectoriesRequest, output: &DescribeDirectoriesResult| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(DescribeDirectoriesRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `DirectoryDescriptions` of every page of `DescribeDirectories` results for `input`, as `describe_directories_pages` requests them.
+    pub fn describe_directories_stream(
+        &self,
+        input: DescribeDirectoriesRequest,
+    ) -> impl futures::Stream<
+        Item = Result<DirectoryDescription, RusotoError<DescribeDirectoriesError>>,
+    > + Send {
+        rusoto_core::pagination::items(self.describe_directories_pages(input), |page| {
+            page.directory_descriptions.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `DescribeDomainControllers` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `Limit` of `input`.
+    pub fn describe_domain_controllers_pages(
+        &self,
+        input: DescribeDomainControllersRequest,
+    ) -> impl futures::Stream<
+        Item = Result<DescribeDomainControllersResult, RusotoError<DescribeDomainControllersError>>,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: DirectoryServiceClient, input| async move {
+                client.describe_domain_controllers(input).await
+            },
+            |input: &DescribeDomainControllersRequest, output: &DescribeDomainControllersResult| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(DescribeDomainControllersRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `DomainControllers` of every page of `DescribeDomainControllers` results for `input`, as `describe_domain_controllers_pages` requests them.
+    pub fn describe_domain_controllers_stream(
+        &self,
+        input: DescribeDomainControllersRequest,
+    ) -> impl futures::Stream<
+        Item = Result<DomainController, RusotoError<DescribeDomainControllersError>>,
+    > + Send {
+        rusoto_core::pagination::items(self.describe_domain_controllers_pages(input), |page| {
+            page.domain_controllers.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `DescribeLDAPSSettings` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `Limit` of `input`.
+    pub fn describe_ldaps_settings_pages(
+        &self,
+        input: DescribeLDAPSSettingsRequest,
+    ) -> impl futures::Stream<
+        Item = Result<DescribeLDAPSSettingsResult, RusotoError<DescribeLDAPSSettingsError>>,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: DirectoryServiceClient, input| async move {
+                client.describe_ldaps_settings(input).await
+            },
+            |input: &DescribeLDAPSSettingsRequest, output: &DescribeLDAPSSettingsResult| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(DescribeLDAPSSettingsRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `LDAPSSettingsInfo` of every page of `DescribeLDAPSSettings` results for `input`, as `describe_ldaps_settings_pages` requests them.
+    pub fn describe_ldaps_settings_stream(
+        &self,
+        input: DescribeLDAPSSettingsRequest,
+    ) -> impl futures::Stream<Item = Result<LDAPSSettingInfo, RusotoError<DescribeLDAPSSettingsError>>>
+           + Send {
+        rusoto_core::pagination::items(self.describe_ldaps_settings_pages(input), |page| {
+            page.ldaps_settings_info.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `DescribeSharedDirectories` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `Limit` of `input`.
+    pub fn describe_shared_directories_pages(
+        &self,
+        input: DescribeSharedDirectoriesRequest,
+    ) -> impl futures::Stream<
+        Item = Result<DescribeSharedDirectoriesResult, RusotoError<DescribeSharedDirectoriesError>>,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: DirectoryServiceClient, input| async move {
+                client.describe_shared_directories(input).await
+            },
+            |input: &DescribeSharedDirectoriesRequest, output: &DescribeSharedDirectoriesResult| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(DescribeSharedDirectoriesRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `SharedDirectories` of every page of `DescribeSharedDirectories` results for `input`, as `describe_shared_directories_pages` requests them.
+    pub fn describe_shared_directories_stream(
+        &self,
+        input: DescribeSharedDirectoriesRequest,
+    ) -> impl futures::Stream<
+        Item = Result<SharedDirectory, RusotoError<DescribeSharedDirectoriesError>>,
+    > + Send {
+        rusoto_core::pagination::items(self.describe_shared_directories_pages(input), |page| {
+            page.shared_directories.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `DescribeSnapshots` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `Limit` of `input`.
+    pub fn describe_snapshots_pages(
+        &self,
+        input: DescribeSnapshotsRequest,
+    ) -> impl futures::Stream<
+        Item = Result<DescribeSnapshotsResult, RusotoError<DescribeSnapshotsError>>,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: DirectoryServiceClient, input| async move {
+                client.describe_snapshots(input).await
+            },
+            |input: &DescribeSnapshotsRequest, output: &DescribeSnapshotsResult| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(DescribeSnapshotsRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `Snapshots` of every page of `DescribeSnapshots` results for `input`, as `describe_snapshots_pages` requests them.
+    pub fn describe_snapshots_stream(
+        &self,
+        input: DescribeSnapshotsRequest,
+    ) -> impl futures::Stream<Item = Result<Snapshot, RusotoError<DescribeSnapshotsError>>> + Send
+    {
+        rusoto_core::pagination::items(self.describe_snapshots_pages(input), |page| {
+            page.snapshots.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `DescribeTrusts` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `Limit` of `input`.
+    pub fn describe_trusts_pages(
+        &self,
+        input: DescribeTrustsRequest,
+    ) -> impl futures::Stream<Item = Result<DescribeTrustsResult, RusotoError<DescribeTrustsError>>> + Send
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: DirectoryServiceClient, input| async move { client.describe_trusts(input).await },
+            |input: &DescribeTrustsRequest, output: &DescribeTrustsResult| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(DescribeTrustsRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `Trusts` of every page of `DescribeTrusts` results for `input`, as `describe_trusts_pages` requests them.
+    pub fn describe_trusts_stream(
+        &self,
+        input: DescribeTrustsRequest,
+    ) -> impl futures::Stream<Item = Result<Trust, RusotoError<DescribeTrustsError>>> + Send {
+        rusoto_core::pagination::items(self.describe_trusts_pages(input), |page| {
+            page.trusts.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `ListCertificates` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `Limit` of `input`.
+    pub fn list_certificates_pages(
+        &self,
+        input: ListCertificatesRequest,
+    ) -> impl futures::Stream<
+        Item = Result<ListCertificatesResult, RusotoError<ListCertificatesError>>,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: DirectoryServiceClient, input| async move { client.list_certificates(input).await },
+            |input: &ListCertificatesRequest, output: &ListCertificatesResult| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListCertificatesRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `CertificatesInfo` of every page of `ListCertificates` results for `input`, as `list_certificates_pages` requests them.
+    pub fn list_certificates_stream(
+        &self,
+        input: ListCertificatesRequest,
+    ) -> impl futures::Stream<Item = Result<CertificateInfo, RusotoError<ListCertificatesError>>> + Send
+    {
+        rusoto_core::pagination::items(self.list_certificates_pages(input), |page| {
+            page.certificates_info.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `ListIpRoutes` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `Limit` of `input`.
+    pub fn list_ip_routes_pages(
+        &self,
+        input: ListIpRoutesRequest,
+    ) -> impl futures::Stream<Item = Result<ListIpRoutesResult, RusotoError<ListIpRoutesError>>> + Send
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: DirectoryServiceClient, input| async move { client.list_ip_routes(input).await },
+            |input: &ListIpRoutesRequest, output: &ListIpRoutesResult| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListIpRoutesRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `IpRoutesInfo` of every page of `ListIpRoutes` results for `input`, as `list_ip_routes_pages` requests them.
+    pub fn list_ip_routes_stream(
+        &self,
+        input: ListIpRoutesRequest,
+    ) -> impl futures::Stream<Item = Result<IpRouteInfo, RusotoError<ListIpRoutesError>>> + Send
+    {
+        rusoto_core::pagination::items(self.list_ip_routes_pages(input), |page| {
+            page.ip_routes_info.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `ListLogSubscriptions` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `Limit` of `input`.
+    pub fn list_log_subscriptions_pages(
+        &self,
+        input: ListLogSubscriptionsRequest,
+    ) -> impl futures::Stream<
+        Item = Result<ListLogSubscriptionsResult, RusotoError<ListLogSubscriptionsError>>,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: DirectoryServiceClient, input| async move {
+                client.list_log_subscriptions(input).await
+            },
+            |input: &ListLogSubscriptionsRequest, output: &ListLogSubscriptionsResult| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListLogSubscriptionsRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `LogSubscriptions` of every page of `ListLogSubscriptions` results for `input`, as `list_log_subscriptions_pages` requests them.
+    pub fn list_log_subscriptions_stream(
+        &self,
+        input: ListLogSubscriptionsRequest,
+    ) -> impl futures::Stream<Item = Result<LogSubscription, RusotoError<ListLogSubscriptionsError>>>
+           + Send {
+        rusoto_core::pagination::items(self.list_log_subscriptions_pages(input), |page| {
+            page.log_subscriptions.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `ListSchemaExtensions` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `Limit` of `input`.
+    pub fn list_schema_extensions_pages(
+        &self,
+        input: ListSchemaExtensionsRequest,
+    ) -> impl futures::Stream<
+        Item = Result<ListSchemaExtensionsResult, RusotoError<ListSchemaExtensionsError>>,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: DirectoryServiceClient, input| async move {
+                client.list_schema_extensions(input).await
+            },
+            |input: &ListSchemaExtensionsRequest, output: &ListSchemaExtensionsResult| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListSchemaExtensionsRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `SchemaExtensionsInfo` of every page of `ListSchemaExtensions` results for `input`, as `list_schema_extensions_pages` requests them.
+    pub fn list_schema_extensions_stream(
+        &self,
+        input: ListSchemaExtensionsRequest,
+    ) -> impl futures::Stream<
+        Item = Result<SchemaExtensionInfo, RusotoError<ListSchemaExtensionsError>>,
+    > + Send {
+        rusoto_core::pagination::items(self.list_schema_extensions_pages(input), |page| {
+            page.schema_extensions_info.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `ListTagsForResource` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `Limit` of `input`.
+    pub fn list_tags_for_resource_pages(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> impl futures::Stream<
+        Item = Result<ListTagsForResourceResult, RusotoError<ListTagsForResourceError>>,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: DirectoryServiceClient, input| async move {
+                client.list_tags_for_resource(input).await
+            },
+            |input: &ListTagsForResourceRequest, output: &ListTagsForResourceResult| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListTagsForResourceRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `Tags` of every page of `ListTagsForResource` results for `input`, as `list_tags_for_resource_pages` requests them.
+    pub fn list_tags_for_resource_stream(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> impl futures::Stream<Item = Result<Tag, RusotoError<ListTagsForResourceError>>> + Send
+    {
+        rusoto_core::pagination::items(self.list_tags_for_resource_pages(input), |page| {
+            page.tags.unwrap_or_default()
+        })
+    }
+}
+
+#[async_trait]
+impl DirectoryService for DirectoryServiceClient {
+    /// <p>Accepts a directory sharing request that was sent from the directory owner account.</p>
+    async fn accept_shared_directory(
+        &self,
+        input: AcceptSharedDirectoryRequest,
+    ) -> Result<AcceptSharedDirectoryResult, RusotoError<AcceptSharedDirectoryError>> {
+        self.accept_shared_directory_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>If the DNS server for your on-premises domain uses a publicly addressable IP address, you must add a CIDR address block to correctly route traffic to and from your Microsoft AD on Amazon Web Services. <i>AddIpRoutes</i> adds this address block. You can also use <i>AddIpRoutes</i> to facilitate routing traffic that uses public IP ranges from your Microsoft AD on AWS to a peer VPC. </p> <p>Before you call <i>AddIpRoutes</i>, ensure that all of the required permissions have been explicitly granted through a policy. For details about what permissions are required to run the <i>AddIpRoutes</i> operation, see <a href="http://docs.aws.amazon.com/directoryservice/latest/admin-guide/UsingWithDS_IAM_ResourcePermissions.html">AWS Directory Service API Permissions: Actions, Resources, and Conditions Reference</a>.</p>
+    async fn add_ip_routes(
+        &self,
+        input: AddIpRoutesRequest,
+    ) -> Result<AddIpRoutesResult, RusotoError<AddIpRoutesError>> {
+        self.add_ip_routes_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Adds or overwrites one or more tags for the specified directory. Each directory can have a maximum of 50 tags. Each tag consists of a key and optional value. Tag keys must be unique to each resource.</p>
+    async fn add_tags_to_resource(
+        &self,
+        input: AddTagsToResourceRequest,
+    ) -> Result<AddTagsToResourceResult, RusotoError<AddTagsToResourceError>> {
+        self.add_tags_to_resource_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Cancels an in-progress schema extension to a Microsoft AD directory. Once a schema extension has started replicating to all domain controllers, the task can no longer be canceled. A schema extension can be canceled during any of the following states; <code>Initializing</code>, <code>CreatingSnapshot</code>, and <code>UpdatingSchema</code>.</p>
+    async fn cancel_schema_extension(
+        &self,
+        input: CancelSchemaExtensionRequest,
+    ) -> Result<CancelSchemaExtensionResult, RusotoError<CancelSchemaExtensionError>> {
+        self.cancel_schema_extension_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Creates an AD Connector to connect to an on-premises directory.</p> <p>Before you call <code>ConnectDirectory</code>, ensure that all of the required permissions have been explicitly granted through a policy. For details about what permissions are required to run the <code>ConnectDirectory</code> operation, see <a href="http://docs.aws.amazon.com/directoryservice/latest/admin-guide/UsingWithDS_IAM_ResourcePermissions.html">AWS Directory Service API Permissions: Actions, Resources, and Conditions Reference</a>.</p>
+    async fn connect_directory(
+        &self,
+        input: ConnectDirectoryRequest,
+    ) -> Result<ConnectDirectoryResult, RusotoError<ConnectDirectoryError>> {
+        self.connect_directory_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Creates an alias for a directory and assigns the alias to the directory. The alias is used to construct the access URL for the directory, such as <code>http://&lt;alias&gt;.awsapps.com</code>.</p> <important> <p>After an alias has been created, it cannot be deleted or reused, so this operation should only be used when absolutely necessary.</p> </important></p>
+    async fn create_alias(
+        &self,
+        input: CreateAliasRequest,
+    ) -> Result<CreateAliasResult, RusotoError<CreateAliasError>> {
+        self.create_alias_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Creates a computer account in the specified directory, and joins the computer to the directory.</p>
+    async fn create_computer(
+        &self,
+        input: CreateComputerRequest,
+    ) -> Result<CreateComputerResult, RusotoError<CreateComputerError>> {
+        self.create_computer_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Creates a conditional forwarder associated with your AWS directory. Conditional forwarders are required in order to set up a trust relationship with another domain. The conditional forwarder points to the trusted domain.</p>
+    async fn create_conditional_forwarder(
+        &self,
+        input: CreateConditionalForwarderRequest,
+    ) -> Result<CreateConditionalForwarderResult, RusotoError<CreateConditionalForwarderError>>
+    {
+        self.create_conditional_forwarder_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Creates a Simple AD directory. For more information, see <a href="https://docs.aws.amazon.com/directoryservice/latest/admin-guide/directory_simple_ad.html">Simple Active Directory</a> in the <i>AWS Directory Service Admin Guide</i>.</p> <p>Before you call <code>CreateDirectory</code>, ensure that all of the required permissions have been explicitly granted through a policy. For details about what permissions are required to run the <code>CreateDirectory</code> operation, see <a href="http://docs.aws.amazon.com/directoryservice/latest/admin-guide/UsingWithDS_IAM_ResourcePermissions.html">AWS Directory Service API Permissions: Actions, Resources, and Conditions Reference</a>.</p>
+    async fn create_directory(
+        &self,
+        input: CreateDirectoryRequest,
+    ) -> Result<CreateDirectoryResult, RusotoError<CreateDirectoryError>> {
+        self.create_directory_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Creates a subscription to forward real-time Directory Service domain controller security logs to the specified Amazon CloudWatch log group in your AWS account.</p>
+    async fn create_log_subscription(
+        &self,
+        input: CreateLogSubscriptionRequest,
+    ) -> Result<CreateLogSubscriptionResult, RusotoError<CreateLogSubscriptionError>> {
+        self.create_log_subscription_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Creates a Microsoft AD directory in the AWS Cloud. For more information, see <a href="https://docs.aws.amazon.com/directoryservice/latest/admin-guide/directory_microsoft_ad.html">AWS Managed Microsoft AD</a> in the <i>AWS Directory Service Admin Guide</i>.</p> <p>Before you call <i>CreateMicrosoftAD</i>, ensure that all of the required permissions have been explicitly granted through a policy. For details about what permissions are required to run the <i>CreateMicrosoftAD</i> operation, see <a href="http://docs.aws.amazon.com/directoryservice/latest/admin-guide/UsingWithDS_IAM_ResourcePermissions.html">AWS Directory Service API Permissions: Actions, Resources, and Conditions Reference</a>.</p>
+    async fn create_microsoft_ad(
+        &self,
+        input: CreateMicrosoftADRequest,
+    ) -> Result<CreateMicrosoftADResult, RusotoError<CreateMicrosoftADError>> {
+        self.create_microsoft_ad_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Creates a snapshot of a Simple AD or Microsoft AD directory in the AWS cloud.</p> <note> <p>You cannot take snapshots of AD Connector directories.</p> </note></p>
+    async fn create_snapshot(
+        &self,
+        input: CreateSnapshotRequest,
+    ) -> Result<CreateSnapshotResult, RusotoError<CreateSnapshotError>> {
+        self.create_snapshot_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>AWS Directory Service for Microsoft Active Directory allows you to configure trust relationships. For example, you can establish a trust between your AWS Managed Microsoft AD directory, and your existing on-premises Microsoft Active Directory. This would allow you to provide users and groups access to resources in either domain, with a single set of credentials.</p> <p>This action initiates the creation of the AWS side of a trust relationship between an AWS Managed Microsoft AD directory and an external domain. You can create either a forest trust or an external trust.</p>
+    async fn create_trust(
+        &self,
+        input: CreateTrustRequest,
+    ) -> Result<CreateTrustResult, RusotoError<CreateTrustError>> {
+        self.create_trust_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes a conditional forwarder that has been set up for your AWS directory.</p>
+    async fn delete_conditional_forwarder(
+        &self,
+        input: DeleteConditionalForwarderRequest,
+    ) -> Result<DeleteConditionalForwarderResult, RusotoError<DeleteConditionalForwarderError>>
+    {
+        self.delete_conditional_forwarder_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes an AWS Directory Service directory.</p> <p>Before you call <code>DeleteDirectory</code>, ensure that all of the required permissions have been explicitly granted through a policy. For details about what permissions are required to run the <code>DeleteDirectory</code> operation, see <a href="http://docs.aws.amazon.com/directoryservice/latest/admin-guide/UsingWithDS_IAM_ResourcePermissions.html">AWS Directory Service API Permissions: Actions, Resources, and Conditions Reference</a>.</p>
+    async fn delete_directory(
+        &self,
+        input: DeleteDirectoryRequest,
+    ) -> Result<DeleteDirectoryResult, RusotoError<DeleteDirectoryError>> {
+        self.delete_directory_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes the specified log subscription.</p>
+    async fn delete_log_subscription(
+        &self,
+        input: DeleteLogSubscriptionRequest,
+    ) -> Result<DeleteLogSubscriptionResult, RusotoError<DeleteLogSubscriptionError>> {
+        self.delete_log_subscription_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes a directory snapshot.</p>
+    async fn delete_snapshot(
+        &self,
+        input: DeleteSnapshotRequest,
+    ) -> Result<DeleteSnapshotResult, RusotoError<DeleteSnapshotError>> {
+        self.delete_snapshot_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes an existing trust relationship between your AWS Managed Microsoft AD directory and an external domain.</p>
+    async fn delete_trust(
+        &self,
+        input: DeleteTrustRequest,
+    ) -> Result<DeleteTrustResult, RusotoError<DeleteTrustError>> {
+        self.delete_trust_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes from the system the certificate that was registered for a secured LDAP connection.</p>
+    async fn deregister_certificate(
+        &self,
+        input: DeregisterCertificateRequest,
+    ) -> Result<DeregisterCertificateResult, RusotoError<DeregisterCertificateError>> {
+        self.deregister_certificate_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Removes the specified directory as a publisher to the specified SNS topic.</p>
+    async fn deregister_event_topic(
+        &self,
+        input: DeregisterEventTopicRequest,
+    ) -> Result<DeregisterEventTopicResult, RusotoError<DeregisterEventTopicError>> {
+        self.deregister_event_topic_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Displays information about the certificate registered for a secured LDAP connection.</p>
+    async fn describe_certificate(
+        &self,
+        input: DescribeCertificateRequest,
+    ) -> Result<DescribeCertificateResult, RusotoError<DescribeCertificateError>> {
+        self.describe_certificate_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Obtains information about the conditional forwarders for this account.</p> <p>If no input parameters are provided for RemoteDomainNames, this request describes all conditional forwarders for the specified directory ID.</p>
+    async fn describe_conditional_forwarders(
+        &self,
+        input: DescribeConditionalForwardersRequest,
+    ) -> Result<DescribeConditionalForwardersResult, RusotoError<DescribeConditionalForwardersError>>
+    {
+        self.describe_conditional_forwarders_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Obtains information about the directories that belong to this account.</p> <p>You can retrieve information about specific directories by passing the directory identifiers in the <code>DirectoryIds</code> parameter. Otherwise, all directories that belong to the current account are returned.</p> <p>This operation supports pagination with the use of the <code>NextToken</code> request and response parameters. If more results are available, the <code>DescribeDirectoriesResult.NextToken</code> member contains a token that you pass in the next call to <a>DescribeDirectories</a> to retrieve the next set of items.</p> <p>You can also specify a maximum number of return results with the <code>Limit</code> parameter.</p>
+    async fn describe_directories(
+        &self,
+        input: DescribeDirectoriesRequest,
+    ) -> Result<DescribeDirectoriesResult, RusotoError<DescribeDirectoriesError>> {
+        self.describe_directories_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Provides information about any domain controllers in your directory.</p>
+    async fn describe_domain_controllers(
+        &self,
+        input: DescribeDomainControllersRequest,
+    ) -> Result<DescribeDomainControllersResult, RusotoError<DescribeDomainControllersError>> {
+        self.describe_domain_controllers_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Obtains information about which SNS topics receive status messages from the specified directory.</p> <p>If no input parameters are provided, such as DirectoryId or TopicName, this request describes all of the associations in the account.</p>
+    async fn describe_event_topics(
+        &self,
+        input: DescribeEventTopicsRequest,
+    ) -> Result<DescribeEventTopicsResult, RusotoError<DescribeEventTopicsError>> {
+        self.describe_event_topics_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Describes the status of LDAP security for the specified directory.</p>
+    async fn describe_ldaps_settings(
+        &self,
+        input: DescribeLDAPSSettingsRequest,
+    ) -> Result<DescribeLDAPSSettingsResult, RusotoError<DescribeLDAPSSettingsError>> {
+        self.describe_ldaps_settings_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns the shared directories in your account. </p>
+    async fn describe_shared_directories(
+        &self,
+        input: DescribeSharedDirectoriesRequest,
+    ) -> Result<DescribeSharedDirectoriesResult, RusotoError<DescribeSharedDirectoriesError>> {
+        self.describe_shared_directories_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Obtains information about the directory snapshots that belong to this account.</p> <p>This operation supports pagination with the use of the <i>NextToken</i> request and response parameters. If more results are available, the <i>DescribeSnapshots.NextToken</i> member contains a token that you pass in the next call to <a>DescribeSnapshots</a> to retrieve the next set of items.</p> <p>You can also specify a maximum number of return results with the <i>Limit</i> parameter.</p>
+    async fn describe_snapshots(
+        &self,
+        input: DescribeSnapshotsRequest,
+    ) -> Result<DescribeSnapshotsResult, RusotoError<DescribeSnapshotsError>> {
+        self.describe_snapshots_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Obtains information about the trust relationships for this account.</p> <p>If no input parameters are provided, such as DirectoryId or TrustIds, this request describes all the trust relationships belonging to the account.</p>
+    async fn describe_trusts(
+        &self,
+        input: DescribeTrustsRequest,
+    ) -> Result<DescribeTrustsResult, RusotoError<DescribeTrustsError>> {
+        self.describe_trusts_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deactivates LDAP secure calls for the specified directory.</p>
+    async fn disable_ldaps(
+        &self,
+        input: DisableLDAPSRequest,
+    ) -> Result<DisableLDAPSResult, RusotoError<DisableLDAPSError>> {
+        self.disable_ldaps_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Disables multi-factor authentication (MFA) with the Remote Authentication Dial In User Service (RADIUS) server for an AD Connector or Microsoft AD directory.</p>
+    async fn disable_radius(
+        &self,
+        input: DisableRadiusRequest,
+    ) -> Result<DisableRadiusResult, RusotoError<DisableRadiusError>> {
+        self.disable_radius_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Disables single-sign on for a directory.</p>
+    async fn disable_sso(
+        &self,
+        input: DisableSsoRequest,
+    ) -> Result<DisableSsoResult, RusotoError<DisableSsoError>> {
+        self.disable_sso_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Activates the switch for the specific directory to always use LDAP secure calls.</p>
+    async fn enable_ldaps(
+        &self,
+        input: EnableLDAPSRequest,
+    ) -> Result<EnableLDAPSResult, RusotoError<EnableLDAPSError>> {
+        self.enable_ldaps_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Enables multi-factor authentication (MFA) with the Remote Authentication Dial In User Service (RADIUS) server for an AD Connector or Microsoft AD directory.</p>
+    async fn enable_radius(
+        &self,
+        input: EnableRadiusRequest,
+    ) -> Result<EnableRadiusResult, RusotoError<EnableRadiusError>> {
+        self.enable_radius_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Enables single sign-on for a directory. Single sign-on allows users in your directory to access certain AWS services from a computer joined to the directory without having to enter their credentials separately.</p>
+    async fn enable_sso(
+        &self,
+        input: EnableSsoRequest,
+    ) -> Result<EnableSsoResult, RusotoError<EnableSsoError>> {
+        self.enable_sso_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Obtains directory limit information for the current Region.</p>
+    async fn get_directory_limits(
+        &self,
+    ) -> Result<GetDirectoryLimitsResult, RusotoError<GetDirectoryLimitsError>> {
+        self.get_directory_limits_with_metadata()
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Obtains the manual snapshot limits for a directory.</p>
+    async fn get_snapshot_limits(
+        &self,
+        input: GetSnapshotLimitsRequest,
+    ) -> Result<GetSnapshotLimitsResult, RusotoError<GetSnapshotLimitsError>> {
+        self.get_snapshot_limits_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>For the specified directory, lists all the certificates registered for a secured LDAP connection.</p>
+    async fn list_certificates(
+        &self,
+        input: ListCertificatesRequest,
+    ) -> Result<ListCertificatesResult, RusotoError<ListCertificatesError>> {
+        self.list_certificates_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Lists the address blocks that you have added to a directory.</p>
+    async fn list_ip_routes(
+        &self,
+        input: ListIpRoutesRequest,
+    ) -> Result<ListIpRoutesResult, RusotoError<ListIpRoutesError>> {
+        self.list_ip_routes_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Lists the active log subscriptions for the AWS account.</p>
+    async fn list_log_subscriptions(
+        &self,
+        input: ListLogSubscriptionsRequest,
+    ) -> Result<ListLogSubscriptionsResult, RusotoError<ListLogSubscriptionsError>> {
+        self.list_log_subscriptions_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Lists all schema extensions applied to a Microsoft AD Directory.</p>
+    async fn list_schema_extensions(
+        &self,
+        input: ListSchemaExtensionsRequest,
+    ) -> Result<ListSchemaExtensionsResult, RusotoError<ListSchemaExtensionsError>> {
+        self.list_schema_extensions_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Lists all tags on a directory.</p>
+    async fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> Result<ListTagsForResourceResult, RusotoError<ListTagsForResourceError>> {
+        self.list_tags_for_resource_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Registers a certificate for secured LDAP connection.</p>
+    async fn register_certificate(
+        &self,
+        input: RegisterCertificateRequest,
+    ) -> Result<RegisterCertificateResult, RusotoError<RegisterCertificateError>> {
+        self.register_certificate_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Associates a directory with an SNS topic. This establishes the directory as a publisher to the specified SNS topic. You can then receive email or text (SMS) messages when the status of your directory changes. You get notified if your directory goes from an Active status to an Impaired or Inoperable status. You also receive a notification when the directory returns to an Active status.</p>
+    async fn register_event_topic(
+        &self,
+        input: RegisterEventTopicRequest,
+    ) -> Result<RegisterEventTopicResult, RusotoError<RegisterEventTopicError>> {
+        self.register_event_topic_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Rejects a directory sharing request that was sent from the directory owner account.</p>
+    async fn reject_shared_directory(
+        &self,
+        input: RejectSharedDirectoryRequest,
+    ) -> Result<RejectSharedDirectoryResult, RusotoError<RejectSharedDirectoryError>> {
+        self.reject_shared_directory_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Removes IP address blocks from a directory.</p>
+    async fn remove_ip_routes(
+        &self,
+        input: RemoveIpRoutesRequest,
+    ) -> Result<RemoveIpRoutesResult, RusotoError<RemoveIpRoutesError>> {
+        self.remove_ip_routes_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Removes tags from a directory.</p>
+    async fn remove_tags_from_resource(
+        &self,
+        input: RemoveTagsFromResourceRequest,
+    ) -> Result<RemoveTagsFromResourceResult, RusotoError<RemoveTagsFromResourceError>> {
+        self.remove_tags_from_resource_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Resets the password for any user in your AWS Managed Microsoft AD or Simple AD directory.</p> <p>You can reset the password for any user in your directory with the following exceptions:</p> <ul> <li> <p>For Simple AD, you cannot reset the password for any user that is a member of either the <b>Domain Admins</b> or <b>Enterprise Admins</b> group except for the administrator user.</p> </li> <li> <p>For AWS Managed Microsoft AD, you can only reset the password for a user that is in an OU based off of the NetBIOS name that you typed when you created your directory. For example, you cannot reset the password for a user in the <b>AWS Reserved</b> OU. For more information about the OU structure for an AWS Managed Microsoft AD directory, see <a href="https://docs.aws.amazon.com/directoryservice/latest/admin-guide/ms_ad_getting_started_what_gets_created.html">What Gets Created</a> in the <i>AWS Directory Service Administration Guide</i>.</p> </li> </ul></p>
+    async fn reset_user_password(
+        &self,
+        input: ResetUserPasswordRequest,
+    ) -> Result<ResetUserPasswordResult, RusotoError<ResetUserPasswordError>> {
+        self.reset_user_password_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Restores a directory using an existing directory snapshot.</p> <p>When you restore a directory from a snapshot, any changes made to the directory after the snapshot date are overwritten.</p> <p>This action returns as soon as the restore operation is initiated. You can monitor the progress of the restore operation by calling the <a>DescribeDirectories</a> operation with the directory identifier. When the <b>DirectoryDescription.Stage</b> value changes to <code>Active</code>, the restore operation is complete.</p>
+    async fn restore_from_snapshot(
+        &self,
+        input: RestoreFromSnapshotRequest,
+    ) -> Result<RestoreFromSnapshotResult, RusotoError<RestoreFromSnapshotError>> {
+        self.restore_from_snapshot_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Shares a specified directory (<code>DirectoryId</code>) in your AWS account (directory owner) with another AWS account (directory consumer). With this operation you can use your directory from any AWS account and from any Amazon VPC within an AWS Region.</p> <p>When you share your AWS Managed Microsoft AD directory, AWS Directory Service creates a shared directory in the directory consumer account. This shared directory contains the metadata to provide access to the directory within the directory owner account. The shared directory is visible in all VPCs in the directory consumer account.</p> <p>The <code>ShareMethod</code> parameter determines whether the specified directory can be shared between AWS accounts inside the same AWS organization (<code>ORGANIZATIONS</code>). It also determines whether you can share the directory with any other AWS account either inside or outside of the organization (<code>HANDSHAKE</code>).</p> <p>The <code>ShareNotes</code> parameter is only used when <code>HANDSHAKE</code> is called, which sends a directory sharing request to the directory consumer. </p>
+    async fn share_directory(
+        &self,
+        input: ShareDirectoryRequest,
+    ) -> Result<ShareDirectoryResult, RusotoError<ShareDirectoryError>> {
+        self.share_directory_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Applies a schema extension to a Microsoft AD directory.</p>
+    async fn start_schema_extension(
+        &self,
+        input: StartSchemaExtensionRequest,
+    ) -> Result<StartSchemaExtensionResult, RusotoError<StartSchemaExtensionError>> {
+        self.start_schema_extension_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Stops the directory sharing between the directory owner and consumer accounts. </p>
+    async fn unshare_directory(
+        &self,
+        input: UnshareDirectoryRequest,
+    ) -> Result<UnshareDirectoryResult, RusotoError<UnshareDirectoryError>> {
+        self.unshare_directory_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Updates a conditional forwarder that has been set up for your AWS directory.</p>
+    async fn update_conditional_forwarder(
+        &self,
+        input: UpdateConditionalForwarderRequest,
+    ) -> Result<UpdateConditionalForwarderResult, RusotoError<UpdateConditionalForwarderError>>
+    {
+        self.update_conditional_forwarder_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Adds or removes domain controllers to or from the directory. Based on the difference between current value and new value (provided through this API call), domain controllers will be added or removed. It may take up to 45 minutes for any new domain controllers to become fully active once the requested number of domain controllers is updated. During this time, you cannot make another update request.</p>
+    async fn update_number_of_domain_controllers(
+        &self,
+        input: UpdateNumberOfDomainControllersRequest,
+    ) -> Result<
+        UpdateNumberOfDomainControllersResult,
+        RusotoError<UpdateNumberOfDomainControllersError>,
+    > {
+        self.update_number_of_domain_controllers_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Updates the Remote Authentication Dial In User Service (RADIUS) server information for an AD Connector or Microsoft AD directory.</p>
+    async fn update_radius(
+        &self,
+        input: UpdateRadiusRequest,
+    ) -> Result<UpdateRadiusResult, RusotoError<UpdateRadiusError>> {
+        self.update_radius_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Updates the trust that has been set up between your AWS Managed Microsoft AD directory and an on-premises Active Directory.</p>
+    async fn update_trust(
+        &self,
+        input: UpdateTrustRequest,
+    ) -> Result<UpdateTrustResult, RusotoError<UpdateTrustError>> {
+        self.update_trust_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>AWS Directory Service for Microsoft Active Directory allows you to configure and verify trust relationships.</p> <p>This action verifies a trust relationship between your AWS Managed Microsoft AD directory and an external domain.</p>
+    async fn verify_trust(
+        &self,
+        input: VerifyTrustRequest,
+    ) -> Result<VerifyTrustResult, RusotoError<VerifyTrustError>> {
+        self.verify_trust_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
     }
 }
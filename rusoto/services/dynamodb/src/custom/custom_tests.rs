@@ -46,3 +46,94 @@ fn attribute_value_with_binary_set() {
     let serialized = serde_json::to_string(&all_default).unwrap();
     assert_eq!(&serialized, r#"{"BS":["Zm9v","YmFy","YmF6"]}"#);
 }
+
+#[tokio::test]
+async fn list_tables_stream_follows_the_last_evaluated_table_name() {
+    use crate::generated::{DynamoDbClient, ListTablesInput};
+    use futures::TryStreamExt;
+    use rusoto_core::Region;
+    use rusoto_mock::{
+        MockCredentialsProvider, MockRequestDispatcher, MultipleMockRequestDispatcher,
+    };
+
+    let page = |body: &str, start: Option<&'static str>| {
+        MockRequestDispatcher::with_status(200)
+            .with_body(body)
+            .with_request_checker(move |request| {
+                let payload = match request.payload {
+                    Some(rusoto_core::signature::SignedRequestPayload::Buffer(ref payload)) => {
+                        payload.clone()
+                    }
+                    _ => panic!("expected a buffered payload"),
+                };
+                let input: serde_json::Value = serde_json::from_slice(&payload).unwrap();
+                assert_eq!(input["ExclusiveStartTableName"].as_str(), start);
+            })
+    };
+    let dispatcher = MultipleMockRequestDispatcher::new(vec![
+        page(
+            r#"{"TableNames": ["a", "b"], "LastEvaluatedTableName": "b"}"#,
+            None,
+        ),
+        page(
+            r#"{"TableNames": ["c"], "LastEvaluatedTableName": "c"}"#,
+            Some("b"),
+        ),
+        // A repeated token ends the pages rather than requesting the same one again.
+        page(
+            r#"{"TableNames": [], "LastEvaluatedTableName": "c"}"#,
+            Some("c"),
+        ),
+    ]);
+    let client = DynamoDbClient::new_with(dispatcher, MockCredentialsProvider, Region::UsEast1);
+
+    let tables: Vec<String> = client
+        .list_tables_stream(ListTablesInput::default())
+        .try_collect()
+        .await
+        .unwrap();
+    assert_eq!(tables, vec!["a", "b", "c"]);
+}
+
+#[tokio::test]
+async fn wait_until_table_exists_retries_until_the_table_is_active() {
+    use crate::generated::{DescribeTableInput, DynamoDbClient};
+    use rusoto_core::waiter::{Waiter, WaiterError};
+    use rusoto_core::Region;
+    use rusoto_mock::{
+        MockCredentialsProvider, MockRequestDispatcher, MultipleMockRequestDispatcher,
+    };
+    use std::time::Duration;
+
+    let not_found = || {
+        MockRequestDispatcher::with_status(400).with_body(
+            r#"{"__type": "com.amazonaws.dynamodb.v20120810#ResourceNotFoundException", "message": "Requested resource not found"}"#,
+        )
+    };
+    let table = |status: &str| {
+        MockRequestDispatcher::with_status(200).with_body(&format!(
+            r#"{{"Table": {{"TableName": "table", "TableStatus": "{}"}}}}"#,
+            status
+        ))
+    };
+    let input = DescribeTableInput {
+        table_name: "table".to_owned(),
+    };
+    let waiter = Waiter::new(Duration::from_millis(1), 4);
+
+    let dispatcher =
+        MultipleMockRequestDispatcher::new(vec![not_found(), table("CREATING"), table("ACTIVE")]);
+    let client = DynamoDbClient::new_with(dispatcher, MockCredentialsProvider, Region::UsEast1);
+    client
+        .wait_until_table_exists_with(input.clone(), &waiter)
+        .await
+        .unwrap();
+
+    let dispatcher =
+        MultipleMockRequestDispatcher::new((0..4).map(|_| table("CREATING")).collect::<Vec<_>>());
+    let client = DynamoDbClient::new_with(dispatcher, MockCredentialsProvider, Region::UsEast1);
+    match client.wait_until_table_exists_with(input, &waiter).await {
+        Err(WaiterError::TimedOut(4)) => {}
+        result => panic!("unexpected result {:?}", result),
+    }
+}
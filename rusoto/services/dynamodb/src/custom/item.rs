@@ -0,0 +1,856 @@
+//! Conversions between types implementing `Serialize` and `Deserialize` and the items and
+//! attribute values of DynamoDB.
+//!
+//! Values are converted as their serde data model has them:
+//!
+//! * booleans are `BOOL`, numbers `N`, strings and characters `S` and bytes `B`
+//! * `None` and units are `NULL`, `Some` the value it holds
+//! * sequences and tuples are `L`, maps and structs `M`, with the keys of maps as `S` or `N`
+//! * unit variants are the `S` of their name, other variants an `M` of their name to their
+//!   value
+//!
+//! Sets are never serialized, as serde doesn't tell them from lists, but `SS`, `NS` and `BS`
+//! deserialize as sequences.
+
+use std::collections::HashMap;
+use std::error::Error;
+use std::fmt;
+
+use serde::de::value::{MapDeserializer, SeqDeserializer};
+use serde::de::{self, DeserializeOwned, IntoDeserializer, Visitor};
+use serde::ser::{self, Serialize};
+
+use crate::generated::AttributeValue;
+
+/// An error converting a value to or from an attribute value.
+#[derive(Clone, Debug, PartialEq)]
+pub struct AttributeValueError {
+    message: String,
+}
+
+impl AttributeValueError {
+    fn new<M: Into<String>>(message: M) -> AttributeValueError {
+        AttributeValueError {
+            message: message.into(),
+        }
+    }
+}
+
+impl fmt::Display for AttributeValueError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.message)
+    }
+}
+
+impl Error for AttributeValueError {}
+
+impl ser::Error for AttributeValueError {
+    fn custom<T: fmt::Display>(msg: T) -> AttributeValueError {
+        AttributeValueError::new(msg.to_string())
+    }
+}
+
+impl de::Error for AttributeValueError {
+    fn custom<T: fmt::Display>(msg: T) -> AttributeValueError {
+        AttributeValueError::new(msg.to_string())
+    }
+}
+
+/// The attribute value of `value`.
+pub fn to_attribute_value<T>(value: &T) -> Result<AttributeValue, AttributeValueError>
+where
+    T: Serialize + ?Sized,
+{
+    value.serialize(ValueSerializer)
+}
+
+/// The value of the type `T` of the attribute value `value`.
+pub fn from_attribute_value<T>(value: AttributeValue) -> Result<T, AttributeValueError>
+where
+    T: DeserializeOwned,
+{
+    T::deserialize(ValueDeserializer(value))
+}
+
+/// The item of `value`, which has to serialize as a map, like structs do.
+///
+/// ```rust,ignore
+/// #[derive(Serialize)]
+/// struct Song { artist: String, title: String, plays: u64 }
+///
+/// let item = rusoto_dynamodb::to_item(&song)?;
+/// client.put_item(PutItemInput { table_name, item, ..Default::default() }).await?;
+/// ```
+pub fn to_item<T>(value: &T) -> Result<HashMap<String, AttributeValue>, AttributeValueError>
+where
+    T: Serialize + ?Sized,
+{
+    to_attribute_value(value)?
+        .m
+        .ok_or_else(|| AttributeValueError::new("an item has to serialize as a map"))
+}
+
+/// The value of the type `T` of the item `item`, e.g. of a `GetItemOutput`.
+pub fn from_item<T>(item: HashMap<String, AttributeValue>) -> Result<T, AttributeValueError>
+where
+    T: DeserializeOwned,
+{
+    from_attribute_value(AttributeValue {
+        m: Some(item),
+        ..Default::default()
+    })
+}
+
+fn number<N: ToString>(n: N) -> AttributeValue {
+    AttributeValue {
+        n: Some(n.to_string()),
+        ..Default::default()
+    }
+}
+
+fn float(f: f64) -> Result<AttributeValue, AttributeValueError> {
+    if !f.is_finite() {
+        return Err(AttributeValueError::new(format!(
+            "{} isn't a DynamoDB number",
+            f
+        )));
+    }
+    Ok(number(f))
+}
+
+fn string(s: String) -> AttributeValue {
+    AttributeValue {
+        s: Some(s),
+        ..Default::default()
+    }
+}
+
+fn null() -> AttributeValue {
+    AttributeValue {
+        null: Some(true),
+        ..Default::default()
+    }
+}
+
+fn list(values: Vec<AttributeValue>) -> AttributeValue {
+    AttributeValue {
+        l: Some(values),
+        ..Default::default()
+    }
+}
+
+fn map(values: HashMap<String, AttributeValue>) -> AttributeValue {
+    AttributeValue {
+        m: Some(values),
+        ..Default::default()
+    }
+}
+
+/// The map of `variant` to `value`, as variants with values are serialized.
+fn variant(variant: &str, value: AttributeValue) -> AttributeValue {
+    let mut values = HashMap::new();
+    values.insert(variant.to_owned(), value);
+    map(values)
+}
+
+struct ValueSerializer;
+
+impl ser::Serializer for ValueSerializer {
+    type Ok = AttributeValue;
+    type Error = AttributeValueError;
+    type SerializeSeq = SerializeList;
+    type SerializeTuple = SerializeList;
+    type SerializeTupleStruct = SerializeList;
+    type SerializeTupleVariant = SerializeList;
+    type SerializeMap = SerializeMap;
+    type SerializeStruct = SerializeMap;
+    type SerializeStructVariant = SerializeMap;
+
+    fn serialize_bool(self, v: bool) -> Result<AttributeValue, AttributeValueError> {
+        Ok(AttributeValue {
+            bool: Some(v),
+            ..Default::default()
+        })
+    }
+
+    fn serialize_i8(self, v: i8) -> Result<AttributeValue, AttributeValueError> {
+        Ok(number(v))
+    }
+
+    fn serialize_i16(self, v: i16) -> Result<AttributeValue, AttributeValueError> {
+        Ok(number(v))
+    }
+
+    fn serialize_i32(self, v: i32) -> Result<AttributeValue, AttributeValueError> {
+        Ok(number(v))
+    }
+
+    fn serialize_i64(self, v: i64) -> Result<AttributeValue, AttributeValueError> {
+        Ok(number(v))
+    }
+
+    fn serialize_i128(self, v: i128) -> Result<AttributeValue, AttributeValueError> {
+        Ok(number(v))
+    }
+
+    fn serialize_u8(self, v: u8) -> Result<AttributeValue, AttributeValueError> {
+        Ok(number(v))
+    }
+
+    fn serialize_u16(self, v: u16) -> Result<AttributeValue, AttributeValueError> {
+        Ok(number(v))
+    }
+
+    fn serialize_u32(self, v: u32) -> Result<AttributeValue, AttributeValueError> {
+        Ok(number(v))
+    }
+
+    fn serialize_u64(self, v: u64) -> Result<AttributeValue, AttributeValueError> {
+        Ok(number(v))
+    }
+
+    fn serialize_u128(self, v: u128) -> Result<AttributeValue, AttributeValueError> {
+        Ok(number(v))
+    }
+
+    fn serialize_f32(self, v: f32) -> Result<AttributeValue, AttributeValueError> {
+        float(f64::from(v))
+    }
+
+    fn serialize_f64(self, v: f64) -> Result<AttributeValue, AttributeValueError> {
+        float(v)
+    }
+
+    fn serialize_char(self, v: char) -> Result<AttributeValue, AttributeValueError> {
+        Ok(string(v.to_string()))
+    }
+
+    fn serialize_str(self, v: &str) -> Result<AttributeValue, AttributeValueError> {
+        Ok(string(v.to_owned()))
+    }
+
+    fn serialize_bytes(self, v: &[u8]) -> Result<AttributeValue, AttributeValueError> {
+        Ok(AttributeValue {
+            b: Some(bytes::Bytes::copy_from_slice(v)),
+            ..Default::default()
+        })
+    }
+
+    fn serialize_none(self) -> Result<AttributeValue, AttributeValueError> {
+        Ok(null())
+    }
+
+    fn serialize_some<T>(self, value: &T) -> Result<AttributeValue, AttributeValueError>
+    where
+        T: Serialize + ?Sized,
+    {
+        value.serialize(self)
+    }
+
+    fn serialize_unit(self) -> Result<AttributeValue, AttributeValueError> {
+        Ok(null())
+    }
+
+    fn serialize_unit_struct(
+        self,
+        _name: &'static str,
+    ) -> Result<AttributeValue, AttributeValueError> {
+        Ok(null())
+    }
+
+    fn serialize_unit_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        variant: &'static str,
+    ) -> Result<AttributeValue, AttributeValueError> {
+        Ok(string(variant.to_owned()))
+    }
+
+    fn serialize_newtype_struct<T>(
+        self,
+        _name: &'static str,
+        value: &T,
+    ) -> Result<AttributeValue, AttributeValueError>
+    where
+        T: Serialize + ?Sized,
+    {
+        value.serialize(self)
+    }
+
+    fn serialize_newtype_variant<T>(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        variant: &'static str,
+        value: &T,
+    ) -> Result<AttributeValue, AttributeValueError>
+    where
+        T: Serialize + ?Sized,
+    {
+        Ok(self::variant(variant, value.serialize(self)?))
+    }
+
+    fn serialize_seq(self, len: Option<usize>) -> Result<SerializeList, AttributeValueError> {
+        Ok(SerializeList {
+            variant: None,
+            values: Vec::with_capacity(len.unwrap_or(0)),
+        })
+    }
+
+    fn serialize_tuple(self, len: usize) -> Result<SerializeList, AttributeValueError> {
+        self.serialize_seq(Some(len))
+    }
+
+    fn serialize_tuple_struct(
+        self,
+        _name: &'static str,
+        len: usize,
+    ) -> Result<SerializeList, AttributeValueError> {
+        self.serialize_seq(Some(len))
+    }
+
+    fn serialize_tuple_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        variant: &'static str,
+        len: usize,
+    ) -> Result<SerializeList, AttributeValueError> {
+        Ok(SerializeList {
+            variant: Some(variant),
+            values: Vec::with_capacity(len),
+        })
+    }
+
+    fn serialize_map(self, _len: Option<usize>) -> Result<SerializeMap, AttributeValueError> {
+        Ok(SerializeMap {
+            variant: None,
+            values: HashMap::new(),
+            key: None,
+        })
+    }
+
+    fn serialize_struct(
+        self,
+        _name: &'static str,
+        len: usize,
+    ) -> Result<SerializeMap, AttributeValueError> {
+        self.serialize_map(Some(len))
+    }
+
+    fn serialize_struct_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        variant: &'static str,
+        _len: usize,
+    ) -> Result<SerializeMap, AttributeValueError> {
+        Ok(SerializeMap {
+            variant: Some(variant),
+            values: HashMap::new(),
+            key: None,
+        })
+    }
+}
+
+/// The `L` of a sequence, tuple or tuple variant.
+struct SerializeList {
+    variant: Option<&'static str>,
+    values: Vec<AttributeValue>,
+}
+
+impl SerializeList {
+    fn push<T>(&mut self, value: &T) -> Result<(), AttributeValueError>
+    where
+        T: Serialize + ?Sized,
+    {
+        self.values.push(value.serialize(ValueSerializer)?);
+        Ok(())
+    }
+
+    fn finish(self) -> Result<AttributeValue, AttributeValueError> {
+        let values = list(self.values);
+        Ok(match self.variant {
+            Some(name) => variant(name, values),
+            None => values,
+        })
+    }
+}
+
+impl ser::SerializeSeq for SerializeList {
+    type Ok = AttributeValue;
+    type Error = AttributeValueError;
+
+    fn serialize_element<T>(&mut self, value: &T) -> Result<(), AttributeValueError>
+    where
+        T: Serialize + ?Sized,
+    {
+        self.push(value)
+    }
+
+    fn end(self) -> Result<AttributeValue, AttributeValueError> {
+        self.finish()
+    }
+}
+
+impl ser::SerializeTuple for SerializeList {
+    type Ok = AttributeValue;
+    type Error = AttributeValueError;
+
+    fn serialize_element<T>(&mut self, value: &T) -> Result<(), AttributeValueError>
+    where
+        T: Serialize + ?Sized,
+    {
+        self.push(value)
+    }
+
+    fn end(self) -> Result<AttributeValue, AttributeValueError> {
+        self.finish()
+    }
+}
+
+impl ser::SerializeTupleStruct for SerializeList {
+    type Ok = AttributeValue;
+    type Error = AttributeValueError;
+
+    fn serialize_field<T>(&mut self, value: &T) -> Result<(), AttributeValueError>
+    where
+        T: Serialize + ?Sized,
+    {
+        self.push(value)
+    }
+
+    fn end(self) -> Result<AttributeValue, AttributeValueError> {
+        self.finish()
+    }
+}
+
+impl ser::SerializeTupleVariant for SerializeList {
+    type Ok = AttributeValue;
+    type Error = AttributeValueError;
+
+    fn serialize_field<T>(&mut self, value: &T) -> Result<(), AttributeValueError>
+    where
+        T: Serialize + ?Sized,
+    {
+        self.push(value)
+    }
+
+    fn end(self) -> Result<AttributeValue, AttributeValueError> {
+        self.finish()
+    }
+}
+
+/// The `M` of a map, struct or struct variant.
+struct SerializeMap {
+    variant: Option<&'static str>,
+    values: HashMap<String, AttributeValue>,
+    key: Option<String>,
+}
+
+impl SerializeMap {
+    fn finish(self) -> Result<AttributeValue, AttributeValueError> {
+        let values = map(self.values);
+        Ok(match self.variant {
+            Some(name) => variant(name, values),
+            None => values,
+        })
+    }
+}
+
+impl ser::SerializeMap for SerializeMap {
+    type Ok = AttributeValue;
+    type Error = AttributeValueError;
+
+    fn serialize_key<T>(&mut self, key: &T) -> Result<(), AttributeValueError>
+    where
+        T: Serialize + ?Sized,
+    {
+        let key = key.serialize(ValueSerializer)?;
+        match key.s.or(key.n) {
+            Some(key) => {
+                self.key = Some(key);
+                Ok(())
+            }
+            None => Err(AttributeValueError::new(
+                "the keys of maps have to be strings or numbers",
+            )),
+        }
+    }
+
+    fn serialize_value<T>(&mut self, value: &T) -> Result<(), AttributeValueError>
+    where
+        T: Serialize + ?Sized,
+    {
+        let key = self
+            .key
+            .take()
+            .ok_or_else(|| AttributeValueError::new("a map value was serialized before its key"))?;
+        self.values.insert(key, value.serialize(ValueSerializer)?);
+        Ok(())
+    }
+
+    fn end(self) -> Result<AttributeValue, AttributeValueError> {
+        self.finish()
+    }
+}
+
+impl ser::SerializeStruct for SerializeMap {
+    type Ok = AttributeValue;
+    type Error = AttributeValueError;
+
+    fn serialize_field<T>(
+        &mut self,
+        key: &'static str,
+        value: &T,
+    ) -> Result<(), AttributeValueError>
+    where
+        T: Serialize + ?Sized,
+    {
+        self.values
+            .insert(key.to_owned(), value.serialize(ValueSerializer)?);
+        Ok(())
+    }
+
+    fn end(self) -> Result<AttributeValue, AttributeValueError> {
+        self.finish()
+    }
+}
+
+impl ser::SerializeStructVariant for SerializeMap {
+    type Ok = AttributeValue;
+    type Error = AttributeValueError;
+
+    fn serialize_field<T>(
+        &mut self,
+        key: &'static str,
+        value: &T,
+    ) -> Result<(), AttributeValueError>
+    where
+        T: Serialize + ?Sized,
+    {
+        ser::SerializeStruct::serialize_field(self, key, value)
+    }
+
+    fn end(self) -> Result<AttributeValue, AttributeValueError> {
+        self.finish()
+    }
+}
+
+struct ValueDeserializer(AttributeValue);
+
+impl<'de> IntoDeserializer<'de, AttributeValueError> for ValueDeserializer {
+    type Deserializer = ValueDeserializer;
+
+    fn into_deserializer(self) -> ValueDeserializer {
+        self
+    }
+}
+
+/// The deserializers of the values of a list or set.
+fn elements<T, F>(values: Vec<T>, element: F) -> impl Iterator<Item = ValueDeserializer>
+where
+    F: Fn(T) -> AttributeValue,
+{
+    values
+        .into_iter()
+        .map(move |value| ValueDeserializer(element(value)))
+}
+
+impl<'de> de::Deserializer<'de> for ValueDeserializer {
+    type Error = AttributeValueError;
+
+    fn deserialize_any<V>(self, visitor: V) -> Result<V::Value, AttributeValueError>
+    where
+        V: Visitor<'de>,
+    {
+        let value = self.0;
+        if let Some(s) = value.s {
+            visitor.visit_string(s)
+        } else if let Some(n) = value.n {
+            if let Ok(n) = n.parse::<i64>() {
+                visitor.visit_i64(n)
+            } else if let Ok(n) = n.parse::<u64>() {
+                visitor.visit_u64(n)
+            } else if let Ok(n) = n.parse::<f64>() {
+                visitor.visit_f64(n)
+            } else {
+                Err(AttributeValueError::new(format!("invalid number {}", n)))
+            }
+        } else if let Some(b) = value.bool {
+            visitor.visit_bool(b)
+        } else if let Some(b) = value.b {
+            visitor.visit_byte_buf(b.to_vec())
+        } else if value.null.is_some() {
+            visitor.visit_unit()
+        } else if let Some(l) = value.l {
+            let mut values = SeqDeserializer::new(elements(l, |value| value));
+            let result = visitor.visit_seq(&mut values)?;
+            values.end()?;
+            Ok(result)
+        } else if let Some(m) = value.m {
+            let values = m
+                .into_iter()
+                .map(|(key, value)| (key, ValueDeserializer(value)));
+            let mut values = MapDeserializer::new(values);
+            let result = visitor.visit_map(&mut values)?;
+            values.end()?;
+            Ok(result)
+        } else if let Some(ss) = value.ss {
+            visitor.visit_seq(SeqDeserializer::new(elements(ss, string)))
+        } else if let Some(ns) = value.ns {
+            visitor.visit_seq(SeqDeserializer::new(elements(ns, number)))
+        } else if let Some(bs) = value.bs {
+            let binary = |b| AttributeValue {
+                b: Some(b),
+                ..Default::default()
+            };
+            visitor.visit_seq(SeqDeserializer::new(elements(bs, binary)))
+        } else {
+            Err(AttributeValueError::new("empty attribute value"))
+        }
+    }
+
+    fn deserialize_option<V>(self, visitor: V) -> Result<V::Value, AttributeValueError>
+    where
+        V: Visitor<'de>,
+    {
+        if self.0.null.is_some() {
+            visitor.visit_none()
+        } else {
+            visitor.visit_some(self)
+        }
+    }
+
+    fn deserialize_newtype_struct<V>(
+        self,
+        _name: &'static str,
+        visitor: V,
+    ) -> Result<V::Value, AttributeValueError>
+    where
+        V: Visitor<'de>,
+    {
+        visitor.visit_newtype_struct(self)
+    }
+
+    fn deserialize_seq<V>(self, visitor: V) -> Result<V::Value, AttributeValueError>
+    where
+        V: Visitor<'de>,
+    {
+        // Binary values are the bytes of `Vec<u8>`, which deserializes as a sequence.
+        match self.0.b {
+            Some(b) => visitor.visit_seq(SeqDeserializer::new(b.into_iter())),
+            None => self.deserialize_any(visitor),
+        }
+    }
+
+    fn deserialize_enum<V>(
+        self,
+        _name: &'static str,
+        _variants: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, AttributeValueError>
+    where
+        V: Visitor<'de>,
+    {
+        if let Some(s) = self.0.s {
+            return visitor.visit_enum(s.into_deserializer());
+        }
+        let mut values = self.0.m.unwrap_or_default();
+        if values.len() != 1 {
+            return Err(AttributeValueError::new(
+                "an enum has to be a string or a map of a single variant",
+            ));
+        }
+        let (variant, value) = values.drain().next().expect("the map has a variant");
+        visitor.visit_enum(Enum { variant, value })
+    }
+
+    serde::forward_to_deserialize_any! {
+        bool i8 i16 i32 i64 i128 u8 u16 u32 u64 u128 f32 f64 char str string
+        bytes byte_buf unit unit_struct tuple tuple_struct map struct identifier
+        ignored_any
+    }
+}
+
+/// A variant with a value, the single entry of a map.
+struct Enum {
+    variant: String,
+    value: AttributeValue,
+}
+
+impl<'de> de::EnumAccess<'de> for Enum {
+    type Error = AttributeValueError;
+    type Variant = ValueDeserializer;
+
+    fn variant_seed<V>(self, seed: V) -> Result<(V::Value, ValueDeserializer), AttributeValueError>
+    where
+        V: de::DeserializeSeed<'de>,
+    {
+        let variant = seed.deserialize(self.variant.into_deserializer())?;
+        Ok((variant, ValueDeserializer(self.value)))
+    }
+}
+
+impl<'de> de::VariantAccess<'de> for ValueDeserializer {
+    type Error = AttributeValueError;
+
+    fn unit_variant(self) -> Result<(), AttributeValueError> {
+        Ok(())
+    }
+
+    fn newtype_variant_seed<T>(self, seed: T) -> Result<T::Value, AttributeValueError>
+    where
+        T: de::DeserializeSeed<'de>,
+    {
+        seed.deserialize(self)
+    }
+
+    fn tuple_variant<V>(self, _len: usize, visitor: V) -> Result<V::Value, AttributeValueError>
+    where
+        V: Visitor<'de>,
+    {
+        de::Deserializer::deserialize_seq(self, visitor)
+    }
+
+    fn struct_variant<V>(
+        self,
+        _fields: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, AttributeValueError>
+    where
+        V: Visitor<'de>,
+    {
+        de::Deserializer::deserialize_map(self, visitor)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde::{Deserialize, Serialize};
+    use std::collections::BTreeMap;
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    enum Format {
+        Single,
+        Album(u32),
+        Split { with: String },
+    }
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Song {
+        artist: String,
+        title: String,
+        plays: u64,
+        rating: f64,
+        explicit: bool,
+        remix_of: Option<String>,
+        tags: Vec<String>,
+        credits: BTreeMap<String, String>,
+        format: Format,
+        formats: Vec<Format>,
+    }
+
+    fn song() -> Song {
+        let mut credits = BTreeMap::new();
+        credits.insert("producer".to_owned(), "Pat".to_owned());
+        Song {
+            artist: "No One You Know".to_owned(),
+            title: "Call Me Today".to_owned(),
+            plays: 42,
+            rating: 4.5,
+            explicit: false,
+            remix_of: None,
+            tags: vec!["pop".to_owned()],
+            credits,
+            format: Format::Album(3),
+            formats: vec![
+                Format::Single,
+                Format::Split {
+                    with: "Someone".to_owned(),
+                },
+            ],
+        }
+    }
+
+    #[test]
+    fn converts_structs_to_items_and_back() {
+        let item = to_item(&song()).unwrap();
+        assert_eq!(item["artist"].s.as_deref(), Some("No One You Know"));
+        assert_eq!(item["plays"].n.as_deref(), Some("42"));
+        assert_eq!(item["rating"].n.as_deref(), Some("4.5"));
+        assert_eq!(item["explicit"].bool, Some(false));
+        assert_eq!(item["remix_of"].null, Some(true));
+        assert_eq!(
+            item["tags"].l.as_ref().unwrap()[0].s.as_deref(),
+            Some("pop")
+        );
+        assert_eq!(
+            item["credits"].m.as_ref().unwrap()["producer"].s.as_deref(),
+            Some("Pat")
+        );
+        assert_eq!(
+            item["format"].m.as_ref().unwrap()["Album"].n.as_deref(),
+            Some("3")
+        );
+        assert_eq!(
+            item["formats"].l.as_ref().unwrap()[0].s.as_deref(),
+            Some("Single")
+        );
+
+        assert_eq!(from_item::<Song>(item).unwrap(), song());
+    }
+
+    #[test]
+    fn deserializes_sets_and_missing_options() {
+        #[derive(Debug, PartialEq, Deserialize)]
+        struct Tags {
+            names: Vec<String>,
+            counts: Vec<u8>,
+            missing: Option<String>,
+        }
+        let mut item = HashMap::new();
+        item.insert(
+            "names".to_owned(),
+            AttributeValue {
+                ss: Some(vec!["a".to_owned(), "b".to_owned()]),
+                ..Default::default()
+            },
+        );
+        item.insert(
+            "counts".to_owned(),
+            AttributeValue {
+                ns: Some(vec!["1".to_owned(), "2".to_owned()]),
+                ..Default::default()
+            },
+        );
+        assert_eq!(
+            from_item::<Tags>(item).unwrap(),
+            Tags {
+                names: vec!["a".to_owned(), "b".to_owned()],
+                counts: vec![1, 2],
+                missing: None,
+            }
+        );
+
+        let bytes: Vec<u8> = from_attribute_value(AttributeValue {
+            b: Some(bytes::Bytes::from_static(b"\x01\x02")),
+            ..Default::default()
+        })
+        .unwrap();
+        assert_eq!(bytes, vec![1, 2]);
+    }
+
+    #[test]
+    fn fails_on_values_dynamodb_cannot_store() {
+        assert!(to_item(&"not a map").is_err());
+        assert!(to_attribute_value(&f64::NAN).is_err());
+        let mut points = HashMap::new();
+        points.insert((1, 2), "point");
+        assert!(to_attribute_value(&points).is_err());
+        assert!(from_attribute_value::<u8>(number(300)).is_err());
+        assert!(from_attribute_value::<String>(AttributeValue::default()).is_err());
+    }
+}
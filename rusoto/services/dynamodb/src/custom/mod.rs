@@ -1,2 +1,8 @@
+mod item;
+
+pub use self::item::{
+    from_attribute_value, from_item, to_attribute_value, to_item, AttributeValueError,
+};
+
 #[cfg(test)]
 mod custom_tests;
@@ -16,8 +16,8 @@ use std::fmt;
 use async_trait::async_trait;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
-use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest, ResponseMetadata};
+use rusoto_core::{Client, ErrorMetadata, RusotoError};
 
 use rusoto_core::proto;
 use rusoto_core::signature::SignedRequest;
@@ -3115,6 +3115,7 @@ pub struct WriteRequest {
 
 /// Errors returned by BatchGetItem
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum BatchGetItemError {
     /// <p>An error occurred on the server side.</p>
     InternalServerError(String),
@@ -3124,6 +3125,8 @@ pub enum BatchGetItemError {
     RequestLimitExceeded(String),
     /// <p>The operation tried to access a nonexistent table or index. The resource might not be specified correctly, or its status might not be <code>ACTIVE</code>.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl BatchGetItemError {
@@ -3131,21 +3134,28 @@ impl BatchGetItemError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(BatchGetItemError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        BatchGetItemError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "ProvisionedThroughputExceededException" => {
-                    return RusotoError::Service(BatchGetItemError::ProvisionedThroughputExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        BatchGetItemError::ProvisionedThroughputExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "RequestLimitExceeded" => {
-                    return RusotoError::Service(BatchGetItemError::RequestLimitExceeded(err.msg))
+                    return RusotoError::service(
+                        BatchGetItemError::RequestLimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(BatchGetItemError::ResourceNotFound(err.msg))
+                    return RusotoError::service(BatchGetItemError::ResourceNotFound(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(BatchGetItemError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3159,12 +3169,14 @@ impl fmt::Display for BatchGetItemError {
             BatchGetItemError::ProvisionedThroughputExceeded(ref cause) => write!(f, "{}", cause),
             BatchGetItemError::RequestLimitExceeded(ref cause) => write!(f, "{}", cause),
             BatchGetItemError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            BatchGetItemError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for BatchGetItemError {}
 /// Errors returned by BatchWriteItem
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum BatchWriteItemError {
     /// <p>An error occurred on the server side.</p>
     InternalServerError(String),
@@ -3176,6 +3188,8 @@ pub enum BatchWriteItemError {
     RequestLimitExceeded(String),
     /// <p>The operation tried to access a nonexistent table or index. The resource might not be specified correctly, or its status might not be <code>ACTIVE</code>.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl BatchWriteItemError {
@@ -3183,26 +3197,37 @@ impl BatchWriteItemError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(BatchWriteItemError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        BatchWriteItemError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "ItemCollectionSizeLimitExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         BatchWriteItemError::ItemCollectionSizeLimitExceeded(err.msg),
+                        &res,
                     )
                 }
                 "ProvisionedThroughputExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         BatchWriteItemError::ProvisionedThroughputExceeded(err.msg),
+                        &res,
                     )
                 }
                 "RequestLimitExceeded" => {
-                    return RusotoError::Service(BatchWriteItemError::RequestLimitExceeded(err.msg))
+                    return RusotoError::service(
+                        BatchWriteItemError::RequestLimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(BatchWriteItemError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        BatchWriteItemError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(BatchWriteItemError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3219,12 +3244,14 @@ impl fmt::Display for BatchWriteItemError {
             BatchWriteItemError::ProvisionedThroughputExceeded(ref cause) => write!(f, "{}", cause),
             BatchWriteItemError::RequestLimitExceeded(ref cause) => write!(f, "{}", cause),
             BatchWriteItemError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            BatchWriteItemError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for BatchWriteItemError {}
 /// Errors returned by CreateBackup
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateBackupError {
     /// <p>There is another ongoing conflicting backup control plane operation on the table. The backup is either being created, deleted or restored to a table.</p>
     BackupInUse(String),
@@ -3238,6 +3265,8 @@ pub enum CreateBackupError {
     TableInUse(String),
     /// <p>A source table with the name <code>TableName</code> does not currently exist within the subscriber's account.</p>
     TableNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateBackupError {
@@ -3245,27 +3274,31 @@ impl CreateBackupError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "BackupInUseException" => {
-                    return RusotoError::Service(CreateBackupError::BackupInUse(err.msg))
+                    return RusotoError::service(CreateBackupError::BackupInUse(err.msg), &res)
                 }
                 "ContinuousBackupsUnavailableException" => {
-                    return RusotoError::Service(CreateBackupError::ContinuousBackupsUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateBackupError::ContinuousBackupsUnavailable(err.msg),
+                        &res,
+                    )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(CreateBackupError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        CreateBackupError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateBackupError::LimitExceeded(err.msg))
+                    return RusotoError::service(CreateBackupError::LimitExceeded(err.msg), &res)
                 }
                 "TableInUseException" => {
-                    return RusotoError::Service(CreateBackupError::TableInUse(err.msg))
+                    return RusotoError::service(CreateBackupError::TableInUse(err.msg), &res)
                 }
                 "TableNotFoundException" => {
-                    return RusotoError::Service(CreateBackupError::TableNotFound(err.msg))
+                    return RusotoError::service(CreateBackupError::TableNotFound(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreateBackupError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3281,12 +3314,14 @@ impl fmt::Display for CreateBackupError {
             CreateBackupError::LimitExceeded(ref cause) => write!(f, "{}", cause),
             CreateBackupError::TableInUse(ref cause) => write!(f, "{}", cause),
             CreateBackupError::TableNotFound(ref cause) => write!(f, "{}", cause),
+            CreateBackupError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateBackupError {}
 /// Errors returned by CreateGlobalTable
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateGlobalTableError {
     /// <p>The specified global table already exists.</p>
     GlobalTableAlreadyExists(String),
@@ -3296,6 +3331,8 @@ pub enum CreateGlobalTableError {
     LimitExceeded(String),
     /// <p>A source table with the name <code>TableName</code> does not currently exist within the subscriber's account.</p>
     TableNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateGlobalTableError {
@@ -3303,23 +3340,31 @@ impl CreateGlobalTableError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "GlobalTableAlreadyExistsException" => {
-                    return RusotoError::Service(CreateGlobalTableError::GlobalTableAlreadyExists(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateGlobalTableError::GlobalTableAlreadyExists(err.msg),
+                        &res,
+                    )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(CreateGlobalTableError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateGlobalTableError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateGlobalTableError::LimitExceeded(err.msg))
+                    return RusotoError::service(
+                        CreateGlobalTableError::LimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "TableNotFoundException" => {
-                    return RusotoError::Service(CreateGlobalTableError::TableNotFound(err.msg))
+                    return RusotoError::service(
+                        CreateGlobalTableError::TableNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreateGlobalTableError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3333,12 +3378,14 @@ impl fmt::Display for CreateGlobalTableError {
             CreateGlobalTableError::InternalServerError(ref cause) => write!(f, "{}", cause),
             CreateGlobalTableError::LimitExceeded(ref cause) => write!(f, "{}", cause),
             CreateGlobalTableError::TableNotFound(ref cause) => write!(f, "{}", cause),
+            CreateGlobalTableError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateGlobalTableError {}
 /// Errors returned by CreateTable
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateTableError {
     /// <p>An error occurred on the server side.</p>
     InternalServerError(String),
@@ -3346,6 +3393,8 @@ pub enum CreateTableError {
     LimitExceeded(String),
     /// <p>The operation conflicts with the resource's availability. For example, you attempted to recreate an existing table, or tried to delete a table currently in the <code>CREATING</code> state.</p>
     ResourceInUse(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateTableError {
@@ -3353,16 +3402,19 @@ impl CreateTableError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(CreateTableError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        CreateTableError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateTableError::LimitExceeded(err.msg))
+                    return RusotoError::service(CreateTableError::LimitExceeded(err.msg), &res)
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(CreateTableError::ResourceInUse(err.msg))
+                    return RusotoError::service(CreateTableError::ResourceInUse(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreateTableError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3375,12 +3427,14 @@ impl fmt::Display for CreateTableError {
             CreateTableError::InternalServerError(ref cause) => write!(f, "{}", cause),
             CreateTableError::LimitExceeded(ref cause) => write!(f, "{}", cause),
             CreateTableError::ResourceInUse(ref cause) => write!(f, "{}", cause),
+            CreateTableError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateTableError {}
 /// Errors returned by DeleteBackup
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteBackupError {
     /// <p>There is another ongoing conflicting backup control plane operation on the table. The backup is either being created, deleted or restored to a table.</p>
     BackupInUse(String),
@@ -3390,6 +3444,8 @@ pub enum DeleteBackupError {
     InternalServerError(String),
     /// <p>There is no limit to the number of daily on-demand backups that can be taken. </p> <p>Up to 50 simultaneous table operations are allowed per account. These operations include <code>CreateTable</code>, <code>UpdateTable</code>, <code>DeleteTable</code>,<code>UpdateTimeToLive</code>, <code>RestoreTableFromBackup</code>, and <code>RestoreTableToPointInTime</code>. </p> <p>The only exception is when you are creating a table with one or more secondary indexes. You can have up to 25 such requests running at a time; however, if the table or index specifications are complex, DynamoDB might temporarily reduce the number of concurrent operations.</p> <p>There is a soft account limit of 256 tables.</p>
     LimitExceeded(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteBackupError {
@@ -3397,19 +3453,22 @@ impl DeleteBackupError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "BackupInUseException" => {
-                    return RusotoError::Service(DeleteBackupError::BackupInUse(err.msg))
+                    return RusotoError::service(DeleteBackupError::BackupInUse(err.msg), &res)
                 }
                 "BackupNotFoundException" => {
-                    return RusotoError::Service(DeleteBackupError::BackupNotFound(err.msg))
+                    return RusotoError::service(DeleteBackupError::BackupNotFound(err.msg), &res)
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(DeleteBackupError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        DeleteBackupError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(DeleteBackupError::LimitExceeded(err.msg))
+                    return RusotoError::service(DeleteBackupError::LimitExceeded(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteBackupError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3423,12 +3482,14 @@ impl fmt::Display for DeleteBackupError {
             DeleteBackupError::BackupNotFound(ref cause) => write!(f, "{}", cause),
             DeleteBackupError::InternalServerError(ref cause) => write!(f, "{}", cause),
             DeleteBackupError::LimitExceeded(ref cause) => write!(f, "{}", cause),
+            DeleteBackupError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteBackupError {}
 /// Errors returned by DeleteItem
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteItemError {
     /// <p>A condition specified in the operation could not be evaluated.</p>
     ConditionalCheckFailed(String),
@@ -3444,6 +3505,8 @@ pub enum DeleteItemError {
     ResourceNotFound(String),
     /// <p>Operation was rejected because there is an ongoing transaction for the item.</p>
     TransactionConflict(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteItemError {
@@ -3451,32 +3514,46 @@ impl DeleteItemError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConditionalCheckFailedException" => {
-                    return RusotoError::Service(DeleteItemError::ConditionalCheckFailed(err.msg))
+                    return RusotoError::service(
+                        DeleteItemError::ConditionalCheckFailed(err.msg),
+                        &res,
+                    )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(DeleteItemError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        DeleteItemError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "ItemCollectionSizeLimitExceededException" => {
-                    return RusotoError::Service(DeleteItemError::ItemCollectionSizeLimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteItemError::ItemCollectionSizeLimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "ProvisionedThroughputExceededException" => {
-                    return RusotoError::Service(DeleteItemError::ProvisionedThroughputExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteItemError::ProvisionedThroughputExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "RequestLimitExceeded" => {
-                    return RusotoError::Service(DeleteItemError::RequestLimitExceeded(err.msg))
+                    return RusotoError::service(
+                        DeleteItemError::RequestLimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteItemError::ResourceNotFound(err.msg))
+                    return RusotoError::service(DeleteItemError::ResourceNotFound(err.msg), &res)
                 }
                 "TransactionConflictException" => {
-                    return RusotoError::Service(DeleteItemError::TransactionConflict(err.msg))
+                    return RusotoError::service(
+                        DeleteItemError::TransactionConflict(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteItemError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3493,12 +3570,14 @@ impl fmt::Display for DeleteItemError {
             DeleteItemError::RequestLimitExceeded(ref cause) => write!(f, "{}", cause),
             DeleteItemError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             DeleteItemError::TransactionConflict(ref cause) => write!(f, "{}", cause),
+            DeleteItemError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteItemError {}
 /// Errors returned by DeleteTable
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteTableError {
     /// <p>An error occurred on the server side.</p>
     InternalServerError(String),
@@ -3508,6 +3587,8 @@ pub enum DeleteTableError {
     ResourceInUse(String),
     /// <p>The operation tried to access a nonexistent table or index. The resource might not be specified correctly, or its status might not be <code>ACTIVE</code>.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteTableError {
@@ -3515,19 +3596,22 @@ impl DeleteTableError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(DeleteTableError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        DeleteTableError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(DeleteTableError::LimitExceeded(err.msg))
+                    return RusotoError::service(DeleteTableError::LimitExceeded(err.msg), &res)
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(DeleteTableError::ResourceInUse(err.msg))
+                    return RusotoError::service(DeleteTableError::ResourceInUse(err.msg), &res)
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteTableError::ResourceNotFound(err.msg))
+                    return RusotoError::service(DeleteTableError::ResourceNotFound(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteTableError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3541,17 +3625,21 @@ impl fmt::Display for DeleteTableError {
             DeleteTableError::LimitExceeded(ref cause) => write!(f, "{}", cause),
             DeleteTableError::ResourceInUse(ref cause) => write!(f, "{}", cause),
             DeleteTableError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            DeleteTableError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteTableError {}
 /// Errors returned by DescribeBackup
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeBackupError {
     /// <p>Backup not found for the given BackupARN. </p>
     BackupNotFound(String),
     /// <p>An error occurred on the server side.</p>
     InternalServerError(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeBackupError {
@@ -3559,13 +3647,16 @@ impl DescribeBackupError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "BackupNotFoundException" => {
-                    return RusotoError::Service(DescribeBackupError::BackupNotFound(err.msg))
+                    return RusotoError::service(DescribeBackupError::BackupNotFound(err.msg), &res)
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(DescribeBackupError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        DescribeBackupError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DescribeBackupError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3577,17 +3668,21 @@ impl fmt::Display for DescribeBackupError {
         match *self {
             DescribeBackupError::BackupNotFound(ref cause) => write!(f, "{}", cause),
             DescribeBackupError::InternalServerError(ref cause) => write!(f, "{}", cause),
+            DescribeBackupError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeBackupError {}
 /// Errors returned by DescribeContinuousBackups
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeContinuousBackupsError {
     /// <p>An error occurred on the server side.</p>
     InternalServerError(String),
     /// <p>A source table with the name <code>TableName</code> does not currently exist within the subscriber's account.</p>
     TableNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeContinuousBackupsError {
@@ -3595,17 +3690,21 @@ impl DescribeContinuousBackupsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DescribeContinuousBackupsError::InternalServerError(err.msg),
+                        &res,
                     )
                 }
                 "TableNotFoundException" => {
-                    return RusotoError::Service(DescribeContinuousBackupsError::TableNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeContinuousBackupsError::TableNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(DescribeContinuousBackupsError::Unhandled, &res)
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -3619,17 +3718,21 @@ impl fmt::Display for DescribeContinuousBackupsError {
                 write!(f, "{}", cause)
             }
             DescribeContinuousBackupsError::TableNotFound(ref cause) => write!(f, "{}", cause),
+            DescribeContinuousBackupsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeContinuousBackupsError {}
 /// Errors returned by DescribeContributorInsights
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeContributorInsightsError {
     /// <p>An error occurred on the server side.</p>
     InternalServerError(String),
     /// <p>The operation tried to access a nonexistent table or index. The resource might not be specified correctly, or its status might not be <code>ACTIVE</code>.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeContributorInsightsError {
@@ -3639,17 +3742,24 @@ impl DescribeContributorInsightsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DescribeContributorInsightsError::InternalServerError(err.msg),
+                        &res,
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DescribeContributorInsightsError::ResourceNotFound(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        DescribeContributorInsightsError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -3663,20 +3773,25 @@ impl fmt::Display for DescribeContributorInsightsError {
                 write!(f, "{}", cause)
             }
             DescribeContributorInsightsError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            DescribeContributorInsightsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeContributorInsightsError {}
 /// Errors returned by DescribeEndpoints
 #[derive(Debug, PartialEq)]
-pub enum DescribeEndpointsError {}
+#[non_exhaustive]
+pub enum DescribeEndpointsError {
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
+}
 
 impl DescribeEndpointsError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<DescribeEndpointsError> {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DescribeEndpointsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3685,17 +3800,22 @@ impl DescribeEndpointsError {
 impl fmt::Display for DescribeEndpointsError {
     #[allow(unused_variables)]
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        match *self {}
+        match *self {
+            DescribeEndpointsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
+        }
     }
 }
 impl Error for DescribeEndpointsError {}
 /// Errors returned by DescribeGlobalTable
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeGlobalTableError {
     /// <p>The specified global table does not exist.</p>
     GlobalTableNotFound(String),
     /// <p>An error occurred on the server side.</p>
     InternalServerError(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeGlobalTableError {
@@ -3703,17 +3823,19 @@ impl DescribeGlobalTableError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "GlobalTableNotFoundException" => {
-                    return RusotoError::Service(DescribeGlobalTableError::GlobalTableNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeGlobalTableError::GlobalTableNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(DescribeGlobalTableError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeGlobalTableError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DescribeGlobalTableError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3725,17 +3847,21 @@ impl fmt::Display for DescribeGlobalTableError {
         match *self {
             DescribeGlobalTableError::GlobalTableNotFound(ref cause) => write!(f, "{}", cause),
             DescribeGlobalTableError::InternalServerError(ref cause) => write!(f, "{}", cause),
+            DescribeGlobalTableError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeGlobalTableError {}
 /// Errors returned by DescribeGlobalTableSettings
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeGlobalTableSettingsError {
     /// <p>The specified global table does not exist.</p>
     GlobalTableNotFound(String),
     /// <p>An error occurred on the server side.</p>
     InternalServerError(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeGlobalTableSettingsError {
@@ -3745,17 +3871,24 @@ impl DescribeGlobalTableSettingsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "GlobalTableNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DescribeGlobalTableSettingsError::GlobalTableNotFound(err.msg),
+                        &res,
                     )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DescribeGlobalTableSettingsError::InternalServerError(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        DescribeGlobalTableSettingsError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -3771,15 +3904,19 @@ impl fmt::Display for DescribeGlobalTableSettingsError {
             DescribeGlobalTableSettingsError::InternalServerError(ref cause) => {
                 write!(f, "{}", cause)
             }
+            DescribeGlobalTableSettingsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeGlobalTableSettingsError {}
 /// Errors returned by DescribeLimits
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeLimitsError {
     /// <p>An error occurred on the server side.</p>
     InternalServerError(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeLimitsError {
@@ -3787,10 +3924,13 @@ impl DescribeLimitsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(DescribeLimitsError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        DescribeLimitsError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DescribeLimitsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3801,17 +3941,21 @@ impl fmt::Display for DescribeLimitsError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             DescribeLimitsError::InternalServerError(ref cause) => write!(f, "{}", cause),
+            DescribeLimitsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeLimitsError {}
 /// Errors returned by DescribeTable
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeTableError {
     /// <p>An error occurred on the server side.</p>
     InternalServerError(String),
     /// <p>The operation tried to access a nonexistent table or index. The resource might not be specified correctly, or its status might not be <code>ACTIVE</code>.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeTableError {
@@ -3819,13 +3963,19 @@ impl DescribeTableError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(DescribeTableError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        DescribeTableError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeTableError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        DescribeTableError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DescribeTableError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3837,17 +3987,21 @@ impl fmt::Display for DescribeTableError {
         match *self {
             DescribeTableError::InternalServerError(ref cause) => write!(f, "{}", cause),
             DescribeTableError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            DescribeTableError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeTableError {}
 /// Errors returned by DescribeTableReplicaAutoScaling
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeTableReplicaAutoScalingError {
     /// <p>An error occurred on the server side.</p>
     InternalServerError(String),
     /// <p>The operation tried to access a nonexistent table or index. The resource might not be specified correctly, or its status might not be <code>ACTIVE</code>.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeTableReplicaAutoScalingError {
@@ -3857,17 +4011,24 @@ impl DescribeTableReplicaAutoScalingError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DescribeTableReplicaAutoScalingError::InternalServerError(err.msg),
+                        &res,
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DescribeTableReplicaAutoScalingError::ResourceNotFound(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        DescribeTableReplicaAutoScalingError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -3883,17 +4044,23 @@ impl fmt::Display for DescribeTableReplicaAutoScalingError {
             DescribeTableReplicaAutoScalingError::ResourceNotFound(ref cause) => {
                 write!(f, "{}", cause)
             }
+            DescribeTableReplicaAutoScalingError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for DescribeTableReplicaAutoScalingError {}
 /// Errors returned by DescribeTimeToLive
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeTimeToLiveError {
     /// <p>An error occurred on the server side.</p>
     InternalServerError(String),
     /// <p>The operation tried to access a nonexistent table or index. The resource might not be specified correctly, or its status might not be <code>ACTIVE</code>.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeTimeToLiveError {
@@ -3901,15 +4068,19 @@ impl DescribeTimeToLiveError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(DescribeTimeToLiveError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeTimeToLiveError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeTimeToLiveError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        DescribeTimeToLiveError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DescribeTimeToLiveError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3921,12 +4092,14 @@ impl fmt::Display for DescribeTimeToLiveError {
         match *self {
             DescribeTimeToLiveError::InternalServerError(ref cause) => write!(f, "{}", cause),
             DescribeTimeToLiveError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            DescribeTimeToLiveError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeTimeToLiveError {}
 /// Errors returned by GetItem
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetItemError {
     /// <p>An error occurred on the server side.</p>
     InternalServerError(String),
@@ -3936,6 +4109,8 @@ pub enum GetItemError {
     RequestLimitExceeded(String),
     /// <p>The operation tried to access a nonexistent table or index. The resource might not be specified correctly, or its status might not be <code>ACTIVE</code>.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetItemError {
@@ -3943,21 +4118,22 @@ impl GetItemError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(GetItemError::InternalServerError(err.msg))
+                    return RusotoError::service(GetItemError::InternalServerError(err.msg), &res)
                 }
                 "ProvisionedThroughputExceededException" => {
-                    return RusotoError::Service(GetItemError::ProvisionedThroughputExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetItemError::ProvisionedThroughputExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "RequestLimitExceeded" => {
-                    return RusotoError::Service(GetItemError::RequestLimitExceeded(err.msg))
+                    return RusotoError::service(GetItemError::RequestLimitExceeded(err.msg), &res)
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetItemError::ResourceNotFound(err.msg))
+                    return RusotoError::service(GetItemError::ResourceNotFound(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetItemError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3971,15 +4147,19 @@ impl fmt::Display for GetItemError {
             GetItemError::ProvisionedThroughputExceeded(ref cause) => write!(f, "{}", cause),
             GetItemError::RequestLimitExceeded(ref cause) => write!(f, "{}", cause),
             GetItemError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            GetItemError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetItemError {}
 /// Errors returned by ListBackups
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListBackupsError {
     /// <p>An error occurred on the server side.</p>
     InternalServerError(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListBackupsError {
@@ -3987,10 +4167,13 @@ impl ListBackupsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(ListBackupsError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        ListBackupsError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListBackupsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -4001,17 +4184,21 @@ impl fmt::Display for ListBackupsError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             ListBackupsError::InternalServerError(ref cause) => write!(f, "{}", cause),
+            ListBackupsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListBackupsError {}
 /// Errors returned by ListContributorInsights
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListContributorInsightsError {
     /// <p>An error occurred on the server side.</p>
     InternalServerError(String),
     /// <p>The operation tried to access a nonexistent table or index. The resource might not be specified correctly, or its status might not be <code>ACTIVE</code>.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListContributorInsightsError {
@@ -4019,17 +4206,19 @@ impl ListContributorInsightsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(ListContributorInsightsError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListContributorInsightsError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListContributorInsightsError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListContributorInsightsError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListContributorInsightsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -4041,15 +4230,19 @@ impl fmt::Display for ListContributorInsightsError {
         match *self {
             ListContributorInsightsError::InternalServerError(ref cause) => write!(f, "{}", cause),
             ListContributorInsightsError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            ListContributorInsightsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListContributorInsightsError {}
 /// Errors returned by ListGlobalTables
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListGlobalTablesError {
     /// <p>An error occurred on the server side.</p>
     InternalServerError(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListGlobalTablesError {
@@ -4057,12 +4250,13 @@ impl ListGlobalTablesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(ListGlobalTablesError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListGlobalTablesError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListGlobalTablesError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -4073,15 +4267,19 @@ impl fmt::Display for ListGlobalTablesError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             ListGlobalTablesError::InternalServerError(ref cause) => write!(f, "{}", cause),
+            ListGlobalTablesError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListGlobalTablesError {}
 /// Errors returned by ListTables
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListTablesError {
     /// <p>An error occurred on the server side.</p>
     InternalServerError(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListTablesError {
@@ -4089,10 +4287,13 @@ impl ListTablesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(ListTablesError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        ListTablesError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListTablesError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -4103,17 +4304,21 @@ impl fmt::Display for ListTablesError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             ListTablesError::InternalServerError(ref cause) => write!(f, "{}", cause),
+            ListTablesError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListTablesError {}
 /// Errors returned by ListTagsOfResource
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListTagsOfResourceError {
     /// <p>An error occurred on the server side.</p>
     InternalServerError(String),
     /// <p>The operation tried to access a nonexistent table or index. The resource might not be specified correctly, or its status might not be <code>ACTIVE</code>.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListTagsOfResourceError {
@@ -4121,15 +4326,19 @@ impl ListTagsOfResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(ListTagsOfResourceError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListTagsOfResourceError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListTagsOfResourceError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        ListTagsOfResourceError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListTagsOfResourceError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -4141,12 +4350,14 @@ impl fmt::Display for ListTagsOfResourceError {
         match *self {
             ListTagsOfResourceError::InternalServerError(ref cause) => write!(f, "{}", cause),
             ListTagsOfResourceError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            ListTagsOfResourceError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListTagsOfResourceError {}
 /// Errors returned by PutItem
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum PutItemError {
     /// <p>A condition specified in the operation could not be evaluated.</p>
     ConditionalCheckFailed(String),
@@ -4162,6 +4373,8 @@ pub enum PutItemError {
     ResourceNotFound(String),
     /// <p>Operation was rejected because there is an ongoing transaction for the item.</p>
     TransactionConflict(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl PutItemError {
@@ -4169,32 +4382,37 @@ impl PutItemError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConditionalCheckFailedException" => {
-                    return RusotoError::Service(PutItemError::ConditionalCheckFailed(err.msg))
+                    return RusotoError::service(
+                        PutItemError::ConditionalCheckFailed(err.msg),
+                        &res,
+                    )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(PutItemError::InternalServerError(err.msg))
+                    return RusotoError::service(PutItemError::InternalServerError(err.msg), &res)
                 }
                 "ItemCollectionSizeLimitExceededException" => {
-                    return RusotoError::Service(PutItemError::ItemCollectionSizeLimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        PutItemError::ItemCollectionSizeLimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "ProvisionedThroughputExceededException" => {
-                    return RusotoError::Service(PutItemError::ProvisionedThroughputExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        PutItemError::ProvisionedThroughputExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "RequestLimitExceeded" => {
-                    return RusotoError::Service(PutItemError::RequestLimitExceeded(err.msg))
+                    return RusotoError::service(PutItemError::RequestLimitExceeded(err.msg), &res)
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(PutItemError::ResourceNotFound(err.msg))
+                    return RusotoError::service(PutItemError::ResourceNotFound(err.msg), &res)
                 }
                 "TransactionConflictException" => {
-                    return RusotoError::Service(PutItemError::TransactionConflict(err.msg))
+                    return RusotoError::service(PutItemError::TransactionConflict(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(PutItemError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -4211,12 +4429,14 @@ impl fmt::Display for PutItemError {
             PutItemError::RequestLimitExceeded(ref cause) => write!(f, "{}", cause),
             PutItemError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             PutItemError::TransactionConflict(ref cause) => write!(f, "{}", cause),
+            PutItemError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for PutItemError {}
 /// Errors returned by Query
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum QueryError {
     /// <p>An error occurred on the server side.</p>
     InternalServerError(String),
@@ -4226,6 +4446,8 @@ pub enum QueryError {
     RequestLimitExceeded(String),
     /// <p>The operation tried to access a nonexistent table or index. The resource might not be specified correctly, or its status might not be <code>ACTIVE</code>.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl QueryError {
@@ -4233,19 +4455,22 @@ impl QueryError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(QueryError::InternalServerError(err.msg))
+                    return RusotoError::service(QueryError::InternalServerError(err.msg), &res)
                 }
                 "ProvisionedThroughputExceededException" => {
-                    return RusotoError::Service(QueryError::ProvisionedThroughputExceeded(err.msg))
+                    return RusotoError::service(
+                        QueryError::ProvisionedThroughputExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "RequestLimitExceeded" => {
-                    return RusotoError::Service(QueryError::RequestLimitExceeded(err.msg))
+                    return RusotoError::service(QueryError::RequestLimitExceeded(err.msg), &res)
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(QueryError::ResourceNotFound(err.msg))
+                    return RusotoError::service(QueryError::ResourceNotFound(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(QueryError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -4259,12 +4484,14 @@ impl fmt::Display for QueryError {
             QueryError::ProvisionedThroughputExceeded(ref cause) => write!(f, "{}", cause),
             QueryError::RequestLimitExceeded(ref cause) => write!(f, "{}", cause),
             QueryError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            QueryError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for QueryError {}
 /// Errors returned by RestoreTableFromBackup
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum RestoreTableFromBackupError {
     /// <p>There is another ongoing conflicting backup control plane operation on the table. The backup is either being created, deleted or restored to a table.</p>
     BackupInUse(String),
@@ -4278,6 +4505,8 @@ pub enum RestoreTableFromBackupError {
     TableAlreadyExists(String),
     /// <p>A target table with the specified name is either being created or deleted. </p>
     TableInUse(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl RestoreTableFromBackupError {
@@ -4285,33 +4514,43 @@ impl RestoreTableFromBackupError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "BackupInUseException" => {
-                    return RusotoError::Service(RestoreTableFromBackupError::BackupInUse(err.msg))
+                    return RusotoError::service(
+                        RestoreTableFromBackupError::BackupInUse(err.msg),
+                        &res,
+                    )
                 }
                 "BackupNotFoundException" => {
-                    return RusotoError::Service(RestoreTableFromBackupError::BackupNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        RestoreTableFromBackupError::BackupNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(RestoreTableFromBackupError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        RestoreTableFromBackupError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(RestoreTableFromBackupError::LimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        RestoreTableFromBackupError::LimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "TableAlreadyExistsException" => {
-                    return RusotoError::Service(RestoreTableFromBackupError::TableAlreadyExists(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        RestoreTableFromBackupError::TableAlreadyExists(err.msg),
+                        &res,
+                    )
                 }
                 "TableInUseException" => {
-                    return RusotoError::Service(RestoreTableFromBackupError::TableInUse(err.msg))
+                    return RusotoError::service(
+                        RestoreTableFromBackupError::TableInUse(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(RestoreTableFromBackupError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -4327,12 +4566,14 @@ impl fmt::Display for RestoreTableFromBackupError {
             RestoreTableFromBackupError::LimitExceeded(ref cause) => write!(f, "{}", cause),
             RestoreTableFromBackupError::TableAlreadyExists(ref cause) => write!(f, "{}", cause),
             RestoreTableFromBackupError::TableInUse(ref cause) => write!(f, "{}", cause),
+            RestoreTableFromBackupError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for RestoreTableFromBackupError {}
 /// Errors returned by RestoreTableToPointInTime
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum RestoreTableToPointInTimeError {
     /// <p>An error occurred on the server side.</p>
     InternalServerError(String),
@@ -4348,6 +4589,8 @@ pub enum RestoreTableToPointInTimeError {
     TableInUse(String),
     /// <p>A source table with the name <code>TableName</code> does not currently exist within the subscriber's account.</p>
     TableNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl RestoreTableToPointInTimeError {
@@ -4355,42 +4598,51 @@ impl RestoreTableToPointInTimeError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         RestoreTableToPointInTimeError::InternalServerError(err.msg),
+                        &res,
                     )
                 }
                 "InvalidRestoreTimeException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         RestoreTableToPointInTimeError::InvalidRestoreTime(err.msg),
+                        &res,
                     )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(RestoreTableToPointInTimeError::LimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        RestoreTableToPointInTimeError::LimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "PointInTimeRecoveryUnavailableException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         RestoreTableToPointInTimeError::PointInTimeRecoveryUnavailable(err.msg),
+                        &res,
                     )
                 }
                 "TableAlreadyExistsException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         RestoreTableToPointInTimeError::TableAlreadyExists(err.msg),
+                        &res,
                     )
                 }
                 "TableInUseException" => {
-                    return RusotoError::Service(RestoreTableToPointInTimeError::TableInUse(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        RestoreTableToPointInTimeError::TableInUse(err.msg),
+                        &res,
+                    )
                 }
                 "TableNotFoundException" => {
-                    return RusotoError::Service(RestoreTableToPointInTimeError::TableNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        RestoreTableToPointInTimeError::TableNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(RestoreTableToPointInTimeError::Unhandled, &res)
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -4411,12 +4663,14 @@ impl fmt::Display for RestoreTableToPointInTimeError {
             RestoreTableToPointInTimeError::TableAlreadyExists(ref cause) => write!(f, "{}", cause),
             RestoreTableToPointInTimeError::TableInUse(ref cause) => write!(f, "{}", cause),
             RestoreTableToPointInTimeError::TableNotFound(ref cause) => write!(f, "{}", cause),
+            RestoreTableToPointInTimeError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for RestoreTableToPointInTimeError {}
 /// Errors returned by Scan
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ScanError {
     /// <p>An error occurred on the server side.</p>
     InternalServerError(String),
@@ -4426,6 +4680,8 @@ pub enum ScanError {
     RequestLimitExceeded(String),
     /// <p>The operation tried to access a nonexistent table or index. The resource might not be specified correctly, or its status might not be <code>ACTIVE</code>.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ScanError {
@@ -4433,19 +4689,22 @@ impl ScanError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(ScanError::InternalServerError(err.msg))
+                    return RusotoError::service(ScanError::InternalServerError(err.msg), &res)
                 }
                 "ProvisionedThroughputExceededException" => {
-                    return RusotoError::Service(ScanError::ProvisionedThroughputExceeded(err.msg))
+                    return RusotoError::service(
+                        ScanError::ProvisionedThroughputExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "RequestLimitExceeded" => {
-                    return RusotoError::Service(ScanError::RequestLimitExceeded(err.msg))
+                    return RusotoError::service(ScanError::RequestLimitExceeded(err.msg), &res)
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ScanError::ResourceNotFound(err.msg))
+                    return RusotoError::service(ScanError::ResourceNotFound(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ScanError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -4459,12 +4718,14 @@ impl fmt::Display for ScanError {
             ScanError::ProvisionedThroughputExceeded(ref cause) => write!(f, "{}", cause),
             ScanError::RequestLimitExceeded(ref cause) => write!(f, "{}", cause),
             ScanError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            ScanError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ScanError {}
 /// Errors returned by TagResource
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum TagResourceError {
     /// <p>An error occurred on the server side.</p>
     InternalServerError(String),
@@ -4474,6 +4735,8 @@ pub enum TagResourceError {
     ResourceInUse(String),
     /// <p>The operation tried to access a nonexistent table or index. The resource might not be specified correctly, or its status might not be <code>ACTIVE</code>.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl TagResourceError {
@@ -4481,19 +4744,22 @@ impl TagResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(TagResourceError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        TagResourceError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(TagResourceError::LimitExceeded(err.msg))
+                    return RusotoError::service(TagResourceError::LimitExceeded(err.msg), &res)
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(TagResourceError::ResourceInUse(err.msg))
+                    return RusotoError::service(TagResourceError::ResourceInUse(err.msg), &res)
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(TagResourceError::ResourceNotFound(err.msg))
+                    return RusotoError::service(TagResourceError::ResourceNotFound(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(TagResourceError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -4507,12 +4773,14 @@ impl fmt::Display for TagResourceError {
             TagResourceError::LimitExceeded(ref cause) => write!(f, "{}", cause),
             TagResourceError::ResourceInUse(ref cause) => write!(f, "{}", cause),
             TagResourceError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            TagResourceError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for TagResourceError {}
 /// Errors returned by TransactGetItems
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum TransactGetItemsError {
     /// <p>An error occurred on the server side.</p>
     InternalServerError(String),
@@ -4524,6 +4792,8 @@ pub enum TransactGetItemsError {
     ResourceNotFound(String),
     /// <p><p>The entire transaction request was canceled.</p> <p>DynamoDB cancels a <code>TransactWriteItems</code> request under the following circumstances:</p> <ul> <li> <p>A condition in one of the condition expressions is not met.</p> </li> <li> <p>A table in the <code>TransactWriteItems</code> request is in a different account or region.</p> </li> <li> <p>More than one action in the <code>TransactWriteItems</code> operation targets the same item.</p> </li> <li> <p>There is insufficient provisioned capacity for the transaction to be completed.</p> </li> <li> <p>An item size becomes too large (larger than 400 KB), or a local secondary index (LSI) becomes too large, or a similar validation error occurs because of changes made by the transaction.</p> </li> <li> <p>There is a user error, such as an invalid data format.</p> </li> </ul> <p>DynamoDB cancels a <code>TransactGetItems</code> request under the following circumstances:</p> <ul> <li> <p>There is an ongoing <code>TransactGetItems</code> operation that conflicts with a concurrent <code>PutItem</code>, <code>UpdateItem</code>, <code>DeleteItem</code> or <code>TransactWriteItems</code> request. In this case the <code>TransactGetItems</code> operation fails with a <code>TransactionCanceledException</code>.</p> </li> <li> <p>A table in the <code>TransactGetItems</code> request is in a different account or region.</p> </li> <li> <p>There is insufficient provisioned capacity for the transaction to be completed.</p> </li> <li> <p>There is a user error, such as an invalid data format.</p> </li> </ul> <note> <p>If using Java, DynamoDB lists the cancellation reasons on the <code>CancellationReasons</code> property. This property is not set for other languages. Transaction cancellation reasons are ordered in the order of requested items, if an item has no error it will have <code>NONE</code> code and <code>Null</code> message.</p> </note> <p>Cancellation reason codes and possible error messages:</p> <ul> <li> <p>No Errors:</p> <ul> <li> <p>Code: <code>NONE</code> </p> </li> <li> <p>Message: <code>null</code> </p> </li> </ul> </li> <li> <p>Conditional Check Failed:</p> <ul> <li> <p>Code: <code>ConditionalCheckFailed</code> </p> </li> <li> <p>Message: The conditional request failed. </p> </li> </ul> </li> <li> <p>Item Collection Size Limit Exceeded:</p> <ul> <li> <p>Code: <code>ItemCollectionSizeLimitExceeded</code> </p> </li> <li> <p>Message: Collection size exceeded.</p> </li> </ul> </li> <li> <p>Transaction Conflict:</p> <ul> <li> <p>Code: <code>TransactionConflict</code> </p> </li> <li> <p>Message: Transaction is ongoing for the item.</p> </li> </ul> </li> <li> <p>Provisioned Throughput Exceeded:</p> <ul> <li> <p>Code: <code>ProvisionedThroughputExceeded</code> </p> </li> <li> <p>Messages: </p> <ul> <li> <p>The level of configured provisioned throughput for the table was exceeded. Consider increasing your provisioning level with the UpdateTable API.</p> <note> <p>This Message is received when provisioned throughput is exceeded is on a provisioned DynamoDB table.</p> </note> </li> <li> <p>The level of configured provisioned throughput for one or more global secondary indexes of the table was exceeded. Consider increasing your provisioning level for the under-provisioned global secondary indexes with the UpdateTable API.</p> <note> <p>This message is returned when provisioned throughput is exceeded is on a provisioned GSI.</p> </note> </li> </ul> </li> </ul> </li> <li> <p>Throttling Error:</p> <ul> <li> <p>Code: <code>ThrottlingError</code> </p> </li> <li> <p>Messages: </p> <ul> <li> <p>Throughput exceeds the current capacity of your table or index. DynamoDB is automatically scaling your table or index so please try again shortly. If exceptions persist, check if you have a hot key: https://docs.aws.amazon.com/amazondynamodb/latest/developerguide/bp-partition-key-design.html.</p> <note> <p>This message is returned when writes get throttled on an On-Demand table as DynamoDB is automatically scaling the table.</p> </note> </li> <li> <p>Throughput exceeds the current capacity for one or more global secondary indexes. DynamoDB is automatically scaling your index so please try again shortly.</p> <note> <p>This message is returned when when writes get throttled on an On-Demand GSI as DynamoDB is automatically scaling the GSI.</p> </note> </li> </ul> </li> </ul> </li> <li> <p>Validation Error:</p> <ul> <li> <p>Code: <code>ValidationError</code> </p> </li> <li> <p>Messages: </p> <ul> <li> <p>One or more parameter values were invalid.</p> </li> <li> <p>The update expression attempted to update the secondary index key beyond allowed size limits.</p> </li> <li> <p>The update expression attempted to update the secondary index key to unsupported type.</p> </li> <li> <p>An operand in the update expression has an incorrect data type.</p> </li> <li> <p>Item size to update has exceeded the maximum allowed size.</p> </li> <li> <p>Number overflow. Attempting to store a number with magnitude larger than supported range.</p> </li> <li> <p>Type mismatch for attribute to update.</p> </li> <li> <p>Nesting Levels have exceeded supported limits.</p> </li> <li> <p>The document path provided in the update expression is invalid for update.</p> </li> <li> <p>The provided expression refers to an attribute that does not exist in the item.</p> </li> </ul> </li> </ul> </li> </ul></p>
     TransactionCanceled(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl TransactGetItemsError {
@@ -4531,30 +4801,37 @@ impl TransactGetItemsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(TransactGetItemsError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        TransactGetItemsError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "ProvisionedThroughputExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         TransactGetItemsError::ProvisionedThroughputExceeded(err.msg),
+                        &res,
                     )
                 }
                 "RequestLimitExceeded" => {
-                    return RusotoError::Service(TransactGetItemsError::RequestLimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        TransactGetItemsError::RequestLimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(TransactGetItemsError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        TransactGetItemsError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "TransactionCanceledException" => {
-                    return RusotoError::Service(TransactGetItemsError::TransactionCanceled(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        TransactGetItemsError::TransactionCanceled(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(TransactGetItemsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -4571,12 +4848,14 @@ impl fmt::Display for TransactGetItemsError {
             TransactGetItemsError::RequestLimitExceeded(ref cause) => write!(f, "{}", cause),
             TransactGetItemsError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             TransactGetItemsError::TransactionCanceled(ref cause) => write!(f, "{}", cause),
+            TransactGetItemsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for TransactGetItemsError {}
 /// Errors returned by TransactWriteItems
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum TransactWriteItemsError {
     /// <p>DynamoDB rejected the request because you retried a request with a different payload but with an idempotent token that was already used.</p>
     IdempotentParameterMismatch(String),
@@ -4592,6 +4871,8 @@ pub enum TransactWriteItemsError {
     TransactionCanceled(String),
     /// <p>The transaction with the given request token is already in progress.</p>
     TransactionInProgress(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl TransactWriteItemsError {
@@ -4599,40 +4880,49 @@ impl TransactWriteItemsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "IdempotentParameterMismatchException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         TransactWriteItemsError::IdempotentParameterMismatch(err.msg),
+                        &res,
                     )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(TransactWriteItemsError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        TransactWriteItemsError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "ProvisionedThroughputExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         TransactWriteItemsError::ProvisionedThroughputExceeded(err.msg),
+                        &res,
                     )
                 }
                 "RequestLimitExceeded" => {
-                    return RusotoError::Service(TransactWriteItemsError::RequestLimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        TransactWriteItemsError::RequestLimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(TransactWriteItemsError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        TransactWriteItemsError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "TransactionCanceledException" => {
-                    return RusotoError::Service(TransactWriteItemsError::TransactionCanceled(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        TransactWriteItemsError::TransactionCanceled(err.msg),
+                        &res,
+                    )
                 }
                 "TransactionInProgressException" => {
-                    return RusotoError::Service(TransactWriteItemsError::TransactionInProgress(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        TransactWriteItemsError::TransactionInProgress(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(TransactWriteItemsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -4653,12 +4943,14 @@ impl fmt::Display for TransactWriteItemsError {
             TransactWriteItemsError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             TransactWriteItemsError::TransactionCanceled(ref cause) => write!(f, "{}", cause),
             TransactWriteItemsError::TransactionInProgress(ref cause) => write!(f, "{}", cause),
+            TransactWriteItemsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for TransactWriteItemsError {}
 /// Errors returned by UntagResource
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UntagResourceError {
     /// <p>An error occurred on the server side.</p>
     InternalServerError(String),
@@ -4668,6 +4960,8 @@ pub enum UntagResourceError {
     ResourceInUse(String),
     /// <p>The operation tried to access a nonexistent table or index. The resource might not be specified correctly, or its status might not be <code>ACTIVE</code>.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UntagResourceError {
@@ -4675,19 +4969,25 @@ impl UntagResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(UntagResourceError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        UntagResourceError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(UntagResourceError::LimitExceeded(err.msg))
+                    return RusotoError::service(UntagResourceError::LimitExceeded(err.msg), &res)
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(UntagResourceError::ResourceInUse(err.msg))
+                    return RusotoError::service(UntagResourceError::ResourceInUse(err.msg), &res)
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UntagResourceError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        UntagResourceError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UntagResourceError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -4701,12 +5001,14 @@ impl fmt::Display for UntagResourceError {
             UntagResourceError::LimitExceeded(ref cause) => write!(f, "{}", cause),
             UntagResourceError::ResourceInUse(ref cause) => write!(f, "{}", cause),
             UntagResourceError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            UntagResourceError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for UntagResourceError {}
 /// Errors returned by UpdateContinuousBackups
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdateContinuousBackupsError {
     /// <p>Backups have not yet been enabled for this table.</p>
     ContinuousBackupsUnavailable(String),
@@ -4714,6 +5016,8 @@ pub enum UpdateContinuousBackupsError {
     InternalServerError(String),
     /// <p>A source table with the name <code>TableName</code> does not currently exist within the subscriber's account.</p>
     TableNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdateContinuousBackupsError {
@@ -4721,22 +5025,25 @@ impl UpdateContinuousBackupsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ContinuousBackupsUnavailableException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         UpdateContinuousBackupsError::ContinuousBackupsUnavailable(err.msg),
+                        &res,
                     )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(UpdateContinuousBackupsError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateContinuousBackupsError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "TableNotFoundException" => {
-                    return RusotoError::Service(UpdateContinuousBackupsError::TableNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateContinuousBackupsError::TableNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UpdateContinuousBackupsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -4751,17 +5058,21 @@ impl fmt::Display for UpdateContinuousBackupsError {
             }
             UpdateContinuousBackupsError::InternalServerError(ref cause) => write!(f, "{}", cause),
             UpdateContinuousBackupsError::TableNotFound(ref cause) => write!(f, "{}", cause),
+            UpdateContinuousBackupsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for UpdateContinuousBackupsError {}
 /// Errors returned by UpdateContributorInsights
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdateContributorInsightsError {
     /// <p>An error occurred on the server side.</p>
     InternalServerError(String),
     /// <p>The operation tried to access a nonexistent table or index. The resource might not be specified correctly, or its status might not be <code>ACTIVE</code>.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdateContributorInsightsError {
@@ -4769,17 +5080,21 @@ impl UpdateContributorInsightsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         UpdateContributorInsightsError::InternalServerError(err.msg),
+                        &res,
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateContributorInsightsError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateContributorInsightsError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(UpdateContributorInsightsError::Unhandled, &res)
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -4793,12 +5108,14 @@ impl fmt::Display for UpdateContributorInsightsError {
                 write!(f, "{}", cause)
             }
             UpdateContributorInsightsError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            UpdateContributorInsightsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for UpdateContributorInsightsError {}
 /// Errors returned by UpdateGlobalTable
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdateGlobalTableError {
     /// <p>The specified global table does not exist.</p>
     GlobalTableNotFound(String),
@@ -4810,6 +5127,8 @@ pub enum UpdateGlobalTableError {
     ReplicaNotFound(String),
     /// <p>A source table with the name <code>TableName</code> does not currently exist within the subscriber's account.</p>
     TableNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdateGlobalTableError {
@@ -4817,28 +5136,37 @@ impl UpdateGlobalTableError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "GlobalTableNotFoundException" => {
-                    return RusotoError::Service(UpdateGlobalTableError::GlobalTableNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateGlobalTableError::GlobalTableNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(UpdateGlobalTableError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateGlobalTableError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "ReplicaAlreadyExistsException" => {
-                    return RusotoError::Service(UpdateGlobalTableError::ReplicaAlreadyExists(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateGlobalTableError::ReplicaAlreadyExists(err.msg),
+                        &res,
+                    )
                 }
                 "ReplicaNotFoundException" => {
-                    return RusotoError::Service(UpdateGlobalTableError::ReplicaNotFound(err.msg))
+                    return RusotoError::service(
+                        UpdateGlobalTableError::ReplicaNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "TableNotFoundException" => {
-                    return RusotoError::Service(UpdateGlobalTableError::TableNotFound(err.msg))
+                    return RusotoError::service(
+                        UpdateGlobalTableError::TableNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UpdateGlobalTableError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -4853,12 +5181,14 @@ impl fmt::Display for UpdateGlobalTableError {
             UpdateGlobalTableError::ReplicaAlreadyExists(ref cause) => write!(f, "{}", cause),
             UpdateGlobalTableError::ReplicaNotFound(ref cause) => write!(f, "{}", cause),
             UpdateGlobalTableError::TableNotFound(ref cause) => write!(f, "{}", cause),
+            UpdateGlobalTableError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for UpdateGlobalTableError {}
 /// Errors returned by UpdateGlobalTableSettings
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdateGlobalTableSettingsError {
     /// <p>The specified global table does not exist.</p>
     GlobalTableNotFound(String),
@@ -4872,6 +5202,8 @@ pub enum UpdateGlobalTableSettingsError {
     ReplicaNotFound(String),
     /// <p>The operation conflicts with the resource's availability. For example, you attempted to recreate an existing table, or tried to delete a table currently in the <code>CREATING</code> state.</p>
     ResourceInUse(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdateGlobalTableSettingsError {
@@ -4879,37 +5211,45 @@ impl UpdateGlobalTableSettingsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "GlobalTableNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         UpdateGlobalTableSettingsError::GlobalTableNotFound(err.msg),
+                        &res,
                     )
                 }
                 "IndexNotFoundException" => {
-                    return RusotoError::Service(UpdateGlobalTableSettingsError::IndexNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateGlobalTableSettingsError::IndexNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         UpdateGlobalTableSettingsError::InternalServerError(err.msg),
+                        &res,
                     )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(UpdateGlobalTableSettingsError::LimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateGlobalTableSettingsError::LimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "ReplicaNotFoundException" => {
-                    return RusotoError::Service(UpdateGlobalTableSettingsError::ReplicaNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateGlobalTableSettingsError::ReplicaNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(UpdateGlobalTableSettingsError::ResourceInUse(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateGlobalTableSettingsError::ResourceInUse(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(UpdateGlobalTableSettingsError::Unhandled, &res)
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -4929,12 +5269,14 @@ impl fmt::Display for UpdateGlobalTableSettingsError {
             UpdateGlobalTableSettingsError::LimitExceeded(ref cause) => write!(f, "{}", cause),
             UpdateGlobalTableSettingsError::ReplicaNotFound(ref cause) => write!(f, "{}", cause),
             UpdateGlobalTableSettingsError::ResourceInUse(ref cause) => write!(f, "{}", cause),
+            UpdateGlobalTableSettingsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for UpdateGlobalTableSettingsError {}
 /// Errors returned by UpdateItem
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdateItemError {
     /// <p>A condition specified in the operation could not be evaluated.</p>
     ConditionalCheckFailed(String),
@@ -4950,6 +5292,8 @@ pub enum UpdateItemError {
     ResourceNotFound(String),
     /// <p>Operation was rejected because there is an ongoing transaction for the item.</p>
     TransactionConflict(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdateItemError {
@@ -4957,32 +5301,46 @@ impl UpdateItemError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConditionalCheckFailedException" => {
-                    return RusotoError::Service(UpdateItemError::ConditionalCheckFailed(err.msg))
+                    return RusotoError::service(
+                        UpdateItemError::ConditionalCheckFailed(err.msg),
+                        &res,
+                    )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(UpdateItemError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        UpdateItemError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "ItemCollectionSizeLimitExceededException" => {
-                    return RusotoError::Service(UpdateItemError::ItemCollectionSizeLimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateItemError::ItemCollectionSizeLimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "ProvisionedThroughputExceededException" => {
-                    return RusotoError::Service(UpdateItemError::ProvisionedThroughputExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateItemError::ProvisionedThroughputExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "RequestLimitExceeded" => {
-                    return RusotoError::Service(UpdateItemError::RequestLimitExceeded(err.msg))
+                    return RusotoError::service(
+                        UpdateItemError::RequestLimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateItemError::ResourceNotFound(err.msg))
+                    return RusotoError::service(UpdateItemError::ResourceNotFound(err.msg), &res)
                 }
                 "TransactionConflictException" => {
-                    return RusotoError::Service(UpdateItemError::TransactionConflict(err.msg))
+                    return RusotoError::service(
+                        UpdateItemError::TransactionConflict(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UpdateItemError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -4999,12 +5357,14 @@ impl fmt::Display for UpdateItemError {
             UpdateItemError::RequestLimitExceeded(ref cause) => write!(f, "{}", cause),
             UpdateItemError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             UpdateItemError::TransactionConflict(ref cause) => write!(f, "{}", cause),
+            UpdateItemError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for UpdateItemError {}
 /// Errors returned by UpdateTable
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdateTableError {
     /// <p>An error occurred on the server side.</p>
     InternalServerError(String),
@@ -5014,6 +5374,8 @@ pub enum UpdateTableError {
     ResourceInUse(String),
     /// <p>The operation tried to access a nonexistent table or index. The resource might not be specified correctly, or its status might not be <code>ACTIVE</code>.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdateTableError {
@@ -5021,19 +5383,22 @@ impl UpdateTableError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(UpdateTableError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        UpdateTableError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(UpdateTableError::LimitExceeded(err.msg))
+                    return RusotoError::service(UpdateTableError::LimitExceeded(err.msg), &res)
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(UpdateTableError::ResourceInUse(err.msg))
+                    return RusotoError::service(UpdateTableError::ResourceInUse(err.msg), &res)
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateTableError::ResourceNotFound(err.msg))
+                    return RusotoError::service(UpdateTableError::ResourceNotFound(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UpdateTableError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -5047,12 +5412,14 @@ impl fmt::Display for UpdateTableError {
             UpdateTableError::LimitExceeded(ref cause) => write!(f, "{}", cause),
             UpdateTableError::ResourceInUse(ref cause) => write!(f, "{}", cause),
             UpdateTableError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            UpdateTableError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for UpdateTableError {}
 /// Errors returned by UpdateTableReplicaAutoScaling
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdateTableReplicaAutoScalingError {
     /// <p>An error occurred on the server side.</p>
     InternalServerError(String),
@@ -5062,6 +5429,8 @@ pub enum UpdateTableReplicaAutoScalingError {
     ResourceInUse(String),
     /// <p>The operation tried to access a nonexistent table or index. The resource might not be specified correctly, or its status might not be <code>ACTIVE</code>.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdateTableReplicaAutoScalingError {
@@ -5071,27 +5440,36 @@ impl UpdateTableReplicaAutoScalingError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         UpdateTableReplicaAutoScalingError::InternalServerError(err.msg),
+                        &res,
                     )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(UpdateTableReplicaAutoScalingError::LimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateTableReplicaAutoScalingError::LimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(UpdateTableReplicaAutoScalingError::ResourceInUse(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateTableReplicaAutoScalingError::ResourceInUse(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         UpdateTableReplicaAutoScalingError::ResourceNotFound(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        UpdateTableReplicaAutoScalingError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -5109,12 +5487,16 @@ impl fmt::Display for UpdateTableReplicaAutoScalingError {
             UpdateTableReplicaAutoScalingError::ResourceNotFound(ref cause) => {
                 write!(f, "{}", cause)
             }
+            UpdateTableReplicaAutoScalingError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for UpdateTableReplicaAutoScalingError {}
 /// Errors returned by UpdateTimeToLive
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdateTimeToLiveError {
     /// <p>An error occurred on the server side.</p>
     InternalServerError(String),
@@ -5124,6 +5506,8 @@ pub enum UpdateTimeToLiveError {
     ResourceInUse(String),
     /// <p>The operation tried to access a nonexistent table or index. The resource might not be specified correctly, or its status might not be <code>ACTIVE</code>.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdateTimeToLiveError {
@@ -5131,21 +5515,31 @@ impl UpdateTimeToLiveError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(UpdateTimeToLiveError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateTimeToLiveError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(UpdateTimeToLiveError::LimitExceeded(err.msg))
+                    return RusotoError::service(
+                        UpdateTimeToLiveError::LimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(UpdateTimeToLiveError::ResourceInUse(err.msg))
+                    return RusotoError::service(
+                        UpdateTimeToLiveError::ResourceInUse(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateTimeToLiveError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        UpdateTimeToLiveError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UpdateTimeToLiveError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -5159,6 +5553,7 @@ impl fmt::Display for UpdateTimeToLiveError {
             UpdateTimeToLiveError::LimitExceeded(ref cause) => write!(f, "{}", cause),
             UpdateTimeToLiveError::ResourceInUse(ref cause) => write!(f, "{}", cause),
             UpdateTimeToLiveError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            UpdateTimeToLiveError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
@@ -5418,6 +5813,7 @@ impl DynamoDbClient {
     /// Creates a client backed by the default tokio event loop.
     ///
     /// The client will use the default credentials provider and tls client.
+    #[cfg(not(target_arch = "wasm32"))]
     pub fn new(region: region::Region) -> DynamoDbClient {
         DynamoDbClient {
             client: Client::shared(),
@@ -5443,259 +5839,542 @@ impl DynamoDbClient {
     pub fn new_with_client(client: Client, region: region::Region) -> DynamoDbClient {
         DynamoDbClient { client, region }
     }
+
+    /// Returns a builder of a client, setting any of its region, credentials provider,
+    /// dispatcher, retry policy, endpoint and timeouts.
+    pub fn builder() -> rusoto_core::ClientBuilder<DynamoDbClient> {
+        rusoto_core::ClientBuilder::new(DynamoDbClient::new_with_client)
+    }
+
+    /// Returns a clone of this client with the overrides of `config` applied to its
+    /// operations, sharing its connections.
+    pub fn customize(&self, config: &rusoto_core::OperationConfig) -> DynamoDbClient {
+        DynamoDbClient {
+            client: self.client.customize(config),
+            region: config.resolve_region(&self.region),
+        }
+    }
 }
 
-#[async_trait]
-impl DynamoDb for DynamoDbClient {
-    /// <p>The <code>BatchGetItem</code> operation returns the attributes of one or more items from one or more tables. You identify requested items by primary key.</p> <p>A single operation can retrieve up to 16 MB of data, which can contain as many as 100 items. <code>BatchGetItem</code> returns a partial result if the response size limit is exceeded, the table's provisioned throughput is exceeded, or an internal processing failure occurs. If a partial result is returned, the operation returns a value for <code>UnprocessedKeys</code>. You can use this value to retry the operation starting with the next item to get.</p> <important> <p>If you request more than 100 items, <code>BatchGetItem</code> returns a <code>ValidationException</code> with the message "Too many items requested for the BatchGetItem call."</p> </important> <p>For example, if you ask to retrieve 100 items, but each individual item is 300 KB in size, the system returns 52 items (so as not to exceed the 16 MB limit). It also returns an appropriate <code>UnprocessedKeys</code> value so you can get the next page of results. If desired, your application can include its own logic to assemble the pages of results into one dataset.</p> <p>If <i>none</i> of the items can be processed due to insufficient provisioned throughput on all of the tables in the request, then <code>BatchGetItem</code> returns a <code>ProvisionedThroughputExceededException</code>. If <i>at least one</i> of the items is successfully processed, then <code>BatchGetItem</code> completes successfully, while returning the keys of the unread items in <code>UnprocessedKeys</code>.</p> <important> <p>If DynamoDB returns any unprocessed items, you should retry the batch operation on those items. However, <i>we strongly recommend that you use an exponential backoff algorithm</i>. If you retry the batch operation immediately, the underlying read or write requests can still fail due to throttling on the individual tables. If you delay the batch operation using exponential backoff, the individual requests in the batch are much more likely to succeed.</p> <p>For more information, see <a href="https://docs.aws.amazon.com/amazondynamodb/latest/developerguide/ErrorHandling.html#BatchOperations">Batch Operations and Error Handling</a> in the <i>Amazon DynamoDB Developer Guide</i>.</p> </important> <p>By default, <code>BatchGetItem</code> performs eventually consistent reads on every table in the request. If you want strongly consistent reads instead, you can set <code>ConsistentRead</code> to <code>true</code> for any or all tables.</p> <p>In order to minimize response latency, <code>BatchGetItem</code> retrieves items in parallel.</p> <p>When designing your application, keep in mind that DynamoDB does not return items in any particular order. To help parse the response by item, include the primary key values for the items in your request in the <code>ProjectionExpression</code> parameter.</p> <p>If a requested item does not exist, it is not returned in the result. Requests for nonexistent items consume the minimum read capacity units according to the type of read. For more information, see <a href="https://docs.aws.amazon.com/amazondynamodb/latest/developerguide/WorkingWithTables.html#CapacityUnitCalculations">Working with Tables</a> in the <i>Amazon DynamoDB Developer Guide</i>.</p>
-    async fn batch_get_item(
+impl DynamoDbClient {
+    /// Calls `batch_get_item`, also returning the metadata of the response.
+    pub async fn batch_get_item_with_metadata(
         &self,
         input: BatchGetItemInput,
-    ) -> Result<BatchGetItemOutput, RusotoError<BatchGetItemError>> {
+    ) -> Result<(BatchGetItemOutput, ResponseMetadata), RusotoError<BatchGetItemError>> {
+        let request = self.build_batch_get_item_request(input);
+
+        let mut response = self
+            .client
+            .sign_and_dispatch(request)
+            .await
+            .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
+        if response.status.is_success() {
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<BatchGetItemOutput, _>()?;
+            Ok((result, metadata))
+        } else {
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(BatchGetItemError::from_response(response))
+        }
+    }
+
+    /// Presigns the request `batch_get_item` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_batch_get_item(
+        &self,
+        presigner: &rusoto_core::Presigner,
+        input: BatchGetItemInput,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_batch_get_item_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_batch_get_item_request(&self, input: BatchGetItemInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "dynamodb", &self.region, "/");
+        request.set_operation("BatchGetItem");
 
         request.set_content_type("application/x-amz-json-1.0".to_owned());
         request.add_header("x-amz-target", "DynamoDB_20120810.BatchGetItem");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `batch_write_item`, also returning the metadata of the response.
+    pub async fn batch_write_item_with_metadata(
+        &self,
+        input: BatchWriteItemInput,
+    ) -> Result<(BatchWriteItemOutput, ResponseMetadata), RusotoError<BatchWriteItemError>> {
+        let request = self.build_batch_write_item_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<BatchGetItemOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<BatchWriteItemOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(BatchGetItemError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(BatchWriteItemError::from_response(response))
         }
     }
 
-    /// <p><p>The <code>BatchWriteItem</code> operation puts or deletes multiple items in one or more tables. A single call to <code>BatchWriteItem</code> can write up to 16 MB of data, which can comprise as many as 25 put or delete requests. Individual items to be written can be as large as 400 KB.</p> <note> <p> <code>BatchWriteItem</code> cannot update items. To update items, use the <code>UpdateItem</code> action.</p> </note> <p>The individual <code>PutItem</code> and <code>DeleteItem</code> operations specified in <code>BatchWriteItem</code> are atomic; however <code>BatchWriteItem</code> as a whole is not. If any requested operations fail because the table&#39;s provisioned throughput is exceeded or an internal processing failure occurs, the failed operations are returned in the <code>UnprocessedItems</code> response parameter. You can investigate and optionally resend the requests. Typically, you would call <code>BatchWriteItem</code> in a loop. Each iteration would check for unprocessed items and submit a new <code>BatchWriteItem</code> request with those unprocessed items until all items have been processed.</p> <p>If <i>none</i> of the items can be processed due to insufficient provisioned throughput on all of the tables in the request, then <code>BatchWriteItem</code> returns a <code>ProvisionedThroughputExceededException</code>.</p> <important> <p>If DynamoDB returns any unprocessed items, you should retry the batch operation on those items. However, <i>we strongly recommend that you use an exponential backoff algorithm</i>. If you retry the batch operation immediately, the underlying read or write requests can still fail due to throttling on the individual tables. If you delay the batch operation using exponential backoff, the individual requests in the batch are much more likely to succeed.</p> <p>For more information, see <a href="https://docs.aws.amazon.com/amazondynamodb/latest/developerguide/ErrorHandling.html#Programming.Errors.BatchOperations">Batch Operations and Error Handling</a> in the <i>Amazon DynamoDB Developer Guide</i>.</p> </important> <p>With <code>BatchWriteItem</code>, you can efficiently write or delete large amounts of data, such as from Amazon EMR, or copy data from another database into DynamoDB. In order to improve performance with these large-scale operations, <code>BatchWriteItem</code> does not behave in the same way as individual <code>PutItem</code> and <code>DeleteItem</code> calls would. For example, you cannot specify conditions on individual put and delete requests, and <code>BatchWriteItem</code> does not return deleted items in the response.</p> <p>If you use a programming language that supports concurrency, you can use threads to write items in parallel. Your application must include the necessary logic to manage the threads. With languages that don&#39;t support threading, you must update or delete the specified items one at a time. In both situations, <code>BatchWriteItem</code> performs the specified put and delete operations in parallel, giving you the power of the thread pool approach without having to introduce complexity into your application.</p> <p>Parallel processing reduces latency, but each specified put and delete request consumes the same number of write capacity units whether it is processed in parallel or not. Delete operations on nonexistent items consume one write capacity unit.</p> <p>If one or more of the following is true, DynamoDB rejects the entire batch write operation:</p> <ul> <li> <p>One or more tables specified in the <code>BatchWriteItem</code> request does not exist.</p> </li> <li> <p>Primary key attributes specified on an item in the request do not match those in the corresponding table&#39;s primary key schema.</p> </li> <li> <p>You try to perform multiple operations on the same item in the same <code>BatchWriteItem</code> request. For example, you cannot put and delete the same item in the same <code>BatchWriteItem</code> request. </p> </li> <li> <p> Your request contains at least two items with identical hash and range keys (which essentially is two put operations). </p> </li> <li> <p>There are more than 25 requests in the batch.</p> </li> <li> <p>Any individual item in a batch exceeds 400 KB.</p> </li> <li> <p>The total request size exceeds 16 MB.</p> </li> </ul></p>
-    async fn batch_write_item(
+    /// Presigns the request `batch_write_item` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_batch_write_item(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: BatchWriteItemInput,
-    ) -> Result<BatchWriteItemOutput, RusotoError<BatchWriteItemError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_batch_write_item_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_batch_write_item_request(&self, input: BatchWriteItemInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "dynamodb", &self.region, "/");
+        request.set_operation("BatchWriteItem");
 
         request.set_content_type("application/x-amz-json-1.0".to_owned());
         request.add_header("x-amz-target", "DynamoDB_20120810.BatchWriteItem");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `create_backup`, also returning the metadata of the response.
+    pub async fn create_backup_with_metadata(
+        &self,
+        input: CreateBackupInput,
+    ) -> Result<(CreateBackupOutput, ResponseMetadata), RusotoError<CreateBackupError>> {
+        let request = self.build_create_backup_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<BatchWriteItemOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<CreateBackupOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(BatchWriteItemError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(CreateBackupError::from_response(response))
         }
     }
 
-    /// <p><p>Creates a backup for an existing table.</p> <p> Each time you create an on-demand backup, the entire table data is backed up. There is no limit to the number of on-demand backups that can be taken. </p> <p> When you create an on-demand backup, a time marker of the request is cataloged, and the backup is created asynchronously, by applying all changes until the time of the request to the last full table snapshot. Backup requests are processed instantaneously and become available for restore within minutes. </p> <p>You can call <code>CreateBackup</code> at a maximum rate of 50 times per second.</p> <p>All backups in DynamoDB work without consuming any provisioned throughput on the table.</p> <p> If you submit a backup request on 2018-12-14 at 14:25:00, the backup is guaranteed to contain all data committed to the table up to 14:24:00, and data committed after 14:26:00 will not be. The backup might contain data modifications made between 14:24:00 and 14:26:00. On-demand backup does not support causal consistency. </p> <p> Along with data, the following are also included on the backups: </p> <ul> <li> <p>Global secondary indexes (GSIs)</p> </li> <li> <p>Local secondary indexes (LSIs)</p> </li> <li> <p>Streams</p> </li> <li> <p>Provisioned read and write capacity</p> </li> </ul></p>
-    async fn create_backup(
+    /// Presigns the request `create_backup` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_backup(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: CreateBackupInput,
-    ) -> Result<CreateBackupOutput, RusotoError<CreateBackupError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_backup_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_backup_request(&self, input: CreateBackupInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "dynamodb", &self.region, "/");
+        request.set_operation("CreateBackup");
 
         request.set_content_type("application/x-amz-json-1.0".to_owned());
         request.add_header("x-amz-target", "DynamoDB_20120810.CreateBackup");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `create_global_table`, also returning the metadata of the response.
+    pub async fn create_global_table_with_metadata(
+        &self,
+        input: CreateGlobalTableInput,
+    ) -> Result<(CreateGlobalTableOutput, ResponseMetadata), RusotoError<CreateGlobalTableError>>
+    {
+        let request = self.build_create_global_table_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<CreateBackupOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<CreateGlobalTableOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(CreateBackupError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(CreateGlobalTableError::from_response(response))
         }
     }
 
-    /// <p><p>Creates a global table from an existing table. A global table creates a replication relationship between two or more DynamoDB tables with the same table name in the provided Regions. </p> <note> <p>This method only applies to <a href="https://docs.aws.amazon.com/amazondynamodb/latest/developerguide/globaltables.V1.html">Version 2017.11.29</a> of global tables.</p> </note> <p>If you want to add a new replica table to a global table, each of the following conditions must be true:</p> <ul> <li> <p>The table must have the same primary key as all of the other replicas.</p> </li> <li> <p>The table must have the same name as all of the other replicas.</p> </li> <li> <p>The table must have DynamoDB Streams enabled, with the stream containing both the new and the old images of the item.</p> </li> <li> <p>None of the replica tables in the global table can contain any data.</p> </li> </ul> <p> If global secondary indexes are specified, then the following conditions must also be met: </p> <ul> <li> <p> The global secondary indexes must have the same name. </p> </li> <li> <p> The global secondary indexes must have the same hash key and sort key (if present). </p> </li> </ul> <important> <p> Write capacity settings should be set consistently across your replica tables and secondary indexes. DynamoDB strongly recommends enabling auto scaling to manage the write capacity settings for all of your global tables replicas and indexes. </p> <p> If you prefer to manage write capacity settings manually, you should provision equal replicated write capacity units to your replica tables. You should also provision equal replicated write capacity units to matching secondary indexes across your global table. </p> </important></p>
-    async fn create_global_table(
+    /// Presigns the request `create_global_table` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_global_table(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: CreateGlobalTableInput,
-    ) -> Result<CreateGlobalTableOutput, RusotoError<CreateGlobalTableError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_global_table_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_global_table_request(&self, input: CreateGlobalTableInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "dynamodb", &self.region, "/");
+        request.set_operation("CreateGlobalTable");
 
         request.set_content_type("application/x-amz-json-1.0".to_owned());
         request.add_header("x-amz-target", "DynamoDB_20120810.CreateGlobalTable");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `create_table`, also returning the metadata of the response.
+    pub async fn create_table_with_metadata(
+        &self,
+        input: CreateTableInput,
+    ) -> Result<(CreateTableOutput, ResponseMetadata), RusotoError<CreateTableError>> {
+        let request = self.build_create_table_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<CreateGlobalTableOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<CreateTableOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(CreateGlobalTableError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(CreateTableError::from_response(response))
         }
     }
 
-    /// <p>The <code>CreateTable</code> operation adds a new table to your account. In an AWS account, table names must be unique within each Region. That is, you can have two tables with same name if you create the tables in different Regions.</p> <p> <code>CreateTable</code> is an asynchronous operation. Upon receiving a <code>CreateTable</code> request, DynamoDB immediately returns a response with a <code>TableStatus</code> of <code>CREATING</code>. After the table is created, DynamoDB sets the <code>TableStatus</code> to <code>ACTIVE</code>. You can perform read and write operations only on an <code>ACTIVE</code> table. </p> <p>You can optionally define secondary indexes on the new table, as part of the <code>CreateTable</code> operation. If you want to create multiple tables with secondary indexes on them, you must create the tables sequentially. Only one table with secondary indexes can be in the <code>CREATING</code> state at any given time.</p> <p>You can use the <code>DescribeTable</code> action to check the table status.</p>
-    async fn create_table(
+    /// Presigns the request `create_table` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_table(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: CreateTableInput,
-    ) -> Result<CreateTableOutput, RusotoError<CreateTableError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_table_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_table_request(&self, input: CreateTableInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "dynamodb", &self.region, "/");
+        request.set_operation("CreateTable");
 
         request.set_content_type("application/x-amz-json-1.0".to_owned());
         request.add_header("x-amz-target", "DynamoDB_20120810.CreateTable");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `delete_backup`, also returning the metadata of the response.
+    pub async fn delete_backup_with_metadata(
+        &self,
+        input: DeleteBackupInput,
+    ) -> Result<(DeleteBackupOutput, ResponseMetadata), RusotoError<DeleteBackupError>> {
+        let request = self.build_delete_backup_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<CreateTableOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DeleteBackupOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(CreateTableError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DeleteBackupError::from_response(response))
         }
     }
 
-    /// <p>Deletes an existing backup of a table.</p> <p>You can call <code>DeleteBackup</code> at a maximum rate of 10 times per second.</p>
-    async fn delete_backup(
+    /// Presigns the request `delete_backup` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_backup(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteBackupInput,
-    ) -> Result<DeleteBackupOutput, RusotoError<DeleteBackupError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_backup_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_backup_request(&self, input: DeleteBackupInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "dynamodb", &self.region, "/");
+        request.set_operation("DeleteBackup");
 
         request.set_content_type("application/x-amz-json-1.0".to_owned());
         request.add_header("x-amz-target", "DynamoDB_20120810.DeleteBackup");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `delete_item`, also returning the metadata of the response.
+    pub async fn delete_item_with_metadata(
+        &self,
+        input: DeleteItemInput,
+    ) -> Result<(DeleteItemOutput, ResponseMetadata), RusotoError<DeleteItemError>> {
+        let request = self.build_delete_item_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<DeleteBackupOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DeleteItemOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteBackupError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DeleteItemError::from_response(response))
         }
     }
 
-    /// <p>Deletes a single item in a table by primary key. You can perform a conditional delete operation that deletes the item if it exists, or if it has an expected attribute value.</p> <p>In addition to deleting an item, you can also return the item's attribute values in the same operation, using the <code>ReturnValues</code> parameter.</p> <p>Unless you specify conditions, the <code>DeleteItem</code> is an idempotent operation; running it multiple times on the same item or attribute does <i>not</i> result in an error response.</p> <p>Conditional deletes are useful for deleting items only if specific conditions are met. If those conditions are met, DynamoDB performs the delete. Otherwise, the item is not deleted.</p>
-    async fn delete_item(
+    /// Presigns the request `delete_item` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_item(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteItemInput,
-    ) -> Result<DeleteItemOutput, RusotoError<DeleteItemError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_item_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_item_request(&self, input: DeleteItemInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "dynamodb", &self.region, "/");
+        request.set_operation("DeleteItem");
 
         request.set_content_type("application/x-amz-json-1.0".to_owned());
         request.add_header("x-amz-target", "DynamoDB_20120810.DeleteItem");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `delete_table`, also returning the metadata of the response.
+    pub async fn delete_table_with_metadata(
+        &self,
+        input: DeleteTableInput,
+    ) -> Result<(DeleteTableOutput, ResponseMetadata), RusotoError<DeleteTableError>> {
+        let request = self.build_delete_table_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<DeleteItemOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DeleteTableOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteItemError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DeleteTableError::from_response(response))
         }
     }
 
-    /// <p>The <code>DeleteTable</code> operation deletes a table and all of its items. After a <code>DeleteTable</code> request, the specified table is in the <code>DELETING</code> state until DynamoDB completes the deletion. If the table is in the <code>ACTIVE</code> state, you can delete it. If a table is in <code>CREATING</code> or <code>UPDATING</code> states, then DynamoDB returns a <code>ResourceInUseException</code>. If the specified table does not exist, DynamoDB returns a <code>ResourceNotFoundException</code>. If table is already in the <code>DELETING</code> state, no error is returned. </p> <note> <p>DynamoDB might continue to accept data read and write operations, such as <code>GetItem</code> and <code>PutItem</code>, on a table in the <code>DELETING</code> state until the table deletion is complete.</p> </note> <p>When you delete a table, any indexes on that table are also deleted.</p> <p>If you have DynamoDB Streams enabled on the table, then the corresponding stream on that table goes into the <code>DISABLED</code> state, and the stream is automatically deleted after 24 hours.</p> <p>Use the <code>DescribeTable</code> action to check the status of the table. </p>
-    async fn delete_table(
+    /// Presigns the request `delete_table` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_table(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteTableInput,
-    ) -> Result<DeleteTableOutput, RusotoError<DeleteTableError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_table_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_table_request(&self, input: DeleteTableInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "dynamodb", &self.region, "/");
+        request.set_operation("DeleteTable");
 
         request.set_content_type("application/x-amz-json-1.0".to_owned());
         request.add_header("x-amz-target", "DynamoDB_20120810.DeleteTable");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `describe_backup`, also returning the metadata of the response.
+    pub async fn describe_backup_with_metadata(
+        &self,
+        input: DescribeBackupInput,
+    ) -> Result<(DescribeBackupOutput, ResponseMetadata), RusotoError<DescribeBackupError>> {
+        let request = self.build_describe_backup_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<DeleteTableOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DescribeBackupOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteTableError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DescribeBackupError::from_response(response))
         }
     }
 
-    /// <p>Describes an existing backup of a table.</p> <p>You can call <code>DescribeBackup</code> at a maximum rate of 10 times per second.</p>
-    async fn describe_backup(
+    /// Presigns the request `describe_backup` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_backup(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeBackupInput,
-    ) -> Result<DescribeBackupOutput, RusotoError<DescribeBackupError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_backup_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_backup_request(&self, input: DescribeBackupInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "dynamodb", &self.region, "/");
+        request.set_operation("DescribeBackup");
 
         request.set_content_type("application/x-amz-json-1.0".to_owned());
         request.add_header("x-amz-target", "DynamoDB_20120810.DescribeBackup");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `describe_continuous_backups`, also returning the metadata of the response.
+    pub async fn describe_continuous_backups_with_metadata(
+        &self,
+        input: DescribeContinuousBackupsInput,
+    ) -> Result<
+        (DescribeContinuousBackupsOutput, ResponseMetadata),
+        RusotoError<DescribeContinuousBackupsError>,
+    > {
+        let request = self.build_describe_continuous_backups_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<DescribeBackupOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DescribeContinuousBackupsOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeBackupError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DescribeContinuousBackupsError::from_response(response))
         }
     }
 
-    /// <p>Checks the status of continuous backups and point in time recovery on the specified table. Continuous backups are <code>ENABLED</code> on all tables at table creation. If point in time recovery is enabled, <code>PointInTimeRecoveryStatus</code> will be set to ENABLED.</p> <p> After continuous backups and point in time recovery are enabled, you can restore to any point in time within <code>EarliestRestorableDateTime</code> and <code>LatestRestorableDateTime</code>. </p> <p> <code>LatestRestorableDateTime</code> is typically 5 minutes before the current time. You can restore your table to any point in time during the last 35 days. </p> <p>You can call <code>DescribeContinuousBackups</code> at a maximum rate of 10 times per second.</p>
-    async fn describe_continuous_backups(
+    /// Presigns the request `describe_continuous_backups` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_continuous_backups(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeContinuousBackupsInput,
-    ) -> Result<DescribeContinuousBackupsOutput, RusotoError<DescribeContinuousBackupsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_continuous_backups_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_continuous_backups_request(
+        &self,
+        input: DescribeContinuousBackupsInput,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "dynamodb", &self.region, "/");
+        request.set_operation("DescribeContinuousBackups");
 
         request.set_content_type("application/x-amz-json-1.0".to_owned());
         request.add_header(
@@ -5705,29 +6384,58 @@ impl DynamoDb for DynamoDbClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `describe_contributor_insights`, also returning the metadata of the response.
+    pub async fn describe_contributor_insights_with_metadata(
+        &self,
+        input: DescribeContributorInsightsInput,
+    ) -> Result<
+        (DescribeContributorInsightsOutput, ResponseMetadata),
+        RusotoError<DescribeContributorInsightsError>,
+    > {
+        let request = self.build_describe_contributor_insights_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DescribeContinuousBackupsOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DescribeContributorInsightsOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeContinuousBackupsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DescribeContributorInsightsError::from_response(response))
         }
     }
 
-    /// <p>Returns information about contributor insights, for a given table or global secondary index.</p>
-    async fn describe_contributor_insights(
+    /// Presigns the request `describe_contributor_insights` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_contributor_insights(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeContributorInsightsInput,
-    ) -> Result<DescribeContributorInsightsOutput, RusotoError<DescribeContributorInsightsError>>
-    {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_contributor_insights_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_contributor_insights_request(
+        &self,
+        input: DescribeContributorInsightsInput,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "dynamodb", &self.region, "/");
+        request.set_operation("DescribeContributorInsights");
 
         request.set_content_type("application/x-amz-json-1.0".to_owned());
         request.add_header(
@@ -5737,83 +6445,164 @@ impl DynamoDb for DynamoDbClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `describe_endpoints`, also returning the metadata of the response.
+    pub async fn describe_endpoints_with_metadata(
+        &self,
+    ) -> Result<(DescribeEndpointsResponse, ResponseMetadata), RusotoError<DescribeEndpointsError>>
+    {
+        let request = self.build_describe_endpoints_request();
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DescribeContributorInsightsOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DescribeEndpointsResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeContributorInsightsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DescribeEndpointsError::from_response(response))
         }
     }
 
-    /// <p>Returns the regional endpoint information.</p>
-    async fn describe_endpoints(
+    /// Presigns the request `describe_endpoints` would send with `presigner` instead of sending it.
+    pub async fn presign_describe_endpoints(
         &self,
-    ) -> Result<DescribeEndpointsResponse, RusotoError<DescribeEndpointsError>> {
+        presigner: &rusoto_core::Presigner,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_endpoints_request();
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_endpoints_request(&self) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "dynamodb", &self.region, "/");
+        request.set_operation("DescribeEndpoints");
 
         request.set_content_type("application/x-amz-json-1.0".to_owned());
         request.add_header("x-amz-target", "DynamoDB_20120810.DescribeEndpoints");
         request.set_payload(Some(bytes::Bytes::from_static(b"{}")));
 
+        request
+    }
+
+    /// Calls `describe_global_table`, also returning the metadata of the response.
+    pub async fn describe_global_table_with_metadata(
+        &self,
+        input: DescribeGlobalTableInput,
+    ) -> Result<(DescribeGlobalTableOutput, ResponseMetadata), RusotoError<DescribeGlobalTableError>>
+    {
+        let request = self.build_describe_global_table_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DescribeEndpointsResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DescribeGlobalTableOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeEndpointsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DescribeGlobalTableError::from_response(response))
         }
     }
 
-    /// <p><p>Returns information about the specified global table.</p> <note> <p>This method only applies to <a href="https://docs.aws.amazon.com/amazondynamodb/latest/developerguide/globaltables.V1.html">Version 2017.11.29</a> of global tables.</p> </note></p>
-    async fn describe_global_table(
+    /// Presigns the request `describe_global_table` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_global_table(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeGlobalTableInput,
-    ) -> Result<DescribeGlobalTableOutput, RusotoError<DescribeGlobalTableError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_global_table_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_global_table_request(
+        &self,
+        input: DescribeGlobalTableInput,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "dynamodb", &self.region, "/");
+        request.set_operation("DescribeGlobalTable");
 
         request.set_content_type("application/x-amz-json-1.0".to_owned());
         request.add_header("x-amz-target", "DynamoDB_20120810.DescribeGlobalTable");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `describe_global_table_settings`, also returning the metadata of the response.
+    pub async fn describe_global_table_settings_with_metadata(
+        &self,
+        input: DescribeGlobalTableSettingsInput,
+    ) -> Result<
+        (DescribeGlobalTableSettingsOutput, ResponseMetadata),
+        RusotoError<DescribeGlobalTableSettingsError>,
+    > {
+        let request = self.build_describe_global_table_settings_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DescribeGlobalTableOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DescribeGlobalTableSettingsOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeGlobalTableError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DescribeGlobalTableSettingsError::from_response(response))
         }
     }
 
-    /// <p><p>Describes Region-specific settings for a global table.</p> <note> <p>This method only applies to <a href="https://docs.aws.amazon.com/amazondynamodb/latest/developerguide/globaltables.V1.html">Version 2017.11.29</a> of global tables.</p> </note></p>
-    async fn describe_global_table_settings(
+    /// Presigns the request `describe_global_table_settings` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_global_table_settings(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeGlobalTableSettingsInput,
-    ) -> Result<DescribeGlobalTableSettingsOutput, RusotoError<DescribeGlobalTableSettingsError>>
-    {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_global_table_settings_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_global_table_settings_request(
+        &self,
+        input: DescribeGlobalTableSettingsInput,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "dynamodb", &self.region, "/");
+        request.set_operation("DescribeGlobalTableSettings");
 
         request.set_content_type("application/x-amz-json-1.0".to_owned());
         request.add_header(
@@ -5823,83 +6612,161 @@ impl DynamoDb for DynamoDbClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `describe_limits`, also returning the metadata of the response.
+    pub async fn describe_limits_with_metadata(
+        &self,
+    ) -> Result<(DescribeLimitsOutput, ResponseMetadata), RusotoError<DescribeLimitsError>> {
+        let request = self.build_describe_limits_request();
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DescribeGlobalTableSettingsOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DescribeLimitsOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeGlobalTableSettingsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DescribeLimitsError::from_response(response))
         }
     }
 
-    /// <p>Returns the current provisioned-capacity limits for your AWS account in a Region, both for the Region as a whole and for any one DynamoDB table that you create there.</p> <p>When you establish an AWS account, the account has initial limits on the maximum read capacity units and write capacity units that you can provision across all of your DynamoDB tables in a given Region. Also, there are per-table limits that apply when you create a table there. For more information, see <a href="https://docs.aws.amazon.com/amazondynamodb/latest/developerguide/Limits.html">Limits</a> page in the <i>Amazon DynamoDB Developer Guide</i>.</p> <p>Although you can increase these limits by filing a case at <a href="https://console.aws.amazon.com/support/home#/">AWS Support Center</a>, obtaining the increase is not instantaneous. The <code>DescribeLimits</code> action lets you write code to compare the capacity you are currently using to those limits imposed by your account so that you have enough time to apply for an increase before you hit a limit.</p> <p>For example, you could use one of the AWS SDKs to do the following:</p> <ol> <li> <p>Call <code>DescribeLimits</code> for a particular Region to obtain your current account limits on provisioned capacity there.</p> </li> <li> <p>Create a variable to hold the aggregate read capacity units provisioned for all your tables in that Region, and one to hold the aggregate write capacity units. Zero them both.</p> </li> <li> <p>Call <code>ListTables</code> to obtain a list of all your DynamoDB tables.</p> </li> <li> <p>For each table name listed by <code>ListTables</code>, do the following:</p> <ul> <li> <p>Call <code>DescribeTable</code> with the table name.</p> </li> <li> <p>Use the data returned by <code>DescribeTable</code> to add the read capacity units and write capacity units provisioned for the table itself to your variables.</p> </li> <li> <p>If the table has one or more global secondary indexes (GSIs), loop over these GSIs and add their provisioned capacity values to your variables as well.</p> </li> </ul> </li> <li> <p>Report the account limits for that Region returned by <code>DescribeLimits</code>, along with the total current provisioned capacity levels you have calculated.</p> </li> </ol> <p>This will let you see whether you are getting close to your account-level limits.</p> <p>The per-table limits apply only when you are creating a new table. They restrict the sum of the provisioned capacity of the new table itself and all its global secondary indexes.</p> <p>For existing tables and their GSIs, DynamoDB doesn't let you increase provisioned capacity extremely rapidly. But the only upper limit that applies is that the aggregate provisioned capacity over all your tables and GSIs cannot exceed either of the per-account limits.</p> <note> <p> <code>DescribeLimits</code> should only be called periodically. You can expect throttling errors if you call it more than once in a minute.</p> </note> <p>The <code>DescribeLimits</code> Request element has no content.</p>
-    async fn describe_limits(
+    /// Presigns the request `describe_limits` would send with `presigner` instead of sending it.
+    pub async fn presign_describe_limits(
         &self,
-    ) -> Result<DescribeLimitsOutput, RusotoError<DescribeLimitsError>> {
+        presigner: &rusoto_core::Presigner,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_limits_request();
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_limits_request(&self) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "dynamodb", &self.region, "/");
+        request.set_operation("DescribeLimits");
 
         request.set_content_type("application/x-amz-json-1.0".to_owned());
         request.add_header("x-amz-target", "DynamoDB_20120810.DescribeLimits");
         request.set_payload(Some(bytes::Bytes::from_static(b"{}")));
 
+        request
+    }
+
+    /// Calls `describe_table`, also returning the metadata of the response.
+    pub async fn describe_table_with_metadata(
+        &self,
+        input: DescribeTableInput,
+    ) -> Result<(DescribeTableOutput, ResponseMetadata), RusotoError<DescribeTableError>> {
+        let request = self.build_describe_table_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<DescribeLimitsOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DescribeTableOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeLimitsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DescribeTableError::from_response(response))
         }
     }
 
-    /// <p><p>Returns information about the table, including the current status of the table, when it was created, the primary key schema, and any indexes on the table.</p> <note> <p>If you issue a <code>DescribeTable</code> request immediately after a <code>CreateTable</code> request, DynamoDB might return a <code>ResourceNotFoundException</code>. This is because <code>DescribeTable</code> uses an eventually consistent query, and the metadata for your table might not be available at that moment. Wait for a few seconds, and then try the <code>DescribeTable</code> request again.</p> </note></p>
-    async fn describe_table(
+    /// Presigns the request `describe_table` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_table(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeTableInput,
-    ) -> Result<DescribeTableOutput, RusotoError<DescribeTableError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_table_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_table_request(&self, input: DescribeTableInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "dynamodb", &self.region, "/");
+        request.set_operation("DescribeTable");
 
         request.set_content_type("application/x-amz-json-1.0".to_owned());
         request.add_header("x-amz-target", "DynamoDB_20120810.DescribeTable");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `describe_table_replica_auto_scaling`, also returning the metadata of the response.
+    pub async fn describe_table_replica_auto_scaling_with_metadata(
+        &self,
+        input: DescribeTableReplicaAutoScalingInput,
+    ) -> Result<
+        (DescribeTableReplicaAutoScalingOutput, ResponseMetadata),
+        RusotoError<DescribeTableReplicaAutoScalingError>,
+    > {
+        let request = self.build_describe_table_replica_auto_scaling_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<DescribeTableOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DescribeTableReplicaAutoScalingOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeTableError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DescribeTableReplicaAutoScalingError::from_response(
+                response,
+            ))
         }
     }
 
-    /// <p><p>Describes auto scaling settings across replicas of the global table at once.</p> <note> <p>This method only applies to <a href="https://docs.aws.amazon.com/amazondynamodb/latest/developerguide/globaltables.V2.html">Version 2019.11.21</a> of global tables.</p> </note></p>
-    async fn describe_table_replica_auto_scaling(
+    /// Presigns the request `describe_table_replica_auto_scaling` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_table_replica_auto_scaling(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeTableReplicaAutoScalingInput,
-    ) -> Result<
-        DescribeTableReplicaAutoScalingOutput,
-        RusotoError<DescribeTableReplicaAutoScalingError>,
-    > {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_table_replica_auto_scaling_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_table_replica_auto_scaling_request(
+        &self,
+        input: DescribeTableReplicaAutoScalingInput,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "dynamodb", &self.region, "/");
+        request.set_operation("DescribeTableReplicaAutoScaling");
 
         request.set_content_type("application/x-amz-json-1.0".to_owned());
         request.add_header(
@@ -5909,301 +6776,593 @@ impl DynamoDb for DynamoDbClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `describe_time_to_live`, also returning the metadata of the response.
+    pub async fn describe_time_to_live_with_metadata(
+        &self,
+        input: DescribeTimeToLiveInput,
+    ) -> Result<(DescribeTimeToLiveOutput, ResponseMetadata), RusotoError<DescribeTimeToLiveError>>
+    {
+        let request = self.build_describe_time_to_live_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DescribeTableReplicaAutoScalingOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DescribeTimeToLiveOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeTableReplicaAutoScalingError::from_response(
-                response,
-            ))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DescribeTimeToLiveError::from_response(response))
         }
     }
 
-    /// <p>Gives a description of the Time to Live (TTL) status on the specified table. </p>
-    async fn describe_time_to_live(
+    /// Presigns the request `describe_time_to_live` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_time_to_live(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeTimeToLiveInput,
-    ) -> Result<DescribeTimeToLiveOutput, RusotoError<DescribeTimeToLiveError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_time_to_live_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_time_to_live_request(&self, input: DescribeTimeToLiveInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "dynamodb", &self.region, "/");
+        request.set_operation("DescribeTimeToLive");
 
         request.set_content_type("application/x-amz-json-1.0".to_owned());
         request.add_header("x-amz-target", "DynamoDB_20120810.DescribeTimeToLive");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `get_item`, also returning the metadata of the response.
+    pub async fn get_item_with_metadata(
+        &self,
+        input: GetItemInput,
+    ) -> Result<(GetItemOutput, ResponseMetadata), RusotoError<GetItemError>> {
+        let request = self.build_get_item_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DescribeTimeToLiveOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result =
+                proto::json::ResponsePayload::new(&response).deserialize::<GetItemOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeTimeToLiveError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(GetItemError::from_response(response))
         }
     }
 
-    /// <p>The <code>GetItem</code> operation returns a set of attributes for the item with the given primary key. If there is no matching item, <code>GetItem</code> does not return any data and there will be no <code>Item</code> element in the response.</p> <p> <code>GetItem</code> provides an eventually consistent read by default. If your application requires a strongly consistent read, set <code>ConsistentRead</code> to <code>true</code>. Although a strongly consistent read might take more time than an eventually consistent read, it always returns the last updated value.</p>
-    async fn get_item(
+    /// Presigns the request `get_item` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_item(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetItemInput,
-    ) -> Result<GetItemOutput, RusotoError<GetItemError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_item_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_item_request(&self, input: GetItemInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "dynamodb", &self.region, "/");
+        request.set_operation("GetItem");
 
         request.set_content_type("application/x-amz-json-1.0".to_owned());
         request.add_header("x-amz-target", "DynamoDB_20120810.GetItem");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `list_backups`, also returning the metadata of the response.
+    pub async fn list_backups_with_metadata(
+        &self,
+        input: ListBackupsInput,
+    ) -> Result<(ListBackupsOutput, ResponseMetadata), RusotoError<ListBackupsError>> {
+        let request = self.build_list_backups_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<GetItemOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ListBackupsOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(GetItemError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ListBackupsError::from_response(response))
         }
     }
 
-    /// <p>List backups associated with an AWS account. To list backups for a given table, specify <code>TableName</code>. <code>ListBackups</code> returns a paginated list of results with at most 1 MB worth of items in a page. You can also specify a limit for the maximum number of entries to be returned in a page. </p> <p>In the request, start time is inclusive, but end time is exclusive. Note that these limits are for the time at which the original backup was requested.</p> <p>You can call <code>ListBackups</code> a maximum of five times per second.</p>
-    async fn list_backups(
+    /// Presigns the request `list_backups` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_backups(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListBackupsInput,
-    ) -> Result<ListBackupsOutput, RusotoError<ListBackupsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_backups_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_backups_request(&self, input: ListBackupsInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "dynamodb", &self.region, "/");
+        request.set_operation("ListBackups");
 
         request.set_content_type("application/x-amz-json-1.0".to_owned());
         request.add_header("x-amz-target", "DynamoDB_20120810.ListBackups");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `list_contributor_insights`, also returning the metadata of the response.
+    pub async fn list_contributor_insights_with_metadata(
+        &self,
+        input: ListContributorInsightsInput,
+    ) -> Result<
+        (ListContributorInsightsOutput, ResponseMetadata),
+        RusotoError<ListContributorInsightsError>,
+    > {
+        let request = self.build_list_contributor_insights_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<ListBackupsOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ListContributorInsightsOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ListBackupsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ListContributorInsightsError::from_response(response))
         }
     }
 
-    /// <p>Returns a list of ContributorInsightsSummary for a table and all its global secondary indexes.</p>
-    async fn list_contributor_insights(
+    /// Presigns the request `list_contributor_insights` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_contributor_insights(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListContributorInsightsInput,
-    ) -> Result<ListContributorInsightsOutput, RusotoError<ListContributorInsightsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_contributor_insights_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_contributor_insights_request(
+        &self,
+        input: ListContributorInsightsInput,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "dynamodb", &self.region, "/");
+        request.set_operation("ListContributorInsights");
 
         request.set_content_type("application/x-amz-json-1.0".to_owned());
         request.add_header("x-amz-target", "DynamoDB_20120810.ListContributorInsights");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `list_global_tables`, also returning the metadata of the response.
+    pub async fn list_global_tables_with_metadata(
+        &self,
+        input: ListGlobalTablesInput,
+    ) -> Result<(ListGlobalTablesOutput, ResponseMetadata), RusotoError<ListGlobalTablesError>>
+    {
+        let request = self.build_list_global_tables_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListContributorInsightsOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ListGlobalTablesOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ListContributorInsightsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ListGlobalTablesError::from_response(response))
         }
     }
 
-    /// <p><p>Lists all global tables that have a replica in the specified Region.</p> <note> <p>This method only applies to <a href="https://docs.aws.amazon.com/amazondynamodb/latest/developerguide/globaltables.V1.html">Version 2017.11.29</a> of global tables.</p> </note></p>
-    async fn list_global_tables(
+    /// Presigns the request `list_global_tables` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_global_tables(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListGlobalTablesInput,
-    ) -> Result<ListGlobalTablesOutput, RusotoError<ListGlobalTablesError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_global_tables_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_global_tables_request(&self, input: ListGlobalTablesInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "dynamodb", &self.region, "/");
+        request.set_operation("ListGlobalTables");
 
         request.set_content_type("application/x-amz-json-1.0".to_owned());
         request.add_header("x-amz-target", "DynamoDB_20120810.ListGlobalTables");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `list_tables`, also returning the metadata of the response.
+    pub async fn list_tables_with_metadata(
+        &self,
+        input: ListTablesInput,
+    ) -> Result<(ListTablesOutput, ResponseMetadata), RusotoError<ListTablesError>> {
+        let request = self.build_list_tables_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<ListGlobalTablesOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ListTablesOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ListGlobalTablesError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ListTablesError::from_response(response))
         }
     }
 
-    /// <p>Returns an array of table names associated with the current account and endpoint. The output from <code>ListTables</code> is paginated, with each page returning a maximum of 100 table names.</p>
-    async fn list_tables(
+    /// Presigns the request `list_tables` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_tables(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListTablesInput,
-    ) -> Result<ListTablesOutput, RusotoError<ListTablesError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_tables_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_tables_request(&self, input: ListTablesInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "dynamodb", &self.region, "/");
+        request.set_operation("ListTables");
 
         request.set_content_type("application/x-amz-json-1.0".to_owned());
         request.add_header("x-amz-target", "DynamoDB_20120810.ListTables");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `list_tags_of_resource`, also returning the metadata of the response.
+    pub async fn list_tags_of_resource_with_metadata(
+        &self,
+        input: ListTagsOfResourceInput,
+    ) -> Result<(ListTagsOfResourceOutput, ResponseMetadata), RusotoError<ListTagsOfResourceError>>
+    {
+        let request = self.build_list_tags_of_resource_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<ListTablesOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ListTagsOfResourceOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ListTablesError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ListTagsOfResourceError::from_response(response))
         }
     }
 
-    /// <p>List all tags on an Amazon DynamoDB resource. You can call ListTagsOfResource up to 10 times per second, per account.</p> <p>For an overview on tagging DynamoDB resources, see <a href="https://docs.aws.amazon.com/amazondynamodb/latest/developerguide/Tagging.html">Tagging for DynamoDB</a> in the <i>Amazon DynamoDB Developer Guide</i>.</p>
-    async fn list_tags_of_resource(
+    /// Presigns the request `list_tags_of_resource` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_tags_of_resource(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListTagsOfResourceInput,
-    ) -> Result<ListTagsOfResourceOutput, RusotoError<ListTagsOfResourceError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_tags_of_resource_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_tags_of_resource_request(&self, input: ListTagsOfResourceInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "dynamodb", &self.region, "/");
+        request.set_operation("ListTagsOfResource");
 
         request.set_content_type("application/x-amz-json-1.0".to_owned());
         request.add_header("x-amz-target", "DynamoDB_20120810.ListTagsOfResource");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `put_item`, also returning the metadata of the response.
+    pub async fn put_item_with_metadata(
+        &self,
+        input: PutItemInput,
+    ) -> Result<(PutItemOutput, ResponseMetadata), RusotoError<PutItemError>> {
+        let request = self.build_put_item_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListTagsOfResourceOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result =
+                proto::json::ResponsePayload::new(&response).deserialize::<PutItemOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ListTagsOfResourceError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(PutItemError::from_response(response))
         }
     }
 
-    /// <p>Creates a new item, or replaces an old item with a new item. If an item that has the same primary key as the new item already exists in the specified table, the new item completely replaces the existing item. You can perform a conditional put operation (add a new item if one with the specified primary key doesn't exist), or replace an existing item if it has certain attribute values. You can return the item's attribute values in the same operation, using the <code>ReturnValues</code> parameter.</p> <important> <p>This topic provides general information about the <code>PutItem</code> API.</p> <p>For information on how to call the <code>PutItem</code> API using the AWS SDK in specific languages, see the following:</p> <ul> <li> <p> <a href="http://docs.aws.amazon.com/goto/aws-cli/dynamodb-2012-08-10/PutItem"> PutItem in the AWS Command Line Interface</a> </p> </li> <li> <p> <a href="http://docs.aws.amazon.com/goto/DotNetSDKV3/dynamodb-2012-08-10/PutItem"> PutItem in the AWS SDK for .NET</a> </p> </li> <li> <p> <a href="http://docs.aws.amazon.com/goto/SdkForCpp/dynamodb-2012-08-10/PutItem"> PutItem in the AWS SDK for C++</a> </p> </li> <li> <p> <a href="http://docs.aws.amazon.com/goto/SdkForGoV1/dynamodb-2012-08-10/PutItem"> PutItem in the AWS SDK for Go</a> </p> </li> <li> <p> <a href="http://docs.aws.amazon.com/goto/SdkForJava/dynamodb-2012-08-10/PutItem"> PutItem in the AWS SDK for Java</a> </p> </li> <li> <p> <a href="http://docs.aws.amazon.com/goto/AWSJavaScriptSDK/dynamodb-2012-08-10/PutItem"> PutItem in the AWS SDK for JavaScript</a> </p> </li> <li> <p> <a href="http://docs.aws.amazon.com/goto/SdkForPHPV3/dynamodb-2012-08-10/PutItem"> PutItem in the AWS SDK for PHP V3</a> </p> </li> <li> <p> <a href="http://docs.aws.amazon.com/goto/boto3/dynamodb-2012-08-10/PutItem"> PutItem in the AWS SDK for Python</a> </p> </li> <li> <p> <a href="http://docs.aws.amazon.com/goto/SdkForRubyV2/dynamodb-2012-08-10/PutItem"> PutItem in the AWS SDK for Ruby V2</a> </p> </li> </ul> </important> <p>When you add an item, the primary key attributes are the only required attributes. Attribute values cannot be null. String and Binary type attributes must have lengths greater than zero. Set type attributes cannot be empty. Requests with empty values will be rejected with a <code>ValidationException</code> exception.</p> <note> <p>To prevent a new item from replacing an existing item, use a conditional expression that contains the <code>attribute_not_exists</code> function with the name of the attribute being used as the partition key for the table. Since every record must contain that attribute, the <code>attribute_not_exists</code> function will only succeed if no matching item exists.</p> </note> <p>For more information about <code>PutItem</code>, see <a href="https://docs.aws.amazon.com/amazondynamodb/latest/developerguide/WorkingWithItems.html">Working with Items</a> in the <i>Amazon DynamoDB Developer Guide</i>.</p>
-    async fn put_item(
+    /// Presigns the request `put_item` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_put_item(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: PutItemInput,
-    ) -> Result<PutItemOutput, RusotoError<PutItemError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_put_item_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_put_item_request(&self, input: PutItemInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "dynamodb", &self.region, "/");
+        request.set_operation("PutItem");
 
         request.set_content_type("application/x-amz-json-1.0".to_owned());
         request.add_header("x-amz-target", "DynamoDB_20120810.PutItem");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `query`, also returning the metadata of the response.
+    pub async fn query_with_metadata(
+        &self,
+        input: QueryInput,
+    ) -> Result<(QueryOutput, ResponseMetadata), RusotoError<QueryError>> {
+        let request = self.build_query_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<PutItemOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result =
+                proto::json::ResponsePayload::new(&response).deserialize::<QueryOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(PutItemError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(QueryError::from_response(response))
         }
     }
 
-    /// <p>The <code>Query</code> operation finds items based on primary key values. You can query any table or secondary index that has a composite primary key (a partition key and a sort key). </p> <p>Use the <code>KeyConditionExpression</code> parameter to provide a specific value for the partition key. The <code>Query</code> operation will return all of the items from the table or index with that partition key value. You can optionally narrow the scope of the <code>Query</code> operation by specifying a sort key value and a comparison operator in <code>KeyConditionExpression</code>. To further refine the <code>Query</code> results, you can optionally provide a <code>FilterExpression</code>. A <code>FilterExpression</code> determines which items within the results should be returned to you. All of the other results are discarded. </p> <p> A <code>Query</code> operation always returns a result set. If no matching items are found, the result set will be empty. Queries that do not return results consume the minimum number of read capacity units for that type of read operation. </p> <note> <p> DynamoDB calculates the number of read capacity units consumed based on item size, not on the amount of data that is returned to an application. The number of capacity units consumed will be the same whether you request all of the attributes (the default behavior) or just some of them (using a projection expression). The number will also be the same whether or not you use a <code>FilterExpression</code>. </p> </note> <p> <code>Query</code> results are always sorted by the sort key value. If the data type of the sort key is Number, the results are returned in numeric order; otherwise, the results are returned in order of UTF-8 bytes. By default, the sort order is ascending. To reverse the order, set the <code>ScanIndexForward</code> parameter to false. </p> <p> A single <code>Query</code> operation will read up to the maximum number of items set (if using the <code>Limit</code> parameter) or a maximum of 1 MB of data and then apply any filtering to the results using <code>FilterExpression</code>. If <code>LastEvaluatedKey</code> is present in the response, you will need to paginate the result set. For more information, see <a href="https://docs.aws.amazon.com/amazondynamodb/latest/developerguide/Query.html#Query.Pagination">Paginating the Results</a> in the <i>Amazon DynamoDB Developer Guide</i>. </p> <p> <code>FilterExpression</code> is applied after a <code>Query</code> finishes, but before the results are returned. A <code>FilterExpression</code> cannot contain partition key or sort key attributes. You need to specify those attributes in the <code>KeyConditionExpression</code>. </p> <note> <p> A <code>Query</code> operation can return an empty result set and a <code>LastEvaluatedKey</code> if all the items read for the page of results are filtered out. </p> </note> <p>You can query a table, a local secondary index, or a global secondary index. For a query on a table or on a local secondary index, you can set the <code>ConsistentRead</code> parameter to <code>true</code> and obtain a strongly consistent result. Global secondary indexes support eventually consistent reads only, so do not specify <code>ConsistentRead</code> when querying a global secondary index.</p>
-    async fn query(&self, input: QueryInput) -> Result<QueryOutput, RusotoError<QueryError>> {
+    /// Presigns the request `query` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_query(
+        &self,
+        presigner: &rusoto_core::Presigner,
+        input: QueryInput,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_query_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_query_request(&self, input: QueryInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "dynamodb", &self.region, "/");
+        request.set_operation("Query");
 
         request.set_content_type("application/x-amz-json-1.0".to_owned());
         request.add_header("x-amz-target", "DynamoDB_20120810.Query");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `restore_table_from_backup`, also returning the metadata of the response.
+    pub async fn restore_table_from_backup_with_metadata(
+        &self,
+        input: RestoreTableFromBackupInput,
+    ) -> Result<
+        (RestoreTableFromBackupOutput, ResponseMetadata),
+        RusotoError<RestoreTableFromBackupError>,
+    > {
+        let request = self.build_restore_table_from_backup_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<QueryOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<RestoreTableFromBackupOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(QueryError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(RestoreTableFromBackupError::from_response(response))
         }
     }
 
-    /// <p><p>Creates a new table from an existing backup. Any number of users can execute up to 4 concurrent restores (any type of restore) in a given account. </p> <p>You can call <code>RestoreTableFromBackup</code> at a maximum rate of 10 times per second.</p> <p>You must manually set up the following on the restored table:</p> <ul> <li> <p>Auto scaling policies</p> </li> <li> <p>IAM policies</p> </li> <li> <p>Amazon CloudWatch metrics and alarms</p> </li> <li> <p>Tags</p> </li> <li> <p>Stream settings</p> </li> <li> <p>Time to Live (TTL) settings</p> </li> </ul></p>
-    async fn restore_table_from_backup(
+    /// Presigns the request `restore_table_from_backup` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_restore_table_from_backup(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: RestoreTableFromBackupInput,
-    ) -> Result<RestoreTableFromBackupOutput, RusotoError<RestoreTableFromBackupError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_restore_table_from_backup_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_restore_table_from_backup_request(
+        &self,
+        input: RestoreTableFromBackupInput,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "dynamodb", &self.region, "/");
+        request.set_operation("RestoreTableFromBackup");
 
         request.set_content_type("application/x-amz-json-1.0".to_owned());
         request.add_header("x-amz-target", "DynamoDB_20120810.RestoreTableFromBackup");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `restore_table_to_point_in_time`, also returning the metadata of the response.
+    pub async fn restore_table_to_point_in_time_with_metadata(
+        &self,
+        input: RestoreTableToPointInTimeInput,
+    ) -> Result<
+        (RestoreTableToPointInTimeOutput, ResponseMetadata),
+        RusotoError<RestoreTableToPointInTimeError>,
+    > {
+        let request = self.build_restore_table_to_point_in_time_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<RestoreTableFromBackupOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<RestoreTableToPointInTimeOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(RestoreTableFromBackupError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(RestoreTableToPointInTimeError::from_response(response))
         }
     }
 
-    /// <p><p>Restores the specified table to the specified point in time within <code>EarliestRestorableDateTime</code> and <code>LatestRestorableDateTime</code>. You can restore your table to any point in time during the last 35 days. Any number of users can execute up to 4 concurrent restores (any type of restore) in a given account. </p> <p> When you restore using point in time recovery, DynamoDB restores your table data to the state based on the selected date and time (day:hour:minute:second) to a new table. </p> <p> Along with data, the following are also included on the new restored table using point in time recovery: </p> <ul> <li> <p>Global secondary indexes (GSIs)</p> </li> <li> <p>Local secondary indexes (LSIs)</p> </li> <li> <p>Provisioned read and write capacity</p> </li> <li> <p>Encryption settings</p> <important> <p> All these settings come from the current settings of the source table at the time of restore. </p> </important> </li> </ul> <p>You must manually set up the following on the restored table:</p> <ul> <li> <p>Auto scaling policies</p> </li> <li> <p>IAM policies</p> </li> <li> <p>Amazon CloudWatch metrics and alarms</p> </li> <li> <p>Tags</p> </li> <li> <p>Stream settings</p> </li> <li> <p>Time to Live (TTL) settings</p> </li> <li> <p>Point in time recovery settings</p> </li> </ul></p>
-    async fn restore_table_to_point_in_time(
+    /// Presigns the request `restore_table_to_point_in_time` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_restore_table_to_point_in_time(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: RestoreTableToPointInTimeInput,
-    ) -> Result<RestoreTableToPointInTimeOutput, RusotoError<RestoreTableToPointInTimeError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_restore_table_to_point_in_time_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_restore_table_to_point_in_time_request(
+        &self,
+        input: RestoreTableToPointInTimeInput,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "dynamodb", &self.region, "/");
+        request.set_operation("RestoreTableToPointInTime");
 
         request.set_content_type("application/x-amz-json-1.0".to_owned());
         request.add_header(
@@ -6213,189 +7372,368 @@ impl DynamoDb for DynamoDbClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `scan`, also returning the metadata of the response.
+    pub async fn scan_with_metadata(
+        &self,
+        input: ScanInput,
+    ) -> Result<(ScanOutput, ResponseMetadata), RusotoError<ScanError>> {
+        let request = self.build_scan_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<RestoreTableToPointInTimeOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result =
+                proto::json::ResponsePayload::new(&response).deserialize::<ScanOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(RestoreTableToPointInTimeError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ScanError::from_response(response))
         }
     }
 
-    /// <p>The <code>Scan</code> operation returns one or more items and item attributes by accessing every item in a table or a secondary index. To have DynamoDB return fewer items, you can provide a <code>FilterExpression</code> operation.</p> <p>If the total number of scanned items exceeds the maximum dataset size limit of 1 MB, the scan stops and results are returned to the user as a <code>LastEvaluatedKey</code> value to continue the scan in a subsequent operation. The results also include the number of items exceeding the limit. A scan can result in no table data meeting the filter criteria. </p> <p>A single <code>Scan</code> operation reads up to the maximum number of items set (if using the <code>Limit</code> parameter) or a maximum of 1 MB of data and then apply any filtering to the results using <code>FilterExpression</code>. If <code>LastEvaluatedKey</code> is present in the response, you need to paginate the result set. For more information, see <a href="https://docs.aws.amazon.com/amazondynamodb/latest/developerguide/Scan.html#Scan.Pagination">Paginating the Results</a> in the <i>Amazon DynamoDB Developer Guide</i>. </p> <p> <code>Scan</code> operations proceed sequentially; however, for faster performance on a large table or secondary index, applications can request a parallel <code>Scan</code> operation by providing the <code>Segment</code> and <code>TotalSegments</code> parameters. For more information, see <a href="https://docs.aws.amazon.com/amazondynamodb/latest/developerguide/Scan.html#Scan.ParallelScan">Parallel Scan</a> in the <i>Amazon DynamoDB Developer Guide</i>.</p> <p> <code>Scan</code> uses eventually consistent reads when accessing the data in a table; therefore, the result set might not include the changes to data in the table immediately before the operation began. If you need a consistent copy of the data, as of the time that the <code>Scan</code> begins, you can set the <code>ConsistentRead</code> parameter to <code>true</code>.</p>
-    async fn scan(&self, input: ScanInput) -> Result<ScanOutput, RusotoError<ScanError>> {
+    /// Presigns the request `scan` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_scan(
+        &self,
+        presigner: &rusoto_core::Presigner,
+        input: ScanInput,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_scan_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_scan_request(&self, input: ScanInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "dynamodb", &self.region, "/");
+        request.set_operation("Scan");
 
         request.set_content_type("application/x-amz-json-1.0".to_owned());
         request.add_header("x-amz-target", "DynamoDB_20120810.Scan");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `tag_resource`, also returning the metadata of the response.
+    pub async fn tag_resource_with_metadata(
+        &self,
+        input: TagResourceInput,
+    ) -> Result<((), ResponseMetadata), RusotoError<TagResourceError>> {
+        let request = self.build_tag_resource_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<ScanOutput, _>()
+            std::mem::drop(response);
+            Ok(((), metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ScanError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(TagResourceError::from_response(response))
         }
     }
 
-    /// <p>Associate a set of tags with an Amazon DynamoDB resource. You can then activate these user-defined tags so that they appear on the Billing and Cost Management console for cost allocation tracking. You can call TagResource up to five times per second, per account. </p> <p>For an overview on tagging DynamoDB resources, see <a href="https://docs.aws.amazon.com/amazondynamodb/latest/developerguide/Tagging.html">Tagging for DynamoDB</a> in the <i>Amazon DynamoDB Developer Guide</i>.</p>
-    async fn tag_resource(
+    /// Presigns the request `tag_resource` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_tag_resource(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: TagResourceInput,
-    ) -> Result<(), RusotoError<TagResourceError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_tag_resource_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_tag_resource_request(&self, input: TagResourceInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "dynamodb", &self.region, "/");
+        request.set_operation("TagResource");
 
         request.set_content_type("application/x-amz-json-1.0".to_owned());
         request.add_header("x-amz-target", "DynamoDB_20120810.TagResource");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `transact_get_items`, also returning the metadata of the response.
+    pub async fn transact_get_items_with_metadata(
+        &self,
+        input: TransactGetItemsInput,
+    ) -> Result<(TransactGetItemsOutput, ResponseMetadata), RusotoError<TransactGetItemsError>>
+    {
+        let request = self.build_transact_get_items_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            std::mem::drop(response);
-            Ok(())
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<TransactGetItemsOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(TagResourceError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(TransactGetItemsError::from_response(response))
         }
     }
 
-    /// <p><p> <code>TransactGetItems</code> is a synchronous operation that atomically retrieves multiple items from one or more tables (but not from indexes) in a single account and Region. A <code>TransactGetItems</code> call can contain up to 25 <code>TransactGetItem</code> objects, each of which contains a <code>Get</code> structure that specifies an item to retrieve from a table in the account and Region. A call to <code>TransactGetItems</code> cannot retrieve items from tables in more than one AWS account or Region. The aggregate size of the items in the transaction cannot exceed 4 MB.</p> <p>DynamoDB rejects the entire <code>TransactGetItems</code> request if any of the following is true:</p> <ul> <li> <p>A conflicting operation is in the process of updating an item to be read.</p> </li> <li> <p>There is insufficient provisioned capacity for the transaction to be completed.</p> </li> <li> <p>There is a user error, such as an invalid data format.</p> </li> <li> <p>The aggregate size of the items in the transaction cannot exceed 4 MB.</p> </li> </ul></p>
-    async fn transact_get_items(
+    /// Presigns the request `transact_get_items` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_transact_get_items(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: TransactGetItemsInput,
-    ) -> Result<TransactGetItemsOutput, RusotoError<TransactGetItemsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_transact_get_items_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_transact_get_items_request(&self, input: TransactGetItemsInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "dynamodb", &self.region, "/");
+        request.set_operation("TransactGetItems");
 
         request.set_content_type("application/x-amz-json-1.0".to_owned());
         request.add_header("x-amz-target", "DynamoDB_20120810.TransactGetItems");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `transact_write_items`, also returning the metadata of the response.
+    pub async fn transact_write_items_with_metadata(
+        &self,
+        input: TransactWriteItemsInput,
+    ) -> Result<(TransactWriteItemsOutput, ResponseMetadata), RusotoError<TransactWriteItemsError>>
+    {
+        let request = self.build_transact_write_items_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<TransactGetItemsOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<TransactWriteItemsOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(TransactGetItemsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(TransactWriteItemsError::from_response(response))
         }
     }
 
-    /// <p><p> <code>TransactWriteItems</code> is a synchronous write operation that groups up to 25 action requests. These actions can target items in different tables, but not in different AWS accounts or Regions, and no two actions can target the same item. For example, you cannot both <code>ConditionCheck</code> and <code>Update</code> the same item. The aggregate size of the items in the transaction cannot exceed 4 MB.</p> <p>The actions are completed atomically so that either all of them succeed, or all of them fail. They are defined by the following objects:</p> <ul> <li> <p> <code>Put</code>  &#x97;   Initiates a <code>PutItem</code> operation to write a new item. This structure specifies the primary key of the item to be written, the name of the table to write it in, an optional condition expression that must be satisfied for the write to succeed, a list of the item&#39;s attributes, and a field indicating whether to retrieve the item&#39;s attributes if the condition is not met.</p> </li> <li> <p> <code>Update</code>  &#x97;   Initiates an <code>UpdateItem</code> operation to update an existing item. This structure specifies the primary key of the item to be updated, the name of the table where it resides, an optional condition expression that must be satisfied for the update to succeed, an expression that defines one or more attributes to be updated, and a field indicating whether to retrieve the item&#39;s attributes if the condition is not met.</p> </li> <li> <p> <code>Delete</code>  &#x97;   Initiates a <code>DeleteItem</code> operation to delete an existing item. This structure specifies the primary key of the item to be deleted, the name of the table where it resides, an optional condition expression that must be satisfied for the deletion to succeed, and a field indicating whether to retrieve the item&#39;s attributes if the condition is not met.</p> </li> <li> <p> <code>ConditionCheck</code>  &#x97;   Applies a condition to an item that is not being modified by the transaction. This structure specifies the primary key of the item to be checked, the name of the table where it resides, a condition expression that must be satisfied for the transaction to succeed, and a field indicating whether to retrieve the item&#39;s attributes if the condition is not met.</p> </li> </ul> <p>DynamoDB rejects the entire <code>TransactWriteItems</code> request if any of the following is true:</p> <ul> <li> <p>A condition in one of the condition expressions is not met.</p> </li> <li> <p>An ongoing operation is in the process of updating the same item.</p> </li> <li> <p>There is insufficient provisioned capacity for the transaction to be completed.</p> </li> <li> <p>An item size becomes too large (bigger than 400 KB), a local secondary index (LSI) becomes too large, or a similar validation error occurs because of changes made by the transaction.</p> </li> <li> <p>The aggregate size of the items in the transaction exceeds 4 MB.</p> </li> <li> <p>There is a user error, such as an invalid data format.</p> </li> </ul></p>
-    async fn transact_write_items(
+    /// Presigns the request `transact_write_items` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_transact_write_items(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: TransactWriteItemsInput,
-    ) -> Result<TransactWriteItemsOutput, RusotoError<TransactWriteItemsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_transact_write_items_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_transact_write_items_request(&self, input: TransactWriteItemsInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "dynamodb", &self.region, "/");
+        request.set_operation("TransactWriteItems");
 
         request.set_content_type("application/x-amz-json-1.0".to_owned());
         request.add_header("x-amz-target", "DynamoDB_20120810.TransactWriteItems");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `untag_resource`, also returning the metadata of the response.
+    pub async fn untag_resource_with_metadata(
+        &self,
+        input: UntagResourceInput,
+    ) -> Result<((), ResponseMetadata), RusotoError<UntagResourceError>> {
+        let request = self.build_untag_resource_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<TransactWriteItemsOutput, _>()
+            std::mem::drop(response);
+            Ok(((), metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(TransactWriteItemsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(UntagResourceError::from_response(response))
         }
     }
 
-    /// <p>Removes the association of tags from an Amazon DynamoDB resource. You can call <code>UntagResource</code> up to five times per second, per account. </p> <p>For an overview on tagging DynamoDB resources, see <a href="https://docs.aws.amazon.com/amazondynamodb/latest/developerguide/Tagging.html">Tagging for DynamoDB</a> in the <i>Amazon DynamoDB Developer Guide</i>.</p>
-    async fn untag_resource(
+    /// Presigns the request `untag_resource` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_untag_resource(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UntagResourceInput,
-    ) -> Result<(), RusotoError<UntagResourceError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_untag_resource_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_untag_resource_request(&self, input: UntagResourceInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "dynamodb", &self.region, "/");
+        request.set_operation("UntagResource");
 
         request.set_content_type("application/x-amz-json-1.0".to_owned());
         request.add_header("x-amz-target", "DynamoDB_20120810.UntagResource");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `update_continuous_backups`, also returning the metadata of the response.
+    pub async fn update_continuous_backups_with_metadata(
+        &self,
+        input: UpdateContinuousBackupsInput,
+    ) -> Result<
+        (UpdateContinuousBackupsOutput, ResponseMetadata),
+        RusotoError<UpdateContinuousBackupsError>,
+    > {
+        let request = self.build_update_continuous_backups_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            std::mem::drop(response);
-            Ok(())
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<UpdateContinuousBackupsOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(UntagResourceError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(UpdateContinuousBackupsError::from_response(response))
         }
     }
 
-    /// <p> <code>UpdateContinuousBackups</code> enables or disables point in time recovery for the specified table. A successful <code>UpdateContinuousBackups</code> call returns the current <code>ContinuousBackupsDescription</code>. Continuous backups are <code>ENABLED</code> on all tables at table creation. If point in time recovery is enabled, <code>PointInTimeRecoveryStatus</code> will be set to ENABLED.</p> <p> Once continuous backups and point in time recovery are enabled, you can restore to any point in time within <code>EarliestRestorableDateTime</code> and <code>LatestRestorableDateTime</code>. </p> <p> <code>LatestRestorableDateTime</code> is typically 5 minutes before the current time. You can restore your table to any point in time during the last 35 days. </p>
-    async fn update_continuous_backups(
+    /// Presigns the request `update_continuous_backups` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_continuous_backups(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UpdateContinuousBackupsInput,
-    ) -> Result<UpdateContinuousBackupsOutput, RusotoError<UpdateContinuousBackupsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_continuous_backups_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_continuous_backups_request(
+        &self,
+        input: UpdateContinuousBackupsInput,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "dynamodb", &self.region, "/");
+        request.set_operation("UpdateContinuousBackups");
 
         request.set_content_type("application/x-amz-json-1.0".to_owned());
         request.add_header("x-amz-target", "DynamoDB_20120810.UpdateContinuousBackups");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `update_contributor_insights`, also returning the metadata of the response.
+    pub async fn update_contributor_insights_with_metadata(
+        &self,
+        input: UpdateContributorInsightsInput,
+    ) -> Result<
+        (UpdateContributorInsightsOutput, ResponseMetadata),
+        RusotoError<UpdateContributorInsightsError>,
+    > {
+        let request = self.build_update_contributor_insights_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<UpdateContinuousBackupsOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<UpdateContributorInsightsOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(UpdateContinuousBackupsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(UpdateContributorInsightsError::from_response(response))
         }
     }
 
-    /// <p>Updates the status for contributor insights for a specific table or index.</p>
-    async fn update_contributor_insights(
+    /// Presigns the request `update_contributor_insights` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_contributor_insights(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UpdateContributorInsightsInput,
-    ) -> Result<UpdateContributorInsightsOutput, RusotoError<UpdateContributorInsightsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_contributor_insights_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_contributor_insights_request(
+        &self,
+        input: UpdateContributorInsightsInput,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "dynamodb", &self.region, "/");
+        request.set_operation("UpdateContributorInsights");
 
         request.set_content_type("application/x-amz-json-1.0".to_owned());
         request.add_header(
@@ -6405,55 +7743,111 @@ impl DynamoDb for DynamoDbClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `update_global_table`, also returning the metadata of the response.
+    pub async fn update_global_table_with_metadata(
+        &self,
+        input: UpdateGlobalTableInput,
+    ) -> Result<(UpdateGlobalTableOutput, ResponseMetadata), RusotoError<UpdateGlobalTableError>>
+    {
+        let request = self.build_update_global_table_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<UpdateContributorInsightsOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<UpdateGlobalTableOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(UpdateContributorInsightsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(UpdateGlobalTableError::from_response(response))
         }
     }
 
-    /// <p><p>Adds or removes replicas in the specified global table. The global table must already exist to be able to use this operation. Any replica to be added must be empty, have the same name as the global table, have the same key schema, have DynamoDB Streams enabled, and have the same provisioned and maximum write capacity units.</p> <note> <p>Although you can use <code>UpdateGlobalTable</code> to add replicas and remove replicas in a single request, for simplicity we recommend that you issue separate requests for adding or removing replicas.</p> </note> <p> If global secondary indexes are specified, then the following conditions must also be met: </p> <ul> <li> <p> The global secondary indexes must have the same name. </p> </li> <li> <p> The global secondary indexes must have the same hash key and sort key (if present). </p> </li> <li> <p> The global secondary indexes must have the same provisioned and maximum write capacity units. </p> </li> </ul></p>
-    async fn update_global_table(
+    /// Presigns the request `update_global_table` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_global_table(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UpdateGlobalTableInput,
-    ) -> Result<UpdateGlobalTableOutput, RusotoError<UpdateGlobalTableError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_global_table_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_global_table_request(&self, input: UpdateGlobalTableInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "dynamodb", &self.region, "/");
+        request.set_operation("UpdateGlobalTable");
 
         request.set_content_type("application/x-amz-json-1.0".to_owned());
         request.add_header("x-amz-target", "DynamoDB_20120810.UpdateGlobalTable");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `update_global_table_settings`, also returning the metadata of the response.
+    pub async fn update_global_table_settings_with_metadata(
+        &self,
+        input: UpdateGlobalTableSettingsInput,
+    ) -> Result<
+        (UpdateGlobalTableSettingsOutput, ResponseMetadata),
+        RusotoError<UpdateGlobalTableSettingsError>,
+    > {
+        let request = self.build_update_global_table_settings_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<UpdateGlobalTableOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<UpdateGlobalTableSettingsOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(UpdateGlobalTableError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(UpdateGlobalTableSettingsError::from_response(response))
         }
     }
 
-    /// <p>Updates settings for a global table.</p>
-    async fn update_global_table_settings(
+    /// Presigns the request `update_global_table_settings` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_global_table_settings(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UpdateGlobalTableSettingsInput,
-    ) -> Result<UpdateGlobalTableSettingsOutput, RusotoError<UpdateGlobalTableSettingsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_global_table_settings_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_global_table_settings_request(
+        &self,
+        input: UpdateGlobalTableSettingsInput,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "dynamodb", &self.region, "/");
+        request.set_operation("UpdateGlobalTableSettings");
 
         request.set_content_type("application/x-amz-json-1.0".to_owned());
         request.add_header(
@@ -6463,83 +7857,162 @@ impl DynamoDb for DynamoDbClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `update_item`, also returning the metadata of the response.
+    pub async fn update_item_with_metadata(
+        &self,
+        input: UpdateItemInput,
+    ) -> Result<(UpdateItemOutput, ResponseMetadata), RusotoError<UpdateItemError>> {
+        let request = self.build_update_item_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<UpdateGlobalTableSettingsOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<UpdateItemOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(UpdateGlobalTableSettingsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(UpdateItemError::from_response(response))
         }
     }
 
-    /// <p>Edits an existing item's attributes, or adds a new item to the table if it does not already exist. You can put, delete, or add attribute values. You can also perform a conditional update on an existing item (insert a new attribute name-value pair if it doesn't exist, or replace an existing name-value pair if it has certain expected attribute values).</p> <p>You can also return the item's attribute values in the same <code>UpdateItem</code> operation using the <code>ReturnValues</code> parameter.</p>
-    async fn update_item(
+    /// Presigns the request `update_item` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_item(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UpdateItemInput,
-    ) -> Result<UpdateItemOutput, RusotoError<UpdateItemError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_item_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_item_request(&self, input: UpdateItemInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "dynamodb", &self.region, "/");
+        request.set_operation("UpdateItem");
 
         request.set_content_type("application/x-amz-json-1.0".to_owned());
         request.add_header("x-amz-target", "DynamoDB_20120810.UpdateItem");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `update_table`, also returning the metadata of the response.
+    pub async fn update_table_with_metadata(
+        &self,
+        input: UpdateTableInput,
+    ) -> Result<(UpdateTableOutput, ResponseMetadata), RusotoError<UpdateTableError>> {
+        let request = self.build_update_table_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<UpdateItemOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<UpdateTableOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(UpdateItemError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(UpdateTableError::from_response(response))
         }
     }
 
-    /// <p>Modifies the provisioned throughput settings, global secondary indexes, or DynamoDB Streams settings for a given table.</p> <p>You can only perform one of the following operations at once:</p> <ul> <li> <p>Modify the provisioned throughput settings of the table.</p> </li> <li> <p>Enable or disable DynamoDB Streams on the table.</p> </li> <li> <p>Remove a global secondary index from the table.</p> </li> <li> <p>Create a new global secondary index on the table. After the index begins backfilling, you can use <code>UpdateTable</code> to perform other operations.</p> </li> </ul> <p> <code>UpdateTable</code> is an asynchronous operation; while it is executing, the table status changes from <code>ACTIVE</code> to <code>UPDATING</code>. While it is <code>UPDATING</code>, you cannot issue another <code>UpdateTable</code> request. When the table returns to the <code>ACTIVE</code> state, the <code>UpdateTable</code> operation is complete.</p>
-    async fn update_table(
+    /// Presigns the request `update_table` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_table(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UpdateTableInput,
-    ) -> Result<UpdateTableOutput, RusotoError<UpdateTableError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_table_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_table_request(&self, input: UpdateTableInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "dynamodb", &self.region, "/");
+        request.set_operation("UpdateTable");
 
         request.set_content_type("application/x-amz-json-1.0".to_owned());
         request.add_header("x-amz-target", "DynamoDB_20120810.UpdateTable");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `update_table_replica_auto_scaling`, also returning the metadata of the response.
+    pub async fn update_table_replica_auto_scaling_with_metadata(
+        &self,
+        input: UpdateTableReplicaAutoScalingInput,
+    ) -> Result<
+        (UpdateTableReplicaAutoScalingOutput, ResponseMetadata),
+        RusotoError<UpdateTableReplicaAutoScalingError>,
+    > {
+        let request = self.build_update_table_replica_auto_scaling_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<UpdateTableOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<UpdateTableReplicaAutoScalingOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(UpdateTableError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(UpdateTableReplicaAutoScalingError::from_response(response))
         }
     }
 
-    /// <p><p>Updates auto scaling settings on your global tables at once.</p> <note> <p>This method only applies to <a href="https://docs.aws.amazon.com/amazondynamodb/latest/developerguide/globaltables.V2.html">Version 2019.11.21</a> of global tables.</p> </note></p>
-    async fn update_table_replica_auto_scaling(
+    /// Presigns the request `update_table_replica_auto_scaling` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_table_replica_auto_scaling(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UpdateTableReplicaAutoScalingInput,
-    ) -> Result<UpdateTableReplicaAutoScalingOutput, RusotoError<UpdateTableReplicaAutoScalingError>>
-    {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_table_replica_auto_scaling_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_table_replica_auto_scaling_request(
+        &self,
+        input: UpdateTableReplicaAutoScalingInput,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "dynamodb", &self.region, "/");
+        request.set_operation("UpdateTableReplicaAutoScaling");
 
         request.set_content_type("application/x-amz-json-1.0".to_owned());
         request.add_header(
@@ -6549,46 +8022,749 @@ impl DynamoDb for DynamoDbClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `update_time_to_live`, also returning the metadata of the response.
+    pub async fn update_time_to_live_with_metadata(
+        &self,
+        input: UpdateTimeToLiveInput,
+    ) -> Result<(UpdateTimeToLiveOutput, ResponseMetadata), RusotoError<UpdateTimeToLiveError>>
+    {
+        let request = self.build_update_time_to_live_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<UpdateTableReplicaAutoScalingOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<UpdateTimeToLiveOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(UpdateTableReplicaAutoScalingError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(UpdateTimeToLiveError::from_response(response))
         }
     }
 
-    /// <p>The <code>UpdateTimeToLive</code> method enables or disables Time to Live (TTL) for the specified table. A successful <code>UpdateTimeToLive</code> call returns the current <code>TimeToLiveSpecification</code>. It can take up to one hour for the change to fully process. Any additional <code>UpdateTimeToLive</code> calls for the same table during this one hour duration result in a <code>ValidationException</code>. </p> <p>TTL compares the current time in epoch time format to the time stored in the TTL attribute of an item. If the epoch time value stored in the attribute is less than the current time, the item is marked as expired and subsequently deleted.</p> <note> <p> The epoch time format is the number of seconds elapsed since 12:00:00 AM January 1, 1970 UTC. </p> </note> <p>DynamoDB deletes expired items on a best-effort basis to ensure availability of throughput for other data operations. </p> <important> <p>DynamoDB typically deletes expired items within two days of expiration. The exact duration within which an item gets deleted after expiration is specific to the nature of the workload. Items that have expired and not been deleted will still show up in reads, queries, and scans.</p> </important> <p>As items are deleted, they are removed from any local secondary index and global secondary index immediately in the same eventually consistent way as a standard delete operation.</p> <p>For more information, see <a href="https://docs.aws.amazon.com/amazondynamodb/latest/developerguide/TTL.html">Time To Live</a> in the Amazon DynamoDB Developer Guide. </p>
-    async fn update_time_to_live(
+    /// Presigns the request `update_time_to_live` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_time_to_live(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UpdateTimeToLiveInput,
-    ) -> Result<UpdateTimeToLiveOutput, RusotoError<UpdateTimeToLiveError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_time_to_live_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_time_to_live_request(&self, input: UpdateTimeToLiveInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "dynamodb", &self.region, "/");
+        request.set_operation("UpdateTimeToLive");
 
         request.set_content_type("application/x-amz-json-1.0".to_owned());
         request.add_header("x-amz-target", "DynamoDB_20120810.UpdateTimeToLive");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
-        let mut response = self
-            .client
-            .sign_and_dispatch(request)
+        request
+    }
+
+    /// The pages of `ListBackups` results for `input`, requesting each next page with the `LastEvaluatedBackupArn` of the last one as its `ExclusiveStartBackupArn`.
+    /// The page size is the `Limit` of `input`.
+    pub fn list_backups_pages(
+        &self,
+        input: ListBackupsInput,
+    ) -> impl futures::Stream<Item = Result<ListBackupsOutput, RusotoError<ListBackupsError>>> + Send
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: DynamoDbClient, input| async move { client.list_backups(input).await },
+            |input: &ListBackupsInput, output: &ListBackupsOutput| {
+                let exclusive_start_backup_arn = output.last_evaluated_backup_arn.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if exclusive_start_backup_arn.is_none()
+                    || exclusive_start_backup_arn == input.exclusive_start_backup_arn
+                {
+                    return None;
+                }
+                Some(ListBackupsInput {
+                    exclusive_start_backup_arn,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `BackupSummaries` of every page of `ListBackups` results for `input`, as `list_backups_pages` requests them.
+    pub fn list_backups_stream(
+        &self,
+        input: ListBackupsInput,
+    ) -> impl futures::Stream<Item = Result<BackupSummary, RusotoError<ListBackupsError>>> + Send
+    {
+        rusoto_core::pagination::items(self.list_backups_pages(input), |page| {
+            page.backup_summaries.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `ListTables` results for `input`, requesting each next page with the `LastEvaluatedTableName` of the last one as its `ExclusiveStartTableName`.
+    /// The page size is the `Limit` of `input`.
+    pub fn list_tables_pages(
+        &self,
+        input: ListTablesInput,
+    ) -> impl futures::Stream<Item = Result<ListTablesOutput, RusotoError<ListTablesError>>> + Send
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: DynamoDbClient, input| async move { client.list_tables(input).await },
+            |input: &ListTablesInput, output: &ListTablesOutput| {
+                let exclusive_start_table_name = output.last_evaluated_table_name.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if exclusive_start_table_name.is_none()
+                    || exclusive_start_table_name == input.exclusive_start_table_name
+                {
+                    return None;
+                }
+                Some(ListTablesInput {
+                    exclusive_start_table_name,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `TableNames` of every page of `ListTables` results for `input`, as `list_tables_pages` requests them.
+    pub fn list_tables_stream(
+        &self,
+        input: ListTablesInput,
+    ) -> impl futures::Stream<Item = Result<String, RusotoError<ListTablesError>>> + Send {
+        rusoto_core::pagination::items(self.list_tables_pages(input), |page| {
+            page.table_names.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `ListTagsOfResource` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    pub fn list_tags_of_resource_pages(
+        &self,
+        input: ListTagsOfResourceInput,
+    ) -> impl futures::Stream<
+        Item = Result<ListTagsOfResourceOutput, RusotoError<ListTagsOfResourceError>>,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: DynamoDbClient, input| async move { client.list_tags_of_resource(input).await },
+            |input: &ListTagsOfResourceInput, output: &ListTagsOfResourceOutput| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListTagsOfResourceInput {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `Tags` of every page of `ListTagsOfResource` results for `input`, as `list_tags_of_resource_pages` requests them.
+    pub fn list_tags_of_resource_stream(
+        &self,
+        input: ListTagsOfResourceInput,
+    ) -> impl futures::Stream<Item = Result<Tag, RusotoError<ListTagsOfResourceError>>> + Send {
+        rusoto_core::pagination::items(self.list_tags_of_resource_pages(input), |page| {
+            page.tags.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `Query` results for `input`, requesting each next page with the `LastEvaluatedKey` of the last one as its `ExclusiveStartKey`.
+    /// The page size is the `Limit` of `input`.
+    pub fn query_pages(
+        &self,
+        input: QueryInput,
+    ) -> impl futures::Stream<Item = Result<QueryOutput, RusotoError<QueryError>>> + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: DynamoDbClient, input| async move { client.query(input).await },
+            |input: &QueryInput, output: &QueryOutput| {
+                let exclusive_start_key = output.last_evaluated_key.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if exclusive_start_key.is_none() || exclusive_start_key == input.exclusive_start_key
+                {
+                    return None;
+                }
+                Some(QueryInput {
+                    exclusive_start_key,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The pages of `Scan` results for `input`, requesting each next page with the `LastEvaluatedKey` of the last one as its `ExclusiveStartKey`.
+    /// The page size is the `Limit` of `input`.
+    pub fn scan_pages(
+        &self,
+        input: ScanInput,
+    ) -> impl futures::Stream<Item = Result<ScanOutput, RusotoError<ScanError>>> + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: DynamoDbClient, input| async move { client.scan(input).await },
+            |input: &ScanInput, output: &ScanOutput| {
+                let exclusive_start_key = output.last_evaluated_key.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if exclusive_start_key.is_none() || exclusive_start_key == input.exclusive_start_key
+                {
+                    return None;
+                }
+                Some(ScanInput {
+                    exclusive_start_key,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// Waits for `ContributorInsightsEnabled`, polling `DescribeContributorInsights` with `input` every 20 seconds, up to 30 times.
+    pub async fn wait_until_contributor_insights_enabled(
+        &self,
+        input: DescribeContributorInsightsInput,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<DescribeContributorInsightsError>> {
+        let waiter = rusoto_core::waiter::Waiter::new(std::time::Duration::from_secs(20), 30);
+        self.wait_until_contributor_insights_enabled_with(input, &waiter)
             .await
-            .map_err(RusotoError::from)?;
-        if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<UpdateTimeToLiveOutput, _>()
-        } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(UpdateTimeToLiveError::from_response(response))
-        }
+    }
+
+    /// Waits for `ContributorInsightsEnabled` like `wait_until_contributor_insights_enabled`, with the delay, attempts and runtime of `waiter`.
+    pub async fn wait_until_contributor_insights_enabled_with(
+        &self,
+        input: DescribeContributorInsightsInput,
+        waiter: &rusoto_core::waiter::Waiter,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<DescribeContributorInsightsError>> {
+        rusoto_core::waiter::wait(
+            waiter,
+            || self.describe_contributor_insights(input.clone()),
+            |result| {
+                if match result {
+                    Ok(output) => std::iter::once(output)
+                        .filter_map(|value| value.contributor_insights_status.as_ref())
+                        .any(|value| value == "ENABLED"),
+                    Err(_) => false,
+                } {
+                    return Some(rusoto_core::waiter::WaiterState::Success);
+                }
+                if match result {
+                    Ok(output) => std::iter::once(output)
+                        .filter_map(|value| value.contributor_insights_status.as_ref())
+                        .any(|value| value == "FAILED"),
+                    Err(_) => false,
+                } {
+                    return Some(rusoto_core::waiter::WaiterState::Failure);
+                }
+                None
+            },
+        )
+        .await
+    }
+
+    /// Waits for `TableExists`, polling `DescribeTable` with `input` every 20 seconds, up to 25 times.
+    pub async fn wait_until_table_exists(
+        &self,
+        input: DescribeTableInput,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<DescribeTableError>> {
+        let waiter = rusoto_core::waiter::Waiter::new(std::time::Duration::from_secs(20), 25);
+        self.wait_until_table_exists_with(input, &waiter).await
+    }
+
+    /// Waits for `TableExists` like `wait_until_table_exists`, with the delay, attempts and runtime of `waiter`.
+    pub async fn wait_until_table_exists_with(
+        &self,
+        input: DescribeTableInput,
+        waiter: &rusoto_core::waiter::Waiter,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<DescribeTableError>> {
+        rusoto_core::waiter::wait(
+            waiter,
+            || self.describe_table(input.clone()),
+            |result| {
+                if match result {
+                    Ok(output) => std::iter::once(output)
+                        .filter_map(|value| value.table.as_ref())
+                        .filter_map(|value| value.table_status.as_ref())
+                        .any(|value| value == "ACTIVE"),
+                    Err(_) => false,
+                } {
+                    return Some(rusoto_core::waiter::WaiterState::Success);
+                }
+                if result
+                    .as_ref()
+                    .err()
+                    .and_then(|error| error.error_code())
+                    .as_deref()
+                    == Some("ResourceNotFoundException")
+                {
+                    return Some(rusoto_core::waiter::WaiterState::Retry);
+                }
+                None
+            },
+        )
+        .await
+    }
+
+    /// Waits for `TableNotExists`, polling `DescribeTable` with `input` every 20 seconds, up to 25 times.
+    pub async fn wait_until_table_not_exists(
+        &self,
+        input: DescribeTableInput,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<DescribeTableError>> {
+        let waiter = rusoto_core::waiter::Waiter::new(std::time::Duration::from_secs(20), 25);
+        self.wait_until_table_not_exists_with(input, &waiter).await
+    }
+
+    /// Waits for `TableNotExists` like `wait_until_table_not_exists`, with the delay, attempts and runtime of `waiter`.
+    pub async fn wait_until_table_not_exists_with(
+        &self,
+        input: DescribeTableInput,
+        waiter: &rusoto_core::waiter::Waiter,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<DescribeTableError>> {
+        rusoto_core::waiter::wait(
+            waiter,
+            || self.describe_table(input.clone()),
+            |result| {
+                if result
+                    .as_ref()
+                    .err()
+                    .and_then(|error| error.error_code())
+                    .as_deref()
+                    == Some("ResourceNotFoundException")
+                {
+                    return Some(rusoto_core::waiter::WaiterState::Success);
+                }
+                None
+            },
+        )
+        .await
+    }
+}
+
+#[async_trait]
+impl DynamoDb for DynamoDbClient {
+    /// <p>The <code>BatchGetItem</code> operation returns the attributes of one or more items from one or more tables. You identify requested items by primary key.</p> <p>A single operation can retrieve up to 16 MB of data, which can contain as many as 100 items. <code>BatchGetItem</code> returns a partial result if the response size limit is exceeded, the table's provisioned throughput is exceeded, or an internal processing failure occurs. If a partial result is returned, the operation returns a value for <code>UnprocessedKeys</code>. You can use this value to retry the operation starting with the next item to get.</p> <important> <p>If you request more than 100 items, <code>BatchGetItem</code> returns a <code>ValidationException</code> with the message "Too many items requested for the BatchGetItem call."</p> </important> <p>For example, if you ask to retrieve 100 items, but each individual item is 300 KB in size, the system returns 52 items (so as not to exceed the 16 MB limit). It also returns an appropriate <code>UnprocessedKeys</code> value so you can get the next page of results. If desired, your application can include its own logic to assemble the pages of results into one dataset.</p> <p>If <i>none</i> of the items can be processed due to insufficient provisioned throughput on all of the tables in the request, then <code>BatchGetItem</code> returns a <code>ProvisionedThroughputExceededException</code>. If <i>at least one</i> of the items is successfully processed, then <code>BatchGetItem</code> completes successfully, while returning the keys of the unread items in <code>UnprocessedKeys</code>.</p> <important> <p>If DynamoDB returns any unprocessed items, you should retry the batch operation on those items. However, <i>we strongly recommend that you use an exponential backoff algorithm</i>. If you retry the batch operation immediately, the underlying read or write requests can still fail due to throttling on the individual tables. If you delay the batch operation using exponential backoff, the individual requests in the batch are much more likely to succeed.</p> <p>For more information, see <a href="https://docs.aws.amazon.com/amazondynamodb/latest/developerguide/ErrorHandling.html#BatchOperations">Batch Operations and Error Handling</a> in the <i>Amazon DynamoDB Developer Guide</i>.</p> </important> <p>By default, <code>BatchGetItem</code> performs eventually consistent reads on every table in the request. If you want strongly consistent reads instead, you can set <code>ConsistentRead</code> to <code>true</code> for any or all tables.</p> <p>In order to minimize response latency, <code>BatchGetItem</code> retrieves items in parallel.</p> <p>When designing your application, keep in mind that DynamoDB does not return items in any particular order. To help parse the response by item, include the primary key values for the items in your request in the <code>ProjectionExpression</code> parameter.</p> <p>If a requested item does not exist, it is not returned in the result. Requests for nonexistent items consume the minimum read capacity units according to the type of read. For more information, see <a href="https://docs.aws.amazon.com/amazondynamodb/latest/developerguide/WorkingWithTables.html#CapacityUnitCalculations">Working with Tables</a> in the <i>Amazon DynamoDB Developer Guide</i>.</p>
+    async fn batch_get_item(
+        &self,
+        input: BatchGetItemInput,
+    ) -> Result<BatchGetItemOutput, RusotoError<BatchGetItemError>> {
+        self.batch_get_item_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>The <code>BatchWriteItem</code> operation puts or deletes multiple items in one or more tables. A single call to <code>BatchWriteItem</code> can write up to 16 MB of data, which can comprise as many as 25 put or delete requests. Individual items to be written can be as large as 400 KB.</p> <note> <p> <code>BatchWriteItem</code> cannot update items. To update items, use the <code>UpdateItem</code> action.</p> </note> <p>The individual <code>PutItem</code> and <code>DeleteItem</code> operations specified in <code>BatchWriteItem</code> are atomic; however <code>BatchWriteItem</code> as a whole is not. If any requested operations fail because the table&#39;s provisioned throughput is exceeded or an internal processing failure occurs, the failed operations are returned in the <code>UnprocessedItems</code> response parameter. You can investigate and optionally resend the requests. Typically, you would call <code>BatchWriteItem</code> in a loop. Each iteration would check for unprocessed items and submit a new <code>BatchWriteItem</code> request with those unprocessed items until all items have been processed.</p> <p>If <i>none</i> of the items can be processed due to insufficient provisioned throughput on all of the tables in the request, then <code>BatchWriteItem</code> returns a <code>ProvisionedThroughputExceededException</code>.</p> <important> <p>If DynamoDB returns any unprocessed items, you should retry the batch operation on those items. However, <i>we strongly recommend that you use an exponential backoff algorithm</i>. If you retry the batch operation immediately, the underlying read or write requests can still fail due to throttling on the individual tables. If you delay the batch operation using exponential backoff, the individual requests in the batch are much more likely to succeed.</p> <p>For more information, see <a href="https://docs.aws.amazon.com/amazondynamodb/latest/developerguide/ErrorHandling.html#Programming.Errors.BatchOperations">Batch Operations and Error Handling</a> in the <i>Amazon DynamoDB Developer Guide</i>.</p> </important> <p>With <code>BatchWriteItem</code>, you can efficiently write or delete large amounts of data, such as from Amazon EMR, or copy data from another database into DynamoDB. In order to improve performance with these large-scale operations, <code>BatchWriteItem</code> does not behave in the same way as individual <code>PutItem</code> and <code>DeleteItem</code> calls would. For example, you cannot specify conditions on individual put and delete requests, and <code>BatchWriteItem</code> does not return deleted items in the response.</p> <p>If you use a programming language that supports concurrency, you can use threads to write items in parallel. Your application must include the necessary logic to manage the threads. With languages that don&#39;t support threading, you must update or delete the specified items one at a time. In both situations, <code>BatchWriteItem</code> performs the specified put and delete operations in parallel, giving you the power of the thread pool approach without having to introduce complexity into your application.</p> <p>Parallel processing reduces latency, but each specified put and delete request consumes the same number of write capacity units whether it is processed in parallel or not. Delete operations on nonexistent items consume one write capacity unit.</p> <p>If one or more of the following is true, DynamoDB rejects the entire batch write operation:</p> <ul> <li> <p>One or more tables specified in the <code>BatchWriteItem</code> request does not exist.</p> </li> <li> <p>Primary key attributes specified on an item in the request do not match those in the corresponding table&#39;s primary key schema.</p> </li> <li> <p>You try to perform multiple operations on the same item in the same <code>BatchWriteItem</code> request. For example, you cannot put and delete the same item in the same <code>BatchWriteItem</code> request. </p> </li> <li> <p> Your request contains at least two items with identical hash and range keys (which essentially is two put operations). </p> </li> <li> <p>There are more than 25 requests in the batch.</p> </li> <li> <p>Any individual item in a batch exceeds 400 KB.</p> </li> <li> <p>The total request size exceeds 16 MB.</p> </li> </ul></p>
+    async fn batch_write_item(
+        &self,
+        input: BatchWriteItemInput,
+    ) -> Result<BatchWriteItemOutput, RusotoError<BatchWriteItemError>> {
+        self.batch_write_item_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Creates a backup for an existing table.</p> <p> Each time you create an on-demand backup, the entire table data is backed up. There is no limit to the number of on-demand backups that can be taken. </p> <p> When you create an on-demand backup, a time marker of the request is cataloged, and the backup is created asynchronously, by applying all changes until the time of the request to the last full table snapshot. Backup requests are processed instantaneously and become available for restore within minutes. </p> <p>You can call <code>CreateBackup</code> at a maximum rate of 50 times per second.</p> <p>All backups in DynamoDB work without consuming any provisioned throughput on the table.</p> <p> If you submit a backup request on 2018-12-14 at 14:25:00, the backup is guaranteed to contain all data committed to the table up to 14:24:00, and data committed after 14:26:00 will not be. The backup might contain data modifications made between 14:24:00 and 14:26:00. On-demand backup does not support causal consistency. </p> <p> Along with data, the following are also included on the backups: </p> <ul> <li> <p>Global secondary indexes (GSIs)</p> </li> <li> <p>Local secondary indexes (LSIs)</p> </li> <li> <p>Streams</p> </li> <li> <p>Provisioned read and write capacity</p> </li> </ul></p>
+    async fn create_backup(
+        &self,
+        input: CreateBackupInput,
+    ) -> Result<CreateBackupOutput, RusotoError<CreateBackupError>> {
+        self.create_backup_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Creates a global table from an existing table. A global table creates a replication relationship between two or more DynamoDB tables with the same table name in the provided Regions. </p> <note> <p>This method only applies to <a href="https://docs.aws.amazon.com/amazondynamodb/latest/developerguide/globaltables.V1.html">Version 2017.11.29</a> of global tables.</p> </note> <p>If you want to add a new replica table to a global table, each of the following conditions must be true:</p> <ul> <li> <p>The table must have the same primary key as all of the other replicas.</p> </li> <li> <p>The table must have the same name as all of the other replicas.</p> </li> <li> <p>The table must have DynamoDB Streams enabled, with the stream containing both the new and the old images of the item.</p> </li> <li> <p>None of the replica tables in the global table can contain any data.</p> </li> </ul> <p> If global secondary indexes are specified, then the following conditions must also be met: </p> <ul> <li> <p> The global secondary indexes must have the same name. </p> </li> <li> <p> The global secondary indexes must have the same hash key and sort key (if present). </p> </li> </ul> <important> <p> Write capacity settings should be set consistently across your replica tables and secondary indexes. DynamoDB strongly recommends enabling auto scaling to manage the write capacity settings for all of your global tables replicas and indexes. </p> <p> If you prefer to manage write capacity settings manually, you should provision equal replicated write capacity units to your replica tables. You should also provision equal replicated write capacity units to matching secondary indexes across your global table. </p> </important></p>
+    async fn create_global_table(
+        &self,
+        input: CreateGlobalTableInput,
+    ) -> Result<CreateGlobalTableOutput, RusotoError<CreateGlobalTableError>> {
+        self.create_global_table_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>The <code>CreateTable</code> operation adds a new table to your account. In an AWS account, table names must be unique within each Region. That is, you can have two tables with same name if you create the tables in different Regions.</p> <p> <code>CreateTable</code> is an asynchronous operation. Upon receiving a <code>CreateTable</code> request, DynamoDB immediately returns a response with a <code>TableStatus</code> of <code>CREATING</code>. After the table is created, DynamoDB sets the <code>TableStatus</code> to <code>ACTIVE</code>. You can perform read and write operations only on an <code>ACTIVE</code> table. </p> <p>You can optionally define secondary indexes on the new table, as part of the <code>CreateTable</code> operation. If you want to create multiple tables with secondary indexes on them, you must create the tables sequentially. Only one table with secondary indexes can be in the <code>CREATING</code> state at any given time.</p> <p>You can use the <code>DescribeTable</code> action to check the table status.</p>
+    async fn create_table(
+        &self,
+        input: CreateTableInput,
+    ) -> Result<CreateTableOutput, RusotoError<CreateTableError>> {
+        self.create_table_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes an existing backup of a table.</p> <p>You can call <code>DeleteBackup</code> at a maximum rate of 10 times per second.</p>
+    async fn delete_backup(
+        &self,
+        input: DeleteBackupInput,
+    ) -> Result<DeleteBackupOutput, RusotoError<DeleteBackupError>> {
+        self.delete_backup_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes a single item in a table by primary key. You can perform a conditional delete operation that deletes the item if it exists, or if it has an expected attribute value.</p> <p>In addition to deleting an item, you can also return the item's attribute values in the same operation, using the <code>ReturnValues</code> parameter.</p> <p>Unless you specify conditions, the <code>DeleteItem</code> is an idempotent operation; running it multiple times on the same item or attribute does <i>not</i> result in an error response.</p> <p>Conditional deletes are useful for deleting items only if specific conditions are met. If those conditions are met, DynamoDB performs the delete. Otherwise, the item is not deleted.</p>
+    async fn delete_item(
+        &self,
+        input: DeleteItemInput,
+    ) -> Result<DeleteItemOutput, RusotoError<DeleteItemError>> {
+        self.delete_item_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>The <code>DeleteTable</code> operation deletes a table and all of its items. After a <code>DeleteTable</code> request, the specified table is in the <code>DELETING</code> state until DynamoDB completes the deletion. If the table is in the <code>ACTIVE</code> state, you can delete it. If a table is in <code>CREATING</code> or <code>UPDATING</code> states, then DynamoDB returns a <code>ResourceInUseException</code>. If the specified table does not exist, DynamoDB returns a <code>ResourceNotFoundException</code>. If table is already in the <code>DELETING</code> state, no error is returned. </p> <note> <p>DynamoDB might continue to accept data read and write operations, such as <code>GetItem</code> and <code>PutItem</code>, on a table in the <code>DELETING</code> state until the table deletion is complete.</p> </note> <p>When you delete a table, any indexes on that table are also deleted.</p> <p>If you have DynamoDB Streams enabled on the table, then the corresponding stream on that table goes into the <code>DISABLED</code> state, and the stream is automatically deleted after 24 hours.</p> <p>Use the <code>DescribeTable</code> action to check the status of the table. </p>
+    async fn delete_table(
+        &self,
+        input: DeleteTableInput,
+    ) -> Result<DeleteTableOutput, RusotoError<DeleteTableError>> {
+        self.delete_table_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Describes an existing backup of a table.</p> <p>You can call <code>DescribeBackup</code> at a maximum rate of 10 times per second.</p>
+    async fn describe_backup(
+        &self,
+        input: DescribeBackupInput,
+    ) -> Result<DescribeBackupOutput, RusotoError<DescribeBackupError>> {
+        self.describe_backup_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Checks the status of continuous backups and point in time recovery on the specified table. Continuous backups are <code>ENABLED</code> on all tables at table creation. If point in time recovery is enabled, <code>PointInTimeRecoveryStatus</code> will be set to ENABLED.</p> <p> After continuous backups and point in time recovery are enabled, you can restore to any point in time within <code>EarliestRestorableDateTime</code> and <code>LatestRestorableDateTime</code>. </p> <p> <code>LatestRestorableDateTime</code> is typically 5 minutes before the current time. You can restore your table to any point in time during the last 35 days. </p> <p>You can call <code>DescribeContinuousBackups</code> at a maximum rate of 10 times per second.</p>
+    async fn describe_continuous_backups(
+        &self,
+        input: DescribeContinuousBackupsInput,
+    ) -> Result<DescribeContinuousBackupsOutput, RusotoError<DescribeContinuousBackupsError>> {
+        self.describe_continuous_backups_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns information about contributor insights, for a given table or global secondary index.</p>
+    async fn describe_contributor_insights(
+        &self,
+        input: DescribeContributorInsightsInput,
+    ) -> Result<DescribeContributorInsightsOutput, RusotoError<DescribeContributorInsightsError>>
+    {
+        self.describe_contributor_insights_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns the regional endpoint information.</p>
+    async fn describe_endpoints(
+        &self,
+    ) -> Result<DescribeEndpointsResponse, RusotoError<DescribeEndpointsError>> {
+        self.describe_endpoints_with_metadata()
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Returns information about the specified global table.</p> <note> <p>This method only applies to <a href="https://docs.aws.amazon.com/amazondynamodb/latest/developerguide/globaltables.V1.html">Version 2017.11.29</a> of global tables.</p> </note></p>
+    async fn describe_global_table(
+        &self,
+        input: DescribeGlobalTableInput,
+    ) -> Result<DescribeGlobalTableOutput, RusotoError<DescribeGlobalTableError>> {
+        self.describe_global_table_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Describes Region-specific settings for a global table.</p> <note> <p>This method only applies to <a href="https://docs.aws.amazon.com/amazondynamodb/latest/developerguide/globaltables.V1.html">Version 2017.11.29</a> of global tables.</p> </note></p>
+    async fn describe_global_table_settings(
+        &self,
+        input: DescribeGlobalTableSettingsInput,
+    ) -> Result<DescribeGlobalTableSettingsOutput, RusotoError<DescribeGlobalTableSettingsError>>
+    {
+        self.describe_global_table_settings_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns the current provisioned-capacity limits for your AWS account in a Region, both for the Region as a whole and for any one DynamoDB table that you create there.</p> <p>When you establish an AWS account, the account has initial limits on the maximum read capacity units and write capacity units that you can provision across all of your DynamoDB tables in a given Region. Also, there are per-table limits that apply when you create a table there. For more information, see <a href="https://docs.aws.amazon.com/amazondynamodb/latest/developerguide/Limits.html">Limits</a> page in the <i>Amazon DynamoDB Developer Guide</i>.</p> <p>Although you can increase these limits by filing a case at <a href="https://console.aws.amazon.com/support/home#/">AWS Support Center</a>, obtaining the increase is not instantaneous. The <code>DescribeLimits</code> action lets you write code to compare the capacity you are currently using to those limits imposed by your account so that you have enough time to apply for an increase before you hit a limit.</p> <p>For example, you could use one of the AWS SDKs to do the following:</p> <ol> <li> <p>Call <code>DescribeLimits</code> for a particular Region to obtain your current account limits on provisioned capacity there.</p> </li> <li> <p>Create a variable to hold the aggregate read capacity units provisioned for all your tables in that Region, and one to hold the aggregate write capacity units. Zero them both.</p> </li> <li> <p>Call <code>ListTables</code> to obtain a list of all your DynamoDB tables.</p> </li> <li> <p>For each table name listed by <code>ListTables</code>, do the following:</p> <ul> <li> <p>Call <code>DescribeTable</code> with the table name.</p> </li> <li> <p>Use the data returned by <code>DescribeTable</code> to add the read capacity units and write capacity units provisioned for the table itself to your variables.</p> </li> <li> <p>If the table has one or more global secondary indexes (GSIs), loop over these GSIs and add their provisioned capacity values to your variables as well.</p> </li> </ul> </li> <li> <p>Report the account limits for that Region returned by <code>DescribeLimits</code>, along with the total current provisioned capacity levels you have calculated.</p> </li> </ol> <p>This will let you see whether you are getting close to your account-level limits.</p> <p>The per-table limits apply only when you are creating a new table. They restrict the sum of the provisioned capacity of the new table itself and all its global secondary indexes.</p> <p>For existing tables and their GSIs, DynamoDB doesn't let you increase provisioned capacity extremely rapidly. But the only upper limit that applies is that the aggregate provisioned capacity over all your tables and GSIs cannot exceed either of the per-account limits.</p> <note> <p> <code>DescribeLimits</code> should only be called periodically. You can expect throttling errors if you call it more than once in a minute.</p> </note> <p>The <code>DescribeLimits</code> Request element has no content.</p>
+    async fn describe_limits(
+        &self,
+    ) -> Result<DescribeLimitsOutput, RusotoError<DescribeLimitsError>> {
+        self.describe_limits_with_metadata()
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Returns information about the table, including the current status of the table, when it was created, the primary key schema, and any indexes on the table.</p> <note> <p>If you issue a <code>DescribeTable</code> request immediately after a <code>CreateTable</code> request, DynamoDB might return a <code>ResourceNotFoundException</code>. This is because <code>DescribeTable</code> uses an eventually consistent query, and the metadata for your table might not be available at that moment. Wait for a few seconds, and then try the <code>DescribeTable</code> request again.</p> </note></p>
+    async fn describe_table(
+        &self,
+        input: DescribeTableInput,
+    ) -> Result<DescribeTableOutput, RusotoError<DescribeTableError>> {
+        self.describe_table_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Describes auto scaling settings across replicas of the global table at once.</p> <note> <p>This method only applies to <a href="https://docs.aws.amazon.com/amazondynamodb/latest/developerguide/globaltables.V2.html">Version 2019.11.21</a> of global tables.</p> </note></p>
+    async fn describe_table_replica_auto_scaling(
+        &self,
+        input: DescribeTableReplicaAutoScalingInput,
+    ) -> Result<
+        DescribeTableReplicaAutoScalingOutput,
+        RusotoError<DescribeTableReplicaAutoScalingError>,
+    > {
+        self.describe_table_replica_auto_scaling_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Gives a description of the Time to Live (TTL) status on the specified table. </p>
+    async fn describe_time_to_live(
+        &self,
+        input: DescribeTimeToLiveInput,
+    ) -> Result<DescribeTimeToLiveOutput, RusotoError<DescribeTimeToLiveError>> {
+        self.describe_time_to_live_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>The <code>GetItem</code> operation returns a set of attributes for the item with the given primary key. If there is no matching item, <code>GetItem</code> does not return any data and there will be no <code>Item</code> element in the response.</p> <p> <code>GetItem</code> provides an eventually consistent read by default. If your application requires a strongly consistent read, set <code>ConsistentRead</code> to <code>true</code>. Although a strongly consistent read might take more time than an eventually consistent read, it always returns the last updated value.</p>
+    async fn get_item(
+        &self,
+        input: GetItemInput,
+    ) -> Result<GetItemOutput, RusotoError<GetItemError>> {
+        self.get_item_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>List backups associated with an AWS account. To list backups for a given table, specify <code>TableName</code>. <code>ListBackups</code> returns a paginated list of results with at most 1 MB worth of items in a page. You can also specify a limit for the maximum number of entries to be returned in a page. </p> <p>In the request, start time is inclusive, but end time is exclusive. Note that these limits are for the time at which the original backup was requested.</p> <p>You can call <code>ListBackups</code> a maximum of five times per second.</p>
+    async fn list_backups(
+        &self,
+        input: ListBackupsInput,
+    ) -> Result<ListBackupsOutput, RusotoError<ListBackupsError>> {
+        self.list_backups_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns a list of ContributorInsightsSummary for a table and all its global secondary indexes.</p>
+    async fn list_contributor_insights(
+        &self,
+        input: ListContributorInsightsInput,
+    ) -> Result<ListContributorInsightsOutput, RusotoError<ListContributorInsightsError>> {
+        self.list_contributor_insights_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Lists all global tables that have a replica in the specified Region.</p> <note> <p>This method only applies to <a href="https://docs.aws.amazon.com/amazondynamodb/latest/developerguide/globaltables.V1.html">Version 2017.11.29</a> of global tables.</p> </note></p>
+    async fn list_global_tables(
+        &self,
+        input: ListGlobalTablesInput,
+    ) -> Result<ListGlobalTablesOutput, RusotoError<ListGlobalTablesError>> {
+        self.list_global_tables_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns an array of table names associated with the current account and endpoint. The output from <code>ListTables</code> is paginated, with each page returning a maximum of 100 table names.</p>
+    async fn list_tables(
+        &self,
+        input: ListTablesInput,
+    ) -> Result<ListTablesOutput, RusotoError<ListTablesError>> {
+        self.list_tables_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>List all tags on an Amazon DynamoDB resource. You can call ListTagsOfResource up to 10 times per second, per account.</p> <p>For an overview on tagging DynamoDB resources, see <a href="https://docs.aws.amazon.com/amazondynamodb/latest/developerguide/Tagging.html">Tagging for DynamoDB</a> in the <i>Amazon DynamoDB Developer Guide</i>.</p>
+    async fn list_tags_of_resource(
+        &self,
+        input: ListTagsOfResourceInput,
+    ) -> Result<ListTagsOfResourceOutput, RusotoError<ListTagsOfResourceError>> {
+        self.list_tags_of_resource_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Creates a new item, or replaces an old item with a new item. If an item that has the same primary key as the new item already exists in the specified table, the new item completely replaces the existing item. You can perform a conditional put operation (add a new item if one with the specified primary key doesn't exist), or replace an existing item if it has certain attribute values. You can return the item's attribute values in the same operation, using the <code>ReturnValues</code> parameter.</p> <important> <p>This topic provides general information about the <code>PutItem</code> API.</p> <p>For information on how to call the <code>PutItem</code> API using the AWS SDK in specific languages, see the following:</p> <ul> <li> <p> <a href="http://docs.aws.amazon.com/goto/aws-cli/dynamodb-2012-08-10/PutItem"> PutItem in the AWS Command Line Interface</a> </p> </li> <li> <p> <a href="http://docs.aws.amazon.com/goto/DotNetSDKV3/dynamodb-2012-08-10/PutItem"> PutItem in the AWS SDK for .NET</a> </p> </li> <li> <p> <a href="http://docs.aws.amazon.com/goto/SdkForCpp/dynamodb-2012-08-10/PutItem"> PutItem in the AWS SDK for C++</a> </p> </li> <li> <p> <a href="http://docs.aws.amazon.com/goto/SdkForGoV1/dynamodb-2012-08-10/PutItem"> PutItem in the AWS SDK for Go</a> </p> </li> <li> <p> <a href="http://docs.aws.amazon.com/goto/SdkForJava/dynamodb-2012-08-10/PutItem"> PutItem in the AWS SDK for Java</a> </p> </li> <li> <p> <a href="http://docs.aws.amazon.com/goto/AWSJavaScriptSDK/dynamodb-2012-08-10/PutItem"> PutItem in the AWS SDK for JavaScript</a> </p> </li> <li> <p> <a href="http://docs.aws.amazon.com/goto/SdkForPHPV3/dynamodb-2012-08-10/PutItem"> PutItem in the AWS SDK for PHP V3</a> </p> </li> <li> <p> <a href="http://docs.aws.amazon.com/goto/boto3/dynamodb-2012-08-10/PutItem"> PutItem in the AWS SDK for Python</a> </p> </li> <li> <p> <a href="http://docs.aws.amazon.com/goto/SdkForRubyV2/dynamodb-2012-08-10/PutItem"> PutItem in the AWS SDK for Ruby V2</a> </p> </li> </ul> </important> <p>When you add an item, the primary key attributes are the only required attributes. Attribute values cannot be null. String and Binary type attributes must have lengths greater than zero. Set type attributes cannot be empty. Requests with empty values will be rejected with a <code>ValidationException</code> exception.</p> <note> <p>To prevent a new item from replacing an existing item, use a conditional expression that contains the <code>attribute_not_exists</code> function with the name of the attribute being used as the partition key for the table. Since every record must contain that attribute, the <code>attribute_not_exists</code> function will only succeed if no matching item exists.</p> </note> <p>For more information about <code>PutItem</code>, see <a href="https://docs.aws.amazon.com/amazondynamodb/latest/developerguide/WorkingWithItems.html">Working with Items</a> in the <i>Amazon DynamoDB Developer Guide</i>.</p>
+    async fn put_item(
+        &self,
+        input: PutItemInput,
+    ) -> Result<PutItemOutput, RusotoError<PutItemError>> {
+        self.put_item_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>The <code>Query</code> operation finds items based on primary key values. You can query any table or secondary index that has a composite primary key (a partition key and a sort key). </p> <p>Use the <code>KeyConditionExpression</code> parameter to provide a specific value for the partition key. The <code>Query</code> operation will return all of the items from the table or index with that partition key value. You can optionally narrow the scope of the <code>Query</code> operation by specifying a sort key value and a comparison operator in <code>KeyConditionExpression</code>. To further refine the <code>Query</code> results, you can optionally provide a <code>FilterExpression</code>. A <code>FilterExpression</code> determines which items within the results should be returned to you. All of the other results are discarded. </p> <p> A <code>Query</code> operation always returns a result set. If no matching items are found, the result set will be empty. Queries that do not return results consume the minimum number of read capacity units for that type of read operation. </p> <note> <p> DynamoDB calculates the number of read capacity units consumed based on item size, not on the amount of data that is returned to an application. The number of capacity units consumed will be the same whether you request all of the attributes (the default behavior) or just some of them (using a projection expression). The number will also be the same whether or not you use a <code>FilterExpression</code>. </p> </note> <p> <code>Query</code> results are always sorted by the sort key value. If the data type of the sort key is Number, the results are returned in numeric order; otherwise, the results are returned in order of UTF-8 bytes. By default, the sort order is ascending. To reverse the order, set the <code>ScanIndexForward</code> parameter to false. </p> <p> A single <code>Query</code> operation will read up to the maximum number of items set (if using the <code>Limit</code> parameter) or a maximum of 1 MB of data and then apply any filtering to the results using <code>FilterExpression</code>. If <code>LastEvaluatedKey</code> is present in the response, you will need to paginate the result set. For more information, see <a href="https://docs.aws.amazon.com/amazondynamodb/latest/developerguide/Query.html#Query.Pagination">Paginating the Results</a> in the <i>Amazon DynamoDB Developer Guide</i>. </p> <p> <code>FilterExpression</code> is applied after a <code>Query</code> finishes, but before the results are returned. A <code>FilterExpression</code> cannot contain partition key or sort key attributes. You need to specify those attributes in the <code>KeyConditionExpression</code>. </p> <note> <p> A <code>Query</code> operation can return an empty result set and a <code>LastEvaluatedKey</code> if all the items read for the page of results are filtered out. </p> </note> <p>You can query a table, a local secondary index, or a global secondary index. For a query on a table or on a local secondary index, you can set the <code>ConsistentRead</code> parameter to <code>true</code> and obtain a strongly consistent result. Global secondary indexes support eventually consistent reads only, so do not specify <code>ConsistentRead</code> when querying a global secondary index.</p>
+    async fn query(&self, input: QueryInput) -> Result<QueryOutput, RusotoError<QueryError>> {
+        self.query_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Creates a new table from an existing backup. Any number of users can execute up to 4 concurrent restores (any type of restore) in a given account. </p> <p>You can call <code>RestoreTableFromBackup</code> at a maximum rate of 10 times per second.</p> <p>You must manually set up the following on the restored table:</p> <ul> <li> <p>Auto scaling policies</p> </li> <li> <p>IAM policies</p> </li> <li> <p>Amazon CloudWatch metrics and alarms</p> </li> <li> <p>Tags</p> </li> <li> <p>Stream settings</p> </li> <li> <p>Time to Live (TTL) settings</p> </li> </ul></p>
+    async fn restore_table_from_backup(
+        &self,
+        input: RestoreTableFromBackupInput,
+    ) -> Result<RestoreTableFromBackupOutput, RusotoError<RestoreTableFromBackupError>> {
+        self.restore_table_from_backup_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Restores the specified table to the specified point in time within <code>EarliestRestorableDateTime</code> and <code>LatestRestorableDateTime</code>. You can restore your table to any point in time during the last 35 days. Any number of users can execute up to 4 concurrent restores (any type of restore) in a given account. </p> <p> When you restore using point in time recovery, DynamoDB restores your table data to the state based on the selected date and time (day:hour:minute:second) to a new table. </p> <p> Along with data, the following are also included on the new restored table using point in time recovery: </p> <ul> <li> <p>Global secondary indexes (GSIs)</p> </li> <li> <p>Local secondary indexes (LSIs)</p> </li> <li> <p>Provisioned read and write capacity</p> </li> <li> <p>Encryption settings</p> <important> <p> All these settings come from the current settings of the source table at the time of restore. </p> </important> </li> </ul> <p>You must manually set up the following on the restored table:</p> <ul> <li> <p>Auto scaling policies</p> </li> <li> <p>IAM policies</p> </li> <li> <p>Amazon CloudWatch metrics and alarms</p> </li> <li> <p>Tags</p> </li> <li> <p>Stream settings</p> </li> <li> <p>Time to Live (TTL) settings</p> </li> <li> <p>Point in time recovery settings</p> </li> </ul></p>
+    async fn restore_table_to_point_in_time(
+        &self,
+        input: RestoreTableToPointInTimeInput,
+    ) -> Result<RestoreTableToPointInTimeOutput, RusotoError<RestoreTableToPointInTimeError>> {
+        self.restore_table_to_point_in_time_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>The <code>Scan</code> operation returns one or more items and item attributes by accessing every item in a table or a secondary index. To have DynamoDB return fewer items, you can provide a <code>FilterExpression</code> operation.</p> <p>If the total number of scanned items exceeds the maximum dataset size limit of 1 MB, the scan stops and results are returned to the user as a <code>LastEvaluatedKey</code> value to continue the scan in a subsequent operation. The results also include the number of items exceeding the limit. A scan can result in no table data meeting the filter criteria. </p> <p>A single <code>Scan</code> operation reads up to the maximum number of items set (if using the <code>Limit</code> parameter) or a maximum of 1 MB of data and then apply any filtering to the results using <code>FilterExpression</code>. If <code>LastEvaluatedKey</code> is present in the response, you need to paginate the result set. For more information, see <a href="https://docs.aws.amazon.com/amazondynamodb/latest/developerguide/Scan.html#Scan.Pagination">Paginating the Results</a> in the <i>Amazon DynamoDB Developer Guide</i>. </p> <p> <code>Scan</code> operations proceed sequentially; however, for faster performance on a large table or secondary index, applications can request a parallel <code>Scan</code> operation by providing the <code>Segment</code> and <code>TotalSegments</code> parameters. For more information, see <a href="https://docs.aws.amazon.com/amazondynamodb/latest/developerguide/Scan.html#Scan.ParallelScan">Parallel Scan</a> in the <i>Amazon DynamoDB Developer Guide</i>.</p> <p> <code>Scan</code> uses eventually consistent reads when accessing the data in a table; therefore, the result set might not include the changes to data in the table immediately before the operation began. If you need a consistent copy of the data, as of the time that the <code>Scan</code> begins, you can set the <code>ConsistentRead</code> parameter to <code>true</code>.</p>
+    async fn scan(&self, input: ScanInput) -> Result<ScanOutput, RusotoError<ScanError>> {
+        self.scan_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Associate a set of tags with an Amazon DynamoDB resource. You can then activate these user-defined tags so that they appear on the Billing and Cost Management console for cost allocation tracking. You can call TagResource up to five times per second, per account. </p> <p>For an overview on tagging DynamoDB resources, see <a href="https://docs.aws.amazon.com/amazondynamodb/latest/developerguide/Tagging.html">Tagging for DynamoDB</a> in the <i>Amazon DynamoDB Developer Guide</i>.</p>
+    async fn tag_resource(
+        &self,
+        input: TagResourceInput,
+    ) -> Result<(), RusotoError<TagResourceError>> {
+        self.tag_resource_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p> <code>TransactGetItems</code> is a synchronous operation that atomically retrieves multiple items from one or more tables (but not from indexes) in a single account and Region. A <code>TransactGetItems</code> call can contain up to 25 <code>TransactGetItem</code> objects, each of which contains a <code>Get</code> structure that specifies an item to retrieve from a table in the account and Region. A call to <code>TransactGetItems</code> cannot retrieve items from tables in more than one AWS account or Region. The aggregate size of the items in the transaction cannot exceed 4 MB.</p> <p>DynamoDB rejects the entire <code>TransactGetItems</code> request if any of the following is true:</p> <ul> <li> <p>A conflicting operation is in the process of updating an item to be read.</p> </li> <li> <p>There is insufficient provisioned capacity for the transaction to be completed.</p> </li> <li> <p>There is a user error, such as an invalid data format.</p> </li> <li> <p>The aggregate size of the items in the transaction cannot exceed 4 MB.</p> </li> </ul></p>
+    async fn transact_get_items(
+        &self,
+        input: TransactGetItemsInput,
+    ) -> Result<TransactGetItemsOutput, RusotoError<TransactGetItemsError>> {
+        self.transact_get_items_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p> <code>TransactWriteItems</code> is a synchronous write operation that groups up to 25 action requests. These actions can target items in different tables, but not in different AWS accounts or Regions, and no two actions can target the same item. For example, you cannot both <code>ConditionCheck</code> and <code>Update</code> the same item. The aggregate size of the items in the transaction cannot exceed 4 MB.</p> <p>The actions are completed atomically so that either all of them succeed, or all of them fail. They are defined by the following objects:</p> <ul> <li> <p> <code>Put</code>  &#x97;   Initiates a <code>PutItem</code> operation to write a new item. This structure specifies the primary key of the item to be written, the name of the table to write it in, an optional condition expression that must be satisfied for the write to succeed, a list of the item&#39;s attributes, and a field indicating whether to retrieve the item&#39;s attributes if the condition is not met.</p> </li> <li> <p> <code>Update</code>  &#x97;   Initiates an <code>UpdateItem</code> operation to update an existing item. This structure specifies the primary key of the item to be updated, the name of the table where it resides, an optional condition expression that must be satisfied for the update to succeed, an expression that defines one or more attributes to be updated, and a field indicating whether to retrieve the item&#39;s attributes if the condition is not met.</p> </li> <li> <p> <code>Delete</code>  &#x97;   Initiates a <code>DeleteItem</code> operation to delete an existing item. This structure specifies the primary key of the item to be deleted, the name of the table where it resides, an optional condition expression that must be satisfied for the deletion to succeed, and a field indicating whether to retrieve the item&#39;s attributes if the condition is not met.</p> </li> <li> <p> <code>ConditionCheck</code>  &#x97;   Applies a condition to an item that is not being modified by the transaction. This structure specifies the primary key of the item to be checked, the name of the table where it resides, a condition expression that must be satisfied for the transaction to succeed, and a field indicating whether to retrieve the item&#39;s attributes if the condition is not met.</p> </li> </ul> <p>DynamoDB rejects the entire <code>TransactWriteItems</code> request if any of the following is true:</p> <ul> <li> <p>A condition in one of the condition expressions is not met.</p> </li> <li> <p>An ongoing operation is in the process of updating the same item.</p> </li> <li> <p>There is insufficient provisioned capacity for the transaction to be completed.</p> </li> <li> <p>An item size becomes too large (bigger than 400 KB), a local secondary index (LSI) becomes too large, or a similar validation error occurs because of changes made by the transaction.</p> </li> <li> <p>The aggregate size of the items in the transaction exceeds 4 MB.</p> </li> <li> <p>There is a user error, such as an invalid data format.</p> </li> </ul></p>
+    async fn transact_write_items(
+        &self,
+        input: TransactWriteItemsInput,
+    ) -> Result<TransactWriteItemsOutput, RusotoError<TransactWriteItemsError>> {
+        self.transact_write_items_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Removes the association of tags from an Amazon DynamoDB resource. You can call <code>UntagResource</code> up to five times per second, per account. </p> <p>For an overview on tagging DynamoDB resources, see <a href="https://docs.aws.amazon.com/amazondynamodb/latest/developerguide/Tagging.html">Tagging for DynamoDB</a> in the <i>Amazon DynamoDB Developer Guide</i>.</p>
+    async fn untag_resource(
+        &self,
+        input: UntagResourceInput,
+    ) -> Result<(), RusotoError<UntagResourceError>> {
+        self.untag_resource_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p> <code>UpdateContinuousBackups</code> enables or disables point in time recovery for the specified table. A successful <code>UpdateContinuousBackups</code> call returns the current <code>ContinuousBackupsDescription</code>. Continuous backups are <code>ENABLED</code> on all tables at table creation. If point in time recovery is enabled, <code>PointInTimeRecoveryStatus</code> will be set to ENABLED.</p> <p> Once continuous backups and point in time recovery are enabled, you can restore to any point in time within <code>EarliestRestorableDateTime</code> and <code>LatestRestorableDateTime</code>. </p> <p> <code>LatestRestorableDateTime</code> is typically 5 minutes before the current time. You can restore your table to any point in time during the last 35 days. </p>
+    async fn update_continuous_backups(
+        &self,
+        input: UpdateContinuousBackupsInput,
+    ) -> Result<UpdateContinuousBackupsOutput, RusotoError<UpdateContinuousBackupsError>> {
+        self.update_continuous_backups_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Updates the status for contributor insights for a specific table or index.</p>
+    async fn update_contributor_insights(
+        &self,
+        input: UpdateContributorInsightsInput,
+    ) -> Result<UpdateContributorInsightsOutput, RusotoError<UpdateContributorInsightsError>> {
+        self.update_contributor_insights_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Adds or removes replicas in the specified global table. The global table must already exist to be able to use this operation. Any replica to be added must be empty, have the same name as the global table, have the same key schema, have DynamoDB Streams enabled, and have the same provisioned and maximum write capacity units.</p> <note> <p>Although you can use <code>UpdateGlobalTable</code> to add replicas and remove replicas in a single request, for simplicity we recommend that you issue separate requests for adding or removing replicas.</p> </note> <p> If global secondary indexes are specified, then the following conditions must also be met: </p> <ul> <li> <p> The global secondary indexes must have the same name. </p> </li> <li> <p> The global secondary indexes must have the same hash key and sort key (if present). </p> </li> <li> <p> The global secondary indexes must have the same provisioned and maximum write capacity units. </p> </li> </ul></p>
+    async fn update_global_table(
+        &self,
+        input: UpdateGlobalTableInput,
+    ) -> Result<UpdateGlobalTableOutput, RusotoError<UpdateGlobalTableError>> {
+        self.update_global_table_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Updates settings for a global table.</p>
+    async fn update_global_table_settings(
+        &self,
+        input: UpdateGlobalTableSettingsInput,
+    ) -> Result<UpdateGlobalTableSettingsOutput, RusotoError<UpdateGlobalTableSettingsError>> {
+        self.update_global_table_settings_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Edits an existing item's attributes, or adds a new item to the table if it does not already exist. You can put, delete, or add attribute values. You can also perform a conditional update on an existing item (insert a new attribute name-value pair if it doesn't exist, or replace an existing name-value pair if it has certain expected attribute values).</p> <p>You can also return the item's attribute values in the same <code>UpdateItem</code> operation using the <code>ReturnValues</code> parameter.</p>
+    async fn update_item(
+        &self,
+        input: UpdateItemInput,
+    ) -> Result<UpdateItemOutput, RusotoError<UpdateItemError>> {
+        self.update_item_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Modifies the provisioned throughput settings, global secondary indexes, or DynamoDB Streams settings for a given table.</p> <p>You can only perform one of the following operations at once:</p> <ul> <li> <p>Modify the provisioned throughput settings of the table.</p> </li> <li> <p>Enable or disable DynamoDB Streams on the table.</p> </li> <li> <p>Remove a global secondary index from the table.</p> </li> <li> <p>Create a new global secondary index on the table. After the index begins backfilling, you can use <code>UpdateTable</code> to perform other operations.</p> </li> </ul> <p> <code>UpdateTable</code> is an asynchronous operation; while it is executing, the table status changes from <code>ACTIVE</code> to <code>UPDATING</code>. While it is <code>UPDATING</code>, you cannot issue another <code>UpdateTable</code> request. When the table returns to the <code>ACTIVE</code> state, the <code>UpdateTable</code> operation is complete.</p>
+    async fn update_table(
+        &self,
+        input: UpdateTableInput,
+    ) -> Result<UpdateTableOutput, RusotoError<UpdateTableError>> {
+        self.update_table_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Updates auto scaling settings on your global tables at once.</p> <note> <p>This method only applies to <a href="https://docs.aws.amazon.com/amazondynamodb/latest/developerguide/globaltables.V2.html">Version 2019.11.21</a> of global tables.</p> </note></p>
+    async fn update_table_replica_auto_scaling(
+        &self,
+        input: UpdateTableReplicaAutoScalingInput,
+    ) -> Result<UpdateTableReplicaAutoScalingOutput, RusotoError<UpdateTableReplicaAutoScalingError>>
+    {
+        self.update_table_replica_auto_scaling_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>The <code>UpdateTimeToLive</code> method enables or disables Time to Live (TTL) for the specified table. A successful <code>UpdateTimeToLive</code> call returns the current <code>TimeToLiveSpecification</code>. It can take up to one hour for the change to fully process. Any additional <code>UpdateTimeToLive</code> calls for the same table during this one hour duration result in a <code>ValidationException</code>. </p> <p>TTL compares the current time in epoch time format to the time stored in the TTL attribute of an item. If the epoch time value stored in the attribute is less than the current time, the item is marked as expired and subsequently deleted.</p> <note> <p> The epoch time format is the number of seconds elapsed since 12:00:00 AM January 1, 1970 UTC. </p> </note> <p>DynamoDB deletes expired items on a best-effort basis to ensure availability of throughput for other data operations. </p> <important> <p>DynamoDB typically deletes expired items within two days of expiration. The exact duration within which an item gets deleted after expiration is specific to the nature of the workload. Items that have expired and not been deleted will still show up in reads, queries, and scans.</p> </important> <p>As items are deleted, they are removed from any local secondary index and global secondary index immediately in the same eventually consistent way as a standard delete operation.</p> <p>For more information, see <a href="https://docs.aws.amazon.com/amazondynamodb/latest/developerguide/TTL.html">Time To Live</a> in the Amazon DynamoDB Developer Guide. </p>
+    async fn update_time_to_live(
+        &self,
+        input: UpdateTimeToLiveInput,
+    ) -> Result<UpdateTimeToLiveOutput, RusotoError<UpdateTimeToLiveError>> {
+        self.update_time_to_live_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
     }
 }
@@ -16,8 +16,8 @@ use std::fmt;
 use async_trait::async_trait;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
-use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest, ResponseMetadata};
+use rusoto_core::{Client, ErrorMetadata, RusotoError};
 
 use rusoto_core::proto;
 use rusoto_core::signature::SignedRequest;
@@ -383,11 +383,14 @@ pub struct StreamRecord {
 
 /// Errors returned by DescribeStream
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeStreamError {
     /// <p>An error occurred on the server side.</p>
     InternalServerError(String),
     /// <p>The operation tried to access a nonexistent stream.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeStreamError {
@@ -395,13 +398,19 @@ impl DescribeStreamError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(DescribeStreamError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        DescribeStreamError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeStreamError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        DescribeStreamError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DescribeStreamError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -413,12 +422,14 @@ impl fmt::Display for DescribeStreamError {
         match *self {
             DescribeStreamError::InternalServerError(ref cause) => write!(f, "{}", cause),
             DescribeStreamError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            DescribeStreamError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeStreamError {}
 /// Errors returned by GetRecords
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetRecordsError {
     /// <p>The shard iterator has expired and can no longer be used to retrieve stream records. A shard iterator expires 15 minutes after it is retrieved using the <code>GetShardIterator</code> action.</p>
     ExpiredIterator(String),
@@ -430,6 +441,8 @@ pub enum GetRecordsError {
     ResourceNotFound(String),
     /// <p><p>The operation attempted to read past the oldest stream record in a shard.</p> <p>In DynamoDB Streams, there is a 24 hour limit on data retention. Stream records whose age exceeds this limit are subject to removal (trimming) from the stream. You might receive a TrimmedDataAccessException if:</p> <ul> <li><p>You request a shard iterator with a sequence number older than the trim point (24 hours).</p> </li> <li><p>You obtain a shard iterator, but before you use the iterator in a <code>GetRecords</code> request, a stream record in the shard exceeds the 24 hour period and is trimmed. This causes the iterator to access a record that no longer exists.</p> </li> </ul></p>
     TrimmedDataAccess(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetRecordsError {
@@ -437,22 +450,25 @@ impl GetRecordsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ExpiredIteratorException" => {
-                    return RusotoError::Service(GetRecordsError::ExpiredIterator(err.msg))
+                    return RusotoError::service(GetRecordsError::ExpiredIterator(err.msg), &res)
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(GetRecordsError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        GetRecordsError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(GetRecordsError::LimitExceeded(err.msg))
+                    return RusotoError::service(GetRecordsError::LimitExceeded(err.msg), &res)
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetRecordsError::ResourceNotFound(err.msg))
+                    return RusotoError::service(GetRecordsError::ResourceNotFound(err.msg), &res)
                 }
                 "TrimmedDataAccessException" => {
-                    return RusotoError::Service(GetRecordsError::TrimmedDataAccess(err.msg))
+                    return RusotoError::service(GetRecordsError::TrimmedDataAccess(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetRecordsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -467,12 +483,14 @@ impl fmt::Display for GetRecordsError {
             GetRecordsError::LimitExceeded(ref cause) => write!(f, "{}", cause),
             GetRecordsError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             GetRecordsError::TrimmedDataAccess(ref cause) => write!(f, "{}", cause),
+            GetRecordsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetRecordsError {}
 /// Errors returned by GetShardIterator
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetShardIteratorError {
     /// <p>An error occurred on the server side.</p>
     InternalServerError(String),
@@ -480,6 +498,8 @@ pub enum GetShardIteratorError {
     ResourceNotFound(String),
     /// <p><p>The operation attempted to read past the oldest stream record in a shard.</p> <p>In DynamoDB Streams, there is a 24 hour limit on data retention. Stream records whose age exceeds this limit are subject to removal (trimming) from the stream. You might receive a TrimmedDataAccessException if:</p> <ul> <li><p>You request a shard iterator with a sequence number older than the trim point (24 hours).</p> </li> <li><p>You obtain a shard iterator, but before you use the iterator in a <code>GetRecords</code> request, a stream record in the shard exceeds the 24 hour period and is trimmed. This causes the iterator to access a record that no longer exists.</p> </li> </ul></p>
     TrimmedDataAccess(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetShardIteratorError {
@@ -487,18 +507,25 @@ impl GetShardIteratorError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(GetShardIteratorError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetShardIteratorError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetShardIteratorError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        GetShardIteratorError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "TrimmedDataAccessException" => {
-                    return RusotoError::Service(GetShardIteratorError::TrimmedDataAccess(err.msg))
+                    return RusotoError::service(
+                        GetShardIteratorError::TrimmedDataAccess(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetShardIteratorError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -511,17 +538,21 @@ impl fmt::Display for GetShardIteratorError {
             GetShardIteratorError::InternalServerError(ref cause) => write!(f, "{}", cause),
             GetShardIteratorError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             GetShardIteratorError::TrimmedDataAccess(ref cause) => write!(f, "{}", cause),
+            GetShardIteratorError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetShardIteratorError {}
 /// Errors returned by ListStreams
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListStreamsError {
     /// <p>An error occurred on the server side.</p>
     InternalServerError(String),
     /// <p>The operation tried to access a nonexistent stream.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListStreamsError {
@@ -529,13 +560,16 @@ impl ListStreamsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(ListStreamsError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        ListStreamsError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListStreamsError::ResourceNotFound(err.msg))
+                    return RusotoError::service(ListStreamsError::ResourceNotFound(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListStreamsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -547,6 +581,7 @@ impl fmt::Display for ListStreamsError {
         match *self {
             ListStreamsError::InternalServerError(ref cause) => write!(f, "{}", cause),
             ListStreamsError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            ListStreamsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
@@ -589,6 +624,7 @@ impl DynamoDbStreamsClient {
     /// Creates a client backed by the default tokio event loop.
     ///
     /// The client will use the default credentials provider and tls client.
+    #[cfg(not(target_arch = "wasm32"))]
     pub fn new(region: region::Region) -> DynamoDbStreamsClient {
         DynamoDbStreamsClient {
             client: Client::shared(),
@@ -614,115 +650,273 @@ impl DynamoDbStreamsClient {
     pub fn new_with_client(client: Client, region: region::Region) -> DynamoDbStreamsClient {
         DynamoDbStreamsClient { client, region }
     }
+
+    /// Returns a builder of a client, setting any of its region, credentials provider,
+    /// dispatcher, retry policy, endpoint and timeouts.
+    pub fn builder() -> rusoto_core::ClientBuilder<DynamoDbStreamsClient> {
+        rusoto_core::ClientBuilder::new(DynamoDbStreamsClient::new_with_client)
+    }
+
+    /// Returns a clone of this client with the overrides of `config` applied to its
+    /// operations, sharing its connections.
+    pub fn customize(&self, config: &rusoto_core::OperationConfig) -> DynamoDbStreamsClient {
+        DynamoDbStreamsClient {
+            client: self.client.customize(config),
+            region: config.resolve_region(&self.region),
+        }
+    }
 }
 
-#[async_trait]
-impl DynamoDbStreams for DynamoDbStreamsClient {
-    /// <p>Returns information about a stream, including the current status of the stream, its Amazon Resource Name (ARN), the composition of its shards, and its corresponding DynamoDB table.</p> <note> <p>You can call <code>DescribeStream</code> at a maximum rate of 10 times per second.</p> </note> <p>Each shard in the stream has a <code>SequenceNumberRange</code> associated with it. If the <code>SequenceNumberRange</code> has a <code>StartingSequenceNumber</code> but no <code>EndingSequenceNumber</code>, then the shard is still open (able to receive more stream records). If both <code>StartingSequenceNumber</code> and <code>EndingSequenceNumber</code> are present, then that shard is closed and can no longer receive more data.</p>
-    async fn describe_stream(
+impl DynamoDbStreamsClient {
+    /// Calls `describe_stream`, also returning the metadata of the response.
+    pub async fn describe_stream_with_metadata(
         &self,
         input: DescribeStreamInput,
-    ) -> Result<DescribeStreamOutput, RusotoError<DescribeStreamError>> {
+    ) -> Result<(DescribeStreamOutput, ResponseMetadata), RusotoError<DescribeStreamError>> {
+        let request = self.build_describe_stream_request(input);
+
+        let mut response = self
+            .client
+            .sign_and_dispatch(request)
+            .await
+            .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
+        if response.status.is_success() {
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DescribeStreamOutput, _>()?;
+            Ok((result, metadata))
+        } else {
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DescribeStreamError::from_response(response))
+        }
+    }
+
+    /// Presigns the request `describe_stream` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_stream(
+        &self,
+        presigner: &rusoto_core::Presigner,
+        input: DescribeStreamInput,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_stream_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_stream_request(&self, input: DescribeStreamInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "dynamodb", &self.region, "/");
+        request.set_operation("DescribeStream");
         request.set_endpoint_prefix("streams.dynamodb".to_string());
         request.set_content_type("application/x-amz-json-1.0".to_owned());
         request.add_header("x-amz-target", "DynamoDBStreams_20120810.DescribeStream");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `get_records`, also returning the metadata of the response.
+    pub async fn get_records_with_metadata(
+        &self,
+        input: GetRecordsInput,
+    ) -> Result<(GetRecordsOutput, ResponseMetadata), RusotoError<GetRecordsError>> {
+        let request = self.build_get_records_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<DescribeStreamOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<GetRecordsOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeStreamError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(GetRecordsError::from_response(response))
         }
     }
 
-    /// <p><p>Retrieves the stream records from a given shard.</p> <p>Specify a shard iterator using the <code>ShardIterator</code> parameter. The shard iterator specifies the position in the shard from which you want to start reading stream records sequentially. If there are no stream records available in the portion of the shard that the iterator points to, <code>GetRecords</code> returns an empty list. Note that it might take multiple calls to get to a portion of the shard that contains stream records.</p> <note> <p> <code>GetRecords</code> can retrieve a maximum of 1 MB of data or 1000 stream records, whichever comes first.</p> </note></p>
-    async fn get_records(
+    /// Presigns the request `get_records` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_records(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetRecordsInput,
-    ) -> Result<GetRecordsOutput, RusotoError<GetRecordsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_records_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_records_request(&self, input: GetRecordsInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "dynamodb", &self.region, "/");
+        request.set_operation("GetRecords");
         request.set_endpoint_prefix("streams.dynamodb".to_string());
         request.set_content_type("application/x-amz-json-1.0".to_owned());
         request.add_header("x-amz-target", "DynamoDBStreams_20120810.GetRecords");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `get_shard_iterator`, also returning the metadata of the response.
+    pub async fn get_shard_iterator_with_metadata(
+        &self,
+        input: GetShardIteratorInput,
+    ) -> Result<(GetShardIteratorOutput, ResponseMetadata), RusotoError<GetShardIteratorError>>
+    {
+        let request = self.build_get_shard_iterator_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<GetRecordsOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<GetShardIteratorOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(GetRecordsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(GetShardIteratorError::from_response(response))
         }
     }
 
-    /// <p><p>Returns a shard iterator. A shard iterator provides information about how to retrieve the stream records from within a shard. Use the shard iterator in a subsequent <code>GetRecords</code> request to read the stream records from the shard.</p> <note> <p>A shard iterator expires 15 minutes after it is returned to the requester.</p> </note></p>
-    async fn get_shard_iterator(
+    /// Presigns the request `get_shard_iterator` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_shard_iterator(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetShardIteratorInput,
-    ) -> Result<GetShardIteratorOutput, RusotoError<GetShardIteratorError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_shard_iterator_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_shard_iterator_request(&self, input: GetShardIteratorInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "dynamodb", &self.region, "/");
+        request.set_operation("GetShardIterator");
         request.set_endpoint_prefix("streams.dynamodb".to_string());
         request.set_content_type("application/x-amz-json-1.0".to_owned());
         request.add_header("x-amz-target", "DynamoDBStreams_20120810.GetShardIterator");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `list_streams`, also returning the metadata of the response.
+    pub async fn list_streams_with_metadata(
+        &self,
+        input: ListStreamsInput,
+    ) -> Result<(ListStreamsOutput, ResponseMetadata), RusotoError<ListStreamsError>> {
+        let request = self.build_list_streams_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<GetShardIteratorOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ListStreamsOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(GetShardIteratorError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ListStreamsError::from_response(response))
         }
     }
 
-    /// <p><p>Returns an array of stream ARNs associated with the current account and endpoint. If the <code>TableName</code> parameter is present, then <code>ListStreams</code> will return only the streams ARNs for that table.</p> <note> <p>You can call <code>ListStreams</code> at a maximum rate of 5 times per second.</p> </note></p>
-    async fn list_streams(
+    /// Presigns the request `list_streams` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_streams(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListStreamsInput,
-    ) -> Result<ListStreamsOutput, RusotoError<ListStreamsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_streams_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_streams_request(&self, input: ListStreamsInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "dynamodb", &self.region, "/");
+        request.set_operation("ListStreams");
         request.set_endpoint_prefix("streams.dynamodb".to_string());
         request.set_content_type("application/x-amz-json-1.0".to_owned());
         request.add_header("x-amz-target", "DynamoDBStreams_20120810.ListStreams");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
-        let mut response = self
-            .client
-            .sign_and_dispatch(request)
+        request
+    }
+}
+
+#[async_trait]
+impl DynamoDbStreams for DynamoDbStreamsClient {
+    /// <p>Returns information about a stream, including the current status of the stream, its Amazon Resource Name (ARN), the composition of its shards, and its corresponding DynamoDB table.</p> <note> <p>You can call <code>DescribeStream</code> at a maximum rate of 10 times per second.</p> </note> <p>Each shard in the stream has a <code>SequenceNumberRange</code> associated with it. If the <code>SequenceNumberRange</code> has a <code>StartingSequenceNumber</code> but no <code>EndingSequenceNumber</code>, then the shard is still open (able to receive more stream records). If both <code>StartingSequenceNumber</code> and <code>EndingSequenceNumber</code> are present, then that shard is closed and can no longer receive more data.</p>
+    async fn describe_stream(
+        &self,
+        input: DescribeStreamInput,
+    ) -> Result<DescribeStreamOutput, RusotoError<DescribeStreamError>> {
+        self.describe_stream_with_metadata(input)
             .await
-            .map_err(RusotoError::from)?;
-        if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<ListStreamsOutput, _>()
-        } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ListStreamsError::from_response(response))
-        }
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Retrieves the stream records from a given shard.</p> <p>Specify a shard iterator using the <code>ShardIterator</code> parameter. The shard iterator specifies the position in the shard from which you want to start reading stream records sequentially. If there are no stream records available in the portion of the shard that the iterator points to, <code>GetRecords</code> returns an empty list. Note that it might take multiple calls to get to a portion of the shard that contains stream records.</p> <note> <p> <code>GetRecords</code> can retrieve a maximum of 1 MB of data or 1000 stream records, whichever comes first.</p> </note></p>
+    async fn get_records(
+        &self,
+        input: GetRecordsInput,
+    ) -> Result<GetRecordsOutput, RusotoError<GetRecordsError>> {
+        self.get_records_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Returns a shard iterator. A shard iterator provides information about how to retrieve the stream records from within a shard. Use the shard iterator in a subsequent <code>GetRecords</code> request to read the stream records from the shard.</p> <note> <p>A shard iterator expires 15 minutes after it is returned to the requester.</p> </note></p>
+    async fn get_shard_iterator(
+        &self,
+        input: GetShardIteratorInput,
+    ) -> Result<GetShardIteratorOutput, RusotoError<GetShardIteratorError>> {
+        self.get_shard_iterator_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Returns an array of stream ARNs associated with the current account and endpoint. If the <code>TableName</code> parameter is present, then <code>ListStreams</code> will return only the streams ARNs for that table.</p> <note> <p>You can call <code>ListStreams</code> at a maximum rate of 5 times per second.</p> </note></p>
+    async fn list_streams(
+        &self,
+        input: ListStreamsInput,
+    ) -> Result<ListStreamsOutput, RusotoError<ListStreamsError>> {
+        self.list_streams_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
     }
 }
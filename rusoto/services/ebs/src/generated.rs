@@ -16,8 +16,8 @@ use std::fmt;
 use async_trait::async_trait;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
-use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest, ResponseMetadata};
+use rusoto_core::{Client, ErrorMetadata, RusotoError};
 
 use rusoto_core::param::{Params, ServiceParams};
 use rusoto_core::proto;
@@ -179,9 +179,12 @@ pub struct ListSnapshotBlocksResponse {
 
 /// Errors returned by GetSnapshotBlock
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetSnapshotBlockError {
     /// <p>The specified resource does not exist.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetSnapshotBlockError {
@@ -189,10 +192,13 @@ impl GetSnapshotBlockError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetSnapshotBlockError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        GetSnapshotBlockError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetSnapshotBlockError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -203,15 +209,19 @@ impl fmt::Display for GetSnapshotBlockError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             GetSnapshotBlockError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            GetSnapshotBlockError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetSnapshotBlockError {}
 /// Errors returned by ListChangedBlocks
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListChangedBlocksError {
     /// <p>The specified resource does not exist.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListChangedBlocksError {
@@ -219,10 +229,13 @@ impl ListChangedBlocksError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListChangedBlocksError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        ListChangedBlocksError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListChangedBlocksError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -233,15 +246,19 @@ impl fmt::Display for ListChangedBlocksError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             ListChangedBlocksError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            ListChangedBlocksError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListChangedBlocksError {}
 /// Errors returned by ListSnapshotBlocks
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListSnapshotBlocksError {
     /// <p>The specified resource does not exist.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListSnapshotBlocksError {
@@ -249,10 +266,13 @@ impl ListSnapshotBlocksError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListSnapshotBlocksError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        ListSnapshotBlocksError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListSnapshotBlocksError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -263,6 +283,7 @@ impl fmt::Display for ListSnapshotBlocksError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             ListSnapshotBlocksError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            ListSnapshotBlocksError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
@@ -299,6 +320,7 @@ impl EbsClient {
     /// Creates a client backed by the default tokio event loop.
     ///
     /// The client will use the default credentials provider and tls client.
+    #[cfg(not(target_arch = "wasm32"))]
     pub fn new(region: region::Region) -> EbsClient {
         EbsClient {
             client: Client::shared(),
@@ -324,35 +346,43 @@ impl EbsClient {
     pub fn new_with_client(client: Client, region: region::Region) -> EbsClient {
         EbsClient { client, region }
     }
+
+    /// Returns a builder of a client, setting any of its region, credentials provider,
+    /// dispatcher, retry policy, endpoint and timeouts.
+    pub fn builder() -> rusoto_core::ClientBuilder<EbsClient> {
+        rusoto_core::ClientBuilder::new(EbsClient::new_with_client)
+    }
+
+    /// Returns a clone of this client with the overrides of `config` applied to its
+    /// operations, sharing its connections.
+    pub fn customize(&self, config: &rusoto_core::OperationConfig) -> EbsClient {
+        EbsClient {
+            client: self.client.customize(config),
+            region: config.resolve_region(&self.region),
+        }
+    }
 }
 
-#[async_trait]
-impl Ebs for EbsClient {
-    /// <p>Returns the data in a block in an Amazon Elastic Block Store snapshot.</p>
-    async fn get_snapshot_block(
+impl EbsClient {
+    /// Calls `get_snapshot_block`, also returning the metadata of the response.
+    pub async fn get_snapshot_block_with_metadata(
         &self,
         input: GetSnapshotBlockRequest,
-    ) -> Result<GetSnapshotBlockResponse, RusotoError<GetSnapshotBlockError>> {
-        let request_uri = format!(
-            "/snapshots/{snapshot_id}/blocks/{block_index}",
-            block_index = input.block_index,
-            snapshot_id = input.snapshot_id
-        );
-
-        let mut request = SignedRequest::new("GET", "ebs", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let mut params = Params::new();
-        params.put("blockToken", &input.block_token);
-        request.set_params(params);
+    ) -> Result<(GetSnapshotBlockResponse, ResponseMetadata), RusotoError<GetSnapshotBlockError>>
+    {
+        let request = self.build_get_snapshot_block_request(input);
 
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
 
             let mut result = GetSnapshotBlockResponse::default();
             result.block_data = Some(response.body);
@@ -370,24 +400,94 @@ impl Ebs for EbsClient {
                 result.data_length = Some(value.parse::<i64>().unwrap())
             };
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             Err(GetSnapshotBlockError::from_response(response))
         }
     }
 
-    /// <p>Returns the block indexes and block tokens for blocks that are different between two Amazon Elastic Block Store snapshots of the same volume/snapshot lineage.</p>
-    async fn list_changed_blocks(
+    /// Presigns the request `get_snapshot_block` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_snapshot_block(
+        &self,
+        presigner: &rusoto_core::Presigner,
+        input: GetSnapshotBlockRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_snapshot_block_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_snapshot_block_request(&self, input: GetSnapshotBlockRequest) -> SignedRequest {
+        let request_uri = format!(
+            "/snapshots/{snapshot_id}/blocks/{block_index}",
+            block_index = input.block_index,
+            snapshot_id = input.snapshot_id
+        );
+
+        let mut request = SignedRequest::new("GET", "ebs", &self.region, &request_uri);
+        request.set_operation("GetSnapshotBlock");
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let mut params = Params::new();
+        params.put("blockToken", &input.block_token);
+        request.set_params(params);
+
+        request
+    }
+
+    /// Calls `list_changed_blocks`, also returning the metadata of the response.
+    pub async fn list_changed_blocks_with_metadata(
         &self,
         input: ListChangedBlocksRequest,
-    ) -> Result<ListChangedBlocksResponse, RusotoError<ListChangedBlocksError>> {
+    ) -> Result<(ListChangedBlocksResponse, ResponseMetadata), RusotoError<ListChangedBlocksError>>
+    {
+        let request = self.build_list_changed_blocks_request(input);
+
+        let mut response = self
+            .client
+            .sign_and_dispatch(request)
+            .await
+            .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
+        if response.status.is_success() {
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ListChangedBlocksResponse, _>()?;
+
+            Ok((result, metadata))
+        } else {
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(ListChangedBlocksError::from_response(response))
+        }
+    }
+
+    /// Presigns the request `list_changed_blocks` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_changed_blocks(
+        &self,
+        presigner: &rusoto_core::Presigner,
+        input: ListChangedBlocksRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_changed_blocks_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_changed_blocks_request(&self, input: ListChangedBlocksRequest) -> SignedRequest {
         let request_uri = format!(
             "/snapshots/{second_snapshot_id}/changedblocks",
             second_snapshot_id = input.second_snapshot_id
         );
 
         let mut request = SignedRequest::new("GET", "ebs", &self.region, &request_uri);
+        request.set_operation("ListChangedBlocks");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -405,34 +505,62 @@ impl Ebs for EbsClient {
         }
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `list_snapshot_blocks`, also returning the metadata of the response.
+    pub async fn list_snapshot_blocks_with_metadata(
+        &self,
+        input: ListSnapshotBlocksRequest,
+    ) -> Result<(ListSnapshotBlocksResponse, ResponseMetadata), RusotoError<ListSnapshotBlocksError>>
+    {
+        let request = self.build_list_snapshot_blocks_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListChangedBlocksResponse, _>()?;
+                .deserialize::<ListSnapshotBlocksResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(ListChangedBlocksError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(ListSnapshotBlocksError::from_response(response))
         }
     }
 
-    /// <p>Returns the block indexes and block tokens for blocks in an Amazon Elastic Block Store snapshot.</p>
-    async fn list_snapshot_blocks(
+    /// Presigns the request `list_snapshot_blocks` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_snapshot_blocks(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListSnapshotBlocksRequest,
-    ) -> Result<ListSnapshotBlocksResponse, RusotoError<ListSnapshotBlocksError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_snapshot_blocks_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_snapshot_blocks_request(
+        &self,
+        input: ListSnapshotBlocksRequest,
+    ) -> SignedRequest {
         let request_uri = format!(
             "/snapshots/{snapshot_id}/blocks",
             snapshot_id = input.snapshot_id
         );
 
         let mut request = SignedRequest::new("GET", "ebs", &self.region, &request_uri);
+        request.set_operation("ListSnapshotBlocks");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -447,20 +575,39 @@ impl Ebs for EbsClient {
         }
         request.set_params(params);
 
-        let mut response = self
-            .client
-            .sign_and_dispatch(request)
+        request
+    }
+}
+
+#[async_trait]
+impl Ebs for EbsClient {
+    /// <p>Returns the data in a block in an Amazon Elastic Block Store snapshot.</p>
+    async fn get_snapshot_block(
+        &self,
+        input: GetSnapshotBlockRequest,
+    ) -> Result<GetSnapshotBlockResponse, RusotoError<GetSnapshotBlockError>> {
+        self.get_snapshot_block_with_metadata(input)
             .await
-            .map_err(RusotoError::from)?;
-        if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListSnapshotBlocksResponse, _>()?;
+            .map(|(output, _)| output)
+    }
 
-            Ok(result)
-        } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(ListSnapshotBlocksError::from_response(response))
-        }
+    /// <p>Returns the block indexes and block tokens for blocks that are different between two Amazon Elastic Block Store snapshots of the same volume/snapshot lineage.</p>
+    async fn list_changed_blocks(
+        &self,
+        input: ListChangedBlocksRequest,
+    ) -> Result<ListChangedBlocksResponse, RusotoError<ListChangedBlocksError>> {
+        self.list_changed_blocks_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns the block indexes and block tokens for blocks in an Amazon Elastic Block Store snapshot.</p>
+    async fn list_snapshot_blocks(
+        &self,
+        input: ListSnapshotBlocksRequest,
+    ) -> Result<ListSnapshotBlocksResponse, RusotoError<ListSnapshotBlocksError>> {
+        self.list_snapshot_blocks_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
     }
 }
@@ -16,8 +16,8 @@ use std::fmt;
 use async_trait::async_trait;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
-use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest, ResponseMetadata};
+use rusoto_core::{Client, ErrorMetadata, RusotoError};
 
 use rusoto_core::proto;
 use rusoto_core::signature::SignedRequest;
@@ -56,6 +56,7 @@ pub struct SendSSHPublicKeyResponse {
 
 /// Errors returned by SendSSHPublicKey
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum SendSSHPublicKeyError {
     /// <p>Indicates that either your AWS credentials are invalid or you do not have access to the EC2 instance.</p>
     Auth(String),
@@ -67,6 +68,8 @@ pub enum SendSSHPublicKeyError {
     Service(String),
     /// <p>Indicates you have been making requests too frequently and have been throttled. Wait for a while and try again. If higher call volume is warranted contact AWS Support.</p>
     Throttling(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl SendSSHPublicKeyError {
@@ -74,24 +77,25 @@ impl SendSSHPublicKeyError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AuthException" => {
-                    return RusotoError::Service(SendSSHPublicKeyError::Auth(err.msg))
+                    return RusotoError::service(SendSSHPublicKeyError::Auth(err.msg), &res)
                 }
                 "EC2InstanceNotFoundException" => {
-                    return RusotoError::Service(SendSSHPublicKeyError::EC2InstanceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        SendSSHPublicKeyError::EC2InstanceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidArgsException" => {
-                    return RusotoError::Service(SendSSHPublicKeyError::InvalidArgs(err.msg))
+                    return RusotoError::service(SendSSHPublicKeyError::InvalidArgs(err.msg), &res)
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(SendSSHPublicKeyError::Service(err.msg))
+                    return RusotoError::service(SendSSHPublicKeyError::Service(err.msg), &res)
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(SendSSHPublicKeyError::Throttling(err.msg))
+                    return RusotoError::service(SendSSHPublicKeyError::Throttling(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(SendSSHPublicKeyError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -106,6 +110,7 @@ impl fmt::Display for SendSSHPublicKeyError {
             SendSSHPublicKeyError::InvalidArgs(ref cause) => write!(f, "{}", cause),
             SendSSHPublicKeyError::Service(ref cause) => write!(f, "{}", cause),
             SendSSHPublicKeyError::Throttling(ref cause) => write!(f, "{}", cause),
+            SendSSHPublicKeyError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
@@ -130,6 +135,7 @@ impl Ec2InstanceConnectClient {
     /// Creates a client backed by the default tokio event loop.
     ///
     /// The client will use the default credentials provider and tls client.
+    #[cfg(not(target_arch = "wasm32"))]
     pub fn new(region: region::Region) -> Ec2InstanceConnectClient {
         Ec2InstanceConnectClient {
             client: Client::shared(),
@@ -155,16 +161,68 @@ impl Ec2InstanceConnectClient {
     pub fn new_with_client(client: Client, region: region::Region) -> Ec2InstanceConnectClient {
         Ec2InstanceConnectClient { client, region }
     }
+
+    /// Returns a builder of a client, setting any of its region, credentials provider,
+    /// dispatcher, retry policy, endpoint and timeouts.
+    pub fn builder() -> rusoto_core::ClientBuilder<Ec2InstanceConnectClient> {
+        rusoto_core::ClientBuilder::new(Ec2InstanceConnectClient::new_with_client)
+    }
+
+    /// Returns a clone of this client with the overrides of `config` applied to its
+    /// operations, sharing its connections.
+    pub fn customize(&self, config: &rusoto_core::OperationConfig) -> Ec2InstanceConnectClient {
+        Ec2InstanceConnectClient {
+            client: self.client.customize(config),
+            region: config.resolve_region(&self.region),
+        }
+    }
 }
 
-#[async_trait]
-impl Ec2InstanceConnect for Ec2InstanceConnectClient {
-    /// <p>Pushes an SSH public key to a particular OS user on a given EC2 instance for 60 seconds.</p>
-    async fn send_ssh_public_key(
+impl Ec2InstanceConnectClient {
+    /// Calls `send_ssh_public_key`, also returning the metadata of the response.
+    pub async fn send_ssh_public_key_with_metadata(
         &self,
         input: SendSSHPublicKeyRequest,
-    ) -> Result<SendSSHPublicKeyResponse, RusotoError<SendSSHPublicKeyError>> {
+    ) -> Result<(SendSSHPublicKeyResponse, ResponseMetadata), RusotoError<SendSSHPublicKeyError>>
+    {
+        let request = self.build_send_ssh_public_key_request(input);
+
+        let mut response = self
+            .client
+            .sign_and_dispatch(request)
+            .await
+            .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
+        if response.status.is_success() {
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<SendSSHPublicKeyResponse, _>()?;
+            Ok((result, metadata))
+        } else {
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(SendSSHPublicKeyError::from_response(response))
+        }
+    }
+
+    /// Presigns the request `send_ssh_public_key` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_send_ssh_public_key(
+        &self,
+        presigner: &rusoto_core::Presigner,
+        input: SendSSHPublicKeyRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_send_ssh_public_key_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_send_ssh_public_key_request(&self, input: SendSSHPublicKeyRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "ec2-instance-connect", &self.region, "/");
+        request.set_operation("SendSSHPublicKey");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -174,19 +232,19 @@ impl Ec2InstanceConnect for Ec2InstanceConnectClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
-        let mut response = self
-            .client
-            .sign_and_dispatch(request)
+        request
+    }
+}
+
+#[async_trait]
+impl Ec2InstanceConnect for Ec2InstanceConnectClient {
+    /// <p>Pushes an SSH public key to a particular OS user on a given EC2 instance for 60 seconds.</p>
+    async fn send_ssh_public_key(
+        &self,
+        input: SendSSHPublicKeyRequest,
+    ) -> Result<SendSSHPublicKeyResponse, RusotoError<SendSSHPublicKeyError>> {
+        self.send_ssh_public_key_with_metadata(input)
             .await
-            .map_err(RusotoError::from)?;
-        if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<SendSSHPublicKeyResponse, _>()
-        } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(SendSSHPublicKeyError::from_response(response))
-        }
+            .map(|(output, _)| output)
     }
 }
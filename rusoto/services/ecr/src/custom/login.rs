@@ -0,0 +1,230 @@
+use std::sync::Arc;
+
+use chrono::{DateTime, Duration, TimeZone, Utc};
+use futures::lock::Mutex;
+
+use rusoto_core::RusotoError;
+
+use crate::{
+    AuthorizationData, Ecr, EcrClient, GetAuthorizationTokenError, GetAuthorizationTokenRequest,
+};
+
+/// The credentials `docker login` takes for an ECR registry.
+#[derive(Clone, PartialEq)]
+pub struct RegistryCredentials {
+    /// The user name, always `AWS`.
+    pub username: String,
+    /// The password, valid for 12 hours.
+    pub password: String,
+    /// The URL of the registry, e.g. `https://111122223333.dkr.ecr.eu-west-1.amazonaws.com`.
+    pub registry: String,
+    /// When the password expires.
+    pub expires_at: Option<DateTime<Utc>>,
+}
+
+impl RegistryCredentials {
+    /// The hostname of the registry, without the scheme, as image names start with.
+    pub fn registry_host(&self) -> &str {
+        let registry = self.registry.trim_end_matches('/');
+        match registry.find("://") {
+            Some(index) => &registry[index + 3..],
+            None => registry,
+        }
+    }
+}
+
+impl std::fmt::Debug for RegistryCredentials {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.debug_struct("RegistryCredentials")
+            .field("username", &self.username)
+            .field("password", &"**********")
+            .field("registry", &self.registry)
+            .field("expires_at", &self.expires_at)
+            .finish()
+    }
+}
+
+/// Gets the credentials of ECR registries with `ecr:GetAuthorizationToken`, decoding the
+/// user name and password of docker from the token.
+///
+/// The credentials are cached and fetched again five minutes before they expire, clones of the
+/// helper share the cache.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use rusoto_core::Region;
+/// use rusoto_ecr::{EcrClient, RegistryCredentialsHelper};
+///
+/// # async fn login() -> Result<(), Box<dyn std::error::Error>> {
+/// let helper = RegistryCredentialsHelper::new(EcrClient::new(Region::EuWest1));
+/// let credentials = helper.credentials().await?;
+/// println!(
+///     "docker login --username {} --password-stdin {}",
+///     credentials.username,
+///     credentials.registry_host()
+/// );
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone)]
+pub struct RegistryCredentialsHelper {
+    client: EcrClient,
+    registry_ids: Option<Vec<String>>,
+    credentials: Arc<Mutex<Option<Vec<RegistryCredentials>>>>,
+}
+
+impl RegistryCredentialsHelper {
+    /// Creates a helper for the default registry of the account of the client's credentials.
+    pub fn new(client: EcrClient) -> RegistryCredentialsHelper {
+        RegistryCredentialsHelper {
+            client,
+            registry_ids: None,
+            credentials: Default::default(),
+        }
+    }
+
+    /// Gets the credentials of the registries of the accounts `registry_ids` instead.
+    pub fn set_registry_ids(&mut self, registry_ids: Vec<String>) {
+        self.registry_ids = Some(registry_ids);
+        self.credentials = Default::default();
+    }
+
+    /// The credentials of the first registry.
+    pub async fn credentials(
+        &self,
+    ) -> Result<RegistryCredentials, RusotoError<GetAuthorizationTokenError>> {
+        self.all_credentials()
+            .await?
+            .into_iter()
+            .next()
+            .ok_or_else(|| RusotoError::ParseError("no authorization data".to_owned()))
+    }
+
+    /// The credentials of the registry a docker credential helper is asked for, a hostname like
+    /// `111122223333.dkr.ecr.eu-west-1.amazonaws.com` or its URL, or `None` if the registry is
+    /// not one of those of the helper.
+    pub async fn credentials_for(
+        &self,
+        registry: &str,
+    ) -> Result<Option<RegistryCredentials>, RusotoError<GetAuthorizationTokenError>> {
+        let host = registry
+            .trim_start_matches("https://")
+            .trim_start_matches("http://")
+            .trim_end_matches('/');
+        Ok(self
+            .all_credentials()
+            .await?
+            .into_iter()
+            .find(|credentials| credentials.registry_host() == host))
+    }
+
+    /// The credentials of all registries of the helper.
+    pub async fn all_credentials(
+        &self,
+    ) -> Result<Vec<RegistryCredentials>, RusotoError<GetAuthorizationTokenError>> {
+        let mut cached = self.credentials.lock().await;
+        if let Some(ref credentials) = *cached {
+            if !credentials.iter().any(is_expiring) {
+                return Ok(credentials.clone());
+            }
+        }
+        let response = self
+            .client
+            .get_authorization_token(GetAuthorizationTokenRequest {
+                registry_ids: self.registry_ids.clone(),
+            })
+            .await?;
+        let credentials = response
+            .authorization_data
+            .unwrap_or_default()
+            .into_iter()
+            .map(registry_credentials)
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(RusotoError::ParseError)?;
+        *cached = Some(credentials.clone());
+        Ok(credentials)
+    }
+}
+
+impl std::fmt::Debug for RegistryCredentialsHelper {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.debug_struct("RegistryCredentialsHelper")
+            .field("registry_ids", &self.registry_ids)
+            .finish()
+    }
+}
+
+/// Credentials are fetched again this long before they expire.
+fn is_expiring(credentials: &RegistryCredentials) -> bool {
+    match credentials.expires_at {
+        Some(expires_at) => expires_at < Utc::now() + Duration::minutes(5),
+        None => false,
+    }
+}
+
+/// Decodes the token of `data`, the base64 encoded `<user name>:<password>`.
+fn registry_credentials(data: AuthorizationData) -> Result<RegistryCredentials, String> {
+    let token = data
+        .authorization_token
+        .ok_or_else(|| "no authorization token".to_owned())?;
+    let token = base64::decode(&token)
+        .ok()
+        .and_then(|token| String::from_utf8(token).ok())
+        .ok_or_else(|| "invalid authorization token".to_owned())?;
+    let separator = token
+        .find(':')
+        .ok_or_else(|| "authorization token without password".to_owned())?;
+    Ok(RegistryCredentials {
+        username: token[..separator].to_owned(),
+        password: token[separator + 1..].to_owned(),
+        registry: data
+            .proxy_endpoint
+            .ok_or_else(|| "no proxy endpoint".to_owned())?,
+        expires_at: data
+            .expires_at
+            .and_then(|seconds| Utc.timestamp_opt(seconds as i64, 0).single()),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rusoto_core::signature::SignedRequest;
+    use rusoto_core::Region;
+    use rusoto_mock::{MockCredentialsProvider, MockRequestDispatcher};
+    use std::sync::Mutex as StdMutex;
+
+    #[tokio::test]
+    async fn decodes_and_caches_registry_credentials() {
+        let requests = Arc::new(StdMutex::new(0));
+        let counted = requests.clone();
+        // `AWS:password` in base64.
+        let dispatcher = MockRequestDispatcher::default()
+            .with_body(
+                r#"{"authorizationData": [{"authorizationToken": "QVdTOnBhc3N3b3Jk",
+                    "expiresAt": 4102444800,
+                    "proxyEndpoint": "https://111122223333.dkr.ecr.eu-west-1.amazonaws.com"}]}"#,
+            )
+            .with_request_checker(move |_: &SignedRequest| *counted.lock().unwrap() += 1);
+        let client = EcrClient::new_with(dispatcher, MockCredentialsProvider, Region::EuWest1);
+        let helper = RegistryCredentialsHelper::new(client);
+
+        let credentials = helper.credentials().await.unwrap();
+        assert_eq!(credentials.username, "AWS");
+        assert_eq!(credentials.password, "password");
+        assert_eq!(
+            credentials.registry_host(),
+            "111122223333.dkr.ecr.eu-west-1.amazonaws.com"
+        );
+        assert_eq!(credentials.expires_at.unwrap().timestamp(), 4_102_444_800);
+
+        let found = helper
+            .credentials_for("111122223333.dkr.ecr.eu-west-1.amazonaws.com")
+            .await
+            .unwrap();
+        assert_eq!(found, Some(credentials));
+        assert_eq!(helper.credentials_for("docker.io").await.unwrap(), None);
+        assert_eq!(*requests.lock().unwrap(), 1);
+    }
+}
@@ -1 +1,3 @@
+mod login;
 
+pub use self::login::{RegistryCredentials, RegistryCredentialsHelper};
@@ -16,8 +16,8 @@ use std::fmt;
 use async_trait::async_trait;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
-use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest, ResponseMetadata};
+use rusoto_core::{Client, ErrorMetadata, RusotoError};
 
 use rusoto_core::proto;
 use rusoto_core::signature::SignedRequest;
@@ -1327,6 +1327,7 @@ pub struct UploadLayerPartResponse {
 
 /// Errors returned by BatchCheckLayerAvailability
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum BatchCheckLayerAvailabilityError {
     /// <p>The specified parameter is invalid. Review the available parameters for the API request.</p>
     InvalidParameter(String),
@@ -1334,6 +1335,8 @@ pub enum BatchCheckLayerAvailabilityError {
     RepositoryNotFound(String),
     /// <p>These errors are usually caused by a server-side issue.</p>
     Server(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl BatchCheckLayerAvailabilityError {
@@ -1343,20 +1346,30 @@ impl BatchCheckLayerAvailabilityError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParameterException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         BatchCheckLayerAvailabilityError::InvalidParameter(err.msg),
+                        &res,
                     )
                 }
                 "RepositoryNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         BatchCheckLayerAvailabilityError::RepositoryNotFound(err.msg),
+                        &res,
                     )
                 }
                 "ServerException" => {
-                    return RusotoError::Service(BatchCheckLayerAvailabilityError::Server(err.msg))
+                    return RusotoError::service(
+                        BatchCheckLayerAvailabilityError::Server(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        BatchCheckLayerAvailabilityError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -1371,12 +1384,14 @@ impl fmt::Display for BatchCheckLayerAvailabilityError {
                 write!(f, "{}", cause)
             }
             BatchCheckLayerAvailabilityError::Server(ref cause) => write!(f, "{}", cause),
+            BatchCheckLayerAvailabilityError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for BatchCheckLayerAvailabilityError {}
 /// Errors returned by BatchDeleteImage
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum BatchDeleteImageError {
     /// <p>The specified parameter is invalid. Review the available parameters for the API request.</p>
     InvalidParameter(String),
@@ -1384,6 +1399,8 @@ pub enum BatchDeleteImageError {
     RepositoryNotFound(String),
     /// <p>These errors are usually caused by a server-side issue.</p>
     Server(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl BatchDeleteImageError {
@@ -1391,16 +1408,22 @@ impl BatchDeleteImageError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParameterException" => {
-                    return RusotoError::Service(BatchDeleteImageError::InvalidParameter(err.msg))
+                    return RusotoError::service(
+                        BatchDeleteImageError::InvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "RepositoryNotFoundException" => {
-                    return RusotoError::Service(BatchDeleteImageError::RepositoryNotFound(err.msg))
+                    return RusotoError::service(
+                        BatchDeleteImageError::RepositoryNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ServerException" => {
-                    return RusotoError::Service(BatchDeleteImageError::Server(err.msg))
+                    return RusotoError::service(BatchDeleteImageError::Server(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(BatchDeleteImageError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1413,12 +1436,14 @@ impl fmt::Display for BatchDeleteImageError {
             BatchDeleteImageError::InvalidParameter(ref cause) => write!(f, "{}", cause),
             BatchDeleteImageError::RepositoryNotFound(ref cause) => write!(f, "{}", cause),
             BatchDeleteImageError::Server(ref cause) => write!(f, "{}", cause),
+            BatchDeleteImageError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for BatchDeleteImageError {}
 /// Errors returned by BatchGetImage
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum BatchGetImageError {
     /// <p>The specified parameter is invalid. Review the available parameters for the API request.</p>
     InvalidParameter(String),
@@ -1426,6 +1451,8 @@ pub enum BatchGetImageError {
     RepositoryNotFound(String),
     /// <p>These errors are usually caused by a server-side issue.</p>
     Server(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl BatchGetImageError {
@@ -1433,16 +1460,22 @@ impl BatchGetImageError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParameterException" => {
-                    return RusotoError::Service(BatchGetImageError::InvalidParameter(err.msg))
+                    return RusotoError::service(
+                        BatchGetImageError::InvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "RepositoryNotFoundException" => {
-                    return RusotoError::Service(BatchGetImageError::RepositoryNotFound(err.msg))
+                    return RusotoError::service(
+                        BatchGetImageError::RepositoryNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ServerException" => {
-                    return RusotoError::Service(BatchGetImageError::Server(err.msg))
+                    return RusotoError::service(BatchGetImageError::Server(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(BatchGetImageError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1455,12 +1488,14 @@ impl fmt::Display for BatchGetImageError {
             BatchGetImageError::InvalidParameter(ref cause) => write!(f, "{}", cause),
             BatchGetImageError::RepositoryNotFound(ref cause) => write!(f, "{}", cause),
             BatchGetImageError::Server(ref cause) => write!(f, "{}", cause),
+            BatchGetImageError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for BatchGetImageError {}
 /// Errors returned by CompleteLayerUpload
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CompleteLayerUploadError {
     /// <p>The specified layer upload does not contain any layer parts.</p>
     EmptyUpload(String),
@@ -1478,6 +1513,8 @@ pub enum CompleteLayerUploadError {
     Server(String),
     /// <p>The upload could not be found, or the specified upload id is not valid for this repository.</p>
     UploadNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CompleteLayerUploadError {
@@ -1485,39 +1522,52 @@ impl CompleteLayerUploadError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "EmptyUploadException" => {
-                    return RusotoError::Service(CompleteLayerUploadError::EmptyUpload(err.msg))
+                    return RusotoError::service(
+                        CompleteLayerUploadError::EmptyUpload(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidLayerException" => {
-                    return RusotoError::Service(CompleteLayerUploadError::InvalidLayer(err.msg))
+                    return RusotoError::service(
+                        CompleteLayerUploadError::InvalidLayer(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(CompleteLayerUploadError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CompleteLayerUploadError::InvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "LayerAlreadyExistsException" => {
-                    return RusotoError::Service(CompleteLayerUploadError::LayerAlreadyExists(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CompleteLayerUploadError::LayerAlreadyExists(err.msg),
+                        &res,
+                    )
                 }
                 "LayerPartTooSmallException" => {
-                    return RusotoError::Service(CompleteLayerUploadError::LayerPartTooSmall(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CompleteLayerUploadError::LayerPartTooSmall(err.msg),
+                        &res,
+                    )
                 }
                 "RepositoryNotFoundException" => {
-                    return RusotoError::Service(CompleteLayerUploadError::RepositoryNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CompleteLayerUploadError::RepositoryNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ServerException" => {
-                    return RusotoError::Service(CompleteLayerUploadError::Server(err.msg))
+                    return RusotoError::service(CompleteLayerUploadError::Server(err.msg), &res)
                 }
                 "UploadNotFoundException" => {
-                    return RusotoError::Service(CompleteLayerUploadError::UploadNotFound(err.msg))
+                    return RusotoError::service(
+                        CompleteLayerUploadError::UploadNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CompleteLayerUploadError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1535,12 +1585,14 @@ impl fmt::Display for CompleteLayerUploadError {
             CompleteLayerUploadError::RepositoryNotFound(ref cause) => write!(f, "{}", cause),
             CompleteLayerUploadError::Server(ref cause) => write!(f, "{}", cause),
             CompleteLayerUploadError::UploadNotFound(ref cause) => write!(f, "{}", cause),
+            CompleteLayerUploadError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CompleteLayerUploadError {}
 /// Errors returned by CreateRepository
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateRepositoryError {
     /// <p>The specified parameter is invalid. Review the available parameters for the API request.</p>
     InvalidParameter(String),
@@ -1554,6 +1606,8 @@ pub enum CreateRepositoryError {
     Server(String),
     /// <p>The list of tags on the repository is over the limit. The maximum number of tags that can be applied to a repository is 50.</p>
     TooManyTags(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateRepositoryError {
@@ -1561,29 +1615,37 @@ impl CreateRepositoryError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParameterException" => {
-                    return RusotoError::Service(CreateRepositoryError::InvalidParameter(err.msg))
+                    return RusotoError::service(
+                        CreateRepositoryError::InvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidTagParameterException" => {
-                    return RusotoError::Service(CreateRepositoryError::InvalidTagParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateRepositoryError::InvalidTagParameter(err.msg),
+                        &res,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateRepositoryError::LimitExceeded(err.msg))
+                    return RusotoError::service(
+                        CreateRepositoryError::LimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "RepositoryAlreadyExistsException" => {
-                    return RusotoError::Service(CreateRepositoryError::RepositoryAlreadyExists(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateRepositoryError::RepositoryAlreadyExists(err.msg),
+                        &res,
+                    )
                 }
                 "ServerException" => {
-                    return RusotoError::Service(CreateRepositoryError::Server(err.msg))
+                    return RusotoError::service(CreateRepositoryError::Server(err.msg), &res)
                 }
                 "TooManyTagsException" => {
-                    return RusotoError::Service(CreateRepositoryError::TooManyTags(err.msg))
+                    return RusotoError::service(CreateRepositoryError::TooManyTags(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreateRepositoryError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1599,12 +1661,14 @@ impl fmt::Display for CreateRepositoryError {
             CreateRepositoryError::RepositoryAlreadyExists(ref cause) => write!(f, "{}", cause),
             CreateRepositoryError::Server(ref cause) => write!(f, "{}", cause),
             CreateRepositoryError::TooManyTags(ref cause) => write!(f, "{}", cause),
+            CreateRepositoryError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateRepositoryError {}
 /// Errors returned by DeleteLifecyclePolicy
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteLifecyclePolicyError {
     /// <p>The specified parameter is invalid. Review the available parameters for the API request.</p>
     InvalidParameter(String),
@@ -1614,6 +1678,8 @@ pub enum DeleteLifecyclePolicyError {
     RepositoryNotFound(String),
     /// <p>These errors are usually caused by a server-side issue.</p>
     Server(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteLifecyclePolicyError {
@@ -1621,25 +1687,28 @@ impl DeleteLifecyclePolicyError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DeleteLifecyclePolicyError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteLifecyclePolicyError::InvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "LifecyclePolicyNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DeleteLifecyclePolicyError::LifecyclePolicyNotFound(err.msg),
+                        &res,
                     )
                 }
                 "RepositoryNotFoundException" => {
-                    return RusotoError::Service(DeleteLifecyclePolicyError::RepositoryNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteLifecyclePolicyError::RepositoryNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ServerException" => {
-                    return RusotoError::Service(DeleteLifecyclePolicyError::Server(err.msg))
+                    return RusotoError::service(DeleteLifecyclePolicyError::Server(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteLifecyclePolicyError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1655,12 +1724,14 @@ impl fmt::Display for DeleteLifecyclePolicyError {
             }
             DeleteLifecyclePolicyError::RepositoryNotFound(ref cause) => write!(f, "{}", cause),
             DeleteLifecyclePolicyError::Server(ref cause) => write!(f, "{}", cause),
+            DeleteLifecyclePolicyError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteLifecyclePolicyError {}
 /// Errors returned by DeleteRepository
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteRepositoryError {
     /// <p>The specified parameter is invalid. Review the available parameters for the API request.</p>
     InvalidParameter(String),
@@ -1670,6 +1741,8 @@ pub enum DeleteRepositoryError {
     RepositoryNotFound(String),
     /// <p>These errors are usually caused by a server-side issue.</p>
     Server(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteRepositoryError {
@@ -1677,19 +1750,28 @@ impl DeleteRepositoryError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DeleteRepositoryError::InvalidParameter(err.msg))
+                    return RusotoError::service(
+                        DeleteRepositoryError::InvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "RepositoryNotEmptyException" => {
-                    return RusotoError::Service(DeleteRepositoryError::RepositoryNotEmpty(err.msg))
+                    return RusotoError::service(
+                        DeleteRepositoryError::RepositoryNotEmpty(err.msg),
+                        &res,
+                    )
                 }
                 "RepositoryNotFoundException" => {
-                    return RusotoError::Service(DeleteRepositoryError::RepositoryNotFound(err.msg))
+                    return RusotoError::service(
+                        DeleteRepositoryError::RepositoryNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ServerException" => {
-                    return RusotoError::Service(DeleteRepositoryError::Server(err.msg))
+                    return RusotoError::service(DeleteRepositoryError::Server(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteRepositoryError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1703,12 +1785,14 @@ impl fmt::Display for DeleteRepositoryError {
             DeleteRepositoryError::RepositoryNotEmpty(ref cause) => write!(f, "{}", cause),
             DeleteRepositoryError::RepositoryNotFound(ref cause) => write!(f, "{}", cause),
             DeleteRepositoryError::Server(ref cause) => write!(f, "{}", cause),
+            DeleteRepositoryError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteRepositoryError {}
 /// Errors returned by DeleteRepositoryPolicy
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteRepositoryPolicyError {
     /// <p>The specified parameter is invalid. Review the available parameters for the API request.</p>
     InvalidParameter(String),
@@ -1718,6 +1802,8 @@ pub enum DeleteRepositoryPolicyError {
     RepositoryPolicyNotFound(String),
     /// <p>These errors are usually caused by a server-side issue.</p>
     Server(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteRepositoryPolicyError {
@@ -1725,25 +1811,28 @@ impl DeleteRepositoryPolicyError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DeleteRepositoryPolicyError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteRepositoryPolicyError::InvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "RepositoryNotFoundException" => {
-                    return RusotoError::Service(DeleteRepositoryPolicyError::RepositoryNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteRepositoryPolicyError::RepositoryNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "RepositoryPolicyNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DeleteRepositoryPolicyError::RepositoryPolicyNotFound(err.msg),
+                        &res,
                     )
                 }
                 "ServerException" => {
-                    return RusotoError::Service(DeleteRepositoryPolicyError::Server(err.msg))
+                    return RusotoError::service(DeleteRepositoryPolicyError::Server(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteRepositoryPolicyError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1759,12 +1848,14 @@ impl fmt::Display for DeleteRepositoryPolicyError {
                 write!(f, "{}", cause)
             }
             DeleteRepositoryPolicyError::Server(ref cause) => write!(f, "{}", cause),
+            DeleteRepositoryPolicyError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteRepositoryPolicyError {}
 /// Errors returned by DescribeImageScanFindings
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeImageScanFindingsError {
     /// <p>The image requested does not exist in the specified repository.</p>
     ImageNotFound(String),
@@ -1776,6 +1867,8 @@ pub enum DescribeImageScanFindingsError {
     ScanNotFound(String),
     /// <p>These errors are usually caused by a server-side issue.</p>
     Server(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeImageScanFindingsError {
@@ -1783,30 +1876,39 @@ impl DescribeImageScanFindingsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ImageNotFoundException" => {
-                    return RusotoError::Service(DescribeImageScanFindingsError::ImageNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeImageScanFindingsError::ImageNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DescribeImageScanFindingsError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeImageScanFindingsError::InvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "RepositoryNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DescribeImageScanFindingsError::RepositoryNotFound(err.msg),
+                        &res,
                     )
                 }
                 "ScanNotFoundException" => {
-                    return RusotoError::Service(DescribeImageScanFindingsError::ScanNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeImageScanFindingsError::ScanNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ServerException" => {
-                    return RusotoError::Service(DescribeImageScanFindingsError::Server(err.msg))
+                    return RusotoError::service(
+                        DescribeImageScanFindingsError::Server(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(DescribeImageScanFindingsError::Unhandled, &res)
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -1821,12 +1923,14 @@ impl fmt::Display for DescribeImageScanFindingsError {
             DescribeImageScanFindingsError::RepositoryNotFound(ref cause) => write!(f, "{}", cause),
             DescribeImageScanFindingsError::ScanNotFound(ref cause) => write!(f, "{}", cause),
             DescribeImageScanFindingsError::Server(ref cause) => write!(f, "{}", cause),
+            DescribeImageScanFindingsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeImageScanFindingsError {}
 /// Errors returned by DescribeImages
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeImagesError {
     /// <p>The image requested does not exist in the specified repository.</p>
     ImageNotFound(String),
@@ -1836,6 +1940,8 @@ pub enum DescribeImagesError {
     RepositoryNotFound(String),
     /// <p>These errors are usually caused by a server-side issue.</p>
     Server(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeImagesError {
@@ -1843,19 +1949,25 @@ impl DescribeImagesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ImageNotFoundException" => {
-                    return RusotoError::Service(DescribeImagesError::ImageNotFound(err.msg))
+                    return RusotoError::service(DescribeImagesError::ImageNotFound(err.msg), &res)
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DescribeImagesError::InvalidParameter(err.msg))
+                    return RusotoError::service(
+                        DescribeImagesError::InvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "RepositoryNotFoundException" => {
-                    return RusotoError::Service(DescribeImagesError::RepositoryNotFound(err.msg))
+                    return RusotoError::service(
+                        DescribeImagesError::RepositoryNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ServerException" => {
-                    return RusotoError::Service(DescribeImagesError::Server(err.msg))
+                    return RusotoError::service(DescribeImagesError::Server(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DescribeImagesError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1869,12 +1981,14 @@ impl fmt::Display for DescribeImagesError {
             DescribeImagesError::InvalidParameter(ref cause) => write!(f, "{}", cause),
             DescribeImagesError::RepositoryNotFound(ref cause) => write!(f, "{}", cause),
             DescribeImagesError::Server(ref cause) => write!(f, "{}", cause),
+            DescribeImagesError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeImagesError {}
 /// Errors returned by DescribeRepositories
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeRepositoriesError {
     /// <p>The specified parameter is invalid. Review the available parameters for the API request.</p>
     InvalidParameter(String),
@@ -1882,6 +1996,8 @@ pub enum DescribeRepositoriesError {
     RepositoryNotFound(String),
     /// <p>These errors are usually caused by a server-side issue.</p>
     Server(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeRepositoriesError {
@@ -1889,20 +2005,22 @@ impl DescribeRepositoriesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DescribeRepositoriesError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeRepositoriesError::InvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "RepositoryNotFoundException" => {
-                    return RusotoError::Service(DescribeRepositoriesError::RepositoryNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeRepositoriesError::RepositoryNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ServerException" => {
-                    return RusotoError::Service(DescribeRepositoriesError::Server(err.msg))
+                    return RusotoError::service(DescribeRepositoriesError::Server(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DescribeRepositoriesError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1915,17 +2033,21 @@ impl fmt::Display for DescribeRepositoriesError {
             DescribeRepositoriesError::InvalidParameter(ref cause) => write!(f, "{}", cause),
             DescribeRepositoriesError::RepositoryNotFound(ref cause) => write!(f, "{}", cause),
             DescribeRepositoriesError::Server(ref cause) => write!(f, "{}", cause),
+            DescribeRepositoriesError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeRepositoriesError {}
 /// Errors returned by GetAuthorizationToken
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetAuthorizationTokenError {
     /// <p>The specified parameter is invalid. Review the available parameters for the API request.</p>
     InvalidParameter(String),
     /// <p>These errors are usually caused by a server-side issue.</p>
     Server(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetAuthorizationTokenError {
@@ -1933,15 +2055,16 @@ impl GetAuthorizationTokenError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParameterException" => {
-                    return RusotoError::Service(GetAuthorizationTokenError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetAuthorizationTokenError::InvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "ServerException" => {
-                    return RusotoError::Service(GetAuthorizationTokenError::Server(err.msg))
+                    return RusotoError::service(GetAuthorizationTokenError::Server(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetAuthorizationTokenError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1953,12 +2076,14 @@ impl fmt::Display for GetAuthorizationTokenError {
         match *self {
             GetAuthorizationTokenError::InvalidParameter(ref cause) => write!(f, "{}", cause),
             GetAuthorizationTokenError::Server(ref cause) => write!(f, "{}", cause),
+            GetAuthorizationTokenError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetAuthorizationTokenError {}
 /// Errors returned by GetDownloadUrlForLayer
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetDownloadUrlForLayerError {
     /// <p>The specified parameter is invalid. Review the available parameters for the API request.</p>
     InvalidParameter(String),
@@ -1970,6 +2095,8 @@ pub enum GetDownloadUrlForLayerError {
     RepositoryNotFound(String),
     /// <p>These errors are usually caused by a server-side issue.</p>
     Server(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetDownloadUrlForLayerError {
@@ -1977,30 +2104,34 @@ impl GetDownloadUrlForLayerError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParameterException" => {
-                    return RusotoError::Service(GetDownloadUrlForLayerError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetDownloadUrlForLayerError::InvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "LayerInaccessibleException" => {
-                    return RusotoError::Service(GetDownloadUrlForLayerError::LayerInaccessible(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetDownloadUrlForLayerError::LayerInaccessible(err.msg),
+                        &res,
+                    )
                 }
                 "LayersNotFoundException" => {
-                    return RusotoError::Service(GetDownloadUrlForLayerError::LayersNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetDownloadUrlForLayerError::LayersNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "RepositoryNotFoundException" => {
-                    return RusotoError::Service(GetDownloadUrlForLayerError::RepositoryNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetDownloadUrlForLayerError::RepositoryNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ServerException" => {
-                    return RusotoError::Service(GetDownloadUrlForLayerError::Server(err.msg))
+                    return RusotoError::service(GetDownloadUrlForLayerError::Server(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetDownloadUrlForLayerError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2015,12 +2146,14 @@ impl fmt::Display for GetDownloadUrlForLayerError {
             GetDownloadUrlForLayerError::LayersNotFound(ref cause) => write!(f, "{}", cause),
             GetDownloadUrlForLayerError::RepositoryNotFound(ref cause) => write!(f, "{}", cause),
             GetDownloadUrlForLayerError::Server(ref cause) => write!(f, "{}", cause),
+            GetDownloadUrlForLayerError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetDownloadUrlForLayerError {}
 /// Errors returned by GetLifecyclePolicy
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetLifecyclePolicyError {
     /// <p>The specified parameter is invalid. Review the available parameters for the API request.</p>
     InvalidParameter(String),
@@ -2030,6 +2163,8 @@ pub enum GetLifecyclePolicyError {
     RepositoryNotFound(String),
     /// <p>These errors are usually caused by a server-side issue.</p>
     Server(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetLifecyclePolicyError {
@@ -2037,23 +2172,28 @@ impl GetLifecyclePolicyError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParameterException" => {
-                    return RusotoError::Service(GetLifecyclePolicyError::InvalidParameter(err.msg))
+                    return RusotoError::service(
+                        GetLifecyclePolicyError::InvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "LifecyclePolicyNotFoundException" => {
-                    return RusotoError::Service(GetLifecyclePolicyError::LifecyclePolicyNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetLifecyclePolicyError::LifecyclePolicyNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "RepositoryNotFoundException" => {
-                    return RusotoError::Service(GetLifecyclePolicyError::RepositoryNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetLifecyclePolicyError::RepositoryNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ServerException" => {
-                    return RusotoError::Service(GetLifecyclePolicyError::Server(err.msg))
+                    return RusotoError::service(GetLifecyclePolicyError::Server(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetLifecyclePolicyError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2067,12 +2207,14 @@ impl fmt::Display for GetLifecyclePolicyError {
             GetLifecyclePolicyError::LifecyclePolicyNotFound(ref cause) => write!(f, "{}", cause),
             GetLifecyclePolicyError::RepositoryNotFound(ref cause) => write!(f, "{}", cause),
             GetLifecyclePolicyError::Server(ref cause) => write!(f, "{}", cause),
+            GetLifecyclePolicyError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetLifecyclePolicyError {}
 /// Errors returned by GetLifecyclePolicyPreview
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetLifecyclePolicyPreviewError {
     /// <p>The specified parameter is invalid. Review the available parameters for the API request.</p>
     InvalidParameter(String),
@@ -2082,6 +2224,8 @@ pub enum GetLifecyclePolicyPreviewError {
     RepositoryNotFound(String),
     /// <p>These errors are usually caused by a server-side issue.</p>
     Server(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetLifecyclePolicyPreviewError {
@@ -2089,25 +2233,33 @@ impl GetLifecyclePolicyPreviewError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParameterException" => {
-                    return RusotoError::Service(GetLifecyclePolicyPreviewError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetLifecyclePolicyPreviewError::InvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "LifecyclePolicyPreviewNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         GetLifecyclePolicyPreviewError::LifecyclePolicyPreviewNotFound(err.msg),
+                        &res,
                     )
                 }
                 "RepositoryNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         GetLifecyclePolicyPreviewError::RepositoryNotFound(err.msg),
+                        &res,
                     )
                 }
                 "ServerException" => {
-                    return RusotoError::Service(GetLifecyclePolicyPreviewError::Server(err.msg))
+                    return RusotoError::service(
+                        GetLifecyclePolicyPreviewError::Server(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(GetLifecyclePolicyPreviewError::Unhandled, &res)
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -2123,12 +2275,14 @@ impl fmt::Display for GetLifecyclePolicyPreviewError {
             }
             GetLifecyclePolicyPreviewError::RepositoryNotFound(ref cause) => write!(f, "{}", cause),
             GetLifecyclePolicyPreviewError::Server(ref cause) => write!(f, "{}", cause),
+            GetLifecyclePolicyPreviewError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetLifecyclePolicyPreviewError {}
 /// Errors returned by GetRepositoryPolicy
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetRepositoryPolicyError {
     /// <p>The specified parameter is invalid. Review the available parameters for the API request.</p>
     InvalidParameter(String),
@@ -2138,6 +2292,8 @@ pub enum GetRepositoryPolicyError {
     RepositoryPolicyNotFound(String),
     /// <p>These errors are usually caused by a server-side issue.</p>
     Server(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetRepositoryPolicyError {
@@ -2145,25 +2301,28 @@ impl GetRepositoryPolicyError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParameterException" => {
-                    return RusotoError::Service(GetRepositoryPolicyError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetRepositoryPolicyError::InvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "RepositoryNotFoundException" => {
-                    return RusotoError::Service(GetRepositoryPolicyError::RepositoryNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetRepositoryPolicyError::RepositoryNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "RepositoryPolicyNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         GetRepositoryPolicyError::RepositoryPolicyNotFound(err.msg),
+                        &res,
                     )
                 }
                 "ServerException" => {
-                    return RusotoError::Service(GetRepositoryPolicyError::Server(err.msg))
+                    return RusotoError::service(GetRepositoryPolicyError::Server(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetRepositoryPolicyError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2177,12 +2336,14 @@ impl fmt::Display for GetRepositoryPolicyError {
             GetRepositoryPolicyError::RepositoryNotFound(ref cause) => write!(f, "{}", cause),
             GetRepositoryPolicyError::RepositoryPolicyNotFound(ref cause) => write!(f, "{}", cause),
             GetRepositoryPolicyError::Server(ref cause) => write!(f, "{}", cause),
+            GetRepositoryPolicyError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetRepositoryPolicyError {}
 /// Errors returned by InitiateLayerUpload
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum InitiateLayerUploadError {
     /// <p>The specified parameter is invalid. Review the available parameters for the API request.</p>
     InvalidParameter(String),
@@ -2190,6 +2351,8 @@ pub enum InitiateLayerUploadError {
     RepositoryNotFound(String),
     /// <p>These errors are usually caused by a server-side issue.</p>
     Server(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl InitiateLayerUploadError {
@@ -2197,20 +2360,22 @@ impl InitiateLayerUploadError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParameterException" => {
-                    return RusotoError::Service(InitiateLayerUploadError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        InitiateLayerUploadError::InvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "RepositoryNotFoundException" => {
-                    return RusotoError::Service(InitiateLayerUploadError::RepositoryNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        InitiateLayerUploadError::RepositoryNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ServerException" => {
-                    return RusotoError::Service(InitiateLayerUploadError::Server(err.msg))
+                    return RusotoError::service(InitiateLayerUploadError::Server(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(InitiateLayerUploadError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2223,12 +2388,14 @@ impl fmt::Display for InitiateLayerUploadError {
             InitiateLayerUploadError::InvalidParameter(ref cause) => write!(f, "{}", cause),
             InitiateLayerUploadError::RepositoryNotFound(ref cause) => write!(f, "{}", cause),
             InitiateLayerUploadError::Server(ref cause) => write!(f, "{}", cause),
+            InitiateLayerUploadError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for InitiateLayerUploadError {}
 /// Errors returned by ListImages
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListImagesError {
     /// <p>The specified parameter is invalid. Review the available parameters for the API request.</p>
     InvalidParameter(String),
@@ -2236,6 +2403,8 @@ pub enum ListImagesError {
     RepositoryNotFound(String),
     /// <p>These errors are usually caused by a server-side issue.</p>
     Server(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListImagesError {
@@ -2243,14 +2412,16 @@ impl ListImagesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParameterException" => {
-                    return RusotoError::Service(ListImagesError::InvalidParameter(err.msg))
+                    return RusotoError::service(ListImagesError::InvalidParameter(err.msg), &res)
                 }
                 "RepositoryNotFoundException" => {
-                    return RusotoError::Service(ListImagesError::RepositoryNotFound(err.msg))
+                    return RusotoError::service(ListImagesError::RepositoryNotFound(err.msg), &res)
+                }
+                "ServerException" => {
+                    return RusotoError::service(ListImagesError::Server(err.msg), &res)
                 }
-                "ServerException" => return RusotoError::Service(ListImagesError::Server(err.msg)),
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListImagesError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2263,12 +2434,14 @@ impl fmt::Display for ListImagesError {
             ListImagesError::InvalidParameter(ref cause) => write!(f, "{}", cause),
             ListImagesError::RepositoryNotFound(ref cause) => write!(f, "{}", cause),
             ListImagesError::Server(ref cause) => write!(f, "{}", cause),
+            ListImagesError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListImagesError {}
 /// Errors returned by ListTagsForResource
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListTagsForResourceError {
     /// <p>The specified parameter is invalid. Review the available parameters for the API request.</p>
     InvalidParameter(String),
@@ -2276,6 +2449,8 @@ pub enum ListTagsForResourceError {
     RepositoryNotFound(String),
     /// <p>These errors are usually caused by a server-side issue.</p>
     Server(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListTagsForResourceError {
@@ -2283,20 +2458,22 @@ impl ListTagsForResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParameterException" => {
-                    return RusotoError::Service(ListTagsForResourceError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListTagsForResourceError::InvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "RepositoryNotFoundException" => {
-                    return RusotoError::Service(ListTagsForResourceError::RepositoryNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListTagsForResourceError::RepositoryNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ServerException" => {
-                    return RusotoError::Service(ListTagsForResourceError::Server(err.msg))
+                    return RusotoError::service(ListTagsForResourceError::Server(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListTagsForResourceError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2309,12 +2486,14 @@ impl fmt::Display for ListTagsForResourceError {
             ListTagsForResourceError::InvalidParameter(ref cause) => write!(f, "{}", cause),
             ListTagsForResourceError::RepositoryNotFound(ref cause) => write!(f, "{}", cause),
             ListTagsForResourceError::Server(ref cause) => write!(f, "{}", cause),
+            ListTagsForResourceError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListTagsForResourceError {}
 /// Errors returned by PutImage
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum PutImageError {
     /// <p>The specified image has already been pushed, and there were no changes to the manifest or image tag after the last push.</p>
     ImageAlreadyExists(String),
@@ -2330,6 +2509,8 @@ pub enum PutImageError {
     RepositoryNotFound(String),
     /// <p>These errors are usually caused by a server-side issue.</p>
     Server(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl PutImageError {
@@ -2337,26 +2518,31 @@ impl PutImageError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ImageAlreadyExistsException" => {
-                    return RusotoError::Service(PutImageError::ImageAlreadyExists(err.msg))
+                    return RusotoError::service(PutImageError::ImageAlreadyExists(err.msg), &res)
                 }
                 "ImageTagAlreadyExistsException" => {
-                    return RusotoError::Service(PutImageError::ImageTagAlreadyExists(err.msg))
+                    return RusotoError::service(
+                        PutImageError::ImageTagAlreadyExists(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(PutImageError::InvalidParameter(err.msg))
+                    return RusotoError::service(PutImageError::InvalidParameter(err.msg), &res)
                 }
                 "LayersNotFoundException" => {
-                    return RusotoError::Service(PutImageError::LayersNotFound(err.msg))
+                    return RusotoError::service(PutImageError::LayersNotFound(err.msg), &res)
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(PutImageError::LimitExceeded(err.msg))
+                    return RusotoError::service(PutImageError::LimitExceeded(err.msg), &res)
                 }
                 "RepositoryNotFoundException" => {
-                    return RusotoError::Service(PutImageError::RepositoryNotFound(err.msg))
+                    return RusotoError::service(PutImageError::RepositoryNotFound(err.msg), &res)
+                }
+                "ServerException" => {
+                    return RusotoError::service(PutImageError::Server(err.msg), &res)
                 }
-                "ServerException" => return RusotoError::Service(PutImageError::Server(err.msg)),
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(PutImageError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2373,12 +2559,14 @@ impl fmt::Display for PutImageError {
             PutImageError::LimitExceeded(ref cause) => write!(f, "{}", cause),
             PutImageError::RepositoryNotFound(ref cause) => write!(f, "{}", cause),
             PutImageError::Server(ref cause) => write!(f, "{}", cause),
+            PutImageError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for PutImageError {}
 /// Errors returned by PutImageScanningConfiguration
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum PutImageScanningConfigurationError {
     /// <p>The specified parameter is invalid. Review the available parameters for the API request.</p>
     InvalidParameter(String),
@@ -2386,6 +2574,8 @@ pub enum PutImageScanningConfigurationError {
     RepositoryNotFound(String),
     /// <p>These errors are usually caused by a server-side issue.</p>
     Server(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl PutImageScanningConfigurationError {
@@ -2395,22 +2585,30 @@ impl PutImageScanningConfigurationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParameterException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         PutImageScanningConfigurationError::InvalidParameter(err.msg),
+                        &res,
                     )
                 }
                 "RepositoryNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         PutImageScanningConfigurationError::RepositoryNotFound(err.msg),
+                        &res,
                     )
                 }
                 "ServerException" => {
-                    return RusotoError::Service(PutImageScanningConfigurationError::Server(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        PutImageScanningConfigurationError::Server(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        PutImageScanningConfigurationError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -2427,12 +2625,16 @@ impl fmt::Display for PutImageScanningConfigurationError {
                 write!(f, "{}", cause)
             }
             PutImageScanningConfigurationError::Server(ref cause) => write!(f, "{}", cause),
+            PutImageScanningConfigurationError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for PutImageScanningConfigurationError {}
 /// Errors returned by PutImageTagMutability
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum PutImageTagMutabilityError {
     /// <p>The specified parameter is invalid. Review the available parameters for the API request.</p>
     InvalidParameter(String),
@@ -2440,6 +2642,8 @@ pub enum PutImageTagMutabilityError {
     RepositoryNotFound(String),
     /// <p>These errors are usually caused by a server-side issue.</p>
     Server(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl PutImageTagMutabilityError {
@@ -2447,20 +2651,22 @@ impl PutImageTagMutabilityError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParameterException" => {
-                    return RusotoError::Service(PutImageTagMutabilityError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        PutImageTagMutabilityError::InvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "RepositoryNotFoundException" => {
-                    return RusotoError::Service(PutImageTagMutabilityError::RepositoryNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        PutImageTagMutabilityError::RepositoryNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ServerException" => {
-                    return RusotoError::Service(PutImageTagMutabilityError::Server(err.msg))
+                    return RusotoError::service(PutImageTagMutabilityError::Server(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(PutImageTagMutabilityError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2473,12 +2679,14 @@ impl fmt::Display for PutImageTagMutabilityError {
             PutImageTagMutabilityError::InvalidParameter(ref cause) => write!(f, "{}", cause),
             PutImageTagMutabilityError::RepositoryNotFound(ref cause) => write!(f, "{}", cause),
             PutImageTagMutabilityError::Server(ref cause) => write!(f, "{}", cause),
+            PutImageTagMutabilityError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for PutImageTagMutabilityError {}
 /// Errors returned by PutLifecyclePolicy
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum PutLifecyclePolicyError {
     /// <p>The specified parameter is invalid. Review the available parameters for the API request.</p>
     InvalidParameter(String),
@@ -2486,6 +2694,8 @@ pub enum PutLifecyclePolicyError {
     RepositoryNotFound(String),
     /// <p>These errors are usually caused by a server-side issue.</p>
     Server(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl PutLifecyclePolicyError {
@@ -2493,18 +2703,22 @@ impl PutLifecyclePolicyError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParameterException" => {
-                    return RusotoError::Service(PutLifecyclePolicyError::InvalidParameter(err.msg))
+                    return RusotoError::service(
+                        PutLifecyclePolicyError::InvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "RepositoryNotFoundException" => {
-                    return RusotoError::Service(PutLifecyclePolicyError::RepositoryNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        PutLifecyclePolicyError::RepositoryNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ServerException" => {
-                    return RusotoError::Service(PutLifecyclePolicyError::Server(err.msg))
+                    return RusotoError::service(PutLifecyclePolicyError::Server(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(PutLifecyclePolicyError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2517,12 +2731,14 @@ impl fmt::Display for PutLifecyclePolicyError {
             PutLifecyclePolicyError::InvalidParameter(ref cause) => write!(f, "{}", cause),
             PutLifecyclePolicyError::RepositoryNotFound(ref cause) => write!(f, "{}", cause),
             PutLifecyclePolicyError::Server(ref cause) => write!(f, "{}", cause),
+            PutLifecyclePolicyError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for PutLifecyclePolicyError {}
 /// Errors returned by SetRepositoryPolicy
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum SetRepositoryPolicyError {
     /// <p>The specified parameter is invalid. Review the available parameters for the API request.</p>
     InvalidParameter(String),
@@ -2530,6 +2746,8 @@ pub enum SetRepositoryPolicyError {
     RepositoryNotFound(String),
     /// <p>These errors are usually caused by a server-side issue.</p>
     Server(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl SetRepositoryPolicyError {
@@ -2537,20 +2755,22 @@ impl SetRepositoryPolicyError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParameterException" => {
-                    return RusotoError::Service(SetRepositoryPolicyError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        SetRepositoryPolicyError::InvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "RepositoryNotFoundException" => {
-                    return RusotoError::Service(SetRepositoryPolicyError::RepositoryNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        SetRepositoryPolicyError::RepositoryNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ServerException" => {
-                    return RusotoError::Service(SetRepositoryPolicyError::Server(err.msg))
+                    return RusotoError::service(SetRepositoryPolicyError::Server(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(SetRepositoryPolicyError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2563,12 +2783,14 @@ impl fmt::Display for SetRepositoryPolicyError {
             SetRepositoryPolicyError::InvalidParameter(ref cause) => write!(f, "{}", cause),
             SetRepositoryPolicyError::RepositoryNotFound(ref cause) => write!(f, "{}", cause),
             SetRepositoryPolicyError::Server(ref cause) => write!(f, "{}", cause),
+            SetRepositoryPolicyError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for SetRepositoryPolicyError {}
 /// Errors returned by StartImageScan
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum StartImageScanError {
     /// <p>The image requested does not exist in the specified repository.</p>
     ImageNotFound(String),
@@ -2578,6 +2800,8 @@ pub enum StartImageScanError {
     RepositoryNotFound(String),
     /// <p>These errors are usually caused by a server-side issue.</p>
     Server(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl StartImageScanError {
@@ -2585,19 +2809,25 @@ impl StartImageScanError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ImageNotFoundException" => {
-                    return RusotoError::Service(StartImageScanError::ImageNotFound(err.msg))
+                    return RusotoError::service(StartImageScanError::ImageNotFound(err.msg), &res)
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(StartImageScanError::InvalidParameter(err.msg))
+                    return RusotoError::service(
+                        StartImageScanError::InvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "RepositoryNotFoundException" => {
-                    return RusotoError::Service(StartImageScanError::RepositoryNotFound(err.msg))
+                    return RusotoError::service(
+                        StartImageScanError::RepositoryNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ServerException" => {
-                    return RusotoError::Service(StartImageScanError::Server(err.msg))
+                    return RusotoError::service(StartImageScanError::Server(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(StartImageScanError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2611,12 +2841,14 @@ impl fmt::Display for StartImageScanError {
             StartImageScanError::InvalidParameter(ref cause) => write!(f, "{}", cause),
             StartImageScanError::RepositoryNotFound(ref cause) => write!(f, "{}", cause),
             StartImageScanError::Server(ref cause) => write!(f, "{}", cause),
+            StartImageScanError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for StartImageScanError {}
 /// Errors returned by StartLifecyclePolicyPreview
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum StartLifecyclePolicyPreviewError {
     /// <p>The specified parameter is invalid. Review the available parameters for the API request.</p>
     InvalidParameter(String),
@@ -2628,6 +2860,8 @@ pub enum StartLifecyclePolicyPreviewError {
     RepositoryNotFound(String),
     /// <p>These errors are usually caused by a server-side issue.</p>
     Server(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl StartLifecyclePolicyPreviewError {
@@ -2637,30 +2871,42 @@ impl StartLifecyclePolicyPreviewError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParameterException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         StartLifecyclePolicyPreviewError::InvalidParameter(err.msg),
+                        &res,
                     )
                 }
                 "LifecyclePolicyNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         StartLifecyclePolicyPreviewError::LifecyclePolicyNotFound(err.msg),
+                        &res,
                     )
                 }
                 "LifecyclePolicyPreviewInProgressException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         StartLifecyclePolicyPreviewError::LifecyclePolicyPreviewInProgress(err.msg),
+                        &res,
                     )
                 }
                 "RepositoryNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         StartLifecyclePolicyPreviewError::RepositoryNotFound(err.msg),
+                        &res,
                     )
                 }
                 "ServerException" => {
-                    return RusotoError::Service(StartLifecyclePolicyPreviewError::Server(err.msg))
+                    return RusotoError::service(
+                        StartLifecyclePolicyPreviewError::Server(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        StartLifecyclePolicyPreviewError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -2681,12 +2927,14 @@ impl fmt::Display for StartLifecyclePolicyPreviewError {
                 write!(f, "{}", cause)
             }
             StartLifecyclePolicyPreviewError::Server(ref cause) => write!(f, "{}", cause),
+            StartLifecyclePolicyPreviewError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for StartLifecyclePolicyPreviewError {}
 /// Errors returned by TagResource
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum TagResourceError {
     /// <p>The specified parameter is invalid. Review the available parameters for the API request.</p>
     InvalidParameter(String),
@@ -2698,6 +2946,8 @@ pub enum TagResourceError {
     Server(String),
     /// <p>The list of tags on the repository is over the limit. The maximum number of tags that can be applied to a repository is 50.</p>
     TooManyTags(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl TagResourceError {
@@ -2705,22 +2955,28 @@ impl TagResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParameterException" => {
-                    return RusotoError::Service(TagResourceError::InvalidParameter(err.msg))
+                    return RusotoError::service(TagResourceError::InvalidParameter(err.msg), &res)
                 }
                 "InvalidTagParameterException" => {
-                    return RusotoError::Service(TagResourceError::InvalidTagParameter(err.msg))
+                    return RusotoError::service(
+                        TagResourceError::InvalidTagParameter(err.msg),
+                        &res,
+                    )
                 }
                 "RepositoryNotFoundException" => {
-                    return RusotoError::Service(TagResourceError::RepositoryNotFound(err.msg))
+                    return RusotoError::service(
+                        TagResourceError::RepositoryNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ServerException" => {
-                    return RusotoError::Service(TagResourceError::Server(err.msg))
+                    return RusotoError::service(TagResourceError::Server(err.msg), &res)
                 }
                 "TooManyTagsException" => {
-                    return RusotoError::Service(TagResourceError::TooManyTags(err.msg))
+                    return RusotoError::service(TagResourceError::TooManyTags(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(TagResourceError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2735,12 +2991,14 @@ impl fmt::Display for TagResourceError {
             TagResourceError::RepositoryNotFound(ref cause) => write!(f, "{}", cause),
             TagResourceError::Server(ref cause) => write!(f, "{}", cause),
             TagResourceError::TooManyTags(ref cause) => write!(f, "{}", cause),
+            TagResourceError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for TagResourceError {}
 /// Errors returned by UntagResource
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UntagResourceError {
     /// <p>The specified parameter is invalid. Review the available parameters for the API request.</p>
     InvalidParameter(String),
@@ -2752,6 +3010,8 @@ pub enum UntagResourceError {
     Server(String),
     /// <p>The list of tags on the repository is over the limit. The maximum number of tags that can be applied to a repository is 50.</p>
     TooManyTags(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UntagResourceError {
@@ -2759,22 +3019,31 @@ impl UntagResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParameterException" => {
-                    return RusotoError::Service(UntagResourceError::InvalidParameter(err.msg))
+                    return RusotoError::service(
+                        UntagResourceError::InvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidTagParameterException" => {
-                    return RusotoError::Service(UntagResourceError::InvalidTagParameter(err.msg))
+                    return RusotoError::service(
+                        UntagResourceError::InvalidTagParameter(err.msg),
+                        &res,
+                    )
                 }
                 "RepositoryNotFoundException" => {
-                    return RusotoError::Service(UntagResourceError::RepositoryNotFound(err.msg))
+                    return RusotoError::service(
+                        UntagResourceError::RepositoryNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ServerException" => {
-                    return RusotoError::Service(UntagResourceError::Server(err.msg))
+                    return RusotoError::service(UntagResourceError::Server(err.msg), &res)
                 }
                 "TooManyTagsException" => {
-                    return RusotoError::Service(UntagResourceError::TooManyTags(err.msg))
+                    return RusotoError::service(UntagResourceError::TooManyTags(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UntagResourceError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2789,12 +3058,14 @@ impl fmt::Display for UntagResourceError {
             UntagResourceError::RepositoryNotFound(ref cause) => write!(f, "{}", cause),
             UntagResourceError::Server(ref cause) => write!(f, "{}", cause),
             UntagResourceError::TooManyTags(ref cause) => write!(f, "{}", cause),
+            UntagResourceError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for UntagResourceError {}
 /// Errors returned by UploadLayerPart
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UploadLayerPartError {
     /// <p>The layer part size is not valid, or the first byte specified is not consecutive to the last byte of a previous layer part upload.</p>
     InvalidLayerPart(String),
@@ -2808,6 +3079,8 @@ pub enum UploadLayerPartError {
     Server(String),
     /// <p>The upload could not be found, or the specified upload id is not valid for this repository.</p>
     UploadNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UploadLayerPartError {
@@ -2815,25 +3088,37 @@ impl UploadLayerPartError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidLayerPartException" => {
-                    return RusotoError::Service(UploadLayerPartError::InvalidLayerPart(err.msg))
+                    return RusotoError::service(
+                        UploadLayerPartError::InvalidLayerPart(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(UploadLayerPartError::InvalidParameter(err.msg))
+                    return RusotoError::service(
+                        UploadLayerPartError::InvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(UploadLayerPartError::LimitExceeded(err.msg))
+                    return RusotoError::service(UploadLayerPartError::LimitExceeded(err.msg), &res)
                 }
                 "RepositoryNotFoundException" => {
-                    return RusotoError::Service(UploadLayerPartError::RepositoryNotFound(err.msg))
+                    return RusotoError::service(
+                        UploadLayerPartError::RepositoryNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ServerException" => {
-                    return RusotoError::Service(UploadLayerPartError::Server(err.msg))
+                    return RusotoError::service(UploadLayerPartError::Server(err.msg), &res)
                 }
                 "UploadNotFoundException" => {
-                    return RusotoError::Service(UploadLayerPartError::UploadNotFound(err.msg))
+                    return RusotoError::service(
+                        UploadLayerPartError::UploadNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UploadLayerPartError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2849,6 +3134,7 @@ impl fmt::Display for UploadLayerPartError {
             UploadLayerPartError::RepositoryNotFound(ref cause) => write!(f, "{}", cause),
             UploadLayerPartError::Server(ref cause) => write!(f, "{}", cause),
             UploadLayerPartError::UploadNotFound(ref cause) => write!(f, "{}", cause),
+            UploadLayerPartError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
@@ -3044,6 +3330,7 @@ impl EcrClient {
     /// Creates a client backed by the default tokio event loop.
     ///
     /// The client will use the default credentials provider and tls client.
+    #[cfg(not(target_arch = "wasm32"))]
     pub fn new(region: region::Region) -> EcrClient {
         EcrClient {
             client: Client::shared(),
@@ -3069,17 +3356,73 @@ impl EcrClient {
     pub fn new_with_client(client: Client, region: region::Region) -> EcrClient {
         EcrClient { client, region }
     }
+
+    /// Returns a builder of a client, setting any of its region, credentials provider,
+    /// dispatcher, retry policy, endpoint and timeouts.
+    pub fn builder() -> rusoto_core::ClientBuilder<EcrClient> {
+        rusoto_core::ClientBuilder::new(EcrClient::new_with_client)
+    }
+
+    /// Returns a clone of this client with the overrides of `config` applied to its
+    /// operations, sharing its connections.
+    pub fn customize(&self, config: &rusoto_core::OperationConfig) -> EcrClient {
+        EcrClient {
+            client: self.client.customize(config),
+            region: config.resolve_region(&self.region),
+        }
+    }
 }
 
-#[async_trait]
-impl Ecr for EcrClient {
-    /// <p><p>Check the availability of multiple image layers in a specified registry and repository.</p> <note> <p>This operation is used by the Amazon ECR proxy, and it is not intended for general use by customers for pulling and pushing images. In most cases, you should use the <code>docker</code> CLI to pull, tag, and push images.</p> </note></p>
-    async fn batch_check_layer_availability(
+impl EcrClient {
+    /// Calls `batch_check_layer_availability`, also returning the metadata of the response.
+    pub async fn batch_check_layer_availability_with_metadata(
         &self,
         input: BatchCheckLayerAvailabilityRequest,
-    ) -> Result<BatchCheckLayerAvailabilityResponse, RusotoError<BatchCheckLayerAvailabilityError>>
-    {
+    ) -> Result<
+        (BatchCheckLayerAvailabilityResponse, ResponseMetadata),
+        RusotoError<BatchCheckLayerAvailabilityError>,
+    > {
+        let request = self.build_batch_check_layer_availability_request(input);
+
+        let mut response = self
+            .client
+            .sign_and_dispatch(request)
+            .await
+            .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
+        if response.status.is_success() {
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<BatchCheckLayerAvailabilityResponse, _>()?;
+            Ok((result, metadata))
+        } else {
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(BatchCheckLayerAvailabilityError::from_response(response))
+        }
+    }
+
+    /// Presigns the request `batch_check_layer_availability` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_batch_check_layer_availability(
+        &self,
+        presigner: &rusoto_core::Presigner,
+        input: BatchCheckLayerAvailabilityRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_batch_check_layer_availability_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_batch_check_layer_availability_request(
+        &self,
+        input: BatchCheckLayerAvailabilityRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "ecr", &self.region, "/");
+        request.set_operation("BatchCheckLayerAvailability");
         request.set_endpoint_prefix("api.ecr".to_string());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -3089,28 +3432,53 @@ impl Ecr for EcrClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `batch_delete_image`, also returning the metadata of the response.
+    pub async fn batch_delete_image_with_metadata(
+        &self,
+        input: BatchDeleteImageRequest,
+    ) -> Result<(BatchDeleteImageResponse, ResponseMetadata), RusotoError<BatchDeleteImageError>>
+    {
+        let request = self.build_batch_delete_image_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<BatchCheckLayerAvailabilityResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<BatchDeleteImageResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(BatchCheckLayerAvailabilityError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(BatchDeleteImageError::from_response(response))
         }
     }
 
-    /// <p>Deletes a list of specified images within a specified repository. Images are specified with either <code>imageTag</code> or <code>imageDigest</code>.</p> <p>You can remove a tag from an image by specifying the image's tag in your request. When you remove the last tag from an image, the image is deleted from your repository.</p> <p>You can completely delete an image (and all of its tags) by specifying the image's digest in your request.</p>
-    async fn batch_delete_image(
+    /// Presigns the request `batch_delete_image` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_batch_delete_image(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: BatchDeleteImageRequest,
-    ) -> Result<BatchDeleteImageResponse, RusotoError<BatchDeleteImageError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_batch_delete_image_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_batch_delete_image_request(&self, input: BatchDeleteImageRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "ecr", &self.region, "/");
+        request.set_operation("BatchDeleteImage");
         request.set_endpoint_prefix("api.ecr".to_string());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -3120,28 +3488,52 @@ impl Ecr for EcrClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `batch_get_image`, also returning the metadata of the response.
+    pub async fn batch_get_image_with_metadata(
+        &self,
+        input: BatchGetImageRequest,
+    ) -> Result<(BatchGetImageResponse, ResponseMetadata), RusotoError<BatchGetImageError>> {
+        let request = self.build_batch_get_image_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<BatchDeleteImageResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<BatchGetImageResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(BatchDeleteImageError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(BatchGetImageError::from_response(response))
         }
     }
 
-    /// <p>Gets detailed information for specified images within a specified repository. Images are specified with either <code>imageTag</code> or <code>imageDigest</code>.</p>
-    async fn batch_get_image(
+    /// Presigns the request `batch_get_image` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_batch_get_image(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: BatchGetImageRequest,
-    ) -> Result<BatchGetImageResponse, RusotoError<BatchGetImageError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_batch_get_image_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_batch_get_image_request(&self, input: BatchGetImageRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "ecr", &self.region, "/");
+        request.set_operation("BatchGetImage");
         request.set_endpoint_prefix("api.ecr".to_string());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -3151,27 +3543,58 @@ impl Ecr for EcrClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `complete_layer_upload`, also returning the metadata of the response.
+    pub async fn complete_layer_upload_with_metadata(
+        &self,
+        input: CompleteLayerUploadRequest,
+    ) -> Result<
+        (CompleteLayerUploadResponse, ResponseMetadata),
+        RusotoError<CompleteLayerUploadError>,
+    > {
+        let request = self.build_complete_layer_upload_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<BatchGetImageResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<CompleteLayerUploadResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(BatchGetImageError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(CompleteLayerUploadError::from_response(response))
         }
     }
 
-    /// <p><p>Informs Amazon ECR that the image layer upload has completed for a specified registry, repository name, and upload ID. You can optionally provide a <code>sha256</code> digest of the image layer for data validation purposes.</p> <note> <p>This operation is used by the Amazon ECR proxy, and it is not intended for general use by customers for pulling and pushing images. In most cases, you should use the <code>docker</code> CLI to pull, tag, and push images.</p> </note></p>
-    async fn complete_layer_upload(
+    /// Presigns the request `complete_layer_upload` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_complete_layer_upload(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: CompleteLayerUploadRequest,
-    ) -> Result<CompleteLayerUploadResponse, RusotoError<CompleteLayerUploadError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_complete_layer_upload_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_complete_layer_upload_request(
+        &self,
+        input: CompleteLayerUploadRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "ecr", &self.region, "/");
+        request.set_operation("CompleteLayerUpload");
         request.set_endpoint_prefix("api.ecr".to_string());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -3181,28 +3604,53 @@ impl Ecr for EcrClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `create_repository`, also returning the metadata of the response.
+    pub async fn create_repository_with_metadata(
+        &self,
+        input: CreateRepositoryRequest,
+    ) -> Result<(CreateRepositoryResponse, ResponseMetadata), RusotoError<CreateRepositoryError>>
+    {
+        let request = self.build_create_repository_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<CompleteLayerUploadResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<CreateRepositoryResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(CompleteLayerUploadError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(CreateRepositoryError::from_response(response))
         }
     }
 
-    /// <p>Creates an Amazon Elastic Container Registry (Amazon ECR) repository, where users can push and pull Docker images. For more information, see <a href="https://docs.aws.amazon.com/AmazonECR/latest/userguide/Repositories.html">Amazon ECR Repositories</a> in the <i>Amazon Elastic Container Registry User Guide</i>.</p>
-    async fn create_repository(
+    /// Presigns the request `create_repository` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_repository(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: CreateRepositoryRequest,
-    ) -> Result<CreateRepositoryResponse, RusotoError<CreateRepositoryError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_repository_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_repository_request(&self, input: CreateRepositoryRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "ecr", &self.region, "/");
+        request.set_operation("CreateRepository");
         request.set_endpoint_prefix("api.ecr".to_string());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -3212,28 +3660,58 @@ impl Ecr for EcrClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `delete_lifecycle_policy`, also returning the metadata of the response.
+    pub async fn delete_lifecycle_policy_with_metadata(
+        &self,
+        input: DeleteLifecyclePolicyRequest,
+    ) -> Result<
+        (DeleteLifecyclePolicyResponse, ResponseMetadata),
+        RusotoError<DeleteLifecyclePolicyError>,
+    > {
+        let request = self.build_delete_lifecycle_policy_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<CreateRepositoryResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DeleteLifecyclePolicyResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(CreateRepositoryError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DeleteLifecyclePolicyError::from_response(response))
         }
     }
 
-    /// <p>Deletes the specified lifecycle policy.</p>
-    async fn delete_lifecycle_policy(
+    /// Presigns the request `delete_lifecycle_policy` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_lifecycle_policy(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteLifecyclePolicyRequest,
-    ) -> Result<DeleteLifecyclePolicyResponse, RusotoError<DeleteLifecyclePolicyError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_lifecycle_policy_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_lifecycle_policy_request(
+        &self,
+        input: DeleteLifecyclePolicyRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "ecr", &self.region, "/");
+        request.set_operation("DeleteLifecyclePolicy");
         request.set_endpoint_prefix("api.ecr".to_string());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -3243,28 +3721,53 @@ impl Ecr for EcrClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `delete_repository`, also returning the metadata of the response.
+    pub async fn delete_repository_with_metadata(
+        &self,
+        input: DeleteRepositoryRequest,
+    ) -> Result<(DeleteRepositoryResponse, ResponseMetadata), RusotoError<DeleteRepositoryError>>
+    {
+        let request = self.build_delete_repository_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DeleteLifecyclePolicyResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DeleteRepositoryResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteLifecyclePolicyError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DeleteRepositoryError::from_response(response))
         }
     }
 
-    /// <p>Deletes an existing image repository. If a repository contains images, you must use the <code>force</code> option to delete it.</p>
-    async fn delete_repository(
+    /// Presigns the request `delete_repository` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_repository(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteRepositoryRequest,
-    ) -> Result<DeleteRepositoryResponse, RusotoError<DeleteRepositoryError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_repository_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_repository_request(&self, input: DeleteRepositoryRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "ecr", &self.region, "/");
+        request.set_operation("DeleteRepository");
         request.set_endpoint_prefix("api.ecr".to_string());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -3274,28 +3777,58 @@ impl Ecr for EcrClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `delete_repository_policy`, also returning the metadata of the response.
+    pub async fn delete_repository_policy_with_metadata(
+        &self,
+        input: DeleteRepositoryPolicyRequest,
+    ) -> Result<
+        (DeleteRepositoryPolicyResponse, ResponseMetadata),
+        RusotoError<DeleteRepositoryPolicyError>,
+    > {
+        let request = self.build_delete_repository_policy_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DeleteRepositoryResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DeleteRepositoryPolicyResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteRepositoryError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DeleteRepositoryPolicyError::from_response(response))
         }
     }
 
-    /// <p>Deletes the repository policy from a specified repository.</p>
-    async fn delete_repository_policy(
+    /// Presigns the request `delete_repository_policy` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_repository_policy(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteRepositoryPolicyRequest,
-    ) -> Result<DeleteRepositoryPolicyResponse, RusotoError<DeleteRepositoryPolicyError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_repository_policy_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_repository_policy_request(
+        &self,
+        input: DeleteRepositoryPolicyRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "ecr", &self.region, "/");
+        request.set_operation("DeleteRepositoryPolicy");
         request.set_endpoint_prefix("api.ecr".to_string());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -3305,29 +3838,58 @@ impl Ecr for EcrClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `describe_image_scan_findings`, also returning the metadata of the response.
+    pub async fn describe_image_scan_findings_with_metadata(
+        &self,
+        input: DescribeImageScanFindingsRequest,
+    ) -> Result<
+        (DescribeImageScanFindingsResponse, ResponseMetadata),
+        RusotoError<DescribeImageScanFindingsError>,
+    > {
+        let request = self.build_describe_image_scan_findings_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DeleteRepositoryPolicyResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DescribeImageScanFindingsResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteRepositoryPolicyError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DescribeImageScanFindingsError::from_response(response))
         }
     }
 
-    /// <p>Describes the image scan findings for the specified image.</p>
-    async fn describe_image_scan_findings(
+    /// Presigns the request `describe_image_scan_findings` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_image_scan_findings(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeImageScanFindingsRequest,
-    ) -> Result<DescribeImageScanFindingsResponse, RusotoError<DescribeImageScanFindingsError>>
-    {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_image_scan_findings_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_image_scan_findings_request(
+        &self,
+        input: DescribeImageScanFindingsRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "ecr", &self.region, "/");
+        request.set_operation("DescribeImageScanFindings");
         request.set_endpoint_prefix("api.ecr".to_string());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -3337,28 +3899,52 @@ impl Ecr for EcrClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `describe_images`, also returning the metadata of the response.
+    pub async fn describe_images_with_metadata(
+        &self,
+        input: DescribeImagesRequest,
+    ) -> Result<(DescribeImagesResponse, ResponseMetadata), RusotoError<DescribeImagesError>> {
+        let request = self.build_describe_images_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DescribeImageScanFindingsResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DescribeImagesResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeImageScanFindingsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DescribeImagesError::from_response(response))
         }
     }
 
-    /// <p><p>Returns metadata about the images in a repository, including image size, image tags, and creation date.</p> <note> <p>Beginning with Docker version 1.9, the Docker client compresses image layers before pushing them to a V2 Docker registry. The output of the <code>docker images</code> command shows the uncompressed image size, so it may return a larger image size than the image sizes returned by <a>DescribeImages</a>.</p> </note></p>
-    async fn describe_images(
+    /// Presigns the request `describe_images` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_images(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeImagesRequest,
-    ) -> Result<DescribeImagesResponse, RusotoError<DescribeImagesError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_images_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_images_request(&self, input: DescribeImagesRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "ecr", &self.region, "/");
+        request.set_operation("DescribeImages");
         request.set_endpoint_prefix("api.ecr".to_string());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -3368,29 +3954,60 @@ impl Ecr for EcrClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `describe_repositories`, also returning the metadata of the response.
+    pub async fn describe_repositories_with_metadata(
+        &self,
+        input: DescribeRepositoriesRequest,
+    ) -> Result<
+        (DescribeRepositoriesResponse, ResponseMetadata),
+        RusotoError<DescribeRepositoriesError>,
+    > {
+        let request = self.build_describe_repositories_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<DescribeImagesResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DescribeRepositoriesResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeImagesError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DescribeRepositoriesError::from_response(response))
         }
     }
 
-    /// <p>Describes image repositories in a registry.</p>
-    async fn describe_repositories(
+    /// Presigns the request `describe_repositories` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_repositories(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeRepositoriesRequest,
-    ) -> Result<DescribeRepositoriesResponse, RusotoError<DescribeRepositoriesError>> {
-        let mut request = SignedRequest::new("POST", "ecr", &self.region, "/");
-        request.set_endpoint_prefix("api.ecr".to_string());
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_repositories_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_repositories_request(
+        &self,
+        input: DescribeRepositoriesRequest,
+    ) -> SignedRequest {
+        let mut request = SignedRequest::new("POST", "ecr", &self.region, "/");
+        request.set_operation("DescribeRepositories");
+        request.set_endpoint_prefix("api.ecr".to_string());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
             "x-amz-target",
             "AmazonEC2ContainerRegistry_V20150921.DescribeRepositories",
@@ -3398,28 +4015,58 @@ impl Ecr for EcrClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `get_authorization_token`, also returning the metadata of the response.
+    pub async fn get_authorization_token_with_metadata(
+        &self,
+        input: GetAuthorizationTokenRequest,
+    ) -> Result<
+        (GetAuthorizationTokenResponse, ResponseMetadata),
+        RusotoError<GetAuthorizationTokenError>,
+    > {
+        let request = self.build_get_authorization_token_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DescribeRepositoriesResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<GetAuthorizationTokenResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeRepositoriesError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(GetAuthorizationTokenError::from_response(response))
         }
     }
 
-    /// <p>Retrieves a token that is valid for a specified registry for 12 hours. This command allows you to use the <code>docker</code> CLI to push and pull images with Amazon ECR. If you do not specify a registry, the default registry is assumed.</p> <p>The <code>authorizationToken</code> returned for each registry specified is a base64 encoded string that can be decoded and used in a <code>docker login</code> command to authenticate to a registry. The AWS CLI offers an <code>aws ecr get-login</code> command that simplifies the login process.</p>
-    async fn get_authorization_token(
+    /// Presigns the request `get_authorization_token` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_authorization_token(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetAuthorizationTokenRequest,
-    ) -> Result<GetAuthorizationTokenResponse, RusotoError<GetAuthorizationTokenError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_authorization_token_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_authorization_token_request(
+        &self,
+        input: GetAuthorizationTokenRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "ecr", &self.region, "/");
+        request.set_operation("GetAuthorizationToken");
         request.set_endpoint_prefix("api.ecr".to_string());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -3429,28 +4076,58 @@ impl Ecr for EcrClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `get_download_url_for_layer`, also returning the metadata of the response.
+    pub async fn get_download_url_for_layer_with_metadata(
+        &self,
+        input: GetDownloadUrlForLayerRequest,
+    ) -> Result<
+        (GetDownloadUrlForLayerResponse, ResponseMetadata),
+        RusotoError<GetDownloadUrlForLayerError>,
+    > {
+        let request = self.build_get_download_url_for_layer_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<GetAuthorizationTokenResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<GetDownloadUrlForLayerResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(GetAuthorizationTokenError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(GetDownloadUrlForLayerError::from_response(response))
         }
     }
 
-    /// <p><p>Retrieves the pre-signed Amazon S3 download URL corresponding to an image layer. You can only get URLs for image layers that are referenced in an image.</p> <note> <p>This operation is used by the Amazon ECR proxy, and it is not intended for general use by customers for pulling and pushing images. In most cases, you should use the <code>docker</code> CLI to pull, tag, and push images.</p> </note></p>
-    async fn get_download_url_for_layer(
+    /// Presigns the request `get_download_url_for_layer` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_download_url_for_layer(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetDownloadUrlForLayerRequest,
-    ) -> Result<GetDownloadUrlForLayerResponse, RusotoError<GetDownloadUrlForLayerError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_download_url_for_layer_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_download_url_for_layer_request(
+        &self,
+        input: GetDownloadUrlForLayerRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "ecr", &self.region, "/");
+        request.set_operation("GetDownloadUrlForLayer");
         request.set_endpoint_prefix("api.ecr".to_string());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -3460,28 +4137,56 @@ impl Ecr for EcrClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `get_lifecycle_policy`, also returning the metadata of the response.
+    pub async fn get_lifecycle_policy_with_metadata(
+        &self,
+        input: GetLifecyclePolicyRequest,
+    ) -> Result<(GetLifecyclePolicyResponse, ResponseMetadata), RusotoError<GetLifecyclePolicyError>>
+    {
+        let request = self.build_get_lifecycle_policy_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<GetDownloadUrlForLayerResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<GetLifecyclePolicyResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(GetDownloadUrlForLayerError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(GetLifecyclePolicyError::from_response(response))
         }
     }
 
-    /// <p>Retrieves the specified lifecycle policy.</p>
-    async fn get_lifecycle_policy(
+    /// Presigns the request `get_lifecycle_policy` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_lifecycle_policy(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetLifecyclePolicyRequest,
-    ) -> Result<GetLifecyclePolicyResponse, RusotoError<GetLifecyclePolicyError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_lifecycle_policy_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_lifecycle_policy_request(
+        &self,
+        input: GetLifecyclePolicyRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "ecr", &self.region, "/");
+        request.set_operation("GetLifecyclePolicy");
         request.set_endpoint_prefix("api.ecr".to_string());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -3491,29 +4196,58 @@ impl Ecr for EcrClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `get_lifecycle_policy_preview`, also returning the metadata of the response.
+    pub async fn get_lifecycle_policy_preview_with_metadata(
+        &self,
+        input: GetLifecyclePolicyPreviewRequest,
+    ) -> Result<
+        (GetLifecyclePolicyPreviewResponse, ResponseMetadata),
+        RusotoError<GetLifecyclePolicyPreviewError>,
+    > {
+        let request = self.build_get_lifecycle_policy_preview_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<GetLifecyclePolicyResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<GetLifecyclePolicyPreviewResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(GetLifecyclePolicyError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(GetLifecyclePolicyPreviewError::from_response(response))
         }
     }
 
-    /// <p>Retrieves the results of the specified lifecycle policy preview request.</p>
-    async fn get_lifecycle_policy_preview(
+    /// Presigns the request `get_lifecycle_policy_preview` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_lifecycle_policy_preview(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetLifecyclePolicyPreviewRequest,
-    ) -> Result<GetLifecyclePolicyPreviewResponse, RusotoError<GetLifecyclePolicyPreviewError>>
-    {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_lifecycle_policy_preview_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_lifecycle_policy_preview_request(
+        &self,
+        input: GetLifecyclePolicyPreviewRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "ecr", &self.region, "/");
+        request.set_operation("GetLifecyclePolicyPreview");
         request.set_endpoint_prefix("api.ecr".to_string());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -3523,28 +4257,58 @@ impl Ecr for EcrClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `get_repository_policy`, also returning the metadata of the response.
+    pub async fn get_repository_policy_with_metadata(
+        &self,
+        input: GetRepositoryPolicyRequest,
+    ) -> Result<
+        (GetRepositoryPolicyResponse, ResponseMetadata),
+        RusotoError<GetRepositoryPolicyError>,
+    > {
+        let request = self.build_get_repository_policy_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<GetLifecyclePolicyPreviewResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<GetRepositoryPolicyResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(GetLifecyclePolicyPreviewError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(GetRepositoryPolicyError::from_response(response))
         }
     }
 
-    /// <p>Retrieves the repository policy for a specified repository.</p>
-    async fn get_repository_policy(
+    /// Presigns the request `get_repository_policy` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_repository_policy(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetRepositoryPolicyRequest,
-    ) -> Result<GetRepositoryPolicyResponse, RusotoError<GetRepositoryPolicyError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_repository_policy_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_repository_policy_request(
+        &self,
+        input: GetRepositoryPolicyRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "ecr", &self.region, "/");
+        request.set_operation("GetRepositoryPolicy");
         request.set_endpoint_prefix("api.ecr".to_string());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -3554,28 +4318,58 @@ impl Ecr for EcrClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `initiate_layer_upload`, also returning the metadata of the response.
+    pub async fn initiate_layer_upload_with_metadata(
+        &self,
+        input: InitiateLayerUploadRequest,
+    ) -> Result<
+        (InitiateLayerUploadResponse, ResponseMetadata),
+        RusotoError<InitiateLayerUploadError>,
+    > {
+        let request = self.build_initiate_layer_upload_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<GetRepositoryPolicyResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<InitiateLayerUploadResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(GetRepositoryPolicyError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(InitiateLayerUploadError::from_response(response))
         }
     }
 
-    /// <p><p>Notify Amazon ECR that you intend to upload an image layer.</p> <note> <p>This operation is used by the Amazon ECR proxy, and it is not intended for general use by customers for pulling and pushing images. In most cases, you should use the <code>docker</code> CLI to pull, tag, and push images.</p> </note></p>
-    async fn initiate_layer_upload(
+    /// Presigns the request `initiate_layer_upload` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_initiate_layer_upload(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: InitiateLayerUploadRequest,
-    ) -> Result<InitiateLayerUploadResponse, RusotoError<InitiateLayerUploadError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_initiate_layer_upload_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_initiate_layer_upload_request(
+        &self,
+        input: InitiateLayerUploadRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "ecr", &self.region, "/");
+        request.set_operation("InitiateLayerUpload");
         request.set_endpoint_prefix("api.ecr".to_string());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -3585,28 +4379,52 @@ impl Ecr for EcrClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `list_images`, also returning the metadata of the response.
+    pub async fn list_images_with_metadata(
+        &self,
+        input: ListImagesRequest,
+    ) -> Result<(ListImagesResponse, ResponseMetadata), RusotoError<ListImagesError>> {
+        let request = self.build_list_images_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<InitiateLayerUploadResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ListImagesResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(InitiateLayerUploadError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ListImagesError::from_response(response))
         }
     }
 
-    /// <p>Lists all the image IDs for a given repository.</p> <p>You can filter images based on whether or not they are tagged by setting the <code>tagStatus</code> parameter to <code>TAGGED</code> or <code>UNTAGGED</code>. For example, you can filter your results to return only <code>UNTAGGED</code> images and then pipe that result to a <a>BatchDeleteImage</a> operation to delete them. Or, you can filter your results to return only <code>TAGGED</code> images to list all of the tags in your repository.</p>
-    async fn list_images(
+    /// Presigns the request `list_images` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_images(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListImagesRequest,
-    ) -> Result<ListImagesResponse, RusotoError<ListImagesError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_images_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_images_request(&self, input: ListImagesRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "ecr", &self.region, "/");
+        request.set_operation("ListImages");
         request.set_endpoint_prefix("api.ecr".to_string());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -3616,27 +4434,58 @@ impl Ecr for EcrClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `list_tags_for_resource`, also returning the metadata of the response.
+    pub async fn list_tags_for_resource_with_metadata(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> Result<
+        (ListTagsForResourceResponse, ResponseMetadata),
+        RusotoError<ListTagsForResourceError>,
+    > {
+        let request = self.build_list_tags_for_resource_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<ListImagesResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ListTagsForResourceResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ListImagesError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ListTagsForResourceError::from_response(response))
         }
     }
 
-    /// <p>List the tags for an Amazon ECR resource.</p>
-    async fn list_tags_for_resource(
+    /// Presigns the request `list_tags_for_resource` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_tags_for_resource(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListTagsForResourceRequest,
-    ) -> Result<ListTagsForResourceResponse, RusotoError<ListTagsForResourceError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_tags_for_resource_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_tags_for_resource_request(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "ecr", &self.region, "/");
+        request.set_operation("ListTagsForResource");
         request.set_endpoint_prefix("api.ecr".to_string());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -3646,28 +4495,52 @@ impl Ecr for EcrClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `put_image`, also returning the metadata of the response.
+    pub async fn put_image_with_metadata(
+        &self,
+        input: PutImageRequest,
+    ) -> Result<(PutImageResponse, ResponseMetadata), RusotoError<PutImageError>> {
+        let request = self.build_put_image_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListTagsForResourceResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<PutImageResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ListTagsForResourceError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(PutImageError::from_response(response))
         }
     }
 
-    /// <p><p>Creates or updates the image manifest and tags associated with an image.</p> <note> <p>This operation is used by the Amazon ECR proxy, and it is not intended for general use by customers for pulling and pushing images. In most cases, you should use the <code>docker</code> CLI to pull, tag, and push images.</p> </note></p>
-    async fn put_image(
+    /// Presigns the request `put_image` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_put_image(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: PutImageRequest,
-    ) -> Result<PutImageResponse, RusotoError<PutImageError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_put_image_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_put_image_request(&self, input: PutImageRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "ecr", &self.region, "/");
+        request.set_operation("PutImage");
         request.set_endpoint_prefix("api.ecr".to_string());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -3677,30 +4550,58 @@ impl Ecr for EcrClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `put_image_scanning_configuration`, also returning the metadata of the response.
+    pub async fn put_image_scanning_configuration_with_metadata(
+        &self,
+        input: PutImageScanningConfigurationRequest,
+    ) -> Result<
+        (PutImageScanningConfigurationResponse, ResponseMetadata),
+        RusotoError<PutImageScanningConfigurationError>,
+    > {
+        let request = self.build_put_image_scanning_configuration_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<PutImageResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<PutImageScanningConfigurationResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(PutImageError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(PutImageScanningConfigurationError::from_response(response))
         }
     }
 
-    /// <p>Updates the image scanning configuration for a repository.</p>
-    async fn put_image_scanning_configuration(
+    /// Presigns the request `put_image_scanning_configuration` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_put_image_scanning_configuration(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: PutImageScanningConfigurationRequest,
-    ) -> Result<
-        PutImageScanningConfigurationResponse,
-        RusotoError<PutImageScanningConfigurationError>,
-    > {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_put_image_scanning_configuration_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_put_image_scanning_configuration_request(
+        &self,
+        input: PutImageScanningConfigurationRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "ecr", &self.region, "/");
+        request.set_operation("PutImageScanningConfiguration");
         request.set_endpoint_prefix("api.ecr".to_string());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -3710,28 +4611,58 @@ impl Ecr for EcrClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `put_image_tag_mutability`, also returning the metadata of the response.
+    pub async fn put_image_tag_mutability_with_metadata(
+        &self,
+        input: PutImageTagMutabilityRequest,
+    ) -> Result<
+        (PutImageTagMutabilityResponse, ResponseMetadata),
+        RusotoError<PutImageTagMutabilityError>,
+    > {
+        let request = self.build_put_image_tag_mutability_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<PutImageScanningConfigurationResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<PutImageTagMutabilityResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(PutImageScanningConfigurationError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(PutImageTagMutabilityError::from_response(response))
         }
     }
 
-    /// <p>Updates the image tag mutability settings for a repository. When a repository is configured with tag immutability, all image tags within the repository will be prevented them from being overwritten. For more information, see <a href="https://docs.aws.amazon.com/AmazonECR/latest/userguide/image-tag-mutability.html">Image Tag Mutability</a> in the <i>Amazon Elastic Container Registry User Guide</i>.</p>
-    async fn put_image_tag_mutability(
+    /// Presigns the request `put_image_tag_mutability` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_put_image_tag_mutability(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: PutImageTagMutabilityRequest,
-    ) -> Result<PutImageTagMutabilityResponse, RusotoError<PutImageTagMutabilityError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_put_image_tag_mutability_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_put_image_tag_mutability_request(
+        &self,
+        input: PutImageTagMutabilityRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "ecr", &self.region, "/");
+        request.set_operation("PutImageTagMutability");
         request.set_endpoint_prefix("api.ecr".to_string());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -3741,28 +4672,56 @@ impl Ecr for EcrClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `put_lifecycle_policy`, also returning the metadata of the response.
+    pub async fn put_lifecycle_policy_with_metadata(
+        &self,
+        input: PutLifecyclePolicyRequest,
+    ) -> Result<(PutLifecyclePolicyResponse, ResponseMetadata), RusotoError<PutLifecyclePolicyError>>
+    {
+        let request = self.build_put_lifecycle_policy_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<PutImageTagMutabilityResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<PutLifecyclePolicyResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(PutImageTagMutabilityError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(PutLifecyclePolicyError::from_response(response))
         }
     }
 
-    /// <p>Creates or updates a lifecycle policy. For information about lifecycle policy syntax, see <a href="https://docs.aws.amazon.com/AmazonECR/latest/userguide/LifecyclePolicies.html">Lifecycle Policy Template</a>.</p>
-    async fn put_lifecycle_policy(
+    /// Presigns the request `put_lifecycle_policy` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_put_lifecycle_policy(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: PutLifecyclePolicyRequest,
-    ) -> Result<PutLifecyclePolicyResponse, RusotoError<PutLifecyclePolicyError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_put_lifecycle_policy_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_put_lifecycle_policy_request(
+        &self,
+        input: PutLifecyclePolicyRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "ecr", &self.region, "/");
+        request.set_operation("PutLifecyclePolicy");
         request.set_endpoint_prefix("api.ecr".to_string());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -3772,28 +4731,58 @@ impl Ecr for EcrClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `set_repository_policy`, also returning the metadata of the response.
+    pub async fn set_repository_policy_with_metadata(
+        &self,
+        input: SetRepositoryPolicyRequest,
+    ) -> Result<
+        (SetRepositoryPolicyResponse, ResponseMetadata),
+        RusotoError<SetRepositoryPolicyError>,
+    > {
+        let request = self.build_set_repository_policy_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<PutLifecyclePolicyResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<SetRepositoryPolicyResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(PutLifecyclePolicyError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(SetRepositoryPolicyError::from_response(response))
         }
     }
 
-    /// <p>Applies a repository policy on a specified repository to control access permissions. For more information, see <a href="https://docs.aws.amazon.com/AmazonECR/latest/userguide/RepositoryPolicies.html">Amazon ECR Repository Policies</a> in the <i>Amazon Elastic Container Registry User Guide</i>.</p>
-    async fn set_repository_policy(
+    /// Presigns the request `set_repository_policy` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_set_repository_policy(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: SetRepositoryPolicyRequest,
-    ) -> Result<SetRepositoryPolicyResponse, RusotoError<SetRepositoryPolicyError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_set_repository_policy_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_set_repository_policy_request(
+        &self,
+        input: SetRepositoryPolicyRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "ecr", &self.region, "/");
+        request.set_operation("SetRepositoryPolicy");
         request.set_endpoint_prefix("api.ecr".to_string());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -3803,28 +4792,52 @@ impl Ecr for EcrClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `start_image_scan`, also returning the metadata of the response.
+    pub async fn start_image_scan_with_metadata(
+        &self,
+        input: StartImageScanRequest,
+    ) -> Result<(StartImageScanResponse, ResponseMetadata), RusotoError<StartImageScanError>> {
+        let request = self.build_start_image_scan_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<SetRepositoryPolicyResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<StartImageScanResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(SetRepositoryPolicyError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(StartImageScanError::from_response(response))
         }
     }
 
-    /// <p>Starts an image vulnerability scan. An image scan can only be started once per day on an individual image. This limit includes if an image was scanned on initial push. For more information, see <a href="https://docs.aws.amazon.com/AmazonECR/latest/userguide/image-scanning.html">Image Scanning</a> in the <i>Amazon Elastic Container Registry User Guide</i>.</p>
-    async fn start_image_scan(
+    /// Presigns the request `start_image_scan` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_start_image_scan(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: StartImageScanRequest,
-    ) -> Result<StartImageScanResponse, RusotoError<StartImageScanError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_start_image_scan_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_start_image_scan_request(&self, input: StartImageScanRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "ecr", &self.region, "/");
+        request.set_operation("StartImageScan");
         request.set_endpoint_prefix("api.ecr".to_string());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -3834,28 +4847,58 @@ impl Ecr for EcrClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `start_lifecycle_policy_preview`, also returning the metadata of the response.
+    pub async fn start_lifecycle_policy_preview_with_metadata(
+        &self,
+        input: StartLifecyclePolicyPreviewRequest,
+    ) -> Result<
+        (StartLifecyclePolicyPreviewResponse, ResponseMetadata),
+        RusotoError<StartLifecyclePolicyPreviewError>,
+    > {
+        let request = self.build_start_lifecycle_policy_preview_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<StartImageScanResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<StartLifecyclePolicyPreviewResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(StartImageScanError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(StartLifecyclePolicyPreviewError::from_response(response))
         }
     }
 
-    /// <p>Starts a preview of the specified lifecycle policy. This allows you to see the results before creating the lifecycle policy.</p>
-    async fn start_lifecycle_policy_preview(
+    /// Presigns the request `start_lifecycle_policy_preview` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_start_lifecycle_policy_preview(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: StartLifecyclePolicyPreviewRequest,
-    ) -> Result<StartLifecyclePolicyPreviewResponse, RusotoError<StartLifecyclePolicyPreviewError>>
-    {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_start_lifecycle_policy_preview_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_start_lifecycle_policy_preview_request(
+        &self,
+        input: StartLifecyclePolicyPreviewRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "ecr", &self.region, "/");
+        request.set_operation("StartLifecyclePolicyPreview");
         request.set_endpoint_prefix("api.ecr".to_string());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -3865,28 +4908,52 @@ impl Ecr for EcrClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `tag_resource`, also returning the metadata of the response.
+    pub async fn tag_resource_with_metadata(
+        &self,
+        input: TagResourceRequest,
+    ) -> Result<(TagResourceResponse, ResponseMetadata), RusotoError<TagResourceError>> {
+        let request = self.build_tag_resource_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<StartLifecyclePolicyPreviewResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<TagResourceResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(StartLifecyclePolicyPreviewError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(TagResourceError::from_response(response))
         }
     }
 
-    /// <p>Adds specified tags to a resource with the specified ARN. Existing tags on a resource are not changed if they are not specified in the request parameters.</p>
-    async fn tag_resource(
+    /// Presigns the request `tag_resource` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_tag_resource(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: TagResourceRequest,
-    ) -> Result<TagResourceResponse, RusotoError<TagResourceError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_tag_resource_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_tag_resource_request(&self, input: TagResourceRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "ecr", &self.region, "/");
+        request.set_operation("TagResource");
         request.set_endpoint_prefix("api.ecr".to_string());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -3896,27 +4963,52 @@ impl Ecr for EcrClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `untag_resource`, also returning the metadata of the response.
+    pub async fn untag_resource_with_metadata(
+        &self,
+        input: UntagResourceRequest,
+    ) -> Result<(UntagResourceResponse, ResponseMetadata), RusotoError<UntagResourceError>> {
+        let request = self.build_untag_resource_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<TagResourceResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<UntagResourceResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(TagResourceError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(UntagResourceError::from_response(response))
         }
     }
 
-    /// <p>Deletes specified tags from a resource.</p>
-    async fn untag_resource(
+    /// Presigns the request `untag_resource` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_untag_resource(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UntagResourceRequest,
-    ) -> Result<UntagResourceResponse, RusotoError<UntagResourceError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_untag_resource_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_untag_resource_request(&self, input: UntagResourceRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "ecr", &self.region, "/");
+        request.set_operation("UntagResource");
         request.set_endpoint_prefix("api.ecr".to_string());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -3926,27 +5018,53 @@ impl Ecr for EcrClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `upload_layer_part`, also returning the metadata of the response.
+    pub async fn upload_layer_part_with_metadata(
+        &self,
+        input: UploadLayerPartRequest,
+    ) -> Result<(UploadLayerPartResponse, ResponseMetadata), RusotoError<UploadLayerPartError>>
+    {
+        let request = self.build_upload_layer_part_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<UntagResourceResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<UploadLayerPartResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(UntagResourceError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(UploadLayerPartError::from_response(response))
         }
     }
 
-    /// <p><p>Uploads an image layer part to Amazon ECR.</p> <note> <p>This operation is used by the Amazon ECR proxy, and it is not intended for general use by customers for pulling and pushing images. In most cases, you should use the <code>docker</code> CLI to pull, tag, and push images.</p> </note></p>
-    async fn upload_layer_part(
+    /// Presigns the request `upload_layer_part` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_upload_layer_part(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UploadLayerPartRequest,
-    ) -> Result<UploadLayerPartResponse, RusotoError<UploadLayerPartError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_upload_layer_part_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_upload_layer_part_request(&self, input: UploadLayerPartRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "ecr", &self.region, "/");
+        request.set_operation("UploadLayerPart");
         request.set_endpoint_prefix("api.ecr".to_string());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -3956,18 +5074,573 @@ impl Ecr for EcrClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
-        let mut response = self
-            .client
-            .sign_and_dispatch(request)
+        request
+    }
+
+    /// The pages of `DescribeImageScanFindings` results for `input`, requesting each next page with the `nextToken` of the last one as its `nextToken`.
+    /// The page size is the `maxResults` of `input`.
+    pub fn describe_image_scan_findings_pages(
+        &self,
+        input: DescribeImageScanFindingsRequest,
+    ) -> impl futures::Stream<
+        Item = Result<
+            DescribeImageScanFindingsResponse,
+            RusotoError<DescribeImageScanFindingsError>,
+        >,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: EcrClient, input| async move { client.describe_image_scan_findings(input).await },
+            |input: &DescribeImageScanFindingsRequest,
+             output: &DescribeImageScanFindingsResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(DescribeImageScanFindingsRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The pages of `DescribeImages` results for `input`, requesting each next page with the `nextToken` of the last one as its `nextToken`.
+    /// The page size is the `maxResults` of `input`.
+    pub fn describe_images_pages(
+        &self,
+        input: DescribeImagesRequest,
+    ) -> impl futures::Stream<Item = Result<DescribeImagesResponse, RusotoError<DescribeImagesError>>>
+           + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: EcrClient, input| async move { client.describe_images(input).await },
+            |input: &DescribeImagesRequest, output: &DescribeImagesResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(DescribeImagesRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `imageDetails` of every page of `DescribeImages` results for `input`, as `describe_images_pages` requests them.
+    pub fn describe_images_stream(
+        &self,
+        input: DescribeImagesRequest,
+    ) -> impl futures::Stream<Item = Result<ImageDetail, RusotoError<DescribeImagesError>>> + Send
+    {
+        rusoto_core::pagination::items(self.describe_images_pages(input), |page| {
+            page.image_details.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `DescribeRepositories` results for `input`, requesting each next page with the `nextToken` of the last one as its `nextToken`.
+    /// The page size is the `maxResults` of `input`.
+    pub fn describe_repositories_pages(
+        &self,
+        input: DescribeRepositoriesRequest,
+    ) -> impl futures::Stream<
+        Item = Result<DescribeRepositoriesResponse, RusotoError<DescribeRepositoriesError>>,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: EcrClient, input| async move { client.describe_repositories(input).await },
+            |input: &DescribeRepositoriesRequest, output: &DescribeRepositoriesResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(DescribeRepositoriesRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `repositories` of every page of `DescribeRepositories` results for `input`, as `describe_repositories_pages` requests them.
+    pub fn describe_repositories_stream(
+        &self,
+        input: DescribeRepositoriesRequest,
+    ) -> impl futures::Stream<Item = Result<Repository, RusotoError<DescribeRepositoriesError>>> + Send
+    {
+        rusoto_core::pagination::items(self.describe_repositories_pages(input), |page| {
+            page.repositories.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `GetLifecyclePolicyPreview` results for `input`, requesting each next page with the `nextToken` of the last one as its `nextToken`.
+    /// The page size is the `maxResults` of `input`.
+    pub fn get_lifecycle_policy_preview_pages(
+        &self,
+        input: GetLifecyclePolicyPreviewRequest,
+    ) -> impl futures::Stream<
+        Item = Result<
+            GetLifecyclePolicyPreviewResponse,
+            RusotoError<GetLifecyclePolicyPreviewError>,
+        >,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: EcrClient, input| async move { client.get_lifecycle_policy_preview(input).await },
+            |input: &GetLifecyclePolicyPreviewRequest,
+             output: &GetLifecyclePolicyPreviewResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(GetLifecyclePolicyPreviewRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `previewResults` of every page of `GetLifecyclePolicyPreview` results for `input`, as `get_lifecycle_policy_preview_pages` requests them.
+    pub fn get_lifecycle_policy_preview_stream(
+        &self,
+        input: GetLifecyclePolicyPreviewRequest,
+    ) -> impl futures::Stream<
+        Item = Result<LifecyclePolicyPreviewResult, RusotoError<GetLifecyclePolicyPreviewError>>,
+    > + Send {
+        rusoto_core::pagination::items(self.get_lifecycle_policy_preview_pages(input), |page| {
+            page.preview_results.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `ListImages` results for `input`, requesting each next page with the `nextToken` of the last one as its `nextToken`.
+    /// The page size is the `maxResults` of `input`.
+    pub fn list_images_pages(
+        &self,
+        input: ListImagesRequest,
+    ) -> impl futures::Stream<Item = Result<ListImagesResponse, RusotoError<ListImagesError>>> + Send
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: EcrClient, input| async move { client.list_images(input).await },
+            |input: &ListImagesRequest, output: &ListImagesResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListImagesRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `imageIds` of every page of `ListImages` results for `input`, as `list_images_pages` requests them.
+    pub fn list_images_stream(
+        &self,
+        input: ListImagesRequest,
+    ) -> impl futures::Stream<Item = Result<ImageIdentifier, RusotoError<ListImagesError>>> + Send
+    {
+        rusoto_core::pagination::items(self.list_images_pages(input), |page| {
+            page.image_ids.unwrap_or_default()
+        })
+    }
+
+    /// Waits for `ImageScanComplete`, polling `DescribeImageScanFindings` with `input` every 5 seconds, up to 60 times.
+    pub async fn wait_until_image_scan_complete(
+        &self,
+        input: DescribeImageScanFindingsRequest,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<DescribeImageScanFindingsError>> {
+        let waiter = rusoto_core::waiter::Waiter::new(std::time::Duration::from_secs(5), 60);
+        self.wait_until_image_scan_complete_with(input, &waiter)
             .await
-            .map_err(RusotoError::from)?;
-        if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<UploadLayerPartResponse, _>()
-        } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(UploadLayerPartError::from_response(response))
-        }
+    }
+
+    /// Waits for `ImageScanComplete` like `wait_until_image_scan_complete`, with the delay, attempts and runtime of `waiter`.
+    pub async fn wait_until_image_scan_complete_with(
+        &self,
+        input: DescribeImageScanFindingsRequest,
+        waiter: &rusoto_core::waiter::Waiter,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<DescribeImageScanFindingsError>> {
+        rusoto_core::waiter::wait(
+            waiter,
+            || self.describe_image_scan_findings(input.clone()),
+            |result| {
+                if match result {
+                    Ok(output) => std::iter::once(output)
+                        .filter_map(|value| value.image_scan_status.as_ref())
+                        .filter_map(|value| value.status.as_ref())
+                        .any(|value| value == "COMPLETE"),
+                    Err(_) => false,
+                } {
+                    return Some(rusoto_core::waiter::WaiterState::Success);
+                }
+                if match result {
+                    Ok(output) => std::iter::once(output)
+                        .filter_map(|value| value.image_scan_status.as_ref())
+                        .filter_map(|value| value.status.as_ref())
+                        .any(|value| value == "FAILED"),
+                    Err(_) => false,
+                } {
+                    return Some(rusoto_core::waiter::WaiterState::Failure);
+                }
+                None
+            },
+        )
+        .await
+    }
+
+    /// Waits for `LifecyclePolicyPreviewComplete`, polling `GetLifecyclePolicyPreview` with `input` every 5 seconds, up to 20 times.
+    pub async fn wait_until_lifecycle_policy_preview_complete(
+        &self,
+        input: GetLifecyclePolicyPreviewRequest,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<GetLifecyclePolicyPreviewError>> {
+        let waiter = rusoto_core::waiter::Waiter::new(std::time::Duration::from_secs(5), 20);
+        self.wait_until_lifecycle_policy_preview_complete_with(input, &waiter)
+            .await
+    }
+
+    /// Waits for `LifecyclePolicyPreviewComplete` like `wait_until_lifecycle_policy_preview_complete`, with the delay, attempts and runtime of `waiter`.
+    pub async fn wait_until_lifecycle_policy_preview_complete_with(
+        &self,
+        input: GetLifecyclePolicyPreviewRequest,
+        waiter: &rusoto_core::waiter::Waiter,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<GetLifecyclePolicyPreviewError>> {
+        rusoto_core::waiter::wait(
+            waiter,
+            || self.get_lifecycle_policy_preview(input.clone()),
+            |result| {
+                if match result {
+                    Ok(output) => std::iter::once(output)
+                        .filter_map(|value| value.status.as_ref())
+                        .any(|value| value == "COMPLETE"),
+                    Err(_) => false,
+                } {
+                    return Some(rusoto_core::waiter::WaiterState::Success);
+                }
+                if match result {
+                    Ok(output) => std::iter::once(output)
+                        .filter_map(|value| value.status.as_ref())
+                        .any(|value| value == "FAILED"),
+                    Err(_) => false,
+                } {
+                    return Some(rusoto_core::waiter::WaiterState::Failure);
+                }
+                None
+            },
+        )
+        .await
+    }
+}
+
+#[async_trait]
+impl Ecr for EcrClient {
+    /// <p><p>Check the availability of multiple image layers in a specified registry and repository.</p> <note> <p>This operation is used by the Amazon ECR proxy, and it is not intended for general use by customers for pulling and pushing images. In most cases, you should use the <code>docker</code> CLI to pull, tag, and push images.</p> </note></p>
+    async fn batch_check_layer_availability(
+        &self,
+        input: BatchCheckLayerAvailabilityRequest,
+    ) -> Result<BatchCheckLayerAvailabilityResponse, RusotoError<BatchCheckLayerAvailabilityError>>
+    {
+        self.batch_check_layer_availability_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes a list of specified images within a specified repository. Images are specified with either <code>imageTag</code> or <code>imageDigest</code>.</p> <p>You can remove a tag from an image by specifying the image's tag in your request. When you remove the last tag from an image, the image is deleted from your repository.</p> <p>You can completely delete an image (and all of its tags) by specifying the image's digest in your request.</p>
+    async fn batch_delete_image(
+        &self,
+        input: BatchDeleteImageRequest,
+    ) -> Result<BatchDeleteImageResponse, RusotoError<BatchDeleteImageError>> {
+        self.batch_delete_image_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Gets detailed information for specified images within a specified repository. Images are specified with either <code>imageTag</code> or <code>imageDigest</code>.</p>
+    async fn batch_get_image(
+        &self,
+        input: BatchGetImageRequest,
+    ) -> Result<BatchGetImageResponse, RusotoError<BatchGetImageError>> {
+        self.batch_get_image_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Informs Amazon ECR that the image layer upload has completed for a specified registry, repository name, and upload ID. You can optionally provide a <code>sha256</code> digest of the image layer for data validation purposes.</p> <note> <p>This operation is used by the Amazon ECR proxy, and it is not intended for general use by customers for pulling and pushing images. In most cases, you should use the <code>docker</code> CLI to pull, tag, and push images.</p> </note></p>
+    async fn complete_layer_upload(
+        &self,
+        input: CompleteLayerUploadRequest,
+    ) -> Result<CompleteLayerUploadResponse, RusotoError<CompleteLayerUploadError>> {
+        self.complete_layer_upload_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Creates an Amazon Elastic Container Registry (Amazon ECR) repository, where users can push and pull Docker images. For more information, see <a href="https://docs.aws.amazon.com/AmazonECR/latest/userguide/Repositories.html">Amazon ECR Repositories</a> in the <i>Amazon Elastic Container Registry User Guide</i>.</p>
+    async fn create_repository(
+        &self,
+        input: CreateRepositoryRequest,
+    ) -> Result<CreateRepositoryResponse, RusotoError<CreateRepositoryError>> {
+        self.create_repository_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes the specified lifecycle policy.</p>
+    async fn delete_lifecycle_policy(
+        &self,
+        input: DeleteLifecyclePolicyRequest,
+    ) -> Result<DeleteLifecyclePolicyResponse, RusotoError<DeleteLifecyclePolicyError>> {
+        self.delete_lifecycle_policy_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes an existing image repository. If a repository contains images, you must use the <code>force</code> option to delete it.</p>
+    async fn delete_repository(
+        &self,
+        input: DeleteRepositoryRequest,
+    ) -> Result<DeleteRepositoryResponse, RusotoError<DeleteRepositoryError>> {
+        self.delete_repository_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes the repository policy from a specified repository.</p>
+    async fn delete_repository_policy(
+        &self,
+        input: DeleteRepositoryPolicyRequest,
+    ) -> Result<DeleteRepositoryPolicyResponse, RusotoError<DeleteRepositoryPolicyError>> {
+        self.delete_repository_policy_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Describes the image scan findings for the specified image.</p>
+    async fn describe_image_scan_findings(
+        &self,
+        input: DescribeImageScanFindingsRequest,
+    ) -> Result<DescribeImageScanFindingsResponse, RusotoError<DescribeImageScanFindingsError>>
+    {
+        self.describe_image_scan_findings_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Returns metadata about the images in a repository, including image size, image tags, and creation date.</p> <note> <p>Beginning with Docker version 1.9, the Docker client compresses image layers before pushing them to a V2 Docker registry. The output of the <code>docker images</code> command shows the uncompressed image size, so it may return a larger image size than the image sizes returned by <a>DescribeImages</a>.</p> </note></p>
+    async fn describe_images(
+        &self,
+        input: DescribeImagesRequest,
+    ) -> Result<DescribeImagesResponse, RusotoError<DescribeImagesError>> {
+        self.describe_images_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Describes image repositories in a registry.</p>
+    async fn describe_repositories(
+        &self,
+        input: DescribeRepositoriesRequest,
+    ) -> Result<DescribeRepositoriesResponse, RusotoError<DescribeRepositoriesError>> {
+        self.describe_repositories_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Retrieves a token that is valid for a specified registry for 12 hours. This command allows you to use the <code>docker</code> CLI to push and pull images with Amazon ECR. If you do not specify a registry, the default registry is assumed.</p> <p>The <code>authorizationToken</code> returned for each registry specified is a base64 encoded string that can be decoded and used in a <code>docker login</code> command to authenticate to a registry. The AWS CLI offers an <code>aws ecr get-login</code> command that simplifies the login process.</p>
+    async fn get_authorization_token(
+        &self,
+        input: GetAuthorizationTokenRequest,
+    ) -> Result<GetAuthorizationTokenResponse, RusotoError<GetAuthorizationTokenError>> {
+        self.get_authorization_token_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Retrieves the pre-signed Amazon S3 download URL corresponding to an image layer. You can only get URLs for image layers that are referenced in an image.</p> <note> <p>This operation is used by the Amazon ECR proxy, and it is not intended for general use by customers for pulling and pushing images. In most cases, you should use the <code>docker</code> CLI to pull, tag, and push images.</p> </note></p>
+    async fn get_download_url_for_layer(
+        &self,
+        input: GetDownloadUrlForLayerRequest,
+    ) -> Result<GetDownloadUrlForLayerResponse, RusotoError<GetDownloadUrlForLayerError>> {
+        self.get_download_url_for_layer_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Retrieves the specified lifecycle policy.</p>
+    async fn get_lifecycle_policy(
+        &self,
+        input: GetLifecyclePolicyRequest,
+    ) -> Result<GetLifecyclePolicyResponse, RusotoError<GetLifecyclePolicyError>> {
+        self.get_lifecycle_policy_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Retrieves the results of the specified lifecycle policy preview request.</p>
+    async fn get_lifecycle_policy_preview(
+        &self,
+        input: GetLifecyclePolicyPreviewRequest,
+    ) -> Result<GetLifecyclePolicyPreviewResponse, RusotoError<GetLifecyclePolicyPreviewError>>
+    {
+        self.get_lifecycle_policy_preview_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Retrieves the repository policy for a specified repository.</p>
+    async fn get_repository_policy(
+        &self,
+        input: GetRepositoryPolicyRequest,
+    ) -> Result<GetRepositoryPolicyResponse, RusotoError<GetRepositoryPolicyError>> {
+        self.get_repository_policy_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Notify Amazon ECR that you intend to upload an image layer.</p> <note> <p>This operation is used by the Amazon ECR proxy, and it is not intended for general use by customers for pulling and pushing images. In most cases, you should use the <code>docker</code> CLI to pull, tag, and push images.</p> </note></p>
+    async fn initiate_layer_upload(
+        &self,
+        input: InitiateLayerUploadRequest,
+    ) -> Result<InitiateLayerUploadResponse, RusotoError<InitiateLayerUploadError>> {
+        self.initiate_layer_upload_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Lists all the image IDs for a given repository.</p> <p>You can filter images based on whether or not they are tagged by setting the <code>tagStatus</code> parameter to <code>TAGGED</code> or <code>UNTAGGED</code>. For example, you can filter your results to return only <code>UNTAGGED</code> images and then pipe that result to a <a>BatchDeleteImage</a> operation to delete them. Or, you can filter your results to return only <code>TAGGED</code> images to list all of the tags in your repository.</p>
+    async fn list_images(
+        &self,
+        input: ListImagesRequest,
+    ) -> Result<ListImagesResponse, RusotoError<ListImagesError>> {
+        self.list_images_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>List the tags for an Amazon ECR resource.</p>
+    async fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> Result<ListTagsForResourceResponse, RusotoError<ListTagsForResourceError>> {
+        self.list_tags_for_resource_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Creates or updates the image manifest and tags associated with an image.</p> <note> <p>This operation is used by the Amazon ECR proxy, and it is not intended for general use by customers for pulling and pushing images. In most cases, you should use the <code>docker</code> CLI to pull, tag, and push images.</p> </note></p>
+    async fn put_image(
+        &self,
+        input: PutImageRequest,
+    ) -> Result<PutImageResponse, RusotoError<PutImageError>> {
+        self.put_image_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Updates the image scanning configuration for a repository.</p>
+    async fn put_image_scanning_configuration(
+        &self,
+        input: PutImageScanningConfigurationRequest,
+    ) -> Result<
+        PutImageScanningConfigurationResponse,
+        RusotoError<PutImageScanningConfigurationError>,
+    > {
+        self.put_image_scanning_configuration_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Updates the image tag mutability settings for a repository. When a repository is configured with tag immutability, all image tags within the repository will be prevented them from being overwritten. For more information, see <a href="https://docs.aws.amazon.com/AmazonECR/latest/userguide/image-tag-mutability.html">Image Tag Mutability</a> in the <i>Amazon Elastic Container Registry User Guide</i>.</p>
+    async fn put_image_tag_mutability(
+        &self,
+        input: PutImageTagMutabilityRequest,
+    ) -> Result<PutImageTagMutabilityResponse, RusotoError<PutImageTagMutabilityError>> {
+        self.put_image_tag_mutability_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Creates or updates a lifecycle policy. For information about lifecycle policy syntax, see <a href="https://docs.aws.amazon.com/AmazonECR/latest/userguide/LifecyclePolicies.html">Lifecycle Policy Template</a>.</p>
+    async fn put_lifecycle_policy(
+        &self,
+        input: PutLifecyclePolicyRequest,
+    ) -> Result<PutLifecyclePolicyResponse, RusotoError<PutLifecyclePolicyError>> {
+        self.put_lifecycle_policy_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Applies a repository policy on a specified repository to control access permissions. For more information, see <a href="https://docs.aws.amazon.com/AmazonECR/latest/userguide/RepositoryPolicies.html">Amazon ECR Repository Policies</a> in the <i>Amazon Elastic Container Registry User Guide</i>.</p>
+    async fn set_repository_policy(
+        &self,
+        input: SetRepositoryPolicyRequest,
+    ) -> Result<SetRepositoryPolicyResponse, RusotoError<SetRepositoryPolicyError>> {
+        self.set_repository_policy_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Starts an image vulnerability scan. An image scan can only be started once per day on an individual image. This limit includes if an image was scanned on initial push. For more information, see <a href="https://docs.aws.amazon.com/AmazonECR/latest/userguide/image-scanning.html">Image Scanning</a> in the <i>Amazon Elastic Container Registry User Guide</i>.</p>
+    async fn start_image_scan(
+        &self,
+        input: StartImageScanRequest,
+    ) -> Result<StartImageScanResponse, RusotoError<StartImageScanError>> {
+        self.start_image_scan_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Starts a preview of the specified lifecycle policy. This allows you to see the results before creating the lifecycle policy.</p>
+    async fn start_lifecycle_policy_preview(
+        &self,
+        input: StartLifecyclePolicyPreviewRequest,
+    ) -> Result<StartLifecyclePolicyPreviewResponse, RusotoError<StartLifecyclePolicyPreviewError>>
+    {
+        self.start_lifecycle_policy_preview_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Adds specified tags to a resource with the specified ARN. Existing tags on a resource are not changed if they are not specified in the request parameters.</p>
+    async fn tag_resource(
+        &self,
+        input: TagResourceRequest,
+    ) -> Result<TagResourceResponse, RusotoError<TagResourceError>> {
+        self.tag_resource_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes specified tags from a resource.</p>
+    async fn untag_resource(
+        &self,
+        input: UntagResourceRequest,
+    ) -> Result<UntagResourceResponse, RusotoError<UntagResourceError>> {
+        self.untag_resource_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Uploads an image layer part to Amazon ECR.</p> <note> <p>This operation is used by the Amazon ECR proxy, and it is not intended for general use by customers for pulling and pushing images. In most cases, you should use the <code>docker</code> CLI to pull, tag, and push images.</p> </note></p>
+    async fn upload_layer_part(
+        &self,
+        input: UploadLayerPartRequest,
+    ) -> Result<UploadLayerPartResponse, RusotoError<UploadLayerPartError>> {
+        self.upload_layer_part_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
     }
 }
@@ -16,8 +16,8 @@ use std::fmt;
 use async_trait::async_trait;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
-use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest, ResponseMetadata};
+use rusoto_core::{Client, ErrorMetadata, RusotoError};
 
 use rusoto_core::proto;
 use rusoto_core::signature::SignedRequest;
@@ -3423,6 +3423,7 @@ pub struct VolumeFrom {
 
 /// Errors returned by CreateCapacityProvider
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateCapacityProviderError {
     /// <p>These errors are usually caused by a client action, such as using an action or resource on behalf of a user that doesn't have permissions to use the action or resource, or specifying an identifier that is not valid.</p>
     Client(String),
@@ -3432,6 +3433,8 @@ pub enum CreateCapacityProviderError {
     LimitExceeded(String),
     /// <p>These errors are usually caused by a server issue.</p>
     Server(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateCapacityProviderError {
@@ -3439,23 +3442,25 @@ impl CreateCapacityProviderError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ClientException" => {
-                    return RusotoError::Service(CreateCapacityProviderError::Client(err.msg))
+                    return RusotoError::service(CreateCapacityProviderError::Client(err.msg), &res)
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(CreateCapacityProviderError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateCapacityProviderError::InvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateCapacityProviderError::LimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateCapacityProviderError::LimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "ServerException" => {
-                    return RusotoError::Service(CreateCapacityProviderError::Server(err.msg))
+                    return RusotoError::service(CreateCapacityProviderError::Server(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreateCapacityProviderError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3469,12 +3474,14 @@ impl fmt::Display for CreateCapacityProviderError {
             CreateCapacityProviderError::InvalidParameter(ref cause) => write!(f, "{}", cause),
             CreateCapacityProviderError::LimitExceeded(ref cause) => write!(f, "{}", cause),
             CreateCapacityProviderError::Server(ref cause) => write!(f, "{}", cause),
+            CreateCapacityProviderError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateCapacityProviderError {}
 /// Errors returned by CreateCluster
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateClusterError {
     /// <p>These errors are usually caused by a client action, such as using an action or resource on behalf of a user that doesn't have permissions to use the action or resource, or specifying an identifier that is not valid.</p>
     Client(String),
@@ -3482,6 +3489,8 @@ pub enum CreateClusterError {
     InvalidParameter(String),
     /// <p>These errors are usually caused by a server issue.</p>
     Server(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateClusterError {
@@ -3489,16 +3498,19 @@ impl CreateClusterError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ClientException" => {
-                    return RusotoError::Service(CreateClusterError::Client(err.msg))
+                    return RusotoError::service(CreateClusterError::Client(err.msg), &res)
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(CreateClusterError::InvalidParameter(err.msg))
+                    return RusotoError::service(
+                        CreateClusterError::InvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "ServerException" => {
-                    return RusotoError::Service(CreateClusterError::Server(err.msg))
+                    return RusotoError::service(CreateClusterError::Server(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreateClusterError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3511,12 +3523,14 @@ impl fmt::Display for CreateClusterError {
             CreateClusterError::Client(ref cause) => write!(f, "{}", cause),
             CreateClusterError::InvalidParameter(ref cause) => write!(f, "{}", cause),
             CreateClusterError::Server(ref cause) => write!(f, "{}", cause),
+            CreateClusterError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateClusterError {}
 /// Errors returned by CreateService
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateServiceError {
     /// <p>You do not have authorization to perform the requested action.</p>
     AccessDenied(String),
@@ -3534,6 +3548,8 @@ pub enum CreateServiceError {
     Server(String),
     /// <p>The specified task is not supported in this Region.</p>
     UnsupportedFeature(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateServiceError {
@@ -3541,33 +3557,40 @@ impl CreateServiceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(CreateServiceError::AccessDenied(err.msg))
+                    return RusotoError::service(CreateServiceError::AccessDenied(err.msg), &res)
                 }
                 "ClientException" => {
-                    return RusotoError::Service(CreateServiceError::Client(err.msg))
+                    return RusotoError::service(CreateServiceError::Client(err.msg), &res)
                 }
                 "ClusterNotFoundException" => {
-                    return RusotoError::Service(CreateServiceError::ClusterNotFound(err.msg))
+                    return RusotoError::service(CreateServiceError::ClusterNotFound(err.msg), &res)
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(CreateServiceError::InvalidParameter(err.msg))
+                    return RusotoError::service(
+                        CreateServiceError::InvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "PlatformTaskDefinitionIncompatibilityException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         CreateServiceError::PlatformTaskDefinitionIncompatibility(err.msg),
+                        &res,
                     )
                 }
                 "PlatformUnknownException" => {
-                    return RusotoError::Service(CreateServiceError::PlatformUnknown(err.msg))
+                    return RusotoError::service(CreateServiceError::PlatformUnknown(err.msg), &res)
                 }
                 "ServerException" => {
-                    return RusotoError::Service(CreateServiceError::Server(err.msg))
+                    return RusotoError::service(CreateServiceError::Server(err.msg), &res)
                 }
                 "UnsupportedFeatureException" => {
-                    return RusotoError::Service(CreateServiceError::UnsupportedFeature(err.msg))
+                    return RusotoError::service(
+                        CreateServiceError::UnsupportedFeature(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreateServiceError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3587,12 +3610,14 @@ impl fmt::Display for CreateServiceError {
             CreateServiceError::PlatformUnknown(ref cause) => write!(f, "{}", cause),
             CreateServiceError::Server(ref cause) => write!(f, "{}", cause),
             CreateServiceError::UnsupportedFeature(ref cause) => write!(f, "{}", cause),
+            CreateServiceError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateServiceError {}
 /// Errors returned by CreateTaskSet
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateTaskSetError {
     /// <p>You do not have authorization to perform the requested action.</p>
     AccessDenied(String),
@@ -3614,6 +3639,8 @@ pub enum CreateTaskSetError {
     ServiceNotFound(String),
     /// <p>The specified task is not supported in this Region.</p>
     UnsupportedFeature(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateTaskSetError {
@@ -3621,39 +3648,49 @@ impl CreateTaskSetError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(CreateTaskSetError::AccessDenied(err.msg))
+                    return RusotoError::service(CreateTaskSetError::AccessDenied(err.msg), &res)
                 }
                 "ClientException" => {
-                    return RusotoError::Service(CreateTaskSetError::Client(err.msg))
+                    return RusotoError::service(CreateTaskSetError::Client(err.msg), &res)
                 }
                 "ClusterNotFoundException" => {
-                    return RusotoError::Service(CreateTaskSetError::ClusterNotFound(err.msg))
+                    return RusotoError::service(CreateTaskSetError::ClusterNotFound(err.msg), &res)
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(CreateTaskSetError::InvalidParameter(err.msg))
+                    return RusotoError::service(
+                        CreateTaskSetError::InvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "PlatformTaskDefinitionIncompatibilityException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         CreateTaskSetError::PlatformTaskDefinitionIncompatibility(err.msg),
+                        &res,
                     )
                 }
                 "PlatformUnknownException" => {
-                    return RusotoError::Service(CreateTaskSetError::PlatformUnknown(err.msg))
+                    return RusotoError::service(CreateTaskSetError::PlatformUnknown(err.msg), &res)
                 }
                 "ServerException" => {
-                    return RusotoError::Service(CreateTaskSetError::Server(err.msg))
+                    return RusotoError::service(CreateTaskSetError::Server(err.msg), &res)
                 }
                 "ServiceNotActiveException" => {
-                    return RusotoError::Service(CreateTaskSetError::ServiceNotActive(err.msg))
+                    return RusotoError::service(
+                        CreateTaskSetError::ServiceNotActive(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceNotFoundException" => {
-                    return RusotoError::Service(CreateTaskSetError::ServiceNotFound(err.msg))
+                    return RusotoError::service(CreateTaskSetError::ServiceNotFound(err.msg), &res)
                 }
                 "UnsupportedFeatureException" => {
-                    return RusotoError::Service(CreateTaskSetError::UnsupportedFeature(err.msg))
+                    return RusotoError::service(
+                        CreateTaskSetError::UnsupportedFeature(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreateTaskSetError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3675,12 +3712,14 @@ impl fmt::Display for CreateTaskSetError {
             CreateTaskSetError::ServiceNotActive(ref cause) => write!(f, "{}", cause),
             CreateTaskSetError::ServiceNotFound(ref cause) => write!(f, "{}", cause),
             CreateTaskSetError::UnsupportedFeature(ref cause) => write!(f, "{}", cause),
+            CreateTaskSetError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateTaskSetError {}
 /// Errors returned by DeleteAccountSetting
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteAccountSettingError {
     /// <p>These errors are usually caused by a client action, such as using an action or resource on behalf of a user that doesn't have permissions to use the action or resource, or specifying an identifier that is not valid.</p>
     Client(String),
@@ -3688,6 +3727,8 @@ pub enum DeleteAccountSettingError {
     InvalidParameter(String),
     /// <p>These errors are usually caused by a server issue.</p>
     Server(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteAccountSettingError {
@@ -3695,18 +3736,19 @@ impl DeleteAccountSettingError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ClientException" => {
-                    return RusotoError::Service(DeleteAccountSettingError::Client(err.msg))
+                    return RusotoError::service(DeleteAccountSettingError::Client(err.msg), &res)
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DeleteAccountSettingError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteAccountSettingError::InvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "ServerException" => {
-                    return RusotoError::Service(DeleteAccountSettingError::Server(err.msg))
+                    return RusotoError::service(DeleteAccountSettingError::Server(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteAccountSettingError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3719,12 +3761,14 @@ impl fmt::Display for DeleteAccountSettingError {
             DeleteAccountSettingError::Client(ref cause) => write!(f, "{}", cause),
             DeleteAccountSettingError::InvalidParameter(ref cause) => write!(f, "{}", cause),
             DeleteAccountSettingError::Server(ref cause) => write!(f, "{}", cause),
+            DeleteAccountSettingError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteAccountSettingError {}
 /// Errors returned by DeleteAttributes
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteAttributesError {
     /// <p>The specified cluster could not be found. You can view your available clusters with <a>ListClusters</a>. Amazon ECS clusters are Region-specific.</p>
     ClusterNotFound(String),
@@ -3732,6 +3776,8 @@ pub enum DeleteAttributesError {
     InvalidParameter(String),
     /// <p>The specified target could not be found. You can view your available container instances with <a>ListContainerInstances</a>. Amazon ECS container instances are cluster-specific and Region-specific.</p>
     TargetNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteAttributesError {
@@ -3739,16 +3785,25 @@ impl DeleteAttributesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ClusterNotFoundException" => {
-                    return RusotoError::Service(DeleteAttributesError::ClusterNotFound(err.msg))
+                    return RusotoError::service(
+                        DeleteAttributesError::ClusterNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DeleteAttributesError::InvalidParameter(err.msg))
+                    return RusotoError::service(
+                        DeleteAttributesError::InvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "TargetNotFoundException" => {
-                    return RusotoError::Service(DeleteAttributesError::TargetNotFound(err.msg))
+                    return RusotoError::service(
+                        DeleteAttributesError::TargetNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteAttributesError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3761,12 +3816,14 @@ impl fmt::Display for DeleteAttributesError {
             DeleteAttributesError::ClusterNotFound(ref cause) => write!(f, "{}", cause),
             DeleteAttributesError::InvalidParameter(ref cause) => write!(f, "{}", cause),
             DeleteAttributesError::TargetNotFound(ref cause) => write!(f, "{}", cause),
+            DeleteAttributesError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteAttributesError {}
 /// Errors returned by DeleteCluster
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteClusterError {
     /// <p>These errors are usually caused by a client action, such as using an action or resource on behalf of a user that doesn't have permissions to use the action or resource, or specifying an identifier that is not valid.</p>
     Client(String),
@@ -3784,6 +3841,8 @@ pub enum DeleteClusterError {
     Server(String),
     /// <p>There is already a current Amazon ECS container agent update in progress on the specified container instance. If the container agent becomes disconnected while it is in a transitional stage, such as <code>PENDING</code> or <code>STAGING</code>, the update process can get stuck in that state. However, when the agent reconnects, it resumes where it stopped previously.</p>
     UpdateInProgress(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteClusterError {
@@ -3791,35 +3850,46 @@ impl DeleteClusterError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ClientException" => {
-                    return RusotoError::Service(DeleteClusterError::Client(err.msg))
+                    return RusotoError::service(DeleteClusterError::Client(err.msg), &res)
                 }
                 "ClusterContainsContainerInstancesException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DeleteClusterError::ClusterContainsContainerInstances(err.msg),
+                        &res,
                     )
                 }
                 "ClusterContainsServicesException" => {
-                    return RusotoError::Service(DeleteClusterError::ClusterContainsServices(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteClusterError::ClusterContainsServices(err.msg),
+                        &res,
+                    )
                 }
                 "ClusterContainsTasksException" => {
-                    return RusotoError::Service(DeleteClusterError::ClusterContainsTasks(err.msg))
+                    return RusotoError::service(
+                        DeleteClusterError::ClusterContainsTasks(err.msg),
+                        &res,
+                    )
                 }
                 "ClusterNotFoundException" => {
-                    return RusotoError::Service(DeleteClusterError::ClusterNotFound(err.msg))
+                    return RusotoError::service(DeleteClusterError::ClusterNotFound(err.msg), &res)
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DeleteClusterError::InvalidParameter(err.msg))
+                    return RusotoError::service(
+                        DeleteClusterError::InvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "ServerException" => {
-                    return RusotoError::Service(DeleteClusterError::Server(err.msg))
+                    return RusotoError::service(DeleteClusterError::Server(err.msg), &res)
                 }
                 "UpdateInProgressException" => {
-                    return RusotoError::Service(DeleteClusterError::UpdateInProgress(err.msg))
+                    return RusotoError::service(
+                        DeleteClusterError::UpdateInProgress(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteClusterError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3839,12 +3909,14 @@ impl fmt::Display for DeleteClusterError {
             DeleteClusterError::InvalidParameter(ref cause) => write!(f, "{}", cause),
             DeleteClusterError::Server(ref cause) => write!(f, "{}", cause),
             DeleteClusterError::UpdateInProgress(ref cause) => write!(f, "{}", cause),
+            DeleteClusterError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteClusterError {}
 /// Errors returned by DeleteService
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteServiceError {
     /// <p>These errors are usually caused by a client action, such as using an action or resource on behalf of a user that doesn't have permissions to use the action or resource, or specifying an identifier that is not valid.</p>
     Client(String),
@@ -3856,6 +3928,8 @@ pub enum DeleteServiceError {
     Server(String),
     /// <p>The specified service could not be found. You can view your available services with <a>ListServices</a>. Amazon ECS services are cluster-specific and Region-specific.</p>
     ServiceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteServiceError {
@@ -3863,22 +3937,25 @@ impl DeleteServiceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ClientException" => {
-                    return RusotoError::Service(DeleteServiceError::Client(err.msg))
+                    return RusotoError::service(DeleteServiceError::Client(err.msg), &res)
                 }
                 "ClusterNotFoundException" => {
-                    return RusotoError::Service(DeleteServiceError::ClusterNotFound(err.msg))
+                    return RusotoError::service(DeleteServiceError::ClusterNotFound(err.msg), &res)
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DeleteServiceError::InvalidParameter(err.msg))
+                    return RusotoError::service(
+                        DeleteServiceError::InvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "ServerException" => {
-                    return RusotoError::Service(DeleteServiceError::Server(err.msg))
+                    return RusotoError::service(DeleteServiceError::Server(err.msg), &res)
                 }
                 "ServiceNotFoundException" => {
-                    return RusotoError::Service(DeleteServiceError::ServiceNotFound(err.msg))
+                    return RusotoError::service(DeleteServiceError::ServiceNotFound(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteServiceError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3893,12 +3970,14 @@ impl fmt::Display for DeleteServiceError {
             DeleteServiceError::InvalidParameter(ref cause) => write!(f, "{}", cause),
             DeleteServiceError::Server(ref cause) => write!(f, "{}", cause),
             DeleteServiceError::ServiceNotFound(ref cause) => write!(f, "{}", cause),
+            DeleteServiceError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteServiceError {}
 /// Errors returned by DeleteTaskSet
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteTaskSetError {
     /// <p>You do not have authorization to perform the requested action.</p>
     AccessDenied(String),
@@ -3918,6 +3997,8 @@ pub enum DeleteTaskSetError {
     TaskSetNotFound(String),
     /// <p>The specified task is not supported in this Region.</p>
     UnsupportedFeature(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteTaskSetError {
@@ -3925,34 +4006,43 @@ impl DeleteTaskSetError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(DeleteTaskSetError::AccessDenied(err.msg))
+                    return RusotoError::service(DeleteTaskSetError::AccessDenied(err.msg), &res)
                 }
                 "ClientException" => {
-                    return RusotoError::Service(DeleteTaskSetError::Client(err.msg))
+                    return RusotoError::service(DeleteTaskSetError::Client(err.msg), &res)
                 }
                 "ClusterNotFoundException" => {
-                    return RusotoError::Service(DeleteTaskSetError::ClusterNotFound(err.msg))
+                    return RusotoError::service(DeleteTaskSetError::ClusterNotFound(err.msg), &res)
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DeleteTaskSetError::InvalidParameter(err.msg))
+                    return RusotoError::service(
+                        DeleteTaskSetError::InvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "ServerException" => {
-                    return RusotoError::Service(DeleteTaskSetError::Server(err.msg))
+                    return RusotoError::service(DeleteTaskSetError::Server(err.msg), &res)
                 }
                 "ServiceNotActiveException" => {
-                    return RusotoError::Service(DeleteTaskSetError::ServiceNotActive(err.msg))
+                    return RusotoError::service(
+                        DeleteTaskSetError::ServiceNotActive(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceNotFoundException" => {
-                    return RusotoError::Service(DeleteTaskSetError::ServiceNotFound(err.msg))
+                    return RusotoError::service(DeleteTaskSetError::ServiceNotFound(err.msg), &res)
                 }
                 "TaskSetNotFoundException" => {
-                    return RusotoError::Service(DeleteTaskSetError::TaskSetNotFound(err.msg))
+                    return RusotoError::service(DeleteTaskSetError::TaskSetNotFound(err.msg), &res)
                 }
                 "UnsupportedFeatureException" => {
-                    return RusotoError::Service(DeleteTaskSetError::UnsupportedFeature(err.msg))
+                    return RusotoError::service(
+                        DeleteTaskSetError::UnsupportedFeature(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteTaskSetError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3971,12 +4061,14 @@ impl fmt::Display for DeleteTaskSetError {
             DeleteTaskSetError::ServiceNotFound(ref cause) => write!(f, "{}", cause),
             DeleteTaskSetError::TaskSetNotFound(ref cause) => write!(f, "{}", cause),
             DeleteTaskSetError::UnsupportedFeature(ref cause) => write!(f, "{}", cause),
+            DeleteTaskSetError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteTaskSetError {}
 /// Errors returned by DeregisterContainerInstance
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeregisterContainerInstanceError {
     /// <p>These errors are usually caused by a client action, such as using an action or resource on behalf of a user that doesn't have permissions to use the action or resource, or specifying an identifier that is not valid.</p>
     Client(String),
@@ -3986,6 +4078,8 @@ pub enum DeregisterContainerInstanceError {
     InvalidParameter(String),
     /// <p>These errors are usually caused by a server issue.</p>
     Server(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeregisterContainerInstanceError {
@@ -3995,23 +4089,36 @@ impl DeregisterContainerInstanceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ClientException" => {
-                    return RusotoError::Service(DeregisterContainerInstanceError::Client(err.msg))
+                    return RusotoError::service(
+                        DeregisterContainerInstanceError::Client(err.msg),
+                        &res,
+                    )
                 }
                 "ClusterNotFoundException" => {
-                    return RusotoError::Service(DeregisterContainerInstanceError::ClusterNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeregisterContainerInstanceError::ClusterNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DeregisterContainerInstanceError::InvalidParameter(err.msg),
+                        &res,
                     )
                 }
                 "ServerException" => {
-                    return RusotoError::Service(DeregisterContainerInstanceError::Server(err.msg))
+                    return RusotoError::service(
+                        DeregisterContainerInstanceError::Server(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        DeregisterContainerInstanceError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -4025,12 +4132,14 @@ impl fmt::Display for DeregisterContainerInstanceError {
             DeregisterContainerInstanceError::ClusterNotFound(ref cause) => write!(f, "{}", cause),
             DeregisterContainerInstanceError::InvalidParameter(ref cause) => write!(f, "{}", cause),
             DeregisterContainerInstanceError::Server(ref cause) => write!(f, "{}", cause),
+            DeregisterContainerInstanceError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeregisterContainerInstanceError {}
 /// Errors returned by DeregisterTaskDefinition
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeregisterTaskDefinitionError {
     /// <p>These errors are usually caused by a client action, such as using an action or resource on behalf of a user that doesn't have permissions to use the action or resource, or specifying an identifier that is not valid.</p>
     Client(String),
@@ -4038,6 +4147,8 @@ pub enum DeregisterTaskDefinitionError {
     InvalidParameter(String),
     /// <p>These errors are usually caused by a server issue.</p>
     Server(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeregisterTaskDefinitionError {
@@ -4045,18 +4156,25 @@ impl DeregisterTaskDefinitionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ClientException" => {
-                    return RusotoError::Service(DeregisterTaskDefinitionError::Client(err.msg))
+                    return RusotoError::service(
+                        DeregisterTaskDefinitionError::Client(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DeregisterTaskDefinitionError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeregisterTaskDefinitionError::InvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "ServerException" => {
-                    return RusotoError::Service(DeregisterTaskDefinitionError::Server(err.msg))
+                    return RusotoError::service(
+                        DeregisterTaskDefinitionError::Server(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeregisterTaskDefinitionError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -4069,12 +4187,14 @@ impl fmt::Display for DeregisterTaskDefinitionError {
             DeregisterTaskDefinitionError::Client(ref cause) => write!(f, "{}", cause),
             DeregisterTaskDefinitionError::InvalidParameter(ref cause) => write!(f, "{}", cause),
             DeregisterTaskDefinitionError::Server(ref cause) => write!(f, "{}", cause),
+            DeregisterTaskDefinitionError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeregisterTaskDefinitionError {}
 /// Errors returned by DescribeCapacityProviders
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeCapacityProvidersError {
     /// <p>These errors are usually caused by a client action, such as using an action or resource on behalf of a user that doesn't have permissions to use the action or resource, or specifying an identifier that is not valid.</p>
     Client(String),
@@ -4082,6 +4202,8 @@ pub enum DescribeCapacityProvidersError {
     InvalidParameter(String),
     /// <p>These errors are usually caused by a server issue.</p>
     Server(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeCapacityProvidersError {
@@ -4089,18 +4211,27 @@ impl DescribeCapacityProvidersError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ClientException" => {
-                    return RusotoError::Service(DescribeCapacityProvidersError::Client(err.msg))
+                    return RusotoError::service(
+                        DescribeCapacityProvidersError::Client(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DescribeCapacityProvidersError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeCapacityProvidersError::InvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "ServerException" => {
-                    return RusotoError::Service(DescribeCapacityProvidersError::Server(err.msg))
+                    return RusotoError::service(
+                        DescribeCapacityProvidersError::Server(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(DescribeCapacityProvidersError::Unhandled, &res)
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -4113,12 +4244,14 @@ impl fmt::Display for DescribeCapacityProvidersError {
             DescribeCapacityProvidersError::Client(ref cause) => write!(f, "{}", cause),
             DescribeCapacityProvidersError::InvalidParameter(ref cause) => write!(f, "{}", cause),
             DescribeCapacityProvidersError::Server(ref cause) => write!(f, "{}", cause),
+            DescribeCapacityProvidersError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeCapacityProvidersError {}
 /// Errors returned by DescribeClusters
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeClustersError {
     /// <p>These errors are usually caused by a client action, such as using an action or resource on behalf of a user that doesn't have permissions to use the action or resource, or specifying an identifier that is not valid.</p>
     Client(String),
@@ -4126,6 +4259,8 @@ pub enum DescribeClustersError {
     InvalidParameter(String),
     /// <p>These errors are usually caused by a server issue.</p>
     Server(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeClustersError {
@@ -4133,16 +4268,19 @@ impl DescribeClustersError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ClientException" => {
-                    return RusotoError::Service(DescribeClustersError::Client(err.msg))
+                    return RusotoError::service(DescribeClustersError::Client(err.msg), &res)
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DescribeClustersError::InvalidParameter(err.msg))
+                    return RusotoError::service(
+                        DescribeClustersError::InvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "ServerException" => {
-                    return RusotoError::Service(DescribeClustersError::Server(err.msg))
+                    return RusotoError::service(DescribeClustersError::Server(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DescribeClustersError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -4155,12 +4293,14 @@ impl fmt::Display for DescribeClustersError {
             DescribeClustersError::Client(ref cause) => write!(f, "{}", cause),
             DescribeClustersError::InvalidParameter(ref cause) => write!(f, "{}", cause),
             DescribeClustersError::Server(ref cause) => write!(f, "{}", cause),
+            DescribeClustersError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeClustersError {}
 /// Errors returned by DescribeContainerInstances
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeContainerInstancesError {
     /// <p>These errors are usually caused by a client action, such as using an action or resource on behalf of a user that doesn't have permissions to use the action or resource, or specifying an identifier that is not valid.</p>
     Client(String),
@@ -4170,6 +4310,8 @@ pub enum DescribeContainerInstancesError {
     InvalidParameter(String),
     /// <p>These errors are usually caused by a server issue.</p>
     Server(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeContainerInstancesError {
@@ -4179,23 +4321,33 @@ impl DescribeContainerInstancesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ClientException" => {
-                    return RusotoError::Service(DescribeContainerInstancesError::Client(err.msg))
+                    return RusotoError::service(
+                        DescribeContainerInstancesError::Client(err.msg),
+                        &res,
+                    )
                 }
                 "ClusterNotFoundException" => {
-                    return RusotoError::Service(DescribeContainerInstancesError::ClusterNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeContainerInstancesError::ClusterNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DescribeContainerInstancesError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeContainerInstancesError::InvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "ServerException" => {
-                    return RusotoError::Service(DescribeContainerInstancesError::Server(err.msg))
+                    return RusotoError::service(
+                        DescribeContainerInstancesError::Server(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(DescribeContainerInstancesError::Unhandled, &res)
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -4209,12 +4361,14 @@ impl fmt::Display for DescribeContainerInstancesError {
             DescribeContainerInstancesError::ClusterNotFound(ref cause) => write!(f, "{}", cause),
             DescribeContainerInstancesError::InvalidParameter(ref cause) => write!(f, "{}", cause),
             DescribeContainerInstancesError::Server(ref cause) => write!(f, "{}", cause),
+            DescribeContainerInstancesError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeContainerInstancesError {}
 /// Errors returned by DescribeServices
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeServicesError {
     /// <p>These errors are usually caused by a client action, such as using an action or resource on behalf of a user that doesn't have permissions to use the action or resource, or specifying an identifier that is not valid.</p>
     Client(String),
@@ -4224,6 +4378,8 @@ pub enum DescribeServicesError {
     InvalidParameter(String),
     /// <p>These errors are usually caused by a server issue.</p>
     Server(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeServicesError {
@@ -4231,19 +4387,25 @@ impl DescribeServicesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ClientException" => {
-                    return RusotoError::Service(DescribeServicesError::Client(err.msg))
+                    return RusotoError::service(DescribeServicesError::Client(err.msg), &res)
                 }
                 "ClusterNotFoundException" => {
-                    return RusotoError::Service(DescribeServicesError::ClusterNotFound(err.msg))
+                    return RusotoError::service(
+                        DescribeServicesError::ClusterNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DescribeServicesError::InvalidParameter(err.msg))
+                    return RusotoError::service(
+                        DescribeServicesError::InvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "ServerException" => {
-                    return RusotoError::Service(DescribeServicesError::Server(err.msg))
+                    return RusotoError::service(DescribeServicesError::Server(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DescribeServicesError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -4257,12 +4419,14 @@ impl fmt::Display for DescribeServicesError {
             DescribeServicesError::ClusterNotFound(ref cause) => write!(f, "{}", cause),
             DescribeServicesError::InvalidParameter(ref cause) => write!(f, "{}", cause),
             DescribeServicesError::Server(ref cause) => write!(f, "{}", cause),
+            DescribeServicesError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeServicesError {}
 /// Errors returned by DescribeTaskDefinition
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeTaskDefinitionError {
     /// <p>These errors are usually caused by a client action, such as using an action or resource on behalf of a user that doesn't have permissions to use the action or resource, or specifying an identifier that is not valid.</p>
     Client(String),
@@ -4270,6 +4434,8 @@ pub enum DescribeTaskDefinitionError {
     InvalidParameter(String),
     /// <p>These errors are usually caused by a server issue.</p>
     Server(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeTaskDefinitionError {
@@ -4277,18 +4443,19 @@ impl DescribeTaskDefinitionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ClientException" => {
-                    return RusotoError::Service(DescribeTaskDefinitionError::Client(err.msg))
+                    return RusotoError::service(DescribeTaskDefinitionError::Client(err.msg), &res)
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DescribeTaskDefinitionError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeTaskDefinitionError::InvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "ServerException" => {
-                    return RusotoError::Service(DescribeTaskDefinitionError::Server(err.msg))
+                    return RusotoError::service(DescribeTaskDefinitionError::Server(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DescribeTaskDefinitionError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -4301,12 +4468,14 @@ impl fmt::Display for DescribeTaskDefinitionError {
             DescribeTaskDefinitionError::Client(ref cause) => write!(f, "{}", cause),
             DescribeTaskDefinitionError::InvalidParameter(ref cause) => write!(f, "{}", cause),
             DescribeTaskDefinitionError::Server(ref cause) => write!(f, "{}", cause),
+            DescribeTaskDefinitionError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeTaskDefinitionError {}
 /// Errors returned by DescribeTaskSets
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeTaskSetsError {
     /// <p>You do not have authorization to perform the requested action.</p>
     AccessDenied(String),
@@ -4324,6 +4493,8 @@ pub enum DescribeTaskSetsError {
     ServiceNotFound(String),
     /// <p>The specified task is not supported in this Region.</p>
     UnsupportedFeature(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeTaskSetsError {
@@ -4331,31 +4502,46 @@ impl DescribeTaskSetsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(DescribeTaskSetsError::AccessDenied(err.msg))
+                    return RusotoError::service(DescribeTaskSetsError::AccessDenied(err.msg), &res)
                 }
                 "ClientException" => {
-                    return RusotoError::Service(DescribeTaskSetsError::Client(err.msg))
+                    return RusotoError::service(DescribeTaskSetsError::Client(err.msg), &res)
                 }
                 "ClusterNotFoundException" => {
-                    return RusotoError::Service(DescribeTaskSetsError::ClusterNotFound(err.msg))
+                    return RusotoError::service(
+                        DescribeTaskSetsError::ClusterNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DescribeTaskSetsError::InvalidParameter(err.msg))
+                    return RusotoError::service(
+                        DescribeTaskSetsError::InvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "ServerException" => {
-                    return RusotoError::Service(DescribeTaskSetsError::Server(err.msg))
+                    return RusotoError::service(DescribeTaskSetsError::Server(err.msg), &res)
                 }
                 "ServiceNotActiveException" => {
-                    return RusotoError::Service(DescribeTaskSetsError::ServiceNotActive(err.msg))
+                    return RusotoError::service(
+                        DescribeTaskSetsError::ServiceNotActive(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceNotFoundException" => {
-                    return RusotoError::Service(DescribeTaskSetsError::ServiceNotFound(err.msg))
+                    return RusotoError::service(
+                        DescribeTaskSetsError::ServiceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "UnsupportedFeatureException" => {
-                    return RusotoError::Service(DescribeTaskSetsError::UnsupportedFeature(err.msg))
+                    return RusotoError::service(
+                        DescribeTaskSetsError::UnsupportedFeature(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DescribeTaskSetsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -4373,12 +4559,14 @@ impl fmt::Display for DescribeTaskSetsError {
             DescribeTaskSetsError::ServiceNotActive(ref cause) => write!(f, "{}", cause),
             DescribeTaskSetsError::ServiceNotFound(ref cause) => write!(f, "{}", cause),
             DescribeTaskSetsError::UnsupportedFeature(ref cause) => write!(f, "{}", cause),
+            DescribeTaskSetsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeTaskSetsError {}
 /// Errors returned by DescribeTasks
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeTasksError {
     /// <p>These errors are usually caused by a client action, such as using an action or resource on behalf of a user that doesn't have permissions to use the action or resource, or specifying an identifier that is not valid.</p>
     Client(String),
@@ -4388,6 +4576,8 @@ pub enum DescribeTasksError {
     InvalidParameter(String),
     /// <p>These errors are usually caused by a server issue.</p>
     Server(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeTasksError {
@@ -4395,19 +4585,22 @@ impl DescribeTasksError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ClientException" => {
-                    return RusotoError::Service(DescribeTasksError::Client(err.msg))
+                    return RusotoError::service(DescribeTasksError::Client(err.msg), &res)
                 }
                 "ClusterNotFoundException" => {
-                    return RusotoError::Service(DescribeTasksError::ClusterNotFound(err.msg))
+                    return RusotoError::service(DescribeTasksError::ClusterNotFound(err.msg), &res)
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DescribeTasksError::InvalidParameter(err.msg))
+                    return RusotoError::service(
+                        DescribeTasksError::InvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "ServerException" => {
-                    return RusotoError::Service(DescribeTasksError::Server(err.msg))
+                    return RusotoError::service(DescribeTasksError::Server(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DescribeTasksError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -4421,17 +4614,21 @@ impl fmt::Display for DescribeTasksError {
             DescribeTasksError::ClusterNotFound(ref cause) => write!(f, "{}", cause),
             DescribeTasksError::InvalidParameter(ref cause) => write!(f, "{}", cause),
             DescribeTasksError::Server(ref cause) => write!(f, "{}", cause),
+            DescribeTasksError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeTasksError {}
 /// Errors returned by DiscoverPollEndpoint
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DiscoverPollEndpointError {
     /// <p>These errors are usually caused by a client action, such as using an action or resource on behalf of a user that doesn't have permissions to use the action or resource, or specifying an identifier that is not valid.</p>
     Client(String),
     /// <p>These errors are usually caused by a server issue.</p>
     Server(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DiscoverPollEndpointError {
@@ -4439,13 +4636,13 @@ impl DiscoverPollEndpointError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ClientException" => {
-                    return RusotoError::Service(DiscoverPollEndpointError::Client(err.msg))
+                    return RusotoError::service(DiscoverPollEndpointError::Client(err.msg), &res)
                 }
                 "ServerException" => {
-                    return RusotoError::Service(DiscoverPollEndpointError::Server(err.msg))
+                    return RusotoError::service(DiscoverPollEndpointError::Server(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DiscoverPollEndpointError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -4457,12 +4654,14 @@ impl fmt::Display for DiscoverPollEndpointError {
         match *self {
             DiscoverPollEndpointError::Client(ref cause) => write!(f, "{}", cause),
             DiscoverPollEndpointError::Server(ref cause) => write!(f, "{}", cause),
+            DiscoverPollEndpointError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DiscoverPollEndpointError {}
 /// Errors returned by ListAccountSettings
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListAccountSettingsError {
     /// <p>These errors are usually caused by a client action, such as using an action or resource on behalf of a user that doesn't have permissions to use the action or resource, or specifying an identifier that is not valid.</p>
     Client(String),
@@ -4470,6 +4669,8 @@ pub enum ListAccountSettingsError {
     InvalidParameter(String),
     /// <p>These errors are usually caused by a server issue.</p>
     Server(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListAccountSettingsError {
@@ -4477,18 +4678,19 @@ impl ListAccountSettingsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ClientException" => {
-                    return RusotoError::Service(ListAccountSettingsError::Client(err.msg))
+                    return RusotoError::service(ListAccountSettingsError::Client(err.msg), &res)
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(ListAccountSettingsError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListAccountSettingsError::InvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "ServerException" => {
-                    return RusotoError::Service(ListAccountSettingsError::Server(err.msg))
+                    return RusotoError::service(ListAccountSettingsError::Server(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListAccountSettingsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -4501,17 +4703,21 @@ impl fmt::Display for ListAccountSettingsError {
             ListAccountSettingsError::Client(ref cause) => write!(f, "{}", cause),
             ListAccountSettingsError::InvalidParameter(ref cause) => write!(f, "{}", cause),
             ListAccountSettingsError::Server(ref cause) => write!(f, "{}", cause),
+            ListAccountSettingsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListAccountSettingsError {}
 /// Errors returned by ListAttributes
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListAttributesError {
     /// <p>The specified cluster could not be found. You can view your available clusters with <a>ListClusters</a>. Amazon ECS clusters are Region-specific.</p>
     ClusterNotFound(String),
     /// <p>The specified parameter is invalid. Review the available parameters for the API request.</p>
     InvalidParameter(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListAttributesError {
@@ -4519,13 +4725,19 @@ impl ListAttributesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ClusterNotFoundException" => {
-                    return RusotoError::Service(ListAttributesError::ClusterNotFound(err.msg))
+                    return RusotoError::service(
+                        ListAttributesError::ClusterNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(ListAttributesError::InvalidParameter(err.msg))
+                    return RusotoError::service(
+                        ListAttributesError::InvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListAttributesError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -4537,12 +4749,14 @@ impl fmt::Display for ListAttributesError {
         match *self {
             ListAttributesError::ClusterNotFound(ref cause) => write!(f, "{}", cause),
             ListAttributesError::InvalidParameter(ref cause) => write!(f, "{}", cause),
+            ListAttributesError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListAttributesError {}
 /// Errors returned by ListClusters
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListClustersError {
     /// <p>These errors are usually caused by a client action, such as using an action or resource on behalf of a user that doesn't have permissions to use the action or resource, or specifying an identifier that is not valid.</p>
     Client(String),
@@ -4550,6 +4764,8 @@ pub enum ListClustersError {
     InvalidParameter(String),
     /// <p>These errors are usually caused by a server issue.</p>
     Server(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListClustersError {
@@ -4557,16 +4773,16 @@ impl ListClustersError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ClientException" => {
-                    return RusotoError::Service(ListClustersError::Client(err.msg))
+                    return RusotoError::service(ListClustersError::Client(err.msg), &res)
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(ListClustersError::InvalidParameter(err.msg))
+                    return RusotoError::service(ListClustersError::InvalidParameter(err.msg), &res)
                 }
                 "ServerException" => {
-                    return RusotoError::Service(ListClustersError::Server(err.msg))
+                    return RusotoError::service(ListClustersError::Server(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListClustersError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -4579,12 +4795,14 @@ impl fmt::Display for ListClustersError {
             ListClustersError::Client(ref cause) => write!(f, "{}", cause),
             ListClustersError::InvalidParameter(ref cause) => write!(f, "{}", cause),
             ListClustersError::Server(ref cause) => write!(f, "{}", cause),
+            ListClustersError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListClustersError {}
 /// Errors returned by ListContainerInstances
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListContainerInstancesError {
     /// <p>These errors are usually caused by a client action, such as using an action or resource on behalf of a user that doesn't have permissions to use the action or resource, or specifying an identifier that is not valid.</p>
     Client(String),
@@ -4594,6 +4812,8 @@ pub enum ListContainerInstancesError {
     InvalidParameter(String),
     /// <p>These errors are usually caused by a server issue.</p>
     Server(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListContainerInstancesError {
@@ -4601,23 +4821,25 @@ impl ListContainerInstancesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ClientException" => {
-                    return RusotoError::Service(ListContainerInstancesError::Client(err.msg))
+                    return RusotoError::service(ListContainerInstancesError::Client(err.msg), &res)
                 }
                 "ClusterNotFoundException" => {
-                    return RusotoError::Service(ListContainerInstancesError::ClusterNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListContainerInstancesError::ClusterNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(ListContainerInstancesError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListContainerInstancesError::InvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "ServerException" => {
-                    return RusotoError::Service(ListContainerInstancesError::Server(err.msg))
+                    return RusotoError::service(ListContainerInstancesError::Server(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListContainerInstancesError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -4631,12 +4853,14 @@ impl fmt::Display for ListContainerInstancesError {
             ListContainerInstancesError::ClusterNotFound(ref cause) => write!(f, "{}", cause),
             ListContainerInstancesError::InvalidParameter(ref cause) => write!(f, "{}", cause),
             ListContainerInstancesError::Server(ref cause) => write!(f, "{}", cause),
+            ListContainerInstancesError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListContainerInstancesError {}
 /// Errors returned by ListServices
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListServicesError {
     /// <p>These errors are usually caused by a client action, such as using an action or resource on behalf of a user that doesn't have permissions to use the action or resource, or specifying an identifier that is not valid.</p>
     Client(String),
@@ -4646,6 +4870,8 @@ pub enum ListServicesError {
     InvalidParameter(String),
     /// <p>These errors are usually caused by a server issue.</p>
     Server(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListServicesError {
@@ -4653,19 +4879,19 @@ impl ListServicesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ClientException" => {
-                    return RusotoError::Service(ListServicesError::Client(err.msg))
+                    return RusotoError::service(ListServicesError::Client(err.msg), &res)
                 }
                 "ClusterNotFoundException" => {
-                    return RusotoError::Service(ListServicesError::ClusterNotFound(err.msg))
+                    return RusotoError::service(ListServicesError::ClusterNotFound(err.msg), &res)
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(ListServicesError::InvalidParameter(err.msg))
+                    return RusotoError::service(ListServicesError::InvalidParameter(err.msg), &res)
                 }
                 "ServerException" => {
-                    return RusotoError::Service(ListServicesError::Server(err.msg))
+                    return RusotoError::service(ListServicesError::Server(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListServicesError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -4679,12 +4905,14 @@ impl fmt::Display for ListServicesError {
             ListServicesError::ClusterNotFound(ref cause) => write!(f, "{}", cause),
             ListServicesError::InvalidParameter(ref cause) => write!(f, "{}", cause),
             ListServicesError::Server(ref cause) => write!(f, "{}", cause),
+            ListServicesError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListServicesError {}
 /// Errors returned by ListTagsForResource
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListTagsForResourceError {
     /// <p>These errors are usually caused by a client action, such as using an action or resource on behalf of a user that doesn't have permissions to use the action or resource, or specifying an identifier that is not valid.</p>
     Client(String),
@@ -4694,6 +4922,8 @@ pub enum ListTagsForResourceError {
     InvalidParameter(String),
     /// <p>These errors are usually caused by a server issue.</p>
     Server(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListTagsForResourceError {
@@ -4701,21 +4931,25 @@ impl ListTagsForResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ClientException" => {
-                    return RusotoError::Service(ListTagsForResourceError::Client(err.msg))
+                    return RusotoError::service(ListTagsForResourceError::Client(err.msg), &res)
                 }
                 "ClusterNotFoundException" => {
-                    return RusotoError::Service(ListTagsForResourceError::ClusterNotFound(err.msg))
+                    return RusotoError::service(
+                        ListTagsForResourceError::ClusterNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(ListTagsForResourceError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListTagsForResourceError::InvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "ServerException" => {
-                    return RusotoError::Service(ListTagsForResourceError::Server(err.msg))
+                    return RusotoError::service(ListTagsForResourceError::Server(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListTagsForResourceError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -4729,12 +4963,14 @@ impl fmt::Display for ListTagsForResourceError {
             ListTagsForResourceError::ClusterNotFound(ref cause) => write!(f, "{}", cause),
             ListTagsForResourceError::InvalidParameter(ref cause) => write!(f, "{}", cause),
             ListTagsForResourceError::Server(ref cause) => write!(f, "{}", cause),
+            ListTagsForResourceError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListTagsForResourceError {}
 /// Errors returned by ListTaskDefinitionFamilies
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListTaskDefinitionFamiliesError {
     /// <p>These errors are usually caused by a client action, such as using an action or resource on behalf of a user that doesn't have permissions to use the action or resource, or specifying an identifier that is not valid.</p>
     Client(String),
@@ -4742,6 +4978,8 @@ pub enum ListTaskDefinitionFamiliesError {
     InvalidParameter(String),
     /// <p>These errors are usually caused by a server issue.</p>
     Server(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListTaskDefinitionFamiliesError {
@@ -4751,18 +4989,27 @@ impl ListTaskDefinitionFamiliesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ClientException" => {
-                    return RusotoError::Service(ListTaskDefinitionFamiliesError::Client(err.msg))
+                    return RusotoError::service(
+                        ListTaskDefinitionFamiliesError::Client(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(ListTaskDefinitionFamiliesError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListTaskDefinitionFamiliesError::InvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "ServerException" => {
-                    return RusotoError::Service(ListTaskDefinitionFamiliesError::Server(err.msg))
+                    return RusotoError::service(
+                        ListTaskDefinitionFamiliesError::Server(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(ListTaskDefinitionFamiliesError::Unhandled, &res)
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -4775,12 +5022,14 @@ impl fmt::Display for ListTaskDefinitionFamiliesError {
             ListTaskDefinitionFamiliesError::Client(ref cause) => write!(f, "{}", cause),
             ListTaskDefinitionFamiliesError::InvalidParameter(ref cause) => write!(f, "{}", cause),
             ListTaskDefinitionFamiliesError::Server(ref cause) => write!(f, "{}", cause),
+            ListTaskDefinitionFamiliesError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListTaskDefinitionFamiliesError {}
 /// Errors returned by ListTaskDefinitions
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListTaskDefinitionsError {
     /// <p>These errors are usually caused by a client action, such as using an action or resource on behalf of a user that doesn't have permissions to use the action or resource, or specifying an identifier that is not valid.</p>
     Client(String),
@@ -4788,6 +5037,8 @@ pub enum ListTaskDefinitionsError {
     InvalidParameter(String),
     /// <p>These errors are usually caused by a server issue.</p>
     Server(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListTaskDefinitionsError {
@@ -4795,18 +5046,19 @@ impl ListTaskDefinitionsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ClientException" => {
-                    return RusotoError::Service(ListTaskDefinitionsError::Client(err.msg))
+                    return RusotoError::service(ListTaskDefinitionsError::Client(err.msg), &res)
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(ListTaskDefinitionsError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListTaskDefinitionsError::InvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "ServerException" => {
-                    return RusotoError::Service(ListTaskDefinitionsError::Server(err.msg))
+                    return RusotoError::service(ListTaskDefinitionsError::Server(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListTaskDefinitionsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -4819,12 +5071,14 @@ impl fmt::Display for ListTaskDefinitionsError {
             ListTaskDefinitionsError::Client(ref cause) => write!(f, "{}", cause),
             ListTaskDefinitionsError::InvalidParameter(ref cause) => write!(f, "{}", cause),
             ListTaskDefinitionsError::Server(ref cause) => write!(f, "{}", cause),
+            ListTaskDefinitionsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListTaskDefinitionsError {}
 /// Errors returned by ListTasks
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListTasksError {
     /// <p>These errors are usually caused by a client action, such as using an action or resource on behalf of a user that doesn't have permissions to use the action or resource, or specifying an identifier that is not valid.</p>
     Client(String),
@@ -4836,25 +5090,31 @@ pub enum ListTasksError {
     Server(String),
     /// <p>The specified service could not be found. You can view your available services with <a>ListServices</a>. Amazon ECS services are cluster-specific and Region-specific.</p>
     ServiceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListTasksError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<ListTasksError> {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
-                "ClientException" => return RusotoError::Service(ListTasksError::Client(err.msg)),
+                "ClientException" => {
+                    return RusotoError::service(ListTasksError::Client(err.msg), &res)
+                }
                 "ClusterNotFoundException" => {
-                    return RusotoError::Service(ListTasksError::ClusterNotFound(err.msg))
+                    return RusotoError::service(ListTasksError::ClusterNotFound(err.msg), &res)
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(ListTasksError::InvalidParameter(err.msg))
+                    return RusotoError::service(ListTasksError::InvalidParameter(err.msg), &res)
+                }
+                "ServerException" => {
+                    return RusotoError::service(ListTasksError::Server(err.msg), &res)
                 }
-                "ServerException" => return RusotoError::Service(ListTasksError::Server(err.msg)),
                 "ServiceNotFoundException" => {
-                    return RusotoError::Service(ListTasksError::ServiceNotFound(err.msg))
+                    return RusotoError::service(ListTasksError::ServiceNotFound(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListTasksError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -4869,12 +5129,14 @@ impl fmt::Display for ListTasksError {
             ListTasksError::InvalidParameter(ref cause) => write!(f, "{}", cause),
             ListTasksError::Server(ref cause) => write!(f, "{}", cause),
             ListTasksError::ServiceNotFound(ref cause) => write!(f, "{}", cause),
+            ListTasksError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListTasksError {}
 /// Errors returned by PutAccountSetting
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum PutAccountSettingError {
     /// <p>These errors are usually caused by a client action, such as using an action or resource on behalf of a user that doesn't have permissions to use the action or resource, or specifying an identifier that is not valid.</p>
     Client(String),
@@ -4882,6 +5144,8 @@ pub enum PutAccountSettingError {
     InvalidParameter(String),
     /// <p>These errors are usually caused by a server issue.</p>
     Server(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl PutAccountSettingError {
@@ -4889,16 +5153,19 @@ impl PutAccountSettingError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ClientException" => {
-                    return RusotoError::Service(PutAccountSettingError::Client(err.msg))
+                    return RusotoError::service(PutAccountSettingError::Client(err.msg), &res)
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(PutAccountSettingError::InvalidParameter(err.msg))
+                    return RusotoError::service(
+                        PutAccountSettingError::InvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "ServerException" => {
-                    return RusotoError::Service(PutAccountSettingError::Server(err.msg))
+                    return RusotoError::service(PutAccountSettingError::Server(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(PutAccountSettingError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -4911,12 +5178,14 @@ impl fmt::Display for PutAccountSettingError {
             PutAccountSettingError::Client(ref cause) => write!(f, "{}", cause),
             PutAccountSettingError::InvalidParameter(ref cause) => write!(f, "{}", cause),
             PutAccountSettingError::Server(ref cause) => write!(f, "{}", cause),
+            PutAccountSettingError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for PutAccountSettingError {}
 /// Errors returned by PutAccountSettingDefault
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum PutAccountSettingDefaultError {
     /// <p>These errors are usually caused by a client action, such as using an action or resource on behalf of a user that doesn't have permissions to use the action or resource, or specifying an identifier that is not valid.</p>
     Client(String),
@@ -4924,6 +5193,8 @@ pub enum PutAccountSettingDefaultError {
     InvalidParameter(String),
     /// <p>These errors are usually caused by a server issue.</p>
     Server(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl PutAccountSettingDefaultError {
@@ -4931,18 +5202,25 @@ impl PutAccountSettingDefaultError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ClientException" => {
-                    return RusotoError::Service(PutAccountSettingDefaultError::Client(err.msg))
+                    return RusotoError::service(
+                        PutAccountSettingDefaultError::Client(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(PutAccountSettingDefaultError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        PutAccountSettingDefaultError::InvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "ServerException" => {
-                    return RusotoError::Service(PutAccountSettingDefaultError::Server(err.msg))
+                    return RusotoError::service(
+                        PutAccountSettingDefaultError::Server(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(PutAccountSettingDefaultError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -4955,12 +5233,14 @@ impl fmt::Display for PutAccountSettingDefaultError {
             PutAccountSettingDefaultError::Client(ref cause) => write!(f, "{}", cause),
             PutAccountSettingDefaultError::InvalidParameter(ref cause) => write!(f, "{}", cause),
             PutAccountSettingDefaultError::Server(ref cause) => write!(f, "{}", cause),
+            PutAccountSettingDefaultError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for PutAccountSettingDefaultError {}
 /// Errors returned by PutAttributes
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum PutAttributesError {
     /// <p>You can apply up to 10 custom attributes per resource. You can view the attributes of a resource with <a>ListAttributes</a>. You can remove existing attributes on a resource with <a>DeleteAttributes</a>.</p>
     AttributeLimitExceeded(String),
@@ -4970,6 +5250,8 @@ pub enum PutAttributesError {
     InvalidParameter(String),
     /// <p>The specified target could not be found. You can view your available container instances with <a>ListContainerInstances</a>. Amazon ECS container instances are cluster-specific and Region-specific.</p>
     TargetNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl PutAttributesError {
@@ -4977,21 +5259,25 @@ impl PutAttributesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AttributeLimitExceededException" => {
-                    return RusotoError::Service(PutAttributesError::AttributeLimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        PutAttributesError::AttributeLimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "ClusterNotFoundException" => {
-                    return RusotoError::Service(PutAttributesError::ClusterNotFound(err.msg))
+                    return RusotoError::service(PutAttributesError::ClusterNotFound(err.msg), &res)
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(PutAttributesError::InvalidParameter(err.msg))
+                    return RusotoError::service(
+                        PutAttributesError::InvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "TargetNotFoundException" => {
-                    return RusotoError::Service(PutAttributesError::TargetNotFound(err.msg))
+                    return RusotoError::service(PutAttributesError::TargetNotFound(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(PutAttributesError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -5005,12 +5291,14 @@ impl fmt::Display for PutAttributesError {
             PutAttributesError::ClusterNotFound(ref cause) => write!(f, "{}", cause),
             PutAttributesError::InvalidParameter(ref cause) => write!(f, "{}", cause),
             PutAttributesError::TargetNotFound(ref cause) => write!(f, "{}", cause),
+            PutAttributesError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for PutAttributesError {}
 /// Errors returned by PutClusterCapacityProviders
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum PutClusterCapacityProvidersError {
     /// <p>These errors are usually caused by a client action, such as using an action or resource on behalf of a user that doesn't have permissions to use the action or resource, or specifying an identifier that is not valid.</p>
     Client(String),
@@ -5024,6 +5312,8 @@ pub enum PutClusterCapacityProvidersError {
     Server(String),
     /// <p>There is already a current Amazon ECS container agent update in progress on the specified container instance. If the container agent becomes disconnected while it is in a transitional stage, such as <code>PENDING</code> or <code>STAGING</code>, the update process can get stuck in that state. However, when the agent reconnects, it resumes where it stopped previously.</p>
     UpdateInProgress(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl PutClusterCapacityProvidersError {
@@ -5033,33 +5323,48 @@ impl PutClusterCapacityProvidersError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ClientException" => {
-                    return RusotoError::Service(PutClusterCapacityProvidersError::Client(err.msg))
+                    return RusotoError::service(
+                        PutClusterCapacityProvidersError::Client(err.msg),
+                        &res,
+                    )
                 }
                 "ClusterNotFoundException" => {
-                    return RusotoError::Service(PutClusterCapacityProvidersError::ClusterNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        PutClusterCapacityProvidersError::ClusterNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         PutClusterCapacityProvidersError::InvalidParameter(err.msg),
+                        &res,
                     )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(PutClusterCapacityProvidersError::ResourceInUse(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        PutClusterCapacityProvidersError::ResourceInUse(err.msg),
+                        &res,
+                    )
                 }
                 "ServerException" => {
-                    return RusotoError::Service(PutClusterCapacityProvidersError::Server(err.msg))
+                    return RusotoError::service(
+                        PutClusterCapacityProvidersError::Server(err.msg),
+                        &res,
+                    )
                 }
                 "UpdateInProgressException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         PutClusterCapacityProvidersError::UpdateInProgress(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        PutClusterCapacityProvidersError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -5075,12 +5380,14 @@ impl fmt::Display for PutClusterCapacityProvidersError {
             PutClusterCapacityProvidersError::ResourceInUse(ref cause) => write!(f, "{}", cause),
             PutClusterCapacityProvidersError::Server(ref cause) => write!(f, "{}", cause),
             PutClusterCapacityProvidersError::UpdateInProgress(ref cause) => write!(f, "{}", cause),
+            PutClusterCapacityProvidersError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for PutClusterCapacityProvidersError {}
 /// Errors returned by RegisterContainerInstance
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum RegisterContainerInstanceError {
     /// <p>These errors are usually caused by a client action, such as using an action or resource on behalf of a user that doesn't have permissions to use the action or resource, or specifying an identifier that is not valid.</p>
     Client(String),
@@ -5088,6 +5395,8 @@ pub enum RegisterContainerInstanceError {
     InvalidParameter(String),
     /// <p>These errors are usually caused by a server issue.</p>
     Server(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl RegisterContainerInstanceError {
@@ -5095,18 +5404,27 @@ impl RegisterContainerInstanceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ClientException" => {
-                    return RusotoError::Service(RegisterContainerInstanceError::Client(err.msg))
+                    return RusotoError::service(
+                        RegisterContainerInstanceError::Client(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(RegisterContainerInstanceError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        RegisterContainerInstanceError::InvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "ServerException" => {
-                    return RusotoError::Service(RegisterContainerInstanceError::Server(err.msg))
+                    return RusotoError::service(
+                        RegisterContainerInstanceError::Server(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(RegisterContainerInstanceError::Unhandled, &res)
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -5119,12 +5437,14 @@ impl fmt::Display for RegisterContainerInstanceError {
             RegisterContainerInstanceError::Client(ref cause) => write!(f, "{}", cause),
             RegisterContainerInstanceError::InvalidParameter(ref cause) => write!(f, "{}", cause),
             RegisterContainerInstanceError::Server(ref cause) => write!(f, "{}", cause),
+            RegisterContainerInstanceError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for RegisterContainerInstanceError {}
 /// Errors returned by RegisterTaskDefinition
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum RegisterTaskDefinitionError {
     /// <p>These errors are usually caused by a client action, such as using an action or resource on behalf of a user that doesn't have permissions to use the action or resource, or specifying an identifier that is not valid.</p>
     Client(String),
@@ -5132,6 +5452,8 @@ pub enum RegisterTaskDefinitionError {
     InvalidParameter(String),
     /// <p>These errors are usually caused by a server issue.</p>
     Server(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl RegisterTaskDefinitionError {
@@ -5139,18 +5461,19 @@ impl RegisterTaskDefinitionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ClientException" => {
-                    return RusotoError::Service(RegisterTaskDefinitionError::Client(err.msg))
+                    return RusotoError::service(RegisterTaskDefinitionError::Client(err.msg), &res)
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(RegisterTaskDefinitionError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        RegisterTaskDefinitionError::InvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "ServerException" => {
-                    return RusotoError::Service(RegisterTaskDefinitionError::Server(err.msg))
+                    return RusotoError::service(RegisterTaskDefinitionError::Server(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(RegisterTaskDefinitionError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -5163,12 +5486,14 @@ impl fmt::Display for RegisterTaskDefinitionError {
             RegisterTaskDefinitionError::Client(ref cause) => write!(f, "{}", cause),
             RegisterTaskDefinitionError::InvalidParameter(ref cause) => write!(f, "{}", cause),
             RegisterTaskDefinitionError::Server(ref cause) => write!(f, "{}", cause),
+            RegisterTaskDefinitionError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for RegisterTaskDefinitionError {}
 /// Errors returned by RunTask
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum RunTaskError {
     /// <p>You do not have authorization to perform the requested action.</p>
     AccessDenied(String),
@@ -5188,6 +5513,8 @@ pub enum RunTaskError {
     Server(String),
     /// <p>The specified task is not supported in this Region.</p>
     UnsupportedFeature(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl RunTaskError {
@@ -5195,30 +5522,37 @@ impl RunTaskError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(RunTaskError::AccessDenied(err.msg))
+                    return RusotoError::service(RunTaskError::AccessDenied(err.msg), &res)
+                }
+                "BlockedException" => {
+                    return RusotoError::service(RunTaskError::Blocked(err.msg), &res)
+                }
+                "ClientException" => {
+                    return RusotoError::service(RunTaskError::Client(err.msg), &res)
                 }
-                "BlockedException" => return RusotoError::Service(RunTaskError::Blocked(err.msg)),
-                "ClientException" => return RusotoError::Service(RunTaskError::Client(err.msg)),
                 "ClusterNotFoundException" => {
-                    return RusotoError::Service(RunTaskError::ClusterNotFound(err.msg))
+                    return RusotoError::service(RunTaskError::ClusterNotFound(err.msg), &res)
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(RunTaskError::InvalidParameter(err.msg))
+                    return RusotoError::service(RunTaskError::InvalidParameter(err.msg), &res)
                 }
                 "PlatformTaskDefinitionIncompatibilityException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         RunTaskError::PlatformTaskDefinitionIncompatibility(err.msg),
+                        &res,
                     )
                 }
                 "PlatformUnknownException" => {
-                    return RusotoError::Service(RunTaskError::PlatformUnknown(err.msg))
+                    return RusotoError::service(RunTaskError::PlatformUnknown(err.msg), &res)
+                }
+                "ServerException" => {
+                    return RusotoError::service(RunTaskError::Server(err.msg), &res)
                 }
-                "ServerException" => return RusotoError::Service(RunTaskError::Server(err.msg)),
                 "UnsupportedFeatureException" => {
-                    return RusotoError::Service(RunTaskError::UnsupportedFeature(err.msg))
+                    return RusotoError::service(RunTaskError::UnsupportedFeature(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(RunTaskError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -5239,12 +5573,14 @@ impl fmt::Display for RunTaskError {
             RunTaskError::PlatformUnknown(ref cause) => write!(f, "{}", cause),
             RunTaskError::Server(ref cause) => write!(f, "{}", cause),
             RunTaskError::UnsupportedFeature(ref cause) => write!(f, "{}", cause),
+            RunTaskError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for RunTaskError {}
 /// Errors returned by StartTask
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum StartTaskError {
     /// <p>These errors are usually caused by a client action, such as using an action or resource on behalf of a user that doesn't have permissions to use the action or resource, or specifying an identifier that is not valid.</p>
     Client(String),
@@ -5254,22 +5590,28 @@ pub enum StartTaskError {
     InvalidParameter(String),
     /// <p>These errors are usually caused by a server issue.</p>
     Server(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl StartTaskError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<StartTaskError> {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
-                "ClientException" => return RusotoError::Service(StartTaskError::Client(err.msg)),
+                "ClientException" => {
+                    return RusotoError::service(StartTaskError::Client(err.msg), &res)
+                }
                 "ClusterNotFoundException" => {
-                    return RusotoError::Service(StartTaskError::ClusterNotFound(err.msg))
+                    return RusotoError::service(StartTaskError::ClusterNotFound(err.msg), &res)
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(StartTaskError::InvalidParameter(err.msg))
+                    return RusotoError::service(StartTaskError::InvalidParameter(err.msg), &res)
+                }
+                "ServerException" => {
+                    return RusotoError::service(StartTaskError::Server(err.msg), &res)
                 }
-                "ServerException" => return RusotoError::Service(StartTaskError::Server(err.msg)),
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(StartTaskError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -5283,12 +5625,14 @@ impl fmt::Display for StartTaskError {
             StartTaskError::ClusterNotFound(ref cause) => write!(f, "{}", cause),
             StartTaskError::InvalidParameter(ref cause) => write!(f, "{}", cause),
             StartTaskError::Server(ref cause) => write!(f, "{}", cause),
+            StartTaskError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for StartTaskError {}
 /// Errors returned by StopTask
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum StopTaskError {
     /// <p>These errors are usually caused by a client action, such as using an action or resource on behalf of a user that doesn't have permissions to use the action or resource, or specifying an identifier that is not valid.</p>
     Client(String),
@@ -5298,22 +5642,28 @@ pub enum StopTaskError {
     InvalidParameter(String),
     /// <p>These errors are usually caused by a server issue.</p>
     Server(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl StopTaskError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<StopTaskError> {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
-                "ClientException" => return RusotoError::Service(StopTaskError::Client(err.msg)),
+                "ClientException" => {
+                    return RusotoError::service(StopTaskError::Client(err.msg), &res)
+                }
                 "ClusterNotFoundException" => {
-                    return RusotoError::Service(StopTaskError::ClusterNotFound(err.msg))
+                    return RusotoError::service(StopTaskError::ClusterNotFound(err.msg), &res)
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(StopTaskError::InvalidParameter(err.msg))
+                    return RusotoError::service(StopTaskError::InvalidParameter(err.msg), &res)
+                }
+                "ServerException" => {
+                    return RusotoError::service(StopTaskError::Server(err.msg), &res)
                 }
-                "ServerException" => return RusotoError::Service(StopTaskError::Server(err.msg)),
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(StopTaskError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -5327,12 +5677,14 @@ impl fmt::Display for StopTaskError {
             StopTaskError::ClusterNotFound(ref cause) => write!(f, "{}", cause),
             StopTaskError::InvalidParameter(ref cause) => write!(f, "{}", cause),
             StopTaskError::Server(ref cause) => write!(f, "{}", cause),
+            StopTaskError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for StopTaskError {}
 /// Errors returned by SubmitAttachmentStateChanges
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum SubmitAttachmentStateChangesError {
     /// <p>You do not have authorization to perform the requested action.</p>
     AccessDenied(String),
@@ -5342,6 +5694,8 @@ pub enum SubmitAttachmentStateChangesError {
     InvalidParameter(String),
     /// <p>These errors are usually caused by a server issue.</p>
     Server(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl SubmitAttachmentStateChangesError {
@@ -5351,23 +5705,36 @@ impl SubmitAttachmentStateChangesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(SubmitAttachmentStateChangesError::AccessDenied(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        SubmitAttachmentStateChangesError::AccessDenied(err.msg),
+                        &res,
+                    )
                 }
                 "ClientException" => {
-                    return RusotoError::Service(SubmitAttachmentStateChangesError::Client(err.msg))
+                    return RusotoError::service(
+                        SubmitAttachmentStateChangesError::Client(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         SubmitAttachmentStateChangesError::InvalidParameter(err.msg),
+                        &res,
                     )
                 }
                 "ServerException" => {
-                    return RusotoError::Service(SubmitAttachmentStateChangesError::Server(err.msg))
+                    return RusotoError::service(
+                        SubmitAttachmentStateChangesError::Server(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        SubmitAttachmentStateChangesError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -5383,12 +5750,14 @@ impl fmt::Display for SubmitAttachmentStateChangesError {
                 write!(f, "{}", cause)
             }
             SubmitAttachmentStateChangesError::Server(ref cause) => write!(f, "{}", cause),
+            SubmitAttachmentStateChangesError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for SubmitAttachmentStateChangesError {}
 /// Errors returned by SubmitContainerStateChange
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum SubmitContainerStateChangeError {
     /// <p>You do not have authorization to perform the requested action.</p>
     AccessDenied(String),
@@ -5396,6 +5765,8 @@ pub enum SubmitContainerStateChangeError {
     Client(String),
     /// <p>These errors are usually caused by a server issue.</p>
     Server(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl SubmitContainerStateChangeError {
@@ -5405,18 +5776,27 @@ impl SubmitContainerStateChangeError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(SubmitContainerStateChangeError::AccessDenied(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        SubmitContainerStateChangeError::AccessDenied(err.msg),
+                        &res,
+                    )
                 }
                 "ClientException" => {
-                    return RusotoError::Service(SubmitContainerStateChangeError::Client(err.msg))
+                    return RusotoError::service(
+                        SubmitContainerStateChangeError::Client(err.msg),
+                        &res,
+                    )
                 }
                 "ServerException" => {
-                    return RusotoError::Service(SubmitContainerStateChangeError::Server(err.msg))
+                    return RusotoError::service(
+                        SubmitContainerStateChangeError::Server(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(SubmitContainerStateChangeError::Unhandled, &res)
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -5429,12 +5809,14 @@ impl fmt::Display for SubmitContainerStateChangeError {
             SubmitContainerStateChangeError::AccessDenied(ref cause) => write!(f, "{}", cause),
             SubmitContainerStateChangeError::Client(ref cause) => write!(f, "{}", cause),
             SubmitContainerStateChangeError::Server(ref cause) => write!(f, "{}", cause),
+            SubmitContainerStateChangeError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for SubmitContainerStateChangeError {}
 /// Errors returned by SubmitTaskStateChange
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum SubmitTaskStateChangeError {
     /// <p>You do not have authorization to perform the requested action.</p>
     AccessDenied(String),
@@ -5444,6 +5826,8 @@ pub enum SubmitTaskStateChangeError {
     InvalidParameter(String),
     /// <p>These errors are usually caused by a server issue.</p>
     Server(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl SubmitTaskStateChangeError {
@@ -5451,21 +5835,25 @@ impl SubmitTaskStateChangeError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(SubmitTaskStateChangeError::AccessDenied(err.msg))
+                    return RusotoError::service(
+                        SubmitTaskStateChangeError::AccessDenied(err.msg),
+                        &res,
+                    )
                 }
                 "ClientException" => {
-                    return RusotoError::Service(SubmitTaskStateChangeError::Client(err.msg))
+                    return RusotoError::service(SubmitTaskStateChangeError::Client(err.msg), &res)
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(SubmitTaskStateChangeError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        SubmitTaskStateChangeError::InvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "ServerException" => {
-                    return RusotoError::Service(SubmitTaskStateChangeError::Server(err.msg))
+                    return RusotoError::service(SubmitTaskStateChangeError::Server(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(SubmitTaskStateChangeError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -5479,12 +5867,14 @@ impl fmt::Display for SubmitTaskStateChangeError {
             SubmitTaskStateChangeError::Client(ref cause) => write!(f, "{}", cause),
             SubmitTaskStateChangeError::InvalidParameter(ref cause) => write!(f, "{}", cause),
             SubmitTaskStateChangeError::Server(ref cause) => write!(f, "{}", cause),
+            SubmitTaskStateChangeError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for SubmitTaskStateChangeError {}
 /// Errors returned by TagResource
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum TagResourceError {
     /// <p>These errors are usually caused by a client action, such as using an action or resource on behalf of a user that doesn't have permissions to use the action or resource, or specifying an identifier that is not valid.</p>
     Client(String),
@@ -5496,6 +5886,8 @@ pub enum TagResourceError {
     ResourceNotFound(String),
     /// <p>These errors are usually caused by a server issue.</p>
     Server(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl TagResourceError {
@@ -5503,22 +5895,22 @@ impl TagResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ClientException" => {
-                    return RusotoError::Service(TagResourceError::Client(err.msg))
+                    return RusotoError::service(TagResourceError::Client(err.msg), &res)
                 }
                 "ClusterNotFoundException" => {
-                    return RusotoError::Service(TagResourceError::ClusterNotFound(err.msg))
+                    return RusotoError::service(TagResourceError::ClusterNotFound(err.msg), &res)
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(TagResourceError::InvalidParameter(err.msg))
+                    return RusotoError::service(TagResourceError::InvalidParameter(err.msg), &res)
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(TagResourceError::ResourceNotFound(err.msg))
+                    return RusotoError::service(TagResourceError::ResourceNotFound(err.msg), &res)
                 }
                 "ServerException" => {
-                    return RusotoError::Service(TagResourceError::Server(err.msg))
+                    return RusotoError::service(TagResourceError::Server(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(TagResourceError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -5533,12 +5925,14 @@ impl fmt::Display for TagResourceError {
             TagResourceError::InvalidParameter(ref cause) => write!(f, "{}", cause),
             TagResourceError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             TagResourceError::Server(ref cause) => write!(f, "{}", cause),
+            TagResourceError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for TagResourceError {}
 /// Errors returned by UntagResource
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UntagResourceError {
     /// <p>These errors are usually caused by a client action, such as using an action or resource on behalf of a user that doesn't have permissions to use the action or resource, or specifying an identifier that is not valid.</p>
     Client(String),
@@ -5550,6 +5944,8 @@ pub enum UntagResourceError {
     ResourceNotFound(String),
     /// <p>These errors are usually caused by a server issue.</p>
     Server(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UntagResourceError {
@@ -5557,22 +5953,28 @@ impl UntagResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ClientException" => {
-                    return RusotoError::Service(UntagResourceError::Client(err.msg))
+                    return RusotoError::service(UntagResourceError::Client(err.msg), &res)
                 }
                 "ClusterNotFoundException" => {
-                    return RusotoError::Service(UntagResourceError::ClusterNotFound(err.msg))
+                    return RusotoError::service(UntagResourceError::ClusterNotFound(err.msg), &res)
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(UntagResourceError::InvalidParameter(err.msg))
+                    return RusotoError::service(
+                        UntagResourceError::InvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UntagResourceError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        UntagResourceError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ServerException" => {
-                    return RusotoError::Service(UntagResourceError::Server(err.msg))
+                    return RusotoError::service(UntagResourceError::Server(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UntagResourceError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -5587,12 +5989,14 @@ impl fmt::Display for UntagResourceError {
             UntagResourceError::InvalidParameter(ref cause) => write!(f, "{}", cause),
             UntagResourceError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             UntagResourceError::Server(ref cause) => write!(f, "{}", cause),
+            UntagResourceError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for UntagResourceError {}
 /// Errors returned by UpdateClusterSettings
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdateClusterSettingsError {
     /// <p>These errors are usually caused by a client action, such as using an action or resource on behalf of a user that doesn't have permissions to use the action or resource, or specifying an identifier that is not valid.</p>
     Client(String),
@@ -5602,6 +6006,8 @@ pub enum UpdateClusterSettingsError {
     InvalidParameter(String),
     /// <p>These errors are usually caused by a server issue.</p>
     Server(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdateClusterSettingsError {
@@ -5609,23 +6015,25 @@ impl UpdateClusterSettingsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ClientException" => {
-                    return RusotoError::Service(UpdateClusterSettingsError::Client(err.msg))
+                    return RusotoError::service(UpdateClusterSettingsError::Client(err.msg), &res)
                 }
                 "ClusterNotFoundException" => {
-                    return RusotoError::Service(UpdateClusterSettingsError::ClusterNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateClusterSettingsError::ClusterNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(UpdateClusterSettingsError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateClusterSettingsError::InvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "ServerException" => {
-                    return RusotoError::Service(UpdateClusterSettingsError::Server(err.msg))
+                    return RusotoError::service(UpdateClusterSettingsError::Server(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UpdateClusterSettingsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -5639,12 +6047,14 @@ impl fmt::Display for UpdateClusterSettingsError {
             UpdateClusterSettingsError::ClusterNotFound(ref cause) => write!(f, "{}", cause),
             UpdateClusterSettingsError::InvalidParameter(ref cause) => write!(f, "{}", cause),
             UpdateClusterSettingsError::Server(ref cause) => write!(f, "{}", cause),
+            UpdateClusterSettingsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for UpdateClusterSettingsError {}
 /// Errors returned by UpdateContainerAgent
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdateContainerAgentError {
     /// <p>These errors are usually caused by a client action, such as using an action or resource on behalf of a user that doesn't have permissions to use the action or resource, or specifying an identifier that is not valid.</p>
     Client(String),
@@ -5660,6 +6070,8 @@ pub enum UpdateContainerAgentError {
     Server(String),
     /// <p>There is already a current Amazon ECS container agent update in progress on the specified container instance. If the container agent becomes disconnected while it is in a transitional stage, such as <code>PENDING</code> or <code>STAGING</code>, the update process can get stuck in that state. However, when the agent reconnects, it resumes where it stopped previously.</p>
     UpdateInProgress(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdateContainerAgentError {
@@ -5667,36 +6079,43 @@ impl UpdateContainerAgentError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ClientException" => {
-                    return RusotoError::Service(UpdateContainerAgentError::Client(err.msg))
+                    return RusotoError::service(UpdateContainerAgentError::Client(err.msg), &res)
                 }
                 "ClusterNotFoundException" => {
-                    return RusotoError::Service(UpdateContainerAgentError::ClusterNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateContainerAgentError::ClusterNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(UpdateContainerAgentError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateContainerAgentError::InvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "MissingVersionException" => {
-                    return RusotoError::Service(UpdateContainerAgentError::MissingVersion(err.msg))
+                    return RusotoError::service(
+                        UpdateContainerAgentError::MissingVersion(err.msg),
+                        &res,
+                    )
                 }
                 "NoUpdateAvailableException" => {
-                    return RusotoError::Service(UpdateContainerAgentError::NoUpdateAvailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateContainerAgentError::NoUpdateAvailable(err.msg),
+                        &res,
+                    )
                 }
                 "ServerException" => {
-                    return RusotoError::Service(UpdateContainerAgentError::Server(err.msg))
+                    return RusotoError::service(UpdateContainerAgentError::Server(err.msg), &res)
                 }
                 "UpdateInProgressException" => {
-                    return RusotoError::Service(UpdateContainerAgentError::UpdateInProgress(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateContainerAgentError::UpdateInProgress(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UpdateContainerAgentError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -5713,12 +6132,14 @@ impl fmt::Display for UpdateContainerAgentError {
             UpdateContainerAgentError::NoUpdateAvailable(ref cause) => write!(f, "{}", cause),
             UpdateContainerAgentError::Server(ref cause) => write!(f, "{}", cause),
             UpdateContainerAgentError::UpdateInProgress(ref cause) => write!(f, "{}", cause),
+            UpdateContainerAgentError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for UpdateContainerAgentError {}
 /// Errors returned by UpdateContainerInstancesState
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdateContainerInstancesStateError {
     /// <p>These errors are usually caused by a client action, such as using an action or resource on behalf of a user that doesn't have permissions to use the action or resource, or specifying an identifier that is not valid.</p>
     Client(String),
@@ -5728,6 +6149,8 @@ pub enum UpdateContainerInstancesStateError {
     InvalidParameter(String),
     /// <p>These errors are usually caused by a server issue.</p>
     Server(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdateContainerInstancesStateError {
@@ -5737,27 +6160,36 @@ impl UpdateContainerInstancesStateError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ClientException" => {
-                    return RusotoError::Service(UpdateContainerInstancesStateError::Client(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateContainerInstancesStateError::Client(err.msg),
+                        &res,
+                    )
                 }
                 "ClusterNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         UpdateContainerInstancesStateError::ClusterNotFound(err.msg),
+                        &res,
                     )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         UpdateContainerInstancesStateError::InvalidParameter(err.msg),
+                        &res,
                     )
                 }
                 "ServerException" => {
-                    return RusotoError::Service(UpdateContainerInstancesStateError::Server(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateContainerInstancesStateError::Server(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        UpdateContainerInstancesStateError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -5775,12 +6207,16 @@ impl fmt::Display for UpdateContainerInstancesStateError {
                 write!(f, "{}", cause)
             }
             UpdateContainerInstancesStateError::Server(ref cause) => write!(f, "{}", cause),
+            UpdateContainerInstancesStateError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for UpdateContainerInstancesStateError {}
 /// Errors returned by UpdateService
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdateServiceError {
     /// <p>You do not have authorization to perform the requested action.</p>
     AccessDenied(String),
@@ -5800,6 +6236,8 @@ pub enum UpdateServiceError {
     ServiceNotActive(String),
     /// <p>The specified service could not be found. You can view your available services with <a>ListServices</a>. Amazon ECS services are cluster-specific and Region-specific.</p>
     ServiceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdateServiceError {
@@ -5807,36 +6245,43 @@ impl UpdateServiceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(UpdateServiceError::AccessDenied(err.msg))
+                    return RusotoError::service(UpdateServiceError::AccessDenied(err.msg), &res)
                 }
                 "ClientException" => {
-                    return RusotoError::Service(UpdateServiceError::Client(err.msg))
+                    return RusotoError::service(UpdateServiceError::Client(err.msg), &res)
                 }
                 "ClusterNotFoundException" => {
-                    return RusotoError::Service(UpdateServiceError::ClusterNotFound(err.msg))
+                    return RusotoError::service(UpdateServiceError::ClusterNotFound(err.msg), &res)
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(UpdateServiceError::InvalidParameter(err.msg))
+                    return RusotoError::service(
+                        UpdateServiceError::InvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "PlatformTaskDefinitionIncompatibilityException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         UpdateServiceError::PlatformTaskDefinitionIncompatibility(err.msg),
+                        &res,
                     )
                 }
                 "PlatformUnknownException" => {
-                    return RusotoError::Service(UpdateServiceError::PlatformUnknown(err.msg))
+                    return RusotoError::service(UpdateServiceError::PlatformUnknown(err.msg), &res)
                 }
                 "ServerException" => {
-                    return RusotoError::Service(UpdateServiceError::Server(err.msg))
+                    return RusotoError::service(UpdateServiceError::Server(err.msg), &res)
                 }
                 "ServiceNotActiveException" => {
-                    return RusotoError::Service(UpdateServiceError::ServiceNotActive(err.msg))
+                    return RusotoError::service(
+                        UpdateServiceError::ServiceNotActive(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceNotFoundException" => {
-                    return RusotoError::Service(UpdateServiceError::ServiceNotFound(err.msg))
+                    return RusotoError::service(UpdateServiceError::ServiceNotFound(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UpdateServiceError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -5857,12 +6302,14 @@ impl fmt::Display for UpdateServiceError {
             UpdateServiceError::Server(ref cause) => write!(f, "{}", cause),
             UpdateServiceError::ServiceNotActive(ref cause) => write!(f, "{}", cause),
             UpdateServiceError::ServiceNotFound(ref cause) => write!(f, "{}", cause),
+            UpdateServiceError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for UpdateServiceError {}
 /// Errors returned by UpdateServicePrimaryTaskSet
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdateServicePrimaryTaskSetError {
     /// <p>You do not have authorization to perform the requested action.</p>
     AccessDenied(String),
@@ -5882,6 +6329,8 @@ pub enum UpdateServicePrimaryTaskSetError {
     TaskSetNotFound(String),
     /// <p>The specified task is not supported in this Region.</p>
     UnsupportedFeature(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdateServicePrimaryTaskSetError {
@@ -5891,48 +6340,66 @@ impl UpdateServicePrimaryTaskSetError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(UpdateServicePrimaryTaskSetError::AccessDenied(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateServicePrimaryTaskSetError::AccessDenied(err.msg),
+                        &res,
+                    )
                 }
                 "ClientException" => {
-                    return RusotoError::Service(UpdateServicePrimaryTaskSetError::Client(err.msg))
+                    return RusotoError::service(
+                        UpdateServicePrimaryTaskSetError::Client(err.msg),
+                        &res,
+                    )
                 }
                 "ClusterNotFoundException" => {
-                    return RusotoError::Service(UpdateServicePrimaryTaskSetError::ClusterNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateServicePrimaryTaskSetError::ClusterNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         UpdateServicePrimaryTaskSetError::InvalidParameter(err.msg),
+                        &res,
                     )
                 }
                 "ServerException" => {
-                    return RusotoError::Service(UpdateServicePrimaryTaskSetError::Server(err.msg))
+                    return RusotoError::service(
+                        UpdateServicePrimaryTaskSetError::Server(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceNotActiveException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         UpdateServicePrimaryTaskSetError::ServiceNotActive(err.msg),
+                        &res,
                     )
                 }
                 "ServiceNotFoundException" => {
-                    return RusotoError::Service(UpdateServicePrimaryTaskSetError::ServiceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateServicePrimaryTaskSetError::ServiceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "TaskSetNotFoundException" => {
-                    return RusotoError::Service(UpdateServicePrimaryTaskSetError::TaskSetNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateServicePrimaryTaskSetError::TaskSetNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "UnsupportedFeatureException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         UpdateServicePrimaryTaskSetError::UnsupportedFeature(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        UpdateServicePrimaryTaskSetError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -5953,12 +6420,14 @@ impl fmt::Display for UpdateServicePrimaryTaskSetError {
             UpdateServicePrimaryTaskSetError::UnsupportedFeature(ref cause) => {
                 write!(f, "{}", cause)
             }
+            UpdateServicePrimaryTaskSetError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for UpdateServicePrimaryTaskSetError {}
 /// Errors returned by UpdateTaskSet
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdateTaskSetError {
     /// <p>You do not have authorization to perform the requested action.</p>
     AccessDenied(String),
@@ -5978,6 +6447,8 @@ pub enum UpdateTaskSetError {
     TaskSetNotFound(String),
     /// <p>The specified task is not supported in this Region.</p>
     UnsupportedFeature(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdateTaskSetError {
@@ -5985,34 +6456,43 @@ impl UpdateTaskSetError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(UpdateTaskSetError::AccessDenied(err.msg))
+                    return RusotoError::service(UpdateTaskSetError::AccessDenied(err.msg), &res)
                 }
                 "ClientException" => {
-                    return RusotoError::Service(UpdateTaskSetError::Client(err.msg))
+                    return RusotoError::service(UpdateTaskSetError::Client(err.msg), &res)
                 }
                 "ClusterNotFoundException" => {
-                    return RusotoError::Service(UpdateTaskSetError::ClusterNotFound(err.msg))
+                    return RusotoError::service(UpdateTaskSetError::ClusterNotFound(err.msg), &res)
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(UpdateTaskSetError::InvalidParameter(err.msg))
+                    return RusotoError::service(
+                        UpdateTaskSetError::InvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "ServerException" => {
-                    return RusotoError::Service(UpdateTaskSetError::Server(err.msg))
+                    return RusotoError::service(UpdateTaskSetError::Server(err.msg), &res)
                 }
                 "ServiceNotActiveException" => {
-                    return RusotoError::Service(UpdateTaskSetError::ServiceNotActive(err.msg))
+                    return RusotoError::service(
+                        UpdateTaskSetError::ServiceNotActive(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceNotFoundException" => {
-                    return RusotoError::Service(UpdateTaskSetError::ServiceNotFound(err.msg))
+                    return RusotoError::service(UpdateTaskSetError::ServiceNotFound(err.msg), &res)
                 }
                 "TaskSetNotFoundException" => {
-                    return RusotoError::Service(UpdateTaskSetError::TaskSetNotFound(err.msg))
+                    return RusotoError::service(UpdateTaskSetError::TaskSetNotFound(err.msg), &res)
                 }
                 "UnsupportedFeatureException" => {
-                    return RusotoError::Service(UpdateTaskSetError::UnsupportedFeature(err.msg))
+                    return RusotoError::service(
+                        UpdateTaskSetError::UnsupportedFeature(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UpdateTaskSetError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -6031,6 +6511,7 @@ impl fmt::Display for UpdateTaskSetError {
             UpdateTaskSetError::ServiceNotFound(ref cause) => write!(f, "{}", cause),
             UpdateTaskSetError::TaskSetNotFound(ref cause) => write!(f, "{}", cause),
             UpdateTaskSetError::UnsupportedFeature(ref cause) => write!(f, "{}", cause),
+            UpdateTaskSetError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
@@ -6340,6 +6821,7 @@ impl EcsClient {
     /// Creates a client backed by the default tokio event loop.
     ///
     /// The client will use the default credentials provider and tls client.
+    #[cfg(not(target_arch = "wasm32"))]
     pub fn new(region: region::Region) -> EcsClient {
         EcsClient {
             client: Client::shared(),
@@ -6365,16 +6847,73 @@ impl EcsClient {
     pub fn new_with_client(client: Client, region: region::Region) -> EcsClient {
         EcsClient { client, region }
     }
+
+    /// Returns a builder of a client, setting any of its region, credentials provider,
+    /// dispatcher, retry policy, endpoint and timeouts.
+    pub fn builder() -> rusoto_core::ClientBuilder<EcsClient> {
+        rusoto_core::ClientBuilder::new(EcsClient::new_with_client)
+    }
+
+    /// Returns a clone of this client with the overrides of `config` applied to its
+    /// operations, sharing its connections.
+    pub fn customize(&self, config: &rusoto_core::OperationConfig) -> EcsClient {
+        EcsClient {
+            client: self.client.customize(config),
+            region: config.resolve_region(&self.region),
+        }
+    }
 }
 
-#[async_trait]
-impl Ecs for EcsClient {
-    /// <p>Creates a new capacity provider. Capacity providers are associated with an Amazon ECS cluster and are used in capacity provider strategies to facilitate cluster auto scaling.</p> <p>Only capacity providers using an Auto Scaling group can be created. Amazon ECS tasks on AWS Fargate use the <code>FARGATE</code> and <code>FARGATE_SPOT</code> capacity providers which are already created and available to all accounts in Regions supported by AWS Fargate.</p>
-    async fn create_capacity_provider(
+impl EcsClient {
+    /// Calls `create_capacity_provider`, also returning the metadata of the response.
+    pub async fn create_capacity_provider_with_metadata(
         &self,
         input: CreateCapacityProviderRequest,
-    ) -> Result<CreateCapacityProviderResponse, RusotoError<CreateCapacityProviderError>> {
+    ) -> Result<
+        (CreateCapacityProviderResponse, ResponseMetadata),
+        RusotoError<CreateCapacityProviderError>,
+    > {
+        let request = self.build_create_capacity_provider_request(input);
+
+        let mut response = self
+            .client
+            .sign_and_dispatch(request)
+            .await
+            .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
+        if response.status.is_success() {
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<CreateCapacityProviderResponse, _>()?;
+            Ok((result, metadata))
+        } else {
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(CreateCapacityProviderError::from_response(response))
+        }
+    }
+
+    /// Presigns the request `create_capacity_provider` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_capacity_provider(
+        &self,
+        presigner: &rusoto_core::Presigner,
+        input: CreateCapacityProviderRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_capacity_provider_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_capacity_provider_request(
+        &self,
+        input: CreateCapacityProviderRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "ecs", &self.region, "/");
+        request.set_operation("CreateCapacityProvider");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -6384,28 +6923,52 @@ impl Ecs for EcsClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `create_cluster`, also returning the metadata of the response.
+    pub async fn create_cluster_with_metadata(
+        &self,
+        input: CreateClusterRequest,
+    ) -> Result<(CreateClusterResponse, ResponseMetadata), RusotoError<CreateClusterError>> {
+        let request = self.build_create_cluster_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<CreateCapacityProviderResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<CreateClusterResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(CreateCapacityProviderError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(CreateClusterError::from_response(response))
         }
     }
 
-    /// <p><p>Creates a new Amazon ECS cluster. By default, your account receives a <code>default</code> cluster when you launch your first container instance. However, you can create your own cluster with a unique name with the <code>CreateCluster</code> action.</p> <note> <p>When you call the <a>CreateCluster</a> API operation, Amazon ECS attempts to create the Amazon ECS service-linked role for your account so that required resources in other AWS services can be managed on your behalf. However, if the IAM user that makes the call does not have permissions to create the service-linked role, it is not created. For more information, see <a href="https://docs.aws.amazon.com/AmazonECS/latest/developerguide/using-service-linked-roles.html">Using Service-Linked Roles for Amazon ECS</a> in the <i>Amazon Elastic Container Service Developer Guide</i>.</p> </note></p>
-    async fn create_cluster(
+    /// Presigns the request `create_cluster` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_cluster(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: CreateClusterRequest,
-    ) -> Result<CreateClusterResponse, RusotoError<CreateClusterError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_cluster_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_cluster_request(&self, input: CreateClusterRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "ecs", &self.region, "/");
+        request.set_operation("CreateCluster");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -6415,27 +6978,52 @@ impl Ecs for EcsClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `create_service`, also returning the metadata of the response.
+    pub async fn create_service_with_metadata(
+        &self,
+        input: CreateServiceRequest,
+    ) -> Result<(CreateServiceResponse, ResponseMetadata), RusotoError<CreateServiceError>> {
+        let request = self.build_create_service_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<CreateClusterResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<CreateServiceResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(CreateClusterError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(CreateServiceError::from_response(response))
         }
     }
 
-    /// <p><p>Runs and maintains a desired number of tasks from a specified task definition. If the number of tasks running in a service drops below the <code>desiredCount</code>, Amazon ECS runs another copy of the task in the specified cluster. To update an existing service, see <a>UpdateService</a>.</p> <p>In addition to maintaining the desired count of tasks in your service, you can optionally run your service behind one or more load balancers. The load balancers distribute traffic across the tasks that are associated with the service. For more information, see <a href="https://docs.aws.amazon.com/AmazonECS/latest/developerguide/service-load-balancing.html">Service Load Balancing</a> in the <i>Amazon Elastic Container Service Developer Guide</i>.</p> <p>Tasks for services that <i>do not</i> use a load balancer are considered healthy if they&#39;re in the <code>RUNNING</code> state. Tasks for services that <i>do</i> use a load balancer are considered healthy if they&#39;re in the <code>RUNNING</code> state and the container instance that they&#39;re hosted on is reported as healthy by the load balancer.</p> <p>There are two service scheduler strategies available:</p> <ul> <li> <p> <code>REPLICA</code> - The replica scheduling strategy places and maintains the desired number of tasks across your cluster. By default, the service scheduler spreads tasks across Availability Zones. You can use task placement strategies and constraints to customize task placement decisions. For more information, see <a href="https://docs.aws.amazon.com/AmazonECS/latest/developerguide/ecs_services.html">Service Scheduler Concepts</a> in the <i>Amazon Elastic Container Service Developer Guide</i>.</p> </li> <li> <p> <code>DAEMON</code> - The daemon scheduling strategy deploys exactly one task on each active container instance that meets all of the task placement constraints that you specify in your cluster. When using this strategy, you don&#39;t need to specify a desired number of tasks, a task placement strategy, or use Service Auto Scaling policies. For more information, see <a href="https://docs.aws.amazon.com/AmazonECS/latest/developerguide/ecs_services.html">Service Scheduler Concepts</a> in the <i>Amazon Elastic Container Service Developer Guide</i>.</p> </li> </ul> <p>You can optionally specify a deployment configuration for your service. The deployment is triggered by changing properties, such as the task definition or the desired count of a service, with an <a>UpdateService</a> operation. The default value for a replica service for <code>minimumHealthyPercent</code> is 100%. The default value for a daemon service for <code>minimumHealthyPercent</code> is 0%.</p> <p>If a service is using the <code>ECS</code> deployment controller, the minimum healthy percent represents a lower limit on the number of tasks in a service that must remain in the <code>RUNNING</code> state during a deployment, as a percentage of the desired number of tasks (rounded up to the nearest integer), and while any container instances are in the <code>DRAINING</code> state if the service contains tasks using the EC2 launch type. This parameter enables you to deploy without using additional cluster capacity. For example, if your service has a desired number of four tasks and a minimum healthy percent of 50%, the scheduler might stop two existing tasks to free up cluster capacity before starting two new tasks. Tasks for services that <i>do not</i> use a load balancer are considered healthy if they&#39;re in the <code>RUNNING</code> state. Tasks for services that <i>do</i> use a load balancer are considered healthy if they&#39;re in the <code>RUNNING</code> state and they&#39;re reported as healthy by the load balancer. The default value for minimum healthy percent is 100%.</p> <p>If a service is using the <code>ECS</code> deployment controller, the <b>maximum percent</b> parameter represents an upper limit on the number of tasks in a service that are allowed in the <code>RUNNING</code> or <code>PENDING</code> state during a deployment, as a percentage of the desired number of tasks (rounded down to the nearest integer), and while any container instances are in the <code>DRAINING</code> state if the service contains tasks using the EC2 launch type. This parameter enables you to define the deployment batch size. For example, if your service has a desired number of four tasks and a maximum percent value of 200%, the scheduler may start four new tasks before stopping the four older tasks (provided that the cluster resources required to do this are available). The default value for maximum percent is 200%.</p> <p>If a service is using either the <code>CODE_DEPLOY</code> or <code>EXTERNAL</code> deployment controller types and tasks that use the EC2 launch type, the <b>minimum healthy percent</b> and <b>maximum percent</b> values are used only to define the lower and upper limit on the number of the tasks in the service that remain in the <code>RUNNING</code> state while the container instances are in the <code>DRAINING</code> state. If the tasks in the service use the Fargate launch type, the minimum healthy percent and maximum percent values aren&#39;t used, although they&#39;re currently visible when describing your service.</p> <p>When creating a service that uses the <code>EXTERNAL</code> deployment controller, you can specify only parameters that aren&#39;t controlled at the task set level. The only required parameter is the service name. You control your services using the <a>CreateTaskSet</a> operation. For more information, see <a href="https://docs.aws.amazon.com/AmazonECS/latest/developerguide/deployment-types.html">Amazon ECS Deployment Types</a> in the <i>Amazon Elastic Container Service Developer Guide</i>.</p> <p>When the service scheduler launches new tasks, it determines task placement in your cluster using the following logic:</p> <ul> <li> <p>Determine which of the container instances in your cluster can support your service&#39;s task definition (for example, they have the required CPU, memory, ports, and container instance attributes).</p> </li> <li> <p>By default, the service scheduler attempts to balance tasks across Availability Zones in this manner (although you can choose a different placement strategy) with the <code>placementStrategy</code> parameter):</p> <ul> <li> <p>Sort the valid container instances, giving priority to instances that have the fewest number of running tasks for this service in their respective Availability Zone. For example, if zone A has one running service task and zones B and C each have zero, valid container instances in either zone B or C are considered optimal for placement.</p> </li> <li> <p>Place the new service task on a valid container instance in an optimal Availability Zone (based on the previous steps), favoring container instances with the fewest number of running tasks for this service.</p> </li> </ul> </li> </ul></p>
-    async fn create_service(
+    /// Presigns the request `create_service` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_service(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: CreateServiceRequest,
-    ) -> Result<CreateServiceResponse, RusotoError<CreateServiceError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_service_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_service_request(&self, input: CreateServiceRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "ecs", &self.region, "/");
+        request.set_operation("CreateService");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -6445,27 +7033,52 @@ impl Ecs for EcsClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `create_task_set`, also returning the metadata of the response.
+    pub async fn create_task_set_with_metadata(
+        &self,
+        input: CreateTaskSetRequest,
+    ) -> Result<(CreateTaskSetResponse, ResponseMetadata), RusotoError<CreateTaskSetError>> {
+        let request = self.build_create_task_set_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<CreateServiceResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<CreateTaskSetResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(CreateServiceError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(CreateTaskSetError::from_response(response))
         }
     }
 
-    /// <p>Create a task set in the specified cluster and service. This is used when a service uses the <code>EXTERNAL</code> deployment controller type. For more information, see <a href="https://docs.aws.amazon.com/AmazonECS/latest/developerguide/deployment-types.html">Amazon ECS Deployment Types</a> in the <i>Amazon Elastic Container Service Developer Guide</i>.</p>
-    async fn create_task_set(
+    /// Presigns the request `create_task_set` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_task_set(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: CreateTaskSetRequest,
-    ) -> Result<CreateTaskSetResponse, RusotoError<CreateTaskSetError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_task_set_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_task_set_request(&self, input: CreateTaskSetRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "ecs", &self.region, "/");
+        request.set_operation("CreateTaskSet");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -6475,27 +7088,58 @@ impl Ecs for EcsClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `delete_account_setting`, also returning the metadata of the response.
+    pub async fn delete_account_setting_with_metadata(
+        &self,
+        input: DeleteAccountSettingRequest,
+    ) -> Result<
+        (DeleteAccountSettingResponse, ResponseMetadata),
+        RusotoError<DeleteAccountSettingError>,
+    > {
+        let request = self.build_delete_account_setting_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<CreateTaskSetResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DeleteAccountSettingResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(CreateTaskSetError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DeleteAccountSettingError::from_response(response))
         }
     }
 
-    /// <p>Disables an account setting for a specified IAM user, IAM role, or the root user for an account.</p>
-    async fn delete_account_setting(
+    /// Presigns the request `delete_account_setting` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_account_setting(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteAccountSettingRequest,
-    ) -> Result<DeleteAccountSettingResponse, RusotoError<DeleteAccountSettingError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_account_setting_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_account_setting_request(
+        &self,
+        input: DeleteAccountSettingRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "ecs", &self.region, "/");
+        request.set_operation("DeleteAccountSetting");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -6505,28 +7149,53 @@ impl Ecs for EcsClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `delete_attributes`, also returning the metadata of the response.
+    pub async fn delete_attributes_with_metadata(
+        &self,
+        input: DeleteAttributesRequest,
+    ) -> Result<(DeleteAttributesResponse, ResponseMetadata), RusotoError<DeleteAttributesError>>
+    {
+        let request = self.build_delete_attributes_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DeleteAccountSettingResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DeleteAttributesResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteAccountSettingError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DeleteAttributesError::from_response(response))
         }
     }
 
-    /// <p>Deletes one or more custom attributes from an Amazon ECS resource.</p>
-    async fn delete_attributes(
+    /// Presigns the request `delete_attributes` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_attributes(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteAttributesRequest,
-    ) -> Result<DeleteAttributesResponse, RusotoError<DeleteAttributesError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_attributes_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_attributes_request(&self, input: DeleteAttributesRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "ecs", &self.region, "/");
+        request.set_operation("DeleteAttributes");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -6536,28 +7205,52 @@ impl Ecs for EcsClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `delete_cluster`, also returning the metadata of the response.
+    pub async fn delete_cluster_with_metadata(
+        &self,
+        input: DeleteClusterRequest,
+    ) -> Result<(DeleteClusterResponse, ResponseMetadata), RusotoError<DeleteClusterError>> {
+        let request = self.build_delete_cluster_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DeleteAttributesResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DeleteClusterResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteAttributesError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DeleteClusterError::from_response(response))
         }
     }
 
-    /// <p>Deletes the specified cluster. The cluster will transition to the <code>INACTIVE</code> state. Clusters with an <code>INACTIVE</code> status may remain discoverable in your account for a period of time. However, this behavior is subject to change in the future, so you should not rely on <code>INACTIVE</code> clusters persisting.</p> <p>You must deregister all container instances from this cluster before you may delete it. You can list the container instances in a cluster with <a>ListContainerInstances</a> and deregister them with <a>DeregisterContainerInstance</a>.</p>
-    async fn delete_cluster(
+    /// Presigns the request `delete_cluster` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_cluster(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteClusterRequest,
-    ) -> Result<DeleteClusterResponse, RusotoError<DeleteClusterError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_cluster_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_cluster_request(&self, input: DeleteClusterRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "ecs", &self.region, "/");
+        request.set_operation("DeleteCluster");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -6567,27 +7260,52 @@ impl Ecs for EcsClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `delete_service`, also returning the metadata of the response.
+    pub async fn delete_service_with_metadata(
+        &self,
+        input: DeleteServiceRequest,
+    ) -> Result<(DeleteServiceResponse, ResponseMetadata), RusotoError<DeleteServiceError>> {
+        let request = self.build_delete_service_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<DeleteClusterResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DeleteServiceResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteClusterError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DeleteServiceError::from_response(response))
         }
     }
 
-    /// <p><p>Deletes a specified service within a cluster. You can delete a service if you have no running tasks in it and the desired task count is zero. If the service is actively maintaining tasks, you cannot delete it, and you must update the service to a desired task count of zero. For more information, see <a>UpdateService</a>.</p> <note> <p>When you delete a service, if there are still running tasks that require cleanup, the service status moves from <code>ACTIVE</code> to <code>DRAINING</code>, and the service is no longer visible in the console or in the <a>ListServices</a> API operation. After all tasks have transitioned to either <code>STOPPING</code> or <code>STOPPED</code> status, the service status moves from <code>DRAINING</code> to <code>INACTIVE</code>. Services in the <code>DRAINING</code> or <code>INACTIVE</code> status can still be viewed with the <a>DescribeServices</a> API operation. However, in the future, <code>INACTIVE</code> services may be cleaned up and purged from Amazon ECS record keeping, and <a>DescribeServices</a> calls on those services return a <code>ServiceNotFoundException</code> error.</p> </note> <important> <p>If you attempt to create a new service with the same name as an existing service in either <code>ACTIVE</code> or <code>DRAINING</code> status, you receive an error.</p> </important></p>
-    async fn delete_service(
+    /// Presigns the request `delete_service` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_service(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteServiceRequest,
-    ) -> Result<DeleteServiceResponse, RusotoError<DeleteServiceError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_service_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_service_request(&self, input: DeleteServiceRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "ecs", &self.region, "/");
+        request.set_operation("DeleteService");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -6597,27 +7315,52 @@ impl Ecs for EcsClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
-        let mut response = self
-            .client
-            .sign_and_dispatch(request)
-            .await
+        request
+    }
+
+    /// Calls `delete_task_set`, also returning the metadata of the response.
+    pub async fn delete_task_set_with_metadata(
+        &self,
+        input: DeleteTaskSetRequest,
+    ) -> Result<(DeleteTaskSetResponse, ResponseMetadata), RusotoError<DeleteTaskSetError>> {
+        let request = self.build_delete_task_set_request(input);
+
+        let mut response = self
+            .client
+            .sign_and_dispatch(request)
+            .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<DeleteServiceResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DeleteTaskSetResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteServiceError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DeleteTaskSetError::from_response(response))
         }
     }
 
-    /// <p>Deletes a specified task set within a service. This is used when a service uses the <code>EXTERNAL</code> deployment controller type. For more information, see <a href="https://docs.aws.amazon.com/AmazonECS/latest/developerguide/deployment-types.html">Amazon ECS Deployment Types</a> in the <i>Amazon Elastic Container Service Developer Guide</i>.</p>
-    async fn delete_task_set(
+    /// Presigns the request `delete_task_set` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_task_set(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteTaskSetRequest,
-    ) -> Result<DeleteTaskSetResponse, RusotoError<DeleteTaskSetError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_task_set_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_task_set_request(&self, input: DeleteTaskSetRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "ecs", &self.region, "/");
+        request.set_operation("DeleteTaskSet");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -6627,28 +7370,58 @@ impl Ecs for EcsClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `deregister_container_instance`, also returning the metadata of the response.
+    pub async fn deregister_container_instance_with_metadata(
+        &self,
+        input: DeregisterContainerInstanceRequest,
+    ) -> Result<
+        (DeregisterContainerInstanceResponse, ResponseMetadata),
+        RusotoError<DeregisterContainerInstanceError>,
+    > {
+        let request = self.build_deregister_container_instance_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<DeleteTaskSetResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DeregisterContainerInstanceResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteTaskSetError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DeregisterContainerInstanceError::from_response(response))
         }
     }
 
-    /// <p><p>Deregisters an Amazon ECS container instance from the specified cluster. This instance is no longer available to run tasks.</p> <p>If you intend to use the container instance for some other purpose after deregistration, you should stop all of the tasks running on the container instance before deregistration. That prevents any orphaned tasks from consuming resources.</p> <p>Deregistering a container instance removes the instance from a cluster, but it does not terminate the EC2 instance. If you are finished using the instance, be sure to terminate it in the Amazon EC2 console to stop billing.</p> <note> <p>If you terminate a running container instance, Amazon ECS automatically deregisters the instance from your cluster (stopped container instances or instances with disconnected agents are not automatically deregistered when terminated).</p> </note></p>
-    async fn deregister_container_instance(
+    /// Presigns the request `deregister_container_instance` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_deregister_container_instance(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeregisterContainerInstanceRequest,
-    ) -> Result<DeregisterContainerInstanceResponse, RusotoError<DeregisterContainerInstanceError>>
-    {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_deregister_container_instance_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_deregister_container_instance_request(
+        &self,
+        input: DeregisterContainerInstanceRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "ecs", &self.region, "/");
+        request.set_operation("DeregisterContainerInstance");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -6658,28 +7431,58 @@ impl Ecs for EcsClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `deregister_task_definition`, also returning the metadata of the response.
+    pub async fn deregister_task_definition_with_metadata(
+        &self,
+        input: DeregisterTaskDefinitionRequest,
+    ) -> Result<
+        (DeregisterTaskDefinitionResponse, ResponseMetadata),
+        RusotoError<DeregisterTaskDefinitionError>,
+    > {
+        let request = self.build_deregister_task_definition_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DeregisterContainerInstanceResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DeregisterTaskDefinitionResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DeregisterContainerInstanceError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DeregisterTaskDefinitionError::from_response(response))
         }
     }
 
-    /// <p><p>Deregisters the specified task definition by family and revision. Upon deregistration, the task definition is marked as <code>INACTIVE</code>. Existing tasks and services that reference an <code>INACTIVE</code> task definition continue to run without disruption. Existing services that reference an <code>INACTIVE</code> task definition can still scale up or down by modifying the service&#39;s desired count.</p> <p>You cannot use an <code>INACTIVE</code> task definition to run new tasks or create new services, and you cannot update an existing service to reference an <code>INACTIVE</code> task definition. However, there may be up to a 10-minute window following deregistration where these restrictions have not yet taken effect.</p> <note> <p>At this time, <code>INACTIVE</code> task definitions remain discoverable in your account indefinitely. However, this behavior is subject to change in the future, so you should not rely on <code>INACTIVE</code> task definitions persisting beyond the lifecycle of any associated tasks and services.</p> </note></p>
-    async fn deregister_task_definition(
+    /// Presigns the request `deregister_task_definition` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_deregister_task_definition(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeregisterTaskDefinitionRequest,
-    ) -> Result<DeregisterTaskDefinitionResponse, RusotoError<DeregisterTaskDefinitionError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_deregister_task_definition_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_deregister_task_definition_request(
+        &self,
+        input: DeregisterTaskDefinitionRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "ecs", &self.region, "/");
+        request.set_operation("DeregisterTaskDefinition");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -6689,29 +7492,58 @@ impl Ecs for EcsClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `describe_capacity_providers`, also returning the metadata of the response.
+    pub async fn describe_capacity_providers_with_metadata(
+        &self,
+        input: DescribeCapacityProvidersRequest,
+    ) -> Result<
+        (DescribeCapacityProvidersResponse, ResponseMetadata),
+        RusotoError<DescribeCapacityProvidersError>,
+    > {
+        let request = self.build_describe_capacity_providers_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DeregisterTaskDefinitionResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DescribeCapacityProvidersResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DeregisterTaskDefinitionError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DescribeCapacityProvidersError::from_response(response))
         }
     }
 
-    /// <p>Describes one or more of your capacity providers.</p>
-    async fn describe_capacity_providers(
+    /// Presigns the request `describe_capacity_providers` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_capacity_providers(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeCapacityProvidersRequest,
-    ) -> Result<DescribeCapacityProvidersResponse, RusotoError<DescribeCapacityProvidersError>>
-    {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_capacity_providers_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_capacity_providers_request(
+        &self,
+        input: DescribeCapacityProvidersRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "ecs", &self.region, "/");
+        request.set_operation("DescribeCapacityProviders");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -6721,28 +7553,53 @@ impl Ecs for EcsClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `describe_clusters`, also returning the metadata of the response.
+    pub async fn describe_clusters_with_metadata(
+        &self,
+        input: DescribeClustersRequest,
+    ) -> Result<(DescribeClustersResponse, ResponseMetadata), RusotoError<DescribeClustersError>>
+    {
+        let request = self.build_describe_clusters_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DescribeCapacityProvidersResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DescribeClustersResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeCapacityProvidersError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DescribeClustersError::from_response(response))
         }
     }
 
-    /// <p>Describes one or more of your clusters.</p>
-    async fn describe_clusters(
+    /// Presigns the request `describe_clusters` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_clusters(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeClustersRequest,
-    ) -> Result<DescribeClustersResponse, RusotoError<DescribeClustersError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_clusters_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_clusters_request(&self, input: DescribeClustersRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "ecs", &self.region, "/");
+        request.set_operation("DescribeClusters");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -6752,29 +7609,58 @@ impl Ecs for EcsClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `describe_container_instances`, also returning the metadata of the response.
+    pub async fn describe_container_instances_with_metadata(
+        &self,
+        input: DescribeContainerInstancesRequest,
+    ) -> Result<
+        (DescribeContainerInstancesResponse, ResponseMetadata),
+        RusotoError<DescribeContainerInstancesError>,
+    > {
+        let request = self.build_describe_container_instances_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DescribeClustersResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DescribeContainerInstancesResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeClustersError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DescribeContainerInstancesError::from_response(response))
         }
     }
 
-    /// <p>Describes Amazon Elastic Container Service container instances. Returns metadata about registered and remaining resources on each container instance requested.</p>
-    async fn describe_container_instances(
+    /// Presigns the request `describe_container_instances` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_container_instances(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeContainerInstancesRequest,
-    ) -> Result<DescribeContainerInstancesResponse, RusotoError<DescribeContainerInstancesError>>
-    {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_container_instances_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_container_instances_request(
+        &self,
+        input: DescribeContainerInstancesRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "ecs", &self.region, "/");
+        request.set_operation("DescribeContainerInstances");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -6784,28 +7670,53 @@ impl Ecs for EcsClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `describe_services`, also returning the metadata of the response.
+    pub async fn describe_services_with_metadata(
+        &self,
+        input: DescribeServicesRequest,
+    ) -> Result<(DescribeServicesResponse, ResponseMetadata), RusotoError<DescribeServicesError>>
+    {
+        let request = self.build_describe_services_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DescribeContainerInstancesResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DescribeServicesResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeContainerInstancesError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DescribeServicesError::from_response(response))
         }
     }
 
-    /// <p>Describes the specified services running in your cluster.</p>
-    async fn describe_services(
+    /// Presigns the request `describe_services` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_services(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeServicesRequest,
-    ) -> Result<DescribeServicesResponse, RusotoError<DescribeServicesError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_services_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_services_request(&self, input: DescribeServicesRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "ecs", &self.region, "/");
+        request.set_operation("DescribeServices");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -6815,28 +7726,58 @@ impl Ecs for EcsClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `describe_task_definition`, also returning the metadata of the response.
+    pub async fn describe_task_definition_with_metadata(
+        &self,
+        input: DescribeTaskDefinitionRequest,
+    ) -> Result<
+        (DescribeTaskDefinitionResponse, ResponseMetadata),
+        RusotoError<DescribeTaskDefinitionError>,
+    > {
+        let request = self.build_describe_task_definition_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DescribeServicesResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DescribeTaskDefinitionResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeServicesError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DescribeTaskDefinitionError::from_response(response))
         }
     }
 
-    /// <p><p>Describes a task definition. You can specify a <code>family</code> and <code>revision</code> to find information about a specific task definition, or you can simply specify the family to find the latest <code>ACTIVE</code> revision in that family.</p> <note> <p>You can only describe <code>INACTIVE</code> task definitions while an active task or service references them.</p> </note></p>
-    async fn describe_task_definition(
+    /// Presigns the request `describe_task_definition` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_task_definition(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeTaskDefinitionRequest,
-    ) -> Result<DescribeTaskDefinitionResponse, RusotoError<DescribeTaskDefinitionError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_task_definition_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_task_definition_request(
+        &self,
+        input: DescribeTaskDefinitionRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "ecs", &self.region, "/");
+        request.set_operation("DescribeTaskDefinition");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -6846,28 +7787,53 @@ impl Ecs for EcsClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `describe_task_sets`, also returning the metadata of the response.
+    pub async fn describe_task_sets_with_metadata(
+        &self,
+        input: DescribeTaskSetsRequest,
+    ) -> Result<(DescribeTaskSetsResponse, ResponseMetadata), RusotoError<DescribeTaskSetsError>>
+    {
+        let request = self.build_describe_task_sets_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DescribeTaskDefinitionResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DescribeTaskSetsResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeTaskDefinitionError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DescribeTaskSetsError::from_response(response))
         }
     }
 
-    /// <p>Describes the task sets in the specified cluster and service. This is used when a service uses the <code>EXTERNAL</code> deployment controller type. For more information, see <a href="https://docs.aws.amazon.com/AmazonECS/latest/developerguide/deployment-types.html">Amazon ECS Deployment Types</a> in the <i>Amazon Elastic Container Service Developer Guide</i>.</p>
-    async fn describe_task_sets(
+    /// Presigns the request `describe_task_sets` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_task_sets(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeTaskSetsRequest,
-    ) -> Result<DescribeTaskSetsResponse, RusotoError<DescribeTaskSetsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_task_sets_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_task_sets_request(&self, input: DescribeTaskSetsRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "ecs", &self.region, "/");
+        request.set_operation("DescribeTaskSets");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -6877,28 +7843,52 @@ impl Ecs for EcsClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `describe_tasks`, also returning the metadata of the response.
+    pub async fn describe_tasks_with_metadata(
+        &self,
+        input: DescribeTasksRequest,
+    ) -> Result<(DescribeTasksResponse, ResponseMetadata), RusotoError<DescribeTasksError>> {
+        let request = self.build_describe_tasks_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DescribeTaskSetsResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DescribeTasksResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeTaskSetsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DescribeTasksError::from_response(response))
         }
     }
 
-    /// <p>Describes a specified task or tasks.</p>
-    async fn describe_tasks(
+    /// Presigns the request `describe_tasks` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_tasks(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeTasksRequest,
-    ) -> Result<DescribeTasksResponse, RusotoError<DescribeTasksError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_tasks_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_tasks_request(&self, input: DescribeTasksRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "ecs", &self.region, "/");
+        request.set_operation("DescribeTasks");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -6908,27 +7898,58 @@ impl Ecs for EcsClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `discover_poll_endpoint`, also returning the metadata of the response.
+    pub async fn discover_poll_endpoint_with_metadata(
+        &self,
+        input: DiscoverPollEndpointRequest,
+    ) -> Result<
+        (DiscoverPollEndpointResponse, ResponseMetadata),
+        RusotoError<DiscoverPollEndpointError>,
+    > {
+        let request = self.build_discover_poll_endpoint_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<DescribeTasksResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DiscoverPollEndpointResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeTasksError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DiscoverPollEndpointError::from_response(response))
         }
     }
 
-    /// <p><note> <p>This action is only used by the Amazon ECS agent, and it is not intended for use outside of the agent.</p> </note> <p>Returns an endpoint for the Amazon ECS agent to poll for updates.</p></p>
-    async fn discover_poll_endpoint(
+    /// Presigns the request `discover_poll_endpoint` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_discover_poll_endpoint(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DiscoverPollEndpointRequest,
-    ) -> Result<DiscoverPollEndpointResponse, RusotoError<DiscoverPollEndpointError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_discover_poll_endpoint_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_discover_poll_endpoint_request(
+        &self,
+        input: DiscoverPollEndpointRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "ecs", &self.region, "/");
+        request.set_operation("DiscoverPollEndpoint");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -6938,28 +7959,58 @@ impl Ecs for EcsClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `list_account_settings`, also returning the metadata of the response.
+    pub async fn list_account_settings_with_metadata(
+        &self,
+        input: ListAccountSettingsRequest,
+    ) -> Result<
+        (ListAccountSettingsResponse, ResponseMetadata),
+        RusotoError<ListAccountSettingsError>,
+    > {
+        let request = self.build_list_account_settings_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DiscoverPollEndpointResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ListAccountSettingsResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DiscoverPollEndpointError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ListAccountSettingsError::from_response(response))
         }
     }
 
-    /// <p>Lists the account settings for a specified principal.</p>
-    async fn list_account_settings(
+    /// Presigns the request `list_account_settings` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_account_settings(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListAccountSettingsRequest,
-    ) -> Result<ListAccountSettingsResponse, RusotoError<ListAccountSettingsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_account_settings_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_account_settings_request(
+        &self,
+        input: ListAccountSettingsRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "ecs", &self.region, "/");
+        request.set_operation("ListAccountSettings");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -6969,28 +8020,52 @@ impl Ecs for EcsClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `list_attributes`, also returning the metadata of the response.
+    pub async fn list_attributes_with_metadata(
+        &self,
+        input: ListAttributesRequest,
+    ) -> Result<(ListAttributesResponse, ResponseMetadata), RusotoError<ListAttributesError>> {
+        let request = self.build_list_attributes_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListAccountSettingsResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ListAttributesResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ListAccountSettingsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ListAttributesError::from_response(response))
         }
     }
 
-    /// <p>Lists the attributes for Amazon ECS resources within a specified target type and cluster. When you specify a target type and cluster, <code>ListAttributes</code> returns a list of attribute objects, one for each attribute on each resource. You can filter the list of results to a single attribute name to only return results that have that name. You can also filter the results by attribute name and value, for example, to see which container instances in a cluster are running a Linux AMI (<code>ecs.os-type=linux</code>). </p>
-    async fn list_attributes(
+    /// Presigns the request `list_attributes` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_attributes(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListAttributesRequest,
-    ) -> Result<ListAttributesResponse, RusotoError<ListAttributesError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_attributes_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_attributes_request(&self, input: ListAttributesRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "ecs", &self.region, "/");
+        request.set_operation("ListAttributes");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -7000,27 +8075,52 @@ impl Ecs for EcsClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `list_clusters`, also returning the metadata of the response.
+    pub async fn list_clusters_with_metadata(
+        &self,
+        input: ListClustersRequest,
+    ) -> Result<(ListClustersResponse, ResponseMetadata), RusotoError<ListClustersError>> {
+        let request = self.build_list_clusters_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<ListAttributesResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ListClustersResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ListAttributesError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ListClustersError::from_response(response))
         }
     }
 
-    /// <p>Returns a list of existing clusters.</p>
-    async fn list_clusters(
+    /// Presigns the request `list_clusters` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_clusters(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListClustersRequest,
-    ) -> Result<ListClustersResponse, RusotoError<ListClustersError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_clusters_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_clusters_request(&self, input: ListClustersRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "ecs", &self.region, "/");
+        request.set_operation("ListClusters");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -7030,27 +8130,58 @@ impl Ecs for EcsClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `list_container_instances`, also returning the metadata of the response.
+    pub async fn list_container_instances_with_metadata(
+        &self,
+        input: ListContainerInstancesRequest,
+    ) -> Result<
+        (ListContainerInstancesResponse, ResponseMetadata),
+        RusotoError<ListContainerInstancesError>,
+    > {
+        let request = self.build_list_container_instances_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<ListClustersResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ListContainerInstancesResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ListClustersError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ListContainerInstancesError::from_response(response))
         }
     }
 
-    /// <p>Returns a list of container instances in a specified cluster. You can filter the results of a <code>ListContainerInstances</code> operation with cluster query language statements inside the <code>filter</code> parameter. For more information, see <a href="https://docs.aws.amazon.com/AmazonECS/latest/developerguide/cluster-query-language.html">Cluster Query Language</a> in the <i>Amazon Elastic Container Service Developer Guide</i>.</p>
-    async fn list_container_instances(
+    /// Presigns the request `list_container_instances` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_container_instances(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListContainerInstancesRequest,
-    ) -> Result<ListContainerInstancesResponse, RusotoError<ListContainerInstancesError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_container_instances_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_container_instances_request(
+        &self,
+        input: ListContainerInstancesRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "ecs", &self.region, "/");
+        request.set_operation("ListContainerInstances");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -7060,28 +8191,52 @@ impl Ecs for EcsClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `list_services`, also returning the metadata of the response.
+    pub async fn list_services_with_metadata(
+        &self,
+        input: ListServicesRequest,
+    ) -> Result<(ListServicesResponse, ResponseMetadata), RusotoError<ListServicesError>> {
+        let request = self.build_list_services_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListContainerInstancesResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ListServicesResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ListContainerInstancesError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ListServicesError::from_response(response))
         }
     }
 
-    /// <p>Lists the services that are running in a specified cluster.</p>
-    async fn list_services(
+    /// Presigns the request `list_services` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_services(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListServicesRequest,
-    ) -> Result<ListServicesResponse, RusotoError<ListServicesError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_services_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_services_request(&self, input: ListServicesRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "ecs", &self.region, "/");
+        request.set_operation("ListServices");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -7091,27 +8246,58 @@ impl Ecs for EcsClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `list_tags_for_resource`, also returning the metadata of the response.
+    pub async fn list_tags_for_resource_with_metadata(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> Result<
+        (ListTagsForResourceResponse, ResponseMetadata),
+        RusotoError<ListTagsForResourceError>,
+    > {
+        let request = self.build_list_tags_for_resource_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<ListServicesResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ListTagsForResourceResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ListServicesError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ListTagsForResourceError::from_response(response))
         }
     }
 
-    /// <p>List the tags for an Amazon ECS resource.</p>
-    async fn list_tags_for_resource(
+    /// Presigns the request `list_tags_for_resource` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_tags_for_resource(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListTagsForResourceRequest,
-    ) -> Result<ListTagsForResourceResponse, RusotoError<ListTagsForResourceError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_tags_for_resource_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_tags_for_resource_request(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "ecs", &self.region, "/");
+        request.set_operation("ListTagsForResource");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -7121,29 +8307,58 @@ impl Ecs for EcsClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
-        let mut response = self
-            .client
-            .sign_and_dispatch(request)
-            .await
-            .map_err(RusotoError::from)?;
-        if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListTagsForResourceResponse, _>()
+        request
+    }
+
+    /// Calls `list_task_definition_families`, also returning the metadata of the response.
+    pub async fn list_task_definition_families_with_metadata(
+        &self,
+        input: ListTaskDefinitionFamiliesRequest,
+    ) -> Result<
+        (ListTaskDefinitionFamiliesResponse, ResponseMetadata),
+        RusotoError<ListTaskDefinitionFamiliesError>,
+    > {
+        let request = self.build_list_task_definition_families_request(input);
+
+        let mut response = self
+            .client
+            .sign_and_dispatch(request)
+            .await
+            .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
+        if response.status.is_success() {
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ListTaskDefinitionFamiliesResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ListTagsForResourceError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ListTaskDefinitionFamiliesError::from_response(response))
         }
     }
 
-    /// <p>Returns a list of task definition families that are registered to your account (which may include task definition families that no longer have any <code>ACTIVE</code> task definition revisions).</p> <p>You can filter out task definition families that do not contain any <code>ACTIVE</code> task definition revisions by setting the <code>status</code> parameter to <code>ACTIVE</code>. You can also filter the results with the <code>familyPrefix</code> parameter.</p>
-    async fn list_task_definition_families(
+    /// Presigns the request `list_task_definition_families` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_task_definition_families(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListTaskDefinitionFamiliesRequest,
-    ) -> Result<ListTaskDefinitionFamiliesResponse, RusotoError<ListTaskDefinitionFamiliesError>>
-    {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_task_definition_families_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_task_definition_families_request(
+        &self,
+        input: ListTaskDefinitionFamiliesRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "ecs", &self.region, "/");
+        request.set_operation("ListTaskDefinitionFamilies");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -7153,28 +8368,58 @@ impl Ecs for EcsClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `list_task_definitions`, also returning the metadata of the response.
+    pub async fn list_task_definitions_with_metadata(
+        &self,
+        input: ListTaskDefinitionsRequest,
+    ) -> Result<
+        (ListTaskDefinitionsResponse, ResponseMetadata),
+        RusotoError<ListTaskDefinitionsError>,
+    > {
+        let request = self.build_list_task_definitions_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListTaskDefinitionFamiliesResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ListTaskDefinitionsResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ListTaskDefinitionFamiliesError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ListTaskDefinitionsError::from_response(response))
         }
     }
 
-    /// <p>Returns a list of task definitions that are registered to your account. You can filter the results by family name with the <code>familyPrefix</code> parameter or by status with the <code>status</code> parameter.</p>
-    async fn list_task_definitions(
+    /// Presigns the request `list_task_definitions` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_task_definitions(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListTaskDefinitionsRequest,
-    ) -> Result<ListTaskDefinitionsResponse, RusotoError<ListTaskDefinitionsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_task_definitions_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_task_definitions_request(
+        &self,
+        input: ListTaskDefinitionsRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "ecs", &self.region, "/");
+        request.set_operation("ListTaskDefinitions");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -7184,28 +8429,52 @@ impl Ecs for EcsClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `list_tasks`, also returning the metadata of the response.
+    pub async fn list_tasks_with_metadata(
+        &self,
+        input: ListTasksRequest,
+    ) -> Result<(ListTasksResponse, ResponseMetadata), RusotoError<ListTasksError>> {
+        let request = self.build_list_tasks_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListTaskDefinitionsResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ListTasksResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ListTaskDefinitionsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ListTasksError::from_response(response))
         }
     }
 
-    /// <p>Returns a list of tasks for a specified cluster. You can filter the results by family name, by a particular container instance, or by the desired status of the task with the <code>family</code>, <code>containerInstance</code>, and <code>desiredStatus</code> parameters.</p> <p>Recently stopped tasks might appear in the returned results. Currently, stopped tasks appear in the returned results for at least one hour. </p>
-    async fn list_tasks(
+    /// Presigns the request `list_tasks` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_tasks(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListTasksRequest,
-    ) -> Result<ListTasksResponse, RusotoError<ListTasksError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_tasks_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_tasks_request(&self, input: ListTasksRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "ecs", &self.region, "/");
+        request.set_operation("ListTasks");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -7215,27 +8484,53 @@ impl Ecs for EcsClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `put_account_setting`, also returning the metadata of the response.
+    pub async fn put_account_setting_with_metadata(
+        &self,
+        input: PutAccountSettingRequest,
+    ) -> Result<(PutAccountSettingResponse, ResponseMetadata), RusotoError<PutAccountSettingError>>
+    {
+        let request = self.build_put_account_setting_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<ListTasksResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<PutAccountSettingResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ListTasksError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(PutAccountSettingError::from_response(response))
         }
     }
 
-    /// <p>Modifies an account setting. Account settings are set on a per-Region basis.</p> <p>If you change the account setting for the root user, the default settings for all of the IAM users and roles for which no individual account setting has been specified are reset. For more information, see <a href="https://docs.aws.amazon.com/AmazonECS/latest/developerguide/ecs-account-settings.html">Account Settings</a> in the <i>Amazon Elastic Container Service Developer Guide</i>.</p> <p>When <code>serviceLongArnFormat</code>, <code>taskLongArnFormat</code>, or <code>containerInstanceLongArnFormat</code> are specified, the Amazon Resource Name (ARN) and resource ID format of the resource type for a specified IAM user, IAM role, or the root user for an account is affected. The opt-in and opt-out account setting must be set for each Amazon ECS resource separately. The ARN and resource ID format of a resource will be defined by the opt-in status of the IAM user or role that created the resource. You must enable this setting to use Amazon ECS features such as resource tagging.</p> <p>When <code>awsvpcTrunking</code> is specified, the elastic network interface (ENI) limit for any new container instances that support the feature is changed. If <code>awsvpcTrunking</code> is enabled, any new container instances that support the feature are launched have the increased ENI limits available to them. For more information, see <a href="https://docs.aws.amazon.com/AmazonECS/latest/developerguide/container-instance-eni.html">Elastic Network Interface Trunking</a> in the <i>Amazon Elastic Container Service Developer Guide</i>.</p> <p>When <code>containerInsights</code> is specified, the default setting indicating whether CloudWatch Container Insights is enabled for your clusters is changed. If <code>containerInsights</code> is enabled, any new clusters that are created will have Container Insights enabled unless you disable it during cluster creation. For more information, see <a href="https://docs.aws.amazon.com/AmazonECS/latest/developerguide/cloudwatch-container-insights.html">CloudWatch Container Insights</a> in the <i>Amazon Elastic Container Service Developer Guide</i>.</p>
-    async fn put_account_setting(
+    /// Presigns the request `put_account_setting` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_put_account_setting(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: PutAccountSettingRequest,
-    ) -> Result<PutAccountSettingResponse, RusotoError<PutAccountSettingError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_put_account_setting_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_put_account_setting_request(&self, input: PutAccountSettingRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "ecs", &self.region, "/");
+        request.set_operation("PutAccountSetting");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -7245,28 +8540,58 @@ impl Ecs for EcsClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `put_account_setting_default`, also returning the metadata of the response.
+    pub async fn put_account_setting_default_with_metadata(
+        &self,
+        input: PutAccountSettingDefaultRequest,
+    ) -> Result<
+        (PutAccountSettingDefaultResponse, ResponseMetadata),
+        RusotoError<PutAccountSettingDefaultError>,
+    > {
+        let request = self.build_put_account_setting_default_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<PutAccountSettingResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<PutAccountSettingDefaultResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(PutAccountSettingError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(PutAccountSettingDefaultError::from_response(response))
         }
     }
 
-    /// <p>Modifies an account setting for all IAM users on an account for whom no individual account setting has been specified. Account settings are set on a per-Region basis.</p>
-    async fn put_account_setting_default(
+    /// Presigns the request `put_account_setting_default` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_put_account_setting_default(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: PutAccountSettingDefaultRequest,
-    ) -> Result<PutAccountSettingDefaultResponse, RusotoError<PutAccountSettingDefaultError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_put_account_setting_default_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_put_account_setting_default_request(
+        &self,
+        input: PutAccountSettingDefaultRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "ecs", &self.region, "/");
+        request.set_operation("PutAccountSettingDefault");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -7276,28 +8601,52 @@ impl Ecs for EcsClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `put_attributes`, also returning the metadata of the response.
+    pub async fn put_attributes_with_metadata(
+        &self,
+        input: PutAttributesRequest,
+    ) -> Result<(PutAttributesResponse, ResponseMetadata), RusotoError<PutAttributesError>> {
+        let request = self.build_put_attributes_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<PutAccountSettingDefaultResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<PutAttributesResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(PutAccountSettingDefaultError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(PutAttributesError::from_response(response))
         }
     }
 
-    /// <p>Create or update an attribute on an Amazon ECS resource. If the attribute does not exist, it is created. If the attribute exists, its value is replaced with the specified value. To delete an attribute, use <a>DeleteAttributes</a>. For more information, see <a href="https://docs.aws.amazon.com/AmazonECS/latest/developerguide/task-placement-constraints.html#attributes">Attributes</a> in the <i>Amazon Elastic Container Service Developer Guide</i>.</p>
-    async fn put_attributes(
+    /// Presigns the request `put_attributes` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_put_attributes(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: PutAttributesRequest,
-    ) -> Result<PutAttributesResponse, RusotoError<PutAttributesError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_put_attributes_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_put_attributes_request(&self, input: PutAttributesRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "ecs", &self.region, "/");
+        request.set_operation("PutAttributes");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -7307,28 +8656,58 @@ impl Ecs for EcsClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `put_cluster_capacity_providers`, also returning the metadata of the response.
+    pub async fn put_cluster_capacity_providers_with_metadata(
+        &self,
+        input: PutClusterCapacityProvidersRequest,
+    ) -> Result<
+        (PutClusterCapacityProvidersResponse, ResponseMetadata),
+        RusotoError<PutClusterCapacityProvidersError>,
+    > {
+        let request = self.build_put_cluster_capacity_providers_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<PutAttributesResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<PutClusterCapacityProvidersResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(PutAttributesError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(PutClusterCapacityProvidersError::from_response(response))
         }
     }
 
-    /// <p>Modifies the available capacity providers and the default capacity provider strategy for a cluster.</p> <p>You must specify both the available capacity providers and a default capacity provider strategy for the cluster. If the specified cluster has existing capacity providers associated with it, you must specify all existing capacity providers in addition to any new ones you want to add. Any existing capacity providers associated with a cluster that are omitted from a <a>PutClusterCapacityProviders</a> API call will be disassociated with the cluster. You can only disassociate an existing capacity provider from a cluster if it's not being used by any existing tasks.</p> <p>When creating a service or running a task on a cluster, if no capacity provider or launch type is specified, then the cluster's default capacity provider strategy is used. It is recommended to define a default capacity provider strategy for your cluster, however you may specify an empty array (<code>[]</code>) to bypass defining a default strategy.</p>
-    async fn put_cluster_capacity_providers(
+    /// Presigns the request `put_cluster_capacity_providers` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_put_cluster_capacity_providers(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: PutClusterCapacityProvidersRequest,
-    ) -> Result<PutClusterCapacityProvidersResponse, RusotoError<PutClusterCapacityProvidersError>>
-    {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_put_cluster_capacity_providers_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_put_cluster_capacity_providers_request(
+        &self,
+        input: PutClusterCapacityProvidersRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "ecs", &self.region, "/");
+        request.set_operation("PutClusterCapacityProviders");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -7338,29 +8717,58 @@ impl Ecs for EcsClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `register_container_instance`, also returning the metadata of the response.
+    pub async fn register_container_instance_with_metadata(
+        &self,
+        input: RegisterContainerInstanceRequest,
+    ) -> Result<
+        (RegisterContainerInstanceResponse, ResponseMetadata),
+        RusotoError<RegisterContainerInstanceError>,
+    > {
+        let request = self.build_register_container_instance_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<PutClusterCapacityProvidersResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<RegisterContainerInstanceResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(PutClusterCapacityProvidersError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(RegisterContainerInstanceError::from_response(response))
         }
     }
 
-    /// <p><note> <p>This action is only used by the Amazon ECS agent, and it is not intended for use outside of the agent.</p> </note> <p>Registers an EC2 instance into the specified cluster. This instance becomes available to place containers on.</p></p>
-    async fn register_container_instance(
+    /// Presigns the request `register_container_instance` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_register_container_instance(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: RegisterContainerInstanceRequest,
-    ) -> Result<RegisterContainerInstanceResponse, RusotoError<RegisterContainerInstanceError>>
-    {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_register_container_instance_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_register_container_instance_request(
+        &self,
+        input: RegisterContainerInstanceRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "ecs", &self.region, "/");
+        request.set_operation("RegisterContainerInstance");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -7370,28 +8778,58 @@ impl Ecs for EcsClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `register_task_definition`, also returning the metadata of the response.
+    pub async fn register_task_definition_with_metadata(
+        &self,
+        input: RegisterTaskDefinitionRequest,
+    ) -> Result<
+        (RegisterTaskDefinitionResponse, ResponseMetadata),
+        RusotoError<RegisterTaskDefinitionError>,
+    > {
+        let request = self.build_register_task_definition_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<RegisterContainerInstanceResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<RegisterTaskDefinitionResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(RegisterContainerInstanceError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(RegisterTaskDefinitionError::from_response(response))
         }
     }
 
-    /// <p>Registers a new task definition from the supplied <code>family</code> and <code>containerDefinitions</code>. Optionally, you can add data volumes to your containers with the <code>volumes</code> parameter. For more information about task definition parameters and defaults, see <a href="https://docs.aws.amazon.com/AmazonECS/latest/developerguide/task_defintions.html">Amazon ECS Task Definitions</a> in the <i>Amazon Elastic Container Service Developer Guide</i>.</p> <p>You can specify an IAM role for your task with the <code>taskRoleArn</code> parameter. When you specify an IAM role for a task, its containers can then use the latest versions of the AWS CLI or SDKs to make API requests to the AWS services that are specified in the IAM policy associated with the role. For more information, see <a href="https://docs.aws.amazon.com/AmazonECS/latest/developerguide/task-iam-roles.html">IAM Roles for Tasks</a> in the <i>Amazon Elastic Container Service Developer Guide</i>.</p> <p>You can specify a Docker networking mode for the containers in your task definition with the <code>networkMode</code> parameter. The available network modes correspond to those described in <a href="https://docs.docker.com/engine/reference/run/#/network-settings">Network settings</a> in the Docker run reference. If you specify the <code>awsvpc</code> network mode, the task is allocated an elastic network interface, and you must specify a <a>NetworkConfiguration</a> when you create a service or run a task with the task definition. For more information, see <a href="https://docs.aws.amazon.com/AmazonECS/latest/developerguide/task-networking.html">Task Networking</a> in the <i>Amazon Elastic Container Service Developer Guide</i>.</p>
-    async fn register_task_definition(
+    /// Presigns the request `register_task_definition` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_register_task_definition(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: RegisterTaskDefinitionRequest,
-    ) -> Result<RegisterTaskDefinitionResponse, RusotoError<RegisterTaskDefinitionError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_register_task_definition_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_register_task_definition_request(
+        &self,
+        input: RegisterTaskDefinitionRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "ecs", &self.region, "/");
+        request.set_operation("RegisterTaskDefinition");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -7401,55 +8839,104 @@ impl Ecs for EcsClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `run_task`, also returning the metadata of the response.
+    pub async fn run_task_with_metadata(
+        &self,
+        input: RunTaskRequest,
+    ) -> Result<(RunTaskResponse, ResponseMetadata), RusotoError<RunTaskError>> {
+        let request = self.build_run_task_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<RegisterTaskDefinitionResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result =
+                proto::json::ResponsePayload::new(&response).deserialize::<RunTaskResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(RegisterTaskDefinitionError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(RunTaskError::from_response(response))
         }
     }
 
-    /// <p><p>Starts a new task using the specified task definition.</p> <p>You can allow Amazon ECS to place tasks for you, or you can customize how Amazon ECS places tasks using placement constraints and placement strategies. For more information, see <a href="https://docs.aws.amazon.com/AmazonECS/latest/developerguide/scheduling_tasks.html">Scheduling Tasks</a> in the <i>Amazon Elastic Container Service Developer Guide</i>.</p> <p>Alternatively, you can use <a>StartTask</a> to use your own scheduler or place tasks manually on specific container instances.</p> <p>The Amazon ECS API follows an eventual consistency model, due to the distributed nature of the system supporting the API. This means that the result of an API command you run that affects your Amazon ECS resources might not be immediately visible to all subsequent commands you run. Keep this in mind when you carry out an API command that immediately follows a previous API command.</p> <p>To manage eventual consistency, you can do the following:</p> <ul> <li> <p>Confirm the state of the resource before you run a command to modify it. Run the DescribeTasks command using an exponential backoff algorithm to ensure that you allow enough time for the previous command to propagate through the system. To do this, run the DescribeTasks command repeatedly, starting with a couple of seconds of wait time and increasing gradually up to five minutes of wait time.</p> </li> <li> <p>Add wait time between subsequent commands, even if the DescribeTasks command returns an accurate response. Apply an exponential backoff algorithm starting with a couple of seconds of wait time, and increase gradually up to about five minutes of wait time.</p> </li> </ul></p>
-    async fn run_task(
+    /// Presigns the request `run_task` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_run_task(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: RunTaskRequest,
-    ) -> Result<RunTaskResponse, RusotoError<RunTaskError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_run_task_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_run_task_request(&self, input: RunTaskRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "ecs", &self.region, "/");
+        request.set_operation("RunTask");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonEC2ContainerServiceV20141113.RunTask");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `start_task`, also returning the metadata of the response.
+    pub async fn start_task_with_metadata(
+        &self,
+        input: StartTaskRequest,
+    ) -> Result<(StartTaskResponse, ResponseMetadata), RusotoError<StartTaskError>> {
+        let request = self.build_start_task_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<RunTaskResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<StartTaskResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(RunTaskError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(StartTaskError::from_response(response))
         }
     }
 
-    /// <p>Starts a new task from the specified task definition on the specified container instance or instances.</p> <p>Alternatively, you can use <a>RunTask</a> to place tasks for you. For more information, see <a href="https://docs.aws.amazon.com/AmazonECS/latest/developerguide/scheduling_tasks.html">Scheduling Tasks</a> in the <i>Amazon Elastic Container Service Developer Guide</i>.</p>
-    async fn start_task(
+    /// Presigns the request `start_task` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_start_task(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: StartTaskRequest,
-    ) -> Result<StartTaskResponse, RusotoError<StartTaskError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_start_task_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_start_task_request(&self, input: StartTaskRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "ecs", &self.region, "/");
+        request.set_operation("StartTask");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -7459,27 +8946,52 @@ impl Ecs for EcsClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `stop_task`, also returning the metadata of the response.
+    pub async fn stop_task_with_metadata(
+        &self,
+        input: StopTaskRequest,
+    ) -> Result<(StopTaskResponse, ResponseMetadata), RusotoError<StopTaskError>> {
+        let request = self.build_stop_task_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<StartTaskResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<StopTaskResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(StartTaskError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(StopTaskError::from_response(response))
         }
     }
 
-    /// <p><p>Stops a running task. Any tags associated with the task will be deleted.</p> <p>When <a>StopTask</a> is called on a task, the equivalent of <code>docker stop</code> is issued to the containers running in the task. This results in a <code>SIGTERM</code> value and a default 30-second timeout, after which the <code>SIGKILL</code> value is sent and the containers are forcibly stopped. If the container handles the <code>SIGTERM</code> value gracefully and exits within 30 seconds from receiving it, no <code>SIGKILL</code> value is sent.</p> <note> <p>The default 30-second timeout can be configured on the Amazon ECS container agent with the <code>ECS<em>CONTAINER</em>STOP_TIMEOUT</code> variable. For more information, see <a href="https://docs.aws.amazon.com/AmazonECS/latest/developerguide/ecs-agent-config.html">Amazon ECS Container Agent Configuration</a> in the <i>Amazon Elastic Container Service Developer Guide</i>.</p> </note></p>
-    async fn stop_task(
+    /// Presigns the request `stop_task` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_stop_task(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: StopTaskRequest,
-    ) -> Result<StopTaskResponse, RusotoError<StopTaskError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_stop_task_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_stop_task_request(&self, input: StopTaskRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "ecs", &self.region, "/");
+        request.set_operation("StopTask");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -7489,28 +9001,58 @@ impl Ecs for EcsClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `submit_attachment_state_changes`, also returning the metadata of the response.
+    pub async fn submit_attachment_state_changes_with_metadata(
+        &self,
+        input: SubmitAttachmentStateChangesRequest,
+    ) -> Result<
+        (SubmitAttachmentStateChangesResponse, ResponseMetadata),
+        RusotoError<SubmitAttachmentStateChangesError>,
+    > {
+        let request = self.build_submit_attachment_state_changes_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<StopTaskResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<SubmitAttachmentStateChangesResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(StopTaskError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(SubmitAttachmentStateChangesError::from_response(response))
         }
     }
 
-    /// <p><note> <p>This action is only used by the Amazon ECS agent, and it is not intended for use outside of the agent.</p> </note> <p>Sent to acknowledge that an attachment changed states.</p></p>
-    async fn submit_attachment_state_changes(
+    /// Presigns the request `submit_attachment_state_changes` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_submit_attachment_state_changes(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: SubmitAttachmentStateChangesRequest,
-    ) -> Result<SubmitAttachmentStateChangesResponse, RusotoError<SubmitAttachmentStateChangesError>>
-    {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_submit_attachment_state_changes_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_submit_attachment_state_changes_request(
+        &self,
+        input: SubmitAttachmentStateChangesRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "ecs", &self.region, "/");
+        request.set_operation("SubmitAttachmentStateChanges");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -7520,29 +9062,58 @@ impl Ecs for EcsClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `submit_container_state_change`, also returning the metadata of the response.
+    pub async fn submit_container_state_change_with_metadata(
+        &self,
+        input: SubmitContainerStateChangeRequest,
+    ) -> Result<
+        (SubmitContainerStateChangeResponse, ResponseMetadata),
+        RusotoError<SubmitContainerStateChangeError>,
+    > {
+        let request = self.build_submit_container_state_change_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<SubmitAttachmentStateChangesResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<SubmitContainerStateChangeResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(SubmitAttachmentStateChangesError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(SubmitContainerStateChangeError::from_response(response))
         }
     }
 
-    /// <p><note> <p>This action is only used by the Amazon ECS agent, and it is not intended for use outside of the agent.</p> </note> <p>Sent to acknowledge that a container changed states.</p></p>
-    async fn submit_container_state_change(
+    /// Presigns the request `submit_container_state_change` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_submit_container_state_change(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: SubmitContainerStateChangeRequest,
-    ) -> Result<SubmitContainerStateChangeResponse, RusotoError<SubmitContainerStateChangeError>>
-    {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_submit_container_state_change_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_submit_container_state_change_request(
+        &self,
+        input: SubmitContainerStateChangeRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "ecs", &self.region, "/");
+        request.set_operation("SubmitContainerStateChange");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -7552,28 +9123,58 @@ impl Ecs for EcsClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `submit_task_state_change`, also returning the metadata of the response.
+    pub async fn submit_task_state_change_with_metadata(
+        &self,
+        input: SubmitTaskStateChangeRequest,
+    ) -> Result<
+        (SubmitTaskStateChangeResponse, ResponseMetadata),
+        RusotoError<SubmitTaskStateChangeError>,
+    > {
+        let request = self.build_submit_task_state_change_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<SubmitContainerStateChangeResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<SubmitTaskStateChangeResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(SubmitContainerStateChangeError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(SubmitTaskStateChangeError::from_response(response))
         }
     }
 
-    /// <p><note> <p>This action is only used by the Amazon ECS agent, and it is not intended for use outside of the agent.</p> </note> <p>Sent to acknowledge that a task changed states.</p></p>
-    async fn submit_task_state_change(
+    /// Presigns the request `submit_task_state_change` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_submit_task_state_change(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: SubmitTaskStateChangeRequest,
-    ) -> Result<SubmitTaskStateChangeResponse, RusotoError<SubmitTaskStateChangeError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_submit_task_state_change_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_submit_task_state_change_request(
+        &self,
+        input: SubmitTaskStateChangeRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "ecs", &self.region, "/");
+        request.set_operation("SubmitTaskStateChange");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -7583,28 +9184,52 @@ impl Ecs for EcsClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `tag_resource`, also returning the metadata of the response.
+    pub async fn tag_resource_with_metadata(
+        &self,
+        input: TagResourceRequest,
+    ) -> Result<(TagResourceResponse, ResponseMetadata), RusotoError<TagResourceError>> {
+        let request = self.build_tag_resource_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<SubmitTaskStateChangeResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<TagResourceResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(SubmitTaskStateChangeError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(TagResourceError::from_response(response))
         }
     }
 
-    /// <p>Associates the specified tags to a resource with the specified <code>resourceArn</code>. If existing tags on a resource are not specified in the request parameters, they are not changed. When a resource is deleted, the tags associated with that resource are deleted as well.</p>
-    async fn tag_resource(
+    /// Presigns the request `tag_resource` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_tag_resource(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: TagResourceRequest,
-    ) -> Result<TagResourceResponse, RusotoError<TagResourceError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_tag_resource_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_tag_resource_request(&self, input: TagResourceRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "ecs", &self.region, "/");
+        request.set_operation("TagResource");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -7614,27 +9239,52 @@ impl Ecs for EcsClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `untag_resource`, also returning the metadata of the response.
+    pub async fn untag_resource_with_metadata(
+        &self,
+        input: UntagResourceRequest,
+    ) -> Result<(UntagResourceResponse, ResponseMetadata), RusotoError<UntagResourceError>> {
+        let request = self.build_untag_resource_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<TagResourceResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<UntagResourceResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(TagResourceError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(UntagResourceError::from_response(response))
         }
     }
 
-    /// <p>Deletes specified tags from a resource.</p>
-    async fn untag_resource(
+    /// Presigns the request `untag_resource` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_untag_resource(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UntagResourceRequest,
-    ) -> Result<UntagResourceResponse, RusotoError<UntagResourceError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_untag_resource_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_untag_resource_request(&self, input: UntagResourceRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "ecs", &self.region, "/");
+        request.set_operation("UntagResource");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -7644,27 +9294,58 @@ impl Ecs for EcsClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
-        let mut response = self
-            .client
-            .sign_and_dispatch(request)
-            .await
+        request
+    }
+
+    /// Calls `update_cluster_settings`, also returning the metadata of the response.
+    pub async fn update_cluster_settings_with_metadata(
+        &self,
+        input: UpdateClusterSettingsRequest,
+    ) -> Result<
+        (UpdateClusterSettingsResponse, ResponseMetadata),
+        RusotoError<UpdateClusterSettingsError>,
+    > {
+        let request = self.build_update_cluster_settings_request(input);
+
+        let mut response = self
+            .client
+            .sign_and_dispatch(request)
+            .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<UntagResourceResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<UpdateClusterSettingsResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(UntagResourceError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(UpdateClusterSettingsError::from_response(response))
         }
     }
 
-    /// <p>Modifies the settings to use for a cluster.</p>
-    async fn update_cluster_settings(
+    /// Presigns the request `update_cluster_settings` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_cluster_settings(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UpdateClusterSettingsRequest,
-    ) -> Result<UpdateClusterSettingsResponse, RusotoError<UpdateClusterSettingsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_cluster_settings_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_cluster_settings_request(
+        &self,
+        input: UpdateClusterSettingsRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "ecs", &self.region, "/");
+        request.set_operation("UpdateClusterSettings");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -7674,28 +9355,58 @@ impl Ecs for EcsClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `update_container_agent`, also returning the metadata of the response.
+    pub async fn update_container_agent_with_metadata(
+        &self,
+        input: UpdateContainerAgentRequest,
+    ) -> Result<
+        (UpdateContainerAgentResponse, ResponseMetadata),
+        RusotoError<UpdateContainerAgentError>,
+    > {
+        let request = self.build_update_container_agent_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<UpdateClusterSettingsResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<UpdateContainerAgentResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(UpdateClusterSettingsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(UpdateContainerAgentError::from_response(response))
         }
     }
 
-    /// <p>Updates the Amazon ECS container agent on a specified container instance. Updating the Amazon ECS container agent does not interrupt running tasks or services on the container instance. The process for updating the agent differs depending on whether your container instance was launched with the Amazon ECS-optimized AMI or another operating system.</p> <p> <code>UpdateContainerAgent</code> requires the Amazon ECS-optimized AMI or Amazon Linux with the <code>ecs-init</code> service installed and running. For help updating the Amazon ECS container agent on other operating systems, see <a href="https://docs.aws.amazon.com/AmazonECS/latest/developerguide/ecs-agent-update.html#manually_update_agent">Manually Updating the Amazon ECS Container Agent</a> in the <i>Amazon Elastic Container Service Developer Guide</i>.</p>
-    async fn update_container_agent(
+    /// Presigns the request `update_container_agent` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_container_agent(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UpdateContainerAgentRequest,
-    ) -> Result<UpdateContainerAgentResponse, RusotoError<UpdateContainerAgentError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_container_agent_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_container_agent_request(
+        &self,
+        input: UpdateContainerAgentRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "ecs", &self.region, "/");
+        request.set_operation("UpdateContainerAgent");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -7705,31 +9416,58 @@ impl Ecs for EcsClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `update_container_instances_state`, also returning the metadata of the response.
+    pub async fn update_container_instances_state_with_metadata(
+        &self,
+        input: UpdateContainerInstancesStateRequest,
+    ) -> Result<
+        (UpdateContainerInstancesStateResponse, ResponseMetadata),
+        RusotoError<UpdateContainerInstancesStateError>,
+    > {
+        let request = self.build_update_container_instances_state_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<UpdateContainerAgentResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<UpdateContainerInstancesStateResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(UpdateContainerAgentError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(UpdateContainerInstancesStateError::from_response(response))
         }
     }
 
-    /// <p>Modifies the status of an Amazon ECS container instance.</p> <p>Once a container instance has reached an <code>ACTIVE</code> state, you can change the status of a container instance to <code>DRAINING</code> to manually remove an instance from a cluster, for example to perform system updates, update the Docker daemon, or scale down the cluster size.</p> <important> <p>A container instance cannot be changed to <code>DRAINING</code> until it has reached an <code>ACTIVE</code> status. If the instance is in any other status, an error will be received.</p> </important> <p>When you set a container instance to <code>DRAINING</code>, Amazon ECS prevents new tasks from being scheduled for placement on the container instance and replacement service tasks are started on other container instances in the cluster if the resources are available. Service tasks on the container instance that are in the <code>PENDING</code> state are stopped immediately.</p> <p>Service tasks on the container instance that are in the <code>RUNNING</code> state are stopped and replaced according to the service's deployment configuration parameters, <code>minimumHealthyPercent</code> and <code>maximumPercent</code>. You can change the deployment configuration of your service using <a>UpdateService</a>.</p> <ul> <li> <p>If <code>minimumHealthyPercent</code> is below 100%, the scheduler can ignore <code>desiredCount</code> temporarily during task replacement. For example, <code>desiredCount</code> is four tasks, a minimum of 50% allows the scheduler to stop two existing tasks before starting two new tasks. If the minimum is 100%, the service scheduler can't remove existing tasks until the replacement tasks are considered healthy. Tasks for services that do not use a load balancer are considered healthy if they are in the <code>RUNNING</code> state. Tasks for services that use a load balancer are considered healthy if they are in the <code>RUNNING</code> state and the container instance they are hosted on is reported as healthy by the load balancer.</p> </li> <li> <p>The <code>maximumPercent</code> parameter represents an upper limit on the number of running tasks during task replacement, which enables you to define the replacement batch size. For example, if <code>desiredCount</code> is four tasks, a maximum of 200% starts four new tasks before stopping the four tasks to be drained, provided that the cluster resources required to do this are available. If the maximum is 100%, then replacement tasks can't start until the draining tasks have stopped.</p> </li> </ul> <p>Any <code>PENDING</code> or <code>RUNNING</code> tasks that do not belong to a service are not affected. You must wait for them to finish or stop them manually.</p> <p>A container instance has completed draining when it has no more <code>RUNNING</code> tasks. You can verify this using <a>ListTasks</a>.</p> <p>When a container instance has been drained, you can set a container instance to <code>ACTIVE</code> status and once it has reached that status the Amazon ECS scheduler can begin scheduling tasks on the instance again.</p>
-    async fn update_container_instances_state(
+    /// Presigns the request `update_container_instances_state` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_container_instances_state(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UpdateContainerInstancesStateRequest,
-    ) -> Result<
-        UpdateContainerInstancesStateResponse,
-        RusotoError<UpdateContainerInstancesStateError>,
-    > {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_container_instances_state_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_container_instances_state_request(
+        &self,
+        input: UpdateContainerInstancesStateRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "ecs", &self.region, "/");
+        request.set_operation("UpdateContainerInstancesState");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -7739,28 +9477,52 @@ impl Ecs for EcsClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `update_service`, also returning the metadata of the response.
+    pub async fn update_service_with_metadata(
+        &self,
+        input: UpdateServiceRequest,
+    ) -> Result<(UpdateServiceResponse, ResponseMetadata), RusotoError<UpdateServiceError>> {
+        let request = self.build_update_service_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<UpdateContainerInstancesStateResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<UpdateServiceResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(UpdateContainerInstancesStateError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(UpdateServiceError::from_response(response))
         }
     }
 
-    /// <p><p>Modifies the parameters of a service.</p> <p>For services using the rolling update (<code>ECS</code>) deployment controller, the desired count, deployment configuration, network configuration, or task definition used can be updated.</p> <p>For services using the blue/green (<code>CODE<em>DEPLOY</code>) deployment controller, only the desired count, deployment configuration, and health check grace period can be updated using this API. If the network configuration, platform version, or task definition need to be updated, a new AWS CodeDeploy deployment should be created. For more information, see &lt;a href=&quot;https://docs.aws.amazon.com/codedeploy/latest/APIReference/API</em>CreateDeployment.html&quot;&gt;CreateDeployment</a> in the <i>AWS CodeDeploy API Reference</i>.</p> <p>For services using an external deployment controller, you can update only the desired count and health check grace period using this API. If the launch type, load balancer, network configuration, platform version, or task definition need to be updated, you should create a new task set. For more information, see <a>CreateTaskSet</a>.</p> <p>You can add to or subtract from the number of instantiations of a task definition in a service by specifying the cluster that the service is running in and a new <code>desiredCount</code> parameter.</p> <p>If you have updated the Docker image of your application, you can create a new task definition with that image and deploy it to your service. The service scheduler uses the minimum healthy percent and maximum percent parameters (in the service&#39;s deployment configuration) to determine the deployment strategy.</p> <note> <p>If your updated Docker image uses the same tag as what is in the existing task definition for your service (for example, <code>my_image:latest</code>), you do not need to create a new revision of your task definition. You can update the service using the <code>forceNewDeployment</code> option. The new tasks launched by the deployment pull the current image/tag combination from your repository when they start.</p> </note> <p>You can also update the deployment configuration of a service. When a deployment is triggered by updating the task definition of a service, the service scheduler uses the deployment configuration parameters, <code>minimumHealthyPercent</code> and <code>maximumPercent</code>, to determine the deployment strategy.</p> <ul> <li> <p>If <code>minimumHealthyPercent</code> is below 100%, the scheduler can ignore <code>desiredCount</code> temporarily during a deployment. For example, if <code>desiredCount</code> is four tasks, a minimum of 50% allows the scheduler to stop two existing tasks before starting two new tasks. Tasks for services that do not use a load balancer are considered healthy if they are in the <code>RUNNING</code> state. Tasks for services that use a load balancer are considered healthy if they are in the <code>RUNNING</code> state and the container instance they are hosted on is reported as healthy by the load balancer.</p> </li> <li> <p>The <code>maximumPercent</code> parameter represents an upper limit on the number of running tasks during a deployment, which enables you to define the deployment batch size. For example, if <code>desiredCount</code> is four tasks, a maximum of 200% starts four new tasks before stopping the four older tasks (provided that the cluster resources required to do this are available).</p> </li> </ul> <p>When <a>UpdateService</a> stops a task during a deployment, the equivalent of <code>docker stop</code> is issued to the containers running in the task. This results in a <code>SIGTERM</code> and a 30-second timeout, after which <code>SIGKILL</code> is sent and the containers are forcibly stopped. If the container handles the <code>SIGTERM</code> gracefully and exits within 30 seconds from receiving it, no <code>SIGKILL</code> is sent.</p> <p>When the service scheduler launches new tasks, it determines task placement in your cluster with the following logic:</p> <ul> <li> <p>Determine which of the container instances in your cluster can support your service&#39;s task definition (for example, they have the required CPU, memory, ports, and container instance attributes).</p> </li> <li> <p>By default, the service scheduler attempts to balance tasks across Availability Zones in this manner (although you can choose a different placement strategy):</p> <ul> <li> <p>Sort the valid container instances by the fewest number of running tasks for this service in the same Availability Zone as the instance. For example, if zone A has one running service task and zones B and C each have zero, valid container instances in either zone B or C are considered optimal for placement.</p> </li> <li> <p>Place the new service task on a valid container instance in an optimal Availability Zone (based on the previous steps), favoring container instances with the fewest number of running tasks for this service.</p> </li> </ul> </li> </ul> <p>When the service scheduler stops running tasks, it attempts to maintain balance across the Availability Zones in your cluster using the following logic: </p> <ul> <li> <p>Sort the container instances by the largest number of running tasks for this service in the same Availability Zone as the instance. For example, if zone A has one running service task and zones B and C each have two, container instances in either zone B or C are considered optimal for termination.</p> </li> <li> <p>Stop the task on a container instance in an optimal Availability Zone (based on the previous steps), favoring container instances with the largest number of running tasks for this service.</p> </li> </ul></p>
-    async fn update_service(
+    /// Presigns the request `update_service` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_service(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UpdateServiceRequest,
-    ) -> Result<UpdateServiceResponse, RusotoError<UpdateServiceError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_service_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_service_request(&self, input: UpdateServiceRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "ecs", &self.region, "/");
+        request.set_operation("UpdateService");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -7770,28 +9532,58 @@ impl Ecs for EcsClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `update_service_primary_task_set`, also returning the metadata of the response.
+    pub async fn update_service_primary_task_set_with_metadata(
+        &self,
+        input: UpdateServicePrimaryTaskSetRequest,
+    ) -> Result<
+        (UpdateServicePrimaryTaskSetResponse, ResponseMetadata),
+        RusotoError<UpdateServicePrimaryTaskSetError>,
+    > {
+        let request = self.build_update_service_primary_task_set_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<UpdateServiceResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<UpdateServicePrimaryTaskSetResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(UpdateServiceError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(UpdateServicePrimaryTaskSetError::from_response(response))
         }
     }
 
-    /// <p>Modifies which task set in a service is the primary task set. Any parameters that are updated on the primary task set in a service will transition to the service. This is used when a service uses the <code>EXTERNAL</code> deployment controller type. For more information, see <a href="https://docs.aws.amazon.com/AmazonECS/latest/developerguide/deployment-types.html">Amazon ECS Deployment Types</a> in the <i>Amazon Elastic Container Service Developer Guide</i>.</p>
-    async fn update_service_primary_task_set(
+    /// Presigns the request `update_service_primary_task_set` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_service_primary_task_set(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UpdateServicePrimaryTaskSetRequest,
-    ) -> Result<UpdateServicePrimaryTaskSetResponse, RusotoError<UpdateServicePrimaryTaskSetError>>
-    {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_service_primary_task_set_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_service_primary_task_set_request(
+        &self,
+        input: UpdateServicePrimaryTaskSetRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "ecs", &self.region, "/");
+        request.set_operation("UpdateServicePrimaryTaskSet");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -7801,28 +9593,52 @@ impl Ecs for EcsClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `update_task_set`, also returning the metadata of the response.
+    pub async fn update_task_set_with_metadata(
+        &self,
+        input: UpdateTaskSetRequest,
+    ) -> Result<(UpdateTaskSetResponse, ResponseMetadata), RusotoError<UpdateTaskSetError>> {
+        let request = self.build_update_task_set_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<UpdateServicePrimaryTaskSetResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<UpdateTaskSetResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(UpdateServicePrimaryTaskSetError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(UpdateTaskSetError::from_response(response))
         }
     }
 
-    /// <p>Modifies a task set. This is used when a service uses the <code>EXTERNAL</code> deployment controller type. For more information, see <a href="https://docs.aws.amazon.com/AmazonECS/latest/developerguide/deployment-types.html">Amazon ECS Deployment Types</a> in the <i>Amazon Elastic Container Service Developer Guide</i>.</p>
-    async fn update_task_set(
+    /// Presigns the request `update_task_set` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_task_set(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UpdateTaskSetRequest,
-    ) -> Result<UpdateTaskSetResponse, RusotoError<UpdateTaskSetError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_task_set_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_task_set_request(&self, input: UpdateTaskSetRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "ecs", &self.region, "/");
+        request.set_operation("UpdateTaskSet");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -7832,18 +9648,935 @@ impl Ecs for EcsClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
-        let mut response = self
-            .client
-            .sign_and_dispatch(request)
+        request
+    }
+
+    /// The pages of `ListAccountSettings` results for `input`, requesting each next page with the `nextToken` of the last one as its `nextToken`.
+    /// The page size is the `maxResults` of `input`.
+    pub fn list_account_settings_pages(
+        &self,
+        input: ListAccountSettingsRequest,
+    ) -> impl futures::Stream<
+        Item = Result<ListAccountSettingsResponse, RusotoError<ListAccountSettingsError>>,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: EcsClient, input| async move { client.list_account_settings(input).await },
+            |input: &ListAccountSettingsRequest, output: &ListAccountSettingsResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListAccountSettingsRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `settings` of every page of `ListAccountSettings` results for `input`, as `list_account_settings_pages` requests them.
+    pub fn list_account_settings_stream(
+        &self,
+        input: ListAccountSettingsRequest,
+    ) -> impl futures::Stream<Item = Result<Setting, RusotoError<ListAccountSettingsError>>> + Send
+    {
+        rusoto_core::pagination::items(self.list_account_settings_pages(input), |page| {
+            page.settings.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `ListAttributes` results for `input`, requesting each next page with the `nextToken` of the last one as its `nextToken`.
+    /// The page size is the `maxResults` of `input`.
+    pub fn list_attributes_pages(
+        &self,
+        input: ListAttributesRequest,
+    ) -> impl futures::Stream<Item = Result<ListAttributesResponse, RusotoError<ListAttributesError>>>
+           + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: EcsClient, input| async move { client.list_attributes(input).await },
+            |input: &ListAttributesRequest, output: &ListAttributesResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListAttributesRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `attributes` of every page of `ListAttributes` results for `input`, as `list_attributes_pages` requests them.
+    pub fn list_attributes_stream(
+        &self,
+        input: ListAttributesRequest,
+    ) -> impl futures::Stream<Item = Result<Attribute, RusotoError<ListAttributesError>>> + Send
+    {
+        rusoto_core::pagination::items(self.list_attributes_pages(input), |page| {
+            page.attributes.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `ListClusters` results for `input`, requesting each next page with the `nextToken` of the last one as its `nextToken`.
+    /// The page size is the `maxResults` of `input`.
+    pub fn list_clusters_pages(
+        &self,
+        input: ListClustersRequest,
+    ) -> impl futures::Stream<Item = Result<ListClustersResponse, RusotoError<ListClustersError>>> + Send
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: EcsClient, input| async move { client.list_clusters(input).await },
+            |input: &ListClustersRequest, output: &ListClustersResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListClustersRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `clusterArns` of every page of `ListClusters` results for `input`, as `list_clusters_pages` requests them.
+    pub fn list_clusters_stream(
+        &self,
+        input: ListClustersRequest,
+    ) -> impl futures::Stream<Item = Result<String, RusotoError<ListClustersError>>> + Send {
+        rusoto_core::pagination::items(self.list_clusters_pages(input), |page| {
+            page.cluster_arns.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `ListContainerInstances` results for `input`, requesting each next page with the `nextToken` of the last one as its `nextToken`.
+    /// The page size is the `maxResults` of `input`.
+    pub fn list_container_instances_pages(
+        &self,
+        input: ListContainerInstancesRequest,
+    ) -> impl futures::Stream<
+        Item = Result<ListContainerInstancesResponse, RusotoError<ListContainerInstancesError>>,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: EcsClient, input| async move { client.list_container_instances(input).await },
+            |input: &ListContainerInstancesRequest, output: &ListContainerInstancesResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListContainerInstancesRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `containerInstanceArns` of every page of `ListContainerInstances` results for `input`, as `list_container_instances_pages` requests them.
+    pub fn list_container_instances_stream(
+        &self,
+        input: ListContainerInstancesRequest,
+    ) -> impl futures::Stream<Item = Result<String, RusotoError<ListContainerInstancesError>>> + Send
+    {
+        rusoto_core::pagination::items(self.list_container_instances_pages(input), |page| {
+            page.container_instance_arns.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `ListServices` results for `input`, requesting each next page with the `nextToken` of the last one as its `nextToken`.
+    /// The page size is the `maxResults` of `input`.
+    pub fn list_services_pages(
+        &self,
+        input: ListServicesRequest,
+    ) -> impl futures::Stream<Item = Result<ListServicesResponse, RusotoError<ListServicesError>>> + Send
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: EcsClient, input| async move { client.list_services(input).await },
+            |input: &ListServicesRequest, output: &ListServicesResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListServicesRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `serviceArns` of every page of `ListServices` results for `input`, as `list_services_pages` requests them.
+    pub fn list_services_stream(
+        &self,
+        input: ListServicesRequest,
+    ) -> impl futures::Stream<Item = Result<String, RusotoError<ListServicesError>>> + Send {
+        rusoto_core::pagination::items(self.list_services_pages(input), |page| {
+            page.service_arns.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `ListTaskDefinitionFamilies` results for `input`, requesting each next page with the `nextToken` of the last one as its `nextToken`.
+    /// The page size is the `maxResults` of `input`.
+    pub fn list_task_definition_families_pages(
+        &self,
+        input: ListTaskDefinitionFamiliesRequest,
+    ) -> impl futures::Stream<
+        Item = Result<
+            ListTaskDefinitionFamiliesResponse,
+            RusotoError<ListTaskDefinitionFamiliesError>,
+        >,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: EcsClient, input| async move { client.list_task_definition_families(input).await },
+            |input: &ListTaskDefinitionFamiliesRequest,
+             output: &ListTaskDefinitionFamiliesResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListTaskDefinitionFamiliesRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `families` of every page of `ListTaskDefinitionFamilies` results for `input`, as `list_task_definition_families_pages` requests them.
+    pub fn list_task_definition_families_stream(
+        &self,
+        input: ListTaskDefinitionFamiliesRequest,
+    ) -> impl futures::Stream<Item = Result<String, RusotoError<ListTaskDefinitionFamiliesError>>> + Send
+    {
+        rusoto_core::pagination::items(self.list_task_definition_families_pages(input), |page| {
+            page.families.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `ListTaskDefinitions` results for `input`, requesting each next page with the `nextToken` of the last one as its `nextToken`.
+    /// The page size is the `maxResults` of `input`.
+    pub fn list_task_definitions_pages(
+        &self,
+        input: ListTaskDefinitionsRequest,
+    ) -> impl futures::Stream<
+        Item = Result<ListTaskDefinitionsResponse, RusotoError<ListTaskDefinitionsError>>,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: EcsClient, input| async move { client.list_task_definitions(input).await },
+            |input: &ListTaskDefinitionsRequest, output: &ListTaskDefinitionsResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListTaskDefinitionsRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `taskDefinitionArns` of every page of `ListTaskDefinitions` results for `input`, as `list_task_definitions_pages` requests them.
+    pub fn list_task_definitions_stream(
+        &self,
+        input: ListTaskDefinitionsRequest,
+    ) -> impl futures::Stream<Item = Result<String, RusotoError<ListTaskDefinitionsError>>> + Send
+    {
+        rusoto_core::pagination::items(self.list_task_definitions_pages(input), |page| {
+            page.task_definition_arns.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `ListTasks` results for `input`, requesting each next page with the `nextToken` of the last one as its `nextToken`.
+    /// The page size is the `maxResults` of `input`.
+    pub fn list_tasks_pages(
+        &self,
+        input: ListTasksRequest,
+    ) -> impl futures::Stream<Item = Result<ListTasksResponse, RusotoError<ListTasksError>>> + Send
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: EcsClient, input| async move { client.list_tasks(input).await },
+            |input: &ListTasksRequest, output: &ListTasksResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListTasksRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `taskArns` of every page of `ListTasks` results for `input`, as `list_tasks_pages` requests them.
+    pub fn list_tasks_stream(
+        &self,
+        input: ListTasksRequest,
+    ) -> impl futures::Stream<Item = Result<String, RusotoError<ListTasksError>>> + Send {
+        rusoto_core::pagination::items(self.list_tasks_pages(input), |page| {
+            page.task_arns.unwrap_or_default()
+        })
+    }
+
+    /// Waits for `ServicesInactive`, polling `DescribeServices` with `input` every 15 seconds, up to 40 times.
+    pub async fn wait_until_services_inactive(
+        &self,
+        input: DescribeServicesRequest,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<DescribeServicesError>> {
+        let waiter = rusoto_core::waiter::Waiter::new(std::time::Duration::from_secs(15), 40);
+        self.wait_until_services_inactive_with(input, &waiter).await
+    }
+
+    /// Waits for `ServicesInactive` like `wait_until_services_inactive`, with the delay, attempts and runtime of `waiter`.
+    pub async fn wait_until_services_inactive_with(
+        &self,
+        input: DescribeServicesRequest,
+        waiter: &rusoto_core::waiter::Waiter,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<DescribeServicesError>> {
+        rusoto_core::waiter::wait(
+            waiter,
+            || self.describe_services(input.clone()),
+            |result| {
+                if match result {
+                    Ok(output) => std::iter::once(output)
+                        .filter_map(|value| value.failures.as_ref())
+                        .flat_map(|value| value.iter())
+                        .filter_map(|value| value.reason.as_ref())
+                        .any(|value| value == "MISSING"),
+                    Err(_) => false,
+                } {
+                    return Some(rusoto_core::waiter::WaiterState::Failure);
+                }
+                if match result {
+                    Ok(output) => std::iter::once(output)
+                        .filter_map(|value| value.services.as_ref())
+                        .flat_map(|value| value.iter())
+                        .filter_map(|value| value.status.as_ref())
+                        .any(|value| value == "INACTIVE"),
+                    Err(_) => false,
+                } {
+                    return Some(rusoto_core::waiter::WaiterState::Success);
+                }
+                None
+            },
+        )
+        .await
+    }
+
+    /// Waits for `TasksRunning`, polling `DescribeTasks` with `input` every 6 seconds, up to 100 times.
+    pub async fn wait_until_tasks_running(
+        &self,
+        input: DescribeTasksRequest,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<DescribeTasksError>> {
+        let waiter = rusoto_core::waiter::Waiter::new(std::time::Duration::from_secs(6), 100);
+        self.wait_until_tasks_running_with(input, &waiter).await
+    }
+
+    /// Waits for `TasksRunning` like `wait_until_tasks_running`, with the delay, attempts and runtime of `waiter`.
+    pub async fn wait_until_tasks_running_with(
+        &self,
+        input: DescribeTasksRequest,
+        waiter: &rusoto_core::waiter::Waiter,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<DescribeTasksError>> {
+        rusoto_core::waiter::wait(
+            waiter,
+            || self.describe_tasks(input.clone()),
+            |result| {
+                if match result {
+                    Ok(output) => std::iter::once(output)
+                        .filter_map(|value| value.tasks.as_ref())
+                        .flat_map(|value| value.iter())
+                        .filter_map(|value| value.last_status.as_ref())
+                        .any(|value| value == "STOPPED"),
+                    Err(_) => false,
+                } {
+                    return Some(rusoto_core::waiter::WaiterState::Failure);
+                }
+                if match result {
+                    Ok(output) => std::iter::once(output)
+                        .filter_map(|value| value.failures.as_ref())
+                        .flat_map(|value| value.iter())
+                        .filter_map(|value| value.reason.as_ref())
+                        .any(|value| value == "MISSING"),
+                    Err(_) => false,
+                } {
+                    return Some(rusoto_core::waiter::WaiterState::Failure);
+                }
+                if match result {
+                    Ok(output) => {
+                        let mut values = std::iter::once(output)
+                            .filter_map(|value| value.tasks.as_ref())
+                            .flat_map(|value| value.iter())
+                            .filter_map(|value| value.last_status.as_ref())
+                            .peekable();
+                        values.peek().is_some() && values.all(|value| value == "RUNNING")
+                    }
+                    Err(_) => false,
+                } {
+                    return Some(rusoto_core::waiter::WaiterState::Success);
+                }
+                None
+            },
+        )
+        .await
+    }
+
+    /// Waits for `TasksStopped`, polling `DescribeTasks` with `input` every 6 seconds, up to 100 times.
+    pub async fn wait_until_tasks_stopped(
+        &self,
+        input: DescribeTasksRequest,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<DescribeTasksError>> {
+        let waiter = rusoto_core::waiter::Waiter::new(std::time::Duration::from_secs(6), 100);
+        self.wait_until_tasks_stopped_with(input, &waiter).await
+    }
+
+    /// Waits for `TasksStopped` like `wait_until_tasks_stopped`, with the delay, attempts and runtime of `waiter`.
+    pub async fn wait_until_tasks_stopped_with(
+        &self,
+        input: DescribeTasksRequest,
+        waiter: &rusoto_core::waiter::Waiter,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<DescribeTasksError>> {
+        rusoto_core::waiter::wait(
+            waiter,
+            || self.describe_tasks(input.clone()),
+            |result| {
+                if match result {
+                    Ok(output) => {
+                        let mut values = std::iter::once(output)
+                            .filter_map(|value| value.tasks.as_ref())
+                            .flat_map(|value| value.iter())
+                            .filter_map(|value| value.last_status.as_ref())
+                            .peekable();
+                        values.peek().is_some() && values.all(|value| value == "STOPPED")
+                    }
+                    Err(_) => false,
+                } {
+                    return Some(rusoto_core::waiter::WaiterState::Success);
+                }
+                None
+            },
+        )
+        .await
+    }
+}
+
+#[async_trait]
+impl Ecs for EcsClient {
+    /// <p>Creates a new capacity provider. Capacity providers are associated with an Amazon ECS cluster and are used in capacity provider strategies to facilitate cluster auto scaling.</p> <p>Only capacity providers using an Auto Scaling group can be created. Amazon ECS tasks on AWS Fargate use the <code>FARGATE</code> and <code>FARGATE_SPOT</code> capacity providers which are already created and available to all accounts in Regions supported by AWS Fargate.</p>
+    async fn create_capacity_provider(
+        &self,
+        input: CreateCapacityProviderRequest,
+    ) -> Result<CreateCapacityProviderResponse, RusotoError<CreateCapacityProviderError>> {
+        self.create_capacity_provider_with_metadata(input)
             .await
-            .map_err(RusotoError::from)?;
-        if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<UpdateTaskSetResponse, _>()
-        } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(UpdateTaskSetError::from_response(response))
-        }
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Creates a new Amazon ECS cluster. By default, your account receives a <code>default</code> cluster when you launch your first container instance. However, you can create your own cluster with a unique name with the <code>CreateCluster</code> action.</p> <note> <p>When you call the <a>CreateCluster</a> API operation, Amazon ECS attempts to create the Amazon ECS service-linked role for your account so that required resources in other AWS services can be managed on your behalf. However, if the IAM user that makes the call does not have permissions to create the service-linked role, it is not created. For more information, see <a href="https://docs.aws.amazon.com/AmazonECS/latest/developerguide/using-service-linked-roles.html">Using Service-Linked Roles for Amazon ECS</a> in the <i>Amazon Elastic Container Service Developer Guide</i>.</p> </note></p>
+    async fn create_cluster(
+        &self,
+        input: CreateClusterRequest,
+    ) -> Result<CreateClusterResponse, RusotoError<CreateClusterError>> {
+        self.create_cluster_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Runs and maintains a desired number of tasks from a specified task definition. If the number of tasks running in a service drops below the <code>desiredCount</code>, Amazon ECS runs another copy of the task in the specified cluster. To update an existing service, see <a>UpdateService</a>.</p> <p>In addition to maintaining the desired count of tasks in your service, you can optionally run your service behind one or more load balancers. The load balancers distribute traffic across the tasks that are associated with the service. For more information, see <a href="https://docs.aws.amazon.com/AmazonECS/latest/developerguide/service-load-balancing.html">Service Load Balancing</a> in the <i>Amazon Elastic Container Service Developer Guide</i>.</p> <p>Tasks for services that <i>do not</i> use a load balancer are considered healthy if they&#39;re in the <code>RUNNING</code> state. Tasks for services that <i>do</i> use a load balancer are considered healthy if they&#39;re in the <code>RUNNING</code> state and the container instance that they&#39;re hosted on is reported as healthy by the load balancer.</p> <p>There are two service scheduler strategies available:</p> <ul> <li> <p> <code>REPLICA</code> - The replica scheduling strategy places and maintains the desired number of tasks across your cluster. By default, the service scheduler spreads tasks across Availability Zones. You can use task placement strategies and constraints to customize task placement decisions. For more information, see <a href="https://docs.aws.amazon.com/AmazonECS/latest/developerguide/ecs_services.html">Service Scheduler Concepts</a> in the <i>Amazon Elastic Container Service Developer Guide</i>.</p> </li> <li> <p> <code>DAEMON</code> - The daemon scheduling strategy deploys exactly one task on each active container instance that meets all of the task placement constraints that you specify in your cluster. When using this strategy, you don&#39;t need to specify a desired number of tasks, a task placement strategy, or use Service Auto Scaling policies. For more information, see <a href="https://docs.aws.amazon.com/AmazonECS/latest/developerguide/ecs_services.html">Service Scheduler Concepts</a> in the <i>Amazon Elastic Container Service Developer Guide</i>.</p> </li> </ul> <p>You can optionally specify a deployment configuration for your service. The deployment is triggered by changing properties, such as the task definition or the desired count of a service, with an <a>UpdateService</a> operation. The default value for a replica service for <code>minimumHealthyPercent</code> is 100%. The default value for a daemon service for <code>minimumHealthyPercent</code> is 0%.</p> <p>If a service is using the <code>ECS</code> deployment controller, the minimum healthy percent represents a lower limit on the number of tasks in a service that must remain in the <code>RUNNING</code> state during a deployment, as a percentage of the desired number of tasks (rounded up to the nearest integer), and while any container instances are in the <code>DRAINING</code> state if the service contains tasks using the EC2 launch type. This parameter enables you to deploy without using additional cluster capacity. For example, if your service has a desired number of four tasks and a minimum healthy percent of 50%, the scheduler might stop two existing tasks to free up cluster capacity before starting two new tasks. Tasks for services that <i>do not</i> use a load balancer are considered healthy if they&#39;re in the <code>RUNNING</code> state. Tasks for services that <i>do</i> use a load balancer are considered healthy if they&#39;re in the <code>RUNNING</code> state and they&#39;re reported as healthy by the load balancer. The default value for minimum healthy percent is 100%.</p> <p>If a service is using the <code>ECS</code> deployment controller, the <b>maximum percent</b> parameter represents an upper limit on the number of tasks in a service that are allowed in the <code>RUNNING</code> or <code>PENDING</code> state during a deployment, as a percentage of the desired number of tasks (rounded down to the nearest integer), and while any container instances are in the <code>DRAINING</code> state if the service contains tasks using the EC2 launch type. This parameter enables you to define the deployment batch size. For example, if your service has a desired number of four tasks and a maximum percent value of 200%, the scheduler may start four new tasks before stopping the four older tasks (provided that the cluster resources required to do this are available). The default value for maximum percent is 200%.</p> <p>If a service is using either the <code>CODE_DEPLOY</code> or <code>EXTERNAL</code> deployment controller types and tasks that use the EC2 launch type, the <b>minimum healthy percent</b> and <b>maximum percent</b> values are used only to define the lower and upper limit on the number of the tasks in the service that remain in the <code>RUNNING</code> state while the container instances are in the <code>DRAINING</code> state. If the tasks in the service use the Fargate launch type, the minimum healthy percent and maximum percent values aren&#39;t used, although they&#39;re currently visible when describing your service.</p> <p>When creating a service that uses the <code>EXTERNAL</code> deployment controller, you can specify only parameters that aren&#39;t controlled at the task set level. The only required parameter is the service name. You control your services using the <a>CreateTaskSet</a> operation. For more information, see <a href="https://docs.aws.amazon.com/AmazonECS/latest/developerguide/deployment-types.html">Amazon ECS Deployment Types</a> in the <i>Amazon Elastic Container Service Developer Guide</i>.</p> <p>When the service scheduler launches new tasks, it determines task placement in your cluster using the following logic:</p> <ul> <li> <p>Determine which of the container instances in your cluster can support your service&#39;s task definition (for example, they have the required CPU, memory, ports, and container instance attributes).</p> </li> <li> <p>By default, the service scheduler attempts to balance tasks across Availability Zones in this manner (although you can choose a different placement strategy) with the <code>placementStrategy</code> parameter):</p> <ul> <li> <p>Sort the valid container instances, giving priority to instances that have the fewest number of running tasks for this service in their respective Availability Zone. For example, if zone A has one running service task and zones B and C each have zero, valid container instances in either zone B or C are considered optimal for placement.</p> </li> <li> <p>Place the new service task on a valid container instance in an optimal Availability Zone (based on the previous steps), favoring container instances with the fewest number of running tasks for this service.</p> </li> </ul> </li> </ul></p>
+    async fn create_service(
+        &self,
+        input: CreateServiceRequest,
+    ) -> Result<CreateServiceResponse, RusotoError<CreateServiceError>> {
+        self.create_service_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Create a task set in the specified cluster and service. This is used when a service uses the <code>EXTERNAL</code> deployment controller type. For more information, see <a href="https://docs.aws.amazon.com/AmazonECS/latest/developerguide/deployment-types.html">Amazon ECS Deployment Types</a> in the <i>Amazon Elastic Container Service Developer Guide</i>.</p>
+    async fn create_task_set(
+        &self,
+        input: CreateTaskSetRequest,
+    ) -> Result<CreateTaskSetResponse, RusotoError<CreateTaskSetError>> {
+        self.create_task_set_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Disables an account setting for a specified IAM user, IAM role, or the root user for an account.</p>
+    async fn delete_account_setting(
+        &self,
+        input: DeleteAccountSettingRequest,
+    ) -> Result<DeleteAccountSettingResponse, RusotoError<DeleteAccountSettingError>> {
+        self.delete_account_setting_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes one or more custom attributes from an Amazon ECS resource.</p>
+    async fn delete_attributes(
+        &self,
+        input: DeleteAttributesRequest,
+    ) -> Result<DeleteAttributesResponse, RusotoError<DeleteAttributesError>> {
+        self.delete_attributes_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes the specified cluster. The cluster will transition to the <code>INACTIVE</code> state. Clusters with an <code>INACTIVE</code> status may remain discoverable in your account for a period of time. However, this behavior is subject to change in the future, so you should not rely on <code>INACTIVE</code> clusters persisting.</p> <p>You must deregister all container instances from this cluster before you may delete it. You can list the container instances in a cluster with <a>ListContainerInstances</a> and deregister them with <a>DeregisterContainerInstance</a>.</p>
+    async fn delete_cluster(
+        &self,
+        input: DeleteClusterRequest,
+    ) -> Result<DeleteClusterResponse, RusotoError<DeleteClusterError>> {
+        self.delete_cluster_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Deletes a specified service within a cluster. You can delete a service if you have no running tasks in it and the desired task count is zero. If the service is actively maintaining tasks, you cannot delete it, and you must update the service to a desired task count of zero. For more information, see <a>UpdateService</a>.</p> <note> <p>When you delete a service, if there are still running tasks that require cleanup, the service status moves from <code>ACTIVE</code> to <code>DRAINING</code>, and the service is no longer visible in the console or in the <a>ListServices</a> API operation. After all tasks have transitioned to either <code>STOPPING</code> or <code>STOPPED</code> status, the service status moves from <code>DRAINING</code> to <code>INACTIVE</code>. Services in the <code>DRAINING</code> or <code>INACTIVE</code> status can still be viewed with the <a>DescribeServices</a> API operation. However, in the future, <code>INACTIVE</code> services may be cleaned up and purged from Amazon ECS record keeping, and <a>DescribeServices</a> calls on those services return a <code>ServiceNotFoundException</code> error.</p> </note> <important> <p>If you attempt to create a new service with the same name as an existing service in either <code>ACTIVE</code> or <code>DRAINING</code> status, you receive an error.</p> </important></p>
+    async fn delete_service(
+        &self,
+        input: DeleteServiceRequest,
+    ) -> Result<DeleteServiceResponse, RusotoError<DeleteServiceError>> {
+        self.delete_service_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes a specified task set within a service. This is used when a service uses the <code>EXTERNAL</code> deployment controller type. For more information, see <a href="https://docs.aws.amazon.com/AmazonECS/latest/developerguide/deployment-types.html">Amazon ECS Deployment Types</a> in the <i>Amazon Elastic Container Service Developer Guide</i>.</p>
+    async fn delete_task_set(
+        &self,
+        input: DeleteTaskSetRequest,
+    ) -> Result<DeleteTaskSetResponse, RusotoError<DeleteTaskSetError>> {
+        self.delete_task_set_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Deregisters an Amazon ECS container instance from the specified cluster. This instance is no longer available to run tasks.</p> <p>If you intend to use the container instance for some other purpose after deregistration, you should stop all of the tasks running on the container instance before deregistration. That prevents any orphaned tasks from consuming resources.</p> <p>Deregistering a container instance removes the instance from a cluster, but it does not terminate the EC2 instance. If you are finished using the instance, be sure to terminate it in the Amazon EC2 console to stop billing.</p> <note> <p>If you terminate a running container instance, Amazon ECS automatically deregisters the instance from your cluster (stopped container instances or instances with disconnected agents are not automatically deregistered when terminated).</p> </note></p>
+    async fn deregister_container_instance(
+        &self,
+        input: DeregisterContainerInstanceRequest,
+    ) -> Result<DeregisterContainerInstanceResponse, RusotoError<DeregisterContainerInstanceError>>
+    {
+        self.deregister_container_instance_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Deregisters the specified task definition by family and revision. Upon deregistration, the task definition is marked as <code>INACTIVE</code>. Existing tasks and services that reference an <code>INACTIVE</code> task definition continue to run without disruption. Existing services that reference an <code>INACTIVE</code> task definition can still scale up or down by modifying the service&#39;s desired count.</p> <p>You cannot use an <code>INACTIVE</code> task definition to run new tasks or create new services, and you cannot update an existing service to reference an <code>INACTIVE</code> task definition. However, there may be up to a 10-minute window following deregistration where these restrictions have not yet taken effect.</p> <note> <p>At this time, <code>INACTIVE</code> task definitions remain discoverable in your account indefinitely. However, this behavior is subject to change in the future, so you should not rely on <code>INACTIVE</code> task definitions persisting beyond the lifecycle of any associated tasks and services.</p> </note></p>
+    async fn deregister_task_definition(
+        &self,
+        input: DeregisterTaskDefinitionRequest,
+    ) -> Result<DeregisterTaskDefinitionResponse, RusotoError<DeregisterTaskDefinitionError>> {
+        self.deregister_task_definition_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Describes one or more of your capacity providers.</p>
+    async fn describe_capacity_providers(
+        &self,
+        input: DescribeCapacityProvidersRequest,
+    ) -> Result<DescribeCapacityProvidersResponse, RusotoError<DescribeCapacityProvidersError>>
+    {
+        self.describe_capacity_providers_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Describes one or more of your clusters.</p>
+    async fn describe_clusters(
+        &self,
+        input: DescribeClustersRequest,
+    ) -> Result<DescribeClustersResponse, RusotoError<DescribeClustersError>> {
+        self.describe_clusters_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Describes Amazon Elastic Container Service container instances. Returns metadata about registered and remaining resources on each container instance requested.</p>
+    async fn describe_container_instances(
+        &self,
+        input: DescribeContainerInstancesRequest,
+    ) -> Result<DescribeContainerInstancesResponse, RusotoError<DescribeContainerInstancesError>>
+    {
+        self.describe_container_instances_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Describes the specified services running in your cluster.</p>
+    async fn describe_services(
+        &self,
+        input: DescribeServicesRequest,
+    ) -> Result<DescribeServicesResponse, RusotoError<DescribeServicesError>> {
+        self.describe_services_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Describes a task definition. You can specify a <code>family</code> and <code>revision</code> to find information about a specific task definition, or you can simply specify the family to find the latest <code>ACTIVE</code> revision in that family.</p> <note> <p>You can only describe <code>INACTIVE</code> task definitions while an active task or service references them.</p> </note></p>
+    async fn describe_task_definition(
+        &self,
+        input: DescribeTaskDefinitionRequest,
+    ) -> Result<DescribeTaskDefinitionResponse, RusotoError<DescribeTaskDefinitionError>> {
+        self.describe_task_definition_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Describes the task sets in the specified cluster and service. This is used when a service uses the <code>EXTERNAL</code> deployment controller type. For more information, see <a href="https://docs.aws.amazon.com/AmazonECS/latest/developerguide/deployment-types.html">Amazon ECS Deployment Types</a> in the <i>Amazon Elastic Container Service Developer Guide</i>.</p>
+    async fn describe_task_sets(
+        &self,
+        input: DescribeTaskSetsRequest,
+    ) -> Result<DescribeTaskSetsResponse, RusotoError<DescribeTaskSetsError>> {
+        self.describe_task_sets_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Describes a specified task or tasks.</p>
+    async fn describe_tasks(
+        &self,
+        input: DescribeTasksRequest,
+    ) -> Result<DescribeTasksResponse, RusotoError<DescribeTasksError>> {
+        self.describe_tasks_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><note> <p>This action is only used by the Amazon ECS agent, and it is not intended for use outside of the agent.</p> </note> <p>Returns an endpoint for the Amazon ECS agent to poll for updates.</p></p>
+    async fn discover_poll_endpoint(
+        &self,
+        input: DiscoverPollEndpointRequest,
+    ) -> Result<DiscoverPollEndpointResponse, RusotoError<DiscoverPollEndpointError>> {
+        self.discover_poll_endpoint_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Lists the account settings for a specified principal.</p>
+    async fn list_account_settings(
+        &self,
+        input: ListAccountSettingsRequest,
+    ) -> Result<ListAccountSettingsResponse, RusotoError<ListAccountSettingsError>> {
+        self.list_account_settings_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Lists the attributes for Amazon ECS resources within a specified target type and cluster. When you specify a target type and cluster, <code>ListAttributes</code> returns a list of attribute objects, one for each attribute on each resource. You can filter the list of results to a single attribute name to only return results that have that name. You can also filter the results by attribute name and value, for example, to see which container instances in a cluster are running a Linux AMI (<code>ecs.os-type=linux</code>). </p>
+    async fn list_attributes(
+        &self,
+        input: ListAttributesRequest,
+    ) -> Result<ListAttributesResponse, RusotoError<ListAttributesError>> {
+        self.list_attributes_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns a list of existing clusters.</p>
+    async fn list_clusters(
+        &self,
+        input: ListClustersRequest,
+    ) -> Result<ListClustersResponse, RusotoError<ListClustersError>> {
+        self.list_clusters_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns a list of container instances in a specified cluster. You can filter the results of a <code>ListContainerInstances</code> operation with cluster query language statements inside the <code>filter</code> parameter. For more information, see <a href="https://docs.aws.amazon.com/AmazonECS/latest/developerguide/cluster-query-language.html">Cluster Query Language</a> in the <i>Amazon Elastic Container Service Developer Guide</i>.</p>
+    async fn list_container_instances(
+        &self,
+        input: ListContainerInstancesRequest,
+    ) -> Result<ListContainerInstancesResponse, RusotoError<ListContainerInstancesError>> {
+        self.list_container_instances_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Lists the services that are running in a specified cluster.</p>
+    async fn list_services(
+        &self,
+        input: ListServicesRequest,
+    ) -> Result<ListServicesResponse, RusotoError<ListServicesError>> {
+        self.list_services_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>List the tags for an Amazon ECS resource.</p>
+    async fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> Result<ListTagsForResourceResponse, RusotoError<ListTagsForResourceError>> {
+        self.list_tags_for_resource_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns a list of task definition families that are registered to your account (which may include task definition families that no longer have any <code>ACTIVE</code> task definition revisions).</p> <p>You can filter out task definition families that do not contain any <code>ACTIVE</code> task definition revisions by setting the <code>status</code> parameter to <code>ACTIVE</code>. You can also filter the results with the <code>familyPrefix</code> parameter.</p>
+    async fn list_task_definition_families(
+        &self,
+        input: ListTaskDefinitionFamiliesRequest,
+    ) -> Result<ListTaskDefinitionFamiliesResponse, RusotoError<ListTaskDefinitionFamiliesError>>
+    {
+        self.list_task_definition_families_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns a list of task definitions that are registered to your account. You can filter the results by family name with the <code>familyPrefix</code> parameter or by status with the <code>status</code> parameter.</p>
+    async fn list_task_definitions(
+        &self,
+        input: ListTaskDefinitionsRequest,
+    ) -> Result<ListTaskDefinitionsResponse, RusotoError<ListTaskDefinitionsError>> {
+        self.list_task_definitions_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns a list of tasks for a specified cluster. You can filter the results by family name, by a particular container instance, or by the desired status of the task with the <code>family</code>, <code>containerInstance</code>, and <code>desiredStatus</code> parameters.</p> <p>Recently stopped tasks might appear in the returned results. Currently, stopped tasks appear in the returned results for at least one hour. </p>
+    async fn list_tasks(
+        &self,
+        input: ListTasksRequest,
+    ) -> Result<ListTasksResponse, RusotoError<ListTasksError>> {
+        self.list_tasks_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Modifies an account setting. Account settings are set on a per-Region basis.</p> <p>If you change the account setting for the root user, the default settings for all of the IAM users and roles for which no individual account setting has been specified are reset. For more information, see <a href="https://docs.aws.amazon.com/AmazonECS/latest/developerguide/ecs-account-settings.html">Account Settings</a> in the <i>Amazon Elastic Container Service Developer Guide</i>.</p> <p>When <code>serviceLongArnFormat</code>, <code>taskLongArnFormat</code>, or <code>containerInstanceLongArnFormat</code> are specified, the Amazon Resource Name (ARN) and resource ID format of the resource type for a specified IAM user, IAM role, or the root user for an account is affected. The opt-in and opt-out account setting must be set for each Amazon ECS resource separately. The ARN and resource ID format of a resource will be defined by the opt-in status of the IAM user or role that created the resource. You must enable this setting to use Amazon ECS features such as resource tagging.</p> <p>When <code>awsvpcTrunking</code> is specified, the elastic network interface (ENI) limit for any new container instances that support the feature is changed. If <code>awsvpcTrunking</code> is enabled, any new container instances that support the feature are launched have the increased ENI limits available to them. For more information, see <a href="https://docs.aws.amazon.com/AmazonECS/latest/developerguide/container-instance-eni.html">Elastic Network Interface Trunking</a> in the <i>Amazon Elastic Container Service Developer Guide</i>.</p> <p>When <code>containerInsights</code> is specified, the default setting indicating whether CloudWatch Container Insights is enabled for your clusters is changed. If <code>containerInsights</code> is enabled, any new clusters that are created will have Container Insights enabled unless you disable it during cluster creation. For more information, see <a href="https://docs.aws.amazon.com/AmazonECS/latest/developerguide/cloudwatch-container-insights.html">CloudWatch Container Insights</a> in the <i>Amazon Elastic Container Service Developer Guide</i>.</p>
+    async fn put_account_setting(
+        &self,
+        input: PutAccountSettingRequest,
+    ) -> Result<PutAccountSettingResponse, RusotoError<PutAccountSettingError>> {
+        self.put_account_setting_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Modifies an account setting for all IAM users on an account for whom no individual account setting has been specified. Account settings are set on a per-Region basis.</p>
+    async fn put_account_setting_default(
+        &self,
+        input: PutAccountSettingDefaultRequest,
+    ) -> Result<PutAccountSettingDefaultResponse, RusotoError<PutAccountSettingDefaultError>> {
+        self.put_account_setting_default_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Create or update an attribute on an Amazon ECS resource. If the attribute does not exist, it is created. If the attribute exists, its value is replaced with the specified value. To delete an attribute, use <a>DeleteAttributes</a>. For more information, see <a href="https://docs.aws.amazon.com/AmazonECS/latest/developerguide/task-placement-constraints.html#attributes">Attributes</a> in the <i>Amazon Elastic Container Service Developer Guide</i>.</p>
+    async fn put_attributes(
+        &self,
+        input: PutAttributesRequest,
+    ) -> Result<PutAttributesResponse, RusotoError<PutAttributesError>> {
+        self.put_attributes_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Modifies the available capacity providers and the default capacity provider strategy for a cluster.</p> <p>You must specify both the available capacity providers and a default capacity provider strategy for the cluster. If the specified cluster has existing capacity providers associated with it, you must specify all existing capacity providers in addition to any new ones you want to add. Any existing capacity providers associated with a cluster that are omitted from a <a>PutClusterCapacityProviders</a> API call will be disassociated with the cluster. You can only disassociate an existing capacity provider from a cluster if it's not being used by any existing tasks.</p> <p>When creating a service or running a task on a cluster, if no capacity provider or launch type is specified, then the cluster's default capacity provider strategy is used. It is recommended to define a default capacity provider strategy for your cluster, however you may specify an empty array (<code>[]</code>) to bypass defining a default strategy.</p>
+    async fn put_cluster_capacity_providers(
+        &self,
+        input: PutClusterCapacityProvidersRequest,
+    ) -> Result<PutClusterCapacityProvidersResponse, RusotoError<PutClusterCapacityProvidersError>>
+    {
+        self.put_cluster_capacity_providers_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><note> <p>This action is only used by the Amazon ECS agent, and it is not intended for use outside of the agent.</p> </note> <p>Registers an EC2 instance into the specified cluster. This instance becomes available to place containers on.</p></p>
+    async fn register_container_instance(
+        &self,
+        input: RegisterContainerInstanceRequest,
+    ) -> Result<RegisterContainerInstanceResponse, RusotoError<RegisterContainerInstanceError>>
+    {
+        self.register_container_instance_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Registers a new task definition from the supplied <code>family</code> and <code>containerDefinitions</code>. Optionally, you can add data volumes to your containers with the <code>volumes</code> parameter. For more information about task definition parameters and defaults, see <a href="https://docs.aws.amazon.com/AmazonECS/latest/developerguide/task_defintions.html">Amazon ECS Task Definitions</a> in the <i>Amazon Elastic Container Service Developer Guide</i>.</p> <p>You can specify an IAM role for your task with the <code>taskRoleArn</code> parameter. When you specify an IAM role for a task, its containers can then use the latest versions of the AWS CLI or SDKs to make API requests to the AWS services that are specified in the IAM policy associated with the role. For more information, see <a href="https://docs.aws.amazon.com/AmazonECS/latest/developerguide/task-iam-roles.html">IAM Roles for Tasks</a> in the <i>Amazon Elastic Container Service Developer Guide</i>.</p> <p>You can specify a Docker networking mode for the containers in your task definition with the <code>networkMode</code> parameter. The available network modes correspond to those described in <a href="https://docs.docker.com/engine/reference/run/#/network-settings">Network settings</a> in the Docker run reference. If you specify the <code>awsvpc</code> network mode, the task is allocated an elastic network interface, and you must specify a <a>NetworkConfiguration</a> when you create a service or run a task with the task definition. For more information, see <a href="https://docs.aws.amazon.com/AmazonECS/latest/developerguide/task-networking.html">Task Networking</a> in the <i>Amazon Elastic Container Service Developer Guide</i>.</p>
+    async fn register_task_definition(
+        &self,
+        input: RegisterTaskDefinitionRequest,
+    ) -> Result<RegisterTaskDefinitionResponse, RusotoError<RegisterTaskDefinitionError>> {
+        self.register_task_definition_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Starts a new task using the specified task definition.</p> <p>You can allow Amazon ECS to place tasks for you, or you can customize how Amazon ECS places tasks using placement constraints and placement strategies. For more information, see <a href="https://docs.aws.amazon.com/AmazonECS/latest/developerguide/scheduling_tasks.html">Scheduling Tasks</a> in the <i>Amazon Elastic Container Service Developer Guide</i>.</p> <p>Alternatively, you can use <a>StartTask</a> to use your own scheduler or place tasks manually on specific container instances.</p> <p>The Amazon ECS API follows an eventual consistency model, due to the distributed nature of the system supporting the API. This means that the result of an API command you run that affects your Amazon ECS resources might not be immediately visible to all subsequent commands you run. Keep this in mind when you carry out an API command that immediately follows a previous API command.</p> <p>To manage eventual consistency, you can do the following:</p> <ul> <li> <p>Confirm the state of the resource before you run a command to modify it. Run the DescribeTasks command using an exponential backoff algorithm to ensure that you allow enough time for the previous command to propagate through the system. To do this, run the DescribeTasks command repeatedly, starting with a couple of seconds of wait time and increasing gradually up to five minutes of wait time.</p> </li> <li> <p>Add wait time between subsequent commands, even if the DescribeTasks command returns an accurate response. Apply an exponential backoff algorithm starting with a couple of seconds of wait time, and increase gradually up to about five minutes of wait time.</p> </li> </ul></p>
+    async fn run_task(
+        &self,
+        input: RunTaskRequest,
+    ) -> Result<RunTaskResponse, RusotoError<RunTaskError>> {
+        self.run_task_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Starts a new task from the specified task definition on the specified container instance or instances.</p> <p>Alternatively, you can use <a>RunTask</a> to place tasks for you. For more information, see <a href="https://docs.aws.amazon.com/AmazonECS/latest/developerguide/scheduling_tasks.html">Scheduling Tasks</a> in the <i>Amazon Elastic Container Service Developer Guide</i>.</p>
+    async fn start_task(
+        &self,
+        input: StartTaskRequest,
+    ) -> Result<StartTaskResponse, RusotoError<StartTaskError>> {
+        self.start_task_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Stops a running task. Any tags associated with the task will be deleted.</p> <p>When <a>StopTask</a> is called on a task, the equivalent of <code>docker stop</code> is issued to the containers running in the task. This results in a <code>SIGTERM</code> value and a default 30-second timeout, after which the <code>SIGKILL</code> value is sent and the containers are forcibly stopped. If the container handles the <code>SIGTERM</code> value gracefully and exits within 30 seconds from receiving it, no <code>SIGKILL</code> value is sent.</p> <note> <p>The default 30-second timeout can be configured on the Amazon ECS container agent with the <code>ECS<em>CONTAINER</em>STOP_TIMEOUT</code> variable. For more information, see <a href="https://docs.aws.amazon.com/AmazonECS/latest/developerguide/ecs-agent-config.html">Amazon ECS Container Agent Configuration</a> in the <i>Amazon Elastic Container Service Developer Guide</i>.</p> </note></p>
+    async fn stop_task(
+        &self,
+        input: StopTaskRequest,
+    ) -> Result<StopTaskResponse, RusotoError<StopTaskError>> {
+        self.stop_task_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><note> <p>This action is only used by the Amazon ECS agent, and it is not intended for use outside of the agent.</p> </note> <p>Sent to acknowledge that an attachment changed states.</p></p>
+    async fn submit_attachment_state_changes(
+        &self,
+        input: SubmitAttachmentStateChangesRequest,
+    ) -> Result<SubmitAttachmentStateChangesResponse, RusotoError<SubmitAttachmentStateChangesError>>
+    {
+        self.submit_attachment_state_changes_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><note> <p>This action is only used by the Amazon ECS agent, and it is not intended for use outside of the agent.</p> </note> <p>Sent to acknowledge that a container changed states.</p></p>
+    async fn submit_container_state_change(
+        &self,
+        input: SubmitContainerStateChangeRequest,
+    ) -> Result<SubmitContainerStateChangeResponse, RusotoError<SubmitContainerStateChangeError>>
+    {
+        self.submit_container_state_change_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><note> <p>This action is only used by the Amazon ECS agent, and it is not intended for use outside of the agent.</p> </note> <p>Sent to acknowledge that a task changed states.</p></p>
+    async fn submit_task_state_change(
+        &self,
+        input: SubmitTaskStateChangeRequest,
+    ) -> Result<SubmitTaskStateChangeResponse, RusotoError<SubmitTaskStateChangeError>> {
+        self.submit_task_state_change_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Associates the specified tags to a resource with the specified <code>resourceArn</code>. If existing tags on a resource are not specified in the request parameters, they are not changed. When a resource is deleted, the tags associated with that resource are deleted as well.</p>
+    async fn tag_resource(
+        &self,
+        input: TagResourceRequest,
+    ) -> Result<TagResourceResponse, RusotoError<TagResourceError>> {
+        self.tag_resource_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes specified tags from a resource.</p>
+    async fn untag_resource(
+        &self,
+        input: UntagResourceRequest,
+    ) -> Result<UntagResourceResponse, RusotoError<UntagResourceError>> {
+        self.untag_resource_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Modifies the settings to use for a cluster.</p>
+    async fn update_cluster_settings(
+        &self,
+        input: UpdateClusterSettingsRequest,
+    ) -> Result<UpdateClusterSettingsResponse, RusotoError<UpdateClusterSettingsError>> {
+        self.update_cluster_settings_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Updates the Amazon ECS container agent on a specified container instance. Updating the Amazon ECS container agent does not interrupt running tasks or services on the container instance. The process for updating the agent differs depending on whether your container instance was launched with the Amazon ECS-optimized AMI or another operating system.</p> <p> <code>UpdateContainerAgent</code> requires the Amazon ECS-optimized AMI or Amazon Linux with the <code>ecs-init</code> service installed and running. For help updating the Amazon ECS container agent on other operating systems, see <a href="https://docs.aws.amazon.com/AmazonECS/latest/developerguide/ecs-agent-update.html#manually_update_agent">Manually Updating the Amazon ECS Container Agent</a> in the <i>Amazon Elastic Container Service Developer Guide</i>.</p>
+    async fn update_container_agent(
+        &self,
+        input: UpdateContainerAgentRequest,
+    ) -> Result<UpdateContainerAgentResponse, RusotoError<UpdateContainerAgentError>> {
+        self.update_container_agent_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Modifies the status of an Amazon ECS container instance.</p> <p>Once a container instance has reached an <code>ACTIVE</code> state, you can change the status of a container instance to <code>DRAINING</code> to manually remove an instance from a cluster, for example to perform system updates, update the Docker daemon, or scale down the cluster size.</p> <important> <p>A container instance cannot be changed to <code>DRAINING</code> until it has reached an <code>ACTIVE</code> status. If the instance is in any other status, an error will be received.</p> </important> <p>When you set a container instance to <code>DRAINING</code>, Amazon ECS prevents new tasks from being scheduled for placement on the container instance and replacement service tasks are started on other container instances in the cluster if the resources are available. Service tasks on the container instance that are in the <code>PENDING</code> state are stopped immediately.</p> <p>Service tasks on the container instance that are in the <code>RUNNING</code> state are stopped and replaced according to the service's deployment configuration parameters, <code>minimumHealthyPercent</code> and <code>maximumPercent</code>. You can change the deployment configuration of your service using <a>UpdateService</a>.</p> <ul> <li> <p>If <code>minimumHealthyPercent</code> is below 100%, the scheduler can ignore <code>desiredCount</code> temporarily during task replacement. For example, <code>desiredCount</code> is four tasks, a minimum of 50% allows the scheduler to stop two existing tasks before starting two new tasks. If the minimum is 100%, the service scheduler can't remove existing tasks until the replacement tasks are considered healthy. Tasks for services that do not use a load balancer are considered healthy if they are in the <code>RUNNING</code> state. Tasks for services that use a load balancer are considered healthy if they are in the <code>RUNNING</code> state and the container instance they are hosted on is reported as healthy by the load balancer.</p> </li> <li> <p>The <code>maximumPercent</code> parameter represents an upper limit on the number of running tasks during task replacement, which enables you to define the replacement batch size. For example, if <code>desiredCount</code> is four tasks, a maximum of 200% starts four new tasks before stopping the four tasks to be drained, provided that the cluster resources required to do this are available. If the maximum is 100%, then replacement tasks can't start until the draining tasks have stopped.</p> </li> </ul> <p>Any <code>PENDING</code> or <code>RUNNING</code> tasks that do not belong to a service are not affected. You must wait for them to finish or stop them manually.</p> <p>A container instance has completed draining when it has no more <code>RUNNING</code> tasks. You can verify this using <a>ListTasks</a>.</p> <p>When a container instance has been drained, you can set a container instance to <code>ACTIVE</code> status and once it has reached that status the Amazon ECS scheduler can begin scheduling tasks on the instance again.</p>
+    async fn update_container_instances_state(
+        &self,
+        input: UpdateContainerInstancesStateRequest,
+    ) -> Result<
+        UpdateContainerInstancesStateResponse,
+        RusotoError<UpdateContainerInstancesStateError>,
+    > {
+        self.update_container_instances_state_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Modifies the parameters of a service.</p> <p>For services using the rolling update (<code>ECS</code>) deployment controller, the desired count, deployment configuration, network configuration, or task definition used can be updated.</p> <p>For services using the blue/green (<code>CODE<em>DEPLOY</code>) deployment controller, only the desired count, deployment configuration, and health check grace period can be updated using this API. If the network configuration, platform version, or task definition need to be updated, a new AWS CodeDeploy deployment should be created. For more information, see &lt;a href=&quot;https://docs.aws.amazon.com/codedeploy/latest/APIReference/API</em>CreateDeployment.html&quot;&gt;CreateDeployment</a> in the <i>AWS CodeDeploy API Reference</i>.</p> <p>For services using an external deployment controller, you can update only the desired count and health check grace period using this API. If the launch type, load balancer, network configuration, platform version, or task definition need to be updated, you should create a new task set. For more information, see <a>CreateTaskSet</a>.</p> <p>You can add to or subtract from the number of instantiations of a task definition in a service by specifying the cluster that the service is running in and a new <code>desiredCount</code> parameter.</p> <p>If you have updated the Docker image of your application, you can create a new task definition with that image and deploy it to your service. The service scheduler uses the minimum healthy percent and maximum percent parameters (in the service&#39;s deployment configuration) to determine the deployment strategy.</p> <note> <p>If your updated Docker image uses the same tag as what is in the existing task definition for your service (for example, <code>my_image:latest</code>), you do not need to create a new revision of your task definition. You can update the service using the <code>forceNewDeployment</code> option. The new tasks launched by the deployment pull the current image/tag combination from your repository when they start.</p> </note> <p>You can also update the deployment configuration of a service. When a deployment is triggered by updating the task definition of a service, the service scheduler uses the deployment configuration parameters, <code>minimumHealthyPercent</code> and <code>maximumPercent</code>, to determine the deployment strategy.</p> <ul> <li> <p>If <code>minimumHealthyPercent</code> is below 100%, the scheduler can ignore <code>desiredCount</code> temporarily during a deployment. For example, if <code>desiredCount</code> is four tasks, a minimum of 50% allows the scheduler to stop two existing tasks before starting two new tasks. Tasks for services that do not use a load balancer are considered healthy if they are in the <code>RUNNING</code> state. Tasks for services that use a load balancer are considered healthy if they are in the <code>RUNNING</code> state and the container instance they are hosted on is reported as healthy by the load balancer.</p> </li> <li> <p>The <code>maximumPercent</code> parameter represents an upper limit on the number of running tasks during a deployment, which enables you to define the deployment batch size. For example, if <code>desiredCount</code> is four tasks, a maximum of 200% starts four new tasks before stopping the four older tasks (provided that the cluster resources required to do this are available).</p> </li> </ul> <p>When <a>UpdateService</a> stops a task during a deployment, the equivalent of <code>docker stop</code> is issued to the containers running in the task. This results in a <code>SIGTERM</code> and a 30-second timeout, after which <code>SIGKILL</code> is sent and the containers are forcibly stopped. If the container handles the <code>SIGTERM</code> gracefully and exits within 30 seconds from receiving it, no <code>SIGKILL</code> is sent.</p> <p>When the service scheduler launches new tasks, it determines task placement in your cluster with the following logic:</p> <ul> <li> <p>Determine which of the container instances in your cluster can support your service&#39;s task definition (for example, they have the required CPU, memory, ports, and container instance attributes).</p> </li> <li> <p>By default, the service scheduler attempts to balance tasks across Availability Zones in this manner (although you can choose a different placement strategy):</p> <ul> <li> <p>Sort the valid container instances by the fewest number of running tasks for this service in the same Availability Zone as the instance. For example, if zone A has one running service task and zones B and C each have zero, valid container instances in either zone B or C are considered optimal for placement.</p> </li> <li> <p>Place the new service task on a valid container instance in an optimal Availability Zone (based on the previous steps), favoring container instances with the fewest number of running tasks for this service.</p> </li> </ul> </li> </ul> <p>When the service scheduler stops running tasks, it attempts to maintain balance across the Availability Zones in your cluster using the following logic: </p> <ul> <li> <p>Sort the container instances by the largest number of running tasks for this service in the same Availability Zone as the instance. For example, if zone A has one running service task and zones B and C each have two, container instances in either zone B or C are considered optimal for termination.</p> </li> <li> <p>Stop the task on a container instance in an optimal Availability Zone (based on the previous steps), favoring container instances with the largest number of running tasks for this service.</p> </li> </ul></p>
+    async fn update_service(
+        &self,
+        input: UpdateServiceRequest,
+    ) -> Result<UpdateServiceResponse, RusotoError<UpdateServiceError>> {
+        self.update_service_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Modifies which task set in a service is the primary task set. Any parameters that are updated on the primary task set in a service will transition to the service. This is used when a service uses the <code>EXTERNAL</code> deployment controller type. For more information, see <a href="https://docs.aws.amazon.com/AmazonECS/latest/developerguide/deployment-types.html">Amazon ECS Deployment Types</a> in the <i>Amazon Elastic Container Service Developer Guide</i>.</p>
+    async fn update_service_primary_task_set(
+        &self,
+        input: UpdateServicePrimaryTaskSetRequest,
+    ) -> Result<UpdateServicePrimaryTaskSetResponse, RusotoError<UpdateServicePrimaryTaskSetError>>
+    {
+        self.update_service_primary_task_set_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Modifies a task set. This is used when a service uses the <code>EXTERNAL</code> deployment controller type. For more information, see <a href="https://docs.aws.amazon.com/AmazonECS/latest/developerguide/deployment-types.html">Amazon ECS Deployment Types</a> in the <i>Amazon Elastic Container Service Developer Guide</i>.</p>
+    async fn update_task_set(
+        &self,
+        input: UpdateTaskSetRequest,
+    ) -> Result<UpdateTaskSetResponse, RusotoError<UpdateTaskSetError>> {
+        self.update_task_set_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
     }
 }
@@ -16,8 +16,8 @@ use std::fmt;
 use async_trait::async_trait;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
-use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest, ResponseMetadata};
+use rusoto_core::{Client, ErrorMetadata, RusotoError};
 
 use rusoto_core::param::{Params, ServiceParams};
 use rusoto_core::proto;
@@ -693,6 +693,7 @@ pub struct UpdateFileSystemRequest {
 
 /// Errors returned by CreateAccessPoint
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateAccessPointError {
     /// <p>Returned if the access point you are trying to create already exists, with the creation token you provided in the request.</p>
     AccessPointAlreadyExists(String),
@@ -704,6 +705,8 @@ pub enum CreateAccessPointError {
     FileSystemNotFound(String),
     /// <p>Returned if an error occurred on the server side.</p>
     InternalServerError(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateAccessPointError {
@@ -711,30 +714,34 @@ impl CreateAccessPointError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessPointAlreadyExists" => {
-                    return RusotoError::Service(CreateAccessPointError::AccessPointAlreadyExists(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateAccessPointError::AccessPointAlreadyExists(err.msg),
+                        &res,
+                    )
                 }
                 "AccessPointLimitExceeded" => {
-                    return RusotoError::Service(CreateAccessPointError::AccessPointLimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateAccessPointError::AccessPointLimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "BadRequest" => {
-                    return RusotoError::Service(CreateAccessPointError::BadRequest(err.msg))
+                    return RusotoError::service(CreateAccessPointError::BadRequest(err.msg), &res)
                 }
                 "FileSystemNotFound" => {
-                    return RusotoError::Service(CreateAccessPointError::FileSystemNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateAccessPointError::FileSystemNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(CreateAccessPointError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateAccessPointError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreateAccessPointError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -749,12 +756,14 @@ impl fmt::Display for CreateAccessPointError {
             CreateAccessPointError::BadRequest(ref cause) => write!(f, "{}", cause),
             CreateAccessPointError::FileSystemNotFound(ref cause) => write!(f, "{}", cause),
             CreateAccessPointError::InternalServerError(ref cause) => write!(f, "{}", cause),
+            CreateAccessPointError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateAccessPointError {}
 /// Errors returned by CreateFileSystem
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateFileSystemError {
     /// <p>Returned if the request is malformed or contains an error such as an invalid parameter value or a missing required parameter.</p>
     BadRequest(String),
@@ -768,6 +777,8 @@ pub enum CreateFileSystemError {
     InternalServerError(String),
     /// <p>Returned if the throughput mode or amount of provisioned throughput can't be changed because the throughput limit of 1024 MiB/s has been reached.</p>
     ThroughputLimitExceeded(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateFileSystemError {
@@ -775,35 +786,40 @@ impl CreateFileSystemError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequest" => {
-                    return RusotoError::Service(CreateFileSystemError::BadRequest(err.msg))
+                    return RusotoError::service(CreateFileSystemError::BadRequest(err.msg), &res)
                 }
                 "FileSystemAlreadyExists" => {
-                    return RusotoError::Service(CreateFileSystemError::FileSystemAlreadyExists(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateFileSystemError::FileSystemAlreadyExists(err.msg),
+                        &res,
+                    )
                 }
                 "FileSystemLimitExceeded" => {
-                    return RusotoError::Service(CreateFileSystemError::FileSystemLimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateFileSystemError::FileSystemLimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "InsufficientThroughputCapacity" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         CreateFileSystemError::InsufficientThroughputCapacity(err.msg),
+                        &res,
                     )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(CreateFileSystemError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateFileSystemError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "ThroughputLimitExceeded" => {
-                    return RusotoError::Service(CreateFileSystemError::ThroughputLimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateFileSystemError::ThroughputLimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreateFileSystemError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -821,12 +837,14 @@ impl fmt::Display for CreateFileSystemError {
             }
             CreateFileSystemError::InternalServerError(ref cause) => write!(f, "{}", cause),
             CreateFileSystemError::ThroughputLimitExceeded(ref cause) => write!(f, "{}", cause),
+            CreateFileSystemError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateFileSystemError {}
 /// Errors returned by CreateMountTarget
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateMountTargetError {
     /// <p>Returned if the request is malformed or contains an error such as an invalid parameter value or a missing required parameter.</p>
     BadRequest(String),
@@ -852,6 +870,8 @@ pub enum CreateMountTargetError {
     SubnetNotFound(String),
     /// <p><p/></p>
     UnsupportedAvailabilityZone(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateMountTargetError {
@@ -859,61 +879,76 @@ impl CreateMountTargetError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequest" => {
-                    return RusotoError::Service(CreateMountTargetError::BadRequest(err.msg))
+                    return RusotoError::service(CreateMountTargetError::BadRequest(err.msg), &res)
                 }
                 "FileSystemNotFound" => {
-                    return RusotoError::Service(CreateMountTargetError::FileSystemNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateMountTargetError::FileSystemNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "IncorrectFileSystemLifeCycleState" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         CreateMountTargetError::IncorrectFileSystemLifeCycleState(err.msg),
+                        &res,
                     )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(CreateMountTargetError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateMountTargetError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "IpAddressInUse" => {
-                    return RusotoError::Service(CreateMountTargetError::IpAddressInUse(err.msg))
+                    return RusotoError::service(
+                        CreateMountTargetError::IpAddressInUse(err.msg),
+                        &res,
+                    )
                 }
                 "MountTargetConflict" => {
-                    return RusotoError::Service(CreateMountTargetError::MountTargetConflict(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateMountTargetError::MountTargetConflict(err.msg),
+                        &res,
+                    )
                 }
                 "NetworkInterfaceLimitExceeded" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         CreateMountTargetError::NetworkInterfaceLimitExceeded(err.msg),
+                        &res,
                     )
                 }
                 "NoFreeAddressesInSubnet" => {
-                    return RusotoError::Service(CreateMountTargetError::NoFreeAddressesInSubnet(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateMountTargetError::NoFreeAddressesInSubnet(err.msg),
+                        &res,
+                    )
                 }
                 "SecurityGroupLimitExceeded" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         CreateMountTargetError::SecurityGroupLimitExceeded(err.msg),
+                        &res,
                     )
                 }
                 "SecurityGroupNotFound" => {
-                    return RusotoError::Service(CreateMountTargetError::SecurityGroupNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateMountTargetError::SecurityGroupNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "SubnetNotFound" => {
-                    return RusotoError::Service(CreateMountTargetError::SubnetNotFound(err.msg))
+                    return RusotoError::service(
+                        CreateMountTargetError::SubnetNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "UnsupportedAvailabilityZone" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         CreateMountTargetError::UnsupportedAvailabilityZone(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreateMountTargetError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -941,12 +976,14 @@ impl fmt::Display for CreateMountTargetError {
             CreateMountTargetError::UnsupportedAvailabilityZone(ref cause) => {
                 write!(f, "{}", cause)
             }
+            CreateMountTargetError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateMountTargetError {}
 /// Errors returned by CreateTags
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateTagsError {
     /// <p>Returned if the request is malformed or contains an error such as an invalid parameter value or a missing required parameter.</p>
     BadRequest(String),
@@ -954,21 +991,28 @@ pub enum CreateTagsError {
     FileSystemNotFound(String),
     /// <p>Returned if an error occurred on the server side.</p>
     InternalServerError(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateTagsError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<CreateTagsError> {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
-                "BadRequest" => return RusotoError::Service(CreateTagsError::BadRequest(err.msg)),
+                "BadRequest" => {
+                    return RusotoError::service(CreateTagsError::BadRequest(err.msg), &res)
+                }
                 "FileSystemNotFound" => {
-                    return RusotoError::Service(CreateTagsError::FileSystemNotFound(err.msg))
+                    return RusotoError::service(CreateTagsError::FileSystemNotFound(err.msg), &res)
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(CreateTagsError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        CreateTagsError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreateTagsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -981,12 +1025,14 @@ impl fmt::Display for CreateTagsError {
             CreateTagsError::BadRequest(ref cause) => write!(f, "{}", cause),
             CreateTagsError::FileSystemNotFound(ref cause) => write!(f, "{}", cause),
             CreateTagsError::InternalServerError(ref cause) => write!(f, "{}", cause),
+            CreateTagsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateTagsError {}
 /// Errors returned by DeleteAccessPoint
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteAccessPointError {
     /// <p>Returned if the specified <code>AccessPointId</code> value doesn't exist in the requester's AWS account.</p>
     AccessPointNotFound(String),
@@ -994,6 +1040,8 @@ pub enum DeleteAccessPointError {
     BadRequest(String),
     /// <p>Returned if an error occurred on the server side.</p>
     InternalServerError(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteAccessPointError {
@@ -1001,20 +1049,22 @@ impl DeleteAccessPointError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessPointNotFound" => {
-                    return RusotoError::Service(DeleteAccessPointError::AccessPointNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteAccessPointError::AccessPointNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "BadRequest" => {
-                    return RusotoError::Service(DeleteAccessPointError::BadRequest(err.msg))
+                    return RusotoError::service(DeleteAccessPointError::BadRequest(err.msg), &res)
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(DeleteAccessPointError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteAccessPointError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteAccessPointError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1027,12 +1077,14 @@ impl fmt::Display for DeleteAccessPointError {
             DeleteAccessPointError::AccessPointNotFound(ref cause) => write!(f, "{}", cause),
             DeleteAccessPointError::BadRequest(ref cause) => write!(f, "{}", cause),
             DeleteAccessPointError::InternalServerError(ref cause) => write!(f, "{}", cause),
+            DeleteAccessPointError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteAccessPointError {}
 /// Errors returned by DeleteFileSystem
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteFileSystemError {
     /// <p>Returned if the request is malformed or contains an error such as an invalid parameter value or a missing required parameter.</p>
     BadRequest(String),
@@ -1042,6 +1094,8 @@ pub enum DeleteFileSystemError {
     FileSystemNotFound(String),
     /// <p>Returned if an error occurred on the server side.</p>
     InternalServerError(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteFileSystemError {
@@ -1049,21 +1103,28 @@ impl DeleteFileSystemError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequest" => {
-                    return RusotoError::Service(DeleteFileSystemError::BadRequest(err.msg))
+                    return RusotoError::service(DeleteFileSystemError::BadRequest(err.msg), &res)
                 }
                 "FileSystemInUse" => {
-                    return RusotoError::Service(DeleteFileSystemError::FileSystemInUse(err.msg))
+                    return RusotoError::service(
+                        DeleteFileSystemError::FileSystemInUse(err.msg),
+                        &res,
+                    )
                 }
                 "FileSystemNotFound" => {
-                    return RusotoError::Service(DeleteFileSystemError::FileSystemNotFound(err.msg))
+                    return RusotoError::service(
+                        DeleteFileSystemError::FileSystemNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(DeleteFileSystemError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteFileSystemError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteFileSystemError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1077,12 +1138,14 @@ impl fmt::Display for DeleteFileSystemError {
             DeleteFileSystemError::FileSystemInUse(ref cause) => write!(f, "{}", cause),
             DeleteFileSystemError::FileSystemNotFound(ref cause) => write!(f, "{}", cause),
             DeleteFileSystemError::InternalServerError(ref cause) => write!(f, "{}", cause),
+            DeleteFileSystemError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteFileSystemError {}
 /// Errors returned by DeleteFileSystemPolicy
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteFileSystemPolicyError {
     /// <p>Returned if the specified <code>FileSystemId</code> value doesn't exist in the requester's AWS account.</p>
     FileSystemNotFound(String),
@@ -1090,6 +1153,8 @@ pub enum DeleteFileSystemPolicyError {
     IncorrectFileSystemLifeCycleState(String),
     /// <p>Returned if an error occurred on the server side.</p>
     InternalServerError(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteFileSystemPolicyError {
@@ -1097,22 +1162,25 @@ impl DeleteFileSystemPolicyError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "FileSystemNotFound" => {
-                    return RusotoError::Service(DeleteFileSystemPolicyError::FileSystemNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteFileSystemPolicyError::FileSystemNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "IncorrectFileSystemLifeCycleState" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DeleteFileSystemPolicyError::IncorrectFileSystemLifeCycleState(err.msg),
+                        &res,
                     )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(DeleteFileSystemPolicyError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteFileSystemPolicyError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteFileSystemPolicyError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1127,12 +1195,14 @@ impl fmt::Display for DeleteFileSystemPolicyError {
                 write!(f, "{}", cause)
             }
             DeleteFileSystemPolicyError::InternalServerError(ref cause) => write!(f, "{}", cause),
+            DeleteFileSystemPolicyError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteFileSystemPolicyError {}
 /// Errors returned by DeleteMountTarget
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteMountTargetError {
     /// <p>Returned if the request is malformed or contains an error such as an invalid parameter value or a missing required parameter.</p>
     BadRequest(String),
@@ -1142,6 +1212,8 @@ pub enum DeleteMountTargetError {
     InternalServerError(String),
     /// <p>Returned if there is no mount target with the specified ID found in the caller's account.</p>
     MountTargetNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteMountTargetError {
@@ -1149,23 +1221,28 @@ impl DeleteMountTargetError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequest" => {
-                    return RusotoError::Service(DeleteMountTargetError::BadRequest(err.msg))
+                    return RusotoError::service(DeleteMountTargetError::BadRequest(err.msg), &res)
                 }
                 "DependencyTimeout" => {
-                    return RusotoError::Service(DeleteMountTargetError::DependencyTimeout(err.msg))
+                    return RusotoError::service(
+                        DeleteMountTargetError::DependencyTimeout(err.msg),
+                        &res,
+                    )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(DeleteMountTargetError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteMountTargetError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "MountTargetNotFound" => {
-                    return RusotoError::Service(DeleteMountTargetError::MountTargetNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteMountTargetError::MountTargetNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteMountTargetError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1179,12 +1256,14 @@ impl fmt::Display for DeleteMountTargetError {
             DeleteMountTargetError::DependencyTimeout(ref cause) => write!(f, "{}", cause),
             DeleteMountTargetError::InternalServerError(ref cause) => write!(f, "{}", cause),
             DeleteMountTargetError::MountTargetNotFound(ref cause) => write!(f, "{}", cause),
+            DeleteMountTargetError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteMountTargetError {}
 /// Errors returned by DeleteTags
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteTagsError {
     /// <p>Returned if the request is malformed or contains an error such as an invalid parameter value or a missing required parameter.</p>
     BadRequest(String),
@@ -1192,21 +1271,28 @@ pub enum DeleteTagsError {
     FileSystemNotFound(String),
     /// <p>Returned if an error occurred on the server side.</p>
     InternalServerError(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteTagsError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<DeleteTagsError> {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
-                "BadRequest" => return RusotoError::Service(DeleteTagsError::BadRequest(err.msg)),
+                "BadRequest" => {
+                    return RusotoError::service(DeleteTagsError::BadRequest(err.msg), &res)
+                }
                 "FileSystemNotFound" => {
-                    return RusotoError::Service(DeleteTagsError::FileSystemNotFound(err.msg))
+                    return RusotoError::service(DeleteTagsError::FileSystemNotFound(err.msg), &res)
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(DeleteTagsError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        DeleteTagsError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteTagsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1219,12 +1305,14 @@ impl fmt::Display for DeleteTagsError {
             DeleteTagsError::BadRequest(ref cause) => write!(f, "{}", cause),
             DeleteTagsError::FileSystemNotFound(ref cause) => write!(f, "{}", cause),
             DeleteTagsError::InternalServerError(ref cause) => write!(f, "{}", cause),
+            DeleteTagsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteTagsError {}
 /// Errors returned by DescribeAccessPoints
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeAccessPointsError {
     /// <p>Returned if the specified <code>AccessPointId</code> value doesn't exist in the requester's AWS account.</p>
     AccessPointNotFound(String),
@@ -1234,6 +1322,8 @@ pub enum DescribeAccessPointsError {
     FileSystemNotFound(String),
     /// <p>Returned if an error occurred on the server side.</p>
     InternalServerError(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeAccessPointsError {
@@ -1241,25 +1331,31 @@ impl DescribeAccessPointsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessPointNotFound" => {
-                    return RusotoError::Service(DescribeAccessPointsError::AccessPointNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeAccessPointsError::AccessPointNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "BadRequest" => {
-                    return RusotoError::Service(DescribeAccessPointsError::BadRequest(err.msg))
+                    return RusotoError::service(
+                        DescribeAccessPointsError::BadRequest(err.msg),
+                        &res,
+                    )
                 }
                 "FileSystemNotFound" => {
-                    return RusotoError::Service(DescribeAccessPointsError::FileSystemNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeAccessPointsError::FileSystemNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(DescribeAccessPointsError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeAccessPointsError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DescribeAccessPointsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1273,12 +1369,14 @@ impl fmt::Display for DescribeAccessPointsError {
             DescribeAccessPointsError::BadRequest(ref cause) => write!(f, "{}", cause),
             DescribeAccessPointsError::FileSystemNotFound(ref cause) => write!(f, "{}", cause),
             DescribeAccessPointsError::InternalServerError(ref cause) => write!(f, "{}", cause),
+            DescribeAccessPointsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeAccessPointsError {}
 /// Errors returned by DescribeFileSystemPolicy
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeFileSystemPolicyError {
     /// <p>Returned if the specified <code>FileSystemId</code> value doesn't exist in the requester's AWS account.</p>
     FileSystemNotFound(String),
@@ -1286,6 +1384,8 @@ pub enum DescribeFileSystemPolicyError {
     InternalServerError(String),
     /// <p>Returned if the default file system policy is in effect for the EFS file system specified.</p>
     PolicyNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeFileSystemPolicyError {
@@ -1293,22 +1393,25 @@ impl DescribeFileSystemPolicyError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "FileSystemNotFound" => {
-                    return RusotoError::Service(DescribeFileSystemPolicyError::FileSystemNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeFileSystemPolicyError::FileSystemNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DescribeFileSystemPolicyError::InternalServerError(err.msg),
+                        &res,
                     )
                 }
                 "PolicyNotFound" => {
-                    return RusotoError::Service(DescribeFileSystemPolicyError::PolicyNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeFileSystemPolicyError::PolicyNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DescribeFileSystemPolicyError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1321,12 +1424,14 @@ impl fmt::Display for DescribeFileSystemPolicyError {
             DescribeFileSystemPolicyError::FileSystemNotFound(ref cause) => write!(f, "{}", cause),
             DescribeFileSystemPolicyError::InternalServerError(ref cause) => write!(f, "{}", cause),
             DescribeFileSystemPolicyError::PolicyNotFound(ref cause) => write!(f, "{}", cause),
+            DescribeFileSystemPolicyError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeFileSystemPolicyError {}
 /// Errors returned by DescribeFileSystems
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeFileSystemsError {
     /// <p>Returned if the request is malformed or contains an error such as an invalid parameter value or a missing required parameter.</p>
     BadRequest(String),
@@ -1334,6 +1439,8 @@ pub enum DescribeFileSystemsError {
     FileSystemNotFound(String),
     /// <p>Returned if an error occurred on the server side.</p>
     InternalServerError(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeFileSystemsError {
@@ -1341,20 +1448,25 @@ impl DescribeFileSystemsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequest" => {
-                    return RusotoError::Service(DescribeFileSystemsError::BadRequest(err.msg))
+                    return RusotoError::service(
+                        DescribeFileSystemsError::BadRequest(err.msg),
+                        &res,
+                    )
                 }
                 "FileSystemNotFound" => {
-                    return RusotoError::Service(DescribeFileSystemsError::FileSystemNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeFileSystemsError::FileSystemNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(DescribeFileSystemsError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeFileSystemsError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DescribeFileSystemsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1367,12 +1479,14 @@ impl fmt::Display for DescribeFileSystemsError {
             DescribeFileSystemsError::BadRequest(ref cause) => write!(f, "{}", cause),
             DescribeFileSystemsError::FileSystemNotFound(ref cause) => write!(f, "{}", cause),
             DescribeFileSystemsError::InternalServerError(ref cause) => write!(f, "{}", cause),
+            DescribeFileSystemsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeFileSystemsError {}
 /// Errors returned by DescribeLifecycleConfiguration
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeLifecycleConfigurationError {
     /// <p>Returned if the request is malformed or contains an error such as an invalid parameter value or a missing required parameter.</p>
     BadRequest(String),
@@ -1380,6 +1494,8 @@ pub enum DescribeLifecycleConfigurationError {
     FileSystemNotFound(String),
     /// <p>Returned if an error occurred on the server side.</p>
     InternalServerError(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeLifecycleConfigurationError {
@@ -1389,22 +1505,30 @@ impl DescribeLifecycleConfigurationError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequest" => {
-                    return RusotoError::Service(DescribeLifecycleConfigurationError::BadRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeLifecycleConfigurationError::BadRequest(err.msg),
+                        &res,
+                    )
                 }
                 "FileSystemNotFound" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DescribeLifecycleConfigurationError::FileSystemNotFound(err.msg),
+                        &res,
                     )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DescribeLifecycleConfigurationError::InternalServerError(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        DescribeLifecycleConfigurationError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -1421,12 +1545,16 @@ impl fmt::Display for DescribeLifecycleConfigurationError {
             DescribeLifecycleConfigurationError::InternalServerError(ref cause) => {
                 write!(f, "{}", cause)
             }
+            DescribeLifecycleConfigurationError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for DescribeLifecycleConfigurationError {}
 /// Errors returned by DescribeMountTargetSecurityGroups
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeMountTargetSecurityGroupsError {
     /// <p>Returned if the request is malformed or contains an error such as an invalid parameter value or a missing required parameter.</p>
     BadRequest(String),
@@ -1436,6 +1564,8 @@ pub enum DescribeMountTargetSecurityGroupsError {
     InternalServerError(String),
     /// <p>Returned if there is no mount target with the specified ID found in the caller's account.</p>
     MountTargetNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeMountTargetSecurityGroupsError {
@@ -1445,27 +1575,36 @@ impl DescribeMountTargetSecurityGroupsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequest" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DescribeMountTargetSecurityGroupsError::BadRequest(err.msg),
+                        &res,
                     )
                 }
                 "IncorrectMountTargetState" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DescribeMountTargetSecurityGroupsError::IncorrectMountTargetState(err.msg),
+                        &res,
                     )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DescribeMountTargetSecurityGroupsError::InternalServerError(err.msg),
+                        &res,
                     )
                 }
                 "MountTargetNotFound" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DescribeMountTargetSecurityGroupsError::MountTargetNotFound(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        DescribeMountTargetSecurityGroupsError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -1485,12 +1624,16 @@ impl fmt::Display for DescribeMountTargetSecurityGroupsError {
             DescribeMountTargetSecurityGroupsError::MountTargetNotFound(ref cause) => {
                 write!(f, "{}", cause)
             }
+            DescribeMountTargetSecurityGroupsError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for DescribeMountTargetSecurityGroupsError {}
 /// Errors returned by DescribeMountTargets
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeMountTargetsError {
     /// <p>Returned if the specified <code>AccessPointId</code> value doesn't exist in the requester's AWS account.</p>
     AccessPointNotFound(String),
@@ -1502,6 +1645,8 @@ pub enum DescribeMountTargetsError {
     InternalServerError(String),
     /// <p>Returned if there is no mount target with the specified ID found in the caller's account.</p>
     MountTargetNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeMountTargetsError {
@@ -1509,30 +1654,37 @@ impl DescribeMountTargetsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessPointNotFound" => {
-                    return RusotoError::Service(DescribeMountTargetsError::AccessPointNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeMountTargetsError::AccessPointNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "BadRequest" => {
-                    return RusotoError::Service(DescribeMountTargetsError::BadRequest(err.msg))
+                    return RusotoError::service(
+                        DescribeMountTargetsError::BadRequest(err.msg),
+                        &res,
+                    )
                 }
                 "FileSystemNotFound" => {
-                    return RusotoError::Service(DescribeMountTargetsError::FileSystemNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeMountTargetsError::FileSystemNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(DescribeMountTargetsError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeMountTargetsError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "MountTargetNotFound" => {
-                    return RusotoError::Service(DescribeMountTargetsError::MountTargetNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeMountTargetsError::MountTargetNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DescribeMountTargetsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1547,12 +1699,14 @@ impl fmt::Display for DescribeMountTargetsError {
             DescribeMountTargetsError::FileSystemNotFound(ref cause) => write!(f, "{}", cause),
             DescribeMountTargetsError::InternalServerError(ref cause) => write!(f, "{}", cause),
             DescribeMountTargetsError::MountTargetNotFound(ref cause) => write!(f, "{}", cause),
+            DescribeMountTargetsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeMountTargetsError {}
 /// Errors returned by DescribeTags
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeTagsError {
     /// <p>Returned if the request is malformed or contains an error such as an invalid parameter value or a missing required parameter.</p>
     BadRequest(String),
@@ -1560,6 +1714,8 @@ pub enum DescribeTagsError {
     FileSystemNotFound(String),
     /// <p>Returned if an error occurred on the server side.</p>
     InternalServerError(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeTagsError {
@@ -1567,16 +1723,22 @@ impl DescribeTagsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequest" => {
-                    return RusotoError::Service(DescribeTagsError::BadRequest(err.msg))
+                    return RusotoError::service(DescribeTagsError::BadRequest(err.msg), &res)
                 }
                 "FileSystemNotFound" => {
-                    return RusotoError::Service(DescribeTagsError::FileSystemNotFound(err.msg))
+                    return RusotoError::service(
+                        DescribeTagsError::FileSystemNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(DescribeTagsError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        DescribeTagsError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DescribeTagsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1589,12 +1751,14 @@ impl fmt::Display for DescribeTagsError {
             DescribeTagsError::BadRequest(ref cause) => write!(f, "{}", cause),
             DescribeTagsError::FileSystemNotFound(ref cause) => write!(f, "{}", cause),
             DescribeTagsError::InternalServerError(ref cause) => write!(f, "{}", cause),
+            DescribeTagsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeTagsError {}
 /// Errors returned by ListTagsForResource
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListTagsForResourceError {
     /// <p>Returned if the specified <code>AccessPointId</code> value doesn't exist in the requester's AWS account.</p>
     AccessPointNotFound(String),
@@ -1604,6 +1768,8 @@ pub enum ListTagsForResourceError {
     FileSystemNotFound(String),
     /// <p>Returned if an error occurred on the server side.</p>
     InternalServerError(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListTagsForResourceError {
@@ -1611,25 +1777,31 @@ impl ListTagsForResourceError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessPointNotFound" => {
-                    return RusotoError::Service(ListTagsForResourceError::AccessPointNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListTagsForResourceError::AccessPointNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "BadRequest" => {
-                    return RusotoError::Service(ListTagsForResourceError::BadRequest(err.msg))
+                    return RusotoError::service(
+                        ListTagsForResourceError::BadRequest(err.msg),
+                        &res,
+                    )
                 }
                 "FileSystemNotFound" => {
-                    return RusotoError::Service(ListTagsForResourceError::FileSystemNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListTagsForResourceError::FileSystemNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(ListTagsForResourceError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListTagsForResourceError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListTagsForResourceError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1643,12 +1815,14 @@ impl fmt::Display for ListTagsForResourceError {
             ListTagsForResourceError::BadRequest(ref cause) => write!(f, "{}", cause),
             ListTagsForResourceError::FileSystemNotFound(ref cause) => write!(f, "{}", cause),
             ListTagsForResourceError::InternalServerError(ref cause) => write!(f, "{}", cause),
+            ListTagsForResourceError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListTagsForResourceError {}
 /// Errors returned by ModifyMountTargetSecurityGroups
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ModifyMountTargetSecurityGroupsError {
     /// <p>Returned if the request is malformed or contains an error such as an invalid parameter value or a missing required parameter.</p>
     BadRequest(String),
@@ -1662,6 +1836,8 @@ pub enum ModifyMountTargetSecurityGroupsError {
     SecurityGroupLimitExceeded(String),
     /// <p>Returned if one of the specified security groups doesn't exist in the subnet's VPC.</p>
     SecurityGroupNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ModifyMountTargetSecurityGroupsError {
@@ -1671,37 +1847,48 @@ impl ModifyMountTargetSecurityGroupsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequest" => {
-                    return RusotoError::Service(ModifyMountTargetSecurityGroupsError::BadRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ModifyMountTargetSecurityGroupsError::BadRequest(err.msg),
+                        &res,
+                    )
                 }
                 "IncorrectMountTargetState" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         ModifyMountTargetSecurityGroupsError::IncorrectMountTargetState(err.msg),
+                        &res,
                     )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         ModifyMountTargetSecurityGroupsError::InternalServerError(err.msg),
+                        &res,
                     )
                 }
                 "MountTargetNotFound" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         ModifyMountTargetSecurityGroupsError::MountTargetNotFound(err.msg),
+                        &res,
                     )
                 }
                 "SecurityGroupLimitExceeded" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         ModifyMountTargetSecurityGroupsError::SecurityGroupLimitExceeded(err.msg),
+                        &res,
                     )
                 }
                 "SecurityGroupNotFound" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         ModifyMountTargetSecurityGroupsError::SecurityGroupNotFound(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        ModifyMountTargetSecurityGroupsError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -1727,12 +1914,16 @@ impl fmt::Display for ModifyMountTargetSecurityGroupsError {
             ModifyMountTargetSecurityGroupsError::SecurityGroupNotFound(ref cause) => {
                 write!(f, "{}", cause)
             }
+            ModifyMountTargetSecurityGroupsError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for ModifyMountTargetSecurityGroupsError {}
 /// Errors returned by PutFileSystemPolicy
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum PutFileSystemPolicyError {
     /// <p>Returned if the specified <code>FileSystemId</code> value doesn't exist in the requester's AWS account.</p>
     FileSystemNotFound(String),
@@ -1742,6 +1933,8 @@ pub enum PutFileSystemPolicyError {
     InternalServerError(String),
     /// <p>Returned if the <code>FileSystemPolicy</code> is is malformed or contains an error such as an invalid parameter value or a missing required parameter. Returned in the case of a policy lockout safety check error.</p>
     InvalidPolicy(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl PutFileSystemPolicyError {
@@ -1749,25 +1942,31 @@ impl PutFileSystemPolicyError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "FileSystemNotFound" => {
-                    return RusotoError::Service(PutFileSystemPolicyError::FileSystemNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        PutFileSystemPolicyError::FileSystemNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "IncorrectFileSystemLifeCycleState" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         PutFileSystemPolicyError::IncorrectFileSystemLifeCycleState(err.msg),
+                        &res,
                     )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(PutFileSystemPolicyError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        PutFileSystemPolicyError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidPolicyException" => {
-                    return RusotoError::Service(PutFileSystemPolicyError::InvalidPolicy(err.msg))
+                    return RusotoError::service(
+                        PutFileSystemPolicyError::InvalidPolicy(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(PutFileSystemPolicyError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1783,12 +1982,14 @@ impl fmt::Display for PutFileSystemPolicyError {
             }
             PutFileSystemPolicyError::InternalServerError(ref cause) => write!(f, "{}", cause),
             PutFileSystemPolicyError::InvalidPolicy(ref cause) => write!(f, "{}", cause),
+            PutFileSystemPolicyError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for PutFileSystemPolicyError {}
 /// Errors returned by PutLifecycleConfiguration
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum PutLifecycleConfigurationError {
     /// <p>Returned if the request is malformed or contains an error such as an invalid parameter value or a missing required parameter.</p>
     BadRequest(String),
@@ -1798,6 +1999,8 @@ pub enum PutLifecycleConfigurationError {
     IncorrectFileSystemLifeCycleState(String),
     /// <p>Returned if an error occurred on the server side.</p>
     InternalServerError(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl PutLifecycleConfigurationError {
@@ -1805,27 +2008,33 @@ impl PutLifecycleConfigurationError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequest" => {
-                    return RusotoError::Service(PutLifecycleConfigurationError::BadRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        PutLifecycleConfigurationError::BadRequest(err.msg),
+                        &res,
+                    )
                 }
                 "FileSystemNotFound" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         PutLifecycleConfigurationError::FileSystemNotFound(err.msg),
+                        &res,
                     )
                 }
                 "IncorrectFileSystemLifeCycleState" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         PutLifecycleConfigurationError::IncorrectFileSystemLifeCycleState(err.msg),
+                        &res,
                     )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         PutLifecycleConfigurationError::InternalServerError(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(PutLifecycleConfigurationError::Unhandled, &res)
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -1843,12 +2052,14 @@ impl fmt::Display for PutLifecycleConfigurationError {
             PutLifecycleConfigurationError::InternalServerError(ref cause) => {
                 write!(f, "{}", cause)
             }
+            PutLifecycleConfigurationError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for PutLifecycleConfigurationError {}
 /// Errors returned by TagResource
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum TagResourceError {
     /// <p>Returned if the specified <code>AccessPointId</code> value doesn't exist in the requester's AWS account.</p>
     AccessPointNotFound(String),
@@ -1858,6 +2069,8 @@ pub enum TagResourceError {
     FileSystemNotFound(String),
     /// <p>Returned if an error occurred on the server side.</p>
     InternalServerError(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl TagResourceError {
@@ -1865,17 +2078,28 @@ impl TagResourceError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessPointNotFound" => {
-                    return RusotoError::Service(TagResourceError::AccessPointNotFound(err.msg))
+                    return RusotoError::service(
+                        TagResourceError::AccessPointNotFound(err.msg),
+                        &res,
+                    )
+                }
+                "BadRequest" => {
+                    return RusotoError::service(TagResourceError::BadRequest(err.msg), &res)
                 }
-                "BadRequest" => return RusotoError::Service(TagResourceError::BadRequest(err.msg)),
                 "FileSystemNotFound" => {
-                    return RusotoError::Service(TagResourceError::FileSystemNotFound(err.msg))
+                    return RusotoError::service(
+                        TagResourceError::FileSystemNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(TagResourceError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        TagResourceError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(TagResourceError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1889,12 +2113,14 @@ impl fmt::Display for TagResourceError {
             TagResourceError::BadRequest(ref cause) => write!(f, "{}", cause),
             TagResourceError::FileSystemNotFound(ref cause) => write!(f, "{}", cause),
             TagResourceError::InternalServerError(ref cause) => write!(f, "{}", cause),
+            TagResourceError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for TagResourceError {}
 /// Errors returned by UntagResource
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UntagResourceError {
     /// <p>Returned if the specified <code>AccessPointId</code> value doesn't exist in the requester's AWS account.</p>
     AccessPointNotFound(String),
@@ -1904,6 +2130,8 @@ pub enum UntagResourceError {
     FileSystemNotFound(String),
     /// <p>Returned if an error occurred on the server side.</p>
     InternalServerError(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UntagResourceError {
@@ -1911,19 +2139,28 @@ impl UntagResourceError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessPointNotFound" => {
-                    return RusotoError::Service(UntagResourceError::AccessPointNotFound(err.msg))
+                    return RusotoError::service(
+                        UntagResourceError::AccessPointNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "BadRequest" => {
-                    return RusotoError::Service(UntagResourceError::BadRequest(err.msg))
+                    return RusotoError::service(UntagResourceError::BadRequest(err.msg), &res)
                 }
                 "FileSystemNotFound" => {
-                    return RusotoError::Service(UntagResourceError::FileSystemNotFound(err.msg))
+                    return RusotoError::service(
+                        UntagResourceError::FileSystemNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(UntagResourceError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        UntagResourceError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UntagResourceError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1937,12 +2174,14 @@ impl fmt::Display for UntagResourceError {
             UntagResourceError::BadRequest(ref cause) => write!(f, "{}", cause),
             UntagResourceError::FileSystemNotFound(ref cause) => write!(f, "{}", cause),
             UntagResourceError::InternalServerError(ref cause) => write!(f, "{}", cause),
+            UntagResourceError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for UntagResourceError {}
 /// Errors returned by UpdateFileSystem
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdateFileSystemError {
     /// <p>Returned if the request is malformed or contains an error such as an invalid parameter value or a missing required parameter.</p>
     BadRequest(String),
@@ -1958,6 +2197,8 @@ pub enum UpdateFileSystemError {
     ThroughputLimitExceeded(String),
     /// <p>Returned if you don’t wait at least 24 hours before changing the throughput mode, or decreasing the Provisioned Throughput value.</p>
     TooManyRequests(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdateFileSystemError {
@@ -1965,36 +2206,46 @@ impl UpdateFileSystemError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequest" => {
-                    return RusotoError::Service(UpdateFileSystemError::BadRequest(err.msg))
+                    return RusotoError::service(UpdateFileSystemError::BadRequest(err.msg), &res)
                 }
                 "FileSystemNotFound" => {
-                    return RusotoError::Service(UpdateFileSystemError::FileSystemNotFound(err.msg))
+                    return RusotoError::service(
+                        UpdateFileSystemError::FileSystemNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "IncorrectFileSystemLifeCycleState" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         UpdateFileSystemError::IncorrectFileSystemLifeCycleState(err.msg),
+                        &res,
                     )
                 }
                 "InsufficientThroughputCapacity" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         UpdateFileSystemError::InsufficientThroughputCapacity(err.msg),
+                        &res,
                     )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(UpdateFileSystemError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateFileSystemError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "ThroughputLimitExceeded" => {
-                    return RusotoError::Service(UpdateFileSystemError::ThroughputLimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateFileSystemError::ThroughputLimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "TooManyRequests" => {
-                    return RusotoError::Service(UpdateFileSystemError::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        UpdateFileSystemError::TooManyRequests(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UpdateFileSystemError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2015,6 +2266,7 @@ impl fmt::Display for UpdateFileSystemError {
             UpdateFileSystemError::InternalServerError(ref cause) => write!(f, "{}", cause),
             UpdateFileSystemError::ThroughputLimitExceeded(ref cause) => write!(f, "{}", cause),
             UpdateFileSystemError::TooManyRequests(ref cause) => write!(f, "{}", cause),
+            UpdateFileSystemError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
@@ -2174,6 +2426,7 @@ impl EfsClient {
     /// Creates a client backed by the default tokio event loop.
     ///
     /// The client will use the default credentials provider and tls client.
+    #[cfg(not(target_arch = "wasm32"))]
     pub fn new(region: region::Region) -> EfsClient {
         EfsClient {
             client: Client::shared(),
@@ -2199,108 +2452,232 @@ impl EfsClient {
     pub fn new_with_client(client: Client, region: region::Region) -> EfsClient {
         EfsClient { client, region }
     }
+
+    /// Returns a builder of a client, setting any of its region, credentials provider,
+    /// dispatcher, retry policy, endpoint and timeouts.
+    pub fn builder() -> rusoto_core::ClientBuilder<EfsClient> {
+        rusoto_core::ClientBuilder::new(EfsClient::new_with_client)
+    }
+
+    /// Returns a clone of this client with the overrides of `config` applied to its
+    /// operations, sharing its connections.
+    pub fn customize(&self, config: &rusoto_core::OperationConfig) -> EfsClient {
+        EfsClient {
+            client: self.client.customize(config),
+            region: config.resolve_region(&self.region),
+        }
+    }
 }
 
-#[async_trait]
-impl Efs for EfsClient {
-    /// <p>Creates an EFS access point. An access point is an application-specific view into an EFS file system that applies an operating system user and group, and a file system path, to any file system request made through the access point. The operating system user and group override any identity information provided by the NFS client. The file system path is exposed as the access point's root directory. Applications using the access point can only access data in its own directory and below. To learn more, see <a href="https://docs.aws.amazon.com/efs/latest/ug/efs-access-points.html">Mounting a File System Using EFS Access Points</a>.</p> <p>This operation requires permissions for the <code>elasticfilesystem:CreateAccessPoint</code> action.</p>
-    async fn create_access_point(
+impl EfsClient {
+    /// Calls `create_access_point`, also returning the metadata of the response.
+    pub async fn create_access_point_with_metadata(
         &self,
         input: CreateAccessPointRequest,
-    ) -> Result<AccessPointDescription, RusotoError<CreateAccessPointError>> {
+    ) -> Result<(AccessPointDescription, ResponseMetadata), RusotoError<CreateAccessPointError>>
+    {
+        let request = self.build_create_access_point_request(input);
+
+        let mut response = self
+            .client
+            .sign_and_dispatch(request)
+            .await
+            .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
+        if response.status.as_u16() == 200 {
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<AccessPointDescription, _>()?;
+
+            Ok((result, metadata))
+        } else {
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(CreateAccessPointError::from_response(response))
+        }
+    }
+
+    /// Presigns the request `create_access_point` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_access_point(
+        &self,
+        presigner: &rusoto_core::Presigner,
+        input: CreateAccessPointRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_access_point_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_access_point_request(&self, input: CreateAccessPointRequest) -> SignedRequest {
         let request_uri = "/2015-02-01/access-points";
 
         let mut request =
             SignedRequest::new("POST", "elasticfilesystem", &self.region, &request_uri);
+        request.set_operation("CreateAccessPoint");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `create_file_system`, also returning the metadata of the response.
+    pub async fn create_file_system_with_metadata(
+        &self,
+        input: CreateFileSystemRequest,
+    ) -> Result<(FileSystemDescription, ResponseMetadata), RusotoError<CreateFileSystemError>> {
+        let request = self.build_create_file_system_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
-        if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let metadata = ResponseMetadata::from_response(&response);
+        if response.status.as_u16() == 201 {
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<AccessPointDescription, _>()?;
+                .deserialize::<FileSystemDescription, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(CreateAccessPointError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(CreateFileSystemError::from_response(response))
         }
     }
 
-    /// <p>Creates a new, empty file system. The operation requires a creation token in the request that Amazon EFS uses to ensure idempotent creation (calling the operation with same creation token has no effect). If a file system does not currently exist that is owned by the caller's AWS account with the specified creation token, this operation does the following:</p> <ul> <li> <p>Creates a new, empty file system. The file system will have an Amazon EFS assigned ID, and an initial lifecycle state <code>creating</code>.</p> </li> <li> <p>Returns with the description of the created file system.</p> </li> </ul> <p>Otherwise, this operation returns a <code>FileSystemAlreadyExists</code> error with the ID of the existing file system.</p> <note> <p>For basic use cases, you can use a randomly generated UUID for the creation token.</p> </note> <p> The idempotent operation allows you to retry a <code>CreateFileSystem</code> call without risk of creating an extra file system. This can happen when an initial call fails in a way that leaves it uncertain whether or not a file system was actually created. An example might be that a transport level timeout occurred or your connection was reset. As long as you use the same creation token, if the initial call had succeeded in creating a file system, the client can learn of its existence from the <code>FileSystemAlreadyExists</code> error.</p> <note> <p>The <code>CreateFileSystem</code> call returns while the file system's lifecycle state is still <code>creating</code>. You can check the file system creation status by calling the <a>DescribeFileSystems</a> operation, which among other things returns the file system state.</p> </note> <p>This operation also takes an optional <code>PerformanceMode</code> parameter that you choose for your file system. We recommend <code>generalPurpose</code> performance mode for most file systems. File systems using the <code>maxIO</code> performance mode can scale to higher levels of aggregate throughput and operations per second with a tradeoff of slightly higher latencies for most file operations. The performance mode can't be changed after the file system has been created. For more information, see <a href="https://docs.aws.amazon.com/efs/latest/ug/performance.html#performancemodes.html">Amazon EFS: Performance Modes</a>.</p> <p>After the file system is fully created, Amazon EFS sets its lifecycle state to <code>available</code>, at which point you can create one or more mount targets for the file system in your VPC. For more information, see <a>CreateMountTarget</a>. You mount your Amazon EFS file system on an EC2 instances in your VPC by using the mount target. For more information, see <a href="https://docs.aws.amazon.com/efs/latest/ug/how-it-works.html">Amazon EFS: How it Works</a>. </p> <p> This operation requires permissions for the <code>elasticfilesystem:CreateFileSystem</code> action. </p>
-    async fn create_file_system(
+    /// Presigns the request `create_file_system` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_file_system(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: CreateFileSystemRequest,
-    ) -> Result<FileSystemDescription, RusotoError<CreateFileSystemError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_file_system_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_file_system_request(&self, input: CreateFileSystemRequest) -> SignedRequest {
         let request_uri = "/2015-02-01/file-systems";
 
         let mut request =
             SignedRequest::new("POST", "elasticfilesystem", &self.region, &request_uri);
+        request.set_operation("CreateFileSystem");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `create_mount_target`, also returning the metadata of the response.
+    pub async fn create_mount_target_with_metadata(
+        &self,
+        input: CreateMountTargetRequest,
+    ) -> Result<(MountTargetDescription, ResponseMetadata), RusotoError<CreateMountTargetError>>
+    {
+        let request = self.build_create_mount_target_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
-        if response.status.as_u16() == 201 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let metadata = ResponseMetadata::from_response(&response);
+        if response.status.as_u16() == 200 {
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<FileSystemDescription, _>()?;
+                .deserialize::<MountTargetDescription, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(CreateFileSystemError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(CreateMountTargetError::from_response(response))
         }
     }
 
-    /// <p><p>Creates a mount target for a file system. You can then mount the file system on EC2 instances by using the mount target.</p> <p>You can create one mount target in each Availability Zone in your VPC. All EC2 instances in a VPC within a given Availability Zone share a single mount target for a given file system. If you have multiple subnets in an Availability Zone, you create a mount target in one of the subnets. EC2 instances do not need to be in the same subnet as the mount target in order to access their file system. For more information, see <a href="https://docs.aws.amazon.com/efs/latest/ug/how-it-works.html">Amazon EFS: How it Works</a>. </p> <p>In the request, you also specify a file system ID for which you are creating the mount target and the file system&#39;s lifecycle state must be <code>available</code>. For more information, see <a>DescribeFileSystems</a>.</p> <p>In the request, you also provide a subnet ID, which determines the following:</p> <ul> <li> <p>VPC in which Amazon EFS creates the mount target</p> </li> <li> <p>Availability Zone in which Amazon EFS creates the mount target</p> </li> <li> <p>IP address range from which Amazon EFS selects the IP address of the mount target (if you don&#39;t specify an IP address in the request)</p> </li> </ul> <p>After creating the mount target, Amazon EFS returns a response that includes, a <code>MountTargetId</code> and an <code>IpAddress</code>. You use this IP address when mounting the file system in an EC2 instance. You can also use the mount target&#39;s DNS name when mounting the file system. The EC2 instance on which you mount the file system by using the mount target can resolve the mount target&#39;s DNS name to its IP address. For more information, see <a href="https://docs.aws.amazon.com/efs/latest/ug/how-it-works.html#how-it-works-implementation">How it Works: Implementation Overview</a>. </p> <p>Note that you can create mount targets for a file system in only one VPC, and there can be only one mount target per Availability Zone. That is, if the file system already has one or more mount targets created for it, the subnet specified in the request to add another mount target must meet the following requirements:</p> <ul> <li> <p>Must belong to the same VPC as the subnets of the existing mount targets</p> </li> <li> <p>Must not be in the same Availability Zone as any of the subnets of the existing mount targets</p> </li> </ul> <p>If the request satisfies the requirements, Amazon EFS does the following:</p> <ul> <li> <p>Creates a new mount target in the specified subnet.</p> </li> <li> <p>Also creates a new network interface in the subnet as follows:</p> <ul> <li> <p>If the request provides an <code>IpAddress</code>, Amazon EFS assigns that IP address to the network interface. Otherwise, Amazon EFS assigns a free address in the subnet (in the same way that the Amazon EC2 <code>CreateNetworkInterface</code> call does when a request does not specify a primary private IP address).</p> </li> <li> <p>If the request provides <code>SecurityGroups</code>, this network interface is associated with those security groups. Otherwise, it belongs to the default security group for the subnet&#39;s VPC.</p> </li> <li> <p>Assigns the description <code>Mount target <i>fsmt-id</i> for file system <i>fs-id</i> </code> where <code> <i>fsmt-id</i> </code> is the mount target ID, and <code> <i>fs-id</i> </code> is the <code>FileSystemId</code>.</p> </li> <li> <p>Sets the <code>requesterManaged</code> property of the network interface to <code>true</code>, and the <code>requesterId</code> value to <code>EFS</code>.</p> </li> </ul> <p>Each Amazon EFS mount target has one corresponding requester-managed EC2 network interface. After the network interface is created, Amazon EFS sets the <code>NetworkInterfaceId</code> field in the mount target&#39;s description to the network interface ID, and the <code>IpAddress</code> field to its address. If network interface creation fails, the entire <code>CreateMountTarget</code> operation fails.</p> </li> </ul> <note> <p>The <code>CreateMountTarget</code> call returns only after creating the network interface, but while the mount target state is still <code>creating</code>, you can check the mount target creation status by calling the <a>DescribeMountTargets</a> operation, which among other things returns the mount target state.</p> </note> <p>We recommend that you create a mount target in each of the Availability Zones. There are cost considerations for using a file system in an Availability Zone through a mount target created in another Availability Zone. For more information, see <a href="http://aws.amazon.com/efs/">Amazon EFS</a>. In addition, by always using a mount target local to the instance&#39;s Availability Zone, you eliminate a partial failure scenario. If the Availability Zone in which your mount target is created goes down, then you can&#39;t access your file system through that mount target. </p> <p>This operation requires permissions for the following action on the file system:</p> <ul> <li> <p> <code>elasticfilesystem:CreateMountTarget</code> </p> </li> </ul> <p>This operation also requires permissions for the following Amazon EC2 actions:</p> <ul> <li> <p> <code>ec2:DescribeSubnets</code> </p> </li> <li> <p> <code>ec2:DescribeNetworkInterfaces</code> </p> </li> <li> <p> <code>ec2:CreateNetworkInterface</code> </p> </li> </ul></p>
-    async fn create_mount_target(
+    /// Presigns the request `create_mount_target` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_mount_target(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: CreateMountTargetRequest,
-    ) -> Result<MountTargetDescription, RusotoError<CreateMountTargetError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_mount_target_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_mount_target_request(&self, input: CreateMountTargetRequest) -> SignedRequest {
         let request_uri = "/2015-02-01/mount-targets";
 
         let mut request =
             SignedRequest::new("POST", "elasticfilesystem", &self.region, &request_uri);
+        request.set_operation("CreateMountTarget");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `create_tags`, also returning the metadata of the response.
+    pub async fn create_tags_with_metadata(
+        &self,
+        input: CreateTagsRequest,
+    ) -> Result<((), ResponseMetadata), RusotoError<CreateTagsError>> {
+        let request = self.build_create_tags_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
-        if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<MountTargetDescription, _>()?;
+        let metadata = ResponseMetadata::from_response(&response);
+        if response.status.as_u16() == 204 {
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = ::std::mem::drop(response);
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(CreateMountTargetError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(CreateTagsError::from_response(response))
         }
     }
 
-    /// <p>Creates or overwrites tags associated with a file system. Each tag is a key-value pair. If a tag key specified in the request already exists on the file system, this operation overwrites its value with the value provided in the request. If you add the <code>Name</code> tag to your file system, Amazon EFS returns it in the response to the <a>DescribeFileSystems</a> operation. </p> <p>This operation requires permission for the <code>elasticfilesystem:CreateTags</code> action.</p>
-    async fn create_tags(
+    /// Presigns the request `create_tags` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_tags(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: CreateTagsRequest,
-    ) -> Result<(), RusotoError<CreateTagsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_tags_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_tags_request(&self, input: CreateTagsRequest) -> SignedRequest {
         let request_uri = format!(
             "/2015-02-01/create-tags/{file_system_id}",
             file_system_id = input.file_system_id
@@ -2308,32 +2685,56 @@ impl Efs for EfsClient {
 
         let mut request =
             SignedRequest::new("POST", "elasticfilesystem", &self.region, &request_uri);
+        request.set_operation("CreateTags");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `delete_access_point`, also returning the metadata of the response.
+    pub async fn delete_access_point_with_metadata(
+        &self,
+        input: DeleteAccessPointRequest,
+    ) -> Result<((), ResponseMetadata), RusotoError<DeleteAccessPointError>> {
+        let request = self.build_delete_access_point_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 204 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = ::std::mem::drop(response);
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(CreateTagsError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(DeleteAccessPointError::from_response(response))
         }
     }
 
-    /// <p>Deletes the specified access point. After deletion is complete, new clients can no longer connect to the access points. Clients connected to the access point at the time of deletion will continue to function until they terminate their connection.</p> <p>This operation requires permissions for the <code>elasticfilesystem:DeleteAccessPoint</code> action.</p>
-    async fn delete_access_point(
+    /// Presigns the request `delete_access_point` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_access_point(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteAccessPointRequest,
-    ) -> Result<(), RusotoError<DeleteAccessPointError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_access_point_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_access_point_request(&self, input: DeleteAccessPointRequest) -> SignedRequest {
         let request_uri = format!(
             "/2015-02-01/access-points/{access_point_id}",
             access_point_id = input.access_point_id
@@ -2341,29 +2742,53 @@ impl Efs for EfsClient {
 
         let mut request =
             SignedRequest::new("DELETE", "elasticfilesystem", &self.region, &request_uri);
+        request.set_operation("DeleteAccessPoint");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
+        request
+    }
+
+    /// Calls `delete_file_system`, also returning the metadata of the response.
+    pub async fn delete_file_system_with_metadata(
+        &self,
+        input: DeleteFileSystemRequest,
+    ) -> Result<((), ResponseMetadata), RusotoError<DeleteFileSystemError>> {
+        let request = self.build_delete_file_system_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 204 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = ::std::mem::drop(response);
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteAccessPointError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(DeleteFileSystemError::from_response(response))
         }
     }
 
-    /// <p>Deletes a file system, permanently severing access to its contents. Upon return, the file system no longer exists and you can't access any contents of the deleted file system.</p> <p> You can't delete a file system that is in use. That is, if the file system has any mount targets, you must first delete them. For more information, see <a>DescribeMountTargets</a> and <a>DeleteMountTarget</a>. </p> <note> <p>The <code>DeleteFileSystem</code> call returns while the file system state is still <code>deleting</code>. You can check the file system deletion status by calling the <a>DescribeFileSystems</a> operation, which returns a list of file systems in your account. If you pass file system ID or creation token for the deleted file system, the <a>DescribeFileSystems</a> returns a <code>404 FileSystemNotFound</code> error.</p> </note> <p>This operation requires permissions for the <code>elasticfilesystem:DeleteFileSystem</code> action.</p>
-    async fn delete_file_system(
+    /// Presigns the request `delete_file_system` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_file_system(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteFileSystemRequest,
-    ) -> Result<(), RusotoError<DeleteFileSystemError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_file_system_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_file_system_request(&self, input: DeleteFileSystemRequest) -> SignedRequest {
         let request_uri = format!(
             "/2015-02-01/file-systems/{file_system_id}",
             file_system_id = input.file_system_id
@@ -2371,29 +2796,56 @@ impl Efs for EfsClient {
 
         let mut request =
             SignedRequest::new("DELETE", "elasticfilesystem", &self.region, &request_uri);
+        request.set_operation("DeleteFileSystem");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
+        request
+    }
+
+    /// Calls `delete_file_system_policy`, also returning the metadata of the response.
+    pub async fn delete_file_system_policy_with_metadata(
+        &self,
+        input: DeleteFileSystemPolicyRequest,
+    ) -> Result<((), ResponseMetadata), RusotoError<DeleteFileSystemPolicyError>> {
+        let request = self.build_delete_file_system_policy_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
-        if response.status.as_u16() == 204 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let metadata = ResponseMetadata::from_response(&response);
+        if response.status.as_u16() == 200 {
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = ::std::mem::drop(response);
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteFileSystemError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(DeleteFileSystemPolicyError::from_response(response))
         }
     }
 
-    /// <p>Deletes the <code>FileSystemPolicy</code> for the specified file system. The default <code>FileSystemPolicy</code> goes into effect once the existing policy is deleted. For more information about the default file system policy, see <a href="https://docs.aws.amazon.com/efs/latest/ug/res-based-policies-efs.html">Using Resource-based Policies with EFS</a>.</p> <p>This operation requires permissions for the <code>elasticfilesystem:DeleteFileSystemPolicy</code> action.</p>
-    async fn delete_file_system_policy(
+    /// Presigns the request `delete_file_system_policy` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_file_system_policy(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteFileSystemPolicyRequest,
-    ) -> Result<(), RusotoError<DeleteFileSystemPolicyError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_file_system_policy_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_file_system_policy_request(
+        &self,
+        input: DeleteFileSystemPolicyRequest,
+    ) -> SignedRequest {
         let request_uri = format!(
             "/2015-02-01/file-systems/{file_system_id}/policy",
             file_system_id = input.file_system_id
@@ -2401,29 +2853,53 @@ impl Efs for EfsClient {
 
         let mut request =
             SignedRequest::new("DELETE", "elasticfilesystem", &self.region, &request_uri);
+        request.set_operation("DeleteFileSystemPolicy");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
+        request
+    }
+
+    /// Calls `delete_mount_target`, also returning the metadata of the response.
+    pub async fn delete_mount_target_with_metadata(
+        &self,
+        input: DeleteMountTargetRequest,
+    ) -> Result<((), ResponseMetadata), RusotoError<DeleteMountTargetError>> {
+        let request = self.build_delete_mount_target_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
-        if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let metadata = ResponseMetadata::from_response(&response);
+        if response.status.as_u16() == 204 {
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = ::std::mem::drop(response);
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteFileSystemPolicyError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(DeleteMountTargetError::from_response(response))
         }
     }
 
-    /// <p><p>Deletes the specified mount target.</p> <p>This operation forcibly breaks any mounts of the file system by using the mount target that is being deleted, which might disrupt instances or applications using those mounts. To avoid applications getting cut off abruptly, you might consider unmounting any mounts of the mount target, if feasible. The operation also deletes the associated network interface. Uncommitted writes might be lost, but breaking a mount target using this operation does not corrupt the file system itself. The file system you created remains. You can mount an EC2 instance in your VPC by using another mount target.</p> <p>This operation requires permissions for the following action on the file system:</p> <ul> <li> <p> <code>elasticfilesystem:DeleteMountTarget</code> </p> </li> </ul> <note> <p>The <code>DeleteMountTarget</code> call returns while the mount target state is still <code>deleting</code>. You can check the mount target deletion by calling the <a>DescribeMountTargets</a> operation, which returns a list of mount target descriptions for the given file system. </p> </note> <p>The operation also requires permissions for the following Amazon EC2 action on the mount target&#39;s network interface:</p> <ul> <li> <p> <code>ec2:DeleteNetworkInterface</code> </p> </li> </ul></p>
-    async fn delete_mount_target(
+    /// Presigns the request `delete_mount_target` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_mount_target(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteMountTargetRequest,
-    ) -> Result<(), RusotoError<DeleteMountTargetError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_mount_target_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_mount_target_request(&self, input: DeleteMountTargetRequest) -> SignedRequest {
         let request_uri = format!(
             "/2015-02-01/mount-targets/{mount_target_id}",
             mount_target_id = input.mount_target_id
@@ -2431,29 +2907,53 @@ impl Efs for EfsClient {
 
         let mut request =
             SignedRequest::new("DELETE", "elasticfilesystem", &self.region, &request_uri);
+        request.set_operation("DeleteMountTarget");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
+        request
+    }
+
+    /// Calls `delete_tags`, also returning the metadata of the response.
+    pub async fn delete_tags_with_metadata(
+        &self,
+        input: DeleteTagsRequest,
+    ) -> Result<((), ResponseMetadata), RusotoError<DeleteTagsError>> {
+        let request = self.build_delete_tags_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 204 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = ::std::mem::drop(response);
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteMountTargetError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(DeleteTagsError::from_response(response))
         }
     }
 
-    /// <p>Deletes the specified tags from a file system. If the <code>DeleteTags</code> request includes a tag key that doesn't exist, Amazon EFS ignores it and doesn't cause an error. For more information about tags and related restrictions, see <a href="https://docs.aws.amazon.com/awsaccountbilling/latest/aboutv2/cost-alloc-tags.html">Tag Restrictions</a> in the <i>AWS Billing and Cost Management User Guide</i>.</p> <p>This operation requires permissions for the <code>elasticfilesystem:DeleteTags</code> action.</p>
-    async fn delete_tags(
+    /// Presigns the request `delete_tags` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_tags(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteTagsRequest,
-    ) -> Result<(), RusotoError<DeleteTagsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_tags_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_tags_request(&self, input: DeleteTagsRequest) -> SignedRequest {
         let request_uri = format!(
             "/2015-02-01/delete-tags/{file_system_id}",
             file_system_id = input.file_system_id
@@ -2461,36 +2961,68 @@ impl Efs for EfsClient {
 
         let mut request =
             SignedRequest::new("POST", "elasticfilesystem", &self.region, &request_uri);
+        request.set_operation("DeleteTags");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `describe_access_points`, also returning the metadata of the response.
+    pub async fn describe_access_points_with_metadata(
+        &self,
+        input: DescribeAccessPointsRequest,
+    ) -> Result<
+        (DescribeAccessPointsResponse, ResponseMetadata),
+        RusotoError<DescribeAccessPointsError>,
+    > {
+        let request = self.build_describe_access_points_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
-        if response.status.as_u16() == 204 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            let result = ::std::mem::drop(response);
+        let metadata = ResponseMetadata::from_response(&response);
+        if response.status.as_u16() == 200 {
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DescribeAccessPointsResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteTagsError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(DescribeAccessPointsError::from_response(response))
         }
     }
 
-    /// <p>Returns the description of a specific Amazon EFS access point if the <code>AccessPointId</code> is provided. If you provide an EFS <code>FileSystemId</code>, it returns descriptions of all access points for that file system. You can provide either an <code>AccessPointId</code> or a <code>FileSystemId</code> in the request, but not both. </p> <p>This operation requires permissions for the <code>elasticfilesystem:DescribeAccessPoints</code> action.</p>
-    async fn describe_access_points(
+    /// Presigns the request `describe_access_points` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_access_points(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeAccessPointsRequest,
-    ) -> Result<DescribeAccessPointsResponse, RusotoError<DescribeAccessPointsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_access_points_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_access_points_request(
+        &self,
+        input: DescribeAccessPointsRequest,
+    ) -> SignedRequest {
         let request_uri = "/2015-02-01/access-points";
 
         let mut request =
             SignedRequest::new("GET", "elasticfilesystem", &self.region, &request_uri);
+        request.set_operation("DescribeAccessPoints");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -2508,28 +3040,57 @@ impl Efs for EfsClient {
         }
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `describe_file_system_policy`, also returning the metadata of the response.
+    pub async fn describe_file_system_policy_with_metadata(
+        &self,
+        input: DescribeFileSystemPolicyRequest,
+    ) -> Result<
+        (FileSystemPolicyDescription, ResponseMetadata),
+        RusotoError<DescribeFileSystemPolicyError>,
+    > {
+        let request = self.build_describe_file_system_policy_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<DescribeAccessPointsResponse, _>()?;
+                .deserialize::<FileSystemPolicyDescription, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeAccessPointsError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(DescribeFileSystemPolicyError::from_response(response))
         }
     }
 
-    /// <p>Returns the <code>FileSystemPolicy</code> for the specified EFS file system.</p> <p>This operation requires permissions for the <code>elasticfilesystem:DescribeFileSystemPolicy</code> action.</p>
-    async fn describe_file_system_policy(
+    /// Presigns the request `describe_file_system_policy` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_file_system_policy(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeFileSystemPolicyRequest,
-    ) -> Result<FileSystemPolicyDescription, RusotoError<DescribeFileSystemPolicyError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_file_system_policy_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_file_system_policy_request(
+        &self,
+        input: DescribeFileSystemPolicyRequest,
+    ) -> SignedRequest {
         let request_uri = format!(
             "/2015-02-01/file-systems/{file_system_id}/policy",
             file_system_id = input.file_system_id
@@ -2537,34 +3098,65 @@ impl Efs for EfsClient {
 
         let mut request =
             SignedRequest::new("GET", "elasticfilesystem", &self.region, &request_uri);
+        request.set_operation("DescribeFileSystemPolicy");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
+        request
+    }
+
+    /// Calls `describe_file_systems`, also returning the metadata of the response.
+    pub async fn describe_file_systems_with_metadata(
+        &self,
+        input: DescribeFileSystemsRequest,
+    ) -> Result<
+        (DescribeFileSystemsResponse, ResponseMetadata),
+        RusotoError<DescribeFileSystemsError>,
+    > {
+        let request = self.build_describe_file_systems_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<FileSystemPolicyDescription, _>()?;
+                .deserialize::<DescribeFileSystemsResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeFileSystemPolicyError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(DescribeFileSystemsError::from_response(response))
         }
     }
 
-    /// <p>Returns the description of a specific Amazon EFS file system if either the file system <code>CreationToken</code> or the <code>FileSystemId</code> is provided. Otherwise, it returns descriptions of all file systems owned by the caller's AWS account in the AWS Region of the endpoint that you're calling.</p> <p>When retrieving all file system descriptions, you can optionally specify the <code>MaxItems</code> parameter to limit the number of descriptions in a response. Currently, this number is automatically set to 10. If more file system descriptions remain, Amazon EFS returns a <code>NextMarker</code>, an opaque token, in the response. In this case, you should send a subsequent request with the <code>Marker</code> request parameter set to the value of <code>NextMarker</code>. </p> <p>To retrieve a list of your file system descriptions, this operation is used in an iterative process, where <code>DescribeFileSystems</code> is called first without the <code>Marker</code> and then the operation continues to call it with the <code>Marker</code> parameter set to the value of the <code>NextMarker</code> from the previous response until the response has no <code>NextMarker</code>. </p> <p> The order of file systems returned in the response of one <code>DescribeFileSystems</code> call and the order of file systems returned across the responses of a multi-call iteration is unspecified. </p> <p> This operation requires permissions for the <code>elasticfilesystem:DescribeFileSystems</code> action. </p>
-    async fn describe_file_systems(
+    /// Presigns the request `describe_file_systems` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_file_systems(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeFileSystemsRequest,
-    ) -> Result<DescribeFileSystemsResponse, RusotoError<DescribeFileSystemsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_file_systems_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_file_systems_request(
+        &self,
+        input: DescribeFileSystemsRequest,
+    ) -> SignedRequest {
         let request_uri = "/2015-02-01/file-systems";
 
         let mut request =
             SignedRequest::new("GET", "elasticfilesystem", &self.region, &request_uri);
+        request.set_operation("DescribeFileSystems");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -2582,29 +3174,57 @@ impl Efs for EfsClient {
         }
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `describe_lifecycle_configuration`, also returning the metadata of the response.
+    pub async fn describe_lifecycle_configuration_with_metadata(
+        &self,
+        input: DescribeLifecycleConfigurationRequest,
+    ) -> Result<
+        (LifecycleConfigurationDescription, ResponseMetadata),
+        RusotoError<DescribeLifecycleConfigurationError>,
+    > {
+        let request = self.build_describe_lifecycle_configuration_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<DescribeFileSystemsResponse, _>()?;
+                .deserialize::<LifecycleConfigurationDescription, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeFileSystemsError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(DescribeLifecycleConfigurationError::from_response(response))
         }
     }
 
-    /// <p>Returns the current <code>LifecycleConfiguration</code> object for the specified Amazon EFS file system. EFS lifecycle management uses the <code>LifecycleConfiguration</code> object to identify which files to move to the EFS Infrequent Access (IA) storage class. For a file system without a <code>LifecycleConfiguration</code> object, the call returns an empty array in the response.</p> <p>This operation requires permissions for the <code>elasticfilesystem:DescribeLifecycleConfiguration</code> operation.</p>
-    async fn describe_lifecycle_configuration(
+    /// Presigns the request `describe_lifecycle_configuration` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_lifecycle_configuration(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeLifecycleConfigurationRequest,
-    ) -> Result<LifecycleConfigurationDescription, RusotoError<DescribeLifecycleConfigurationError>>
-    {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_lifecycle_configuration_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_lifecycle_configuration_request(
+        &self,
+        input: DescribeLifecycleConfigurationRequest,
+    ) -> SignedRequest {
         let request_uri = format!(
             "/2015-02-01/file-systems/{file_system_id}/lifecycle-configuration",
             file_system_id = input.file_system_id
@@ -2612,33 +3232,62 @@ impl Efs for EfsClient {
 
         let mut request =
             SignedRequest::new("GET", "elasticfilesystem", &self.region, &request_uri);
+        request.set_operation("DescribeLifecycleConfiguration");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
+        request
+    }
+
+    /// Calls `describe_mount_target_security_groups`, also returning the metadata of the response.
+    pub async fn describe_mount_target_security_groups_with_metadata(
+        &self,
+        input: DescribeMountTargetSecurityGroupsRequest,
+    ) -> Result<
+        (DescribeMountTargetSecurityGroupsResponse, ResponseMetadata),
+        RusotoError<DescribeMountTargetSecurityGroupsError>,
+    > {
+        let request = self.build_describe_mount_target_security_groups_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<LifecycleConfigurationDescription, _>()?;
+                .deserialize::<DescribeMountTargetSecurityGroupsResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeLifecycleConfigurationError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(DescribeMountTargetSecurityGroupsError::from_response(
+                response,
+            ))
         }
     }
 
-    /// <p><p>Returns the security groups currently in effect for a mount target. This operation requires that the network interface of the mount target has been created and the lifecycle state of the mount target is not <code>deleted</code>.</p> <p>This operation requires permissions for the following actions:</p> <ul> <li> <p> <code>elasticfilesystem:DescribeMountTargetSecurityGroups</code> action on the mount target&#39;s file system. </p> </li> <li> <p> <code>ec2:DescribeNetworkInterfaceAttribute</code> action on the mount target&#39;s network interface. </p> </li> </ul></p>
-    async fn describe_mount_target_security_groups(
+    /// Presigns the request `describe_mount_target_security_groups` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_mount_target_security_groups(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeMountTargetSecurityGroupsRequest,
-    ) -> Result<
-        DescribeMountTargetSecurityGroupsResponse,
-        RusotoError<DescribeMountTargetSecurityGroupsError>,
-    > {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_mount_target_security_groups_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_mount_target_security_groups_request(
+        &self,
+        input: DescribeMountTargetSecurityGroupsRequest,
+    ) -> SignedRequest {
         let request_uri = format!(
             "/2015-02-01/mount-targets/{mount_target_id}/security-groups",
             mount_target_id = input.mount_target_id
@@ -2646,36 +3295,65 @@ impl Efs for EfsClient {
 
         let mut request =
             SignedRequest::new("GET", "elasticfilesystem", &self.region, &request_uri);
+        request.set_operation("DescribeMountTargetSecurityGroups");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
+        request
+    }
+
+    /// Calls `describe_mount_targets`, also returning the metadata of the response.
+    pub async fn describe_mount_targets_with_metadata(
+        &self,
+        input: DescribeMountTargetsRequest,
+    ) -> Result<
+        (DescribeMountTargetsResponse, ResponseMetadata),
+        RusotoError<DescribeMountTargetsError>,
+    > {
+        let request = self.build_describe_mount_targets_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<DescribeMountTargetSecurityGroupsResponse, _>()?;
+                .deserialize::<DescribeMountTargetsResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeMountTargetSecurityGroupsError::from_response(
-                response,
-            ))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(DescribeMountTargetsError::from_response(response))
         }
     }
 
-    /// <p>Returns the descriptions of all the current mount targets, or a specific mount target, for a file system. When requesting all of the current mount targets, the order of mount targets returned in the response is unspecified.</p> <p>This operation requires permissions for the <code>elasticfilesystem:DescribeMountTargets</code> action, on either the file system ID that you specify in <code>FileSystemId</code>, or on the file system of the mount target that you specify in <code>MountTargetId</code>.</p>
-    async fn describe_mount_targets(
+    /// Presigns the request `describe_mount_targets` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_mount_targets(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeMountTargetsRequest,
-    ) -> Result<DescribeMountTargetsResponse, RusotoError<DescribeMountTargetsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_mount_targets_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_mount_targets_request(
+        &self,
+        input: DescribeMountTargetsRequest,
+    ) -> SignedRequest {
         let request_uri = "/2015-02-01/mount-targets";
 
         let mut request =
             SignedRequest::new("GET", "elasticfilesystem", &self.region, &request_uri);
+        request.set_operation("DescribeMountTargets");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -2696,28 +3374,51 @@ impl Efs for EfsClient {
         }
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `describe_tags`, also returning the metadata of the response.
+    pub async fn describe_tags_with_metadata(
+        &self,
+        input: DescribeTagsRequest,
+    ) -> Result<(DescribeTagsResponse, ResponseMetadata), RusotoError<DescribeTagsError>> {
+        let request = self.build_describe_tags_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<DescribeMountTargetsResponse, _>()?;
+                .deserialize::<DescribeTagsResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeMountTargetsError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(DescribeTagsError::from_response(response))
         }
     }
 
-    /// <p>Returns the tags associated with a file system. The order of tags returned in the response of one <code>DescribeTags</code> call and the order of tags returned across the responses of a multiple-call iteration (when using pagination) is unspecified. </p> <p> This operation requires permissions for the <code>elasticfilesystem:DescribeTags</code> action. </p>
-    async fn describe_tags(
+    /// Presigns the request `describe_tags` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_tags(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeTagsRequest,
-    ) -> Result<DescribeTagsResponse, RusotoError<DescribeTagsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_tags_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_tags_request(&self, input: DescribeTagsRequest) -> SignedRequest {
         let request_uri = format!(
             "/2015-02-01/tags/{file_system_id}/",
             file_system_id = input.file_system_id
@@ -2725,6 +3426,7 @@ impl Efs for EfsClient {
 
         let mut request =
             SignedRequest::new("GET", "elasticfilesystem", &self.region, &request_uri);
+        request.set_operation("DescribeTags");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -2736,28 +3438,57 @@ impl Efs for EfsClient {
         }
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `list_tags_for_resource`, also returning the metadata of the response.
+    pub async fn list_tags_for_resource_with_metadata(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> Result<
+        (ListTagsForResourceResponse, ResponseMetadata),
+        RusotoError<ListTagsForResourceError>,
+    > {
+        let request = self.build_list_tags_for_resource_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<DescribeTagsResponse, _>()?;
+                .deserialize::<ListTagsForResourceResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeTagsError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(ListTagsForResourceError::from_response(response))
         }
     }
 
-    /// <p>Lists all tags for a top-level EFS resource. You must provide the ID of the resource that you want to retrieve the tags for.</p> <p>This operation requires permissions for the <code>elasticfilesystem:DescribeAccessPoints</code> action.</p>
-    async fn list_tags_for_resource(
+    /// Presigns the request `list_tags_for_resource` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_tags_for_resource(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListTagsForResourceRequest,
-    ) -> Result<ListTagsForResourceResponse, RusotoError<ListTagsForResourceError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_tags_for_resource_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_tags_for_resource_request(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> SignedRequest {
         let request_uri = format!(
             "/2015-02-01/resource-tags/{resource_id}",
             resource_id = input.resource_id
@@ -2765,6 +3496,7 @@ impl Efs for EfsClient {
 
         let mut request =
             SignedRequest::new("GET", "elasticfilesystem", &self.region, &request_uri);
+        request.set_operation("ListTagsForResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -2776,28 +3508,55 @@ impl Efs for EfsClient {
         }
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `modify_mount_target_security_groups`, also returning the metadata of the response.
+    pub async fn modify_mount_target_security_groups_with_metadata(
+        &self,
+        input: ModifyMountTargetSecurityGroupsRequest,
+    ) -> Result<((), ResponseMetadata), RusotoError<ModifyMountTargetSecurityGroupsError>> {
+        let request = self.build_modify_mount_target_security_groups_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
-        if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListTagsForResourceResponse, _>()?;
+        let metadata = ResponseMetadata::from_response(&response);
+        if response.status.as_u16() == 204 {
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = ::std::mem::drop(response);
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(ListTagsForResourceError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(ModifyMountTargetSecurityGroupsError::from_response(
+                response,
+            ))
         }
     }
 
-    /// <p><p>Modifies the set of security groups in effect for a mount target.</p> <p>When you create a mount target, Amazon EFS also creates a new network interface. For more information, see <a>CreateMountTarget</a>. This operation replaces the security groups in effect for the network interface associated with a mount target, with the <code>SecurityGroups</code> provided in the request. This operation requires that the network interface of the mount target has been created and the lifecycle state of the mount target is not <code>deleted</code>. </p> <p>The operation requires permissions for the following actions:</p> <ul> <li> <p> <code>elasticfilesystem:ModifyMountTargetSecurityGroups</code> action on the mount target&#39;s file system. </p> </li> <li> <p> <code>ec2:ModifyNetworkInterfaceAttribute</code> action on the mount target&#39;s network interface. </p> </li> </ul></p>
-    async fn modify_mount_target_security_groups(
+    /// Presigns the request `modify_mount_target_security_groups` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_modify_mount_target_security_groups(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ModifyMountTargetSecurityGroupsRequest,
-    ) -> Result<(), RusotoError<ModifyMountTargetSecurityGroupsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_modify_mount_target_security_groups_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_modify_mount_target_security_groups_request(
+        &self,
+        input: ModifyMountTargetSecurityGroupsRequest,
+    ) -> SignedRequest {
         let request_uri = format!(
             "/2015-02-01/mount-targets/{mount_target_id}/security-groups",
             mount_target_id = input.mount_target_id
@@ -2805,34 +3564,63 @@ impl Efs for EfsClient {
 
         let mut request =
             SignedRequest::new("PUT", "elasticfilesystem", &self.region, &request_uri);
+        request.set_operation("ModifyMountTargetSecurityGroups");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `put_file_system_policy`, also returning the metadata of the response.
+    pub async fn put_file_system_policy_with_metadata(
+        &self,
+        input: PutFileSystemPolicyRequest,
+    ) -> Result<
+        (FileSystemPolicyDescription, ResponseMetadata),
+        RusotoError<PutFileSystemPolicyError>,
+    > {
+        let request = self.build_put_file_system_policy_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
-        if response.status.as_u16() == 204 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            let result = ::std::mem::drop(response);
+        let metadata = ResponseMetadata::from_response(&response);
+        if response.status.as_u16() == 200 {
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<FileSystemPolicyDescription, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(ModifyMountTargetSecurityGroupsError::from_response(
-                response,
-            ))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(PutFileSystemPolicyError::from_response(response))
         }
     }
 
-    /// <p>Applies an Amazon EFS <code>FileSystemPolicy</code> to an Amazon EFS file system. A file system policy is an IAM resource-based policy and can contain multiple policy statements. A file system always has exactly one file system policy, which can be the default policy or an explicit policy set or updated using this API operation. When an explicit policy is set, it overrides the default policy. For more information about the default file system policy, see <a href="https://docs.aws.amazon.com/efs/latest/ug/res-based-policies-efs.html">Using Resource-based Policies with EFS</a>. </p> <p>This operation requires permissions for the <code>elasticfilesystem:PutFileSystemPolicy</code> action.</p>
-    async fn put_file_system_policy(
+    /// Presigns the request `put_file_system_policy` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_put_file_system_policy(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: PutFileSystemPolicyRequest,
-    ) -> Result<FileSystemPolicyDescription, RusotoError<PutFileSystemPolicyError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_put_file_system_policy_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_put_file_system_policy_request(
+        &self,
+        input: PutFileSystemPolicyRequest,
+    ) -> SignedRequest {
         let request_uri = format!(
             "/2015-02-01/file-systems/{file_system_id}/policy",
             file_system_id = input.file_system_id
@@ -2840,34 +3628,63 @@ impl Efs for EfsClient {
 
         let mut request =
             SignedRequest::new("PUT", "elasticfilesystem", &self.region, &request_uri);
+        request.set_operation("PutFileSystemPolicy");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `put_lifecycle_configuration`, also returning the metadata of the response.
+    pub async fn put_lifecycle_configuration_with_metadata(
+        &self,
+        input: PutLifecycleConfigurationRequest,
+    ) -> Result<
+        (LifecycleConfigurationDescription, ResponseMetadata),
+        RusotoError<PutLifecycleConfigurationError>,
+    > {
+        let request = self.build_put_lifecycle_configuration_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<FileSystemPolicyDescription, _>()?;
+                .deserialize::<LifecycleConfigurationDescription, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(PutFileSystemPolicyError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(PutLifecycleConfigurationError::from_response(response))
         }
     }
 
-    /// <p>Enables lifecycle management by creating a new <code>LifecycleConfiguration</code> object. A <code>LifecycleConfiguration</code> object defines when files in an Amazon EFS file system are automatically transitioned to the lower-cost EFS Infrequent Access (IA) storage class. A <code>LifecycleConfiguration</code> applies to all files in a file system.</p> <p>Each Amazon EFS file system supports one lifecycle configuration, which applies to all files in the file system. If a <code>LifecycleConfiguration</code> object already exists for the specified file system, a <code>PutLifecycleConfiguration</code> call modifies the existing configuration. A <code>PutLifecycleConfiguration</code> call with an empty <code>LifecyclePolicies</code> array in the request body deletes any existing <code>LifecycleConfiguration</code> and disables lifecycle management.</p> <p>In the request, specify the following: </p> <ul> <li> <p>The ID for the file system for which you are enabling, disabling, or modifying lifecycle management.</p> </li> <li> <p>A <code>LifecyclePolicies</code> array of <code>LifecyclePolicy</code> objects that define when files are moved to the IA storage class. The array can contain only one <code>LifecyclePolicy</code> item.</p> </li> </ul> <p>This operation requires permissions for the <code>elasticfilesystem:PutLifecycleConfiguration</code> operation.</p> <p>To apply a <code>LifecycleConfiguration</code> object to an encrypted file system, you need the same AWS Key Management Service (AWS KMS) permissions as when you created the encrypted file system. </p>
-    async fn put_lifecycle_configuration(
+    /// Presigns the request `put_lifecycle_configuration` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_put_lifecycle_configuration(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: PutLifecycleConfigurationRequest,
-    ) -> Result<LifecycleConfigurationDescription, RusotoError<PutLifecycleConfigurationError>>
-    {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_put_lifecycle_configuration_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_put_lifecycle_configuration_request(
+        &self,
+        input: PutLifecycleConfigurationRequest,
+    ) -> SignedRequest {
         let request_uri = format!(
             "/2015-02-01/file-systems/{file_system_id}/lifecycle-configuration",
             file_system_id = input.file_system_id
@@ -2875,33 +3692,56 @@ impl Efs for EfsClient {
 
         let mut request =
             SignedRequest::new("PUT", "elasticfilesystem", &self.region, &request_uri);
+        request.set_operation("PutLifecycleConfiguration");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `tag_resource`, also returning the metadata of the response.
+    pub async fn tag_resource_with_metadata(
+        &self,
+        input: TagResourceRequest,
+    ) -> Result<((), ResponseMetadata), RusotoError<TagResourceError>> {
+        let request = self.build_tag_resource_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<LifecycleConfigurationDescription, _>()?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = ::std::mem::drop(response);
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(PutLifecycleConfigurationError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(TagResourceError::from_response(response))
         }
     }
 
-    /// <p>Creates a tag for an EFS resource. You can create tags for EFS file systems and access points using this API operation.</p> <p>This operation requires permissions for the <code>elasticfilesystem:TagResource</code> action.</p>
-    async fn tag_resource(
+    /// Presigns the request `tag_resource` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_tag_resource(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: TagResourceRequest,
-    ) -> Result<(), RusotoError<TagResourceError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_tag_resource_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_tag_resource_request(&self, input: TagResourceRequest) -> SignedRequest {
         let request_uri = format!(
             "/2015-02-01/resource-tags/{resource_id}",
             resource_id = input.resource_id
@@ -2909,32 +3749,56 @@ impl Efs for EfsClient {
 
         let mut request =
             SignedRequest::new("POST", "elasticfilesystem", &self.region, &request_uri);
+        request.set_operation("TagResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `untag_resource`, also returning the metadata of the response.
+    pub async fn untag_resource_with_metadata(
+        &self,
+        input: UntagResourceRequest,
+    ) -> Result<((), ResponseMetadata), RusotoError<UntagResourceError>> {
+        let request = self.build_untag_resource_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = ::std::mem::drop(response);
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(TagResourceError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(UntagResourceError::from_response(response))
         }
     }
 
-    /// <p>Removes tags from an EFS resource. You can remove tags from EFS file systems and access points using this API operation.</p> <p>This operation requires permissions for the <code>elasticfilesystem:UntagResource</code> action.</p>
-    async fn untag_resource(
+    /// Presigns the request `untag_resource` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_untag_resource(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UntagResourceRequest,
-    ) -> Result<(), RusotoError<UntagResourceError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_untag_resource_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_untag_resource_request(&self, input: UntagResourceRequest) -> SignedRequest {
         let request_uri = format!(
             "/2015-02-01/resource-tags/{resource_id}",
             resource_id = input.resource_id
@@ -2942,32 +3806,57 @@ impl Efs for EfsClient {
 
         let mut request =
             SignedRequest::new("DELETE", "elasticfilesystem", &self.region, &request_uri);
+        request.set_operation("UntagResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `update_file_system`, also returning the metadata of the response.
+    pub async fn update_file_system_with_metadata(
+        &self,
+        input: UpdateFileSystemRequest,
+    ) -> Result<(FileSystemDescription, ResponseMetadata), RusotoError<UpdateFileSystemError>> {
+        let request = self.build_update_file_system_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
-        if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            let result = ::std::mem::drop(response);
+        let metadata = ResponseMetadata::from_response(&response);
+        if response.status.as_u16() == 202 {
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<FileSystemDescription, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(UntagResourceError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(UpdateFileSystemError::from_response(response))
         }
     }
 
-    /// <p>Updates the throughput mode or the amount of provisioned throughput of an existing file system.</p>
-    async fn update_file_system(
+    /// Presigns the request `update_file_system` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_file_system(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UpdateFileSystemRequest,
-    ) -> Result<FileSystemDescription, RusotoError<UpdateFileSystemError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_file_system_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_file_system_request(&self, input: UpdateFileSystemRequest) -> SignedRequest {
         let request_uri = format!(
             "/2015-02-01/file-systems/{file_system_id}",
             file_system_id = input.file_system_id
@@ -2975,25 +3864,397 @@ impl Efs for EfsClient {
 
         let mut request =
             SignedRequest::new("PUT", "elasticfilesystem", &self.region, &request_uri);
+        request.set_operation("UpdateFileSystem");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
-        let mut response = self
-            .client
-            .sign_and_dispatch(request)
+        request
+    }
+
+    /// The pages of `DescribeAccessPoints` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `MaxResults` of `input`.
+    pub fn describe_access_points_pages(
+        &self,
+        input: DescribeAccessPointsRequest,
+    ) -> impl futures::Stream<
+        Item = Result<DescribeAccessPointsResponse, RusotoError<DescribeAccessPointsError>>,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: EfsClient, input| async move { client.describe_access_points(input).await },
+            |input: &DescribeAccessPointsRequest, output: &DescribeAccessPointsResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(DescribeAccessPointsRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `AccessPoints` of every page of `DescribeAccessPoints` results for `input`, as `describe_access_points_pages` requests them.
+    pub fn describe_access_points_stream(
+        &self,
+        input: DescribeAccessPointsRequest,
+    ) -> impl futures::Stream<
+        Item = Result<AccessPointDescription, RusotoError<DescribeAccessPointsError>>,
+    > + Send {
+        rusoto_core::pagination::items(self.describe_access_points_pages(input), |page| {
+            page.access_points.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `DescribeFileSystems` results for `input`, requesting each next page with the `NextMarker` of the last one as its `Marker`.
+    /// The page size is the `MaxItems` of `input`.
+    pub fn describe_file_systems_pages(
+        &self,
+        input: DescribeFileSystemsRequest,
+    ) -> impl futures::Stream<
+        Item = Result<DescribeFileSystemsResponse, RusotoError<DescribeFileSystemsError>>,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: EfsClient, input| async move { client.describe_file_systems(input).await },
+            |input: &DescribeFileSystemsRequest, output: &DescribeFileSystemsResponse| {
+                let marker = output.next_marker.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if marker.is_none() || marker == input.marker {
+                    return None;
+                }
+                Some(DescribeFileSystemsRequest {
+                    marker,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `FileSystems` of every page of `DescribeFileSystems` results for `input`, as `describe_file_systems_pages` requests them.
+    pub fn describe_file_systems_stream(
+        &self,
+        input: DescribeFileSystemsRequest,
+    ) -> impl futures::Stream<
+        Item = Result<FileSystemDescription, RusotoError<DescribeFileSystemsError>>,
+    > + Send {
+        rusoto_core::pagination::items(self.describe_file_systems_pages(input), |page| {
+            page.file_systems.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `DescribeMountTargets` results for `input`, requesting each next page with the `NextMarker` of the last one as its `Marker`.
+    /// The page size is the `MaxItems` of `input`.
+    pub fn describe_mount_targets_pages(
+        &self,
+        input: DescribeMountTargetsRequest,
+    ) -> impl futures::Stream<
+        Item = Result<DescribeMountTargetsResponse, RusotoError<DescribeMountTargetsError>>,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: EfsClient, input| async move { client.describe_mount_targets(input).await },
+            |input: &DescribeMountTargetsRequest, output: &DescribeMountTargetsResponse| {
+                let marker = output.next_marker.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if marker.is_none() || marker == input.marker {
+                    return None;
+                }
+                Some(DescribeMountTargetsRequest {
+                    marker,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `MountTargets` of every page of `DescribeMountTargets` results for `input`, as `describe_mount_targets_pages` requests them.
+    pub fn describe_mount_targets_stream(
+        &self,
+        input: DescribeMountTargetsRequest,
+    ) -> impl futures::Stream<
+        Item = Result<MountTargetDescription, RusotoError<DescribeMountTargetsError>>,
+    > + Send {
+        rusoto_core::pagination::items(self.describe_mount_targets_pages(input), |page| {
+            page.mount_targets.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `DescribeTags` results for `input`, requesting each next page with the `NextMarker` of the last one as its `Marker`.
+    /// The page size is the `MaxItems` of `input`.
+    pub fn describe_tags_pages(
+        &self,
+        input: DescribeTagsRequest,
+    ) -> impl futures::Stream<Item = Result<DescribeTagsResponse, RusotoError<DescribeTagsError>>> + Send
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: EfsClient, input| async move { client.describe_tags(input).await },
+            |input: &DescribeTagsRequest, output: &DescribeTagsResponse| {
+                let marker = output.next_marker.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if marker.is_none() || marker == input.marker {
+                    return None;
+                }
+                Some(DescribeTagsRequest {
+                    marker,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `Tags` of every page of `DescribeTags` results for `input`, as `describe_tags_pages` requests them.
+    pub fn describe_tags_stream(
+        &self,
+        input: DescribeTagsRequest,
+    ) -> impl futures::Stream<Item = Result<Tag, RusotoError<DescribeTagsError>>> + Send {
+        rusoto_core::pagination::items(self.describe_tags_pages(input), |page| page.tags)
+    }
+}
+
+#[async_trait]
+impl Efs for EfsClient {
+    /// <p>Creates an EFS access point. An access point is an application-specific view into an EFS file system that applies an operating system user and group, and a file system path, to any file system request made through the access point. The operating system user and group override any identity information provided by the NFS client. The file system path is exposed as the access point's root directory. Applications using the access point can only access data in its own directory and below. To learn more, see <a href="https://docs.aws.amazon.com/efs/latest/ug/efs-access-points.html">Mounting a File System Using EFS Access Points</a>.</p> <p>This operation requires permissions for the <code>elasticfilesystem:CreateAccessPoint</code> action.</p>
+    async fn create_access_point(
+        &self,
+        input: CreateAccessPointRequest,
+    ) -> Result<AccessPointDescription, RusotoError<CreateAccessPointError>> {
+        self.create_access_point_with_metadata(input)
             .await
-            .map_err(RusotoError::from)?;
-        if response.status.as_u16() == 202 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<FileSystemDescription, _>()?;
+            .map(|(output, _)| output)
+    }
 
-            Ok(result)
-        } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(UpdateFileSystemError::from_response(response))
-        }
+    /// <p>Creates a new, empty file system. The operation requires a creation token in the request that Amazon EFS uses to ensure idempotent creation (calling the operation with same creation token has no effect). If a file system does not currently exist that is owned by the caller's AWS account with the specified creation token, this operation does the following:</p> <ul> <li> <p>Creates a new, empty file system. The file system will have an Amazon EFS assigned ID, and an initial lifecycle state <code>creating</code>.</p> </li> <li> <p>Returns with the description of the created file system.</p> </li> </ul> <p>Otherwise, this operation returns a <code>FileSystemAlreadyExists</code> error with the ID of the existing file system.</p> <note> <p>For basic use cases, you can use a randomly generated UUID for the creation token.</p> </note> <p> The idempotent operation allows you to retry a <code>CreateFileSystem</code> call without risk of creating an extra file system. This can happen when an initial call fails in a way that leaves it uncertain whether or not a file system was actually created. An example might be that a transport level timeout occurred or your connection was reset. As long as you use the same creation token, if the initial call had succeeded in creating a file system, the client can learn of its existence from the <code>FileSystemAlreadyExists</code> error.</p> <note> <p>The <code>CreateFileSystem</code> call returns while the file system's lifecycle state is still <code>creating</code>. You can check the file system creation status by calling the <a>DescribeFileSystems</a> operation, which among other things returns the file system state.</p> </note> <p>This operation also takes an optional <code>PerformanceMode</code> parameter that you choose for your file system. We recommend <code>generalPurpose</code> performance mode for most file systems. File systems using the <code>maxIO</code> performance mode can scale to higher levels of aggregate throughput and operations per second with a tradeoff of slightly higher latencies for most file operations. The performance mode can't be changed after the file system has been created. For more information, see <a href="https://docs.aws.amazon.com/efs/latest/ug/performance.html#performancemodes.html">Amazon EFS: Performance Modes</a>.</p> <p>After the file system is fully created, Amazon EFS sets its lifecycle state to <code>available</code>, at which point you can create one or more mount targets for the file system in your VPC. For more information, see <a>CreateMountTarget</a>. You mount your Amazon EFS file system on an EC2 instances in your VPC by using the mount target. For more information, see <a href="https://docs.aws.amazon.com/efs/latest/ug/how-it-works.html">Amazon EFS: How it Works</a>. </p> <p> This operation requires permissions for the <code>elasticfilesystem:CreateFileSystem</code> action. </p>
+    async fn create_file_system(
+        &self,
+        input: CreateFileSystemRequest,
+    ) -> Result<FileSystemDescription, RusotoError<CreateFileSystemError>> {
+        self.create_file_system_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Creates a mount target for a file system. You can then mount the file system on EC2 instances by using the mount target.</p> <p>You can create one mount target in each Availability Zone in your VPC. All EC2 instances in a VPC within a given Availability Zone share a single mount target for a given file system. If you have multiple subnets in an Availability Zone, you create a mount target in one of the subnets. EC2 instances do not need to be in the same subnet as the mount target in order to access their file system. For more information, see <a href="https://docs.aws.amazon.com/efs/latest/ug/how-it-works.html">Amazon EFS: How it Works</a>. </p> <p>In the request, you also specify a file system ID for which you are creating the mount target and the file system&#39;s lifecycle state must be <code>available</code>. For more information, see <a>DescribeFileSystems</a>.</p> <p>In the request, you also provide a subnet ID, which determines the following:</p> <ul> <li> <p>VPC in which Amazon EFS creates the mount target</p> </li> <li> <p>Availability Zone in which Amazon EFS creates the mount target</p> </li> <li> <p>IP address range from which Amazon EFS selects the IP address of the mount target (if you don&#39;t specify an IP address in the request)</p> </li> </ul> <p>After creating the mount target, Amazon EFS returns a response that includes, a <code>MountTargetId</code> and an <code>IpAddress</code>. You use this IP address when mounting the file system in an EC2 instance. You can also use the mount target&#39;s DNS name when mounting the file system. The EC2 instance on which you mount the file system by using the mount target can resolve the mount target&#39;s DNS name to its IP address. For more information, see <a href="https://docs.aws.amazon.com/efs/latest/ug/how-it-works.html#how-it-works-implementation">How it Works: Implementation Overview</a>. </p> <p>Note that you can create mount targets for a file system in only one VPC, and there can be only one mount target per Availability Zone. That is, if the file system already has one or more mount targets created for it, the subnet specified in the request to add another mount target must meet the following requirements:</p> <ul> <li> <p>Must belong to the same VPC as the subnets of the existing mount targets</p> </li> <li> <p>Must not be in the same Availability Zone as any of the subnets of the existing mount targets</p> </li> </ul> <p>If the request satisfies the requirements, Amazon EFS does the following:</p> <ul> <li> <p>Creates a new mount target in the specified subnet.</p> </li> <li> <p>Also creates a new network interface in the subnet as follows:</p> <ul> <li> <p>If the request provides an <code>IpAddress</code>, Amazon EFS assigns that IP address to the network interface. Otherwise, Amazon EFS assigns a free address in the subnet (in the same way that the Amazon EC2 <code>CreateNetworkInterface</code> call does when a request does not specify a primary private IP address).</p> </li> <li> <p>If the request provides <code>SecurityGroups</code>, this network interface is associated with those security groups. Otherwise, it belongs to the default security group for the subnet&#39;s VPC.</p> </li> <li> <p>Assigns the description <code>Mount target <i>fsmt-id</i> for file system <i>fs-id</i> </code> where <code> <i>fsmt-id</i> </code> is the mount target ID, and <code> <i>fs-id</i> </code> is the <code>FileSystemId</code>.</p> </li> <li> <p>Sets the <code>requesterManaged</code> property of the network interface to <code>true</code>, and the <code>requesterId</code> value to <code>EFS</code>.</p> </li> </ul> <p>Each Amazon EFS mount target has one corresponding requester-managed EC2 network interface. After the network interface is created, Amazon EFS sets the <code>NetworkInterfaceId</code> field in the mount target&#39;s description to the network interface ID, and the <code>IpAddress</code> field to its address. If network interface creation fails, the entire <code>CreateMountTarget</code> operation fails.</p> </li> </ul> <note> <p>The <code>CreateMountTarget</code> call returns only after creating the network interface, but while the mount target state is still <code>creating</code>, you can check the mount target creation status by calling the <a>DescribeMountTargets</a> operation, which among other things returns the mount target state.</p> </note> <p>We recommend that you create a mount target in each of the Availability Zones. There are cost considerations for using a file system in an Availability Zone through a mount target created in another Availability Zone. For more information, see <a href="http://aws.amazon.com/efs/">Amazon EFS</a>. In addition, by always using a mount target local to the instance&#39;s Availability Zone, you eliminate a partial failure scenario. If the Availability Zone in which your mount target is created goes down, then you can&#39;t access your file system through that mount target. </p> <p>This operation requires permissions for the following action on the file system:</p> <ul> <li> <p> <code>elasticfilesystem:CreateMountTarget</code> </p> </li> </ul> <p>This operation also requires permissions for the following Amazon EC2 actions:</p> <ul> <li> <p> <code>ec2:DescribeSubnets</code> </p> </li> <li> <p> <code>ec2:DescribeNetworkInterfaces</code> </p> </li> <li> <p> <code>ec2:CreateNetworkInterface</code> </p> </li> </ul></p>
+    async fn create_mount_target(
+        &self,
+        input: CreateMountTargetRequest,
+    ) -> Result<MountTargetDescription, RusotoError<CreateMountTargetError>> {
+        self.create_mount_target_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Creates or overwrites tags associated with a file system. Each tag is a key-value pair. If a tag key specified in the request already exists on the file system, this operation overwrites its value with the value provided in the request. If you add the <code>Name</code> tag to your file system, Amazon EFS returns it in the response to the <a>DescribeFileSystems</a> operation. </p> <p>This operation requires permission for the <code>elasticfilesystem:CreateTags</code> action.</p>
+    async fn create_tags(
+        &self,
+        input: CreateTagsRequest,
+    ) -> Result<(), RusotoError<CreateTagsError>> {
+        self.create_tags_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes the specified access point. After deletion is complete, new clients can no longer connect to the access points. Clients connected to the access point at the time of deletion will continue to function until they terminate their connection.</p> <p>This operation requires permissions for the <code>elasticfilesystem:DeleteAccessPoint</code> action.</p>
+    async fn delete_access_point(
+        &self,
+        input: DeleteAccessPointRequest,
+    ) -> Result<(), RusotoError<DeleteAccessPointError>> {
+        self.delete_access_point_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes a file system, permanently severing access to its contents. Upon return, the file system no longer exists and you can't access any contents of the deleted file system.</p> <p> You can't delete a file system that is in use. That is, if the file system has any mount targets, you must first delete them. For more information, see <a>DescribeMountTargets</a> and <a>DeleteMountTarget</a>. </p> <note> <p>The <code>DeleteFileSystem</code> call returns while the file system state is still <code>deleting</code>. You can check the file system deletion status by calling the <a>DescribeFileSystems</a> operation, which returns a list of file systems in your account. If you pass file system ID or creation token for the deleted file system, the <a>DescribeFileSystems</a> returns a <code>404 FileSystemNotFound</code> error.</p> </note> <p>This operation requires permissions for the <code>elasticfilesystem:DeleteFileSystem</code> action.</p>
+    async fn delete_file_system(
+        &self,
+        input: DeleteFileSystemRequest,
+    ) -> Result<(), RusotoError<DeleteFileSystemError>> {
+        self.delete_file_system_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes the <code>FileSystemPolicy</code> for the specified file system. The default <code>FileSystemPolicy</code> goes into effect once the existing policy is deleted. For more information about the default file system policy, see <a href="https://docs.aws.amazon.com/efs/latest/ug/res-based-policies-efs.html">Using Resource-based Policies with EFS</a>.</p> <p>This operation requires permissions for the <code>elasticfilesystem:DeleteFileSystemPolicy</code> action.</p>
+    async fn delete_file_system_policy(
+        &self,
+        input: DeleteFileSystemPolicyRequest,
+    ) -> Result<(), RusotoError<DeleteFileSystemPolicyError>> {
+        self.delete_file_system_policy_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Deletes the specified mount target.</p> <p>This operation forcibly breaks any mounts of the file system by using the mount target that is being deleted, which might disrupt instances or applications using those mounts. To avoid applications getting cut off abruptly, you might consider unmounting any mounts of the mount target, if feasible. The operation also deletes the associated network interface. Uncommitted writes might be lost, but breaking a mount target using this operation does not corrupt the file system itself. The file system you created remains. You can mount an EC2 instance in your VPC by using another mount target.</p> <p>This operation requires permissions for the following action on the file system:</p> <ul> <li> <p> <code>elasticfilesystem:DeleteMountTarget</code> </p> </li> </ul> <note> <p>The <code>DeleteMountTarget</code> call returns while the mount target state is still <code>deleting</code>. You can check the mount target deletion by calling the <a>DescribeMountTargets</a> operation, which returns a list of mount target descriptions for the given file system. </p> </note> <p>The operation also requires permissions for the following Amazon EC2 action on the mount target&#39;s network interface:</p> <ul> <li> <p> <code>ec2:DeleteNetworkInterface</code> </p> </li> </ul></p>
+    async fn delete_mount_target(
+        &self,
+        input: DeleteMountTargetRequest,
+    ) -> Result<(), RusotoError<DeleteMountTargetError>> {
+        self.delete_mount_target_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes the specified tags from a file system. If the <code>DeleteTags</code> request includes a tag key that doesn't exist, Amazon EFS ignores it and doesn't cause an error. For more information about tags and related restrictions, see <a href="https://docs.aws.amazon.com/awsaccountbilling/latest/aboutv2/cost-alloc-tags.html">Tag Restrictions</a> in the <i>AWS Billing and Cost Management User Guide</i>.</p> <p>This operation requires permissions for the <code>elasticfilesystem:DeleteTags</code> action.</p>
+    async fn delete_tags(
+        &self,
+        input: DeleteTagsRequest,
+    ) -> Result<(), RusotoError<DeleteTagsError>> {
+        self.delete_tags_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns the description of a specific Amazon EFS access point if the <code>AccessPointId</code> is provided. If you provide an EFS <code>FileSystemId</code>, it returns descriptions of all access points for that file system. You can provide either an <code>AccessPointId</code> or a <code>FileSystemId</code> in the request, but not both. </p> <p>This operation requires permissions for the <code>elasticfilesystem:DescribeAccessPoints</code> action.</p>
+    async fn describe_access_points(
+        &self,
+        input: DescribeAccessPointsRequest,
+    ) -> Result<DescribeAccessPointsResponse, RusotoError<DescribeAccessPointsError>> {
+        self.describe_access_points_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns the <code>FileSystemPolicy</code> for the specified EFS file system.</p> <p>This operation requires permissions for the <code>elasticfilesystem:DescribeFileSystemPolicy</code> action.</p>
+    async fn describe_file_system_policy(
+        &self,
+        input: DescribeFileSystemPolicyRequest,
+    ) -> Result<FileSystemPolicyDescription, RusotoError<DescribeFileSystemPolicyError>> {
+        self.describe_file_system_policy_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns the description of a specific Amazon EFS file system if either the file system <code>CreationToken</code> or the <code>FileSystemId</code> is provided. Otherwise, it returns descriptions of all file systems owned by the caller's AWS account in the AWS Region of the endpoint that you're calling.</p> <p>When retrieving all file system descriptions, you can optionally specify the <code>MaxItems</code> parameter to limit the number of descriptions in a response. Currently, this number is automatically set to 10. If more file system descriptions remain, Amazon EFS returns a <code>NextMarker</code>, an opaque token, in the response. In this case, you should send a subsequent request with the <code>Marker</code> request parameter set to the value of <code>NextMarker</code>. </p> <p>To retrieve a list of your file system descriptions, this operation is used in an iterative process, where <code>DescribeFileSystems</code> is called first without the <code>Marker</code> and then the operation continues to call it with the <code>Marker</code> parameter set to the value of the <code>NextMarker</code> from the previous response until the response has no <code>NextMarker</code>. </p> <p> The order of file systems returned in the response of one <code>DescribeFileSystems</code> call and the order of file systems returned across the responses of a multi-call iteration is unspecified. </p> <p> This operation requires permissions for the <code>elasticfilesystem:DescribeFileSystems</code> action. </p>
+    async fn describe_file_systems(
+        &self,
+        input: DescribeFileSystemsRequest,
+    ) -> Result<DescribeFileSystemsResponse, RusotoError<DescribeFileSystemsError>> {
+        self.describe_file_systems_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns the current <code>LifecycleConfiguration</code> object for the specified Amazon EFS file system. EFS lifecycle management uses the <code>LifecycleConfiguration</code> object to identify which files to move to the EFS Infrequent Access (IA) storage class. For a file system without a <code>LifecycleConfiguration</code> object, the call returns an empty array in the response.</p> <p>This operation requires permissions for the <code>elasticfilesystem:DescribeLifecycleConfiguration</code> operation.</p>
+    async fn describe_lifecycle_configuration(
+        &self,
+        input: DescribeLifecycleConfigurationRequest,
+    ) -> Result<LifecycleConfigurationDescription, RusotoError<DescribeLifecycleConfigurationError>>
+    {
+        self.describe_lifecycle_configuration_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Returns the security groups currently in effect for a mount target. This operation requires that the network interface of the mount target has been created and the lifecycle state of the mount target is not <code>deleted</code>.</p> <p>This operation requires permissions for the following actions:</p> <ul> <li> <p> <code>elasticfilesystem:DescribeMountTargetSecurityGroups</code> action on the mount target&#39;s file system. </p> </li> <li> <p> <code>ec2:DescribeNetworkInterfaceAttribute</code> action on the mount target&#39;s network interface. </p> </li> </ul></p>
+    async fn describe_mount_target_security_groups(
+        &self,
+        input: DescribeMountTargetSecurityGroupsRequest,
+    ) -> Result<
+        DescribeMountTargetSecurityGroupsResponse,
+        RusotoError<DescribeMountTargetSecurityGroupsError>,
+    > {
+        self.describe_mount_target_security_groups_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns the descriptions of all the current mount targets, or a specific mount target, for a file system. When requesting all of the current mount targets, the order of mount targets returned in the response is unspecified.</p> <p>This operation requires permissions for the <code>elasticfilesystem:DescribeMountTargets</code> action, on either the file system ID that you specify in <code>FileSystemId</code>, or on the file system of the mount target that you specify in <code>MountTargetId</code>.</p>
+    async fn describe_mount_targets(
+        &self,
+        input: DescribeMountTargetsRequest,
+    ) -> Result<DescribeMountTargetsResponse, RusotoError<DescribeMountTargetsError>> {
+        self.describe_mount_targets_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns the tags associated with a file system. The order of tags returned in the response of one <code>DescribeTags</code> call and the order of tags returned across the responses of a multiple-call iteration (when using pagination) is unspecified. </p> <p> This operation requires permissions for the <code>elasticfilesystem:DescribeTags</code> action. </p>
+    async fn describe_tags(
+        &self,
+        input: DescribeTagsRequest,
+    ) -> Result<DescribeTagsResponse, RusotoError<DescribeTagsError>> {
+        self.describe_tags_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Lists all tags for a top-level EFS resource. You must provide the ID of the resource that you want to retrieve the tags for.</p> <p>This operation requires permissions for the <code>elasticfilesystem:DescribeAccessPoints</code> action.</p>
+    async fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> Result<ListTagsForResourceResponse, RusotoError<ListTagsForResourceError>> {
+        self.list_tags_for_resource_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Modifies the set of security groups in effect for a mount target.</p> <p>When you create a mount target, Amazon EFS also creates a new network interface. For more information, see <a>CreateMountTarget</a>. This operation replaces the security groups in effect for the network interface associated with a mount target, with the <code>SecurityGroups</code> provided in the request. This operation requires that the network interface of the mount target has been created and the lifecycle state of the mount target is not <code>deleted</code>. </p> <p>The operation requires permissions for the following actions:</p> <ul> <li> <p> <code>elasticfilesystem:ModifyMountTargetSecurityGroups</code> action on the mount target&#39;s file system. </p> </li> <li> <p> <code>ec2:ModifyNetworkInterfaceAttribute</code> action on the mount target&#39;s network interface. </p> </li> </ul></p>
+    async fn modify_mount_target_security_groups(
+        &self,
+        input: ModifyMountTargetSecurityGroupsRequest,
+    ) -> Result<(), RusotoError<ModifyMountTargetSecurityGroupsError>> {
+        self.modify_mount_target_security_groups_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Applies an Amazon EFS <code>FileSystemPolicy</code> to an Amazon EFS file system. A file system policy is an IAM resource-based policy and can contain multiple policy statements. A file system always has exactly one file system policy, which can be the default policy or an explicit policy set or updated using this API operation. When an explicit policy is set, it overrides the default policy. For more information about the default file system policy, see <a href="https://docs.aws.amazon.com/efs/latest/ug/res-based-policies-efs.html">Using Resource-based Policies with EFS</a>. </p> <p>This operation requires permissions for the <code>elasticfilesystem:PutFileSystemPolicy</code> action.</p>
+    async fn put_file_system_policy(
+        &self,
+        input: PutFileSystemPolicyRequest,
+    ) -> Result<FileSystemPolicyDescription, RusotoError<PutFileSystemPolicyError>> {
+        self.put_file_system_policy_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Enables lifecycle management by creating a new <code>LifecycleConfiguration</code> object. A <code>LifecycleConfiguration</code> object defines when files in an Amazon EFS file system are automatically transitioned to the lower-cost EFS Infrequent Access (IA) storage class. A <code>LifecycleConfiguration</code> applies to all files in a file system.</p> <p>Each Amazon EFS file system supports one lifecycle configuration, which applies to all files in the file system. If a <code>LifecycleConfiguration</code> object already exists for the specified file system, a <code>PutLifecycleConfiguration</code> call modifies the existing configuration. A <code>PutLifecycleConfiguration</code> call with an empty <code>LifecyclePolicies</code> array in the request body deletes any existing <code>LifecycleConfiguration</code> and disables lifecycle management.</p> <p>In the request, specify the following: </p> <ul> <li> <p>The ID for the file system for which you are enabling, disabling, or modifying lifecycle management.</p> </li> <li> <p>A <code>LifecyclePolicies</code> array of <code>LifecyclePolicy</code> objects that define when files are moved to the IA storage class. The array can contain only one <code>LifecyclePolicy</code> item.</p> </li> </ul> <p>This operation requires permissions for the <code>elasticfilesystem:PutLifecycleConfiguration</code> operation.</p> <p>To apply a <code>LifecycleConfiguration</code> object to an encrypted file system, you need the same AWS Key Management Service (AWS KMS) permissions as when you created the encrypted file system. </p>
+    async fn put_lifecycle_configuration(
+        &self,
+        input: PutLifecycleConfigurationRequest,
+    ) -> Result<LifecycleConfigurationDescription, RusotoError<PutLifecycleConfigurationError>>
+    {
+        self.put_lifecycle_configuration_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Creates a tag for an EFS resource. You can create tags for EFS file systems and access points using this API operation.</p> <p>This operation requires permissions for the <code>elasticfilesystem:TagResource</code> action.</p>
+    async fn tag_resource(
+        &self,
+        input: TagResourceRequest,
+    ) -> Result<(), RusotoError<TagResourceError>> {
+        self.tag_resource_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Removes tags from an EFS resource. You can remove tags from EFS file systems and access points using this API operation.</p> <p>This operation requires permissions for the <code>elasticfilesystem:UntagResource</code> action.</p>
+    async fn untag_resource(
+        &self,
+        input: UntagResourceRequest,
+    ) -> Result<(), RusotoError<UntagResourceError>> {
+        self.untag_resource_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Updates the throughput mode or the amount of provisioned throughput of an existing file system.</p>
+    async fn update_file_system(
+        &self,
+        input: UpdateFileSystemRequest,
+    ) -> Result<FileSystemDescription, RusotoError<UpdateFileSystemError>> {
+        self.update_file_system_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
     }
 }
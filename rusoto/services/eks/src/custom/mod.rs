@@ -1 +1,3 @@
+mod token;
 
+pub use self::token::{cluster_token, get_cluster_token, EksToken};
@@ -16,8 +16,8 @@ use std::fmt;
 use async_trait::async_trait;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
-use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest, ResponseMetadata};
+use rusoto_core::{Client, ErrorMetadata, RusotoError};
 
 use rusoto_core::param::{Params, ServiceParams};
 use rusoto_core::proto;
@@ -1059,6 +1059,7 @@ pub struct VpcConfigResponse {
 
 /// Errors returned by CreateCluster
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateClusterError {
     /// <p>These errors are usually caused by a client action. Actions can include using an action or resource on behalf of a user that doesn't have permissions to use the action or resource or specifying an identifier that is not valid.</p>
     Client(String),
@@ -1074,6 +1075,8 @@ pub enum CreateClusterError {
     ServiceUnavailable(String),
     /// <p>At least one of your specified cluster subnets is in an Availability Zone that does not support Amazon EKS. The exception output specifies the supported Availability Zones for your account, from which you can choose subnets for your cluster.</p>
     UnsupportedAvailabilityZone(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateClusterError {
@@ -1081,30 +1084,40 @@ impl CreateClusterError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ClientException" => {
-                    return RusotoError::Service(CreateClusterError::Client(err.msg))
+                    return RusotoError::service(CreateClusterError::Client(err.msg), &res)
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(CreateClusterError::InvalidParameter(err.msg))
+                    return RusotoError::service(
+                        CreateClusterError::InvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(CreateClusterError::ResourceInUse(err.msg))
+                    return RusotoError::service(CreateClusterError::ResourceInUse(err.msg), &res)
                 }
                 "ResourceLimitExceededException" => {
-                    return RusotoError::Service(CreateClusterError::ResourceLimitExceeded(err.msg))
+                    return RusotoError::service(
+                        CreateClusterError::ResourceLimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "ServerException" => {
-                    return RusotoError::Service(CreateClusterError::Server(err.msg))
+                    return RusotoError::service(CreateClusterError::Server(err.msg), &res)
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(CreateClusterError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        CreateClusterError::ServiceUnavailable(err.msg),
+                        &res,
+                    )
                 }
                 "UnsupportedAvailabilityZoneException" => {
-                    return RusotoError::Service(CreateClusterError::UnsupportedAvailabilityZone(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateClusterError::UnsupportedAvailabilityZone(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreateClusterError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1121,12 +1134,14 @@ impl fmt::Display for CreateClusterError {
             CreateClusterError::Server(ref cause) => write!(f, "{}", cause),
             CreateClusterError::ServiceUnavailable(ref cause) => write!(f, "{}", cause),
             CreateClusterError::UnsupportedAvailabilityZone(ref cause) => write!(f, "{}", cause),
+            CreateClusterError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateClusterError {}
 /// Errors returned by CreateFargateProfile
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateFargateProfileError {
     /// <p>These errors are usually caused by a client action. Actions can include using an action or resource on behalf of a user that doesn't have permissions to use the action or resource or specifying an identifier that is not valid.</p>
     Client(String),
@@ -1140,6 +1155,8 @@ pub enum CreateFargateProfileError {
     Server(String),
     /// <p>At least one of your specified cluster subnets is in an Availability Zone that does not support Amazon EKS. The exception output specifies the supported Availability Zones for your account, from which you can choose subnets for your cluster.</p>
     UnsupportedAvailabilityZone(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateFargateProfileError {
@@ -1147,31 +1164,37 @@ impl CreateFargateProfileError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ClientException" => {
-                    return RusotoError::Service(CreateFargateProfileError::Client(err.msg))
+                    return RusotoError::service(CreateFargateProfileError::Client(err.msg), &res)
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(CreateFargateProfileError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateFargateProfileError::InvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(CreateFargateProfileError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        CreateFargateProfileError::InvalidRequest(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceLimitExceededException" => {
-                    return RusotoError::Service(CreateFargateProfileError::ResourceLimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateFargateProfileError::ResourceLimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "ServerException" => {
-                    return RusotoError::Service(CreateFargateProfileError::Server(err.msg))
+                    return RusotoError::service(CreateFargateProfileError::Server(err.msg), &res)
                 }
                 "UnsupportedAvailabilityZoneException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         CreateFargateProfileError::UnsupportedAvailabilityZone(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreateFargateProfileError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1189,12 +1212,14 @@ impl fmt::Display for CreateFargateProfileError {
             CreateFargateProfileError::UnsupportedAvailabilityZone(ref cause) => {
                 write!(f, "{}", cause)
             }
+            CreateFargateProfileError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateFargateProfileError {}
 /// Errors returned by CreateNodegroup
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateNodegroupError {
     /// <p>These errors are usually caused by a client action. Actions can include using an action or resource on behalf of a user that doesn't have permissions to use the action or resource or specifying an identifier that is not valid.</p>
     Client(String),
@@ -1210,6 +1235,8 @@ pub enum CreateNodegroupError {
     Server(String),
     /// <p>The service is unavailable. Back off and retry the operation.</p>
     ServiceUnavailable(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateNodegroupError {
@@ -1217,30 +1244,40 @@ impl CreateNodegroupError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ClientException" => {
-                    return RusotoError::Service(CreateNodegroupError::Client(err.msg))
+                    return RusotoError::service(CreateNodegroupError::Client(err.msg), &res)
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(CreateNodegroupError::InvalidParameter(err.msg))
+                    return RusotoError::service(
+                        CreateNodegroupError::InvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(CreateNodegroupError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        CreateNodegroupError::InvalidRequest(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(CreateNodegroupError::ResourceInUse(err.msg))
+                    return RusotoError::service(CreateNodegroupError::ResourceInUse(err.msg), &res)
                 }
                 "ResourceLimitExceededException" => {
-                    return RusotoError::Service(CreateNodegroupError::ResourceLimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateNodegroupError::ResourceLimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "ServerException" => {
-                    return RusotoError::Service(CreateNodegroupError::Server(err.msg))
+                    return RusotoError::service(CreateNodegroupError::Server(err.msg), &res)
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(CreateNodegroupError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        CreateNodegroupError::ServiceUnavailable(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreateNodegroupError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1257,12 +1294,14 @@ impl fmt::Display for CreateNodegroupError {
             CreateNodegroupError::ResourceLimitExceeded(ref cause) => write!(f, "{}", cause),
             CreateNodegroupError::Server(ref cause) => write!(f, "{}", cause),
             CreateNodegroupError::ServiceUnavailable(ref cause) => write!(f, "{}", cause),
+            CreateNodegroupError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateNodegroupError {}
 /// Errors returned by DeleteCluster
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteClusterError {
     /// <p>These errors are usually caused by a client action. Actions can include using an action or resource on behalf of a user that doesn't have permissions to use the action or resource or specifying an identifier that is not valid.</p>
     Client(String),
@@ -1274,6 +1313,8 @@ pub enum DeleteClusterError {
     Server(String),
     /// <p>The service is unavailable. Back off and retry the operation.</p>
     ServiceUnavailable(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteClusterError {
@@ -1281,22 +1322,28 @@ impl DeleteClusterError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ClientException" => {
-                    return RusotoError::Service(DeleteClusterError::Client(err.msg))
+                    return RusotoError::service(DeleteClusterError::Client(err.msg), &res)
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(DeleteClusterError::ResourceInUse(err.msg))
+                    return RusotoError::service(DeleteClusterError::ResourceInUse(err.msg), &res)
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteClusterError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        DeleteClusterError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ServerException" => {
-                    return RusotoError::Service(DeleteClusterError::Server(err.msg))
+                    return RusotoError::service(DeleteClusterError::Server(err.msg), &res)
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(DeleteClusterError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        DeleteClusterError::ServiceUnavailable(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteClusterError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1311,12 +1358,14 @@ impl fmt::Display for DeleteClusterError {
             DeleteClusterError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             DeleteClusterError::Server(ref cause) => write!(f, "{}", cause),
             DeleteClusterError::ServiceUnavailable(ref cause) => write!(f, "{}", cause),
+            DeleteClusterError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteClusterError {}
 /// Errors returned by DeleteFargateProfile
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteFargateProfileError {
     /// <p>These errors are usually caused by a client action. Actions can include using an action or resource on behalf of a user that doesn't have permissions to use the action or resource or specifying an identifier that is not valid.</p>
     Client(String),
@@ -1326,6 +1375,8 @@ pub enum DeleteFargateProfileError {
     ResourceNotFound(String),
     /// <p>These errors are usually caused by a server-side issue.</p>
     Server(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteFargateProfileError {
@@ -1333,23 +1384,25 @@ impl DeleteFargateProfileError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ClientException" => {
-                    return RusotoError::Service(DeleteFargateProfileError::Client(err.msg))
+                    return RusotoError::service(DeleteFargateProfileError::Client(err.msg), &res)
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DeleteFargateProfileError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteFargateProfileError::InvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteFargateProfileError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteFargateProfileError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ServerException" => {
-                    return RusotoError::Service(DeleteFargateProfileError::Server(err.msg))
+                    return RusotoError::service(DeleteFargateProfileError::Server(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteFargateProfileError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1363,12 +1416,14 @@ impl fmt::Display for DeleteFargateProfileError {
             DeleteFargateProfileError::InvalidParameter(ref cause) => write!(f, "{}", cause),
             DeleteFargateProfileError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             DeleteFargateProfileError::Server(ref cause) => write!(f, "{}", cause),
+            DeleteFargateProfileError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteFargateProfileError {}
 /// Errors returned by DeleteNodegroup
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteNodegroupError {
     /// <p>These errors are usually caused by a client action. Actions can include using an action or resource on behalf of a user that doesn't have permissions to use the action or resource or specifying an identifier that is not valid.</p>
     Client(String),
@@ -1382,6 +1437,8 @@ pub enum DeleteNodegroupError {
     Server(String),
     /// <p>The service is unavailable. Back off and retry the operation.</p>
     ServiceUnavailable(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteNodegroupError {
@@ -1389,25 +1446,34 @@ impl DeleteNodegroupError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ClientException" => {
-                    return RusotoError::Service(DeleteNodegroupError::Client(err.msg))
+                    return RusotoError::service(DeleteNodegroupError::Client(err.msg), &res)
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DeleteNodegroupError::InvalidParameter(err.msg))
+                    return RusotoError::service(
+                        DeleteNodegroupError::InvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(DeleteNodegroupError::ResourceInUse(err.msg))
+                    return RusotoError::service(DeleteNodegroupError::ResourceInUse(err.msg), &res)
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteNodegroupError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        DeleteNodegroupError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ServerException" => {
-                    return RusotoError::Service(DeleteNodegroupError::Server(err.msg))
+                    return RusotoError::service(DeleteNodegroupError::Server(err.msg), &res)
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(DeleteNodegroupError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        DeleteNodegroupError::ServiceUnavailable(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteNodegroupError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1423,12 +1489,14 @@ impl fmt::Display for DeleteNodegroupError {
             DeleteNodegroupError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             DeleteNodegroupError::Server(ref cause) => write!(f, "{}", cause),
             DeleteNodegroupError::ServiceUnavailable(ref cause) => write!(f, "{}", cause),
+            DeleteNodegroupError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteNodegroupError {}
 /// Errors returned by DescribeCluster
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeClusterError {
     /// <p>These errors are usually caused by a client action. Actions can include using an action or resource on behalf of a user that doesn't have permissions to use the action or resource or specifying an identifier that is not valid.</p>
     Client(String),
@@ -1438,6 +1506,8 @@ pub enum DescribeClusterError {
     Server(String),
     /// <p>The service is unavailable. Back off and retry the operation.</p>
     ServiceUnavailable(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeClusterError {
@@ -1445,19 +1515,25 @@ impl DescribeClusterError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ClientException" => {
-                    return RusotoError::Service(DescribeClusterError::Client(err.msg))
+                    return RusotoError::service(DescribeClusterError::Client(err.msg), &res)
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeClusterError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        DescribeClusterError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ServerException" => {
-                    return RusotoError::Service(DescribeClusterError::Server(err.msg))
+                    return RusotoError::service(DescribeClusterError::Server(err.msg), &res)
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(DescribeClusterError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        DescribeClusterError::ServiceUnavailable(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DescribeClusterError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1471,12 +1547,14 @@ impl fmt::Display for DescribeClusterError {
             DescribeClusterError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             DescribeClusterError::Server(ref cause) => write!(f, "{}", cause),
             DescribeClusterError::ServiceUnavailable(ref cause) => write!(f, "{}", cause),
+            DescribeClusterError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeClusterError {}
 /// Errors returned by DescribeFargateProfile
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeFargateProfileError {
     /// <p>These errors are usually caused by a client action. Actions can include using an action or resource on behalf of a user that doesn't have permissions to use the action or resource or specifying an identifier that is not valid.</p>
     Client(String),
@@ -1486,6 +1564,8 @@ pub enum DescribeFargateProfileError {
     ResourceNotFound(String),
     /// <p>These errors are usually caused by a server-side issue.</p>
     Server(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeFargateProfileError {
@@ -1493,23 +1573,25 @@ impl DescribeFargateProfileError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ClientException" => {
-                    return RusotoError::Service(DescribeFargateProfileError::Client(err.msg))
+                    return RusotoError::service(DescribeFargateProfileError::Client(err.msg), &res)
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DescribeFargateProfileError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeFargateProfileError::InvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeFargateProfileError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeFargateProfileError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ServerException" => {
-                    return RusotoError::Service(DescribeFargateProfileError::Server(err.msg))
+                    return RusotoError::service(DescribeFargateProfileError::Server(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DescribeFargateProfileError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1523,12 +1605,14 @@ impl fmt::Display for DescribeFargateProfileError {
             DescribeFargateProfileError::InvalidParameter(ref cause) => write!(f, "{}", cause),
             DescribeFargateProfileError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             DescribeFargateProfileError::Server(ref cause) => write!(f, "{}", cause),
+            DescribeFargateProfileError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeFargateProfileError {}
 /// Errors returned by DescribeNodegroup
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeNodegroupError {
     /// <p>These errors are usually caused by a client action. Actions can include using an action or resource on behalf of a user that doesn't have permissions to use the action or resource or specifying an identifier that is not valid.</p>
     Client(String),
@@ -1540,6 +1624,8 @@ pub enum DescribeNodegroupError {
     Server(String),
     /// <p>The service is unavailable. Back off and retry the operation.</p>
     ServiceUnavailable(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeNodegroupError {
@@ -1547,24 +1633,31 @@ impl DescribeNodegroupError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ClientException" => {
-                    return RusotoError::Service(DescribeNodegroupError::Client(err.msg))
+                    return RusotoError::service(DescribeNodegroupError::Client(err.msg), &res)
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DescribeNodegroupError::InvalidParameter(err.msg))
+                    return RusotoError::service(
+                        DescribeNodegroupError::InvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeNodegroupError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        DescribeNodegroupError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ServerException" => {
-                    return RusotoError::Service(DescribeNodegroupError::Server(err.msg))
+                    return RusotoError::service(DescribeNodegroupError::Server(err.msg), &res)
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(DescribeNodegroupError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeNodegroupError::ServiceUnavailable(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DescribeNodegroupError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1579,12 +1672,14 @@ impl fmt::Display for DescribeNodegroupError {
             DescribeNodegroupError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             DescribeNodegroupError::Server(ref cause) => write!(f, "{}", cause),
             DescribeNodegroupError::ServiceUnavailable(ref cause) => write!(f, "{}", cause),
+            DescribeNodegroupError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeNodegroupError {}
 /// Errors returned by DescribeUpdate
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeUpdateError {
     /// <p>These errors are usually caused by a client action. Actions can include using an action or resource on behalf of a user that doesn't have permissions to use the action or resource or specifying an identifier that is not valid.</p>
     Client(String),
@@ -1594,6 +1689,8 @@ pub enum DescribeUpdateError {
     ResourceNotFound(String),
     /// <p>These errors are usually caused by a server-side issue.</p>
     Server(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeUpdateError {
@@ -1601,19 +1698,25 @@ impl DescribeUpdateError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ClientException" => {
-                    return RusotoError::Service(DescribeUpdateError::Client(err.msg))
+                    return RusotoError::service(DescribeUpdateError::Client(err.msg), &res)
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DescribeUpdateError::InvalidParameter(err.msg))
+                    return RusotoError::service(
+                        DescribeUpdateError::InvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeUpdateError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        DescribeUpdateError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ServerException" => {
-                    return RusotoError::Service(DescribeUpdateError::Server(err.msg))
+                    return RusotoError::service(DescribeUpdateError::Server(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DescribeUpdateError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1627,12 +1730,14 @@ impl fmt::Display for DescribeUpdateError {
             DescribeUpdateError::InvalidParameter(ref cause) => write!(f, "{}", cause),
             DescribeUpdateError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             DescribeUpdateError::Server(ref cause) => write!(f, "{}", cause),
+            DescribeUpdateError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeUpdateError {}
 /// Errors returned by ListClusters
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListClustersError {
     /// <p>These errors are usually caused by a client action. Actions can include using an action or resource on behalf of a user that doesn't have permissions to use the action or resource or specifying an identifier that is not valid.</p>
     Client(String),
@@ -1642,6 +1747,8 @@ pub enum ListClustersError {
     Server(String),
     /// <p>The service is unavailable. Back off and retry the operation.</p>
     ServiceUnavailable(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListClustersError {
@@ -1649,19 +1756,22 @@ impl ListClustersError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ClientException" => {
-                    return RusotoError::Service(ListClustersError::Client(err.msg))
+                    return RusotoError::service(ListClustersError::Client(err.msg), &res)
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(ListClustersError::InvalidParameter(err.msg))
+                    return RusotoError::service(ListClustersError::InvalidParameter(err.msg), &res)
                 }
                 "ServerException" => {
-                    return RusotoError::Service(ListClustersError::Server(err.msg))
+                    return RusotoError::service(ListClustersError::Server(err.msg), &res)
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(ListClustersError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        ListClustersError::ServiceUnavailable(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListClustersError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1675,12 +1785,14 @@ impl fmt::Display for ListClustersError {
             ListClustersError::InvalidParameter(ref cause) => write!(f, "{}", cause),
             ListClustersError::Server(ref cause) => write!(f, "{}", cause),
             ListClustersError::ServiceUnavailable(ref cause) => write!(f, "{}", cause),
+            ListClustersError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListClustersError {}
 /// Errors returned by ListFargateProfiles
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListFargateProfilesError {
     /// <p>These errors are usually caused by a client action. Actions can include using an action or resource on behalf of a user that doesn't have permissions to use the action or resource or specifying an identifier that is not valid.</p>
     Client(String),
@@ -1690,6 +1802,8 @@ pub enum ListFargateProfilesError {
     ResourceNotFound(String),
     /// <p>These errors are usually caused by a server-side issue.</p>
     Server(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListFargateProfilesError {
@@ -1697,23 +1811,25 @@ impl ListFargateProfilesError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ClientException" => {
-                    return RusotoError::Service(ListFargateProfilesError::Client(err.msg))
+                    return RusotoError::service(ListFargateProfilesError::Client(err.msg), &res)
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(ListFargateProfilesError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListFargateProfilesError::InvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListFargateProfilesError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListFargateProfilesError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ServerException" => {
-                    return RusotoError::Service(ListFargateProfilesError::Server(err.msg))
+                    return RusotoError::service(ListFargateProfilesError::Server(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListFargateProfilesError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1727,12 +1843,14 @@ impl fmt::Display for ListFargateProfilesError {
             ListFargateProfilesError::InvalidParameter(ref cause) => write!(f, "{}", cause),
             ListFargateProfilesError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             ListFargateProfilesError::Server(ref cause) => write!(f, "{}", cause),
+            ListFargateProfilesError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListFargateProfilesError {}
 /// Errors returned by ListNodegroups
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListNodegroupsError {
     /// <p>These errors are usually caused by a client action. Actions can include using an action or resource on behalf of a user that doesn't have permissions to use the action or resource or specifying an identifier that is not valid.</p>
     Client(String),
@@ -1744,6 +1862,8 @@ pub enum ListNodegroupsError {
     Server(String),
     /// <p>The service is unavailable. Back off and retry the operation.</p>
     ServiceUnavailable(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListNodegroupsError {
@@ -1751,22 +1871,31 @@ impl ListNodegroupsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ClientException" => {
-                    return RusotoError::Service(ListNodegroupsError::Client(err.msg))
+                    return RusotoError::service(ListNodegroupsError::Client(err.msg), &res)
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(ListNodegroupsError::InvalidParameter(err.msg))
+                    return RusotoError::service(
+                        ListNodegroupsError::InvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListNodegroupsError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        ListNodegroupsError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ServerException" => {
-                    return RusotoError::Service(ListNodegroupsError::Server(err.msg))
+                    return RusotoError::service(ListNodegroupsError::Server(err.msg), &res)
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(ListNodegroupsError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        ListNodegroupsError::ServiceUnavailable(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListNodegroupsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1781,17 +1910,21 @@ impl fmt::Display for ListNodegroupsError {
             ListNodegroupsError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             ListNodegroupsError::Server(ref cause) => write!(f, "{}", cause),
             ListNodegroupsError::ServiceUnavailable(ref cause) => write!(f, "{}", cause),
+            ListNodegroupsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListNodegroupsError {}
 /// Errors returned by ListTagsForResource
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListTagsForResourceError {
     /// <p>This exception is thrown if the request contains a semantic error. The precise meaning will depend on the API, and will be documented in the error message.</p>
     BadRequest(String),
     /// <p>A service resource associated with the request could not be found. Clients should not retry such requests.</p>
     NotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListTagsForResourceError {
@@ -1799,13 +1932,16 @@ impl ListTagsForResourceError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(ListTagsForResourceError::BadRequest(err.msg))
+                    return RusotoError::service(
+                        ListTagsForResourceError::BadRequest(err.msg),
+                        &res,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(ListTagsForResourceError::NotFound(err.msg))
+                    return RusotoError::service(ListTagsForResourceError::NotFound(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListTagsForResourceError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1817,12 +1953,14 @@ impl fmt::Display for ListTagsForResourceError {
         match *self {
             ListTagsForResourceError::BadRequest(ref cause) => write!(f, "{}", cause),
             ListTagsForResourceError::NotFound(ref cause) => write!(f, "{}", cause),
+            ListTagsForResourceError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListTagsForResourceError {}
 /// Errors returned by ListUpdates
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListUpdatesError {
     /// <p>These errors are usually caused by a client action. Actions can include using an action or resource on behalf of a user that doesn't have permissions to use the action or resource or specifying an identifier that is not valid.</p>
     Client(String),
@@ -1832,6 +1970,8 @@ pub enum ListUpdatesError {
     ResourceNotFound(String),
     /// <p>These errors are usually caused by a server-side issue.</p>
     Server(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListUpdatesError {
@@ -1839,19 +1979,19 @@ impl ListUpdatesError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ClientException" => {
-                    return RusotoError::Service(ListUpdatesError::Client(err.msg))
+                    return RusotoError::service(ListUpdatesError::Client(err.msg), &res)
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(ListUpdatesError::InvalidParameter(err.msg))
+                    return RusotoError::service(ListUpdatesError::InvalidParameter(err.msg), &res)
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListUpdatesError::ResourceNotFound(err.msg))
+                    return RusotoError::service(ListUpdatesError::ResourceNotFound(err.msg), &res)
                 }
                 "ServerException" => {
-                    return RusotoError::Service(ListUpdatesError::Server(err.msg))
+                    return RusotoError::service(ListUpdatesError::Server(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListUpdatesError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1865,17 +2005,21 @@ impl fmt::Display for ListUpdatesError {
             ListUpdatesError::InvalidParameter(ref cause) => write!(f, "{}", cause),
             ListUpdatesError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             ListUpdatesError::Server(ref cause) => write!(f, "{}", cause),
+            ListUpdatesError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListUpdatesError {}
 /// Errors returned by TagResource
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum TagResourceError {
     /// <p>This exception is thrown if the request contains a semantic error. The precise meaning will depend on the API, and will be documented in the error message.</p>
     BadRequest(String),
     /// <p>A service resource associated with the request could not be found. Clients should not retry such requests.</p>
     NotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl TagResourceError {
@@ -1883,13 +2027,13 @@ impl TagResourceError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(TagResourceError::BadRequest(err.msg))
+                    return RusotoError::service(TagResourceError::BadRequest(err.msg), &res)
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(TagResourceError::NotFound(err.msg))
+                    return RusotoError::service(TagResourceError::NotFound(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(TagResourceError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1901,17 +2045,21 @@ impl fmt::Display for TagResourceError {
         match *self {
             TagResourceError::BadRequest(ref cause) => write!(f, "{}", cause),
             TagResourceError::NotFound(ref cause) => write!(f, "{}", cause),
+            TagResourceError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for TagResourceError {}
 /// Errors returned by UntagResource
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UntagResourceError {
     /// <p>This exception is thrown if the request contains a semantic error. The precise meaning will depend on the API, and will be documented in the error message.</p>
     BadRequest(String),
     /// <p>A service resource associated with the request could not be found. Clients should not retry such requests.</p>
     NotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UntagResourceError {
@@ -1919,13 +2067,13 @@ impl UntagResourceError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(UntagResourceError::BadRequest(err.msg))
+                    return RusotoError::service(UntagResourceError::BadRequest(err.msg), &res)
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(UntagResourceError::NotFound(err.msg))
+                    return RusotoError::service(UntagResourceError::NotFound(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UntagResourceError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1937,12 +2085,14 @@ impl fmt::Display for UntagResourceError {
         match *self {
             UntagResourceError::BadRequest(ref cause) => write!(f, "{}", cause),
             UntagResourceError::NotFound(ref cause) => write!(f, "{}", cause),
+            UntagResourceError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for UntagResourceError {}
 /// Errors returned by UpdateClusterConfig
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdateClusterConfigError {
     /// <p>These errors are usually caused by a client action. Actions can include using an action or resource on behalf of a user that doesn't have permissions to use the action or resource or specifying an identifier that is not valid.</p>
     Client(String),
@@ -1956,6 +2106,8 @@ pub enum UpdateClusterConfigError {
     ResourceNotFound(String),
     /// <p>These errors are usually caused by a server-side issue.</p>
     Server(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdateClusterConfigError {
@@ -1963,29 +2115,37 @@ impl UpdateClusterConfigError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ClientException" => {
-                    return RusotoError::Service(UpdateClusterConfigError::Client(err.msg))
+                    return RusotoError::service(UpdateClusterConfigError::Client(err.msg), &res)
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(UpdateClusterConfigError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateClusterConfigError::InvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(UpdateClusterConfigError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        UpdateClusterConfigError::InvalidRequest(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(UpdateClusterConfigError::ResourceInUse(err.msg))
+                    return RusotoError::service(
+                        UpdateClusterConfigError::ResourceInUse(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateClusterConfigError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateClusterConfigError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ServerException" => {
-                    return RusotoError::Service(UpdateClusterConfigError::Server(err.msg))
+                    return RusotoError::service(UpdateClusterConfigError::Server(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UpdateClusterConfigError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2001,12 +2161,14 @@ impl fmt::Display for UpdateClusterConfigError {
             UpdateClusterConfigError::ResourceInUse(ref cause) => write!(f, "{}", cause),
             UpdateClusterConfigError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             UpdateClusterConfigError::Server(ref cause) => write!(f, "{}", cause),
+            UpdateClusterConfigError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for UpdateClusterConfigError {}
 /// Errors returned by UpdateClusterVersion
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdateClusterVersionError {
     /// <p>These errors are usually caused by a client action. Actions can include using an action or resource on behalf of a user that doesn't have permissions to use the action or resource or specifying an identifier that is not valid.</p>
     Client(String),
@@ -2020,6 +2182,8 @@ pub enum UpdateClusterVersionError {
     ResourceNotFound(String),
     /// <p>These errors are usually caused by a server-side issue.</p>
     Server(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdateClusterVersionError {
@@ -2027,29 +2191,37 @@ impl UpdateClusterVersionError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ClientException" => {
-                    return RusotoError::Service(UpdateClusterVersionError::Client(err.msg))
+                    return RusotoError::service(UpdateClusterVersionError::Client(err.msg), &res)
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(UpdateClusterVersionError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateClusterVersionError::InvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(UpdateClusterVersionError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        UpdateClusterVersionError::InvalidRequest(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(UpdateClusterVersionError::ResourceInUse(err.msg))
+                    return RusotoError::service(
+                        UpdateClusterVersionError::ResourceInUse(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateClusterVersionError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateClusterVersionError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ServerException" => {
-                    return RusotoError::Service(UpdateClusterVersionError::Server(err.msg))
+                    return RusotoError::service(UpdateClusterVersionError::Server(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UpdateClusterVersionError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2065,12 +2237,14 @@ impl fmt::Display for UpdateClusterVersionError {
             UpdateClusterVersionError::ResourceInUse(ref cause) => write!(f, "{}", cause),
             UpdateClusterVersionError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             UpdateClusterVersionError::Server(ref cause) => write!(f, "{}", cause),
+            UpdateClusterVersionError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for UpdateClusterVersionError {}
 /// Errors returned by UpdateNodegroupConfig
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdateNodegroupConfigError {
     /// <p>These errors are usually caused by a client action. Actions can include using an action or resource on behalf of a user that doesn't have permissions to use the action or resource or specifying an identifier that is not valid.</p>
     Client(String),
@@ -2084,6 +2258,8 @@ pub enum UpdateNodegroupConfigError {
     ResourceNotFound(String),
     /// <p>These errors are usually caused by a server-side issue.</p>
     Server(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdateNodegroupConfigError {
@@ -2091,31 +2267,37 @@ impl UpdateNodegroupConfigError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ClientException" => {
-                    return RusotoError::Service(UpdateNodegroupConfigError::Client(err.msg))
+                    return RusotoError::service(UpdateNodegroupConfigError::Client(err.msg), &res)
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(UpdateNodegroupConfigError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateNodegroupConfigError::InvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(UpdateNodegroupConfigError::InvalidRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateNodegroupConfigError::InvalidRequest(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(UpdateNodegroupConfigError::ResourceInUse(err.msg))
+                    return RusotoError::service(
+                        UpdateNodegroupConfigError::ResourceInUse(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateNodegroupConfigError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateNodegroupConfigError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ServerException" => {
-                    return RusotoError::Service(UpdateNodegroupConfigError::Server(err.msg))
+                    return RusotoError::service(UpdateNodegroupConfigError::Server(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UpdateNodegroupConfigError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2131,12 +2313,14 @@ impl fmt::Display for UpdateNodegroupConfigError {
             UpdateNodegroupConfigError::ResourceInUse(ref cause) => write!(f, "{}", cause),
             UpdateNodegroupConfigError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             UpdateNodegroupConfigError::Server(ref cause) => write!(f, "{}", cause),
+            UpdateNodegroupConfigError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for UpdateNodegroupConfigError {}
 /// Errors returned by UpdateNodegroupVersion
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdateNodegroupVersionError {
     /// <p>These errors are usually caused by a client action. Actions can include using an action or resource on behalf of a user that doesn't have permissions to use the action or resource or specifying an identifier that is not valid.</p>
     Client(String),
@@ -2150,6 +2334,8 @@ pub enum UpdateNodegroupVersionError {
     ResourceNotFound(String),
     /// <p>These errors are usually caused by a server-side issue.</p>
     Server(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdateNodegroupVersionError {
@@ -2157,33 +2343,37 @@ impl UpdateNodegroupVersionError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ClientException" => {
-                    return RusotoError::Service(UpdateNodegroupVersionError::Client(err.msg))
+                    return RusotoError::service(UpdateNodegroupVersionError::Client(err.msg), &res)
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(UpdateNodegroupVersionError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateNodegroupVersionError::InvalidParameter(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(UpdateNodegroupVersionError::InvalidRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateNodegroupVersionError::InvalidRequest(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(UpdateNodegroupVersionError::ResourceInUse(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateNodegroupVersionError::ResourceInUse(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateNodegroupVersionError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateNodegroupVersionError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ServerException" => {
-                    return RusotoError::Service(UpdateNodegroupVersionError::Server(err.msg))
+                    return RusotoError::service(UpdateNodegroupVersionError::Server(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UpdateNodegroupVersionError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2199,6 +2389,7 @@ impl fmt::Display for UpdateNodegroupVersionError {
             UpdateNodegroupVersionError::ResourceInUse(ref cause) => write!(f, "{}", cause),
             UpdateNodegroupVersionError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             UpdateNodegroupVersionError::Server(ref cause) => write!(f, "{}", cause),
+            UpdateNodegroupVersionError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
@@ -2343,6 +2534,7 @@ impl EksClient {
     /// Creates a client backed by the default tokio event loop.
     ///
     /// The client will use the default credentials provider and tls client.
+    #[cfg(not(target_arch = "wasm32"))]
     pub fn new(region: region::Region) -> EksClient {
         EksClient {
             client: Client::shared(),
@@ -2368,135 +2560,295 @@ impl EksClient {
     pub fn new_with_client(client: Client, region: region::Region) -> EksClient {
         EksClient { client, region }
     }
+
+    /// Returns a builder of a client, setting any of its region, credentials provider,
+    /// dispatcher, retry policy, endpoint and timeouts.
+    pub fn builder() -> rusoto_core::ClientBuilder<EksClient> {
+        rusoto_core::ClientBuilder::new(EksClient::new_with_client)
+    }
+
+    /// Returns a clone of this client with the overrides of `config` applied to its
+    /// operations, sharing its connections.
+    pub fn customize(&self, config: &rusoto_core::OperationConfig) -> EksClient {
+        EksClient {
+            client: self.client.customize(config),
+            region: config.resolve_region(&self.region),
+        }
+    }
 }
 
-#[async_trait]
-impl Eks for EksClient {
-    /// <p>Creates an Amazon EKS control plane. </p> <p>The Amazon EKS control plane consists of control plane instances that run the Kubernetes software, such as <code>etcd</code> and the API server. The control plane runs in an account managed by AWS, and the Kubernetes API is exposed via the Amazon EKS API server endpoint. Each Amazon EKS cluster control plane is single-tenant and unique and runs on its own set of Amazon EC2 instances.</p> <p>The cluster control plane is provisioned across multiple Availability Zones and fronted by an Elastic Load Balancing Network Load Balancer. Amazon EKS also provisions elastic network interfaces in your VPC subnets to provide connectivity from the control plane instances to the worker nodes (for example, to support <code>kubectl exec</code>, <code>logs</code>, and <code>proxy</code> data flows).</p> <p>Amazon EKS worker nodes run in your AWS account and connect to your cluster's control plane via the Kubernetes API server endpoint and a certificate file that is created for your cluster.</p> <p>You can use the <code>endpointPublicAccess</code> and <code>endpointPrivateAccess</code> parameters to enable or disable public and private access to your cluster's Kubernetes API server endpoint. By default, public access is enabled, and private access is disabled. For more information, see <a href="https://docs.aws.amazon.com/eks/latest/userguide/cluster-endpoint.html">Amazon EKS Cluster Endpoint Access Control</a> in the <i> <i>Amazon EKS User Guide</i> </i>. </p> <p>You can use the <code>logging</code> parameter to enable or disable exporting the Kubernetes control plane logs for your cluster to CloudWatch Logs. By default, cluster control plane logs aren't exported to CloudWatch Logs. For more information, see <a href="https://docs.aws.amazon.com/eks/latest/userguide/control-plane-logs.html">Amazon EKS Cluster Control Plane Logs</a> in the <i> <i>Amazon EKS User Guide</i> </i>.</p> <note> <p>CloudWatch Logs ingestion, archive storage, and data scanning rates apply to exported control plane logs. For more information, see <a href="http://aws.amazon.com/cloudwatch/pricing/">Amazon CloudWatch Pricing</a>.</p> </note> <p>Cluster creation typically takes between 10 and 15 minutes. After you create an Amazon EKS cluster, you must configure your Kubernetes tooling to communicate with the API server and launch worker nodes into your cluster. For more information, see <a href="https://docs.aws.amazon.com/eks/latest/userguide/managing-auth.html">Managing Cluster Authentication</a> and <a href="https://docs.aws.amazon.com/eks/latest/userguide/launch-workers.html">Launching Amazon EKS Worker Nodes</a> in the <i>Amazon EKS User Guide</i>.</p>
-    async fn create_cluster(
+impl EksClient {
+    /// Calls `create_cluster`, also returning the metadata of the response.
+    pub async fn create_cluster_with_metadata(
         &self,
         input: CreateClusterRequest,
-    ) -> Result<CreateClusterResponse, RusotoError<CreateClusterError>> {
+    ) -> Result<(CreateClusterResponse, ResponseMetadata), RusotoError<CreateClusterError>> {
+        let request = self.build_create_cluster_request(input);
+
+        let mut response = self
+            .client
+            .sign_and_dispatch(request)
+            .await
+            .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
+        if response.status.is_success() {
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<CreateClusterResponse, _>()?;
+
+            Ok((result, metadata))
+        } else {
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(CreateClusterError::from_response(response))
+        }
+    }
+
+    /// Presigns the request `create_cluster` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_cluster(
+        &self,
+        presigner: &rusoto_core::Presigner,
+        input: CreateClusterRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_cluster_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_cluster_request(&self, input: CreateClusterRequest) -> SignedRequest {
         let request_uri = "/clusters";
 
         let mut request = SignedRequest::new("POST", "eks", &self.region, &request_uri);
+        request.set_operation("CreateCluster");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `create_fargate_profile`, also returning the metadata of the response.
+    pub async fn create_fargate_profile_with_metadata(
+        &self,
+        input: CreateFargateProfileRequest,
+    ) -> Result<
+        (CreateFargateProfileResponse, ResponseMetadata),
+        RusotoError<CreateFargateProfileError>,
+    > {
+        let request = self.build_create_fargate_profile_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<CreateClusterResponse, _>()?;
+                .deserialize::<CreateFargateProfileResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(CreateClusterError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(CreateFargateProfileError::from_response(response))
         }
     }
 
-    /// <p>Creates an AWS Fargate profile for your Amazon EKS cluster. You must have at least one Fargate profile in a cluster to be able to run pods on Fargate.</p> <p>The Fargate profile allows an administrator to declare which pods run on Fargate and specify which pods run on which Fargate profile. This declaration is done through the profile’s selectors. Each profile can have up to five selectors that contain a namespace and labels. A namespace is required for every selector. The label field consists of multiple optional key-value pairs. Pods that match the selectors are scheduled on Fargate. If a to-be-scheduled pod matches any of the selectors in the Fargate profile, then that pod is run on Fargate.</p> <p>When you create a Fargate profile, you must specify a pod execution role to use with the pods that are scheduled with the profile. This role is added to the cluster's Kubernetes <a href="https://kubernetes.io/docs/admin/authorization/rbac/">Role Based Access Control</a> (RBAC) for authorization so that the <code>kubelet</code> that is running on the Fargate infrastructure can register with your Amazon EKS cluster so that it can appear in your cluster as a node. The pod execution role also provides IAM permissions to the Fargate infrastructure to allow read access to Amazon ECR image repositories. For more information, see <a href="https://docs.aws.amazon.com/eks/latest/userguide/pod-execution-role.html">Pod Execution Role</a> in the <i>Amazon EKS User Guide</i>.</p> <p>Fargate profiles are immutable. However, you can create a new updated profile to replace an existing profile and then delete the original after the updated profile has finished creating.</p> <p>If any Fargate profiles in a cluster are in the <code>DELETING</code> status, you must wait for that Fargate profile to finish deleting before you can create any other profiles in that cluster.</p> <p>For more information, see <a href="https://docs.aws.amazon.com/eks/latest/userguide/fargate-profile.html">AWS Fargate Profile</a> in the <i>Amazon EKS User Guide</i>.</p>
-    async fn create_fargate_profile(
+    /// Presigns the request `create_fargate_profile` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_fargate_profile(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: CreateFargateProfileRequest,
-    ) -> Result<CreateFargateProfileResponse, RusotoError<CreateFargateProfileError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_fargate_profile_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_fargate_profile_request(
+        &self,
+        input: CreateFargateProfileRequest,
+    ) -> SignedRequest {
         let request_uri = format!(
             "/clusters/{name}/fargate-profiles",
             name = input.cluster_name
         );
 
         let mut request = SignedRequest::new("POST", "eks", &self.region, &request_uri);
+        request.set_operation("CreateFargateProfile");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `create_nodegroup`, also returning the metadata of the response.
+    pub async fn create_nodegroup_with_metadata(
+        &self,
+        input: CreateNodegroupRequest,
+    ) -> Result<(CreateNodegroupResponse, ResponseMetadata), RusotoError<CreateNodegroupError>>
+    {
+        let request = self.build_create_nodegroup_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<CreateFargateProfileResponse, _>()?;
+                .deserialize::<CreateNodegroupResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(CreateFargateProfileError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(CreateNodegroupError::from_response(response))
         }
     }
 
-    /// <p>Creates a managed worker node group for an Amazon EKS cluster. You can only create a node group for your cluster that is equal to the current Kubernetes version for the cluster. All node groups are created with the latest AMI release version for the respective minor Kubernetes version of the cluster.</p> <p>An Amazon EKS managed node group is an Amazon EC2 Auto Scaling group and associated Amazon EC2 instances that are managed by AWS for an Amazon EKS cluster. Each node group uses a version of the Amazon EKS-optimized Amazon Linux 2 AMI. For more information, see <a href="https://docs.aws.amazon.com/eks/latest/userguide/managed-node-groups.html">Managed Node Groups</a> in the <i>Amazon EKS User Guide</i>. </p>
-    async fn create_nodegroup(
+    /// Presigns the request `create_nodegroup` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_nodegroup(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: CreateNodegroupRequest,
-    ) -> Result<CreateNodegroupResponse, RusotoError<CreateNodegroupError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_nodegroup_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_nodegroup_request(&self, input: CreateNodegroupRequest) -> SignedRequest {
         let request_uri = format!("/clusters/{name}/node-groups", name = input.cluster_name);
 
         let mut request = SignedRequest::new("POST", "eks", &self.region, &request_uri);
+        request.set_operation("CreateNodegroup");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `delete_cluster`, also returning the metadata of the response.
+    pub async fn delete_cluster_with_metadata(
+        &self,
+        input: DeleteClusterRequest,
+    ) -> Result<(DeleteClusterResponse, ResponseMetadata), RusotoError<DeleteClusterError>> {
+        let request = self.build_delete_cluster_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<CreateNodegroupResponse, _>()?;
+                .deserialize::<DeleteClusterResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(CreateNodegroupError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(DeleteClusterError::from_response(response))
         }
     }
 
-    /// <p>Deletes the Amazon EKS cluster control plane.</p> <p>If you have active services in your cluster that are associated with a load balancer, you must delete those services before deleting the cluster so that the load balancers are deleted properly. Otherwise, you can have orphaned resources in your VPC that prevent you from being able to delete the VPC. For more information, see <a href="https://docs.aws.amazon.com/eks/latest/userguide/delete-cluster.html">Deleting a Cluster</a> in the <i>Amazon EKS User Guide</i>.</p> <p>If you have managed node groups or Fargate profiles attached to the cluster, you must delete them first. For more information, see <a>DeleteNodegroup</a> and<a>DeleteFargateProfile</a>.</p>
-    async fn delete_cluster(
+    /// Presigns the request `delete_cluster` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_cluster(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteClusterRequest,
-    ) -> Result<DeleteClusterResponse, RusotoError<DeleteClusterError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_cluster_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_cluster_request(&self, input: DeleteClusterRequest) -> SignedRequest {
         let request_uri = format!("/clusters/{name}", name = input.name);
 
         let mut request = SignedRequest::new("DELETE", "eks", &self.region, &request_uri);
+        request.set_operation("DeleteCluster");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
+        request
+    }
+
+    /// Calls `delete_fargate_profile`, also returning the metadata of the response.
+    pub async fn delete_fargate_profile_with_metadata(
+        &self,
+        input: DeleteFargateProfileRequest,
+    ) -> Result<
+        (DeleteFargateProfileResponse, ResponseMetadata),
+        RusotoError<DeleteFargateProfileError>,
+    > {
+        let request = self.build_delete_fargate_profile_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<DeleteClusterResponse, _>()?;
+                .deserialize::<DeleteFargateProfileResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteClusterError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(DeleteFargateProfileError::from_response(response))
         }
     }
 
-    /// <p>Deletes an AWS Fargate profile.</p> <p>When you delete a Fargate profile, any pods running on Fargate that were created with the profile are deleted. If those pods match another Fargate profile, then they are scheduled on Fargate with that profile. If they no longer match any Fargate profiles, then they are not scheduled on Fargate and they may remain in a pending state.</p> <p>Only one Fargate profile in a cluster can be in the <code>DELETING</code> status at a time. You must wait for a Fargate profile to finish deleting before you can delete any other profiles in that cluster.</p>
-    async fn delete_fargate_profile(
+    /// Presigns the request `delete_fargate_profile` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_fargate_profile(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteFargateProfileRequest,
-    ) -> Result<DeleteFargateProfileResponse, RusotoError<DeleteFargateProfileError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_fargate_profile_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_fargate_profile_request(
+        &self,
+        input: DeleteFargateProfileRequest,
+    ) -> SignedRequest {
         let request_uri = format!(
             "/clusters/{name}/fargate-profiles/{fargate_profile_name}",
             name = input.cluster_name,
@@ -2504,30 +2856,55 @@ impl Eks for EksClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "eks", &self.region, &request_uri);
+        request.set_operation("DeleteFargateProfile");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
+        request
+    }
+
+    /// Calls `delete_nodegroup`, also returning the metadata of the response.
+    pub async fn delete_nodegroup_with_metadata(
+        &self,
+        input: DeleteNodegroupRequest,
+    ) -> Result<(DeleteNodegroupResponse, ResponseMetadata), RusotoError<DeleteNodegroupError>>
+    {
+        let request = self.build_delete_nodegroup_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<DeleteFargateProfileResponse, _>()?;
+                .deserialize::<DeleteNodegroupResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteFargateProfileError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(DeleteNodegroupError::from_response(response))
         }
     }
 
-    /// <p>Deletes an Amazon EKS node group for a cluster.</p>
-    async fn delete_nodegroup(
+    /// Presigns the request `delete_nodegroup` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_nodegroup(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteNodegroupRequest,
-    ) -> Result<DeleteNodegroupResponse, RusotoError<DeleteNodegroupError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_nodegroup_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_nodegroup_request(&self, input: DeleteNodegroupRequest) -> SignedRequest {
         let request_uri = format!(
             "/clusters/{name}/node-groups/{nodegroup_name}",
             name = input.cluster_name,
@@ -2535,57 +2912,112 @@ impl Eks for EksClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "eks", &self.region, &request_uri);
+        request.set_operation("DeleteNodegroup");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
+        request
+    }
+
+    /// Calls `describe_cluster`, also returning the metadata of the response.
+    pub async fn describe_cluster_with_metadata(
+        &self,
+        input: DescribeClusterRequest,
+    ) -> Result<(DescribeClusterResponse, ResponseMetadata), RusotoError<DescribeClusterError>>
+    {
+        let request = self.build_describe_cluster_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<DeleteNodegroupResponse, _>()?;
+                .deserialize::<DescribeClusterResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteNodegroupError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(DescribeClusterError::from_response(response))
         }
     }
 
-    /// <p><p>Returns descriptive information about an Amazon EKS cluster.</p> <p>The API server endpoint and certificate authority data returned by this operation are required for <code>kubelet</code> and <code>kubectl</code> to communicate with your Kubernetes API server. For more information, see <a href="https://docs.aws.amazon.com/eks/latest/userguide/create-kubeconfig.html">Create a kubeconfig for Amazon EKS</a>.</p> <note> <p>The API server endpoint and certificate authority data aren&#39;t available until the cluster reaches the <code>ACTIVE</code> state.</p> </note></p>
-    async fn describe_cluster(
+    /// Presigns the request `describe_cluster` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_cluster(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeClusterRequest,
-    ) -> Result<DescribeClusterResponse, RusotoError<DescribeClusterError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_cluster_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_cluster_request(&self, input: DescribeClusterRequest) -> SignedRequest {
         let request_uri = format!("/clusters/{name}", name = input.name);
 
         let mut request = SignedRequest::new("GET", "eks", &self.region, &request_uri);
+        request.set_operation("DescribeCluster");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
+        request
+    }
+
+    /// Calls `describe_fargate_profile`, also returning the metadata of the response.
+    pub async fn describe_fargate_profile_with_metadata(
+        &self,
+        input: DescribeFargateProfileRequest,
+    ) -> Result<
+        (DescribeFargateProfileResponse, ResponseMetadata),
+        RusotoError<DescribeFargateProfileError>,
+    > {
+        let request = self.build_describe_fargate_profile_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<DescribeClusterResponse, _>()?;
+                .deserialize::<DescribeFargateProfileResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeClusterError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(DescribeFargateProfileError::from_response(response))
         }
     }
 
-    /// <p>Returns descriptive information about an AWS Fargate profile.</p>
-    async fn describe_fargate_profile(
+    /// Presigns the request `describe_fargate_profile` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_fargate_profile(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeFargateProfileRequest,
-    ) -> Result<DescribeFargateProfileResponse, RusotoError<DescribeFargateProfileError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_fargate_profile_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_fargate_profile_request(
+        &self,
+        input: DescribeFargateProfileRequest,
+    ) -> SignedRequest {
         let request_uri = format!(
             "/clusters/{name}/fargate-profiles/{fargate_profile_name}",
             name = input.cluster_name,
@@ -2593,30 +3025,55 @@ impl Eks for EksClient {
         );
 
         let mut request = SignedRequest::new("GET", "eks", &self.region, &request_uri);
+        request.set_operation("DescribeFargateProfile");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
+        request
+    }
+
+    /// Calls `describe_nodegroup`, also returning the metadata of the response.
+    pub async fn describe_nodegroup_with_metadata(
+        &self,
+        input: DescribeNodegroupRequest,
+    ) -> Result<(DescribeNodegroupResponse, ResponseMetadata), RusotoError<DescribeNodegroupError>>
+    {
+        let request = self.build_describe_nodegroup_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<DescribeFargateProfileResponse, _>()?;
+                .deserialize::<DescribeNodegroupResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeFargateProfileError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(DescribeNodegroupError::from_response(response))
         }
     }
 
-    /// <p>Returns descriptive information about an Amazon EKS node group.</p>
-    async fn describe_nodegroup(
+    /// Presigns the request `describe_nodegroup` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_nodegroup(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeNodegroupRequest,
-    ) -> Result<DescribeNodegroupResponse, RusotoError<DescribeNodegroupError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_nodegroup_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_nodegroup_request(&self, input: DescribeNodegroupRequest) -> SignedRequest {
         let request_uri = format!(
             "/clusters/{name}/node-groups/{nodegroup_name}",
             name = input.cluster_name,
@@ -2624,30 +3081,54 @@ impl Eks for EksClient {
         );
 
         let mut request = SignedRequest::new("GET", "eks", &self.region, &request_uri);
+        request.set_operation("DescribeNodegroup");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
+        request
+    }
+
+    /// Calls `describe_update`, also returning the metadata of the response.
+    pub async fn describe_update_with_metadata(
+        &self,
+        input: DescribeUpdateRequest,
+    ) -> Result<(DescribeUpdateResponse, ResponseMetadata), RusotoError<DescribeUpdateError>> {
+        let request = self.build_describe_update_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<DescribeNodegroupResponse, _>()?;
+                .deserialize::<DescribeUpdateResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeNodegroupError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(DescribeUpdateError::from_response(response))
         }
     }
 
-    /// <p>Returns descriptive information about an update against your Amazon EKS cluster or associated managed node group.</p> <p>When the status of the update is <code>Succeeded</code>, the update is complete. If an update fails, the status is <code>Failed</code>, and an error detail explains the reason for the failure.</p>
-    async fn describe_update(
+    /// Presigns the request `describe_update` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_update(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeUpdateRequest,
-    ) -> Result<DescribeUpdateResponse, RusotoError<DescribeUpdateError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_update_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_update_request(&self, input: DescribeUpdateRequest) -> SignedRequest {
         let request_uri = format!(
             "/clusters/{name}/updates/{update_id}",
             name = input.name,
@@ -2655,6 +3136,7 @@ impl Eks for EksClient {
         );
 
         let mut request = SignedRequest::new("GET", "eks", &self.region, &request_uri);
+        request.set_operation("DescribeUpdate");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -2663,31 +3145,55 @@ impl Eks for EksClient {
         }
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `list_clusters`, also returning the metadata of the response.
+    pub async fn list_clusters_with_metadata(
+        &self,
+        input: ListClustersRequest,
+    ) -> Result<(ListClustersResponse, ResponseMetadata), RusotoError<ListClustersError>> {
+        let request = self.build_list_clusters_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<DescribeUpdateResponse, _>()?;
+                .deserialize::<ListClustersResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeUpdateError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(ListClustersError::from_response(response))
         }
     }
 
-    /// <p>Lists the Amazon EKS clusters in your AWS account in the specified Region.</p>
-    async fn list_clusters(
+    /// Presigns the request `list_clusters` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_clusters(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListClustersRequest,
-    ) -> Result<ListClustersResponse, RusotoError<ListClustersError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_clusters_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_clusters_request(&self, input: ListClustersRequest) -> SignedRequest {
         let request_uri = "/clusters";
 
         let mut request = SignedRequest::new("GET", "eks", &self.region, &request_uri);
+        request.set_operation("ListClusters");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -2699,34 +3205,64 @@ impl Eks for EksClient {
         }
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `list_fargate_profiles`, also returning the metadata of the response.
+    pub async fn list_fargate_profiles_with_metadata(
+        &self,
+        input: ListFargateProfilesRequest,
+    ) -> Result<
+        (ListFargateProfilesResponse, ResponseMetadata),
+        RusotoError<ListFargateProfilesError>,
+    > {
+        let request = self.build_list_fargate_profiles_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListClustersResponse, _>()?;
+                .deserialize::<ListFargateProfilesResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(ListClustersError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(ListFargateProfilesError::from_response(response))
         }
     }
 
-    /// <p>Lists the AWS Fargate profiles associated with the specified cluster in your AWS account in the specified Region.</p>
-    async fn list_fargate_profiles(
+    /// Presigns the request `list_fargate_profiles` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_fargate_profiles(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListFargateProfilesRequest,
-    ) -> Result<ListFargateProfilesResponse, RusotoError<ListFargateProfilesError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_fargate_profiles_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_fargate_profiles_request(
+        &self,
+        input: ListFargateProfilesRequest,
+    ) -> SignedRequest {
         let request_uri = format!(
             "/clusters/{name}/fargate-profiles",
             name = input.cluster_name
         );
 
         let mut request = SignedRequest::new("GET", "eks", &self.region, &request_uri);
+        request.set_operation("ListFargateProfiles");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -2738,31 +3274,55 @@ impl Eks for EksClient {
         }
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `list_nodegroups`, also returning the metadata of the response.
+    pub async fn list_nodegroups_with_metadata(
+        &self,
+        input: ListNodegroupsRequest,
+    ) -> Result<(ListNodegroupsResponse, ResponseMetadata), RusotoError<ListNodegroupsError>> {
+        let request = self.build_list_nodegroups_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListFargateProfilesResponse, _>()?;
+                .deserialize::<ListNodegroupsResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(ListFargateProfilesError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(ListNodegroupsError::from_response(response))
         }
     }
 
-    /// <p>Lists the Amazon EKS node groups associated with the specified cluster in your AWS account in the specified Region.</p>
-    async fn list_nodegroups(
+    /// Presigns the request `list_nodegroups` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_nodegroups(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListNodegroupsRequest,
-    ) -> Result<ListNodegroupsResponse, RusotoError<ListNodegroupsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_nodegroups_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_nodegroups_request(&self, input: ListNodegroupsRequest) -> SignedRequest {
         let request_uri = format!("/clusters/{name}/node-groups", name = input.cluster_name);
 
         let mut request = SignedRequest::new("GET", "eks", &self.region, &request_uri);
+        request.set_operation("ListNodegroups");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -2774,58 +3334,112 @@ impl Eks for EksClient {
         }
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `list_tags_for_resource`, also returning the metadata of the response.
+    pub async fn list_tags_for_resource_with_metadata(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> Result<
+        (ListTagsForResourceResponse, ResponseMetadata),
+        RusotoError<ListTagsForResourceError>,
+    > {
+        let request = self.build_list_tags_for_resource_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListNodegroupsResponse, _>()?;
+                .deserialize::<ListTagsForResourceResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(ListNodegroupsError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(ListTagsForResourceError::from_response(response))
         }
     }
 
-    /// <p>List the tags for an Amazon EKS resource.</p>
-    async fn list_tags_for_resource(
+    /// Presigns the request `list_tags_for_resource` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_tags_for_resource(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListTagsForResourceRequest,
-    ) -> Result<ListTagsForResourceResponse, RusotoError<ListTagsForResourceError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_tags_for_resource_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_tags_for_resource_request(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> SignedRequest {
         let request_uri = format!("/tags/{resource_arn}", resource_arn = input.resource_arn);
 
         let mut request = SignedRequest::new("GET", "eks", &self.region, &request_uri);
+        request.set_operation("ListTagsForResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
+        request
+    }
+
+    /// Calls `list_updates`, also returning the metadata of the response.
+    pub async fn list_updates_with_metadata(
+        &self,
+        input: ListUpdatesRequest,
+    ) -> Result<(ListUpdatesResponse, ResponseMetadata), RusotoError<ListUpdatesError>> {
+        let request = self.build_list_updates_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListTagsForResourceResponse, _>()?;
+                .deserialize::<ListUpdatesResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(ListTagsForResourceError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(ListUpdatesError::from_response(response))
         }
     }
 
-    /// <p>Lists the updates associated with an Amazon EKS cluster or managed node group in your AWS account, in the specified Region.</p>
-    async fn list_updates(
+    /// Presigns the request `list_updates` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_updates(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListUpdatesRequest,
-    ) -> Result<ListUpdatesResponse, RusotoError<ListUpdatesError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_updates_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_updates_request(&self, input: ListUpdatesRequest) -> SignedRequest {
         let request_uri = format!("/clusters/{name}/updates", name = input.name);
 
         let mut request = SignedRequest::new("GET", "eks", &self.region, &request_uri);
+        request.set_operation("ListUpdates");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -2840,61 +3454,109 @@ impl Eks for EksClient {
         }
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `tag_resource`, also returning the metadata of the response.
+    pub async fn tag_resource_with_metadata(
+        &self,
+        input: TagResourceRequest,
+    ) -> Result<(TagResourceResponse, ResponseMetadata), RusotoError<TagResourceError>> {
+        let request = self.build_tag_resource_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListUpdatesResponse, _>()?;
+                .deserialize::<TagResourceResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(ListUpdatesError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(TagResourceError::from_response(response))
         }
     }
 
-    /// <p>Associates the specified tags to a resource with the specified <code>resourceArn</code>. If existing tags on a resource are not specified in the request parameters, they are not changed. When a resource is deleted, the tags associated with that resource are deleted as well. Tags that you create for Amazon EKS resources do not propagate to any other resources associated with the cluster. For example, if you tag a cluster with this operation, that tag does not automatically propagate to the subnets and worker nodes associated with the cluster.</p>
-    async fn tag_resource(
+    /// Presigns the request `tag_resource` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_tag_resource(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: TagResourceRequest,
-    ) -> Result<TagResourceResponse, RusotoError<TagResourceError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_tag_resource_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_tag_resource_request(&self, input: TagResourceRequest) -> SignedRequest {
         let request_uri = format!("/tags/{resource_arn}", resource_arn = input.resource_arn);
 
         let mut request = SignedRequest::new("POST", "eks", &self.region, &request_uri);
+        request.set_operation("TagResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `untag_resource`, also returning the metadata of the response.
+    pub async fn untag_resource_with_metadata(
+        &self,
+        input: UntagResourceRequest,
+    ) -> Result<(UntagResourceResponse, ResponseMetadata), RusotoError<UntagResourceError>> {
+        let request = self.build_untag_resource_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<TagResourceResponse, _>()?;
+                .deserialize::<UntagResourceResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(TagResourceError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(UntagResourceError::from_response(response))
         }
     }
 
-    /// <p>Deletes specified tags from a resource.</p>
-    async fn untag_resource(
+    /// Presigns the request `untag_resource` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_untag_resource(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UntagResourceRequest,
-    ) -> Result<UntagResourceResponse, RusotoError<UntagResourceError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_untag_resource_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_untag_resource_request(&self, input: UntagResourceRequest) -> SignedRequest {
         let request_uri = format!("/tags/{resource_arn}", resource_arn = input.resource_arn);
 
         let mut request = SignedRequest::new("DELETE", "eks", &self.region, &request_uri);
+        request.set_operation("UntagResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -2903,88 +3565,177 @@ impl Eks for EksClient {
         }
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `update_cluster_config`, also returning the metadata of the response.
+    pub async fn update_cluster_config_with_metadata(
+        &self,
+        input: UpdateClusterConfigRequest,
+    ) -> Result<
+        (UpdateClusterConfigResponse, ResponseMetadata),
+        RusotoError<UpdateClusterConfigError>,
+    > {
+        let request = self.build_update_cluster_config_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<UntagResourceResponse, _>()?;
+                .deserialize::<UpdateClusterConfigResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(UntagResourceError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(UpdateClusterConfigError::from_response(response))
         }
     }
 
-    /// <p>Updates an Amazon EKS cluster configuration. Your cluster continues to function during the update. The response output includes an update ID that you can use to track the status of your cluster update with the <a>DescribeUpdate</a> API operation.</p> <p>You can use this API operation to enable or disable exporting the Kubernetes control plane logs for your cluster to CloudWatch Logs. By default, cluster control plane logs aren't exported to CloudWatch Logs. For more information, see <a href="https://docs.aws.amazon.com/eks/latest/userguide/control-plane-logs.html">Amazon EKS Cluster Control Plane Logs</a> in the <i> <i>Amazon EKS User Guide</i> </i>.</p> <note> <p>CloudWatch Logs ingestion, archive storage, and data scanning rates apply to exported control plane logs. For more information, see <a href="http://aws.amazon.com/cloudwatch/pricing/">Amazon CloudWatch Pricing</a>.</p> </note> <p>You can also use this API operation to enable or disable public and private access to your cluster's Kubernetes API server endpoint. By default, public access is enabled, and private access is disabled. For more information, see <a href="https://docs.aws.amazon.com/eks/latest/userguide/cluster-endpoint.html">Amazon EKS Cluster Endpoint Access Control</a> in the <i> <i>Amazon EKS User Guide</i> </i>. </p> <important> <p>At this time, you can not update the subnets or security group IDs for an existing cluster.</p> </important> <p>Cluster updates are asynchronous, and they should finish within a few minutes. During an update, the cluster status moves to <code>UPDATING</code> (this status transition is eventually consistent). When the update is complete (either <code>Failed</code> or <code>Successful</code>), the cluster status moves to <code>Active</code>.</p>
-    async fn update_cluster_config(
+    /// Presigns the request `update_cluster_config` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_cluster_config(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UpdateClusterConfigRequest,
-    ) -> Result<UpdateClusterConfigResponse, RusotoError<UpdateClusterConfigError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_cluster_config_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_cluster_config_request(
+        &self,
+        input: UpdateClusterConfigRequest,
+    ) -> SignedRequest {
         let request_uri = format!("/clusters/{name}/update-config", name = input.name);
 
         let mut request = SignedRequest::new("POST", "eks", &self.region, &request_uri);
+        request.set_operation("UpdateClusterConfig");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `update_cluster_version`, also returning the metadata of the response.
+    pub async fn update_cluster_version_with_metadata(
+        &self,
+        input: UpdateClusterVersionRequest,
+    ) -> Result<
+        (UpdateClusterVersionResponse, ResponseMetadata),
+        RusotoError<UpdateClusterVersionError>,
+    > {
+        let request = self.build_update_cluster_version_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<UpdateClusterConfigResponse, _>()?;
+                .deserialize::<UpdateClusterVersionResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(UpdateClusterConfigError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(UpdateClusterVersionError::from_response(response))
         }
     }
 
-    /// <p>Updates an Amazon EKS cluster to the specified Kubernetes version. Your cluster continues to function during the update. The response output includes an update ID that you can use to track the status of your cluster update with the <a>DescribeUpdate</a> API operation.</p> <p>Cluster updates are asynchronous, and they should finish within a few minutes. During an update, the cluster status moves to <code>UPDATING</code> (this status transition is eventually consistent). When the update is complete (either <code>Failed</code> or <code>Successful</code>), the cluster status moves to <code>Active</code>.</p> <p>If your cluster has managed node groups attached to it, all of your node groups’ Kubernetes versions must match the cluster’s Kubernetes version in order to update the cluster to a new Kubernetes version.</p>
-    async fn update_cluster_version(
+    /// Presigns the request `update_cluster_version` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_cluster_version(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UpdateClusterVersionRequest,
-    ) -> Result<UpdateClusterVersionResponse, RusotoError<UpdateClusterVersionError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_cluster_version_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_cluster_version_request(
+        &self,
+        input: UpdateClusterVersionRequest,
+    ) -> SignedRequest {
         let request_uri = format!("/clusters/{name}/updates", name = input.name);
 
         let mut request = SignedRequest::new("POST", "eks", &self.region, &request_uri);
+        request.set_operation("UpdateClusterVersion");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `update_nodegroup_config`, also returning the metadata of the response.
+    pub async fn update_nodegroup_config_with_metadata(
+        &self,
+        input: UpdateNodegroupConfigRequest,
+    ) -> Result<
+        (UpdateNodegroupConfigResponse, ResponseMetadata),
+        RusotoError<UpdateNodegroupConfigError>,
+    > {
+        let request = self.build_update_nodegroup_config_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<UpdateClusterVersionResponse, _>()?;
+                .deserialize::<UpdateNodegroupConfigResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(UpdateClusterVersionError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(UpdateNodegroupConfigError::from_response(response))
         }
     }
 
-    /// <p>Updates an Amazon EKS managed node group configuration. Your node group continues to function during the update. The response output includes an update ID that you can use to track the status of your node group update with the <a>DescribeUpdate</a> API operation. Currently you can update the Kubernetes labels for a node group or the scaling configuration.</p>
-    async fn update_nodegroup_config(
+    /// Presigns the request `update_nodegroup_config` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_nodegroup_config(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UpdateNodegroupConfigRequest,
-    ) -> Result<UpdateNodegroupConfigResponse, RusotoError<UpdateNodegroupConfigError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_nodegroup_config_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_nodegroup_config_request(
+        &self,
+        input: UpdateNodegroupConfigRequest,
+    ) -> SignedRequest {
         let request_uri = format!(
             "/clusters/{name}/node-groups/{nodegroup_name}/update-config",
             name = input.cluster_name,
@@ -2992,33 +3743,63 @@ impl Eks for EksClient {
         );
 
         let mut request = SignedRequest::new("POST", "eks", &self.region, &request_uri);
+        request.set_operation("UpdateNodegroupConfig");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `update_nodegroup_version`, also returning the metadata of the response.
+    pub async fn update_nodegroup_version_with_metadata(
+        &self,
+        input: UpdateNodegroupVersionRequest,
+    ) -> Result<
+        (UpdateNodegroupVersionResponse, ResponseMetadata),
+        RusotoError<UpdateNodegroupVersionError>,
+    > {
+        let request = self.build_update_nodegroup_version_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<UpdateNodegroupConfigResponse, _>()?;
+                .deserialize::<UpdateNodegroupVersionResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(UpdateNodegroupConfigError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(UpdateNodegroupVersionError::from_response(response))
         }
     }
 
-    /// <p>Updates the Kubernetes version or AMI version of an Amazon EKS managed node group.</p> <p>You can update to the latest available AMI version of a node group's current Kubernetes version by not specifying a Kubernetes version in the request. You can update to the latest AMI version of your cluster's current Kubernetes version by specifying your cluster's Kubernetes version in the request. For more information, see <a href="https://docs.aws.amazon.com/eks/latest/userguide/eks-linux-ami-versions.html">Amazon EKS-Optimized Linux AMI Versions</a> in the <i>Amazon EKS User Guide</i>.</p> <p>You cannot roll back a node group to an earlier Kubernetes version or AMI version.</p> <p>When a node in a managed node group is terminated due to a scaling action or update, the pods in that node are drained first. Amazon EKS attempts to drain the nodes gracefully and will fail if it is unable to do so. You can <code>force</code> the update if Amazon EKS is unable to drain the nodes as a result of a pod disruption budget issue.</p>
-    async fn update_nodegroup_version(
+    /// Presigns the request `update_nodegroup_version` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_nodegroup_version(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UpdateNodegroupVersionRequest,
-    ) -> Result<UpdateNodegroupVersionResponse, RusotoError<UpdateNodegroupVersionError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_nodegroup_version_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_nodegroup_version_request(
+        &self,
+        input: UpdateNodegroupVersionRequest,
+    ) -> SignedRequest {
         let request_uri = format!(
             "/clusters/{name}/node-groups/{nodegroup_name}/update-version",
             name = input.cluster_name,
@@ -3026,25 +3807,654 @@ impl Eks for EksClient {
         );
 
         let mut request = SignedRequest::new("POST", "eks", &self.region, &request_uri);
+        request.set_operation("UpdateNodegroupVersion");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
-        let mut response = self
-            .client
-            .sign_and_dispatch(request)
+        request
+    }
+
+    /// The pages of `ListClusters` results for `input`, requesting each next page with the `nextToken` of the last one as its `nextToken`.
+    /// The page size is the `maxResults` of `input`.
+    pub fn list_clusters_pages(
+        &self,
+        input: ListClustersRequest,
+    ) -> impl futures::Stream<Item = Result<ListClustersResponse, RusotoError<ListClustersError>>> + Send
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: EksClient, input| async move { client.list_clusters(input).await },
+            |input: &ListClustersRequest, output: &ListClustersResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListClustersRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `clusters` of every page of `ListClusters` results for `input`, as `list_clusters_pages` requests them.
+    pub fn list_clusters_stream(
+        &self,
+        input: ListClustersRequest,
+    ) -> impl futures::Stream<Item = Result<String, RusotoError<ListClustersError>>> + Send {
+        rusoto_core::pagination::items(self.list_clusters_pages(input), |page| {
+            page.clusters.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `ListFargateProfiles` results for `input`, requesting each next page with the `nextToken` of the last one as its `nextToken`.
+    /// The page size is the `maxResults` of `input`.
+    pub fn list_fargate_profiles_pages(
+        &self,
+        input: ListFargateProfilesRequest,
+    ) -> impl futures::Stream<
+        Item = Result<ListFargateProfilesResponse, RusotoError<ListFargateProfilesError>>,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: EksClient, input| async move { client.list_fargate_profiles(input).await },
+            |input: &ListFargateProfilesRequest, output: &ListFargateProfilesResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListFargateProfilesRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `fargateProfileNames` of every page of `ListFargateProfiles` results for `input`, as `list_fargate_profiles_pages` requests them.
+    pub fn list_fargate_profiles_stream(
+        &self,
+        input: ListFargateProfilesRequest,
+    ) -> impl futures::Stream<Item = Result<String, RusotoError<ListFargateProfilesError>>> + Send
+    {
+        rusoto_core::pagination::items(self.list_fargate_profiles_pages(input), |page| {
+            page.fargate_profile_names.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `ListNodegroups` results for `input`, requesting each next page with the `nextToken` of the last one as its `nextToken`.
+    /// The page size is the `maxResults` of `input`.
+    pub fn list_nodegroups_pages(
+        &self,
+        input: ListNodegroupsRequest,
+    ) -> impl futures::Stream<Item = Result<ListNodegroupsResponse, RusotoError<ListNodegroupsError>>>
+           + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: EksClient, input| async move { client.list_nodegroups(input).await },
+            |input: &ListNodegroupsRequest, output: &ListNodegroupsResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListNodegroupsRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `nodegroups` of every page of `ListNodegroups` results for `input`, as `list_nodegroups_pages` requests them.
+    pub fn list_nodegroups_stream(
+        &self,
+        input: ListNodegroupsRequest,
+    ) -> impl futures::Stream<Item = Result<String, RusotoError<ListNodegroupsError>>> + Send {
+        rusoto_core::pagination::items(self.list_nodegroups_pages(input), |page| {
+            page.nodegroups.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `ListUpdates` results for `input`, requesting each next page with the `nextToken` of the last one as its `nextToken`.
+    /// The page size is the `maxResults` of `input`.
+    pub fn list_updates_pages(
+        &self,
+        input: ListUpdatesRequest,
+    ) -> impl futures::Stream<Item = Result<ListUpdatesResponse, RusotoError<ListUpdatesError>>> + Send
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: EksClient, input| async move { client.list_updates(input).await },
+            |input: &ListUpdatesRequest, output: &ListUpdatesResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListUpdatesRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `updateIds` of every page of `ListUpdates` results for `input`, as `list_updates_pages` requests them.
+    pub fn list_updates_stream(
+        &self,
+        input: ListUpdatesRequest,
+    ) -> impl futures::Stream<Item = Result<String, RusotoError<ListUpdatesError>>> + Send {
+        rusoto_core::pagination::items(self.list_updates_pages(input), |page| {
+            page.update_ids.unwrap_or_default()
+        })
+    }
+
+    /// Waits for `ClusterActive`, polling `DescribeCluster` with `input` every 30 seconds, up to 40 times.
+    pub async fn wait_until_cluster_active(
+        &self,
+        input: DescribeClusterRequest,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<DescribeClusterError>> {
+        let waiter = rusoto_core::waiter::Waiter::new(std::time::Duration::from_secs(30), 40);
+        self.wait_until_cluster_active_with(input, &waiter).await
+    }
+
+    /// Waits for `ClusterActive` like `wait_until_cluster_active`, with the delay, attempts and runtime of `waiter`.
+    pub async fn wait_until_cluster_active_with(
+        &self,
+        input: DescribeClusterRequest,
+        waiter: &rusoto_core::waiter::Waiter,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<DescribeClusterError>> {
+        rusoto_core::waiter::wait(
+            waiter,
+            || self.describe_cluster(input.clone()),
+            |result| {
+                if match result {
+                    Ok(output) => std::iter::once(output)
+                        .filter_map(|value| value.cluster.as_ref())
+                        .filter_map(|value| value.status.as_ref())
+                        .any(|value| value == "DELETING"),
+                    Err(_) => false,
+                } {
+                    return Some(rusoto_core::waiter::WaiterState::Failure);
+                }
+                if match result {
+                    Ok(output) => std::iter::once(output)
+                        .filter_map(|value| value.cluster.as_ref())
+                        .filter_map(|value| value.status.as_ref())
+                        .any(|value| value == "FAILED"),
+                    Err(_) => false,
+                } {
+                    return Some(rusoto_core::waiter::WaiterState::Failure);
+                }
+                if match result {
+                    Ok(output) => std::iter::once(output)
+                        .filter_map(|value| value.cluster.as_ref())
+                        .filter_map(|value| value.status.as_ref())
+                        .any(|value| value == "ACTIVE"),
+                    Err(_) => false,
+                } {
+                    return Some(rusoto_core::waiter::WaiterState::Success);
+                }
+                None
+            },
+        )
+        .await
+    }
+
+    /// Waits for `ClusterDeleted`, polling `DescribeCluster` with `input` every 30 seconds, up to 40 times.
+    pub async fn wait_until_cluster_deleted(
+        &self,
+        input: DescribeClusterRequest,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<DescribeClusterError>> {
+        let waiter = rusoto_core::waiter::Waiter::new(std::time::Duration::from_secs(30), 40);
+        self.wait_until_cluster_deleted_with(input, &waiter).await
+    }
+
+    /// Waits for `ClusterDeleted` like `wait_until_cluster_deleted`, with the delay, attempts and runtime of `waiter`.
+    pub async fn wait_until_cluster_deleted_with(
+        &self,
+        input: DescribeClusterRequest,
+        waiter: &rusoto_core::waiter::Waiter,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<DescribeClusterError>> {
+        rusoto_core::waiter::wait(
+            waiter,
+            || self.describe_cluster(input.clone()),
+            |result| {
+                if match result {
+                    Ok(output) => std::iter::once(output)
+                        .filter_map(|value| value.cluster.as_ref())
+                        .filter_map(|value| value.status.as_ref())
+                        .any(|value| value == "ACTIVE"),
+                    Err(_) => false,
+                } {
+                    return Some(rusoto_core::waiter::WaiterState::Failure);
+                }
+                if match result {
+                    Ok(output) => std::iter::once(output)
+                        .filter_map(|value| value.cluster.as_ref())
+                        .filter_map(|value| value.status.as_ref())
+                        .any(|value| value == "CREATING"),
+                    Err(_) => false,
+                } {
+                    return Some(rusoto_core::waiter::WaiterState::Failure);
+                }
+                if match result {
+                    Ok(output) => std::iter::once(output)
+                        .filter_map(|value| value.cluster.as_ref())
+                        .filter_map(|value| value.status.as_ref())
+                        .any(|value| value == "PENDING"),
+                    Err(_) => false,
+                } {
+                    return Some(rusoto_core::waiter::WaiterState::Failure);
+                }
+                if result
+                    .as_ref()
+                    .err()
+                    .and_then(|error| error.error_code())
+                    .as_deref()
+                    == Some("ResourceNotFoundException")
+                {
+                    return Some(rusoto_core::waiter::WaiterState::Success);
+                }
+                None
+            },
+        )
+        .await
+    }
+
+    /// Waits for `FargateProfileActive`, polling `DescribeFargateProfile` with `input` every 10 seconds, up to 60 times.
+    pub async fn wait_until_fargate_profile_active(
+        &self,
+        input: DescribeFargateProfileRequest,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<DescribeFargateProfileError>> {
+        let waiter = rusoto_core::waiter::Waiter::new(std::time::Duration::from_secs(10), 60);
+        self.wait_until_fargate_profile_active_with(input, &waiter)
             .await
-            .map_err(RusotoError::from)?;
-        if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<UpdateNodegroupVersionResponse, _>()?;
+    }
 
-            Ok(result)
-        } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(UpdateNodegroupVersionError::from_response(response))
-        }
+    /// Waits for `FargateProfileActive` like `wait_until_fargate_profile_active`, with the delay, attempts and runtime of `waiter`.
+    pub async fn wait_until_fargate_profile_active_with(
+        &self,
+        input: DescribeFargateProfileRequest,
+        waiter: &rusoto_core::waiter::Waiter,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<DescribeFargateProfileError>> {
+        rusoto_core::waiter::wait(
+            waiter,
+            || self.describe_fargate_profile(input.clone()),
+            |result| {
+                if match result {
+                    Ok(output) => std::iter::once(output)
+                        .filter_map(|value| value.fargate_profile.as_ref())
+                        .filter_map(|value| value.status.as_ref())
+                        .any(|value| value == "CREATE_FAILED"),
+                    Err(_) => false,
+                } {
+                    return Some(rusoto_core::waiter::WaiterState::Failure);
+                }
+                if match result {
+                    Ok(output) => std::iter::once(output)
+                        .filter_map(|value| value.fargate_profile.as_ref())
+                        .filter_map(|value| value.status.as_ref())
+                        .any(|value| value == "ACTIVE"),
+                    Err(_) => false,
+                } {
+                    return Some(rusoto_core::waiter::WaiterState::Success);
+                }
+                None
+            },
+        )
+        .await
+    }
+
+    /// Waits for `FargateProfileDeleted`, polling `DescribeFargateProfile` with `input` every 30 seconds, up to 60 times.
+    pub async fn wait_until_fargate_profile_deleted(
+        &self,
+        input: DescribeFargateProfileRequest,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<DescribeFargateProfileError>> {
+        let waiter = rusoto_core::waiter::Waiter::new(std::time::Duration::from_secs(30), 60);
+        self.wait_until_fargate_profile_deleted_with(input, &waiter)
+            .await
+    }
+
+    /// Waits for `FargateProfileDeleted` like `wait_until_fargate_profile_deleted`, with the delay, attempts and runtime of `waiter`.
+    pub async fn wait_until_fargate_profile_deleted_with(
+        &self,
+        input: DescribeFargateProfileRequest,
+        waiter: &rusoto_core::waiter::Waiter,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<DescribeFargateProfileError>> {
+        rusoto_core::waiter::wait(
+            waiter,
+            || self.describe_fargate_profile(input.clone()),
+            |result| {
+                if match result {
+                    Ok(output) => std::iter::once(output)
+                        .filter_map(|value| value.fargate_profile.as_ref())
+                        .filter_map(|value| value.status.as_ref())
+                        .any(|value| value == "DELETE_FAILED"),
+                    Err(_) => false,
+                } {
+                    return Some(rusoto_core::waiter::WaiterState::Failure);
+                }
+                if result
+                    .as_ref()
+                    .err()
+                    .and_then(|error| error.error_code())
+                    .as_deref()
+                    == Some("ResourceNotFoundException")
+                {
+                    return Some(rusoto_core::waiter::WaiterState::Success);
+                }
+                None
+            },
+        )
+        .await
+    }
+
+    /// Waits for `NodegroupActive`, polling `DescribeNodegroup` with `input` every 30 seconds, up to 80 times.
+    pub async fn wait_until_nodegroup_active(
+        &self,
+        input: DescribeNodegroupRequest,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<DescribeNodegroupError>> {
+        let waiter = rusoto_core::waiter::Waiter::new(std::time::Duration::from_secs(30), 80);
+        self.wait_until_nodegroup_active_with(input, &waiter).await
+    }
+
+    /// Waits for `NodegroupActive` like `wait_until_nodegroup_active`, with the delay, attempts and runtime of `waiter`.
+    pub async fn wait_until_nodegroup_active_with(
+        &self,
+        input: DescribeNodegroupRequest,
+        waiter: &rusoto_core::waiter::Waiter,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<DescribeNodegroupError>> {
+        rusoto_core::waiter::wait(
+            waiter,
+            || self.describe_nodegroup(input.clone()),
+            |result| {
+                if match result {
+                    Ok(output) => std::iter::once(output)
+                        .filter_map(|value| value.nodegroup.as_ref())
+                        .filter_map(|value| value.status.as_ref())
+                        .any(|value| value == "CREATE_FAILED"),
+                    Err(_) => false,
+                } {
+                    return Some(rusoto_core::waiter::WaiterState::Failure);
+                }
+                if match result {
+                    Ok(output) => std::iter::once(output)
+                        .filter_map(|value| value.nodegroup.as_ref())
+                        .filter_map(|value| value.status.as_ref())
+                        .any(|value| value == "ACTIVE"),
+                    Err(_) => false,
+                } {
+                    return Some(rusoto_core::waiter::WaiterState::Success);
+                }
+                None
+            },
+        )
+        .await
+    }
+
+    /// Waits for `NodegroupDeleted`, polling `DescribeNodegroup` with `input` every 30 seconds, up to 40 times.
+    pub async fn wait_until_nodegroup_deleted(
+        &self,
+        input: DescribeNodegroupRequest,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<DescribeNodegroupError>> {
+        let waiter = rusoto_core::waiter::Waiter::new(std::time::Duration::from_secs(30), 40);
+        self.wait_until_nodegroup_deleted_with(input, &waiter).await
+    }
+
+    /// Waits for `NodegroupDeleted` like `wait_until_nodegroup_deleted`, with the delay, attempts and runtime of `waiter`.
+    pub async fn wait_until_nodegroup_deleted_with(
+        &self,
+        input: DescribeNodegroupRequest,
+        waiter: &rusoto_core::waiter::Waiter,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<DescribeNodegroupError>> {
+        rusoto_core::waiter::wait(
+            waiter,
+            || self.describe_nodegroup(input.clone()),
+            |result| {
+                if match result {
+                    Ok(output) => std::iter::once(output)
+                        .filter_map(|value| value.nodegroup.as_ref())
+                        .filter_map(|value| value.status.as_ref())
+                        .any(|value| value == "DELETE_FAILED"),
+                    Err(_) => false,
+                } {
+                    return Some(rusoto_core::waiter::WaiterState::Failure);
+                }
+                if result
+                    .as_ref()
+                    .err()
+                    .and_then(|error| error.error_code())
+                    .as_deref()
+                    == Some("ResourceNotFoundException")
+                {
+                    return Some(rusoto_core::waiter::WaiterState::Success);
+                }
+                None
+            },
+        )
+        .await
+    }
+}
+
+#[async_trait]
+impl Eks for EksClient {
+    /// <p>Creates an Amazon EKS control plane. </p> <p>The Amazon EKS control plane consists of control plane instances that run the Kubernetes software, such as <code>etcd</code> and the API server. The control plane runs in an account managed by AWS, and the Kubernetes API is exposed via the Amazon EKS API server endpoint. Each Amazon EKS cluster control plane is single-tenant and unique and runs on its own set of Amazon EC2 instances.</p> <p>The cluster control plane is provisioned across multiple Availability Zones and fronted by an Elastic Load Balancing Network Load Balancer. Amazon EKS also provisions elastic network interfaces in your VPC subnets to provide connectivity from the control plane instances to the worker nodes (for example, to support <code>kubectl exec</code>, <code>logs</code>, and <code>proxy</code> data flows).</p> <p>Amazon EKS worker nodes run in your AWS account and connect to your cluster's control plane via the Kubernetes API server endpoint and a certificate file that is created for your cluster.</p> <p>You can use the <code>endpointPublicAccess</code> and <code>endpointPrivateAccess</code> parameters to enable or disable public and private access to your cluster's Kubernetes API server endpoint. By default, public access is enabled, and private access is disabled. For more information, see <a href="https://docs.aws.amazon.com/eks/latest/userguide/cluster-endpoint.html">Amazon EKS Cluster Endpoint Access Control</a> in the <i> <i>Amazon EKS User Guide</i> </i>. </p> <p>You can use the <code>logging</code> parameter to enable or disable exporting the Kubernetes control plane logs for your cluster to CloudWatch Logs. By default, cluster control plane logs aren't exported to CloudWatch Logs. For more information, see <a href="https://docs.aws.amazon.com/eks/latest/userguide/control-plane-logs.html">Amazon EKS Cluster Control Plane Logs</a> in the <i> <i>Amazon EKS User Guide</i> </i>.</p> <note> <p>CloudWatch Logs ingestion, archive storage, and data scanning rates apply to exported control plane logs. For more information, see <a href="http://aws.amazon.com/cloudwatch/pricing/">Amazon CloudWatch Pricing</a>.</p> </note> <p>Cluster creation typically takes between 10 and 15 minutes. After you create an Amazon EKS cluster, you must configure your Kubernetes tooling to communicate with the API server and launch worker nodes into your cluster. For more information, see <a href="https://docs.aws.amazon.com/eks/latest/userguide/managing-auth.html">Managing Cluster Authentication</a> and <a href="https://docs.aws.amazon.com/eks/latest/userguide/launch-workers.html">Launching Amazon EKS Worker Nodes</a> in the <i>Amazon EKS User Guide</i>.</p>
+    async fn create_cluster(
+        &self,
+        input: CreateClusterRequest,
+    ) -> Result<CreateClusterResponse, RusotoError<CreateClusterError>> {
+        self.create_cluster_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Creates an AWS Fargate profile for your Amazon EKS cluster. You must have at least one Fargate profile in a cluster to be able to run pods on Fargate.</p> <p>The Fargate profile allows an administrator to declare which pods run on Fargate and specify which pods run on which Fargate profile. This declaration is done through the profile’s selectors. Each profile can have up to five selectors that contain a namespace and labels. A namespace is required for every selector. The label field consists of multiple optional key-value pairs. Pods that match the selectors are scheduled on Fargate. If a to-be-scheduled pod matches any of the selectors in the Fargate profile, then that pod is run on Fargate.</p> <p>When you create a Fargate profile, you must specify a pod execution role to use with the pods that are scheduled with the profile. This role is added to the cluster's Kubernetes <a href="https://kubernetes.io/docs/admin/authorization/rbac/">Role Based Access Control</a> (RBAC) for authorization so that the <code>kubelet</code> that is running on the Fargate infrastructure can register with your Amazon EKS cluster so that it can appear in your cluster as a node. The pod execution role also provides IAM permissions to the Fargate infrastructure to allow read access to Amazon ECR image repositories. For more information, see <a href="https://docs.aws.amazon.com/eks/latest/userguide/pod-execution-role.html">Pod Execution Role</a> in the <i>Amazon EKS User Guide</i>.</p> <p>Fargate profiles are immutable. However, you can create a new updated profile to replace an existing profile and then delete the original after the updated profile has finished creating.</p> <p>If any Fargate profiles in a cluster are in the <code>DELETING</code> status, you must wait for that Fargate profile to finish deleting before you can create any other profiles in that cluster.</p> <p>For more information, see <a href="https://docs.aws.amazon.com/eks/latest/userguide/fargate-profile.html">AWS Fargate Profile</a> in the <i>Amazon EKS User Guide</i>.</p>
+    async fn create_fargate_profile(
+        &self,
+        input: CreateFargateProfileRequest,
+    ) -> Result<CreateFargateProfileResponse, RusotoError<CreateFargateProfileError>> {
+        self.create_fargate_profile_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Creates a managed worker node group for an Amazon EKS cluster. You can only create a node group for your cluster that is equal to the current Kubernetes version for the cluster. All node groups are created with the latest AMI release version for the respective minor Kubernetes version of the cluster.</p> <p>An Amazon EKS managed node group is an Amazon EC2 Auto Scaling group and associated Amazon EC2 instances that are managed by AWS for an Amazon EKS cluster. Each node group uses a version of the Amazon EKS-optimized Amazon Linux 2 AMI. For more information, see <a href="https://docs.aws.amazon.com/eks/latest/userguide/managed-node-groups.html">Managed Node Groups</a> in the <i>Amazon EKS User Guide</i>. </p>
+    async fn create_nodegroup(
+        &self,
+        input: CreateNodegroupRequest,
+    ) -> Result<CreateNodegroupResponse, RusotoError<CreateNodegroupError>> {
+        self.create_nodegroup_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes the Amazon EKS cluster control plane.</p> <p>If you have active services in your cluster that are associated with a load balancer, you must delete those services before deleting the cluster so that the load balancers are deleted properly. Otherwise, you can have orphaned resources in your VPC that prevent you from being able to delete the VPC. For more information, see <a href="https://docs.aws.amazon.com/eks/latest/userguide/delete-cluster.html">Deleting a Cluster</a> in the <i>Amazon EKS User Guide</i>.</p> <p>If you have managed node groups or Fargate profiles attached to the cluster, you must delete them first. For more information, see <a>DeleteNodegroup</a> and<a>DeleteFargateProfile</a>.</p>
+    async fn delete_cluster(
+        &self,
+        input: DeleteClusterRequest,
+    ) -> Result<DeleteClusterResponse, RusotoError<DeleteClusterError>> {
+        self.delete_cluster_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes an AWS Fargate profile.</p> <p>When you delete a Fargate profile, any pods running on Fargate that were created with the profile are deleted. If those pods match another Fargate profile, then they are scheduled on Fargate with that profile. If they no longer match any Fargate profiles, then they are not scheduled on Fargate and they may remain in a pending state.</p> <p>Only one Fargate profile in a cluster can be in the <code>DELETING</code> status at a time. You must wait for a Fargate profile to finish deleting before you can delete any other profiles in that cluster.</p>
+    async fn delete_fargate_profile(
+        &self,
+        input: DeleteFargateProfileRequest,
+    ) -> Result<DeleteFargateProfileResponse, RusotoError<DeleteFargateProfileError>> {
+        self.delete_fargate_profile_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes an Amazon EKS node group for a cluster.</p>
+    async fn delete_nodegroup(
+        &self,
+        input: DeleteNodegroupRequest,
+    ) -> Result<DeleteNodegroupResponse, RusotoError<DeleteNodegroupError>> {
+        self.delete_nodegroup_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Returns descriptive information about an Amazon EKS cluster.</p> <p>The API server endpoint and certificate authority data returned by this operation are required for <code>kubelet</code> and <code>kubectl</code> to communicate with your Kubernetes API server. For more information, see <a href="https://docs.aws.amazon.com/eks/latest/userguide/create-kubeconfig.html">Create a kubeconfig for Amazon EKS</a>.</p> <note> <p>The API server endpoint and certificate authority data aren&#39;t available until the cluster reaches the <code>ACTIVE</code> state.</p> </note></p>
+    async fn describe_cluster(
+        &self,
+        input: DescribeClusterRequest,
+    ) -> Result<DescribeClusterResponse, RusotoError<DescribeClusterError>> {
+        self.describe_cluster_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns descriptive information about an AWS Fargate profile.</p>
+    async fn describe_fargate_profile(
+        &self,
+        input: DescribeFargateProfileRequest,
+    ) -> Result<DescribeFargateProfileResponse, RusotoError<DescribeFargateProfileError>> {
+        self.describe_fargate_profile_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns descriptive information about an Amazon EKS node group.</p>
+    async fn describe_nodegroup(
+        &self,
+        input: DescribeNodegroupRequest,
+    ) -> Result<DescribeNodegroupResponse, RusotoError<DescribeNodegroupError>> {
+        self.describe_nodegroup_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns descriptive information about an update against your Amazon EKS cluster or associated managed node group.</p> <p>When the status of the update is <code>Succeeded</code>, the update is complete. If an update fails, the status is <code>Failed</code>, and an error detail explains the reason for the failure.</p>
+    async fn describe_update(
+        &self,
+        input: DescribeUpdateRequest,
+    ) -> Result<DescribeUpdateResponse, RusotoError<DescribeUpdateError>> {
+        self.describe_update_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Lists the Amazon EKS clusters in your AWS account in the specified Region.</p>
+    async fn list_clusters(
+        &self,
+        input: ListClustersRequest,
+    ) -> Result<ListClustersResponse, RusotoError<ListClustersError>> {
+        self.list_clusters_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Lists the AWS Fargate profiles associated with the specified cluster in your AWS account in the specified Region.</p>
+    async fn list_fargate_profiles(
+        &self,
+        input: ListFargateProfilesRequest,
+    ) -> Result<ListFargateProfilesResponse, RusotoError<ListFargateProfilesError>> {
+        self.list_fargate_profiles_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Lists the Amazon EKS node groups associated with the specified cluster in your AWS account in the specified Region.</p>
+    async fn list_nodegroups(
+        &self,
+        input: ListNodegroupsRequest,
+    ) -> Result<ListNodegroupsResponse, RusotoError<ListNodegroupsError>> {
+        self.list_nodegroups_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>List the tags for an Amazon EKS resource.</p>
+    async fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> Result<ListTagsForResourceResponse, RusotoError<ListTagsForResourceError>> {
+        self.list_tags_for_resource_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Lists the updates associated with an Amazon EKS cluster or managed node group in your AWS account, in the specified Region.</p>
+    async fn list_updates(
+        &self,
+        input: ListUpdatesRequest,
+    ) -> Result<ListUpdatesResponse, RusotoError<ListUpdatesError>> {
+        self.list_updates_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Associates the specified tags to a resource with the specified <code>resourceArn</code>. If existing tags on a resource are not specified in the request parameters, they are not changed. When a resource is deleted, the tags associated with that resource are deleted as well. Tags that you create for Amazon EKS resources do not propagate to any other resources associated with the cluster. For example, if you tag a cluster with this operation, that tag does not automatically propagate to the subnets and worker nodes associated with the cluster.</p>
+    async fn tag_resource(
+        &self,
+        input: TagResourceRequest,
+    ) -> Result<TagResourceResponse, RusotoError<TagResourceError>> {
+        self.tag_resource_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes specified tags from a resource.</p>
+    async fn untag_resource(
+        &self,
+        input: UntagResourceRequest,
+    ) -> Result<UntagResourceResponse, RusotoError<UntagResourceError>> {
+        self.untag_resource_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Updates an Amazon EKS cluster configuration. Your cluster continues to function during the update. The response output includes an update ID that you can use to track the status of your cluster update with the <a>DescribeUpdate</a> API operation.</p> <p>You can use this API operation to enable or disable exporting the Kubernetes control plane logs for your cluster to CloudWatch Logs. By default, cluster control plane logs aren't exported to CloudWatch Logs. For more information, see <a href="https://docs.aws.amazon.com/eks/latest/userguide/control-plane-logs.html">Amazon EKS Cluster Control Plane Logs</a> in the <i> <i>Amazon EKS User Guide</i> </i>.</p> <note> <p>CloudWatch Logs ingestion, archive storage, and data scanning rates apply to exported control plane logs. For more information, see <a href="http://aws.amazon.com/cloudwatch/pricing/">Amazon CloudWatch Pricing</a>.</p> </note> <p>You can also use this API operation to enable or disable public and private access to your cluster's Kubernetes API server endpoint. By default, public access is enabled, and private access is disabled. For more information, see <a href="https://docs.aws.amazon.com/eks/latest/userguide/cluster-endpoint.html">Amazon EKS Cluster Endpoint Access Control</a> in the <i> <i>Amazon EKS User Guide</i> </i>. </p> <important> <p>At this time, you can not update the subnets or security group IDs for an existing cluster.</p> </important> <p>Cluster updates are asynchronous, and they should finish within a few minutes. During an update, the cluster status moves to <code>UPDATING</code> (this status transition is eventually consistent). When the update is complete (either <code>Failed</code> or <code>Successful</code>), the cluster status moves to <code>Active</code>.</p>
+    async fn update_cluster_config(
+        &self,
+        input: UpdateClusterConfigRequest,
+    ) -> Result<UpdateClusterConfigResponse, RusotoError<UpdateClusterConfigError>> {
+        self.update_cluster_config_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Updates an Amazon EKS cluster to the specified Kubernetes version. Your cluster continues to function during the update. The response output includes an update ID that you can use to track the status of your cluster update with the <a>DescribeUpdate</a> API operation.</p> <p>Cluster updates are asynchronous, and they should finish within a few minutes. During an update, the cluster status moves to <code>UPDATING</code> (this status transition is eventually consistent). When the update is complete (either <code>Failed</code> or <code>Successful</code>), the cluster status moves to <code>Active</code>.</p> <p>If your cluster has managed node groups attached to it, all of your node groups’ Kubernetes versions must match the cluster’s Kubernetes version in order to update the cluster to a new Kubernetes version.</p>
+    async fn update_cluster_version(
+        &self,
+        input: UpdateClusterVersionRequest,
+    ) -> Result<UpdateClusterVersionResponse, RusotoError<UpdateClusterVersionError>> {
+        self.update_cluster_version_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Updates an Amazon EKS managed node group configuration. Your node group continues to function during the update. The response output includes an update ID that you can use to track the status of your node group update with the <a>DescribeUpdate</a> API operation. Currently you can update the Kubernetes labels for a node group or the scaling configuration.</p>
+    async fn update_nodegroup_config(
+        &self,
+        input: UpdateNodegroupConfigRequest,
+    ) -> Result<UpdateNodegroupConfigResponse, RusotoError<UpdateNodegroupConfigError>> {
+        self.update_nodegroup_config_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Updates the Kubernetes version or AMI version of an Amazon EKS managed node group.</p> <p>You can update to the latest available AMI version of a node group's current Kubernetes version by not specifying a Kubernetes version in the request. You can update to the latest AMI version of your cluster's current Kubernetes version by specifying your cluster's Kubernetes version in the request. For more information, see <a href="https://docs.aws.amazon.com/eks/latest/userguide/eks-linux-ami-versions.html">Amazon EKS-Optimized Linux AMI Versions</a> in the <i>Amazon EKS User Guide</i>.</p> <p>You cannot roll back a node group to an earlier Kubernetes version or AMI version.</p> <p>When a node in a managed node group is terminated due to a scaling action or update, the pods in that node are drained first. Amazon EKS attempts to drain the nodes gracefully and will fail if it is unable to do so. You can <code>force</code> the update if Amazon EKS is unable to drain the nodes as a result of a pod disruption budget issue.</p>
+    async fn update_nodegroup_version(
+        &self,
+        input: UpdateNodegroupVersionRequest,
+    ) -> Result<UpdateNodegroupVersionResponse, RusotoError<UpdateNodegroupVersionError>> {
+        self.update_nodegroup_version_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
     }
 }
@@ -16,8 +16,8 @@ use std::fmt;
 use async_trait::async_trait;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
-use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest, ResponseMetadata};
+use rusoto_core::{Client, ErrorMetadata, RusotoError};
 
 use rusoto_core::param::{Params, ServiceParams};
 use rusoto_core::proto::xml::error::*;
@@ -7723,6 +7723,7 @@ impl UpdateActionsMessageDeserializer {
 }
 /// Errors returned by AddTagsToResource
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum AddTagsToResourceError {
     /// <p>The requested cluster ID does not refer to an existing cluster.</p>
     CacheClusterNotFoundFault(String),
@@ -7732,6 +7733,8 @@ pub enum AddTagsToResourceError {
     SnapshotNotFoundFault(String),
     /// <p>The request cannot be processed because it would cause the resource to have more than the allowed number of tags. The maximum number of tags permitted on a resource is 50.</p>
     TagQuotaPerResourceExceeded(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl AddTagsToResourceError {
@@ -7743,28 +7746,32 @@ impl AddTagsToResourceError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "CacheClusterNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             AddTagsToResourceError::CacheClusterNotFoundFault(parsed_error.message),
+                            &res,
                         )
                     }
                     "InvalidARN" => {
-                        return RusotoError::Service(AddTagsToResourceError::InvalidARNFault(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            AddTagsToResourceError::InvalidARNFault(parsed_error.message),
+                            &res,
+                        )
                     }
                     "SnapshotNotFoundFault" => {
-                        return RusotoError::Service(AddTagsToResourceError::SnapshotNotFoundFault(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            AddTagsToResourceError::SnapshotNotFoundFault(parsed_error.message),
+                            &res,
+                        )
                     }
                     "TagQuotaPerResourceExceeded" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             AddTagsToResourceError::TagQuotaPerResourceExceeded(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
-                    _ => {}
+                    _ => return RusotoError::unhandled(AddTagsToResourceError::Unhandled, &res),
                 }
             }
         }
@@ -7789,12 +7796,14 @@ impl fmt::Display for AddTagsToResourceError {
             AddTagsToResourceError::TagQuotaPerResourceExceeded(ref cause) => {
                 write!(f, "{}", cause)
             }
+            AddTagsToResourceError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for AddTagsToResourceError {}
 /// Errors returned by AuthorizeCacheSecurityGroupIngress
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum AuthorizeCacheSecurityGroupIngressError {
     /// <p>The specified Amazon EC2 security group is already authorized for the specified cache security group.</p>
     AuthorizationAlreadyExistsFault(String),
@@ -7806,6 +7815,8 @@ pub enum AuthorizeCacheSecurityGroupIngressError {
     InvalidParameterCombination(String),
     /// <p>The value for a parameter is invalid.</p>
     InvalidParameterValue(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl AuthorizeCacheSecurityGroupIngressError {
@@ -7818,7 +7829,7 @@ impl AuthorizeCacheSecurityGroupIngressError {
             find_start_element(&mut stack);
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
-                                    "AuthorizationAlreadyExists" => return RusotoError::Service(AuthorizeCacheSecurityGroupIngressError::AuthorizationAlreadyExistsFault(parsed_error.message)),"CacheSecurityGroupNotFound" => return RusotoError::Service(AuthorizeCacheSecurityGroupIngressError::CacheSecurityGroupNotFoundFault(parsed_error.message)),"InvalidCacheSecurityGroupState" => return RusotoError::Service(AuthorizeCacheSecurityGroupIngressError::InvalidCacheSecurityGroupStateFault(parsed_error.message)),"InvalidParameterCombination" => return RusotoError::Service(AuthorizeCacheSecurityGroupIngressError::InvalidParameterCombination(parsed_error.message)),"InvalidParameterValue" => return RusotoError::Service(AuthorizeCacheSecurityGroupIngressError::InvalidParameterValue(parsed_error.message)),_ => {}
+                                    "AuthorizationAlreadyExists" => return RusotoError::service(AuthorizeCacheSecurityGroupIngressError::AuthorizationAlreadyExistsFault(parsed_error.message), &res),"CacheSecurityGroupNotFound" => return RusotoError::service(AuthorizeCacheSecurityGroupIngressError::CacheSecurityGroupNotFoundFault(parsed_error.message), &res),"InvalidCacheSecurityGroupState" => return RusotoError::service(AuthorizeCacheSecurityGroupIngressError::InvalidCacheSecurityGroupStateFault(parsed_error.message), &res),"InvalidParameterCombination" => return RusotoError::service(AuthorizeCacheSecurityGroupIngressError::InvalidParameterCombination(parsed_error.message), &res),"InvalidParameterValue" => return RusotoError::service(AuthorizeCacheSecurityGroupIngressError::InvalidParameterValue(parsed_error.message), &res),_ => return RusotoError::unhandled(AuthorizeCacheSecurityGroupIngressError::Unhandled, &res),
                                 }
             }
         }
@@ -7852,17 +7863,23 @@ impl fmt::Display for AuthorizeCacheSecurityGroupIngressError {
             AuthorizeCacheSecurityGroupIngressError::InvalidParameterValue(ref cause) => {
                 write!(f, "{}", cause)
             }
+            AuthorizeCacheSecurityGroupIngressError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for AuthorizeCacheSecurityGroupIngressError {}
 /// Errors returned by BatchApplyUpdateAction
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum BatchApplyUpdateActionError {
     /// <p>The value for a parameter is invalid.</p>
     InvalidParameterValue(String),
     /// <p>The service update doesn't exist</p>
     ServiceUpdateNotFoundFault(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl BatchApplyUpdateActionError {
@@ -7874,20 +7891,24 @@ impl BatchApplyUpdateActionError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InvalidParameterValue" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             BatchApplyUpdateActionError::InvalidParameterValue(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "ServiceUpdateNotFoundFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             BatchApplyUpdateActionError::ServiceUpdateNotFoundFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
-                    _ => {}
+                    _ => {
+                        return RusotoError::unhandled(BatchApplyUpdateActionError::Unhandled, &res)
+                    }
                 }
             }
         }
@@ -7910,17 +7931,21 @@ impl fmt::Display for BatchApplyUpdateActionError {
             BatchApplyUpdateActionError::ServiceUpdateNotFoundFault(ref cause) => {
                 write!(f, "{}", cause)
             }
+            BatchApplyUpdateActionError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for BatchApplyUpdateActionError {}
 /// Errors returned by BatchStopUpdateAction
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum BatchStopUpdateActionError {
     /// <p>The value for a parameter is invalid.</p>
     InvalidParameterValue(String),
     /// <p>The service update doesn't exist</p>
     ServiceUpdateNotFoundFault(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl BatchStopUpdateActionError {
@@ -7932,18 +7957,22 @@ impl BatchStopUpdateActionError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InvalidParameterValue" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             BatchStopUpdateActionError::InvalidParameterValue(parsed_error.message),
+                            &res,
                         )
                     }
                     "ServiceUpdateNotFoundFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             BatchStopUpdateActionError::ServiceUpdateNotFoundFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
-                    _ => {}
+                    _ => {
+                        return RusotoError::unhandled(BatchStopUpdateActionError::Unhandled, &res)
+                    }
                 }
             }
         }
@@ -7966,12 +7995,14 @@ impl fmt::Display for BatchStopUpdateActionError {
             BatchStopUpdateActionError::ServiceUpdateNotFoundFault(ref cause) => {
                 write!(f, "{}", cause)
             }
+            BatchStopUpdateActionError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for BatchStopUpdateActionError {}
 /// Errors returned by CompleteMigration
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CompleteMigrationError {
     /// <p>The requested replication group is not in the <code>available</code> state.</p>
     InvalidReplicationGroupStateFault(String),
@@ -7979,6 +8010,8 @@ pub enum CompleteMigrationError {
     ReplicationGroupNotFoundFault(String),
     /// <p>The designated replication group is not available for data migration.</p>
     ReplicationGroupNotUnderMigrationFault(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CompleteMigrationError {
@@ -7990,27 +8023,30 @@ impl CompleteMigrationError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InvalidReplicationGroupState" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             CompleteMigrationError::InvalidReplicationGroupStateFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "ReplicationGroupNotFoundFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             CompleteMigrationError::ReplicationGroupNotFoundFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "ReplicationGroupNotUnderMigrationFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             CompleteMigrationError::ReplicationGroupNotUnderMigrationFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
-                    _ => {}
+                    _ => return RusotoError::unhandled(CompleteMigrationError::Unhandled, &res),
                 }
             }
         }
@@ -8038,12 +8074,14 @@ impl fmt::Display for CompleteMigrationError {
             CompleteMigrationError::ReplicationGroupNotUnderMigrationFault(ref cause) => {
                 write!(f, "{}", cause)
             }
+            CompleteMigrationError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CompleteMigrationError {}
 /// Errors returned by CopySnapshot
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CopySnapshotError {
     /// <p>Two or more incompatible parameters were specified.</p>
     InvalidParameterCombination(String),
@@ -8057,6 +8095,8 @@ pub enum CopySnapshotError {
     SnapshotNotFoundFault(String),
     /// <p>The request cannot be processed because it would exceed the maximum number of snapshots.</p>
     SnapshotQuotaExceededFault(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CopySnapshotError {
@@ -8068,36 +8108,42 @@ impl CopySnapshotError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InvalidParameterCombination" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             CopySnapshotError::InvalidParameterCombination(parsed_error.message),
+                            &res,
                         )
                     }
                     "InvalidParameterValue" => {
-                        return RusotoError::Service(CopySnapshotError::InvalidParameterValue(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            CopySnapshotError::InvalidParameterValue(parsed_error.message),
+                            &res,
+                        )
                     }
                     "InvalidSnapshotState" => {
-                        return RusotoError::Service(CopySnapshotError::InvalidSnapshotStateFault(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            CopySnapshotError::InvalidSnapshotStateFault(parsed_error.message),
+                            &res,
+                        )
                     }
                     "SnapshotAlreadyExistsFault" => {
-                        return RusotoError::Service(CopySnapshotError::SnapshotAlreadyExistsFault(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            CopySnapshotError::SnapshotAlreadyExistsFault(parsed_error.message),
+                            &res,
+                        )
                     }
                     "SnapshotNotFoundFault" => {
-                        return RusotoError::Service(CopySnapshotError::SnapshotNotFoundFault(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            CopySnapshotError::SnapshotNotFoundFault(parsed_error.message),
+                            &res,
+                        )
                     }
                     "SnapshotQuotaExceededFault" => {
-                        return RusotoError::Service(CopySnapshotError::SnapshotQuotaExceededFault(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            CopySnapshotError::SnapshotQuotaExceededFault(parsed_error.message),
+                            &res,
+                        )
                     }
-                    _ => {}
+                    _ => return RusotoError::unhandled(CopySnapshotError::Unhandled, &res),
                 }
             }
         }
@@ -8122,12 +8168,14 @@ impl fmt::Display for CopySnapshotError {
             CopySnapshotError::SnapshotAlreadyExistsFault(ref cause) => write!(f, "{}", cause),
             CopySnapshotError::SnapshotNotFoundFault(ref cause) => write!(f, "{}", cause),
             CopySnapshotError::SnapshotQuotaExceededFault(ref cause) => write!(f, "{}", cause),
+            CopySnapshotError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CopySnapshotError {}
 /// Errors returned by CreateCacheCluster
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateCacheClusterError {
     /// <p>You already have a cluster with the given identifier.</p>
     CacheClusterAlreadyExistsFault(String),
@@ -8157,6 +8205,8 @@ pub enum CreateCacheClusterError {
     ReplicationGroupNotFoundFault(String),
     /// <p>The request cannot be processed because it would cause the resource to have more than the allowed number of tags. The maximum number of tags permitted on a resource is 50.</p>
     TagQuotaPerResourceExceeded(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateCacheClusterError {
@@ -8168,102 +8218,116 @@ impl CreateCacheClusterError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "CacheClusterAlreadyExists" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             CreateCacheClusterError::CacheClusterAlreadyExistsFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "CacheParameterGroupNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             CreateCacheClusterError::CacheParameterGroupNotFoundFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "CacheSecurityGroupNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             CreateCacheClusterError::CacheSecurityGroupNotFoundFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "CacheSubnetGroupNotFoundFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             CreateCacheClusterError::CacheSubnetGroupNotFoundFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "ClusterQuotaForCustomerExceeded" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             CreateCacheClusterError::ClusterQuotaForCustomerExceededFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "InsufficientCacheClusterCapacity" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             CreateCacheClusterError::InsufficientCacheClusterCapacityFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "InvalidParameterCombination" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             CreateCacheClusterError::InvalidParameterCombination(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "InvalidParameterValue" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             CreateCacheClusterError::InvalidParameterValue(parsed_error.message),
+                            &res,
                         )
                     }
                     "InvalidReplicationGroupState" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             CreateCacheClusterError::InvalidReplicationGroupStateFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "InvalidVPCNetworkStateFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             CreateCacheClusterError::InvalidVPCNetworkStateFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "NodeQuotaForClusterExceeded" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             CreateCacheClusterError::NodeQuotaForClusterExceededFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "NodeQuotaForCustomerExceeded" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             CreateCacheClusterError::NodeQuotaForCustomerExceededFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "ReplicationGroupNotFoundFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             CreateCacheClusterError::ReplicationGroupNotFoundFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "TagQuotaPerResourceExceeded" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             CreateCacheClusterError::TagQuotaPerResourceExceeded(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
-                    _ => {}
+                    _ => return RusotoError::unhandled(CreateCacheClusterError::Unhandled, &res),
                 }
             }
         }
@@ -8322,12 +8386,14 @@ impl fmt::Display for CreateCacheClusterError {
             CreateCacheClusterError::TagQuotaPerResourceExceeded(ref cause) => {
                 write!(f, "{}", cause)
             }
+            CreateCacheClusterError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateCacheClusterError {}
 /// Errors returned by CreateCacheParameterGroup
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateCacheParameterGroupError {
     /// <p>A cache parameter group with the requested name already exists.</p>
     CacheParameterGroupAlreadyExistsFault(String),
@@ -8339,6 +8405,8 @@ pub enum CreateCacheParameterGroupError {
     InvalidParameterCombination(String),
     /// <p>The value for a parameter is invalid.</p>
     InvalidParameterValue(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateCacheParameterGroupError {
@@ -8350,41 +8418,51 @@ impl CreateCacheParameterGroupError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "CacheParameterGroupAlreadyExists" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             CreateCacheParameterGroupError::CacheParameterGroupAlreadyExistsFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "CacheParameterGroupQuotaExceeded" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             CreateCacheParameterGroupError::CacheParameterGroupQuotaExceededFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "InvalidCacheParameterGroupState" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             CreateCacheParameterGroupError::InvalidCacheParameterGroupStateFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "InvalidParameterCombination" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             CreateCacheParameterGroupError::InvalidParameterCombination(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "InvalidParameterValue" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             CreateCacheParameterGroupError::InvalidParameterValue(
                                 parsed_error.message,
                             ),
+                            &res,
+                        )
+                    }
+                    _ => {
+                        return RusotoError::unhandled(
+                            CreateCacheParameterGroupError::Unhandled,
+                            &res,
                         )
                     }
-                    _ => {}
                 }
             }
         }
@@ -8418,12 +8496,14 @@ impl fmt::Display for CreateCacheParameterGroupError {
             CreateCacheParameterGroupError::InvalidParameterValue(ref cause) => {
                 write!(f, "{}", cause)
             }
+            CreateCacheParameterGroupError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateCacheParameterGroupError {}
 /// Errors returned by CreateCacheSecurityGroup
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateCacheSecurityGroupError {
     /// <p>A cache security group with the specified name already exists.</p>
     CacheSecurityGroupAlreadyExistsFault(String),
@@ -8433,6 +8513,8 @@ pub enum CreateCacheSecurityGroupError {
     InvalidParameterCombination(String),
     /// <p>The value for a parameter is invalid.</p>
     InvalidParameterValue(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateCacheSecurityGroupError {
@@ -8444,34 +8526,43 @@ impl CreateCacheSecurityGroupError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "CacheSecurityGroupAlreadyExists" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             CreateCacheSecurityGroupError::CacheSecurityGroupAlreadyExistsFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "QuotaExceeded.CacheSecurityGroup" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             CreateCacheSecurityGroupError::CacheSecurityGroupQuotaExceededFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "InvalidParameterCombination" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             CreateCacheSecurityGroupError::InvalidParameterCombination(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "InvalidParameterValue" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             CreateCacheSecurityGroupError::InvalidParameterValue(
                                 parsed_error.message,
                             ),
+                            &res,
+                        )
+                    }
+                    _ => {
+                        return RusotoError::unhandled(
+                            CreateCacheSecurityGroupError::Unhandled,
+                            &res,
                         )
                     }
-                    _ => {}
                 }
             }
         }
@@ -8502,12 +8593,14 @@ impl fmt::Display for CreateCacheSecurityGroupError {
             CreateCacheSecurityGroupError::InvalidParameterValue(ref cause) => {
                 write!(f, "{}", cause)
             }
+            CreateCacheSecurityGroupError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateCacheSecurityGroupError {}
 /// Errors returned by CreateCacheSubnetGroup
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateCacheSubnetGroupError {
     /// <p>The requested cache subnet group name is already in use by an existing cache subnet group.</p>
     CacheSubnetGroupAlreadyExistsFault(String),
@@ -8517,6 +8610,8 @@ pub enum CreateCacheSubnetGroupError {
     CacheSubnetQuotaExceededFault(String),
     /// <p>An invalid subnet identifier was specified.</p>
     InvalidSubnet(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateCacheSubnetGroupError {
@@ -8528,32 +8623,38 @@ impl CreateCacheSubnetGroupError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "CacheSubnetGroupAlreadyExists" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             CreateCacheSubnetGroupError::CacheSubnetGroupAlreadyExistsFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "CacheSubnetGroupQuotaExceeded" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             CreateCacheSubnetGroupError::CacheSubnetGroupQuotaExceededFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "CacheSubnetQuotaExceededFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             CreateCacheSubnetGroupError::CacheSubnetQuotaExceededFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "InvalidSubnet" => {
-                        return RusotoError::Service(CreateCacheSubnetGroupError::InvalidSubnet(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            CreateCacheSubnetGroupError::InvalidSubnet(parsed_error.message),
+                            &res,
+                        )
+                    }
+                    _ => {
+                        return RusotoError::unhandled(CreateCacheSubnetGroupError::Unhandled, &res)
                     }
-                    _ => {}
                 }
             }
         }
@@ -8582,12 +8683,14 @@ impl fmt::Display for CreateCacheSubnetGroupError {
                 write!(f, "{}", cause)
             }
             CreateCacheSubnetGroupError::InvalidSubnet(ref cause) => write!(f, "{}", cause),
+            CreateCacheSubnetGroupError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateCacheSubnetGroupError {}
 /// Errors returned by CreateReplicationGroup
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateReplicationGroupError {
     /// <p>The requested cluster ID does not refer to an existing cluster.</p>
     CacheClusterNotFoundFault(String),
@@ -8619,6 +8722,8 @@ pub enum CreateReplicationGroupError {
     ReplicationGroupAlreadyExistsFault(String),
     /// <p>The request cannot be processed because it would cause the resource to have more than the allowed number of tags. The maximum number of tags permitted on a resource is 50.</p>
     TagQuotaPerResourceExceeded(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateReplicationGroupError {
@@ -8629,7 +8734,7 @@ impl CreateReplicationGroupError {
             find_start_element(&mut stack);
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
-                                    "CacheClusterNotFound" => return RusotoError::Service(CreateReplicationGroupError::CacheClusterNotFoundFault(parsed_error.message)),"CacheParameterGroupNotFound" => return RusotoError::Service(CreateReplicationGroupError::CacheParameterGroupNotFoundFault(parsed_error.message)),"CacheSecurityGroupNotFound" => return RusotoError::Service(CreateReplicationGroupError::CacheSecurityGroupNotFoundFault(parsed_error.message)),"CacheSubnetGroupNotFoundFault" => return RusotoError::Service(CreateReplicationGroupError::CacheSubnetGroupNotFoundFault(parsed_error.message)),"ClusterQuotaForCustomerExceeded" => return RusotoError::Service(CreateReplicationGroupError::ClusterQuotaForCustomerExceededFault(parsed_error.message)),"InsufficientCacheClusterCapacity" => return RusotoError::Service(CreateReplicationGroupError::InsufficientCacheClusterCapacityFault(parsed_error.message)),"InvalidCacheClusterState" => return RusotoError::Service(CreateReplicationGroupError::InvalidCacheClusterStateFault(parsed_error.message)),"InvalidParameterCombination" => return RusotoError::Service(CreateReplicationGroupError::InvalidParameterCombination(parsed_error.message)),"InvalidParameterValue" => return RusotoError::Service(CreateReplicationGroupError::InvalidParameterValue(parsed_error.message)),"InvalidVPCNetworkStateFault" => return RusotoError::Service(CreateReplicationGroupError::InvalidVPCNetworkStateFault(parsed_error.message)),"NodeGroupsPerReplicationGroupQuotaExceeded" => return RusotoError::Service(CreateReplicationGroupError::NodeGroupsPerReplicationGroupQuotaExceededFault(parsed_error.message)),"NodeQuotaForClusterExceeded" => return RusotoError::Service(CreateReplicationGroupError::NodeQuotaForClusterExceededFault(parsed_error.message)),"NodeQuotaForCustomerExceeded" => return RusotoError::Service(CreateReplicationGroupError::NodeQuotaForCustomerExceededFault(parsed_error.message)),"ReplicationGroupAlreadyExists" => return RusotoError::Service(CreateReplicationGroupError::ReplicationGroupAlreadyExistsFault(parsed_error.message)),"TagQuotaPerResourceExceeded" => return RusotoError::Service(CreateReplicationGroupError::TagQuotaPerResourceExceeded(parsed_error.message)),_ => {}
+                                    "CacheClusterNotFound" => return RusotoError::service(CreateReplicationGroupError::CacheClusterNotFoundFault(parsed_error.message), &res),"CacheParameterGroupNotFound" => return RusotoError::service(CreateReplicationGroupError::CacheParameterGroupNotFoundFault(parsed_error.message), &res),"CacheSecurityGroupNotFound" => return RusotoError::service(CreateReplicationGroupError::CacheSecurityGroupNotFoundFault(parsed_error.message), &res),"CacheSubnetGroupNotFoundFault" => return RusotoError::service(CreateReplicationGroupError::CacheSubnetGroupNotFoundFault(parsed_error.message), &res),"ClusterQuotaForCustomerExceeded" => return RusotoError::service(CreateReplicationGroupError::ClusterQuotaForCustomerExceededFault(parsed_error.message), &res),"InsufficientCacheClusterCapacity" => return RusotoError::service(CreateReplicationGroupError::InsufficientCacheClusterCapacityFault(parsed_error.message), &res),"InvalidCacheClusterState" => return RusotoError::service(CreateReplicationGroupError::InvalidCacheClusterStateFault(parsed_error.message), &res),"InvalidParameterCombination" => return RusotoError::service(CreateReplicationGroupError::InvalidParameterCombination(parsed_error.message), &res),"InvalidParameterValue" => return RusotoError::service(CreateReplicationGroupError::InvalidParameterValue(parsed_error.message), &res),"InvalidVPCNetworkStateFault" => return RusotoError::service(CreateReplicationGroupError::InvalidVPCNetworkStateFault(parsed_error.message), &res),"NodeGroupsPerReplicationGroupQuotaExceeded" => return RusotoError::service(CreateReplicationGroupError::NodeGroupsPerReplicationGroupQuotaExceededFault(parsed_error.message), &res),"NodeQuotaForClusterExceeded" => return RusotoError::service(CreateReplicationGroupError::NodeQuotaForClusterExceededFault(parsed_error.message), &res),"NodeQuotaForCustomerExceeded" => return RusotoError::service(CreateReplicationGroupError::NodeQuotaForCustomerExceededFault(parsed_error.message), &res),"ReplicationGroupAlreadyExists" => return RusotoError::service(CreateReplicationGroupError::ReplicationGroupAlreadyExistsFault(parsed_error.message), &res),"TagQuotaPerResourceExceeded" => return RusotoError::service(CreateReplicationGroupError::TagQuotaPerResourceExceeded(parsed_error.message), &res),_ => return RusotoError::unhandled(CreateReplicationGroupError::Unhandled, &res),
                                 }
             }
         }
@@ -8691,12 +8796,14 @@ impl fmt::Display for CreateReplicationGroupError {
             CreateReplicationGroupError::TagQuotaPerResourceExceeded(ref cause) => {
                 write!(f, "{}", cause)
             }
+            CreateReplicationGroupError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateReplicationGroupError {}
 /// Errors returned by CreateSnapshot
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateSnapshotError {
     /// <p>The requested cluster ID does not refer to an existing cluster.</p>
     CacheClusterNotFoundFault(String),
@@ -8716,6 +8823,8 @@ pub enum CreateSnapshotError {
     SnapshotFeatureNotSupportedFault(String),
     /// <p>The request cannot be processed because it would exceed the maximum number of snapshots.</p>
     SnapshotQuotaExceededFault(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateSnapshotError {
@@ -8727,59 +8836,68 @@ impl CreateSnapshotError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "CacheClusterNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             CreateSnapshotError::CacheClusterNotFoundFault(parsed_error.message),
+                            &res,
                         )
                     }
                     "InvalidCacheClusterState" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             CreateSnapshotError::InvalidCacheClusterStateFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "InvalidParameterCombination" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             CreateSnapshotError::InvalidParameterCombination(parsed_error.message),
+                            &res,
                         )
                     }
                     "InvalidParameterValue" => {
-                        return RusotoError::Service(CreateSnapshotError::InvalidParameterValue(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            CreateSnapshotError::InvalidParameterValue(parsed_error.message),
+                            &res,
+                        )
                     }
                     "InvalidReplicationGroupState" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             CreateSnapshotError::InvalidReplicationGroupStateFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "ReplicationGroupNotFoundFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             CreateSnapshotError::ReplicationGroupNotFoundFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "SnapshotAlreadyExistsFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             CreateSnapshotError::SnapshotAlreadyExistsFault(parsed_error.message),
+                            &res,
                         )
                     }
                     "SnapshotFeatureNotSupportedFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             CreateSnapshotError::SnapshotFeatureNotSupportedFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "SnapshotQuotaExceededFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             CreateSnapshotError::SnapshotQuotaExceededFault(parsed_error.message),
+                            &res,
                         )
                     }
-                    _ => {}
+                    _ => return RusotoError::unhandled(CreateSnapshotError::Unhandled, &res),
                 }
             }
         }
@@ -8811,12 +8929,14 @@ impl fmt::Display for CreateSnapshotError {
                 write!(f, "{}", cause)
             }
             CreateSnapshotError::SnapshotQuotaExceededFault(ref cause) => write!(f, "{}", cause),
+            CreateSnapshotError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateSnapshotError {}
 /// Errors returned by DecreaseReplicaCount
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DecreaseReplicaCountError {
     /// <p>The request cannot be processed because it would exceed the allowed number of clusters per customer.</p>
     ClusterQuotaForCustomerExceededFault(String),
@@ -8842,6 +8962,8 @@ pub enum DecreaseReplicaCountError {
     ReplicationGroupNotFoundFault(String),
     /// <p>The specified service linked role (SLR) was not found.</p>
     ServiceLinkedRoleNotFoundFault(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DecreaseReplicaCountError {
@@ -8853,84 +8975,96 @@ impl DecreaseReplicaCountError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "ClusterQuotaForCustomerExceeded" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             DecreaseReplicaCountError::ClusterQuotaForCustomerExceededFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "InsufficientCacheClusterCapacity" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             DecreaseReplicaCountError::InsufficientCacheClusterCapacityFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "InvalidCacheClusterState" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             DecreaseReplicaCountError::InvalidCacheClusterStateFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "InvalidParameterCombination" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             DecreaseReplicaCountError::InvalidParameterCombination(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "InvalidParameterValue" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             DecreaseReplicaCountError::InvalidParameterValue(parsed_error.message),
+                            &res,
                         )
                     }
                     "InvalidReplicationGroupState" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             DecreaseReplicaCountError::InvalidReplicationGroupStateFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "InvalidVPCNetworkStateFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             DecreaseReplicaCountError::InvalidVPCNetworkStateFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "NoOperationFault" => {
-                        return RusotoError::Service(DecreaseReplicaCountError::NoOperationFault(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            DecreaseReplicaCountError::NoOperationFault(parsed_error.message),
+                            &res,
+                        )
                     }
-                    "NodeGroupsPerReplicationGroupQuotaExceeded" => return RusotoError::Service(
+                    "NodeGroupsPerReplicationGroupQuotaExceeded" => return RusotoError::service(
                         DecreaseReplicaCountError::NodeGroupsPerReplicationGroupQuotaExceededFault(
                             parsed_error.message,
                         ),
+                        &res,
                     ),
                     "NodeQuotaForCustomerExceeded" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             DecreaseReplicaCountError::NodeQuotaForCustomerExceededFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "ReplicationGroupNotFoundFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             DecreaseReplicaCountError::ReplicationGroupNotFoundFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "ServiceLinkedRoleNotFoundFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             DecreaseReplicaCountError::ServiceLinkedRoleNotFoundFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
-                    _ => {}
+                    _ => return RusotoError::unhandled(DecreaseReplicaCountError::Unhandled, &res),
                 }
             }
         }
@@ -8981,12 +9115,14 @@ impl fmt::Display for DecreaseReplicaCountError {
             DecreaseReplicaCountError::ServiceLinkedRoleNotFoundFault(ref cause) => {
                 write!(f, "{}", cause)
             }
+            DecreaseReplicaCountError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DecreaseReplicaCountError {}
 /// Errors returned by DeleteCacheCluster
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteCacheClusterError {
     /// <p>The requested cluster ID does not refer to an existing cluster.</p>
     CacheClusterNotFoundFault(String),
@@ -9002,6 +9138,8 @@ pub enum DeleteCacheClusterError {
     SnapshotFeatureNotSupportedFault(String),
     /// <p>The request cannot be processed because it would exceed the maximum number of snapshots.</p>
     SnapshotQuotaExceededFault(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteCacheClusterError {
@@ -9013,53 +9151,60 @@ impl DeleteCacheClusterError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "CacheClusterNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             DeleteCacheClusterError::CacheClusterNotFoundFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "InvalidCacheClusterState" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             DeleteCacheClusterError::InvalidCacheClusterStateFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "InvalidParameterCombination" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             DeleteCacheClusterError::InvalidParameterCombination(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "InvalidParameterValue" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             DeleteCacheClusterError::InvalidParameterValue(parsed_error.message),
+                            &res,
                         )
                     }
                     "SnapshotAlreadyExistsFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             DeleteCacheClusterError::SnapshotAlreadyExistsFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "SnapshotFeatureNotSupportedFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             DeleteCacheClusterError::SnapshotFeatureNotSupportedFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "SnapshotQuotaExceededFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             DeleteCacheClusterError::SnapshotQuotaExceededFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
-                    _ => {}
+                    _ => return RusotoError::unhandled(DeleteCacheClusterError::Unhandled, &res),
                 }
             }
         }
@@ -9095,12 +9240,14 @@ impl fmt::Display for DeleteCacheClusterError {
             DeleteCacheClusterError::SnapshotQuotaExceededFault(ref cause) => {
                 write!(f, "{}", cause)
             }
+            DeleteCacheClusterError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteCacheClusterError {}
 /// Errors returned by DeleteCacheParameterGroup
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteCacheParameterGroupError {
     /// <p>The requested cache parameter group name does not refer to an existing cache parameter group.</p>
     CacheParameterGroupNotFoundFault(String),
@@ -9110,6 +9257,8 @@ pub enum DeleteCacheParameterGroupError {
     InvalidParameterCombination(String),
     /// <p>The value for a parameter is invalid.</p>
     InvalidParameterValue(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteCacheParameterGroupError {
@@ -9121,34 +9270,43 @@ impl DeleteCacheParameterGroupError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "CacheParameterGroupNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             DeleteCacheParameterGroupError::CacheParameterGroupNotFoundFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "InvalidCacheParameterGroupState" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             DeleteCacheParameterGroupError::InvalidCacheParameterGroupStateFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "InvalidParameterCombination" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             DeleteCacheParameterGroupError::InvalidParameterCombination(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "InvalidParameterValue" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             DeleteCacheParameterGroupError::InvalidParameterValue(
                                 parsed_error.message,
                             ),
+                            &res,
+                        )
+                    }
+                    _ => {
+                        return RusotoError::unhandled(
+                            DeleteCacheParameterGroupError::Unhandled,
+                            &res,
                         )
                     }
-                    _ => {}
                 }
             }
         }
@@ -9179,12 +9337,14 @@ impl fmt::Display for DeleteCacheParameterGroupError {
             DeleteCacheParameterGroupError::InvalidParameterValue(ref cause) => {
                 write!(f, "{}", cause)
             }
+            DeleteCacheParameterGroupError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteCacheParameterGroupError {}
 /// Errors returned by DeleteCacheSecurityGroup
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteCacheSecurityGroupError {
     /// <p>The requested cache security group name does not refer to an existing cache security group.</p>
     CacheSecurityGroupNotFoundFault(String),
@@ -9194,6 +9354,8 @@ pub enum DeleteCacheSecurityGroupError {
     InvalidParameterCombination(String),
     /// <p>The value for a parameter is invalid.</p>
     InvalidParameterValue(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteCacheSecurityGroupError {
@@ -9205,34 +9367,43 @@ impl DeleteCacheSecurityGroupError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "CacheSecurityGroupNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             DeleteCacheSecurityGroupError::CacheSecurityGroupNotFoundFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "InvalidCacheSecurityGroupState" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             DeleteCacheSecurityGroupError::InvalidCacheSecurityGroupStateFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "InvalidParameterCombination" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             DeleteCacheSecurityGroupError::InvalidParameterCombination(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "InvalidParameterValue" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             DeleteCacheSecurityGroupError::InvalidParameterValue(
                                 parsed_error.message,
                             ),
+                            &res,
+                        )
+                    }
+                    _ => {
+                        return RusotoError::unhandled(
+                            DeleteCacheSecurityGroupError::Unhandled,
+                            &res,
                         )
                     }
-                    _ => {}
                 }
             }
         }
@@ -9263,17 +9434,21 @@ impl fmt::Display for DeleteCacheSecurityGroupError {
             DeleteCacheSecurityGroupError::InvalidParameterValue(ref cause) => {
                 write!(f, "{}", cause)
             }
+            DeleteCacheSecurityGroupError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteCacheSecurityGroupError {}
 /// Errors returned by DeleteCacheSubnetGroup
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteCacheSubnetGroupError {
     /// <p>The requested cache subnet group is currently in use.</p>
     CacheSubnetGroupInUse(String),
     /// <p>The requested cache subnet group name does not refer to an existing cache subnet group.</p>
     CacheSubnetGroupNotFoundFault(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteCacheSubnetGroupError {
@@ -9285,20 +9460,24 @@ impl DeleteCacheSubnetGroupError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "CacheSubnetGroupInUse" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             DeleteCacheSubnetGroupError::CacheSubnetGroupInUse(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "CacheSubnetGroupNotFoundFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             DeleteCacheSubnetGroupError::CacheSubnetGroupNotFoundFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
-                    _ => {}
+                    _ => {
+                        return RusotoError::unhandled(DeleteCacheSubnetGroupError::Unhandled, &res)
+                    }
                 }
             }
         }
@@ -9321,12 +9500,14 @@ impl fmt::Display for DeleteCacheSubnetGroupError {
             DeleteCacheSubnetGroupError::CacheSubnetGroupNotFoundFault(ref cause) => {
                 write!(f, "{}", cause)
             }
+            DeleteCacheSubnetGroupError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteCacheSubnetGroupError {}
 /// Errors returned by DeleteReplicationGroup
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteReplicationGroupError {
     /// <p>Two or more incompatible parameters were specified.</p>
     InvalidParameterCombination(String),
@@ -9342,6 +9523,8 @@ pub enum DeleteReplicationGroupError {
     SnapshotFeatureNotSupportedFault(String),
     /// <p>The request cannot be processed because it would exceed the maximum number of snapshots.</p>
     SnapshotQuotaExceededFault(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteReplicationGroupError {
@@ -9353,55 +9536,64 @@ impl DeleteReplicationGroupError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InvalidParameterCombination" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             DeleteReplicationGroupError::InvalidParameterCombination(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "InvalidParameterValue" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             DeleteReplicationGroupError::InvalidParameterValue(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "InvalidReplicationGroupState" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             DeleteReplicationGroupError::InvalidReplicationGroupStateFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "ReplicationGroupNotFoundFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             DeleteReplicationGroupError::ReplicationGroupNotFoundFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "SnapshotAlreadyExistsFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             DeleteReplicationGroupError::SnapshotAlreadyExistsFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "SnapshotFeatureNotSupportedFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             DeleteReplicationGroupError::SnapshotFeatureNotSupportedFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "SnapshotQuotaExceededFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             DeleteReplicationGroupError::SnapshotQuotaExceededFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
-                    _ => {}
+                    _ => {
+                        return RusotoError::unhandled(DeleteReplicationGroupError::Unhandled, &res)
+                    }
                 }
             }
         }
@@ -9439,12 +9631,14 @@ impl fmt::Display for DeleteReplicationGroupError {
             DeleteReplicationGroupError::SnapshotQuotaExceededFault(ref cause) => {
                 write!(f, "{}", cause)
             }
+            DeleteReplicationGroupError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteReplicationGroupError {}
 /// Errors returned by DeleteSnapshot
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteSnapshotError {
     /// <p>Two or more incompatible parameters were specified.</p>
     InvalidParameterCombination(String),
@@ -9454,6 +9648,8 @@ pub enum DeleteSnapshotError {
     InvalidSnapshotStateFault(String),
     /// <p>The requested snapshot name does not refer to an existing snapshot.</p>
     SnapshotNotFoundFault(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteSnapshotError {
@@ -9465,26 +9661,30 @@ impl DeleteSnapshotError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InvalidParameterCombination" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             DeleteSnapshotError::InvalidParameterCombination(parsed_error.message),
+                            &res,
                         )
                     }
                     "InvalidParameterValue" => {
-                        return RusotoError::Service(DeleteSnapshotError::InvalidParameterValue(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            DeleteSnapshotError::InvalidParameterValue(parsed_error.message),
+                            &res,
+                        )
                     }
                     "InvalidSnapshotState" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             DeleteSnapshotError::InvalidSnapshotStateFault(parsed_error.message),
+                            &res,
                         )
                     }
                     "SnapshotNotFoundFault" => {
-                        return RusotoError::Service(DeleteSnapshotError::SnapshotNotFoundFault(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            DeleteSnapshotError::SnapshotNotFoundFault(parsed_error.message),
+                            &res,
+                        )
                     }
-                    _ => {}
+                    _ => return RusotoError::unhandled(DeleteSnapshotError::Unhandled, &res),
                 }
             }
         }
@@ -9507,12 +9707,14 @@ impl fmt::Display for DeleteSnapshotError {
             DeleteSnapshotError::InvalidParameterValue(ref cause) => write!(f, "{}", cause),
             DeleteSnapshotError::InvalidSnapshotStateFault(ref cause) => write!(f, "{}", cause),
             DeleteSnapshotError::SnapshotNotFoundFault(ref cause) => write!(f, "{}", cause),
+            DeleteSnapshotError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteSnapshotError {}
 /// Errors returned by DescribeCacheClusters
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeCacheClustersError {
     /// <p>The requested cluster ID does not refer to an existing cluster.</p>
     CacheClusterNotFoundFault(String),
@@ -9520,6 +9722,8 @@ pub enum DescribeCacheClustersError {
     InvalidParameterCombination(String),
     /// <p>The value for a parameter is invalid.</p>
     InvalidParameterValue(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeCacheClustersError {
@@ -9531,25 +9735,30 @@ impl DescribeCacheClustersError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "CacheClusterNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             DescribeCacheClustersError::CacheClusterNotFoundFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "InvalidParameterCombination" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             DescribeCacheClustersError::InvalidParameterCombination(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "InvalidParameterValue" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             DescribeCacheClustersError::InvalidParameterValue(parsed_error.message),
+                            &res,
                         )
                     }
-                    _ => {}
+                    _ => {
+                        return RusotoError::unhandled(DescribeCacheClustersError::Unhandled, &res)
+                    }
                 }
             }
         }
@@ -9575,13 +9784,18 @@ impl fmt::Display for DescribeCacheClustersError {
                 write!(f, "{}", cause)
             }
             DescribeCacheClustersError::InvalidParameterValue(ref cause) => write!(f, "{}", cause),
+            DescribeCacheClustersError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeCacheClustersError {}
 /// Errors returned by DescribeCacheEngineVersions
 #[derive(Debug, PartialEq)]
-pub enum DescribeCacheEngineVersionsError {}
+#[non_exhaustive]
+pub enum DescribeCacheEngineVersionsError {
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
+}
 
 impl DescribeCacheEngineVersionsError {
     pub fn from_response(
@@ -9591,10 +9805,8 @@ impl DescribeCacheEngineVersionsError {
             let reader = EventReader::new(res.body.as_ref());
             let mut stack = XmlResponse::new(reader.into_iter().peekable());
             find_start_element(&mut stack);
-            if let Ok(parsed_error) = Self::deserialize(&mut stack) {
-                match &parsed_error.code[..] {
-                    _ => {}
-                }
+            if Self::deserialize(&mut stack).is_ok() {
+                return RusotoError::unhandled(DescribeCacheEngineVersionsError::Unhandled, &res);
             }
         }
         RusotoError::Unknown(res)
@@ -9611,12 +9823,15 @@ impl DescribeCacheEngineVersionsError {
 impl fmt::Display for DescribeCacheEngineVersionsError {
     #[allow(unused_variables)]
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        match *self {}
+        match *self {
+            DescribeCacheEngineVersionsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
+        }
     }
 }
 impl Error for DescribeCacheEngineVersionsError {}
 /// Errors returned by DescribeCacheParameterGroups
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeCacheParameterGroupsError {
     /// <p>The requested cache parameter group name does not refer to an existing cache parameter group.</p>
     CacheParameterGroupNotFoundFault(String),
@@ -9624,6 +9839,8 @@ pub enum DescribeCacheParameterGroupsError {
     InvalidParameterCombination(String),
     /// <p>The value for a parameter is invalid.</p>
     InvalidParameterValue(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeCacheParameterGroupsError {
@@ -9637,27 +9854,35 @@ impl DescribeCacheParameterGroupsError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "CacheParameterGroupNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             DescribeCacheParameterGroupsError::CacheParameterGroupNotFoundFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "InvalidParameterCombination" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             DescribeCacheParameterGroupsError::InvalidParameterCombination(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "InvalidParameterValue" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             DescribeCacheParameterGroupsError::InvalidParameterValue(
                                 parsed_error.message,
                             ),
+                            &res,
+                        )
+                    }
+                    _ => {
+                        return RusotoError::unhandled(
+                            DescribeCacheParameterGroupsError::Unhandled,
+                            &res,
                         )
                     }
-                    _ => {}
                 }
             }
         }
@@ -9685,12 +9910,14 @@ impl fmt::Display for DescribeCacheParameterGroupsError {
             DescribeCacheParameterGroupsError::InvalidParameterValue(ref cause) => {
                 write!(f, "{}", cause)
             }
+            DescribeCacheParameterGroupsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeCacheParameterGroupsError {}
 /// Errors returned by DescribeCacheParameters
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeCacheParametersError {
     /// <p>The requested cache parameter group name does not refer to an existing cache parameter group.</p>
     CacheParameterGroupNotFoundFault(String),
@@ -9698,6 +9925,8 @@ pub enum DescribeCacheParametersError {
     InvalidParameterCombination(String),
     /// <p>The value for a parameter is invalid.</p>
     InvalidParameterValue(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeCacheParametersError {
@@ -9709,27 +9938,35 @@ impl DescribeCacheParametersError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "CacheParameterGroupNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             DescribeCacheParametersError::CacheParameterGroupNotFoundFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "InvalidParameterCombination" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             DescribeCacheParametersError::InvalidParameterCombination(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "InvalidParameterValue" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             DescribeCacheParametersError::InvalidParameterValue(
                                 parsed_error.message,
                             ),
+                            &res,
+                        )
+                    }
+                    _ => {
+                        return RusotoError::unhandled(
+                            DescribeCacheParametersError::Unhandled,
+                            &res,
                         )
                     }
-                    _ => {}
                 }
             }
         }
@@ -9757,12 +9994,14 @@ impl fmt::Display for DescribeCacheParametersError {
             DescribeCacheParametersError::InvalidParameterValue(ref cause) => {
                 write!(f, "{}", cause)
             }
+            DescribeCacheParametersError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeCacheParametersError {}
 /// Errors returned by DescribeCacheSecurityGroups
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeCacheSecurityGroupsError {
     /// <p>The requested cache security group name does not refer to an existing cache security group.</p>
     CacheSecurityGroupNotFoundFault(String),
@@ -9770,6 +10009,8 @@ pub enum DescribeCacheSecurityGroupsError {
     InvalidParameterCombination(String),
     /// <p>The value for a parameter is invalid.</p>
     InvalidParameterValue(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeCacheSecurityGroupsError {
@@ -9783,27 +10024,35 @@ impl DescribeCacheSecurityGroupsError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "CacheSecurityGroupNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             DescribeCacheSecurityGroupsError::CacheSecurityGroupNotFoundFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "InvalidParameterCombination" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             DescribeCacheSecurityGroupsError::InvalidParameterCombination(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "InvalidParameterValue" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             DescribeCacheSecurityGroupsError::InvalidParameterValue(
                                 parsed_error.message,
                             ),
+                            &res,
+                        )
+                    }
+                    _ => {
+                        return RusotoError::unhandled(
+                            DescribeCacheSecurityGroupsError::Unhandled,
+                            &res,
                         )
                     }
-                    _ => {}
                 }
             }
         }
@@ -9831,15 +10080,19 @@ impl fmt::Display for DescribeCacheSecurityGroupsError {
             DescribeCacheSecurityGroupsError::InvalidParameterValue(ref cause) => {
                 write!(f, "{}", cause)
             }
+            DescribeCacheSecurityGroupsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeCacheSecurityGroupsError {}
 /// Errors returned by DescribeCacheSubnetGroups
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeCacheSubnetGroupsError {
     /// <p>The requested cache subnet group name does not refer to an existing cache subnet group.</p>
     CacheSubnetGroupNotFoundFault(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeCacheSubnetGroupsError {
@@ -9851,13 +10104,19 @@ impl DescribeCacheSubnetGroupsError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "CacheSubnetGroupNotFoundFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             DescribeCacheSubnetGroupsError::CacheSubnetGroupNotFoundFault(
                                 parsed_error.message,
                             ),
+                            &res,
+                        )
+                    }
+                    _ => {
+                        return RusotoError::unhandled(
+                            DescribeCacheSubnetGroupsError::Unhandled,
+                            &res,
                         )
                     }
-                    _ => {}
                 }
             }
         }
@@ -9879,17 +10138,21 @@ impl fmt::Display for DescribeCacheSubnetGroupsError {
             DescribeCacheSubnetGroupsError::CacheSubnetGroupNotFoundFault(ref cause) => {
                 write!(f, "{}", cause)
             }
+            DescribeCacheSubnetGroupsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeCacheSubnetGroupsError {}
 /// Errors returned by DescribeEngineDefaultParameters
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeEngineDefaultParametersError {
     /// <p>Two or more incompatible parameters were specified.</p>
     InvalidParameterCombination(String),
     /// <p>The value for a parameter is invalid.</p>
     InvalidParameterValue(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeEngineDefaultParametersError {
@@ -9903,20 +10166,27 @@ impl DescribeEngineDefaultParametersError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InvalidParameterCombination" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             DescribeEngineDefaultParametersError::InvalidParameterCombination(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "InvalidParameterValue" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             DescribeEngineDefaultParametersError::InvalidParameterValue(
                                 parsed_error.message,
                             ),
+                            &res,
+                        )
+                    }
+                    _ => {
+                        return RusotoError::unhandled(
+                            DescribeEngineDefaultParametersError::Unhandled,
+                            &res,
                         )
                     }
-                    _ => {}
                 }
             }
         }
@@ -9941,17 +10211,23 @@ impl fmt::Display for DescribeEngineDefaultParametersError {
             DescribeEngineDefaultParametersError::InvalidParameterValue(ref cause) => {
                 write!(f, "{}", cause)
             }
+            DescribeEngineDefaultParametersError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for DescribeEngineDefaultParametersError {}
 /// Errors returned by DescribeEvents
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeEventsError {
     /// <p>Two or more incompatible parameters were specified.</p>
     InvalidParameterCombination(String),
     /// <p>The value for a parameter is invalid.</p>
     InvalidParameterValue(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeEventsError {
@@ -9963,16 +10239,18 @@ impl DescribeEventsError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InvalidParameterCombination" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             DescribeEventsError::InvalidParameterCombination(parsed_error.message),
+                            &res,
                         )
                     }
                     "InvalidParameterValue" => {
-                        return RusotoError::Service(DescribeEventsError::InvalidParameterValue(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            DescribeEventsError::InvalidParameterValue(parsed_error.message),
+                            &res,
+                        )
                     }
-                    _ => {}
+                    _ => return RusotoError::unhandled(DescribeEventsError::Unhandled, &res),
                 }
             }
         }
@@ -9993,12 +10271,14 @@ impl fmt::Display for DescribeEventsError {
         match *self {
             DescribeEventsError::InvalidParameterCombination(ref cause) => write!(f, "{}", cause),
             DescribeEventsError::InvalidParameterValue(ref cause) => write!(f, "{}", cause),
+            DescribeEventsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeEventsError {}
 /// Errors returned by DescribeReplicationGroups
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeReplicationGroupsError {
     /// <p>Two or more incompatible parameters were specified.</p>
     InvalidParameterCombination(String),
@@ -10006,6 +10286,8 @@ pub enum DescribeReplicationGroupsError {
     InvalidParameterValue(String),
     /// <p>The specified replication group does not exist.</p>
     ReplicationGroupNotFoundFault(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeReplicationGroupsError {
@@ -10017,27 +10299,35 @@ impl DescribeReplicationGroupsError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InvalidParameterCombination" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             DescribeReplicationGroupsError::InvalidParameterCombination(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "InvalidParameterValue" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             DescribeReplicationGroupsError::InvalidParameterValue(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "ReplicationGroupNotFoundFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             DescribeReplicationGroupsError::ReplicationGroupNotFoundFault(
                                 parsed_error.message,
                             ),
+                            &res,
+                        )
+                    }
+                    _ => {
+                        return RusotoError::unhandled(
+                            DescribeReplicationGroupsError::Unhandled,
+                            &res,
                         )
                     }
-                    _ => {}
                 }
             }
         }
@@ -10065,12 +10355,14 @@ impl fmt::Display for DescribeReplicationGroupsError {
             DescribeReplicationGroupsError::ReplicationGroupNotFoundFault(ref cause) => {
                 write!(f, "{}", cause)
             }
+            DescribeReplicationGroupsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeReplicationGroupsError {}
 /// Errors returned by DescribeReservedCacheNodes
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeReservedCacheNodesError {
     /// <p>Two or more incompatible parameters were specified.</p>
     InvalidParameterCombination(String),
@@ -10078,6 +10370,8 @@ pub enum DescribeReservedCacheNodesError {
     InvalidParameterValue(String),
     /// <p>The requested reserved cache node was not found.</p>
     ReservedCacheNodeNotFoundFault(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeReservedCacheNodesError {
@@ -10091,27 +10385,35 @@ impl DescribeReservedCacheNodesError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InvalidParameterCombination" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             DescribeReservedCacheNodesError::InvalidParameterCombination(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "InvalidParameterValue" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             DescribeReservedCacheNodesError::InvalidParameterValue(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "ReservedCacheNodeNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             DescribeReservedCacheNodesError::ReservedCacheNodeNotFoundFault(
                                 parsed_error.message,
                             ),
+                            &res,
+                        )
+                    }
+                    _ => {
+                        return RusotoError::unhandled(
+                            DescribeReservedCacheNodesError::Unhandled,
+                            &res,
                         )
                     }
-                    _ => {}
                 }
             }
         }
@@ -10139,12 +10441,14 @@ impl fmt::Display for DescribeReservedCacheNodesError {
             DescribeReservedCacheNodesError::ReservedCacheNodeNotFoundFault(ref cause) => {
                 write!(f, "{}", cause)
             }
+            DescribeReservedCacheNodesError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeReservedCacheNodesError {}
 /// Errors returned by DescribeReservedCacheNodesOfferings
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeReservedCacheNodesOfferingsError {
     /// <p>Two or more incompatible parameters were specified.</p>
     InvalidParameterCombination(String),
@@ -10152,6 +10456,8 @@ pub enum DescribeReservedCacheNodesOfferingsError {
     InvalidParameterValue(String),
     /// <p>The requested cache node offering does not exist.</p>
     ReservedCacheNodesOfferingNotFoundFault(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeReservedCacheNodesOfferingsError {
@@ -10164,7 +10470,7 @@ impl DescribeReservedCacheNodesOfferingsError {
             find_start_element(&mut stack);
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
-                                    "InvalidParameterCombination" => return RusotoError::Service(DescribeReservedCacheNodesOfferingsError::InvalidParameterCombination(parsed_error.message)),"InvalidParameterValue" => return RusotoError::Service(DescribeReservedCacheNodesOfferingsError::InvalidParameterValue(parsed_error.message)),"ReservedCacheNodesOfferingNotFound" => return RusotoError::Service(DescribeReservedCacheNodesOfferingsError::ReservedCacheNodesOfferingNotFoundFault(parsed_error.message)),_ => {}
+                                    "InvalidParameterCombination" => return RusotoError::service(DescribeReservedCacheNodesOfferingsError::InvalidParameterCombination(parsed_error.message), &res),"InvalidParameterValue" => return RusotoError::service(DescribeReservedCacheNodesOfferingsError::InvalidParameterValue(parsed_error.message), &res),"ReservedCacheNodesOfferingNotFound" => return RusotoError::service(DescribeReservedCacheNodesOfferingsError::ReservedCacheNodesOfferingNotFoundFault(parsed_error.message), &res),_ => return RusotoError::unhandled(DescribeReservedCacheNodesOfferingsError::Unhandled, &res),
                                 }
             }
         }
@@ -10192,12 +10498,16 @@ impl fmt::Display for DescribeReservedCacheNodesOfferingsError {
             DescribeReservedCacheNodesOfferingsError::ReservedCacheNodesOfferingNotFoundFault(
                 ref cause,
             ) => write!(f, "{}", cause),
+            DescribeReservedCacheNodesOfferingsError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for DescribeReservedCacheNodesOfferingsError {}
 /// Errors returned by DescribeServiceUpdates
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeServiceUpdatesError {
     /// <p>Two or more incompatible parameters were specified.</p>
     InvalidParameterCombination(String),
@@ -10205,6 +10515,8 @@ pub enum DescribeServiceUpdatesError {
     InvalidParameterValue(String),
     /// <p>The service update doesn't exist</p>
     ServiceUpdateNotFoundFault(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeServiceUpdatesError {
@@ -10216,27 +10528,32 @@ impl DescribeServiceUpdatesError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InvalidParameterCombination" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             DescribeServiceUpdatesError::InvalidParameterCombination(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "InvalidParameterValue" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             DescribeServiceUpdatesError::InvalidParameterValue(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "ServiceUpdateNotFoundFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             DescribeServiceUpdatesError::ServiceUpdateNotFoundFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
-                    _ => {}
+                    _ => {
+                        return RusotoError::unhandled(DescribeServiceUpdatesError::Unhandled, &res)
+                    }
                 }
             }
         }
@@ -10262,12 +10579,14 @@ impl fmt::Display for DescribeServiceUpdatesError {
             DescribeServiceUpdatesError::ServiceUpdateNotFoundFault(ref cause) => {
                 write!(f, "{}", cause)
             }
+            DescribeServiceUpdatesError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeServiceUpdatesError {}
 /// Errors returned by DescribeSnapshots
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeSnapshotsError {
     /// <p>The requested cluster ID does not refer to an existing cluster.</p>
     CacheClusterNotFoundFault(String),
@@ -10277,6 +10596,8 @@ pub enum DescribeSnapshotsError {
     InvalidParameterValue(String),
     /// <p>The requested snapshot name does not refer to an existing snapshot.</p>
     SnapshotNotFoundFault(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeSnapshotsError {
@@ -10288,28 +10609,32 @@ impl DescribeSnapshotsError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "CacheClusterNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             DescribeSnapshotsError::CacheClusterNotFoundFault(parsed_error.message),
+                            &res,
                         )
                     }
                     "InvalidParameterCombination" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             DescribeSnapshotsError::InvalidParameterCombination(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "InvalidParameterValue" => {
-                        return RusotoError::Service(DescribeSnapshotsError::InvalidParameterValue(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            DescribeSnapshotsError::InvalidParameterValue(parsed_error.message),
+                            &res,
+                        )
                     }
                     "SnapshotNotFoundFault" => {
-                        return RusotoError::Service(DescribeSnapshotsError::SnapshotNotFoundFault(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            DescribeSnapshotsError::SnapshotNotFoundFault(parsed_error.message),
+                            &res,
+                        )
                     }
-                    _ => {}
+                    _ => return RusotoError::unhandled(DescribeSnapshotsError::Unhandled, &res),
                 }
             }
         }
@@ -10334,17 +10659,21 @@ impl fmt::Display for DescribeSnapshotsError {
             }
             DescribeSnapshotsError::InvalidParameterValue(ref cause) => write!(f, "{}", cause),
             DescribeSnapshotsError::SnapshotNotFoundFault(ref cause) => write!(f, "{}", cause),
+            DescribeSnapshotsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeSnapshotsError {}
 /// Errors returned by DescribeUpdateActions
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeUpdateActionsError {
     /// <p>Two or more incompatible parameters were specified.</p>
     InvalidParameterCombination(String),
     /// <p>The value for a parameter is invalid.</p>
     InvalidParameterValue(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeUpdateActionsError {
@@ -10356,18 +10685,22 @@ impl DescribeUpdateActionsError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InvalidParameterCombination" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             DescribeUpdateActionsError::InvalidParameterCombination(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "InvalidParameterValue" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             DescribeUpdateActionsError::InvalidParameterValue(parsed_error.message),
+                            &res,
                         )
                     }
-                    _ => {}
+                    _ => {
+                        return RusotoError::unhandled(DescribeUpdateActionsError::Unhandled, &res)
+                    }
                 }
             }
         }
@@ -10390,12 +10723,14 @@ impl fmt::Display for DescribeUpdateActionsError {
                 write!(f, "{}", cause)
             }
             DescribeUpdateActionsError::InvalidParameterValue(ref cause) => write!(f, "{}", cause),
+            DescribeUpdateActionsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeUpdateActionsError {}
 /// Errors returned by IncreaseReplicaCount
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum IncreaseReplicaCountError {
     /// <p>The request cannot be processed because it would exceed the allowed number of clusters per customer.</p>
     ClusterQuotaForCustomerExceededFault(String),
@@ -10421,6 +10756,8 @@ pub enum IncreaseReplicaCountError {
     NodeQuotaForCustomerExceededFault(String),
     /// <p>The specified replication group does not exist.</p>
     ReplicationGroupNotFoundFault(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl IncreaseReplicaCountError {
@@ -10432,82 +10769,94 @@ impl IncreaseReplicaCountError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "ClusterQuotaForCustomerExceeded" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             IncreaseReplicaCountError::ClusterQuotaForCustomerExceededFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "InsufficientCacheClusterCapacity" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             IncreaseReplicaCountError::InsufficientCacheClusterCapacityFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "InvalidCacheClusterState" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             IncreaseReplicaCountError::InvalidCacheClusterStateFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "InvalidKMSKeyFault" => {
-                        return RusotoError::Service(IncreaseReplicaCountError::InvalidKMSKeyFault(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            IncreaseReplicaCountError::InvalidKMSKeyFault(parsed_error.message),
+                            &res,
+                        )
                     }
                     "InvalidParameterCombination" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             IncreaseReplicaCountError::InvalidParameterCombination(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "InvalidParameterValue" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             IncreaseReplicaCountError::InvalidParameterValue(parsed_error.message),
+                            &res,
                         )
                     }
                     "InvalidReplicationGroupState" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             IncreaseReplicaCountError::InvalidReplicationGroupStateFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "InvalidVPCNetworkStateFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             IncreaseReplicaCountError::InvalidVPCNetworkStateFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "NoOperationFault" => {
-                        return RusotoError::Service(IncreaseReplicaCountError::NoOperationFault(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            IncreaseReplicaCountError::NoOperationFault(parsed_error.message),
+                            &res,
+                        )
                     }
-                    "NodeGroupsPerReplicationGroupQuotaExceeded" => return RusotoError::Service(
+                    "NodeGroupsPerReplicationGroupQuotaExceeded" => return RusotoError::service(
                         IncreaseReplicaCountError::NodeGroupsPerReplicationGroupQuotaExceededFault(
                             parsed_error.message,
                         ),
+                        &res,
                     ),
                     "NodeQuotaForCustomerExceeded" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             IncreaseReplicaCountError::NodeQuotaForCustomerExceededFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "ReplicationGroupNotFoundFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             IncreaseReplicaCountError::ReplicationGroupNotFoundFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
-                    _ => {}
+                    _ => return RusotoError::unhandled(IncreaseReplicaCountError::Unhandled, &res),
                 }
             }
         }
@@ -10556,12 +10905,14 @@ impl fmt::Display for IncreaseReplicaCountError {
             IncreaseReplicaCountError::ReplicationGroupNotFoundFault(ref cause) => {
                 write!(f, "{}", cause)
             }
+            IncreaseReplicaCountError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for IncreaseReplicaCountError {}
 /// Errors returned by ListAllowedNodeTypeModifications
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListAllowedNodeTypeModificationsError {
     /// <p>The requested cluster ID does not refer to an existing cluster.</p>
     CacheClusterNotFoundFault(String),
@@ -10571,6 +10922,8 @@ pub enum ListAllowedNodeTypeModificationsError {
     InvalidParameterValue(String),
     /// <p>The specified replication group does not exist.</p>
     ReplicationGroupNotFoundFault(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListAllowedNodeTypeModificationsError {
@@ -10584,34 +10937,43 @@ impl ListAllowedNodeTypeModificationsError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "CacheClusterNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             ListAllowedNodeTypeModificationsError::CacheClusterNotFoundFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "InvalidParameterCombination" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             ListAllowedNodeTypeModificationsError::InvalidParameterCombination(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "InvalidParameterValue" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             ListAllowedNodeTypeModificationsError::InvalidParameterValue(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "ReplicationGroupNotFoundFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             ListAllowedNodeTypeModificationsError::ReplicationGroupNotFoundFault(
                                 parsed_error.message,
                             ),
+                            &res,
+                        )
+                    }
+                    _ => {
+                        return RusotoError::unhandled(
+                            ListAllowedNodeTypeModificationsError::Unhandled,
+                            &res,
                         )
                     }
-                    _ => {}
                 }
             }
         }
@@ -10642,12 +11004,16 @@ impl fmt::Display for ListAllowedNodeTypeModificationsError {
             ListAllowedNodeTypeModificationsError::ReplicationGroupNotFoundFault(ref cause) => {
                 write!(f, "{}", cause)
             }
+            ListAllowedNodeTypeModificationsError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for ListAllowedNodeTypeModificationsError {}
 /// Errors returned by ListTagsForResource
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListTagsForResourceError {
     /// <p>The requested cluster ID does not refer to an existing cluster.</p>
     CacheClusterNotFoundFault(String),
@@ -10655,6 +11021,8 @@ pub enum ListTagsForResourceError {
     InvalidARNFault(String),
     /// <p>The requested snapshot name does not refer to an existing snapshot.</p>
     SnapshotNotFoundFault(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListTagsForResourceError {
@@ -10666,23 +11034,26 @@ impl ListTagsForResourceError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "CacheClusterNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             ListTagsForResourceError::CacheClusterNotFoundFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "InvalidARN" => {
-                        return RusotoError::Service(ListTagsForResourceError::InvalidARNFault(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            ListTagsForResourceError::InvalidARNFault(parsed_error.message),
+                            &res,
+                        )
                     }
                     "SnapshotNotFoundFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             ListTagsForResourceError::SnapshotNotFoundFault(parsed_error.message),
+                            &res,
                         )
                     }
-                    _ => {}
+                    _ => return RusotoError::unhandled(ListTagsForResourceError::Unhandled, &res),
                 }
             }
         }
@@ -10706,12 +11077,14 @@ impl fmt::Display for ListTagsForResourceError {
             }
             ListTagsForResourceError::InvalidARNFault(ref cause) => write!(f, "{}", cause),
             ListTagsForResourceError::SnapshotNotFoundFault(ref cause) => write!(f, "{}", cause),
+            ListTagsForResourceError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListTagsForResourceError {}
 /// Errors returned by ModifyCacheCluster
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ModifyCacheClusterError {
     /// <p>The requested cluster ID does not refer to an existing cluster.</p>
     CacheClusterNotFoundFault(String),
@@ -10735,6 +11108,8 @@ pub enum ModifyCacheClusterError {
     NodeQuotaForClusterExceededFault(String),
     /// <p>The request cannot be processed because it would exceed the allowed number of cache nodes per customer.</p>
     NodeQuotaForCustomerExceededFault(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ModifyCacheClusterError {
@@ -10746,81 +11121,92 @@ impl ModifyCacheClusterError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "CacheClusterNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             ModifyCacheClusterError::CacheClusterNotFoundFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "CacheParameterGroupNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             ModifyCacheClusterError::CacheParameterGroupNotFoundFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "CacheSecurityGroupNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             ModifyCacheClusterError::CacheSecurityGroupNotFoundFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "InsufficientCacheClusterCapacity" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             ModifyCacheClusterError::InsufficientCacheClusterCapacityFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "InvalidCacheClusterState" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             ModifyCacheClusterError::InvalidCacheClusterStateFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "InvalidCacheSecurityGroupState" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             ModifyCacheClusterError::InvalidCacheSecurityGroupStateFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "InvalidParameterCombination" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             ModifyCacheClusterError::InvalidParameterCombination(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "InvalidParameterValue" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             ModifyCacheClusterError::InvalidParameterValue(parsed_error.message),
+                            &res,
                         )
                     }
                     "InvalidVPCNetworkStateFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             ModifyCacheClusterError::InvalidVPCNetworkStateFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "NodeQuotaForClusterExceeded" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             ModifyCacheClusterError::NodeQuotaForClusterExceededFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "NodeQuotaForCustomerExceeded" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             ModifyCacheClusterError::NodeQuotaForCustomerExceededFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
-                    _ => {}
+                    _ => return RusotoError::unhandled(ModifyCacheClusterError::Unhandled, &res),
                 }
             }
         }
@@ -10868,12 +11254,14 @@ impl fmt::Display for ModifyCacheClusterError {
             ModifyCacheClusterError::NodeQuotaForCustomerExceededFault(ref cause) => {
                 write!(f, "{}", cause)
             }
+            ModifyCacheClusterError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ModifyCacheClusterError {}
 /// Errors returned by ModifyCacheParameterGroup
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ModifyCacheParameterGroupError {
     /// <p>The requested cache parameter group name does not refer to an existing cache parameter group.</p>
     CacheParameterGroupNotFoundFault(String),
@@ -10883,6 +11271,8 @@ pub enum ModifyCacheParameterGroupError {
     InvalidParameterCombination(String),
     /// <p>The value for a parameter is invalid.</p>
     InvalidParameterValue(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ModifyCacheParameterGroupError {
@@ -10894,34 +11284,43 @@ impl ModifyCacheParameterGroupError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "CacheParameterGroupNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             ModifyCacheParameterGroupError::CacheParameterGroupNotFoundFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "InvalidCacheParameterGroupState" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             ModifyCacheParameterGroupError::InvalidCacheParameterGroupStateFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "InvalidParameterCombination" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             ModifyCacheParameterGroupError::InvalidParameterCombination(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "InvalidParameterValue" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             ModifyCacheParameterGroupError::InvalidParameterValue(
                                 parsed_error.message,
                             ),
+                            &res,
+                        )
+                    }
+                    _ => {
+                        return RusotoError::unhandled(
+                            ModifyCacheParameterGroupError::Unhandled,
+                            &res,
                         )
                     }
-                    _ => {}
                 }
             }
         }
@@ -10952,12 +11351,14 @@ impl fmt::Display for ModifyCacheParameterGroupError {
             ModifyCacheParameterGroupError::InvalidParameterValue(ref cause) => {
                 write!(f, "{}", cause)
             }
+            ModifyCacheParameterGroupError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ModifyCacheParameterGroupError {}
 /// Errors returned by ModifyCacheSubnetGroup
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ModifyCacheSubnetGroupError {
     /// <p>The requested cache subnet group name does not refer to an existing cache subnet group.</p>
     CacheSubnetGroupNotFoundFault(String),
@@ -10967,6 +11368,8 @@ pub enum ModifyCacheSubnetGroupError {
     InvalidSubnet(String),
     /// <p>The requested subnet is being used by another cache subnet group.</p>
     SubnetInUse(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ModifyCacheSubnetGroupError {
@@ -10978,30 +11381,36 @@ impl ModifyCacheSubnetGroupError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "CacheSubnetGroupNotFoundFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             ModifyCacheSubnetGroupError::CacheSubnetGroupNotFoundFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "CacheSubnetQuotaExceededFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             ModifyCacheSubnetGroupError::CacheSubnetQuotaExceededFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "InvalidSubnet" => {
-                        return RusotoError::Service(ModifyCacheSubnetGroupError::InvalidSubnet(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            ModifyCacheSubnetGroupError::InvalidSubnet(parsed_error.message),
+                            &res,
+                        )
                     }
                     "SubnetInUse" => {
-                        return RusotoError::Service(ModifyCacheSubnetGroupError::SubnetInUse(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            ModifyCacheSubnetGroupError::SubnetInUse(parsed_error.message),
+                            &res,
+                        )
+                    }
+                    _ => {
+                        return RusotoError::unhandled(ModifyCacheSubnetGroupError::Unhandled, &res)
                     }
-                    _ => {}
                 }
             }
         }
@@ -11028,12 +11437,14 @@ impl fmt::Display for ModifyCacheSubnetGroupError {
             }
             ModifyCacheSubnetGroupError::InvalidSubnet(ref cause) => write!(f, "{}", cause),
             ModifyCacheSubnetGroupError::SubnetInUse(ref cause) => write!(f, "{}", cause),
+            ModifyCacheSubnetGroupError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ModifyCacheSubnetGroupError {}
 /// Errors returned by ModifyReplicationGroup
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ModifyReplicationGroupError {
     /// <p>The requested cluster ID does not refer to an existing cluster.</p>
     CacheClusterNotFoundFault(String),
@@ -11063,6 +11474,8 @@ pub enum ModifyReplicationGroupError {
     NodeQuotaForCustomerExceededFault(String),
     /// <p>The specified replication group does not exist.</p>
     ReplicationGroupNotFoundFault(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ModifyReplicationGroupError {
@@ -11074,102 +11487,118 @@ impl ModifyReplicationGroupError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "CacheClusterNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             ModifyReplicationGroupError::CacheClusterNotFoundFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "CacheParameterGroupNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             ModifyReplicationGroupError::CacheParameterGroupNotFoundFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "CacheSecurityGroupNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             ModifyReplicationGroupError::CacheSecurityGroupNotFoundFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "InsufficientCacheClusterCapacity" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             ModifyReplicationGroupError::InsufficientCacheClusterCapacityFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "InvalidCacheClusterState" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             ModifyReplicationGroupError::InvalidCacheClusterStateFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "InvalidCacheSecurityGroupState" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             ModifyReplicationGroupError::InvalidCacheSecurityGroupStateFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "InvalidKMSKeyFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             ModifyReplicationGroupError::InvalidKMSKeyFault(parsed_error.message),
+                            &res,
                         )
                     }
                     "InvalidParameterCombination" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             ModifyReplicationGroupError::InvalidParameterCombination(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "InvalidParameterValue" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             ModifyReplicationGroupError::InvalidParameterValue(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "InvalidReplicationGroupState" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             ModifyReplicationGroupError::InvalidReplicationGroupStateFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "InvalidVPCNetworkStateFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             ModifyReplicationGroupError::InvalidVPCNetworkStateFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "NodeQuotaForClusterExceeded" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             ModifyReplicationGroupError::NodeQuotaForClusterExceededFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "NodeQuotaForCustomerExceeded" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             ModifyReplicationGroupError::NodeQuotaForCustomerExceededFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "ReplicationGroupNotFoundFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             ModifyReplicationGroupError::ReplicationGroupNotFoundFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
-                    _ => {}
+                    _ => {
+                        return RusotoError::unhandled(ModifyReplicationGroupError::Unhandled, &res)
+                    }
                 }
             }
         }
@@ -11226,12 +11655,14 @@ impl fmt::Display for ModifyReplicationGroupError {
             ModifyReplicationGroupError::ReplicationGroupNotFoundFault(ref cause) => {
                 write!(f, "{}", cause)
             }
+            ModifyReplicationGroupError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ModifyReplicationGroupError {}
 /// Errors returned by ModifyReplicationGroupShardConfiguration
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ModifyReplicationGroupShardConfigurationError {
     /// <p>The requested cache node type is not available in the specified Availability Zone. For more information, see <a href="http://docs.aws.amazon.com/AmazonElastiCache/latest/red-ug/ErrorMessages.html#ErrorMessages.INSUFFICIENT_CACHE_CLUSTER_CAPACITY">InsufficientCacheClusterCapacity</a> in the ElastiCache User Guide.</p>
     InsufficientCacheClusterCapacityFault(String),
@@ -11253,6 +11684,8 @@ pub enum ModifyReplicationGroupShardConfigurationError {
     NodeQuotaForCustomerExceededFault(String),
     /// <p>The specified replication group does not exist.</p>
     ReplicationGroupNotFoundFault(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ModifyReplicationGroupShardConfigurationError {
@@ -11265,7 +11698,7 @@ impl ModifyReplicationGroupShardConfigurationError {
             find_start_element(&mut stack);
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
-                                    "InsufficientCacheClusterCapacity" => return RusotoError::Service(ModifyReplicationGroupShardConfigurationError::InsufficientCacheClusterCapacityFault(parsed_error.message)),"InvalidCacheClusterState" => return RusotoError::Service(ModifyReplicationGroupShardConfigurationError::InvalidCacheClusterStateFault(parsed_error.message)),"InvalidKMSKeyFault" => return RusotoError::Service(ModifyReplicationGroupShardConfigurationError::InvalidKMSKeyFault(parsed_error.message)),"InvalidParameterCombination" => return RusotoError::Service(ModifyReplicationGroupShardConfigurationError::InvalidParameterCombination(parsed_error.message)),"InvalidParameterValue" => return RusotoError::Service(ModifyReplicationGroupShardConfigurationError::InvalidParameterValue(parsed_error.message)),"InvalidReplicationGroupState" => return RusotoError::Service(ModifyReplicationGroupShardConfigurationError::InvalidReplicationGroupStateFault(parsed_error.message)),"InvalidVPCNetworkStateFault" => return RusotoError::Service(ModifyReplicationGroupShardConfigurationError::InvalidVPCNetworkStateFault(parsed_error.message)),"NodeGroupsPerReplicationGroupQuotaExceeded" => return RusotoError::Service(ModifyReplicationGroupShardConfigurationError::NodeGroupsPerReplicationGroupQuotaExceededFault(parsed_error.message)),"NodeQuotaForCustomerExceeded" => return RusotoError::Service(ModifyReplicationGroupShardConfigurationError::NodeQuotaForCustomerExceededFault(parsed_error.message)),"ReplicationGroupNotFoundFault" => return RusotoError::Service(ModifyReplicationGroupShardConfigurationError::ReplicationGroupNotFoundFault(parsed_error.message)),_ => {}
+                                    "InsufficientCacheClusterCapacity" => return RusotoError::service(ModifyReplicationGroupShardConfigurationError::InsufficientCacheClusterCapacityFault(parsed_error.message), &res),"InvalidCacheClusterState" => return RusotoError::service(ModifyReplicationGroupShardConfigurationError::InvalidCacheClusterStateFault(parsed_error.message), &res),"InvalidKMSKeyFault" => return RusotoError::service(ModifyReplicationGroupShardConfigurationError::InvalidKMSKeyFault(parsed_error.message), &res),"InvalidParameterCombination" => return RusotoError::service(ModifyReplicationGroupShardConfigurationError::InvalidParameterCombination(parsed_error.message), &res),"InvalidParameterValue" => return RusotoError::service(ModifyReplicationGroupShardConfigurationError::InvalidParameterValue(parsed_error.message), &res),"InvalidReplicationGroupState" => return RusotoError::service(ModifyReplicationGroupShardConfigurationError::InvalidReplicationGroupStateFault(parsed_error.message), &res),"InvalidVPCNetworkStateFault" => return RusotoError::service(ModifyReplicationGroupShardConfigurationError::InvalidVPCNetworkStateFault(parsed_error.message), &res),"NodeGroupsPerReplicationGroupQuotaExceeded" => return RusotoError::service(ModifyReplicationGroupShardConfigurationError::NodeGroupsPerReplicationGroupQuotaExceededFault(parsed_error.message), &res),"NodeQuotaForCustomerExceeded" => return RusotoError::service(ModifyReplicationGroupShardConfigurationError::NodeQuotaForCustomerExceededFault(parsed_error.message), &res),"ReplicationGroupNotFoundFault" => return RusotoError::service(ModifyReplicationGroupShardConfigurationError::ReplicationGroupNotFoundFault(parsed_error.message), &res),_ => return RusotoError::unhandled(ModifyReplicationGroupShardConfigurationError::Unhandled, &res),
                                 }
             }
         }
@@ -11293,13 +11726,15 @@ ModifyReplicationGroupShardConfigurationError::InvalidReplicationGroupStateFault
 ModifyReplicationGroupShardConfigurationError::InvalidVPCNetworkStateFault(ref cause) => write!(f, "{}", cause),
 ModifyReplicationGroupShardConfigurationError::NodeGroupsPerReplicationGroupQuotaExceededFault(ref cause) => write!(f, "{}", cause),
 ModifyReplicationGroupShardConfigurationError::NodeQuotaForCustomerExceededFault(ref cause) => write!(f, "{}", cause),
-ModifyReplicationGroupShardConfigurationError::ReplicationGroupNotFoundFault(ref cause) => write!(f, "{}", cause)
-                        }
+ModifyReplicationGroupShardConfigurationError::ReplicationGroupNotFoundFault(ref cause) => write!(f, "{}", cause),
+            ModifyReplicationGroupShardConfigurationError::Unhandled(ref metadata) => write!(f, "{}", metadata),
+        }
     }
 }
 impl Error for ModifyReplicationGroupShardConfigurationError {}
 /// Errors returned by PurchaseReservedCacheNodesOffering
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum PurchaseReservedCacheNodesOfferingError {
     /// <p>Two or more incompatible parameters were specified.</p>
     InvalidParameterCombination(String),
@@ -11311,6 +11746,8 @@ pub enum PurchaseReservedCacheNodesOfferingError {
     ReservedCacheNodeQuotaExceededFault(String),
     /// <p>The requested cache node offering does not exist.</p>
     ReservedCacheNodesOfferingNotFoundFault(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl PurchaseReservedCacheNodesOfferingError {
@@ -11323,7 +11760,7 @@ impl PurchaseReservedCacheNodesOfferingError {
             find_start_element(&mut stack);
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
-                                    "InvalidParameterCombination" => return RusotoError::Service(PurchaseReservedCacheNodesOfferingError::InvalidParameterCombination(parsed_error.message)),"InvalidParameterValue" => return RusotoError::Service(PurchaseReservedCacheNodesOfferingError::InvalidParameterValue(parsed_error.message)),"ReservedCacheNodeAlreadyExists" => return RusotoError::Service(PurchaseReservedCacheNodesOfferingError::ReservedCacheNodeAlreadyExistsFault(parsed_error.message)),"ReservedCacheNodeQuotaExceeded" => return RusotoError::Service(PurchaseReservedCacheNodesOfferingError::ReservedCacheNodeQuotaExceededFault(parsed_error.message)),"ReservedCacheNodesOfferingNotFound" => return RusotoError::Service(PurchaseReservedCacheNodesOfferingError::ReservedCacheNodesOfferingNotFoundFault(parsed_error.message)),_ => {}
+                                    "InvalidParameterCombination" => return RusotoError::service(PurchaseReservedCacheNodesOfferingError::InvalidParameterCombination(parsed_error.message), &res),"InvalidParameterValue" => return RusotoError::service(PurchaseReservedCacheNodesOfferingError::InvalidParameterValue(parsed_error.message), &res),"ReservedCacheNodeAlreadyExists" => return RusotoError::service(PurchaseReservedCacheNodesOfferingError::ReservedCacheNodeAlreadyExistsFault(parsed_error.message), &res),"ReservedCacheNodeQuotaExceeded" => return RusotoError::service(PurchaseReservedCacheNodesOfferingError::ReservedCacheNodeQuotaExceededFault(parsed_error.message), &res),"ReservedCacheNodesOfferingNotFound" => return RusotoError::service(PurchaseReservedCacheNodesOfferingError::ReservedCacheNodesOfferingNotFoundFault(parsed_error.message), &res),_ => return RusotoError::unhandled(PurchaseReservedCacheNodesOfferingError::Unhandled, &res),
                                 }
             }
         }
@@ -11357,17 +11794,23 @@ impl fmt::Display for PurchaseReservedCacheNodesOfferingError {
             PurchaseReservedCacheNodesOfferingError::ReservedCacheNodesOfferingNotFoundFault(
                 ref cause,
             ) => write!(f, "{}", cause),
+            PurchaseReservedCacheNodesOfferingError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for PurchaseReservedCacheNodesOfferingError {}
 /// Errors returned by RebootCacheCluster
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum RebootCacheClusterError {
     /// <p>The requested cluster ID does not refer to an existing cluster.</p>
     CacheClusterNotFoundFault(String),
     /// <p>The requested cluster is not in the <code>available</code> state.</p>
     InvalidCacheClusterStateFault(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl RebootCacheClusterError {
@@ -11379,20 +11822,22 @@ impl RebootCacheClusterError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "CacheClusterNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             RebootCacheClusterError::CacheClusterNotFoundFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "InvalidCacheClusterState" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             RebootCacheClusterError::InvalidCacheClusterStateFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
-                    _ => {}
+                    _ => return RusotoError::unhandled(RebootCacheClusterError::Unhandled, &res),
                 }
             }
         }
@@ -11415,12 +11860,14 @@ impl fmt::Display for RebootCacheClusterError {
             RebootCacheClusterError::InvalidCacheClusterStateFault(ref cause) => {
                 write!(f, "{}", cause)
             }
+            RebootCacheClusterError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for RebootCacheClusterError {}
 /// Errors returned by RemoveTagsFromResource
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum RemoveTagsFromResourceError {
     /// <p>The requested cluster ID does not refer to an existing cluster.</p>
     CacheClusterNotFoundFault(String),
@@ -11430,6 +11877,8 @@ pub enum RemoveTagsFromResourceError {
     SnapshotNotFoundFault(String),
     /// <p>The requested tag was not found on this resource.</p>
     TagNotFoundFault(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl RemoveTagsFromResourceError {
@@ -11441,30 +11890,36 @@ impl RemoveTagsFromResourceError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "CacheClusterNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             RemoveTagsFromResourceError::CacheClusterNotFoundFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "InvalidARN" => {
-                        return RusotoError::Service(RemoveTagsFromResourceError::InvalidARNFault(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            RemoveTagsFromResourceError::InvalidARNFault(parsed_error.message),
+                            &res,
+                        )
                     }
                     "SnapshotNotFoundFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             RemoveTagsFromResourceError::SnapshotNotFoundFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "TagNotFound" => {
-                        return RusotoError::Service(RemoveTagsFromResourceError::TagNotFoundFault(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            RemoveTagsFromResourceError::TagNotFoundFault(parsed_error.message),
+                            &res,
+                        )
+                    }
+                    _ => {
+                        return RusotoError::unhandled(RemoveTagsFromResourceError::Unhandled, &res)
                     }
-                    _ => {}
                 }
             }
         }
@@ -11489,12 +11944,14 @@ impl fmt::Display for RemoveTagsFromResourceError {
             RemoveTagsFromResourceError::InvalidARNFault(ref cause) => write!(f, "{}", cause),
             RemoveTagsFromResourceError::SnapshotNotFoundFault(ref cause) => write!(f, "{}", cause),
             RemoveTagsFromResourceError::TagNotFoundFault(ref cause) => write!(f, "{}", cause),
+            RemoveTagsFromResourceError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for RemoveTagsFromResourceError {}
 /// Errors returned by ResetCacheParameterGroup
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ResetCacheParameterGroupError {
     /// <p>The requested cache parameter group name does not refer to an existing cache parameter group.</p>
     CacheParameterGroupNotFoundFault(String),
@@ -11504,6 +11961,8 @@ pub enum ResetCacheParameterGroupError {
     InvalidParameterCombination(String),
     /// <p>The value for a parameter is invalid.</p>
     InvalidParameterValue(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ResetCacheParameterGroupError {
@@ -11515,34 +11974,43 @@ impl ResetCacheParameterGroupError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "CacheParameterGroupNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             ResetCacheParameterGroupError::CacheParameterGroupNotFoundFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "InvalidCacheParameterGroupState" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             ResetCacheParameterGroupError::InvalidCacheParameterGroupStateFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "InvalidParameterCombination" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             ResetCacheParameterGroupError::InvalidParameterCombination(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "InvalidParameterValue" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             ResetCacheParameterGroupError::InvalidParameterValue(
                                 parsed_error.message,
                             ),
+                            &res,
+                        )
+                    }
+                    _ => {
+                        return RusotoError::unhandled(
+                            ResetCacheParameterGroupError::Unhandled,
+                            &res,
                         )
                     }
-                    _ => {}
                 }
             }
         }
@@ -11573,12 +12041,14 @@ impl fmt::Display for ResetCacheParameterGroupError {
             ResetCacheParameterGroupError::InvalidParameterValue(ref cause) => {
                 write!(f, "{}", cause)
             }
+            ResetCacheParameterGroupError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ResetCacheParameterGroupError {}
 /// Errors returned by RevokeCacheSecurityGroupIngress
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum RevokeCacheSecurityGroupIngressError {
     /// <p>The specified Amazon EC2 security group is not authorized for the specified cache security group.</p>
     AuthorizationNotFoundFault(String),
@@ -11590,6 +12060,8 @@ pub enum RevokeCacheSecurityGroupIngressError {
     InvalidParameterCombination(String),
     /// <p>The value for a parameter is invalid.</p>
     InvalidParameterValue(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl RevokeCacheSecurityGroupIngressError {
@@ -11603,39 +12075,49 @@ impl RevokeCacheSecurityGroupIngressError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "AuthorizationNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             RevokeCacheSecurityGroupIngressError::AuthorizationNotFoundFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "CacheSecurityGroupNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             RevokeCacheSecurityGroupIngressError::CacheSecurityGroupNotFoundFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
-                    "InvalidCacheSecurityGroupState" => return RusotoError::Service(
+                    "InvalidCacheSecurityGroupState" => return RusotoError::service(
                         RevokeCacheSecurityGroupIngressError::InvalidCacheSecurityGroupStateFault(
                             parsed_error.message,
                         ),
+                        &res,
                     ),
                     "InvalidParameterCombination" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             RevokeCacheSecurityGroupIngressError::InvalidParameterCombination(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "InvalidParameterValue" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             RevokeCacheSecurityGroupIngressError::InvalidParameterValue(
                                 parsed_error.message,
                             ),
+                            &res,
+                        )
+                    }
+                    _ => {
+                        return RusotoError::unhandled(
+                            RevokeCacheSecurityGroupIngressError::Unhandled,
+                            &res,
                         )
                     }
-                    _ => {}
                 }
             }
         }
@@ -11669,12 +12151,16 @@ impl fmt::Display for RevokeCacheSecurityGroupIngressError {
             RevokeCacheSecurityGroupIngressError::InvalidParameterValue(ref cause) => {
                 write!(f, "{}", cause)
             }
+            RevokeCacheSecurityGroupIngressError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for RevokeCacheSecurityGroupIngressError {}
 /// Errors returned by StartMigration
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum StartMigrationError {
     /// <p>The value for a parameter is invalid.</p>
     InvalidParameterValue(String),
@@ -11684,6 +12170,8 @@ pub enum StartMigrationError {
     ReplicationGroupAlreadyUnderMigrationFault(String),
     /// <p>The specified replication group does not exist.</p>
     ReplicationGroupNotFoundFault(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl StartMigrationError {
@@ -11695,32 +12183,36 @@ impl StartMigrationError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InvalidParameterValue" => {
-                        return RusotoError::Service(StartMigrationError::InvalidParameterValue(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            StartMigrationError::InvalidParameterValue(parsed_error.message),
+                            &res,
+                        )
                     }
                     "InvalidReplicationGroupState" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             StartMigrationError::InvalidReplicationGroupStateFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "ReplicationGroupAlreadyUnderMigrationFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             StartMigrationError::ReplicationGroupAlreadyUnderMigrationFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "ReplicationGroupNotFoundFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             StartMigrationError::ReplicationGroupNotFoundFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
-                    _ => {}
+                    _ => return RusotoError::unhandled(StartMigrationError::Unhandled, &res),
                 }
             }
         }
@@ -11747,12 +12239,14 @@ impl fmt::Display for StartMigrationError {
                 write!(f, "{}", cause)
             }
             StartMigrationError::ReplicationGroupNotFoundFault(ref cause) => write!(f, "{}", cause),
+            StartMigrationError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for StartMigrationError {}
 /// Errors returned by TestFailover
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum TestFailoverError {
     /// <p>The customer has exceeded the allowed rate of API calls.</p>
     APICallRateForCustomerExceededFault(String),
@@ -11772,6 +12266,8 @@ pub enum TestFailoverError {
     ReplicationGroupNotFoundFault(String),
     /// <p>The <code>TestFailover</code> action is not available.</p>
     TestFailoverNotAvailableFault(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl TestFailoverError {
@@ -11783,55 +12279,64 @@ impl TestFailoverError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "APICallRateForCustomerExceeded" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             TestFailoverError::APICallRateForCustomerExceededFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "InvalidCacheClusterState" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             TestFailoverError::InvalidCacheClusterStateFault(parsed_error.message),
+                            &res,
                         )
                     }
                     "InvalidKMSKeyFault" => {
-                        return RusotoError::Service(TestFailoverError::InvalidKMSKeyFault(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            TestFailoverError::InvalidKMSKeyFault(parsed_error.message),
+                            &res,
+                        )
                     }
                     "InvalidParameterCombination" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             TestFailoverError::InvalidParameterCombination(parsed_error.message),
+                            &res,
                         )
                     }
                     "InvalidParameterValue" => {
-                        return RusotoError::Service(TestFailoverError::InvalidParameterValue(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            TestFailoverError::InvalidParameterValue(parsed_error.message),
+                            &res,
+                        )
                     }
                     "InvalidReplicationGroupState" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             TestFailoverError::InvalidReplicationGroupStateFault(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "NodeGroupNotFoundFault" => {
-                        return RusotoError::Service(TestFailoverError::NodeGroupNotFoundFault(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            TestFailoverError::NodeGroupNotFoundFault(parsed_error.message),
+                            &res,
+                        )
                     }
                     "ReplicationGroupNotFoundFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             TestFailoverError::ReplicationGroupNotFoundFault(parsed_error.message),
+                            &res,
                         )
                     }
                     "TestFailoverNotAvailableFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             TestFailoverError::TestFailoverNotAvailableFault(parsed_error.message),
+                            &res,
                         )
                     }
-                    _ => {}
+                    _ => return RusotoError::unhandled(TestFailoverError::Unhandled, &res),
                 }
             }
         }
@@ -11863,6 +12368,7 @@ impl fmt::Display for TestFailoverError {
             TestFailoverError::NodeGroupNotFoundFault(ref cause) => write!(f, "{}", cause),
             TestFailoverError::ReplicationGroupNotFoundFault(ref cause) => write!(f, "{}", cause),
             TestFailoverError::TestFailoverNotAvailableFault(ref cause) => write!(f, "{}", cause),
+            TestFailoverError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
@@ -12190,6 +12696,7 @@ impl ElastiCacheClient {
     /// Creates a client backed by the default tokio event loop.
     ///
     /// The client will use the default credentials provider and tls client.
+    #[cfg(not(target_arch = "wasm32"))]
     pub fn new(region: region::Region) -> ElastiCacheClient {
         ElastiCacheClient {
             client: Client::shared(),
@@ -12215,35 +12722,49 @@ impl ElastiCacheClient {
     pub fn new_with_client(client: Client, region: region::Region) -> ElastiCacheClient {
         ElastiCacheClient { client, region }
     }
+
+    /// Returns a builder of a client, setting any of its region, credentials provider,
+    /// dispatcher, retry policy, endpoint and timeouts.
+    pub fn builder() -> rusoto_core::ClientBuilder<ElastiCacheClient> {
+        rusoto_core::ClientBuilder::new(ElastiCacheClient::new_with_client)
+    }
+
+    /// Returns a clone of this client with the overrides of `config` applied to its
+    /// operations, sharing its connections.
+    pub fn customize(&self, config: &rusoto_core::OperationConfig) -> ElastiCacheClient {
+        ElastiCacheClient {
+            client: self.client.customize(config),
+            region: config.resolve_region(&self.region),
+        }
+    }
 }
 
-#[async_trait]
-impl ElastiCache for ElastiCacheClient {
-    /// <p>Adds up to 50 cost allocation tags to the named resource. A cost allocation tag is a key-value pair where the key and value are case-sensitive. You can use cost allocation tags to categorize and track your AWS costs.</p> <p> When you apply tags to your ElastiCache resources, AWS generates a cost allocation report as a comma-separated value (CSV) file with your usage and costs aggregated by your tags. You can apply tags that represent business categories (such as cost centers, application names, or owners) to organize your costs across multiple services. For more information, see <a href="https://docs.aws.amazon.com/AmazonElastiCache/latest/red-ug/Tagging.html">Using Cost Allocation Tags in Amazon ElastiCache</a> in the <i>ElastiCache User Guide</i>.</p>
-    async fn add_tags_to_resource(
+impl ElastiCacheClient {
+    /// Calls `add_tags_to_resource`, also returning the metadata of the response.
+    pub async fn add_tags_to_resource_with_metadata(
         &self,
         input: AddTagsToResourceMessage,
-    ) -> Result<TagListMessage, RusotoError<AddTagsToResourceError>> {
-        let mut request = SignedRequest::new("POST", "elasticache", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "AddTagsToResource");
-        params.put("Version", "2015-02-02");
-        AddTagsToResourceMessageSerializer::serialize(&mut params, "", &input);
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+    ) -> Result<(TagListMessage, ResponseMetadata), RusotoError<AddTagsToResourceError>> {
+        let request = self.build_add_tags_to_resource_request(input);
 
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(AddTagsToResourceError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -12263,39 +12784,63 @@ impl ElastiCache for ElastiCacheClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p><p>Allows network ingress to a cache security group. Applications using ElastiCache must be running on Amazon EC2, and Amazon EC2 security groups are used as the authorization mechanism.</p> <note> <p>You cannot authorize ingress from an Amazon EC2 security group in one region to an ElastiCache cluster in another region.</p> </note></p>
-    async fn authorize_cache_security_group_ingress(
+    /// Presigns the request `add_tags_to_resource` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_add_tags_to_resource(
         &self,
-        input: AuthorizeCacheSecurityGroupIngressMessage,
-    ) -> Result<
-        AuthorizeCacheSecurityGroupIngressResult,
-        RusotoError<AuthorizeCacheSecurityGroupIngressError>,
-    > {
+        presigner: &rusoto_core::Presigner,
+        input: AddTagsToResourceMessage,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_add_tags_to_resource_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_add_tags_to_resource_request(&self, input: AddTagsToResourceMessage) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "elasticache", &self.region, "/");
+        request.set_operation("AddTagsToResource");
         let mut params = Params::new();
 
-        params.put("Action", "AuthorizeCacheSecurityGroupIngress");
+        params.put("Action", "AddTagsToResource");
         params.put("Version", "2015-02-02");
-        AuthorizeCacheSecurityGroupIngressMessageSerializer::serialize(&mut params, "", &input);
+        AddTagsToResourceMessageSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `authorize_cache_security_group_ingress`, also returning the metadata of the response.
+    pub async fn authorize_cache_security_group_ingress_with_metadata(
+        &self,
+        input: AuthorizeCacheSecurityGroupIngressMessage,
+    ) -> Result<
+        (AuthorizeCacheSecurityGroupIngressResult, ResponseMetadata),
+        RusotoError<AuthorizeCacheSecurityGroupIngressError>,
+    > {
+        let request = self.build_authorize_cache_security_group_ingress_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(AuthorizeCacheSecurityGroupIngressError::from_response(
                 response,
             ));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -12317,34 +12862,64 @@ impl ElastiCache for ElastiCacheClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Apply the service update. For more information on service updates and applying them, see <a href="https://docs.aws.amazon.com/AmazonElastiCache/latest/red-ug/applying-updates.html">Applying Service Updates</a>.</p>
-    async fn batch_apply_update_action(
+    /// Presigns the request `authorize_cache_security_group_ingress` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_authorize_cache_security_group_ingress(
         &self,
-        input: BatchApplyUpdateActionMessage,
-    ) -> Result<UpdateActionResultsMessage, RusotoError<BatchApplyUpdateActionError>> {
+        presigner: &rusoto_core::Presigner,
+        input: AuthorizeCacheSecurityGroupIngressMessage,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_authorize_cache_security_group_ingress_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_authorize_cache_security_group_ingress_request(
+        &self,
+        input: AuthorizeCacheSecurityGroupIngressMessage,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "elasticache", &self.region, "/");
+        request.set_operation("AuthorizeCacheSecurityGroupIngress");
         let mut params = Params::new();
 
-        params.put("Action", "BatchApplyUpdateAction");
+        params.put("Action", "AuthorizeCacheSecurityGroupIngress");
         params.put("Version", "2015-02-02");
-        BatchApplyUpdateActionMessageSerializer::serialize(&mut params, "", &input);
+        AuthorizeCacheSecurityGroupIngressMessageSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `batch_apply_update_action`, also returning the metadata of the response.
+    pub async fn batch_apply_update_action_with_metadata(
+        &self,
+        input: BatchApplyUpdateActionMessage,
+    ) -> Result<
+        (UpdateActionResultsMessage, ResponseMetadata),
+        RusotoError<BatchApplyUpdateActionError>,
+    > {
+        let request = self.build_batch_apply_update_action_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(BatchApplyUpdateActionError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -12366,34 +12941,64 @@ impl ElastiCache for ElastiCacheClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Stop the service update. For more information on service updates and stopping them, see <a href="https://docs.aws.amazon.com/AmazonElastiCache/latest/red-ug/stopping-self-service-updates.html">Stopping Service Updates</a>.</p>
-    async fn batch_stop_update_action(
+    /// Presigns the request `batch_apply_update_action` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_batch_apply_update_action(
         &self,
-        input: BatchStopUpdateActionMessage,
-    ) -> Result<UpdateActionResultsMessage, RusotoError<BatchStopUpdateActionError>> {
+        presigner: &rusoto_core::Presigner,
+        input: BatchApplyUpdateActionMessage,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_batch_apply_update_action_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_batch_apply_update_action_request(
+        &self,
+        input: BatchApplyUpdateActionMessage,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "elasticache", &self.region, "/");
+        request.set_operation("BatchApplyUpdateAction");
         let mut params = Params::new();
 
-        params.put("Action", "BatchStopUpdateAction");
+        params.put("Action", "BatchApplyUpdateAction");
         params.put("Version", "2015-02-02");
-        BatchStopUpdateActionMessageSerializer::serialize(&mut params, "", &input);
+        BatchApplyUpdateActionMessageSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `batch_stop_update_action`, also returning the metadata of the response.
+    pub async fn batch_stop_update_action_with_metadata(
+        &self,
+        input: BatchStopUpdateActionMessage,
+    ) -> Result<
+        (UpdateActionResultsMessage, ResponseMetadata),
+        RusotoError<BatchStopUpdateActionError>,
+    > {
+        let request = self.build_batch_stop_update_action_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(BatchStopUpdateActionError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -12415,34 +13020,62 @@ impl ElastiCache for ElastiCacheClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Complete the migration of data.</p>
-    async fn complete_migration(
+    /// Presigns the request `batch_stop_update_action` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_batch_stop_update_action(
         &self,
-        input: CompleteMigrationMessage,
-    ) -> Result<CompleteMigrationResponse, RusotoError<CompleteMigrationError>> {
+        presigner: &rusoto_core::Presigner,
+        input: BatchStopUpdateActionMessage,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_batch_stop_update_action_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_batch_stop_update_action_request(
+        &self,
+        input: BatchStopUpdateActionMessage,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "elasticache", &self.region, "/");
+        request.set_operation("BatchStopUpdateAction");
         let mut params = Params::new();
 
-        params.put("Action", "CompleteMigration");
+        params.put("Action", "BatchStopUpdateAction");
         params.put("Version", "2015-02-02");
-        CompleteMigrationMessageSerializer::serialize(&mut params, "", &input);
+        BatchStopUpdateActionMessageSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `complete_migration`, also returning the metadata of the response.
+    pub async fn complete_migration_with_metadata(
+        &self,
+        input: CompleteMigrationMessage,
+    ) -> Result<(CompleteMigrationResponse, ResponseMetadata), RusotoError<CompleteMigrationError>>
+    {
+        let request = self.build_complete_migration_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(CompleteMigrationError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -12464,34 +13097,58 @@ impl ElastiCache for ElastiCacheClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p><p>Makes a copy of an existing snapshot.</p> <note> <p>This operation is valid for Redis only.</p> </note> <important> <p>Users or groups that have permissions to use the <code>CopySnapshot</code> operation can create their own Amazon S3 buckets and copy snapshots to it. To control access to your snapshots, use an IAM policy to control who has the ability to use the <code>CopySnapshot</code> operation. For more information about using IAM to control the use of ElastiCache operations, see <a href="https://docs.aws.amazon.com/AmazonElastiCache/latest/red-ug/backups-exporting.html">Exporting Snapshots</a> and <a href="https://docs.aws.amazon.com/AmazonElastiCache/latest/red-ug/IAM.html">Authentication &amp; Access Control</a>.</p> </important> <p>You could receive the following error messages.</p> <p class="title"> <b>Error Messages</b> </p> <ul> <li> <p> <b>Error Message:</b> The S3 bucket %s is outside of the region.</p> <p> <b>Solution:</b> Create an Amazon S3 bucket in the same region as your snapshot. For more information, see <a href="https://docs.aws.amazon.com/AmazonElastiCache/latest/red-ug/backups-exporting.html#backups-exporting-create-s3-bucket">Step 1: Create an Amazon S3 Bucket</a> in the ElastiCache User Guide.</p> </li> <li> <p> <b>Error Message:</b> The S3 bucket %s does not exist.</p> <p> <b>Solution:</b> Create an Amazon S3 bucket in the same region as your snapshot. For more information, see <a href="https://docs.aws.amazon.com/AmazonElastiCache/latest/red-ug/backups-exporting.html#backups-exporting-create-s3-bucket">Step 1: Create an Amazon S3 Bucket</a> in the ElastiCache User Guide.</p> </li> <li> <p> <b>Error Message:</b> The S3 bucket %s is not owned by the authenticated user.</p> <p> <b>Solution:</b> Create an Amazon S3 bucket in the same region as your snapshot. For more information, see <a href="https://docs.aws.amazon.com/AmazonElastiCache/latest/red-ug/backups-exporting.html#backups-exporting-create-s3-bucket">Step 1: Create an Amazon S3 Bucket</a> in the ElastiCache User Guide.</p> </li> <li> <p> <b>Error Message:</b> The authenticated user does not have sufficient permissions to perform the desired activity.</p> <p> <b>Solution:</b> Contact your system administrator to get the needed permissions.</p> </li> <li> <p> <b>Error Message:</b> The S3 bucket %s already contains an object with key %s.</p> <p> <b>Solution:</b> Give the <code>TargetSnapshotName</code> a new and unique value. If exporting a snapshot, you could alternatively create a new Amazon S3 bucket and use this same value for <code>TargetSnapshotName</code>.</p> </li> <li> <p> <b>Error Message: </b> ElastiCache has not been granted READ permissions %s on the S3 Bucket.</p> <p> <b>Solution:</b> Add List and Read permissions on the bucket. For more information, see <a href="https://docs.aws.amazon.com/AmazonElastiCache/latest/red-ug/backups-exporting.html#backups-exporting-grant-access">Step 2: Grant ElastiCache Access to Your Amazon S3 Bucket</a> in the ElastiCache User Guide.</p> </li> <li> <p> <b>Error Message: </b> ElastiCache has not been granted WRITE permissions %s on the S3 Bucket.</p> <p> <b>Solution:</b> Add Upload/Delete permissions on the bucket. For more information, see <a href="https://docs.aws.amazon.com/AmazonElastiCache/latest/red-ug/backups-exporting.html#backups-exporting-grant-access">Step 2: Grant ElastiCache Access to Your Amazon S3 Bucket</a> in the ElastiCache User Guide.</p> </li> <li> <p> <b>Error Message: </b> ElastiCache has not been granted READ_ACP permissions %s on the S3 Bucket.</p> <p> <b>Solution:</b> Add View Permissions on the bucket. For more information, see <a href="https://docs.aws.amazon.com/AmazonElastiCache/latest/red-ug/backups-exporting.html#backups-exporting-grant-access">Step 2: Grant ElastiCache Access to Your Amazon S3 Bucket</a> in the ElastiCache User Guide.</p> </li> </ul></p>
-    async fn copy_snapshot(
+    /// Presigns the request `complete_migration` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_complete_migration(
         &self,
-        input: CopySnapshotMessage,
-    ) -> Result<CopySnapshotResult, RusotoError<CopySnapshotError>> {
+        presigner: &rusoto_core::Presigner,
+        input: CompleteMigrationMessage,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_complete_migration_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_complete_migration_request(&self, input: CompleteMigrationMessage) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "elasticache", &self.region, "/");
+        request.set_operation("CompleteMigration");
         let mut params = Params::new();
 
-        params.put("Action", "CopySnapshot");
+        params.put("Action", "CompleteMigration");
         params.put("Version", "2015-02-02");
-        CopySnapshotMessageSerializer::serialize(&mut params, "", &input);
+        CompleteMigrationMessageSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `copy_snapshot`, also returning the metadata of the response.
+    pub async fn copy_snapshot_with_metadata(
+        &self,
+        input: CopySnapshotMessage,
+    ) -> Result<(CopySnapshotResult, ResponseMetadata), RusotoError<CopySnapshotError>> {
+        let request = self.build_copy_snapshot_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(CopySnapshotError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -12510,34 +13167,59 @@ impl ElastiCache for ElastiCacheClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Creates a cluster. All nodes in the cluster run the same protocol-compliant cache engine software, either Memcached or Redis.</p> <p>This operation is not supported for Redis (cluster mode enabled) clusters.</p>
-    async fn create_cache_cluster(
+    /// Presigns the request `copy_snapshot` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_copy_snapshot(
         &self,
-        input: CreateCacheClusterMessage,
-    ) -> Result<CreateCacheClusterResult, RusotoError<CreateCacheClusterError>> {
+        presigner: &rusoto_core::Presigner,
+        input: CopySnapshotMessage,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_copy_snapshot_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_copy_snapshot_request(&self, input: CopySnapshotMessage) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "elasticache", &self.region, "/");
+        request.set_operation("CopySnapshot");
         let mut params = Params::new();
 
-        params.put("Action", "CreateCacheCluster");
+        params.put("Action", "CopySnapshot");
         params.put("Version", "2015-02-02");
-        CreateCacheClusterMessageSerializer::serialize(&mut params, "", &input);
+        CopySnapshotMessageSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `create_cache_cluster`, also returning the metadata of the response.
+    pub async fn create_cache_cluster_with_metadata(
+        &self,
+        input: CreateCacheClusterMessage,
+    ) -> Result<(CreateCacheClusterResult, ResponseMetadata), RusotoError<CreateCacheClusterError>>
+    {
+        let request = self.build_create_cache_cluster_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(CreateCacheClusterError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -12559,34 +13241,64 @@ impl ElastiCache for ElastiCacheClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p><p>Creates a new Amazon ElastiCache cache parameter group. An ElastiCache cache parameter group is a collection of parameters and their values that are applied to all of the nodes in any cluster or replication group using the CacheParameterGroup.</p> <p>A newly created CacheParameterGroup is an exact duplicate of the default parameter group for the CacheParameterGroupFamily. To customize the newly created CacheParameterGroup you can change the values of specific parameters. For more information, see:</p> <ul> <li> <p> <a href="https://docs.aws.amazon.com/AmazonElastiCache/latest/APIReference/API_ModifyCacheParameterGroup.html">ModifyCacheParameterGroup</a> in the ElastiCache API Reference.</p> </li> <li> <p> <a href="https://docs.aws.amazon.com/AmazonElastiCache/latest/red-ug/ParameterGroups.html">Parameters and Parameter Groups</a> in the ElastiCache User Guide.</p> </li> </ul></p>
-    async fn create_cache_parameter_group(
+    /// Presigns the request `create_cache_cluster` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_cache_cluster(
         &self,
-        input: CreateCacheParameterGroupMessage,
-    ) -> Result<CreateCacheParameterGroupResult, RusotoError<CreateCacheParameterGroupError>> {
+        presigner: &rusoto_core::Presigner,
+        input: CreateCacheClusterMessage,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_cache_cluster_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_cache_cluster_request(
+        &self,
+        input: CreateCacheClusterMessage,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "elasticache", &self.region, "/");
+        request.set_operation("CreateCacheCluster");
         let mut params = Params::new();
 
-        params.put("Action", "CreateCacheParameterGroup");
+        params.put("Action", "CreateCacheCluster");
         params.put("Version", "2015-02-02");
-        CreateCacheParameterGroupMessageSerializer::serialize(&mut params, "", &input);
+        CreateCacheClusterMessageSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `create_cache_parameter_group`, also returning the metadata of the response.
+    pub async fn create_cache_parameter_group_with_metadata(
+        &self,
+        input: CreateCacheParameterGroupMessage,
+    ) -> Result<
+        (CreateCacheParameterGroupResult, ResponseMetadata),
+        RusotoError<CreateCacheParameterGroupError>,
+    > {
+        let request = self.build_create_cache_parameter_group_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(CreateCacheParameterGroupError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -12608,34 +13320,64 @@ impl ElastiCache for ElastiCacheClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Creates a new cache security group. Use a cache security group to control access to one or more clusters.</p> <p>Cache security groups are only used when you are creating a cluster outside of an Amazon Virtual Private Cloud (Amazon VPC). If you are creating a cluster inside of a VPC, use a cache subnet group instead. For more information, see <a href="https://docs.aws.amazon.com/AmazonElastiCache/latest/APIReference/API_CreateCacheSubnetGroup.html">CreateCacheSubnetGroup</a>.</p>
-    async fn create_cache_security_group(
+    /// Presigns the request `create_cache_parameter_group` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_cache_parameter_group(
         &self,
-        input: CreateCacheSecurityGroupMessage,
-    ) -> Result<CreateCacheSecurityGroupResult, RusotoError<CreateCacheSecurityGroupError>> {
+        presigner: &rusoto_core::Presigner,
+        input: CreateCacheParameterGroupMessage,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_cache_parameter_group_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_cache_parameter_group_request(
+        &self,
+        input: CreateCacheParameterGroupMessage,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "elasticache", &self.region, "/");
+        request.set_operation("CreateCacheParameterGroup");
         let mut params = Params::new();
 
-        params.put("Action", "CreateCacheSecurityGroup");
+        params.put("Action", "CreateCacheParameterGroup");
         params.put("Version", "2015-02-02");
-        CreateCacheSecurityGroupMessageSerializer::serialize(&mut params, "", &input);
+        CreateCacheParameterGroupMessageSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `create_cache_security_group`, also returning the metadata of the response.
+    pub async fn create_cache_security_group_with_metadata(
+        &self,
+        input: CreateCacheSecurityGroupMessage,
+    ) -> Result<
+        (CreateCacheSecurityGroupResult, ResponseMetadata),
+        RusotoError<CreateCacheSecurityGroupError>,
+    > {
+        let request = self.build_create_cache_security_group_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(CreateCacheSecurityGroupError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -12657,34 +13399,64 @@ impl ElastiCache for ElastiCacheClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Creates a new cache subnet group.</p> <p>Use this parameter only when you are creating a cluster in an Amazon Virtual Private Cloud (Amazon VPC).</p>
-    async fn create_cache_subnet_group(
+    /// Presigns the request `create_cache_security_group` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_cache_security_group(
         &self,
-        input: CreateCacheSubnetGroupMessage,
-    ) -> Result<CreateCacheSubnetGroupResult, RusotoError<CreateCacheSubnetGroupError>> {
+        presigner: &rusoto_core::Presigner,
+        input: CreateCacheSecurityGroupMessage,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_cache_security_group_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_cache_security_group_request(
+        &self,
+        input: CreateCacheSecurityGroupMessage,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "elasticache", &self.region, "/");
+        request.set_operation("CreateCacheSecurityGroup");
         let mut params = Params::new();
 
-        params.put("Action", "CreateCacheSubnetGroup");
+        params.put("Action", "CreateCacheSecurityGroup");
         params.put("Version", "2015-02-02");
-        CreateCacheSubnetGroupMessageSerializer::serialize(&mut params, "", &input);
+        CreateCacheSecurityGroupMessageSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `create_cache_subnet_group`, also returning the metadata of the response.
+    pub async fn create_cache_subnet_group_with_metadata(
+        &self,
+        input: CreateCacheSubnetGroupMessage,
+    ) -> Result<
+        (CreateCacheSubnetGroupResult, ResponseMetadata),
+        RusotoError<CreateCacheSubnetGroupError>,
+    > {
+        let request = self.build_create_cache_subnet_group_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(CreateCacheSubnetGroupError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -12706,34 +13478,64 @@ impl ElastiCache for ElastiCacheClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p><p>Creates a Redis (cluster mode disabled) or a Redis (cluster mode enabled) replication group.</p> <p>A Redis (cluster mode disabled) replication group is a collection of clusters, where one of the clusters is a read/write primary and the others are read-only replicas. Writes to the primary are asynchronously propagated to the replicas.</p> <p>A Redis (cluster mode enabled) replication group is a collection of 1 to 90 node groups (shards). Each node group (shard) has one read/write primary node and up to 5 read-only replica nodes. Writes to the primary are asynchronously propagated to the replicas. Redis (cluster mode enabled) replication groups partition the data across node groups (shards).</p> <p>When a Redis (cluster mode disabled) replication group has been successfully created, you can add one or more read replicas to it, up to a total of 5 read replicas. You cannot alter a Redis (cluster mode enabled) replication group after it has been created. However, if you need to increase or decrease the number of node groups (console: shards), you can avail yourself of ElastiCache for Redis&#39; enhanced backup and restore. For more information, see <a href="https://docs.aws.amazon.com/AmazonElastiCache/latest/red-ug/backups-restoring.html">Restoring From a Backup with Cluster Resizing</a> in the <i>ElastiCache User Guide</i>.</p> <note> <p>This operation is valid for Redis only.</p> </note></p>
-    async fn create_replication_group(
+    /// Presigns the request `create_cache_subnet_group` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_cache_subnet_group(
         &self,
-        input: CreateReplicationGroupMessage,
-    ) -> Result<CreateReplicationGroupResult, RusotoError<CreateReplicationGroupError>> {
+        presigner: &rusoto_core::Presigner,
+        input: CreateCacheSubnetGroupMessage,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_cache_subnet_group_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_cache_subnet_group_request(
+        &self,
+        input: CreateCacheSubnetGroupMessage,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "elasticache", &self.region, "/");
+        request.set_operation("CreateCacheSubnetGroup");
         let mut params = Params::new();
 
-        params.put("Action", "CreateReplicationGroup");
+        params.put("Action", "CreateCacheSubnetGroup");
         params.put("Version", "2015-02-02");
-        CreateReplicationGroupMessageSerializer::serialize(&mut params, "", &input);
+        CreateCacheSubnetGroupMessageSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `create_replication_group`, also returning the metadata of the response.
+    pub async fn create_replication_group_with_metadata(
+        &self,
+        input: CreateReplicationGroupMessage,
+    ) -> Result<
+        (CreateReplicationGroupResult, ResponseMetadata),
+        RusotoError<CreateReplicationGroupError>,
+    > {
+        let request = self.build_create_replication_group_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(CreateReplicationGroupError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -12755,34 +13557,61 @@ impl ElastiCache for ElastiCacheClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p><p>Creates a copy of an entire cluster or replication group at a specific moment in time.</p> <note> <p>This operation is valid for Redis only.</p> </note></p>
-    async fn create_snapshot(
+    /// Presigns the request `create_replication_group` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_replication_group(
         &self,
-        input: CreateSnapshotMessage,
-    ) -> Result<CreateSnapshotResult, RusotoError<CreateSnapshotError>> {
+        presigner: &rusoto_core::Presigner,
+        input: CreateReplicationGroupMessage,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_replication_group_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_replication_group_request(
+        &self,
+        input: CreateReplicationGroupMessage,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "elasticache", &self.region, "/");
+        request.set_operation("CreateReplicationGroup");
         let mut params = Params::new();
 
-        params.put("Action", "CreateSnapshot");
+        params.put("Action", "CreateReplicationGroup");
         params.put("Version", "2015-02-02");
-        CreateSnapshotMessageSerializer::serialize(&mut params, "", &input);
+        CreateReplicationGroupMessageSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `create_snapshot`, also returning the metadata of the response.
+    pub async fn create_snapshot_with_metadata(
+        &self,
+        input: CreateSnapshotMessage,
+    ) -> Result<(CreateSnapshotResult, ResponseMetadata), RusotoError<CreateSnapshotError>> {
+        let request = self.build_create_snapshot_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(CreateSnapshotError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -12802,34 +13631,61 @@ impl ElastiCache for ElastiCacheClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Dynamically decreases the number of replics in a Redis (cluster mode disabled) replication group or the number of replica nodes in one or more node groups (shards) of a Redis (cluster mode enabled) replication group. This operation is performed with no cluster down time.</p>
-    async fn decrease_replica_count(
+    /// Presigns the request `create_snapshot` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_snapshot(
         &self,
-        input: DecreaseReplicaCountMessage,
-    ) -> Result<DecreaseReplicaCountResult, RusotoError<DecreaseReplicaCountError>> {
+        presigner: &rusoto_core::Presigner,
+        input: CreateSnapshotMessage,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_snapshot_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_snapshot_request(&self, input: CreateSnapshotMessage) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "elasticache", &self.region, "/");
+        request.set_operation("CreateSnapshot");
         let mut params = Params::new();
 
-        params.put("Action", "DecreaseReplicaCount");
+        params.put("Action", "CreateSnapshot");
         params.put("Version", "2015-02-02");
-        DecreaseReplicaCountMessageSerializer::serialize(&mut params, "", &input);
+        CreateSnapshotMessageSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `decrease_replica_count`, also returning the metadata of the response.
+    pub async fn decrease_replica_count_with_metadata(
+        &self,
+        input: DecreaseReplicaCountMessage,
+    ) -> Result<
+        (DecreaseReplicaCountResult, ResponseMetadata),
+        RusotoError<DecreaseReplicaCountError>,
+    > {
+        let request = self.build_decrease_replica_count_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(DecreaseReplicaCountError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -12851,34 +13707,62 @@ impl ElastiCache for ElastiCacheClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p><p>Deletes a previously provisioned cluster. <code>DeleteCacheCluster</code> deletes all associated cache nodes, node endpoints and the cluster itself. When you receive a successful response from this operation, Amazon ElastiCache immediately begins deleting the cluster; you cannot cancel or revert this operation.</p> <p>This operation is not valid for:</p> <ul> <li> <p>Redis (cluster mode enabled) clusters</p> </li> <li> <p>A cluster that is the last read replica of a replication group</p> </li> <li> <p>A node group (shard) that has Multi-AZ mode enabled</p> </li> <li> <p>A cluster from a Redis (cluster mode enabled) replication group</p> </li> <li> <p>A cluster that is not in the <code>available</code> state</p> </li> </ul></p>
-    async fn delete_cache_cluster(
+    /// Presigns the request `decrease_replica_count` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_decrease_replica_count(
         &self,
-        input: DeleteCacheClusterMessage,
-    ) -> Result<DeleteCacheClusterResult, RusotoError<DeleteCacheClusterError>> {
+        presigner: &rusoto_core::Presigner,
+        input: DecreaseReplicaCountMessage,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_decrease_replica_count_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_decrease_replica_count_request(
+        &self,
+        input: DecreaseReplicaCountMessage,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "elasticache", &self.region, "/");
+        request.set_operation("DecreaseReplicaCount");
         let mut params = Params::new();
 
-        params.put("Action", "DeleteCacheCluster");
+        params.put("Action", "DecreaseReplicaCount");
         params.put("Version", "2015-02-02");
-        DeleteCacheClusterMessageSerializer::serialize(&mut params, "", &input);
+        DecreaseReplicaCountMessageSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `delete_cache_cluster`, also returning the metadata of the response.
+    pub async fn delete_cache_cluster_with_metadata(
+        &self,
+        input: DeleteCacheClusterMessage,
+    ) -> Result<(DeleteCacheClusterResult, ResponseMetadata), RusotoError<DeleteCacheClusterError>>
+    {
+        let request = self.build_delete_cache_cluster_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(DeleteCacheClusterError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -12900,118 +13784,220 @@ impl ElastiCache for ElastiCacheClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Deletes the specified cache parameter group. You cannot delete a cache parameter group if it is associated with any cache clusters.</p>
-    async fn delete_cache_parameter_group(
+    /// Presigns the request `delete_cache_cluster` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_cache_cluster(
         &self,
-        input: DeleteCacheParameterGroupMessage,
-    ) -> Result<(), RusotoError<DeleteCacheParameterGroupError>> {
+        presigner: &rusoto_core::Presigner,
+        input: DeleteCacheClusterMessage,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_cache_cluster_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_cache_cluster_request(
+        &self,
+        input: DeleteCacheClusterMessage,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "elasticache", &self.region, "/");
+        request.set_operation("DeleteCacheCluster");
         let mut params = Params::new();
 
-        params.put("Action", "DeleteCacheParameterGroup");
+        params.put("Action", "DeleteCacheCluster");
         params.put("Version", "2015-02-02");
-        DeleteCacheParameterGroupMessageSerializer::serialize(&mut params, "", &input);
+        DeleteCacheClusterMessageSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `delete_cache_parameter_group`, also returning the metadata of the response.
+    pub async fn delete_cache_parameter_group_with_metadata(
+        &self,
+        input: DeleteCacheParameterGroupMessage,
+    ) -> Result<((), ResponseMetadata), RusotoError<DeleteCacheParameterGroupError>> {
+        let request = self.build_delete_cache_parameter_group_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(DeleteCacheParameterGroupError::from_response(response));
         }
 
         std::mem::drop(response);
-        Ok(())
+        Ok(((), metadata))
     }
 
-    /// <p><p>Deletes a cache security group.</p> <note> <p>You cannot delete a cache security group if it is associated with any clusters.</p> </note></p>
-    async fn delete_cache_security_group(
+    /// Presigns the request `delete_cache_parameter_group` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_cache_parameter_group(
         &self,
-        input: DeleteCacheSecurityGroupMessage,
-    ) -> Result<(), RusotoError<DeleteCacheSecurityGroupError>> {
+        presigner: &rusoto_core::Presigner,
+        input: DeleteCacheParameterGroupMessage,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_cache_parameter_group_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_cache_parameter_group_request(
+        &self,
+        input: DeleteCacheParameterGroupMessage,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "elasticache", &self.region, "/");
+        request.set_operation("DeleteCacheParameterGroup");
         let mut params = Params::new();
 
-        params.put("Action", "DeleteCacheSecurityGroup");
+        params.put("Action", "DeleteCacheParameterGroup");
         params.put("Version", "2015-02-02");
-        DeleteCacheSecurityGroupMessageSerializer::serialize(&mut params, "", &input);
+        DeleteCacheParameterGroupMessageSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `delete_cache_security_group`, also returning the metadata of the response.
+    pub async fn delete_cache_security_group_with_metadata(
+        &self,
+        input: DeleteCacheSecurityGroupMessage,
+    ) -> Result<((), ResponseMetadata), RusotoError<DeleteCacheSecurityGroupError>> {
+        let request = self.build_delete_cache_security_group_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(DeleteCacheSecurityGroupError::from_response(response));
         }
 
         std::mem::drop(response);
-        Ok(())
+        Ok(((), metadata))
     }
 
-    /// <p><p>Deletes a cache subnet group.</p> <note> <p>You cannot delete a cache subnet group if it is associated with any clusters.</p> </note></p>
-    async fn delete_cache_subnet_group(
+    /// Presigns the request `delete_cache_security_group` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_cache_security_group(
         &self,
-        input: DeleteCacheSubnetGroupMessage,
-    ) -> Result<(), RusotoError<DeleteCacheSubnetGroupError>> {
+        presigner: &rusoto_core::Presigner,
+        input: DeleteCacheSecurityGroupMessage,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_cache_security_group_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_cache_security_group_request(
+        &self,
+        input: DeleteCacheSecurityGroupMessage,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "elasticache", &self.region, "/");
+        request.set_operation("DeleteCacheSecurityGroup");
         let mut params = Params::new();
 
-        params.put("Action", "DeleteCacheSubnetGroup");
+        params.put("Action", "DeleteCacheSecurityGroup");
         params.put("Version", "2015-02-02");
-        DeleteCacheSubnetGroupMessageSerializer::serialize(&mut params, "", &input);
+        DeleteCacheSecurityGroupMessageSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `delete_cache_subnet_group`, also returning the metadata of the response.
+    pub async fn delete_cache_subnet_group_with_metadata(
+        &self,
+        input: DeleteCacheSubnetGroupMessage,
+    ) -> Result<((), ResponseMetadata), RusotoError<DeleteCacheSubnetGroupError>> {
+        let request = self.build_delete_cache_subnet_group_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(DeleteCacheSubnetGroupError::from_response(response));
         }
 
         std::mem::drop(response);
-        Ok(())
+        Ok(((), metadata))
     }
 
-    /// <p><p>Deletes an existing replication group. By default, this operation deletes the entire replication group, including the primary/primaries and all of the read replicas. If the replication group has only one primary, you can optionally delete only the read replicas, while retaining the primary by setting <code>RetainPrimaryCluster=true</code>.</p> <p>When you receive a successful response from this operation, Amazon ElastiCache immediately begins deleting the selected resources; you cannot cancel or revert this operation.</p> <note> <p>This operation is valid for Redis only.</p> </note></p>
-    async fn delete_replication_group(
+    /// Presigns the request `delete_cache_subnet_group` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_cache_subnet_group(
         &self,
-        input: DeleteReplicationGroupMessage,
-    ) -> Result<DeleteReplicationGroupResult, RusotoError<DeleteReplicationGroupError>> {
+        presigner: &rusoto_core::Presigner,
+        input: DeleteCacheSubnetGroupMessage,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_cache_subnet_group_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_cache_subnet_group_request(
+        &self,
+        input: DeleteCacheSubnetGroupMessage,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "elasticache", &self.region, "/");
+        request.set_operation("DeleteCacheSubnetGroup");
         let mut params = Params::new();
 
-        params.put("Action", "DeleteReplicationGroup");
+        params.put("Action", "DeleteCacheSubnetGroup");
         params.put("Version", "2015-02-02");
-        DeleteReplicationGroupMessageSerializer::serialize(&mut params, "", &input);
+        DeleteCacheSubnetGroupMessageSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `delete_replication_group`, also returning the metadata of the response.
+    pub async fn delete_replication_group_with_metadata(
+        &self,
+        input: DeleteReplicationGroupMessage,
+    ) -> Result<
+        (DeleteReplicationGroupResult, ResponseMetadata),
+        RusotoError<DeleteReplicationGroupError>,
+    > {
+        let request = self.build_delete_replication_group_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(DeleteReplicationGroupError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -13033,34 +14019,61 @@ impl ElastiCache for ElastiCacheClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p><p>Deletes an existing snapshot. When you receive a successful response from this operation, ElastiCache immediately begins deleting the snapshot; you cannot cancel or revert this operation.</p> <note> <p>This operation is valid for Redis only.</p> </note></p>
-    async fn delete_snapshot(
+    /// Presigns the request `delete_replication_group` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_replication_group(
         &self,
-        input: DeleteSnapshotMessage,
-    ) -> Result<DeleteSnapshotResult, RusotoError<DeleteSnapshotError>> {
+        presigner: &rusoto_core::Presigner,
+        input: DeleteReplicationGroupMessage,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_replication_group_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_replication_group_request(
+        &self,
+        input: DeleteReplicationGroupMessage,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "elasticache", &self.region, "/");
+        request.set_operation("DeleteReplicationGroup");
         let mut params = Params::new();
 
-        params.put("Action", "DeleteSnapshot");
+        params.put("Action", "DeleteReplicationGroup");
         params.put("Version", "2015-02-02");
-        DeleteSnapshotMessageSerializer::serialize(&mut params, "", &input);
+        DeleteReplicationGroupMessageSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `delete_snapshot`, also returning the metadata of the response.
+    pub async fn delete_snapshot_with_metadata(
+        &self,
+        input: DeleteSnapshotMessage,
+    ) -> Result<(DeleteSnapshotResult, ResponseMetadata), RusotoError<DeleteSnapshotError>> {
+        let request = self.build_delete_snapshot_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(DeleteSnapshotError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -13080,34 +14093,59 @@ impl ElastiCache for ElastiCacheClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Returns information about all provisioned clusters if no cluster identifier is specified, or about a specific cache cluster if a cluster identifier is supplied.</p> <p>By default, abbreviated information about the clusters is returned. You can use the optional <i>ShowCacheNodeInfo</i> flag to retrieve detailed information about the cache nodes associated with the clusters. These details include the DNS address and port for the cache node endpoint.</p> <p>If the cluster is in the <i>creating</i> state, only cluster-level information is displayed until all of the nodes are successfully provisioned.</p> <p>If the cluster is in the <i>deleting</i> state, only cluster-level information is displayed.</p> <p>If cache nodes are currently being added to the cluster, node endpoint information and creation time for the additional nodes are not displayed until they are completely provisioned. When the cluster state is <i>available</i>, the cluster is ready for use.</p> <p>If cache nodes are currently being removed from the cluster, no endpoint information for the removed nodes is displayed.</p>
-    async fn describe_cache_clusters(
+    /// Presigns the request `delete_snapshot` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_snapshot(
         &self,
-        input: DescribeCacheClustersMessage,
-    ) -> Result<CacheClusterMessage, RusotoError<DescribeCacheClustersError>> {
+        presigner: &rusoto_core::Presigner,
+        input: DeleteSnapshotMessage,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_snapshot_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_snapshot_request(&self, input: DeleteSnapshotMessage) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "elasticache", &self.region, "/");
+        request.set_operation("DeleteSnapshot");
         let mut params = Params::new();
 
-        params.put("Action", "DescribeCacheClusters");
+        params.put("Action", "DeleteSnapshot");
         params.put("Version", "2015-02-02");
-        DescribeCacheClustersMessageSerializer::serialize(&mut params, "", &input);
+        DeleteSnapshotMessageSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
-        let mut response = self
-            .client
+        request
+    }
+
+    /// Calls `describe_cache_clusters`, also returning the metadata of the response.
+    pub async fn describe_cache_clusters_with_metadata(
+        &self,
+        input: DescribeCacheClustersMessage,
+    ) -> Result<(CacheClusterMessage, ResponseMetadata), RusotoError<DescribeCacheClustersError>>
+    {
+        let request = self.build_describe_cache_clusters_request(input);
+
+        let mut response = self
+            .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(DescribeCacheClustersError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -13129,34 +14167,64 @@ impl ElastiCache for ElastiCacheClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Returns a list of the available cache engines and their versions.</p>
-    async fn describe_cache_engine_versions(
+    /// Presigns the request `describe_cache_clusters` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_cache_clusters(
         &self,
-        input: DescribeCacheEngineVersionsMessage,
-    ) -> Result<CacheEngineVersionMessage, RusotoError<DescribeCacheEngineVersionsError>> {
+        presigner: &rusoto_core::Presigner,
+        input: DescribeCacheClustersMessage,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_cache_clusters_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_cache_clusters_request(
+        &self,
+        input: DescribeCacheClustersMessage,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "elasticache", &self.region, "/");
+        request.set_operation("DescribeCacheClusters");
         let mut params = Params::new();
 
-        params.put("Action", "DescribeCacheEngineVersions");
+        params.put("Action", "DescribeCacheClusters");
         params.put("Version", "2015-02-02");
-        DescribeCacheEngineVersionsMessageSerializer::serialize(&mut params, "", &input);
+        DescribeCacheClustersMessageSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `describe_cache_engine_versions`, also returning the metadata of the response.
+    pub async fn describe_cache_engine_versions_with_metadata(
+        &self,
+        input: DescribeCacheEngineVersionsMessage,
+    ) -> Result<
+        (CacheEngineVersionMessage, ResponseMetadata),
+        RusotoError<DescribeCacheEngineVersionsError>,
+    > {
+        let request = self.build_describe_cache_engine_versions_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(DescribeCacheEngineVersionsError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -13178,34 +14246,64 @@ impl ElastiCache for ElastiCacheClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Returns a list of cache parameter group descriptions. If a cache parameter group name is specified, the list contains only the descriptions for that group.</p>
-    async fn describe_cache_parameter_groups(
+    /// Presigns the request `describe_cache_engine_versions` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_cache_engine_versions(
         &self,
-        input: DescribeCacheParameterGroupsMessage,
-    ) -> Result<CacheParameterGroupsMessage, RusotoError<DescribeCacheParameterGroupsError>> {
+        presigner: &rusoto_core::Presigner,
+        input: DescribeCacheEngineVersionsMessage,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_cache_engine_versions_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_cache_engine_versions_request(
+        &self,
+        input: DescribeCacheEngineVersionsMessage,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "elasticache", &self.region, "/");
+        request.set_operation("DescribeCacheEngineVersions");
         let mut params = Params::new();
 
-        params.put("Action", "DescribeCacheParameterGroups");
+        params.put("Action", "DescribeCacheEngineVersions");
         params.put("Version", "2015-02-02");
-        DescribeCacheParameterGroupsMessageSerializer::serialize(&mut params, "", &input);
+        DescribeCacheEngineVersionsMessageSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `describe_cache_parameter_groups`, also returning the metadata of the response.
+    pub async fn describe_cache_parameter_groups_with_metadata(
+        &self,
+        input: DescribeCacheParameterGroupsMessage,
+    ) -> Result<
+        (CacheParameterGroupsMessage, ResponseMetadata),
+        RusotoError<DescribeCacheParameterGroupsError>,
+    > {
+        let request = self.build_describe_cache_parameter_groups_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(DescribeCacheParameterGroupsError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -13227,34 +14325,64 @@ impl ElastiCache for ElastiCacheClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Returns the detailed parameter list for a particular cache parameter group.</p>
-    async fn describe_cache_parameters(
+    /// Presigns the request `describe_cache_parameter_groups` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_cache_parameter_groups(
         &self,
-        input: DescribeCacheParametersMessage,
-    ) -> Result<CacheParameterGroupDetails, RusotoError<DescribeCacheParametersError>> {
+        presigner: &rusoto_core::Presigner,
+        input: DescribeCacheParameterGroupsMessage,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_cache_parameter_groups_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_cache_parameter_groups_request(
+        &self,
+        input: DescribeCacheParameterGroupsMessage,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "elasticache", &self.region, "/");
+        request.set_operation("DescribeCacheParameterGroups");
         let mut params = Params::new();
 
-        params.put("Action", "DescribeCacheParameters");
+        params.put("Action", "DescribeCacheParameterGroups");
         params.put("Version", "2015-02-02");
-        DescribeCacheParametersMessageSerializer::serialize(&mut params, "", &input);
+        DescribeCacheParameterGroupsMessageSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `describe_cache_parameters`, also returning the metadata of the response.
+    pub async fn describe_cache_parameters_with_metadata(
+        &self,
+        input: DescribeCacheParametersMessage,
+    ) -> Result<
+        (CacheParameterGroupDetails, ResponseMetadata),
+        RusotoError<DescribeCacheParametersError>,
+    > {
+        let request = self.build_describe_cache_parameters_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(DescribeCacheParametersError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -13276,34 +14404,64 @@ impl ElastiCache for ElastiCacheClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Returns a list of cache security group descriptions. If a cache security group name is specified, the list contains only the description of that group. This applicable only when you have ElastiCache in Classic setup </p>
-    async fn describe_cache_security_groups(
+    /// Presigns the request `describe_cache_parameters` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_cache_parameters(
         &self,
-        input: DescribeCacheSecurityGroupsMessage,
-    ) -> Result<CacheSecurityGroupMessage, RusotoError<DescribeCacheSecurityGroupsError>> {
+        presigner: &rusoto_core::Presigner,
+        input: DescribeCacheParametersMessage,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_cache_parameters_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_cache_parameters_request(
+        &self,
+        input: DescribeCacheParametersMessage,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "elasticache", &self.region, "/");
+        request.set_operation("DescribeCacheParameters");
         let mut params = Params::new();
 
-        params.put("Action", "DescribeCacheSecurityGroups");
+        params.put("Action", "DescribeCacheParameters");
         params.put("Version", "2015-02-02");
-        DescribeCacheSecurityGroupsMessageSerializer::serialize(&mut params, "", &input);
+        DescribeCacheParametersMessageSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `describe_cache_security_groups`, also returning the metadata of the response.
+    pub async fn describe_cache_security_groups_with_metadata(
+        &self,
+        input: DescribeCacheSecurityGroupsMessage,
+    ) -> Result<
+        (CacheSecurityGroupMessage, ResponseMetadata),
+        RusotoError<DescribeCacheSecurityGroupsError>,
+    > {
+        let request = self.build_describe_cache_security_groups_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(DescribeCacheSecurityGroupsError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -13325,34 +14483,64 @@ impl ElastiCache for ElastiCacheClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Returns a list of cache subnet group descriptions. If a subnet group name is specified, the list contains only the description of that group. This is applicable only when you have ElastiCache in VPC setup. All ElastiCache clusters now launch in VPC by default. </p>
-    async fn describe_cache_subnet_groups(
+    /// Presigns the request `describe_cache_security_groups` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_cache_security_groups(
         &self,
-        input: DescribeCacheSubnetGroupsMessage,
-    ) -> Result<CacheSubnetGroupMessage, RusotoError<DescribeCacheSubnetGroupsError>> {
+        presigner: &rusoto_core::Presigner,
+        input: DescribeCacheSecurityGroupsMessage,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_cache_security_groups_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_cache_security_groups_request(
+        &self,
+        input: DescribeCacheSecurityGroupsMessage,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "elasticache", &self.region, "/");
+        request.set_operation("DescribeCacheSecurityGroups");
         let mut params = Params::new();
 
-        params.put("Action", "DescribeCacheSubnetGroups");
+        params.put("Action", "DescribeCacheSecurityGroups");
         params.put("Version", "2015-02-02");
-        DescribeCacheSubnetGroupsMessageSerializer::serialize(&mut params, "", &input);
+        DescribeCacheSecurityGroupsMessageSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `describe_cache_subnet_groups`, also returning the metadata of the response.
+    pub async fn describe_cache_subnet_groups_with_metadata(
+        &self,
+        input: DescribeCacheSubnetGroupsMessage,
+    ) -> Result<
+        (CacheSubnetGroupMessage, ResponseMetadata),
+        RusotoError<DescribeCacheSubnetGroupsError>,
+    > {
+        let request = self.build_describe_cache_subnet_groups_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(DescribeCacheSubnetGroupsError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -13374,39 +14562,66 @@ impl ElastiCache for ElastiCacheClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Returns the default engine and system parameter information for the specified cache engine.</p>
-    async fn describe_engine_default_parameters(
+    /// Presigns the request `describe_cache_subnet_groups` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_cache_subnet_groups(
         &self,
-        input: DescribeEngineDefaultParametersMessage,
-    ) -> Result<
-        DescribeEngineDefaultParametersResult,
-        RusotoError<DescribeEngineDefaultParametersError>,
-    > {
+        presigner: &rusoto_core::Presigner,
+        input: DescribeCacheSubnetGroupsMessage,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_cache_subnet_groups_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_cache_subnet_groups_request(
+        &self,
+        input: DescribeCacheSubnetGroupsMessage,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "elasticache", &self.region, "/");
+        request.set_operation("DescribeCacheSubnetGroups");
         let mut params = Params::new();
 
-        params.put("Action", "DescribeEngineDefaultParameters");
+        params.put("Action", "DescribeCacheSubnetGroups");
         params.put("Version", "2015-02-02");
-        DescribeEngineDefaultParametersMessageSerializer::serialize(&mut params, "", &input);
+        DescribeCacheSubnetGroupsMessageSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `describe_engine_default_parameters`, also returning the metadata of the response.
+    pub async fn describe_engine_default_parameters_with_metadata(
+        &self,
+        input: DescribeEngineDefaultParametersMessage,
+    ) -> Result<
+        (DescribeEngineDefaultParametersResult, ResponseMetadata),
+        RusotoError<DescribeEngineDefaultParametersError>,
+    > {
+        let request = self.build_describe_engine_default_parameters_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(DescribeEngineDefaultParametersError::from_response(
                 response,
             ));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -13428,34 +14643,61 @@ impl ElastiCache for ElastiCacheClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Returns events related to clusters, cache security groups, and cache parameter groups. You can obtain events specific to a particular cluster, cache security group, or cache parameter group by providing the name as a parameter.</p> <p>By default, only the events occurring within the last hour are returned; however, you can retrieve up to 14 days' worth of events if necessary.</p>
-    async fn describe_events(
+    /// Presigns the request `describe_engine_default_parameters` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_engine_default_parameters(
         &self,
-        input: DescribeEventsMessage,
-    ) -> Result<EventsMessage, RusotoError<DescribeEventsError>> {
+        presigner: &rusoto_core::Presigner,
+        input: DescribeEngineDefaultParametersMessage,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_engine_default_parameters_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_engine_default_parameters_request(
+        &self,
+        input: DescribeEngineDefaultParametersMessage,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "elasticache", &self.region, "/");
+        request.set_operation("DescribeEngineDefaultParameters");
         let mut params = Params::new();
 
-        params.put("Action", "DescribeEvents");
+        params.put("Action", "DescribeEngineDefaultParameters");
         params.put("Version", "2015-02-02");
-        DescribeEventsMessageSerializer::serialize(&mut params, "", &input);
+        DescribeEngineDefaultParametersMessageSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `describe_events`, also returning the metadata of the response.
+    pub async fn describe_events_with_metadata(
+        &self,
+        input: DescribeEventsMessage,
+    ) -> Result<(EventsMessage, ResponseMetadata), RusotoError<DescribeEventsError>> {
+        let request = self.build_describe_events_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(DescribeEventsError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -13474,34 +14716,61 @@ impl ElastiCache for ElastiCacheClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p><p>Returns information about a particular replication group. If no identifier is specified, <code>DescribeReplicationGroups</code> returns information about all replication groups.</p> <note> <p>This operation is valid for Redis only.</p> </note></p>
-    async fn describe_replication_groups(
+    /// Presigns the request `describe_events` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_events(
         &self,
-        input: DescribeReplicationGroupsMessage,
-    ) -> Result<ReplicationGroupMessage, RusotoError<DescribeReplicationGroupsError>> {
+        presigner: &rusoto_core::Presigner,
+        input: DescribeEventsMessage,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_events_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_events_request(&self, input: DescribeEventsMessage) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "elasticache", &self.region, "/");
+        request.set_operation("DescribeEvents");
         let mut params = Params::new();
 
-        params.put("Action", "DescribeReplicationGroups");
+        params.put("Action", "DescribeEvents");
         params.put("Version", "2015-02-02");
-        DescribeReplicationGroupsMessageSerializer::serialize(&mut params, "", &input);
+        DescribeEventsMessageSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `describe_replication_groups`, also returning the metadata of the response.
+    pub async fn describe_replication_groups_with_metadata(
+        &self,
+        input: DescribeReplicationGroupsMessage,
+    ) -> Result<
+        (ReplicationGroupMessage, ResponseMetadata),
+        RusotoError<DescribeReplicationGroupsError>,
+    > {
+        let request = self.build_describe_replication_groups_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(DescribeReplicationGroupsError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -13523,34 +14792,64 @@ impl ElastiCache for ElastiCacheClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Returns information about reserved cache nodes for this account, or about a specified reserved cache node.</p>
-    async fn describe_reserved_cache_nodes(
+    /// Presigns the request `describe_replication_groups` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_replication_groups(
         &self,
-        input: DescribeReservedCacheNodesMessage,
-    ) -> Result<ReservedCacheNodeMessage, RusotoError<DescribeReservedCacheNodesError>> {
+        presigner: &rusoto_core::Presigner,
+        input: DescribeReplicationGroupsMessage,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_replication_groups_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_replication_groups_request(
+        &self,
+        input: DescribeReplicationGroupsMessage,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "elasticache", &self.region, "/");
+        request.set_operation("DescribeReplicationGroups");
         let mut params = Params::new();
 
-        params.put("Action", "DescribeReservedCacheNodes");
+        params.put("Action", "DescribeReplicationGroups");
         params.put("Version", "2015-02-02");
-        DescribeReservedCacheNodesMessageSerializer::serialize(&mut params, "", &input);
+        DescribeReplicationGroupsMessageSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `describe_reserved_cache_nodes`, also returning the metadata of the response.
+    pub async fn describe_reserved_cache_nodes_with_metadata(
+        &self,
+        input: DescribeReservedCacheNodesMessage,
+    ) -> Result<
+        (ReservedCacheNodeMessage, ResponseMetadata),
+        RusotoError<DescribeReservedCacheNodesError>,
+    > {
+        let request = self.build_describe_reserved_cache_nodes_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(DescribeReservedCacheNodesError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -13572,39 +14871,66 @@ impl ElastiCache for ElastiCacheClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Lists available reserved cache node offerings.</p>
-    async fn describe_reserved_cache_nodes_offerings(
+    /// Presigns the request `describe_reserved_cache_nodes` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_reserved_cache_nodes(
         &self,
-        input: DescribeReservedCacheNodesOfferingsMessage,
-    ) -> Result<
-        ReservedCacheNodesOfferingMessage,
-        RusotoError<DescribeReservedCacheNodesOfferingsError>,
-    > {
+        presigner: &rusoto_core::Presigner,
+        input: DescribeReservedCacheNodesMessage,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_reserved_cache_nodes_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_reserved_cache_nodes_request(
+        &self,
+        input: DescribeReservedCacheNodesMessage,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "elasticache", &self.region, "/");
+        request.set_operation("DescribeReservedCacheNodes");
         let mut params = Params::new();
 
-        params.put("Action", "DescribeReservedCacheNodesOfferings");
+        params.put("Action", "DescribeReservedCacheNodes");
         params.put("Version", "2015-02-02");
-        DescribeReservedCacheNodesOfferingsMessageSerializer::serialize(&mut params, "", &input);
+        DescribeReservedCacheNodesMessageSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `describe_reserved_cache_nodes_offerings`, also returning the metadata of the response.
+    pub async fn describe_reserved_cache_nodes_offerings_with_metadata(
+        &self,
+        input: DescribeReservedCacheNodesOfferingsMessage,
+    ) -> Result<
+        (ReservedCacheNodesOfferingMessage, ResponseMetadata),
+        RusotoError<DescribeReservedCacheNodesOfferingsError>,
+    > {
+        let request = self.build_describe_reserved_cache_nodes_offerings_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(DescribeReservedCacheNodesOfferingsError::from_response(
                 response,
             ));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -13626,34 +14952,62 @@ impl ElastiCache for ElastiCacheClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Returns details of the service updates</p>
-    async fn describe_service_updates(
+    /// Presigns the request `describe_reserved_cache_nodes_offerings` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_reserved_cache_nodes_offerings(
         &self,
-        input: DescribeServiceUpdatesMessage,
-    ) -> Result<ServiceUpdatesMessage, RusotoError<DescribeServiceUpdatesError>> {
+        presigner: &rusoto_core::Presigner,
+        input: DescribeReservedCacheNodesOfferingsMessage,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_reserved_cache_nodes_offerings_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_reserved_cache_nodes_offerings_request(
+        &self,
+        input: DescribeReservedCacheNodesOfferingsMessage,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "elasticache", &self.region, "/");
+        request.set_operation("DescribeReservedCacheNodesOfferings");
         let mut params = Params::new();
 
-        params.put("Action", "DescribeServiceUpdates");
+        params.put("Action", "DescribeReservedCacheNodesOfferings");
         params.put("Version", "2015-02-02");
-        DescribeServiceUpdatesMessageSerializer::serialize(&mut params, "", &input);
+        DescribeReservedCacheNodesOfferingsMessageSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `describe_service_updates`, also returning the metadata of the response.
+    pub async fn describe_service_updates_with_metadata(
+        &self,
+        input: DescribeServiceUpdatesMessage,
+    ) -> Result<(ServiceUpdatesMessage, ResponseMetadata), RusotoError<DescribeServiceUpdatesError>>
+    {
+        let request = self.build_describe_service_updates_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(DescribeServiceUpdatesError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -13675,34 +15029,62 @@ impl ElastiCache for ElastiCacheClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p><p>Returns information about cluster or replication group snapshots. By default, <code>DescribeSnapshots</code> lists all of your snapshots; it can optionally describe a single snapshot, or just the snapshots associated with a particular cache cluster.</p> <note> <p>This operation is valid for Redis only.</p> </note></p>
-    async fn describe_snapshots(
+    /// Presigns the request `describe_service_updates` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_service_updates(
         &self,
-        input: DescribeSnapshotsMessage,
-    ) -> Result<DescribeSnapshotsListMessage, RusotoError<DescribeSnapshotsError>> {
+        presigner: &rusoto_core::Presigner,
+        input: DescribeServiceUpdatesMessage,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_service_updates_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_service_updates_request(
+        &self,
+        input: DescribeServiceUpdatesMessage,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "elasticache", &self.region, "/");
+        request.set_operation("DescribeServiceUpdates");
         let mut params = Params::new();
 
-        params.put("Action", "DescribeSnapshots");
+        params.put("Action", "DescribeServiceUpdates");
         params.put("Version", "2015-02-02");
-        DescribeSnapshotsMessageSerializer::serialize(&mut params, "", &input);
+        DescribeServiceUpdatesMessageSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `describe_snapshots`, also returning the metadata of the response.
+    pub async fn describe_snapshots_with_metadata(
+        &self,
+        input: DescribeSnapshotsMessage,
+    ) -> Result<(DescribeSnapshotsListMessage, ResponseMetadata), RusotoError<DescribeSnapshotsError>>
+    {
+        let request = self.build_describe_snapshots_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(DescribeSnapshotsError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -13724,34 +15106,59 @@ impl ElastiCache for ElastiCacheClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Returns details of the update actions </p>
-    async fn describe_update_actions(
+    /// Presigns the request `describe_snapshots` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_snapshots(
         &self,
-        input: DescribeUpdateActionsMessage,
-    ) -> Result<UpdateActionsMessage, RusotoError<DescribeUpdateActionsError>> {
+        presigner: &rusoto_core::Presigner,
+        input: DescribeSnapshotsMessage,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_snapshots_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_snapshots_request(&self, input: DescribeSnapshotsMessage) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "elasticache", &self.region, "/");
+        request.set_operation("DescribeSnapshots");
         let mut params = Params::new();
 
-        params.put("Action", "DescribeUpdateActions");
+        params.put("Action", "DescribeSnapshots");
         params.put("Version", "2015-02-02");
-        DescribeUpdateActionsMessageSerializer::serialize(&mut params, "", &input);
+        DescribeSnapshotsMessageSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `describe_update_actions`, also returning the metadata of the response.
+    pub async fn describe_update_actions_with_metadata(
+        &self,
+        input: DescribeUpdateActionsMessage,
+    ) -> Result<(UpdateActionsMessage, ResponseMetadata), RusotoError<DescribeUpdateActionsError>>
+    {
+        let request = self.build_describe_update_actions_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(DescribeUpdateActionsError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -13773,34 +15180,64 @@ impl ElastiCache for ElastiCacheClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Dynamically increases the number of replics in a Redis (cluster mode disabled) replication group or the number of replica nodes in one or more node groups (shards) of a Redis (cluster mode enabled) replication group. This operation is performed with no cluster down time.</p>
-    async fn increase_replica_count(
+    /// Presigns the request `describe_update_actions` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_update_actions(
         &self,
-        input: IncreaseReplicaCountMessage,
-    ) -> Result<IncreaseReplicaCountResult, RusotoError<IncreaseReplicaCountError>> {
+        presigner: &rusoto_core::Presigner,
+        input: DescribeUpdateActionsMessage,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_update_actions_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_update_actions_request(
+        &self,
+        input: DescribeUpdateActionsMessage,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "elasticache", &self.region, "/");
+        request.set_operation("DescribeUpdateActions");
         let mut params = Params::new();
 
-        params.put("Action", "IncreaseReplicaCount");
+        params.put("Action", "DescribeUpdateActions");
         params.put("Version", "2015-02-02");
-        IncreaseReplicaCountMessageSerializer::serialize(&mut params, "", &input);
+        DescribeUpdateActionsMessageSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `increase_replica_count`, also returning the metadata of the response.
+    pub async fn increase_replica_count_with_metadata(
+        &self,
+        input: IncreaseReplicaCountMessage,
+    ) -> Result<
+        (IncreaseReplicaCountResult, ResponseMetadata),
+        RusotoError<IncreaseReplicaCountError>,
+    > {
+        let request = self.build_increase_replica_count_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(IncreaseReplicaCountError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -13822,39 +15259,66 @@ impl ElastiCache for ElastiCacheClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Lists all available node types that you can scale your Redis cluster's or replication group's current node type.</p> <p>When you use the <code>ModifyCacheCluster</code> or <code>ModifyReplicationGroup</code> operations to scale your cluster or replication group, the value of the <code>CacheNodeType</code> parameter must be one of the node types returned by this operation.</p>
-    async fn list_allowed_node_type_modifications(
+    /// Presigns the request `increase_replica_count` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_increase_replica_count(
         &self,
-        input: ListAllowedNodeTypeModificationsMessage,
-    ) -> Result<
-        AllowedNodeTypeModificationsMessage,
-        RusotoError<ListAllowedNodeTypeModificationsError>,
-    > {
+        presigner: &rusoto_core::Presigner,
+        input: IncreaseReplicaCountMessage,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_increase_replica_count_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_increase_replica_count_request(
+        &self,
+        input: IncreaseReplicaCountMessage,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "elasticache", &self.region, "/");
+        request.set_operation("IncreaseReplicaCount");
         let mut params = Params::new();
 
-        params.put("Action", "ListAllowedNodeTypeModifications");
+        params.put("Action", "IncreaseReplicaCount");
         params.put("Version", "2015-02-02");
-        ListAllowedNodeTypeModificationsMessageSerializer::serialize(&mut params, "", &input);
+        IncreaseReplicaCountMessageSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `list_allowed_node_type_modifications`, also returning the metadata of the response.
+    pub async fn list_allowed_node_type_modifications_with_metadata(
+        &self,
+        input: ListAllowedNodeTypeModificationsMessage,
+    ) -> Result<
+        (AllowedNodeTypeModificationsMessage, ResponseMetadata),
+        RusotoError<ListAllowedNodeTypeModificationsError>,
+    > {
+        let request = self.build_list_allowed_node_type_modifications_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(ListAllowedNodeTypeModificationsError::from_response(
                 response,
             ));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -13876,34 +15340,61 @@ impl ElastiCache for ElastiCacheClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Lists all cost allocation tags currently on the named resource. A <code>cost allocation tag</code> is a key-value pair where the key is case-sensitive and the value is optional. You can use cost allocation tags to categorize and track your AWS costs.</p> <p>If the cluster is not in the <i>available</i> state, <code>ListTagsForResource</code> returns an error.</p> <p>You can have a maximum of 50 cost allocation tags on an ElastiCache resource. For more information, see <a href="https://docs.aws.amazon.com/AmazonElastiCache/latest/red-ug/Tagging.html">Monitoring Costs with Tags</a>.</p>
-    async fn list_tags_for_resource(
+    /// Presigns the request `list_allowed_node_type_modifications` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_allowed_node_type_modifications(
         &self,
-        input: ListTagsForResourceMessage,
-    ) -> Result<TagListMessage, RusotoError<ListTagsForResourceError>> {
+        presigner: &rusoto_core::Presigner,
+        input: ListAllowedNodeTypeModificationsMessage,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_allowed_node_type_modifications_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_allowed_node_type_modifications_request(
+        &self,
+        input: ListAllowedNodeTypeModificationsMessage,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "elasticache", &self.region, "/");
+        request.set_operation("ListAllowedNodeTypeModifications");
         let mut params = Params::new();
 
-        params.put("Action", "ListTagsForResource");
+        params.put("Action", "ListAllowedNodeTypeModifications");
         params.put("Version", "2015-02-02");
-        ListTagsForResourceMessageSerializer::serialize(&mut params, "", &input);
+        ListAllowedNodeTypeModificationsMessageSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `list_tags_for_resource`, also returning the metadata of the response.
+    pub async fn list_tags_for_resource_with_metadata(
+        &self,
+        input: ListTagsForResourceMessage,
+    ) -> Result<(TagListMessage, ResponseMetadata), RusotoError<ListTagsForResourceError>> {
+        let request = self.build_list_tags_for_resource_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(ListTagsForResourceError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -13923,34 +15414,62 @@ impl ElastiCache for ElastiCacheClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Modifies the settings for a cluster. You can use this operation to change one or more cluster configuration parameters by specifying the parameters and the new values.</p>
-    async fn modify_cache_cluster(
+    /// Presigns the request `list_tags_for_resource` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_tags_for_resource(
         &self,
-        input: ModifyCacheClusterMessage,
-    ) -> Result<ModifyCacheClusterResult, RusotoError<ModifyCacheClusterError>> {
+        presigner: &rusoto_core::Presigner,
+        input: ListTagsForResourceMessage,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_tags_for_resource_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_tags_for_resource_request(
+        &self,
+        input: ListTagsForResourceMessage,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "elasticache", &self.region, "/");
+        request.set_operation("ListTagsForResource");
         let mut params = Params::new();
 
-        params.put("Action", "ModifyCacheCluster");
+        params.put("Action", "ListTagsForResource");
         params.put("Version", "2015-02-02");
-        ModifyCacheClusterMessageSerializer::serialize(&mut params, "", &input);
+        ListTagsForResourceMessageSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `modify_cache_cluster`, also returning the metadata of the response.
+    pub async fn modify_cache_cluster_with_metadata(
+        &self,
+        input: ModifyCacheClusterMessage,
+    ) -> Result<(ModifyCacheClusterResult, ResponseMetadata), RusotoError<ModifyCacheClusterError>>
+    {
+        let request = self.build_modify_cache_cluster_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(ModifyCacheClusterError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -13972,34 +15491,64 @@ impl ElastiCache for ElastiCacheClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Modifies the parameters of a cache parameter group. You can modify up to 20 parameters in a single request by submitting a list parameter name and value pairs.</p>
-    async fn modify_cache_parameter_group(
+    /// Presigns the request `modify_cache_cluster` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_modify_cache_cluster(
         &self,
-        input: ModifyCacheParameterGroupMessage,
-    ) -> Result<CacheParameterGroupNameMessage, RusotoError<ModifyCacheParameterGroupError>> {
+        presigner: &rusoto_core::Presigner,
+        input: ModifyCacheClusterMessage,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_modify_cache_cluster_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_modify_cache_cluster_request(
+        &self,
+        input: ModifyCacheClusterMessage,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "elasticache", &self.region, "/");
+        request.set_operation("ModifyCacheCluster");
         let mut params = Params::new();
 
-        params.put("Action", "ModifyCacheParameterGroup");
+        params.put("Action", "ModifyCacheCluster");
         params.put("Version", "2015-02-02");
-        ModifyCacheParameterGroupMessageSerializer::serialize(&mut params, "", &input);
+        ModifyCacheClusterMessageSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `modify_cache_parameter_group`, also returning the metadata of the response.
+    pub async fn modify_cache_parameter_group_with_metadata(
+        &self,
+        input: ModifyCacheParameterGroupMessage,
+    ) -> Result<
+        (CacheParameterGroupNameMessage, ResponseMetadata),
+        RusotoError<ModifyCacheParameterGroupError>,
+    > {
+        let request = self.build_modify_cache_parameter_group_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(ModifyCacheParameterGroupError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -14021,34 +15570,64 @@ impl ElastiCache for ElastiCacheClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Modifies an existing cache subnet group.</p>
-    async fn modify_cache_subnet_group(
+    /// Presigns the request `modify_cache_parameter_group` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_modify_cache_parameter_group(
         &self,
-        input: ModifyCacheSubnetGroupMessage,
-    ) -> Result<ModifyCacheSubnetGroupResult, RusotoError<ModifyCacheSubnetGroupError>> {
+        presigner: &rusoto_core::Presigner,
+        input: ModifyCacheParameterGroupMessage,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_modify_cache_parameter_group_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_modify_cache_parameter_group_request(
+        &self,
+        input: ModifyCacheParameterGroupMessage,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "elasticache", &self.region, "/");
+        request.set_operation("ModifyCacheParameterGroup");
         let mut params = Params::new();
 
-        params.put("Action", "ModifyCacheSubnetGroup");
+        params.put("Action", "ModifyCacheParameterGroup");
         params.put("Version", "2015-02-02");
-        ModifyCacheSubnetGroupMessageSerializer::serialize(&mut params, "", &input);
+        ModifyCacheParameterGroupMessageSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `modify_cache_subnet_group`, also returning the metadata of the response.
+    pub async fn modify_cache_subnet_group_with_metadata(
+        &self,
+        input: ModifyCacheSubnetGroupMessage,
+    ) -> Result<
+        (ModifyCacheSubnetGroupResult, ResponseMetadata),
+        RusotoError<ModifyCacheSubnetGroupError>,
+    > {
+        let request = self.build_modify_cache_subnet_group_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(ModifyCacheSubnetGroupError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -14070,34 +15649,64 @@ impl ElastiCache for ElastiCacheClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p><p>Modifies the settings for a replication group.</p> <p>For Redis (cluster mode enabled) clusters, this operation cannot be used to change a cluster&#39;s node type or engine version. For more information, see:</p> <ul> <li> <p> <a href="https://docs.aws.amazon.com/AmazonElastiCache/latest/red-ug/scaling-redis-cluster-mode-enabled.html">Scaling for Amazon ElastiCache for Redis (cluster mode enabled)</a> in the ElastiCache User Guide</p> </li> <li> <p> <a href="https://docs.aws.amazon.com/AmazonElastiCache/latest/APIReference/API_ModifyReplicationGroupShardConfiguration.html">ModifyReplicationGroupShardConfiguration</a> in the ElastiCache API Reference</p> </li> </ul> <note> <p>This operation is valid for Redis only.</p> </note></p>
-    async fn modify_replication_group(
+    /// Presigns the request `modify_cache_subnet_group` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_modify_cache_subnet_group(
         &self,
-        input: ModifyReplicationGroupMessage,
-    ) -> Result<ModifyReplicationGroupResult, RusotoError<ModifyReplicationGroupError>> {
+        presigner: &rusoto_core::Presigner,
+        input: ModifyCacheSubnetGroupMessage,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_modify_cache_subnet_group_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_modify_cache_subnet_group_request(
+        &self,
+        input: ModifyCacheSubnetGroupMessage,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "elasticache", &self.region, "/");
+        request.set_operation("ModifyCacheSubnetGroup");
         let mut params = Params::new();
 
-        params.put("Action", "ModifyReplicationGroup");
+        params.put("Action", "ModifyCacheSubnetGroup");
         params.put("Version", "2015-02-02");
-        ModifyReplicationGroupMessageSerializer::serialize(&mut params, "", &input);
+        ModifyCacheSubnetGroupMessageSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `modify_replication_group`, also returning the metadata of the response.
+    pub async fn modify_replication_group_with_metadata(
+        &self,
+        input: ModifyReplicationGroupMessage,
+    ) -> Result<
+        (ModifyReplicationGroupResult, ResponseMetadata),
+        RusotoError<ModifyReplicationGroupError>,
+    > {
+        let request = self.build_modify_replication_group_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(ModifyReplicationGroupError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -14119,41 +15728,67 @@ impl ElastiCache for ElastiCacheClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Modifies a replication group's shards (node groups) by allowing you to add shards, remove shards, or rebalance the keyspaces among exisiting shards.</p>
-    async fn modify_replication_group_shard_configuration(
+    /// Presigns the request `modify_replication_group` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_modify_replication_group(
         &self,
-        input: ModifyReplicationGroupShardConfigurationMessage,
-    ) -> Result<
-        ModifyReplicationGroupShardConfigurationResult,
-        RusotoError<ModifyReplicationGroupShardConfigurationError>,
-    > {
+        presigner: &rusoto_core::Presigner,
+        input: ModifyReplicationGroupMessage,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_modify_replication_group_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_modify_replication_group_request(
+        &self,
+        input: ModifyReplicationGroupMessage,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "elasticache", &self.region, "/");
+        request.set_operation("ModifyReplicationGroup");
         let mut params = Params::new();
 
-        params.put("Action", "ModifyReplicationGroupShardConfiguration");
+        params.put("Action", "ModifyReplicationGroup");
         params.put("Version", "2015-02-02");
-        ModifyReplicationGroupShardConfigurationMessageSerializer::serialize(
-            &mut params,
-            "",
-            &input,
-        );
+        ModifyReplicationGroupMessageSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `modify_replication_group_shard_configuration`, also returning the metadata of the response.
+    pub async fn modify_replication_group_shard_configuration_with_metadata(
+        &self,
+        input: ModifyReplicationGroupShardConfigurationMessage,
+    ) -> Result<
+        (
+            ModifyReplicationGroupShardConfigurationResult,
+            ResponseMetadata,
+        ),
+        RusotoError<ModifyReplicationGroupShardConfigurationError>,
+    > {
+        let request = self.build_modify_replication_group_shard_configuration_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(ModifyReplicationGroupShardConfigurationError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -14175,39 +15810,70 @@ impl ElastiCache for ElastiCacheClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Allows you to purchase a reserved cache node offering.</p>
-    async fn purchase_reserved_cache_nodes_offering(
+    /// Presigns the request `modify_replication_group_shard_configuration` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_modify_replication_group_shard_configuration(
         &self,
-        input: PurchaseReservedCacheNodesOfferingMessage,
-    ) -> Result<
-        PurchaseReservedCacheNodesOfferingResult,
-        RusotoError<PurchaseReservedCacheNodesOfferingError>,
-    > {
+        presigner: &rusoto_core::Presigner,
+        input: ModifyReplicationGroupShardConfigurationMessage,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_modify_replication_group_shard_configuration_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_modify_replication_group_shard_configuration_request(
+        &self,
+        input: ModifyReplicationGroupShardConfigurationMessage,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "elasticache", &self.region, "/");
+        request.set_operation("ModifyReplicationGroupShardConfiguration");
         let mut params = Params::new();
 
-        params.put("Action", "PurchaseReservedCacheNodesOffering");
+        params.put("Action", "ModifyReplicationGroupShardConfiguration");
         params.put("Version", "2015-02-02");
-        PurchaseReservedCacheNodesOfferingMessageSerializer::serialize(&mut params, "", &input);
+        ModifyReplicationGroupShardConfigurationMessageSerializer::serialize(
+            &mut params,
+            "",
+            &input,
+        );
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `purchase_reserved_cache_nodes_offering`, also returning the metadata of the response.
+    pub async fn purchase_reserved_cache_nodes_offering_with_metadata(
+        &self,
+        input: PurchaseReservedCacheNodesOfferingMessage,
+    ) -> Result<
+        (PurchaseReservedCacheNodesOfferingResult, ResponseMetadata),
+        RusotoError<PurchaseReservedCacheNodesOfferingError>,
+    > {
+        let request = self.build_purchase_reserved_cache_nodes_offering_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(PurchaseReservedCacheNodesOfferingError::from_response(
                 response,
             ));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -14229,34 +15895,62 @@ impl ElastiCache for ElastiCacheClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Reboots some, or all, of the cache nodes within a provisioned cluster. This operation applies any modified cache parameter groups to the cluster. The reboot operation takes place as soon as possible, and results in a momentary outage to the cluster. During the reboot, the cluster status is set to REBOOTING.</p> <p>The reboot causes the contents of the cache (for each cache node being rebooted) to be lost.</p> <p>When the reboot is complete, a cluster event is created.</p> <p>Rebooting a cluster is currently supported on Memcached and Redis (cluster mode disabled) clusters. Rebooting is not supported on Redis (cluster mode enabled) clusters.</p> <p>If you make changes to parameters that require a Redis (cluster mode enabled) cluster reboot for the changes to be applied, see <a href="http://docs.aws.amazon.com/AmazonElastiCache/latest/red-ug/Clusters.Rebooting.html">Rebooting a Cluster</a> for an alternate process.</p>
-    async fn reboot_cache_cluster(
+    /// Presigns the request `purchase_reserved_cache_nodes_offering` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_purchase_reserved_cache_nodes_offering(
         &self,
-        input: RebootCacheClusterMessage,
-    ) -> Result<RebootCacheClusterResult, RusotoError<RebootCacheClusterError>> {
+        presigner: &rusoto_core::Presigner,
+        input: PurchaseReservedCacheNodesOfferingMessage,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_purchase_reserved_cache_nodes_offering_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_purchase_reserved_cache_nodes_offering_request(
+        &self,
+        input: PurchaseReservedCacheNodesOfferingMessage,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "elasticache", &self.region, "/");
+        request.set_operation("PurchaseReservedCacheNodesOffering");
         let mut params = Params::new();
 
-        params.put("Action", "RebootCacheCluster");
+        params.put("Action", "PurchaseReservedCacheNodesOffering");
         params.put("Version", "2015-02-02");
-        RebootCacheClusterMessageSerializer::serialize(&mut params, "", &input);
+        PurchaseReservedCacheNodesOfferingMessageSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `reboot_cache_cluster`, also returning the metadata of the response.
+    pub async fn reboot_cache_cluster_with_metadata(
+        &self,
+        input: RebootCacheClusterMessage,
+    ) -> Result<(RebootCacheClusterResult, ResponseMetadata), RusotoError<RebootCacheClusterError>>
+    {
+        let request = self.build_reboot_cache_cluster_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(RebootCacheClusterError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -14278,34 +15972,61 @@ impl ElastiCache for ElastiCacheClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Removes the tags identified by the <code>TagKeys</code> list from the named resource.</p>
-    async fn remove_tags_from_resource(
+    /// Presigns the request `reboot_cache_cluster` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_reboot_cache_cluster(
         &self,
-        input: RemoveTagsFromResourceMessage,
-    ) -> Result<TagListMessage, RusotoError<RemoveTagsFromResourceError>> {
+        presigner: &rusoto_core::Presigner,
+        input: RebootCacheClusterMessage,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_reboot_cache_cluster_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_reboot_cache_cluster_request(
+        &self,
+        input: RebootCacheClusterMessage,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "elasticache", &self.region, "/");
+        request.set_operation("RebootCacheCluster");
         let mut params = Params::new();
 
-        params.put("Action", "RemoveTagsFromResource");
+        params.put("Action", "RebootCacheCluster");
         params.put("Version", "2015-02-02");
-        RemoveTagsFromResourceMessageSerializer::serialize(&mut params, "", &input);
+        RebootCacheClusterMessageSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `remove_tags_from_resource`, also returning the metadata of the response.
+    pub async fn remove_tags_from_resource_with_metadata(
+        &self,
+        input: RemoveTagsFromResourceMessage,
+    ) -> Result<(TagListMessage, ResponseMetadata), RusotoError<RemoveTagsFromResourceError>> {
+        let request = self.build_remove_tags_from_resource_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(RemoveTagsFromResourceError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -14327,34 +16048,64 @@ impl ElastiCache for ElastiCacheClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Modifies the parameters of a cache parameter group to the engine or system default value. You can reset specific parameters by submitting a list of parameter names. To reset the entire cache parameter group, specify the <code>ResetAllParameters</code> and <code>CacheParameterGroupName</code> parameters.</p>
-    async fn reset_cache_parameter_group(
+    /// Presigns the request `remove_tags_from_resource` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_remove_tags_from_resource(
         &self,
-        input: ResetCacheParameterGroupMessage,
-    ) -> Result<CacheParameterGroupNameMessage, RusotoError<ResetCacheParameterGroupError>> {
+        presigner: &rusoto_core::Presigner,
+        input: RemoveTagsFromResourceMessage,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_remove_tags_from_resource_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_remove_tags_from_resource_request(
+        &self,
+        input: RemoveTagsFromResourceMessage,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "elasticache", &self.region, "/");
+        request.set_operation("RemoveTagsFromResource");
         let mut params = Params::new();
 
-        params.put("Action", "ResetCacheParameterGroup");
+        params.put("Action", "RemoveTagsFromResource");
         params.put("Version", "2015-02-02");
-        ResetCacheParameterGroupMessageSerializer::serialize(&mut params, "", &input);
+        RemoveTagsFromResourceMessageSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `reset_cache_parameter_group`, also returning the metadata of the response.
+    pub async fn reset_cache_parameter_group_with_metadata(
+        &self,
+        input: ResetCacheParameterGroupMessage,
+    ) -> Result<
+        (CacheParameterGroupNameMessage, ResponseMetadata),
+        RusotoError<ResetCacheParameterGroupError>,
+    > {
+        let request = self.build_reset_cache_parameter_group_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(ResetCacheParameterGroupError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -14376,39 +16127,66 @@ impl ElastiCache for ElastiCacheClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Revokes ingress from a cache security group. Use this operation to disallow access from an Amazon EC2 security group that had been previously authorized.</p>
-    async fn revoke_cache_security_group_ingress(
+    /// Presigns the request `reset_cache_parameter_group` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_reset_cache_parameter_group(
         &self,
-        input: RevokeCacheSecurityGroupIngressMessage,
-    ) -> Result<
-        RevokeCacheSecurityGroupIngressResult,
-        RusotoError<RevokeCacheSecurityGroupIngressError>,
-    > {
+        presigner: &rusoto_core::Presigner,
+        input: ResetCacheParameterGroupMessage,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_reset_cache_parameter_group_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_reset_cache_parameter_group_request(
+        &self,
+        input: ResetCacheParameterGroupMessage,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "elasticache", &self.region, "/");
+        request.set_operation("ResetCacheParameterGroup");
         let mut params = Params::new();
 
-        params.put("Action", "RevokeCacheSecurityGroupIngress");
+        params.put("Action", "ResetCacheParameterGroup");
         params.put("Version", "2015-02-02");
-        RevokeCacheSecurityGroupIngressMessageSerializer::serialize(&mut params, "", &input);
+        ResetCacheParameterGroupMessageSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `revoke_cache_security_group_ingress`, also returning the metadata of the response.
+    pub async fn revoke_cache_security_group_ingress_with_metadata(
+        &self,
+        input: RevokeCacheSecurityGroupIngressMessage,
+    ) -> Result<
+        (RevokeCacheSecurityGroupIngressResult, ResponseMetadata),
+        RusotoError<RevokeCacheSecurityGroupIngressError>,
+    > {
+        let request = self.build_revoke_cache_security_group_ingress_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(RevokeCacheSecurityGroupIngressError::from_response(
                 response,
             ));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -14430,34 +16208,61 @@ impl ElastiCache for ElastiCacheClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Start the migration of data.</p>
-    async fn start_migration(
+    /// Presigns the request `revoke_cache_security_group_ingress` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_revoke_cache_security_group_ingress(
         &self,
-        input: StartMigrationMessage,
-    ) -> Result<StartMigrationResponse, RusotoError<StartMigrationError>> {
+        presigner: &rusoto_core::Presigner,
+        input: RevokeCacheSecurityGroupIngressMessage,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_revoke_cache_security_group_ingress_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_revoke_cache_security_group_ingress_request(
+        &self,
+        input: RevokeCacheSecurityGroupIngressMessage,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "elasticache", &self.region, "/");
+        request.set_operation("RevokeCacheSecurityGroupIngress");
         let mut params = Params::new();
 
-        params.put("Action", "StartMigration");
+        params.put("Action", "RevokeCacheSecurityGroupIngress");
         params.put("Version", "2015-02-02");
-        StartMigrationMessageSerializer::serialize(&mut params, "", &input);
+        RevokeCacheSecurityGroupIngressMessageSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `start_migration`, also returning the metadata of the response.
+    pub async fn start_migration_with_metadata(
+        &self,
+        input: StartMigrationMessage,
+    ) -> Result<(StartMigrationResponse, ResponseMetadata), RusotoError<StartMigrationError>> {
+        let request = self.build_start_migration_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(StartMigrationError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -14479,34 +16284,58 @@ impl ElastiCache for ElastiCacheClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Represents the input of a <code>TestFailover</code> operation which test automatic failover on a specified node group (called shard in the console) in a replication group (called cluster in the console).</p> <p class="title"> <b>Note the following</b> </p> <ul> <li> <p>A customer can use this operation to test automatic failover on up to 5 shards (called node groups in the ElastiCache API and AWS CLI) in any rolling 24-hour period.</p> </li> <li> <p>If calling this operation on shards in different clusters (called replication groups in the API and CLI), the calls can be made concurrently.</p> <p> </p> </li> <li> <p>If calling this operation multiple times on different shards in the same Redis (cluster mode enabled) replication group, the first node replacement must complete before a subsequent call can be made.</p> </li> <li> <p>To determine whether the node replacement is complete you can check Events using the Amazon ElastiCache console, the AWS CLI, or the ElastiCache API. Look for the following automatic failover related events, listed here in order of occurrance:</p> <ol> <li> <p>Replication group message: <code>Test Failover API called for node group &lt;node-group-id&gt;</code> </p> </li> <li> <p>Cache cluster message: <code>Failover from master node &lt;primary-node-id&gt; to replica node &lt;node-id&gt; completed</code> </p> </li> <li> <p>Replication group message: <code>Failover from master node &lt;primary-node-id&gt; to replica node &lt;node-id&gt; completed</code> </p> </li> <li> <p>Cache cluster message: <code>Recovering cache nodes &lt;node-id&gt;</code> </p> </li> <li> <p>Cache cluster message: <code>Finished recovery for cache nodes &lt;node-id&gt;</code> </p> </li> </ol> <p>For more information see:</p> <ul> <li> <p> <a href="https://docs.aws.amazon.com/AmazonElastiCache/latest/red-ug/ECEvents.Viewing.html">Viewing ElastiCache Events</a> in the <i>ElastiCache User Guide</i> </p> </li> <li> <p> <a href="https://docs.aws.amazon.com/AmazonElastiCache/latest/APIReference/API_DescribeEvents.html">DescribeEvents</a> in the ElastiCache API Reference</p> </li> </ul> </li> </ul> <p>Also see, <a href="https://docs.aws.amazon.com/AmazonElastiCache/latest/red-ug/AutoFailover.html#auto-failover-test">Testing Multi-AZ with Automatic Failover</a> in the <i>ElastiCache User Guide</i>.</p>
-    async fn test_failover(
+    /// Presigns the request `start_migration` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_start_migration(
         &self,
-        input: TestFailoverMessage,
-    ) -> Result<TestFailoverResult, RusotoError<TestFailoverError>> {
+        presigner: &rusoto_core::Presigner,
+        input: StartMigrationMessage,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_start_migration_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_start_migration_request(&self, input: StartMigrationMessage) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "elasticache", &self.region, "/");
+        request.set_operation("StartMigration");
         let mut params = Params::new();
 
-        params.put("Action", "TestFailover");
+        params.put("Action", "StartMigration");
         params.put("Version", "2015-02-02");
-        TestFailoverMessageSerializer::serialize(&mut params, "", &input);
+        StartMigrationMessageSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `test_failover`, also returning the metadata of the response.
+    pub async fn test_failover_with_metadata(
+        &self,
+        input: TestFailoverMessage,
+    ) -> Result<(TestFailoverResult, ResponseMetadata), RusotoError<TestFailoverError>> {
+        let request = self.build_test_failover_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(TestFailoverError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -14525,6 +16354,1344 @@ impl ElastiCache for ElastiCacheClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
+    }
+
+    /// Presigns the request `test_failover` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_test_failover(
+        &self,
+        presigner: &rusoto_core::Presigner,
+        input: TestFailoverMessage,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_test_failover_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_test_failover_request(&self, input: TestFailoverMessage) -> SignedRequest {
+        let mut request = SignedRequest::new("POST", "elasticache", &self.region, "/");
+        request.set_operation("TestFailover");
+        let mut params = Params::new();
+
+        params.put("Action", "TestFailover");
+        params.put("Version", "2015-02-02");
+        TestFailoverMessageSerializer::serialize(&mut params, "", &input);
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        request
+    }
+
+    /// The pages of `DescribeCacheClusters` results for `input`, requesting each next page with the `Marker` of the last one as its `Marker`.
+    /// The page size is the `MaxRecords` of `input`.
+    pub fn describe_cache_clusters_pages(
+        &self,
+        input: DescribeCacheClustersMessage,
+    ) -> impl futures::Stream<
+        Item = Result<CacheClusterMessage, RusotoError<DescribeCacheClustersError>>,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: ElastiCacheClient, input| async move {
+                client.describe_cache_clusters(input).await
+            },
+            |input: &DescribeCacheClustersMessage, output: &CacheClusterMessage| {
+                let marker = output.marker.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if marker.is_none() || marker == input.marker {
+                    return None;
+                }
+                Some(DescribeCacheClustersMessage {
+                    marker,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `CacheClusters` of every page of `DescribeCacheClusters` results for `input`, as `describe_cache_clusters_pages` requests them.
+    pub fn describe_cache_clusters_stream(
+        &self,
+        input: DescribeCacheClustersMessage,
+    ) -> impl futures::Stream<Item = Result<CacheCluster, RusotoError<DescribeCacheClustersError>>> + Send
+    {
+        rusoto_core::pagination::items(self.describe_cache_clusters_pages(input), |page| {
+            page.cache_clusters.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `DescribeCacheEngineVersions` results for `input`, requesting each next page with the `Marker` of the last one as its `Marker`.
+    /// The page size is the `MaxRecords` of `input`.
+    pub fn describe_cache_engine_versions_pages(
+        &self,
+        input: DescribeCacheEngineVersionsMessage,
+    ) -> impl futures::Stream<
+        Item = Result<CacheEngineVersionMessage, RusotoError<DescribeCacheEngineVersionsError>>,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: ElastiCacheClient, input| async move {
+                client.describe_cache_engine_versions(input).await
+            },
+            |input: &DescribeCacheEngineVersionsMessage, output: &CacheEngineVersionMessage| {
+                let marker = output.marker.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if marker.is_none() || marker == input.marker {
+                    return None;
+                }
+                Some(DescribeCacheEngineVersionsMessage {
+                    marker,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `CacheEngineVersions` of every page of `DescribeCacheEngineVersions` results for `input`, as `describe_cache_engine_versions_pages` requests them.
+    pub fn describe_cache_engine_versions_stream(
+        &self,
+        input: DescribeCacheEngineVersionsMessage,
+    ) -> impl futures::Stream<
+        Item = Result<CacheEngineVersion, RusotoError<DescribeCacheEngineVersionsError>>,
+    > + Send {
+        rusoto_core::pagination::items(self.describe_cache_engine_versions_pages(input), |page| {
+            page.cache_engine_versions.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `DescribeCacheParameterGroups` results for `input`, requesting each next page with the `Marker` of the last one as its `Marker`.
+    /// The page size is the `MaxRecords` of `input`.
+    pub fn describe_cache_parameter_groups_pages(
+        &self,
+        input: DescribeCacheParameterGroupsMessage,
+    ) -> impl futures::Stream<
+        Item = Result<CacheParameterGroupsMessage, RusotoError<DescribeCacheParameterGroupsError>>,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: ElastiCacheClient, input| async move {
+                client.describe_cache_parameter_groups(input).await
+            },
+            |input: &DescribeCacheParameterGroupsMessage, output: &CacheParameterGroupsMessage| {
+                let marker = output.marker.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if marker.is_none() || marker == input.marker {
+                    return None;
+                }
+                Some(DescribeCacheParameterGroupsMessage {
+                    marker,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `CacheParameterGroups` of every page of `DescribeCacheParameterGroups` results for `input`, as `describe_cache_parameter_groups_pages` requests them.
+    pub fn describe_cache_parameter_groups_stream(
+        &self,
+        input: DescribeCacheParameterGroupsMessage,
+    ) -> impl futures::Stream<
+        Item = Result<CacheParameterGroup, RusotoError<DescribeCacheParameterGroupsError>>,
+    > + Send {
+        rusoto_core::pagination::items(self.describe_cache_parameter_groups_pages(input), |page| {
+            page.cache_parameter_groups.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `DescribeCacheParameters` results for `input`, requesting each next page with the `Marker` of the last one as its `Marker`.
+    /// The page size is the `MaxRecords` of `input`.
+    pub fn describe_cache_parameters_pages(
+        &self,
+        input: DescribeCacheParametersMessage,
+    ) -> impl futures::Stream<
+        Item = Result<CacheParameterGroupDetails, RusotoError<DescribeCacheParametersError>>,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: ElastiCacheClient, input| async move {
+                client.describe_cache_parameters(input).await
+            },
+            |input: &DescribeCacheParametersMessage, output: &CacheParameterGroupDetails| {
+                let marker = output.marker.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if marker.is_none() || marker == input.marker {
+                    return None;
+                }
+                Some(DescribeCacheParametersMessage {
+                    marker,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `Parameters` of every page of `DescribeCacheParameters` results for `input`, as `describe_cache_parameters_pages` requests them.
+    pub fn describe_cache_parameters_stream(
+        &self,
+        input: DescribeCacheParametersMessage,
+    ) -> impl futures::Stream<Item = Result<Parameter, RusotoError<DescribeCacheParametersError>>> + Send
+    {
+        rusoto_core::pagination::items(self.describe_cache_parameters_pages(input), |page| {
+            page.parameters.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `DescribeCacheSecurityGroups` results for `input`, requesting each next page with the `Marker` of the last one as its `Marker`.
+    /// The page size is the `MaxRecords` of `input`.
+    pub fn describe_cache_security_groups_pages(
+        &self,
+        input: DescribeCacheSecurityGroupsMessage,
+    ) -> impl futures::Stream<
+        Item = Result<CacheSecurityGroupMessage, RusotoError<DescribeCacheSecurityGroupsError>>,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: ElastiCacheClient, input| async move {
+                client.describe_cache_security_groups(input).await
+            },
+            |input: &DescribeCacheSecurityGroupsMessage, output: &CacheSecurityGroupMessage| {
+                let marker = output.marker.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if marker.is_none() || marker == input.marker {
+                    return None;
+                }
+                Some(DescribeCacheSecurityGroupsMessage {
+                    marker,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `CacheSecurityGroups` of every page of `DescribeCacheSecurityGroups` results for `input`, as `describe_cache_security_groups_pages` requests them.
+    pub fn describe_cache_security_groups_stream(
+        &self,
+        input: DescribeCacheSecurityGroupsMessage,
+    ) -> impl futures::Stream<
+        Item = Result<CacheSecurityGroup, RusotoError<DescribeCacheSecurityGroupsError>>,
+    > + Send {
+        rusoto_core::pagination::items(self.describe_cache_security_groups_pages(input), |page| {
+            page.cache_security_groups.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `DescribeCacheSubnetGroups` results for `input`, requesting each next page with the `Marker` of the last one as its `Marker`.
+    /// The page size is the `MaxRecords` of `input`.
+    pub fn describe_cache_subnet_groups_pages(
+        &self,
+        input: DescribeCacheSubnetGroupsMessage,
+    ) -> impl futures::Stream<
+        Item = Result<CacheSubnetGroupMessage, RusotoError<DescribeCacheSubnetGroupsError>>,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: ElastiCacheClient, input| async move {
+                client.describe_cache_subnet_groups(input).await
+            },
+            |input: &DescribeCacheSubnetGroupsMessage, output: &CacheSubnetGroupMessage| {
+                let marker = output.marker.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if marker.is_none() || marker == input.marker {
+                    return None;
+                }
+                Some(DescribeCacheSubnetGroupsMessage {
+                    marker,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `CacheSubnetGroups` of every page of `DescribeCacheSubnetGroups` results for `input`, as `describe_cache_subnet_groups_pages` requests them.
+    pub fn describe_cache_subnet_groups_stream(
+        &self,
+        input: DescribeCacheSubnetGroupsMessage,
+    ) -> impl futures::Stream<
+        Item = Result<CacheSubnetGroup, RusotoError<DescribeCacheSubnetGroupsError>>,
+    > + Send {
+        rusoto_core::pagination::items(self.describe_cache_subnet_groups_pages(input), |page| {
+            page.cache_subnet_groups.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `DescribeEvents` results for `input`, requesting each next page with the `Marker` of the last one as its `Marker`.
+    /// The page size is the `MaxRecords` of `input`.
+    pub fn describe_events_pages(
+        &self,
+        input: DescribeEventsMessage,
+    ) -> impl futures::Stream<Item = Result<EventsMessage, RusotoError<DescribeEventsError>>> + Send
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: ElastiCacheClient, input| async move { client.describe_events(input).await },
+            |input: &DescribeEventsMessage, output: &EventsMessage| {
+                let marker = output.marker.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if marker.is_none() || marker == input.marker {
+                    return None;
+                }
+                Some(DescribeEventsMessage {
+                    marker,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `Events` of every page of `DescribeEvents` results for `input`, as `describe_events_pages` requests them.
+    pub fn describe_events_stream(
+        &self,
+        input: DescribeEventsMessage,
+    ) -> impl futures::Stream<Item = Result<Event, RusotoError<DescribeEventsError>>> + Send {
+        rusoto_core::pagination::items(self.describe_events_pages(input), |page| {
+            page.events.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `DescribeReplicationGroups` results for `input`, requesting each next page with the `Marker` of the last one as its `Marker`.
+    /// The page size is the `MaxRecords` of `input`.
+    pub fn describe_replication_groups_pages(
+        &self,
+        input: DescribeReplicationGroupsMessage,
+    ) -> impl futures::Stream<
+        Item = Result<ReplicationGroupMessage, RusotoError<DescribeReplicationGroupsError>>,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: ElastiCacheClient, input| async move {
+                client.describe_replication_groups(input).await
+            },
+            |input: &DescribeReplicationGroupsMessage, output: &ReplicationGroupMessage| {
+                let marker = output.marker.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if marker.is_none() || marker == input.marker {
+                    return None;
+                }
+                Some(DescribeReplicationGroupsMessage {
+                    marker,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `ReplicationGroups` of every page of `DescribeReplicationGroups` results for `input`, as `describe_replication_groups_pages` requests them.
+    pub fn describe_replication_groups_stream(
+        &self,
+        input: DescribeReplicationGroupsMessage,
+    ) -> impl futures::Stream<
+        Item = Result<ReplicationGroup, RusotoError<DescribeReplicationGroupsError>>,
+    > + Send {
+        rusoto_core::pagination::items(self.describe_replication_groups_pages(input), |page| {
+            page.replication_groups.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `DescribeReservedCacheNodes` results for `input`, requesting each next page with the `Marker` of the last one as its `Marker`.
+    /// The page size is the `MaxRecords` of `input`.
+    pub fn describe_reserved_cache_nodes_pages(
+        &self,
+        input: DescribeReservedCacheNodesMessage,
+    ) -> impl futures::Stream<
+        Item = Result<ReservedCacheNodeMessage, RusotoError<DescribeReservedCacheNodesError>>,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: ElastiCacheClient, input| async move {
+                client.describe_reserved_cache_nodes(input).await
+            },
+            |input: &DescribeReservedCacheNodesMessage, output: &ReservedCacheNodeMessage| {
+                let marker = output.marker.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if marker.is_none() || marker == input.marker {
+                    return None;
+                }
+                Some(DescribeReservedCacheNodesMessage {
+                    marker,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `ReservedCacheNodes` of every page of `DescribeReservedCacheNodes` results for `input`, as `describe_reserved_cache_nodes_pages` requests them.
+    pub fn describe_reserved_cache_nodes_stream(
+        &self,
+        input: DescribeReservedCacheNodesMessage,
+    ) -> impl futures::Stream<
+        Item = Result<ReservedCacheNode, RusotoError<DescribeReservedCacheNodesError>>,
+    > + Send {
+        rusoto_core::pagination::items(self.describe_reserved_cache_nodes_pages(input), |page| {
+            page.reserved_cache_nodes.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `DescribeReservedCacheNodesOfferings` results for `input`, requesting each next page with the `Marker` of the last one as its `Marker`.
+    /// The page size is the `MaxRecords` of `input`.
+    pub fn describe_reserved_cache_nodes_offerings_pages(
+        &self,
+        input: DescribeReservedCacheNodesOfferingsMessage,
+    ) -> impl futures::Stream<
+        Item = Result<
+            ReservedCacheNodesOfferingMessage,
+            RusotoError<DescribeReservedCacheNodesOfferingsError>,
+        >,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: ElastiCacheClient, input| async move {
+                client.describe_reserved_cache_nodes_offerings(input).await
+            },
+            |input: &DescribeReservedCacheNodesOfferingsMessage,
+             output: &ReservedCacheNodesOfferingMessage| {
+                let marker = output.marker.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if marker.is_none() || marker == input.marker {
+                    return None;
+                }
+                Some(DescribeReservedCacheNodesOfferingsMessage {
+                    marker,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `ReservedCacheNodesOfferings` of every page of `DescribeReservedCacheNodesOfferings` results for `input`, as `describe_reserved_cache_nodes_offerings_pages` requests them.
+    pub fn describe_reserved_cache_nodes_offerings_stream(
+        &self,
+        input: DescribeReservedCacheNodesOfferingsMessage,
+    ) -> impl futures::Stream<
+        Item = Result<
+            ReservedCacheNodesOffering,
+            RusotoError<DescribeReservedCacheNodesOfferingsError>,
+        >,
+    > + Send {
+        rusoto_core::pagination::items(
+            self.describe_reserved_cache_nodes_offerings_pages(input),
+            |page| page.reserved_cache_nodes_offerings.unwrap_or_default(),
+        )
+    }
+
+    /// The pages of `DescribeServiceUpdates` results for `input`, requesting each next page with the `Marker` of the last one as its `Marker`.
+    /// The page size is the `MaxRecords` of `input`.
+    pub fn describe_service_updates_pages(
+        &self,
+        input: DescribeServiceUpdatesMessage,
+    ) -> impl futures::Stream<
+        Item = Result<ServiceUpdatesMessage, RusotoError<DescribeServiceUpdatesError>>,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: ElastiCacheClient, input| async move {
+                client.describe_service_updates(input).await
+            },
+            |input: &DescribeServiceUpdatesMessage, output: &ServiceUpdatesMessage| {
+                let marker = output.marker.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if marker.is_none() || marker == input.marker {
+                    return None;
+                }
+                Some(DescribeServiceUpdatesMessage {
+                    marker,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `ServiceUpdates` of every page of `DescribeServiceUpdates` results for `input`, as `describe_service_updates_pages` requests them.
+    pub fn describe_service_updates_stream(
+        &self,
+        input: DescribeServiceUpdatesMessage,
+    ) -> impl futures::Stream<Item = Result<ServiceUpdate, RusotoError<DescribeServiceUpdatesError>>>
+           + Send {
+        rusoto_core::pagination::items(self.describe_service_updates_pages(input), |page| {
+            page.service_updates.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `DescribeSnapshots` results for `input`, requesting each next page with the `Marker` of the last one as its `Marker`.
+    /// The page size is the `MaxRecords` of `input`.
+    pub fn describe_snapshots_pages(
+        &self,
+        input: DescribeSnapshotsMessage,
+    ) -> impl futures::Stream<
+        Item = Result<DescribeSnapshotsListMessage, RusotoError<DescribeSnapshotsError>>,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: ElastiCacheClient, input| async move { client.describe_snapshots(input).await },
+            |input: &DescribeSnapshotsMessage, output: &DescribeSnapshotsListMessage| {
+                let marker = output.marker.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if marker.is_none() || marker == input.marker {
+                    return None;
+                }
+                Some(DescribeSnapshotsMessage {
+                    marker,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `Snapshots` of every page of `DescribeSnapshots` results for `input`, as `describe_snapshots_pages` requests them.
+    pub fn describe_snapshots_stream(
+        &self,
+        input: DescribeSnapshotsMessage,
+    ) -> impl futures::Stream<Item = Result<Snapshot, RusotoError<DescribeSnapshotsError>>> + Send
+    {
+        rusoto_core::pagination::items(self.describe_snapshots_pages(input), |page| {
+            page.snapshots.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `DescribeUpdateActions` results for `input`, requesting each next page with the `Marker` of the last one as its `Marker`.
+    /// The page size is the `MaxRecords` of `input`.
+    pub fn describe_update_actions_pages(
+        &self,
+        input: DescribeUpdateActionsMessage,
+    ) -> impl futures::Stream<
+        Item = Result<UpdateActionsMessage, RusotoError<DescribeUpdateActionsError>>,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: ElastiCacheClient, input| async move {
+                client.describe_update_actions(input).await
+            },
+            |input: &DescribeUpdateActionsMessage, output: &UpdateActionsMessage| {
+                let marker = output.marker.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if marker.is_none() || marker == input.marker {
+                    return None;
+                }
+                Some(DescribeUpdateActionsMessage {
+                    marker,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `UpdateActions` of every page of `DescribeUpdateActions` results for `input`, as `describe_update_actions_pages` requests them.
+    pub fn describe_update_actions_stream(
+        &self,
+        input: DescribeUpdateActionsMessage,
+    ) -> impl futures::Stream<Item = Result<UpdateAction, RusotoError<DescribeUpdateActionsError>>> + Send
+    {
+        rusoto_core::pagination::items(self.describe_update_actions_pages(input), |page| {
+            page.update_actions.unwrap_or_default()
+        })
+    }
+
+    /// Waits for `CacheClusterAvailable`, polling `DescribeCacheClusters` with `input` every 15 seconds, up to 40 times.
+    pub async fn wait_until_cache_cluster_available(
+        &self,
+        input: DescribeCacheClustersMessage,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<DescribeCacheClustersError>> {
+        let waiter = rusoto_core::waiter::Waiter::new(std::time::Duration::from_secs(15), 40);
+        self.wait_until_cache_cluster_available_with(input, &waiter)
+            .await
+    }
+
+    /// Waits for `CacheClusterAvailable` like `wait_until_cache_cluster_available`, with the delay, attempts and runtime of `waiter`.
+    pub async fn wait_until_cache_cluster_available_with(
+        &self,
+        input: DescribeCacheClustersMessage,
+        waiter: &rusoto_core::waiter::Waiter,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<DescribeCacheClustersError>> {
+        rusoto_core::waiter::wait(
+            waiter,
+            || self.describe_cache_clusters(input.clone()),
+            |result| {
+                if match result {
+                    Ok(output) => {
+                        let mut values = std::iter::once(output)
+                            .filter_map(|value| value.cache_clusters.as_ref())
+                            .flat_map(|value| value.iter())
+                            .filter_map(|value| value.cache_cluster_status.as_ref())
+                            .peekable();
+                        values.peek().is_some() && values.all(|value| value == "available")
+                    }
+                    Err(_) => false,
+                } {
+                    return Some(rusoto_core::waiter::WaiterState::Success);
+                }
+                if match result {
+                    Ok(output) => std::iter::once(output)
+                        .filter_map(|value| value.cache_clusters.as_ref())
+                        .flat_map(|value| value.iter())
+                        .filter_map(|value| value.cache_cluster_status.as_ref())
+                        .any(|value| value == "deleted"),
+                    Err(_) => false,
+                } {
+                    return Some(rusoto_core::waiter::WaiterState::Failure);
+                }
+                if match result {
+                    Ok(output) => std::iter::once(output)
+                        .filter_map(|value| value.cache_clusters.as_ref())
+                        .flat_map(|value| value.iter())
+                        .filter_map(|value| value.cache_cluster_status.as_ref())
+                        .any(|value| value == "deleting"),
+                    Err(_) => false,
+                } {
+                    return Some(rusoto_core::waiter::WaiterState::Failure);
+                }
+                if match result {
+                    Ok(output) => std::iter::once(output)
+                        .filter_map(|value| value.cache_clusters.as_ref())
+                        .flat_map(|value| value.iter())
+                        .filter_map(|value| value.cache_cluster_status.as_ref())
+                        .any(|value| value == "incompatible-network"),
+                    Err(_) => false,
+                } {
+                    return Some(rusoto_core::waiter::WaiterState::Failure);
+                }
+                if match result {
+                    Ok(output) => std::iter::once(output)
+                        .filter_map(|value| value.cache_clusters.as_ref())
+                        .flat_map(|value| value.iter())
+                        .filter_map(|value| value.cache_cluster_status.as_ref())
+                        .any(|value| value == "restore-failed"),
+                    Err(_) => false,
+                } {
+                    return Some(rusoto_core::waiter::WaiterState::Failure);
+                }
+                None
+            },
+        )
+        .await
+    }
+
+    /// Waits for `CacheClusterDeleted`, polling `DescribeCacheClusters` with `input` every 15 seconds, up to 40 times.
+    pub async fn wait_until_cache_cluster_deleted(
+        &self,
+        input: DescribeCacheClustersMessage,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<DescribeCacheClustersError>> {
+        let waiter = rusoto_core::waiter::Waiter::new(std::time::Duration::from_secs(15), 40);
+        self.wait_until_cache_cluster_deleted_with(input, &waiter)
+            .await
+    }
+
+    /// Waits for `CacheClusterDeleted` like `wait_until_cache_cluster_deleted`, with the delay, attempts and runtime of `waiter`.
+    pub async fn wait_until_cache_cluster_deleted_with(
+        &self,
+        input: DescribeCacheClustersMessage,
+        waiter: &rusoto_core::waiter::Waiter,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<DescribeCacheClustersError>> {
+        rusoto_core::waiter::wait(
+            waiter,
+            || self.describe_cache_clusters(input.clone()),
+            |result| {
+                if match result {
+                    Ok(output) => {
+                        let mut values = std::iter::once(output)
+                            .filter_map(|value| value.cache_clusters.as_ref())
+                            .flat_map(|value| value.iter())
+                            .filter_map(|value| value.cache_cluster_status.as_ref())
+                            .peekable();
+                        values.peek().is_some() && values.all(|value| value == "deleted")
+                    }
+                    Err(_) => false,
+                } {
+                    return Some(rusoto_core::waiter::WaiterState::Success);
+                }
+                if result
+                    .as_ref()
+                    .err()
+                    .and_then(|error| error.error_code())
+                    .as_deref()
+                    == Some("CacheClusterNotFound")
+                {
+                    return Some(rusoto_core::waiter::WaiterState::Success);
+                }
+                if match result {
+                    Ok(output) => std::iter::once(output)
+                        .filter_map(|value| value.cache_clusters.as_ref())
+                        .flat_map(|value| value.iter())
+                        .filter_map(|value| value.cache_cluster_status.as_ref())
+                        .any(|value| value == "available"),
+                    Err(_) => false,
+                } {
+                    return Some(rusoto_core::waiter::WaiterState::Failure);
+                }
+                if match result {
+                    Ok(output) => std::iter::once(output)
+                        .filter_map(|value| value.cache_clusters.as_ref())
+                        .flat_map(|value| value.iter())
+                        .filter_map(|value| value.cache_cluster_status.as_ref())
+                        .any(|value| value == "creating"),
+                    Err(_) => false,
+                } {
+                    return Some(rusoto_core::waiter::WaiterState::Failure);
+                }
+                if match result {
+                    Ok(output) => std::iter::once(output)
+                        .filter_map(|value| value.cache_clusters.as_ref())
+                        .flat_map(|value| value.iter())
+                        .filter_map(|value| value.cache_cluster_status.as_ref())
+                        .any(|value| value == "incompatible-network"),
+                    Err(_) => false,
+                } {
+                    return Some(rusoto_core::waiter::WaiterState::Failure);
+                }
+                if match result {
+                    Ok(output) => std::iter::once(output)
+                        .filter_map(|value| value.cache_clusters.as_ref())
+                        .flat_map(|value| value.iter())
+                        .filter_map(|value| value.cache_cluster_status.as_ref())
+                        .any(|value| value == "modifying"),
+                    Err(_) => false,
+                } {
+                    return Some(rusoto_core::waiter::WaiterState::Failure);
+                }
+                if match result {
+                    Ok(output) => std::iter::once(output)
+                        .filter_map(|value| value.cache_clusters.as_ref())
+                        .flat_map(|value| value.iter())
+                        .filter_map(|value| value.cache_cluster_status.as_ref())
+                        .any(|value| value == "restore-failed"),
+                    Err(_) => false,
+                } {
+                    return Some(rusoto_core::waiter::WaiterState::Failure);
+                }
+                if match result {
+                    Ok(output) => std::iter::once(output)
+                        .filter_map(|value| value.cache_clusters.as_ref())
+                        .flat_map(|value| value.iter())
+                        .filter_map(|value| value.cache_cluster_status.as_ref())
+                        .any(|value| value == "snapshotting"),
+                    Err(_) => false,
+                } {
+                    return Some(rusoto_core::waiter::WaiterState::Failure);
+                }
+                None
+            },
+        )
+        .await
+    }
+
+    /// Waits for `ReplicationGroupAvailable`, polling `DescribeReplicationGroups` with `input` every 15 seconds, up to 40 times.
+    pub async fn wait_until_replication_group_available(
+        &self,
+        input: DescribeReplicationGroupsMessage,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<DescribeReplicationGroupsError>> {
+        let waiter = rusoto_core::waiter::Waiter::new(std::time::Duration::from_secs(15), 40);
+        self.wait_until_replication_group_available_with(input, &waiter)
+            .await
+    }
+
+    /// Waits for `ReplicationGroupAvailable` like `wait_until_replication_group_available`, with the delay, attempts and runtime of `waiter`.
+    pub async fn wait_until_replication_group_available_with(
+        &self,
+        input: DescribeReplicationGroupsMessage,
+        waiter: &rusoto_core::waiter::Waiter,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<DescribeReplicationGroupsError>> {
+        rusoto_core::waiter::wait(
+            waiter,
+            || self.describe_replication_groups(input.clone()),
+            |result| {
+                if match result {
+                    Ok(output) => {
+                        let mut values = std::iter::once(output)
+                            .filter_map(|value| value.replication_groups.as_ref())
+                            .flat_map(|value| value.iter())
+                            .filter_map(|value| value.status.as_ref())
+                            .peekable();
+                        values.peek().is_some() && values.all(|value| value == "available")
+                    }
+                    Err(_) => false,
+                } {
+                    return Some(rusoto_core::waiter::WaiterState::Success);
+                }
+                if match result {
+                    Ok(output) => std::iter::once(output)
+                        .filter_map(|value| value.replication_groups.as_ref())
+                        .flat_map(|value| value.iter())
+                        .filter_map(|value| value.status.as_ref())
+                        .any(|value| value == "deleted"),
+                    Err(_) => false,
+                } {
+                    return Some(rusoto_core::waiter::WaiterState::Failure);
+                }
+                None
+            },
+        )
+        .await
+    }
+
+    /// Waits for `ReplicationGroupDeleted`, polling `DescribeReplicationGroups` with `input` every 15 seconds, up to 40 times.
+    pub async fn wait_until_replication_group_deleted(
+        &self,
+        input: DescribeReplicationGroupsMessage,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<DescribeReplicationGroupsError>> {
+        let waiter = rusoto_core::waiter::Waiter::new(std::time::Duration::from_secs(15), 40);
+        self.wait_until_replication_group_deleted_with(input, &waiter)
+            .await
+    }
+
+    /// Waits for `ReplicationGroupDeleted` like `wait_until_replication_group_deleted`, with the delay, attempts and runtime of `waiter`.
+    pub async fn wait_until_replication_group_deleted_with(
+        &self,
+        input: DescribeReplicationGroupsMessage,
+        waiter: &rusoto_core::waiter::Waiter,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<DescribeReplicationGroupsError>> {
+        rusoto_core::waiter::wait(
+            waiter,
+            || self.describe_replication_groups(input.clone()),
+            |result| {
+                if match result {
+                    Ok(output) => {
+                        let mut values = std::iter::once(output)
+                            .filter_map(|value| value.replication_groups.as_ref())
+                            .flat_map(|value| value.iter())
+                            .filter_map(|value| value.status.as_ref())
+                            .peekable();
+                        values.peek().is_some() && values.all(|value| value == "deleted")
+                    }
+                    Err(_) => false,
+                } {
+                    return Some(rusoto_core::waiter::WaiterState::Success);
+                }
+                if match result {
+                    Ok(output) => std::iter::once(output)
+                        .filter_map(|value| value.replication_groups.as_ref())
+                        .flat_map(|value| value.iter())
+                        .filter_map(|value| value.status.as_ref())
+                        .any(|value| value == "available"),
+                    Err(_) => false,
+                } {
+                    return Some(rusoto_core::waiter::WaiterState::Failure);
+                }
+                if result
+                    .as_ref()
+                    .err()
+                    .and_then(|error| error.error_code())
+                    .as_deref()
+                    == Some("ReplicationGroupNotFoundFault")
+                {
+                    return Some(rusoto_core::waiter::WaiterState::Success);
+                }
+                None
+            },
+        )
+        .await
+    }
+}
+
+#[async_trait]
+impl ElastiCache for ElastiCacheClient {
+    /// <p>Adds up to 50 cost allocation tags to the named resource. A cost allocation tag is a key-value pair where the key and value are case-sensitive. You can use cost allocation tags to categorize and track your AWS costs.</p> <p> When you apply tags to your ElastiCache resources, AWS generates a cost allocation report as a comma-separated value (CSV) file with your usage and costs aggregated by your tags. You can apply tags that represent business categories (such as cost centers, application names, or owners) to organize your costs across multiple services. For more information, see <a href="https://docs.aws.amazon.com/AmazonElastiCache/latest/red-ug/Tagging.html">Using Cost Allocation Tags in Amazon ElastiCache</a> in the <i>ElastiCache User Guide</i>.</p>
+    async fn add_tags_to_resource(
+        &self,
+        input: AddTagsToResourceMessage,
+    ) -> Result<TagListMessage, RusotoError<AddTagsToResourceError>> {
+        self.add_tags_to_resource_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Allows network ingress to a cache security group. Applications using ElastiCache must be running on Amazon EC2, and Amazon EC2 security groups are used as the authorization mechanism.</p> <note> <p>You cannot authorize ingress from an Amazon EC2 security group in one region to an ElastiCache cluster in another region.</p> </note></p>
+    async fn authorize_cache_security_group_ingress(
+        &self,
+        input: AuthorizeCacheSecurityGroupIngressMessage,
+    ) -> Result<
+        AuthorizeCacheSecurityGroupIngressResult,
+        RusotoError<AuthorizeCacheSecurityGroupIngressError>,
+    > {
+        self.authorize_cache_security_group_ingress_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Apply the service update. For more information on service updates and applying them, see <a href="https://docs.aws.amazon.com/AmazonElastiCache/latest/red-ug/applying-updates.html">Applying Service Updates</a>.</p>
+    async fn batch_apply_update_action(
+        &self,
+        input: BatchApplyUpdateActionMessage,
+    ) -> Result<UpdateActionResultsMessage, RusotoError<BatchApplyUpdateActionError>> {
+        self.batch_apply_update_action_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Stop the service update. For more information on service updates and stopping them, see <a href="https://docs.aws.amazon.com/AmazonElastiCache/latest/red-ug/stopping-self-service-updates.html">Stopping Service Updates</a>.</p>
+    async fn batch_stop_update_action(
+        &self,
+        input: BatchStopUpdateActionMessage,
+    ) -> Result<UpdateActionResultsMessage, RusotoError<BatchStopUpdateActionError>> {
+        self.batch_stop_update_action_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Complete the migration of data.</p>
+    async fn complete_migration(
+        &self,
+        input: CompleteMigrationMessage,
+    ) -> Result<CompleteMigrationResponse, RusotoError<CompleteMigrationError>> {
+        self.complete_migration_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Makes a copy of an existing snapshot.</p> <note> <p>This operation is valid for Redis only.</p> </note> <important> <p>Users or groups that have permissions to use the <code>CopySnapshot</code> operation can create their own Amazon S3 buckets and copy snapshots to it. To control access to your snapshots, use an IAM policy to control who has the ability to use the <code>CopySnapshot</code> operation. For more information about using IAM to control the use of ElastiCache operations, see <a href="https://docs.aws.amazon.com/AmazonElastiCache/latest/red-ug/backups-exporting.html">Exporting Snapshots</a> and <a href="https://docs.aws.amazon.com/AmazonElastiCache/latest/red-ug/IAM.html">Authentication &amp; Access Control</a>.</p> </important> <p>You could receive the following error messages.</p> <p class="title"> <b>Error Messages</b> </p> <ul> <li> <p> <b>Error Message:</b> The S3 bucket %s is outside of the region.</p> <p> <b>Solution:</b> Create an Amazon S3 bucket in the same region as your snapshot. For more information, see <a href="https://docs.aws.amazon.com/AmazonElastiCache/latest/red-ug/backups-exporting.html#backups-exporting-create-s3-bucket">Step 1: Create an Amazon S3 Bucket</a> in the ElastiCache User Guide.</p> </li> <li> <p> <b>Error Message:</b> The S3 bucket %s does not exist.</p> <p> <b>Solution:</b> Create an Amazon S3 bucket in the same region as your snapshot. For more information, see <a href="https://docs.aws.amazon.com/AmazonElastiCache/latest/red-ug/backups-exporting.html#backups-exporting-create-s3-bucket">Step 1: Create an Amazon S3 Bucket</a> in the ElastiCache User Guide.</p> </li> <li> <p> <b>Error Message:</b> The S3 bucket %s is not owned by the authenticated user.</p> <p> <b>Solution:</b> Create an Amazon S3 bucket in the same region as your snapshot. For more information, see <a href="https://docs.aws.amazon.com/AmazonElastiCache/latest/red-ug/backups-exporting.html#backups-exporting-create-s3-bucket">Step 1: Create an Amazon S3 Bucket</a> in the ElastiCache User Guide.</p> </li> <li> <p> <b>Error Message:</b> The authenticated user does not have sufficient permissions to perform the desired activity.</p> <p> <b>Solution:</b> Contact your system administrator to get the needed permissions.</p> </li> <li> <p> <b>Error Message:</b> The S3 bucket %s already contains an object with key %s.</p> <p> <b>Solution:</b> Give the <code>TargetSnapshotName</code> a new and unique value. If exporting a snapshot, you could alternatively create a new Amazon S3 bucket and use this same value for <code>TargetSnapshotName</code>.</p> </li> <li> <p> <b>Error Message: </b> ElastiCache has not been granted READ permissions %s on the S3 Bucket.</p> <p> <b>Solution:</b> Add List and Read permissions on the bucket. For more information, see <a href="https://docs.aws.amazon.com/AmazonElastiCache/latest/red-ug/backups-exporting.html#backups-exporting-grant-access">Step 2: Grant ElastiCache Access to Your Amazon S3 Bucket</a> in the ElastiCache User Guide.</p> </li> <li> <p> <b>Error Message: </b> ElastiCache has not been granted WRITE permissions %s on the S3 Bucket.</p> <p> <b>Solution:</b> Add Upload/Delete permissions on the bucket. For more information, see <a href="https://docs.aws.amazon.com/AmazonElastiCache/latest/red-ug/backups-exporting.html#backups-exporting-grant-access">Step 2: Grant ElastiCache Access to Your Amazon S3 Bucket</a> in the ElastiCache User Guide.</p> </li> <li> <p> <b>Error Message: </b> ElastiCache has not been granted READ_ACP permissions %s on the S3 Bucket.</p> <p> <b>Solution:</b> Add View Permissions on the bucket. For more information, see <a href="https://docs.aws.amazon.com/AmazonElastiCache/latest/red-ug/backups-exporting.html#backups-exporting-grant-access">Step 2: Grant ElastiCache Access to Your Amazon S3 Bucket</a> in the ElastiCache User Guide.</p> </li> </ul></p>
+    async fn copy_snapshot(
+        &self,
+        input: CopySnapshotMessage,
+    ) -> Result<CopySnapshotResult, RusotoError<CopySnapshotError>> {
+        self.copy_snapshot_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Creates a cluster. All nodes in the cluster run the same protocol-compliant cache engine software, either Memcached or Redis.</p> <p>This operation is not supported for Redis (cluster mode enabled) clusters.</p>
+    async fn create_cache_cluster(
+        &self,
+        input: CreateCacheClusterMessage,
+    ) -> Result<CreateCacheClusterResult, RusotoError<CreateCacheClusterError>> {
+        self.create_cache_cluster_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Creates a new Amazon ElastiCache cache parameter group. An ElastiCache cache parameter group is a collection of parameters and their values that are applied to all of the nodes in any cluster or replication group using the CacheParameterGroup.</p> <p>A newly created CacheParameterGroup is an exact duplicate of the default parameter group for the CacheParameterGroupFamily. To customize the newly created CacheParameterGroup you can change the values of specific parameters. For more information, see:</p> <ul> <li> <p> <a href="https://docs.aws.amazon.com/AmazonElastiCache/latest/APIReference/API_ModifyCacheParameterGroup.html">ModifyCacheParameterGroup</a> in the ElastiCache API Reference.</p> </li> <li> <p> <a href="https://docs.aws.amazon.com/AmazonElastiCache/latest/red-ug/ParameterGroups.html">Parameters and Parameter Groups</a> in the ElastiCache User Guide.</p> </li> </ul></p>
+    async fn create_cache_parameter_group(
+        &self,
+        input: CreateCacheParameterGroupMessage,
+    ) -> Result<CreateCacheParameterGroupResult, RusotoError<CreateCacheParameterGroupError>> {
+        self.create_cache_parameter_group_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Creates a new cache security group. Use a cache security group to control access to one or more clusters.</p> <p>Cache security groups are only used when you are creating a cluster outside of an Amazon Virtual Private Cloud (Amazon VPC). If you are creating a cluster inside of a VPC, use a cache subnet group instead. For more information, see <a href="https://docs.aws.amazon.com/AmazonElastiCache/latest/APIReference/API_CreateCacheSubnetGroup.html">CreateCacheSubnetGroup</a>.</p>
+    async fn create_cache_security_group(
+        &self,
+        input: CreateCacheSecurityGroupMessage,
+    ) -> Result<CreateCacheSecurityGroupResult, RusotoError<CreateCacheSecurityGroupError>> {
+        self.create_cache_security_group_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Creates a new cache subnet group.</p> <p>Use this parameter only when you are creating a cluster in an Amazon Virtual Private Cloud (Amazon VPC).</p>
+    async fn create_cache_subnet_group(
+        &self,
+        input: CreateCacheSubnetGroupMessage,
+    ) -> Result<CreateCacheSubnetGroupResult, RusotoError<CreateCacheSubnetGroupError>> {
+        self.create_cache_subnet_group_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Creates a Redis (cluster mode disabled) or a Redis (cluster mode enabled) replication group.</p> <p>A Redis (cluster mode disabled) replication group is a collection of clusters, where one of the clusters is a read/write primary and the others are read-only replicas. Writes to the primary are asynchronously propagated to the replicas.</p> <p>A Redis (cluster mode enabled) replication group is a collection of 1 to 90 node groups (shards). Each node group (shard) has one read/write primary node and up to 5 read-only replica nodes. Writes to the primary are asynchronously propagated to the replicas. Redis (cluster mode enabled) replication groups partition the data across node groups (shards).</p> <p>When a Redis (cluster mode disabled) replication group has been successfully created, you can add one or more read replicas to it, up to a total of 5 read replicas. You cannot alter a Redis (cluster mode enabled) replication group after it has been created. However, if you need to increase or decrease the number of node groups (console: shards), you can avail yourself of ElastiCache for Redis&#39; enhanced backup and restore. For more information, see <a href="https://docs.aws.amazon.com/AmazonElastiCache/latest/red-ug/backups-restoring.html">Restoring From a Backup with Cluster Resizing</a> in the <i>ElastiCache User Guide</i>.</p> <note> <p>This operation is valid for Redis only.</p> </note></p>
+    async fn create_replication_group(
+        &self,
+        input: CreateReplicationGroupMessage,
+    ) -> Result<CreateReplicationGroupResult, RusotoError<CreateReplicationGroupError>> {
+        self.create_replication_group_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Creates a copy of an entire cluster or replication group at a specific moment in time.</p> <note> <p>This operation is valid for Redis only.</p> </note></p>
+    async fn create_snapshot(
+        &self,
+        input: CreateSnapshotMessage,
+    ) -> Result<CreateSnapshotResult, RusotoError<CreateSnapshotError>> {
+        self.create_snapshot_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Dynamically decreases the number of replics in a Redis (cluster mode disabled) replication group or the number of replica nodes in one or more node groups (shards) of a Redis (cluster mode enabled) replication group. This operation is performed with no cluster down time.</p>
+    async fn decrease_replica_count(
+        &self,
+        input: DecreaseReplicaCountMessage,
+    ) -> Result<DecreaseReplicaCountResult, RusotoError<DecreaseReplicaCountError>> {
+        self.decrease_replica_count_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Deletes a previously provisioned cluster. <code>DeleteCacheCluster</code> deletes all associated cache nodes, node endpoints and the cluster itself. When you receive a successful response from this operation, Amazon ElastiCache immediately begins deleting the cluster; you cannot cancel or revert this operation.</p> <p>This operation is not valid for:</p> <ul> <li> <p>Redis (cluster mode enabled) clusters</p> </li> <li> <p>A cluster that is the last read replica of a replication group</p> </li> <li> <p>A node group (shard) that has Multi-AZ mode enabled</p> </li> <li> <p>A cluster from a Redis (cluster mode enabled) replication group</p> </li> <li> <p>A cluster that is not in the <code>available</code> state</p> </li> </ul></p>
+    async fn delete_cache_cluster(
+        &self,
+        input: DeleteCacheClusterMessage,
+    ) -> Result<DeleteCacheClusterResult, RusotoError<DeleteCacheClusterError>> {
+        self.delete_cache_cluster_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes the specified cache parameter group. You cannot delete a cache parameter group if it is associated with any cache clusters.</p>
+    async fn delete_cache_parameter_group(
+        &self,
+        input: DeleteCacheParameterGroupMessage,
+    ) -> Result<(), RusotoError<DeleteCacheParameterGroupError>> {
+        self.delete_cache_parameter_group_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Deletes a cache security group.</p> <note> <p>You cannot delete a cache security group if it is associated with any clusters.</p> </note></p>
+    async fn delete_cache_security_group(
+        &self,
+        input: DeleteCacheSecurityGroupMessage,
+    ) -> Result<(), RusotoError<DeleteCacheSecurityGroupError>> {
+        self.delete_cache_security_group_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Deletes a cache subnet group.</p> <note> <p>You cannot delete a cache subnet group if it is associated with any clusters.</p> </note></p>
+    async fn delete_cache_subnet_group(
+        &self,
+        input: DeleteCacheSubnetGroupMessage,
+    ) -> Result<(), RusotoError<DeleteCacheSubnetGroupError>> {
+        self.delete_cache_subnet_group_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Deletes an existing replication group. By default, this operation deletes the entire replication group, including the primary/primaries and all of the read replicas. If the replication group has only one primary, you can optionally delete only the read replicas, while retaining the primary by setting <code>RetainPrimaryCluster=true</code>.</p> <p>When you receive a successful response from this operation, Amazon ElastiCache immediately begins deleting the selected resources; you cannot cancel or revert this operation.</p> <note> <p>This operation is valid for Redis only.</p> </note></p>
+    async fn delete_replication_group(
+        &self,
+        input: DeleteReplicationGroupMessage,
+    ) -> Result<DeleteReplicationGroupResult, RusotoError<DeleteReplicationGroupError>> {
+        self.delete_replication_group_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Deletes an existing snapshot. When you receive a successful response from this operation, ElastiCache immediately begins deleting the snapshot; you cannot cancel or revert this operation.</p> <note> <p>This operation is valid for Redis only.</p> </note></p>
+    async fn delete_snapshot(
+        &self,
+        input: DeleteSnapshotMessage,
+    ) -> Result<DeleteSnapshotResult, RusotoError<DeleteSnapshotError>> {
+        self.delete_snapshot_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns information about all provisioned clusters if no cluster identifier is specified, or about a specific cache cluster if a cluster identifier is supplied.</p> <p>By default, abbreviated information about the clusters is returned. You can use the optional <i>ShowCacheNodeInfo</i> flag to retrieve detailed information about the cache nodes associated with the clusters. These details include the DNS address and port for the cache node endpoint.</p> <p>If the cluster is in the <i>creating</i> state, only cluster-level information is displayed until all of the nodes are successfully provisioned.</p> <p>If the cluster is in the <i>deleting</i> state, only cluster-level information is displayed.</p> <p>If cache nodes are currently being added to the cluster, node endpoint information and creation time for the additional nodes are not displayed until they are completely provisioned. When the cluster state is <i>available</i>, the cluster is ready for use.</p> <p>If cache nodes are currently being removed from the cluster, no endpoint information for the removed nodes is displayed.</p>
+    async fn describe_cache_clusters(
+        &self,
+        input: DescribeCacheClustersMessage,
+    ) -> Result<CacheClusterMessage, RusotoError<DescribeCacheClustersError>> {
+        self.describe_cache_clusters_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns a list of the available cache engines and their versions.</p>
+    async fn describe_cache_engine_versions(
+        &self,
+        input: DescribeCacheEngineVersionsMessage,
+    ) -> Result<CacheEngineVersionMessage, RusotoError<DescribeCacheEngineVersionsError>> {
+        self.describe_cache_engine_versions_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns a list of cache parameter group descriptions. If a cache parameter group name is specified, the list contains only the descriptions for that group.</p>
+    async fn describe_cache_parameter_groups(
+        &self,
+        input: DescribeCacheParameterGroupsMessage,
+    ) -> Result<CacheParameterGroupsMessage, RusotoError<DescribeCacheParameterGroupsError>> {
+        self.describe_cache_parameter_groups_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns the detailed parameter list for a particular cache parameter group.</p>
+    async fn describe_cache_parameters(
+        &self,
+        input: DescribeCacheParametersMessage,
+    ) -> Result<CacheParameterGroupDetails, RusotoError<DescribeCacheParametersError>> {
+        self.describe_cache_parameters_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns a list of cache security group descriptions. If a cache security group name is specified, the list contains only the description of that group. This applicable only when you have ElastiCache in Classic setup </p>
+    async fn describe_cache_security_groups(
+        &self,
+        input: DescribeCacheSecurityGroupsMessage,
+    ) -> Result<CacheSecurityGroupMessage, RusotoError<DescribeCacheSecurityGroupsError>> {
+        self.describe_cache_security_groups_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns a list of cache subnet group descriptions. If a subnet group name is specified, the list contains only the description of that group. This is applicable only when you have ElastiCache in VPC setup. All ElastiCache clusters now launch in VPC by default. </p>
+    async fn describe_cache_subnet_groups(
+        &self,
+        input: DescribeCacheSubnetGroupsMessage,
+    ) -> Result<CacheSubnetGroupMessage, RusotoError<DescribeCacheSubnetGroupsError>> {
+        self.describe_cache_subnet_groups_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns the default engine and system parameter information for the specified cache engine.</p>
+    async fn describe_engine_default_parameters(
+        &self,
+        input: DescribeEngineDefaultParametersMessage,
+    ) -> Result<
+        DescribeEngineDefaultParametersResult,
+        RusotoError<DescribeEngineDefaultParametersError>,
+    > {
+        self.describe_engine_default_parameters_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns events related to clusters, cache security groups, and cache parameter groups. You can obtain events specific to a particular cluster, cache security group, or cache parameter group by providing the name as a parameter.</p> <p>By default, only the events occurring within the last hour are returned; however, you can retrieve up to 14 days' worth of events if necessary.</p>
+    async fn describe_events(
+        &self,
+        input: DescribeEventsMessage,
+    ) -> Result<EventsMessage, RusotoError<DescribeEventsError>> {
+        self.describe_events_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Returns information about a particular replication group. If no identifier is specified, <code>DescribeReplicationGroups</code> returns information about all replication groups.</p> <note> <p>This operation is valid for Redis only.</p> </note></p>
+    async fn describe_replication_groups(
+        &self,
+        input: DescribeReplicationGroupsMessage,
+    ) -> Result<ReplicationGroupMessage, RusotoError<DescribeReplicationGroupsError>> {
+        self.describe_replication_groups_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns information about reserved cache nodes for this account, or about a specified reserved cache node.</p>
+    async fn describe_reserved_cache_nodes(
+        &self,
+        input: DescribeReservedCacheNodesMessage,
+    ) -> Result<ReservedCacheNodeMessage, RusotoError<DescribeReservedCacheNodesError>> {
+        self.describe_reserved_cache_nodes_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Lists available reserved cache node offerings.</p>
+    async fn describe_reserved_cache_nodes_offerings(
+        &self,
+        input: DescribeReservedCacheNodesOfferingsMessage,
+    ) -> Result<
+        ReservedCacheNodesOfferingMessage,
+        RusotoError<DescribeReservedCacheNodesOfferingsError>,
+    > {
+        self.describe_reserved_cache_nodes_offerings_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns details of the service updates</p>
+    async fn describe_service_updates(
+        &self,
+        input: DescribeServiceUpdatesMessage,
+    ) -> Result<ServiceUpdatesMessage, RusotoError<DescribeServiceUpdatesError>> {
+        self.describe_service_updates_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Returns information about cluster or replication group snapshots. By default, <code>DescribeSnapshots</code> lists all of your snapshots; it can optionally describe a single snapshot, or just the snapshots associated with a particular cache cluster.</p> <note> <p>This operation is valid for Redis only.</p> </note></p>
+    async fn describe_snapshots(
+        &self,
+        input: DescribeSnapshotsMessage,
+    ) -> Result<DescribeSnapshotsListMessage, RusotoError<DescribeSnapshotsError>> {
+        self.describe_snapshots_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns details of the update actions </p>
+    async fn describe_update_actions(
+        &self,
+        input: DescribeUpdateActionsMessage,
+    ) -> Result<UpdateActionsMessage, RusotoError<DescribeUpdateActionsError>> {
+        self.describe_update_actions_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Dynamically increases the number of replics in a Redis (cluster mode disabled) replication group or the number of replica nodes in one or more node groups (shards) of a Redis (cluster mode enabled) replication group. This operation is performed with no cluster down time.</p>
+    async fn increase_replica_count(
+        &self,
+        input: IncreaseReplicaCountMessage,
+    ) -> Result<IncreaseReplicaCountResult, RusotoError<IncreaseReplicaCountError>> {
+        self.increase_replica_count_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Lists all available node types that you can scale your Redis cluster's or replication group's current node type.</p> <p>When you use the <code>ModifyCacheCluster</code> or <code>ModifyReplicationGroup</code> operations to scale your cluster or replication group, the value of the <code>CacheNodeType</code> parameter must be one of the node types returned by this operation.</p>
+    async fn list_allowed_node_type_modifications(
+        &self,
+        input: ListAllowedNodeTypeModificationsMessage,
+    ) -> Result<
+        AllowedNodeTypeModificationsMessage,
+        RusotoError<ListAllowedNodeTypeModificationsError>,
+    > {
+        self.list_allowed_node_type_modifications_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Lists all cost allocation tags currently on the named resource. A <code>cost allocation tag</code> is a key-value pair where the key is case-sensitive and the value is optional. You can use cost allocation tags to categorize and track your AWS costs.</p> <p>If the cluster is not in the <i>available</i> state, <code>ListTagsForResource</code> returns an error.</p> <p>You can have a maximum of 50 cost allocation tags on an ElastiCache resource. For more information, see <a href="https://docs.aws.amazon.com/AmazonElastiCache/latest/red-ug/Tagging.html">Monitoring Costs with Tags</a>.</p>
+    async fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceMessage,
+    ) -> Result<TagListMessage, RusotoError<ListTagsForResourceError>> {
+        self.list_tags_for_resource_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Modifies the settings for a cluster. You can use this operation to change one or more cluster configuration parameters by specifying the parameters and the new values.</p>
+    async fn modify_cache_cluster(
+        &self,
+        input: ModifyCacheClusterMessage,
+    ) -> Result<ModifyCacheClusterResult, RusotoError<ModifyCacheClusterError>> {
+        self.modify_cache_cluster_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Modifies the parameters of a cache parameter group. You can modify up to 20 parameters in a single request by submitting a list parameter name and value pairs.</p>
+    async fn modify_cache_parameter_group(
+        &self,
+        input: ModifyCacheParameterGroupMessage,
+    ) -> Result<CacheParameterGroupNameMessage, RusotoError<ModifyCacheParameterGroupError>> {
+        self.modify_cache_parameter_group_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Modifies an existing cache subnet group.</p>
+    async fn modify_cache_subnet_group(
+        &self,
+        input: ModifyCacheSubnetGroupMessage,
+    ) -> Result<ModifyCacheSubnetGroupResult, RusotoError<ModifyCacheSubnetGroupError>> {
+        self.modify_cache_subnet_group_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Modifies the settings for a replication group.</p> <p>For Redis (cluster mode enabled) clusters, this operation cannot be used to change a cluster&#39;s node type or engine version. For more information, see:</p> <ul> <li> <p> <a href="https://docs.aws.amazon.com/AmazonElastiCache/latest/red-ug/scaling-redis-cluster-mode-enabled.html">Scaling for Amazon ElastiCache for Redis (cluster mode enabled)</a> in the ElastiCache User Guide</p> </li> <li> <p> <a href="https://docs.aws.amazon.com/AmazonElastiCache/latest/APIReference/API_ModifyReplicationGroupShardConfiguration.html">ModifyReplicationGroupShardConfiguration</a> in the ElastiCache API Reference</p> </li> </ul> <note> <p>This operation is valid for Redis only.</p> </note></p>
+    async fn modify_replication_group(
+        &self,
+        input: ModifyReplicationGroupMessage,
+    ) -> Result<ModifyReplicationGroupResult, RusotoError<ModifyReplicationGroupError>> {
+        self.modify_replication_group_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Modifies a replication group's shards (node groups) by allowing you to add shards, remove shards, or rebalance the keyspaces among exisiting shards.</p>
+    async fn modify_replication_group_shard_configuration(
+        &self,
+        input: ModifyReplicationGroupShardConfigurationMessage,
+    ) -> Result<
+        ModifyReplicationGroupShardConfigurationResult,
+        RusotoError<ModifyReplicationGroupShardConfigurationError>,
+    > {
+        self.modify_replication_group_shard_configuration_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Allows you to purchase a reserved cache node offering.</p>
+    async fn purchase_reserved_cache_nodes_offering(
+        &self,
+        input: PurchaseReservedCacheNodesOfferingMessage,
+    ) -> Result<
+        PurchaseReservedCacheNodesOfferingResult,
+        RusotoError<PurchaseReservedCacheNodesOfferingError>,
+    > {
+        self.purchase_reserved_cache_nodes_offering_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Reboots some, or all, of the cache nodes within a provisioned cluster. This operation applies any modified cache parameter groups to the cluster. The reboot operation takes place as soon as possible, and results in a momentary outage to the cluster. During the reboot, the cluster status is set to REBOOTING.</p> <p>The reboot causes the contents of the cache (for each cache node being rebooted) to be lost.</p> <p>When the reboot is complete, a cluster event is created.</p> <p>Rebooting a cluster is currently supported on Memcached and Redis (cluster mode disabled) clusters. Rebooting is not supported on Redis (cluster mode enabled) clusters.</p> <p>If you make changes to parameters that require a Redis (cluster mode enabled) cluster reboot for the changes to be applied, see <a href="http://docs.aws.amazon.com/AmazonElastiCache/latest/red-ug/Clusters.Rebooting.html">Rebooting a Cluster</a> for an alternate process.</p>
+    async fn reboot_cache_cluster(
+        &self,
+        input: RebootCacheClusterMessage,
+    ) -> Result<RebootCacheClusterResult, RusotoError<RebootCacheClusterError>> {
+        self.reboot_cache_cluster_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Removes the tags identified by the <code>TagKeys</code> list from the named resource.</p>
+    async fn remove_tags_from_resource(
+        &self,
+        input: RemoveTagsFromResourceMessage,
+    ) -> Result<TagListMessage, RusotoError<RemoveTagsFromResourceError>> {
+        self.remove_tags_from_resource_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Modifies the parameters of a cache parameter group to the engine or system default value. You can reset specific parameters by submitting a list of parameter names. To reset the entire cache parameter group, specify the <code>ResetAllParameters</code> and <code>CacheParameterGroupName</code> parameters.</p>
+    async fn reset_cache_parameter_group(
+        &self,
+        input: ResetCacheParameterGroupMessage,
+    ) -> Result<CacheParameterGroupNameMessage, RusotoError<ResetCacheParameterGroupError>> {
+        self.reset_cache_parameter_group_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Revokes ingress from a cache security group. Use this operation to disallow access from an Amazon EC2 security group that had been previously authorized.</p>
+    async fn revoke_cache_security_group_ingress(
+        &self,
+        input: RevokeCacheSecurityGroupIngressMessage,
+    ) -> Result<
+        RevokeCacheSecurityGroupIngressResult,
+        RusotoError<RevokeCacheSecurityGroupIngressError>,
+    > {
+        self.revoke_cache_security_group_ingress_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Start the migration of data.</p>
+    async fn start_migration(
+        &self,
+        input: StartMigrationMessage,
+    ) -> Result<StartMigrationResponse, RusotoError<StartMigrationError>> {
+        self.start_migration_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Represents the input of a <code>TestFailover</code> operation which test automatic failover on a specified node group (called shard in the console) in a replication group (called cluster in the console).</p> <p class="title"> <b>Note the following</b> </p> <ul> <li> <p>A customer can use this operation to test automatic failover on up to 5 shards (called node groups in the ElastiCache API and AWS CLI) in any rolling 24-hour period.</p> </li> <li> <p>If calling this operation on shards in different clusters (called replication groups in the API and CLI), the calls can be made concurrently.</p> <p> </p> </li> <li> <p>If calling this operation multiple times on different shards in the same Redis (cluster mode enabled) replication group, the first node replacement must complete before a subsequent call can be made.</p> </li> <li> <p>To determine whether the node replacement is complete you can check Events using the Amazon ElastiCache console, the AWS CLI, or the ElastiCache API. Look for the following automatic failover related events, listed here in order of occurrance:</p> <ol> <li> <p>Replication group message: <code>Test Failover API called for node group &lt;node-group-id&gt;</code> </p> </li> <li> <p>Cache cluster message: <code>Failover from master node &lt;primary-node-id&gt; to replica node &lt;node-id&gt; completed</code> </p> </li> <li> <p>Replication group message: <code>Failover from master node &lt;primary-node-id&gt; to replica node &lt;node-id&gt; completed</code> </p> </li> <li> <p>Cache cluster message: <code>Recovering cache nodes &lt;node-id&gt;</code> </p> </li> <li> <p>Cache cluster message: <code>Finished recovery for cache nodes &lt;node-id&gt;</code> </p> </li> </ol> <p>For more information see:</p> <ul> <li> <p> <a href="https://docs.aws.amazon.com/AmazonElastiCache/latest/red-ug/ECEvents.Viewing.html">Viewing ElastiCache Events</a> in the <i>ElastiCache User Guide</i> </p> </li> <li> <p> <a href="https://docs.aws.amazon.com/AmazonElastiCache/latest/APIReference/API_DescribeEvents.html">DescribeEvents</a> in the ElastiCache API Reference</p> </li> </ul> </li> </ul> <p>Also see, <a href="https://docs.aws.amazon.com/AmazonElastiCache/latest/red-ug/AutoFailover.html#auto-failover-test">Testing Multi-AZ with Automatic Failover</a> in the <i>ElastiCache User Guide</i>.</p>
+    async fn test_failover(
+        &self,
+        input: TestFailoverMessage,
+    ) -> Result<TestFailoverResult, RusotoError<TestFailoverError>> {
+        self.test_failover_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
     }
 }
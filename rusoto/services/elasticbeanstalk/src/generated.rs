@@ -16,8 +16,8 @@ use std::fmt;
 use async_trait::async_trait;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
-use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest, ResponseMetadata};
+use rusoto_core::{Client, ErrorMetadata, RusotoError};
 
 use rusoto_core::param::{Params, ServiceParams};
 use rusoto_core::proto::xml::error::*;
@@ -7465,9 +7465,12 @@ impl VirtualizationTypeDeserializer {
 }
 /// Errors returned by AbortEnvironmentUpdate
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum AbortEnvironmentUpdateError {
     /// <p>The specified account does not have sufficient privileges for one or more AWS services.</p>
     InsufficientPrivileges(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl AbortEnvironmentUpdateError {
@@ -7479,13 +7482,16 @@ impl AbortEnvironmentUpdateError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InsufficientPrivilegesException" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             AbortEnvironmentUpdateError::InsufficientPrivileges(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
-                    _ => {}
+                    _ => {
+                        return RusotoError::unhandled(AbortEnvironmentUpdateError::Unhandled, &res)
+                    }
                 }
             }
         }
@@ -7507,17 +7513,21 @@ impl fmt::Display for AbortEnvironmentUpdateError {
             AbortEnvironmentUpdateError::InsufficientPrivileges(ref cause) => {
                 write!(f, "{}", cause)
             }
+            AbortEnvironmentUpdateError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for AbortEnvironmentUpdateError {}
 /// Errors returned by ApplyEnvironmentManagedAction
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ApplyEnvironmentManagedActionError {
     /// <p>A generic service exception has occurred.</p>
     ElasticBeanstalkService(String),
     /// <p>Cannot modify the managed action in its current state.</p>
     ManagedActionInvalidState(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ApplyEnvironmentManagedActionError {
@@ -7531,20 +7541,27 @@ impl ApplyEnvironmentManagedActionError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "ElasticBeanstalkServiceException" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             ApplyEnvironmentManagedActionError::ElasticBeanstalkService(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "ManagedActionInvalidStateException" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             ApplyEnvironmentManagedActionError::ManagedActionInvalidState(
                                 parsed_error.message,
                             ),
+                            &res,
+                        )
+                    }
+                    _ => {
+                        return RusotoError::unhandled(
+                            ApplyEnvironmentManagedActionError::Unhandled,
+                            &res,
                         )
                     }
-                    _ => {}
                 }
             }
         }
@@ -7569,13 +7586,20 @@ impl fmt::Display for ApplyEnvironmentManagedActionError {
             ApplyEnvironmentManagedActionError::ManagedActionInvalidState(ref cause) => {
                 write!(f, "{}", cause)
             }
+            ApplyEnvironmentManagedActionError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for ApplyEnvironmentManagedActionError {}
 /// Errors returned by CheckDNSAvailability
 #[derive(Debug, PartialEq)]
-pub enum CheckDNSAvailabilityError {}
+#[non_exhaustive]
+pub enum CheckDNSAvailabilityError {
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
+}
 
 impl CheckDNSAvailabilityError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<CheckDNSAvailabilityError> {
@@ -7583,10 +7607,8 @@ impl CheckDNSAvailabilityError {
             let reader = EventReader::new(res.body.as_ref());
             let mut stack = XmlResponse::new(reader.into_iter().peekable());
             find_start_element(&mut stack);
-            if let Ok(parsed_error) = Self::deserialize(&mut stack) {
-                match &parsed_error.code[..] {
-                    _ => {}
-                }
+            if Self::deserialize(&mut stack).is_ok() {
+                return RusotoError::unhandled(CheckDNSAvailabilityError::Unhandled, &res);
             }
         }
         RusotoError::Unknown(res)
@@ -7603,17 +7625,22 @@ impl CheckDNSAvailabilityError {
 impl fmt::Display for CheckDNSAvailabilityError {
     #[allow(unused_variables)]
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        match *self {}
+        match *self {
+            CheckDNSAvailabilityError::Unhandled(ref metadata) => write!(f, "{}", metadata),
+        }
     }
 }
 impl Error for CheckDNSAvailabilityError {}
 /// Errors returned by ComposeEnvironments
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ComposeEnvironmentsError {
     /// <p>The specified account does not have sufficient privileges for one or more AWS services.</p>
     InsufficientPrivileges(String),
     /// <p>The specified account has reached its limit of environments.</p>
     TooManyEnvironments(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ComposeEnvironmentsError {
@@ -7625,16 +7652,18 @@ impl ComposeEnvironmentsError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InsufficientPrivilegesException" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             ComposeEnvironmentsError::InsufficientPrivileges(parsed_error.message),
+                            &res,
                         )
                     }
                     "TooManyEnvironmentsException" => {
-                        return RusotoError::Service(ComposeEnvironmentsError::TooManyEnvironments(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            ComposeEnvironmentsError::TooManyEnvironments(parsed_error.message),
+                            &res,
+                        )
                     }
-                    _ => {}
+                    _ => return RusotoError::unhandled(ComposeEnvironmentsError::Unhandled, &res),
                 }
             }
         }
@@ -7655,15 +7684,19 @@ impl fmt::Display for ComposeEnvironmentsError {
         match *self {
             ComposeEnvironmentsError::InsufficientPrivileges(ref cause) => write!(f, "{}", cause),
             ComposeEnvironmentsError::TooManyEnvironments(ref cause) => write!(f, "{}", cause),
+            ComposeEnvironmentsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ComposeEnvironmentsError {}
 /// Errors returned by CreateApplication
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateApplicationError {
     /// <p>The specified account has reached its limit of applications.</p>
     TooManyApplications(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateApplicationError {
@@ -7675,11 +7708,12 @@ impl CreateApplicationError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "TooManyApplicationsException" => {
-                        return RusotoError::Service(CreateApplicationError::TooManyApplications(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            CreateApplicationError::TooManyApplications(parsed_error.message),
+                            &res,
+                        )
                     }
-                    _ => {}
+                    _ => return RusotoError::unhandled(CreateApplicationError::Unhandled, &res),
                 }
             }
         }
@@ -7699,12 +7733,14 @@ impl fmt::Display for CreateApplicationError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             CreateApplicationError::TooManyApplications(ref cause) => write!(f, "{}", cause),
+            CreateApplicationError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateApplicationError {}
 /// Errors returned by CreateApplicationVersion
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateApplicationVersionError {
     /// <p>AWS CodeBuild is not available in the specified region.</p>
     CodeBuildNotInServiceRegion(String),
@@ -7716,6 +7752,8 @@ pub enum CreateApplicationVersionError {
     TooManyApplicationVersions(String),
     /// <p>The specified account has reached its limit of applications.</p>
     TooManyApplications(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateApplicationVersionError {
@@ -7727,41 +7765,51 @@ impl CreateApplicationVersionError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "CodeBuildNotInServiceRegionException" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             CreateApplicationVersionError::CodeBuildNotInServiceRegion(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "InsufficientPrivilegesException" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             CreateApplicationVersionError::InsufficientPrivileges(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "S3LocationNotInServiceRegionException" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             CreateApplicationVersionError::S3LocationNotInServiceRegion(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "TooManyApplicationVersionsException" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             CreateApplicationVersionError::TooManyApplicationVersions(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "TooManyApplicationsException" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             CreateApplicationVersionError::TooManyApplications(
                                 parsed_error.message,
                             ),
+                            &res,
+                        )
+                    }
+                    _ => {
+                        return RusotoError::unhandled(
+                            CreateApplicationVersionError::Unhandled,
+                            &res,
                         )
                     }
-                    _ => {}
                 }
             }
         }
@@ -7793,12 +7841,14 @@ impl fmt::Display for CreateApplicationVersionError {
                 write!(f, "{}", cause)
             }
             CreateApplicationVersionError::TooManyApplications(ref cause) => write!(f, "{}", cause),
+            CreateApplicationVersionError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateApplicationVersionError {}
 /// Errors returned by CreateConfigurationTemplate
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateConfigurationTemplateError {
     /// <p>The specified account does not have sufficient privileges for one or more AWS services.</p>
     InsufficientPrivileges(String),
@@ -7806,6 +7856,8 @@ pub enum CreateConfigurationTemplateError {
     TooManyBuckets(String),
     /// <p>The specified account has reached its limit of configuration templates.</p>
     TooManyConfigurationTemplates(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateConfigurationTemplateError {
@@ -7819,25 +7871,33 @@ impl CreateConfigurationTemplateError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InsufficientPrivilegesException" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             CreateConfigurationTemplateError::InsufficientPrivileges(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "TooManyBucketsException" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             CreateConfigurationTemplateError::TooManyBuckets(parsed_error.message),
+                            &res,
                         )
                     }
                     "TooManyConfigurationTemplatesException" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             CreateConfigurationTemplateError::TooManyConfigurationTemplates(
                                 parsed_error.message,
                             ),
+                            &res,
+                        )
+                    }
+                    _ => {
+                        return RusotoError::unhandled(
+                            CreateConfigurationTemplateError::Unhandled,
+                            &res,
                         )
                     }
-                    _ => {}
                 }
             }
         }
@@ -7863,17 +7923,21 @@ impl fmt::Display for CreateConfigurationTemplateError {
             CreateConfigurationTemplateError::TooManyConfigurationTemplates(ref cause) => {
                 write!(f, "{}", cause)
             }
+            CreateConfigurationTemplateError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateConfigurationTemplateError {}
 /// Errors returned by CreateEnvironment
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateEnvironmentError {
     /// <p>The specified account does not have sufficient privileges for one or more AWS services.</p>
     InsufficientPrivileges(String),
     /// <p>The specified account has reached its limit of environments.</p>
     TooManyEnvironments(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateEnvironmentError {
@@ -7885,16 +7949,18 @@ impl CreateEnvironmentError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InsufficientPrivilegesException" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             CreateEnvironmentError::InsufficientPrivileges(parsed_error.message),
+                            &res,
                         )
                     }
                     "TooManyEnvironmentsException" => {
-                        return RusotoError::Service(CreateEnvironmentError::TooManyEnvironments(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            CreateEnvironmentError::TooManyEnvironments(parsed_error.message),
+                            &res,
+                        )
                     }
-                    _ => {}
+                    _ => return RusotoError::unhandled(CreateEnvironmentError::Unhandled, &res),
                 }
             }
         }
@@ -7915,12 +7981,14 @@ impl fmt::Display for CreateEnvironmentError {
         match *self {
             CreateEnvironmentError::InsufficientPrivileges(ref cause) => write!(f, "{}", cause),
             CreateEnvironmentError::TooManyEnvironments(ref cause) => write!(f, "{}", cause),
+            CreateEnvironmentError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateEnvironmentError {}
 /// Errors returned by CreatePlatformVersion
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreatePlatformVersionError {
     /// <p>A generic service exception has occurred.</p>
     ElasticBeanstalkService(String),
@@ -7928,6 +7996,8 @@ pub enum CreatePlatformVersionError {
     InsufficientPrivileges(String),
     /// <p>You have exceeded the maximum number of allowed platforms associated with the account.</p>
     TooManyPlatforms(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreatePlatformVersionError {
@@ -7939,25 +8009,30 @@ impl CreatePlatformVersionError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "ElasticBeanstalkServiceException" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             CreatePlatformVersionError::ElasticBeanstalkService(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "InsufficientPrivilegesException" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             CreatePlatformVersionError::InsufficientPrivileges(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "TooManyPlatformsException" => {
-                        return RusotoError::Service(CreatePlatformVersionError::TooManyPlatforms(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            CreatePlatformVersionError::TooManyPlatforms(parsed_error.message),
+                            &res,
+                        )
+                    }
+                    _ => {
+                        return RusotoError::unhandled(CreatePlatformVersionError::Unhandled, &res)
                     }
-                    _ => {}
                 }
             }
         }
@@ -7981,12 +8056,14 @@ impl fmt::Display for CreatePlatformVersionError {
             }
             CreatePlatformVersionError::InsufficientPrivileges(ref cause) => write!(f, "{}", cause),
             CreatePlatformVersionError::TooManyPlatforms(ref cause) => write!(f, "{}", cause),
+            CreatePlatformVersionError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreatePlatformVersionError {}
 /// Errors returned by CreateStorageLocation
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateStorageLocationError {
     /// <p>The specified account does not have sufficient privileges for one or more AWS services.</p>
     InsufficientPrivileges(String),
@@ -7994,6 +8071,8 @@ pub enum CreateStorageLocationError {
     S3SubscriptionRequired(String),
     /// <p>The specified account has reached its limit of Amazon S3 buckets.</p>
     TooManyBuckets(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateStorageLocationError {
@@ -8005,25 +8084,30 @@ impl CreateStorageLocationError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InsufficientPrivilegesException" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             CreateStorageLocationError::InsufficientPrivileges(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "S3SubscriptionRequiredException" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             CreateStorageLocationError::S3SubscriptionRequired(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "TooManyBucketsException" => {
-                        return RusotoError::Service(CreateStorageLocationError::TooManyBuckets(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            CreateStorageLocationError::TooManyBuckets(parsed_error.message),
+                            &res,
+                        )
+                    }
+                    _ => {
+                        return RusotoError::unhandled(CreateStorageLocationError::Unhandled, &res)
                     }
-                    _ => {}
                 }
             }
         }
@@ -8045,15 +8129,19 @@ impl fmt::Display for CreateStorageLocationError {
             CreateStorageLocationError::InsufficientPrivileges(ref cause) => write!(f, "{}", cause),
             CreateStorageLocationError::S3SubscriptionRequired(ref cause) => write!(f, "{}", cause),
             CreateStorageLocationError::TooManyBuckets(ref cause) => write!(f, "{}", cause),
+            CreateStorageLocationError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateStorageLocationError {}
 /// Errors returned by DeleteApplication
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteApplicationError {
     /// <p>Unable to perform the specified operation because another operation that effects an element in this activity is already in progress.</p>
     OperationInProgress(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteApplicationError {
@@ -8065,11 +8153,12 @@ impl DeleteApplicationError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "OperationInProgressFailure" => {
-                        return RusotoError::Service(DeleteApplicationError::OperationInProgress(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            DeleteApplicationError::OperationInProgress(parsed_error.message),
+                            &res,
+                        )
                     }
-                    _ => {}
+                    _ => return RusotoError::unhandled(DeleteApplicationError::Unhandled, &res),
                 }
             }
         }
@@ -8089,12 +8178,14 @@ impl fmt::Display for DeleteApplicationError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             DeleteApplicationError::OperationInProgress(ref cause) => write!(f, "{}", cause),
+            DeleteApplicationError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteApplicationError {}
 /// Errors returned by DeleteApplicationVersion
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteApplicationVersionError {
     /// <p>The specified account does not have sufficient privileges for one or more AWS services.</p>
     InsufficientPrivileges(String),
@@ -8104,6 +8195,8 @@ pub enum DeleteApplicationVersionError {
     S3LocationNotInServiceRegion(String),
     /// <p>Unable to delete the Amazon S3 source bundle associated with the application version. The application version was deleted successfully.</p>
     SourceBundleDeletion(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteApplicationVersionError {
@@ -8115,34 +8208,43 @@ impl DeleteApplicationVersionError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InsufficientPrivilegesException" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             DeleteApplicationVersionError::InsufficientPrivileges(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "OperationInProgressFailure" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             DeleteApplicationVersionError::OperationInProgress(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "S3LocationNotInServiceRegionException" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             DeleteApplicationVersionError::S3LocationNotInServiceRegion(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "SourceBundleDeletionFailure" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             DeleteApplicationVersionError::SourceBundleDeletion(
                                 parsed_error.message,
                             ),
+                            &res,
+                        )
+                    }
+                    _ => {
+                        return RusotoError::unhandled(
+                            DeleteApplicationVersionError::Unhandled,
+                            &res,
                         )
                     }
-                    _ => {}
                 }
             }
         }
@@ -8171,15 +8273,19 @@ impl fmt::Display for DeleteApplicationVersionError {
             DeleteApplicationVersionError::SourceBundleDeletion(ref cause) => {
                 write!(f, "{}", cause)
             }
+            DeleteApplicationVersionError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteApplicationVersionError {}
 /// Errors returned by DeleteConfigurationTemplate
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteConfigurationTemplateError {
     /// <p>Unable to perform the specified operation because another operation that effects an element in this activity is already in progress.</p>
     OperationInProgress(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteConfigurationTemplateError {
@@ -8193,13 +8299,19 @@ impl DeleteConfigurationTemplateError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "OperationInProgressFailure" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             DeleteConfigurationTemplateError::OperationInProgress(
                                 parsed_error.message,
                             ),
+                            &res,
+                        )
+                    }
+                    _ => {
+                        return RusotoError::unhandled(
+                            DeleteConfigurationTemplateError::Unhandled,
+                            &res,
                         )
                     }
-                    _ => {}
                 }
             }
         }
@@ -8221,13 +8333,18 @@ impl fmt::Display for DeleteConfigurationTemplateError {
             DeleteConfigurationTemplateError::OperationInProgress(ref cause) => {
                 write!(f, "{}", cause)
             }
+            DeleteConfigurationTemplateError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteConfigurationTemplateError {}
 /// Errors returned by DeleteEnvironmentConfiguration
 #[derive(Debug, PartialEq)]
-pub enum DeleteEnvironmentConfigurationError {}
+#[non_exhaustive]
+pub enum DeleteEnvironmentConfigurationError {
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
+}
 
 impl DeleteEnvironmentConfigurationError {
     pub fn from_response(
@@ -8237,10 +8354,11 @@ impl DeleteEnvironmentConfigurationError {
             let reader = EventReader::new(res.body.as_ref());
             let mut stack = XmlResponse::new(reader.into_iter().peekable());
             find_start_element(&mut stack);
-            if let Ok(parsed_error) = Self::deserialize(&mut stack) {
-                match &parsed_error.code[..] {
-                    _ => {}
-                }
+            if Self::deserialize(&mut stack).is_ok() {
+                return RusotoError::unhandled(
+                    DeleteEnvironmentConfigurationError::Unhandled,
+                    &res,
+                );
             }
         }
         RusotoError::Unknown(res)
@@ -8257,12 +8375,17 @@ impl DeleteEnvironmentConfigurationError {
 impl fmt::Display for DeleteEnvironmentConfigurationError {
     #[allow(unused_variables)]
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        match *self {}
+        match *self {
+            DeleteEnvironmentConfigurationError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
+        }
     }
 }
 impl Error for DeleteEnvironmentConfigurationError {}
 /// Errors returned by DeletePlatformVersion
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeletePlatformVersionError {
     /// <p>A generic service exception has occurred.</p>
     ElasticBeanstalkService(String),
@@ -8272,6 +8395,8 @@ pub enum DeletePlatformVersionError {
     OperationInProgress(String),
     /// <p>You cannot delete the platform version because there are still environments running on it.</p>
     PlatformVersionStillReferenced(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeletePlatformVersionError {
@@ -8283,32 +8408,38 @@ impl DeletePlatformVersionError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "ElasticBeanstalkServiceException" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             DeletePlatformVersionError::ElasticBeanstalkService(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "InsufficientPrivilegesException" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             DeletePlatformVersionError::InsufficientPrivileges(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "OperationInProgressFailure" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             DeletePlatformVersionError::OperationInProgress(parsed_error.message),
+                            &res,
                         )
                     }
                     "PlatformVersionStillReferencedException" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             DeletePlatformVersionError::PlatformVersionStillReferenced(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
-                    _ => {}
+                    _ => {
+                        return RusotoError::unhandled(DeletePlatformVersionError::Unhandled, &res)
+                    }
                 }
             }
         }
@@ -8335,15 +8466,19 @@ impl fmt::Display for DeletePlatformVersionError {
             DeletePlatformVersionError::PlatformVersionStillReferenced(ref cause) => {
                 write!(f, "{}", cause)
             }
+            DeletePlatformVersionError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeletePlatformVersionError {}
 /// Errors returned by DescribeAccountAttributes
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeAccountAttributesError {
     /// <p>The specified account does not have sufficient privileges for one or more AWS services.</p>
     InsufficientPrivileges(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeAccountAttributesError {
@@ -8355,13 +8490,19 @@ impl DescribeAccountAttributesError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InsufficientPrivilegesException" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             DescribeAccountAttributesError::InsufficientPrivileges(
                                 parsed_error.message,
                             ),
+                            &res,
+                        )
+                    }
+                    _ => {
+                        return RusotoError::unhandled(
+                            DescribeAccountAttributesError::Unhandled,
+                            &res,
                         )
                     }
-                    _ => {}
                 }
             }
         }
@@ -8383,13 +8524,18 @@ impl fmt::Display for DescribeAccountAttributesError {
             DescribeAccountAttributesError::InsufficientPrivileges(ref cause) => {
                 write!(f, "{}", cause)
             }
+            DescribeAccountAttributesError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeAccountAttributesError {}
 /// Errors returned by DescribeApplicationVersions
 #[derive(Debug, PartialEq)]
-pub enum DescribeApplicationVersionsError {}
+#[non_exhaustive]
+pub enum DescribeApplicationVersionsError {
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
+}
 
 impl DescribeApplicationVersionsError {
     pub fn from_response(
@@ -8399,10 +8545,8 @@ impl DescribeApplicationVersionsError {
             let reader = EventReader::new(res.body.as_ref());
             let mut stack = XmlResponse::new(reader.into_iter().peekable());
             find_start_element(&mut stack);
-            if let Ok(parsed_error) = Self::deserialize(&mut stack) {
-                match &parsed_error.code[..] {
-                    _ => {}
-                }
+            if Self::deserialize(&mut stack).is_ok() {
+                return RusotoError::unhandled(DescribeApplicationVersionsError::Unhandled, &res);
             }
         }
         RusotoError::Unknown(res)
@@ -8419,13 +8563,19 @@ impl DescribeApplicationVersionsError {
 impl fmt::Display for DescribeApplicationVersionsError {
     #[allow(unused_variables)]
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        match *self {}
+        match *self {
+            DescribeApplicationVersionsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
+        }
     }
 }
 impl Error for DescribeApplicationVersionsError {}
 /// Errors returned by DescribeApplications
 #[derive(Debug, PartialEq)]
-pub enum DescribeApplicationsError {}
+#[non_exhaustive]
+pub enum DescribeApplicationsError {
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
+}
 
 impl DescribeApplicationsError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<DescribeApplicationsError> {
@@ -8433,10 +8583,8 @@ impl DescribeApplicationsError {
             let reader = EventReader::new(res.body.as_ref());
             let mut stack = XmlResponse::new(reader.into_iter().peekable());
             find_start_element(&mut stack);
-            if let Ok(parsed_error) = Self::deserialize(&mut stack) {
-                match &parsed_error.code[..] {
-                    _ => {}
-                }
+            if Self::deserialize(&mut stack).is_ok() {
+                return RusotoError::unhandled(DescribeApplicationsError::Unhandled, &res);
             }
         }
         RusotoError::Unknown(res)
@@ -8453,15 +8601,20 @@ impl DescribeApplicationsError {
 impl fmt::Display for DescribeApplicationsError {
     #[allow(unused_variables)]
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        match *self {}
+        match *self {
+            DescribeApplicationsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
+        }
     }
 }
 impl Error for DescribeApplicationsError {}
 /// Errors returned by DescribeConfigurationOptions
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeConfigurationOptionsError {
     /// <p>The specified account has reached its limit of Amazon S3 buckets.</p>
     TooManyBuckets(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeConfigurationOptionsError {
@@ -8475,11 +8628,17 @@ impl DescribeConfigurationOptionsError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "TooManyBucketsException" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             DescribeConfigurationOptionsError::TooManyBuckets(parsed_error.message),
+                            &res,
+                        )
+                    }
+                    _ => {
+                        return RusotoError::unhandled(
+                            DescribeConfigurationOptionsError::Unhandled,
+                            &res,
                         )
                     }
-                    _ => {}
                 }
             }
         }
@@ -8499,15 +8658,19 @@ impl fmt::Display for DescribeConfigurationOptionsError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             DescribeConfigurationOptionsError::TooManyBuckets(ref cause) => write!(f, "{}", cause),
+            DescribeConfigurationOptionsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeConfigurationOptionsError {}
 /// Errors returned by DescribeConfigurationSettings
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeConfigurationSettingsError {
     /// <p>The specified account has reached its limit of Amazon S3 buckets.</p>
     TooManyBuckets(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeConfigurationSettingsError {
@@ -8521,13 +8684,19 @@ impl DescribeConfigurationSettingsError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "TooManyBucketsException" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             DescribeConfigurationSettingsError::TooManyBuckets(
                                 parsed_error.message,
                             ),
+                            &res,
+                        )
+                    }
+                    _ => {
+                        return RusotoError::unhandled(
+                            DescribeConfigurationSettingsError::Unhandled,
+                            &res,
                         )
                     }
-                    _ => {}
                 }
             }
         }
@@ -8547,17 +8716,23 @@ impl fmt::Display for DescribeConfigurationSettingsError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             DescribeConfigurationSettingsError::TooManyBuckets(ref cause) => write!(f, "{}", cause),
+            DescribeConfigurationSettingsError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for DescribeConfigurationSettingsError {}
 /// Errors returned by DescribeEnvironmentHealth
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeEnvironmentHealthError {
     /// <p>A generic service exception has occurred.</p>
     ElasticBeanstalkService(String),
     /// <p>One or more input parameters is not valid. Please correct the input parameters and try the operation again.</p>
     InvalidRequest(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeEnvironmentHealthError {
@@ -8569,18 +8744,25 @@ impl DescribeEnvironmentHealthError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "ElasticBeanstalkServiceException" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             DescribeEnvironmentHealthError::ElasticBeanstalkService(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "InvalidRequestException" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             DescribeEnvironmentHealthError::InvalidRequest(parsed_error.message),
+                            &res,
+                        )
+                    }
+                    _ => {
+                        return RusotoError::unhandled(
+                            DescribeEnvironmentHealthError::Unhandled,
+                            &res,
                         )
                     }
-                    _ => {}
                 }
             }
         }
@@ -8603,15 +8785,19 @@ impl fmt::Display for DescribeEnvironmentHealthError {
                 write!(f, "{}", cause)
             }
             DescribeEnvironmentHealthError::InvalidRequest(ref cause) => write!(f, "{}", cause),
+            DescribeEnvironmentHealthError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeEnvironmentHealthError {}
 /// Errors returned by DescribeEnvironmentManagedActionHistory
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeEnvironmentManagedActionHistoryError {
     /// <p>A generic service exception has occurred.</p>
     ElasticBeanstalkService(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeEnvironmentManagedActionHistoryError {
@@ -8625,13 +8811,19 @@ impl DescribeEnvironmentManagedActionHistoryError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "ElasticBeanstalkServiceException" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             DescribeEnvironmentManagedActionHistoryError::ElasticBeanstalkService(
                                 parsed_error.message,
                             ),
+                            &res,
+                        )
+                    }
+                    _ => {
+                        return RusotoError::unhandled(
+                            DescribeEnvironmentManagedActionHistoryError::Unhandled,
+                            &res,
                         )
                     }
-                    _ => {}
                 }
             }
         }
@@ -8653,15 +8845,21 @@ impl fmt::Display for DescribeEnvironmentManagedActionHistoryError {
             DescribeEnvironmentManagedActionHistoryError::ElasticBeanstalkService(ref cause) => {
                 write!(f, "{}", cause)
             }
+            DescribeEnvironmentManagedActionHistoryError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for DescribeEnvironmentManagedActionHistoryError {}
 /// Errors returned by DescribeEnvironmentManagedActions
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeEnvironmentManagedActionsError {
     /// <p>A generic service exception has occurred.</p>
     ElasticBeanstalkService(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeEnvironmentManagedActionsError {
@@ -8675,13 +8873,19 @@ impl DescribeEnvironmentManagedActionsError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "ElasticBeanstalkServiceException" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             DescribeEnvironmentManagedActionsError::ElasticBeanstalkService(
                                 parsed_error.message,
                             ),
+                            &res,
+                        )
+                    }
+                    _ => {
+                        return RusotoError::unhandled(
+                            DescribeEnvironmentManagedActionsError::Unhandled,
+                            &res,
                         )
                     }
-                    _ => {}
                 }
             }
         }
@@ -8703,15 +8907,21 @@ impl fmt::Display for DescribeEnvironmentManagedActionsError {
             DescribeEnvironmentManagedActionsError::ElasticBeanstalkService(ref cause) => {
                 write!(f, "{}", cause)
             }
+            DescribeEnvironmentManagedActionsError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for DescribeEnvironmentManagedActionsError {}
 /// Errors returned by DescribeEnvironmentResources
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeEnvironmentResourcesError {
     /// <p>The specified account does not have sufficient privileges for one or more AWS services.</p>
     InsufficientPrivileges(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeEnvironmentResourcesError {
@@ -8725,13 +8935,19 @@ impl DescribeEnvironmentResourcesError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InsufficientPrivilegesException" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             DescribeEnvironmentResourcesError::InsufficientPrivileges(
                                 parsed_error.message,
                             ),
+                            &res,
+                        )
+                    }
+                    _ => {
+                        return RusotoError::unhandled(
+                            DescribeEnvironmentResourcesError::Unhandled,
+                            &res,
                         )
                     }
-                    _ => {}
                 }
             }
         }
@@ -8753,13 +8969,18 @@ impl fmt::Display for DescribeEnvironmentResourcesError {
             DescribeEnvironmentResourcesError::InsufficientPrivileges(ref cause) => {
                 write!(f, "{}", cause)
             }
+            DescribeEnvironmentResourcesError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeEnvironmentResourcesError {}
 /// Errors returned by DescribeEnvironments
 #[derive(Debug, PartialEq)]
-pub enum DescribeEnvironmentsError {}
+#[non_exhaustive]
+pub enum DescribeEnvironmentsError {
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
+}
 
 impl DescribeEnvironmentsError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<DescribeEnvironmentsError> {
@@ -8767,10 +8988,8 @@ impl DescribeEnvironmentsError {
             let reader = EventReader::new(res.body.as_ref());
             let mut stack = XmlResponse::new(reader.into_iter().peekable());
             find_start_element(&mut stack);
-            if let Ok(parsed_error) = Self::deserialize(&mut stack) {
-                match &parsed_error.code[..] {
-                    _ => {}
-                }
+            if Self::deserialize(&mut stack).is_ok() {
+                return RusotoError::unhandled(DescribeEnvironmentsError::Unhandled, &res);
             }
         }
         RusotoError::Unknown(res)
@@ -8787,13 +9006,19 @@ impl DescribeEnvironmentsError {
 impl fmt::Display for DescribeEnvironmentsError {
     #[allow(unused_variables)]
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        match *self {}
+        match *self {
+            DescribeEnvironmentsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
+        }
     }
 }
 impl Error for DescribeEnvironmentsError {}
 /// Errors returned by DescribeEvents
 #[derive(Debug, PartialEq)]
-pub enum DescribeEventsError {}
+#[non_exhaustive]
+pub enum DescribeEventsError {
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
+}
 
 impl DescribeEventsError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<DescribeEventsError> {
@@ -8801,10 +9026,8 @@ impl DescribeEventsError {
             let reader = EventReader::new(res.body.as_ref());
             let mut stack = XmlResponse::new(reader.into_iter().peekable());
             find_start_element(&mut stack);
-            if let Ok(parsed_error) = Self::deserialize(&mut stack) {
-                match &parsed_error.code[..] {
-                    _ => {}
-                }
+            if Self::deserialize(&mut stack).is_ok() {
+                return RusotoError::unhandled(DescribeEventsError::Unhandled, &res);
             }
         }
         RusotoError::Unknown(res)
@@ -8821,17 +9044,22 @@ impl DescribeEventsError {
 impl fmt::Display for DescribeEventsError {
     #[allow(unused_variables)]
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        match *self {}
+        match *self {
+            DescribeEventsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
+        }
     }
 }
 impl Error for DescribeEventsError {}
 /// Errors returned by DescribeInstancesHealth
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeInstancesHealthError {
     /// <p>A generic service exception has occurred.</p>
     ElasticBeanstalkService(String),
     /// <p>One or more input parameters is not valid. Please correct the input parameters and try the operation again.</p>
     InvalidRequest(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeInstancesHealthError {
@@ -8843,18 +9071,25 @@ impl DescribeInstancesHealthError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "ElasticBeanstalkServiceException" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             DescribeInstancesHealthError::ElasticBeanstalkService(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "InvalidRequestException" => {
-                        return RusotoError::Service(DescribeInstancesHealthError::InvalidRequest(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            DescribeInstancesHealthError::InvalidRequest(parsed_error.message),
+                            &res,
+                        )
+                    }
+                    _ => {
+                        return RusotoError::unhandled(
+                            DescribeInstancesHealthError::Unhandled,
+                            &res,
+                        )
                     }
-                    _ => {}
                 }
             }
         }
@@ -8877,17 +9112,21 @@ impl fmt::Display for DescribeInstancesHealthError {
                 write!(f, "{}", cause)
             }
             DescribeInstancesHealthError::InvalidRequest(ref cause) => write!(f, "{}", cause),
+            DescribeInstancesHealthError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeInstancesHealthError {}
 /// Errors returned by DescribePlatformVersion
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribePlatformVersionError {
     /// <p>A generic service exception has occurred.</p>
     ElasticBeanstalkService(String),
     /// <p>The specified account does not have sufficient privileges for one or more AWS services.</p>
     InsufficientPrivileges(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribePlatformVersionError {
@@ -8899,20 +9138,27 @@ impl DescribePlatformVersionError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "ElasticBeanstalkServiceException" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             DescribePlatformVersionError::ElasticBeanstalkService(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "InsufficientPrivilegesException" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             DescribePlatformVersionError::InsufficientPrivileges(
                                 parsed_error.message,
                             ),
+                            &res,
+                        )
+                    }
+                    _ => {
+                        return RusotoError::unhandled(
+                            DescribePlatformVersionError::Unhandled,
+                            &res,
                         )
                     }
-                    _ => {}
                 }
             }
         }
@@ -8937,13 +9183,18 @@ impl fmt::Display for DescribePlatformVersionError {
             DescribePlatformVersionError::InsufficientPrivileges(ref cause) => {
                 write!(f, "{}", cause)
             }
+            DescribePlatformVersionError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribePlatformVersionError {}
 /// Errors returned by ListAvailableSolutionStacks
 #[derive(Debug, PartialEq)]
-pub enum ListAvailableSolutionStacksError {}
+#[non_exhaustive]
+pub enum ListAvailableSolutionStacksError {
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
+}
 
 impl ListAvailableSolutionStacksError {
     pub fn from_response(
@@ -8953,10 +9204,8 @@ impl ListAvailableSolutionStacksError {
             let reader = EventReader::new(res.body.as_ref());
             let mut stack = XmlResponse::new(reader.into_iter().peekable());
             find_start_element(&mut stack);
-            if let Ok(parsed_error) = Self::deserialize(&mut stack) {
-                match &parsed_error.code[..] {
-                    _ => {}
-                }
+            if Self::deserialize(&mut stack).is_ok() {
+                return RusotoError::unhandled(ListAvailableSolutionStacksError::Unhandled, &res);
             }
         }
         RusotoError::Unknown(res)
@@ -8973,17 +9222,22 @@ impl ListAvailableSolutionStacksError {
 impl fmt::Display for ListAvailableSolutionStacksError {
     #[allow(unused_variables)]
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        match *self {}
+        match *self {
+            ListAvailableSolutionStacksError::Unhandled(ref metadata) => write!(f, "{}", metadata),
+        }
     }
 }
 impl Error for ListAvailableSolutionStacksError {}
 /// Errors returned by ListPlatformVersions
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListPlatformVersionsError {
     /// <p>A generic service exception has occurred.</p>
     ElasticBeanstalkService(String),
     /// <p>The specified account does not have sufficient privileges for one or more AWS services.</p>
     InsufficientPrivileges(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListPlatformVersionsError {
@@ -8995,18 +9249,20 @@ impl ListPlatformVersionsError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "ElasticBeanstalkServiceException" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             ListPlatformVersionsError::ElasticBeanstalkService(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "InsufficientPrivilegesException" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             ListPlatformVersionsError::InsufficientPrivileges(parsed_error.message),
+                            &res,
                         )
                     }
-                    _ => {}
+                    _ => return RusotoError::unhandled(ListPlatformVersionsError::Unhandled, &res),
                 }
             }
         }
@@ -9027,12 +9283,14 @@ impl fmt::Display for ListPlatformVersionsError {
         match *self {
             ListPlatformVersionsError::ElasticBeanstalkService(ref cause) => write!(f, "{}", cause),
             ListPlatformVersionsError::InsufficientPrivileges(ref cause) => write!(f, "{}", cause),
+            ListPlatformVersionsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListPlatformVersionsError {}
 /// Errors returned by ListTagsForResource
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListTagsForResourceError {
     /// <p>The specified account does not have sufficient privileges for one or more AWS services.</p>
     InsufficientPrivileges(String),
@@ -9040,6 +9298,8 @@ pub enum ListTagsForResourceError {
     ResourceNotFound(String),
     /// <p>The type of the specified Amazon Resource Name (ARN) isn't supported for this operation.</p>
     ResourceTypeNotSupported(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListTagsForResourceError {
@@ -9051,23 +9311,26 @@ impl ListTagsForResourceError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InsufficientPrivilegesException" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             ListTagsForResourceError::InsufficientPrivileges(parsed_error.message),
+                            &res,
                         )
                     }
                     "ResourceNotFoundException" => {
-                        return RusotoError::Service(ListTagsForResourceError::ResourceNotFound(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            ListTagsForResourceError::ResourceNotFound(parsed_error.message),
+                            &res,
+                        )
                     }
                     "ResourceTypeNotSupportedException" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             ListTagsForResourceError::ResourceTypeNotSupported(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
-                    _ => {}
+                    _ => return RusotoError::unhandled(ListTagsForResourceError::Unhandled, &res),
                 }
             }
         }
@@ -9089,15 +9352,19 @@ impl fmt::Display for ListTagsForResourceError {
             ListTagsForResourceError::InsufficientPrivileges(ref cause) => write!(f, "{}", cause),
             ListTagsForResourceError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             ListTagsForResourceError::ResourceTypeNotSupported(ref cause) => write!(f, "{}", cause),
+            ListTagsForResourceError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListTagsForResourceError {}
 /// Errors returned by RebuildEnvironment
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum RebuildEnvironmentError {
     /// <p>The specified account does not have sufficient privileges for one or more AWS services.</p>
     InsufficientPrivileges(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl RebuildEnvironmentError {
@@ -9109,11 +9376,12 @@ impl RebuildEnvironmentError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InsufficientPrivilegesException" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             RebuildEnvironmentError::InsufficientPrivileges(parsed_error.message),
+                            &res,
                         )
                     }
-                    _ => {}
+                    _ => return RusotoError::unhandled(RebuildEnvironmentError::Unhandled, &res),
                 }
             }
         }
@@ -9133,13 +9401,18 @@ impl fmt::Display for RebuildEnvironmentError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             RebuildEnvironmentError::InsufficientPrivileges(ref cause) => write!(f, "{}", cause),
+            RebuildEnvironmentError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for RebuildEnvironmentError {}
 /// Errors returned by RequestEnvironmentInfo
 #[derive(Debug, PartialEq)]
-pub enum RequestEnvironmentInfoError {}
+#[non_exhaustive]
+pub enum RequestEnvironmentInfoError {
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
+}
 
 impl RequestEnvironmentInfoError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<RequestEnvironmentInfoError> {
@@ -9147,10 +9420,8 @@ impl RequestEnvironmentInfoError {
             let reader = EventReader::new(res.body.as_ref());
             let mut stack = XmlResponse::new(reader.into_iter().peekable());
             find_start_element(&mut stack);
-            if let Ok(parsed_error) = Self::deserialize(&mut stack) {
-                match &parsed_error.code[..] {
-                    _ => {}
-                }
+            if Self::deserialize(&mut stack).is_ok() {
+                return RusotoError::unhandled(RequestEnvironmentInfoError::Unhandled, &res);
             }
         }
         RusotoError::Unknown(res)
@@ -9167,13 +9438,19 @@ impl RequestEnvironmentInfoError {
 impl fmt::Display for RequestEnvironmentInfoError {
     #[allow(unused_variables)]
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        match *self {}
+        match *self {
+            RequestEnvironmentInfoError::Unhandled(ref metadata) => write!(f, "{}", metadata),
+        }
     }
 }
 impl Error for RequestEnvironmentInfoError {}
 /// Errors returned by RestartAppServer
 #[derive(Debug, PartialEq)]
-pub enum RestartAppServerError {}
+#[non_exhaustive]
+pub enum RestartAppServerError {
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
+}
 
 impl RestartAppServerError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<RestartAppServerError> {
@@ -9181,10 +9458,8 @@ impl RestartAppServerError {
             let reader = EventReader::new(res.body.as_ref());
             let mut stack = XmlResponse::new(reader.into_iter().peekable());
             find_start_element(&mut stack);
-            if let Ok(parsed_error) = Self::deserialize(&mut stack) {
-                match &parsed_error.code[..] {
-                    _ => {}
-                }
+            if Self::deserialize(&mut stack).is_ok() {
+                return RusotoError::unhandled(RestartAppServerError::Unhandled, &res);
             }
         }
         RusotoError::Unknown(res)
@@ -9201,13 +9476,19 @@ impl RestartAppServerError {
 impl fmt::Display for RestartAppServerError {
     #[allow(unused_variables)]
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        match *self {}
+        match *self {
+            RestartAppServerError::Unhandled(ref metadata) => write!(f, "{}", metadata),
+        }
     }
 }
 impl Error for RestartAppServerError {}
 /// Errors returned by RetrieveEnvironmentInfo
 #[derive(Debug, PartialEq)]
-pub enum RetrieveEnvironmentInfoError {}
+#[non_exhaustive]
+pub enum RetrieveEnvironmentInfoError {
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
+}
 
 impl RetrieveEnvironmentInfoError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<RetrieveEnvironmentInfoError> {
@@ -9215,10 +9496,8 @@ impl RetrieveEnvironmentInfoError {
             let reader = EventReader::new(res.body.as_ref());
             let mut stack = XmlResponse::new(reader.into_iter().peekable());
             find_start_element(&mut stack);
-            if let Ok(parsed_error) = Self::deserialize(&mut stack) {
-                match &parsed_error.code[..] {
-                    _ => {}
-                }
+            if Self::deserialize(&mut stack).is_ok() {
+                return RusotoError::unhandled(RetrieveEnvironmentInfoError::Unhandled, &res);
             }
         }
         RusotoError::Unknown(res)
@@ -9235,13 +9514,19 @@ impl RetrieveEnvironmentInfoError {
 impl fmt::Display for RetrieveEnvironmentInfoError {
     #[allow(unused_variables)]
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        match *self {}
+        match *self {
+            RetrieveEnvironmentInfoError::Unhandled(ref metadata) => write!(f, "{}", metadata),
+        }
     }
 }
 impl Error for RetrieveEnvironmentInfoError {}
 /// Errors returned by SwapEnvironmentCNAMEs
 #[derive(Debug, PartialEq)]
-pub enum SwapEnvironmentCNAMEsError {}
+#[non_exhaustive]
+pub enum SwapEnvironmentCNAMEsError {
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
+}
 
 impl SwapEnvironmentCNAMEsError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<SwapEnvironmentCNAMEsError> {
@@ -9249,10 +9534,8 @@ impl SwapEnvironmentCNAMEsError {
             let reader = EventReader::new(res.body.as_ref());
             let mut stack = XmlResponse::new(reader.into_iter().peekable());
             find_start_element(&mut stack);
-            if let Ok(parsed_error) = Self::deserialize(&mut stack) {
-                match &parsed_error.code[..] {
-                    _ => {}
-                }
+            if Self::deserialize(&mut stack).is_ok() {
+                return RusotoError::unhandled(SwapEnvironmentCNAMEsError::Unhandled, &res);
             }
         }
         RusotoError::Unknown(res)
@@ -9269,15 +9552,20 @@ impl SwapEnvironmentCNAMEsError {
 impl fmt::Display for SwapEnvironmentCNAMEsError {
     #[allow(unused_variables)]
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        match *self {}
+        match *self {
+            SwapEnvironmentCNAMEsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
+        }
     }
 }
 impl Error for SwapEnvironmentCNAMEsError {}
 /// Errors returned by TerminateEnvironment
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum TerminateEnvironmentError {
     /// <p>The specified account does not have sufficient privileges for one or more AWS services.</p>
     InsufficientPrivileges(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl TerminateEnvironmentError {
@@ -9289,11 +9577,12 @@ impl TerminateEnvironmentError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InsufficientPrivilegesException" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             TerminateEnvironmentError::InsufficientPrivileges(parsed_error.message),
+                            &res,
                         )
                     }
-                    _ => {}
+                    _ => return RusotoError::unhandled(TerminateEnvironmentError::Unhandled, &res),
                 }
             }
         }
@@ -9313,13 +9602,18 @@ impl fmt::Display for TerminateEnvironmentError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             TerminateEnvironmentError::InsufficientPrivileges(ref cause) => write!(f, "{}", cause),
+            TerminateEnvironmentError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for TerminateEnvironmentError {}
 /// Errors returned by UpdateApplication
 #[derive(Debug, PartialEq)]
-pub enum UpdateApplicationError {}
+#[non_exhaustive]
+pub enum UpdateApplicationError {
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
+}
 
 impl UpdateApplicationError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<UpdateApplicationError> {
@@ -9327,10 +9621,8 @@ impl UpdateApplicationError {
             let reader = EventReader::new(res.body.as_ref());
             let mut stack = XmlResponse::new(reader.into_iter().peekable());
             find_start_element(&mut stack);
-            if let Ok(parsed_error) = Self::deserialize(&mut stack) {
-                match &parsed_error.code[..] {
-                    _ => {}
-                }
+            if Self::deserialize(&mut stack).is_ok() {
+                return RusotoError::unhandled(UpdateApplicationError::Unhandled, &res);
             }
         }
         RusotoError::Unknown(res)
@@ -9347,15 +9639,20 @@ impl UpdateApplicationError {
 impl fmt::Display for UpdateApplicationError {
     #[allow(unused_variables)]
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        match *self {}
+        match *self {
+            UpdateApplicationError::Unhandled(ref metadata) => write!(f, "{}", metadata),
+        }
     }
 }
 impl Error for UpdateApplicationError {}
 /// Errors returned by UpdateApplicationResourceLifecycle
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdateApplicationResourceLifecycleError {
     /// <p>The specified account does not have sufficient privileges for one or more AWS services.</p>
     InsufficientPrivileges(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdateApplicationResourceLifecycleError {
@@ -9369,13 +9666,19 @@ impl UpdateApplicationResourceLifecycleError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InsufficientPrivilegesException" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             UpdateApplicationResourceLifecycleError::InsufficientPrivileges(
                                 parsed_error.message,
                             ),
+                            &res,
+                        )
+                    }
+                    _ => {
+                        return RusotoError::unhandled(
+                            UpdateApplicationResourceLifecycleError::Unhandled,
+                            &res,
                         )
                     }
-                    _ => {}
                 }
             }
         }
@@ -9397,13 +9700,20 @@ impl fmt::Display for UpdateApplicationResourceLifecycleError {
             UpdateApplicationResourceLifecycleError::InsufficientPrivileges(ref cause) => {
                 write!(f, "{}", cause)
             }
+            UpdateApplicationResourceLifecycleError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for UpdateApplicationResourceLifecycleError {}
 /// Errors returned by UpdateApplicationVersion
 #[derive(Debug, PartialEq)]
-pub enum UpdateApplicationVersionError {}
+#[non_exhaustive]
+pub enum UpdateApplicationVersionError {
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
+}
 
 impl UpdateApplicationVersionError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<UpdateApplicationVersionError> {
@@ -9411,10 +9721,8 @@ impl UpdateApplicationVersionError {
             let reader = EventReader::new(res.body.as_ref());
             let mut stack = XmlResponse::new(reader.into_iter().peekable());
             find_start_element(&mut stack);
-            if let Ok(parsed_error) = Self::deserialize(&mut stack) {
-                match &parsed_error.code[..] {
-                    _ => {}
-                }
+            if Self::deserialize(&mut stack).is_ok() {
+                return RusotoError::unhandled(UpdateApplicationVersionError::Unhandled, &res);
             }
         }
         RusotoError::Unknown(res)
@@ -9431,17 +9739,22 @@ impl UpdateApplicationVersionError {
 impl fmt::Display for UpdateApplicationVersionError {
     #[allow(unused_variables)]
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        match *self {}
+        match *self {
+            UpdateApplicationVersionError::Unhandled(ref metadata) => write!(f, "{}", metadata),
+        }
     }
 }
 impl Error for UpdateApplicationVersionError {}
 /// Errors returned by UpdateConfigurationTemplate
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdateConfigurationTemplateError {
     /// <p>The specified account does not have sufficient privileges for one or more AWS services.</p>
     InsufficientPrivileges(String),
     /// <p>The specified account has reached its limit of Amazon S3 buckets.</p>
     TooManyBuckets(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdateConfigurationTemplateError {
@@ -9455,18 +9768,25 @@ impl UpdateConfigurationTemplateError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InsufficientPrivilegesException" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             UpdateConfigurationTemplateError::InsufficientPrivileges(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "TooManyBucketsException" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             UpdateConfigurationTemplateError::TooManyBuckets(parsed_error.message),
+                            &res,
+                        )
+                    }
+                    _ => {
+                        return RusotoError::unhandled(
+                            UpdateConfigurationTemplateError::Unhandled,
+                            &res,
                         )
                     }
-                    _ => {}
                 }
             }
         }
@@ -9489,17 +9809,21 @@ impl fmt::Display for UpdateConfigurationTemplateError {
                 write!(f, "{}", cause)
             }
             UpdateConfigurationTemplateError::TooManyBuckets(ref cause) => write!(f, "{}", cause),
+            UpdateConfigurationTemplateError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for UpdateConfigurationTemplateError {}
 /// Errors returned by UpdateEnvironment
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdateEnvironmentError {
     /// <p>The specified account does not have sufficient privileges for one or more AWS services.</p>
     InsufficientPrivileges(String),
     /// <p>The specified account has reached its limit of Amazon S3 buckets.</p>
     TooManyBuckets(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdateEnvironmentError {
@@ -9511,16 +9835,18 @@ impl UpdateEnvironmentError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InsufficientPrivilegesException" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             UpdateEnvironmentError::InsufficientPrivileges(parsed_error.message),
+                            &res,
                         )
                     }
                     "TooManyBucketsException" => {
-                        return RusotoError::Service(UpdateEnvironmentError::TooManyBuckets(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            UpdateEnvironmentError::TooManyBuckets(parsed_error.message),
+                            &res,
+                        )
                     }
-                    _ => {}
+                    _ => return RusotoError::unhandled(UpdateEnvironmentError::Unhandled, &res),
                 }
             }
         }
@@ -9541,12 +9867,14 @@ impl fmt::Display for UpdateEnvironmentError {
         match *self {
             UpdateEnvironmentError::InsufficientPrivileges(ref cause) => write!(f, "{}", cause),
             UpdateEnvironmentError::TooManyBuckets(ref cause) => write!(f, "{}", cause),
+            UpdateEnvironmentError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for UpdateEnvironmentError {}
 /// Errors returned by UpdateTagsForResource
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdateTagsForResourceError {
     /// <p>The specified account does not have sufficient privileges for one or more AWS services.</p>
     InsufficientPrivileges(String),
@@ -9558,6 +9886,8 @@ pub enum UpdateTagsForResourceError {
     ResourceTypeNotSupported(String),
     /// <p>The number of tags in the resource would exceed the number of tags that each resource can have.</p> <p>To calculate this, the operation considers both the number of tags the resource already has and the tags this operation would add if it succeeded.</p>
     TooManyTags(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdateTagsForResourceError {
@@ -9569,35 +9899,42 @@ impl UpdateTagsForResourceError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InsufficientPrivilegesException" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             UpdateTagsForResourceError::InsufficientPrivileges(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "OperationInProgressFailure" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             UpdateTagsForResourceError::OperationInProgress(parsed_error.message),
+                            &res,
                         )
                     }
                     "ResourceNotFoundException" => {
-                        return RusotoError::Service(UpdateTagsForResourceError::ResourceNotFound(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            UpdateTagsForResourceError::ResourceNotFound(parsed_error.message),
+                            &res,
+                        )
                     }
                     "ResourceTypeNotSupportedException" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             UpdateTagsForResourceError::ResourceTypeNotSupported(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "TooManyTagsException" => {
-                        return RusotoError::Service(UpdateTagsForResourceError::TooManyTags(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            UpdateTagsForResourceError::TooManyTags(parsed_error.message),
+                            &res,
+                        )
+                    }
+                    _ => {
+                        return RusotoError::unhandled(UpdateTagsForResourceError::Unhandled, &res)
                     }
-                    _ => {}
                 }
             }
         }
@@ -9623,17 +9960,21 @@ impl fmt::Display for UpdateTagsForResourceError {
                 write!(f, "{}", cause)
             }
             UpdateTagsForResourceError::TooManyTags(ref cause) => write!(f, "{}", cause),
+            UpdateTagsForResourceError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for UpdateTagsForResourceError {}
 /// Errors returned by ValidateConfigurationSettings
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ValidateConfigurationSettingsError {
     /// <p>The specified account does not have sufficient privileges for one or more AWS services.</p>
     InsufficientPrivileges(String),
     /// <p>The specified account has reached its limit of Amazon S3 buckets.</p>
     TooManyBuckets(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ValidateConfigurationSettingsError {
@@ -9647,20 +9988,27 @@ impl ValidateConfigurationSettingsError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InsufficientPrivilegesException" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             ValidateConfigurationSettingsError::InsufficientPrivileges(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "TooManyBucketsException" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             ValidateConfigurationSettingsError::TooManyBuckets(
                                 parsed_error.message,
                             ),
+                            &res,
+                        )
+                    }
+                    _ => {
+                        return RusotoError::unhandled(
+                            ValidateConfigurationSettingsError::Unhandled,
+                            &res,
                         )
                     }
-                    _ => {}
                 }
             }
         }
@@ -9683,6 +10031,9 @@ impl fmt::Display for ValidateConfigurationSettingsError {
                 write!(f, "{}", cause)
             }
             ValidateConfigurationSettingsError::TooManyBuckets(ref cause) => write!(f, "{}", cause),
+            ValidateConfigurationSettingsError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
@@ -9980,6 +10331,7 @@ impl ElasticBeanstalkClient {
     /// Creates a client backed by the default tokio event loop.
     ///
     /// The client will use the default credentials provider and tls client.
+    #[cfg(not(target_arch = "wasm32"))]
     pub fn new(region: region::Region) -> ElasticBeanstalkClient {
         ElasticBeanstalkClient {
             client: Client::shared(),
@@ -10005,64 +10357,104 @@ impl ElasticBeanstalkClient {
     pub fn new_with_client(client: Client, region: region::Region) -> ElasticBeanstalkClient {
         ElasticBeanstalkClient { client, region }
     }
+
+    /// Returns a builder of a client, setting any of its region, credentials provider,
+    /// dispatcher, retry policy, endpoint and timeouts.
+    pub fn builder() -> rusoto_core::ClientBuilder<ElasticBeanstalkClient> {
+        rusoto_core::ClientBuilder::new(ElasticBeanstalkClient::new_with_client)
+    }
+
+    /// Returns a clone of this client with the overrides of `config` applied to its
+    /// operations, sharing its connections.
+    pub fn customize(&self, config: &rusoto_core::OperationConfig) -> ElasticBeanstalkClient {
+        ElasticBeanstalkClient {
+            client: self.client.customize(config),
+            region: config.resolve_region(&self.region),
+        }
+    }
 }
 
-#[async_trait]
-impl ElasticBeanstalk for ElasticBeanstalkClient {
-    /// <p>Cancels in-progress environment configuration update or application version deployment.</p>
-    async fn abort_environment_update(
+impl ElasticBeanstalkClient {
+    /// Calls `abort_environment_update`, also returning the metadata of the response.
+    pub async fn abort_environment_update_with_metadata(
         &self,
         input: AbortEnvironmentUpdateMessage,
-    ) -> Result<(), RusotoError<AbortEnvironmentUpdateError>> {
-        let mut request = SignedRequest::new("POST", "elasticbeanstalk", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "AbortEnvironmentUpdate");
-        params.put("Version", "2010-12-01");
-        AbortEnvironmentUpdateMessageSerializer::serialize(&mut params, "", &input);
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+    ) -> Result<((), ResponseMetadata), RusotoError<AbortEnvironmentUpdateError>> {
+        let request = self.build_abort_environment_update_request(input);
 
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(AbortEnvironmentUpdateError::from_response(response));
         }
 
         std::mem::drop(response);
-        Ok(())
+        Ok(((), metadata))
     }
 
-    /// <p>Applies a scheduled managed action immediately. A managed action can be applied only if its status is <code>Scheduled</code>. Get the status and action ID of a managed action with <a>DescribeEnvironmentManagedActions</a>.</p>
-    async fn apply_environment_managed_action(
+    /// Presigns the request `abort_environment_update` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_abort_environment_update(
         &self,
-        input: ApplyEnvironmentManagedActionRequest,
-    ) -> Result<ApplyEnvironmentManagedActionResult, RusotoError<ApplyEnvironmentManagedActionError>>
-    {
+        presigner: &rusoto_core::Presigner,
+        input: AbortEnvironmentUpdateMessage,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_abort_environment_update_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_abort_environment_update_request(
+        &self,
+        input: AbortEnvironmentUpdateMessage,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "elasticbeanstalk", &self.region, "/");
+        request.set_operation("AbortEnvironmentUpdate");
         let mut params = Params::new();
 
-        params.put("Action", "ApplyEnvironmentManagedAction");
+        params.put("Action", "AbortEnvironmentUpdate");
         params.put("Version", "2010-12-01");
-        ApplyEnvironmentManagedActionRequestSerializer::serialize(&mut params, "", &input);
+        AbortEnvironmentUpdateMessageSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `apply_environment_managed_action`, also returning the metadata of the response.
+    pub async fn apply_environment_managed_action_with_metadata(
+        &self,
+        input: ApplyEnvironmentManagedActionRequest,
+    ) -> Result<
+        (ApplyEnvironmentManagedActionResult, ResponseMetadata),
+        RusotoError<ApplyEnvironmentManagedActionError>,
+    > {
+        let request = self.build_apply_environment_managed_action_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(ApplyEnvironmentManagedActionError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -10084,34 +10476,64 @@ impl ElasticBeanstalk for ElasticBeanstalkClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Checks if the specified CNAME is available.</p>
-    async fn check_dns_availability(
+    /// Presigns the request `apply_environment_managed_action` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_apply_environment_managed_action(
         &self,
-        input: CheckDNSAvailabilityMessage,
-    ) -> Result<CheckDNSAvailabilityResultMessage, RusotoError<CheckDNSAvailabilityError>> {
+        presigner: &rusoto_core::Presigner,
+        input: ApplyEnvironmentManagedActionRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_apply_environment_managed_action_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_apply_environment_managed_action_request(
+        &self,
+        input: ApplyEnvironmentManagedActionRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "elasticbeanstalk", &self.region, "/");
+        request.set_operation("ApplyEnvironmentManagedAction");
         let mut params = Params::new();
 
-        params.put("Action", "CheckDNSAvailability");
+        params.put("Action", "ApplyEnvironmentManagedAction");
         params.put("Version", "2010-12-01");
-        CheckDNSAvailabilityMessageSerializer::serialize(&mut params, "", &input);
+        ApplyEnvironmentManagedActionRequestSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `check_dns_availability`, also returning the metadata of the response.
+    pub async fn check_dns_availability_with_metadata(
+        &self,
+        input: CheckDNSAvailabilityMessage,
+    ) -> Result<
+        (CheckDNSAvailabilityResultMessage, ResponseMetadata),
+        RusotoError<CheckDNSAvailabilityError>,
+    > {
+        let request = self.build_check_dns_availability_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(CheckDNSAvailabilityError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -10133,34 +10555,64 @@ impl ElasticBeanstalk for ElasticBeanstalkClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Create or update a group of environments that each run a separate component of a single application. Takes a list of version labels that specify application source bundles for each of the environments to create or update. The name of each environment and other required information must be included in the source bundles in an environment manifest named <code>env.yaml</code>. See <a href="https://docs.aws.amazon.com/elasticbeanstalk/latest/dg/environment-mgmt-compose.html">Compose Environments</a> for details.</p>
-    async fn compose_environments(
+    /// Presigns the request `check_dns_availability` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_check_dns_availability(
         &self,
-        input: ComposeEnvironmentsMessage,
-    ) -> Result<EnvironmentDescriptionsMessage, RusotoError<ComposeEnvironmentsError>> {
+        presigner: &rusoto_core::Presigner,
+        input: CheckDNSAvailabilityMessage,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_check_dns_availability_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_check_dns_availability_request(
+        &self,
+        input: CheckDNSAvailabilityMessage,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "elasticbeanstalk", &self.region, "/");
+        request.set_operation("CheckDNSAvailability");
         let mut params = Params::new();
 
-        params.put("Action", "ComposeEnvironments");
+        params.put("Action", "CheckDNSAvailability");
         params.put("Version", "2010-12-01");
-        ComposeEnvironmentsMessageSerializer::serialize(&mut params, "", &input);
+        CheckDNSAvailabilityMessageSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `compose_environments`, also returning the metadata of the response.
+    pub async fn compose_environments_with_metadata(
+        &self,
+        input: ComposeEnvironmentsMessage,
+    ) -> Result<
+        (EnvironmentDescriptionsMessage, ResponseMetadata),
+        RusotoError<ComposeEnvironmentsError>,
+    > {
+        let request = self.build_compose_environments_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(ComposeEnvironmentsError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -10182,34 +10634,64 @@ impl ElasticBeanstalk for ElasticBeanstalkClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p> Creates an application that has one configuration template named <code>default</code> and no application versions. </p>
-    async fn create_application(
+    /// Presigns the request `compose_environments` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_compose_environments(
         &self,
-        input: CreateApplicationMessage,
-    ) -> Result<ApplicationDescriptionMessage, RusotoError<CreateApplicationError>> {
+        presigner: &rusoto_core::Presigner,
+        input: ComposeEnvironmentsMessage,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_compose_environments_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_compose_environments_request(
+        &self,
+        input: ComposeEnvironmentsMessage,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "elasticbeanstalk", &self.region, "/");
+        request.set_operation("ComposeEnvironments");
         let mut params = Params::new();
 
-        params.put("Action", "CreateApplication");
+        params.put("Action", "ComposeEnvironments");
         params.put("Version", "2010-12-01");
-        CreateApplicationMessageSerializer::serialize(&mut params, "", &input);
+        ComposeEnvironmentsMessageSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `create_application`, also returning the metadata of the response.
+    pub async fn create_application_with_metadata(
+        &self,
+        input: CreateApplicationMessage,
+    ) -> Result<
+        (ApplicationDescriptionMessage, ResponseMetadata),
+        RusotoError<CreateApplicationError>,
+    > {
+        let request = self.build_create_application_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(CreateApplicationError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -10231,35 +10713,61 @@ impl ElasticBeanstalk for ElasticBeanstalkClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p><p>Creates an application version for the specified application. You can create an application version from a source bundle in Amazon S3, a commit in AWS CodeCommit, or the output of an AWS CodeBuild build as follows:</p> <p>Specify a commit in an AWS CodeCommit repository with <code>SourceBuildInformation</code>.</p> <p>Specify a build in an AWS CodeBuild with <code>SourceBuildInformation</code> and <code>BuildConfiguration</code>.</p> <p>Specify a source bundle in S3 with <code>SourceBundle</code> </p> <p>Omit both <code>SourceBuildInformation</code> and <code>SourceBundle</code> to use the default sample application.</p> <note> <p>Once you create an application version with a specified Amazon S3 bucket and key location, you cannot change that Amazon S3 location. If you change the Amazon S3 location, you receive an exception when you attempt to launch an environment from the application version.</p> </note></p>
-    async fn create_application_version(
+    /// Presigns the request `create_application` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_application(
         &self,
-        input: CreateApplicationVersionMessage,
-    ) -> Result<ApplicationVersionDescriptionMessage, RusotoError<CreateApplicationVersionError>>
-    {
+        presigner: &rusoto_core::Presigner,
+        input: CreateApplicationMessage,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_application_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_application_request(&self, input: CreateApplicationMessage) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "elasticbeanstalk", &self.region, "/");
+        request.set_operation("CreateApplication");
         let mut params = Params::new();
 
-        params.put("Action", "CreateApplicationVersion");
+        params.put("Action", "CreateApplication");
         params.put("Version", "2010-12-01");
-        CreateApplicationVersionMessageSerializer::serialize(&mut params, "", &input);
+        CreateApplicationMessageSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `create_application_version`, also returning the metadata of the response.
+    pub async fn create_application_version_with_metadata(
+        &self,
+        input: CreateApplicationVersionMessage,
+    ) -> Result<
+        (ApplicationVersionDescriptionMessage, ResponseMetadata),
+        RusotoError<CreateApplicationVersionError>,
+    > {
+        let request = self.build_create_application_version_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(CreateApplicationVersionError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -10281,35 +10789,64 @@ impl ElasticBeanstalk for ElasticBeanstalkClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p><p>Creates a configuration template. Templates are associated with a specific application and are used to deploy different versions of the application with the same configuration settings.</p> <p>Templates aren&#39;t associated with any environment. The <code>EnvironmentName</code> response element is always <code>null</code>.</p> <p>Related Topics</p> <ul> <li> <p> <a>DescribeConfigurationOptions</a> </p> </li> <li> <p> <a>DescribeConfigurationSettings</a> </p> </li> <li> <p> <a>ListAvailableSolutionStacks</a> </p> </li> </ul></p>
-    async fn create_configuration_template(
+    /// Presigns the request `create_application_version` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_application_version(
         &self,
-        input: CreateConfigurationTemplateMessage,
-    ) -> Result<ConfigurationSettingsDescription, RusotoError<CreateConfigurationTemplateError>>
-    {
+        presigner: &rusoto_core::Presigner,
+        input: CreateApplicationVersionMessage,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_application_version_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_application_version_request(
+        &self,
+        input: CreateApplicationVersionMessage,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "elasticbeanstalk", &self.region, "/");
+        request.set_operation("CreateApplicationVersion");
         let mut params = Params::new();
 
-        params.put("Action", "CreateConfigurationTemplate");
+        params.put("Action", "CreateApplicationVersion");
         params.put("Version", "2010-12-01");
-        CreateConfigurationTemplateMessageSerializer::serialize(&mut params, "", &input);
+        CreateApplicationVersionMessageSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `create_configuration_template`, also returning the metadata of the response.
+    pub async fn create_configuration_template_with_metadata(
+        &self,
+        input: CreateConfigurationTemplateMessage,
+    ) -> Result<
+        (ConfigurationSettingsDescription, ResponseMetadata),
+        RusotoError<CreateConfigurationTemplateError>,
+    > {
+        let request = self.build_create_configuration_template_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(CreateConfigurationTemplateError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -10331,34 +10868,62 @@ impl ElasticBeanstalk for ElasticBeanstalkClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Launches an environment for the specified application using the specified configuration.</p>
-    async fn create_environment(
+    /// Presigns the request `create_configuration_template` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_configuration_template(
         &self,
-        input: CreateEnvironmentMessage,
-    ) -> Result<EnvironmentDescription, RusotoError<CreateEnvironmentError>> {
+        presigner: &rusoto_core::Presigner,
+        input: CreateConfigurationTemplateMessage,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_configuration_template_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_configuration_template_request(
+        &self,
+        input: CreateConfigurationTemplateMessage,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "elasticbeanstalk", &self.region, "/");
+        request.set_operation("CreateConfigurationTemplate");
         let mut params = Params::new();
 
-        params.put("Action", "CreateEnvironment");
+        params.put("Action", "CreateConfigurationTemplate");
         params.put("Version", "2010-12-01");
-        CreateEnvironmentMessageSerializer::serialize(&mut params, "", &input);
+        CreateConfigurationTemplateMessageSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `create_environment`, also returning the metadata of the response.
+    pub async fn create_environment_with_metadata(
+        &self,
+        input: CreateEnvironmentMessage,
+    ) -> Result<(EnvironmentDescription, ResponseMetadata), RusotoError<CreateEnvironmentError>>
+    {
+        let request = self.build_create_environment_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(CreateEnvironmentError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -10380,34 +10945,61 @@ impl ElasticBeanstalk for ElasticBeanstalkClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Create a new version of your custom platform.</p>
-    async fn create_platform_version(
+    /// Presigns the request `create_environment` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_environment(
         &self,
-        input: CreatePlatformVersionRequest,
-    ) -> Result<CreatePlatformVersionResult, RusotoError<CreatePlatformVersionError>> {
+        presigner: &rusoto_core::Presigner,
+        input: CreateEnvironmentMessage,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_environment_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_environment_request(&self, input: CreateEnvironmentMessage) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "elasticbeanstalk", &self.region, "/");
+        request.set_operation("CreateEnvironment");
         let mut params = Params::new();
 
-        params.put("Action", "CreatePlatformVersion");
+        params.put("Action", "CreateEnvironment");
         params.put("Version", "2010-12-01");
-        CreatePlatformVersionRequestSerializer::serialize(&mut params, "", &input);
+        CreateEnvironmentMessageSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `create_platform_version`, also returning the metadata of the response.
+    pub async fn create_platform_version_with_metadata(
+        &self,
+        input: CreatePlatformVersionRequest,
+    ) -> Result<
+        (CreatePlatformVersionResult, ResponseMetadata),
+        RusotoError<CreatePlatformVersionError>,
+    > {
+        let request = self.build_create_platform_version_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(CreatePlatformVersionError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -10429,33 +11021,63 @@ impl ElasticBeanstalk for ElasticBeanstalkClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Creates a bucket in Amazon S3 to store application versions, logs, and other files used by Elastic Beanstalk environments. The Elastic Beanstalk console and EB CLI call this API the first time you create an environment in a region. If the storage location already exists, <code>CreateStorageLocation</code> still returns the bucket name but does not create a new bucket.</p>
-    async fn create_storage_location(
+    /// Presigns the request `create_platform_version` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_platform_version(
         &self,
-    ) -> Result<CreateStorageLocationResultMessage, RusotoError<CreateStorageLocationError>> {
+        presigner: &rusoto_core::Presigner,
+        input: CreatePlatformVersionRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_platform_version_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_platform_version_request(
+        &self,
+        input: CreatePlatformVersionRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "elasticbeanstalk", &self.region, "/");
+        request.set_operation("CreatePlatformVersion");
         let mut params = Params::new();
 
-        params.put("Action", "CreateStorageLocation");
+        params.put("Action", "CreatePlatformVersion");
         params.put("Version", "2010-12-01");
-
+        CreatePlatformVersionRequestSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `create_storage_location`, also returning the metadata of the response.
+    pub async fn create_storage_location_with_metadata(
+        &self,
+    ) -> Result<
+        (CreateStorageLocationResultMessage, ResponseMetadata),
+        RusotoError<CreateStorageLocationError>,
+    > {
+        let request = self.build_create_storage_location_request();
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(CreateStorageLocationError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -10477,146 +11099,265 @@ impl ElasticBeanstalk for ElasticBeanstalkClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p><p>Deletes the specified application along with all associated versions and configurations. The application versions will not be deleted from your Amazon S3 bucket.</p> <note> <p>You cannot delete an application that has a running environment.</p> </note></p>
-    async fn delete_application(
+    /// Presigns the request `create_storage_location` would send with `presigner` instead of sending it.
+    pub async fn presign_create_storage_location(
         &self,
-        input: DeleteApplicationMessage,
-    ) -> Result<(), RusotoError<DeleteApplicationError>> {
+        presigner: &rusoto_core::Presigner,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_storage_location_request();
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_storage_location_request(&self) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "elasticbeanstalk", &self.region, "/");
+        request.set_operation("CreateStorageLocation");
         let mut params = Params::new();
 
-        params.put("Action", "DeleteApplication");
+        params.put("Action", "CreateStorageLocation");
         params.put("Version", "2010-12-01");
-        DeleteApplicationMessageSerializer::serialize(&mut params, "", &input);
+
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `delete_application`, also returning the metadata of the response.
+    pub async fn delete_application_with_metadata(
+        &self,
+        input: DeleteApplicationMessage,
+    ) -> Result<((), ResponseMetadata), RusotoError<DeleteApplicationError>> {
+        let request = self.build_delete_application_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(DeleteApplicationError::from_response(response));
         }
 
         std::mem::drop(response);
-        Ok(())
+        Ok(((), metadata))
     }
 
-    /// <p><p>Deletes the specified version from the specified application.</p> <note> <p>You cannot delete an application version that is associated with a running environment.</p> </note></p>
-    async fn delete_application_version(
+    /// Presigns the request `delete_application` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_application(
         &self,
-        input: DeleteApplicationVersionMessage,
-    ) -> Result<(), RusotoError<DeleteApplicationVersionError>> {
+        presigner: &rusoto_core::Presigner,
+        input: DeleteApplicationMessage,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_application_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_application_request(&self, input: DeleteApplicationMessage) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "elasticbeanstalk", &self.region, "/");
+        request.set_operation("DeleteApplication");
         let mut params = Params::new();
 
-        params.put("Action", "DeleteApplicationVersion");
+        params.put("Action", "DeleteApplication");
         params.put("Version", "2010-12-01");
-        DeleteApplicationVersionMessageSerializer::serialize(&mut params, "", &input);
+        DeleteApplicationMessageSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `delete_application_version`, also returning the metadata of the response.
+    pub async fn delete_application_version_with_metadata(
+        &self,
+        input: DeleteApplicationVersionMessage,
+    ) -> Result<((), ResponseMetadata), RusotoError<DeleteApplicationVersionError>> {
+        let request = self.build_delete_application_version_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(DeleteApplicationVersionError::from_response(response));
         }
 
         std::mem::drop(response);
-        Ok(())
+        Ok(((), metadata))
     }
 
-    /// <p><p>Deletes the specified configuration template.</p> <note> <p>When you launch an environment using a configuration template, the environment gets a copy of the template. You can delete or modify the environment&#39;s copy of the template without affecting the running environment.</p> </note></p>
-    async fn delete_configuration_template(
+    /// Presigns the request `delete_application_version` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_application_version(
         &self,
-        input: DeleteConfigurationTemplateMessage,
-    ) -> Result<(), RusotoError<DeleteConfigurationTemplateError>> {
+        presigner: &rusoto_core::Presigner,
+        input: DeleteApplicationVersionMessage,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_application_version_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_application_version_request(
+        &self,
+        input: DeleteApplicationVersionMessage,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "elasticbeanstalk", &self.region, "/");
+        request.set_operation("DeleteApplicationVersion");
         let mut params = Params::new();
 
-        params.put("Action", "DeleteConfigurationTemplate");
+        params.put("Action", "DeleteApplicationVersion");
         params.put("Version", "2010-12-01");
-        DeleteConfigurationTemplateMessageSerializer::serialize(&mut params, "", &input);
+        DeleteApplicationVersionMessageSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `delete_configuration_template`, also returning the metadata of the response.
+    pub async fn delete_configuration_template_with_metadata(
+        &self,
+        input: DeleteConfigurationTemplateMessage,
+    ) -> Result<((), ResponseMetadata), RusotoError<DeleteConfigurationTemplateError>> {
+        let request = self.build_delete_configuration_template_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(DeleteConfigurationTemplateError::from_response(response));
         }
 
         std::mem::drop(response);
-        Ok(())
+        Ok(((), metadata))
     }
 
-    /// <p>Deletes the draft configuration associated with the running environment.</p> <p>Updating a running environment with any configuration changes creates a draft configuration set. You can get the draft configuration using <a>DescribeConfigurationSettings</a> while the update is in progress or if the update fails. The <code>DeploymentStatus</code> for the draft configuration indicates whether the deployment is in process or has failed. The draft configuration remains in existence until it is deleted with this action.</p>
-    async fn delete_environment_configuration(
+    /// Presigns the request `delete_configuration_template` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_configuration_template(
         &self,
-        input: DeleteEnvironmentConfigurationMessage,
-    ) -> Result<(), RusotoError<DeleteEnvironmentConfigurationError>> {
+        presigner: &rusoto_core::Presigner,
+        input: DeleteConfigurationTemplateMessage,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_configuration_template_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_configuration_template_request(
+        &self,
+        input: DeleteConfigurationTemplateMessage,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "elasticbeanstalk", &self.region, "/");
+        request.set_operation("DeleteConfigurationTemplate");
         let mut params = Params::new();
 
-        params.put("Action", "DeleteEnvironmentConfiguration");
+        params.put("Action", "DeleteConfigurationTemplate");
         params.put("Version", "2010-12-01");
-        DeleteEnvironmentConfigurationMessageSerializer::serialize(&mut params, "", &input);
+        DeleteConfigurationTemplateMessageSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `delete_environment_configuration`, also returning the metadata of the response.
+    pub async fn delete_environment_configuration_with_metadata(
+        &self,
+        input: DeleteEnvironmentConfigurationMessage,
+    ) -> Result<((), ResponseMetadata), RusotoError<DeleteEnvironmentConfigurationError>> {
+        let request = self.build_delete_environment_configuration_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(DeleteEnvironmentConfigurationError::from_response(response));
         }
 
         std::mem::drop(response);
-        Ok(())
+        Ok(((), metadata))
     }
 
-    /// <p>Deletes the specified version of a custom platform.</p>
-    async fn delete_platform_version(
+    /// Presigns the request `delete_environment_configuration` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_environment_configuration(
         &self,
-        input: DeletePlatformVersionRequest,
-    ) -> Result<DeletePlatformVersionResult, RusotoError<DeletePlatformVersionError>> {
+        presigner: &rusoto_core::Presigner,
+        input: DeleteEnvironmentConfigurationMessage,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_environment_configuration_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_environment_configuration_request(
+        &self,
+        input: DeleteEnvironmentConfigurationMessage,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "elasticbeanstalk", &self.region, "/");
+        request.set_operation("DeleteEnvironmentConfiguration");
         let mut params = Params::new();
 
-        params.put("Action", "DeletePlatformVersion");
+        params.put("Action", "DeleteEnvironmentConfiguration");
         params.put("Version", "2010-12-01");
-        DeletePlatformVersionRequestSerializer::serialize(&mut params, "", &input);
+        DeleteEnvironmentConfigurationMessageSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `delete_platform_version`, also returning the metadata of the response.
+    pub async fn delete_platform_version_with_metadata(
+        &self,
+        input: DeletePlatformVersionRequest,
+    ) -> Result<
+        (DeletePlatformVersionResult, ResponseMetadata),
+        RusotoError<DeletePlatformVersionError>,
+    > {
+        let request = self.build_delete_platform_version_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(DeletePlatformVersionError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -10638,33 +11379,63 @@ impl ElasticBeanstalk for ElasticBeanstalkClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Returns attributes related to AWS Elastic Beanstalk that are associated with the calling AWS account.</p> <p>The result currently has one set of attributes—resource quotas.</p>
-    async fn describe_account_attributes(
+    /// Presigns the request `delete_platform_version` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_platform_version(
         &self,
-    ) -> Result<DescribeAccountAttributesResult, RusotoError<DescribeAccountAttributesError>> {
+        presigner: &rusoto_core::Presigner,
+        input: DeletePlatformVersionRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_platform_version_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_platform_version_request(
+        &self,
+        input: DeletePlatformVersionRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "elasticbeanstalk", &self.region, "/");
+        request.set_operation("DeletePlatformVersion");
         let mut params = Params::new();
 
-        params.put("Action", "DescribeAccountAttributes");
+        params.put("Action", "DeletePlatformVersion");
         params.put("Version", "2010-12-01");
-
+        DeletePlatformVersionRequestSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `describe_account_attributes`, also returning the metadata of the response.
+    pub async fn describe_account_attributes_with_metadata(
+        &self,
+    ) -> Result<
+        (DescribeAccountAttributesResult, ResponseMetadata),
+        RusotoError<DescribeAccountAttributesError>,
+    > {
+        let request = self.build_describe_account_attributes_request();
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(DescribeAccountAttributesError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -10686,35 +11457,60 @@ impl ElasticBeanstalk for ElasticBeanstalkClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Retrieve a list of application versions.</p>
-    async fn describe_application_versions(
+    /// Presigns the request `describe_account_attributes` would send with `presigner` instead of sending it.
+    pub async fn presign_describe_account_attributes(
         &self,
-        input: DescribeApplicationVersionsMessage,
-    ) -> Result<ApplicationVersionDescriptionsMessage, RusotoError<DescribeApplicationVersionsError>>
-    {
+        presigner: &rusoto_core::Presigner,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_account_attributes_request();
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_account_attributes_request(&self) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "elasticbeanstalk", &self.region, "/");
+        request.set_operation("DescribeAccountAttributes");
         let mut params = Params::new();
 
-        params.put("Action", "DescribeApplicationVersions");
+        params.put("Action", "DescribeAccountAttributes");
         params.put("Version", "2010-12-01");
-        DescribeApplicationVersionsMessageSerializer::serialize(&mut params, "", &input);
+
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `describe_application_versions`, also returning the metadata of the response.
+    pub async fn describe_application_versions_with_metadata(
+        &self,
+        input: DescribeApplicationVersionsMessage,
+    ) -> Result<
+        (ApplicationVersionDescriptionsMessage, ResponseMetadata),
+        RusotoError<DescribeApplicationVersionsError>,
+    > {
+        let request = self.build_describe_application_versions_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(DescribeApplicationVersionsError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -10736,34 +11532,64 @@ impl ElasticBeanstalk for ElasticBeanstalkClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Returns the descriptions of existing applications.</p>
-    async fn describe_applications(
+    /// Presigns the request `describe_application_versions` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_application_versions(
         &self,
-        input: DescribeApplicationsMessage,
-    ) -> Result<ApplicationDescriptionsMessage, RusotoError<DescribeApplicationsError>> {
+        presigner: &rusoto_core::Presigner,
+        input: DescribeApplicationVersionsMessage,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_application_versions_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_application_versions_request(
+        &self,
+        input: DescribeApplicationVersionsMessage,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "elasticbeanstalk", &self.region, "/");
+        request.set_operation("DescribeApplicationVersions");
         let mut params = Params::new();
 
-        params.put("Action", "DescribeApplications");
+        params.put("Action", "DescribeApplicationVersions");
         params.put("Version", "2010-12-01");
-        DescribeApplicationsMessageSerializer::serialize(&mut params, "", &input);
+        DescribeApplicationVersionsMessageSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `describe_applications`, also returning the metadata of the response.
+    pub async fn describe_applications_with_metadata(
+        &self,
+        input: DescribeApplicationsMessage,
+    ) -> Result<
+        (ApplicationDescriptionsMessage, ResponseMetadata),
+        RusotoError<DescribeApplicationsError>,
+    > {
+        let request = self.build_describe_applications_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(DescribeApplicationsError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -10785,35 +11611,64 @@ impl ElasticBeanstalk for ElasticBeanstalkClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Describes the configuration options that are used in a particular configuration template or environment, or that a specified solution stack defines. The description includes the values the options, their default values, and an indication of the required action on a running environment if an option value is changed.</p>
-    async fn describe_configuration_options(
+    /// Presigns the request `describe_applications` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_applications(
         &self,
-        input: DescribeConfigurationOptionsMessage,
-    ) -> Result<ConfigurationOptionsDescription, RusotoError<DescribeConfigurationOptionsError>>
-    {
+        presigner: &rusoto_core::Presigner,
+        input: DescribeApplicationsMessage,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_applications_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_applications_request(
+        &self,
+        input: DescribeApplicationsMessage,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "elasticbeanstalk", &self.region, "/");
+        request.set_operation("DescribeApplications");
         let mut params = Params::new();
 
-        params.put("Action", "DescribeConfigurationOptions");
+        params.put("Action", "DescribeApplications");
         params.put("Version", "2010-12-01");
-        DescribeConfigurationOptionsMessageSerializer::serialize(&mut params, "", &input);
+        DescribeApplicationsMessageSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `describe_configuration_options`, also returning the metadata of the response.
+    pub async fn describe_configuration_options_with_metadata(
+        &self,
+        input: DescribeConfigurationOptionsMessage,
+    ) -> Result<
+        (ConfigurationOptionsDescription, ResponseMetadata),
+        RusotoError<DescribeConfigurationOptionsError>,
+    > {
+        let request = self.build_describe_configuration_options_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(DescribeConfigurationOptionsError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -10835,35 +11690,64 @@ impl ElasticBeanstalk for ElasticBeanstalkClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p><p>Returns a description of the settings for the specified configuration set, that is, either a configuration template or the configuration set associated with a running environment.</p> <p>When describing the settings for the configuration set associated with a running environment, it is possible to receive two sets of setting descriptions. One is the deployed configuration set, and the other is a draft configuration of an environment that is either in the process of deployment or that failed to deploy.</p> <p>Related Topics</p> <ul> <li> <p> <a>DeleteEnvironmentConfiguration</a> </p> </li> </ul></p>
-    async fn describe_configuration_settings(
+    /// Presigns the request `describe_configuration_options` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_configuration_options(
         &self,
-        input: DescribeConfigurationSettingsMessage,
-    ) -> Result<ConfigurationSettingsDescriptions, RusotoError<DescribeConfigurationSettingsError>>
-    {
+        presigner: &rusoto_core::Presigner,
+        input: DescribeConfigurationOptionsMessage,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_configuration_options_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_configuration_options_request(
+        &self,
+        input: DescribeConfigurationOptionsMessage,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "elasticbeanstalk", &self.region, "/");
+        request.set_operation("DescribeConfigurationOptions");
         let mut params = Params::new();
 
-        params.put("Action", "DescribeConfigurationSettings");
+        params.put("Action", "DescribeConfigurationOptions");
         params.put("Version", "2010-12-01");
-        DescribeConfigurationSettingsMessageSerializer::serialize(&mut params, "", &input);
+        DescribeConfigurationOptionsMessageSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `describe_configuration_settings`, also returning the metadata of the response.
+    pub async fn describe_configuration_settings_with_metadata(
+        &self,
+        input: DescribeConfigurationSettingsMessage,
+    ) -> Result<
+        (ConfigurationSettingsDescriptions, ResponseMetadata),
+        RusotoError<DescribeConfigurationSettingsError>,
+    > {
+        let request = self.build_describe_configuration_settings_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(DescribeConfigurationSettingsError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -10885,34 +11769,64 @@ impl ElasticBeanstalk for ElasticBeanstalkClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Returns information about the overall health of the specified environment. The <b>DescribeEnvironmentHealth</b> operation is only available with AWS Elastic Beanstalk Enhanced Health.</p>
-    async fn describe_environment_health(
+    /// Presigns the request `describe_configuration_settings` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_configuration_settings(
         &self,
-        input: DescribeEnvironmentHealthRequest,
-    ) -> Result<DescribeEnvironmentHealthResult, RusotoError<DescribeEnvironmentHealthError>> {
+        presigner: &rusoto_core::Presigner,
+        input: DescribeConfigurationSettingsMessage,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_configuration_settings_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_configuration_settings_request(
+        &self,
+        input: DescribeConfigurationSettingsMessage,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "elasticbeanstalk", &self.region, "/");
+        request.set_operation("DescribeConfigurationSettings");
         let mut params = Params::new();
 
-        params.put("Action", "DescribeEnvironmentHealth");
+        params.put("Action", "DescribeConfigurationSettings");
         params.put("Version", "2010-12-01");
-        DescribeEnvironmentHealthRequestSerializer::serialize(&mut params, "", &input);
+        DescribeConfigurationSettingsMessageSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `describe_environment_health`, also returning the metadata of the response.
+    pub async fn describe_environment_health_with_metadata(
+        &self,
+        input: DescribeEnvironmentHealthRequest,
+    ) -> Result<
+        (DescribeEnvironmentHealthResult, ResponseMetadata),
+        RusotoError<DescribeEnvironmentHealthError>,
+    > {
+        let request = self.build_describe_environment_health_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(DescribeEnvironmentHealthError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -10934,43 +11848,69 @@ impl ElasticBeanstalk for ElasticBeanstalkClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Lists an environment's completed and failed managed actions.</p>
-    async fn describe_environment_managed_action_history(
+    /// Presigns the request `describe_environment_health` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_environment_health(
         &self,
-        input: DescribeEnvironmentManagedActionHistoryRequest,
-    ) -> Result<
-        DescribeEnvironmentManagedActionHistoryResult,
-        RusotoError<DescribeEnvironmentManagedActionHistoryError>,
-    > {
+        presigner: &rusoto_core::Presigner,
+        input: DescribeEnvironmentHealthRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_environment_health_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_environment_health_request(
+        &self,
+        input: DescribeEnvironmentHealthRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "elasticbeanstalk", &self.region, "/");
+        request.set_operation("DescribeEnvironmentHealth");
         let mut params = Params::new();
 
-        params.put("Action", "DescribeEnvironmentManagedActionHistory");
+        params.put("Action", "DescribeEnvironmentHealth");
         params.put("Version", "2010-12-01");
-        DescribeEnvironmentManagedActionHistoryRequestSerializer::serialize(
-            &mut params,
-            "",
-            &input,
-        );
+        DescribeEnvironmentHealthRequestSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `describe_environment_managed_action_history`, also returning the metadata of the response.
+    pub async fn describe_environment_managed_action_history_with_metadata(
+        &self,
+        input: DescribeEnvironmentManagedActionHistoryRequest,
+    ) -> Result<
+        (
+            DescribeEnvironmentManagedActionHistoryResult,
+            ResponseMetadata,
+        ),
+        RusotoError<DescribeEnvironmentManagedActionHistoryError>,
+    > {
+        let request = self.build_describe_environment_managed_action_history_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(DescribeEnvironmentManagedActionHistoryError::from_response(
                 response,
             ));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -10992,39 +11932,70 @@ impl ElasticBeanstalk for ElasticBeanstalkClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Lists an environment's upcoming and in-progress managed actions.</p>
-    async fn describe_environment_managed_actions(
+    /// Presigns the request `describe_environment_managed_action_history` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_environment_managed_action_history(
         &self,
-        input: DescribeEnvironmentManagedActionsRequest,
-    ) -> Result<
-        DescribeEnvironmentManagedActionsResult,
-        RusotoError<DescribeEnvironmentManagedActionsError>,
-    > {
+        presigner: &rusoto_core::Presigner,
+        input: DescribeEnvironmentManagedActionHistoryRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_environment_managed_action_history_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_environment_managed_action_history_request(
+        &self,
+        input: DescribeEnvironmentManagedActionHistoryRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "elasticbeanstalk", &self.region, "/");
+        request.set_operation("DescribeEnvironmentManagedActionHistory");
         let mut params = Params::new();
 
-        params.put("Action", "DescribeEnvironmentManagedActions");
+        params.put("Action", "DescribeEnvironmentManagedActionHistory");
         params.put("Version", "2010-12-01");
-        DescribeEnvironmentManagedActionsRequestSerializer::serialize(&mut params, "", &input);
+        DescribeEnvironmentManagedActionHistoryRequestSerializer::serialize(
+            &mut params,
+            "",
+            &input,
+        );
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `describe_environment_managed_actions`, also returning the metadata of the response.
+    pub async fn describe_environment_managed_actions_with_metadata(
+        &self,
+        input: DescribeEnvironmentManagedActionsRequest,
+    ) -> Result<
+        (DescribeEnvironmentManagedActionsResult, ResponseMetadata),
+        RusotoError<DescribeEnvironmentManagedActionsError>,
+    > {
+        let request = self.build_describe_environment_managed_actions_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(DescribeEnvironmentManagedActionsError::from_response(
                 response,
             ));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -11046,37 +12017,64 @@ impl ElasticBeanstalk for ElasticBeanstalkClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Returns AWS resources for this environment.</p>
-    async fn describe_environment_resources(
+    /// Presigns the request `describe_environment_managed_actions` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_environment_managed_actions(
         &self,
-        input: DescribeEnvironmentResourcesMessage,
-    ) -> Result<
-        EnvironmentResourceDescriptionsMessage,
-        RusotoError<DescribeEnvironmentResourcesError>,
-    > {
+        presigner: &rusoto_core::Presigner,
+        input: DescribeEnvironmentManagedActionsRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_environment_managed_actions_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_environment_managed_actions_request(
+        &self,
+        input: DescribeEnvironmentManagedActionsRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "elasticbeanstalk", &self.region, "/");
+        request.set_operation("DescribeEnvironmentManagedActions");
         let mut params = Params::new();
 
-        params.put("Action", "DescribeEnvironmentResources");
+        params.put("Action", "DescribeEnvironmentManagedActions");
         params.put("Version", "2010-12-01");
-        DescribeEnvironmentResourcesMessageSerializer::serialize(&mut params, "", &input);
+        DescribeEnvironmentManagedActionsRequestSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `describe_environment_resources`, also returning the metadata of the response.
+    pub async fn describe_environment_resources_with_metadata(
+        &self,
+        input: DescribeEnvironmentResourcesMessage,
+    ) -> Result<
+        (EnvironmentResourceDescriptionsMessage, ResponseMetadata),
+        RusotoError<DescribeEnvironmentResourcesError>,
+    > {
+        let request = self.build_describe_environment_resources_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(DescribeEnvironmentResourcesError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -11098,34 +12096,64 @@ impl ElasticBeanstalk for ElasticBeanstalkClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Returns descriptions for existing environments.</p>
-    async fn describe_environments(
+    /// Presigns the request `describe_environment_resources` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_environment_resources(
         &self,
-        input: DescribeEnvironmentsMessage,
-    ) -> Result<EnvironmentDescriptionsMessage, RusotoError<DescribeEnvironmentsError>> {
+        presigner: &rusoto_core::Presigner,
+        input: DescribeEnvironmentResourcesMessage,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_environment_resources_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_environment_resources_request(
+        &self,
+        input: DescribeEnvironmentResourcesMessage,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "elasticbeanstalk", &self.region, "/");
+        request.set_operation("DescribeEnvironmentResources");
         let mut params = Params::new();
 
-        params.put("Action", "DescribeEnvironments");
+        params.put("Action", "DescribeEnvironmentResources");
         params.put("Version", "2010-12-01");
-        DescribeEnvironmentsMessageSerializer::serialize(&mut params, "", &input);
+        DescribeEnvironmentResourcesMessageSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `describe_environments`, also returning the metadata of the response.
+    pub async fn describe_environments_with_metadata(
+        &self,
+        input: DescribeEnvironmentsMessage,
+    ) -> Result<
+        (EnvironmentDescriptionsMessage, ResponseMetadata),
+        RusotoError<DescribeEnvironmentsError>,
+    > {
+        let request = self.build_describe_environments_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(DescribeEnvironmentsError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -11147,34 +12175,62 @@ impl ElasticBeanstalk for ElasticBeanstalkClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p><p>Returns list of event descriptions matching criteria up to the last 6 weeks.</p> <note> <p>This action returns the most recent 1,000 events from the specified <code>NextToken</code>.</p> </note></p>
-    async fn describe_events(
+    /// Presigns the request `describe_environments` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_environments(
         &self,
-        input: DescribeEventsMessage,
-    ) -> Result<EventDescriptionsMessage, RusotoError<DescribeEventsError>> {
+        presigner: &rusoto_core::Presigner,
+        input: DescribeEnvironmentsMessage,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_environments_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_environments_request(
+        &self,
+        input: DescribeEnvironmentsMessage,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "elasticbeanstalk", &self.region, "/");
+        request.set_operation("DescribeEnvironments");
         let mut params = Params::new();
 
-        params.put("Action", "DescribeEvents");
+        params.put("Action", "DescribeEnvironments");
         params.put("Version", "2010-12-01");
-        DescribeEventsMessageSerializer::serialize(&mut params, "", &input);
+        DescribeEnvironmentsMessageSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `describe_events`, also returning the metadata of the response.
+    pub async fn describe_events_with_metadata(
+        &self,
+        input: DescribeEventsMessage,
+    ) -> Result<(EventDescriptionsMessage, ResponseMetadata), RusotoError<DescribeEventsError>>
+    {
+        let request = self.build_describe_events_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(DescribeEventsError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -11196,34 +12252,61 @@ impl ElasticBeanstalk for ElasticBeanstalkClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Retrieves detailed information about the health of instances in your AWS Elastic Beanstalk. This operation requires <a href="https://docs.aws.amazon.com/elasticbeanstalk/latest/dg/health-enhanced.html">enhanced health reporting</a>.</p>
-    async fn describe_instances_health(
+    /// Presigns the request `describe_events` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_events(
         &self,
-        input: DescribeInstancesHealthRequest,
-    ) -> Result<DescribeInstancesHealthResult, RusotoError<DescribeInstancesHealthError>> {
+        presigner: &rusoto_core::Presigner,
+        input: DescribeEventsMessage,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_events_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_events_request(&self, input: DescribeEventsMessage) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "elasticbeanstalk", &self.region, "/");
+        request.set_operation("DescribeEvents");
         let mut params = Params::new();
 
-        params.put("Action", "DescribeInstancesHealth");
+        params.put("Action", "DescribeEvents");
         params.put("Version", "2010-12-01");
-        DescribeInstancesHealthRequestSerializer::serialize(&mut params, "", &input);
+        DescribeEventsMessageSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `describe_instances_health`, also returning the metadata of the response.
+    pub async fn describe_instances_health_with_metadata(
+        &self,
+        input: DescribeInstancesHealthRequest,
+    ) -> Result<
+        (DescribeInstancesHealthResult, ResponseMetadata),
+        RusotoError<DescribeInstancesHealthError>,
+    > {
+        let request = self.build_describe_instances_health_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(DescribeInstancesHealthError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -11245,34 +12328,64 @@ impl ElasticBeanstalk for ElasticBeanstalkClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Describes the version of the platform.</p>
-    async fn describe_platform_version(
+    /// Presigns the request `describe_instances_health` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_instances_health(
         &self,
-        input: DescribePlatformVersionRequest,
-    ) -> Result<DescribePlatformVersionResult, RusotoError<DescribePlatformVersionError>> {
+        presigner: &rusoto_core::Presigner,
+        input: DescribeInstancesHealthRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_instances_health_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_instances_health_request(
+        &self,
+        input: DescribeInstancesHealthRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "elasticbeanstalk", &self.region, "/");
+        request.set_operation("DescribeInstancesHealth");
         let mut params = Params::new();
 
-        params.put("Action", "DescribePlatformVersion");
+        params.put("Action", "DescribeInstancesHealth");
         params.put("Version", "2010-12-01");
-        DescribePlatformVersionRequestSerializer::serialize(&mut params, "", &input);
+        DescribeInstancesHealthRequestSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `describe_platform_version`, also returning the metadata of the response.
+    pub async fn describe_platform_version_with_metadata(
+        &self,
+        input: DescribePlatformVersionRequest,
+    ) -> Result<
+        (DescribePlatformVersionResult, ResponseMetadata),
+        RusotoError<DescribePlatformVersionError>,
+    > {
+        let request = self.build_describe_platform_version_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(DescribePlatformVersionError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -11294,36 +12407,63 @@ impl ElasticBeanstalk for ElasticBeanstalkClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Returns a list of the available solution stack names, with the public version first and then in reverse chronological order.</p>
-    async fn list_available_solution_stacks(
+    /// Presigns the request `describe_platform_version` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_platform_version(
         &self,
-    ) -> Result<
-        ListAvailableSolutionStacksResultMessage,
-        RusotoError<ListAvailableSolutionStacksError>,
-    > {
+        presigner: &rusoto_core::Presigner,
+        input: DescribePlatformVersionRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_platform_version_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_platform_version_request(
+        &self,
+        input: DescribePlatformVersionRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "elasticbeanstalk", &self.region, "/");
+        request.set_operation("DescribePlatformVersion");
         let mut params = Params::new();
 
-        params.put("Action", "ListAvailableSolutionStacks");
+        params.put("Action", "DescribePlatformVersion");
         params.put("Version", "2010-12-01");
-
+        DescribePlatformVersionRequestSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `list_available_solution_stacks`, also returning the metadata of the response.
+    pub async fn list_available_solution_stacks_with_metadata(
+        &self,
+    ) -> Result<
+        (ListAvailableSolutionStacksResultMessage, ResponseMetadata),
+        RusotoError<ListAvailableSolutionStacksError>,
+    > {
+        let request = self.build_list_available_solution_stacks_request();
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(ListAvailableSolutionStacksError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -11345,34 +12485,60 @@ impl ElasticBeanstalk for ElasticBeanstalkClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Lists the available platforms.</p>
-    async fn list_platform_versions(
+    /// Presigns the request `list_available_solution_stacks` would send with `presigner` instead of sending it.
+    pub async fn presign_list_available_solution_stacks(
         &self,
-        input: ListPlatformVersionsRequest,
-    ) -> Result<ListPlatformVersionsResult, RusotoError<ListPlatformVersionsError>> {
-        let mut request = SignedRequest::new("POST", "elasticbeanstalk", &self.region, "/");
+        presigner: &rusoto_core::Presigner,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_available_solution_stacks_request();
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_available_solution_stacks_request(&self) -> SignedRequest {
+        let mut request = SignedRequest::new("POST", "elasticbeanstalk", &self.region, "/");
+        request.set_operation("ListAvailableSolutionStacks");
         let mut params = Params::new();
 
-        params.put("Action", "ListPlatformVersions");
+        params.put("Action", "ListAvailableSolutionStacks");
         params.put("Version", "2010-12-01");
-        ListPlatformVersionsRequestSerializer::serialize(&mut params, "", &input);
+
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `list_platform_versions`, also returning the metadata of the response.
+    pub async fn list_platform_versions_with_metadata(
+        &self,
+        input: ListPlatformVersionsRequest,
+    ) -> Result<
+        (ListPlatformVersionsResult, ResponseMetadata),
+        RusotoError<ListPlatformVersionsError>,
+    > {
+        let request = self.build_list_platform_versions_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(ListPlatformVersionsError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -11394,34 +12560,64 @@ impl ElasticBeanstalk for ElasticBeanstalkClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Returns the tags applied to an AWS Elastic Beanstalk resource. The response contains a list of tag key-value pairs.</p> <p>Currently, Elastic Beanstalk only supports tagging of Elastic Beanstalk environments. For details about environment tagging, see <a href="https://docs.aws.amazon.com/elasticbeanstalk/latest/dg/using-features.tagging.html">Tagging Resources in Your Elastic Beanstalk Environment</a>.</p>
-    async fn list_tags_for_resource(
+    /// Presigns the request `list_platform_versions` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_platform_versions(
         &self,
-        input: ListTagsForResourceMessage,
-    ) -> Result<ResourceTagsDescriptionMessage, RusotoError<ListTagsForResourceError>> {
+        presigner: &rusoto_core::Presigner,
+        input: ListPlatformVersionsRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_platform_versions_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_platform_versions_request(
+        &self,
+        input: ListPlatformVersionsRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "elasticbeanstalk", &self.region, "/");
+        request.set_operation("ListPlatformVersions");
         let mut params = Params::new();
 
-        params.put("Action", "ListTagsForResource");
+        params.put("Action", "ListPlatformVersions");
         params.put("Version", "2010-12-01");
-        ListTagsForResourceMessageSerializer::serialize(&mut params, "", &input);
+        ListPlatformVersionsRequestSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `list_tags_for_resource`, also returning the metadata of the response.
+    pub async fn list_tags_for_resource_with_metadata(
+        &self,
+        input: ListTagsForResourceMessage,
+    ) -> Result<
+        (ResourceTagsDescriptionMessage, ResponseMetadata),
+        RusotoError<ListTagsForResourceError>,
+    > {
+        let request = self.build_list_tags_for_resource_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(ListTagsForResourceError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -11443,119 +12639,214 @@ impl ElasticBeanstalk for ElasticBeanstalkClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Deletes and recreates all of the AWS resources (for example: the Auto Scaling group, load balancer, etc.) for a specified environment and forces a restart.</p>
-    async fn rebuild_environment(
+    /// Presigns the request `list_tags_for_resource` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_tags_for_resource(
         &self,
-        input: RebuildEnvironmentMessage,
-    ) -> Result<(), RusotoError<RebuildEnvironmentError>> {
+        presigner: &rusoto_core::Presigner,
+        input: ListTagsForResourceMessage,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_tags_for_resource_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_tags_for_resource_request(
+        &self,
+        input: ListTagsForResourceMessage,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "elasticbeanstalk", &self.region, "/");
+        request.set_operation("ListTagsForResource");
         let mut params = Params::new();
 
-        params.put("Action", "RebuildEnvironment");
+        params.put("Action", "ListTagsForResource");
         params.put("Version", "2010-12-01");
-        RebuildEnvironmentMessageSerializer::serialize(&mut params, "", &input);
+        ListTagsForResourceMessageSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `rebuild_environment`, also returning the metadata of the response.
+    pub async fn rebuild_environment_with_metadata(
+        &self,
+        input: RebuildEnvironmentMessage,
+    ) -> Result<((), ResponseMetadata), RusotoError<RebuildEnvironmentError>> {
+        let request = self.build_rebuild_environment_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(RebuildEnvironmentError::from_response(response));
         }
 
         std::mem::drop(response);
-        Ok(())
+        Ok(((), metadata))
     }
 
-    /// <p><p>Initiates a request to compile the specified type of information of the deployed environment.</p> <p> Setting the <code>InfoType</code> to <code>tail</code> compiles the last lines from the application server log files of every Amazon EC2 instance in your environment. </p> <p> Setting the <code>InfoType</code> to <code>bundle</code> compresses the application server log files for every Amazon EC2 instance into a <code>.zip</code> file. Legacy and .NET containers do not support bundle logs. </p> <p> Use <a>RetrieveEnvironmentInfo</a> to obtain the set of logs. </p> <p>Related Topics</p> <ul> <li> <p> <a>RetrieveEnvironmentInfo</a> </p> </li> </ul></p>
-    async fn request_environment_info(
+    /// Presigns the request `rebuild_environment` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_rebuild_environment(
         &self,
-        input: RequestEnvironmentInfoMessage,
-    ) -> Result<(), RusotoError<RequestEnvironmentInfoError>> {
+        presigner: &rusoto_core::Presigner,
+        input: RebuildEnvironmentMessage,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_rebuild_environment_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_rebuild_environment_request(&self, input: RebuildEnvironmentMessage) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "elasticbeanstalk", &self.region, "/");
+        request.set_operation("RebuildEnvironment");
         let mut params = Params::new();
 
-        params.put("Action", "RequestEnvironmentInfo");
+        params.put("Action", "RebuildEnvironment");
         params.put("Version", "2010-12-01");
-        RequestEnvironmentInfoMessageSerializer::serialize(&mut params, "", &input);
+        RebuildEnvironmentMessageSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `request_environment_info`, also returning the metadata of the response.
+    pub async fn request_environment_info_with_metadata(
+        &self,
+        input: RequestEnvironmentInfoMessage,
+    ) -> Result<((), ResponseMetadata), RusotoError<RequestEnvironmentInfoError>> {
+        let request = self.build_request_environment_info_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(RequestEnvironmentInfoError::from_response(response));
         }
 
         std::mem::drop(response);
-        Ok(())
+        Ok(((), metadata))
     }
 
-    /// <p>Causes the environment to restart the application container server running on each Amazon EC2 instance.</p>
-    async fn restart_app_server(
+    /// Presigns the request `request_environment_info` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_request_environment_info(
         &self,
-        input: RestartAppServerMessage,
-    ) -> Result<(), RusotoError<RestartAppServerError>> {
+        presigner: &rusoto_core::Presigner,
+        input: RequestEnvironmentInfoMessage,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_request_environment_info_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_request_environment_info_request(
+        &self,
+        input: RequestEnvironmentInfoMessage,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "elasticbeanstalk", &self.region, "/");
+        request.set_operation("RequestEnvironmentInfo");
         let mut params = Params::new();
 
-        params.put("Action", "RestartAppServer");
+        params.put("Action", "RequestEnvironmentInfo");
         params.put("Version", "2010-12-01");
-        RestartAppServerMessageSerializer::serialize(&mut params, "", &input);
+        RequestEnvironmentInfoMessageSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `restart_app_server`, also returning the metadata of the response.
+    pub async fn restart_app_server_with_metadata(
+        &self,
+        input: RestartAppServerMessage,
+    ) -> Result<((), ResponseMetadata), RusotoError<RestartAppServerError>> {
+        let request = self.build_restart_app_server_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(RestartAppServerError::from_response(response));
         }
 
         std::mem::drop(response);
-        Ok(())
+        Ok(((), metadata))
     }
 
-    /// <p><p>Retrieves the compiled information from a <a>RequestEnvironmentInfo</a> request.</p> <p>Related Topics</p> <ul> <li> <p> <a>RequestEnvironmentInfo</a> </p> </li> </ul></p>
-    async fn retrieve_environment_info(
+    /// Presigns the request `restart_app_server` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_restart_app_server(
         &self,
-        input: RetrieveEnvironmentInfoMessage,
-    ) -> Result<RetrieveEnvironmentInfoResultMessage, RusotoError<RetrieveEnvironmentInfoError>>
-    {
+        presigner: &rusoto_core::Presigner,
+        input: RestartAppServerMessage,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_restart_app_server_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_restart_app_server_request(&self, input: RestartAppServerMessage) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "elasticbeanstalk", &self.region, "/");
+        request.set_operation("RestartAppServer");
         let mut params = Params::new();
 
-        params.put("Action", "RetrieveEnvironmentInfo");
+        params.put("Action", "RestartAppServer");
         params.put("Version", "2010-12-01");
-        RetrieveEnvironmentInfoMessageSerializer::serialize(&mut params, "", &input);
+        RestartAppServerMessageSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `retrieve_environment_info`, also returning the metadata of the response.
+    pub async fn retrieve_environment_info_with_metadata(
+        &self,
+        input: RetrieveEnvironmentInfoMessage,
+    ) -> Result<
+        (RetrieveEnvironmentInfoResultMessage, ResponseMetadata),
+        RusotoError<RetrieveEnvironmentInfoError>,
+    > {
+        let request = self.build_retrieve_environment_info_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(RetrieveEnvironmentInfoError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -11577,62 +12868,114 @@ impl ElasticBeanstalk for ElasticBeanstalkClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Swaps the CNAMEs of two environments.</p>
-    async fn swap_environment_cnam_es(
+    /// Presigns the request `retrieve_environment_info` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_retrieve_environment_info(
         &self,
-        input: SwapEnvironmentCNAMEsMessage,
-    ) -> Result<(), RusotoError<SwapEnvironmentCNAMEsError>> {
+        presigner: &rusoto_core::Presigner,
+        input: RetrieveEnvironmentInfoMessage,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_retrieve_environment_info_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_retrieve_environment_info_request(
+        &self,
+        input: RetrieveEnvironmentInfoMessage,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "elasticbeanstalk", &self.region, "/");
+        request.set_operation("RetrieveEnvironmentInfo");
         let mut params = Params::new();
 
-        params.put("Action", "SwapEnvironmentCNAMEs");
+        params.put("Action", "RetrieveEnvironmentInfo");
         params.put("Version", "2010-12-01");
-        SwapEnvironmentCNAMEsMessageSerializer::serialize(&mut params, "", &input);
+        RetrieveEnvironmentInfoMessageSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `swap_environment_cnam_es`, also returning the metadata of the response.
+    pub async fn swap_environment_cnam_es_with_metadata(
+        &self,
+        input: SwapEnvironmentCNAMEsMessage,
+    ) -> Result<((), ResponseMetadata), RusotoError<SwapEnvironmentCNAMEsError>> {
+        let request = self.build_swap_environment_cnam_es_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(SwapEnvironmentCNAMEsError::from_response(response));
         }
 
         std::mem::drop(response);
-        Ok(())
+        Ok(((), metadata))
     }
 
-    /// <p>Terminates the specified environment.</p>
-    async fn terminate_environment(
+    /// Presigns the request `swap_environment_cnam_es` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_swap_environment_cnam_es(
         &self,
-        input: TerminateEnvironmentMessage,
-    ) -> Result<EnvironmentDescription, RusotoError<TerminateEnvironmentError>> {
+        presigner: &rusoto_core::Presigner,
+        input: SwapEnvironmentCNAMEsMessage,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_swap_environment_cnam_es_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_swap_environment_cnam_es_request(
+        &self,
+        input: SwapEnvironmentCNAMEsMessage,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "elasticbeanstalk", &self.region, "/");
+        request.set_operation("SwapEnvironmentCNAMEs");
         let mut params = Params::new();
 
-        params.put("Action", "TerminateEnvironment");
+        params.put("Action", "SwapEnvironmentCNAMEs");
         params.put("Version", "2010-12-01");
-        TerminateEnvironmentMessageSerializer::serialize(&mut params, "", &input);
+        SwapEnvironmentCNAMEsMessageSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `terminate_environment`, also returning the metadata of the response.
+    pub async fn terminate_environment_with_metadata(
+        &self,
+        input: TerminateEnvironmentMessage,
+    ) -> Result<(EnvironmentDescription, ResponseMetadata), RusotoError<TerminateEnvironmentError>>
+    {
+        let request = self.build_terminate_environment_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(TerminateEnvironmentError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -11654,34 +12997,64 @@ impl ElasticBeanstalk for ElasticBeanstalkClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p><p>Updates the specified application to have the specified properties.</p> <note> <p>If a property (for example, <code>description</code>) is not provided, the value remains unchanged. To clear these properties, specify an empty string.</p> </note></p>
-    async fn update_application(
+    /// Presigns the request `terminate_environment` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_terminate_environment(
         &self,
-        input: UpdateApplicationMessage,
-    ) -> Result<ApplicationDescriptionMessage, RusotoError<UpdateApplicationError>> {
+        presigner: &rusoto_core::Presigner,
+        input: TerminateEnvironmentMessage,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_terminate_environment_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_terminate_environment_request(
+        &self,
+        input: TerminateEnvironmentMessage,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "elasticbeanstalk", &self.region, "/");
+        request.set_operation("TerminateEnvironment");
         let mut params = Params::new();
 
-        params.put("Action", "UpdateApplication");
+        params.put("Action", "TerminateEnvironment");
         params.put("Version", "2010-12-01");
-        UpdateApplicationMessageSerializer::serialize(&mut params, "", &input);
+        TerminateEnvironmentMessageSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `update_application`, also returning the metadata of the response.
+    pub async fn update_application_with_metadata(
+        &self,
+        input: UpdateApplicationMessage,
+    ) -> Result<
+        (ApplicationDescriptionMessage, ResponseMetadata),
+        RusotoError<UpdateApplicationError>,
+    > {
+        let request = self.build_update_application_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(UpdateApplicationError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -11703,39 +13076,66 @@ impl ElasticBeanstalk for ElasticBeanstalkClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Modifies lifecycle settings for an application.</p>
-    async fn update_application_resource_lifecycle(
+    /// Presigns the request `update_application` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_application(
         &self,
-        input: UpdateApplicationResourceLifecycleMessage,
-    ) -> Result<
-        ApplicationResourceLifecycleDescriptionMessage,
-        RusotoError<UpdateApplicationResourceLifecycleError>,
-    > {
+        presigner: &rusoto_core::Presigner,
+        input: UpdateApplicationMessage,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_application_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_application_request(&self, input: UpdateApplicationMessage) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "elasticbeanstalk", &self.region, "/");
+        request.set_operation("UpdateApplication");
         let mut params = Params::new();
 
-        params.put("Action", "UpdateApplicationResourceLifecycle");
+        params.put("Action", "UpdateApplication");
         params.put("Version", "2010-12-01");
-        UpdateApplicationResourceLifecycleMessageSerializer::serialize(&mut params, "", &input);
+        UpdateApplicationMessageSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `update_application_resource_lifecycle`, also returning the metadata of the response.
+    pub async fn update_application_resource_lifecycle_with_metadata(
+        &self,
+        input: UpdateApplicationResourceLifecycleMessage,
+    ) -> Result<
+        (
+            ApplicationResourceLifecycleDescriptionMessage,
+            ResponseMetadata,
+        ),
+        RusotoError<UpdateApplicationResourceLifecycleError>,
+    > {
+        let request = self.build_update_application_resource_lifecycle_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(UpdateApplicationResourceLifecycleError::from_response(
                 response,
             ));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -11757,35 +13157,64 @@ impl ElasticBeanstalk for ElasticBeanstalkClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p><p>Updates the specified application version to have the specified properties.</p> <note> <p>If a property (for example, <code>description</code>) is not provided, the value remains unchanged. To clear properties, specify an empty string.</p> </note></p>
-    async fn update_application_version(
+    /// Presigns the request `update_application_resource_lifecycle` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_application_resource_lifecycle(
         &self,
-        input: UpdateApplicationVersionMessage,
-    ) -> Result<ApplicationVersionDescriptionMessage, RusotoError<UpdateApplicationVersionError>>
-    {
+        presigner: &rusoto_core::Presigner,
+        input: UpdateApplicationResourceLifecycleMessage,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_application_resource_lifecycle_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_application_resource_lifecycle_request(
+        &self,
+        input: UpdateApplicationResourceLifecycleMessage,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "elasticbeanstalk", &self.region, "/");
+        request.set_operation("UpdateApplicationResourceLifecycle");
         let mut params = Params::new();
 
-        params.put("Action", "UpdateApplicationVersion");
+        params.put("Action", "UpdateApplicationResourceLifecycle");
         params.put("Version", "2010-12-01");
-        UpdateApplicationVersionMessageSerializer::serialize(&mut params, "", &input);
+        UpdateApplicationResourceLifecycleMessageSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `update_application_version`, also returning the metadata of the response.
+    pub async fn update_application_version_with_metadata(
+        &self,
+        input: UpdateApplicationVersionMessage,
+    ) -> Result<
+        (ApplicationVersionDescriptionMessage, ResponseMetadata),
+        RusotoError<UpdateApplicationVersionError>,
+    > {
+        let request = self.build_update_application_version_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(UpdateApplicationVersionError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -11807,35 +13236,64 @@ impl ElasticBeanstalk for ElasticBeanstalkClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p><p>Updates the specified configuration template to have the specified properties or configuration option values.</p> <note> <p>If a property (for example, <code>ApplicationName</code>) is not provided, its value remains unchanged. To clear such properties, specify an empty string.</p> </note> <p>Related Topics</p> <ul> <li> <p> <a>DescribeConfigurationOptions</a> </p> </li> </ul></p>
-    async fn update_configuration_template(
+    /// Presigns the request `update_application_version` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_application_version(
         &self,
-        input: UpdateConfigurationTemplateMessage,
-    ) -> Result<ConfigurationSettingsDescription, RusotoError<UpdateConfigurationTemplateError>>
-    {
+        presigner: &rusoto_core::Presigner,
+        input: UpdateApplicationVersionMessage,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_application_version_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_application_version_request(
+        &self,
+        input: UpdateApplicationVersionMessage,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "elasticbeanstalk", &self.region, "/");
+        request.set_operation("UpdateApplicationVersion");
         let mut params = Params::new();
 
-        params.put("Action", "UpdateConfigurationTemplate");
+        params.put("Action", "UpdateApplicationVersion");
         params.put("Version", "2010-12-01");
-        UpdateConfigurationTemplateMessageSerializer::serialize(&mut params, "", &input);
+        UpdateApplicationVersionMessageSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `update_configuration_template`, also returning the metadata of the response.
+    pub async fn update_configuration_template_with_metadata(
+        &self,
+        input: UpdateConfigurationTemplateMessage,
+    ) -> Result<
+        (ConfigurationSettingsDescription, ResponseMetadata),
+        RusotoError<UpdateConfigurationTemplateError>,
+    > {
+        let request = self.build_update_configuration_template_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(UpdateConfigurationTemplateError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -11857,34 +13315,62 @@ impl ElasticBeanstalk for ElasticBeanstalkClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Updates the environment description, deploys a new application version, updates the configuration settings to an entirely new configuration template, or updates select configuration option values in the running environment.</p> <p> Attempting to update both the release and configuration is not allowed and AWS Elastic Beanstalk returns an <code>InvalidParameterCombination</code> error. </p> <p> When updating the configuration settings to a new template or individual settings, a draft configuration is created and <a>DescribeConfigurationSettings</a> for this environment returns two setting descriptions with different <code>DeploymentStatus</code> values. </p>
-    async fn update_environment(
+    /// Presigns the request `update_configuration_template` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_configuration_template(
         &self,
-        input: UpdateEnvironmentMessage,
-    ) -> Result<EnvironmentDescription, RusotoError<UpdateEnvironmentError>> {
+        presigner: &rusoto_core::Presigner,
+        input: UpdateConfigurationTemplateMessage,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_configuration_template_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_configuration_template_request(
+        &self,
+        input: UpdateConfigurationTemplateMessage,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "elasticbeanstalk", &self.region, "/");
+        request.set_operation("UpdateConfigurationTemplate");
         let mut params = Params::new();
 
-        params.put("Action", "UpdateEnvironment");
+        params.put("Action", "UpdateConfigurationTemplate");
         params.put("Version", "2010-12-01");
-        UpdateEnvironmentMessageSerializer::serialize(&mut params, "", &input);
+        UpdateConfigurationTemplateMessageSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
-        let mut response = self
+        request
+    }
+
+    /// Calls `update_environment`, also returning the metadata of the response.
+    pub async fn update_environment_with_metadata(
+        &self,
+        input: UpdateEnvironmentMessage,
+    ) -> Result<(EnvironmentDescription, ResponseMetadata), RusotoError<UpdateEnvironmentError>>
+    {
+        let request = self.build_update_environment_request(input);
+
+        let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(UpdateEnvironmentError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -11906,65 +13392,113 @@ impl ElasticBeanstalk for ElasticBeanstalkClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Update the list of tags applied to an AWS Elastic Beanstalk resource. Two lists can be passed: <code>TagsToAdd</code> for tags to add or update, and <code>TagsToRemove</code>.</p> <p>Currently, Elastic Beanstalk only supports tagging of Elastic Beanstalk environments. For details about environment tagging, see <a href="https://docs.aws.amazon.com/elasticbeanstalk/latest/dg/using-features.tagging.html">Tagging Resources in Your Elastic Beanstalk Environment</a>.</p> <p>If you create a custom IAM user policy to control permission to this operation, specify one of the following two virtual actions (or both) instead of the API operation name:</p> <dl> <dt>elasticbeanstalk:AddTags</dt> <dd> <p>Controls permission to call <code>UpdateTagsForResource</code> and pass a list of tags to add in the <code>TagsToAdd</code> parameter.</p> </dd> <dt>elasticbeanstalk:RemoveTags</dt> <dd> <p>Controls permission to call <code>UpdateTagsForResource</code> and pass a list of tag keys to remove in the <code>TagsToRemove</code> parameter.</p> </dd> </dl> <p>For details about creating a custom user policy, see <a href="https://docs.aws.amazon.com/elasticbeanstalk/latest/dg/AWSHowTo.iam.managed-policies.html#AWSHowTo.iam.policies">Creating a Custom User Policy</a>.</p>
-    async fn update_tags_for_resource(
+    /// Presigns the request `update_environment` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_environment(
         &self,
-        input: UpdateTagsForResourceMessage,
-    ) -> Result<(), RusotoError<UpdateTagsForResourceError>> {
+        presigner: &rusoto_core::Presigner,
+        input: UpdateEnvironmentMessage,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_environment_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_environment_request(&self, input: UpdateEnvironmentMessage) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "elasticbeanstalk", &self.region, "/");
+        request.set_operation("UpdateEnvironment");
         let mut params = Params::new();
 
-        params.put("Action", "UpdateTagsForResource");
+        params.put("Action", "UpdateEnvironment");
         params.put("Version", "2010-12-01");
-        UpdateTagsForResourceMessageSerializer::serialize(&mut params, "", &input);
+        UpdateEnvironmentMessageSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `update_tags_for_resource`, also returning the metadata of the response.
+    pub async fn update_tags_for_resource_with_metadata(
+        &self,
+        input: UpdateTagsForResourceMessage,
+    ) -> Result<((), ResponseMetadata), RusotoError<UpdateTagsForResourceError>> {
+        let request = self.build_update_tags_for_resource_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(UpdateTagsForResourceError::from_response(response));
         }
 
         std::mem::drop(response);
-        Ok(())
+        Ok(((), metadata))
     }
 
-    /// <p>Takes a set of configuration settings and either a configuration template or environment, and determines whether those values are valid.</p> <p>This action returns a list of messages indicating any errors or warnings associated with the selection of option values.</p>
-    async fn validate_configuration_settings(
+    /// Presigns the request `update_tags_for_resource` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_tags_for_resource(
         &self,
-        input: ValidateConfigurationSettingsMessage,
-    ) -> Result<
-        ConfigurationSettingsValidationMessages,
-        RusotoError<ValidateConfigurationSettingsError>,
-    > {
+        presigner: &rusoto_core::Presigner,
+        input: UpdateTagsForResourceMessage,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_tags_for_resource_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_tags_for_resource_request(
+        &self,
+        input: UpdateTagsForResourceMessage,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "elasticbeanstalk", &self.region, "/");
+        request.set_operation("UpdateTagsForResource");
         let mut params = Params::new();
 
-        params.put("Action", "ValidateConfigurationSettings");
+        params.put("Action", "UpdateTagsForResource");
         params.put("Version", "2010-12-01");
-        ValidateConfigurationSettingsMessageSerializer::serialize(&mut params, "", &input);
+        UpdateTagsForResourceMessageSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `validate_configuration_settings`, also returning the metadata of the response.
+    pub async fn validate_configuration_settings_with_metadata(
+        &self,
+        input: ValidateConfigurationSettingsMessage,
+    ) -> Result<
+        (ConfigurationSettingsValidationMessages, ResponseMetadata),
+        RusotoError<ValidateConfigurationSettingsError>,
+    > {
+        let request = self.build_validate_configuration_settings_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(ValidateConfigurationSettingsError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -11986,7 +13520,867 @@ impl ElasticBeanstalk for ElasticBeanstalkClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
+    }
+
+    /// Presigns the request `validate_configuration_settings` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_validate_configuration_settings(
+        &self,
+        presigner: &rusoto_core::Presigner,
+        input: ValidateConfigurationSettingsMessage,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_validate_configuration_settings_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_validate_configuration_settings_request(
+        &self,
+        input: ValidateConfigurationSettingsMessage,
+    ) -> SignedRequest {
+        let mut request = SignedRequest::new("POST", "elasticbeanstalk", &self.region, "/");
+        request.set_operation("ValidateConfigurationSettings");
+        let mut params = Params::new();
+
+        params.put("Action", "ValidateConfigurationSettings");
+        params.put("Version", "2010-12-01");
+        ValidateConfigurationSettingsMessageSerializer::serialize(&mut params, "", &input);
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        request
+    }
+
+    /// The pages of `DescribeApplicationVersions` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `MaxRecords` of `input`.
+    pub fn describe_application_versions_pages(
+        &self,
+        input: DescribeApplicationVersionsMessage,
+    ) -> impl futures::Stream<
+        Item = Result<
+            ApplicationVersionDescriptionsMessage,
+            RusotoError<DescribeApplicationVersionsError>,
+        >,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: ElasticBeanstalkClient, input| async move {
+                client.describe_application_versions(input).await
+            },
+            |input: &DescribeApplicationVersionsMessage,
+             output: &ApplicationVersionDescriptionsMessage| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(DescribeApplicationVersionsMessage {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `ApplicationVersions` of every page of `DescribeApplicationVersions` results for `input`, as `describe_application_versions_pages` requests them.
+    pub fn describe_application_versions_stream(
+        &self,
+        input: DescribeApplicationVersionsMessage,
+    ) -> impl futures::Stream<
+        Item = Result<ApplicationVersionDescription, RusotoError<DescribeApplicationVersionsError>>,
+    > + Send {
+        rusoto_core::pagination::items(self.describe_application_versions_pages(input), |page| {
+            page.application_versions.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `DescribeEnvironmentManagedActionHistory` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `MaxItems` of `input`.
+    pub fn describe_environment_managed_action_history_pages(
+        &self,
+        input: DescribeEnvironmentManagedActionHistoryRequest,
+    ) -> impl futures::Stream<
+        Item = Result<
+            DescribeEnvironmentManagedActionHistoryResult,
+            RusotoError<DescribeEnvironmentManagedActionHistoryError>,
+        >,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: ElasticBeanstalkClient, input| async move {
+                client
+                    .describe_environment_managed_action_history(input)
+                    .await
+            },
+            |input: &DescribeEnvironmentManagedActionHistoryRequest,
+             output: &DescribeEnvironmentManagedActionHistoryResult| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(DescribeEnvironmentManagedActionHistoryRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `ManagedActionHistoryItems` of every page of `DescribeEnvironmentManagedActionHistory` results for `input`, as `describe_environment_managed_action_history_pages` requests them.
+    pub fn describe_environment_managed_action_history_stream(
+        &self,
+        input: DescribeEnvironmentManagedActionHistoryRequest,
+    ) -> impl futures::Stream<
+        Item = Result<
+            ManagedActionHistoryItem,
+            RusotoError<DescribeEnvironmentManagedActionHistoryError>,
+        >,
+    > + Send {
+        rusoto_core::pagination::items(
+            self.describe_environment_managed_action_history_pages(input),
+            |page| page.managed_action_history_items.unwrap_or_default(),
+        )
+    }
+
+    /// The pages of `DescribeEnvironments` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `MaxRecords` of `input`.
+    pub fn describe_environments_pages(
+        &self,
+        input: DescribeEnvironmentsMessage,
+    ) -> impl futures::Stream<
+        Item = Result<EnvironmentDescriptionsMessage, RusotoError<DescribeEnvironmentsError>>,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: ElasticBeanstalkClient, input| async move {
+                client.describe_environments(input).await
+            },
+            |input: &DescribeEnvironmentsMessage, output: &EnvironmentDescriptionsMessage| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(DescribeEnvironmentsMessage {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `Environments` of every page of `DescribeEnvironments` results for `input`, as `describe_environments_pages` requests them.
+    pub fn describe_environments_stream(
+        &self,
+        input: DescribeEnvironmentsMessage,
+    ) -> impl futures::Stream<
+        Item = Result<EnvironmentDescription, RusotoError<DescribeEnvironmentsError>>,
+    > + Send {
+        rusoto_core::pagination::items(self.describe_environments_pages(input), |page| {
+            page.environments.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `DescribeEvents` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `MaxRecords` of `input`.
+    pub fn describe_events_pages(
+        &self,
+        input: DescribeEventsMessage,
+    ) -> impl futures::Stream<
+        Item = Result<EventDescriptionsMessage, RusotoError<DescribeEventsError>>,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: ElasticBeanstalkClient, input| async move { client.describe_events(input).await },
+            |input: &DescribeEventsMessage, output: &EventDescriptionsMessage| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(DescribeEventsMessage {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `Events` of every page of `DescribeEvents` results for `input`, as `describe_events_pages` requests them.
+    pub fn describe_events_stream(
+        &self,
+        input: DescribeEventsMessage,
+    ) -> impl futures::Stream<Item = Result<EventDescription, RusotoError<DescribeEventsError>>> + Send
+    {
+        rusoto_core::pagination::items(self.describe_events_pages(input), |page| {
+            page.events.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `ListPlatformVersions` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `MaxRecords` of `input`.
+    pub fn list_platform_versions_pages(
+        &self,
+        input: ListPlatformVersionsRequest,
+    ) -> impl futures::Stream<
+        Item = Result<ListPlatformVersionsResult, RusotoError<ListPlatformVersionsError>>,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: ElasticBeanstalkClient, input| async move {
+                client.list_platform_versions(input).await
+            },
+            |input: &ListPlatformVersionsRequest, output: &ListPlatformVersionsResult| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListPlatformVersionsRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `PlatformSummaryList` of every page of `ListPlatformVersions` results for `input`, as `list_platform_versions_pages` requests them.
+    pub fn list_platform_versions_stream(
+        &self,
+        input: ListPlatformVersionsRequest,
+    ) -> impl futures::Stream<Item = Result<PlatformSummary, RusotoError<ListPlatformVersionsError>>>
+           + Send {
+        rusoto_core::pagination::items(self.list_platform_versions_pages(input), |page| {
+            page.platform_summary_list.unwrap_or_default()
+        })
+    }
+
+    /// Waits for `EnvironmentExists`, polling `DescribeEnvironments` with `input` every 20 seconds, up to 20 times.
+    pub async fn wait_until_environment_exists(
+        &self,
+        input: DescribeEnvironmentsMessage,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<DescribeEnvironmentsError>> {
+        let waiter = rusoto_core::waiter::Waiter::new(std::time::Duration::from_secs(20), 20);
+        self.wait_until_environment_exists_with(input, &waiter)
+            .await
+    }
+
+    /// Waits for `EnvironmentExists` like `wait_until_environment_exists`, with the delay, attempts and runtime of `waiter`.
+    pub async fn wait_until_environment_exists_with(
+        &self,
+        input: DescribeEnvironmentsMessage,
+        waiter: &rusoto_core::waiter::Waiter,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<DescribeEnvironmentsError>> {
+        rusoto_core::waiter::wait(
+            waiter,
+            || self.describe_environments(input.clone()),
+            |result| {
+                if match result {
+                    Ok(output) => {
+                        let mut values = std::iter::once(output)
+                            .filter_map(|value| value.environments.as_ref())
+                            .flat_map(|value| value.iter())
+                            .filter_map(|value| value.status.as_ref())
+                            .peekable();
+                        values.peek().is_some() && values.all(|value| value == "Ready")
+                    }
+                    Err(_) => false,
+                } {
+                    return Some(rusoto_core::waiter::WaiterState::Success);
+                }
+                if match result {
+                    Ok(output) => {
+                        let mut values = std::iter::once(output)
+                            .filter_map(|value| value.environments.as_ref())
+                            .flat_map(|value| value.iter())
+                            .filter_map(|value| value.status.as_ref())
+                            .peekable();
+                        values.peek().is_some() && values.all(|value| value == "Launching")
+                    }
+                    Err(_) => false,
+                } {
+                    return Some(rusoto_core::waiter::WaiterState::Retry);
+                }
+                None
+            },
+        )
+        .await
+    }
+
+    /// Waits for `EnvironmentTerminated`, polling `DescribeEnvironments` with `input` every 20 seconds, up to 20 times.
+    pub async fn wait_until_environment_terminated(
+        &self,
+        input: DescribeEnvironmentsMessage,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<DescribeEnvironmentsError>> {
+        let waiter = rusoto_core::waiter::Waiter::new(std::time::Duration::from_secs(20), 20);
+        self.wait_until_environment_terminated_with(input, &waiter)
+            .await
+    }
+
+    /// Waits for `EnvironmentTerminated` like `wait_until_environment_terminated`, with the delay, attempts and runtime of `waiter`.
+    pub async fn wait_until_environment_terminated_with(
+        &self,
+        input: DescribeEnvironmentsMessage,
+        waiter: &rusoto_core::waiter::Waiter,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<DescribeEnvironmentsError>> {
+        rusoto_core::waiter::wait(
+            waiter,
+            || self.describe_environments(input.clone()),
+            |result| {
+                if match result {
+                    Ok(output) => {
+                        let mut values = std::iter::once(output)
+                            .filter_map(|value| value.environments.as_ref())
+                            .flat_map(|value| value.iter())
+                            .filter_map(|value| value.status.as_ref())
+                            .peekable();
+                        values.peek().is_some() && values.all(|value| value == "Terminated")
+                    }
+                    Err(_) => false,
+                } {
+                    return Some(rusoto_core::waiter::WaiterState::Success);
+                }
+                if match result {
+                    Ok(output) => {
+                        let mut values = std::iter::once(output)
+                            .filter_map(|value| value.environments.as_ref())
+                            .flat_map(|value| value.iter())
+                            .filter_map(|value| value.status.as_ref())
+                            .peekable();
+                        values.peek().is_some() && values.all(|value| value == "Terminating")
+                    }
+                    Err(_) => false,
+                } {
+                    return Some(rusoto_core::waiter::WaiterState::Retry);
+                }
+                None
+            },
+        )
+        .await
+    }
+
+    /// Waits for `EnvironmentUpdated`, polling `DescribeEnvironments` with `input` every 20 seconds, up to 20 times.
+    pub async fn wait_until_environment_updated(
+        &self,
+        input: DescribeEnvironmentsMessage,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<DescribeEnvironmentsError>> {
+        let waiter = rusoto_core::waiter::Waiter::new(std::time::Duration::from_secs(20), 20);
+        self.wait_until_environment_updated_with(input, &waiter)
+            .await
+    }
+
+    /// Waits for `EnvironmentUpdated` like `wait_until_environment_updated`, with the delay, attempts and runtime of `waiter`.
+    pub async fn wait_until_environment_updated_with(
+        &self,
+        input: DescribeEnvironmentsMessage,
+        waiter: &rusoto_core::waiter::Waiter,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<DescribeEnvironmentsError>> {
+        rusoto_core::waiter::wait(
+            waiter,
+            || self.describe_environments(input.clone()),
+            |result| {
+                if match result {
+                    Ok(output) => {
+                        let mut values = std::iter::once(output)
+                            .filter_map(|value| value.environments.as_ref())
+                            .flat_map(|value| value.iter())
+                            .filter_map(|value| value.status.as_ref())
+                            .peekable();
+                        values.peek().is_some() && values.all(|value| value == "Ready")
+                    }
+                    Err(_) => false,
+                } {
+                    return Some(rusoto_core::waiter::WaiterState::Success);
+                }
+                if match result {
+                    Ok(output) => {
+                        let mut values = std::iter::once(output)
+                            .filter_map(|value| value.environments.as_ref())
+                            .flat_map(|value| value.iter())
+                            .filter_map(|value| value.status.as_ref())
+                            .peekable();
+                        values.peek().is_some() && values.all(|value| value == "Updating")
+                    }
+                    Err(_) => false,
+                } {
+                    return Some(rusoto_core::waiter::WaiterState::Retry);
+                }
+                None
+            },
+        )
+        .await
+    }
+}
+
+#[async_trait]
+impl ElasticBeanstalk for ElasticBeanstalkClient {
+    /// <p>Cancels in-progress environment configuration update or application version deployment.</p>
+    async fn abort_environment_update(
+        &self,
+        input: AbortEnvironmentUpdateMessage,
+    ) -> Result<(), RusotoError<AbortEnvironmentUpdateError>> {
+        self.abort_environment_update_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Applies a scheduled managed action immediately. A managed action can be applied only if its status is <code>Scheduled</code>. Get the status and action ID of a managed action with <a>DescribeEnvironmentManagedActions</a>.</p>
+    async fn apply_environment_managed_action(
+        &self,
+        input: ApplyEnvironmentManagedActionRequest,
+    ) -> Result<ApplyEnvironmentManagedActionResult, RusotoError<ApplyEnvironmentManagedActionError>>
+    {
+        self.apply_environment_managed_action_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Checks if the specified CNAME is available.</p>
+    async fn check_dns_availability(
+        &self,
+        input: CheckDNSAvailabilityMessage,
+    ) -> Result<CheckDNSAvailabilityResultMessage, RusotoError<CheckDNSAvailabilityError>> {
+        self.check_dns_availability_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Create or update a group of environments that each run a separate component of a single application. Takes a list of version labels that specify application source bundles for each of the environments to create or update. The name of each environment and other required information must be included in the source bundles in an environment manifest named <code>env.yaml</code>. See <a href="https://docs.aws.amazon.com/elasticbeanstalk/latest/dg/environment-mgmt-compose.html">Compose Environments</a> for details.</p>
+    async fn compose_environments(
+        &self,
+        input: ComposeEnvironmentsMessage,
+    ) -> Result<EnvironmentDescriptionsMessage, RusotoError<ComposeEnvironmentsError>> {
+        self.compose_environments_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p> Creates an application that has one configuration template named <code>default</code> and no application versions. </p>
+    async fn create_application(
+        &self,
+        input: CreateApplicationMessage,
+    ) -> Result<ApplicationDescriptionMessage, RusotoError<CreateApplicationError>> {
+        self.create_application_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Creates an application version for the specified application. You can create an application version from a source bundle in Amazon S3, a commit in AWS CodeCommit, or the output of an AWS CodeBuild build as follows:</p> <p>Specify a commit in an AWS CodeCommit repository with <code>SourceBuildInformation</code>.</p> <p>Specify a build in an AWS CodeBuild with <code>SourceBuildInformation</code> and <code>BuildConfiguration</code>.</p> <p>Specify a source bundle in S3 with <code>SourceBundle</code> </p> <p>Omit both <code>SourceBuildInformation</code> and <code>SourceBundle</code> to use the default sample application.</p> <note> <p>Once you create an application version with a specified Amazon S3 bucket and key location, you cannot change that Amazon S3 location. If you change the Amazon S3 location, you receive an exception when you attempt to launch an environment from the application version.</p> </note></p>
+    async fn create_application_version(
+        &self,
+        input: CreateApplicationVersionMessage,
+    ) -> Result<ApplicationVersionDescriptionMessage, RusotoError<CreateApplicationVersionError>>
+    {
+        self.create_application_version_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Creates a configuration template. Templates are associated with a specific application and are used to deploy different versions of the application with the same configuration settings.</p> <p>Templates aren&#39;t associated with any environment. The <code>EnvironmentName</code> response element is always <code>null</code>.</p> <p>Related Topics</p> <ul> <li> <p> <a>DescribeConfigurationOptions</a> </p> </li> <li> <p> <a>DescribeConfigurationSettings</a> </p> </li> <li> <p> <a>ListAvailableSolutionStacks</a> </p> </li> </ul></p>
+    async fn create_configuration_template(
+        &self,
+        input: CreateConfigurationTemplateMessage,
+    ) -> Result<ConfigurationSettingsDescription, RusotoError<CreateConfigurationTemplateError>>
+    {
+        self.create_configuration_template_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Launches an environment for the specified application using the specified configuration.</p>
+    async fn create_environment(
+        &self,
+        input: CreateEnvironmentMessage,
+    ) -> Result<EnvironmentDescription, RusotoError<CreateEnvironmentError>> {
+        self.create_environment_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Create a new version of your custom platform.</p>
+    async fn create_platform_version(
+        &self,
+        input: CreatePlatformVersionRequest,
+    ) -> Result<CreatePlatformVersionResult, RusotoError<CreatePlatformVersionError>> {
+        self.create_platform_version_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Creates a bucket in Amazon S3 to store application versions, logs, and other files used by Elastic Beanstalk environments. The Elastic Beanstalk console and EB CLI call this API the first time you create an environment in a region. If the storage location already exists, <code>CreateStorageLocation</code> still returns the bucket name but does not create a new bucket.</p>
+    async fn create_storage_location(
+        &self,
+    ) -> Result<CreateStorageLocationResultMessage, RusotoError<CreateStorageLocationError>> {
+        self.create_storage_location_with_metadata()
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Deletes the specified application along with all associated versions and configurations. The application versions will not be deleted from your Amazon S3 bucket.</p> <note> <p>You cannot delete an application that has a running environment.</p> </note></p>
+    async fn delete_application(
+        &self,
+        input: DeleteApplicationMessage,
+    ) -> Result<(), RusotoError<DeleteApplicationError>> {
+        self.delete_application_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Deletes the specified version from the specified application.</p> <note> <p>You cannot delete an application version that is associated with a running environment.</p> </note></p>
+    async fn delete_application_version(
+        &self,
+        input: DeleteApplicationVersionMessage,
+    ) -> Result<(), RusotoError<DeleteApplicationVersionError>> {
+        self.delete_application_version_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Deletes the specified configuration template.</p> <note> <p>When you launch an environment using a configuration template, the environment gets a copy of the template. You can delete or modify the environment&#39;s copy of the template without affecting the running environment.</p> </note></p>
+    async fn delete_configuration_template(
+        &self,
+        input: DeleteConfigurationTemplateMessage,
+    ) -> Result<(), RusotoError<DeleteConfigurationTemplateError>> {
+        self.delete_configuration_template_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes the draft configuration associated with the running environment.</p> <p>Updating a running environment with any configuration changes creates a draft configuration set. You can get the draft configuration using <a>DescribeConfigurationSettings</a> while the update is in progress or if the update fails. The <code>DeploymentStatus</code> for the draft configuration indicates whether the deployment is in process or has failed. The draft configuration remains in existence until it is deleted with this action.</p>
+    async fn delete_environment_configuration(
+        &self,
+        input: DeleteEnvironmentConfigurationMessage,
+    ) -> Result<(), RusotoError<DeleteEnvironmentConfigurationError>> {
+        self.delete_environment_configuration_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes the specified version of a custom platform.</p>
+    async fn delete_platform_version(
+        &self,
+        input: DeletePlatformVersionRequest,
+    ) -> Result<DeletePlatformVersionResult, RusotoError<DeletePlatformVersionError>> {
+        self.delete_platform_version_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns attributes related to AWS Elastic Beanstalk that are associated with the calling AWS account.</p> <p>The result currently has one set of attributes—resource quotas.</p>
+    async fn describe_account_attributes(
+        &self,
+    ) -> Result<DescribeAccountAttributesResult, RusotoError<DescribeAccountAttributesError>> {
+        self.describe_account_attributes_with_metadata()
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Retrieve a list of application versions.</p>
+    async fn describe_application_versions(
+        &self,
+        input: DescribeApplicationVersionsMessage,
+    ) -> Result<ApplicationVersionDescriptionsMessage, RusotoError<DescribeApplicationVersionsError>>
+    {
+        self.describe_application_versions_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns the descriptions of existing applications.</p>
+    async fn describe_applications(
+        &self,
+        input: DescribeApplicationsMessage,
+    ) -> Result<ApplicationDescriptionsMessage, RusotoError<DescribeApplicationsError>> {
+        self.describe_applications_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Describes the configuration options that are used in a particular configuration template or environment, or that a specified solution stack defines. The description includes the values the options, their default values, and an indication of the required action on a running environment if an option value is changed.</p>
+    async fn describe_configuration_options(
+        &self,
+        input: DescribeConfigurationOptionsMessage,
+    ) -> Result<ConfigurationOptionsDescription, RusotoError<DescribeConfigurationOptionsError>>
+    {
+        self.describe_configuration_options_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Returns a description of the settings for the specified configuration set, that is, either a configuration template or the configuration set associated with a running environment.</p> <p>When describing the settings for the configuration set associated with a running environment, it is possible to receive two sets of setting descriptions. One is the deployed configuration set, and the other is a draft configuration of an environment that is either in the process of deployment or that failed to deploy.</p> <p>Related Topics</p> <ul> <li> <p> <a>DeleteEnvironmentConfiguration</a> </p> </li> </ul></p>
+    async fn describe_configuration_settings(
+        &self,
+        input: DescribeConfigurationSettingsMessage,
+    ) -> Result<ConfigurationSettingsDescriptions, RusotoError<DescribeConfigurationSettingsError>>
+    {
+        self.describe_configuration_settings_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns information about the overall health of the specified environment. The <b>DescribeEnvironmentHealth</b> operation is only available with AWS Elastic Beanstalk Enhanced Health.</p>
+    async fn describe_environment_health(
+        &self,
+        input: DescribeEnvironmentHealthRequest,
+    ) -> Result<DescribeEnvironmentHealthResult, RusotoError<DescribeEnvironmentHealthError>> {
+        self.describe_environment_health_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Lists an environment's completed and failed managed actions.</p>
+    async fn describe_environment_managed_action_history(
+        &self,
+        input: DescribeEnvironmentManagedActionHistoryRequest,
+    ) -> Result<
+        DescribeEnvironmentManagedActionHistoryResult,
+        RusotoError<DescribeEnvironmentManagedActionHistoryError>,
+    > {
+        self.describe_environment_managed_action_history_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Lists an environment's upcoming and in-progress managed actions.</p>
+    async fn describe_environment_managed_actions(
+        &self,
+        input: DescribeEnvironmentManagedActionsRequest,
+    ) -> Result<
+        DescribeEnvironmentManagedActionsResult,
+        RusotoError<DescribeEnvironmentManagedActionsError>,
+    > {
+        self.describe_environment_managed_actions_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns AWS resources for this environment.</p>
+    async fn describe_environment_resources(
+        &self,
+        input: DescribeEnvironmentResourcesMessage,
+    ) -> Result<
+        EnvironmentResourceDescriptionsMessage,
+        RusotoError<DescribeEnvironmentResourcesError>,
+    > {
+        self.describe_environment_resources_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns descriptions for existing environments.</p>
+    async fn describe_environments(
+        &self,
+        input: DescribeEnvironmentsMessage,
+    ) -> Result<EnvironmentDescriptionsMessage, RusotoError<DescribeEnvironmentsError>> {
+        self.describe_environments_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Returns list of event descriptions matching criteria up to the last 6 weeks.</p> <note> <p>This action returns the most recent 1,000 events from the specified <code>NextToken</code>.</p> </note></p>
+    async fn describe_events(
+        &self,
+        input: DescribeEventsMessage,
+    ) -> Result<EventDescriptionsMessage, RusotoError<DescribeEventsError>> {
+        self.describe_events_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Retrieves detailed information about the health of instances in your AWS Elastic Beanstalk. This operation requires <a href="https://docs.aws.amazon.com/elasticbeanstalk/latest/dg/health-enhanced.html">enhanced health reporting</a>.</p>
+    async fn describe_instances_health(
+        &self,
+        input: DescribeInstancesHealthRequest,
+    ) -> Result<DescribeInstancesHealthResult, RusotoError<DescribeInstancesHealthError>> {
+        self.describe_instances_health_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Describes the version of the platform.</p>
+    async fn describe_platform_version(
+        &self,
+        input: DescribePlatformVersionRequest,
+    ) -> Result<DescribePlatformVersionResult, RusotoError<DescribePlatformVersionError>> {
+        self.describe_platform_version_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns a list of the available solution stack names, with the public version first and then in reverse chronological order.</p>
+    async fn list_available_solution_stacks(
+        &self,
+    ) -> Result<
+        ListAvailableSolutionStacksResultMessage,
+        RusotoError<ListAvailableSolutionStacksError>,
+    > {
+        self.list_available_solution_stacks_with_metadata()
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Lists the available platforms.</p>
+    async fn list_platform_versions(
+        &self,
+        input: ListPlatformVersionsRequest,
+    ) -> Result<ListPlatformVersionsResult, RusotoError<ListPlatformVersionsError>> {
+        self.list_platform_versions_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns the tags applied to an AWS Elastic Beanstalk resource. The response contains a list of tag key-value pairs.</p> <p>Currently, Elastic Beanstalk only supports tagging of Elastic Beanstalk environments. For details about environment tagging, see <a href="https://docs.aws.amazon.com/elasticbeanstalk/latest/dg/using-features.tagging.html">Tagging Resources in Your Elastic Beanstalk Environment</a>.</p>
+    async fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceMessage,
+    ) -> Result<ResourceTagsDescriptionMessage, RusotoError<ListTagsForResourceError>> {
+        self.list_tags_for_resource_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes and recreates all of the AWS resources (for example: the Auto Scaling group, load balancer, etc.) for a specified environment and forces a restart.</p>
+    async fn rebuild_environment(
+        &self,
+        input: RebuildEnvironmentMessage,
+    ) -> Result<(), RusotoError<RebuildEnvironmentError>> {
+        self.rebuild_environment_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Initiates a request to compile the specified type of information of the deployed environment.</p> <p> Setting the <code>InfoType</code> to <code>tail</code> compiles the last lines from the application server log files of every Amazon EC2 instance in your environment. </p> <p> Setting the <code>InfoType</code> to <code>bundle</code> compresses the application server log files for every Amazon EC2 instance into a <code>.zip</code> file. Legacy and .NET containers do not support bundle logs. </p> <p> Use <a>RetrieveEnvironmentInfo</a> to obtain the set of logs. </p> <p>Related Topics</p> <ul> <li> <p> <a>RetrieveEnvironmentInfo</a> </p> </li> </ul></p>
+    async fn request_environment_info(
+        &self,
+        input: RequestEnvironmentInfoMessage,
+    ) -> Result<(), RusotoError<RequestEnvironmentInfoError>> {
+        self.request_environment_info_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Causes the environment to restart the application container server running on each Amazon EC2 instance.</p>
+    async fn restart_app_server(
+        &self,
+        input: RestartAppServerMessage,
+    ) -> Result<(), RusotoError<RestartAppServerError>> {
+        self.restart_app_server_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Retrieves the compiled information from a <a>RequestEnvironmentInfo</a> request.</p> <p>Related Topics</p> <ul> <li> <p> <a>RequestEnvironmentInfo</a> </p> </li> </ul></p>
+    async fn retrieve_environment_info(
+        &self,
+        input: RetrieveEnvironmentInfoMessage,
+    ) -> Result<RetrieveEnvironmentInfoResultMessage, RusotoError<RetrieveEnvironmentInfoError>>
+    {
+        self.retrieve_environment_info_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Swaps the CNAMEs of two environments.</p>
+    async fn swap_environment_cnam_es(
+        &self,
+        input: SwapEnvironmentCNAMEsMessage,
+    ) -> Result<(), RusotoError<SwapEnvironmentCNAMEsError>> {
+        self.swap_environment_cnam_es_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Terminates the specified environment.</p>
+    async fn terminate_environment(
+        &self,
+        input: TerminateEnvironmentMessage,
+    ) -> Result<EnvironmentDescription, RusotoError<TerminateEnvironmentError>> {
+        self.terminate_environment_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Updates the specified application to have the specified properties.</p> <note> <p>If a property (for example, <code>description</code>) is not provided, the value remains unchanged. To clear these properties, specify an empty string.</p> </note></p>
+    async fn update_application(
+        &self,
+        input: UpdateApplicationMessage,
+    ) -> Result<ApplicationDescriptionMessage, RusotoError<UpdateApplicationError>> {
+        self.update_application_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Modifies lifecycle settings for an application.</p>
+    async fn update_application_resource_lifecycle(
+        &self,
+        input: UpdateApplicationResourceLifecycleMessage,
+    ) -> Result<
+        ApplicationResourceLifecycleDescriptionMessage,
+        RusotoError<UpdateApplicationResourceLifecycleError>,
+    > {
+        self.update_application_resource_lifecycle_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Updates the specified application version to have the specified properties.</p> <note> <p>If a property (for example, <code>description</code>) is not provided, the value remains unchanged. To clear properties, specify an empty string.</p> </note></p>
+    async fn update_application_version(
+        &self,
+        input: UpdateApplicationVersionMessage,
+    ) -> Result<ApplicationVersionDescriptionMessage, RusotoError<UpdateApplicationVersionError>>
+    {
+        self.update_application_version_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Updates the specified configuration template to have the specified properties or configuration option values.</p> <note> <p>If a property (for example, <code>ApplicationName</code>) is not provided, its value remains unchanged. To clear such properties, specify an empty string.</p> </note> <p>Related Topics</p> <ul> <li> <p> <a>DescribeConfigurationOptions</a> </p> </li> </ul></p>
+    async fn update_configuration_template(
+        &self,
+        input: UpdateConfigurationTemplateMessage,
+    ) -> Result<ConfigurationSettingsDescription, RusotoError<UpdateConfigurationTemplateError>>
+    {
+        self.update_configuration_template_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Updates the environment description, deploys a new application version, updates the configuration settings to an entirely new configuration template, or updates select configuration option values in the running environment.</p> <p> Attempting to update both the release and configuration is not allowed and AWS Elastic Beanstalk returns an <code>InvalidParameterCombination</code> error. </p> <p> When updating the configuration settings to a new template or individual settings, a draft configuration is created and <a>DescribeConfigurationSettings</a> for this environment returns two setting descriptions with different <code>DeploymentStatus</code> values. </p>
+    async fn update_environment(
+        &self,
+        input: UpdateEnvironmentMessage,
+    ) -> Result<EnvironmentDescription, RusotoError<UpdateEnvironmentError>> {
+        self.update_environment_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Update the list of tags applied to an AWS Elastic Beanstalk resource. Two lists can be passed: <code>TagsToAdd</code> for tags to add or update, and <code>TagsToRemove</code>.</p> <p>Currently, Elastic Beanstalk only supports tagging of Elastic Beanstalk environments. For details about environment tagging, see <a href="https://docs.aws.amazon.com/elasticbeanstalk/latest/dg/using-features.tagging.html">Tagging Resources in Your Elastic Beanstalk Environment</a>.</p> <p>If you create a custom IAM user policy to control permission to this operation, specify one of the following two virtual actions (or both) instead of the API operation name:</p> <dl> <dt>elasticbeanstalk:AddTags</dt> <dd> <p>Controls permission to call <code>UpdateTagsForResource</code> and pass a list of tags to add in the <code>TagsToAdd</code> parameter.</p> </dd> <dt>elasticbeanstalk:RemoveTags</dt> <dd> <p>Controls permission to call <code>UpdateTagsForResource</code> and pass a list of tag keys to remove in the <code>TagsToRemove</code> parameter.</p> </dd> </dl> <p>For details about creating a custom user policy, see <a href="https://docs.aws.amazon.com/elasticbeanstalk/latest/dg/AWSHowTo.iam.managed-policies.html#AWSHowTo.iam.policies">Creating a Custom User Policy</a>.</p>
+    async fn update_tags_for_resource(
+        &self,
+        input: UpdateTagsForResourceMessage,
+    ) -> Result<(), RusotoError<UpdateTagsForResourceError>> {
+        self.update_tags_for_resource_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Takes a set of configuration settings and either a configuration template or environment, and determines whether those values are valid.</p> <p>This action returns a list of messages indicating any errors or warnings associated with the selection of option values.</p>
+    async fn validate_configuration_settings(
+        &self,
+        input: ValidateConfigurationSettingsMessage,
+    ) -> Result<
+        ConfigurationSettingsValidationMessages,
+        RusotoError<ValidateConfigurationSettingsError>,
+    > {
+        self.validate_configuration_settings_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
     }
 }
 
@@ -16,8 +16,8 @@ use std::fmt;
 use async_trait::async_trait;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
-use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest, ResponseMetadata};
+use rusoto_core::{Client, ErrorMetadata, RusotoError};
 
 use rusoto_core::param::{Params, ServiceParams};
 use rusoto_core::proto;
@@ -1431,6 +1431,7 @@ pub struct Warning {
 
 /// Errors returned by CancelJob
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CancelJobError {
     /// <p>General authentication failure. The request was not signed correctly.</p>
     AccessDenied(String),
@@ -1442,6 +1443,8 @@ pub enum CancelJobError {
     ResourceInUse(String),
     /// <p>The requested resource does not exist or is not available. For example, the pipeline to which you're trying to add a job doesn't exist or is still being created.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CancelJobError {
@@ -1449,22 +1452,22 @@ impl CancelJobError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(CancelJobError::AccessDenied(err.msg))
+                    return RusotoError::service(CancelJobError::AccessDenied(err.msg), &res)
                 }
                 "IncompatibleVersionException" => {
-                    return RusotoError::Service(CancelJobError::IncompatibleVersion(err.msg))
+                    return RusotoError::service(CancelJobError::IncompatibleVersion(err.msg), &res)
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(CancelJobError::InternalService(err.msg))
+                    return RusotoError::service(CancelJobError::InternalService(err.msg), &res)
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(CancelJobError::ResourceInUse(err.msg))
+                    return RusotoError::service(CancelJobError::ResourceInUse(err.msg), &res)
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(CancelJobError::ResourceNotFound(err.msg))
+                    return RusotoError::service(CancelJobError::ResourceNotFound(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CancelJobError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1479,12 +1482,14 @@ impl fmt::Display for CancelJobError {
             CancelJobError::InternalService(ref cause) => write!(f, "{}", cause),
             CancelJobError::ResourceInUse(ref cause) => write!(f, "{}", cause),
             CancelJobError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            CancelJobError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CancelJobError {}
 /// Errors returned by CreateJob
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateJobError {
     /// <p>General authentication failure. The request was not signed correctly.</p>
     AccessDenied(String),
@@ -1496,6 +1501,8 @@ pub enum CreateJobError {
     LimitExceeded(String),
     /// <p>The requested resource does not exist or is not available. For example, the pipeline to which you're trying to add a job doesn't exist or is still being created.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateJobError {
@@ -1503,22 +1510,22 @@ impl CreateJobError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(CreateJobError::AccessDenied(err.msg))
+                    return RusotoError::service(CreateJobError::AccessDenied(err.msg), &res)
                 }
                 "IncompatibleVersionException" => {
-                    return RusotoError::Service(CreateJobError::IncompatibleVersion(err.msg))
+                    return RusotoError::service(CreateJobError::IncompatibleVersion(err.msg), &res)
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(CreateJobError::InternalService(err.msg))
+                    return RusotoError::service(CreateJobError::InternalService(err.msg), &res)
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateJobError::LimitExceeded(err.msg))
+                    return RusotoError::service(CreateJobError::LimitExceeded(err.msg), &res)
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(CreateJobError::ResourceNotFound(err.msg))
+                    return RusotoError::service(CreateJobError::ResourceNotFound(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreateJobError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1533,12 +1540,14 @@ impl fmt::Display for CreateJobError {
             CreateJobError::InternalService(ref cause) => write!(f, "{}", cause),
             CreateJobError::LimitExceeded(ref cause) => write!(f, "{}", cause),
             CreateJobError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            CreateJobError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateJobError {}
 /// Errors returned by CreatePipeline
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreatePipelineError {
     /// <p>General authentication failure. The request was not signed correctly.</p>
     AccessDenied(String),
@@ -1550,6 +1559,8 @@ pub enum CreatePipelineError {
     LimitExceeded(String),
     /// <p>The requested resource does not exist or is not available. For example, the pipeline to which you're trying to add a job doesn't exist or is still being created.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreatePipelineError {
@@ -1557,22 +1568,31 @@ impl CreatePipelineError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(CreatePipelineError::AccessDenied(err.msg))
+                    return RusotoError::service(CreatePipelineError::AccessDenied(err.msg), &res)
                 }
                 "IncompatibleVersionException" => {
-                    return RusotoError::Service(CreatePipelineError::IncompatibleVersion(err.msg))
+                    return RusotoError::service(
+                        CreatePipelineError::IncompatibleVersion(err.msg),
+                        &res,
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(CreatePipelineError::InternalService(err.msg))
+                    return RusotoError::service(
+                        CreatePipelineError::InternalService(err.msg),
+                        &res,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreatePipelineError::LimitExceeded(err.msg))
+                    return RusotoError::service(CreatePipelineError::LimitExceeded(err.msg), &res)
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(CreatePipelineError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        CreatePipelineError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreatePipelineError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1587,12 +1607,14 @@ impl fmt::Display for CreatePipelineError {
             CreatePipelineError::InternalService(ref cause) => write!(f, "{}", cause),
             CreatePipelineError::LimitExceeded(ref cause) => write!(f, "{}", cause),
             CreatePipelineError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            CreatePipelineError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreatePipelineError {}
 /// Errors returned by CreatePreset
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreatePresetError {
     /// <p>General authentication failure. The request was not signed correctly.</p>
     AccessDenied(String),
@@ -1602,6 +1624,8 @@ pub enum CreatePresetError {
     InternalService(String),
     /// <p>Too many operations for a given AWS account. For example, the number of pipelines exceeds the maximum allowed.</p>
     LimitExceeded(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreatePresetError {
@@ -1609,19 +1633,22 @@ impl CreatePresetError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(CreatePresetError::AccessDenied(err.msg))
+                    return RusotoError::service(CreatePresetError::AccessDenied(err.msg), &res)
                 }
                 "IncompatibleVersionException" => {
-                    return RusotoError::Service(CreatePresetError::IncompatibleVersion(err.msg))
+                    return RusotoError::service(
+                        CreatePresetError::IncompatibleVersion(err.msg),
+                        &res,
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(CreatePresetError::InternalService(err.msg))
+                    return RusotoError::service(CreatePresetError::InternalService(err.msg), &res)
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreatePresetError::LimitExceeded(err.msg))
+                    return RusotoError::service(CreatePresetError::LimitExceeded(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreatePresetError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1635,12 +1662,14 @@ impl fmt::Display for CreatePresetError {
             CreatePresetError::IncompatibleVersion(ref cause) => write!(f, "{}", cause),
             CreatePresetError::InternalService(ref cause) => write!(f, "{}", cause),
             CreatePresetError::LimitExceeded(ref cause) => write!(f, "{}", cause),
+            CreatePresetError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreatePresetError {}
 /// Errors returned by DeletePipeline
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeletePipelineError {
     /// <p>General authentication failure. The request was not signed correctly.</p>
     AccessDenied(String),
@@ -1652,6 +1681,8 @@ pub enum DeletePipelineError {
     ResourceInUse(String),
     /// <p>The requested resource does not exist or is not available. For example, the pipeline to which you're trying to add a job doesn't exist or is still being created.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeletePipelineError {
@@ -1659,22 +1690,31 @@ impl DeletePipelineError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(DeletePipelineError::AccessDenied(err.msg))
+                    return RusotoError::service(DeletePipelineError::AccessDenied(err.msg), &res)
                 }
                 "IncompatibleVersionException" => {
-                    return RusotoError::Service(DeletePipelineError::IncompatibleVersion(err.msg))
+                    return RusotoError::service(
+                        DeletePipelineError::IncompatibleVersion(err.msg),
+                        &res,
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(DeletePipelineError::InternalService(err.msg))
+                    return RusotoError::service(
+                        DeletePipelineError::InternalService(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(DeletePipelineError::ResourceInUse(err.msg))
+                    return RusotoError::service(DeletePipelineError::ResourceInUse(err.msg), &res)
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeletePipelineError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        DeletePipelineError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeletePipelineError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1689,12 +1729,14 @@ impl fmt::Display for DeletePipelineError {
             DeletePipelineError::InternalService(ref cause) => write!(f, "{}", cause),
             DeletePipelineError::ResourceInUse(ref cause) => write!(f, "{}", cause),
             DeletePipelineError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            DeletePipelineError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeletePipelineError {}
 /// Errors returned by DeletePreset
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeletePresetError {
     /// <p>General authentication failure. The request was not signed correctly.</p>
     AccessDenied(String),
@@ -1704,6 +1746,8 @@ pub enum DeletePresetError {
     InternalService(String),
     /// <p>The requested resource does not exist or is not available. For example, the pipeline to which you're trying to add a job doesn't exist or is still being created.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeletePresetError {
@@ -1711,19 +1755,22 @@ impl DeletePresetError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(DeletePresetError::AccessDenied(err.msg))
+                    return RusotoError::service(DeletePresetError::AccessDenied(err.msg), &res)
                 }
                 "IncompatibleVersionException" => {
-                    return RusotoError::Service(DeletePresetError::IncompatibleVersion(err.msg))
+                    return RusotoError::service(
+                        DeletePresetError::IncompatibleVersion(err.msg),
+                        &res,
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(DeletePresetError::InternalService(err.msg))
+                    return RusotoError::service(DeletePresetError::InternalService(err.msg), &res)
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeletePresetError::ResourceNotFound(err.msg))
+                    return RusotoError::service(DeletePresetError::ResourceNotFound(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeletePresetError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1737,12 +1784,14 @@ impl fmt::Display for DeletePresetError {
             DeletePresetError::IncompatibleVersion(ref cause) => write!(f, "{}", cause),
             DeletePresetError::InternalService(ref cause) => write!(f, "{}", cause),
             DeletePresetError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            DeletePresetError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeletePresetError {}
 /// Errors returned by ListJobsByPipeline
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListJobsByPipelineError {
     /// <p>General authentication failure. The request was not signed correctly.</p>
     AccessDenied(String),
@@ -1752,6 +1801,8 @@ pub enum ListJobsByPipelineError {
     InternalService(String),
     /// <p>The requested resource does not exist or is not available. For example, the pipeline to which you're trying to add a job doesn't exist or is still being created.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListJobsByPipelineError {
@@ -1759,21 +1810,31 @@ impl ListJobsByPipelineError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(ListJobsByPipelineError::AccessDenied(err.msg))
+                    return RusotoError::service(
+                        ListJobsByPipelineError::AccessDenied(err.msg),
+                        &res,
+                    )
                 }
                 "IncompatibleVersionException" => {
-                    return RusotoError::Service(ListJobsByPipelineError::IncompatibleVersion(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListJobsByPipelineError::IncompatibleVersion(err.msg),
+                        &res,
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(ListJobsByPipelineError::InternalService(err.msg))
+                    return RusotoError::service(
+                        ListJobsByPipelineError::InternalService(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListJobsByPipelineError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        ListJobsByPipelineError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListJobsByPipelineError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1787,12 +1848,14 @@ impl fmt::Display for ListJobsByPipelineError {
             ListJobsByPipelineError::IncompatibleVersion(ref cause) => write!(f, "{}", cause),
             ListJobsByPipelineError::InternalService(ref cause) => write!(f, "{}", cause),
             ListJobsByPipelineError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            ListJobsByPipelineError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListJobsByPipelineError {}
 /// Errors returned by ListJobsByStatus
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListJobsByStatusError {
     /// <p>General authentication failure. The request was not signed correctly.</p>
     AccessDenied(String),
@@ -1802,6 +1865,8 @@ pub enum ListJobsByStatusError {
     InternalService(String),
     /// <p>The requested resource does not exist or is not available. For example, the pipeline to which you're trying to add a job doesn't exist or is still being created.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListJobsByStatusError {
@@ -1809,21 +1874,28 @@ impl ListJobsByStatusError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(ListJobsByStatusError::AccessDenied(err.msg))
+                    return RusotoError::service(ListJobsByStatusError::AccessDenied(err.msg), &res)
                 }
                 "IncompatibleVersionException" => {
-                    return RusotoError::Service(ListJobsByStatusError::IncompatibleVersion(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListJobsByStatusError::IncompatibleVersion(err.msg),
+                        &res,
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(ListJobsByStatusError::InternalService(err.msg))
+                    return RusotoError::service(
+                        ListJobsByStatusError::InternalService(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListJobsByStatusError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        ListJobsByStatusError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListJobsByStatusError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1837,12 +1909,14 @@ impl fmt::Display for ListJobsByStatusError {
             ListJobsByStatusError::IncompatibleVersion(ref cause) => write!(f, "{}", cause),
             ListJobsByStatusError::InternalService(ref cause) => write!(f, "{}", cause),
             ListJobsByStatusError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            ListJobsByStatusError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListJobsByStatusError {}
 /// Errors returned by ListPipelines
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListPipelinesError {
     /// <p>General authentication failure. The request was not signed correctly.</p>
     AccessDenied(String),
@@ -1850,6 +1924,8 @@ pub enum ListPipelinesError {
     IncompatibleVersion(String),
     /// <p>Elastic Transcoder encountered an unexpected exception while trying to fulfill the request.</p>
     InternalService(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListPipelinesError {
@@ -1857,16 +1933,19 @@ impl ListPipelinesError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(ListPipelinesError::AccessDenied(err.msg))
+                    return RusotoError::service(ListPipelinesError::AccessDenied(err.msg), &res)
                 }
                 "IncompatibleVersionException" => {
-                    return RusotoError::Service(ListPipelinesError::IncompatibleVersion(err.msg))
+                    return RusotoError::service(
+                        ListPipelinesError::IncompatibleVersion(err.msg),
+                        &res,
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(ListPipelinesError::InternalService(err.msg))
+                    return RusotoError::service(ListPipelinesError::InternalService(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListPipelinesError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1879,12 +1958,14 @@ impl fmt::Display for ListPipelinesError {
             ListPipelinesError::AccessDenied(ref cause) => write!(f, "{}", cause),
             ListPipelinesError::IncompatibleVersion(ref cause) => write!(f, "{}", cause),
             ListPipelinesError::InternalService(ref cause) => write!(f, "{}", cause),
+            ListPipelinesError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListPipelinesError {}
 /// Errors returned by ListPresets
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListPresetsError {
     /// <p>General authentication failure. The request was not signed correctly.</p>
     AccessDenied(String),
@@ -1892,6 +1973,8 @@ pub enum ListPresetsError {
     IncompatibleVersion(String),
     /// <p>Elastic Transcoder encountered an unexpected exception while trying to fulfill the request.</p>
     InternalService(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListPresetsError {
@@ -1899,16 +1982,19 @@ impl ListPresetsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(ListPresetsError::AccessDenied(err.msg))
+                    return RusotoError::service(ListPresetsError::AccessDenied(err.msg), &res)
                 }
                 "IncompatibleVersionException" => {
-                    return RusotoError::Service(ListPresetsError::IncompatibleVersion(err.msg))
+                    return RusotoError::service(
+                        ListPresetsError::IncompatibleVersion(err.msg),
+                        &res,
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(ListPresetsError::InternalService(err.msg))
+                    return RusotoError::service(ListPresetsError::InternalService(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListPresetsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1921,12 +2007,14 @@ impl fmt::Display for ListPresetsError {
             ListPresetsError::AccessDenied(ref cause) => write!(f, "{}", cause),
             ListPresetsError::IncompatibleVersion(ref cause) => write!(f, "{}", cause),
             ListPresetsError::InternalService(ref cause) => write!(f, "{}", cause),
+            ListPresetsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListPresetsError {}
 /// Errors returned by ReadJob
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ReadJobError {
     /// <p>General authentication failure. The request was not signed correctly.</p>
     AccessDenied(String),
@@ -1936,6 +2024,8 @@ pub enum ReadJobError {
     InternalService(String),
     /// <p>The requested resource does not exist or is not available. For example, the pipeline to which you're trying to add a job doesn't exist or is still being created.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ReadJobError {
@@ -1943,19 +2033,19 @@ impl ReadJobError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(ReadJobError::AccessDenied(err.msg))
+                    return RusotoError::service(ReadJobError::AccessDenied(err.msg), &res)
                 }
                 "IncompatibleVersionException" => {
-                    return RusotoError::Service(ReadJobError::IncompatibleVersion(err.msg))
+                    return RusotoError::service(ReadJobError::IncompatibleVersion(err.msg), &res)
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(ReadJobError::InternalService(err.msg))
+                    return RusotoError::service(ReadJobError::InternalService(err.msg), &res)
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ReadJobError::ResourceNotFound(err.msg))
+                    return RusotoError::service(ReadJobError::ResourceNotFound(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ReadJobError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1969,12 +2059,14 @@ impl fmt::Display for ReadJobError {
             ReadJobError::IncompatibleVersion(ref cause) => write!(f, "{}", cause),
             ReadJobError::InternalService(ref cause) => write!(f, "{}", cause),
             ReadJobError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            ReadJobError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ReadJobError {}
 /// Errors returned by ReadPipeline
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ReadPipelineError {
     /// <p>General authentication failure. The request was not signed correctly.</p>
     AccessDenied(String),
@@ -1984,6 +2076,8 @@ pub enum ReadPipelineError {
     InternalService(String),
     /// <p>The requested resource does not exist or is not available. For example, the pipeline to which you're trying to add a job doesn't exist or is still being created.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ReadPipelineError {
@@ -1991,19 +2085,22 @@ impl ReadPipelineError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(ReadPipelineError::AccessDenied(err.msg))
+                    return RusotoError::service(ReadPipelineError::AccessDenied(err.msg), &res)
                 }
                 "IncompatibleVersionException" => {
-                    return RusotoError::Service(ReadPipelineError::IncompatibleVersion(err.msg))
+                    return RusotoError::service(
+                        ReadPipelineError::IncompatibleVersion(err.msg),
+                        &res,
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(ReadPipelineError::InternalService(err.msg))
+                    return RusotoError::service(ReadPipelineError::InternalService(err.msg), &res)
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ReadPipelineError::ResourceNotFound(err.msg))
+                    return RusotoError::service(ReadPipelineError::ResourceNotFound(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ReadPipelineError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2017,12 +2114,14 @@ impl fmt::Display for ReadPipelineError {
             ReadPipelineError::IncompatibleVersion(ref cause) => write!(f, "{}", cause),
             ReadPipelineError::InternalService(ref cause) => write!(f, "{}", cause),
             ReadPipelineError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            ReadPipelineError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ReadPipelineError {}
 /// Errors returned by ReadPreset
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ReadPresetError {
     /// <p>General authentication failure. The request was not signed correctly.</p>
     AccessDenied(String),
@@ -2032,6 +2131,8 @@ pub enum ReadPresetError {
     InternalService(String),
     /// <p>The requested resource does not exist or is not available. For example, the pipeline to which you're trying to add a job doesn't exist or is still being created.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ReadPresetError {
@@ -2039,19 +2140,22 @@ impl ReadPresetError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(ReadPresetError::AccessDenied(err.msg))
+                    return RusotoError::service(ReadPresetError::AccessDenied(err.msg), &res)
                 }
                 "IncompatibleVersionException" => {
-                    return RusotoError::Service(ReadPresetError::IncompatibleVersion(err.msg))
+                    return RusotoError::service(
+                        ReadPresetError::IncompatibleVersion(err.msg),
+                        &res,
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(ReadPresetError::InternalService(err.msg))
+                    return RusotoError::service(ReadPresetError::InternalService(err.msg), &res)
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ReadPresetError::ResourceNotFound(err.msg))
+                    return RusotoError::service(ReadPresetError::ResourceNotFound(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ReadPresetError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2065,12 +2169,14 @@ impl fmt::Display for ReadPresetError {
             ReadPresetError::IncompatibleVersion(ref cause) => write!(f, "{}", cause),
             ReadPresetError::InternalService(ref cause) => write!(f, "{}", cause),
             ReadPresetError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            ReadPresetError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ReadPresetError {}
 /// Errors returned by TestRole
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum TestRoleError {
     /// <p>General authentication failure. The request was not signed correctly.</p>
     AccessDenied(String),
@@ -2080,6 +2186,8 @@ pub enum TestRoleError {
     InternalService(String),
     /// <p>The requested resource does not exist or is not available. For example, the pipeline to which you're trying to add a job doesn't exist or is still being created.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl TestRoleError {
@@ -2087,19 +2195,19 @@ impl TestRoleError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(TestRoleError::AccessDenied(err.msg))
+                    return RusotoError::service(TestRoleError::AccessDenied(err.msg), &res)
                 }
                 "IncompatibleVersionException" => {
-                    return RusotoError::Service(TestRoleError::IncompatibleVersion(err.msg))
+                    return RusotoError::service(TestRoleError::IncompatibleVersion(err.msg), &res)
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(TestRoleError::InternalService(err.msg))
+                    return RusotoError::service(TestRoleError::InternalService(err.msg), &res)
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(TestRoleError::ResourceNotFound(err.msg))
+                    return RusotoError::service(TestRoleError::ResourceNotFound(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(TestRoleError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2113,12 +2221,14 @@ impl fmt::Display for TestRoleError {
             TestRoleError::IncompatibleVersion(ref cause) => write!(f, "{}", cause),
             TestRoleError::InternalService(ref cause) => write!(f, "{}", cause),
             TestRoleError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            TestRoleError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for TestRoleError {}
 /// Errors returned by UpdatePipeline
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdatePipelineError {
     /// <p>General authentication failure. The request was not signed correctly.</p>
     AccessDenied(String),
@@ -2130,6 +2240,8 @@ pub enum UpdatePipelineError {
     ResourceInUse(String),
     /// <p>The requested resource does not exist or is not available. For example, the pipeline to which you're trying to add a job doesn't exist or is still being created.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdatePipelineError {
@@ -2137,22 +2249,31 @@ impl UpdatePipelineError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(UpdatePipelineError::AccessDenied(err.msg))
+                    return RusotoError::service(UpdatePipelineError::AccessDenied(err.msg), &res)
                 }
                 "IncompatibleVersionException" => {
-                    return RusotoError::Service(UpdatePipelineError::IncompatibleVersion(err.msg))
+                    return RusotoError::service(
+                        UpdatePipelineError::IncompatibleVersion(err.msg),
+                        &res,
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(UpdatePipelineError::InternalService(err.msg))
+                    return RusotoError::service(
+                        UpdatePipelineError::InternalService(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(UpdatePipelineError::ResourceInUse(err.msg))
+                    return RusotoError::service(UpdatePipelineError::ResourceInUse(err.msg), &res)
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdatePipelineError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        UpdatePipelineError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UpdatePipelineError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2167,12 +2288,14 @@ impl fmt::Display for UpdatePipelineError {
             UpdatePipelineError::InternalService(ref cause) => write!(f, "{}", cause),
             UpdatePipelineError::ResourceInUse(ref cause) => write!(f, "{}", cause),
             UpdatePipelineError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            UpdatePipelineError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for UpdatePipelineError {}
 /// Errors returned by UpdatePipelineNotifications
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdatePipelineNotificationsError {
     /// <p>General authentication failure. The request was not signed correctly.</p>
     AccessDenied(String),
@@ -2184,6 +2307,8 @@ pub enum UpdatePipelineNotificationsError {
     ResourceInUse(String),
     /// <p>The requested resource does not exist or is not available. For example, the pipeline to which you're trying to add a job doesn't exist or is still being created.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdatePipelineNotificationsError {
@@ -2193,32 +2318,42 @@ impl UpdatePipelineNotificationsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(UpdatePipelineNotificationsError::AccessDenied(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdatePipelineNotificationsError::AccessDenied(err.msg),
+                        &res,
+                    )
                 }
                 "IncompatibleVersionException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         UpdatePipelineNotificationsError::IncompatibleVersion(err.msg),
+                        &res,
                     )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(UpdatePipelineNotificationsError::InternalService(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdatePipelineNotificationsError::InternalService(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(UpdatePipelineNotificationsError::ResourceInUse(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdatePipelineNotificationsError::ResourceInUse(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         UpdatePipelineNotificationsError::ResourceNotFound(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        UpdatePipelineNotificationsError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -2235,12 +2370,14 @@ impl fmt::Display for UpdatePipelineNotificationsError {
             UpdatePipelineNotificationsError::InternalService(ref cause) => write!(f, "{}", cause),
             UpdatePipelineNotificationsError::ResourceInUse(ref cause) => write!(f, "{}", cause),
             UpdatePipelineNotificationsError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            UpdatePipelineNotificationsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for UpdatePipelineNotificationsError {}
 /// Errors returned by UpdatePipelineStatus
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdatePipelineStatusError {
     /// <p>General authentication failure. The request was not signed correctly.</p>
     AccessDenied(String),
@@ -2252,6 +2389,8 @@ pub enum UpdatePipelineStatusError {
     ResourceInUse(String),
     /// <p>The requested resource does not exist or is not available. For example, the pipeline to which you're trying to add a job doesn't exist or is still being created.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdatePipelineStatusError {
@@ -2259,28 +2398,37 @@ impl UpdatePipelineStatusError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(UpdatePipelineStatusError::AccessDenied(err.msg))
+                    return RusotoError::service(
+                        UpdatePipelineStatusError::AccessDenied(err.msg),
+                        &res,
+                    )
                 }
                 "IncompatibleVersionException" => {
-                    return RusotoError::Service(UpdatePipelineStatusError::IncompatibleVersion(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdatePipelineStatusError::IncompatibleVersion(err.msg),
+                        &res,
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(UpdatePipelineStatusError::InternalService(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdatePipelineStatusError::InternalService(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(UpdatePipelineStatusError::ResourceInUse(err.msg))
+                    return RusotoError::service(
+                        UpdatePipelineStatusError::ResourceInUse(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdatePipelineStatusError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdatePipelineStatusError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UpdatePipelineStatusError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2295,6 +2443,7 @@ impl fmt::Display for UpdatePipelineStatusError {
             UpdatePipelineStatusError::InternalService(ref cause) => write!(f, "{}", cause),
             UpdatePipelineStatusError::ResourceInUse(ref cause) => write!(f, "{}", cause),
             UpdatePipelineStatusError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            UpdatePipelineStatusError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
@@ -2415,6 +2564,7 @@ impl EtsClient {
     /// Creates a client backed by the default tokio event loop.
     ///
     /// The client will use the default credentials provider and tls client.
+    #[cfg(not(target_arch = "wasm32"))]
     pub fn new(region: region::Region) -> EtsClient {
         EtsClient {
             client: Client::shared(),
@@ -2440,192 +2590,391 @@ impl EtsClient {
     pub fn new_with_client(client: Client, region: region::Region) -> EtsClient {
         EtsClient { client, region }
     }
+
+    /// Returns a builder of a client, setting any of its region, credentials provider,
+    /// dispatcher, retry policy, endpoint and timeouts.
+    pub fn builder() -> rusoto_core::ClientBuilder<EtsClient> {
+        rusoto_core::ClientBuilder::new(EtsClient::new_with_client)
+    }
+
+    /// Returns a clone of this client with the overrides of `config` applied to its
+    /// operations, sharing its connections.
+    pub fn customize(&self, config: &rusoto_core::OperationConfig) -> EtsClient {
+        EtsClient {
+            client: self.client.customize(config),
+            region: config.resolve_region(&self.region),
+        }
+    }
 }
 
-#[async_trait]
-impl Ets for EtsClient {
-    /// <p><p>The CancelJob operation cancels an unfinished job.</p> <note> <p>You can only cancel a job that has a status of <code>Submitted</code>. To prevent a pipeline from starting to process a job while you&#39;re getting the job identifier, use <a>UpdatePipelineStatus</a> to temporarily pause the pipeline.</p> </note></p>
-    async fn cancel_job(
+impl EtsClient {
+    /// Calls `cancel_job`, also returning the metadata of the response.
+    pub async fn cancel_job_with_metadata(
         &self,
         input: CancelJobRequest,
-    ) -> Result<CancelJobResponse, RusotoError<CancelJobError>> {
+    ) -> Result<(CancelJobResponse, ResponseMetadata), RusotoError<CancelJobError>> {
+        let request = self.build_cancel_job_request(input);
+
+        let mut response = self
+            .client
+            .sign_and_dispatch(request)
+            .await
+            .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
+        if response.status.as_u16() == 202 {
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<CancelJobResponse, _>()?;
+
+            Ok((result, metadata))
+        } else {
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(CancelJobError::from_response(response))
+        }
+    }
+
+    /// Presigns the request `cancel_job` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_cancel_job(
+        &self,
+        presigner: &rusoto_core::Presigner,
+        input: CancelJobRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_cancel_job_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_cancel_job_request(&self, input: CancelJobRequest) -> SignedRequest {
         let request_uri = format!("/2012-09-25/jobs/{id}", id = input.id);
 
         let mut request =
             SignedRequest::new("DELETE", "elastictranscoder", &self.region, &request_uri);
+        request.set_operation("CancelJob");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
+        request
+    }
+
+    /// Calls `create_job`, also returning the metadata of the response.
+    pub async fn create_job_with_metadata(
+        &self,
+        input: CreateJobRequest,
+    ) -> Result<(CreateJobResponse, ResponseMetadata), RusotoError<CreateJobError>> {
+        let request = self.build_create_job_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
-        if response.status.as_u16() == 202 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let metadata = ResponseMetadata::from_response(&response);
+        if response.status.as_u16() == 201 {
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<CancelJobResponse, _>()?;
+                .deserialize::<CreateJobResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(CancelJobError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(CreateJobError::from_response(response))
         }
     }
 
-    /// <p>When you create a job, Elastic Transcoder returns JSON data that includes the values that you specified plus information about the job that is created.</p> <p>If you have specified more than one output for your jobs (for example, one output for the Kindle Fire and another output for the Apple iPhone 4s), you currently must use the Elastic Transcoder API to list the jobs (as opposed to the AWS Console).</p>
-    async fn create_job(
+    /// Presigns the request `create_job` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_job(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: CreateJobRequest,
-    ) -> Result<CreateJobResponse, RusotoError<CreateJobError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_job_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_job_request(&self, input: CreateJobRequest) -> SignedRequest {
         let request_uri = "/2012-09-25/jobs";
 
         let mut request =
             SignedRequest::new("POST", "elastictranscoder", &self.region, &request_uri);
+        request.set_operation("CreateJob");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `create_pipeline`, also returning the metadata of the response.
+    pub async fn create_pipeline_with_metadata(
+        &self,
+        input: CreatePipelineRequest,
+    ) -> Result<(CreatePipelineResponse, ResponseMetadata), RusotoError<CreatePipelineError>> {
+        let request = self.build_create_pipeline_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 201 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<CreateJobResponse, _>()?;
+                .deserialize::<CreatePipelineResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(CreateJobError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(CreatePipelineError::from_response(response))
         }
     }
 
-    /// <p>The CreatePipeline operation creates a pipeline with settings that you specify.</p>
-    async fn create_pipeline(
+    /// Presigns the request `create_pipeline` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_pipeline(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: CreatePipelineRequest,
-    ) -> Result<CreatePipelineResponse, RusotoError<CreatePipelineError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_pipeline_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_pipeline_request(&self, input: CreatePipelineRequest) -> SignedRequest {
         let request_uri = "/2012-09-25/pipelines";
 
         let mut request =
             SignedRequest::new("POST", "elastictranscoder", &self.region, &request_uri);
+        request.set_operation("CreatePipeline");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `create_preset`, also returning the metadata of the response.
+    pub async fn create_preset_with_metadata(
+        &self,
+        input: CreatePresetRequest,
+    ) -> Result<(CreatePresetResponse, ResponseMetadata), RusotoError<CreatePresetError>> {
+        let request = self.build_create_preset_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 201 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<CreatePipelineResponse, _>()?;
+                .deserialize::<CreatePresetResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(CreatePipelineError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(CreatePresetError::from_response(response))
         }
     }
 
-    /// <p>The CreatePreset operation creates a preset with settings that you specify.</p> <important> <p>Elastic Transcoder checks the CreatePreset settings to ensure that they meet Elastic Transcoder requirements and to determine whether they comply with H.264 standards. If your settings are not valid for Elastic Transcoder, Elastic Transcoder returns an HTTP 400 response (<code>ValidationException</code>) and does not create the preset. If the settings are valid for Elastic Transcoder but aren't strictly compliant with the H.264 standard, Elastic Transcoder creates the preset and returns a warning message in the response. This helps you determine whether your settings comply with the H.264 standard while giving you greater flexibility with respect to the video that Elastic Transcoder produces.</p> </important> <p>Elastic Transcoder uses the H.264 video-compression format. For more information, see the International Telecommunication Union publication <i>Recommendation ITU-T H.264: Advanced video coding for generic audiovisual services</i>.</p>
-    async fn create_preset(
+    /// Presigns the request `create_preset` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_preset(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: CreatePresetRequest,
-    ) -> Result<CreatePresetResponse, RusotoError<CreatePresetError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_preset_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_preset_request(&self, input: CreatePresetRequest) -> SignedRequest {
         let request_uri = "/2012-09-25/presets";
 
         let mut request =
             SignedRequest::new("POST", "elastictranscoder", &self.region, &request_uri);
+        request.set_operation("CreatePreset");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `delete_pipeline`, also returning the metadata of the response.
+    pub async fn delete_pipeline_with_metadata(
+        &self,
+        input: DeletePipelineRequest,
+    ) -> Result<(DeletePipelineResponse, ResponseMetadata), RusotoError<DeletePipelineError>> {
+        let request = self.build_delete_pipeline_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
-        if response.status.as_u16() == 201 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let metadata = ResponseMetadata::from_response(&response);
+        if response.status.as_u16() == 202 {
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<CreatePresetResponse, _>()?;
+                .deserialize::<DeletePipelineResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(CreatePresetError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(DeletePipelineError::from_response(response))
         }
     }
 
-    /// <p>The DeletePipeline operation removes a pipeline.</p> <p> You can only delete a pipeline that has never been used or that is not currently in use (doesn't contain any active jobs). If the pipeline is currently in use, <code>DeletePipeline</code> returns an error. </p>
-    async fn delete_pipeline(
+    /// Presigns the request `delete_pipeline` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_pipeline(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeletePipelineRequest,
-    ) -> Result<DeletePipelineResponse, RusotoError<DeletePipelineError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_pipeline_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_pipeline_request(&self, input: DeletePipelineRequest) -> SignedRequest {
         let request_uri = format!("/2012-09-25/pipelines/{id}", id = input.id);
 
         let mut request =
             SignedRequest::new("DELETE", "elastictranscoder", &self.region, &request_uri);
+        request.set_operation("DeletePipeline");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
+        request
+    }
+
+    /// Calls `delete_preset`, also returning the metadata of the response.
+    pub async fn delete_preset_with_metadata(
+        &self,
+        input: DeletePresetRequest,
+    ) -> Result<(DeletePresetResponse, ResponseMetadata), RusotoError<DeletePresetError>> {
+        let request = self.build_delete_preset_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 202 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<DeletePipelineResponse, _>()?;
+                .deserialize::<DeletePresetResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(DeletePipelineError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(DeletePresetError::from_response(response))
         }
     }
 
-    /// <p><p>The DeletePreset operation removes a preset that you&#39;ve added in an AWS region.</p> <note> <p>You can&#39;t delete the default presets that are included with Elastic Transcoder.</p> </note></p>
-    async fn delete_preset(
+    /// Presigns the request `delete_preset` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_preset(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeletePresetRequest,
-    ) -> Result<DeletePresetResponse, RusotoError<DeletePresetError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_preset_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_preset_request(&self, input: DeletePresetRequest) -> SignedRequest {
         let request_uri = format!("/2012-09-25/presets/{id}", id = input.id);
 
         let mut request =
             SignedRequest::new("DELETE", "elastictranscoder", &self.region, &request_uri);
+        request.set_operation("DeletePreset");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
+        request
+    }
+
+    /// Calls `list_jobs_by_pipeline`, also returning the metadata of the response.
+    pub async fn list_jobs_by_pipeline_with_metadata(
+        &self,
+        input: ListJobsByPipelineRequest,
+    ) -> Result<(ListJobsByPipelineResponse, ResponseMetadata), RusotoError<ListJobsByPipelineError>>
+    {
+        let request = self.build_list_jobs_by_pipeline_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
-        if response.status.as_u16() == 202 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let metadata = ResponseMetadata::from_response(&response);
+        if response.status.is_success() {
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<DeletePresetResponse, _>()?;
+                .deserialize::<ListJobsByPipelineResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(DeletePresetError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(ListJobsByPipelineError::from_response(response))
         }
     }
 
-    /// <p>The ListJobsByPipeline operation gets a list of the jobs currently in a pipeline.</p> <p>Elastic Transcoder returns all of the jobs currently in the specified pipeline. The response body contains one element for each job that satisfies the search criteria.</p>
-    async fn list_jobs_by_pipeline(
+    /// Presigns the request `list_jobs_by_pipeline` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_jobs_by_pipeline(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListJobsByPipelineRequest,
-    ) -> Result<ListJobsByPipelineResponse, RusotoError<ListJobsByPipelineError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_jobs_by_pipeline_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_jobs_by_pipeline_request(
+        &self,
+        input: ListJobsByPipelineRequest,
+    ) -> SignedRequest {
         let request_uri = format!(
             "/2012-09-25/jobsByPipeline/{pipeline_id}",
             pipeline_id = input.pipeline_id
@@ -2633,6 +2982,7 @@ impl Ets for EtsClient {
 
         let mut request =
             SignedRequest::new("GET", "elastictranscoder", &self.region, &request_uri);
+        request.set_operation("ListJobsByPipeline");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -2644,32 +2994,57 @@ impl Ets for EtsClient {
         }
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `list_jobs_by_status`, also returning the metadata of the response.
+    pub async fn list_jobs_by_status_with_metadata(
+        &self,
+        input: ListJobsByStatusRequest,
+    ) -> Result<(ListJobsByStatusResponse, ResponseMetadata), RusotoError<ListJobsByStatusError>>
+    {
+        let request = self.build_list_jobs_by_status_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListJobsByPipelineResponse, _>()?;
+                .deserialize::<ListJobsByStatusResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(ListJobsByPipelineError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(ListJobsByStatusError::from_response(response))
         }
     }
 
-    /// <p>The ListJobsByStatus operation gets a list of jobs that have a specified status. The response body contains one element for each job that satisfies the search criteria.</p>
-    async fn list_jobs_by_status(
+    /// Presigns the request `list_jobs_by_status` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_jobs_by_status(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListJobsByStatusRequest,
-    ) -> Result<ListJobsByStatusResponse, RusotoError<ListJobsByStatusError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_jobs_by_status_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_jobs_by_status_request(&self, input: ListJobsByStatusRequest) -> SignedRequest {
         let request_uri = format!("/2012-09-25/jobsByStatus/{status}", status = input.status);
 
         let mut request =
             SignedRequest::new("GET", "elastictranscoder", &self.region, &request_uri);
+        request.set_operation("ListJobsByStatus");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -2681,32 +3056,56 @@ impl Ets for EtsClient {
         }
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `list_pipelines`, also returning the metadata of the response.
+    pub async fn list_pipelines_with_metadata(
+        &self,
+        input: ListPipelinesRequest,
+    ) -> Result<(ListPipelinesResponse, ResponseMetadata), RusotoError<ListPipelinesError>> {
+        let request = self.build_list_pipelines_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListJobsByStatusResponse, _>()?;
+                .deserialize::<ListPipelinesResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(ListJobsByStatusError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(ListPipelinesError::from_response(response))
         }
     }
 
-    /// <p>The ListPipelines operation gets a list of the pipelines associated with the current AWS account.</p>
-    async fn list_pipelines(
+    /// Presigns the request `list_pipelines` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_pipelines(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListPipelinesRequest,
-    ) -> Result<ListPipelinesResponse, RusotoError<ListPipelinesError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_pipelines_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_pipelines_request(&self, input: ListPipelinesRequest) -> SignedRequest {
         let request_uri = "/2012-09-25/pipelines";
 
         let mut request =
             SignedRequest::new("GET", "elastictranscoder", &self.region, &request_uri);
+        request.set_operation("ListPipelines");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -2718,32 +3117,56 @@ impl Ets for EtsClient {
         }
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `list_presets`, also returning the metadata of the response.
+    pub async fn list_presets_with_metadata(
+        &self,
+        input: ListPresetsRequest,
+    ) -> Result<(ListPresetsResponse, ResponseMetadata), RusotoError<ListPresetsError>> {
+        let request = self.build_list_presets_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListPipelinesResponse, _>()?;
+                .deserialize::<ListPresetsResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(ListPipelinesError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(ListPresetsError::from_response(response))
         }
     }
 
-    /// <p>The ListPresets operation gets a list of the default presets included with Elastic Transcoder and the presets that you've added in an AWS region.</p>
-    async fn list_presets(
+    /// Presigns the request `list_presets` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_presets(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListPresetsRequest,
-    ) -> Result<ListPresetsResponse, RusotoError<ListPresetsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_presets_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_presets_request(&self, input: ListPresetsRequest) -> SignedRequest {
         let request_uri = "/2012-09-25/presets";
 
         let mut request =
             SignedRequest::new("GET", "elastictranscoder", &self.region, &request_uri);
+        request.set_operation("ListPresets");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -2755,229 +3178,568 @@ impl Ets for EtsClient {
         }
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `read_job`, also returning the metadata of the response.
+    pub async fn read_job_with_metadata(
+        &self,
+        input: ReadJobRequest,
+    ) -> Result<(ReadJobResponse, ResponseMetadata), RusotoError<ReadJobError>> {
+        let request = self.build_read_job_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListPresetsResponse, _>()?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result =
+                proto::json::ResponsePayload::new(&response).deserialize::<ReadJobResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(ListPresetsError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(ReadJobError::from_response(response))
         }
     }
 
-    /// <p>The ReadJob operation returns detailed information about a job.</p>
-    async fn read_job(
+    /// Presigns the request `read_job` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_read_job(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ReadJobRequest,
-    ) -> Result<ReadJobResponse, RusotoError<ReadJobError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_read_job_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_read_job_request(&self, input: ReadJobRequest) -> SignedRequest {
         let request_uri = format!("/2012-09-25/jobs/{id}", id = input.id);
 
         let mut request =
             SignedRequest::new("GET", "elastictranscoder", &self.region, &request_uri);
+        request.set_operation("ReadJob");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
+        request
+    }
+
+    /// Calls `read_pipeline`, also returning the metadata of the response.
+    pub async fn read_pipeline_with_metadata(
+        &self,
+        input: ReadPipelineRequest,
+    ) -> Result<(ReadPipelineResponse, ResponseMetadata), RusotoError<ReadPipelineError>> {
+        let request = self.build_read_pipeline_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            let result =
-                proto::json::ResponsePayload::new(&response).deserialize::<ReadJobResponse, _>()?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ReadPipelineResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(ReadJobError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(ReadPipelineError::from_response(response))
         }
     }
 
-    /// <p>The ReadPipeline operation gets detailed information about a pipeline.</p>
-    async fn read_pipeline(
+    /// Presigns the request `read_pipeline` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_read_pipeline(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ReadPipelineRequest,
-    ) -> Result<ReadPipelineResponse, RusotoError<ReadPipelineError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_read_pipeline_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_read_pipeline_request(&self, input: ReadPipelineRequest) -> SignedRequest {
         let request_uri = format!("/2012-09-25/pipelines/{id}", id = input.id);
 
         let mut request =
             SignedRequest::new("GET", "elastictranscoder", &self.region, &request_uri);
+        request.set_operation("ReadPipeline");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
+        request
+    }
+
+    /// Calls `read_preset`, also returning the metadata of the response.
+    pub async fn read_preset_with_metadata(
+        &self,
+        input: ReadPresetRequest,
+    ) -> Result<(ReadPresetResponse, ResponseMetadata), RusotoError<ReadPresetError>> {
+        let request = self.build_read_preset_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<ReadPipelineResponse, _>()?;
+                .deserialize::<ReadPresetResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(ReadPipelineError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(ReadPresetError::from_response(response))
         }
     }
 
-    /// <p>The ReadPreset operation gets detailed information about a preset.</p>
-    async fn read_preset(
+    /// Presigns the request `read_preset` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_read_preset(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ReadPresetRequest,
-    ) -> Result<ReadPresetResponse, RusotoError<ReadPresetError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_read_preset_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_read_preset_request(&self, input: ReadPresetRequest) -> SignedRequest {
         let request_uri = format!("/2012-09-25/presets/{id}", id = input.id);
 
         let mut request =
             SignedRequest::new("GET", "elastictranscoder", &self.region, &request_uri);
+        request.set_operation("ReadPreset");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
+        request
+    }
+
+    /// Calls `test_role`, also returning the metadata of the response.
+    pub async fn test_role_with_metadata(
+        &self,
+        input: TestRoleRequest,
+    ) -> Result<(TestRoleResponse, ResponseMetadata), RusotoError<TestRoleError>> {
+        let request = self.build_test_role_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
-        if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let metadata = ResponseMetadata::from_response(&response);
+        if response.status.as_u16() == 200 {
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<ReadPresetResponse, _>()?;
+                .deserialize::<TestRoleResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(ReadPresetError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(TestRoleError::from_response(response))
         }
     }
 
-    /// <p>The TestRole operation tests the IAM role used to create the pipeline.</p> <p>The <code>TestRole</code> action lets you determine whether the IAM role you are using has sufficient permissions to let Elastic Transcoder perform tasks associated with the transcoding process. The action attempts to assume the specified IAM role, checks read access to the input and output buckets, and tries to send a test notification to Amazon SNS topics that you specify.</p>
-    async fn test_role(
+    /// Presigns the request `test_role` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_test_role(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: TestRoleRequest,
-    ) -> Result<TestRoleResponse, RusotoError<TestRoleError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_test_role_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_test_role_request(&self, input: TestRoleRequest) -> SignedRequest {
         let request_uri = "/2012-09-25/roleTests";
 
         let mut request =
             SignedRequest::new("POST", "elastictranscoder", &self.region, &request_uri);
+        request.set_operation("TestRole");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `update_pipeline`, also returning the metadata of the response.
+    pub async fn update_pipeline_with_metadata(
+        &self,
+        input: UpdatePipelineRequest,
+    ) -> Result<(UpdatePipelineResponse, ResponseMetadata), RusotoError<UpdatePipelineError>> {
+        let request = self.build_update_pipeline_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<TestRoleResponse, _>()?;
+                .deserialize::<UpdatePipelineResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(TestRoleError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(UpdatePipelineError::from_response(response))
         }
     }
 
-    /// <p><p> Use the <code>UpdatePipeline</code> operation to update settings for a pipeline.</p> <important> <p>When you change pipeline settings, your changes take effect immediately. Jobs that you have already submitted and that Elastic Transcoder has not started to process are affected in addition to jobs that you submit after you change settings. </p> </important></p>
-    async fn update_pipeline(
+    /// Presigns the request `update_pipeline` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_pipeline(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UpdatePipelineRequest,
-    ) -> Result<UpdatePipelineResponse, RusotoError<UpdatePipelineError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_pipeline_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_pipeline_request(&self, input: UpdatePipelineRequest) -> SignedRequest {
         let request_uri = format!("/2012-09-25/pipelines/{id}", id = input.id);
 
         let mut request =
             SignedRequest::new("PUT", "elastictranscoder", &self.region, &request_uri);
+        request.set_operation("UpdatePipeline");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `update_pipeline_notifications`, also returning the metadata of the response.
+    pub async fn update_pipeline_notifications_with_metadata(
+        &self,
+        input: UpdatePipelineNotificationsRequest,
+    ) -> Result<
+        (UpdatePipelineNotificationsResponse, ResponseMetadata),
+        RusotoError<UpdatePipelineNotificationsError>,
+    > {
+        let request = self.build_update_pipeline_notifications_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
-        if response.status.as_u16() == 200 {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let metadata = ResponseMetadata::from_response(&response);
+        if response.status.is_success() {
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<UpdatePipelineResponse, _>()?;
+                .deserialize::<UpdatePipelineNotificationsResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(UpdatePipelineError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(UpdatePipelineNotificationsError::from_response(response))
         }
     }
 
-    /// <p>With the UpdatePipelineNotifications operation, you can update Amazon Simple Notification Service (Amazon SNS) notifications for a pipeline.</p> <p>When you update notifications for a pipeline, Elastic Transcoder returns the values that you specified in the request.</p>
-    async fn update_pipeline_notifications(
+    /// Presigns the request `update_pipeline_notifications` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_pipeline_notifications(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UpdatePipelineNotificationsRequest,
-    ) -> Result<UpdatePipelineNotificationsResponse, RusotoError<UpdatePipelineNotificationsError>>
-    {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_pipeline_notifications_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_pipeline_notifications_request(
+        &self,
+        input: UpdatePipelineNotificationsRequest,
+    ) -> SignedRequest {
         let request_uri = format!("/2012-09-25/pipelines/{id}/notifications", id = input.id);
 
         let mut request =
             SignedRequest::new("POST", "elastictranscoder", &self.region, &request_uri);
+        request.set_operation("UpdatePipelineNotifications");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `update_pipeline_status`, also returning the metadata of the response.
+    pub async fn update_pipeline_status_with_metadata(
+        &self,
+        input: UpdatePipelineStatusRequest,
+    ) -> Result<
+        (UpdatePipelineStatusResponse, ResponseMetadata),
+        RusotoError<UpdatePipelineStatusError>,
+    > {
+        let request = self.build_update_pipeline_status_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<UpdatePipelineNotificationsResponse, _>()?;
+                .deserialize::<UpdatePipelineStatusResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(UpdatePipelineNotificationsError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(UpdatePipelineStatusError::from_response(response))
         }
     }
 
-    /// <p>The UpdatePipelineStatus operation pauses or reactivates a pipeline, so that the pipeline stops or restarts the processing of jobs.</p> <p>Changing the pipeline status is useful if you want to cancel one or more jobs. You can't cancel jobs after Elastic Transcoder has started processing them; if you pause the pipeline to which you submitted the jobs, you have more time to get the job IDs for the jobs that you want to cancel, and to send a <a>CancelJob</a> request. </p>
-    async fn update_pipeline_status(
+    /// Presigns the request `update_pipeline_status` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_pipeline_status(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UpdatePipelineStatusRequest,
-    ) -> Result<UpdatePipelineStatusResponse, RusotoError<UpdatePipelineStatusError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_pipeline_status_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_pipeline_status_request(
+        &self,
+        input: UpdatePipelineStatusRequest,
+    ) -> SignedRequest {
         let request_uri = format!("/2012-09-25/pipelines/{id}/status", id = input.id);
 
         let mut request =
             SignedRequest::new("POST", "elastictranscoder", &self.region, &request_uri);
+        request.set_operation("UpdatePipelineStatus");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
-        let mut response = self
-            .client
-            .sign_and_dispatch(request)
+        request
+    }
+}
+
+#[async_trait]
+impl Ets for EtsClient {
+    /// <p><p>The CancelJob operation cancels an unfinished job.</p> <note> <p>You can only cancel a job that has a status of <code>Submitted</code>. To prevent a pipeline from starting to process a job while you&#39;re getting the job identifier, use <a>UpdatePipelineStatus</a> to temporarily pause the pipeline.</p> </note></p>
+    async fn cancel_job(
+        &self,
+        input: CancelJobRequest,
+    ) -> Result<CancelJobResponse, RusotoError<CancelJobError>> {
+        self.cancel_job_with_metadata(input)
             .await
-            .map_err(RusotoError::from)?;
-        if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<UpdatePipelineStatusResponse, _>()?;
+            .map(|(output, _)| output)
+    }
 
-            Ok(result)
-        } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(UpdatePipelineStatusError::from_response(response))
-        }
+    /// <p>When you create a job, Elastic Transcoder returns JSON data that includes the values that you specified plus information about the job that is created.</p> <p>If you have specified more than one output for your jobs (for example, one output for the Kindle Fire and another output for the Apple iPhone 4s), you currently must use the Elastic Transcoder API to list the jobs (as opposed to the AWS Console).</p>
+    async fn create_job(
+        &self,
+        input: CreateJobRequest,
+    ) -> Result<CreateJobResponse, RusotoError<CreateJobError>> {
+        self.create_job_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>The CreatePipeline operation creates a pipeline with settings that you specify.</p>
+    async fn create_pipeline(
+        &self,
+        input: CreatePipelineRequest,
+    ) -> Result<CreatePipelineResponse, RusotoError<CreatePipelineError>> {
+        self.create_pipeline_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>The CreatePreset operation creates a preset with settings that you specify.</p> <important> <p>Elastic Transcoder checks the CreatePreset settings to ensure that they meet Elastic Transcoder requirements and to determine whether they comply with H.264 standards. If your settings are not valid for Elastic Transcoder, Elastic Transcoder returns an HTTP 400 response (<code>ValidationException</code>) and does not create the preset. If the settings are valid for Elastic Transcoder but aren't strictly compliant with the H.264 standard, Elastic Transcoder creates the preset and returns a warning message in the response. This helps you determine whether your settings comply with the H.264 standard while giving you greater flexibility with respect to the video that Elastic Transcoder produces.</p> </important> <p>Elastic Transcoder uses the H.264 video-compression format. For more information, see the International Telecommunication Union publication <i>Recommendation ITU-T H.264: Advanced video coding for generic audiovisual services</i>.</p>
+    async fn create_preset(
+        &self,
+        input: CreatePresetRequest,
+    ) -> Result<CreatePresetResponse, RusotoError<CreatePresetError>> {
+        self.create_preset_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>The DeletePipeline operation removes a pipeline.</p> <p> You can only delete a pipeline that has never been used or that is not currently in use (doesn't contain any active jobs). If the pipeline is currently in use, <code>DeletePipeline</code> returns an error. </p>
+    async fn delete_pipeline(
+        &self,
+        input: DeletePipelineRequest,
+    ) -> Result<DeletePipelineResponse, RusotoError<DeletePipelineError>> {
+        self.delete_pipeline_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>The DeletePreset operation removes a preset that you&#39;ve added in an AWS region.</p> <note> <p>You can&#39;t delete the default presets that are included with Elastic Transcoder.</p> </note></p>
+    async fn delete_preset(
+        &self,
+        input: DeletePresetRequest,
+    ) -> Result<DeletePresetResponse, RusotoError<DeletePresetError>> {
+        self.delete_preset_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>The ListJobsByPipeline operation gets a list of the jobs currently in a pipeline.</p> <p>Elastic Transcoder returns all of the jobs currently in the specified pipeline. The response body contains one element for each job that satisfies the search criteria.</p>
+    async fn list_jobs_by_pipeline(
+        &self,
+        input: ListJobsByPipelineRequest,
+    ) -> Result<ListJobsByPipelineResponse, RusotoError<ListJobsByPipelineError>> {
+        self.list_jobs_by_pipeline_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>The ListJobsByStatus operation gets a list of jobs that have a specified status. The response body contains one element for each job that satisfies the search criteria.</p>
+    async fn list_jobs_by_status(
+        &self,
+        input: ListJobsByStatusRequest,
+    ) -> Result<ListJobsByStatusResponse, RusotoError<ListJobsByStatusError>> {
+        self.list_jobs_by_status_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>The ListPipelines operation gets a list of the pipelines associated with the current AWS account.</p>
+    async fn list_pipelines(
+        &self,
+        input: ListPipelinesRequest,
+    ) -> Result<ListPipelinesResponse, RusotoError<ListPipelinesError>> {
+        self.list_pipelines_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>The ListPresets operation gets a list of the default presets included with Elastic Transcoder and the presets that you've added in an AWS region.</p>
+    async fn list_presets(
+        &self,
+        input: ListPresetsRequest,
+    ) -> Result<ListPresetsResponse, RusotoError<ListPresetsError>> {
+        self.list_presets_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>The ReadJob operation returns detailed information about a job.</p>
+    async fn read_job(
+        &self,
+        input: ReadJobRequest,
+    ) -> Result<ReadJobResponse, RusotoError<ReadJobError>> {
+        self.read_job_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>The ReadPipeline operation gets detailed information about a pipeline.</p>
+    async fn read_pipeline(
+        &self,
+        input: ReadPipelineRequest,
+    ) -> Result<ReadPipelineResponse, RusotoError<ReadPipelineError>> {
+        self.read_pipeline_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>The ReadPreset operation gets detailed information about a preset.</p>
+    async fn read_preset(
+        &self,
+        input: ReadPresetRequest,
+    ) -> Result<ReadPresetResponse, RusotoError<ReadPresetError>> {
+        self.read_preset_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>The TestRole operation tests the IAM role used to create the pipeline.</p> <p>The <code>TestRole</code> action lets you determine whether the IAM role you are using has sufficient permissions to let Elastic Transcoder perform tasks associated with the transcoding process. The action attempts to assume the specified IAM role, checks read access to the input and output buckets, and tries to send a test notification to Amazon SNS topics that you specify.</p>
+    async fn test_role(
+        &self,
+        input: TestRoleRequest,
+    ) -> Result<TestRoleResponse, RusotoError<TestRoleError>> {
+        self.test_role_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p> Use the <code>UpdatePipeline</code> operation to update settings for a pipeline.</p> <important> <p>When you change pipeline settings, your changes take effect immediately. Jobs that you have already submitted and that Elastic Transcoder has not started to process are affected in addition to jobs that you submit after you change settings. </p> </important></p>
+    async fn update_pipeline(
+        &self,
+        input: UpdatePipelineRequest,
+    ) -> Result<UpdatePipelineResponse, RusotoError<UpdatePipelineError>> {
+        self.update_pipeline_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>With the UpdatePipelineNotifications operation, you can update Amazon Simple Notification Service (Amazon SNS) notifications for a pipeline.</p> <p>When you update notifications for a pipeline, Elastic Transcoder returns the values that you specified in the request.</p>
+    async fn update_pipeline_notifications(
+        &self,
+        input: UpdatePipelineNotificationsRequest,
+    ) -> Result<UpdatePipelineNotificationsResponse, RusotoError<UpdatePipelineNotificationsError>>
+    {
+        self.update_pipeline_notifications_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>The UpdatePipelineStatus operation pauses or reactivates a pipeline, so that the pipeline stops or restarts the processing of jobs.</p> <p>Changing the pipeline status is useful if you want to cancel one or more jobs. You can't cancel jobs after Elastic Transcoder has started processing them; if you pause the pipeline to which you submitted the jobs, you have more time to get the job IDs for the jobs that you want to cancel, and to send a <a>CancelJob</a> request. </p>
+    async fn update_pipeline_status(
+        &self,
+        input: UpdatePipelineStatusRequest,
+    ) -> Result<UpdatePipelineStatusResponse, RusotoError<UpdatePipelineStatusError>> {
+        self.update_pipeline_status_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
     }
 }
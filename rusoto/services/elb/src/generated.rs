@@ -16,8 +16,8 @@ use std::fmt;
 use async_trait::async_trait;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
-use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest, ResponseMetadata};
+use rusoto_core::{Client, ErrorMetadata, RusotoError};
 
 use rusoto_core::param::{Params, ServiceParams};
 use rusoto_core::proto::xml::error::*;
@@ -4153,6 +4153,7 @@ impl VPCIdDeserializer {
 }
 /// Errors returned by AddTags
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum AddTagsError {
     /// <p>The specified load balancer does not exist.</p>
     AccessPointNotFound(String),
@@ -4160,6 +4161,8 @@ pub enum AddTagsError {
     DuplicateTagKeys(String),
     /// <p>The quota for the number of tags that can be assigned to a load balancer has been reached.</p>
     TooManyTags(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl AddTagsError {
@@ -4171,21 +4174,24 @@ impl AddTagsError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "LoadBalancerNotFound" => {
-                        return RusotoError::Service(AddTagsError::AccessPointNotFound(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            AddTagsError::AccessPointNotFound(parsed_error.message),
+                            &res,
+                        )
                     }
                     "DuplicateTagKeys" => {
-                        return RusotoError::Service(AddTagsError::DuplicateTagKeys(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            AddTagsError::DuplicateTagKeys(parsed_error.message),
+                            &res,
+                        )
                     }
                     "TooManyTags" => {
-                        return RusotoError::Service(AddTagsError::TooManyTags(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            AddTagsError::TooManyTags(parsed_error.message),
+                            &res,
+                        )
                     }
-                    _ => {}
+                    _ => return RusotoError::unhandled(AddTagsError::Unhandled, &res),
                 }
             }
         }
@@ -4207,12 +4213,14 @@ impl fmt::Display for AddTagsError {
             AddTagsError::AccessPointNotFound(ref cause) => write!(f, "{}", cause),
             AddTagsError::DuplicateTagKeys(ref cause) => write!(f, "{}", cause),
             AddTagsError::TooManyTags(ref cause) => write!(f, "{}", cause),
+            AddTagsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for AddTagsError {}
 /// Errors returned by ApplySecurityGroupsToLoadBalancer
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ApplySecurityGroupsToLoadBalancerError {
     /// <p>The specified load balancer does not exist.</p>
     AccessPointNotFound(String),
@@ -4220,6 +4228,8 @@ pub enum ApplySecurityGroupsToLoadBalancerError {
     InvalidConfigurationRequest(String),
     /// <p>One or more of the specified security groups do not exist.</p>
     InvalidSecurityGroup(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ApplySecurityGroupsToLoadBalancerError {
@@ -4233,27 +4243,35 @@ impl ApplySecurityGroupsToLoadBalancerError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "LoadBalancerNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             ApplySecurityGroupsToLoadBalancerError::AccessPointNotFound(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "InvalidConfigurationRequest" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             ApplySecurityGroupsToLoadBalancerError::InvalidConfigurationRequest(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "InvalidSecurityGroup" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             ApplySecurityGroupsToLoadBalancerError::InvalidSecurityGroup(
                                 parsed_error.message,
                             ),
+                            &res,
+                        )
+                    }
+                    _ => {
+                        return RusotoError::unhandled(
+                            ApplySecurityGroupsToLoadBalancerError::Unhandled,
+                            &res,
                         )
                     }
-                    _ => {}
                 }
             }
         }
@@ -4281,12 +4299,16 @@ impl fmt::Display for ApplySecurityGroupsToLoadBalancerError {
             ApplySecurityGroupsToLoadBalancerError::InvalidSecurityGroup(ref cause) => {
                 write!(f, "{}", cause)
             }
+            ApplySecurityGroupsToLoadBalancerError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for ApplySecurityGroupsToLoadBalancerError {}
 /// Errors returned by AttachLoadBalancerToSubnets
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum AttachLoadBalancerToSubnetsError {
     /// <p>The specified load balancer does not exist.</p>
     AccessPointNotFound(String),
@@ -4296,6 +4318,8 @@ pub enum AttachLoadBalancerToSubnetsError {
     InvalidSubnet(String),
     /// <p>One or more of the specified subnets do not exist.</p>
     SubnetNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl AttachLoadBalancerToSubnetsError {
@@ -4309,30 +4333,39 @@ impl AttachLoadBalancerToSubnetsError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "LoadBalancerNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             AttachLoadBalancerToSubnetsError::AccessPointNotFound(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "InvalidConfigurationRequest" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             AttachLoadBalancerToSubnetsError::InvalidConfigurationRequest(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "InvalidSubnet" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             AttachLoadBalancerToSubnetsError::InvalidSubnet(parsed_error.message),
+                            &res,
                         )
                     }
                     "SubnetNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             AttachLoadBalancerToSubnetsError::SubnetNotFound(parsed_error.message),
+                            &res,
+                        )
+                    }
+                    _ => {
+                        return RusotoError::unhandled(
+                            AttachLoadBalancerToSubnetsError::Unhandled,
+                            &res,
                         )
                     }
-                    _ => {}
                 }
             }
         }
@@ -4359,15 +4392,19 @@ impl fmt::Display for AttachLoadBalancerToSubnetsError {
             }
             AttachLoadBalancerToSubnetsError::InvalidSubnet(ref cause) => write!(f, "{}", cause),
             AttachLoadBalancerToSubnetsError::SubnetNotFound(ref cause) => write!(f, "{}", cause),
+            AttachLoadBalancerToSubnetsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for AttachLoadBalancerToSubnetsError {}
 /// Errors returned by ConfigureHealthCheck
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ConfigureHealthCheckError {
     /// <p>The specified load balancer does not exist.</p>
     AccessPointNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ConfigureHealthCheckError {
@@ -4379,11 +4416,12 @@ impl ConfigureHealthCheckError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "LoadBalancerNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             ConfigureHealthCheckError::AccessPointNotFound(parsed_error.message),
+                            &res,
                         )
                     }
-                    _ => {}
+                    _ => return RusotoError::unhandled(ConfigureHealthCheckError::Unhandled, &res),
                 }
             }
         }
@@ -4403,12 +4441,14 @@ impl fmt::Display for ConfigureHealthCheckError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             ConfigureHealthCheckError::AccessPointNotFound(ref cause) => write!(f, "{}", cause),
+            ConfigureHealthCheckError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ConfigureHealthCheckError {}
 /// Errors returned by CreateAppCookieStickinessPolicy
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateAppCookieStickinessPolicyError {
     /// <p>The specified load balancer does not exist.</p>
     AccessPointNotFound(String),
@@ -4418,6 +4458,8 @@ pub enum CreateAppCookieStickinessPolicyError {
     InvalidConfigurationRequest(String),
     /// <p>The quota for the number of policies for this load balancer has been reached.</p>
     TooManyPolicies(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateAppCookieStickinessPolicyError {
@@ -4431,34 +4473,43 @@ impl CreateAppCookieStickinessPolicyError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "LoadBalancerNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             CreateAppCookieStickinessPolicyError::AccessPointNotFound(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "DuplicatePolicyName" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             CreateAppCookieStickinessPolicyError::DuplicatePolicyName(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "InvalidConfigurationRequest" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             CreateAppCookieStickinessPolicyError::InvalidConfigurationRequest(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "TooManyPolicies" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             CreateAppCookieStickinessPolicyError::TooManyPolicies(
                                 parsed_error.message,
                             ),
+                            &res,
+                        )
+                    }
+                    _ => {
+                        return RusotoError::unhandled(
+                            CreateAppCookieStickinessPolicyError::Unhandled,
+                            &res,
                         )
                     }
-                    _ => {}
                 }
             }
         }
@@ -4489,12 +4540,16 @@ impl fmt::Display for CreateAppCookieStickinessPolicyError {
             CreateAppCookieStickinessPolicyError::TooManyPolicies(ref cause) => {
                 write!(f, "{}", cause)
             }
+            CreateAppCookieStickinessPolicyError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for CreateAppCookieStickinessPolicyError {}
 /// Errors returned by CreateLBCookieStickinessPolicy
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateLBCookieStickinessPolicyError {
     /// <p>The specified load balancer does not exist.</p>
     AccessPointNotFound(String),
@@ -4504,6 +4559,8 @@ pub enum CreateLBCookieStickinessPolicyError {
     InvalidConfigurationRequest(String),
     /// <p>The quota for the number of policies for this load balancer has been reached.</p>
     TooManyPolicies(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateLBCookieStickinessPolicyError {
@@ -4517,34 +4574,43 @@ impl CreateLBCookieStickinessPolicyError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "LoadBalancerNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             CreateLBCookieStickinessPolicyError::AccessPointNotFound(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "DuplicatePolicyName" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             CreateLBCookieStickinessPolicyError::DuplicatePolicyName(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "InvalidConfigurationRequest" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             CreateLBCookieStickinessPolicyError::InvalidConfigurationRequest(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "TooManyPolicies" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             CreateLBCookieStickinessPolicyError::TooManyPolicies(
                                 parsed_error.message,
                             ),
+                            &res,
+                        )
+                    }
+                    _ => {
+                        return RusotoError::unhandled(
+                            CreateLBCookieStickinessPolicyError::Unhandled,
+                            &res,
                         )
                     }
-                    _ => {}
                 }
             }
         }
@@ -4575,12 +4641,16 @@ impl fmt::Display for CreateLBCookieStickinessPolicyError {
             CreateLBCookieStickinessPolicyError::TooManyPolicies(ref cause) => {
                 write!(f, "{}", cause)
             }
+            CreateLBCookieStickinessPolicyError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for CreateLBCookieStickinessPolicyError {}
 /// Errors returned by CreateLoadBalancer
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateLoadBalancerError {
     /// <p>The specified ARN does not refer to a valid SSL certificate in AWS Identity and Access Management (IAM) or AWS Certificate Manager (ACM). Note that if you recently uploaded the certificate to IAM, this error might indicate that the certificate is not fully available yet.</p>
     CertificateNotFound(String),
@@ -4606,6 +4676,8 @@ pub enum CreateLoadBalancerError {
     TooManyTags(String),
     /// <p>The specified protocol or signature version is not supported.</p>
     UnsupportedProtocol(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateLoadBalancerError {
@@ -4617,68 +4689,80 @@ impl CreateLoadBalancerError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "CertificateNotFound" => {
-                        return RusotoError::Service(CreateLoadBalancerError::CertificateNotFound(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            CreateLoadBalancerError::CertificateNotFound(parsed_error.message),
+                            &res,
+                        )
                     }
                     "DuplicateLoadBalancerName" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             CreateLoadBalancerError::DuplicateAccessPointName(parsed_error.message),
+                            &res,
                         )
                     }
                     "DuplicateTagKeys" => {
-                        return RusotoError::Service(CreateLoadBalancerError::DuplicateTagKeys(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            CreateLoadBalancerError::DuplicateTagKeys(parsed_error.message),
+                            &res,
+                        )
                     }
                     "InvalidConfigurationRequest" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             CreateLoadBalancerError::InvalidConfigurationRequest(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "InvalidScheme" => {
-                        return RusotoError::Service(CreateLoadBalancerError::InvalidScheme(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            CreateLoadBalancerError::InvalidScheme(parsed_error.message),
+                            &res,
+                        )
                     }
                     "InvalidSecurityGroup" => {
-                        return RusotoError::Service(CreateLoadBalancerError::InvalidSecurityGroup(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            CreateLoadBalancerError::InvalidSecurityGroup(parsed_error.message),
+                            &res,
+                        )
                     }
                     "InvalidSubnet" => {
-                        return RusotoError::Service(CreateLoadBalancerError::InvalidSubnet(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            CreateLoadBalancerError::InvalidSubnet(parsed_error.message),
+                            &res,
+                        )
                     }
                     "OperationNotPermitted" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             CreateLoadBalancerError::OperationNotPermitted(parsed_error.message),
+                            &res,
                         )
                     }
                     "SubnetNotFound" => {
-                        return RusotoError::Service(CreateLoadBalancerError::SubnetNotFound(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            CreateLoadBalancerError::SubnetNotFound(parsed_error.message),
+                            &res,
+                        )
                     }
                     "TooManyLoadBalancers" => {
-                        return RusotoError::Service(CreateLoadBalancerError::TooManyAccessPoints(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            CreateLoadBalancerError::TooManyAccessPoints(parsed_error.message),
+                            &res,
+                        )
                     }
                     "TooManyTags" => {
-                        return RusotoError::Service(CreateLoadBalancerError::TooManyTags(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            CreateLoadBalancerError::TooManyTags(parsed_error.message),
+                            &res,
+                        )
                     }
                     "UnsupportedProtocol" => {
-                        return RusotoError::Service(CreateLoadBalancerError::UnsupportedProtocol(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            CreateLoadBalancerError::UnsupportedProtocol(parsed_error.message),
+                            &res,
+                        )
                     }
-                    _ => {}
+                    _ => return RusotoError::unhandled(CreateLoadBalancerError::Unhandled, &res),
                 }
             }
         }
@@ -4711,12 +4795,14 @@ impl fmt::Display for CreateLoadBalancerError {
             CreateLoadBalancerError::TooManyAccessPoints(ref cause) => write!(f, "{}", cause),
             CreateLoadBalancerError::TooManyTags(ref cause) => write!(f, "{}", cause),
             CreateLoadBalancerError::UnsupportedProtocol(ref cause) => write!(f, "{}", cause),
+            CreateLoadBalancerError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateLoadBalancerError {}
 /// Errors returned by CreateLoadBalancerListeners
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateLoadBalancerListenersError {
     /// <p>The specified load balancer does not exist.</p>
     AccessPointNotFound(String),
@@ -4728,6 +4814,8 @@ pub enum CreateLoadBalancerListenersError {
     InvalidConfigurationRequest(String),
     /// <p>The specified protocol or signature version is not supported.</p>
     UnsupportedProtocol(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateLoadBalancerListenersError {
@@ -4741,41 +4829,51 @@ impl CreateLoadBalancerListenersError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "LoadBalancerNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             CreateLoadBalancerListenersError::AccessPointNotFound(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "CertificateNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             CreateLoadBalancerListenersError::CertificateNotFound(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "DuplicateListener" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             CreateLoadBalancerListenersError::DuplicateListener(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "InvalidConfigurationRequest" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             CreateLoadBalancerListenersError::InvalidConfigurationRequest(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "UnsupportedProtocol" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             CreateLoadBalancerListenersError::UnsupportedProtocol(
                                 parsed_error.message,
                             ),
+                            &res,
+                        )
+                    }
+                    _ => {
+                        return RusotoError::unhandled(
+                            CreateLoadBalancerListenersError::Unhandled,
+                            &res,
                         )
                     }
-                    _ => {}
                 }
             }
         }
@@ -4809,12 +4907,14 @@ impl fmt::Display for CreateLoadBalancerListenersError {
             CreateLoadBalancerListenersError::UnsupportedProtocol(ref cause) => {
                 write!(f, "{}", cause)
             }
+            CreateLoadBalancerListenersError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateLoadBalancerListenersError {}
 /// Errors returned by CreateLoadBalancerPolicy
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateLoadBalancerPolicyError {
     /// <p>The specified load balancer does not exist.</p>
     AccessPointNotFound(String),
@@ -4826,6 +4926,8 @@ pub enum CreateLoadBalancerPolicyError {
     PolicyTypeNotFound(String),
     /// <p>The quota for the number of policies for this load balancer has been reached.</p>
     TooManyPolicies(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateLoadBalancerPolicyError {
@@ -4837,37 +4939,47 @@ impl CreateLoadBalancerPolicyError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "LoadBalancerNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             CreateLoadBalancerPolicyError::AccessPointNotFound(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "DuplicatePolicyName" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             CreateLoadBalancerPolicyError::DuplicatePolicyName(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "InvalidConfigurationRequest" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             CreateLoadBalancerPolicyError::InvalidConfigurationRequest(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "PolicyTypeNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             CreateLoadBalancerPolicyError::PolicyTypeNotFound(parsed_error.message),
+                            &res,
                         )
                     }
                     "TooManyPolicies" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             CreateLoadBalancerPolicyError::TooManyPolicies(parsed_error.message),
+                            &res,
+                        )
+                    }
+                    _ => {
+                        return RusotoError::unhandled(
+                            CreateLoadBalancerPolicyError::Unhandled,
+                            &res,
                         )
                     }
-                    _ => {}
                 }
             }
         }
@@ -4893,13 +5005,18 @@ impl fmt::Display for CreateLoadBalancerPolicyError {
             }
             CreateLoadBalancerPolicyError::PolicyTypeNotFound(ref cause) => write!(f, "{}", cause),
             CreateLoadBalancerPolicyError::TooManyPolicies(ref cause) => write!(f, "{}", cause),
+            CreateLoadBalancerPolicyError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateLoadBalancerPolicyError {}
 /// Errors returned by DeleteLoadBalancer
 #[derive(Debug, PartialEq)]
-pub enum DeleteLoadBalancerError {}
+#[non_exhaustive]
+pub enum DeleteLoadBalancerError {
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
+}
 
 impl DeleteLoadBalancerError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<DeleteLoadBalancerError> {
@@ -4907,10 +5024,8 @@ impl DeleteLoadBalancerError {
             let reader = EventReader::new(res.body.as_ref());
             let mut stack = XmlResponse::new(reader.into_iter().peekable());
             find_start_element(&mut stack);
-            if let Ok(parsed_error) = Self::deserialize(&mut stack) {
-                match &parsed_error.code[..] {
-                    _ => {}
-                }
+            if Self::deserialize(&mut stack).is_ok() {
+                return RusotoError::unhandled(DeleteLoadBalancerError::Unhandled, &res);
             }
         }
         RusotoError::Unknown(res)
@@ -4927,15 +5042,20 @@ impl DeleteLoadBalancerError {
 impl fmt::Display for DeleteLoadBalancerError {
     #[allow(unused_variables)]
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        match *self {}
+        match *self {
+            DeleteLoadBalancerError::Unhandled(ref metadata) => write!(f, "{}", metadata),
+        }
     }
 }
 impl Error for DeleteLoadBalancerError {}
 /// Errors returned by DeleteLoadBalancerListeners
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteLoadBalancerListenersError {
     /// <p>The specified load balancer does not exist.</p>
     AccessPointNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteLoadBalancerListenersError {
@@ -4949,13 +5069,19 @@ impl DeleteLoadBalancerListenersError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "LoadBalancerNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             DeleteLoadBalancerListenersError::AccessPointNotFound(
                                 parsed_error.message,
                             ),
+                            &res,
+                        )
+                    }
+                    _ => {
+                        return RusotoError::unhandled(
+                            DeleteLoadBalancerListenersError::Unhandled,
+                            &res,
                         )
                     }
-                    _ => {}
                 }
             }
         }
@@ -4977,17 +5103,21 @@ impl fmt::Display for DeleteLoadBalancerListenersError {
             DeleteLoadBalancerListenersError::AccessPointNotFound(ref cause) => {
                 write!(f, "{}", cause)
             }
+            DeleteLoadBalancerListenersError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteLoadBalancerListenersError {}
 /// Errors returned by DeleteLoadBalancerPolicy
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteLoadBalancerPolicyError {
     /// <p>The specified load balancer does not exist.</p>
     AccessPointNotFound(String),
     /// <p>The requested configuration change is not valid.</p>
     InvalidConfigurationRequest(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteLoadBalancerPolicyError {
@@ -4999,20 +5129,27 @@ impl DeleteLoadBalancerPolicyError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "LoadBalancerNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             DeleteLoadBalancerPolicyError::AccessPointNotFound(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "InvalidConfigurationRequest" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             DeleteLoadBalancerPolicyError::InvalidConfigurationRequest(
                                 parsed_error.message,
                             ),
+                            &res,
+                        )
+                    }
+                    _ => {
+                        return RusotoError::unhandled(
+                            DeleteLoadBalancerPolicyError::Unhandled,
+                            &res,
                         )
                     }
-                    _ => {}
                 }
             }
         }
@@ -5035,17 +5172,21 @@ impl fmt::Display for DeleteLoadBalancerPolicyError {
             DeleteLoadBalancerPolicyError::InvalidConfigurationRequest(ref cause) => {
                 write!(f, "{}", cause)
             }
+            DeleteLoadBalancerPolicyError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteLoadBalancerPolicyError {}
 /// Errors returned by DeregisterInstancesFromLoadBalancer
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeregisterInstancesFromLoadBalancerError {
     /// <p>The specified load balancer does not exist.</p>
     AccessPointNotFound(String),
     /// <p>The specified endpoint is not valid.</p>
     InvalidEndPoint(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeregisterInstancesFromLoadBalancerError {
@@ -5059,20 +5200,27 @@ impl DeregisterInstancesFromLoadBalancerError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "LoadBalancerNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             DeregisterInstancesFromLoadBalancerError::AccessPointNotFound(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "InvalidInstance" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             DeregisterInstancesFromLoadBalancerError::InvalidEndPoint(
                                 parsed_error.message,
                             ),
+                            &res,
+                        )
+                    }
+                    _ => {
+                        return RusotoError::unhandled(
+                            DeregisterInstancesFromLoadBalancerError::Unhandled,
+                            &res,
                         )
                     }
-                    _ => {}
                 }
             }
         }
@@ -5097,13 +5245,20 @@ impl fmt::Display for DeregisterInstancesFromLoadBalancerError {
             DeregisterInstancesFromLoadBalancerError::InvalidEndPoint(ref cause) => {
                 write!(f, "{}", cause)
             }
+            DeregisterInstancesFromLoadBalancerError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for DeregisterInstancesFromLoadBalancerError {}
 /// Errors returned by DescribeAccountLimits
 #[derive(Debug, PartialEq)]
-pub enum DescribeAccountLimitsError {}
+#[non_exhaustive]
+pub enum DescribeAccountLimitsError {
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
+}
 
 impl DescribeAccountLimitsError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<DescribeAccountLimitsError> {
@@ -5111,10 +5266,8 @@ impl DescribeAccountLimitsError {
             let reader = EventReader::new(res.body.as_ref());
             let mut stack = XmlResponse::new(reader.into_iter().peekable());
             find_start_element(&mut stack);
-            if let Ok(parsed_error) = Self::deserialize(&mut stack) {
-                match &parsed_error.code[..] {
-                    _ => {}
-                }
+            if Self::deserialize(&mut stack).is_ok() {
+                return RusotoError::unhandled(DescribeAccountLimitsError::Unhandled, &res);
             }
         }
         RusotoError::Unknown(res)
@@ -5131,17 +5284,22 @@ impl DescribeAccountLimitsError {
 impl fmt::Display for DescribeAccountLimitsError {
     #[allow(unused_variables)]
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        match *self {}
+        match *self {
+            DescribeAccountLimitsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
+        }
     }
 }
 impl Error for DescribeAccountLimitsError {}
 /// Errors returned by DescribeInstanceHealth
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeInstanceHealthError {
     /// <p>The specified load balancer does not exist.</p>
     AccessPointNotFound(String),
     /// <p>The specified endpoint is not valid.</p>
     InvalidEndPoint(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeInstanceHealthError {
@@ -5153,16 +5311,20 @@ impl DescribeInstanceHealthError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "LoadBalancerNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             DescribeInstanceHealthError::AccessPointNotFound(parsed_error.message),
+                            &res,
                         )
                     }
                     "InvalidInstance" => {
-                        return RusotoError::Service(DescribeInstanceHealthError::InvalidEndPoint(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            DescribeInstanceHealthError::InvalidEndPoint(parsed_error.message),
+                            &res,
+                        )
+                    }
+                    _ => {
+                        return RusotoError::unhandled(DescribeInstanceHealthError::Unhandled, &res)
                     }
-                    _ => {}
                 }
             }
         }
@@ -5183,17 +5345,21 @@ impl fmt::Display for DescribeInstanceHealthError {
         match *self {
             DescribeInstanceHealthError::AccessPointNotFound(ref cause) => write!(f, "{}", cause),
             DescribeInstanceHealthError::InvalidEndPoint(ref cause) => write!(f, "{}", cause),
+            DescribeInstanceHealthError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeInstanceHealthError {}
 /// Errors returned by DescribeLoadBalancerAttributes
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeLoadBalancerAttributesError {
     /// <p>The specified load balancer does not exist.</p>
     AccessPointNotFound(String),
     /// <p>The specified load balancer attribute does not exist.</p>
     LoadBalancerAttributeNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeLoadBalancerAttributesError {
@@ -5207,20 +5373,27 @@ impl DescribeLoadBalancerAttributesError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "LoadBalancerNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             DescribeLoadBalancerAttributesError::AccessPointNotFound(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "LoadBalancerAttributeNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             DescribeLoadBalancerAttributesError::LoadBalancerAttributeNotFound(
                                 parsed_error.message,
                             ),
+                            &res,
+                        )
+                    }
+                    _ => {
+                        return RusotoError::unhandled(
+                            DescribeLoadBalancerAttributesError::Unhandled,
+                            &res,
                         )
                     }
-                    _ => {}
                 }
             }
         }
@@ -5245,17 +5418,23 @@ impl fmt::Display for DescribeLoadBalancerAttributesError {
             DescribeLoadBalancerAttributesError::LoadBalancerAttributeNotFound(ref cause) => {
                 write!(f, "{}", cause)
             }
+            DescribeLoadBalancerAttributesError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for DescribeLoadBalancerAttributesError {}
 /// Errors returned by DescribeLoadBalancerPolicies
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeLoadBalancerPoliciesError {
     /// <p>The specified load balancer does not exist.</p>
     AccessPointNotFound(String),
     /// <p>One or more of the specified policies do not exist.</p>
     PolicyNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeLoadBalancerPoliciesError {
@@ -5269,18 +5448,25 @@ impl DescribeLoadBalancerPoliciesError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "LoadBalancerNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             DescribeLoadBalancerPoliciesError::AccessPointNotFound(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "PolicyNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             DescribeLoadBalancerPoliciesError::PolicyNotFound(parsed_error.message),
+                            &res,
+                        )
+                    }
+                    _ => {
+                        return RusotoError::unhandled(
+                            DescribeLoadBalancerPoliciesError::Unhandled,
+                            &res,
                         )
                     }
-                    _ => {}
                 }
             }
         }
@@ -5303,15 +5489,19 @@ impl fmt::Display for DescribeLoadBalancerPoliciesError {
                 write!(f, "{}", cause)
             }
             DescribeLoadBalancerPoliciesError::PolicyNotFound(ref cause) => write!(f, "{}", cause),
+            DescribeLoadBalancerPoliciesError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeLoadBalancerPoliciesError {}
 /// Errors returned by DescribeLoadBalancerPolicyTypes
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeLoadBalancerPolicyTypesError {
     /// <p>One or more of the specified policy types do not exist.</p>
     PolicyTypeNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeLoadBalancerPolicyTypesError {
@@ -5325,13 +5515,19 @@ impl DescribeLoadBalancerPolicyTypesError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "PolicyTypeNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             DescribeLoadBalancerPolicyTypesError::PolicyTypeNotFound(
                                 parsed_error.message,
                             ),
+                            &res,
+                        )
+                    }
+                    _ => {
+                        return RusotoError::unhandled(
+                            DescribeLoadBalancerPolicyTypesError::Unhandled,
+                            &res,
                         )
                     }
-                    _ => {}
                 }
             }
         }
@@ -5353,17 +5549,23 @@ impl fmt::Display for DescribeLoadBalancerPolicyTypesError {
             DescribeLoadBalancerPolicyTypesError::PolicyTypeNotFound(ref cause) => {
                 write!(f, "{}", cause)
             }
+            DescribeLoadBalancerPolicyTypesError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for DescribeLoadBalancerPolicyTypesError {}
 /// Errors returned by DescribeLoadBalancers
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeLoadBalancersError {
     /// <p>The specified load balancer does not exist.</p>
     AccessPointNotFound(String),
     /// <p>A request made by Elastic Load Balancing to another service exceeds the maximum request rate permitted for your account.</p>
     DependencyThrottle(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeLoadBalancersError {
@@ -5375,16 +5577,20 @@ impl DescribeLoadBalancersError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "LoadBalancerNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             DescribeLoadBalancersError::AccessPointNotFound(parsed_error.message),
+                            &res,
                         )
                     }
                     "DependencyThrottle" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             DescribeLoadBalancersError::DependencyThrottle(parsed_error.message),
+                            &res,
                         )
                     }
-                    _ => {}
+                    _ => {
+                        return RusotoError::unhandled(DescribeLoadBalancersError::Unhandled, &res)
+                    }
                 }
             }
         }
@@ -5405,15 +5611,19 @@ impl fmt::Display for DescribeLoadBalancersError {
         match *self {
             DescribeLoadBalancersError::AccessPointNotFound(ref cause) => write!(f, "{}", cause),
             DescribeLoadBalancersError::DependencyThrottle(ref cause) => write!(f, "{}", cause),
+            DescribeLoadBalancersError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeLoadBalancersError {}
 /// Errors returned by DescribeTags
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeTagsError {
     /// <p>The specified load balancer does not exist.</p>
     AccessPointNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeTagsError {
@@ -5425,11 +5635,12 @@ impl DescribeTagsError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "LoadBalancerNotFound" => {
-                        return RusotoError::Service(DescribeTagsError::AccessPointNotFound(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            DescribeTagsError::AccessPointNotFound(parsed_error.message),
+                            &res,
+                        )
                     }
-                    _ => {}
+                    _ => return RusotoError::unhandled(DescribeTagsError::Unhandled, &res),
                 }
             }
         }
@@ -5449,17 +5660,21 @@ impl fmt::Display for DescribeTagsError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             DescribeTagsError::AccessPointNotFound(ref cause) => write!(f, "{}", cause),
+            DescribeTagsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeTagsError {}
 /// Errors returned by DetachLoadBalancerFromSubnets
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DetachLoadBalancerFromSubnetsError {
     /// <p>The specified load balancer does not exist.</p>
     AccessPointNotFound(String),
     /// <p>The requested configuration change is not valid.</p>
     InvalidConfigurationRequest(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DetachLoadBalancerFromSubnetsError {
@@ -5473,20 +5688,27 @@ impl DetachLoadBalancerFromSubnetsError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "LoadBalancerNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             DetachLoadBalancerFromSubnetsError::AccessPointNotFound(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "InvalidConfigurationRequest" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             DetachLoadBalancerFromSubnetsError::InvalidConfigurationRequest(
                                 parsed_error.message,
                             ),
+                            &res,
+                        )
+                    }
+                    _ => {
+                        return RusotoError::unhandled(
+                            DetachLoadBalancerFromSubnetsError::Unhandled,
+                            &res,
                         )
                     }
-                    _ => {}
                 }
             }
         }
@@ -5511,17 +5733,23 @@ impl fmt::Display for DetachLoadBalancerFromSubnetsError {
             DetachLoadBalancerFromSubnetsError::InvalidConfigurationRequest(ref cause) => {
                 write!(f, "{}", cause)
             }
+            DetachLoadBalancerFromSubnetsError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for DetachLoadBalancerFromSubnetsError {}
 /// Errors returned by DisableAvailabilityZonesForLoadBalancer
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DisableAvailabilityZonesForLoadBalancerError {
     /// <p>The specified load balancer does not exist.</p>
     AccessPointNotFound(String),
     /// <p>The requested configuration change is not valid.</p>
     InvalidConfigurationRequest(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DisableAvailabilityZonesForLoadBalancerError {
@@ -5535,18 +5763,25 @@ impl DisableAvailabilityZonesForLoadBalancerError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "LoadBalancerNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             DisableAvailabilityZonesForLoadBalancerError::AccessPointNotFound(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
-                    "InvalidConfigurationRequest" => return RusotoError::Service(
+                    "InvalidConfigurationRequest" => return RusotoError::service(
                         DisableAvailabilityZonesForLoadBalancerError::InvalidConfigurationRequest(
                             parsed_error.message,
                         ),
+                        &res,
                     ),
-                    _ => {}
+                    _ => {
+                        return RusotoError::unhandled(
+                            DisableAvailabilityZonesForLoadBalancerError::Unhandled,
+                            &res,
+                        )
+                    }
                 }
             }
         }
@@ -5571,15 +5806,21 @@ impl fmt::Display for DisableAvailabilityZonesForLoadBalancerError {
             DisableAvailabilityZonesForLoadBalancerError::InvalidConfigurationRequest(
                 ref cause,
             ) => write!(f, "{}", cause),
+            DisableAvailabilityZonesForLoadBalancerError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for DisableAvailabilityZonesForLoadBalancerError {}
 /// Errors returned by EnableAvailabilityZonesForLoadBalancer
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum EnableAvailabilityZonesForLoadBalancerError {
     /// <p>The specified load balancer does not exist.</p>
     AccessPointNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl EnableAvailabilityZonesForLoadBalancerError {
@@ -5593,13 +5834,19 @@ impl EnableAvailabilityZonesForLoadBalancerError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "LoadBalancerNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             EnableAvailabilityZonesForLoadBalancerError::AccessPointNotFound(
                                 parsed_error.message,
                             ),
+                            &res,
+                        )
+                    }
+                    _ => {
+                        return RusotoError::unhandled(
+                            EnableAvailabilityZonesForLoadBalancerError::Unhandled,
+                            &res,
                         )
                     }
-                    _ => {}
                 }
             }
         }
@@ -5621,12 +5868,16 @@ impl fmt::Display for EnableAvailabilityZonesForLoadBalancerError {
             EnableAvailabilityZonesForLoadBalancerError::AccessPointNotFound(ref cause) => {
                 write!(f, "{}", cause)
             }
+            EnableAvailabilityZonesForLoadBalancerError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for EnableAvailabilityZonesForLoadBalancerError {}
 /// Errors returned by ModifyLoadBalancerAttributes
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ModifyLoadBalancerAttributesError {
     /// <p>The specified load balancer does not exist.</p>
     AccessPointNotFound(String),
@@ -5634,6 +5885,8 @@ pub enum ModifyLoadBalancerAttributesError {
     InvalidConfigurationRequest(String),
     /// <p>The specified load balancer attribute does not exist.</p>
     LoadBalancerAttributeNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ModifyLoadBalancerAttributesError {
@@ -5647,27 +5900,35 @@ impl ModifyLoadBalancerAttributesError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "LoadBalancerNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             ModifyLoadBalancerAttributesError::AccessPointNotFound(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "InvalidConfigurationRequest" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             ModifyLoadBalancerAttributesError::InvalidConfigurationRequest(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "LoadBalancerAttributeNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             ModifyLoadBalancerAttributesError::LoadBalancerAttributeNotFound(
                                 parsed_error.message,
                             ),
+                            &res,
+                        )
+                    }
+                    _ => {
+                        return RusotoError::unhandled(
+                            ModifyLoadBalancerAttributesError::Unhandled,
+                            &res,
                         )
                     }
-                    _ => {}
                 }
             }
         }
@@ -5695,17 +5956,21 @@ impl fmt::Display for ModifyLoadBalancerAttributesError {
             ModifyLoadBalancerAttributesError::LoadBalancerAttributeNotFound(ref cause) => {
                 write!(f, "{}", cause)
             }
+            ModifyLoadBalancerAttributesError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ModifyLoadBalancerAttributesError {}
 /// Errors returned by RegisterInstancesWithLoadBalancer
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum RegisterInstancesWithLoadBalancerError {
     /// <p>The specified load balancer does not exist.</p>
     AccessPointNotFound(String),
     /// <p>The specified endpoint is not valid.</p>
     InvalidEndPoint(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl RegisterInstancesWithLoadBalancerError {
@@ -5719,20 +5984,27 @@ impl RegisterInstancesWithLoadBalancerError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "LoadBalancerNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             RegisterInstancesWithLoadBalancerError::AccessPointNotFound(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "InvalidInstance" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             RegisterInstancesWithLoadBalancerError::InvalidEndPoint(
                                 parsed_error.message,
                             ),
+                            &res,
+                        )
+                    }
+                    _ => {
+                        return RusotoError::unhandled(
+                            RegisterInstancesWithLoadBalancerError::Unhandled,
+                            &res,
                         )
                     }
-                    _ => {}
                 }
             }
         }
@@ -5757,15 +6029,21 @@ impl fmt::Display for RegisterInstancesWithLoadBalancerError {
             RegisterInstancesWithLoadBalancerError::InvalidEndPoint(ref cause) => {
                 write!(f, "{}", cause)
             }
+            RegisterInstancesWithLoadBalancerError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for RegisterInstancesWithLoadBalancerError {}
 /// Errors returned by RemoveTags
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum RemoveTagsError {
     /// <p>The specified load balancer does not exist.</p>
     AccessPointNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl RemoveTagsError {
@@ -5777,11 +6055,12 @@ impl RemoveTagsError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "LoadBalancerNotFound" => {
-                        return RusotoError::Service(RemoveTagsError::AccessPointNotFound(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            RemoveTagsError::AccessPointNotFound(parsed_error.message),
+                            &res,
+                        )
                     }
-                    _ => {}
+                    _ => return RusotoError::unhandled(RemoveTagsError::Unhandled, &res),
                 }
             }
         }
@@ -5801,12 +6080,14 @@ impl fmt::Display for RemoveTagsError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             RemoveTagsError::AccessPointNotFound(ref cause) => write!(f, "{}", cause),
+            RemoveTagsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for RemoveTagsError {}
 /// Errors returned by SetLoadBalancerListenerSSLCertificate
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum SetLoadBalancerListenerSSLCertificateError {
     /// <p>The specified load balancer does not exist.</p>
     AccessPointNotFound(String),
@@ -5818,6 +6099,8 @@ pub enum SetLoadBalancerListenerSSLCertificateError {
     ListenerNotFound(String),
     /// <p>The specified protocol or signature version is not supported.</p>
     UnsupportedProtocol(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl SetLoadBalancerListenerSSLCertificateError {
@@ -5831,41 +6114,51 @@ impl SetLoadBalancerListenerSSLCertificateError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "LoadBalancerNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             SetLoadBalancerListenerSSLCertificateError::AccessPointNotFound(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "CertificateNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             SetLoadBalancerListenerSSLCertificateError::CertificateNotFound(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "InvalidConfigurationRequest" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             SetLoadBalancerListenerSSLCertificateError::InvalidConfigurationRequest(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "ListenerNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             SetLoadBalancerListenerSSLCertificateError::ListenerNotFound(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "UnsupportedProtocol" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             SetLoadBalancerListenerSSLCertificateError::UnsupportedProtocol(
                                 parsed_error.message,
                             ),
+                            &res,
+                        )
+                    }
+                    _ => {
+                        return RusotoError::unhandled(
+                            SetLoadBalancerListenerSSLCertificateError::Unhandled,
+                            &res,
                         )
                     }
-                    _ => {}
                 }
             }
         }
@@ -5899,12 +6192,16 @@ impl fmt::Display for SetLoadBalancerListenerSSLCertificateError {
             SetLoadBalancerListenerSSLCertificateError::UnsupportedProtocol(ref cause) => {
                 write!(f, "{}", cause)
             }
+            SetLoadBalancerListenerSSLCertificateError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for SetLoadBalancerListenerSSLCertificateError {}
 /// Errors returned by SetLoadBalancerPoliciesForBackendServer
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum SetLoadBalancerPoliciesForBackendServerError {
     /// <p>The specified load balancer does not exist.</p>
     AccessPointNotFound(String),
@@ -5912,6 +6209,8 @@ pub enum SetLoadBalancerPoliciesForBackendServerError {
     InvalidConfigurationRequest(String),
     /// <p>One or more of the specified policies do not exist.</p>
     PolicyNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl SetLoadBalancerPoliciesForBackendServerError {
@@ -5925,25 +6224,33 @@ impl SetLoadBalancerPoliciesForBackendServerError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "LoadBalancerNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             SetLoadBalancerPoliciesForBackendServerError::AccessPointNotFound(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
-                    "InvalidConfigurationRequest" => return RusotoError::Service(
+                    "InvalidConfigurationRequest" => return RusotoError::service(
                         SetLoadBalancerPoliciesForBackendServerError::InvalidConfigurationRequest(
                             parsed_error.message,
                         ),
+                        &res,
                     ),
                     "PolicyNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             SetLoadBalancerPoliciesForBackendServerError::PolicyNotFound(
                                 parsed_error.message,
                             ),
+                            &res,
+                        )
+                    }
+                    _ => {
+                        return RusotoError::unhandled(
+                            SetLoadBalancerPoliciesForBackendServerError::Unhandled,
+                            &res,
                         )
                     }
-                    _ => {}
                 }
             }
         }
@@ -5971,12 +6278,16 @@ impl fmt::Display for SetLoadBalancerPoliciesForBackendServerError {
             SetLoadBalancerPoliciesForBackendServerError::PolicyNotFound(ref cause) => {
                 write!(f, "{}", cause)
             }
+            SetLoadBalancerPoliciesForBackendServerError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for SetLoadBalancerPoliciesForBackendServerError {}
 /// Errors returned by SetLoadBalancerPoliciesOfListener
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum SetLoadBalancerPoliciesOfListenerError {
     /// <p>The specified load balancer does not exist.</p>
     AccessPointNotFound(String),
@@ -5986,6 +6297,8 @@ pub enum SetLoadBalancerPoliciesOfListenerError {
     ListenerNotFound(String),
     /// <p>One or more of the specified policies do not exist.</p>
     PolicyNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl SetLoadBalancerPoliciesOfListenerError {
@@ -5999,34 +6312,43 @@ impl SetLoadBalancerPoliciesOfListenerError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "LoadBalancerNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             SetLoadBalancerPoliciesOfListenerError::AccessPointNotFound(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "InvalidConfigurationRequest" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             SetLoadBalancerPoliciesOfListenerError::InvalidConfigurationRequest(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "ListenerNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             SetLoadBalancerPoliciesOfListenerError::ListenerNotFound(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "PolicyNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             SetLoadBalancerPoliciesOfListenerError::PolicyNotFound(
                                 parsed_error.message,
                             ),
+                            &res,
+                        )
+                    }
+                    _ => {
+                        return RusotoError::unhandled(
+                            SetLoadBalancerPoliciesOfListenerError::Unhandled,
+                            &res,
                         )
                     }
-                    _ => {}
                 }
             }
         }
@@ -6057,6 +6379,9 @@ impl fmt::Display for SetLoadBalancerPoliciesOfListenerError {
             SetLoadBalancerPoliciesOfListenerError::PolicyNotFound(ref cause) => {
                 write!(f, "{}", cause)
             }
+            SetLoadBalancerPoliciesOfListenerError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
@@ -6276,6 +6601,7 @@ impl ElbClient {
     /// Creates a client backed by the default tokio event loop.
     ///
     /// The client will use the default credentials provider and tls client.
+    #[cfg(not(target_arch = "wasm32"))]
     pub fn new(region: region::Region) -> ElbClient {
         ElbClient {
             client: Client::shared(),
@@ -6301,35 +6627,49 @@ impl ElbClient {
     pub fn new_with_client(client: Client, region: region::Region) -> ElbClient {
         ElbClient { client, region }
     }
+
+    /// Returns a builder of a client, setting any of its region, credentials provider,
+    /// dispatcher, retry policy, endpoint and timeouts.
+    pub fn builder() -> rusoto_core::ClientBuilder<ElbClient> {
+        rusoto_core::ClientBuilder::new(ElbClient::new_with_client)
+    }
+
+    /// Returns a clone of this client with the overrides of `config` applied to its
+    /// operations, sharing its connections.
+    pub fn customize(&self, config: &rusoto_core::OperationConfig) -> ElbClient {
+        ElbClient {
+            client: self.client.customize(config),
+            region: config.resolve_region(&self.region),
+        }
+    }
 }
 
-#[async_trait]
-impl Elb for ElbClient {
-    /// <p>Adds the specified tags to the specified load balancer. Each load balancer can have a maximum of 10 tags.</p> <p>Each tag consists of a key and an optional value. If a tag with the same key is already associated with the load balancer, <code>AddTags</code> updates its value.</p> <p>For more information, see <a href="http://docs.aws.amazon.com/elasticloadbalancing/latest/classic/add-remove-tags.html">Tag Your Classic Load Balancer</a> in the <i>Classic Load Balancers Guide</i>.</p>
-    async fn add_tags(
+impl ElbClient {
+    /// Calls `add_tags`, also returning the metadata of the response.
+    pub async fn add_tags_with_metadata(
         &self,
         input: AddTagsInput,
-    ) -> Result<AddTagsOutput, RusotoError<AddTagsError>> {
-        let mut request = SignedRequest::new("POST", "elasticloadbalancing", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "AddTags");
-        params.put("Version", "2012-06-01");
-        AddTagsInputSerializer::serialize(&mut params, "", &input);
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+    ) -> Result<(AddTagsOutput, ResponseMetadata), RusotoError<AddTagsError>> {
+        let request = self.build_add_tags_request(input);
 
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(AddTagsError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -6348,39 +6688,63 @@ impl Elb for ElbClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Associates one or more security groups with your load balancer in a virtual private cloud (VPC). The specified security groups override the previously associated security groups.</p> <p>For more information, see <a href="http://docs.aws.amazon.com/elasticloadbalancing/latest/classic/elb-security-groups.html#elb-vpc-security-groups">Security Groups for Load Balancers in a VPC</a> in the <i>Classic Load Balancers Guide</i>.</p>
-    async fn apply_security_groups_to_load_balancer(
+    /// Presigns the request `add_tags` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_add_tags(
         &self,
-        input: ApplySecurityGroupsToLoadBalancerInput,
-    ) -> Result<
-        ApplySecurityGroupsToLoadBalancerOutput,
-        RusotoError<ApplySecurityGroupsToLoadBalancerError>,
-    > {
+        presigner: &rusoto_core::Presigner,
+        input: AddTagsInput,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_add_tags_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_add_tags_request(&self, input: AddTagsInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "elasticloadbalancing", &self.region, "/");
+        request.set_operation("AddTags");
         let mut params = Params::new();
 
-        params.put("Action", "ApplySecurityGroupsToLoadBalancer");
+        params.put("Action", "AddTags");
         params.put("Version", "2012-06-01");
-        ApplySecurityGroupsToLoadBalancerInputSerializer::serialize(&mut params, "", &input);
+        AddTagsInputSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `apply_security_groups_to_load_balancer`, also returning the metadata of the response.
+    pub async fn apply_security_groups_to_load_balancer_with_metadata(
+        &self,
+        input: ApplySecurityGroupsToLoadBalancerInput,
+    ) -> Result<
+        (ApplySecurityGroupsToLoadBalancerOutput, ResponseMetadata),
+        RusotoError<ApplySecurityGroupsToLoadBalancerError>,
+    > {
+        let request = self.build_apply_security_groups_to_load_balancer_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(ApplySecurityGroupsToLoadBalancerError::from_response(
                 response,
             ));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -6402,35 +6766,64 @@ impl Elb for ElbClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Adds one or more subnets to the set of configured subnets for the specified load balancer.</p> <p>The load balancer evenly distributes requests across all registered subnets. For more information, see <a href="http://docs.aws.amazon.com/elasticloadbalancing/latest/classic/elb-manage-subnets.html">Add or Remove Subnets for Your Load Balancer in a VPC</a> in the <i>Classic Load Balancers Guide</i>.</p>
-    async fn attach_load_balancer_to_subnets(
+    /// Presigns the request `apply_security_groups_to_load_balancer` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_apply_security_groups_to_load_balancer(
         &self,
-        input: AttachLoadBalancerToSubnetsInput,
-    ) -> Result<AttachLoadBalancerToSubnetsOutput, RusotoError<AttachLoadBalancerToSubnetsError>>
-    {
+        presigner: &rusoto_core::Presigner,
+        input: ApplySecurityGroupsToLoadBalancerInput,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_apply_security_groups_to_load_balancer_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_apply_security_groups_to_load_balancer_request(
+        &self,
+        input: ApplySecurityGroupsToLoadBalancerInput,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "elasticloadbalancing", &self.region, "/");
+        request.set_operation("ApplySecurityGroupsToLoadBalancer");
         let mut params = Params::new();
 
-        params.put("Action", "AttachLoadBalancerToSubnets");
+        params.put("Action", "ApplySecurityGroupsToLoadBalancer");
         params.put("Version", "2012-06-01");
-        AttachLoadBalancerToSubnetsInputSerializer::serialize(&mut params, "", &input);
+        ApplySecurityGroupsToLoadBalancerInputSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `attach_load_balancer_to_subnets`, also returning the metadata of the response.
+    pub async fn attach_load_balancer_to_subnets_with_metadata(
+        &self,
+        input: AttachLoadBalancerToSubnetsInput,
+    ) -> Result<
+        (AttachLoadBalancerToSubnetsOutput, ResponseMetadata),
+        RusotoError<AttachLoadBalancerToSubnetsError>,
+    > {
+        let request = self.build_attach_load_balancer_to_subnets_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(AttachLoadBalancerToSubnetsError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -6452,34 +6845,64 @@ impl Elb for ElbClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Specifies the health check settings to use when evaluating the health state of your EC2 instances.</p> <p>For more information, see <a href="http://docs.aws.amazon.com/elasticloadbalancing/latest/classic/elb-healthchecks.html">Configure Health Checks for Your Load Balancer</a> in the <i>Classic Load Balancers Guide</i>.</p>
-    async fn configure_health_check(
+    /// Presigns the request `attach_load_balancer_to_subnets` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_attach_load_balancer_to_subnets(
         &self,
-        input: ConfigureHealthCheckInput,
-    ) -> Result<ConfigureHealthCheckOutput, RusotoError<ConfigureHealthCheckError>> {
+        presigner: &rusoto_core::Presigner,
+        input: AttachLoadBalancerToSubnetsInput,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_attach_load_balancer_to_subnets_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_attach_load_balancer_to_subnets_request(
+        &self,
+        input: AttachLoadBalancerToSubnetsInput,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "elasticloadbalancing", &self.region, "/");
+        request.set_operation("AttachLoadBalancerToSubnets");
         let mut params = Params::new();
 
-        params.put("Action", "ConfigureHealthCheck");
+        params.put("Action", "AttachLoadBalancerToSubnets");
         params.put("Version", "2012-06-01");
-        ConfigureHealthCheckInputSerializer::serialize(&mut params, "", &input);
+        AttachLoadBalancerToSubnetsInputSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `configure_health_check`, also returning the metadata of the response.
+    pub async fn configure_health_check_with_metadata(
+        &self,
+        input: ConfigureHealthCheckInput,
+    ) -> Result<
+        (ConfigureHealthCheckOutput, ResponseMetadata),
+        RusotoError<ConfigureHealthCheckError>,
+    > {
+        let request = self.build_configure_health_check_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(ConfigureHealthCheckError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -6501,39 +6924,66 @@ impl Elb for ElbClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Generates a stickiness policy with sticky session lifetimes that follow that of an application-generated cookie. This policy can be associated only with HTTP/HTTPS listeners.</p> <p>This policy is similar to the policy created by <a>CreateLBCookieStickinessPolicy</a>, except that the lifetime of the special Elastic Load Balancing cookie, <code>AWSELB</code>, follows the lifetime of the application-generated cookie specified in the policy configuration. The load balancer only inserts a new stickiness cookie when the application response includes a new application cookie.</p> <p>If the application cookie is explicitly removed or expires, the session stops being sticky until a new application cookie is issued.</p> <p>For more information, see <a href="http://docs.aws.amazon.com/elasticloadbalancing/latest/classic/elb-sticky-sessions.html#enable-sticky-sessions-application">Application-Controlled Session Stickiness</a> in the <i>Classic Load Balancers Guide</i>.</p>
-    async fn create_app_cookie_stickiness_policy(
+    /// Presigns the request `configure_health_check` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_configure_health_check(
         &self,
-        input: CreateAppCookieStickinessPolicyInput,
-    ) -> Result<
-        CreateAppCookieStickinessPolicyOutput,
-        RusotoError<CreateAppCookieStickinessPolicyError>,
-    > {
+        presigner: &rusoto_core::Presigner,
+        input: ConfigureHealthCheckInput,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_configure_health_check_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_configure_health_check_request(
+        &self,
+        input: ConfigureHealthCheckInput,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "elasticloadbalancing", &self.region, "/");
+        request.set_operation("ConfigureHealthCheck");
         let mut params = Params::new();
 
-        params.put("Action", "CreateAppCookieStickinessPolicy");
+        params.put("Action", "ConfigureHealthCheck");
         params.put("Version", "2012-06-01");
-        CreateAppCookieStickinessPolicyInputSerializer::serialize(&mut params, "", &input);
+        ConfigureHealthCheckInputSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `create_app_cookie_stickiness_policy`, also returning the metadata of the response.
+    pub async fn create_app_cookie_stickiness_policy_with_metadata(
+        &self,
+        input: CreateAppCookieStickinessPolicyInput,
+    ) -> Result<
+        (CreateAppCookieStickinessPolicyOutput, ResponseMetadata),
+        RusotoError<CreateAppCookieStickinessPolicyError>,
+    > {
+        let request = self.build_create_app_cookie_stickiness_policy_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(CreateAppCookieStickinessPolicyError::from_response(
                 response,
             ));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -6555,37 +7005,64 @@ impl Elb for ElbClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Generates a stickiness policy with sticky session lifetimes controlled by the lifetime of the browser (user-agent) or a specified expiration period. This policy can be associated only with HTTP/HTTPS listeners.</p> <p>When a load balancer implements this policy, the load balancer uses a special cookie to track the instance for each request. When the load balancer receives a request, it first checks to see if this cookie is present in the request. If so, the load balancer sends the request to the application server specified in the cookie. If not, the load balancer sends the request to a server that is chosen based on the existing load-balancing algorithm.</p> <p>A cookie is inserted into the response for binding subsequent requests from the same user to that server. The validity of the cookie is based on the cookie expiration time, which is specified in the policy configuration.</p> <p>For more information, see <a href="http://docs.aws.amazon.com/elasticloadbalancing/latest/classic/elb-sticky-sessions.html#enable-sticky-sessions-duration">Duration-Based Session Stickiness</a> in the <i>Classic Load Balancers Guide</i>.</p>
-    async fn create_lb_cookie_stickiness_policy(
+    /// Presigns the request `create_app_cookie_stickiness_policy` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_app_cookie_stickiness_policy(
         &self,
-        input: CreateLBCookieStickinessPolicyInput,
-    ) -> Result<
-        CreateLBCookieStickinessPolicyOutput,
-        RusotoError<CreateLBCookieStickinessPolicyError>,
-    > {
+        presigner: &rusoto_core::Presigner,
+        input: CreateAppCookieStickinessPolicyInput,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_app_cookie_stickiness_policy_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_app_cookie_stickiness_policy_request(
+        &self,
+        input: CreateAppCookieStickinessPolicyInput,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "elasticloadbalancing", &self.region, "/");
+        request.set_operation("CreateAppCookieStickinessPolicy");
         let mut params = Params::new();
 
-        params.put("Action", "CreateLBCookieStickinessPolicy");
+        params.put("Action", "CreateAppCookieStickinessPolicy");
         params.put("Version", "2012-06-01");
-        CreateLBCookieStickinessPolicyInputSerializer::serialize(&mut params, "", &input);
+        CreateAppCookieStickinessPolicyInputSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `create_lb_cookie_stickiness_policy`, also returning the metadata of the response.
+    pub async fn create_lb_cookie_stickiness_policy_with_metadata(
+        &self,
+        input: CreateLBCookieStickinessPolicyInput,
+    ) -> Result<
+        (CreateLBCookieStickinessPolicyOutput, ResponseMetadata),
+        RusotoError<CreateLBCookieStickinessPolicyError>,
+    > {
+        let request = self.build_create_lb_cookie_stickiness_policy_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(CreateLBCookieStickinessPolicyError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -6607,34 +7084,62 @@ impl Elb for ElbClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Creates a Classic Load Balancer.</p> <p>You can add listeners, security groups, subnets, and tags when you create your load balancer, or you can add them later using <a>CreateLoadBalancerListeners</a>, <a>ApplySecurityGroupsToLoadBalancer</a>, <a>AttachLoadBalancerToSubnets</a>, and <a>AddTags</a>.</p> <p>To describe your current load balancers, see <a>DescribeLoadBalancers</a>. When you are finished with a load balancer, you can delete it using <a>DeleteLoadBalancer</a>.</p> <p>You can create up to 20 load balancers per region per account. You can request an increase for the number of load balancers for your account. For more information, see <a href="http://docs.aws.amazon.com/elasticloadbalancing/latest/classic/elb-limits.html">Limits for Your Classic Load Balancer</a> in the <i>Classic Load Balancers Guide</i>.</p>
-    async fn create_load_balancer(
+    /// Presigns the request `create_lb_cookie_stickiness_policy` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_lb_cookie_stickiness_policy(
         &self,
-        input: CreateAccessPointInput,
-    ) -> Result<CreateAccessPointOutput, RusotoError<CreateLoadBalancerError>> {
+        presigner: &rusoto_core::Presigner,
+        input: CreateLBCookieStickinessPolicyInput,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_lb_cookie_stickiness_policy_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_lb_cookie_stickiness_policy_request(
+        &self,
+        input: CreateLBCookieStickinessPolicyInput,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "elasticloadbalancing", &self.region, "/");
+        request.set_operation("CreateLBCookieStickinessPolicy");
         let mut params = Params::new();
 
-        params.put("Action", "CreateLoadBalancer");
+        params.put("Action", "CreateLBCookieStickinessPolicy");
         params.put("Version", "2012-06-01");
-        CreateAccessPointInputSerializer::serialize(&mut params, "", &input);
+        CreateLBCookieStickinessPolicyInputSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
-        let mut response = self
-            .client
-            .sign_and_dispatch(request)
+        request
+    }
+
+    /// Calls `create_load_balancer`, also returning the metadata of the response.
+    pub async fn create_load_balancer_with_metadata(
+        &self,
+        input: CreateAccessPointInput,
+    ) -> Result<(CreateAccessPointOutput, ResponseMetadata), RusotoError<CreateLoadBalancerError>>
+    {
+        let request = self.build_create_load_balancer_request(input);
+
+        let mut response = self
+            .client
+            .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(CreateLoadBalancerError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -6656,35 +7161,61 @@ impl Elb for ElbClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Creates one or more listeners for the specified load balancer. If a listener with the specified port does not already exist, it is created; otherwise, the properties of the new listener must match the properties of the existing listener.</p> <p>For more information, see <a href="http://docs.aws.amazon.com/elasticloadbalancing/latest/classic/elb-listener-config.html">Listeners for Your Classic Load Balancer</a> in the <i>Classic Load Balancers Guide</i>.</p>
-    async fn create_load_balancer_listeners(
+    /// Presigns the request `create_load_balancer` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_load_balancer(
         &self,
-        input: CreateLoadBalancerListenerInput,
-    ) -> Result<CreateLoadBalancerListenerOutput, RusotoError<CreateLoadBalancerListenersError>>
-    {
+        presigner: &rusoto_core::Presigner,
+        input: CreateAccessPointInput,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_load_balancer_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_load_balancer_request(&self, input: CreateAccessPointInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "elasticloadbalancing", &self.region, "/");
+        request.set_operation("CreateLoadBalancer");
         let mut params = Params::new();
 
-        params.put("Action", "CreateLoadBalancerListeners");
+        params.put("Action", "CreateLoadBalancer");
         params.put("Version", "2012-06-01");
-        CreateLoadBalancerListenerInputSerializer::serialize(&mut params, "", &input);
+        CreateAccessPointInputSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `create_load_balancer_listeners`, also returning the metadata of the response.
+    pub async fn create_load_balancer_listeners_with_metadata(
+        &self,
+        input: CreateLoadBalancerListenerInput,
+    ) -> Result<
+        (CreateLoadBalancerListenerOutput, ResponseMetadata),
+        RusotoError<CreateLoadBalancerListenersError>,
+    > {
+        let request = self.build_create_load_balancer_listeners_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(CreateLoadBalancerListenersError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -6706,34 +7237,64 @@ impl Elb for ElbClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Creates a policy with the specified attributes for the specified load balancer.</p> <p>Policies are settings that are saved for your load balancer and that can be applied to the listener or the application server, depending on the policy type.</p>
-    async fn create_load_balancer_policy(
+    /// Presigns the request `create_load_balancer_listeners` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_load_balancer_listeners(
         &self,
-        input: CreateLoadBalancerPolicyInput,
-    ) -> Result<CreateLoadBalancerPolicyOutput, RusotoError<CreateLoadBalancerPolicyError>> {
+        presigner: &rusoto_core::Presigner,
+        input: CreateLoadBalancerListenerInput,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_load_balancer_listeners_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_load_balancer_listeners_request(
+        &self,
+        input: CreateLoadBalancerListenerInput,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "elasticloadbalancing", &self.region, "/");
+        request.set_operation("CreateLoadBalancerListeners");
         let mut params = Params::new();
 
-        params.put("Action", "CreateLoadBalancerPolicy");
+        params.put("Action", "CreateLoadBalancerListeners");
         params.put("Version", "2012-06-01");
-        CreateLoadBalancerPolicyInputSerializer::serialize(&mut params, "", &input);
+        CreateLoadBalancerListenerInputSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `create_load_balancer_policy`, also returning the metadata of the response.
+    pub async fn create_load_balancer_policy_with_metadata(
+        &self,
+        input: CreateLoadBalancerPolicyInput,
+    ) -> Result<
+        (CreateLoadBalancerPolicyOutput, ResponseMetadata),
+        RusotoError<CreateLoadBalancerPolicyError>,
+    > {
+        let request = self.build_create_load_balancer_policy_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(CreateLoadBalancerPolicyError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -6755,34 +7316,62 @@ impl Elb for ElbClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Deletes the specified load balancer.</p> <p>If you are attempting to recreate a load balancer, you must reconfigure all settings. The DNS name associated with a deleted load balancer are no longer usable. The name and associated DNS record of the deleted load balancer no longer exist and traffic sent to any of its IP addresses is no longer delivered to your instances.</p> <p>If the load balancer does not exist or has already been deleted, the call to <code>DeleteLoadBalancer</code> still succeeds.</p>
-    async fn delete_load_balancer(
+    /// Presigns the request `create_load_balancer_policy` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_load_balancer_policy(
         &self,
-        input: DeleteAccessPointInput,
-    ) -> Result<DeleteAccessPointOutput, RusotoError<DeleteLoadBalancerError>> {
+        presigner: &rusoto_core::Presigner,
+        input: CreateLoadBalancerPolicyInput,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_load_balancer_policy_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_load_balancer_policy_request(
+        &self,
+        input: CreateLoadBalancerPolicyInput,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "elasticloadbalancing", &self.region, "/");
+        request.set_operation("CreateLoadBalancerPolicy");
         let mut params = Params::new();
 
-        params.put("Action", "DeleteLoadBalancer");
+        params.put("Action", "CreateLoadBalancerPolicy");
         params.put("Version", "2012-06-01");
-        DeleteAccessPointInputSerializer::serialize(&mut params, "", &input);
+        CreateLoadBalancerPolicyInputSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `delete_load_balancer`, also returning the metadata of the response.
+    pub async fn delete_load_balancer_with_metadata(
+        &self,
+        input: DeleteAccessPointInput,
+    ) -> Result<(DeleteAccessPointOutput, ResponseMetadata), RusotoError<DeleteLoadBalancerError>>
+    {
+        let request = self.build_delete_load_balancer_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(DeleteLoadBalancerError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -6804,35 +7393,61 @@ impl Elb for ElbClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Deletes the specified listeners from the specified load balancer.</p>
-    async fn delete_load_balancer_listeners(
+    /// Presigns the request `delete_load_balancer` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_load_balancer(
         &self,
-        input: DeleteLoadBalancerListenerInput,
-    ) -> Result<DeleteLoadBalancerListenerOutput, RusotoError<DeleteLoadBalancerListenersError>>
-    {
+        presigner: &rusoto_core::Presigner,
+        input: DeleteAccessPointInput,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_load_balancer_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_load_balancer_request(&self, input: DeleteAccessPointInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "elasticloadbalancing", &self.region, "/");
+        request.set_operation("DeleteLoadBalancer");
         let mut params = Params::new();
 
-        params.put("Action", "DeleteLoadBalancerListeners");
+        params.put("Action", "DeleteLoadBalancer");
         params.put("Version", "2012-06-01");
-        DeleteLoadBalancerListenerInputSerializer::serialize(&mut params, "", &input);
+        DeleteAccessPointInputSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `delete_load_balancer_listeners`, also returning the metadata of the response.
+    pub async fn delete_load_balancer_listeners_with_metadata(
+        &self,
+        input: DeleteLoadBalancerListenerInput,
+    ) -> Result<
+        (DeleteLoadBalancerListenerOutput, ResponseMetadata),
+        RusotoError<DeleteLoadBalancerListenersError>,
+    > {
+        let request = self.build_delete_load_balancer_listeners_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(DeleteLoadBalancerListenersError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -6854,34 +7469,64 @@ impl Elb for ElbClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Deletes the specified policy from the specified load balancer. This policy must not be enabled for any listeners.</p>
-    async fn delete_load_balancer_policy(
+    /// Presigns the request `delete_load_balancer_listeners` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_load_balancer_listeners(
         &self,
-        input: DeleteLoadBalancerPolicyInput,
-    ) -> Result<DeleteLoadBalancerPolicyOutput, RusotoError<DeleteLoadBalancerPolicyError>> {
+        presigner: &rusoto_core::Presigner,
+        input: DeleteLoadBalancerListenerInput,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_load_balancer_listeners_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_load_balancer_listeners_request(
+        &self,
+        input: DeleteLoadBalancerListenerInput,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "elasticloadbalancing", &self.region, "/");
+        request.set_operation("DeleteLoadBalancerListeners");
         let mut params = Params::new();
 
-        params.put("Action", "DeleteLoadBalancerPolicy");
+        params.put("Action", "DeleteLoadBalancerListeners");
         params.put("Version", "2012-06-01");
-        DeleteLoadBalancerPolicyInputSerializer::serialize(&mut params, "", &input);
+        DeleteLoadBalancerListenerInputSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `delete_load_balancer_policy`, also returning the metadata of the response.
+    pub async fn delete_load_balancer_policy_with_metadata(
+        &self,
+        input: DeleteLoadBalancerPolicyInput,
+    ) -> Result<
+        (DeleteLoadBalancerPolicyOutput, ResponseMetadata),
+        RusotoError<DeleteLoadBalancerPolicyError>,
+    > {
+        let request = self.build_delete_load_balancer_policy_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(DeleteLoadBalancerPolicyError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -6903,37 +7548,66 @@ impl Elb for ElbClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Deregisters the specified instances from the specified load balancer. After the instance is deregistered, it no longer receives traffic from the load balancer.</p> <p>You can use <a>DescribeLoadBalancers</a> to verify that the instance is deregistered from the load balancer.</p> <p>For more information, see <a href="http://docs.aws.amazon.com/elasticloadbalancing/latest/classic/elb-deregister-register-instances.html">Register or De-Register EC2 Instances</a> in the <i>Classic Load Balancers Guide</i>.</p>
-    async fn deregister_instances_from_load_balancer(
+    /// Presigns the request `delete_load_balancer_policy` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_load_balancer_policy(
         &self,
-        input: DeregisterEndPointsInput,
-    ) -> Result<DeregisterEndPointsOutput, RusotoError<DeregisterInstancesFromLoadBalancerError>>
-    {
+        presigner: &rusoto_core::Presigner,
+        input: DeleteLoadBalancerPolicyInput,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_load_balancer_policy_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_load_balancer_policy_request(
+        &self,
+        input: DeleteLoadBalancerPolicyInput,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "elasticloadbalancing", &self.region, "/");
+        request.set_operation("DeleteLoadBalancerPolicy");
         let mut params = Params::new();
 
-        params.put("Action", "DeregisterInstancesFromLoadBalancer");
+        params.put("Action", "DeleteLoadBalancerPolicy");
         params.put("Version", "2012-06-01");
-        DeregisterEndPointsInputSerializer::serialize(&mut params, "", &input);
+        DeleteLoadBalancerPolicyInputSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `deregister_instances_from_load_balancer`, also returning the metadata of the response.
+    pub async fn deregister_instances_from_load_balancer_with_metadata(
+        &self,
+        input: DeregisterEndPointsInput,
+    ) -> Result<
+        (DeregisterEndPointsOutput, ResponseMetadata),
+        RusotoError<DeregisterInstancesFromLoadBalancerError>,
+    > {
+        let request = self.build_deregister_instances_from_load_balancer_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(DeregisterInstancesFromLoadBalancerError::from_response(
                 response,
             ));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -6955,34 +7629,64 @@ impl Elb for ElbClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Describes the current Elastic Load Balancing resource limits for your AWS account.</p> <p>For more information, see <a href="http://docs.aws.amazon.com/elasticloadbalancing/latest/classic/elb-limits.html">Limits for Your Classic Load Balancer</a> in the <i>Classic Load Balancers Guide</i>.</p>
-    async fn describe_account_limits(
+    /// Presigns the request `deregister_instances_from_load_balancer` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_deregister_instances_from_load_balancer(
         &self,
-        input: DescribeAccountLimitsInput,
-    ) -> Result<DescribeAccountLimitsOutput, RusotoError<DescribeAccountLimitsError>> {
+        presigner: &rusoto_core::Presigner,
+        input: DeregisterEndPointsInput,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_deregister_instances_from_load_balancer_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_deregister_instances_from_load_balancer_request(
+        &self,
+        input: DeregisterEndPointsInput,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "elasticloadbalancing", &self.region, "/");
+        request.set_operation("DeregisterInstancesFromLoadBalancer");
         let mut params = Params::new();
 
-        params.put("Action", "DescribeAccountLimits");
+        params.put("Action", "DeregisterInstancesFromLoadBalancer");
         params.put("Version", "2012-06-01");
-        DescribeAccountLimitsInputSerializer::serialize(&mut params, "", &input);
+        DeregisterEndPointsInputSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `describe_account_limits`, also returning the metadata of the response.
+    pub async fn describe_account_limits_with_metadata(
+        &self,
+        input: DescribeAccountLimitsInput,
+    ) -> Result<
+        (DescribeAccountLimitsOutput, ResponseMetadata),
+        RusotoError<DescribeAccountLimitsError>,
+    > {
+        let request = self.build_describe_account_limits_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(DescribeAccountLimitsError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -7004,34 +7708,64 @@ impl Elb for ElbClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Describes the state of the specified instances with respect to the specified load balancer. If no instances are specified, the call describes the state of all instances that are currently registered with the load balancer. If instances are specified, their state is returned even if they are no longer registered with the load balancer. The state of terminated instances is not returned.</p>
-    async fn describe_instance_health(
+    /// Presigns the request `describe_account_limits` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_account_limits(
         &self,
-        input: DescribeEndPointStateInput,
-    ) -> Result<DescribeEndPointStateOutput, RusotoError<DescribeInstanceHealthError>> {
+        presigner: &rusoto_core::Presigner,
+        input: DescribeAccountLimitsInput,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_account_limits_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_account_limits_request(
+        &self,
+        input: DescribeAccountLimitsInput,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "elasticloadbalancing", &self.region, "/");
+        request.set_operation("DescribeAccountLimits");
         let mut params = Params::new();
 
-        params.put("Action", "DescribeInstanceHealth");
+        params.put("Action", "DescribeAccountLimits");
         params.put("Version", "2012-06-01");
-        DescribeEndPointStateInputSerializer::serialize(&mut params, "", &input);
+        DescribeAccountLimitsInputSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `describe_instance_health`, also returning the metadata of the response.
+    pub async fn describe_instance_health_with_metadata(
+        &self,
+        input: DescribeEndPointStateInput,
+    ) -> Result<
+        (DescribeEndPointStateOutput, ResponseMetadata),
+        RusotoError<DescribeInstanceHealthError>,
+    > {
+        let request = self.build_describe_instance_health_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(DescribeInstanceHealthError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -7053,37 +7787,64 @@ impl Elb for ElbClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Describes the attributes for the specified load balancer.</p>
-    async fn describe_load_balancer_attributes(
+    /// Presigns the request `describe_instance_health` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_instance_health(
         &self,
-        input: DescribeLoadBalancerAttributesInput,
-    ) -> Result<
-        DescribeLoadBalancerAttributesOutput,
-        RusotoError<DescribeLoadBalancerAttributesError>,
-    > {
+        presigner: &rusoto_core::Presigner,
+        input: DescribeEndPointStateInput,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_instance_health_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_instance_health_request(
+        &self,
+        input: DescribeEndPointStateInput,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "elasticloadbalancing", &self.region, "/");
+        request.set_operation("DescribeInstanceHealth");
         let mut params = Params::new();
 
-        params.put("Action", "DescribeLoadBalancerAttributes");
+        params.put("Action", "DescribeInstanceHealth");
         params.put("Version", "2012-06-01");
-        DescribeLoadBalancerAttributesInputSerializer::serialize(&mut params, "", &input);
+        DescribeEndPointStateInputSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `describe_load_balancer_attributes`, also returning the metadata of the response.
+    pub async fn describe_load_balancer_attributes_with_metadata(
+        &self,
+        input: DescribeLoadBalancerAttributesInput,
+    ) -> Result<
+        (DescribeLoadBalancerAttributesOutput, ResponseMetadata),
+        RusotoError<DescribeLoadBalancerAttributesError>,
+    > {
+        let request = self.build_describe_load_balancer_attributes_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(DescribeLoadBalancerAttributesError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -7105,35 +7866,64 @@ impl Elb for ElbClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Describes the specified policies.</p> <p>If you specify a load balancer name, the action returns the descriptions of all policies created for the load balancer. If you specify a policy name associated with your load balancer, the action returns the description of that policy. If you don't specify a load balancer name, the action returns descriptions of the specified sample policies, or descriptions of all sample policies. The names of the sample policies have the <code>ELBSample-</code> prefix.</p>
-    async fn describe_load_balancer_policies(
+    /// Presigns the request `describe_load_balancer_attributes` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_load_balancer_attributes(
         &self,
-        input: DescribeLoadBalancerPoliciesInput,
-    ) -> Result<DescribeLoadBalancerPoliciesOutput, RusotoError<DescribeLoadBalancerPoliciesError>>
-    {
+        presigner: &rusoto_core::Presigner,
+        input: DescribeLoadBalancerAttributesInput,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_load_balancer_attributes_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_load_balancer_attributes_request(
+        &self,
+        input: DescribeLoadBalancerAttributesInput,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "elasticloadbalancing", &self.region, "/");
+        request.set_operation("DescribeLoadBalancerAttributes");
         let mut params = Params::new();
 
-        params.put("Action", "DescribeLoadBalancerPolicies");
+        params.put("Action", "DescribeLoadBalancerAttributes");
         params.put("Version", "2012-06-01");
-        DescribeLoadBalancerPoliciesInputSerializer::serialize(&mut params, "", &input);
+        DescribeLoadBalancerAttributesInputSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `describe_load_balancer_policies`, also returning the metadata of the response.
+    pub async fn describe_load_balancer_policies_with_metadata(
+        &self,
+        input: DescribeLoadBalancerPoliciesInput,
+    ) -> Result<
+        (DescribeLoadBalancerPoliciesOutput, ResponseMetadata),
+        RusotoError<DescribeLoadBalancerPoliciesError>,
+    > {
+        let request = self.build_describe_load_balancer_policies_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(DescribeLoadBalancerPoliciesError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -7155,39 +7945,66 @@ impl Elb for ElbClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Describes the specified load balancer policy types or all load balancer policy types.</p> <p>The description of each type indicates how it can be used. For example, some policies can be used only with layer 7 listeners, some policies can be used only with layer 4 listeners, and some policies can be used only with your EC2 instances.</p> <p>You can use <a>CreateLoadBalancerPolicy</a> to create a policy configuration for any of these policy types. Then, depending on the policy type, use either <a>SetLoadBalancerPoliciesOfListener</a> or <a>SetLoadBalancerPoliciesForBackendServer</a> to set the policy.</p>
-    async fn describe_load_balancer_policy_types(
+    /// Presigns the request `describe_load_balancer_policies` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_load_balancer_policies(
         &self,
-        input: DescribeLoadBalancerPolicyTypesInput,
-    ) -> Result<
-        DescribeLoadBalancerPolicyTypesOutput,
-        RusotoError<DescribeLoadBalancerPolicyTypesError>,
-    > {
+        presigner: &rusoto_core::Presigner,
+        input: DescribeLoadBalancerPoliciesInput,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_load_balancer_policies_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_load_balancer_policies_request(
+        &self,
+        input: DescribeLoadBalancerPoliciesInput,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "elasticloadbalancing", &self.region, "/");
+        request.set_operation("DescribeLoadBalancerPolicies");
         let mut params = Params::new();
 
-        params.put("Action", "DescribeLoadBalancerPolicyTypes");
+        params.put("Action", "DescribeLoadBalancerPolicies");
         params.put("Version", "2012-06-01");
-        DescribeLoadBalancerPolicyTypesInputSerializer::serialize(&mut params, "", &input);
+        DescribeLoadBalancerPoliciesInputSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
-        let mut response = self
-            .client
-            .sign_and_dispatch(request)
-            .await
-            .map_err(RusotoError::from)?;
-        if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        request
+    }
+
+    /// Calls `describe_load_balancer_policy_types`, also returning the metadata of the response.
+    pub async fn describe_load_balancer_policy_types_with_metadata(
+        &self,
+        input: DescribeLoadBalancerPolicyTypesInput,
+    ) -> Result<
+        (DescribeLoadBalancerPolicyTypesOutput, ResponseMetadata),
+        RusotoError<DescribeLoadBalancerPolicyTypesError>,
+    > {
+        let request = self.build_describe_load_balancer_policy_types_request(input);
+
+        let mut response = self
+            .client
+            .sign_and_dispatch(request)
+            .await
+            .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
+        if !response.status.is_success() {
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(DescribeLoadBalancerPolicyTypesError::from_response(
                 response,
             ));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -7209,34 +8026,64 @@ impl Elb for ElbClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Describes the specified the load balancers. If no load balancers are specified, the call describes all of your load balancers.</p>
-    async fn describe_load_balancers(
+    /// Presigns the request `describe_load_balancer_policy_types` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_load_balancer_policy_types(
         &self,
-        input: DescribeAccessPointsInput,
-    ) -> Result<DescribeAccessPointsOutput, RusotoError<DescribeLoadBalancersError>> {
+        presigner: &rusoto_core::Presigner,
+        input: DescribeLoadBalancerPolicyTypesInput,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_load_balancer_policy_types_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_load_balancer_policy_types_request(
+        &self,
+        input: DescribeLoadBalancerPolicyTypesInput,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "elasticloadbalancing", &self.region, "/");
+        request.set_operation("DescribeLoadBalancerPolicyTypes");
         let mut params = Params::new();
 
-        params.put("Action", "DescribeLoadBalancers");
+        params.put("Action", "DescribeLoadBalancerPolicyTypes");
         params.put("Version", "2012-06-01");
-        DescribeAccessPointsInputSerializer::serialize(&mut params, "", &input);
+        DescribeLoadBalancerPolicyTypesInputSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `describe_load_balancers`, also returning the metadata of the response.
+    pub async fn describe_load_balancers_with_metadata(
+        &self,
+        input: DescribeAccessPointsInput,
+    ) -> Result<
+        (DescribeAccessPointsOutput, ResponseMetadata),
+        RusotoError<DescribeLoadBalancersError>,
+    > {
+        let request = self.build_describe_load_balancers_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(DescribeLoadBalancersError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -7258,34 +8105,61 @@ impl Elb for ElbClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Describes the tags associated with the specified load balancers.</p>
-    async fn describe_tags(
+    /// Presigns the request `describe_load_balancers` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_load_balancers(
         &self,
-        input: DescribeTagsInput,
-    ) -> Result<DescribeTagsOutput, RusotoError<DescribeTagsError>> {
+        presigner: &rusoto_core::Presigner,
+        input: DescribeAccessPointsInput,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_load_balancers_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_load_balancers_request(
+        &self,
+        input: DescribeAccessPointsInput,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "elasticloadbalancing", &self.region, "/");
+        request.set_operation("DescribeLoadBalancers");
         let mut params = Params::new();
 
-        params.put("Action", "DescribeTags");
+        params.put("Action", "DescribeLoadBalancers");
         params.put("Version", "2012-06-01");
-        DescribeTagsInputSerializer::serialize(&mut params, "", &input);
+        DescribeAccessPointsInputSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `describe_tags`, also returning the metadata of the response.
+    pub async fn describe_tags_with_metadata(
+        &self,
+        input: DescribeTagsInput,
+    ) -> Result<(DescribeTagsOutput, ResponseMetadata), RusotoError<DescribeTagsError>> {
+        let request = self.build_describe_tags_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(DescribeTagsError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -7304,35 +8178,61 @@ impl Elb for ElbClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Removes the specified subnets from the set of configured subnets for the load balancer.</p> <p>After a subnet is removed, all EC2 instances registered with the load balancer in the removed subnet go into the <code>OutOfService</code> state. Then, the load balancer balances the traffic among the remaining routable subnets.</p>
-    async fn detach_load_balancer_from_subnets(
+    /// Presigns the request `describe_tags` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_tags(
         &self,
-        input: DetachLoadBalancerFromSubnetsInput,
-    ) -> Result<DetachLoadBalancerFromSubnetsOutput, RusotoError<DetachLoadBalancerFromSubnetsError>>
-    {
+        presigner: &rusoto_core::Presigner,
+        input: DescribeTagsInput,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_tags_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_tags_request(&self, input: DescribeTagsInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "elasticloadbalancing", &self.region, "/");
+        request.set_operation("DescribeTags");
         let mut params = Params::new();
 
-        params.put("Action", "DetachLoadBalancerFromSubnets");
+        params.put("Action", "DescribeTags");
         params.put("Version", "2012-06-01");
-        DetachLoadBalancerFromSubnetsInputSerializer::serialize(&mut params, "", &input);
+        DescribeTagsInputSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `detach_load_balancer_from_subnets`, also returning the metadata of the response.
+    pub async fn detach_load_balancer_from_subnets_with_metadata(
+        &self,
+        input: DetachLoadBalancerFromSubnetsInput,
+    ) -> Result<
+        (DetachLoadBalancerFromSubnetsOutput, ResponseMetadata),
+        RusotoError<DetachLoadBalancerFromSubnetsError>,
+    > {
+        let request = self.build_detach_load_balancer_from_subnets_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(DetachLoadBalancerFromSubnetsError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -7354,39 +8254,66 @@ impl Elb for ElbClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Removes the specified Availability Zones from the set of Availability Zones for the specified load balancer in EC2-Classic or a default VPC.</p> <p>For load balancers in a non-default VPC, use <a>DetachLoadBalancerFromSubnets</a>.</p> <p>There must be at least one Availability Zone registered with a load balancer at all times. After an Availability Zone is removed, all instances registered with the load balancer that are in the removed Availability Zone go into the <code>OutOfService</code> state. Then, the load balancer attempts to equally balance the traffic among its remaining Availability Zones.</p> <p>For more information, see <a href="http://docs.aws.amazon.com/elasticloadbalancing/latest/classic/enable-disable-az.html">Add or Remove Availability Zones</a> in the <i>Classic Load Balancers Guide</i>.</p>
-    async fn disable_availability_zones_for_load_balancer(
+    /// Presigns the request `detach_load_balancer_from_subnets` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_detach_load_balancer_from_subnets(
         &self,
-        input: RemoveAvailabilityZonesInput,
-    ) -> Result<
-        RemoveAvailabilityZonesOutput,
-        RusotoError<DisableAvailabilityZonesForLoadBalancerError>,
-    > {
+        presigner: &rusoto_core::Presigner,
+        input: DetachLoadBalancerFromSubnetsInput,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_detach_load_balancer_from_subnets_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_detach_load_balancer_from_subnets_request(
+        &self,
+        input: DetachLoadBalancerFromSubnetsInput,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "elasticloadbalancing", &self.region, "/");
+        request.set_operation("DetachLoadBalancerFromSubnets");
         let mut params = Params::new();
 
-        params.put("Action", "DisableAvailabilityZonesForLoadBalancer");
+        params.put("Action", "DetachLoadBalancerFromSubnets");
         params.put("Version", "2012-06-01");
-        RemoveAvailabilityZonesInputSerializer::serialize(&mut params, "", &input);
+        DetachLoadBalancerFromSubnetsInputSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `disable_availability_zones_for_load_balancer`, also returning the metadata of the response.
+    pub async fn disable_availability_zones_for_load_balancer_with_metadata(
+        &self,
+        input: RemoveAvailabilityZonesInput,
+    ) -> Result<
+        (RemoveAvailabilityZonesOutput, ResponseMetadata),
+        RusotoError<DisableAvailabilityZonesForLoadBalancerError>,
+    > {
+        let request = self.build_disable_availability_zones_for_load_balancer_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(DisableAvailabilityZonesForLoadBalancerError::from_response(
                 response,
             ));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -7408,37 +8335,66 @@ impl Elb for ElbClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Adds the specified Availability Zones to the set of Availability Zones for the specified load balancer in EC2-Classic or a default VPC.</p> <p>For load balancers in a non-default VPC, use <a>AttachLoadBalancerToSubnets</a>.</p> <p>The load balancer evenly distributes requests across all its registered Availability Zones that contain instances. For more information, see <a href="http://docs.aws.amazon.com/elasticloadbalancing/latest/classic/enable-disable-az.html">Add or Remove Availability Zones</a> in the <i>Classic Load Balancers Guide</i>.</p>
-    async fn enable_availability_zones_for_load_balancer(
+    /// Presigns the request `disable_availability_zones_for_load_balancer` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_disable_availability_zones_for_load_balancer(
         &self,
-        input: AddAvailabilityZonesInput,
-    ) -> Result<AddAvailabilityZonesOutput, RusotoError<EnableAvailabilityZonesForLoadBalancerError>>
-    {
+        presigner: &rusoto_core::Presigner,
+        input: RemoveAvailabilityZonesInput,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_disable_availability_zones_for_load_balancer_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_disable_availability_zones_for_load_balancer_request(
+        &self,
+        input: RemoveAvailabilityZonesInput,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "elasticloadbalancing", &self.region, "/");
+        request.set_operation("DisableAvailabilityZonesForLoadBalancer");
         let mut params = Params::new();
 
-        params.put("Action", "EnableAvailabilityZonesForLoadBalancer");
+        params.put("Action", "DisableAvailabilityZonesForLoadBalancer");
         params.put("Version", "2012-06-01");
-        AddAvailabilityZonesInputSerializer::serialize(&mut params, "", &input);
+        RemoveAvailabilityZonesInputSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `enable_availability_zones_for_load_balancer`, also returning the metadata of the response.
+    pub async fn enable_availability_zones_for_load_balancer_with_metadata(
+        &self,
+        input: AddAvailabilityZonesInput,
+    ) -> Result<
+        (AddAvailabilityZonesOutput, ResponseMetadata),
+        RusotoError<EnableAvailabilityZonesForLoadBalancerError>,
+    > {
+        let request = self.build_enable_availability_zones_for_load_balancer_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(EnableAvailabilityZonesForLoadBalancerError::from_response(
                 response,
             ));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -7460,35 +8416,64 @@ impl Elb for ElbClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p><p>Modifies the attributes of the specified load balancer.</p> <p>You can modify the load balancer attributes, such as <code>AccessLogs</code>, <code>ConnectionDraining</code>, and <code>CrossZoneLoadBalancing</code> by either enabling or disabling them. Or, you can modify the load balancer attribute <code>ConnectionSettings</code> by specifying an idle connection timeout value for your load balancer.</p> <p>For more information, see the following in the <i>Classic Load Balancers Guide</i>:</p> <ul> <li> <p> <a href="http://docs.aws.amazon.com/elasticloadbalancing/latest/classic/enable-disable-crosszone-lb.html">Cross-Zone Load Balancing</a> </p> </li> <li> <p> <a href="http://docs.aws.amazon.com/elasticloadbalancing/latest/classic/config-conn-drain.html">Connection Draining</a> </p> </li> <li> <p> <a href="http://docs.aws.amazon.com/elasticloadbalancing/latest/classic/access-log-collection.html">Access Logs</a> </p> </li> <li> <p> <a href="http://docs.aws.amazon.com/elasticloadbalancing/latest/classic/config-idle-timeout.html">Idle Connection Timeout</a> </p> </li> </ul></p>
-    async fn modify_load_balancer_attributes(
+    /// Presigns the request `enable_availability_zones_for_load_balancer` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_enable_availability_zones_for_load_balancer(
         &self,
-        input: ModifyLoadBalancerAttributesInput,
-    ) -> Result<ModifyLoadBalancerAttributesOutput, RusotoError<ModifyLoadBalancerAttributesError>>
-    {
+        presigner: &rusoto_core::Presigner,
+        input: AddAvailabilityZonesInput,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_enable_availability_zones_for_load_balancer_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_enable_availability_zones_for_load_balancer_request(
+        &self,
+        input: AddAvailabilityZonesInput,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "elasticloadbalancing", &self.region, "/");
+        request.set_operation("EnableAvailabilityZonesForLoadBalancer");
         let mut params = Params::new();
 
-        params.put("Action", "ModifyLoadBalancerAttributes");
+        params.put("Action", "EnableAvailabilityZonesForLoadBalancer");
         params.put("Version", "2012-06-01");
-        ModifyLoadBalancerAttributesInputSerializer::serialize(&mut params, "", &input);
+        AddAvailabilityZonesInputSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `modify_load_balancer_attributes`, also returning the metadata of the response.
+    pub async fn modify_load_balancer_attributes_with_metadata(
+        &self,
+        input: ModifyLoadBalancerAttributesInput,
+    ) -> Result<
+        (ModifyLoadBalancerAttributesOutput, ResponseMetadata),
+        RusotoError<ModifyLoadBalancerAttributesError>,
+    > {
+        let request = self.build_modify_load_balancer_attributes_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(ModifyLoadBalancerAttributesError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -7510,36 +8495,66 @@ impl Elb for ElbClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Adds the specified instances to the specified load balancer.</p> <p>The instance must be a running instance in the same network as the load balancer (EC2-Classic or the same VPC). If you have EC2-Classic instances and a load balancer in a VPC with ClassicLink enabled, you can link the EC2-Classic instances to that VPC and then register the linked EC2-Classic instances with the load balancer in the VPC.</p> <p>Note that <code>RegisterInstanceWithLoadBalancer</code> completes when the request has been registered. Instance registration takes a little time to complete. To check the state of the registered instances, use <a>DescribeLoadBalancers</a> or <a>DescribeInstanceHealth</a>.</p> <p>After the instance is registered, it starts receiving traffic and requests from the load balancer. Any instance that is not in one of the Availability Zones registered for the load balancer is moved to the <code>OutOfService</code> state. If an Availability Zone is added to the load balancer later, any instances registered with the load balancer move to the <code>InService</code> state.</p> <p>To deregister instances from a load balancer, use <a>DeregisterInstancesFromLoadBalancer</a>.</p> <p>For more information, see <a href="http://docs.aws.amazon.com/elasticloadbalancing/latest/classic/elb-deregister-register-instances.html">Register or De-Register EC2 Instances</a> in the <i>Classic Load Balancers Guide</i>.</p>
-    async fn register_instances_with_load_balancer(
+    /// Presigns the request `modify_load_balancer_attributes` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_modify_load_balancer_attributes(
         &self,
-        input: RegisterEndPointsInput,
-    ) -> Result<RegisterEndPointsOutput, RusotoError<RegisterInstancesWithLoadBalancerError>> {
+        presigner: &rusoto_core::Presigner,
+        input: ModifyLoadBalancerAttributesInput,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_modify_load_balancer_attributes_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_modify_load_balancer_attributes_request(
+        &self,
+        input: ModifyLoadBalancerAttributesInput,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "elasticloadbalancing", &self.region, "/");
+        request.set_operation("ModifyLoadBalancerAttributes");
         let mut params = Params::new();
 
-        params.put("Action", "RegisterInstancesWithLoadBalancer");
+        params.put("Action", "ModifyLoadBalancerAttributes");
         params.put("Version", "2012-06-01");
-        RegisterEndPointsInputSerializer::serialize(&mut params, "", &input);
+        ModifyLoadBalancerAttributesInputSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `register_instances_with_load_balancer`, also returning the metadata of the response.
+    pub async fn register_instances_with_load_balancer_with_metadata(
+        &self,
+        input: RegisterEndPointsInput,
+    ) -> Result<
+        (RegisterEndPointsOutput, ResponseMetadata),
+        RusotoError<RegisterInstancesWithLoadBalancerError>,
+    > {
+        let request = self.build_register_instances_with_load_balancer_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(RegisterInstancesWithLoadBalancerError::from_response(
                 response,
             ));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -7561,34 +8576,61 @@ impl Elb for ElbClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Removes one or more tags from the specified load balancer.</p>
-    async fn remove_tags(
+    /// Presigns the request `register_instances_with_load_balancer` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_register_instances_with_load_balancer(
         &self,
-        input: RemoveTagsInput,
-    ) -> Result<RemoveTagsOutput, RusotoError<RemoveTagsError>> {
+        presigner: &rusoto_core::Presigner,
+        input: RegisterEndPointsInput,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_register_instances_with_load_balancer_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_register_instances_with_load_balancer_request(
+        &self,
+        input: RegisterEndPointsInput,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "elasticloadbalancing", &self.region, "/");
+        request.set_operation("RegisterInstancesWithLoadBalancer");
         let mut params = Params::new();
 
-        params.put("Action", "RemoveTags");
+        params.put("Action", "RegisterInstancesWithLoadBalancer");
         params.put("Version", "2012-06-01");
-        RemoveTagsInputSerializer::serialize(&mut params, "", &input);
+        RegisterEndPointsInputSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `remove_tags`, also returning the metadata of the response.
+    pub async fn remove_tags_with_metadata(
+        &self,
+        input: RemoveTagsInput,
+    ) -> Result<(RemoveTagsOutput, ResponseMetadata), RusotoError<RemoveTagsError>> {
+        let request = self.build_remove_tags_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(RemoveTagsError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -7607,39 +8649,66 @@ impl Elb for ElbClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Sets the certificate that terminates the specified listener's SSL connections. The specified certificate replaces any prior certificate that was used on the same load balancer and port.</p> <p>For more information about updating your SSL certificate, see <a href="http://docs.aws.amazon.com/elasticloadbalancing/latest/classic/elb-update-ssl-cert.html">Replace the SSL Certificate for Your Load Balancer</a> in the <i>Classic Load Balancers Guide</i>.</p>
-    async fn set_load_balancer_listener_ssl_certificate(
+    /// Presigns the request `remove_tags` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_remove_tags(
         &self,
-        input: SetLoadBalancerListenerSSLCertificateInput,
-    ) -> Result<
-        SetLoadBalancerListenerSSLCertificateOutput,
-        RusotoError<SetLoadBalancerListenerSSLCertificateError>,
-    > {
+        presigner: &rusoto_core::Presigner,
+        input: RemoveTagsInput,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_remove_tags_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_remove_tags_request(&self, input: RemoveTagsInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "elasticloadbalancing", &self.region, "/");
+        request.set_operation("RemoveTags");
         let mut params = Params::new();
 
-        params.put("Action", "SetLoadBalancerListenerSSLCertificate");
+        params.put("Action", "RemoveTags");
         params.put("Version", "2012-06-01");
-        SetLoadBalancerListenerSSLCertificateInputSerializer::serialize(&mut params, "", &input);
+        RemoveTagsInputSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `set_load_balancer_listener_ssl_certificate`, also returning the metadata of the response.
+    pub async fn set_load_balancer_listener_ssl_certificate_with_metadata(
+        &self,
+        input: SetLoadBalancerListenerSSLCertificateInput,
+    ) -> Result<
+        (
+            SetLoadBalancerListenerSSLCertificateOutput,
+            ResponseMetadata,
+        ),
+        RusotoError<SetLoadBalancerListenerSSLCertificateError>,
+    > {
+        let request = self.build_set_load_balancer_listener_ssl_certificate_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(SetLoadBalancerListenerSSLCertificateError::from_response(
                 response,
             ));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -7661,39 +8730,69 @@ impl Elb for ElbClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Replaces the set of policies associated with the specified port on which the EC2 instance is listening with a new set of policies. At this time, only the back-end server authentication policy type can be applied to the instance ports; this policy type is composed of multiple public key policies.</p> <p>Each time you use <code>SetLoadBalancerPoliciesForBackendServer</code> to enable the policies, use the <code>PolicyNames</code> parameter to list the policies that you want to enable.</p> <p>You can use <a>DescribeLoadBalancers</a> or <a>DescribeLoadBalancerPolicies</a> to verify that the policy is associated with the EC2 instance.</p> <p>For more information about enabling back-end instance authentication, see <a href="http://docs.aws.amazon.com/elasticloadbalancing/latest/classic/elb-create-https-ssl-load-balancer.html#configure_backendauth_clt">Configure Back-end Instance Authentication</a> in the <i>Classic Load Balancers Guide</i>. For more information about Proxy Protocol, see <a href="http://docs.aws.amazon.com/elasticloadbalancing/latest/classic/enable-proxy-protocol.html">Configure Proxy Protocol Support</a> in the <i>Classic Load Balancers Guide</i>.</p>
-    async fn set_load_balancer_policies_for_backend_server(
+    /// Presigns the request `set_load_balancer_listener_ssl_certificate` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_set_load_balancer_listener_ssl_certificate(
         &self,
-        input: SetLoadBalancerPoliciesForBackendServerInput,
-    ) -> Result<
-        SetLoadBalancerPoliciesForBackendServerOutput,
-        RusotoError<SetLoadBalancerPoliciesForBackendServerError>,
-    > {
+        presigner: &rusoto_core::Presigner,
+        input: SetLoadBalancerListenerSSLCertificateInput,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_set_load_balancer_listener_ssl_certificate_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_set_load_balancer_listener_ssl_certificate_request(
+        &self,
+        input: SetLoadBalancerListenerSSLCertificateInput,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "elasticloadbalancing", &self.region, "/");
+        request.set_operation("SetLoadBalancerListenerSSLCertificate");
         let mut params = Params::new();
 
-        params.put("Action", "SetLoadBalancerPoliciesForBackendServer");
+        params.put("Action", "SetLoadBalancerListenerSSLCertificate");
         params.put("Version", "2012-06-01");
-        SetLoadBalancerPoliciesForBackendServerInputSerializer::serialize(&mut params, "", &input);
+        SetLoadBalancerListenerSSLCertificateInputSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `set_load_balancer_policies_for_backend_server`, also returning the metadata of the response.
+    pub async fn set_load_balancer_policies_for_backend_server_with_metadata(
+        &self,
+        input: SetLoadBalancerPoliciesForBackendServerInput,
+    ) -> Result<
+        (
+            SetLoadBalancerPoliciesForBackendServerOutput,
+            ResponseMetadata,
+        ),
+        RusotoError<SetLoadBalancerPoliciesForBackendServerError>,
+    > {
+        let request = self.build_set_load_balancer_policies_for_backend_server_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(SetLoadBalancerPoliciesForBackendServerError::from_response(
                 response,
             ));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -7715,39 +8814,66 @@ impl Elb for ElbClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Replaces the current set of policies for the specified load balancer port with the specified set of policies.</p> <p>To enable back-end server authentication, use <a>SetLoadBalancerPoliciesForBackendServer</a>.</p> <p>For more information about setting policies, see <a href="http://docs.aws.amazon.com/elasticloadbalancing/latest/classic/ssl-config-update.html">Update the SSL Negotiation Configuration</a>, <a href="http://docs.aws.amazon.com/elasticloadbalancing/latest/classic/elb-sticky-sessions.html#enable-sticky-sessions-duration">Duration-Based Session Stickiness</a>, and <a href="http://docs.aws.amazon.com/elasticloadbalancing/latest/classic/elb-sticky-sessions.html#enable-sticky-sessions-application">Application-Controlled Session Stickiness</a> in the <i>Classic Load Balancers Guide</i>.</p>
-    async fn set_load_balancer_policies_of_listener(
+    /// Presigns the request `set_load_balancer_policies_for_backend_server` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_set_load_balancer_policies_for_backend_server(
         &self,
-        input: SetLoadBalancerPoliciesOfListenerInput,
-    ) -> Result<
-        SetLoadBalancerPoliciesOfListenerOutput,
-        RusotoError<SetLoadBalancerPoliciesOfListenerError>,
-    > {
+        presigner: &rusoto_core::Presigner,
+        input: SetLoadBalancerPoliciesForBackendServerInput,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_set_load_balancer_policies_for_backend_server_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_set_load_balancer_policies_for_backend_server_request(
+        &self,
+        input: SetLoadBalancerPoliciesForBackendServerInput,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "elasticloadbalancing", &self.region, "/");
+        request.set_operation("SetLoadBalancerPoliciesForBackendServer");
         let mut params = Params::new();
 
-        params.put("Action", "SetLoadBalancerPoliciesOfListener");
+        params.put("Action", "SetLoadBalancerPoliciesForBackendServer");
         params.put("Version", "2012-06-01");
-        SetLoadBalancerPoliciesOfListenerInputSerializer::serialize(&mut params, "", &input);
+        SetLoadBalancerPoliciesForBackendServerInputSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `set_load_balancer_policies_of_listener`, also returning the metadata of the response.
+    pub async fn set_load_balancer_policies_of_listener_with_metadata(
+        &self,
+        input: SetLoadBalancerPoliciesOfListenerInput,
+    ) -> Result<
+        (SetLoadBalancerPoliciesOfListenerOutput, ResponseMetadata),
+        RusotoError<SetLoadBalancerPoliciesOfListenerError>,
+    > {
+        let request = self.build_set_load_balancer_policies_of_listener_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(SetLoadBalancerPoliciesOfListenerError::from_response(
                 response,
             ));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -7769,7 +8895,569 @@ impl Elb for ElbClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
+    }
+
+    /// Presigns the request `set_load_balancer_policies_of_listener` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_set_load_balancer_policies_of_listener(
+        &self,
+        presigner: &rusoto_core::Presigner,
+        input: SetLoadBalancerPoliciesOfListenerInput,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_set_load_balancer_policies_of_listener_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_set_load_balancer_policies_of_listener_request(
+        &self,
+        input: SetLoadBalancerPoliciesOfListenerInput,
+    ) -> SignedRequest {
+        let mut request = SignedRequest::new("POST", "elasticloadbalancing", &self.region, "/");
+        request.set_operation("SetLoadBalancerPoliciesOfListener");
+        let mut params = Params::new();
+
+        params.put("Action", "SetLoadBalancerPoliciesOfListener");
+        params.put("Version", "2012-06-01");
+        SetLoadBalancerPoliciesOfListenerInputSerializer::serialize(&mut params, "", &input);
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        request
+    }
+
+    /// The pages of `DescribeAccountLimits` results for `input`, requesting each next page with the `NextMarker` of the last one as its `Marker`.
+    /// The page size is the `PageSize` of `input`.
+    pub fn describe_account_limits_pages(
+        &self,
+        input: DescribeAccountLimitsInput,
+    ) -> impl futures::Stream<
+        Item = Result<DescribeAccountLimitsOutput, RusotoError<DescribeAccountLimitsError>>,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: ElbClient, input| async move { client.describe_account_limits(input).await },
+            |input: &DescribeAccountLimitsInput, output: &DescribeAccountLimitsOutput| {
+                let marker = output.next_marker.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if marker.is_none() || marker == input.marker {
+                    return None;
+                }
+                Some(DescribeAccountLimitsInput {
+                    marker,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `Limits` of every page of `DescribeAccountLimits` results for `input`, as `describe_account_limits_pages` requests them.
+    pub fn describe_account_limits_stream(
+        &self,
+        input: DescribeAccountLimitsInput,
+    ) -> impl futures::Stream<Item = Result<Limit, RusotoError<DescribeAccountLimitsError>>> + Send
+    {
+        rusoto_core::pagination::items(self.describe_account_limits_pages(input), |page| {
+            page.limits.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `DescribeLoadBalancers` results for `input`, requesting each next page with the `NextMarker` of the last one as its `Marker`.
+    /// The page size is the `PageSize` of `input`.
+    pub fn describe_load_balancers_pages(
+        &self,
+        input: DescribeAccessPointsInput,
+    ) -> impl futures::Stream<
+        Item = Result<DescribeAccessPointsOutput, RusotoError<DescribeLoadBalancersError>>,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: ElbClient, input| async move { client.describe_load_balancers(input).await },
+            |input: &DescribeAccessPointsInput, output: &DescribeAccessPointsOutput| {
+                let marker = output.next_marker.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if marker.is_none() || marker == input.marker {
+                    return None;
+                }
+                Some(DescribeAccessPointsInput {
+                    marker,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `LoadBalancerDescriptions` of every page of `DescribeLoadBalancers` results for `input`, as `describe_load_balancers_pages` requests them.
+    pub fn describe_load_balancers_stream(
+        &self,
+        input: DescribeAccessPointsInput,
+    ) -> impl futures::Stream<
+        Item = Result<LoadBalancerDescription, RusotoError<DescribeLoadBalancersError>>,
+    > + Send {
+        rusoto_core::pagination::items(self.describe_load_balancers_pages(input), |page| {
+            page.load_balancer_descriptions.unwrap_or_default()
+        })
+    }
+
+    /// Waits for `AnyInstanceInService`, polling `DescribeInstanceHealth` with `input` every 15 seconds, up to 40 times.
+    pub async fn wait_until_any_instance_in_service(
+        &self,
+        input: DescribeEndPointStateInput,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<DescribeInstanceHealthError>> {
+        let waiter = rusoto_core::waiter::Waiter::new(std::time::Duration::from_secs(15), 40);
+        self.wait_until_any_instance_in_service_with(input, &waiter)
+            .await
+    }
+
+    /// Waits for `AnyInstanceInService` like `wait_until_any_instance_in_service`, with the delay, attempts and runtime of `waiter`.
+    pub async fn wait_until_any_instance_in_service_with(
+        &self,
+        input: DescribeEndPointStateInput,
+        waiter: &rusoto_core::waiter::Waiter,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<DescribeInstanceHealthError>> {
+        rusoto_core::waiter::wait(
+            waiter,
+            || self.describe_instance_health(input.clone()),
+            |result| {
+                if match result {
+                    Ok(output) => std::iter::once(output)
+                        .filter_map(|value| value.instance_states.as_ref())
+                        .flat_map(|value| value.iter())
+                        .filter_map(|value| value.state.as_ref())
+                        .any(|value| value == "InService"),
+                    Err(_) => false,
+                } {
+                    return Some(rusoto_core::waiter::WaiterState::Success);
+                }
+                None
+            },
+        )
+        .await
+    }
+
+    /// Waits for `InstanceDeregistered`, polling `DescribeInstanceHealth` with `input` every 15 seconds, up to 40 times.
+    pub async fn wait_until_instance_deregistered(
+        &self,
+        input: DescribeEndPointStateInput,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<DescribeInstanceHealthError>> {
+        let waiter = rusoto_core::waiter::Waiter::new(std::time::Duration::from_secs(15), 40);
+        self.wait_until_instance_deregistered_with(input, &waiter)
+            .await
+    }
+
+    /// Waits for `InstanceDeregistered` like `wait_until_instance_deregistered`, with the delay, attempts and runtime of `waiter`.
+    pub async fn wait_until_instance_deregistered_with(
+        &self,
+        input: DescribeEndPointStateInput,
+        waiter: &rusoto_core::waiter::Waiter,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<DescribeInstanceHealthError>> {
+        rusoto_core::waiter::wait(
+            waiter,
+            || self.describe_instance_health(input.clone()),
+            |result| {
+                if match result {
+                    Ok(output) => {
+                        let mut values = std::iter::once(output)
+                            .filter_map(|value| value.instance_states.as_ref())
+                            .flat_map(|value| value.iter())
+                            .filter_map(|value| value.state.as_ref())
+                            .peekable();
+                        values.peek().is_some() && values.all(|value| value == "OutOfService")
+                    }
+                    Err(_) => false,
+                } {
+                    return Some(rusoto_core::waiter::WaiterState::Success);
+                }
+                if result
+                    .as_ref()
+                    .err()
+                    .and_then(|error| error.error_code())
+                    .as_deref()
+                    == Some("InvalidInstance")
+                {
+                    return Some(rusoto_core::waiter::WaiterState::Success);
+                }
+                None
+            },
+        )
+        .await
+    }
+
+    /// Waits for `InstanceInService`, polling `DescribeInstanceHealth` with `input` every 15 seconds, up to 40 times.
+    pub async fn wait_until_instance_in_service(
+        &self,
+        input: DescribeEndPointStateInput,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<DescribeInstanceHealthError>> {
+        let waiter = rusoto_core::waiter::Waiter::new(std::time::Duration::from_secs(15), 40);
+        self.wait_until_instance_in_service_with(input, &waiter)
+            .await
+    }
+
+    /// Waits for `InstanceInService` like `wait_until_instance_in_service`, with the delay, attempts and runtime of `waiter`.
+    pub async fn wait_until_instance_in_service_with(
+        &self,
+        input: DescribeEndPointStateInput,
+        waiter: &rusoto_core::waiter::Waiter,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<DescribeInstanceHealthError>> {
+        rusoto_core::waiter::wait(
+            waiter,
+            || self.describe_instance_health(input.clone()),
+            |result| {
+                if match result {
+                    Ok(output) => {
+                        let mut values = std::iter::once(output)
+                            .filter_map(|value| value.instance_states.as_ref())
+                            .flat_map(|value| value.iter())
+                            .filter_map(|value| value.state.as_ref())
+                            .peekable();
+                        values.peek().is_some() && values.all(|value| value == "InService")
+                    }
+                    Err(_) => false,
+                } {
+                    return Some(rusoto_core::waiter::WaiterState::Success);
+                }
+                if result
+                    .as_ref()
+                    .err()
+                    .and_then(|error| error.error_code())
+                    .as_deref()
+                    == Some("InvalidInstance")
+                {
+                    return Some(rusoto_core::waiter::WaiterState::Retry);
+                }
+                None
+            },
+        )
+        .await
+    }
+}
+
+#[async_trait]
+impl Elb for ElbClient {
+    /// <p>Adds the specified tags to the specified load balancer. Each load balancer can have a maximum of 10 tags.</p> <p>Each tag consists of a key and an optional value. If a tag with the same key is already associated with the load balancer, <code>AddTags</code> updates its value.</p> <p>For more information, see <a href="http://docs.aws.amazon.com/elasticloadbalancing/latest/classic/add-remove-tags.html">Tag Your Classic Load Balancer</a> in the <i>Classic Load Balancers Guide</i>.</p>
+    async fn add_tags(
+        &self,
+        input: AddTagsInput,
+    ) -> Result<AddTagsOutput, RusotoError<AddTagsError>> {
+        self.add_tags_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Associates one or more security groups with your load balancer in a virtual private cloud (VPC). The specified security groups override the previously associated security groups.</p> <p>For more information, see <a href="http://docs.aws.amazon.com/elasticloadbalancing/latest/classic/elb-security-groups.html#elb-vpc-security-groups">Security Groups for Load Balancers in a VPC</a> in the <i>Classic Load Balancers Guide</i>.</p>
+    async fn apply_security_groups_to_load_balancer(
+        &self,
+        input: ApplySecurityGroupsToLoadBalancerInput,
+    ) -> Result<
+        ApplySecurityGroupsToLoadBalancerOutput,
+        RusotoError<ApplySecurityGroupsToLoadBalancerError>,
+    > {
+        self.apply_security_groups_to_load_balancer_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Adds one or more subnets to the set of configured subnets for the specified load balancer.</p> <p>The load balancer evenly distributes requests across all registered subnets. For more information, see <a href="http://docs.aws.amazon.com/elasticloadbalancing/latest/classic/elb-manage-subnets.html">Add or Remove Subnets for Your Load Balancer in a VPC</a> in the <i>Classic Load Balancers Guide</i>.</p>
+    async fn attach_load_balancer_to_subnets(
+        &self,
+        input: AttachLoadBalancerToSubnetsInput,
+    ) -> Result<AttachLoadBalancerToSubnetsOutput, RusotoError<AttachLoadBalancerToSubnetsError>>
+    {
+        self.attach_load_balancer_to_subnets_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Specifies the health check settings to use when evaluating the health state of your EC2 instances.</p> <p>For more information, see <a href="http://docs.aws.amazon.com/elasticloadbalancing/latest/classic/elb-healthchecks.html">Configure Health Checks for Your Load Balancer</a> in the <i>Classic Load Balancers Guide</i>.</p>
+    async fn configure_health_check(
+        &self,
+        input: ConfigureHealthCheckInput,
+    ) -> Result<ConfigureHealthCheckOutput, RusotoError<ConfigureHealthCheckError>> {
+        self.configure_health_check_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Generates a stickiness policy with sticky session lifetimes that follow that of an application-generated cookie. This policy can be associated only with HTTP/HTTPS listeners.</p> <p>This policy is similar to the policy created by <a>CreateLBCookieStickinessPolicy</a>, except that the lifetime of the special Elastic Load Balancing cookie, <code>AWSELB</code>, follows the lifetime of the application-generated cookie specified in the policy configuration. The load balancer only inserts a new stickiness cookie when the application response includes a new application cookie.</p> <p>If the application cookie is explicitly removed or expires, the session stops being sticky until a new application cookie is issued.</p> <p>For more information, see <a href="http://docs.aws.amazon.com/elasticloadbalancing/latest/classic/elb-sticky-sessions.html#enable-sticky-sessions-application">Application-Controlled Session Stickiness</a> in the <i>Classic Load Balancers Guide</i>.</p>
+    async fn create_app_cookie_stickiness_policy(
+        &self,
+        input: CreateAppCookieStickinessPolicyInput,
+    ) -> Result<
+        CreateAppCookieStickinessPolicyOutput,
+        RusotoError<CreateAppCookieStickinessPolicyError>,
+    > {
+        self.create_app_cookie_stickiness_policy_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Generates a stickiness policy with sticky session lifetimes controlled by the lifetime of the browser (user-agent) or a specified expiration period. This policy can be associated only with HTTP/HTTPS listeners.</p> <p>When a load balancer implements this policy, the load balancer uses a special cookie to track the instance for each request. When the load balancer receives a request, it first checks to see if this cookie is present in the request. If so, the load balancer sends the request to the application server specified in the cookie. If not, the load balancer sends the request to a server that is chosen based on the existing load-balancing algorithm.</p> <p>A cookie is inserted into the response for binding subsequent requests from the same user to that server. The validity of the cookie is based on the cookie expiration time, which is specified in the policy configuration.</p> <p>For more information, see <a href="http://docs.aws.amazon.com/elasticloadbalancing/latest/classic/elb-sticky-sessions.html#enable-sticky-sessions-duration">Duration-Based Session Stickiness</a> in the <i>Classic Load Balancers Guide</i>.</p>
+    async fn create_lb_cookie_stickiness_policy(
+        &self,
+        input: CreateLBCookieStickinessPolicyInput,
+    ) -> Result<
+        CreateLBCookieStickinessPolicyOutput,
+        RusotoError<CreateLBCookieStickinessPolicyError>,
+    > {
+        self.create_lb_cookie_stickiness_policy_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Creates a Classic Load Balancer.</p> <p>You can add listeners, security groups, subnets, and tags when you create your load balancer, or you can add them later using <a>CreateLoadBalancerListeners</a>, <a>ApplySecurityGroupsToLoadBalancer</a>, <a>AttachLoadBalancerToSubnets</a>, and <a>AddTags</a>.</p> <p>To describe your current load balancers, see <a>DescribeLoadBalancers</a>. When you are finished with a load balancer, you can delete it using <a>DeleteLoadBalancer</a>.</p> <p>You can create up to 20 load balancers per region per account. You can request an increase for the number of load balancers for your account. For more information, see <a href="http://docs.aws.amazon.com/elasticloadbalancing/latest/classic/elb-limits.html">Limits for Your Classic Load Balancer</a> in the <i>Classic Load Balancers Guide</i>.</p>
+    async fn create_load_balancer(
+        &self,
+        input: CreateAccessPointInput,
+    ) -> Result<CreateAccessPointOutput, RusotoError<CreateLoadBalancerError>> {
+        self.create_load_balancer_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Creates one or more listeners for the specified load balancer. If a listener with the specified port does not already exist, it is created; otherwise, the properties of the new listener must match the properties of the existing listener.</p> <p>For more information, see <a href="http://docs.aws.amazon.com/elasticloadbalancing/latest/classic/elb-listener-config.html">Listeners for Your Classic Load Balancer</a> in the <i>Classic Load Balancers Guide</i>.</p>
+    async fn create_load_balancer_listeners(
+        &self,
+        input: CreateLoadBalancerListenerInput,
+    ) -> Result<CreateLoadBalancerListenerOutput, RusotoError<CreateLoadBalancerListenersError>>
+    {
+        self.create_load_balancer_listeners_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Creates a policy with the specified attributes for the specified load balancer.</p> <p>Policies are settings that are saved for your load balancer and that can be applied to the listener or the application server, depending on the policy type.</p>
+    async fn create_load_balancer_policy(
+        &self,
+        input: CreateLoadBalancerPolicyInput,
+    ) -> Result<CreateLoadBalancerPolicyOutput, RusotoError<CreateLoadBalancerPolicyError>> {
+        self.create_load_balancer_policy_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes the specified load balancer.</p> <p>If you are attempting to recreate a load balancer, you must reconfigure all settings. The DNS name associated with a deleted load balancer are no longer usable. The name and associated DNS record of the deleted load balancer no longer exist and traffic sent to any of its IP addresses is no longer delivered to your instances.</p> <p>If the load balancer does not exist or has already been deleted, the call to <code>DeleteLoadBalancer</code> still succeeds.</p>
+    async fn delete_load_balancer(
+        &self,
+        input: DeleteAccessPointInput,
+    ) -> Result<DeleteAccessPointOutput, RusotoError<DeleteLoadBalancerError>> {
+        self.delete_load_balancer_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes the specified listeners from the specified load balancer.</p>
+    async fn delete_load_balancer_listeners(
+        &self,
+        input: DeleteLoadBalancerListenerInput,
+    ) -> Result<DeleteLoadBalancerListenerOutput, RusotoError<DeleteLoadBalancerListenersError>>
+    {
+        self.delete_load_balancer_listeners_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes the specified policy from the specified load balancer. This policy must not be enabled for any listeners.</p>
+    async fn delete_load_balancer_policy(
+        &self,
+        input: DeleteLoadBalancerPolicyInput,
+    ) -> Result<DeleteLoadBalancerPolicyOutput, RusotoError<DeleteLoadBalancerPolicyError>> {
+        self.delete_load_balancer_policy_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deregisters the specified instances from the specified load balancer. After the instance is deregistered, it no longer receives traffic from the load balancer.</p> <p>You can use <a>DescribeLoadBalancers</a> to verify that the instance is deregistered from the load balancer.</p> <p>For more information, see <a href="http://docs.aws.amazon.com/elasticloadbalancing/latest/classic/elb-deregister-register-instances.html">Register or De-Register EC2 Instances</a> in the <i>Classic Load Balancers Guide</i>.</p>
+    async fn deregister_instances_from_load_balancer(
+        &self,
+        input: DeregisterEndPointsInput,
+    ) -> Result<DeregisterEndPointsOutput, RusotoError<DeregisterInstancesFromLoadBalancerError>>
+    {
+        self.deregister_instances_from_load_balancer_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Describes the current Elastic Load Balancing resource limits for your AWS account.</p> <p>For more information, see <a href="http://docs.aws.amazon.com/elasticloadbalancing/latest/classic/elb-limits.html">Limits for Your Classic Load Balancer</a> in the <i>Classic Load Balancers Guide</i>.</p>
+    async fn describe_account_limits(
+        &self,
+        input: DescribeAccountLimitsInput,
+    ) -> Result<DescribeAccountLimitsOutput, RusotoError<DescribeAccountLimitsError>> {
+        self.describe_account_limits_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Describes the state of the specified instances with respect to the specified load balancer. If no instances are specified, the call describes the state of all instances that are currently registered with the load balancer. If instances are specified, their state is returned even if they are no longer registered with the load balancer. The state of terminated instances is not returned.</p>
+    async fn describe_instance_health(
+        &self,
+        input: DescribeEndPointStateInput,
+    ) -> Result<DescribeEndPointStateOutput, RusotoError<DescribeInstanceHealthError>> {
+        self.describe_instance_health_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Describes the attributes for the specified load balancer.</p>
+    async fn describe_load_balancer_attributes(
+        &self,
+        input: DescribeLoadBalancerAttributesInput,
+    ) -> Result<
+        DescribeLoadBalancerAttributesOutput,
+        RusotoError<DescribeLoadBalancerAttributesError>,
+    > {
+        self.describe_load_balancer_attributes_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Describes the specified policies.</p> <p>If you specify a load balancer name, the action returns the descriptions of all policies created for the load balancer. If you specify a policy name associated with your load balancer, the action returns the description of that policy. If you don't specify a load balancer name, the action returns descriptions of the specified sample policies, or descriptions of all sample policies. The names of the sample policies have the <code>ELBSample-</code> prefix.</p>
+    async fn describe_load_balancer_policies(
+        &self,
+        input: DescribeLoadBalancerPoliciesInput,
+    ) -> Result<DescribeLoadBalancerPoliciesOutput, RusotoError<DescribeLoadBalancerPoliciesError>>
+    {
+        self.describe_load_balancer_policies_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Describes the specified load balancer policy types or all load balancer policy types.</p> <p>The description of each type indicates how it can be used. For example, some policies can be used only with layer 7 listeners, some policies can be used only with layer 4 listeners, and some policies can be used only with your EC2 instances.</p> <p>You can use <a>CreateLoadBalancerPolicy</a> to create a policy configuration for any of these policy types. Then, depending on the policy type, use either <a>SetLoadBalancerPoliciesOfListener</a> or <a>SetLoadBalancerPoliciesForBackendServer</a> to set the policy.</p>
+    async fn describe_load_balancer_policy_types(
+        &self,
+        input: DescribeLoadBalancerPolicyTypesInput,
+    ) -> Result<
+        DescribeLoadBalancerPolicyTypesOutput,
+        RusotoError<DescribeLoadBalancerPolicyTypesError>,
+    > {
+        self.describe_load_balancer_policy_types_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Describes the specified the load balancers. If no load balancers are specified, the call describes all of your load balancers.</p>
+    async fn describe_load_balancers(
+        &self,
+        input: DescribeAccessPointsInput,
+    ) -> Result<DescribeAccessPointsOutput, RusotoError<DescribeLoadBalancersError>> {
+        self.describe_load_balancers_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Describes the tags associated with the specified load balancers.</p>
+    async fn describe_tags(
+        &self,
+        input: DescribeTagsInput,
+    ) -> Result<DescribeTagsOutput, RusotoError<DescribeTagsError>> {
+        self.describe_tags_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Removes the specified subnets from the set of configured subnets for the load balancer.</p> <p>After a subnet is removed, all EC2 instances registered with the load balancer in the removed subnet go into the <code>OutOfService</code> state. Then, the load balancer balances the traffic among the remaining routable subnets.</p>
+    async fn detach_load_balancer_from_subnets(
+        &self,
+        input: DetachLoadBalancerFromSubnetsInput,
+    ) -> Result<DetachLoadBalancerFromSubnetsOutput, RusotoError<DetachLoadBalancerFromSubnetsError>>
+    {
+        self.detach_load_balancer_from_subnets_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Removes the specified Availability Zones from the set of Availability Zones for the specified load balancer in EC2-Classic or a default VPC.</p> <p>For load balancers in a non-default VPC, use <a>DetachLoadBalancerFromSubnets</a>.</p> <p>There must be at least one Availability Zone registered with a load balancer at all times. After an Availability Zone is removed, all instances registered with the load balancer that are in the removed Availability Zone go into the <code>OutOfService</code> state. Then, the load balancer attempts to equally balance the traffic among its remaining Availability Zones.</p> <p>For more information, see <a href="http://docs.aws.amazon.com/elasticloadbalancing/latest/classic/enable-disable-az.html">Add or Remove Availability Zones</a> in the <i>Classic Load Balancers Guide</i>.</p>
+    async fn disable_availability_zones_for_load_balancer(
+        &self,
+        input: RemoveAvailabilityZonesInput,
+    ) -> Result<
+        RemoveAvailabilityZonesOutput,
+        RusotoError<DisableAvailabilityZonesForLoadBalancerError>,
+    > {
+        self.disable_availability_zones_for_load_balancer_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Adds the specified Availability Zones to the set of Availability Zones for the specified load balancer in EC2-Classic or a default VPC.</p> <p>For load balancers in a non-default VPC, use <a>AttachLoadBalancerToSubnets</a>.</p> <p>The load balancer evenly distributes requests across all its registered Availability Zones that contain instances. For more information, see <a href="http://docs.aws.amazon.com/elasticloadbalancing/latest/classic/enable-disable-az.html">Add or Remove Availability Zones</a> in the <i>Classic Load Balancers Guide</i>.</p>
+    async fn enable_availability_zones_for_load_balancer(
+        &self,
+        input: AddAvailabilityZonesInput,
+    ) -> Result<AddAvailabilityZonesOutput, RusotoError<EnableAvailabilityZonesForLoadBalancerError>>
+    {
+        self.enable_availability_zones_for_load_balancer_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Modifies the attributes of the specified load balancer.</p> <p>You can modify the load balancer attributes, such as <code>AccessLogs</code>, <code>ConnectionDraining</code>, and <code>CrossZoneLoadBalancing</code> by either enabling or disabling them. Or, you can modify the load balancer attribute <code>ConnectionSettings</code> by specifying an idle connection timeout value for your load balancer.</p> <p>For more information, see the following in the <i>Classic Load Balancers Guide</i>:</p> <ul> <li> <p> <a href="http://docs.aws.amazon.com/elasticloadbalancing/latest/classic/enable-disable-crosszone-lb.html">Cross-Zone Load Balancing</a> </p> </li> <li> <p> <a href="http://docs.aws.amazon.com/elasticloadbalancing/latest/classic/config-conn-drain.html">Connection Draining</a> </p> </li> <li> <p> <a href="http://docs.aws.amazon.com/elasticloadbalancing/latest/classic/access-log-collection.html">Access Logs</a> </p> </li> <li> <p> <a href="http://docs.aws.amazon.com/elasticloadbalancing/latest/classic/config-idle-timeout.html">Idle Connection Timeout</a> </p> </li> </ul></p>
+    async fn modify_load_balancer_attributes(
+        &self,
+        input: ModifyLoadBalancerAttributesInput,
+    ) -> Result<ModifyLoadBalancerAttributesOutput, RusotoError<ModifyLoadBalancerAttributesError>>
+    {
+        self.modify_load_balancer_attributes_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Adds the specified instances to the specified load balancer.</p> <p>The instance must be a running instance in the same network as the load balancer (EC2-Classic or the same VPC). If you have EC2-Classic instances and a load balancer in a VPC with ClassicLink enabled, you can link the EC2-Classic instances to that VPC and then register the linked EC2-Classic instances with the load balancer in the VPC.</p> <p>Note that <code>RegisterInstanceWithLoadBalancer</code> completes when the request has been registered. Instance registration takes a little time to complete. To check the state of the registered instances, use <a>DescribeLoadBalancers</a> or <a>DescribeInstanceHealth</a>.</p> <p>After the instance is registered, it starts receiving traffic and requests from the load balancer. Any instance that is not in one of the Availability Zones registered for the load balancer is moved to the <code>OutOfService</code> state. If an Availability Zone is added to the load balancer later, any instances registered with the load balancer move to the <code>InService</code> state.</p> <p>To deregister instances from a load balancer, use <a>DeregisterInstancesFromLoadBalancer</a>.</p> <p>For more information, see <a href="http://docs.aws.amazon.com/elasticloadbalancing/latest/classic/elb-deregister-register-instances.html">Register or De-Register EC2 Instances</a> in the <i>Classic Load Balancers Guide</i>.</p>
+    async fn register_instances_with_load_balancer(
+        &self,
+        input: RegisterEndPointsInput,
+    ) -> Result<RegisterEndPointsOutput, RusotoError<RegisterInstancesWithLoadBalancerError>> {
+        self.register_instances_with_load_balancer_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Removes one or more tags from the specified load balancer.</p>
+    async fn remove_tags(
+        &self,
+        input: RemoveTagsInput,
+    ) -> Result<RemoveTagsOutput, RusotoError<RemoveTagsError>> {
+        self.remove_tags_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Sets the certificate that terminates the specified listener's SSL connections. The specified certificate replaces any prior certificate that was used on the same load balancer and port.</p> <p>For more information about updating your SSL certificate, see <a href="http://docs.aws.amazon.com/elasticloadbalancing/latest/classic/elb-update-ssl-cert.html">Replace the SSL Certificate for Your Load Balancer</a> in the <i>Classic Load Balancers Guide</i>.</p>
+    async fn set_load_balancer_listener_ssl_certificate(
+        &self,
+        input: SetLoadBalancerListenerSSLCertificateInput,
+    ) -> Result<
+        SetLoadBalancerListenerSSLCertificateOutput,
+        RusotoError<SetLoadBalancerListenerSSLCertificateError>,
+    > {
+        self.set_load_balancer_listener_ssl_certificate_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Replaces the set of policies associated with the specified port on which the EC2 instance is listening with a new set of policies. At this time, only the back-end server authentication policy type can be applied to the instance ports; this policy type is composed of multiple public key policies.</p> <p>Each time you use <code>SetLoadBalancerPoliciesForBackendServer</code> to enable the policies, use the <code>PolicyNames</code> parameter to list the policies that you want to enable.</p> <p>You can use <a>DescribeLoadBalancers</a> or <a>DescribeLoadBalancerPolicies</a> to verify that the policy is associated with the EC2 instance.</p> <p>For more information about enabling back-end instance authentication, see <a href="http://docs.aws.amazon.com/elasticloadbalancing/latest/classic/elb-create-https-ssl-load-balancer.html#configure_backendauth_clt">Configure Back-end Instance Authentication</a> in the <i>Classic Load Balancers Guide</i>. For more information about Proxy Protocol, see <a href="http://docs.aws.amazon.com/elasticloadbalancing/latest/classic/enable-proxy-protocol.html">Configure Proxy Protocol Support</a> in the <i>Classic Load Balancers Guide</i>.</p>
+    async fn set_load_balancer_policies_for_backend_server(
+        &self,
+        input: SetLoadBalancerPoliciesForBackendServerInput,
+    ) -> Result<
+        SetLoadBalancerPoliciesForBackendServerOutput,
+        RusotoError<SetLoadBalancerPoliciesForBackendServerError>,
+    > {
+        self.set_load_balancer_policies_for_backend_server_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Replaces the current set of policies for the specified load balancer port with the specified set of policies.</p> <p>To enable back-end server authentication, use <a>SetLoadBalancerPoliciesForBackendServer</a>.</p> <p>For more information about setting policies, see <a href="http://docs.aws.amazon.com/elasticloadbalancing/latest/classic/ssl-config-update.html">Update the SSL Negotiation Configuration</a>, <a href="http://docs.aws.amazon.com/elasticloadbalancing/latest/classic/elb-sticky-sessions.html#enable-sticky-sessions-duration">Duration-Based Session Stickiness</a>, and <a href="http://docs.aws.amazon.com/elasticloadbalancing/latest/classic/elb-sticky-sessions.html#enable-sticky-sessions-application">Application-Controlled Session Stickiness</a> in the <i>Classic Load Balancers Guide</i>.</p>
+    async fn set_load_balancer_policies_of_listener(
+        &self,
+        input: SetLoadBalancerPoliciesOfListenerInput,
+    ) -> Result<
+        SetLoadBalancerPoliciesOfListenerOutput,
+        RusotoError<SetLoadBalancerPoliciesOfListenerError>,
+    > {
+        self.set_load_balancer_policies_of_listener_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
     }
 }
 
@@ -16,8 +16,8 @@ use std::fmt;
 use async_trait::async_trait;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
-use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest, ResponseMetadata};
+use rusoto_core::{Client, ErrorMetadata, RusotoError};
 
 use rusoto_core::param::{Params, ServiceParams};
 use rusoto_core::proto::xml::error::*;
@@ -6391,6 +6391,7 @@ impl ZoneNameDeserializer {
 }
 /// Errors returned by AddListenerCertificates
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum AddListenerCertificatesError {
     /// <p>The specified certificate does not exist.</p>
     CertificateNotFound(String),
@@ -6398,6 +6399,8 @@ pub enum AddListenerCertificatesError {
     ListenerNotFound(String),
     /// <p>You've reached the limit on the number of certificates per load balancer.</p>
     TooManyCertificates(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl AddListenerCertificatesError {
@@ -6409,21 +6412,29 @@ impl AddListenerCertificatesError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "CertificateNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             AddListenerCertificatesError::CertificateNotFound(parsed_error.message),
+                            &res,
                         )
                     }
                     "ListenerNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             AddListenerCertificatesError::ListenerNotFound(parsed_error.message),
+                            &res,
                         )
                     }
                     "TooManyCertificates" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             AddListenerCertificatesError::TooManyCertificates(parsed_error.message),
+                            &res,
+                        )
+                    }
+                    _ => {
+                        return RusotoError::unhandled(
+                            AddListenerCertificatesError::Unhandled,
+                            &res,
                         )
                     }
-                    _ => {}
                 }
             }
         }
@@ -6445,12 +6456,14 @@ impl fmt::Display for AddListenerCertificatesError {
             AddListenerCertificatesError::CertificateNotFound(ref cause) => write!(f, "{}", cause),
             AddListenerCertificatesError::ListenerNotFound(ref cause) => write!(f, "{}", cause),
             AddListenerCertificatesError::TooManyCertificates(ref cause) => write!(f, "{}", cause),
+            AddListenerCertificatesError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for AddListenerCertificatesError {}
 /// Errors returned by AddTags
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum AddTagsError {
     /// <p>A tag key was specified more than once.</p>
     DuplicateTagKeys(String),
@@ -6460,6 +6473,8 @@ pub enum AddTagsError {
     TargetGroupNotFound(String),
     /// <p>You've reached the limit on the number of tags per load balancer.</p>
     TooManyTags(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl AddTagsError {
@@ -6471,26 +6486,30 @@ impl AddTagsError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "DuplicateTagKeys" => {
-                        return RusotoError::Service(AddTagsError::DuplicateTagKeys(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            AddTagsError::DuplicateTagKeys(parsed_error.message),
+                            &res,
+                        )
                     }
                     "LoadBalancerNotFound" => {
-                        return RusotoError::Service(AddTagsError::LoadBalancerNotFound(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            AddTagsError::LoadBalancerNotFound(parsed_error.message),
+                            &res,
+                        )
                     }
                     "TargetGroupNotFound" => {
-                        return RusotoError::Service(AddTagsError::TargetGroupNotFound(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            AddTagsError::TargetGroupNotFound(parsed_error.message),
+                            &res,
+                        )
                     }
                     "TooManyTags" => {
-                        return RusotoError::Service(AddTagsError::TooManyTags(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            AddTagsError::TooManyTags(parsed_error.message),
+                            &res,
+                        )
                     }
-                    _ => {}
+                    _ => return RusotoError::unhandled(AddTagsError::Unhandled, &res),
                 }
             }
         }
@@ -6513,12 +6532,14 @@ impl fmt::Display for AddTagsError {
             AddTagsError::LoadBalancerNotFound(ref cause) => write!(f, "{}", cause),
             AddTagsError::TargetGroupNotFound(ref cause) => write!(f, "{}", cause),
             AddTagsError::TooManyTags(ref cause) => write!(f, "{}", cause),
+            AddTagsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for AddTagsError {}
 /// Errors returned by CreateListener
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateListenerError {
     /// <p>The specified certificate does not exist.</p>
     CertificateNotFound(String),
@@ -6552,6 +6573,8 @@ pub enum CreateListenerError {
     TooManyUniqueTargetGroupsPerLoadBalancer(String),
     /// <p>The specified protocol is not supported.</p>
     UnsupportedProtocol(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateListenerError {
@@ -6563,90 +6586,106 @@ impl CreateListenerError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "CertificateNotFound" => {
-                        return RusotoError::Service(CreateListenerError::CertificateNotFound(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            CreateListenerError::CertificateNotFound(parsed_error.message),
+                            &res,
+                        )
                     }
                     "DuplicateListener" => {
-                        return RusotoError::Service(CreateListenerError::DuplicateListener(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            CreateListenerError::DuplicateListener(parsed_error.message),
+                            &res,
+                        )
                     }
                     "IncompatibleProtocols" => {
-                        return RusotoError::Service(CreateListenerError::IncompatibleProtocols(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            CreateListenerError::IncompatibleProtocols(parsed_error.message),
+                            &res,
+                        )
                     }
                     "InvalidConfigurationRequest" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             CreateListenerError::InvalidConfigurationRequest(parsed_error.message),
+                            &res,
                         )
                     }
                     "InvalidLoadBalancerAction" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             CreateListenerError::InvalidLoadBalancerAction(parsed_error.message),
+                            &res,
                         )
                     }
                     "LoadBalancerNotFound" => {
-                        return RusotoError::Service(CreateListenerError::LoadBalancerNotFound(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            CreateListenerError::LoadBalancerNotFound(parsed_error.message),
+                            &res,
+                        )
                     }
                     "SSLPolicyNotFound" => {
-                        return RusotoError::Service(CreateListenerError::SSLPolicyNotFound(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            CreateListenerError::SSLPolicyNotFound(parsed_error.message),
+                            &res,
+                        )
                     }
                     "TargetGroupAssociationLimit" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             CreateListenerError::TargetGroupAssociationLimit(parsed_error.message),
+                            &res,
                         )
                     }
                     "TargetGroupNotFound" => {
-                        return RusotoError::Service(CreateListenerError::TargetGroupNotFound(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            CreateListenerError::TargetGroupNotFound(parsed_error.message),
+                            &res,
+                        )
                     }
                     "TooManyActions" => {
-                        return RusotoError::Service(CreateListenerError::TooManyActions(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            CreateListenerError::TooManyActions(parsed_error.message),
+                            &res,
+                        )
                     }
                     "TooManyCertificates" => {
-                        return RusotoError::Service(CreateListenerError::TooManyCertificates(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            CreateListenerError::TooManyCertificates(parsed_error.message),
+                            &res,
+                        )
                     }
                     "TooManyListeners" => {
-                        return RusotoError::Service(CreateListenerError::TooManyListeners(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            CreateListenerError::TooManyListeners(parsed_error.message),
+                            &res,
+                        )
                     }
                     "TooManyRegistrationsForTargetId" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             CreateListenerError::TooManyRegistrationsForTargetId(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "TooManyTargets" => {
-                        return RusotoError::Service(CreateListenerError::TooManyTargets(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            CreateListenerError::TooManyTargets(parsed_error.message),
+                            &res,
+                        )
                     }
                     "TooManyUniqueTargetGroupsPerLoadBalancer" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             CreateListenerError::TooManyUniqueTargetGroupsPerLoadBalancer(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "UnsupportedProtocol" => {
-                        return RusotoError::Service(CreateListenerError::UnsupportedProtocol(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            CreateListenerError::UnsupportedProtocol(parsed_error.message),
+                            &res,
+                        )
                     }
-                    _ => {}
+                    _ => return RusotoError::unhandled(CreateListenerError::Unhandled, &res),
                 }
             }
         }
@@ -6685,12 +6724,14 @@ impl fmt::Display for CreateListenerError {
                 write!(f, "{}", cause)
             }
             CreateListenerError::UnsupportedProtocol(ref cause) => write!(f, "{}", cause),
+            CreateListenerError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateListenerError {}
 /// Errors returned by CreateLoadBalancer
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateLoadBalancerError {
     /// <p>The specified allocation ID does not exist.</p>
     AllocationIdNotFound(String),
@@ -6718,6 +6759,8 @@ pub enum CreateLoadBalancerError {
     TooManyLoadBalancers(String),
     /// <p>You've reached the limit on the number of tags per load balancer.</p>
     TooManyTags(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateLoadBalancerError {
@@ -6729,77 +6772,90 @@ impl CreateLoadBalancerError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "AllocationIdNotFound" => {
-                        return RusotoError::Service(CreateLoadBalancerError::AllocationIdNotFound(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            CreateLoadBalancerError::AllocationIdNotFound(parsed_error.message),
+                            &res,
+                        )
                     }
                     "AvailabilityZoneNotSupported" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             CreateLoadBalancerError::AvailabilityZoneNotSupported(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "DuplicateLoadBalancerName" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             CreateLoadBalancerError::DuplicateLoadBalancerName(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "DuplicateTagKeys" => {
-                        return RusotoError::Service(CreateLoadBalancerError::DuplicateTagKeys(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            CreateLoadBalancerError::DuplicateTagKeys(parsed_error.message),
+                            &res,
+                        )
                     }
                     "InvalidConfigurationRequest" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             CreateLoadBalancerError::InvalidConfigurationRequest(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "InvalidScheme" => {
-                        return RusotoError::Service(CreateLoadBalancerError::InvalidScheme(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            CreateLoadBalancerError::InvalidScheme(parsed_error.message),
+                            &res,
+                        )
                     }
                     "InvalidSecurityGroup" => {
-                        return RusotoError::Service(CreateLoadBalancerError::InvalidSecurityGroup(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            CreateLoadBalancerError::InvalidSecurityGroup(parsed_error.message),
+                            &res,
+                        )
                     }
                     "InvalidSubnet" => {
-                        return RusotoError::Service(CreateLoadBalancerError::InvalidSubnet(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            CreateLoadBalancerError::InvalidSubnet(parsed_error.message),
+                            &res,
+                        )
                     }
                     "OperationNotPermitted" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             CreateLoadBalancerError::OperationNotPermitted(parsed_error.message),
+                            &res,
                         )
                     }
                     "ResourceInUse" => {
-                        return RusotoError::Service(CreateLoadBalancerError::ResourceInUse(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            CreateLoadBalancerError::ResourceInUse(parsed_error.message),
+                            &res,
+                        )
                     }
                     "SubnetNotFound" => {
-                        return RusotoError::Service(CreateLoadBalancerError::SubnetNotFound(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            CreateLoadBalancerError::SubnetNotFound(parsed_error.message),
+                            &res,
+                        )
                     }
                     "TooManyLoadBalancers" => {
-                        return RusotoError::Service(CreateLoadBalancerError::TooManyLoadBalancers(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            CreateLoadBalancerError::TooManyLoadBalancers(parsed_error.message),
+                            &res,
+                        )
                     }
                     "TooManyTags" => {
-                        return RusotoError::Service(CreateLoadBalancerError::TooManyTags(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            CreateLoadBalancerError::TooManyTags(parsed_error.message),
+                            &res,
+                        )
                     }
-                    _ => {}
+                    _ => return RusotoError::unhandled(CreateLoadBalancerError::Unhandled, &res),
                 }
             }
         }
@@ -6835,12 +6891,14 @@ impl fmt::Display for CreateLoadBalancerError {
             CreateLoadBalancerError::SubnetNotFound(ref cause) => write!(f, "{}", cause),
             CreateLoadBalancerError::TooManyLoadBalancers(ref cause) => write!(f, "{}", cause),
             CreateLoadBalancerError::TooManyTags(ref cause) => write!(f, "{}", cause),
+            CreateLoadBalancerError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateLoadBalancerError {}
 /// Errors returned by CreateRule
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateRuleError {
     /// <p>The specified configuration is not valid with this protocol.</p>
     IncompatibleProtocols(String),
@@ -6870,6 +6928,8 @@ pub enum CreateRuleError {
     TooManyUniqueTargetGroupsPerLoadBalancer(String),
     /// <p>The specified protocol is not supported.</p>
     UnsupportedProtocol(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateRuleError {
@@ -6881,78 +6941,92 @@ impl CreateRuleError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "IncompatibleProtocols" => {
-                        return RusotoError::Service(CreateRuleError::IncompatibleProtocols(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            CreateRuleError::IncompatibleProtocols(parsed_error.message),
+                            &res,
+                        )
                     }
                     "InvalidConfigurationRequest" => {
-                        return RusotoError::Service(CreateRuleError::InvalidConfigurationRequest(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            CreateRuleError::InvalidConfigurationRequest(parsed_error.message),
+                            &res,
+                        )
                     }
                     "InvalidLoadBalancerAction" => {
-                        return RusotoError::Service(CreateRuleError::InvalidLoadBalancerAction(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            CreateRuleError::InvalidLoadBalancerAction(parsed_error.message),
+                            &res,
+                        )
                     }
                     "ListenerNotFound" => {
-                        return RusotoError::Service(CreateRuleError::ListenerNotFound(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            CreateRuleError::ListenerNotFound(parsed_error.message),
+                            &res,
+                        )
                     }
                     "PriorityInUse" => {
-                        return RusotoError::Service(CreateRuleError::PriorityInUse(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            CreateRuleError::PriorityInUse(parsed_error.message),
+                            &res,
+                        )
                     }
                     "TargetGroupAssociationLimit" => {
-                        return RusotoError::Service(CreateRuleError::TargetGroupAssociationLimit(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            CreateRuleError::TargetGroupAssociationLimit(parsed_error.message),
+                            &res,
+                        )
                     }
                     "TargetGroupNotFound" => {
-                        return RusotoError::Service(CreateRuleError::TargetGroupNotFound(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            CreateRuleError::TargetGroupNotFound(parsed_error.message),
+                            &res,
+                        )
                     }
                     "TooManyActions" => {
-                        return RusotoError::Service(CreateRuleError::TooManyActions(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            CreateRuleError::TooManyActions(parsed_error.message),
+                            &res,
+                        )
                     }
                     "TooManyRegistrationsForTargetId" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             CreateRuleError::TooManyRegistrationsForTargetId(parsed_error.message),
+                            &res,
                         )
                     }
                     "TooManyRules" => {
-                        return RusotoError::Service(CreateRuleError::TooManyRules(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            CreateRuleError::TooManyRules(parsed_error.message),
+                            &res,
+                        )
                     }
                     "TooManyTargetGroups" => {
-                        return RusotoError::Service(CreateRuleError::TooManyTargetGroups(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            CreateRuleError::TooManyTargetGroups(parsed_error.message),
+                            &res,
+                        )
                     }
                     "TooManyTargets" => {
-                        return RusotoError::Service(CreateRuleError::TooManyTargets(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            CreateRuleError::TooManyTargets(parsed_error.message),
+                            &res,
+                        )
                     }
                     "TooManyUniqueTargetGroupsPerLoadBalancer" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             CreateRuleError::TooManyUniqueTargetGroupsPerLoadBalancer(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "UnsupportedProtocol" => {
-                        return RusotoError::Service(CreateRuleError::UnsupportedProtocol(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            CreateRuleError::UnsupportedProtocol(parsed_error.message),
+                            &res,
+                        )
                     }
-                    _ => {}
+                    _ => return RusotoError::unhandled(CreateRuleError::Unhandled, &res),
                 }
             }
         }
@@ -6987,12 +7061,14 @@ impl fmt::Display for CreateRuleError {
                 write!(f, "{}", cause)
             }
             CreateRuleError::UnsupportedProtocol(ref cause) => write!(f, "{}", cause),
+            CreateRuleError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateRuleError {}
 /// Errors returned by CreateTargetGroup
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateTargetGroupError {
     /// <p>A target group with the specified name already exists.</p>
     DuplicateTargetGroupName(String),
@@ -7000,6 +7076,8 @@ pub enum CreateTargetGroupError {
     InvalidConfigurationRequest(String),
     /// <p>You've reached the limit on the number of target groups for your AWS account.</p>
     TooManyTargetGroups(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateTargetGroupError {
@@ -7011,23 +7089,26 @@ impl CreateTargetGroupError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "DuplicateTargetGroupName" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             CreateTargetGroupError::DuplicateTargetGroupName(parsed_error.message),
+                            &res,
                         )
                     }
                     "InvalidConfigurationRequest" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             CreateTargetGroupError::InvalidConfigurationRequest(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "TooManyTargetGroups" => {
-                        return RusotoError::Service(CreateTargetGroupError::TooManyTargetGroups(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            CreateTargetGroupError::TooManyTargetGroups(parsed_error.message),
+                            &res,
+                        )
                     }
-                    _ => {}
+                    _ => return RusotoError::unhandled(CreateTargetGroupError::Unhandled, &res),
                 }
             }
         }
@@ -7051,15 +7132,19 @@ impl fmt::Display for CreateTargetGroupError {
                 write!(f, "{}", cause)
             }
             CreateTargetGroupError::TooManyTargetGroups(ref cause) => write!(f, "{}", cause),
+            CreateTargetGroupError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateTargetGroupError {}
 /// Errors returned by DeleteListener
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteListenerError {
     /// <p>The specified listener does not exist.</p>
     ListenerNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteListenerError {
@@ -7071,11 +7156,12 @@ impl DeleteListenerError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "ListenerNotFound" => {
-                        return RusotoError::Service(DeleteListenerError::ListenerNotFound(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            DeleteListenerError::ListenerNotFound(parsed_error.message),
+                            &res,
+                        )
                     }
-                    _ => {}
+                    _ => return RusotoError::unhandled(DeleteListenerError::Unhandled, &res),
                 }
             }
         }
@@ -7095,12 +7181,14 @@ impl fmt::Display for DeleteListenerError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             DeleteListenerError::ListenerNotFound(ref cause) => write!(f, "{}", cause),
+            DeleteListenerError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteListenerError {}
 /// Errors returned by DeleteLoadBalancer
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteLoadBalancerError {
     /// <p>The specified load balancer does not exist.</p>
     LoadBalancerNotFound(String),
@@ -7108,6 +7196,8 @@ pub enum DeleteLoadBalancerError {
     OperationNotPermitted(String),
     /// <p>A specified resource is in use.</p>
     ResourceInUse(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteLoadBalancerError {
@@ -7119,21 +7209,24 @@ impl DeleteLoadBalancerError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "LoadBalancerNotFound" => {
-                        return RusotoError::Service(DeleteLoadBalancerError::LoadBalancerNotFound(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            DeleteLoadBalancerError::LoadBalancerNotFound(parsed_error.message),
+                            &res,
+                        )
                     }
                     "OperationNotPermitted" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             DeleteLoadBalancerError::OperationNotPermitted(parsed_error.message),
+                            &res,
                         )
                     }
                     "ResourceInUse" => {
-                        return RusotoError::Service(DeleteLoadBalancerError::ResourceInUse(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            DeleteLoadBalancerError::ResourceInUse(parsed_error.message),
+                            &res,
+                        )
                     }
-                    _ => {}
+                    _ => return RusotoError::unhandled(DeleteLoadBalancerError::Unhandled, &res),
                 }
             }
         }
@@ -7155,17 +7248,21 @@ impl fmt::Display for DeleteLoadBalancerError {
             DeleteLoadBalancerError::LoadBalancerNotFound(ref cause) => write!(f, "{}", cause),
             DeleteLoadBalancerError::OperationNotPermitted(ref cause) => write!(f, "{}", cause),
             DeleteLoadBalancerError::ResourceInUse(ref cause) => write!(f, "{}", cause),
+            DeleteLoadBalancerError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteLoadBalancerError {}
 /// Errors returned by DeleteRule
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteRuleError {
     /// <p>This operation is not allowed.</p>
     OperationNotPermitted(String),
     /// <p>The specified rule does not exist.</p>
     RuleNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteRuleError {
@@ -7177,16 +7274,18 @@ impl DeleteRuleError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "OperationNotPermitted" => {
-                        return RusotoError::Service(DeleteRuleError::OperationNotPermitted(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            DeleteRuleError::OperationNotPermitted(parsed_error.message),
+                            &res,
+                        )
                     }
                     "RuleNotFound" => {
-                        return RusotoError::Service(DeleteRuleError::RuleNotFound(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            DeleteRuleError::RuleNotFound(parsed_error.message),
+                            &res,
+                        )
                     }
-                    _ => {}
+                    _ => return RusotoError::unhandled(DeleteRuleError::Unhandled, &res),
                 }
             }
         }
@@ -7207,15 +7306,19 @@ impl fmt::Display for DeleteRuleError {
         match *self {
             DeleteRuleError::OperationNotPermitted(ref cause) => write!(f, "{}", cause),
             DeleteRuleError::RuleNotFound(ref cause) => write!(f, "{}", cause),
+            DeleteRuleError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteRuleError {}
 /// Errors returned by DeleteTargetGroup
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteTargetGroupError {
     /// <p>A specified resource is in use.</p>
     ResourceInUse(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteTargetGroupError {
@@ -7227,11 +7330,12 @@ impl DeleteTargetGroupError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "ResourceInUse" => {
-                        return RusotoError::Service(DeleteTargetGroupError::ResourceInUse(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            DeleteTargetGroupError::ResourceInUse(parsed_error.message),
+                            &res,
+                        )
                     }
-                    _ => {}
+                    _ => return RusotoError::unhandled(DeleteTargetGroupError::Unhandled, &res),
                 }
             }
         }
@@ -7251,17 +7355,21 @@ impl fmt::Display for DeleteTargetGroupError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             DeleteTargetGroupError::ResourceInUse(ref cause) => write!(f, "{}", cause),
+            DeleteTargetGroupError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteTargetGroupError {}
 /// Errors returned by DeregisterTargets
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeregisterTargetsError {
     /// <p>The specified target does not exist, is not in the same VPC as the target group, or has an unsupported instance type.</p>
     InvalidTarget(String),
     /// <p>The specified target group does not exist.</p>
     TargetGroupNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeregisterTargetsError {
@@ -7273,16 +7381,18 @@ impl DeregisterTargetsError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InvalidTarget" => {
-                        return RusotoError::Service(DeregisterTargetsError::InvalidTarget(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            DeregisterTargetsError::InvalidTarget(parsed_error.message),
+                            &res,
+                        )
                     }
                     "TargetGroupNotFound" => {
-                        return RusotoError::Service(DeregisterTargetsError::TargetGroupNotFound(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            DeregisterTargetsError::TargetGroupNotFound(parsed_error.message),
+                            &res,
+                        )
                     }
-                    _ => {}
+                    _ => return RusotoError::unhandled(DeregisterTargetsError::Unhandled, &res),
                 }
             }
         }
@@ -7303,13 +7413,18 @@ impl fmt::Display for DeregisterTargetsError {
         match *self {
             DeregisterTargetsError::InvalidTarget(ref cause) => write!(f, "{}", cause),
             DeregisterTargetsError::TargetGroupNotFound(ref cause) => write!(f, "{}", cause),
+            DeregisterTargetsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeregisterTargetsError {}
 /// Errors returned by DescribeAccountLimits
 #[derive(Debug, PartialEq)]
-pub enum DescribeAccountLimitsError {}
+#[non_exhaustive]
+pub enum DescribeAccountLimitsError {
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
+}
 
 impl DescribeAccountLimitsError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<DescribeAccountLimitsError> {
@@ -7317,10 +7432,8 @@ impl DescribeAccountLimitsError {
             let reader = EventReader::new(res.body.as_ref());
             let mut stack = XmlResponse::new(reader.into_iter().peekable());
             find_start_element(&mut stack);
-            if let Ok(parsed_error) = Self::deserialize(&mut stack) {
-                match &parsed_error.code[..] {
-                    _ => {}
-                }
+            if Self::deserialize(&mut stack).is_ok() {
+                return RusotoError::unhandled(DescribeAccountLimitsError::Unhandled, &res);
             }
         }
         RusotoError::Unknown(res)
@@ -7337,15 +7450,20 @@ impl DescribeAccountLimitsError {
 impl fmt::Display for DescribeAccountLimitsError {
     #[allow(unused_variables)]
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        match *self {}
+        match *self {
+            DescribeAccountLimitsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
+        }
     }
 }
 impl Error for DescribeAccountLimitsError {}
 /// Errors returned by DescribeListenerCertificates
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeListenerCertificatesError {
     /// <p>The specified listener does not exist.</p>
     ListenerNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeListenerCertificatesError {
@@ -7359,13 +7477,19 @@ impl DescribeListenerCertificatesError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "ListenerNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             DescribeListenerCertificatesError::ListenerNotFound(
                                 parsed_error.message,
                             ),
+                            &res,
+                        )
+                    }
+                    _ => {
+                        return RusotoError::unhandled(
+                            DescribeListenerCertificatesError::Unhandled,
+                            &res,
                         )
                     }
-                    _ => {}
                 }
             }
         }
@@ -7387,12 +7511,14 @@ impl fmt::Display for DescribeListenerCertificatesError {
             DescribeListenerCertificatesError::ListenerNotFound(ref cause) => {
                 write!(f, "{}", cause)
             }
+            DescribeListenerCertificatesError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeListenerCertificatesError {}
 /// Errors returned by DescribeListeners
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeListenersError {
     /// <p>The specified listener does not exist.</p>
     ListenerNotFound(String),
@@ -7400,6 +7526,8 @@ pub enum DescribeListenersError {
     LoadBalancerNotFound(String),
     /// <p>The specified protocol is not supported.</p>
     UnsupportedProtocol(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeListenersError {
@@ -7411,21 +7539,24 @@ impl DescribeListenersError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "ListenerNotFound" => {
-                        return RusotoError::Service(DescribeListenersError::ListenerNotFound(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            DescribeListenersError::ListenerNotFound(parsed_error.message),
+                            &res,
+                        )
                     }
                     "LoadBalancerNotFound" => {
-                        return RusotoError::Service(DescribeListenersError::LoadBalancerNotFound(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            DescribeListenersError::LoadBalancerNotFound(parsed_error.message),
+                            &res,
+                        )
                     }
                     "UnsupportedProtocol" => {
-                        return RusotoError::Service(DescribeListenersError::UnsupportedProtocol(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            DescribeListenersError::UnsupportedProtocol(parsed_error.message),
+                            &res,
+                        )
                     }
-                    _ => {}
+                    _ => return RusotoError::unhandled(DescribeListenersError::Unhandled, &res),
                 }
             }
         }
@@ -7447,15 +7578,19 @@ impl fmt::Display for DescribeListenersError {
             DescribeListenersError::ListenerNotFound(ref cause) => write!(f, "{}", cause),
             DescribeListenersError::LoadBalancerNotFound(ref cause) => write!(f, "{}", cause),
             DescribeListenersError::UnsupportedProtocol(ref cause) => write!(f, "{}", cause),
+            DescribeListenersError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeListenersError {}
 /// Errors returned by DescribeLoadBalancerAttributes
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeLoadBalancerAttributesError {
     /// <p>The specified load balancer does not exist.</p>
     LoadBalancerNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeLoadBalancerAttributesError {
@@ -7469,13 +7604,19 @@ impl DescribeLoadBalancerAttributesError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "LoadBalancerNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             DescribeLoadBalancerAttributesError::LoadBalancerNotFound(
                                 parsed_error.message,
                             ),
+                            &res,
+                        )
+                    }
+                    _ => {
+                        return RusotoError::unhandled(
+                            DescribeLoadBalancerAttributesError::Unhandled,
+                            &res,
                         )
                     }
-                    _ => {}
                 }
             }
         }
@@ -7497,15 +7638,21 @@ impl fmt::Display for DescribeLoadBalancerAttributesError {
             DescribeLoadBalancerAttributesError::LoadBalancerNotFound(ref cause) => {
                 write!(f, "{}", cause)
             }
+            DescribeLoadBalancerAttributesError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for DescribeLoadBalancerAttributesError {}
 /// Errors returned by DescribeLoadBalancers
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeLoadBalancersError {
     /// <p>The specified load balancer does not exist.</p>
     LoadBalancerNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeLoadBalancersError {
@@ -7517,11 +7664,14 @@ impl DescribeLoadBalancersError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "LoadBalancerNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             DescribeLoadBalancersError::LoadBalancerNotFound(parsed_error.message),
+                            &res,
                         )
                     }
-                    _ => {}
+                    _ => {
+                        return RusotoError::unhandled(DescribeLoadBalancersError::Unhandled, &res)
+                    }
                 }
             }
         }
@@ -7541,12 +7691,14 @@ impl fmt::Display for DescribeLoadBalancersError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             DescribeLoadBalancersError::LoadBalancerNotFound(ref cause) => write!(f, "{}", cause),
+            DescribeLoadBalancersError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeLoadBalancersError {}
 /// Errors returned by DescribeRules
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeRulesError {
     /// <p>The specified listener does not exist.</p>
     ListenerNotFound(String),
@@ -7554,6 +7706,8 @@ pub enum DescribeRulesError {
     RuleNotFound(String),
     /// <p>The specified protocol is not supported.</p>
     UnsupportedProtocol(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeRulesError {
@@ -7565,21 +7719,24 @@ impl DescribeRulesError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "ListenerNotFound" => {
-                        return RusotoError::Service(DescribeRulesError::ListenerNotFound(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            DescribeRulesError::ListenerNotFound(parsed_error.message),
+                            &res,
+                        )
                     }
                     "RuleNotFound" => {
-                        return RusotoError::Service(DescribeRulesError::RuleNotFound(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            DescribeRulesError::RuleNotFound(parsed_error.message),
+                            &res,
+                        )
                     }
                     "UnsupportedProtocol" => {
-                        return RusotoError::Service(DescribeRulesError::UnsupportedProtocol(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            DescribeRulesError::UnsupportedProtocol(parsed_error.message),
+                            &res,
+                        )
                     }
-                    _ => {}
+                    _ => return RusotoError::unhandled(DescribeRulesError::Unhandled, &res),
                 }
             }
         }
@@ -7601,15 +7758,19 @@ impl fmt::Display for DescribeRulesError {
             DescribeRulesError::ListenerNotFound(ref cause) => write!(f, "{}", cause),
             DescribeRulesError::RuleNotFound(ref cause) => write!(f, "{}", cause),
             DescribeRulesError::UnsupportedProtocol(ref cause) => write!(f, "{}", cause),
+            DescribeRulesError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeRulesError {}
 /// Errors returned by DescribeSSLPolicies
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeSSLPoliciesError {
     /// <p>The specified SSL policy does not exist.</p>
     SSLPolicyNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeSSLPoliciesError {
@@ -7621,11 +7782,12 @@ impl DescribeSSLPoliciesError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "SSLPolicyNotFound" => {
-                        return RusotoError::Service(DescribeSSLPoliciesError::SSLPolicyNotFound(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            DescribeSSLPoliciesError::SSLPolicyNotFound(parsed_error.message),
+                            &res,
+                        )
                     }
-                    _ => {}
+                    _ => return RusotoError::unhandled(DescribeSSLPoliciesError::Unhandled, &res),
                 }
             }
         }
@@ -7645,12 +7807,14 @@ impl fmt::Display for DescribeSSLPoliciesError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             DescribeSSLPoliciesError::SSLPolicyNotFound(ref cause) => write!(f, "{}", cause),
+            DescribeSSLPoliciesError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeSSLPoliciesError {}
 /// Errors returned by DescribeTags
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeTagsError {
     /// <p>The specified listener does not exist.</p>
     ListenerNotFound(String),
@@ -7660,6 +7824,8 @@ pub enum DescribeTagsError {
     RuleNotFound(String),
     /// <p>The specified target group does not exist.</p>
     TargetGroupNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeTagsError {
@@ -7671,26 +7837,30 @@ impl DescribeTagsError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "ListenerNotFound" => {
-                        return RusotoError::Service(DescribeTagsError::ListenerNotFound(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            DescribeTagsError::ListenerNotFound(parsed_error.message),
+                            &res,
+                        )
                     }
                     "LoadBalancerNotFound" => {
-                        return RusotoError::Service(DescribeTagsError::LoadBalancerNotFound(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            DescribeTagsError::LoadBalancerNotFound(parsed_error.message),
+                            &res,
+                        )
                     }
                     "RuleNotFound" => {
-                        return RusotoError::Service(DescribeTagsError::RuleNotFound(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            DescribeTagsError::RuleNotFound(parsed_error.message),
+                            &res,
+                        )
                     }
                     "TargetGroupNotFound" => {
-                        return RusotoError::Service(DescribeTagsError::TargetGroupNotFound(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            DescribeTagsError::TargetGroupNotFound(parsed_error.message),
+                            &res,
+                        )
                     }
-                    _ => {}
+                    _ => return RusotoError::unhandled(DescribeTagsError::Unhandled, &res),
                 }
             }
         }
@@ -7713,15 +7883,19 @@ impl fmt::Display for DescribeTagsError {
             DescribeTagsError::LoadBalancerNotFound(ref cause) => write!(f, "{}", cause),
             DescribeTagsError::RuleNotFound(ref cause) => write!(f, "{}", cause),
             DescribeTagsError::TargetGroupNotFound(ref cause) => write!(f, "{}", cause),
+            DescribeTagsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeTagsError {}
 /// Errors returned by DescribeTargetGroupAttributes
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeTargetGroupAttributesError {
     /// <p>The specified target group does not exist.</p>
     TargetGroupNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeTargetGroupAttributesError {
@@ -7735,13 +7909,19 @@ impl DescribeTargetGroupAttributesError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "TargetGroupNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             DescribeTargetGroupAttributesError::TargetGroupNotFound(
                                 parsed_error.message,
                             ),
+                            &res,
+                        )
+                    }
+                    _ => {
+                        return RusotoError::unhandled(
+                            DescribeTargetGroupAttributesError::Unhandled,
+                            &res,
                         )
                     }
-                    _ => {}
                 }
             }
         }
@@ -7763,17 +7943,23 @@ impl fmt::Display for DescribeTargetGroupAttributesError {
             DescribeTargetGroupAttributesError::TargetGroupNotFound(ref cause) => {
                 write!(f, "{}", cause)
             }
+            DescribeTargetGroupAttributesError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for DescribeTargetGroupAttributesError {}
 /// Errors returned by DescribeTargetGroups
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeTargetGroupsError {
     /// <p>The specified load balancer does not exist.</p>
     LoadBalancerNotFound(String),
     /// <p>The specified target group does not exist.</p>
     TargetGroupNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeTargetGroupsError {
@@ -7785,16 +7971,18 @@ impl DescribeTargetGroupsError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "LoadBalancerNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             DescribeTargetGroupsError::LoadBalancerNotFound(parsed_error.message),
+                            &res,
                         )
                     }
                     "TargetGroupNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             DescribeTargetGroupsError::TargetGroupNotFound(parsed_error.message),
+                            &res,
                         )
                     }
-                    _ => {}
+                    _ => return RusotoError::unhandled(DescribeTargetGroupsError::Unhandled, &res),
                 }
             }
         }
@@ -7815,12 +8003,14 @@ impl fmt::Display for DescribeTargetGroupsError {
         match *self {
             DescribeTargetGroupsError::LoadBalancerNotFound(ref cause) => write!(f, "{}", cause),
             DescribeTargetGroupsError::TargetGroupNotFound(ref cause) => write!(f, "{}", cause),
+            DescribeTargetGroupsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeTargetGroupsError {}
 /// Errors returned by DescribeTargetHealth
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeTargetHealthError {
     /// <p>The health of the specified targets could not be retrieved due to an internal error.</p>
     HealthUnavailable(String),
@@ -7828,6 +8018,8 @@ pub enum DescribeTargetHealthError {
     InvalidTarget(String),
     /// <p>The specified target group does not exist.</p>
     TargetGroupNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeTargetHealthError {
@@ -7839,21 +8031,24 @@ impl DescribeTargetHealthError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "HealthUnavailable" => {
-                        return RusotoError::Service(DescribeTargetHealthError::HealthUnavailable(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            DescribeTargetHealthError::HealthUnavailable(parsed_error.message),
+                            &res,
+                        )
                     }
                     "InvalidTarget" => {
-                        return RusotoError::Service(DescribeTargetHealthError::InvalidTarget(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            DescribeTargetHealthError::InvalidTarget(parsed_error.message),
+                            &res,
+                        )
                     }
                     "TargetGroupNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             DescribeTargetHealthError::TargetGroupNotFound(parsed_error.message),
+                            &res,
                         )
                     }
-                    _ => {}
+                    _ => return RusotoError::unhandled(DescribeTargetHealthError::Unhandled, &res),
                 }
             }
         }
@@ -7875,12 +8070,14 @@ impl fmt::Display for DescribeTargetHealthError {
             DescribeTargetHealthError::HealthUnavailable(ref cause) => write!(f, "{}", cause),
             DescribeTargetHealthError::InvalidTarget(ref cause) => write!(f, "{}", cause),
             DescribeTargetHealthError::TargetGroupNotFound(ref cause) => write!(f, "{}", cause),
+            DescribeTargetHealthError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeTargetHealthError {}
 /// Errors returned by ModifyListener
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ModifyListenerError {
     /// <p>The specified certificate does not exist.</p>
     CertificateNotFound(String),
@@ -7914,6 +8111,8 @@ pub enum ModifyListenerError {
     TooManyUniqueTargetGroupsPerLoadBalancer(String),
     /// <p>The specified protocol is not supported.</p>
     UnsupportedProtocol(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ModifyListenerError {
@@ -7925,90 +8124,106 @@ impl ModifyListenerError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "CertificateNotFound" => {
-                        return RusotoError::Service(ModifyListenerError::CertificateNotFound(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            ModifyListenerError::CertificateNotFound(parsed_error.message),
+                            &res,
+                        )
                     }
                     "DuplicateListener" => {
-                        return RusotoError::Service(ModifyListenerError::DuplicateListener(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            ModifyListenerError::DuplicateListener(parsed_error.message),
+                            &res,
+                        )
                     }
                     "IncompatibleProtocols" => {
-                        return RusotoError::Service(ModifyListenerError::IncompatibleProtocols(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            ModifyListenerError::IncompatibleProtocols(parsed_error.message),
+                            &res,
+                        )
                     }
                     "InvalidConfigurationRequest" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             ModifyListenerError::InvalidConfigurationRequest(parsed_error.message),
+                            &res,
                         )
                     }
                     "InvalidLoadBalancerAction" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             ModifyListenerError::InvalidLoadBalancerAction(parsed_error.message),
+                            &res,
                         )
                     }
                     "ListenerNotFound" => {
-                        return RusotoError::Service(ModifyListenerError::ListenerNotFound(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            ModifyListenerError::ListenerNotFound(parsed_error.message),
+                            &res,
+                        )
                     }
                     "SSLPolicyNotFound" => {
-                        return RusotoError::Service(ModifyListenerError::SSLPolicyNotFound(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            ModifyListenerError::SSLPolicyNotFound(parsed_error.message),
+                            &res,
+                        )
                     }
                     "TargetGroupAssociationLimit" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             ModifyListenerError::TargetGroupAssociationLimit(parsed_error.message),
+                            &res,
                         )
                     }
                     "TargetGroupNotFound" => {
-                        return RusotoError::Service(ModifyListenerError::TargetGroupNotFound(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            ModifyListenerError::TargetGroupNotFound(parsed_error.message),
+                            &res,
+                        )
                     }
                     "TooManyActions" => {
-                        return RusotoError::Service(ModifyListenerError::TooManyActions(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            ModifyListenerError::TooManyActions(parsed_error.message),
+                            &res,
+                        )
                     }
                     "TooManyCertificates" => {
-                        return RusotoError::Service(ModifyListenerError::TooManyCertificates(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            ModifyListenerError::TooManyCertificates(parsed_error.message),
+                            &res,
+                        )
                     }
                     "TooManyListeners" => {
-                        return RusotoError::Service(ModifyListenerError::TooManyListeners(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            ModifyListenerError::TooManyListeners(parsed_error.message),
+                            &res,
+                        )
                     }
                     "TooManyRegistrationsForTargetId" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             ModifyListenerError::TooManyRegistrationsForTargetId(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "TooManyTargets" => {
-                        return RusotoError::Service(ModifyListenerError::TooManyTargets(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            ModifyListenerError::TooManyTargets(parsed_error.message),
+                            &res,
+                        )
                     }
                     "TooManyUniqueTargetGroupsPerLoadBalancer" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             ModifyListenerError::TooManyUniqueTargetGroupsPerLoadBalancer(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "UnsupportedProtocol" => {
-                        return RusotoError::Service(ModifyListenerError::UnsupportedProtocol(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            ModifyListenerError::UnsupportedProtocol(parsed_error.message),
+                            &res,
+                        )
                     }
-                    _ => {}
+                    _ => return RusotoError::unhandled(ModifyListenerError::Unhandled, &res),
                 }
             }
         }
@@ -8047,17 +8262,21 @@ impl fmt::Display for ModifyListenerError {
                 write!(f, "{}", cause)
             }
             ModifyListenerError::UnsupportedProtocol(ref cause) => write!(f, "{}", cause),
+            ModifyListenerError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ModifyListenerError {}
 /// Errors returned by ModifyLoadBalancerAttributes
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ModifyLoadBalancerAttributesError {
     /// <p>The requested configuration is not valid.</p>
     InvalidConfigurationRequest(String),
     /// <p>The specified load balancer does not exist.</p>
     LoadBalancerNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ModifyLoadBalancerAttributesError {
@@ -8071,20 +8290,27 @@ impl ModifyLoadBalancerAttributesError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InvalidConfigurationRequest" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             ModifyLoadBalancerAttributesError::InvalidConfigurationRequest(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "LoadBalancerNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             ModifyLoadBalancerAttributesError::LoadBalancerNotFound(
                                 parsed_error.message,
                             ),
+                            &res,
+                        )
+                    }
+                    _ => {
+                        return RusotoError::unhandled(
+                            ModifyLoadBalancerAttributesError::Unhandled,
+                            &res,
                         )
                     }
-                    _ => {}
                 }
             }
         }
@@ -8109,12 +8335,14 @@ impl fmt::Display for ModifyLoadBalancerAttributesError {
             ModifyLoadBalancerAttributesError::LoadBalancerNotFound(ref cause) => {
                 write!(f, "{}", cause)
             }
+            ModifyLoadBalancerAttributesError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ModifyLoadBalancerAttributesError {}
 /// Errors returned by ModifyRule
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ModifyRuleError {
     /// <p>The specified configuration is not valid with this protocol.</p>
     IncompatibleProtocols(String),
@@ -8138,6 +8366,8 @@ pub enum ModifyRuleError {
     TooManyUniqueTargetGroupsPerLoadBalancer(String),
     /// <p>The specified protocol is not supported.</p>
     UnsupportedProtocol(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ModifyRuleError {
@@ -8149,63 +8379,74 @@ impl ModifyRuleError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "IncompatibleProtocols" => {
-                        return RusotoError::Service(ModifyRuleError::IncompatibleProtocols(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            ModifyRuleError::IncompatibleProtocols(parsed_error.message),
+                            &res,
+                        )
                     }
                     "InvalidLoadBalancerAction" => {
-                        return RusotoError::Service(ModifyRuleError::InvalidLoadBalancerAction(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            ModifyRuleError::InvalidLoadBalancerAction(parsed_error.message),
+                            &res,
+                        )
                     }
                     "OperationNotPermitted" => {
-                        return RusotoError::Service(ModifyRuleError::OperationNotPermitted(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            ModifyRuleError::OperationNotPermitted(parsed_error.message),
+                            &res,
+                        )
                     }
                     "RuleNotFound" => {
-                        return RusotoError::Service(ModifyRuleError::RuleNotFound(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            ModifyRuleError::RuleNotFound(parsed_error.message),
+                            &res,
+                        )
                     }
                     "TargetGroupAssociationLimit" => {
-                        return RusotoError::Service(ModifyRuleError::TargetGroupAssociationLimit(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            ModifyRuleError::TargetGroupAssociationLimit(parsed_error.message),
+                            &res,
+                        )
                     }
                     "TargetGroupNotFound" => {
-                        return RusotoError::Service(ModifyRuleError::TargetGroupNotFound(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            ModifyRuleError::TargetGroupNotFound(parsed_error.message),
+                            &res,
+                        )
                     }
                     "TooManyActions" => {
-                        return RusotoError::Service(ModifyRuleError::TooManyActions(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            ModifyRuleError::TooManyActions(parsed_error.message),
+                            &res,
+                        )
                     }
                     "TooManyRegistrationsForTargetId" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             ModifyRuleError::TooManyRegistrationsForTargetId(parsed_error.message),
+                            &res,
                         )
                     }
                     "TooManyTargets" => {
-                        return RusotoError::Service(ModifyRuleError::TooManyTargets(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            ModifyRuleError::TooManyTargets(parsed_error.message),
+                            &res,
+                        )
                     }
                     "TooManyUniqueTargetGroupsPerLoadBalancer" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             ModifyRuleError::TooManyUniqueTargetGroupsPerLoadBalancer(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "UnsupportedProtocol" => {
-                        return RusotoError::Service(ModifyRuleError::UnsupportedProtocol(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            ModifyRuleError::UnsupportedProtocol(parsed_error.message),
+                            &res,
+                        )
                     }
-                    _ => {}
+                    _ => return RusotoError::unhandled(ModifyRuleError::Unhandled, &res),
                 }
             }
         }
@@ -8237,17 +8478,21 @@ impl fmt::Display for ModifyRuleError {
                 write!(f, "{}", cause)
             }
             ModifyRuleError::UnsupportedProtocol(ref cause) => write!(f, "{}", cause),
+            ModifyRuleError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ModifyRuleError {}
 /// Errors returned by ModifyTargetGroup
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ModifyTargetGroupError {
     /// <p>The requested configuration is not valid.</p>
     InvalidConfigurationRequest(String),
     /// <p>The specified target group does not exist.</p>
     TargetGroupNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ModifyTargetGroupError {
@@ -8259,18 +8504,20 @@ impl ModifyTargetGroupError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InvalidConfigurationRequest" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             ModifyTargetGroupError::InvalidConfigurationRequest(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "TargetGroupNotFound" => {
-                        return RusotoError::Service(ModifyTargetGroupError::TargetGroupNotFound(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            ModifyTargetGroupError::TargetGroupNotFound(parsed_error.message),
+                            &res,
+                        )
                     }
-                    _ => {}
+                    _ => return RusotoError::unhandled(ModifyTargetGroupError::Unhandled, &res),
                 }
             }
         }
@@ -8293,17 +8540,21 @@ impl fmt::Display for ModifyTargetGroupError {
                 write!(f, "{}", cause)
             }
             ModifyTargetGroupError::TargetGroupNotFound(ref cause) => write!(f, "{}", cause),
+            ModifyTargetGroupError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ModifyTargetGroupError {}
 /// Errors returned by ModifyTargetGroupAttributes
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ModifyTargetGroupAttributesError {
     /// <p>The requested configuration is not valid.</p>
     InvalidConfigurationRequest(String),
     /// <p>The specified target group does not exist.</p>
     TargetGroupNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ModifyTargetGroupAttributesError {
@@ -8317,20 +8568,27 @@ impl ModifyTargetGroupAttributesError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InvalidConfigurationRequest" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             ModifyTargetGroupAttributesError::InvalidConfigurationRequest(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "TargetGroupNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             ModifyTargetGroupAttributesError::TargetGroupNotFound(
                                 parsed_error.message,
                             ),
+                            &res,
+                        )
+                    }
+                    _ => {
+                        return RusotoError::unhandled(
+                            ModifyTargetGroupAttributesError::Unhandled,
+                            &res,
                         )
                     }
-                    _ => {}
                 }
             }
         }
@@ -8355,12 +8613,14 @@ impl fmt::Display for ModifyTargetGroupAttributesError {
             ModifyTargetGroupAttributesError::TargetGroupNotFound(ref cause) => {
                 write!(f, "{}", cause)
             }
+            ModifyTargetGroupAttributesError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ModifyTargetGroupAttributesError {}
 /// Errors returned by RegisterTargets
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum RegisterTargetsError {
     /// <p>The specified target does not exist, is not in the same VPC as the target group, or has an unsupported instance type.</p>
     InvalidTarget(String),
@@ -8370,6 +8630,8 @@ pub enum RegisterTargetsError {
     TooManyRegistrationsForTargetId(String),
     /// <p>You've reached the limit on the number of targets.</p>
     TooManyTargets(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl RegisterTargetsError {
@@ -8381,28 +8643,32 @@ impl RegisterTargetsError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InvalidTarget" => {
-                        return RusotoError::Service(RegisterTargetsError::InvalidTarget(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            RegisterTargetsError::InvalidTarget(parsed_error.message),
+                            &res,
+                        )
                     }
                     "TargetGroupNotFound" => {
-                        return RusotoError::Service(RegisterTargetsError::TargetGroupNotFound(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            RegisterTargetsError::TargetGroupNotFound(parsed_error.message),
+                            &res,
+                        )
                     }
                     "TooManyRegistrationsForTargetId" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             RegisterTargetsError::TooManyRegistrationsForTargetId(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "TooManyTargets" => {
-                        return RusotoError::Service(RegisterTargetsError::TooManyTargets(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            RegisterTargetsError::TooManyTargets(parsed_error.message),
+                            &res,
+                        )
                     }
-                    _ => {}
+                    _ => return RusotoError::unhandled(RegisterTargetsError::Unhandled, &res),
                 }
             }
         }
@@ -8427,17 +8693,21 @@ impl fmt::Display for RegisterTargetsError {
                 write!(f, "{}", cause)
             }
             RegisterTargetsError::TooManyTargets(ref cause) => write!(f, "{}", cause),
+            RegisterTargetsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for RegisterTargetsError {}
 /// Errors returned by RemoveListenerCertificates
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum RemoveListenerCertificatesError {
     /// <p>The specified listener does not exist.</p>
     ListenerNotFound(String),
     /// <p>This operation is not allowed.</p>
     OperationNotPermitted(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl RemoveListenerCertificatesError {
@@ -8451,18 +8721,25 @@ impl RemoveListenerCertificatesError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "ListenerNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             RemoveListenerCertificatesError::ListenerNotFound(parsed_error.message),
+                            &res,
                         )
                     }
                     "OperationNotPermitted" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             RemoveListenerCertificatesError::OperationNotPermitted(
                                 parsed_error.message,
                             ),
+                            &res,
+                        )
+                    }
+                    _ => {
+                        return RusotoError::unhandled(
+                            RemoveListenerCertificatesError::Unhandled,
+                            &res,
                         )
                     }
-                    _ => {}
                 }
             }
         }
@@ -8485,12 +8762,14 @@ impl fmt::Display for RemoveListenerCertificatesError {
             RemoveListenerCertificatesError::OperationNotPermitted(ref cause) => {
                 write!(f, "{}", cause)
             }
+            RemoveListenerCertificatesError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for RemoveListenerCertificatesError {}
 /// Errors returned by RemoveTags
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum RemoveTagsError {
     /// <p>The specified listener does not exist.</p>
     ListenerNotFound(String),
@@ -8502,6 +8781,8 @@ pub enum RemoveTagsError {
     TargetGroupNotFound(String),
     /// <p>You've reached the limit on the number of tags per load balancer.</p>
     TooManyTags(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl RemoveTagsError {
@@ -8513,31 +8794,36 @@ impl RemoveTagsError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "ListenerNotFound" => {
-                        return RusotoError::Service(RemoveTagsError::ListenerNotFound(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            RemoveTagsError::ListenerNotFound(parsed_error.message),
+                            &res,
+                        )
                     }
                     "LoadBalancerNotFound" => {
-                        return RusotoError::Service(RemoveTagsError::LoadBalancerNotFound(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            RemoveTagsError::LoadBalancerNotFound(parsed_error.message),
+                            &res,
+                        )
                     }
                     "RuleNotFound" => {
-                        return RusotoError::Service(RemoveTagsError::RuleNotFound(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            RemoveTagsError::RuleNotFound(parsed_error.message),
+                            &res,
+                        )
                     }
                     "TargetGroupNotFound" => {
-                        return RusotoError::Service(RemoveTagsError::TargetGroupNotFound(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            RemoveTagsError::TargetGroupNotFound(parsed_error.message),
+                            &res,
+                        )
                     }
                     "TooManyTags" => {
-                        return RusotoError::Service(RemoveTagsError::TooManyTags(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            RemoveTagsError::TooManyTags(parsed_error.message),
+                            &res,
+                        )
                     }
-                    _ => {}
+                    _ => return RusotoError::unhandled(RemoveTagsError::Unhandled, &res),
                 }
             }
         }
@@ -8561,12 +8847,14 @@ impl fmt::Display for RemoveTagsError {
             RemoveTagsError::RuleNotFound(ref cause) => write!(f, "{}", cause),
             RemoveTagsError::TargetGroupNotFound(ref cause) => write!(f, "{}", cause),
             RemoveTagsError::TooManyTags(ref cause) => write!(f, "{}", cause),
+            RemoveTagsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for RemoveTagsError {}
 /// Errors returned by SetIpAddressType
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum SetIpAddressTypeError {
     /// <p>The requested configuration is not valid.</p>
     InvalidConfigurationRequest(String),
@@ -8574,6 +8862,8 @@ pub enum SetIpAddressTypeError {
     InvalidSubnet(String),
     /// <p>The specified load balancer does not exist.</p>
     LoadBalancerNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl SetIpAddressTypeError {
@@ -8585,23 +8875,26 @@ impl SetIpAddressTypeError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InvalidConfigurationRequest" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             SetIpAddressTypeError::InvalidConfigurationRequest(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "InvalidSubnet" => {
-                        return RusotoError::Service(SetIpAddressTypeError::InvalidSubnet(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            SetIpAddressTypeError::InvalidSubnet(parsed_error.message),
+                            &res,
+                        )
                     }
                     "LoadBalancerNotFound" => {
-                        return RusotoError::Service(SetIpAddressTypeError::LoadBalancerNotFound(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            SetIpAddressTypeError::LoadBalancerNotFound(parsed_error.message),
+                            &res,
+                        )
                     }
-                    _ => {}
+                    _ => return RusotoError::unhandled(SetIpAddressTypeError::Unhandled, &res),
                 }
             }
         }
@@ -8623,12 +8916,14 @@ impl fmt::Display for SetIpAddressTypeError {
             SetIpAddressTypeError::InvalidConfigurationRequest(ref cause) => write!(f, "{}", cause),
             SetIpAddressTypeError::InvalidSubnet(ref cause) => write!(f, "{}", cause),
             SetIpAddressTypeError::LoadBalancerNotFound(ref cause) => write!(f, "{}", cause),
+            SetIpAddressTypeError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for SetIpAddressTypeError {}
 /// Errors returned by SetRulePriorities
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum SetRulePrioritiesError {
     /// <p>This operation is not allowed.</p>
     OperationNotPermitted(String),
@@ -8636,6 +8931,8 @@ pub enum SetRulePrioritiesError {
     PriorityInUse(String),
     /// <p>The specified rule does not exist.</p>
     RuleNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl SetRulePrioritiesError {
@@ -8647,21 +8944,24 @@ impl SetRulePrioritiesError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "OperationNotPermitted" => {
-                        return RusotoError::Service(SetRulePrioritiesError::OperationNotPermitted(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            SetRulePrioritiesError::OperationNotPermitted(parsed_error.message),
+                            &res,
+                        )
                     }
                     "PriorityInUse" => {
-                        return RusotoError::Service(SetRulePrioritiesError::PriorityInUse(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            SetRulePrioritiesError::PriorityInUse(parsed_error.message),
+                            &res,
+                        )
                     }
                     "RuleNotFound" => {
-                        return RusotoError::Service(SetRulePrioritiesError::RuleNotFound(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            SetRulePrioritiesError::RuleNotFound(parsed_error.message),
+                            &res,
+                        )
                     }
-                    _ => {}
+                    _ => return RusotoError::unhandled(SetRulePrioritiesError::Unhandled, &res),
                 }
             }
         }
@@ -8683,12 +8983,14 @@ impl fmt::Display for SetRulePrioritiesError {
             SetRulePrioritiesError::OperationNotPermitted(ref cause) => write!(f, "{}", cause),
             SetRulePrioritiesError::PriorityInUse(ref cause) => write!(f, "{}", cause),
             SetRulePrioritiesError::RuleNotFound(ref cause) => write!(f, "{}", cause),
+            SetRulePrioritiesError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for SetRulePrioritiesError {}
 /// Errors returned by SetSecurityGroups
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum SetSecurityGroupsError {
     /// <p>The requested configuration is not valid.</p>
     InvalidConfigurationRequest(String),
@@ -8696,6 +8998,8 @@ pub enum SetSecurityGroupsError {
     InvalidSecurityGroup(String),
     /// <p>The specified load balancer does not exist.</p>
     LoadBalancerNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl SetSecurityGroupsError {
@@ -8707,23 +9011,26 @@ impl SetSecurityGroupsError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InvalidConfigurationRequest" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
                             SetSecurityGroupsError::InvalidConfigurationRequest(
                                 parsed_error.message,
                             ),
+                            &res,
                         )
                     }
                     "InvalidSecurityGroup" => {
-                        return RusotoError::Service(SetSecurityGroupsError::InvalidSecurityGroup(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            SetSecurityGroupsError::InvalidSecurityGroup(parsed_error.message),
+                            &res,
+                        )
                     }
                     "LoadBalancerNotFound" => {
-                        return RusotoError::Service(SetSecurityGroupsError::LoadBalancerNotFound(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            SetSecurityGroupsError::LoadBalancerNotFound(parsed_error.message),
+                            &res,
+                        )
                     }
-                    _ => {}
+                    _ => return RusotoError::unhandled(SetSecurityGroupsError::Unhandled, &res),
                 }
             }
         }
@@ -8747,12 +9054,14 @@ impl fmt::Display for SetSecurityGroupsError {
             }
             SetSecurityGroupsError::InvalidSecurityGroup(ref cause) => write!(f, "{}", cause),
             SetSecurityGroupsError::LoadBalancerNotFound(ref cause) => write!(f, "{}", cause),
+            SetSecurityGroupsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for SetSecurityGroupsError {}
 /// Errors returned by SetSubnets
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum SetSubnetsError {
     /// <p>The specified allocation ID does not exist.</p>
     AllocationIdNotFound(String),
@@ -8766,6 +9075,8 @@ pub enum SetSubnetsError {
     LoadBalancerNotFound(String),
     /// <p>The specified subnet does not exist.</p>
     SubnetNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl SetSubnetsError {
@@ -8777,36 +9088,42 @@ impl SetSubnetsError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "AllocationIdNotFound" => {
-                        return RusotoError::Service(SetSubnetsError::AllocationIdNotFound(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            SetSubnetsError::AllocationIdNotFound(parsed_error.message),
+                            &res,
+                        )
                     }
                     "AvailabilityZoneNotSupported" => {
-                        return RusotoError::Service(SetSubnetsError::AvailabilityZoneNotSupported(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            SetSubnetsError::AvailabilityZoneNotSupported(parsed_error.message),
+                            &res,
+                        )
                     }
                     "InvalidConfigurationRequest" => {
-                        return RusotoError::Service(SetSubnetsError::InvalidConfigurationRequest(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            SetSubnetsError::InvalidConfigurationRequest(parsed_error.message),
+                            &res,
+                        )
                     }
                     "InvalidSubnet" => {
-                        return RusotoError::Service(SetSubnetsError::InvalidSubnet(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            SetSubnetsError::InvalidSubnet(parsed_error.message),
+                            &res,
+                        )
                     }
                     "LoadBalancerNotFound" => {
-                        return RusotoError::Service(SetSubnetsError::LoadBalancerNotFound(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            SetSubnetsError::LoadBalancerNotFound(parsed_error.message),
+                            &res,
+                        )
                     }
                     "SubnetNotFound" => {
-                        return RusotoError::Service(SetSubnetsError::SubnetNotFound(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            SetSubnetsError::SubnetNotFound(parsed_error.message),
+                            &res,
+                        )
                     }
-                    _ => {}
+                    _ => return RusotoError::unhandled(SetSubnetsError::Unhandled, &res),
                 }
             }
         }
@@ -8831,6 +9148,7 @@ impl fmt::Display for SetSubnetsError {
             SetSubnetsError::InvalidSubnet(ref cause) => write!(f, "{}", cause),
             SetSubnetsError::LoadBalancerNotFound(ref cause) => write!(f, "{}", cause),
             SetSubnetsError::SubnetNotFound(ref cause) => write!(f, "{}", cause),
+            SetSubnetsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
@@ -9056,6 +9374,7 @@ impl ElbClient {
     /// Creates a client backed by the default tokio event loop.
     ///
     /// The client will use the default credentials provider and tls client.
+    #[cfg(not(target_arch = "wasm32"))]
     pub fn new(region: region::Region) -> ElbClient {
         ElbClient {
             client: Client::shared(),
@@ -9081,35 +9400,52 @@ impl ElbClient {
     pub fn new_with_client(client: Client, region: region::Region) -> ElbClient {
         ElbClient { client, region }
     }
+
+    /// Returns a builder of a client, setting any of its region, credentials provider,
+    /// dispatcher, retry policy, endpoint and timeouts.
+    pub fn builder() -> rusoto_core::ClientBuilder<ElbClient> {
+        rusoto_core::ClientBuilder::new(ElbClient::new_with_client)
+    }
+
+    /// Returns a clone of this client with the overrides of `config` applied to its
+    /// operations, sharing its connections.
+    pub fn customize(&self, config: &rusoto_core::OperationConfig) -> ElbClient {
+        ElbClient {
+            client: self.client.customize(config),
+            region: config.resolve_region(&self.region),
+        }
+    }
 }
 
-#[async_trait]
-impl Elb for ElbClient {
-    /// <p>Adds the specified SSL server certificate to the certificate list for the specified HTTPS or TLS listener.</p> <p>If the certificate in already in the certificate list, the call is successful but the certificate is not added again.</p> <p>To get the certificate list for a listener, use <a>DescribeListenerCertificates</a>. To remove certificates from the certificate list for a listener, use <a>RemoveListenerCertificates</a>. To replace the default certificate for a listener, use <a>ModifyListener</a>.</p> <p>For more information, see <a href="https://docs.aws.amazon.com/elasticloadbalancing/latest/application/create-https-listener.html#https-listener-certificates">SSL Certificates</a> in the <i>Application Load Balancers Guide</i>.</p>
-    async fn add_listener_certificates(
+impl ElbClient {
+    /// Calls `add_listener_certificates`, also returning the metadata of the response.
+    pub async fn add_listener_certificates_with_metadata(
         &self,
         input: AddListenerCertificatesInput,
-    ) -> Result<AddListenerCertificatesOutput, RusotoError<AddListenerCertificatesError>> {
-        let mut request = SignedRequest::new("POST", "elasticloadbalancing", &self.region, "/");
-        let mut params = Params::new();
-
-        params.put("Action", "AddListenerCertificates");
-        params.put("Version", "2015-12-01");
-        AddListenerCertificatesInputSerializer::serialize(&mut params, "", &input);
-        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+    ) -> Result<
+        (AddListenerCertificatesOutput, ResponseMetadata),
+        RusotoError<AddListenerCertificatesError>,
+    > {
+        let request = self.build_add_listener_certificates_request(input);
 
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(AddListenerCertificatesError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -9131,34 +9467,61 @@ impl Elb for ElbClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Adds the specified tags to the specified Elastic Load Balancing resource. You can tag your Application Load Balancers, Network Load Balancers, and your target groups.</p> <p>Each tag consists of a key and an optional value. If a resource already has a tag with the same key, <code>AddTags</code> updates its value.</p> <p>To list the current tags for your resources, use <a>DescribeTags</a>. To remove tags from your resources, use <a>RemoveTags</a>.</p>
-    async fn add_tags(
+    /// Presigns the request `add_listener_certificates` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_add_listener_certificates(
         &self,
-        input: AddTagsInput,
-    ) -> Result<AddTagsOutput, RusotoError<AddTagsError>> {
+        presigner: &rusoto_core::Presigner,
+        input: AddListenerCertificatesInput,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_add_listener_certificates_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_add_listener_certificates_request(
+        &self,
+        input: AddListenerCertificatesInput,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "elasticloadbalancing", &self.region, "/");
+        request.set_operation("AddListenerCertificates");
         let mut params = Params::new();
 
-        params.put("Action", "AddTags");
+        params.put("Action", "AddListenerCertificates");
         params.put("Version", "2015-12-01");
-        AddTagsInputSerializer::serialize(&mut params, "", &input);
+        AddListenerCertificatesInputSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `add_tags`, also returning the metadata of the response.
+    pub async fn add_tags_with_metadata(
+        &self,
+        input: AddTagsInput,
+    ) -> Result<(AddTagsOutput, ResponseMetadata), RusotoError<AddTagsError>> {
+        let request = self.build_add_tags_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(AddTagsError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -9177,34 +9540,58 @@ impl Elb for ElbClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Creates a listener for the specified Application Load Balancer or Network Load Balancer.</p> <p>To update a listener, use <a>ModifyListener</a>. When you are finished with a listener, you can delete it using <a>DeleteListener</a>. If you are finished with both the listener and the load balancer, you can delete them both using <a>DeleteLoadBalancer</a>.</p> <p>This operation is idempotent, which means that it completes at most one time. If you attempt to create multiple listeners with the same settings, each call succeeds.</p> <p>For more information, see <a href="https://docs.aws.amazon.com/elasticloadbalancing/latest/application/load-balancer-listeners.html">Listeners for Your Application Load Balancers</a> in the <i>Application Load Balancers Guide</i> and <a href="https://docs.aws.amazon.com/elasticloadbalancing/latest/network/load-balancer-listeners.html">Listeners for Your Network Load Balancers</a> in the <i>Network Load Balancers Guide</i>.</p>
-    async fn create_listener(
+    /// Presigns the request `add_tags` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_add_tags(
         &self,
-        input: CreateListenerInput,
-    ) -> Result<CreateListenerOutput, RusotoError<CreateListenerError>> {
+        presigner: &rusoto_core::Presigner,
+        input: AddTagsInput,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_add_tags_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_add_tags_request(&self, input: AddTagsInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "elasticloadbalancing", &self.region, "/");
+        request.set_operation("AddTags");
         let mut params = Params::new();
 
-        params.put("Action", "CreateListener");
+        params.put("Action", "AddTags");
         params.put("Version", "2015-12-01");
-        CreateListenerInputSerializer::serialize(&mut params, "", &input);
+        AddTagsInputSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `create_listener`, also returning the metadata of the response.
+    pub async fn create_listener_with_metadata(
+        &self,
+        input: CreateListenerInput,
+    ) -> Result<(CreateListenerOutput, ResponseMetadata), RusotoError<CreateListenerError>> {
+        let request = self.build_create_listener_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(CreateListenerError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -9224,34 +9611,59 @@ impl Elb for ElbClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Creates an Application Load Balancer or a Network Load Balancer.</p> <p>When you create a load balancer, you can specify security groups, public subnets, IP address type, and tags. Otherwise, you could do so later using <a>SetSecurityGroups</a>, <a>SetSubnets</a>, <a>SetIpAddressType</a>, and <a>AddTags</a>.</p> <p>To create listeners for your load balancer, use <a>CreateListener</a>. To describe your current load balancers, see <a>DescribeLoadBalancers</a>. When you are finished with a load balancer, you can delete it using <a>DeleteLoadBalancer</a>.</p> <p>For limit information, see <a href="https://docs.aws.amazon.com/elasticloadbalancing/latest/application/load-balancer-limits.html">Limits for Your Application Load Balancer</a> in the <i>Application Load Balancers Guide</i> and <a href="https://docs.aws.amazon.com/elasticloadbalancing/latest/network/load-balancer-limits.html">Limits for Your Network Load Balancer</a> in the <i>Network Load Balancers Guide</i>.</p> <p>This operation is idempotent, which means that it completes at most one time. If you attempt to create multiple load balancers with the same settings, each call succeeds.</p> <p>For more information, see <a href="https://docs.aws.amazon.com/elasticloadbalancing/latest/application/application-load-balancers.html">Application Load Balancers</a> in the <i>Application Load Balancers Guide</i> and <a href="https://docs.aws.amazon.com/elasticloadbalancing/latest/network/network-load-balancers.html">Network Load Balancers</a> in the <i>Network Load Balancers Guide</i>.</p>
-    async fn create_load_balancer(
+    /// Presigns the request `create_listener` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_listener(
         &self,
-        input: CreateLoadBalancerInput,
-    ) -> Result<CreateLoadBalancerOutput, RusotoError<CreateLoadBalancerError>> {
+        presigner: &rusoto_core::Presigner,
+        input: CreateListenerInput,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_listener_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_listener_request(&self, input: CreateListenerInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "elasticloadbalancing", &self.region, "/");
+        request.set_operation("CreateListener");
         let mut params = Params::new();
 
-        params.put("Action", "CreateLoadBalancer");
+        params.put("Action", "CreateListener");
         params.put("Version", "2015-12-01");
-        CreateLoadBalancerInputSerializer::serialize(&mut params, "", &input);
+        CreateListenerInputSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `create_load_balancer`, also returning the metadata of the response.
+    pub async fn create_load_balancer_with_metadata(
+        &self,
+        input: CreateLoadBalancerInput,
+    ) -> Result<(CreateLoadBalancerOutput, ResponseMetadata), RusotoError<CreateLoadBalancerError>>
+    {
+        let request = self.build_create_load_balancer_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(CreateLoadBalancerError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -9273,34 +9685,58 @@ impl Elb for ElbClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Creates a rule for the specified listener. The listener must be associated with an Application Load Balancer.</p> <p>Rules are evaluated in priority order, from the lowest value to the highest value. When the conditions for a rule are met, its actions are performed. If the conditions for no rules are met, the actions for the default rule are performed. For more information, see <a href="https://docs.aws.amazon.com/elasticloadbalancing/latest/application/load-balancer-listeners.html#listener-rules">Listener Rules</a> in the <i>Application Load Balancers Guide</i>.</p> <p>To view your current rules, use <a>DescribeRules</a>. To update a rule, use <a>ModifyRule</a>. To set the priorities of your rules, use <a>SetRulePriorities</a>. To delete a rule, use <a>DeleteRule</a>.</p>
-    async fn create_rule(
+    /// Presigns the request `create_load_balancer` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_load_balancer(
         &self,
-        input: CreateRuleInput,
-    ) -> Result<CreateRuleOutput, RusotoError<CreateRuleError>> {
+        presigner: &rusoto_core::Presigner,
+        input: CreateLoadBalancerInput,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_load_balancer_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_load_balancer_request(&self, input: CreateLoadBalancerInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "elasticloadbalancing", &self.region, "/");
+        request.set_operation("CreateLoadBalancer");
         let mut params = Params::new();
 
-        params.put("Action", "CreateRule");
+        params.put("Action", "CreateLoadBalancer");
         params.put("Version", "2015-12-01");
-        CreateRuleInputSerializer::serialize(&mut params, "", &input);
+        CreateLoadBalancerInputSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `create_rule`, also returning the metadata of the response.
+    pub async fn create_rule_with_metadata(
+        &self,
+        input: CreateRuleInput,
+    ) -> Result<(CreateRuleOutput, ResponseMetadata), RusotoError<CreateRuleError>> {
+        let request = self.build_create_rule_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(CreateRuleError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -9319,34 +9755,59 @@ impl Elb for ElbClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Creates a target group.</p> <p>To register targets with the target group, use <a>RegisterTargets</a>. To update the health check settings for the target group, use <a>ModifyTargetGroup</a>. To monitor the health of targets in the target group, use <a>DescribeTargetHealth</a>.</p> <p>To route traffic to the targets in a target group, specify the target group in an action using <a>CreateListener</a> or <a>CreateRule</a>.</p> <p>To delete a target group, use <a>DeleteTargetGroup</a>.</p> <p>This operation is idempotent, which means that it completes at most one time. If you attempt to create multiple target groups with the same settings, each call succeeds.</p> <p>For more information, see <a href="https://docs.aws.amazon.com/elasticloadbalancing/latest/application/load-balancer-target-groups.html">Target Groups for Your Application Load Balancers</a> in the <i>Application Load Balancers Guide</i> or <a href="https://docs.aws.amazon.com/elasticloadbalancing/latest/network/load-balancer-target-groups.html">Target Groups for Your Network Load Balancers</a> in the <i>Network Load Balancers Guide</i>.</p>
-    async fn create_target_group(
+    /// Presigns the request `create_rule` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_rule(
         &self,
-        input: CreateTargetGroupInput,
-    ) -> Result<CreateTargetGroupOutput, RusotoError<CreateTargetGroupError>> {
+        presigner: &rusoto_core::Presigner,
+        input: CreateRuleInput,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_rule_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_rule_request(&self, input: CreateRuleInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "elasticloadbalancing", &self.region, "/");
+        request.set_operation("CreateRule");
         let mut params = Params::new();
 
-        params.put("Action", "CreateTargetGroup");
+        params.put("Action", "CreateRule");
         params.put("Version", "2015-12-01");
-        CreateTargetGroupInputSerializer::serialize(&mut params, "", &input);
+        CreateRuleInputSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `create_target_group`, also returning the metadata of the response.
+    pub async fn create_target_group_with_metadata(
+        &self,
+        input: CreateTargetGroupInput,
+    ) -> Result<(CreateTargetGroupOutput, ResponseMetadata), RusotoError<CreateTargetGroupError>>
+    {
+        let request = self.build_create_target_group_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(CreateTargetGroupError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -9368,34 +9829,58 @@ impl Elb for ElbClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Deletes the specified listener.</p> <p>Alternatively, your listener is deleted when you delete the load balancer to which it is attached, using <a>DeleteLoadBalancer</a>.</p>
-    async fn delete_listener(
+    /// Presigns the request `create_target_group` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_target_group(
         &self,
-        input: DeleteListenerInput,
-    ) -> Result<DeleteListenerOutput, RusotoError<DeleteListenerError>> {
+        presigner: &rusoto_core::Presigner,
+        input: CreateTargetGroupInput,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_target_group_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_target_group_request(&self, input: CreateTargetGroupInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "elasticloadbalancing", &self.region, "/");
+        request.set_operation("CreateTargetGroup");
         let mut params = Params::new();
 
-        params.put("Action", "DeleteListener");
+        params.put("Action", "CreateTargetGroup");
         params.put("Version", "2015-12-01");
-        DeleteListenerInputSerializer::serialize(&mut params, "", &input);
+        CreateTargetGroupInputSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
-        let mut response = self
-            .client
-            .sign_and_dispatch(request)
-            .await
+        request
+    }
+
+    /// Calls `delete_listener`, also returning the metadata of the response.
+    pub async fn delete_listener_with_metadata(
+        &self,
+        input: DeleteListenerInput,
+    ) -> Result<(DeleteListenerOutput, ResponseMetadata), RusotoError<DeleteListenerError>> {
+        let request = self.build_delete_listener_request(input);
+
+        let mut response = self
+            .client
+            .sign_and_dispatch(request)
+            .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(DeleteListenerError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -9415,34 +9900,59 @@ impl Elb for ElbClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Deletes the specified Application Load Balancer or Network Load Balancer and its attached listeners.</p> <p>You can't delete a load balancer if deletion protection is enabled. If the load balancer does not exist or has already been deleted, the call succeeds.</p> <p>Deleting a load balancer does not affect its registered targets. For example, your EC2 instances continue to run and are still registered to their target groups. If you no longer need these EC2 instances, you can stop or terminate them.</p>
-    async fn delete_load_balancer(
+    /// Presigns the request `delete_listener` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_listener(
         &self,
-        input: DeleteLoadBalancerInput,
-    ) -> Result<DeleteLoadBalancerOutput, RusotoError<DeleteLoadBalancerError>> {
+        presigner: &rusoto_core::Presigner,
+        input: DeleteListenerInput,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_listener_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_listener_request(&self, input: DeleteListenerInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "elasticloadbalancing", &self.region, "/");
+        request.set_operation("DeleteListener");
         let mut params = Params::new();
 
-        params.put("Action", "DeleteLoadBalancer");
+        params.put("Action", "DeleteListener");
         params.put("Version", "2015-12-01");
-        DeleteLoadBalancerInputSerializer::serialize(&mut params, "", &input);
+        DeleteListenerInputSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `delete_load_balancer`, also returning the metadata of the response.
+    pub async fn delete_load_balancer_with_metadata(
+        &self,
+        input: DeleteLoadBalancerInput,
+    ) -> Result<(DeleteLoadBalancerOutput, ResponseMetadata), RusotoError<DeleteLoadBalancerError>>
+    {
+        let request = self.build_delete_load_balancer_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(DeleteLoadBalancerError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -9464,34 +9974,58 @@ impl Elb for ElbClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Deletes the specified rule.</p>
-    async fn delete_rule(
+    /// Presigns the request `delete_load_balancer` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_load_balancer(
         &self,
-        input: DeleteRuleInput,
-    ) -> Result<DeleteRuleOutput, RusotoError<DeleteRuleError>> {
+        presigner: &rusoto_core::Presigner,
+        input: DeleteLoadBalancerInput,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_load_balancer_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_load_balancer_request(&self, input: DeleteLoadBalancerInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "elasticloadbalancing", &self.region, "/");
+        request.set_operation("DeleteLoadBalancer");
         let mut params = Params::new();
 
-        params.put("Action", "DeleteRule");
+        params.put("Action", "DeleteLoadBalancer");
         params.put("Version", "2015-12-01");
-        DeleteRuleInputSerializer::serialize(&mut params, "", &input);
+        DeleteLoadBalancerInputSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `delete_rule`, also returning the metadata of the response.
+    pub async fn delete_rule_with_metadata(
+        &self,
+        input: DeleteRuleInput,
+    ) -> Result<(DeleteRuleOutput, ResponseMetadata), RusotoError<DeleteRuleError>> {
+        let request = self.build_delete_rule_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(DeleteRuleError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -9510,34 +10044,59 @@ impl Elb for ElbClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Deletes the specified target group.</p> <p>You can delete a target group if it is not referenced by any actions. Deleting a target group also deletes any associated health checks.</p>
-    async fn delete_target_group(
+    /// Presigns the request `delete_rule` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_rule(
         &self,
-        input: DeleteTargetGroupInput,
-    ) -> Result<DeleteTargetGroupOutput, RusotoError<DeleteTargetGroupError>> {
+        presigner: &rusoto_core::Presigner,
+        input: DeleteRuleInput,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_rule_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_rule_request(&self, input: DeleteRuleInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "elasticloadbalancing", &self.region, "/");
+        request.set_operation("DeleteRule");
         let mut params = Params::new();
 
-        params.put("Action", "DeleteTargetGroup");
+        params.put("Action", "DeleteRule");
         params.put("Version", "2015-12-01");
-        DeleteTargetGroupInputSerializer::serialize(&mut params, "", &input);
+        DeleteRuleInputSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `delete_target_group`, also returning the metadata of the response.
+    pub async fn delete_target_group_with_metadata(
+        &self,
+        input: DeleteTargetGroupInput,
+    ) -> Result<(DeleteTargetGroupOutput, ResponseMetadata), RusotoError<DeleteTargetGroupError>>
+    {
+        let request = self.build_delete_target_group_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(DeleteTargetGroupError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -9559,34 +10118,59 @@ impl Elb for ElbClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Deregisters the specified targets from the specified target group. After the targets are deregistered, they no longer receive traffic from the load balancer.</p>
-    async fn deregister_targets(
+    /// Presigns the request `delete_target_group` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_target_group(
         &self,
-        input: DeregisterTargetsInput,
-    ) -> Result<DeregisterTargetsOutput, RusotoError<DeregisterTargetsError>> {
+        presigner: &rusoto_core::Presigner,
+        input: DeleteTargetGroupInput,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_target_group_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_target_group_request(&self, input: DeleteTargetGroupInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "elasticloadbalancing", &self.region, "/");
+        request.set_operation("DeleteTargetGroup");
         let mut params = Params::new();
 
-        params.put("Action", "DeregisterTargets");
+        params.put("Action", "DeleteTargetGroup");
         params.put("Version", "2015-12-01");
-        DeregisterTargetsInputSerializer::serialize(&mut params, "", &input);
+        DeleteTargetGroupInputSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `deregister_targets`, also returning the metadata of the response.
+    pub async fn deregister_targets_with_metadata(
+        &self,
+        input: DeregisterTargetsInput,
+    ) -> Result<(DeregisterTargetsOutput, ResponseMetadata), RusotoError<DeregisterTargetsError>>
+    {
+        let request = self.build_deregister_targets_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(DeregisterTargetsError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -9608,34 +10192,61 @@ impl Elb for ElbClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Describes the current Elastic Load Balancing resource limits for your AWS account.</p> <p>For more information, see <a href="https://docs.aws.amazon.com/elasticloadbalancing/latest/application/load-balancer-limits.html">Limits for Your Application Load Balancers</a> in the <i>Application Load Balancer Guide</i> or <a href="https://docs.aws.amazon.com/elasticloadbalancing/latest/network/load-balancer-limits.html">Limits for Your Network Load Balancers</a> in the <i>Network Load Balancers Guide</i>.</p>
-    async fn describe_account_limits(
+    /// Presigns the request `deregister_targets` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_deregister_targets(
         &self,
-        input: DescribeAccountLimitsInput,
-    ) -> Result<DescribeAccountLimitsOutput, RusotoError<DescribeAccountLimitsError>> {
+        presigner: &rusoto_core::Presigner,
+        input: DeregisterTargetsInput,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_deregister_targets_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_deregister_targets_request(&self, input: DeregisterTargetsInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "elasticloadbalancing", &self.region, "/");
+        request.set_operation("DeregisterTargets");
         let mut params = Params::new();
 
-        params.put("Action", "DescribeAccountLimits");
+        params.put("Action", "DeregisterTargets");
         params.put("Version", "2015-12-01");
-        DescribeAccountLimitsInputSerializer::serialize(&mut params, "", &input);
+        DeregisterTargetsInputSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `describe_account_limits`, also returning the metadata of the response.
+    pub async fn describe_account_limits_with_metadata(
+        &self,
+        input: DescribeAccountLimitsInput,
+    ) -> Result<
+        (DescribeAccountLimitsOutput, ResponseMetadata),
+        RusotoError<DescribeAccountLimitsError>,
+    > {
+        let request = self.build_describe_account_limits_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(DescribeAccountLimitsError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -9657,35 +10268,64 @@ impl Elb for ElbClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Describes the default certificate and the certificate list for the specified HTTPS or TLS listener.</p> <p>If the default certificate is also in the certificate list, it appears twice in the results (once with <code>IsDefault</code> set to true and once with <code>IsDefault</code> set to false).</p> <p>For more information, see <a href="https://docs.aws.amazon.com/elasticloadbalancing/latest/application/create-https-listener.html#https-listener-certificates">SSL Certificates</a> in the <i>Application Load Balancers Guide</i>.</p>
-    async fn describe_listener_certificates(
+    /// Presigns the request `describe_account_limits` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_account_limits(
         &self,
-        input: DescribeListenerCertificatesInput,
-    ) -> Result<DescribeListenerCertificatesOutput, RusotoError<DescribeListenerCertificatesError>>
-    {
+        presigner: &rusoto_core::Presigner,
+        input: DescribeAccountLimitsInput,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_account_limits_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_account_limits_request(
+        &self,
+        input: DescribeAccountLimitsInput,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "elasticloadbalancing", &self.region, "/");
+        request.set_operation("DescribeAccountLimits");
         let mut params = Params::new();
 
-        params.put("Action", "DescribeListenerCertificates");
+        params.put("Action", "DescribeAccountLimits");
         params.put("Version", "2015-12-01");
-        DescribeListenerCertificatesInputSerializer::serialize(&mut params, "", &input);
+        DescribeAccountLimitsInputSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `describe_listener_certificates`, also returning the metadata of the response.
+    pub async fn describe_listener_certificates_with_metadata(
+        &self,
+        input: DescribeListenerCertificatesInput,
+    ) -> Result<
+        (DescribeListenerCertificatesOutput, ResponseMetadata),
+        RusotoError<DescribeListenerCertificatesError>,
+    > {
+        let request = self.build_describe_listener_certificates_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(DescribeListenerCertificatesError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -9707,34 +10347,62 @@ impl Elb for ElbClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Describes the specified listeners or the listeners for the specified Application Load Balancer or Network Load Balancer. You must specify either a load balancer or one or more listeners.</p> <p>For an HTTPS or TLS listener, the output includes the default certificate for the listener. To describe the certificate list for the listener, use <a>DescribeListenerCertificates</a>.</p>
-    async fn describe_listeners(
+    /// Presigns the request `describe_listener_certificates` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_listener_certificates(
         &self,
-        input: DescribeListenersInput,
-    ) -> Result<DescribeListenersOutput, RusotoError<DescribeListenersError>> {
+        presigner: &rusoto_core::Presigner,
+        input: DescribeListenerCertificatesInput,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_listener_certificates_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_listener_certificates_request(
+        &self,
+        input: DescribeListenerCertificatesInput,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "elasticloadbalancing", &self.region, "/");
+        request.set_operation("DescribeListenerCertificates");
         let mut params = Params::new();
 
-        params.put("Action", "DescribeListeners");
+        params.put("Action", "DescribeListenerCertificates");
         params.put("Version", "2015-12-01");
-        DescribeListenersInputSerializer::serialize(&mut params, "", &input);
+        DescribeListenerCertificatesInputSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `describe_listeners`, also returning the metadata of the response.
+    pub async fn describe_listeners_with_metadata(
+        &self,
+        input: DescribeListenersInput,
+    ) -> Result<(DescribeListenersOutput, ResponseMetadata), RusotoError<DescribeListenersError>>
+    {
+        let request = self.build_describe_listeners_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(DescribeListenersError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -9756,37 +10424,61 @@ impl Elb for ElbClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Describes the attributes for the specified Application Load Balancer or Network Load Balancer.</p> <p>For more information, see <a href="https://docs.aws.amazon.com/elasticloadbalancing/latest/application/application-load-balancers.html#load-balancer-attributes">Load Balancer Attributes</a> in the <i>Application Load Balancers Guide</i> or <a href="https://docs.aws.amazon.com/elasticloadbalancing/latest/network/network-load-balancers.html#load-balancer-attributes">Load Balancer Attributes</a> in the <i>Network Load Balancers Guide</i>.</p>
-    async fn describe_load_balancer_attributes(
+    /// Presigns the request `describe_listeners` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_listeners(
         &self,
-        input: DescribeLoadBalancerAttributesInput,
-    ) -> Result<
-        DescribeLoadBalancerAttributesOutput,
-        RusotoError<DescribeLoadBalancerAttributesError>,
-    > {
+        presigner: &rusoto_core::Presigner,
+        input: DescribeListenersInput,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_listeners_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_listeners_request(&self, input: DescribeListenersInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "elasticloadbalancing", &self.region, "/");
+        request.set_operation("DescribeListeners");
         let mut params = Params::new();
 
-        params.put("Action", "DescribeLoadBalancerAttributes");
+        params.put("Action", "DescribeListeners");
         params.put("Version", "2015-12-01");
-        DescribeLoadBalancerAttributesInputSerializer::serialize(&mut params, "", &input);
+        DescribeListenersInputSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `describe_load_balancer_attributes`, also returning the metadata of the response.
+    pub async fn describe_load_balancer_attributes_with_metadata(
+        &self,
+        input: DescribeLoadBalancerAttributesInput,
+    ) -> Result<
+        (DescribeLoadBalancerAttributesOutput, ResponseMetadata),
+        RusotoError<DescribeLoadBalancerAttributesError>,
+    > {
+        let request = self.build_describe_load_balancer_attributes_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(DescribeLoadBalancerAttributesError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -9808,34 +10500,64 @@ impl Elb for ElbClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Describes the specified load balancers or all of your load balancers.</p> <p>To describe the listeners for a load balancer, use <a>DescribeListeners</a>. To describe the attributes for a load balancer, use <a>DescribeLoadBalancerAttributes</a>.</p>
-    async fn describe_load_balancers(
+    /// Presigns the request `describe_load_balancer_attributes` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_load_balancer_attributes(
         &self,
-        input: DescribeLoadBalancersInput,
-    ) -> Result<DescribeLoadBalancersOutput, RusotoError<DescribeLoadBalancersError>> {
+        presigner: &rusoto_core::Presigner,
+        input: DescribeLoadBalancerAttributesInput,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_load_balancer_attributes_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_load_balancer_attributes_request(
+        &self,
+        input: DescribeLoadBalancerAttributesInput,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "elasticloadbalancing", &self.region, "/");
+        request.set_operation("DescribeLoadBalancerAttributes");
         let mut params = Params::new();
 
-        params.put("Action", "DescribeLoadBalancers");
+        params.put("Action", "DescribeLoadBalancerAttributes");
         params.put("Version", "2015-12-01");
-        DescribeLoadBalancersInputSerializer::serialize(&mut params, "", &input);
+        DescribeLoadBalancerAttributesInputSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `describe_load_balancers`, also returning the metadata of the response.
+    pub async fn describe_load_balancers_with_metadata(
+        &self,
+        input: DescribeLoadBalancersInput,
+    ) -> Result<
+        (DescribeLoadBalancersOutput, ResponseMetadata),
+        RusotoError<DescribeLoadBalancersError>,
+    > {
+        let request = self.build_describe_load_balancers_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(DescribeLoadBalancersError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -9857,34 +10579,61 @@ impl Elb for ElbClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Describes the specified rules or the rules for the specified listener. You must specify either a listener or one or more rules.</p>
-    async fn describe_rules(
+    /// Presigns the request `describe_load_balancers` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_load_balancers(
         &self,
-        input: DescribeRulesInput,
-    ) -> Result<DescribeRulesOutput, RusotoError<DescribeRulesError>> {
+        presigner: &rusoto_core::Presigner,
+        input: DescribeLoadBalancersInput,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_load_balancers_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_load_balancers_request(
+        &self,
+        input: DescribeLoadBalancersInput,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "elasticloadbalancing", &self.region, "/");
+        request.set_operation("DescribeLoadBalancers");
         let mut params = Params::new();
 
-        params.put("Action", "DescribeRules");
+        params.put("Action", "DescribeLoadBalancers");
         params.put("Version", "2015-12-01");
-        DescribeRulesInputSerializer::serialize(&mut params, "", &input);
+        DescribeLoadBalancersInputSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `describe_rules`, also returning the metadata of the response.
+    pub async fn describe_rules_with_metadata(
+        &self,
+        input: DescribeRulesInput,
+    ) -> Result<(DescribeRulesOutput, ResponseMetadata), RusotoError<DescribeRulesError>> {
+        let request = self.build_describe_rules_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(DescribeRulesError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -9904,34 +10653,59 @@ impl Elb for ElbClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Describes the specified policies or all policies used for SSL negotiation.</p> <p>For more information, see <a href="https://docs.aws.amazon.com/elasticloadbalancing/latest/application/create-https-listener.html#describe-ssl-policies">Security Policies</a> in the <i>Application Load Balancers Guide</i>.</p>
-    async fn describe_ssl_policies(
+    /// Presigns the request `describe_rules` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_rules(
         &self,
-        input: DescribeSSLPoliciesInput,
-    ) -> Result<DescribeSSLPoliciesOutput, RusotoError<DescribeSSLPoliciesError>> {
+        presigner: &rusoto_core::Presigner,
+        input: DescribeRulesInput,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_rules_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_rules_request(&self, input: DescribeRulesInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "elasticloadbalancing", &self.region, "/");
+        request.set_operation("DescribeRules");
         let mut params = Params::new();
 
-        params.put("Action", "DescribeSSLPolicies");
+        params.put("Action", "DescribeRules");
         params.put("Version", "2015-12-01");
-        DescribeSSLPoliciesInputSerializer::serialize(&mut params, "", &input);
+        DescribeRulesInputSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `describe_ssl_policies`, also returning the metadata of the response.
+    pub async fn describe_ssl_policies_with_metadata(
+        &self,
+        input: DescribeSSLPoliciesInput,
+    ) -> Result<(DescribeSSLPoliciesOutput, ResponseMetadata), RusotoError<DescribeSSLPoliciesError>>
+    {
+        let request = self.build_describe_ssl_policies_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(DescribeSSLPoliciesError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -9953,34 +10727,61 @@ impl Elb for ElbClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Describes the tags for the specified resources. You can describe the tags for one or more Application Load Balancers, Network Load Balancers, and target groups.</p>
-    async fn describe_tags(
+    /// Presigns the request `describe_ssl_policies` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_ssl_policies(
         &self,
-        input: DescribeTagsInput,
-    ) -> Result<DescribeTagsOutput, RusotoError<DescribeTagsError>> {
+        presigner: &rusoto_core::Presigner,
+        input: DescribeSSLPoliciesInput,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_ssl_policies_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_ssl_policies_request(
+        &self,
+        input: DescribeSSLPoliciesInput,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "elasticloadbalancing", &self.region, "/");
+        request.set_operation("DescribeSSLPolicies");
         let mut params = Params::new();
 
-        params.put("Action", "DescribeTags");
+        params.put("Action", "DescribeSSLPolicies");
         params.put("Version", "2015-12-01");
-        DescribeTagsInputSerializer::serialize(&mut params, "", &input);
+        DescribeSSLPoliciesInputSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `describe_tags`, also returning the metadata of the response.
+    pub async fn describe_tags_with_metadata(
+        &self,
+        input: DescribeTagsInput,
+    ) -> Result<(DescribeTagsOutput, ResponseMetadata), RusotoError<DescribeTagsError>> {
+        let request = self.build_describe_tags_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(DescribeTagsError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -9999,35 +10800,61 @@ impl Elb for ElbClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Describes the attributes for the specified target group.</p> <p>For more information, see <a href="https://docs.aws.amazon.com/elasticloadbalancing/latest/application/load-balancer-target-groups.html#target-group-attributes">Target Group Attributes</a> in the <i>Application Load Balancers Guide</i> or <a href="https://docs.aws.amazon.com/elasticloadbalancing/latest/network/load-balancer-target-groups.html#target-group-attributes">Target Group Attributes</a> in the <i>Network Load Balancers Guide</i>.</p>
-    async fn describe_target_group_attributes(
+    /// Presigns the request `describe_tags` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_tags(
         &self,
-        input: DescribeTargetGroupAttributesInput,
-    ) -> Result<DescribeTargetGroupAttributesOutput, RusotoError<DescribeTargetGroupAttributesError>>
-    {
+        presigner: &rusoto_core::Presigner,
+        input: DescribeTagsInput,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_tags_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_tags_request(&self, input: DescribeTagsInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "elasticloadbalancing", &self.region, "/");
+        request.set_operation("DescribeTags");
         let mut params = Params::new();
 
-        params.put("Action", "DescribeTargetGroupAttributes");
+        params.put("Action", "DescribeTags");
         params.put("Version", "2015-12-01");
-        DescribeTargetGroupAttributesInputSerializer::serialize(&mut params, "", &input);
+        DescribeTagsInputSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `describe_target_group_attributes`, also returning the metadata of the response.
+    pub async fn describe_target_group_attributes_with_metadata(
+        &self,
+        input: DescribeTargetGroupAttributesInput,
+    ) -> Result<
+        (DescribeTargetGroupAttributesOutput, ResponseMetadata),
+        RusotoError<DescribeTargetGroupAttributesError>,
+    > {
+        let request = self.build_describe_target_group_attributes_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(DescribeTargetGroupAttributesError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -10049,34 +10876,64 @@ impl Elb for ElbClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Describes the specified target groups or all of your target groups. By default, all target groups are described. Alternatively, you can specify one of the following to filter the results: the ARN of the load balancer, the names of one or more target groups, or the ARNs of one or more target groups.</p> <p>To describe the targets for a target group, use <a>DescribeTargetHealth</a>. To describe the attributes of a target group, use <a>DescribeTargetGroupAttributes</a>.</p>
-    async fn describe_target_groups(
+    /// Presigns the request `describe_target_group_attributes` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_target_group_attributes(
         &self,
-        input: DescribeTargetGroupsInput,
-    ) -> Result<DescribeTargetGroupsOutput, RusotoError<DescribeTargetGroupsError>> {
+        presigner: &rusoto_core::Presigner,
+        input: DescribeTargetGroupAttributesInput,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_target_group_attributes_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_target_group_attributes_request(
+        &self,
+        input: DescribeTargetGroupAttributesInput,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "elasticloadbalancing", &self.region, "/");
+        request.set_operation("DescribeTargetGroupAttributes");
         let mut params = Params::new();
 
-        params.put("Action", "DescribeTargetGroups");
+        params.put("Action", "DescribeTargetGroupAttributes");
         params.put("Version", "2015-12-01");
-        DescribeTargetGroupsInputSerializer::serialize(&mut params, "", &input);
+        DescribeTargetGroupAttributesInputSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `describe_target_groups`, also returning the metadata of the response.
+    pub async fn describe_target_groups_with_metadata(
+        &self,
+        input: DescribeTargetGroupsInput,
+    ) -> Result<
+        (DescribeTargetGroupsOutput, ResponseMetadata),
+        RusotoError<DescribeTargetGroupsError>,
+    > {
+        let request = self.build_describe_target_groups_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(DescribeTargetGroupsError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -10098,34 +10955,64 @@ impl Elb for ElbClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Describes the health of the specified targets or all of your targets.</p>
-    async fn describe_target_health(
+    /// Presigns the request `describe_target_groups` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_target_groups(
         &self,
-        input: DescribeTargetHealthInput,
-    ) -> Result<DescribeTargetHealthOutput, RusotoError<DescribeTargetHealthError>> {
+        presigner: &rusoto_core::Presigner,
+        input: DescribeTargetGroupsInput,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_target_groups_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_target_groups_request(
+        &self,
+        input: DescribeTargetGroupsInput,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "elasticloadbalancing", &self.region, "/");
+        request.set_operation("DescribeTargetGroups");
         let mut params = Params::new();
 
-        params.put("Action", "DescribeTargetHealth");
+        params.put("Action", "DescribeTargetGroups");
         params.put("Version", "2015-12-01");
-        DescribeTargetHealthInputSerializer::serialize(&mut params, "", &input);
+        DescribeTargetGroupsInputSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `describe_target_health`, also returning the metadata of the response.
+    pub async fn describe_target_health_with_metadata(
+        &self,
+        input: DescribeTargetHealthInput,
+    ) -> Result<
+        (DescribeTargetHealthOutput, ResponseMetadata),
+        RusotoError<DescribeTargetHealthError>,
+    > {
+        let request = self.build_describe_target_health_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(DescribeTargetHealthError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -10147,34 +11034,61 @@ impl Elb for ElbClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Replaces the specified properties of the specified listener. Any properties that you do not specify remain unchanged.</p> <p>Changing the protocol from HTTPS to HTTP, or from TLS to TCP, removes the security policy and default certificate properties. If you change the protocol from HTTP to HTTPS, or from TCP to TLS, you must add the security policy and default certificate properties.</p> <p>To add an item to a list, remove an item from a list, or update an item in a list, you must provide the entire list. For example, to add an action, specify a list with the current actions plus the new action.</p>
-    async fn modify_listener(
+    /// Presigns the request `describe_target_health` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_target_health(
         &self,
-        input: ModifyListenerInput,
-    ) -> Result<ModifyListenerOutput, RusotoError<ModifyListenerError>> {
+        presigner: &rusoto_core::Presigner,
+        input: DescribeTargetHealthInput,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_target_health_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_target_health_request(
+        &self,
+        input: DescribeTargetHealthInput,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "elasticloadbalancing", &self.region, "/");
+        request.set_operation("DescribeTargetHealth");
         let mut params = Params::new();
 
-        params.put("Action", "ModifyListener");
+        params.put("Action", "DescribeTargetHealth");
         params.put("Version", "2015-12-01");
-        ModifyListenerInputSerializer::serialize(&mut params, "", &input);
+        DescribeTargetHealthInputSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `modify_listener`, also returning the metadata of the response.
+    pub async fn modify_listener_with_metadata(
+        &self,
+        input: ModifyListenerInput,
+    ) -> Result<(ModifyListenerOutput, ResponseMetadata), RusotoError<ModifyListenerError>> {
+        let request = self.build_modify_listener_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(ModifyListenerError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -10194,35 +11108,61 @@ impl Elb for ElbClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Modifies the specified attributes of the specified Application Load Balancer or Network Load Balancer.</p> <p>If any of the specified attributes can't be modified as requested, the call fails. Any existing attributes that you do not modify retain their current values.</p>
-    async fn modify_load_balancer_attributes(
+    /// Presigns the request `modify_listener` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_modify_listener(
         &self,
-        input: ModifyLoadBalancerAttributesInput,
-    ) -> Result<ModifyLoadBalancerAttributesOutput, RusotoError<ModifyLoadBalancerAttributesError>>
-    {
+        presigner: &rusoto_core::Presigner,
+        input: ModifyListenerInput,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_modify_listener_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_modify_listener_request(&self, input: ModifyListenerInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "elasticloadbalancing", &self.region, "/");
+        request.set_operation("ModifyListener");
         let mut params = Params::new();
 
-        params.put("Action", "ModifyLoadBalancerAttributes");
+        params.put("Action", "ModifyListener");
         params.put("Version", "2015-12-01");
-        ModifyLoadBalancerAttributesInputSerializer::serialize(&mut params, "", &input);
+        ModifyListenerInputSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `modify_load_balancer_attributes`, also returning the metadata of the response.
+    pub async fn modify_load_balancer_attributes_with_metadata(
+        &self,
+        input: ModifyLoadBalancerAttributesInput,
+    ) -> Result<
+        (ModifyLoadBalancerAttributesOutput, ResponseMetadata),
+        RusotoError<ModifyLoadBalancerAttributesError>,
+    > {
+        let request = self.build_modify_load_balancer_attributes_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(ModifyLoadBalancerAttributesError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -10244,34 +11184,61 @@ impl Elb for ElbClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Replaces the specified properties of the specified rule. Any properties that you do not specify are unchanged.</p> <p>To add an item to a list, remove an item from a list, or update an item in a list, you must provide the entire list. For example, to add an action, specify a list with the current actions plus the new action.</p> <p>To modify the actions for the default rule, use <a>ModifyListener</a>.</p>
-    async fn modify_rule(
+    /// Presigns the request `modify_load_balancer_attributes` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_modify_load_balancer_attributes(
         &self,
-        input: ModifyRuleInput,
-    ) -> Result<ModifyRuleOutput, RusotoError<ModifyRuleError>> {
+        presigner: &rusoto_core::Presigner,
+        input: ModifyLoadBalancerAttributesInput,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_modify_load_balancer_attributes_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_modify_load_balancer_attributes_request(
+        &self,
+        input: ModifyLoadBalancerAttributesInput,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "elasticloadbalancing", &self.region, "/");
+        request.set_operation("ModifyLoadBalancerAttributes");
         let mut params = Params::new();
 
-        params.put("Action", "ModifyRule");
+        params.put("Action", "ModifyLoadBalancerAttributes");
         params.put("Version", "2015-12-01");
-        ModifyRuleInputSerializer::serialize(&mut params, "", &input);
+        ModifyLoadBalancerAttributesInputSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `modify_rule`, also returning the metadata of the response.
+    pub async fn modify_rule_with_metadata(
+        &self,
+        input: ModifyRuleInput,
+    ) -> Result<(ModifyRuleOutput, ResponseMetadata), RusotoError<ModifyRuleError>> {
+        let request = self.build_modify_rule_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(ModifyRuleError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -10290,34 +11257,59 @@ impl Elb for ElbClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Modifies the health checks used when evaluating the health state of the targets in the specified target group.</p> <p>To monitor the health of the targets, use <a>DescribeTargetHealth</a>.</p>
-    async fn modify_target_group(
+    /// Presigns the request `modify_rule` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_modify_rule(
         &self,
-        input: ModifyTargetGroupInput,
-    ) -> Result<ModifyTargetGroupOutput, RusotoError<ModifyTargetGroupError>> {
+        presigner: &rusoto_core::Presigner,
+        input: ModifyRuleInput,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_modify_rule_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_modify_rule_request(&self, input: ModifyRuleInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "elasticloadbalancing", &self.region, "/");
+        request.set_operation("ModifyRule");
         let mut params = Params::new();
 
-        params.put("Action", "ModifyTargetGroup");
+        params.put("Action", "ModifyRule");
         params.put("Version", "2015-12-01");
-        ModifyTargetGroupInputSerializer::serialize(&mut params, "", &input);
+        ModifyRuleInputSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `modify_target_group`, also returning the metadata of the response.
+    pub async fn modify_target_group_with_metadata(
+        &self,
+        input: ModifyTargetGroupInput,
+    ) -> Result<(ModifyTargetGroupOutput, ResponseMetadata), RusotoError<ModifyTargetGroupError>>
+    {
+        let request = self.build_modify_target_group_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(ModifyTargetGroupError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -10339,35 +11331,61 @@ impl Elb for ElbClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Modifies the specified attributes of the specified target group.</p>
-    async fn modify_target_group_attributes(
+    /// Presigns the request `modify_target_group` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_modify_target_group(
         &self,
-        input: ModifyTargetGroupAttributesInput,
-    ) -> Result<ModifyTargetGroupAttributesOutput, RusotoError<ModifyTargetGroupAttributesError>>
-    {
+        presigner: &rusoto_core::Presigner,
+        input: ModifyTargetGroupInput,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_modify_target_group_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_modify_target_group_request(&self, input: ModifyTargetGroupInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "elasticloadbalancing", &self.region, "/");
+        request.set_operation("ModifyTargetGroup");
         let mut params = Params::new();
 
-        params.put("Action", "ModifyTargetGroupAttributes");
+        params.put("Action", "ModifyTargetGroup");
         params.put("Version", "2015-12-01");
-        ModifyTargetGroupAttributesInputSerializer::serialize(&mut params, "", &input);
+        ModifyTargetGroupInputSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `modify_target_group_attributes`, also returning the metadata of the response.
+    pub async fn modify_target_group_attributes_with_metadata(
+        &self,
+        input: ModifyTargetGroupAttributesInput,
+    ) -> Result<
+        (ModifyTargetGroupAttributesOutput, ResponseMetadata),
+        RusotoError<ModifyTargetGroupAttributesError>,
+    > {
+        let request = self.build_modify_target_group_attributes_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(ModifyTargetGroupAttributesError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -10389,34 +11407,61 @@ impl Elb for ElbClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Registers the specified targets with the specified target group.</p> <p>If the target is an EC2 instance, it must be in the <code>running</code> state when you register it.</p> <p>By default, the load balancer routes requests to registered targets using the protocol and port for the target group. Alternatively, you can override the port for a target when you register it. You can register each EC2 instance or IP address with the same target group multiple times using different ports.</p> <p>With a Network Load Balancer, you cannot register instances by instance ID if they have the following instance types: C1, CC1, CC2, CG1, CG2, CR1, CS1, G1, G2, HI1, HS1, M1, M2, M3, and T1. You can register instances of these types by IP address.</p> <p>To remove a target from a target group, use <a>DeregisterTargets</a>.</p>
-    async fn register_targets(
+    /// Presigns the request `modify_target_group_attributes` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_modify_target_group_attributes(
         &self,
-        input: RegisterTargetsInput,
-    ) -> Result<RegisterTargetsOutput, RusotoError<RegisterTargetsError>> {
+        presigner: &rusoto_core::Presigner,
+        input: ModifyTargetGroupAttributesInput,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_modify_target_group_attributes_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_modify_target_group_attributes_request(
+        &self,
+        input: ModifyTargetGroupAttributesInput,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "elasticloadbalancing", &self.region, "/");
+        request.set_operation("ModifyTargetGroupAttributes");
         let mut params = Params::new();
 
-        params.put("Action", "RegisterTargets");
+        params.put("Action", "ModifyTargetGroupAttributes");
         params.put("Version", "2015-12-01");
-        RegisterTargetsInputSerializer::serialize(&mut params, "", &input);
+        ModifyTargetGroupAttributesInputSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `register_targets`, also returning the metadata of the response.
+    pub async fn register_targets_with_metadata(
+        &self,
+        input: RegisterTargetsInput,
+    ) -> Result<(RegisterTargetsOutput, ResponseMetadata), RusotoError<RegisterTargetsError>> {
+        let request = self.build_register_targets_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(RegisterTargetsError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -10438,35 +11483,61 @@ impl Elb for ElbClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Removes the specified certificate from the certificate list for the specified HTTPS or TLS listener.</p> <p>You can't remove the default certificate for a listener. To replace the default certificate, call <a>ModifyListener</a>.</p> <p>To list the certificates for your listener, use <a>DescribeListenerCertificates</a>.</p>
-    async fn remove_listener_certificates(
+    /// Presigns the request `register_targets` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_register_targets(
         &self,
-        input: RemoveListenerCertificatesInput,
-    ) -> Result<RemoveListenerCertificatesOutput, RusotoError<RemoveListenerCertificatesError>>
-    {
+        presigner: &rusoto_core::Presigner,
+        input: RegisterTargetsInput,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_register_targets_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_register_targets_request(&self, input: RegisterTargetsInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "elasticloadbalancing", &self.region, "/");
+        request.set_operation("RegisterTargets");
         let mut params = Params::new();
 
-        params.put("Action", "RemoveListenerCertificates");
+        params.put("Action", "RegisterTargets");
         params.put("Version", "2015-12-01");
-        RemoveListenerCertificatesInputSerializer::serialize(&mut params, "", &input);
+        RegisterTargetsInputSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `remove_listener_certificates`, also returning the metadata of the response.
+    pub async fn remove_listener_certificates_with_metadata(
+        &self,
+        input: RemoveListenerCertificatesInput,
+    ) -> Result<
+        (RemoveListenerCertificatesOutput, ResponseMetadata),
+        RusotoError<RemoveListenerCertificatesError>,
+    > {
+        let request = self.build_remove_listener_certificates_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(RemoveListenerCertificatesError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -10488,34 +11559,61 @@ impl Elb for ElbClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Removes the specified tags from the specified Elastic Load Balancing resource.</p> <p>To list the current tags for your resources, use <a>DescribeTags</a>.</p>
-    async fn remove_tags(
+    /// Presigns the request `remove_listener_certificates` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_remove_listener_certificates(
         &self,
-        input: RemoveTagsInput,
-    ) -> Result<RemoveTagsOutput, RusotoError<RemoveTagsError>> {
+        presigner: &rusoto_core::Presigner,
+        input: RemoveListenerCertificatesInput,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_remove_listener_certificates_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_remove_listener_certificates_request(
+        &self,
+        input: RemoveListenerCertificatesInput,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "elasticloadbalancing", &self.region, "/");
+        request.set_operation("RemoveListenerCertificates");
         let mut params = Params::new();
 
-        params.put("Action", "RemoveTags");
+        params.put("Action", "RemoveListenerCertificates");
         params.put("Version", "2015-12-01");
-        RemoveTagsInputSerializer::serialize(&mut params, "", &input);
+        RemoveListenerCertificatesInputSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `remove_tags`, also returning the metadata of the response.
+    pub async fn remove_tags_with_metadata(
+        &self,
+        input: RemoveTagsInput,
+    ) -> Result<(RemoveTagsOutput, ResponseMetadata), RusotoError<RemoveTagsError>> {
+        let request = self.build_remove_tags_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(RemoveTagsError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -10534,34 +11632,59 @@ impl Elb for ElbClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Sets the type of IP addresses used by the subnets of the specified Application Load Balancer or Network Load Balancer.</p>
-    async fn set_ip_address_type(
+    /// Presigns the request `remove_tags` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_remove_tags(
         &self,
-        input: SetIpAddressTypeInput,
-    ) -> Result<SetIpAddressTypeOutput, RusotoError<SetIpAddressTypeError>> {
+        presigner: &rusoto_core::Presigner,
+        input: RemoveTagsInput,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_remove_tags_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_remove_tags_request(&self, input: RemoveTagsInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "elasticloadbalancing", &self.region, "/");
+        request.set_operation("RemoveTags");
         let mut params = Params::new();
 
-        params.put("Action", "SetIpAddressType");
+        params.put("Action", "RemoveTags");
         params.put("Version", "2015-12-01");
-        SetIpAddressTypeInputSerializer::serialize(&mut params, "", &input);
+        RemoveTagsInputSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `set_ip_address_type`, also returning the metadata of the response.
+    pub async fn set_ip_address_type_with_metadata(
+        &self,
+        input: SetIpAddressTypeInput,
+    ) -> Result<(SetIpAddressTypeOutput, ResponseMetadata), RusotoError<SetIpAddressTypeError>>
+    {
+        let request = self.build_set_ip_address_type_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(SetIpAddressTypeError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -10583,34 +11706,59 @@ impl Elb for ElbClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Sets the priorities of the specified rules.</p> <p>You can reorder the rules as long as there are no priority conflicts in the new order. Any existing rules that you do not specify retain their current priority.</p>
-    async fn set_rule_priorities(
+    /// Presigns the request `set_ip_address_type` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_set_ip_address_type(
         &self,
-        input: SetRulePrioritiesInput,
-    ) -> Result<SetRulePrioritiesOutput, RusotoError<SetRulePrioritiesError>> {
+        presigner: &rusoto_core::Presigner,
+        input: SetIpAddressTypeInput,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_set_ip_address_type_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_set_ip_address_type_request(&self, input: SetIpAddressTypeInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "elasticloadbalancing", &self.region, "/");
+        request.set_operation("SetIpAddressType");
         let mut params = Params::new();
 
-        params.put("Action", "SetRulePriorities");
+        params.put("Action", "SetIpAddressType");
         params.put("Version", "2015-12-01");
-        SetRulePrioritiesInputSerializer::serialize(&mut params, "", &input);
+        SetIpAddressTypeInputSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `set_rule_priorities`, also returning the metadata of the response.
+    pub async fn set_rule_priorities_with_metadata(
+        &self,
+        input: SetRulePrioritiesInput,
+    ) -> Result<(SetRulePrioritiesOutput, ResponseMetadata), RusotoError<SetRulePrioritiesError>>
+    {
+        let request = self.build_set_rule_priorities_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(SetRulePrioritiesError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -10632,34 +11780,59 @@ impl Elb for ElbClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Associates the specified security groups with the specified Application Load Balancer. The specified security groups override the previously associated security groups.</p> <p>You can't specify a security group for a Network Load Balancer.</p>
-    async fn set_security_groups(
+    /// Presigns the request `set_rule_priorities` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_set_rule_priorities(
         &self,
-        input: SetSecurityGroupsInput,
-    ) -> Result<SetSecurityGroupsOutput, RusotoError<SetSecurityGroupsError>> {
+        presigner: &rusoto_core::Presigner,
+        input: SetRulePrioritiesInput,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_set_rule_priorities_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_set_rule_priorities_request(&self, input: SetRulePrioritiesInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "elasticloadbalancing", &self.region, "/");
+        request.set_operation("SetRulePriorities");
         let mut params = Params::new();
 
-        params.put("Action", "SetSecurityGroups");
+        params.put("Action", "SetRulePriorities");
         params.put("Version", "2015-12-01");
-        SetSecurityGroupsInputSerializer::serialize(&mut params, "", &input);
+        SetRulePrioritiesInputSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `set_security_groups`, also returning the metadata of the response.
+    pub async fn set_security_groups_with_metadata(
+        &self,
+        input: SetSecurityGroupsInput,
+    ) -> Result<(SetSecurityGroupsOutput, ResponseMetadata), RusotoError<SetSecurityGroupsError>>
+    {
+        let request = self.build_set_security_groups_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(SetSecurityGroupsError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -10681,34 +11854,58 @@ impl Elb for ElbClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
     }
 
-    /// <p>Enables the Availability Zones for the specified public subnets for the specified load balancer. The specified subnets replace the previously enabled subnets.</p> <p>When you specify subnets for a Network Load Balancer, you must include all subnets that were enabled previously, with their existing configurations, plus any additional subnets.</p>
-    async fn set_subnets(
+    /// Presigns the request `set_security_groups` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_set_security_groups(
         &self,
-        input: SetSubnetsInput,
-    ) -> Result<SetSubnetsOutput, RusotoError<SetSubnetsError>> {
+        presigner: &rusoto_core::Presigner,
+        input: SetSecurityGroupsInput,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_set_security_groups_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_set_security_groups_request(&self, input: SetSecurityGroupsInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "elasticloadbalancing", &self.region, "/");
+        request.set_operation("SetSecurityGroups");
         let mut params = Params::new();
 
-        params.put("Action", "SetSubnets");
+        params.put("Action", "SetSecurityGroups");
         params.put("Version", "2015-12-01");
-        SetSubnetsInputSerializer::serialize(&mut params, "", &input);
+        SetSecurityGroupsInputSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
 
+        request
+    }
+
+    /// Calls `set_subnets`, also returning the metadata of the response.
+    pub async fn set_subnets_with_metadata(
+        &self,
+        input: SetSubnetsInput,
+    ) -> Result<(SetSubnetsOutput, ResponseMetadata), RusotoError<SetSubnetsError>> {
+        let request = self.build_set_subnets_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if !response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             return Err(SetSubnetsError::from_response(response));
         }
 
-        let xml_response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+        let xml_response = response
+            .buffer_with_limit(self.client.max_buffered_body_size())
+            .await
+            .map_err(RusotoError::from)?;
         let result;
 
         if xml_response.body.is_empty() {
@@ -10727,7 +11924,888 @@ impl Elb for ElbClient {
             end_element(&actual_tag_name, &mut stack)?;
         }
         // parse non-payload
-        Ok(result)
+        Ok((result, metadata))
+    }
+
+    /// Presigns the request `set_subnets` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_set_subnets(
+        &self,
+        presigner: &rusoto_core::Presigner,
+        input: SetSubnetsInput,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_set_subnets_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_set_subnets_request(&self, input: SetSubnetsInput) -> SignedRequest {
+        let mut request = SignedRequest::new("POST", "elasticloadbalancing", &self.region, "/");
+        request.set_operation("SetSubnets");
+        let mut params = Params::new();
+
+        params.put("Action", "SetSubnets");
+        params.put("Version", "2015-12-01");
+        SetSubnetsInputSerializer::serialize(&mut params, "", &input);
+        request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+
+        request
+    }
+
+    /// The pages of `DescribeAccountLimits` results for `input`, requesting each next page with the `NextMarker` of the last one as its `Marker`.
+    /// The page size is the `PageSize` of `input`.
+    pub fn describe_account_limits_pages(
+        &self,
+        input: DescribeAccountLimitsInput,
+    ) -> impl futures::Stream<
+        Item = Result<DescribeAccountLimitsOutput, RusotoError<DescribeAccountLimitsError>>,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: ElbClient, input| async move { client.describe_account_limits(input).await },
+            |input: &DescribeAccountLimitsInput, output: &DescribeAccountLimitsOutput| {
+                let marker = output.next_marker.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if marker.is_none() || marker == input.marker {
+                    return None;
+                }
+                Some(DescribeAccountLimitsInput {
+                    marker,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `Limits` of every page of `DescribeAccountLimits` results for `input`, as `describe_account_limits_pages` requests them.
+    pub fn describe_account_limits_stream(
+        &self,
+        input: DescribeAccountLimitsInput,
+    ) -> impl futures::Stream<Item = Result<Limit, RusotoError<DescribeAccountLimitsError>>> + Send
+    {
+        rusoto_core::pagination::items(self.describe_account_limits_pages(input), |page| {
+            page.limits.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `DescribeListenerCertificates` results for `input`, requesting each next page with the `NextMarker` of the last one as its `Marker`.
+    /// The page size is the `PageSize` of `input`.
+    pub fn describe_listener_certificates_pages(
+        &self,
+        input: DescribeListenerCertificatesInput,
+    ) -> impl futures::Stream<
+        Item = Result<
+            DescribeListenerCertificatesOutput,
+            RusotoError<DescribeListenerCertificatesError>,
+        >,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: ElbClient, input| async move { client.describe_listener_certificates(input).await },
+            |input: &DescribeListenerCertificatesInput,
+             output: &DescribeListenerCertificatesOutput| {
+                let marker = output.next_marker.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if marker.is_none() || marker == input.marker {
+                    return None;
+                }
+                Some(DescribeListenerCertificatesInput {
+                    marker,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `Certificates` of every page of `DescribeListenerCertificates` results for `input`, as `describe_listener_certificates_pages` requests them.
+    pub fn describe_listener_certificates_stream(
+        &self,
+        input: DescribeListenerCertificatesInput,
+    ) -> impl futures::Stream<
+        Item = Result<Certificate, RusotoError<DescribeListenerCertificatesError>>,
+    > + Send {
+        rusoto_core::pagination::items(self.describe_listener_certificates_pages(input), |page| {
+            page.certificates.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `DescribeListeners` results for `input`, requesting each next page with the `NextMarker` of the last one as its `Marker`.
+    /// The page size is the `PageSize` of `input`.
+    pub fn describe_listeners_pages(
+        &self,
+        input: DescribeListenersInput,
+    ) -> impl futures::Stream<
+        Item = Result<DescribeListenersOutput, RusotoError<DescribeListenersError>>,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: ElbClient, input| async move { client.describe_listeners(input).await },
+            |input: &DescribeListenersInput, output: &DescribeListenersOutput| {
+                let marker = output.next_marker.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if marker.is_none() || marker == input.marker {
+                    return None;
+                }
+                Some(DescribeListenersInput {
+                    marker,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `Listeners` of every page of `DescribeListeners` results for `input`, as `describe_listeners_pages` requests them.
+    pub fn describe_listeners_stream(
+        &self,
+        input: DescribeListenersInput,
+    ) -> impl futures::Stream<Item = Result<Listener, RusotoError<DescribeListenersError>>> + Send
+    {
+        rusoto_core::pagination::items(self.describe_listeners_pages(input), |page| {
+            page.listeners.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `DescribeLoadBalancers` results for `input`, requesting each next page with the `NextMarker` of the last one as its `Marker`.
+    /// The page size is the `PageSize` of `input`.
+    pub fn describe_load_balancers_pages(
+        &self,
+        input: DescribeLoadBalancersInput,
+    ) -> impl futures::Stream<
+        Item = Result<DescribeLoadBalancersOutput, RusotoError<DescribeLoadBalancersError>>,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: ElbClient, input| async move { client.describe_load_balancers(input).await },
+            |input: &DescribeLoadBalancersInput, output: &DescribeLoadBalancersOutput| {
+                let marker = output.next_marker.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if marker.is_none() || marker == input.marker {
+                    return None;
+                }
+                Some(DescribeLoadBalancersInput {
+                    marker,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `LoadBalancers` of every page of `DescribeLoadBalancers` results for `input`, as `describe_load_balancers_pages` requests them.
+    pub fn describe_load_balancers_stream(
+        &self,
+        input: DescribeLoadBalancersInput,
+    ) -> impl futures::Stream<Item = Result<LoadBalancer, RusotoError<DescribeLoadBalancersError>>> + Send
+    {
+        rusoto_core::pagination::items(self.describe_load_balancers_pages(input), |page| {
+            page.load_balancers.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `DescribeRules` results for `input`, requesting each next page with the `NextMarker` of the last one as its `Marker`.
+    /// The page size is the `PageSize` of `input`.
+    pub fn describe_rules_pages(
+        &self,
+        input: DescribeRulesInput,
+    ) -> impl futures::Stream<Item = Result<DescribeRulesOutput, RusotoError<DescribeRulesError>>> + Send
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: ElbClient, input| async move { client.describe_rules(input).await },
+            |input: &DescribeRulesInput, output: &DescribeRulesOutput| {
+                let marker = output.next_marker.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if marker.is_none() || marker == input.marker {
+                    return None;
+                }
+                Some(DescribeRulesInput {
+                    marker,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `Rules` of every page of `DescribeRules` results for `input`, as `describe_rules_pages` requests them.
+    pub fn describe_rules_stream(
+        &self,
+        input: DescribeRulesInput,
+    ) -> impl futures::Stream<Item = Result<Rule, RusotoError<DescribeRulesError>>> + Send {
+        rusoto_core::pagination::items(self.describe_rules_pages(input), |page| {
+            page.rules.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `DescribeSSLPolicies` results for `input`, requesting each next page with the `NextMarker` of the last one as its `Marker`.
+    /// The page size is the `PageSize` of `input`.
+    pub fn describe_ssl_policies_pages(
+        &self,
+        input: DescribeSSLPoliciesInput,
+    ) -> impl futures::Stream<
+        Item = Result<DescribeSSLPoliciesOutput, RusotoError<DescribeSSLPoliciesError>>,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: ElbClient, input| async move { client.describe_ssl_policies(input).await },
+            |input: &DescribeSSLPoliciesInput, output: &DescribeSSLPoliciesOutput| {
+                let marker = output.next_marker.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if marker.is_none() || marker == input.marker {
+                    return None;
+                }
+                Some(DescribeSSLPoliciesInput {
+                    marker,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `SslPolicies` of every page of `DescribeSSLPolicies` results for `input`, as `describe_ssl_policies_pages` requests them.
+    pub fn describe_ssl_policies_stream(
+        &self,
+        input: DescribeSSLPoliciesInput,
+    ) -> impl futures::Stream<Item = Result<SslPolicy, RusotoError<DescribeSSLPoliciesError>>> + Send
+    {
+        rusoto_core::pagination::items(self.describe_ssl_policies_pages(input), |page| {
+            page.ssl_policies.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `DescribeTargetGroups` results for `input`, requesting each next page with the `NextMarker` of the last one as its `Marker`.
+    /// The page size is the `PageSize` of `input`.
+    pub fn describe_target_groups_pages(
+        &self,
+        input: DescribeTargetGroupsInput,
+    ) -> impl futures::Stream<
+        Item = Result<DescribeTargetGroupsOutput, RusotoError<DescribeTargetGroupsError>>,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: ElbClient, input| async move { client.describe_target_groups(input).await },
+            |input: &DescribeTargetGroupsInput, output: &DescribeTargetGroupsOutput| {
+                let marker = output.next_marker.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if marker.is_none() || marker == input.marker {
+                    return None;
+                }
+                Some(DescribeTargetGroupsInput {
+                    marker,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `TargetGroups` of every page of `DescribeTargetGroups` results for `input`, as `describe_target_groups_pages` requests them.
+    pub fn describe_target_groups_stream(
+        &self,
+        input: DescribeTargetGroupsInput,
+    ) -> impl futures::Stream<Item = Result<TargetGroup, RusotoError<DescribeTargetGroupsError>>> + Send
+    {
+        rusoto_core::pagination::items(self.describe_target_groups_pages(input), |page| {
+            page.target_groups.unwrap_or_default()
+        })
+    }
+
+    /// Waits for `LoadBalancerAvailable`, polling `DescribeLoadBalancers` with `input` every 15 seconds, up to 40 times.
+    pub async fn wait_until_load_balancer_available(
+        &self,
+        input: DescribeLoadBalancersInput,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<DescribeLoadBalancersError>> {
+        let waiter = rusoto_core::waiter::Waiter::new(std::time::Duration::from_secs(15), 40);
+        self.wait_until_load_balancer_available_with(input, &waiter)
+            .await
+    }
+
+    /// Waits for `LoadBalancerAvailable` like `wait_until_load_balancer_available`, with the delay, attempts and runtime of `waiter`.
+    pub async fn wait_until_load_balancer_available_with(
+        &self,
+        input: DescribeLoadBalancersInput,
+        waiter: &rusoto_core::waiter::Waiter,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<DescribeLoadBalancersError>> {
+        rusoto_core::waiter::wait(
+            waiter,
+            || self.describe_load_balancers(input.clone()),
+            |result| {
+                if match result {
+                    Ok(output) => {
+                        let mut values = std::iter::once(output)
+                            .filter_map(|value| value.load_balancers.as_ref())
+                            .flat_map(|value| value.iter())
+                            .filter_map(|value| value.state.as_ref())
+                            .filter_map(|value| value.code.as_ref())
+                            .peekable();
+                        values.peek().is_some() && values.all(|value| value == "active")
+                    }
+                    Err(_) => false,
+                } {
+                    return Some(rusoto_core::waiter::WaiterState::Success);
+                }
+                if match result {
+                    Ok(output) => std::iter::once(output)
+                        .filter_map(|value| value.load_balancers.as_ref())
+                        .flat_map(|value| value.iter())
+                        .filter_map(|value| value.state.as_ref())
+                        .filter_map(|value| value.code.as_ref())
+                        .any(|value| value == "provisioning"),
+                    Err(_) => false,
+                } {
+                    return Some(rusoto_core::waiter::WaiterState::Retry);
+                }
+                if result
+                    .as_ref()
+                    .err()
+                    .and_then(|error| error.error_code())
+                    .as_deref()
+                    == Some("LoadBalancerNotFound")
+                {
+                    return Some(rusoto_core::waiter::WaiterState::Retry);
+                }
+                None
+            },
+        )
+        .await
+    }
+
+    /// Waits for `LoadBalancerExists`, polling `DescribeLoadBalancers` with `input` every 15 seconds, up to 40 times.
+    pub async fn wait_until_load_balancer_exists(
+        &self,
+        input: DescribeLoadBalancersInput,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<DescribeLoadBalancersError>> {
+        let waiter = rusoto_core::waiter::Waiter::new(std::time::Duration::from_secs(15), 40);
+        self.wait_until_load_balancer_exists_with(input, &waiter)
+            .await
+    }
+
+    /// Waits for `LoadBalancerExists` like `wait_until_load_balancer_exists`, with the delay, attempts and runtime of `waiter`.
+    pub async fn wait_until_load_balancer_exists_with(
+        &self,
+        input: DescribeLoadBalancersInput,
+        waiter: &rusoto_core::waiter::Waiter,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<DescribeLoadBalancersError>> {
+        rusoto_core::waiter::wait(
+            waiter,
+            || self.describe_load_balancers(input.clone()),
+            |result| {
+                if rusoto_core::waiter::status(result) == Some(200) {
+                    return Some(rusoto_core::waiter::WaiterState::Success);
+                }
+                if result
+                    .as_ref()
+                    .err()
+                    .and_then(|error| error.error_code())
+                    .as_deref()
+                    == Some("LoadBalancerNotFound")
+                {
+                    return Some(rusoto_core::waiter::WaiterState::Retry);
+                }
+                None
+            },
+        )
+        .await
+    }
+
+    /// Waits for `LoadBalancersDeleted`, polling `DescribeLoadBalancers` with `input` every 15 seconds, up to 40 times.
+    pub async fn wait_until_load_balancers_deleted(
+        &self,
+        input: DescribeLoadBalancersInput,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<DescribeLoadBalancersError>> {
+        let waiter = rusoto_core::waiter::Waiter::new(std::time::Duration::from_secs(15), 40);
+        self.wait_until_load_balancers_deleted_with(input, &waiter)
+            .await
+    }
+
+    /// Waits for `LoadBalancersDeleted` like `wait_until_load_balancers_deleted`, with the delay, attempts and runtime of `waiter`.
+    pub async fn wait_until_load_balancers_deleted_with(
+        &self,
+        input: DescribeLoadBalancersInput,
+        waiter: &rusoto_core::waiter::Waiter,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<DescribeLoadBalancersError>> {
+        rusoto_core::waiter::wait(
+            waiter,
+            || self.describe_load_balancers(input.clone()),
+            |result| {
+                if match result {
+                    Ok(output) => {
+                        let mut values = std::iter::once(output)
+                            .filter_map(|value| value.load_balancers.as_ref())
+                            .flat_map(|value| value.iter())
+                            .filter_map(|value| value.state.as_ref())
+                            .filter_map(|value| value.code.as_ref())
+                            .peekable();
+                        values.peek().is_some() && values.all(|value| value == "active")
+                    }
+                    Err(_) => false,
+                } {
+                    return Some(rusoto_core::waiter::WaiterState::Retry);
+                }
+                if result
+                    .as_ref()
+                    .err()
+                    .and_then(|error| error.error_code())
+                    .as_deref()
+                    == Some("LoadBalancerNotFound")
+                {
+                    return Some(rusoto_core::waiter::WaiterState::Success);
+                }
+                None
+            },
+        )
+        .await
+    }
+
+    /// Waits for `TargetDeregistered`, polling `DescribeTargetHealth` with `input` every 15 seconds, up to 40 times.
+    pub async fn wait_until_target_deregistered(
+        &self,
+        input: DescribeTargetHealthInput,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<DescribeTargetHealthError>> {
+        let waiter = rusoto_core::waiter::Waiter::new(std::time::Duration::from_secs(15), 40);
+        self.wait_until_target_deregistered_with(input, &waiter)
+            .await
+    }
+
+    /// Waits for `TargetDeregistered` like `wait_until_target_deregistered`, with the delay, attempts and runtime of `waiter`.
+    pub async fn wait_until_target_deregistered_with(
+        &self,
+        input: DescribeTargetHealthInput,
+        waiter: &rusoto_core::waiter::Waiter,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<DescribeTargetHealthError>> {
+        rusoto_core::waiter::wait(
+            waiter,
+            || self.describe_target_health(input.clone()),
+            |result| {
+                if result
+                    .as_ref()
+                    .err()
+                    .and_then(|error| error.error_code())
+                    .as_deref()
+                    == Some("InvalidTarget")
+                {
+                    return Some(rusoto_core::waiter::WaiterState::Success);
+                }
+                if match result {
+                    Ok(output) => {
+                        let mut values = std::iter::once(output)
+                            .filter_map(|value| value.target_health_descriptions.as_ref())
+                            .flat_map(|value| value.iter())
+                            .filter_map(|value| value.target_health.as_ref())
+                            .filter_map(|value| value.state.as_ref())
+                            .peekable();
+                        values.peek().is_some() && values.all(|value| value == "unused")
+                    }
+                    Err(_) => false,
+                } {
+                    return Some(rusoto_core::waiter::WaiterState::Success);
+                }
+                None
+            },
+        )
+        .await
+    }
+
+    /// Waits for `TargetInService`, polling `DescribeTargetHealth` with `input` every 15 seconds, up to 40 times.
+    pub async fn wait_until_target_in_service(
+        &self,
+        input: DescribeTargetHealthInput,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<DescribeTargetHealthError>> {
+        let waiter = rusoto_core::waiter::Waiter::new(std::time::Duration::from_secs(15), 40);
+        self.wait_until_target_in_service_with(input, &waiter).await
+    }
+
+    /// Waits for `TargetInService` like `wait_until_target_in_service`, with the delay, attempts and runtime of `waiter`.
+    pub async fn wait_until_target_in_service_with(
+        &self,
+        input: DescribeTargetHealthInput,
+        waiter: &rusoto_core::waiter::Waiter,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<DescribeTargetHealthError>> {
+        rusoto_core::waiter::wait(
+            waiter,
+            || self.describe_target_health(input.clone()),
+            |result| {
+                if match result {
+                    Ok(output) => {
+                        let mut values = std::iter::once(output)
+                            .filter_map(|value| value.target_health_descriptions.as_ref())
+                            .flat_map(|value| value.iter())
+                            .filter_map(|value| value.target_health.as_ref())
+                            .filter_map(|value| value.state.as_ref())
+                            .peekable();
+                        values.peek().is_some() && values.all(|value| value == "healthy")
+                    }
+                    Err(_) => false,
+                } {
+                    return Some(rusoto_core::waiter::WaiterState::Success);
+                }
+                if result
+                    .as_ref()
+                    .err()
+                    .and_then(|error| error.error_code())
+                    .as_deref()
+                    == Some("InvalidInstance")
+                {
+                    return Some(rusoto_core::waiter::WaiterState::Retry);
+                }
+                None
+            },
+        )
+        .await
+    }
+}
+
+#[async_trait]
+impl Elb for ElbClient {
+    /// <p>Adds the specified SSL server certificate to the certificate list for the specified HTTPS or TLS listener.</p> <p>If the certificate in already in the certificate list, the call is successful but the certificate is not added again.</p> <p>To get the certificate list for a listener, use <a>DescribeListenerCertificates</a>. To remove certificates from the certificate list for a listener, use <a>RemoveListenerCertificates</a>. To replace the default certificate for a listener, use <a>ModifyListener</a>.</p> <p>For more information, see <a href="https://docs.aws.amazon.com/elasticloadbalancing/latest/application/create-https-listener.html#https-listener-certificates">SSL Certificates</a> in the <i>Application Load Balancers Guide</i>.</p>
+    async fn add_listener_certificates(
+        &self,
+        input: AddListenerCertificatesInput,
+    ) -> Result<AddListenerCertificatesOutput, RusotoError<AddListenerCertificatesError>> {
+        self.add_listener_certificates_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Adds the specified tags to the specified Elastic Load Balancing resource. You can tag your Application Load Balancers, Network Load Balancers, and your target groups.</p> <p>Each tag consists of a key and an optional value. If a resource already has a tag with the same key, <code>AddTags</code> updates its value.</p> <p>To list the current tags for your resources, use <a>DescribeTags</a>. To remove tags from your resources, use <a>RemoveTags</a>.</p>
+    async fn add_tags(
+        &self,
+        input: AddTagsInput,
+    ) -> Result<AddTagsOutput, RusotoError<AddTagsError>> {
+        self.add_tags_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Creates a listener for the specified Application Load Balancer or Network Load Balancer.</p> <p>To update a listener, use <a>ModifyListener</a>. When you are finished with a listener, you can delete it using <a>DeleteListener</a>. If you are finished with both the listener and the load balancer, you can delete them both using <a>DeleteLoadBalancer</a>.</p> <p>This operation is idempotent, which means that it completes at most one time. If you attempt to create multiple listeners with the same settings, each call succeeds.</p> <p>For more information, see <a href="https://docs.aws.amazon.com/elasticloadbalancing/latest/application/load-balancer-listeners.html">Listeners for Your Application Load Balancers</a> in the <i>Application Load Balancers Guide</i> and <a href="https://docs.aws.amazon.com/elasticloadbalancing/latest/network/load-balancer-listeners.html">Listeners for Your Network Load Balancers</a> in the <i>Network Load Balancers Guide</i>.</p>
+    async fn create_listener(
+        &self,
+        input: CreateListenerInput,
+    ) -> Result<CreateListenerOutput, RusotoError<CreateListenerError>> {
+        self.create_listener_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Creates an Application Load Balancer or a Network Load Balancer.</p> <p>When you create a load balancer, you can specify security groups, public subnets, IP address type, and tags. Otherwise, you could do so later using <a>SetSecurityGroups</a>, <a>SetSubnets</a>, <a>SetIpAddressType</a>, and <a>AddTags</a>.</p> <p>To create listeners for your load balancer, use <a>CreateListener</a>. To describe your current load balancers, see <a>DescribeLoadBalancers</a>. When you are finished with a load balancer, you can delete it using <a>DeleteLoadBalancer</a>.</p> <p>For limit information, see <a href="https://docs.aws.amazon.com/elasticloadbalancing/latest/application/load-balancer-limits.html">Limits for Your Application Load Balancer</a> in the <i>Application Load Balancers Guide</i> and <a href="https://docs.aws.amazon.com/elasticloadbalancing/latest/network/load-balancer-limits.html">Limits for Your Network Load Balancer</a> in the <i>Network Load Balancers Guide</i>.</p> <p>This operation is idempotent, which means that it completes at most one time. If you attempt to create multiple load balancers with the same settings, each call succeeds.</p> <p>For more information, see <a href="https://docs.aws.amazon.com/elasticloadbalancing/latest/application/application-load-balancers.html">Application Load Balancers</a> in the <i>Application Load Balancers Guide</i> and <a href="https://docs.aws.amazon.com/elasticloadbalancing/latest/network/network-load-balancers.html">Network Load Balancers</a> in the <i>Network Load Balancers Guide</i>.</p>
+    async fn create_load_balancer(
+        &self,
+        input: CreateLoadBalancerInput,
+    ) -> Result<CreateLoadBalancerOutput, RusotoError<CreateLoadBalancerError>> {
+        self.create_load_balancer_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Creates a rule for the specified listener. The listener must be associated with an Application Load Balancer.</p> <p>Rules are evaluated in priority order, from the lowest value to the highest value. When the conditions for a rule are met, its actions are performed. If the conditions for no rules are met, the actions for the default rule are performed. For more information, see <a href="https://docs.aws.amazon.com/elasticloadbalancing/latest/application/load-balancer-listeners.html#listener-rules">Listener Rules</a> in the <i>Application Load Balancers Guide</i>.</p> <p>To view your current rules, use <a>DescribeRules</a>. To update a rule, use <a>ModifyRule</a>. To set the priorities of your rules, use <a>SetRulePriorities</a>. To delete a rule, use <a>DeleteRule</a>.</p>
+    async fn create_rule(
+        &self,
+        input: CreateRuleInput,
+    ) -> Result<CreateRuleOutput, RusotoError<CreateRuleError>> {
+        self.create_rule_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Creates a target group.</p> <p>To register targets with the target group, use <a>RegisterTargets</a>. To update the health check settings for the target group, use <a>ModifyTargetGroup</a>. To monitor the health of targets in the target group, use <a>DescribeTargetHealth</a>.</p> <p>To route traffic to the targets in a target group, specify the target group in an action using <a>CreateListener</a> or <a>CreateRule</a>.</p> <p>To delete a target group, use <a>DeleteTargetGroup</a>.</p> <p>This operation is idempotent, which means that it completes at most one time. If you attempt to create multiple target groups with the same settings, each call succeeds.</p> <p>For more information, see <a href="https://docs.aws.amazon.com/elasticloadbalancing/latest/application/load-balancer-target-groups.html">Target Groups for Your Application Load Balancers</a> in the <i>Application Load Balancers Guide</i> or <a href="https://docs.aws.amazon.com/elasticloadbalancing/latest/network/load-balancer-target-groups.html">Target Groups for Your Network Load Balancers</a> in the <i>Network Load Balancers Guide</i>.</p>
+    async fn create_target_group(
+        &self,
+        input: CreateTargetGroupInput,
+    ) -> Result<CreateTargetGroupOutput, RusotoError<CreateTargetGroupError>> {
+        self.create_target_group_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes the specified listener.</p> <p>Alternatively, your listener is deleted when you delete the load balancer to which it is attached, using <a>DeleteLoadBalancer</a>.</p>
+    async fn delete_listener(
+        &self,
+        input: DeleteListenerInput,
+    ) -> Result<DeleteListenerOutput, RusotoError<DeleteListenerError>> {
+        self.delete_listener_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes the specified Application Load Balancer or Network Load Balancer and its attached listeners.</p> <p>You can't delete a load balancer if deletion protection is enabled. If the load balancer does not exist or has already been deleted, the call succeeds.</p> <p>Deleting a load balancer does not affect its registered targets. For example, your EC2 instances continue to run and are still registered to their target groups. If you no longer need these EC2 instances, you can stop or terminate them.</p>
+    async fn delete_load_balancer(
+        &self,
+        input: DeleteLoadBalancerInput,
+    ) -> Result<DeleteLoadBalancerOutput, RusotoError<DeleteLoadBalancerError>> {
+        self.delete_load_balancer_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes the specified rule.</p>
+    async fn delete_rule(
+        &self,
+        input: DeleteRuleInput,
+    ) -> Result<DeleteRuleOutput, RusotoError<DeleteRuleError>> {
+        self.delete_rule_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes the specified target group.</p> <p>You can delete a target group if it is not referenced by any actions. Deleting a target group also deletes any associated health checks.</p>
+    async fn delete_target_group(
+        &self,
+        input: DeleteTargetGroupInput,
+    ) -> Result<DeleteTargetGroupOutput, RusotoError<DeleteTargetGroupError>> {
+        self.delete_target_group_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deregisters the specified targets from the specified target group. After the targets are deregistered, they no longer receive traffic from the load balancer.</p>
+    async fn deregister_targets(
+        &self,
+        input: DeregisterTargetsInput,
+    ) -> Result<DeregisterTargetsOutput, RusotoError<DeregisterTargetsError>> {
+        self.deregister_targets_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Describes the current Elastic Load Balancing resource limits for your AWS account.</p> <p>For more information, see <a href="https://docs.aws.amazon.com/elasticloadbalancing/latest/application/load-balancer-limits.html">Limits for Your Application Load Balancers</a> in the <i>Application Load Balancer Guide</i> or <a href="https://docs.aws.amazon.com/elasticloadbalancing/latest/network/load-balancer-limits.html">Limits for Your Network Load Balancers</a> in the <i>Network Load Balancers Guide</i>.</p>
+    async fn describe_account_limits(
+        &self,
+        input: DescribeAccountLimitsInput,
+    ) -> Result<DescribeAccountLimitsOutput, RusotoError<DescribeAccountLimitsError>> {
+        self.describe_account_limits_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Describes the default certificate and the certificate list for the specified HTTPS or TLS listener.</p> <p>If the default certificate is also in the certificate list, it appears twice in the results (once with <code>IsDefault</code> set to true and once with <code>IsDefault</code> set to false).</p> <p>For more information, see <a href="https://docs.aws.amazon.com/elasticloadbalancing/latest/application/create-https-listener.html#https-listener-certificates">SSL Certificates</a> in the <i>Application Load Balancers Guide</i>.</p>
+    async fn describe_listener_certificates(
+        &self,
+        input: DescribeListenerCertificatesInput,
+    ) -> Result<DescribeListenerCertificatesOutput, RusotoError<DescribeListenerCertificatesError>>
+    {
+        self.describe_listener_certificates_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Describes the specified listeners or the listeners for the specified Application Load Balancer or Network Load Balancer. You must specify either a load balancer or one or more listeners.</p> <p>For an HTTPS or TLS listener, the output includes the default certificate for the listener. To describe the certificate list for the listener, use <a>DescribeListenerCertificates</a>.</p>
+    async fn describe_listeners(
+        &self,
+        input: DescribeListenersInput,
+    ) -> Result<DescribeListenersOutput, RusotoError<DescribeListenersError>> {
+        self.describe_listeners_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Describes the attributes for the specified Application Load Balancer or Network Load Balancer.</p> <p>For more information, see <a href="https://docs.aws.amazon.com/elasticloadbalancing/latest/application/application-load-balancers.html#load-balancer-attributes">Load Balancer Attributes</a> in the <i>Application Load Balancers Guide</i> or <a href="https://docs.aws.amazon.com/elasticloadbalancing/latest/network/network-load-balancers.html#load-balancer-attributes">Load Balancer Attributes</a> in the <i>Network Load Balancers Guide</i>.</p>
+    async fn describe_load_balancer_attributes(
+        &self,
+        input: DescribeLoadBalancerAttributesInput,
+    ) -> Result<
+        DescribeLoadBalancerAttributesOutput,
+        RusotoError<DescribeLoadBalancerAttributesError>,
+    > {
+        self.describe_load_balancer_attributes_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Describes the specified load balancers or all of your load balancers.</p> <p>To describe the listeners for a load balancer, use <a>DescribeListeners</a>. To describe the attributes for a load balancer, use <a>DescribeLoadBalancerAttributes</a>.</p>
+    async fn describe_load_balancers(
+        &self,
+        input: DescribeLoadBalancersInput,
+    ) -> Result<DescribeLoadBalancersOutput, RusotoError<DescribeLoadBalancersError>> {
+        self.describe_load_balancers_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Describes the specified rules or the rules for the specified listener. You must specify either a listener or one or more rules.</p>
+    async fn describe_rules(
+        &self,
+        input: DescribeRulesInput,
+    ) -> Result<DescribeRulesOutput, RusotoError<DescribeRulesError>> {
+        self.describe_rules_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Describes the specified policies or all policies used for SSL negotiation.</p> <p>For more information, see <a href="https://docs.aws.amazon.com/elasticloadbalancing/latest/application/create-https-listener.html#describe-ssl-policies">Security Policies</a> in the <i>Application Load Balancers Guide</i>.</p>
+    async fn describe_ssl_policies(
+        &self,
+        input: DescribeSSLPoliciesInput,
+    ) -> Result<DescribeSSLPoliciesOutput, RusotoError<DescribeSSLPoliciesError>> {
+        self.describe_ssl_policies_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Describes the tags for the specified resources. You can describe the tags for one or more Application Load Balancers, Network Load Balancers, and target groups.</p>
+    async fn describe_tags(
+        &self,
+        input: DescribeTagsInput,
+    ) -> Result<DescribeTagsOutput, RusotoError<DescribeTagsError>> {
+        self.describe_tags_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Describes the attributes for the specified target group.</p> <p>For more information, see <a href="https://docs.aws.amazon.com/elasticloadbalancing/latest/application/load-balancer-target-groups.html#target-group-attributes">Target Group Attributes</a> in the <i>Application Load Balancers Guide</i> or <a href="https://docs.aws.amazon.com/elasticloadbalancing/latest/network/load-balancer-target-groups.html#target-group-attributes">Target Group Attributes</a> in the <i>Network Load Balancers Guide</i>.</p>
+    async fn describe_target_group_attributes(
+        &self,
+        input: DescribeTargetGroupAttributesInput,
+    ) -> Result<DescribeTargetGroupAttributesOutput, RusotoError<DescribeTargetGroupAttributesError>>
+    {
+        self.describe_target_group_attributes_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Describes the specified target groups or all of your target groups. By default, all target groups are described. Alternatively, you can specify one of the following to filter the results: the ARN of the load balancer, the names of one or more target groups, or the ARNs of one or more target groups.</p> <p>To describe the targets for a target group, use <a>DescribeTargetHealth</a>. To describe the attributes of a target group, use <a>DescribeTargetGroupAttributes</a>.</p>
+    async fn describe_target_groups(
+        &self,
+        input: DescribeTargetGroupsInput,
+    ) -> Result<DescribeTargetGroupsOutput, RusotoError<DescribeTargetGroupsError>> {
+        self.describe_target_groups_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Describes the health of the specified targets or all of your targets.</p>
+    async fn describe_target_health(
+        &self,
+        input: DescribeTargetHealthInput,
+    ) -> Result<DescribeTargetHealthOutput, RusotoError<DescribeTargetHealthError>> {
+        self.describe_target_health_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Replaces the specified properties of the specified listener. Any properties that you do not specify remain unchanged.</p> <p>Changing the protocol from HTTPS to HTTP, or from TLS to TCP, removes the security policy and default certificate properties. If you change the protocol from HTTP to HTTPS, or from TCP to TLS, you must add the security policy and default certificate properties.</p> <p>To add an item to a list, remove an item from a list, or update an item in a list, you must provide the entire list. For example, to add an action, specify a list with the current actions plus the new action.</p>
+    async fn modify_listener(
+        &self,
+        input: ModifyListenerInput,
+    ) -> Result<ModifyListenerOutput, RusotoError<ModifyListenerError>> {
+        self.modify_listener_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Modifies the specified attributes of the specified Application Load Balancer or Network Load Balancer.</p> <p>If any of the specified attributes can't be modified as requested, the call fails. Any existing attributes that you do not modify retain their current values.</p>
+    async fn modify_load_balancer_attributes(
+        &self,
+        input: ModifyLoadBalancerAttributesInput,
+    ) -> Result<ModifyLoadBalancerAttributesOutput, RusotoError<ModifyLoadBalancerAttributesError>>
+    {
+        self.modify_load_balancer_attributes_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Replaces the specified properties of the specified rule. Any properties that you do not specify are unchanged.</p> <p>To add an item to a list, remove an item from a list, or update an item in a list, you must provide the entire list. For example, to add an action, specify a list with the current actions plus the new action.</p> <p>To modify the actions for the default rule, use <a>ModifyListener</a>.</p>
+    async fn modify_rule(
+        &self,
+        input: ModifyRuleInput,
+    ) -> Result<ModifyRuleOutput, RusotoError<ModifyRuleError>> {
+        self.modify_rule_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Modifies the health checks used when evaluating the health state of the targets in the specified target group.</p> <p>To monitor the health of the targets, use <a>DescribeTargetHealth</a>.</p>
+    async fn modify_target_group(
+        &self,
+        input: ModifyTargetGroupInput,
+    ) -> Result<ModifyTargetGroupOutput, RusotoError<ModifyTargetGroupError>> {
+        self.modify_target_group_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Modifies the specified attributes of the specified target group.</p>
+    async fn modify_target_group_attributes(
+        &self,
+        input: ModifyTargetGroupAttributesInput,
+    ) -> Result<ModifyTargetGroupAttributesOutput, RusotoError<ModifyTargetGroupAttributesError>>
+    {
+        self.modify_target_group_attributes_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Registers the specified targets with the specified target group.</p> <p>If the target is an EC2 instance, it must be in the <code>running</code> state when you register it.</p> <p>By default, the load balancer routes requests to registered targets using the protocol and port for the target group. Alternatively, you can override the port for a target when you register it. You can register each EC2 instance or IP address with the same target group multiple times using different ports.</p> <p>With a Network Load Balancer, you cannot register instances by instance ID if they have the following instance types: C1, CC1, CC2, CG1, CG2, CR1, CS1, G1, G2, HI1, HS1, M1, M2, M3, and T1. You can register instances of these types by IP address.</p> <p>To remove a target from a target group, use <a>DeregisterTargets</a>.</p>
+    async fn register_targets(
+        &self,
+        input: RegisterTargetsInput,
+    ) -> Result<RegisterTargetsOutput, RusotoError<RegisterTargetsError>> {
+        self.register_targets_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Removes the specified certificate from the certificate list for the specified HTTPS or TLS listener.</p> <p>You can't remove the default certificate for a listener. To replace the default certificate, call <a>ModifyListener</a>.</p> <p>To list the certificates for your listener, use <a>DescribeListenerCertificates</a>.</p>
+    async fn remove_listener_certificates(
+        &self,
+        input: RemoveListenerCertificatesInput,
+    ) -> Result<RemoveListenerCertificatesOutput, RusotoError<RemoveListenerCertificatesError>>
+    {
+        self.remove_listener_certificates_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Removes the specified tags from the specified Elastic Load Balancing resource.</p> <p>To list the current tags for your resources, use <a>DescribeTags</a>.</p>
+    async fn remove_tags(
+        &self,
+        input: RemoveTagsInput,
+    ) -> Result<RemoveTagsOutput, RusotoError<RemoveTagsError>> {
+        self.remove_tags_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Sets the type of IP addresses used by the subnets of the specified Application Load Balancer or Network Load Balancer.</p>
+    async fn set_ip_address_type(
+        &self,
+        input: SetIpAddressTypeInput,
+    ) -> Result<SetIpAddressTypeOutput, RusotoError<SetIpAddressTypeError>> {
+        self.set_ip_address_type_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Sets the priorities of the specified rules.</p> <p>You can reorder the rules as long as there are no priority conflicts in the new order. Any existing rules that you do not specify retain their current priority.</p>
+    async fn set_rule_priorities(
+        &self,
+        input: SetRulePrioritiesInput,
+    ) -> Result<SetRulePrioritiesOutput, RusotoError<SetRulePrioritiesError>> {
+        self.set_rule_priorities_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Associates the specified security groups with the specified Application Load Balancer. The specified security groups override the previously associated security groups.</p> <p>You can't specify a security group for a Network Load Balancer.</p>
+    async fn set_security_groups(
+        &self,
+        input: SetSecurityGroupsInput,
+    ) -> Result<SetSecurityGroupsOutput, RusotoError<SetSecurityGroupsError>> {
+        self.set_security_groups_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Enables the Availability Zones for the specified public subnets for the specified load balancer. The specified subnets replace the previously enabled subnets.</p> <p>When you specify subnets for a Network Load Balancer, you must include all subnets that were enabled previously, with their existing configurations, plus any additional subnets.</p>
+    async fn set_subnets(
+        &self,
+        input: SetSubnetsInput,
+    ) -> Result<SetSubnetsOutput, RusotoError<SetSubnetsError>> {
+        self.set_subnets_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
     }
 }
 
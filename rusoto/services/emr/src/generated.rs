@@ -16,8 +16,8 @@ use std::fmt;
 use async_trait::async_trait;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
-use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest, ResponseMetadata};
+use rusoto_core::{Client, ErrorMetadata, RusotoError};
 
 use rusoto_core::proto;
 use rusoto_core::signature::SignedRequest;
@@ -2548,11 +2548,14 @@ pub struct VolumeSpecification {
 
 /// Errors returned by AddInstanceFleet
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum AddInstanceFleetError {
     /// <p>This exception occurs when there is an internal failure in the EMR service.</p>
     InternalServer(String),
     /// <p>This exception occurs when there is something wrong with user input.</p>
     InvalidRequest(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl AddInstanceFleetError {
@@ -2560,13 +2563,19 @@ impl AddInstanceFleetError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(AddInstanceFleetError::InternalServer(err.msg))
+                    return RusotoError::service(
+                        AddInstanceFleetError::InternalServer(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(AddInstanceFleetError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        AddInstanceFleetError::InvalidRequest(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(AddInstanceFleetError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2578,15 +2587,19 @@ impl fmt::Display for AddInstanceFleetError {
         match *self {
             AddInstanceFleetError::InternalServer(ref cause) => write!(f, "{}", cause),
             AddInstanceFleetError::InvalidRequest(ref cause) => write!(f, "{}", cause),
+            AddInstanceFleetError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for AddInstanceFleetError {}
 /// Errors returned by AddInstanceGroups
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum AddInstanceGroupsError {
     /// <p>Indicates that an error occurred while processing the request and that the request was not completed.</p>
     InternalServerError(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl AddInstanceGroupsError {
@@ -2594,12 +2607,13 @@ impl AddInstanceGroupsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(AddInstanceGroupsError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        AddInstanceGroupsError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(AddInstanceGroupsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2610,15 +2624,19 @@ impl fmt::Display for AddInstanceGroupsError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             AddInstanceGroupsError::InternalServerError(ref cause) => write!(f, "{}", cause),
+            AddInstanceGroupsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for AddInstanceGroupsError {}
 /// Errors returned by AddJobFlowSteps
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum AddJobFlowStepsError {
     /// <p>Indicates that an error occurred while processing the request and that the request was not completed.</p>
     InternalServerError(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl AddJobFlowStepsError {
@@ -2626,10 +2644,13 @@ impl AddJobFlowStepsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(AddJobFlowStepsError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        AddJobFlowStepsError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(AddJobFlowStepsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2640,17 +2661,21 @@ impl fmt::Display for AddJobFlowStepsError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             AddJobFlowStepsError::InternalServerError(ref cause) => write!(f, "{}", cause),
+            AddJobFlowStepsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for AddJobFlowStepsError {}
 /// Errors returned by AddTags
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum AddTagsError {
     /// <p>This exception occurs when there is an internal failure in the EMR service.</p>
     InternalServer(String),
     /// <p>This exception occurs when there is something wrong with user input.</p>
     InvalidRequest(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl AddTagsError {
@@ -2658,13 +2683,13 @@ impl AddTagsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(AddTagsError::InternalServer(err.msg))
+                    return RusotoError::service(AddTagsError::InternalServer(err.msg), &res)
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(AddTagsError::InvalidRequest(err.msg))
+                    return RusotoError::service(AddTagsError::InvalidRequest(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(AddTagsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2676,17 +2701,21 @@ impl fmt::Display for AddTagsError {
         match *self {
             AddTagsError::InternalServer(ref cause) => write!(f, "{}", cause),
             AddTagsError::InvalidRequest(ref cause) => write!(f, "{}", cause),
+            AddTagsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for AddTagsError {}
 /// Errors returned by CancelSteps
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CancelStepsError {
     /// <p>Indicates that an error occurred while processing the request and that the request was not completed.</p>
     InternalServerError(String),
     /// <p>This exception occurs when there is something wrong with user input.</p>
     InvalidRequest(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CancelStepsError {
@@ -2694,13 +2723,16 @@ impl CancelStepsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(CancelStepsError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        CancelStepsError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(CancelStepsError::InvalidRequest(err.msg))
+                    return RusotoError::service(CancelStepsError::InvalidRequest(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CancelStepsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2712,17 +2744,21 @@ impl fmt::Display for CancelStepsError {
         match *self {
             CancelStepsError::InternalServerError(ref cause) => write!(f, "{}", cause),
             CancelStepsError::InvalidRequest(ref cause) => write!(f, "{}", cause),
+            CancelStepsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CancelStepsError {}
 /// Errors returned by CreateSecurityConfiguration
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateSecurityConfigurationError {
     /// <p>This exception occurs when there is an internal failure in the EMR service.</p>
     InternalServer(String),
     /// <p>This exception occurs when there is something wrong with user input.</p>
     InvalidRequest(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateSecurityConfigurationError {
@@ -2732,17 +2768,24 @@ impl CreateSecurityConfigurationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(CreateSecurityConfigurationError::InternalServer(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateSecurityConfigurationError::InternalServer(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(CreateSecurityConfigurationError::InvalidRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateSecurityConfigurationError::InvalidRequest(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        CreateSecurityConfigurationError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -2754,17 +2797,21 @@ impl fmt::Display for CreateSecurityConfigurationError {
         match *self {
             CreateSecurityConfigurationError::InternalServer(ref cause) => write!(f, "{}", cause),
             CreateSecurityConfigurationError::InvalidRequest(ref cause) => write!(f, "{}", cause),
+            CreateSecurityConfigurationError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateSecurityConfigurationError {}
 /// Errors returned by DeleteSecurityConfiguration
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteSecurityConfigurationError {
     /// <p>This exception occurs when there is an internal failure in the EMR service.</p>
     InternalServer(String),
     /// <p>This exception occurs when there is something wrong with user input.</p>
     InvalidRequest(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteSecurityConfigurationError {
@@ -2774,17 +2821,24 @@ impl DeleteSecurityConfigurationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(DeleteSecurityConfigurationError::InternalServer(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteSecurityConfigurationError::InternalServer(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DeleteSecurityConfigurationError::InvalidRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteSecurityConfigurationError::InvalidRequest(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        DeleteSecurityConfigurationError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -2796,17 +2850,21 @@ impl fmt::Display for DeleteSecurityConfigurationError {
         match *self {
             DeleteSecurityConfigurationError::InternalServer(ref cause) => write!(f, "{}", cause),
             DeleteSecurityConfigurationError::InvalidRequest(ref cause) => write!(f, "{}", cause),
+            DeleteSecurityConfigurationError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteSecurityConfigurationError {}
 /// Errors returned by DescribeCluster
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeClusterError {
     /// <p>This exception occurs when there is an internal failure in the EMR service.</p>
     InternalServer(String),
     /// <p>This exception occurs when there is something wrong with user input.</p>
     InvalidRequest(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeClusterError {
@@ -2814,13 +2872,19 @@ impl DescribeClusterError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(DescribeClusterError::InternalServer(err.msg))
+                    return RusotoError::service(
+                        DescribeClusterError::InternalServer(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DescribeClusterError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        DescribeClusterError::InvalidRequest(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DescribeClusterError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2832,15 +2896,19 @@ impl fmt::Display for DescribeClusterError {
         match *self {
             DescribeClusterError::InternalServer(ref cause) => write!(f, "{}", cause),
             DescribeClusterError::InvalidRequest(ref cause) => write!(f, "{}", cause),
+            DescribeClusterError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeClusterError {}
 /// Errors returned by DescribeJobFlows
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeJobFlowsError {
     /// <p>Indicates that an error occurred while processing the request and that the request was not completed.</p>
     InternalServerError(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeJobFlowsError {
@@ -2848,12 +2916,13 @@ impl DescribeJobFlowsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(DescribeJobFlowsError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeJobFlowsError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DescribeJobFlowsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2864,17 +2933,21 @@ impl fmt::Display for DescribeJobFlowsError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             DescribeJobFlowsError::InternalServerError(ref cause) => write!(f, "{}", cause),
+            DescribeJobFlowsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeJobFlowsError {}
 /// Errors returned by DescribeSecurityConfiguration
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeSecurityConfigurationError {
     /// <p>This exception occurs when there is an internal failure in the EMR service.</p>
     InternalServer(String),
     /// <p>This exception occurs when there is something wrong with user input.</p>
     InvalidRequest(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeSecurityConfigurationError {
@@ -2884,17 +2957,24 @@ impl DescribeSecurityConfigurationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DescribeSecurityConfigurationError::InternalServer(err.msg),
+                        &res,
                     )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DescribeSecurityConfigurationError::InvalidRequest(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        DescribeSecurityConfigurationError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -2906,17 +2986,23 @@ impl fmt::Display for DescribeSecurityConfigurationError {
         match *self {
             DescribeSecurityConfigurationError::InternalServer(ref cause) => write!(f, "{}", cause),
             DescribeSecurityConfigurationError::InvalidRequest(ref cause) => write!(f, "{}", cause),
+            DescribeSecurityConfigurationError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for DescribeSecurityConfigurationError {}
 /// Errors returned by DescribeStep
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeStepError {
     /// <p>This exception occurs when there is an internal failure in the EMR service.</p>
     InternalServer(String),
     /// <p>This exception occurs when there is something wrong with user input.</p>
     InvalidRequest(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeStepError {
@@ -2924,13 +3010,13 @@ impl DescribeStepError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(DescribeStepError::InternalServer(err.msg))
+                    return RusotoError::service(DescribeStepError::InternalServer(err.msg), &res)
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DescribeStepError::InvalidRequest(err.msg))
+                    return RusotoError::service(DescribeStepError::InvalidRequest(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DescribeStepError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2942,17 +3028,21 @@ impl fmt::Display for DescribeStepError {
         match *self {
             DescribeStepError::InternalServer(ref cause) => write!(f, "{}", cause),
             DescribeStepError::InvalidRequest(ref cause) => write!(f, "{}", cause),
+            DescribeStepError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeStepError {}
 /// Errors returned by GetBlockPublicAccessConfiguration
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetBlockPublicAccessConfigurationError {
     /// <p>This exception occurs when there is an internal failure in the EMR service.</p>
     InternalServer(String),
     /// <p>This exception occurs when there is something wrong with user input.</p>
     InvalidRequest(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetBlockPublicAccessConfigurationError {
@@ -2962,17 +3052,24 @@ impl GetBlockPublicAccessConfigurationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         GetBlockPublicAccessConfigurationError::InternalServer(err.msg),
+                        &res,
                     )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         GetBlockPublicAccessConfigurationError::InvalidRequest(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        GetBlockPublicAccessConfigurationError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -2988,17 +3085,23 @@ impl fmt::Display for GetBlockPublicAccessConfigurationError {
             GetBlockPublicAccessConfigurationError::InvalidRequest(ref cause) => {
                 write!(f, "{}", cause)
             }
+            GetBlockPublicAccessConfigurationError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for GetBlockPublicAccessConfigurationError {}
 /// Errors returned by ListBootstrapActions
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListBootstrapActionsError {
     /// <p>This exception occurs when there is an internal failure in the EMR service.</p>
     InternalServer(String),
     /// <p>This exception occurs when there is something wrong with user input.</p>
     InvalidRequest(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListBootstrapActionsError {
@@ -3006,13 +3109,19 @@ impl ListBootstrapActionsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(ListBootstrapActionsError::InternalServer(err.msg))
+                    return RusotoError::service(
+                        ListBootstrapActionsError::InternalServer(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(ListBootstrapActionsError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        ListBootstrapActionsError::InvalidRequest(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListBootstrapActionsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3024,17 +3133,21 @@ impl fmt::Display for ListBootstrapActionsError {
         match *self {
             ListBootstrapActionsError::InternalServer(ref cause) => write!(f, "{}", cause),
             ListBootstrapActionsError::InvalidRequest(ref cause) => write!(f, "{}", cause),
+            ListBootstrapActionsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListBootstrapActionsError {}
 /// Errors returned by ListClusters
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListClustersError {
     /// <p>This exception occurs when there is an internal failure in the EMR service.</p>
     InternalServer(String),
     /// <p>This exception occurs when there is something wrong with user input.</p>
     InvalidRequest(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListClustersError {
@@ -3042,13 +3155,13 @@ impl ListClustersError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(ListClustersError::InternalServer(err.msg))
+                    return RusotoError::service(ListClustersError::InternalServer(err.msg), &res)
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(ListClustersError::InvalidRequest(err.msg))
+                    return RusotoError::service(ListClustersError::InvalidRequest(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListClustersError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3060,17 +3173,21 @@ impl fmt::Display for ListClustersError {
         match *self {
             ListClustersError::InternalServer(ref cause) => write!(f, "{}", cause),
             ListClustersError::InvalidRequest(ref cause) => write!(f, "{}", cause),
+            ListClustersError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListClustersError {}
 /// Errors returned by ListInstanceFleets
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListInstanceFleetsError {
     /// <p>This exception occurs when there is an internal failure in the EMR service.</p>
     InternalServer(String),
     /// <p>This exception occurs when there is something wrong with user input.</p>
     InvalidRequest(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListInstanceFleetsError {
@@ -3078,13 +3195,19 @@ impl ListInstanceFleetsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(ListInstanceFleetsError::InternalServer(err.msg))
+                    return RusotoError::service(
+                        ListInstanceFleetsError::InternalServer(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(ListInstanceFleetsError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        ListInstanceFleetsError::InvalidRequest(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListInstanceFleetsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3096,17 +3219,21 @@ impl fmt::Display for ListInstanceFleetsError {
         match *self {
             ListInstanceFleetsError::InternalServer(ref cause) => write!(f, "{}", cause),
             ListInstanceFleetsError::InvalidRequest(ref cause) => write!(f, "{}", cause),
+            ListInstanceFleetsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListInstanceFleetsError {}
 /// Errors returned by ListInstanceGroups
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListInstanceGroupsError {
     /// <p>This exception occurs when there is an internal failure in the EMR service.</p>
     InternalServer(String),
     /// <p>This exception occurs when there is something wrong with user input.</p>
     InvalidRequest(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListInstanceGroupsError {
@@ -3114,13 +3241,19 @@ impl ListInstanceGroupsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(ListInstanceGroupsError::InternalServer(err.msg))
+                    return RusotoError::service(
+                        ListInstanceGroupsError::InternalServer(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(ListInstanceGroupsError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        ListInstanceGroupsError::InvalidRequest(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListInstanceGroupsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3132,17 +3265,21 @@ impl fmt::Display for ListInstanceGroupsError {
         match *self {
             ListInstanceGroupsError::InternalServer(ref cause) => write!(f, "{}", cause),
             ListInstanceGroupsError::InvalidRequest(ref cause) => write!(f, "{}", cause),
+            ListInstanceGroupsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListInstanceGroupsError {}
 /// Errors returned by ListInstances
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListInstancesError {
     /// <p>This exception occurs when there is an internal failure in the EMR service.</p>
     InternalServer(String),
     /// <p>This exception occurs when there is something wrong with user input.</p>
     InvalidRequest(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListInstancesError {
@@ -3150,13 +3287,13 @@ impl ListInstancesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(ListInstancesError::InternalServer(err.msg))
+                    return RusotoError::service(ListInstancesError::InternalServer(err.msg), &res)
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(ListInstancesError::InvalidRequest(err.msg))
+                    return RusotoError::service(ListInstancesError::InvalidRequest(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListInstancesError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3168,17 +3305,21 @@ impl fmt::Display for ListInstancesError {
         match *self {
             ListInstancesError::InternalServer(ref cause) => write!(f, "{}", cause),
             ListInstancesError::InvalidRequest(ref cause) => write!(f, "{}", cause),
+            ListInstancesError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListInstancesError {}
 /// Errors returned by ListSecurityConfigurations
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListSecurityConfigurationsError {
     /// <p>This exception occurs when there is an internal failure in the EMR service.</p>
     InternalServer(String),
     /// <p>This exception occurs when there is something wrong with user input.</p>
     InvalidRequest(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListSecurityConfigurationsError {
@@ -3188,17 +3329,21 @@ impl ListSecurityConfigurationsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(ListSecurityConfigurationsError::InternalServer(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListSecurityConfigurationsError::InternalServer(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(ListSecurityConfigurationsError::InvalidRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListSecurityConfigurationsError::InvalidRequest(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(ListSecurityConfigurationsError::Unhandled, &res)
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -3210,17 +3355,21 @@ impl fmt::Display for ListSecurityConfigurationsError {
         match *self {
             ListSecurityConfigurationsError::InternalServer(ref cause) => write!(f, "{}", cause),
             ListSecurityConfigurationsError::InvalidRequest(ref cause) => write!(f, "{}", cause),
+            ListSecurityConfigurationsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListSecurityConfigurationsError {}
 /// Errors returned by ListSteps
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListStepsError {
     /// <p>This exception occurs when there is an internal failure in the EMR service.</p>
     InternalServer(String),
     /// <p>This exception occurs when there is something wrong with user input.</p>
     InvalidRequest(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListStepsError {
@@ -3228,13 +3377,13 @@ impl ListStepsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(ListStepsError::InternalServer(err.msg))
+                    return RusotoError::service(ListStepsError::InternalServer(err.msg), &res)
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(ListStepsError::InvalidRequest(err.msg))
+                    return RusotoError::service(ListStepsError::InvalidRequest(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListStepsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3246,17 +3395,21 @@ impl fmt::Display for ListStepsError {
         match *self {
             ListStepsError::InternalServer(ref cause) => write!(f, "{}", cause),
             ListStepsError::InvalidRequest(ref cause) => write!(f, "{}", cause),
+            ListStepsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListStepsError {}
 /// Errors returned by ModifyCluster
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ModifyClusterError {
     /// <p>Indicates that an error occurred while processing the request and that the request was not completed.</p>
     InternalServerError(String),
     /// <p>This exception occurs when there is something wrong with user input.</p>
     InvalidRequest(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ModifyClusterError {
@@ -3264,13 +3417,16 @@ impl ModifyClusterError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(ModifyClusterError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        ModifyClusterError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(ModifyClusterError::InvalidRequest(err.msg))
+                    return RusotoError::service(ModifyClusterError::InvalidRequest(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ModifyClusterError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3282,17 +3438,21 @@ impl fmt::Display for ModifyClusterError {
         match *self {
             ModifyClusterError::InternalServerError(ref cause) => write!(f, "{}", cause),
             ModifyClusterError::InvalidRequest(ref cause) => write!(f, "{}", cause),
+            ModifyClusterError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ModifyClusterError {}
 /// Errors returned by ModifyInstanceFleet
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ModifyInstanceFleetError {
     /// <p>This exception occurs when there is an internal failure in the EMR service.</p>
     InternalServer(String),
     /// <p>This exception occurs when there is something wrong with user input.</p>
     InvalidRequest(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ModifyInstanceFleetError {
@@ -3300,13 +3460,19 @@ impl ModifyInstanceFleetError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(ModifyInstanceFleetError::InternalServer(err.msg))
+                    return RusotoError::service(
+                        ModifyInstanceFleetError::InternalServer(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(ModifyInstanceFleetError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        ModifyInstanceFleetError::InvalidRequest(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ModifyInstanceFleetError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3318,15 +3484,19 @@ impl fmt::Display for ModifyInstanceFleetError {
         match *self {
             ModifyInstanceFleetError::InternalServer(ref cause) => write!(f, "{}", cause),
             ModifyInstanceFleetError::InvalidRequest(ref cause) => write!(f, "{}", cause),
+            ModifyInstanceFleetError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ModifyInstanceFleetError {}
 /// Errors returned by ModifyInstanceGroups
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ModifyInstanceGroupsError {
     /// <p>Indicates that an error occurred while processing the request and that the request was not completed.</p>
     InternalServerError(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ModifyInstanceGroupsError {
@@ -3334,12 +3504,13 @@ impl ModifyInstanceGroupsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(ModifyInstanceGroupsError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ModifyInstanceGroupsError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ModifyInstanceGroupsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3350,20 +3521,25 @@ impl fmt::Display for ModifyInstanceGroupsError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             ModifyInstanceGroupsError::InternalServerError(ref cause) => write!(f, "{}", cause),
+            ModifyInstanceGroupsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ModifyInstanceGroupsError {}
 /// Errors returned by PutAutoScalingPolicy
 #[derive(Debug, PartialEq)]
-pub enum PutAutoScalingPolicyError {}
+#[non_exhaustive]
+pub enum PutAutoScalingPolicyError {
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
+}
 
 impl PutAutoScalingPolicyError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<PutAutoScalingPolicyError> {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(PutAutoScalingPolicyError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3372,17 +3548,22 @@ impl PutAutoScalingPolicyError {
 impl fmt::Display for PutAutoScalingPolicyError {
     #[allow(unused_variables)]
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        match *self {}
+        match *self {
+            PutAutoScalingPolicyError::Unhandled(ref metadata) => write!(f, "{}", metadata),
+        }
     }
 }
 impl Error for PutAutoScalingPolicyError {}
 /// Errors returned by PutBlockPublicAccessConfiguration
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum PutBlockPublicAccessConfigurationError {
     /// <p>This exception occurs when there is an internal failure in the EMR service.</p>
     InternalServer(String),
     /// <p>This exception occurs when there is something wrong with user input.</p>
     InvalidRequest(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl PutBlockPublicAccessConfigurationError {
@@ -3392,17 +3573,24 @@ impl PutBlockPublicAccessConfigurationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         PutBlockPublicAccessConfigurationError::InternalServer(err.msg),
+                        &res,
                     )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         PutBlockPublicAccessConfigurationError::InvalidRequest(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        PutBlockPublicAccessConfigurationError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -3418,20 +3606,27 @@ impl fmt::Display for PutBlockPublicAccessConfigurationError {
             PutBlockPublicAccessConfigurationError::InvalidRequest(ref cause) => {
                 write!(f, "{}", cause)
             }
+            PutBlockPublicAccessConfigurationError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for PutBlockPublicAccessConfigurationError {}
 /// Errors returned by RemoveAutoScalingPolicy
 #[derive(Debug, PartialEq)]
-pub enum RemoveAutoScalingPolicyError {}
+#[non_exhaustive]
+pub enum RemoveAutoScalingPolicyError {
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
+}
 
 impl RemoveAutoScalingPolicyError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<RemoveAutoScalingPolicyError> {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(RemoveAutoScalingPolicyError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3440,17 +3635,22 @@ impl RemoveAutoScalingPolicyError {
 impl fmt::Display for RemoveAutoScalingPolicyError {
     #[allow(unused_variables)]
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        match *self {}
+        match *self {
+            RemoveAutoScalingPolicyError::Unhandled(ref metadata) => write!(f, "{}", metadata),
+        }
     }
 }
 impl Error for RemoveAutoScalingPolicyError {}
 /// Errors returned by RemoveTags
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum RemoveTagsError {
     /// <p>This exception occurs when there is an internal failure in the EMR service.</p>
     InternalServer(String),
     /// <p>This exception occurs when there is something wrong with user input.</p>
     InvalidRequest(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl RemoveTagsError {
@@ -3458,13 +3658,13 @@ impl RemoveTagsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(RemoveTagsError::InternalServer(err.msg))
+                    return RusotoError::service(RemoveTagsError::InternalServer(err.msg), &res)
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(RemoveTagsError::InvalidRequest(err.msg))
+                    return RusotoError::service(RemoveTagsError::InvalidRequest(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(RemoveTagsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3476,15 +3676,19 @@ impl fmt::Display for RemoveTagsError {
         match *self {
             RemoveTagsError::InternalServer(ref cause) => write!(f, "{}", cause),
             RemoveTagsError::InvalidRequest(ref cause) => write!(f, "{}", cause),
+            RemoveTagsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for RemoveTagsError {}
 /// Errors returned by RunJobFlow
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum RunJobFlowError {
     /// <p>Indicates that an error occurred while processing the request and that the request was not completed.</p>
     InternalServerError(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl RunJobFlowError {
@@ -3492,10 +3696,13 @@ impl RunJobFlowError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(RunJobFlowError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        RunJobFlowError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(RunJobFlowError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3506,15 +3713,19 @@ impl fmt::Display for RunJobFlowError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             RunJobFlowError::InternalServerError(ref cause) => write!(f, "{}", cause),
+            RunJobFlowError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for RunJobFlowError {}
 /// Errors returned by SetTerminationProtection
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum SetTerminationProtectionError {
     /// <p>Indicates that an error occurred while processing the request and that the request was not completed.</p>
     InternalServerError(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl SetTerminationProtectionError {
@@ -3522,12 +3733,13 @@ impl SetTerminationProtectionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         SetTerminationProtectionError::InternalServerError(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(SetTerminationProtectionError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3538,15 +3750,19 @@ impl fmt::Display for SetTerminationProtectionError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             SetTerminationProtectionError::InternalServerError(ref cause) => write!(f, "{}", cause),
+            SetTerminationProtectionError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for SetTerminationProtectionError {}
 /// Errors returned by SetVisibleToAllUsers
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum SetVisibleToAllUsersError {
     /// <p>Indicates that an error occurred while processing the request and that the request was not completed.</p>
     InternalServerError(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl SetVisibleToAllUsersError {
@@ -3554,12 +3770,13 @@ impl SetVisibleToAllUsersError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(SetVisibleToAllUsersError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        SetVisibleToAllUsersError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(SetVisibleToAllUsersError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3570,15 +3787,19 @@ impl fmt::Display for SetVisibleToAllUsersError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             SetVisibleToAllUsersError::InternalServerError(ref cause) => write!(f, "{}", cause),
+            SetVisibleToAllUsersError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for SetVisibleToAllUsersError {}
 /// Errors returned by TerminateJobFlows
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum TerminateJobFlowsError {
     /// <p>Indicates that an error occurred while processing the request and that the request was not completed.</p>
     InternalServerError(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl TerminateJobFlowsError {
@@ -3586,12 +3807,13 @@ impl TerminateJobFlowsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(TerminateJobFlowsError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        TerminateJobFlowsError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(TerminateJobFlowsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3602,6 +3824,7 @@ impl fmt::Display for TerminateJobFlowsError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             TerminateJobFlowsError::InternalServerError(ref cause) => write!(f, "{}", cause),
+            TerminateJobFlowsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
@@ -3805,6 +4028,7 @@ impl EmrClient {
     /// Creates a client backed by the default tokio event loop.
     ///
     /// The client will use the default credentials provider and tls client.
+    #[cfg(not(target_arch = "wasm32"))]
     pub fn new(region: region::Region) -> EmrClient {
         EmrClient {
             client: Client::shared(),
@@ -3830,152 +4054,335 @@ impl EmrClient {
     pub fn new_with_client(client: Client, region: region::Region) -> EmrClient {
         EmrClient { client, region }
     }
+
+    /// Returns a builder of a client, setting any of its region, credentials provider,
+    /// dispatcher, retry policy, endpoint and timeouts.
+    pub fn builder() -> rusoto_core::ClientBuilder<EmrClient> {
+        rusoto_core::ClientBuilder::new(EmrClient::new_with_client)
+    }
+
+    /// Returns a clone of this client with the overrides of `config` applied to its
+    /// operations, sharing its connections.
+    pub fn customize(&self, config: &rusoto_core::OperationConfig) -> EmrClient {
+        EmrClient {
+            client: self.client.customize(config),
+            region: config.resolve_region(&self.region),
+        }
+    }
 }
 
-#[async_trait]
-impl Emr for EmrClient {
-    /// <p><p>Adds an instance fleet to a running cluster.</p> <note> <p>The instance fleet configuration is available only in Amazon EMR versions 4.8.0 and later, excluding 5.0.x.</p> </note></p>
-    async fn add_instance_fleet(
+impl EmrClient {
+    /// Calls `add_instance_fleet`, also returning the metadata of the response.
+    pub async fn add_instance_fleet_with_metadata(
         &self,
         input: AddInstanceFleetInput,
-    ) -> Result<AddInstanceFleetOutput, RusotoError<AddInstanceFleetError>> {
+    ) -> Result<(AddInstanceFleetOutput, ResponseMetadata), RusotoError<AddInstanceFleetError>>
+    {
+        let request = self.build_add_instance_fleet_request(input);
+
+        let mut response = self
+            .client
+            .sign_and_dispatch(request)
+            .await
+            .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
+        if response.status.is_success() {
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<AddInstanceFleetOutput, _>()?;
+            Ok((result, metadata))
+        } else {
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(AddInstanceFleetError::from_response(response))
+        }
+    }
+
+    /// Presigns the request `add_instance_fleet` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_add_instance_fleet(
+        &self,
+        presigner: &rusoto_core::Presigner,
+        input: AddInstanceFleetInput,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_add_instance_fleet_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_add_instance_fleet_request(&self, input: AddInstanceFleetInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "elasticmapreduce", &self.region, "/");
+        request.set_operation("AddInstanceFleet");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "ElasticMapReduce.AddInstanceFleet");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `add_instance_groups`, also returning the metadata of the response.
+    pub async fn add_instance_groups_with_metadata(
+        &self,
+        input: AddInstanceGroupsInput,
+    ) -> Result<(AddInstanceGroupsOutput, ResponseMetadata), RusotoError<AddInstanceGroupsError>>
+    {
+        let request = self.build_add_instance_groups_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<AddInstanceFleetOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<AddInstanceGroupsOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(AddInstanceFleetError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(AddInstanceGroupsError::from_response(response))
         }
     }
 
-    /// <p>Adds one or more instance groups to a running cluster.</p>
-    async fn add_instance_groups(
+    /// Presigns the request `add_instance_groups` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_add_instance_groups(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: AddInstanceGroupsInput,
-    ) -> Result<AddInstanceGroupsOutput, RusotoError<AddInstanceGroupsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_add_instance_groups_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_add_instance_groups_request(&self, input: AddInstanceGroupsInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "elasticmapreduce", &self.region, "/");
+        request.set_operation("AddInstanceGroups");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "ElasticMapReduce.AddInstanceGroups");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `add_job_flow_steps`, also returning the metadata of the response.
+    pub async fn add_job_flow_steps_with_metadata(
+        &self,
+        input: AddJobFlowStepsInput,
+    ) -> Result<(AddJobFlowStepsOutput, ResponseMetadata), RusotoError<AddJobFlowStepsError>> {
+        let request = self.build_add_job_flow_steps_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<AddInstanceGroupsOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<AddJobFlowStepsOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(AddInstanceGroupsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(AddJobFlowStepsError::from_response(response))
         }
     }
 
-    /// <p>AddJobFlowSteps adds new steps to a running cluster. A maximum of 256 steps are allowed in each job flow.</p> <p>If your cluster is long-running (such as a Hive data warehouse) or complex, you may require more than 256 steps to process your data. You can bypass the 256-step limitation in various ways, including using SSH to connect to the master node and submitting queries directly to the software running on the master node, such as Hive and Hadoop. For more information on how to do this, see <a href="https://docs.aws.amazon.com/emr/latest/ManagementGuide/AddMoreThan256Steps.html">Add More than 256 Steps to a Cluster</a> in the <i>Amazon EMR Management Guide</i>.</p> <p>A step specifies the location of a JAR file stored either on the master node of the cluster or in Amazon S3. Each step is performed by the main function of the main class of the JAR file. The main class can be specified either in the manifest of the JAR or by using the MainFunction parameter of the step.</p> <p>Amazon EMR executes each step in the order listed. For a step to be considered complete, the main function must exit with a zero exit code and all Hadoop jobs started while the step was running must have completed and run successfully.</p> <p>You can only add steps to a cluster that is in one of the following states: STARTING, BOOTSTRAPPING, RUNNING, or WAITING.</p>
-    async fn add_job_flow_steps(
+    /// Presigns the request `add_job_flow_steps` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_add_job_flow_steps(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: AddJobFlowStepsInput,
-    ) -> Result<AddJobFlowStepsOutput, RusotoError<AddJobFlowStepsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_add_job_flow_steps_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_add_job_flow_steps_request(&self, input: AddJobFlowStepsInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "elasticmapreduce", &self.region, "/");
+        request.set_operation("AddJobFlowSteps");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "ElasticMapReduce.AddJobFlowSteps");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `add_tags`, also returning the metadata of the response.
+    pub async fn add_tags_with_metadata(
+        &self,
+        input: AddTagsInput,
+    ) -> Result<(AddTagsOutput, ResponseMetadata), RusotoError<AddTagsError>> {
+        let request = self.build_add_tags_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<AddJobFlowStepsOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result =
+                proto::json::ResponsePayload::new(&response).deserialize::<AddTagsOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(AddJobFlowStepsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(AddTagsError::from_response(response))
         }
     }
 
-    /// <p>Adds tags to an Amazon EMR resource. Tags make it easier to associate clusters in various ways, such as grouping clusters to track your Amazon EMR resource allocation costs. For more information, see <a href="https://docs.aws.amazon.com/emr/latest/ManagementGuide/emr-plan-tags.html">Tag Clusters</a>. </p>
-    async fn add_tags(
+    /// Presigns the request `add_tags` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_add_tags(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: AddTagsInput,
-    ) -> Result<AddTagsOutput, RusotoError<AddTagsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_add_tags_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_add_tags_request(&self, input: AddTagsInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "elasticmapreduce", &self.region, "/");
+        request.set_operation("AddTags");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "ElasticMapReduce.AddTags");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `cancel_steps`, also returning the metadata of the response.
+    pub async fn cancel_steps_with_metadata(
+        &self,
+        input: CancelStepsInput,
+    ) -> Result<(CancelStepsOutput, ResponseMetadata), RusotoError<CancelStepsError>> {
+        let request = self.build_cancel_steps_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<AddTagsOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<CancelStepsOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(AddTagsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(CancelStepsError::from_response(response))
         }
     }
 
-    /// <p>Cancels a pending step or steps in a running cluster. Available only in Amazon EMR versions 4.8.0 and later, excluding version 5.0.0. A maximum of 256 steps are allowed in each CancelSteps request. CancelSteps is idempotent but asynchronous; it does not guarantee a step will be canceled, even if the request is successfully submitted. You can only cancel steps that are in a <code>PENDING</code> state.</p>
-    async fn cancel_steps(
+    /// Presigns the request `cancel_steps` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_cancel_steps(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: CancelStepsInput,
-    ) -> Result<CancelStepsOutput, RusotoError<CancelStepsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_cancel_steps_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_cancel_steps_request(&self, input: CancelStepsInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "elasticmapreduce", &self.region, "/");
+        request.set_operation("CancelSteps");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "ElasticMapReduce.CancelSteps");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `create_security_configuration`, also returning the metadata of the response.
+    pub async fn create_security_configuration_with_metadata(
+        &self,
+        input: CreateSecurityConfigurationInput,
+    ) -> Result<
+        (CreateSecurityConfigurationOutput, ResponseMetadata),
+        RusotoError<CreateSecurityConfigurationError>,
+    > {
+        let request = self.build_create_security_configuration_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<CancelStepsOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<CreateSecurityConfigurationOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(CancelStepsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(CreateSecurityConfigurationError::from_response(response))
         }
     }
 
-    /// <p>Creates a security configuration, which is stored in the service and can be specified when a cluster is created.</p>
-    async fn create_security_configuration(
+    /// Presigns the request `create_security_configuration` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_security_configuration(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: CreateSecurityConfigurationInput,
-    ) -> Result<CreateSecurityConfigurationOutput, RusotoError<CreateSecurityConfigurationError>>
-    {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_security_configuration_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_security_configuration_request(
+        &self,
+        input: CreateSecurityConfigurationInput,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "elasticmapreduce", &self.region, "/");
+        request.set_operation("CreateSecurityConfiguration");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -3985,29 +4392,58 @@ impl Emr for EmrClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `delete_security_configuration`, also returning the metadata of the response.
+    pub async fn delete_security_configuration_with_metadata(
+        &self,
+        input: DeleteSecurityConfigurationInput,
+    ) -> Result<
+        (DeleteSecurityConfigurationOutput, ResponseMetadata),
+        RusotoError<DeleteSecurityConfigurationError>,
+    > {
+        let request = self.build_delete_security_configuration_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<CreateSecurityConfigurationOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DeleteSecurityConfigurationOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(CreateSecurityConfigurationError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DeleteSecurityConfigurationError::from_response(response))
         }
     }
 
-    /// <p>Deletes a security configuration.</p>
-    async fn delete_security_configuration(
+    /// Presigns the request `delete_security_configuration` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_security_configuration(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteSecurityConfigurationInput,
-    ) -> Result<DeleteSecurityConfigurationOutput, RusotoError<DeleteSecurityConfigurationError>>
-    {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_security_configuration_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_security_configuration_request(
+        &self,
+        input: DeleteSecurityConfigurationInput,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "elasticmapreduce", &self.region, "/");
+        request.set_operation("DeleteSecurityConfiguration");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -4017,83 +4453,163 @@ impl Emr for EmrClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `describe_cluster`, also returning the metadata of the response.
+    pub async fn describe_cluster_with_metadata(
+        &self,
+        input: DescribeClusterInput,
+    ) -> Result<(DescribeClusterOutput, ResponseMetadata), RusotoError<DescribeClusterError>> {
+        let request = self.build_describe_cluster_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DeleteSecurityConfigurationOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DescribeClusterOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteSecurityConfigurationError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DescribeClusterError::from_response(response))
         }
     }
 
-    /// <p>Provides cluster-level details including status, hardware and software configuration, VPC settings, and so on. </p>
-    async fn describe_cluster(
+    /// Presigns the request `describe_cluster` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_cluster(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeClusterInput,
-    ) -> Result<DescribeClusterOutput, RusotoError<DescribeClusterError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_cluster_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_cluster_request(&self, input: DescribeClusterInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "elasticmapreduce", &self.region, "/");
+        request.set_operation("DescribeCluster");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "ElasticMapReduce.DescribeCluster");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `describe_job_flows`, also returning the metadata of the response.
+    pub async fn describe_job_flows_with_metadata(
+        &self,
+        input: DescribeJobFlowsInput,
+    ) -> Result<(DescribeJobFlowsOutput, ResponseMetadata), RusotoError<DescribeJobFlowsError>>
+    {
+        let request = self.build_describe_job_flows_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<DescribeClusterOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DescribeJobFlowsOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeClusterError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DescribeJobFlowsError::from_response(response))
         }
     }
 
-    /// <p>This API is deprecated and will eventually be removed. We recommend you use <a>ListClusters</a>, <a>DescribeCluster</a>, <a>ListSteps</a>, <a>ListInstanceGroups</a> and <a>ListBootstrapActions</a> instead.</p> <p>DescribeJobFlows returns a list of job flows that match all of the supplied parameters. The parameters can include a list of job flow IDs, job flow states, and restrictions on job flow creation date and time.</p> <p>Regardless of supplied parameters, only job flows created within the last two months are returned.</p> <p>If no parameters are supplied, then job flows matching either of the following criteria are returned:</p> <ul> <li> <p>Job flows created and completed in the last two weeks</p> </li> <li> <p> Job flows created within the last two months that are in one of the following states: <code>RUNNING</code>, <code>WAITING</code>, <code>SHUTTING_DOWN</code>, <code>STARTING</code> </p> </li> </ul> <p>Amazon EMR can return a maximum of 512 job flow descriptions.</p>
-    async fn describe_job_flows(
+    /// Presigns the request `describe_job_flows` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_job_flows(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeJobFlowsInput,
-    ) -> Result<DescribeJobFlowsOutput, RusotoError<DescribeJobFlowsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_job_flows_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_job_flows_request(&self, input: DescribeJobFlowsInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "elasticmapreduce", &self.region, "/");
+        request.set_operation("DescribeJobFlows");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "ElasticMapReduce.DescribeJobFlows");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `describe_security_configuration`, also returning the metadata of the response.
+    pub async fn describe_security_configuration_with_metadata(
+        &self,
+        input: DescribeSecurityConfigurationInput,
+    ) -> Result<
+        (DescribeSecurityConfigurationOutput, ResponseMetadata),
+        RusotoError<DescribeSecurityConfigurationError>,
+    > {
+        let request = self.build_describe_security_configuration_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<DescribeJobFlowsOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DescribeSecurityConfigurationOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeJobFlowsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DescribeSecurityConfigurationError::from_response(response))
         }
     }
 
-    /// <p>Provides the details of a security configuration by returning the configuration JSON.</p>
-    async fn describe_security_configuration(
+    /// Presigns the request `describe_security_configuration` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_security_configuration(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeSecurityConfigurationInput,
-    ) -> Result<DescribeSecurityConfigurationOutput, RusotoError<DescribeSecurityConfigurationError>>
-    {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_security_configuration_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_security_configuration_request(
+        &self,
+        input: DescribeSecurityConfigurationInput,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "elasticmapreduce", &self.region, "/");
+        request.set_operation("DescribeSecurityConfiguration");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -4103,57 +4619,107 @@ impl Emr for EmrClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `describe_step`, also returning the metadata of the response.
+    pub async fn describe_step_with_metadata(
+        &self,
+        input: DescribeStepInput,
+    ) -> Result<(DescribeStepOutput, ResponseMetadata), RusotoError<DescribeStepError>> {
+        let request = self.build_describe_step_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DescribeSecurityConfigurationOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DescribeStepOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeSecurityConfigurationError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DescribeStepError::from_response(response))
         }
     }
 
-    /// <p>Provides more detail about the cluster step.</p>
-    async fn describe_step(
+    /// Presigns the request `describe_step` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_step(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeStepInput,
-    ) -> Result<DescribeStepOutput, RusotoError<DescribeStepError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_step_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_step_request(&self, input: DescribeStepInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "elasticmapreduce", &self.region, "/");
+        request.set_operation("DescribeStep");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "ElasticMapReduce.DescribeStep");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `get_block_public_access_configuration`, also returning the metadata of the response.
+    pub async fn get_block_public_access_configuration_with_metadata(
+        &self,
+    ) -> Result<
+        (GetBlockPublicAccessConfigurationOutput, ResponseMetadata),
+        RusotoError<GetBlockPublicAccessConfigurationError>,
+    > {
+        let request = self.build_get_block_public_access_configuration_request();
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<DescribeStepOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<GetBlockPublicAccessConfigurationOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeStepError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(GetBlockPublicAccessConfigurationError::from_response(
+                response,
+            ))
         }
     }
 
-    /// <p>Returns the Amazon EMR block public access configuration for your AWS account in the current Region. For more information see <a href="https://docs.aws.amazon.com/emr/latest/ManagementGuide/configure-block-public-access.html">Configure Block Public Access for Amazon EMR</a> in the <i>Amazon EMR Management Guide</i>.</p>
-    async fn get_block_public_access_configuration(
+    /// Presigns the request `get_block_public_access_configuration` would send with `presigner` instead of sending it.
+    pub async fn presign_get_block_public_access_configuration(
         &self,
-    ) -> Result<
-        GetBlockPublicAccessConfigurationOutput,
-        RusotoError<GetBlockPublicAccessConfigurationError>,
-    > {
+        presigner: &rusoto_core::Presigner,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_block_public_access_configuration_request();
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_block_public_access_configuration_request(&self) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "elasticmapreduce", &self.region, "/");
+        request.set_operation("GetBlockPublicAccessConfiguration");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -4162,169 +4728,326 @@ impl Emr for EmrClient {
         );
         request.set_payload(Some(bytes::Bytes::from_static(b"{}")));
 
+        request
+    }
+
+    /// Calls `list_bootstrap_actions`, also returning the metadata of the response.
+    pub async fn list_bootstrap_actions_with_metadata(
+        &self,
+        input: ListBootstrapActionsInput,
+    ) -> Result<
+        (ListBootstrapActionsOutput, ResponseMetadata),
+        RusotoError<ListBootstrapActionsError>,
+    > {
+        let request = self.build_list_bootstrap_actions_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<GetBlockPublicAccessConfigurationOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ListBootstrapActionsOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(GetBlockPublicAccessConfigurationError::from_response(
-                response,
-            ))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ListBootstrapActionsError::from_response(response))
         }
     }
 
-    /// <p>Provides information about the bootstrap actions associated with a cluster.</p>
-    async fn list_bootstrap_actions(
+    /// Presigns the request `list_bootstrap_actions` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_bootstrap_actions(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListBootstrapActionsInput,
-    ) -> Result<ListBootstrapActionsOutput, RusotoError<ListBootstrapActionsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_bootstrap_actions_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_bootstrap_actions_request(
+        &self,
+        input: ListBootstrapActionsInput,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "elasticmapreduce", &self.region, "/");
+        request.set_operation("ListBootstrapActions");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "ElasticMapReduce.ListBootstrapActions");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `list_clusters`, also returning the metadata of the response.
+    pub async fn list_clusters_with_metadata(
+        &self,
+        input: ListClustersInput,
+    ) -> Result<(ListClustersOutput, ResponseMetadata), RusotoError<ListClustersError>> {
+        let request = self.build_list_clusters_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListBootstrapActionsOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ListClustersOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ListBootstrapActionsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ListClustersError::from_response(response))
         }
     }
 
-    /// <p>Provides the status of all clusters visible to this AWS account. Allows you to filter the list of clusters based on certain criteria; for example, filtering by cluster creation date and time or by status. This call returns a maximum of 50 clusters per call, but returns a marker to track the paging of the cluster list across multiple ListClusters calls.</p>
-    async fn list_clusters(
+    /// Presigns the request `list_clusters` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_clusters(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListClustersInput,
-    ) -> Result<ListClustersOutput, RusotoError<ListClustersError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_clusters_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_clusters_request(&self, input: ListClustersInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "elasticmapreduce", &self.region, "/");
+        request.set_operation("ListClusters");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "ElasticMapReduce.ListClusters");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `list_instance_fleets`, also returning the metadata of the response.
+    pub async fn list_instance_fleets_with_metadata(
+        &self,
+        input: ListInstanceFleetsInput,
+    ) -> Result<(ListInstanceFleetsOutput, ResponseMetadata), RusotoError<ListInstanceFleetsError>>
+    {
+        let request = self.build_list_instance_fleets_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<ListClustersOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ListInstanceFleetsOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ListClustersError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ListInstanceFleetsError::from_response(response))
         }
     }
 
-    /// <p><p>Lists all available details about the instance fleets in a cluster.</p> <note> <p>The instance fleet configuration is available only in Amazon EMR versions 4.8.0 and later, excluding 5.0.x versions.</p> </note></p>
-    async fn list_instance_fleets(
+    /// Presigns the request `list_instance_fleets` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_instance_fleets(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListInstanceFleetsInput,
-    ) -> Result<ListInstanceFleetsOutput, RusotoError<ListInstanceFleetsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_instance_fleets_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_instance_fleets_request(&self, input: ListInstanceFleetsInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "elasticmapreduce", &self.region, "/");
+        request.set_operation("ListInstanceFleets");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "ElasticMapReduce.ListInstanceFleets");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `list_instance_groups`, also returning the metadata of the response.
+    pub async fn list_instance_groups_with_metadata(
+        &self,
+        input: ListInstanceGroupsInput,
+    ) -> Result<(ListInstanceGroupsOutput, ResponseMetadata), RusotoError<ListInstanceGroupsError>>
+    {
+        let request = self.build_list_instance_groups_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListInstanceFleetsOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ListInstanceGroupsOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ListInstanceFleetsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ListInstanceGroupsError::from_response(response))
         }
     }
 
-    /// <p>Provides all available details about the instance groups in a cluster.</p>
-    async fn list_instance_groups(
+    /// Presigns the request `list_instance_groups` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_instance_groups(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListInstanceGroupsInput,
-    ) -> Result<ListInstanceGroupsOutput, RusotoError<ListInstanceGroupsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_instance_groups_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_instance_groups_request(&self, input: ListInstanceGroupsInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "elasticmapreduce", &self.region, "/");
+        request.set_operation("ListInstanceGroups");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "ElasticMapReduce.ListInstanceGroups");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `list_instances`, also returning the metadata of the response.
+    pub async fn list_instances_with_metadata(
+        &self,
+        input: ListInstancesInput,
+    ) -> Result<(ListInstancesOutput, ResponseMetadata), RusotoError<ListInstancesError>> {
+        let request = self.build_list_instances_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListInstanceGroupsOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ListInstancesOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ListInstanceGroupsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ListInstancesError::from_response(response))
         }
     }
 
-    /// <p>Provides information for all active EC2 instances and EC2 instances terminated in the last 30 days, up to a maximum of 2,000. EC2 instances in any of the following states are considered active: AWAITING_FULFILLMENT, PROVISIONING, BOOTSTRAPPING, RUNNING.</p>
-    async fn list_instances(
+    /// Presigns the request `list_instances` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_instances(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListInstancesInput,
-    ) -> Result<ListInstancesOutput, RusotoError<ListInstancesError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_instances_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_instances_request(&self, input: ListInstancesInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "elasticmapreduce", &self.region, "/");
+        request.set_operation("ListInstances");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "ElasticMapReduce.ListInstances");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `list_security_configurations`, also returning the metadata of the response.
+    pub async fn list_security_configurations_with_metadata(
+        &self,
+        input: ListSecurityConfigurationsInput,
+    ) -> Result<
+        (ListSecurityConfigurationsOutput, ResponseMetadata),
+        RusotoError<ListSecurityConfigurationsError>,
+    > {
+        let request = self.build_list_security_configurations_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<ListInstancesOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ListSecurityConfigurationsOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ListInstancesError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ListSecurityConfigurationsError::from_response(response))
         }
     }
 
-    /// <p>Lists all the security configurations visible to this account, providing their creation dates and times, and their names. This call returns a maximum of 50 clusters per call, but returns a marker to track the paging of the cluster list across multiple ListSecurityConfigurations calls.</p>
-    async fn list_security_configurations(
+    /// Presigns the request `list_security_configurations` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_security_configurations(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListSecurityConfigurationsInput,
-    ) -> Result<ListSecurityConfigurationsOutput, RusotoError<ListSecurityConfigurationsError>>
-    {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_security_configurations_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_security_configurations_request(
+        &self,
+        input: ListSecurityConfigurationsInput,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "elasticmapreduce", &self.region, "/");
+        request.set_operation("ListSecurityConfigurations");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -4334,167 +5057,322 @@ impl Emr for EmrClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `list_steps`, also returning the metadata of the response.
+    pub async fn list_steps_with_metadata(
+        &self,
+        input: ListStepsInput,
+    ) -> Result<(ListStepsOutput, ResponseMetadata), RusotoError<ListStepsError>> {
+        let request = self.build_list_steps_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListSecurityConfigurationsOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result =
+                proto::json::ResponsePayload::new(&response).deserialize::<ListStepsOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ListSecurityConfigurationsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ListStepsError::from_response(response))
         }
     }
 
-    /// <p>Provides a list of steps for the cluster in reverse order unless you specify <code>stepIds</code> with the request of filter by <code>StepStates</code>. You can specify a maximum of ten <code>stepIDs</code>.</p>
-    async fn list_steps(
+    /// Presigns the request `list_steps` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_steps(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListStepsInput,
-    ) -> Result<ListStepsOutput, RusotoError<ListStepsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_steps_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_steps_request(&self, input: ListStepsInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "elasticmapreduce", &self.region, "/");
+        request.set_operation("ListSteps");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "ElasticMapReduce.ListSteps");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `modify_cluster`, also returning the metadata of the response.
+    pub async fn modify_cluster_with_metadata(
+        &self,
+        input: ModifyClusterInput,
+    ) -> Result<(ModifyClusterOutput, ResponseMetadata), RusotoError<ModifyClusterError>> {
+        let request = self.build_modify_cluster_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<ListStepsOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ModifyClusterOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ListStepsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ModifyClusterError::from_response(response))
         }
     }
 
-    /// <p>Modifies the number of steps that can be executed concurrently for the cluster specified using ClusterID.</p>
-    async fn modify_cluster(
+    /// Presigns the request `modify_cluster` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_modify_cluster(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ModifyClusterInput,
-    ) -> Result<ModifyClusterOutput, RusotoError<ModifyClusterError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_modify_cluster_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_modify_cluster_request(&self, input: ModifyClusterInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "elasticmapreduce", &self.region, "/");
+        request.set_operation("ModifyCluster");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "ElasticMapReduce.ModifyCluster");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `modify_instance_fleet`, also returning the metadata of the response.
+    pub async fn modify_instance_fleet_with_metadata(
+        &self,
+        input: ModifyInstanceFleetInput,
+    ) -> Result<((), ResponseMetadata), RusotoError<ModifyInstanceFleetError>> {
+        let request = self.build_modify_instance_fleet_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<ModifyClusterOutput, _>()
+            std::mem::drop(response);
+            Ok(((), metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ModifyClusterError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ModifyInstanceFleetError::from_response(response))
         }
     }
 
-    /// <p><p>Modifies the target On-Demand and target Spot capacities for the instance fleet with the specified InstanceFleetID within the cluster specified using ClusterID. The call either succeeds or fails atomically.</p> <note> <p>The instance fleet configuration is available only in Amazon EMR versions 4.8.0 and later, excluding 5.0.x versions.</p> </note></p>
-    async fn modify_instance_fleet(
+    /// Presigns the request `modify_instance_fleet` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_modify_instance_fleet(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ModifyInstanceFleetInput,
-    ) -> Result<(), RusotoError<ModifyInstanceFleetError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_modify_instance_fleet_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_modify_instance_fleet_request(
+        &self,
+        input: ModifyInstanceFleetInput,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "elasticmapreduce", &self.region, "/");
+        request.set_operation("ModifyInstanceFleet");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "ElasticMapReduce.ModifyInstanceFleet");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `modify_instance_groups`, also returning the metadata of the response.
+    pub async fn modify_instance_groups_with_metadata(
+        &self,
+        input: ModifyInstanceGroupsInput,
+    ) -> Result<((), ResponseMetadata), RusotoError<ModifyInstanceGroupsError>> {
+        let request = self.build_modify_instance_groups_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
             std::mem::drop(response);
-            Ok(())
+            Ok(((), metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ModifyInstanceFleetError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ModifyInstanceGroupsError::from_response(response))
         }
     }
 
-    /// <p>ModifyInstanceGroups modifies the number of nodes and configuration settings of an instance group. The input parameters include the new target instance count for the group and the instance group ID. The call will either succeed or fail atomically.</p>
-    async fn modify_instance_groups(
+    /// Presigns the request `modify_instance_groups` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_modify_instance_groups(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ModifyInstanceGroupsInput,
-    ) -> Result<(), RusotoError<ModifyInstanceGroupsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_modify_instance_groups_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_modify_instance_groups_request(
+        &self,
+        input: ModifyInstanceGroupsInput,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "elasticmapreduce", &self.region, "/");
+        request.set_operation("ModifyInstanceGroups");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "ElasticMapReduce.ModifyInstanceGroups");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `put_auto_scaling_policy`, also returning the metadata of the response.
+    pub async fn put_auto_scaling_policy_with_metadata(
+        &self,
+        input: PutAutoScalingPolicyInput,
+    ) -> Result<
+        (PutAutoScalingPolicyOutput, ResponseMetadata),
+        RusotoError<PutAutoScalingPolicyError>,
+    > {
+        let request = self.build_put_auto_scaling_policy_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            std::mem::drop(response);
-            Ok(())
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<PutAutoScalingPolicyOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ModifyInstanceGroupsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(PutAutoScalingPolicyError::from_response(response))
         }
     }
 
-    /// <p>Creates or updates an automatic scaling policy for a core instance group or task instance group in an Amazon EMR cluster. The automatic scaling policy defines how an instance group dynamically adds and terminates EC2 instances in response to the value of a CloudWatch metric.</p>
-    async fn put_auto_scaling_policy(
+    /// Presigns the request `put_auto_scaling_policy` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_put_auto_scaling_policy(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: PutAutoScalingPolicyInput,
-    ) -> Result<PutAutoScalingPolicyOutput, RusotoError<PutAutoScalingPolicyError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_put_auto_scaling_policy_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_put_auto_scaling_policy_request(
+        &self,
+        input: PutAutoScalingPolicyInput,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "elasticmapreduce", &self.region, "/");
+        request.set_operation("PutAutoScalingPolicy");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "ElasticMapReduce.PutAutoScalingPolicy");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `put_block_public_access_configuration`, also returning the metadata of the response.
+    pub async fn put_block_public_access_configuration_with_metadata(
+        &self,
+        input: PutBlockPublicAccessConfigurationInput,
+    ) -> Result<
+        (PutBlockPublicAccessConfigurationOutput, ResponseMetadata),
+        RusotoError<PutBlockPublicAccessConfigurationError>,
+    > {
+        let request = self.build_put_block_public_access_configuration_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<PutAutoScalingPolicyOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<PutBlockPublicAccessConfigurationOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(PutAutoScalingPolicyError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(PutBlockPublicAccessConfigurationError::from_response(
+                response,
+            ))
         }
     }
 
-    /// <p>Creates or updates an Amazon EMR block public access configuration for your AWS account in the current Region. For more information see <a href="https://docs.aws.amazon.com/emr/latest/ManagementGuide/configure-block-public-access.html">Configure Block Public Access for Amazon EMR</a> in the <i>Amazon EMR Management Guide</i>.</p>
-    async fn put_block_public_access_configuration(
+    /// Presigns the request `put_block_public_access_configuration` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_put_block_public_access_configuration(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: PutBlockPublicAccessConfigurationInput,
-    ) -> Result<
-        PutBlockPublicAccessConfigurationOutput,
-        RusotoError<PutBlockPublicAccessConfigurationError>,
-    > {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_put_block_public_access_configuration_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_put_block_public_access_configuration_request(
+        &self,
+        input: PutBlockPublicAccessConfigurationInput,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "elasticmapreduce", &self.region, "/");
+        request.set_operation("PutBlockPublicAccessConfiguration");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -4504,184 +5382,1054 @@ impl Emr for EmrClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `remove_auto_scaling_policy`, also returning the metadata of the response.
+    pub async fn remove_auto_scaling_policy_with_metadata(
+        &self,
+        input: RemoveAutoScalingPolicyInput,
+    ) -> Result<
+        (RemoveAutoScalingPolicyOutput, ResponseMetadata),
+        RusotoError<RemoveAutoScalingPolicyError>,
+    > {
+        let request = self.build_remove_auto_scaling_policy_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<PutBlockPublicAccessConfigurationOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<RemoveAutoScalingPolicyOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(PutBlockPublicAccessConfigurationError::from_response(
-                response,
-            ))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(RemoveAutoScalingPolicyError::from_response(response))
         }
     }
 
-    /// <p>Removes an automatic scaling policy from a specified instance group within an EMR cluster.</p>
-    async fn remove_auto_scaling_policy(
+    /// Presigns the request `remove_auto_scaling_policy` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_remove_auto_scaling_policy(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: RemoveAutoScalingPolicyInput,
-    ) -> Result<RemoveAutoScalingPolicyOutput, RusotoError<RemoveAutoScalingPolicyError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_remove_auto_scaling_policy_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_remove_auto_scaling_policy_request(
+        &self,
+        input: RemoveAutoScalingPolicyInput,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "elasticmapreduce", &self.region, "/");
+        request.set_operation("RemoveAutoScalingPolicy");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "ElasticMapReduce.RemoveAutoScalingPolicy");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `remove_tags`, also returning the metadata of the response.
+    pub async fn remove_tags_with_metadata(
+        &self,
+        input: RemoveTagsInput,
+    ) -> Result<(RemoveTagsOutput, ResponseMetadata), RusotoError<RemoveTagsError>> {
+        let request = self.build_remove_tags_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<RemoveAutoScalingPolicyOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<RemoveTagsOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(RemoveAutoScalingPolicyError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(RemoveTagsError::from_response(response))
         }
     }
 
-    /// <p>Removes tags from an Amazon EMR resource. Tags make it easier to associate clusters in various ways, such as grouping clusters to track your Amazon EMR resource allocation costs. For more information, see <a href="https://docs.aws.amazon.com/emr/latest/ManagementGuide/emr-plan-tags.html">Tag Clusters</a>. </p> <p>The following example removes the stack tag with value Prod from a cluster:</p>
-    async fn remove_tags(
+    /// Presigns the request `remove_tags` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_remove_tags(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: RemoveTagsInput,
-    ) -> Result<RemoveTagsOutput, RusotoError<RemoveTagsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_remove_tags_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_remove_tags_request(&self, input: RemoveTagsInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "elasticmapreduce", &self.region, "/");
+        request.set_operation("RemoveTags");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "ElasticMapReduce.RemoveTags");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `run_job_flow`, also returning the metadata of the response.
+    pub async fn run_job_flow_with_metadata(
+        &self,
+        input: RunJobFlowInput,
+    ) -> Result<(RunJobFlowOutput, ResponseMetadata), RusotoError<RunJobFlowError>> {
+        let request = self.build_run_job_flow_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<RemoveTagsOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<RunJobFlowOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(RemoveTagsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(RunJobFlowError::from_response(response))
         }
     }
 
-    /// <p><p>RunJobFlow creates and starts running a new cluster (job flow). The cluster runs the steps specified. After the steps complete, the cluster stops and the HDFS partition is lost. To prevent loss of data, configure the last step of the job flow to store results in Amazon S3. If the <a>JobFlowInstancesConfig</a> <code>KeepJobFlowAliveWhenNoSteps</code> parameter is set to <code>TRUE</code>, the cluster transitions to the WAITING state rather than shutting down after the steps have completed. </p> <p>For additional protection, you can set the <a>JobFlowInstancesConfig</a> <code>TerminationProtected</code> parameter to <code>TRUE</code> to lock the cluster and prevent it from being terminated by API call, user intervention, or in the event of a job flow error.</p> <p>A maximum of 256 steps are allowed in each job flow.</p> <p>If your cluster is long-running (such as a Hive data warehouse) or complex, you may require more than 256 steps to process your data. You can bypass the 256-step limitation in various ways, including using the SSH shell to connect to the master node and submitting queries directly to the software running on the master node, such as Hive and Hadoop. For more information on how to do this, see <a href="https://docs.aws.amazon.com/emr/latest/ManagementGuide/AddMoreThan256Steps.html">Add More than 256 Steps to a Cluster</a> in the <i>Amazon EMR Management Guide</i>.</p> <p>For long running clusters, we recommend that you periodically store your results.</p> <note> <p>The instance fleets configuration is available only in Amazon EMR versions 4.8.0 and later, excluding 5.0.x versions. The RunJobFlow request can contain InstanceFleets parameters or InstanceGroups parameters, but not both.</p> </note></p>
-    async fn run_job_flow(
+    /// Presigns the request `run_job_flow` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_run_job_flow(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: RunJobFlowInput,
-    ) -> Result<RunJobFlowOutput, RusotoError<RunJobFlowError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_run_job_flow_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_run_job_flow_request(&self, input: RunJobFlowInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "elasticmapreduce", &self.region, "/");
+        request.set_operation("RunJobFlow");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "ElasticMapReduce.RunJobFlow");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `set_termination_protection`, also returning the metadata of the response.
+    pub async fn set_termination_protection_with_metadata(
+        &self,
+        input: SetTerminationProtectionInput,
+    ) -> Result<((), ResponseMetadata), RusotoError<SetTerminationProtectionError>> {
+        let request = self.build_set_termination_protection_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<RunJobFlowOutput, _>()
+            std::mem::drop(response);
+            Ok(((), metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(RunJobFlowError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(SetTerminationProtectionError::from_response(response))
         }
     }
 
-    /// <p>SetTerminationProtection locks a cluster (job flow) so the EC2 instances in the cluster cannot be terminated by user intervention, an API call, or in the event of a job-flow error. The cluster still terminates upon successful completion of the job flow. Calling <code>SetTerminationProtection</code> on a cluster is similar to calling the Amazon EC2 <code>DisableAPITermination</code> API on all EC2 instances in a cluster.</p> <p> <code>SetTerminationProtection</code> is used to prevent accidental termination of a cluster and to ensure that in the event of an error, the instances persist so that you can recover any data stored in their ephemeral instance storage.</p> <p> To terminate a cluster that has been locked by setting <code>SetTerminationProtection</code> to <code>true</code>, you must first unlock the job flow by a subsequent call to <code>SetTerminationProtection</code> in which you set the value to <code>false</code>. </p> <p> For more information, see<a href="https://docs.aws.amazon.com/emr/latest/ManagementGuide/UsingEMR_TerminationProtection.html">Managing Cluster Termination</a> in the <i>Amazon EMR Management Guide</i>. </p>
-    async fn set_termination_protection(
+    /// Presigns the request `set_termination_protection` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_set_termination_protection(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: SetTerminationProtectionInput,
-    ) -> Result<(), RusotoError<SetTerminationProtectionError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_set_termination_protection_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_set_termination_protection_request(
+        &self,
+        input: SetTerminationProtectionInput,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "elasticmapreduce", &self.region, "/");
+        request.set_operation("SetTerminationProtection");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "ElasticMapReduce.SetTerminationProtection");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `set_visible_to_all_users`, also returning the metadata of the response.
+    pub async fn set_visible_to_all_users_with_metadata(
+        &self,
+        input: SetVisibleToAllUsersInput,
+    ) -> Result<((), ResponseMetadata), RusotoError<SetVisibleToAllUsersError>> {
+        let request = self.build_set_visible_to_all_users_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
             std::mem::drop(response);
-            Ok(())
+            Ok(((), metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(SetTerminationProtectionError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(SetVisibleToAllUsersError::from_response(response))
         }
     }
 
-    /// <p>Sets the <a>Cluster$VisibleToAllUsers</a> value, which determines whether the cluster is visible to all IAM users of the AWS account associated with the cluster. Only the IAM user who created the cluster or the AWS account root user can call this action. The default value, <code>true</code>, indicates that all IAM users in the AWS account can perform cluster actions if they have the proper IAM policy permissions. If set to <code>false</code>, only the IAM user that created the cluster can perform actions. This action works on running clusters. You can override the default <code>true</code> setting when you create a cluster by using the <code>VisibleToAllUsers</code> parameter with <code>RunJobFlow</code>.</p>
-    async fn set_visible_to_all_users(
+    /// Presigns the request `set_visible_to_all_users` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_set_visible_to_all_users(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: SetVisibleToAllUsersInput,
-    ) -> Result<(), RusotoError<SetVisibleToAllUsersError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_set_visible_to_all_users_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_set_visible_to_all_users_request(
+        &self,
+        input: SetVisibleToAllUsersInput,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "elasticmapreduce", &self.region, "/");
+        request.set_operation("SetVisibleToAllUsers");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "ElasticMapReduce.SetVisibleToAllUsers");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `terminate_job_flows`, also returning the metadata of the response.
+    pub async fn terminate_job_flows_with_metadata(
+        &self,
+        input: TerminateJobFlowsInput,
+    ) -> Result<((), ResponseMetadata), RusotoError<TerminateJobFlowsError>> {
+        let request = self.build_terminate_job_flows_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
             std::mem::drop(response);
-            Ok(())
+            Ok(((), metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(SetVisibleToAllUsersError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(TerminateJobFlowsError::from_response(response))
         }
     }
 
-    /// <p>TerminateJobFlows shuts a list of clusters (job flows) down. When a job flow is shut down, any step not yet completed is canceled and the EC2 instances on which the cluster is running are stopped. Any log files not already saved are uploaded to Amazon S3 if a LogUri was specified when the cluster was created.</p> <p>The maximum number of clusters allowed is 10. The call to <code>TerminateJobFlows</code> is asynchronous. Depending on the configuration of the cluster, it may take up to 1-5 minutes for the cluster to completely terminate and release allocated resources, such as Amazon EC2 instances.</p>
-    async fn terminate_job_flows(
+    /// Presigns the request `terminate_job_flows` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_terminate_job_flows(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: TerminateJobFlowsInput,
-    ) -> Result<(), RusotoError<TerminateJobFlowsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_terminate_job_flows_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_terminate_job_flows_request(&self, input: TerminateJobFlowsInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "elasticmapreduce", &self.region, "/");
+        request.set_operation("TerminateJobFlows");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "ElasticMapReduce.TerminateJobFlows");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
-        let mut response = self
-            .client
-            .sign_and_dispatch(request)
-            .await
-            .map_err(RusotoError::from)?;
-        if response.status.is_success() {
-            std::mem::drop(response);
-            Ok(())
-        } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(TerminateJobFlowsError::from_response(response))
-        }
+        request
+    }
+
+    /// The pages of `ListBootstrapActions` results for `input`, requesting each next page with the `Marker` of the last one as its `Marker`.
+    pub fn list_bootstrap_actions_pages(
+        &self,
+        input: ListBootstrapActionsInput,
+    ) -> impl futures::Stream<
+        Item = Result<ListBootstrapActionsOutput, RusotoError<ListBootstrapActionsError>>,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: EmrClient, input| async move { client.list_bootstrap_actions(input).await },
+            |input: &ListBootstrapActionsInput, output: &ListBootstrapActionsOutput| {
+                let marker = output.marker.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if marker.is_none() || marker == input.marker {
+                    return None;
+                }
+                Some(ListBootstrapActionsInput {
+                    marker,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `BootstrapActions` of every page of `ListBootstrapActions` results for `input`, as `list_bootstrap_actions_pages` requests them.
+    pub fn list_bootstrap_actions_stream(
+        &self,
+        input: ListBootstrapActionsInput,
+    ) -> impl futures::Stream<Item = Result<Command, RusotoError<ListBootstrapActionsError>>> + Send
+    {
+        rusoto_core::pagination::items(self.list_bootstrap_actions_pages(input), |page| {
+            page.bootstrap_actions.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `ListClusters` results for `input`, requesting each next page with the `Marker` of the last one as its `Marker`.
+    pub fn list_clusters_pages(
+        &self,
+        input: ListClustersInput,
+    ) -> impl futures::Stream<Item = Result<ListClustersOutput, RusotoError<ListClustersError>>> + Send
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: EmrClient, input| async move { client.list_clusters(input).await },
+            |input: &ListClustersInput, output: &ListClustersOutput| {
+                let marker = output.marker.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if marker.is_none() || marker == input.marker {
+                    return None;
+                }
+                Some(ListClustersInput {
+                    marker,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `Clusters` of every page of `ListClusters` results for `input`, as `list_clusters_pages` requests them.
+    pub fn list_clusters_stream(
+        &self,
+        input: ListClustersInput,
+    ) -> impl futures::Stream<Item = Result<ClusterSummary, RusotoError<ListClustersError>>> + Send
+    {
+        rusoto_core::pagination::items(self.list_clusters_pages(input), |page| {
+            page.clusters.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `ListInstanceFleets` results for `input`, requesting each next page with the `Marker` of the last one as its `Marker`.
+    pub fn list_instance_fleets_pages(
+        &self,
+        input: ListInstanceFleetsInput,
+    ) -> impl futures::Stream<
+        Item = Result<ListInstanceFleetsOutput, RusotoError<ListInstanceFleetsError>>,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: EmrClient, input| async move { client.list_instance_fleets(input).await },
+            |input: &ListInstanceFleetsInput, output: &ListInstanceFleetsOutput| {
+                let marker = output.marker.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if marker.is_none() || marker == input.marker {
+                    return None;
+                }
+                Some(ListInstanceFleetsInput {
+                    marker,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `InstanceFleets` of every page of `ListInstanceFleets` results for `input`, as `list_instance_fleets_pages` requests them.
+    pub fn list_instance_fleets_stream(
+        &self,
+        input: ListInstanceFleetsInput,
+    ) -> impl futures::Stream<Item = Result<InstanceFleet, RusotoError<ListInstanceFleetsError>>> + Send
+    {
+        rusoto_core::pagination::items(self.list_instance_fleets_pages(input), |page| {
+            page.instance_fleets.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `ListInstanceGroups` results for `input`, requesting each next page with the `Marker` of the last one as its `Marker`.
+    pub fn list_instance_groups_pages(
+        &self,
+        input: ListInstanceGroupsInput,
+    ) -> impl futures::Stream<
+        Item = Result<ListInstanceGroupsOutput, RusotoError<ListInstanceGroupsError>>,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: EmrClient, input| async move { client.list_instance_groups(input).await },
+            |input: &ListInstanceGroupsInput, output: &ListInstanceGroupsOutput| {
+                let marker = output.marker.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if marker.is_none() || marker == input.marker {
+                    return None;
+                }
+                Some(ListInstanceGroupsInput {
+                    marker,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `InstanceGroups` of every page of `ListInstanceGroups` results for `input`, as `list_instance_groups_pages` requests them.
+    pub fn list_instance_groups_stream(
+        &self,
+        input: ListInstanceGroupsInput,
+    ) -> impl futures::Stream<Item = Result<InstanceGroup, RusotoError<ListInstanceGroupsError>>> + Send
+    {
+        rusoto_core::pagination::items(self.list_instance_groups_pages(input), |page| {
+            page.instance_groups.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `ListInstances` results for `input`, requesting each next page with the `Marker` of the last one as its `Marker`.
+    pub fn list_instances_pages(
+        &self,
+        input: ListInstancesInput,
+    ) -> impl futures::Stream<Item = Result<ListInstancesOutput, RusotoError<ListInstancesError>>> + Send
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: EmrClient, input| async move { client.list_instances(input).await },
+            |input: &ListInstancesInput, output: &ListInstancesOutput| {
+                let marker = output.marker.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if marker.is_none() || marker == input.marker {
+                    return None;
+                }
+                Some(ListInstancesInput {
+                    marker,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `Instances` of every page of `ListInstances` results for `input`, as `list_instances_pages` requests them.
+    pub fn list_instances_stream(
+        &self,
+        input: ListInstancesInput,
+    ) -> impl futures::Stream<Item = Result<Instance, RusotoError<ListInstancesError>>> + Send {
+        rusoto_core::pagination::items(self.list_instances_pages(input), |page| {
+            page.instances.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `ListSecurityConfigurations` results for `input`, requesting each next page with the `Marker` of the last one as its `Marker`.
+    pub fn list_security_configurations_pages(
+        &self,
+        input: ListSecurityConfigurationsInput,
+    ) -> impl futures::Stream<
+        Item = Result<
+            ListSecurityConfigurationsOutput,
+            RusotoError<ListSecurityConfigurationsError>,
+        >,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: EmrClient, input| async move { client.list_security_configurations(input).await },
+            |input: &ListSecurityConfigurationsInput, output: &ListSecurityConfigurationsOutput| {
+                let marker = output.marker.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if marker.is_none() || marker == input.marker {
+                    return None;
+                }
+                Some(ListSecurityConfigurationsInput {
+                    marker,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `SecurityConfigurations` of every page of `ListSecurityConfigurations` results for `input`, as `list_security_configurations_pages` requests them.
+    pub fn list_security_configurations_stream(
+        &self,
+        input: ListSecurityConfigurationsInput,
+    ) -> impl futures::Stream<
+        Item = Result<SecurityConfigurationSummary, RusotoError<ListSecurityConfigurationsError>>,
+    > + Send {
+        rusoto_core::pagination::items(self.list_security_configurations_pages(input), |page| {
+            page.security_configurations.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `ListSteps` results for `input`, requesting each next page with the `Marker` of the last one as its `Marker`.
+    pub fn list_steps_pages(
+        &self,
+        input: ListStepsInput,
+    ) -> impl futures::Stream<Item = Result<ListStepsOutput, RusotoError<ListStepsError>>> + Send
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: EmrClient, input| async move { client.list_steps(input).await },
+            |input: &ListStepsInput, output: &ListStepsOutput| {
+                let marker = output.marker.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if marker.is_none() || marker == input.marker {
+                    return None;
+                }
+                Some(ListStepsInput {
+                    marker,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `Steps` of every page of `ListSteps` results for `input`, as `list_steps_pages` requests them.
+    pub fn list_steps_stream(
+        &self,
+        input: ListStepsInput,
+    ) -> impl futures::Stream<Item = Result<StepSummary, RusotoError<ListStepsError>>> + Send {
+        rusoto_core::pagination::items(self.list_steps_pages(input), |page| {
+            page.steps.unwrap_or_default()
+        })
+    }
+
+    /// Waits for `ClusterRunning`, polling `DescribeCluster` with `input` every 30 seconds, up to 60 times.
+    pub async fn wait_until_cluster_running(
+        &self,
+        input: DescribeClusterInput,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<DescribeClusterError>> {
+        let waiter = rusoto_core::waiter::Waiter::new(std::time::Duration::from_secs(30), 60);
+        self.wait_until_cluster_running_with(input, &waiter).await
+    }
+
+    /// Waits for `ClusterRunning` like `wait_until_cluster_running`, with the delay, attempts and runtime of `waiter`.
+    pub async fn wait_until_cluster_running_with(
+        &self,
+        input: DescribeClusterInput,
+        waiter: &rusoto_core::waiter::Waiter,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<DescribeClusterError>> {
+        rusoto_core::waiter::wait(
+            waiter,
+            || self.describe_cluster(input.clone()),
+            |result| {
+                if match result {
+                    Ok(output) => std::iter::once(output)
+                        .filter_map(|value| value.cluster.as_ref())
+                        .filter_map(|value| value.status.as_ref())
+                        .filter_map(|value| value.state.as_ref())
+                        .any(|value| value == "RUNNING"),
+                    Err(_) => false,
+                } {
+                    return Some(rusoto_core::waiter::WaiterState::Success);
+                }
+                if match result {
+                    Ok(output) => std::iter::once(output)
+                        .filter_map(|value| value.cluster.as_ref())
+                        .filter_map(|value| value.status.as_ref())
+                        .filter_map(|value| value.state.as_ref())
+                        .any(|value| value == "WAITING"),
+                    Err(_) => false,
+                } {
+                    return Some(rusoto_core::waiter::WaiterState::Success);
+                }
+                if match result {
+                    Ok(output) => std::iter::once(output)
+                        .filter_map(|value| value.cluster.as_ref())
+                        .filter_map(|value| value.status.as_ref())
+                        .filter_map(|value| value.state.as_ref())
+                        .any(|value| value == "TERMINATING"),
+                    Err(_) => false,
+                } {
+                    return Some(rusoto_core::waiter::WaiterState::Failure);
+                }
+                if match result {
+                    Ok(output) => std::iter::once(output)
+                        .filter_map(|value| value.cluster.as_ref())
+                        .filter_map(|value| value.status.as_ref())
+                        .filter_map(|value| value.state.as_ref())
+                        .any(|value| value == "TERMINATED"),
+                    Err(_) => false,
+                } {
+                    return Some(rusoto_core::waiter::WaiterState::Failure);
+                }
+                if match result {
+                    Ok(output) => std::iter::once(output)
+                        .filter_map(|value| value.cluster.as_ref())
+                        .filter_map(|value| value.status.as_ref())
+                        .filter_map(|value| value.state.as_ref())
+                        .any(|value| value == "TERMINATED_WITH_ERRORS"),
+                    Err(_) => false,
+                } {
+                    return Some(rusoto_core::waiter::WaiterState::Failure);
+                }
+                None
+            },
+        )
+        .await
+    }
+
+    /// Waits for `ClusterTerminated`, polling `DescribeCluster` with `input` every 30 seconds, up to 60 times.
+    pub async fn wait_until_cluster_terminated(
+        &self,
+        input: DescribeClusterInput,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<DescribeClusterError>> {
+        let waiter = rusoto_core::waiter::Waiter::new(std::time::Duration::from_secs(30), 60);
+        self.wait_until_cluster_terminated_with(input, &waiter)
+            .await
+    }
+
+    /// Waits for `ClusterTerminated` like `wait_until_cluster_terminated`, with the delay, attempts and runtime of `waiter`.
+    pub async fn wait_until_cluster_terminated_with(
+        &self,
+        input: DescribeClusterInput,
+        waiter: &rusoto_core::waiter::Waiter,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<DescribeClusterError>> {
+        rusoto_core::waiter::wait(
+            waiter,
+            || self.describe_cluster(input.clone()),
+            |result| {
+                if match result {
+                    Ok(output) => std::iter::once(output)
+                        .filter_map(|value| value.cluster.as_ref())
+                        .filter_map(|value| value.status.as_ref())
+                        .filter_map(|value| value.state.as_ref())
+                        .any(|value| value == "TERMINATED"),
+                    Err(_) => false,
+                } {
+                    return Some(rusoto_core::waiter::WaiterState::Success);
+                }
+                if match result {
+                    Ok(output) => std::iter::once(output)
+                        .filter_map(|value| value.cluster.as_ref())
+                        .filter_map(|value| value.status.as_ref())
+                        .filter_map(|value| value.state.as_ref())
+                        .any(|value| value == "TERMINATED_WITH_ERRORS"),
+                    Err(_) => false,
+                } {
+                    return Some(rusoto_core::waiter::WaiterState::Failure);
+                }
+                None
+            },
+        )
+        .await
+    }
+
+    /// Waits for `StepComplete`, polling `DescribeStep` with `input` every 30 seconds, up to 60 times.
+    pub async fn wait_until_step_complete(
+        &self,
+        input: DescribeStepInput,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<DescribeStepError>> {
+        let waiter = rusoto_core::waiter::Waiter::new(std::time::Duration::from_secs(30), 60);
+        self.wait_until_step_complete_with(input, &waiter).await
+    }
+
+    /// Waits for `StepComplete` like `wait_until_step_complete`, with the delay, attempts and runtime of `waiter`.
+    pub async fn wait_until_step_complete_with(
+        &self,
+        input: DescribeStepInput,
+        waiter: &rusoto_core::waiter::Waiter,
+    ) -> Result<(), rusoto_core::waiter::WaiterError<DescribeStepError>> {
+        rusoto_core::waiter::wait(
+            waiter,
+            || self.describe_step(input.clone()),
+            |result| {
+                if match result {
+                    Ok(output) => std::iter::once(output)
+                        .filter_map(|value| value.step.as_ref())
+                        .filter_map(|value| value.status.as_ref())
+                        .filter_map(|value| value.state.as_ref())
+                        .any(|value| value == "COMPLETED"),
+                    Err(_) => false,
+                } {
+                    return Some(rusoto_core::waiter::WaiterState::Success);
+                }
+                if match result {
+                    Ok(output) => std::iter::once(output)
+                        .filter_map(|value| value.step.as_ref())
+                        .filter_map(|value| value.status.as_ref())
+                        .filter_map(|value| value.state.as_ref())
+                        .any(|value| value == "FAILED"),
+                    Err(_) => false,
+                } {
+                    return Some(rusoto_core::waiter::WaiterState::Failure);
+                }
+                if match result {
+                    Ok(output) => std::iter::once(output)
+                        .filter_map(|value| value.step.as_ref())
+                        .filter_map(|value| value.status.as_ref())
+                        .filter_map(|value| value.state.as_ref())
+                        .any(|value| value == "CANCELLED"),
+                    Err(_) => false,
+                } {
+                    return Some(rusoto_core::waiter::WaiterState::Failure);
+                }
+                None
+            },
+        )
+        .await
+    }
+}
+
+#[async_trait]
+impl Emr for EmrClient {
+    /// <p><p>Adds an instance fleet to a running cluster.</p> <note> <p>The instance fleet configuration is available only in Amazon EMR versions 4.8.0 and later, excluding 5.0.x.</p> </note></p>
+    async fn add_instance_fleet(
+        &self,
+        input: AddInstanceFleetInput,
+    ) -> Result<AddInstanceFleetOutput, RusotoError<AddInstanceFleetError>> {
+        self.add_instance_fleet_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Adds one or more instance groups to a running cluster.</p>
+    async fn add_instance_groups(
+        &self,
+        input: AddInstanceGroupsInput,
+    ) -> Result<AddInstanceGroupsOutput, RusotoError<AddInstanceGroupsError>> {
+        self.add_instance_groups_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>AddJobFlowSteps adds new steps to a running cluster. A maximum of 256 steps are allowed in each job flow.</p> <p>If your cluster is long-running (such as a Hive data warehouse) or complex, you may require more than 256 steps to process your data. You can bypass the 256-step limitation in various ways, including using SSH to connect to the master node and submitting queries directly to the software running on the master node, such as Hive and Hadoop. For more information on how to do this, see <a href="https://docs.aws.amazon.com/emr/latest/ManagementGuide/AddMoreThan256Steps.html">Add More than 256 Steps to a Cluster</a> in the <i>Amazon EMR Management Guide</i>.</p> <p>A step specifies the location of a JAR file stored either on the master node of the cluster or in Amazon S3. Each step is performed by the main function of the main class of the JAR file. The main class can be specified either in the manifest of the JAR or by using the MainFunction parameter of the step.</p> <p>Amazon EMR executes each step in the order listed. For a step to be considered complete, the main function must exit with a zero exit code and all Hadoop jobs started while the step was running must have completed and run successfully.</p> <p>You can only add steps to a cluster that is in one of the following states: STARTING, BOOTSTRAPPING, RUNNING, or WAITING.</p>
+    async fn add_job_flow_steps(
+        &self,
+        input: AddJobFlowStepsInput,
+    ) -> Result<AddJobFlowStepsOutput, RusotoError<AddJobFlowStepsError>> {
+        self.add_job_flow_steps_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Adds tags to an Amazon EMR resource. Tags make it easier to associate clusters in various ways, such as grouping clusters to track your Amazon EMR resource allocation costs. For more information, see <a href="https://docs.aws.amazon.com/emr/latest/ManagementGuide/emr-plan-tags.html">Tag Clusters</a>. </p>
+    async fn add_tags(
+        &self,
+        input: AddTagsInput,
+    ) -> Result<AddTagsOutput, RusotoError<AddTagsError>> {
+        self.add_tags_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Cancels a pending step or steps in a running cluster. Available only in Amazon EMR versions 4.8.0 and later, excluding version 5.0.0. A maximum of 256 steps are allowed in each CancelSteps request. CancelSteps is idempotent but asynchronous; it does not guarantee a step will be canceled, even if the request is successfully submitted. You can only cancel steps that are in a <code>PENDING</code> state.</p>
+    async fn cancel_steps(
+        &self,
+        input: CancelStepsInput,
+    ) -> Result<CancelStepsOutput, RusotoError<CancelStepsError>> {
+        self.cancel_steps_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Creates a security configuration, which is stored in the service and can be specified when a cluster is created.</p>
+    async fn create_security_configuration(
+        &self,
+        input: CreateSecurityConfigurationInput,
+    ) -> Result<CreateSecurityConfigurationOutput, RusotoError<CreateSecurityConfigurationError>>
+    {
+        self.create_security_configuration_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes a security configuration.</p>
+    async fn delete_security_configuration(
+        &self,
+        input: DeleteSecurityConfigurationInput,
+    ) -> Result<DeleteSecurityConfigurationOutput, RusotoError<DeleteSecurityConfigurationError>>
+    {
+        self.delete_security_configuration_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Provides cluster-level details including status, hardware and software configuration, VPC settings, and so on. </p>
+    async fn describe_cluster(
+        &self,
+        input: DescribeClusterInput,
+    ) -> Result<DescribeClusterOutput, RusotoError<DescribeClusterError>> {
+        self.describe_cluster_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>This API is deprecated and will eventually be removed. We recommend you use <a>ListClusters</a>, <a>DescribeCluster</a>, <a>ListSteps</a>, <a>ListInstanceGroups</a> and <a>ListBootstrapActions</a> instead.</p> <p>DescribeJobFlows returns a list of job flows that match all of the supplied parameters. The parameters can include a list of job flow IDs, job flow states, and restrictions on job flow creation date and time.</p> <p>Regardless of supplied parameters, only job flows created within the last two months are returned.</p> <p>If no parameters are supplied, then job flows matching either of the following criteria are returned:</p> <ul> <li> <p>Job flows created and completed in the last two weeks</p> </li> <li> <p> Job flows created within the last two months that are in one of the following states: <code>RUNNING</code>, <code>WAITING</code>, <code>SHUTTING_DOWN</code>, <code>STARTING</code> </p> </li> </ul> <p>Amazon EMR can return a maximum of 512 job flow descriptions.</p>
+    async fn describe_job_flows(
+        &self,
+        input: DescribeJobFlowsInput,
+    ) -> Result<DescribeJobFlowsOutput, RusotoError<DescribeJobFlowsError>> {
+        self.describe_job_flows_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Provides the details of a security configuration by returning the configuration JSON.</p>
+    async fn describe_security_configuration(
+        &self,
+        input: DescribeSecurityConfigurationInput,
+    ) -> Result<DescribeSecurityConfigurationOutput, RusotoError<DescribeSecurityConfigurationError>>
+    {
+        self.describe_security_configuration_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Provides more detail about the cluster step.</p>
+    async fn describe_step(
+        &self,
+        input: DescribeStepInput,
+    ) -> Result<DescribeStepOutput, RusotoError<DescribeStepError>> {
+        self.describe_step_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns the Amazon EMR block public access configuration for your AWS account in the current Region. For more information see <a href="https://docs.aws.amazon.com/emr/latest/ManagementGuide/configure-block-public-access.html">Configure Block Public Access for Amazon EMR</a> in the <i>Amazon EMR Management Guide</i>.</p>
+    async fn get_block_public_access_configuration(
+        &self,
+    ) -> Result<
+        GetBlockPublicAccessConfigurationOutput,
+        RusotoError<GetBlockPublicAccessConfigurationError>,
+    > {
+        self.get_block_public_access_configuration_with_metadata()
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Provides information about the bootstrap actions associated with a cluster.</p>
+    async fn list_bootstrap_actions(
+        &self,
+        input: ListBootstrapActionsInput,
+    ) -> Result<ListBootstrapActionsOutput, RusotoError<ListBootstrapActionsError>> {
+        self.list_bootstrap_actions_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Provides the status of all clusters visible to this AWS account. Allows you to filter the list of clusters based on certain criteria; for example, filtering by cluster creation date and time or by status. This call returns a maximum of 50 clusters per call, but returns a marker to track the paging of the cluster list across multiple ListClusters calls.</p>
+    async fn list_clusters(
+        &self,
+        input: ListClustersInput,
+    ) -> Result<ListClustersOutput, RusotoError<ListClustersError>> {
+        self.list_clusters_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Lists all available details about the instance fleets in a cluster.</p> <note> <p>The instance fleet configuration is available only in Amazon EMR versions 4.8.0 and later, excluding 5.0.x versions.</p> </note></p>
+    async fn list_instance_fleets(
+        &self,
+        input: ListInstanceFleetsInput,
+    ) -> Result<ListInstanceFleetsOutput, RusotoError<ListInstanceFleetsError>> {
+        self.list_instance_fleets_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Provides all available details about the instance groups in a cluster.</p>
+    async fn list_instance_groups(
+        &self,
+        input: ListInstanceGroupsInput,
+    ) -> Result<ListInstanceGroupsOutput, RusotoError<ListInstanceGroupsError>> {
+        self.list_instance_groups_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Provides information for all active EC2 instances and EC2 instances terminated in the last 30 days, up to a maximum of 2,000. EC2 instances in any of the following states are considered active: AWAITING_FULFILLMENT, PROVISIONING, BOOTSTRAPPING, RUNNING.</p>
+    async fn list_instances(
+        &self,
+        input: ListInstancesInput,
+    ) -> Result<ListInstancesOutput, RusotoError<ListInstancesError>> {
+        self.list_instances_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Lists all the security configurations visible to this account, providing their creation dates and times, and their names. This call returns a maximum of 50 clusters per call, but returns a marker to track the paging of the cluster list across multiple ListSecurityConfigurations calls.</p>
+    async fn list_security_configurations(
+        &self,
+        input: ListSecurityConfigurationsInput,
+    ) -> Result<ListSecurityConfigurationsOutput, RusotoError<ListSecurityConfigurationsError>>
+    {
+        self.list_security_configurations_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Provides a list of steps for the cluster in reverse order unless you specify <code>stepIds</code> with the request of filter by <code>StepStates</code>. You can specify a maximum of ten <code>stepIDs</code>.</p>
+    async fn list_steps(
+        &self,
+        input: ListStepsInput,
+    ) -> Result<ListStepsOutput, RusotoError<ListStepsError>> {
+        self.list_steps_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Modifies the number of steps that can be executed concurrently for the cluster specified using ClusterID.</p>
+    async fn modify_cluster(
+        &self,
+        input: ModifyClusterInput,
+    ) -> Result<ModifyClusterOutput, RusotoError<ModifyClusterError>> {
+        self.modify_cluster_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Modifies the target On-Demand and target Spot capacities for the instance fleet with the specified InstanceFleetID within the cluster specified using ClusterID. The call either succeeds or fails atomically.</p> <note> <p>The instance fleet configuration is available only in Amazon EMR versions 4.8.0 and later, excluding 5.0.x versions.</p> </note></p>
+    async fn modify_instance_fleet(
+        &self,
+        input: ModifyInstanceFleetInput,
+    ) -> Result<(), RusotoError<ModifyInstanceFleetError>> {
+        self.modify_instance_fleet_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>ModifyInstanceGroups modifies the number of nodes and configuration settings of an instance group. The input parameters include the new target instance count for the group and the instance group ID. The call will either succeed or fail atomically.</p>
+    async fn modify_instance_groups(
+        &self,
+        input: ModifyInstanceGroupsInput,
+    ) -> Result<(), RusotoError<ModifyInstanceGroupsError>> {
+        self.modify_instance_groups_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Creates or updates an automatic scaling policy for a core instance group or task instance group in an Amazon EMR cluster. The automatic scaling policy defines how an instance group dynamically adds and terminates EC2 instances in response to the value of a CloudWatch metric.</p>
+    async fn put_auto_scaling_policy(
+        &self,
+        input: PutAutoScalingPolicyInput,
+    ) -> Result<PutAutoScalingPolicyOutput, RusotoError<PutAutoScalingPolicyError>> {
+        self.put_auto_scaling_policy_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Creates or updates an Amazon EMR block public access configuration for your AWS account in the current Region. For more information see <a href="https://docs.aws.amazon.com/emr/latest/ManagementGuide/configure-block-public-access.html">Configure Block Public Access for Amazon EMR</a> in the <i>Amazon EMR Management Guide</i>.</p>
+    async fn put_block_public_access_configuration(
+        &self,
+        input: PutBlockPublicAccessConfigurationInput,
+    ) -> Result<
+        PutBlockPublicAccessConfigurationOutput,
+        RusotoError<PutBlockPublicAccessConfigurationError>,
+    > {
+        self.put_block_public_access_configuration_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Removes an automatic scaling policy from a specified instance group within an EMR cluster.</p>
+    async fn remove_auto_scaling_policy(
+        &self,
+        input: RemoveAutoScalingPolicyInput,
+    ) -> Result<RemoveAutoScalingPolicyOutput, RusotoError<RemoveAutoScalingPolicyError>> {
+        self.remove_auto_scaling_policy_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Removes tags from an Amazon EMR resource. Tags make it easier to associate clusters in various ways, such as grouping clusters to track your Amazon EMR resource allocation costs. For more information, see <a href="https://docs.aws.amazon.com/emr/latest/ManagementGuide/emr-plan-tags.html">Tag Clusters</a>. </p> <p>The following example removes the stack tag with value Prod from a cluster:</p>
+    async fn remove_tags(
+        &self,
+        input: RemoveTagsInput,
+    ) -> Result<RemoveTagsOutput, RusotoError<RemoveTagsError>> {
+        self.remove_tags_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>RunJobFlow creates and starts running a new cluster (job flow). The cluster runs the steps specified. After the steps complete, the cluster stops and the HDFS partition is lost. To prevent loss of data, configure the last step of the job flow to store results in Amazon S3. If the <a>JobFlowInstancesConfig</a> <code>KeepJobFlowAliveWhenNoSteps</code> parameter is set to <code>TRUE</code>, the cluster transitions to the WAITING state rather than shutting down after the steps have completed. </p> <p>For additional protection, you can set the <a>JobFlowInstancesConfig</a> <code>TerminationProtected</code> parameter to <code>TRUE</code> to lock the cluster and prevent it from being terminated by API call, user intervention, or in the event of a job flow error.</p> <p>A maximum of 256 steps are allowed in each job flow.</p> <p>If your cluster is long-running (such as a Hive data warehouse) or complex, you may require more than 256 steps to process your data. You can bypass the 256-step limitation in various ways, including using the SSH shell to connect to the master node and submitting queries directly to the software running on the master node, such as Hive and Hadoop. For more information on how to do this, see <a href="https://docs.aws.amazon.com/emr/latest/ManagementGuide/AddMoreThan256Steps.html">Add More than 256 Steps to a Cluster</a> in the <i>Amazon EMR Management Guide</i>.</p> <p>For long running clusters, we recommend that you periodically store your results.</p> <note> <p>The instance fleets configuration is available only in Amazon EMR versions 4.8.0 and later, excluding 5.0.x versions. The RunJobFlow request can contain InstanceFleets parameters or InstanceGroups parameters, but not both.</p> </note></p>
+    async fn run_job_flow(
+        &self,
+        input: RunJobFlowInput,
+    ) -> Result<RunJobFlowOutput, RusotoError<RunJobFlowError>> {
+        self.run_job_flow_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>SetTerminationProtection locks a cluster (job flow) so the EC2 instances in the cluster cannot be terminated by user intervention, an API call, or in the event of a job-flow error. The cluster still terminates upon successful completion of the job flow. Calling <code>SetTerminationProtection</code> on a cluster is similar to calling the Amazon EC2 <code>DisableAPITermination</code> API on all EC2 instances in a cluster.</p> <p> <code>SetTerminationProtection</code> is used to prevent accidental termination of a cluster and to ensure that in the event of an error, the instances persist so that you can recover any data stored in their ephemeral instance storage.</p> <p> To terminate a cluster that has been locked by setting <code>SetTerminationProtection</code> to <code>true</code>, you must first unlock the job flow by a subsequent call to <code>SetTerminationProtection</code> in which you set the value to <code>false</code>. </p> <p> For more information, see<a href="https://docs.aws.amazon.com/emr/latest/ManagementGuide/UsingEMR_TerminationProtection.html">Managing Cluster Termination</a> in the <i>Amazon EMR Management Guide</i>. </p>
+    async fn set_termination_protection(
+        &self,
+        input: SetTerminationProtectionInput,
+    ) -> Result<(), RusotoError<SetTerminationProtectionError>> {
+        self.set_termination_protection_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Sets the <a>Cluster$VisibleToAllUsers</a> value, which determines whether the cluster is visible to all IAM users of the AWS account associated with the cluster. Only the IAM user who created the cluster or the AWS account root user can call this action. The default value, <code>true</code>, indicates that all IAM users in the AWS account can perform cluster actions if they have the proper IAM policy permissions. If set to <code>false</code>, only the IAM user that created the cluster can perform actions. This action works on running clusters. You can override the default <code>true</code> setting when you create a cluster by using the <code>VisibleToAllUsers</code> parameter with <code>RunJobFlow</code>.</p>
+    async fn set_visible_to_all_users(
+        &self,
+        input: SetVisibleToAllUsersInput,
+    ) -> Result<(), RusotoError<SetVisibleToAllUsersError>> {
+        self.set_visible_to_all_users_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>TerminateJobFlows shuts a list of clusters (job flows) down. When a job flow is shut down, any step not yet completed is canceled and the EC2 instances on which the cluster is running are stopped. Any log files not already saved are uploaded to Amazon S3 if a LogUri was specified when the cluster was created.</p> <p>The maximum number of clusters allowed is 10. The call to <code>TerminateJobFlows</code> is asynchronous. Depending on the configuration of the cluster, it may take up to 1-5 minutes for the cluster to completely terminate and release allocated resources, such as Amazon EC2 instances.</p>
+    async fn terminate_job_flows(
+        &self,
+        input: TerminateJobFlowsInput,
+    ) -> Result<(), RusotoError<TerminateJobFlowsError>> {
+        self.terminate_job_flows_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
     }
 }
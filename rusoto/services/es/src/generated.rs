@@ -16,8 +16,8 @@ use std::fmt;
 use async_trait::async_trait;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
-use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest, ResponseMetadata};
+use rusoto_core::{Client, ErrorMetadata, RusotoError};
 
 use rusoto_core::param::{Params, ServiceParams};
 use rusoto_core::proto;
@@ -1399,6 +1399,7 @@ pub struct ZoneAwarenessConfig {
 
 /// Errors returned by AddTags
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum AddTagsError {
     /// <p>An error occurred while processing the request.</p>
     Base(String),
@@ -1406,21 +1407,23 @@ pub enum AddTagsError {
     Internal(String),
     /// <p>An exception for trying to create more than allowed resources or sub-resources. Gives http status code of 409.</p>
     LimitExceeded(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl AddTagsError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<AddTagsError> {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
-                "BaseException" => return RusotoError::Service(AddTagsError::Base(err.msg)),
+                "BaseException" => return RusotoError::service(AddTagsError::Base(err.msg), &res),
                 "InternalException" => {
-                    return RusotoError::Service(AddTagsError::Internal(err.msg))
+                    return RusotoError::service(AddTagsError::Internal(err.msg), &res)
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(AddTagsError::LimitExceeded(err.msg))
+                    return RusotoError::service(AddTagsError::LimitExceeded(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(AddTagsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1433,12 +1436,14 @@ impl fmt::Display for AddTagsError {
             AddTagsError::Base(ref cause) => write!(f, "{}", cause),
             AddTagsError::Internal(ref cause) => write!(f, "{}", cause),
             AddTagsError::LimitExceeded(ref cause) => write!(f, "{}", cause),
+            AddTagsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for AddTagsError {}
 /// Errors returned by CancelElasticsearchServiceSoftwareUpdate
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CancelElasticsearchServiceSoftwareUpdateError {
     /// <p>An error occurred while processing the request.</p>
     Base(String),
@@ -1446,6 +1451,8 @@ pub enum CancelElasticsearchServiceSoftwareUpdateError {
     Internal(String),
     /// <p>An exception for accessing or deleting a resource that does not exist. Gives http status code of 400.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CancelElasticsearchServiceSoftwareUpdateError {
@@ -1455,22 +1462,30 @@ impl CancelElasticsearchServiceSoftwareUpdateError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BaseException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         CancelElasticsearchServiceSoftwareUpdateError::Base(err.msg),
+                        &res,
                     )
                 }
                 "InternalException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         CancelElasticsearchServiceSoftwareUpdateError::Internal(err.msg),
+                        &res,
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         CancelElasticsearchServiceSoftwareUpdateError::ResourceNotFound(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        CancelElasticsearchServiceSoftwareUpdateError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -1489,12 +1504,16 @@ impl fmt::Display for CancelElasticsearchServiceSoftwareUpdateError {
             CancelElasticsearchServiceSoftwareUpdateError::ResourceNotFound(ref cause) => {
                 write!(f, "{}", cause)
             }
+            CancelElasticsearchServiceSoftwareUpdateError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for CancelElasticsearchServiceSoftwareUpdateError {}
 /// Errors returned by CreateElasticsearchDomain
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateElasticsearchDomainError {
     /// <p>An error occurred while processing the request.</p>
     Base(String),
@@ -1508,6 +1527,8 @@ pub enum CreateElasticsearchDomainError {
     LimitExceeded(String),
     /// <p>An exception for creating a resource that already exists. Gives http status code of 400.</p>
     ResourceAlreadyExists(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateElasticsearchDomainError {
@@ -1515,33 +1536,45 @@ impl CreateElasticsearchDomainError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BaseException" => {
-                    return RusotoError::Service(CreateElasticsearchDomainError::Base(err.msg))
+                    return RusotoError::service(
+                        CreateElasticsearchDomainError::Base(err.msg),
+                        &res,
+                    )
                 }
                 "DisabledOperationException" => {
-                    return RusotoError::Service(CreateElasticsearchDomainError::DisabledOperation(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateElasticsearchDomainError::DisabledOperation(err.msg),
+                        &res,
+                    )
                 }
                 "InternalException" => {
-                    return RusotoError::Service(CreateElasticsearchDomainError::Internal(err.msg))
+                    return RusotoError::service(
+                        CreateElasticsearchDomainError::Internal(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidTypeException" => {
-                    return RusotoError::Service(CreateElasticsearchDomainError::InvalidType(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateElasticsearchDomainError::InvalidType(err.msg),
+                        &res,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateElasticsearchDomainError::LimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateElasticsearchDomainError::LimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceAlreadyExistsException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         CreateElasticsearchDomainError::ResourceAlreadyExists(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(CreateElasticsearchDomainError::Unhandled, &res)
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -1559,12 +1592,14 @@ impl fmt::Display for CreateElasticsearchDomainError {
             CreateElasticsearchDomainError::ResourceAlreadyExists(ref cause) => {
                 write!(f, "{}", cause)
             }
+            CreateElasticsearchDomainError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateElasticsearchDomainError {}
 /// Errors returned by DeleteElasticsearchDomain
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteElasticsearchDomainError {
     /// <p>An error occurred while processing the request.</p>
     Base(String),
@@ -1572,6 +1607,8 @@ pub enum DeleteElasticsearchDomainError {
     Internal(String),
     /// <p>An exception for accessing or deleting a resource that does not exist. Gives http status code of 400.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteElasticsearchDomainError {
@@ -1579,18 +1616,27 @@ impl DeleteElasticsearchDomainError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BaseException" => {
-                    return RusotoError::Service(DeleteElasticsearchDomainError::Base(err.msg))
+                    return RusotoError::service(
+                        DeleteElasticsearchDomainError::Base(err.msg),
+                        &res,
+                    )
                 }
                 "InternalException" => {
-                    return RusotoError::Service(DeleteElasticsearchDomainError::Internal(err.msg))
+                    return RusotoError::service(
+                        DeleteElasticsearchDomainError::Internal(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteElasticsearchDomainError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteElasticsearchDomainError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(DeleteElasticsearchDomainError::Unhandled, &res)
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -1603,17 +1649,21 @@ impl fmt::Display for DeleteElasticsearchDomainError {
             DeleteElasticsearchDomainError::Base(ref cause) => write!(f, "{}", cause),
             DeleteElasticsearchDomainError::Internal(ref cause) => write!(f, "{}", cause),
             DeleteElasticsearchDomainError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            DeleteElasticsearchDomainError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteElasticsearchDomainError {}
 /// Errors returned by DeleteElasticsearchServiceRole
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteElasticsearchServiceRoleError {
     /// <p>An error occurred while processing the request.</p>
     Base(String),
     /// <p>The request processing has failed because of an unknown error, exception or failure (the failure is internal to the service) . Gives http status code of 500.</p>
     Internal(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteElasticsearchServiceRoleError {
@@ -1623,15 +1673,24 @@ impl DeleteElasticsearchServiceRoleError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BaseException" => {
-                    return RusotoError::Service(DeleteElasticsearchServiceRoleError::Base(err.msg))
+                    return RusotoError::service(
+                        DeleteElasticsearchServiceRoleError::Base(err.msg),
+                        &res,
+                    )
                 }
                 "InternalException" => {
-                    return RusotoError::Service(DeleteElasticsearchServiceRoleError::Internal(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteElasticsearchServiceRoleError::Internal(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        DeleteElasticsearchServiceRoleError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -1643,12 +1702,16 @@ impl fmt::Display for DeleteElasticsearchServiceRoleError {
         match *self {
             DeleteElasticsearchServiceRoleError::Base(ref cause) => write!(f, "{}", cause),
             DeleteElasticsearchServiceRoleError::Internal(ref cause) => write!(f, "{}", cause),
+            DeleteElasticsearchServiceRoleError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for DeleteElasticsearchServiceRoleError {}
 /// Errors returned by DescribeElasticsearchDomain
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeElasticsearchDomainError {
     /// <p>An error occurred while processing the request.</p>
     Base(String),
@@ -1656,6 +1719,8 @@ pub enum DescribeElasticsearchDomainError {
     Internal(String),
     /// <p>An exception for accessing or deleting a resource that does not exist. Gives http status code of 400.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeElasticsearchDomainError {
@@ -1665,20 +1730,30 @@ impl DescribeElasticsearchDomainError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BaseException" => {
-                    return RusotoError::Service(DescribeElasticsearchDomainError::Base(err.msg))
+                    return RusotoError::service(
+                        DescribeElasticsearchDomainError::Base(err.msg),
+                        &res,
+                    )
                 }
                 "InternalException" => {
-                    return RusotoError::Service(DescribeElasticsearchDomainError::Internal(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeElasticsearchDomainError::Internal(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DescribeElasticsearchDomainError::ResourceNotFound(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        DescribeElasticsearchDomainError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -1691,12 +1766,14 @@ impl fmt::Display for DescribeElasticsearchDomainError {
             DescribeElasticsearchDomainError::Base(ref cause) => write!(f, "{}", cause),
             DescribeElasticsearchDomainError::Internal(ref cause) => write!(f, "{}", cause),
             DescribeElasticsearchDomainError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            DescribeElasticsearchDomainError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeElasticsearchDomainError {}
 /// Errors returned by DescribeElasticsearchDomainConfig
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeElasticsearchDomainConfigError {
     /// <p>An error occurred while processing the request.</p>
     Base(String),
@@ -1704,6 +1781,8 @@ pub enum DescribeElasticsearchDomainConfigError {
     Internal(String),
     /// <p>An exception for accessing or deleting a resource that does not exist. Gives http status code of 400.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeElasticsearchDomainConfigError {
@@ -1713,22 +1792,30 @@ impl DescribeElasticsearchDomainConfigError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BaseException" => {
-                    return RusotoError::Service(DescribeElasticsearchDomainConfigError::Base(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeElasticsearchDomainConfigError::Base(err.msg),
+                        &res,
+                    )
                 }
                 "InternalException" => {
-                    return RusotoError::Service(DescribeElasticsearchDomainConfigError::Internal(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeElasticsearchDomainConfigError::Internal(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DescribeElasticsearchDomainConfigError::ResourceNotFound(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        DescribeElasticsearchDomainConfigError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -1743,17 +1830,23 @@ impl fmt::Display for DescribeElasticsearchDomainConfigError {
             DescribeElasticsearchDomainConfigError::ResourceNotFound(ref cause) => {
                 write!(f, "{}", cause)
             }
+            DescribeElasticsearchDomainConfigError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for DescribeElasticsearchDomainConfigError {}
 /// Errors returned by DescribeElasticsearchDomains
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeElasticsearchDomainsError {
     /// <p>An error occurred while processing the request.</p>
     Base(String),
     /// <p>The request processing has failed because of an unknown error, exception or failure (the failure is internal to the service) . Gives http status code of 500.</p>
     Internal(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeElasticsearchDomainsError {
@@ -1763,15 +1856,24 @@ impl DescribeElasticsearchDomainsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BaseException" => {
-                    return RusotoError::Service(DescribeElasticsearchDomainsError::Base(err.msg))
+                    return RusotoError::service(
+                        DescribeElasticsearchDomainsError::Base(err.msg),
+                        &res,
+                    )
                 }
                 "InternalException" => {
-                    return RusotoError::Service(DescribeElasticsearchDomainsError::Internal(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeElasticsearchDomainsError::Internal(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        DescribeElasticsearchDomainsError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -1783,12 +1885,14 @@ impl fmt::Display for DescribeElasticsearchDomainsError {
         match *self {
             DescribeElasticsearchDomainsError::Base(ref cause) => write!(f, "{}", cause),
             DescribeElasticsearchDomainsError::Internal(ref cause) => write!(f, "{}", cause),
+            DescribeElasticsearchDomainsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeElasticsearchDomainsError {}
 /// Errors returned by DescribeElasticsearchInstanceTypeLimits
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeElasticsearchInstanceTypeLimitsError {
     /// <p>An error occurred while processing the request.</p>
     Base(String),
@@ -1800,6 +1904,8 @@ pub enum DescribeElasticsearchInstanceTypeLimitsError {
     LimitExceeded(String),
     /// <p>An exception for accessing or deleting a resource that does not exist. Gives http status code of 400.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeElasticsearchInstanceTypeLimitsError {
@@ -1809,32 +1915,42 @@ impl DescribeElasticsearchInstanceTypeLimitsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BaseException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DescribeElasticsearchInstanceTypeLimitsError::Base(err.msg),
+                        &res,
                     )
                 }
                 "InternalException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DescribeElasticsearchInstanceTypeLimitsError::Internal(err.msg),
+                        &res,
                     )
                 }
                 "InvalidTypeException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DescribeElasticsearchInstanceTypeLimitsError::InvalidType(err.msg),
+                        &res,
                     )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DescribeElasticsearchInstanceTypeLimitsError::LimitExceeded(err.msg),
+                        &res,
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DescribeElasticsearchInstanceTypeLimitsError::ResourceNotFound(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        DescribeElasticsearchInstanceTypeLimitsError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -1857,12 +1973,16 @@ impl fmt::Display for DescribeElasticsearchInstanceTypeLimitsError {
             DescribeElasticsearchInstanceTypeLimitsError::ResourceNotFound(ref cause) => {
                 write!(f, "{}", cause)
             }
+            DescribeElasticsearchInstanceTypeLimitsError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for DescribeElasticsearchInstanceTypeLimitsError {}
 /// Errors returned by DescribeReservedElasticsearchInstanceOfferings
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeReservedElasticsearchInstanceOfferingsError {
     /// <p>An error occured because the client wanted to access a not supported operation. Gives http status code of 409.</p>
     DisabledOperation(String),
@@ -1870,6 +1990,8 @@ pub enum DescribeReservedElasticsearchInstanceOfferingsError {
     Internal(String),
     /// <p>An exception for accessing or deleting a resource that does not exist. Gives http status code of 400.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeReservedElasticsearchInstanceOfferingsError {
@@ -1879,26 +2001,34 @@ impl DescribeReservedElasticsearchInstanceOfferingsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "DisabledOperationException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DescribeReservedElasticsearchInstanceOfferingsError::DisabledOperation(
                             err.msg,
                         ),
+                        &res,
                     )
                 }
                 "InternalException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DescribeReservedElasticsearchInstanceOfferingsError::Internal(err.msg),
+                        &res,
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DescribeReservedElasticsearchInstanceOfferingsError::ResourceNotFound(
                             err.msg,
                         ),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        DescribeReservedElasticsearchInstanceOfferingsError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -1917,12 +2047,16 @@ impl fmt::Display for DescribeReservedElasticsearchInstanceOfferingsError {
             DescribeReservedElasticsearchInstanceOfferingsError::ResourceNotFound(ref cause) => {
                 write!(f, "{}", cause)
             }
+            DescribeReservedElasticsearchInstanceOfferingsError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for DescribeReservedElasticsearchInstanceOfferingsError {}
 /// Errors returned by DescribeReservedElasticsearchInstances
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeReservedElasticsearchInstancesError {
     /// <p>An error occured because the client wanted to access a not supported operation. Gives http status code of 409.</p>
     DisabledOperation(String),
@@ -1930,6 +2064,8 @@ pub enum DescribeReservedElasticsearchInstancesError {
     Internal(String),
     /// <p>An exception for accessing or deleting a resource that does not exist. Gives http status code of 400.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeReservedElasticsearchInstancesError {
@@ -1939,22 +2075,30 @@ impl DescribeReservedElasticsearchInstancesError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "DisabledOperationException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DescribeReservedElasticsearchInstancesError::DisabledOperation(err.msg),
+                        &res,
                     )
                 }
                 "InternalException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DescribeReservedElasticsearchInstancesError::Internal(err.msg),
+                        &res,
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DescribeReservedElasticsearchInstancesError::ResourceNotFound(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        DescribeReservedElasticsearchInstancesError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -1973,12 +2117,16 @@ impl fmt::Display for DescribeReservedElasticsearchInstancesError {
             DescribeReservedElasticsearchInstancesError::ResourceNotFound(ref cause) => {
                 write!(f, "{}", cause)
             }
+            DescribeReservedElasticsearchInstancesError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for DescribeReservedElasticsearchInstancesError {}
 /// Errors returned by GetCompatibleElasticsearchVersions
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetCompatibleElasticsearchVersionsError {
     /// <p>An error occurred while processing the request.</p>
     Base(String),
@@ -1988,6 +2136,8 @@ pub enum GetCompatibleElasticsearchVersionsError {
     Internal(String),
     /// <p>An exception for accessing or deleting a resource that does not exist. Gives http status code of 400.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetCompatibleElasticsearchVersionsError {
@@ -1997,27 +2147,36 @@ impl GetCompatibleElasticsearchVersionsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BaseException" => {
-                    return RusotoError::Service(GetCompatibleElasticsearchVersionsError::Base(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetCompatibleElasticsearchVersionsError::Base(err.msg),
+                        &res,
+                    )
                 }
                 "DisabledOperationException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         GetCompatibleElasticsearchVersionsError::DisabledOperation(err.msg),
+                        &res,
                     )
                 }
                 "InternalException" => {
-                    return RusotoError::Service(GetCompatibleElasticsearchVersionsError::Internal(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetCompatibleElasticsearchVersionsError::Internal(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         GetCompatibleElasticsearchVersionsError::ResourceNotFound(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        GetCompatibleElasticsearchVersionsError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -2035,12 +2194,16 @@ impl fmt::Display for GetCompatibleElasticsearchVersionsError {
             GetCompatibleElasticsearchVersionsError::ResourceNotFound(ref cause) => {
                 write!(f, "{}", cause)
             }
+            GetCompatibleElasticsearchVersionsError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for GetCompatibleElasticsearchVersionsError {}
 /// Errors returned by GetUpgradeHistory
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetUpgradeHistoryError {
     /// <p>An error occurred while processing the request.</p>
     Base(String),
@@ -2050,6 +2213,8 @@ pub enum GetUpgradeHistoryError {
     Internal(String),
     /// <p>An exception for accessing or deleting a resource that does not exist. Gives http status code of 400.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetUpgradeHistoryError {
@@ -2057,19 +2222,25 @@ impl GetUpgradeHistoryError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BaseException" => {
-                    return RusotoError::Service(GetUpgradeHistoryError::Base(err.msg))
+                    return RusotoError::service(GetUpgradeHistoryError::Base(err.msg), &res)
                 }
                 "DisabledOperationException" => {
-                    return RusotoError::Service(GetUpgradeHistoryError::DisabledOperation(err.msg))
+                    return RusotoError::service(
+                        GetUpgradeHistoryError::DisabledOperation(err.msg),
+                        &res,
+                    )
                 }
                 "InternalException" => {
-                    return RusotoError::Service(GetUpgradeHistoryError::Internal(err.msg))
+                    return RusotoError::service(GetUpgradeHistoryError::Internal(err.msg), &res)
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetUpgradeHistoryError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        GetUpgradeHistoryError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetUpgradeHistoryError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2083,12 +2254,14 @@ impl fmt::Display for GetUpgradeHistoryError {
             GetUpgradeHistoryError::DisabledOperation(ref cause) => write!(f, "{}", cause),
             GetUpgradeHistoryError::Internal(ref cause) => write!(f, "{}", cause),
             GetUpgradeHistoryError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            GetUpgradeHistoryError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetUpgradeHistoryError {}
 /// Errors returned by GetUpgradeStatus
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetUpgradeStatusError {
     /// <p>An error occurred while processing the request.</p>
     Base(String),
@@ -2098,6 +2271,8 @@ pub enum GetUpgradeStatusError {
     Internal(String),
     /// <p>An exception for accessing or deleting a resource that does not exist. Gives http status code of 400.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetUpgradeStatusError {
@@ -2105,19 +2280,25 @@ impl GetUpgradeStatusError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BaseException" => {
-                    return RusotoError::Service(GetUpgradeStatusError::Base(err.msg))
+                    return RusotoError::service(GetUpgradeStatusError::Base(err.msg), &res)
                 }
                 "DisabledOperationException" => {
-                    return RusotoError::Service(GetUpgradeStatusError::DisabledOperation(err.msg))
+                    return RusotoError::service(
+                        GetUpgradeStatusError::DisabledOperation(err.msg),
+                        &res,
+                    )
                 }
                 "InternalException" => {
-                    return RusotoError::Service(GetUpgradeStatusError::Internal(err.msg))
+                    return RusotoError::service(GetUpgradeStatusError::Internal(err.msg), &res)
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetUpgradeStatusError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        GetUpgradeStatusError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetUpgradeStatusError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2131,15 +2312,19 @@ impl fmt::Display for GetUpgradeStatusError {
             GetUpgradeStatusError::DisabledOperation(ref cause) => write!(f, "{}", cause),
             GetUpgradeStatusError::Internal(ref cause) => write!(f, "{}", cause),
             GetUpgradeStatusError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            GetUpgradeStatusError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetUpgradeStatusError {}
 /// Errors returned by ListDomainNames
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListDomainNamesError {
     /// <p>An error occurred while processing the request.</p>
     Base(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListDomainNamesError {
@@ -2147,10 +2332,10 @@ impl ListDomainNamesError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BaseException" => {
-                    return RusotoError::Service(ListDomainNamesError::Base(err.msg))
+                    return RusotoError::service(ListDomainNamesError::Base(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListDomainNamesError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2161,12 +2346,14 @@ impl fmt::Display for ListDomainNamesError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             ListDomainNamesError::Base(ref cause) => write!(f, "{}", cause),
+            ListDomainNamesError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListDomainNamesError {}
 /// Errors returned by ListElasticsearchInstanceTypes
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListElasticsearchInstanceTypesError {
     /// <p>An error occurred while processing the request.</p>
     Base(String),
@@ -2174,6 +2361,8 @@ pub enum ListElasticsearchInstanceTypesError {
     Internal(String),
     /// <p>An exception for accessing or deleting a resource that does not exist. Gives http status code of 400.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListElasticsearchInstanceTypesError {
@@ -2183,20 +2372,30 @@ impl ListElasticsearchInstanceTypesError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BaseException" => {
-                    return RusotoError::Service(ListElasticsearchInstanceTypesError::Base(err.msg))
+                    return RusotoError::service(
+                        ListElasticsearchInstanceTypesError::Base(err.msg),
+                        &res,
+                    )
                 }
                 "InternalException" => {
-                    return RusotoError::Service(ListElasticsearchInstanceTypesError::Internal(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListElasticsearchInstanceTypesError::Internal(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         ListElasticsearchInstanceTypesError::ResourceNotFound(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        ListElasticsearchInstanceTypesError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -2211,12 +2410,16 @@ impl fmt::Display for ListElasticsearchInstanceTypesError {
             ListElasticsearchInstanceTypesError::ResourceNotFound(ref cause) => {
                 write!(f, "{}", cause)
             }
+            ListElasticsearchInstanceTypesError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for ListElasticsearchInstanceTypesError {}
 /// Errors returned by ListElasticsearchVersions
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListElasticsearchVersionsError {
     /// <p>An error occurred while processing the request.</p>
     Base(String),
@@ -2224,6 +2427,8 @@ pub enum ListElasticsearchVersionsError {
     Internal(String),
     /// <p>An exception for accessing or deleting a resource that does not exist. Gives http status code of 400.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListElasticsearchVersionsError {
@@ -2231,18 +2436,27 @@ impl ListElasticsearchVersionsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BaseException" => {
-                    return RusotoError::Service(ListElasticsearchVersionsError::Base(err.msg))
+                    return RusotoError::service(
+                        ListElasticsearchVersionsError::Base(err.msg),
+                        &res,
+                    )
                 }
                 "InternalException" => {
-                    return RusotoError::Service(ListElasticsearchVersionsError::Internal(err.msg))
+                    return RusotoError::service(
+                        ListElasticsearchVersionsError::Internal(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListElasticsearchVersionsError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListElasticsearchVersionsError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(ListElasticsearchVersionsError::Unhandled, &res)
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -2255,12 +2469,14 @@ impl fmt::Display for ListElasticsearchVersionsError {
             ListElasticsearchVersionsError::Base(ref cause) => write!(f, "{}", cause),
             ListElasticsearchVersionsError::Internal(ref cause) => write!(f, "{}", cause),
             ListElasticsearchVersionsError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            ListElasticsearchVersionsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListElasticsearchVersionsError {}
 /// Errors returned by ListTags
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListTagsError {
     /// <p>An error occurred while processing the request.</p>
     Base(String),
@@ -2268,21 +2484,23 @@ pub enum ListTagsError {
     Internal(String),
     /// <p>An exception for accessing or deleting a resource that does not exist. Gives http status code of 400.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListTagsError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<ListTagsError> {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
-                "BaseException" => return RusotoError::Service(ListTagsError::Base(err.msg)),
+                "BaseException" => return RusotoError::service(ListTagsError::Base(err.msg), &res),
                 "InternalException" => {
-                    return RusotoError::Service(ListTagsError::Internal(err.msg))
+                    return RusotoError::service(ListTagsError::Internal(err.msg), &res)
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListTagsError::ResourceNotFound(err.msg))
+                    return RusotoError::service(ListTagsError::ResourceNotFound(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListTagsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2295,12 +2513,14 @@ impl fmt::Display for ListTagsError {
             ListTagsError::Base(ref cause) => write!(f, "{}", cause),
             ListTagsError::Internal(ref cause) => write!(f, "{}", cause),
             ListTagsError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            ListTagsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListTagsError {}
 /// Errors returned by PurchaseReservedElasticsearchInstanceOffering
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum PurchaseReservedElasticsearchInstanceOfferingError {
     /// <p>An error occured because the client wanted to access a not supported operation. Gives http status code of 409.</p>
     DisabledOperation(String),
@@ -2312,6 +2532,8 @@ pub enum PurchaseReservedElasticsearchInstanceOfferingError {
     ResourceAlreadyExists(String),
     /// <p>An exception for accessing or deleting a resource that does not exist. Gives http status code of 400.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl PurchaseReservedElasticsearchInstanceOfferingError {
@@ -2321,38 +2543,48 @@ impl PurchaseReservedElasticsearchInstanceOfferingError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "DisabledOperationException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         PurchaseReservedElasticsearchInstanceOfferingError::DisabledOperation(
                             err.msg,
                         ),
+                        &res,
                     )
                 }
                 "InternalException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         PurchaseReservedElasticsearchInstanceOfferingError::Internal(err.msg),
+                        &res,
                     )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         PurchaseReservedElasticsearchInstanceOfferingError::LimitExceeded(err.msg),
+                        &res,
                     )
                 }
                 "ResourceAlreadyExistsException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         PurchaseReservedElasticsearchInstanceOfferingError::ResourceAlreadyExists(
                             err.msg,
                         ),
+                        &res,
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         PurchaseReservedElasticsearchInstanceOfferingError::ResourceNotFound(
                             err.msg,
                         ),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        PurchaseReservedElasticsearchInstanceOfferingError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -2377,29 +2609,37 @@ impl fmt::Display for PurchaseReservedElasticsearchInstanceOfferingError {
             PurchaseReservedElasticsearchInstanceOfferingError::ResourceNotFound(ref cause) => {
                 write!(f, "{}", cause)
             }
+            PurchaseReservedElasticsearchInstanceOfferingError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for PurchaseReservedElasticsearchInstanceOfferingError {}
 /// Errors returned by RemoveTags
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum RemoveTagsError {
     /// <p>An error occurred while processing the request.</p>
     Base(String),
     /// <p>The request processing has failed because of an unknown error, exception or failure (the failure is internal to the service) . Gives http status code of 500.</p>
     Internal(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl RemoveTagsError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<RemoveTagsError> {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
-                "BaseException" => return RusotoError::Service(RemoveTagsError::Base(err.msg)),
+                "BaseException" => {
+                    return RusotoError::service(RemoveTagsError::Base(err.msg), &res)
+                }
                 "InternalException" => {
-                    return RusotoError::Service(RemoveTagsError::Internal(err.msg))
+                    return RusotoError::service(RemoveTagsError::Internal(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(RemoveTagsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2411,12 +2651,14 @@ impl fmt::Display for RemoveTagsError {
         match *self {
             RemoveTagsError::Base(ref cause) => write!(f, "{}", cause),
             RemoveTagsError::Internal(ref cause) => write!(f, "{}", cause),
+            RemoveTagsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for RemoveTagsError {}
 /// Errors returned by StartElasticsearchServiceSoftwareUpdate
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum StartElasticsearchServiceSoftwareUpdateError {
     /// <p>An error occurred while processing the request.</p>
     Base(String),
@@ -2424,6 +2666,8 @@ pub enum StartElasticsearchServiceSoftwareUpdateError {
     Internal(String),
     /// <p>An exception for accessing or deleting a resource that does not exist. Gives http status code of 400.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl StartElasticsearchServiceSoftwareUpdateError {
@@ -2433,22 +2677,30 @@ impl StartElasticsearchServiceSoftwareUpdateError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BaseException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         StartElasticsearchServiceSoftwareUpdateError::Base(err.msg),
+                        &res,
                     )
                 }
                 "InternalException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         StartElasticsearchServiceSoftwareUpdateError::Internal(err.msg),
+                        &res,
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         StartElasticsearchServiceSoftwareUpdateError::ResourceNotFound(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        StartElasticsearchServiceSoftwareUpdateError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -2465,12 +2717,16 @@ impl fmt::Display for StartElasticsearchServiceSoftwareUpdateError {
             StartElasticsearchServiceSoftwareUpdateError::ResourceNotFound(ref cause) => {
                 write!(f, "{}", cause)
             }
+            StartElasticsearchServiceSoftwareUpdateError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for StartElasticsearchServiceSoftwareUpdateError {}
 /// Errors returned by UpdateElasticsearchDomainConfig
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdateElasticsearchDomainConfigError {
     /// <p>An error occurred while processing the request.</p>
     Base(String),
@@ -2482,6 +2738,8 @@ pub enum UpdateElasticsearchDomainConfigError {
     LimitExceeded(String),
     /// <p>An exception for accessing or deleting a resource that does not exist. Gives http status code of 400.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdateElasticsearchDomainConfigError {
@@ -2491,32 +2749,42 @@ impl UpdateElasticsearchDomainConfigError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BaseException" => {
-                    return RusotoError::Service(UpdateElasticsearchDomainConfigError::Base(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateElasticsearchDomainConfigError::Base(err.msg),
+                        &res,
+                    )
                 }
                 "InternalException" => {
-                    return RusotoError::Service(UpdateElasticsearchDomainConfigError::Internal(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateElasticsearchDomainConfigError::Internal(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidTypeException" => {
-                    return RusotoError::Service(UpdateElasticsearchDomainConfigError::InvalidType(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateElasticsearchDomainConfigError::InvalidType(err.msg),
+                        &res,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         UpdateElasticsearchDomainConfigError::LimitExceeded(err.msg),
+                        &res,
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         UpdateElasticsearchDomainConfigError::ResourceNotFound(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        UpdateElasticsearchDomainConfigError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -2535,12 +2803,16 @@ impl fmt::Display for UpdateElasticsearchDomainConfigError {
             UpdateElasticsearchDomainConfigError::ResourceNotFound(ref cause) => {
                 write!(f, "{}", cause)
             }
+            UpdateElasticsearchDomainConfigError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for UpdateElasticsearchDomainConfigError {}
 /// Errors returned by UpgradeElasticsearchDomain
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpgradeElasticsearchDomainError {
     /// <p>An error occurred while processing the request.</p>
     Base(String),
@@ -2552,6 +2824,8 @@ pub enum UpgradeElasticsearchDomainError {
     ResourceAlreadyExists(String),
     /// <p>An exception for accessing or deleting a resource that does not exist. Gives http status code of 400.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpgradeElasticsearchDomainError {
@@ -2561,28 +2835,39 @@ impl UpgradeElasticsearchDomainError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BaseException" => {
-                    return RusotoError::Service(UpgradeElasticsearchDomainError::Base(err.msg))
+                    return RusotoError::service(
+                        UpgradeElasticsearchDomainError::Base(err.msg),
+                        &res,
+                    )
                 }
                 "DisabledOperationException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         UpgradeElasticsearchDomainError::DisabledOperation(err.msg),
+                        &res,
                     )
                 }
                 "InternalException" => {
-                    return RusotoError::Service(UpgradeElasticsearchDomainError::Internal(err.msg))
+                    return RusotoError::service(
+                        UpgradeElasticsearchDomainError::Internal(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceAlreadyExistsException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         UpgradeElasticsearchDomainError::ResourceAlreadyExists(err.msg),
+                        &res,
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpgradeElasticsearchDomainError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpgradeElasticsearchDomainError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(UpgradeElasticsearchDomainError::Unhandled, &res)
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -2599,6 +2884,7 @@ impl fmt::Display for UpgradeElasticsearchDomainError {
                 write!(f, "{}", cause)
             }
             UpgradeElasticsearchDomainError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            UpgradeElasticsearchDomainError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
@@ -2780,6 +3066,7 @@ impl EsClient {
     /// Creates a client backed by the default tokio event loop.
     ///
     /// The client will use the default credentials provider and tls client.
+    #[cfg(not(target_arch = "wasm32"))]
     pub fn new(region: region::Region) -> EsClient {
         EsClient {
             client: Client::shared(),
@@ -2805,261 +3092,543 @@ impl EsClient {
     pub fn new_with_client(client: Client, region: region::Region) -> EsClient {
         EsClient { client, region }
     }
+
+    /// Returns a builder of a client, setting any of its region, credentials provider,
+    /// dispatcher, retry policy, endpoint and timeouts.
+    pub fn builder() -> rusoto_core::ClientBuilder<EsClient> {
+        rusoto_core::ClientBuilder::new(EsClient::new_with_client)
+    }
+
+    /// Returns a clone of this client with the overrides of `config` applied to its
+    /// operations, sharing its connections.
+    pub fn customize(&self, config: &rusoto_core::OperationConfig) -> EsClient {
+        EsClient {
+            client: self.client.customize(config),
+            region: config.resolve_region(&self.region),
+        }
+    }
 }
 
-#[async_trait]
-impl Es for EsClient {
-    /// <p>Attaches tags to an existing Elasticsearch domain. Tags are a set of case-sensitive key value pairs. An Elasticsearch domain may have up to 10 tags. See <a href="http://docs.aws.amazon.com/elasticsearch-service/latest/developerguide/es-managedomains.html#es-managedomains-awsresorcetagging" target="_blank"> Tagging Amazon Elasticsearch Service Domains for more information.</a></p>
-    async fn add_tags(&self, input: AddTagsRequest) -> Result<(), RusotoError<AddTagsError>> {
+impl EsClient {
+    /// Calls `add_tags`, also returning the metadata of the response.
+    pub async fn add_tags_with_metadata(
+        &self,
+        input: AddTagsRequest,
+    ) -> Result<((), ResponseMetadata), RusotoError<AddTagsError>> {
+        let request = self.build_add_tags_request(input);
+
+        let mut response = self
+            .client
+            .sign_and_dispatch(request)
+            .await
+            .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
+        if response.status.is_success() {
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = ::std::mem::drop(response);
+
+            Ok((result, metadata))
+        } else {
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(AddTagsError::from_response(response))
+        }
+    }
+
+    /// Presigns the request `add_tags` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_add_tags(
+        &self,
+        presigner: &rusoto_core::Presigner,
+        input: AddTagsRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_add_tags_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_add_tags_request(&self, input: AddTagsRequest) -> SignedRequest {
         let request_uri = "/2015-01-01/tags";
 
         let mut request = SignedRequest::new("POST", "es", &self.region, &request_uri);
+        request.set_operation("AddTags");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `cancel_elasticsearch_service_software_update`, also returning the metadata of the response.
+    pub async fn cancel_elasticsearch_service_software_update_with_metadata(
+        &self,
+        input: CancelElasticsearchServiceSoftwareUpdateRequest,
+    ) -> Result<
+        (
+            CancelElasticsearchServiceSoftwareUpdateResponse,
+            ResponseMetadata,
+        ),
+        RusotoError<CancelElasticsearchServiceSoftwareUpdateError>,
+    > {
+        let request = self.build_cancel_elasticsearch_service_software_update_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            let result = ::std::mem::drop(response);
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<CancelElasticsearchServiceSoftwareUpdateResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(AddTagsError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(CancelElasticsearchServiceSoftwareUpdateError::from_response(response))
         }
     }
 
-    /// <p>Cancels a scheduled service software update for an Amazon ES domain. You can only perform this operation before the <code>AutomatedUpdateDate</code> and when the <code>UpdateStatus</code> is in the <code>PENDING_UPDATE</code> state.</p>
-    async fn cancel_elasticsearch_service_software_update(
+    /// Presigns the request `cancel_elasticsearch_service_software_update` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_cancel_elasticsearch_service_software_update(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: CancelElasticsearchServiceSoftwareUpdateRequest,
-    ) -> Result<
-        CancelElasticsearchServiceSoftwareUpdateResponse,
-        RusotoError<CancelElasticsearchServiceSoftwareUpdateError>,
-    > {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_cancel_elasticsearch_service_software_update_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_cancel_elasticsearch_service_software_update_request(
+        &self,
+        input: CancelElasticsearchServiceSoftwareUpdateRequest,
+    ) -> SignedRequest {
         let request_uri = "/2015-01-01/es/serviceSoftwareUpdate/cancel";
 
         let mut request = SignedRequest::new("POST", "es", &self.region, &request_uri);
+        request.set_operation("CancelElasticsearchServiceSoftwareUpdate");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `create_elasticsearch_domain`, also returning the metadata of the response.
+    pub async fn create_elasticsearch_domain_with_metadata(
+        &self,
+        input: CreateElasticsearchDomainRequest,
+    ) -> Result<
+        (CreateElasticsearchDomainResponse, ResponseMetadata),
+        RusotoError<CreateElasticsearchDomainError>,
+    > {
+        let request = self.build_create_elasticsearch_domain_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<CancelElasticsearchServiceSoftwareUpdateResponse, _>()?;
+                .deserialize::<CreateElasticsearchDomainResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(CancelElasticsearchServiceSoftwareUpdateError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(CreateElasticsearchDomainError::from_response(response))
         }
     }
 
-    /// <p>Creates a new Elasticsearch domain. For more information, see <a href="http://docs.aws.amazon.com/elasticsearch-service/latest/developerguide/es-createupdatedomains.html#es-createdomains" target="_blank">Creating Elasticsearch Domains</a> in the <i>Amazon Elasticsearch Service Developer Guide</i>.</p>
-    async fn create_elasticsearch_domain(
+    /// Presigns the request `create_elasticsearch_domain` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_elasticsearch_domain(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: CreateElasticsearchDomainRequest,
-    ) -> Result<CreateElasticsearchDomainResponse, RusotoError<CreateElasticsearchDomainError>>
-    {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_elasticsearch_domain_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_elasticsearch_domain_request(
+        &self,
+        input: CreateElasticsearchDomainRequest,
+    ) -> SignedRequest {
         let request_uri = "/2015-01-01/es/domain";
 
         let mut request = SignedRequest::new("POST", "es", &self.region, &request_uri);
+        request.set_operation("CreateElasticsearchDomain");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `delete_elasticsearch_domain`, also returning the metadata of the response.
+    pub async fn delete_elasticsearch_domain_with_metadata(
+        &self,
+        input: DeleteElasticsearchDomainRequest,
+    ) -> Result<
+        (DeleteElasticsearchDomainResponse, ResponseMetadata),
+        RusotoError<DeleteElasticsearchDomainError>,
+    > {
+        let request = self.build_delete_elasticsearch_domain_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<CreateElasticsearchDomainResponse, _>()?;
+                .deserialize::<DeleteElasticsearchDomainResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(CreateElasticsearchDomainError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(DeleteElasticsearchDomainError::from_response(response))
         }
     }
 
-    /// <p>Permanently deletes the specified Elasticsearch domain and all of its data. Once a domain is deleted, it cannot be recovered.</p>
-    async fn delete_elasticsearch_domain(
+    /// Presigns the request `delete_elasticsearch_domain` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_elasticsearch_domain(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteElasticsearchDomainRequest,
-    ) -> Result<DeleteElasticsearchDomainResponse, RusotoError<DeleteElasticsearchDomainError>>
-    {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_elasticsearch_domain_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_elasticsearch_domain_request(
+        &self,
+        input: DeleteElasticsearchDomainRequest,
+    ) -> SignedRequest {
         let request_uri = format!(
             "/2015-01-01/es/domain/{domain_name}",
             domain_name = input.domain_name
         );
 
         let mut request = SignedRequest::new("DELETE", "es", &self.region, &request_uri);
+        request.set_operation("DeleteElasticsearchDomain");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
+        request
+    }
+
+    /// Calls `delete_elasticsearch_service_role`, also returning the metadata of the response.
+    pub async fn delete_elasticsearch_service_role_with_metadata(
+        &self,
+    ) -> Result<((), ResponseMetadata), RusotoError<DeleteElasticsearchServiceRoleError>> {
+        let request = self.build_delete_elasticsearch_service_role_request();
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<DeleteElasticsearchDomainResponse, _>()?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = ::std::mem::drop(response);
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteElasticsearchDomainError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(DeleteElasticsearchServiceRoleError::from_response(response))
         }
     }
 
-    /// <p>Deletes the service-linked role that Elasticsearch Service uses to manage and maintain VPC domains. Role deletion will fail if any existing VPC domains use the role. You must delete any such Elasticsearch domains before deleting the role. See <a href="http://docs.aws.amazon.com/elasticsearch-service/latest/developerguide/es-vpc.html#es-enabling-slr" target="_blank">Deleting Elasticsearch Service Role</a> in <i>VPC Endpoints for Amazon Elasticsearch Service Domains</i>.</p>
-    async fn delete_elasticsearch_service_role(
+    /// Presigns the request `delete_elasticsearch_service_role` would send with `presigner` instead of sending it.
+    pub async fn presign_delete_elasticsearch_service_role(
         &self,
-    ) -> Result<(), RusotoError<DeleteElasticsearchServiceRoleError>> {
+        presigner: &rusoto_core::Presigner,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_elasticsearch_service_role_request();
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_elasticsearch_service_role_request(&self) -> SignedRequest {
         let request_uri = "/2015-01-01/es/role";
 
         let mut request = SignedRequest::new("DELETE", "es", &self.region, &request_uri);
+        request.set_operation("DeleteElasticsearchServiceRole");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
+        request
+    }
+
+    /// Calls `describe_elasticsearch_domain`, also returning the metadata of the response.
+    pub async fn describe_elasticsearch_domain_with_metadata(
+        &self,
+        input: DescribeElasticsearchDomainRequest,
+    ) -> Result<
+        (DescribeElasticsearchDomainResponse, ResponseMetadata),
+        RusotoError<DescribeElasticsearchDomainError>,
+    > {
+        let request = self.build_describe_elasticsearch_domain_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            let result = ::std::mem::drop(response);
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DescribeElasticsearchDomainResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteElasticsearchServiceRoleError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(DescribeElasticsearchDomainError::from_response(response))
         }
     }
 
-    /// <p>Returns domain configuration information about the specified Elasticsearch domain, including the domain ID, domain endpoint, and domain ARN.</p>
-    async fn describe_elasticsearch_domain(
+    /// Presigns the request `describe_elasticsearch_domain` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_elasticsearch_domain(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeElasticsearchDomainRequest,
-    ) -> Result<DescribeElasticsearchDomainResponse, RusotoError<DescribeElasticsearchDomainError>>
-    {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_elasticsearch_domain_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_elasticsearch_domain_request(
+        &self,
+        input: DescribeElasticsearchDomainRequest,
+    ) -> SignedRequest {
         let request_uri = format!(
             "/2015-01-01/es/domain/{domain_name}",
             domain_name = input.domain_name
         );
 
         let mut request = SignedRequest::new("GET", "es", &self.region, &request_uri);
+        request.set_operation("DescribeElasticsearchDomain");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
+        request
+    }
+
+    /// Calls `describe_elasticsearch_domain_config`, also returning the metadata of the response.
+    pub async fn describe_elasticsearch_domain_config_with_metadata(
+        &self,
+        input: DescribeElasticsearchDomainConfigRequest,
+    ) -> Result<
+        (DescribeElasticsearchDomainConfigResponse, ResponseMetadata),
+        RusotoError<DescribeElasticsearchDomainConfigError>,
+    > {
+        let request = self.build_describe_elasticsearch_domain_config_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<DescribeElasticsearchDomainResponse, _>()?;
+                .deserialize::<DescribeElasticsearchDomainConfigResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeElasticsearchDomainError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(DescribeElasticsearchDomainConfigError::from_response(
+                response,
+            ))
         }
     }
 
-    /// <p>Provides cluster configuration information about the specified Elasticsearch domain, such as the state, creation date, update version, and update date for cluster options.</p>
-    async fn describe_elasticsearch_domain_config(
+    /// Presigns the request `describe_elasticsearch_domain_config` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_elasticsearch_domain_config(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeElasticsearchDomainConfigRequest,
-    ) -> Result<
-        DescribeElasticsearchDomainConfigResponse,
-        RusotoError<DescribeElasticsearchDomainConfigError>,
-    > {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_elasticsearch_domain_config_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_elasticsearch_domain_config_request(
+        &self,
+        input: DescribeElasticsearchDomainConfigRequest,
+    ) -> SignedRequest {
         let request_uri = format!(
             "/2015-01-01/es/domain/{domain_name}/config",
             domain_name = input.domain_name
         );
 
         let mut request = SignedRequest::new("GET", "es", &self.region, &request_uri);
+        request.set_operation("DescribeElasticsearchDomainConfig");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
+        request
+    }
+
+    /// Calls `describe_elasticsearch_domains`, also returning the metadata of the response.
+    pub async fn describe_elasticsearch_domains_with_metadata(
+        &self,
+        input: DescribeElasticsearchDomainsRequest,
+    ) -> Result<
+        (DescribeElasticsearchDomainsResponse, ResponseMetadata),
+        RusotoError<DescribeElasticsearchDomainsError>,
+    > {
+        let request = self.build_describe_elasticsearch_domains_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<DescribeElasticsearchDomainConfigResponse, _>()?;
+                .deserialize::<DescribeElasticsearchDomainsResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeElasticsearchDomainConfigError::from_response(
-                response,
-            ))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(DescribeElasticsearchDomainsError::from_response(response))
         }
     }
 
-    /// <p>Returns domain configuration information about the specified Elasticsearch domains, including the domain ID, domain endpoint, and domain ARN.</p>
-    async fn describe_elasticsearch_domains(
+    /// Presigns the request `describe_elasticsearch_domains` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_elasticsearch_domains(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeElasticsearchDomainsRequest,
-    ) -> Result<DescribeElasticsearchDomainsResponse, RusotoError<DescribeElasticsearchDomainsError>>
-    {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_elasticsearch_domains_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_elasticsearch_domains_request(
+        &self,
+        input: DescribeElasticsearchDomainsRequest,
+    ) -> SignedRequest {
         let request_uri = "/2015-01-01/es/domain-info";
 
         let mut request = SignedRequest::new("POST", "es", &self.region, &request_uri);
+        request.set_operation("DescribeElasticsearchDomains");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `describe_elasticsearch_instance_type_limits`, also returning the metadata of the response.
+    pub async fn describe_elasticsearch_instance_type_limits_with_metadata(
+        &self,
+        input: DescribeElasticsearchInstanceTypeLimitsRequest,
+    ) -> Result<
+        (
+            DescribeElasticsearchInstanceTypeLimitsResponse,
+            ResponseMetadata,
+        ),
+        RusotoError<DescribeElasticsearchInstanceTypeLimitsError>,
+    > {
+        let request = self.build_describe_elasticsearch_instance_type_limits_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<DescribeElasticsearchDomainsResponse, _>()?;
+                .deserialize::<DescribeElasticsearchInstanceTypeLimitsResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeElasticsearchDomainsError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(DescribeElasticsearchInstanceTypeLimitsError::from_response(
+                response,
+            ))
         }
     }
 
-    /// <p> Describe Elasticsearch Limits for a given InstanceType and ElasticsearchVersion. When modifying existing Domain, specify the <code> <a>DomainName</a> </code> to know what Limits are supported for modifying. </p>
-    async fn describe_elasticsearch_instance_type_limits(
+    /// Presigns the request `describe_elasticsearch_instance_type_limits` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_elasticsearch_instance_type_limits(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeElasticsearchInstanceTypeLimitsRequest,
-    ) -> Result<
-        DescribeElasticsearchInstanceTypeLimitsResponse,
-        RusotoError<DescribeElasticsearchInstanceTypeLimitsError>,
-    > {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_elasticsearch_instance_type_limits_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_elasticsearch_instance_type_limits_request(
+        &self,
+        input: DescribeElasticsearchInstanceTypeLimitsRequest,
+    ) -> SignedRequest {
         let request_uri = format!(
             "/2015-01-01/es/instanceTypeLimits/{elasticsearch_version}/{instance_type}",
             elasticsearch_version = input.elasticsearch_version,
@@ -3067,6 +3636,7 @@ impl Es for EsClient {
         );
 
         let mut request = SignedRequest::new("GET", "es", &self.region, &request_uri);
+        request.set_operation("DescribeElasticsearchInstanceTypeLimits");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -3075,36 +3645,65 @@ impl Es for EsClient {
         }
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `describe_reserved_elasticsearch_instance_offerings`, also returning the metadata of the response.
+    pub async fn describe_reserved_elasticsearch_instance_offerings_with_metadata(
+        &self,
+        input: DescribeReservedElasticsearchInstanceOfferingsRequest,
+    ) -> Result<
+        (
+            DescribeReservedElasticsearchInstanceOfferingsResponse,
+            ResponseMetadata,
+        ),
+        RusotoError<DescribeReservedElasticsearchInstanceOfferingsError>,
+    > {
+        let request = self.build_describe_reserved_elasticsearch_instance_offerings_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<DescribeElasticsearchInstanceTypeLimitsResponse, _>()?;
+                .deserialize::<DescribeReservedElasticsearchInstanceOfferingsResponse, _>(
+            )?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeElasticsearchInstanceTypeLimitsError::from_response(
-                response,
-            ))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(DescribeReservedElasticsearchInstanceOfferingsError::from_response(response))
         }
     }
 
-    /// <p>Lists available reserved Elasticsearch instance offerings.</p>
-    async fn describe_reserved_elasticsearch_instance_offerings(
+    /// Presigns the request `describe_reserved_elasticsearch_instance_offerings` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_reserved_elasticsearch_instance_offerings(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeReservedElasticsearchInstanceOfferingsRequest,
-    ) -> Result<
-        DescribeReservedElasticsearchInstanceOfferingsResponse,
-        RusotoError<DescribeReservedElasticsearchInstanceOfferingsError>,
-    > {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_reserved_elasticsearch_instance_offerings_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_reserved_elasticsearch_instance_offerings_request(
+        &self,
+        input: DescribeReservedElasticsearchInstanceOfferingsRequest,
+    ) -> SignedRequest {
         let request_uri = "/2015-01-01/es/reservedInstanceOfferings";
 
         let mut request = SignedRequest::new("GET", "es", &self.region, &request_uri);
+        request.set_operation("DescribeReservedElasticsearchInstanceOfferings");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -3119,35 +3718,66 @@ impl Es for EsClient {
         }
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `describe_reserved_elasticsearch_instances`, also returning the metadata of the response.
+    pub async fn describe_reserved_elasticsearch_instances_with_metadata(
+        &self,
+        input: DescribeReservedElasticsearchInstancesRequest,
+    ) -> Result<
+        (
+            DescribeReservedElasticsearchInstancesResponse,
+            ResponseMetadata,
+        ),
+        RusotoError<DescribeReservedElasticsearchInstancesError>,
+    > {
+        let request = self.build_describe_reserved_elasticsearch_instances_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<DescribeReservedElasticsearchInstanceOfferingsResponse, _>(
-            )?;
+                .deserialize::<DescribeReservedElasticsearchInstancesResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeReservedElasticsearchInstanceOfferingsError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(DescribeReservedElasticsearchInstancesError::from_response(
+                response,
+            ))
         }
     }
 
-    /// <p>Returns information about reserved Elasticsearch instances for this account.</p>
-    async fn describe_reserved_elasticsearch_instances(
+    /// Presigns the request `describe_reserved_elasticsearch_instances` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_reserved_elasticsearch_instances(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeReservedElasticsearchInstancesRequest,
-    ) -> Result<
-        DescribeReservedElasticsearchInstancesResponse,
-        RusotoError<DescribeReservedElasticsearchInstancesError>,
-    > {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_reserved_elasticsearch_instances_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_reserved_elasticsearch_instances_request(
+        &self,
+        input: DescribeReservedElasticsearchInstancesRequest,
+    ) -> SignedRequest {
         let request_uri = "/2015-01-01/es/reservedInstances";
 
         let mut request = SignedRequest::new("GET", "es", &self.region, &request_uri);
+        request.set_operation("DescribeReservedElasticsearchInstances");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -3162,36 +3792,63 @@ impl Es for EsClient {
         }
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `get_compatible_elasticsearch_versions`, also returning the metadata of the response.
+    pub async fn get_compatible_elasticsearch_versions_with_metadata(
+        &self,
+        input: GetCompatibleElasticsearchVersionsRequest,
+    ) -> Result<
+        (GetCompatibleElasticsearchVersionsResponse, ResponseMetadata),
+        RusotoError<GetCompatibleElasticsearchVersionsError>,
+    > {
+        let request = self.build_get_compatible_elasticsearch_versions_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<DescribeReservedElasticsearchInstancesResponse, _>()?;
+                .deserialize::<GetCompatibleElasticsearchVersionsResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeReservedElasticsearchInstancesError::from_response(
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(GetCompatibleElasticsearchVersionsError::from_response(
                 response,
             ))
         }
     }
 
-    /// <p> Returns a list of upgrade compatible Elastisearch versions. You can optionally pass a <code> <a>DomainName</a> </code> to get all upgrade compatible Elasticsearch versions for that specific domain. </p>
-    async fn get_compatible_elasticsearch_versions(
+    /// Presigns the request `get_compatible_elasticsearch_versions` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_compatible_elasticsearch_versions(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetCompatibleElasticsearchVersionsRequest,
-    ) -> Result<
-        GetCompatibleElasticsearchVersionsResponse,
-        RusotoError<GetCompatibleElasticsearchVersionsError>,
-    > {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_compatible_elasticsearch_versions_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_compatible_elasticsearch_versions_request(
+        &self,
+        input: GetCompatibleElasticsearchVersionsRequest,
+    ) -> SignedRequest {
         let request_uri = "/2015-01-01/es/compatibleVersions";
 
         let mut request = SignedRequest::new("GET", "es", &self.region, &request_uri);
+        request.set_operation("GetCompatibleElasticsearchVersions");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -3200,36 +3857,59 @@ impl Es for EsClient {
         }
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `get_upgrade_history`, also returning the metadata of the response.
+    pub async fn get_upgrade_history_with_metadata(
+        &self,
+        input: GetUpgradeHistoryRequest,
+    ) -> Result<(GetUpgradeHistoryResponse, ResponseMetadata), RusotoError<GetUpgradeHistoryError>>
+    {
+        let request = self.build_get_upgrade_history_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<GetCompatibleElasticsearchVersionsResponse, _>()?;
+                .deserialize::<GetUpgradeHistoryResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(GetCompatibleElasticsearchVersionsError::from_response(
-                response,
-            ))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(GetUpgradeHistoryError::from_response(response))
         }
     }
 
-    /// <p>Retrieves the complete history of the last 10 upgrades that were performed on the domain.</p>
-    async fn get_upgrade_history(
+    /// Presigns the request `get_upgrade_history` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_upgrade_history(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetUpgradeHistoryRequest,
-    ) -> Result<GetUpgradeHistoryResponse, RusotoError<GetUpgradeHistoryError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_upgrade_history_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_upgrade_history_request(&self, input: GetUpgradeHistoryRequest) -> SignedRequest {
         let request_uri = format!(
             "/2015-01-01/es/upgradeDomain/{domain_name}/history",
             domain_name = input.domain_name
         );
 
         let mut request = SignedRequest::new("GET", "es", &self.region, &request_uri);
+        request.set_operation("GetUpgradeHistory");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -3241,93 +3921,169 @@ impl Es for EsClient {
         }
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `get_upgrade_status`, also returning the metadata of the response.
+    pub async fn get_upgrade_status_with_metadata(
+        &self,
+        input: GetUpgradeStatusRequest,
+    ) -> Result<(GetUpgradeStatusResponse, ResponseMetadata), RusotoError<GetUpgradeStatusError>>
+    {
+        let request = self.build_get_upgrade_status_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<GetUpgradeHistoryResponse, _>()?;
+                .deserialize::<GetUpgradeStatusResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(GetUpgradeHistoryError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(GetUpgradeStatusError::from_response(response))
         }
     }
 
-    /// <p>Retrieves the latest status of the last upgrade or upgrade eligibility check that was performed on the domain.</p>
-    async fn get_upgrade_status(
+    /// Presigns the request `get_upgrade_status` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_upgrade_status(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetUpgradeStatusRequest,
-    ) -> Result<GetUpgradeStatusResponse, RusotoError<GetUpgradeStatusError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_upgrade_status_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_upgrade_status_request(&self, input: GetUpgradeStatusRequest) -> SignedRequest {
         let request_uri = format!(
             "/2015-01-01/es/upgradeDomain/{domain_name}/status",
             domain_name = input.domain_name
         );
 
         let mut request = SignedRequest::new("GET", "es", &self.region, &request_uri);
+        request.set_operation("GetUpgradeStatus");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
+        request
+    }
+
+    /// Calls `list_domain_names`, also returning the metadata of the response.
+    pub async fn list_domain_names_with_metadata(
+        &self,
+    ) -> Result<(ListDomainNamesResponse, ResponseMetadata), RusotoError<ListDomainNamesError>>
+    {
+        let request = self.build_list_domain_names_request();
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<GetUpgradeStatusResponse, _>()?;
+                .deserialize::<ListDomainNamesResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(GetUpgradeStatusError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(ListDomainNamesError::from_response(response))
         }
     }
 
-    /// <p>Returns the name of all Elasticsearch domains owned by the current user's account. </p>
-    async fn list_domain_names(
+    /// Presigns the request `list_domain_names` would send with `presigner` instead of sending it.
+    pub async fn presign_list_domain_names(
         &self,
-    ) -> Result<ListDomainNamesResponse, RusotoError<ListDomainNamesError>> {
+        presigner: &rusoto_core::Presigner,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_domain_names_request();
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_domain_names_request(&self) -> SignedRequest {
         let request_uri = "/2015-01-01/domain";
 
         let mut request = SignedRequest::new("GET", "es", &self.region, &request_uri);
+        request.set_operation("ListDomainNames");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
+        request
+    }
+
+    /// Calls `list_elasticsearch_instance_types`, also returning the metadata of the response.
+    pub async fn list_elasticsearch_instance_types_with_metadata(
+        &self,
+        input: ListElasticsearchInstanceTypesRequest,
+    ) -> Result<
+        (ListElasticsearchInstanceTypesResponse, ResponseMetadata),
+        RusotoError<ListElasticsearchInstanceTypesError>,
+    > {
+        let request = self.build_list_elasticsearch_instance_types_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListDomainNamesResponse, _>()?;
+                .deserialize::<ListElasticsearchInstanceTypesResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(ListDomainNamesError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(ListElasticsearchInstanceTypesError::from_response(response))
         }
     }
 
-    /// <p>List all Elasticsearch instance types that are supported for given ElasticsearchVersion</p>
-    async fn list_elasticsearch_instance_types(
+    /// Presigns the request `list_elasticsearch_instance_types` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_elasticsearch_instance_types(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListElasticsearchInstanceTypesRequest,
-    ) -> Result<
-        ListElasticsearchInstanceTypesResponse,
-        RusotoError<ListElasticsearchInstanceTypesError>,
-    > {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_elasticsearch_instance_types_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_elasticsearch_instance_types_request(
+        &self,
+        input: ListElasticsearchInstanceTypesRequest,
+    ) -> SignedRequest {
         let request_uri = format!(
             "/2015-01-01/es/instanceTypes/{elasticsearch_version}",
             elasticsearch_version = input.elasticsearch_version
         );
 
         let mut request = SignedRequest::new("GET", "es", &self.region, &request_uri);
+        request.set_operation("ListElasticsearchInstanceTypes");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -3342,32 +4098,61 @@ impl Es for EsClient {
         }
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `list_elasticsearch_versions`, also returning the metadata of the response.
+    pub async fn list_elasticsearch_versions_with_metadata(
+        &self,
+        input: ListElasticsearchVersionsRequest,
+    ) -> Result<
+        (ListElasticsearchVersionsResponse, ResponseMetadata),
+        RusotoError<ListElasticsearchVersionsError>,
+    > {
+        let request = self.build_list_elasticsearch_versions_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListElasticsearchInstanceTypesResponse, _>()?;
+                .deserialize::<ListElasticsearchVersionsResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(ListElasticsearchInstanceTypesError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(ListElasticsearchVersionsError::from_response(response))
         }
     }
 
-    /// <p>List all supported Elasticsearch versions</p>
-    async fn list_elasticsearch_versions(
+    /// Presigns the request `list_elasticsearch_versions` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_elasticsearch_versions(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListElasticsearchVersionsRequest,
-    ) -> Result<ListElasticsearchVersionsResponse, RusotoError<ListElasticsearchVersionsError>>
-    {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_elasticsearch_versions_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_elasticsearch_versions_request(
+        &self,
+        input: ListElasticsearchVersionsRequest,
+    ) -> SignedRequest {
         let request_uri = "/2015-01-01/es/versions";
 
         let mut request = SignedRequest::new("GET", "es", &self.region, &request_uri);
+        request.set_operation("ListElasticsearchVersions");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -3379,218 +4164,857 @@ impl Es for EsClient {
         }
         request.set_params(params);
 
+        request
+    }
+
+    /// Calls `list_tags`, also returning the metadata of the response.
+    pub async fn list_tags_with_metadata(
+        &self,
+        input: ListTagsRequest,
+    ) -> Result<(ListTagsResponse, ResponseMetadata), RusotoError<ListTagsError>> {
+        let request = self.build_list_tags_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListElasticsearchVersionsResponse, _>()?;
+                .deserialize::<ListTagsResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(ListElasticsearchVersionsError::from_response(response))
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            Err(ListTagsError::from_response(response))
         }
     }
 
-    /// <p>Returns all tags for the given Elasticsearch domain.</p>
-    async fn list_tags(
+    /// Presigns the request `list_tags` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_tags(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListTagsRequest,
-    ) -> Result<ListTagsResponse, RusotoError<ListTagsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_tags_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_tags_request(&self, input: ListTagsRequest) -> SignedRequest {
         let request_uri = "/2015-01-01/tags/";
 
         let mut request = SignedRequest::new("GET", "es", &self.region, &request_uri);
+        request.set_operation("ListTags");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
         params.put("arn", &input.arn);
         request.set_params(params);
 
-        let mut response = self
-            .client
-            .sign_and_dispatch(request)
-            .await
-            .map_err(RusotoError::from)?;
-        if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            let result = proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListTagsResponse, _>()?;
-
-            Ok(result)
-        } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            Err(ListTagsError::from_response(response))
-        }
+        request
     }
 
-    /// <p>Allows you to purchase reserved Elasticsearch instances.</p>
-    async fn purchase_reserved_elasticsearch_instance_offering(
+    /// Calls `purchase_reserved_elasticsearch_instance_offering`, also returning the metadata of the response.
+    pub async fn purchase_reserved_elasticsearch_instance_offering_with_metadata(
         &self,
         input: PurchaseReservedElasticsearchInstanceOfferingRequest,
     ) -> Result<
-        PurchaseReservedElasticsearchInstanceOfferingResponse,
+        (
+            PurchaseReservedElasticsearchInstanceOfferingResponse,
+            ResponseMetadata,
+        ),
         RusotoError<PurchaseReservedElasticsearchInstanceOfferingError>,
     > {
-        let request_uri = "/2015-01-01/es/purchaseReservedInstanceOffering";
-
-        let mut request = SignedRequest::new("POST", "es", &self.region, &request_uri);
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-
-        let encoded = Some(serde_json::to_vec(&input).unwrap());
-        request.set_payload(encoded);
+        let request = self.build_purchase_reserved_elasticsearch_instance_offering_request(input);
 
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<PurchaseReservedElasticsearchInstanceOfferingResponse, _>(
             )?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             Err(PurchaseReservedElasticsearchInstanceOfferingError::from_response(response))
         }
     }
 
-    /// <p>Removes the specified set of tags from the specified Elasticsearch domain.</p>
-    async fn remove_tags(
+    /// Presigns the request `purchase_reserved_elasticsearch_instance_offering` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_purchase_reserved_elasticsearch_instance_offering(
         &self,
-        input: RemoveTagsRequest,
-    ) -> Result<(), RusotoError<RemoveTagsError>> {
-        let request_uri = "/2015-01-01/tags-removal";
+        presigner: &rusoto_core::Presigner,
+        input: PurchaseReservedElasticsearchInstanceOfferingRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_purchase_reserved_elasticsearch_instance_offering_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_purchase_reserved_elasticsearch_instance_offering_request(
+        &self,
+        input: PurchaseReservedElasticsearchInstanceOfferingRequest,
+    ) -> SignedRequest {
+        let request_uri = "/2015-01-01/es/purchaseReservedInstanceOffering";
 
         let mut request = SignedRequest::new("POST", "es", &self.region, &request_uri);
+        request.set_operation("PurchaseReservedElasticsearchInstanceOffering");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `remove_tags`, also returning the metadata of the response.
+    pub async fn remove_tags_with_metadata(
+        &self,
+        input: RemoveTagsRequest,
+    ) -> Result<((), ResponseMetadata), RusotoError<RemoveTagsError>> {
+        let request = self.build_remove_tags_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = ::std::mem::drop(response);
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             Err(RemoveTagsError::from_response(response))
         }
     }
 
-    /// <p>Schedules a service software update for an Amazon ES domain.</p>
-    async fn start_elasticsearch_service_software_update(
+    /// Presigns the request `remove_tags` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_remove_tags(
         &self,
-        input: StartElasticsearchServiceSoftwareUpdateRequest,
-    ) -> Result<
-        StartElasticsearchServiceSoftwareUpdateResponse,
-        RusotoError<StartElasticsearchServiceSoftwareUpdateError>,
-    > {
-        let request_uri = "/2015-01-01/es/serviceSoftwareUpdate/start";
+        presigner: &rusoto_core::Presigner,
+        input: RemoveTagsRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_remove_tags_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_remove_tags_request(&self, input: RemoveTagsRequest) -> SignedRequest {
+        let request_uri = "/2015-01-01/tags-removal";
 
         let mut request = SignedRequest::new("POST", "es", &self.region, &request_uri);
+        request.set_operation("RemoveTags");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `start_elasticsearch_service_software_update`, also returning the metadata of the response.
+    pub async fn start_elasticsearch_service_software_update_with_metadata(
+        &self,
+        input: StartElasticsearchServiceSoftwareUpdateRequest,
+    ) -> Result<
+        (
+            StartElasticsearchServiceSoftwareUpdateResponse,
+            ResponseMetadata,
+        ),
+        RusotoError<StartElasticsearchServiceSoftwareUpdateError>,
+    > {
+        let request = self.build_start_elasticsearch_service_software_update_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<StartElasticsearchServiceSoftwareUpdateResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             Err(StartElasticsearchServiceSoftwareUpdateError::from_response(
                 response,
             ))
         }
     }
 
-    /// <p>Modifies the cluster configuration of the specified Elasticsearch domain, setting as setting the instance type and the number of instances. </p>
-    async fn update_elasticsearch_domain_config(
+    /// Presigns the request `start_elasticsearch_service_software_update` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_start_elasticsearch_service_software_update(
         &self,
-        input: UpdateElasticsearchDomainConfigRequest,
-    ) -> Result<
-        UpdateElasticsearchDomainConfigResponse,
-        RusotoError<UpdateElasticsearchDomainConfigError>,
-    > {
-        let request_uri = format!(
-            "/2015-01-01/es/domain/{domain_name}/config",
-            domain_name = input.domain_name
-        );
-
+        presigner: &rusoto_core::Presigner,
+        input: StartElasticsearchServiceSoftwareUpdateRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_start_elasticsearch_service_software_update_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_start_elasticsearch_service_software_update_request(
+        &self,
+        input: StartElasticsearchServiceSoftwareUpdateRequest,
+    ) -> SignedRequest {
+        let request_uri = "/2015-01-01/es/serviceSoftwareUpdate/start";
+
         let mut request = SignedRequest::new("POST", "es", &self.region, &request_uri);
+        request.set_operation("StartElasticsearchServiceSoftwareUpdate");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `update_elasticsearch_domain_config`, also returning the metadata of the response.
+    pub async fn update_elasticsearch_domain_config_with_metadata(
+        &self,
+        input: UpdateElasticsearchDomainConfigRequest,
+    ) -> Result<
+        (UpdateElasticsearchDomainConfigResponse, ResponseMetadata),
+        RusotoError<UpdateElasticsearchDomainConfigError>,
+    > {
+        let request = self.build_update_elasticsearch_domain_config_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<UpdateElasticsearchDomainConfigResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             Err(UpdateElasticsearchDomainConfigError::from_response(
                 response,
             ))
         }
     }
 
-    /// <p>Allows you to either upgrade your domain or perform an Upgrade eligibility check to a compatible Elasticsearch version.</p>
-    async fn upgrade_elasticsearch_domain(
+    /// Presigns the request `update_elasticsearch_domain_config` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_elasticsearch_domain_config(
         &self,
-        input: UpgradeElasticsearchDomainRequest,
-    ) -> Result<UpgradeElasticsearchDomainResponse, RusotoError<UpgradeElasticsearchDomainError>>
-    {
-        let request_uri = "/2015-01-01/es/upgradeDomain";
+        presigner: &rusoto_core::Presigner,
+        input: UpdateElasticsearchDomainConfigRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_elasticsearch_domain_config_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_elasticsearch_domain_config_request(
+        &self,
+        input: UpdateElasticsearchDomainConfigRequest,
+    ) -> SignedRequest {
+        let request_uri = format!(
+            "/2015-01-01/es/domain/{domain_name}/config",
+            domain_name = input.domain_name
+        );
 
         let mut request = SignedRequest::new("POST", "es", &self.region, &request_uri);
+        request.set_operation("UpdateElasticsearchDomainConfig");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
         request.set_payload(encoded);
 
+        request
+    }
+
+    /// Calls `upgrade_elasticsearch_domain`, also returning the metadata of the response.
+    pub async fn upgrade_elasticsearch_domain_with_metadata(
+        &self,
+        input: UpgradeElasticsearchDomainRequest,
+    ) -> Result<
+        (UpgradeElasticsearchDomainResponse, ResponseMetadata),
+        RusotoError<UpgradeElasticsearchDomainError>,
+    > {
+        let request = self.build_upgrade_elasticsearch_domain_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             let result = proto::json::ResponsePayload::new(&response)
                 .deserialize::<UpgradeElasticsearchDomainResponse, _>()?;
 
-            Ok(result)
+            Ok((result, metadata))
         } else {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
             Err(UpgradeElasticsearchDomainError::from_response(response))
         }
     }
+
+    /// Presigns the request `upgrade_elasticsearch_domain` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_upgrade_elasticsearch_domain(
+        &self,
+        presigner: &rusoto_core::Presigner,
+        input: UpgradeElasticsearchDomainRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_upgrade_elasticsearch_domain_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_upgrade_elasticsearch_domain_request(
+        &self,
+        input: UpgradeElasticsearchDomainRequest,
+    ) -> SignedRequest {
+        let request_uri = "/2015-01-01/es/upgradeDomain";
+
+        let mut request = SignedRequest::new("POST", "es", &self.region, &request_uri);
+        request.set_operation("UpgradeElasticsearchDomain");
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+
+        let encoded = Some(serde_json::to_vec(&input).unwrap());
+        request.set_payload(encoded);
+
+        request
+    }
+
+    /// The pages of `DescribeReservedElasticsearchInstanceOfferings` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `MaxResults` of `input`.
+    pub fn describe_reserved_elasticsearch_instance_offerings_pages(
+        &self,
+        input: DescribeReservedElasticsearchInstanceOfferingsRequest,
+    ) -> impl futures::Stream<
+        Item = Result<
+            DescribeReservedElasticsearchInstanceOfferingsResponse,
+            RusotoError<DescribeReservedElasticsearchInstanceOfferingsError>,
+        >,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: EsClient, input| async move {
+                client
+                    .describe_reserved_elasticsearch_instance_offerings(input)
+                    .await
+            },
+            |input: &DescribeReservedElasticsearchInstanceOfferingsRequest,
+             output: &DescribeReservedElasticsearchInstanceOfferingsResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(DescribeReservedElasticsearchInstanceOfferingsRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `ReservedElasticsearchInstanceOfferings` of every page of `DescribeReservedElasticsearchInstanceOfferings` results for `input`, as `describe_reserved_elasticsearch_instance_offerings_pages` requests them.
+    pub fn describe_reserved_elasticsearch_instance_offerings_stream(
+        &self,
+        input: DescribeReservedElasticsearchInstanceOfferingsRequest,
+    ) -> impl futures::Stream<
+        Item = Result<
+            ReservedElasticsearchInstanceOffering,
+            RusotoError<DescribeReservedElasticsearchInstanceOfferingsError>,
+        >,
+    > + Send {
+        rusoto_core::pagination::items(
+            self.describe_reserved_elasticsearch_instance_offerings_pages(input),
+            |page| {
+                page.reserved_elasticsearch_instance_offerings
+                    .unwrap_or_default()
+            },
+        )
+    }
+
+    /// The pages of `DescribeReservedElasticsearchInstances` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `MaxResults` of `input`.
+    pub fn describe_reserved_elasticsearch_instances_pages(
+        &self,
+        input: DescribeReservedElasticsearchInstancesRequest,
+    ) -> impl futures::Stream<
+        Item = Result<
+            DescribeReservedElasticsearchInstancesResponse,
+            RusotoError<DescribeReservedElasticsearchInstancesError>,
+        >,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: EsClient, input| async move {
+                client
+                    .describe_reserved_elasticsearch_instances(input)
+                    .await
+            },
+            |input: &DescribeReservedElasticsearchInstancesRequest,
+             output: &DescribeReservedElasticsearchInstancesResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(DescribeReservedElasticsearchInstancesRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `ReservedElasticsearchInstances` of every page of `DescribeReservedElasticsearchInstances` results for `input`, as `describe_reserved_elasticsearch_instances_pages` requests them.
+    pub fn describe_reserved_elasticsearch_instances_stream(
+        &self,
+        input: DescribeReservedElasticsearchInstancesRequest,
+    ) -> impl futures::Stream<
+        Item = Result<
+            ReservedElasticsearchInstance,
+            RusotoError<DescribeReservedElasticsearchInstancesError>,
+        >,
+    > + Send {
+        rusoto_core::pagination::items(
+            self.describe_reserved_elasticsearch_instances_pages(input),
+            |page| page.reserved_elasticsearch_instances.unwrap_or_default(),
+        )
+    }
+
+    /// The pages of `GetUpgradeHistory` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `MaxResults` of `input`.
+    pub fn get_upgrade_history_pages(
+        &self,
+        input: GetUpgradeHistoryRequest,
+    ) -> impl futures::Stream<
+        Item = Result<GetUpgradeHistoryResponse, RusotoError<GetUpgradeHistoryError>>,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: EsClient, input| async move { client.get_upgrade_history(input).await },
+            |input: &GetUpgradeHistoryRequest, output: &GetUpgradeHistoryResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(GetUpgradeHistoryRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `UpgradeHistories` of every page of `GetUpgradeHistory` results for `input`, as `get_upgrade_history_pages` requests them.
+    pub fn get_upgrade_history_stream(
+        &self,
+        input: GetUpgradeHistoryRequest,
+    ) -> impl futures::Stream<Item = Result<UpgradeHistory, RusotoError<GetUpgradeHistoryError>>> + Send
+    {
+        rusoto_core::pagination::items(self.get_upgrade_history_pages(input), |page| {
+            page.upgrade_histories.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `ListElasticsearchInstanceTypes` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `MaxResults` of `input`.
+    pub fn list_elasticsearch_instance_types_pages(
+        &self,
+        input: ListElasticsearchInstanceTypesRequest,
+    ) -> impl futures::Stream<
+        Item = Result<
+            ListElasticsearchInstanceTypesResponse,
+            RusotoError<ListElasticsearchInstanceTypesError>,
+        >,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: EsClient, input| async move {
+                client.list_elasticsearch_instance_types(input).await
+            },
+            |input: &ListElasticsearchInstanceTypesRequest,
+             output: &ListElasticsearchInstanceTypesResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListElasticsearchInstanceTypesRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `ElasticsearchInstanceTypes` of every page of `ListElasticsearchInstanceTypes` results for `input`, as `list_elasticsearch_instance_types_pages` requests them.
+    pub fn list_elasticsearch_instance_types_stream(
+        &self,
+        input: ListElasticsearchInstanceTypesRequest,
+    ) -> impl futures::Stream<Item = Result<String, RusotoError<ListElasticsearchInstanceTypesError>>>
+           + Send {
+        rusoto_core::pagination::items(
+            self.list_elasticsearch_instance_types_pages(input),
+            |page| page.elasticsearch_instance_types.unwrap_or_default(),
+        )
+    }
+
+    /// The pages of `ListElasticsearchVersions` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `MaxResults` of `input`.
+    pub fn list_elasticsearch_versions_pages(
+        &self,
+        input: ListElasticsearchVersionsRequest,
+    ) -> impl futures::Stream<
+        Item = Result<
+            ListElasticsearchVersionsResponse,
+            RusotoError<ListElasticsearchVersionsError>,
+        >,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: EsClient, input| async move { client.list_elasticsearch_versions(input).await },
+            |input: &ListElasticsearchVersionsRequest,
+             output: &ListElasticsearchVersionsResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListElasticsearchVersionsRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `ElasticsearchVersions` of every page of `ListElasticsearchVersions` results for `input`, as `list_elasticsearch_versions_pages` requests them.
+    pub fn list_elasticsearch_versions_stream(
+        &self,
+        input: ListElasticsearchVersionsRequest,
+    ) -> impl futures::Stream<Item = Result<String, RusotoError<ListElasticsearchVersionsError>>> + Send
+    {
+        rusoto_core::pagination::items(self.list_elasticsearch_versions_pages(input), |page| {
+            page.elasticsearch_versions.unwrap_or_default()
+        })
+    }
+}
+
+#[async_trait]
+impl Es for EsClient {
+    /// <p>Attaches tags to an existing Elasticsearch domain. Tags are a set of case-sensitive key value pairs. An Elasticsearch domain may have up to 10 tags. See <a href="http://docs.aws.amazon.com/elasticsearch-service/latest/developerguide/es-managedomains.html#es-managedomains-awsresorcetagging" target="_blank"> Tagging Amazon Elasticsearch Service Domains for more information.</a></p>
+    async fn add_tags(&self, input: AddTagsRequest) -> Result<(), RusotoError<AddTagsError>> {
+        self.add_tags_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Cancels a scheduled service software update for an Amazon ES domain. You can only perform this operation before the <code>AutomatedUpdateDate</code> and when the <code>UpdateStatus</code> is in the <code>PENDING_UPDATE</code> state.</p>
+    async fn cancel_elasticsearch_service_software_update(
+        &self,
+        input: CancelElasticsearchServiceSoftwareUpdateRequest,
+    ) -> Result<
+        CancelElasticsearchServiceSoftwareUpdateResponse,
+        RusotoError<CancelElasticsearchServiceSoftwareUpdateError>,
+    > {
+        self.cancel_elasticsearch_service_software_update_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Creates a new Elasticsearch domain. For more information, see <a href="http://docs.aws.amazon.com/elasticsearch-service/latest/developerguide/es-createupdatedomains.html#es-createdomains" target="_blank">Creating Elasticsearch Domains</a> in the <i>Amazon Elasticsearch Service Developer Guide</i>.</p>
+    async fn create_elasticsearch_domain(
+        &self,
+        input: CreateElasticsearchDomainRequest,
+    ) -> Result<CreateElasticsearchDomainResponse, RusotoError<CreateElasticsearchDomainError>>
+    {
+        self.create_elasticsearch_domain_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Permanently deletes the specified Elasticsearch domain and all of its data. Once a domain is deleted, it cannot be recovered.</p>
+    async fn delete_elasticsearch_domain(
+        &self,
+        input: DeleteElasticsearchDomainRequest,
+    ) -> Result<DeleteElasticsearchDomainResponse, RusotoError<DeleteElasticsearchDomainError>>
+    {
+        self.delete_elasticsearch_domain_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes the service-linked role that Elasticsearch Service uses to manage and maintain VPC domains. Role deletion will fail if any existing VPC domains use the role. You must delete any such Elasticsearch domains before deleting the role. See <a href="http://docs.aws.amazon.com/elasticsearch-service/latest/developerguide/es-vpc.html#es-enabling-slr" target="_blank">Deleting Elasticsearch Service Role</a> in <i>VPC Endpoints for Amazon Elasticsearch Service Domains</i>.</p>
+    async fn delete_elasticsearch_service_role(
+        &self,
+    ) -> Result<(), RusotoError<DeleteElasticsearchServiceRoleError>> {
+        self.delete_elasticsearch_service_role_with_metadata()
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns domain configuration information about the specified Elasticsearch domain, including the domain ID, domain endpoint, and domain ARN.</p>
+    async fn describe_elasticsearch_domain(
+        &self,
+        input: DescribeElasticsearchDomainRequest,
+    ) -> Result<DescribeElasticsearchDomainResponse, RusotoError<DescribeElasticsearchDomainError>>
+    {
+        self.describe_elasticsearch_domain_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Provides cluster configuration information about the specified Elasticsearch domain, such as the state, creation date, update version, and update date for cluster options.</p>
+    async fn describe_elasticsearch_domain_config(
+        &self,
+        input: DescribeElasticsearchDomainConfigRequest,
+    ) -> Result<
+        DescribeElasticsearchDomainConfigResponse,
+        RusotoError<DescribeElasticsearchDomainConfigError>,
+    > {
+        self.describe_elasticsearch_domain_config_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns domain configuration information about the specified Elasticsearch domains, including the domain ID, domain endpoint, and domain ARN.</p>
+    async fn describe_elasticsearch_domains(
+        &self,
+        input: DescribeElasticsearchDomainsRequest,
+    ) -> Result<DescribeElasticsearchDomainsResponse, RusotoError<DescribeElasticsearchDomainsError>>
+    {
+        self.describe_elasticsearch_domains_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p> Describe Elasticsearch Limits for a given InstanceType and ElasticsearchVersion. When modifying existing Domain, specify the <code> <a>DomainName</a> </code> to know what Limits are supported for modifying. </p>
+    async fn describe_elasticsearch_instance_type_limits(
+        &self,
+        input: DescribeElasticsearchInstanceTypeLimitsRequest,
+    ) -> Result<
+        DescribeElasticsearchInstanceTypeLimitsResponse,
+        RusotoError<DescribeElasticsearchInstanceTypeLimitsError>,
+    > {
+        self.describe_elasticsearch_instance_type_limits_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Lists available reserved Elasticsearch instance offerings.</p>
+    async fn describe_reserved_elasticsearch_instance_offerings(
+        &self,
+        input: DescribeReservedElasticsearchInstanceOfferingsRequest,
+    ) -> Result<
+        DescribeReservedElasticsearchInstanceOfferingsResponse,
+        RusotoError<DescribeReservedElasticsearchInstanceOfferingsError>,
+    > {
+        self.describe_reserved_elasticsearch_instance_offerings_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns information about reserved Elasticsearch instances for this account.</p>
+    async fn describe_reserved_elasticsearch_instances(
+        &self,
+        input: DescribeReservedElasticsearchInstancesRequest,
+    ) -> Result<
+        DescribeReservedElasticsearchInstancesResponse,
+        RusotoError<DescribeReservedElasticsearchInstancesError>,
+    > {
+        self.describe_reserved_elasticsearch_instances_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p> Returns a list of upgrade compatible Elastisearch versions. You can optionally pass a <code> <a>DomainName</a> </code> to get all upgrade compatible Elasticsearch versions for that specific domain. </p>
+    async fn get_compatible_elasticsearch_versions(
+        &self,
+        input: GetCompatibleElasticsearchVersionsRequest,
+    ) -> Result<
+        GetCompatibleElasticsearchVersionsResponse,
+        RusotoError<GetCompatibleElasticsearchVersionsError>,
+    > {
+        self.get_compatible_elasticsearch_versions_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Retrieves the complete history of the last 10 upgrades that were performed on the domain.</p>
+    async fn get_upgrade_history(
+        &self,
+        input: GetUpgradeHistoryRequest,
+    ) -> Result<GetUpgradeHistoryResponse, RusotoError<GetUpgradeHistoryError>> {
+        self.get_upgrade_history_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Retrieves the latest status of the last upgrade or upgrade eligibility check that was performed on the domain.</p>
+    async fn get_upgrade_status(
+        &self,
+        input: GetUpgradeStatusRequest,
+    ) -> Result<GetUpgradeStatusResponse, RusotoError<GetUpgradeStatusError>> {
+        self.get_upgrade_status_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns the name of all Elasticsearch domains owned by the current user's account. </p>
+    async fn list_domain_names(
+        &self,
+    ) -> Result<ListDomainNamesResponse, RusotoError<ListDomainNamesError>> {
+        self.list_domain_names_with_metadata()
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>List all Elasticsearch instance types that are supported for given ElasticsearchVersion</p>
+    async fn list_elasticsearch_instance_types(
+        &self,
+        input: ListElasticsearchInstanceTypesRequest,
+    ) -> Result<
+        ListElasticsearchInstanceTypesResponse,
+        RusotoError<ListElasticsearchInstanceTypesError>,
+    > {
+        self.list_elasticsearch_instance_types_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>List all supported Elasticsearch versions</p>
+    async fn list_elasticsearch_versions(
+        &self,
+        input: ListElasticsearchVersionsRequest,
+    ) -> Result<ListElasticsearchVersionsResponse, RusotoError<ListElasticsearchVersionsError>>
+    {
+        self.list_elasticsearch_versions_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns all tags for the given Elasticsearch domain.</p>
+    async fn list_tags(
+        &self,
+        input: ListTagsRequest,
+    ) -> Result<ListTagsResponse, RusotoError<ListTagsError>> {
+        self.list_tags_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Allows you to purchase reserved Elasticsearch instances.</p>
+    async fn purchase_reserved_elasticsearch_instance_offering(
+        &self,
+        input: PurchaseReservedElasticsearchInstanceOfferingRequest,
+    ) -> Result<
+        PurchaseReservedElasticsearchInstanceOfferingResponse,
+        RusotoError<PurchaseReservedElasticsearchInstanceOfferingError>,
+    > {
+        self.purchase_reserved_elasticsearch_instance_offering_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Removes the specified set of tags from the specified Elasticsearch domain.</p>
+    async fn remove_tags(
+        &self,
+        input: RemoveTagsRequest,
+    ) -> Result<(), RusotoError<RemoveTagsError>> {
+        self.remove_tags_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Schedules a service software update for an Amazon ES domain.</p>
+    async fn start_elasticsearch_service_software_update(
+        &self,
+        input: StartElasticsearchServiceSoftwareUpdateRequest,
+    ) -> Result<
+        StartElasticsearchServiceSoftwareUpdateResponse,
+        RusotoError<StartElasticsearchServiceSoftwareUpdateError>,
+    > {
+        self.start_elasticsearch_service_software_update_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Modifies the cluster configuration of the specified Elasticsearch domain, setting as setting the instance type and the number of instances. </p>
+    async fn update_elasticsearch_domain_config(
+        &self,
+        input: UpdateElasticsearchDomainConfigRequest,
+    ) -> Result<
+        UpdateElasticsearchDomainConfigResponse,
+        RusotoError<UpdateElasticsearchDomainConfigError>,
+    > {
+        self.update_elasticsearch_domain_config_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Allows you to either upgrade your domain or perform an Upgrade eligibility check to a compatible Elasticsearch version.</p>
+    async fn upgrade_elasticsearch_domain(
+        &self,
+        input: UpgradeElasticsearchDomainRequest,
+    ) -> Result<UpgradeElasticsearchDomainResponse, RusotoError<UpgradeElasticsearchDomainError>>
+    {
+        self.upgrade_elasticsearch_domain_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
 }
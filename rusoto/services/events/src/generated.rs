@@ -16,8 +16,8 @@ use std::fmt;
 use async_trait::async_trait;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
-use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest, ResponseMetadata};
+use rusoto_core::{Client, ErrorMetadata, RusotoError};
 
 use rusoto_core::proto;
 use rusoto_core::signature::SignedRequest;
@@ -1210,6 +1210,7 @@ pub struct UntagResourceResponse {}
 
 /// Errors returned by ActivateEventSource
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ActivateEventSourceError {
     /// <p>This exception occurs due to unexpected causes.</p>
     Internal(String),
@@ -1217,6 +1218,8 @@ pub enum ActivateEventSourceError {
     InvalidState(String),
     /// <p>An entity that you specified doesn't exist.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ActivateEventSourceError {
@@ -1224,18 +1227,22 @@ impl ActivateEventSourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalException" => {
-                    return RusotoError::Service(ActivateEventSourceError::Internal(err.msg))
+                    return RusotoError::service(ActivateEventSourceError::Internal(err.msg), &res)
                 }
                 "InvalidStateException" => {
-                    return RusotoError::Service(ActivateEventSourceError::InvalidState(err.msg))
+                    return RusotoError::service(
+                        ActivateEventSourceError::InvalidState(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ActivateEventSourceError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ActivateEventSourceError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ActivateEventSourceError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1248,12 +1255,14 @@ impl fmt::Display for ActivateEventSourceError {
             ActivateEventSourceError::Internal(ref cause) => write!(f, "{}", cause),
             ActivateEventSourceError::InvalidState(ref cause) => write!(f, "{}", cause),
             ActivateEventSourceError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            ActivateEventSourceError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ActivateEventSourceError {}
 /// Errors returned by CreateEventBus
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateEventBusError {
     /// <p>There is concurrent modification on a resource.</p>
     ConcurrentModification(String),
@@ -1267,6 +1276,8 @@ pub enum CreateEventBusError {
     ResourceAlreadyExists(String),
     /// <p>An entity that you specified doesn't exist.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateEventBusError {
@@ -1274,29 +1285,34 @@ impl CreateEventBusError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(CreateEventBusError::ConcurrentModification(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateEventBusError::ConcurrentModification(err.msg),
+                        &res,
+                    )
                 }
                 "InternalException" => {
-                    return RusotoError::Service(CreateEventBusError::Internal(err.msg))
+                    return RusotoError::service(CreateEventBusError::Internal(err.msg), &res)
                 }
                 "InvalidStateException" => {
-                    return RusotoError::Service(CreateEventBusError::InvalidState(err.msg))
+                    return RusotoError::service(CreateEventBusError::InvalidState(err.msg), &res)
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateEventBusError::LimitExceeded(err.msg))
+                    return RusotoError::service(CreateEventBusError::LimitExceeded(err.msg), &res)
                 }
                 "ResourceAlreadyExistsException" => {
-                    return RusotoError::Service(CreateEventBusError::ResourceAlreadyExists(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateEventBusError::ResourceAlreadyExists(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(CreateEventBusError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        CreateEventBusError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreateEventBusError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1312,12 +1328,14 @@ impl fmt::Display for CreateEventBusError {
             CreateEventBusError::LimitExceeded(ref cause) => write!(f, "{}", cause),
             CreateEventBusError::ResourceAlreadyExists(ref cause) => write!(f, "{}", cause),
             CreateEventBusError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            CreateEventBusError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateEventBusError {}
 /// Errors returned by CreatePartnerEventSource
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreatePartnerEventSourceError {
     /// <p>There is concurrent modification on a resource.</p>
     ConcurrentModification(String),
@@ -1327,6 +1345,8 @@ pub enum CreatePartnerEventSourceError {
     LimitExceeded(String),
     /// <p>The resource that you're trying to create already exists.</p>
     ResourceAlreadyExists(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreatePartnerEventSourceError {
@@ -1334,25 +1354,31 @@ impl CreatePartnerEventSourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         CreatePartnerEventSourceError::ConcurrentModification(err.msg),
+                        &res,
                     )
                 }
                 "InternalException" => {
-                    return RusotoError::Service(CreatePartnerEventSourceError::Internal(err.msg))
+                    return RusotoError::service(
+                        CreatePartnerEventSourceError::Internal(err.msg),
+                        &res,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreatePartnerEventSourceError::LimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreatePartnerEventSourceError::LimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceAlreadyExistsException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         CreatePartnerEventSourceError::ResourceAlreadyExists(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreatePartnerEventSourceError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1370,12 +1396,14 @@ impl fmt::Display for CreatePartnerEventSourceError {
             CreatePartnerEventSourceError::ResourceAlreadyExists(ref cause) => {
                 write!(f, "{}", cause)
             }
+            CreatePartnerEventSourceError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreatePartnerEventSourceError {}
 /// Errors returned by DeactivateEventSource
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeactivateEventSourceError {
     /// <p>This exception occurs due to unexpected causes.</p>
     Internal(String),
@@ -1383,6 +1411,8 @@ pub enum DeactivateEventSourceError {
     InvalidState(String),
     /// <p>An entity that you specified doesn't exist.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeactivateEventSourceError {
@@ -1390,18 +1420,25 @@ impl DeactivateEventSourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalException" => {
-                    return RusotoError::Service(DeactivateEventSourceError::Internal(err.msg))
+                    return RusotoError::service(
+                        DeactivateEventSourceError::Internal(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidStateException" => {
-                    return RusotoError::Service(DeactivateEventSourceError::InvalidState(err.msg))
+                    return RusotoError::service(
+                        DeactivateEventSourceError::InvalidState(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeactivateEventSourceError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeactivateEventSourceError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeactivateEventSourceError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1414,15 +1451,19 @@ impl fmt::Display for DeactivateEventSourceError {
             DeactivateEventSourceError::Internal(ref cause) => write!(f, "{}", cause),
             DeactivateEventSourceError::InvalidState(ref cause) => write!(f, "{}", cause),
             DeactivateEventSourceError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            DeactivateEventSourceError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeactivateEventSourceError {}
 /// Errors returned by DeleteEventBus
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteEventBusError {
     /// <p>This exception occurs due to unexpected causes.</p>
     Internal(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteEventBusError {
@@ -1430,10 +1471,10 @@ impl DeleteEventBusError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalException" => {
-                    return RusotoError::Service(DeleteEventBusError::Internal(err.msg))
+                    return RusotoError::service(DeleteEventBusError::Internal(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteEventBusError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1444,15 +1485,19 @@ impl fmt::Display for DeleteEventBusError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             DeleteEventBusError::Internal(ref cause) => write!(f, "{}", cause),
+            DeleteEventBusError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteEventBusError {}
 /// Errors returned by DeletePartnerEventSource
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeletePartnerEventSourceError {
     /// <p>This exception occurs due to unexpected causes.</p>
     Internal(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeletePartnerEventSourceError {
@@ -1460,10 +1505,13 @@ impl DeletePartnerEventSourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalException" => {
-                    return RusotoError::Service(DeletePartnerEventSourceError::Internal(err.msg))
+                    return RusotoError::service(
+                        DeletePartnerEventSourceError::Internal(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeletePartnerEventSourceError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1474,12 +1522,14 @@ impl fmt::Display for DeletePartnerEventSourceError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             DeletePartnerEventSourceError::Internal(ref cause) => write!(f, "{}", cause),
+            DeletePartnerEventSourceError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeletePartnerEventSourceError {}
 /// Errors returned by DeleteRule
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteRuleError {
     /// <p>There is concurrent modification on a resource.</p>
     ConcurrentModification(String),
@@ -1489,6 +1539,8 @@ pub enum DeleteRuleError {
     ManagedRule(String),
     /// <p>An entity that you specified doesn't exist.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteRuleError {
@@ -1496,19 +1548,22 @@ impl DeleteRuleError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(DeleteRuleError::ConcurrentModification(err.msg))
+                    return RusotoError::service(
+                        DeleteRuleError::ConcurrentModification(err.msg),
+                        &res,
+                    )
                 }
                 "InternalException" => {
-                    return RusotoError::Service(DeleteRuleError::Internal(err.msg))
+                    return RusotoError::service(DeleteRuleError::Internal(err.msg), &res)
                 }
                 "ManagedRuleException" => {
-                    return RusotoError::Service(DeleteRuleError::ManagedRule(err.msg))
+                    return RusotoError::service(DeleteRuleError::ManagedRule(err.msg), &res)
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteRuleError::ResourceNotFound(err.msg))
+                    return RusotoError::service(DeleteRuleError::ResourceNotFound(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteRuleError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1522,17 +1577,21 @@ impl fmt::Display for DeleteRuleError {
             DeleteRuleError::Internal(ref cause) => write!(f, "{}", cause),
             DeleteRuleError::ManagedRule(ref cause) => write!(f, "{}", cause),
             DeleteRuleError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            DeleteRuleError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteRuleError {}
 /// Errors returned by DescribeEventBus
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeEventBusError {
     /// <p>This exception occurs due to unexpected causes.</p>
     Internal(String),
     /// <p>An entity that you specified doesn't exist.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeEventBusError {
@@ -1540,13 +1599,16 @@ impl DescribeEventBusError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalException" => {
-                    return RusotoError::Service(DescribeEventBusError::Internal(err.msg))
+                    return RusotoError::service(DescribeEventBusError::Internal(err.msg), &res)
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeEventBusError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        DescribeEventBusError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DescribeEventBusError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1558,17 +1620,21 @@ impl fmt::Display for DescribeEventBusError {
         match *self {
             DescribeEventBusError::Internal(ref cause) => write!(f, "{}", cause),
             DescribeEventBusError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            DescribeEventBusError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeEventBusError {}
 /// Errors returned by DescribeEventSource
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeEventSourceError {
     /// <p>This exception occurs due to unexpected causes.</p>
     Internal(String),
     /// <p>An entity that you specified doesn't exist.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeEventSourceError {
@@ -1576,15 +1642,16 @@ impl DescribeEventSourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalException" => {
-                    return RusotoError::Service(DescribeEventSourceError::Internal(err.msg))
+                    return RusotoError::service(DescribeEventSourceError::Internal(err.msg), &res)
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeEventSourceError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeEventSourceError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DescribeEventSourceError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1596,17 +1663,21 @@ impl fmt::Display for DescribeEventSourceError {
         match *self {
             DescribeEventSourceError::Internal(ref cause) => write!(f, "{}", cause),
             DescribeEventSourceError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            DescribeEventSourceError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeEventSourceError {}
 /// Errors returned by DescribePartnerEventSource
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribePartnerEventSourceError {
     /// <p>This exception occurs due to unexpected causes.</p>
     Internal(String),
     /// <p>An entity that you specified doesn't exist.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribePartnerEventSourceError {
@@ -1616,15 +1687,21 @@ impl DescribePartnerEventSourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalException" => {
-                    return RusotoError::Service(DescribePartnerEventSourceError::Internal(err.msg))
+                    return RusotoError::service(
+                        DescribePartnerEventSourceError::Internal(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribePartnerEventSourceError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribePartnerEventSourceError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(DescribePartnerEventSourceError::Unhandled, &res)
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -1636,17 +1713,21 @@ impl fmt::Display for DescribePartnerEventSourceError {
         match *self {
             DescribePartnerEventSourceError::Internal(ref cause) => write!(f, "{}", cause),
             DescribePartnerEventSourceError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            DescribePartnerEventSourceError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribePartnerEventSourceError {}
 /// Errors returned by DescribeRule
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeRuleError {
     /// <p>This exception occurs due to unexpected causes.</p>
     Internal(String),
     /// <p>An entity that you specified doesn't exist.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeRuleError {
@@ -1654,13 +1735,13 @@ impl DescribeRuleError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalException" => {
-                    return RusotoError::Service(DescribeRuleError::Internal(err.msg))
+                    return RusotoError::service(DescribeRuleError::Internal(err.msg), &res)
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeRuleError::ResourceNotFound(err.msg))
+                    return RusotoError::service(DescribeRuleError::ResourceNotFound(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DescribeRuleError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1672,12 +1753,14 @@ impl fmt::Display for DescribeRuleError {
         match *self {
             DescribeRuleError::Internal(ref cause) => write!(f, "{}", cause),
             DescribeRuleError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            DescribeRuleError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeRuleError {}
 /// Errors returned by DisableRule
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DisableRuleError {
     /// <p>There is concurrent modification on a resource.</p>
     ConcurrentModification(String),
@@ -1687,6 +1770,8 @@ pub enum DisableRuleError {
     ManagedRule(String),
     /// <p>An entity that you specified doesn't exist.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DisableRuleError {
@@ -1694,19 +1779,22 @@ impl DisableRuleError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(DisableRuleError::ConcurrentModification(err.msg))
+                    return RusotoError::service(
+                        DisableRuleError::ConcurrentModification(err.msg),
+                        &res,
+                    )
                 }
                 "InternalException" => {
-                    return RusotoError::Service(DisableRuleError::Internal(err.msg))
+                    return RusotoError::service(DisableRuleError::Internal(err.msg), &res)
                 }
                 "ManagedRuleException" => {
-                    return RusotoError::Service(DisableRuleError::ManagedRule(err.msg))
+                    return RusotoError::service(DisableRuleError::ManagedRule(err.msg), &res)
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DisableRuleError::ResourceNotFound(err.msg))
+                    return RusotoError::service(DisableRuleError::ResourceNotFound(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DisableRuleError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1720,12 +1808,14 @@ impl fmt::Display for DisableRuleError {
             DisableRuleError::Internal(ref cause) => write!(f, "{}", cause),
             DisableRuleError::ManagedRule(ref cause) => write!(f, "{}", cause),
             DisableRuleError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            DisableRuleError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DisableRuleError {}
 /// Errors returned by EnableRule
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum EnableRuleError {
     /// <p>There is concurrent modification on a resource.</p>
     ConcurrentModification(String),
@@ -1735,6 +1825,8 @@ pub enum EnableRuleError {
     ManagedRule(String),
     /// <p>An entity that you specified doesn't exist.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl EnableRuleError {
@@ -1742,19 +1834,22 @@ impl EnableRuleError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(EnableRuleError::ConcurrentModification(err.msg))
+                    return RusotoError::service(
+                        EnableRuleError::ConcurrentModification(err.msg),
+                        &res,
+                    )
                 }
                 "InternalException" => {
-                    return RusotoError::Service(EnableRuleError::Internal(err.msg))
+                    return RusotoError::service(EnableRuleError::Internal(err.msg), &res)
                 }
                 "ManagedRuleException" => {
-                    return RusotoError::Service(EnableRuleError::ManagedRule(err.msg))
+                    return RusotoError::service(EnableRuleError::ManagedRule(err.msg), &res)
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(EnableRuleError::ResourceNotFound(err.msg))
+                    return RusotoError::service(EnableRuleError::ResourceNotFound(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(EnableRuleError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1768,15 +1863,19 @@ impl fmt::Display for EnableRuleError {
             EnableRuleError::Internal(ref cause) => write!(f, "{}", cause),
             EnableRuleError::ManagedRule(ref cause) => write!(f, "{}", cause),
             EnableRuleError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            EnableRuleError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for EnableRuleError {}
 /// Errors returned by ListEventBuses
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListEventBusesError {
     /// <p>This exception occurs due to unexpected causes.</p>
     Internal(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListEventBusesError {
@@ -1784,10 +1883,10 @@ impl ListEventBusesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalException" => {
-                    return RusotoError::Service(ListEventBusesError::Internal(err.msg))
+                    return RusotoError::service(ListEventBusesError::Internal(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListEventBusesError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1798,15 +1897,19 @@ impl fmt::Display for ListEventBusesError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             ListEventBusesError::Internal(ref cause) => write!(f, "{}", cause),
+            ListEventBusesError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListEventBusesError {}
 /// Errors returned by ListEventSources
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListEventSourcesError {
     /// <p>This exception occurs due to unexpected causes.</p>
     Internal(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListEventSourcesError {
@@ -1814,10 +1917,10 @@ impl ListEventSourcesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalException" => {
-                    return RusotoError::Service(ListEventSourcesError::Internal(err.msg))
+                    return RusotoError::service(ListEventSourcesError::Internal(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListEventSourcesError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1828,17 +1931,21 @@ impl fmt::Display for ListEventSourcesError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             ListEventSourcesError::Internal(ref cause) => write!(f, "{}", cause),
+            ListEventSourcesError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListEventSourcesError {}
 /// Errors returned by ListPartnerEventSourceAccounts
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListPartnerEventSourceAccountsError {
     /// <p>This exception occurs due to unexpected causes.</p>
     Internal(String),
     /// <p>An entity that you specified doesn't exist.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListPartnerEventSourceAccountsError {
@@ -1848,17 +1955,24 @@ impl ListPartnerEventSourceAccountsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalException" => {
-                    return RusotoError::Service(ListPartnerEventSourceAccountsError::Internal(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListPartnerEventSourceAccountsError::Internal(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         ListPartnerEventSourceAccountsError::ResourceNotFound(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        ListPartnerEventSourceAccountsError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -1872,15 +1986,21 @@ impl fmt::Display for ListPartnerEventSourceAccountsError {
             ListPartnerEventSourceAccountsError::ResourceNotFound(ref cause) => {
                 write!(f, "{}", cause)
             }
+            ListPartnerEventSourceAccountsError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for ListPartnerEventSourceAccountsError {}
 /// Errors returned by ListPartnerEventSources
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListPartnerEventSourcesError {
     /// <p>This exception occurs due to unexpected causes.</p>
     Internal(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListPartnerEventSourcesError {
@@ -1888,10 +2008,13 @@ impl ListPartnerEventSourcesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalException" => {
-                    return RusotoError::Service(ListPartnerEventSourcesError::Internal(err.msg))
+                    return RusotoError::service(
+                        ListPartnerEventSourcesError::Internal(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListPartnerEventSourcesError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1902,17 +2025,21 @@ impl fmt::Display for ListPartnerEventSourcesError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             ListPartnerEventSourcesError::Internal(ref cause) => write!(f, "{}", cause),
+            ListPartnerEventSourcesError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListPartnerEventSourcesError {}
 /// Errors returned by ListRuleNamesByTarget
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListRuleNamesByTargetError {
     /// <p>This exception occurs due to unexpected causes.</p>
     Internal(String),
     /// <p>An entity that you specified doesn't exist.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListRuleNamesByTargetError {
@@ -1920,15 +2047,19 @@ impl ListRuleNamesByTargetError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalException" => {
-                    return RusotoError::Service(ListRuleNamesByTargetError::Internal(err.msg))
+                    return RusotoError::service(
+                        ListRuleNamesByTargetError::Internal(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListRuleNamesByTargetError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListRuleNamesByTargetError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListRuleNamesByTargetError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1940,17 +2071,21 @@ impl fmt::Display for ListRuleNamesByTargetError {
         match *self {
             ListRuleNamesByTargetError::Internal(ref cause) => write!(f, "{}", cause),
             ListRuleNamesByTargetError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            ListRuleNamesByTargetError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListRuleNamesByTargetError {}
 /// Errors returned by ListRules
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListRulesError {
     /// <p>This exception occurs due to unexpected causes.</p>
     Internal(String),
     /// <p>An entity that you specified doesn't exist.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListRulesError {
@@ -1958,13 +2093,13 @@ impl ListRulesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalException" => {
-                    return RusotoError::Service(ListRulesError::Internal(err.msg))
+                    return RusotoError::service(ListRulesError::Internal(err.msg), &res)
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListRulesError::ResourceNotFound(err.msg))
+                    return RusotoError::service(ListRulesError::ResourceNotFound(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListRulesError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1976,17 +2111,21 @@ impl fmt::Display for ListRulesError {
         match *self {
             ListRulesError::Internal(ref cause) => write!(f, "{}", cause),
             ListRulesError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            ListRulesError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListRulesError {}
 /// Errors returned by ListTagsForResource
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListTagsForResourceError {
     /// <p>This exception occurs due to unexpected causes.</p>
     Internal(String),
     /// <p>An entity that you specified doesn't exist.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListTagsForResourceError {
@@ -1994,15 +2133,16 @@ impl ListTagsForResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalException" => {
-                    return RusotoError::Service(ListTagsForResourceError::Internal(err.msg))
+                    return RusotoError::service(ListTagsForResourceError::Internal(err.msg), &res)
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListTagsForResourceError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListTagsForResourceError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListTagsForResourceError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2014,17 +2154,21 @@ impl fmt::Display for ListTagsForResourceError {
         match *self {
             ListTagsForResourceError::Internal(ref cause) => write!(f, "{}", cause),
             ListTagsForResourceError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            ListTagsForResourceError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListTagsForResourceError {}
 /// Errors returned by ListTargetsByRule
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListTargetsByRuleError {
     /// <p>This exception occurs due to unexpected causes.</p>
     Internal(String),
     /// <p>An entity that you specified doesn't exist.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListTargetsByRuleError {
@@ -2032,13 +2176,16 @@ impl ListTargetsByRuleError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalException" => {
-                    return RusotoError::Service(ListTargetsByRuleError::Internal(err.msg))
+                    return RusotoError::service(ListTargetsByRuleError::Internal(err.msg), &res)
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListTargetsByRuleError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        ListTargetsByRuleError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListTargetsByRuleError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2050,15 +2197,19 @@ impl fmt::Display for ListTargetsByRuleError {
         match *self {
             ListTargetsByRuleError::Internal(ref cause) => write!(f, "{}", cause),
             ListTargetsByRuleError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            ListTargetsByRuleError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListTargetsByRuleError {}
 /// Errors returned by PutEvents
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum PutEventsError {
     /// <p>This exception occurs due to unexpected causes.</p>
     Internal(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl PutEventsError {
@@ -2066,10 +2217,10 @@ impl PutEventsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalException" => {
-                    return RusotoError::Service(PutEventsError::Internal(err.msg))
+                    return RusotoError::service(PutEventsError::Internal(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(PutEventsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2080,15 +2231,19 @@ impl fmt::Display for PutEventsError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             PutEventsError::Internal(ref cause) => write!(f, "{}", cause),
+            PutEventsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for PutEventsError {}
 /// Errors returned by PutPartnerEvents
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum PutPartnerEventsError {
     /// <p>This exception occurs due to unexpected causes.</p>
     Internal(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl PutPartnerEventsError {
@@ -2096,10 +2251,10 @@ impl PutPartnerEventsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalException" => {
-                    return RusotoError::Service(PutPartnerEventsError::Internal(err.msg))
+                    return RusotoError::service(PutPartnerEventsError::Internal(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(PutPartnerEventsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2110,12 +2265,14 @@ impl fmt::Display for PutPartnerEventsError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             PutPartnerEventsError::Internal(ref cause) => write!(f, "{}", cause),
+            PutPartnerEventsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for PutPartnerEventsError {}
 /// Errors returned by PutPermission
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum PutPermissionError {
     /// <p>There is concurrent modification on a resource.</p>
     ConcurrentModification(String),
@@ -2125,6 +2282,8 @@ pub enum PutPermissionError {
     PolicyLengthExceeded(String),
     /// <p>An entity that you specified doesn't exist.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl PutPermissionError {
@@ -2132,21 +2291,28 @@ impl PutPermissionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(PutPermissionError::ConcurrentModification(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        PutPermissionError::ConcurrentModification(err.msg),
+                        &res,
+                    )
                 }
                 "InternalException" => {
-                    return RusotoError::Service(PutPermissionError::Internal(err.msg))
+                    return RusotoError::service(PutPermissionError::Internal(err.msg), &res)
                 }
                 "PolicyLengthExceededException" => {
-                    return RusotoError::Service(PutPermissionError::PolicyLengthExceeded(err.msg))
+                    return RusotoError::service(
+                        PutPermissionError::PolicyLengthExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(PutPermissionError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        PutPermissionError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(PutPermissionError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2160,12 +2326,14 @@ impl fmt::Display for PutPermissionError {
             PutPermissionError::Internal(ref cause) => write!(f, "{}", cause),
             PutPermissionError::PolicyLengthExceeded(ref cause) => write!(f, "{}", cause),
             PutPermissionError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            PutPermissionError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for PutPermissionError {}
 /// Errors returned by PutRule
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum PutRuleError {
     /// <p>There is concurrent modification on a resource.</p>
     ConcurrentModification(String),
@@ -2179,6 +2347,8 @@ pub enum PutRuleError {
     ManagedRule(String),
     /// <p>An entity that you specified doesn't exist.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl PutRuleError {
@@ -2186,25 +2356,28 @@ impl PutRuleError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(PutRuleError::ConcurrentModification(err.msg))
+                    return RusotoError::service(
+                        PutRuleError::ConcurrentModification(err.msg),
+                        &res,
+                    )
                 }
                 "InternalException" => {
-                    return RusotoError::Service(PutRuleError::Internal(err.msg))
+                    return RusotoError::service(PutRuleError::Internal(err.msg), &res)
                 }
                 "InvalidEventPatternException" => {
-                    return RusotoError::Service(PutRuleError::InvalidEventPattern(err.msg))
+                    return RusotoError::service(PutRuleError::InvalidEventPattern(err.msg), &res)
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(PutRuleError::LimitExceeded(err.msg))
+                    return RusotoError::service(PutRuleError::LimitExceeded(err.msg), &res)
                 }
                 "ManagedRuleException" => {
-                    return RusotoError::Service(PutRuleError::ManagedRule(err.msg))
+                    return RusotoError::service(PutRuleError::ManagedRule(err.msg), &res)
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(PutRuleError::ResourceNotFound(err.msg))
+                    return RusotoError::service(PutRuleError::ResourceNotFound(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(PutRuleError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2220,12 +2393,14 @@ impl fmt::Display for PutRuleError {
             PutRuleError::LimitExceeded(ref cause) => write!(f, "{}", cause),
             PutRuleError::ManagedRule(ref cause) => write!(f, "{}", cause),
             PutRuleError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            PutRuleError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for PutRuleError {}
 /// Errors returned by PutTargets
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum PutTargetsError {
     /// <p>There is concurrent modification on a resource.</p>
     ConcurrentModification(String),
@@ -2237,6 +2412,8 @@ pub enum PutTargetsError {
     ManagedRule(String),
     /// <p>An entity that you specified doesn't exist.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl PutTargetsError {
@@ -2244,22 +2421,25 @@ impl PutTargetsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(PutTargetsError::ConcurrentModification(err.msg))
+                    return RusotoError::service(
+                        PutTargetsError::ConcurrentModification(err.msg),
+                        &res,
+                    )
                 }
                 "InternalException" => {
-                    return RusotoError::Service(PutTargetsError::Internal(err.msg))
+                    return RusotoError::service(PutTargetsError::Internal(err.msg), &res)
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(PutTargetsError::LimitExceeded(err.msg))
+                    return RusotoError::service(PutTargetsError::LimitExceeded(err.msg), &res)
                 }
                 "ManagedRuleException" => {
-                    return RusotoError::Service(PutTargetsError::ManagedRule(err.msg))
+                    return RusotoError::service(PutTargetsError::ManagedRule(err.msg), &res)
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(PutTargetsError::ResourceNotFound(err.msg))
+                    return RusotoError::service(PutTargetsError::ResourceNotFound(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(PutTargetsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2274,12 +2454,14 @@ impl fmt::Display for PutTargetsError {
             PutTargetsError::LimitExceeded(ref cause) => write!(f, "{}", cause),
             PutTargetsError::ManagedRule(ref cause) => write!(f, "{}", cause),
             PutTargetsError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            PutTargetsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for PutTargetsError {}
 /// Errors returned by RemovePermission
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum RemovePermissionError {
     /// <p>There is concurrent modification on a resource.</p>
     ConcurrentModification(String),
@@ -2287,6 +2469,8 @@ pub enum RemovePermissionError {
     Internal(String),
     /// <p>An entity that you specified doesn't exist.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl RemovePermissionError {
@@ -2294,18 +2478,22 @@ impl RemovePermissionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(RemovePermissionError::ConcurrentModification(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        RemovePermissionError::ConcurrentModification(err.msg),
+                        &res,
+                    )
                 }
                 "InternalException" => {
-                    return RusotoError::Service(RemovePermissionError::Internal(err.msg))
+                    return RusotoError::service(RemovePermissionError::Internal(err.msg), &res)
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(RemovePermissionError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        RemovePermissionError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(RemovePermissionError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2318,12 +2506,14 @@ impl fmt::Display for RemovePermissionError {
             RemovePermissionError::ConcurrentModification(ref cause) => write!(f, "{}", cause),
             RemovePermissionError::Internal(ref cause) => write!(f, "{}", cause),
             RemovePermissionError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            RemovePermissionError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for RemovePermissionError {}
 /// Errors returned by RemoveTargets
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum RemoveTargetsError {
     /// <p>There is concurrent modification on a resource.</p>
     ConcurrentModification(String),
@@ -2333,6 +2523,8 @@ pub enum RemoveTargetsError {
     ManagedRule(String),
     /// <p>An entity that you specified doesn't exist.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl RemoveTargetsError {
@@ -2340,21 +2532,25 @@ impl RemoveTargetsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(RemoveTargetsError::ConcurrentModification(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        RemoveTargetsError::ConcurrentModification(err.msg),
+                        &res,
+                    )
                 }
                 "InternalException" => {
-                    return RusotoError::Service(RemoveTargetsError::Internal(err.msg))
+                    return RusotoError::service(RemoveTargetsError::Internal(err.msg), &res)
                 }
                 "ManagedRuleException" => {
-                    return RusotoError::Service(RemoveTargetsError::ManagedRule(err.msg))
+                    return RusotoError::service(RemoveTargetsError::ManagedRule(err.msg), &res)
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(RemoveTargetsError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        RemoveTargetsError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(RemoveTargetsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2368,12 +2564,14 @@ impl fmt::Display for RemoveTargetsError {
             RemoveTargetsError::Internal(ref cause) => write!(f, "{}", cause),
             RemoveTargetsError::ManagedRule(ref cause) => write!(f, "{}", cause),
             RemoveTargetsError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            RemoveTargetsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for RemoveTargetsError {}
 /// Errors returned by TagResource
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum TagResourceError {
     /// <p>There is concurrent modification on a resource.</p>
     ConcurrentModification(String),
@@ -2383,6 +2581,8 @@ pub enum TagResourceError {
     ManagedRule(String),
     /// <p>An entity that you specified doesn't exist.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl TagResourceError {
@@ -2390,19 +2590,22 @@ impl TagResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(TagResourceError::ConcurrentModification(err.msg))
+                    return RusotoError::service(
+                        TagResourceError::ConcurrentModification(err.msg),
+                        &res,
+                    )
                 }
                 "InternalException" => {
-                    return RusotoError::Service(TagResourceError::Internal(err.msg))
+                    return RusotoError::service(TagResourceError::Internal(err.msg), &res)
                 }
                 "ManagedRuleException" => {
-                    return RusotoError::Service(TagResourceError::ManagedRule(err.msg))
+                    return RusotoError::service(TagResourceError::ManagedRule(err.msg), &res)
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(TagResourceError::ResourceNotFound(err.msg))
+                    return RusotoError::service(TagResourceError::ResourceNotFound(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(TagResourceError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2416,17 +2619,21 @@ impl fmt::Display for TagResourceError {
             TagResourceError::Internal(ref cause) => write!(f, "{}", cause),
             TagResourceError::ManagedRule(ref cause) => write!(f, "{}", cause),
             TagResourceError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            TagResourceError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for TagResourceError {}
 /// Errors returned by TestEventPattern
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum TestEventPatternError {
     /// <p>This exception occurs due to unexpected causes.</p>
     Internal(String),
     /// <p>The event pattern isn't valid.</p>
     InvalidEventPattern(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl TestEventPatternError {
@@ -2434,15 +2641,16 @@ impl TestEventPatternError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalException" => {
-                    return RusotoError::Service(TestEventPatternError::Internal(err.msg))
+                    return RusotoError::service(TestEventPatternError::Internal(err.msg), &res)
                 }
                 "InvalidEventPatternException" => {
-                    return RusotoError::Service(TestEventPatternError::InvalidEventPattern(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        TestEventPatternError::InvalidEventPattern(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(TestEventPatternError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2454,12 +2662,14 @@ impl fmt::Display for TestEventPatternError {
         match *self {
             TestEventPatternError::Internal(ref cause) => write!(f, "{}", cause),
             TestEventPatternError::InvalidEventPattern(ref cause) => write!(f, "{}", cause),
+            TestEventPatternError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for TestEventPatternError {}
 /// Errors returned by UntagResource
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UntagResourceError {
     /// <p>There is concurrent modification on a resource.</p>
     ConcurrentModification(String),
@@ -2469,6 +2679,8 @@ pub enum UntagResourceError {
     ManagedRule(String),
     /// <p>An entity that you specified doesn't exist.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UntagResourceError {
@@ -2476,21 +2688,25 @@ impl UntagResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(UntagResourceError::ConcurrentModification(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UntagResourceError::ConcurrentModification(err.msg),
+                        &res,
+                    )
                 }
                 "InternalException" => {
-                    return RusotoError::Service(UntagResourceError::Internal(err.msg))
+                    return RusotoError::service(UntagResourceError::Internal(err.msg), &res)
                 }
                 "ManagedRuleException" => {
-                    return RusotoError::Service(UntagResourceError::ManagedRule(err.msg))
+                    return RusotoError::service(UntagResourceError::ManagedRule(err.msg), &res)
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UntagResourceError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        UntagResourceError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UntagResourceError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2504,6 +2720,7 @@ impl fmt::Display for UntagResourceError {
             UntagResourceError::Internal(ref cause) => write!(f, "{}", cause),
             UntagResourceError::ManagedRule(ref cause) => write!(f, "{}", cause),
             UntagResourceError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            UntagResourceError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
@@ -2711,6 +2928,7 @@ impl EventBridgeClient {
     /// Creates a client backed by the default tokio event loop.
     ///
     /// The client will use the default credentials provider and tls client.
+    #[cfg(not(target_arch = "wasm32"))]
     pub fn new(region: region::Region) -> EventBridgeClient {
         EventBridgeClient {
             client: Client::shared(),
@@ -2736,860 +2954,2072 @@ impl EventBridgeClient {
     pub fn new_with_client(client: Client, region: region::Region) -> EventBridgeClient {
         EventBridgeClient { client, region }
     }
+
+    /// Returns a builder of a client, setting any of its region, credentials provider,
+    /// dispatcher, retry policy, endpoint and timeouts.
+    pub fn builder() -> rusoto_core::ClientBuilder<EventBridgeClient> {
+        rusoto_core::ClientBuilder::new(EventBridgeClient::new_with_client)
+    }
+
+    /// Returns a clone of this client with the overrides of `config` applied to its
+    /// operations, sharing its connections.
+    pub fn customize(&self, config: &rusoto_core::OperationConfig) -> EventBridgeClient {
+        EventBridgeClient {
+            client: self.client.customize(config),
+            region: config.resolve_region(&self.region),
+        }
+    }
 }
 
-#[async_trait]
-impl EventBridge for EventBridgeClient {
-    /// <p><p>Activates a partner event source that has been deactivated. Once activated, your matching event bus will start receiving events from the event source.</p> <note> <p>This operation is performed by AWS customers, not by SaaS partners.</p> </note></p>
-    async fn activate_event_source(
+impl EventBridgeClient {
+    /// Calls `activate_event_source`, also returning the metadata of the response.
+    pub async fn activate_event_source_with_metadata(
         &self,
         input: ActivateEventSourceRequest,
-    ) -> Result<(), RusotoError<ActivateEventSourceError>> {
+    ) -> Result<((), ResponseMetadata), RusotoError<ActivateEventSourceError>> {
+        let request = self.build_activate_event_source_request(input);
+
+        let mut response = self
+            .client
+            .sign_and_dispatch(request)
+            .await
+            .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
+        if response.status.is_success() {
+            std::mem::drop(response);
+            Ok(((), metadata))
+        } else {
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ActivateEventSourceError::from_response(response))
+        }
+    }
+
+    /// Presigns the request `activate_event_source` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_activate_event_source(
+        &self,
+        presigner: &rusoto_core::Presigner,
+        input: ActivateEventSourceRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_activate_event_source_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_activate_event_source_request(
+        &self,
+        input: ActivateEventSourceRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "events", &self.region, "/");
+        request.set_operation("ActivateEventSource");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSEvents.ActivateEventSource");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `create_event_bus`, also returning the metadata of the response.
+    pub async fn create_event_bus_with_metadata(
+        &self,
+        input: CreateEventBusRequest,
+    ) -> Result<(CreateEventBusResponse, ResponseMetadata), RusotoError<CreateEventBusError>> {
+        let request = self.build_create_event_bus_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            std::mem::drop(response);
-            Ok(())
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<CreateEventBusResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ActivateEventSourceError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(CreateEventBusError::from_response(response))
         }
     }
 
-    /// <p><p>Creates a new event bus within your account. This can be a custom event bus which you can use to receive events from your own custom applications and services, or it can be a partner event bus which can be matched to a partner event source.</p> <note> <p>This operation is used by AWS customers, not by SaaS partners.</p> </note></p>
-    async fn create_event_bus(
+    /// Presigns the request `create_event_bus` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_event_bus(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: CreateEventBusRequest,
-    ) -> Result<CreateEventBusResponse, RusotoError<CreateEventBusError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_event_bus_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_event_bus_request(&self, input: CreateEventBusRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "events", &self.region, "/");
+        request.set_operation("CreateEventBus");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSEvents.CreateEventBus");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `create_partner_event_source`, also returning the metadata of the response.
+    pub async fn create_partner_event_source_with_metadata(
+        &self,
+        input: CreatePartnerEventSourceRequest,
+    ) -> Result<
+        (CreatePartnerEventSourceResponse, ResponseMetadata),
+        RusotoError<CreatePartnerEventSourceError>,
+    > {
+        let request = self.build_create_partner_event_source_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<CreateEventBusResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<CreatePartnerEventSourceResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(CreateEventBusError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(CreatePartnerEventSourceError::from_response(response))
         }
     }
 
-    /// <p><p>Called by an SaaS partner to create a partner event source.</p> <note> <p>This operation is not used by AWS customers.</p> </note> <p>Each partner event source can be used by one AWS account to create a matching partner event bus in that AWS account. A SaaS partner must create one partner event source for each AWS account that wants to receive those event types. </p> <p>A partner event source creates events based on resources in the SaaS partner&#39;s service or application.</p> <p>An AWS account that creates a partner event bus that matches the partner event source can use that event bus to receive events from the partner, and then process them using AWS Events rules and targets.</p> <p>Partner event source names follow this format:</p> <p> <code>aws.partner/<i>partner<em>name</i>/<i>event</em>namespace</i>/<i>event<em>name</i> </code> </p> <ul> <li> <p> <i>partner</em>name</i> is determined during partner registration and identifies the partner to AWS customers.</p> </li> <li> <p>For <i>event<em>namespace</i>, we recommend that partners use a string that identifies the AWS customer within the partner&#39;s system. This should not be the customer&#39;s AWS account ID.</p> </li> <li> <p> <i>event</em>name</i> is determined by the partner, and should uniquely identify an event-generating resource within the partner system. This should help AWS customers decide whether to create an event bus to receive these events.</p> </li> </ul></p>
-    async fn create_partner_event_source(
+    /// Presigns the request `create_partner_event_source` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_partner_event_source(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: CreatePartnerEventSourceRequest,
-    ) -> Result<CreatePartnerEventSourceResponse, RusotoError<CreatePartnerEventSourceError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_partner_event_source_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_partner_event_source_request(
+        &self,
+        input: CreatePartnerEventSourceRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "events", &self.region, "/");
+        request.set_operation("CreatePartnerEventSource");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSEvents.CreatePartnerEventSource");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `deactivate_event_source`, also returning the metadata of the response.
+    pub async fn deactivate_event_source_with_metadata(
+        &self,
+        input: DeactivateEventSourceRequest,
+    ) -> Result<((), ResponseMetadata), RusotoError<DeactivateEventSourceError>> {
+        let request = self.build_deactivate_event_source_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<CreatePartnerEventSourceResponse, _>()
+            std::mem::drop(response);
+            Ok(((), metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(CreatePartnerEventSourceError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DeactivateEventSourceError::from_response(response))
         }
     }
 
-    /// <p>An AWS customer uses this operation to temporarily stop receiving events from the specified partner event source. The matching event bus isn't deleted. </p> <p>When you deactivate a partner event source, the source goes into <code>PENDING</code> state. If it remains in <code>PENDING</code> state for more than two weeks, it's deleted.</p> <p>To activate a deactivated partner event source, use <a>ActivateEventSource</a>.</p>
-    async fn deactivate_event_source(
+    /// Presigns the request `deactivate_event_source` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_deactivate_event_source(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeactivateEventSourceRequest,
-    ) -> Result<(), RusotoError<DeactivateEventSourceError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_deactivate_event_source_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_deactivate_event_source_request(
+        &self,
+        input: DeactivateEventSourceRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "events", &self.region, "/");
+        request.set_operation("DeactivateEventSource");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSEvents.DeactivateEventSource");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `delete_event_bus`, also returning the metadata of the response.
+    pub async fn delete_event_bus_with_metadata(
+        &self,
+        input: DeleteEventBusRequest,
+    ) -> Result<((), ResponseMetadata), RusotoError<DeleteEventBusError>> {
+        let request = self.build_delete_event_bus_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
             std::mem::drop(response);
-            Ok(())
+            Ok(((), metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DeactivateEventSourceError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DeleteEventBusError::from_response(response))
         }
     }
 
-    /// <p><p>Deletes the specified custom event bus or partner event bus. All rules associated with this event bus are also deleted. You can&#39;t delete your account&#39;s default event bus.</p> <note> <p>This operation is performed by AWS customers, not by SaaS partners.</p> </note></p>
-    async fn delete_event_bus(
+    /// Presigns the request `delete_event_bus` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_event_bus(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteEventBusRequest,
-    ) -> Result<(), RusotoError<DeleteEventBusError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_event_bus_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_event_bus_request(&self, input: DeleteEventBusRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "events", &self.region, "/");
+        request.set_operation("DeleteEventBus");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSEvents.DeleteEventBus");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `delete_partner_event_source`, also returning the metadata of the response.
+    pub async fn delete_partner_event_source_with_metadata(
+        &self,
+        input: DeletePartnerEventSourceRequest,
+    ) -> Result<((), ResponseMetadata), RusotoError<DeletePartnerEventSourceError>> {
+        let request = self.build_delete_partner_event_source_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
             std::mem::drop(response);
-            Ok(())
+            Ok(((), metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteEventBusError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DeletePartnerEventSourceError::from_response(response))
         }
     }
 
-    /// <p>This operation is used by SaaS partners to delete a partner event source. AWS customers don't use this operation.</p> <p>When you delete an event source, the status of the corresponding partner event bus in the AWS customer account becomes <code>DELETED</code>.</p>
-    async fn delete_partner_event_source(
+    /// Presigns the request `delete_partner_event_source` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_partner_event_source(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeletePartnerEventSourceRequest,
-    ) -> Result<(), RusotoError<DeletePartnerEventSourceError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_partner_event_source_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_partner_event_source_request(
+        &self,
+        input: DeletePartnerEventSourceRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "events", &self.region, "/");
+        request.set_operation("DeletePartnerEventSource");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSEvents.DeletePartnerEventSource");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `delete_rule`, also returning the metadata of the response.
+    pub async fn delete_rule_with_metadata(
+        &self,
+        input: DeleteRuleRequest,
+    ) -> Result<((), ResponseMetadata), RusotoError<DeleteRuleError>> {
+        let request = self.build_delete_rule_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
             std::mem::drop(response);
-            Ok(())
+            Ok(((), metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DeletePartnerEventSourceError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DeleteRuleError::from_response(response))
         }
     }
 
-    /// <p>Deletes the specified rule.</p> <p>Before you can delete the rule, you must remove all targets, using <a>RemoveTargets</a>.</p> <p>When you delete a rule, incoming events might continue to match to the deleted rule. Allow a short period of time for changes to take effect.</p> <p>Managed rules are rules created and managed by another AWS service on your behalf. These rules are created by those other AWS services to support functionality in those services. You can delete these rules using the <code>Force</code> option, but you should do so only if you're sure that the other service isn't still using that rule.</p>
-    async fn delete_rule(
+    /// Presigns the request `delete_rule` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_rule(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteRuleRequest,
-    ) -> Result<(), RusotoError<DeleteRuleError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_rule_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_rule_request(&self, input: DeleteRuleRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "events", &self.region, "/");
+        request.set_operation("DeleteRule");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSEvents.DeleteRule");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `describe_event_bus`, also returning the metadata of the response.
+    pub async fn describe_event_bus_with_metadata(
+        &self,
+        input: DescribeEventBusRequest,
+    ) -> Result<(DescribeEventBusResponse, ResponseMetadata), RusotoError<DescribeEventBusError>>
+    {
+        let request = self.build_describe_event_bus_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            std::mem::drop(response);
-            Ok(())
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DescribeEventBusResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteRuleError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DescribeEventBusError::from_response(response))
         }
     }
 
-    /// <p>Displays details about an event bus in your account. This can include the external AWS accounts that are permitted to write events to your default event bus, and the associated policy. For custom event buses and partner event buses, it displays the name, ARN, policy, state, and creation time.</p> <p> To enable your account to receive events from other accounts on its default event bus, use <a>PutPermission</a>.</p> <p>For more information about partner event buses, see <a>CreateEventBus</a>.</p>
-    async fn describe_event_bus(
+    /// Presigns the request `describe_event_bus` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_event_bus(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeEventBusRequest,
-    ) -> Result<DescribeEventBusResponse, RusotoError<DescribeEventBusError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_event_bus_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_event_bus_request(&self, input: DescribeEventBusRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "events", &self.region, "/");
+        request.set_operation("DescribeEventBus");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSEvents.DescribeEventBus");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `describe_event_source`, also returning the metadata of the response.
+    pub async fn describe_event_source_with_metadata(
+        &self,
+        input: DescribeEventSourceRequest,
+    ) -> Result<
+        (DescribeEventSourceResponse, ResponseMetadata),
+        RusotoError<DescribeEventSourceError>,
+    > {
+        let request = self.build_describe_event_source_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DescribeEventBusResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DescribeEventSourceResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeEventBusError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DescribeEventSourceError::from_response(response))
         }
     }
 
-    /// <p><p>This operation lists details about a partner event source that is shared with your account.</p> <note> <p>This operation is run by AWS customers, not by SaaS partners.</p> </note></p>
-    async fn describe_event_source(
+    /// Presigns the request `describe_event_source` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_event_source(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeEventSourceRequest,
-    ) -> Result<DescribeEventSourceResponse, RusotoError<DescribeEventSourceError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_event_source_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_event_source_request(
+        &self,
+        input: DescribeEventSourceRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "events", &self.region, "/");
+        request.set_operation("DescribeEventSource");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSEvents.DescribeEventSource");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `describe_partner_event_source`, also returning the metadata of the response.
+    pub async fn describe_partner_event_source_with_metadata(
+        &self,
+        input: DescribePartnerEventSourceRequest,
+    ) -> Result<
+        (DescribePartnerEventSourceResponse, ResponseMetadata),
+        RusotoError<DescribePartnerEventSourceError>,
+    > {
+        let request = self.build_describe_partner_event_source_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DescribeEventSourceResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DescribePartnerEventSourceResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeEventSourceError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DescribePartnerEventSourceError::from_response(response))
         }
     }
 
-    /// <p><p>An SaaS partner can use this operation to list details about a partner event source that they have created.</p> <note> <p>AWS customers do not use this operation. Instead, AWS customers can use <a>DescribeEventSource</a> to see details about a partner event source that is shared with them.</p> </note></p>
-    async fn describe_partner_event_source(
+    /// Presigns the request `describe_partner_event_source` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_partner_event_source(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribePartnerEventSourceRequest,
-    ) -> Result<DescribePartnerEventSourceResponse, RusotoError<DescribePartnerEventSourceError>>
-    {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_partner_event_source_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_partner_event_source_request(
+        &self,
+        input: DescribePartnerEventSourceRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "events", &self.region, "/");
+        request.set_operation("DescribePartnerEventSource");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSEvents.DescribePartnerEventSource");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `describe_rule`, also returning the metadata of the response.
+    pub async fn describe_rule_with_metadata(
+        &self,
+        input: DescribeRuleRequest,
+    ) -> Result<(DescribeRuleResponse, ResponseMetadata), RusotoError<DescribeRuleError>> {
+        let request = self.build_describe_rule_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DescribePartnerEventSourceResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DescribeRuleResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribePartnerEventSourceError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DescribeRuleError::from_response(response))
         }
     }
 
-    /// <p>Describes the specified rule.</p> <p> <code>DescribeRule</code> doesn't list the targets of a rule. To see the targets associated with a rule, use <a>ListTargetsByRule</a>.</p>
-    async fn describe_rule(
+    /// Presigns the request `describe_rule` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_rule(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeRuleRequest,
-    ) -> Result<DescribeRuleResponse, RusotoError<DescribeRuleError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_rule_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_rule_request(&self, input: DescribeRuleRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "events", &self.region, "/");
+        request.set_operation("DescribeRule");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSEvents.DescribeRule");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `disable_rule`, also returning the metadata of the response.
+    pub async fn disable_rule_with_metadata(
+        &self,
+        input: DisableRuleRequest,
+    ) -> Result<((), ResponseMetadata), RusotoError<DisableRuleError>> {
+        let request = self.build_disable_rule_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<DescribeRuleResponse, _>()
+            std::mem::drop(response);
+            Ok(((), metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeRuleError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DisableRuleError::from_response(response))
         }
     }
 
-    /// <p>Disables the specified rule. A disabled rule won't match any events and won't self-trigger if it has a schedule expression.</p> <p>When you disable a rule, incoming events might continue to match to the disabled rule. Allow a short period of time for changes to take effect.</p>
-    async fn disable_rule(
+    /// Presigns the request `disable_rule` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_disable_rule(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DisableRuleRequest,
-    ) -> Result<(), RusotoError<DisableRuleError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_disable_rule_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_disable_rule_request(&self, input: DisableRuleRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "events", &self.region, "/");
+        request.set_operation("DisableRule");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSEvents.DisableRule");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `enable_rule`, also returning the metadata of the response.
+    pub async fn enable_rule_with_metadata(
+        &self,
+        input: EnableRuleRequest,
+    ) -> Result<((), ResponseMetadata), RusotoError<EnableRuleError>> {
+        let request = self.build_enable_rule_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
             std::mem::drop(response);
-            Ok(())
+            Ok(((), metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DisableRuleError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(EnableRuleError::from_response(response))
         }
     }
 
-    /// <p>Enables the specified rule. If the rule doesn't exist, the operation fails.</p> <p>When you enable a rule, incoming events might not immediately start matching to a newly enabled rule. Allow a short period of time for changes to take effect.</p>
-    async fn enable_rule(
+    /// Presigns the request `enable_rule` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_enable_rule(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: EnableRuleRequest,
-    ) -> Result<(), RusotoError<EnableRuleError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_enable_rule_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_enable_rule_request(&self, input: EnableRuleRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "events", &self.region, "/");
+        request.set_operation("EnableRule");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSEvents.EnableRule");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `list_event_buses`, also returning the metadata of the response.
+    pub async fn list_event_buses_with_metadata(
+        &self,
+        input: ListEventBusesRequest,
+    ) -> Result<(ListEventBusesResponse, ResponseMetadata), RusotoError<ListEventBusesError>> {
+        let request = self.build_list_event_buses_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            std::mem::drop(response);
-            Ok(())
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ListEventBusesResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(EnableRuleError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ListEventBusesError::from_response(response))
         }
     }
 
-    /// <p><p>Lists all the event buses in your account, including the default event bus, custom event buses, and partner event buses.</p> <note> <p>This operation is run by AWS customers, not by SaaS partners.</p> </note></p>
-    async fn list_event_buses(
+    /// Presigns the request `list_event_buses` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_event_buses(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListEventBusesRequest,
-    ) -> Result<ListEventBusesResponse, RusotoError<ListEventBusesError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_event_buses_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_event_buses_request(&self, input: ListEventBusesRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "events", &self.region, "/");
+        request.set_operation("ListEventBuses");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSEvents.ListEventBuses");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
-        let mut response = self
-            .client
-            .sign_and_dispatch(request)
-            .await
-            .map_err(RusotoError::from)?;
+        request
+    }
+
+    /// Calls `list_event_sources`, also returning the metadata of the response.
+    pub async fn list_event_sources_with_metadata(
+        &self,
+        input: ListEventSourcesRequest,
+    ) -> Result<(ListEventSourcesResponse, ResponseMetadata), RusotoError<ListEventSourcesError>>
+    {
+        let request = self.build_list_event_sources_request(input);
+
+        let mut response = self
+            .client
+            .sign_and_dispatch(request)
+            .await
+            .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<ListEventBusesResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ListEventSourcesResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ListEventBusesError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ListEventSourcesError::from_response(response))
         }
     }
 
-    /// <p><p>You can use this to see all the partner event sources that have been shared with your AWS account. For more information about partner event sources, see <a>CreateEventBus</a>.</p> <note> <p>This operation is run by AWS customers, not by SaaS partners.</p> </note></p>
-    async fn list_event_sources(
+    /// Presigns the request `list_event_sources` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_event_sources(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListEventSourcesRequest,
-    ) -> Result<ListEventSourcesResponse, RusotoError<ListEventSourcesError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_event_sources_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_event_sources_request(&self, input: ListEventSourcesRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "events", &self.region, "/");
+        request.set_operation("ListEventSources");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSEvents.ListEventSources");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `list_partner_event_source_accounts`, also returning the metadata of the response.
+    pub async fn list_partner_event_source_accounts_with_metadata(
+        &self,
+        input: ListPartnerEventSourceAccountsRequest,
+    ) -> Result<
+        (ListPartnerEventSourceAccountsResponse, ResponseMetadata),
+        RusotoError<ListPartnerEventSourceAccountsError>,
+    > {
+        let request = self.build_list_partner_event_source_accounts_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListEventSourcesResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ListPartnerEventSourceAccountsResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ListEventSourcesError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ListPartnerEventSourceAccountsError::from_response(response))
         }
     }
 
-    /// <p><p>An SaaS partner can use this operation to display the AWS account ID that a particular partner event source name is associated with.</p> <note> <p>This operation is used by SaaS partners, not by AWS customers.</p> </note></p>
-    async fn list_partner_event_source_accounts(
+    /// Presigns the request `list_partner_event_source_accounts` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_partner_event_source_accounts(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListPartnerEventSourceAccountsRequest,
-    ) -> Result<
-        ListPartnerEventSourceAccountsResponse,
-        RusotoError<ListPartnerEventSourceAccountsError>,
-    > {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_partner_event_source_accounts_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_partner_event_source_accounts_request(
+        &self,
+        input: ListPartnerEventSourceAccountsRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "events", &self.region, "/");
+        request.set_operation("ListPartnerEventSourceAccounts");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSEvents.ListPartnerEventSourceAccounts");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `list_partner_event_sources`, also returning the metadata of the response.
+    pub async fn list_partner_event_sources_with_metadata(
+        &self,
+        input: ListPartnerEventSourcesRequest,
+    ) -> Result<
+        (ListPartnerEventSourcesResponse, ResponseMetadata),
+        RusotoError<ListPartnerEventSourcesError>,
+    > {
+        let request = self.build_list_partner_event_sources_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListPartnerEventSourceAccountsResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ListPartnerEventSourcesResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ListPartnerEventSourceAccountsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ListPartnerEventSourcesError::from_response(response))
         }
     }
 
-    /// <p><p>An SaaS partner can use this operation to list all the partner event source names that they have created.</p> <note> <p>This operation is not used by AWS customers.</p> </note></p>
-    async fn list_partner_event_sources(
+    /// Presigns the request `list_partner_event_sources` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_partner_event_sources(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListPartnerEventSourcesRequest,
-    ) -> Result<ListPartnerEventSourcesResponse, RusotoError<ListPartnerEventSourcesError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_partner_event_sources_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_partner_event_sources_request(
+        &self,
+        input: ListPartnerEventSourcesRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "events", &self.region, "/");
+        request.set_operation("ListPartnerEventSources");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSEvents.ListPartnerEventSources");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `list_rule_names_by_target`, also returning the metadata of the response.
+    pub async fn list_rule_names_by_target_with_metadata(
+        &self,
+        input: ListRuleNamesByTargetRequest,
+    ) -> Result<
+        (ListRuleNamesByTargetResponse, ResponseMetadata),
+        RusotoError<ListRuleNamesByTargetError>,
+    > {
+        let request = self.build_list_rule_names_by_target_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListPartnerEventSourcesResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ListRuleNamesByTargetResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ListPartnerEventSourcesError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ListRuleNamesByTargetError::from_response(response))
         }
     }
 
-    /// <p>Lists the rules for the specified target. You can see which rules can invoke a specific target in your account.</p>
-    async fn list_rule_names_by_target(
+    /// Presigns the request `list_rule_names_by_target` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_rule_names_by_target(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListRuleNamesByTargetRequest,
-    ) -> Result<ListRuleNamesByTargetResponse, RusotoError<ListRuleNamesByTargetError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_rule_names_by_target_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_rule_names_by_target_request(
+        &self,
+        input: ListRuleNamesByTargetRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "events", &self.region, "/");
+        request.set_operation("ListRuleNamesByTarget");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSEvents.ListRuleNamesByTarget");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `list_rules`, also returning the metadata of the response.
+    pub async fn list_rules_with_metadata(
+        &self,
+        input: ListRulesRequest,
+    ) -> Result<(ListRulesResponse, ResponseMetadata), RusotoError<ListRulesError>> {
+        let request = self.build_list_rules_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListRuleNamesByTargetResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ListRulesResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ListRuleNamesByTargetError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ListRulesError::from_response(response))
         }
     }
 
-    /// <p>Lists your EventBridge rules. You can either list all the rules or provide a prefix to match to the rule names.</p> <p> <code>ListRules</code> doesn't list the targets of a rule. To see the targets associated with a rule, use <a>ListTargetsByRule</a>.</p>
-    async fn list_rules(
+    /// Presigns the request `list_rules` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_rules(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListRulesRequest,
-    ) -> Result<ListRulesResponse, RusotoError<ListRulesError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_rules_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_rules_request(&self, input: ListRulesRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "events", &self.region, "/");
+        request.set_operation("ListRules");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSEvents.ListRules");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `list_tags_for_resource`, also returning the metadata of the response.
+    pub async fn list_tags_for_resource_with_metadata(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> Result<
+        (ListTagsForResourceResponse, ResponseMetadata),
+        RusotoError<ListTagsForResourceError>,
+    > {
+        let request = self.build_list_tags_for_resource_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<ListRulesResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ListTagsForResourceResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ListRulesError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ListTagsForResourceError::from_response(response))
         }
     }
 
-    /// <p>Displays the tags associated with an EventBridge resource. In EventBridge, rules can be tagged.</p>
-    async fn list_tags_for_resource(
+    /// Presigns the request `list_tags_for_resource` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_tags_for_resource(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListTagsForResourceRequest,
-    ) -> Result<ListTagsForResourceResponse, RusotoError<ListTagsForResourceError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_tags_for_resource_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_tags_for_resource_request(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "events", &self.region, "/");
+        request.set_operation("ListTagsForResource");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSEvents.ListTagsForResource");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `list_targets_by_rule`, also returning the metadata of the response.
+    pub async fn list_targets_by_rule_with_metadata(
+        &self,
+        input: ListTargetsByRuleRequest,
+    ) -> Result<(ListTargetsByRuleResponse, ResponseMetadata), RusotoError<ListTargetsByRuleError>>
+    {
+        let request = self.build_list_targets_by_rule_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListTagsForResourceResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ListTargetsByRuleResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ListTagsForResourceError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ListTargetsByRuleError::from_response(response))
         }
     }
 
-    /// <p>Lists the targets assigned to the specified rule.</p>
-    async fn list_targets_by_rule(
+    /// Presigns the request `list_targets_by_rule` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_targets_by_rule(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListTargetsByRuleRequest,
-    ) -> Result<ListTargetsByRuleResponse, RusotoError<ListTargetsByRuleError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_targets_by_rule_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_targets_by_rule_request(&self, input: ListTargetsByRuleRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "events", &self.region, "/");
+        request.set_operation("ListTargetsByRule");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSEvents.ListTargetsByRule");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `put_events`, also returning the metadata of the response.
+    pub async fn put_events_with_metadata(
+        &self,
+        input: PutEventsRequest,
+    ) -> Result<(PutEventsResponse, ResponseMetadata), RusotoError<PutEventsError>> {
+        let request = self.build_put_events_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListTargetsByRuleResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<PutEventsResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ListTargetsByRuleError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(PutEventsError::from_response(response))
         }
     }
 
-    /// <p>Sends custom events to EventBridge so that they can be matched to rules. These events can be from your custom applications and services.</p>
-    async fn put_events(
+    /// Presigns the request `put_events` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_put_events(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: PutEventsRequest,
-    ) -> Result<PutEventsResponse, RusotoError<PutEventsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_put_events_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_put_events_request(&self, input: PutEventsRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "events", &self.region, "/");
+        request.set_operation("PutEvents");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSEvents.PutEvents");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `put_partner_events`, also returning the metadata of the response.
+    pub async fn put_partner_events_with_metadata(
+        &self,
+        input: PutPartnerEventsRequest,
+    ) -> Result<(PutPartnerEventsResponse, ResponseMetadata), RusotoError<PutPartnerEventsError>>
+    {
+        let request = self.build_put_partner_events_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<PutEventsResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<PutPartnerEventsResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(PutEventsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(PutPartnerEventsError::from_response(response))
         }
     }
 
-    /// <p><p>This is used by SaaS partners to write events to a customer&#39;s partner event bus.</p> <note> <p>AWS customers do not use this operation. Instead, AWS customers can use <a>PutEvents</a> to write custom events from their own applications to an event bus.</p> </note></p>
-    async fn put_partner_events(
+    /// Presigns the request `put_partner_events` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_put_partner_events(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: PutPartnerEventsRequest,
-    ) -> Result<PutPartnerEventsResponse, RusotoError<PutPartnerEventsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_put_partner_events_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_put_partner_events_request(&self, input: PutPartnerEventsRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "events", &self.region, "/");
+        request.set_operation("PutPartnerEvents");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSEvents.PutPartnerEvents");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `put_permission`, also returning the metadata of the response.
+    pub async fn put_permission_with_metadata(
+        &self,
+        input: PutPermissionRequest,
+    ) -> Result<((), ResponseMetadata), RusotoError<PutPermissionError>> {
+        let request = self.build_put_permission_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<PutPartnerEventsResponse, _>()
+            std::mem::drop(response);
+            Ok(((), metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(PutPartnerEventsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(PutPermissionError::from_response(response))
         }
     }
 
-    /// <p>Running <code>PutPermission</code> permits the specified AWS account or AWS organization to put events to the specified <i>event bus</i>. Rules in your account are triggered by these events arriving to an event bus in your account. </p> <p>For another account to send events to your account, that external account must have a rule with your account's event bus as a target.</p> <p>To enable multiple AWS accounts to put events to an event bus, run <code>PutPermission</code> once for each of these accounts. Or, if all the accounts are members of the same AWS organization, you can run <code>PutPermission</code> once specifying <code>Principal</code> as "*" and specifying the AWS organization ID in <code>Condition</code>, to grant permissions to all accounts in that organization.</p> <p>If you grant permissions using an organization, then accounts in that organization must specify a <code>RoleArn</code> with proper permissions when they use <code>PutTarget</code> to add your account's event bus as a target. For more information, see <a href="https://docs.aws.amazon.com/eventbridge/latest/userguide/eventbridge-cross-account-event-delivery.html">Sending and Receiving Events Between AWS Accounts</a> in the <i>Amazon EventBridge User Guide</i>.</p> <p>The permission policy on an event bus can't exceed 10 KB in size.</p>
-    async fn put_permission(
+    /// Presigns the request `put_permission` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_put_permission(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: PutPermissionRequest,
-    ) -> Result<(), RusotoError<PutPermissionError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_put_permission_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_put_permission_request(&self, input: PutPermissionRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "events", &self.region, "/");
+        request.set_operation("PutPermission");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSEvents.PutPermission");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `put_rule`, also returning the metadata of the response.
+    pub async fn put_rule_with_metadata(
+        &self,
+        input: PutRuleRequest,
+    ) -> Result<(PutRuleResponse, ResponseMetadata), RusotoError<PutRuleError>> {
+        let request = self.build_put_rule_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            std::mem::drop(response);
-            Ok(())
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result =
+                proto::json::ResponsePayload::new(&response).deserialize::<PutRuleResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(PutPermissionError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(PutRuleError::from_response(response))
         }
     }
 
-    /// <p>Creates or updates the specified rule. Rules are enabled by default or based on value of the state. You can disable a rule using <a>DisableRule</a>.</p> <p>A single rule watches for events from a single event bus. Events generated by AWS services go to your account's default event bus. Events generated by SaaS partner services or applications go to the matching partner event bus. If you have custom applications or services, you can specify whether their events go to your default event bus or a custom event bus that you have created. For more information, see <a>CreateEventBus</a>.</p> <p>If you're updating an existing rule, the rule is replaced with what you specify in this <code>PutRule</code> command. If you omit arguments in <code>PutRule</code>, the old values for those arguments aren't kept. Instead, they're replaced with null values.</p> <p>When you create or update a rule, incoming events might not immediately start matching to new or updated rules. Allow a short period of time for changes to take effect.</p> <p>A rule must contain at least an <code>EventPattern</code> or <code>ScheduleExpression</code>. Rules with <code>EventPatterns</code> are triggered when a matching event is observed. Rules with <code>ScheduleExpressions</code> self-trigger based on the given schedule. A rule can have both an <code>EventPattern</code> and a <code>ScheduleExpression</code>, in which case the rule triggers on matching events as well as on a schedule.</p> <p>When you initially create a rule, you can optionally assign one or more tags to the rule. Tags can help you organize and categorize your resources. You can also use them to scope user permissions, by granting a user permission to access or change only rules with certain tag values. To use the <code>PutRule</code> operation and assign tags, you must have both the <code>events:PutRule</code> and <code>events:TagResource</code> permissions.</p> <p>If you are updating an existing rule, any tags you specify in the <code>PutRule</code> operation are ignored. To update the tags of an existing rule, use <a>TagResource</a> and <a>UntagResource</a>.</p> <p>Most services in AWS treat <code>:</code> or <code>/</code> as the same character in Amazon Resource Names (ARNs). However, EventBridge uses an exact match in event patterns and rules. Be sure to use the correct ARN characters when creating event patterns so that they match the ARN syntax in the event that you want to match.</p> <p>In EventBridge, you could create rules that lead to infinite loops, where a rule is fired repeatedly. For example, a rule might detect that ACLs have changed on an S3 bucket, and trigger software to change them to the desired state. If you don't write the rule carefully, the subsequent change to the ACLs fires the rule again, creating an infinite loop.</p> <p>To prevent this, write the rules so that the triggered actions don't refire the same rule. For example, your rule could fire only if ACLs are found to be in a bad state, instead of after any change. </p> <p>An infinite loop can quickly cause higher than expected charges. We recommend that you use budgeting, which alerts you when charges exceed your specified limit. For more information, see <a href="https://docs.aws.amazon.com/awsaccountbilling/latest/aboutv2/budgets-managing-costs.html">Managing Your Costs with Budgets</a>.</p>
-    async fn put_rule(
+    /// Presigns the request `put_rule` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_put_rule(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: PutRuleRequest,
-    ) -> Result<PutRuleResponse, RusotoError<PutRuleError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_put_rule_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_put_rule_request(&self, input: PutRuleRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "events", &self.region, "/");
+        request.set_operation("PutRule");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSEvents.PutRule");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `put_targets`, also returning the metadata of the response.
+    pub async fn put_targets_with_metadata(
+        &self,
+        input: PutTargetsRequest,
+    ) -> Result<(PutTargetsResponse, ResponseMetadata), RusotoError<PutTargetsError>> {
+        let request = self.build_put_targets_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<PutRuleResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<PutTargetsResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(PutRuleError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(PutTargetsError::from_response(response))
         }
     }
 
-    /// <p>Adds the specified targets to the specified rule, or updates the targets if they're already associated with the rule.</p> <p>Targets are the resources that are invoked when a rule is triggered.</p> <p>You can configure the following as targets in EventBridge:</p> <ul> <li> <p>EC2 instances</p> </li> <li> <p>SSM Run Command</p> </li> <li> <p>SSM Automation</p> </li> <li> <p>AWS Lambda functions</p> </li> <li> <p>Data streams in Amazon Kinesis Data Streams</p> </li> <li> <p>Data delivery streams in Amazon Kinesis Data Firehose</p> </li> <li> <p>Amazon ECS tasks</p> </li> <li> <p>AWS Step Functions state machines</p> </li> <li> <p>AWS Batch jobs</p> </li> <li> <p>AWS CodeBuild projects</p> </li> <li> <p>Pipelines in AWS CodePipeline</p> </li> <li> <p>Amazon Inspector assessment templates</p> </li> <li> <p>Amazon SNS topics</p> </li> <li> <p>Amazon SQS queues, including FIFO queues</p> </li> <li> <p>The default event bus of another AWS account</p> </li> </ul> <p>Creating rules with built-in targets is supported only on the AWS Management Console. The built-in targets are <code>EC2 CreateSnapshot API call</code>, <code>EC2 RebootInstances API call</code>, <code>EC2 StopInstances API call</code>, and <code>EC2 TerminateInstances API call</code>. </p> <p>For some target types, <code>PutTargets</code> provides target-specific parameters. If the target is a Kinesis data stream, you can optionally specify which shard the event goes to by using the <code>KinesisParameters</code> argument. To invoke a command on multiple EC2 instances with one rule, you can use the <code>RunCommandParameters</code> field.</p> <p>To be able to make API calls against the resources that you own, Amazon EventBridge needs the appropriate permissions. For AWS Lambda and Amazon SNS resources, EventBridge relies on resource-based policies. For EC2 instances, Kinesis data streams, and AWS Step Functions state machines, EventBridge relies on IAM roles that you specify in the <code>RoleARN</code> argument in <code>PutTargets</code>. For more information, see <a href="https://docs.aws.amazon.com/eventbridge/latest/userguide/auth-and-access-control-eventbridge.html">Authentication and Access Control</a> in the <i>Amazon EventBridge User Guide</i>.</p> <p>If another AWS account is in the same Region and has granted you permission (using <code>PutPermission</code>), you can send events to that account. Set that account's event bus as a target of the rules in your account. To send the matched events to the other account, specify that account's event bus as the <code>Arn</code> value when you run <code>PutTargets</code>. If your account sends events to another account, your account is charged for each sent event. Each event sent to another account is charged as a custom event. The account receiving the event isn't charged. For more information, see <a href="https://aws.amazon.com/eventbridge/pricing/">Amazon EventBridge Pricing</a>.</p> <p>If you're setting an event bus in another account as the target and that account granted permission to your account through an organization instead of directly by the account ID, you must specify a <code>RoleArn</code> with proper permissions in the <code>Target</code> structure. For more information, see <a href="https://docs.aws.amazon.com/eventbridge/latest/userguide/eventbridge-cross-account-event-delivery.html">Sending and Receiving Events Between AWS Accounts</a> in the <i>Amazon EventBridge User Guide</i>.</p> <p>For more information about enabling cross-account events, see <a>PutPermission</a>.</p> <p> <code>Input</code>, <code>InputPath</code>, and <code>InputTransformer</code> are mutually exclusive and optional parameters of a target. When a rule is triggered due to a matched event:</p> <ul> <li> <p>If none of the following arguments are specified for a target, the entire event is passed to the target in JSON format (unless the target is Amazon EC2 Run Command or Amazon ECS task, in which case nothing from the event is passed to the target).</p> </li> <li> <p>If <code>Input</code> is specified in the form of valid JSON, then the matched event is overridden with this constant.</p> </li> <li> <p>If <code>InputPath</code> is specified in the form of JSONPath (for example, <code>$.detail</code>), only the part of the event specified in the path is passed to the target (for example, only the detail part of the event is passed).</p> </li> <li> <p>If <code>InputTransformer</code> is specified, one or more specified JSONPaths are extracted from the event and used as values in a template that you specify as the input to the target.</p> </li> </ul> <p>When you specify <code>InputPath</code> or <code>InputTransformer</code>, you must use JSON dot notation, not bracket notation.</p> <p>When you add targets to a rule and the associated rule triggers soon after, new or updated targets might not be immediately invoked. Allow a short period of time for changes to take effect.</p> <p>This action can partially fail if too many requests are made at the same time. If that happens, <code>FailedEntryCount</code> is nonzero in the response, and each entry in <code>FailedEntries</code> provides the ID of the failed target and the error code.</p>
-    async fn put_targets(
+    /// Presigns the request `put_targets` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_put_targets(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: PutTargetsRequest,
-    ) -> Result<PutTargetsResponse, RusotoError<PutTargetsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_put_targets_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_put_targets_request(&self, input: PutTargetsRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "events", &self.region, "/");
+        request.set_operation("PutTargets");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSEvents.PutTargets");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `remove_permission`, also returning the metadata of the response.
+    pub async fn remove_permission_with_metadata(
+        &self,
+        input: RemovePermissionRequest,
+    ) -> Result<((), ResponseMetadata), RusotoError<RemovePermissionError>> {
+        let request = self.build_remove_permission_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<PutTargetsResponse, _>()
+            std::mem::drop(response);
+            Ok(((), metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(PutTargetsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(RemovePermissionError::from_response(response))
         }
     }
 
-    /// <p>Revokes the permission of another AWS account to be able to put events to the specified event bus. Specify the account to revoke by the <code>StatementId</code> value that you associated with the account when you granted it permission with <code>PutPermission</code>. You can find the <code>StatementId</code> by using <a>DescribeEventBus</a>.</p>
-    async fn remove_permission(
+    /// Presigns the request `remove_permission` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_remove_permission(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: RemovePermissionRequest,
-    ) -> Result<(), RusotoError<RemovePermissionError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_remove_permission_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_remove_permission_request(&self, input: RemovePermissionRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "events", &self.region, "/");
+        request.set_operation("RemovePermission");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSEvents.RemovePermission");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `remove_targets`, also returning the metadata of the response.
+    pub async fn remove_targets_with_metadata(
+        &self,
+        input: RemoveTargetsRequest,
+    ) -> Result<(RemoveTargetsResponse, ResponseMetadata), RusotoError<RemoveTargetsError>> {
+        let request = self.build_remove_targets_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            std::mem::drop(response);
-            Ok(())
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<RemoveTargetsResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(RemovePermissionError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(RemoveTargetsError::from_response(response))
         }
     }
 
-    /// <p>Removes the specified targets from the specified rule. When the rule is triggered, those targets are no longer be invoked.</p> <p>When you remove a target, when the associated rule triggers, removed targets might continue to be invoked. Allow a short period of time for changes to take effect.</p> <p>This action can partially fail if too many requests are made at the same time. If that happens, <code>FailedEntryCount</code> is non-zero in the response and each entry in <code>FailedEntries</code> provides the ID of the failed target and the error code.</p>
-    async fn remove_targets(
+    /// Presigns the request `remove_targets` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_remove_targets(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: RemoveTargetsRequest,
-    ) -> Result<RemoveTargetsResponse, RusotoError<RemoveTargetsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_remove_targets_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_remove_targets_request(&self, input: RemoveTargetsRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "events", &self.region, "/");
+        request.set_operation("RemoveTargets");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSEvents.RemoveTargets");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `tag_resource`, also returning the metadata of the response.
+    pub async fn tag_resource_with_metadata(
+        &self,
+        input: TagResourceRequest,
+    ) -> Result<(TagResourceResponse, ResponseMetadata), RusotoError<TagResourceError>> {
+        let request = self.build_tag_resource_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<RemoveTargetsResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<TagResourceResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(RemoveTargetsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(TagResourceError::from_response(response))
         }
     }
 
-    /// <p>Assigns one or more tags (key-value pairs) to the specified EventBridge resource. Tags can help you organize and categorize your resources. You can also use them to scope user permissions by granting a user permission to access or change only resources with certain tag values. In EventBridge, rules can be tagged.</p> <p>Tags don't have any semantic meaning to AWS and are interpreted strictly as strings of characters.</p> <p>You can use the <code>TagResource</code> action with a rule that already has tags. If you specify a new tag key for the rule, this tag is appended to the list of tags associated with the rule. If you specify a tag key that is already associated with the rule, the new tag value that you specify replaces the previous value for that tag.</p> <p>You can associate as many as 50 tags with a resource.</p>
-    async fn tag_resource(
+    /// Presigns the request `tag_resource` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_tag_resource(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: TagResourceRequest,
-    ) -> Result<TagResourceResponse, RusotoError<TagResourceError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_tag_resource_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_tag_resource_request(&self, input: TagResourceRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "events", &self.region, "/");
+        request.set_operation("TagResource");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSEvents.TagResource");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `test_event_pattern`, also returning the metadata of the response.
+    pub async fn test_event_pattern_with_metadata(
+        &self,
+        input: TestEventPatternRequest,
+    ) -> Result<(TestEventPatternResponse, ResponseMetadata), RusotoError<TestEventPatternError>>
+    {
+        let request = self.build_test_event_pattern_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<TagResourceResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<TestEventPatternResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(TagResourceError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(TestEventPatternError::from_response(response))
         }
     }
 
-    /// <p>Tests whether the specified event pattern matches the provided event.</p> <p>Most services in AWS treat <code>:</code> or <code>/</code> as the same character in Amazon Resource Names (ARNs). However, EventBridge uses an exact match in event patterns and rules. Be sure to use the correct ARN characters when creating event patterns so that they match the ARN syntax in the event that you want to match.</p>
-    async fn test_event_pattern(
+    /// Presigns the request `test_event_pattern` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_test_event_pattern(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: TestEventPatternRequest,
-    ) -> Result<TestEventPatternResponse, RusotoError<TestEventPatternError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_test_event_pattern_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_test_event_pattern_request(&self, input: TestEventPatternRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "events", &self.region, "/");
+        request.set_operation("TestEventPattern");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSEvents.TestEventPattern");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `untag_resource`, also returning the metadata of the response.
+    pub async fn untag_resource_with_metadata(
+        &self,
+        input: UntagResourceRequest,
+    ) -> Result<(UntagResourceResponse, ResponseMetadata), RusotoError<UntagResourceError>> {
+        let request = self.build_untag_resource_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<TestEventPatternResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<UntagResourceResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(TestEventPatternError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(UntagResourceError::from_response(response))
         }
     }
 
-    /// <p>Removes one or more tags from the specified EventBridge resource. In EventBridge, rules can be tagged.</p>
-    async fn untag_resource(
+    /// Presigns the request `untag_resource` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_untag_resource(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UntagResourceRequest,
-    ) -> Result<UntagResourceResponse, RusotoError<UntagResourceError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_untag_resource_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_untag_resource_request(&self, input: UntagResourceRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "events", &self.region, "/");
+        request.set_operation("UntagResource");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSEvents.UntagResource");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
-        let mut response = self
-            .client
-            .sign_and_dispatch(request)
-            .await
-            .map_err(RusotoError::from)?;
-        if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<UntagResourceResponse, _>()
-        } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(UntagResourceError::from_response(response))
-        }
+        request
+    }
+
+    /// The pages of `ListRuleNamesByTarget` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `Limit` of `input`.
+    pub fn list_rule_names_by_target_pages(
+        &self,
+        input: ListRuleNamesByTargetRequest,
+    ) -> impl futures::Stream<
+        Item = Result<ListRuleNamesByTargetResponse, RusotoError<ListRuleNamesByTargetError>>,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: EventBridgeClient, input| async move {
+                client.list_rule_names_by_target(input).await
+            },
+            |input: &ListRuleNamesByTargetRequest, output: &ListRuleNamesByTargetResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListRuleNamesByTargetRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `RuleNames` of every page of `ListRuleNamesByTarget` results for `input`, as `list_rule_names_by_target_pages` requests them.
+    pub fn list_rule_names_by_target_stream(
+        &self,
+        input: ListRuleNamesByTargetRequest,
+    ) -> impl futures::Stream<Item = Result<String, RusotoError<ListRuleNamesByTargetError>>> + Send
+    {
+        rusoto_core::pagination::items(self.list_rule_names_by_target_pages(input), |page| {
+            page.rule_names.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `ListRules` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `Limit` of `input`.
+    pub fn list_rules_pages(
+        &self,
+        input: ListRulesRequest,
+    ) -> impl futures::Stream<Item = Result<ListRulesResponse, RusotoError<ListRulesError>>> + Send
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: EventBridgeClient, input| async move { client.list_rules(input).await },
+            |input: &ListRulesRequest, output: &ListRulesResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListRulesRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `Rules` of every page of `ListRules` results for `input`, as `list_rules_pages` requests them.
+    pub fn list_rules_stream(
+        &self,
+        input: ListRulesRequest,
+    ) -> impl futures::Stream<Item = Result<Rule, RusotoError<ListRulesError>>> + Send {
+        rusoto_core::pagination::items(self.list_rules_pages(input), |page| {
+            page.rules.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `ListTargetsByRule` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `Limit` of `input`.
+    pub fn list_targets_by_rule_pages(
+        &self,
+        input: ListTargetsByRuleRequest,
+    ) -> impl futures::Stream<
+        Item = Result<ListTargetsByRuleResponse, RusotoError<ListTargetsByRuleError>>,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: EventBridgeClient, input| async move { client.list_targets_by_rule(input).await },
+            |input: &ListTargetsByRuleRequest, output: &ListTargetsByRuleResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListTargetsByRuleRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `Targets` of every page of `ListTargetsByRule` results for `input`, as `list_targets_by_rule_pages` requests them.
+    pub fn list_targets_by_rule_stream(
+        &self,
+        input: ListTargetsByRuleRequest,
+    ) -> impl futures::Stream<Item = Result<Target, RusotoError<ListTargetsByRuleError>>> + Send
+    {
+        rusoto_core::pagination::items(self.list_targets_by_rule_pages(input), |page| {
+            page.targets.unwrap_or_default()
+        })
+    }
+}
+
+#[async_trait]
+impl EventBridge for EventBridgeClient {
+    /// <p><p>Activates a partner event source that has been deactivated. Once activated, your matching event bus will start receiving events from the event source.</p> <note> <p>This operation is performed by AWS customers, not by SaaS partners.</p> </note></p>
+    async fn activate_event_source(
+        &self,
+        input: ActivateEventSourceRequest,
+    ) -> Result<(), RusotoError<ActivateEventSourceError>> {
+        self.activate_event_source_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Creates a new event bus within your account. This can be a custom event bus which you can use to receive events from your own custom applications and services, or it can be a partner event bus which can be matched to a partner event source.</p> <note> <p>This operation is used by AWS customers, not by SaaS partners.</p> </note></p>
+    async fn create_event_bus(
+        &self,
+        input: CreateEventBusRequest,
+    ) -> Result<CreateEventBusResponse, RusotoError<CreateEventBusError>> {
+        self.create_event_bus_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Called by an SaaS partner to create a partner event source.</p> <note> <p>This operation is not used by AWS customers.</p> </note> <p>Each partner event source can be used by one AWS account to create a matching partner event bus in that AWS account. A SaaS partner must create one partner event source for each AWS account that wants to receive those event types. </p> <p>A partner event source creates events based on resources in the SaaS partner&#39;s service or application.</p> <p>An AWS account that creates a partner event bus that matches the partner event source can use that event bus to receive events from the partner, and then process them using AWS Events rules and targets.</p> <p>Partner event source names follow this format:</p> <p> <code>aws.partner/<i>partner<em>name</i>/<i>event</em>namespace</i>/<i>event<em>name</i> </code> </p> <ul> <li> <p> <i>partner</em>name</i> is determined during partner registration and identifies the partner to AWS customers.</p> </li> <li> <p>For <i>event<em>namespace</i>, we recommend that partners use a string that identifies the AWS customer within the partner&#39;s system. This should not be the customer&#39;s AWS account ID.</p> </li> <li> <p> <i>event</em>name</i> is determined by the partner, and should uniquely identify an event-generating resource within the partner system. This should help AWS customers decide whether to create an event bus to receive these events.</p> </li> </ul></p>
+    async fn create_partner_event_source(
+        &self,
+        input: CreatePartnerEventSourceRequest,
+    ) -> Result<CreatePartnerEventSourceResponse, RusotoError<CreatePartnerEventSourceError>> {
+        self.create_partner_event_source_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>An AWS customer uses this operation to temporarily stop receiving events from the specified partner event source. The matching event bus isn't deleted. </p> <p>When you deactivate a partner event source, the source goes into <code>PENDING</code> state. If it remains in <code>PENDING</code> state for more than two weeks, it's deleted.</p> <p>To activate a deactivated partner event source, use <a>ActivateEventSource</a>.</p>
+    async fn deactivate_event_source(
+        &self,
+        input: DeactivateEventSourceRequest,
+    ) -> Result<(), RusotoError<DeactivateEventSourceError>> {
+        self.deactivate_event_source_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Deletes the specified custom event bus or partner event bus. All rules associated with this event bus are also deleted. You can&#39;t delete your account&#39;s default event bus.</p> <note> <p>This operation is performed by AWS customers, not by SaaS partners.</p> </note></p>
+    async fn delete_event_bus(
+        &self,
+        input: DeleteEventBusRequest,
+    ) -> Result<(), RusotoError<DeleteEventBusError>> {
+        self.delete_event_bus_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>This operation is used by SaaS partners to delete a partner event source. AWS customers don't use this operation.</p> <p>When you delete an event source, the status of the corresponding partner event bus in the AWS customer account becomes <code>DELETED</code>.</p>
+    async fn delete_partner_event_source(
+        &self,
+        input: DeletePartnerEventSourceRequest,
+    ) -> Result<(), RusotoError<DeletePartnerEventSourceError>> {
+        self.delete_partner_event_source_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes the specified rule.</p> <p>Before you can delete the rule, you must remove all targets, using <a>RemoveTargets</a>.</p> <p>When you delete a rule, incoming events might continue to match to the deleted rule. Allow a short period of time for changes to take effect.</p> <p>Managed rules are rules created and managed by another AWS service on your behalf. These rules are created by those other AWS services to support functionality in those services. You can delete these rules using the <code>Force</code> option, but you should do so only if you're sure that the other service isn't still using that rule.</p>
+    async fn delete_rule(
+        &self,
+        input: DeleteRuleRequest,
+    ) -> Result<(), RusotoError<DeleteRuleError>> {
+        self.delete_rule_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Displays details about an event bus in your account. This can include the external AWS accounts that are permitted to write events to your default event bus, and the associated policy. For custom event buses and partner event buses, it displays the name, ARN, policy, state, and creation time.</p> <p> To enable your account to receive events from other accounts on its default event bus, use <a>PutPermission</a>.</p> <p>For more information about partner event buses, see <a>CreateEventBus</a>.</p>
+    async fn describe_event_bus(
+        &self,
+        input: DescribeEventBusRequest,
+    ) -> Result<DescribeEventBusResponse, RusotoError<DescribeEventBusError>> {
+        self.describe_event_bus_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>This operation lists details about a partner event source that is shared with your account.</p> <note> <p>This operation is run by AWS customers, not by SaaS partners.</p> </note></p>
+    async fn describe_event_source(
+        &self,
+        input: DescribeEventSourceRequest,
+    ) -> Result<DescribeEventSourceResponse, RusotoError<DescribeEventSourceError>> {
+        self.describe_event_source_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>An SaaS partner can use this operation to list details about a partner event source that they have created.</p> <note> <p>AWS customers do not use this operation. Instead, AWS customers can use <a>DescribeEventSource</a> to see details about a partner event source that is shared with them.</p> </note></p>
+    async fn describe_partner_event_source(
+        &self,
+        input: DescribePartnerEventSourceRequest,
+    ) -> Result<DescribePartnerEventSourceResponse, RusotoError<DescribePartnerEventSourceError>>
+    {
+        self.describe_partner_event_source_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Describes the specified rule.</p> <p> <code>DescribeRule</code> doesn't list the targets of a rule. To see the targets associated with a rule, use <a>ListTargetsByRule</a>.</p>
+    async fn describe_rule(
+        &self,
+        input: DescribeRuleRequest,
+    ) -> Result<DescribeRuleResponse, RusotoError<DescribeRuleError>> {
+        self.describe_rule_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Disables the specified rule. A disabled rule won't match any events and won't self-trigger if it has a schedule expression.</p> <p>When you disable a rule, incoming events might continue to match to the disabled rule. Allow a short period of time for changes to take effect.</p>
+    async fn disable_rule(
+        &self,
+        input: DisableRuleRequest,
+    ) -> Result<(), RusotoError<DisableRuleError>> {
+        self.disable_rule_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Enables the specified rule. If the rule doesn't exist, the operation fails.</p> <p>When you enable a rule, incoming events might not immediately start matching to a newly enabled rule. Allow a short period of time for changes to take effect.</p>
+    async fn enable_rule(
+        &self,
+        input: EnableRuleRequest,
+    ) -> Result<(), RusotoError<EnableRuleError>> {
+        self.enable_rule_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Lists all the event buses in your account, including the default event bus, custom event buses, and partner event buses.</p> <note> <p>This operation is run by AWS customers, not by SaaS partners.</p> </note></p>
+    async fn list_event_buses(
+        &self,
+        input: ListEventBusesRequest,
+    ) -> Result<ListEventBusesResponse, RusotoError<ListEventBusesError>> {
+        self.list_event_buses_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>You can use this to see all the partner event sources that have been shared with your AWS account. For more information about partner event sources, see <a>CreateEventBus</a>.</p> <note> <p>This operation is run by AWS customers, not by SaaS partners.</p> </note></p>
+    async fn list_event_sources(
+        &self,
+        input: ListEventSourcesRequest,
+    ) -> Result<ListEventSourcesResponse, RusotoError<ListEventSourcesError>> {
+        self.list_event_sources_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>An SaaS partner can use this operation to display the AWS account ID that a particular partner event source name is associated with.</p> <note> <p>This operation is used by SaaS partners, not by AWS customers.</p> </note></p>
+    async fn list_partner_event_source_accounts(
+        &self,
+        input: ListPartnerEventSourceAccountsRequest,
+    ) -> Result<
+        ListPartnerEventSourceAccountsResponse,
+        RusotoError<ListPartnerEventSourceAccountsError>,
+    > {
+        self.list_partner_event_source_accounts_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>An SaaS partner can use this operation to list all the partner event source names that they have created.</p> <note> <p>This operation is not used by AWS customers.</p> </note></p>
+    async fn list_partner_event_sources(
+        &self,
+        input: ListPartnerEventSourcesRequest,
+    ) -> Result<ListPartnerEventSourcesResponse, RusotoError<ListPartnerEventSourcesError>> {
+        self.list_partner_event_sources_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Lists the rules for the specified target. You can see which rules can invoke a specific target in your account.</p>
+    async fn list_rule_names_by_target(
+        &self,
+        input: ListRuleNamesByTargetRequest,
+    ) -> Result<ListRuleNamesByTargetResponse, RusotoError<ListRuleNamesByTargetError>> {
+        self.list_rule_names_by_target_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Lists your EventBridge rules. You can either list all the rules or provide a prefix to match to the rule names.</p> <p> <code>ListRules</code> doesn't list the targets of a rule. To see the targets associated with a rule, use <a>ListTargetsByRule</a>.</p>
+    async fn list_rules(
+        &self,
+        input: ListRulesRequest,
+    ) -> Result<ListRulesResponse, RusotoError<ListRulesError>> {
+        self.list_rules_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Displays the tags associated with an EventBridge resource. In EventBridge, rules can be tagged.</p>
+    async fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> Result<ListTagsForResourceResponse, RusotoError<ListTagsForResourceError>> {
+        self.list_tags_for_resource_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Lists the targets assigned to the specified rule.</p>
+    async fn list_targets_by_rule(
+        &self,
+        input: ListTargetsByRuleRequest,
+    ) -> Result<ListTargetsByRuleResponse, RusotoError<ListTargetsByRuleError>> {
+        self.list_targets_by_rule_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Sends custom events to EventBridge so that they can be matched to rules. These events can be from your custom applications and services.</p>
+    async fn put_events(
+        &self,
+        input: PutEventsRequest,
+    ) -> Result<PutEventsResponse, RusotoError<PutEventsError>> {
+        self.put_events_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>This is used by SaaS partners to write events to a customer&#39;s partner event bus.</p> <note> <p>AWS customers do not use this operation. Instead, AWS customers can use <a>PutEvents</a> to write custom events from their own applications to an event bus.</p> </note></p>
+    async fn put_partner_events(
+        &self,
+        input: PutPartnerEventsRequest,
+    ) -> Result<PutPartnerEventsResponse, RusotoError<PutPartnerEventsError>> {
+        self.put_partner_events_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Running <code>PutPermission</code> permits the specified AWS account or AWS organization to put events to the specified <i>event bus</i>. Rules in your account are triggered by these events arriving to an event bus in your account. </p> <p>For another account to send events to your account, that external account must have a rule with your account's event bus as a target.</p> <p>To enable multiple AWS accounts to put events to an event bus, run <code>PutPermission</code> once for each of these accounts. Or, if all the accounts are members of the same AWS organization, you can run <code>PutPermission</code> once specifying <code>Principal</code> as "*" and specifying the AWS organization ID in <code>Condition</code>, to grant permissions to all accounts in that organization.</p> <p>If you grant permissions using an organization, then accounts in that organization must specify a <code>RoleArn</code> with proper permissions when they use <code>PutTarget</code> to add your account's event bus as a target. For more information, see <a href="https://docs.aws.amazon.com/eventbridge/latest/userguide/eventbridge-cross-account-event-delivery.html">Sending and Receiving Events Between AWS Accounts</a> in the <i>Amazon EventBridge User Guide</i>.</p> <p>The permission policy on an event bus can't exceed 10 KB in size.</p>
+    async fn put_permission(
+        &self,
+        input: PutPermissionRequest,
+    ) -> Result<(), RusotoError<PutPermissionError>> {
+        self.put_permission_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Creates or updates the specified rule. Rules are enabled by default or based on value of the state. You can disable a rule using <a>DisableRule</a>.</p> <p>A single rule watches for events from a single event bus. Events generated by AWS services go to your account's default event bus. Events generated by SaaS partner services or applications go to the matching partner event bus. If you have custom applications or services, you can specify whether their events go to your default event bus or a custom event bus that you have created. For more information, see <a>CreateEventBus</a>.</p> <p>If you're updating an existing rule, the rule is replaced with what you specify in this <code>PutRule</code> command. If you omit arguments in <code>PutRule</code>, the old values for those arguments aren't kept. Instead, they're replaced with null values.</p> <p>When you create or update a rule, incoming events might not immediately start matching to new or updated rules. Allow a short period of time for changes to take effect.</p> <p>A rule must contain at least an <code>EventPattern</code> or <code>ScheduleExpression</code>. Rules with <code>EventPatterns</code> are triggered when a matching event is observed. Rules with <code>ScheduleExpressions</code> self-trigger based on the given schedule. A rule can have both an <code>EventPattern</code> and a <code>ScheduleExpression</code>, in which case the rule triggers on matching events as well as on a schedule.</p> <p>When you initially create a rule, you can optionally assign one or more tags to the rule. Tags can help you organize and categorize your resources. You can also use them to scope user permissions, by granting a user permission to access or change only rules with certain tag values. To use the <code>PutRule</code> operation and assign tags, you must have both the <code>events:PutRule</code> and <code>events:TagResource</code> permissions.</p> <p>If you are updating an existing rule, any tags you specify in the <code>PutRule</code> operation are ignored. To update the tags of an existing rule, use <a>TagResource</a> and <a>UntagResource</a>.</p> <p>Most services in AWS treat <code>:</code> or <code>/</code> as the same character in Amazon Resource Names (ARNs). However, EventBridge uses an exact match in event patterns and rules. Be sure to use the correct ARN characters when creating event patterns so that they match the ARN syntax in the event that you want to match.</p> <p>In EventBridge, you could create rules that lead to infinite loops, where a rule is fired repeatedly. For example, a rule might detect that ACLs have changed on an S3 bucket, and trigger software to change them to the desired state. If you don't write the rule carefully, the subsequent change to the ACLs fires the rule again, creating an infinite loop.</p> <p>To prevent this, write the rules so that the triggered actions don't refire the same rule. For example, your rule could fire only if ACLs are found to be in a bad state, instead of after any change. </p> <p>An infinite loop can quickly cause higher than expected charges. We recommend that you use budgeting, which alerts you when charges exceed your specified limit. For more information, see <a href="https://docs.aws.amazon.com/awsaccountbilling/latest/aboutv2/budgets-managing-costs.html">Managing Your Costs with Budgets</a>.</p>
+    async fn put_rule(
+        &self,
+        input: PutRuleRequest,
+    ) -> Result<PutRuleResponse, RusotoError<PutRuleError>> {
+        self.put_rule_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Adds the specified targets to the specified rule, or updates the targets if they're already associated with the rule.</p> <p>Targets are the resources that are invoked when a rule is triggered.</p> <p>You can configure the following as targets in EventBridge:</p> <ul> <li> <p>EC2 instances</p> </li> <li> <p>SSM Run Command</p> </li> <li> <p>SSM Automation</p> </li> <li> <p>AWS Lambda functions</p> </li> <li> <p>Data streams in Amazon Kinesis Data Streams</p> </li> <li> <p>Data delivery streams in Amazon Kinesis Data Firehose</p> </li> <li> <p>Amazon ECS tasks</p> </li> <li> <p>AWS Step Functions state machines</p> </li> <li> <p>AWS Batch jobs</p> </li> <li> <p>AWS CodeBuild projects</p> </li> <li> <p>Pipelines in AWS CodePipeline</p> </li> <li> <p>Amazon Inspector assessment templates</p> </li> <li> <p>Amazon SNS topics</p> </li> <li> <p>Amazon SQS queues, including FIFO queues</p> </li> <li> <p>The default event bus of another AWS account</p> </li> </ul> <p>Creating rules with built-in targets is supported only on the AWS Management Console. The built-in targets are <code>EC2 CreateSnapshot API call</code>, <code>EC2 RebootInstances API call</code>, <code>EC2 StopInstances API call</code>, and <code>EC2 TerminateInstances API call</code>. </p> <p>For some target types, <code>PutTargets</code> provides target-specific parameters. If the target is a Kinesis data stream, you can optionally specify which shard the event goes to by using the <code>KinesisParameters</code> argument. To invoke a command on multiple EC2 instances with one rule, you can use the <code>RunCommandParameters</code> field.</p> <p>To be able to make API calls against the resources that you own, Amazon EventBridge needs the appropriate permissions. For AWS Lambda and Amazon SNS resources, EventBridge relies on resource-based policies. For EC2 instances, Kinesis data streams, and AWS Step Functions state machines, EventBridge relies on IAM roles that you specify in the <code>RoleARN</code> argument in <code>PutTargets</code>. For more information, see <a href="https://docs.aws.amazon.com/eventbridge/latest/userguide/auth-and-access-control-eventbridge.html">Authentication and Access Control</a> in the <i>Amazon EventBridge User Guide</i>.</p> <p>If another AWS account is in the same Region and has granted you permission (using <code>PutPermission</code>), you can send events to that account. Set that account's event bus as a target of the rules in your account. To send the matched events to the other account, specify that account's event bus as the <code>Arn</code> value when you run <code>PutTargets</code>. If your account sends events to another account, your account is charged for each sent event. Each event sent to another account is charged as a custom event. The account receiving the event isn't charged. For more information, see <a href="https://aws.amazon.com/eventbridge/pricing/">Amazon EventBridge Pricing</a>.</p> <p>If you're setting an event bus in another account as the target and that account granted permission to your account through an organization instead of directly by the account ID, you must specify a <code>RoleArn</code> with proper permissions in the <code>Target</code> structure. For more information, see <a href="https://docs.aws.amazon.com/eventbridge/latest/userguide/eventbridge-cross-account-event-delivery.html">Sending and Receiving Events Between AWS Accounts</a> in the <i>Amazon EventBridge User Guide</i>.</p> <p>For more information about enabling cross-account events, see <a>PutPermission</a>.</p> <p> <code>Input</code>, <code>InputPath</code>, and <code>InputTransformer</code> are mutually exclusive and optional parameters of a target. When a rule is triggered due to a matched event:</p> <ul> <li> <p>If none of the following arguments are specified for a target, the entire event is passed to the target in JSON format (unless the target is Amazon EC2 Run Command or Amazon ECS task, in which case nothing from the event is passed to the target).</p> </li> <li> <p>If <code>Input</code> is specified in the form of valid JSON, then the matched event is overridden with this constant.</p> </li> <li> <p>If <code>InputPath</code> is specified in the form of JSONPath (for example, <code>$.detail</code>), only the part of the event specified in the path is passed to the target (for example, only the detail part of the event is passed).</p> </li> <li> <p>If <code>InputTransformer</code> is specified, one or more specified JSONPaths are extracted from the event and used as values in a template that you specify as the input to the target.</p> </li> </ul> <p>When you specify <code>InputPath</code> or <code>InputTransformer</code>, you must use JSON dot notation, not bracket notation.</p> <p>When you add targets to a rule and the associated rule triggers soon after, new or updated targets might not be immediately invoked. Allow a short period of time for changes to take effect.</p> <p>This action can partially fail if too many requests are made at the same time. If that happens, <code>FailedEntryCount</code> is nonzero in the response, and each entry in <code>FailedEntries</code> provides the ID of the failed target and the error code.</p>
+    async fn put_targets(
+        &self,
+        input: PutTargetsRequest,
+    ) -> Result<PutTargetsResponse, RusotoError<PutTargetsError>> {
+        self.put_targets_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Revokes the permission of another AWS account to be able to put events to the specified event bus. Specify the account to revoke by the <code>StatementId</code> value that you associated with the account when you granted it permission with <code>PutPermission</code>. You can find the <code>StatementId</code> by using <a>DescribeEventBus</a>.</p>
+    async fn remove_permission(
+        &self,
+        input: RemovePermissionRequest,
+    ) -> Result<(), RusotoError<RemovePermissionError>> {
+        self.remove_permission_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Removes the specified targets from the specified rule. When the rule is triggered, those targets are no longer be invoked.</p> <p>When you remove a target, when the associated rule triggers, removed targets might continue to be invoked. Allow a short period of time for changes to take effect.</p> <p>This action can partially fail if too many requests are made at the same time. If that happens, <code>FailedEntryCount</code> is non-zero in the response and each entry in <code>FailedEntries</code> provides the ID of the failed target and the error code.</p>
+    async fn remove_targets(
+        &self,
+        input: RemoveTargetsRequest,
+    ) -> Result<RemoveTargetsResponse, RusotoError<RemoveTargetsError>> {
+        self.remove_targets_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Assigns one or more tags (key-value pairs) to the specified EventBridge resource. Tags can help you organize and categorize your resources. You can also use them to scope user permissions by granting a user permission to access or change only resources with certain tag values. In EventBridge, rules can be tagged.</p> <p>Tags don't have any semantic meaning to AWS and are interpreted strictly as strings of characters.</p> <p>You can use the <code>TagResource</code> action with a rule that already has tags. If you specify a new tag key for the rule, this tag is appended to the list of tags associated with the rule. If you specify a tag key that is already associated with the rule, the new tag value that you specify replaces the previous value for that tag.</p> <p>You can associate as many as 50 tags with a resource.</p>
+    async fn tag_resource(
+        &self,
+        input: TagResourceRequest,
+    ) -> Result<TagResourceResponse, RusotoError<TagResourceError>> {
+        self.tag_resource_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Tests whether the specified event pattern matches the provided event.</p> <p>Most services in AWS treat <code>:</code> or <code>/</code> as the same character in Amazon Resource Names (ARNs). However, EventBridge uses an exact match in event patterns and rules. Be sure to use the correct ARN characters when creating event patterns so that they match the ARN syntax in the event that you want to match.</p>
+    async fn test_event_pattern(
+        &self,
+        input: TestEventPatternRequest,
+    ) -> Result<TestEventPatternResponse, RusotoError<TestEventPatternError>> {
+        self.test_event_pattern_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Removes one or more tags from the specified EventBridge resource. In EventBridge, rules can be tagged.</p>
+    async fn untag_resource(
+        &self,
+        input: UntagResourceRequest,
+    ) -> Result<UntagResourceResponse, RusotoError<UntagResourceError>> {
+        self.untag_resource_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
     }
 }
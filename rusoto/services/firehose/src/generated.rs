@@ -16,8 +16,8 @@ use std::fmt;
 use async_trait::async_trait;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
-use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest, ResponseMetadata};
+use rusoto_core::{Client, ErrorMetadata, RusotoError};
 
 use rusoto_core::proto;
 use rusoto_core::signature::SignedRequest;
@@ -1531,6 +1531,7 @@ pub struct UpdateDestinationOutput {}
 
 /// Errors returned by CreateDeliveryStream
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateDeliveryStreamError {
     /// <p>The specified input parameter has a value that is not valid.</p>
     InvalidArgument(String),
@@ -1540,6 +1541,8 @@ pub enum CreateDeliveryStreamError {
     LimitExceeded(String),
     /// <p>The resource is already in use and not available for this operation.</p>
     ResourceInUse(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateDeliveryStreamError {
@@ -1547,23 +1550,31 @@ impl CreateDeliveryStreamError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidArgumentException" => {
-                    return RusotoError::Service(CreateDeliveryStreamError::InvalidArgument(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateDeliveryStreamError::InvalidArgument(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidKMSResourceException" => {
-                    return RusotoError::Service(CreateDeliveryStreamError::InvalidKMSResource(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateDeliveryStreamError::InvalidKMSResource(err.msg),
+                        &res,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateDeliveryStreamError::LimitExceeded(err.msg))
+                    return RusotoError::service(
+                        CreateDeliveryStreamError::LimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(CreateDeliveryStreamError::ResourceInUse(err.msg))
+                    return RusotoError::service(
+                        CreateDeliveryStreamError::ResourceInUse(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreateDeliveryStreamError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1577,17 +1588,21 @@ impl fmt::Display for CreateDeliveryStreamError {
             CreateDeliveryStreamError::InvalidKMSResource(ref cause) => write!(f, "{}", cause),
             CreateDeliveryStreamError::LimitExceeded(ref cause) => write!(f, "{}", cause),
             CreateDeliveryStreamError::ResourceInUse(ref cause) => write!(f, "{}", cause),
+            CreateDeliveryStreamError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateDeliveryStreamError {}
 /// Errors returned by DeleteDeliveryStream
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteDeliveryStreamError {
     /// <p>The resource is already in use and not available for this operation.</p>
     ResourceInUse(String),
     /// <p>The specified resource could not be found.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteDeliveryStreamError {
@@ -1595,15 +1610,19 @@ impl DeleteDeliveryStreamError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceInUseException" => {
-                    return RusotoError::Service(DeleteDeliveryStreamError::ResourceInUse(err.msg))
+                    return RusotoError::service(
+                        DeleteDeliveryStreamError::ResourceInUse(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteDeliveryStreamError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteDeliveryStreamError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteDeliveryStreamError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1615,15 +1634,19 @@ impl fmt::Display for DeleteDeliveryStreamError {
         match *self {
             DeleteDeliveryStreamError::ResourceInUse(ref cause) => write!(f, "{}", cause),
             DeleteDeliveryStreamError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            DeleteDeliveryStreamError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteDeliveryStreamError {}
 /// Errors returned by DescribeDeliveryStream
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeDeliveryStreamError {
     /// <p>The specified resource could not be found.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeDeliveryStreamError {
@@ -1631,12 +1654,13 @@ impl DescribeDeliveryStreamError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeDeliveryStreamError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeDeliveryStreamError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DescribeDeliveryStreamError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1647,20 +1671,25 @@ impl fmt::Display for DescribeDeliveryStreamError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             DescribeDeliveryStreamError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            DescribeDeliveryStreamError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeDeliveryStreamError {}
 /// Errors returned by ListDeliveryStreams
 #[derive(Debug, PartialEq)]
-pub enum ListDeliveryStreamsError {}
+#[non_exhaustive]
+pub enum ListDeliveryStreamsError {
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
+}
 
 impl ListDeliveryStreamsError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<ListDeliveryStreamsError> {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListDeliveryStreamsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1669,12 +1698,15 @@ impl ListDeliveryStreamsError {
 impl fmt::Display for ListDeliveryStreamsError {
     #[allow(unused_variables)]
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        match *self {}
+        match *self {
+            ListDeliveryStreamsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
+        }
     }
 }
 impl Error for ListDeliveryStreamsError {}
 /// Errors returned by ListTagsForDeliveryStream
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListTagsForDeliveryStreamError {
     /// <p>The specified input parameter has a value that is not valid.</p>
     InvalidArgument(String),
@@ -1682,6 +1714,8 @@ pub enum ListTagsForDeliveryStreamError {
     LimitExceeded(String),
     /// <p>The specified resource could not be found.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListTagsForDeliveryStreamError {
@@ -1689,22 +1723,27 @@ impl ListTagsForDeliveryStreamError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidArgumentException" => {
-                    return RusotoError::Service(ListTagsForDeliveryStreamError::InvalidArgument(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListTagsForDeliveryStreamError::InvalidArgument(err.msg),
+                        &res,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(ListTagsForDeliveryStreamError::LimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListTagsForDeliveryStreamError::LimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListTagsForDeliveryStreamError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListTagsForDeliveryStreamError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(ListTagsForDeliveryStreamError::Unhandled, &res)
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -1717,12 +1756,14 @@ impl fmt::Display for ListTagsForDeliveryStreamError {
             ListTagsForDeliveryStreamError::InvalidArgument(ref cause) => write!(f, "{}", cause),
             ListTagsForDeliveryStreamError::LimitExceeded(ref cause) => write!(f, "{}", cause),
             ListTagsForDeliveryStreamError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            ListTagsForDeliveryStreamError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListTagsForDeliveryStreamError {}
 /// Errors returned by PutRecord
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum PutRecordError {
     /// <p>The specified input parameter has a value that is not valid.</p>
     InvalidArgument(String),
@@ -1732,6 +1773,8 @@ pub enum PutRecordError {
     ResourceNotFound(String),
     /// <p>The service is unavailable. Back off and retry the operation. If you continue to see the exception, throughput limits for the delivery stream may have been exceeded. For more information about limits and how to request an increase, see <a href="https://docs.aws.amazon.com/firehose/latest/dev/limits.html">Amazon Kinesis Data Firehose Limits</a>.</p>
     ServiceUnavailable(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl PutRecordError {
@@ -1739,19 +1782,19 @@ impl PutRecordError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidArgumentException" => {
-                    return RusotoError::Service(PutRecordError::InvalidArgument(err.msg))
+                    return RusotoError::service(PutRecordError::InvalidArgument(err.msg), &res)
                 }
                 "InvalidKMSResourceException" => {
-                    return RusotoError::Service(PutRecordError::InvalidKMSResource(err.msg))
+                    return RusotoError::service(PutRecordError::InvalidKMSResource(err.msg), &res)
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(PutRecordError::ResourceNotFound(err.msg))
+                    return RusotoError::service(PutRecordError::ResourceNotFound(err.msg), &res)
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(PutRecordError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(PutRecordError::ServiceUnavailable(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(PutRecordError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1765,12 +1808,14 @@ impl fmt::Display for PutRecordError {
             PutRecordError::InvalidKMSResource(ref cause) => write!(f, "{}", cause),
             PutRecordError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             PutRecordError::ServiceUnavailable(ref cause) => write!(f, "{}", cause),
+            PutRecordError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for PutRecordError {}
 /// Errors returned by PutRecordBatch
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum PutRecordBatchError {
     /// <p>The specified input parameter has a value that is not valid.</p>
     InvalidArgument(String),
@@ -1780,6 +1825,8 @@ pub enum PutRecordBatchError {
     ResourceNotFound(String),
     /// <p>The service is unavailable. Back off and retry the operation. If you continue to see the exception, throughput limits for the delivery stream may have been exceeded. For more information about limits and how to request an increase, see <a href="https://docs.aws.amazon.com/firehose/latest/dev/limits.html">Amazon Kinesis Data Firehose Limits</a>.</p>
     ServiceUnavailable(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl PutRecordBatchError {
@@ -1787,19 +1834,31 @@ impl PutRecordBatchError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidArgumentException" => {
-                    return RusotoError::Service(PutRecordBatchError::InvalidArgument(err.msg))
+                    return RusotoError::service(
+                        PutRecordBatchError::InvalidArgument(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidKMSResourceException" => {
-                    return RusotoError::Service(PutRecordBatchError::InvalidKMSResource(err.msg))
+                    return RusotoError::service(
+                        PutRecordBatchError::InvalidKMSResource(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(PutRecordBatchError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        PutRecordBatchError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(PutRecordBatchError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        PutRecordBatchError::ServiceUnavailable(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(PutRecordBatchError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1813,12 +1872,14 @@ impl fmt::Display for PutRecordBatchError {
             PutRecordBatchError::InvalidKMSResource(ref cause) => write!(f, "{}", cause),
             PutRecordBatchError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             PutRecordBatchError::ServiceUnavailable(ref cause) => write!(f, "{}", cause),
+            PutRecordBatchError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for PutRecordBatchError {}
 /// Errors returned by StartDeliveryStreamEncryption
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum StartDeliveryStreamEncryptionError {
     /// <p>The specified input parameter has a value that is not valid.</p>
     InvalidArgument(String),
@@ -1830,6 +1891,8 @@ pub enum StartDeliveryStreamEncryptionError {
     ResourceInUse(String),
     /// <p>The specified resource could not be found.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl StartDeliveryStreamEncryptionError {
@@ -1839,32 +1902,42 @@ impl StartDeliveryStreamEncryptionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidArgumentException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         StartDeliveryStreamEncryptionError::InvalidArgument(err.msg),
+                        &res,
                     )
                 }
                 "InvalidKMSResourceException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         StartDeliveryStreamEncryptionError::InvalidKMSResource(err.msg),
+                        &res,
                     )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(StartDeliveryStreamEncryptionError::LimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        StartDeliveryStreamEncryptionError::LimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(StartDeliveryStreamEncryptionError::ResourceInUse(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        StartDeliveryStreamEncryptionError::ResourceInUse(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         StartDeliveryStreamEncryptionError::ResourceNotFound(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        StartDeliveryStreamEncryptionError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -1885,12 +1958,16 @@ impl fmt::Display for StartDeliveryStreamEncryptionError {
             StartDeliveryStreamEncryptionError::ResourceNotFound(ref cause) => {
                 write!(f, "{}", cause)
             }
+            StartDeliveryStreamEncryptionError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for StartDeliveryStreamEncryptionError {}
 /// Errors returned by StopDeliveryStreamEncryption
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum StopDeliveryStreamEncryptionError {
     /// <p>The specified input parameter has a value that is not valid.</p>
     InvalidArgument(String),
@@ -1900,6 +1977,8 @@ pub enum StopDeliveryStreamEncryptionError {
     ResourceInUse(String),
     /// <p>The specified resource could not be found.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl StopDeliveryStreamEncryptionError {
@@ -1909,27 +1988,36 @@ impl StopDeliveryStreamEncryptionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidArgumentException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         StopDeliveryStreamEncryptionError::InvalidArgument(err.msg),
+                        &res,
                     )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(StopDeliveryStreamEncryptionError::LimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        StopDeliveryStreamEncryptionError::LimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(StopDeliveryStreamEncryptionError::ResourceInUse(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        StopDeliveryStreamEncryptionError::ResourceInUse(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         StopDeliveryStreamEncryptionError::ResourceNotFound(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        StopDeliveryStreamEncryptionError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -1945,12 +2033,14 @@ impl fmt::Display for StopDeliveryStreamEncryptionError {
             StopDeliveryStreamEncryptionError::ResourceNotFound(ref cause) => {
                 write!(f, "{}", cause)
             }
+            StopDeliveryStreamEncryptionError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for StopDeliveryStreamEncryptionError {}
 /// Errors returned by TagDeliveryStream
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum TagDeliveryStreamError {
     /// <p>The specified input parameter has a value that is not valid.</p>
     InvalidArgument(String),
@@ -1960,6 +2050,8 @@ pub enum TagDeliveryStreamError {
     ResourceInUse(String),
     /// <p>The specified resource could not be found.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl TagDeliveryStreamError {
@@ -1967,19 +2059,31 @@ impl TagDeliveryStreamError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidArgumentException" => {
-                    return RusotoError::Service(TagDeliveryStreamError::InvalidArgument(err.msg))
+                    return RusotoError::service(
+                        TagDeliveryStreamError::InvalidArgument(err.msg),
+                        &res,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(TagDeliveryStreamError::LimitExceeded(err.msg))
+                    return RusotoError::service(
+                        TagDeliveryStreamError::LimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(TagDeliveryStreamError::ResourceInUse(err.msg))
+                    return RusotoError::service(
+                        TagDeliveryStreamError::ResourceInUse(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(TagDeliveryStreamError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        TagDeliveryStreamError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(TagDeliveryStreamError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1993,12 +2097,14 @@ impl fmt::Display for TagDeliveryStreamError {
             TagDeliveryStreamError::LimitExceeded(ref cause) => write!(f, "{}", cause),
             TagDeliveryStreamError::ResourceInUse(ref cause) => write!(f, "{}", cause),
             TagDeliveryStreamError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            TagDeliveryStreamError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for TagDeliveryStreamError {}
 /// Errors returned by UntagDeliveryStream
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UntagDeliveryStreamError {
     /// <p>The specified input parameter has a value that is not valid.</p>
     InvalidArgument(String),
@@ -2008,6 +2114,8 @@ pub enum UntagDeliveryStreamError {
     ResourceInUse(String),
     /// <p>The specified resource could not be found.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UntagDeliveryStreamError {
@@ -2015,21 +2123,31 @@ impl UntagDeliveryStreamError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidArgumentException" => {
-                    return RusotoError::Service(UntagDeliveryStreamError::InvalidArgument(err.msg))
+                    return RusotoError::service(
+                        UntagDeliveryStreamError::InvalidArgument(err.msg),
+                        &res,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(UntagDeliveryStreamError::LimitExceeded(err.msg))
+                    return RusotoError::service(
+                        UntagDeliveryStreamError::LimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(UntagDeliveryStreamError::ResourceInUse(err.msg))
+                    return RusotoError::service(
+                        UntagDeliveryStreamError::ResourceInUse(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UntagDeliveryStreamError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UntagDeliveryStreamError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UntagDeliveryStreamError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2043,12 +2161,14 @@ impl fmt::Display for UntagDeliveryStreamError {
             UntagDeliveryStreamError::LimitExceeded(ref cause) => write!(f, "{}", cause),
             UntagDeliveryStreamError::ResourceInUse(ref cause) => write!(f, "{}", cause),
             UntagDeliveryStreamError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            UntagDeliveryStreamError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for UntagDeliveryStreamError {}
 /// Errors returned by UpdateDestination
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdateDestinationError {
     /// <p>Another modification has already happened. Fetch <code>VersionId</code> again and use it to update the destination.</p>
     ConcurrentModification(String),
@@ -2058,6 +2178,8 @@ pub enum UpdateDestinationError {
     ResourceInUse(String),
     /// <p>The specified resource could not be found.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdateDestinationError {
@@ -2065,21 +2187,31 @@ impl UpdateDestinationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(UpdateDestinationError::ConcurrentModification(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateDestinationError::ConcurrentModification(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidArgumentException" => {
-                    return RusotoError::Service(UpdateDestinationError::InvalidArgument(err.msg))
+                    return RusotoError::service(
+                        UpdateDestinationError::InvalidArgument(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(UpdateDestinationError::ResourceInUse(err.msg))
+                    return RusotoError::service(
+                        UpdateDestinationError::ResourceInUse(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateDestinationError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        UpdateDestinationError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UpdateDestinationError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2093,6 +2225,7 @@ impl fmt::Display for UpdateDestinationError {
             UpdateDestinationError::InvalidArgument(ref cause) => write!(f, "{}", cause),
             UpdateDestinationError::ResourceInUse(ref cause) => write!(f, "{}", cause),
             UpdateDestinationError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            UpdateDestinationError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
@@ -2183,6 +2316,7 @@ impl KinesisFirehoseClient {
     /// Creates a client backed by the default tokio event loop.
     ///
     /// The client will use the default credentials provider and tls client.
+    #[cfg(not(target_arch = "wasm32"))]
     pub fn new(region: region::Region) -> KinesisFirehoseClient {
         KinesisFirehoseClient {
             client: Client::shared(),
@@ -2208,128 +2342,303 @@ impl KinesisFirehoseClient {
     pub fn new_with_client(client: Client, region: region::Region) -> KinesisFirehoseClient {
         KinesisFirehoseClient { client, region }
     }
+
+    /// Returns a builder of a client, setting any of its region, credentials provider,
+    /// dispatcher, retry policy, endpoint and timeouts.
+    pub fn builder() -> rusoto_core::ClientBuilder<KinesisFirehoseClient> {
+        rusoto_core::ClientBuilder::new(KinesisFirehoseClient::new_with_client)
+    }
+
+    /// Returns a clone of this client with the overrides of `config` applied to its
+    /// operations, sharing its connections.
+    pub fn customize(&self, config: &rusoto_core::OperationConfig) -> KinesisFirehoseClient {
+        KinesisFirehoseClient {
+            client: self.client.customize(config),
+            region: config.resolve_region(&self.region),
+        }
+    }
 }
 
-#[async_trait]
-impl KinesisFirehose for KinesisFirehoseClient {
-    /// <p>Creates a Kinesis Data Firehose delivery stream.</p> <p>By default, you can create up to 50 delivery streams per AWS Region.</p> <p>This is an asynchronous operation that immediately returns. The initial status of the delivery stream is <code>CREATING</code>. After the delivery stream is created, its status is <code>ACTIVE</code> and it now accepts data. If the delivery stream creation fails, the status transitions to <code>CREATING_FAILED</code>. Attempts to send data to a delivery stream that is not in the <code>ACTIVE</code> state cause an exception. To check the state of a delivery stream, use <a>DescribeDeliveryStream</a>.</p> <p>If the status of a delivery stream is <code>CREATING_FAILED</code>, this status doesn't change, and you can't invoke <code>CreateDeliveryStream</code> again on it. However, you can invoke the <a>DeleteDeliveryStream</a> operation to delete it.</p> <p>A Kinesis Data Firehose delivery stream can be configured to receive records directly from providers using <a>PutRecord</a> or <a>PutRecordBatch</a>, or it can be configured to use an existing Kinesis stream as its source. To specify a Kinesis data stream as input, set the <code>DeliveryStreamType</code> parameter to <code>KinesisStreamAsSource</code>, and provide the Kinesis stream Amazon Resource Name (ARN) and role ARN in the <code>KinesisStreamSourceConfiguration</code> parameter.</p> <p>To create a delivery stream with server-side encryption (SSE) enabled, include <a>DeliveryStreamEncryptionConfigurationInput</a> in your request. This is optional. You can also invoke <a>StartDeliveryStreamEncryption</a> to turn on SSE for an existing delivery stream that doesn't have SSE enabled.</p> <p>A delivery stream is configured with a single destination: Amazon S3, Amazon ES, Amazon Redshift, or Splunk. You must specify only one of the following destination configuration parameters: <code>ExtendedS3DestinationConfiguration</code>, <code>S3DestinationConfiguration</code>, <code>ElasticsearchDestinationConfiguration</code>, <code>RedshiftDestinationConfiguration</code>, or <code>SplunkDestinationConfiguration</code>.</p> <p>When you specify <code>S3DestinationConfiguration</code>, you can also provide the following optional values: BufferingHints, <code>EncryptionConfiguration</code>, and <code>CompressionFormat</code>. By default, if no <code>BufferingHints</code> value is provided, Kinesis Data Firehose buffers data up to 5 MB or for 5 minutes, whichever condition is satisfied first. <code>BufferingHints</code> is a hint, so there are some cases where the service cannot adhere to these conditions strictly. For example, record boundaries might be such that the size is a little over or under the configured buffering size. By default, no encryption is performed. We strongly recommend that you enable encryption to ensure secure data storage in Amazon S3.</p> <p>A few notes about Amazon Redshift as a destination:</p> <ul> <li> <p>An Amazon Redshift destination requires an S3 bucket as intermediate location. Kinesis Data Firehose first delivers data to Amazon S3 and then uses <code>COPY</code> syntax to load data into an Amazon Redshift table. This is specified in the <code>RedshiftDestinationConfiguration.S3Configuration</code> parameter.</p> </li> <li> <p>The compression formats <code>SNAPPY</code> or <code>ZIP</code> cannot be specified in <code>RedshiftDestinationConfiguration.S3Configuration</code> because the Amazon Redshift <code>COPY</code> operation that reads from the S3 bucket doesn't support these compression formats.</p> </li> <li> <p>We strongly recommend that you use the user name and password you provide exclusively with Kinesis Data Firehose, and that the permissions for the account are restricted for Amazon Redshift <code>INSERT</code> permissions.</p> </li> </ul> <p>Kinesis Data Firehose assumes the IAM role that is configured as part of the destination. The role should allow the Kinesis Data Firehose principal to assume the role, and the role should have permissions that allow the service to deliver the data. For more information, see <a href="https://docs.aws.amazon.com/firehose/latest/dev/controlling-access.html#using-iam-s3">Grant Kinesis Data Firehose Access to an Amazon S3 Destination</a> in the <i>Amazon Kinesis Data Firehose Developer Guide</i>.</p>
-    async fn create_delivery_stream(
+impl KinesisFirehoseClient {
+    /// Calls `create_delivery_stream`, also returning the metadata of the response.
+    pub async fn create_delivery_stream_with_metadata(
         &self,
         input: CreateDeliveryStreamInput,
-    ) -> Result<CreateDeliveryStreamOutput, RusotoError<CreateDeliveryStreamError>> {
+    ) -> Result<
+        (CreateDeliveryStreamOutput, ResponseMetadata),
+        RusotoError<CreateDeliveryStreamError>,
+    > {
+        let request = self.build_create_delivery_stream_request(input);
+
+        let mut response = self
+            .client
+            .sign_and_dispatch(request)
+            .await
+            .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
+        if response.status.is_success() {
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<CreateDeliveryStreamOutput, _>()?;
+            Ok((result, metadata))
+        } else {
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(CreateDeliveryStreamError::from_response(response))
+        }
+    }
+
+    /// Presigns the request `create_delivery_stream` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_delivery_stream(
+        &self,
+        presigner: &rusoto_core::Presigner,
+        input: CreateDeliveryStreamInput,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_delivery_stream_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_delivery_stream_request(
+        &self,
+        input: CreateDeliveryStreamInput,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "firehose", &self.region, "/");
+        request.set_operation("CreateDeliveryStream");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Firehose_20150804.CreateDeliveryStream");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `delete_delivery_stream`, also returning the metadata of the response.
+    pub async fn delete_delivery_stream_with_metadata(
+        &self,
+        input: DeleteDeliveryStreamInput,
+    ) -> Result<
+        (DeleteDeliveryStreamOutput, ResponseMetadata),
+        RusotoError<DeleteDeliveryStreamError>,
+    > {
+        let request = self.build_delete_delivery_stream_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<CreateDeliveryStreamOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DeleteDeliveryStreamOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(CreateDeliveryStreamError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DeleteDeliveryStreamError::from_response(response))
         }
     }
 
-    /// <p>Deletes a delivery stream and its data.</p> <p>To check the state of a delivery stream, use <a>DescribeDeliveryStream</a>. You can delete a delivery stream only if it is in one of the following states: <code>ACTIVE</code>, <code>DELETING</code>, <code>CREATING_FAILED</code>, or <code>DELETING_FAILED</code>. You can't delete a delivery stream that is in the <code>CREATING</code> state. While the deletion request is in process, the delivery stream is in the <code>DELETING</code> state.</p> <p>While the delivery stream is in the <code>DELETING</code> state, the service might continue to accept records, but it doesn't make any guarantees with respect to delivering the data. Therefore, as a best practice, first stop any applications that are sending records before you delete a delivery stream.</p>
-    async fn delete_delivery_stream(
+    /// Presigns the request `delete_delivery_stream` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_delivery_stream(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteDeliveryStreamInput,
-    ) -> Result<DeleteDeliveryStreamOutput, RusotoError<DeleteDeliveryStreamError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_delivery_stream_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_delivery_stream_request(
+        &self,
+        input: DeleteDeliveryStreamInput,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "firehose", &self.region, "/");
+        request.set_operation("DeleteDeliveryStream");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Firehose_20150804.DeleteDeliveryStream");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `describe_delivery_stream`, also returning the metadata of the response.
+    pub async fn describe_delivery_stream_with_metadata(
+        &self,
+        input: DescribeDeliveryStreamInput,
+    ) -> Result<
+        (DescribeDeliveryStreamOutput, ResponseMetadata),
+        RusotoError<DescribeDeliveryStreamError>,
+    > {
+        let request = self.build_describe_delivery_stream_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DeleteDeliveryStreamOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DescribeDeliveryStreamOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteDeliveryStreamError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DescribeDeliveryStreamError::from_response(response))
         }
     }
 
-    /// <p>Describes the specified delivery stream and its status. For example, after your delivery stream is created, call <code>DescribeDeliveryStream</code> to see whether the delivery stream is <code>ACTIVE</code> and therefore ready for data to be sent to it. </p> <p>If the status of a delivery stream is <code>CREATING_FAILED</code>, this status doesn't change, and you can't invoke <a>CreateDeliveryStream</a> again on it. However, you can invoke the <a>DeleteDeliveryStream</a> operation to delete it. If the status is <code>DELETING_FAILED</code>, you can force deletion by invoking <a>DeleteDeliveryStream</a> again but with <a>DeleteDeliveryStreamInput$AllowForceDelete</a> set to true.</p>
-    async fn describe_delivery_stream(
+    /// Presigns the request `describe_delivery_stream` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_delivery_stream(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeDeliveryStreamInput,
-    ) -> Result<DescribeDeliveryStreamOutput, RusotoError<DescribeDeliveryStreamError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_delivery_stream_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_delivery_stream_request(
+        &self,
+        input: DescribeDeliveryStreamInput,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "firehose", &self.region, "/");
+        request.set_operation("DescribeDeliveryStream");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Firehose_20150804.DescribeDeliveryStream");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `list_delivery_streams`, also returning the metadata of the response.
+    pub async fn list_delivery_streams_with_metadata(
+        &self,
+        input: ListDeliveryStreamsInput,
+    ) -> Result<(ListDeliveryStreamsOutput, ResponseMetadata), RusotoError<ListDeliveryStreamsError>>
+    {
+        let request = self.build_list_delivery_streams_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DescribeDeliveryStreamOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ListDeliveryStreamsOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeDeliveryStreamError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ListDeliveryStreamsError::from_response(response))
         }
     }
 
-    /// <p>Lists your delivery streams in alphabetical order of their names.</p> <p>The number of delivery streams might be too large to return using a single call to <code>ListDeliveryStreams</code>. You can limit the number of delivery streams returned, using the <code>Limit</code> parameter. To determine whether there are more delivery streams to list, check the value of <code>HasMoreDeliveryStreams</code> in the output. If there are more delivery streams to list, you can request them by calling this operation again and setting the <code>ExclusiveStartDeliveryStreamName</code> parameter to the name of the last delivery stream returned in the last call.</p>
-    async fn list_delivery_streams(
+    /// Presigns the request `list_delivery_streams` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_delivery_streams(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListDeliveryStreamsInput,
-    ) -> Result<ListDeliveryStreamsOutput, RusotoError<ListDeliveryStreamsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_delivery_streams_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_delivery_streams_request(
+        &self,
+        input: ListDeliveryStreamsInput,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "firehose", &self.region, "/");
+        request.set_operation("ListDeliveryStreams");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Firehose_20150804.ListDeliveryStreams");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `list_tags_for_delivery_stream`, also returning the metadata of the response.
+    pub async fn list_tags_for_delivery_stream_with_metadata(
+        &self,
+        input: ListTagsForDeliveryStreamInput,
+    ) -> Result<
+        (ListTagsForDeliveryStreamOutput, ResponseMetadata),
+        RusotoError<ListTagsForDeliveryStreamError>,
+    > {
+        let request = self.build_list_tags_for_delivery_stream_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListDeliveryStreamsOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ListTagsForDeliveryStreamOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ListDeliveryStreamsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ListTagsForDeliveryStreamError::from_response(response))
         }
     }
 
-    /// <p>Lists the tags for the specified delivery stream. This operation has a limit of five transactions per second per account. </p>
-    async fn list_tags_for_delivery_stream(
+    /// Presigns the request `list_tags_for_delivery_stream` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_tags_for_delivery_stream(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListTagsForDeliveryStreamInput,
-    ) -> Result<ListTagsForDeliveryStreamOutput, RusotoError<ListTagsForDeliveryStreamError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_tags_for_delivery_stream_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_tags_for_delivery_stream_request(
+        &self,
+        input: ListTagsForDeliveryStreamInput,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "firehose", &self.region, "/");
+        request.set_operation("ListTagsForDeliveryStream");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -2339,83 +2648,162 @@ impl KinesisFirehose for KinesisFirehoseClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `put_record`, also returning the metadata of the response.
+    pub async fn put_record_with_metadata(
+        &self,
+        input: PutRecordInput,
+    ) -> Result<(PutRecordOutput, ResponseMetadata), RusotoError<PutRecordError>> {
+        let request = self.build_put_record_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListTagsForDeliveryStreamOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result =
+                proto::json::ResponsePayload::new(&response).deserialize::<PutRecordOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ListTagsForDeliveryStreamError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(PutRecordError::from_response(response))
         }
     }
 
-    /// <p><p>Writes a single data record into an Amazon Kinesis Data Firehose delivery stream. To write multiple data records into a delivery stream, use <a>PutRecordBatch</a>. Applications using these operations are referred to as producers.</p> <p>By default, each delivery stream can take in up to 2,000 transactions per second, 5,000 records per second, or 5 MB per second. If you use <a>PutRecord</a> and <a>PutRecordBatch</a>, the limits are an aggregate across these two operations for each delivery stream. For more information about limits and how to request an increase, see <a href="https://docs.aws.amazon.com/firehose/latest/dev/limits.html">Amazon Kinesis Data Firehose Limits</a>. </p> <p>You must specify the name of the delivery stream and the data record when using <a>PutRecord</a>. The data record consists of a data blob that can be up to 1,000 KB in size, and any kind of data. For example, it can be a segment from a log file, geographic location data, website clickstream data, and so on.</p> <p>Kinesis Data Firehose buffers records before delivering them to the destination. To disambiguate the data blobs at the destination, a common solution is to use delimiters in the data, such as a newline (<code>\n</code>) or some other character unique within the data. This allows the consumer application to parse individual data items when reading the data from the destination.</p> <p>The <code>PutRecord</code> operation returns a <code>RecordId</code>, which is a unique string assigned to each record. Producer applications can use this ID for purposes such as auditability and investigation.</p> <p>If the <code>PutRecord</code> operation throws a <code>ServiceUnavailableException</code>, back off and retry. If the exception persists, it is possible that the throughput limits have been exceeded for the delivery stream. </p> <p>Data records sent to Kinesis Data Firehose are stored for 24 hours from the time they are added to a delivery stream as it tries to send the records to the destination. If the destination is unreachable for more than 24 hours, the data is no longer available.</p> <important> <p>Don&#39;t concatenate two or more base64 strings to form the data fields of your records. Instead, concatenate the raw data, then perform base64 encoding.</p> </important></p>
-    async fn put_record(
+    /// Presigns the request `put_record` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_put_record(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: PutRecordInput,
-    ) -> Result<PutRecordOutput, RusotoError<PutRecordError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_put_record_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_put_record_request(&self, input: PutRecordInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "firehose", &self.region, "/");
+        request.set_operation("PutRecord");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Firehose_20150804.PutRecord");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `put_record_batch`, also returning the metadata of the response.
+    pub async fn put_record_batch_with_metadata(
+        &self,
+        input: PutRecordBatchInput,
+    ) -> Result<(PutRecordBatchOutput, ResponseMetadata), RusotoError<PutRecordBatchError>> {
+        let request = self.build_put_record_batch_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<PutRecordOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<PutRecordBatchOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(PutRecordError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(PutRecordBatchError::from_response(response))
         }
     }
 
-    /// <p><p>Writes multiple data records into a delivery stream in a single call, which can achieve higher throughput per producer than when writing single records. To write single data records into a delivery stream, use <a>PutRecord</a>. Applications using these operations are referred to as producers.</p> <p>By default, each delivery stream can take in up to 2,000 transactions per second, 5,000 records per second, or 5 MB per second. If you use <a>PutRecord</a> and <a>PutRecordBatch</a>, the limits are an aggregate across these two operations for each delivery stream. For more information about limits, see <a href="https://docs.aws.amazon.com/firehose/latest/dev/limits.html">Amazon Kinesis Data Firehose Limits</a>.</p> <p>Each <a>PutRecordBatch</a> request supports up to 500 records. Each record in the request can be as large as 1,000 KB (before 64-bit encoding), up to a limit of 4 MB for the entire request. These limits cannot be changed.</p> <p>You must specify the name of the delivery stream and the data record when using <a>PutRecord</a>. The data record consists of a data blob that can be up to 1,000 KB in size, and any kind of data. For example, it could be a segment from a log file, geographic location data, website clickstream data, and so on.</p> <p>Kinesis Data Firehose buffers records before delivering them to the destination. To disambiguate the data blobs at the destination, a common solution is to use delimiters in the data, such as a newline (<code>\n</code>) or some other character unique within the data. This allows the consumer application to parse individual data items when reading the data from the destination.</p> <p>The <a>PutRecordBatch</a> response includes a count of failed records, <code>FailedPutCount</code>, and an array of responses, <code>RequestResponses</code>. Even if the <a>PutRecordBatch</a> call succeeds, the value of <code>FailedPutCount</code> may be greater than 0, indicating that there are records for which the operation didn&#39;t succeed. Each entry in the <code>RequestResponses</code> array provides additional information about the processed record. It directly correlates with a record in the request array using the same ordering, from the top to the bottom. The response array always includes the same number of records as the request array. <code>RequestResponses</code> includes both successfully and unsuccessfully processed records. Kinesis Data Firehose tries to process all records in each <a>PutRecordBatch</a> request. A single record failure does not stop the processing of subsequent records. </p> <p>A successfully processed record includes a <code>RecordId</code> value, which is unique for the record. An unsuccessfully processed record includes <code>ErrorCode</code> and <code>ErrorMessage</code> values. <code>ErrorCode</code> reflects the type of error, and is one of the following values: <code>ServiceUnavailableException</code> or <code>InternalFailure</code>. <code>ErrorMessage</code> provides more detailed information about the error.</p> <p>If there is an internal server error or a timeout, the write might have completed or it might have failed. If <code>FailedPutCount</code> is greater than 0, retry the request, resending only those records that might have failed processing. This minimizes the possible duplicate records and also reduces the total bytes sent (and corresponding charges). We recommend that you handle any duplicates at the destination.</p> <p>If <a>PutRecordBatch</a> throws <code>ServiceUnavailableException</code>, back off and retry. If the exception persists, it is possible that the throughput limits have been exceeded for the delivery stream.</p> <p>Data records sent to Kinesis Data Firehose are stored for 24 hours from the time they are added to a delivery stream as it attempts to send the records to the destination. If the destination is unreachable for more than 24 hours, the data is no longer available.</p> <important> <p>Don&#39;t concatenate two or more base64 strings to form the data fields of your records. Instead, concatenate the raw data, then perform base64 encoding.</p> </important></p>
-    async fn put_record_batch(
+    /// Presigns the request `put_record_batch` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_put_record_batch(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: PutRecordBatchInput,
-    ) -> Result<PutRecordBatchOutput, RusotoError<PutRecordBatchError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_put_record_batch_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_put_record_batch_request(&self, input: PutRecordBatchInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "firehose", &self.region, "/");
+        request.set_operation("PutRecordBatch");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Firehose_20150804.PutRecordBatch");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `start_delivery_stream_encryption`, also returning the metadata of the response.
+    pub async fn start_delivery_stream_encryption_with_metadata(
+        &self,
+        input: StartDeliveryStreamEncryptionInput,
+    ) -> Result<
+        (StartDeliveryStreamEncryptionOutput, ResponseMetadata),
+        RusotoError<StartDeliveryStreamEncryptionError>,
+    > {
+        let request = self.build_start_delivery_stream_encryption_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<PutRecordBatchOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<StartDeliveryStreamEncryptionOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(PutRecordBatchError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(StartDeliveryStreamEncryptionError::from_response(response))
         }
     }
 
-    /// <p>Enables server-side encryption (SSE) for the delivery stream. </p> <p>This operation is asynchronous. It returns immediately. When you invoke it, Kinesis Data Firehose first sets the encryption status of the stream to <code>ENABLING</code>, and then to <code>ENABLED</code>. The encryption status of a delivery stream is the <code>Status</code> property in <a>DeliveryStreamEncryptionConfiguration</a>. If the operation fails, the encryption status changes to <code>ENABLING_FAILED</code>. You can continue to read and write data to your delivery stream while the encryption status is <code>ENABLING</code>, but the data is not encrypted. It can take up to 5 seconds after the encryption status changes to <code>ENABLED</code> before all records written to the delivery stream are encrypted. To find out whether a record or a batch of records was encrypted, check the response elements <a>PutRecordOutput$Encrypted</a> and <a>PutRecordBatchOutput$Encrypted</a>, respectively.</p> <p>To check the encryption status of a delivery stream, use <a>DescribeDeliveryStream</a>.</p> <p>Even if encryption is currently enabled for a delivery stream, you can still invoke this operation on it to change the ARN of the CMK or both its type and ARN. In this case, Kinesis Data Firehose schedules the grant it had on the old CMK for retirement and creates a grant that enables it to use the new CMK to encrypt and decrypt data and to manage the grant.</p> <p>If a delivery stream already has encryption enabled and then you invoke this operation to change the ARN of the CMK or both its type and ARN and you get <code>ENABLING_FAILED</code>, this only means that the attempt to change the CMK failed. In this case, encryption remains enabled with the old CMK.</p> <p>If the encryption status of your delivery stream is <code>ENABLING_FAILED</code>, you can invoke this operation again. </p> <p>You can only enable SSE for a delivery stream that uses <code>DirectPut</code> as its source. </p> <p>The <code>StartDeliveryStreamEncryption</code> and <code>StopDeliveryStreamEncryption</code> operations have a combined limit of 25 calls per delivery stream per 24 hours. For example, you reach the limit if you call <code>StartDeliveryStreamEncryption</code> 13 times and <code>StopDeliveryStreamEncryption</code> 12 times for the same delivery stream in a 24-hour period.</p>
-    async fn start_delivery_stream_encryption(
+    /// Presigns the request `start_delivery_stream_encryption` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_start_delivery_stream_encryption(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: StartDeliveryStreamEncryptionInput,
-    ) -> Result<StartDeliveryStreamEncryptionOutput, RusotoError<StartDeliveryStreamEncryptionError>>
-    {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_start_delivery_stream_encryption_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_start_delivery_stream_encryption_request(
+        &self,
+        input: StartDeliveryStreamEncryptionInput,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "firehose", &self.region, "/");
+        request.set_operation("StartDeliveryStreamEncryption");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -2425,29 +2813,58 @@ impl KinesisFirehose for KinesisFirehoseClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `stop_delivery_stream_encryption`, also returning the metadata of the response.
+    pub async fn stop_delivery_stream_encryption_with_metadata(
+        &self,
+        input: StopDeliveryStreamEncryptionInput,
+    ) -> Result<
+        (StopDeliveryStreamEncryptionOutput, ResponseMetadata),
+        RusotoError<StopDeliveryStreamEncryptionError>,
+    > {
+        let request = self.build_stop_delivery_stream_encryption_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<StartDeliveryStreamEncryptionOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<StopDeliveryStreamEncryptionOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(StartDeliveryStreamEncryptionError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(StopDeliveryStreamEncryptionError::from_response(response))
         }
     }
 
-    /// <p>Disables server-side encryption (SSE) for the delivery stream. </p> <p>This operation is asynchronous. It returns immediately. When you invoke it, Kinesis Data Firehose first sets the encryption status of the stream to <code>DISABLING</code>, and then to <code>DISABLED</code>. You can continue to read and write data to your stream while its status is <code>DISABLING</code>. It can take up to 5 seconds after the encryption status changes to <code>DISABLED</code> before all records written to the delivery stream are no longer subject to encryption. To find out whether a record or a batch of records was encrypted, check the response elements <a>PutRecordOutput$Encrypted</a> and <a>PutRecordBatchOutput$Encrypted</a>, respectively.</p> <p>To check the encryption state of a delivery stream, use <a>DescribeDeliveryStream</a>. </p> <p>If SSE is enabled using a customer managed CMK and then you invoke <code>StopDeliveryStreamEncryption</code>, Kinesis Data Firehose schedules the related KMS grant for retirement and then retires it after it ensures that it is finished delivering records to the destination.</p> <p>The <code>StartDeliveryStreamEncryption</code> and <code>StopDeliveryStreamEncryption</code> operations have a combined limit of 25 calls per delivery stream per 24 hours. For example, you reach the limit if you call <code>StartDeliveryStreamEncryption</code> 13 times and <code>StopDeliveryStreamEncryption</code> 12 times for the same delivery stream in a 24-hour period.</p>
-    async fn stop_delivery_stream_encryption(
+    /// Presigns the request `stop_delivery_stream_encryption` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_stop_delivery_stream_encryption(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: StopDeliveryStreamEncryptionInput,
-    ) -> Result<StopDeliveryStreamEncryptionOutput, RusotoError<StopDeliveryStreamEncryptionError>>
-    {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_stop_delivery_stream_encryption_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_stop_delivery_stream_encryption_request(
+        &self,
+        input: StopDeliveryStreamEncryptionInput,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "firehose", &self.region, "/");
+        request.set_operation("StopDeliveryStreamEncryption");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -2457,101 +2874,293 @@ impl KinesisFirehose for KinesisFirehoseClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `tag_delivery_stream`, also returning the metadata of the response.
+    pub async fn tag_delivery_stream_with_metadata(
+        &self,
+        input: TagDeliveryStreamInput,
+    ) -> Result<(TagDeliveryStreamOutput, ResponseMetadata), RusotoError<TagDeliveryStreamError>>
+    {
+        let request = self.build_tag_delivery_stream_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<StopDeliveryStreamEncryptionOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<TagDeliveryStreamOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(StopDeliveryStreamEncryptionError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(TagDeliveryStreamError::from_response(response))
         }
     }
 
-    /// <p>Adds or updates tags for the specified delivery stream. A tag is a key-value pair that you can define and assign to AWS resources. If you specify a tag that already exists, the tag value is replaced with the value that you specify in the request. Tags are metadata. For example, you can add friendly names and descriptions or other types of information that can help you distinguish the delivery stream. For more information about tags, see <a href="https://docs.aws.amazon.com/awsaccountbilling/latest/aboutv2/cost-alloc-tags.html">Using Cost Allocation Tags</a> in the <i>AWS Billing and Cost Management User Guide</i>. </p> <p>Each delivery stream can have up to 50 tags. </p> <p>This operation has a limit of five transactions per second per account. </p>
-    async fn tag_delivery_stream(
+    /// Presigns the request `tag_delivery_stream` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_tag_delivery_stream(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: TagDeliveryStreamInput,
-    ) -> Result<TagDeliveryStreamOutput, RusotoError<TagDeliveryStreamError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_tag_delivery_stream_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_tag_delivery_stream_request(&self, input: TagDeliveryStreamInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "firehose", &self.region, "/");
+        request.set_operation("TagDeliveryStream");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Firehose_20150804.TagDeliveryStream");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `untag_delivery_stream`, also returning the metadata of the response.
+    pub async fn untag_delivery_stream_with_metadata(
+        &self,
+        input: UntagDeliveryStreamInput,
+    ) -> Result<(UntagDeliveryStreamOutput, ResponseMetadata), RusotoError<UntagDeliveryStreamError>>
+    {
+        let request = self.build_untag_delivery_stream_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<TagDeliveryStreamOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<UntagDeliveryStreamOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(TagDeliveryStreamError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(UntagDeliveryStreamError::from_response(response))
         }
     }
 
-    /// <p>Removes tags from the specified delivery stream. Removed tags are deleted, and you can't recover them after this operation successfully completes.</p> <p>If you specify a tag that doesn't exist, the operation ignores it.</p> <p>This operation has a limit of five transactions per second per account. </p>
-    async fn untag_delivery_stream(
+    /// Presigns the request `untag_delivery_stream` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_untag_delivery_stream(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UntagDeliveryStreamInput,
-    ) -> Result<UntagDeliveryStreamOutput, RusotoError<UntagDeliveryStreamError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_untag_delivery_stream_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_untag_delivery_stream_request(
+        &self,
+        input: UntagDeliveryStreamInput,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "firehose", &self.region, "/");
+        request.set_operation("UntagDeliveryStream");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Firehose_20150804.UntagDeliveryStream");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `update_destination`, also returning the metadata of the response.
+    pub async fn update_destination_with_metadata(
+        &self,
+        input: UpdateDestinationInput,
+    ) -> Result<(UpdateDestinationOutput, ResponseMetadata), RusotoError<UpdateDestinationError>>
+    {
+        let request = self.build_update_destination_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<UntagDeliveryStreamOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<UpdateDestinationOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(UntagDeliveryStreamError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(UpdateDestinationError::from_response(response))
         }
     }
 
-    /// <p>Updates the specified destination of the specified delivery stream.</p> <p>Use this operation to change the destination type (for example, to replace the Amazon S3 destination with Amazon Redshift) or change the parameters associated with a destination (for example, to change the bucket name of the Amazon S3 destination). The update might not occur immediately. The target delivery stream remains active while the configurations are updated, so data writes to the delivery stream can continue during this process. The updated configurations are usually effective within a few minutes.</p> <p>Switching between Amazon ES and other services is not supported. For an Amazon ES destination, you can only update to another Amazon ES destination.</p> <p>If the destination type is the same, Kinesis Data Firehose merges the configuration parameters specified with the destination configuration that already exists on the delivery stream. If any of the parameters are not specified in the call, the existing values are retained. For example, in the Amazon S3 destination, if <a>EncryptionConfiguration</a> is not specified, then the existing <code>EncryptionConfiguration</code> is maintained on the destination.</p> <p>If the destination type is not the same, for example, changing the destination from Amazon S3 to Amazon Redshift, Kinesis Data Firehose does not merge any parameters. In this case, all parameters must be specified.</p> <p>Kinesis Data Firehose uses <code>CurrentDeliveryStreamVersionId</code> to avoid race conditions and conflicting merges. This is a required field, and the service updates the configuration only if the existing configuration has a version ID that matches. After the update is applied successfully, the version ID is updated, and can be retrieved using <a>DescribeDeliveryStream</a>. Use the new version ID to set <code>CurrentDeliveryStreamVersionId</code> in the next call.</p>
-    async fn update_destination(
+    /// Presigns the request `update_destination` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_destination(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UpdateDestinationInput,
-    ) -> Result<UpdateDestinationOutput, RusotoError<UpdateDestinationError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_destination_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_destination_request(&self, input: UpdateDestinationInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "firehose", &self.region, "/");
+        request.set_operation("UpdateDestination");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "Firehose_20150804.UpdateDestination");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
-        let mut response = self
-            .client
-            .sign_and_dispatch(request)
+        request
+    }
+}
+
+#[async_trait]
+impl KinesisFirehose for KinesisFirehoseClient {
+    /// <p>Creates a Kinesis Data Firehose delivery stream.</p> <p>By default, you can create up to 50 delivery streams per AWS Region.</p> <p>This is an asynchronous operation that immediately returns. The initial status of the delivery stream is <code>CREATING</code>. After the delivery stream is created, its status is <code>ACTIVE</code> and it now accepts data. If the delivery stream creation fails, the status transitions to <code>CREATING_FAILED</code>. Attempts to send data to a delivery stream that is not in the <code>ACTIVE</code> state cause an exception. To check the state of a delivery stream, use <a>DescribeDeliveryStream</a>.</p> <p>If the status of a delivery stream is <code>CREATING_FAILED</code>, this status doesn't change, and you can't invoke <code>CreateDeliveryStream</code> again on it. However, you can invoke the <a>DeleteDeliveryStream</a> operation to delete it.</p> <p>A Kinesis Data Firehose delivery stream can be configured to receive records directly from providers using <a>PutRecord</a> or <a>PutRecordBatch</a>, or it can be configured to use an existing Kinesis stream as its source. To specify a Kinesis data stream as input, set the <code>DeliveryStreamType</code> parameter to <code>KinesisStreamAsSource</code>, and provide the Kinesis stream Amazon Resource Name (ARN) and role ARN in the <code>KinesisStreamSourceConfiguration</code> parameter.</p> <p>To create a delivery stream with server-side encryption (SSE) enabled, include <a>DeliveryStreamEncryptionConfigurationInput</a> in your request. This is optional. You can also invoke <a>StartDeliveryStreamEncryption</a> to turn on SSE for an existing delivery stream that doesn't have SSE enabled.</p> <p>A delivery stream is configured with a single destination: Amazon S3, Amazon ES, Amazon Redshift, or Splunk. You must specify only one of the following destination configuration parameters: <code>ExtendedS3DestinationConfiguration</code>, <code>S3DestinationConfiguration</code>, <code>ElasticsearchDestinationConfiguration</code>, <code>RedshiftDestinationConfiguration</code>, or <code>SplunkDestinationConfiguration</code>.</p> <p>When you specify <code>S3DestinationConfiguration</code>, you can also provide the following optional values: BufferingHints, <code>EncryptionConfiguration</code>, and <code>CompressionFormat</code>. By default, if no <code>BufferingHints</code> value is provided, Kinesis Data Firehose buffers data up to 5 MB or for 5 minutes, whichever condition is satisfied first. <code>BufferingHints</code> is a hint, so there are some cases where the service cannot adhere to these conditions strictly. For example, record boundaries might be such that the size is a little over or under the configured buffering size. By default, no encryption is performed. We strongly recommend that you enable encryption to ensure secure data storage in Amazon S3.</p> <p>A few notes about Amazon Redshift as a destination:</p> <ul> <li> <p>An Amazon Redshift destination requires an S3 bucket as intermediate location. Kinesis Data Firehose first delivers data to Amazon S3 and then uses <code>COPY</code> syntax to load data into an Amazon Redshift table. This is specified in the <code>RedshiftDestinationConfiguration.S3Configuration</code> parameter.</p> </li> <li> <p>The compression formats <code>SNAPPY</code> or <code>ZIP</code> cannot be specified in <code>RedshiftDestinationConfiguration.S3Configuration</code> because the Amazon Redshift <code>COPY</code> operation that reads from the S3 bucket doesn't support these compression formats.</p> </li> <li> <p>We strongly recommend that you use the user name and password you provide exclusively with Kinesis Data Firehose, and that the permissions for the account are restricted for Amazon Redshift <code>INSERT</code> permissions.</p> </li> </ul> <p>Kinesis Data Firehose assumes the IAM role that is configured as part of the destination. The role should allow the Kinesis Data Firehose principal to assume the role, and the role should have permissions that allow the service to deliver the data. For more information, see <a href="https://docs.aws.amazon.com/firehose/latest/dev/controlling-access.html#using-iam-s3">Grant Kinesis Data Firehose Access to an Amazon S3 Destination</a> in the <i>Amazon Kinesis Data Firehose Developer Guide</i>.</p>
+    async fn create_delivery_stream(
+        &self,
+        input: CreateDeliveryStreamInput,
+    ) -> Result<CreateDeliveryStreamOutput, RusotoError<CreateDeliveryStreamError>> {
+        self.create_delivery_stream_with_metadata(input)
             .await
-            .map_err(RusotoError::from)?;
-        if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<UpdateDestinationOutput, _>()
-        } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(UpdateDestinationError::from_response(response))
-        }
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes a delivery stream and its data.</p> <p>To check the state of a delivery stream, use <a>DescribeDeliveryStream</a>. You can delete a delivery stream only if it is in one of the following states: <code>ACTIVE</code>, <code>DELETING</code>, <code>CREATING_FAILED</code>, or <code>DELETING_FAILED</code>. You can't delete a delivery stream that is in the <code>CREATING</code> state. While the deletion request is in process, the delivery stream is in the <code>DELETING</code> state.</p> <p>While the delivery stream is in the <code>DELETING</code> state, the service might continue to accept records, but it doesn't make any guarantees with respect to delivering the data. Therefore, as a best practice, first stop any applications that are sending records before you delete a delivery stream.</p>
+    async fn delete_delivery_stream(
+        &self,
+        input: DeleteDeliveryStreamInput,
+    ) -> Result<DeleteDeliveryStreamOutput, RusotoError<DeleteDeliveryStreamError>> {
+        self.delete_delivery_stream_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Describes the specified delivery stream and its status. For example, after your delivery stream is created, call <code>DescribeDeliveryStream</code> to see whether the delivery stream is <code>ACTIVE</code> and therefore ready for data to be sent to it. </p> <p>If the status of a delivery stream is <code>CREATING_FAILED</code>, this status doesn't change, and you can't invoke <a>CreateDeliveryStream</a> again on it. However, you can invoke the <a>DeleteDeliveryStream</a> operation to delete it. If the status is <code>DELETING_FAILED</code>, you can force deletion by invoking <a>DeleteDeliveryStream</a> again but with <a>DeleteDeliveryStreamInput$AllowForceDelete</a> set to true.</p>
+    async fn describe_delivery_stream(
+        &self,
+        input: DescribeDeliveryStreamInput,
+    ) -> Result<DescribeDeliveryStreamOutput, RusotoError<DescribeDeliveryStreamError>> {
+        self.describe_delivery_stream_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Lists your delivery streams in alphabetical order of their names.</p> <p>The number of delivery streams might be too large to return using a single call to <code>ListDeliveryStreams</code>. You can limit the number of delivery streams returned, using the <code>Limit</code> parameter. To determine whether there are more delivery streams to list, check the value of <code>HasMoreDeliveryStreams</code> in the output. If there are more delivery streams to list, you can request them by calling this operation again and setting the <code>ExclusiveStartDeliveryStreamName</code> parameter to the name of the last delivery stream returned in the last call.</p>
+    async fn list_delivery_streams(
+        &self,
+        input: ListDeliveryStreamsInput,
+    ) -> Result<ListDeliveryStreamsOutput, RusotoError<ListDeliveryStreamsError>> {
+        self.list_delivery_streams_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Lists the tags for the specified delivery stream. This operation has a limit of five transactions per second per account. </p>
+    async fn list_tags_for_delivery_stream(
+        &self,
+        input: ListTagsForDeliveryStreamInput,
+    ) -> Result<ListTagsForDeliveryStreamOutput, RusotoError<ListTagsForDeliveryStreamError>> {
+        self.list_tags_for_delivery_stream_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Writes a single data record into an Amazon Kinesis Data Firehose delivery stream. To write multiple data records into a delivery stream, use <a>PutRecordBatch</a>. Applications using these operations are referred to as producers.</p> <p>By default, each delivery stream can take in up to 2,000 transactions per second, 5,000 records per second, or 5 MB per second. If you use <a>PutRecord</a> and <a>PutRecordBatch</a>, the limits are an aggregate across these two operations for each delivery stream. For more information about limits and how to request an increase, see <a href="https://docs.aws.amazon.com/firehose/latest/dev/limits.html">Amazon Kinesis Data Firehose Limits</a>. </p> <p>You must specify the name of the delivery stream and the data record when using <a>PutRecord</a>. The data record consists of a data blob that can be up to 1,000 KB in size, and any kind of data. For example, it can be a segment from a log file, geographic location data, website clickstream data, and so on.</p> <p>Kinesis Data Firehose buffers records before delivering them to the destination. To disambiguate the data blobs at the destination, a common solution is to use delimiters in the data, such as a newline (<code>\n</code>) or some other character unique within the data. This allows the consumer application to parse individual data items when reading the data from the destination.</p> <p>The <code>PutRecord</code> operation returns a <code>RecordId</code>, which is a unique string assigned to each record. Producer applications can use this ID for purposes such as auditability and investigation.</p> <p>If the <code>PutRecord</code> operation throws a <code>ServiceUnavailableException</code>, back off and retry. If the exception persists, it is possible that the throughput limits have been exceeded for the delivery stream. </p> <p>Data records sent to Kinesis Data Firehose are stored for 24 hours from the time they are added to a delivery stream as it tries to send the records to the destination. If the destination is unreachable for more than 24 hours, the data is no longer available.</p> <important> <p>Don&#39;t concatenate two or more base64 strings to form the data fields of your records. Instead, concatenate the raw data, then perform base64 encoding.</p> </important></p>
+    async fn put_record(
+        &self,
+        input: PutRecordInput,
+    ) -> Result<PutRecordOutput, RusotoError<PutRecordError>> {
+        self.put_record_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Writes multiple data records into a delivery stream in a single call, which can achieve higher throughput per producer than when writing single records. To write single data records into a delivery stream, use <a>PutRecord</a>. Applications using these operations are referred to as producers.</p> <p>By default, each delivery stream can take in up to 2,000 transactions per second, 5,000 records per second, or 5 MB per second. If you use <a>PutRecord</a> and <a>PutRecordBatch</a>, the limits are an aggregate across these two operations for each delivery stream. For more information about limits, see <a href="https://docs.aws.amazon.com/firehose/latest/dev/limits.html">Amazon Kinesis Data Firehose Limits</a>.</p> <p>Each <a>PutRecordBatch</a> request supports up to 500 records. Each record in the request can be as large as 1,000 KB (before 64-bit encoding), up to a limit of 4 MB for the entire request. These limits cannot be changed.</p> <p>You must specify the name of the delivery stream and the data record when using <a>PutRecord</a>. The data record consists of a data blob that can be up to 1,000 KB in size, and any kind of data. For example, it could be a segment from a log file, geographic location data, website clickstream data, and so on.</p> <p>Kinesis Data Firehose buffers records before delivering them to the destination. To disambiguate the data blobs at the destination, a common solution is to use delimiters in the data, such as a newline (<code>\n</code>) or some other character unique within the data. This allows the consumer application to parse individual data items when reading the data from the destination.</p> <p>The <a>PutRecordBatch</a> response includes a count of failed records, <code>FailedPutCount</code>, and an array of responses, <code>RequestResponses</code>. Even if the <a>PutRecordBatch</a> call succeeds, the value of <code>FailedPutCount</code> may be greater than 0, indicating that there are records for which the operation didn&#39;t succeed. Each entry in the <code>RequestResponses</code> array provides additional information about the processed record. It directly correlates with a record in the request array using the same ordering, from the top to the bottom. The response array always includes the same number of records as the request array. <code>RequestResponses</code> includes both successfully and unsuccessfully processed records. Kinesis Data Firehose tries to process all records in each <a>PutRecordBatch</a> request. A single record failure does not stop the processing of subsequent records. </p> <p>A successfully processed record includes a <code>RecordId</code> value, which is unique for the record. An unsuccessfully processed record includes <code>ErrorCode</code> and <code>ErrorMessage</code> values. <code>ErrorCode</code> reflects the type of error, and is one of the following values: <code>ServiceUnavailableException</code> or <code>InternalFailure</code>. <code>ErrorMessage</code> provides more detailed information about the error.</p> <p>If there is an internal server error or a timeout, the write might have completed or it might have failed. If <code>FailedPutCount</code> is greater than 0, retry the request, resending only those records that might have failed processing. This minimizes the possible duplicate records and also reduces the total bytes sent (and corresponding charges). We recommend that you handle any duplicates at the destination.</p> <p>If <a>PutRecordBatch</a> throws <code>ServiceUnavailableException</code>, back off and retry. If the exception persists, it is possible that the throughput limits have been exceeded for the delivery stream.</p> <p>Data records sent to Kinesis Data Firehose are stored for 24 hours from the time they are added to a delivery stream as it attempts to send the records to the destination. If the destination is unreachable for more than 24 hours, the data is no longer available.</p> <important> <p>Don&#39;t concatenate two or more base64 strings to form the data fields of your records. Instead, concatenate the raw data, then perform base64 encoding.</p> </important></p>
+    async fn put_record_batch(
+        &self,
+        input: PutRecordBatchInput,
+    ) -> Result<PutRecordBatchOutput, RusotoError<PutRecordBatchError>> {
+        self.put_record_batch_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Enables server-side encryption (SSE) for the delivery stream. </p> <p>This operation is asynchronous. It returns immediately. When you invoke it, Kinesis Data Firehose first sets the encryption status of the stream to <code>ENABLING</code>, and then to <code>ENABLED</code>. The encryption status of a delivery stream is the <code>Status</code> property in <a>DeliveryStreamEncryptionConfiguration</a>. If the operation fails, the encryption status changes to <code>ENABLING_FAILED</code>. You can continue to read and write data to your delivery stream while the encryption status is <code>ENABLING</code>, but the data is not encrypted. It can take up to 5 seconds after the encryption status changes to <code>ENABLED</code> before all records written to the delivery stream are encrypted. To find out whether a record or a batch of records was encrypted, check the response elements <a>PutRecordOutput$Encrypted</a> and <a>PutRecordBatchOutput$Encrypted</a>, respectively.</p> <p>To check the encryption status of a delivery stream, use <a>DescribeDeliveryStream</a>.</p> <p>Even if encryption is currently enabled for a delivery stream, you can still invoke this operation on it to change the ARN of the CMK or both its type and ARN. In this case, Kinesis Data Firehose schedules the grant it had on the old CMK for retirement and creates a grant that enables it to use the new CMK to encrypt and decrypt data and to manage the grant.</p> <p>If a delivery stream already has encryption enabled and then you invoke this operation to change the ARN of the CMK or both its type and ARN and you get <code>ENABLING_FAILED</code>, this only means that the attempt to change the CMK failed. In this case, encryption remains enabled with the old CMK.</p> <p>If the encryption status of your delivery stream is <code>ENABLING_FAILED</code>, you can invoke this operation again. </p> <p>You can only enable SSE for a delivery stream that uses <code>DirectPut</code> as its source. </p> <p>The <code>StartDeliveryStreamEncryption</code> and <code>StopDeliveryStreamEncryption</code> operations have a combined limit of 25 calls per delivery stream per 24 hours. For example, you reach the limit if you call <code>StartDeliveryStreamEncryption</code> 13 times and <code>StopDeliveryStreamEncryption</code> 12 times for the same delivery stream in a 24-hour period.</p>
+    async fn start_delivery_stream_encryption(
+        &self,
+        input: StartDeliveryStreamEncryptionInput,
+    ) -> Result<StartDeliveryStreamEncryptionOutput, RusotoError<StartDeliveryStreamEncryptionError>>
+    {
+        self.start_delivery_stream_encryption_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Disables server-side encryption (SSE) for the delivery stream. </p> <p>This operation is asynchronous. It returns immediately. When you invoke it, Kinesis Data Firehose first sets the encryption status of the stream to <code>DISABLING</code>, and then to <code>DISABLED</code>. You can continue to read and write data to your stream while its status is <code>DISABLING</code>. It can take up to 5 seconds after the encryption status changes to <code>DISABLED</code> before all records written to the delivery stream are no longer subject to encryption. To find out whether a record or a batch of records was encrypted, check the response elements <a>PutRecordOutput$Encrypted</a> and <a>PutRecordBatchOutput$Encrypted</a>, respectively.</p> <p>To check the encryption state of a delivery stream, use <a>DescribeDeliveryStream</a>. </p> <p>If SSE is enabled using a customer managed CMK and then you invoke <code>StopDeliveryStreamEncryption</code>, Kinesis Data Firehose schedules the related KMS grant for retirement and then retires it after it ensures that it is finished delivering records to the destination.</p> <p>The <code>StartDeliveryStreamEncryption</code> and <code>StopDeliveryStreamEncryption</code> operations have a combined limit of 25 calls per delivery stream per 24 hours. For example, you reach the limit if you call <code>StartDeliveryStreamEncryption</code> 13 times and <code>StopDeliveryStreamEncryption</code> 12 times for the same delivery stream in a 24-hour period.</p>
+    async fn stop_delivery_stream_encryption(
+        &self,
+        input: StopDeliveryStreamEncryptionInput,
+    ) -> Result<StopDeliveryStreamEncryptionOutput, RusotoError<StopDeliveryStreamEncryptionError>>
+    {
+        self.stop_delivery_stream_encryption_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Adds or updates tags for the specified delivery stream. A tag is a key-value pair that you can define and assign to AWS resources. If you specify a tag that already exists, the tag value is replaced with the value that you specify in the request. Tags are metadata. For example, you can add friendly names and descriptions or other types of information that can help you distinguish the delivery stream. For more information about tags, see <a href="https://docs.aws.amazon.com/awsaccountbilling/latest/aboutv2/cost-alloc-tags.html">Using Cost Allocation Tags</a> in the <i>AWS Billing and Cost Management User Guide</i>. </p> <p>Each delivery stream can have up to 50 tags. </p> <p>This operation has a limit of five transactions per second per account. </p>
+    async fn tag_delivery_stream(
+        &self,
+        input: TagDeliveryStreamInput,
+    ) -> Result<TagDeliveryStreamOutput, RusotoError<TagDeliveryStreamError>> {
+        self.tag_delivery_stream_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Removes tags from the specified delivery stream. Removed tags are deleted, and you can't recover them after this operation successfully completes.</p> <p>If you specify a tag that doesn't exist, the operation ignores it.</p> <p>This operation has a limit of five transactions per second per account. </p>
+    async fn untag_delivery_stream(
+        &self,
+        input: UntagDeliveryStreamInput,
+    ) -> Result<UntagDeliveryStreamOutput, RusotoError<UntagDeliveryStreamError>> {
+        self.untag_delivery_stream_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Updates the specified destination of the specified delivery stream.</p> <p>Use this operation to change the destination type (for example, to replace the Amazon S3 destination with Amazon Redshift) or change the parameters associated with a destination (for example, to change the bucket name of the Amazon S3 destination). The update might not occur immediately. The target delivery stream remains active while the configurations are updated, so data writes to the delivery stream can continue during this process. The updated configurations are usually effective within a few minutes.</p> <p>Switching between Amazon ES and other services is not supported. For an Amazon ES destination, you can only update to another Amazon ES destination.</p> <p>If the destination type is the same, Kinesis Data Firehose merges the configuration parameters specified with the destination configuration that already exists on the delivery stream. If any of the parameters are not specified in the call, the existing values are retained. For example, in the Amazon S3 destination, if <a>EncryptionConfiguration</a> is not specified, then the existing <code>EncryptionConfiguration</code> is maintained on the destination.</p> <p>If the destination type is not the same, for example, changing the destination from Amazon S3 to Amazon Redshift, Kinesis Data Firehose does not merge any parameters. In this case, all parameters must be specified.</p> <p>Kinesis Data Firehose uses <code>CurrentDeliveryStreamVersionId</code> to avoid race conditions and conflicting merges. This is a required field, and the service updates the configuration only if the existing configuration has a version ID that matches. After the update is applied successfully, the version ID is updated, and can be retrieved using <a>DescribeDeliveryStream</a>. Use the new version ID to set <code>CurrentDeliveryStreamVersionId</code> in the next call.</p>
+    async fn update_destination(
+        &self,
+        input: UpdateDestinationInput,
+    ) -> Result<UpdateDestinationOutput, RusotoError<UpdateDestinationError>> {
+        self.update_destination_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
     }
 }
@@ -16,8 +16,8 @@ use std::fmt;
 use async_trait::async_trait;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
-use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest, ResponseMetadata};
+use rusoto_core::{Client, ErrorMetadata, RusotoError};
 
 use rusoto_core::proto;
 use rusoto_core::signature::SignedRequest;
@@ -555,6 +555,7 @@ pub struct UntagResourceResponse {}
 
 /// Errors returned by AssociateAdminAccount
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum AssociateAdminAccountError {
     /// <p>The operation failed because of a system problem, even though the request was valid. Retry your request.</p>
     InternalError(String),
@@ -564,6 +565,8 @@ pub enum AssociateAdminAccountError {
     InvalidOperation(String),
     /// <p>The specified resource was not found.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl AssociateAdminAccountError {
@@ -571,23 +574,31 @@ impl AssociateAdminAccountError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalErrorException" => {
-                    return RusotoError::Service(AssociateAdminAccountError::InternalError(err.msg))
+                    return RusotoError::service(
+                        AssociateAdminAccountError::InternalError(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(AssociateAdminAccountError::InvalidInput(err.msg))
+                    return RusotoError::service(
+                        AssociateAdminAccountError::InvalidInput(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidOperationException" => {
-                    return RusotoError::Service(AssociateAdminAccountError::InvalidOperation(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        AssociateAdminAccountError::InvalidOperation(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(AssociateAdminAccountError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        AssociateAdminAccountError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(AssociateAdminAccountError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -601,12 +612,14 @@ impl fmt::Display for AssociateAdminAccountError {
             AssociateAdminAccountError::InvalidInput(ref cause) => write!(f, "{}", cause),
             AssociateAdminAccountError::InvalidOperation(ref cause) => write!(f, "{}", cause),
             AssociateAdminAccountError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            AssociateAdminAccountError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for AssociateAdminAccountError {}
 /// Errors returned by DeleteNotificationChannel
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteNotificationChannelError {
     /// <p>The operation failed because of a system problem, even though the request was valid. Retry your request.</p>
     InternalError(String),
@@ -614,6 +627,8 @@ pub enum DeleteNotificationChannelError {
     InvalidOperation(String),
     /// <p>The specified resource was not found.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteNotificationChannelError {
@@ -621,22 +636,27 @@ impl DeleteNotificationChannelError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalErrorException" => {
-                    return RusotoError::Service(DeleteNotificationChannelError::InternalError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteNotificationChannelError::InternalError(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidOperationException" => {
-                    return RusotoError::Service(DeleteNotificationChannelError::InvalidOperation(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteNotificationChannelError::InvalidOperation(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteNotificationChannelError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteNotificationChannelError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(DeleteNotificationChannelError::Unhandled, &res)
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -649,12 +669,14 @@ impl fmt::Display for DeleteNotificationChannelError {
             DeleteNotificationChannelError::InternalError(ref cause) => write!(f, "{}", cause),
             DeleteNotificationChannelError::InvalidOperation(ref cause) => write!(f, "{}", cause),
             DeleteNotificationChannelError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            DeleteNotificationChannelError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteNotificationChannelError {}
 /// Errors returned by DeletePolicy
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeletePolicyError {
     /// <p>The operation failed because of a system problem, even though the request was valid. Retry your request.</p>
     InternalError(String),
@@ -662,6 +684,8 @@ pub enum DeletePolicyError {
     InvalidOperation(String),
     /// <p>The specified resource was not found.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeletePolicyError {
@@ -669,16 +693,16 @@ impl DeletePolicyError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalErrorException" => {
-                    return RusotoError::Service(DeletePolicyError::InternalError(err.msg))
+                    return RusotoError::service(DeletePolicyError::InternalError(err.msg), &res)
                 }
                 "InvalidOperationException" => {
-                    return RusotoError::Service(DeletePolicyError::InvalidOperation(err.msg))
+                    return RusotoError::service(DeletePolicyError::InvalidOperation(err.msg), &res)
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeletePolicyError::ResourceNotFound(err.msg))
+                    return RusotoError::service(DeletePolicyError::ResourceNotFound(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeletePolicyError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -691,12 +715,14 @@ impl fmt::Display for DeletePolicyError {
             DeletePolicyError::InternalError(ref cause) => write!(f, "{}", cause),
             DeletePolicyError::InvalidOperation(ref cause) => write!(f, "{}", cause),
             DeletePolicyError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            DeletePolicyError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeletePolicyError {}
 /// Errors returned by DisassociateAdminAccount
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DisassociateAdminAccountError {
     /// <p>The operation failed because of a system problem, even though the request was valid. Retry your request.</p>
     InternalError(String),
@@ -704,6 +730,8 @@ pub enum DisassociateAdminAccountError {
     InvalidOperation(String),
     /// <p>The specified resource was not found.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DisassociateAdminAccountError {
@@ -711,22 +739,25 @@ impl DisassociateAdminAccountError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalErrorException" => {
-                    return RusotoError::Service(DisassociateAdminAccountError::InternalError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DisassociateAdminAccountError::InternalError(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidOperationException" => {
-                    return RusotoError::Service(DisassociateAdminAccountError::InvalidOperation(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DisassociateAdminAccountError::InvalidOperation(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DisassociateAdminAccountError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DisassociateAdminAccountError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DisassociateAdminAccountError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -739,12 +770,14 @@ impl fmt::Display for DisassociateAdminAccountError {
             DisassociateAdminAccountError::InternalError(ref cause) => write!(f, "{}", cause),
             DisassociateAdminAccountError::InvalidOperation(ref cause) => write!(f, "{}", cause),
             DisassociateAdminAccountError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            DisassociateAdminAccountError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DisassociateAdminAccountError {}
 /// Errors returned by GetAdminAccount
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetAdminAccountError {
     /// <p>The operation failed because of a system problem, even though the request was valid. Retry your request.</p>
     InternalError(String),
@@ -752,6 +785,8 @@ pub enum GetAdminAccountError {
     InvalidOperation(String),
     /// <p>The specified resource was not found.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetAdminAccountError {
@@ -759,16 +794,22 @@ impl GetAdminAccountError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalErrorException" => {
-                    return RusotoError::Service(GetAdminAccountError::InternalError(err.msg))
+                    return RusotoError::service(GetAdminAccountError::InternalError(err.msg), &res)
                 }
                 "InvalidOperationException" => {
-                    return RusotoError::Service(GetAdminAccountError::InvalidOperation(err.msg))
+                    return RusotoError::service(
+                        GetAdminAccountError::InvalidOperation(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetAdminAccountError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        GetAdminAccountError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetAdminAccountError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -781,17 +822,21 @@ impl fmt::Display for GetAdminAccountError {
             GetAdminAccountError::InternalError(ref cause) => write!(f, "{}", cause),
             GetAdminAccountError::InvalidOperation(ref cause) => write!(f, "{}", cause),
             GetAdminAccountError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            GetAdminAccountError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetAdminAccountError {}
 /// Errors returned by GetComplianceDetail
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetComplianceDetailError {
     /// <p>The operation failed because of a system problem, even though the request was valid. Retry your request.</p>
     InternalError(String),
     /// <p>The specified resource was not found.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetComplianceDetailError {
@@ -799,15 +844,19 @@ impl GetComplianceDetailError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalErrorException" => {
-                    return RusotoError::Service(GetComplianceDetailError::InternalError(err.msg))
+                    return RusotoError::service(
+                        GetComplianceDetailError::InternalError(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetComplianceDetailError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetComplianceDetailError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetComplianceDetailError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -819,12 +868,14 @@ impl fmt::Display for GetComplianceDetailError {
         match *self {
             GetComplianceDetailError::InternalError(ref cause) => write!(f, "{}", cause),
             GetComplianceDetailError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            GetComplianceDetailError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetComplianceDetailError {}
 /// Errors returned by GetNotificationChannel
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetNotificationChannelError {
     /// <p>The operation failed because of a system problem, even though the request was valid. Retry your request.</p>
     InternalError(String),
@@ -832,6 +883,8 @@ pub enum GetNotificationChannelError {
     InvalidOperation(String),
     /// <p>The specified resource was not found.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetNotificationChannelError {
@@ -839,22 +892,25 @@ impl GetNotificationChannelError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalErrorException" => {
-                    return RusotoError::Service(GetNotificationChannelError::InternalError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetNotificationChannelError::InternalError(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidOperationException" => {
-                    return RusotoError::Service(GetNotificationChannelError::InvalidOperation(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetNotificationChannelError::InvalidOperation(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetNotificationChannelError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetNotificationChannelError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetNotificationChannelError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -867,12 +923,14 @@ impl fmt::Display for GetNotificationChannelError {
             GetNotificationChannelError::InternalError(ref cause) => write!(f, "{}", cause),
             GetNotificationChannelError::InvalidOperation(ref cause) => write!(f, "{}", cause),
             GetNotificationChannelError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            GetNotificationChannelError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetNotificationChannelError {}
 /// Errors returned by GetPolicy
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetPolicyError {
     /// <p>The operation failed because of a system problem, even though the request was valid. Retry your request.</p>
     InternalError(String),
@@ -882,6 +940,8 @@ pub enum GetPolicyError {
     InvalidType(String),
     /// <p>The specified resource was not found.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetPolicyError {
@@ -889,19 +949,19 @@ impl GetPolicyError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalErrorException" => {
-                    return RusotoError::Service(GetPolicyError::InternalError(err.msg))
+                    return RusotoError::service(GetPolicyError::InternalError(err.msg), &res)
                 }
                 "InvalidOperationException" => {
-                    return RusotoError::Service(GetPolicyError::InvalidOperation(err.msg))
+                    return RusotoError::service(GetPolicyError::InvalidOperation(err.msg), &res)
                 }
                 "InvalidTypeException" => {
-                    return RusotoError::Service(GetPolicyError::InvalidType(err.msg))
+                    return RusotoError::service(GetPolicyError::InvalidType(err.msg), &res)
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetPolicyError::ResourceNotFound(err.msg))
+                    return RusotoError::service(GetPolicyError::ResourceNotFound(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetPolicyError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -915,12 +975,14 @@ impl fmt::Display for GetPolicyError {
             GetPolicyError::InvalidOperation(ref cause) => write!(f, "{}", cause),
             GetPolicyError::InvalidType(ref cause) => write!(f, "{}", cause),
             GetPolicyError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            GetPolicyError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetPolicyError {}
 /// Errors returned by GetProtectionStatus
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetProtectionStatusError {
     /// <p>The operation failed because of a system problem, even though the request was valid. Retry your request.</p>
     InternalError(String),
@@ -928,6 +990,8 @@ pub enum GetProtectionStatusError {
     InvalidInput(String),
     /// <p>The specified resource was not found.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetProtectionStatusError {
@@ -935,18 +999,25 @@ impl GetProtectionStatusError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalErrorException" => {
-                    return RusotoError::Service(GetProtectionStatusError::InternalError(err.msg))
+                    return RusotoError::service(
+                        GetProtectionStatusError::InternalError(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(GetProtectionStatusError::InvalidInput(err.msg))
+                    return RusotoError::service(
+                        GetProtectionStatusError::InvalidInput(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetProtectionStatusError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetProtectionStatusError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetProtectionStatusError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -959,17 +1030,21 @@ impl fmt::Display for GetProtectionStatusError {
             GetProtectionStatusError::InternalError(ref cause) => write!(f, "{}", cause),
             GetProtectionStatusError::InvalidInput(ref cause) => write!(f, "{}", cause),
             GetProtectionStatusError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            GetProtectionStatusError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetProtectionStatusError {}
 /// Errors returned by ListComplianceStatus
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListComplianceStatusError {
     /// <p>The operation failed because of a system problem, even though the request was valid. Retry your request.</p>
     InternalError(String),
     /// <p>The specified resource was not found.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListComplianceStatusError {
@@ -977,15 +1052,19 @@ impl ListComplianceStatusError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalErrorException" => {
-                    return RusotoError::Service(ListComplianceStatusError::InternalError(err.msg))
+                    return RusotoError::service(
+                        ListComplianceStatusError::InternalError(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListComplianceStatusError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListComplianceStatusError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListComplianceStatusError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -997,17 +1076,21 @@ impl fmt::Display for ListComplianceStatusError {
         match *self {
             ListComplianceStatusError::InternalError(ref cause) => write!(f, "{}", cause),
             ListComplianceStatusError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            ListComplianceStatusError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListComplianceStatusError {}
 /// Errors returned by ListMemberAccounts
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListMemberAccountsError {
     /// <p>The operation failed because of a system problem, even though the request was valid. Retry your request.</p>
     InternalError(String),
     /// <p>The specified resource was not found.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListMemberAccountsError {
@@ -1015,13 +1098,19 @@ impl ListMemberAccountsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalErrorException" => {
-                    return RusotoError::Service(ListMemberAccountsError::InternalError(err.msg))
+                    return RusotoError::service(
+                        ListMemberAccountsError::InternalError(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListMemberAccountsError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        ListMemberAccountsError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListMemberAccountsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1033,12 +1122,14 @@ impl fmt::Display for ListMemberAccountsError {
         match *self {
             ListMemberAccountsError::InternalError(ref cause) => write!(f, "{}", cause),
             ListMemberAccountsError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            ListMemberAccountsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListMemberAccountsError {}
 /// Errors returned by ListPolicies
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListPoliciesError {
     /// <p>The operation failed because of a system problem, even though the request was valid. Retry your request.</p>
     InternalError(String),
@@ -1048,6 +1139,8 @@ pub enum ListPoliciesError {
     LimitExceeded(String),
     /// <p>The specified resource was not found.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListPoliciesError {
@@ -1055,19 +1148,19 @@ impl ListPoliciesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalErrorException" => {
-                    return RusotoError::Service(ListPoliciesError::InternalError(err.msg))
+                    return RusotoError::service(ListPoliciesError::InternalError(err.msg), &res)
                 }
                 "InvalidOperationException" => {
-                    return RusotoError::Service(ListPoliciesError::InvalidOperation(err.msg))
+                    return RusotoError::service(ListPoliciesError::InvalidOperation(err.msg), &res)
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(ListPoliciesError::LimitExceeded(err.msg))
+                    return RusotoError::service(ListPoliciesError::LimitExceeded(err.msg), &res)
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListPoliciesError::ResourceNotFound(err.msg))
+                    return RusotoError::service(ListPoliciesError::ResourceNotFound(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListPoliciesError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1081,12 +1174,14 @@ impl fmt::Display for ListPoliciesError {
             ListPoliciesError::InvalidOperation(ref cause) => write!(f, "{}", cause),
             ListPoliciesError::LimitExceeded(ref cause) => write!(f, "{}", cause),
             ListPoliciesError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            ListPoliciesError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListPoliciesError {}
 /// Errors returned by ListTagsForResource
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListTagsForResourceError {
     /// <p>The operation failed because of a system problem, even though the request was valid. Retry your request.</p>
     InternalError(String),
@@ -1096,6 +1191,8 @@ pub enum ListTagsForResourceError {
     InvalidOperation(String),
     /// <p>The specified resource was not found.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListTagsForResourceError {
@@ -1103,23 +1200,31 @@ impl ListTagsForResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalErrorException" => {
-                    return RusotoError::Service(ListTagsForResourceError::InternalError(err.msg))
+                    return RusotoError::service(
+                        ListTagsForResourceError::InternalError(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(ListTagsForResourceError::InvalidInput(err.msg))
+                    return RusotoError::service(
+                        ListTagsForResourceError::InvalidInput(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidOperationException" => {
-                    return RusotoError::Service(ListTagsForResourceError::InvalidOperation(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListTagsForResourceError::InvalidOperation(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListTagsForResourceError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListTagsForResourceError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListTagsForResourceError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1133,12 +1238,14 @@ impl fmt::Display for ListTagsForResourceError {
             ListTagsForResourceError::InvalidInput(ref cause) => write!(f, "{}", cause),
             ListTagsForResourceError::InvalidOperation(ref cause) => write!(f, "{}", cause),
             ListTagsForResourceError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            ListTagsForResourceError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListTagsForResourceError {}
 /// Errors returned by PutNotificationChannel
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum PutNotificationChannelError {
     /// <p>The operation failed because of a system problem, even though the request was valid. Retry your request.</p>
     InternalError(String),
@@ -1146,6 +1253,8 @@ pub enum PutNotificationChannelError {
     InvalidOperation(String),
     /// <p>The specified resource was not found.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl PutNotificationChannelError {
@@ -1153,22 +1262,25 @@ impl PutNotificationChannelError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalErrorException" => {
-                    return RusotoError::Service(PutNotificationChannelError::InternalError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        PutNotificationChannelError::InternalError(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidOperationException" => {
-                    return RusotoError::Service(PutNotificationChannelError::InvalidOperation(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        PutNotificationChannelError::InvalidOperation(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(PutNotificationChannelError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        PutNotificationChannelError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(PutNotificationChannelError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1181,12 +1293,14 @@ impl fmt::Display for PutNotificationChannelError {
             PutNotificationChannelError::InternalError(ref cause) => write!(f, "{}", cause),
             PutNotificationChannelError::InvalidOperation(ref cause) => write!(f, "{}", cause),
             PutNotificationChannelError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            PutNotificationChannelError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for PutNotificationChannelError {}
 /// Errors returned by PutPolicy
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum PutPolicyError {
     /// <p>The operation failed because of a system problem, even though the request was valid. Retry your request.</p>
     InternalError(String),
@@ -1200,6 +1314,8 @@ pub enum PutPolicyError {
     LimitExceeded(String),
     /// <p>The specified resource was not found.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl PutPolicyError {
@@ -1207,25 +1323,25 @@ impl PutPolicyError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalErrorException" => {
-                    return RusotoError::Service(PutPolicyError::InternalError(err.msg))
+                    return RusotoError::service(PutPolicyError::InternalError(err.msg), &res)
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(PutPolicyError::InvalidInput(err.msg))
+                    return RusotoError::service(PutPolicyError::InvalidInput(err.msg), &res)
                 }
                 "InvalidOperationException" => {
-                    return RusotoError::Service(PutPolicyError::InvalidOperation(err.msg))
+                    return RusotoError::service(PutPolicyError::InvalidOperation(err.msg), &res)
                 }
                 "InvalidTypeException" => {
-                    return RusotoError::Service(PutPolicyError::InvalidType(err.msg))
+                    return RusotoError::service(PutPolicyError::InvalidType(err.msg), &res)
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(PutPolicyError::LimitExceeded(err.msg))
+                    return RusotoError::service(PutPolicyError::LimitExceeded(err.msg), &res)
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(PutPolicyError::ResourceNotFound(err.msg))
+                    return RusotoError::service(PutPolicyError::ResourceNotFound(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(PutPolicyError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1241,12 +1357,14 @@ impl fmt::Display for PutPolicyError {
             PutPolicyError::InvalidType(ref cause) => write!(f, "{}", cause),
             PutPolicyError::LimitExceeded(ref cause) => write!(f, "{}", cause),
             PutPolicyError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            PutPolicyError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for PutPolicyError {}
 /// Errors returned by TagResource
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum TagResourceError {
     /// <p>The operation failed because of a system problem, even though the request was valid. Retry your request.</p>
     InternalError(String),
@@ -1258,6 +1376,8 @@ pub enum TagResourceError {
     LimitExceeded(String),
     /// <p>The specified resource was not found.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl TagResourceError {
@@ -1265,22 +1385,22 @@ impl TagResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalErrorException" => {
-                    return RusotoError::Service(TagResourceError::InternalError(err.msg))
+                    return RusotoError::service(TagResourceError::InternalError(err.msg), &res)
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(TagResourceError::InvalidInput(err.msg))
+                    return RusotoError::service(TagResourceError::InvalidInput(err.msg), &res)
                 }
                 "InvalidOperationException" => {
-                    return RusotoError::Service(TagResourceError::InvalidOperation(err.msg))
+                    return RusotoError::service(TagResourceError::InvalidOperation(err.msg), &res)
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(TagResourceError::LimitExceeded(err.msg))
+                    return RusotoError::service(TagResourceError::LimitExceeded(err.msg), &res)
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(TagResourceError::ResourceNotFound(err.msg))
+                    return RusotoError::service(TagResourceError::ResourceNotFound(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(TagResourceError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1295,12 +1415,14 @@ impl fmt::Display for TagResourceError {
             TagResourceError::InvalidOperation(ref cause) => write!(f, "{}", cause),
             TagResourceError::LimitExceeded(ref cause) => write!(f, "{}", cause),
             TagResourceError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            TagResourceError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for TagResourceError {}
 /// Errors returned by UntagResource
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UntagResourceError {
     /// <p>The operation failed because of a system problem, even though the request was valid. Retry your request.</p>
     InternalError(String),
@@ -1310,6 +1432,8 @@ pub enum UntagResourceError {
     InvalidOperation(String),
     /// <p>The specified resource was not found.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UntagResourceError {
@@ -1317,19 +1441,25 @@ impl UntagResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalErrorException" => {
-                    return RusotoError::Service(UntagResourceError::InternalError(err.msg))
+                    return RusotoError::service(UntagResourceError::InternalError(err.msg), &res)
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(UntagResourceError::InvalidInput(err.msg))
+                    return RusotoError::service(UntagResourceError::InvalidInput(err.msg), &res)
                 }
                 "InvalidOperationException" => {
-                    return RusotoError::Service(UntagResourceError::InvalidOperation(err.msg))
+                    return RusotoError::service(
+                        UntagResourceError::InvalidOperation(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UntagResourceError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        UntagResourceError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UntagResourceError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1343,6 +1473,7 @@ impl fmt::Display for UntagResourceError {
             UntagResourceError::InvalidInput(ref cause) => write!(f, "{}", cause),
             UntagResourceError::InvalidOperation(ref cause) => write!(f, "{}", cause),
             UntagResourceError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            UntagResourceError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
@@ -1459,6 +1590,7 @@ impl FmsClient {
     /// Creates a client backed by the default tokio event loop.
     ///
     /// The client will use the default credentials provider and tls client.
+    #[cfg(not(target_arch = "wasm32"))]
     pub fn new(region: region::Region) -> FmsClient {
         FmsClient {
             client: Client::shared(),
@@ -1484,464 +1616,1186 @@ impl FmsClient {
     pub fn new_with_client(client: Client, region: region::Region) -> FmsClient {
         FmsClient { client, region }
     }
+
+    /// Returns a builder of a client, setting any of its region, credentials provider,
+    /// dispatcher, retry policy, endpoint and timeouts.
+    pub fn builder() -> rusoto_core::ClientBuilder<FmsClient> {
+        rusoto_core::ClientBuilder::new(FmsClient::new_with_client)
+    }
+
+    /// Returns a clone of this client with the overrides of `config` applied to its
+    /// operations, sharing its connections.
+    pub fn customize(&self, config: &rusoto_core::OperationConfig) -> FmsClient {
+        FmsClient {
+            client: self.client.customize(config),
+            region: config.resolve_region(&self.region),
+        }
+    }
 }
 
-#[async_trait]
-impl Fms for FmsClient {
-    /// <p>Sets the AWS Firewall Manager administrator account. AWS Firewall Manager must be associated with the master account of your AWS organization or associated with a member account that has the appropriate permissions. If the account ID that you submit is not an AWS Organizations master account, AWS Firewall Manager will set the appropriate permissions for the given member account.</p> <p>The account that you associate with AWS Firewall Manager is called the AWS Firewall Manager administrator account. </p>
-    async fn associate_admin_account(
+impl FmsClient {
+    /// Calls `associate_admin_account`, also returning the metadata of the response.
+    pub async fn associate_admin_account_with_metadata(
         &self,
         input: AssociateAdminAccountRequest,
-    ) -> Result<(), RusotoError<AssociateAdminAccountError>> {
+    ) -> Result<((), ResponseMetadata), RusotoError<AssociateAdminAccountError>> {
+        let request = self.build_associate_admin_account_request(input);
+
+        let mut response = self
+            .client
+            .sign_and_dispatch(request)
+            .await
+            .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
+        if response.status.is_success() {
+            std::mem::drop(response);
+            Ok(((), metadata))
+        } else {
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(AssociateAdminAccountError::from_response(response))
+        }
+    }
+
+    /// Presigns the request `associate_admin_account` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_associate_admin_account(
+        &self,
+        presigner: &rusoto_core::Presigner,
+        input: AssociateAdminAccountRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_associate_admin_account_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_associate_admin_account_request(
+        &self,
+        input: AssociateAdminAccountRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "fms", &self.region, "/");
+        request.set_operation("AssociateAdminAccount");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSFMS_20180101.AssociateAdminAccount");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `delete_notification_channel`, also returning the metadata of the response.
+    pub async fn delete_notification_channel_with_metadata(
+        &self,
+    ) -> Result<((), ResponseMetadata), RusotoError<DeleteNotificationChannelError>> {
+        let request = self.build_delete_notification_channel_request();
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
             std::mem::drop(response);
-            Ok(())
+            Ok(((), metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(AssociateAdminAccountError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DeleteNotificationChannelError::from_response(response))
         }
     }
 
-    /// <p>Deletes an AWS Firewall Manager association with the IAM role and the Amazon Simple Notification Service (SNS) topic that is used to record AWS Firewall Manager SNS logs.</p>
-    async fn delete_notification_channel(
+    /// Presigns the request `delete_notification_channel` would send with `presigner` instead of sending it.
+    pub async fn presign_delete_notification_channel(
         &self,
-    ) -> Result<(), RusotoError<DeleteNotificationChannelError>> {
+        presigner: &rusoto_core::Presigner,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_notification_channel_request();
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_notification_channel_request(&self) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "fms", &self.region, "/");
+        request.set_operation("DeleteNotificationChannel");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSFMS_20180101.DeleteNotificationChannel");
         request.set_payload(Some(bytes::Bytes::from_static(b"{}")));
 
+        request
+    }
+
+    /// Calls `delete_policy`, also returning the metadata of the response.
+    pub async fn delete_policy_with_metadata(
+        &self,
+        input: DeletePolicyRequest,
+    ) -> Result<((), ResponseMetadata), RusotoError<DeletePolicyError>> {
+        let request = self.build_delete_policy_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
             std::mem::drop(response);
-            Ok(())
+            Ok(((), metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteNotificationChannelError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DeletePolicyError::from_response(response))
         }
     }
 
-    /// <p>Permanently deletes an AWS Firewall Manager policy. </p>
-    async fn delete_policy(
+    /// Presigns the request `delete_policy` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_policy(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeletePolicyRequest,
-    ) -> Result<(), RusotoError<DeletePolicyError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_policy_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_policy_request(&self, input: DeletePolicyRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "fms", &self.region, "/");
+        request.set_operation("DeletePolicy");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSFMS_20180101.DeletePolicy");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `disassociate_admin_account`, also returning the metadata of the response.
+    pub async fn disassociate_admin_account_with_metadata(
+        &self,
+    ) -> Result<((), ResponseMetadata), RusotoError<DisassociateAdminAccountError>> {
+        let request = self.build_disassociate_admin_account_request();
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
             std::mem::drop(response);
-            Ok(())
+            Ok(((), metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DeletePolicyError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DisassociateAdminAccountError::from_response(response))
         }
     }
 
-    /// <p>Disassociates the account that has been set as the AWS Firewall Manager administrator account. To set a different account as the administrator account, you must submit an <code>AssociateAdminAccount</code> request.</p>
-    async fn disassociate_admin_account(
+    /// Presigns the request `disassociate_admin_account` would send with `presigner` instead of sending it.
+    pub async fn presign_disassociate_admin_account(
         &self,
-    ) -> Result<(), RusotoError<DisassociateAdminAccountError>> {
+        presigner: &rusoto_core::Presigner,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_disassociate_admin_account_request();
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_disassociate_admin_account_request(&self) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "fms", &self.region, "/");
+        request.set_operation("DisassociateAdminAccount");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSFMS_20180101.DisassociateAdminAccount");
         request.set_payload(Some(bytes::Bytes::from_static(b"{}")));
 
+        request
+    }
+
+    /// Calls `get_admin_account`, also returning the metadata of the response.
+    pub async fn get_admin_account_with_metadata(
+        &self,
+    ) -> Result<(GetAdminAccountResponse, ResponseMetadata), RusotoError<GetAdminAccountError>>
+    {
+        let request = self.build_get_admin_account_request();
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            std::mem::drop(response);
-            Ok(())
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<GetAdminAccountResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DisassociateAdminAccountError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(GetAdminAccountError::from_response(response))
         }
     }
 
-    /// <p>Returns the AWS Organizations master account that is associated with AWS Firewall Manager as the AWS Firewall Manager administrator.</p>
-    async fn get_admin_account(
+    /// Presigns the request `get_admin_account` would send with `presigner` instead of sending it.
+    pub async fn presign_get_admin_account(
         &self,
-    ) -> Result<GetAdminAccountResponse, RusotoError<GetAdminAccountError>> {
+        presigner: &rusoto_core::Presigner,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_admin_account_request();
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_admin_account_request(&self) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "fms", &self.region, "/");
+        request.set_operation("GetAdminAccount");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSFMS_20180101.GetAdminAccount");
         request.set_payload(Some(bytes::Bytes::from_static(b"{}")));
 
+        request
+    }
+
+    /// Calls `get_compliance_detail`, also returning the metadata of the response.
+    pub async fn get_compliance_detail_with_metadata(
+        &self,
+        input: GetComplianceDetailRequest,
+    ) -> Result<
+        (GetComplianceDetailResponse, ResponseMetadata),
+        RusotoError<GetComplianceDetailError>,
+    > {
+        let request = self.build_get_compliance_detail_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<GetAdminAccountResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<GetComplianceDetailResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(GetAdminAccountError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(GetComplianceDetailError::from_response(response))
         }
     }
 
-    /// <p>Returns detailed compliance information about the specified member account. Details include resources that are in and out of compliance with the specified policy. Resources are considered noncompliant for AWS WAF and Shield Advanced policies if the specified policy has not been applied to them. Resources are considered noncompliant for security group policies if they are in scope of the policy, they violate one or more of the policy rules, and remediation is disabled or not possible. </p>
-    async fn get_compliance_detail(
+    /// Presigns the request `get_compliance_detail` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_compliance_detail(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetComplianceDetailRequest,
-    ) -> Result<GetComplianceDetailResponse, RusotoError<GetComplianceDetailError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_compliance_detail_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_compliance_detail_request(
+        &self,
+        input: GetComplianceDetailRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "fms", &self.region, "/");
+        request.set_operation("GetComplianceDetail");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSFMS_20180101.GetComplianceDetail");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `get_notification_channel`, also returning the metadata of the response.
+    pub async fn get_notification_channel_with_metadata(
+        &self,
+    ) -> Result<
+        (GetNotificationChannelResponse, ResponseMetadata),
+        RusotoError<GetNotificationChannelError>,
+    > {
+        let request = self.build_get_notification_channel_request();
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<GetComplianceDetailResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<GetNotificationChannelResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(GetComplianceDetailError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(GetNotificationChannelError::from_response(response))
         }
     }
 
-    /// <p>Information about the Amazon Simple Notification Service (SNS) topic that is used to record AWS Firewall Manager SNS logs.</p>
-    async fn get_notification_channel(
+    /// Presigns the request `get_notification_channel` would send with `presigner` instead of sending it.
+    pub async fn presign_get_notification_channel(
         &self,
-    ) -> Result<GetNotificationChannelResponse, RusotoError<GetNotificationChannelError>> {
+        presigner: &rusoto_core::Presigner,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_notification_channel_request();
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_notification_channel_request(&self) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "fms", &self.region, "/");
+        request.set_operation("GetNotificationChannel");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSFMS_20180101.GetNotificationChannel");
         request.set_payload(Some(bytes::Bytes::from_static(b"{}")));
 
+        request
+    }
+
+    /// Calls `get_policy`, also returning the metadata of the response.
+    pub async fn get_policy_with_metadata(
+        &self,
+        input: GetPolicyRequest,
+    ) -> Result<(GetPolicyResponse, ResponseMetadata), RusotoError<GetPolicyError>> {
+        let request = self.build_get_policy_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<GetNotificationChannelResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<GetPolicyResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(GetNotificationChannelError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(GetPolicyError::from_response(response))
         }
     }
 
-    /// <p>Returns information about the specified AWS Firewall Manager policy.</p>
-    async fn get_policy(
+    /// Presigns the request `get_policy` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_policy(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetPolicyRequest,
-    ) -> Result<GetPolicyResponse, RusotoError<GetPolicyError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_policy_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_policy_request(&self, input: GetPolicyRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "fms", &self.region, "/");
+        request.set_operation("GetPolicy");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSFMS_20180101.GetPolicy");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `get_protection_status`, also returning the metadata of the response.
+    pub async fn get_protection_status_with_metadata(
+        &self,
+        input: GetProtectionStatusRequest,
+    ) -> Result<
+        (GetProtectionStatusResponse, ResponseMetadata),
+        RusotoError<GetProtectionStatusError>,
+    > {
+        let request = self.build_get_protection_status_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<GetPolicyResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<GetProtectionStatusResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(GetPolicyError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(GetProtectionStatusError::from_response(response))
         }
     }
 
-    /// <p>If you created a Shield Advanced policy, returns policy-level attack summary information in the event of a potential DDoS attack. Other policy types are currently unsupported.</p>
-    async fn get_protection_status(
+    /// Presigns the request `get_protection_status` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_protection_status(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetProtectionStatusRequest,
-    ) -> Result<GetProtectionStatusResponse, RusotoError<GetProtectionStatusError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_protection_status_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_protection_status_request(
+        &self,
+        input: GetProtectionStatusRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "fms", &self.region, "/");
+        request.set_operation("GetProtectionStatus");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSFMS_20180101.GetProtectionStatus");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `list_compliance_status`, also returning the metadata of the response.
+    pub async fn list_compliance_status_with_metadata(
+        &self,
+        input: ListComplianceStatusRequest,
+    ) -> Result<
+        (ListComplianceStatusResponse, ResponseMetadata),
+        RusotoError<ListComplianceStatusError>,
+    > {
+        let request = self.build_list_compliance_status_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<GetProtectionStatusResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ListComplianceStatusResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(GetProtectionStatusError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ListComplianceStatusError::from_response(response))
         }
     }
 
-    /// <p>Returns an array of <code>PolicyComplianceStatus</code> objects in the response. Use <code>PolicyComplianceStatus</code> to get a summary of which member accounts are protected by the specified policy. </p>
-    async fn list_compliance_status(
+    /// Presigns the request `list_compliance_status` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_compliance_status(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListComplianceStatusRequest,
-    ) -> Result<ListComplianceStatusResponse, RusotoError<ListComplianceStatusError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_compliance_status_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_compliance_status_request(
+        &self,
+        input: ListComplianceStatusRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "fms", &self.region, "/");
+        request.set_operation("ListComplianceStatus");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSFMS_20180101.ListComplianceStatus");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `list_member_accounts`, also returning the metadata of the response.
+    pub async fn list_member_accounts_with_metadata(
+        &self,
+        input: ListMemberAccountsRequest,
+    ) -> Result<(ListMemberAccountsResponse, ResponseMetadata), RusotoError<ListMemberAccountsError>>
+    {
+        let request = self.build_list_member_accounts_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListComplianceStatusResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ListMemberAccountsResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ListComplianceStatusError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ListMemberAccountsError::from_response(response))
         }
     }
 
-    /// <p>Returns a <code>MemberAccounts</code> object that lists the member accounts in the administrator's AWS organization.</p> <p>The <code>ListMemberAccounts</code> must be submitted by the account that is set as the AWS Firewall Manager administrator.</p>
-    async fn list_member_accounts(
+    /// Presigns the request `list_member_accounts` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_member_accounts(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListMemberAccountsRequest,
-    ) -> Result<ListMemberAccountsResponse, RusotoError<ListMemberAccountsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_member_accounts_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_member_accounts_request(
+        &self,
+        input: ListMemberAccountsRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "fms", &self.region, "/");
+        request.set_operation("ListMemberAccounts");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSFMS_20180101.ListMemberAccounts");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `list_policies`, also returning the metadata of the response.
+    pub async fn list_policies_with_metadata(
+        &self,
+        input: ListPoliciesRequest,
+    ) -> Result<(ListPoliciesResponse, ResponseMetadata), RusotoError<ListPoliciesError>> {
+        let request = self.build_list_policies_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListMemberAccountsResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ListPoliciesResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ListMemberAccountsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ListPoliciesError::from_response(response))
         }
     }
 
-    /// <p>Returns an array of <code>PolicySummary</code> objects in the response.</p>
-    async fn list_policies(
+    /// Presigns the request `list_policies` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_policies(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListPoliciesRequest,
-    ) -> Result<ListPoliciesResponse, RusotoError<ListPoliciesError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_policies_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_policies_request(&self, input: ListPoliciesRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "fms", &self.region, "/");
+        request.set_operation("ListPolicies");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSFMS_20180101.ListPolicies");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `list_tags_for_resource`, also returning the metadata of the response.
+    pub async fn list_tags_for_resource_with_metadata(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> Result<
+        (ListTagsForResourceResponse, ResponseMetadata),
+        RusotoError<ListTagsForResourceError>,
+    > {
+        let request = self.build_list_tags_for_resource_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<ListPoliciesResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ListTagsForResourceResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ListPoliciesError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ListTagsForResourceError::from_response(response))
         }
     }
 
-    /// <p>Retrieves the list of tags for the specified AWS resource. </p>
-    async fn list_tags_for_resource(
+    /// Presigns the request `list_tags_for_resource` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_tags_for_resource(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListTagsForResourceRequest,
-    ) -> Result<ListTagsForResourceResponse, RusotoError<ListTagsForResourceError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_tags_for_resource_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_tags_for_resource_request(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "fms", &self.region, "/");
+        request.set_operation("ListTagsForResource");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSFMS_20180101.ListTagsForResource");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `put_notification_channel`, also returning the metadata of the response.
+    pub async fn put_notification_channel_with_metadata(
+        &self,
+        input: PutNotificationChannelRequest,
+    ) -> Result<((), ResponseMetadata), RusotoError<PutNotificationChannelError>> {
+        let request = self.build_put_notification_channel_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListTagsForResourceResponse, _>()
+            std::mem::drop(response);
+            Ok(((), metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ListTagsForResourceError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(PutNotificationChannelError::from_response(response))
         }
     }
 
-    /// <p>Designates the IAM role and Amazon Simple Notification Service (SNS) topic that AWS Firewall Manager uses to record SNS logs.</p>
-    async fn put_notification_channel(
+    /// Presigns the request `put_notification_channel` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_put_notification_channel(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: PutNotificationChannelRequest,
-    ) -> Result<(), RusotoError<PutNotificationChannelError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_put_notification_channel_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_put_notification_channel_request(
+        &self,
+        input: PutNotificationChannelRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "fms", &self.region, "/");
+        request.set_operation("PutNotificationChannel");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSFMS_20180101.PutNotificationChannel");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `put_policy`, also returning the metadata of the response.
+    pub async fn put_policy_with_metadata(
+        &self,
+        input: PutPolicyRequest,
+    ) -> Result<(PutPolicyResponse, ResponseMetadata), RusotoError<PutPolicyError>> {
+        let request = self.build_put_policy_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            std::mem::drop(response);
-            Ok(())
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<PutPolicyResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(PutNotificationChannelError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(PutPolicyError::from_response(response))
         }
     }
 
-    /// <p>Creates an AWS Firewall Manager policy.</p> <p>Firewall Manager provides the following types of policies: </p> <ul> <li> <p>A Shield Advanced policy, which applies Shield Advanced protection to specified accounts and resources</p> </li> <li> <p>An AWS WAF policy, which contains a rule group and defines which resources are to be protected by that rule group</p> </li> <li> <p>A security group policy, which manages VPC security groups across your AWS organization. </p> </li> </ul> <p>Each policy is specific to one of the three types. If you want to enforce more than one policy type across accounts, you can create multiple policies. You can create multiple policies for each type.</p> <p>You must be subscribed to Shield Advanced to create a Shield Advanced policy. For more information about subscribing to Shield Advanced, see <a href="https://docs.aws.amazon.com/waf/latest/DDOSAPIReference/API_CreateSubscription.html">CreateSubscription</a>.</p>
-    async fn put_policy(
+    /// Presigns the request `put_policy` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_put_policy(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: PutPolicyRequest,
-    ) -> Result<PutPolicyResponse, RusotoError<PutPolicyError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_put_policy_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_put_policy_request(&self, input: PutPolicyRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "fms", &self.region, "/");
+        request.set_operation("PutPolicy");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSFMS_20180101.PutPolicy");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `tag_resource`, also returning the metadata of the response.
+    pub async fn tag_resource_with_metadata(
+        &self,
+        input: TagResourceRequest,
+    ) -> Result<(TagResourceResponse, ResponseMetadata), RusotoError<TagResourceError>> {
+        let request = self.build_tag_resource_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<PutPolicyResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<TagResourceResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(PutPolicyError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(TagResourceError::from_response(response))
         }
     }
 
-    /// <p>Adds one or more tags to an AWS resource.</p>
-    async fn tag_resource(
+    /// Presigns the request `tag_resource` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_tag_resource(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: TagResourceRequest,
-    ) -> Result<TagResourceResponse, RusotoError<TagResourceError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_tag_resource_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_tag_resource_request(&self, input: TagResourceRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "fms", &self.region, "/");
+        request.set_operation("TagResource");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSFMS_20180101.TagResource");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `untag_resource`, also returning the metadata of the response.
+    pub async fn untag_resource_with_metadata(
+        &self,
+        input: UntagResourceRequest,
+    ) -> Result<(UntagResourceResponse, ResponseMetadata), RusotoError<UntagResourceError>> {
+        let request = self.build_untag_resource_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<TagResourceResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<UntagResourceResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(TagResourceError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(UntagResourceError::from_response(response))
         }
     }
 
-    /// <p>Removes one or more tags from an AWS resource.</p>
-    async fn untag_resource(
+    /// Presigns the request `untag_resource` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_untag_resource(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UntagResourceRequest,
-    ) -> Result<UntagResourceResponse, RusotoError<UntagResourceError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_untag_resource_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_untag_resource_request(&self, input: UntagResourceRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "fms", &self.region, "/");
+        request.set_operation("UntagResource");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSFMS_20180101.UntagResource");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
-        let mut response = self
-            .client
-            .sign_and_dispatch(request)
+        request
+    }
+
+    /// The pages of `ListComplianceStatus` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `MaxResults` of `input`.
+    pub fn list_compliance_status_pages(
+        &self,
+        input: ListComplianceStatusRequest,
+    ) -> impl futures::Stream<
+        Item = Result<ListComplianceStatusResponse, RusotoError<ListComplianceStatusError>>,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: FmsClient, input| async move { client.list_compliance_status(input).await },
+            |input: &ListComplianceStatusRequest, output: &ListComplianceStatusResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListComplianceStatusRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `PolicyComplianceStatusList` of every page of `ListComplianceStatus` results for `input`, as `list_compliance_status_pages` requests them.
+    pub fn list_compliance_status_stream(
+        &self,
+        input: ListComplianceStatusRequest,
+    ) -> impl futures::Stream<
+        Item = Result<PolicyComplianceStatus, RusotoError<ListComplianceStatusError>>,
+    > + Send {
+        rusoto_core::pagination::items(self.list_compliance_status_pages(input), |page| {
+            page.policy_compliance_status_list.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `ListMemberAccounts` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `MaxResults` of `input`.
+    pub fn list_member_accounts_pages(
+        &self,
+        input: ListMemberAccountsRequest,
+    ) -> impl futures::Stream<
+        Item = Result<ListMemberAccountsResponse, RusotoError<ListMemberAccountsError>>,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: FmsClient, input| async move { client.list_member_accounts(input).await },
+            |input: &ListMemberAccountsRequest, output: &ListMemberAccountsResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListMemberAccountsRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `MemberAccounts` of every page of `ListMemberAccounts` results for `input`, as `list_member_accounts_pages` requests them.
+    pub fn list_member_accounts_stream(
+        &self,
+        input: ListMemberAccountsRequest,
+    ) -> impl futures::Stream<Item = Result<String, RusotoError<ListMemberAccountsError>>> + Send
+    {
+        rusoto_core::pagination::items(self.list_member_accounts_pages(input), |page| {
+            page.member_accounts.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `ListPolicies` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `MaxResults` of `input`.
+    pub fn list_policies_pages(
+        &self,
+        input: ListPoliciesRequest,
+    ) -> impl futures::Stream<Item = Result<ListPoliciesResponse, RusotoError<ListPoliciesError>>> + Send
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: FmsClient, input| async move { client.list_policies(input).await },
+            |input: &ListPoliciesRequest, output: &ListPoliciesResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListPoliciesRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `PolicyList` of every page of `ListPolicies` results for `input`, as `list_policies_pages` requests them.
+    pub fn list_policies_stream(
+        &self,
+        input: ListPoliciesRequest,
+    ) -> impl futures::Stream<Item = Result<PolicySummary, RusotoError<ListPoliciesError>>> + Send
+    {
+        rusoto_core::pagination::items(self.list_policies_pages(input), |page| {
+            page.policy_list.unwrap_or_default()
+        })
+    }
+}
+
+#[async_trait]
+impl Fms for FmsClient {
+    /// <p>Sets the AWS Firewall Manager administrator account. AWS Firewall Manager must be associated with the master account of your AWS organization or associated with a member account that has the appropriate permissions. If the account ID that you submit is not an AWS Organizations master account, AWS Firewall Manager will set the appropriate permissions for the given member account.</p> <p>The account that you associate with AWS Firewall Manager is called the AWS Firewall Manager administrator account. </p>
+    async fn associate_admin_account(
+        &self,
+        input: AssociateAdminAccountRequest,
+    ) -> Result<(), RusotoError<AssociateAdminAccountError>> {
+        self.associate_admin_account_with_metadata(input)
             .await
-            .map_err(RusotoError::from)?;
-        if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<UntagResourceResponse, _>()
-        } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(UntagResourceError::from_response(response))
-        }
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes an AWS Firewall Manager association with the IAM role and the Amazon Simple Notification Service (SNS) topic that is used to record AWS Firewall Manager SNS logs.</p>
+    async fn delete_notification_channel(
+        &self,
+    ) -> Result<(), RusotoError<DeleteNotificationChannelError>> {
+        self.delete_notification_channel_with_metadata()
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Permanently deletes an AWS Firewall Manager policy. </p>
+    async fn delete_policy(
+        &self,
+        input: DeletePolicyRequest,
+    ) -> Result<(), RusotoError<DeletePolicyError>> {
+        self.delete_policy_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Disassociates the account that has been set as the AWS Firewall Manager administrator account. To set a different account as the administrator account, you must submit an <code>AssociateAdminAccount</code> request.</p>
+    async fn disassociate_admin_account(
+        &self,
+    ) -> Result<(), RusotoError<DisassociateAdminAccountError>> {
+        self.disassociate_admin_account_with_metadata()
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns the AWS Organizations master account that is associated with AWS Firewall Manager as the AWS Firewall Manager administrator.</p>
+    async fn get_admin_account(
+        &self,
+    ) -> Result<GetAdminAccountResponse, RusotoError<GetAdminAccountError>> {
+        self.get_admin_account_with_metadata()
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns detailed compliance information about the specified member account. Details include resources that are in and out of compliance with the specified policy. Resources are considered noncompliant for AWS WAF and Shield Advanced policies if the specified policy has not been applied to them. Resources are considered noncompliant for security group policies if they are in scope of the policy, they violate one or more of the policy rules, and remediation is disabled or not possible. </p>
+    async fn get_compliance_detail(
+        &self,
+        input: GetComplianceDetailRequest,
+    ) -> Result<GetComplianceDetailResponse, RusotoError<GetComplianceDetailError>> {
+        self.get_compliance_detail_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Information about the Amazon Simple Notification Service (SNS) topic that is used to record AWS Firewall Manager SNS logs.</p>
+    async fn get_notification_channel(
+        &self,
+    ) -> Result<GetNotificationChannelResponse, RusotoError<GetNotificationChannelError>> {
+        self.get_notification_channel_with_metadata()
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns information about the specified AWS Firewall Manager policy.</p>
+    async fn get_policy(
+        &self,
+        input: GetPolicyRequest,
+    ) -> Result<GetPolicyResponse, RusotoError<GetPolicyError>> {
+        self.get_policy_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>If you created a Shield Advanced policy, returns policy-level attack summary information in the event of a potential DDoS attack. Other policy types are currently unsupported.</p>
+    async fn get_protection_status(
+        &self,
+        input: GetProtectionStatusRequest,
+    ) -> Result<GetProtectionStatusResponse, RusotoError<GetProtectionStatusError>> {
+        self.get_protection_status_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns an array of <code>PolicyComplianceStatus</code> objects in the response. Use <code>PolicyComplianceStatus</code> to get a summary of which member accounts are protected by the specified policy. </p>
+    async fn list_compliance_status(
+        &self,
+        input: ListComplianceStatusRequest,
+    ) -> Result<ListComplianceStatusResponse, RusotoError<ListComplianceStatusError>> {
+        self.list_compliance_status_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns a <code>MemberAccounts</code> object that lists the member accounts in the administrator's AWS organization.</p> <p>The <code>ListMemberAccounts</code> must be submitted by the account that is set as the AWS Firewall Manager administrator.</p>
+    async fn list_member_accounts(
+        &self,
+        input: ListMemberAccountsRequest,
+    ) -> Result<ListMemberAccountsResponse, RusotoError<ListMemberAccountsError>> {
+        self.list_member_accounts_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns an array of <code>PolicySummary</code> objects in the response.</p>
+    async fn list_policies(
+        &self,
+        input: ListPoliciesRequest,
+    ) -> Result<ListPoliciesResponse, RusotoError<ListPoliciesError>> {
+        self.list_policies_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Retrieves the list of tags for the specified AWS resource. </p>
+    async fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> Result<ListTagsForResourceResponse, RusotoError<ListTagsForResourceError>> {
+        self.list_tags_for_resource_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Designates the IAM role and Amazon Simple Notification Service (SNS) topic that AWS Firewall Manager uses to record SNS logs.</p>
+    async fn put_notification_channel(
+        &self,
+        input: PutNotificationChannelRequest,
+    ) -> Result<(), RusotoError<PutNotificationChannelError>> {
+        self.put_notification_channel_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Creates an AWS Firewall Manager policy.</p> <p>Firewall Manager provides the following types of policies: </p> <ul> <li> <p>A Shield Advanced policy, which applies Shield Advanced protection to specified accounts and resources</p> </li> <li> <p>An AWS WAF policy, which contains a rule group and defines which resources are to be protected by that rule group</p> </li> <li> <p>A security group policy, which manages VPC security groups across your AWS organization. </p> </li> </ul> <p>Each policy is specific to one of the three types. If you want to enforce more than one policy type across accounts, you can create multiple policies. You can create multiple policies for each type.</p> <p>You must be subscribed to Shield Advanced to create a Shield Advanced policy. For more information about subscribing to Shield Advanced, see <a href="https://docs.aws.amazon.com/waf/latest/DDOSAPIReference/API_CreateSubscription.html">CreateSubscription</a>.</p>
+    async fn put_policy(
+        &self,
+        input: PutPolicyRequest,
+    ) -> Result<PutPolicyResponse, RusotoError<PutPolicyError>> {
+        self.put_policy_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Adds one or more tags to an AWS resource.</p>
+    async fn tag_resource(
+        &self,
+        input: TagResourceRequest,
+    ) -> Result<TagResourceResponse, RusotoError<TagResourceError>> {
+        self.tag_resource_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Removes one or more tags from an AWS resource.</p>
+    async fn untag_resource(
+        &self,
+        input: UntagResourceRequest,
+    ) -> Result<UntagResourceResponse, RusotoError<UntagResourceError>> {
+        self.untag_resource_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
     }
 }
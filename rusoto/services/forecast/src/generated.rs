@@ -16,8 +16,8 @@ use std::fmt;
 use async_trait::async_trait;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
-use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest, ResponseMetadata};
+use rusoto_core::{Client, ErrorMetadata, RusotoError};
 
 use rusoto_core::proto;
 use rusoto_core::signature::SignedRequest;
@@ -1370,6 +1370,7 @@ pub struct WindowSummary {
 
 /// Errors returned by CreateDataset
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateDatasetError {
     /// <p>We can't process the request because it includes an invalid value or a value that exceeds the valid range.</p>
     InvalidInput(String),
@@ -1377,6 +1378,8 @@ pub enum CreateDatasetError {
     LimitExceeded(String),
     /// <p>There is already a resource with this name. Try again with a different name.</p>
     ResourceAlreadyExists(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateDatasetError {
@@ -1384,16 +1387,19 @@ impl CreateDatasetError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidInputException" => {
-                    return RusotoError::Service(CreateDatasetError::InvalidInput(err.msg))
+                    return RusotoError::service(CreateDatasetError::InvalidInput(err.msg), &res)
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateDatasetError::LimitExceeded(err.msg))
+                    return RusotoError::service(CreateDatasetError::LimitExceeded(err.msg), &res)
                 }
                 "ResourceAlreadyExistsException" => {
-                    return RusotoError::Service(CreateDatasetError::ResourceAlreadyExists(err.msg))
+                    return RusotoError::service(
+                        CreateDatasetError::ResourceAlreadyExists(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreateDatasetError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1406,12 +1412,14 @@ impl fmt::Display for CreateDatasetError {
             CreateDatasetError::InvalidInput(ref cause) => write!(f, "{}", cause),
             CreateDatasetError::LimitExceeded(ref cause) => write!(f, "{}", cause),
             CreateDatasetError::ResourceAlreadyExists(ref cause) => write!(f, "{}", cause),
+            CreateDatasetError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateDatasetError {}
 /// Errors returned by CreateDatasetGroup
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateDatasetGroupError {
     /// <p>We can't process the request because it includes an invalid value or a value that exceeds the valid range.</p>
     InvalidInput(String),
@@ -1423,6 +1431,8 @@ pub enum CreateDatasetGroupError {
     ResourceInUse(String),
     /// <p>We can't find a resource with that Amazon Resource Name (ARN). Check the ARN and try again.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateDatasetGroupError {
@@ -1430,24 +1440,37 @@ impl CreateDatasetGroupError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidInputException" => {
-                    return RusotoError::Service(CreateDatasetGroupError::InvalidInput(err.msg))
+                    return RusotoError::service(
+                        CreateDatasetGroupError::InvalidInput(err.msg),
+                        &res,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateDatasetGroupError::LimitExceeded(err.msg))
+                    return RusotoError::service(
+                        CreateDatasetGroupError::LimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceAlreadyExistsException" => {
-                    return RusotoError::Service(CreateDatasetGroupError::ResourceAlreadyExists(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateDatasetGroupError::ResourceAlreadyExists(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(CreateDatasetGroupError::ResourceInUse(err.msg))
+                    return RusotoError::service(
+                        CreateDatasetGroupError::ResourceInUse(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(CreateDatasetGroupError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        CreateDatasetGroupError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreateDatasetGroupError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1462,12 +1485,14 @@ impl fmt::Display for CreateDatasetGroupError {
             CreateDatasetGroupError::ResourceAlreadyExists(ref cause) => write!(f, "{}", cause),
             CreateDatasetGroupError::ResourceInUse(ref cause) => write!(f, "{}", cause),
             CreateDatasetGroupError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            CreateDatasetGroupError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateDatasetGroupError {}
 /// Errors returned by CreateDatasetImportJob
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateDatasetImportJobError {
     /// <p>We can't process the request because it includes an invalid value or a value that exceeds the valid range.</p>
     InvalidInput(String),
@@ -1479,6 +1504,8 @@ pub enum CreateDatasetImportJobError {
     ResourceInUse(String),
     /// <p>We can't find a resource with that Amazon Resource Name (ARN). Check the ARN and try again.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateDatasetImportJobError {
@@ -1486,30 +1513,37 @@ impl CreateDatasetImportJobError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidInputException" => {
-                    return RusotoError::Service(CreateDatasetImportJobError::InvalidInput(err.msg))
+                    return RusotoError::service(
+                        CreateDatasetImportJobError::InvalidInput(err.msg),
+                        &res,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateDatasetImportJobError::LimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateDatasetImportJobError::LimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceAlreadyExistsException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         CreateDatasetImportJobError::ResourceAlreadyExists(err.msg),
+                        &res,
                     )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(CreateDatasetImportJobError::ResourceInUse(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateDatasetImportJobError::ResourceInUse(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(CreateDatasetImportJobError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateDatasetImportJobError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreateDatasetImportJobError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1524,12 +1558,14 @@ impl fmt::Display for CreateDatasetImportJobError {
             CreateDatasetImportJobError::ResourceAlreadyExists(ref cause) => write!(f, "{}", cause),
             CreateDatasetImportJobError::ResourceInUse(ref cause) => write!(f, "{}", cause),
             CreateDatasetImportJobError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            CreateDatasetImportJobError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateDatasetImportJobError {}
 /// Errors returned by CreateForecast
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateForecastError {
     /// <p>We can't process the request because it includes an invalid value or a value that exceeds the valid range.</p>
     InvalidInput(String),
@@ -1541,6 +1577,8 @@ pub enum CreateForecastError {
     ResourceInUse(String),
     /// <p>We can't find a resource with that Amazon Resource Name (ARN). Check the ARN and try again.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateForecastError {
@@ -1548,24 +1586,28 @@ impl CreateForecastError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidInputException" => {
-                    return RusotoError::Service(CreateForecastError::InvalidInput(err.msg))
+                    return RusotoError::service(CreateForecastError::InvalidInput(err.msg), &res)
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateForecastError::LimitExceeded(err.msg))
+                    return RusotoError::service(CreateForecastError::LimitExceeded(err.msg), &res)
                 }
                 "ResourceAlreadyExistsException" => {
-                    return RusotoError::Service(CreateForecastError::ResourceAlreadyExists(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateForecastError::ResourceAlreadyExists(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(CreateForecastError::ResourceInUse(err.msg))
+                    return RusotoError::service(CreateForecastError::ResourceInUse(err.msg), &res)
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(CreateForecastError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        CreateForecastError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreateForecastError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1580,12 +1622,14 @@ impl fmt::Display for CreateForecastError {
             CreateForecastError::ResourceAlreadyExists(ref cause) => write!(f, "{}", cause),
             CreateForecastError::ResourceInUse(ref cause) => write!(f, "{}", cause),
             CreateForecastError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            CreateForecastError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateForecastError {}
 /// Errors returned by CreateForecastExportJob
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateForecastExportJobError {
     /// <p>We can't process the request because it includes an invalid value or a value that exceeds the valid range.</p>
     InvalidInput(String),
@@ -1597,6 +1641,8 @@ pub enum CreateForecastExportJobError {
     ResourceInUse(String),
     /// <p>We can't find a resource with that Amazon Resource Name (ARN). Check the ARN and try again.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateForecastExportJobError {
@@ -1604,32 +1650,37 @@ impl CreateForecastExportJobError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidInputException" => {
-                    return RusotoError::Service(CreateForecastExportJobError::InvalidInput(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateForecastExportJobError::InvalidInput(err.msg),
+                        &res,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateForecastExportJobError::LimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateForecastExportJobError::LimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceAlreadyExistsException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         CreateForecastExportJobError::ResourceAlreadyExists(err.msg),
+                        &res,
                     )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(CreateForecastExportJobError::ResourceInUse(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateForecastExportJobError::ResourceInUse(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(CreateForecastExportJobError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateForecastExportJobError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreateForecastExportJobError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1646,12 +1697,14 @@ impl fmt::Display for CreateForecastExportJobError {
             }
             CreateForecastExportJobError::ResourceInUse(ref cause) => write!(f, "{}", cause),
             CreateForecastExportJobError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            CreateForecastExportJobError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateForecastExportJobError {}
 /// Errors returned by CreatePredictor
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreatePredictorError {
     /// <p>We can't process the request because it includes an invalid value or a value that exceeds the valid range.</p>
     InvalidInput(String),
@@ -1663,6 +1716,8 @@ pub enum CreatePredictorError {
     ResourceInUse(String),
     /// <p>We can't find a resource with that Amazon Resource Name (ARN). Check the ARN and try again.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreatePredictorError {
@@ -1670,24 +1725,28 @@ impl CreatePredictorError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidInputException" => {
-                    return RusotoError::Service(CreatePredictorError::InvalidInput(err.msg))
+                    return RusotoError::service(CreatePredictorError::InvalidInput(err.msg), &res)
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreatePredictorError::LimitExceeded(err.msg))
+                    return RusotoError::service(CreatePredictorError::LimitExceeded(err.msg), &res)
                 }
                 "ResourceAlreadyExistsException" => {
-                    return RusotoError::Service(CreatePredictorError::ResourceAlreadyExists(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreatePredictorError::ResourceAlreadyExists(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(CreatePredictorError::ResourceInUse(err.msg))
+                    return RusotoError::service(CreatePredictorError::ResourceInUse(err.msg), &res)
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(CreatePredictorError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        CreatePredictorError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreatePredictorError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1702,12 +1761,14 @@ impl fmt::Display for CreatePredictorError {
             CreatePredictorError::ResourceAlreadyExists(ref cause) => write!(f, "{}", cause),
             CreatePredictorError::ResourceInUse(ref cause) => write!(f, "{}", cause),
             CreatePredictorError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            CreatePredictorError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreatePredictorError {}
 /// Errors returned by DeleteDataset
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteDatasetError {
     /// <p>We can't process the request because it includes an invalid value or a value that exceeds the valid range.</p>
     InvalidInput(String),
@@ -1715,6 +1776,8 @@ pub enum DeleteDatasetError {
     ResourceInUse(String),
     /// <p>We can't find a resource with that Amazon Resource Name (ARN). Check the ARN and try again.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteDatasetError {
@@ -1722,16 +1785,19 @@ impl DeleteDatasetError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidInputException" => {
-                    return RusotoError::Service(DeleteDatasetError::InvalidInput(err.msg))
+                    return RusotoError::service(DeleteDatasetError::InvalidInput(err.msg), &res)
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(DeleteDatasetError::ResourceInUse(err.msg))
+                    return RusotoError::service(DeleteDatasetError::ResourceInUse(err.msg), &res)
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteDatasetError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        DeleteDatasetError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteDatasetError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1744,12 +1810,14 @@ impl fmt::Display for DeleteDatasetError {
             DeleteDatasetError::InvalidInput(ref cause) => write!(f, "{}", cause),
             DeleteDatasetError::ResourceInUse(ref cause) => write!(f, "{}", cause),
             DeleteDatasetError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            DeleteDatasetError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteDatasetError {}
 /// Errors returned by DeleteDatasetGroup
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteDatasetGroupError {
     /// <p>We can't process the request because it includes an invalid value or a value that exceeds the valid range.</p>
     InvalidInput(String),
@@ -1757,6 +1825,8 @@ pub enum DeleteDatasetGroupError {
     ResourceInUse(String),
     /// <p>We can't find a resource with that Amazon Resource Name (ARN). Check the ARN and try again.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteDatasetGroupError {
@@ -1764,16 +1834,25 @@ impl DeleteDatasetGroupError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidInputException" => {
-                    return RusotoError::Service(DeleteDatasetGroupError::InvalidInput(err.msg))
+                    return RusotoError::service(
+                        DeleteDatasetGroupError::InvalidInput(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(DeleteDatasetGroupError::ResourceInUse(err.msg))
+                    return RusotoError::service(
+                        DeleteDatasetGroupError::ResourceInUse(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteDatasetGroupError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        DeleteDatasetGroupError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteDatasetGroupError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1786,12 +1865,14 @@ impl fmt::Display for DeleteDatasetGroupError {
             DeleteDatasetGroupError::InvalidInput(ref cause) => write!(f, "{}", cause),
             DeleteDatasetGroupError::ResourceInUse(ref cause) => write!(f, "{}", cause),
             DeleteDatasetGroupError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            DeleteDatasetGroupError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteDatasetGroupError {}
 /// Errors returned by DeleteDatasetImportJob
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteDatasetImportJobError {
     /// <p>We can't process the request because it includes an invalid value or a value that exceeds the valid range.</p>
     InvalidInput(String),
@@ -1799,6 +1880,8 @@ pub enum DeleteDatasetImportJobError {
     ResourceInUse(String),
     /// <p>We can't find a resource with that Amazon Resource Name (ARN). Check the ARN and try again.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteDatasetImportJobError {
@@ -1806,20 +1889,25 @@ impl DeleteDatasetImportJobError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidInputException" => {
-                    return RusotoError::Service(DeleteDatasetImportJobError::InvalidInput(err.msg))
+                    return RusotoError::service(
+                        DeleteDatasetImportJobError::InvalidInput(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(DeleteDatasetImportJobError::ResourceInUse(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteDatasetImportJobError::ResourceInUse(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteDatasetImportJobError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteDatasetImportJobError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteDatasetImportJobError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1832,12 +1920,14 @@ impl fmt::Display for DeleteDatasetImportJobError {
             DeleteDatasetImportJobError::InvalidInput(ref cause) => write!(f, "{}", cause),
             DeleteDatasetImportJobError::ResourceInUse(ref cause) => write!(f, "{}", cause),
             DeleteDatasetImportJobError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            DeleteDatasetImportJobError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteDatasetImportJobError {}
 /// Errors returned by DeleteForecast
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteForecastError {
     /// <p>We can't process the request because it includes an invalid value or a value that exceeds the valid range.</p>
     InvalidInput(String),
@@ -1845,6 +1935,8 @@ pub enum DeleteForecastError {
     ResourceInUse(String),
     /// <p>We can't find a resource with that Amazon Resource Name (ARN). Check the ARN and try again.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteForecastError {
@@ -1852,16 +1944,19 @@ impl DeleteForecastError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidInputException" => {
-                    return RusotoError::Service(DeleteForecastError::InvalidInput(err.msg))
+                    return RusotoError::service(DeleteForecastError::InvalidInput(err.msg), &res)
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(DeleteForecastError::ResourceInUse(err.msg))
+                    return RusotoError::service(DeleteForecastError::ResourceInUse(err.msg), &res)
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteForecastError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        DeleteForecastError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteForecastError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1874,12 +1969,14 @@ impl fmt::Display for DeleteForecastError {
             DeleteForecastError::InvalidInput(ref cause) => write!(f, "{}", cause),
             DeleteForecastError::ResourceInUse(ref cause) => write!(f, "{}", cause),
             DeleteForecastError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            DeleteForecastError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteForecastError {}
 /// Errors returned by DeleteForecastExportJob
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteForecastExportJobError {
     /// <p>We can't process the request because it includes an invalid value or a value that exceeds the valid range.</p>
     InvalidInput(String),
@@ -1887,6 +1984,8 @@ pub enum DeleteForecastExportJobError {
     ResourceInUse(String),
     /// <p>We can't find a resource with that Amazon Resource Name (ARN). Check the ARN and try again.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteForecastExportJobError {
@@ -1894,22 +1993,25 @@ impl DeleteForecastExportJobError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidInputException" => {
-                    return RusotoError::Service(DeleteForecastExportJobError::InvalidInput(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteForecastExportJobError::InvalidInput(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(DeleteForecastExportJobError::ResourceInUse(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteForecastExportJobError::ResourceInUse(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteForecastExportJobError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteForecastExportJobError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteForecastExportJobError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1922,12 +2024,14 @@ impl fmt::Display for DeleteForecastExportJobError {
             DeleteForecastExportJobError::InvalidInput(ref cause) => write!(f, "{}", cause),
             DeleteForecastExportJobError::ResourceInUse(ref cause) => write!(f, "{}", cause),
             DeleteForecastExportJobError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            DeleteForecastExportJobError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteForecastExportJobError {}
 /// Errors returned by DeletePredictor
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeletePredictorError {
     /// <p>We can't process the request because it includes an invalid value or a value that exceeds the valid range.</p>
     InvalidInput(String),
@@ -1935,6 +2039,8 @@ pub enum DeletePredictorError {
     ResourceInUse(String),
     /// <p>We can't find a resource with that Amazon Resource Name (ARN). Check the ARN and try again.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeletePredictorError {
@@ -1942,16 +2048,19 @@ impl DeletePredictorError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidInputException" => {
-                    return RusotoError::Service(DeletePredictorError::InvalidInput(err.msg))
+                    return RusotoError::service(DeletePredictorError::InvalidInput(err.msg), &res)
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(DeletePredictorError::ResourceInUse(err.msg))
+                    return RusotoError::service(DeletePredictorError::ResourceInUse(err.msg), &res)
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeletePredictorError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        DeletePredictorError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeletePredictorError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1964,17 +2073,21 @@ impl fmt::Display for DeletePredictorError {
             DeletePredictorError::InvalidInput(ref cause) => write!(f, "{}", cause),
             DeletePredictorError::ResourceInUse(ref cause) => write!(f, "{}", cause),
             DeletePredictorError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            DeletePredictorError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeletePredictorError {}
 /// Errors returned by DescribeDataset
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeDatasetError {
     /// <p>We can't process the request because it includes an invalid value or a value that exceeds the valid range.</p>
     InvalidInput(String),
     /// <p>We can't find a resource with that Amazon Resource Name (ARN). Check the ARN and try again.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeDatasetError {
@@ -1982,13 +2095,16 @@ impl DescribeDatasetError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidInputException" => {
-                    return RusotoError::Service(DescribeDatasetError::InvalidInput(err.msg))
+                    return RusotoError::service(DescribeDatasetError::InvalidInput(err.msg), &res)
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeDatasetError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        DescribeDatasetError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DescribeDatasetError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2000,17 +2116,21 @@ impl fmt::Display for DescribeDatasetError {
         match *self {
             DescribeDatasetError::InvalidInput(ref cause) => write!(f, "{}", cause),
             DescribeDatasetError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            DescribeDatasetError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeDatasetError {}
 /// Errors returned by DescribeDatasetGroup
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeDatasetGroupError {
     /// <p>We can't process the request because it includes an invalid value or a value that exceeds the valid range.</p>
     InvalidInput(String),
     /// <p>We can't find a resource with that Amazon Resource Name (ARN). Check the ARN and try again.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeDatasetGroupError {
@@ -2018,15 +2138,19 @@ impl DescribeDatasetGroupError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidInputException" => {
-                    return RusotoError::Service(DescribeDatasetGroupError::InvalidInput(err.msg))
+                    return RusotoError::service(
+                        DescribeDatasetGroupError::InvalidInput(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeDatasetGroupError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeDatasetGroupError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DescribeDatasetGroupError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2038,17 +2162,21 @@ impl fmt::Display for DescribeDatasetGroupError {
         match *self {
             DescribeDatasetGroupError::InvalidInput(ref cause) => write!(f, "{}", cause),
             DescribeDatasetGroupError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            DescribeDatasetGroupError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeDatasetGroupError {}
 /// Errors returned by DescribeDatasetImportJob
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeDatasetImportJobError {
     /// <p>We can't process the request because it includes an invalid value or a value that exceeds the valid range.</p>
     InvalidInput(String),
     /// <p>We can't find a resource with that Amazon Resource Name (ARN). Check the ARN and try again.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeDatasetImportJobError {
@@ -2056,17 +2184,19 @@ impl DescribeDatasetImportJobError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidInputException" => {
-                    return RusotoError::Service(DescribeDatasetImportJobError::InvalidInput(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeDatasetImportJobError::InvalidInput(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeDatasetImportJobError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeDatasetImportJobError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DescribeDatasetImportJobError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2078,17 +2208,21 @@ impl fmt::Display for DescribeDatasetImportJobError {
         match *self {
             DescribeDatasetImportJobError::InvalidInput(ref cause) => write!(f, "{}", cause),
             DescribeDatasetImportJobError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            DescribeDatasetImportJobError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeDatasetImportJobError {}
 /// Errors returned by DescribeForecast
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeForecastError {
     /// <p>We can't process the request because it includes an invalid value or a value that exceeds the valid range.</p>
     InvalidInput(String),
     /// <p>We can't find a resource with that Amazon Resource Name (ARN). Check the ARN and try again.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeForecastError {
@@ -2096,13 +2230,16 @@ impl DescribeForecastError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidInputException" => {
-                    return RusotoError::Service(DescribeForecastError::InvalidInput(err.msg))
+                    return RusotoError::service(DescribeForecastError::InvalidInput(err.msg), &res)
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeForecastError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        DescribeForecastError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DescribeForecastError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2114,17 +2251,21 @@ impl fmt::Display for DescribeForecastError {
         match *self {
             DescribeForecastError::InvalidInput(ref cause) => write!(f, "{}", cause),
             DescribeForecastError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            DescribeForecastError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeForecastError {}
 /// Errors returned by DescribeForecastExportJob
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeForecastExportJobError {
     /// <p>We can't process the request because it includes an invalid value or a value that exceeds the valid range.</p>
     InvalidInput(String),
     /// <p>We can't find a resource with that Amazon Resource Name (ARN). Check the ARN and try again.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeForecastExportJobError {
@@ -2132,17 +2273,21 @@ impl DescribeForecastExportJobError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidInputException" => {
-                    return RusotoError::Service(DescribeForecastExportJobError::InvalidInput(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeForecastExportJobError::InvalidInput(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeForecastExportJobError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeForecastExportJobError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(DescribeForecastExportJobError::Unhandled, &res)
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -2154,17 +2299,21 @@ impl fmt::Display for DescribeForecastExportJobError {
         match *self {
             DescribeForecastExportJobError::InvalidInput(ref cause) => write!(f, "{}", cause),
             DescribeForecastExportJobError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            DescribeForecastExportJobError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeForecastExportJobError {}
 /// Errors returned by DescribePredictor
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribePredictorError {
     /// <p>We can't process the request because it includes an invalid value or a value that exceeds the valid range.</p>
     InvalidInput(String),
     /// <p>We can't find a resource with that Amazon Resource Name (ARN). Check the ARN and try again.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribePredictorError {
@@ -2172,13 +2321,19 @@ impl DescribePredictorError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidInputException" => {
-                    return RusotoError::Service(DescribePredictorError::InvalidInput(err.msg))
+                    return RusotoError::service(
+                        DescribePredictorError::InvalidInput(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribePredictorError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        DescribePredictorError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DescribePredictorError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2190,12 +2345,14 @@ impl fmt::Display for DescribePredictorError {
         match *self {
             DescribePredictorError::InvalidInput(ref cause) => write!(f, "{}", cause),
             DescribePredictorError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            DescribePredictorError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribePredictorError {}
 /// Errors returned by GetAccuracyMetrics
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetAccuracyMetricsError {
     /// <p>We can't process the request because it includes an invalid value or a value that exceeds the valid range.</p>
     InvalidInput(String),
@@ -2203,6 +2360,8 @@ pub enum GetAccuracyMetricsError {
     ResourceInUse(String),
     /// <p>We can't find a resource with that Amazon Resource Name (ARN). Check the ARN and try again.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetAccuracyMetricsError {
@@ -2210,16 +2369,25 @@ impl GetAccuracyMetricsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidInputException" => {
-                    return RusotoError::Service(GetAccuracyMetricsError::InvalidInput(err.msg))
+                    return RusotoError::service(
+                        GetAccuracyMetricsError::InvalidInput(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(GetAccuracyMetricsError::ResourceInUse(err.msg))
+                    return RusotoError::service(
+                        GetAccuracyMetricsError::ResourceInUse(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetAccuracyMetricsError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        GetAccuracyMetricsError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetAccuracyMetricsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2232,15 +2400,19 @@ impl fmt::Display for GetAccuracyMetricsError {
             GetAccuracyMetricsError::InvalidInput(ref cause) => write!(f, "{}", cause),
             GetAccuracyMetricsError::ResourceInUse(ref cause) => write!(f, "{}", cause),
             GetAccuracyMetricsError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            GetAccuracyMetricsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetAccuracyMetricsError {}
 /// Errors returned by ListDatasetGroups
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListDatasetGroupsError {
     /// <p>The token is not valid. Tokens expire after 24 hours.</p>
     InvalidNextToken(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListDatasetGroupsError {
@@ -2248,10 +2420,13 @@ impl ListDatasetGroupsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(ListDatasetGroupsError::InvalidNextToken(err.msg))
+                    return RusotoError::service(
+                        ListDatasetGroupsError::InvalidNextToken(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListDatasetGroupsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2262,17 +2437,21 @@ impl fmt::Display for ListDatasetGroupsError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             ListDatasetGroupsError::InvalidNextToken(ref cause) => write!(f, "{}", cause),
+            ListDatasetGroupsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListDatasetGroupsError {}
 /// Errors returned by ListDatasetImportJobs
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListDatasetImportJobsError {
     /// <p>We can't process the request because it includes an invalid value or a value that exceeds the valid range.</p>
     InvalidInput(String),
     /// <p>The token is not valid. Tokens expire after 24 hours.</p>
     InvalidNextToken(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListDatasetImportJobsError {
@@ -2280,15 +2459,19 @@ impl ListDatasetImportJobsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidInputException" => {
-                    return RusotoError::Service(ListDatasetImportJobsError::InvalidInput(err.msg))
+                    return RusotoError::service(
+                        ListDatasetImportJobsError::InvalidInput(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(ListDatasetImportJobsError::InvalidNextToken(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListDatasetImportJobsError::InvalidNextToken(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListDatasetImportJobsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2300,15 +2483,19 @@ impl fmt::Display for ListDatasetImportJobsError {
         match *self {
             ListDatasetImportJobsError::InvalidInput(ref cause) => write!(f, "{}", cause),
             ListDatasetImportJobsError::InvalidNextToken(ref cause) => write!(f, "{}", cause),
+            ListDatasetImportJobsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListDatasetImportJobsError {}
 /// Errors returned by ListDatasets
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListDatasetsError {
     /// <p>The token is not valid. Tokens expire after 24 hours.</p>
     InvalidNextToken(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListDatasetsError {
@@ -2316,10 +2503,10 @@ impl ListDatasetsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(ListDatasetsError::InvalidNextToken(err.msg))
+                    return RusotoError::service(ListDatasetsError::InvalidNextToken(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListDatasetsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2330,17 +2517,21 @@ impl fmt::Display for ListDatasetsError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             ListDatasetsError::InvalidNextToken(ref cause) => write!(f, "{}", cause),
+            ListDatasetsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListDatasetsError {}
 /// Errors returned by ListForecastExportJobs
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListForecastExportJobsError {
     /// <p>We can't process the request because it includes an invalid value or a value that exceeds the valid range.</p>
     InvalidInput(String),
     /// <p>The token is not valid. Tokens expire after 24 hours.</p>
     InvalidNextToken(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListForecastExportJobsError {
@@ -2348,15 +2539,19 @@ impl ListForecastExportJobsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidInputException" => {
-                    return RusotoError::Service(ListForecastExportJobsError::InvalidInput(err.msg))
+                    return RusotoError::service(
+                        ListForecastExportJobsError::InvalidInput(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(ListForecastExportJobsError::InvalidNextToken(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListForecastExportJobsError::InvalidNextToken(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListForecastExportJobsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2368,17 +2563,21 @@ impl fmt::Display for ListForecastExportJobsError {
         match *self {
             ListForecastExportJobsError::InvalidInput(ref cause) => write!(f, "{}", cause),
             ListForecastExportJobsError::InvalidNextToken(ref cause) => write!(f, "{}", cause),
+            ListForecastExportJobsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListForecastExportJobsError {}
 /// Errors returned by ListForecasts
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListForecastsError {
     /// <p>We can't process the request because it includes an invalid value or a value that exceeds the valid range.</p>
     InvalidInput(String),
     /// <p>The token is not valid. Tokens expire after 24 hours.</p>
     InvalidNextToken(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListForecastsError {
@@ -2386,13 +2585,16 @@ impl ListForecastsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidInputException" => {
-                    return RusotoError::Service(ListForecastsError::InvalidInput(err.msg))
+                    return RusotoError::service(ListForecastsError::InvalidInput(err.msg), &res)
                 }
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(ListForecastsError::InvalidNextToken(err.msg))
+                    return RusotoError::service(
+                        ListForecastsError::InvalidNextToken(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListForecastsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2404,17 +2606,21 @@ impl fmt::Display for ListForecastsError {
         match *self {
             ListForecastsError::InvalidInput(ref cause) => write!(f, "{}", cause),
             ListForecastsError::InvalidNextToken(ref cause) => write!(f, "{}", cause),
+            ListForecastsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListForecastsError {}
 /// Errors returned by ListPredictors
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListPredictorsError {
     /// <p>We can't process the request because it includes an invalid value or a value that exceeds the valid range.</p>
     InvalidInput(String),
     /// <p>The token is not valid. Tokens expire after 24 hours.</p>
     InvalidNextToken(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListPredictorsError {
@@ -2422,13 +2628,16 @@ impl ListPredictorsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidInputException" => {
-                    return RusotoError::Service(ListPredictorsError::InvalidInput(err.msg))
+                    return RusotoError::service(ListPredictorsError::InvalidInput(err.msg), &res)
                 }
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(ListPredictorsError::InvalidNextToken(err.msg))
+                    return RusotoError::service(
+                        ListPredictorsError::InvalidNextToken(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListPredictorsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2440,12 +2649,14 @@ impl fmt::Display for ListPredictorsError {
         match *self {
             ListPredictorsError::InvalidInput(ref cause) => write!(f, "{}", cause),
             ListPredictorsError::InvalidNextToken(ref cause) => write!(f, "{}", cause),
+            ListPredictorsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListPredictorsError {}
 /// Errors returned by UpdateDatasetGroup
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdateDatasetGroupError {
     /// <p>We can't process the request because it includes an invalid value or a value that exceeds the valid range.</p>
     InvalidInput(String),
@@ -2453,6 +2664,8 @@ pub enum UpdateDatasetGroupError {
     ResourceInUse(String),
     /// <p>We can't find a resource with that Amazon Resource Name (ARN). Check the ARN and try again.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdateDatasetGroupError {
@@ -2460,16 +2673,25 @@ impl UpdateDatasetGroupError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidInputException" => {
-                    return RusotoError::Service(UpdateDatasetGroupError::InvalidInput(err.msg))
+                    return RusotoError::service(
+                        UpdateDatasetGroupError::InvalidInput(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(UpdateDatasetGroupError::ResourceInUse(err.msg))
+                    return RusotoError::service(
+                        UpdateDatasetGroupError::ResourceInUse(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateDatasetGroupError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        UpdateDatasetGroupError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UpdateDatasetGroupError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2482,6 +2704,7 @@ impl fmt::Display for UpdateDatasetGroupError {
             UpdateDatasetGroupError::InvalidInput(ref cause) => write!(f, "{}", cause),
             UpdateDatasetGroupError::ResourceInUse(ref cause) => write!(f, "{}", cause),
             UpdateDatasetGroupError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            UpdateDatasetGroupError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
@@ -2656,6 +2879,7 @@ impl ForecastClient {
     /// Creates a client backed by the default tokio event loop.
     ///
     /// The client will use the default credentials provider and tls client.
+    #[cfg(not(target_arch = "wasm32"))]
     pub fn new(region: region::Region) -> ForecastClient {
         ForecastClient {
             client: Client::shared(),
@@ -2681,723 +2905,1896 @@ impl ForecastClient {
     pub fn new_with_client(client: Client, region: region::Region) -> ForecastClient {
         ForecastClient { client, region }
     }
+
+    /// Returns a builder of a client, setting any of its region, credentials provider,
+    /// dispatcher, retry policy, endpoint and timeouts.
+    pub fn builder() -> rusoto_core::ClientBuilder<ForecastClient> {
+        rusoto_core::ClientBuilder::new(ForecastClient::new_with_client)
+    }
+
+    /// Returns a clone of this client with the overrides of `config` applied to its
+    /// operations, sharing its connections.
+    pub fn customize(&self, config: &rusoto_core::OperationConfig) -> ForecastClient {
+        ForecastClient {
+            client: self.client.customize(config),
+            region: config.resolve_region(&self.region),
+        }
+    }
 }
 
-#[async_trait]
-impl Forecast for ForecastClient {
-    /// <p><p>Creates an Amazon Forecast dataset. The information about the dataset that you provide helps Forecast understand how to consume the data for model training. This includes the following:</p> <ul> <li> <p> <i> <code>DataFrequency</code> </i> - How frequently your historical time-series data is collected.</p> </li> <li> <p> <i> <code>Domain</code> </i> and <i> <code>DatasetType</code> </i> - Each dataset has an associated dataset domain and a type within the domain. Amazon Forecast provides a list of predefined domains and types within each domain. For each unique dataset domain and type within the domain, Amazon Forecast requires your data to include a minimum set of predefined fields.</p> </li> <li> <p> <i> <code>Schema</code> </i> - A schema specifies the fields in the dataset, including the field name and data type.</p> </li> </ul> <p>After creating a dataset, you import your training data into it and add the dataset to a dataset group. You use the dataset group to create a predictor. For more information, see <a>howitworks-datasets-groups</a>.</p> <p>To get a list of all your datasets, use the <a>ListDatasets</a> operation.</p> <p>For example Forecast datasets, see the <a href="https://github.com/aws-samples/amazon-forecast-samples/tree/master/data">Amazon Forecast Sample GitHub repository</a>.</p> <note> <p>The <code>Status</code> of a dataset must be <code>ACTIVE</code> before you can import training data. Use the <a>DescribeDataset</a> operation to get the status.</p> </note></p>
-    async fn create_dataset(
+impl ForecastClient {
+    /// Calls `create_dataset`, also returning the metadata of the response.
+    pub async fn create_dataset_with_metadata(
         &self,
         input: CreateDatasetRequest,
-    ) -> Result<CreateDatasetResponse, RusotoError<CreateDatasetError>> {
+    ) -> Result<(CreateDatasetResponse, ResponseMetadata), RusotoError<CreateDatasetError>> {
+        let request = self.build_create_dataset_request(input);
+
+        let mut response = self
+            .client
+            .sign_and_dispatch(request)
+            .await
+            .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
+        if response.status.is_success() {
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<CreateDatasetResponse, _>()?;
+            Ok((result, metadata))
+        } else {
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(CreateDatasetError::from_response(response))
+        }
+    }
+
+    /// Presigns the request `create_dataset` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_dataset(
+        &self,
+        presigner: &rusoto_core::Presigner,
+        input: CreateDatasetRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_dataset_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_dataset_request(&self, input: CreateDatasetRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "forecast", &self.region, "/");
+        request.set_operation("CreateDataset");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonForecast.CreateDataset");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `create_dataset_group`, also returning the metadata of the response.
+    pub async fn create_dataset_group_with_metadata(
+        &self,
+        input: CreateDatasetGroupRequest,
+    ) -> Result<(CreateDatasetGroupResponse, ResponseMetadata), RusotoError<CreateDatasetGroupError>>
+    {
+        let request = self.build_create_dataset_group_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<CreateDatasetResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<CreateDatasetGroupResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(CreateDatasetError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(CreateDatasetGroupError::from_response(response))
         }
     }
 
-    /// <p><p>Creates a dataset group, which holds a collection of related datasets. You can add datasets to the dataset group when you create the dataset group, or later by using the <a>UpdateDatasetGroup</a> operation.</p> <p>After creating a dataset group and adding datasets, you use the dataset group when you create a predictor. For more information, see <a>howitworks-datasets-groups</a>.</p> <p>To get a list of all your datasets groups, use the <a>ListDatasetGroups</a> operation.</p> <note> <p>The <code>Status</code> of a dataset group must be <code>ACTIVE</code> before you can create use the dataset group to create a predictor. To get the status, use the <a>DescribeDatasetGroup</a> operation.</p> </note></p>
-    async fn create_dataset_group(
+    /// Presigns the request `create_dataset_group` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_dataset_group(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: CreateDatasetGroupRequest,
-    ) -> Result<CreateDatasetGroupResponse, RusotoError<CreateDatasetGroupError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_dataset_group_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_dataset_group_request(
+        &self,
+        input: CreateDatasetGroupRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "forecast", &self.region, "/");
+        request.set_operation("CreateDatasetGroup");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonForecast.CreateDatasetGroup");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `create_dataset_import_job`, also returning the metadata of the response.
+    pub async fn create_dataset_import_job_with_metadata(
+        &self,
+        input: CreateDatasetImportJobRequest,
+    ) -> Result<
+        (CreateDatasetImportJobResponse, ResponseMetadata),
+        RusotoError<CreateDatasetImportJobError>,
+    > {
+        let request = self.build_create_dataset_import_job_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<CreateDatasetGroupResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<CreateDatasetImportJobResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(CreateDatasetGroupError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(CreateDatasetImportJobError::from_response(response))
         }
     }
 
-    /// <p>Imports your training data to an Amazon Forecast dataset. You provide the location of your training data in an Amazon Simple Storage Service (Amazon S3) bucket and the Amazon Resource Name (ARN) of the dataset that you want to import the data to.</p> <p>You must specify a <a>DataSource</a> object that includes an AWS Identity and Access Management (IAM) role that Amazon Forecast can assume to access the data. For more information, see <a>aws-forecast-iam-roles</a>.</p> <p>The training data must be in CSV format. The delimiter must be a comma (,).</p> <p>You can specify the path to a specific CSV file, the S3 bucket, or to a folder in the S3 bucket. For the latter two cases, Amazon Forecast imports all files up to the limit of 10,000 files.</p> <p>To get a list of all your dataset import jobs, filtered by specified criteria, use the <a>ListDatasetImportJobs</a> operation.</p>
-    async fn create_dataset_import_job(
+    /// Presigns the request `create_dataset_import_job` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_dataset_import_job(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: CreateDatasetImportJobRequest,
-    ) -> Result<CreateDatasetImportJobResponse, RusotoError<CreateDatasetImportJobError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_dataset_import_job_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_dataset_import_job_request(
+        &self,
+        input: CreateDatasetImportJobRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "forecast", &self.region, "/");
+        request.set_operation("CreateDatasetImportJob");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonForecast.CreateDatasetImportJob");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `create_forecast`, also returning the metadata of the response.
+    pub async fn create_forecast_with_metadata(
+        &self,
+        input: CreateForecastRequest,
+    ) -> Result<(CreateForecastResponse, ResponseMetadata), RusotoError<CreateForecastError>> {
+        let request = self.build_create_forecast_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<CreateDatasetImportJobResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<CreateForecastResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(CreateDatasetImportJobError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(CreateForecastError::from_response(response))
         }
     }
 
-    /// <p><p>Creates a forecast for each item in the <code>TARGET<em>TIME</em>SERIES</code> dataset that was used to train the predictor. This is known as inference. To retrieve the forecast for a single item at low latency, use the operation. To export the complete forecast into your Amazon Simple Storage Service (Amazon S3) bucket, use the <a>CreateForecastExportJob</a> operation.</p> <p>The range of the forecast is determined by the <code>ForecastHorizon</code> value, which you specify in the <a>CreatePredictor</a> request, multiplied by the <code>DataFrequency</code> value, which you specify in the <a>CreateDataset</a> request. When you query a forecast, you can request a specific date range within the forecast.</p> <p>To get a list of all your forecasts, use the <a>ListForecasts</a> operation.</p> <note> <p>The forecasts generated by Amazon Forecast are in the same time zone as the dataset that was used to create the predictor.</p> </note> <p>For more information, see <a>howitworks-forecast</a>.</p> <note> <p>The <code>Status</code> of the forecast must be <code>ACTIVE</code> before you can query or export the forecast. Use the <a>DescribeForecast</a> operation to get the status.</p> </note></p>
-    async fn create_forecast(
+    /// Presigns the request `create_forecast` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_forecast(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: CreateForecastRequest,
-    ) -> Result<CreateForecastResponse, RusotoError<CreateForecastError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_forecast_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_forecast_request(&self, input: CreateForecastRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "forecast", &self.region, "/");
+        request.set_operation("CreateForecast");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonForecast.CreateForecast");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `create_forecast_export_job`, also returning the metadata of the response.
+    pub async fn create_forecast_export_job_with_metadata(
+        &self,
+        input: CreateForecastExportJobRequest,
+    ) -> Result<
+        (CreateForecastExportJobResponse, ResponseMetadata),
+        RusotoError<CreateForecastExportJobError>,
+    > {
+        let request = self.build_create_forecast_export_job_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<CreateForecastResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<CreateForecastExportJobResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(CreateForecastError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(CreateForecastExportJobError::from_response(response))
         }
     }
 
-    /// <p><p>Exports a forecast created by the <a>CreateForecast</a> operation to your Amazon Simple Storage Service (Amazon S3) bucket. The forecast file name will match the following conventions:</p> <p>&lt;ForecastExportJobName&gt;<em>&lt;ExportTimestamp&gt;</em>&lt;PageNumber&gt;</p> <p>where the &lt;ExportTimestamp&gt; component is in Java SimpleDateFormat (yyyy-MM-ddTHH-mm-ssZ).</p> <p>You must specify a <a>DataDestination</a> object that includes an AWS Identity and Access Management (IAM) role that Amazon Forecast can assume to access the Amazon S3 bucket. For more information, see <a>aws-forecast-iam-roles</a>.</p> <p>For more information, see <a>howitworks-forecast</a>.</p> <p>To get a list of all your forecast export jobs, use the <a>ListForecastExportJobs</a> operation.</p> <note> <p>The <code>Status</code> of the forecast export job must be <code>ACTIVE</code> before you can access the forecast in your Amazon S3 bucket. To get the status, use the <a>DescribeForecastExportJob</a> operation.</p> </note></p>
-    async fn create_forecast_export_job(
+    /// Presigns the request `create_forecast_export_job` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_forecast_export_job(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: CreateForecastExportJobRequest,
-    ) -> Result<CreateForecastExportJobResponse, RusotoError<CreateForecastExportJobError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_forecast_export_job_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_forecast_export_job_request(
+        &self,
+        input: CreateForecastExportJobRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "forecast", &self.region, "/");
+        request.set_operation("CreateForecastExportJob");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonForecast.CreateForecastExportJob");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `create_predictor`, also returning the metadata of the response.
+    pub async fn create_predictor_with_metadata(
+        &self,
+        input: CreatePredictorRequest,
+    ) -> Result<(CreatePredictorResponse, ResponseMetadata), RusotoError<CreatePredictorError>>
+    {
+        let request = self.build_create_predictor_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<CreateForecastExportJobResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<CreatePredictorResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(CreateForecastExportJobError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(CreatePredictorError::from_response(response))
         }
     }
 
-    /// <p><p>Creates an Amazon Forecast predictor.</p> <p>In the request, you provide a dataset group and either specify an algorithm or let Amazon Forecast choose the algorithm for you using AutoML. If you specify an algorithm, you also can override algorithm-specific hyperparameters.</p> <p>Amazon Forecast uses the chosen algorithm to train a model using the latest version of the datasets in the specified dataset group. The result is called a predictor. You then generate a forecast using the <a>CreateForecast</a> operation.</p> <p>After training a model, the <code>CreatePredictor</code> operation also evaluates it. To see the evaluation metrics, use the <a>GetAccuracyMetrics</a> operation. Always review the evaluation metrics before deciding to use the predictor to generate a forecast.</p> <p>Optionally, you can specify a featurization configuration to fill and aggregate the data fields in the <code>TARGET<em>TIME</em>SERIES</code> dataset to improve model training. For more information, see <a>FeaturizationConfig</a>.</p> <p>For RELATED<em>TIME</em>SERIES datasets, <code>CreatePredictor</code> verifies that the <code>DataFrequency</code> specified when the dataset was created matches the <code>ForecastFrequency</code>. TARGET<em>TIME</em>SERIES datasets don&#39;t have this restriction. Amazon Forecast also verifies the delimiter and timestamp format. For more information, see <a>howitworks-datasets-groups</a>.</p> <p> <b>AutoML</b> </p> <p>If you want Amazon Forecast to evaluate each algorithm and choose the one that minimizes the <code>objective function</code>, set <code>PerformAutoML</code> to <code>true</code>. The <code>objective function</code> is defined as the mean of the weighted p10, p50, and p90 quantile losses. For more information, see <a>EvaluationResult</a>.</p> <p>When AutoML is enabled, the following properties are disallowed:</p> <ul> <li> <p> <code>AlgorithmArn</code> </p> </li> <li> <p> <code>HPOConfig</code> </p> </li> <li> <p> <code>PerformHPO</code> </p> </li> <li> <p> <code>TrainingParameters</code> </p> </li> </ul> <p>To get a list of all of your predictors, use the <a>ListPredictors</a> operation.</p> <note> <p>Before you can use the predictor to create a forecast, the <code>Status</code> of the predictor must be <code>ACTIVE</code>, signifying that training has completed. To get the status, use the <a>DescribePredictor</a> operation.</p> </note></p>
-    async fn create_predictor(
+    /// Presigns the request `create_predictor` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_predictor(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: CreatePredictorRequest,
-    ) -> Result<CreatePredictorResponse, RusotoError<CreatePredictorError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_predictor_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_predictor_request(&self, input: CreatePredictorRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "forecast", &self.region, "/");
+        request.set_operation("CreatePredictor");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonForecast.CreatePredictor");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `delete_dataset`, also returning the metadata of the response.
+    pub async fn delete_dataset_with_metadata(
+        &self,
+        input: DeleteDatasetRequest,
+    ) -> Result<((), ResponseMetadata), RusotoError<DeleteDatasetError>> {
+        let request = self.build_delete_dataset_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<CreatePredictorResponse, _>()
+            std::mem::drop(response);
+            Ok(((), metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(CreatePredictorError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DeleteDatasetError::from_response(response))
         }
     }
 
-    /// <p>Deletes an Amazon Forecast dataset that was created using the <a>CreateDataset</a> operation. You can only delete datasets that have a status of <code>ACTIVE</code> or <code>CREATE_FAILED</code>. To get the status use the <a>DescribeDataset</a> operation.</p>
-    async fn delete_dataset(
+    /// Presigns the request `delete_dataset` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_dataset(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteDatasetRequest,
-    ) -> Result<(), RusotoError<DeleteDatasetError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_dataset_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_dataset_request(&self, input: DeleteDatasetRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "forecast", &self.region, "/");
+        request.set_operation("DeleteDataset");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonForecast.DeleteDataset");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `delete_dataset_group`, also returning the metadata of the response.
+    pub async fn delete_dataset_group_with_metadata(
+        &self,
+        input: DeleteDatasetGroupRequest,
+    ) -> Result<((), ResponseMetadata), RusotoError<DeleteDatasetGroupError>> {
+        let request = self.build_delete_dataset_group_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
             std::mem::drop(response);
-            Ok(())
+            Ok(((), metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteDatasetError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DeleteDatasetGroupError::from_response(response))
         }
     }
 
-    /// <p>Deletes a dataset group created using the <a>CreateDatasetGroup</a> operation. You can only delete dataset groups that have a status of <code>ACTIVE</code>, <code>CREATE_FAILED</code>, or <code>UPDATE_FAILED</code>. To get the status, use the <a>DescribeDatasetGroup</a> operation.</p> <p>This operation deletes only the dataset group, not the datasets in the group.</p>
-    async fn delete_dataset_group(
+    /// Presigns the request `delete_dataset_group` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_dataset_group(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteDatasetGroupRequest,
-    ) -> Result<(), RusotoError<DeleteDatasetGroupError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_dataset_group_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_dataset_group_request(
+        &self,
+        input: DeleteDatasetGroupRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "forecast", &self.region, "/");
+        request.set_operation("DeleteDatasetGroup");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonForecast.DeleteDatasetGroup");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `delete_dataset_import_job`, also returning the metadata of the response.
+    pub async fn delete_dataset_import_job_with_metadata(
+        &self,
+        input: DeleteDatasetImportJobRequest,
+    ) -> Result<((), ResponseMetadata), RusotoError<DeleteDatasetImportJobError>> {
+        let request = self.build_delete_dataset_import_job_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
             std::mem::drop(response);
-            Ok(())
+            Ok(((), metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteDatasetGroupError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DeleteDatasetImportJobError::from_response(response))
         }
     }
 
-    /// <p>Deletes a dataset import job created using the <a>CreateDatasetImportJob</a> operation. You can delete only dataset import jobs that have a status of <code>ACTIVE</code> or <code>CREATE_FAILED</code>. To get the status, use the <a>DescribeDatasetImportJob</a> operation.</p>
-    async fn delete_dataset_import_job(
+    /// Presigns the request `delete_dataset_import_job` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_dataset_import_job(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteDatasetImportJobRequest,
-    ) -> Result<(), RusotoError<DeleteDatasetImportJobError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_dataset_import_job_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_dataset_import_job_request(
+        &self,
+        input: DeleteDatasetImportJobRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "forecast", &self.region, "/");
+        request.set_operation("DeleteDatasetImportJob");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonForecast.DeleteDatasetImportJob");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `delete_forecast`, also returning the metadata of the response.
+    pub async fn delete_forecast_with_metadata(
+        &self,
+        input: DeleteForecastRequest,
+    ) -> Result<((), ResponseMetadata), RusotoError<DeleteForecastError>> {
+        let request = self.build_delete_forecast_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
             std::mem::drop(response);
-            Ok(())
+            Ok(((), metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteDatasetImportJobError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DeleteForecastError::from_response(response))
         }
     }
 
-    /// <p>Deletes a forecast created using the <a>CreateForecast</a> operation. You can delete only forecasts that have a status of <code>ACTIVE</code> or <code>CREATE_FAILED</code>. To get the status, use the <a>DescribeForecast</a> operation.</p> <p>You can't delete a forecast while it is being exported. After a forecast is deleted, you can no longer query the forecast.</p>
-    async fn delete_forecast(
+    /// Presigns the request `delete_forecast` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_forecast(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteForecastRequest,
-    ) -> Result<(), RusotoError<DeleteForecastError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_forecast_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_forecast_request(&self, input: DeleteForecastRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "forecast", &self.region, "/");
+        request.set_operation("DeleteForecast");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonForecast.DeleteForecast");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `delete_forecast_export_job`, also returning the metadata of the response.
+    pub async fn delete_forecast_export_job_with_metadata(
+        &self,
+        input: DeleteForecastExportJobRequest,
+    ) -> Result<((), ResponseMetadata), RusotoError<DeleteForecastExportJobError>> {
+        let request = self.build_delete_forecast_export_job_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
             std::mem::drop(response);
-            Ok(())
+            Ok(((), metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteForecastError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DeleteForecastExportJobError::from_response(response))
         }
     }
 
-    /// <p>Deletes a forecast export job created using the <a>CreateForecastExportJob</a> operation. You can delete only export jobs that have a status of <code>ACTIVE</code> or <code>CREATE_FAILED</code>. To get the status, use the <a>DescribeForecastExportJob</a> operation.</p>
-    async fn delete_forecast_export_job(
+    /// Presigns the request `delete_forecast_export_job` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_forecast_export_job(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteForecastExportJobRequest,
-    ) -> Result<(), RusotoError<DeleteForecastExportJobError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_forecast_export_job_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_forecast_export_job_request(
+        &self,
+        input: DeleteForecastExportJobRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "forecast", &self.region, "/");
+        request.set_operation("DeleteForecastExportJob");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonForecast.DeleteForecastExportJob");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `delete_predictor`, also returning the metadata of the response.
+    pub async fn delete_predictor_with_metadata(
+        &self,
+        input: DeletePredictorRequest,
+    ) -> Result<((), ResponseMetadata), RusotoError<DeletePredictorError>> {
+        let request = self.build_delete_predictor_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
             std::mem::drop(response);
-            Ok(())
+            Ok(((), metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteForecastExportJobError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DeletePredictorError::from_response(response))
         }
     }
 
-    /// <p>Deletes a predictor created using the <a>CreatePredictor</a> operation. You can delete only predictor that have a status of <code>ACTIVE</code> or <code>CREATE_FAILED</code>. To get the status, use the <a>DescribePredictor</a> operation.</p>
-    async fn delete_predictor(
+    /// Presigns the request `delete_predictor` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_predictor(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeletePredictorRequest,
-    ) -> Result<(), RusotoError<DeletePredictorError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_predictor_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_predictor_request(&self, input: DeletePredictorRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "forecast", &self.region, "/");
+        request.set_operation("DeletePredictor");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonForecast.DeletePredictor");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `describe_dataset`, also returning the metadata of the response.
+    pub async fn describe_dataset_with_metadata(
+        &self,
+        input: DescribeDatasetRequest,
+    ) -> Result<(DescribeDatasetResponse, ResponseMetadata), RusotoError<DescribeDatasetError>>
+    {
+        let request = self.build_describe_dataset_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            std::mem::drop(response);
-            Ok(())
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DescribeDatasetResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DeletePredictorError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DescribeDatasetError::from_response(response))
         }
     }
 
-    /// <p><p>Describes an Amazon Forecast dataset created using the <a>CreateDataset</a> operation.</p> <p>In addition to listing the parameters specified in the <code>CreateDataset</code> request, this operation includes the following dataset properties:</p> <ul> <li> <p> <code>CreationTime</code> </p> </li> <li> <p> <code>LastModificationTime</code> </p> </li> <li> <p> <code>Status</code> </p> </li> </ul></p>
-    async fn describe_dataset(
+    /// Presigns the request `describe_dataset` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_dataset(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeDatasetRequest,
-    ) -> Result<DescribeDatasetResponse, RusotoError<DescribeDatasetError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_dataset_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_dataset_request(&self, input: DescribeDatasetRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "forecast", &self.region, "/");
+        request.set_operation("DescribeDataset");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonForecast.DescribeDataset");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `describe_dataset_group`, also returning the metadata of the response.
+    pub async fn describe_dataset_group_with_metadata(
+        &self,
+        input: DescribeDatasetGroupRequest,
+    ) -> Result<
+        (DescribeDatasetGroupResponse, ResponseMetadata),
+        RusotoError<DescribeDatasetGroupError>,
+    > {
+        let request = self.build_describe_dataset_group_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<DescribeDatasetResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DescribeDatasetGroupResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeDatasetError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DescribeDatasetGroupError::from_response(response))
         }
     }
 
-    /// <p><p>Describes a dataset group created using the <a>CreateDatasetGroup</a> operation.</p> <p>In addition to listing the parameters provided in the <code>CreateDatasetGroup</code> request, this operation includes the following properties:</p> <ul> <li> <p> <code>DatasetArns</code> - The datasets belonging to the group.</p> </li> <li> <p> <code>CreationTime</code> </p> </li> <li> <p> <code>LastModificationTime</code> </p> </li> <li> <p> <code>Status</code> </p> </li> </ul></p>
-    async fn describe_dataset_group(
+    /// Presigns the request `describe_dataset_group` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_dataset_group(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeDatasetGroupRequest,
-    ) -> Result<DescribeDatasetGroupResponse, RusotoError<DescribeDatasetGroupError>> {
-        let mut request = SignedRequest::new("POST", "forecast", &self.region, "/");
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_dataset_group_request(input);
+        self.client.presign(presigner, request).await
+    }
 
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
+    fn build_describe_dataset_group_request(
+        &self,
+        input: DescribeDatasetGroupRequest,
+    ) -> SignedRequest {
+        let mut request = SignedRequest::new("POST", "forecast", &self.region, "/");
+        request.set_operation("DescribeDatasetGroup");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonForecast.DescribeDatasetGroup");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `describe_dataset_import_job`, also returning the metadata of the response.
+    pub async fn describe_dataset_import_job_with_metadata(
+        &self,
+        input: DescribeDatasetImportJobRequest,
+    ) -> Result<
+        (DescribeDatasetImportJobResponse, ResponseMetadata),
+        RusotoError<DescribeDatasetImportJobError>,
+    > {
+        let request = self.build_describe_dataset_import_job_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DescribeDatasetGroupResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DescribeDatasetImportJobResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeDatasetGroupError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DescribeDatasetImportJobError::from_response(response))
         }
     }
 
-    /// <p><p>Describes a dataset import job created using the <a>CreateDatasetImportJob</a> operation.</p> <p>In addition to listing the parameters provided in the <code>CreateDatasetImportJob</code> request, this operation includes the following properties:</p> <ul> <li> <p> <code>CreationTime</code> </p> </li> <li> <p> <code>LastModificationTime</code> </p> </li> <li> <p> <code>DataSize</code> </p> </li> <li> <p> <code>FieldStatistics</code> </p> </li> <li> <p> <code>Status</code> </p> </li> <li> <p> <code>Message</code> - If an error occurred, information about the error.</p> </li> </ul></p>
-    async fn describe_dataset_import_job(
+    /// Presigns the request `describe_dataset_import_job` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_dataset_import_job(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeDatasetImportJobRequest,
-    ) -> Result<DescribeDatasetImportJobResponse, RusotoError<DescribeDatasetImportJobError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_dataset_import_job_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_dataset_import_job_request(
+        &self,
+        input: DescribeDatasetImportJobRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "forecast", &self.region, "/");
+        request.set_operation("DescribeDatasetImportJob");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonForecast.DescribeDatasetImportJob");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `describe_forecast`, also returning the metadata of the response.
+    pub async fn describe_forecast_with_metadata(
+        &self,
+        input: DescribeForecastRequest,
+    ) -> Result<(DescribeForecastResponse, ResponseMetadata), RusotoError<DescribeForecastError>>
+    {
+        let request = self.build_describe_forecast_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DescribeDatasetImportJobResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DescribeForecastResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeDatasetImportJobError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DescribeForecastError::from_response(response))
         }
     }
 
-    /// <p><p>Describes a forecast created using the <a>CreateForecast</a> operation.</p> <p>In addition to listing the properties provided in the <code>CreateForecast</code> request, this operation lists the following properties:</p> <ul> <li> <p> <code>DatasetGroupArn</code> - The dataset group that provided the training data.</p> </li> <li> <p> <code>CreationTime</code> </p> </li> <li> <p> <code>LastModificationTime</code> </p> </li> <li> <p> <code>Status</code> </p> </li> <li> <p> <code>Message</code> - If an error occurred, information about the error.</p> </li> </ul></p>
-    async fn describe_forecast(
+    /// Presigns the request `describe_forecast` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_forecast(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeForecastRequest,
-    ) -> Result<DescribeForecastResponse, RusotoError<DescribeForecastError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_forecast_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_forecast_request(&self, input: DescribeForecastRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "forecast", &self.region, "/");
+        request.set_operation("DescribeForecast");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonForecast.DescribeForecast");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `describe_forecast_export_job`, also returning the metadata of the response.
+    pub async fn describe_forecast_export_job_with_metadata(
+        &self,
+        input: DescribeForecastExportJobRequest,
+    ) -> Result<
+        (DescribeForecastExportJobResponse, ResponseMetadata),
+        RusotoError<DescribeForecastExportJobError>,
+    > {
+        let request = self.build_describe_forecast_export_job_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DescribeForecastResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DescribeForecastExportJobResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeForecastError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DescribeForecastExportJobError::from_response(response))
         }
     }
 
-    /// <p><p>Describes a forecast export job created using the <a>CreateForecastExportJob</a> operation.</p> <p>In addition to listing the properties provided by the user in the <code>CreateForecastExportJob</code> request, this operation lists the following properties:</p> <ul> <li> <p> <code>CreationTime</code> </p> </li> <li> <p> <code>LastModificationTime</code> </p> </li> <li> <p> <code>Status</code> </p> </li> <li> <p> <code>Message</code> - If an error occurred, information about the error.</p> </li> </ul></p>
-    async fn describe_forecast_export_job(
+    /// Presigns the request `describe_forecast_export_job` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_forecast_export_job(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeForecastExportJobRequest,
-    ) -> Result<DescribeForecastExportJobResponse, RusotoError<DescribeForecastExportJobError>>
-    {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_forecast_export_job_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_forecast_export_job_request(
+        &self,
+        input: DescribeForecastExportJobRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "forecast", &self.region, "/");
+        request.set_operation("DescribeForecastExportJob");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonForecast.DescribeForecastExportJob");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `describe_predictor`, also returning the metadata of the response.
+    pub async fn describe_predictor_with_metadata(
+        &self,
+        input: DescribePredictorRequest,
+    ) -> Result<(DescribePredictorResponse, ResponseMetadata), RusotoError<DescribePredictorError>>
+    {
+        let request = self.build_describe_predictor_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DescribeForecastExportJobResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DescribePredictorResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeForecastExportJobError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DescribePredictorError::from_response(response))
         }
     }
 
-    /// <p><p>Describes a predictor created using the <a>CreatePredictor</a> operation.</p> <p>In addition to listing the properties provided in the <code>CreatePredictor</code> request, this operation lists the following properties:</p> <ul> <li> <p> <code>DatasetImportJobArns</code> - The dataset import jobs used to import training data.</p> </li> <li> <p> <code>AutoMLAlgorithmArns</code> - If AutoML is performed, the algorithms that were evaluated.</p> </li> <li> <p> <code>CreationTime</code> </p> </li> <li> <p> <code>LastModificationTime</code> </p> </li> <li> <p> <code>Status</code> </p> </li> <li> <p> <code>Message</code> - If an error occurred, information about the error.</p> </li> </ul></p>
-    async fn describe_predictor(
+    /// Presigns the request `describe_predictor` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_predictor(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribePredictorRequest,
-    ) -> Result<DescribePredictorResponse, RusotoError<DescribePredictorError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_predictor_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_predictor_request(&self, input: DescribePredictorRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "forecast", &self.region, "/");
+        request.set_operation("DescribePredictor");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonForecast.DescribePredictor");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `get_accuracy_metrics`, also returning the metadata of the response.
+    pub async fn get_accuracy_metrics_with_metadata(
+        &self,
+        input: GetAccuracyMetricsRequest,
+    ) -> Result<(GetAccuracyMetricsResponse, ResponseMetadata), RusotoError<GetAccuracyMetricsError>>
+    {
+        let request = self.build_get_accuracy_metrics_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DescribePredictorResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<GetAccuracyMetricsResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribePredictorError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(GetAccuracyMetricsError::from_response(response))
         }
     }
 
-    /// <p><p>Provides metrics on the accuracy of the models that were trained by the <a>CreatePredictor</a> operation. Use metrics to see how well the model performed and to decide whether to use the predictor to generate a forecast. For more information, see <a>metrics</a>.</p> <p>This operation generates metrics for each backtest window that was evaluated. The number of backtest windows (<code>NumberOfBacktestWindows</code>) is specified using the <a>EvaluationParameters</a> object, which is optionally included in the <code>CreatePredictor</code> request. If <code>NumberOfBacktestWindows</code> isn&#39;t specified, the number defaults to one.</p> <p>The parameters of the <code>filling</code> method determine which items contribute to the metrics. If you want all items to contribute, specify <code>zero</code>. If you want only those items that have complete data in the range being evaluated to contribute, specify <code>nan</code>. For more information, see <a>FeaturizationMethod</a>.</p> <note> <p>Before you can get accuracy metrics, the <code>Status</code> of the predictor must be <code>ACTIVE</code>, signifying that training has completed. To get the status, use the <a>DescribePredictor</a> operation.</p> </note></p>
-    async fn get_accuracy_metrics(
+    /// Presigns the request `get_accuracy_metrics` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_accuracy_metrics(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetAccuracyMetricsRequest,
-    ) -> Result<GetAccuracyMetricsResponse, RusotoError<GetAccuracyMetricsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_accuracy_metrics_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_accuracy_metrics_request(
+        &self,
+        input: GetAccuracyMetricsRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "forecast", &self.region, "/");
+        request.set_operation("GetAccuracyMetrics");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonForecast.GetAccuracyMetrics");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `list_dataset_groups`, also returning the metadata of the response.
+    pub async fn list_dataset_groups_with_metadata(
+        &self,
+        input: ListDatasetGroupsRequest,
+    ) -> Result<(ListDatasetGroupsResponse, ResponseMetadata), RusotoError<ListDatasetGroupsError>>
+    {
+        let request = self.build_list_dataset_groups_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<GetAccuracyMetricsResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ListDatasetGroupsResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(GetAccuracyMetricsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ListDatasetGroupsError::from_response(response))
         }
     }
 
-    /// <p>Returns a list of dataset groups created using the <a>CreateDatasetGroup</a> operation. For each dataset group, this operation returns a summary of its properties, including its Amazon Resource Name (ARN). You can retrieve the complete set of properties by using the dataset group ARN with the <a>DescribeDatasetGroup</a> operation.</p>
-    async fn list_dataset_groups(
+    /// Presigns the request `list_dataset_groups` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_dataset_groups(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListDatasetGroupsRequest,
-    ) -> Result<ListDatasetGroupsResponse, RusotoError<ListDatasetGroupsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_dataset_groups_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_dataset_groups_request(&self, input: ListDatasetGroupsRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "forecast", &self.region, "/");
+        request.set_operation("ListDatasetGroups");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonForecast.ListDatasetGroups");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `list_dataset_import_jobs`, also returning the metadata of the response.
+    pub async fn list_dataset_import_jobs_with_metadata(
+        &self,
+        input: ListDatasetImportJobsRequest,
+    ) -> Result<
+        (ListDatasetImportJobsResponse, ResponseMetadata),
+        RusotoError<ListDatasetImportJobsError>,
+    > {
+        let request = self.build_list_dataset_import_jobs_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListDatasetGroupsResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ListDatasetImportJobsResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ListDatasetGroupsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ListDatasetImportJobsError::from_response(response))
         }
     }
 
-    /// <p>Returns a list of dataset import jobs created using the <a>CreateDatasetImportJob</a> operation. For each import job, this operation returns a summary of its properties, including its Amazon Resource Name (ARN). You can retrieve the complete set of properties by using the ARN with the <a>DescribeDatasetImportJob</a> operation. You can filter the list by providing an array of <a>Filter</a> objects.</p>
-    async fn list_dataset_import_jobs(
+    /// Presigns the request `list_dataset_import_jobs` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_dataset_import_jobs(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListDatasetImportJobsRequest,
-    ) -> Result<ListDatasetImportJobsResponse, RusotoError<ListDatasetImportJobsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_dataset_import_jobs_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_dataset_import_jobs_request(
+        &self,
+        input: ListDatasetImportJobsRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "forecast", &self.region, "/");
+        request.set_operation("ListDatasetImportJobs");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonForecast.ListDatasetImportJobs");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `list_datasets`, also returning the metadata of the response.
+    pub async fn list_datasets_with_metadata(
+        &self,
+        input: ListDatasetsRequest,
+    ) -> Result<(ListDatasetsResponse, ResponseMetadata), RusotoError<ListDatasetsError>> {
+        let request = self.build_list_datasets_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListDatasetImportJobsResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ListDatasetsResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ListDatasetImportJobsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ListDatasetsError::from_response(response))
         }
     }
 
-    /// <p>Returns a list of datasets created using the <a>CreateDataset</a> operation. For each dataset, a summary of its properties, including its Amazon Resource Name (ARN), is returned. To retrieve the complete set of properties, use the ARN with the <a>DescribeDataset</a> operation.</p>
-    async fn list_datasets(
+    /// Presigns the request `list_datasets` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_datasets(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListDatasetsRequest,
-    ) -> Result<ListDatasetsResponse, RusotoError<ListDatasetsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_datasets_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_datasets_request(&self, input: ListDatasetsRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "forecast", &self.region, "/");
+        request.set_operation("ListDatasets");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonForecast.ListDatasets");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `list_forecast_export_jobs`, also returning the metadata of the response.
+    pub async fn list_forecast_export_jobs_with_metadata(
+        &self,
+        input: ListForecastExportJobsRequest,
+    ) -> Result<
+        (ListForecastExportJobsResponse, ResponseMetadata),
+        RusotoError<ListForecastExportJobsError>,
+    > {
+        let request = self.build_list_forecast_export_jobs_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<ListDatasetsResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ListForecastExportJobsResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ListDatasetsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ListForecastExportJobsError::from_response(response))
         }
     }
 
-    /// <p>Returns a list of forecast export jobs created using the <a>CreateForecastExportJob</a> operation. For each forecast export job, this operation returns a summary of its properties, including its Amazon Resource Name (ARN). To retrieve the complete set of properties, use the ARN with the <a>DescribeForecastExportJob</a> operation. You can filter the list using an array of <a>Filter</a> objects.</p>
-    async fn list_forecast_export_jobs(
+    /// Presigns the request `list_forecast_export_jobs` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_forecast_export_jobs(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListForecastExportJobsRequest,
-    ) -> Result<ListForecastExportJobsResponse, RusotoError<ListForecastExportJobsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_forecast_export_jobs_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_forecast_export_jobs_request(
+        &self,
+        input: ListForecastExportJobsRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "forecast", &self.region, "/");
+        request.set_operation("ListForecastExportJobs");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonForecast.ListForecastExportJobs");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `list_forecasts`, also returning the metadata of the response.
+    pub async fn list_forecasts_with_metadata(
+        &self,
+        input: ListForecastsRequest,
+    ) -> Result<(ListForecastsResponse, ResponseMetadata), RusotoError<ListForecastsError>> {
+        let request = self.build_list_forecasts_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListForecastExportJobsResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ListForecastsResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ListForecastExportJobsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ListForecastsError::from_response(response))
         }
     }
 
-    /// <p>Returns a list of forecasts created using the <a>CreateForecast</a> operation. For each forecast, this operation returns a summary of its properties, including its Amazon Resource Name (ARN). To retrieve the complete set of properties, specify the ARN with the <a>DescribeForecast</a> operation. You can filter the list using an array of <a>Filter</a> objects.</p>
-    async fn list_forecasts(
+    /// Presigns the request `list_forecasts` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_forecasts(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListForecastsRequest,
-    ) -> Result<ListForecastsResponse, RusotoError<ListForecastsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_forecasts_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_forecasts_request(&self, input: ListForecastsRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "forecast", &self.region, "/");
+        request.set_operation("ListForecasts");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonForecast.ListForecasts");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `list_predictors`, also returning the metadata of the response.
+    pub async fn list_predictors_with_metadata(
+        &self,
+        input: ListPredictorsRequest,
+    ) -> Result<(ListPredictorsResponse, ResponseMetadata), RusotoError<ListPredictorsError>> {
+        let request = self.build_list_predictors_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<ListForecastsResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ListPredictorsResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ListForecastsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ListPredictorsError::from_response(response))
         }
     }
 
-    /// <p>Returns a list of predictors created using the <a>CreatePredictor</a> operation. For each predictor, this operation returns a summary of its properties, including its Amazon Resource Name (ARN). You can retrieve the complete set of properties by using the ARN with the <a>DescribePredictor</a> operation. You can filter the list using an array of <a>Filter</a> objects.</p>
-    async fn list_predictors(
+    /// Presigns the request `list_predictors` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_predictors(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListPredictorsRequest,
-    ) -> Result<ListPredictorsResponse, RusotoError<ListPredictorsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_predictors_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_predictors_request(&self, input: ListPredictorsRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "forecast", &self.region, "/");
+        request.set_operation("ListPredictors");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonForecast.ListPredictors");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `update_dataset_group`, also returning the metadata of the response.
+    pub async fn update_dataset_group_with_metadata(
+        &self,
+        input: UpdateDatasetGroupRequest,
+    ) -> Result<(UpdateDatasetGroupResponse, ResponseMetadata), RusotoError<UpdateDatasetGroupError>>
+    {
+        let request = self.build_update_dataset_group_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<ListPredictorsResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<UpdateDatasetGroupResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ListPredictorsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(UpdateDatasetGroupError::from_response(response))
         }
     }
 
-    /// <p><p>Replaces the datasets in a dataset group with the specified datasets.</p> <note> <p>The <code>Status</code> of the dataset group must be <code>ACTIVE</code> before you can use the dataset group to create a predictor. Use the <a>DescribeDatasetGroup</a> operation to get the status.</p> </note></p>
-    async fn update_dataset_group(
+    /// Presigns the request `update_dataset_group` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_dataset_group(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UpdateDatasetGroupRequest,
-    ) -> Result<UpdateDatasetGroupResponse, RusotoError<UpdateDatasetGroupError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_dataset_group_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_dataset_group_request(
+        &self,
+        input: UpdateDatasetGroupRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "forecast", &self.region, "/");
+        request.set_operation("UpdateDatasetGroup");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AmazonForecast.UpdateDatasetGroup");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
-        let mut response = self
-            .client
-            .sign_and_dispatch(request)
+        request
+    }
+
+    /// The pages of `ListDatasetGroups` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `MaxResults` of `input`.
+    pub fn list_dataset_groups_pages(
+        &self,
+        input: ListDatasetGroupsRequest,
+    ) -> impl futures::Stream<
+        Item = Result<ListDatasetGroupsResponse, RusotoError<ListDatasetGroupsError>>,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: ForecastClient, input| async move { client.list_dataset_groups(input).await },
+            |input: &ListDatasetGroupsRequest, output: &ListDatasetGroupsResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListDatasetGroupsRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `DatasetGroups` of every page of `ListDatasetGroups` results for `input`, as `list_dataset_groups_pages` requests them.
+    pub fn list_dataset_groups_stream(
+        &self,
+        input: ListDatasetGroupsRequest,
+    ) -> impl futures::Stream<Item = Result<DatasetGroupSummary, RusotoError<ListDatasetGroupsError>>>
+           + Send {
+        rusoto_core::pagination::items(self.list_dataset_groups_pages(input), |page| {
+            page.dataset_groups.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `ListDatasetImportJobs` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `MaxResults` of `input`.
+    pub fn list_dataset_import_jobs_pages(
+        &self,
+        input: ListDatasetImportJobsRequest,
+    ) -> impl futures::Stream<
+        Item = Result<ListDatasetImportJobsResponse, RusotoError<ListDatasetImportJobsError>>,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: ForecastClient, input| async move { client.list_dataset_import_jobs(input).await },
+            |input: &ListDatasetImportJobsRequest, output: &ListDatasetImportJobsResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListDatasetImportJobsRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `DatasetImportJobs` of every page of `ListDatasetImportJobs` results for `input`, as `list_dataset_import_jobs_pages` requests them.
+    pub fn list_dataset_import_jobs_stream(
+        &self,
+        input: ListDatasetImportJobsRequest,
+    ) -> impl futures::Stream<
+        Item = Result<DatasetImportJobSummary, RusotoError<ListDatasetImportJobsError>>,
+    > + Send {
+        rusoto_core::pagination::items(self.list_dataset_import_jobs_pages(input), |page| {
+            page.dataset_import_jobs.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `ListDatasets` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `MaxResults` of `input`.
+    pub fn list_datasets_pages(
+        &self,
+        input: ListDatasetsRequest,
+    ) -> impl futures::Stream<Item = Result<ListDatasetsResponse, RusotoError<ListDatasetsError>>> + Send
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: ForecastClient, input| async move { client.list_datasets(input).await },
+            |input: &ListDatasetsRequest, output: &ListDatasetsResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListDatasetsRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `Datasets` of every page of `ListDatasets` results for `input`, as `list_datasets_pages` requests them.
+    pub fn list_datasets_stream(
+        &self,
+        input: ListDatasetsRequest,
+    ) -> impl futures::Stream<Item = Result<DatasetSummary, RusotoError<ListDatasetsError>>> + Send
+    {
+        rusoto_core::pagination::items(self.list_datasets_pages(input), |page| {
+            page.datasets.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `ListForecastExportJobs` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `MaxResults` of `input`.
+    pub fn list_forecast_export_jobs_pages(
+        &self,
+        input: ListForecastExportJobsRequest,
+    ) -> impl futures::Stream<
+        Item = Result<ListForecastExportJobsResponse, RusotoError<ListForecastExportJobsError>>,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: ForecastClient, input| async move { client.list_forecast_export_jobs(input).await },
+            |input: &ListForecastExportJobsRequest, output: &ListForecastExportJobsResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListForecastExportJobsRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `ForecastExportJobs` of every page of `ListForecastExportJobs` results for `input`, as `list_forecast_export_jobs_pages` requests them.
+    pub fn list_forecast_export_jobs_stream(
+        &self,
+        input: ListForecastExportJobsRequest,
+    ) -> impl futures::Stream<
+        Item = Result<ForecastExportJobSummary, RusotoError<ListForecastExportJobsError>>,
+    > + Send {
+        rusoto_core::pagination::items(self.list_forecast_export_jobs_pages(input), |page| {
+            page.forecast_export_jobs.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `ListForecasts` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `MaxResults` of `input`.
+    pub fn list_forecasts_pages(
+        &self,
+        input: ListForecastsRequest,
+    ) -> impl futures::Stream<Item = Result<ListForecastsResponse, RusotoError<ListForecastsError>>> + Send
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: ForecastClient, input| async move { client.list_forecasts(input).await },
+            |input: &ListForecastsRequest, output: &ListForecastsResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListForecastsRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `Forecasts` of every page of `ListForecasts` results for `input`, as `list_forecasts_pages` requests them.
+    pub fn list_forecasts_stream(
+        &self,
+        input: ListForecastsRequest,
+    ) -> impl futures::Stream<Item = Result<ForecastSummary, RusotoError<ListForecastsError>>> + Send
+    {
+        rusoto_core::pagination::items(self.list_forecasts_pages(input), |page| {
+            page.forecasts.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `ListPredictors` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `MaxResults` of `input`.
+    pub fn list_predictors_pages(
+        &self,
+        input: ListPredictorsRequest,
+    ) -> impl futures::Stream<Item = Result<ListPredictorsResponse, RusotoError<ListPredictorsError>>>
+           + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: ForecastClient, input| async move { client.list_predictors(input).await },
+            |input: &ListPredictorsRequest, output: &ListPredictorsResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListPredictorsRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `Predictors` of every page of `ListPredictors` results for `input`, as `list_predictors_pages` requests them.
+    pub fn list_predictors_stream(
+        &self,
+        input: ListPredictorsRequest,
+    ) -> impl futures::Stream<Item = Result<PredictorSummary, RusotoError<ListPredictorsError>>> + Send
+    {
+        rusoto_core::pagination::items(self.list_predictors_pages(input), |page| {
+            page.predictors.unwrap_or_default()
+        })
+    }
+}
+
+#[async_trait]
+impl Forecast for ForecastClient {
+    /// <p><p>Creates an Amazon Forecast dataset. The information about the dataset that you provide helps Forecast understand how to consume the data for model training. This includes the following:</p> <ul> <li> <p> <i> <code>DataFrequency</code> </i> - How frequently your historical time-series data is collected.</p> </li> <li> <p> <i> <code>Domain</code> </i> and <i> <code>DatasetType</code> </i> - Each dataset has an associated dataset domain and a type within the domain. Amazon Forecast provides a list of predefined domains and types within each domain. For each unique dataset domain and type within the domain, Amazon Forecast requires your data to include a minimum set of predefined fields.</p> </li> <li> <p> <i> <code>Schema</code> </i> - A schema specifies the fields in the dataset, including the field name and data type.</p> </li> </ul> <p>After creating a dataset, you import your training data into it and add the dataset to a dataset group. You use the dataset group to create a predictor. For more information, see <a>howitworks-datasets-groups</a>.</p> <p>To get a list of all your datasets, use the <a>ListDatasets</a> operation.</p> <p>For example Forecast datasets, see the <a href="https://github.com/aws-samples/amazon-forecast-samples/tree/master/data">Amazon Forecast Sample GitHub repository</a>.</p> <note> <p>The <code>Status</code> of a dataset must be <code>ACTIVE</code> before you can import training data. Use the <a>DescribeDataset</a> operation to get the status.</p> </note></p>
+    async fn create_dataset(
+        &self,
+        input: CreateDatasetRequest,
+    ) -> Result<CreateDatasetResponse, RusotoError<CreateDatasetError>> {
+        self.create_dataset_with_metadata(input)
             .await
-            .map_err(RusotoError::from)?;
-        if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<UpdateDatasetGroupResponse, _>()
-        } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(UpdateDatasetGroupError::from_response(response))
-        }
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Creates a dataset group, which holds a collection of related datasets. You can add datasets to the dataset group when you create the dataset group, or later by using the <a>UpdateDatasetGroup</a> operation.</p> <p>After creating a dataset group and adding datasets, you use the dataset group when you create a predictor. For more information, see <a>howitworks-datasets-groups</a>.</p> <p>To get a list of all your datasets groups, use the <a>ListDatasetGroups</a> operation.</p> <note> <p>The <code>Status</code> of a dataset group must be <code>ACTIVE</code> before you can create use the dataset group to create a predictor. To get the status, use the <a>DescribeDatasetGroup</a> operation.</p> </note></p>
+    async fn create_dataset_group(
+        &self,
+        input: CreateDatasetGroupRequest,
+    ) -> Result<CreateDatasetGroupResponse, RusotoError<CreateDatasetGroupError>> {
+        self.create_dataset_group_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Imports your training data to an Amazon Forecast dataset. You provide the location of your training data in an Amazon Simple Storage Service (Amazon S3) bucket and the Amazon Resource Name (ARN) of the dataset that you want to import the data to.</p> <p>You must specify a <a>DataSource</a> object that includes an AWS Identity and Access Management (IAM) role that Amazon Forecast can assume to access the data. For more information, see <a>aws-forecast-iam-roles</a>.</p> <p>The training data must be in CSV format. The delimiter must be a comma (,).</p> <p>You can specify the path to a specific CSV file, the S3 bucket, or to a folder in the S3 bucket. For the latter two cases, Amazon Forecast imports all files up to the limit of 10,000 files.</p> <p>To get a list of all your dataset import jobs, filtered by specified criteria, use the <a>ListDatasetImportJobs</a> operation.</p>
+    async fn create_dataset_import_job(
+        &self,
+        input: CreateDatasetImportJobRequest,
+    ) -> Result<CreateDatasetImportJobResponse, RusotoError<CreateDatasetImportJobError>> {
+        self.create_dataset_import_job_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Creates a forecast for each item in the <code>TARGET<em>TIME</em>SERIES</code> dataset that was used to train the predictor. This is known as inference. To retrieve the forecast for a single item at low latency, use the operation. To export the complete forecast into your Amazon Simple Storage Service (Amazon S3) bucket, use the <a>CreateForecastExportJob</a> operation.</p> <p>The range of the forecast is determined by the <code>ForecastHorizon</code> value, which you specify in the <a>CreatePredictor</a> request, multiplied by the <code>DataFrequency</code> value, which you specify in the <a>CreateDataset</a> request. When you query a forecast, you can request a specific date range within the forecast.</p> <p>To get a list of all your forecasts, use the <a>ListForecasts</a> operation.</p> <note> <p>The forecasts generated by Amazon Forecast are in the same time zone as the dataset that was used to create the predictor.</p> </note> <p>For more information, see <a>howitworks-forecast</a>.</p> <note> <p>The <code>Status</code> of the forecast must be <code>ACTIVE</code> before you can query or export the forecast. Use the <a>DescribeForecast</a> operation to get the status.</p> </note></p>
+    async fn create_forecast(
+        &self,
+        input: CreateForecastRequest,
+    ) -> Result<CreateForecastResponse, RusotoError<CreateForecastError>> {
+        self.create_forecast_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Exports a forecast created by the <a>CreateForecast</a> operation to your Amazon Simple Storage Service (Amazon S3) bucket. The forecast file name will match the following conventions:</p> <p>&lt;ForecastExportJobName&gt;<em>&lt;ExportTimestamp&gt;</em>&lt;PageNumber&gt;</p> <p>where the &lt;ExportTimestamp&gt; component is in Java SimpleDateFormat (yyyy-MM-ddTHH-mm-ssZ).</p> <p>You must specify a <a>DataDestination</a> object that includes an AWS Identity and Access Management (IAM) role that Amazon Forecast can assume to access the Amazon S3 bucket. For more information, see <a>aws-forecast-iam-roles</a>.</p> <p>For more information, see <a>howitworks-forecast</a>.</p> <p>To get a list of all your forecast export jobs, use the <a>ListForecastExportJobs</a> operation.</p> <note> <p>The <code>Status</code> of the forecast export job must be <code>ACTIVE</code> before you can access the forecast in your Amazon S3 bucket. To get the status, use the <a>DescribeForecastExportJob</a> operation.</p> </note></p>
+    async fn create_forecast_export_job(
+        &self,
+        input: CreateForecastExportJobRequest,
+    ) -> Result<CreateForecastExportJobResponse, RusotoError<CreateForecastExportJobError>> {
+        self.create_forecast_export_job_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Creates an Amazon Forecast predictor.</p> <p>In the request, you provide a dataset group and either specify an algorithm or let Amazon Forecast choose the algorithm for you using AutoML. If you specify an algorithm, you also can override algorithm-specific hyperparameters.</p> <p>Amazon Forecast uses the chosen algorithm to train a model using the latest version of the datasets in the specified dataset group. The result is called a predictor. You then generate a forecast using the <a>CreateForecast</a> operation.</p> <p>After training a model, the <code>CreatePredictor</code> operation also evaluates it. To see the evaluation metrics, use the <a>GetAccuracyMetrics</a> operation. Always review the evaluation metrics before deciding to use the predictor to generate a forecast.</p> <p>Optionally, you can specify a featurization configuration to fill and aggregate the data fields in the <code>TARGET<em>TIME</em>SERIES</code> dataset to improve model training. For more information, see <a>FeaturizationConfig</a>.</p> <p>For RELATED<em>TIME</em>SERIES datasets, <code>CreatePredictor</code> verifies that the <code>DataFrequency</code> specified when the dataset was created matches the <code>ForecastFrequency</code>. TARGET<em>TIME</em>SERIES datasets don&#39;t have this restriction. Amazon Forecast also verifies the delimiter and timestamp format. For more information, see <a>howitworks-datasets-groups</a>.</p> <p> <b>AutoML</b> </p> <p>If you want Amazon Forecast to evaluate each algorithm and choose the one that minimizes the <code>objective function</code>, set <code>PerformAutoML</code> to <code>true</code>. The <code>objective function</code> is defined as the mean of the weighted p10, p50, and p90 quantile losses. For more information, see <a>EvaluationResult</a>.</p> <p>When AutoML is enabled, the following properties are disallowed:</p> <ul> <li> <p> <code>AlgorithmArn</code> </p> </li> <li> <p> <code>HPOConfig</code> </p> </li> <li> <p> <code>PerformHPO</code> </p> </li> <li> <p> <code>TrainingParameters</code> </p> </li> </ul> <p>To get a list of all of your predictors, use the <a>ListPredictors</a> operation.</p> <note> <p>Before you can use the predictor to create a forecast, the <code>Status</code> of the predictor must be <code>ACTIVE</code>, signifying that training has completed. To get the status, use the <a>DescribePredictor</a> operation.</p> </note></p>
+    async fn create_predictor(
+        &self,
+        input: CreatePredictorRequest,
+    ) -> Result<CreatePredictorResponse, RusotoError<CreatePredictorError>> {
+        self.create_predictor_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes an Amazon Forecast dataset that was created using the <a>CreateDataset</a> operation. You can only delete datasets that have a status of <code>ACTIVE</code> or <code>CREATE_FAILED</code>. To get the status use the <a>DescribeDataset</a> operation.</p>
+    async fn delete_dataset(
+        &self,
+        input: DeleteDatasetRequest,
+    ) -> Result<(), RusotoError<DeleteDatasetError>> {
+        self.delete_dataset_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes a dataset group created using the <a>CreateDatasetGroup</a> operation. You can only delete dataset groups that have a status of <code>ACTIVE</code>, <code>CREATE_FAILED</code>, or <code>UPDATE_FAILED</code>. To get the status, use the <a>DescribeDatasetGroup</a> operation.</p> <p>This operation deletes only the dataset group, not the datasets in the group.</p>
+    async fn delete_dataset_group(
+        &self,
+        input: DeleteDatasetGroupRequest,
+    ) -> Result<(), RusotoError<DeleteDatasetGroupError>> {
+        self.delete_dataset_group_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes a dataset import job created using the <a>CreateDatasetImportJob</a> operation. You can delete only dataset import jobs that have a status of <code>ACTIVE</code> or <code>CREATE_FAILED</code>. To get the status, use the <a>DescribeDatasetImportJob</a> operation.</p>
+    async fn delete_dataset_import_job(
+        &self,
+        input: DeleteDatasetImportJobRequest,
+    ) -> Result<(), RusotoError<DeleteDatasetImportJobError>> {
+        self.delete_dataset_import_job_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes a forecast created using the <a>CreateForecast</a> operation. You can delete only forecasts that have a status of <code>ACTIVE</code> or <code>CREATE_FAILED</code>. To get the status, use the <a>DescribeForecast</a> operation.</p> <p>You can't delete a forecast while it is being exported. After a forecast is deleted, you can no longer query the forecast.</p>
+    async fn delete_forecast(
+        &self,
+        input: DeleteForecastRequest,
+    ) -> Result<(), RusotoError<DeleteForecastError>> {
+        self.delete_forecast_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes a forecast export job created using the <a>CreateForecastExportJob</a> operation. You can delete only export jobs that have a status of <code>ACTIVE</code> or <code>CREATE_FAILED</code>. To get the status, use the <a>DescribeForecastExportJob</a> operation.</p>
+    async fn delete_forecast_export_job(
+        &self,
+        input: DeleteForecastExportJobRequest,
+    ) -> Result<(), RusotoError<DeleteForecastExportJobError>> {
+        self.delete_forecast_export_job_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes a predictor created using the <a>CreatePredictor</a> operation. You can delete only predictor that have a status of <code>ACTIVE</code> or <code>CREATE_FAILED</code>. To get the status, use the <a>DescribePredictor</a> operation.</p>
+    async fn delete_predictor(
+        &self,
+        input: DeletePredictorRequest,
+    ) -> Result<(), RusotoError<DeletePredictorError>> {
+        self.delete_predictor_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Describes an Amazon Forecast dataset created using the <a>CreateDataset</a> operation.</p> <p>In addition to listing the parameters specified in the <code>CreateDataset</code> request, this operation includes the following dataset properties:</p> <ul> <li> <p> <code>CreationTime</code> </p> </li> <li> <p> <code>LastModificationTime</code> </p> </li> <li> <p> <code>Status</code> </p> </li> </ul></p>
+    async fn describe_dataset(
+        &self,
+        input: DescribeDatasetRequest,
+    ) -> Result<DescribeDatasetResponse, RusotoError<DescribeDatasetError>> {
+        self.describe_dataset_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Describes a dataset group created using the <a>CreateDatasetGroup</a> operation.</p> <p>In addition to listing the parameters provided in the <code>CreateDatasetGroup</code> request, this operation includes the following properties:</p> <ul> <li> <p> <code>DatasetArns</code> - The datasets belonging to the group.</p> </li> <li> <p> <code>CreationTime</code> </p> </li> <li> <p> <code>LastModificationTime</code> </p> </li> <li> <p> <code>Status</code> </p> </li> </ul></p>
+    async fn describe_dataset_group(
+        &self,
+        input: DescribeDatasetGroupRequest,
+    ) -> Result<DescribeDatasetGroupResponse, RusotoError<DescribeDatasetGroupError>> {
+        self.describe_dataset_group_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Describes a dataset import job created using the <a>CreateDatasetImportJob</a> operation.</p> <p>In addition to listing the parameters provided in the <code>CreateDatasetImportJob</code> request, this operation includes the following properties:</p> <ul> <li> <p> <code>CreationTime</code> </p> </li> <li> <p> <code>LastModificationTime</code> </p> </li> <li> <p> <code>DataSize</code> </p> </li> <li> <p> <code>FieldStatistics</code> </p> </li> <li> <p> <code>Status</code> </p> </li> <li> <p> <code>Message</code> - If an error occurred, information about the error.</p> </li> </ul></p>
+    async fn describe_dataset_import_job(
+        &self,
+        input: DescribeDatasetImportJobRequest,
+    ) -> Result<DescribeDatasetImportJobResponse, RusotoError<DescribeDatasetImportJobError>> {
+        self.describe_dataset_import_job_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Describes a forecast created using the <a>CreateForecast</a> operation.</p> <p>In addition to listing the properties provided in the <code>CreateForecast</code> request, this operation lists the following properties:</p> <ul> <li> <p> <code>DatasetGroupArn</code> - The dataset group that provided the training data.</p> </li> <li> <p> <code>CreationTime</code> </p> </li> <li> <p> <code>LastModificationTime</code> </p> </li> <li> <p> <code>Status</code> </p> </li> <li> <p> <code>Message</code> - If an error occurred, information about the error.</p> </li> </ul></p>
+    async fn describe_forecast(
+        &self,
+        input: DescribeForecastRequest,
+    ) -> Result<DescribeForecastResponse, RusotoError<DescribeForecastError>> {
+        self.describe_forecast_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Describes a forecast export job created using the <a>CreateForecastExportJob</a> operation.</p> <p>In addition to listing the properties provided by the user in the <code>CreateForecastExportJob</code> request, this operation lists the following properties:</p> <ul> <li> <p> <code>CreationTime</code> </p> </li> <li> <p> <code>LastModificationTime</code> </p> </li> <li> <p> <code>Status</code> </p> </li> <li> <p> <code>Message</code> - If an error occurred, information about the error.</p> </li> </ul></p>
+    async fn describe_forecast_export_job(
+        &self,
+        input: DescribeForecastExportJobRequest,
+    ) -> Result<DescribeForecastExportJobResponse, RusotoError<DescribeForecastExportJobError>>
+    {
+        self.describe_forecast_export_job_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Describes a predictor created using the <a>CreatePredictor</a> operation.</p> <p>In addition to listing the properties provided in the <code>CreatePredictor</code> request, this operation lists the following properties:</p> <ul> <li> <p> <code>DatasetImportJobArns</code> - The dataset import jobs used to import training data.</p> </li> <li> <p> <code>AutoMLAlgorithmArns</code> - If AutoML is performed, the algorithms that were evaluated.</p> </li> <li> <p> <code>CreationTime</code> </p> </li> <li> <p> <code>LastModificationTime</code> </p> </li> <li> <p> <code>Status</code> </p> </li> <li> <p> <code>Message</code> - If an error occurred, information about the error.</p> </li> </ul></p>
+    async fn describe_predictor(
+        &self,
+        input: DescribePredictorRequest,
+    ) -> Result<DescribePredictorResponse, RusotoError<DescribePredictorError>> {
+        self.describe_predictor_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Provides metrics on the accuracy of the models that were trained by the <a>CreatePredictor</a> operation. Use metrics to see how well the model performed and to decide whether to use the predictor to generate a forecast. For more information, see <a>metrics</a>.</p> <p>This operation generates metrics for each backtest window that was evaluated. The number of backtest windows (<code>NumberOfBacktestWindows</code>) is specified using the <a>EvaluationParameters</a> object, which is optionally included in the <code>CreatePredictor</code> request. If <code>NumberOfBacktestWindows</code> isn&#39;t specified, the number defaults to one.</p> <p>The parameters of the <code>filling</code> method determine which items contribute to the metrics. If you want all items to contribute, specify <code>zero</code>. If you want only those items that have complete data in the range being evaluated to contribute, specify <code>nan</code>. For more information, see <a>FeaturizationMethod</a>.</p> <note> <p>Before you can get accuracy metrics, the <code>Status</code> of the predictor must be <code>ACTIVE</code>, signifying that training has completed. To get the status, use the <a>DescribePredictor</a> operation.</p> </note></p>
+    async fn get_accuracy_metrics(
+        &self,
+        input: GetAccuracyMetricsRequest,
+    ) -> Result<GetAccuracyMetricsResponse, RusotoError<GetAccuracyMetricsError>> {
+        self.get_accuracy_metrics_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns a list of dataset groups created using the <a>CreateDatasetGroup</a> operation. For each dataset group, this operation returns a summary of its properties, including its Amazon Resource Name (ARN). You can retrieve the complete set of properties by using the dataset group ARN with the <a>DescribeDatasetGroup</a> operation.</p>
+    async fn list_dataset_groups(
+        &self,
+        input: ListDatasetGroupsRequest,
+    ) -> Result<ListDatasetGroupsResponse, RusotoError<ListDatasetGroupsError>> {
+        self.list_dataset_groups_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns a list of dataset import jobs created using the <a>CreateDatasetImportJob</a> operation. For each import job, this operation returns a summary of its properties, including its Amazon Resource Name (ARN). You can retrieve the complete set of properties by using the ARN with the <a>DescribeDatasetImportJob</a> operation. You can filter the list by providing an array of <a>Filter</a> objects.</p>
+    async fn list_dataset_import_jobs(
+        &self,
+        input: ListDatasetImportJobsRequest,
+    ) -> Result<ListDatasetImportJobsResponse, RusotoError<ListDatasetImportJobsError>> {
+        self.list_dataset_import_jobs_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns a list of datasets created using the <a>CreateDataset</a> operation. For each dataset, a summary of its properties, including its Amazon Resource Name (ARN), is returned. To retrieve the complete set of properties, use the ARN with the <a>DescribeDataset</a> operation.</p>
+    async fn list_datasets(
+        &self,
+        input: ListDatasetsRequest,
+    ) -> Result<ListDatasetsResponse, RusotoError<ListDatasetsError>> {
+        self.list_datasets_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns a list of forecast export jobs created using the <a>CreateForecastExportJob</a> operation. For each forecast export job, this operation returns a summary of its properties, including its Amazon Resource Name (ARN). To retrieve the complete set of properties, use the ARN with the <a>DescribeForecastExportJob</a> operation. You can filter the list using an array of <a>Filter</a> objects.</p>
+    async fn list_forecast_export_jobs(
+        &self,
+        input: ListForecastExportJobsRequest,
+    ) -> Result<ListForecastExportJobsResponse, RusotoError<ListForecastExportJobsError>> {
+        self.list_forecast_export_jobs_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns a list of forecasts created using the <a>CreateForecast</a> operation. For each forecast, this operation returns a summary of its properties, including its Amazon Resource Name (ARN). To retrieve the complete set of properties, specify the ARN with the <a>DescribeForecast</a> operation. You can filter the list using an array of <a>Filter</a> objects.</p>
+    async fn list_forecasts(
+        &self,
+        input: ListForecastsRequest,
+    ) -> Result<ListForecastsResponse, RusotoError<ListForecastsError>> {
+        self.list_forecasts_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns a list of predictors created using the <a>CreatePredictor</a> operation. For each predictor, this operation returns a summary of its properties, including its Amazon Resource Name (ARN). You can retrieve the complete set of properties by using the ARN with the <a>DescribePredictor</a> operation. You can filter the list using an array of <a>Filter</a> objects.</p>
+    async fn list_predictors(
+        &self,
+        input: ListPredictorsRequest,
+    ) -> Result<ListPredictorsResponse, RusotoError<ListPredictorsError>> {
+        self.list_predictors_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Replaces the datasets in a dataset group with the specified datasets.</p> <note> <p>The <code>Status</code> of the dataset group must be <code>ACTIVE</code> before you can use the dataset group to create a predictor. Use the <a>DescribeDatasetGroup</a> operation to get the status.</p> </note></p>
+    async fn update_dataset_group(
+        &self,
+        input: UpdateDatasetGroupRequest,
+    ) -> Result<UpdateDatasetGroupResponse, RusotoError<UpdateDatasetGroupError>> {
+        self.update_dataset_group_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
     }
 }
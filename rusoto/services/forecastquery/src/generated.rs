@@ -16,8 +16,8 @@ use std::fmt;
 use async_trait::async_trait;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
-use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest, ResponseMetadata};
+use rusoto_core::{Client, ErrorMetadata, RusotoError};
 
 use rusoto_core::proto;
 use rusoto_core::signature::SignedRequest;
@@ -82,6 +82,7 @@ pub struct QueryForecastResponse {
 
 /// Errors returned by QueryForecast
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum QueryForecastError {
     /// <p>The value that you provided was invalid or too long.</p>
     InvalidInput(String),
@@ -93,6 +94,8 @@ pub enum QueryForecastError {
     ResourceInUse(String),
     /// <p>We can't find that resource. Check the information that you've provided and try again.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl QueryForecastError {
@@ -100,22 +103,28 @@ impl QueryForecastError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidInputException" => {
-                    return RusotoError::Service(QueryForecastError::InvalidInput(err.msg))
+                    return RusotoError::service(QueryForecastError::InvalidInput(err.msg), &res)
                 }
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(QueryForecastError::InvalidNextToken(err.msg))
+                    return RusotoError::service(
+                        QueryForecastError::InvalidNextToken(err.msg),
+                        &res,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(QueryForecastError::LimitExceeded(err.msg))
+                    return RusotoError::service(QueryForecastError::LimitExceeded(err.msg), &res)
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(QueryForecastError::ResourceInUse(err.msg))
+                    return RusotoError::service(QueryForecastError::ResourceInUse(err.msg), &res)
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(QueryForecastError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        QueryForecastError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(QueryForecastError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -130,6 +139,7 @@ impl fmt::Display for QueryForecastError {
             QueryForecastError::LimitExceeded(ref cause) => write!(f, "{}", cause),
             QueryForecastError::ResourceInUse(ref cause) => write!(f, "{}", cause),
             QueryForecastError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            QueryForecastError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
@@ -154,6 +164,7 @@ impl ForecastQueryClient {
     /// Creates a client backed by the default tokio event loop.
     ///
     /// The client will use the default credentials provider and tls client.
+    #[cfg(not(target_arch = "wasm32"))]
     pub fn new(region: region::Region) -> ForecastQueryClient {
         ForecastQueryClient {
             client: Client::shared(),
@@ -179,34 +190,86 @@ impl ForecastQueryClient {
     pub fn new_with_client(client: Client, region: region::Region) -> ForecastQueryClient {
         ForecastQueryClient { client, region }
     }
+
+    /// Returns a builder of a client, setting any of its region, credentials provider,
+    /// dispatcher, retry policy, endpoint and timeouts.
+    pub fn builder() -> rusoto_core::ClientBuilder<ForecastQueryClient> {
+        rusoto_core::ClientBuilder::new(ForecastQueryClient::new_with_client)
+    }
+
+    /// Returns a clone of this client with the overrides of `config` applied to its
+    /// operations, sharing its connections.
+    pub fn customize(&self, config: &rusoto_core::OperationConfig) -> ForecastQueryClient {
+        ForecastQueryClient {
+            client: self.client.customize(config),
+            region: config.resolve_region(&self.region),
+        }
+    }
 }
 
-#[async_trait]
-impl ForecastQuery for ForecastQueryClient {
-    /// <p><p>Retrieves a forecast filtered by the supplied criteria.</p> <p>The criteria is a key-value pair. The key is either <code>item<em>id</code> (or the equivalent non-timestamp, non-target field) from the <code>TARGET</em>TIME_SERIES</code> dataset, or one of the forecast dimensions specified as part of the <code>FeaturizationConfig</code> object.</p> <p>By default, the complete date range of the filtered forecast is returned. Optionally, you can request a specific date range within the forecast.</p> <note> <p>The forecasts generated by Amazon Forecast are in the same timezone as the dataset that was used to create the predictor.</p> </note></p>
-    async fn query_forecast(
+impl ForecastQueryClient {
+    /// Calls `query_forecast`, also returning the metadata of the response.
+    pub async fn query_forecast_with_metadata(
         &self,
         input: QueryForecastRequest,
-    ) -> Result<QueryForecastResponse, RusotoError<QueryForecastError>> {
-        let mut request = SignedRequest::new("POST", "forecast", &self.region, "/");
-        request.set_endpoint_prefix("forecastquery".to_string());
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "AmazonForecastRuntime.QueryForecast");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
+    ) -> Result<(QueryForecastResponse, ResponseMetadata), RusotoError<QueryForecastError>> {
+        let request = self.build_query_forecast_request(input);
 
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<QueryForecastResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<QueryForecastResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
             Err(QueryForecastError::from_response(response))
         }
     }
+
+    /// Presigns the request `query_forecast` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_query_forecast(
+        &self,
+        presigner: &rusoto_core::Presigner,
+        input: QueryForecastRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_query_forecast_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_query_forecast_request(&self, input: QueryForecastRequest) -> SignedRequest {
+        let mut request = SignedRequest::new("POST", "forecast", &self.region, "/");
+        request.set_operation("QueryForecast");
+        request.set_endpoint_prefix("forecastquery".to_string());
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "AmazonForecastRuntime.QueryForecast");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        request
+    }
+}
+
+#[async_trait]
+impl ForecastQuery for ForecastQueryClient {
+    /// <p><p>Retrieves a forecast filtered by the supplied criteria.</p> <p>The criteria is a key-value pair. The key is either <code>item<em>id</code> (or the equivalent non-timestamp, non-target field) from the <code>TARGET</em>TIME_SERIES</code> dataset, or one of the forecast dimensions specified as part of the <code>FeaturizationConfig</code> object.</p> <p>By default, the complete date range of the filtered forecast is returned. Optionally, you can request a specific date range within the forecast.</p> <note> <p>The forecasts generated by Amazon Forecast are in the same timezone as the dataset that was used to create the predictor.</p> </note></p>
+    async fn query_forecast(
+        &self,
+        input: QueryForecastRequest,
+    ) -> Result<QueryForecastResponse, RusotoError<QueryForecastError>> {
+        self.query_forecast_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
 }
@@ -16,8 +16,8 @@ use std::fmt;
 use async_trait::async_trait;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
-use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest, ResponseMetadata};
+use rusoto_core::{Client, ErrorMetadata, RusotoError};
 
 use rusoto_core::proto;
 use rusoto_core::signature::SignedRequest;
@@ -1348,11 +1348,14 @@ pub struct VariableEntry {
 
 /// Errors returned by BatchCreateVariable
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum BatchCreateVariableError {
     /// <p>An exception indicating an internal server error.</p>
     InternalServer(String),
     /// <p>An exception indicating a throttling error.</p>
     Throttling(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl BatchCreateVariableError {
@@ -1360,13 +1363,19 @@ impl BatchCreateVariableError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(BatchCreateVariableError::InternalServer(err.msg))
+                    return RusotoError::service(
+                        BatchCreateVariableError::InternalServer(err.msg),
+                        &res,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(BatchCreateVariableError::Throttling(err.msg))
+                    return RusotoError::service(
+                        BatchCreateVariableError::Throttling(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(BatchCreateVariableError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1378,17 +1387,21 @@ impl fmt::Display for BatchCreateVariableError {
         match *self {
             BatchCreateVariableError::InternalServer(ref cause) => write!(f, "{}", cause),
             BatchCreateVariableError::Throttling(ref cause) => write!(f, "{}", cause),
+            BatchCreateVariableError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for BatchCreateVariableError {}
 /// Errors returned by BatchGetVariable
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum BatchGetVariableError {
     /// <p>An exception indicating an internal server error.</p>
     InternalServer(String),
     /// <p>An exception indicating a throttling error.</p>
     Throttling(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl BatchGetVariableError {
@@ -1396,13 +1409,16 @@ impl BatchGetVariableError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(BatchGetVariableError::InternalServer(err.msg))
+                    return RusotoError::service(
+                        BatchGetVariableError::InternalServer(err.msg),
+                        &res,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(BatchGetVariableError::Throttling(err.msg))
+                    return RusotoError::service(BatchGetVariableError::Throttling(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(BatchGetVariableError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1414,12 +1430,14 @@ impl fmt::Display for BatchGetVariableError {
         match *self {
             BatchGetVariableError::InternalServer(ref cause) => write!(f, "{}", cause),
             BatchGetVariableError::Throttling(ref cause) => write!(f, "{}", cause),
+            BatchGetVariableError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for BatchGetVariableError {}
 /// Errors returned by CreateDetectorVersion
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateDetectorVersionError {
     /// <p>An exception indicating an internal server error.</p>
     InternalServer(String),
@@ -1427,6 +1445,8 @@ pub enum CreateDetectorVersionError {
     ResourceNotFound(String),
     /// <p>An exception indicating a throttling error.</p>
     Throttling(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateDetectorVersionError {
@@ -1434,20 +1454,25 @@ impl CreateDetectorVersionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(CreateDetectorVersionError::InternalServer(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateDetectorVersionError::InternalServer(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(CreateDetectorVersionError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateDetectorVersionError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(CreateDetectorVersionError::Throttling(err.msg))
+                    return RusotoError::service(
+                        CreateDetectorVersionError::Throttling(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreateDetectorVersionError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1460,17 +1485,21 @@ impl fmt::Display for CreateDetectorVersionError {
             CreateDetectorVersionError::InternalServer(ref cause) => write!(f, "{}", cause),
             CreateDetectorVersionError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             CreateDetectorVersionError::Throttling(ref cause) => write!(f, "{}", cause),
+            CreateDetectorVersionError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateDetectorVersionError {}
 /// Errors returned by CreateModelVersion
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateModelVersionError {
     /// <p>An exception indicating an internal server error.</p>
     InternalServer(String),
     /// <p>An exception indicating a throttling error.</p>
     Throttling(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateModelVersionError {
@@ -1478,13 +1507,16 @@ impl CreateModelVersionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(CreateModelVersionError::InternalServer(err.msg))
+                    return RusotoError::service(
+                        CreateModelVersionError::InternalServer(err.msg),
+                        &res,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(CreateModelVersionError::Throttling(err.msg))
+                    return RusotoError::service(CreateModelVersionError::Throttling(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreateModelVersionError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1496,17 +1528,21 @@ impl fmt::Display for CreateModelVersionError {
         match *self {
             CreateModelVersionError::InternalServer(ref cause) => write!(f, "{}", cause),
             CreateModelVersionError::Throttling(ref cause) => write!(f, "{}", cause),
+            CreateModelVersionError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateModelVersionError {}
 /// Errors returned by CreateRule
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateRuleError {
     /// <p>An exception indicating an internal server error.</p>
     InternalServer(String),
     /// <p>An exception indicating a throttling error.</p>
     Throttling(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateRuleError {
@@ -1514,13 +1550,13 @@ impl CreateRuleError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(CreateRuleError::InternalServer(err.msg))
+                    return RusotoError::service(CreateRuleError::InternalServer(err.msg), &res)
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(CreateRuleError::Throttling(err.msg))
+                    return RusotoError::service(CreateRuleError::Throttling(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreateRuleError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1532,17 +1568,21 @@ impl fmt::Display for CreateRuleError {
         match *self {
             CreateRuleError::InternalServer(ref cause) => write!(f, "{}", cause),
             CreateRuleError::Throttling(ref cause) => write!(f, "{}", cause),
+            CreateRuleError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateRuleError {}
 /// Errors returned by CreateVariable
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateVariableError {
     /// <p>An exception indicating an internal server error.</p>
     InternalServer(String),
     /// <p>An exception indicating a throttling error.</p>
     Throttling(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateVariableError {
@@ -1550,13 +1590,13 @@ impl CreateVariableError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(CreateVariableError::InternalServer(err.msg))
+                    return RusotoError::service(CreateVariableError::InternalServer(err.msg), &res)
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(CreateVariableError::Throttling(err.msg))
+                    return RusotoError::service(CreateVariableError::Throttling(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreateVariableError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1568,12 +1608,14 @@ impl fmt::Display for CreateVariableError {
         match *self {
             CreateVariableError::InternalServer(ref cause) => write!(f, "{}", cause),
             CreateVariableError::Throttling(ref cause) => write!(f, "{}", cause),
+            CreateVariableError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateVariableError {}
 /// Errors returned by DeleteDetectorVersion
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteDetectorVersionError {
     /// <p>An exception indicating an internal server error.</p>
     InternalServer(String),
@@ -1581,6 +1623,8 @@ pub enum DeleteDetectorVersionError {
     ResourceNotFound(String),
     /// <p>An exception indicating a throttling error.</p>
     Throttling(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteDetectorVersionError {
@@ -1588,20 +1632,25 @@ impl DeleteDetectorVersionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(DeleteDetectorVersionError::InternalServer(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteDetectorVersionError::InternalServer(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteDetectorVersionError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteDetectorVersionError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DeleteDetectorVersionError::Throttling(err.msg))
+                    return RusotoError::service(
+                        DeleteDetectorVersionError::Throttling(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteDetectorVersionError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1614,17 +1663,21 @@ impl fmt::Display for DeleteDetectorVersionError {
             DeleteDetectorVersionError::InternalServer(ref cause) => write!(f, "{}", cause),
             DeleteDetectorVersionError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             DeleteDetectorVersionError::Throttling(ref cause) => write!(f, "{}", cause),
+            DeleteDetectorVersionError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteDetectorVersionError {}
 /// Errors returned by DeleteEvent
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteEventError {
     /// <p>An exception indicating an internal server error.</p>
     InternalServer(String),
     /// <p>An exception indicating a throttling error.</p>
     Throttling(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteEventError {
@@ -1632,13 +1685,13 @@ impl DeleteEventError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(DeleteEventError::InternalServer(err.msg))
+                    return RusotoError::service(DeleteEventError::InternalServer(err.msg), &res)
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DeleteEventError::Throttling(err.msg))
+                    return RusotoError::service(DeleteEventError::Throttling(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteEventError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1650,12 +1703,14 @@ impl fmt::Display for DeleteEventError {
         match *self {
             DeleteEventError::InternalServer(ref cause) => write!(f, "{}", cause),
             DeleteEventError::Throttling(ref cause) => write!(f, "{}", cause),
+            DeleteEventError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteEventError {}
 /// Errors returned by DescribeDetector
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeDetectorError {
     /// <p>An exception indicating an internal server error.</p>
     InternalServer(String),
@@ -1663,6 +1718,8 @@ pub enum DescribeDetectorError {
     ResourceNotFound(String),
     /// <p>An exception indicating a throttling error.</p>
     Throttling(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeDetectorError {
@@ -1670,16 +1727,22 @@ impl DescribeDetectorError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(DescribeDetectorError::InternalServer(err.msg))
+                    return RusotoError::service(
+                        DescribeDetectorError::InternalServer(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeDetectorError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        DescribeDetectorError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DescribeDetectorError::Throttling(err.msg))
+                    return RusotoError::service(DescribeDetectorError::Throttling(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DescribeDetectorError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1692,12 +1755,14 @@ impl fmt::Display for DescribeDetectorError {
             DescribeDetectorError::InternalServer(ref cause) => write!(f, "{}", cause),
             DescribeDetectorError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             DescribeDetectorError::Throttling(ref cause) => write!(f, "{}", cause),
+            DescribeDetectorError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeDetectorError {}
 /// Errors returned by DescribeModelVersions
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeModelVersionsError {
     /// <p>An exception indicating an internal server error.</p>
     InternalServer(String),
@@ -1705,6 +1770,8 @@ pub enum DescribeModelVersionsError {
     ResourceNotFound(String),
     /// <p>An exception indicating a throttling error.</p>
     Throttling(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeModelVersionsError {
@@ -1712,20 +1779,25 @@ impl DescribeModelVersionsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(DescribeModelVersionsError::InternalServer(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeModelVersionsError::InternalServer(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeModelVersionsError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeModelVersionsError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DescribeModelVersionsError::Throttling(err.msg))
+                    return RusotoError::service(
+                        DescribeModelVersionsError::Throttling(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DescribeModelVersionsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1738,12 +1810,14 @@ impl fmt::Display for DescribeModelVersionsError {
             DescribeModelVersionsError::InternalServer(ref cause) => write!(f, "{}", cause),
             DescribeModelVersionsError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             DescribeModelVersionsError::Throttling(ref cause) => write!(f, "{}", cause),
+            DescribeModelVersionsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeModelVersionsError {}
 /// Errors returned by GetDetectorVersion
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetDetectorVersionError {
     /// <p>An exception indicating an internal server error.</p>
     InternalServer(String),
@@ -1751,6 +1825,8 @@ pub enum GetDetectorVersionError {
     ResourceNotFound(String),
     /// <p>An exception indicating a throttling error.</p>
     Throttling(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetDetectorVersionError {
@@ -1758,16 +1834,22 @@ impl GetDetectorVersionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(GetDetectorVersionError::InternalServer(err.msg))
+                    return RusotoError::service(
+                        GetDetectorVersionError::InternalServer(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetDetectorVersionError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        GetDetectorVersionError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(GetDetectorVersionError::Throttling(err.msg))
+                    return RusotoError::service(GetDetectorVersionError::Throttling(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetDetectorVersionError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1780,12 +1862,14 @@ impl fmt::Display for GetDetectorVersionError {
             GetDetectorVersionError::InternalServer(ref cause) => write!(f, "{}", cause),
             GetDetectorVersionError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             GetDetectorVersionError::Throttling(ref cause) => write!(f, "{}", cause),
+            GetDetectorVersionError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetDetectorVersionError {}
 /// Errors returned by GetDetectors
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetDetectorsError {
     /// <p>An exception indicating an internal server error.</p>
     InternalServer(String),
@@ -1793,6 +1877,8 @@ pub enum GetDetectorsError {
     ResourceNotFound(String),
     /// <p>An exception indicating a throttling error.</p>
     Throttling(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetDetectorsError {
@@ -1800,16 +1886,16 @@ impl GetDetectorsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(GetDetectorsError::InternalServer(err.msg))
+                    return RusotoError::service(GetDetectorsError::InternalServer(err.msg), &res)
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetDetectorsError::ResourceNotFound(err.msg))
+                    return RusotoError::service(GetDetectorsError::ResourceNotFound(err.msg), &res)
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(GetDetectorsError::Throttling(err.msg))
+                    return RusotoError::service(GetDetectorsError::Throttling(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetDetectorsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1822,12 +1908,14 @@ impl fmt::Display for GetDetectorsError {
             GetDetectorsError::InternalServer(ref cause) => write!(f, "{}", cause),
             GetDetectorsError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             GetDetectorsError::Throttling(ref cause) => write!(f, "{}", cause),
+            GetDetectorsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetDetectorsError {}
 /// Errors returned by GetExternalModels
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetExternalModelsError {
     /// <p>An exception indicating an internal server error.</p>
     InternalServer(String),
@@ -1835,6 +1923,8 @@ pub enum GetExternalModelsError {
     ResourceNotFound(String),
     /// <p>An exception indicating a throttling error.</p>
     Throttling(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetExternalModelsError {
@@ -1842,16 +1932,22 @@ impl GetExternalModelsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(GetExternalModelsError::InternalServer(err.msg))
+                    return RusotoError::service(
+                        GetExternalModelsError::InternalServer(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetExternalModelsError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        GetExternalModelsError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(GetExternalModelsError::Throttling(err.msg))
+                    return RusotoError::service(GetExternalModelsError::Throttling(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetExternalModelsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1864,12 +1960,14 @@ impl fmt::Display for GetExternalModelsError {
             GetExternalModelsError::InternalServer(ref cause) => write!(f, "{}", cause),
             GetExternalModelsError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             GetExternalModelsError::Throttling(ref cause) => write!(f, "{}", cause),
+            GetExternalModelsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetExternalModelsError {}
 /// Errors returned by GetModelVersion
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetModelVersionError {
     /// <p>An exception indicating an internal server error.</p>
     InternalServer(String),
@@ -1877,6 +1975,8 @@ pub enum GetModelVersionError {
     ResourceNotFound(String),
     /// <p>An exception indicating a throttling error.</p>
     Throttling(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetModelVersionError {
@@ -1884,16 +1984,22 @@ impl GetModelVersionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(GetModelVersionError::InternalServer(err.msg))
+                    return RusotoError::service(
+                        GetModelVersionError::InternalServer(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetModelVersionError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        GetModelVersionError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(GetModelVersionError::Throttling(err.msg))
+                    return RusotoError::service(GetModelVersionError::Throttling(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetModelVersionError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1906,12 +2012,14 @@ impl fmt::Display for GetModelVersionError {
             GetModelVersionError::InternalServer(ref cause) => write!(f, "{}", cause),
             GetModelVersionError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             GetModelVersionError::Throttling(ref cause) => write!(f, "{}", cause),
+            GetModelVersionError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetModelVersionError {}
 /// Errors returned by GetModels
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetModelsError {
     /// <p>An exception indicating an internal server error.</p>
     InternalServer(String),
@@ -1919,6 +2027,8 @@ pub enum GetModelsError {
     ResourceNotFound(String),
     /// <p>An exception indicating a throttling error.</p>
     Throttling(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetModelsError {
@@ -1926,16 +2036,16 @@ impl GetModelsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(GetModelsError::InternalServer(err.msg))
+                    return RusotoError::service(GetModelsError::InternalServer(err.msg), &res)
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetModelsError::ResourceNotFound(err.msg))
+                    return RusotoError::service(GetModelsError::ResourceNotFound(err.msg), &res)
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(GetModelsError::Throttling(err.msg))
+                    return RusotoError::service(GetModelsError::Throttling(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetModelsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1948,12 +2058,14 @@ impl fmt::Display for GetModelsError {
             GetModelsError::InternalServer(ref cause) => write!(f, "{}", cause),
             GetModelsError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             GetModelsError::Throttling(ref cause) => write!(f, "{}", cause),
+            GetModelsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetModelsError {}
 /// Errors returned by GetOutcomes
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetOutcomesError {
     /// <p>An exception indicating an internal server error.</p>
     InternalServer(String),
@@ -1961,6 +2073,8 @@ pub enum GetOutcomesError {
     ResourceNotFound(String),
     /// <p>An exception indicating a throttling error.</p>
     Throttling(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetOutcomesError {
@@ -1968,16 +2082,16 @@ impl GetOutcomesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(GetOutcomesError::InternalServer(err.msg))
+                    return RusotoError::service(GetOutcomesError::InternalServer(err.msg), &res)
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetOutcomesError::ResourceNotFound(err.msg))
+                    return RusotoError::service(GetOutcomesError::ResourceNotFound(err.msg), &res)
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(GetOutcomesError::Throttling(err.msg))
+                    return RusotoError::service(GetOutcomesError::Throttling(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetOutcomesError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1990,12 +2104,14 @@ impl fmt::Display for GetOutcomesError {
             GetOutcomesError::InternalServer(ref cause) => write!(f, "{}", cause),
             GetOutcomesError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             GetOutcomesError::Throttling(ref cause) => write!(f, "{}", cause),
+            GetOutcomesError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetOutcomesError {}
 /// Errors returned by GetPrediction
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetPredictionError {
     /// <p>An exception indicating an internal server error.</p>
     InternalServer(String),
@@ -2003,6 +2119,8 @@ pub enum GetPredictionError {
     ResourceNotFound(String),
     /// <p>An exception indicating a throttling error.</p>
     Throttling(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetPredictionError {
@@ -2010,16 +2128,19 @@ impl GetPredictionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(GetPredictionError::InternalServer(err.msg))
+                    return RusotoError::service(GetPredictionError::InternalServer(err.msg), &res)
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetPredictionError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        GetPredictionError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(GetPredictionError::Throttling(err.msg))
+                    return RusotoError::service(GetPredictionError::Throttling(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetPredictionError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2032,12 +2153,14 @@ impl fmt::Display for GetPredictionError {
             GetPredictionError::InternalServer(ref cause) => write!(f, "{}", cause),
             GetPredictionError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             GetPredictionError::Throttling(ref cause) => write!(f, "{}", cause),
+            GetPredictionError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetPredictionError {}
 /// Errors returned by GetRules
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetRulesError {
     /// <p>An exception indicating an internal server error.</p>
     InternalServer(String),
@@ -2045,6 +2168,8 @@ pub enum GetRulesError {
     ResourceNotFound(String),
     /// <p>An exception indicating a throttling error.</p>
     Throttling(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetRulesError {
@@ -2052,16 +2177,16 @@ impl GetRulesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(GetRulesError::InternalServer(err.msg))
+                    return RusotoError::service(GetRulesError::InternalServer(err.msg), &res)
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetRulesError::ResourceNotFound(err.msg))
+                    return RusotoError::service(GetRulesError::ResourceNotFound(err.msg), &res)
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(GetRulesError::Throttling(err.msg))
+                    return RusotoError::service(GetRulesError::Throttling(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetRulesError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2074,12 +2199,14 @@ impl fmt::Display for GetRulesError {
             GetRulesError::InternalServer(ref cause) => write!(f, "{}", cause),
             GetRulesError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             GetRulesError::Throttling(ref cause) => write!(f, "{}", cause),
+            GetRulesError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetRulesError {}
 /// Errors returned by GetVariables
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetVariablesError {
     /// <p>An exception indicating an internal server error.</p>
     InternalServer(String),
@@ -2087,6 +2214,8 @@ pub enum GetVariablesError {
     ResourceNotFound(String),
     /// <p>An exception indicating a throttling error.</p>
     Throttling(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetVariablesError {
@@ -2094,16 +2223,16 @@ impl GetVariablesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(GetVariablesError::InternalServer(err.msg))
+                    return RusotoError::service(GetVariablesError::InternalServer(err.msg), &res)
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetVariablesError::ResourceNotFound(err.msg))
+                    return RusotoError::service(GetVariablesError::ResourceNotFound(err.msg), &res)
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(GetVariablesError::Throttling(err.msg))
+                    return RusotoError::service(GetVariablesError::Throttling(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetVariablesError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2116,17 +2245,21 @@ impl fmt::Display for GetVariablesError {
             GetVariablesError::InternalServer(ref cause) => write!(f, "{}", cause),
             GetVariablesError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             GetVariablesError::Throttling(ref cause) => write!(f, "{}", cause),
+            GetVariablesError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetVariablesError {}
 /// Errors returned by PutDetector
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum PutDetectorError {
     /// <p>An exception indicating an internal server error.</p>
     InternalServer(String),
     /// <p>An exception indicating a throttling error.</p>
     Throttling(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl PutDetectorError {
@@ -2134,13 +2267,13 @@ impl PutDetectorError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(PutDetectorError::InternalServer(err.msg))
+                    return RusotoError::service(PutDetectorError::InternalServer(err.msg), &res)
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(PutDetectorError::Throttling(err.msg))
+                    return RusotoError::service(PutDetectorError::Throttling(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(PutDetectorError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2152,17 +2285,21 @@ impl fmt::Display for PutDetectorError {
         match *self {
             PutDetectorError::InternalServer(ref cause) => write!(f, "{}", cause),
             PutDetectorError::Throttling(ref cause) => write!(f, "{}", cause),
+            PutDetectorError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for PutDetectorError {}
 /// Errors returned by PutExternalModel
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum PutExternalModelError {
     /// <p>An exception indicating an internal server error.</p>
     InternalServer(String),
     /// <p>An exception indicating a throttling error.</p>
     Throttling(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl PutExternalModelError {
@@ -2170,13 +2307,16 @@ impl PutExternalModelError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(PutExternalModelError::InternalServer(err.msg))
+                    return RusotoError::service(
+                        PutExternalModelError::InternalServer(err.msg),
+                        &res,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(PutExternalModelError::Throttling(err.msg))
+                    return RusotoError::service(PutExternalModelError::Throttling(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(PutExternalModelError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2188,17 +2328,21 @@ impl fmt::Display for PutExternalModelError {
         match *self {
             PutExternalModelError::InternalServer(ref cause) => write!(f, "{}", cause),
             PutExternalModelError::Throttling(ref cause) => write!(f, "{}", cause),
+            PutExternalModelError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for PutExternalModelError {}
 /// Errors returned by PutModel
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum PutModelError {
     /// <p>An exception indicating an internal server error.</p>
     InternalServer(String),
     /// <p>An exception indicating a throttling error.</p>
     Throttling(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl PutModelError {
@@ -2206,13 +2350,13 @@ impl PutModelError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(PutModelError::InternalServer(err.msg))
+                    return RusotoError::service(PutModelError::InternalServer(err.msg), &res)
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(PutModelError::Throttling(err.msg))
+                    return RusotoError::service(PutModelError::Throttling(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(PutModelError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2224,17 +2368,21 @@ impl fmt::Display for PutModelError {
         match *self {
             PutModelError::InternalServer(ref cause) => write!(f, "{}", cause),
             PutModelError::Throttling(ref cause) => write!(f, "{}", cause),
+            PutModelError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for PutModelError {}
 /// Errors returned by PutOutcome
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum PutOutcomeError {
     /// <p>An exception indicating an internal server error.</p>
     InternalServer(String),
     /// <p>An exception indicating a throttling error.</p>
     Throttling(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl PutOutcomeError {
@@ -2242,13 +2390,13 @@ impl PutOutcomeError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(PutOutcomeError::InternalServer(err.msg))
+                    return RusotoError::service(PutOutcomeError::InternalServer(err.msg), &res)
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(PutOutcomeError::Throttling(err.msg))
+                    return RusotoError::service(PutOutcomeError::Throttling(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(PutOutcomeError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2260,12 +2408,14 @@ impl fmt::Display for PutOutcomeError {
         match *self {
             PutOutcomeError::InternalServer(ref cause) => write!(f, "{}", cause),
             PutOutcomeError::Throttling(ref cause) => write!(f, "{}", cause),
+            PutOutcomeError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for PutOutcomeError {}
 /// Errors returned by UpdateDetectorVersion
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdateDetectorVersionError {
     /// <p>An exception indicating an internal server error.</p>
     InternalServer(String),
@@ -2273,6 +2423,8 @@ pub enum UpdateDetectorVersionError {
     ResourceNotFound(String),
     /// <p>An exception indicating a throttling error.</p>
     Throttling(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdateDetectorVersionError {
@@ -2280,20 +2432,25 @@ impl UpdateDetectorVersionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(UpdateDetectorVersionError::InternalServer(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateDetectorVersionError::InternalServer(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateDetectorVersionError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateDetectorVersionError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(UpdateDetectorVersionError::Throttling(err.msg))
+                    return RusotoError::service(
+                        UpdateDetectorVersionError::Throttling(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UpdateDetectorVersionError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2306,17 +2463,21 @@ impl fmt::Display for UpdateDetectorVersionError {
             UpdateDetectorVersionError::InternalServer(ref cause) => write!(f, "{}", cause),
             UpdateDetectorVersionError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             UpdateDetectorVersionError::Throttling(ref cause) => write!(f, "{}", cause),
+            UpdateDetectorVersionError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for UpdateDetectorVersionError {}
 /// Errors returned by UpdateDetectorVersionMetadata
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdateDetectorVersionMetadataError {
     /// <p>An exception indicating an internal server error.</p>
     InternalServer(String),
     /// <p>An exception indicating a throttling error.</p>
     Throttling(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdateDetectorVersionMetadataError {
@@ -2326,17 +2487,24 @@ impl UpdateDetectorVersionMetadataError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         UpdateDetectorVersionMetadataError::InternalServer(err.msg),
+                        &res,
                     )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(UpdateDetectorVersionMetadataError::Throttling(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateDetectorVersionMetadataError::Throttling(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        UpdateDetectorVersionMetadataError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -2348,12 +2516,16 @@ impl fmt::Display for UpdateDetectorVersionMetadataError {
         match *self {
             UpdateDetectorVersionMetadataError::InternalServer(ref cause) => write!(f, "{}", cause),
             UpdateDetectorVersionMetadataError::Throttling(ref cause) => write!(f, "{}", cause),
+            UpdateDetectorVersionMetadataError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for UpdateDetectorVersionMetadataError {}
 /// Errors returned by UpdateDetectorVersionStatus
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdateDetectorVersionStatusError {
     /// <p>An exception indicating an internal server error.</p>
     InternalServer(String),
@@ -2361,6 +2533,8 @@ pub enum UpdateDetectorVersionStatusError {
     ResourceNotFound(String),
     /// <p>An exception indicating a throttling error.</p>
     Throttling(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdateDetectorVersionStatusError {
@@ -2370,22 +2544,30 @@ impl UpdateDetectorVersionStatusError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(UpdateDetectorVersionStatusError::InternalServer(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateDetectorVersionStatusError::InternalServer(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         UpdateDetectorVersionStatusError::ResourceNotFound(err.msg),
+                        &res,
                     )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(UpdateDetectorVersionStatusError::Throttling(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateDetectorVersionStatusError::Throttling(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        UpdateDetectorVersionStatusError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -2398,12 +2580,14 @@ impl fmt::Display for UpdateDetectorVersionStatusError {
             UpdateDetectorVersionStatusError::InternalServer(ref cause) => write!(f, "{}", cause),
             UpdateDetectorVersionStatusError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             UpdateDetectorVersionStatusError::Throttling(ref cause) => write!(f, "{}", cause),
+            UpdateDetectorVersionStatusError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for UpdateDetectorVersionStatusError {}
 /// Errors returned by UpdateModelVersion
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdateModelVersionError {
     /// <p>An exception indicating an internal server error.</p>
     InternalServer(String),
@@ -2411,6 +2595,8 @@ pub enum UpdateModelVersionError {
     ResourceNotFound(String),
     /// <p>An exception indicating a throttling error.</p>
     Throttling(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdateModelVersionError {
@@ -2418,16 +2604,22 @@ impl UpdateModelVersionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(UpdateModelVersionError::InternalServer(err.msg))
+                    return RusotoError::service(
+                        UpdateModelVersionError::InternalServer(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateModelVersionError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        UpdateModelVersionError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(UpdateModelVersionError::Throttling(err.msg))
+                    return RusotoError::service(UpdateModelVersionError::Throttling(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UpdateModelVersionError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2440,12 +2632,14 @@ impl fmt::Display for UpdateModelVersionError {
             UpdateModelVersionError::InternalServer(ref cause) => write!(f, "{}", cause),
             UpdateModelVersionError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             UpdateModelVersionError::Throttling(ref cause) => write!(f, "{}", cause),
+            UpdateModelVersionError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for UpdateModelVersionError {}
 /// Errors returned by UpdateRuleMetadata
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdateRuleMetadataError {
     /// <p>An exception indicating an internal server error.</p>
     InternalServer(String),
@@ -2453,6 +2647,8 @@ pub enum UpdateRuleMetadataError {
     ResourceNotFound(String),
     /// <p>An exception indicating a throttling error.</p>
     Throttling(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdateRuleMetadataError {
@@ -2460,16 +2656,22 @@ impl UpdateRuleMetadataError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(UpdateRuleMetadataError::InternalServer(err.msg))
+                    return RusotoError::service(
+                        UpdateRuleMetadataError::InternalServer(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateRuleMetadataError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        UpdateRuleMetadataError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(UpdateRuleMetadataError::Throttling(err.msg))
+                    return RusotoError::service(UpdateRuleMetadataError::Throttling(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UpdateRuleMetadataError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2482,12 +2684,14 @@ impl fmt::Display for UpdateRuleMetadataError {
             UpdateRuleMetadataError::InternalServer(ref cause) => write!(f, "{}", cause),
             UpdateRuleMetadataError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             UpdateRuleMetadataError::Throttling(ref cause) => write!(f, "{}", cause),
+            UpdateRuleMetadataError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for UpdateRuleMetadataError {}
 /// Errors returned by UpdateRuleVersion
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdateRuleVersionError {
     /// <p>An exception indicating an internal server error.</p>
     InternalServer(String),
@@ -2495,6 +2699,8 @@ pub enum UpdateRuleVersionError {
     ResourceNotFound(String),
     /// <p>An exception indicating a throttling error.</p>
     Throttling(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdateRuleVersionError {
@@ -2502,16 +2708,22 @@ impl UpdateRuleVersionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(UpdateRuleVersionError::InternalServer(err.msg))
+                    return RusotoError::service(
+                        UpdateRuleVersionError::InternalServer(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateRuleVersionError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        UpdateRuleVersionError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(UpdateRuleVersionError::Throttling(err.msg))
+                    return RusotoError::service(UpdateRuleVersionError::Throttling(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UpdateRuleVersionError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2524,12 +2736,14 @@ impl fmt::Display for UpdateRuleVersionError {
             UpdateRuleVersionError::InternalServer(ref cause) => write!(f, "{}", cause),
             UpdateRuleVersionError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             UpdateRuleVersionError::Throttling(ref cause) => write!(f, "{}", cause),
+            UpdateRuleVersionError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for UpdateRuleVersionError {}
 /// Errors returned by UpdateVariable
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdateVariableError {
     /// <p>An exception indicating an internal server error.</p>
     InternalServer(String),
@@ -2537,6 +2751,8 @@ pub enum UpdateVariableError {
     ResourceNotFound(String),
     /// <p>An exception indicating a throttling error.</p>
     Throttling(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdateVariableError {
@@ -2544,16 +2760,19 @@ impl UpdateVariableError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(UpdateVariableError::InternalServer(err.msg))
+                    return RusotoError::service(UpdateVariableError::InternalServer(err.msg), &res)
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateVariableError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        UpdateVariableError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(UpdateVariableError::Throttling(err.msg))
+                    return RusotoError::service(UpdateVariableError::Throttling(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UpdateVariableError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -2566,6 +2785,7 @@ impl fmt::Display for UpdateVariableError {
             UpdateVariableError::InternalServer(ref cause) => write!(f, "{}", cause),
             UpdateVariableError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
             UpdateVariableError::Throttling(ref cause) => write!(f, "{}", cause),
+            UpdateVariableError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
@@ -2764,6 +2984,7 @@ impl FraudDetectorClient {
     /// Creates a client backed by the default tokio event loop.
     ///
     /// The client will use the default credentials provider and tls client.
+    #[cfg(not(target_arch = "wasm32"))]
     pub fn new(region: region::Region) -> FraudDetectorClient {
         FraudDetectorClient {
             client: Client::shared(),
@@ -2789,16 +3010,71 @@ impl FraudDetectorClient {
     pub fn new_with_client(client: Client, region: region::Region) -> FraudDetectorClient {
         FraudDetectorClient { client, region }
     }
+
+    /// Returns a builder of a client, setting any of its region, credentials provider,
+    /// dispatcher, retry policy, endpoint and timeouts.
+    pub fn builder() -> rusoto_core::ClientBuilder<FraudDetectorClient> {
+        rusoto_core::ClientBuilder::new(FraudDetectorClient::new_with_client)
+    }
+
+    /// Returns a clone of this client with the overrides of `config` applied to its
+    /// operations, sharing its connections.
+    pub fn customize(&self, config: &rusoto_core::OperationConfig) -> FraudDetectorClient {
+        FraudDetectorClient {
+            client: self.client.customize(config),
+            region: config.resolve_region(&self.region),
+        }
+    }
 }
 
-#[async_trait]
-impl FraudDetector for FraudDetectorClient {
-    /// <p>Creates a batch of variables.</p>
-    async fn batch_create_variable(
+impl FraudDetectorClient {
+    /// Calls `batch_create_variable`, also returning the metadata of the response.
+    pub async fn batch_create_variable_with_metadata(
         &self,
         input: BatchCreateVariableRequest,
-    ) -> Result<BatchCreateVariableResult, RusotoError<BatchCreateVariableError>> {
+    ) -> Result<(BatchCreateVariableResult, ResponseMetadata), RusotoError<BatchCreateVariableError>>
+    {
+        let request = self.build_batch_create_variable_request(input);
+
+        let mut response = self
+            .client
+            .sign_and_dispatch(request)
+            .await
+            .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
+        if response.status.is_success() {
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<BatchCreateVariableResult, _>()?;
+            Ok((result, metadata))
+        } else {
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(BatchCreateVariableError::from_response(response))
+        }
+    }
+
+    /// Presigns the request `batch_create_variable` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_batch_create_variable(
+        &self,
+        presigner: &rusoto_core::Presigner,
+        input: BatchCreateVariableRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_batch_create_variable_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_batch_create_variable_request(
+        &self,
+        input: BatchCreateVariableRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "frauddetector", &self.region, "/");
+        request.set_operation("BatchCreateVariable");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -2808,55 +3084,111 @@ impl FraudDetector for FraudDetectorClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `batch_get_variable`, also returning the metadata of the response.
+    pub async fn batch_get_variable_with_metadata(
+        &self,
+        input: BatchGetVariableRequest,
+    ) -> Result<(BatchGetVariableResult, ResponseMetadata), RusotoError<BatchGetVariableError>>
+    {
+        let request = self.build_batch_get_variable_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<BatchCreateVariableResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<BatchGetVariableResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(BatchCreateVariableError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(BatchGetVariableError::from_response(response))
         }
     }
 
-    /// <p>Gets a batch of variables.</p>
-    async fn batch_get_variable(
+    /// Presigns the request `batch_get_variable` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_batch_get_variable(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: BatchGetVariableRequest,
-    ) -> Result<BatchGetVariableResult, RusotoError<BatchGetVariableError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_batch_get_variable_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_batch_get_variable_request(&self, input: BatchGetVariableRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "frauddetector", &self.region, "/");
+        request.set_operation("BatchGetVariable");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSHawksNestServiceFacade.BatchGetVariable");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `create_detector_version`, also returning the metadata of the response.
+    pub async fn create_detector_version_with_metadata(
+        &self,
+        input: CreateDetectorVersionRequest,
+    ) -> Result<
+        (CreateDetectorVersionResult, ResponseMetadata),
+        RusotoError<CreateDetectorVersionError>,
+    > {
+        let request = self.build_create_detector_version_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<BatchGetVariableResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<CreateDetectorVersionResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(BatchGetVariableError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(CreateDetectorVersionError::from_response(response))
         }
     }
 
-    /// <p>Creates a detector version. The detector version starts in a <code>DRAFT</code> status.</p>
-    async fn create_detector_version(
+    /// Presigns the request `create_detector_version` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_detector_version(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: CreateDetectorVersionRequest,
-    ) -> Result<CreateDetectorVersionResult, RusotoError<CreateDetectorVersionError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_detector_version_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_detector_version_request(
+        &self,
+        input: CreateDetectorVersionRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "frauddetector", &self.region, "/");
+        request.set_operation("CreateDetectorVersion");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -2866,28 +3198,56 @@ impl FraudDetector for FraudDetectorClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `create_model_version`, also returning the metadata of the response.
+    pub async fn create_model_version_with_metadata(
+        &self,
+        input: CreateModelVersionRequest,
+    ) -> Result<(CreateModelVersionResult, ResponseMetadata), RusotoError<CreateModelVersionError>>
+    {
+        let request = self.build_create_model_version_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<CreateDetectorVersionResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<CreateModelVersionResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(CreateDetectorVersionError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(CreateModelVersionError::from_response(response))
         }
     }
 
-    /// <p>Creates a version of the model using the specified model type. </p>
-    async fn create_model_version(
+    /// Presigns the request `create_model_version` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_model_version(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: CreateModelVersionRequest,
-    ) -> Result<CreateModelVersionResult, RusotoError<CreateModelVersionError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_model_version_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_model_version_request(
+        &self,
+        input: CreateModelVersionRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "frauddetector", &self.region, "/");
+        request.set_operation("CreateModelVersion");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -2897,82 +3257,162 @@ impl FraudDetector for FraudDetectorClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `create_rule`, also returning the metadata of the response.
+    pub async fn create_rule_with_metadata(
+        &self,
+        input: CreateRuleRequest,
+    ) -> Result<(CreateRuleResult, ResponseMetadata), RusotoError<CreateRuleError>> {
+        let request = self.build_create_rule_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<CreateModelVersionResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<CreateRuleResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(CreateModelVersionError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(CreateRuleError::from_response(response))
         }
     }
 
-    /// <p>Creates a rule for use with the specified detector. </p>
-    async fn create_rule(
+    /// Presigns the request `create_rule` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_rule(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: CreateRuleRequest,
-    ) -> Result<CreateRuleResult, RusotoError<CreateRuleError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_rule_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_rule_request(&self, input: CreateRuleRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "frauddetector", &self.region, "/");
+        request.set_operation("CreateRule");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSHawksNestServiceFacade.CreateRule");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `create_variable`, also returning the metadata of the response.
+    pub async fn create_variable_with_metadata(
+        &self,
+        input: CreateVariableRequest,
+    ) -> Result<(CreateVariableResult, ResponseMetadata), RusotoError<CreateVariableError>> {
+        let request = self.build_create_variable_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<CreateRuleResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<CreateVariableResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(CreateRuleError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(CreateVariableError::from_response(response))
         }
     }
 
-    /// <p>Creates a variable.</p>
-    async fn create_variable(
+    /// Presigns the request `create_variable` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_variable(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: CreateVariableRequest,
-    ) -> Result<CreateVariableResult, RusotoError<CreateVariableError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_variable_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_variable_request(&self, input: CreateVariableRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "frauddetector", &self.region, "/");
+        request.set_operation("CreateVariable");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSHawksNestServiceFacade.CreateVariable");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `delete_detector_version`, also returning the metadata of the response.
+    pub async fn delete_detector_version_with_metadata(
+        &self,
+        input: DeleteDetectorVersionRequest,
+    ) -> Result<
+        (DeleteDetectorVersionResult, ResponseMetadata),
+        RusotoError<DeleteDetectorVersionError>,
+    > {
+        let request = self.build_delete_detector_version_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<CreateVariableResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DeleteDetectorVersionResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(CreateVariableError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DeleteDetectorVersionError::from_response(response))
         }
     }
 
-    /// <p>Deletes the detector version.</p>
-    async fn delete_detector_version(
+    /// Presigns the request `delete_detector_version` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_detector_version(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteDetectorVersionRequest,
-    ) -> Result<DeleteDetectorVersionResult, RusotoError<DeleteDetectorVersionError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_detector_version_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_detector_version_request(
+        &self,
+        input: DeleteDetectorVersionRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "frauddetector", &self.region, "/");
+        request.set_operation("DeleteDetectorVersion");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -2982,82 +3422,163 @@ impl FraudDetector for FraudDetectorClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `delete_event`, also returning the metadata of the response.
+    pub async fn delete_event_with_metadata(
+        &self,
+        input: DeleteEventRequest,
+    ) -> Result<(DeleteEventResult, ResponseMetadata), RusotoError<DeleteEventError>> {
+        let request = self.build_delete_event_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DeleteDetectorVersionResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DeleteEventResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteDetectorVersionError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DeleteEventError::from_response(response))
         }
     }
 
-    /// <p>Deletes the specified event.</p>
-    async fn delete_event(
+    /// Presigns the request `delete_event` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_event(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteEventRequest,
-    ) -> Result<DeleteEventResult, RusotoError<DeleteEventError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_event_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_event_request(&self, input: DeleteEventRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "frauddetector", &self.region, "/");
+        request.set_operation("DeleteEvent");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSHawksNestServiceFacade.DeleteEvent");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `describe_detector`, also returning the metadata of the response.
+    pub async fn describe_detector_with_metadata(
+        &self,
+        input: DescribeDetectorRequest,
+    ) -> Result<(DescribeDetectorResult, ResponseMetadata), RusotoError<DescribeDetectorError>>
+    {
+        let request = self.build_describe_detector_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<DeleteEventResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DescribeDetectorResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteEventError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DescribeDetectorError::from_response(response))
         }
     }
 
-    /// <p>Gets all versions for a specified detector.</p>
-    async fn describe_detector(
+    /// Presigns the request `describe_detector` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_detector(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeDetectorRequest,
-    ) -> Result<DescribeDetectorResult, RusotoError<DescribeDetectorError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_detector_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_detector_request(&self, input: DescribeDetectorRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "frauddetector", &self.region, "/");
+        request.set_operation("DescribeDetector");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSHawksNestServiceFacade.DescribeDetector");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `describe_model_versions`, also returning the metadata of the response.
+    pub async fn describe_model_versions_with_metadata(
+        &self,
+        input: DescribeModelVersionsRequest,
+    ) -> Result<
+        (DescribeModelVersionsResult, ResponseMetadata),
+        RusotoError<DescribeModelVersionsError>,
+    > {
+        let request = self.build_describe_model_versions_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<DescribeDetectorResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DescribeModelVersionsResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeDetectorError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DescribeModelVersionsError::from_response(response))
         }
     }
 
-    /// <p>Gets all of the model versions for the specified model type or for the specified model type and model ID. You can also get details for a single, specified model version. </p>
-    async fn describe_model_versions(
+    /// Presigns the request `describe_model_versions` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_model_versions(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeModelVersionsRequest,
-    ) -> Result<DescribeModelVersionsResult, RusotoError<DescribeModelVersionsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_model_versions_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_model_versions_request(
+        &self,
+        input: DescribeModelVersionsRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "frauddetector", &self.region, "/");
+        request.set_operation("DescribeModelVersions");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -3067,28 +3588,56 @@ impl FraudDetector for FraudDetectorClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `get_detector_version`, also returning the metadata of the response.
+    pub async fn get_detector_version_with_metadata(
+        &self,
+        input: GetDetectorVersionRequest,
+    ) -> Result<(GetDetectorVersionResult, ResponseMetadata), RusotoError<GetDetectorVersionError>>
+    {
+        let request = self.build_get_detector_version_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DescribeModelVersionsResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<GetDetectorVersionResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeModelVersionsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(GetDetectorVersionError::from_response(response))
         }
     }
 
-    /// <p>Gets a particular detector version. </p>
-    async fn get_detector_version(
+    /// Presigns the request `get_detector_version` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_detector_version(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetDetectorVersionRequest,
-    ) -> Result<GetDetectorVersionResult, RusotoError<GetDetectorVersionError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_detector_version_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_detector_version_request(
+        &self,
+        input: GetDetectorVersionRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "frauddetector", &self.region, "/");
+        request.set_operation("GetDetectorVersion");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -3098,55 +3647,105 @@ impl FraudDetector for FraudDetectorClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `get_detectors`, also returning the metadata of the response.
+    pub async fn get_detectors_with_metadata(
+        &self,
+        input: GetDetectorsRequest,
+    ) -> Result<(GetDetectorsResult, ResponseMetadata), RusotoError<GetDetectorsError>> {
+        let request = self.build_get_detectors_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<GetDetectorVersionResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<GetDetectorsResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(GetDetectorVersionError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(GetDetectorsError::from_response(response))
         }
     }
 
-    /// <p>Gets all of detectors. This is a paginated API. If you provide a null <code>maxSizePerPage</code>, this actions retrieves a maximum of 10 records per page. If you provide a <code>maxSizePerPage</code>, the value must be between 5 and 10. To get the next page results, provide the pagination token from the <code>GetEventTypesResponse</code> as part of your request. A null pagination token fetches the records from the beginning. </p>
-    async fn get_detectors(
+    /// Presigns the request `get_detectors` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_detectors(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetDetectorsRequest,
-    ) -> Result<GetDetectorsResult, RusotoError<GetDetectorsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_detectors_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_detectors_request(&self, input: GetDetectorsRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "frauddetector", &self.region, "/");
+        request.set_operation("GetDetectors");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSHawksNestServiceFacade.GetDetectors");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `get_external_models`, also returning the metadata of the response.
+    pub async fn get_external_models_with_metadata(
+        &self,
+        input: GetExternalModelsRequest,
+    ) -> Result<(GetExternalModelsResult, ResponseMetadata), RusotoError<GetExternalModelsError>>
+    {
+        let request = self.build_get_external_models_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<GetDetectorsResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<GetExternalModelsResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(GetDetectorsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(GetExternalModelsError::from_response(response))
         }
     }
 
-    /// <p>Gets the details for one or more Amazon SageMaker models that have been imported into the service. This is a paginated API. If you provide a null <code>maxSizePerPage</code>, this actions retrieves a maximum of 10 records per page. If you provide a <code>maxSizePerPage</code>, the value must be between 5 and 10. To get the next page results, provide the pagination token from the <code>GetExternalModelsResult</code> as part of your request. A null pagination token fetches the records from the beginning. </p>
-    async fn get_external_models(
+    /// Presigns the request `get_external_models` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_external_models(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetExternalModelsRequest,
-    ) -> Result<GetExternalModelsResult, RusotoError<GetExternalModelsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_external_models_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_external_models_request(&self, input: GetExternalModelsRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "frauddetector", &self.region, "/");
+        request.set_operation("GetExternalModels");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -3156,297 +3755,579 @@ impl FraudDetector for FraudDetectorClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `get_model_version`, also returning the metadata of the response.
+    pub async fn get_model_version_with_metadata(
+        &self,
+        input: GetModelVersionRequest,
+    ) -> Result<(GetModelVersionResult, ResponseMetadata), RusotoError<GetModelVersionError>> {
+        let request = self.build_get_model_version_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<GetExternalModelsResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<GetModelVersionResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(GetExternalModelsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(GetModelVersionError::from_response(response))
         }
     }
 
-    /// <p>Gets a model version. </p>
-    async fn get_model_version(
+    /// Presigns the request `get_model_version` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_model_version(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetModelVersionRequest,
-    ) -> Result<GetModelVersionResult, RusotoError<GetModelVersionError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_model_version_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_model_version_request(&self, input: GetModelVersionRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "frauddetector", &self.region, "/");
+        request.set_operation("GetModelVersion");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSHawksNestServiceFacade.GetModelVersion");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `get_models`, also returning the metadata of the response.
+    pub async fn get_models_with_metadata(
+        &self,
+        input: GetModelsRequest,
+    ) -> Result<(GetModelsResult, ResponseMetadata), RusotoError<GetModelsError>> {
+        let request = self.build_get_models_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<GetModelVersionResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result =
+                proto::json::ResponsePayload::new(&response).deserialize::<GetModelsResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(GetModelVersionError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(GetModelsError::from_response(response))
         }
     }
 
-    /// <p>Gets all of the models for the AWS account, or the specified model type, or gets a single model for the specified model type, model ID combination. </p>
-    async fn get_models(
+    /// Presigns the request `get_models` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_models(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetModelsRequest,
-    ) -> Result<GetModelsResult, RusotoError<GetModelsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_models_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_models_request(&self, input: GetModelsRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "frauddetector", &self.region, "/");
+        request.set_operation("GetModels");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSHawksNestServiceFacade.GetModels");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
-        let mut response = self
+        request
+    }
+
+    /// Calls `get_outcomes`, also returning the metadata of the response.
+    pub async fn get_outcomes_with_metadata(
+        &self,
+        input: GetOutcomesRequest,
+    ) -> Result<(GetOutcomesResult, ResponseMetadata), RusotoError<GetOutcomesError>> {
+        let request = self.build_get_outcomes_request(input);
+
+        let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<GetModelsResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<GetOutcomesResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(GetModelsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(GetOutcomesError::from_response(response))
         }
     }
 
-    /// <p>Gets one or more outcomes. This is a paginated API. If you provide a null <code>maxSizePerPage</code>, this actions retrieves a maximum of 10 records per page. If you provide a <code>maxSizePerPage</code>, the value must be between 50 and 100. To get the next page results, provide the pagination token from the <code>GetOutcomesResult</code> as part of your request. A null pagination token fetches the records from the beginning. </p>
-    async fn get_outcomes(
+    /// Presigns the request `get_outcomes` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_outcomes(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetOutcomesRequest,
-    ) -> Result<GetOutcomesResult, RusotoError<GetOutcomesError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_outcomes_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_outcomes_request(&self, input: GetOutcomesRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "frauddetector", &self.region, "/");
+        request.set_operation("GetOutcomes");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSHawksNestServiceFacade.GetOutcomes");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `get_prediction`, also returning the metadata of the response.
+    pub async fn get_prediction_with_metadata(
+        &self,
+        input: GetPredictionRequest,
+    ) -> Result<(GetPredictionResult, ResponseMetadata), RusotoError<GetPredictionError>> {
+        let request = self.build_get_prediction_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<GetOutcomesResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<GetPredictionResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(GetOutcomesError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(GetPredictionError::from_response(response))
         }
     }
 
-    /// <p>Evaluates an event against a detector version. If a version ID is not provided, the detector’s (<code>ACTIVE</code>) version is used. </p>
-    async fn get_prediction(
+    /// Presigns the request `get_prediction` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_prediction(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetPredictionRequest,
-    ) -> Result<GetPredictionResult, RusotoError<GetPredictionError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_prediction_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_prediction_request(&self, input: GetPredictionRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "frauddetector", &self.region, "/");
+        request.set_operation("GetPrediction");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSHawksNestServiceFacade.GetPrediction");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `get_rules`, also returning the metadata of the response.
+    pub async fn get_rules_with_metadata(
+        &self,
+        input: GetRulesRequest,
+    ) -> Result<(GetRulesResult, ResponseMetadata), RusotoError<GetRulesError>> {
+        let request = self.build_get_rules_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<GetPredictionResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result =
+                proto::json::ResponsePayload::new(&response).deserialize::<GetRulesResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(GetPredictionError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(GetRulesError::from_response(response))
         }
     }
 
-    /// <p>Gets all rules available for the specified detector.</p>
-    async fn get_rules(
+    /// Presigns the request `get_rules` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_rules(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetRulesRequest,
-    ) -> Result<GetRulesResult, RusotoError<GetRulesError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_rules_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_rules_request(&self, input: GetRulesRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "frauddetector", &self.region, "/");
+        request.set_operation("GetRules");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSHawksNestServiceFacade.GetRules");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `get_variables`, also returning the metadata of the response.
+    pub async fn get_variables_with_metadata(
+        &self,
+        input: GetVariablesRequest,
+    ) -> Result<(GetVariablesResult, ResponseMetadata), RusotoError<GetVariablesError>> {
+        let request = self.build_get_variables_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<GetRulesResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<GetVariablesResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(GetRulesError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(GetVariablesError::from_response(response))
         }
     }
 
-    /// <p>Gets all of the variables or the specific variable. This is a paginated API. Providing null <code>maxSizePerPage</code> results in retrieving maximum of 100 records per page. If you provide <code>maxSizePerPage</code> the value must be between 50 and 100. To get the next page result, a provide a pagination token from <code>GetVariablesResult</code> as part of your request. Null pagination token fetches the records from the beginning. </p>
-    async fn get_variables(
+    /// Presigns the request `get_variables` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_variables(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetVariablesRequest,
-    ) -> Result<GetVariablesResult, RusotoError<GetVariablesError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_variables_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_variables_request(&self, input: GetVariablesRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "frauddetector", &self.region, "/");
+        request.set_operation("GetVariables");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSHawksNestServiceFacade.GetVariables");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `put_detector`, also returning the metadata of the response.
+    pub async fn put_detector_with_metadata(
+        &self,
+        input: PutDetectorRequest,
+    ) -> Result<(PutDetectorResult, ResponseMetadata), RusotoError<PutDetectorError>> {
+        let request = self.build_put_detector_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<GetVariablesResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<PutDetectorResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(GetVariablesError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(PutDetectorError::from_response(response))
         }
     }
 
-    /// <p>Creates or updates a detector. </p>
-    async fn put_detector(
+    /// Presigns the request `put_detector` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_put_detector(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: PutDetectorRequest,
-    ) -> Result<PutDetectorResult, RusotoError<PutDetectorError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_put_detector_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_put_detector_request(&self, input: PutDetectorRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "frauddetector", &self.region, "/");
+        request.set_operation("PutDetector");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSHawksNestServiceFacade.PutDetector");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `put_external_model`, also returning the metadata of the response.
+    pub async fn put_external_model_with_metadata(
+        &self,
+        input: PutExternalModelRequest,
+    ) -> Result<(PutExternalModelResult, ResponseMetadata), RusotoError<PutExternalModelError>>
+    {
+        let request = self.build_put_external_model_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<PutDetectorResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<PutExternalModelResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(PutDetectorError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(PutExternalModelError::from_response(response))
         }
     }
 
-    /// <p>Creates or updates an Amazon SageMaker model endpoint. You can also use this action to update the configuration of the model endpoint, including the IAM role and/or the mapped variables. </p>
-    async fn put_external_model(
+    /// Presigns the request `put_external_model` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_put_external_model(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: PutExternalModelRequest,
-    ) -> Result<PutExternalModelResult, RusotoError<PutExternalModelError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_put_external_model_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_put_external_model_request(&self, input: PutExternalModelRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "frauddetector", &self.region, "/");
+        request.set_operation("PutExternalModel");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSHawksNestServiceFacade.PutExternalModel");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `put_model`, also returning the metadata of the response.
+    pub async fn put_model_with_metadata(
+        &self,
+        input: PutModelRequest,
+    ) -> Result<(PutModelResult, ResponseMetadata), RusotoError<PutModelError>> {
+        let request = self.build_put_model_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<PutExternalModelResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result =
+                proto::json::ResponsePayload::new(&response).deserialize::<PutModelResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(PutExternalModelError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(PutModelError::from_response(response))
         }
     }
 
-    /// <p>Creates or updates a model. </p>
-    async fn put_model(
+    /// Presigns the request `put_model` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_put_model(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: PutModelRequest,
-    ) -> Result<PutModelResult, RusotoError<PutModelError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_put_model_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_put_model_request(&self, input: PutModelRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "frauddetector", &self.region, "/");
+        request.set_operation("PutModel");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSHawksNestServiceFacade.PutModel");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `put_outcome`, also returning the metadata of the response.
+    pub async fn put_outcome_with_metadata(
+        &self,
+        input: PutOutcomeRequest,
+    ) -> Result<(PutOutcomeResult, ResponseMetadata), RusotoError<PutOutcomeError>> {
+        let request = self.build_put_outcome_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<PutModelResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<PutOutcomeResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(PutModelError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(PutOutcomeError::from_response(response))
         }
     }
 
-    /// <p>Creates or updates an outcome. </p>
-    async fn put_outcome(
+    /// Presigns the request `put_outcome` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_put_outcome(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: PutOutcomeRequest,
-    ) -> Result<PutOutcomeResult, RusotoError<PutOutcomeError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_put_outcome_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_put_outcome_request(&self, input: PutOutcomeRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "frauddetector", &self.region, "/");
+        request.set_operation("PutOutcome");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSHawksNestServiceFacade.PutOutcome");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `update_detector_version`, also returning the metadata of the response.
+    pub async fn update_detector_version_with_metadata(
+        &self,
+        input: UpdateDetectorVersionRequest,
+    ) -> Result<
+        (UpdateDetectorVersionResult, ResponseMetadata),
+        RusotoError<UpdateDetectorVersionError>,
+    > {
+        let request = self.build_update_detector_version_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<PutOutcomeResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<UpdateDetectorVersionResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(PutOutcomeError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(UpdateDetectorVersionError::from_response(response))
         }
     }
 
-    /// <p> Updates a detector version. The detector version attributes that you can update include models, external model endpoints, rules, and description. You can only update a <code>DRAFT</code> detector version.</p>
-    async fn update_detector_version(
+    /// Presigns the request `update_detector_version` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_detector_version(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UpdateDetectorVersionRequest,
-    ) -> Result<UpdateDetectorVersionResult, RusotoError<UpdateDetectorVersionError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_detector_version_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_detector_version_request(
+        &self,
+        input: UpdateDetectorVersionRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "frauddetector", &self.region, "/");
+        request.set_operation("UpdateDetectorVersion");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -3456,29 +4337,58 @@ impl FraudDetector for FraudDetectorClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `update_detector_version_metadata`, also returning the metadata of the response.
+    pub async fn update_detector_version_metadata_with_metadata(
+        &self,
+        input: UpdateDetectorVersionMetadataRequest,
+    ) -> Result<
+        (UpdateDetectorVersionMetadataResult, ResponseMetadata),
+        RusotoError<UpdateDetectorVersionMetadataError>,
+    > {
+        let request = self.build_update_detector_version_metadata_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<UpdateDetectorVersionResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<UpdateDetectorVersionMetadataResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(UpdateDetectorVersionError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(UpdateDetectorVersionMetadataError::from_response(response))
         }
     }
 
-    /// <p>Updates the detector version's description. You can update the metadata for any detector version (<code>DRAFT, ACTIVE,</code> or <code>INACTIVE</code>). </p>
-    async fn update_detector_version_metadata(
+    /// Presigns the request `update_detector_version_metadata` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_detector_version_metadata(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UpdateDetectorVersionMetadataRequest,
-    ) -> Result<UpdateDetectorVersionMetadataResult, RusotoError<UpdateDetectorVersionMetadataError>>
-    {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_detector_version_metadata_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_detector_version_metadata_request(
+        &self,
+        input: UpdateDetectorVersionMetadataRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "frauddetector", &self.region, "/");
+        request.set_operation("UpdateDetectorVersionMetadata");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -3488,29 +4398,58 @@ impl FraudDetector for FraudDetectorClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `update_detector_version_status`, also returning the metadata of the response.
+    pub async fn update_detector_version_status_with_metadata(
+        &self,
+        input: UpdateDetectorVersionStatusRequest,
+    ) -> Result<
+        (UpdateDetectorVersionStatusResult, ResponseMetadata),
+        RusotoError<UpdateDetectorVersionStatusError>,
+    > {
+        let request = self.build_update_detector_version_status_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<UpdateDetectorVersionMetadataResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<UpdateDetectorVersionStatusResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(UpdateDetectorVersionMetadataError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(UpdateDetectorVersionStatusError::from_response(response))
         }
     }
 
-    /// <p>Updates the detector version’s status. You can perform the following promotions or demotions using <code>UpdateDetectorVersionStatus</code>: <code>DRAFT</code> to <code>ACTIVE</code>, <code>ACTIVE</code> to <code>INACTIVE</code>, and <code>INACTIVE</code> to <code>ACTIVE</code>.</p>
-    async fn update_detector_version_status(
+    /// Presigns the request `update_detector_version_status` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_detector_version_status(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UpdateDetectorVersionStatusRequest,
-    ) -> Result<UpdateDetectorVersionStatusResult, RusotoError<UpdateDetectorVersionStatusError>>
-    {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_detector_version_status_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_detector_version_status_request(
+        &self,
+        input: UpdateDetectorVersionStatusRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "frauddetector", &self.region, "/");
+        request.set_operation("UpdateDetectorVersionStatus");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -3520,28 +4459,56 @@ impl FraudDetector for FraudDetectorClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `update_model_version`, also returning the metadata of the response.
+    pub async fn update_model_version_with_metadata(
+        &self,
+        input: UpdateModelVersionRequest,
+    ) -> Result<(UpdateModelVersionResult, ResponseMetadata), RusotoError<UpdateModelVersionError>>
+    {
+        let request = self.build_update_model_version_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<UpdateDetectorVersionStatusResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<UpdateModelVersionResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(UpdateDetectorVersionStatusError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(UpdateModelVersionError::from_response(response))
         }
     }
 
-    /// <p><p>Updates a model version. You can update the description and status attributes using this action. You can perform the following status updates: </p> <ol> <li> <p>Change the <code>TRAINING<em>COMPLETE</code> status to <code>ACTIVE</code> </p> </li> <li> <p>Change <code>ACTIVE</code> back to <code>TRAINING</em>COMPLETE</code> </p> </li> </ol></p>
-    async fn update_model_version(
+    /// Presigns the request `update_model_version` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_model_version(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UpdateModelVersionRequest,
-    ) -> Result<UpdateModelVersionResult, RusotoError<UpdateModelVersionError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_model_version_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_model_version_request(
+        &self,
+        input: UpdateModelVersionRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "frauddetector", &self.region, "/");
+        request.set_operation("UpdateModelVersion");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -3551,28 +4518,56 @@ impl FraudDetector for FraudDetectorClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `update_rule_metadata`, also returning the metadata of the response.
+    pub async fn update_rule_metadata_with_metadata(
+        &self,
+        input: UpdateRuleMetadataRequest,
+    ) -> Result<(UpdateRuleMetadataResult, ResponseMetadata), RusotoError<UpdateRuleMetadataError>>
+    {
+        let request = self.build_update_rule_metadata_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<UpdateModelVersionResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<UpdateRuleMetadataResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(UpdateModelVersionError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(UpdateRuleMetadataError::from_response(response))
         }
     }
 
-    /// <p>Updates a rule's metadata. </p>
-    async fn update_rule_metadata(
+    /// Presigns the request `update_rule_metadata` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_rule_metadata(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UpdateRuleMetadataRequest,
-    ) -> Result<UpdateRuleMetadataResult, RusotoError<UpdateRuleMetadataError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_rule_metadata_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_rule_metadata_request(
+        &self,
+        input: UpdateRuleMetadataRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "frauddetector", &self.region, "/");
+        request.set_operation("UpdateRuleMetadata");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -3582,28 +4577,53 @@ impl FraudDetector for FraudDetectorClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `update_rule_version`, also returning the metadata of the response.
+    pub async fn update_rule_version_with_metadata(
+        &self,
+        input: UpdateRuleVersionRequest,
+    ) -> Result<(UpdateRuleVersionResult, ResponseMetadata), RusotoError<UpdateRuleVersionError>>
+    {
+        let request = self.build_update_rule_version_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<UpdateRuleMetadataResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<UpdateRuleVersionResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(UpdateRuleMetadataError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(UpdateRuleVersionError::from_response(response))
         }
     }
 
-    /// <p>Updates a rule version resulting in a new rule version. </p>
-    async fn update_rule_version(
+    /// Presigns the request `update_rule_version` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_rule_version(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UpdateRuleVersionRequest,
-    ) -> Result<UpdateRuleVersionResult, RusotoError<UpdateRuleVersionError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_rule_version_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_rule_version_request(&self, input: UpdateRuleVersionRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "frauddetector", &self.region, "/");
+        request.set_operation("UpdateRuleVersion");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -3613,45 +4633,363 @@ impl FraudDetector for FraudDetectorClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `update_variable`, also returning the metadata of the response.
+    pub async fn update_variable_with_metadata(
+        &self,
+        input: UpdateVariableRequest,
+    ) -> Result<(UpdateVariableResult, ResponseMetadata), RusotoError<UpdateVariableError>> {
+        let request = self.build_update_variable_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<UpdateRuleVersionResult, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<UpdateVariableResult, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(UpdateRuleVersionError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(UpdateVariableError::from_response(response))
         }
     }
 
-    /// <p>Updates a variable.</p>
-    async fn update_variable(
+    /// Presigns the request `update_variable` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_variable(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UpdateVariableRequest,
-    ) -> Result<UpdateVariableResult, RusotoError<UpdateVariableError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_variable_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_variable_request(&self, input: UpdateVariableRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "frauddetector", &self.region, "/");
+        request.set_operation("UpdateVariable");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSHawksNestServiceFacade.UpdateVariable");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
-        let mut response = self
-            .client
-            .sign_and_dispatch(request)
+        request
+    }
+}
+
+#[async_trait]
+impl FraudDetector for FraudDetectorClient {
+    /// <p>Creates a batch of variables.</p>
+    async fn batch_create_variable(
+        &self,
+        input: BatchCreateVariableRequest,
+    ) -> Result<BatchCreateVariableResult, RusotoError<BatchCreateVariableError>> {
+        self.batch_create_variable_with_metadata(input)
             .await
-            .map_err(RusotoError::from)?;
-        if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<UpdateVariableResult, _>()
-        } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(UpdateVariableError::from_response(response))
-        }
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Gets a batch of variables.</p>
+    async fn batch_get_variable(
+        &self,
+        input: BatchGetVariableRequest,
+    ) -> Result<BatchGetVariableResult, RusotoError<BatchGetVariableError>> {
+        self.batch_get_variable_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Creates a detector version. The detector version starts in a <code>DRAFT</code> status.</p>
+    async fn create_detector_version(
+        &self,
+        input: CreateDetectorVersionRequest,
+    ) -> Result<CreateDetectorVersionResult, RusotoError<CreateDetectorVersionError>> {
+        self.create_detector_version_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Creates a version of the model using the specified model type. </p>
+    async fn create_model_version(
+        &self,
+        input: CreateModelVersionRequest,
+    ) -> Result<CreateModelVersionResult, RusotoError<CreateModelVersionError>> {
+        self.create_model_version_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Creates a rule for use with the specified detector. </p>
+    async fn create_rule(
+        &self,
+        input: CreateRuleRequest,
+    ) -> Result<CreateRuleResult, RusotoError<CreateRuleError>> {
+        self.create_rule_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Creates a variable.</p>
+    async fn create_variable(
+        &self,
+        input: CreateVariableRequest,
+    ) -> Result<CreateVariableResult, RusotoError<CreateVariableError>> {
+        self.create_variable_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes the detector version.</p>
+    async fn delete_detector_version(
+        &self,
+        input: DeleteDetectorVersionRequest,
+    ) -> Result<DeleteDetectorVersionResult, RusotoError<DeleteDetectorVersionError>> {
+        self.delete_detector_version_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Deletes the specified event.</p>
+    async fn delete_event(
+        &self,
+        input: DeleteEventRequest,
+    ) -> Result<DeleteEventResult, RusotoError<DeleteEventError>> {
+        self.delete_event_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Gets all versions for a specified detector.</p>
+    async fn describe_detector(
+        &self,
+        input: DescribeDetectorRequest,
+    ) -> Result<DescribeDetectorResult, RusotoError<DescribeDetectorError>> {
+        self.describe_detector_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Gets all of the model versions for the specified model type or for the specified model type and model ID. You can also get details for a single, specified model version. </p>
+    async fn describe_model_versions(
+        &self,
+        input: DescribeModelVersionsRequest,
+    ) -> Result<DescribeModelVersionsResult, RusotoError<DescribeModelVersionsError>> {
+        self.describe_model_versions_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Gets a particular detector version. </p>
+    async fn get_detector_version(
+        &self,
+        input: GetDetectorVersionRequest,
+    ) -> Result<GetDetectorVersionResult, RusotoError<GetDetectorVersionError>> {
+        self.get_detector_version_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Gets all of detectors. This is a paginated API. If you provide a null <code>maxSizePerPage</code>, this actions retrieves a maximum of 10 records per page. If you provide a <code>maxSizePerPage</code>, the value must be between 5 and 10. To get the next page results, provide the pagination token from the <code>GetEventTypesResponse</code> as part of your request. A null pagination token fetches the records from the beginning. </p>
+    async fn get_detectors(
+        &self,
+        input: GetDetectorsRequest,
+    ) -> Result<GetDetectorsResult, RusotoError<GetDetectorsError>> {
+        self.get_detectors_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Gets the details for one or more Amazon SageMaker models that have been imported into the service. This is a paginated API. If you provide a null <code>maxSizePerPage</code>, this actions retrieves a maximum of 10 records per page. If you provide a <code>maxSizePerPage</code>, the value must be between 5 and 10. To get the next page results, provide the pagination token from the <code>GetExternalModelsResult</code> as part of your request. A null pagination token fetches the records from the beginning. </p>
+    async fn get_external_models(
+        &self,
+        input: GetExternalModelsRequest,
+    ) -> Result<GetExternalModelsResult, RusotoError<GetExternalModelsError>> {
+        self.get_external_models_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Gets a model version. </p>
+    async fn get_model_version(
+        &self,
+        input: GetModelVersionRequest,
+    ) -> Result<GetModelVersionResult, RusotoError<GetModelVersionError>> {
+        self.get_model_version_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Gets all of the models for the AWS account, or the specified model type, or gets a single model for the specified model type, model ID combination. </p>
+    async fn get_models(
+        &self,
+        input: GetModelsRequest,
+    ) -> Result<GetModelsResult, RusotoError<GetModelsError>> {
+        self.get_models_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Gets one or more outcomes. This is a paginated API. If you provide a null <code>maxSizePerPage</code>, this actions retrieves a maximum of 10 records per page. If you provide a <code>maxSizePerPage</code>, the value must be between 50 and 100. To get the next page results, provide the pagination token from the <code>GetOutcomesResult</code> as part of your request. A null pagination token fetches the records from the beginning. </p>
+    async fn get_outcomes(
+        &self,
+        input: GetOutcomesRequest,
+    ) -> Result<GetOutcomesResult, RusotoError<GetOutcomesError>> {
+        self.get_outcomes_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Evaluates an event against a detector version. If a version ID is not provided, the detector’s (<code>ACTIVE</code>) version is used. </p>
+    async fn get_prediction(
+        &self,
+        input: GetPredictionRequest,
+    ) -> Result<GetPredictionResult, RusotoError<GetPredictionError>> {
+        self.get_prediction_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Gets all rules available for the specified detector.</p>
+    async fn get_rules(
+        &self,
+        input: GetRulesRequest,
+    ) -> Result<GetRulesResult, RusotoError<GetRulesError>> {
+        self.get_rules_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Gets all of the variables or the specific variable. This is a paginated API. Providing null <code>maxSizePerPage</code> results in retrieving maximum of 100 records per page. If you provide <code>maxSizePerPage</code> the value must be between 50 and 100. To get the next page result, a provide a pagination token from <code>GetVariablesResult</code> as part of your request. Null pagination token fetches the records from the beginning. </p>
+    async fn get_variables(
+        &self,
+        input: GetVariablesRequest,
+    ) -> Result<GetVariablesResult, RusotoError<GetVariablesError>> {
+        self.get_variables_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Creates or updates a detector. </p>
+    async fn put_detector(
+        &self,
+        input: PutDetectorRequest,
+    ) -> Result<PutDetectorResult, RusotoError<PutDetectorError>> {
+        self.put_detector_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Creates or updates an Amazon SageMaker model endpoint. You can also use this action to update the configuration of the model endpoint, including the IAM role and/or the mapped variables. </p>
+    async fn put_external_model(
+        &self,
+        input: PutExternalModelRequest,
+    ) -> Result<PutExternalModelResult, RusotoError<PutExternalModelError>> {
+        self.put_external_model_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Creates or updates a model. </p>
+    async fn put_model(
+        &self,
+        input: PutModelRequest,
+    ) -> Result<PutModelResult, RusotoError<PutModelError>> {
+        self.put_model_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Creates or updates an outcome. </p>
+    async fn put_outcome(
+        &self,
+        input: PutOutcomeRequest,
+    ) -> Result<PutOutcomeResult, RusotoError<PutOutcomeError>> {
+        self.put_outcome_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p> Updates a detector version. The detector version attributes that you can update include models, external model endpoints, rules, and description. You can only update a <code>DRAFT</code> detector version.</p>
+    async fn update_detector_version(
+        &self,
+        input: UpdateDetectorVersionRequest,
+    ) -> Result<UpdateDetectorVersionResult, RusotoError<UpdateDetectorVersionError>> {
+        self.update_detector_version_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Updates the detector version's description. You can update the metadata for any detector version (<code>DRAFT, ACTIVE,</code> or <code>INACTIVE</code>). </p>
+    async fn update_detector_version_metadata(
+        &self,
+        input: UpdateDetectorVersionMetadataRequest,
+    ) -> Result<UpdateDetectorVersionMetadataResult, RusotoError<UpdateDetectorVersionMetadataError>>
+    {
+        self.update_detector_version_metadata_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Updates the detector version’s status. You can perform the following promotions or demotions using <code>UpdateDetectorVersionStatus</code>: <code>DRAFT</code> to <code>ACTIVE</code>, <code>ACTIVE</code> to <code>INACTIVE</code>, and <code>INACTIVE</code> to <code>ACTIVE</code>.</p>
+    async fn update_detector_version_status(
+        &self,
+        input: UpdateDetectorVersionStatusRequest,
+    ) -> Result<UpdateDetectorVersionStatusResult, RusotoError<UpdateDetectorVersionStatusError>>
+    {
+        self.update_detector_version_status_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Updates a model version. You can update the description and status attributes using this action. You can perform the following status updates: </p> <ol> <li> <p>Change the <code>TRAINING<em>COMPLETE</code> status to <code>ACTIVE</code> </p> </li> <li> <p>Change <code>ACTIVE</code> back to <code>TRAINING</em>COMPLETE</code> </p> </li> </ol></p>
+    async fn update_model_version(
+        &self,
+        input: UpdateModelVersionRequest,
+    ) -> Result<UpdateModelVersionResult, RusotoError<UpdateModelVersionError>> {
+        self.update_model_version_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Updates a rule's metadata. </p>
+    async fn update_rule_metadata(
+        &self,
+        input: UpdateRuleMetadataRequest,
+    ) -> Result<UpdateRuleMetadataResult, RusotoError<UpdateRuleMetadataError>> {
+        self.update_rule_metadata_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Updates a rule version resulting in a new rule version. </p>
+    async fn update_rule_version(
+        &self,
+        input: UpdateRuleVersionRequest,
+    ) -> Result<UpdateRuleVersionResult, RusotoError<UpdateRuleVersionError>> {
+        self.update_rule_version_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Updates a variable.</p>
+    async fn update_variable(
+        &self,
+        input: UpdateVariableRequest,
+    ) -> Result<UpdateVariableResult, RusotoError<UpdateVariableError>> {
+        self.update_variable_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
     }
 }
@@ -16,8 +16,8 @@ use std::fmt;
 use async_trait::async_trait;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
-use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest, ResponseMetadata};
+use rusoto_core::{Client, ErrorMetadata, RusotoError};
 
 use rusoto_core::proto;
 use rusoto_core::signature::SignedRequest;
@@ -1008,6 +1008,7 @@ pub struct WindowsFileSystemConfiguration {
 
 /// Errors returned by CancelDataRepositoryTask
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CancelDataRepositoryTaskError {
     /// <p>A generic error indicating a failure with a client request.</p>
     BadRequest(String),
@@ -1019,6 +1020,8 @@ pub enum CancelDataRepositoryTaskError {
     InternalServerError(String),
     /// <p>The requested operation is not supported for this resource or API.</p>
     UnsupportedOperation(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CancelDataRepositoryTaskError {
@@ -1026,30 +1029,37 @@ impl CancelDataRepositoryTaskError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "BadRequest" => {
-                    return RusotoError::Service(CancelDataRepositoryTaskError::BadRequest(err.msg))
+                    return RusotoError::service(
+                        CancelDataRepositoryTaskError::BadRequest(err.msg),
+                        &res,
+                    )
                 }
                 "DataRepositoryTaskEnded" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         CancelDataRepositoryTaskError::DataRepositoryTaskEnded(err.msg),
+                        &res,
                     )
                 }
                 "DataRepositoryTaskNotFound" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         CancelDataRepositoryTaskError::DataRepositoryTaskNotFound(err.msg),
+                        &res,
                     )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         CancelDataRepositoryTaskError::InternalServerError(err.msg),
+                        &res,
                     )
                 }
                 "UnsupportedOperation" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         CancelDataRepositoryTaskError::UnsupportedOperation(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CancelDataRepositoryTaskError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1070,12 +1080,14 @@ impl fmt::Display for CancelDataRepositoryTaskError {
             CancelDataRepositoryTaskError::UnsupportedOperation(ref cause) => {
                 write!(f, "{}", cause)
             }
+            CancelDataRepositoryTaskError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CancelDataRepositoryTaskError {}
 /// Errors returned by CreateBackup
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateBackupError {
     /// <p>Another backup is already under way. Wait for completion before initiating additional backups of this file system.</p>
     BackupInProgress(String),
@@ -1091,6 +1103,8 @@ pub enum CreateBackupError {
     ServiceLimitExceeded(String),
     /// <p>The requested operation is not supported for this resource or API.</p>
     UnsupportedOperation(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateBackupError {
@@ -1098,30 +1112,43 @@ impl CreateBackupError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "BackupInProgress" => {
-                    return RusotoError::Service(CreateBackupError::BackupInProgress(err.msg))
+                    return RusotoError::service(CreateBackupError::BackupInProgress(err.msg), &res)
                 }
                 "BadRequest" => {
-                    return RusotoError::Service(CreateBackupError::BadRequest(err.msg))
+                    return RusotoError::service(CreateBackupError::BadRequest(err.msg), &res)
                 }
                 "FileSystemNotFound" => {
-                    return RusotoError::Service(CreateBackupError::FileSystemNotFound(err.msg))
+                    return RusotoError::service(
+                        CreateBackupError::FileSystemNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "IncompatibleParameterError" => {
-                    return RusotoError::Service(CreateBackupError::IncompatibleParameterError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateBackupError::IncompatibleParameterError(err.msg),
+                        &res,
+                    )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(CreateBackupError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        CreateBackupError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceLimitExceeded" => {
-                    return RusotoError::Service(CreateBackupError::ServiceLimitExceeded(err.msg))
+                    return RusotoError::service(
+                        CreateBackupError::ServiceLimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "UnsupportedOperation" => {
-                    return RusotoError::Service(CreateBackupError::UnsupportedOperation(err.msg))
+                    return RusotoError::service(
+                        CreateBackupError::UnsupportedOperation(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreateBackupError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1138,12 +1165,14 @@ impl fmt::Display for CreateBackupError {
             CreateBackupError::InternalServerError(ref cause) => write!(f, "{}", cause),
             CreateBackupError::ServiceLimitExceeded(ref cause) => write!(f, "{}", cause),
             CreateBackupError::UnsupportedOperation(ref cause) => write!(f, "{}", cause),
+            CreateBackupError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateBackupError {}
 /// Errors returned by CreateDataRepositoryTask
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateDataRepositoryTaskError {
     /// <p>A generic error indicating a failure with a client request.</p>
     BadRequest(String),
@@ -1159,6 +1188,8 @@ pub enum CreateDataRepositoryTaskError {
     ServiceLimitExceeded(String),
     /// <p>The requested operation is not supported for this resource or API.</p>
     UnsupportedOperation(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateDataRepositoryTaskError {
@@ -1166,40 +1197,49 @@ impl CreateDataRepositoryTaskError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "BadRequest" => {
-                    return RusotoError::Service(CreateDataRepositoryTaskError::BadRequest(err.msg))
+                    return RusotoError::service(
+                        CreateDataRepositoryTaskError::BadRequest(err.msg),
+                        &res,
+                    )
                 }
                 "DataRepositoryTaskExecuting" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         CreateDataRepositoryTaskError::DataRepositoryTaskExecuting(err.msg),
+                        &res,
                     )
                 }
                 "FileSystemNotFound" => {
-                    return RusotoError::Service(CreateDataRepositoryTaskError::FileSystemNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateDataRepositoryTaskError::FileSystemNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "IncompatibleParameterError" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         CreateDataRepositoryTaskError::IncompatibleParameterError(err.msg),
+                        &res,
                     )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         CreateDataRepositoryTaskError::InternalServerError(err.msg),
+                        &res,
                     )
                 }
                 "ServiceLimitExceeded" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         CreateDataRepositoryTaskError::ServiceLimitExceeded(err.msg),
+                        &res,
                     )
                 }
                 "UnsupportedOperation" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         CreateDataRepositoryTaskError::UnsupportedOperation(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreateDataRepositoryTaskError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1224,12 +1264,14 @@ impl fmt::Display for CreateDataRepositoryTaskError {
             CreateDataRepositoryTaskError::UnsupportedOperation(ref cause) => {
                 write!(f, "{}", cause)
             }
+            CreateDataRepositoryTaskError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateDataRepositoryTaskError {}
 /// Errors returned by CreateFileSystem
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateFileSystemError {
     /// <p>An Active Directory error.</p>
     ActiveDirectoryError(String),
@@ -1249,6 +1291,8 @@ pub enum CreateFileSystemError {
     MissingFileSystemConfiguration(String),
     /// <p>An error indicating that a particular service limit was exceeded. You can increase some service limits by contacting AWS Support. </p>
     ServiceLimitExceeded(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateFileSystemError {
@@ -1256,46 +1300,58 @@ impl CreateFileSystemError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ActiveDirectoryError" => {
-                    return RusotoError::Service(CreateFileSystemError::ActiveDirectoryError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateFileSystemError::ActiveDirectoryError(err.msg),
+                        &res,
+                    )
                 }
                 "BadRequest" => {
-                    return RusotoError::Service(CreateFileSystemError::BadRequest(err.msg))
+                    return RusotoError::service(CreateFileSystemError::BadRequest(err.msg), &res)
                 }
                 "IncompatibleParameterError" => {
-                    return RusotoError::Service(CreateFileSystemError::IncompatibleParameterError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateFileSystemError::IncompatibleParameterError(err.msg),
+                        &res,
+                    )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(CreateFileSystemError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateFileSystemError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidExportPath" => {
-                    return RusotoError::Service(CreateFileSystemError::InvalidExportPath(err.msg))
+                    return RusotoError::service(
+                        CreateFileSystemError::InvalidExportPath(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidImportPath" => {
-                    return RusotoError::Service(CreateFileSystemError::InvalidImportPath(err.msg))
+                    return RusotoError::service(
+                        CreateFileSystemError::InvalidImportPath(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidNetworkSettings" => {
-                    return RusotoError::Service(CreateFileSystemError::InvalidNetworkSettings(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateFileSystemError::InvalidNetworkSettings(err.msg),
+                        &res,
+                    )
                 }
                 "MissingFileSystemConfiguration" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         CreateFileSystemError::MissingFileSystemConfiguration(err.msg),
+                        &res,
                     )
                 }
                 "ServiceLimitExceeded" => {
-                    return RusotoError::Service(CreateFileSystemError::ServiceLimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateFileSystemError::ServiceLimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreateFileSystemError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1316,12 +1372,14 @@ impl fmt::Display for CreateFileSystemError {
                 write!(f, "{}", cause)
             }
             CreateFileSystemError::ServiceLimitExceeded(ref cause) => write!(f, "{}", cause),
+            CreateFileSystemError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateFileSystemError {}
 /// Errors returned by CreateFileSystemFromBackup
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateFileSystemFromBackupError {
     /// <p>An Active Directory error.</p>
     ActiveDirectoryError(String),
@@ -1339,6 +1397,8 @@ pub enum CreateFileSystemFromBackupError {
     MissingFileSystemConfiguration(String),
     /// <p>An error indicating that a particular service limit was exceeded. You can increase some service limits by contacting AWS Support. </p>
     ServiceLimitExceeded(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateFileSystemFromBackupError {
@@ -1348,47 +1408,57 @@ impl CreateFileSystemFromBackupError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ActiveDirectoryError" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         CreateFileSystemFromBackupError::ActiveDirectoryError(err.msg),
+                        &res,
                     )
                 }
                 "BackupNotFound" => {
-                    return RusotoError::Service(CreateFileSystemFromBackupError::BackupNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateFileSystemFromBackupError::BackupNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "BadRequest" => {
-                    return RusotoError::Service(CreateFileSystemFromBackupError::BadRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateFileSystemFromBackupError::BadRequest(err.msg),
+                        &res,
+                    )
                 }
                 "IncompatibleParameterError" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         CreateFileSystemFromBackupError::IncompatibleParameterError(err.msg),
+                        &res,
                     )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         CreateFileSystemFromBackupError::InternalServerError(err.msg),
+                        &res,
                     )
                 }
                 "InvalidNetworkSettings" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         CreateFileSystemFromBackupError::InvalidNetworkSettings(err.msg),
+                        &res,
                     )
                 }
                 "MissingFileSystemConfiguration" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         CreateFileSystemFromBackupError::MissingFileSystemConfiguration(err.msg),
+                        &res,
                     )
                 }
                 "ServiceLimitExceeded" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         CreateFileSystemFromBackupError::ServiceLimitExceeded(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(CreateFileSystemFromBackupError::Unhandled, &res)
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -1418,12 +1488,14 @@ impl fmt::Display for CreateFileSystemFromBackupError {
             CreateFileSystemFromBackupError::ServiceLimitExceeded(ref cause) => {
                 write!(f, "{}", cause)
             }
+            CreateFileSystemFromBackupError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateFileSystemFromBackupError {}
 /// Errors returned by DeleteBackup
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteBackupError {
     /// <p>Another backup is already under way. Wait for completion before initiating additional backups of this file system.</p>
     BackupInProgress(String),
@@ -1437,6 +1509,8 @@ pub enum DeleteBackupError {
     IncompatibleParameterError(String),
     /// <p>A generic error indicating a server-side failure.</p>
     InternalServerError(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteBackupError {
@@ -1444,27 +1518,31 @@ impl DeleteBackupError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "BackupInProgress" => {
-                    return RusotoError::Service(DeleteBackupError::BackupInProgress(err.msg))
+                    return RusotoError::service(DeleteBackupError::BackupInProgress(err.msg), &res)
                 }
                 "BackupNotFound" => {
-                    return RusotoError::Service(DeleteBackupError::BackupNotFound(err.msg))
+                    return RusotoError::service(DeleteBackupError::BackupNotFound(err.msg), &res)
                 }
                 "BackupRestoring" => {
-                    return RusotoError::Service(DeleteBackupError::BackupRestoring(err.msg))
+                    return RusotoError::service(DeleteBackupError::BackupRestoring(err.msg), &res)
                 }
                 "BadRequest" => {
-                    return RusotoError::Service(DeleteBackupError::BadRequest(err.msg))
+                    return RusotoError::service(DeleteBackupError::BadRequest(err.msg), &res)
                 }
                 "IncompatibleParameterError" => {
-                    return RusotoError::Service(DeleteBackupError::IncompatibleParameterError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteBackupError::IncompatibleParameterError(err.msg),
+                        &res,
+                    )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(DeleteBackupError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        DeleteBackupError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteBackupError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1480,12 +1558,14 @@ impl fmt::Display for DeleteBackupError {
             DeleteBackupError::BadRequest(ref cause) => write!(f, "{}", cause),
             DeleteBackupError::IncompatibleParameterError(ref cause) => write!(f, "{}", cause),
             DeleteBackupError::InternalServerError(ref cause) => write!(f, "{}", cause),
+            DeleteBackupError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteBackupError {}
 /// Errors returned by DeleteFileSystem
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteFileSystemError {
     /// <p>A generic error indicating a failure with a client request.</p>
     BadRequest(String),
@@ -1497,6 +1577,8 @@ pub enum DeleteFileSystemError {
     InternalServerError(String),
     /// <p>An error indicating that a particular service limit was exceeded. You can increase some service limits by contacting AWS Support. </p>
     ServiceLimitExceeded(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteFileSystemError {
@@ -1504,28 +1586,34 @@ impl DeleteFileSystemError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "BadRequest" => {
-                    return RusotoError::Service(DeleteFileSystemError::BadRequest(err.msg))
+                    return RusotoError::service(DeleteFileSystemError::BadRequest(err.msg), &res)
                 }
                 "FileSystemNotFound" => {
-                    return RusotoError::Service(DeleteFileSystemError::FileSystemNotFound(err.msg))
+                    return RusotoError::service(
+                        DeleteFileSystemError::FileSystemNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "IncompatibleParameterError" => {
-                    return RusotoError::Service(DeleteFileSystemError::IncompatibleParameterError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteFileSystemError::IncompatibleParameterError(err.msg),
+                        &res,
+                    )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(DeleteFileSystemError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteFileSystemError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "ServiceLimitExceeded" => {
-                    return RusotoError::Service(DeleteFileSystemError::ServiceLimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteFileSystemError::ServiceLimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteFileSystemError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1540,12 +1628,14 @@ impl fmt::Display for DeleteFileSystemError {
             DeleteFileSystemError::IncompatibleParameterError(ref cause) => write!(f, "{}", cause),
             DeleteFileSystemError::InternalServerError(ref cause) => write!(f, "{}", cause),
             DeleteFileSystemError::ServiceLimitExceeded(ref cause) => write!(f, "{}", cause),
+            DeleteFileSystemError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteFileSystemError {}
 /// Errors returned by DescribeBackups
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeBackupsError {
     /// <p>No Amazon FSx backups were found based upon the supplied parameters.</p>
     BackupNotFound(String),
@@ -1555,6 +1645,8 @@ pub enum DescribeBackupsError {
     FileSystemNotFound(String),
     /// <p>A generic error indicating a server-side failure.</p>
     InternalServerError(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeBackupsError {
@@ -1562,19 +1654,28 @@ impl DescribeBackupsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "BackupNotFound" => {
-                    return RusotoError::Service(DescribeBackupsError::BackupNotFound(err.msg))
+                    return RusotoError::service(
+                        DescribeBackupsError::BackupNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "BadRequest" => {
-                    return RusotoError::Service(DescribeBackupsError::BadRequest(err.msg))
+                    return RusotoError::service(DescribeBackupsError::BadRequest(err.msg), &res)
                 }
                 "FileSystemNotFound" => {
-                    return RusotoError::Service(DescribeBackupsError::FileSystemNotFound(err.msg))
+                    return RusotoError::service(
+                        DescribeBackupsError::FileSystemNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(DescribeBackupsError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        DescribeBackupsError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DescribeBackupsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1588,12 +1689,14 @@ impl fmt::Display for DescribeBackupsError {
             DescribeBackupsError::BadRequest(ref cause) => write!(f, "{}", cause),
             DescribeBackupsError::FileSystemNotFound(ref cause) => write!(f, "{}", cause),
             DescribeBackupsError::InternalServerError(ref cause) => write!(f, "{}", cause),
+            DescribeBackupsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeBackupsError {}
 /// Errors returned by DescribeDataRepositoryTasks
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeDataRepositoryTasksError {
     /// <p>A generic error indicating a failure with a client request.</p>
     BadRequest(String),
@@ -1603,6 +1706,8 @@ pub enum DescribeDataRepositoryTasksError {
     FileSystemNotFound(String),
     /// <p>A generic error indicating a server-side failure.</p>
     InternalServerError(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeDataRepositoryTasksError {
@@ -1612,27 +1717,36 @@ impl DescribeDataRepositoryTasksError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "BadRequest" => {
-                    return RusotoError::Service(DescribeDataRepositoryTasksError::BadRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeDataRepositoryTasksError::BadRequest(err.msg),
+                        &res,
+                    )
                 }
                 "DataRepositoryTaskNotFound" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DescribeDataRepositoryTasksError::DataRepositoryTaskNotFound(err.msg),
+                        &res,
                     )
                 }
                 "FileSystemNotFound" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DescribeDataRepositoryTasksError::FileSystemNotFound(err.msg),
+                        &res,
                     )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DescribeDataRepositoryTasksError::InternalServerError(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        DescribeDataRepositoryTasksError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -1652,12 +1766,14 @@ impl fmt::Display for DescribeDataRepositoryTasksError {
             DescribeDataRepositoryTasksError::InternalServerError(ref cause) => {
                 write!(f, "{}", cause)
             }
+            DescribeDataRepositoryTasksError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeDataRepositoryTasksError {}
 /// Errors returned by DescribeFileSystems
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeFileSystemsError {
     /// <p>A generic error indicating a failure with a client request.</p>
     BadRequest(String),
@@ -1665,6 +1781,8 @@ pub enum DescribeFileSystemsError {
     FileSystemNotFound(String),
     /// <p>A generic error indicating a server-side failure.</p>
     InternalServerError(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeFileSystemsError {
@@ -1672,20 +1790,25 @@ impl DescribeFileSystemsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "BadRequest" => {
-                    return RusotoError::Service(DescribeFileSystemsError::BadRequest(err.msg))
+                    return RusotoError::service(
+                        DescribeFileSystemsError::BadRequest(err.msg),
+                        &res,
+                    )
                 }
                 "FileSystemNotFound" => {
-                    return RusotoError::Service(DescribeFileSystemsError::FileSystemNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeFileSystemsError::FileSystemNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(DescribeFileSystemsError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeFileSystemsError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DescribeFileSystemsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1698,12 +1821,14 @@ impl fmt::Display for DescribeFileSystemsError {
             DescribeFileSystemsError::BadRequest(ref cause) => write!(f, "{}", cause),
             DescribeFileSystemsError::FileSystemNotFound(ref cause) => write!(f, "{}", cause),
             DescribeFileSystemsError::InternalServerError(ref cause) => write!(f, "{}", cause),
+            DescribeFileSystemsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeFileSystemsError {}
 /// Errors returned by ListTagsForResource
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListTagsForResourceError {
     /// <p>A generic error indicating a failure with a client request.</p>
     BadRequest(String),
@@ -1715,6 +1840,8 @@ pub enum ListTagsForResourceError {
     ResourceDoesNotSupportTagging(String),
     /// <p>The resource specified by the Amazon Resource Name (ARN) can't be found.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListTagsForResourceError {
@@ -1722,30 +1849,37 @@ impl ListTagsForResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "BadRequest" => {
-                    return RusotoError::Service(ListTagsForResourceError::BadRequest(err.msg))
+                    return RusotoError::service(
+                        ListTagsForResourceError::BadRequest(err.msg),
+                        &res,
+                    )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(ListTagsForResourceError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListTagsForResourceError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "NotServiceResourceError" => {
-                    return RusotoError::Service(ListTagsForResourceError::NotServiceResourceError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListTagsForResourceError::NotServiceResourceError(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceDoesNotSupportTagging" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         ListTagsForResourceError::ResourceDoesNotSupportTagging(err.msg),
+                        &res,
                     )
                 }
                 "ResourceNotFound" => {
-                    return RusotoError::Service(ListTagsForResourceError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ListTagsForResourceError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListTagsForResourceError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1762,12 +1896,14 @@ impl fmt::Display for ListTagsForResourceError {
                 write!(f, "{}", cause)
             }
             ListTagsForResourceError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            ListTagsForResourceError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListTagsForResourceError {}
 /// Errors returned by TagResource
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum TagResourceError {
     /// <p>A generic error indicating a failure with a client request.</p>
     BadRequest(String),
@@ -1779,29 +1915,40 @@ pub enum TagResourceError {
     ResourceDoesNotSupportTagging(String),
     /// <p>The resource specified by the Amazon Resource Name (ARN) can't be found.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl TagResourceError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<TagResourceError> {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
-                "BadRequest" => return RusotoError::Service(TagResourceError::BadRequest(err.msg)),
+                "BadRequest" => {
+                    return RusotoError::service(TagResourceError::BadRequest(err.msg), &res)
+                }
                 "InternalServerError" => {
-                    return RusotoError::Service(TagResourceError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        TagResourceError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "NotServiceResourceError" => {
-                    return RusotoError::Service(TagResourceError::NotServiceResourceError(err.msg))
+                    return RusotoError::service(
+                        TagResourceError::NotServiceResourceError(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceDoesNotSupportTagging" => {
-                    return RusotoError::Service(TagResourceError::ResourceDoesNotSupportTagging(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        TagResourceError::ResourceDoesNotSupportTagging(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFound" => {
-                    return RusotoError::Service(TagResourceError::ResourceNotFound(err.msg))
+                    return RusotoError::service(TagResourceError::ResourceNotFound(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(TagResourceError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1816,12 +1963,14 @@ impl fmt::Display for TagResourceError {
             TagResourceError::NotServiceResourceError(ref cause) => write!(f, "{}", cause),
             TagResourceError::ResourceDoesNotSupportTagging(ref cause) => write!(f, "{}", cause),
             TagResourceError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            TagResourceError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for TagResourceError {}
 /// Errors returned by UntagResource
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UntagResourceError {
     /// <p>A generic error indicating a failure with a client request.</p>
     BadRequest(String),
@@ -1833,6 +1982,8 @@ pub enum UntagResourceError {
     ResourceDoesNotSupportTagging(String),
     /// <p>The resource specified by the Amazon Resource Name (ARN) can't be found.</p>
     ResourceNotFound(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UntagResourceError {
@@ -1840,26 +1991,34 @@ impl UntagResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "BadRequest" => {
-                    return RusotoError::Service(UntagResourceError::BadRequest(err.msg))
+                    return RusotoError::service(UntagResourceError::BadRequest(err.msg), &res)
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(UntagResourceError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        UntagResourceError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "NotServiceResourceError" => {
-                    return RusotoError::Service(UntagResourceError::NotServiceResourceError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UntagResourceError::NotServiceResourceError(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceDoesNotSupportTagging" => {
-                    return RusotoError::Service(UntagResourceError::ResourceDoesNotSupportTagging(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UntagResourceError::ResourceDoesNotSupportTagging(err.msg),
+                        &res,
+                    )
                 }
                 "ResourceNotFound" => {
-                    return RusotoError::Service(UntagResourceError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        UntagResourceError::ResourceNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UntagResourceError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1874,12 +2033,14 @@ impl fmt::Display for UntagResourceError {
             UntagResourceError::NotServiceResourceError(ref cause) => write!(f, "{}", cause),
             UntagResourceError::ResourceDoesNotSupportTagging(ref cause) => write!(f, "{}", cause),
             UntagResourceError::ResourceNotFound(ref cause) => write!(f, "{}", cause),
+            UntagResourceError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for UntagResourceError {}
 /// Errors returned by UpdateFileSystem
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdateFileSystemError {
     /// <p>A generic error indicating a failure with a client request.</p>
     BadRequest(String),
@@ -1893,6 +2054,8 @@ pub enum UpdateFileSystemError {
     MissingFileSystemConfiguration(String),
     /// <p>The requested operation is not supported for this resource or API.</p>
     UnsupportedOperation(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdateFileSystemError {
@@ -1900,33 +2063,40 @@ impl UpdateFileSystemError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "BadRequest" => {
-                    return RusotoError::Service(UpdateFileSystemError::BadRequest(err.msg))
+                    return RusotoError::service(UpdateFileSystemError::BadRequest(err.msg), &res)
                 }
                 "FileSystemNotFound" => {
-                    return RusotoError::Service(UpdateFileSystemError::FileSystemNotFound(err.msg))
+                    return RusotoError::service(
+                        UpdateFileSystemError::FileSystemNotFound(err.msg),
+                        &res,
+                    )
                 }
                 "IncompatibleParameterError" => {
-                    return RusotoError::Service(UpdateFileSystemError::IncompatibleParameterError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateFileSystemError::IncompatibleParameterError(err.msg),
+                        &res,
+                    )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(UpdateFileSystemError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateFileSystemError::InternalServerError(err.msg),
+                        &res,
+                    )
                 }
                 "MissingFileSystemConfiguration" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         UpdateFileSystemError::MissingFileSystemConfiguration(err.msg),
+                        &res,
                     )
                 }
                 "UnsupportedOperation" => {
-                    return RusotoError::Service(UpdateFileSystemError::UnsupportedOperation(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateFileSystemError::UnsupportedOperation(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UpdateFileSystemError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -1944,6 +2114,7 @@ impl fmt::Display for UpdateFileSystemError {
                 write!(f, "{}", cause)
             }
             UpdateFileSystemError::UnsupportedOperation(ref cause) => write!(f, "{}", cause),
+            UpdateFileSystemError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
@@ -2046,6 +2217,7 @@ impl FsxClient {
     /// Creates a client backed by the default tokio event loop.
     ///
     /// The client will use the default credentials provider and tls client.
+    #[cfg(not(target_arch = "wasm32"))]
     pub fn new(region: region::Region) -> FsxClient {
         FsxClient {
             client: Client::shared(),
@@ -2071,16 +2243,73 @@ impl FsxClient {
     pub fn new_with_client(client: Client, region: region::Region) -> FsxClient {
         FsxClient { client, region }
     }
+
+    /// Returns a builder of a client, setting any of its region, credentials provider,
+    /// dispatcher, retry policy, endpoint and timeouts.
+    pub fn builder() -> rusoto_core::ClientBuilder<FsxClient> {
+        rusoto_core::ClientBuilder::new(FsxClient::new_with_client)
+    }
+
+    /// Returns a clone of this client with the overrides of `config` applied to its
+    /// operations, sharing its connections.
+    pub fn customize(&self, config: &rusoto_core::OperationConfig) -> FsxClient {
+        FsxClient {
+            client: self.client.customize(config),
+            region: config.resolve_region(&self.region),
+        }
+    }
 }
 
-#[async_trait]
-impl Fsx for FsxClient {
-    /// <p><p>Cancels an existing Amazon FSx for Lustre data repository task if that task is in either the <code>PENDING</code> or <code>EXECUTING</code> state. When you cancel a task, Amazon FSx does the following.</p> <ul> <li> <p>Any files that FSx has already exported are not reverted.</p> </li> <li> <p>FSx continues to export any files that are &quot;in-flight&quot; when the cancel operation is received.</p> </li> <li> <p>FSx does not export any files that have not yet been exported.</p> </li> </ul></p>
-    async fn cancel_data_repository_task(
+impl FsxClient {
+    /// Calls `cancel_data_repository_task`, also returning the metadata of the response.
+    pub async fn cancel_data_repository_task_with_metadata(
         &self,
         input: CancelDataRepositoryTaskRequest,
-    ) -> Result<CancelDataRepositoryTaskResponse, RusotoError<CancelDataRepositoryTaskError>> {
+    ) -> Result<
+        (CancelDataRepositoryTaskResponse, ResponseMetadata),
+        RusotoError<CancelDataRepositoryTaskError>,
+    > {
+        let request = self.build_cancel_data_repository_task_request(input);
+
+        let mut response = self
+            .client
+            .sign_and_dispatch(request)
+            .await
+            .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
+        if response.status.is_success() {
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<CancelDataRepositoryTaskResponse, _>()?;
+            Ok((result, metadata))
+        } else {
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(CancelDataRepositoryTaskError::from_response(response))
+        }
+    }
+
+    /// Presigns the request `cancel_data_repository_task` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_cancel_data_repository_task(
+        &self,
+        presigner: &rusoto_core::Presigner,
+        input: CancelDataRepositoryTaskRequest,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_cancel_data_repository_task_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_cancel_data_repository_task_request(
+        &self,
+        input: CancelDataRepositoryTaskRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "fsx", &self.region, "/");
+        request.set_operation("CancelDataRepositoryTask");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -2090,55 +2319,110 @@ impl Fsx for FsxClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `create_backup`, also returning the metadata of the response.
+    pub async fn create_backup_with_metadata(
+        &self,
+        input: CreateBackupRequest,
+    ) -> Result<(CreateBackupResponse, ResponseMetadata), RusotoError<CreateBackupError>> {
+        let request = self.build_create_backup_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<CancelDataRepositoryTaskResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<CreateBackupResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(CancelDataRepositoryTaskError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(CreateBackupError::from_response(response))
         }
     }
 
-    /// <p><p>Creates a backup of an existing Amazon FSx for Windows File Server file system. Creating regular backups for your file system is a best practice that complements the replication that Amazon FSx for Windows File Server performs for your file system. It also enables you to restore from user modification of data.</p> <p>If a backup with the specified client request token exists, and the parameters match, this operation returns the description of the existing backup. If a backup specified client request token exists, and the parameters don&#39;t match, this operation returns <code>IncompatibleParameterError</code>. If a backup with the specified client request token doesn&#39;t exist, <code>CreateBackup</code> does the following: </p> <ul> <li> <p>Creates a new Amazon FSx backup with an assigned ID, and an initial lifecycle state of <code>CREATING</code>.</p> </li> <li> <p>Returns the description of the backup.</p> </li> </ul> <p>By using the idempotent operation, you can retry a <code>CreateBackup</code> operation without the risk of creating an extra backup. This approach can be useful when an initial call fails in a way that makes it unclear whether a backup was created. If you use the same client request token and the initial call created a backup, the operation returns a successful result because all the parameters are the same.</p> <p>The <code>CreateFileSystem</code> operation returns while the backup&#39;s lifecycle state is still <code>CREATING</code>. You can check the file system creation status by calling the <a>DescribeBackups</a> operation, which returns the backup state along with other information.</p> <note> <p/> </note></p>
-    async fn create_backup(
+    /// Presigns the request `create_backup` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_backup(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: CreateBackupRequest,
-    ) -> Result<CreateBackupResponse, RusotoError<CreateBackupError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_backup_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_backup_request(&self, input: CreateBackupRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "fsx", &self.region, "/");
+        request.set_operation("CreateBackup");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSSimbaAPIService_v20180301.CreateBackup");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `create_data_repository_task`, also returning the metadata of the response.
+    pub async fn create_data_repository_task_with_metadata(
+        &self,
+        input: CreateDataRepositoryTaskRequest,
+    ) -> Result<
+        (CreateDataRepositoryTaskResponse, ResponseMetadata),
+        RusotoError<CreateDataRepositoryTaskError>,
+    > {
+        let request = self.build_create_data_repository_task_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<CreateBackupResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<CreateDataRepositoryTaskResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(CreateBackupError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(CreateDataRepositoryTaskError::from_response(response))
         }
     }
 
-    /// <p>Creates an Amazon FSx for Lustre data repository task. You use data repository tasks to perform bulk operations between your Amazon FSx file system and its linked data repository. An example of a data repository task is exporting any data and metadata changes, including POSIX metadata, to files, directories, and symbolic links (symlinks) from your FSx file system to its linked data repository. A <code>CreateDataRepositoryTask</code> operation will fail if a data repository is not linked to the FSx file system. To learn more about data repository tasks, see <a href="https://docs.aws.amazon.com/fsx/latest/LustreGuide/data-repository-tasks.html">Using Data Repository Tasks</a>. To learn more about linking a data repository to your file system, see <a href="https://docs.aws.amazon.com/fsx/latest/LustreGuide/getting-started-step1.html">Step 1: Create Your Amazon FSx for Lustre File System</a>.</p>
-    async fn create_data_repository_task(
+    /// Presigns the request `create_data_repository_task` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_data_repository_task(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: CreateDataRepositoryTaskRequest,
-    ) -> Result<CreateDataRepositoryTaskResponse, RusotoError<CreateDataRepositoryTaskError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_data_repository_task_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_data_repository_task_request(
+        &self,
+        input: CreateDataRepositoryTaskRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "fsx", &self.region, "/");
+        request.set_operation("CreateDataRepositoryTask");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -2148,28 +2432,53 @@ impl Fsx for FsxClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `create_file_system`, also returning the metadata of the response.
+    pub async fn create_file_system_with_metadata(
+        &self,
+        input: CreateFileSystemRequest,
+    ) -> Result<(CreateFileSystemResponse, ResponseMetadata), RusotoError<CreateFileSystemError>>
+    {
+        let request = self.build_create_file_system_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<CreateDataRepositoryTaskResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<CreateFileSystemResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(CreateDataRepositoryTaskError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(CreateFileSystemError::from_response(response))
         }
     }
 
-    /// <p><p>Creates a new, empty Amazon FSx file system.</p> <p>If a file system with the specified client request token exists and the parameters match, <code>CreateFileSystem</code> returns the description of the existing file system. If a file system specified client request token exists and the parameters don&#39;t match, this call returns <code>IncompatibleParameterError</code>. If a file system with the specified client request token doesn&#39;t exist, <code>CreateFileSystem</code> does the following: </p> <ul> <li> <p>Creates a new, empty Amazon FSx file system with an assigned ID, and an initial lifecycle state of <code>CREATING</code>.</p> </li> <li> <p>Returns the description of the file system.</p> </li> </ul> <p>This operation requires a client request token in the request that Amazon FSx uses to ensure idempotent creation. This means that calling the operation multiple times with the same client request token has no effect. By using the idempotent operation, you can retry a <code>CreateFileSystem</code> operation without the risk of creating an extra file system. This approach can be useful when an initial call fails in a way that makes it unclear whether a file system was created. Examples are if a transport level timeout occurred, or your connection was reset. If you use the same client request token and the initial call created a file system, the client receives success as long as the parameters are the same.</p> <note> <p>The <code>CreateFileSystem</code> call returns while the file system&#39;s lifecycle state is still <code>CREATING</code>. You can check the file-system creation status by calling the <a>DescribeFileSystems</a> operation, which returns the file system state along with other information.</p> </note></p>
-    async fn create_file_system(
+    /// Presigns the request `create_file_system` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_file_system(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: CreateFileSystemRequest,
-    ) -> Result<CreateFileSystemResponse, RusotoError<CreateFileSystemError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_file_system_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_file_system_request(&self, input: CreateFileSystemRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "fsx", &self.region, "/");
+        request.set_operation("CreateFileSystem");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -2179,29 +2488,58 @@ impl Fsx for FsxClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `create_file_system_from_backup`, also returning the metadata of the response.
+    pub async fn create_file_system_from_backup_with_metadata(
+        &self,
+        input: CreateFileSystemFromBackupRequest,
+    ) -> Result<
+        (CreateFileSystemFromBackupResponse, ResponseMetadata),
+        RusotoError<CreateFileSystemFromBackupError>,
+    > {
+        let request = self.build_create_file_system_from_backup_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<CreateFileSystemResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<CreateFileSystemFromBackupResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(CreateFileSystemError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(CreateFileSystemFromBackupError::from_response(response))
         }
     }
 
-    /// <p><p>Creates a new Amazon FSx file system from an existing Amazon FSx for Windows File Server backup.</p> <p>If a file system with the specified client request token exists and the parameters match, this operation returns the description of the file system. If a client request token specified by the file system exists and the parameters don&#39;t match, this call returns <code>IncompatibleParameterError</code>. If a file system with the specified client request token doesn&#39;t exist, this operation does the following:</p> <ul> <li> <p>Creates a new Amazon FSx file system from backup with an assigned ID, and an initial lifecycle state of <code>CREATING</code>.</p> </li> <li> <p>Returns the description of the file system.</p> </li> </ul> <p>Parameters like Active Directory, default share name, automatic backup, and backup settings default to the parameters of the file system that was backed up, unless overridden. You can explicitly supply other settings.</p> <p>By using the idempotent operation, you can retry a <code>CreateFileSystemFromBackup</code> call without the risk of creating an extra file system. This approach can be useful when an initial call fails in a way that makes it unclear whether a file system was created. Examples are if a transport level timeout occurred, or your connection was reset. If you use the same client request token and the initial call created a file system, the client receives success as long as the parameters are the same.</p> <note> <p>The <code>CreateFileSystemFromBackup</code> call returns while the file system&#39;s lifecycle state is still <code>CREATING</code>. You can check the file-system creation status by calling the <a>DescribeFileSystems</a> operation, which returns the file system state along with other information.</p> </note></p>
-    async fn create_file_system_from_backup(
+    /// Presigns the request `create_file_system_from_backup` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_file_system_from_backup(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: CreateFileSystemFromBackupRequest,
-    ) -> Result<CreateFileSystemFromBackupResponse, RusotoError<CreateFileSystemFromBackupError>>
-    {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_file_system_from_backup_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_file_system_from_backup_request(
+        &self,
+        input: CreateFileSystemFromBackupRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "fsx", &self.region, "/");
+        request.set_operation("CreateFileSystemFromBackup");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -2211,55 +2549,105 @@ impl Fsx for FsxClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `delete_backup`, also returning the metadata of the response.
+    pub async fn delete_backup_with_metadata(
+        &self,
+        input: DeleteBackupRequest,
+    ) -> Result<(DeleteBackupResponse, ResponseMetadata), RusotoError<DeleteBackupError>> {
+        let request = self.build_delete_backup_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<CreateFileSystemFromBackupResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DeleteBackupResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(CreateFileSystemFromBackupError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DeleteBackupError::from_response(response))
         }
     }
 
-    /// <p><p>Deletes an Amazon FSx for Windows File Server backup, deleting its contents. After deletion, the backup no longer exists, and its data is gone.</p> <p>The <code>DeleteBackup</code> call returns instantly. The backup will not show up in later <code>DescribeBackups</code> calls.</p> <important> <p>The data in a deleted backup is also deleted and can&#39;t be recovered by any means.</p> </important></p>
-    async fn delete_backup(
+    /// Presigns the request `delete_backup` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_backup(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteBackupRequest,
-    ) -> Result<DeleteBackupResponse, RusotoError<DeleteBackupError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_backup_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_backup_request(&self, input: DeleteBackupRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "fsx", &self.region, "/");
+        request.set_operation("DeleteBackup");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSSimbaAPIService_v20180301.DeleteBackup");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `delete_file_system`, also returning the metadata of the response.
+    pub async fn delete_file_system_with_metadata(
+        &self,
+        input: DeleteFileSystemRequest,
+    ) -> Result<(DeleteFileSystemResponse, ResponseMetadata), RusotoError<DeleteFileSystemError>>
+    {
+        let request = self.build_delete_file_system_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<DeleteBackupResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DeleteFileSystemResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteBackupError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DeleteFileSystemError::from_response(response))
         }
     }
 
-    /// <p><p>Deletes a file system, deleting its contents. After deletion, the file system no longer exists, and its data is gone. Any existing automatic backups will also be deleted.</p> <p>By default, when you delete an Amazon FSx for Windows File Server file system, a final backup is created upon deletion. This final backup is not subject to the file system&#39;s retention policy, and must be manually deleted.</p> <p>The <code>DeleteFileSystem</code> action returns while the file system has the <code>DELETING</code> status. You can check the file system deletion status by calling the <a>DescribeFileSystems</a> action, which returns a list of file systems in your account. If you pass the file system ID for a deleted file system, the <a>DescribeFileSystems</a> returns a <code>FileSystemNotFound</code> error.</p> <note> <p>Deleting an Amazon FSx for Lustre file system will fail with a 400 BadRequest if a data repository task is in a <code>PENDING</code> or <code>EXECUTING</code> state.</p> </note> <important> <p>The data in a deleted file system is also deleted and can&#39;t be recovered by any means.</p> </important></p>
-    async fn delete_file_system(
+    /// Presigns the request `delete_file_system` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_file_system(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteFileSystemRequest,
-    ) -> Result<DeleteFileSystemResponse, RusotoError<DeleteFileSystemError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_file_system_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_file_system_request(&self, input: DeleteFileSystemRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "fsx", &self.region, "/");
+        request.set_operation("DeleteFileSystem");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -2269,28 +2657,53 @@ impl Fsx for FsxClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `describe_backups`, also returning the metadata of the response.
+    pub async fn describe_backups_with_metadata(
+        &self,
+        input: DescribeBackupsRequest,
+    ) -> Result<(DescribeBackupsResponse, ResponseMetadata), RusotoError<DescribeBackupsError>>
+    {
+        let request = self.build_describe_backups_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DeleteFileSystemResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DescribeBackupsResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteFileSystemError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DescribeBackupsError::from_response(response))
         }
     }
 
-    /// <p><p>Returns the description of specific Amazon FSx for Windows File Server backups, if a <code>BackupIds</code> value is provided for that backup. Otherwise, it returns all backups owned by your AWS account in the AWS Region of the endpoint that you&#39;re calling.</p> <p>When retrieving all backups, you can optionally specify the <code>MaxResults</code> parameter to limit the number of backups in a response. If more backups remain, Amazon FSx returns a <code>NextToken</code> value in the response. In this case, send a later request with the <code>NextToken</code> request parameter set to the value of <code>NextToken</code> from the last response.</p> <p>This action is used in an iterative process to retrieve a list of your backups. <code>DescribeBackups</code> is called first without a <code>NextToken</code>value. Then the action continues to be called with the <code>NextToken</code> parameter set to the value of the last <code>NextToken</code> value until a response has no <code>NextToken</code>.</p> <p>When using this action, keep the following in mind:</p> <ul> <li> <p>The implementation might return fewer than <code>MaxResults</code> file system descriptions while still including a <code>NextToken</code> value.</p> </li> <li> <p>The order of backups returned in the response of one <code>DescribeBackups</code> call and the order of backups returned across the responses of a multi-call iteration is unspecified.</p> </li> </ul></p>
-    async fn describe_backups(
+    /// Presigns the request `describe_backups` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_backups(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeBackupsRequest,
-    ) -> Result<DescribeBackupsResponse, RusotoError<DescribeBackupsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_backups_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_backups_request(&self, input: DescribeBackupsRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "fsx", &self.region, "/");
+        request.set_operation("DescribeBackups");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -2300,28 +2713,58 @@ impl Fsx for FsxClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `describe_data_repository_tasks`, also returning the metadata of the response.
+    pub async fn describe_data_repository_tasks_with_metadata(
+        &self,
+        input: DescribeDataRepositoryTasksRequest,
+    ) -> Result<
+        (DescribeDataRepositoryTasksResponse, ResponseMetadata),
+        RusotoError<DescribeDataRepositoryTasksError>,
+    > {
+        let request = self.build_describe_data_repository_tasks_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<DescribeBackupsResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DescribeDataRepositoryTasksResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeBackupsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DescribeDataRepositoryTasksError::from_response(response))
         }
     }
 
-    /// <p>Returns the description of specific Amazon FSx for Lustre data repository tasks, if one or more <code>TaskIds</code> values are provided in the request, or if filters are used in the request. You can use filters to narrow the response to include just tasks for specific file systems, or tasks in a specific lifecycle state. Otherwise, it returns all data repository tasks owned by your AWS account in the AWS Region of the endpoint that you're calling.</p> <p>When retrieving all tasks, you can paginate the response by using the optional <code>MaxResults</code> parameter to limit the number of tasks returned in a response. If more tasks remain, Amazon FSx returns a <code>NextToken</code> value in the response. In this case, send a later request with the <code>NextToken</code> request parameter set to the value of <code>NextToken</code> from the last response.</p>
-    async fn describe_data_repository_tasks(
+    /// Presigns the request `describe_data_repository_tasks` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_data_repository_tasks(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeDataRepositoryTasksRequest,
-    ) -> Result<DescribeDataRepositoryTasksResponse, RusotoError<DescribeDataRepositoryTasksError>>
-    {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_data_repository_tasks_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_data_repository_tasks_request(
+        &self,
+        input: DescribeDataRepositoryTasksRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "fsx", &self.region, "/");
+        request.set_operation("DescribeDataRepositoryTasks");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -2331,28 +2774,58 @@ impl Fsx for FsxClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `describe_file_systems`, also returning the metadata of the response.
+    pub async fn describe_file_systems_with_metadata(
+        &self,
+        input: DescribeFileSystemsRequest,
+    ) -> Result<
+        (DescribeFileSystemsResponse, ResponseMetadata),
+        RusotoError<DescribeFileSystemsError>,
+    > {
+        let request = self.build_describe_file_systems_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DescribeDataRepositoryTasksResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DescribeFileSystemsResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeDataRepositoryTasksError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DescribeFileSystemsError::from_response(response))
         }
     }
 
-    /// <p><p>Returns the description of specific Amazon FSx file systems, if a <code>FileSystemIds</code> value is provided for that file system. Otherwise, it returns descriptions of all file systems owned by your AWS account in the AWS Region of the endpoint that you&#39;re calling.</p> <p>When retrieving all file system descriptions, you can optionally specify the <code>MaxResults</code> parameter to limit the number of descriptions in a response. If more file system descriptions remain, Amazon FSx returns a <code>NextToken</code> value in the response. In this case, send a later request with the <code>NextToken</code> request parameter set to the value of <code>NextToken</code> from the last response.</p> <p>This action is used in an iterative process to retrieve a list of your file system descriptions. <code>DescribeFileSystems</code> is called first without a <code>NextToken</code>value. Then the action continues to be called with the <code>NextToken</code> parameter set to the value of the last <code>NextToken</code> value until a response has no <code>NextToken</code>.</p> <p>When using this action, keep the following in mind:</p> <ul> <li> <p>The implementation might return fewer than <code>MaxResults</code> file system descriptions while still including a <code>NextToken</code> value.</p> </li> <li> <p>The order of file systems returned in the response of one <code>DescribeFileSystems</code> call and the order of file systems returned across the responses of a multicall iteration is unspecified.</p> </li> </ul></p>
-    async fn describe_file_systems(
+    /// Presigns the request `describe_file_systems` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_file_systems(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeFileSystemsRequest,
-    ) -> Result<DescribeFileSystemsResponse, RusotoError<DescribeFileSystemsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_file_systems_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_file_systems_request(
+        &self,
+        input: DescribeFileSystemsRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "fsx", &self.region, "/");
+        request.set_operation("DescribeFileSystems");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -2362,28 +2835,58 @@ impl Fsx for FsxClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `list_tags_for_resource`, also returning the metadata of the response.
+    pub async fn list_tags_for_resource_with_metadata(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> Result<
+        (ListTagsForResourceResponse, ResponseMetadata),
+        RusotoError<ListTagsForResourceError>,
+    > {
+        let request = self.build_list_tags_for_resource_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DescribeFileSystemsResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ListTagsForResourceResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeFileSystemsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ListTagsForResourceError::from_response(response))
         }
     }
 
-    /// <p><p>Lists tags for an Amazon FSx file systems and backups in the case of Amazon FSx for Windows File Server.</p> <p>When retrieving all tags, you can optionally specify the <code>MaxResults</code> parameter to limit the number of tags in a response. If more tags remain, Amazon FSx returns a <code>NextToken</code> value in the response. In this case, send a later request with the <code>NextToken</code> request parameter set to the value of <code>NextToken</code> from the last response.</p> <p>This action is used in an iterative process to retrieve a list of your tags. <code>ListTagsForResource</code> is called first without a <code>NextToken</code>value. Then the action continues to be called with the <code>NextToken</code> parameter set to the value of the last <code>NextToken</code> value until a response has no <code>NextToken</code>.</p> <p>When using this action, keep the following in mind:</p> <ul> <li> <p>The implementation might return fewer than <code>MaxResults</code> file system descriptions while still including a <code>NextToken</code> value.</p> </li> <li> <p>The order of tags returned in the response of one <code>ListTagsForResource</code> call and the order of tags returned across the responses of a multi-call iteration is unspecified.</p> </li> </ul></p>
-    async fn list_tags_for_resource(
+    /// Presigns the request `list_tags_for_resource` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_tags_for_resource(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListTagsForResourceRequest,
-    ) -> Result<ListTagsForResourceResponse, RusotoError<ListTagsForResourceError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_tags_for_resource_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_tags_for_resource_request(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "fsx", &self.region, "/");
+        request.set_operation("ListTagsForResource");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -2393,82 +2896,157 @@ impl Fsx for FsxClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `tag_resource`, also returning the metadata of the response.
+    pub async fn tag_resource_with_metadata(
+        &self,
+        input: TagResourceRequest,
+    ) -> Result<(TagResourceResponse, ResponseMetadata), RusotoError<TagResourceError>> {
+        let request = self.build_tag_resource_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListTagsForResourceResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<TagResourceResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ListTagsForResourceError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(TagResourceError::from_response(response))
         }
     }
 
-    /// <p>Tags an Amazon FSx resource.</p>
-    async fn tag_resource(
+    /// Presigns the request `tag_resource` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_tag_resource(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: TagResourceRequest,
-    ) -> Result<TagResourceResponse, RusotoError<TagResourceError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_tag_resource_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_tag_resource_request(&self, input: TagResourceRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "fsx", &self.region, "/");
+        request.set_operation("TagResource");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSSimbaAPIService_v20180301.TagResource");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `untag_resource`, also returning the metadata of the response.
+    pub async fn untag_resource_with_metadata(
+        &self,
+        input: UntagResourceRequest,
+    ) -> Result<(UntagResourceResponse, ResponseMetadata), RusotoError<UntagResourceError>> {
+        let request = self.build_untag_resource_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<TagResourceResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<UntagResourceResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(TagResourceError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(UntagResourceError::from_response(response))
         }
     }
 
-    /// <p>This action removes a tag from an Amazon FSx resource.</p>
-    async fn untag_resource(
+    /// Presigns the request `untag_resource` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_untag_resource(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UntagResourceRequest,
-    ) -> Result<UntagResourceResponse, RusotoError<UntagResourceError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_untag_resource_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_untag_resource_request(&self, input: UntagResourceRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "fsx", &self.region, "/");
+        request.set_operation("UntagResource");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "AWSSimbaAPIService_v20180301.UntagResource");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `update_file_system`, also returning the metadata of the response.
+    pub async fn update_file_system_with_metadata(
+        &self,
+        input: UpdateFileSystemRequest,
+    ) -> Result<(UpdateFileSystemResponse, ResponseMetadata), RusotoError<UpdateFileSystemError>>
+    {
+        let request = self.build_update_file_system_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<UntagResourceResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<UpdateFileSystemResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(UntagResourceError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(UpdateFileSystemError::from_response(response))
         }
     }
 
-    /// <p>Updates a file system configuration.</p>
-    async fn update_file_system(
+    /// Presigns the request `update_file_system` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_file_system(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UpdateFileSystemRequest,
-    ) -> Result<UpdateFileSystemResponse, RusotoError<UpdateFileSystemError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_file_system_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_file_system_request(&self, input: UpdateFileSystemRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "fsx", &self.region, "/");
+        request.set_operation("UpdateFileSystem");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header(
@@ -2478,19 +3056,261 @@ impl Fsx for FsxClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
-        let mut response = self
-            .client
-            .sign_and_dispatch(request)
+        request
+    }
+
+    /// The pages of `DescribeBackups` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `MaxResults` of `input`.
+    pub fn describe_backups_pages(
+        &self,
+        input: DescribeBackupsRequest,
+    ) -> impl futures::Stream<
+        Item = Result<DescribeBackupsResponse, RusotoError<DescribeBackupsError>>,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: FsxClient, input| async move { client.describe_backups(input).await },
+            |input: &DescribeBackupsRequest, output: &DescribeBackupsResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(DescribeBackupsRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `Backups` of every page of `DescribeBackups` results for `input`, as `describe_backups_pages` requests them.
+    pub fn describe_backups_stream(
+        &self,
+        input: DescribeBackupsRequest,
+    ) -> impl futures::Stream<Item = Result<Backup, RusotoError<DescribeBackupsError>>> + Send {
+        rusoto_core::pagination::items(self.describe_backups_pages(input), |page| {
+            page.backups.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `DescribeFileSystems` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `MaxResults` of `input`.
+    pub fn describe_file_systems_pages(
+        &self,
+        input: DescribeFileSystemsRequest,
+    ) -> impl futures::Stream<
+        Item = Result<DescribeFileSystemsResponse, RusotoError<DescribeFileSystemsError>>,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: FsxClient, input| async move { client.describe_file_systems(input).await },
+            |input: &DescribeFileSystemsRequest, output: &DescribeFileSystemsResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(DescribeFileSystemsRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `FileSystems` of every page of `DescribeFileSystems` results for `input`, as `describe_file_systems_pages` requests them.
+    pub fn describe_file_systems_stream(
+        &self,
+        input: DescribeFileSystemsRequest,
+    ) -> impl futures::Stream<Item = Result<FileSystem, RusotoError<DescribeFileSystemsError>>> + Send
+    {
+        rusoto_core::pagination::items(self.describe_file_systems_pages(input), |page| {
+            page.file_systems.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `ListTagsForResource` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `MaxResults` of `input`.
+    pub fn list_tags_for_resource_pages(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> impl futures::Stream<
+        Item = Result<ListTagsForResourceResponse, RusotoError<ListTagsForResourceError>>,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: FsxClient, input| async move { client.list_tags_for_resource(input).await },
+            |input: &ListTagsForResourceRequest, output: &ListTagsForResourceResponse| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListTagsForResourceRequest {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `Tags` of every page of `ListTagsForResource` results for `input`, as `list_tags_for_resource_pages` requests them.
+    pub fn list_tags_for_resource_stream(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> impl futures::Stream<Item = Result<Tag, RusotoError<ListTagsForResourceError>>> + Send
+    {
+        rusoto_core::pagination::items(self.list_tags_for_resource_pages(input), |page| {
+            page.tags.unwrap_or_default()
+        })
+    }
+}
+
+#[async_trait]
+impl Fsx for FsxClient {
+    /// <p><p>Cancels an existing Amazon FSx for Lustre data repository task if that task is in either the <code>PENDING</code> or <code>EXECUTING</code> state. When you cancel a task, Amazon FSx does the following.</p> <ul> <li> <p>Any files that FSx has already exported are not reverted.</p> </li> <li> <p>FSx continues to export any files that are &quot;in-flight&quot; when the cancel operation is received.</p> </li> <li> <p>FSx does not export any files that have not yet been exported.</p> </li> </ul></p>
+    async fn cancel_data_repository_task(
+        &self,
+        input: CancelDataRepositoryTaskRequest,
+    ) -> Result<CancelDataRepositoryTaskResponse, RusotoError<CancelDataRepositoryTaskError>> {
+        self.cancel_data_repository_task_with_metadata(input)
             .await
-            .map_err(RusotoError::from)?;
-        if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<UpdateFileSystemResponse, _>()
-        } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(UpdateFileSystemError::from_response(response))
-        }
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Creates a backup of an existing Amazon FSx for Windows File Server file system. Creating regular backups for your file system is a best practice that complements the replication that Amazon FSx for Windows File Server performs for your file system. It also enables you to restore from user modification of data.</p> <p>If a backup with the specified client request token exists, and the parameters match, this operation returns the description of the existing backup. If a backup specified client request token exists, and the parameters don&#39;t match, this operation returns <code>IncompatibleParameterError</code>. If a backup with the specified client request token doesn&#39;t exist, <code>CreateBackup</code> does the following: </p> <ul> <li> <p>Creates a new Amazon FSx backup with an assigned ID, and an initial lifecycle state of <code>CREATING</code>.</p> </li> <li> <p>Returns the description of the backup.</p> </li> </ul> <p>By using the idempotent operation, you can retry a <code>CreateBackup</code> operation without the risk of creating an extra backup. This approach can be useful when an initial call fails in a way that makes it unclear whether a backup was created. If you use the same client request token and the initial call created a backup, the operation returns a successful result because all the parameters are the same.</p> <p>The <code>CreateFileSystem</code> operation returns while the backup&#39;s lifecycle state is still <code>CREATING</code>. You can check the file system creation status by calling the <a>DescribeBackups</a> operation, which returns the backup state along with other information.</p> <note> <p/> </note></p>
+    async fn create_backup(
+        &self,
+        input: CreateBackupRequest,
+    ) -> Result<CreateBackupResponse, RusotoError<CreateBackupError>> {
+        self.create_backup_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Creates an Amazon FSx for Lustre data repository task. You use data repository tasks to perform bulk operations between your Amazon FSx file system and its linked data repository. An example of a data repository task is exporting any data and metadata changes, including POSIX metadata, to files, directories, and symbolic links (symlinks) from your FSx file system to its linked data repository. A <code>CreateDataRepositoryTask</code> operation will fail if a data repository is not linked to the FSx file system. To learn more about data repository tasks, see <a href="https://docs.aws.amazon.com/fsx/latest/LustreGuide/data-repository-tasks.html">Using Data Repository Tasks</a>. To learn more about linking a data repository to your file system, see <a href="https://docs.aws.amazon.com/fsx/latest/LustreGuide/getting-started-step1.html">Step 1: Create Your Amazon FSx for Lustre File System</a>.</p>
+    async fn create_data_repository_task(
+        &self,
+        input: CreateDataRepositoryTaskRequest,
+    ) -> Result<CreateDataRepositoryTaskResponse, RusotoError<CreateDataRepositoryTaskError>> {
+        self.create_data_repository_task_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Creates a new, empty Amazon FSx file system.</p> <p>If a file system with the specified client request token exists and the parameters match, <code>CreateFileSystem</code> returns the description of the existing file system. If a file system specified client request token exists and the parameters don&#39;t match, this call returns <code>IncompatibleParameterError</code>. If a file system with the specified client request token doesn&#39;t exist, <code>CreateFileSystem</code> does the following: </p> <ul> <li> <p>Creates a new, empty Amazon FSx file system with an assigned ID, and an initial lifecycle state of <code>CREATING</code>.</p> </li> <li> <p>Returns the description of the file system.</p> </li> </ul> <p>This operation requires a client request token in the request that Amazon FSx uses to ensure idempotent creation. This means that calling the operation multiple times with the same client request token has no effect. By using the idempotent operation, you can retry a <code>CreateFileSystem</code> operation without the risk of creating an extra file system. This approach can be useful when an initial call fails in a way that makes it unclear whether a file system was created. Examples are if a transport level timeout occurred, or your connection was reset. If you use the same client request token and the initial call created a file system, the client receives success as long as the parameters are the same.</p> <note> <p>The <code>CreateFileSystem</code> call returns while the file system&#39;s lifecycle state is still <code>CREATING</code>. You can check the file-system creation status by calling the <a>DescribeFileSystems</a> operation, which returns the file system state along with other information.</p> </note></p>
+    async fn create_file_system(
+        &self,
+        input: CreateFileSystemRequest,
+    ) -> Result<CreateFileSystemResponse, RusotoError<CreateFileSystemError>> {
+        self.create_file_system_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Creates a new Amazon FSx file system from an existing Amazon FSx for Windows File Server backup.</p> <p>If a file system with the specified client request token exists and the parameters match, this operation returns the description of the file system. If a client request token specified by the file system exists and the parameters don&#39;t match, this call returns <code>IncompatibleParameterError</code>. If a file system with the specified client request token doesn&#39;t exist, this operation does the following:</p> <ul> <li> <p>Creates a new Amazon FSx file system from backup with an assigned ID, and an initial lifecycle state of <code>CREATING</code>.</p> </li> <li> <p>Returns the description of the file system.</p> </li> </ul> <p>Parameters like Active Directory, default share name, automatic backup, and backup settings default to the parameters of the file system that was backed up, unless overridden. You can explicitly supply other settings.</p> <p>By using the idempotent operation, you can retry a <code>CreateFileSystemFromBackup</code> call without the risk of creating an extra file system. This approach can be useful when an initial call fails in a way that makes it unclear whether a file system was created. Examples are if a transport level timeout occurred, or your connection was reset. If you use the same client request token and the initial call created a file system, the client receives success as long as the parameters are the same.</p> <note> <p>The <code>CreateFileSystemFromBackup</code> call returns while the file system&#39;s lifecycle state is still <code>CREATING</code>. You can check the file-system creation status by calling the <a>DescribeFileSystems</a> operation, which returns the file system state along with other information.</p> </note></p>
+    async fn create_file_system_from_backup(
+        &self,
+        input: CreateFileSystemFromBackupRequest,
+    ) -> Result<CreateFileSystemFromBackupResponse, RusotoError<CreateFileSystemFromBackupError>>
+    {
+        self.create_file_system_from_backup_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Deletes an Amazon FSx for Windows File Server backup, deleting its contents. After deletion, the backup no longer exists, and its data is gone.</p> <p>The <code>DeleteBackup</code> call returns instantly. The backup will not show up in later <code>DescribeBackups</code> calls.</p> <important> <p>The data in a deleted backup is also deleted and can&#39;t be recovered by any means.</p> </important></p>
+    async fn delete_backup(
+        &self,
+        input: DeleteBackupRequest,
+    ) -> Result<DeleteBackupResponse, RusotoError<DeleteBackupError>> {
+        self.delete_backup_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Deletes a file system, deleting its contents. After deletion, the file system no longer exists, and its data is gone. Any existing automatic backups will also be deleted.</p> <p>By default, when you delete an Amazon FSx for Windows File Server file system, a final backup is created upon deletion. This final backup is not subject to the file system&#39;s retention policy, and must be manually deleted.</p> <p>The <code>DeleteFileSystem</code> action returns while the file system has the <code>DELETING</code> status. You can check the file system deletion status by calling the <a>DescribeFileSystems</a> action, which returns a list of file systems in your account. If you pass the file system ID for a deleted file system, the <a>DescribeFileSystems</a> returns a <code>FileSystemNotFound</code> error.</p> <note> <p>Deleting an Amazon FSx for Lustre file system will fail with a 400 BadRequest if a data repository task is in a <code>PENDING</code> or <code>EXECUTING</code> state.</p> </note> <important> <p>The data in a deleted file system is also deleted and can&#39;t be recovered by any means.</p> </important></p>
+    async fn delete_file_system(
+        &self,
+        input: DeleteFileSystemRequest,
+    ) -> Result<DeleteFileSystemResponse, RusotoError<DeleteFileSystemError>> {
+        self.delete_file_system_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Returns the description of specific Amazon FSx for Windows File Server backups, if a <code>BackupIds</code> value is provided for that backup. Otherwise, it returns all backups owned by your AWS account in the AWS Region of the endpoint that you&#39;re calling.</p> <p>When retrieving all backups, you can optionally specify the <code>MaxResults</code> parameter to limit the number of backups in a response. If more backups remain, Amazon FSx returns a <code>NextToken</code> value in the response. In this case, send a later request with the <code>NextToken</code> request parameter set to the value of <code>NextToken</code> from the last response.</p> <p>This action is used in an iterative process to retrieve a list of your backups. <code>DescribeBackups</code> is called first without a <code>NextToken</code>value. Then the action continues to be called with the <code>NextToken</code> parameter set to the value of the last <code>NextToken</code> value until a response has no <code>NextToken</code>.</p> <p>When using this action, keep the following in mind:</p> <ul> <li> <p>The implementation might return fewer than <code>MaxResults</code> file system descriptions while still including a <code>NextToken</code> value.</p> </li> <li> <p>The order of backups returned in the response of one <code>DescribeBackups</code> call and the order of backups returned across the responses of a multi-call iteration is unspecified.</p> </li> </ul></p>
+    async fn describe_backups(
+        &self,
+        input: DescribeBackupsRequest,
+    ) -> Result<DescribeBackupsResponse, RusotoError<DescribeBackupsError>> {
+        self.describe_backups_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Returns the description of specific Amazon FSx for Lustre data repository tasks, if one or more <code>TaskIds</code> values are provided in the request, or if filters are used in the request. You can use filters to narrow the response to include just tasks for specific file systems, or tasks in a specific lifecycle state. Otherwise, it returns all data repository tasks owned by your AWS account in the AWS Region of the endpoint that you're calling.</p> <p>When retrieving all tasks, you can paginate the response by using the optional <code>MaxResults</code> parameter to limit the number of tasks returned in a response. If more tasks remain, Amazon FSx returns a <code>NextToken</code> value in the response. In this case, send a later request with the <code>NextToken</code> request parameter set to the value of <code>NextToken</code> from the last response.</p>
+    async fn describe_data_repository_tasks(
+        &self,
+        input: DescribeDataRepositoryTasksRequest,
+    ) -> Result<DescribeDataRepositoryTasksResponse, RusotoError<DescribeDataRepositoryTasksError>>
+    {
+        self.describe_data_repository_tasks_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Returns the description of specific Amazon FSx file systems, if a <code>FileSystemIds</code> value is provided for that file system. Otherwise, it returns descriptions of all file systems owned by your AWS account in the AWS Region of the endpoint that you&#39;re calling.</p> <p>When retrieving all file system descriptions, you can optionally specify the <code>MaxResults</code> parameter to limit the number of descriptions in a response. If more file system descriptions remain, Amazon FSx returns a <code>NextToken</code> value in the response. In this case, send a later request with the <code>NextToken</code> request parameter set to the value of <code>NextToken</code> from the last response.</p> <p>This action is used in an iterative process to retrieve a list of your file system descriptions. <code>DescribeFileSystems</code> is called first without a <code>NextToken</code>value. Then the action continues to be called with the <code>NextToken</code> parameter set to the value of the last <code>NextToken</code> value until a response has no <code>NextToken</code>.</p> <p>When using this action, keep the following in mind:</p> <ul> <li> <p>The implementation might return fewer than <code>MaxResults</code> file system descriptions while still including a <code>NextToken</code> value.</p> </li> <li> <p>The order of file systems returned in the response of one <code>DescribeFileSystems</code> call and the order of file systems returned across the responses of a multicall iteration is unspecified.</p> </li> </ul></p>
+    async fn describe_file_systems(
+        &self,
+        input: DescribeFileSystemsRequest,
+    ) -> Result<DescribeFileSystemsResponse, RusotoError<DescribeFileSystemsError>> {
+        self.describe_file_systems_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Lists tags for an Amazon FSx file systems and backups in the case of Amazon FSx for Windows File Server.</p> <p>When retrieving all tags, you can optionally specify the <code>MaxResults</code> parameter to limit the number of tags in a response. If more tags remain, Amazon FSx returns a <code>NextToken</code> value in the response. In this case, send a later request with the <code>NextToken</code> request parameter set to the value of <code>NextToken</code> from the last response.</p> <p>This action is used in an iterative process to retrieve a list of your tags. <code>ListTagsForResource</code> is called first without a <code>NextToken</code>value. Then the action continues to be called with the <code>NextToken</code> parameter set to the value of the last <code>NextToken</code> value until a response has no <code>NextToken</code>.</p> <p>When using this action, keep the following in mind:</p> <ul> <li> <p>The implementation might return fewer than <code>MaxResults</code> file system descriptions while still including a <code>NextToken</code> value.</p> </li> <li> <p>The order of tags returned in the response of one <code>ListTagsForResource</code> call and the order of tags returned across the responses of a multi-call iteration is unspecified.</p> </li> </ul></p>
+    async fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> Result<ListTagsForResourceResponse, RusotoError<ListTagsForResourceError>> {
+        self.list_tags_for_resource_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Tags an Amazon FSx resource.</p>
+    async fn tag_resource(
+        &self,
+        input: TagResourceRequest,
+    ) -> Result<TagResourceResponse, RusotoError<TagResourceError>> {
+        self.tag_resource_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>This action removes a tag from an Amazon FSx resource.</p>
+    async fn untag_resource(
+        &self,
+        input: UntagResourceRequest,
+    ) -> Result<UntagResourceResponse, RusotoError<UntagResourceError>> {
+        self.untag_resource_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Updates a file system configuration.</p>
+    async fn update_file_system(
+        &self,
+        input: UpdateFileSystemRequest,
+    ) -> Result<UpdateFileSystemResponse, RusotoError<UpdateFileSystemError>> {
+        self.update_file_system_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
     }
 }
@@ -16,8 +16,8 @@ use std::fmt;
 use async_trait::async_trait;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
-use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest, ResponseMetadata};
+use rusoto_core::{Client, ErrorMetadata, RusotoError};
 
 use rusoto_core::proto;
 use rusoto_core::signature::SignedRequest;
@@ -3375,6 +3375,7 @@ pub struct VpcPeeringConnectionStatus {
 
 /// Errors returned by AcceptMatch
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum AcceptMatchError {
     /// <p>The service encountered an unrecoverable internal failure while processing the request. Clients can retry such requests immediately or after a waiting period.</p>
     InternalService(String),
@@ -3384,6 +3385,8 @@ pub enum AcceptMatchError {
     NotFound(String),
     /// <p>The requested operation is not supported in the Region specified.</p>
     UnsupportedRegion(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl AcceptMatchError {
@@ -3391,19 +3394,19 @@ impl AcceptMatchError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceException" => {
-                    return RusotoError::Service(AcceptMatchError::InternalService(err.msg))
+                    return RusotoError::service(AcceptMatchError::InternalService(err.msg), &res)
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(AcceptMatchError::InvalidRequest(err.msg))
+                    return RusotoError::service(AcceptMatchError::InvalidRequest(err.msg), &res)
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(AcceptMatchError::NotFound(err.msg))
+                    return RusotoError::service(AcceptMatchError::NotFound(err.msg), &res)
                 }
                 "UnsupportedRegionException" => {
-                    return RusotoError::Service(AcceptMatchError::UnsupportedRegion(err.msg))
+                    return RusotoError::service(AcceptMatchError::UnsupportedRegion(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(AcceptMatchError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3417,12 +3420,14 @@ impl fmt::Display for AcceptMatchError {
             AcceptMatchError::InvalidRequest(ref cause) => write!(f, "{}", cause),
             AcceptMatchError::NotFound(ref cause) => write!(f, "{}", cause),
             AcceptMatchError::UnsupportedRegion(ref cause) => write!(f, "{}", cause),
+            AcceptMatchError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for AcceptMatchError {}
 /// Errors returned by CreateAlias
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateAliasError {
     /// <p>The requested operation would cause a conflict with the current state of a service resource associated with the request. Resolve the conflict before retrying this request.</p>
     Conflict(String),
@@ -3436,6 +3441,8 @@ pub enum CreateAliasError {
     TaggingFailed(String),
     /// <p>The client failed authentication. Clients should not retry such requests.</p>
     Unauthorized(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateAliasError {
@@ -3443,25 +3450,25 @@ impl CreateAliasError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConflictException" => {
-                    return RusotoError::Service(CreateAliasError::Conflict(err.msg))
+                    return RusotoError::service(CreateAliasError::Conflict(err.msg), &res)
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(CreateAliasError::InternalService(err.msg))
+                    return RusotoError::service(CreateAliasError::InternalService(err.msg), &res)
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(CreateAliasError::InvalidRequest(err.msg))
+                    return RusotoError::service(CreateAliasError::InvalidRequest(err.msg), &res)
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateAliasError::LimitExceeded(err.msg))
+                    return RusotoError::service(CreateAliasError::LimitExceeded(err.msg), &res)
                 }
                 "TaggingFailedException" => {
-                    return RusotoError::Service(CreateAliasError::TaggingFailed(err.msg))
+                    return RusotoError::service(CreateAliasError::TaggingFailed(err.msg), &res)
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(CreateAliasError::Unauthorized(err.msg))
+                    return RusotoError::service(CreateAliasError::Unauthorized(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreateAliasError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3477,12 +3484,14 @@ impl fmt::Display for CreateAliasError {
             CreateAliasError::LimitExceeded(ref cause) => write!(f, "{}", cause),
             CreateAliasError::TaggingFailed(ref cause) => write!(f, "{}", cause),
             CreateAliasError::Unauthorized(ref cause) => write!(f, "{}", cause),
+            CreateAliasError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateAliasError {}
 /// Errors returned by CreateBuild
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateBuildError {
     /// <p>The requested operation would cause a conflict with the current state of a service resource associated with the request. Resolve the conflict before retrying this request.</p>
     Conflict(String),
@@ -3494,6 +3503,8 @@ pub enum CreateBuildError {
     TaggingFailed(String),
     /// <p>The client failed authentication. Clients should not retry such requests.</p>
     Unauthorized(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateBuildError {
@@ -3501,22 +3512,22 @@ impl CreateBuildError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConflictException" => {
-                    return RusotoError::Service(CreateBuildError::Conflict(err.msg))
+                    return RusotoError::service(CreateBuildError::Conflict(err.msg), &res)
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(CreateBuildError::InternalService(err.msg))
+                    return RusotoError::service(CreateBuildError::InternalService(err.msg), &res)
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(CreateBuildError::InvalidRequest(err.msg))
+                    return RusotoError::service(CreateBuildError::InvalidRequest(err.msg), &res)
                 }
                 "TaggingFailedException" => {
-                    return RusotoError::Service(CreateBuildError::TaggingFailed(err.msg))
+                    return RusotoError::service(CreateBuildError::TaggingFailed(err.msg), &res)
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(CreateBuildError::Unauthorized(err.msg))
+                    return RusotoError::service(CreateBuildError::Unauthorized(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreateBuildError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3531,12 +3542,14 @@ impl fmt::Display for CreateBuildError {
             CreateBuildError::InvalidRequest(ref cause) => write!(f, "{}", cause),
             CreateBuildError::TaggingFailed(ref cause) => write!(f, "{}", cause),
             CreateBuildError::Unauthorized(ref cause) => write!(f, "{}", cause),
+            CreateBuildError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateBuildError {}
 /// Errors returned by CreateFleet
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateFleetError {
     /// <p>The requested operation would cause a conflict with the current state of a service resource associated with the request. Resolve the conflict before retrying this request.</p>
     Conflict(String),
@@ -3552,6 +3565,8 @@ pub enum CreateFleetError {
     TaggingFailed(String),
     /// <p>The client failed authentication. Clients should not retry such requests.</p>
     Unauthorized(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateFleetError {
@@ -3559,28 +3574,28 @@ impl CreateFleetError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConflictException" => {
-                    return RusotoError::Service(CreateFleetError::Conflict(err.msg))
+                    return RusotoError::service(CreateFleetError::Conflict(err.msg), &res)
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(CreateFleetError::InternalService(err.msg))
+                    return RusotoError::service(CreateFleetError::InternalService(err.msg), &res)
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(CreateFleetError::InvalidRequest(err.msg))
+                    return RusotoError::service(CreateFleetError::InvalidRequest(err.msg), &res)
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateFleetError::LimitExceeded(err.msg))
+                    return RusotoError::service(CreateFleetError::LimitExceeded(err.msg), &res)
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(CreateFleetError::NotFound(err.msg))
+                    return RusotoError::service(CreateFleetError::NotFound(err.msg), &res)
                 }
                 "TaggingFailedException" => {
-                    return RusotoError::Service(CreateFleetError::TaggingFailed(err.msg))
+                    return RusotoError::service(CreateFleetError::TaggingFailed(err.msg), &res)
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(CreateFleetError::Unauthorized(err.msg))
+                    return RusotoError::service(CreateFleetError::Unauthorized(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreateFleetError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3597,12 +3612,14 @@ impl fmt::Display for CreateFleetError {
             CreateFleetError::NotFound(ref cause) => write!(f, "{}", cause),
             CreateFleetError::TaggingFailed(ref cause) => write!(f, "{}", cause),
             CreateFleetError::Unauthorized(ref cause) => write!(f, "{}", cause),
+            CreateFleetError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateFleetError {}
 /// Errors returned by CreateGameSession
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateGameSessionError {
     /// <p>The requested operation would cause a conflict with the current state of a service resource associated with the request. Resolve the conflict before retrying this request.</p>
     Conflict(String),
@@ -3624,6 +3641,8 @@ pub enum CreateGameSessionError {
     TerminalRoutingStrategy(String),
     /// <p>The client failed authentication. Clients should not retry such requests.</p>
     Unauthorized(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateGameSessionError {
@@ -3631,45 +3650,61 @@ impl CreateGameSessionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConflictException" => {
-                    return RusotoError::Service(CreateGameSessionError::Conflict(err.msg))
+                    return RusotoError::service(CreateGameSessionError::Conflict(err.msg), &res)
                 }
                 "FleetCapacityExceededException" => {
-                    return RusotoError::Service(CreateGameSessionError::FleetCapacityExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateGameSessionError::FleetCapacityExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "IdempotentParameterMismatchException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         CreateGameSessionError::IdempotentParameterMismatch(err.msg),
+                        &res,
                     )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(CreateGameSessionError::InternalService(err.msg))
+                    return RusotoError::service(
+                        CreateGameSessionError::InternalService(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidFleetStatusException" => {
-                    return RusotoError::Service(CreateGameSessionError::InvalidFleetStatus(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateGameSessionError::InvalidFleetStatus(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(CreateGameSessionError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        CreateGameSessionError::InvalidRequest(err.msg),
+                        &res,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateGameSessionError::LimitExceeded(err.msg))
+                    return RusotoError::service(
+                        CreateGameSessionError::LimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(CreateGameSessionError::NotFound(err.msg))
+                    return RusotoError::service(CreateGameSessionError::NotFound(err.msg), &res)
                 }
                 "TerminalRoutingStrategyException" => {
-                    return RusotoError::Service(CreateGameSessionError::TerminalRoutingStrategy(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateGameSessionError::TerminalRoutingStrategy(err.msg),
+                        &res,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(CreateGameSessionError::Unauthorized(err.msg))
+                    return RusotoError::service(
+                        CreateGameSessionError::Unauthorized(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreateGameSessionError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3691,12 +3726,14 @@ impl fmt::Display for CreateGameSessionError {
             CreateGameSessionError::NotFound(ref cause) => write!(f, "{}", cause),
             CreateGameSessionError::TerminalRoutingStrategy(ref cause) => write!(f, "{}", cause),
             CreateGameSessionError::Unauthorized(ref cause) => write!(f, "{}", cause),
+            CreateGameSessionError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateGameSessionError {}
 /// Errors returned by CreateGameSessionQueue
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateGameSessionQueueError {
     /// <p>The service encountered an unrecoverable internal failure while processing the request. Clients can retry such requests immediately or after a waiting period.</p>
     InternalService(String),
@@ -3708,6 +3745,8 @@ pub enum CreateGameSessionQueueError {
     TaggingFailed(String),
     /// <p>The client failed authentication. Clients should not retry such requests.</p>
     Unauthorized(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateGameSessionQueueError {
@@ -3715,30 +3754,37 @@ impl CreateGameSessionQueueError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceException" => {
-                    return RusotoError::Service(CreateGameSessionQueueError::InternalService(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateGameSessionQueueError::InternalService(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(CreateGameSessionQueueError::InvalidRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateGameSessionQueueError::InvalidRequest(err.msg),
+                        &res,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateGameSessionQueueError::LimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateGameSessionQueueError::LimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "TaggingFailedException" => {
-                    return RusotoError::Service(CreateGameSessionQueueError::TaggingFailed(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateGameSessionQueueError::TaggingFailed(err.msg),
+                        &res,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(CreateGameSessionQueueError::Unauthorized(err.msg))
+                    return RusotoError::service(
+                        CreateGameSessionQueueError::Unauthorized(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreateGameSessionQueueError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3753,12 +3799,14 @@ impl fmt::Display for CreateGameSessionQueueError {
             CreateGameSessionQueueError::LimitExceeded(ref cause) => write!(f, "{}", cause),
             CreateGameSessionQueueError::TaggingFailed(ref cause) => write!(f, "{}", cause),
             CreateGameSessionQueueError::Unauthorized(ref cause) => write!(f, "{}", cause),
+            CreateGameSessionQueueError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateGameSessionQueueError {}
 /// Errors returned by CreateMatchmakingConfiguration
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateMatchmakingConfigurationError {
     /// <p>The service encountered an unrecoverable internal failure while processing the request. Clients can retry such requests immediately or after a waiting period.</p>
     InternalService(String),
@@ -3772,6 +3820,8 @@ pub enum CreateMatchmakingConfigurationError {
     TaggingFailed(String),
     /// <p>The requested operation is not supported in the Region specified.</p>
     UnsupportedRegion(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateMatchmakingConfigurationError {
@@ -3781,37 +3831,48 @@ impl CreateMatchmakingConfigurationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         CreateMatchmakingConfigurationError::InternalService(err.msg),
+                        &res,
                     )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         CreateMatchmakingConfigurationError::InvalidRequest(err.msg),
+                        &res,
                     )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         CreateMatchmakingConfigurationError::LimitExceeded(err.msg),
+                        &res,
                     )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(CreateMatchmakingConfigurationError::NotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateMatchmakingConfigurationError::NotFound(err.msg),
+                        &res,
+                    )
                 }
                 "TaggingFailedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         CreateMatchmakingConfigurationError::TaggingFailed(err.msg),
+                        &res,
                     )
                 }
                 "UnsupportedRegionException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         CreateMatchmakingConfigurationError::UnsupportedRegion(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        CreateMatchmakingConfigurationError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -3833,12 +3894,16 @@ impl fmt::Display for CreateMatchmakingConfigurationError {
             CreateMatchmakingConfigurationError::UnsupportedRegion(ref cause) => {
                 write!(f, "{}", cause)
             }
+            CreateMatchmakingConfigurationError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for CreateMatchmakingConfigurationError {}
 /// Errors returned by CreateMatchmakingRuleSet
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateMatchmakingRuleSetError {
     /// <p>The service encountered an unrecoverable internal failure while processing the request. Clients can retry such requests immediately or after a waiting period.</p>
     InternalService(String),
@@ -3848,6 +3913,8 @@ pub enum CreateMatchmakingRuleSetError {
     TaggingFailed(String),
     /// <p>The requested operation is not supported in the Region specified.</p>
     UnsupportedRegion(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateMatchmakingRuleSetError {
@@ -3855,27 +3922,31 @@ impl CreateMatchmakingRuleSetError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceException" => {
-                    return RusotoError::Service(CreateMatchmakingRuleSetError::InternalService(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateMatchmakingRuleSetError::InternalService(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(CreateMatchmakingRuleSetError::InvalidRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateMatchmakingRuleSetError::InvalidRequest(err.msg),
+                        &res,
+                    )
                 }
                 "TaggingFailedException" => {
-                    return RusotoError::Service(CreateMatchmakingRuleSetError::TaggingFailed(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateMatchmakingRuleSetError::TaggingFailed(err.msg),
+                        &res,
+                    )
                 }
                 "UnsupportedRegionException" => {
-                    return RusotoError::Service(CreateMatchmakingRuleSetError::UnsupportedRegion(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateMatchmakingRuleSetError::UnsupportedRegion(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreateMatchmakingRuleSetError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3889,12 +3960,14 @@ impl fmt::Display for CreateMatchmakingRuleSetError {
             CreateMatchmakingRuleSetError::InvalidRequest(ref cause) => write!(f, "{}", cause),
             CreateMatchmakingRuleSetError::TaggingFailed(ref cause) => write!(f, "{}", cause),
             CreateMatchmakingRuleSetError::UnsupportedRegion(ref cause) => write!(f, "{}", cause),
+            CreateMatchmakingRuleSetError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateMatchmakingRuleSetError {}
 /// Errors returned by CreatePlayerSession
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreatePlayerSessionError {
     /// <p>The game instance is currently full and cannot allow the requested player(s) to join. Clients can retry such requests immediately or after a waiting period.</p>
     GameSessionFull(String),
@@ -3910,6 +3983,8 @@ pub enum CreatePlayerSessionError {
     TerminalRoutingStrategy(String),
     /// <p>The client failed authentication. Clients should not retry such requests.</p>
     Unauthorized(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreatePlayerSessionError {
@@ -3917,32 +3992,46 @@ impl CreatePlayerSessionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "GameSessionFullException" => {
-                    return RusotoError::Service(CreatePlayerSessionError::GameSessionFull(err.msg))
+                    return RusotoError::service(
+                        CreatePlayerSessionError::GameSessionFull(err.msg),
+                        &res,
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(CreatePlayerSessionError::InternalService(err.msg))
+                    return RusotoError::service(
+                        CreatePlayerSessionError::InternalService(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidGameSessionStatusException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         CreatePlayerSessionError::InvalidGameSessionStatus(err.msg),
+                        &res,
                     )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(CreatePlayerSessionError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        CreatePlayerSessionError::InvalidRequest(err.msg),
+                        &res,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(CreatePlayerSessionError::NotFound(err.msg))
+                    return RusotoError::service(CreatePlayerSessionError::NotFound(err.msg), &res)
                 }
                 "TerminalRoutingStrategyException" => {
-                    return RusotoError::Service(CreatePlayerSessionError::TerminalRoutingStrategy(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreatePlayerSessionError::TerminalRoutingStrategy(err.msg),
+                        &res,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(CreatePlayerSessionError::Unauthorized(err.msg))
+                    return RusotoError::service(
+                        CreatePlayerSessionError::Unauthorized(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreatePlayerSessionError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -3959,12 +4048,14 @@ impl fmt::Display for CreatePlayerSessionError {
             CreatePlayerSessionError::NotFound(ref cause) => write!(f, "{}", cause),
             CreatePlayerSessionError::TerminalRoutingStrategy(ref cause) => write!(f, "{}", cause),
             CreatePlayerSessionError::Unauthorized(ref cause) => write!(f, "{}", cause),
+            CreatePlayerSessionError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreatePlayerSessionError {}
 /// Errors returned by CreatePlayerSessions
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreatePlayerSessionsError {
     /// <p>The game instance is currently full and cannot allow the requested player(s) to join. Clients can retry such requests immediately or after a waiting period.</p>
     GameSessionFull(String),
@@ -3980,6 +4071,8 @@ pub enum CreatePlayerSessionsError {
     TerminalRoutingStrategy(String),
     /// <p>The client failed authentication. Clients should not retry such requests.</p>
     Unauthorized(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreatePlayerSessionsError {
@@ -3987,36 +4080,46 @@ impl CreatePlayerSessionsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "GameSessionFullException" => {
-                    return RusotoError::Service(CreatePlayerSessionsError::GameSessionFull(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreatePlayerSessionsError::GameSessionFull(err.msg),
+                        &res,
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(CreatePlayerSessionsError::InternalService(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreatePlayerSessionsError::InternalService(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidGameSessionStatusException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         CreatePlayerSessionsError::InvalidGameSessionStatus(err.msg),
+                        &res,
                     )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(CreatePlayerSessionsError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        CreatePlayerSessionsError::InvalidRequest(err.msg),
+                        &res,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(CreatePlayerSessionsError::NotFound(err.msg))
+                    return RusotoError::service(CreatePlayerSessionsError::NotFound(err.msg), &res)
                 }
                 "TerminalRoutingStrategyException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         CreatePlayerSessionsError::TerminalRoutingStrategy(err.msg),
+                        &res,
                     )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(CreatePlayerSessionsError::Unauthorized(err.msg))
+                    return RusotoError::service(
+                        CreatePlayerSessionsError::Unauthorized(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreatePlayerSessionsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -4035,12 +4138,14 @@ impl fmt::Display for CreatePlayerSessionsError {
             CreatePlayerSessionsError::NotFound(ref cause) => write!(f, "{}", cause),
             CreatePlayerSessionsError::TerminalRoutingStrategy(ref cause) => write!(f, "{}", cause),
             CreatePlayerSessionsError::Unauthorized(ref cause) => write!(f, "{}", cause),
+            CreatePlayerSessionsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreatePlayerSessionsError {}
 /// Errors returned by CreateScript
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateScriptError {
     /// <p>The requested operation would cause a conflict with the current state of a service resource associated with the request. Resolve the conflict before retrying this request.</p>
     Conflict(String),
@@ -4052,6 +4157,8 @@ pub enum CreateScriptError {
     TaggingFailed(String),
     /// <p>The client failed authentication. Clients should not retry such requests.</p>
     Unauthorized(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateScriptError {
@@ -4059,22 +4166,22 @@ impl CreateScriptError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConflictException" => {
-                    return RusotoError::Service(CreateScriptError::Conflict(err.msg))
+                    return RusotoError::service(CreateScriptError::Conflict(err.msg), &res)
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(CreateScriptError::InternalService(err.msg))
+                    return RusotoError::service(CreateScriptError::InternalService(err.msg), &res)
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(CreateScriptError::InvalidRequest(err.msg))
+                    return RusotoError::service(CreateScriptError::InvalidRequest(err.msg), &res)
                 }
                 "TaggingFailedException" => {
-                    return RusotoError::Service(CreateScriptError::TaggingFailed(err.msg))
+                    return RusotoError::service(CreateScriptError::TaggingFailed(err.msg), &res)
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(CreateScriptError::Unauthorized(err.msg))
+                    return RusotoError::service(CreateScriptError::Unauthorized(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(CreateScriptError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -4089,12 +4196,14 @@ impl fmt::Display for CreateScriptError {
             CreateScriptError::InvalidRequest(ref cause) => write!(f, "{}", cause),
             CreateScriptError::TaggingFailed(ref cause) => write!(f, "{}", cause),
             CreateScriptError::Unauthorized(ref cause) => write!(f, "{}", cause),
+            CreateScriptError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateScriptError {}
 /// Errors returned by CreateVpcPeeringAuthorization
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateVpcPeeringAuthorizationError {
     /// <p>The service encountered an unrecoverable internal failure while processing the request. Clients can retry such requests immediately or after a waiting period.</p>
     InternalService(String),
@@ -4104,6 +4213,8 @@ pub enum CreateVpcPeeringAuthorizationError {
     NotFound(String),
     /// <p>The client failed authentication. Clients should not retry such requests.</p>
     Unauthorized(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateVpcPeeringAuthorizationError {
@@ -4113,27 +4224,36 @@ impl CreateVpcPeeringAuthorizationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         CreateVpcPeeringAuthorizationError::InternalService(err.msg),
+                        &res,
                     )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         CreateVpcPeeringAuthorizationError::InvalidRequest(err.msg),
+                        &res,
                     )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(CreateVpcPeeringAuthorizationError::NotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateVpcPeeringAuthorizationError::NotFound(err.msg),
+                        &res,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(CreateVpcPeeringAuthorizationError::Unauthorized(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateVpcPeeringAuthorizationError::Unauthorized(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        CreateVpcPeeringAuthorizationError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -4149,12 +4269,16 @@ impl fmt::Display for CreateVpcPeeringAuthorizationError {
             CreateVpcPeeringAuthorizationError::InvalidRequest(ref cause) => write!(f, "{}", cause),
             CreateVpcPeeringAuthorizationError::NotFound(ref cause) => write!(f, "{}", cause),
             CreateVpcPeeringAuthorizationError::Unauthorized(ref cause) => write!(f, "{}", cause),
+            CreateVpcPeeringAuthorizationError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for CreateVpcPeeringAuthorizationError {}
 /// Errors returned by CreateVpcPeeringConnection
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CreateVpcPeeringConnectionError {
     /// <p>The service encountered an unrecoverable internal failure while processing the request. Clients can retry such requests immediately or after a waiting period.</p>
     InternalService(String),
@@ -4164,6 +4288,8 @@ pub enum CreateVpcPeeringConnectionError {
     NotFound(String),
     /// <p>The client failed authentication. Clients should not retry such requests.</p>
     Unauthorized(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl CreateVpcPeeringConnectionError {
@@ -4173,25 +4299,33 @@ impl CreateVpcPeeringConnectionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceException" => {
-                    return RusotoError::Service(CreateVpcPeeringConnectionError::InternalService(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateVpcPeeringConnectionError::InternalService(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(CreateVpcPeeringConnectionError::InvalidRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateVpcPeeringConnectionError::InvalidRequest(err.msg),
+                        &res,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(CreateVpcPeeringConnectionError::NotFound(err.msg))
+                    return RusotoError::service(
+                        CreateVpcPeeringConnectionError::NotFound(err.msg),
+                        &res,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(CreateVpcPeeringConnectionError::Unauthorized(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        CreateVpcPeeringConnectionError::Unauthorized(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(CreateVpcPeeringConnectionError::Unhandled, &res)
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -4205,12 +4339,14 @@ impl fmt::Display for CreateVpcPeeringConnectionError {
             CreateVpcPeeringConnectionError::InvalidRequest(ref cause) => write!(f, "{}", cause),
             CreateVpcPeeringConnectionError::NotFound(ref cause) => write!(f, "{}", cause),
             CreateVpcPeeringConnectionError::Unauthorized(ref cause) => write!(f, "{}", cause),
+            CreateVpcPeeringConnectionError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for CreateVpcPeeringConnectionError {}
 /// Errors returned by DeleteAlias
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteAliasError {
     /// <p>The service encountered an unrecoverable internal failure while processing the request. Clients can retry such requests immediately or after a waiting period.</p>
     InternalService(String),
@@ -4222,6 +4358,8 @@ pub enum DeleteAliasError {
     TaggingFailed(String),
     /// <p>The client failed authentication. Clients should not retry such requests.</p>
     Unauthorized(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteAliasError {
@@ -4229,22 +4367,22 @@ impl DeleteAliasError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceException" => {
-                    return RusotoError::Service(DeleteAliasError::InternalService(err.msg))
+                    return RusotoError::service(DeleteAliasError::InternalService(err.msg), &res)
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DeleteAliasError::InvalidRequest(err.msg))
+                    return RusotoError::service(DeleteAliasError::InvalidRequest(err.msg), &res)
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteAliasError::NotFound(err.msg))
+                    return RusotoError::service(DeleteAliasError::NotFound(err.msg), &res)
                 }
                 "TaggingFailedException" => {
-                    return RusotoError::Service(DeleteAliasError::TaggingFailed(err.msg))
+                    return RusotoError::service(DeleteAliasError::TaggingFailed(err.msg), &res)
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(DeleteAliasError::Unauthorized(err.msg))
+                    return RusotoError::service(DeleteAliasError::Unauthorized(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteAliasError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -4259,12 +4397,14 @@ impl fmt::Display for DeleteAliasError {
             DeleteAliasError::NotFound(ref cause) => write!(f, "{}", cause),
             DeleteAliasError::TaggingFailed(ref cause) => write!(f, "{}", cause),
             DeleteAliasError::Unauthorized(ref cause) => write!(f, "{}", cause),
+            DeleteAliasError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteAliasError {}
 /// Errors returned by DeleteBuild
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteBuildError {
     /// <p>The service encountered an unrecoverable internal failure while processing the request. Clients can retry such requests immediately or after a waiting period.</p>
     InternalService(String),
@@ -4276,6 +4416,8 @@ pub enum DeleteBuildError {
     TaggingFailed(String),
     /// <p>The client failed authentication. Clients should not retry such requests.</p>
     Unauthorized(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteBuildError {
@@ -4283,22 +4425,22 @@ impl DeleteBuildError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceException" => {
-                    return RusotoError::Service(DeleteBuildError::InternalService(err.msg))
+                    return RusotoError::service(DeleteBuildError::InternalService(err.msg), &res)
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DeleteBuildError::InvalidRequest(err.msg))
+                    return RusotoError::service(DeleteBuildError::InvalidRequest(err.msg), &res)
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteBuildError::NotFound(err.msg))
+                    return RusotoError::service(DeleteBuildError::NotFound(err.msg), &res)
                 }
                 "TaggingFailedException" => {
-                    return RusotoError::Service(DeleteBuildError::TaggingFailed(err.msg))
+                    return RusotoError::service(DeleteBuildError::TaggingFailed(err.msg), &res)
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(DeleteBuildError::Unauthorized(err.msg))
+                    return RusotoError::service(DeleteBuildError::Unauthorized(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteBuildError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -4313,12 +4455,14 @@ impl fmt::Display for DeleteBuildError {
             DeleteBuildError::NotFound(ref cause) => write!(f, "{}", cause),
             DeleteBuildError::TaggingFailed(ref cause) => write!(f, "{}", cause),
             DeleteBuildError::Unauthorized(ref cause) => write!(f, "{}", cause),
+            DeleteBuildError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteBuildError {}
 /// Errors returned by DeleteFleet
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteFleetError {
     /// <p>The service encountered an unrecoverable internal failure while processing the request. Clients can retry such requests immediately or after a waiting period.</p>
     InternalService(String),
@@ -4332,6 +4476,8 @@ pub enum DeleteFleetError {
     TaggingFailed(String),
     /// <p>The client failed authentication. Clients should not retry such requests.</p>
     Unauthorized(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteFleetError {
@@ -4339,25 +4485,28 @@ impl DeleteFleetError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceException" => {
-                    return RusotoError::Service(DeleteFleetError::InternalService(err.msg))
+                    return RusotoError::service(DeleteFleetError::InternalService(err.msg), &res)
                 }
                 "InvalidFleetStatusException" => {
-                    return RusotoError::Service(DeleteFleetError::InvalidFleetStatus(err.msg))
+                    return RusotoError::service(
+                        DeleteFleetError::InvalidFleetStatus(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DeleteFleetError::InvalidRequest(err.msg))
+                    return RusotoError::service(DeleteFleetError::InvalidRequest(err.msg), &res)
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteFleetError::NotFound(err.msg))
+                    return RusotoError::service(DeleteFleetError::NotFound(err.msg), &res)
                 }
                 "TaggingFailedException" => {
-                    return RusotoError::Service(DeleteFleetError::TaggingFailed(err.msg))
+                    return RusotoError::service(DeleteFleetError::TaggingFailed(err.msg), &res)
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(DeleteFleetError::Unauthorized(err.msg))
+                    return RusotoError::service(DeleteFleetError::Unauthorized(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteFleetError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -4373,12 +4522,14 @@ impl fmt::Display for DeleteFleetError {
             DeleteFleetError::NotFound(ref cause) => write!(f, "{}", cause),
             DeleteFleetError::TaggingFailed(ref cause) => write!(f, "{}", cause),
             DeleteFleetError::Unauthorized(ref cause) => write!(f, "{}", cause),
+            DeleteFleetError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteFleetError {}
 /// Errors returned by DeleteGameSessionQueue
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteGameSessionQueueError {
     /// <p>The service encountered an unrecoverable internal failure while processing the request. Clients can retry such requests immediately or after a waiting period.</p>
     InternalService(String),
@@ -4390,6 +4541,8 @@ pub enum DeleteGameSessionQueueError {
     TaggingFailed(String),
     /// <p>The client failed authentication. Clients should not retry such requests.</p>
     Unauthorized(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteGameSessionQueueError {
@@ -4397,28 +4550,37 @@ impl DeleteGameSessionQueueError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceException" => {
-                    return RusotoError::Service(DeleteGameSessionQueueError::InternalService(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteGameSessionQueueError::InternalService(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DeleteGameSessionQueueError::InvalidRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteGameSessionQueueError::InvalidRequest(err.msg),
+                        &res,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteGameSessionQueueError::NotFound(err.msg))
+                    return RusotoError::service(
+                        DeleteGameSessionQueueError::NotFound(err.msg),
+                        &res,
+                    )
                 }
                 "TaggingFailedException" => {
-                    return RusotoError::Service(DeleteGameSessionQueueError::TaggingFailed(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteGameSessionQueueError::TaggingFailed(err.msg),
+                        &res,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(DeleteGameSessionQueueError::Unauthorized(err.msg))
+                    return RusotoError::service(
+                        DeleteGameSessionQueueError::Unauthorized(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteGameSessionQueueError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -4433,12 +4595,14 @@ impl fmt::Display for DeleteGameSessionQueueError {
             DeleteGameSessionQueueError::NotFound(ref cause) => write!(f, "{}", cause),
             DeleteGameSessionQueueError::TaggingFailed(ref cause) => write!(f, "{}", cause),
             DeleteGameSessionQueueError::Unauthorized(ref cause) => write!(f, "{}", cause),
+            DeleteGameSessionQueueError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteGameSessionQueueError {}
 /// Errors returned by DeleteMatchmakingConfiguration
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteMatchmakingConfigurationError {
     /// <p>The service encountered an unrecoverable internal failure while processing the request. Clients can retry such requests immediately or after a waiting period.</p>
     InternalService(String),
@@ -4450,6 +4614,8 @@ pub enum DeleteMatchmakingConfigurationError {
     TaggingFailed(String),
     /// <p>The requested operation is not supported in the Region specified.</p>
     UnsupportedRegion(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteMatchmakingConfigurationError {
@@ -4459,32 +4625,42 @@ impl DeleteMatchmakingConfigurationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DeleteMatchmakingConfigurationError::InternalService(err.msg),
+                        &res,
                     )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DeleteMatchmakingConfigurationError::InvalidRequest(err.msg),
+                        &res,
                     )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteMatchmakingConfigurationError::NotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteMatchmakingConfigurationError::NotFound(err.msg),
+                        &res,
+                    )
                 }
                 "TaggingFailedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DeleteMatchmakingConfigurationError::TaggingFailed(err.msg),
+                        &res,
                     )
                 }
                 "UnsupportedRegionException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DeleteMatchmakingConfigurationError::UnsupportedRegion(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        DeleteMatchmakingConfigurationError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -4505,12 +4681,16 @@ impl fmt::Display for DeleteMatchmakingConfigurationError {
             DeleteMatchmakingConfigurationError::UnsupportedRegion(ref cause) => {
                 write!(f, "{}", cause)
             }
+            DeleteMatchmakingConfigurationError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for DeleteMatchmakingConfigurationError {}
 /// Errors returned by DeleteMatchmakingRuleSet
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteMatchmakingRuleSetError {
     /// <p>The service encountered an unrecoverable internal failure while processing the request. Clients can retry such requests immediately or after a waiting period.</p>
     InternalService(String),
@@ -4522,6 +4702,8 @@ pub enum DeleteMatchmakingRuleSetError {
     TaggingFailed(String),
     /// <p>The requested operation is not supported in the Region specified.</p>
     UnsupportedRegion(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteMatchmakingRuleSetError {
@@ -4529,30 +4711,37 @@ impl DeleteMatchmakingRuleSetError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceException" => {
-                    return RusotoError::Service(DeleteMatchmakingRuleSetError::InternalService(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteMatchmakingRuleSetError::InternalService(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DeleteMatchmakingRuleSetError::InvalidRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteMatchmakingRuleSetError::InvalidRequest(err.msg),
+                        &res,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteMatchmakingRuleSetError::NotFound(err.msg))
+                    return RusotoError::service(
+                        DeleteMatchmakingRuleSetError::NotFound(err.msg),
+                        &res,
+                    )
                 }
                 "TaggingFailedException" => {
-                    return RusotoError::Service(DeleteMatchmakingRuleSetError::TaggingFailed(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteMatchmakingRuleSetError::TaggingFailed(err.msg),
+                        &res,
+                    )
                 }
                 "UnsupportedRegionException" => {
-                    return RusotoError::Service(DeleteMatchmakingRuleSetError::UnsupportedRegion(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteMatchmakingRuleSetError::UnsupportedRegion(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteMatchmakingRuleSetError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -4567,12 +4756,14 @@ impl fmt::Display for DeleteMatchmakingRuleSetError {
             DeleteMatchmakingRuleSetError::NotFound(ref cause) => write!(f, "{}", cause),
             DeleteMatchmakingRuleSetError::TaggingFailed(ref cause) => write!(f, "{}", cause),
             DeleteMatchmakingRuleSetError::UnsupportedRegion(ref cause) => write!(f, "{}", cause),
+            DeleteMatchmakingRuleSetError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteMatchmakingRuleSetError {}
 /// Errors returned by DeleteScalingPolicy
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteScalingPolicyError {
     /// <p>The service encountered an unrecoverable internal failure while processing the request. Clients can retry such requests immediately or after a waiting period.</p>
     InternalService(String),
@@ -4582,6 +4773,8 @@ pub enum DeleteScalingPolicyError {
     NotFound(String),
     /// <p>The client failed authentication. Clients should not retry such requests.</p>
     Unauthorized(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteScalingPolicyError {
@@ -4589,19 +4782,28 @@ impl DeleteScalingPolicyError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceException" => {
-                    return RusotoError::Service(DeleteScalingPolicyError::InternalService(err.msg))
+                    return RusotoError::service(
+                        DeleteScalingPolicyError::InternalService(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DeleteScalingPolicyError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        DeleteScalingPolicyError::InvalidRequest(err.msg),
+                        &res,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteScalingPolicyError::NotFound(err.msg))
+                    return RusotoError::service(DeleteScalingPolicyError::NotFound(err.msg), &res)
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(DeleteScalingPolicyError::Unauthorized(err.msg))
+                    return RusotoError::service(
+                        DeleteScalingPolicyError::Unauthorized(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteScalingPolicyError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -4615,12 +4817,14 @@ impl fmt::Display for DeleteScalingPolicyError {
             DeleteScalingPolicyError::InvalidRequest(ref cause) => write!(f, "{}", cause),
             DeleteScalingPolicyError::NotFound(ref cause) => write!(f, "{}", cause),
             DeleteScalingPolicyError::Unauthorized(ref cause) => write!(f, "{}", cause),
+            DeleteScalingPolicyError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteScalingPolicyError {}
 /// Errors returned by DeleteScript
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteScriptError {
     /// <p>The service encountered an unrecoverable internal failure while processing the request. Clients can retry such requests immediately or after a waiting period.</p>
     InternalService(String),
@@ -4632,6 +4836,8 @@ pub enum DeleteScriptError {
     TaggingFailed(String),
     /// <p>The client failed authentication. Clients should not retry such requests.</p>
     Unauthorized(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteScriptError {
@@ -4639,22 +4845,22 @@ impl DeleteScriptError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceException" => {
-                    return RusotoError::Service(DeleteScriptError::InternalService(err.msg))
+                    return RusotoError::service(DeleteScriptError::InternalService(err.msg), &res)
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DeleteScriptError::InvalidRequest(err.msg))
+                    return RusotoError::service(DeleteScriptError::InvalidRequest(err.msg), &res)
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteScriptError::NotFound(err.msg))
+                    return RusotoError::service(DeleteScriptError::NotFound(err.msg), &res)
                 }
                 "TaggingFailedException" => {
-                    return RusotoError::Service(DeleteScriptError::TaggingFailed(err.msg))
+                    return RusotoError::service(DeleteScriptError::TaggingFailed(err.msg), &res)
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(DeleteScriptError::Unauthorized(err.msg))
+                    return RusotoError::service(DeleteScriptError::Unauthorized(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DeleteScriptError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -4669,12 +4875,14 @@ impl fmt::Display for DeleteScriptError {
             DeleteScriptError::NotFound(ref cause) => write!(f, "{}", cause),
             DeleteScriptError::TaggingFailed(ref cause) => write!(f, "{}", cause),
             DeleteScriptError::Unauthorized(ref cause) => write!(f, "{}", cause),
+            DeleteScriptError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteScriptError {}
 /// Errors returned by DeleteVpcPeeringAuthorization
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteVpcPeeringAuthorizationError {
     /// <p>The service encountered an unrecoverable internal failure while processing the request. Clients can retry such requests immediately or after a waiting period.</p>
     InternalService(String),
@@ -4684,6 +4892,8 @@ pub enum DeleteVpcPeeringAuthorizationError {
     NotFound(String),
     /// <p>The client failed authentication. Clients should not retry such requests.</p>
     Unauthorized(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteVpcPeeringAuthorizationError {
@@ -4693,27 +4903,36 @@ impl DeleteVpcPeeringAuthorizationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DeleteVpcPeeringAuthorizationError::InternalService(err.msg),
+                        &res,
                     )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DeleteVpcPeeringAuthorizationError::InvalidRequest(err.msg),
+                        &res,
                     )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteVpcPeeringAuthorizationError::NotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteVpcPeeringAuthorizationError::NotFound(err.msg),
+                        &res,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(DeleteVpcPeeringAuthorizationError::Unauthorized(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteVpcPeeringAuthorizationError::Unauthorized(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        DeleteVpcPeeringAuthorizationError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -4729,12 +4948,16 @@ impl fmt::Display for DeleteVpcPeeringAuthorizationError {
             DeleteVpcPeeringAuthorizationError::InvalidRequest(ref cause) => write!(f, "{}", cause),
             DeleteVpcPeeringAuthorizationError::NotFound(ref cause) => write!(f, "{}", cause),
             DeleteVpcPeeringAuthorizationError::Unauthorized(ref cause) => write!(f, "{}", cause),
+            DeleteVpcPeeringAuthorizationError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for DeleteVpcPeeringAuthorizationError {}
 /// Errors returned by DeleteVpcPeeringConnection
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DeleteVpcPeeringConnectionError {
     /// <p>The service encountered an unrecoverable internal failure while processing the request. Clients can retry such requests immediately or after a waiting period.</p>
     InternalService(String),
@@ -4744,6 +4967,8 @@ pub enum DeleteVpcPeeringConnectionError {
     NotFound(String),
     /// <p>The client failed authentication. Clients should not retry such requests.</p>
     Unauthorized(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DeleteVpcPeeringConnectionError {
@@ -4753,25 +4978,33 @@ impl DeleteVpcPeeringConnectionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceException" => {
-                    return RusotoError::Service(DeleteVpcPeeringConnectionError::InternalService(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteVpcPeeringConnectionError::InternalService(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DeleteVpcPeeringConnectionError::InvalidRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteVpcPeeringConnectionError::InvalidRequest(err.msg),
+                        &res,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteVpcPeeringConnectionError::NotFound(err.msg))
+                    return RusotoError::service(
+                        DeleteVpcPeeringConnectionError::NotFound(err.msg),
+                        &res,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(DeleteVpcPeeringConnectionError::Unauthorized(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DeleteVpcPeeringConnectionError::Unauthorized(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(DeleteVpcPeeringConnectionError::Unhandled, &res)
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -4785,12 +5018,14 @@ impl fmt::Display for DeleteVpcPeeringConnectionError {
             DeleteVpcPeeringConnectionError::InvalidRequest(ref cause) => write!(f, "{}", cause),
             DeleteVpcPeeringConnectionError::NotFound(ref cause) => write!(f, "{}", cause),
             DeleteVpcPeeringConnectionError::Unauthorized(ref cause) => write!(f, "{}", cause),
+            DeleteVpcPeeringConnectionError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DeleteVpcPeeringConnectionError {}
 /// Errors returned by DescribeAlias
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeAliasError {
     /// <p>The service encountered an unrecoverable internal failure while processing the request. Clients can retry such requests immediately or after a waiting period.</p>
     InternalService(String),
@@ -4800,6 +5035,8 @@ pub enum DescribeAliasError {
     NotFound(String),
     /// <p>The client failed authentication. Clients should not retry such requests.</p>
     Unauthorized(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeAliasError {
@@ -4807,19 +5044,19 @@ impl DescribeAliasError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceException" => {
-                    return RusotoError::Service(DescribeAliasError::InternalService(err.msg))
+                    return RusotoError::service(DescribeAliasError::InternalService(err.msg), &res)
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DescribeAliasError::InvalidRequest(err.msg))
+                    return RusotoError::service(DescribeAliasError::InvalidRequest(err.msg), &res)
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DescribeAliasError::NotFound(err.msg))
+                    return RusotoError::service(DescribeAliasError::NotFound(err.msg), &res)
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(DescribeAliasError::Unauthorized(err.msg))
+                    return RusotoError::service(DescribeAliasError::Unauthorized(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DescribeAliasError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -4833,12 +5070,14 @@ impl fmt::Display for DescribeAliasError {
             DescribeAliasError::InvalidRequest(ref cause) => write!(f, "{}", cause),
             DescribeAliasError::NotFound(ref cause) => write!(f, "{}", cause),
             DescribeAliasError::Unauthorized(ref cause) => write!(f, "{}", cause),
+            DescribeAliasError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeAliasError {}
 /// Errors returned by DescribeBuild
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeBuildError {
     /// <p>The service encountered an unrecoverable internal failure while processing the request. Clients can retry such requests immediately or after a waiting period.</p>
     InternalService(String),
@@ -4848,6 +5087,8 @@ pub enum DescribeBuildError {
     NotFound(String),
     /// <p>The client failed authentication. Clients should not retry such requests.</p>
     Unauthorized(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeBuildError {
@@ -4855,19 +5096,19 @@ impl DescribeBuildError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceException" => {
-                    return RusotoError::Service(DescribeBuildError::InternalService(err.msg))
+                    return RusotoError::service(DescribeBuildError::InternalService(err.msg), &res)
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DescribeBuildError::InvalidRequest(err.msg))
+                    return RusotoError::service(DescribeBuildError::InvalidRequest(err.msg), &res)
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DescribeBuildError::NotFound(err.msg))
+                    return RusotoError::service(DescribeBuildError::NotFound(err.msg), &res)
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(DescribeBuildError::Unauthorized(err.msg))
+                    return RusotoError::service(DescribeBuildError::Unauthorized(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DescribeBuildError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -4881,12 +5122,14 @@ impl fmt::Display for DescribeBuildError {
             DescribeBuildError::InvalidRequest(ref cause) => write!(f, "{}", cause),
             DescribeBuildError::NotFound(ref cause) => write!(f, "{}", cause),
             DescribeBuildError::Unauthorized(ref cause) => write!(f, "{}", cause),
+            DescribeBuildError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeBuildError {}
 /// Errors returned by DescribeEC2InstanceLimits
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeEC2InstanceLimitsError {
     /// <p>The service encountered an unrecoverable internal failure while processing the request. Clients can retry such requests immediately or after a waiting period.</p>
     InternalService(String),
@@ -4894,6 +5137,8 @@ pub enum DescribeEC2InstanceLimitsError {
     InvalidRequest(String),
     /// <p>The client failed authentication. Clients should not retry such requests.</p>
     Unauthorized(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeEC2InstanceLimitsError {
@@ -4901,22 +5146,27 @@ impl DescribeEC2InstanceLimitsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceException" => {
-                    return RusotoError::Service(DescribeEC2InstanceLimitsError::InternalService(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeEC2InstanceLimitsError::InternalService(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DescribeEC2InstanceLimitsError::InvalidRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeEC2InstanceLimitsError::InvalidRequest(err.msg),
+                        &res,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(DescribeEC2InstanceLimitsError::Unauthorized(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeEC2InstanceLimitsError::Unauthorized(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(DescribeEC2InstanceLimitsError::Unhandled, &res)
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -4929,12 +5179,14 @@ impl fmt::Display for DescribeEC2InstanceLimitsError {
             DescribeEC2InstanceLimitsError::InternalService(ref cause) => write!(f, "{}", cause),
             DescribeEC2InstanceLimitsError::InvalidRequest(ref cause) => write!(f, "{}", cause),
             DescribeEC2InstanceLimitsError::Unauthorized(ref cause) => write!(f, "{}", cause),
+            DescribeEC2InstanceLimitsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeEC2InstanceLimitsError {}
 /// Errors returned by DescribeFleetAttributes
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeFleetAttributesError {
     /// <p>The service encountered an unrecoverable internal failure while processing the request. Clients can retry such requests immediately or after a waiting period.</p>
     InternalService(String),
@@ -4944,6 +5196,8 @@ pub enum DescribeFleetAttributesError {
     NotFound(String),
     /// <p>The client failed authentication. Clients should not retry such requests.</p>
     Unauthorized(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeFleetAttributesError {
@@ -4951,25 +5205,31 @@ impl DescribeFleetAttributesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceException" => {
-                    return RusotoError::Service(DescribeFleetAttributesError::InternalService(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeFleetAttributesError::InternalService(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DescribeFleetAttributesError::InvalidRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeFleetAttributesError::InvalidRequest(err.msg),
+                        &res,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DescribeFleetAttributesError::NotFound(err.msg))
+                    return RusotoError::service(
+                        DescribeFleetAttributesError::NotFound(err.msg),
+                        &res,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(DescribeFleetAttributesError::Unauthorized(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeFleetAttributesError::Unauthorized(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DescribeFleetAttributesError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -4983,12 +5243,14 @@ impl fmt::Display for DescribeFleetAttributesError {
             DescribeFleetAttributesError::InvalidRequest(ref cause) => write!(f, "{}", cause),
             DescribeFleetAttributesError::NotFound(ref cause) => write!(f, "{}", cause),
             DescribeFleetAttributesError::Unauthorized(ref cause) => write!(f, "{}", cause),
+            DescribeFleetAttributesError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeFleetAttributesError {}
 /// Errors returned by DescribeFleetCapacity
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeFleetCapacityError {
     /// <p>The service encountered an unrecoverable internal failure while processing the request. Clients can retry such requests immediately or after a waiting period.</p>
     InternalService(String),
@@ -4998,6 +5260,8 @@ pub enum DescribeFleetCapacityError {
     NotFound(String),
     /// <p>The client failed authentication. Clients should not retry such requests.</p>
     Unauthorized(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeFleetCapacityError {
@@ -5005,23 +5269,31 @@ impl DescribeFleetCapacityError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceException" => {
-                    return RusotoError::Service(DescribeFleetCapacityError::InternalService(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeFleetCapacityError::InternalService(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DescribeFleetCapacityError::InvalidRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeFleetCapacityError::InvalidRequest(err.msg),
+                        &res,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DescribeFleetCapacityError::NotFound(err.msg))
+                    return RusotoError::service(
+                        DescribeFleetCapacityError::NotFound(err.msg),
+                        &res,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(DescribeFleetCapacityError::Unauthorized(err.msg))
+                    return RusotoError::service(
+                        DescribeFleetCapacityError::Unauthorized(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DescribeFleetCapacityError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -5035,12 +5307,14 @@ impl fmt::Display for DescribeFleetCapacityError {
             DescribeFleetCapacityError::InvalidRequest(ref cause) => write!(f, "{}", cause),
             DescribeFleetCapacityError::NotFound(ref cause) => write!(f, "{}", cause),
             DescribeFleetCapacityError::Unauthorized(ref cause) => write!(f, "{}", cause),
+            DescribeFleetCapacityError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeFleetCapacityError {}
 /// Errors returned by DescribeFleetEvents
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeFleetEventsError {
     /// <p>The service encountered an unrecoverable internal failure while processing the request. Clients can retry such requests immediately or after a waiting period.</p>
     InternalService(String),
@@ -5050,6 +5324,8 @@ pub enum DescribeFleetEventsError {
     NotFound(String),
     /// <p>The client failed authentication. Clients should not retry such requests.</p>
     Unauthorized(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeFleetEventsError {
@@ -5057,19 +5333,28 @@ impl DescribeFleetEventsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceException" => {
-                    return RusotoError::Service(DescribeFleetEventsError::InternalService(err.msg))
+                    return RusotoError::service(
+                        DescribeFleetEventsError::InternalService(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DescribeFleetEventsError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        DescribeFleetEventsError::InvalidRequest(err.msg),
+                        &res,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DescribeFleetEventsError::NotFound(err.msg))
+                    return RusotoError::service(DescribeFleetEventsError::NotFound(err.msg), &res)
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(DescribeFleetEventsError::Unauthorized(err.msg))
+                    return RusotoError::service(
+                        DescribeFleetEventsError::Unauthorized(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DescribeFleetEventsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -5083,12 +5368,14 @@ impl fmt::Display for DescribeFleetEventsError {
             DescribeFleetEventsError::InvalidRequest(ref cause) => write!(f, "{}", cause),
             DescribeFleetEventsError::NotFound(ref cause) => write!(f, "{}", cause),
             DescribeFleetEventsError::Unauthorized(ref cause) => write!(f, "{}", cause),
+            DescribeFleetEventsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeFleetEventsError {}
 /// Errors returned by DescribeFleetPortSettings
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeFleetPortSettingsError {
     /// <p>The service encountered an unrecoverable internal failure while processing the request. Clients can retry such requests immediately or after a waiting period.</p>
     InternalService(String),
@@ -5098,6 +5385,8 @@ pub enum DescribeFleetPortSettingsError {
     NotFound(String),
     /// <p>The client failed authentication. Clients should not retry such requests.</p>
     Unauthorized(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeFleetPortSettingsError {
@@ -5105,25 +5394,33 @@ impl DescribeFleetPortSettingsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceException" => {
-                    return RusotoError::Service(DescribeFleetPortSettingsError::InternalService(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeFleetPortSettingsError::InternalService(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DescribeFleetPortSettingsError::InvalidRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeFleetPortSettingsError::InvalidRequest(err.msg),
+                        &res,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DescribeFleetPortSettingsError::NotFound(err.msg))
+                    return RusotoError::service(
+                        DescribeFleetPortSettingsError::NotFound(err.msg),
+                        &res,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(DescribeFleetPortSettingsError::Unauthorized(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeFleetPortSettingsError::Unauthorized(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(DescribeFleetPortSettingsError::Unhandled, &res)
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -5137,12 +5434,14 @@ impl fmt::Display for DescribeFleetPortSettingsError {
             DescribeFleetPortSettingsError::InvalidRequest(ref cause) => write!(f, "{}", cause),
             DescribeFleetPortSettingsError::NotFound(ref cause) => write!(f, "{}", cause),
             DescribeFleetPortSettingsError::Unauthorized(ref cause) => write!(f, "{}", cause),
+            DescribeFleetPortSettingsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeFleetPortSettingsError {}
 /// Errors returned by DescribeFleetUtilization
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeFleetUtilizationError {
     /// <p>The service encountered an unrecoverable internal failure while processing the request. Clients can retry such requests immediately or after a waiting period.</p>
     InternalService(String),
@@ -5152,6 +5451,8 @@ pub enum DescribeFleetUtilizationError {
     NotFound(String),
     /// <p>The client failed authentication. Clients should not retry such requests.</p>
     Unauthorized(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeFleetUtilizationError {
@@ -5159,25 +5460,31 @@ impl DescribeFleetUtilizationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceException" => {
-                    return RusotoError::Service(DescribeFleetUtilizationError::InternalService(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeFleetUtilizationError::InternalService(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DescribeFleetUtilizationError::InvalidRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeFleetUtilizationError::InvalidRequest(err.msg),
+                        &res,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DescribeFleetUtilizationError::NotFound(err.msg))
+                    return RusotoError::service(
+                        DescribeFleetUtilizationError::NotFound(err.msg),
+                        &res,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(DescribeFleetUtilizationError::Unauthorized(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeFleetUtilizationError::Unauthorized(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DescribeFleetUtilizationError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -5191,12 +5498,14 @@ impl fmt::Display for DescribeFleetUtilizationError {
             DescribeFleetUtilizationError::InvalidRequest(ref cause) => write!(f, "{}", cause),
             DescribeFleetUtilizationError::NotFound(ref cause) => write!(f, "{}", cause),
             DescribeFleetUtilizationError::Unauthorized(ref cause) => write!(f, "{}", cause),
+            DescribeFleetUtilizationError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeFleetUtilizationError {}
 /// Errors returned by DescribeGameSessionDetails
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeGameSessionDetailsError {
     /// <p>The service encountered an unrecoverable internal failure while processing the request. Clients can retry such requests immediately or after a waiting period.</p>
     InternalService(String),
@@ -5208,6 +5517,8 @@ pub enum DescribeGameSessionDetailsError {
     TerminalRoutingStrategy(String),
     /// <p>The client failed authentication. Clients should not retry such requests.</p>
     Unauthorized(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeGameSessionDetailsError {
@@ -5217,30 +5528,39 @@ impl DescribeGameSessionDetailsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceException" => {
-                    return RusotoError::Service(DescribeGameSessionDetailsError::InternalService(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeGameSessionDetailsError::InternalService(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DescribeGameSessionDetailsError::InvalidRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeGameSessionDetailsError::InvalidRequest(err.msg),
+                        &res,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DescribeGameSessionDetailsError::NotFound(err.msg))
+                    return RusotoError::service(
+                        DescribeGameSessionDetailsError::NotFound(err.msg),
+                        &res,
+                    )
                 }
                 "TerminalRoutingStrategyException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DescribeGameSessionDetailsError::TerminalRoutingStrategy(err.msg),
+                        &res,
                     )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(DescribeGameSessionDetailsError::Unauthorized(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeGameSessionDetailsError::Unauthorized(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(DescribeGameSessionDetailsError::Unhandled, &res)
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -5257,12 +5577,14 @@ impl fmt::Display for DescribeGameSessionDetailsError {
                 write!(f, "{}", cause)
             }
             DescribeGameSessionDetailsError::Unauthorized(ref cause) => write!(f, "{}", cause),
+            DescribeGameSessionDetailsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeGameSessionDetailsError {}
 /// Errors returned by DescribeGameSessionPlacement
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeGameSessionPlacementError {
     /// <p>The service encountered an unrecoverable internal failure while processing the request. Clients can retry such requests immediately or after a waiting period.</p>
     InternalService(String),
@@ -5272,6 +5594,8 @@ pub enum DescribeGameSessionPlacementError {
     NotFound(String),
     /// <p>The client failed authentication. Clients should not retry such requests.</p>
     Unauthorized(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeGameSessionPlacementError {
@@ -5281,27 +5605,36 @@ impl DescribeGameSessionPlacementError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DescribeGameSessionPlacementError::InternalService(err.msg),
+                        &res,
                     )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DescribeGameSessionPlacementError::InvalidRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeGameSessionPlacementError::InvalidRequest(err.msg),
+                        &res,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DescribeGameSessionPlacementError::NotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeGameSessionPlacementError::NotFound(err.msg),
+                        &res,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(DescribeGameSessionPlacementError::Unauthorized(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeGameSessionPlacementError::Unauthorized(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        DescribeGameSessionPlacementError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -5315,12 +5648,14 @@ impl fmt::Display for DescribeGameSessionPlacementError {
             DescribeGameSessionPlacementError::InvalidRequest(ref cause) => write!(f, "{}", cause),
             DescribeGameSessionPlacementError::NotFound(ref cause) => write!(f, "{}", cause),
             DescribeGameSessionPlacementError::Unauthorized(ref cause) => write!(f, "{}", cause),
+            DescribeGameSessionPlacementError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeGameSessionPlacementError {}
 /// Errors returned by DescribeGameSessionQueues
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeGameSessionQueuesError {
     /// <p>The service encountered an unrecoverable internal failure while processing the request. Clients can retry such requests immediately or after a waiting period.</p>
     InternalService(String),
@@ -5330,6 +5665,8 @@ pub enum DescribeGameSessionQueuesError {
     NotFound(String),
     /// <p>The client failed authentication. Clients should not retry such requests.</p>
     Unauthorized(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeGameSessionQueuesError {
@@ -5337,25 +5674,33 @@ impl DescribeGameSessionQueuesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceException" => {
-                    return RusotoError::Service(DescribeGameSessionQueuesError::InternalService(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeGameSessionQueuesError::InternalService(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DescribeGameSessionQueuesError::InvalidRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeGameSessionQueuesError::InvalidRequest(err.msg),
+                        &res,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DescribeGameSessionQueuesError::NotFound(err.msg))
+                    return RusotoError::service(
+                        DescribeGameSessionQueuesError::NotFound(err.msg),
+                        &res,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(DescribeGameSessionQueuesError::Unauthorized(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeGameSessionQueuesError::Unauthorized(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(DescribeGameSessionQueuesError::Unhandled, &res)
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -5369,12 +5714,14 @@ impl fmt::Display for DescribeGameSessionQueuesError {
             DescribeGameSessionQueuesError::InvalidRequest(ref cause) => write!(f, "{}", cause),
             DescribeGameSessionQueuesError::NotFound(ref cause) => write!(f, "{}", cause),
             DescribeGameSessionQueuesError::Unauthorized(ref cause) => write!(f, "{}", cause),
+            DescribeGameSessionQueuesError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeGameSessionQueuesError {}
 /// Errors returned by DescribeGameSessions
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeGameSessionsError {
     /// <p>The service encountered an unrecoverable internal failure while processing the request. Clients can retry such requests immediately or after a waiting period.</p>
     InternalService(String),
@@ -5386,6 +5733,8 @@ pub enum DescribeGameSessionsError {
     TerminalRoutingStrategy(String),
     /// <p>The client failed authentication. Clients should not retry such requests.</p>
     Unauthorized(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeGameSessionsError {
@@ -5393,26 +5742,34 @@ impl DescribeGameSessionsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceException" => {
-                    return RusotoError::Service(DescribeGameSessionsError::InternalService(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeGameSessionsError::InternalService(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DescribeGameSessionsError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        DescribeGameSessionsError::InvalidRequest(err.msg),
+                        &res,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DescribeGameSessionsError::NotFound(err.msg))
+                    return RusotoError::service(DescribeGameSessionsError::NotFound(err.msg), &res)
                 }
                 "TerminalRoutingStrategyException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DescribeGameSessionsError::TerminalRoutingStrategy(err.msg),
+                        &res,
                     )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(DescribeGameSessionsError::Unauthorized(err.msg))
+                    return RusotoError::service(
+                        DescribeGameSessionsError::Unauthorized(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DescribeGameSessionsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -5427,12 +5784,14 @@ impl fmt::Display for DescribeGameSessionsError {
             DescribeGameSessionsError::NotFound(ref cause) => write!(f, "{}", cause),
             DescribeGameSessionsError::TerminalRoutingStrategy(ref cause) => write!(f, "{}", cause),
             DescribeGameSessionsError::Unauthorized(ref cause) => write!(f, "{}", cause),
+            DescribeGameSessionsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeGameSessionsError {}
 /// Errors returned by DescribeInstances
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeInstancesError {
     /// <p>The service encountered an unrecoverable internal failure while processing the request. Clients can retry such requests immediately or after a waiting period.</p>
     InternalService(String),
@@ -5442,6 +5801,8 @@ pub enum DescribeInstancesError {
     NotFound(String),
     /// <p>The client failed authentication. Clients should not retry such requests.</p>
     Unauthorized(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeInstancesError {
@@ -5449,19 +5810,28 @@ impl DescribeInstancesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceException" => {
-                    return RusotoError::Service(DescribeInstancesError::InternalService(err.msg))
+                    return RusotoError::service(
+                        DescribeInstancesError::InternalService(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DescribeInstancesError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        DescribeInstancesError::InvalidRequest(err.msg),
+                        &res,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DescribeInstancesError::NotFound(err.msg))
+                    return RusotoError::service(DescribeInstancesError::NotFound(err.msg), &res)
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(DescribeInstancesError::Unauthorized(err.msg))
+                    return RusotoError::service(
+                        DescribeInstancesError::Unauthorized(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DescribeInstancesError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -5475,12 +5845,14 @@ impl fmt::Display for DescribeInstancesError {
             DescribeInstancesError::InvalidRequest(ref cause) => write!(f, "{}", cause),
             DescribeInstancesError::NotFound(ref cause) => write!(f, "{}", cause),
             DescribeInstancesError::Unauthorized(ref cause) => write!(f, "{}", cause),
+            DescribeInstancesError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeInstancesError {}
 /// Errors returned by DescribeMatchmaking
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeMatchmakingError {
     /// <p>The service encountered an unrecoverable internal failure while processing the request. Clients can retry such requests immediately or after a waiting period.</p>
     InternalService(String),
@@ -5488,6 +5860,8 @@ pub enum DescribeMatchmakingError {
     InvalidRequest(String),
     /// <p>The requested operation is not supported in the Region specified.</p>
     UnsupportedRegion(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeMatchmakingError {
@@ -5495,18 +5869,25 @@ impl DescribeMatchmakingError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceException" => {
-                    return RusotoError::Service(DescribeMatchmakingError::InternalService(err.msg))
+                    return RusotoError::service(
+                        DescribeMatchmakingError::InternalService(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DescribeMatchmakingError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        DescribeMatchmakingError::InvalidRequest(err.msg),
+                        &res,
+                    )
                 }
                 "UnsupportedRegionException" => {
-                    return RusotoError::Service(DescribeMatchmakingError::UnsupportedRegion(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeMatchmakingError::UnsupportedRegion(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DescribeMatchmakingError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -5519,12 +5900,14 @@ impl fmt::Display for DescribeMatchmakingError {
             DescribeMatchmakingError::InternalService(ref cause) => write!(f, "{}", cause),
             DescribeMatchmakingError::InvalidRequest(ref cause) => write!(f, "{}", cause),
             DescribeMatchmakingError::UnsupportedRegion(ref cause) => write!(f, "{}", cause),
+            DescribeMatchmakingError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeMatchmakingError {}
 /// Errors returned by DescribeMatchmakingConfigurations
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeMatchmakingConfigurationsError {
     /// <p>The service encountered an unrecoverable internal failure while processing the request. Clients can retry such requests immediately or after a waiting period.</p>
     InternalService(String),
@@ -5532,6 +5915,8 @@ pub enum DescribeMatchmakingConfigurationsError {
     InvalidRequest(String),
     /// <p>The requested operation is not supported in the Region specified.</p>
     UnsupportedRegion(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeMatchmakingConfigurationsError {
@@ -5541,22 +5926,30 @@ impl DescribeMatchmakingConfigurationsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DescribeMatchmakingConfigurationsError::InternalService(err.msg),
+                        &res,
                     )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DescribeMatchmakingConfigurationsError::InvalidRequest(err.msg),
+                        &res,
                     )
                 }
                 "UnsupportedRegionException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DescribeMatchmakingConfigurationsError::UnsupportedRegion(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        DescribeMatchmakingConfigurationsError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -5575,12 +5968,16 @@ impl fmt::Display for DescribeMatchmakingConfigurationsError {
             DescribeMatchmakingConfigurationsError::UnsupportedRegion(ref cause) => {
                 write!(f, "{}", cause)
             }
+            DescribeMatchmakingConfigurationsError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for DescribeMatchmakingConfigurationsError {}
 /// Errors returned by DescribeMatchmakingRuleSets
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeMatchmakingRuleSetsError {
     /// <p>The service encountered an unrecoverable internal failure while processing the request. Clients can retry such requests immediately or after a waiting period.</p>
     InternalService(String),
@@ -5590,6 +5987,8 @@ pub enum DescribeMatchmakingRuleSetsError {
     NotFound(String),
     /// <p>The requested operation is not supported in the Region specified.</p>
     UnsupportedRegion(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeMatchmakingRuleSetsError {
@@ -5599,27 +5998,36 @@ impl DescribeMatchmakingRuleSetsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceException" => {
-                    return RusotoError::Service(DescribeMatchmakingRuleSetsError::InternalService(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeMatchmakingRuleSetsError::InternalService(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DescribeMatchmakingRuleSetsError::InvalidRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeMatchmakingRuleSetsError::InvalidRequest(err.msg),
+                        &res,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DescribeMatchmakingRuleSetsError::NotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeMatchmakingRuleSetsError::NotFound(err.msg),
+                        &res,
+                    )
                 }
                 "UnsupportedRegionException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DescribeMatchmakingRuleSetsError::UnsupportedRegion(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        DescribeMatchmakingRuleSetsError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -5635,12 +6043,14 @@ impl fmt::Display for DescribeMatchmakingRuleSetsError {
             DescribeMatchmakingRuleSetsError::UnsupportedRegion(ref cause) => {
                 write!(f, "{}", cause)
             }
+            DescribeMatchmakingRuleSetsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeMatchmakingRuleSetsError {}
 /// Errors returned by DescribePlayerSessions
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribePlayerSessionsError {
     /// <p>The service encountered an unrecoverable internal failure while processing the request. Clients can retry such requests immediately or after a waiting period.</p>
     InternalService(String),
@@ -5650,6 +6060,8 @@ pub enum DescribePlayerSessionsError {
     NotFound(String),
     /// <p>The client failed authentication. Clients should not retry such requests.</p>
     Unauthorized(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribePlayerSessionsError {
@@ -5657,23 +6069,31 @@ impl DescribePlayerSessionsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceException" => {
-                    return RusotoError::Service(DescribePlayerSessionsError::InternalService(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribePlayerSessionsError::InternalService(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DescribePlayerSessionsError::InvalidRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribePlayerSessionsError::InvalidRequest(err.msg),
+                        &res,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DescribePlayerSessionsError::NotFound(err.msg))
+                    return RusotoError::service(
+                        DescribePlayerSessionsError::NotFound(err.msg),
+                        &res,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(DescribePlayerSessionsError::Unauthorized(err.msg))
+                    return RusotoError::service(
+                        DescribePlayerSessionsError::Unauthorized(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DescribePlayerSessionsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -5687,12 +6107,14 @@ impl fmt::Display for DescribePlayerSessionsError {
             DescribePlayerSessionsError::InvalidRequest(ref cause) => write!(f, "{}", cause),
             DescribePlayerSessionsError::NotFound(ref cause) => write!(f, "{}", cause),
             DescribePlayerSessionsError::Unauthorized(ref cause) => write!(f, "{}", cause),
+            DescribePlayerSessionsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribePlayerSessionsError {}
 /// Errors returned by DescribeRuntimeConfiguration
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeRuntimeConfigurationError {
     /// <p>The service encountered an unrecoverable internal failure while processing the request. Clients can retry such requests immediately or after a waiting period.</p>
     InternalService(String),
@@ -5702,6 +6124,8 @@ pub enum DescribeRuntimeConfigurationError {
     NotFound(String),
     /// <p>The client failed authentication. Clients should not retry such requests.</p>
     Unauthorized(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeRuntimeConfigurationError {
@@ -5711,27 +6135,36 @@ impl DescribeRuntimeConfigurationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DescribeRuntimeConfigurationError::InternalService(err.msg),
+                        &res,
                     )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DescribeRuntimeConfigurationError::InvalidRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeRuntimeConfigurationError::InvalidRequest(err.msg),
+                        &res,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DescribeRuntimeConfigurationError::NotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeRuntimeConfigurationError::NotFound(err.msg),
+                        &res,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(DescribeRuntimeConfigurationError::Unauthorized(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeRuntimeConfigurationError::Unauthorized(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        DescribeRuntimeConfigurationError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -5745,12 +6178,14 @@ impl fmt::Display for DescribeRuntimeConfigurationError {
             DescribeRuntimeConfigurationError::InvalidRequest(ref cause) => write!(f, "{}", cause),
             DescribeRuntimeConfigurationError::NotFound(ref cause) => write!(f, "{}", cause),
             DescribeRuntimeConfigurationError::Unauthorized(ref cause) => write!(f, "{}", cause),
+            DescribeRuntimeConfigurationError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeRuntimeConfigurationError {}
 /// Errors returned by DescribeScalingPolicies
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeScalingPoliciesError {
     /// <p>The service encountered an unrecoverable internal failure while processing the request. Clients can retry such requests immediately or after a waiting period.</p>
     InternalService(String),
@@ -5760,6 +6195,8 @@ pub enum DescribeScalingPoliciesError {
     NotFound(String),
     /// <p>The client failed authentication. Clients should not retry such requests.</p>
     Unauthorized(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeScalingPoliciesError {
@@ -5767,25 +6204,31 @@ impl DescribeScalingPoliciesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceException" => {
-                    return RusotoError::Service(DescribeScalingPoliciesError::InternalService(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeScalingPoliciesError::InternalService(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DescribeScalingPoliciesError::InvalidRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeScalingPoliciesError::InvalidRequest(err.msg),
+                        &res,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DescribeScalingPoliciesError::NotFound(err.msg))
+                    return RusotoError::service(
+                        DescribeScalingPoliciesError::NotFound(err.msg),
+                        &res,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(DescribeScalingPoliciesError::Unauthorized(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeScalingPoliciesError::Unauthorized(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DescribeScalingPoliciesError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -5799,12 +6242,14 @@ impl fmt::Display for DescribeScalingPoliciesError {
             DescribeScalingPoliciesError::InvalidRequest(ref cause) => write!(f, "{}", cause),
             DescribeScalingPoliciesError::NotFound(ref cause) => write!(f, "{}", cause),
             DescribeScalingPoliciesError::Unauthorized(ref cause) => write!(f, "{}", cause),
+            DescribeScalingPoliciesError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeScalingPoliciesError {}
 /// Errors returned by DescribeScript
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeScriptError {
     /// <p>The service encountered an unrecoverable internal failure while processing the request. Clients can retry such requests immediately or after a waiting period.</p>
     InternalService(String),
@@ -5814,6 +6259,8 @@ pub enum DescribeScriptError {
     NotFound(String),
     /// <p>The client failed authentication. Clients should not retry such requests.</p>
     Unauthorized(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeScriptError {
@@ -5821,19 +6268,22 @@ impl DescribeScriptError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceException" => {
-                    return RusotoError::Service(DescribeScriptError::InternalService(err.msg))
+                    return RusotoError::service(
+                        DescribeScriptError::InternalService(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DescribeScriptError::InvalidRequest(err.msg))
+                    return RusotoError::service(DescribeScriptError::InvalidRequest(err.msg), &res)
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DescribeScriptError::NotFound(err.msg))
+                    return RusotoError::service(DescribeScriptError::NotFound(err.msg), &res)
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(DescribeScriptError::Unauthorized(err.msg))
+                    return RusotoError::service(DescribeScriptError::Unauthorized(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(DescribeScriptError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -5847,12 +6297,14 @@ impl fmt::Display for DescribeScriptError {
             DescribeScriptError::InvalidRequest(ref cause) => write!(f, "{}", cause),
             DescribeScriptError::NotFound(ref cause) => write!(f, "{}", cause),
             DescribeScriptError::Unauthorized(ref cause) => write!(f, "{}", cause),
+            DescribeScriptError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for DescribeScriptError {}
 /// Errors returned by DescribeVpcPeeringAuthorizations
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeVpcPeeringAuthorizationsError {
     /// <p>The service encountered an unrecoverable internal failure while processing the request. Clients can retry such requests immediately or after a waiting period.</p>
     InternalService(String),
@@ -5860,6 +6312,8 @@ pub enum DescribeVpcPeeringAuthorizationsError {
     InvalidRequest(String),
     /// <p>The client failed authentication. Clients should not retry such requests.</p>
     Unauthorized(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeVpcPeeringAuthorizationsError {
@@ -5869,22 +6323,30 @@ impl DescribeVpcPeeringAuthorizationsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DescribeVpcPeeringAuthorizationsError::InternalService(err.msg),
+                        &res,
                     )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DescribeVpcPeeringAuthorizationsError::InvalidRequest(err.msg),
+                        &res,
                     )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DescribeVpcPeeringAuthorizationsError::Unauthorized(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        DescribeVpcPeeringAuthorizationsError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -5903,12 +6365,16 @@ impl fmt::Display for DescribeVpcPeeringAuthorizationsError {
             DescribeVpcPeeringAuthorizationsError::Unauthorized(ref cause) => {
                 write!(f, "{}", cause)
             }
+            DescribeVpcPeeringAuthorizationsError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for DescribeVpcPeeringAuthorizationsError {}
 /// Errors returned by DescribeVpcPeeringConnections
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum DescribeVpcPeeringConnectionsError {
     /// <p>The service encountered an unrecoverable internal failure while processing the request. Clients can retry such requests immediately or after a waiting period.</p>
     InternalService(String),
@@ -5918,6 +6384,8 @@ pub enum DescribeVpcPeeringConnectionsError {
     NotFound(String),
     /// <p>The client failed authentication. Clients should not retry such requests.</p>
     Unauthorized(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl DescribeVpcPeeringConnectionsError {
@@ -5927,27 +6395,36 @@ impl DescribeVpcPeeringConnectionsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DescribeVpcPeeringConnectionsError::InternalService(err.msg),
+                        &res,
                     )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         DescribeVpcPeeringConnectionsError::InvalidRequest(err.msg),
+                        &res,
                     )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DescribeVpcPeeringConnectionsError::NotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeVpcPeeringConnectionsError::NotFound(err.msg),
+                        &res,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(DescribeVpcPeeringConnectionsError::Unauthorized(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        DescribeVpcPeeringConnectionsError::Unauthorized(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        DescribeVpcPeeringConnectionsError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -5963,12 +6440,16 @@ impl fmt::Display for DescribeVpcPeeringConnectionsError {
             DescribeVpcPeeringConnectionsError::InvalidRequest(ref cause) => write!(f, "{}", cause),
             DescribeVpcPeeringConnectionsError::NotFound(ref cause) => write!(f, "{}", cause),
             DescribeVpcPeeringConnectionsError::Unauthorized(ref cause) => write!(f, "{}", cause),
+            DescribeVpcPeeringConnectionsError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for DescribeVpcPeeringConnectionsError {}
 /// Errors returned by GetGameSessionLogUrl
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetGameSessionLogUrlError {
     /// <p>The service encountered an unrecoverable internal failure while processing the request. Clients can retry such requests immediately or after a waiting period.</p>
     InternalService(String),
@@ -5978,6 +6459,8 @@ pub enum GetGameSessionLogUrlError {
     NotFound(String),
     /// <p>The client failed authentication. Clients should not retry such requests.</p>
     Unauthorized(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetGameSessionLogUrlError {
@@ -5985,21 +6468,28 @@ impl GetGameSessionLogUrlError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceException" => {
-                    return RusotoError::Service(GetGameSessionLogUrlError::InternalService(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        GetGameSessionLogUrlError::InternalService(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(GetGameSessionLogUrlError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        GetGameSessionLogUrlError::InvalidRequest(err.msg),
+                        &res,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetGameSessionLogUrlError::NotFound(err.msg))
+                    return RusotoError::service(GetGameSessionLogUrlError::NotFound(err.msg), &res)
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(GetGameSessionLogUrlError::Unauthorized(err.msg))
+                    return RusotoError::service(
+                        GetGameSessionLogUrlError::Unauthorized(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetGameSessionLogUrlError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -6013,12 +6503,14 @@ impl fmt::Display for GetGameSessionLogUrlError {
             GetGameSessionLogUrlError::InvalidRequest(ref cause) => write!(f, "{}", cause),
             GetGameSessionLogUrlError::NotFound(ref cause) => write!(f, "{}", cause),
             GetGameSessionLogUrlError::Unauthorized(ref cause) => write!(f, "{}", cause),
+            GetGameSessionLogUrlError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetGameSessionLogUrlError {}
 /// Errors returned by GetInstanceAccess
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum GetInstanceAccessError {
     /// <p>The service encountered an unrecoverable internal failure while processing the request. Clients can retry such requests immediately or after a waiting period.</p>
     InternalService(String),
@@ -6028,6 +6520,8 @@ pub enum GetInstanceAccessError {
     NotFound(String),
     /// <p>The client failed authentication. Clients should not retry such requests.</p>
     Unauthorized(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl GetInstanceAccessError {
@@ -6035,19 +6529,28 @@ impl GetInstanceAccessError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceException" => {
-                    return RusotoError::Service(GetInstanceAccessError::InternalService(err.msg))
+                    return RusotoError::service(
+                        GetInstanceAccessError::InternalService(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(GetInstanceAccessError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        GetInstanceAccessError::InvalidRequest(err.msg),
+                        &res,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetInstanceAccessError::NotFound(err.msg))
+                    return RusotoError::service(GetInstanceAccessError::NotFound(err.msg), &res)
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(GetInstanceAccessError::Unauthorized(err.msg))
+                    return RusotoError::service(
+                        GetInstanceAccessError::Unauthorized(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(GetInstanceAccessError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -6061,12 +6564,14 @@ impl fmt::Display for GetInstanceAccessError {
             GetInstanceAccessError::InvalidRequest(ref cause) => write!(f, "{}", cause),
             GetInstanceAccessError::NotFound(ref cause) => write!(f, "{}", cause),
             GetInstanceAccessError::Unauthorized(ref cause) => write!(f, "{}", cause),
+            GetInstanceAccessError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for GetInstanceAccessError {}
 /// Errors returned by ListAliases
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListAliasesError {
     /// <p>The service encountered an unrecoverable internal failure while processing the request. Clients can retry such requests immediately or after a waiting period.</p>
     InternalService(String),
@@ -6074,6 +6579,8 @@ pub enum ListAliasesError {
     InvalidRequest(String),
     /// <p>The client failed authentication. Clients should not retry such requests.</p>
     Unauthorized(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListAliasesError {
@@ -6081,16 +6588,16 @@ impl ListAliasesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceException" => {
-                    return RusotoError::Service(ListAliasesError::InternalService(err.msg))
+                    return RusotoError::service(ListAliasesError::InternalService(err.msg), &res)
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(ListAliasesError::InvalidRequest(err.msg))
+                    return RusotoError::service(ListAliasesError::InvalidRequest(err.msg), &res)
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(ListAliasesError::Unauthorized(err.msg))
+                    return RusotoError::service(ListAliasesError::Unauthorized(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListAliasesError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -6103,12 +6610,14 @@ impl fmt::Display for ListAliasesError {
             ListAliasesError::InternalService(ref cause) => write!(f, "{}", cause),
             ListAliasesError::InvalidRequest(ref cause) => write!(f, "{}", cause),
             ListAliasesError::Unauthorized(ref cause) => write!(f, "{}", cause),
+            ListAliasesError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListAliasesError {}
 /// Errors returned by ListBuilds
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListBuildsError {
     /// <p>The service encountered an unrecoverable internal failure while processing the request. Clients can retry such requests immediately or after a waiting period.</p>
     InternalService(String),
@@ -6116,6 +6625,8 @@ pub enum ListBuildsError {
     InvalidRequest(String),
     /// <p>The client failed authentication. Clients should not retry such requests.</p>
     Unauthorized(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListBuildsError {
@@ -6123,16 +6634,16 @@ impl ListBuildsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceException" => {
-                    return RusotoError::Service(ListBuildsError::InternalService(err.msg))
+                    return RusotoError::service(ListBuildsError::InternalService(err.msg), &res)
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(ListBuildsError::InvalidRequest(err.msg))
+                    return RusotoError::service(ListBuildsError::InvalidRequest(err.msg), &res)
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(ListBuildsError::Unauthorized(err.msg))
+                    return RusotoError::service(ListBuildsError::Unauthorized(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListBuildsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -6145,12 +6656,14 @@ impl fmt::Display for ListBuildsError {
             ListBuildsError::InternalService(ref cause) => write!(f, "{}", cause),
             ListBuildsError::InvalidRequest(ref cause) => write!(f, "{}", cause),
             ListBuildsError::Unauthorized(ref cause) => write!(f, "{}", cause),
+            ListBuildsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListBuildsError {}
 /// Errors returned by ListFleets
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListFleetsError {
     /// <p>The service encountered an unrecoverable internal failure while processing the request. Clients can retry such requests immediately or after a waiting period.</p>
     InternalService(String),
@@ -6160,6 +6673,8 @@ pub enum ListFleetsError {
     NotFound(String),
     /// <p>The client failed authentication. Clients should not retry such requests.</p>
     Unauthorized(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListFleetsError {
@@ -6167,19 +6682,19 @@ impl ListFleetsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceException" => {
-                    return RusotoError::Service(ListFleetsError::InternalService(err.msg))
+                    return RusotoError::service(ListFleetsError::InternalService(err.msg), &res)
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(ListFleetsError::InvalidRequest(err.msg))
+                    return RusotoError::service(ListFleetsError::InvalidRequest(err.msg), &res)
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(ListFleetsError::NotFound(err.msg))
+                    return RusotoError::service(ListFleetsError::NotFound(err.msg), &res)
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(ListFleetsError::Unauthorized(err.msg))
+                    return RusotoError::service(ListFleetsError::Unauthorized(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListFleetsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -6193,12 +6708,14 @@ impl fmt::Display for ListFleetsError {
             ListFleetsError::InvalidRequest(ref cause) => write!(f, "{}", cause),
             ListFleetsError::NotFound(ref cause) => write!(f, "{}", cause),
             ListFleetsError::Unauthorized(ref cause) => write!(f, "{}", cause),
+            ListFleetsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListFleetsError {}
 /// Errors returned by ListScripts
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListScriptsError {
     /// <p>The service encountered an unrecoverable internal failure while processing the request. Clients can retry such requests immediately or after a waiting period.</p>
     InternalService(String),
@@ -6206,6 +6723,8 @@ pub enum ListScriptsError {
     InvalidRequest(String),
     /// <p>The client failed authentication. Clients should not retry such requests.</p>
     Unauthorized(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListScriptsError {
@@ -6213,16 +6732,16 @@ impl ListScriptsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceException" => {
-                    return RusotoError::Service(ListScriptsError::InternalService(err.msg))
+                    return RusotoError::service(ListScriptsError::InternalService(err.msg), &res)
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(ListScriptsError::InvalidRequest(err.msg))
+                    return RusotoError::service(ListScriptsError::InvalidRequest(err.msg), &res)
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(ListScriptsError::Unauthorized(err.msg))
+                    return RusotoError::service(ListScriptsError::Unauthorized(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListScriptsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -6235,12 +6754,14 @@ impl fmt::Display for ListScriptsError {
             ListScriptsError::InternalService(ref cause) => write!(f, "{}", cause),
             ListScriptsError::InvalidRequest(ref cause) => write!(f, "{}", cause),
             ListScriptsError::Unauthorized(ref cause) => write!(f, "{}", cause),
+            ListScriptsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListScriptsError {}
 /// Errors returned by ListTagsForResource
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ListTagsForResourceError {
     /// <p>The service encountered an unrecoverable internal failure while processing the request. Clients can retry such requests immediately or after a waiting period.</p>
     InternalService(String),
@@ -6250,6 +6771,8 @@ pub enum ListTagsForResourceError {
     NotFound(String),
     /// <p> The requested tagging operation did not succeed. This may be due to invalid tag format or the maximum tag limit may have been exceeded. Resolve the issue before retrying. </p>
     TaggingFailed(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ListTagsForResourceError {
@@ -6257,19 +6780,28 @@ impl ListTagsForResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceException" => {
-                    return RusotoError::Service(ListTagsForResourceError::InternalService(err.msg))
+                    return RusotoError::service(
+                        ListTagsForResourceError::InternalService(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(ListTagsForResourceError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        ListTagsForResourceError::InvalidRequest(err.msg),
+                        &res,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(ListTagsForResourceError::NotFound(err.msg))
+                    return RusotoError::service(ListTagsForResourceError::NotFound(err.msg), &res)
                 }
                 "TaggingFailedException" => {
-                    return RusotoError::Service(ListTagsForResourceError::TaggingFailed(err.msg))
+                    return RusotoError::service(
+                        ListTagsForResourceError::TaggingFailed(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ListTagsForResourceError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -6283,12 +6815,14 @@ impl fmt::Display for ListTagsForResourceError {
             ListTagsForResourceError::InvalidRequest(ref cause) => write!(f, "{}", cause),
             ListTagsForResourceError::NotFound(ref cause) => write!(f, "{}", cause),
             ListTagsForResourceError::TaggingFailed(ref cause) => write!(f, "{}", cause),
+            ListTagsForResourceError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ListTagsForResourceError {}
 /// Errors returned by PutScalingPolicy
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum PutScalingPolicyError {
     /// <p>The service encountered an unrecoverable internal failure while processing the request. Clients can retry such requests immediately or after a waiting period.</p>
     InternalService(String),
@@ -6298,6 +6832,8 @@ pub enum PutScalingPolicyError {
     NotFound(String),
     /// <p>The client failed authentication. Clients should not retry such requests.</p>
     Unauthorized(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl PutScalingPolicyError {
@@ -6305,19 +6841,25 @@ impl PutScalingPolicyError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceException" => {
-                    return RusotoError::Service(PutScalingPolicyError::InternalService(err.msg))
+                    return RusotoError::service(
+                        PutScalingPolicyError::InternalService(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(PutScalingPolicyError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        PutScalingPolicyError::InvalidRequest(err.msg),
+                        &res,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(PutScalingPolicyError::NotFound(err.msg))
+                    return RusotoError::service(PutScalingPolicyError::NotFound(err.msg), &res)
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(PutScalingPolicyError::Unauthorized(err.msg))
+                    return RusotoError::service(PutScalingPolicyError::Unauthorized(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(PutScalingPolicyError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -6331,12 +6873,14 @@ impl fmt::Display for PutScalingPolicyError {
             PutScalingPolicyError::InvalidRequest(ref cause) => write!(f, "{}", cause),
             PutScalingPolicyError::NotFound(ref cause) => write!(f, "{}", cause),
             PutScalingPolicyError::Unauthorized(ref cause) => write!(f, "{}", cause),
+            PutScalingPolicyError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for PutScalingPolicyError {}
 /// Errors returned by RequestUploadCredentials
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum RequestUploadCredentialsError {
     /// <p>The service encountered an unrecoverable internal failure while processing the request. Clients can retry such requests immediately or after a waiting period.</p>
     InternalService(String),
@@ -6346,6 +6890,8 @@ pub enum RequestUploadCredentialsError {
     NotFound(String),
     /// <p>The client failed authentication. Clients should not retry such requests.</p>
     Unauthorized(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl RequestUploadCredentialsError {
@@ -6353,25 +6899,31 @@ impl RequestUploadCredentialsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceException" => {
-                    return RusotoError::Service(RequestUploadCredentialsError::InternalService(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        RequestUploadCredentialsError::InternalService(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(RequestUploadCredentialsError::InvalidRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        RequestUploadCredentialsError::InvalidRequest(err.msg),
+                        &res,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(RequestUploadCredentialsError::NotFound(err.msg))
+                    return RusotoError::service(
+                        RequestUploadCredentialsError::NotFound(err.msg),
+                        &res,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(RequestUploadCredentialsError::Unauthorized(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        RequestUploadCredentialsError::Unauthorized(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(RequestUploadCredentialsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -6385,12 +6937,14 @@ impl fmt::Display for RequestUploadCredentialsError {
             RequestUploadCredentialsError::InvalidRequest(ref cause) => write!(f, "{}", cause),
             RequestUploadCredentialsError::NotFound(ref cause) => write!(f, "{}", cause),
             RequestUploadCredentialsError::Unauthorized(ref cause) => write!(f, "{}", cause),
+            RequestUploadCredentialsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for RequestUploadCredentialsError {}
 /// Errors returned by ResolveAlias
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ResolveAliasError {
     /// <p>The service encountered an unrecoverable internal failure while processing the request. Clients can retry such requests immediately or after a waiting period.</p>
     InternalService(String),
@@ -6402,6 +6956,8 @@ pub enum ResolveAliasError {
     TerminalRoutingStrategy(String),
     /// <p>The client failed authentication. Clients should not retry such requests.</p>
     Unauthorized(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ResolveAliasError {
@@ -6409,24 +6965,25 @@ impl ResolveAliasError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceException" => {
-                    return RusotoError::Service(ResolveAliasError::InternalService(err.msg))
+                    return RusotoError::service(ResolveAliasError::InternalService(err.msg), &res)
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(ResolveAliasError::InvalidRequest(err.msg))
+                    return RusotoError::service(ResolveAliasError::InvalidRequest(err.msg), &res)
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(ResolveAliasError::NotFound(err.msg))
+                    return RusotoError::service(ResolveAliasError::NotFound(err.msg), &res)
                 }
                 "TerminalRoutingStrategyException" => {
-                    return RusotoError::Service(ResolveAliasError::TerminalRoutingStrategy(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ResolveAliasError::TerminalRoutingStrategy(err.msg),
+                        &res,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(ResolveAliasError::Unauthorized(err.msg))
+                    return RusotoError::service(ResolveAliasError::Unauthorized(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(ResolveAliasError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -6441,12 +6998,14 @@ impl fmt::Display for ResolveAliasError {
             ResolveAliasError::NotFound(ref cause) => write!(f, "{}", cause),
             ResolveAliasError::TerminalRoutingStrategy(ref cause) => write!(f, "{}", cause),
             ResolveAliasError::Unauthorized(ref cause) => write!(f, "{}", cause),
+            ResolveAliasError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for ResolveAliasError {}
 /// Errors returned by SearchGameSessions
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum SearchGameSessionsError {
     /// <p>The service encountered an unrecoverable internal failure while processing the request. Clients can retry such requests immediately or after a waiting period.</p>
     InternalService(String),
@@ -6458,6 +7017,8 @@ pub enum SearchGameSessionsError {
     TerminalRoutingStrategy(String),
     /// <p>The client failed authentication. Clients should not retry such requests.</p>
     Unauthorized(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl SearchGameSessionsError {
@@ -6465,24 +7026,34 @@ impl SearchGameSessionsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceException" => {
-                    return RusotoError::Service(SearchGameSessionsError::InternalService(err.msg))
+                    return RusotoError::service(
+                        SearchGameSessionsError::InternalService(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(SearchGameSessionsError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        SearchGameSessionsError::InvalidRequest(err.msg),
+                        &res,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(SearchGameSessionsError::NotFound(err.msg))
+                    return RusotoError::service(SearchGameSessionsError::NotFound(err.msg), &res)
                 }
                 "TerminalRoutingStrategyException" => {
-                    return RusotoError::Service(SearchGameSessionsError::TerminalRoutingStrategy(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        SearchGameSessionsError::TerminalRoutingStrategy(err.msg),
+                        &res,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(SearchGameSessionsError::Unauthorized(err.msg))
+                    return RusotoError::service(
+                        SearchGameSessionsError::Unauthorized(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(SearchGameSessionsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -6497,12 +7068,14 @@ impl fmt::Display for SearchGameSessionsError {
             SearchGameSessionsError::NotFound(ref cause) => write!(f, "{}", cause),
             SearchGameSessionsError::TerminalRoutingStrategy(ref cause) => write!(f, "{}", cause),
             SearchGameSessionsError::Unauthorized(ref cause) => write!(f, "{}", cause),
+            SearchGameSessionsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for SearchGameSessionsError {}
 /// Errors returned by StartFleetActions
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum StartFleetActionsError {
     /// <p>The service encountered an unrecoverable internal failure while processing the request. Clients can retry such requests immediately or after a waiting period.</p>
     InternalService(String),
@@ -6512,6 +7085,8 @@ pub enum StartFleetActionsError {
     NotFound(String),
     /// <p>The client failed authentication. Clients should not retry such requests.</p>
     Unauthorized(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl StartFleetActionsError {
@@ -6519,19 +7094,28 @@ impl StartFleetActionsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceException" => {
-                    return RusotoError::Service(StartFleetActionsError::InternalService(err.msg))
+                    return RusotoError::service(
+                        StartFleetActionsError::InternalService(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(StartFleetActionsError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        StartFleetActionsError::InvalidRequest(err.msg),
+                        &res,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(StartFleetActionsError::NotFound(err.msg))
+                    return RusotoError::service(StartFleetActionsError::NotFound(err.msg), &res)
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(StartFleetActionsError::Unauthorized(err.msg))
+                    return RusotoError::service(
+                        StartFleetActionsError::Unauthorized(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(StartFleetActionsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -6545,12 +7129,14 @@ impl fmt::Display for StartFleetActionsError {
             StartFleetActionsError::InvalidRequest(ref cause) => write!(f, "{}", cause),
             StartFleetActionsError::NotFound(ref cause) => write!(f, "{}", cause),
             StartFleetActionsError::Unauthorized(ref cause) => write!(f, "{}", cause),
+            StartFleetActionsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for StartFleetActionsError {}
 /// Errors returned by StartGameSessionPlacement
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum StartGameSessionPlacementError {
     /// <p>The service encountered an unrecoverable internal failure while processing the request. Clients can retry such requests immediately or after a waiting period.</p>
     InternalService(String),
@@ -6560,6 +7146,8 @@ pub enum StartGameSessionPlacementError {
     NotFound(String),
     /// <p>The client failed authentication. Clients should not retry such requests.</p>
     Unauthorized(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl StartGameSessionPlacementError {
@@ -6567,25 +7155,33 @@ impl StartGameSessionPlacementError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceException" => {
-                    return RusotoError::Service(StartGameSessionPlacementError::InternalService(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        StartGameSessionPlacementError::InternalService(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(StartGameSessionPlacementError::InvalidRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        StartGameSessionPlacementError::InvalidRequest(err.msg),
+                        &res,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(StartGameSessionPlacementError::NotFound(err.msg))
+                    return RusotoError::service(
+                        StartGameSessionPlacementError::NotFound(err.msg),
+                        &res,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(StartGameSessionPlacementError::Unauthorized(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        StartGameSessionPlacementError::Unauthorized(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(StartGameSessionPlacementError::Unhandled, &res)
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -6599,12 +7195,14 @@ impl fmt::Display for StartGameSessionPlacementError {
             StartGameSessionPlacementError::InvalidRequest(ref cause) => write!(f, "{}", cause),
             StartGameSessionPlacementError::NotFound(ref cause) => write!(f, "{}", cause),
             StartGameSessionPlacementError::Unauthorized(ref cause) => write!(f, "{}", cause),
+            StartGameSessionPlacementError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for StartGameSessionPlacementError {}
 /// Errors returned by StartMatchBackfill
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum StartMatchBackfillError {
     /// <p>The service encountered an unrecoverable internal failure while processing the request. Clients can retry such requests immediately or after a waiting period.</p>
     InternalService(String),
@@ -6614,6 +7212,8 @@ pub enum StartMatchBackfillError {
     NotFound(String),
     /// <p>The requested operation is not supported in the Region specified.</p>
     UnsupportedRegion(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl StartMatchBackfillError {
@@ -6621,21 +7221,28 @@ impl StartMatchBackfillError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceException" => {
-                    return RusotoError::Service(StartMatchBackfillError::InternalService(err.msg))
+                    return RusotoError::service(
+                        StartMatchBackfillError::InternalService(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(StartMatchBackfillError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        StartMatchBackfillError::InvalidRequest(err.msg),
+                        &res,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(StartMatchBackfillError::NotFound(err.msg))
+                    return RusotoError::service(StartMatchBackfillError::NotFound(err.msg), &res)
                 }
                 "UnsupportedRegionException" => {
-                    return RusotoError::Service(StartMatchBackfillError::UnsupportedRegion(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        StartMatchBackfillError::UnsupportedRegion(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(StartMatchBackfillError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -6649,12 +7256,14 @@ impl fmt::Display for StartMatchBackfillError {
             StartMatchBackfillError::InvalidRequest(ref cause) => write!(f, "{}", cause),
             StartMatchBackfillError::NotFound(ref cause) => write!(f, "{}", cause),
             StartMatchBackfillError::UnsupportedRegion(ref cause) => write!(f, "{}", cause),
+            StartMatchBackfillError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for StartMatchBackfillError {}
 /// Errors returned by StartMatchmaking
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum StartMatchmakingError {
     /// <p>The service encountered an unrecoverable internal failure while processing the request. Clients can retry such requests immediately or after a waiting period.</p>
     InternalService(String),
@@ -6664,6 +7273,8 @@ pub enum StartMatchmakingError {
     NotFound(String),
     /// <p>The requested operation is not supported in the Region specified.</p>
     UnsupportedRegion(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl StartMatchmakingError {
@@ -6671,19 +7282,28 @@ impl StartMatchmakingError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceException" => {
-                    return RusotoError::Service(StartMatchmakingError::InternalService(err.msg))
+                    return RusotoError::service(
+                        StartMatchmakingError::InternalService(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(StartMatchmakingError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        StartMatchmakingError::InvalidRequest(err.msg),
+                        &res,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(StartMatchmakingError::NotFound(err.msg))
+                    return RusotoError::service(StartMatchmakingError::NotFound(err.msg), &res)
                 }
                 "UnsupportedRegionException" => {
-                    return RusotoError::Service(StartMatchmakingError::UnsupportedRegion(err.msg))
+                    return RusotoError::service(
+                        StartMatchmakingError::UnsupportedRegion(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(StartMatchmakingError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -6697,12 +7317,14 @@ impl fmt::Display for StartMatchmakingError {
             StartMatchmakingError::InvalidRequest(ref cause) => write!(f, "{}", cause),
             StartMatchmakingError::NotFound(ref cause) => write!(f, "{}", cause),
             StartMatchmakingError::UnsupportedRegion(ref cause) => write!(f, "{}", cause),
+            StartMatchmakingError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for StartMatchmakingError {}
 /// Errors returned by StopFleetActions
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum StopFleetActionsError {
     /// <p>The service encountered an unrecoverable internal failure while processing the request. Clients can retry such requests immediately or after a waiting period.</p>
     InternalService(String),
@@ -6712,6 +7334,8 @@ pub enum StopFleetActionsError {
     NotFound(String),
     /// <p>The client failed authentication. Clients should not retry such requests.</p>
     Unauthorized(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl StopFleetActionsError {
@@ -6719,19 +7343,25 @@ impl StopFleetActionsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceException" => {
-                    return RusotoError::Service(StopFleetActionsError::InternalService(err.msg))
+                    return RusotoError::service(
+                        StopFleetActionsError::InternalService(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(StopFleetActionsError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        StopFleetActionsError::InvalidRequest(err.msg),
+                        &res,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(StopFleetActionsError::NotFound(err.msg))
+                    return RusotoError::service(StopFleetActionsError::NotFound(err.msg), &res)
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(StopFleetActionsError::Unauthorized(err.msg))
+                    return RusotoError::service(StopFleetActionsError::Unauthorized(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(StopFleetActionsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -6745,12 +7375,14 @@ impl fmt::Display for StopFleetActionsError {
             StopFleetActionsError::InvalidRequest(ref cause) => write!(f, "{}", cause),
             StopFleetActionsError::NotFound(ref cause) => write!(f, "{}", cause),
             StopFleetActionsError::Unauthorized(ref cause) => write!(f, "{}", cause),
+            StopFleetActionsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for StopFleetActionsError {}
 /// Errors returned by StopGameSessionPlacement
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum StopGameSessionPlacementError {
     /// <p>The service encountered an unrecoverable internal failure while processing the request. Clients can retry such requests immediately or after a waiting period.</p>
     InternalService(String),
@@ -6760,6 +7392,8 @@ pub enum StopGameSessionPlacementError {
     NotFound(String),
     /// <p>The client failed authentication. Clients should not retry such requests.</p>
     Unauthorized(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl StopGameSessionPlacementError {
@@ -6767,25 +7401,31 @@ impl StopGameSessionPlacementError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceException" => {
-                    return RusotoError::Service(StopGameSessionPlacementError::InternalService(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        StopGameSessionPlacementError::InternalService(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(StopGameSessionPlacementError::InvalidRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        StopGameSessionPlacementError::InvalidRequest(err.msg),
+                        &res,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(StopGameSessionPlacementError::NotFound(err.msg))
+                    return RusotoError::service(
+                        StopGameSessionPlacementError::NotFound(err.msg),
+                        &res,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(StopGameSessionPlacementError::Unauthorized(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        StopGameSessionPlacementError::Unauthorized(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(StopGameSessionPlacementError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -6799,12 +7439,14 @@ impl fmt::Display for StopGameSessionPlacementError {
             StopGameSessionPlacementError::InvalidRequest(ref cause) => write!(f, "{}", cause),
             StopGameSessionPlacementError::NotFound(ref cause) => write!(f, "{}", cause),
             StopGameSessionPlacementError::Unauthorized(ref cause) => write!(f, "{}", cause),
+            StopGameSessionPlacementError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for StopGameSessionPlacementError {}
 /// Errors returned by StopMatchmaking
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum StopMatchmakingError {
     /// <p>The service encountered an unrecoverable internal failure while processing the request. Clients can retry such requests immediately or after a waiting period.</p>
     InternalService(String),
@@ -6814,6 +7456,8 @@ pub enum StopMatchmakingError {
     NotFound(String),
     /// <p>The requested operation is not supported in the Region specified.</p>
     UnsupportedRegion(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl StopMatchmakingError {
@@ -6821,19 +7465,28 @@ impl StopMatchmakingError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceException" => {
-                    return RusotoError::Service(StopMatchmakingError::InternalService(err.msg))
+                    return RusotoError::service(
+                        StopMatchmakingError::InternalService(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(StopMatchmakingError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        StopMatchmakingError::InvalidRequest(err.msg),
+                        &res,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(StopMatchmakingError::NotFound(err.msg))
+                    return RusotoError::service(StopMatchmakingError::NotFound(err.msg), &res)
                 }
                 "UnsupportedRegionException" => {
-                    return RusotoError::Service(StopMatchmakingError::UnsupportedRegion(err.msg))
+                    return RusotoError::service(
+                        StopMatchmakingError::UnsupportedRegion(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(StopMatchmakingError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -6847,12 +7500,14 @@ impl fmt::Display for StopMatchmakingError {
             StopMatchmakingError::InvalidRequest(ref cause) => write!(f, "{}", cause),
             StopMatchmakingError::NotFound(ref cause) => write!(f, "{}", cause),
             StopMatchmakingError::UnsupportedRegion(ref cause) => write!(f, "{}", cause),
+            StopMatchmakingError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for StopMatchmakingError {}
 /// Errors returned by TagResource
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum TagResourceError {
     /// <p>The service encountered an unrecoverable internal failure while processing the request. Clients can retry such requests immediately or after a waiting period.</p>
     InternalService(String),
@@ -6862,6 +7517,8 @@ pub enum TagResourceError {
     NotFound(String),
     /// <p> The requested tagging operation did not succeed. This may be due to invalid tag format or the maximum tag limit may have been exceeded. Resolve the issue before retrying. </p>
     TaggingFailed(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl TagResourceError {
@@ -6869,19 +7526,19 @@ impl TagResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceException" => {
-                    return RusotoError::Service(TagResourceError::InternalService(err.msg))
+                    return RusotoError::service(TagResourceError::InternalService(err.msg), &res)
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(TagResourceError::InvalidRequest(err.msg))
+                    return RusotoError::service(TagResourceError::InvalidRequest(err.msg), &res)
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(TagResourceError::NotFound(err.msg))
+                    return RusotoError::service(TagResourceError::NotFound(err.msg), &res)
                 }
                 "TaggingFailedException" => {
-                    return RusotoError::Service(TagResourceError::TaggingFailed(err.msg))
+                    return RusotoError::service(TagResourceError::TaggingFailed(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(TagResourceError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -6895,12 +7552,14 @@ impl fmt::Display for TagResourceError {
             TagResourceError::InvalidRequest(ref cause) => write!(f, "{}", cause),
             TagResourceError::NotFound(ref cause) => write!(f, "{}", cause),
             TagResourceError::TaggingFailed(ref cause) => write!(f, "{}", cause),
+            TagResourceError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for TagResourceError {}
 /// Errors returned by UntagResource
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UntagResourceError {
     /// <p>The service encountered an unrecoverable internal failure while processing the request. Clients can retry such requests immediately or after a waiting period.</p>
     InternalService(String),
@@ -6910,6 +7569,8 @@ pub enum UntagResourceError {
     NotFound(String),
     /// <p> The requested tagging operation did not succeed. This may be due to invalid tag format or the maximum tag limit may have been exceeded. Resolve the issue before retrying. </p>
     TaggingFailed(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UntagResourceError {
@@ -6917,19 +7578,19 @@ impl UntagResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceException" => {
-                    return RusotoError::Service(UntagResourceError::InternalService(err.msg))
+                    return RusotoError::service(UntagResourceError::InternalService(err.msg), &res)
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(UntagResourceError::InvalidRequest(err.msg))
+                    return RusotoError::service(UntagResourceError::InvalidRequest(err.msg), &res)
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(UntagResourceError::NotFound(err.msg))
+                    return RusotoError::service(UntagResourceError::NotFound(err.msg), &res)
                 }
                 "TaggingFailedException" => {
-                    return RusotoError::Service(UntagResourceError::TaggingFailed(err.msg))
+                    return RusotoError::service(UntagResourceError::TaggingFailed(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UntagResourceError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -6943,12 +7604,14 @@ impl fmt::Display for UntagResourceError {
             UntagResourceError::InvalidRequest(ref cause) => write!(f, "{}", cause),
             UntagResourceError::NotFound(ref cause) => write!(f, "{}", cause),
             UntagResourceError::TaggingFailed(ref cause) => write!(f, "{}", cause),
+            UntagResourceError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for UntagResourceError {}
 /// Errors returned by UpdateAlias
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdateAliasError {
     /// <p>The service encountered an unrecoverable internal failure while processing the request. Clients can retry such requests immediately or after a waiting period.</p>
     InternalService(String),
@@ -6958,6 +7621,8 @@ pub enum UpdateAliasError {
     NotFound(String),
     /// <p>The client failed authentication. Clients should not retry such requests.</p>
     Unauthorized(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdateAliasError {
@@ -6965,19 +7630,19 @@ impl UpdateAliasError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceException" => {
-                    return RusotoError::Service(UpdateAliasError::InternalService(err.msg))
+                    return RusotoError::service(UpdateAliasError::InternalService(err.msg), &res)
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(UpdateAliasError::InvalidRequest(err.msg))
+                    return RusotoError::service(UpdateAliasError::InvalidRequest(err.msg), &res)
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(UpdateAliasError::NotFound(err.msg))
+                    return RusotoError::service(UpdateAliasError::NotFound(err.msg), &res)
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(UpdateAliasError::Unauthorized(err.msg))
+                    return RusotoError::service(UpdateAliasError::Unauthorized(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UpdateAliasError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -6991,12 +7656,14 @@ impl fmt::Display for UpdateAliasError {
             UpdateAliasError::InvalidRequest(ref cause) => write!(f, "{}", cause),
             UpdateAliasError::NotFound(ref cause) => write!(f, "{}", cause),
             UpdateAliasError::Unauthorized(ref cause) => write!(f, "{}", cause),
+            UpdateAliasError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for UpdateAliasError {}
 /// Errors returned by UpdateBuild
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdateBuildError {
     /// <p>The service encountered an unrecoverable internal failure while processing the request. Clients can retry such requests immediately or after a waiting period.</p>
     InternalService(String),
@@ -7006,6 +7673,8 @@ pub enum UpdateBuildError {
     NotFound(String),
     /// <p>The client failed authentication. Clients should not retry such requests.</p>
     Unauthorized(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdateBuildError {
@@ -7013,19 +7682,19 @@ impl UpdateBuildError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceException" => {
-                    return RusotoError::Service(UpdateBuildError::InternalService(err.msg))
+                    return RusotoError::service(UpdateBuildError::InternalService(err.msg), &res)
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(UpdateBuildError::InvalidRequest(err.msg))
+                    return RusotoError::service(UpdateBuildError::InvalidRequest(err.msg), &res)
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(UpdateBuildError::NotFound(err.msg))
+                    return RusotoError::service(UpdateBuildError::NotFound(err.msg), &res)
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(UpdateBuildError::Unauthorized(err.msg))
+                    return RusotoError::service(UpdateBuildError::Unauthorized(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UpdateBuildError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -7039,12 +7708,14 @@ impl fmt::Display for UpdateBuildError {
             UpdateBuildError::InvalidRequest(ref cause) => write!(f, "{}", cause),
             UpdateBuildError::NotFound(ref cause) => write!(f, "{}", cause),
             UpdateBuildError::Unauthorized(ref cause) => write!(f, "{}", cause),
+            UpdateBuildError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for UpdateBuildError {}
 /// Errors returned by UpdateFleetAttributes
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdateFleetAttributesError {
     /// <p>The requested operation would cause a conflict with the current state of a service resource associated with the request. Resolve the conflict before retrying this request.</p>
     Conflict(String),
@@ -7060,6 +7731,8 @@ pub enum UpdateFleetAttributesError {
     NotFound(String),
     /// <p>The client failed authentication. Clients should not retry such requests.</p>
     Unauthorized(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdateFleetAttributesError {
@@ -7067,34 +7740,49 @@ impl UpdateFleetAttributesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConflictException" => {
-                    return RusotoError::Service(UpdateFleetAttributesError::Conflict(err.msg))
+                    return RusotoError::service(
+                        UpdateFleetAttributesError::Conflict(err.msg),
+                        &res,
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(UpdateFleetAttributesError::InternalService(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateFleetAttributesError::InternalService(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidFleetStatusException" => {
-                    return RusotoError::Service(UpdateFleetAttributesError::InvalidFleetStatus(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateFleetAttributesError::InvalidFleetStatus(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(UpdateFleetAttributesError::InvalidRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateFleetAttributesError::InvalidRequest(err.msg),
+                        &res,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(UpdateFleetAttributesError::LimitExceeded(err.msg))
+                    return RusotoError::service(
+                        UpdateFleetAttributesError::LimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(UpdateFleetAttributesError::NotFound(err.msg))
+                    return RusotoError::service(
+                        UpdateFleetAttributesError::NotFound(err.msg),
+                        &res,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(UpdateFleetAttributesError::Unauthorized(err.msg))
+                    return RusotoError::service(
+                        UpdateFleetAttributesError::Unauthorized(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UpdateFleetAttributesError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -7111,12 +7799,14 @@ impl fmt::Display for UpdateFleetAttributesError {
             UpdateFleetAttributesError::LimitExceeded(ref cause) => write!(f, "{}", cause),
             UpdateFleetAttributesError::NotFound(ref cause) => write!(f, "{}", cause),
             UpdateFleetAttributesError::Unauthorized(ref cause) => write!(f, "{}", cause),
+            UpdateFleetAttributesError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for UpdateFleetAttributesError {}
 /// Errors returned by UpdateFleetCapacity
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdateFleetCapacityError {
     /// <p>The requested operation would cause a conflict with the current state of a service resource associated with the request. Resolve the conflict before retrying this request.</p>
     Conflict(String),
@@ -7132,6 +7822,8 @@ pub enum UpdateFleetCapacityError {
     NotFound(String),
     /// <p>The client failed authentication. Clients should not retry such requests.</p>
     Unauthorized(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdateFleetCapacityError {
@@ -7139,30 +7831,43 @@ impl UpdateFleetCapacityError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConflictException" => {
-                    return RusotoError::Service(UpdateFleetCapacityError::Conflict(err.msg))
+                    return RusotoError::service(UpdateFleetCapacityError::Conflict(err.msg), &res)
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(UpdateFleetCapacityError::InternalService(err.msg))
+                    return RusotoError::service(
+                        UpdateFleetCapacityError::InternalService(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidFleetStatusException" => {
-                    return RusotoError::Service(UpdateFleetCapacityError::InvalidFleetStatus(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateFleetCapacityError::InvalidFleetStatus(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(UpdateFleetCapacityError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        UpdateFleetCapacityError::InvalidRequest(err.msg),
+                        &res,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(UpdateFleetCapacityError::LimitExceeded(err.msg))
+                    return RusotoError::service(
+                        UpdateFleetCapacityError::LimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(UpdateFleetCapacityError::NotFound(err.msg))
+                    return RusotoError::service(UpdateFleetCapacityError::NotFound(err.msg), &res)
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(UpdateFleetCapacityError::Unauthorized(err.msg))
+                    return RusotoError::service(
+                        UpdateFleetCapacityError::Unauthorized(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UpdateFleetCapacityError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -7179,12 +7884,14 @@ impl fmt::Display for UpdateFleetCapacityError {
             UpdateFleetCapacityError::LimitExceeded(ref cause) => write!(f, "{}", cause),
             UpdateFleetCapacityError::NotFound(ref cause) => write!(f, "{}", cause),
             UpdateFleetCapacityError::Unauthorized(ref cause) => write!(f, "{}", cause),
+            UpdateFleetCapacityError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for UpdateFleetCapacityError {}
 /// Errors returned by UpdateFleetPortSettings
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdateFleetPortSettingsError {
     /// <p>The requested operation would cause a conflict with the current state of a service resource associated with the request. Resolve the conflict before retrying this request.</p>
     Conflict(String),
@@ -7200,6 +7907,8 @@ pub enum UpdateFleetPortSettingsError {
     NotFound(String),
     /// <p>The client failed authentication. Clients should not retry such requests.</p>
     Unauthorized(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdateFleetPortSettingsError {
@@ -7207,38 +7916,49 @@ impl UpdateFleetPortSettingsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConflictException" => {
-                    return RusotoError::Service(UpdateFleetPortSettingsError::Conflict(err.msg))
+                    return RusotoError::service(
+                        UpdateFleetPortSettingsError::Conflict(err.msg),
+                        &res,
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(UpdateFleetPortSettingsError::InternalService(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateFleetPortSettingsError::InternalService(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidFleetStatusException" => {
-                    return RusotoError::Service(UpdateFleetPortSettingsError::InvalidFleetStatus(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateFleetPortSettingsError::InvalidFleetStatus(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(UpdateFleetPortSettingsError::InvalidRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateFleetPortSettingsError::InvalidRequest(err.msg),
+                        &res,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(UpdateFleetPortSettingsError::LimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateFleetPortSettingsError::LimitExceeded(err.msg),
+                        &res,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(UpdateFleetPortSettingsError::NotFound(err.msg))
+                    return RusotoError::service(
+                        UpdateFleetPortSettingsError::NotFound(err.msg),
+                        &res,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(UpdateFleetPortSettingsError::Unauthorized(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateFleetPortSettingsError::Unauthorized(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UpdateFleetPortSettingsError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -7255,12 +7975,14 @@ impl fmt::Display for UpdateFleetPortSettingsError {
             UpdateFleetPortSettingsError::LimitExceeded(ref cause) => write!(f, "{}", cause),
             UpdateFleetPortSettingsError::NotFound(ref cause) => write!(f, "{}", cause),
             UpdateFleetPortSettingsError::Unauthorized(ref cause) => write!(f, "{}", cause),
+            UpdateFleetPortSettingsError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for UpdateFleetPortSettingsError {}
 /// Errors returned by UpdateGameSession
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdateGameSessionError {
     /// <p>The requested operation would cause a conflict with the current state of a service resource associated with the request. Resolve the conflict before retrying this request.</p>
     Conflict(String),
@@ -7274,6 +7996,8 @@ pub enum UpdateGameSessionError {
     NotFound(String),
     /// <p>The client failed authentication. Clients should not retry such requests.</p>
     Unauthorized(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdateGameSessionError {
@@ -7281,27 +8005,37 @@ impl UpdateGameSessionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConflictException" => {
-                    return RusotoError::Service(UpdateGameSessionError::Conflict(err.msg))
+                    return RusotoError::service(UpdateGameSessionError::Conflict(err.msg), &res)
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(UpdateGameSessionError::InternalService(err.msg))
+                    return RusotoError::service(
+                        UpdateGameSessionError::InternalService(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidGameSessionStatusException" => {
-                    return RusotoError::Service(UpdateGameSessionError::InvalidGameSessionStatus(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateGameSessionError::InvalidGameSessionStatus(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(UpdateGameSessionError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        UpdateGameSessionError::InvalidRequest(err.msg),
+                        &res,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(UpdateGameSessionError::NotFound(err.msg))
+                    return RusotoError::service(UpdateGameSessionError::NotFound(err.msg), &res)
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(UpdateGameSessionError::Unauthorized(err.msg))
+                    return RusotoError::service(
+                        UpdateGameSessionError::Unauthorized(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UpdateGameSessionError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -7317,12 +8051,14 @@ impl fmt::Display for UpdateGameSessionError {
             UpdateGameSessionError::InvalidRequest(ref cause) => write!(f, "{}", cause),
             UpdateGameSessionError::NotFound(ref cause) => write!(f, "{}", cause),
             UpdateGameSessionError::Unauthorized(ref cause) => write!(f, "{}", cause),
+            UpdateGameSessionError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for UpdateGameSessionError {}
 /// Errors returned by UpdateGameSessionQueue
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdateGameSessionQueueError {
     /// <p>The service encountered an unrecoverable internal failure while processing the request. Clients can retry such requests immediately or after a waiting period.</p>
     InternalService(String),
@@ -7332,6 +8068,8 @@ pub enum UpdateGameSessionQueueError {
     NotFound(String),
     /// <p>The client failed authentication. Clients should not retry such requests.</p>
     Unauthorized(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdateGameSessionQueueError {
@@ -7339,23 +8077,31 @@ impl UpdateGameSessionQueueError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceException" => {
-                    return RusotoError::Service(UpdateGameSessionQueueError::InternalService(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateGameSessionQueueError::InternalService(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(UpdateGameSessionQueueError::InvalidRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateGameSessionQueueError::InvalidRequest(err.msg),
+                        &res,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(UpdateGameSessionQueueError::NotFound(err.msg))
+                    return RusotoError::service(
+                        UpdateGameSessionQueueError::NotFound(err.msg),
+                        &res,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(UpdateGameSessionQueueError::Unauthorized(err.msg))
+                    return RusotoError::service(
+                        UpdateGameSessionQueueError::Unauthorized(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UpdateGameSessionQueueError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -7369,12 +8115,14 @@ impl fmt::Display for UpdateGameSessionQueueError {
             UpdateGameSessionQueueError::InvalidRequest(ref cause) => write!(f, "{}", cause),
             UpdateGameSessionQueueError::NotFound(ref cause) => write!(f, "{}", cause),
             UpdateGameSessionQueueError::Unauthorized(ref cause) => write!(f, "{}", cause),
+            UpdateGameSessionQueueError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for UpdateGameSessionQueueError {}
 /// Errors returned by UpdateMatchmakingConfiguration
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdateMatchmakingConfigurationError {
     /// <p>The service encountered an unrecoverable internal failure while processing the request. Clients can retry such requests immediately or after a waiting period.</p>
     InternalService(String),
@@ -7384,6 +8132,8 @@ pub enum UpdateMatchmakingConfigurationError {
     NotFound(String),
     /// <p>The requested operation is not supported in the Region specified.</p>
     UnsupportedRegion(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdateMatchmakingConfigurationError {
@@ -7393,27 +8143,36 @@ impl UpdateMatchmakingConfigurationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         UpdateMatchmakingConfigurationError::InternalService(err.msg),
+                        &res,
                     )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         UpdateMatchmakingConfigurationError::InvalidRequest(err.msg),
+                        &res,
                     )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(UpdateMatchmakingConfigurationError::NotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateMatchmakingConfigurationError::NotFound(err.msg),
+                        &res,
+                    )
                 }
                 "UnsupportedRegionException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         UpdateMatchmakingConfigurationError::UnsupportedRegion(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(
+                        UpdateMatchmakingConfigurationError::Unhandled,
+                        &res,
+                    )
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -7433,12 +8192,16 @@ impl fmt::Display for UpdateMatchmakingConfigurationError {
             UpdateMatchmakingConfigurationError::UnsupportedRegion(ref cause) => {
                 write!(f, "{}", cause)
             }
+            UpdateMatchmakingConfigurationError::Unhandled(ref metadata) => {
+                write!(f, "{}", metadata)
+            }
         }
     }
 }
 impl Error for UpdateMatchmakingConfigurationError {}
 /// Errors returned by UpdateRuntimeConfiguration
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdateRuntimeConfigurationError {
     /// <p>The service encountered an unrecoverable internal failure while processing the request. Clients can retry such requests immediately or after a waiting period.</p>
     InternalService(String),
@@ -7450,6 +8213,8 @@ pub enum UpdateRuntimeConfigurationError {
     NotFound(String),
     /// <p>The client failed authentication. Clients should not retry such requests.</p>
     Unauthorized(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdateRuntimeConfigurationError {
@@ -7459,30 +8224,39 @@ impl UpdateRuntimeConfigurationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceException" => {
-                    return RusotoError::Service(UpdateRuntimeConfigurationError::InternalService(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateRuntimeConfigurationError::InternalService(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidFleetStatusException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         UpdateRuntimeConfigurationError::InvalidFleetStatus(err.msg),
+                        &res,
                     )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(UpdateRuntimeConfigurationError::InvalidRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateRuntimeConfigurationError::InvalidRequest(err.msg),
+                        &res,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(UpdateRuntimeConfigurationError::NotFound(err.msg))
+                    return RusotoError::service(
+                        UpdateRuntimeConfigurationError::NotFound(err.msg),
+                        &res,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(UpdateRuntimeConfigurationError::Unauthorized(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        UpdateRuntimeConfigurationError::Unauthorized(err.msg),
+                        &res,
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(UpdateRuntimeConfigurationError::Unhandled, &res)
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -7499,12 +8273,14 @@ impl fmt::Display for UpdateRuntimeConfigurationError {
             UpdateRuntimeConfigurationError::InvalidRequest(ref cause) => write!(f, "{}", cause),
             UpdateRuntimeConfigurationError::NotFound(ref cause) => write!(f, "{}", cause),
             UpdateRuntimeConfigurationError::Unauthorized(ref cause) => write!(f, "{}", cause),
+            UpdateRuntimeConfigurationError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for UpdateRuntimeConfigurationError {}
 /// Errors returned by UpdateScript
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum UpdateScriptError {
     /// <p>The service encountered an unrecoverable internal failure while processing the request. Clients can retry such requests immediately or after a waiting period.</p>
     InternalService(String),
@@ -7514,6 +8290,8 @@ pub enum UpdateScriptError {
     NotFound(String),
     /// <p>The client failed authentication. Clients should not retry such requests.</p>
     Unauthorized(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl UpdateScriptError {
@@ -7521,19 +8299,19 @@ impl UpdateScriptError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceException" => {
-                    return RusotoError::Service(UpdateScriptError::InternalService(err.msg))
+                    return RusotoError::service(UpdateScriptError::InternalService(err.msg), &res)
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(UpdateScriptError::InvalidRequest(err.msg))
+                    return RusotoError::service(UpdateScriptError::InvalidRequest(err.msg), &res)
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(UpdateScriptError::NotFound(err.msg))
+                    return RusotoError::service(UpdateScriptError::NotFound(err.msg), &res)
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(UpdateScriptError::Unauthorized(err.msg))
+                    return RusotoError::service(UpdateScriptError::Unauthorized(err.msg), &res)
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => return RusotoError::unhandled(UpdateScriptError::Unhandled, &res),
             }
         }
         RusotoError::Unknown(res)
@@ -7547,12 +8325,14 @@ impl fmt::Display for UpdateScriptError {
             UpdateScriptError::InvalidRequest(ref cause) => write!(f, "{}", cause),
             UpdateScriptError::NotFound(ref cause) => write!(f, "{}", cause),
             UpdateScriptError::Unauthorized(ref cause) => write!(f, "{}", cause),
+            UpdateScriptError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
 impl Error for UpdateScriptError {}
 /// Errors returned by ValidateMatchmakingRuleSet
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ValidateMatchmakingRuleSetError {
     /// <p>The service encountered an unrecoverable internal failure while processing the request. Clients can retry such requests immediately or after a waiting period.</p>
     InternalService(String),
@@ -7560,6 +8340,8 @@ pub enum ValidateMatchmakingRuleSetError {
     InvalidRequest(String),
     /// <p>The requested operation is not supported in the Region specified.</p>
     UnsupportedRegion(String),
+    /// An error code not modeled by this version of the service definition.
+    Unhandled(ErrorMetadata),
 }
 
 impl ValidateMatchmakingRuleSetError {
@@ -7569,22 +8351,27 @@ impl ValidateMatchmakingRuleSetError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceException" => {
-                    return RusotoError::Service(ValidateMatchmakingRuleSetError::InternalService(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ValidateMatchmakingRuleSetError::InternalService(err.msg),
+                        &res,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(ValidateMatchmakingRuleSetError::InvalidRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        ValidateMatchmakingRuleSetError::InvalidRequest(err.msg),
+                        &res,
+                    )
                 }
                 "UnsupportedRegionException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
                         ValidateMatchmakingRuleSetError::UnsupportedRegion(err.msg),
+                        &res,
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
+                _ => {
+                    return RusotoError::unhandled(ValidateMatchmakingRuleSetError::Unhandled, &res)
+                }
             }
         }
         RusotoError::Unknown(res)
@@ -7597,6 +8384,7 @@ impl fmt::Display for ValidateMatchmakingRuleSetError {
             ValidateMatchmakingRuleSetError::InternalService(ref cause) => write!(f, "{}", cause),
             ValidateMatchmakingRuleSetError::InvalidRequest(ref cause) => write!(f, "{}", cause),
             ValidateMatchmakingRuleSetError::UnsupportedRegion(ref cause) => write!(f, "{}", cause),
+            ValidateMatchmakingRuleSetError::Unhandled(ref metadata) => write!(f, "{}", metadata),
         }
     }
 }
@@ -8085,6 +8873,7 @@ impl GameLiftClient {
     /// Creates a client backed by the default tokio event loop.
     ///
     /// The client will use the default credentials provider and tls client.
+    #[cfg(not(target_arch = "wasm32"))]
     pub fn new(region: region::Region) -> GameLiftClient {
         GameLiftClient {
             client: Client::shared(),
@@ -8110,2131 +8899,5640 @@ impl GameLiftClient {
     pub fn new_with_client(client: Client, region: region::Region) -> GameLiftClient {
         GameLiftClient { client, region }
     }
+
+    /// Returns a builder of a client, setting any of its region, credentials provider,
+    /// dispatcher, retry policy, endpoint and timeouts.
+    pub fn builder() -> rusoto_core::ClientBuilder<GameLiftClient> {
+        rusoto_core::ClientBuilder::new(GameLiftClient::new_with_client)
+    }
+
+    /// Returns a clone of this client with the overrides of `config` applied to its
+    /// operations, sharing its connections.
+    pub fn customize(&self, config: &rusoto_core::OperationConfig) -> GameLiftClient {
+        GameLiftClient {
+            client: self.client.customize(config),
+            region: config.resolve_region(&self.region),
+        }
+    }
 }
 
-#[async_trait]
-impl GameLift for GameLiftClient {
-    /// <p><p>Registers a player&#39;s acceptance or rejection of a proposed FlexMatch match. A matchmaking configuration may require player acceptance; if so, then matches built with that configuration cannot be completed unless all players accept the proposed match within a specified time limit. </p> <p>When FlexMatch builds a match, all the matchmaking tickets involved in the proposed match are placed into status <code>REQUIRES_ACCEPTANCE</code>. This is a trigger for your game to get acceptance from all players in the ticket. Acceptances are only valid for tickets when they are in this status; all other acceptances result in an error.</p> <p>To register acceptance, specify the ticket ID, a response, and one or more players. Once all players have registered acceptance, the matchmaking tickets advance to status <code>PLACING</code>, where a new game session is created for the match. </p> <p>If any player rejects the match, or if acceptances are not received before a specified timeout, the proposed match is dropped. The matchmaking tickets are then handled in one of two ways: For tickets where one or more players rejected the match, the ticket status is returned to <code>SEARCHING</code> to find a new match. For tickets where one or more players failed to respond, the ticket status is set to <code>CANCELLED</code>, and processing is terminated. A new matchmaking request for these players can be submitted as needed. </p> <p> <b>Learn more</b> </p> <p> <a href="https://docs.aws.amazon.com/gamelift/latest/developerguide/match-client.html"> Add FlexMatch to a Game Client</a> </p> <p> <a href="https://docs.aws.amazon.com/gamelift/latest/developerguide/match-events.html"> FlexMatch Events Reference</a> </p> <p> <b>Related operations</b> </p> <ul> <li> <p> <a>StartMatchmaking</a> </p> </li> <li> <p> <a>DescribeMatchmaking</a> </p> </li> <li> <p> <a>StopMatchmaking</a> </p> </li> <li> <p> <a>AcceptMatch</a> </p> </li> <li> <p> <a>StartMatchBackfill</a> </p> </li> </ul></p>
-    async fn accept_match(
+impl GameLiftClient {
+    /// Calls `accept_match`, also returning the metadata of the response.
+    pub async fn accept_match_with_metadata(
         &self,
         input: AcceptMatchInput,
-    ) -> Result<AcceptMatchOutput, RusotoError<AcceptMatchError>> {
-        let mut request = SignedRequest::new("POST", "gamelift", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "GameLift.AcceptMatch");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
+    ) -> Result<(AcceptMatchOutput, ResponseMetadata), RusotoError<AcceptMatchError>> {
+        let request = self.build_accept_match_request(input);
 
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<AcceptMatchOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<AcceptMatchOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
             Err(AcceptMatchError::from_response(response))
         }
     }
 
-    /// <p><p>Creates an alias for a fleet. In most situations, you can use an alias ID in place of a fleet ID. An alias provides a level of abstraction for a fleet that is useful when redirecting player traffic from one fleet to another, such as when updating your game build. </p> <p>Amazon GameLift supports two types of routing strategies for aliases: simple and terminal. A simple alias points to an active fleet. A terminal alias is used to display messaging or link to a URL instead of routing players to an active fleet. For example, you might use a terminal alias when a game version is no longer supported and you want to direct players to an upgrade site. </p> <p>To create a fleet alias, specify an alias name, routing strategy, and optional description. Each simple alias can point to only one fleet, but a fleet can have multiple aliases. If successful, a new alias record is returned, including an alias ID and an ARN. You can reassign an alias to another fleet by calling <code>UpdateAlias</code>.</p> <ul> <li> <p> <a>CreateAlias</a> </p> </li> <li> <p> <a>ListAliases</a> </p> </li> <li> <p> <a>DescribeAlias</a> </p> </li> <li> <p> <a>UpdateAlias</a> </p> </li> <li> <p> <a>DeleteAlias</a> </p> </li> <li> <p> <a>ResolveAlias</a> </p> </li> </ul></p>
-    async fn create_alias(
+    /// Presigns the request `accept_match` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_accept_match(
         &self,
-        input: CreateAliasInput,
-    ) -> Result<CreateAliasOutput, RusotoError<CreateAliasError>> {
+        presigner: &rusoto_core::Presigner,
+        input: AcceptMatchInput,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_accept_match_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_accept_match_request(&self, input: AcceptMatchInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "gamelift", &self.region, "/");
+        request.set_operation("AcceptMatch");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
-        request.add_header("x-amz-target", "GameLift.CreateAlias");
+        request.add_header("x-amz-target", "GameLift.AcceptMatch");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
-        let mut response = self
-            .client
+        request
+    }
+
+    /// Calls `create_alias`, also returning the metadata of the response.
+    pub async fn create_alias_with_metadata(
+        &self,
+        input: CreateAliasInput,
+    ) -> Result<(CreateAliasOutput, ResponseMetadata), RusotoError<CreateAliasError>> {
+        let request = self.build_create_alias_request(input);
+
+        let mut response = self
+            .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<CreateAliasOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<CreateAliasOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
             Err(CreateAliasError::from_response(response))
         }
     }
 
-    /// <p><p>Creates a new Amazon GameLift build record for your game server binary files and points to the location of your game server build files in an Amazon Simple Storage Service (Amazon S3) location. </p> <p>Game server binaries must be combined into a zip file for use with Amazon GameLift. </p> <important> <p>To create new builds directly from a file directory, use the AWS CLI command <b> <a href="https://docs.aws.amazon.com/cli/latest/reference/gamelift/upload-build.html">upload-build</a> </b>. This helper command uploads build files and creates a new build record in one step, and automatically handles the necessary permissions. </p> </important> <p>The <code>CreateBuild</code> operation should be used only in the following scenarios:</p> <ul> <li> <p>To create a new game build with build files that are in an Amazon S3 bucket under your own AWS account. To use this option, you must first give Amazon GameLift access to that Amazon S3 bucket. Then call <code>CreateBuild</code> and specify a build name, operating system, and the Amazon S3 storage location of your game build.</p> </li> <li> <p>To upload build files directly to Amazon GameLift&#39;s Amazon S3 account. To use this option, first call <code>CreateBuild</code> and specify a build name and operating system. This action creates a new build record and returns an Amazon S3 storage location (bucket and key only) and temporary access credentials. Use the credentials to manually upload your build file to the provided storage location (see the Amazon S3 topic <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/UploadingObjects.html">Uploading Objects</a>). You can upload build files to the GameLift Amazon S3 location only once. </p> </li> </ul> <p>If successful, this operation creates a new build record with a unique build ID and places it in <code>INITIALIZED</code> status. You can use <a>DescribeBuild</a> to check the status of your build. A build must be in <code>READY</code> status before it can be used to create fleets.</p> <p> <b>Learn more</b> </p> <p> <a href="https://docs.aws.amazon.com/gamelift/latest/developerguide/gamelift-build-intro.html">Uploading Your Game</a> <a href="https://docs.aws.amazon.com/general/latest/gr/aws-arns-and-namespaces.html">https://docs.aws.amazon.com/general/latest/gr/aws-arns-and-namespaces.html</a> </p> <p> <a href="https://docs.aws.amazon.com/gamelift/latest/developerguide/gamelift-build-cli-uploading.html#gamelift-build-cli-uploading-create-build"> Create a Build with Files in Amazon S3</a> </p> <p> <b>Related operations</b> </p> <ul> <li> <p> <a>CreateBuild</a> </p> </li> <li> <p> <a>ListBuilds</a> </p> </li> <li> <p> <a>DescribeBuild</a> </p> </li> <li> <p> <a>UpdateBuild</a> </p> </li> <li> <p> <a>DeleteBuild</a> </p> </li> </ul></p>
-    async fn create_build(
+    /// Presigns the request `create_alias` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_alias(
+        &self,
+        presigner: &rusoto_core::Presigner,
+        input: CreateAliasInput,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_alias_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_alias_request(&self, input: CreateAliasInput) -> SignedRequest {
+        let mut request = SignedRequest::new("POST", "gamelift", &self.region, "/");
+        request.set_operation("CreateAlias");
+
+        request.set_content_type("application/x-amz-json-1.1".to_owned());
+        request.add_header("x-amz-target", "GameLift.CreateAlias");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        request
+    }
+
+    /// Calls `create_build`, also returning the metadata of the response.
+    pub async fn create_build_with_metadata(
         &self,
         input: CreateBuildInput,
-    ) -> Result<CreateBuildOutput, RusotoError<CreateBuildError>> {
+    ) -> Result<(CreateBuildOutput, ResponseMetadata), RusotoError<CreateBuildError>> {
+        let request = self.build_create_build_request(input);
+
+        let mut response = self
+            .client
+            .sign_and_dispatch(request)
+            .await
+            .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
+        if response.status.is_success() {
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<CreateBuildOutput, _>()?;
+            Ok((result, metadata))
+        } else {
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(CreateBuildError::from_response(response))
+        }
+    }
+
+    /// Presigns the request `create_build` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_build(
+        &self,
+        presigner: &rusoto_core::Presigner,
+        input: CreateBuildInput,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_build_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_build_request(&self, input: CreateBuildInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "gamelift", &self.region, "/");
+        request.set_operation("CreateBuild");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "GameLift.CreateBuild");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `create_fleet`, also returning the metadata of the response.
+    pub async fn create_fleet_with_metadata(
+        &self,
+        input: CreateFleetInput,
+    ) -> Result<(CreateFleetOutput, ResponseMetadata), RusotoError<CreateFleetError>> {
+        let request = self.build_create_fleet_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<CreateBuildOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<CreateFleetOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(CreateBuildError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(CreateFleetError::from_response(response))
         }
     }
 
-    /// <p><p>Creates a new fleet to run your game servers. whether they are custom game builds or Realtime Servers with game-specific script. A fleet is a set of Amazon Elastic Compute Cloud (Amazon EC2) instances, each of which can host multiple game sessions. When creating a fleet, you choose the hardware specifications, set some configuration options, and specify the game server to deploy on the new fleet. </p> <p>To create a new fleet, you must provide the following: (1) a fleet name, (2) an EC2 instance type and fleet type (spot or on-demand), (3) the build ID for your game build or script ID if using Realtime Servers, and (4) a runtime configuration, which determines how game servers will run on each instance in the fleet. </p> <p>If the <code>CreateFleet</code> call is successful, Amazon GameLift performs the following tasks. You can track the process of a fleet by checking the fleet status or by monitoring fleet creation events:</p> <ul> <li> <p>Creates a fleet record. Status: <code>NEW</code>.</p> </li> <li> <p>Begins writing events to the fleet event log, which can be accessed in the Amazon GameLift console.</p> </li> <li> <p>Sets the fleet&#39;s target capacity to 1 (desired instances), which triggers Amazon GameLift to start one new EC2 instance.</p> </li> <li> <p>Downloads the game build or Realtime script to the new instance and installs it. Statuses: <code>DOWNLOADING</code>, <code>VALIDATING</code>, <code>BUILDING</code>. </p> </li> <li> <p>Starts launching server processes on the instance. If the fleet is configured to run multiple server processes per instance, Amazon GameLift staggers each process launch by a few seconds. Status: <code>ACTIVATING</code>.</p> </li> <li> <p>Sets the fleet&#39;s status to <code>ACTIVE</code> as soon as one server process is ready to host a game session.</p> </li> </ul> <p> <b>Learn more</b> </p> <p> <a href="https://docs.aws.amazon.com/gamelift/latest/developerguide/fleets-intro.html"> Setting Up Fleets</a> </p> <p> <a href="https://docs.aws.amazon.com/gamelift/latest/developerguide/fleets-creating-debug.html#fleets-creating-debug-creation"> Debug Fleet Creation Issues</a> </p> <p> <b>Related operations</b> </p> <ul> <li> <p> <a>CreateFleet</a> </p> </li> <li> <p> <a>ListFleets</a> </p> </li> <li> <p> <a>DeleteFleet</a> </p> </li> <li> <p> <a>DescribeFleetAttributes</a> </p> </li> <li> <p> <a>UpdateFleetAttributes</a> </p> </li> <li> <p>Manage fleet actions:</p> <ul> <li> <p> <a>StartFleetActions</a> </p> </li> <li> <p> <a>StopFleetActions</a> </p> </li> </ul> </li> </ul></p>
-    async fn create_fleet(
+    /// Presigns the request `create_fleet` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_fleet(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: CreateFleetInput,
-    ) -> Result<CreateFleetOutput, RusotoError<CreateFleetError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_fleet_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_fleet_request(&self, input: CreateFleetInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "gamelift", &self.region, "/");
+        request.set_operation("CreateFleet");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "GameLift.CreateFleet");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `create_game_session`, also returning the metadata of the response.
+    pub async fn create_game_session_with_metadata(
+        &self,
+        input: CreateGameSessionInput,
+    ) -> Result<(CreateGameSessionOutput, ResponseMetadata), RusotoError<CreateGameSessionError>>
+    {
+        let request = self.build_create_game_session_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<CreateFleetOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<CreateGameSessionOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(CreateFleetError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(CreateGameSessionError::from_response(response))
         }
     }
 
-    /// <p><p>Creates a multiplayer game session for players. This action creates a game session record and assigns an available server process in the specified fleet to host the game session. A fleet must have an <code>ACTIVE</code> status before a game session can be created in it.</p> <p>To create a game session, specify either fleet ID or alias ID and indicate a maximum number of players to allow in the game session. You can also provide a name and game-specific properties for this game session. If successful, a <a>GameSession</a> object is returned containing the game session properties and other settings you specified.</p> <p> <b>Idempotency tokens.</b> You can add a token that uniquely identifies game session requests. This is useful for ensuring that game session requests are idempotent. Multiple requests with the same idempotency token are processed only once; subsequent requests return the original result. All response values are the same with the exception of game session status, which may change.</p> <p> <b>Resource creation limits.</b> If you are creating a game session on a fleet with a resource creation limit policy in force, then you must specify a creator ID. Without this ID, Amazon GameLift has no way to evaluate the policy for this new game session request.</p> <p> <b>Player acceptance policy.</b> By default, newly created game sessions are open to new players. You can restrict new player access by using <a>UpdateGameSession</a> to change the game session&#39;s player session creation policy.</p> <p> <b>Game session logs.</b> Logs are retained for all active game sessions for 14 days. To access the logs, call <a>GetGameSessionLogUrl</a> to download the log files.</p> <p> <i>Available in Amazon GameLift Local.</i> </p> <ul> <li> <p> <a>CreateGameSession</a> </p> </li> <li> <p> <a>DescribeGameSessions</a> </p> </li> <li> <p> <a>DescribeGameSessionDetails</a> </p> </li> <li> <p> <a>SearchGameSessions</a> </p> </li> <li> <p> <a>UpdateGameSession</a> </p> </li> <li> <p> <a>GetGameSessionLogUrl</a> </p> </li> <li> <p>Game session placements</p> <ul> <li> <p> <a>StartGameSessionPlacement</a> </p> </li> <li> <p> <a>DescribeGameSessionPlacement</a> </p> </li> <li> <p> <a>StopGameSessionPlacement</a> </p> </li> </ul> </li> </ul></p>
-    async fn create_game_session(
+    /// Presigns the request `create_game_session` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_game_session(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: CreateGameSessionInput,
-    ) -> Result<CreateGameSessionOutput, RusotoError<CreateGameSessionError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_game_session_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_game_session_request(&self, input: CreateGameSessionInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "gamelift", &self.region, "/");
+        request.set_operation("CreateGameSession");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "GameLift.CreateGameSession");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `create_game_session_queue`, also returning the metadata of the response.
+    pub async fn create_game_session_queue_with_metadata(
+        &self,
+        input: CreateGameSessionQueueInput,
+    ) -> Result<
+        (CreateGameSessionQueueOutput, ResponseMetadata),
+        RusotoError<CreateGameSessionQueueError>,
+    > {
+        let request = self.build_create_game_session_queue_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<CreateGameSessionOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<CreateGameSessionQueueOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(CreateGameSessionError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(CreateGameSessionQueueError::from_response(response))
         }
     }
 
-    /// <p><p>Establishes a new queue for processing requests to place new game sessions. A queue identifies where new game sessions can be hosted -- by specifying a list of destinations (fleets or aliases) -- and how long requests can wait in the queue before timing out. You can set up a queue to try to place game sessions on fleets in multiple Regions. To add placement requests to a queue, call <a>StartGameSessionPlacement</a> and reference the queue name.</p> <p> <b>Destination order.</b> When processing a request for a game session, Amazon GameLift tries each destination in order until it finds one with available resources to host the new game session. A queue&#39;s default order is determined by how destinations are listed. The default order is overridden when a game session placement request provides player latency information. Player latency information enables Amazon GameLift to prioritize destinations where players report the lowest average latency, as a result placing the new game session where the majority of players will have the best possible gameplay experience.</p> <p> <b>Player latency policies.</b> For placement requests containing player latency information, use player latency policies to protect individual players from very high latencies. With a latency cap, even when a destination can deliver a low latency for most players, the game is not placed where any individual player is reporting latency higher than a policy&#39;s maximum. A queue can have multiple latency policies, which are enforced consecutively starting with the policy with the lowest latency cap. Use multiple policies to gradually relax latency controls; for example, you might set a policy with a low latency cap for the first 60 seconds, a second policy with a higher cap for the next 60 seconds, etc. </p> <p>To create a new queue, provide a name, timeout value, a list of destinations and, if desired, a set of latency policies. If successful, a new queue object is returned.</p> <ul> <li> <p> <a>CreateGameSessionQueue</a> </p> </li> <li> <p> <a>DescribeGameSessionQueues</a> </p> </li> <li> <p> <a>UpdateGameSessionQueue</a> </p> </li> <li> <p> <a>DeleteGameSessionQueue</a> </p> </li> </ul></p>
-    async fn create_game_session_queue(
+    /// Presigns the request `create_game_session_queue` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_game_session_queue(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: CreateGameSessionQueueInput,
-    ) -> Result<CreateGameSessionQueueOutput, RusotoError<CreateGameSessionQueueError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_game_session_queue_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_game_session_queue_request(
+        &self,
+        input: CreateGameSessionQueueInput,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "gamelift", &self.region, "/");
+        request.set_operation("CreateGameSessionQueue");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "GameLift.CreateGameSessionQueue");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `create_matchmaking_configuration`, also returning the metadata of the response.
+    pub async fn create_matchmaking_configuration_with_metadata(
+        &self,
+        input: CreateMatchmakingConfigurationInput,
+    ) -> Result<
+        (CreateMatchmakingConfigurationOutput, ResponseMetadata),
+        RusotoError<CreateMatchmakingConfigurationError>,
+    > {
+        let request = self.build_create_matchmaking_configuration_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<CreateGameSessionQueueOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<CreateMatchmakingConfigurationOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(CreateGameSessionQueueError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(CreateMatchmakingConfigurationError::from_response(response))
         }
     }
 
-    /// <p><p>Defines a new matchmaking configuration for use with FlexMatch. A matchmaking configuration sets out guidelines for matching players and getting the matches into games. You can set up multiple matchmaking configurations to handle the scenarios needed for your game. Each matchmaking ticket (<a>StartMatchmaking</a> or <a>StartMatchBackfill</a>) specifies a configuration for the match and provides player attributes to support the configuration being used. </p> <p>To create a matchmaking configuration, at a minimum you must specify the following: configuration name; a rule set that governs how to evaluate players and find acceptable matches; a game session queue to use when placing a new game session for the match; and the maximum time allowed for a matchmaking attempt.</p> <p>There are two ways to track the progress of matchmaking tickets: (1) polling ticket status with <a>DescribeMatchmaking</a>; or (2) receiving notifications with Amazon Simple Notification Service (SNS). To use notifications, you first need to set up an SNS topic to receive the notifications, and provide the topic ARN in the matchmaking configuration. Since notifications promise only &quot;best effort&quot; delivery, we recommend calling <code>DescribeMatchmaking</code> if no notifications are received within 30 seconds.</p> <p> <b>Learn more</b> </p> <p> <a href="https://docs.aws.amazon.com/gamelift/latest/developerguide/match-configuration.html"> Design a FlexMatch Matchmaker</a> </p> <p> <a href="https://docs.aws.amazon.com/gamelift/latest/developerguide/match-notification.html"> Setting up Notifications for Matchmaking</a> </p> <p> <b>Related operations</b> </p> <ul> <li> <p> <a>CreateMatchmakingConfiguration</a> </p> </li> <li> <p> <a>DescribeMatchmakingConfigurations</a> </p> </li> <li> <p> <a>UpdateMatchmakingConfiguration</a> </p> </li> <li> <p> <a>DeleteMatchmakingConfiguration</a> </p> </li> <li> <p> <a>CreateMatchmakingRuleSet</a> </p> </li> <li> <p> <a>DescribeMatchmakingRuleSets</a> </p> </li> <li> <p> <a>ValidateMatchmakingRuleSet</a> </p> </li> <li> <p> <a>DeleteMatchmakingRuleSet</a> </p> </li> </ul></p>
-    async fn create_matchmaking_configuration(
+    /// Presigns the request `create_matchmaking_configuration` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_matchmaking_configuration(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: CreateMatchmakingConfigurationInput,
-    ) -> Result<
-        CreateMatchmakingConfigurationOutput,
-        RusotoError<CreateMatchmakingConfigurationError>,
-    > {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_matchmaking_configuration_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_matchmaking_configuration_request(
+        &self,
+        input: CreateMatchmakingConfigurationInput,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "gamelift", &self.region, "/");
+        request.set_operation("CreateMatchmakingConfiguration");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "GameLift.CreateMatchmakingConfiguration");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `create_matchmaking_rule_set`, also returning the metadata of the response.
+    pub async fn create_matchmaking_rule_set_with_metadata(
+        &self,
+        input: CreateMatchmakingRuleSetInput,
+    ) -> Result<
+        (CreateMatchmakingRuleSetOutput, ResponseMetadata),
+        RusotoError<CreateMatchmakingRuleSetError>,
+    > {
+        let request = self.build_create_matchmaking_rule_set_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<CreateMatchmakingConfigurationOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<CreateMatchmakingRuleSetOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(CreateMatchmakingConfigurationError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(CreateMatchmakingRuleSetError::from_response(response))
         }
     }
 
-    /// <p><p>Creates a new rule set for FlexMatch matchmaking. A rule set describes the type of match to create, such as the number and size of teams. It also sets the parameters for acceptable player matches, such as minimum skill level or character type. A rule set is used by a <a>MatchmakingConfiguration</a>. </p> <p>To create a matchmaking rule set, provide unique rule set name and the rule set body in JSON format. Rule sets must be defined in the same Region as the matchmaking configuration they are used with.</p> <p>Since matchmaking rule sets cannot be edited, it is a good idea to check the rule set syntax using <a>ValidateMatchmakingRuleSet</a> before creating a new rule set.</p> <p> <b>Learn more</b> </p> <ul> <li> <p> <a href="https://docs.aws.amazon.com/gamelift/latest/developerguide/match-rulesets.html">Build a Rule Set</a> </p> </li> <li> <p> <a href="https://docs.aws.amazon.com/gamelift/latest/developerguide/match-configuration.html">Design a Matchmaker</a> </p> </li> <li> <p> <a href="https://docs.aws.amazon.com/gamelift/latest/developerguide/match-intro.html">Matchmaking with FlexMatch</a> </p> </li> </ul> <p> <b>Related operations</b> </p> <ul> <li> <p> <a>CreateMatchmakingConfiguration</a> </p> </li> <li> <p> <a>DescribeMatchmakingConfigurations</a> </p> </li> <li> <p> <a>UpdateMatchmakingConfiguration</a> </p> </li> <li> <p> <a>DeleteMatchmakingConfiguration</a> </p> </li> <li> <p> <a>CreateMatchmakingRuleSet</a> </p> </li> <li> <p> <a>DescribeMatchmakingRuleSets</a> </p> </li> <li> <p> <a>ValidateMatchmakingRuleSet</a> </p> </li> <li> <p> <a>DeleteMatchmakingRuleSet</a> </p> </li> </ul></p>
-    async fn create_matchmaking_rule_set(
+    /// Presigns the request `create_matchmaking_rule_set` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_matchmaking_rule_set(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: CreateMatchmakingRuleSetInput,
-    ) -> Result<CreateMatchmakingRuleSetOutput, RusotoError<CreateMatchmakingRuleSetError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_matchmaking_rule_set_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_matchmaking_rule_set_request(
+        &self,
+        input: CreateMatchmakingRuleSetInput,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "gamelift", &self.region, "/");
+        request.set_operation("CreateMatchmakingRuleSet");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "GameLift.CreateMatchmakingRuleSet");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `create_player_session`, also returning the metadata of the response.
+    pub async fn create_player_session_with_metadata(
+        &self,
+        input: CreatePlayerSessionInput,
+    ) -> Result<(CreatePlayerSessionOutput, ResponseMetadata), RusotoError<CreatePlayerSessionError>>
+    {
+        let request = self.build_create_player_session_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<CreateMatchmakingRuleSetOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<CreatePlayerSessionOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(CreateMatchmakingRuleSetError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(CreatePlayerSessionError::from_response(response))
         }
     }
 
-    /// <p><p>Reserves an open player slot in an active game session. Before a player can be added, a game session must have an <code>ACTIVE</code> status, have a creation policy of <code>ALLOW_ALL</code>, and have an open player slot. To add a group of players to a game session, use <a>CreatePlayerSessions</a>. When the player connects to the game server and references a player session ID, the game server contacts the Amazon GameLift service to validate the player reservation and accept the player.</p> <p>To create a player session, specify a game session ID, player ID, and optionally a string of player data. If successful, a slot is reserved in the game session for the player and a new <a>PlayerSession</a> object is returned. Player sessions cannot be updated. </p> <p> <i>Available in Amazon GameLift Local.</i> </p> <ul> <li> <p> <a>CreatePlayerSession</a> </p> </li> <li> <p> <a>CreatePlayerSessions</a> </p> </li> <li> <p> <a>DescribePlayerSessions</a> </p> </li> <li> <p>Game session placements</p> <ul> <li> <p> <a>StartGameSessionPlacement</a> </p> </li> <li> <p> <a>DescribeGameSessionPlacement</a> </p> </li> <li> <p> <a>StopGameSessionPlacement</a> </p> </li> </ul> </li> </ul></p>
-    async fn create_player_session(
+    /// Presigns the request `create_player_session` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_player_session(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: CreatePlayerSessionInput,
-    ) -> Result<CreatePlayerSessionOutput, RusotoError<CreatePlayerSessionError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_player_session_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_player_session_request(
+        &self,
+        input: CreatePlayerSessionInput,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "gamelift", &self.region, "/");
+        request.set_operation("CreatePlayerSession");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "GameLift.CreatePlayerSession");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `create_player_sessions`, also returning the metadata of the response.
+    pub async fn create_player_sessions_with_metadata(
+        &self,
+        input: CreatePlayerSessionsInput,
+    ) -> Result<
+        (CreatePlayerSessionsOutput, ResponseMetadata),
+        RusotoError<CreatePlayerSessionsError>,
+    > {
+        let request = self.build_create_player_sessions_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<CreatePlayerSessionOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<CreatePlayerSessionsOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(CreatePlayerSessionError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(CreatePlayerSessionsError::from_response(response))
         }
     }
 
-    /// <p><p>Reserves open slots in a game session for a group of players. Before players can be added, a game session must have an <code>ACTIVE</code> status, have a creation policy of <code>ALLOW_ALL</code>, and have an open player slot. To add a single player to a game session, use <a>CreatePlayerSession</a>. When a player connects to the game server and references a player session ID, the game server contacts the Amazon GameLift service to validate the player reservation and accept the player.</p> <p>To create player sessions, specify a game session ID, a list of player IDs, and optionally a set of player data strings. If successful, a slot is reserved in the game session for each player and a set of new <a>PlayerSession</a> objects is returned. Player sessions cannot be updated.</p> <p> <i>Available in Amazon GameLift Local.</i> </p> <ul> <li> <p> <a>CreatePlayerSession</a> </p> </li> <li> <p> <a>CreatePlayerSessions</a> </p> </li> <li> <p> <a>DescribePlayerSessions</a> </p> </li> <li> <p>Game session placements</p> <ul> <li> <p> <a>StartGameSessionPlacement</a> </p> </li> <li> <p> <a>DescribeGameSessionPlacement</a> </p> </li> <li> <p> <a>StopGameSessionPlacement</a> </p> </li> </ul> </li> </ul></p>
-    async fn create_player_sessions(
+    /// Presigns the request `create_player_sessions` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_player_sessions(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: CreatePlayerSessionsInput,
-    ) -> Result<CreatePlayerSessionsOutput, RusotoError<CreatePlayerSessionsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_player_sessions_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_player_sessions_request(
+        &self,
+        input: CreatePlayerSessionsInput,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "gamelift", &self.region, "/");
+        request.set_operation("CreatePlayerSessions");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "GameLift.CreatePlayerSessions");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `create_script`, also returning the metadata of the response.
+    pub async fn create_script_with_metadata(
+        &self,
+        input: CreateScriptInput,
+    ) -> Result<(CreateScriptOutput, ResponseMetadata), RusotoError<CreateScriptError>> {
+        let request = self.build_create_script_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<CreatePlayerSessionsOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<CreateScriptOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(CreatePlayerSessionsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(CreateScriptError::from_response(response))
         }
     }
 
-    /// <p><p>Creates a new script record for your Realtime Servers script. Realtime scripts are JavaScript that provide configuration settings and optional custom game logic for your game. The script is deployed when you create a Realtime Servers fleet to host your game sessions. Script logic is executed during an active game session. </p> <p>To create a new script record, specify a script name and provide the script file(s). The script files and all dependencies must be zipped into a single file. You can pull the zip file from either of these locations: </p> <ul> <li> <p>A locally available directory. Use the <i>ZipFile</i> parameter for this option.</p> </li> <li> <p>An Amazon Simple Storage Service (Amazon S3) bucket under your AWS account. Use the <i>StorageLocation</i> parameter for this option. You&#39;ll need to have an Identity Access Management (IAM) role that allows the Amazon GameLift service to access your S3 bucket. </p> </li> </ul> <p>If the call is successful, a new script record is created with a unique script ID. If the script file is provided as a local file, the file is uploaded to an Amazon GameLift-owned S3 bucket and the script record&#39;s storage location reflects this location. If the script file is provided as an S3 bucket, Amazon GameLift accesses the file at this storage location as needed for deployment.</p> <p> <b>Learn more</b> </p> <p> <a href="https://docs.aws.amazon.com/gamelift/latest/developerguide/realtime-intro.html">Amazon GameLift Realtime Servers</a> </p> <p> <a href="https://docs.aws.amazon.com/gamelift/latest/developerguide/setting-up-role.html">Set Up a Role for Amazon GameLift Access</a> </p> <p> <b>Related operations</b> </p> <ul> <li> <p> <a>CreateScript</a> </p> </li> <li> <p> <a>ListScripts</a> </p> </li> <li> <p> <a>DescribeScript</a> </p> </li> <li> <p> <a>UpdateScript</a> </p> </li> <li> <p> <a>DeleteScript</a> </p> </li> </ul></p>
-    async fn create_script(
+    /// Presigns the request `create_script` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_script(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: CreateScriptInput,
-    ) -> Result<CreateScriptOutput, RusotoError<CreateScriptError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_script_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_script_request(&self, input: CreateScriptInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "gamelift", &self.region, "/");
+        request.set_operation("CreateScript");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "GameLift.CreateScript");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `create_vpc_peering_authorization`, also returning the metadata of the response.
+    pub async fn create_vpc_peering_authorization_with_metadata(
+        &self,
+        input: CreateVpcPeeringAuthorizationInput,
+    ) -> Result<
+        (CreateVpcPeeringAuthorizationOutput, ResponseMetadata),
+        RusotoError<CreateVpcPeeringAuthorizationError>,
+    > {
+        let request = self.build_create_vpc_peering_authorization_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<CreateScriptOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<CreateVpcPeeringAuthorizationOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(CreateScriptError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(CreateVpcPeeringAuthorizationError::from_response(response))
         }
     }
 
-    /// <p><p>Requests authorization to create or delete a peer connection between the VPC for your Amazon GameLift fleet and a virtual private cloud (VPC) in your AWS account. VPC peering enables the game servers on your fleet to communicate directly with other AWS resources. Once you&#39;ve received authorization, call <a>CreateVpcPeeringConnection</a> to establish the peering connection. For more information, see <a href="https://docs.aws.amazon.com/gamelift/latest/developerguide/vpc-peering.html">VPC Peering with Amazon GameLift Fleets</a>.</p> <p>You can peer with VPCs that are owned by any AWS account you have access to, including the account that you use to manage your Amazon GameLift fleets. You cannot peer with VPCs that are in different Regions.</p> <p>To request authorization to create a connection, call this operation from the AWS account with the VPC that you want to peer to your Amazon GameLift fleet. For example, to enable your game servers to retrieve data from a DynamoDB table, use the account that manages that DynamoDB resource. Identify the following values: (1) The ID of the VPC that you want to peer with, and (2) the ID of the AWS account that you use to manage Amazon GameLift. If successful, VPC peering is authorized for the specified VPC. </p> <p>To request authorization to delete a connection, call this operation from the AWS account with the VPC that is peered with your Amazon GameLift fleet. Identify the following values: (1) VPC ID that you want to delete the peering connection for, and (2) ID of the AWS account that you use to manage Amazon GameLift. </p> <p>The authorization remains valid for 24 hours unless it is canceled by a call to <a>DeleteVpcPeeringAuthorization</a>. You must create or delete the peering connection while the authorization is valid. </p> <ul> <li> <p> <a>CreateVpcPeeringAuthorization</a> </p> </li> <li> <p> <a>DescribeVpcPeeringAuthorizations</a> </p> </li> <li> <p> <a>DeleteVpcPeeringAuthorization</a> </p> </li> <li> <p> <a>CreateVpcPeeringConnection</a> </p> </li> <li> <p> <a>DescribeVpcPeeringConnections</a> </p> </li> <li> <p> <a>DeleteVpcPeeringConnection</a> </p> </li> </ul></p>
-    async fn create_vpc_peering_authorization(
+    /// Presigns the request `create_vpc_peering_authorization` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_vpc_peering_authorization(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: CreateVpcPeeringAuthorizationInput,
-    ) -> Result<CreateVpcPeeringAuthorizationOutput, RusotoError<CreateVpcPeeringAuthorizationError>>
-    {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_vpc_peering_authorization_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_vpc_peering_authorization_request(
+        &self,
+        input: CreateVpcPeeringAuthorizationInput,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "gamelift", &self.region, "/");
+        request.set_operation("CreateVpcPeeringAuthorization");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "GameLift.CreateVpcPeeringAuthorization");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `create_vpc_peering_connection`, also returning the metadata of the response.
+    pub async fn create_vpc_peering_connection_with_metadata(
+        &self,
+        input: CreateVpcPeeringConnectionInput,
+    ) -> Result<
+        (CreateVpcPeeringConnectionOutput, ResponseMetadata),
+        RusotoError<CreateVpcPeeringConnectionError>,
+    > {
+        let request = self.build_create_vpc_peering_connection_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<CreateVpcPeeringAuthorizationOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<CreateVpcPeeringConnectionOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(CreateVpcPeeringAuthorizationError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(CreateVpcPeeringConnectionError::from_response(response))
         }
     }
 
-    /// <p><p>Establishes a VPC peering connection between a virtual private cloud (VPC) in an AWS account with the VPC for your Amazon GameLift fleet. VPC peering enables the game servers on your fleet to communicate directly with other AWS resources. You can peer with VPCs in any AWS account that you have access to, including the account that you use to manage your Amazon GameLift fleets. You cannot peer with VPCs that are in different Regions. For more information, see <a href="https://docs.aws.amazon.com/gamelift/latest/developerguide/vpc-peering.html">VPC Peering with Amazon GameLift Fleets</a>.</p> <p>Before calling this operation to establish the peering connection, you first need to call <a>CreateVpcPeeringAuthorization</a> and identify the VPC you want to peer with. Once the authorization for the specified VPC is issued, you have 24 hours to establish the connection. These two operations handle all tasks necessary to peer the two VPCs, including acceptance, updating routing tables, etc. </p> <p>To establish the connection, call this operation from the AWS account that is used to manage the Amazon GameLift fleets. Identify the following values: (1) The ID of the fleet you want to be enable a VPC peering connection for; (2) The AWS account with the VPC that you want to peer with; and (3) The ID of the VPC you want to peer with. This operation is asynchronous. If successful, a <a>VpcPeeringConnection</a> request is created. You can use continuous polling to track the request&#39;s status using <a>DescribeVpcPeeringConnections</a>, or by monitoring fleet events for success or failure using <a>DescribeFleetEvents</a>. </p> <ul> <li> <p> <a>CreateVpcPeeringAuthorization</a> </p> </li> <li> <p> <a>DescribeVpcPeeringAuthorizations</a> </p> </li> <li> <p> <a>DeleteVpcPeeringAuthorization</a> </p> </li> <li> <p> <a>CreateVpcPeeringConnection</a> </p> </li> <li> <p> <a>DescribeVpcPeeringConnections</a> </p> </li> <li> <p> <a>DeleteVpcPeeringConnection</a> </p> </li> </ul></p>
-    async fn create_vpc_peering_connection(
+    /// Presigns the request `create_vpc_peering_connection` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_create_vpc_peering_connection(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: CreateVpcPeeringConnectionInput,
-    ) -> Result<CreateVpcPeeringConnectionOutput, RusotoError<CreateVpcPeeringConnectionError>>
-    {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_create_vpc_peering_connection_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_create_vpc_peering_connection_request(
+        &self,
+        input: CreateVpcPeeringConnectionInput,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "gamelift", &self.region, "/");
+        request.set_operation("CreateVpcPeeringConnection");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "GameLift.CreateVpcPeeringConnection");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `delete_alias`, also returning the metadata of the response.
+    pub async fn delete_alias_with_metadata(
+        &self,
+        input: DeleteAliasInput,
+    ) -> Result<((), ResponseMetadata), RusotoError<DeleteAliasError>> {
+        let request = self.build_delete_alias_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<CreateVpcPeeringConnectionOutput, _>()
+            std::mem::drop(response);
+            Ok(((), metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(CreateVpcPeeringConnectionError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DeleteAliasError::from_response(response))
         }
     }
 
-    /// <p><p>Deletes an alias. This action removes all record of the alias. Game clients attempting to access a server process using the deleted alias receive an error. To delete an alias, specify the alias ID to be deleted.</p> <ul> <li> <p> <a>CreateAlias</a> </p> </li> <li> <p> <a>ListAliases</a> </p> </li> <li> <p> <a>DescribeAlias</a> </p> </li> <li> <p> <a>UpdateAlias</a> </p> </li> <li> <p> <a>DeleteAlias</a> </p> </li> <li> <p> <a>ResolveAlias</a> </p> </li> </ul></p>
-    async fn delete_alias(
+    /// Presigns the request `delete_alias` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_alias(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteAliasInput,
-    ) -> Result<(), RusotoError<DeleteAliasError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_alias_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_alias_request(&self, input: DeleteAliasInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "gamelift", &self.region, "/");
+        request.set_operation("DeleteAlias");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "GameLift.DeleteAlias");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `delete_build`, also returning the metadata of the response.
+    pub async fn delete_build_with_metadata(
+        &self,
+        input: DeleteBuildInput,
+    ) -> Result<((), ResponseMetadata), RusotoError<DeleteBuildError>> {
+        let request = self.build_delete_build_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
             std::mem::drop(response);
-            Ok(())
+            Ok(((), metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteAliasError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DeleteBuildError::from_response(response))
         }
     }
 
-    /// <p><p>Deletes a build. This action permanently deletes the build record and any uploaded build files.</p> <p>To delete a build, specify its ID. Deleting a build does not affect the status of any active fleets using the build, but you can no longer create new fleets with the deleted build.</p> <p> <b>Learn more</b> </p> <p> <a href="https://docs.aws.amazon.com/gamelift/latest/developerguide/build-intro.html"> Working with Builds</a> </p> <p> <b>Related operations</b> </p> <ul> <li> <p> <a>CreateBuild</a> </p> </li> <li> <p> <a>ListBuilds</a> </p> </li> <li> <p> <a>DescribeBuild</a> </p> </li> <li> <p> <a>UpdateBuild</a> </p> </li> <li> <p> <a>DeleteBuild</a> </p> </li> </ul></p>
-    async fn delete_build(
+    /// Presigns the request `delete_build` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_build(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteBuildInput,
-    ) -> Result<(), RusotoError<DeleteBuildError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_build_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_build_request(&self, input: DeleteBuildInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "gamelift", &self.region, "/");
+        request.set_operation("DeleteBuild");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "GameLift.DeleteBuild");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `delete_fleet`, also returning the metadata of the response.
+    pub async fn delete_fleet_with_metadata(
+        &self,
+        input: DeleteFleetInput,
+    ) -> Result<((), ResponseMetadata), RusotoError<DeleteFleetError>> {
+        let request = self.build_delete_fleet_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
             std::mem::drop(response);
-            Ok(())
+            Ok(((), metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteBuildError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DeleteFleetError::from_response(response))
         }
     }
 
-    /// <p><p>Deletes everything related to a fleet. Before deleting a fleet, you must set the fleet&#39;s desired capacity to zero. See <a>UpdateFleetCapacity</a>.</p> <p>If the fleet being deleted has a VPC peering connection, you first need to get a valid authorization (good for 24 hours) by calling <a>CreateVpcPeeringAuthorization</a>. You do not need to explicitly delete the VPC peering connection--this is done as part of the delete fleet process.</p> <p>This action removes the fleet&#39;s resources and the fleet record. Once a fleet is deleted, you can no longer use that fleet.</p> <p> <b>Learn more</b> </p> <p> <a href="https://docs.aws.amazon.com/gamelift/latest/developerguide/fleets-intro.html"> Working with Fleets</a>.</p> <p> <b>Related operations</b> </p> <ul> <li> <p> <a>CreateFleet</a> </p> </li> <li> <p> <a>ListFleets</a> </p> </li> <li> <p> <a>DeleteFleet</a> </p> </li> <li> <p> <a>DescribeFleetAttributes</a> </p> </li> <li> <p> <a>UpdateFleetAttributes</a> </p> </li> <li> <p>Manage fleet actions:</p> <ul> <li> <p> <a>StartFleetActions</a> </p> </li> <li> <p> <a>StopFleetActions</a> </p> </li> </ul> </li> </ul></p>
-    async fn delete_fleet(
+    /// Presigns the request `delete_fleet` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_fleet(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteFleetInput,
-    ) -> Result<(), RusotoError<DeleteFleetError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_fleet_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_fleet_request(&self, input: DeleteFleetInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "gamelift", &self.region, "/");
+        request.set_operation("DeleteFleet");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "GameLift.DeleteFleet");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `delete_game_session_queue`, also returning the metadata of the response.
+    pub async fn delete_game_session_queue_with_metadata(
+        &self,
+        input: DeleteGameSessionQueueInput,
+    ) -> Result<
+        (DeleteGameSessionQueueOutput, ResponseMetadata),
+        RusotoError<DeleteGameSessionQueueError>,
+    > {
+        let request = self.build_delete_game_session_queue_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            std::mem::drop(response);
-            Ok(())
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DeleteGameSessionQueueOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteFleetError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DeleteGameSessionQueueError::from_response(response))
         }
     }
 
-    /// <p><p>Deletes a game session queue. This action means that any <a>StartGameSessionPlacement</a> requests that reference this queue will fail. To delete a queue, specify the queue name.</p> <ul> <li> <p> <a>CreateGameSessionQueue</a> </p> </li> <li> <p> <a>DescribeGameSessionQueues</a> </p> </li> <li> <p> <a>UpdateGameSessionQueue</a> </p> </li> <li> <p> <a>DeleteGameSessionQueue</a> </p> </li> </ul></p>
-    async fn delete_game_session_queue(
+    /// Presigns the request `delete_game_session_queue` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_game_session_queue(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteGameSessionQueueInput,
-    ) -> Result<DeleteGameSessionQueueOutput, RusotoError<DeleteGameSessionQueueError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_game_session_queue_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_game_session_queue_request(
+        &self,
+        input: DeleteGameSessionQueueInput,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "gamelift", &self.region, "/");
+        request.set_operation("DeleteGameSessionQueue");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "GameLift.DeleteGameSessionQueue");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `delete_matchmaking_configuration`, also returning the metadata of the response.
+    pub async fn delete_matchmaking_configuration_with_metadata(
+        &self,
+        input: DeleteMatchmakingConfigurationInput,
+    ) -> Result<
+        (DeleteMatchmakingConfigurationOutput, ResponseMetadata),
+        RusotoError<DeleteMatchmakingConfigurationError>,
+    > {
+        let request = self.build_delete_matchmaking_configuration_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DeleteGameSessionQueueOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DeleteMatchmakingConfigurationOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteGameSessionQueueError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DeleteMatchmakingConfigurationError::from_response(response))
         }
     }
 
-    /// <p><p>Permanently removes a FlexMatch matchmaking configuration. To delete, specify the configuration name. A matchmaking configuration cannot be deleted if it is being used in any active matchmaking tickets.</p> <p> <b>Related operations</b> </p> <ul> <li> <p> <a>CreateMatchmakingConfiguration</a> </p> </li> <li> <p> <a>DescribeMatchmakingConfigurations</a> </p> </li> <li> <p> <a>UpdateMatchmakingConfiguration</a> </p> </li> <li> <p> <a>DeleteMatchmakingConfiguration</a> </p> </li> <li> <p> <a>CreateMatchmakingRuleSet</a> </p> </li> <li> <p> <a>DescribeMatchmakingRuleSets</a> </p> </li> <li> <p> <a>ValidateMatchmakingRuleSet</a> </p> </li> <li> <p> <a>DeleteMatchmakingRuleSet</a> </p> </li> </ul></p>
-    async fn delete_matchmaking_configuration(
+    /// Presigns the request `delete_matchmaking_configuration` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_matchmaking_configuration(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteMatchmakingConfigurationInput,
-    ) -> Result<
-        DeleteMatchmakingConfigurationOutput,
-        RusotoError<DeleteMatchmakingConfigurationError>,
-    > {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_matchmaking_configuration_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_matchmaking_configuration_request(
+        &self,
+        input: DeleteMatchmakingConfigurationInput,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "gamelift", &self.region, "/");
+        request.set_operation("DeleteMatchmakingConfiguration");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "GameLift.DeleteMatchmakingConfiguration");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `delete_matchmaking_rule_set`, also returning the metadata of the response.
+    pub async fn delete_matchmaking_rule_set_with_metadata(
+        &self,
+        input: DeleteMatchmakingRuleSetInput,
+    ) -> Result<
+        (DeleteMatchmakingRuleSetOutput, ResponseMetadata),
+        RusotoError<DeleteMatchmakingRuleSetError>,
+    > {
+        let request = self.build_delete_matchmaking_rule_set_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DeleteMatchmakingConfigurationOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DeleteMatchmakingRuleSetOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteMatchmakingConfigurationError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DeleteMatchmakingRuleSetError::from_response(response))
         }
     }
 
-    /// <p><p>Deletes an existing matchmaking rule set. To delete the rule set, provide the rule set name. Rule sets cannot be deleted if they are currently being used by a matchmaking configuration. </p> <p> <b>Learn more</b> </p> <ul> <li> <p> <a href="https://docs.aws.amazon.com/gamelift/latest/developerguide/match-rulesets.html">Build a Rule Set</a> </p> </li> </ul> <p> <b>Related operations</b> </p> <ul> <li> <p> <a>CreateMatchmakingConfiguration</a> </p> </li> <li> <p> <a>DescribeMatchmakingConfigurations</a> </p> </li> <li> <p> <a>UpdateMatchmakingConfiguration</a> </p> </li> <li> <p> <a>DeleteMatchmakingConfiguration</a> </p> </li> <li> <p> <a>CreateMatchmakingRuleSet</a> </p> </li> <li> <p> <a>DescribeMatchmakingRuleSets</a> </p> </li> <li> <p> <a>ValidateMatchmakingRuleSet</a> </p> </li> <li> <p> <a>DeleteMatchmakingRuleSet</a> </p> </li> </ul></p>
-    async fn delete_matchmaking_rule_set(
+    /// Presigns the request `delete_matchmaking_rule_set` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_matchmaking_rule_set(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteMatchmakingRuleSetInput,
-    ) -> Result<DeleteMatchmakingRuleSetOutput, RusotoError<DeleteMatchmakingRuleSetError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_matchmaking_rule_set_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_matchmaking_rule_set_request(
+        &self,
+        input: DeleteMatchmakingRuleSetInput,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "gamelift", &self.region, "/");
+        request.set_operation("DeleteMatchmakingRuleSet");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "GameLift.DeleteMatchmakingRuleSet");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `delete_scaling_policy`, also returning the metadata of the response.
+    pub async fn delete_scaling_policy_with_metadata(
+        &self,
+        input: DeleteScalingPolicyInput,
+    ) -> Result<((), ResponseMetadata), RusotoError<DeleteScalingPolicyError>> {
+        let request = self.build_delete_scaling_policy_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DeleteMatchmakingRuleSetOutput, _>()
+            std::mem::drop(response);
+            Ok(((), metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteMatchmakingRuleSetError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DeleteScalingPolicyError::from_response(response))
         }
     }
 
-    /// <p><p>Deletes a fleet scaling policy. This action means that the policy is no longer in force and removes all record of it. To delete a scaling policy, specify both the scaling policy name and the fleet ID it is associated with.</p> <p>To temporarily suspend scaling policies, call <a>StopFleetActions</a>. This operation suspends all policies for the fleet.</p> <ul> <li> <p> <a>DescribeFleetCapacity</a> </p> </li> <li> <p> <a>UpdateFleetCapacity</a> </p> </li> <li> <p> <a>DescribeEC2InstanceLimits</a> </p> </li> <li> <p>Manage scaling policies:</p> <ul> <li> <p> <a>PutScalingPolicy</a> (auto-scaling)</p> </li> <li> <p> <a>DescribeScalingPolicies</a> (auto-scaling)</p> </li> <li> <p> <a>DeleteScalingPolicy</a> (auto-scaling)</p> </li> </ul> </li> <li> <p>Manage fleet actions:</p> <ul> <li> <p> <a>StartFleetActions</a> </p> </li> <li> <p> <a>StopFleetActions</a> </p> </li> </ul> </li> </ul></p>
-    async fn delete_scaling_policy(
+    /// Presigns the request `delete_scaling_policy` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_scaling_policy(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteScalingPolicyInput,
-    ) -> Result<(), RusotoError<DeleteScalingPolicyError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_scaling_policy_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_scaling_policy_request(
+        &self,
+        input: DeleteScalingPolicyInput,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "gamelift", &self.region, "/");
+        request.set_operation("DeleteScalingPolicy");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "GameLift.DeleteScalingPolicy");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `delete_script`, also returning the metadata of the response.
+    pub async fn delete_script_with_metadata(
+        &self,
+        input: DeleteScriptInput,
+    ) -> Result<((), ResponseMetadata), RusotoError<DeleteScriptError>> {
+        let request = self.build_delete_script_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
             std::mem::drop(response);
-            Ok(())
+            Ok(((), metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteScalingPolicyError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DeleteScriptError::from_response(response))
         }
     }
 
-    /// <p><p>Deletes a Realtime script. This action permanently deletes the script record. If script files were uploaded, they are also deleted (files stored in an S3 bucket are not deleted). </p> <p>To delete a script, specify the script ID. Before deleting a script, be sure to terminate all fleets that are deployed with the script being deleted. Fleet instances periodically check for script updates, and if the script record no longer exists, the instance will go into an error state and be unable to host game sessions.</p> <p> <b>Learn more</b> </p> <p> <a href="https://docs.aws.amazon.com/gamelift/latest/developerguide/realtime-intro.html">Amazon GameLift Realtime Servers</a> </p> <p> <b>Related operations</b> </p> <ul> <li> <p> <a>CreateScript</a> </p> </li> <li> <p> <a>ListScripts</a> </p> </li> <li> <p> <a>DescribeScript</a> </p> </li> <li> <p> <a>UpdateScript</a> </p> </li> <li> <p> <a>DeleteScript</a> </p> </li> </ul></p>
-    async fn delete_script(
+    /// Presigns the request `delete_script` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_script(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteScriptInput,
-    ) -> Result<(), RusotoError<DeleteScriptError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_script_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_script_request(&self, input: DeleteScriptInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "gamelift", &self.region, "/");
+        request.set_operation("DeleteScript");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "GameLift.DeleteScript");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `delete_vpc_peering_authorization`, also returning the metadata of the response.
+    pub async fn delete_vpc_peering_authorization_with_metadata(
+        &self,
+        input: DeleteVpcPeeringAuthorizationInput,
+    ) -> Result<
+        (DeleteVpcPeeringAuthorizationOutput, ResponseMetadata),
+        RusotoError<DeleteVpcPeeringAuthorizationError>,
+    > {
+        let request = self.build_delete_vpc_peering_authorization_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            std::mem::drop(response);
-            Ok(())
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DeleteVpcPeeringAuthorizationOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteScriptError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DeleteVpcPeeringAuthorizationError::from_response(response))
         }
     }
 
-    /// <p><p>Cancels a pending VPC peering authorization for the specified VPC. If you need to delete an existing VPC peering connection, call <a>DeleteVpcPeeringConnection</a>. </p> <ul> <li> <p> <a>CreateVpcPeeringAuthorization</a> </p> </li> <li> <p> <a>DescribeVpcPeeringAuthorizations</a> </p> </li> <li> <p> <a>DeleteVpcPeeringAuthorization</a> </p> </li> <li> <p> <a>CreateVpcPeeringConnection</a> </p> </li> <li> <p> <a>DescribeVpcPeeringConnections</a> </p> </li> <li> <p> <a>DeleteVpcPeeringConnection</a> </p> </li> </ul></p>
-    async fn delete_vpc_peering_authorization(
+    /// Presigns the request `delete_vpc_peering_authorization` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_vpc_peering_authorization(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteVpcPeeringAuthorizationInput,
-    ) -> Result<DeleteVpcPeeringAuthorizationOutput, RusotoError<DeleteVpcPeeringAuthorizationError>>
-    {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_vpc_peering_authorization_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_vpc_peering_authorization_request(
+        &self,
+        input: DeleteVpcPeeringAuthorizationInput,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "gamelift", &self.region, "/");
+        request.set_operation("DeleteVpcPeeringAuthorization");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "GameLift.DeleteVpcPeeringAuthorization");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `delete_vpc_peering_connection`, also returning the metadata of the response.
+    pub async fn delete_vpc_peering_connection_with_metadata(
+        &self,
+        input: DeleteVpcPeeringConnectionInput,
+    ) -> Result<
+        (DeleteVpcPeeringConnectionOutput, ResponseMetadata),
+        RusotoError<DeleteVpcPeeringConnectionError>,
+    > {
+        let request = self.build_delete_vpc_peering_connection_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DeleteVpcPeeringAuthorizationOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DeleteVpcPeeringConnectionOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteVpcPeeringAuthorizationError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DeleteVpcPeeringConnectionError::from_response(response))
         }
     }
 
-    /// <p><p>Removes a VPC peering connection. To delete the connection, you must have a valid authorization for the VPC peering connection that you want to delete. You can check for an authorization by calling <a>DescribeVpcPeeringAuthorizations</a> or request a new one using <a>CreateVpcPeeringAuthorization</a>. </p> <p>Once a valid authorization exists, call this operation from the AWS account that is used to manage the Amazon GameLift fleets. Identify the connection to delete by the connection ID and fleet ID. If successful, the connection is removed. </p> <ul> <li> <p> <a>CreateVpcPeeringAuthorization</a> </p> </li> <li> <p> <a>DescribeVpcPeeringAuthorizations</a> </p> </li> <li> <p> <a>DeleteVpcPeeringAuthorization</a> </p> </li> <li> <p> <a>CreateVpcPeeringConnection</a> </p> </li> <li> <p> <a>DescribeVpcPeeringConnections</a> </p> </li> <li> <p> <a>DeleteVpcPeeringConnection</a> </p> </li> </ul></p>
-    async fn delete_vpc_peering_connection(
+    /// Presigns the request `delete_vpc_peering_connection` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_delete_vpc_peering_connection(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DeleteVpcPeeringConnectionInput,
-    ) -> Result<DeleteVpcPeeringConnectionOutput, RusotoError<DeleteVpcPeeringConnectionError>>
-    {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_delete_vpc_peering_connection_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_delete_vpc_peering_connection_request(
+        &self,
+        input: DeleteVpcPeeringConnectionInput,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "gamelift", &self.region, "/");
+        request.set_operation("DeleteVpcPeeringConnection");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "GameLift.DeleteVpcPeeringConnection");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `describe_alias`, also returning the metadata of the response.
+    pub async fn describe_alias_with_metadata(
+        &self,
+        input: DescribeAliasInput,
+    ) -> Result<(DescribeAliasOutput, ResponseMetadata), RusotoError<DescribeAliasError>> {
+        let request = self.build_describe_alias_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DeleteVpcPeeringConnectionOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DescribeAliasOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DeleteVpcPeeringConnectionError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DescribeAliasError::from_response(response))
         }
     }
 
-    /// <p><p>Retrieves properties for an alias. This operation returns all alias metadata and settings. To get an alias&#39;s target fleet ID only, use <code>ResolveAlias</code>. </p> <p>To get alias properties, specify the alias ID. If successful, the requested alias record is returned.</p> <ul> <li> <p> <a>CreateAlias</a> </p> </li> <li> <p> <a>ListAliases</a> </p> </li> <li> <p> <a>DescribeAlias</a> </p> </li> <li> <p> <a>UpdateAlias</a> </p> </li> <li> <p> <a>DeleteAlias</a> </p> </li> <li> <p> <a>ResolveAlias</a> </p> </li> </ul></p>
-    async fn describe_alias(
+    /// Presigns the request `describe_alias` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_alias(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeAliasInput,
-    ) -> Result<DescribeAliasOutput, RusotoError<DescribeAliasError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_alias_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_alias_request(&self, input: DescribeAliasInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "gamelift", &self.region, "/");
+        request.set_operation("DescribeAlias");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "GameLift.DescribeAlias");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `describe_build`, also returning the metadata of the response.
+    pub async fn describe_build_with_metadata(
+        &self,
+        input: DescribeBuildInput,
+    ) -> Result<(DescribeBuildOutput, ResponseMetadata), RusotoError<DescribeBuildError>> {
+        let request = self.build_describe_build_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<DescribeAliasOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DescribeBuildOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeAliasError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DescribeBuildError::from_response(response))
         }
     }
 
-    /// <p><p>Retrieves properties for a build. To request a build record, specify a build ID. If successful, an object containing the build properties is returned.</p> <p> <b>Learn more</b> </p> <p> <a href="https://docs.aws.amazon.com/gamelift/latest/developerguide/build-intro.html"> Working with Builds</a> </p> <p> <b>Related operations</b> </p> <ul> <li> <p> <a>CreateBuild</a> </p> </li> <li> <p> <a>ListBuilds</a> </p> </li> <li> <p> <a>DescribeBuild</a> </p> </li> <li> <p> <a>UpdateBuild</a> </p> </li> <li> <p> <a>DeleteBuild</a> </p> </li> </ul></p>
-    async fn describe_build(
+    /// Presigns the request `describe_build` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_build(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeBuildInput,
-    ) -> Result<DescribeBuildOutput, RusotoError<DescribeBuildError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_build_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_build_request(&self, input: DescribeBuildInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "gamelift", &self.region, "/");
+        request.set_operation("DescribeBuild");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "GameLift.DescribeBuild");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `describe_ec2_instance_limits`, also returning the metadata of the response.
+    pub async fn describe_ec2_instance_limits_with_metadata(
+        &self,
+        input: DescribeEC2InstanceLimitsInput,
+    ) -> Result<
+        (DescribeEC2InstanceLimitsOutput, ResponseMetadata),
+        RusotoError<DescribeEC2InstanceLimitsError>,
+    > {
+        let request = self.build_describe_ec2_instance_limits_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<DescribeBuildOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DescribeEC2InstanceLimitsOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeBuildError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DescribeEC2InstanceLimitsError::from_response(response))
         }
     }
 
-    /// <p><p>Retrieves the following information for the specified EC2 instance type:</p> <ul> <li> <p>maximum number of instances allowed per AWS account (service limit)</p> </li> <li> <p>current usage level for the AWS account</p> </li> </ul> <p>Service limits vary depending on Region. Available Regions for Amazon GameLift can be found in the AWS Management Console for Amazon GameLift (see the drop-down list in the upper right corner).</p> <p> <b>Learn more</b> </p> <p> <a href="https://docs.aws.amazon.com/gamelift/latest/developerguide/fleets-intro.html"> Working with Fleets</a>.</p> <p> <b>Related operations</b> </p> <ul> <li> <p> <a>CreateFleet</a> </p> </li> <li> <p> <a>ListFleets</a> </p> </li> <li> <p> <a>DeleteFleet</a> </p> </li> <li> <p>Describe fleets:</p> <ul> <li> <p> <a>DescribeFleetAttributes</a> </p> </li> <li> <p> <a>DescribeFleetCapacity</a> </p> </li> <li> <p> <a>DescribeFleetPortSettings</a> </p> </li> <li> <p> <a>DescribeFleetUtilization</a> </p> </li> <li> <p> <a>DescribeRuntimeConfiguration</a> </p> </li> <li> <p> <a>DescribeEC2InstanceLimits</a> </p> </li> <li> <p> <a>DescribeFleetEvents</a> </p> </li> </ul> </li> <li> <p>Update fleets:</p> <ul> <li> <p> <a>UpdateFleetAttributes</a> </p> </li> <li> <p> <a>UpdateFleetCapacity</a> </p> </li> <li> <p> <a>UpdateFleetPortSettings</a> </p> </li> <li> <p> <a>UpdateRuntimeConfiguration</a> </p> </li> </ul> </li> <li> <p>Manage fleet actions:</p> <ul> <li> <p> <a>StartFleetActions</a> </p> </li> <li> <p> <a>StopFleetActions</a> </p> </li> </ul> </li> </ul></p>
-    async fn describe_ec2_instance_limits(
+    /// Presigns the request `describe_ec2_instance_limits` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_ec2_instance_limits(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeEC2InstanceLimitsInput,
-    ) -> Result<DescribeEC2InstanceLimitsOutput, RusotoError<DescribeEC2InstanceLimitsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_ec2_instance_limits_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_ec2_instance_limits_request(
+        &self,
+        input: DescribeEC2InstanceLimitsInput,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "gamelift", &self.region, "/");
+        request.set_operation("DescribeEC2InstanceLimits");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "GameLift.DescribeEC2InstanceLimits");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `describe_fleet_attributes`, also returning the metadata of the response.
+    pub async fn describe_fleet_attributes_with_metadata(
+        &self,
+        input: DescribeFleetAttributesInput,
+    ) -> Result<
+        (DescribeFleetAttributesOutput, ResponseMetadata),
+        RusotoError<DescribeFleetAttributesError>,
+    > {
+        let request = self.build_describe_fleet_attributes_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DescribeEC2InstanceLimitsOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DescribeFleetAttributesOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeEC2InstanceLimitsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DescribeFleetAttributesError::from_response(response))
         }
     }
 
-    /// <p><p>Retrieves fleet properties, including metadata, status, and configuration, for one or more fleets. You can request attributes for all fleets, or specify a list of one or more fleet IDs. When requesting multiple fleets, use the pagination parameters to retrieve results as a set of sequential pages. If successful, a <a>FleetAttributes</a> object is returned for each requested fleet ID. When specifying a list of fleet IDs, attribute objects are returned only for fleets that currently exist. </p> <note> <p>Some API actions may limit the number of fleet IDs allowed in one request. If a request exceeds this limit, the request fails and the error message includes the maximum allowed.</p> </note> <p> <b>Learn more</b> </p> <p> <a href="https://docs.aws.amazon.com/gamelift/latest/developerguide/fleets-intro.html"> Working with Fleets</a>.</p> <p> <b>Related operations</b> </p> <ul> <li> <p> <a>CreateFleet</a> </p> </li> <li> <p> <a>ListFleets</a> </p> </li> <li> <p> <a>DeleteFleet</a> </p> </li> <li> <p>Describe fleets:</p> <ul> <li> <p> <a>DescribeFleetAttributes</a> </p> </li> <li> <p> <a>DescribeFleetCapacity</a> </p> </li> <li> <p> <a>DescribeFleetPortSettings</a> </p> </li> <li> <p> <a>DescribeFleetUtilization</a> </p> </li> <li> <p> <a>DescribeRuntimeConfiguration</a> </p> </li> <li> <p> <a>DescribeEC2InstanceLimits</a> </p> </li> <li> <p> <a>DescribeFleetEvents</a> </p> </li> </ul> </li> <li> <p> <a>UpdateFleetAttributes</a> </p> </li> <li> <p>Manage fleet actions:</p> <ul> <li> <p> <a>StartFleetActions</a> </p> </li> <li> <p> <a>StopFleetActions</a> </p> </li> </ul> </li> </ul></p>
-    async fn describe_fleet_attributes(
+    /// Presigns the request `describe_fleet_attributes` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_fleet_attributes(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeFleetAttributesInput,
-    ) -> Result<DescribeFleetAttributesOutput, RusotoError<DescribeFleetAttributesError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_fleet_attributes_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_fleet_attributes_request(
+        &self,
+        input: DescribeFleetAttributesInput,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "gamelift", &self.region, "/");
+        request.set_operation("DescribeFleetAttributes");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "GameLift.DescribeFleetAttributes");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `describe_fleet_capacity`, also returning the metadata of the response.
+    pub async fn describe_fleet_capacity_with_metadata(
+        &self,
+        input: DescribeFleetCapacityInput,
+    ) -> Result<
+        (DescribeFleetCapacityOutput, ResponseMetadata),
+        RusotoError<DescribeFleetCapacityError>,
+    > {
+        let request = self.build_describe_fleet_capacity_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DescribeFleetAttributesOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DescribeFleetCapacityOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeFleetAttributesError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DescribeFleetCapacityError::from_response(response))
         }
     }
 
-    /// <p><p>Retrieves the current status of fleet capacity for one or more fleets. This information includes the number of instances that have been requested for the fleet and the number currently active. You can request capacity for all fleets, or specify a list of one or more fleet IDs. When requesting multiple fleets, use the pagination parameters to retrieve results as a set of sequential pages. If successful, a <a>FleetCapacity</a> object is returned for each requested fleet ID. When specifying a list of fleet IDs, attribute objects are returned only for fleets that currently exist. </p> <note> <p>Some API actions may limit the number of fleet IDs allowed in one request. If a request exceeds this limit, the request fails and the error message includes the maximum allowed.</p> </note> <p> <b>Learn more</b> </p> <p> <a href="https://docs.aws.amazon.com/gamelift/latest/developerguide/fleets-intro.html"> Working with Fleets</a>.</p> <p> <b>Related operations</b> </p> <ul> <li> <p> <a>CreateFleet</a> </p> </li> <li> <p> <a>ListFleets</a> </p> </li> <li> <p> <a>DeleteFleet</a> </p> </li> <li> <p>Describe fleets:</p> <ul> <li> <p> <a>DescribeFleetAttributes</a> </p> </li> <li> <p> <a>DescribeFleetCapacity</a> </p> </li> <li> <p> <a>DescribeFleetPortSettings</a> </p> </li> <li> <p> <a>DescribeFleetUtilization</a> </p> </li> <li> <p> <a>DescribeRuntimeConfiguration</a> </p> </li> <li> <p> <a>DescribeEC2InstanceLimits</a> </p> </li> <li> <p> <a>DescribeFleetEvents</a> </p> </li> </ul> </li> <li> <p> <a>UpdateFleetAttributes</a> </p> </li> <li> <p>Manage fleet actions:</p> <ul> <li> <p> <a>StartFleetActions</a> </p> </li> <li> <p> <a>StopFleetActions</a> </p> </li> </ul> </li> </ul></p>
-    async fn describe_fleet_capacity(
+    /// Presigns the request `describe_fleet_capacity` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_fleet_capacity(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeFleetCapacityInput,
-    ) -> Result<DescribeFleetCapacityOutput, RusotoError<DescribeFleetCapacityError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_fleet_capacity_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_fleet_capacity_request(
+        &self,
+        input: DescribeFleetCapacityInput,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "gamelift", &self.region, "/");
+        request.set_operation("DescribeFleetCapacity");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "GameLift.DescribeFleetCapacity");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `describe_fleet_events`, also returning the metadata of the response.
+    pub async fn describe_fleet_events_with_metadata(
+        &self,
+        input: DescribeFleetEventsInput,
+    ) -> Result<(DescribeFleetEventsOutput, ResponseMetadata), RusotoError<DescribeFleetEventsError>>
+    {
+        let request = self.build_describe_fleet_events_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DescribeFleetCapacityOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DescribeFleetEventsOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeFleetCapacityError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DescribeFleetEventsError::from_response(response))
         }
     }
 
-    /// <p><p>Retrieves entries from the specified fleet&#39;s event log. You can specify a time range to limit the result set. Use the pagination parameters to retrieve results as a set of sequential pages. If successful, a collection of event log entries matching the request are returned.</p> <p> <b>Learn more</b> </p> <p> <a href="https://docs.aws.amazon.com/gamelift/latest/developerguide/fleets-intro.html"> Working with Fleets</a>.</p> <p> <b>Related operations</b> </p> <ul> <li> <p> <a>CreateFleet</a> </p> </li> <li> <p> <a>ListFleets</a> </p> </li> <li> <p> <a>DeleteFleet</a> </p> </li> <li> <p>Describe fleets:</p> <ul> <li> <p> <a>DescribeFleetAttributes</a> </p> </li> <li> <p> <a>DescribeFleetCapacity</a> </p> </li> <li> <p> <a>DescribeFleetPortSettings</a> </p> </li> <li> <p> <a>DescribeFleetUtilization</a> </p> </li> <li> <p> <a>DescribeRuntimeConfiguration</a> </p> </li> <li> <p> <a>DescribeEC2InstanceLimits</a> </p> </li> <li> <p> <a>DescribeFleetEvents</a> </p> </li> </ul> </li> <li> <p> <a>UpdateFleetAttributes</a> </p> </li> <li> <p>Manage fleet actions:</p> <ul> <li> <p> <a>StartFleetActions</a> </p> </li> <li> <p> <a>StopFleetActions</a> </p> </li> </ul> </li> </ul></p>
-    async fn describe_fleet_events(
+    /// Presigns the request `describe_fleet_events` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_fleet_events(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeFleetEventsInput,
-    ) -> Result<DescribeFleetEventsOutput, RusotoError<DescribeFleetEventsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_fleet_events_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_fleet_events_request(
+        &self,
+        input: DescribeFleetEventsInput,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "gamelift", &self.region, "/");
+        request.set_operation("DescribeFleetEvents");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "GameLift.DescribeFleetEvents");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `describe_fleet_port_settings`, also returning the metadata of the response.
+    pub async fn describe_fleet_port_settings_with_metadata(
+        &self,
+        input: DescribeFleetPortSettingsInput,
+    ) -> Result<
+        (DescribeFleetPortSettingsOutput, ResponseMetadata),
+        RusotoError<DescribeFleetPortSettingsError>,
+    > {
+        let request = self.build_describe_fleet_port_settings_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DescribeFleetEventsOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DescribeFleetPortSettingsOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeFleetEventsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DescribeFleetPortSettingsError::from_response(response))
         }
     }
 
-    /// <p><p>Retrieves the inbound connection permissions for a fleet. Connection permissions include a range of IP addresses and port settings that incoming traffic can use to access server processes in the fleet. To get a fleet&#39;s inbound connection permissions, specify a fleet ID. If successful, a collection of <a>IpPermission</a> objects is returned for the requested fleet ID. If the requested fleet has been deleted, the result set is empty.</p> <p> <b>Learn more</b> </p> <p> <a href="https://docs.aws.amazon.com/gamelift/latest/developerguide/fleets-intro.html"> Working with Fleets</a>.</p> <p> <b>Related operations</b> </p> <ul> <li> <p> <a>CreateFleet</a> </p> </li> <li> <p> <a>ListFleets</a> </p> </li> <li> <p> <a>DeleteFleet</a> </p> </li> <li> <p>Describe fleets:</p> <ul> <li> <p> <a>DescribeFleetAttributes</a> </p> </li> <li> <p> <a>DescribeFleetCapacity</a> </p> </li> <li> <p> <a>DescribeFleetPortSettings</a> </p> </li> <li> <p> <a>DescribeFleetUtilization</a> </p> </li> <li> <p> <a>DescribeRuntimeConfiguration</a> </p> </li> <li> <p> <a>DescribeEC2InstanceLimits</a> </p> </li> <li> <p> <a>DescribeFleetEvents</a> </p> </li> </ul> </li> <li> <p> <a>UpdateFleetAttributes</a> </p> </li> <li> <p>Manage fleet actions:</p> <ul> <li> <p> <a>StartFleetActions</a> </p> </li> <li> <p> <a>StopFleetActions</a> </p> </li> </ul> </li> </ul></p>
-    async fn describe_fleet_port_settings(
+    /// Presigns the request `describe_fleet_port_settings` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_fleet_port_settings(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeFleetPortSettingsInput,
-    ) -> Result<DescribeFleetPortSettingsOutput, RusotoError<DescribeFleetPortSettingsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_fleet_port_settings_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_fleet_port_settings_request(
+        &self,
+        input: DescribeFleetPortSettingsInput,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "gamelift", &self.region, "/");
+        request.set_operation("DescribeFleetPortSettings");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "GameLift.DescribeFleetPortSettings");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `describe_fleet_utilization`, also returning the metadata of the response.
+    pub async fn describe_fleet_utilization_with_metadata(
+        &self,
+        input: DescribeFleetUtilizationInput,
+    ) -> Result<
+        (DescribeFleetUtilizationOutput, ResponseMetadata),
+        RusotoError<DescribeFleetUtilizationError>,
+    > {
+        let request = self.build_describe_fleet_utilization_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DescribeFleetPortSettingsOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DescribeFleetUtilizationOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeFleetPortSettingsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DescribeFleetUtilizationError::from_response(response))
         }
     }
 
-    /// <p><p>Retrieves utilization statistics for one or more fleets. You can request utilization data for all fleets, or specify a list of one or more fleet IDs. When requesting multiple fleets, use the pagination parameters to retrieve results as a set of sequential pages. If successful, a <a>FleetUtilization</a> object is returned for each requested fleet ID. When specifying a list of fleet IDs, utilization objects are returned only for fleets that currently exist. </p> <note> <p>Some API actions may limit the number of fleet IDs allowed in one request. If a request exceeds this limit, the request fails and the error message includes the maximum allowed.</p> </note> <p> <b>Learn more</b> </p> <p> <a href="https://docs.aws.amazon.com/gamelift/latest/developerguide/fleets-intro.html"> Working with Fleets</a>.</p> <p> <b>Related operations</b> </p> <ul> <li> <p> <a>CreateFleet</a> </p> </li> <li> <p> <a>ListFleets</a> </p> </li> <li> <p> <a>DeleteFleet</a> </p> </li> <li> <p>Describe fleets:</p> <ul> <li> <p> <a>DescribeFleetAttributes</a> </p> </li> <li> <p> <a>DescribeFleetCapacity</a> </p> </li> <li> <p> <a>DescribeFleetPortSettings</a> </p> </li> <li> <p> <a>DescribeFleetUtilization</a> </p> </li> <li> <p> <a>DescribeRuntimeConfiguration</a> </p> </li> <li> <p> <a>DescribeEC2InstanceLimits</a> </p> </li> <li> <p> <a>DescribeFleetEvents</a> </p> </li> </ul> </li> <li> <p> <a>UpdateFleetAttributes</a> </p> </li> <li> <p>Manage fleet actions:</p> <ul> <li> <p> <a>StartFleetActions</a> </p> </li> <li> <p> <a>StopFleetActions</a> </p> </li> </ul> </li> </ul></p>
-    async fn describe_fleet_utilization(
+    /// Presigns the request `describe_fleet_utilization` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_fleet_utilization(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeFleetUtilizationInput,
-    ) -> Result<DescribeFleetUtilizationOutput, RusotoError<DescribeFleetUtilizationError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_fleet_utilization_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_fleet_utilization_request(
+        &self,
+        input: DescribeFleetUtilizationInput,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "gamelift", &self.region, "/");
+        request.set_operation("DescribeFleetUtilization");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "GameLift.DescribeFleetUtilization");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `describe_game_session_details`, also returning the metadata of the response.
+    pub async fn describe_game_session_details_with_metadata(
+        &self,
+        input: DescribeGameSessionDetailsInput,
+    ) -> Result<
+        (DescribeGameSessionDetailsOutput, ResponseMetadata),
+        RusotoError<DescribeGameSessionDetailsError>,
+    > {
+        let request = self.build_describe_game_session_details_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DescribeFleetUtilizationOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DescribeGameSessionDetailsOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeFleetUtilizationError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DescribeGameSessionDetailsError::from_response(response))
         }
     }
 
-    /// <p><p>Retrieves properties, including the protection policy in force, for one or more game sessions. This action can be used in several ways: (1) provide a <code>GameSessionId</code> or <code>GameSessionArn</code> to request details for a specific game session; (2) provide either a <code>FleetId</code> or an <code>AliasId</code> to request properties for all game sessions running on a fleet. </p> <p>To get game session record(s), specify just one of the following: game session ID, fleet ID, or alias ID. You can filter this request by game session status. Use the pagination parameters to retrieve results as a set of sequential pages. If successful, a <a>GameSessionDetail</a> object is returned for each session matching the request.</p> <ul> <li> <p> <a>CreateGameSession</a> </p> </li> <li> <p> <a>DescribeGameSessions</a> </p> </li> <li> <p> <a>DescribeGameSessionDetails</a> </p> </li> <li> <p> <a>SearchGameSessions</a> </p> </li> <li> <p> <a>UpdateGameSession</a> </p> </li> <li> <p> <a>GetGameSessionLogUrl</a> </p> </li> <li> <p>Game session placements</p> <ul> <li> <p> <a>StartGameSessionPlacement</a> </p> </li> <li> <p> <a>DescribeGameSessionPlacement</a> </p> </li> <li> <p> <a>StopGameSessionPlacement</a> </p> </li> </ul> </li> </ul></p>
-    async fn describe_game_session_details(
+    /// Presigns the request `describe_game_session_details` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_game_session_details(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeGameSessionDetailsInput,
-    ) -> Result<DescribeGameSessionDetailsOutput, RusotoError<DescribeGameSessionDetailsError>>
-    {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_game_session_details_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_game_session_details_request(
+        &self,
+        input: DescribeGameSessionDetailsInput,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "gamelift", &self.region, "/");
+        request.set_operation("DescribeGameSessionDetails");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "GameLift.DescribeGameSessionDetails");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `describe_game_session_placement`, also returning the metadata of the response.
+    pub async fn describe_game_session_placement_with_metadata(
+        &self,
+        input: DescribeGameSessionPlacementInput,
+    ) -> Result<
+        (DescribeGameSessionPlacementOutput, ResponseMetadata),
+        RusotoError<DescribeGameSessionPlacementError>,
+    > {
+        let request = self.build_describe_game_session_placement_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DescribeGameSessionDetailsOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DescribeGameSessionPlacementOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeGameSessionDetailsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DescribeGameSessionPlacementError::from_response(response))
         }
     }
 
-    /// <p><p>Retrieves properties and current status of a game session placement request. To get game session placement details, specify the placement ID. If successful, a <a>GameSessionPlacement</a> object is returned.</p> <ul> <li> <p> <a>CreateGameSession</a> </p> </li> <li> <p> <a>DescribeGameSessions</a> </p> </li> <li> <p> <a>DescribeGameSessionDetails</a> </p> </li> <li> <p> <a>SearchGameSessions</a> </p> </li> <li> <p> <a>UpdateGameSession</a> </p> </li> <li> <p> <a>GetGameSessionLogUrl</a> </p> </li> <li> <p>Game session placements</p> <ul> <li> <p> <a>StartGameSessionPlacement</a> </p> </li> <li> <p> <a>DescribeGameSessionPlacement</a> </p> </li> <li> <p> <a>StopGameSessionPlacement</a> </p> </li> </ul> </li> </ul></p>
-    async fn describe_game_session_placement(
+    /// Presigns the request `describe_game_session_placement` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_game_session_placement(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeGameSessionPlacementInput,
-    ) -> Result<DescribeGameSessionPlacementOutput, RusotoError<DescribeGameSessionPlacementError>>
-    {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_game_session_placement_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_game_session_placement_request(
+        &self,
+        input: DescribeGameSessionPlacementInput,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "gamelift", &self.region, "/");
+        request.set_operation("DescribeGameSessionPlacement");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "GameLift.DescribeGameSessionPlacement");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `describe_game_session_queues`, also returning the metadata of the response.
+    pub async fn describe_game_session_queues_with_metadata(
+        &self,
+        input: DescribeGameSessionQueuesInput,
+    ) -> Result<
+        (DescribeGameSessionQueuesOutput, ResponseMetadata),
+        RusotoError<DescribeGameSessionQueuesError>,
+    > {
+        let request = self.build_describe_game_session_queues_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DescribeGameSessionPlacementOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DescribeGameSessionQueuesOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeGameSessionPlacementError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DescribeGameSessionQueuesError::from_response(response))
         }
     }
 
-    /// <p><p>Retrieves the properties for one or more game session queues. When requesting multiple queues, use the pagination parameters to retrieve results as a set of sequential pages. If successful, a <a>GameSessionQueue</a> object is returned for each requested queue. When specifying a list of queues, objects are returned only for queues that currently exist in the Region.</p> <ul> <li> <p> <a>CreateGameSessionQueue</a> </p> </li> <li> <p> <a>DescribeGameSessionQueues</a> </p> </li> <li> <p> <a>UpdateGameSessionQueue</a> </p> </li> <li> <p> <a>DeleteGameSessionQueue</a> </p> </li> </ul></p>
-    async fn describe_game_session_queues(
+    /// Presigns the request `describe_game_session_queues` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_game_session_queues(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeGameSessionQueuesInput,
-    ) -> Result<DescribeGameSessionQueuesOutput, RusotoError<DescribeGameSessionQueuesError>> {
-        let mut request = SignedRequest::new("POST", "gamelift", &self.region, "/");
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_game_session_queues_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_game_session_queues_request(
+        &self,
+        input: DescribeGameSessionQueuesInput,
+    ) -> SignedRequest {
+        let mut request = SignedRequest::new("POST", "gamelift", &self.region, "/");
+        request.set_operation("DescribeGameSessionQueues");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "GameLift.DescribeGameSessionQueues");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `describe_game_sessions`, also returning the metadata of the response.
+    pub async fn describe_game_sessions_with_metadata(
+        &self,
+        input: DescribeGameSessionsInput,
+    ) -> Result<
+        (DescribeGameSessionsOutput, ResponseMetadata),
+        RusotoError<DescribeGameSessionsError>,
+    > {
+        let request = self.build_describe_game_sessions_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DescribeGameSessionQueuesOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DescribeGameSessionsOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeGameSessionQueuesError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DescribeGameSessionsError::from_response(response))
         }
     }
 
-    /// <p><p>Retrieves a set of one or more game sessions. Request a specific game session or request all game sessions on a fleet. Alternatively, use <a>SearchGameSessions</a> to request a set of active game sessions that are filtered by certain criteria. To retrieve protection policy settings for game sessions, use <a>DescribeGameSessionDetails</a>.</p> <p>To get game sessions, specify one of the following: game session ID, fleet ID, or alias ID. You can filter this request by game session status. Use the pagination parameters to retrieve results as a set of sequential pages. If successful, a <a>GameSession</a> object is returned for each game session matching the request.</p> <p> <i>Available in Amazon GameLift Local.</i> </p> <ul> <li> <p> <a>CreateGameSession</a> </p> </li> <li> <p> <a>DescribeGameSessions</a> </p> </li> <li> <p> <a>DescribeGameSessionDetails</a> </p> </li> <li> <p> <a>SearchGameSessions</a> </p> </li> <li> <p> <a>UpdateGameSession</a> </p> </li> <li> <p> <a>GetGameSessionLogUrl</a> </p> </li> <li> <p>Game session placements</p> <ul> <li> <p> <a>StartGameSessionPlacement</a> </p> </li> <li> <p> <a>DescribeGameSessionPlacement</a> </p> </li> <li> <p> <a>StopGameSessionPlacement</a> </p> </li> </ul> </li> </ul></p>
-    async fn describe_game_sessions(
+    /// Presigns the request `describe_game_sessions` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_game_sessions(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeGameSessionsInput,
-    ) -> Result<DescribeGameSessionsOutput, RusotoError<DescribeGameSessionsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_game_sessions_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_game_sessions_request(
+        &self,
+        input: DescribeGameSessionsInput,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "gamelift", &self.region, "/");
+        request.set_operation("DescribeGameSessions");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "GameLift.DescribeGameSessions");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `describe_instances`, also returning the metadata of the response.
+    pub async fn describe_instances_with_metadata(
+        &self,
+        input: DescribeInstancesInput,
+    ) -> Result<(DescribeInstancesOutput, ResponseMetadata), RusotoError<DescribeInstancesError>>
+    {
+        let request = self.build_describe_instances_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DescribeGameSessionsOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DescribeInstancesOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeGameSessionsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DescribeInstancesError::from_response(response))
         }
     }
 
-    /// <p>Retrieves information about a fleet's instances, including instance IDs. Use this action to get details on all instances in the fleet or get details on one specific instance.</p> <p>To get a specific instance, specify fleet ID and instance ID. To get all instances in a fleet, specify a fleet ID only. Use the pagination parameters to retrieve results as a set of sequential pages. If successful, an <a>Instance</a> object is returned for each result.</p>
-    async fn describe_instances(
+    /// Presigns the request `describe_instances` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_instances(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeInstancesInput,
-    ) -> Result<DescribeInstancesOutput, RusotoError<DescribeInstancesError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_instances_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_instances_request(&self, input: DescribeInstancesInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "gamelift", &self.region, "/");
+        request.set_operation("DescribeInstances");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "GameLift.DescribeInstances");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `describe_matchmaking`, also returning the metadata of the response.
+    pub async fn describe_matchmaking_with_metadata(
+        &self,
+        input: DescribeMatchmakingInput,
+    ) -> Result<(DescribeMatchmakingOutput, ResponseMetadata), RusotoError<DescribeMatchmakingError>>
+    {
+        let request = self.build_describe_matchmaking_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<DescribeInstancesOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DescribeMatchmakingOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeInstancesError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DescribeMatchmakingError::from_response(response))
         }
     }
 
-    /// <p><p>Retrieves one or more matchmaking tickets. Use this operation to retrieve ticket information, including status and--once a successful match is made--acquire connection information for the resulting new game session. </p> <p>You can use this operation to track the progress of matchmaking requests (through polling) as an alternative to using event notifications. See more details on tracking matchmaking requests through polling or notifications in <a>StartMatchmaking</a>. </p> <p>To request matchmaking tickets, provide a list of up to 10 ticket IDs. If the request is successful, a ticket object is returned for each requested ID that currently exists.</p> <p> <b>Learn more</b> </p> <p> <a href="https://docs.aws.amazon.com/gamelift/latest/developerguide/match-client.html"> Add FlexMatch to a Game Client</a> </p> <p> <a href="https://docs.aws.amazon.com/gamelift/latest/developerguide/match-notification.html"> Set Up FlexMatch Event Notification</a> </p> <p> <b>Related operations</b> </p> <ul> <li> <p> <a>StartMatchmaking</a> </p> </li> <li> <p> <a>DescribeMatchmaking</a> </p> </li> <li> <p> <a>StopMatchmaking</a> </p> </li> <li> <p> <a>AcceptMatch</a> </p> </li> <li> <p> <a>StartMatchBackfill</a> </p> </li> </ul></p>
-    async fn describe_matchmaking(
+    /// Presigns the request `describe_matchmaking` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_matchmaking(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeMatchmakingInput,
-    ) -> Result<DescribeMatchmakingOutput, RusotoError<DescribeMatchmakingError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_matchmaking_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_matchmaking_request(&self, input: DescribeMatchmakingInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "gamelift", &self.region, "/");
+        request.set_operation("DescribeMatchmaking");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "GameLift.DescribeMatchmaking");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `describe_matchmaking_configurations`, also returning the metadata of the response.
+    pub async fn describe_matchmaking_configurations_with_metadata(
+        &self,
+        input: DescribeMatchmakingConfigurationsInput,
+    ) -> Result<
+        (DescribeMatchmakingConfigurationsOutput, ResponseMetadata),
+        RusotoError<DescribeMatchmakingConfigurationsError>,
+    > {
+        let request = self.build_describe_matchmaking_configurations_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DescribeMatchmakingOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DescribeMatchmakingConfigurationsOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeMatchmakingError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DescribeMatchmakingConfigurationsError::from_response(
+                response,
+            ))
         }
     }
 
-    /// <p><p>Retrieves the details of FlexMatch matchmaking configurations. With this operation, you have the following options: (1) retrieve all existing configurations, (2) provide the names of one or more configurations to retrieve, or (3) retrieve all configurations that use a specified rule set name. When requesting multiple items, use the pagination parameters to retrieve results as a set of sequential pages. If successful, a configuration is returned for each requested name. When specifying a list of names, only configurations that currently exist are returned. </p> <p> <b>Learn more</b> </p> <p> <a href="https://docs.aws.amazon.com/gamelift/latest/developerguide/matchmaker-build.html"> Setting Up FlexMatch Matchmakers</a> </p> <p> <b>Related operations</b> </p> <ul> <li> <p> <a>CreateMatchmakingConfiguration</a> </p> </li> <li> <p> <a>DescribeMatchmakingConfigurations</a> </p> </li> <li> <p> <a>UpdateMatchmakingConfiguration</a> </p> </li> <li> <p> <a>DeleteMatchmakingConfiguration</a> </p> </li> <li> <p> <a>CreateMatchmakingRuleSet</a> </p> </li> <li> <p> <a>DescribeMatchmakingRuleSets</a> </p> </li> <li> <p> <a>ValidateMatchmakingRuleSet</a> </p> </li> <li> <p> <a>DeleteMatchmakingRuleSet</a> </p> </li> </ul></p>
-    async fn describe_matchmaking_configurations(
+    /// Presigns the request `describe_matchmaking_configurations` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_matchmaking_configurations(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeMatchmakingConfigurationsInput,
-    ) -> Result<
-        DescribeMatchmakingConfigurationsOutput,
-        RusotoError<DescribeMatchmakingConfigurationsError>,
-    > {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_matchmaking_configurations_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_matchmaking_configurations_request(
+        &self,
+        input: DescribeMatchmakingConfigurationsInput,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "gamelift", &self.region, "/");
+        request.set_operation("DescribeMatchmakingConfigurations");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "GameLift.DescribeMatchmakingConfigurations");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `describe_matchmaking_rule_sets`, also returning the metadata of the response.
+    pub async fn describe_matchmaking_rule_sets_with_metadata(
+        &self,
+        input: DescribeMatchmakingRuleSetsInput,
+    ) -> Result<
+        (DescribeMatchmakingRuleSetsOutput, ResponseMetadata),
+        RusotoError<DescribeMatchmakingRuleSetsError>,
+    > {
+        let request = self.build_describe_matchmaking_rule_sets_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DescribeMatchmakingConfigurationsOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DescribeMatchmakingRuleSetsOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeMatchmakingConfigurationsError::from_response(
-                response,
-            ))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DescribeMatchmakingRuleSetsError::from_response(response))
         }
     }
 
-    /// <p><p>Retrieves the details for FlexMatch matchmaking rule sets. You can request all existing rule sets for the Region, or provide a list of one or more rule set names. When requesting multiple items, use the pagination parameters to retrieve results as a set of sequential pages. If successful, a rule set is returned for each requested name. </p> <p> <b>Learn more</b> </p> <ul> <li> <p> <a href="https://docs.aws.amazon.com/gamelift/latest/developerguide/match-rulesets.html">Build a Rule Set</a> </p> </li> </ul> <p> <b>Related operations</b> </p> <ul> <li> <p> <a>CreateMatchmakingConfiguration</a> </p> </li> <li> <p> <a>DescribeMatchmakingConfigurations</a> </p> </li> <li> <p> <a>UpdateMatchmakingConfiguration</a> </p> </li> <li> <p> <a>DeleteMatchmakingConfiguration</a> </p> </li> <li> <p> <a>CreateMatchmakingRuleSet</a> </p> </li> <li> <p> <a>DescribeMatchmakingRuleSets</a> </p> </li> <li> <p> <a>ValidateMatchmakingRuleSet</a> </p> </li> <li> <p> <a>DeleteMatchmakingRuleSet</a> </p> </li> </ul></p>
-    async fn describe_matchmaking_rule_sets(
+    /// Presigns the request `describe_matchmaking_rule_sets` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_matchmaking_rule_sets(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeMatchmakingRuleSetsInput,
-    ) -> Result<DescribeMatchmakingRuleSetsOutput, RusotoError<DescribeMatchmakingRuleSetsError>>
-    {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_matchmaking_rule_sets_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_matchmaking_rule_sets_request(
+        &self,
+        input: DescribeMatchmakingRuleSetsInput,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "gamelift", &self.region, "/");
+        request.set_operation("DescribeMatchmakingRuleSets");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "GameLift.DescribeMatchmakingRuleSets");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `describe_player_sessions`, also returning the metadata of the response.
+    pub async fn describe_player_sessions_with_metadata(
+        &self,
+        input: DescribePlayerSessionsInput,
+    ) -> Result<
+        (DescribePlayerSessionsOutput, ResponseMetadata),
+        RusotoError<DescribePlayerSessionsError>,
+    > {
+        let request = self.build_describe_player_sessions_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DescribeMatchmakingRuleSetsOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DescribePlayerSessionsOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeMatchmakingRuleSetsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DescribePlayerSessionsError::from_response(response))
         }
     }
 
-    /// <p><p>Retrieves properties for one or more player sessions. This action can be used in several ways: (1) provide a <code>PlayerSessionId</code> to request properties for a specific player session; (2) provide a <code>GameSessionId</code> to request properties for all player sessions in the specified game session; (3) provide a <code>PlayerId</code> to request properties for all player sessions of a specified player. </p> <p>To get game session record(s), specify only one of the following: a player session ID, a game session ID, or a player ID. You can filter this request by player session status. Use the pagination parameters to retrieve results as a set of sequential pages. If successful, a <a>PlayerSession</a> object is returned for each session matching the request.</p> <p> <i>Available in Amazon GameLift Local.</i> </p> <ul> <li> <p> <a>CreatePlayerSession</a> </p> </li> <li> <p> <a>CreatePlayerSessions</a> </p> </li> <li> <p> <a>DescribePlayerSessions</a> </p> </li> <li> <p>Game session placements</p> <ul> <li> <p> <a>StartGameSessionPlacement</a> </p> </li> <li> <p> <a>DescribeGameSessionPlacement</a> </p> </li> <li> <p> <a>StopGameSessionPlacement</a> </p> </li> </ul> </li> </ul></p>
-    async fn describe_player_sessions(
+    /// Presigns the request `describe_player_sessions` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_player_sessions(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribePlayerSessionsInput,
-    ) -> Result<DescribePlayerSessionsOutput, RusotoError<DescribePlayerSessionsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_player_sessions_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_player_sessions_request(
+        &self,
+        input: DescribePlayerSessionsInput,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "gamelift", &self.region, "/");
+        request.set_operation("DescribePlayerSessions");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "GameLift.DescribePlayerSessions");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `describe_runtime_configuration`, also returning the metadata of the response.
+    pub async fn describe_runtime_configuration_with_metadata(
+        &self,
+        input: DescribeRuntimeConfigurationInput,
+    ) -> Result<
+        (DescribeRuntimeConfigurationOutput, ResponseMetadata),
+        RusotoError<DescribeRuntimeConfigurationError>,
+    > {
+        let request = self.build_describe_runtime_configuration_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DescribePlayerSessionsOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DescribeRuntimeConfigurationOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribePlayerSessionsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DescribeRuntimeConfigurationError::from_response(response))
         }
     }
 
-    /// <p><p>Retrieves the current runtime configuration for the specified fleet. The runtime configuration tells Amazon GameLift how to launch server processes on instances in the fleet.</p> <p> <b>Learn more</b> </p> <p> <a href="https://docs.aws.amazon.com/gamelift/latest/developerguide/fleets-intro.html"> Working with Fleets</a>.</p> <p> <b>Related operations</b> </p> <ul> <li> <p> <a>CreateFleet</a> </p> </li> <li> <p> <a>ListFleets</a> </p> </li> <li> <p> <a>DeleteFleet</a> </p> </li> <li> <p>Describe fleets:</p> <ul> <li> <p> <a>DescribeFleetAttributes</a> </p> </li> <li> <p> <a>DescribeFleetCapacity</a> </p> </li> <li> <p> <a>DescribeFleetPortSettings</a> </p> </li> <li> <p> <a>DescribeFleetUtilization</a> </p> </li> <li> <p> <a>DescribeRuntimeConfiguration</a> </p> </li> <li> <p> <a>DescribeEC2InstanceLimits</a> </p> </li> <li> <p> <a>DescribeFleetEvents</a> </p> </li> </ul> </li> <li> <p> <a>UpdateFleetAttributes</a> </p> </li> <li> <p>Manage fleet actions:</p> <ul> <li> <p> <a>StartFleetActions</a> </p> </li> <li> <p> <a>StopFleetActions</a> </p> </li> </ul> </li> </ul></p>
-    async fn describe_runtime_configuration(
+    /// Presigns the request `describe_runtime_configuration` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_runtime_configuration(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeRuntimeConfigurationInput,
-    ) -> Result<DescribeRuntimeConfigurationOutput, RusotoError<DescribeRuntimeConfigurationError>>
-    {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_runtime_configuration_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_runtime_configuration_request(
+        &self,
+        input: DescribeRuntimeConfigurationInput,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "gamelift", &self.region, "/");
+        request.set_operation("DescribeRuntimeConfiguration");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "GameLift.DescribeRuntimeConfiguration");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `describe_scaling_policies`, also returning the metadata of the response.
+    pub async fn describe_scaling_policies_with_metadata(
+        &self,
+        input: DescribeScalingPoliciesInput,
+    ) -> Result<
+        (DescribeScalingPoliciesOutput, ResponseMetadata),
+        RusotoError<DescribeScalingPoliciesError>,
+    > {
+        let request = self.build_describe_scaling_policies_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DescribeRuntimeConfigurationOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DescribeScalingPoliciesOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeRuntimeConfigurationError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DescribeScalingPoliciesError::from_response(response))
         }
     }
 
-    /// <p><p>Retrieves all scaling policies applied to a fleet.</p> <p>To get a fleet&#39;s scaling policies, specify the fleet ID. You can filter this request by policy status, such as to retrieve only active scaling policies. Use the pagination parameters to retrieve results as a set of sequential pages. If successful, set of <a>ScalingPolicy</a> objects is returned for the fleet.</p> <p>A fleet may have all of its scaling policies suspended (<a>StopFleetActions</a>). This action does not affect the status of the scaling policies, which remains ACTIVE. To see whether a fleet&#39;s scaling policies are in force or suspended, call <a>DescribeFleetAttributes</a> and check the stopped actions.</p> <ul> <li> <p> <a>DescribeFleetCapacity</a> </p> </li> <li> <p> <a>UpdateFleetCapacity</a> </p> </li> <li> <p> <a>DescribeEC2InstanceLimits</a> </p> </li> <li> <p>Manage scaling policies:</p> <ul> <li> <p> <a>PutScalingPolicy</a> (auto-scaling)</p> </li> <li> <p> <a>DescribeScalingPolicies</a> (auto-scaling)</p> </li> <li> <p> <a>DeleteScalingPolicy</a> (auto-scaling)</p> </li> </ul> </li> <li> <p>Manage fleet actions:</p> <ul> <li> <p> <a>StartFleetActions</a> </p> </li> <li> <p> <a>StopFleetActions</a> </p> </li> </ul> </li> </ul></p>
-    async fn describe_scaling_policies(
+    /// Presigns the request `describe_scaling_policies` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_scaling_policies(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeScalingPoliciesInput,
-    ) -> Result<DescribeScalingPoliciesOutput, RusotoError<DescribeScalingPoliciesError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_scaling_policies_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_scaling_policies_request(
+        &self,
+        input: DescribeScalingPoliciesInput,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "gamelift", &self.region, "/");
+        request.set_operation("DescribeScalingPolicies");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "GameLift.DescribeScalingPolicies");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `describe_script`, also returning the metadata of the response.
+    pub async fn describe_script_with_metadata(
+        &self,
+        input: DescribeScriptInput,
+    ) -> Result<(DescribeScriptOutput, ResponseMetadata), RusotoError<DescribeScriptError>> {
+        let request = self.build_describe_script_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DescribeScalingPoliciesOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DescribeScriptOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeScalingPoliciesError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DescribeScriptError::from_response(response))
         }
     }
 
-    /// <p><p>Retrieves properties for a Realtime script. </p> <p>To request a script record, specify the script ID. If successful, an object containing the script properties is returned.</p> <p> <b>Learn more</b> </p> <p> <a href="https://docs.aws.amazon.com/gamelift/latest/developerguide/realtime-intro.html">Amazon GameLift Realtime Servers</a> </p> <p> <b>Related operations</b> </p> <ul> <li> <p> <a>CreateScript</a> </p> </li> <li> <p> <a>ListScripts</a> </p> </li> <li> <p> <a>DescribeScript</a> </p> </li> <li> <p> <a>UpdateScript</a> </p> </li> <li> <p> <a>DeleteScript</a> </p> </li> </ul></p>
-    async fn describe_script(
+    /// Presigns the request `describe_script` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_script(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeScriptInput,
-    ) -> Result<DescribeScriptOutput, RusotoError<DescribeScriptError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_script_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_script_request(&self, input: DescribeScriptInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "gamelift", &self.region, "/");
+        request.set_operation("DescribeScript");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "GameLift.DescribeScript");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `describe_vpc_peering_authorizations`, also returning the metadata of the response.
+    pub async fn describe_vpc_peering_authorizations_with_metadata(
+        &self,
+    ) -> Result<
+        (DescribeVpcPeeringAuthorizationsOutput, ResponseMetadata),
+        RusotoError<DescribeVpcPeeringAuthorizationsError>,
+    > {
+        let request = self.build_describe_vpc_peering_authorizations_request();
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<DescribeScriptOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DescribeVpcPeeringAuthorizationsOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeScriptError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DescribeVpcPeeringAuthorizationsError::from_response(
+                response,
+            ))
         }
     }
 
-    /// <p><p>Retrieves valid VPC peering authorizations that are pending for the AWS account. This operation returns all VPC peering authorizations and requests for peering. This includes those initiated and received by this account. </p> <ul> <li> <p> <a>CreateVpcPeeringAuthorization</a> </p> </li> <li> <p> <a>DescribeVpcPeeringAuthorizations</a> </p> </li> <li> <p> <a>DeleteVpcPeeringAuthorization</a> </p> </li> <li> <p> <a>CreateVpcPeeringConnection</a> </p> </li> <li> <p> <a>DescribeVpcPeeringConnections</a> </p> </li> <li> <p> <a>DeleteVpcPeeringConnection</a> </p> </li> </ul></p>
-    async fn describe_vpc_peering_authorizations(
+    /// Presigns the request `describe_vpc_peering_authorizations` would send with `presigner` instead of sending it.
+    pub async fn presign_describe_vpc_peering_authorizations(
         &self,
-    ) -> Result<
-        DescribeVpcPeeringAuthorizationsOutput,
-        RusotoError<DescribeVpcPeeringAuthorizationsError>,
-    > {
+        presigner: &rusoto_core::Presigner,
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_vpc_peering_authorizations_request();
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_vpc_peering_authorizations_request(&self) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "gamelift", &self.region, "/");
+        request.set_operation("DescribeVpcPeeringAuthorizations");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "GameLift.DescribeVpcPeeringAuthorizations");
         request.set_payload(Some(bytes::Bytes::from_static(b"{}")));
 
+        request
+    }
+
+    /// Calls `describe_vpc_peering_connections`, also returning the metadata of the response.
+    pub async fn describe_vpc_peering_connections_with_metadata(
+        &self,
+        input: DescribeVpcPeeringConnectionsInput,
+    ) -> Result<
+        (DescribeVpcPeeringConnectionsOutput, ResponseMetadata),
+        RusotoError<DescribeVpcPeeringConnectionsError>,
+    > {
+        let request = self.build_describe_vpc_peering_connections_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DescribeVpcPeeringAuthorizationsOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<DescribeVpcPeeringConnectionsOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeVpcPeeringAuthorizationsError::from_response(
-                response,
-            ))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(DescribeVpcPeeringConnectionsError::from_response(response))
         }
     }
 
-    /// <p><p>Retrieves information on VPC peering connections. Use this operation to get peering information for all fleets or for one specific fleet ID. </p> <p>To retrieve connection information, call this operation from the AWS account that is used to manage the Amazon GameLift fleets. Specify a fleet ID or leave the parameter empty to retrieve all connection records. If successful, the retrieved information includes both active and pending connections. Active connections identify the IpV4 CIDR block that the VPC uses to connect. </p> <ul> <li> <p> <a>CreateVpcPeeringAuthorization</a> </p> </li> <li> <p> <a>DescribeVpcPeeringAuthorizations</a> </p> </li> <li> <p> <a>DeleteVpcPeeringAuthorization</a> </p> </li> <li> <p> <a>CreateVpcPeeringConnection</a> </p> </li> <li> <p> <a>DescribeVpcPeeringConnections</a> </p> </li> <li> <p> <a>DeleteVpcPeeringConnection</a> </p> </li> </ul></p>
-    async fn describe_vpc_peering_connections(
+    /// Presigns the request `describe_vpc_peering_connections` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_describe_vpc_peering_connections(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: DescribeVpcPeeringConnectionsInput,
-    ) -> Result<DescribeVpcPeeringConnectionsOutput, RusotoError<DescribeVpcPeeringConnectionsError>>
-    {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_describe_vpc_peering_connections_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_describe_vpc_peering_connections_request(
+        &self,
+        input: DescribeVpcPeeringConnectionsInput,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "gamelift", &self.region, "/");
+        request.set_operation("DescribeVpcPeeringConnections");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "GameLift.DescribeVpcPeeringConnections");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `get_game_session_log_url`, also returning the metadata of the response.
+    pub async fn get_game_session_log_url_with_metadata(
+        &self,
+        input: GetGameSessionLogUrlInput,
+    ) -> Result<
+        (GetGameSessionLogUrlOutput, ResponseMetadata),
+        RusotoError<GetGameSessionLogUrlError>,
+    > {
+        let request = self.build_get_game_session_log_url_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<DescribeVpcPeeringConnectionsOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<GetGameSessionLogUrlOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(DescribeVpcPeeringConnectionsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(GetGameSessionLogUrlError::from_response(response))
         }
     }
 
-    /// <p><p>Retrieves the location of stored game session logs for a specified game session. When a game session is terminated, Amazon GameLift automatically stores the logs in Amazon S3 and retains them for 14 days. Use this URL to download the logs.</p> <note> <p>See the <a href="https://docs.aws.amazon.com/general/latest/gr/aws_service_limits.html#limits_gamelift">AWS Service Limits</a> page for maximum log file sizes. Log files that exceed this limit are not saved.</p> </note> <ul> <li> <p> <a>CreateGameSession</a> </p> </li> <li> <p> <a>DescribeGameSessions</a> </p> </li> <li> <p> <a>DescribeGameSessionDetails</a> </p> </li> <li> <p> <a>SearchGameSessions</a> </p> </li> <li> <p> <a>UpdateGameSession</a> </p> </li> <li> <p> <a>GetGameSessionLogUrl</a> </p> </li> <li> <p>Game session placements</p> <ul> <li> <p> <a>StartGameSessionPlacement</a> </p> </li> <li> <p> <a>DescribeGameSessionPlacement</a> </p> </li> <li> <p> <a>StopGameSessionPlacement</a> </p> </li> </ul> </li> </ul></p>
-    async fn get_game_session_log_url(
+    /// Presigns the request `get_game_session_log_url` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_game_session_log_url(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetGameSessionLogUrlInput,
-    ) -> Result<GetGameSessionLogUrlOutput, RusotoError<GetGameSessionLogUrlError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_game_session_log_url_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_game_session_log_url_request(
+        &self,
+        input: GetGameSessionLogUrlInput,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "gamelift", &self.region, "/");
+        request.set_operation("GetGameSessionLogUrl");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "GameLift.GetGameSessionLogUrl");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `get_instance_access`, also returning the metadata of the response.
+    pub async fn get_instance_access_with_metadata(
+        &self,
+        input: GetInstanceAccessInput,
+    ) -> Result<(GetInstanceAccessOutput, ResponseMetadata), RusotoError<GetInstanceAccessError>>
+    {
+        let request = self.build_get_instance_access_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<GetGameSessionLogUrlOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<GetInstanceAccessOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(GetGameSessionLogUrlError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(GetInstanceAccessError::from_response(response))
         }
     }
 
-    /// <p>Requests remote access to a fleet instance. Remote access is useful for debugging, gathering benchmarking data, or watching activity in real time. </p> <p>Access requires credentials that match the operating system of the instance. For a Windows instance, Amazon GameLift returns a user name and password as strings for use with a Windows Remote Desktop client. For a Linux instance, Amazon GameLift returns a user name and RSA private key, also as strings, for use with an SSH client. The private key must be saved in the proper format to a <code>.pem</code> file before using. If you're making this request using the AWS CLI, saving the secret can be handled as part of the GetInstanceAccess request. (See the example later in this topic). For more information on remote access, see <a href="https://docs.aws.amazon.com/gamelift/latest/developerguide/fleets-remote-access.html">Remotely Accessing an Instance</a>.</p> <p>To request access to a specific instance, specify the IDs of both the instance and the fleet it belongs to. You can retrieve a fleet's instance IDs by calling <a>DescribeInstances</a>. If successful, an <a>InstanceAccess</a> object is returned containing the instance's IP address and a set of credentials.</p>
-    async fn get_instance_access(
+    /// Presigns the request `get_instance_access` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_get_instance_access(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: GetInstanceAccessInput,
-    ) -> Result<GetInstanceAccessOutput, RusotoError<GetInstanceAccessError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_get_instance_access_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_get_instance_access_request(&self, input: GetInstanceAccessInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "gamelift", &self.region, "/");
+        request.set_operation("GetInstanceAccess");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "GameLift.GetInstanceAccess");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `list_aliases`, also returning the metadata of the response.
+    pub async fn list_aliases_with_metadata(
+        &self,
+        input: ListAliasesInput,
+    ) -> Result<(ListAliasesOutput, ResponseMetadata), RusotoError<ListAliasesError>> {
+        let request = self.build_list_aliases_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<GetInstanceAccessOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ListAliasesOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(GetInstanceAccessError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ListAliasesError::from_response(response))
         }
     }
 
-    /// <p><p>Retrieves all aliases for this AWS account. You can filter the result set by alias name and/or routing strategy type. Use the pagination parameters to retrieve results in sequential pages.</p> <note> <p>Returned aliases are not listed in any particular order.</p> </note> <ul> <li> <p> <a>CreateAlias</a> </p> </li> <li> <p> <a>ListAliases</a> </p> </li> <li> <p> <a>DescribeAlias</a> </p> </li> <li> <p> <a>UpdateAlias</a> </p> </li> <li> <p> <a>DeleteAlias</a> </p> </li> <li> <p> <a>ResolveAlias</a> </p> </li> </ul></p>
-    async fn list_aliases(
+    /// Presigns the request `list_aliases` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_aliases(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListAliasesInput,
-    ) -> Result<ListAliasesOutput, RusotoError<ListAliasesError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_aliases_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_aliases_request(&self, input: ListAliasesInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "gamelift", &self.region, "/");
+        request.set_operation("ListAliases");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "GameLift.ListAliases");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `list_builds`, also returning the metadata of the response.
+    pub async fn list_builds_with_metadata(
+        &self,
+        input: ListBuildsInput,
+    ) -> Result<(ListBuildsOutput, ResponseMetadata), RusotoError<ListBuildsError>> {
+        let request = self.build_list_builds_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<ListAliasesOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ListBuildsOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ListAliasesError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ListBuildsError::from_response(response))
         }
     }
 
-    /// <p><p>Retrieves build records for all builds associated with the AWS account in use. You can limit results to builds that are in a specific status by using the <code>Status</code> parameter. Use the pagination parameters to retrieve results in a set of sequential pages. </p> <note> <p>Build records are not listed in any particular order.</p> </note> <p> <b>Learn more</b> </p> <p> <a href="https://docs.aws.amazon.com/gamelift/latest/developerguide/build-intro.html"> Working with Builds</a> </p> <p> <b>Related operations</b> </p> <ul> <li> <p> <a>CreateBuild</a> </p> </li> <li> <p> <a>ListBuilds</a> </p> </li> <li> <p> <a>DescribeBuild</a> </p> </li> <li> <p> <a>UpdateBuild</a> </p> </li> <li> <p> <a>DeleteBuild</a> </p> </li> </ul></p>
-    async fn list_builds(
+    /// Presigns the request `list_builds` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_builds(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListBuildsInput,
-    ) -> Result<ListBuildsOutput, RusotoError<ListBuildsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_builds_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_builds_request(&self, input: ListBuildsInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "gamelift", &self.region, "/");
+        request.set_operation("ListBuilds");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "GameLift.ListBuilds");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `list_fleets`, also returning the metadata of the response.
+    pub async fn list_fleets_with_metadata(
+        &self,
+        input: ListFleetsInput,
+    ) -> Result<(ListFleetsOutput, ResponseMetadata), RusotoError<ListFleetsError>> {
+        let request = self.build_list_fleets_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<ListBuildsOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ListFleetsOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ListBuildsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ListFleetsError::from_response(response))
         }
     }
 
-    /// <p><p>Retrieves a collection of fleet records for this AWS account. You can filter the result set to find only those fleets that are deployed with a specific build or script. Use the pagination parameters to retrieve results in sequential pages.</p> <note> <p>Fleet records are not listed in a particular order.</p> </note> <p> <b>Learn more</b> </p> <p> <a href="https://docs.aws.amazon.com/gamelift/latest/developerguide/fleets-intro.html"> Set Up Fleets</a>.</p> <p> <b>Related operations</b> </p> <ul> <li> <p> <a>CreateFleet</a> </p> </li> <li> <p> <a>ListFleets</a> </p> </li> <li> <p> <a>DeleteFleet</a> </p> </li> <li> <p> <a>DescribeFleetAttributes</a> </p> </li> <li> <p> <a>UpdateFleetAttributes</a> </p> </li> <li> <p>Manage fleet actions:</p> <ul> <li> <p> <a>StartFleetActions</a> </p> </li> <li> <p> <a>StopFleetActions</a> </p> </li> </ul> </li> </ul></p>
-    async fn list_fleets(
+    /// Presigns the request `list_fleets` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_fleets(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListFleetsInput,
-    ) -> Result<ListFleetsOutput, RusotoError<ListFleetsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_fleets_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_fleets_request(&self, input: ListFleetsInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "gamelift", &self.region, "/");
+        request.set_operation("ListFleets");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "GameLift.ListFleets");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `list_scripts`, also returning the metadata of the response.
+    pub async fn list_scripts_with_metadata(
+        &self,
+        input: ListScriptsInput,
+    ) -> Result<(ListScriptsOutput, ResponseMetadata), RusotoError<ListScriptsError>> {
+        let request = self.build_list_scripts_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<ListFleetsOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ListScriptsOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ListFleetsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ListScriptsError::from_response(response))
         }
     }
 
-    /// <p><p>Retrieves script records for all Realtime scripts that are associated with the AWS account in use. </p> <p> <b>Learn more</b> </p> <p> <a href="https://docs.aws.amazon.com/gamelift/latest/developerguide/realtime-intro.html">Amazon GameLift Realtime Servers</a> </p> <p> <b>Related operations</b> </p> <ul> <li> <p> <a>CreateScript</a> </p> </li> <li> <p> <a>ListScripts</a> </p> </li> <li> <p> <a>DescribeScript</a> </p> </li> <li> <p> <a>UpdateScript</a> </p> </li> <li> <p> <a>DeleteScript</a> </p> </li> </ul></p>
-    async fn list_scripts(
+    /// Presigns the request `list_scripts` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_scripts(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListScriptsInput,
-    ) -> Result<ListScriptsOutput, RusotoError<ListScriptsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_scripts_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_scripts_request(&self, input: ListScriptsInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "gamelift", &self.region, "/");
+        request.set_operation("ListScripts");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "GameLift.ListScripts");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `list_tags_for_resource`, also returning the metadata of the response.
+    pub async fn list_tags_for_resource_with_metadata(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> Result<
+        (ListTagsForResourceResponse, ResponseMetadata),
+        RusotoError<ListTagsForResourceError>,
+    > {
+        let request = self.build_list_tags_for_resource_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<ListScriptsOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ListTagsForResourceResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ListScriptsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ListTagsForResourceError::from_response(response))
         }
     }
 
-    /// <p><p> Retrieves all tags that are assigned to a GameLift resource. Resource tags are used to organize AWS resources for a range of purposes. This action handles the permissions necessary to manage tags for the following GameLift resource types:</p> <ul> <li> <p>Build</p> </li> <li> <p>Script</p> </li> <li> <p>Fleet</p> </li> <li> <p>Alias</p> </li> <li> <p>GameSessionQueue</p> </li> <li> <p>MatchmakingConfiguration</p> </li> <li> <p>MatchmakingRuleSet</p> </li> </ul> <p>To list tags for a resource, specify the unique ARN value for the resource.</p> <p> <b>Learn more</b> </p> <p> <a href="https://docs.aws.amazon.com/general/latest/gr/aws_tagging.html">Tagging AWS Resources</a> in the <i>AWS General Reference</i> </p> <p> <a href="http://aws.amazon.com/answers/account-management/aws-tagging-strategies/"> AWS Tagging Strategies</a> </p> <p> <b>Related operations</b> </p> <ul> <li> <p> <a>TagResource</a> </p> </li> <li> <p> <a>UntagResource</a> </p> </li> <li> <p> <a>ListTagsForResource</a> </p> </li> </ul></p>
-    async fn list_tags_for_resource(
+    /// Presigns the request `list_tags_for_resource` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_list_tags_for_resource(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ListTagsForResourceRequest,
-    ) -> Result<ListTagsForResourceResponse, RusotoError<ListTagsForResourceError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_list_tags_for_resource_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_list_tags_for_resource_request(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "gamelift", &self.region, "/");
+        request.set_operation("ListTagsForResource");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "GameLift.ListTagsForResource");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `put_scaling_policy`, also returning the metadata of the response.
+    pub async fn put_scaling_policy_with_metadata(
+        &self,
+        input: PutScalingPolicyInput,
+    ) -> Result<(PutScalingPolicyOutput, ResponseMetadata), RusotoError<PutScalingPolicyError>>
+    {
+        let request = self.build_put_scaling_policy_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<ListTagsForResourceResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<PutScalingPolicyOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ListTagsForResourceError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(PutScalingPolicyError::from_response(response))
         }
     }
 
-    /// <p><p>Creates or updates a scaling policy for a fleet. Scaling policies are used to automatically scale a fleet&#39;s hosting capacity to meet player demand. An active scaling policy instructs Amazon GameLift to track a fleet metric and automatically change the fleet&#39;s capacity when a certain threshold is reached. There are two types of scaling policies: target-based and rule-based. Use a target-based policy to quickly and efficiently manage fleet scaling; this option is the most commonly used. Use rule-based policies when you need to exert fine-grained control over auto-scaling. </p> <p>Fleets can have multiple scaling policies of each type in force at the same time; you can have one target-based policy, one or multiple rule-based scaling policies, or both. We recommend caution, however, because multiple auto-scaling policies can have unintended consequences.</p> <p>You can temporarily suspend all scaling policies for a fleet by calling <a>StopFleetActions</a> with the fleet action AUTO_SCALING. To resume scaling policies, call <a>StartFleetActions</a> with the same fleet action. To stop just one scaling policy--or to permanently remove it, you must delete the policy with <a>DeleteScalingPolicy</a>.</p> <p>Learn more about how to work with auto-scaling in <a href="https://docs.aws.amazon.com/gamelift/latest/developerguide/fleets-autoscaling.html">Set Up Fleet Automatic Scaling</a>.</p> <p> <b>Target-based policy</b> </p> <p>A target-based policy tracks a single metric: PercentAvailableGameSessions. This metric tells us how much of a fleet&#39;s hosting capacity is ready to host game sessions but is not currently in use. This is the fleet&#39;s buffer; it measures the additional player demand that the fleet could handle at current capacity. With a target-based policy, you set your ideal buffer size and leave it to Amazon GameLift to take whatever action is needed to maintain that target. </p> <p>For example, you might choose to maintain a 10% buffer for a fleet that has the capacity to host 100 simultaneous game sessions. This policy tells Amazon GameLift to take action whenever the fleet&#39;s available capacity falls below or rises above 10 game sessions. Amazon GameLift will start new instances or stop unused instances in order to return to the 10% buffer. </p> <p>To create or update a target-based policy, specify a fleet ID and name, and set the policy type to &quot;TargetBased&quot;. Specify the metric to track (PercentAvailableGameSessions) and reference a <a>TargetConfiguration</a> object with your desired buffer value. Exclude all other parameters. On a successful request, the policy name is returned. The scaling policy is automatically in force as soon as it&#39;s successfully created. If the fleet&#39;s auto-scaling actions are temporarily suspended, the new policy will be in force once the fleet actions are restarted.</p> <p> <b>Rule-based policy</b> </p> <p>A rule-based policy tracks specified fleet metric, sets a threshold value, and specifies the type of action to initiate when triggered. With a rule-based policy, you can select from several available fleet metrics. Each policy specifies whether to scale up or scale down (and by how much), so you need one policy for each type of action. </p> <p>For example, a policy may make the following statement: &quot;If the percentage of idle instances is greater than 20% for more than 15 minutes, then reduce the fleet capacity by 10%.&quot;</p> <p>A policy&#39;s rule statement has the following structure:</p> <p>If <code>[MetricName]</code> is <code>[ComparisonOperator]</code> <code>[Threshold]</code> for <code>[EvaluationPeriods]</code> minutes, then <code>[ScalingAdjustmentType]</code> to/by <code>[ScalingAdjustment]</code>.</p> <p>To implement the example, the rule statement would look like this:</p> <p>If <code>[PercentIdleInstances]</code> is <code>[GreaterThanThreshold]</code> <code>[20]</code> for <code>[15]</code> minutes, then <code>[PercentChangeInCapacity]</code> to/by <code>[10]</code>.</p> <p>To create or update a scaling policy, specify a unique combination of name and fleet ID, and set the policy type to &quot;RuleBased&quot;. Specify the parameter values for a policy rule statement. On a successful request, the policy name is returned. Scaling policies are automatically in force as soon as they&#39;re successfully created. If the fleet&#39;s auto-scaling actions are temporarily suspended, the new policy will be in force once the fleet actions are restarted.</p> <ul> <li> <p> <a>DescribeFleetCapacity</a> </p> </li> <li> <p> <a>UpdateFleetCapacity</a> </p> </li> <li> <p> <a>DescribeEC2InstanceLimits</a> </p> </li> <li> <p>Manage scaling policies:</p> <ul> <li> <p> <a>PutScalingPolicy</a> (auto-scaling)</p> </li> <li> <p> <a>DescribeScalingPolicies</a> (auto-scaling)</p> </li> <li> <p> <a>DeleteScalingPolicy</a> (auto-scaling)</p> </li> </ul> </li> <li> <p>Manage fleet actions:</p> <ul> <li> <p> <a>StartFleetActions</a> </p> </li> <li> <p> <a>StopFleetActions</a> </p> </li> </ul> </li> </ul></p>
-    async fn put_scaling_policy(
+    /// Presigns the request `put_scaling_policy` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_put_scaling_policy(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: PutScalingPolicyInput,
-    ) -> Result<PutScalingPolicyOutput, RusotoError<PutScalingPolicyError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_put_scaling_policy_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_put_scaling_policy_request(&self, input: PutScalingPolicyInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "gamelift", &self.region, "/");
+        request.set_operation("PutScalingPolicy");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "GameLift.PutScalingPolicy");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `request_upload_credentials`, also returning the metadata of the response.
+    pub async fn request_upload_credentials_with_metadata(
+        &self,
+        input: RequestUploadCredentialsInput,
+    ) -> Result<
+        (RequestUploadCredentialsOutput, ResponseMetadata),
+        RusotoError<RequestUploadCredentialsError>,
+    > {
+        let request = self.build_request_upload_credentials_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<PutScalingPolicyOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<RequestUploadCredentialsOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(PutScalingPolicyError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(RequestUploadCredentialsError::from_response(response))
         }
     }
 
-    /// <p><p>Retrieves a fresh set of credentials for use when uploading a new set of game build files to Amazon GameLift&#39;s Amazon S3. This is done as part of the build creation process; see <a>CreateBuild</a>.</p> <p>To request new credentials, specify the build ID as returned with an initial <code>CreateBuild</code> request. If successful, a new set of credentials are returned, along with the S3 storage location associated with the build ID.</p> <p> <b>Learn more</b> </p> <p> <a href="https://docs.aws.amazon.com/gamelift/latest/developerguide/gamelift-build-intro.html">Uploading Your Game</a> </p> <p> <b>Related operations</b> </p> <ul> <li> <p> <a>CreateBuild</a> </p> </li> <li> <p> <a>ListBuilds</a> </p> </li> <li> <p> <a>DescribeBuild</a> </p> </li> <li> <p> <a>UpdateBuild</a> </p> </li> <li> <p> <a>DeleteBuild</a> </p> </li> </ul></p>
-    async fn request_upload_credentials(
+    /// Presigns the request `request_upload_credentials` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_request_upload_credentials(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: RequestUploadCredentialsInput,
-    ) -> Result<RequestUploadCredentialsOutput, RusotoError<RequestUploadCredentialsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_request_upload_credentials_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_request_upload_credentials_request(
+        &self,
+        input: RequestUploadCredentialsInput,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "gamelift", &self.region, "/");
+        request.set_operation("RequestUploadCredentials");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "GameLift.RequestUploadCredentials");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `resolve_alias`, also returning the metadata of the response.
+    pub async fn resolve_alias_with_metadata(
+        &self,
+        input: ResolveAliasInput,
+    ) -> Result<(ResolveAliasOutput, ResponseMetadata), RusotoError<ResolveAliasError>> {
+        let request = self.build_resolve_alias_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<RequestUploadCredentialsOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ResolveAliasOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(RequestUploadCredentialsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ResolveAliasError::from_response(response))
         }
     }
 
-    /// <p><p>Retrieves the fleet ID that an alias is currently pointing to.</p> <ul> <li> <p> <a>CreateAlias</a> </p> </li> <li> <p> <a>ListAliases</a> </p> </li> <li> <p> <a>DescribeAlias</a> </p> </li> <li> <p> <a>UpdateAlias</a> </p> </li> <li> <p> <a>DeleteAlias</a> </p> </li> <li> <p> <a>ResolveAlias</a> </p> </li> </ul></p>
-    async fn resolve_alias(
+    /// Presigns the request `resolve_alias` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_resolve_alias(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ResolveAliasInput,
-    ) -> Result<ResolveAliasOutput, RusotoError<ResolveAliasError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_resolve_alias_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_resolve_alias_request(&self, input: ResolveAliasInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "gamelift", &self.region, "/");
+        request.set_operation("ResolveAlias");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "GameLift.ResolveAlias");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `search_game_sessions`, also returning the metadata of the response.
+    pub async fn search_game_sessions_with_metadata(
+        &self,
+        input: SearchGameSessionsInput,
+    ) -> Result<(SearchGameSessionsOutput, ResponseMetadata), RusotoError<SearchGameSessionsError>>
+    {
+        let request = self.build_search_game_sessions_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<ResolveAliasOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<SearchGameSessionsOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ResolveAliasError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(SearchGameSessionsError::from_response(response))
         }
     }
 
-    /// <p><p>Retrieves all active game sessions that match a set of search criteria and sorts them in a specified order. You can search or sort by the following game session attributes:</p> <ul> <li> <p> <b>gameSessionId</b> -- A unique identifier for the game session. You can use either a <code>GameSessionId</code> or <code>GameSessionArn</code> value. </p> </li> <li> <p> <b>gameSessionName</b> -- Name assigned to a game session. This value is set when requesting a new game session with <a>CreateGameSession</a> or updating with <a>UpdateGameSession</a>. Game session names do not need to be unique to a game session.</p> </li> <li> <p> <b>gameSessionProperties</b> -- Custom data defined in a game session&#39;s <code>GameProperty</code> parameter. <code>GameProperty</code> values are stored as key:value pairs; the filter expression must indicate the key and a string to search the data values for. For example, to search for game sessions with custom data containing the key:value pair &quot;gameMode:brawl&quot;, specify the following: <code>gameSessionProperties.gameMode = &quot;brawl&quot;</code>. All custom data values are searched as strings.</p> </li> <li> <p> <b>maximumSessions</b> -- Maximum number of player sessions allowed for a game session. This value is set when requesting a new game session with <a>CreateGameSession</a> or updating with <a>UpdateGameSession</a>.</p> </li> <li> <p> <b>creationTimeMillis</b> -- Value indicating when a game session was created. It is expressed in Unix time as milliseconds.</p> </li> <li> <p> <b>playerSessionCount</b> -- Number of players currently connected to a game session. This value changes rapidly as players join the session or drop out.</p> </li> <li> <p> <b>hasAvailablePlayerSessions</b> -- Boolean value indicating whether a game session has reached its maximum number of players. It is highly recommended that all search requests include this filter attribute to optimize search performance and return only sessions that players can join. </p> </li> </ul> <note> <p>Returned values for <code>playerSessionCount</code> and <code>hasAvailablePlayerSessions</code> change quickly as players join sessions and others drop out. Results should be considered a snapshot in time. Be sure to refresh search results often, and handle sessions that fill up before a player can join. </p> </note> <p>To search or sort, specify either a fleet ID or an alias ID, and provide a search filter expression, a sort expression, or both. If successful, a collection of <a>GameSession</a> objects matching the request is returned. Use the pagination parameters to retrieve results as a set of sequential pages. </p> <p>You can search for game sessions one fleet at a time only. To find game sessions across multiple fleets, you must search each fleet separately and combine the results. This search feature finds only game sessions that are in <code>ACTIVE</code> status. To locate games in statuses other than active, use <a>DescribeGameSessionDetails</a>.</p> <ul> <li> <p> <a>CreateGameSession</a> </p> </li> <li> <p> <a>DescribeGameSessions</a> </p> </li> <li> <p> <a>DescribeGameSessionDetails</a> </p> </li> <li> <p> <a>SearchGameSessions</a> </p> </li> <li> <p> <a>UpdateGameSession</a> </p> </li> <li> <p> <a>GetGameSessionLogUrl</a> </p> </li> <li> <p>Game session placements</p> <ul> <li> <p> <a>StartGameSessionPlacement</a> </p> </li> <li> <p> <a>DescribeGameSessionPlacement</a> </p> </li> <li> <p> <a>StopGameSessionPlacement</a> </p> </li> </ul> </li> </ul></p>
-    async fn search_game_sessions(
+    /// Presigns the request `search_game_sessions` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_search_game_sessions(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: SearchGameSessionsInput,
-    ) -> Result<SearchGameSessionsOutput, RusotoError<SearchGameSessionsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_search_game_sessions_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_search_game_sessions_request(&self, input: SearchGameSessionsInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "gamelift", &self.region, "/");
+        request.set_operation("SearchGameSessions");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "GameLift.SearchGameSessions");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `start_fleet_actions`, also returning the metadata of the response.
+    pub async fn start_fleet_actions_with_metadata(
+        &self,
+        input: StartFleetActionsInput,
+    ) -> Result<(StartFleetActionsOutput, ResponseMetadata), RusotoError<StartFleetActionsError>>
+    {
+        let request = self.build_start_fleet_actions_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<SearchGameSessionsOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<StartFleetActionsOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(SearchGameSessionsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(StartFleetActionsError::from_response(response))
         }
     }
 
-    /// <p><p>Resumes activity on a fleet that was suspended with <a>StopFleetActions</a>. Currently, this operation is used to restart a fleet&#39;s auto-scaling activity. </p> <p>To start fleet actions, specify the fleet ID and the type of actions to restart. When auto-scaling fleet actions are restarted, Amazon GameLift once again initiates scaling events as triggered by the fleet&#39;s scaling policies. If actions on the fleet were never stopped, this operation will have no effect. You can view a fleet&#39;s stopped actions using <a>DescribeFleetAttributes</a>.</p> <p> <b>Learn more</b> </p> <p> <a href="https://docs.aws.amazon.com/gamelift/latest/developerguide/fleets-intro.html"> Working with Fleets</a>.</p> <p> <b>Related operations</b> </p> <ul> <li> <p> <a>CreateFleet</a> </p> </li> <li> <p> <a>ListFleets</a> </p> </li> <li> <p> <a>DeleteFleet</a> </p> </li> <li> <p>Describe fleets:</p> <ul> <li> <p> <a>DescribeFleetAttributes</a> </p> </li> <li> <p> <a>DescribeFleetCapacity</a> </p> </li> <li> <p> <a>DescribeFleetPortSettings</a> </p> </li> <li> <p> <a>DescribeFleetUtilization</a> </p> </li> <li> <p> <a>DescribeRuntimeConfiguration</a> </p> </li> <li> <p> <a>DescribeEC2InstanceLimits</a> </p> </li> <li> <p> <a>DescribeFleetEvents</a> </p> </li> </ul> </li> <li> <p>Update fleets:</p> <ul> <li> <p> <a>UpdateFleetAttributes</a> </p> </li> <li> <p> <a>UpdateFleetCapacity</a> </p> </li> <li> <p> <a>UpdateFleetPortSettings</a> </p> </li> <li> <p> <a>UpdateRuntimeConfiguration</a> </p> </li> </ul> </li> <li> <p>Manage fleet actions:</p> <ul> <li> <p> <a>StartFleetActions</a> </p> </li> <li> <p> <a>StopFleetActions</a> </p> </li> </ul> </li> </ul></p>
-    async fn start_fleet_actions(
+    /// Presigns the request `start_fleet_actions` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_start_fleet_actions(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: StartFleetActionsInput,
-    ) -> Result<StartFleetActionsOutput, RusotoError<StartFleetActionsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_start_fleet_actions_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_start_fleet_actions_request(&self, input: StartFleetActionsInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "gamelift", &self.region, "/");
+        request.set_operation("StartFleetActions");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "GameLift.StartFleetActions");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `start_game_session_placement`, also returning the metadata of the response.
+    pub async fn start_game_session_placement_with_metadata(
+        &self,
+        input: StartGameSessionPlacementInput,
+    ) -> Result<
+        (StartGameSessionPlacementOutput, ResponseMetadata),
+        RusotoError<StartGameSessionPlacementError>,
+    > {
+        let request = self.build_start_game_session_placement_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<StartFleetActionsOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<StartGameSessionPlacementOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(StartFleetActionsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(StartGameSessionPlacementError::from_response(response))
         }
     }
 
-    /// <p><p>Places a request for a new game session in a queue (see <a>CreateGameSessionQueue</a>). When processing a placement request, Amazon GameLift searches for available resources on the queue&#39;s destinations, scanning each until it finds resources or the placement request times out.</p> <p>A game session placement request can also request player sessions. When a new game session is successfully created, Amazon GameLift creates a player session for each player included in the request.</p> <p>When placing a game session, by default Amazon GameLift tries each fleet in the order they are listed in the queue configuration. Ideally, a queue&#39;s destinations are listed in preference order.</p> <p>Alternatively, when requesting a game session with players, you can also provide latency data for each player in relevant Regions. Latency data indicates the performance lag a player experiences when connected to a fleet in the Region. Amazon GameLift uses latency data to reorder the list of destinations to place the game session in a Region with minimal lag. If latency data is provided for multiple players, Amazon GameLift calculates each Region&#39;s average lag for all players and reorders to get the best game play across all players. </p> <p>To place a new game session request, specify the following:</p> <ul> <li> <p>The queue name and a set of game session properties and settings</p> </li> <li> <p>A unique ID (such as a UUID) for the placement. You use this ID to track the status of the placement request</p> </li> <li> <p>(Optional) A set of player data and a unique player ID for each player that you are joining to the new game session (player data is optional, but if you include it, you must also provide a unique ID for each player)</p> </li> <li> <p>Latency data for all players (if you want to optimize game play for the players)</p> </li> </ul> <p>If successful, a new game session placement is created.</p> <p>To track the status of a placement request, call <a>DescribeGameSessionPlacement</a> and check the request&#39;s status. If the status is <code>FULFILLED</code>, a new game session has been created and a game session ARN and Region are referenced. If the placement request times out, you can resubmit the request or retry it with a different queue. </p> <ul> <li> <p> <a>CreateGameSession</a> </p> </li> <li> <p> <a>DescribeGameSessions</a> </p> </li> <li> <p> <a>DescribeGameSessionDetails</a> </p> </li> <li> <p> <a>SearchGameSessions</a> </p> </li> <li> <p> <a>UpdateGameSession</a> </p> </li> <li> <p> <a>GetGameSessionLogUrl</a> </p> </li> <li> <p>Game session placements</p> <ul> <li> <p> <a>StartGameSessionPlacement</a> </p> </li> <li> <p> <a>DescribeGameSessionPlacement</a> </p> </li> <li> <p> <a>StopGameSessionPlacement</a> </p> </li> </ul> </li> </ul></p>
-    async fn start_game_session_placement(
+    /// Presigns the request `start_game_session_placement` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_start_game_session_placement(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: StartGameSessionPlacementInput,
-    ) -> Result<StartGameSessionPlacementOutput, RusotoError<StartGameSessionPlacementError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_start_game_session_placement_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_start_game_session_placement_request(
+        &self,
+        input: StartGameSessionPlacementInput,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "gamelift", &self.region, "/");
+        request.set_operation("StartGameSessionPlacement");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "GameLift.StartGameSessionPlacement");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `start_match_backfill`, also returning the metadata of the response.
+    pub async fn start_match_backfill_with_metadata(
+        &self,
+        input: StartMatchBackfillInput,
+    ) -> Result<(StartMatchBackfillOutput, ResponseMetadata), RusotoError<StartMatchBackfillError>>
+    {
+        let request = self.build_start_match_backfill_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<StartGameSessionPlacementOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<StartMatchBackfillOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(StartGameSessionPlacementError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(StartMatchBackfillError::from_response(response))
         }
     }
 
-    /// <p><p>Finds new players to fill open slots in an existing game session. This operation can be used to add players to matched games that start with fewer than the maximum number of players or to replace players when they drop out. By backfilling with the same matchmaker used to create the original match, you ensure that new players meet the match criteria and maintain a consistent experience throughout the game session. You can backfill a match anytime after a game session has been created. </p> <p>To request a match backfill, specify a unique ticket ID, the existing game session&#39;s ARN, a matchmaking configuration, and a set of data that describes all current players in the game session. If successful, a match backfill ticket is created and returned with status set to QUEUED. The ticket is placed in the matchmaker&#39;s ticket pool and processed. Track the status of the ticket to respond as needed. </p> <p>The process of finding backfill matches is essentially identical to the initial matchmaking process. The matchmaker searches the pool and groups tickets together to form potential matches, allowing only one backfill ticket per potential match. Once the a match is formed, the matchmaker creates player sessions for the new players. All tickets in the match are updated with the game session&#39;s connection information, and the <a>GameSession</a> object is updated to include matchmaker data on the new players. For more detail on how match backfill requests are processed, see <a href="https://docs.aws.amazon.com/gamelift/latest/developerguide/gamelift-match.html"> How Amazon GameLift FlexMatch Works</a>. </p> <p> <b>Learn more</b> </p> <p> <a href="https://docs.aws.amazon.com/gamelift/latest/developerguide/match-backfill.html"> Backfill Existing Games with FlexMatch</a> </p> <p> <a href="https://docs.aws.amazon.com/gamelift/latest/developerguide/gamelift-match.html"> How GameLift FlexMatch Works</a> </p> <p> <b>Related operations</b> </p> <ul> <li> <p> <a>StartMatchmaking</a> </p> </li> <li> <p> <a>DescribeMatchmaking</a> </p> </li> <li> <p> <a>StopMatchmaking</a> </p> </li> <li> <p> <a>AcceptMatch</a> </p> </li> <li> <p> <a>StartMatchBackfill</a> </p> </li> </ul></p>
-    async fn start_match_backfill(
+    /// Presigns the request `start_match_backfill` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_start_match_backfill(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: StartMatchBackfillInput,
-    ) -> Result<StartMatchBackfillOutput, RusotoError<StartMatchBackfillError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_start_match_backfill_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_start_match_backfill_request(&self, input: StartMatchBackfillInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "gamelift", &self.region, "/");
+        request.set_operation("StartMatchBackfill");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "GameLift.StartMatchBackfill");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `start_matchmaking`, also returning the metadata of the response.
+    pub async fn start_matchmaking_with_metadata(
+        &self,
+        input: StartMatchmakingInput,
+    ) -> Result<(StartMatchmakingOutput, ResponseMetadata), RusotoError<StartMatchmakingError>>
+    {
+        let request = self.build_start_matchmaking_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<StartMatchBackfillOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<StartMatchmakingOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(StartMatchBackfillError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(StartMatchmakingError::from_response(response))
         }
     }
 
-    /// <p><p>Uses FlexMatch to create a game match for a group of players based on custom matchmaking rules, and starts a new game for the matched players. Each matchmaking request specifies the type of match to build (team configuration, rules for an acceptable match, etc.). The request also specifies the players to find a match for and where to host the new game session for optimal performance. A matchmaking request might start with a single player or a group of players who want to play together. FlexMatch finds additional players as needed to fill the match. Match type, rules, and the queue used to place a new game session are defined in a <code>MatchmakingConfiguration</code>. </p> <p>To start matchmaking, provide a unique ticket ID, specify a matchmaking configuration, and include the players to be matched. You must also include a set of player attributes relevant for the matchmaking configuration. If successful, a matchmaking ticket is returned with status set to <code>QUEUED</code>. Track the status of the ticket to respond as needed and acquire game session connection information for successfully completed matches.</p> <p> <b>Tracking ticket status</b> -- A couple of options are available for tracking the status of matchmaking requests: </p> <ul> <li> <p>Polling -- Call <code>DescribeMatchmaking</code>. This operation returns the full ticket object, including current status and (for completed tickets) game session connection info. We recommend polling no more than once every 10 seconds.</p> </li> <li> <p>Notifications -- Get event notifications for changes in ticket status using Amazon Simple Notification Service (SNS). Notifications are easy to set up (see <a>CreateMatchmakingConfiguration</a>) and typically deliver match status changes faster and more efficiently than polling. We recommend that you use polling to back up to notifications (since delivery is not guaranteed) and call <code>DescribeMatchmaking</code> only when notifications are not received within 30 seconds.</p> </li> </ul> <p> <b>Processing a matchmaking request</b> -- FlexMatch handles a matchmaking request as follows: </p> <ol> <li> <p>Your client code submits a <code>StartMatchmaking</code> request for one or more players and tracks the status of the request ticket. </p> </li> <li> <p>FlexMatch uses this ticket and others in process to build an acceptable match. When a potential match is identified, all tickets in the proposed match are advanced to the next status. </p> </li> <li> <p>If the match requires player acceptance (set in the matchmaking configuration), the tickets move into status <code>REQUIRES_ACCEPTANCE</code>. This status triggers your client code to solicit acceptance from all players in every ticket involved in the match, and then call <a>AcceptMatch</a> for each player. If any player rejects or fails to accept the match before a specified timeout, the proposed match is dropped (see <code>AcceptMatch</code> for more details).</p> </li> <li> <p>Once a match is proposed and accepted, the matchmaking tickets move into status <code>PLACING</code>. FlexMatch locates resources for a new game session using the game session queue (set in the matchmaking configuration) and creates the game session based on the match data. </p> </li> <li> <p>When the match is successfully placed, the matchmaking tickets move into <code>COMPLETED</code> status. Connection information (including game session endpoint and player session) is added to the matchmaking tickets. Matched players can use the connection information to join the game. </p> </li> </ol> <p> <b>Learn more</b> </p> <p> <a href="https://docs.aws.amazon.com/gamelift/latest/developerguide/match-client.html"> Add FlexMatch to a Game Client</a> </p> <p> <a href="https://docs.aws.amazon.com/gamelift/latest/developerguide/match-notification.html"> Set Up FlexMatch Event Notification</a> </p> <p> <a href="https://docs.aws.amazon.com/gamelift/latest/developerguide/match-tasks.html"> FlexMatch Integration Roadmap</a> </p> <p> <a href="https://docs.aws.amazon.com/gamelift/latest/developerguide/gamelift-match.html"> How GameLift FlexMatch Works</a> </p> <p> <b>Related operations</b> </p> <ul> <li> <p> <a>StartMatchmaking</a> </p> </li> <li> <p> <a>DescribeMatchmaking</a> </p> </li> <li> <p> <a>StopMatchmaking</a> </p> </li> <li> <p> <a>AcceptMatch</a> </p> </li> <li> <p> <a>StartMatchBackfill</a> </p> </li> </ul></p>
-    async fn start_matchmaking(
+    /// Presigns the request `start_matchmaking` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_start_matchmaking(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: StartMatchmakingInput,
-    ) -> Result<StartMatchmakingOutput, RusotoError<StartMatchmakingError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_start_matchmaking_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_start_matchmaking_request(&self, input: StartMatchmakingInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "gamelift", &self.region, "/");
+        request.set_operation("StartMatchmaking");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "GameLift.StartMatchmaking");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `stop_fleet_actions`, also returning the metadata of the response.
+    pub async fn stop_fleet_actions_with_metadata(
+        &self,
+        input: StopFleetActionsInput,
+    ) -> Result<(StopFleetActionsOutput, ResponseMetadata), RusotoError<StopFleetActionsError>>
+    {
+        let request = self.build_stop_fleet_actions_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<StartMatchmakingOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<StopFleetActionsOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(StartMatchmakingError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(StopFleetActionsError::from_response(response))
         }
     }
 
-    /// <p><p>Suspends activity on a fleet. Currently, this operation is used to stop a fleet&#39;s auto-scaling activity. It is used to temporarily stop scaling events triggered by the fleet&#39;s scaling policies. The policies can be retained and auto-scaling activity can be restarted using <a>StartFleetActions</a>. You can view a fleet&#39;s stopped actions using <a>DescribeFleetAttributes</a>.</p> <p>To stop fleet actions, specify the fleet ID and the type of actions to suspend. When auto-scaling fleet actions are stopped, Amazon GameLift no longer initiates scaling events except to maintain the fleet&#39;s desired instances setting (<a>FleetCapacity</a>. Changes to the fleet&#39;s capacity must be done manually using <a>UpdateFleetCapacity</a>. </p> <p> <b>Learn more</b> </p> <p> <a href="https://docs.aws.amazon.com/gamelift/latest/developerguide/fleets-intro.html"> Working with Fleets</a>.</p> <p> <b>Related operations</b> </p> <ul> <li> <p> <a>CreateFleet</a> </p> </li> <li> <p> <a>ListFleets</a> </p> </li> <li> <p> <a>DeleteFleet</a> </p> </li> <li> <p>Describe fleets:</p> <ul> <li> <p> <a>DescribeFleetAttributes</a> </p> </li> <li> <p> <a>DescribeFleetCapacity</a> </p> </li> <li> <p> <a>DescribeFleetPortSettings</a> </p> </li> <li> <p> <a>DescribeFleetUtilization</a> </p> </li> <li> <p> <a>DescribeRuntimeConfiguration</a> </p> </li> <li> <p> <a>DescribeEC2InstanceLimits</a> </p> </li> <li> <p> <a>DescribeFleetEvents</a> </p> </li> </ul> </li> <li> <p>Update fleets:</p> <ul> <li> <p> <a>UpdateFleetAttributes</a> </p> </li> <li> <p> <a>UpdateFleetCapacity</a> </p> </li> <li> <p> <a>UpdateFleetPortSettings</a> </p> </li> <li> <p> <a>UpdateRuntimeConfiguration</a> </p> </li> </ul> </li> <li> <p>Manage fleet actions:</p> <ul> <li> <p> <a>StartFleetActions</a> </p> </li> <li> <p> <a>StopFleetActions</a> </p> </li> </ul> </li> </ul></p>
-    async fn stop_fleet_actions(
+    /// Presigns the request `stop_fleet_actions` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_stop_fleet_actions(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: StopFleetActionsInput,
-    ) -> Result<StopFleetActionsOutput, RusotoError<StopFleetActionsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_stop_fleet_actions_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_stop_fleet_actions_request(&self, input: StopFleetActionsInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "gamelift", &self.region, "/");
+        request.set_operation("StopFleetActions");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "GameLift.StopFleetActions");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `stop_game_session_placement`, also returning the metadata of the response.
+    pub async fn stop_game_session_placement_with_metadata(
+        &self,
+        input: StopGameSessionPlacementInput,
+    ) -> Result<
+        (StopGameSessionPlacementOutput, ResponseMetadata),
+        RusotoError<StopGameSessionPlacementError>,
+    > {
+        let request = self.build_stop_game_session_placement_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<StopFleetActionsOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<StopGameSessionPlacementOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(StopFleetActionsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(StopGameSessionPlacementError::from_response(response))
         }
     }
 
-    /// <p><p>Cancels a game session placement that is in <code>PENDING</code> status. To stop a placement, provide the placement ID values. If successful, the placement is moved to <code>CANCELLED</code> status.</p> <ul> <li> <p> <a>CreateGameSession</a> </p> </li> <li> <p> <a>DescribeGameSessions</a> </p> </li> <li> <p> <a>DescribeGameSessionDetails</a> </p> </li> <li> <p> <a>SearchGameSessions</a> </p> </li> <li> <p> <a>UpdateGameSession</a> </p> </li> <li> <p> <a>GetGameSessionLogUrl</a> </p> </li> <li> <p>Game session placements</p> <ul> <li> <p> <a>StartGameSessionPlacement</a> </p> </li> <li> <p> <a>DescribeGameSessionPlacement</a> </p> </li> <li> <p> <a>StopGameSessionPlacement</a> </p> </li> </ul> </li> </ul></p>
-    async fn stop_game_session_placement(
+    /// Presigns the request `stop_game_session_placement` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_stop_game_session_placement(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: StopGameSessionPlacementInput,
-    ) -> Result<StopGameSessionPlacementOutput, RusotoError<StopGameSessionPlacementError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_stop_game_session_placement_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_stop_game_session_placement_request(
+        &self,
+        input: StopGameSessionPlacementInput,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "gamelift", &self.region, "/");
+        request.set_operation("StopGameSessionPlacement");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "GameLift.StopGameSessionPlacement");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `stop_matchmaking`, also returning the metadata of the response.
+    pub async fn stop_matchmaking_with_metadata(
+        &self,
+        input: StopMatchmakingInput,
+    ) -> Result<(StopMatchmakingOutput, ResponseMetadata), RusotoError<StopMatchmakingError>> {
+        let request = self.build_stop_matchmaking_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<StopGameSessionPlacementOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<StopMatchmakingOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(StopGameSessionPlacementError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(StopMatchmakingError::from_response(response))
         }
     }
 
-    /// <p><p>Cancels a matchmaking ticket or match backfill ticket that is currently being processed. To stop the matchmaking operation, specify the ticket ID. If successful, work on the ticket is stopped, and the ticket status is changed to <code>CANCELLED</code>.</p> <p>This call is also used to turn off automatic backfill for an individual game session. This is for game sessions that are created with a matchmaking configuration that has automatic backfill enabled. The ticket ID is included in the <code>MatchmakerData</code> of an updated game session object, which is provided to the game server.</p> <note> <p>If the action is successful, the service sends back an empty JSON struct with the HTTP 200 response (not an empty HTTP body).</p> </note> <p> <b>Learn more</b> </p> <p> <a href="https://docs.aws.amazon.com/gamelift/latest/developerguide/match-client.html"> Add FlexMatch to a Game Client</a> </p> <p> <b>Related operations</b> </p> <ul> <li> <p> <a>StartMatchmaking</a> </p> </li> <li> <p> <a>DescribeMatchmaking</a> </p> </li> <li> <p> <a>StopMatchmaking</a> </p> </li> <li> <p> <a>AcceptMatch</a> </p> </li> <li> <p> <a>StartMatchBackfill</a> </p> </li> </ul></p>
-    async fn stop_matchmaking(
+    /// Presigns the request `stop_matchmaking` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_stop_matchmaking(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: StopMatchmakingInput,
-    ) -> Result<StopMatchmakingOutput, RusotoError<StopMatchmakingError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_stop_matchmaking_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_stop_matchmaking_request(&self, input: StopMatchmakingInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "gamelift", &self.region, "/");
+        request.set_operation("StopMatchmaking");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "GameLift.StopMatchmaking");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `tag_resource`, also returning the metadata of the response.
+    pub async fn tag_resource_with_metadata(
+        &self,
+        input: TagResourceRequest,
+    ) -> Result<(TagResourceResponse, ResponseMetadata), RusotoError<TagResourceError>> {
+        let request = self.build_tag_resource_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<StopMatchmakingOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<TagResourceResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(StopMatchmakingError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(TagResourceError::from_response(response))
         }
     }
 
-    /// <p><p> Assigns a tag to a GameLift resource. AWS resource tags provide an additional management tool set. You can use tags to organize resources, create IAM permissions policies to manage access to groups of resources, customize AWS cost breakdowns, etc. This action handles the permissions necessary to manage tags for the following GameLift resource types:</p> <ul> <li> <p>Build</p> </li> <li> <p>Script</p> </li> <li> <p>Fleet</p> </li> <li> <p>Alias</p> </li> <li> <p>GameSessionQueue</p> </li> <li> <p>MatchmakingConfiguration</p> </li> <li> <p>MatchmakingRuleSet</p> </li> </ul> <p>To add a tag to a resource, specify the unique ARN value for the resource and provide a trig list containing one or more tags. The operation succeeds even if the list includes tags that are already assigned to the specified resource. </p> <p> <b>Learn more</b> </p> <p> <a href="https://docs.aws.amazon.com/general/latest/gr/aws_tagging.html">Tagging AWS Resources</a> in the <i>AWS General Reference</i> </p> <p> <a href="http://aws.amazon.com/answers/account-management/aws-tagging-strategies/"> AWS Tagging Strategies</a> </p> <p> <b>Related operations</b> </p> <ul> <li> <p> <a>TagResource</a> </p> </li> <li> <p> <a>UntagResource</a> </p> </li> <li> <p> <a>ListTagsForResource</a> </p> </li> </ul></p>
-    async fn tag_resource(
+    /// Presigns the request `tag_resource` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_tag_resource(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: TagResourceRequest,
-    ) -> Result<TagResourceResponse, RusotoError<TagResourceError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_tag_resource_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_tag_resource_request(&self, input: TagResourceRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "gamelift", &self.region, "/");
+        request.set_operation("TagResource");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "GameLift.TagResource");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `untag_resource`, also returning the metadata of the response.
+    pub async fn untag_resource_with_metadata(
+        &self,
+        input: UntagResourceRequest,
+    ) -> Result<(UntagResourceResponse, ResponseMetadata), RusotoError<UntagResourceError>> {
+        let request = self.build_untag_resource_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<TagResourceResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<UntagResourceResponse, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(TagResourceError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(UntagResourceError::from_response(response))
         }
     }
 
-    /// <p><p>Removes a tag that is assigned to a GameLift resource. Resource tags are used to organize AWS resources for a range of purposes. This action handles the permissions necessary to manage tags for the following GameLift resource types:</p> <ul> <li> <p>Build</p> </li> <li> <p>Script</p> </li> <li> <p>Fleet</p> </li> <li> <p>Alias</p> </li> <li> <p>GameSessionQueue</p> </li> <li> <p>MatchmakingConfiguration</p> </li> <li> <p>MatchmakingRuleSet</p> </li> </ul> <p>To remove a tag from a resource, specify the unique ARN value for the resource and provide a string list containing one or more tags to be removed. This action succeeds even if the list includes tags that are not currently assigned to the specified resource.</p> <p> <b>Learn more</b> </p> <p> <a href="https://docs.aws.amazon.com/general/latest/gr/aws_tagging.html">Tagging AWS Resources</a> in the <i>AWS General Reference</i> </p> <p> <a href="http://aws.amazon.com/answers/account-management/aws-tagging-strategies/"> AWS Tagging Strategies</a> </p> <p> <b>Related operations</b> </p> <ul> <li> <p> <a>TagResource</a> </p> </li> <li> <p> <a>UntagResource</a> </p> </li> <li> <p> <a>ListTagsForResource</a> </p> </li> </ul></p>
-    async fn untag_resource(
+    /// Presigns the request `untag_resource` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_untag_resource(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UntagResourceRequest,
-    ) -> Result<UntagResourceResponse, RusotoError<UntagResourceError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_untag_resource_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_untag_resource_request(&self, input: UntagResourceRequest) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "gamelift", &self.region, "/");
+        request.set_operation("UntagResource");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "GameLift.UntagResource");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `update_alias`, also returning the metadata of the response.
+    pub async fn update_alias_with_metadata(
+        &self,
+        input: UpdateAliasInput,
+    ) -> Result<(UpdateAliasOutput, ResponseMetadata), RusotoError<UpdateAliasError>> {
+        let request = self.build_update_alias_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<UntagResourceResponse, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<UpdateAliasOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(UntagResourceError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(UpdateAliasError::from_response(response))
         }
     }
 
-    /// <p><p>Updates properties for an alias. To update properties, specify the alias ID to be updated and provide the information to be changed. To reassign an alias to another fleet, provide an updated routing strategy. If successful, the updated alias record is returned.</p> <ul> <li> <p> <a>CreateAlias</a> </p> </li> <li> <p> <a>ListAliases</a> </p> </li> <li> <p> <a>DescribeAlias</a> </p> </li> <li> <p> <a>UpdateAlias</a> </p> </li> <li> <p> <a>DeleteAlias</a> </p> </li> <li> <p> <a>ResolveAlias</a> </p> </li> </ul></p>
-    async fn update_alias(
+    /// Presigns the request `update_alias` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_alias(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UpdateAliasInput,
-    ) -> Result<UpdateAliasOutput, RusotoError<UpdateAliasError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_alias_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_alias_request(&self, input: UpdateAliasInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "gamelift", &self.region, "/");
+        request.set_operation("UpdateAlias");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "GameLift.UpdateAlias");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `update_build`, also returning the metadata of the response.
+    pub async fn update_build_with_metadata(
+        &self,
+        input: UpdateBuildInput,
+    ) -> Result<(UpdateBuildOutput, ResponseMetadata), RusotoError<UpdateBuildError>> {
+        let request = self.build_update_build_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<UpdateAliasOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<UpdateBuildOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(UpdateAliasError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(UpdateBuildError::from_response(response))
         }
     }
 
-    /// <p><p>Updates metadata in a build record, including the build name and version. To update the metadata, specify the build ID to update and provide the new values. If successful, a build object containing the updated metadata is returned.</p> <p> <b>Learn more</b> </p> <p> <a href="https://docs.aws.amazon.com/gamelift/latest/developerguide/build-intro.html"> Working with Builds</a> </p> <p> <b>Related operations</b> </p> <ul> <li> <p> <a>CreateBuild</a> </p> </li> <li> <p> <a>ListBuilds</a> </p> </li> <li> <p> <a>DescribeBuild</a> </p> </li> <li> <p> <a>UpdateBuild</a> </p> </li> <li> <p> <a>DeleteBuild</a> </p> </li> </ul></p>
-    async fn update_build(
+    /// Presigns the request `update_build` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_build(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UpdateBuildInput,
-    ) -> Result<UpdateBuildOutput, RusotoError<UpdateBuildError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_build_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_build_request(&self, input: UpdateBuildInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "gamelift", &self.region, "/");
+        request.set_operation("UpdateBuild");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "GameLift.UpdateBuild");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `update_fleet_attributes`, also returning the metadata of the response.
+    pub async fn update_fleet_attributes_with_metadata(
+        &self,
+        input: UpdateFleetAttributesInput,
+    ) -> Result<
+        (UpdateFleetAttributesOutput, ResponseMetadata),
+        RusotoError<UpdateFleetAttributesError>,
+    > {
+        let request = self.build_update_fleet_attributes_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<UpdateBuildOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<UpdateFleetAttributesOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(UpdateBuildError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(UpdateFleetAttributesError::from_response(response))
         }
     }
 
-    /// <p><p>Updates fleet properties, including name and description, for a fleet. To update metadata, specify the fleet ID and the property values that you want to change. If successful, the fleet ID for the updated fleet is returned.</p> <p> <b>Learn more</b> </p> <p> <a href="https://docs.aws.amazon.com/gamelift/latest/developerguide/fleets-intro.html"> Working with Fleets</a>.</p> <p> <b>Related operations</b> </p> <ul> <li> <p> <a>CreateFleet</a> </p> </li> <li> <p> <a>ListFleets</a> </p> </li> <li> <p> <a>DeleteFleet</a> </p> </li> <li> <p> <a>DescribeFleetAttributes</a> </p> </li> <li> <p>Update fleets:</p> <ul> <li> <p> <a>UpdateFleetAttributes</a> </p> </li> <li> <p> <a>UpdateFleetCapacity</a> </p> </li> <li> <p> <a>UpdateFleetPortSettings</a> </p> </li> <li> <p> <a>UpdateRuntimeConfiguration</a> </p> </li> </ul> </li> <li> <p>Manage fleet actions:</p> <ul> <li> <p> <a>StartFleetActions</a> </p> </li> <li> <p> <a>StopFleetActions</a> </p> </li> </ul> </li> </ul></p>
-    async fn update_fleet_attributes(
+    /// Presigns the request `update_fleet_attributes` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_fleet_attributes(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UpdateFleetAttributesInput,
-    ) -> Result<UpdateFleetAttributesOutput, RusotoError<UpdateFleetAttributesError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_fleet_attributes_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_fleet_attributes_request(
+        &self,
+        input: UpdateFleetAttributesInput,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "gamelift", &self.region, "/");
+        request.set_operation("UpdateFleetAttributes");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "GameLift.UpdateFleetAttributes");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `update_fleet_capacity`, also returning the metadata of the response.
+    pub async fn update_fleet_capacity_with_metadata(
+        &self,
+        input: UpdateFleetCapacityInput,
+    ) -> Result<(UpdateFleetCapacityOutput, ResponseMetadata), RusotoError<UpdateFleetCapacityError>>
+    {
+        let request = self.build_update_fleet_capacity_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<UpdateFleetAttributesOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<UpdateFleetCapacityOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(UpdateFleetAttributesError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(UpdateFleetCapacityError::from_response(response))
         }
     }
 
-    /// <p><p>Updates capacity settings for a fleet. Use this action to specify the number of EC2 instances (hosts) that you want this fleet to contain. Before calling this action, you may want to call <a>DescribeEC2InstanceLimits</a> to get the maximum capacity based on the fleet&#39;s EC2 instance type.</p> <p>Specify minimum and maximum number of instances. Amazon GameLift will not change fleet capacity to values fall outside of this range. This is particularly important when using auto-scaling (see <a>PutScalingPolicy</a>) to allow capacity to adjust based on player demand while imposing limits on automatic adjustments.</p> <p>To update fleet capacity, specify the fleet ID and the number of instances you want the fleet to host. If successful, Amazon GameLift starts or terminates instances so that the fleet&#39;s active instance count matches the desired instance count. You can view a fleet&#39;s current capacity information by calling <a>DescribeFleetCapacity</a>. If the desired instance count is higher than the instance type&#39;s limit, the &quot;Limit Exceeded&quot; exception occurs.</p> <p> <b>Learn more</b> </p> <p> <a href="https://docs.aws.amazon.com/gamelift/latest/developerguide/fleets-intro.html"> Working with Fleets</a>.</p> <p> <b>Related operations</b> </p> <ul> <li> <p> <a>CreateFleet</a> </p> </li> <li> <p> <a>ListFleets</a> </p> </li> <li> <p> <a>DeleteFleet</a> </p> </li> <li> <p> <a>DescribeFleetAttributes</a> </p> </li> <li> <p>Update fleets:</p> <ul> <li> <p> <a>UpdateFleetAttributes</a> </p> </li> <li> <p> <a>UpdateFleetCapacity</a> </p> </li> <li> <p> <a>UpdateFleetPortSettings</a> </p> </li> <li> <p> <a>UpdateRuntimeConfiguration</a> </p> </li> </ul> </li> <li> <p>Manage fleet actions:</p> <ul> <li> <p> <a>StartFleetActions</a> </p> </li> <li> <p> <a>StopFleetActions</a> </p> </li> </ul> </li> </ul></p>
-    async fn update_fleet_capacity(
+    /// Presigns the request `update_fleet_capacity` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_fleet_capacity(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UpdateFleetCapacityInput,
-    ) -> Result<UpdateFleetCapacityOutput, RusotoError<UpdateFleetCapacityError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_fleet_capacity_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_fleet_capacity_request(
+        &self,
+        input: UpdateFleetCapacityInput,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "gamelift", &self.region, "/");
+        request.set_operation("UpdateFleetCapacity");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "GameLift.UpdateFleetCapacity");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
-        let mut response = self
-            .client
-            .sign_and_dispatch(request)
-            .await
+        request
+    }
+
+    /// Calls `update_fleet_port_settings`, also returning the metadata of the response.
+    pub async fn update_fleet_port_settings_with_metadata(
+        &self,
+        input: UpdateFleetPortSettingsInput,
+    ) -> Result<
+        (UpdateFleetPortSettingsOutput, ResponseMetadata),
+        RusotoError<UpdateFleetPortSettingsError>,
+    > {
+        let request = self.build_update_fleet_port_settings_request(input);
+
+        let mut response = self
+            .client
+            .sign_and_dispatch(request)
+            .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<UpdateFleetCapacityOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<UpdateFleetPortSettingsOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(UpdateFleetCapacityError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(UpdateFleetPortSettingsError::from_response(response))
         }
     }
 
-    /// <p><p>Updates port settings for a fleet. To update settings, specify the fleet ID to be updated and list the permissions you want to update. List the permissions you want to add in <code>InboundPermissionAuthorizations</code>, and permissions you want to remove in <code>InboundPermissionRevocations</code>. Permissions to be removed must match existing fleet permissions. If successful, the fleet ID for the updated fleet is returned.</p> <p> <b>Learn more</b> </p> <p> <a href="https://docs.aws.amazon.com/gamelift/latest/developerguide/fleets-intro.html"> Working with Fleets</a>.</p> <p> <b>Related operations</b> </p> <ul> <li> <p> <a>CreateFleet</a> </p> </li> <li> <p> <a>ListFleets</a> </p> </li> <li> <p> <a>DeleteFleet</a> </p> </li> <li> <p> <a>DescribeFleetAttributes</a> </p> </li> <li> <p>Update fleets:</p> <ul> <li> <p> <a>UpdateFleetAttributes</a> </p> </li> <li> <p> <a>UpdateFleetCapacity</a> </p> </li> <li> <p> <a>UpdateFleetPortSettings</a> </p> </li> <li> <p> <a>UpdateRuntimeConfiguration</a> </p> </li> </ul> </li> <li> <p>Manage fleet actions:</p> <ul> <li> <p> <a>StartFleetActions</a> </p> </li> <li> <p> <a>StopFleetActions</a> </p> </li> </ul> </li> </ul></p>
-    async fn update_fleet_port_settings(
+    /// Presigns the request `update_fleet_port_settings` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_fleet_port_settings(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UpdateFleetPortSettingsInput,
-    ) -> Result<UpdateFleetPortSettingsOutput, RusotoError<UpdateFleetPortSettingsError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_fleet_port_settings_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_fleet_port_settings_request(
+        &self,
+        input: UpdateFleetPortSettingsInput,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "gamelift", &self.region, "/");
+        request.set_operation("UpdateFleetPortSettings");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "GameLift.UpdateFleetPortSettings");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `update_game_session`, also returning the metadata of the response.
+    pub async fn update_game_session_with_metadata(
+        &self,
+        input: UpdateGameSessionInput,
+    ) -> Result<(UpdateGameSessionOutput, ResponseMetadata), RusotoError<UpdateGameSessionError>>
+    {
+        let request = self.build_update_game_session_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<UpdateFleetPortSettingsOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<UpdateGameSessionOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(UpdateFleetPortSettingsError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(UpdateGameSessionError::from_response(response))
         }
     }
 
-    /// <p><p>Updates game session properties. This includes the session name, maximum player count, protection policy, which controls whether or not an active game session can be terminated during a scale-down event, and the player session creation policy, which controls whether or not new players can join the session. To update a game session, specify the game session ID and the values you want to change. If successful, an updated <a>GameSession</a> object is returned. </p> <ul> <li> <p> <a>CreateGameSession</a> </p> </li> <li> <p> <a>DescribeGameSessions</a> </p> </li> <li> <p> <a>DescribeGameSessionDetails</a> </p> </li> <li> <p> <a>SearchGameSessions</a> </p> </li> <li> <p> <a>UpdateGameSession</a> </p> </li> <li> <p> <a>GetGameSessionLogUrl</a> </p> </li> <li> <p>Game session placements</p> <ul> <li> <p> <a>StartGameSessionPlacement</a> </p> </li> <li> <p> <a>DescribeGameSessionPlacement</a> </p> </li> <li> <p> <a>StopGameSessionPlacement</a> </p> </li> </ul> </li> </ul></p>
-    async fn update_game_session(
+    /// Presigns the request `update_game_session` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_game_session(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UpdateGameSessionInput,
-    ) -> Result<UpdateGameSessionOutput, RusotoError<UpdateGameSessionError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_game_session_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_game_session_request(&self, input: UpdateGameSessionInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "gamelift", &self.region, "/");
+        request.set_operation("UpdateGameSession");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "GameLift.UpdateGameSession");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `update_game_session_queue`, also returning the metadata of the response.
+    pub async fn update_game_session_queue_with_metadata(
+        &self,
+        input: UpdateGameSessionQueueInput,
+    ) -> Result<
+        (UpdateGameSessionQueueOutput, ResponseMetadata),
+        RusotoError<UpdateGameSessionQueueError>,
+    > {
+        let request = self.build_update_game_session_queue_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<UpdateGameSessionOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<UpdateGameSessionQueueOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(UpdateGameSessionError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(UpdateGameSessionQueueError::from_response(response))
         }
     }
 
-    /// <p><p>Updates settings for a game session queue, which determines how new game session requests in the queue are processed. To update settings, specify the queue name to be updated and provide the new settings. When updating destinations, provide a complete list of destinations. </p> <ul> <li> <p> <a>CreateGameSessionQueue</a> </p> </li> <li> <p> <a>DescribeGameSessionQueues</a> </p> </li> <li> <p> <a>UpdateGameSessionQueue</a> </p> </li> <li> <p> <a>DeleteGameSessionQueue</a> </p> </li> </ul></p>
-    async fn update_game_session_queue(
+    /// Presigns the request `update_game_session_queue` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_game_session_queue(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UpdateGameSessionQueueInput,
-    ) -> Result<UpdateGameSessionQueueOutput, RusotoError<UpdateGameSessionQueueError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_game_session_queue_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_game_session_queue_request(
+        &self,
+        input: UpdateGameSessionQueueInput,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "gamelift", &self.region, "/");
+        request.set_operation("UpdateGameSessionQueue");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "GameLift.UpdateGameSessionQueue");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `update_matchmaking_configuration`, also returning the metadata of the response.
+    pub async fn update_matchmaking_configuration_with_metadata(
+        &self,
+        input: UpdateMatchmakingConfigurationInput,
+    ) -> Result<
+        (UpdateMatchmakingConfigurationOutput, ResponseMetadata),
+        RusotoError<UpdateMatchmakingConfigurationError>,
+    > {
+        let request = self.build_update_matchmaking_configuration_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<UpdateGameSessionQueueOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<UpdateMatchmakingConfigurationOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(UpdateGameSessionQueueError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(UpdateMatchmakingConfigurationError::from_response(response))
         }
     }
 
-    /// <p><p>Updates settings for a FlexMatch matchmaking configuration. These changes affect all matches and game sessions that are created after the update. To update settings, specify the configuration name to be updated and provide the new settings. </p> <p> <b>Learn more</b> </p> <p> <a href="https://docs.aws.amazon.com/gamelift/latest/developerguide/match-configuration.html"> Design a FlexMatch Matchmaker</a> </p> <p> <b>Related operations</b> </p> <ul> <li> <p> <a>CreateMatchmakingConfiguration</a> </p> </li> <li> <p> <a>DescribeMatchmakingConfigurations</a> </p> </li> <li> <p> <a>UpdateMatchmakingConfiguration</a> </p> </li> <li> <p> <a>DeleteMatchmakingConfiguration</a> </p> </li> <li> <p> <a>CreateMatchmakingRuleSet</a> </p> </li> <li> <p> <a>DescribeMatchmakingRuleSets</a> </p> </li> <li> <p> <a>ValidateMatchmakingRuleSet</a> </p> </li> <li> <p> <a>DeleteMatchmakingRuleSet</a> </p> </li> </ul></p>
-    async fn update_matchmaking_configuration(
+    /// Presigns the request `update_matchmaking_configuration` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_matchmaking_configuration(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UpdateMatchmakingConfigurationInput,
-    ) -> Result<
-        UpdateMatchmakingConfigurationOutput,
-        RusotoError<UpdateMatchmakingConfigurationError>,
-    > {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_matchmaking_configuration_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_matchmaking_configuration_request(
+        &self,
+        input: UpdateMatchmakingConfigurationInput,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "gamelift", &self.region, "/");
+        request.set_operation("UpdateMatchmakingConfiguration");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "GameLift.UpdateMatchmakingConfiguration");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `update_runtime_configuration`, also returning the metadata of the response.
+    pub async fn update_runtime_configuration_with_metadata(
+        &self,
+        input: UpdateRuntimeConfigurationInput,
+    ) -> Result<
+        (UpdateRuntimeConfigurationOutput, ResponseMetadata),
+        RusotoError<UpdateRuntimeConfigurationError>,
+    > {
+        let request = self.build_update_runtime_configuration_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<UpdateMatchmakingConfigurationOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<UpdateRuntimeConfigurationOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(UpdateMatchmakingConfigurationError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(UpdateRuntimeConfigurationError::from_response(response))
         }
     }
 
-    /// <p><p>Updates the current runtime configuration for the specified fleet, which tells Amazon GameLift how to launch server processes on instances in the fleet. You can update a fleet&#39;s runtime configuration at any time after the fleet is created; it does not need to be in an <code>ACTIVE</code> status.</p> <p>To update runtime configuration, specify the fleet ID and provide a <code>RuntimeConfiguration</code> object with an updated set of server process configurations.</p> <p>Each instance in a Amazon GameLift fleet checks regularly for an updated runtime configuration and changes how it launches server processes to comply with the latest version. Existing server processes are not affected by the update; runtime configuration changes are applied gradually as existing processes shut down and new processes are launched during Amazon GameLift&#39;s normal process recycling activity.</p> <p> <b>Learn more</b> </p> <p> <a href="https://docs.aws.amazon.com/gamelift/latest/developerguide/fleets-intro.html"> Working with Fleets</a>.</p> <p> <b>Related operations</b> </p> <ul> <li> <p> <a>CreateFleet</a> </p> </li> <li> <p> <a>ListFleets</a> </p> </li> <li> <p> <a>DeleteFleet</a> </p> </li> <li> <p> <a>DescribeFleetAttributes</a> </p> </li> <li> <p>Update fleets:</p> <ul> <li> <p> <a>UpdateFleetAttributes</a> </p> </li> <li> <p> <a>UpdateFleetCapacity</a> </p> </li> <li> <p> <a>UpdateFleetPortSettings</a> </p> </li> <li> <p> <a>UpdateRuntimeConfiguration</a> </p> </li> </ul> </li> <li> <p>Manage fleet actions:</p> <ul> <li> <p> <a>StartFleetActions</a> </p> </li> <li> <p> <a>StopFleetActions</a> </p> </li> </ul> </li> </ul></p>
-    async fn update_runtime_configuration(
+    /// Presigns the request `update_runtime_configuration` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_runtime_configuration(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UpdateRuntimeConfigurationInput,
-    ) -> Result<UpdateRuntimeConfigurationOutput, RusotoError<UpdateRuntimeConfigurationError>>
-    {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_runtime_configuration_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_runtime_configuration_request(
+        &self,
+        input: UpdateRuntimeConfigurationInput,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "gamelift", &self.region, "/");
+        request.set_operation("UpdateRuntimeConfiguration");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "GameLift.UpdateRuntimeConfiguration");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `update_script`, also returning the metadata of the response.
+    pub async fn update_script_with_metadata(
+        &self,
+        input: UpdateScriptInput,
+    ) -> Result<(UpdateScriptOutput, ResponseMetadata), RusotoError<UpdateScriptError>> {
+        let request = self.build_update_script_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<UpdateRuntimeConfigurationOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<UpdateScriptOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(UpdateRuntimeConfigurationError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(UpdateScriptError::from_response(response))
         }
     }
 
-    /// <p><p>Updates Realtime script metadata and content.</p> <p>To update script metadata, specify the script ID and provide updated name and/or version values. </p> <p>To update script content, provide an updated zip file by pointing to either a local file or an Amazon S3 bucket location. You can use either method regardless of how the original script was uploaded. Use the <i>Version</i> parameter to track updates to the script.</p> <p>If the call is successful, the updated metadata is stored in the script record and a revised script is uploaded to the Amazon GameLift service. Once the script is updated and acquired by a fleet instance, the new version is used for all new game sessions. </p> <p> <b>Learn more</b> </p> <p> <a href="https://docs.aws.amazon.com/gamelift/latest/developerguide/realtime-intro.html">Amazon GameLift Realtime Servers</a> </p> <p> <b>Related operations</b> </p> <ul> <li> <p> <a>CreateScript</a> </p> </li> <li> <p> <a>ListScripts</a> </p> </li> <li> <p> <a>DescribeScript</a> </p> </li> <li> <p> <a>UpdateScript</a> </p> </li> <li> <p> <a>DeleteScript</a> </p> </li> </ul></p>
-    async fn update_script(
+    /// Presigns the request `update_script` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_update_script(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: UpdateScriptInput,
-    ) -> Result<UpdateScriptOutput, RusotoError<UpdateScriptError>> {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_update_script_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_update_script_request(&self, input: UpdateScriptInput) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "gamelift", &self.region, "/");
+        request.set_operation("UpdateScript");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "GameLift.UpdateScript");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
+        request
+    }
+
+    /// Calls `validate_matchmaking_rule_set`, also returning the metadata of the response.
+    pub async fn validate_matchmaking_rule_set_with_metadata(
+        &self,
+        input: ValidateMatchmakingRuleSetInput,
+    ) -> Result<
+        (ValidateMatchmakingRuleSetOutput, ResponseMetadata),
+        RusotoError<ValidateMatchmakingRuleSetError>,
+    > {
+        let request = self.build_validate_matchmaking_rule_set_request(input);
+
         let mut response = self
             .client
             .sign_and_dispatch(request)
             .await
             .map_err(RusotoError::from)?;
+        let metadata = ResponseMetadata::from_response(&response);
         if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response).deserialize::<UpdateScriptOutput, _>()
+            let response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await
+                .map_err(RusotoError::from)?;
+            let result = proto::json::ResponsePayload::new(&response)
+                .deserialize::<ValidateMatchmakingRuleSetOutput, _>()?;
+            Ok((result, metadata))
         } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(UpdateScriptError::from_response(response))
+            let try_response = response
+                .buffer_with_limit(self.client.max_buffered_body_size())
+                .await;
+            let response = try_response.map_err(RusotoError::from)?;
+            Err(ValidateMatchmakingRuleSetError::from_response(response))
         }
     }
 
-    /// <p><p>Validates the syntax of a matchmaking rule or rule set. This operation checks that the rule set is using syntactically correct JSON and that it conforms to allowed property expressions. To validate syntax, provide a rule set JSON string.</p> <p> <b>Learn more</b> </p> <ul> <li> <p> <a href="https://docs.aws.amazon.com/gamelift/latest/developerguide/match-rulesets.html">Build a Rule Set</a> </p> </li> </ul> <p> <b>Related operations</b> </p> <ul> <li> <p> <a>CreateMatchmakingConfiguration</a> </p> </li> <li> <p> <a>DescribeMatchmakingConfigurations</a> </p> </li> <li> <p> <a>UpdateMatchmakingConfiguration</a> </p> </li> <li> <p> <a>DeleteMatchmakingConfiguration</a> </p> </li> <li> <p> <a>CreateMatchmakingRuleSet</a> </p> </li> <li> <p> <a>DescribeMatchmakingRuleSets</a> </p> </li> <li> <p> <a>ValidateMatchmakingRuleSet</a> </p> </li> <li> <p> <a>DeleteMatchmakingRuleSet</a> </p> </li> </ul></p>
-    async fn validate_matchmaking_rule_set(
+    /// Presigns the request `validate_matchmaking_rule_set` would send for `input` with `presigner` instead of sending it.
+    pub async fn presign_validate_matchmaking_rule_set(
         &self,
+        presigner: &rusoto_core::Presigner,
         input: ValidateMatchmakingRuleSetInput,
-    ) -> Result<ValidateMatchmakingRuleSetOutput, RusotoError<ValidateMatchmakingRuleSetError>>
-    {
+    ) -> Result<rusoto_core::PresignedRequest, rusoto_core::PresignError> {
+        let request = self.build_validate_matchmaking_rule_set_request(input);
+        self.client.presign(presigner, request).await
+    }
+
+    fn build_validate_matchmaking_rule_set_request(
+        &self,
+        input: ValidateMatchmakingRuleSetInput,
+    ) -> SignedRequest {
         let mut request = SignedRequest::new("POST", "gamelift", &self.region, "/");
+        request.set_operation("ValidateMatchmakingRuleSet");
 
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-target", "GameLift.ValidateMatchmakingRuleSet");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
-        let mut response = self
-            .client
-            .sign_and_dispatch(request)
+        request
+    }
+
+    /// The pages of `DescribeFleetAttributes` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `Limit` of `input`.
+    pub fn describe_fleet_attributes_pages(
+        &self,
+        input: DescribeFleetAttributesInput,
+    ) -> impl futures::Stream<
+        Item = Result<DescribeFleetAttributesOutput, RusotoError<DescribeFleetAttributesError>>,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: GameLiftClient, input| async move { client.describe_fleet_attributes(input).await },
+            |input: &DescribeFleetAttributesInput, output: &DescribeFleetAttributesOutput| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(DescribeFleetAttributesInput {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `FleetAttributes` of every page of `DescribeFleetAttributes` results for `input`, as `describe_fleet_attributes_pages` requests them.
+    pub fn describe_fleet_attributes_stream(
+        &self,
+        input: DescribeFleetAttributesInput,
+    ) -> impl futures::Stream<
+        Item = Result<FleetAttributes, RusotoError<DescribeFleetAttributesError>>,
+    > + Send {
+        rusoto_core::pagination::items(self.describe_fleet_attributes_pages(input), |page| {
+            page.fleet_attributes.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `DescribeFleetCapacity` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `Limit` of `input`.
+    pub fn describe_fleet_capacity_pages(
+        &self,
+        input: DescribeFleetCapacityInput,
+    ) -> impl futures::Stream<
+        Item = Result<DescribeFleetCapacityOutput, RusotoError<DescribeFleetCapacityError>>,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: GameLiftClient, input| async move { client.describe_fleet_capacity(input).await },
+            |input: &DescribeFleetCapacityInput, output: &DescribeFleetCapacityOutput| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(DescribeFleetCapacityInput {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `FleetCapacity` of every page of `DescribeFleetCapacity` results for `input`, as `describe_fleet_capacity_pages` requests them.
+    pub fn describe_fleet_capacity_stream(
+        &self,
+        input: DescribeFleetCapacityInput,
+    ) -> impl futures::Stream<Item = Result<FleetCapacity, RusotoError<DescribeFleetCapacityError>>> + Send
+    {
+        rusoto_core::pagination::items(self.describe_fleet_capacity_pages(input), |page| {
+            page.fleet_capacity.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `DescribeFleetEvents` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `Limit` of `input`.
+    pub fn describe_fleet_events_pages(
+        &self,
+        input: DescribeFleetEventsInput,
+    ) -> impl futures::Stream<
+        Item = Result<DescribeFleetEventsOutput, RusotoError<DescribeFleetEventsError>>,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: GameLiftClient, input| async move { client.describe_fleet_events(input).await },
+            |input: &DescribeFleetEventsInput, output: &DescribeFleetEventsOutput| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(DescribeFleetEventsInput {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `Events` of every page of `DescribeFleetEvents` results for `input`, as `describe_fleet_events_pages` requests them.
+    pub fn describe_fleet_events_stream(
+        &self,
+        input: DescribeFleetEventsInput,
+    ) -> impl futures::Stream<Item = Result<Event, RusotoError<DescribeFleetEventsError>>> + Send
+    {
+        rusoto_core::pagination::items(self.describe_fleet_events_pages(input), |page| {
+            page.events.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `DescribeFleetUtilization` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `Limit` of `input`.
+    pub fn describe_fleet_utilization_pages(
+        &self,
+        input: DescribeFleetUtilizationInput,
+    ) -> impl futures::Stream<
+        Item = Result<DescribeFleetUtilizationOutput, RusotoError<DescribeFleetUtilizationError>>,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: GameLiftClient, input| async move {
+                client.describe_fleet_utilization(input).await
+            },
+            |input: &DescribeFleetUtilizationInput, output: &DescribeFleetUtilizationOutput| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(DescribeFleetUtilizationInput {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `FleetUtilization` of every page of `DescribeFleetUtilization` results for `input`, as `describe_fleet_utilization_pages` requests them.
+    pub fn describe_fleet_utilization_stream(
+        &self,
+        input: DescribeFleetUtilizationInput,
+    ) -> impl futures::Stream<
+        Item = Result<FleetUtilization, RusotoError<DescribeFleetUtilizationError>>,
+    > + Send {
+        rusoto_core::pagination::items(self.describe_fleet_utilization_pages(input), |page| {
+            page.fleet_utilization.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `DescribeGameSessionDetails` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `Limit` of `input`.
+    pub fn describe_game_session_details_pages(
+        &self,
+        input: DescribeGameSessionDetailsInput,
+    ) -> impl futures::Stream<
+        Item = Result<
+            DescribeGameSessionDetailsOutput,
+            RusotoError<DescribeGameSessionDetailsError>,
+        >,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: GameLiftClient, input| async move {
+                client.describe_game_session_details(input).await
+            },
+            |input: &DescribeGameSessionDetailsInput, output: &DescribeGameSessionDetailsOutput| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(DescribeGameSessionDetailsInput {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `GameSessionDetails` of every page of `DescribeGameSessionDetails` results for `input`, as `describe_game_session_details_pages` requests them.
+    pub fn describe_game_session_details_stream(
+        &self,
+        input: DescribeGameSessionDetailsInput,
+    ) -> impl futures::Stream<
+        Item = Result<GameSessionDetail, RusotoError<DescribeGameSessionDetailsError>>,
+    > + Send {
+        rusoto_core::pagination::items(self.describe_game_session_details_pages(input), |page| {
+            page.game_session_details.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `DescribeGameSessionQueues` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `Limit` of `input`.
+    pub fn describe_game_session_queues_pages(
+        &self,
+        input: DescribeGameSessionQueuesInput,
+    ) -> impl futures::Stream<
+        Item = Result<DescribeGameSessionQueuesOutput, RusotoError<DescribeGameSessionQueuesError>>,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: GameLiftClient, input| async move {
+                client.describe_game_session_queues(input).await
+            },
+            |input: &DescribeGameSessionQueuesInput, output: &DescribeGameSessionQueuesOutput| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(DescribeGameSessionQueuesInput {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `GameSessionQueues` of every page of `DescribeGameSessionQueues` results for `input`, as `describe_game_session_queues_pages` requests them.
+    pub fn describe_game_session_queues_stream(
+        &self,
+        input: DescribeGameSessionQueuesInput,
+    ) -> impl futures::Stream<
+        Item = Result<GameSessionQueue, RusotoError<DescribeGameSessionQueuesError>>,
+    > + Send {
+        rusoto_core::pagination::items(self.describe_game_session_queues_pages(input), |page| {
+            page.game_session_queues.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `DescribeGameSessions` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `Limit` of `input`.
+    pub fn describe_game_sessions_pages(
+        &self,
+        input: DescribeGameSessionsInput,
+    ) -> impl futures::Stream<
+        Item = Result<DescribeGameSessionsOutput, RusotoError<DescribeGameSessionsError>>,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: GameLiftClient, input| async move { client.describe_game_sessions(input).await },
+            |input: &DescribeGameSessionsInput, output: &DescribeGameSessionsOutput| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(DescribeGameSessionsInput {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `GameSessions` of every page of `DescribeGameSessions` results for `input`, as `describe_game_sessions_pages` requests them.
+    pub fn describe_game_sessions_stream(
+        &self,
+        input: DescribeGameSessionsInput,
+    ) -> impl futures::Stream<Item = Result<GameSession, RusotoError<DescribeGameSessionsError>>> + Send
+    {
+        rusoto_core::pagination::items(self.describe_game_sessions_pages(input), |page| {
+            page.game_sessions.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `DescribeInstances` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `Limit` of `input`.
+    pub fn describe_instances_pages(
+        &self,
+        input: DescribeInstancesInput,
+    ) -> impl futures::Stream<
+        Item = Result<DescribeInstancesOutput, RusotoError<DescribeInstancesError>>,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: GameLiftClient, input| async move { client.describe_instances(input).await },
+            |input: &DescribeInstancesInput, output: &DescribeInstancesOutput| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(DescribeInstancesInput {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `Instances` of every page of `DescribeInstances` results for `input`, as `describe_instances_pages` requests them.
+    pub fn describe_instances_stream(
+        &self,
+        input: DescribeInstancesInput,
+    ) -> impl futures::Stream<Item = Result<Instance, RusotoError<DescribeInstancesError>>> + Send
+    {
+        rusoto_core::pagination::items(self.describe_instances_pages(input), |page| {
+            page.instances.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `DescribeMatchmakingConfigurations` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `Limit` of `input`.
+    pub fn describe_matchmaking_configurations_pages(
+        &self,
+        input: DescribeMatchmakingConfigurationsInput,
+    ) -> impl futures::Stream<
+        Item = Result<
+            DescribeMatchmakingConfigurationsOutput,
+            RusotoError<DescribeMatchmakingConfigurationsError>,
+        >,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: GameLiftClient, input| async move {
+                client.describe_matchmaking_configurations(input).await
+            },
+            |input: &DescribeMatchmakingConfigurationsInput,
+             output: &DescribeMatchmakingConfigurationsOutput| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(DescribeMatchmakingConfigurationsInput {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `Configurations` of every page of `DescribeMatchmakingConfigurations` results for `input`, as `describe_matchmaking_configurations_pages` requests them.
+    pub fn describe_matchmaking_configurations_stream(
+        &self,
+        input: DescribeMatchmakingConfigurationsInput,
+    ) -> impl futures::Stream<
+        Item = Result<
+            MatchmakingConfiguration,
+            RusotoError<DescribeMatchmakingConfigurationsError>,
+        >,
+    > + Send {
+        rusoto_core::pagination::items(
+            self.describe_matchmaking_configurations_pages(input),
+            |page| page.configurations.unwrap_or_default(),
+        )
+    }
+
+    /// The pages of `DescribeMatchmakingRuleSets` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `Limit` of `input`.
+    pub fn describe_matchmaking_rule_sets_pages(
+        &self,
+        input: DescribeMatchmakingRuleSetsInput,
+    ) -> impl futures::Stream<
+        Item = Result<
+            DescribeMatchmakingRuleSetsOutput,
+            RusotoError<DescribeMatchmakingRuleSetsError>,
+        >,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: GameLiftClient, input| async move {
+                client.describe_matchmaking_rule_sets(input).await
+            },
+            |input: &DescribeMatchmakingRuleSetsInput,
+             output: &DescribeMatchmakingRuleSetsOutput| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(DescribeMatchmakingRuleSetsInput {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `RuleSets` of every page of `DescribeMatchmakingRuleSets` results for `input`, as `describe_matchmaking_rule_sets_pages` requests them.
+    pub fn describe_matchmaking_rule_sets_stream(
+        &self,
+        input: DescribeMatchmakingRuleSetsInput,
+    ) -> impl futures::Stream<
+        Item = Result<MatchmakingRuleSet, RusotoError<DescribeMatchmakingRuleSetsError>>,
+    > + Send {
+        rusoto_core::pagination::items(self.describe_matchmaking_rule_sets_pages(input), |page| {
+            page.rule_sets
+        })
+    }
+
+    /// The pages of `DescribePlayerSessions` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `Limit` of `input`.
+    pub fn describe_player_sessions_pages(
+        &self,
+        input: DescribePlayerSessionsInput,
+    ) -> impl futures::Stream<
+        Item = Result<DescribePlayerSessionsOutput, RusotoError<DescribePlayerSessionsError>>,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: GameLiftClient, input| async move { client.describe_player_sessions(input).await },
+            |input: &DescribePlayerSessionsInput, output: &DescribePlayerSessionsOutput| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(DescribePlayerSessionsInput {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `PlayerSessions` of every page of `DescribePlayerSessions` results for `input`, as `describe_player_sessions_pages` requests them.
+    pub fn describe_player_sessions_stream(
+        &self,
+        input: DescribePlayerSessionsInput,
+    ) -> impl futures::Stream<Item = Result<PlayerSession, RusotoError<DescribePlayerSessionsError>>>
+           + Send {
+        rusoto_core::pagination::items(self.describe_player_sessions_pages(input), |page| {
+            page.player_sessions.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `DescribeScalingPolicies` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `Limit` of `input`.
+    pub fn describe_scaling_policies_pages(
+        &self,
+        input: DescribeScalingPoliciesInput,
+    ) -> impl futures::Stream<
+        Item = Result<DescribeScalingPoliciesOutput, RusotoError<DescribeScalingPoliciesError>>,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: GameLiftClient, input| async move { client.describe_scaling_policies(input).await },
+            |input: &DescribeScalingPoliciesInput, output: &DescribeScalingPoliciesOutput| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(DescribeScalingPoliciesInput {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `ScalingPolicies` of every page of `DescribeScalingPolicies` results for `input`, as `describe_scaling_policies_pages` requests them.
+    pub fn describe_scaling_policies_stream(
+        &self,
+        input: DescribeScalingPoliciesInput,
+    ) -> impl futures::Stream<Item = Result<ScalingPolicy, RusotoError<DescribeScalingPoliciesError>>>
+           + Send {
+        rusoto_core::pagination::items(self.describe_scaling_policies_pages(input), |page| {
+            page.scaling_policies.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `ListAliases` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `Limit` of `input`.
+    pub fn list_aliases_pages(
+        &self,
+        input: ListAliasesInput,
+    ) -> impl futures::Stream<Item = Result<ListAliasesOutput, RusotoError<ListAliasesError>>> + Send
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: GameLiftClient, input| async move { client.list_aliases(input).await },
+            |input: &ListAliasesInput, output: &ListAliasesOutput| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListAliasesInput {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `Aliases` of every page of `ListAliases` results for `input`, as `list_aliases_pages` requests them.
+    pub fn list_aliases_stream(
+        &self,
+        input: ListAliasesInput,
+    ) -> impl futures::Stream<Item = Result<Alias, RusotoError<ListAliasesError>>> + Send {
+        rusoto_core::pagination::items(self.list_aliases_pages(input), |page| {
+            page.aliases.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `ListBuilds` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `Limit` of `input`.
+    pub fn list_builds_pages(
+        &self,
+        input: ListBuildsInput,
+    ) -> impl futures::Stream<Item = Result<ListBuildsOutput, RusotoError<ListBuildsError>>> + Send
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: GameLiftClient, input| async move { client.list_builds(input).await },
+            |input: &ListBuildsInput, output: &ListBuildsOutput| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListBuildsInput {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `Builds` of every page of `ListBuilds` results for `input`, as `list_builds_pages` requests them.
+    pub fn list_builds_stream(
+        &self,
+        input: ListBuildsInput,
+    ) -> impl futures::Stream<Item = Result<Build, RusotoError<ListBuildsError>>> + Send {
+        rusoto_core::pagination::items(self.list_builds_pages(input), |page| {
+            page.builds.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `ListFleets` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `Limit` of `input`.
+    pub fn list_fleets_pages(
+        &self,
+        input: ListFleetsInput,
+    ) -> impl futures::Stream<Item = Result<ListFleetsOutput, RusotoError<ListFleetsError>>> + Send
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: GameLiftClient, input| async move { client.list_fleets(input).await },
+            |input: &ListFleetsInput, output: &ListFleetsOutput| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListFleetsInput {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `FleetIds` of every page of `ListFleets` results for `input`, as `list_fleets_pages` requests them.
+    pub fn list_fleets_stream(
+        &self,
+        input: ListFleetsInput,
+    ) -> impl futures::Stream<Item = Result<String, RusotoError<ListFleetsError>>> + Send {
+        rusoto_core::pagination::items(self.list_fleets_pages(input), |page| {
+            page.fleet_ids.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `ListScripts` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `Limit` of `input`.
+    pub fn list_scripts_pages(
+        &self,
+        input: ListScriptsInput,
+    ) -> impl futures::Stream<Item = Result<ListScriptsOutput, RusotoError<ListScriptsError>>> + Send
+    {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: GameLiftClient, input| async move { client.list_scripts(input).await },
+            |input: &ListScriptsInput, output: &ListScriptsOutput| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(ListScriptsInput {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `Scripts` of every page of `ListScripts` results for `input`, as `list_scripts_pages` requests them.
+    pub fn list_scripts_stream(
+        &self,
+        input: ListScriptsInput,
+    ) -> impl futures::Stream<Item = Result<Script, RusotoError<ListScriptsError>>> + Send {
+        rusoto_core::pagination::items(self.list_scripts_pages(input), |page| {
+            page.scripts.unwrap_or_default()
+        })
+    }
+
+    /// The pages of `SearchGameSessions` results for `input`, requesting each next page with the `NextToken` of the last one as its `NextToken`.
+    /// The page size is the `Limit` of `input`.
+    pub fn search_game_sessions_pages(
+        &self,
+        input: SearchGameSessionsInput,
+    ) -> impl futures::Stream<
+        Item = Result<SearchGameSessionsOutput, RusotoError<SearchGameSessionsError>>,
+    > + Send {
+        rusoto_core::pagination::paginate(
+            self.clone(),
+            input,
+            |client: GameLiftClient, input| async move { client.search_game_sessions(input).await },
+            |input: &SearchGameSessionsInput, output: &SearchGameSessionsOutput| {
+                let next_token = output.next_token.clone();
+                // Stop when the service repeats the tokens, rather than request the same page forever
+                if next_token.is_none() || next_token == input.next_token {
+                    return None;
+                }
+                Some(SearchGameSessionsInput {
+                    next_token,
+                    ..input.clone()
+                })
+            },
+        )
+    }
+
+    /// The `GameSessions` of every page of `SearchGameSessions` results for `input`, as `search_game_sessions_pages` requests them.
+    pub fn search_game_sessions_stream(
+        &self,
+        input: SearchGameSessionsInput,
+    ) -> impl futures::Stream<Item = Result<GameSession, RusotoError<SearchGameSessionsError>>> + Send
+    {
+        rusoto_core::pagination::items(self.search_game_sessions_pages(input), |page| {
+            page.game_sessions.unwrap_or_default()
+        })
+    }
+}
+
+#[async_trait]
+impl GameLift for GameLiftClient {
+    /// <p><p>Registers a player&#39;s acceptance or rejection of a proposed FlexMatch match. A matchmaking configuration may require player acceptance; if so, then matches built with that configuration cannot be completed unless all players accept the proposed match within a specified time limit. </p> <p>When FlexMatch builds a match, all the matchmaking tickets involved in the proposed match are placed into status <code>REQUIRES_ACCEPTANCE</code>. This is a trigger for your game to get acceptance from all players in the ticket. Acceptances are only valid for tickets when they are in this status; all other acceptances result in an error.</p> <p>To register acceptance, specify the ticket ID, a response, and one or more players. Once all players have registered acceptance, the matchmaking tickets advance to status <code>PLACING</code>, where a new game session is created for the match. </p> <p>If any player rejects the match, or if acceptances are not received before a specified timeout, the proposed match is dropped. The matchmaking tickets are then handled in one of two ways: For tickets where one or more players rejected the match, the ticket status is returned to <code>SEARCHING</code> to find a new match. For tickets where one or more players failed to respond, the ticket status is set to <code>CANCELLED</code>, and processing is terminated. A new matchmaking request for these players can be submitted as needed. </p> <p> <b>Learn more</b> </p> <p> <a href="https://docs.aws.amazon.com/gamelift/latest/developerguide/match-client.html"> Add FlexMatch to a Game Client</a> </p> <p> <a href="https://docs.aws.amazon.com/gamelift/latest/developerguide/match-events.html"> FlexMatch Events Reference</a> </p> <p> <b>Related operations</b> </p> <ul> <li> <p> <a>StartMatchmaking</a> </p> </li> <li> <p> <a>DescribeMatchmaking</a> </p> </li> <li> <p> <a>StopMatchmaking</a> </p> </li> <li> <p> <a>AcceptMatch</a> </p> </li> <li> <p> <a>StartMatchBackfill</a> </p> </li> </ul></p>
+    async fn accept_match(
+        &self,
+        input: AcceptMatchInput,
+    ) -> Result<AcceptMatchOutput, RusotoError<AcceptMatchError>> {
+        self.accept_match_with_metadata(input)
             .await
-            .map_err(RusotoError::from)?;
-        if response.status.is_success() {
-            let response = response.buffer().await.map_err(RusotoError::HttpDispatch)?;
-            proto::json::ResponsePayload::new(&response)
-                .deserialize::<ValidateMatchmakingRuleSetOutput, _>()
-        } else {
-            let try_response = response.buffer().await;
-            let response = try_response.map_err(RusotoError::HttpDispatch)?;
-            Err(ValidateMatchmakingRuleSetError::from_response(response))
-        }
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Creates an alias for a fleet. In most situations, you can use an alias ID in place of a fleet ID. An alias provides a level of abstraction for a fleet that is useful when redirecting player traffic from one fleet to another, such as when updating your game build. </p> <p>Amazon GameLift supports two types of routing strategies for aliases: simple and terminal. A simple alias points to an active fleet. A terminal alias is used to display messaging or link to a URL instead of routing players to an active fleet. For example, you might use a terminal alias when a game version is no longer supported and you want to direct players to an upgrade site. </p> <p>To create a fleet alias, specify an alias name, routing strategy, and optional description. Each simple alias can point to only one fleet, but a fleet can have multiple aliases. If successful, a new alias record is returned, including an alias ID and an ARN. You can reassign an alias to another fleet by calling <code>UpdateAlias</code>.</p> <ul> <li> <p> <a>CreateAlias</a> </p> </li> <li> <p> <a>ListAliases</a> </p> </li> <li> <p> <a>DescribeAlias</a> </p> </li> <li> <p> <a>UpdateAlias</a> </p> </li> <li> <p> <a>DeleteAlias</a> </p> </li> <li> <p> <a>ResolveAlias</a> </p> </li> </ul></p>
+    async fn create_alias(
+        &self,
+        input: CreateAliasInput,
+    ) -> Result<CreateAliasOutput, RusotoError<CreateAliasError>> {
+        self.create_alias_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Creates a new Amazon GameLift build record for your game server binary files and points to the location of your game server build files in an Amazon Simple Storage Service (Amazon S3) location. </p> <p>Game server binaries must be combined into a zip file for use with Amazon GameLift. </p> <important> <p>To create new builds directly from a file directory, use the AWS CLI command <b> <a href="https://docs.aws.amazon.com/cli/latest/reference/gamelift/upload-build.html">upload-build</a> </b>. This helper command uploads build files and creates a new build record in one step, and automatically handles the necessary permissions. </p> </important> <p>The <code>CreateBuild</code> operation should be used only in the following scenarios:</p> <ul> <li> <p>To create a new game build with build files that are in an Amazon S3 bucket under your own AWS account. To use this option, you must first give Amazon GameLift access to that Amazon S3 bucket. Then call <code>CreateBuild</code> and specify a build name, operating system, and the Amazon S3 storage location of your game build.</p> </li> <li> <p>To upload build files directly to Amazon GameLift&#39;s Amazon S3 account. To use this option, first call <code>CreateBuild</code> and specify a build name and operating system. This action creates a new build record and returns an Amazon S3 storage location (bucket and key only) and temporary access credentials. Use the credentials to manually upload your build file to the provided storage location (see the Amazon S3 topic <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/UploadingObjects.html">Uploading Objects</a>). You can upload build files to the GameLift Amazon S3 location only once. </p> </li> </ul> <p>If successful, this operation creates a new build record with a unique build ID and places it in <code>INITIALIZED</code> status. You can use <a>DescribeBuild</a> to check the status of your build. A build must be in <code>READY</code> status before it can be used to create fleets.</p> <p> <b>Learn more</b> </p> <p> <a href="https://docs.aws.amazon.com/gamelift/latest/developerguide/gamelift-build-intro.html">Uploading Your Game</a> <a href="https://docs.aws.amazon.com/general/latest/gr/aws-arns-and-namespaces.html">https://docs.aws.amazon.com/general/latest/gr/aws-arns-and-namespaces.html</a> </p> <p> <a href="https://docs.aws.amazon.com/gamelift/latest/developerguide/gamelift-build-cli-uploading.html#gamelift-build-cli-uploading-create-build"> Create a Build with Files in Amazon S3</a> </p> <p> <b>Related operations</b> </p> <ul> <li> <p> <a>CreateBuild</a> </p> </li> <li> <p> <a>ListBuilds</a> </p> </li> <li> <p> <a>DescribeBuild</a> </p> </li> <li> <p> <a>UpdateBuild</a> </p> </li> <li> <p> <a>DeleteBuild</a> </p> </li> </ul></p>
+    async fn create_build(
+        &self,
+        input: CreateBuildInput,
+    ) -> Result<CreateBuildOutput, RusotoError<CreateBuildError>> {
+        self.create_build_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Creates a new fleet to run your game servers. whether they are custom game builds or Realtime Servers with game-specific script. A fleet is a set of Amazon Elastic Compute Cloud (Amazon EC2) instances, each of which can host multiple game sessions. When creating a fleet, you choose the hardware specifications, set some configuration options, and specify the game server to deploy on the new fleet. </p> <p>To create a new fleet, you must provide the following: (1) a fleet name, (2) an EC2 instance type and fleet type (spot or on-demand), (3) the build ID for your game build or script ID if using Realtime Servers, and (4) a runtime configuration, which determines how game servers will run on each instance in the fleet. </p> <p>If the <code>CreateFleet</code> call is successful, Amazon GameLift performs the following tasks. You can track the process of a fleet by checking the fleet status or by monitoring fleet creation events:</p> <ul> <li> <p>Creates a fleet record. Status: <code>NEW</code>.</p> </li> <li> <p>Begins writing events to the fleet event log, which can be accessed in the Amazon GameLift console.</p> </li> <li> <p>Sets the fleet&#39;s target capacity to 1 (desired instances), which triggers Amazon GameLift to start one new EC2 instance.</p> </li> <li> <p>Downloads the game build or Realtime script to the new instance and installs it. Statuses: <code>DOWNLOADING</code>, <code>VALIDATING</code>, <code>BUILDING</code>. </p> </li> <li> <p>Starts launching server processes on the instance. If the fleet is configured to run multiple server processes per instance, Amazon GameLift staggers each process launch by a few seconds. Status: <code>ACTIVATING</code>.</p> </li> <li> <p>Sets the fleet&#39;s status to <code>ACTIVE</code> as soon as one server process is ready to host a game session.</p> </li> </ul> <p> <b>Learn more</b> </p> <p> <a href="https://docs.aws.amazon.com/gamelift/latest/developerguide/fleets-intro.html"> Setting Up Fleets</a> </p> <p> <a href="https://docs.aws.amazon.com/gamelift/latest/developerguide/fleets-creating-debug.html#fleets-creating-debug-creation"> Debug Fleet Creation Issues</a> </p> <p> <b>Related operations</b> </p> <ul> <li> <p> <a>CreateFleet</a> </p> </li> <li> <p> <a>ListFleets</a> </p> </li> <li> <p> <a>DeleteFleet</a> </p> </li> <li> <p> <a>DescribeFleetAttributes</a> </p> </li> <li> <p> <a>UpdateFleetAttributes</a> </p> </li> <li> <p>Manage fleet actions:</p> <ul> <li> <p> <a>StartFleetActions</a> </p> </li> <li> <p> <a>StopFleetActions</a> </p> </li> </ul> </li> </ul></p>
+    async fn create_fleet(
+        &self,
+        input: CreateFleetInput,
+    ) -> Result<CreateFleetOutput, RusotoError<CreateFleetError>> {
+        self.create_fleet_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Creates a multiplayer game session for players. This action creates a game session record and assigns an available server process in the specified fleet to host the game session. A fleet must have an <code>ACTIVE</code> status before a game session can be created in it.</p> <p>To create a game session, specify either fleet ID or alias ID and indicate a maximum number of players to allow in the game session. You can also provide a name and game-specific properties for this game session. If successful, a <a>GameSession</a> object is returned containing the game session properties and other settings you specified.</p> <p> <b>Idempotency tokens.</b> You can add a token that uniquely identifies game session requests. This is useful for ensuring that game session requests are idempotent. Multiple requests with the same idempotency token are processed only once; subsequent requests return the original result. All response values are the same with the exception of game session status, which may change.</p> <p> <b>Resource creation limits.</b> If you are creating a game session on a fleet with a resource creation limit policy in force, then you must specify a creator ID. Without this ID, Amazon GameLift has no way to evaluate the policy for this new game session request.</p> <p> <b>Player acceptance policy.</b> By default, newly created game sessions are open to new players. You can restrict new player access by using <a>UpdateGameSession</a> to change the game session&#39;s player session creation policy.</p> <p> <b>Game session logs.</b> Logs are retained for all active game sessions for 14 days. To access the logs, call <a>GetGameSessionLogUrl</a> to download the log files.</p> <p> <i>Available in Amazon GameLift Local.</i> </p> <ul> <li> <p> <a>CreateGameSession</a> </p> </li> <li> <p> <a>DescribeGameSessions</a> </p> </li> <li> <p> <a>DescribeGameSessionDetails</a> </p> </li> <li> <p> <a>SearchGameSessions</a> </p> </li> <li> <p> <a>UpdateGameSession</a> </p> </li> <li> <p> <a>GetGameSessionLogUrl</a> </p> </li> <li> <p>Game session placements</p> <ul> <li> <p> <a>StartGameSessionPlacement</a> </p> </li> <li> <p> <a>DescribeGameSessionPlacement</a> </p> </li> <li> <p> <a>StopGameSessionPlacement</a> </p> </li> </ul> </li> </ul></p>
+    async fn create_game_session(
+        &self,
+        input: CreateGameSessionInput,
+    ) -> Result<CreateGameSessionOutput, RusotoError<CreateGameSessionError>> {
+        self.create_game_session_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Establishes a new queue for processing requests to place new game sessions. A queue identifies where new game sessions can be hosted -- by specifying a list of destinations (fleets or aliases) -- and how long requests can wait in the queue before timing out. You can set up a queue to try to place game sessions on fleets in multiple Regions. To add placement requests to a queue, call <a>StartGameSessionPlacement</a> and reference the queue name.</p> <p> <b>Destination order.</b> When processing a request for a game session, Amazon GameLift tries each destination in order until it finds one with available resources to host the new game session. A queue&#39;s default order is determined by how destinations are listed. The default order is overridden when a game session placement request provides player latency information. Player latency information enables Amazon GameLift to prioritize destinations where players report the lowest average latency, as a result placing the new game session where the majority of players will have the best possible gameplay experience.</p> <p> <b>Player latency policies.</b> For placement requests containing player latency information, use player latency policies to protect individual players from very high latencies. With a latency cap, even when a destination can deliver a low latency for most players, the game is not placed where any individual player is reporting latency higher than a policy&#39;s maximum. A queue can have multiple latency policies, which are enforced consecutively starting with the policy with the lowest latency cap. Use multiple policies to gradually relax latency controls; for example, you might set a policy with a low latency cap for the first 60 seconds, a second policy with a higher cap for the next 60 seconds, etc. </p> <p>To create a new queue, provide a name, timeout value, a list of destinations and, if desired, a set of latency policies. If successful, a new queue object is returned.</p> <ul> <li> <p> <a>CreateGameSessionQueue</a> </p> </li> <li> <p> <a>DescribeGameSessionQueues</a> </p> </li> <li> <p> <a>UpdateGameSessionQueue</a> </p> </li> <li> <p> <a>DeleteGameSessionQueue</a> </p> </li> </ul></p>
+    async fn create_game_session_queue(
+        &self,
+        input: CreateGameSessionQueueInput,
+    ) -> Result<CreateGameSessionQueueOutput, RusotoError<CreateGameSessionQueueError>> {
+        self.create_game_session_queue_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Defines a new matchmaking configuration for use with FlexMatch. A matchmaking configuration sets out guidelines for matching players and getting the matches into games. You can set up multiple matchmaking configurations to handle the scenarios needed for your game. Each matchmaking ticket (<a>StartMatchmaking</a> or <a>StartMatchBackfill</a>) specifies a configuration for the match and provides player attributes to support the configuration being used. </p> <p>To create a matchmaking configuration, at a minimum you must specify the following: configuration name; a rule set that governs how to evaluate players and find acceptable matches; a game session queue to use when placing a new game session for the match; and the maximum time allowed for a matchmaking attempt.</p> <p>There are two ways to track the progress of matchmaking tickets: (1) polling ticket status with <a>DescribeMatchmaking</a>; or (2) receiving notifications with Amazon Simple Notification Service (SNS). To use notifications, you first need to set up an SNS topic to receive the notifications, and provide the topic ARN in the matchmaking configuration. Since notifications promise only &quot;best effort&quot; delivery, we recommend calling <code>DescribeMatchmaking</code> if no notifications are received within 30 seconds.</p> <p> <b>Learn more</b> </p> <p> <a href="https://docs.aws.amazon.com/gamelift/latest/developerguide/match-configuration.html"> Design a FlexMatch Matchmaker</a> </p> <p> <a href="https://docs.aws.amazon.com/gamelift/latest/developerguide/match-notification.html"> Setting up Notifications for Matchmaking</a> </p> <p> <b>Related operations</b> </p> <ul> <li> <p> <a>CreateMatchmakingConfiguration</a> </p> </li> <li> <p> <a>DescribeMatchmakingConfigurations</a> </p> </li> <li> <p> <a>UpdateMatchmakingConfiguration</a> </p> </li> <li> <p> <a>DeleteMatchmakingConfiguration</a> </p> </li> <li> <p> <a>CreateMatchmakingRuleSet</a> </p> </li> <li> <p> <a>DescribeMatchmakingRuleSets</a> </p> </li> <li> <p> <a>ValidateMatchmakingRuleSet</a> </p> </li> <li> <p> <a>DeleteMatchmakingRuleSet</a> </p> </li> </ul></p>
+    async fn create_matchmaking_configuration(
+        &self,
+        input: CreateMatchmakingConfigurationInput,
+    ) -> Result<
+        CreateMatchmakingConfigurationOutput,
+        RusotoError<CreateMatchmakingConfigurationError>,
+    > {
+        self.create_matchmaking_configuration_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Creates a new rule set for FlexMatch matchmaking. A rule set describes the type of match to create, such as the number and size of teams. It also sets the parameters for acceptable player matches, such as minimum skill level or character type. A rule set is used by a <a>MatchmakingConfiguration</a>. </p> <p>To create a matchmaking rule set, provide unique rule set name and the rule set body in JSON format. Rule sets must be defined in the same Region as the matchmaking configuration they are used with.</p> <p>Since matchmaking rule sets cannot be edited, it is a good idea to check the rule set syntax using <a>ValidateMatchmakingRuleSet</a> before creating a new rule set.</p> <p> <b>Learn more</b> </p> <ul> <li> <p> <a href="https://docs.aws.amazon.com/gamelift/latest/developerguide/match-rulesets.html">Build a Rule Set</a> </p> </li> <li> <p> <a href="https://docs.aws.amazon.com/gamelift/latest/developerguide/match-configuration.html">Design a Matchmaker</a> </p> </li> <li> <p> <a href="https://docs.aws.amazon.com/gamelift/latest/developerguide/match-intro.html">Matchmaking with FlexMatch</a> </p> </li> </ul> <p> <b>Related operations</b> </p> <ul> <li> <p> <a>CreateMatchmakingConfiguration</a> </p> </li> <li> <p> <a>DescribeMatchmakingConfigurations</a> </p> </li> <li> <p> <a>UpdateMatchmakingConfiguration</a> </p> </li> <li> <p> <a>DeleteMatchmakingConfiguration</a> </p> </li> <li> <p> <a>CreateMatchmakingRuleSet</a> </p> </li> <li> <p> <a>DescribeMatchmakingRuleSets</a> </p> </li> <li> <p> <a>ValidateMatchmakingRuleSet</a> </p> </li> <li> <p> <a>DeleteMatchmakingRuleSet</a> </p> </li> </ul></p>
+    async fn create_matchmaking_rule_set(
+        &self,
+        input: CreateMatchmakingRuleSetInput,
+    ) -> Result<CreateMatchmakingRuleSetOutput, RusotoError<CreateMatchmakingRuleSetError>> {
+        self.create_matchmaking_rule_set_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Reserves an open player slot in an active game session. Before a player can be added, a game session must have an <code>ACTIVE</code> status, have a creation policy of <code>ALLOW_ALL</code>, and have an open player slot. To add a group of players to a game session, use <a>CreatePlayerSessions</a>. When the player connects to the game server and references a player session ID, the game server contacts the Amazon GameLift service to validate the player reservation and accept the player.</p> <p>To create a player session, specify a game session ID, player ID, and optionally a string of player data. If successful, a slot is reserved in the game session for the player and a new <a>PlayerSession</a> object is returned. Player sessions cannot be updated. </p> <p> <i>Available in Amazon GameLift Local.</i> </p> <ul> <li> <p> <a>CreatePlayerSession</a> </p> </li> <li> <p> <a>CreatePlayerSessions</a> </p> </li> <li> <p> <a>DescribePlayerSessions</a> </p> </li> <li> <p>Game session placements</p> <ul> <li> <p> <a>StartGameSessionPlacement</a> </p> </li> <li> <p> <a>DescribeGameSessionPlacement</a> </p> </li> <li> <p> <a>StopGameSessionPlacement</a> </p> </li> </ul> </li> </ul></p>
+    async fn create_player_session(
+        &self,
+        input: CreatePlayerSessionInput,
+    ) -> Result<CreatePlayerSessionOutput, RusotoError<CreatePlayerSessionError>> {
+        self.create_player_session_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Reserves open slots in a game session for a group of players. Before players can be added, a game session must have an <code>ACTIVE</code> status, have a creation policy of <code>ALLOW_ALL</code>, and have an open player slot. To add a single player to a game session, use <a>CreatePlayerSession</a>. When a player connects to the game server and references a player session ID, the game server contacts the Amazon GameLift service to validate the player reservation and accept the player.</p> <p>To create player sessions, specify a game session ID, a list of player IDs, and optionally a set of player data strings. If successful, a slot is reserved in the game session for each player and a set of new <a>PlayerSession</a> objects is returned. Player sessions cannot be updated.</p> <p> <i>Available in Amazon GameLift Local.</i> </p> <ul> <li> <p> <a>CreatePlayerSession</a> </p> </li> <li> <p> <a>CreatePlayerSessions</a> </p> </li> <li> <p> <a>DescribePlayerSessions</a> </p> </li> <li> <p>Game session placements</p> <ul> <li> <p> <a>StartGameSessionPlacement</a> </p> </li> <li> <p> <a>DescribeGameSessionPlacement</a> </p> </li> <li> <p> <a>StopGameSessionPlacement</a> </p> </li> </ul> </li> </ul></p>
+    async fn create_player_sessions(
+        &self,
+        input: CreatePlayerSessionsInput,
+    ) -> Result<CreatePlayerSessionsOutput, RusotoError<CreatePlayerSessionsError>> {
+        self.create_player_sessions_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Creates a new script record for your Realtime Servers script. Realtime scripts are JavaScript that provide configuration settings and optional custom game logic for your game. The script is deployed when you create a Realtime Servers fleet to host your game sessions. Script logic is executed during an active game session. </p> <p>To create a new script record, specify a script name and provide the script file(s). The script files and all dependencies must be zipped into a single file. You can pull the zip file from either of these locations: </p> <ul> <li> <p>A locally available directory. Use the <i>ZipFile</i> parameter for this option.</p> </li> <li> <p>An Amazon Simple Storage Service (Amazon S3) bucket under your AWS account. Use the <i>StorageLocation</i> parameter for this option. You&#39;ll need to have an Identity Access Management (IAM) role that allows the Amazon GameLift service to access your S3 bucket. </p> </li> </ul> <p>If the call is successful, a new script record is created with a unique script ID. If the script file is provided as a local file, the file is uploaded to an Amazon GameLift-owned S3 bucket and the script record&#39;s storage location reflects this location. If the script file is provided as an S3 bucket, Amazon GameLift accesses the file at this storage location as needed for deployment.</p> <p> <b>Learn more</b> </p> <p> <a href="https://docs.aws.amazon.com/gamelift/latest/developerguide/realtime-intro.html">Amazon GameLift Realtime Servers</a> </p> <p> <a href="https://docs.aws.amazon.com/gamelift/latest/developerguide/setting-up-role.html">Set Up a Role for Amazon GameLift Access</a> </p> <p> <b>Related operations</b> </p> <ul> <li> <p> <a>CreateScript</a> </p> </li> <li> <p> <a>ListScripts</a> </p> </li> <li> <p> <a>DescribeScript</a> </p> </li> <li> <p> <a>UpdateScript</a> </p> </li> <li> <p> <a>DeleteScript</a> </p> </li> </ul></p>
+    async fn create_script(
+        &self,
+        input: CreateScriptInput,
+    ) -> Result<CreateScriptOutput, RusotoError<CreateScriptError>> {
+        self.create_script_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Requests authorization to create or delete a peer connection between the VPC for your Amazon GameLift fleet and a virtual private cloud (VPC) in your AWS account. VPC peering enables the game servers on your fleet to communicate directly with other AWS resources. Once you&#39;ve received authorization, call <a>CreateVpcPeeringConnection</a> to establish the peering connection. For more information, see <a href="https://docs.aws.amazon.com/gamelift/latest/developerguide/vpc-peering.html">VPC Peering with Amazon GameLift Fleets</a>.</p> <p>You can peer with VPCs that are owned by any AWS account you have access to, including the account that you use to manage your Amazon GameLift fleets. You cannot peer with VPCs that are in different Regions.</p> <p>To request authorization to create a connection, call this operation from the AWS account with the VPC that you want to peer to your Amazon GameLift fleet. For example, to enable your game servers to retrieve data from a DynamoDB table, use the account that manages that DynamoDB resource. Identify the following values: (1) The ID of the VPC that you want to peer with, and (2) the ID of the AWS account that you use to manage Amazon GameLift. If successful, VPC peering is authorized for the specified VPC. </p> <p>To request authorization to delete a connection, call this operation from the AWS account with the VPC that is peered with your Amazon GameLift fleet. Identify the following values: (1) VPC ID that you want to delete the peering connection for, and (2) ID of the AWS account that you use to manage Amazon GameLift. </p> <p>The authorization remains valid for 24 hours unless it is canceled by a call to <a>DeleteVpcPeeringAuthorization</a>. You must create or delete the peering connection while the authorization is valid. </p> <ul> <li> <p> <a>CreateVpcPeeringAuthorization</a> </p> </li> <li> <p> <a>DescribeVpcPeeringAuthorizations</a> </p> </li> <li> <p> <a>DeleteVpcPeeringAuthorization</a> </p> </li> <li> <p> <a>CreateVpcPeeringConnection</a> </p> </li> <li> <p> <a>DescribeVpcPeeringConnections</a> </p> </li> <li> <p> <a>DeleteVpcPeeringConnection</a> </p> </li> </ul></p>
+    async fn create_vpc_peering_authorization(
+        &self,
+        input: CreateVpcPeeringAuthorizationInput,
+    ) -> Result<CreateVpcPeeringAuthorizationOutput, RusotoError<CreateVpcPeeringAuthorizationError>>
+    {
+        self.create_vpc_peering_authorization_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Establishes a VPC peering connection between a virtual private cloud (VPC) in an AWS account with the VPC for your Amazon GameLift fleet. VPC peering enables the game servers on your fleet to communicate directly with other AWS resources. You can peer with VPCs in any AWS account that you have access to, including the account that you use to manage your Amazon GameLift fleets. You cannot peer with VPCs that are in different Regions. For more information, see <a href="https://docs.aws.amazon.com/gamelift/latest/developerguide/vpc-peering.html">VPC Peering with Amazon GameLift Fleets</a>.</p> <p>Before calling this operation to establish the peering connection, you first need to call <a>CreateVpcPeeringAuthorization</a> and identify the VPC you want to peer with. Once the authorization for the specified VPC is issued, you have 24 hours to establish the connection. These two operations handle all tasks necessary to peer the two VPCs, including acceptance, updating routing tables, etc. </p> <p>To establish the connection, call this operation from the AWS account that is used to manage the Amazon GameLift fleets. Identify the following values: (1) The ID of the fleet you want to be enable a VPC peering connection for; (2) The AWS account with the VPC that you want to peer with; and (3) The ID of the VPC you want to peer with. This operation is asynchronous. If successful, a <a>VpcPeeringConnection</a> request is created. You can use continuous polling to track the request&#39;s status using <a>DescribeVpcPeeringConnections</a>, or by monitoring fleet events for success or failure using <a>DescribeFleetEvents</a>. </p> <ul> <li> <p> <a>CreateVpcPeeringAuthorization</a> </p> </li> <li> <p> <a>DescribeVpcPeeringAuthorizations</a> </p> </li> <li> <p> <a>DeleteVpcPeeringAuthorization</a> </p> </li> <li> <p> <a>CreateVpcPeeringConnection</a> </p> </li> <li> <p> <a>DescribeVpcPeeringConnections</a> </p> </li> <li> <p> <a>DeleteVpcPeeringConnection</a> </p> </li> </ul></p>
+    async fn create_vpc_peering_connection(
+        &self,
+        input: CreateVpcPeeringConnectionInput,
+    ) -> Result<CreateVpcPeeringConnectionOutput, RusotoError<CreateVpcPeeringConnectionError>>
+    {
+        self.create_vpc_peering_connection_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Deletes an alias. This action removes all record of the alias. Game clients attempting to access a server process using the deleted alias receive an error. To delete an alias, specify the alias ID to be deleted.</p> <ul> <li> <p> <a>CreateAlias</a> </p> </li> <li> <p> <a>ListAliases</a> </p> </li> <li> <p> <a>DescribeAlias</a> </p> </li> <li> <p> <a>UpdateAlias</a> </p> </li> <li> <p> <a>DeleteAlias</a> </p> </li> <li> <p> <a>ResolveAlias</a> </p> </li> </ul></p>
+    async fn delete_alias(
+        &self,
+        input: DeleteAliasInput,
+    ) -> Result<(), RusotoError<DeleteAliasError>> {
+        self.delete_alias_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Deletes a build. This action permanently deletes the build record and any uploaded build files.</p> <p>To delete a build, specify its ID. Deleting a build does not affect the status of any active fleets using the build, but you can no longer create new fleets with the deleted build.</p> <p> <b>Learn more</b> </p> <p> <a href="https://docs.aws.amazon.com/gamelift/latest/developerguide/build-intro.html"> Working with Builds</a> </p> <p> <b>Related operations</b> </p> <ul> <li> <p> <a>CreateBuild</a> </p> </li> <li> <p> <a>ListBuilds</a> </p> </li> <li> <p> <a>DescribeBuild</a> </p> </li> <li> <p> <a>UpdateBuild</a> </p> </li> <li> <p> <a>DeleteBuild</a> </p> </li> </ul></p>
+    async fn delete_build(
+        &self,
+        input: DeleteBuildInput,
+    ) -> Result<(), RusotoError<DeleteBuildError>> {
+        self.delete_build_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Deletes everything related to a fleet. Before deleting a fleet, you must set the fleet&#39;s desired capacity to zero. See <a>UpdateFleetCapacity</a>.</p> <p>If the fleet being deleted has a VPC peering connection, you first need to get a valid authorization (good for 24 hours) by calling <a>CreateVpcPeeringAuthorization</a>. You do not need to explicitly delete the VPC peering connection--this is done as part of the delete fleet process.</p> <p>This action removes the fleet&#39;s resources and the fleet record. Once a fleet is deleted, you can no longer use that fleet.</p> <p> <b>Learn more</b> </p> <p> <a href="https://docs.aws.amazon.com/gamelift/latest/developerguide/fleets-intro.html"> Working with Fleets</a>.</p> <p> <b>Related operations</b> </p> <ul> <li> <p> <a>CreateFleet</a> </p> </li> <li> <p> <a>ListFleets</a> </p> </li> <li> <p> <a>DeleteFleet</a> </p> </li> <li> <p> <a>DescribeFleetAttributes</a> </p> </li> <li> <p> <a>UpdateFleetAttributes</a> </p> </li> <li> <p>Manage fleet actions:</p> <ul> <li> <p> <a>StartFleetActions</a> </p> </li> <li> <p> <a>StopFleetActions</a> </p> </li> </ul> </li> </ul></p>
+    async fn delete_fleet(
+        &self,
+        input: DeleteFleetInput,
+    ) -> Result<(), RusotoError<DeleteFleetError>> {
+        self.delete_fleet_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Deletes a game session queue. This action means that any <a>StartGameSessionPlacement</a> requests that reference this queue will fail. To delete a queue, specify the queue name.</p> <ul> <li> <p> <a>CreateGameSessionQueue</a> </p> </li> <li> <p> <a>DescribeGameSessionQueues</a> </p> </li> <li> <p> <a>UpdateGameSessionQueue</a> </p> </li> <li> <p> <a>DeleteGameSessionQueue</a> </p> </li> </ul></p>
+    async fn delete_game_session_queue(
+        &self,
+        input: DeleteGameSessionQueueInput,
+    ) -> Result<DeleteGameSessionQueueOutput, RusotoError<DeleteGameSessionQueueError>> {
+        self.delete_game_session_queue_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Permanently removes a FlexMatch matchmaking configuration. To delete, specify the configuration name. A matchmaking configuration cannot be deleted if it is being used in any active matchmaking tickets.</p> <p> <b>Related operations</b> </p> <ul> <li> <p> <a>CreateMatchmakingConfiguration</a> </p> </li> <li> <p> <a>DescribeMatchmakingConfigurations</a> </p> </li> <li> <p> <a>UpdateMatchmakingConfiguration</a> </p> </li> <li> <p> <a>DeleteMatchmakingConfiguration</a> </p> </li> <li> <p> <a>CreateMatchmakingRuleSet</a> </p> </li> <li> <p> <a>DescribeMatchmakingRuleSets</a> </p> </li> <li> <p> <a>ValidateMatchmakingRuleSet</a> </p> </li> <li> <p> <a>DeleteMatchmakingRuleSet</a> </p> </li> </ul></p>
+    async fn delete_matchmaking_configuration(
+        &self,
+        input: DeleteMatchmakingConfigurationInput,
+    ) -> Result<
+        DeleteMatchmakingConfigurationOutput,
+        RusotoError<DeleteMatchmakingConfigurationError>,
+    > {
+        self.delete_matchmaking_configuration_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Deletes an existing matchmaking rule set. To delete the rule set, provide the rule set name. Rule sets cannot be deleted if they are currently being used by a matchmaking configuration. </p> <p> <b>Learn more</b> </p> <ul> <li> <p> <a href="https://docs.aws.amazon.com/gamelift/latest/developerguide/match-rulesets.html">Build a Rule Set</a> </p> </li> </ul> <p> <b>Related operations</b> </p> <ul> <li> <p> <a>CreateMatchmakingConfiguration</a> </p> </li> <li> <p> <a>DescribeMatchmakingConfigurations</a> </p> </li> <li> <p> <a>UpdateMatchmakingConfiguration</a> </p> </li> <li> <p> <a>DeleteMatchmakingConfiguration</a> </p> </li> <li> <p> <a>CreateMatchmakingRuleSet</a> </p> </li> <li> <p> <a>DescribeMatchmakingRuleSets</a> </p> </li> <li> <p> <a>ValidateMatchmakingRuleSet</a> </p> </li> <li> <p> <a>DeleteMatchmakingRuleSet</a> </p> </li> </ul></p>
+    async fn delete_matchmaking_rule_set(
+        &self,
+        input: DeleteMatchmakingRuleSetInput,
+    ) -> Result<DeleteMatchmakingRuleSetOutput, RusotoError<DeleteMatchmakingRuleSetError>> {
+        self.delete_matchmaking_rule_set_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Deletes a fleet scaling policy. This action means that the policy is no longer in force and removes all record of it. To delete a scaling policy, specify both the scaling policy name and the fleet ID it is associated with.</p> <p>To temporarily suspend scaling policies, call <a>StopFleetActions</a>. This operation suspends all policies for the fleet.</p> <ul> <li> <p> <a>DescribeFleetCapacity</a> </p> </li> <li> <p> <a>UpdateFleetCapacity</a> </p> </li> <li> <p> <a>DescribeEC2InstanceLimits</a> </p> </li> <li> <p>Manage scaling policies:</p> <ul> <li> <p> <a>PutScalingPolicy</a> (auto-scaling)</p> </li> <li> <p> <a>DescribeScalingPolicies</a> (auto-scaling)</p> </li> <li> <p> <a>DeleteScalingPolicy</a> (auto-scaling)</p> </li> </ul> </li> <li> <p>Manage fleet actions:</p> <ul> <li> <p> <a>StartFleetActions</a> </p> </li> <li> <p> <a>StopFleetActions</a> </p> </li> </ul> </li> </ul></p>
+    async fn delete_scaling_policy(
+        &self,
+        input: DeleteScalingPolicyInput,
+    ) -> Result<(), RusotoError<DeleteScalingPolicyError>> {
+        self.delete_scaling_policy_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Deletes a Realtime script. This action permanently deletes the script record. If script files were uploaded, they are also deleted (files stored in an S3 bucket are not deleted). </p> <p>To delete a script, specify the script ID. Before deleting a script, be sure to terminate all fleets that are deployed with the script being deleted. Fleet instances periodically check for script updates, and if the script record no longer exists, the instance will go into an error state and be unable to host game sessions.</p> <p> <b>Learn more</b> </p> <p> <a href="https://docs.aws.amazon.com/gamelift/latest/developerguide/realtime-intro.html">Amazon GameLift Realtime Servers</a> </p> <p> <b>Related operations</b> </p> <ul> <li> <p> <a>CreateScript</a> </p> </li> <li> <p> <a>ListScripts</a> </p> </li> <li> <p> <a>DescribeScript</a> </p> </li> <li> <p> <a>UpdateScript</a> </p> </li> <li> <p> <a>DeleteScript</a> </p> </li> </ul></p>
+    async fn delete_script(
+        &self,
+        input: DeleteScriptInput,
+    ) -> Result<(), RusotoError<DeleteScriptError>> {
+        self.delete_script_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Cancels a pending VPC peering authorization for the specified VPC. If you need to delete an existing VPC peering connection, call <a>DeleteVpcPeeringConnection</a>. </p> <ul> <li> <p> <a>CreateVpcPeeringAuthorization</a> </p> </li> <li> <p> <a>DescribeVpcPeeringAuthorizations</a> </p> </li> <li> <p> <a>DeleteVpcPeeringAuthorization</a> </p> </li> <li> <p> <a>CreateVpcPeeringConnection</a> </p> </li> <li> <p> <a>DescribeVpcPeeringConnections</a> </p> </li> <li> <p> <a>DeleteVpcPeeringConnection</a> </p> </li> </ul></p>
+    async fn delete_vpc_peering_authorization(
+        &self,
+        input: DeleteVpcPeeringAuthorizationInput,
+    ) -> Result<DeleteVpcPeeringAuthorizationOutput, RusotoError<DeleteVpcPeeringAuthorizationError>>
+    {
+        self.delete_vpc_peering_authorization_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Removes a VPC peering connection. To delete the connection, you must have a valid authorization for the VPC peering connection that you want to delete. You can check for an authorization by calling <a>DescribeVpcPeeringAuthorizations</a> or request a new one using <a>CreateVpcPeeringAuthorization</a>. </p> <p>Once a valid authorization exists, call this operation from the AWS account that is used to manage the Amazon GameLift fleets. Identify the connection to delete by the connection ID and fleet ID. If successful, the connection is removed. </p> <ul> <li> <p> <a>CreateVpcPeeringAuthorization</a> </p> </li> <li> <p> <a>DescribeVpcPeeringAuthorizations</a> </p> </li> <li> <p> <a>DeleteVpcPeeringAuthorization</a> </p> </li> <li> <p> <a>CreateVpcPeeringConnection</a> </p> </li> <li> <p> <a>DescribeVpcPeeringConnections</a> </p> </li> <li> <p> <a>DeleteVpcPeeringConnection</a> </p> </li> </ul></p>
+    async fn delete_vpc_peering_connection(
+        &self,
+        input: DeleteVpcPeeringConnectionInput,
+    ) -> Result<DeleteVpcPeeringConnectionOutput, RusotoError<DeleteVpcPeeringConnectionError>>
+    {
+        self.delete_vpc_peering_connection_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Retrieves properties for an alias. This operation returns all alias metadata and settings. To get an alias&#39;s target fleet ID only, use <code>ResolveAlias</code>. </p> <p>To get alias properties, specify the alias ID. If successful, the requested alias record is returned.</p> <ul> <li> <p> <a>CreateAlias</a> </p> </li> <li> <p> <a>ListAliases</a> </p> </li> <li> <p> <a>DescribeAlias</a> </p> </li> <li> <p> <a>UpdateAlias</a> </p> </li> <li> <p> <a>DeleteAlias</a> </p> </li> <li> <p> <a>ResolveAlias</a> </p> </li> </ul></p>
+    async fn describe_alias(
+        &self,
+        input: DescribeAliasInput,
+    ) -> Result<DescribeAliasOutput, RusotoError<DescribeAliasError>> {
+        self.describe_alias_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Retrieves properties for a build. To request a build record, specify a build ID. If successful, an object containing the build properties is returned.</p> <p> <b>Learn more</b> </p> <p> <a href="https://docs.aws.amazon.com/gamelift/latest/developerguide/build-intro.html"> Working with Builds</a> </p> <p> <b>Related operations</b> </p> <ul> <li> <p> <a>CreateBuild</a> </p> </li> <li> <p> <a>ListBuilds</a> </p> </li> <li> <p> <a>DescribeBuild</a> </p> </li> <li> <p> <a>UpdateBuild</a> </p> </li> <li> <p> <a>DeleteBuild</a> </p> </li> </ul></p>
+    async fn describe_build(
+        &self,
+        input: DescribeBuildInput,
+    ) -> Result<DescribeBuildOutput, RusotoError<DescribeBuildError>> {
+        self.describe_build_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Retrieves the following information for the specified EC2 instance type:</p> <ul> <li> <p>maximum number of instances allowed per AWS account (service limit)</p> </li> <li> <p>current usage level for the AWS account</p> </li> </ul> <p>Service limits vary depending on Region. Available Regions for Amazon GameLift can be found in the AWS Management Console for Amazon GameLift (see the drop-down list in the upper right corner).</p> <p> <b>Learn more</b> </p> <p> <a href="https://docs.aws.amazon.com/gamelift/latest/developerguide/fleets-intro.html"> Working with Fleets</a>.</p> <p> <b>Related operations</b> </p> <ul> <li> <p> <a>CreateFleet</a> </p> </li> <li> <p> <a>ListFleets</a> </p> </li> <li> <p> <a>DeleteFleet</a> </p> </li> <li> <p>Describe fleets:</p> <ul> <li> <p> <a>DescribeFleetAttributes</a> </p> </li> <li> <p> <a>DescribeFleetCapacity</a> </p> </li> <li> <p> <a>DescribeFleetPortSettings</a> </p> </li> <li> <p> <a>DescribeFleetUtilization</a> </p> </li> <li> <p> <a>DescribeRuntimeConfiguration</a> </p> </li> <li> <p> <a>DescribeEC2InstanceLimits</a> </p> </li> <li> <p> <a>DescribeFleetEvents</a> </p> </li> </ul> </li> <li> <p>Update fleets:</p> <ul> <li> <p> <a>UpdateFleetAttributes</a> </p> </li> <li> <p> <a>UpdateFleetCapacity</a> </p> </li> <li> <p> <a>UpdateFleetPortSettings</a> </p> </li> <li> <p> <a>UpdateRuntimeConfiguration</a> </p> </li> </ul> </li> <li> <p>Manage fleet actions:</p> <ul> <li> <p> <a>StartFleetActions</a> </p> </li> <li> <p> <a>StopFleetActions</a> </p> </li> </ul> </li> </ul></p>
+    async fn describe_ec2_instance_limits(
+        &self,
+        input: DescribeEC2InstanceLimitsInput,
+    ) -> Result<DescribeEC2InstanceLimitsOutput, RusotoError<DescribeEC2InstanceLimitsError>> {
+        self.describe_ec2_instance_limits_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Retrieves fleet properties, including metadata, status, and configuration, for one or more fleets. You can request attributes for all fleets, or specify a list of one or more fleet IDs. When requesting multiple fleets, use the pagination parameters to retrieve results as a set of sequential pages. If successful, a <a>FleetAttributes</a> object is returned for each requested fleet ID. When specifying a list of fleet IDs, attribute objects are returned only for fleets that currently exist. </p> <note> <p>Some API actions may limit the number of fleet IDs allowed in one request. If a request exceeds this limit, the request fails and the error message includes the maximum allowed.</p> </note> <p> <b>Learn more</b> </p> <p> <a href="https://docs.aws.amazon.com/gamelift/latest/developerguide/fleets-intro.html"> Working with Fleets</a>.</p> <p> <b>Related operations</b> </p> <ul> <li> <p> <a>CreateFleet</a> </p> </li> <li> <p> <a>ListFleets</a> </p> </li> <li> <p> <a>DeleteFleet</a> </p> </li> <li> <p>Describe fleets:</p> <ul> <li> <p> <a>DescribeFleetAttributes</a> </p> </li> <li> <p> <a>DescribeFleetCapacity</a> </p> </li> <li> <p> <a>DescribeFleetPortSettings</a> </p> </li> <li> <p> <a>DescribeFleetUtilization</a> </p> </li> <li> <p> <a>DescribeRuntimeConfiguration</a> </p> </li> <li> <p> <a>DescribeEC2InstanceLimits</a> </p> </li> <li> <p> <a>DescribeFleetEvents</a> </p> </li> </ul> </li> <li> <p> <a>UpdateFleetAttributes</a> </p> </li> <li> <p>Manage fleet actions:</p> <ul> <li> <p> <a>StartFleetActions</a> </p> </li> <li> <p> <a>StopFleetActions</a> </p> </li> </ul> </li> </ul></p>
+    async fn describe_fleet_attributes(
+        &self,
+        input: DescribeFleetAttributesInput,
+    ) -> Result<DescribeFleetAttributesOutput, RusotoError<DescribeFleetAttributesError>> {
+        self.describe_fleet_attributes_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Retrieves the current status of fleet capacity for one or more fleets. This information includes the number of instances that have been requested for the fleet and the number currently active. You can request capacity for all fleets, or specify a list of one or more fleet IDs. When requesting multiple fleets, use the pagination parameters to retrieve results as a set of sequential pages. If successful, a <a>FleetCapacity</a> object is returned for each requested fleet ID. When specifying a list of fleet IDs, attribute objects are returned only for fleets that currently exist. </p> <note> <p>Some API actions may limit the number of fleet IDs allowed in one request. If a request exceeds this limit, the request fails and the error message includes the maximum allowed.</p> </note> <p> <b>Learn more</b> </p> <p> <a href="https://docs.aws.amazon.com/gamelift/latest/developerguide/fleets-intro.html"> Working with Fleets</a>.</p> <p> <b>Related operations</b> </p> <ul> <li> <p> <a>CreateFleet</a> </p> </li> <li> <p> <a>ListFleets</a> </p> </li> <li> <p> <a>DeleteFleet</a> </p> </li> <li> <p>Describe fleets:</p> <ul> <li> <p> <a>DescribeFleetAttributes</a> </p> </li> <li> <p> <a>DescribeFleetCapacity</a> </p> </li> <li> <p> <a>DescribeFleetPortSettings</a> </p> </li> <li> <p> <a>DescribeFleetUtilization</a> </p> </li> <li> <p> <a>DescribeRuntimeConfiguration</a> </p> </li> <li> <p> <a>DescribeEC2InstanceLimits</a> </p> </li> <li> <p> <a>DescribeFleetEvents</a> </p> </li> </ul> </li> <li> <p> <a>UpdateFleetAttributes</a> </p> </li> <li> <p>Manage fleet actions:</p> <ul> <li> <p> <a>StartFleetActions</a> </p> </li> <li> <p> <a>StopFleetActions</a> </p> </li> </ul> </li> </ul></p>
+    async fn describe_fleet_capacity(
+        &self,
+        input: DescribeFleetCapacityInput,
+    ) -> Result<DescribeFleetCapacityOutput, RusotoError<DescribeFleetCapacityError>> {
+        self.describe_fleet_capacity_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Retrieves entries from the specified fleet&#39;s event log. You can specify a time range to limit the result set. Use the pagination parameters to retrieve results as a set of sequential pages. If successful, a collection of event log entries matching the request are returned.</p> <p> <b>Learn more</b> </p> <p> <a href="https://docs.aws.amazon.com/gamelift/latest/developerguide/fleets-intro.html"> Working with Fleets</a>.</p> <p> <b>Related operations</b> </p> <ul> <li> <p> <a>CreateFleet</a> </p> </li> <li> <p> <a>ListFleets</a> </p> </li> <li> <p> <a>DeleteFleet</a> </p> </li> <li> <p>Describe fleets:</p> <ul> <li> <p> <a>DescribeFleetAttributes</a> </p> </li> <li> <p> <a>DescribeFleetCapacity</a> </p> </li> <li> <p> <a>DescribeFleetPortSettings</a> </p> </li> <li> <p> <a>DescribeFleetUtilization</a> </p> </li> <li> <p> <a>DescribeRuntimeConfiguration</a> </p> </li> <li> <p> <a>DescribeEC2InstanceLimits</a> </p> </li> <li> <p> <a>DescribeFleetEvents</a> </p> </li> </ul> </li> <li> <p> <a>UpdateFleetAttributes</a> </p> </li> <li> <p>Manage fleet actions:</p> <ul> <li> <p> <a>StartFleetActions</a> </p> </li> <li> <p> <a>StopFleetActions</a> </p> </li> </ul> </li> </ul></p>
+    async fn describe_fleet_events(
+        &self,
+        input: DescribeFleetEventsInput,
+    ) -> Result<DescribeFleetEventsOutput, RusotoError<DescribeFleetEventsError>> {
+        self.describe_fleet_events_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Retrieves the inbound connection permissions for a fleet. Connection permissions include a range of IP addresses and port settings that incoming traffic can use to access server processes in the fleet. To get a fleet&#39;s inbound connection permissions, specify a fleet ID. If successful, a collection of <a>IpPermission</a> objects is returned for the requested fleet ID. If the requested fleet has been deleted, the result set is empty.</p> <p> <b>Learn more</b> </p> <p> <a href="https://docs.aws.amazon.com/gamelift/latest/developerguide/fleets-intro.html"> Working with Fleets</a>.</p> <p> <b>Related operations</b> </p> <ul> <li> <p> <a>CreateFleet</a> </p> </li> <li> <p> <a>ListFleets</a> </p> </li> <li> <p> <a>DeleteFleet</a> </p> </li> <li> <p>Describe fleets:</p> <ul> <li> <p> <a>DescribeFleetAttributes</a> </p> </li> <li> <p> <a>DescribeFleetCapacity</a> </p> </li> <li> <p> <a>DescribeFleetPortSettings</a> </p> </li> <li> <p> <a>DescribeFleetUtilization</a> </p> </li> <li> <p> <a>DescribeRuntimeConfiguration</a> </p> </li> <li> <p> <a>DescribeEC2InstanceLimits</a> </p> </li> <li> <p> <a>DescribeFleetEvents</a> </p> </li> </ul> </li> <li> <p> <a>UpdateFleetAttributes</a> </p> </li> <li> <p>Manage fleet actions:</p> <ul> <li> <p> <a>StartFleetActions</a> </p> </li> <li> <p> <a>StopFleetActions</a> </p> </li> </ul> </li> </ul></p>
+    async fn describe_fleet_port_settings(
+        &self,
+        input: DescribeFleetPortSettingsInput,
+    ) -> Result<DescribeFleetPortSettingsOutput, RusotoError<DescribeFleetPortSettingsError>> {
+        self.describe_fleet_port_settings_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Retrieves utilization statistics for one or more fleets. You can request utilization data for all fleets, or specify a list of one or more fleet IDs. When requesting multiple fleets, use the pagination parameters to retrieve results as a set of sequential pages. If successful, a <a>FleetUtilization</a> object is returned for each requested fleet ID. When specifying a list of fleet IDs, utilization objects are returned only for fleets that currently exist. </p> <note> <p>Some API actions may limit the number of fleet IDs allowed in one request. If a request exceeds this limit, the request fails and the error message includes the maximum allowed.</p> </note> <p> <b>Learn more</b> </p> <p> <a href="https://docs.aws.amazon.com/gamelift/latest/developerguide/fleets-intro.html"> Working with Fleets</a>.</p> <p> <b>Related operations</b> </p> <ul> <li> <p> <a>CreateFleet</a> </p> </li> <li> <p> <a>ListFleets</a> </p> </li> <li> <p> <a>DeleteFleet</a> </p> </li> <li> <p>Describe fleets:</p> <ul> <li> <p> <a>DescribeFleetAttributes</a> </p> </li> <li> <p> <a>DescribeFleetCapacity</a> </p> </li> <li> <p> <a>DescribeFleetPortSettings</a> </p> </li> <li> <p> <a>DescribeFleetUtilization</a> </p> </li> <li> <p> <a>DescribeRuntimeConfiguration</a> </p> </li> <li> <p> <a>DescribeEC2InstanceLimits</a> </p> </li> <li> <p> <a>DescribeFleetEvents</a> </p> </li> </ul> </li> <li> <p> <a>UpdateFleetAttributes</a> </p> </li> <li> <p>Manage fleet actions:</p> <ul> <li> <p> <a>StartFleetActions</a> </p> </li> <li> <p> <a>StopFleetActions</a> </p> </li> </ul> </li> </ul></p>
+    async fn describe_fleet_utilization(
+        &self,
+        input: DescribeFleetUtilizationInput,
+    ) -> Result<DescribeFleetUtilizationOutput, RusotoError<DescribeFleetUtilizationError>> {
+        self.describe_fleet_utilization_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Retrieves properties, including the protection policy in force, for one or more game sessions. This action can be used in several ways: (1) provide a <code>GameSessionId</code> or <code>GameSessionArn</code> to request details for a specific game session; (2) provide either a <code>FleetId</code> or an <code>AliasId</code> to request properties for all game sessions running on a fleet. </p> <p>To get game session record(s), specify just one of the following: game session ID, fleet ID, or alias ID. You can filter this request by game session status. Use the pagination parameters to retrieve results as a set of sequential pages. If successful, a <a>GameSessionDetail</a> object is returned for each session matching the request.</p> <ul> <li> <p> <a>CreateGameSession</a> </p> </li> <li> <p> <a>DescribeGameSessions</a> </p> </li> <li> <p> <a>DescribeGameSessionDetails</a> </p> </li> <li> <p> <a>SearchGameSessions</a> </p> </li> <li> <p> <a>UpdateGameSession</a> </p> </li> <li> <p> <a>GetGameSessionLogUrl</a> </p> </li> <li> <p>Game session placements</p> <ul> <li> <p> <a>StartGameSessionPlacement</a> </p> </li> <li> <p> <a>DescribeGameSessionPlacement</a> </p> </li> <li> <p> <a>StopGameSessionPlacement</a> </p> </li> </ul> </li> </ul></p>
+    async fn describe_game_session_details(
+        &self,
+        input: DescribeGameSessionDetailsInput,
+    ) -> Result<DescribeGameSessionDetailsOutput, RusotoError<DescribeGameSessionDetailsError>>
+    {
+        self.describe_game_session_details_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Retrieves properties and current status of a game session placement request. To get game session placement details, specify the placement ID. If successful, a <a>GameSessionPlacement</a> object is returned.</p> <ul> <li> <p> <a>CreateGameSession</a> </p> </li> <li> <p> <a>DescribeGameSessions</a> </p> </li> <li> <p> <a>DescribeGameSessionDetails</a> </p> </li> <li> <p> <a>SearchGameSessions</a> </p> </li> <li> <p> <a>UpdateGameSession</a> </p> </li> <li> <p> <a>GetGameSessionLogUrl</a> </p> </li> <li> <p>Game session placements</p> <ul> <li> <p> <a>StartGameSessionPlacement</a> </p> </li> <li> <p> <a>DescribeGameSessionPlacement</a> </p> </li> <li> <p> <a>StopGameSessionPlacement</a> </p> </li> </ul> </li> </ul></p>
+    async fn describe_game_session_placement(
+        &self,
+        input: DescribeGameSessionPlacementInput,
+    ) -> Result<DescribeGameSessionPlacementOutput, RusotoError<DescribeGameSessionPlacementError>>
+    {
+        self.describe_game_session_placement_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Retrieves the properties for one or more game session queues. When requesting multiple queues, use the pagination parameters to retrieve results as a set of sequential pages. If successful, a <a>GameSessionQueue</a> object is returned for each requested queue. When specifying a list of queues, objects are returned only for queues that currently exist in the Region.</p> <ul> <li> <p> <a>CreateGameSessionQueue</a> </p> </li> <li> <p> <a>DescribeGameSessionQueues</a> </p> </li> <li> <p> <a>UpdateGameSessionQueue</a> </p> </li> <li> <p> <a>DeleteGameSessionQueue</a> </p> </li> </ul></p>
+    async fn describe_game_session_queues(
+        &self,
+        input: DescribeGameSessionQueuesInput,
+    ) -> Result<DescribeGameSessionQueuesOutput, RusotoError<DescribeGameSessionQueuesError>> {
+        self.describe_game_session_queues_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Retrieves a set of one or more game sessions. Request a specific game session or request all game sessions on a fleet. Alternatively, use <a>SearchGameSessions</a> to request a set of active game sessions that are filtered by certain criteria. To retrieve protection policy settings for game sessions, use <a>DescribeGameSessionDetails</a>.</p> <p>To get game sessions, specify one of the following: game session ID, fleet ID, or alias ID. You can filter this request by game session status. Use the pagination parameters to retrieve results as a set of sequential pages. If successful, a <a>GameSession</a> object is returned for each game session matching the request.</p> <p> <i>Available in Amazon GameLift Local.</i> </p> <ul> <li> <p> <a>CreateGameSession</a> </p> </li> <li> <p> <a>DescribeGameSessions</a> </p> </li> <li> <p> <a>DescribeGameSessionDetails</a> </p> </li> <li> <p> <a>SearchGameSessions</a> </p> </li> <li> <p> <a>UpdateGameSession</a> </p> </li> <li> <p> <a>GetGameSessionLogUrl</a> </p> </li> <li> <p>Game session placements</p> <ul> <li> <p> <a>StartGameSessionPlacement</a> </p> </li> <li> <p> <a>DescribeGameSessionPlacement</a> </p> </li> <li> <p> <a>StopGameSessionPlacement</a> </p> </li> </ul> </li> </ul></p>
+    async fn describe_game_sessions(
+        &self,
+        input: DescribeGameSessionsInput,
+    ) -> Result<DescribeGameSessionsOutput, RusotoError<DescribeGameSessionsError>> {
+        self.describe_game_sessions_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Retrieves information about a fleet's instances, including instance IDs. Use this action to get details on all instances in the fleet or get details on one specific instance.</p> <p>To get a specific instance, specify fleet ID and instance ID. To get all instances in a fleet, specify a fleet ID only. Use the pagination parameters to retrieve results as a set of sequential pages. If successful, an <a>Instance</a> object is returned for each result.</p>
+    async fn describe_instances(
+        &self,
+        input: DescribeInstancesInput,
+    ) -> Result<DescribeInstancesOutput, RusotoError<DescribeInstancesError>> {
+        self.describe_instances_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Retrieves one or more matchmaking tickets. Use this operation to retrieve ticket information, including status and--once a successful match is made--acquire connection information for the resulting new game session. </p> <p>You can use this operation to track the progress of matchmaking requests (through polling) as an alternative to using event notifications. See more details on tracking matchmaking requests through polling or notifications in <a>StartMatchmaking</a>. </p> <p>To request matchmaking tickets, provide a list of up to 10 ticket IDs. If the request is successful, a ticket object is returned for each requested ID that currently exists.</p> <p> <b>Learn more</b> </p> <p> <a href="https://docs.aws.amazon.com/gamelift/latest/developerguide/match-client.html"> Add FlexMatch to a Game Client</a> </p> <p> <a href="https://docs.aws.amazon.com/gamelift/latest/developerguide/match-notification.html"> Set Up FlexMatch Event Notification</a> </p> <p> <b>Related operations</b> </p> <ul> <li> <p> <a>StartMatchmaking</a> </p> </li> <li> <p> <a>DescribeMatchmaking</a> </p> </li> <li> <p> <a>StopMatchmaking</a> </p> </li> <li> <p> <a>AcceptMatch</a> </p> </li> <li> <p> <a>StartMatchBackfill</a> </p> </li> </ul></p>
+    async fn describe_matchmaking(
+        &self,
+        input: DescribeMatchmakingInput,
+    ) -> Result<DescribeMatchmakingOutput, RusotoError<DescribeMatchmakingError>> {
+        self.describe_matchmaking_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Retrieves the details of FlexMatch matchmaking configurations. With this operation, you have the following options: (1) retrieve all existing configurations, (2) provide the names of one or more configurations to retrieve, or (3) retrieve all configurations that use a specified rule set name. When requesting multiple items, use the pagination parameters to retrieve results as a set of sequential pages. If successful, a configuration is returned for each requested name. When specifying a list of names, only configurations that currently exist are returned. </p> <p> <b>Learn more</b> </p> <p> <a href="https://docs.aws.amazon.com/gamelift/latest/developerguide/matchmaker-build.html"> Setting Up FlexMatch Matchmakers</a> </p> <p> <b>Related operations</b> </p> <ul> <li> <p> <a>CreateMatchmakingConfiguration</a> </p> </li> <li> <p> <a>DescribeMatchmakingConfigurations</a> </p> </li> <li> <p> <a>UpdateMatchmakingConfiguration</a> </p> </li> <li> <p> <a>DeleteMatchmakingConfiguration</a> </p> </li> <li> <p> <a>CreateMatchmakingRuleSet</a> </p> </li> <li> <p> <a>DescribeMatchmakingRuleSets</a> </p> </li> <li> <p> <a>ValidateMatchmakingRuleSet</a> </p> </li> <li> <p> <a>DeleteMatchmakingRuleSet</a> </p> </li> </ul></p>
+    async fn describe_matchmaking_configurations(
+        &self,
+        input: DescribeMatchmakingConfigurationsInput,
+    ) -> Result<
+        DescribeMatchmakingConfigurationsOutput,
+        RusotoError<DescribeMatchmakingConfigurationsError>,
+    > {
+        self.describe_matchmaking_configurations_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Retrieves the details for FlexMatch matchmaking rule sets. You can request all existing rule sets for the Region, or provide a list of one or more rule set names. When requesting multiple items, use the pagination parameters to retrieve results as a set of sequential pages. If successful, a rule set is returned for each requested name. </p> <p> <b>Learn more</b> </p> <ul> <li> <p> <a href="https://docs.aws.amazon.com/gamelift/latest/developerguide/match-rulesets.html">Build a Rule Set</a> </p> </li> </ul> <p> <b>Related operations</b> </p> <ul> <li> <p> <a>CreateMatchmakingConfiguration</a> </p> </li> <li> <p> <a>DescribeMatchmakingConfigurations</a> </p> </li> <li> <p> <a>UpdateMatchmakingConfiguration</a> </p> </li> <li> <p> <a>DeleteMatchmakingConfiguration</a> </p> </li> <li> <p> <a>CreateMatchmakingRuleSet</a> </p> </li> <li> <p> <a>DescribeMatchmakingRuleSets</a> </p> </li> <li> <p> <a>ValidateMatchmakingRuleSet</a> </p> </li> <li> <p> <a>DeleteMatchmakingRuleSet</a> </p> </li> </ul></p>
+    async fn describe_matchmaking_rule_sets(
+        &self,
+        input: DescribeMatchmakingRuleSetsInput,
+    ) -> Result<DescribeMatchmakingRuleSetsOutput, RusotoError<DescribeMatchmakingRuleSetsError>>
+    {
+        self.describe_matchmaking_rule_sets_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Retrieves properties for one or more player sessions. This action can be used in several ways: (1) provide a <code>PlayerSessionId</code> to request properties for a specific player session; (2) provide a <code>GameSessionId</code> to request properties for all player sessions in the specified game session; (3) provide a <code>PlayerId</code> to request properties for all player sessions of a specified player. </p> <p>To get game session record(s), specify only one of the following: a player session ID, a game session ID, or a player ID. You can filter this request by player session status. Use the pagination parameters to retrieve results as a set of sequential pages. If successful, a <a>PlayerSession</a> object is returned for each session matching the request.</p> <p> <i>Available in Amazon GameLift Local.</i> </p> <ul> <li> <p> <a>CreatePlayerSession</a> </p> </li> <li> <p> <a>CreatePlayerSessions</a> </p> </li> <li> <p> <a>DescribePlayerSessions</a> </p> </li> <li> <p>Game session placements</p> <ul> <li> <p> <a>StartGameSessionPlacement</a> </p> </li> <li> <p> <a>DescribeGameSessionPlacement</a> </p> </li> <li> <p> <a>StopGameSessionPlacement</a> </p> </li> </ul> </li> </ul></p>
+    async fn describe_player_sessions(
+        &self,
+        input: DescribePlayerSessionsInput,
+    ) -> Result<DescribePlayerSessionsOutput, RusotoError<DescribePlayerSessionsError>> {
+        self.describe_player_sessions_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Retrieves the current runtime configuration for the specified fleet. The runtime configuration tells Amazon GameLift how to launch server processes on instances in the fleet.</p> <p> <b>Learn more</b> </p> <p> <a href="https://docs.aws.amazon.com/gamelift/latest/developerguide/fleets-intro.html"> Working with Fleets</a>.</p> <p> <b>Related operations</b> </p> <ul> <li> <p> <a>CreateFleet</a> </p> </li> <li> <p> <a>ListFleets</a> </p> </li> <li> <p> <a>DeleteFleet</a> </p> </li> <li> <p>Describe fleets:</p> <ul> <li> <p> <a>DescribeFleetAttributes</a> </p> </li> <li> <p> <a>DescribeFleetCapacity</a> </p> </li> <li> <p> <a>DescribeFleetPortSettings</a> </p> </li> <li> <p> <a>DescribeFleetUtilization</a> </p> </li> <li> <p> <a>DescribeRuntimeConfiguration</a> </p> </li> <li> <p> <a>DescribeEC2InstanceLimits</a> </p> </li> <li> <p> <a>DescribeFleetEvents</a> </p> </li> </ul> </li> <li> <p> <a>UpdateFleetAttributes</a> </p> </li> <li> <p>Manage fleet actions:</p> <ul> <li> <p> <a>StartFleetActions</a> </p> </li> <li> <p> <a>StopFleetActions</a> </p> </li> </ul> </li> </ul></p>
+    async fn describe_runtime_configuration(
+        &self,
+        input: DescribeRuntimeConfigurationInput,
+    ) -> Result<DescribeRuntimeConfigurationOutput, RusotoError<DescribeRuntimeConfigurationError>>
+    {
+        self.describe_runtime_configuration_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Retrieves all scaling policies applied to a fleet.</p> <p>To get a fleet&#39;s scaling policies, specify the fleet ID. You can filter this request by policy status, such as to retrieve only active scaling policies. Use the pagination parameters to retrieve results as a set of sequential pages. If successful, set of <a>ScalingPolicy</a> objects is returned for the fleet.</p> <p>A fleet may have all of its scaling policies suspended (<a>StopFleetActions</a>). This action does not affect the status of the scaling policies, which remains ACTIVE. To see whether a fleet&#39;s scaling policies are in force or suspended, call <a>DescribeFleetAttributes</a> and check the stopped actions.</p> <ul> <li> <p> <a>DescribeFleetCapacity</a> </p> </li> <li> <p> <a>UpdateFleetCapacity</a> </p> </li> <li> <p> <a>DescribeEC2InstanceLimits</a> </p> </li> <li> <p>Manage scaling policies:</p> <ul> <li> <p> <a>PutScalingPolicy</a> (auto-scaling)</p> </li> <li> <p> <a>DescribeScalingPolicies</a> (auto-scaling)</p> </li> <li> <p> <a>DeleteScalingPolicy</a> (auto-scaling)</p> </li> </ul> </li> <li> <p>Manage fleet actions:</p> <ul> <li> <p> <a>StartFleetActions</a> </p> </li> <li> <p> <a>StopFleetActions</a> </p> </li> </ul> </li> </ul></p>
+    async fn describe_scaling_policies(
+        &self,
+        input: DescribeScalingPoliciesInput,
+    ) -> Result<DescribeScalingPoliciesOutput, RusotoError<DescribeScalingPoliciesError>> {
+        self.describe_scaling_policies_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Retrieves properties for a Realtime script. </p> <p>To request a script record, specify the script ID. If successful, an object containing the script properties is returned.</p> <p> <b>Learn more</b> </p> <p> <a href="https://docs.aws.amazon.com/gamelift/latest/developerguide/realtime-intro.html">Amazon GameLift Realtime Servers</a> </p> <p> <b>Related operations</b> </p> <ul> <li> <p> <a>CreateScript</a> </p> </li> <li> <p> <a>ListScripts</a> </p> </li> <li> <p> <a>DescribeScript</a> </p> </li> <li> <p> <a>UpdateScript</a> </p> </li> <li> <p> <a>DeleteScript</a> </p> </li> </ul></p>
+    async fn describe_script(
+        &self,
+        input: DescribeScriptInput,
+    ) -> Result<DescribeScriptOutput, RusotoError<DescribeScriptError>> {
+        self.describe_script_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Retrieves valid VPC peering authorizations that are pending for the AWS account. This operation returns all VPC peering authorizations and requests for peering. This includes those initiated and received by this account. </p> <ul> <li> <p> <a>CreateVpcPeeringAuthorization</a> </p> </li> <li> <p> <a>DescribeVpcPeeringAuthorizations</a> </p> </li> <li> <p> <a>DeleteVpcPeeringAuthorization</a> </p> </li> <li> <p> <a>CreateVpcPeeringConnection</a> </p> </li> <li> <p> <a>DescribeVpcPeeringConnections</a> </p> </li> <li> <p> <a>DeleteVpcPeeringConnection</a> </p> </li> </ul></p>
+    async fn describe_vpc_peering_authorizations(
+        &self,
+    ) -> Result<
+        DescribeVpcPeeringAuthorizationsOutput,
+        RusotoError<DescribeVpcPeeringAuthorizationsError>,
+    > {
+        self.describe_vpc_peering_authorizations_with_metadata()
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Retrieves information on VPC peering connections. Use this operation to get peering information for all fleets or for one specific fleet ID. </p> <p>To retrieve connection information, call this operation from the AWS account that is used to manage the Amazon GameLift fleets. Specify a fleet ID or leave the parameter empty to retrieve all connection records. If successful, the retrieved information includes both active and pending connections. Active connections identify the IpV4 CIDR block that the VPC uses to connect. </p> <ul> <li> <p> <a>CreateVpcPeeringAuthorization</a> </p> </li> <li> <p> <a>DescribeVpcPeeringAuthorizations</a> </p> </li> <li> <p> <a>DeleteVpcPeeringAuthorization</a> </p> </li> <li> <p> <a>CreateVpcPeeringConnection</a> </p> </li> <li> <p> <a>DescribeVpcPeeringConnections</a> </p> </li> <li> <p> <a>DeleteVpcPeeringConnection</a> </p> </li> </ul></p>
+    async fn describe_vpc_peering_connections(
+        &self,
+        input: DescribeVpcPeeringConnectionsInput,
+    ) -> Result<DescribeVpcPeeringConnectionsOutput, RusotoError<DescribeVpcPeeringConnectionsError>>
+    {
+        self.describe_vpc_peering_connections_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Retrieves the location of stored game session logs for a specified game session. When a game session is terminated, Amazon GameLift automatically stores the logs in Amazon S3 and retains them for 14 days. Use this URL to download the logs.</p> <note> <p>See the <a href="https://docs.aws.amazon.com/general/latest/gr/aws_service_limits.html#limits_gamelift">AWS Service Limits</a> page for maximum log file sizes. Log files that exceed this limit are not saved.</p> </note> <ul> <li> <p> <a>CreateGameSession</a> </p> </li> <li> <p> <a>DescribeGameSessions</a> </p> </li> <li> <p> <a>DescribeGameSessionDetails</a> </p> </li> <li> <p> <a>SearchGameSessions</a> </p> </li> <li> <p> <a>UpdateGameSession</a> </p> </li> <li> <p> <a>GetGameSessionLogUrl</a> </p> </li> <li> <p>Game session placements</p> <ul> <li> <p> <a>StartGameSessionPlacement</a> </p> </li> <li> <p> <a>DescribeGameSessionPlacement</a> </p> </li> <li> <p> <a>StopGameSessionPlacement</a> </p> </li> </ul> </li> </ul></p>
+    async fn get_game_session_log_url(
+        &self,
+        input: GetGameSessionLogUrlInput,
+    ) -> Result<GetGameSessionLogUrlOutput, RusotoError<GetGameSessionLogUrlError>> {
+        self.get_game_session_log_url_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p>Requests remote access to a fleet instance. Remote access is useful for debugging, gathering benchmarking data, or watching activity in real time. </p> <p>Access requires credentials that match the operating system of the instance. For a Windows instance, Amazon GameLift returns a user name and password as strings for use with a Windows Remote Desktop client. For a Linux instance, Amazon GameLift returns a user name and RSA private key, also as strings, for use with an SSH client. The private key must be saved in the proper format to a <code>.pem</code> file before using. If you're making this request using the AWS CLI, saving the secret can be handled as part of the GetInstanceAccess request. (See the example later in this topic). For more information on remote access, see <a href="https://docs.aws.amazon.com/gamelift/latest/developerguide/fleets-remote-access.html">Remotely Accessing an Instance</a>.</p> <p>To request access to a specific instance, specify the IDs of both the instance and the fleet it belongs to. You can retrieve a fleet's instance IDs by calling <a>DescribeInstances</a>. If successful, an <a>InstanceAccess</a> object is returned containing the instance's IP address and a set of credentials.</p>
+    async fn get_instance_access(
+        &self,
+        input: GetInstanceAccessInput,
+    ) -> Result<GetInstanceAccessOutput, RusotoError<GetInstanceAccessError>> {
+        self.get_instance_access_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Retrieves all aliases for this AWS account. You can filter the result set by alias name and/or routing strategy type. Use the pagination parameters to retrieve results in sequential pages.</p> <note> <p>Returned aliases are not listed in any particular order.</p> </note> <ul> <li> <p> <a>CreateAlias</a> </p> </li> <li> <p> <a>ListAliases</a> </p> </li> <li> <p> <a>DescribeAlias</a> </p> </li> <li> <p> <a>UpdateAlias</a> </p> </li> <li> <p> <a>DeleteAlias</a> </p> </li> <li> <p> <a>ResolveAlias</a> </p> </li> </ul></p>
+    async fn list_aliases(
+        &self,
+        input: ListAliasesInput,
+    ) -> Result<ListAliasesOutput, RusotoError<ListAliasesError>> {
+        self.list_aliases_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Retrieves build records for all builds associated with the AWS account in use. You can limit results to builds that are in a specific status by using the <code>Status</code> parameter. Use the pagination parameters to retrieve results in a set of sequential pages. </p> <note> <p>Build records are not listed in any particular order.</p> </note> <p> <b>Learn more</b> </p> <p> <a href="https://docs.aws.amazon.com/gamelift/latest/developerguide/build-intro.html"> Working with Builds</a> </p> <p> <b>Related operations</b> </p> <ul> <li> <p> <a>CreateBuild</a> </p> </li> <li> <p> <a>ListBuilds</a> </p> </li> <li> <p> <a>DescribeBuild</a> </p> </li> <li> <p> <a>UpdateBuild</a> </p> </li> <li> <p> <a>DeleteBuild</a> </p> </li> </ul></p>
+    async fn list_builds(
+        &self,
+        input: ListBuildsInput,
+    ) -> Result<ListBuildsOutput, RusotoError<ListBuildsError>> {
+        self.list_builds_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Retrieves a collection of fleet records for this AWS account. You can filter the result set to find only those fleets that are deployed with a specific build or script. Use the pagination parameters to retrieve results in sequential pages.</p> <note> <p>Fleet records are not listed in a particular order.</p> </note> <p> <b>Learn more</b> </p> <p> <a href="https://docs.aws.amazon.com/gamelift/latest/developerguide/fleets-intro.html"> Set Up Fleets</a>.</p> <p> <b>Related operations</b> </p> <ul> <li> <p> <a>CreateFleet</a> </p> </li> <li> <p> <a>ListFleets</a> </p> </li> <li> <p> <a>DeleteFleet</a> </p> </li> <li> <p> <a>DescribeFleetAttributes</a> </p> </li> <li> <p> <a>UpdateFleetAttributes</a> </p> </li> <li> <p>Manage fleet actions:</p> <ul> <li> <p> <a>StartFleetActions</a> </p> </li> <li> <p> <a>StopFleetActions</a> </p> </li> </ul> </li> </ul></p>
+    async fn list_fleets(
+        &self,
+        input: ListFleetsInput,
+    ) -> Result<ListFleetsOutput, RusotoError<ListFleetsError>> {
+        self.list_fleets_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Retrieves script records for all Realtime scripts that are associated with the AWS account in use. </p> <p> <b>Learn more</b> </p> <p> <a href="https://docs.aws.amazon.com/gamelift/latest/developerguide/realtime-intro.html">Amazon GameLift Realtime Servers</a> </p> <p> <b>Related operations</b> </p> <ul> <li> <p> <a>CreateScript</a> </p> </li> <li> <p> <a>ListScripts</a> </p> </li> <li> <p> <a>DescribeScript</a> </p> </li> <li> <p> <a>UpdateScript</a> </p> </li> <li> <p> <a>DeleteScript</a> </p> </li> </ul></p>
+    async fn list_scripts(
+        &self,
+        input: ListScriptsInput,
+    ) -> Result<ListScriptsOutput, RusotoError<ListScriptsError>> {
+        self.list_scripts_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p> Retrieves all tags that are assigned to a GameLift resource. Resource tags are used to organize AWS resources for a range of purposes. This action handles the permissions necessary to manage tags for the following GameLift resource types:</p> <ul> <li> <p>Build</p> </li> <li> <p>Script</p> </li> <li> <p>Fleet</p> </li> <li> <p>Alias</p> </li> <li> <p>GameSessionQueue</p> </li> <li> <p>MatchmakingConfiguration</p> </li> <li> <p>MatchmakingRuleSet</p> </li> </ul> <p>To list tags for a resource, specify the unique ARN value for the resource.</p> <p> <b>Learn more</b> </p> <p> <a href="https://docs.aws.amazon.com/general/latest/gr/aws_tagging.html">Tagging AWS Resources</a> in the <i>AWS General Reference</i> </p> <p> <a href="http://aws.amazon.com/answers/account-management/aws-tagging-strategies/"> AWS Tagging Strategies</a> </p> <p> <b>Related operations</b> </p> <ul> <li> <p> <a>TagResource</a> </p> </li> <li> <p> <a>UntagResource</a> </p> </li> <li> <p> <a>ListTagsForResource</a> </p> </li> </ul></p>
+    async fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> Result<ListTagsForResourceResponse, RusotoError<ListTagsForResourceError>> {
+        self.list_tags_for_resource_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Creates or updates a scaling policy for a fleet. Scaling policies are used to automatically scale a fleet&#39;s hosting capacity to meet player demand. An active scaling policy instructs Amazon GameLift to track a fleet metric and automatically change the fleet&#39;s capacity when a certain threshold is reached. There are two types of scaling policies: target-based and rule-based. Use a target-based policy to quickly and efficiently manage fleet scaling; this option is the most commonly used. Use rule-based policies when you need to exert fine-grained control over auto-scaling. </p> <p>Fleets can have multiple scaling policies of each type in force at the same time; you can have one target-based policy, one or multiple rule-based scaling policies, or both. We recommend caution, however, because multiple auto-scaling policies can have unintended consequences.</p> <p>You can temporarily suspend all scaling policies for a fleet by calling <a>StopFleetActions</a> with the fleet action AUTO_SCALING. To resume scaling policies, call <a>StartFleetActions</a> with the same fleet action. To stop just one scaling policy--or to permanently remove it, you must delete the policy with <a>DeleteScalingPolicy</a>.</p> <p>Learn more about how to work with auto-scaling in <a href="https://docs.aws.amazon.com/gamelift/latest/developerguide/fleets-autoscaling.html">Set Up Fleet Automatic Scaling</a>.</p> <p> <b>Target-based policy</b> </p> <p>A target-based policy tracks a single metric: PercentAvailableGameSessions. This metric tells us how much of a fleet&#39;s hosting capacity is ready to host game sessions but is not currently in use. This is the fleet&#39;s buffer; it measures the additional player demand that the fleet could handle at current capacity. With a target-based policy, you set your ideal buffer size and leave it to Amazon GameLift to take whatever action is needed to maintain that target. </p> <p>For example, you might choose to maintain a 10% buffer for a fleet that has the capacity to host 100 simultaneous game sessions. This policy tells Amazon GameLift to take action whenever the fleet&#39;s available capacity falls below or rises above 10 game sessions. Amazon GameLift will start new instances or stop unused instances in order to return to the 10% buffer. </p> <p>To create or update a target-based policy, specify a fleet ID and name, and set the policy type to &quot;TargetBased&quot;. Specify the metric to track (PercentAvailableGameSessions) and reference a <a>TargetConfiguration</a> object with your desired buffer value. Exclude all other parameters. On a successful request, the policy name is returned. The scaling policy is automatically in force as soon as it&#39;s successfully created. If the fleet&#39;s auto-scaling actions are temporarily suspended, the new policy will be in force once the fleet actions are restarted.</p> <p> <b>Rule-based policy</b> </p> <p>A rule-based policy tracks specified fleet metric, sets a threshold value, and specifies the type of action to initiate when triggered. With a rule-based policy, you can select from several available fleet metrics. Each policy specifies whether to scale up or scale down (and by how much), so you need one policy for each type of action. </p> <p>For example, a policy may make the following statement: &quot;If the percentage of idle instances is greater than 20% for more than 15 minutes, then reduce the fleet capacity by 10%.&quot;</p> <p>A policy&#39;s rule statement has the following structure:</p> <p>If <code>[MetricName]</code> is <code>[ComparisonOperator]</code> <code>[Threshold]</code> for <code>[EvaluationPeriods]</code> minutes, then <code>[ScalingAdjustmentType]</code> to/by <code>[ScalingAdjustment]</code>.</p> <p>To implement the example, the rule statement would look like this:</p> <p>If <code>[PercentIdleInstances]</code> is <code>[GreaterThanThreshold]</code> <code>[20]</code> for <code>[15]</code> minutes, then <code>[PercentChangeInCapacity]</code> to/by <code>[10]</code>.</p> <p>To create or update a scaling policy, specify a unique combination of name and fleet ID, and set the policy type to &quot;RuleBased&quot;. Specify the parameter values for a policy rule statement. On a successful request, the policy name is returned. Scaling policies are automatically in force as soon as they&#39;re successfully created. If the fleet&#39;s auto-scaling actions are temporarily suspended, the new policy will be in force once the fleet actions are restarted.</p> <ul> <li> <p> <a>DescribeFleetCapacity</a> </p> </li> <li> <p> <a>UpdateFleetCapacity</a> </p> </li> <li> <p> <a>DescribeEC2InstanceLimits</a> </p> </li> <li> <p>Manage scaling policies:</p> <ul> <li> <p> <a>PutScalingPolicy</a> (auto-scaling)</p> </li> <li> <p> <a>DescribeScalingPolicies</a> (auto-scaling)</p> </li> <li> <p> <a>DeleteScalingPolicy</a> (auto-scaling)</p> </li> </ul> </li> <li> <p>Manage fleet actions:</p> <ul> <li> <p> <a>StartFleetActions</a> </p> </li> <li> <p> <a>StopFleetActions</a> </p> </li> </ul> </li> </ul></p>
+    async fn put_scaling_policy(
+        &self,
+        input: PutScalingPolicyInput,
+    ) -> Result<PutScalingPolicyOutput, RusotoError<PutScalingPolicyError>> {
+        self.put_scaling_policy_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Retrieves a fresh set of credentials for use when uploading a new set of game build files to Amazon GameLift&#39;s Amazon S3. This is done as part of the build creation process; see <a>CreateBuild</a>.</p> <p>To request new credentials, specify the build ID as returned with an initial <code>CreateBuild</code> request. If successful, a new set of credentials are returned, along with the S3 storage location associated with the build ID.</p> <p> <b>Learn more</b> </p> <p> <a href="https://docs.aws.amazon.com/gamelift/latest/developerguide/gamelift-build-intro.html">Uploading Your Game</a> </p> <p> <b>Related operations</b> </p> <ul> <li> <p> <a>CreateBuild</a> </p> </li> <li> <p> <a>ListBuilds</a> </p> </li> <li> <p> <a>DescribeBuild</a> </p> </li> <li> <p> <a>UpdateBuild</a> </p> </li> <li> <p> <a>DeleteBuild</a> </p> </li> </ul></p>
+    async fn request_upload_credentials(
+        &self,
+        input: RequestUploadCredentialsInput,
+    ) -> Result<RequestUploadCredentialsOutput, RusotoError<RequestUploadCredentialsError>> {
+        self.request_upload_credentials_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Retrieves the fleet ID that an alias is currently pointing to.</p> <ul> <li> <p> <a>CreateAlias</a> </p> </li> <li> <p> <a>ListAliases</a> </p> </li> <li> <p> <a>DescribeAlias</a> </p> </li> <li> <p> <a>UpdateAlias</a> </p> </li> <li> <p> <a>DeleteAlias</a> </p> </li> <li> <p> <a>ResolveAlias</a> </p> </li> </ul></p>
+    async fn resolve_alias(
+        &self,
+        input: ResolveAliasInput,
+    ) -> Result<ResolveAliasOutput, RusotoError<ResolveAliasError>> {
+        self.resolve_alias_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Retrieves all active game sessions that match a set of search criteria and sorts them in a specified order. You can search or sort by the following game session attributes:</p> <ul> <li> <p> <b>gameSessionId</b> -- A unique identifier for the game session. You can use either a <code>GameSessionId</code> or <code>GameSessionArn</code> value. </p> </li> <li> <p> <b>gameSessionName</b> -- Name assigned to a game session. This value is set when requesting a new game session with <a>CreateGameSession</a> or updating with <a>UpdateGameSession</a>. Game session names do not need to be unique to a game session.</p> </li> <li> <p> <b>gameSessionProperties</b> -- Custom data defined in a game session&#39;s <code>GameProperty</code> parameter. <code>GameProperty</code> values are stored as key:value pairs; the filter expression must indicate the key and a string to search the data values for. For example, to search for game sessions with custom data containing the key:value pair &quot;gameMode:brawl&quot;, specify the following: <code>gameSessionProperties.gameMode = &quot;brawl&quot;</code>. All custom data values are searched as strings.</p> </li> <li> <p> <b>maximumSessions</b> -- Maximum number of player sessions allowed for a game session. This value is set when requesting a new game session with <a>CreateGameSession</a> or updating with <a>UpdateGameSession</a>.</p> </li> <li> <p> <b>creationTimeMillis</b> -- Value indicating when a game session was created. It is expressed in Unix time as milliseconds.</p> </li> <li> <p> <b>playerSessionCount</b> -- Number of players currently connected to a game session. This value changes rapidly as players join the session or drop out.</p> </li> <li> <p> <b>hasAvailablePlayerSessions</b> -- Boolean value indicating whether a game session has reached its maximum number of players. It is highly recommended that all search requests include this filter attribute to optimize search performance and return only sessions that players can join. </p> </li> </ul> <note> <p>Returned values for <code>playerSessionCount</code> and <code>hasAvailablePlayerSessions</code> change quickly as players join sessions and others drop out. Results should be considered a snapshot in time. Be sure to refresh search results often, and handle sessions that fill up before a player can join. </p> </note> <p>To search or sort, specify either a fleet ID or an alias ID, and provide a search filter expression, a sort expression, or both. If successful, a collection of <a>GameSession</a> objects matching the request is returned. Use the pagination parameters to retrieve results as a set of sequential pages. </p> <p>You can search for game sessions one fleet at a time only. To find game sessions across multiple fleets, you must search each fleet separately and combine the results. This search feature finds only game sessions that are in <code>ACTIVE</code> status. To locate games in statuses other than active, use <a>DescribeGameSessionDetails</a>.</p> <ul> <li> <p> <a>CreateGameSession</a> </p> </li> <li> <p> <a>DescribeGameSessions</a> </p> </li> <li> <p> <a>DescribeGameSessionDetails</a> </p> </li> <li> <p> <a>SearchGameSessions</a> </p> </li> <li> <p> <a>UpdateGameSession</a> </p> </li> <li> <p> <a>GetGameSessionLogUrl</a> </p> </li> <li> <p>Game session placements</p> <ul> <li> <p> <a>StartGameSessionPlacement</a> </p> </li> <li> <p> <a>DescribeGameSessionPlacement</a> </p> </li> <li> <p> <a>StopGameSessionPlacement</a> </p> </li> </ul> </li> </ul></p>
+    async fn search_game_sessions(
+        &self,
+        input: SearchGameSessionsInput,
+    ) -> Result<SearchGameSessionsOutput, RusotoError<SearchGameSessionsError>> {
+        self.search_game_sessions_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Resumes activity on a fleet that was suspended with <a>StopFleetActions</a>. Currently, this operation is used to restart a fleet&#39;s auto-scaling activity. </p> <p>To start fleet actions, specify the fleet ID and the type of actions to restart. When auto-scaling fleet actions are restarted, Amazon GameLift once again initiates scaling events as triggered by the fleet&#39;s scaling policies. If actions on the fleet were never stopped, this operation will have no effect. You can view a fleet&#39;s stopped actions using <a>DescribeFleetAttributes</a>.</p> <p> <b>Learn more</b> </p> <p> <a href="https://docs.aws.amazon.com/gamelift/latest/developerguide/fleets-intro.html"> Working with Fleets</a>.</p> <p> <b>Related operations</b> </p> <ul> <li> <p> <a>CreateFleet</a> </p> </li> <li> <p> <a>ListFleets</a> </p> </li> <li> <p> <a>DeleteFleet</a> </p> </li> <li> <p>Describe fleets:</p> <ul> <li> <p> <a>DescribeFleetAttributes</a> </p> </li> <li> <p> <a>DescribeFleetCapacity</a> </p> </li> <li> <p> <a>DescribeFleetPortSettings</a> </p> </li> <li> <p> <a>DescribeFleetUtilization</a> </p> </li> <li> <p> <a>DescribeRuntimeConfiguration</a> </p> </li> <li> <p> <a>DescribeEC2InstanceLimits</a> </p> </li> <li> <p> <a>DescribeFleetEvents</a> </p> </li> </ul> </li> <li> <p>Update fleets:</p> <ul> <li> <p> <a>UpdateFleetAttributes</a> </p> </li> <li> <p> <a>UpdateFleetCapacity</a> </p> </li> <li> <p> <a>UpdateFleetPortSettings</a> </p> </li> <li> <p> <a>UpdateRuntimeConfiguration</a> </p> </li> </ul> </li> <li> <p>Manage fleet actions:</p> <ul> <li> <p> <a>StartFleetActions</a> </p> </li> <li> <p> <a>StopFleetActions</a> </p> </li> </ul> </li> </ul></p>
+    async fn start_fleet_actions(
+        &self,
+        input: StartFleetActionsInput,
+    ) -> Result<StartFleetActionsOutput, RusotoError<StartFleetActionsError>> {
+        self.start_fleet_actions_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Places a request for a new game session in a queue (see <a>CreateGameSessionQueue</a>). When processing a placement request, Amazon GameLift searches for available resources on the queue&#39;s destinations, scanning each until it finds resources or the placement request times out.</p> <p>A game session placement request can also request player sessions. When a new game session is successfully created, Amazon GameLift creates a player session for each player included in the request.</p> <p>When placing a game session, by default Amazon GameLift tries each fleet in the order they are listed in the queue configuration. Ideally, a queue&#39;s destinations are listed in preference order.</p> <p>Alternatively, when requesting a game session with players, you can also provide latency data for each player in relevant Regions. Latency data indicates the performance lag a player experiences when connected to a fleet in the Region. Amazon GameLift uses latency data to reorder the list of destinations to place the game session in a Region with minimal lag. If latency data is provided for multiple players, Amazon GameLift calculates each Region&#39;s average lag for all players and reorders to get the best game play across all players. </p> <p>To place a new game session request, specify the following:</p> <ul> <li> <p>The queue name and a set of game session properties and settings</p> </li> <li> <p>A unique ID (such as a UUID) for the placement. You use this ID to track the status of the placement request</p> </li> <li> <p>(Optional) A set of player data and a unique player ID for each player that you are joining to the new game session (player data is optional, but if you include it, you must also provide a unique ID for each player)</p> </li> <li> <p>Latency data for all players (if you want to optimize game play for the players)</p> </li> </ul> <p>If successful, a new game session placement is created.</p> <p>To track the status of a placement request, call <a>DescribeGameSessionPlacement</a> and check the request&#39;s status. If the status is <code>FULFILLED</code>, a new game session has been created and a game session ARN and Region are referenced. If the placement request times out, you can resubmit the request or retry it with a different queue. </p> <ul> <li> <p> <a>CreateGameSession</a> </p> </li> <li> <p> <a>DescribeGameSessions</a> </p> </li> <li> <p> <a>DescribeGameSessionDetails</a> </p> </li> <li> <p> <a>SearchGameSessions</a> </p> </li> <li> <p> <a>UpdateGameSession</a> </p> </li> <li> <p> <a>GetGameSessionLogUrl</a> </p> </li> <li> <p>Game session placements</p> <ul> <li> <p> <a>StartGameSessionPlacement</a> </p> </li> <li> <p> <a>DescribeGameSessionPlacement</a> </p> </li> <li> <p> <a>StopGameSessionPlacement</a> </p> </li> </ul> </li> </ul></p>
+    async fn start_game_session_placement(
+        &self,
+        input: StartGameSessionPlacementInput,
+    ) -> Result<StartGameSessionPlacementOutput, RusotoError<StartGameSessionPlacementError>> {
+        self.start_game_session_placement_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Finds new players to fill open slots in an existing game session. This operation can be used to add players to matched games that start with fewer than the maximum number of players or to replace players when they drop out. By backfilling with the same matchmaker used to create the original match, you ensure that new players meet the match criteria and maintain a consistent experience throughout the game session. You can backfill a match anytime after a game session has been created. </p> <p>To request a match backfill, specify a unique ticket ID, the existing game session&#39;s ARN, a matchmaking configuration, and a set of data that describes all current players in the game session. If successful, a match backfill ticket is created and returned with status set to QUEUED. The ticket is placed in the matchmaker&#39;s ticket pool and processed. Track the status of the ticket to respond as needed. </p> <p>The process of finding backfill matches is essentially identical to the initial matchmaking process. The matchmaker searches the pool and groups tickets together to form potential matches, allowing only one backfill ticket per potential match. Once the a match is formed, the matchmaker creates player sessions for the new players. All tickets in the match are updated with the game session&#39;s connection information, and the <a>GameSession</a> object is updated to include matchmaker data on the new players. For more detail on how match backfill requests are processed, see <a href="https://docs.aws.amazon.com/gamelift/latest/developerguide/gamelift-match.html"> How Amazon GameLift FlexMatch Works</a>. </p> <p> <b>Learn more</b> </p> <p> <a href="https://docs.aws.amazon.com/gamelift/latest/developerguide/match-backfill.html"> Backfill Existing Games with FlexMatch</a> </p> <p> <a href="https://docs.aws.amazon.com/gamelift/latest/developerguide/gamelift-match.html"> How GameLift FlexMatch Works</a> </p> <p> <b>Related operations</b> </p> <ul> <li> <p> <a>StartMatchmaking</a> </p> </li> <li> <p> <a>DescribeMatchmaking</a> </p> </li> <li> <p> <a>StopMatchmaking</a> </p> </li> <li> <p> <a>AcceptMatch</a> </p> </li> <li> <p> <a>StartMatchBackfill</a> </p> </li> </ul></p>
+    async fn start_match_backfill(
+        &self,
+        input: StartMatchBackfillInput,
+    ) -> Result<StartMatchBackfillOutput, RusotoError<StartMatchBackfillError>> {
+        self.start_match_backfill_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Uses FlexMatch to create a game match for a group of players based on custom matchmaking rules, and starts a new game for the matched players. Each matchmaking request specifies the type of match to build (team configuration, rules for an acceptable match, etc.). The request also specifies the players to find a match for and where to host the new game session for optimal performance. A matchmaking request might start with a single player or a group of players who want to play together. FlexMatch finds additional players as needed to fill the match. Match type, rules, and the queue used to place a new game session are defined in a <code>MatchmakingConfiguration</code>. </p> <p>To start matchmaking, provide a unique ticket ID, specify a matchmaking configuration, and include the players to be matched. You must also include a set of player attributes relevant for the matchmaking configuration. If successful, a matchmaking ticket is returned with status set to <code>QUEUED</code>. Track the status of the ticket to respond as needed and acquire game session connection information for successfully completed matches.</p> <p> <b>Tracking ticket status</b> -- A couple of options are available for tracking the status of matchmaking requests: </p> <ul> <li> <p>Polling -- Call <code>DescribeMatchmaking</code>. This operation returns the full ticket object, including current status and (for completed tickets) game session connection info. We recommend polling no more than once every 10 seconds.</p> </li> <li> <p>Notifications -- Get event notifications for changes in ticket status using Amazon Simple Notification Service (SNS). Notifications are easy to set up (see <a>CreateMatchmakingConfiguration</a>) and typically deliver match status changes faster and more efficiently than polling. We recommend that you use polling to back up to notifications (since delivery is not guaranteed) and call <code>DescribeMatchmaking</code> only when notifications are not received within 30 seconds.</p> </li> </ul> <p> <b>Processing a matchmaking request</b> -- FlexMatch handles a matchmaking request as follows: </p> <ol> <li> <p>Your client code submits a <code>StartMatchmaking</code> request for one or more players and tracks the status of the request ticket. </p> </li> <li> <p>FlexMatch uses this ticket and others in process to build an acceptable match. When a potential match is identified, all tickets in the proposed match are advanced to the next status. </p> </li> <li> <p>If the match requires player acceptance (set in the matchmaking configuration), the tickets move into status <code>REQUIRES_ACCEPTANCE</code>. This status triggers your client code to solicit acceptance from all players in every ticket involved in the match, and then call <a>AcceptMatch</a> for each player. If any player rejects or fails to accept the match before a specified timeout, the proposed match is dropped (see <code>AcceptMatch</code> for more details).</p> </li> <li> <p>Once a match is proposed and accepted, the matchmaking tickets move into status <code>PLACING</code>. FlexMatch locates resources for a new game session using the game session queue (set in the matchmaking configuration) and creates the game session based on the match data. </p> </li> <li> <p>When the match is successfully placed, the matchmaking tickets move into <code>COMPLETED</code> status. Connection information (including game session endpoint and player session) is added to the matchmaking tickets. Matched players can use the connection information to join the game. </p> </li> </ol> <p> <b>Learn more</b> </p> <p> <a href="https://docs.aws.amazon.com/gamelift/latest/developerguide/match-client.html"> Add FlexMatch to a Game Client</a> </p> <p> <a href="https://docs.aws.amazon.com/gamelift/latest/developerguide/match-notification.html"> Set Up FlexMatch Event Notification</a> </p> <p> <a href="https://docs.aws.amazon.com/gamelift/latest/developerguide/match-tasks.html"> FlexMatch Integration Roadmap</a> </p> <p> <a href="https://docs.aws.amazon.com/gamelift/latest/developerguide/gamelift-match.html"> How GameLift FlexMatch Works</a> </p> <p> <b>Related operations</b> </p> <ul> <li> <p> <a>StartMatchmaking</a> </p> </li> <li> <p> <a>DescribeMatchmaking</a> </p> </li> <li> <p> <a>StopMatchmaking</a> </p> </li> <li> <p> <a>AcceptMatch</a> </p> </li> <li> <p> <a>StartMatchBackfill</a> </p> </li> </ul></p>
+    async fn start_matchmaking(
+        &self,
+        input: StartMatchmakingInput,
+    ) -> Result<StartMatchmakingOutput, RusotoError<StartMatchmakingError>> {
+        self.start_matchmaking_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Suspends activity on a fleet. Currently, this operation is used to stop a fleet&#39;s auto-scaling activity. It is used to temporarily stop scaling events triggered by the fleet&#39;s scaling policies. The policies can be retained and auto-scaling activity can be restarted using <a>StartFleetActions</a>. You can view a fleet&#39;s stopped actions using <a>DescribeFleetAttributes</a>.</p> <p>To stop fleet actions, specify the fleet ID and the type of actions to suspend. When auto-scaling fleet actions are stopped, Amazon GameLift no longer initiates scaling events except to maintain the fleet&#39;s desired instances setting (<a>FleetCapacity</a>. Changes to the fleet&#39;s capacity must be done manually using <a>UpdateFleetCapacity</a>. </p> <p> <b>Learn more</b> </p> <p> <a href="https://docs.aws.amazon.com/gamelift/latest/developerguide/fleets-intro.html"> Working with Fleets</a>.</p> <p> <b>Related operations</b> </p> <ul> <li> <p> <a>CreateFleet</a> </p> </li> <li> <p> <a>ListFleets</a> </p> </li> <li> <p> <a>DeleteFleet</a> </p> </li> <li> <p>Describe fleets:</p> <ul> <li> <p> <a>DescribeFleetAttributes</a> </p> </li> <li> <p> <a>DescribeFleetCapacity</a> </p> </li> <li> <p> <a>DescribeFleetPortSettings</a> </p> </li> <li> <p> <a>DescribeFleetUtilization</a> </p> </li> <li> <p> <a>DescribeRuntimeConfiguration</a> </p> </li> <li> <p> <a>DescribeEC2InstanceLimits</a> </p> </li> <li> <p> <a>DescribeFleetEvents</a> </p> </li> </ul> </li> <li> <p>Update fleets:</p> <ul> <li> <p> <a>UpdateFleetAttributes</a> </p> </li> <li> <p> <a>UpdateFleetCapacity</a> </p> </li> <li> <p> <a>UpdateFleetPortSettings</a> </p> </li> <li> <p> <a>UpdateRuntimeConfiguration</a> </p> </li> </ul> </li> <li> <p>Manage fleet actions:</p> <ul> <li> <p> <a>StartFleetActions</a> </p> </li> <li> <p> <a>StopFleetActions</a> </p> </li> </ul> </li> </ul></p>
+    async fn stop_fleet_actions(
+        &self,
+        input: StopFleetActionsInput,
+    ) -> Result<StopFleetActionsOutput, RusotoError<StopFleetActionsError>> {
+        self.stop_fleet_actions_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Cancels a game session placement that is in <code>PENDING</code> status. To stop a placement, provide the placement ID values. If successful, the placement is moved to <code>CANCELLED</code> status.</p> <ul> <li> <p> <a>CreateGameSession</a> </p> </li> <li> <p> <a>DescribeGameSessions</a> </p> </li> <li> <p> <a>DescribeGameSessionDetails</a> </p> </li> <li> <p> <a>SearchGameSessions</a> </p> </li> <li> <p> <a>UpdateGameSession</a> </p> </li> <li> <p> <a>GetGameSessionLogUrl</a> </p> </li> <li> <p>Game session placements</p> <ul> <li> <p> <a>StartGameSessionPlacement</a> </p> </li> <li> <p> <a>DescribeGameSessionPlacement</a> </p> </li> <li> <p> <a>StopGameSessionPlacement</a> </p> </li> </ul> </li> </ul></p>
+    async fn stop_game_session_placement(
+        &self,
+        input: StopGameSessionPlacementInput,
+    ) -> Result<StopGameSessionPlacementOutput, RusotoError<StopGameSessionPlacementError>> {
+        self.stop_game_session_placement_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Cancels a matchmaking ticket or match backfill ticket that is currently being processed. To stop the matchmaking operation, specify the ticket ID. If successful, work on the ticket is stopped, and the ticket status is changed to <code>CANCELLED</code>.</p> <p>This call is also used to turn off automatic backfill for an individual game session. This is for game sessions that are created with a matchmaking configuration that has automatic backfill enabled. The ticket ID is included in the <code>MatchmakerData</code> of an updated game session object, which is provided to the game server.</p> <note> <p>If the action is successful, the service sends back an empty JSON struct with the HTTP 200 response (not an empty HTTP body).</p> </note> <p> <b>Learn more</b> </p> <p> <a href="https://docs.aws.amazon.com/gamelift/latest/developerguide/match-client.html"> Add FlexMatch to a Game Client</a> </p> <p> <b>Related operations</b> </p> <ul> <li> <p> <a>StartMatchmaking</a> </p> </li> <li> <p> <a>DescribeMatchmaking</a> </p> </li> <li> <p> <a>StopMatchmaking</a> </p> </li> <li> <p> <a>AcceptMatch</a> </p> </li> <li> <p> <a>StartMatchBackfill</a> </p> </li> </ul></p>
+    async fn stop_matchmaking(
+        &self,
+        input: StopMatchmakingInput,
+    ) -> Result<StopMatchmakingOutput, RusotoError<StopMatchmakingError>> {
+        self.stop_matchmaking_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p> Assigns a tag to a GameLift resource. AWS resource tags provide an additional management tool set. You can use tags to organize resources, create IAM permissions policies to manage access to groups of resources, customize AWS cost breakdowns, etc. This action handles the permissions necessary to manage tags for the following GameLift resource types:</p> <ul> <li> <p>Build</p> </li> <li> <p>Script</p> </li> <li> <p>Fleet</p> </li> <li> <p>Alias</p> </li> <li> <p>GameSessionQueue</p> </li> <li> <p>MatchmakingConfiguration</p> </li> <li> <p>MatchmakingRuleSet</p> </li> </ul> <p>To add a tag to a resource, specify the unique ARN value for the resource and provide a trig list containing one or more tags. The operation succeeds even if the list includes tags that are already assigned to the specified resource. </p> <p> <b>Learn more</b> </p> <p> <a href="https://docs.aws.amazon.com/general/latest/gr/aws_tagging.html">Tagging AWS Resources</a> in the <i>AWS General Reference</i> </p> <p> <a href="http://aws.amazon.com/answers/account-management/aws-tagging-strategies/"> AWS Tagging Strategies</a> </p> <p> <b>Related operations</b> </p> <ul> <li> <p> <a>TagResource</a> </p> </li> <li> <p> <a>UntagResource</a> </p> </li> <li> <p> <a>ListTagsForResource</a> </p> </li> </ul></p>
+    async fn tag_resource(
+        &self,
+        input: TagResourceRequest,
+    ) -> Result<TagResourceResponse, RusotoError<TagResourceError>> {
+        self.tag_resource_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Removes a tag that is assigned to a GameLift resource. Resource tags are used to organize AWS resources for a range of purposes. This action handles the permissions necessary to manage tags for the following GameLift resource types:</p> <ul> <li> <p>Build</p> </li> <li> <p>Script</p> </li> <li> <p>Fleet</p> </li> <li> <p>Alias</p> </li> <li> <p>GameSessionQueue</p> </li> <li> <p>MatchmakingConfiguration</p> </li> <li> <p>MatchmakingRuleSet</p> </li> </ul> <p>To remove a tag from a resource, specify the unique ARN value for the resource and provide a string list containing one or more tags to be removed. This action succeeds even if the list includes tags that are not currently assigned to the specified resource.</p> <p> <b>Learn more</b> </p> <p> <a href="https://docs.aws.amazon.com/general/latest/gr/aws_tagging.html">Tagging AWS Resources</a> in the <i>AWS General Reference</i> </p> <p> <a href="http://aws.amazon.com/answers/account-management/aws-tagging-strategies/"> AWS Tagging Strategies</a> </p> <p> <b>Related operations</b> </p> <ul> <li> <p> <a>TagResource</a> </p> </li> <li> <p> <a>UntagResource</a> </p> </li> <li> <p> <a>ListTagsForResource</a> </p> </li> </ul></p>
+    async fn untag_resource(
+        &self,
+        input: UntagResourceRequest,
+    ) -> Result<UntagResourceResponse, RusotoError<UntagResourceError>> {
+        self.untag_resource_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Updates properties for an alias. To update properties, specify the alias ID to be updated and provide the information to be changed. To reassign an alias to another fleet, provide an updated routing strategy. If successful, the updated alias record is returned.</p> <ul> <li> <p> <a>CreateAlias</a> </p> </li> <li> <p> <a>ListAliases</a> </p> </li> <li> <p> <a>DescribeAlias</a> </p> </li> <li> <p> <a>UpdateAlias</a> </p> </li> <li> <p> <a>DeleteAlias</a> </p> </li> <li> <p> <a>ResolveAlias</a> </p> </li> </ul></p>
+    async fn update_alias(
+        &self,
+        input: UpdateAliasInput,
+    ) -> Result<UpdateAliasOutput, RusotoError<UpdateAliasError>> {
+        self.update_alias_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Updates metadata in a build record, including the build name and version. To update the metadata, specify the build ID to update and provide the new values. If successful, a build object containing the updated metadata is returned.</p> <p> <b>Learn more</b> </p> <p> <a href="https://docs.aws.amazon.com/gamelift/latest/developerguide/build-intro.html"> Working with Builds</a> </p> <p> <b>Related operations</b> </p> <ul> <li> <p> <a>CreateBuild</a> </p> </li> <li> <p> <a>ListBuilds</a> </p> </li> <li> <p> <a>DescribeBuild</a> </p> </li> <li> <p> <a>UpdateBuild</a> </p> </li> <li> <p> <a>DeleteBuild</a> </p> </li> </ul></p>
+    async fn update_build(
+        &self,
+        input: UpdateBuildInput,
+    ) -> Result<UpdateBuildOutput, RusotoError<UpdateBuildError>> {
+        self.update_build_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Updates fleet properties, including name and description, for a fleet. To update metadata, specify the fleet ID and the property values that you want to change. If successful, the fleet ID for the updated fleet is returned.</p> <p> <b>Learn more</b> </p> <p> <a href="https://docs.aws.amazon.com/gamelift/latest/developerguide/fleets-intro.html"> Working with Fleets</a>.</p> <p> <b>Related operations</b> </p> <ul> <li> <p> <a>CreateFleet</a> </p> </li> <li> <p> <a>ListFleets</a> </p> </li> <li> <p> <a>DeleteFleet</a> </p> </li> <li> <p> <a>DescribeFleetAttributes</a> </p> </li> <li> <p>Update fleets:</p> <ul> <li> <p> <a>UpdateFleetAttributes</a> </p> </li> <li> <p> <a>UpdateFleetCapacity</a> </p> </li> <li> <p> <a>UpdateFleetPortSettings</a> </p> </li> <li> <p> <a>UpdateRuntimeConfiguration</a> </p> </li> </ul> </li> <li> <p>Manage fleet actions:</p> <ul> <li> <p> <a>StartFleetActions</a> </p> </li> <li> <p> <a>StopFleetActions</a> </p> </li> </ul> </li> </ul></p>
+    async fn update_fleet_attributes(
+        &self,
+        input: UpdateFleetAttributesInput,
+    ) -> Result<UpdateFleetAttributesOutput, RusotoError<UpdateFleetAttributesError>> {
+        self.update_fleet_attributes_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Updates capacity settings for a fleet. Use this action to specify the number of EC2 instances (hosts) that you want this fleet to contain. Before calling this action, you may want to call <a>DescribeEC2InstanceLimits</a> to get the maximum capacity based on the fleet&#39;s EC2 instance type.</p> <p>Specify minimum and maximum number of instances. Amazon GameLift will not change fleet capacity to values fall outside of this range. This is particularly important when using auto-scaling (see <a>PutScalingPolicy</a>) to allow capacity to adjust based on player demand while imposing limits on automatic adjustments.</p> <p>To update fleet capacity, specify the fleet ID and the number of instances you want the fleet to host. If successful, Amazon GameLift starts or terminates instances so that the fleet&#39;s active instance count matches the desired instance count. You can view a fleet&#39;s current capacity information by calling <a>DescribeFleetCapacity</a>. If the desired instance count is higher than the instance type&#39;s limit, the &quot;Limit Exceeded&quot; exception occurs.</p> <p> <b>Learn more</b> </p> <p> <a href="https://docs.aws.amazon.com/gamelift/latest/developerguide/fleets-intro.html"> Working with Fleets</a>.</p> <p> <b>Related operations</b> </p> <ul> <li> <p> <a>CreateFleet</a> </p> </li> <li> <p> <a>ListFleets</a> </p> </li> <li> <p> <a>DeleteFleet</a> </p> </li> <li> <p> <a>DescribeFleetAttributes</a> </p> </li> <li> <p>Update fleets:</p> <ul> <li> <p> <a>UpdateFleetAttributes</a> </p> </li> <li> <p> <a>UpdateFleetCapacity</a> </p> </li> <li> <p> <a>UpdateFleetPortSettings</a> </p> </li> <li> <p> <a>UpdateRuntimeConfiguration</a> </p> </li> </ul> </li> <li> <p>Manage fleet actions:</p> <ul> <li> <p> <a>StartFleetActions</a> </p> </li> <li> <p> <a>StopFleetActions</a> </p> </li> </ul> </li> </ul></p>
+    async fn update_fleet_capacity(
+        &self,
+        input: UpdateFleetCapacityInput,
+    ) -> Result<UpdateFleetCapacityOutput, RusotoError<UpdateFleetCapacityError>> {
+        self.update_fleet_capacity_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Updates port settings for a fleet. To update settings, specify the fleet ID to be updated and list the permissions you want to update. List the permissions you want to add in <code>InboundPermissionAuthorizations</code>, and permissions you want to remove in <code>InboundPermissionRevocations</code>. Permissions to be removed must match existing fleet permissions. If successful, the fleet ID for the updated fleet is returned.</p> <p> <b>Learn more</b> </p> <p> <a href="https://docs.aws.amazon.com/gamelift/latest/developerguide/fleets-intro.html"> Working with Fleets</a>.</p> <p> <b>Related operations</b> </p> <ul> <li> <p> <a>CreateFleet</a> </p> </li> <li> <p> <a>ListFleets</a> </p> </li> <li> <p> <a>DeleteFleet</a> </p> </li> <li> <p> <a>DescribeFleetAttributes</a> </p> </li> <li> <p>Update fleets:</p> <ul> <li> <p> <a>UpdateFleetAttributes</a> </p> </li> <li> <p> <a>UpdateFleetCapacity</a> </p> </li> <li> <p> <a>UpdateFleetPortSettings</a> </p> </li> <li> <p> <a>UpdateRuntimeConfiguration</a> </p> </li> </ul> </li> <li> <p>Manage fleet actions:</p> <ul> <li> <p> <a>StartFleetActions</a> </p> </li> <li> <p> <a>StopFleetActions</a> </p> </li> </ul> </li> </ul></p>
+    async fn update_fleet_port_settings(
+        &self,
+        input: UpdateFleetPortSettingsInput,
+    ) -> Result<UpdateFleetPortSettingsOutput, RusotoError<UpdateFleetPortSettingsError>> {
+        self.update_fleet_port_settings_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Updates game session properties. This includes the session name, maximum player count, protection policy, which controls whether or not an active game session can be terminated during a scale-down event, and the player session creation policy, which controls whether or not new players can join the session. To update a game session, specify the game session ID and the values you want to change. If successful, an updated <a>GameSession</a> object is returned. </p> <ul> <li> <p> <a>CreateGameSession</a> </p> </li> <li> <p> <a>DescribeGameSessions</a> </p> </li> <li> <p> <a>DescribeGameSessionDetails</a> </p> </li> <li> <p> <a>SearchGameSessions</a> </p> </li> <li> <p> <a>UpdateGameSession</a> </p> </li> <li> <p> <a>GetGameSessionLogUrl</a> </p> </li> <li> <p>Game session placements</p> <ul> <li> <p> <a>StartGameSessionPlacement</a> </p> </li> <li> <p> <a>DescribeGameSessionPlacement</a> </p> </li> <li> <p> <a>StopGameSessionPlacement</a> </p> </li> </ul> </li> </ul></p>
+    async fn update_game_session(
+        &self,
+        input: UpdateGameSessionInput,
+    ) -> Result<UpdateGameSessionOutput, RusotoError<UpdateGameSessionError>> {
+        self.update_game_session_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Updates settings for a game session queue, which determines how new game session requests in the queue are processed. To update settings, specify the queue name to be updated and provide the new settings. When updating destinations, provide a complete list of destinations. </p> <ul> <li> <p> <a>CreateGameSessionQueue</a> </p> </li> <li> <p> <a>DescribeGameSessionQueues</a> </p> </li> <li> <p> <a>UpdateGameSessionQueue</a> </p> </li> <li> <p> <a>DeleteGameSessionQueue</a> </p> </li> </ul></p>
+    async fn update_game_session_queue(
+        &self,
+        input: UpdateGameSessionQueueInput,
+    ) -> Result<UpdateGameSessionQueueOutput, RusotoError<UpdateGameSessionQueueError>> {
+        self.update_game_session_queue_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Updates settings for a FlexMatch matchmaking configuration. These changes affect all matches and game sessions that are created after the update. To update settings, specify the configuration name to be updated and provide the new settings. </p> <p> <b>Learn more</b> </p> <p> <a href="https://docs.aws.amazon.com/gamelift/latest/developerguide/match-configuration.html"> Design a FlexMatch Matchmaker</a> </p> <p> <b>Related operations</b> </p> <ul> <li> <p> <a>CreateMatchmakingConfiguration</a> </p> </li> <li> <p> <a>DescribeMatchmakingConfigurations</a> </p> </li> <li> <p> <a>UpdateMatchmakingConfiguration</a> </p> </li> <li> <p> <a>DeleteMatchmakingConfiguration</a> </p> </li> <li> <p> <a>CreateMatchmakingRuleSet</a> </p> </li> <li> <p> <a>DescribeMatchmakingRuleSets</a> </p> </li> <li> <p> <a>ValidateMatchmakingRuleSet</a> </p> </li> <li> <p> <a>DeleteMatchmakingRuleSet</a> </p> </li> </ul></p>
+    async fn update_matchmaking_configuration(
+        &self,
+        input: UpdateMatchmakingConfigurationInput,
+    ) -> Result<
+        UpdateMatchmakingConfigurationOutput,
+        RusotoError<UpdateMatchmakingConfigurationError>,
+    > {
+        self.update_matchmaking_configuration_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Updates the current runtime configuration for the specified fleet, which tells Amazon GameLift how to launch server processes on instances in the fleet. You can update a fleet&#39;s runtime configuration at any time after the fleet is created; it does not need to be in an <code>ACTIVE</code> status.</p> <p>To update runtime configuration, specify the fleet ID and provide a <code>RuntimeConfiguration</code> object with an updated set of server process configurations.</p> <p>Each instance in a Amazon GameLift fleet checks regularly for an updated runtime configuration and changes how it launches server processes to comply with the latest version. Existing server processes are not affected by the update; runtime configuration changes are applied gradually as existing processes shut down and new processes are launched during Amazon GameLift&#39;s normal process recycling activity.</p> <p> <b>Learn more</b> </p> <p> <a href="https://docs.aws.amazon.com/gamelift/latest/developerguide/fleets-intro.html"> Working with Fleets</a>.</p> <p> <b>Related operations</b> </p> <ul> <li> <p> <a>CreateFleet</a> </p> </li> <li> <p> <a>ListFleets</a> </p> </li> <li> <p> <a>DeleteFleet</a> </p> </li> <li> <p> <a>DescribeFleetAttributes</a> </p> </li> <li> <p>Update fleets:</p> <ul> <li> <p> <a>UpdateFleetAttributes</a> </p> </li> <li> <p> <a>UpdateFleetCapacity</a> </p> </li> <li> <p> <a>UpdateFleetPortSettings</a> </p> </li> <li> <p> <a>UpdateRuntimeConfiguration</a> </p> </li> </ul> </li> <li> <p>Manage fleet actions:</p> <ul> <li> <p> <a>StartFleetActions</a> </p> </li> <li> <p> <a>StopFleetActions</a> </p> </li> </ul> </li> </ul></p>
+    async fn update_runtime_configuration(
+        &self,
+        input: UpdateRuntimeConfigurationInput,
+    ) -> Result<UpdateRuntimeConfigurationOutput, RusotoError<UpdateRuntimeConfigurationError>>
+    {
+        self.update_runtime_configuration_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Updates Realtime script metadata and content.</p> <p>To update script metadata, specify the script ID and provide updated name and/or version values. </p> <p>To update script content, provide an updated zip file by pointing to either a local file or an Amazon S3 bucket location. You can use either method regardless of how the original script was uploaded. Use the <i>Version</i> parameter to track updates to the script.</p> <p>If the call is successful, the updated metadata is stored in the script record and a revised script is uploaded to the Amazon GameLift service. Once the script is updated and acquired by a fleet instance, the new version is used for all new game sessions. </p> <p> <b>Learn more</b> </p> <p> <a href="https://docs.aws.amazon.com/gamelift/latest/developerguide/realtime-intro.html">Amazon GameLift Realtime Servers</a> </p> <p> <b>Related operations</b> </p> <ul> <li> <p> <a>CreateScript</a> </p> </li> <li> <p> <a>ListScripts</a> </p> </li> <li> <p> <a>DescribeScript</a> </p> </li> <li> <p> <a>UpdateScript</a> </p> </li> <li> <p> <a>DeleteScript</a> </p> </li> </ul></p>
+    async fn update_script(
+        &self,
+        input: UpdateScriptInput,
+    ) -> Result<UpdateScriptOutput, RusotoError<UpdateScriptError>> {
+        self.update_script_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
+    }
+
+    /// <p><p>Validates the syntax of a matchmaking rule or rule set. This operation checks that the rule set is using syntactically correct JSON and that it conforms to allowed property expressions. To validate syntax, provide a rule set JSON string.</p> <p> <b>Learn more</b> </p> <ul> <li> <p> <a href="https://docs.aws.amazon.com/gamelift/latest/developerguide/match-rulesets.html">Build a Rule Set</a> </p> </li> </ul> <p> <b>Related operations</b> </p> <ul> <li> <p> <a>CreateMatchmakingConfiguration</a> </p> </li> <li> <p> <a>DescribeMatchmakingConfigurations</a> </p> </li> <li> <p> <a>UpdateMatchmakingConfiguration</a> </p> </li> <li> <p> <a>DeleteMatchmakingConfiguration</a> </p> </li> <li> <p> <a>CreateMatchmakingRuleSet</a> </p> </li> <li> <p> <a>DescribeMatchmakingRuleSets</a> </p> </li> <li> <p> <a>ValidateMatchmakingRuleSet</a> </p> </li> <li> <p> <a>DeleteMatchmakingRuleSet</a> </p> </li> </ul></p>
+    async fn validate_matchmaking_rule_set(
+        &self,
+        input: ValidateMatchmakingRuleSetInput,
+    ) -> Result<ValidateMatchmakingRuleSetOutput, RusotoError<ValidateMatchmakingRuleSetError>>
+    {
+        self.validate_matchmaking_rule_set_with_metadata(input)
+            .await
+            .map(|(output, _)| output)
     }
 }
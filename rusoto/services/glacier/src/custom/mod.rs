@@ -1,2 +1,5 @@
 #[cfg(test)]
 mod custom_tests;
+mod tree_hash;
+
+pub use self::tree_hash::{TreeHash, TreeHasher, TREE_HASH_CHUNK_SIZE};
@@ -0,0 +1,280 @@
+//! The SHA-256 tree hashes Glacier checks the archives and parts it is sent against.
+//!
+//! The tree hash of some data is the SHA-256 digest of each of its 1 MiB chunks, combined
+//! pairwise into the digest of their concatenation, level by level, until one is left. A
+//! digest without a pair on a level is carried to the next one as it is.
+
+use std::fmt;
+
+use bytes::{Bytes, BytesMut};
+use futures::TryStreamExt;
+use rusoto_core::{ByteStream, RusotoError};
+use sha2::{Digest, Sha256};
+
+use crate::generated::{
+    ArchiveCreationOutput, Glacier, GlacierClient, UploadArchiveError, UploadArchiveInput,
+    UploadMultipartPartError, UploadMultipartPartInput, UploadMultipartPartOutput,
+};
+
+/// The size of the chunks whose digests are the leaves of a tree hash.
+pub const TREE_HASH_CHUNK_SIZE: usize = 1024 * 1024;
+
+/// A SHA-256 tree hash, displayed as the lowercase hex Glacier expects in its
+/// `x-amz-sha256-tree-hash` headers.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct TreeHash([u8; 32]);
+
+impl TreeHash {
+    /// The tree hash of `data`.
+    pub fn of(data: &[u8]) -> TreeHash {
+        let mut hasher = TreeHasher::new();
+        hasher.update(data);
+        hasher.finish()
+    }
+
+    /// The tree hash of the data whose consecutive parts have the tree hashes `parts`, as the
+    /// checksum completing a multipart upload is.
+    ///
+    /// This is only the tree hash of the whole data when every part but the last is a power
+    /// of two of 1 MiB chunks long, as the parts of multipart uploads are. `None` if `parts`
+    /// is empty.
+    pub fn combine(parts: &[TreeHash]) -> Option<TreeHash> {
+        let mut level: Vec<[u8; 32]> = parts.iter().map(|part| part.0).collect();
+        if level.is_empty() {
+            return None;
+        }
+        while level.len() > 1 {
+            level = level
+                .chunks(2)
+                .map(|pair| match pair {
+                    [left, right] => join(left, right),
+                    [single] => *single,
+                    _ => unreachable!("chunks of two"),
+                })
+                .collect();
+        }
+        Some(TreeHash(level[0]))
+    }
+
+    /// The digest the tree is hashed into.
+    pub fn as_bytes(&self) -> &[u8; 32] {
+        &self.0
+    }
+}
+
+impl fmt::Display for TreeHash {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for byte in &self.0 {
+            write!(f, "{:02x}", byte)?;
+        }
+        Ok(())
+    }
+}
+
+/// An incremental computation of a tree hash, from the data it is updated with in any pieces.
+///
+/// Only the chunk being read and a digest per level of the tree are kept, so data of any
+/// size can be hashed as it streams.
+#[derive(Clone, Default)]
+pub struct TreeHasher {
+    chunk: Sha256,
+    chunk_len: usize,
+    /// The digests of the complete subtrees hashed so far, with their heights, the leftmost
+    /// and highest first.
+    subtrees: Vec<(u32, [u8; 32])>,
+}
+
+impl TreeHasher {
+    /// A computation of the tree hash of no data yet.
+    pub fn new() -> TreeHasher {
+        TreeHasher::default()
+    }
+
+    /// Hashes `data` after the data hashed so far.
+    pub fn update(&mut self, mut data: &[u8]) {
+        while !data.is_empty() {
+            let len = data.len().min(TREE_HASH_CHUNK_SIZE - self.chunk_len);
+            self.chunk.update(&data[..len]);
+            self.chunk_len += len;
+            data = &data[len..];
+            if self.chunk_len == TREE_HASH_CHUNK_SIZE {
+                self.finish_chunk();
+            }
+        }
+    }
+
+    /// The tree hash of the data hashed. The tree hash of no data is the digest of no data.
+    pub fn finish(mut self) -> TreeHash {
+        if self.chunk_len > 0 || self.subtrees.is_empty() {
+            self.finish_chunk();
+        }
+        let (_, mut digest) = self.subtrees.pop().expect("a chunk was hashed");
+        // The subtrees left are each higher than the ones after them, which are the levels
+        // the digests without pairs are carried up to.
+        while let Some((_, left)) = self.subtrees.pop() {
+            digest = join(&left, &digest);
+        }
+        TreeHash(digest)
+    }
+
+    fn finish_chunk(&mut self) {
+        let mut digest: [u8; 32] = std::mem::take(&mut self.chunk).finalize().into();
+        self.chunk_len = 0;
+        let mut height = 0;
+        while let Some(&(left_height, left)) = self.subtrees.last() {
+            if left_height != height {
+                break;
+            }
+            self.subtrees.pop();
+            digest = join(&left, &digest);
+            height += 1;
+        }
+        self.subtrees.push((height, digest));
+    }
+}
+
+impl fmt::Debug for TreeHasher {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("TreeHasher")
+            .field("chunk_len", &self.chunk_len)
+            .field("subtrees", &self.subtrees.len())
+            .finish()
+    }
+}
+
+fn join(left: &[u8; 32], right: &[u8; 32]) -> [u8; 32] {
+    let mut hasher = Sha256::new();
+    hasher.update(left);
+    hasher.update(right);
+    hasher.finalize().into()
+}
+
+/// Reads `body` into memory, as Glacier needs its length and tree hash before it is sent.
+async fn read_hashed(body: ByteStream) -> Result<(Bytes, TreeHash), std::io::Error> {
+    let mut hasher = TreeHasher::new();
+    let data = body
+        .try_fold(BytesMut::new(), |mut data, bytes| {
+            hasher.update(&bytes);
+            data.extend_from_slice(&bytes);
+            async move { Ok(data) }
+        })
+        .await?;
+    Ok((data.freeze(), hasher.finish()))
+}
+
+impl GlacierClient {
+    /// Uploads `body` as an archive with `input`, whose body and checksum are set to `body`
+    /// and its tree hash.
+    pub async fn upload_archive_stream(
+        &self,
+        input: UploadArchiveInput,
+        body: ByteStream,
+    ) -> Result<ArchiveCreationOutput, RusotoError<UploadArchiveError>> {
+        let (body, checksum) = read_hashed(body).await?;
+        self.upload_archive(UploadArchiveInput {
+            body: Some(body),
+            checksum: Some(checksum.to_string()),
+            ..input
+        })
+        .await
+    }
+
+    /// Uploads `body` as a part of a multipart upload with `input`, whose body and checksum
+    /// are set to `body` and its tree hash. The tree hashes of the parts, which the output
+    /// has, combine into the checksum completing the upload with `TreeHash::combine`.
+    pub async fn upload_multipart_part_stream(
+        &self,
+        input: UploadMultipartPartInput,
+        body: ByteStream,
+    ) -> Result<UploadMultipartPartOutput, RusotoError<UploadMultipartPartError>> {
+        let (body, checksum) = read_hashed(body).await?;
+        self.upload_multipart_part(UploadMultipartPartInput {
+            body: Some(body),
+            checksum: Some(checksum.to_string()),
+            ..input
+        })
+        .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rusoto_core::Region;
+    use rusoto_mock::{MockCredentialsProvider, MockRequestDispatcher};
+
+    fn sha256(data: &[u8]) -> [u8; 32] {
+        Sha256::digest(data).into()
+    }
+
+    fn data(len: usize) -> Vec<u8> {
+        (0..len).map(|i| (i % 251) as u8).collect()
+    }
+
+    #[test]
+    fn hashes_trees_of_chunks() {
+        assert_eq!(TreeHash::of(b"").0, sha256(b""));
+        assert_eq!(TreeHash::of(b"archive").0, sha256(b"archive"));
+
+        let data = data(4 * TREE_HASH_CHUNK_SIZE + 5);
+        let chunks: Vec<_> = data.chunks(TREE_HASH_CHUNK_SIZE).map(sha256).collect();
+        let four = join(&join(&chunks[0], &chunks[1]), &join(&chunks[2], &chunks[3]));
+        assert_eq!(TreeHash::of(&data[..4 * TREE_HASH_CHUNK_SIZE]).0, four);
+        assert_eq!(TreeHash::of(&data).0, join(&four, &chunks[4]));
+        assert_eq!(
+            TreeHash::of(&data[..3 * TREE_HASH_CHUNK_SIZE]).0,
+            join(&join(&chunks[0], &chunks[1]), &chunks[2])
+        );
+    }
+
+    #[test]
+    fn hashes_incrementally() {
+        let data = data(7 * TREE_HASH_CHUNK_SIZE + 3);
+        let mut hasher = TreeHasher::new();
+        for piece in data.chunks(300_007) {
+            hasher.update(piece);
+        }
+        let hash = hasher.finish();
+        assert_eq!(hash, TreeHash::of(&data));
+
+        let parts: Vec<_> = data
+            .chunks(2 * TREE_HASH_CHUNK_SIZE)
+            .map(TreeHash::of)
+            .collect();
+        assert_eq!(TreeHash::combine(&parts), Some(hash));
+        assert_eq!(TreeHash::combine(&[]), None);
+        assert_eq!(hash.to_string().len(), 64);
+    }
+
+    #[tokio::test]
+    async fn uploads_streams_with_their_tree_hash() {
+        let data = data(TREE_HASH_CHUNK_SIZE + 1);
+        let expected = TreeHash::of(&data).to_string();
+        let dispatcher = MockRequestDispatcher::with_status(201)
+            .with_header("x-amz-archive-id", "archive")
+            .with_request_checker(move |request| {
+                assert_eq!(
+                    request.headers().get("x-amz-sha256-tree-hash"),
+                    Some(&vec![expected.clone().into_bytes()])
+                );
+            });
+        let client = GlacierClient::new_with(dispatcher, MockCredentialsProvider, Region::UsEast1);
+        let stream = futures::stream::iter(
+            data.chunks(1000)
+                .map(|piece| Ok(Bytes::copy_from_slice(piece)))
+                .collect::<Vec<_>>(),
+        );
+        let output = client
+            .upload_archive_stream(
+                UploadArchiveInput {
+                    account_id: "-".to_owned(),
+                    vault_name: "vault".to_owned(),
+                    ..Default::default()
+                },
+                ByteStream::new(stream),
+            )
+            .await
+            .unwrap();
+        assert_eq!(output.archive_id.as_deref(), Some("archive"));
+    }
+}